@@ -0,0 +1,55 @@
+//! Assumes a role with the credentials of another assumed role, then asks STS who the final
+//! credentials belong to.
+
+use rusoto_core::credential::AutoRefreshingProvider;
+use rusoto_core::HttpClient;
+use rusoto_sts::{
+    GetCallerIdentityRequest, Sts, StsAssumeRoleSessionCredentialsProvider, StsClient,
+};
+
+use crate::{localstack_client, localstack_region, ExampleResult};
+
+/// The role assumed with the static credentials.
+pub const FIRST_ROLE_ARN: &str = "arn:aws:iam::000000000000:role/rusoto-examples-first";
+/// The role assumed with the credentials of `FIRST_ROLE_ARN`.
+pub const SECOND_ROLE_ARN: &str = "arn:aws:iam::000000000000:role/rusoto-examples-second";
+
+/// The identity at the end of the chain.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallerIdentity {
+    /// The account the credentials belong to
+    pub account: Option<String>,
+    /// The ARN of the assumed role session
+    pub arn: Option<String>,
+}
+
+/// Runs the example, starting from LocalStack's static credentials.
+pub fn run() -> ExampleResult<CallerIdentity> {
+    let base: StsClient = localstack_client(StsClient::new_with);
+    let first = AutoRefreshingProvider::new(assume_role(base, FIRST_ROLE_ARN))?;
+
+    // STS clients signing with the credentials of the previous role
+    let chained = StsClient::new_with(HttpClient::new()?, first, localstack_region());
+    let second = AutoRefreshingProvider::new(assume_role(chained, SECOND_ROLE_ARN))?;
+    let client = StsClient::new_with(HttpClient::new()?, second, localstack_region());
+
+    let identity = client
+        .get_caller_identity(GetCallerIdentityRequest {})
+        .sync()?;
+    Ok(CallerIdentity {
+        account: identity.account,
+        arn: identity.arn,
+    })
+}
+
+fn assume_role(client: StsClient, role_arn: &str) -> StsAssumeRoleSessionCredentialsProvider {
+    StsAssumeRoleSessionCredentialsProvider::new(
+        client,
+        role_arn.to_owned(),
+        "rusoto-examples".to_owned(),
+        None,
+        None,
+        None,
+        None,
+    )
+}
@@ -0,0 +1,9 @@
+use std::error::Error;
+
+use rusoto_examples::assume_role_chain;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let identity = assume_role_chain::run()?;
+    println!("{:#?}", identity);
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use std::error::Error;
+
+use rusoto_dynamodb::DynamoDbClient;
+use rusoto_examples::{dynamodb_mapping, localstack_client};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let client: DynamoDbClient = localstack_client(DynamoDbClient::new_with);
+    let report = dynamodb_mapping::run(&client)?;
+    println!("{:#?}", report);
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use std::error::Error;
+
+use rusoto_examples::{localstack_client, s3_transfer};
+use rusoto_s3::S3Client;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let client: S3Client = localstack_client(S3Client::new_with);
+    let report = s3_transfer::run(&client)?;
+    println!("{:#?}", report);
+    Ok(())
+}
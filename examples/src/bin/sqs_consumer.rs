@@ -0,0 +1,11 @@
+use std::error::Error;
+
+use rusoto_examples::{localstack_client, sqs_consumer};
+use rusoto_sqs::SqsClient;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let client: SqsClient = localstack_client(SqsClient::new_with);
+    let report = sqs_consumer::run(&client)?;
+    println!("{:#?}", report);
+    Ok(())
+}
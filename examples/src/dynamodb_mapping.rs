@@ -0,0 +1,160 @@
+//! Stores customers and their orders in a single DynamoDB table with the `single_table`
+//! mapping, then reads them back.
+
+use std::time::Duration;
+
+use futures::Stream;
+use rusoto_core::waiter::WaiterConfig;
+use rusoto_core::RusotoFuture;
+use rusoto_dynamodb::single_table::{
+    number_value, string_value, take_number, take_string, Entity, EntityError, SingleTable,
+    SingleTableExt,
+};
+use rusoto_dynamodb::stream::Item;
+use rusoto_dynamodb::waiters::TableWaiterExt;
+use rusoto_dynamodb::{
+    AttributeDefinition, BillingMode, CreateTableInput, DeleteTableInput, DynamoDb, DynamoDbClient,
+    KeySchemaElement, KeyType, ScalarAttributeType,
+};
+
+use crate::{unique_name, ExampleResult};
+
+/// A customer, stored in its own partition.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Customer {
+    /// Identifier of the customer
+    pub id: String,
+    /// Name of the customer
+    pub name: String,
+}
+
+impl Entity for Customer {
+    const TYPE: &'static str = "Customer";
+    const PARTITION_KEY: &'static str = "CUSTOMER#{id}";
+    const SORT_KEY: &'static str = "PROFILE#{id}";
+
+    fn to_attributes(&self) -> Item {
+        let mut item = Item::new();
+        item.insert("id".to_owned(), string_value(self.id.as_str()));
+        item.insert("name".to_owned(), string_value(self.name.as_str()));
+        item
+    }
+
+    fn from_attributes(mut item: Item) -> Result<Customer, EntityError> {
+        Ok(Customer {
+            id: take_string(&mut item, "id")?,
+            name: take_string(&mut item, "name")?,
+        })
+    }
+}
+
+/// An order, stored in the partition of its customer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Order {
+    /// Identifier of the customer who placed the order
+    pub customer_id: String,
+    /// Identifier of the order
+    pub order_id: String,
+    /// Total of the order in cents
+    pub total: i64,
+}
+
+impl Entity for Order {
+    const TYPE: &'static str = "Order";
+    const PARTITION_KEY: &'static str = "CUSTOMER#{customer_id}";
+    const SORT_KEY: &'static str = "ORDER#{order_id}";
+
+    fn to_attributes(&self) -> Item {
+        let mut item = Item::new();
+        item.insert(
+            "customer_id".to_owned(),
+            string_value(self.customer_id.as_str()),
+        );
+        item.insert("order_id".to_owned(), string_value(self.order_id.as_str()));
+        item.insert("total".to_owned(), number_value(self.total));
+        item
+    }
+
+    fn from_attributes(mut item: Item) -> Result<Order, EntityError> {
+        Ok(Order {
+            customer_id: take_string(&mut item, "customer_id")?,
+            order_id: take_string(&mut item, "order_id")?,
+            total: take_number(&mut item, "total")?,
+        })
+    }
+}
+
+/// The entities read back from the table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MappingReport {
+    /// The customer, read by key
+    pub customer: Customer,
+    /// The orders of the customer, queried from its partition
+    pub orders: Vec<Order>,
+}
+
+/// Runs the example with a new table, which is deleted afterwards.
+pub fn run(client: &DynamoDbClient) -> ExampleResult<MappingReport> {
+    let table_name = unique_name("rusoto-examples");
+    client
+        .create_table_and_wait(
+            create_table_input(&table_name),
+            WaiterConfig::new(Duration::from_secs(1), 60),
+        )
+        .sync()?;
+
+    let report = store_and_read(client, &SingleTable::new(&table_name));
+
+    client
+        .delete_table(DeleteTableInput { table_name })
+        .sync()?;
+    report
+}
+
+fn create_table_input(table_name: &str) -> CreateTableInput {
+    let key = |name: &str, key_type: KeyType| KeySchemaElement {
+        attribute_name: name.to_owned(),
+        key_type,
+    };
+    let attribute = |name: &str| AttributeDefinition {
+        attribute_name: name.to_owned(),
+        attribute_type: ScalarAttributeType::S,
+    };
+    CreateTableInput {
+        table_name: table_name.to_owned(),
+        key_schema: vec![key("PK", KeyType::Hash), key("SK", KeyType::Range)],
+        attribute_definitions: vec![attribute("PK"), attribute("SK")],
+        billing_mode: Some(BillingMode::PayPerRequest),
+        ..Default::default()
+    }
+}
+
+fn store_and_read(client: &DynamoDbClient, table: &SingleTable) -> ExampleResult<MappingReport> {
+    let customer = Customer {
+        id: "42".to_owned(),
+        name: "Ferris".to_owned(),
+    };
+    client.put_entity(table, &customer).sync()?;
+    for (order_id, total) in &[("1", 1250), ("2", 399), ("3", 9900)] {
+        let order = Order {
+            customer_id: customer.id.clone(),
+            order_id: (*order_id).to_owned(),
+            total: *total,
+        };
+        client.put_entity(table, &order).sync()?;
+    }
+
+    // the profile and the orders share a partition, but only the orders are queried
+    let orders: Vec<Order> = RusotoFuture::from_future(
+        client
+            .query_entities(table, &[("customer_id", customer.id.as_str())])
+            .collect(),
+    )
+    .sync()?;
+    let customer = client
+        .get_entity::<Customer>(table, &[("id", customer.id.as_str())])
+        .sync()?
+        .ok_or("the customer was not found")?;
+
+    Ok(MappingReport { customer, orders })
+}
@@ -0,0 +1,51 @@
+//! End-to-end examples of Rusoto's higher-level APIs, run against
+//! [LocalStack](https://github.com/localstack/localstack).
+//!
+//! Each module runs one scenario with clients created by `localstack_client`, and has a program
+//! of the same name in `src/bin` running it. The tests in `tests/localstack.rs` run them all.
+
+use std::env;
+use std::error::Error;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rusoto_core::credential::StaticProvider;
+use rusoto_core::{HttpClient, Region};
+
+pub mod assume_role_chain;
+pub mod dynamodb_mapping;
+pub mod s3_transfer;
+pub mod sqs_consumer;
+
+/// The endpoint LocalStack serves every service on by default.
+pub const DEFAULT_ENDPOINT: &str = "http://localhost:4566";
+
+/// The outcome of an example.
+pub type ExampleResult<T> = Result<T, Box<dyn Error>>;
+
+/// A custom region sending requests to LocalStack, at `DEFAULT_ENDPOINT` or the endpoint set in
+/// the `LOCALSTACK_ENDPOINT` environment variable.
+pub fn localstack_region() -> Region {
+    Region::Custom {
+        name: "us-east-1".to_owned(),
+        endpoint: env::var("LOCALSTACK_ENDPOINT").unwrap_or_else(|_| DEFAULT_ENDPOINT.to_owned()),
+    }
+}
+
+/// Creates a client talking to LocalStack with its dummy credentials, e.g.
+/// `localstack_client(S3Client::new_with)`.
+pub fn localstack_client<C, F>(new_with: F) -> C
+where
+    F: FnOnce(HttpClient, StaticProvider, Region) -> C,
+{
+    let dispatcher = HttpClient::new().expect("failed to create request dispatcher");
+    let credentials = StaticProvider::new_minimal("test".to_owned(), "test".to_owned());
+    new_with(dispatcher, credentials, localstack_region())
+}
+
+/// A name starting with `prefix` which no earlier run of the examples used.
+pub fn unique_name(prefix: &str) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    format!("{}-{}-{}", prefix, now.as_secs(), now.subsec_nanos())
+}
@@ -0,0 +1,88 @@
+//! Uploads an object to a new bucket, then downloads it in parallel ranged parts with
+//! `DownloadExt` and checks that the data survived the round trip.
+
+use bytes::Bytes;
+use futures::Stream;
+use rusoto_core::RusotoFuture;
+use rusoto_s3::download::{DownloadConfig, DownloadExt};
+use rusoto_s3::{
+    CreateBucketRequest, DeleteBucketRequest, DeleteObjectRequest, GetObjectRequest,
+    PutObjectRequest, S3Client, S3,
+};
+
+use crate::{unique_name, ExampleResult};
+
+const KEY: &str = "data.bin";
+const OBJECT_SIZE: usize = 3 * 1024 * 1024 + 17;
+const PART_SIZE: u64 = 1024 * 1024;
+
+/// The outcome of the transfer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferReport {
+    /// Size of the object in bytes
+    pub bytes: usize,
+    /// Number of ranged requests the object was downloaded with
+    pub parts: usize,
+}
+
+/// Runs the example with a new bucket, which is deleted afterwards.
+pub fn run(client: &S3Client) -> ExampleResult<TransferReport> {
+    let bucket = unique_name("rusoto-examples");
+    client
+        .create_bucket(CreateBucketRequest {
+            bucket: bucket.clone(),
+            ..Default::default()
+        })
+        .sync()?;
+
+    let report = transfer(client, &bucket);
+
+    // clean up whether or not the transfer worked
+    let _ = client
+        .delete_object(DeleteObjectRequest {
+            bucket: bucket.clone(),
+            key: KEY.to_owned(),
+            ..Default::default()
+        })
+        .sync();
+    client
+        .delete_bucket(DeleteBucketRequest { bucket })
+        .sync()?;
+    report
+}
+
+fn transfer(client: &S3Client, bucket: &str) -> ExampleResult<TransferReport> {
+    let data: Vec<u8> = (0..OBJECT_SIZE).map(|i| (i % 251) as u8).collect();
+    client
+        .put_object(PutObjectRequest {
+            bucket: bucket.to_owned(),
+            key: KEY.to_owned(),
+            content_length: Some(data.len() as i64),
+            body: Some(data.clone().into()),
+            ..Default::default()
+        })
+        .sync()?;
+
+    let request = GetObjectRequest {
+        bucket: bucket.to_owned(),
+        key: KEY.to_owned(),
+        ..Default::default()
+    };
+    let config = DownloadConfig {
+        part_size: PART_SIZE,
+        concurrency: 4,
+        ..Default::default()
+    };
+    // the parts arrive in order, however many are downloaded at once
+    let parts: Vec<Bytes> =
+        RusotoFuture::from_future(client.download_parts(request, config).collect()).sync()?;
+
+    let downloaded: Vec<u8> = parts.iter().flat_map(|part| part.iter().cloned()).collect();
+    if downloaded != data {
+        return Err("the downloaded object differs from the uploaded one".into());
+    }
+    Ok(TransferReport {
+        bytes: downloaded.len(),
+        parts: parts.len(),
+    })
+}
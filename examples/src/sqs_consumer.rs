@@ -0,0 +1,108 @@
+//! Sends a batch of messages to a new queue, then consumes them with long polling, deleting
+//! each received batch once it is handled.
+
+use rusoto_sqs::{
+    CreateQueueRequest, DeleteMessageBatchRequest, DeleteMessageBatchRequestEntry,
+    DeleteQueueRequest, ReceiveMessageRequest, SendMessageBatchRequest,
+    SendMessageBatchRequestEntry, Sqs, SqsClient,
+};
+
+use crate::{unique_name, ExampleResult};
+
+const MESSAGES: usize = 10;
+/// Receives which may return no message before giving up on the remaining ones.
+const MAX_EMPTY_RECEIVES: usize = 3;
+
+/// The messages sent and consumed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsumerReport {
+    /// Number of messages sent
+    pub sent: usize,
+    /// Bodies of the messages received, sorted
+    pub received: Vec<String>,
+}
+
+/// Runs the example with a new queue, which is deleted afterwards.
+pub fn run(client: &SqsClient) -> ExampleResult<ConsumerReport> {
+    let queue_url = client
+        .create_queue(CreateQueueRequest {
+            queue_name: unique_name("rusoto-examples"),
+            ..Default::default()
+        })
+        .sync()?
+        .queue_url
+        .ok_or("the queue has no URL")?;
+
+    let report = send_and_consume(client, &queue_url);
+
+    client
+        .delete_queue(DeleteQueueRequest { queue_url })
+        .sync()?;
+    report
+}
+
+fn send_and_consume(client: &SqsClient, queue_url: &str) -> ExampleResult<ConsumerReport> {
+    let entries = (0..MESSAGES)
+        .map(|i| SendMessageBatchRequestEntry {
+            id: i.to_string(),
+            message_body: format!("message {}", i),
+            ..Default::default()
+        })
+        .collect();
+    let sent = client
+        .send_message_batch(SendMessageBatchRequest {
+            queue_url: queue_url.to_owned(),
+            entries,
+        })
+        .sync()?;
+    if !sent.failed.is_empty() {
+        return Err(format!("{} messages were not sent", sent.failed.len()).into());
+    }
+
+    let mut received = Vec::new();
+    let mut empty_receives = 0;
+    while received.len() < MESSAGES && empty_receives < MAX_EMPTY_RECEIVES {
+        let messages = client
+            .receive_message(ReceiveMessageRequest {
+                queue_url: queue_url.to_owned(),
+                max_number_of_messages: Some(10),
+                wait_time_seconds: Some(5),
+                visibility_timeout: Some(30),
+                ..Default::default()
+            })
+            .sync()?
+            .messages
+            .unwrap_or_default();
+        if messages.is_empty() {
+            empty_receives += 1;
+            continue;
+        }
+
+        // a message is only deleted once it has been handled, here by keeping its body
+        let mut entries = Vec::with_capacity(messages.len());
+        for (i, message) in messages.into_iter().enumerate() {
+            received.extend(message.body);
+            if let Some(receipt_handle) = message.receipt_handle {
+                entries.push(DeleteMessageBatchRequestEntry {
+                    id: i.to_string(),
+                    receipt_handle,
+                });
+            }
+        }
+        let deleted = client
+            .delete_message_batch(DeleteMessageBatchRequest {
+                queue_url: queue_url.to_owned(),
+                entries,
+            })
+            .sync()?;
+        if !deleted.failed.is_empty() {
+            return Err(format!("{} messages were not deleted", deleted.failed.len()).into());
+        }
+    }
+
+    received.sort();
+    Ok(ConsumerReport {
+        sent: MESSAGES,
+        received,
+    })
+}
@@ -0,0 +1,52 @@
+//! Runs every example against LocalStack. These tests are ignored by default; start LocalStack
+//! and run them with `cargo test -- --ignored`.
+
+use rusoto_dynamodb::DynamoDbClient;
+use rusoto_examples::{
+    assume_role_chain, dynamodb_mapping, localstack_client, s3_transfer, sqs_consumer,
+};
+use rusoto_s3::S3Client;
+use rusoto_sqs::SqsClient;
+
+#[test]
+#[ignore]
+fn s3_transfer_round_trips_the_object() {
+    let client: S3Client = localstack_client(S3Client::new_with);
+    let report = s3_transfer::run(&client).unwrap();
+    assert_eq!(report.bytes, 3 * 1024 * 1024 + 17);
+    assert_eq!(report.parts, 4);
+}
+
+#[test]
+#[ignore]
+fn dynamodb_mapping_reads_back_the_entities() {
+    let client: DynamoDbClient = localstack_client(DynamoDbClient::new_with);
+    let report = dynamodb_mapping::run(&client).unwrap();
+    assert_eq!(report.customer.name, "Ferris");
+    let order_ids: Vec<&str> = report
+        .orders
+        .iter()
+        .map(|order| order.order_id.as_str())
+        .collect();
+    assert_eq!(order_ids, vec!["1", "2", "3"]);
+}
+
+#[test]
+#[ignore]
+fn sqs_consumer_receives_every_message_once() {
+    let client: SqsClient = localstack_client(SqsClient::new_with);
+    let report = sqs_consumer::run(&client).unwrap();
+    let expected: Vec<String> = {
+        let mut bodies: Vec<String> = (0..report.sent).map(|i| format!("message {}", i)).collect();
+        bodies.sort();
+        bodies
+    };
+    assert_eq!(report.received, expected);
+}
+
+#[test]
+#[ignore]
+fn assume_role_chain_ends_with_the_second_role() {
+    let identity = assume_role_chain::run().unwrap();
+    assert!(identity.arn.is_some());
+}
@@ -5,6 +5,7 @@ extern crate rusoto_core;
 
 use rusoto_application_autoscaling::{
     ApplicationAutoScaling, ApplicationAutoScalingClient, DescribeScalingPoliciesRequest,
+    ServiceNamespace,
 };
 use rusoto_core::Region;
 
@@ -13,8 +14,12 @@ fn should_describe_scaling_policies() {
     let client = ApplicationAutoScalingClient::new(Region::UsEast1);
 
     let request = DescribeScalingPoliciesRequest {
-        service_namespace: "ec2".to_owned(),
-        ..Default::default()
+        max_results: None,
+        next_token: None,
+        policy_names: None,
+        resource_id: None,
+        scalable_dimension: None,
+        service_namespace: ServiceNamespace::Ec2,
     };
 
     client.describe_scaling_policies(request).sync().unwrap();
@@ -3,7 +3,9 @@
 extern crate rusoto_comprehend;
 extern crate rusoto_core;
 
-use rusoto_comprehend::{Comprehend, ComprehendClient, DetectSentimentRequest};
+use rusoto_comprehend::{
+    Comprehend, ComprehendClient, DetectSentimentRequest, LanguageCode, SentimentType,
+};
 use rusoto_core::Region;
 
 #[test]
@@ -11,16 +13,15 @@ fn should_detect_sentiment() {
     let client = ComprehendClient::new(Region::UsEast1);
 
     let request = DetectSentimentRequest {
-        language_code: "en".to_owned(),
+        language_code: LanguageCode::En,
         text: "everything is awesome".to_owned(),
-        ..Default::default()
     };
 
     match client.detect_sentiment(request).sync() {
         Ok(response) => {
             println!("{:#?}", response);
             assert_eq!(
-                "POSITIVE",
+                SentimentType::Positive,
                 response
                     .sentiment
                     .expect("Should have a sentiment in response")
@@ -4,15 +4,20 @@ extern crate rusoto_core;
 extern crate rusoto_ram;
 
 use rusoto_core::Region;
-use rusoto_ram::{ListResourcesRequest, Ram, RamClient};
+use rusoto_ram::{ListResourcesRequest, Ram, RamClient, ResourceOwner};
 
 #[test]
 fn should_work() {
     let client = RamClient::new(Region::UsEast1);
     let response = client
         .list_resources(ListResourcesRequest {
-            resource_owner: "SELF".into(),
-            ..ListResourcesRequest::default()
+            max_results: None,
+            next_token: None,
+            principal: None,
+            resource_arns: None,
+            resource_owner: ResourceOwner::SelfValue,
+            resource_share_arns: None,
+            resource_type: None,
         })
         .sync()
         .expect("expected an ok response");
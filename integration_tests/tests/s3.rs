@@ -26,8 +26,8 @@ use rusoto_s3::{
     CORSConfiguration, CORSRule, CompleteMultipartUploadRequest, CompletedMultipartUpload,
     CompletedPart, CopyObjectRequest, CreateBucketRequest, CreateMultipartUploadRequest,
     DeleteBucketRequest, DeleteObjectRequest, GetObjectError, GetObjectRequest, HeadObjectRequest,
-    ListObjectsRequest, ListObjectsV2Request, PutBucketCorsRequest, PutObjectRequest, S3Client,
-    StreamingBody, UploadPartCopyRequest, UploadPartRequest, S3,
+    ListObjectsRequest, ListObjectsV2Request, MetadataDirective, PutBucketCorsRequest,
+    PutObjectRequest, S3Client, StreamingBody, UploadPartCopyRequest, UploadPartRequest, S3,
 };
 
 struct TestS3Client {
@@ -846,7 +846,7 @@ fn test_copy_object(client: &S3Client, bucket: &str, filename: &str) {
         copy_source: format!("{}/{}", bucket, filename),
         cache_control: Some("max-age=123".to_owned()),
         content_type: Some("application/json".to_owned()),
-        metadata_directive: Some("REPLACE".to_owned()),
+        metadata_directive: Some(MetadataDirective::Replace),
         ..Default::default()
     };
 
@@ -864,7 +864,7 @@ fn test_copy_object_utf8(client: &S3Client, bucket: &str, filename: &str) {
         copy_source: rusoto_s3::util::encode_key(format!("{}/{}", bucket, filename)),
         cache_control: Some("max-age=123".to_owned()),
         content_type: Some("application/json".to_owned()),
-        metadata_directive: Some("REPLACE".to_owned()),
+        metadata_directive: Some(MetadataDirective::Replace),
         ..Default::default()
     };
 
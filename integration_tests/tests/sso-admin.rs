@@ -0,0 +1,17 @@
+#![cfg(feature = "sso-admin")]
+
+extern crate rusoto_core;
+extern crate rusoto_sso_admin;
+
+use rusoto_core::Region;
+use rusoto_sso_admin::{ListInstancesRequest, SsoAdmin, SsoAdminClient};
+
+#[test]
+fn should_list_instances() {
+    let client = SsoAdminClient::new(Region::UsEast1);
+    let response = client
+        .list_instances(ListInstancesRequest::default())
+        .sync()
+        .expect("expected an ok response");
+    println!("response is {:#?}", response);
+}
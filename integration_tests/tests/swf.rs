@@ -4,15 +4,18 @@ extern crate rusoto_core;
 extern crate rusoto_swf;
 
 use rusoto_core::Region;
-use rusoto_swf::{ListDomainsInput, Swf, SwfClient};
+use rusoto_swf::{ListDomainsInput, RegistrationStatus, Swf, SwfClient};
 
 #[test]
 fn should_list_domains() {
     let client = SwfClient::new(Region::UsEast1);
 
-    let mut request = ListDomainsInput::default();
-    request.maximum_page_size = Some(10);
-    request.registration_status = "REGISTERED".to_string();
+    let request = ListDomainsInput {
+        maximum_page_size: Some(10),
+        next_page_token: None,
+        registration_status: RegistrationStatus::Registered,
+        reverse_order: None,
+    };
 
     client.list_domains(request).sync().unwrap();
 }
@@ -41,6 +41,7 @@ extern crate serde_json;
 
 use std::fs::File;
 use std::io::Read;
+use std::sync::Mutex;
 use std::time::Duration;
 
 use futures::future::{err, ok, FutureResult};
@@ -160,6 +161,41 @@ impl DispatchSignedRequest for MockRequestDispatcher {
     }
 }
 
+/// Mocks a sequence of API responses, one per request
+///
+/// Each request is answered by the next dispatcher of the sequence, e.g. for operations making
+/// a `HeadObject` call before their `GetObject` calls. Dispatching more requests than there are
+/// dispatchers panics.
+pub struct MultipleMockRequestDispatcher {
+    dispatchers: Mutex<std::vec::IntoIter<MockRequestDispatcher>>,
+}
+
+impl MultipleMockRequestDispatcher {
+    /// Returns an instance answering requests with `dispatchers`, in order
+    pub fn new<I>(dispatchers: I) -> MultipleMockRequestDispatcher
+    where
+        I: IntoIterator<Item = MockRequestDispatcher>,
+    {
+        MultipleMockRequestDispatcher {
+            dispatchers: Mutex::new(dispatchers.into_iter().collect::<Vec<_>>().into_iter()),
+        }
+    }
+}
+
+impl DispatchSignedRequest for MultipleMockRequestDispatcher {
+    type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+    fn dispatch(&self, request: SignedRequest, timeout: Option<Duration>) -> Self::Future {
+        let dispatcher = self
+            .dispatchers
+            .lock()
+            .unwrap()
+            .next()
+            .expect("no mock response left for the request");
+        dispatcher.dispatch(request, timeout)
+    }
+}
+
 /// An interface for producing response body content
 pub trait ReadMockResponse {
     /// Return a response body string for a given directory and file name
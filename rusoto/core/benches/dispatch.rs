@@ -0,0 +1,127 @@
+//! Benchmarks of serializing, signing and dispatching requests and deserializing responses,
+//! with a dispatcher which answers immediately.
+//!
+//! Run with `cargo bench -p rusoto_core`.
+
+use std::collections::HashMap;
+use std::thread;
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use futures::future::{self, FutureResult};
+use http::{HeaderMap, StatusCode};
+use rusoto_core::credential::StaticProvider;
+use rusoto_core::request::HttpResponse;
+use rusoto_core::service::{Operation, ServiceClient};
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::{ByteStream, Client, DispatchSignedRequest, HttpDispatchError, Region};
+use serde_derive::Serialize;
+use serde_json::Value;
+
+const GET_ITEM_RESPONSE: &[u8] = br#"{"Item":{"pk":{"S":"USER#42"},"sk":{"S":"PROFILE"},"name":{"S":"Jane"},"visits":{"N":"1234"}}}"#;
+
+struct StaticDispatcher;
+
+impl DispatchSignedRequest for StaticDispatcher {
+    type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+    fn dispatch(&self, _request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+        future::ok(HttpResponse {
+            status: StatusCode::OK,
+            body: ByteStream::from(GET_ITEM_RESPONSE.to_vec()),
+            headers: HeaderMap::default(),
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct AttributeValue {
+    #[serde(rename = "S")]
+    s: String,
+}
+
+#[derive(Serialize)]
+struct GetItem {
+    #[serde(rename = "TableName")]
+    table_name: String,
+    #[serde(rename = "Key")]
+    key: HashMap<String, AttributeValue>,
+}
+
+impl Operation for GetItem {
+    type Output = Value;
+    type Error = ();
+
+    fn method(&self) -> &str {
+        "POST"
+    }
+
+    fn path(&self) -> String {
+        "/".to_owned()
+    }
+
+    fn body(&self) -> Option<Vec<u8>> {
+        serde_json::to_vec(self).ok()
+    }
+
+    fn content_type(&self) -> &str {
+        "application/x-amz-json-1.0"
+    }
+}
+
+fn get_item() -> GetItem {
+    let mut key = HashMap::new();
+    for (name, value) in &[("pk", "USER#42"), ("sk", "PROFILE")] {
+        key.insert(
+            (*name).to_owned(),
+            AttributeValue {
+                s: (*value).to_owned(),
+            },
+        );
+    }
+    GetItem {
+        table_name: "table".to_owned(),
+        key,
+    }
+}
+
+fn service_client() -> ServiceClient {
+    let credentials = StaticProvider::new_minimal("AKIDEXAMPLE".to_owned(), "secret".to_owned());
+    let client = Client::new_with(credentials, StaticDispatcher);
+    ServiceClient::new_with_client(client, "dynamodb", Region::UsEast1)
+}
+
+fn serialize(c: &mut Criterion) {
+    let operation = get_item();
+    c.bench_function("serialize json request", |b| b.iter(|| operation.body()));
+}
+
+fn dispatch(c: &mut Criterion) {
+    let client = service_client();
+    let operation = get_item();
+    c.bench_function("dispatch json request", |b| {
+        b.iter(|| client.call(&operation).sync().unwrap())
+    });
+
+    c.bench_function("dispatch 1000 json requests on 4 threads", |b| {
+        b.iter(|| {
+            let threads: Vec<_> = (0..4)
+                .map(|_| {
+                    let client = client.clone();
+                    thread::spawn(move || {
+                        let operation = get_item();
+                        for _ in 0..250 {
+                            client.call(&operation).sync().unwrap();
+                        }
+                    })
+                })
+                .collect();
+            for thread in threads {
+                thread.join().unwrap();
+            }
+        })
+    });
+}
+
+criterion_group!(benches, serialize, dispatch);
+criterion_main!(benches);
@@ -0,0 +1,211 @@
+//! Batching items for the operations which accept many of them per call.
+//!
+//! Operations such as `PutRecordBatch`, `PutMetricData` or `PutLogEvents` accept batches of
+//! items within per-call limits. A `Batcher` decides how items fill batches, and `batched` turns
+//! a channel of items into a stream of batches, each sent once it's full or once its oldest item
+//! waited for the flush interval. `backoff` and `is_transient` help retrying the calls sending
+//! the batches.
+
+use std::time::Duration;
+
+use futures::{Async, Future, Poll, Stream};
+
+use crate::error::RusotoError;
+use crate::request::HttpDispatchError;
+use crate::waiter::sleep;
+
+/// The longest delay returned by `backoff`.
+pub const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Groups items into batches.
+pub trait Batcher {
+    /// The items put into batches
+    type Item;
+    /// The batches of items
+    type Batch;
+
+    /// Adds `item` to the pending batch, returning a batch once one is full.
+    fn push(&mut self, item: Self::Item) -> Option<Self::Batch>;
+
+    /// Returns a batch of the pending items, if there are any.
+    ///
+    /// Flushing the batcher calls it until it returns `None`.
+    fn flush(&mut self) -> Option<Self::Batch>;
+
+    /// Whether no item is pending.
+    fn is_empty(&self) -> bool;
+}
+
+/// Stream of the batches of the items received by a channel, returned by `batched`.
+pub struct Batched<S, B, E> {
+    items: S,
+    batcher: B,
+    flush_interval: Duration,
+    deadline: Option<Box<dyn Future<Item = (), Error = E> + Send>>,
+    flushing: bool,
+}
+
+/// Batches the items received by `items` with `batcher`.
+///
+/// A batch is streamed once it's full. The batcher is flushed once `flush_interval` has passed
+/// since the first item of the pending batch was received, and when `items` ends. The stream
+/// ends once `items` has ended and the last batch was streamed. Must be run on a Tokio runtime.
+pub fn batched<S, B, E>(items: S, batcher: B, flush_interval: Duration) -> Batched<S, B, E>
+where
+    S: Stream<Item = B::Item, Error = ()>,
+    B: Batcher,
+    E: From<HttpDispatchError> + Send + 'static,
+{
+    Batched {
+        items,
+        batcher,
+        flush_interval,
+        deadline: None,
+        flushing: false,
+    }
+}
+
+impl<S, B, E> Stream for Batched<S, B, E>
+where
+    S: Stream<Item = B::Item, Error = ()>,
+    B: Batcher,
+    E: From<HttpDispatchError> + Send + 'static,
+{
+    type Item = B::Batch;
+    type Error = E;
+
+    fn poll(&mut self) -> Poll<Option<B::Batch>, E> {
+        if self.flushing {
+            match self.batcher.flush() {
+                Some(batch) => return Ok(Async::Ready(Some(batch))),
+                None => self.flushing = false,
+            }
+        }
+        loop {
+            match self.items.poll() {
+                Ok(Async::Ready(Some(item))) => {
+                    let batch = self.batcher.push(item);
+                    if batch.is_some() || self.batcher.is_empty() {
+                        self.deadline = None;
+                    }
+                    if self.deadline.is_none() && !self.batcher.is_empty() {
+                        self.deadline = Some(Box::new(sleep(self.flush_interval)));
+                    }
+                    if batch.is_some() {
+                        return Ok(Async::Ready(batch));
+                    }
+                }
+                Ok(Async::NotReady) => break,
+                // a channel only ends once every sender is dropped
+                Ok(Async::Ready(None)) | Err(()) => {
+                    self.deadline = None;
+                    self.flushing = true;
+                    return Ok(Async::Ready(self.batcher.flush()));
+                }
+            }
+        }
+        let expired = match self.deadline {
+            Some(ref mut deadline) => deadline.poll()?.is_ready(),
+            None => false,
+        };
+        if expired {
+            self.deadline = None;
+            if let Some(batch) = self.batcher.flush() {
+                self.flushing = true;
+                return Ok(Async::Ready(Some(batch)));
+            }
+        }
+        Ok(Async::NotReady)
+    }
+}
+
+/// The delay before the `attempt`th retry of a call: `base_delay`, doubled for every previous
+/// retry, up to `MAX_BACKOFF`.
+pub fn backoff(base_delay: Duration, attempt: u32) -> Duration {
+    let factor = 1u32
+        .checked_shl(attempt.saturating_sub(1))
+        .unwrap_or(u32::max_value());
+    base_delay
+        .checked_mul(factor)
+        .map_or(MAX_BACKOFF, |delay| delay.min(MAX_BACKOFF))
+}
+
+/// Whether a call which failed with `err` may succeed when retried.
+///
+/// That's the case when the request couldn't be dispatched, or when the service answered with
+/// an error it doesn't model and which reports throttling or a server failure. Errors the
+/// service models have to be checked by the caller.
+pub fn is_transient<E>(err: &RusotoError<E>) -> bool {
+    match *err {
+        RusotoError::Unknown(ref response) => {
+            response.status.as_u16() == 429
+                || response.status.is_server_error()
+                || response.body_as_str().contains("Throttling")
+        }
+        RusotoError::HttpDispatch(_) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+
+    /// Batches of up to `size` numbers.
+    struct Numbers {
+        size: usize,
+        pending: Vec<u32>,
+    }
+
+    impl Batcher for Numbers {
+        type Item = u32;
+        type Batch = Vec<u32>;
+
+        fn push(&mut self, item: u32) -> Option<Vec<u32>> {
+            self.pending.push(item);
+            if self.pending.len() >= self.size {
+                self.flush()
+            } else {
+                None
+            }
+        }
+
+        fn flush(&mut self) -> Option<Vec<u32>> {
+            if self.pending.is_empty() {
+                None
+            } else {
+                Some(self.pending.split_off(0))
+            }
+        }
+
+        fn is_empty(&self) -> bool {
+            self.pending.is_empty()
+        }
+    }
+
+    #[test]
+    fn streams_full_batches_and_the_remaining_items() {
+        let numbers = Numbers {
+            size: 2,
+            pending: Vec::new(),
+        };
+        let batches = batched::<_, _, HttpDispatchError>(
+            stream::iter_ok(vec![1, 2, 3, 4, 5]),
+            numbers,
+            Duration::from_secs(1),
+        );
+        assert_eq!(
+            batches.collect().wait().unwrap(),
+            vec![vec![1, 2], vec![3, 4], vec![5]]
+        );
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_a_maximum() {
+        let base = Duration::from_millis(100);
+        assert_eq!(backoff(base, 1), base);
+        assert_eq!(backoff(base, 3), Duration::from_millis(400));
+        assert_eq!(backoff(base, 40), MAX_BACKOFF);
+    }
+}
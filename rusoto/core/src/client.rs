@@ -1,25 +1,125 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::mem;
 use std::sync::{Arc, Mutex, Weak};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
+use futures::future::FutureResult;
 use futures::{Async, Future, Poll};
+use tokio_timer::Delay;
 
 use crate::credential::{
     CredentialsError, DefaultCredentialsProvider, ProvideAwsCredentials, StaticProvider,
+    SystemEnvironment,
 };
+use crate::csm::CsmCall;
+use crate::endpoint_discovery::{DiscoverEndpointsFuture, EndpointCache, EndpointDiscovery};
 use crate::error::RusotoError;
 use crate::future::{self, RusotoFuture};
-use crate::request::{DispatchSignedRequest, HttpClient, HttpDispatchError, HttpResponse};
+#[cfg(feature = "tracing")]
+use crate::instrument::RequestSpan;
+use crate::region::Region;
+use crate::request::{
+    BufferedHttpResponse, BufferedHttpResponseFuture, DispatchSignedRequest, HttpClient,
+    HttpDispatchError, HttpResponse, DEFAULT_USER_AGENT,
+};
+use crate::retry::{
+    classify_response, error_code, try_clone_request, RetryConfig, RetryHandler, RetryKind,
+    ServiceRetryCodes,
+};
+use crate::shutdown::{InFlight, Lifecycle, ShutdownFuture};
 use crate::signature::SignedRequest;
+use crate::stream::ByteStream;
+use crate::xray::XrayCall;
 
 lazy_static! {
     static ref SHARED_CLIENT: Mutex<Weak<ClientInner<DefaultCredentialsProvider, HttpClient>>> =
         Mutex::new(Weak::new());
 }
 
+/// A function called with every request, before it is signed or right before it is dispatched.
+type RequestInterceptor = dyn Fn(&mut SignedRequest) + Send + Sync;
+
+/// A function called with every response as soon as it has been received.
+type ResponseInterceptor = dyn Fn(&mut HttpResponse) + Send + Sync;
+
+/// A function deciding whether a failed request is sent again, after changing an unsigned copy.
+type RedirectHandler = dyn Fn(&HttpResponse, &mut SignedRequest) -> bool + Send + Sync;
+
+/// Signs and dispatches requests until the client is shut down.
+type SharedInner = Arc<dyn SignAndDispatch + Send + Sync>;
+
 /// Re-usable logic for all clients.
 #[derive(Clone)]
 pub struct Client {
-    inner: Arc<dyn SignAndDispatch + Send + Sync>,
+    inner: Arc<Lifecycle<SharedInner>>,
+    interceptors: Interceptors,
+    retry: Arc<RetryHandler>,
+    retry_codes: ServiceRetryCodes,
+    endpoints: Arc<EndpointCache>,
+    timeouts: Timeouts,
+    user_agent: UserAgent,
+}
+
+/// The timeouts requests are sent with unless their `RusotoFuture` sets its own.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct Timeouts {
+    /// Timeout of each attempt
+    pub(crate) attempt: Option<Duration>,
+    /// Timeout of the whole operation
+    pub(crate) operation: Option<Duration>,
+}
+
+/// What the `User-Agent` of requests names in addition to rusoto.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct UserAgent {
+    suffix: Option<String>,
+    app_name: Option<String>,
+}
+
+impl UserAgent {
+    /// The `User-Agent` of requests, if it isn't the default one.
+    fn header(&self) -> Option<String> {
+        if self.suffix.is_none() && self.app_name.is_none() {
+            return None;
+        }
+        let mut user_agent = DEFAULT_USER_AGENT.clone();
+        if let Some(ref suffix) = self.suffix {
+            user_agent.push(' ');
+            user_agent.push_str(suffix);
+        }
+        if let Some(ref app_name) = self.app_name {
+            user_agent.push_str(" app/");
+            user_agent.push_str(app_name);
+        }
+        Some(user_agent)
+    }
+}
+
+/// The longest application name the AWS SDKs recommend.
+const MAX_APP_NAME: usize = 50;
+
+/// Replaces the characters an HTTP token can't contain, such as spaces, with `_`.
+fn user_agent_token(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[derive(Clone, Default)]
+struct Interceptors {
+    /// Called before the request is signed
+    unsigned: Vec<Arc<RequestInterceptor>>,
+    request: Vec<Arc<RequestInterceptor>>,
+    response: Vec<Arc<ResponseInterceptor>>,
+    redirect: Vec<Arc<RedirectHandler>>,
 }
 
 impl Client {
@@ -27,7 +127,15 @@ impl Client {
     pub fn shared() -> Self {
         let mut lock = SHARED_CLIENT.lock().unwrap();
         if let Some(inner) = lock.upgrade() {
-            return Client { inner };
+            return Client {
+                inner: Arc::new(Lifecycle::new(inner as SharedInner)),
+                interceptors: Interceptors::default(),
+                retry: Arc::new(RetryHandler::from_env(&SystemEnvironment)),
+                retry_codes: ServiceRetryCodes::default(),
+                endpoints: Arc::new(EndpointCache::from_env()),
+                timeouts: Timeouts::default(),
+                user_agent: UserAgent::default(),
+            };
         }
         let credentials_provider =
             DefaultCredentialsProvider::new().expect("failed to create credentials provider");
@@ -37,7 +145,15 @@ impl Client {
             dispatcher: Arc::new(dispatcher),
         });
         *lock = Arc::downgrade(&inner);
-        Client { inner }
+        Client {
+            inner: Arc::new(Lifecycle::new(inner as SharedInner)),
+            interceptors: Interceptors::default(),
+            retry: Arc::new(RetryHandler::from_env(&SystemEnvironment)),
+            retry_codes: ServiceRetryCodes::default(),
+            endpoints: Arc::new(EndpointCache::from_env()),
+            timeouts: Timeouts::default(),
+            user_agent: UserAgent::default(),
+        }
     }
 
     /// Create a client from a credentials provider and request dispatcher.
@@ -53,7 +169,13 @@ impl Client {
             dispatcher: Arc::new(dispatcher),
         };
         Client {
-            inner: Arc::new(inner),
+            inner: Arc::new(Lifecycle::new(Arc::new(inner) as SharedInner)),
+            interceptors: Interceptors::default(),
+            retry: Arc::new(RetryHandler::from_env(&SystemEnvironment)),
+            retry_codes: ServiceRetryCodes::default(),
+            endpoints: Arc::new(EndpointCache::from_env()),
+            timeouts: Timeouts::default(),
+            user_agent: UserAgent::default(),
         }
     }
 
@@ -72,20 +194,364 @@ impl Client {
             dispatcher: Arc::new(dispatcher),
         };
         Client {
-            inner: Arc::new(inner),
+            inner: Arc::new(Lifecycle::new(Arc::new(inner) as SharedInner)),
+            interceptors: Interceptors::default(),
+            retry: Arc::new(RetryHandler::from_env(&SystemEnvironment)),
+            retry_codes: ServiceRetryCodes::default(),
+            endpoints: Arc::new(EndpointCache::from_env()),
+            timeouts: Timeouts::default(),
+            user_agent: UserAgent::default(),
+        }
+    }
+
+    /// Add a function called with every request made through this client.
+    ///
+    /// Request interceptors run in the order they were added, after the request has been
+    /// signed and right before it is dispatched, so they see exactly what is sent. Headers added
+    /// by an interceptor are sent unsigned, and changing a signed header or the payload
+    /// invalidates the signature.
+    ///
+    /// Interceptors apply to this `Client` value and its clones only, so a client returned by
+    /// `Client::shared()` can be customized without affecting other users of the shared client.
+    ///
+    /// ```rust
+    /// use rusoto_core::Client;
+    ///
+    /// let client = Client::shared().with_request_interceptor(|request| {
+    ///     request.add_header("x-trace-id", "1-5759e988-bd862e3fe1be46a994272793");
+    /// });
+    /// ```
+    pub fn with_request_interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(&mut SignedRequest) + Send + Sync + 'static,
+    {
+        self.interceptors.request.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Add a function called with every request made through this client, before it is signed.
+    ///
+    /// Unlike request interceptors, these functions may change anything that is signed, such as
+    /// the host, path or signed headers. They run once per call, in the order they were added,
+    /// before endpoint discovery and before the first attempt.
+    pub fn with_unsigned_request_interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(&mut SignedRequest) + Send + Sync + 'static,
+    {
+        self.interceptors.unsigned.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Add a function called with every response received through this client, before it is
+    /// handed to the operation's response handler.
+    ///
+    /// Response interceptors run in the order they were added.
+    pub fn with_response_interceptor<F>(mut self, interceptor: F) -> Self
+    where
+        F: Fn(&mut HttpResponse) + Send + Sync + 'static,
+    {
+        self.interceptors.response.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Add a function called with every unsuccessful response, which can redirect the request.
+    ///
+    /// The function is called with the response and an unsigned copy of the request, before
+    /// the response is retried or handed to the operation's response handler. If it returns
+    /// `true`, the copy, which it may have sent to another host or region, is signed and sent
+    /// instead. Redirect handlers run in the order they were added until one returns `true`, and
+    /// each call is redirected at most once. Requests with a streaming body can't be copied and
+    /// aren't redirected.
+    pub fn with_redirect_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&HttpResponse, &mut SignedRequest) -> bool + Send + Sync + 'static,
+    {
+        self.interceptors.redirect.push(Arc::new(handler));
+        self
+    }
+
+    /// Retry the error codes of a service in addition to the ones retried for every service.
+    ///
+    /// Generated clients set the codes of their service when they are created, so this is
+    /// only needed to send requests built by hand.
+    pub fn with_retry_codes(mut self, codes: ServiceRetryCodes) -> Self {
+        self.retry_codes = codes;
+        self
+    }
+
+    /// Retry requests as configured by `config`, rather than by `AWS_RETRY_MODE` and
+    /// `AWS_MAX_ATTEMPTS`.
+    ///
+    /// The client gets its own retry quota and rate limiter, shared by its clones.
+    pub fn with_retry_config(mut self, config: RetryConfig) -> Self {
+        self.retry = Arc::new(RetryHandler::new(config));
+        self
+    }
+
+    /// Send every attempt of a request with `timeout`, unless its `RusotoFuture` sets another
+    /// one with `set_timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeouts.attempt = Some(timeout);
+        self
+    }
+
+    /// Give up on a request after `timeout`, including retries and the backoff between them,
+    /// unless its `RusotoFuture` sets another one with `set_operation_timeout`.
+    pub fn with_operation_timeout(mut self, timeout: Duration) -> Self {
+        self.timeouts.operation = Some(timeout);
+        self
+    }
+
+    /// Append `suffix` to the `User-Agent` and `x-amz-user-agent` headers of every request.
+    ///
+    /// Requests which set their own headers are left as they are.
+    pub fn with_user_agent_suffix(mut self, suffix: &str) -> Self {
+        self.user_agent.suffix = Some(suffix.to_owned());
+        self
+    }
+
+    /// Name the application making requests in their `User-Agent` and `x-amz-user-agent`
+    /// headers, as `app/<app_name>`, so their traffic can be attributed to it, e.g. in
+    /// CloudTrail.
+    ///
+    /// Characters which can't appear in the header, such as spaces, are replaced with `_`.
+    /// Names longer than 50 characters are sent as they are, but logged as a warning since the
+    /// AWS SDKs recommend shorter ones.
+    pub fn with_app_name(mut self, app_name: &str) -> Self {
+        if app_name.len() > MAX_APP_NAME {
+            warn!(
+                "the application name {} is longer than {} characters",
+                app_name, MAX_APP_NAME
+            );
         }
+        self.user_agent.app_name = Some(user_agent_token(app_name));
+        self
+    }
+
+    /// The name of the application making requests, set with `with_app_name`.
+    pub fn app_name(&self) -> Option<&str> {
+        self.user_agent.app_name.as_ref().map(String::as_str)
+    }
+
+    /// The retry settings requests are sent with.
+    pub fn retry_config(&self) -> &RetryConfig {
+        self.retry.config()
+    }
+
+    /// The timeouts requests are sent with unless their `RusotoFuture` sets its own.
+    pub(crate) fn timeouts(&self) -> Timeouts {
+        self.timeouts
     }
 
-    /// Fetch credentials, sign the request and dispatch it.
+    /// Fetch credentials, sign the request and dispatch it, retrying as configured by
+    /// `AWS_RETRY_MODE` and `AWS_MAX_ATTEMPTS` or `with_retry_config`.
+    ///
+    /// The retry quota and the rate limiter of the adaptive retry mode are shared by the clones
+    /// of this `Client`. See the `retry` module for details.
     pub fn sign_and_dispatch<T, E>(
+        &self,
+        mut request: SignedRequest,
+        response_handler: fn(
+            HttpResponse,
+        ) -> Box<dyn Future<Item = T, Error = RusotoError<E>> + Send>,
+    ) -> RusotoFuture<T, E> {
+        let (inner, in_flight) = match self.inner.begin() {
+            Some(started) => started,
+            None => return shut_down(response_handler),
+        };
+        self.intercept_unsigned(&mut request);
+        let future = inner.sign_and_dispatch(
+            request,
+            self.interceptors.clone(),
+            self.retry.clone(),
+            self.retry_codes,
+            None,
+            in_flight,
+        );
+        future::new(self.with_timeouts(future), response_handler)
+    }
+
+    /// Fetch credentials, sign the request and dispatch it like `sign_and_dispatch`, and buffer
+    /// the response.
+    ///
+    /// This sends requests built by hand, e.g. for operations rusoto has no generated client
+    /// for yet. Successful responses are returned as they were received, others fail with
+    /// `RusotoError::Unknown`, leaving it to the caller to parse the error.
+    pub fn send_raw(
         &self,
         request: SignedRequest,
+    ) -> RusotoFuture<BufferedHttpResponse, Infallible> {
+        self.sign_and_dispatch(request, buffer_raw_response)
+    }
+
+    /// Like `sign_and_dispatch`, but sends the request to an endpoint discovered through the
+    /// service's endpoint operation when discovery is required or `AWS_ENABLE_ENDPOINT_DISCOVERY`
+    /// is set.
+    ///
+    /// Discovered endpoints are cached per service and region by the clones of this `Client`.
+    /// See the `endpoint_discovery` module for details.
+    pub fn sign_and_dispatch_with_discovery<T, E>(
+        &self,
+        mut request: SignedRequest,
+        discovery: EndpointDiscovery,
         response_handler: fn(
             HttpResponse,
         ) -> Box<dyn Future<Item = T, Error = RusotoError<E>> + Send>,
     ) -> RusotoFuture<T, E> {
-        future::new(self.inner.sign_and_dispatch(request), response_handler)
+        let custom_endpoint = match request.region {
+            Region::Custom { .. } => true,
+            _ => false,
+        };
+        if custom_endpoint || !(discovery.required || self.endpoints.enabled()) {
+            return self.sign_and_dispatch(request, response_handler);
+        }
+        let (inner, in_flight) = match self.inner.begin() {
+            Some(started) => started,
+            None => return shut_down(response_handler),
+        };
+        self.intercept_unsigned(&mut request);
+        let key = format!("{}:{}", request.service, request.region.name());
+        let future = match self.endpoints.get(&key) {
+            Some(host) => {
+                request.set_hostname(Some(host));
+                None
+            }
+            None => Some((discovery.discover)()),
+        };
+        let discovery = PendingDiscovery {
+            cache: self.endpoints.clone(),
+            key,
+            required: discovery.required,
+            future,
+        };
+        let future = inner.sign_and_dispatch(
+            request,
+            self.interceptors.clone(),
+            self.retry.clone(),
+            self.retry_codes,
+            Some(discovery),
+            in_flight,
+        );
+        future::new(self.with_timeouts(future), response_handler)
+    }
+
+    /// Stop dispatching requests and wait up to `timeout` for the requests in flight.
+    ///
+    /// Requests made through this client or its clones after it has been shut down fail with
+    /// an `HttpDispatchError`. Requests in flight complete as usual; the returned future
+    /// resolves once all of them have, and fails with `ShutdownError::TimedOut` if some are
+    /// still in flight after `timeout`. Shutting down the same client again just waits again.
+    ///
+    /// See the `shutdown` module for details.
+    pub fn shutdown(&self, timeout: Duration) -> ShutdownFuture {
+        self.inner.close();
+        self.inner.drain(timeout)
+    }
+
+    /// Whether `shutdown` has been called on this client or one of its clones.
+    pub fn is_shut_down(&self) -> bool {
+        self.inner.is_closed()
+    }
+
+    fn intercept_unsigned(&self, request: &mut SignedRequest) {
+        for interceptor in &self.interceptors.unsigned {
+            interceptor(request);
+        }
+        // after the interceptors, so headers they set win
+        if let Some(user_agent) = self.user_agent.header() {
+            for header in &["user-agent", "x-amz-user-agent"] {
+                if !request.headers.contains_key(*header) {
+                    request.add_header(*header, &user_agent);
+                }
+            }
+        }
+    }
+
+    fn with_timeouts(&self, mut future: DispatchFuture) -> DispatchFuture {
+        if let Some(timeout) = self.timeouts.attempt {
+            future.set_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeouts.operation {
+            future.set_operation_timeout(timeout);
+        }
+        future
+    }
+}
+
+/// A client of an AWS service. Every generated client implements it.
+///
+/// Code which doesn't depend on the service a client is for, such as a wrapper logging the
+/// requests of any client or a container building clients from shared configuration, can be
+/// written once against this trait.
+pub trait AwsService {
+    /// The name of the service, e.g. `Amazon SQS`.
+    fn service_name(&self) -> &'static str;
+
+    /// The prefix of the endpoints of the service, e.g. `sqs`.
+    fn endpoint_prefix(&self) -> &'static str;
+
+    /// The region the client sends its requests to.
+    fn region(&self) -> &Region;
+
+    /// The `Client` signing and dispatching the requests of the client.
+    fn client(&self) -> &Client;
+
+    /// Creates a client of the service sending its requests through `client` to `region`.
+    fn from_client(client: Client, region: Region) -> Self
+    where
+        Self: Sized;
+
+    /// Creates a client of the same service sending its requests to `region`, with the same
+    /// credentials, dispatcher and configuration.
+    fn with_region(&self, region: Region) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_client(self.client().clone(), region)
     }
+
+    /// Creates a client of the same service and region sending its requests through `client`,
+    /// e.g. with other interceptors or retries.
+    fn with_client(&self, client: Client) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_client(client, self.region().clone())
+    }
+}
+
+/// Buffers the response of a request sent with `Client::send_raw`.
+fn buffer_raw_response(
+    response: HttpResponse,
+) -> Box<dyn Future<Item = BufferedHttpResponse, Error = RusotoError<Infallible>> + Send> {
+    Box::new(response.buffer().from_err().and_then(|response| {
+        if response.status.is_success() {
+            Ok(response)
+        } else {
+            Err(RusotoError::Unknown(response))
+        }
+    }))
+}
+
+/// Fails a request made after the client has been shut down.
+fn shut_down<T, E>(
+    response_handler: fn(HttpResponse) -> Box<dyn Future<Item = T, Error = RusotoError<E>> + Send>,
+) -> RusotoFuture<T, E> {
+    let error = HttpDispatchError::new("client has been shut down".to_owned());
+    future::new(
+        Box::new(futures::future::err(SignAndDispatchError::Dispatch(error))),
+        response_handler,
+    )
+}
+
+/// The endpoint discovery of a request.
+struct PendingDiscovery {
+    cache: Arc<EndpointCache>,
+    /// Cache key of the request's service and region
+    key: String,
+    required: bool,
+    /// The call to the endpoint operation, unless a cached endpoint is used
+    future: Option<DiscoverEndpointsFuture>,
 }
 
 pub enum SignAndDispatchError {
@@ -93,16 +559,36 @@ pub enum SignAndDispatchError {
     Dispatch(HttpDispatchError),
 }
 
+/// The future of a request being signed and dispatched.
+type DispatchFuture =
+    Box<dyn TimeoutFuture<Item = HttpResponse, Error = SignAndDispatchError> + Send>;
+
 trait SignAndDispatch {
     fn sign_and_dispatch(
         &self,
         request: SignedRequest,
-    ) -> Box<dyn TimeoutFuture<Item = HttpResponse, Error = SignAndDispatchError> + Send>;
+        interceptors: Interceptors,
+        retry: Arc<RetryHandler>,
+        retry_codes: ServiceRetryCodes,
+        discovery: Option<PendingDiscovery>,
+        in_flight: InFlight,
+    ) -> DispatchFuture;
 }
 
 pub trait TimeoutFuture: Future {
+    /// Sets the timeout of each attempt.
     fn set_timeout(&mut self, timeout: Duration);
     fn clear_timeout(&mut self);
+
+    /// Sets the timeout of the whole operation, including retries and the backoff between them.
+    fn set_operation_timeout(&mut self, _timeout: Duration) {}
+    fn clear_operation_timeout(&mut self) {}
+}
+
+impl TimeoutFuture for FutureResult<HttpResponse, SignAndDispatchError> {
+    fn set_timeout(&mut self, _timeout: Duration) {}
+
+    fn clear_timeout(&mut self) {}
 }
 
 struct ClientInner<P, D> {
@@ -128,20 +614,419 @@ where
 {
     fn sign_and_dispatch(
         &self,
-        request: SignedRequest,
-    ) -> Box<dyn TimeoutFuture<Item = HttpResponse, Error = SignAndDispatchError> + Send> {
+        mut request: SignedRequest,
+        interceptors: Interceptors,
+        retry: Arc<RetryHandler>,
+        retry_codes: ServiceRetryCodes,
+        discovery: Option<PendingDiscovery>,
+        in_flight: InFlight,
+    ) -> DispatchFuture {
+        let mut discovery = discovery;
+        #[cfg(feature = "tracing")]
+        let span = RequestSpan::new(&request);
+        let csm = CsmCall::start(&request);
+        // reads the trace context of the thread making the call
+        let xray = XrayCall::start(&mut request);
+        let state = match discovery.as_mut().and_then(|d| d.future.take()) {
+            Some(future) => SignAndDispatchState::Discovering { future, request },
+            None => SignAndDispatchState::Lazy { request },
+        };
         Box::new(SignAndDispatchFuture {
             inner: self.clone(),
-            state: Some(SignAndDispatchState::Lazy { request }),
+            interceptors,
+            retry,
+            retry_codes,
+            discovery,
+            attempt: 1,
+            retry_cost: 0,
+            replay: None,
+            redirected: false,
+            #[cfg(feature = "tracing")]
+            span,
+            csm,
+            xray,
+            state: Some(state),
             timeout: None,
+            operation_timeout: None,
+            deadline: None,
+            in_flight: Some(in_flight),
         })
     }
 }
 
 pub struct SignAndDispatchFuture<P: ProvideAwsCredentials, D: DispatchSignedRequest> {
     inner: ClientInner<P, D>,
+    interceptors: Interceptors,
+    retry: Arc<RetryHandler>,
+    /// The error codes the service retries beyond the ones retried for every service
+    retry_codes: ServiceRetryCodes,
+    discovery: Option<PendingDiscovery>,
+    /// The attempt in progress, starting at 1
+    attempt: u32,
+    /// Retry tokens spent on the last retry
+    retry_cost: u32,
+    /// An unsigned copy of the request to send on the next attempt
+    replay: Option<SignedRequest>,
+    /// Whether a redirect handler has already redirected the request
+    redirected: bool,
+    #[cfg(feature = "tracing")]
+    span: RequestSpan,
+    csm: Option<CsmCall>,
+    xray: Option<XrayCall>,
     state: Option<SignAndDispatchState<P, D>>,
+    /// Timeout of each attempt
     timeout: Option<Duration>,
+    /// Timeout of the whole operation
+    operation_timeout: Option<Duration>,
+    /// Fires once the operation timeout has passed, starting at the first poll
+    deadline: Option<Delay>,
+    /// Counts the request as in flight for `Client::shutdown`
+    in_flight: Option<InFlight>,
+}
+
+impl<P, D> SignAndDispatchFuture<P, D>
+where
+    P: ProvideAwsCredentials,
+    D: DispatchSignedRequest,
+{
+    fn dispatch(&self, mut request: SignedRequest) -> D::Future {
+        for interceptor in &self.interceptors.request {
+            interceptor(&mut request);
+        }
+        self.inner.dispatcher.dispatch(request, self.timeout)
+    }
+
+    fn record_response(&self, response: &HttpResponse) {
+        #[cfg(feature = "tracing")]
+        self.span.record_response(response);
+        if let Some(ref csm) = self.csm {
+            csm.finish_with_response(response);
+        }
+        if let Some(ref xray) = self.xray {
+            xray.finish_with_response(response, self.attempt);
+        }
+    }
+
+    fn record_error(&self, error: &SignAndDispatchError) {
+        let (exception, message) = match *error {
+            SignAndDispatchError::Credentials(ref err) => ("CredentialsError", err.description()),
+            SignAndDispatchError::Dispatch(ref err) => ("HttpDispatchError", err.description()),
+        };
+        #[cfg(feature = "tracing")]
+        self.span.record_error(message);
+        if let Some(ref csm) = self.csm {
+            csm.finish_with_error(exception, message);
+        }
+        if let Some(ref xray) = self.xray {
+            xray.finish_with_error(message, self.attempt);
+        }
+    }
+
+    fn timer_error(err: tokio_timer::Error) -> SignAndDispatchError {
+        SignAndDispatchError::Dispatch(HttpDispatchError::new(format!("timer error: {}", err)))
+    }
+
+    /// Fetches credentials for, or directly dispatches, a request which may be sent.
+    fn start_attempt(&mut self, mut request: SignedRequest) {
+        match self.inner.credentials_provider.as_ref() {
+            Some(p) => {
+                let future = p.credentials();
+                self.state = Some(SignAndDispatchState::FetchingCredentials { future, request });
+            }
+            None => {
+                request.complement_with_plus(true);
+                let future = self.dispatch(request);
+                self.state = Some(SignAndDispatchState::Dispatching { future });
+            }
+        }
+    }
+
+    /// Sends the request to the discovered host, if any.
+    fn discovered(
+        &mut self,
+        mut request: SignedRequest,
+        host: Option<String>,
+        reason: &str,
+    ) -> Poll<HttpResponse, SignAndDispatchError> {
+        let required = self.discovery.as_ref().map_or(false, |d| d.required);
+        match host {
+            Some(host) => request.set_hostname(Some(host)),
+            None if required => {
+                return Err(SignAndDispatchError::Dispatch(HttpDispatchError::new(
+                    format!("endpoint discovery failed: {}", reason),
+                )));
+            }
+            None => warn!(
+                "Endpoint discovery failed, using the regional endpoint: {}",
+                reason
+            ),
+        }
+        self.state = Some(SignAndDispatchState::Lazy { request });
+        self.poll_state()
+    }
+
+    fn can_retry(&self) -> bool {
+        self.replay.is_some() && self.attempt < self.retry.max_attempts()
+    }
+
+    /// A copy of the request changed by the first redirect handler asking to redirect it.
+    fn redirect(&mut self, response: &HttpResponse) -> Option<SignedRequest> {
+        if self.redirected {
+            return None;
+        }
+        let mut request = self.replay.as_ref().and_then(try_clone_request)?;
+        for handler in &self.interceptors.redirect {
+            if handler(response, &mut request) {
+                self.redirected = true;
+                return Some(request);
+            }
+        }
+        None
+    }
+
+    /// Backs off before the next attempt if the request can be retried.
+    fn schedule_retry(&mut self, timed_out: bool) -> Option<Delay> {
+        let retry = if self.can_retry() {
+            self.retry.retry_after(self.attempt, timed_out)
+        } else {
+            None
+        };
+        // don't back off past the deadline, to fail with the last error instead
+        let retry = retry.filter(|&(backoff, _)| match self.deadline {
+            Some(ref deadline) => Instant::now() + backoff < deadline.deadline(),
+            None => true,
+        });
+        match retry {
+            Some((backoff, cost)) => {
+                self.retry_cost = cost;
+                Some(Delay::new(Instant::now() + backoff))
+            }
+            None => {
+                self.give_up();
+                None
+            }
+        }
+    }
+
+    /// Records that a retryable failure is final.
+    fn give_up(&mut self) {
+        if self.retry.retries_enabled()
+            && self.replay.is_some()
+            && self.attempt >= self.retry.max_attempts()
+        {
+            if let Some(ref mut csm) = self.csm {
+                csm.set_max_retries_exceeded();
+            }
+        }
+    }
+
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn record_retry(&self, reason: &str) {
+        #[cfg(feature = "tracing")]
+        self.span.record_retry(self.attempt + 1, reason);
+    }
+
+    fn finish(&mut self, mut response: HttpResponse) -> HttpResponse {
+        for interceptor in &self.interceptors.response {
+            interceptor(&mut response);
+        }
+        if let Some(in_flight) = self.in_flight.take() {
+            // the connection is in use until the body has been read
+            let body = mem::replace(&mut response.body, ByteStream::from(Vec::new()));
+            response.body = in_flight.hold_until_read(body);
+        }
+        response
+    }
+
+    /// Fails once the operation timeout has passed.
+    fn poll_deadline(&mut self) -> Result<(), SignAndDispatchError> {
+        if self.deadline.is_none() {
+            match self.operation_timeout {
+                Some(timeout) => self.deadline = Some(Delay::new(Instant::now() + timeout)),
+                None => return Ok(()),
+            }
+        }
+        match self.deadline.as_mut().map(Delay::poll) {
+            Some(Err(err)) => Err(Self::timer_error(err)),
+            Some(Ok(Async::Ready(()))) => {
+                // abandon the attempt in progress
+                self.state = None;
+                Err(SignAndDispatchError::Dispatch(HttpDispatchError::timeout(
+                    "Operation timed out".to_owned(),
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn poll_state(&mut self) -> Poll<HttpResponse, SignAndDispatchError> {
+        match self.state.take().unwrap() {
+            SignAndDispatchState::Discovering {
+                mut future,
+                request,
+            } => match future.poll() {
+                Err(err) => self.discovered(request, None, &err),
+                Ok(Async::NotReady) => {
+                    self.state = Some(SignAndDispatchState::Discovering { future, request });
+                    Ok(Async::NotReady)
+                }
+                Ok(Async::Ready(endpoints)) => {
+                    let host = self
+                        .discovery
+                        .as_ref()
+                        .and_then(|d| d.cache.insert(&d.key, &endpoints));
+                    self.discovered(request, host, "no endpoints returned")
+                }
+            },
+            SignAndDispatchState::Lazy { request } => {
+                if self.retry.retries_enabled() || !self.interceptors.redirect.is_empty() {
+                    self.replay = try_clone_request(&request);
+                }
+                let wait = self.retry.acquire_send_token();
+                if wait > Duration::from_secs(0) {
+                    let delay = Delay::new(Instant::now() + wait);
+                    self.state = Some(SignAndDispatchState::Delaying { delay, request });
+                } else {
+                    self.start_attempt(request);
+                }
+                self.poll_state()
+            }
+            SignAndDispatchState::Delaying { mut delay, request } => match delay.poll() {
+                Err(err) => Err(Self::timer_error(err)),
+                Ok(Async::NotReady) => {
+                    self.state = Some(SignAndDispatchState::Delaying { delay, request });
+                    Ok(Async::NotReady)
+                }
+                Ok(Async::Ready(())) => {
+                    self.start_attempt(request);
+                    self.poll_state()
+                }
+            },
+            SignAndDispatchState::FetchingCredentials {
+                mut future,
+                mut request,
+            } => match future.poll() {
+                Err(err) => Err(SignAndDispatchError::Credentials(err)),
+                Ok(Async::NotReady) => {
+                    self.state =
+                        Some(SignAndDispatchState::FetchingCredentials { future, request });
+                    Ok(Async::NotReady)
+                }
+                Ok(Async::Ready(credentials)) => {
+                    if let Some(ref mut csm) = self.csm {
+                        csm.set_credentials(
+                            credentials.aws_access_key_id(),
+                            credentials.token().as_ref().map(String::as_str),
+                        );
+                    }
+                    request.sign_with_plus(&credentials, true);
+                    let future = self.dispatch(request);
+                    self.state = Some(SignAndDispatchState::Dispatching { future });
+                    self.poll_state()
+                }
+            },
+            SignAndDispatchState::Dispatching { mut future } => match future.poll() {
+                Err(err) => {
+                    self.retry.record_attempt(Some(RetryKind::Transient));
+                    let timed_out = err.is_timeout();
+                    match self.schedule_retry(timed_out) {
+                        Some(delay) => {
+                            if let Some(ref mut csm) = self.csm {
+                                csm.retry_after_error("HttpDispatchError", err.description());
+                            }
+                            self.record_retry(err.description());
+                            self.state = Some(SignAndDispatchState::BackingOff { delay });
+                            self.poll_state()
+                        }
+                        None => Err(SignAndDispatchError::Dispatch(err)),
+                    }
+                }
+                Ok(Async::NotReady) => {
+                    self.state = Some(SignAndDispatchState::Dispatching { future });
+                    Ok(Async::NotReady)
+                }
+                Ok(Async::Ready(response)) => {
+                    if !response.status.is_success() {
+                        if let Some(request) = self.redirect(&response) {
+                            self.record_retry("redirected");
+                            self.state = Some(SignAndDispatchState::Lazy { request });
+                            return self.poll_state();
+                        }
+                    }
+                    if response.status.as_u16() == 421 {
+                        // the discovered endpoint no longer serves this client
+                        if let Some(ref discovery) = self.discovery {
+                            discovery.cache.invalidate(&discovery.key);
+                        }
+                    }
+                    if !response.status.is_success() && self.can_retry() {
+                        // the error code may only be in the body
+                        let future = response.buffer();
+                        self.state = Some(SignAndDispatchState::Buffering { future });
+                        return self.poll_state();
+                    }
+                    let code = error_code(&response.headers, &[]);
+                    let kind = classify_response(
+                        response.status,
+                        code.as_ref().map(String::as_str),
+                        &self.retry_codes,
+                    );
+                    self.retry.record_attempt(kind);
+                    if response.status.is_success() {
+                        self.retry.record_success(self.retry_cost);
+                    } else if kind.is_some() {
+                        self.give_up();
+                    }
+                    Ok(Async::Ready(self.finish(response)))
+                }
+            },
+            SignAndDispatchState::Buffering { mut future } => match future.poll() {
+                Err(err) => Err(SignAndDispatchError::Dispatch(err)),
+                Ok(Async::NotReady) => {
+                    self.state = Some(SignAndDispatchState::Buffering { future });
+                    Ok(Async::NotReady)
+                }
+                Ok(Async::Ready(buffered)) => {
+                    let code = error_code(&buffered.headers, &buffered.body);
+                    let kind = classify_response(
+                        buffered.status,
+                        code.as_ref().map(String::as_str),
+                        &self.retry_codes,
+                    );
+                    self.retry.record_attempt(kind);
+                    let response = HttpResponse {
+                        status: buffered.status,
+                        headers: buffered.headers,
+                        body: ByteStream::from(buffered.body),
+                    };
+                    if kind.is_some() {
+                        if let Some(delay) = self.schedule_retry(false) {
+                            if let Some(ref mut csm) = self.csm {
+                                csm.retry_after_response(&response);
+                            }
+                            self.record_retry(code.as_ref().map_or("", String::as_str));
+                            self.state = Some(SignAndDispatchState::BackingOff { delay });
+                            return self.poll_state();
+                        }
+                    }
+                    Ok(Async::Ready(self.finish(response)))
+                }
+            },
+            SignAndDispatchState::BackingOff { mut delay } => match delay.poll() {
+                Err(err) => Err(Self::timer_error(err)),
+                Ok(Async::NotReady) => {
+                    self.state = Some(SignAndDispatchState::BackingOff { delay });
+                    Ok(Async::NotReady)
+                }
+                Ok(Async::Ready(())) => {
+                    self.attempt += 1;
+                    let request = self.replay.take().expect("retried without a request");
+                    self.state = Some(SignAndDispatchState::Lazy { request });
+                    self.poll_state()
+                }
+            },
+        }
+    }
 }
 
 impl<P, D> TimeoutFuture for SignAndDispatchFuture<P, D>
@@ -156,13 +1041,31 @@ where
     fn clear_timeout(&mut self) {
         self.timeout = None;
     }
+
+    fn set_operation_timeout(&mut self, timeout: Duration) {
+        self.operation_timeout = Some(timeout);
+    }
+
+    fn clear_operation_timeout(&mut self) {
+        self.operation_timeout = None;
+    }
 }
 
 #[allow(clippy::large_enum_variant)]
 enum SignAndDispatchState<P: ProvideAwsCredentials, D: DispatchSignedRequest> {
+    /// Calling the endpoint operation of the service
+    Discovering {
+        future: DiscoverEndpointsFuture,
+        request: SignedRequest,
+    },
     Lazy {
         request: SignedRequest,
     },
+    /// Waiting for the rate limiter of the adaptive retry mode
+    Delaying {
+        delay: Delay,
+        request: SignedRequest,
+    },
     FetchingCredentials {
         future: P::Future,
         request: SignedRequest,
@@ -170,6 +1073,14 @@ enum SignAndDispatchState<P: ProvideAwsCredentials, D: DispatchSignedRequest> {
     Dispatching {
         future: D::Future,
     },
+    /// Reading an error response to find out whether it can be retried
+    Buffering {
+        future: BufferedHttpResponseFuture,
+    },
+    /// Waiting before the next attempt
+    BackingOff {
+        delay: Delay,
+    },
 }
 
 impl<P, D> Future for SignAndDispatchFuture<P, D>
@@ -181,48 +1092,18 @@ where
     type Error = SignAndDispatchError;
 
     fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
-        match self.state.take().unwrap() {
-            SignAndDispatchState::Lazy { mut request } => {
-                match self.inner.credentials_provider.as_ref() {
-                    Some(p) => {
-                        let future = p.credentials();
-                        self.state =
-                            Some(SignAndDispatchState::FetchingCredentials { future, request });
-                    }
-                    None => {
-                        request.complement_with_plus(true);
-                        let future = self.inner.dispatcher.dispatch(request, self.timeout);
-                        self.state = Some(SignAndDispatchState::Dispatching { future });
-                    }
-                }
-                self.poll()
-            }
-            SignAndDispatchState::FetchingCredentials {
-                mut future,
-                mut request,
-            } => match future.poll() {
-                Err(err) => Err(SignAndDispatchError::Credentials(err)),
-                Ok(Async::NotReady) => {
-                    self.state =
-                        Some(SignAndDispatchState::FetchingCredentials { future, request });
-                    Ok(Async::NotReady)
-                }
-                Ok(Async::Ready(credentials)) => {
-                    request.sign_with_plus(&credentials, true);
-                    let future = self.inner.dispatcher.dispatch(request, self.timeout);
-                    self.state = Some(SignAndDispatchState::Dispatching { future });
-                    self.poll()
-                }
-            },
-            SignAndDispatchState::Dispatching { mut future } => match future.poll() {
-                Err(err) => Err(SignAndDispatchError::Dispatch(err)),
-                Ok(Async::NotReady) => {
-                    self.state = Some(SignAndDispatchState::Dispatching { future });
-                    Ok(Async::NotReady)
-                }
-                Ok(Async::Ready(response)) => Ok(Async::Ready(response)),
-            },
+        #[cfg(feature = "tracing")]
+        let span = self.span.span().clone();
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        let result = self.poll_deadline().and_then(|_| self.poll_state());
+        match result {
+            Ok(Async::Ready(ref response)) => self.record_response(response),
+            Err(ref err) => self.record_error(err),
+            Ok(Async::NotReady) => {}
         }
+        result
     }
 }
 
@@ -232,3 +1113,455 @@ fn client_is_send_and_sync() {
 
     is_send_and_sync::<Client>();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoint_discovery::DiscoveredEndpoint;
+    use crate::region::Region;
+    use crate::retry::{RetryConfig, RetryMode};
+    use crate::shutdown::ShutdownError;
+    use bytes::Bytes;
+    use futures::future;
+    use futures::Stream;
+    use http::{HeaderMap, StatusCode};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct EchoDispatcher;
+
+    impl DispatchSignedRequest for EchoDispatcher {
+        type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            let mut headers = HeaderMap::<String>::default();
+            for (name, values) in request.headers() {
+                let value = String::from_utf8(values[0].clone()).unwrap();
+                headers.insert(name.parse::<http::header::HeaderName>().unwrap(), value);
+            }
+            future::ok(HttpResponse {
+                status: StatusCode::OK,
+                body: ByteStream::from(Vec::new()),
+                headers,
+            })
+        }
+    }
+
+    #[derive(Clone)]
+    struct EchoService {
+        client: Client,
+        region: Region,
+    }
+
+    impl AwsService for EchoService {
+        fn service_name(&self) -> &'static str {
+            "Echo"
+        }
+
+        fn endpoint_prefix(&self) -> &'static str {
+            "echo"
+        }
+
+        fn region(&self) -> &Region {
+            &self.region
+        }
+
+        fn client(&self) -> &Client {
+            &self.client
+        }
+
+        fn from_client(client: Client, region: Region) -> EchoService {
+            EchoService { client, region }
+        }
+    }
+
+    #[test]
+    fn services_are_rebuilt_with_another_region_or_client() {
+        let service =
+            EchoService::from_client(Client::new_not_signing(EchoDispatcher), Region::UsEast1);
+
+        let moved = service.with_region(Region::EuWest1);
+        assert_eq!(*moved.region(), Region::EuWest1);
+        // the same client sends the requests of both
+        let _ = service.client().shutdown(Duration::from_secs(0));
+        assert!(moved.client().is_shut_down());
+
+        let rebuilt = moved.with_client(Client::new_not_signing(EchoDispatcher));
+        assert_eq!(*rebuilt.region(), Region::EuWest1);
+        assert!(!rebuilt.client().is_shut_down());
+
+        let services: Vec<&dyn AwsService> = vec![&service, &rebuilt];
+        let names: Vec<_> = services
+            .iter()
+            .map(|service| {
+                format!(
+                    "{} in {}",
+                    service.endpoint_prefix(),
+                    service.region().name()
+                )
+            })
+            .collect();
+        assert_eq!(names, vec!["echo in us-east-1", "echo in eu-west-1"]);
+    }
+
+    #[test]
+    fn interceptors_see_every_request_and_response() {
+        let client = Client::new_not_signing(EchoDispatcher)
+            .with_request_interceptor(|request| request.add_header("x-trace-id", "abc"))
+            .with_response_interceptor(|response| response.status = StatusCode::ACCEPTED);
+        let request = SignedRequest::new("GET", "sts", &Region::UsEast1, "/");
+
+        let (inner, in_flight) = client.inner.begin().unwrap();
+        let response = inner
+            .sign_and_dispatch(
+                request,
+                client.interceptors.clone(),
+                client.retry.clone(),
+                client.retry_codes,
+                None,
+                in_flight,
+            )
+            .wait()
+            .ok()
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::ACCEPTED);
+        assert_eq!(response.headers.get("x-trace-id").unwrap(), "abc");
+    }
+
+    /// Throttles the first `throttled` requests it receives.
+    struct ThrottlingDispatcher {
+        throttled: usize,
+        received: Arc<AtomicUsize>,
+    }
+
+    impl DispatchSignedRequest for ThrottlingDispatcher {
+        type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, _request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            let received = self.received.fetch_add(1, Ordering::SeqCst) + 1;
+            let (status, body) = if received <= self.throttled {
+                (
+                    StatusCode::BAD_REQUEST,
+                    r#"{"__type": "ThrottlingException"}"#,
+                )
+            } else {
+                (StatusCode::OK, "{}")
+            };
+            future::ok(HttpResponse {
+                status,
+                body: ByteStream::from(body.as_bytes().to_vec()),
+                headers: HeaderMap::default(),
+            })
+        }
+    }
+
+    fn send_with_retries(throttled: usize, max_attempts: u32) -> (StatusCode, usize) {
+        let received = Arc::new(AtomicUsize::new(0));
+        let mut client = Client::new_not_signing(ThrottlingDispatcher {
+            throttled,
+            received: received.clone(),
+        });
+        client.retry = Arc::new(RetryHandler::new(RetryConfig {
+            mode: RetryMode::Adaptive,
+            max_attempts,
+        }));
+        let mut request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+        request.set_payload(Some(b"{}".to_vec()));
+
+        let (inner, in_flight) = client.inner.begin().unwrap();
+        let future = inner
+            .sign_and_dispatch(
+                request,
+                client.interceptors.clone(),
+                client.retry.clone(),
+                client.retry_codes,
+                None,
+                in_flight,
+            )
+            .map(|response| response.status)
+            .map_err(|_| ());
+        let status = tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(future)
+            .unwrap();
+        (status, received.load(Ordering::SeqCst))
+    }
+
+    #[test]
+    fn retries_throttled_requests() {
+        assert_eq!(send_with_retries(1, 3), (StatusCode::OK, 2));
+    }
+
+    #[test]
+    fn returns_the_last_response_once_attempts_are_exhausted() {
+        assert_eq!(send_with_retries(5, 2), (StatusCode::BAD_REQUEST, 2));
+    }
+
+    fn status_handler(
+        response: HttpResponse,
+    ) -> Box<dyn Future<Item = StatusCode, Error = RusotoError<()>> + Send> {
+        Box::new(future::ok(response.status))
+    }
+
+    #[test]
+    fn sends_requests_to_discovered_endpoints() {
+        let hosts = Arc::new(Mutex::new(Vec::new()));
+        let seen = hosts.clone();
+        let client = Client::new_not_signing(EchoDispatcher)
+            .with_request_interceptor(move |request| seen.lock().unwrap().push(request.hostname()));
+        let discoveries = Arc::new(AtomicUsize::new(0));
+
+        for _ in 0..2 {
+            let counter = discoveries.clone();
+            let discovery = EndpointDiscovery::new(true, move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Box::new(future::ok(vec![DiscoveredEndpoint::new(
+                    "https://discovered.example.com".to_owned(),
+                    10,
+                )]))
+            });
+            let request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+            let status = client
+                .sign_and_dispatch_with_discovery(request, discovery, status_handler)
+                .wait()
+                .unwrap();
+            assert_eq!(status, StatusCode::OK);
+        }
+
+        assert_eq!(discoveries.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            *hosts.lock().unwrap(),
+            vec!["discovered.example.com", "discovered.example.com"]
+        );
+    }
+
+    /// Redirects requests which aren't sent to us-west-2 there.
+    struct RedirectingDispatcher {
+        regions: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl DispatchSignedRequest for RedirectingDispatcher {
+        type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            let region = request.region.name().to_owned();
+            self.regions.lock().unwrap().push(region.clone());
+            let mut headers = HeaderMap::<String>::default();
+            let status = if region == "us-west-2" {
+                StatusCode::OK
+            } else {
+                headers.insert("x-amz-bucket-region", "us-west-2".to_owned());
+                StatusCode::MOVED_PERMANENTLY
+            };
+            future::ok(HttpResponse {
+                status,
+                body: ByteStream::from(Vec::new()),
+                headers,
+            })
+        }
+    }
+
+    fn redirect_to_header_region(response: &HttpResponse, request: &mut SignedRequest) -> bool {
+        match response.headers.get("x-amz-bucket-region") {
+            Some(region) => {
+                request.region = region.parse().unwrap();
+                true
+            }
+            None => false,
+        }
+    }
+
+    #[test]
+    fn redirect_handlers_resend_requests_once() {
+        let regions = Arc::new(Mutex::new(Vec::new()));
+        let client = Client::new_not_signing(RedirectingDispatcher {
+            regions: regions.clone(),
+        })
+        .with_redirect_handler(redirect_to_header_region);
+
+        let request = SignedRequest::new("HEAD", "s3", &Region::UsEast1, "/bucket");
+        let status = client
+            .sign_and_dispatch(request, status_handler)
+            .wait()
+            .unwrap();
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(*regions.lock().unwrap(), vec!["us-east-1", "us-west-2"]);
+
+        // a handler which doesn't fix the request doesn't loop
+        regions.lock().unwrap().clear();
+        let request = SignedRequest::new("HEAD", "s3", &Region::EuWest1, "/bucket");
+        let client = Client::new_not_signing(RedirectingDispatcher {
+            regions: regions.clone(),
+        })
+        .with_redirect_handler(|_, request| {
+            request.region = Region::EuWest1;
+            true
+        });
+        let status = client
+            .sign_and_dispatch(request, status_handler)
+            .wait()
+            .unwrap();
+        assert_eq!(status, StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(*regions.lock().unwrap(), vec!["eu-west-1", "eu-west-1"]);
+    }
+
+    /// Never responds.
+    struct HangingDispatcher;
+
+    impl DispatchSignedRequest for HangingDispatcher {
+        type Future = future::Empty<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, _request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            future::empty()
+        }
+    }
+
+    #[test]
+    fn operation_timeout_abandons_the_attempt_in_progress() {
+        let client = Client::new_not_signing(HangingDispatcher);
+        let request = SignedRequest::new("GET", "sts", &Region::UsEast1, "/");
+        let future = client
+            .sign_and_dispatch(request, status_handler)
+            .with_timeout(Duration::from_secs(60))
+            .with_operation_timeout(Duration::from_millis(10));
+
+        let result = tokio::runtime::Runtime::new().unwrap().block_on(future);
+        match result {
+            Err(RusotoError::HttpDispatch(ref err)) => {
+                assert_eq!(err.description(), "Operation timed out");
+                assert!(err.is_timeout());
+            }
+            other => panic!("expected an operation timeout, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn client_timeouts_apply_to_every_request() {
+        let client = Client::new_not_signing(HangingDispatcher)
+            .with_operation_timeout(Duration::from_millis(10));
+        let request = SignedRequest::new("GET", "sts", &Region::UsEast1, "/");
+        let future = client.sign_and_dispatch(request, status_handler);
+
+        let result = tokio::runtime::Runtime::new().unwrap().block_on(future);
+        match result {
+            Err(RusotoError::HttpDispatch(ref err)) => {
+                assert_eq!(err.description(), "Operation timed out");
+                assert!(err.is_timeout());
+            }
+            other => panic!("expected an operation timeout, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn user_agent_suffix_is_appended() {
+        let client = Client::new_not_signing(EchoDispatcher).with_user_agent_suffix("app/1.0");
+        let request = SignedRequest::new("GET", "sts", &Region::UsEast1, "/");
+        let response = client.send_raw(request).wait().unwrap();
+        assert_eq!(
+            response.headers.get("user-agent").unwrap(),
+            &format!("{} app/1.0", *DEFAULT_USER_AGENT)
+        );
+
+        let mut request = SignedRequest::new("GET", "sts", &Region::UsEast1, "/");
+        request.add_header("user-agent", "custom");
+        let response = client.send_raw(request).wait().unwrap();
+        assert_eq!(response.headers.get("user-agent").unwrap(), "custom");
+    }
+
+    #[test]
+    fn app_name_is_sent_in_both_user_agent_headers() {
+        let client = Client::new_not_signing(EchoDispatcher)
+            .with_user_agent_suffix("platform/2")
+            .with_app_name("billing export");
+        assert_eq!(client.app_name(), Some("billing_export"));
+
+        let request = SignedRequest::new("GET", "sts", &Region::UsEast1, "/");
+        let response = client.send_raw(request).wait().unwrap();
+        let expected = format!("{} platform/2 app/billing_export", *DEFAULT_USER_AGENT);
+        assert_eq!(response.headers.get("user-agent").unwrap(), &expected);
+        assert_eq!(response.headers.get("x-amz-user-agent").unwrap(), &expected);
+    }
+
+    /// Responds with a streamed body.
+    struct StreamingDispatcher;
+
+    impl DispatchSignedRequest for StreamingDispatcher {
+        type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, _request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            let body = futures::stream::once(Ok(Bytes::from_static(b"body")));
+            future::ok(HttpResponse {
+                status: StatusCode::OK,
+                body: ByteStream::new(body),
+                headers: HeaderMap::default(),
+            })
+        }
+    }
+
+    fn response_handler(
+        response: HttpResponse,
+    ) -> Box<dyn Future<Item = HttpResponse, Error = RusotoError<()>> + Send> {
+        Box::new(future::ok(response))
+    }
+
+    #[test]
+    fn sends_raw_requests() {
+        let client = Client::new_not_signing(EchoDispatcher);
+        let mut request = SignedRequest::new("GET", "sts", &Region::UsEast1, "/");
+        request.add_header("x-custom", "value");
+
+        let response = client.send_raw(request).wait().unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.headers.get("x-custom").unwrap(), "value");
+    }
+
+    #[test]
+    fn rejects_requests_after_shutdown() {
+        let client = Client::new_not_signing(EchoDispatcher);
+        let clone = client.clone();
+        assert_eq!(client.shutdown(Duration::from_secs(1)).wait(), Ok(()));
+
+        assert!(clone.is_shut_down());
+        let request = SignedRequest::new("GET", "sts", &Region::UsEast1, "/");
+        assert!(clone
+            .sign_and_dispatch(request, status_handler)
+            .wait()
+            .is_err());
+    }
+
+    #[test]
+    fn shutdown_waits_for_response_bodies() {
+        let client = Client::new_not_signing(StreamingDispatcher);
+        let request = SignedRequest::new("GET", "sts", &Region::UsEast1, "/");
+        let response = client
+            .sign_and_dispatch(request, response_handler)
+            .wait()
+            .unwrap();
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        assert_eq!(
+            runtime.block_on(client.shutdown(Duration::from_millis(10))),
+            Err(ShutdownError::TimedOut { in_flight: 1 })
+        );
+        assert_eq!(
+            runtime.block_on(response.body.concat2()).unwrap(),
+            &b"body"[..]
+        );
+        assert_eq!(
+            runtime.block_on(client.shutdown(Duration::from_secs(1))),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fails_when_required_discovery_fails() {
+        let client = Client::new_not_signing(EchoDispatcher);
+        let discovery =
+            EndpointDiscovery::new(true, || Box::new(future::err("unavailable".to_owned())));
+        let request = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+        let result = client
+            .sign_and_dispatch_with_discovery(request, discovery, status_handler)
+            .wait();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,642 @@
+//! Configuring clients, and introspection of the configuration a client ends up using.
+//!
+//! `ClientBuilder` gathers the settings shared by the clients of an application in one place:
+//! region, credentials, retries, timeouts, endpoints, `User-Agent`, application name and proxy.
+//! It builds a `Config`, which every generated client can be created from with its
+//! `from_config` method:
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use rusoto_core::config::ClientBuilder;
+//! use rusoto_core::proxy::Proxy;
+//! use rusoto_core::retry::{RetryConfig, RetryMode};
+//! use rusoto_core::Region;
+//!
+//! let config = ClientBuilder::new()
+//!     .with_region(Region::EuWest1)
+//!     .with_retry_config(RetryConfig {
+//!         mode: RetryMode::Standard,
+//!         max_attempts: 5,
+//!     })
+//!     .with_operation_timeout(Duration::from_secs(30))
+//!     .with_user_agent_suffix("inventory/1.2")
+//!     .with_app_name("inventory")
+//!     .with_proxy(Proxy::new("http://proxy.example.com:3128").unwrap())
+//!     .with_endpoint_resolver(|endpoint_prefix, _region| match endpoint_prefix {
+//!         "sqs" => Some("http://localhost:9324".to_owned()),
+//!         _ => None,
+//!     })
+//!     .build()
+//!     .unwrap();
+//! // let sqs = SqsClient::from_config(&config);
+//! ```
+//!
+//! Region, endpoint and credentials can each come from code, the environment or the shared
+//! config file, which makes it hard to tell which endpoint a client actually talks to.
+//! `EffectiveConfig` reports the resolved values along with where each one came from. Generated
+//! clients expose it through their `effective_config()` method.
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::client::Client;
+use crate::credential::{
+    CredentialsError, DefaultCredentialsProvider, Environment, ProfileProvider,
+    ProvideAwsCredentials, SystemEnvironment,
+};
+use crate::proxy::Proxy;
+use crate::region::Region;
+use crate::request::{DispatchSignedRequest, HttpClient, HttpConfig, TlsError};
+use crate::retry::{RetryConfig, RetryMode};
+use crate::signature::SignedRequest;
+
+/// A function returning the endpoint of the service with the given endpoint prefix in a region,
+/// or `None` for its regional endpoint.
+type EndpointResolver = dyn Fn(&str, &Region) -> Option<String> + Send + Sync;
+
+/// The credentials requests are signed with.
+enum Credentials<P> {
+    Default,
+    Provider(P),
+    Unsigned,
+}
+
+/// Builds the `Config` clients are created from.
+///
+/// Settings which aren't set are resolved as for the clients created with `new`: the region and
+/// credentials from the environment, the shared config and credentials files or the instance
+/// metadata, and the retries from `AWS_RETRY_MODE` and `AWS_MAX_ATTEMPTS`. The retries and the
+/// application name are read from the environment set with `with_environment`, the environment
+/// of the current process by default.
+pub struct ClientBuilder<P = DefaultCredentialsProvider> {
+    region: Option<Region>,
+    credentials: Credentials<P>,
+    retry: Option<RetryConfig>,
+    timeout: Option<Duration>,
+    operation_timeout: Option<Duration>,
+    endpoint_resolver: Option<Arc<EndpointResolver>>,
+    user_agent_suffix: Option<String>,
+    app_name: Option<String>,
+    proxy: Option<Proxy>,
+    environment: Arc<dyn Environment>,
+}
+
+impl ClientBuilder {
+    /// Creates a builder with every setting left to its default.
+    pub fn new() -> ClientBuilder {
+        ClientBuilder {
+            region: None,
+            credentials: Credentials::Default,
+            retry: None,
+            timeout: None,
+            operation_timeout: None,
+            endpoint_resolver: None,
+            user_agent_suffix: None,
+            app_name: None,
+            proxy: None,
+            environment: Arc::new(SystemEnvironment),
+        }
+    }
+}
+
+impl Default for ClientBuilder {
+    fn default() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+}
+
+impl<P> ClientBuilder<P> {
+    /// Sends requests to `region`, rather than to the region resolved by each client.
+    pub fn with_region(mut self, region: Region) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Signs requests with the credentials of `provider`, rather than of the default
+    /// credentials provider.
+    pub fn with_credentials<Q>(self, provider: Q) -> ClientBuilder<Q> {
+        ClientBuilder {
+            region: self.region,
+            credentials: Credentials::Provider(provider),
+            retry: self.retry,
+            timeout: self.timeout,
+            operation_timeout: self.operation_timeout,
+            endpoint_resolver: self.endpoint_resolver,
+            user_agent_suffix: self.user_agent_suffix,
+            app_name: self.app_name,
+            proxy: self.proxy,
+            environment: self.environment,
+        }
+    }
+
+    /// Sends requests without signing them, as `Client::new_not_signing` does.
+    pub fn without_signing(mut self) -> Self {
+        self.credentials = Credentials::Unsigned;
+        self
+    }
+
+    /// Retries requests as configured by `config`. See `Client::with_retry_config`.
+    pub fn with_retry_config(mut self, config: RetryConfig) -> Self {
+        self.retry = Some(config);
+        self
+    }
+
+    /// Sends every attempt of a request with `timeout`. See `Client::with_timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Gives up on requests after `timeout`, including retries. See
+    /// `Client::with_operation_timeout`.
+    pub fn with_operation_timeout(mut self, timeout: Duration) -> Self {
+        self.operation_timeout = Some(timeout);
+        self
+    }
+
+    /// Sends the requests of a service to the endpoint `resolver` returns for its endpoint
+    /// prefix, e.g. `sqs`, and region. Services it returns `None` for use their regional
+    /// endpoint, or the one named by `AWS_ENDPOINT_URL_<SERVICE>` or `AWS_ENDPOINT_URL` when the
+    /// region isn't set with `with_region`.
+    pub fn with_endpoint_resolver<F>(mut self, resolver: F) -> Self
+    where
+        F: Fn(&str, &Region) -> Option<String> + Send + Sync + 'static,
+    {
+        self.endpoint_resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Appends `suffix` to the `User-Agent` header of every request. See
+    /// `Client::with_user_agent_suffix`.
+    pub fn with_user_agent_suffix<S>(mut self, suffix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.user_agent_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Names the application making requests in their `User-Agent`, rather than the name set
+    /// by `AWS_SDK_UA_APP_ID`, if any. See `Client::with_app_name`.
+    pub fn with_app_name<S>(mut self, app_name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.app_name = Some(app_name.into());
+        self
+    }
+
+    /// Sends requests through `proxy`. See `HttpClient::new_with_proxy`.
+    pub fn with_proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Reads the retries and the application name from `environment`, rather than from the
+    /// environment of the current process.
+    pub fn with_environment<E>(mut self, environment: E) -> Self
+    where
+        E: Environment + 'static,
+    {
+        self.environment = Arc::new(environment);
+        self
+    }
+
+    /// Builds the configuration, with a new `HttpClient` dispatching the requests of every
+    /// client created from it.
+    pub fn build(self) -> Result<Config, ConfigError>
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+    {
+        match self.proxy.clone() {
+            Some(proxy) => {
+                let dispatcher = HttpClient::new_with_proxy(proxy, HttpConfig::new())
+                    .map_err(ConfigError::Tls)?;
+                self.build_with_dispatcher(dispatcher)
+            }
+            None => {
+                let dispatcher = HttpClient::new().map_err(ConfigError::Tls)?;
+                self.build_with_dispatcher(dispatcher)
+            }
+        }
+    }
+
+    /// Builds the configuration, with `dispatcher` dispatching the requests of every client
+    /// created from it. The proxy, if any, is left to the dispatcher.
+    pub fn build_with_dispatcher<D>(self, dispatcher: D) -> Result<Config, ConfigError>
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        let mut client = match self.credentials {
+            Credentials::Default => Client::new_with(
+                DefaultCredentialsProvider::new().map_err(ConfigError::Credentials)?,
+                dispatcher,
+            ),
+            Credentials::Provider(provider) => Client::new_with(provider, dispatcher),
+            Credentials::Unsigned => Client::new_not_signing(dispatcher),
+        };
+        let environment = self.environment;
+        let retry = self
+            .retry
+            .unwrap_or_else(|| RetryConfig::from_environment(&*environment));
+        client = client.with_retry_config(retry);
+        if let Some(timeout) = self.timeout {
+            client = client.with_timeout(timeout);
+        }
+        if let Some(timeout) = self.operation_timeout {
+            client = client.with_operation_timeout(timeout);
+        }
+        if let Some(ref suffix) = self.user_agent_suffix {
+            client = client.with_user_agent_suffix(suffix);
+        }
+        let app_name = self.app_name.or_else(|| {
+            environment
+                .var("AWS_SDK_UA_APP_ID")
+                .filter(|value| !value.is_empty())
+        });
+        if let Some(app_name) = app_name {
+            client = client.with_app_name(&app_name);
+        }
+        Ok(Config {
+            client,
+            region: self.region,
+            endpoint_resolver: self.endpoint_resolver,
+        })
+    }
+}
+
+/// The configuration shared by the clients created from it with their `from_config` method.
+///
+/// The clients share the same `Client`, and so its credentials, connections and retry quota.
+#[derive(Clone)]
+pub struct Config {
+    client: Client,
+    region: Option<Region>,
+    endpoint_resolver: Option<Arc<EndpointResolver>>,
+}
+
+impl Config {
+    /// Creates a builder with every setting left to its default.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// The `Client` signing and dispatching the requests of the clients created from this
+    /// configuration.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// The name of the application making requests, sent in their `User-Agent`.
+    pub fn app_name(&self) -> Option<&str> {
+        self.client.app_name()
+    }
+
+    /// The region the requests of a service are sent to.
+    ///
+    /// `endpoint_prefix` is the prefix of the service's regional hostnames and `env_name` the
+    /// upper case service name used in the `AWS_ENDPOINT_URL_<env_name>` variable.
+    pub fn region_for(&self, endpoint_prefix: &str, env_name: &str) -> Region {
+        let region = match self.region {
+            Some(ref region) => region.clone(),
+            None => Region::from_env_for_service(env_name),
+        };
+        let endpoint = self
+            .endpoint_resolver
+            .as_ref()
+            .and_then(|resolve| resolve(endpoint_prefix, &region));
+        match endpoint {
+            Some(endpoint) => Region::Custom {
+                name: region.name().to_owned(),
+                endpoint,
+            },
+            None => region,
+        }
+    }
+}
+
+/// An error produced when a `Config` can't be built.
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    /// The default credentials provider could not be created.
+    Credentials(CredentialsError),
+    /// The TLS client could not be created.
+    Tls(TlsError),
+}
+
+impl Error for ConfigError {
+    fn description(&self) -> &str {
+        match *self {
+            ConfigError::Credentials(ref err) => err.description(),
+            ConfigError::Tls(ref err) => err.description(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConfigError::Credentials(ref err) => write!(f, "{}", err),
+            ConfigError::Tls(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+/// Where a configuration value came from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigSource {
+    /// Set in code, e.g. by passing a `Region` to the client constructor.
+    Code,
+    /// Read from the named environment variable.
+    Environment(String),
+    /// Read from the shared AWS config file.
+    Profile,
+    /// Rusoto's built-in default.
+    Default,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConfigSource::Code => write!(f, "code"),
+            ConfigSource::Environment(ref var) => write!(f, "environment variable {}", var),
+            ConfigSource::Profile => write!(f, "profile"),
+            ConfigSource::Default => write!(f, "default"),
+        }
+    }
+}
+
+/// A configuration value along with where it came from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolvedValue<T> {
+    /// The value in effect
+    pub value: T,
+    /// Where the value came from
+    pub source: ConfigSource,
+}
+
+impl<T> ResolvedValue<T> {
+    fn new(value: T, source: ConfigSource) -> ResolvedValue<T> {
+        ResolvedValue { value, source }
+    }
+}
+
+/// The configuration a client sends its requests with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EffectiveConfig {
+    /// Name of the region requests are signed for
+    pub region: ResolvedValue<String>,
+    /// Scheme and host requests are sent to, e.g. `https://dynamodb.us-east-1.amazonaws.com`
+    pub endpoint: ResolvedValue<String>,
+    /// Service name requests are signed with
+    pub signing_name: String,
+    /// How failed requests are retried
+    pub retry_mode: ResolvedValue<RetryMode>,
+    /// Number of times a request is attempted before giving up
+    pub max_attempts: ResolvedValue<u32>,
+    /// Timeout applied to each request, if any
+    pub timeout: ResolvedValue<Option<Duration>>,
+}
+
+impl EffectiveConfig {
+    /// Resolves the configuration of a client for the given region.
+    ///
+    /// `endpoint_prefix` is the prefix of the service's regional hostnames, `signing_name` the
+    /// name its requests are signed with and `env_name` the upper case service name used in the
+    /// `AWS_ENDPOINT_URL_<env_name>` variable.
+    ///
+    /// A client only holds the final `Region`, so sources are inferred: a value which matches
+    /// the environment or the profile is reported as coming from there, even if the same value
+    /// was also passed in code.
+    pub fn resolve(
+        region: &Region,
+        endpoint_prefix: &str,
+        signing_name: &str,
+        env_name: &str,
+    ) -> EffectiveConfig {
+        let (retry, mode_source, attempts_source) =
+            RetryConfig::from_env_with_sources(&SystemEnvironment);
+        EffectiveConfig {
+            region: ResolvedValue::new(region.name().to_owned(), region_source(region.name())),
+            endpoint: ResolvedValue::new(
+                regional_endpoint(region, endpoint_prefix),
+                endpoint_source(region, env_name),
+            ),
+            signing_name: signing_name.to_owned(),
+            retry_mode: ResolvedValue::new(retry.mode, mode_source),
+            max_attempts: ResolvedValue::new(retry.max_attempts, attempts_source),
+            timeout: ResolvedValue::new(None, ConfigSource::Default),
+        }
+    }
+
+    /// Reports the retries and timeout of `client` where they were set in code, e.g. with
+    /// `ClientBuilder`.
+    ///
+    /// The timeout is the one of each attempt, which the `RusotoFuture` of a request can still
+    /// override.
+    pub fn with_client(mut self, client: &Client) -> EffectiveConfig {
+        let retry = client.retry_config();
+        if *retry != RetryConfig::from_env() {
+            self.retry_mode = ResolvedValue::new(retry.mode, ConfigSource::Code);
+            self.max_attempts = ResolvedValue::new(retry.max_attempts, ConfigSource::Code);
+        }
+        if let Some(timeout) = client.timeouts().attempt {
+            self.timeout = ResolvedValue::new(Some(timeout), ConfigSource::Code);
+        }
+        self
+    }
+}
+
+/// Returns the scheme and host requests for `endpoint_prefix` are sent to in `region`.
+pub fn regional_endpoint(region: &Region, endpoint_prefix: &str) -> String {
+    let request = SignedRequest::new("GET", endpoint_prefix, region, "/");
+    format!("{}://{}", request.scheme(), request.hostname())
+}
+
+fn non_empty_env(var: &str) -> Option<String> {
+    env::var(var).ok().filter(|value| !value.is_empty())
+}
+
+fn region_source(name: &str) -> ConfigSource {
+    // same order as `Region::default`
+    for var in &["AWS_DEFAULT_REGION", "AWS_REGION"] {
+        if let Some(value) = non_empty_env(var) {
+            return if value == name {
+                ConfigSource::Environment((*var).to_owned())
+            } else {
+                ConfigSource::Code
+            };
+        }
+    }
+    match ProfileProvider::region() {
+        Ok(Some(ref region)) if region == name => ConfigSource::Profile,
+        Ok(Some(_)) => ConfigSource::Code,
+        _ if name == Region::UsEast1.name() => ConfigSource::Default,
+        _ => ConfigSource::Code,
+    }
+}
+
+fn endpoint_source(region: &Region, env_name: &str) -> ConfigSource {
+    let endpoint = match *region {
+        Region::Custom { ref endpoint, .. } => endpoint,
+        _ => return ConfigSource::Default,
+    };
+    let service_var = format!("AWS_ENDPOINT_URL_{}", env_name);
+    for var in &[service_var.as_str(), "AWS_ENDPOINT_URL"] {
+        if let Some(value) = non_empty_env(var) {
+            return if value == *endpoint {
+                ConfigSource::Environment((*var).to_owned())
+            } else {
+                ConfigSource::Code
+            };
+        }
+    }
+    ConfigSource::Code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::VirtualEnvironment;
+
+    #[test]
+    fn resolves_regional_endpoints() {
+        assert_eq!(
+            regional_endpoint(&Region::UsWest2, "dynamodb"),
+            "https://dynamodb.us-west-2.amazonaws.com"
+        );
+        assert_eq!(
+            regional_endpoint(&Region::CnNorth1, "sqs"),
+            "https://sqs.cn-north-1.amazonaws.com.cn"
+        );
+        let local = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: "http://localhost:8000".to_owned(),
+        };
+        assert_eq!(
+            regional_endpoint(&local, "dynamodb"),
+            "http://localhost:8000"
+        );
+    }
+
+    #[test]
+    fn reports_endpoint_overrides_from_the_environment() {
+        env::set_var(
+            "AWS_ENDPOINT_URL_RUSOTO_CONFIG_TEST",
+            "http://localhost:4566",
+        );
+        let region = Region::from_env_for_service("RUSOTO_CONFIG_TEST");
+        let config = EffectiveConfig::resolve(&region, "sqs", "sqs", "RUSOTO_CONFIG_TEST");
+        env::remove_var("AWS_ENDPOINT_URL_RUSOTO_CONFIG_TEST");
+
+        assert_eq!(config.endpoint.value, "http://localhost:4566");
+        assert_eq!(
+            config.endpoint.source,
+            ConfigSource::Environment("AWS_ENDPOINT_URL_RUSOTO_CONFIG_TEST".to_owned())
+        );
+        assert_eq!(config.signing_name, "sqs");
+    }
+
+    #[test]
+    fn reports_explicit_endpoints_as_set_in_code() {
+        let region = Region::Custom {
+            name: "eu-west-1".to_owned(),
+            endpoint: "https://sqs.example.com".to_owned(),
+        };
+        let config = EffectiveConfig::resolve(&region, "sqs", "sqs", "RUSOTO_CONFIG_CODE_TEST");
+        assert_eq!(config.endpoint.source, ConfigSource::Code);
+    }
+
+    #[test]
+    fn resolves_endpoints_per_service() {
+        let config = ClientBuilder::new()
+            .with_region(Region::EuWest1)
+            .with_endpoint_resolver(|endpoint_prefix, region| match endpoint_prefix {
+                "sqs" => Some(format!("http://localhost:9324/{}", region.name())),
+                _ => None,
+            })
+            .without_signing()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.region_for("sqs", "SQS"),
+            Region::Custom {
+                name: "eu-west-1".to_owned(),
+                endpoint: "http://localhost:9324/eu-west-1".to_owned(),
+            }
+        );
+        assert_eq!(config.region_for("dynamodb", "DYNAMODB"), Region::EuWest1);
+    }
+
+    #[test]
+    fn reads_the_app_name_from_the_environment() {
+        let environment = VirtualEnvironment::new().with_var("AWS_SDK_UA_APP_ID", "from-env");
+        let from_env = ClientBuilder::new()
+            .with_environment(environment.clone())
+            .without_signing()
+            .build()
+            .unwrap();
+        let from_code = ClientBuilder::new()
+            .with_environment(environment)
+            .with_app_name("from-code")
+            .without_signing()
+            .build()
+            .unwrap();
+
+        assert_eq!(from_env.app_name(), Some("from-env"));
+        assert_eq!(from_code.app_name(), Some("from-code"));
+    }
+
+    #[test]
+    fn reads_the_retries_from_the_environment() {
+        let config = ClientBuilder::new()
+            .with_environment(VirtualEnvironment::new().with_var("AWS_MAX_ATTEMPTS", "4"))
+            .without_signing()
+            .build()
+            .unwrap();
+        assert_eq!(
+            *config.client().retry_config(),
+            RetryConfig {
+                mode: RetryMode::Legacy,
+                max_attempts: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn applies_retries_and_timeouts_to_the_client() {
+        let retry = RetryConfig {
+            mode: RetryMode::Standard,
+            max_attempts: 7,
+        };
+        let config = ClientBuilder::new()
+            .with_region(Region::UsWest2)
+            .with_retry_config(retry.clone())
+            .with_timeout(Duration::from_secs(3))
+            .without_signing()
+            .build()
+            .unwrap();
+        assert_eq!(*config.client().retry_config(), retry);
+
+        let region = config.region_for("sqs", "SQS");
+        let effective =
+            EffectiveConfig::resolve(&region, "sqs", "sqs", "SQS").with_client(config.client());
+        assert_eq!(
+            effective.max_attempts,
+            ResolvedValue::new(7, ConfigSource::Code)
+        );
+        assert_eq!(
+            effective.timeout,
+            ResolvedValue::new(Some(Duration::from_secs(3)), ConfigSource::Code)
+        );
+    }
+}
@@ -0,0 +1,362 @@
+//! AWS SDK Client-Side Monitoring.
+//!
+//! When `AWS_CSM_ENABLED` is `true`, a UDP datagram is sent to the CSM agent for every API call
+//! and every attempt of it, as the other AWS SDKs do. The agent is expected on
+//! `AWS_CSM_HOST` (default `127.0.0.1`) at `AWS_CSM_PORT` (default `31000`), and
+//! `AWS_CSM_CLIENT_ID` is included in every event.
+//!
+//! Publishing is best effort: events that can't be sent are dropped.
+
+use std::env;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use crate::instrument::{operation_name, request_id};
+use crate::request::{HttpResponse, DEFAULT_USER_AGENT};
+use crate::signature::SignedRequest;
+
+const DEFAULT_HOST: &str = "127.0.0.1";
+const DEFAULT_PORT: u16 = 31000;
+
+lazy_static! {
+    static ref PUBLISHER: Option<Publisher> = Publisher::from_env();
+}
+
+struct Publisher {
+    socket: UdpSocket,
+    agent: SocketAddr,
+    client_id: String,
+}
+
+impl Publisher {
+    fn from_env() -> Option<Publisher> {
+        let enabled = env::var("AWS_CSM_ENABLED").ok()?;
+        if !enabled.trim().eq_ignore_ascii_case("true") {
+            return None;
+        }
+        let host = env::var("AWS_CSM_HOST").unwrap_or_else(|_| DEFAULT_HOST.to_owned());
+        let port = env::var("AWS_CSM_PORT")
+            .ok()
+            .and_then(|port| port.trim().parse().ok())
+            .unwrap_or(DEFAULT_PORT);
+        let agent = (host.as_str(), port).to_socket_addrs().ok()?.next()?;
+        let bind = if agent.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = UdpSocket::bind(bind).ok()?;
+        socket.set_nonblocking(true).ok()?;
+
+        Some(Publisher {
+            socket,
+            agent,
+            client_id: truncate(
+                env::var("AWS_CSM_CLIENT_ID").unwrap_or_default(),
+                MAX_CLIENT_ID,
+            ),
+        })
+    }
+
+    fn publish(&self, event: &Event) {
+        if let Ok(datagram) = serde_json::to_vec(event) {
+            let _ = self.socket.send_to(&datagram, self.agent);
+        }
+    }
+}
+
+// field length limits defined by the CSM protocol
+const MAX_CLIENT_ID: usize = 255;
+const MAX_USER_AGENT: usize = 256;
+const MAX_EXCEPTION: usize = 128;
+const MAX_EXCEPTION_MESSAGE: usize = 512;
+
+fn truncate(mut value: String, max_chars: usize) -> String {
+    if let Some((index, _)) = value.char_indices().nth(max_chars) {
+        value.truncate(index);
+    }
+    value
+}
+
+fn millis_since_epoch() -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    now.as_secs() * 1000 + u64::from(now.subsec_millis())
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Event {
+    version: u8,
+    #[serde(rename = "Type")]
+    event_type: &'static str,
+    client_id: String,
+    service: String,
+    api: String,
+    timestamp: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<String>,
+    // ApiCallAttempt fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fqdn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user_agent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    access_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attempt_latency: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    http_status_code: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x_amzn_request_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x_amz_id2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    aws_exception: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sdk_exception: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sdk_exception_message: Option<String>,
+    // ApiCall fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attempt_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latency: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    final_http_status_code: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    final_aws_exception: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    final_sdk_exception: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    final_sdk_exception_message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_retries_exceeded: Option<u8>,
+}
+
+/// The outcome of a single attempt.
+enum Outcome<'a> {
+    Response(&'a HttpResponse),
+    SdkError(&'static str, String),
+}
+
+/// Collects the monitoring data of one API call.
+pub(crate) struct CsmCall {
+    service: String,
+    api: String,
+    region: String,
+    fqdn: String,
+    user_agent: String,
+    access_key: Option<String>,
+    session_token: Option<String>,
+    started: Instant,
+    attempt_started: Instant,
+    attempts: u32,
+    max_retries_exceeded: bool,
+}
+
+impl CsmCall {
+    /// Starts monitoring a call, if client side monitoring is enabled.
+    pub(crate) fn start(request: &SignedRequest) -> Option<CsmCall> {
+        PUBLISHER.as_ref()?;
+
+        let user_agent = request
+            .headers
+            .get("user-agent")
+            .and_then(|values| values.first())
+            .map(|value| String::from_utf8_lossy(value).into_owned())
+            .unwrap_or_else(|| DEFAULT_USER_AGENT.clone());
+        let now = Instant::now();
+        Some(CsmCall {
+            service: request.service.clone(),
+            api: operation_name(request).unwrap_or_default(),
+            region: request.region.name().to_owned(),
+            fqdn: request.hostname(),
+            user_agent: truncate(user_agent, MAX_USER_AGENT),
+            access_key: None,
+            session_token: None,
+            started: now,
+            attempt_started: now,
+            attempts: 1,
+            max_retries_exceeded: false,
+        })
+    }
+
+    /// Records the credentials the request was signed with.
+    pub(crate) fn set_credentials(&mut self, access_key: &str, session_token: Option<&str>) {
+        self.access_key = Some(access_key.to_owned());
+        self.session_token = session_token.map(str::to_owned);
+    }
+
+    /// Publishes the attempt event of a response which is going to be retried.
+    pub(crate) fn retry_after_response(&mut self, response: &HttpResponse) {
+        self.retry(Outcome::Response(response));
+    }
+
+    /// Publishes the attempt event of a failed request which is going to be retried.
+    pub(crate) fn retry_after_error(&mut self, exception: &'static str, message: &str) {
+        self.retry(Outcome::SdkError(exception, message.to_owned()));
+    }
+
+    /// Records that the call failed with a retryable error after its last attempt.
+    pub(crate) fn set_max_retries_exceeded(&mut self) {
+        self.max_retries_exceeded = true;
+    }
+
+    /// Publishes the attempt and call events for a received response.
+    pub(crate) fn finish_with_response(&self, response: &HttpResponse) {
+        self.finish(Outcome::Response(response));
+    }
+
+    /// Publishes the attempt and call events for a request which failed without a response.
+    pub(crate) fn finish_with_error(&self, exception: &'static str, message: &str) {
+        self.finish(Outcome::SdkError(exception, message.to_owned()));
+    }
+
+    fn event(&self, event_type: &'static str) -> Event {
+        Event {
+            version: 1,
+            event_type,
+            client_id: PUBLISHER
+                .as_ref()
+                .map(|p| p.client_id.clone())
+                .unwrap_or_default(),
+            service: self.service.clone(),
+            api: self.api.clone(),
+            timestamp: millis_since_epoch(),
+            region: Some(self.region.clone()),
+            ..Default::default()
+        }
+    }
+
+    fn retry(&mut self, outcome: Outcome) {
+        if let Some(publisher) = PUBLISHER.as_ref() {
+            publisher.publish(&self.attempt_event(outcome));
+        }
+        self.attempts += 1;
+        self.attempt_started = Instant::now();
+    }
+
+    fn finish(&self, outcome: Outcome) {
+        let publisher = match PUBLISHER.as_ref() {
+            Some(publisher) => publisher,
+            None => return,
+        };
+        let (attempt, call) = self.events(outcome);
+        publisher.publish(&attempt);
+        publisher.publish(&call);
+    }
+
+    fn events(&self, outcome: Outcome) -> (Event, Event) {
+        let attempt = self.attempt_event(outcome);
+
+        let mut call = self.event("ApiCall");
+        call.attempt_count = Some(self.attempts);
+        call.latency = Some(elapsed_millis(self.started));
+        call.final_http_status_code = attempt.http_status_code;
+        call.final_aws_exception = attempt.aws_exception.clone();
+        call.final_sdk_exception = attempt.sdk_exception.clone();
+        call.final_sdk_exception_message = attempt.sdk_exception_message.clone();
+        call.max_retries_exceeded = Some(self.max_retries_exceeded as u8);
+
+        (attempt, call)
+    }
+
+    fn attempt_event(&self, outcome: Outcome) -> Event {
+        let mut attempt = self.event("ApiCallAttempt");
+        attempt.fqdn = Some(self.fqdn.clone());
+        attempt.user_agent = Some(self.user_agent.clone());
+        attempt.access_key = self.access_key.clone();
+        attempt.session_token = self.session_token.clone();
+        attempt.attempt_latency = Some(elapsed_millis(self.attempt_started));
+
+        match outcome {
+            Outcome::Response(response) => {
+                attempt.http_status_code = Some(response.status.as_u16());
+                attempt.x_amzn_request_id = request_id(response).map(str::to_owned);
+                attempt.x_amz_id2 = response.headers.get("x-amz-id-2").cloned();
+                if !response.status.is_success() {
+                    // JSON protocols name the error in a header; others only in the body,
+                    // which hasn't been read yet
+                    attempt.aws_exception = response
+                        .headers
+                        .get("x-amzn-errortype")
+                        .map(|error| error.split(':').next().unwrap_or_default().to_owned())
+                        .map(|error| truncate(error, MAX_EXCEPTION));
+                }
+            }
+            Outcome::SdkError(exception, message) => {
+                attempt.sdk_exception = Some(exception.to_owned());
+                attempt.sdk_exception_message = Some(truncate(message, MAX_EXCEPTION_MESSAGE));
+            }
+        }
+        attempt
+    }
+}
+
+fn elapsed_millis(since: Instant) -> u64 {
+    let elapsed = since.elapsed();
+    elapsed.as_secs() * 1000 + u64::from(elapsed.subsec_millis())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::region::Region;
+    use crate::stream::ByteStream;
+    use http::{HeaderMap, StatusCode};
+
+    #[test]
+    fn serializes_attempt_and_call_events() {
+        let mut request = SignedRequest::new("POST", "dynamodb", &Region::UsWest2, "/");
+        request.add_header("x-amz-target", "DynamoDB_20120810.ListTables");
+        let now = Instant::now();
+        let call = CsmCall {
+            service: request.service.clone(),
+            api: operation_name(&request).unwrap(),
+            region: "us-west-2".to_owned(),
+            fqdn: request.hostname(),
+            user_agent: "rusoto/0.41.0".to_owned(),
+            access_key: Some("AKIDEXAMPLE".to_owned()),
+            session_token: None,
+            started: now,
+            attempt_started: now,
+            attempts: 1,
+            max_retries_exceeded: false,
+        };
+        let mut headers = HeaderMap::<String>::default();
+        headers.insert("x-amzn-requestid", "request-id".to_owned());
+        headers.insert("x-amzn-errortype", "ThrottlingException:http://".to_owned());
+        let response = HttpResponse {
+            status: StatusCode::BAD_REQUEST,
+            body: ByteStream::from(Vec::new()),
+            headers,
+        };
+
+        let (attempt, call) = call.events(Outcome::Response(&response));
+        let attempt: serde_json::Value = serde_json::to_value(&attempt).unwrap();
+        let call: serde_json::Value = serde_json::to_value(&call).unwrap();
+
+        assert_eq!(attempt["Type"], "ApiCallAttempt");
+        assert_eq!(attempt["Api"], "ListTables");
+        assert_eq!(attempt["Fqdn"], "dynamodb.us-west-2.amazonaws.com");
+        assert_eq!(attempt["HttpStatusCode"], 400);
+        assert_eq!(attempt["XAmznRequestId"], "request-id");
+        assert_eq!(attempt["AwsException"], "ThrottlingException");
+        assert_eq!(attempt["AccessKey"], "AKIDEXAMPLE");
+        assert!(attempt.get("SessionToken").is_none());
+        assert_eq!(call["Type"], "ApiCall");
+        assert_eq!(call["AttemptCount"], 1);
+        assert_eq!(call["FinalAwsException"], "ThrottlingException");
+        assert_eq!(call["MaxRetriesExceeded"], 0);
+    }
+
+    #[test]
+    fn truncates_on_char_boundaries() {
+        assert_eq!(truncate("héllo".to_owned(), 2), "hé");
+        assert_eq!(truncate("hi".to_owned(), 5), "hi");
+    }
+}
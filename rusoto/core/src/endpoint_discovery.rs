@@ -0,0 +1,190 @@
+//! Endpoint discovery.
+//!
+//! Some services, such as DynamoDB, can tell clients which endpoint to send their requests to
+//! through an endpoint operation, usually `DescribeEndpoints`. Operations marked with the
+//! `endpointdiscovery` trait in the service model send their requests to the discovered
+//! endpoint, which is cached for the period returned by the service.
+//!
+//! Discovery is required by a few services and optional for the others. Optional discovery is
+//! enabled by setting `AWS_ENABLE_ENDPOINT_DISCOVERY` to `true`; without it requests go to the
+//! regional endpoint, as before. Requests for a custom region always go to its endpoint.
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use futures::Future;
+
+/// An endpoint returned by a service's endpoint operation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiscoveredEndpoint {
+    /// Host name, and optionally port, to send requests to
+    pub address: String,
+    /// How long the endpoint may be used for
+    pub cache_period: Duration,
+}
+
+impl DiscoveredEndpoint {
+    /// Create a new DiscoveredEndpoint from the fields of the `Endpoint` shape.
+    pub fn new(address: String, cache_period_in_minutes: i64) -> DiscoveredEndpoint {
+        DiscoveredEndpoint {
+            address,
+            cache_period: Duration::from_secs(cache_period_in_minutes.max(0) as u64 * 60),
+        }
+    }
+
+    /// The host of the endpoint, without the scheme some services include.
+    fn host(&self) -> &str {
+        let address = self.address.trim();
+        address
+            .find("://")
+            .map_or(address, |index| &address[index + "://".len()..])
+            .trim_end_matches('/')
+    }
+}
+
+/// The future returned by a call to the endpoint operation.
+pub type DiscoverEndpointsFuture =
+    Box<dyn Future<Item = Vec<DiscoveredEndpoint>, Error = String> + Send>;
+
+/// How to discover the endpoint of an operation, passed to
+/// `Client::sign_and_dispatch_with_discovery` by generated clients.
+pub struct EndpointDiscovery {
+    pub(crate) required: bool,
+    pub(crate) discover: Box<dyn FnOnce() -> DiscoverEndpointsFuture + Send>,
+}
+
+impl EndpointDiscovery {
+    /// Create a new EndpointDiscovery calling `discover` when no endpoint is cached.
+    ///
+    /// When `required` is true, requests fail if no endpoint can be discovered, even if
+    /// `AWS_ENABLE_ENDPOINT_DISCOVERY` isn't set.
+    pub fn new<F>(required: bool, discover: F) -> EndpointDiscovery
+    where
+        F: FnOnce() -> DiscoverEndpointsFuture + Send + 'static,
+    {
+        EndpointDiscovery {
+            required,
+            discover: Box::new(discover),
+        }
+    }
+}
+
+impl fmt::Debug for EndpointDiscovery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EndpointDiscovery")
+            .field("required", &self.required)
+            .finish()
+    }
+}
+
+struct CachedEndpoint {
+    host: String,
+    expires: Instant,
+}
+
+/// The endpoints discovered by the clones of a `Client`.
+pub(crate) struct EndpointCache {
+    enabled: bool,
+    endpoints: Mutex<HashMap<String, CachedEndpoint>>,
+}
+
+impl EndpointCache {
+    pub(crate) fn new(enabled: bool) -> EndpointCache {
+        EndpointCache {
+            enabled,
+            endpoints: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn from_env() -> EndpointCache {
+        let enabled = env::var("AWS_ENABLE_ENDPOINT_DISCOVERY")
+            .map(|value| value.trim().eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        EndpointCache::new(enabled)
+    }
+
+    /// Whether optional endpoint discovery is enabled.
+    pub(crate) fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the cached host for `key`, unless it has expired.
+    pub(crate) fn get(&self, key: &str) -> Option<String> {
+        let mut endpoints = self.endpoints.lock().unwrap();
+        match endpoints.get(key) {
+            Some(endpoint) if endpoint.expires > Instant::now() => Some(endpoint.host.clone()),
+            Some(_) => {
+                endpoints.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Caches the first of the discovered endpoints, returning its host.
+    pub(crate) fn insert(&self, key: &str, endpoints: &[DiscoveredEndpoint]) -> Option<String> {
+        let endpoint = endpoints.first()?;
+        let host = endpoint.host().to_owned();
+        if host.is_empty() {
+            return None;
+        }
+        self.endpoints.lock().unwrap().insert(
+            key.to_owned(),
+            CachedEndpoint {
+                host: host.clone(),
+                expires: Instant::now() + endpoint.cache_period,
+            },
+        );
+        Some(host)
+    }
+
+    /// Forgets the endpoint of `key`, e.g. after the service rejected a request sent to it.
+    pub(crate) fn invalidate(&self, key: &str) {
+        self.endpoints.lock().unwrap().remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caches_endpoints_for_their_cache_period() {
+        let cache = EndpointCache::new(true);
+        assert_eq!(cache.get("dynamodb:us-east-1"), None);
+
+        let endpoints = vec![DiscoveredEndpoint::new(
+            "dynamodb.us-east-1.amazonaws.com".to_owned(),
+            1440,
+        )];
+        assert_eq!(
+            cache.insert("dynamodb:us-east-1", &endpoints),
+            Some("dynamodb.us-east-1.amazonaws.com".to_owned())
+        );
+        assert_eq!(
+            cache.get("dynamodb:us-east-1"),
+            Some("dynamodb.us-east-1.amazonaws.com".to_owned())
+        );
+
+        cache.invalidate("dynamodb:us-east-1");
+        assert_eq!(cache.get("dynamodb:us-east-1"), None);
+    }
+
+    #[test]
+    fn expired_endpoints_are_not_used() {
+        let cache = EndpointCache::new(true);
+        let endpoints = vec![DiscoveredEndpoint::new(
+            "https://example.com/".to_owned(),
+            0,
+        )];
+        assert_eq!(
+            cache.insert("key", &endpoints),
+            Some("example.com".to_owned())
+        );
+        assert_eq!(cache.get("key"), None);
+        assert_eq!(cache.insert("key", &[]), None);
+    }
+}
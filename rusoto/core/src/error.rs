@@ -27,6 +27,61 @@ pub enum RusotoError<E> {
 /// Result carrying a generic `RusotoError`.
 pub type RusotoResult<T, E> = Result<T, RusotoError<E>>;
 
+impl<E> RusotoError<E> {
+    /// Maps the service specific error with `f`, leaving all other variants untouched.
+    ///
+    /// This is useful for helpers which combine several operations into a single error type.
+    pub fn map_service<F, T>(self, f: F) -> RusotoError<T>
+    where
+        F: FnOnce(E) -> T,
+    {
+        match self {
+            RusotoError::Service(err) => RusotoError::Service(f(err)),
+            RusotoError::HttpDispatch(err) => RusotoError::HttpDispatch(err),
+            RusotoError::Credentials(err) => RusotoError::Credentials(err),
+            RusotoError::Validation(err) => RusotoError::Validation(err),
+            RusotoError::ParseError(err) => RusotoError::ParseError(err),
+            RusotoError::Unknown(err) => RusotoError::Unknown(err),
+        }
+    }
+}
+
+/// Error returned by the builders of request types when a required member hasn't been set.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BuildError {
+    /// The name of the missing member
+    pub member: &'static str,
+    message: String,
+}
+
+impl BuildError {
+    /// Creates an error for the missing member `member`.
+    pub fn missing(member: &'static str) -> BuildError {
+        BuildError {
+            member,
+            message: format!("missing required member `{}`", member),
+        }
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for BuildError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+impl<E> From<BuildError> for RusotoError<E> {
+    fn from(err: BuildError) -> Self {
+        RusotoError::Validation(err.message)
+    }
+}
+
 impl<E> From<XmlParseError> for RusotoError<E> {
     fn from(err: XmlParseError) -> Self {
         let XmlParseError(message) = err;
@@ -85,3 +140,18 @@ impl<E: Error + 'static> Error for RusotoError<E> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_errors_are_validation_errors() {
+        let error = BuildError::missing("bucket");
+        assert_eq!(error.to_string(), "missing required member `bucket`");
+        assert_eq!(
+            RusotoError::<()>::from(error),
+            RusotoError::Validation("missing required member `bucket`".to_owned())
+        );
+    }
+}
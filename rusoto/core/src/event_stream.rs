@@ -0,0 +1,5 @@
+//! Encoding, decoding and signing of `application/vnd.amazon.eventstream` messages, used by
+//! bidirectional streaming operations sent over HTTP/2.
+//!
+//! Moved to rusoto_signature.
+pub use rusoto_signature::event_stream::*;
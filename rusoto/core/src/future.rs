@@ -155,7 +155,10 @@ pub fn new<T, E>(
 }
 
 impl<T, E> RusotoFuture<T, E> {
-    /// Set the timeout on the future to the provided duration.
+    /// Set the timeout of each attempt to the provided duration.
+    ///
+    /// An attempt which times out is abandoned and, if the retry settings allow it, retried.
+    /// Use `with_operation_timeout` to limit the time spent on all attempts together.
     ///
     /// Unlike `set_timeout` this method can be easily chained:
     ///
@@ -182,7 +185,7 @@ impl<T, E> RusotoFuture<T, E> {
         self
     }
 
-    /// Set the timeout on the future to the provided duration.
+    /// Set the timeout of each attempt to the provided duration.
     ///
     /// This is only guaranteed to take effect when called before the future
     /// is polled for the first time.
@@ -192,7 +195,7 @@ impl<T, E> RusotoFuture<T, E> {
         }
     }
 
-    /// Clear the timeout on the future.
+    /// Clear the timeout of each attempt.
     ///
     /// This is only guaranteed to take effect when called before the future
     /// is polled for the first time.
@@ -202,6 +205,61 @@ impl<T, E> RusotoFuture<T, E> {
         }
     }
 
+    /// Set the timeout of the whole operation to the provided duration.
+    ///
+    /// The operation timeout starts when the future is first polled and covers fetching
+    /// credentials, every attempt and the backoff between them. Once it has passed, the attempt
+    /// in progress is abandoned and the future fails with an `HttpDispatch` error. Retries
+    /// which would only start after it has passed aren't made, so the error of the last attempt
+    /// is returned instead.
+    ///
+    /// Combine it with a shorter attempt timeout, so a hung connection is abandoned and retried
+    /// while the call as a whole still has a larger budget:
+    ///
+    /// ```rust,ignore
+    /// # // TODO: remove ignore when the cyclic dependency issue has been fixed
+    /// # // https://github.com/rusoto/rusoto/pull/1141#issuecomment-421865362
+    /// # extern crate rusoto_core;
+    /// # extern crate rusoto_s3;
+    /// #
+    /// # use std::time::Duration;
+    /// # use rusoto_core::Region;
+    /// # use rusoto_s3::{S3, S3Client};
+    /// #
+    /// # let s3 = S3Client::new(Region::default());
+    /// #
+    /// let future = s3.list_buckets()
+    ///     .with_timeout(Duration::from_secs(2))
+    ///     .with_operation_timeout(Duration::from_secs(10));
+    /// ```
+    ///
+    /// This is only guaranteed to take effect when called before the future
+    /// is polled for the first time.
+    pub fn with_operation_timeout(mut self, timeout: Duration) -> Self {
+        self.set_operation_timeout(timeout);
+        self
+    }
+
+    /// Set the timeout of the whole operation to the provided duration.
+    ///
+    /// This is only guaranteed to take effect when called before the future
+    /// is polled for the first time.
+    pub fn set_operation_timeout(&mut self, timeout: Duration) {
+        if let Some(RusotoFutureState::SignAndDispatch { ref mut future, .. }) = self.state {
+            future.set_operation_timeout(timeout);
+        }
+    }
+
+    /// Clear the timeout of the whole operation.
+    ///
+    /// This is only guaranteed to take effect when called before the future
+    /// is polled for the first time.
+    pub fn clear_operation_timeout(&mut self) {
+        if let Some(RusotoFutureState::SignAndDispatch { ref mut future, .. }) = self.state {
+            future.clear_operation_timeout();
+        }
+    }
+
     /// Blocks the current thread until the future has resolved.
     ///
     /// This is meant to provide a simple way for non-async consumers
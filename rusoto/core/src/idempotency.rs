@@ -0,0 +1,26 @@
+//! Idempotency tokens.
+//!
+//! Operations which create resources often accept a client token, such as the `ClientToken` of
+//! EC2's `RunInstances`. Requests repeated with the same token are only carried out once, so a
+//! request can be retried after a network error without creating a second resource. Generated
+//! clients fill in a token from `generate_token` when such a member is left `None`.
+
+use uuid::Uuid;
+
+/// Returns a new, random idempotency token: a version 4 UUID.
+pub fn generate_token() -> String {
+    Uuid::new_v4().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_distinct_uuids() {
+        let token = generate_token();
+        assert_eq!(token.len(), 36);
+        assert_eq!(Uuid::parse_str(&token).unwrap().get_version_num(), 4);
+        assert_ne!(token, generate_token());
+    }
+}
@@ -0,0 +1,122 @@
+//! Instrumentation of dispatched requests.
+//!
+//! With the `tracing` feature, every request gets an `aws_request` span carrying the service,
+//! operation and region. The current attempt is updated as the request is retried, and the HTTP
+//! status, latency and AWS request id are recorded on the span once the final response arrives.
+
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+
+#[cfg(feature = "tracing")]
+use tracing::field;
+
+use crate::request::HttpResponse;
+use crate::signature::{SignedRequest, SignedRequestPayload};
+
+/// Headers AWS services return the request id in, in order of preference.
+const REQUEST_ID_HEADERS: &[&str] = &["x-amzn-requestid", "x-amz-request-id"];
+
+/// Returns the AWS request id of a response, if any.
+pub(crate) fn request_id(response: &HttpResponse) -> Option<&str> {
+    REQUEST_ID_HEADERS
+        .iter()
+        .filter_map(|name| response.headers.get(*name))
+        .map(String::as_str)
+        .next()
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) struct RequestSpan {
+    span: tracing::Span,
+    started: Instant,
+}
+
+#[cfg(feature = "tracing")]
+impl RequestSpan {
+    pub(crate) fn new(request: &SignedRequest) -> RequestSpan {
+        let operation = operation_name(request);
+        let span = tracing::info_span!(
+            "aws_request",
+            service = request.service.as_str(),
+            operation = operation.as_ref().map_or("", String::as_str),
+            region = request.region.name(),
+            http.method = request.method.as_str(),
+            attempt = 1u64,
+            http.status_code = field::Empty,
+            latency_ms = field::Empty,
+            request_id = field::Empty,
+        );
+        RequestSpan {
+            span,
+            started: Instant::now(),
+        }
+    }
+
+    pub(crate) fn span(&self) -> &tracing::Span {
+        &self.span
+    }
+
+    pub(crate) fn record_response(&self, response: &HttpResponse) {
+        let latency = self.started.elapsed();
+        let latency_ms = latency.as_secs() * 1000 + u64::from(latency.subsec_millis());
+        self.span
+            .record("http.status_code", &u64::from(response.status.as_u16()));
+        self.span.record("latency_ms", &latency_ms);
+        if let Some(request_id) = request_id(response) {
+            self.span.record("request_id", &request_id);
+        }
+        tracing::debug!(parent: &self.span, "response received");
+    }
+
+    pub(crate) fn record_retry(&self, attempt: u32, reason: &str) {
+        self.span.record("attempt", &u64::from(attempt));
+        tracing::debug!(parent: &self.span, reason, "retrying request");
+    }
+
+    pub(crate) fn record_error(&self, error: &str) {
+        tracing::warn!(parent: &self.span, error, "request failed");
+    }
+}
+
+/// Works out the API operation of a request.
+///
+/// JSON protocols name it in the `X-Amz-Target` header, query protocols in the `Action`
+/// parameter. REST protocols don't carry it, in which case `None` is returned.
+pub(crate) fn operation_name(request: &SignedRequest) -> Option<String> {
+    if let Some(target) = request.headers.get("x-amz-target").and_then(|v| v.first()) {
+        let target = String::from_utf8_lossy(target);
+        return target.rsplit('.').next().map(str::to_owned);
+    }
+    if let Some(Some(action)) = request.params.get("Action") {
+        return Some(action.clone());
+    }
+    if let Some(SignedRequestPayload::Buffer(ref body)) = request.payload {
+        return body
+            .split(|b| *b == b'&')
+            .find(|pair| pair.starts_with(b"Action="))
+            .map(|pair| String::from_utf8_lossy(&pair[b"Action=".len()..]).into_owned());
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::region::Region;
+
+    #[test]
+    fn finds_operation_of_each_protocol() {
+        let mut json = SignedRequest::new("POST", "dynamodb", &Region::UsEast1, "/");
+        json.add_header("x-amz-target", "DynamoDB_20120810.ListTables");
+        assert_eq!(operation_name(&json), Some("ListTables".to_owned()));
+
+        let mut query = SignedRequest::new("POST", "sts", &Region::UsEast1, "/");
+        query.set_payload(Some(
+            b"Action=GetCallerIdentity&Version=2011-06-15".to_vec(),
+        ));
+        assert_eq!(operation_name(&query), Some("GetCallerIdentity".to_owned()));
+
+        let rest = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket");
+        assert_eq!(operation_name(&rest), None);
+    }
+}
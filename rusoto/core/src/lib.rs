@@ -20,18 +20,24 @@
 //! A high level overview is available in `README.md` at <https://github.com/rusoto/rusoto>.
 
 extern crate bytes;
+#[cfg(feature = "chrono")]
+extern crate chrono;
 extern crate futures;
 extern crate hyper;
 #[cfg(feature = "rustls")]
 extern crate hyper_rustls as tls;
-#[cfg(feature = "native-tls")]
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
 extern crate hyper_tls as tls;
 #[macro_use]
 extern crate lazy_static;
 #[macro_use]
 extern crate log;
 extern crate base64;
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+extern crate native_tls_crate;
 pub extern crate rusoto_credential as credential;
+#[cfg(feature = "rustls")]
+extern crate rustls_crate;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
@@ -39,30 +45,55 @@ extern crate serde_json;
 extern crate time;
 extern crate tokio;
 extern crate tokio_timer;
+#[cfg(feature = "tracing")]
+extern crate tracing;
+extern crate uuid;
+#[cfg(feature = "rustls")]
+extern crate webpki_roots;
 extern crate xml;
 
+// rustls wins when both TLS features are enabled, e.g. when another crate in the dependency
+// graph leaves the default features of a service crate turned on. For the same reason, a TLS
+// feature wins over `http-only`, which only compiles TLS out when neither is enabled.
+#[cfg(not(any(feature = "native-tls", feature = "rustls", feature = "http-only")))]
+compile_error!("rusoto_core requires the `native-tls`, the `rustls` or the `http-only` feature");
+
 mod client;
+mod csm;
 mod error;
 mod future;
+mod instrument;
 mod stream;
 
+pub mod batch;
+pub mod config;
+pub mod endpoint_discovery;
+pub mod event_stream;
+pub mod idempotency;
 pub mod param;
+pub mod proxy;
 #[doc(hidden)]
 pub mod region;
 pub mod request;
-#[doc(hidden)]
+pub mod retry;
+pub mod service;
+pub mod shutdown;
 pub mod signature;
+pub mod timestamp;
+pub mod waiter;
+pub mod xray;
 
-#[doc(hidden)]
-pub use crate::client::Client;
+pub use crate::client::{AwsService, Client};
 #[doc(hidden)]
 pub mod proto;
 #[doc(hidden)]
 pub mod serialization;
 
 pub use crate::credential::{CredentialsError, DefaultCredentialsProvider, ProvideAwsCredentials};
-pub use crate::error::{RusotoError, RusotoResult};
+pub use crate::error::{BuildError, RusotoError, RusotoResult};
 pub use crate::future::RusotoFuture;
 pub use crate::region::Region;
-pub use crate::request::{DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError};
-pub use crate::stream::ByteStream;
+pub use crate::request::{
+    DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError, RedirectPolicy,
+};
+pub use crate::stream::{ByteStream, TransferProgress};
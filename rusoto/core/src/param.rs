@@ -74,3 +74,11 @@ impl ToParam for String {
         self.clone()
     }
 }
+
+#[cfg(feature = "chrono")]
+impl ToParam for chrono::DateTime<chrono::Utc> {
+    /// Converts a timestamp to an ISO 8601 paramater
+    fn to_param(&self) -> String {
+        crate::timestamp::to_iso8601(self)
+    }
+}
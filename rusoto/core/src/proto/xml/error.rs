@@ -33,7 +33,7 @@ impl XmlErrorDeserializer {
                 "Detail" => {
                     start_element("Detail", stack)?;
                     if let Ok(characters) = characters(stack) {
-                        obj.detail = Some(characters.to_string());
+                        obj.detail = Some(characters);
                         end_element("Detail", stack)?;
                     }
                 }
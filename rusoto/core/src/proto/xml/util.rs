@@ -88,11 +88,11 @@ pub fn characters<T: Peek + Next>(stack: &mut T) -> Result<String, XmlParseError
         // If it is, return an empty string.
         let current = stack.peek();
         if let Some(&Ok(XmlEvent::EndElement { .. })) = current {
-            return Ok("".to_string());
+            return Ok(String::new());
         }
     }
     if let Some(Ok(XmlEvent::Characters(data))) = stack.next() {
-        Ok(data.to_string())
+        Ok(data)
     } else {
         Err(XmlParseError::new("Expected characters"))
     }
@@ -198,35 +198,53 @@ pub fn find_start_element<T: Peek + Next>(stack: &mut T) {
 enum DeserializerNext {
     Close,
     Skip,
-    Element(String),
+    Element,
 }
 
 pub fn deserialize_elements<T, S, F>(
     tag_name: &str,
     stack: &mut T,
-    mut handle_element: F,
+    handle_element: F,
 ) -> Result<S, XmlParseError>
 where
     T: Peek + Next,
     S: Default,
     F: FnMut(&str, &mut T, &mut S) -> Result<(), XmlParseError>,
 {
-    let mut obj = S::default();
+    deserialize_elements_into(tag_name, stack, S::default(), handle_element)
+}
 
+/// Deserializes the child elements of `tag_name` into `obj`, for the values which have no default
+pub fn deserialize_elements_into<T, S, F>(
+    tag_name: &str,
+    stack: &mut T,
+    mut obj: S,
+    mut handle_element: F,
+) -> Result<S, XmlParseError>
+where
+    T: Peek + Next,
+    F: FnMut(&str, &mut T, &mut S) -> Result<(), XmlParseError>,
+{
     start_element(tag_name, stack)?;
 
+    // the name of each child is copied out of the stack before it's consumed, into one buffer
+    let mut name = String::new();
     loop {
         let next_event = match stack.peek() {
             Some(&Ok(XmlEvent::EndElement { .. })) => DeserializerNext::Close,
-            Some(&Ok(XmlEvent::StartElement { ref name, .. })) => {
-                DeserializerNext::Element(name.local_name.to_owned())
+            Some(&Ok(XmlEvent::StartElement {
+                name: ref element, ..
+            })) => {
+                name.clear();
+                name.push_str(&element.local_name);
+                DeserializerNext::Element
             }
             _ => DeserializerNext::Skip,
         };
 
         match next_event {
-            DeserializerNext::Element(name) => {
-                handle_element(&name[..], stack, &mut obj)?;
+            DeserializerNext::Element => {
+                handle_element(&name, stack, &mut obj)?;
             }
             DeserializerNext::Close => break,
             DeserializerNext::Skip => {
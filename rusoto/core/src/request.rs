@@ -9,15 +9,22 @@ use std::error::Error;
 use std::fmt;
 use std::io;
 use std::io::Error as IoError;
+use std::io::Write;
 use std::mem;
 use std::rc::Rc;
 use std::sync::Arc;
 use std::time::Duration;
 
+#[cfg(unix)]
+use crate::credential::unix_socket::UnixSocketConnector;
+use crate::proxy::{Proxy, ProxyConnector};
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
 use crate::tls::HttpsConnector;
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
+use flate2::write::{GzDecoder, ZlibDecoder};
 use futures::{Async, Future, Poll, Stream};
-use http::{HeaderMap, Request, StatusCode};
+use http::header::{HeaderValue, ACCEPT_ENCODING, LOCATION};
+use http::{HeaderMap, Method, Request, StatusCode, Uri};
 use hyper::body::Body;
 use hyper::client::connect::Connect;
 use hyper::client::Builder as HyperBuilder;
@@ -37,7 +44,7 @@ include!(concat!(env!("OUT_DIR"), "/user_agent_vars.rs"));
 // Use a lazy static to cache the default User-Agent header
 // because it never changes once it's been computed.
 lazy_static! {
-    static ref DEFAULT_USER_AGENT: String = format!(
+    pub(crate) static ref DEFAULT_USER_AGENT: String = format!(
         "rusoto/{} rust/{} {}",
         env!("CARGO_PKG_VERSION"),
         RUST_VERSION,
@@ -95,10 +102,40 @@ impl fmt::Debug for BufferedHttpResponse {
 }
 
 /// Future returned from `HttpResponse::buffer`.
+///
+/// A body received in a single chunk is returned as is. Bodies received in several chunks are
+/// copied once, into a buffer sized by the `Content-Length` header.
 pub struct BufferedHttpResponseFuture {
     status: StatusCode,
     headers: HeaderMap<String>,
-    future: ::futures::stream::Concat2<ByteStream>,
+    body: ByteStream,
+    first: Option<Bytes>,
+    buffer: Option<BytesMut>,
+}
+
+impl BufferedHttpResponseFuture {
+    fn push(&mut self, chunk: Bytes) {
+        if let Some(ref mut buffer) = self.buffer {
+            buffer.extend_from_slice(&chunk);
+            return;
+        }
+        let first = match self.first.take() {
+            Some(first) => first,
+            None => {
+                self.first = Some(chunk);
+                return;
+            }
+        };
+        let content_length = self
+            .headers
+            .get("content-length")
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(0);
+        let mut buffer = BytesMut::with_capacity(content_length.max(first.len() + chunk.len()));
+        buffer.extend_from_slice(&first);
+        buffer.extend_from_slice(&chunk);
+        self.buffer = Some(buffer);
+    }
 }
 
 impl Future for BufferedHttpResponseFuture {
@@ -106,16 +143,22 @@ impl Future for BufferedHttpResponseFuture {
     type Error = HttpDispatchError;
 
     fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
-        self.future
-            .poll()
-            .map_err(std::convert::Into::into)
-            .map(|r#async| {
-                r#async.map(|body| BufferedHttpResponse {
-                    status: self.status,
-                    headers: mem::replace(&mut self.headers, Default::default()),
-                    body,
-                })
-            })
+        loop {
+            match self.body.poll()? {
+                Async::Ready(Some(chunk)) => self.push(chunk),
+                Async::Ready(None) => break,
+                Async::NotReady => return Ok(Async::NotReady),
+            }
+        }
+        let body = match self.buffer.take() {
+            Some(buffer) => buffer.freeze(),
+            None => self.first.take().unwrap_or_else(Bytes::new),
+        };
+        Ok(Async::Ready(BufferedHttpResponse {
+            status: self.status,
+            headers: mem::replace(&mut self.headers, Default::default()),
+            body,
+        }))
     }
 }
 
@@ -125,7 +168,9 @@ impl HttpResponse {
         BufferedHttpResponseFuture {
             status: self.status,
             headers: self.headers,
-            future: self.body.concat2(),
+            body: self.body,
+            first: None,
+            buffer: None,
         }
     }
 
@@ -150,18 +195,172 @@ impl HttpResponse {
             body: ByteStream::new(body),
         }
     }
+
+    /// Decompresses a body compressed with gzip or deflate, as named by its `Content-Encoding`.
+    /// The `Content-Encoding` and `Content-Length` headers of the compressed body are removed.
+    fn decompressed(self) -> HttpResponse {
+        let decoder = match self
+            .headers
+            .get("content-encoding")
+            .map(|encoding| encoding.trim().to_ascii_lowercase())
+        {
+            Some(ref encoding) if encoding == "gzip" || encoding == "x-gzip" => {
+                Decoder::Gzip(GzDecoder::new(Vec::new()))
+            }
+            Some(ref encoding) if encoding == "deflate" => {
+                Decoder::Deflate(ZlibDecoder::new(Vec::new()))
+            }
+            _ => return self,
+        };
+        let HttpResponse {
+            status,
+            mut headers,
+            body,
+        } = self;
+        headers.remove("content-encoding");
+        headers.remove("content-length");
+        HttpResponse {
+            status,
+            headers,
+            body: ByteStream::new(DecodingStream {
+                body,
+                decoder,
+                received: false,
+                finished: false,
+            }),
+        }
+    }
+}
+
+/// Decompresses a response body as it is received.
+struct DecodingStream {
+    body: ByteStream,
+    decoder: Decoder,
+    /// Whether any data was received. Empty bodies, such as the ones of `HEAD` responses, are
+    /// left empty.
+    received: bool,
+    finished: bool,
+}
+
+impl Stream for DecodingStream {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Bytes>, io::Error> {
+        while !self.finished {
+            match self.body.poll()? {
+                Async::Ready(Some(chunk)) => {
+                    self.received |= !chunk.is_empty();
+                    self.decoder.write_all(&chunk)?;
+                }
+                Async::Ready(None) => {
+                    self.finished = true;
+                    if self.received {
+                        self.decoder.try_finish()?;
+                    }
+                }
+                Async::NotReady => return Ok(Async::NotReady),
+            }
+            let decoded = self.decoder.take();
+            if !decoded.is_empty() {
+                return Ok(Async::Ready(Some(Bytes::from(decoded))));
+            }
+        }
+        Ok(Async::Ready(None))
+    }
+}
+
+enum Decoder {
+    Gzip(GzDecoder<Vec<u8>>),
+    /// HTTP's deflate is zlib data
+    Deflate(ZlibDecoder<Vec<u8>>),
+}
+
+impl Decoder {
+    fn write_all(&mut self, data: &[u8]) -> io::Result<()> {
+        match *self {
+            Decoder::Gzip(ref mut decoder) => decoder.write_all(data),
+            Decoder::Deflate(ref mut decoder) => decoder.write_all(data),
+        }
+    }
+
+    fn try_finish(&mut self) -> io::Result<()> {
+        match *self {
+            Decoder::Gzip(ref mut decoder) => decoder.try_finish(),
+            Decoder::Deflate(ref mut decoder) => decoder.try_finish(),
+        }
+    }
+
+    /// The data decompressed since the last call.
+    fn take(&mut self) -> Vec<u8> {
+        let decoded = match *self {
+            Decoder::Gzip(ref mut decoder) => decoder.get_mut(),
+            Decoder::Deflate(ref mut decoder) => decoder.get_mut(),
+        };
+        mem::replace(decoded, Vec::new())
+    }
+}
+
+/// A redirect received while sending a request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Redirect {
+    /// The status of the redirect, such as `307 Temporary Redirect`.
+    pub status: StatusCode,
+    /// The URI the request was sent to.
+    pub from: String,
+    /// The `Location` the request was redirected to.
+    pub location: String,
+}
+
+impl fmt::Display for Redirect {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} from {} to {}", self.status, self.from, self.location)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
 /// An error produced when sending the request, such as a timeout error.
 pub struct HttpDispatchError {
     message: String,
+    redirects: Vec<Redirect>,
+    timed_out: bool,
 }
 
 impl HttpDispatchError {
     /// Construct a new HttpDispatchError for testing purposes
     pub fn new(message: String) -> HttpDispatchError {
-        HttpDispatchError { message }
+        HttpDispatchError {
+            message,
+            redirects: Vec::new(),
+            timed_out: false,
+        }
+    }
+
+    /// Construct a new HttpDispatchError for a request which ran out of time
+    pub fn timeout(message: String) -> HttpDispatchError {
+        HttpDispatchError {
+            timed_out: true,
+            ..HttpDispatchError::new(message)
+        }
+    }
+
+    /// Whether the request failed because it ran out of time, rather than on a connection or
+    /// protocol error.
+    pub fn is_timeout(&self) -> bool {
+        self.timed_out
+    }
+
+    /// The redirects received before the error, oldest first. When the error is a refused
+    /// redirect, it is the last one.
+    ///
+    /// These show where requests sent to a misconfigured endpoint ended up.
+    pub fn redirects(&self) -> &[Redirect] {
+        &self.redirects
+    }
+
+    fn with_redirects(mut self, redirects: &[Redirect]) -> HttpDispatchError {
+        self.redirects = redirects.to_vec();
+        self
     }
 }
 
@@ -173,22 +372,26 @@ impl Error for HttpDispatchError {
 
 impl fmt::Display for HttpDispatchError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.message)
+        write!(f, "{}", self.message)?;
+        for redirect in &self.redirects {
+            write!(f, " (redirected: {})", redirect)?;
+        }
+        Ok(())
     }
 }
 
 impl From<HyperError> for HttpDispatchError {
     fn from(err: HyperError) -> HttpDispatchError {
-        HttpDispatchError {
-            message: err.to_string(),
-        }
+        HttpDispatchError::new(err.to_string())
     }
 }
 
 impl From<IoError> for HttpDispatchError {
     fn from(err: IoError) -> HttpDispatchError {
-        HttpDispatchError {
-            message: err.to_string(),
+        if err.kind() == io::ErrorKind::TimedOut {
+            HttpDispatchError::timeout(err.to_string())
+        } else {
+            HttpDispatchError::new(err.to_string())
         }
     }
 }
@@ -219,8 +422,8 @@ impl<D: DispatchSignedRequest> DispatchSignedRequest for Arc<D> {
 pub struct HttpClientFuture(ClientFutureInner);
 
 enum ClientFutureInner {
-    Hyper(HyperResponseFuture),
-    HyperWithTimeout(Timeout<HyperResponseFuture>),
+    Hyper(RedirectingFuture),
+    HyperWithTimeout(Timeout<RedirectingFuture>),
     Error(String),
 }
 
@@ -230,80 +433,306 @@ impl Future for HttpClientFuture {
 
     fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
         match self.0 {
-            ClientFutureInner::Error(ref message) => Err(HttpDispatchError {
-                message: message.clone(),
-            }),
-            ClientFutureInner::Hyper(ref mut hyper_future) => {
-                Ok(hyper_future.poll()?.map(HttpResponse::from_hyper))
-            }
+            ClientFutureInner::Error(ref message) => Err(HttpDispatchError::new(message.clone())),
+            ClientFutureInner::Hyper(ref mut hyper_future) => hyper_future.poll(),
             ClientFutureInner::HyperWithTimeout(ref mut deadline_future) => {
                 match deadline_future.poll() {
                     Err(deadline_err) => {
                         if deadline_err.is_elapsed() {
-                            Err(HttpDispatchError {
-                                message: "Request timed out".into(),
-                            })
+                            Err(HttpDispatchError::timeout("Request timed out".into()))
                         } else if deadline_err.is_inner() {
-                            Err(deadline_err.into_inner().unwrap().into())
+                            Err(deadline_err.into_inner().unwrap())
                         } else {
-                            Err(HttpDispatchError {
-                                message: format!("deadline error: {}", deadline_err),
-                            })
+                            Err(HttpDispatchError::new(format!(
+                                "deadline error: {}",
+                                deadline_err
+                            )))
                         }
                     }
-                    Ok(Async::NotReady) => Ok(Async::NotReady),
-                    Ok(Async::Ready(hyper_res)) => {
-                        Ok(Async::Ready(HttpResponse::from_hyper(hyper_res)))
-                    }
+                    Ok(poll) => Ok(poll),
                 }
             }
         }
     }
 }
 
-/// Http client for use with AWS services.
-pub struct HttpClient<C = HttpsConnector<HttpConnector>> {
-    inner: HyperClient<C, Body>,
+/// How `HttpClient` handles redirects naming a `Location`.
+///
+/// Redirects without a `Location` header, such as S3's `PermanentRedirect` errors naming the
+/// region of a bucket, and `304 Not Modified` responses are always returned as responses.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RedirectPolicy {
+    /// Return redirects as responses, for the service to handle.
+    None,
+    /// Send `GET` requests again to the `Location` of redirects on the same scheme and host, at
+    /// most `max_redirects` times. Any other redirect is an `HttpDispatchError`.
+    SameHostGet {
+        /// The number of redirects followed before giving up.
+        max_redirects: usize,
+    },
 }
 
-impl HttpClient {
-    /// Create a tls-enabled http client.
-    pub fn new() -> Result<Self, TlsError> {
-        #[cfg(feature = "native-tls")]
-        let connector = match HttpsConnector::new(4) {
-            Ok(connector) => connector,
-            Err(tls_error) => {
-                return Err(TlsError {
-                    message: format!("Couldn't create NativeTlsClient: {}", tls_error),
-                })
+impl Default for RedirectPolicy {
+    /// Follows up to 5 redirects of `GET` requests on the same host.
+    fn default() -> RedirectPolicy {
+        RedirectPolicy::SameHostGet { max_redirects: 5 }
+    }
+}
+
+type Resend = Box<dyn Fn(Uri) -> HyperResponseFuture + Send>;
+
+/// Sends a request, following its redirects as allowed by a `RedirectPolicy`.
+struct RedirectingFuture {
+    response: HyperResponseFuture,
+    policy: RedirectPolicy,
+    method: Method,
+    uri: Uri,
+    /// Sends the request again to another URI. Only `GET` requests can be.
+    resend: Option<Resend>,
+    redirects: Vec<Redirect>,
+    /// Whether the request accepts compressed responses, which are decompressed.
+    decompress: bool,
+}
+
+impl RedirectingFuture {
+    fn error(&self, message: String) -> HttpDispatchError {
+        HttpDispatchError::new(message).with_redirects(&self.redirects)
+    }
+
+    fn to_response(&self, response: HyperResponse<Body>) -> HttpResponse {
+        let response = HttpResponse::from_hyper(response);
+        if self.decompress {
+            response.decompressed()
+        } else {
+            response
+        }
+    }
+}
+
+impl Future for RedirectingFuture {
+    type Item = HttpResponse;
+    type Error = HttpDispatchError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        loop {
+            let response = match self.response.poll() {
+                Ok(Async::Ready(response)) => response,
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                Err(err) => return Err(self.error(err.to_string())),
+            };
+            let status = response.status();
+            // requests never ask for an informational response, such as `100 Continue` or
+            // `101 Switching Protocols`, and one is never the final response
+            if status.is_informational() {
+                return Err(self.error(format!("unexpected informational response: {}", status)));
             }
-        };
+            let location = match response
+                .headers()
+                .get(LOCATION)
+                .and_then(|location| location.to_str().ok())
+            {
+                Some(location) if is_followable_redirect(status) => location.to_owned(),
+                _ => return Ok(Async::Ready(self.to_response(response))),
+            };
+            let target = match redirect_target(
+                self.policy,
+                &self.method,
+                &self.uri,
+                &location,
+                self.redirects.len(),
+            ) {
+                Ok(Some(target)) => target,
+                Ok(None) => return Ok(Async::Ready(self.to_response(response))),
+                Err(message) => {
+                    self.redirects.push(Redirect {
+                        status,
+                        from: self.uri.to_string(),
+                        location,
+                    });
+                    return Err(self.error(message));
+                }
+            };
+            let resend = match self.resend {
+                Some(ref resend) => resend,
+                None => return Ok(Async::Ready(self.to_response(response))),
+            };
+            self.redirects.push(Redirect {
+                status,
+                from: self.uri.to_string(),
+                location,
+            });
+            self.response = resend(target.clone());
+            self.uri = target;
+        }
+    }
+}
+
+/// Whether `status` asks for the request to be sent to its `Location`.
+fn is_followable_redirect(status: StatusCode) -> bool {
+    status.is_redirection() && status != StatusCode::NOT_MODIFIED
+}
 
-        #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::new(4);
+/// Where a request redirected to `location` is sent again, if it is followed. An error when the
+/// policy refuses the redirect.
+fn redirect_target(
+    policy: RedirectPolicy,
+    method: &Method,
+    uri: &Uri,
+    location: &str,
+    followed: usize,
+) -> Result<Option<Uri>, String> {
+    let max_redirects = match policy {
+        RedirectPolicy::None => return Ok(None),
+        RedirectPolicy::SameHostGet { max_redirects } => max_redirects,
+    };
+    if *method != Method::GET {
+        return Err(format!(
+            "refusing to follow the redirect of a {} request",
+            method
+        ));
+    }
+    let target = match resolve_location(uri, location) {
+        Some(target) => target,
+        None => return Err(format!("invalid redirect location: {:?}", location)),
+    };
+    if target.scheme_part() != uri.scheme_part() || target.authority_part() != uri.authority_part()
+    {
+        return Err("refusing to follow a redirect to another host".to_owned());
+    }
+    if followed >= max_redirects {
+        return Err(format!(
+            "too many redirects (at most {} are followed)",
+            max_redirects
+        ));
+    }
+    Ok(Some(target))
+}
 
-        Ok(Self::from_connector(connector))
+/// Resolves the absolute or path-absolute URI of a `Location` header against `base`.
+fn resolve_location(base: &Uri, location: &str) -> Option<Uri> {
+    let mut parts = location.parse::<Uri>().ok()?.into_parts();
+    if parts.scheme.is_none() {
+        if !location.starts_with('/') {
+            return None;
+        }
+        let base = base.clone().into_parts();
+        parts.scheme = base.scheme;
+        parts.authority = base.authority;
     }
+    Uri::from_parts(parts).ok()
+}
+
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+type TransportConnector = HttpsConnector<HttpConnector>;
+// with the `http-only` feature and no TLS backend, only plain `http://` URIs can be connected to
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+type TransportConnector = HttpConnector;
+
+/// The connector of `HttpClient::new`, which also connects to Unix domain sockets on Unix.
+#[cfg(unix)]
+pub type DefaultConnector = UnixSocketConnector<TransportConnector>;
+/// The connector of `HttpClient::new`.
+#[cfg(not(unix))]
+pub type DefaultConnector = TransportConnector;
+
+/// Http client for use with AWS services.
+///
+/// The clients created by `new` and `new_with_config` send the requests of regions with a
+/// custom endpoint of the form `unix:///path/to/socket` to that Unix domain socket.
+pub struct HttpClient<C = DefaultConnector> {
+    inner: Arc<HyperClient<C, Body>>,
+    redirect_policy: RedirectPolicy,
+    decompress_responses: bool,
+}
+
+/// Creates the connector of `HttpClient::new`, e.g. to build a hyper client for connections
+/// rusoto doesn't make itself, such as WebSocket upgrades.
+pub fn default_connector() -> Result<DefaultConnector, TlsError> {
+    #[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+    let connector = match HttpsConnector::new(4) {
+        Ok(connector) => connector,
+        Err(tls_error) => {
+            return Err(TlsError {
+                message: format!("Couldn't create NativeTlsClient: {}", tls_error),
+            })
+        }
+    };
+
+    #[cfg(feature = "rustls")]
+    let connector = HttpsConnector::new(4);
 
+    #[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+    let connector = HttpConnector::new(4);
+
+    #[cfg(unix)]
+    let connector = UnixSocketConnector::wrap(connector);
+
+    Ok(connector)
+}
+
+impl HttpClient {
     /// Create a tls-enabled http client.
+    ///
+    /// With the `http-only` feature and neither TLS feature enabled, the client can only send
+    /// requests to custom `http://` endpoints, and requests to `https://` URIs fail.
+    pub fn new() -> Result<Self, TlsError> {
+        Ok(Self::from_connector(default_connector()?))
+    }
+
+    /// Create a tls-enabled http client, with extra configuration options.
+    ///
+    /// See `new` for the client built with the `http-only` feature.
     pub fn new_with_config(config: HttpConfig) -> Result<Self, TlsError> {
-        #[cfg(feature = "native-tls")]
-        let connector = match HttpsConnector::new(4) {
-            Ok(connector) => connector,
-            Err(tls_error) => {
-                return Err(TlsError {
-                    message: format!("Couldn't create NativeTlsClient: {}", tls_error),
-                })
-            }
-        };
+        Ok(Self::from_connector_with_config(
+            default_connector()?,
+            config,
+        ))
+    }
+}
 
-        #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::new(4);
+/// The connector of `HttpClient::new_with_proxy`.
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+pub type ProxiedConnector = HttpsConnector<ProxyConnector>;
+/// The connector of `HttpClient::new_with_proxy`.
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+pub type ProxiedConnector = ProxyConnector;
 
-        Ok(Self::from_connector_with_config(connector, config))
+impl HttpClient<ProxiedConnector> {
+    /// Create a tls-enabled http client sending its requests through `proxy`, with extra
+    /// configuration options.
+    ///
+    /// Connections to `https://` URIs are tunnelled through the proxy, so TLS is negotiated
+    /// with the endpoint itself. See the `proxy` module for details.
+    pub fn new_with_proxy(proxy: Proxy, config: HttpConfig) -> Result<Self, TlsError> {
+        Ok(Self::from_connector_with_config(
+            proxied_connector(proxy)?,
+            config,
+        ))
     }
 }
 
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+fn proxied_connector(proxy: Proxy) -> Result<ProxiedConnector, TlsError> {
+    let tls = native_tls_crate::TlsConnector::new().map_err(|tls_error| TlsError {
+        message: format!("Couldn't create NativeTlsClient: {}", tls_error),
+    })?;
+    Ok(HttpsConnector::from((ProxyConnector::new(proxy), tls)))
+}
+
+#[cfg(feature = "rustls")]
+fn proxied_connector(proxy: Proxy) -> Result<ProxiedConnector, TlsError> {
+    let mut config = rustls_crate::ClientConfig::new();
+    config
+        .root_store
+        .add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS);
+    Ok(HttpsConnector::from((ProxyConnector::new(proxy), config)))
+}
+
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+fn proxied_connector(proxy: Proxy) -> Result<ProxiedConnector, TlsError> {
+    Ok(ProxyConnector::new(proxy))
+}
+
 impl<C> HttpClient<C>
 where
     C: Connect,
@@ -312,7 +741,11 @@ where
     /// Allows for a custom connector to be used with the HttpClient
     pub fn from_connector(connector: C) -> Self {
         let inner = HyperClient::builder().build(connector);
-        HttpClient { inner }
+        HttpClient {
+            inner: Arc::new(inner),
+            redirect_policy: RedirectPolicy::default(),
+            decompress_responses: false,
+        }
     }
 
     /// Allows for a custom connector to be used with the HttpClient
@@ -322,21 +755,63 @@ where
         config
             .read_buf_size
             .map(|sz| builder.http1_read_buf_exact_size(sz));
+        builder.http2_only(config.http2_only);
         let inner = builder.build(connector);
 
-        HttpClient { inner }
+        HttpClient {
+            inner: Arc::new(inner),
+            redirect_policy: config.redirect_policy,
+            decompress_responses: config.decompress_responses,
+        }
     }
 
     /// Alows for a custom builder and connector to be used with the HttpClient
     pub fn from_builder(builder: HyperBuilder, connector: C) -> Self {
         let inner = builder.build(connector);
-        HttpClient { inner }
+        HttpClient {
+            inner: Arc::new(inner),
+            redirect_policy: RedirectPolicy::default(),
+            decompress_responses: false,
+        }
+    }
+
+    /// Allows for a pre-built hyper client to be used with the HttpClient, e.g. one whose
+    /// connector goes through a Unix socket or a proxy, or is shared with the rest of the
+    /// application.
+    ///
+    /// ```rust
+    /// use hyper::client::HttpConnector;
+    /// use rusoto_core::request::HttpClient;
+    ///
+    /// let mut connector = HttpConnector::new(4);
+    /// connector.set_nodelay(true);
+    /// let hyper_client = hyper::Client::builder().build(connector);
+    /// let client = HttpClient::from_hyper_client(hyper_client);
+    /// ```
+    pub fn from_hyper_client(client: HyperClient<C, Body>) -> Self {
+        Self::from_hyper_client_with_config(client, HttpConfig::new())
+    }
+
+    /// Allows for a pre-built hyper client to be used with the HttpClient
+    /// with extra configuration options.
+    ///
+    /// Only the redirect and decompression options of `config` apply: the read buffer size
+    /// and HTTP/2 options are settings of the hyper client, which is already built.
+    pub fn from_hyper_client_with_config(client: HyperClient<C, Body>, config: HttpConfig) -> Self {
+        HttpClient {
+            inner: Arc::new(client),
+            redirect_policy: config.redirect_policy,
+            decompress_responses: config.decompress_responses,
+        }
     }
 }
 
 /// Configuration options for the HTTP Client
 pub struct HttpConfig {
     read_buf_size: Option<usize>,
+    http2_only: bool,
+    redirect_policy: RedirectPolicy,
+    decompress_responses: bool,
 }
 
 impl HttpConfig {
@@ -344,6 +819,9 @@ impl HttpConfig {
     pub fn new() -> HttpConfig {
         HttpConfig {
             read_buf_size: None,
+            http2_only: false,
+            redirect_policy: RedirectPolicy::default(),
+            decompress_responses: false,
         }
     }
     /// Sets the size of the read buffer for inbound data
@@ -352,6 +830,26 @@ impl HttpConfig {
     pub fn read_buf_size(&mut self, sz: usize) {
         self.read_buf_size = Some(sz);
     }
+    /// Sets whether the client only speaks HTTP/2.
+    /// Required by operations which stream a request body while the
+    /// response is being received, such as Transcribe Streaming's
+    /// `StartStreamTranscription`.
+    pub fn http2_only(&mut self, enabled: bool) {
+        self.http2_only = enabled;
+    }
+    /// Sets how redirects are handled. By default, `GET` requests follow up to 5 redirects on
+    /// the same host, and other redirects naming a `Location` are errors.
+    pub fn redirect_policy(&mut self, policy: RedirectPolicy) {
+        self.redirect_policy = policy;
+    }
+    /// Sets whether requests accept responses compressed with gzip or deflate, which are
+    /// decompressed as they are received. Disabled by default.
+    /// The `Accept-Encoding` header is added once requests are signed, so it isn't part of
+    /// their signature, and requests naming their own `Accept-Encoding` are left as they are.
+    /// Objects stored compressed in S3 are decompressed too.
+    pub fn decompress_responses(&mut self, enabled: bool) {
+        self.decompress_responses = enabled;
+    }
 }
 
 impl Default for HttpConfig {
@@ -388,12 +886,39 @@ where
             );
         }
 
+        // added after signing, so proxies rewriting it don't invalidate the signature
+        let decompress = self.decompress_responses && !req.headers().contains_key(ACCEPT_ENCODING);
+        if decompress {
+            req.headers_mut()
+                .insert(ACCEPT_ENCODING, HeaderValue::from_static("gzip, deflate"));
+        }
+
+        let resend = if *req.method() == Method::GET && self.redirect_policy != RedirectPolicy::None
+        {
+            let client = self.inner.clone();
+            let headers = req.headers().clone();
+            Some(Box::new(move |uri| {
+                let mut request = Request::new(Body::empty());
+                *request.uri_mut() = uri;
+                *request.headers_mut() = headers.clone();
+                client.request(request)
+            }) as Resend)
+        } else {
+            None
+        };
+        let future = RedirectingFuture {
+            policy: self.redirect_policy,
+            method: req.method().clone(),
+            uri: req.uri().clone(),
+            response: self.inner.request(req),
+            resend,
+            redirects: Vec::new(),
+            decompress,
+        };
+
         let inner = match timeout {
-            None => ClientFutureInner::Hyper(self.inner.request(req)),
-            Some(duration) => {
-                let future = Timeout::new(self.inner.request(req), duration);
-                ClientFutureInner::HyperWithTimeout(future)
-            }
+            None => ClientFutureInner::Hyper(future),
+            Some(duration) => ClientFutureInner::HyperWithTimeout(Timeout::new(future, duration)),
         };
 
         HttpClientFuture(inner)
@@ -431,6 +956,18 @@ mod tests {
         is_send_and_sync::<HttpClient>();
     }
 
+    #[test]
+    fn http_client_from_hyper_client_keeps_config() {
+        let hyper_client = HyperClient::builder().build(HttpConnector::new(1));
+        let mut config = HttpConfig::new();
+        config.decompress_responses(true);
+        let client = HttpClient::from_hyper_client_with_config(hyper_client, config);
+        assert!(client.decompress_responses);
+
+        let hyper_client = HyperClient::builder().build(HttpConnector::new(1));
+        assert!(!HttpClient::from_hyper_client(hyper_client).decompress_responses);
+    }
+
     #[test]
     fn http_client_future_is_send() {
         fn is_send<T: Send>() {}
@@ -438,6 +975,37 @@ mod tests {
         is_send::<HttpClientFuture>();
     }
 
+    fn response(chunks: Vec<Bytes>) -> HttpResponse {
+        let body = ::futures::stream::iter_ok(chunks);
+        HttpResponse {
+            status: StatusCode::OK,
+            body: ByteStream::new(body),
+            headers: HeaderMap::default(),
+        }
+    }
+
+    #[test]
+    fn buffering_a_single_chunk_does_not_copy() {
+        let chunk = Bytes::from(vec![1u8; 4096]);
+        let buffered = response(vec![chunk.clone()]).buffer().wait().unwrap();
+        assert_eq!(buffered.body.as_ptr(), chunk.as_ptr());
+    }
+
+    #[test]
+    fn buffering_concatenates_chunks() {
+        let mut chunked = response(vec![
+            Bytes::from_static(b"hello"),
+            Bytes::from_static(b" "),
+            Bytes::from_static(b"world"),
+        ]);
+        chunked.headers.insert("content-length", "11".to_owned());
+        let buffered = chunked.buffer().wait().unwrap();
+        assert_eq!(buffered.body, Bytes::from_static(b"hello world"));
+
+        let empty = response(vec![]).buffer().wait().unwrap();
+        assert!(empty.body.is_empty());
+    }
+
     #[test]
     fn custom_region_http() {
         let a_region = Region::Custom {
@@ -482,10 +1050,151 @@ mod tests {
         assert_eq!("localhost", request.hostname());
     }
 
+    #[test]
+    fn custom_region_unix_socket() {
+        let a_region = Region::Custom {
+            endpoint: "unix:///tmp/s.sock".to_owned(),
+            name: "eu-west-3".to_owned(),
+        };
+        let request = SignedRequest::new("GET", "sqs", &a_region, "/queues");
+        assert_eq!("unix", request.scheme());
+        assert_eq!("localhost", request.hostname());
+        assert_eq!(Some("/tmp/s.sock"), request.unix_socket());
+
+        let request: Request<Body> = request.try_into().unwrap();
+        assert_eq!(
+            "unix://2f746d702f732e736f636b/queues",
+            request.uri().to_string()
+        );
+    }
+
     #[test]
     fn from_io_error_preserves_error_message() {
         let io_error = ::std::io::Error::new(::std::io::ErrorKind::Other, "my error message");
         let error = HttpDispatchError::from(io_error);
-        assert_eq!(error.to_string(), "my error message")
+        assert_eq!(error.to_string(), "my error message");
+        assert!(!error.is_timeout());
+    }
+
+    #[test]
+    fn from_timed_out_io_error_is_a_timeout() {
+        let io_error = ::std::io::Error::new(::std::io::ErrorKind::TimedOut, "connect timed out");
+        let error = HttpDispatchError::from(io_error);
+        assert_eq!(error.to_string(), "connect timed out");
+        assert!(error.is_timeout());
+    }
+
+    fn redirect(location: &str, method: Method, followed: usize) -> Result<Option<Uri>, String> {
+        let uri = "https://s3.amazonaws.com/bucket/key"
+            .parse::<Uri>()
+            .unwrap();
+        redirect_target(RedirectPolicy::default(), &method, &uri, location, followed)
+    }
+
+    #[test]
+    fn follows_get_redirects_on_the_same_host() {
+        assert_eq!(
+            redirect("/bucket/other", Method::GET, 0),
+            Ok(Some(
+                "https://s3.amazonaws.com/bucket/other".parse().unwrap()
+            ))
+        );
+        assert_eq!(
+            redirect("https://s3.amazonaws.com/bucket/other?x=1", Method::GET, 4),
+            Ok(Some(
+                "https://s3.amazonaws.com/bucket/other?x=1".parse().unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn refuses_other_redirects() {
+        assert!(redirect("/bucket/other", Method::PUT, 0).is_err());
+        assert!(redirect("https://example.com/bucket/key", Method::GET, 0).is_err());
+        assert!(redirect("http://s3.amazonaws.com/bucket/key", Method::GET, 0).is_err());
+        assert!(redirect("relative", Method::GET, 0).is_err());
+        assert!(redirect("/bucket/other", Method::GET, 5).is_err());
+
+        let uri = "https://s3.amazonaws.com/bucket/key"
+            .parse::<Uri>()
+            .unwrap();
+        assert_eq!(
+            redirect_target(RedirectPolicy::None, &Method::PUT, &uri, "/other", 0),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn only_redirects_with_a_location_are_followed() {
+        assert!(is_followable_redirect(StatusCode::TEMPORARY_REDIRECT));
+        assert!(is_followable_redirect(StatusCode::MOVED_PERMANENTLY));
+        assert!(!is_followable_redirect(StatusCode::NOT_MODIFIED));
+        assert!(!is_followable_redirect(StatusCode::OK));
+    }
+
+    #[test]
+    fn dispatch_errors_show_their_redirects() {
+        let error = HttpDispatchError::new("refusing to follow a redirect to another host".into())
+            .with_redirects(&[Redirect {
+                status: StatusCode::TEMPORARY_REDIRECT,
+                from: "https://s3.amazonaws.com/bucket".to_owned(),
+                location: "https://example.com/bucket".to_owned(),
+            }]);
+        assert_eq!(error.redirects().len(), 1);
+        assert_eq!(
+            error.to_string(),
+            "refusing to follow a redirect to another host (redirected: 307 Temporary Redirect \
+             from https://s3.amazonaws.com/bucket to https://example.com/bucket)"
+        );
+    }
+
+    fn compressed(encoding: &str, chunks: Vec<Bytes>) -> HttpResponse {
+        let mut response = response(chunks);
+        response
+            .headers
+            .insert("content-encoding", encoding.to_owned());
+        response.headers.insert("content-length", "42".to_owned());
+        response.decompressed()
+    }
+
+    #[test]
+    fn decompresses_gzip_and_deflate_bodies() {
+        use flate2::write::{GzEncoder, ZlibEncoder};
+        use flate2::Compression;
+
+        let json = br#"{"MetricDataResults": [{"Id": "m1", "Values": [1.0, 2.0, 3.0]}]}"#;
+        let mut gzip = GzEncoder::new(Vec::new(), Compression::default());
+        gzip.write_all(json).unwrap();
+        let gzip = Bytes::from(gzip.finish().unwrap());
+        let mut deflate = ZlibEncoder::new(Vec::new(), Compression::default());
+        deflate.write_all(json).unwrap();
+        let deflate = Bytes::from(deflate.finish().unwrap());
+
+        // split across chunks, as received
+        let chunks = vec![gzip.slice_to(5), gzip.slice_from(5)];
+        let buffered = compressed("gzip", chunks).buffer().wait().unwrap();
+        assert_eq!(&buffered.body[..], &json[..]);
+        assert!(!buffered.headers.contains_key("content-encoding"));
+        assert!(!buffered.headers.contains_key("content-length"));
+
+        let buffered = compressed("deflate", vec![deflate])
+            .buffer()
+            .wait()
+            .unwrap();
+        assert_eq!(&buffered.body[..], &json[..]);
+    }
+
+    #[test]
+    fn leaves_other_bodies_as_they_are() {
+        let buffered = compressed("identity", vec![Bytes::from_static(b"{}")])
+            .buffer()
+            .wait()
+            .unwrap();
+        assert_eq!(&buffered.body[..], b"{}");
+        assert_eq!(buffered.headers["content-encoding"], "identity");
+
+        // such as the empty bodies of HEAD responses
+        let buffered = compressed("gzip", vec![]).buffer().wait().unwrap();
+        assert!(buffered.body.is_empty());
     }
 }
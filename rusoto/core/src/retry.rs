@@ -0,0 +1,694 @@
+//! Retrying failed requests.
+//!
+//! Retries are configured through the environment, as in the other AWS SDKs:
+//!
+//! * `AWS_RETRY_MODE` selects `legacy` (the default), `standard` or `adaptive` retries.
+//! * `AWS_MAX_ATTEMPTS` sets how many times a request is attempted in total.
+//!
+//! In `legacy` mode requests are sent once unless `AWS_MAX_ATTEMPTS` says otherwise. The
+//! `standard` mode attempts requests up to 3 times, backing off exponentially between attempts
+//! and drawing every retry from a per-client quota, so that an outage doesn't multiply the
+//! load on a service. The `adaptive` mode adds a client side rate limiter on top: throttling
+//! responses lower the rate at which the client sends requests, which recovers gradually once
+//! requests succeed again. This keeps hot loops against APIs such as DynamoDB or EC2 from
+//! retrying straight into more throttling.
+//!
+//! Requests with a streaming body can't be replayed and are always sent once.
+//!
+//! Error responses are retried by their error code, or by their status when it doesn't name a
+//! retryable error. The codes every AWS SDK retries are retried for every service. Generated
+//! clients add the codes of their own service, such as EC2's `RequestLimitExceeded`, with
+//! `Client::with_retry_codes`.
+//!
+//! The timeout set with `RusotoFuture::with_timeout` applies to each attempt, and attempts which
+//! time out are retried. `RusotoFuture::with_operation_timeout` limits the whole call instead,
+//! including the backoff between attempts, as the attempt and API call timeouts of other SDKs.
+
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use http::{HeaderMap, StatusCode};
+
+use crate::config::ConfigSource;
+use crate::credential::{Environment, SystemEnvironment};
+use crate::signature::{SignedRequest, SignedRequestPayload};
+
+/// How failed requests are retried.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RetryMode {
+    /// Requests are attempted `AWS_MAX_ATTEMPTS` times, once by default.
+    Legacy,
+    /// Requests are retried with exponential backoff, within a per-client retry quota.
+    Standard,
+    /// Like `Standard`, with the sending rate limited after throttling responses.
+    Adaptive,
+}
+
+impl RetryMode {
+    fn default_max_attempts(self) -> u32 {
+        match self {
+            RetryMode::Legacy => 1,
+            RetryMode::Standard | RetryMode::Adaptive => 3,
+        }
+    }
+}
+
+impl FromStr for RetryMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<RetryMode, String> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "legacy" => Ok(RetryMode::Legacy),
+            "standard" => Ok(RetryMode::Standard),
+            "adaptive" => Ok(RetryMode::Adaptive),
+            other => Err(format!("Not a valid retry mode: {}", other)),
+        }
+    }
+}
+
+impl fmt::Display for RetryMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            RetryMode::Legacy => "legacy",
+            RetryMode::Standard => "standard",
+            RetryMode::Adaptive => "adaptive",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The retry settings of a client.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryConfig {
+    /// How failed requests are retried
+    pub mode: RetryMode,
+    /// Number of times a request is attempted, including the first attempt
+    pub max_attempts: u32,
+}
+
+impl RetryConfig {
+    /// Reads the retry settings from `AWS_RETRY_MODE` and `AWS_MAX_ATTEMPTS` in the environment
+    /// of the current process.
+    ///
+    /// Invalid values are ignored.
+    pub fn from_env() -> RetryConfig {
+        RetryConfig::from_environment(&SystemEnvironment)
+    }
+
+    /// Reads the retry settings from `AWS_RETRY_MODE` and `AWS_MAX_ATTEMPTS` in `environment`.
+    ///
+    /// Invalid values are ignored.
+    pub fn from_environment(environment: &dyn Environment) -> RetryConfig {
+        let (config, _, _) = RetryConfig::from_env_with_sources(environment);
+        config
+    }
+
+    pub(crate) fn from_env_with_sources(
+        environment: &dyn Environment,
+    ) -> (RetryConfig, ConfigSource, ConfigSource) {
+        let (mode, mode_source) = match environment.var("AWS_RETRY_MODE").map(|v| v.parse()) {
+            Some(Ok(mode)) => (mode, ConfigSource::Environment("AWS_RETRY_MODE".to_owned())),
+            _ => (RetryMode::Legacy, ConfigSource::Default),
+        };
+        let max_attempts = environment
+            .var("AWS_MAX_ATTEMPTS")
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .filter(|attempts| *attempts > 0);
+        let (max_attempts, attempts_source) = match max_attempts {
+            Some(attempts) => (
+                attempts,
+                ConfigSource::Environment("AWS_MAX_ATTEMPTS".to_owned()),
+            ),
+            None => (mode.default_max_attempts(), ConfigSource::Default),
+        };
+        (
+            RetryConfig { mode, max_attempts },
+            mode_source,
+            attempts_source,
+        )
+    }
+}
+
+// error codes and statuses the AWS SDKs retry on
+const THROTTLING_CODES: &[&str] = &[
+    "Throttling",
+    "ThrottlingException",
+    "ThrottledException",
+    "RequestThrottledException",
+    "TooManyRequestsException",
+    "ProvisionedThroughputExceededException",
+    "TransactionInProgressException",
+    "RequestLimitExceeded",
+    "BandwidthLimitExceeded",
+    "LimitExceededException",
+    "RequestThrottled",
+    "SlowDown",
+    "PriorRequestNotComplete",
+    "EC2ThrottledException",
+];
+const TRANSIENT_CODES: &[&str] = &["RequestTimeout", "RequestTimeoutException"];
+const TRANSIENT_STATUSES: &[u16] = &[500, 502, 503, 504];
+
+/// The error codes a service retries in addition to the ones retried for every service.
+///
+/// Generated crates define theirs as `RETRY_CODES`, from the errors their botocore model marks
+/// as retryable or throttling and from botocore's `retry.json`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ServiceRetryCodes {
+    /// Codes of errors asking the client to slow down.
+    pub throttling: &'static [&'static str],
+    /// Codes of errors which are likely to go away when the request is sent again.
+    pub transient: &'static [&'static str],
+}
+
+impl ServiceRetryCodes {
+    /// Whether `code` is the code of a throttling error.
+    pub fn is_throttle(&self, code: &str) -> bool {
+        THROTTLING_CODES.contains(&code) || self.throttling.contains(&code)
+    }
+
+    /// Whether an error with `code` is retried, either as a throttling or a transient error.
+    pub fn is_retryable(&self, code: &str) -> bool {
+        self.is_throttle(code) || TRANSIENT_CODES.contains(&code) || self.transient.contains(&code)
+    }
+}
+
+/// Why a request failed, if it's worth retrying.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum RetryKind {
+    /// The service asked the client to slow down.
+    Throttling,
+    /// The request failed for a reason which is likely to go away.
+    Transient,
+}
+
+/// Classifies an error response from its status and error code.
+pub(crate) fn classify_response(
+    status: StatusCode,
+    code: Option<&str>,
+    codes: &ServiceRetryCodes,
+) -> Option<RetryKind> {
+    if let Some(code) = code {
+        if codes.is_throttle(code) {
+            return Some(RetryKind::Throttling);
+        }
+        if codes.is_retryable(code) {
+            return Some(RetryKind::Transient);
+        }
+    }
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        Some(RetryKind::Throttling)
+    } else if TRANSIENT_STATUSES.contains(&status.as_u16()) {
+        Some(RetryKind::Transient)
+    } else {
+        None
+    }
+}
+
+/// Extracts the AWS error code of an error response.
+///
+/// JSON protocols return it in the `x-amzn-ErrorType` header or a `__type` field, query and
+/// REST-XML protocols in a `<Code>` element.
+pub(crate) fn error_code(headers: &HeaderMap<String>, body: &[u8]) -> Option<String> {
+    if let Some(error_type) = headers.get("x-amzn-errortype") {
+        return error_type.split(':').next().map(str::to_owned);
+    }
+    let body = String::from_utf8_lossy(body);
+    if let Some(start) = body.find("<Code>") {
+        let code = &body[start + "<Code>".len()..];
+        return code
+            .find("</Code>")
+            .map(|end| code[..end].trim().to_owned());
+    }
+    ["\"__type\"", "\"code\"", "\"Code\""]
+        .iter()
+        .filter_map(|key| json_string_after(&body, key))
+        .next()
+        // `__type` may be qualified, e.g. `com.amazonaws.dynamodb.v20120810#ThrottlingException`
+        .map(|code| code.rsplit('#').next().unwrap_or_default().to_owned())
+}
+
+fn json_string_after<'a>(body: &'a str, key: &str) -> Option<&'a str> {
+    let rest = body[body.find(key)? + key.len()..].trim_start();
+    if !rest.starts_with(':') {
+        return None;
+    }
+    let rest = rest[1..].trim_start();
+    if !rest.starts_with('"') {
+        return None;
+    }
+    rest[1..].find('"').map(|end| &rest[1..=end])
+}
+
+/// Copies a request so it can be sent again, unless its body is a stream.
+pub(crate) fn try_clone_request(request: &SignedRequest) -> Option<SignedRequest> {
+    let payload = match request.payload {
+        None => None,
+        Some(SignedRequestPayload::Buffer(ref body)) => {
+            Some(SignedRequestPayload::Buffer(body.clone()))
+        }
+        Some(SignedRequestPayload::Stream(_)) => return None,
+    };
+    Some(SignedRequest {
+        method: request.method.clone(),
+        service: request.service.clone(),
+        region: request.region.clone(),
+        path: request.path.clone(),
+        headers: request.headers.clone(),
+        params: request.params.clone(),
+        scheme: request.scheme.clone(),
+        hostname: request.hostname.clone(),
+        payload,
+        canonical_query_string: request.canonical_query_string.clone(),
+        canonical_uri: request.canonical_uri.clone(),
+    })
+}
+
+const MAX_BACKOFF: Duration = Duration::from_secs(20);
+
+// retry quota of the standard mode
+const INITIAL_RETRY_TOKENS: u32 = 500;
+const RETRY_COST: u32 = 5;
+const TIMEOUT_RETRY_COST: u32 = 10;
+const NO_RETRY_INCREMENT: u32 = 1;
+
+/// The retry settings and state shared by the clones of a `Client`.
+pub(crate) struct RetryHandler {
+    config: RetryConfig,
+    retry_tokens: Mutex<u32>,
+    rate_limiter: Option<Mutex<RateLimiter>>,
+    started: Instant,
+}
+
+impl RetryHandler {
+    pub(crate) fn new(config: RetryConfig) -> RetryHandler {
+        let rate_limiter = if config.mode == RetryMode::Adaptive {
+            Some(Mutex::new(RateLimiter::new(0.0)))
+        } else {
+            None
+        };
+        RetryHandler {
+            config,
+            retry_tokens: Mutex::new(INITIAL_RETRY_TOKENS),
+            rate_limiter,
+            started: Instant::now(),
+        }
+    }
+
+    pub(crate) fn from_env(environment: &dyn Environment) -> RetryHandler {
+        RetryHandler::new(RetryConfig::from_environment(environment))
+    }
+
+    pub(crate) fn config(&self) -> &RetryConfig {
+        &self.config
+    }
+
+    /// Whether requests may be sent more than once.
+    pub(crate) fn retries_enabled(&self) -> bool {
+        self.config.max_attempts > 1
+    }
+
+    pub(crate) fn max_attempts(&self) -> u32 {
+        self.config.max_attempts
+    }
+
+    fn now(&self) -> f64 {
+        duration_secs(self.started.elapsed())
+    }
+
+    /// Takes a token from the rate limiter, returning how long to wait before sending.
+    pub(crate) fn acquire_send_token(&self) -> Duration {
+        match self.rate_limiter {
+            Some(ref limiter) => limiter.lock().unwrap().acquire(self.now()),
+            None => Duration::from_secs(0),
+        }
+    }
+
+    /// Records the outcome of an attempt with the rate limiter.
+    pub(crate) fn record_attempt(&self, kind: Option<RetryKind>) {
+        if let Some(ref limiter) = self.rate_limiter {
+            let throttled = kind == Some(RetryKind::Throttling);
+            limiter.lock().unwrap().update(throttled, self.now());
+        }
+    }
+
+    /// Decides whether to retry after the `attempt`th attempt failed, returning how long to
+    /// back off and the retry tokens spent, or `None` to give up.
+    pub(crate) fn retry_after(&self, attempt: u32, timed_out: bool) -> Option<(Duration, u32)> {
+        if attempt >= self.config.max_attempts {
+            return None;
+        }
+        let cost = if self.config.mode == RetryMode::Legacy {
+            0
+        } else {
+            let cost = if timed_out {
+                TIMEOUT_RETRY_COST
+            } else {
+                RETRY_COST
+            };
+            let mut tokens = self.retry_tokens.lock().unwrap();
+            if *tokens < cost {
+                return None;
+            }
+            *tokens -= cost;
+            cost
+        };
+        Some((backoff(attempt, random_fraction()), cost))
+    }
+
+    /// Refunds the retry quota once a request succeeds.
+    pub(crate) fn record_success(&self, last_retry_cost: u32) {
+        if self.config.mode == RetryMode::Legacy {
+            return;
+        }
+        let refund = if last_retry_cost > 0 {
+            last_retry_cost
+        } else {
+            NO_RETRY_INCREMENT
+        };
+        let mut tokens = self.retry_tokens.lock().unwrap();
+        *tokens = (*tokens + refund).min(INITIAL_RETRY_TOKENS);
+    }
+}
+
+/// Exponential backoff with full jitter: a random delay below `2^(attempt - 1)` seconds.
+fn backoff(attempt: u32, jitter: f64) -> Duration {
+    let ceiling = 2f64.powi(attempt.saturating_sub(1).min(16) as i32);
+    let delay = jitter * ceiling;
+    if delay >= duration_secs(MAX_BACKOFF) {
+        MAX_BACKOFF
+    } else {
+        Duration::from_millis((delay * 1000.0) as u64)
+    }
+}
+
+/// A random number in `[0, 1)`, good enough to spread retries out.
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+fn duration_secs(duration: Duration) -> f64 {
+    duration.as_secs() as f64 + f64::from(duration.subsec_nanos()) / 1e9
+}
+
+// constants of the CUBIC algorithm used by the adaptive mode
+const SMOOTH: f64 = 0.8;
+const BETA: f64 = 0.7;
+const SCALE_CONSTANT: f64 = 0.4;
+const MIN_FILL_RATE: f64 = 0.5;
+const MIN_CAPACITY: f64 = 1.0;
+
+/// The client side rate limiter of the adaptive retry mode.
+///
+/// A token bucket which stays disabled until the first throttling response. From then on its
+/// fill rate follows a CUBIC curve: cut down on every throttling response, and grown back
+/// towards the rate at which the client was throttled, and beyond, as requests succeed. Times
+/// are in seconds since the client was created.
+struct RateLimiter {
+    enabled: bool,
+    fill_rate: f64,
+    max_capacity: f64,
+    current_capacity: f64,
+    last_timestamp: Option<f64>,
+    measured_tx_rate: f64,
+    last_tx_rate_bucket: f64,
+    request_count: u64,
+    last_max_rate: f64,
+    last_throttle_time: f64,
+    time_window: f64,
+}
+
+impl RateLimiter {
+    fn new(now: f64) -> RateLimiter {
+        RateLimiter {
+            enabled: false,
+            fill_rate: 0.0,
+            max_capacity: 0.0,
+            current_capacity: 0.0,
+            last_timestamp: None,
+            measured_tx_rate: 0.0,
+            last_tx_rate_bucket: now.floor(),
+            request_count: 0,
+            last_max_rate: 0.0,
+            last_throttle_time: now,
+            time_window: 0.0,
+        }
+    }
+
+    /// Takes a token, returning how long the caller has to wait for it.
+    ///
+    /// The capacity may go negative, so concurrent callers queue up behind each other.
+    fn acquire(&mut self, now: f64) -> Duration {
+        if !self.enabled {
+            return Duration::from_secs(0);
+        }
+        self.refill(now);
+        let wait = if self.current_capacity >= 1.0 {
+            0.0
+        } else {
+            (1.0 - self.current_capacity) / self.fill_rate
+        };
+        self.current_capacity -= 1.0;
+        Duration::from_millis((wait * 1000.0).ceil() as u64)
+    }
+
+    fn refill(&mut self, now: f64) {
+        if let Some(last_timestamp) = self.last_timestamp {
+            let fill_amount = (now - last_timestamp) * self.fill_rate;
+            self.current_capacity = (self.current_capacity + fill_amount).min(self.max_capacity);
+        }
+        self.last_timestamp = Some(now);
+    }
+
+    fn update_rate(&mut self, new_rate: f64, now: f64) {
+        self.refill(now);
+        self.fill_rate = new_rate.max(MIN_FILL_RATE);
+        self.max_capacity = new_rate.max(MIN_CAPACITY);
+        self.current_capacity = self.current_capacity.min(self.max_capacity);
+    }
+
+    fn update_measured_rate(&mut self, now: f64) {
+        let time_bucket = (now * 2.0).floor() / 2.0;
+        self.request_count += 1;
+        if time_bucket > self.last_tx_rate_bucket {
+            let current_rate = self.request_count as f64 / (time_bucket - self.last_tx_rate_bucket);
+            self.measured_tx_rate = current_rate * SMOOTH + self.measured_tx_rate * (1.0 - SMOOTH);
+            self.request_count = 0;
+            self.last_tx_rate_bucket = time_bucket;
+        }
+    }
+
+    fn calculate_time_window(&mut self) {
+        self.time_window = (self.last_max_rate * (1.0 - BETA) / SCALE_CONSTANT).cbrt();
+    }
+
+    /// Adjusts the sending rate after a response was received.
+    fn update(&mut self, throttled: bool, now: f64) {
+        self.update_measured_rate(now);
+        let calculated_rate = if throttled {
+            let rate_to_use = if self.enabled {
+                self.measured_tx_rate.min(self.fill_rate)
+            } else {
+                self.measured_tx_rate
+            };
+            self.last_max_rate = rate_to_use;
+            self.calculate_time_window();
+            self.last_throttle_time = now;
+            self.enabled = true;
+            rate_to_use * BETA
+        } else {
+            self.calculate_time_window();
+            let elapsed = now - self.last_throttle_time;
+            SCALE_CONSTANT * (elapsed - self.time_window).powi(3) + self.last_max_rate
+        };
+        let new_rate = calculated_rate.min(2.0 * self.measured_tx_rate);
+        self.update_rate(new_rate, now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::credential::VirtualEnvironment;
+
+    #[test]
+    fn parses_retry_modes() {
+        assert_eq!("adaptive".parse(), Ok(RetryMode::Adaptive));
+        assert_eq!(" Standard ".parse(), Ok(RetryMode::Standard));
+        assert_eq!("legacy".parse(), Ok(RetryMode::Legacy));
+        assert!("sometimes".parse::<RetryMode>().is_err());
+    }
+
+    #[test]
+    fn reads_the_retry_settings_from_the_environment() {
+        let handler = RetryHandler::from_env(&VirtualEnvironment::new());
+        assert_eq!(
+            *handler.config(),
+            RetryConfig {
+                mode: RetryMode::Legacy,
+                max_attempts: 1,
+            }
+        );
+
+        let environment = VirtualEnvironment::new().with_var("AWS_RETRY_MODE", "adaptive");
+        let handler = RetryHandler::from_env(&environment);
+        assert_eq!(handler.config().mode, RetryMode::Adaptive);
+        assert_eq!(handler.config().max_attempts, 3);
+
+        let environment = environment
+            .with_var("AWS_MAX_ATTEMPTS", "5")
+            .with_var("AWS_RETRY_MODE", "sometimes");
+        let (config, mode_source, attempts_source) =
+            RetryConfig::from_env_with_sources(&environment);
+        assert_eq!(config.mode, RetryMode::Legacy);
+        assert_eq!(config.max_attempts, 5);
+        assert_eq!(mode_source, ConfigSource::Default);
+        assert_eq!(
+            attempts_source,
+            ConfigSource::Environment("AWS_MAX_ATTEMPTS".to_owned())
+        );
+    }
+
+    #[test]
+    fn classifies_error_responses() {
+        let mut headers = HeaderMap::<String>::default();
+        headers.insert(
+            "x-amzn-errortype",
+            "ProvisionedThroughputExceededException:http://internal.amazon.com/".to_owned(),
+        );
+        let code = error_code(&headers, b"");
+        assert_eq!(
+            code.as_ref().map(String::as_str),
+            Some("ProvisionedThroughputExceededException")
+        );
+        assert_eq!(
+            classify_response(
+                StatusCode::BAD_REQUEST,
+                code.as_ref().map(String::as_str),
+                &ServiceRetryCodes::default()
+            ),
+            Some(RetryKind::Throttling)
+        );
+
+        let xml = b"<Response><Errors><Error><Code>RequestLimitExceeded</Code>\
+                    </Error></Errors></Response>";
+        let code = error_code(&HeaderMap::default(), xml);
+        assert_eq!(
+            code.as_ref().map(String::as_str),
+            Some("RequestLimitExceeded")
+        );
+
+        let json = br#"{"__type": "com.amazonaws.dynamodb.v20120810#ThrottlingException"}"#;
+        let code = error_code(&HeaderMap::default(), json);
+        assert_eq!(
+            code.as_ref().map(String::as_str),
+            Some("ThrottlingException")
+        );
+
+        assert_eq!(
+            classify_response(
+                StatusCode::SERVICE_UNAVAILABLE,
+                None,
+                &ServiceRetryCodes::default()
+            ),
+            Some(RetryKind::Transient)
+        );
+        assert_eq!(
+            classify_response(
+                StatusCode::BAD_REQUEST,
+                Some("ValidationException"),
+                &ServiceRetryCodes::default()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn classifies_service_specific_codes() {
+        let codes = ServiceRetryCodes {
+            throttling: &["ConcurrentInvocationLimitExceeded"],
+            transient: &["IDPCommunicationError"],
+        };
+        assert!(codes.is_throttle("ConcurrentInvocationLimitExceeded"));
+        assert!(codes.is_throttle("ThrottlingException"));
+        assert!(codes.is_retryable("IDPCommunicationError"));
+        assert!(!codes.is_throttle("IDPCommunicationError"));
+        assert!(!ServiceRetryCodes::default().is_retryable("IDPCommunicationError"));
+
+        assert_eq!(
+            classify_response(
+                StatusCode::BAD_REQUEST,
+                Some("ConcurrentInvocationLimitExceeded"),
+                &codes
+            ),
+            Some(RetryKind::Throttling)
+        );
+        assert_eq!(
+            classify_response(
+                StatusCode::BAD_REQUEST,
+                Some("IDPCommunicationError"),
+                &codes
+            ),
+            Some(RetryKind::Transient)
+        );
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_a_maximum() {
+        assert_eq!(backoff(1, 0.5), Duration::from_millis(500));
+        assert_eq!(backoff(3, 0.5), Duration::from_secs(2));
+        assert_eq!(backoff(10, 0.99), MAX_BACKOFF);
+        assert_eq!(backoff(3, 0.0), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn retry_quota_is_spent_and_refunded() {
+        let handler = RetryHandler::new(RetryConfig {
+            mode: RetryMode::Standard,
+            max_attempts: 3,
+        });
+        assert!(handler.retry_after(3, false).is_none());
+        *handler.retry_tokens.lock().unwrap() = RETRY_COST;
+        let (_, cost) = handler.retry_after(1, false).unwrap();
+        assert_eq!(cost, RETRY_COST);
+        assert!(handler.retry_after(1, false).is_none());
+        handler.record_success(cost);
+        assert_eq!(*handler.retry_tokens.lock().unwrap(), RETRY_COST);
+    }
+
+    #[test]
+    fn rate_limiter_slows_down_after_throttling_and_recovers() {
+        let mut limiter = RateLimiter::new(0.0);
+        assert_eq!(limiter.acquire(0.0), Duration::from_secs(0));
+
+        // send 10 requests per second for a while, then get throttled
+        let mut now = 0.0;
+        for _ in 0..50 {
+            now += 0.1;
+            limiter.update(false, now);
+        }
+        assert!(!limiter.enabled);
+        limiter.update(true, now);
+        assert!(limiter.enabled);
+        let throttled_rate = limiter.fill_rate;
+        assert!(throttled_rate < limiter.last_max_rate);
+        assert!((throttled_rate - limiter.last_max_rate * BETA).abs() < 1e-9);
+
+        // a burst of requests has to wait for tokens
+        let waits: Vec<Duration> = (0..20).map(|_| limiter.acquire(now)).collect();
+        assert!(waits.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(*waits.last().unwrap() > Duration::from_secs(0));
+
+        // successful responses raise the rate again
+        for _ in 0..50 {
+            now += 0.1;
+            limiter.update(false, now);
+        }
+        assert!(limiter.fill_rate > throttled_rate);
+    }
+}
@@ -0,0 +1,290 @@
+//! Clients for services which aren't part of Rusoto.
+//!
+//! APIs of your own which are secured with Signature Version 4, such as API Gateway APIs using
+//! IAM authorization, can be called through a `Client` with the same credentials, signing,
+//! retries, interceptors and shutdown handling as the generated clients. Each operation is a
+//! type implementing `Operation`, which describes its HTTP request and the JSON it returns, and
+//! `ServiceClient::call` sends it.
+//!
+//! ```rust,no_run
+//! # extern crate rusoto_core;
+//! # extern crate serde;
+//! # #[macro_use] extern crate serde_derive;
+//! #
+//! use rusoto_core::param::{Params, ServiceParams};
+//! use rusoto_core::service::{Operation, ServiceClient};
+//! use rusoto_core::Region;
+//!
+//! #[derive(Deserialize)]
+//! struct Widget {
+//!     name: String,
+//! }
+//!
+//! /// `GET /widgets/{id}`
+//! struct GetWidget {
+//!     id: String,
+//!     version: Option<String>,
+//! }
+//!
+//! impl Operation for GetWidget {
+//!     type Output = Widget;
+//!     type Error = ();
+//!
+//!     fn method(&self) -> &str {
+//!         "GET"
+//!     }
+//!
+//!     fn path(&self) -> String {
+//!         format!("/widgets/{}", self.id)
+//!     }
+//!
+//!     fn params(&self) -> Params {
+//!         let mut params = Params::new();
+//!         if let Some(ref version) = self.version {
+//!             params.put("version", version);
+//!         }
+//!         params
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let region = Region::Custom {
+//!     name: "eu-west-1".to_owned(),
+//!     endpoint: "https://abcdef1234.execute-api.eu-west-1.amazonaws.com/prod".to_owned(),
+//! };
+//! let client = ServiceClient::new("execute-api", region);
+//! let widget = client
+//!     .call(&GetWidget {
+//!         id: "42".to_owned(),
+//!         version: None,
+//!     })
+//!     .sync()
+//!     .unwrap();
+//! println!("{}", widget.name);
+//! # }
+//! ```
+
+use futures::Future;
+use serde::de::DeserializeOwned;
+
+use crate::client::Client;
+use crate::error::RusotoError;
+use crate::future::RusotoFuture;
+use crate::param::Params;
+use crate::proto::json::ResponsePayload;
+use crate::region::Region;
+use crate::request::{BufferedHttpResponse, HttpResponse};
+use crate::signature::SignedRequest;
+
+/// An operation of a JSON service, sent with `ServiceClient::call`.
+///
+/// Values of the implementing type are the inputs of the operation.
+pub trait Operation {
+    /// What successful responses deserialize to.
+    ///
+    /// Responses with an empty body are deserialized from `{}`, so operations returning nothing
+    /// can use a struct without fields or `serde::de::IgnoredAny`.
+    type Output: DeserializeOwned + Send + 'static;
+
+    /// The service specific errors returned by `error`.
+    type Error: Send + 'static;
+
+    /// The HTTP method, such as `GET` or `POST`.
+    fn method(&self) -> &str;
+
+    /// The path of the request, starting with a `/`. It is appended to the path of a custom
+    /// region's endpoint.
+    fn path(&self) -> String;
+
+    /// The query parameters of the request. There are none by default.
+    fn params(&self) -> Params {
+        Params::new()
+    }
+
+    /// The body of the request, e.g. serialized with `serde_json::to_vec`. There is none by
+    /// default.
+    fn body(&self) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// The content type of the body, `application/json` by default.
+    fn content_type(&self) -> &str {
+        "application/json"
+    }
+
+    /// Converts an unsuccessful response into an error.
+    ///
+    /// By default, responses are returned as they are in `RusotoError::Unknown`.
+    fn error(response: BufferedHttpResponse) -> RusotoError<Self::Error> {
+        RusotoError::Unknown(response)
+    }
+}
+
+/// A client for a service which isn't part of Rusoto, signing requests for `service`.
+#[derive(Clone)]
+pub struct ServiceClient {
+    client: Client,
+    service: String,
+    region: Region,
+}
+
+impl ServiceClient {
+    /// Creates a client signing requests for `service` in `region` with the shared default
+    /// client.
+    ///
+    /// Requests are sent to `https://{service}.{region}.amazonaws.com` unless `region` is a
+    /// custom region, whose endpoint and path are used instead.
+    pub fn new(service: &str, region: Region) -> ServiceClient {
+        ServiceClient::new_with_client(Client::shared(), service, region)
+    }
+
+    /// Creates a client signing and dispatching requests with `client`.
+    pub fn new_with_client(client: Client, service: &str, region: Region) -> ServiceClient {
+        ServiceClient {
+            client,
+            service: service.to_owned(),
+            region,
+        }
+    }
+
+    /// Signs and sends `operation`, retrying like the generated clients do.
+    pub fn call<O: Operation>(&self, operation: &O) -> RusotoFuture<O::Output, O::Error> {
+        let path = operation.path();
+        let mut request =
+            SignedRequest::new(operation.method(), &self.service, &self.region, &path);
+        request.set_params(operation.params());
+        if let Some(body) = operation.body() {
+            request.set_content_type(operation.content_type().to_owned());
+            request.set_payload(Some(body));
+        }
+
+        self.client.sign_and_dispatch(request, handle_response::<O>)
+    }
+}
+
+fn handle_response<O: Operation>(
+    response: HttpResponse,
+) -> Box<dyn Future<Item = O::Output, Error = RusotoError<O::Error>> + Send> {
+    Box::new(response.buffer().from_err().and_then(|response| {
+        if response.status.is_success() {
+            ResponsePayload::new(&response).deserialize::<O::Output, _>()
+        } else {
+            Err(O::error(response))
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::future::{self, FutureResult};
+    use http::{HeaderMap, StatusCode};
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::credential::StaticProvider;
+    use crate::param::ServiceParams;
+    use crate::request::{DispatchSignedRequest, HttpDispatchError};
+    use crate::ByteStream;
+
+    /// Responds with a description of the request it received.
+    struct DescribingDispatcher;
+
+    impl DispatchSignedRequest for DescribingDispatcher {
+        type Future = FutureResult<HttpResponse, HttpDispatchError>;
+
+        fn dispatch(&self, request: SignedRequest, _timeout: Option<Duration>) -> Self::Future {
+            let header = |name: &str| {
+                request
+                    .headers()
+                    .get(name)
+                    .map(|values| String::from_utf8(values[0].clone()).unwrap())
+            };
+            let status = if request.path.ends_with("missing") {
+                StatusCode::NOT_FOUND
+            } else {
+                StatusCode::OK
+            };
+            let body = json!({
+                "method": request.method(),
+                "host": request.hostname(),
+                "path": request.canonical_uri(),
+                "query": request.canonical_query_string(),
+                "contentType": header("content-type"),
+                "authorization": header("authorization"),
+                "body": request.payload.as_ref().map(|_| "present"),
+            });
+            future::ok(HttpResponse {
+                status,
+                body: ByteStream::from(serde_json::to_vec(&body).unwrap()),
+                headers: HeaderMap::default(),
+            })
+        }
+    }
+
+    struct PutWidget {
+        id: &'static str,
+    }
+
+    impl Operation for PutWidget {
+        type Output = Value;
+        type Error = String;
+
+        fn method(&self) -> &str {
+            "PUT"
+        }
+
+        fn path(&self) -> String {
+            format!("/widgets/{}", self.id)
+        }
+
+        fn params(&self) -> Params {
+            let mut params = Params::new();
+            params.put("dryRun", "true");
+            params
+        }
+
+        fn body(&self) -> Option<Vec<u8>> {
+            Some(br#"{"name": "widget"}"#.to_vec())
+        }
+
+        fn error(response: BufferedHttpResponse) -> RusotoError<String> {
+            RusotoError::Service(format!("status {}", response.status.as_u16()))
+        }
+    }
+
+    fn client() -> ServiceClient {
+        let region = Region::Custom {
+            name: "eu-west-1".to_owned(),
+            endpoint: "https://abc.execute-api.eu-west-1.amazonaws.com/prod".to_owned(),
+        };
+        let credentials = StaticProvider::new_minimal("access_key".to_owned(), "secret".to_owned());
+        let client = Client::new_with(credentials, DescribingDispatcher);
+        ServiceClient::new_with_client(client, "execute-api", region)
+    }
+
+    #[test]
+    fn signs_and_sends_operations() {
+        let response = client().call(&PutWidget { id: "42" }).sync().unwrap();
+
+        assert_eq!(response["method"], "PUT");
+        assert_eq!(response["host"], "abc.execute-api.eu-west-1.amazonaws.com");
+        assert_eq!(response["path"], "/prod/widgets/42");
+        assert_eq!(response["query"], "dryRun=true");
+        assert_eq!(response["contentType"], "application/json");
+        assert_eq!(response["body"], "present");
+        assert!(response["authorization"]
+            .as_str()
+            .unwrap()
+            .contains("/eu-west-1/execute-api/aws4_request"));
+    }
+
+    #[test]
+    fn converts_unsuccessful_responses() {
+        assert_eq!(
+            client().call(&PutWidget { id: "missing" }).sync().err(),
+            Some(RusotoError::Service("status 404".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Shutting clients down gracefully.
+//!
+//! `Client::shutdown` stops a client, and its clones, from dispatching new requests and returns a
+//! `ShutdownFuture` which resolves once the requests in flight have completed. A request is in
+//! flight until its response has been received and its body read or dropped. To shut down a
+//! service client, create it with `new_with_client` and keep a clone of the `Client`.
+//!
+//! Once the last in flight request completes, the client no longer holds on to its request
+//! dispatcher, so the connection pool of its `HttpClient` is closed unless it is shared with
+//! clients which haven't been shut down, such as other users of `Client::shared()`.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use rusoto_core::Client;
+//! use tokio::runtime::Runtime;
+//!
+//! let client = Client::shared();
+//! // ...
+//! Runtime::new()
+//!     .unwrap()
+//!     .block_on(client.shutdown(Duration::from_secs(30)))
+//!     .expect("requests still in flight");
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use futures::task::{self, Task};
+use futures::{Async, Future, Poll, Stream};
+use tokio_timer::Delay;
+
+use crate::stream::ByteStream;
+
+/// Holds a resource, such as a request dispatcher, until the lifecycle is closed, and counts the
+/// requests using it.
+pub(crate) struct Lifecycle<T> {
+    resource: Mutex<Option<T>>,
+    requests: Arc<InFlightRequests>,
+}
+
+impl<T: Clone> Lifecycle<T> {
+    pub(crate) fn new(resource: T) -> Lifecycle<T> {
+        Lifecycle {
+            resource: Mutex::new(Some(resource)),
+            requests: Arc::new(InFlightRequests::default()),
+        }
+    }
+
+    /// Starts a request with the resource, unless the lifecycle has been closed.
+    pub(crate) fn begin(&self) -> Option<(T, InFlight)> {
+        let resource = self.resource.lock().unwrap();
+        resource
+            .as_ref()
+            .map(|resource| (resource.clone(), InFlight::new(self.requests.clone())))
+    }
+
+    /// Releases the resource. Requests in flight keep their own handle on it.
+    pub(crate) fn close(&self) {
+        self.resource.lock().unwrap().take();
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.resource.lock().unwrap().is_none()
+    }
+
+    /// Waits up to `timeout` for the requests in flight.
+    pub(crate) fn drain(&self, timeout: Duration) -> ShutdownFuture {
+        ShutdownFuture {
+            requests: self.requests.clone(),
+            delay: Delay::new(Instant::now() + timeout),
+        }
+    }
+}
+
+#[derive(Default)]
+struct InFlightRequests {
+    state: Mutex<InFlightState>,
+}
+
+#[derive(Default)]
+struct InFlightState {
+    count: usize,
+    /// Tasks waiting for the count to drop to zero
+    waiting: Vec<Task>,
+}
+
+/// Marks a request as in flight until dropped.
+pub(crate) struct InFlight {
+    requests: Arc<InFlightRequests>,
+}
+
+impl InFlight {
+    fn new(requests: Arc<InFlightRequests>) -> InFlight {
+        requests.state.lock().unwrap().count += 1;
+        InFlight { requests }
+    }
+
+    /// Keeps the request in flight until `body` has been read or dropped.
+    pub(crate) fn hold_until_read(self, body: ByteStream) -> ByteStream {
+        if ByteStream::buffered(&body).is_some() {
+            return body;
+        }
+        ByteStream::new(InFlightBody {
+            body,
+            _in_flight: self,
+        })
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        let mut state = self.requests.state.lock().unwrap();
+        state.count -= 1;
+        if state.count == 0 {
+            for task in state.waiting.drain(..) {
+                task.notify();
+            }
+        }
+    }
+}
+
+struct InFlightBody {
+    body: ByteStream,
+    _in_flight: InFlight,
+}
+
+impl Stream for InFlightBody {
+    type Item = Bytes;
+    type Error = io::Error;
+
+    fn poll(&mut self) -> Poll<Option<Bytes>, io::Error> {
+        self.body.poll()
+    }
+}
+
+/// Future returned by `Client::shutdown`, resolving once no request is in flight.
+pub struct ShutdownFuture {
+    requests: Arc<InFlightRequests>,
+    delay: Delay,
+}
+
+impl Future for ShutdownFuture {
+    type Item = ();
+    type Error = ShutdownError;
+
+    fn poll(&mut self) -> Poll<(), ShutdownError> {
+        let in_flight = {
+            let mut state = self.requests.state.lock().unwrap();
+            if state.count == 0 {
+                return Ok(Async::Ready(()));
+            }
+            if !state.waiting.iter().any(Task::will_notify_current) {
+                state.waiting.push(task::current());
+            }
+            state.count
+        };
+        match self.delay.poll() {
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Ok(Async::Ready(())) => Err(ShutdownError::TimedOut { in_flight }),
+            Err(err) => Err(ShutdownError::Timer(err.to_string())),
+        }
+    }
+}
+
+/// An error returned by `ShutdownFuture`.
+#[derive(Debug, PartialEq)]
+pub enum ShutdownError {
+    /// Requests were still in flight when the timeout elapsed
+    TimedOut {
+        /// The number of requests in flight
+        in_flight: usize,
+    },
+    /// The timer failed, e.g. because the future isn't run on a Tokio runtime
+    Timer(String),
+}
+
+impl fmt::Display for ShutdownError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ShutdownError::TimedOut { in_flight } => {
+                write!(
+                    f,
+                    "{}: {} requests in flight",
+                    self.description(),
+                    in_flight
+                )
+            }
+            ShutdownError::Timer(ref cause) => write!(f, "{}: {}", self.description(), cause),
+        }
+    }
+}
+
+impl Error for ShutdownError {
+    fn description(&self) -> &str {
+        match *self {
+            ShutdownError::TimedOut { .. } => "Timed out waiting for requests in flight",
+            ShutdownError::Timer(_) => "Timer error",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future;
+
+    #[test]
+    fn closing_rejects_new_requests() {
+        let lifecycle = Lifecycle::new(());
+        assert!(lifecycle.begin().is_some());
+        lifecycle.close();
+        assert!(lifecycle.is_closed());
+        assert!(lifecycle.begin().is_none());
+    }
+
+    #[test]
+    fn drains_requests_in_flight() {
+        let lifecycle = Lifecycle::new(());
+        let (_, in_flight) = lifecycle.begin().unwrap();
+        let body = in_flight.hold_until_read(ByteStream::new(futures::stream::once(Ok(
+            Bytes::from_static(b"body"),
+        ))));
+        lifecycle.close();
+
+        let mut drain = lifecycle.drain(Duration::from_secs(5));
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        let pending = runtime
+            .block_on(future::lazy(move || {
+                let pending = drain.poll() == Ok(Async::NotReady);
+                future::ok::<_, ()>((pending, drain))
+            }))
+            .unwrap();
+        assert!(pending.0);
+
+        assert_eq!(runtime.block_on(body.concat2()).unwrap(), &b"body"[..]);
+        assert_eq!(runtime.block_on(pending.1), Ok(()));
+    }
+
+    #[test]
+    fn times_out_with_requests_in_flight() {
+        let lifecycle = Lifecycle::new(());
+        let _in_flight = lifecycle.begin().unwrap();
+        let drain = lifecycle.drain(Duration::from_millis(10));
+        let result = tokio::runtime::Runtime::new().unwrap().block_on(drain);
+        assert_eq!(result, Err(ShutdownError::TimedOut { in_flight: 1 }));
+    }
+}
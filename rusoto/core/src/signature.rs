@@ -1,2 +1,31 @@
+//! Signing requests with AWS Signature Version 4.
+//!
+//! `SignedRequest` describes an HTTP request to an AWS service: its method, service, region,
+//! path, query parameters, headers and payload. It can be used to call operations rusoto doesn't
+//! have a generated client for yet, such as those of new services or beta endpoints.
+//!
+//! `Client::send_raw` fetches credentials from the client's provider, signs the request in its
+//! headers and dispatches it, retrying as configured:
+//!
+//! ```rust,no_run
+//! use rusoto_core::signature::SignedRequest;
+//! use rusoto_core::{Client, Region};
+//!
+//! let mut request = SignedRequest::new("POST", "sts", &Region::UsEast1, "/");
+//! request.add_param("Action", "GetCallerIdentity");
+//! request.add_param("Version", "2011-06-15");
+//!
+//! let response = Client::shared().send_raw(request).sync().unwrap();
+//! println!("{}", String::from_utf8_lossy(&response.body));
+//! ```
+//!
+//! Requests can also be signed by hand with any `ProvideAwsCredentials`, in their headers with
+//! `SignedRequest::sign` or in their query string with `SignedRequest::sign_query_string`, and
+//! dispatched with any `DispatchSignedRequest`, such as `HttpClient`. Use
+//! `SignedRequest::generate_presigned_url` to get a URL others can use without credentials, and
+//! `SignedRequest::generate_presigned_websocket_url` to get a `wss://` URL to connect to AWS IoT
+//! Core or an API Gateway WebSocket API with.
+
 // moved to rusoto_signature
+pub use rusoto_signature::checksum::*;
 pub use rusoto_signature::signature::*;
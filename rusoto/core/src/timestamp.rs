@@ -0,0 +1,417 @@
+//! Timestamp members of requests and responses.
+//!
+//! By default timestamps are exposed the way their protocol transfers them: services using the
+//! JSON protocols send seconds since the Unix epoch, exposed as `f64`, while services using the
+//! query and REST-XML protocols send ISO 8601 strings, exposed as `String`.
+//!
+//! With the `chrono` feature enabled, both are `chrono::DateTime<Utc>`. The generated code then
+//! converts them to and from the format each protocol expects: epoch seconds in JSON bodies,
+//! ISO 8601 in XML bodies and query strings, and RFC 822 dates in HTTP headers.
+
+use std::error::Error;
+use std::fmt;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::proto::xml::util::XmlParseError;
+
+/// A timestamp sent as seconds since the Unix epoch, by services using the JSON protocols.
+#[cfg(not(feature = "chrono"))]
+pub type EpochTimestamp = f64;
+/// A timestamp sent as seconds since the Unix epoch, by services using the JSON protocols.
+#[cfg(feature = "chrono")]
+pub type EpochTimestamp = DateTime<Utc>;
+
+/// A timestamp sent as an ISO 8601 string, by services using the query and REST-XML protocols.
+#[cfg(not(feature = "chrono"))]
+pub type IsoTimestamp = String;
+/// A timestamp sent as an ISO 8601 string, by services using the query and REST-XML protocols.
+#[cfg(feature = "chrono")]
+pub type IsoTimestamp = DateTime<Utc>;
+
+/// An error produced when a timestamp sent by AWS can't be parsed.
+#[derive(Debug, PartialEq)]
+pub struct ParseTimestampError {
+    message: String,
+}
+
+impl ParseTimestampError {
+    #[cfg(feature = "chrono")]
+    fn new(value: &str) -> ParseTimestampError {
+        ParseTimestampError {
+            message: format!("invalid timestamp: {}", value),
+        }
+    }
+}
+
+impl fmt::Display for ParseTimestampError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ParseTimestampError {
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<ParseTimestampError> for XmlParseError {
+    fn from(err: ParseTimestampError) -> XmlParseError {
+        XmlParseError(err.message)
+    }
+}
+
+/// Converts a timestamp read from an XML body.
+#[cfg(not(feature = "chrono"))]
+pub fn from_iso8601(value: String) -> Result<IsoTimestamp, ParseTimestampError> {
+    Ok(value)
+}
+
+/// Converts a timestamp read from an XML body.
+#[cfg(feature = "chrono")]
+pub fn from_iso8601(value: String) -> Result<IsoTimestamp, ParseTimestampError> {
+    DateTime::parse_from_rfc3339(&value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|_| ParseTimestampError::new(&value))
+}
+
+/// Formats a timestamp for an XML body or a query string.
+#[cfg(not(feature = "chrono"))]
+pub fn to_iso8601(value: &IsoTimestamp) -> String {
+    value.clone()
+}
+
+/// Formats a timestamp for an XML body or a query string.
+#[cfg(feature = "chrono")]
+pub fn to_iso8601(value: &IsoTimestamp) -> String {
+    value.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+}
+
+/// Converts a timestamp read from an HTTP header, e.g. `Last-Modified`.
+#[cfg(not(feature = "chrono"))]
+pub fn from_http_date(value: &str) -> Result<IsoTimestamp, ParseTimestampError> {
+    Ok(value.to_owned())
+}
+
+/// Converts a timestamp read from an HTTP header, e.g. `Last-Modified`.
+#[cfg(feature = "chrono")]
+pub fn from_http_date(value: &str) -> Result<IsoTimestamp, ParseTimestampError> {
+    DateTime::parse_from_rfc2822(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|_| ParseTimestampError::new(value))
+}
+
+/// Formats a timestamp for an HTTP header, e.g. `If-Modified-Since`.
+#[cfg(not(feature = "chrono"))]
+pub fn to_http_date(value: &IsoTimestamp) -> String {
+    value.clone()
+}
+
+/// Formats a timestamp for an HTTP header, e.g. `If-Modified-Since`.
+#[cfg(feature = "chrono")]
+pub fn to_http_date(value: &IsoTimestamp) -> String {
+    value.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// Creates a timestamp for a JSON body from seconds since the Unix epoch.
+#[cfg(not(feature = "chrono"))]
+pub fn epoch_timestamp(seconds: f64) -> EpochTimestamp {
+    seconds
+}
+
+/// Creates a timestamp for a JSON body from seconds since the Unix epoch, keeping millisecond
+/// precision. Seconds which aren't finite give the Unix epoch.
+#[cfg(feature = "chrono")]
+pub fn epoch_timestamp(seconds: f64) -> EpochTimestamp {
+    from_epoch_seconds(seconds).unwrap_or_else(|| Utc.timestamp(0, 0))
+}
+
+/// The seconds since the Unix epoch of a timestamp of a JSON body.
+#[cfg(not(feature = "chrono"))]
+pub fn to_epoch_seconds(value: &EpochTimestamp) -> f64 {
+    *value
+}
+
+/// The seconds since the Unix epoch of a timestamp of a JSON body.
+#[cfg(feature = "chrono")]
+pub fn to_epoch_seconds(value: &EpochTimestamp) -> f64 {
+    value.timestamp_millis() as f64 / 1000.0
+}
+
+/// Converts seconds since the Unix epoch, keeping millisecond precision.
+#[cfg(feature = "chrono")]
+fn from_epoch_seconds(seconds: f64) -> Option<EpochTimestamp> {
+    if !seconds.is_finite() {
+        return None;
+    }
+    let millis = (seconds * 1000.0).round() as i64;
+    Utc.timestamp_opt(
+        millis.div_euclid(1000),
+        (millis.rem_euclid(1000) * 1_000_000) as u32,
+    )
+    .single()
+}
+
+/// Serializes and deserializes timestamps of JSON bodies as seconds since the Unix epoch.
+///
+/// Used with `#[serde(with = "rusoto_core::timestamp::epoch_seconds")]` by the generated code.
+pub mod epoch_seconds {
+    use serde::{Deserializer, Serializer};
+
+    use super::EpochTimestamp;
+
+    /// Serializes a timestamp as seconds since the Unix epoch.
+    #[cfg(not(feature = "chrono"))]
+    pub fn serialize<S: Serializer>(
+        value: &EpochTimestamp,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(*value)
+    }
+
+    /// Serializes a timestamp as seconds since the Unix epoch.
+    #[cfg(feature = "chrono")]
+    pub fn serialize<S: Serializer>(
+        value: &EpochTimestamp,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(value.timestamp_millis() as f64 / 1000.0)
+    }
+
+    /// Deserializes seconds since the Unix epoch.
+    #[cfg(not(feature = "chrono"))]
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<EpochTimestamp, D::Error> {
+        serde::Deserialize::deserialize(deserializer)
+    }
+
+    /// Deserializes seconds since the Unix epoch, or an ISO 8601 string as sent by a few
+    /// services.
+    #[cfg(feature = "chrono")]
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<EpochTimestamp, D::Error> {
+        deserializer.deserialize_any(visitor::EpochTimestampVisitor)
+    }
+
+    #[cfg(feature = "chrono")]
+    mod visitor {
+        use std::fmt;
+
+        use serde::de::{Error, Visitor};
+
+        use super::super::{from_epoch_seconds, from_iso8601, EpochTimestamp};
+
+        pub struct EpochTimestampVisitor;
+
+        impl<'de> Visitor<'de> for EpochTimestampVisitor {
+            type Value = EpochTimestamp;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("seconds since the Unix epoch")
+            }
+
+            fn visit_f64<E: Error>(self, value: f64) -> Result<EpochTimestamp, E> {
+                from_epoch_seconds(value)
+                    .ok_or_else(|| E::custom(format!("invalid timestamp: {}", value)))
+            }
+
+            fn visit_i64<E: Error>(self, value: i64) -> Result<EpochTimestamp, E> {
+                self.visit_f64(value as f64)
+            }
+
+            fn visit_u64<E: Error>(self, value: u64) -> Result<EpochTimestamp, E> {
+                self.visit_f64(value as f64)
+            }
+
+            fn visit_str<E: Error>(self, value: &str) -> Result<EpochTimestamp, E> {
+                from_iso8601(value.to_owned()).map_err(E::custom)
+            }
+        }
+    }
+
+    /// Serializes and deserializes optional timestamps of JSON bodies.
+    ///
+    /// Used with `#[serde(default, with = "rusoto_core::timestamp::epoch_seconds::option")]`.
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        use super::super::EpochTimestamp;
+
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(with = "crate::timestamp::epoch_seconds")] EpochTimestamp);
+
+        /// Serializes a timestamp, if set, as seconds since the Unix epoch.
+        pub fn serialize<S: Serializer>(
+            value: &Option<EpochTimestamp>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match *value {
+                Some(ref value) => super::serialize(value, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        /// Deserializes seconds since the Unix epoch, or `null`.
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<EpochTimestamp>, D::Error> {
+            Option::<Wrapper>::deserialize(deserializer)
+                .map(|value| value.map(|Wrapper(value)| value))
+        }
+    }
+}
+
+/// Serializes and deserializes timestamps of JSON bodies as ISO 8601 strings, for the members
+/// whose model asks for that format instead of epoch seconds.
+///
+/// Used with `#[serde(with = "rusoto_core::timestamp::iso8601")]` by the generated code.
+pub mod iso8601 {
+    use serde::{Deserializer, Serializer};
+
+    use super::EpochTimestamp;
+
+    /// Serializes a timestamp as seconds since the Unix epoch, as without `chrono` the
+    /// timestamp is the number of seconds.
+    #[cfg(not(feature = "chrono"))]
+    pub fn serialize<S: Serializer>(
+        value: &EpochTimestamp,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::epoch_seconds::serialize(value, serializer)
+    }
+
+    /// Serializes a timestamp as an ISO 8601 string.
+    #[cfg(feature = "chrono")]
+    pub fn serialize<S: Serializer>(
+        value: &EpochTimestamp,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&super::to_iso8601(value))
+    }
+
+    /// Deserializes an ISO 8601 string, or seconds since the Unix epoch.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<EpochTimestamp, D::Error> {
+        super::epoch_seconds::deserialize(deserializer)
+    }
+
+    /// Serializes and deserializes optional timestamps of JSON bodies.
+    ///
+    /// Used with `#[serde(default, with = "rusoto_core::timestamp::iso8601::option")]`.
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        use super::super::EpochTimestamp;
+
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(with = "crate::timestamp::iso8601")] EpochTimestamp);
+
+        /// Serializes a timestamp, if set, as an ISO 8601 string.
+        pub fn serialize<S: Serializer>(
+            value: &Option<EpochTimestamp>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match *value {
+                Some(ref value) => super::serialize(value, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        /// Deserializes an ISO 8601 string, or `null`.
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<EpochTimestamp>, D::Error> {
+            Option::<Wrapper>::deserialize(deserializer)
+                .map(|value| value.map(|Wrapper(value)| value))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Member {
+        #[serde(with = "epoch_seconds")]
+        required: EpochTimestamp,
+        #[serde(default, with = "epoch_seconds::option")]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        optional: Option<EpochTimestamp>,
+    }
+
+    #[cfg(not(feature = "chrono"))]
+    #[test]
+    fn keeps_the_wire_format_without_chrono() {
+        let member: Member = serde_json::from_str(r#"{"required": 1570902630.5}"#).unwrap();
+        assert_eq!(member.required, 1_570_902_630.5);
+        assert_eq!(member.optional, None);
+        assert_eq!(
+            serde_json::to_string(&member).unwrap(),
+            r#"{"required":1570902630.5}"#
+        );
+        assert_eq!(
+            from_iso8601("2019-10-12T17:50:30.000Z".to_owned()),
+            Ok("2019-10-12T17:50:30.000Z".to_owned())
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn converts_epoch_seconds() {
+        let member: Member =
+            serde_json::from_str(r#"{"required": 1570902630.5, "optional": 1570902630}"#).unwrap();
+        assert_eq!(member.required, Utc.timestamp(1_570_902_630, 500_000_000));
+        assert_eq!(member.optional, Some(Utc.timestamp(1_570_902_630, 0)));
+        assert_eq!(
+            serde_json::to_string(&member).unwrap(),
+            r#"{"required":1570902630.5,"optional":1570902630.0}"#
+        );
+    }
+
+    #[test]
+    fn converts_epoch_seconds_both_ways() {
+        let timestamp = epoch_timestamp(1_570_902_630.5);
+        assert_eq!(to_epoch_seconds(&timestamp), 1_570_902_630.5);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn converts_iso8601_members() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Iso8601Member {
+            #[serde(with = "iso8601")]
+            required: EpochTimestamp,
+        }
+
+        let member: Iso8601Member =
+            serde_json::from_str(r#"{"required": "2019-10-12T17:50:30.500Z"}"#).unwrap();
+        assert_eq!(member.required, Utc.timestamp(1_570_902_630, 500_000_000));
+        assert_eq!(
+            serde_json::to_string(&member).unwrap(),
+            r#"{"required":"2019-10-12T17:50:30.500Z"}"#
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn converts_iso8601_and_http_dates() {
+        let timestamp = Utc.timestamp(1_570_902_630, 0);
+        assert_eq!(
+            from_iso8601("2019-10-12T17:50:30.000Z".to_owned()),
+            Ok(timestamp)
+        );
+        assert_eq!(to_iso8601(&timestamp), "2019-10-12T17:50:30.000Z");
+        assert_eq!(
+            from_http_date("Sat, 12 Oct 2019 17:50:30 GMT"),
+            Ok(timestamp)
+        );
+        assert_eq!(to_http_date(&timestamp), "Sat, 12 Oct 2019 17:50:30 GMT");
+        assert!(from_iso8601("yesterday".to_owned()).is_err());
+    }
+}
@@ -0,0 +1,139 @@
+//! Polling for long running operations.
+//!
+//! Many AWS resources are created asynchronously and have to be described repeatedly until
+//! they reach the desired state. `poll_until` implements that loop, waiting a fixed delay
+//! between attempts and giving up after a maximum number of attempts.
+
+use std::time::{Duration, Instant};
+
+use futures::future::{self, Loop};
+use futures::{Future, IntoFuture};
+use tokio_timer::Delay;
+
+use crate::request::HttpDispatchError;
+
+/// How often, and for how long, to poll a resource.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaiterConfig {
+    /// Time to wait between two attempts
+    pub delay: Duration,
+    /// Number of attempts after which polling gives up
+    pub max_attempts: usize,
+}
+
+impl WaiterConfig {
+    /// Create a new WaiterConfig
+    pub fn new(delay: Duration, max_attempts: usize) -> WaiterConfig {
+        WaiterConfig {
+            delay,
+            max_attempts,
+        }
+    }
+}
+
+impl Default for WaiterConfig {
+    /// Polls every 5 seconds for up to 10 minutes.
+    fn default() -> WaiterConfig {
+        WaiterConfig::new(Duration::from_secs(5), 120)
+    }
+}
+
+/// Returns a future which resolves after `duration`.
+///
+/// Must be run on a Tokio runtime. Timer failures are reported as `HttpDispatchError`s.
+pub fn sleep<E>(duration: Duration) -> impl Future<Item = (), Error = E> + Send
+where
+    E: From<HttpDispatchError> + Send,
+{
+    Delay::new(Instant::now() + duration)
+        .map_err(|err| E::from(HttpDispatchError::new(format!("timer error: {}", err))))
+}
+
+struct PollState<F, E> {
+    poll: F,
+    attempts: usize,
+    on_timeout: Option<E>,
+}
+
+/// Calls `poll` until it resolves to `Some`, waiting `config.delay` between attempts.
+///
+/// Errors returned by `poll` end polling immediately. If the resource isn't ready after
+/// `config.max_attempts` attempts, the returned future fails with `on_timeout`.
+pub fn poll_until<F, R, T, E>(
+    config: WaiterConfig,
+    poll: F,
+    on_timeout: E,
+) -> Box<dyn Future<Item = T, Error = E> + Send>
+where
+    F: FnMut() -> R + Send + 'static,
+    R: IntoFuture<Item = Option<T>, Error = E>,
+    R::Future: Send + 'static,
+    T: Send + 'static,
+    E: From<HttpDispatchError> + Send + 'static,
+{
+    let state = PollState {
+        poll,
+        attempts: 0,
+        on_timeout: Some(on_timeout),
+    };
+
+    Box::new(future::loop_fn(state, move |mut state| {
+        let delay = config.delay;
+        let max_attempts = config.max_attempts;
+        state.attempts += 1;
+
+        (state.poll)().into_future().and_then(
+            move |result| -> Box<dyn Future<Item = _, Error = E> + Send> {
+                match result {
+                    Some(value) => Box::new(future::ok(Loop::Break(value))),
+                    None if state.attempts >= max_attempts => Box::new(future::err(
+                        state
+                            .on_timeout
+                            .take()
+                            .expect("waiter polled after timing out"),
+                    )),
+                    None => Box::new(sleep(delay).map(move |_| Loop::Continue(state))),
+                }
+            },
+        )
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RusotoError;
+
+    #[test]
+    fn resolves_once_ready() {
+        let mut calls = 0;
+        let future = poll_until(
+            WaiterConfig::new(Duration::from_millis(1), 5),
+            move || {
+                calls += 1;
+                let result: Result<_, RusotoError<()>> =
+                    Ok(if calls == 3 { Some(calls) } else { None });
+                result
+            },
+            RusotoError::Validation("timed out".to_owned()),
+        );
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        assert_eq!(runtime.block_on(future), Ok(3));
+    }
+
+    #[test]
+    fn times_out_after_max_attempts() {
+        let future = poll_until(
+            WaiterConfig::new(Duration::from_millis(1), 2),
+            || -> Result<Option<()>, RusotoError<()>> { Ok(None) },
+            RusotoError::Validation("timed out".to_owned()),
+        );
+
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        assert_eq!(
+            runtime.block_on(future),
+            Err(RusotoError::Validation("timed out".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,418 @@
+//! AWS X-Ray trace context propagation.
+//!
+//! When a trace context is present, every request is sent with an `X-Amzn-Trace-Id` header, so
+//! the services it reaches join the trace. The context is the one set for the current thread
+//! with `with_trace_context`, or else the one in the `_X_AMZN_TRACE_ID` environment variable,
+//! which AWS Lambda sets for every invocation. It is read when the operation is called, not when
+//! its future is polled, so futures run on another thread keep the context they were created in.
+//!
+//! Once a `SubsegmentEmitter` is installed with `set_subsegment_emitter`, such as the UDP
+//! emitter of `rusoto_xray`, every call made in a sampled trace context with a parent is also
+//! described by a `Subsegment`. The header names the subsegment as the parent of the service's
+//! segments, and the subsegment is emitted once the call completes, so the call shows up in the
+//! service map between the caller and the service.
+//!
+//! ```rust
+//! use rusoto_core::xray::{with_trace_context, TraceContext};
+//!
+//! let context = TraceContext::parse(
+//!     "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1",
+//! )
+//! .unwrap();
+//! with_trace_context(context, || {
+//!     // requests made here are sent with the trace header
+//! });
+//! ```
+
+use std::cell::RefCell;
+use std::env;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use uuid::Uuid;
+
+use crate::instrument::{operation_name, request_id};
+use crate::request::HttpResponse;
+use crate::signature::SignedRequest;
+
+/// The header carrying the trace context.
+pub const TRACE_HEADER: &str = "x-amzn-trace-id";
+
+/// The environment variable AWS Lambda puts the trace context of the invocation in.
+pub const TRACE_ENV_VAR: &str = "_X_AMZN_TRACE_ID";
+
+thread_local! {
+    static CURRENT: RefCell<Option<TraceContext>> = RefCell::new(None);
+}
+
+lazy_static! {
+    static ref EMITTER: RwLock<Option<Arc<dyn SubsegmentEmitter>>> = RwLock::new(None);
+}
+
+/// The trace a request belongs to, as carried by the `X-Amzn-Trace-Id` header.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TraceContext {
+    /// The id of the trace, e.g. `1-5759e988-bd862e3fe1be46a994272793`
+    pub trace_id: String,
+    /// The id of the segment or subsegment the request is made in
+    pub parent_id: Option<String>,
+    /// Whether the trace is recorded, if decided
+    pub sampled: Option<bool>,
+}
+
+impl TraceContext {
+    /// Starts a new, sampled trace.
+    pub fn new_trace() -> TraceContext {
+        TraceContext {
+            trace_id: generate_trace_id(),
+            parent_id: None,
+            sampled: Some(true),
+        }
+    }
+
+    /// Parses the value of an `X-Amzn-Trace-Id` header, which must name the root of the trace.
+    pub fn parse(header: &str) -> Option<TraceContext> {
+        let mut context = TraceContext {
+            trace_id: String::new(),
+            parent_id: None,
+            sampled: None,
+        };
+        for field in header.split(';') {
+            let mut parts = field.splitn(2, '=');
+            let key = parts.next().unwrap_or_default().trim();
+            let value = parts.next().unwrap_or_default().trim();
+            match key {
+                "Root" => context.trace_id = value.to_owned(),
+                "Parent" if !value.is_empty() => context.parent_id = Some(value.to_owned()),
+                "Sampled" => {
+                    context.sampled = match value {
+                        "1" => Some(true),
+                        "0" => Some(false),
+                        _ => None,
+                    }
+                }
+                _ => {}
+            }
+        }
+        if context.trace_id.is_empty() {
+            None
+        } else {
+            Some(context)
+        }
+    }
+
+    /// The same trace, with another parent.
+    pub fn with_parent<S>(&self, parent_id: S) -> TraceContext
+    where
+        S: Into<String>,
+    {
+        TraceContext {
+            parent_id: Some(parent_id.into()),
+            ..self.clone()
+        }
+    }
+}
+
+/// Formats the context as the value of an `X-Amzn-Trace-Id` header.
+impl fmt::Display for TraceContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Root={}", self.trace_id)?;
+        if let Some(ref parent_id) = self.parent_id {
+            write!(f, ";Parent={}", parent_id)?;
+        }
+        match self.sampled {
+            Some(true) => write!(f, ";Sampled=1"),
+            Some(false) => write!(f, ";Sampled=0"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns a new trace id: the current time and 96 random bits, as X-Ray expects them.
+pub fn generate_trace_id() -> String {
+    let random = Uuid::new_v4();
+    let random = random.as_bytes();
+    format!("1-{:08x}-{}", now().trunc() as u32, hex(&random[..12]))
+}
+
+/// Returns a new segment or subsegment id: 64 random bits as 16 hexadecimal digits.
+pub fn generate_id() -> String {
+    hex(&Uuid::new_v4().as_bytes()[..8])
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Returns the seconds since the epoch, as X-Ray times segments.
+pub fn now() -> f64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    now.as_secs() as f64 + f64::from(now.subsec_micros()) / 1_000_000.0
+}
+
+/// Runs `f` with `context` as the trace context of the requests made on the current thread,
+/// restoring the previous context afterwards.
+pub fn with_trace_context<F, R>(context: TraceContext, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    struct Restore(Option<TraceContext>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CURRENT.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+
+    let previous = CURRENT.with(|current| current.borrow_mut().replace(context));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// Returns the trace context of requests made on the current thread: the one set with
+/// `with_trace_context`, or else the one in `_X_AMZN_TRACE_ID`.
+pub fn current_trace_context() -> Option<TraceContext> {
+    CURRENT
+        .with(|current| current.borrow().clone())
+        .or_else(|| {
+            env::var(TRACE_ENV_VAR)
+                .ok()
+                .and_then(|header| TraceContext::parse(&header))
+        })
+}
+
+/// A call to an AWS service made in a sampled trace.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Subsegment {
+    /// The id of the subsegment, sent as the parent in the trace header
+    pub id: String,
+    /// The id of the trace
+    pub trace_id: String,
+    /// The id of the segment or subsegment the call was made in
+    pub parent_id: String,
+    /// The service called, as named in signatures, e.g. `dynamodb`
+    pub service: String,
+    /// The operation called, unless the service has a REST protocol
+    pub operation: Option<String>,
+    /// The region the call was sent to
+    pub region: String,
+    /// Seconds since the epoch when the call was made
+    pub start_time: f64,
+    /// Seconds since the epoch when the call completed
+    pub end_time: f64,
+    /// The HTTP status of the final response, if one was received
+    pub http_status: Option<u16>,
+    /// The AWS request id of the final response
+    pub request_id: Option<String>,
+    /// Number of times the request was retried
+    pub retries: u32,
+    /// Why the call failed without a response
+    pub error: Option<String>,
+}
+
+/// Receives the subsegments of completed calls, e.g. to send them to the X-Ray daemon.
+///
+/// Emitters are called on the thread completing the call, and should not block.
+pub trait SubsegmentEmitter: Send + Sync {
+    /// Emits the subsegment of a completed call.
+    fn emit(&self, subsegment: &Subsegment);
+}
+
+impl<F> SubsegmentEmitter for F
+where
+    F: Fn(&Subsegment) + Send + Sync,
+{
+    fn emit(&self, subsegment: &Subsegment) {
+        self(subsegment)
+    }
+}
+
+/// Emits the subsegments of all calls made from now on with `emitter`, replacing the emitter
+/// installed before.
+pub fn set_subsegment_emitter<E>(emitter: E)
+where
+    E: SubsegmentEmitter + 'static,
+{
+    *EMITTER.write().unwrap() = Some(Arc::new(emitter));
+}
+
+/// Stops emitting subsegments. Trace headers are still sent.
+pub fn clear_subsegment_emitter() {
+    *EMITTER.write().unwrap() = None;
+}
+
+/// Collects the subsegment of one API call.
+pub(crate) struct XrayCall {
+    emitter: Arc<dyn SubsegmentEmitter>,
+    subsegment: Subsegment,
+}
+
+impl XrayCall {
+    /// Adds the trace header to a request made in a trace context, and starts its subsegment if
+    /// it is emitted. Requests which already have the header are left alone.
+    pub(crate) fn start(request: &mut SignedRequest) -> Option<XrayCall> {
+        if request.headers.contains_key(TRACE_HEADER) {
+            return None;
+        }
+        let context = current_trace_context()?;
+        let emitter = EMITTER.read().unwrap().clone();
+        let (emitter, parent_id) = match (emitter, context.parent_id.clone()) {
+            (Some(emitter), Some(parent_id)) if context.sampled == Some(true) => {
+                (emitter, parent_id)
+            }
+            _ => {
+                request.add_header(TRACE_HEADER, &context.to_string());
+                return None;
+            }
+        };
+
+        let id = generate_id();
+        request.add_header(TRACE_HEADER, &context.with_parent(id.as_str()).to_string());
+        Some(XrayCall {
+            emitter,
+            subsegment: Subsegment {
+                id,
+                trace_id: context.trace_id,
+                parent_id,
+                service: request.service.clone(),
+                operation: operation_name(request),
+                region: request.region.name().to_owned(),
+                start_time: now(),
+                end_time: 0.0,
+                http_status: None,
+                request_id: None,
+                retries: 0,
+                error: None,
+            },
+        })
+    }
+
+    /// Emits the subsegment of a call which received a response after `attempts` attempts.
+    pub(crate) fn finish_with_response(&self, response: &HttpResponse, attempts: u32) {
+        let mut subsegment = self.subsegment(attempts);
+        subsegment.http_status = Some(response.status.as_u16());
+        subsegment.request_id = request_id(response).map(str::to_owned);
+        self.emitter.emit(&subsegment);
+    }
+
+    /// Emits the subsegment of a call which failed without a response after `attempts` attempts.
+    pub(crate) fn finish_with_error(&self, message: &str, attempts: u32) {
+        let mut subsegment = self.subsegment(attempts);
+        subsegment.error = Some(message.to_owned());
+        self.emitter.emit(&subsegment);
+    }
+
+    fn subsegment(&self, attempts: u32) -> Subsegment {
+        Subsegment {
+            end_time: now(),
+            retries: attempts.saturating_sub(1),
+            ..self.subsegment.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::region::Region;
+    use crate::stream::ByteStream;
+    use http::{HeaderMap, StatusCode};
+    use std::sync::Mutex;
+
+    const HEADER: &str =
+        "Root=1-5759e988-bd862e3fe1be46a994272793;Parent=53995c3f42cd8ad8;Sampled=1";
+
+    #[test]
+    fn parses_and_formats_headers() {
+        let context = TraceContext::parse(HEADER).unwrap();
+        assert_eq!(context.trace_id, "1-5759e988-bd862e3fe1be46a994272793");
+        assert_eq!(context.parent_id, Some("53995c3f42cd8ad8".to_owned()));
+        assert_eq!(context.sampled, Some(true));
+        assert_eq!(context.to_string(), HEADER);
+
+        let context = TraceContext::parse("Root=1-5759e988-bd862e3fe1be46a994272793; Sampled=?");
+        assert_eq!(
+            context.map(|context| context.to_string()),
+            Some("Root=1-5759e988-bd862e3fe1be46a994272793".to_owned())
+        );
+        assert_eq!(TraceContext::parse("Parent=53995c3f42cd8ad8"), None);
+    }
+
+    #[test]
+    fn generates_ids_in_the_xray_format() {
+        let trace_id = generate_trace_id();
+        let parts: Vec<&str> = trace_id.split('-').collect();
+        assert_eq!(parts[0], "1");
+        assert_eq!(parts[1].len(), 8);
+        assert_eq!(parts[2].len(), 24);
+        assert_eq!(generate_id().len(), 16);
+        assert_ne!(generate_id(), generate_id());
+    }
+
+    #[test]
+    fn scopes_the_context_to_the_closure() {
+        let context = TraceContext::parse(HEADER).unwrap();
+        let inner = with_trace_context(context.clone(), || {
+            CURRENT.with(|current| current.borrow().clone())
+        });
+        assert_eq!(inner, Some(context));
+        assert_eq!(CURRENT.with(|current| current.borrow().clone()), None);
+    }
+
+    // the emitter is global, so a single test installs it
+    #[test]
+    fn adds_the_header_and_emits_subsegments() {
+        let emitted = Arc::new(Mutex::new(Vec::new()));
+        let recorded = emitted.clone();
+        set_subsegment_emitter(move |subsegment: &Subsegment| {
+            recorded.lock().unwrap().push(subsegment.clone())
+        });
+
+        let mut request = SignedRequest::new("POST", "dynamodb", &Region::UsWest2, "/");
+        request.add_header("x-amz-target", "DynamoDB_20120810.ListTables");
+        let call = with_trace_context(TraceContext::parse(HEADER).unwrap(), || {
+            XrayCall::start(&mut request)
+        })
+        .unwrap();
+
+        let mut headers = HeaderMap::<String>::default();
+        headers.insert("x-amzn-requestid", "REQUESTID".to_owned());
+        let response = HttpResponse {
+            status: StatusCode::OK,
+            headers,
+            body: ByteStream::from(Vec::new()),
+        };
+        call.finish_with_response(&response, 2);
+        clear_subsegment_emitter();
+
+        let subsegment = emitted.lock().unwrap().pop().unwrap();
+        let header = String::from_utf8(request.headers[TRACE_HEADER][0].clone()).unwrap();
+        assert_eq!(
+            header,
+            format!(
+                "Root=1-5759e988-bd862e3fe1be46a994272793;Parent={};Sampled=1",
+                subsegment.id
+            )
+        );
+        assert_eq!(subsegment.parent_id, "53995c3f42cd8ad8");
+        assert_eq!(subsegment.service, "dynamodb");
+        assert_eq!(subsegment.operation, Some("ListTables".to_owned()));
+        assert_eq!(subsegment.region, "us-west-2");
+        assert_eq!(subsegment.http_status, Some(200));
+        assert_eq!(subsegment.request_id, Some("REQUESTID".to_owned()));
+        assert_eq!(subsegment.retries, 1);
+        assert!(subsegment.end_time >= subsegment.start_time);
+
+        // without an emitter, the context is passed on as it is
+        let mut request = SignedRequest::new("POST", "dynamodb", &Region::UsWest2, "/");
+        let call = with_trace_context(TraceContext::parse(HEADER).unwrap(), || {
+            XrayCall::start(&mut request)
+        });
+        assert!(call.is_none());
+        assert_eq!(request.headers[TRACE_HEADER][0], HEADER.as_bytes());
+    }
+}
@@ -1,16 +1,19 @@
-//! The Credentials provider to read from a task's IAM Role.
+//! The Credentials provider to read from a task's IAM Role, and a client for the metadata of the
+//! task.
 
 use std::error::Error;
 use std::time::Duration;
 
-use futures::future::{err, FutureResult};
+use futures::future::{err, result, FutureResult};
 use futures::{Async, Future, Poll};
-use hyper::{Body, Request};
+use hyper::{Body, Request, Uri};
 
+use crate::env::non_empty_var;
 use crate::request::{HttpClient, HttpClientFuture};
+use crate::unix_socket;
 use crate::{
-    non_empty_env_var, parse_credentials_from_aws_service, AwsCredentials, CredentialsError,
-    ProvideAwsCredentials,
+    parse_credentials_from_aws_service, AwsCredentials, CredentialsError, Environment,
+    ProvideAwsCredentials, SystemEnvironment,
 };
 
 // The following constants are documented in AWS' ECS developers guide,
@@ -21,6 +24,10 @@ const AWS_CONTAINER_CREDENTIALS_RELATIVE_URI: &str = "AWS_CONTAINER_CREDENTIALS_
 // environment variables, but they are used by the Java, Go, JavaScript and the Python SDKs.
 const AWS_CONTAINER_CREDENTIALS_FULL_URI: &str = "AWS_CONTAINER_CREDENTIALS_FULL_URI";
 const AWS_CONTAINER_AUTHORIZATION_TOKEN: &str = "AWS_CONTAINER_AUTHORIZATION_TOKEN";
+// The task metadata endpoint, version 4 on Fargate platform 1.4 and recent container agents,
+// version 3 before that.
+const ECS_CONTAINER_METADATA_URI_V4: &str = "ECS_CONTAINER_METADATA_URI_V4";
+const ECS_CONTAINER_METADATA_URI: &str = "ECS_CONTAINER_METADATA_URI";
 
 /// Provides AWS credentials from a task's IAM role.
 ///
@@ -34,7 +41,8 @@ const AWS_CONTAINER_AUTHORIZATION_TOKEN: &str = "AWS_CONTAINER_AUTHORIZATION_TOK
 /// be used to obtain the AWS credentials. If that environment variable is not set, rusoto will use
 /// the URL set in environment variable ```AWS_CONTAINER_CREDENTIALS_FULL_URI``` to obtain AWS
 /// credentials and will (optionally) also set the ```Authorization``` header to the value of
-/// environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN```.
+/// environment variable ```AWS_CONTAINER_AUTHORIZATION_TOKEN```. The full URL can name a Unix
+/// domain socket, e.g. `unix:///var/run/credentials.sock:/v1/credentials`.
 ///
 /// # Example
 ///
@@ -122,18 +130,18 @@ fn credentials_from_container(
     client: &HttpClient,
     timeout: Duration,
 ) -> Result<HttpClientFuture, CredentialsError> {
-    Ok(client.request(request_from_env_vars()?, timeout))
+    Ok(client.request(request_from_env_vars(&SystemEnvironment)?, timeout))
 }
 
-fn request_from_env_vars() -> Result<Request<Body>, CredentialsError> {
-    let relative_uri = non_empty_env_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI)
+fn request_from_env_vars(environment: &dyn Environment) -> Result<Request<Body>, CredentialsError> {
+    let relative_uri = non_empty_var(environment, AWS_CONTAINER_CREDENTIALS_RELATIVE_URI)
         .map(|path| format!("http://{}{}", AWS_CREDENTIALS_PROVIDER_IP, path));
     match relative_uri {
         Some(ref uri) => new_request(uri, AWS_CONTAINER_CREDENTIALS_RELATIVE_URI),
-        None => match non_empty_env_var(AWS_CONTAINER_CREDENTIALS_FULL_URI) {
+        None => match non_empty_var(environment, AWS_CONTAINER_CREDENTIALS_FULL_URI) {
             Some(ref uri) => {
                 let mut request = new_request(uri, AWS_CONTAINER_CREDENTIALS_FULL_URI)?;
-                if let Some(token) = non_empty_env_var(AWS_CONTAINER_AUTHORIZATION_TOKEN) {
+                if let Some(token) = non_empty_var(environment, AWS_CONTAINER_AUTHORIZATION_TOKEN) {
                     match token.parse() {
                         Ok(parsed_token) => {
                             request.headers_mut().insert("authorization", parsed_token);
@@ -157,33 +165,172 @@ fn request_from_env_vars() -> Result<Request<Body>, CredentialsError> {
 }
 
 fn new_request(uri: &str, env_var_name: &str) -> Result<Request<Body>, CredentialsError> {
-    Request::get(uri).body(Body::empty()).map_err(|error| {
+    let invalid = |error: &dyn Error| {
         CredentialsError::new(format!(
             "Error while parsing URI '{}' derived from environment variable '{}': {}",
             uri,
             env_var_name,
             error.description()
         ))
-    })
+    };
+    let parsed = unix_socket::parse_uri(uri).map_err(|error| invalid(&error))?;
+    Request::get(parsed)
+        .body(Body::empty())
+        .map_err(|error| invalid(&error))
+}
+
+/// Reads the metadata of the ECS task it runs in, from the task metadata endpoint named by the
+/// `ECS_CONTAINER_METADATA_URI_V4` or `ECS_CONTAINER_METADATA_URI` environment variable.
+///
+/// Like `ContainerProvider`, the client has a default timeout of 30 seconds.
+#[derive(Clone, Debug)]
+pub struct TaskMetadataClient {
+    client: HttpClient,
+    timeout: Duration,
+    metadata_uri: Option<String>,
+}
+
+impl TaskMetadataClient {
+    /// Create a new client for the task metadata endpoint of the environment of the process.
+    pub fn new() -> Self {
+        TaskMetadataClient::from_environment(&SystemEnvironment)
+    }
+
+    /// Create a new client for the task metadata endpoint named by `environment`.
+    pub fn from_environment(environment: &dyn Environment) -> Self {
+        TaskMetadataClient {
+            client: HttpClient::new(),
+            timeout: Duration::from_secs(30),
+            metadata_uri: non_empty_var(environment, ECS_CONTAINER_METADATA_URI_V4)
+                .or_else(|| non_empty_var(environment, ECS_CONTAINER_METADATA_URI)),
+        }
+    }
+
+    /// Set the timeout on the client to the specified duration.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Whether the environment names a task metadata endpoint, i.e. the process runs in an ECS
+    /// task.
+    pub fn is_available(&self) -> bool {
+        self.metadata_uri.is_some()
+    }
+
+    /// The ARN of the task, e.g.
+    /// `arn:aws:ecs:eu-west-1:123456789012:task/default/febee046097849aba589d4435207c04a`.
+    pub fn task_arn(&self) -> TaskMetadataFuture<String> {
+        let uri = match self.metadata_uri {
+            Some(ref uri) => format!("{}/task", uri.trim_end_matches('/')),
+            None => {
+                return TaskMetadataFuture::new(err(CredentialsError::new(format!(
+                    "Neither environment variable '{}' nor '{}' is set",
+                    ECS_CONTAINER_METADATA_URI_V4, ECS_CONTAINER_METADATA_URI
+                ))))
+            }
+        };
+        let client = self.client.clone();
+        let timeout = self.timeout;
+        let future = result(uri.parse::<Uri>().map_err(CredentialsError::new))
+            .and_then(move |uri| client.get(uri, timeout))
+            .and_then(|task| Ok(serde_json::from_str::<TaskMetadata>(&task)?.task_arn));
+        TaskMetadataFuture::new(future)
+    }
+
+    /// The name of the region the task runs in, e.g. `eu-west-1`, taken from its ARN.
+    pub fn region(&self) -> TaskMetadataFuture<String> {
+        TaskMetadataFuture::new(self.task_arn().and_then(|arn| {
+            region_from_arn(&arn)
+                .map(str::to_owned)
+                .ok_or_else(|| CredentialsError::new(format!("Malformed task ARN: {}", arn)))
+        }))
+    }
+}
+
+impl Default for TaskMetadataClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Deserialize)]
+struct TaskMetadata {
+    #[serde(rename = "TaskARN")]
+    task_arn: String,
+}
+
+/// Future returned from `TaskMetadataClient`.
+pub struct TaskMetadataFuture<T> {
+    inner: Box<dyn Future<Item = T, Error = CredentialsError> + Send>,
+}
+
+impl<T> TaskMetadataFuture<T> {
+    fn new<F>(future: F) -> Self
+    where
+        F: Future<Item = T, Error = CredentialsError> + Send + 'static,
+    {
+        TaskMetadataFuture {
+            inner: Box::new(future),
+        }
+    }
+}
+
+impl<T> Future for TaskMetadataFuture<T> {
+    type Item = T;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+/// The region of an ARN, `arn:partition:service:region:account:resource`.
+fn region_from_arn(arn: &str) -> Option<&str> {
+    let mut fields = arn.splitn(6, ':');
+    if fields.next() != Some("arn") {
+        return None;
+    }
+    fields.nth(2).filter(|region| !region.is_empty())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::lock_env;
-    use std::env;
+    use crate::VirtualEnvironment;
+
+    #[test]
+    fn task_metadata_endpoint_from_env_vars() {
+        let environment = VirtualEnvironment::new()
+            .with_var(ECS_CONTAINER_METADATA_URI, "http://169.254.170.2/v3/abc")
+            .with_var(ECS_CONTAINER_METADATA_URI_V4, "http://169.254.170.2/v4/abc");
+        let client = TaskMetadataClient::from_environment(&environment);
+        assert_eq!(
+            client.metadata_uri,
+            Some("http://169.254.170.2/v4/abc".to_owned())
+        );
+        assert!(!TaskMetadataClient::from_environment(&VirtualEnvironment::new()).is_available());
+    }
+
+    #[test]
+    fn region_of_task_arns() {
+        assert_eq!(
+            region_from_arn(
+                "arn:aws:ecs:eu-west-1:123456789012:task/default/febee046097849aba589d4435207c04a"
+            ),
+            Some("eu-west-1")
+        );
+        assert_eq!(region_from_arn("arn:aws:iam::123456789012:role/x"), None);
+        assert_eq!(region_from_arn("not-an-arn"), None);
+    }
 
     #[test]
     fn request_from_relative_uri() {
         let path = "/xxx";
-        let _guard = lock_env();
-        env::set_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI, path);
-        env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, "dummy");
-        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "dummy");
-        let result = request_from_env_vars();
-        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
-        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
-        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
+        let environment = VirtualEnvironment::new()
+            .with_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI, path)
+            .with_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, "dummy")
+            .with_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "dummy");
+        let result = request_from_env_vars(&environment);
         assert!(result.is_ok());
         let request = result.ok().unwrap();
         assert_eq!(request.uri().path(), path);
@@ -192,36 +339,28 @@ mod tests {
 
     #[test]
     fn error_from_missing_env_vars() {
-        let _guard = lock_env();
-        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
-        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
-        let result = request_from_env_vars();
+        let environment = VirtualEnvironment::new();
+        let result = request_from_env_vars(&environment);
         assert!(result.is_err());
     }
 
     #[test]
     fn error_from_empty_env_vars() {
-        let _guard = lock_env();
-        env::set_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI, "");
-        env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, "");
-        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "");
-        let result = request_from_env_vars();
-        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
-        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
-        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
+        let environment = VirtualEnvironment::new()
+            .with_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI, "")
+            .with_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, "")
+            .with_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "");
+        let result = request_from_env_vars(&environment);
         assert!(result.is_err());
     }
 
     #[test]
     fn request_from_full_uri_with_token() {
         let url = "http://localhost/xxx";
-        let _guard = lock_env();
-        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
-        env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url);
-        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "dummy");
-        let result = request_from_env_vars();
-        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
-        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
+        let environment = VirtualEnvironment::new()
+            .with_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url)
+            .with_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "dummy");
+        let result = request_from_env_vars(&environment);
         assert!(result.is_ok());
         let request = result.ok().unwrap();
         assert_eq!(request.uri().to_string(), url);
@@ -231,28 +370,35 @@ mod tests {
     #[test]
     fn request_from_full_uri_without_token() {
         let url = "http://localhost/xxx";
-        let _guard = lock_env();
-        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
-        env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url);
-        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
-        let result = request_from_env_vars();
-        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
+        let environment =
+            VirtualEnvironment::new().with_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url);
+        let result = request_from_env_vars(&environment);
         assert!(result.is_ok());
         let request = result.ok().unwrap();
         assert_eq!(request.uri().to_string(), url);
         assert_eq!(request.headers().contains_key("authorization"), false);
     }
 
+    #[test]
+    fn request_from_full_uri_of_unix_socket() {
+        let environment = VirtualEnvironment::new().with_var(
+            AWS_CONTAINER_CREDENTIALS_FULL_URI,
+            "unix:///tmp/c.sock:/creds",
+        );
+        let request = request_from_env_vars(&environment).unwrap();
+        assert_eq!(
+            request.uri().to_string(),
+            "unix://2f746d702f632e736f636b/creds"
+        );
+    }
+
     #[test]
     fn request_from_full_uri_with_empty_token() {
         let url = "http://localhost/xxx";
-        let _guard = lock_env();
-        env::remove_var(AWS_CONTAINER_CREDENTIALS_RELATIVE_URI);
-        env::set_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url);
-        env::set_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "");
-        let result = request_from_env_vars();
-        env::remove_var(AWS_CONTAINER_CREDENTIALS_FULL_URI);
-        env::remove_var(AWS_CONTAINER_AUTHORIZATION_TOKEN);
+        let environment = VirtualEnvironment::new()
+            .with_var(AWS_CONTAINER_CREDENTIALS_FULL_URI, url)
+            .with_var(AWS_CONTAINER_AUTHORIZATION_TOKEN, "");
+        let result = request_from_env_vars(&environment);
         assert!(result.is_ok());
         let request = result.ok().unwrap();
         assert_eq!(request.uri().to_string(), url);
@@ -0,0 +1,245 @@
+//! The Credentials provider to read from a credentials endpoint.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use futures::future::{err, FutureResult};
+use futures::{Async, Future, Poll};
+use hyper::{Body, Request};
+
+use crate::request::{HttpClient, HttpClientFuture};
+use crate::unix_socket;
+use crate::{
+    parse_credentials_from_aws_service, AwsCredentials, CredentialsError, ProvideAwsCredentials,
+};
+
+/// Provides AWS credentials from an HTTP endpoint.
+///
+/// The endpoint is sent a `GET` request and must answer with the JSON document returned by the
+/// ECS and EC2 metadata services (`AccessKeyId`, `SecretAccessKey`, `Token` and `Expiration`).
+/// This is useful for custom credential brokers, outside of ECS. Use `ContainerProvider` inside
+/// ECS tasks.
+///
+/// The request optionally carries an `Authorization` header, set either to a fixed token or to
+/// the contents of a token file. The file is read on every request, so the token can be rotated.
+///
+/// The provider has a default timeout of 30 seconds. While it should work well for most setups,
+/// you can change the timeout using the `set_timeout` method.
+///
+/// `ChainProvider` does not use this provider unless it is added with `set_endpoint_provider`.
+///
+/// # Example
+///
+/// ```rust
+/// extern crate rusoto_credential;
+///
+/// use rusoto_credential::{ChainProvider, EndpointCredentialsProvider};
+///
+/// fn main() {
+///   let mut provider = EndpointCredentialsProvider::new("http://localhost:8080/credentials");
+///   provider.set_authorization_token_file("/var/run/secrets/credentials-token");
+///
+///   let mut chain = ChainProvider::new();
+///   chain.set_endpoint_provider(provider);
+///
+///   // ...
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct EndpointCredentialsProvider {
+    client: HttpClient,
+    url: String,
+    authorization: Option<Authorization>,
+    timeout: Duration,
+}
+
+#[derive(Clone, Debug)]
+enum Authorization {
+    Token(String),
+    TokenFile(PathBuf),
+}
+
+impl EndpointCredentialsProvider {
+    /// Create a new provider fetching credentials from `url`.
+    ///
+    /// `url` can name a Unix domain socket, e.g. `unix:///var/run/credentials.sock`, optionally
+    /// followed by `:` and the path of the credentials, e.g. `unix:///var/run/c.sock:/creds`.
+    pub fn new<S>(url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        EndpointCredentialsProvider {
+            client: HttpClient::new(),
+            url: url.into(),
+            authorization: None,
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Send `token` in the `Authorization` header.
+    pub fn set_authorization_token<S>(&mut self, token: S)
+    where
+        S: Into<String>,
+    {
+        self.authorization = Some(Authorization::Token(token.into()));
+    }
+
+    /// Send the contents of the file at `path` in the `Authorization` header.
+    pub fn set_authorization_token_file<P>(&mut self, path: P)
+    where
+        P: AsRef<Path>,
+    {
+        self.authorization = Some(Authorization::TokenFile(path.as_ref().to_path_buf()));
+    }
+
+    /// Set the timeout on the provider to the specified duration.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// The URL credentials are fetched from.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn request(&self) -> Result<Request<Body>, CredentialsError> {
+        let invalid = |error: &dyn fmt::Display| {
+            CredentialsError::new(format!(
+                "Error while parsing credentials endpoint URI '{}': {}",
+                self.url, error
+            ))
+        };
+        let uri = unix_socket::parse_uri(&self.url).map_err(|error| invalid(&error))?;
+        let mut request = Request::get(uri)
+            .body(Body::empty())
+            .map_err(|error| invalid(&error))?;
+        let token = match self.authorization {
+            Some(Authorization::Token(ref token)) => token.clone(),
+            Some(Authorization::TokenFile(ref path)) => {
+                fs::read_to_string(path).map_err(|error| {
+                    CredentialsError::new(format!(
+                        "Couldn't read authorization token file {}: {}",
+                        path.display(),
+                        error
+                    ))
+                })?
+            }
+            None => return Ok(request),
+        };
+        let token = token.trim();
+        if !token.is_empty() {
+            let value = token.parse().map_err(|error| {
+                CredentialsError::new(format!("failed to parse token: {}", error))
+            })?;
+            request.headers_mut().insert("authorization", value);
+        }
+        Ok(request)
+    }
+}
+
+/// Future returned from `EndpointCredentialsProvider`.
+pub struct EndpointCredentialsProviderFuture {
+    inner: EndpointCredentialsProviderFutureInner,
+}
+
+enum EndpointCredentialsProviderFutureInner {
+    Result(FutureResult<String, CredentialsError>),
+    Future(HttpClientFuture),
+}
+
+impl Future for EndpointCredentialsProviderFuture {
+    type Item = AwsCredentials;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let resp = match self.inner {
+            EndpointCredentialsProviderFutureInner::Result(ref mut result) => {
+                try_ready!(result.poll())
+            }
+            EndpointCredentialsProviderFutureInner::Future(ref mut future) => {
+                try_ready!(future.poll())
+            }
+        };
+        let creds = parse_credentials_from_aws_service(&resp)?;
+        Ok(Async::Ready(creds))
+    }
+}
+
+impl ProvideAwsCredentials for EndpointCredentialsProvider {
+    type Future = EndpointCredentialsProviderFuture;
+
+    fn credentials(&self) -> Self::Future {
+        let inner = match self.request() {
+            Ok(request) => EndpointCredentialsProviderFutureInner::Future(
+                self.client.request(request, self.timeout),
+            ),
+            Err(e) => EndpointCredentialsProviderFutureInner::Result(err(e)),
+        };
+        EndpointCredentialsProviderFuture { inner }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::io::Write;
+
+    #[test]
+    fn request_without_authorization() {
+        let provider = EndpointCredentialsProvider::new("http://localhost:8080/credentials");
+        let request = provider.request().unwrap();
+        assert_eq!(
+            request.uri().to_string(),
+            "http://localhost:8080/credentials"
+        );
+        assert_eq!(request.headers().contains_key("authorization"), false);
+    }
+
+    #[test]
+    fn request_to_unix_socket() {
+        let provider =
+            EndpointCredentialsProvider::new("unix:///tmp/credentials.sock:/v1/credentials");
+        let request = provider.request().unwrap();
+        assert_eq!(
+            request.uri().to_string(),
+            "unix://2f746d702f63726564656e7469616c732e736f636b/v1/credentials"
+        );
+    }
+
+    #[test]
+    fn request_with_token() {
+        let mut provider = EndpointCredentialsProvider::new("http://localhost/xxx");
+        provider.set_authorization_token("Bearer abc");
+        let request = provider.request().unwrap();
+        assert_eq!(
+            request.headers().get("authorization").unwrap(),
+            "Bearer abc"
+        );
+    }
+
+    #[test]
+    fn request_with_token_file() {
+        let path = env::temp_dir().join("rusoto_endpoint_credentials_token");
+        let mut file = fs::File::create(&path).unwrap();
+        writeln!(file, "secret-token").unwrap();
+
+        let mut provider = EndpointCredentialsProvider::new("http://localhost/xxx");
+        provider.set_authorization_token_file(&path);
+        let request = provider.request();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            request.unwrap().headers().get("authorization").unwrap(),
+            "secret-token"
+        );
+    }
+
+    #[test]
+    fn error_from_missing_token_file() {
+        let mut provider = EndpointCredentialsProvider::new("http://localhost/xxx");
+        provider.set_authorization_token_file("/nonexistent/rusoto/token");
+        assert!(provider.request().is_err());
+    }
+}
@@ -0,0 +1,130 @@
+//! Access to the environment credentials and configuration are read from.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use dirs::home_dir;
+
+/// The environment variables and home directory providers read their configuration from.
+///
+/// `SystemEnvironment` reads the environment of the current process. Providers can be given a
+/// `VirtualEnvironment` instead, so that tests and embedders don't have to modify the process
+/// environment, which is shared by every thread.
+pub trait Environment: Debug + Send + Sync {
+    /// Returns the value of the variable `name`, or `None` if it is unset or not valid unicode.
+    fn var(&self, name: &str) -> Option<String>;
+
+    /// Returns the home directory of the current user, if it is known.
+    fn home_dir(&self) -> Option<PathBuf>;
+}
+
+impl<E: Environment + ?Sized> Environment for Arc<E> {
+    fn var(&self, name: &str) -> Option<String> {
+        (**self).var(name)
+    }
+
+    fn home_dir(&self) -> Option<PathBuf> {
+        (**self).home_dir()
+    }
+}
+
+/// The environment of the current process, as returned by `std::env`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemEnvironment;
+
+impl Environment for SystemEnvironment {
+    fn var(&self, name: &str) -> Option<String> {
+        std::env::var(name).ok()
+    }
+
+    fn home_dir(&self) -> Option<PathBuf> {
+        home_dir()
+    }
+}
+
+/// An environment with a fixed set of variables and home directory.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate futures;
+/// # extern crate rusoto_credential;
+/// #
+/// # fn main() {
+/// use futures::future::Future;
+/// use rusoto_credential::{EnvironmentProvider, ProvideAwsCredentials, VirtualEnvironment};
+///
+/// let environment = VirtualEnvironment::new()
+///     .with_var("AWS_ACCESS_KEY_ID", "ANTN35UAENTS5UIAEATD")
+///     .with_var("AWS_SECRET_ACCESS_KEY", "TtnuieannGt2rGuie2t8Tt7urarg5nauedRndrur");
+///
+/// let creds = EnvironmentProvider::with_environment(environment)
+///     .credentials()
+///     .wait()
+///     .unwrap();
+///
+/// assert_eq!(creds.aws_access_key_id(), "ANTN35UAENTS5UIAEATD");
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct VirtualEnvironment {
+    vars: HashMap<String, String>,
+    home_dir: Option<PathBuf>,
+}
+
+impl VirtualEnvironment {
+    /// Create an empty `VirtualEnvironment` without a home directory.
+    pub fn new() -> VirtualEnvironment {
+        VirtualEnvironment::default()
+    }
+
+    /// Returns the environment with the variable `name` set to `value`.
+    pub fn with_var<N, V>(mut self, name: N, value: V) -> VirtualEnvironment
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.set_var(name, value);
+        self
+    }
+
+    /// Returns the environment with its home directory set to `home_dir`.
+    pub fn with_home_dir<P>(mut self, home_dir: P) -> VirtualEnvironment
+    where
+        P: Into<PathBuf>,
+    {
+        self.home_dir = Some(home_dir.into());
+        self
+    }
+
+    /// Set the variable `name` to `value`.
+    pub fn set_var<N, V>(&mut self, name: N, value: V)
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.vars.insert(name.into(), value.into());
+    }
+
+    /// Unset the variable `name`.
+    pub fn remove_var(&mut self, name: &str) {
+        self.vars.remove(name);
+    }
+}
+
+impl Environment for VirtualEnvironment {
+    fn var(&self, name: &str) -> Option<String> {
+        self.vars.get(name).cloned()
+    }
+
+    fn home_dir(&self) -> Option<PathBuf> {
+        self.home_dir.clone()
+    }
+}
+
+/// Returns the value of the variable `name`, or `None` if it is unset or empty.
+pub(crate) fn non_empty_var(environment: &dyn Environment, name: &str) -> Option<String> {
+    environment.var(name).filter(|value| !value.is_empty())
+}
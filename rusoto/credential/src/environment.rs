@@ -1,11 +1,16 @@
 //! The Credentials Provider to read from Environment Variables.
 
+use std::sync::Arc;
+
 use chrono::{DateTime, FixedOffset, Utc};
 
 use futures::future::{result, FutureResult};
 use futures::{Future, Poll};
 
-use crate::{non_empty_env_var, AwsCredentials, CredentialsError, ProvideAwsCredentials};
+use crate::env::non_empty_var;
+use crate::{
+    AwsCredentials, CredentialsError, Environment, ProvideAwsCredentials, SystemEnvironment,
+};
 
 /// Provides AWS credentials from environment variables.
 ///
@@ -28,6 +33,9 @@ use crate::{non_empty_env_var, AwsCredentials, CredentialsError, ProvideAwsCrede
 ///   Expiration time in RFC 3339 format (e.g. `1996-12-19T16:39:57-08:00`). If unset, credentials
 ///   won't expire.
 ///
+/// Variables are read from the environment of the process unless the provider is given another
+/// `Environment`, see `EnvironmentProvider::with_environment`.
+///
 /// # Example
 ///
 /// ```rust
@@ -59,13 +67,12 @@ use crate::{non_empty_env_var, AwsCredentials, CredentialsError, ProvideAwsCrede
 #[derive(Debug, Clone)]
 pub struct EnvironmentProvider {
     prefix: String,
+    environment: Arc<dyn Environment>,
 }
 
 impl Default for EnvironmentProvider {
     fn default() -> Self {
-        EnvironmentProvider {
-            prefix: "AWS".to_owned(),
-        }
+        EnvironmentProvider::with_prefix("AWS")
     }
 }
 
@@ -100,8 +107,28 @@ impl EnvironmentProvider {
     pub fn with_prefix(prefix: &str) -> Self {
         EnvironmentProvider {
             prefix: prefix.to_owned(),
+            environment: Arc::new(SystemEnvironment),
         }
     }
+
+    /// Create an EnvironmentProvider which reads the standard variables from `environment`
+    /// instead of the environment of the process.
+    pub fn with_environment<E>(environment: E) -> Self
+    where
+        E: Environment + 'static,
+    {
+        let mut provider = EnvironmentProvider::default();
+        provider.set_environment(environment);
+        provider
+    }
+
+    /// Set the environment variables are read from.
+    pub fn set_environment<E>(&mut self, environment: E)
+    where
+        E: Environment + 'static,
+    {
+        self.environment = Arc::new(environment);
+    }
 }
 
 /// A private trait for building the environment variable names based
@@ -110,6 +137,8 @@ impl EnvironmentProvider {
 trait EnvironmentVariableProvider {
     fn prefix(&self) -> &str;
 
+    fn environment(&self) -> &dyn Environment;
+
     fn access_key_id_var(&self) -> String {
         format!("{}_ACCESS_KEY_ID", self.prefix())
     }
@@ -131,6 +160,10 @@ impl EnvironmentVariableProvider for EnvironmentProvider {
     fn prefix(&self) -> &str {
         self.prefix.as_str()
     }
+
+    fn environment(&self) -> &dyn Environment {
+        &*self.environment
+    }
 }
 
 /// Provides AWS credentials from environment variables as a Future.
@@ -161,13 +194,14 @@ impl ProvideAwsCredentials for EnvironmentProvider {
 fn credentials_from_environment(
     provider: &dyn EnvironmentVariableProvider,
 ) -> Result<AwsCredentials, CredentialsError> {
-    let env_key = get_critical_variable(provider.access_key_id_var())?;
-    let env_secret = get_critical_variable(provider.secret_access_key_var())?;
+    let environment = provider.environment();
+    let env_key = get_critical_variable(environment, provider.access_key_id_var())?;
+    let env_secret = get_critical_variable(environment, provider.secret_access_key_var())?;
     // Present when using temporary credentials, e.g. on Lambda with IAM roles
-    let token = non_empty_env_var(&provider.session_token_var());
+    let token = non_empty_var(environment, &provider.session_token_var());
     // Mimic botocore's behavior, see https://github.com/boto/botocore/pull/1187.
     let var_name = provider.credential_expiration_var();
-    let expires_at = match non_empty_env_var(&var_name) {
+    let expires_at = match non_empty_var(environment, &var_name) {
         Some(val) => Some(
             DateTime::<FixedOffset>::parse_from_rfc3339(&val)
                 .map(|dt| dt.with_timezone(&Utc))
@@ -184,17 +218,19 @@ fn credentials_from_environment(
 }
 
 /// Force an error if we do not see the particular variable name in the env.
-fn get_critical_variable(var_name: String) -> Result<String, CredentialsError> {
-    non_empty_env_var(&var_name)
+fn get_critical_variable(
+    environment: &dyn Environment,
+    var_name: String,
+) -> Result<String, CredentialsError> {
+    non_empty_var(environment, &var_name)
         .ok_or_else(|| CredentialsError::new(format!("No (or empty) {} in environment", var_name)))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::lock_env;
+    use crate::VirtualEnvironment;
     use chrono::Utc;
-    use std::env;
 
     static AWS_ACCESS_KEY_ID: &str = "AWS_ACCESS_KEY_ID";
     static AWS_SECRET_ACCESS_KEY: &str = "AWS_SECRET_ACCESS_KEY";
@@ -207,14 +243,13 @@ mod tests {
 
     #[test]
     fn get_temporary_credentials_from_env() {
-        let _guard = lock_env();
-        env::set_var(AWS_ACCESS_KEY_ID, "id");
-        env::set_var(AWS_SECRET_ACCESS_KEY, "secret");
-        env::set_var(AWS_SESSION_TOKEN, "token");
-        let result = EnvironmentProvider::default().credentials().wait();
-        env::remove_var(AWS_ACCESS_KEY_ID);
-        env::remove_var(AWS_SECRET_ACCESS_KEY);
-        env::remove_var(AWS_SESSION_TOKEN);
+        let environment = VirtualEnvironment::new()
+            .with_var(AWS_ACCESS_KEY_ID, "id")
+            .with_var(AWS_SECRET_ACCESS_KEY, "secret")
+            .with_var(AWS_SESSION_TOKEN, "token");
+        let result = EnvironmentProvider::with_environment(environment)
+            .credentials()
+            .wait();
         assert!(result.is_ok());
         let creds = result.ok().unwrap();
         assert_eq!(creds.aws_access_key_id(), "id");
@@ -224,13 +259,12 @@ mod tests {
 
     #[test]
     fn get_non_temporary_credentials_from_env() {
-        let _guard = lock_env();
-        env::set_var(AWS_ACCESS_KEY_ID, "id");
-        env::set_var(AWS_SECRET_ACCESS_KEY, "secret");
-        env::remove_var(AWS_SESSION_TOKEN);
-        let result = EnvironmentProvider::default().credentials().wait();
-        env::remove_var(AWS_ACCESS_KEY_ID);
-        env::remove_var(AWS_SECRET_ACCESS_KEY);
+        let environment = VirtualEnvironment::new()
+            .with_var(AWS_ACCESS_KEY_ID, "id")
+            .with_var(AWS_SECRET_ACCESS_KEY, "secret");
+        let result = EnvironmentProvider::with_environment(environment)
+            .credentials()
+            .wait();
         assert!(result.is_ok());
         let creds = result.ok().unwrap();
         assert_eq!(creds.aws_access_key_id(), "id");
@@ -240,12 +274,10 @@ mod tests {
 
     #[test]
     fn environment_provider_missing_key_id() {
-        let _guard = lock_env();
-        env::remove_var(AWS_ACCESS_KEY_ID);
-        env::set_var(AWS_SECRET_ACCESS_KEY, "secret");
-        env::remove_var(AWS_SESSION_TOKEN);
-        let result = EnvironmentProvider::default().credentials().wait();
-        env::remove_var(AWS_SECRET_ACCESS_KEY);
+        let environment = VirtualEnvironment::new().with_var(AWS_SECRET_ACCESS_KEY, "secret");
+        let result = EnvironmentProvider::with_environment(environment)
+            .credentials()
+            .wait();
         assert!(result.is_err());
         assert_eq!(
             result.err(),
@@ -255,12 +287,10 @@ mod tests {
 
     #[test]
     fn environment_provider_missing_secret() {
-        let _guard = lock_env();
-        env::remove_var(AWS_SECRET_ACCESS_KEY);
-        env::set_var(AWS_ACCESS_KEY_ID, "id");
-        env::remove_var(AWS_SESSION_TOKEN);
-        let result = EnvironmentProvider::default().credentials().wait();
-        env::remove_var(AWS_ACCESS_KEY_ID);
+        let environment = VirtualEnvironment::new().with_var(AWS_ACCESS_KEY_ID, "id");
+        let result = EnvironmentProvider::with_environment(environment)
+            .credentials()
+            .wait();
         assert!(result.is_err());
         assert_eq!(
             result.err(),
@@ -270,11 +300,9 @@ mod tests {
 
     #[test]
     fn environment_provider_missing_credentials() {
-        let _guard = lock_env();
-        env::remove_var(AWS_SECRET_ACCESS_KEY);
-        env::remove_var(AWS_ACCESS_KEY_ID);
-        env::remove_var(AWS_SESSION_TOKEN);
-        let result = EnvironmentProvider::default().credentials().wait();
+        let result = EnvironmentProvider::with_environment(VirtualEnvironment::new())
+            .credentials()
+            .wait();
         assert!(result.is_err());
         assert_eq!(
             result.err(),
@@ -282,18 +310,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn environment_provider_empty_credentials() {
+        let environment = VirtualEnvironment::new()
+            .with_var(AWS_ACCESS_KEY_ID, "")
+            .with_var(AWS_SECRET_ACCESS_KEY, "secret");
+        let result = EnvironmentProvider::with_environment(environment)
+            .credentials()
+            .wait();
+        assert_eq!(
+            result.err(),
+            Some(CredentialsError::new(E_NO_ACCESS_KEY_ID))
+        );
+    }
+
     #[test]
     fn environment_provider_bad_expiration() {
-        let _guard = lock_env();
-        env::set_var(AWS_ACCESS_KEY_ID, "id");
-        env::set_var(AWS_SECRET_ACCESS_KEY, "secret");
-        env::set_var(AWS_SESSION_TOKEN, "token");
-        env::set_var(AWS_CREDENTIAL_EXPIRATION, "lore ipsum");
-        let result = EnvironmentProvider::default().credentials().wait();
-        env::remove_var(AWS_ACCESS_KEY_ID);
-        env::remove_var(AWS_SECRET_ACCESS_KEY);
-        env::remove_var(AWS_SESSION_TOKEN);
-        env::remove_var(AWS_CREDENTIAL_EXPIRATION);
+        let environment = VirtualEnvironment::new()
+            .with_var(AWS_ACCESS_KEY_ID, "id")
+            .with_var(AWS_SECRET_ACCESS_KEY, "secret")
+            .with_var(AWS_SESSION_TOKEN, "token")
+            .with_var(AWS_CREDENTIAL_EXPIRATION, "lore ipsum");
+        let result = EnvironmentProvider::with_environment(environment)
+            .credentials()
+            .wait();
         assert!(result.is_err());
         assert!(match &result.err() {
             &Some(CredentialsError { ref message }) => message.starts_with(E_INVALID_EXPIRATION),
@@ -303,18 +343,15 @@ mod tests {
 
     #[test]
     fn get_temporary_credentials_with_expiration_from_env() {
-        let _guard = lock_env();
         let now = Utc::now();
-        let now_str = now.to_rfc3339();
-        env::set_var(AWS_ACCESS_KEY_ID, "id");
-        env::set_var(AWS_SECRET_ACCESS_KEY, "secret");
-        env::set_var(AWS_SESSION_TOKEN, "token");
-        env::set_var(AWS_CREDENTIAL_EXPIRATION, now_str);
-        let result = EnvironmentProvider::default().credentials().wait();
-        env::remove_var(AWS_ACCESS_KEY_ID);
-        env::remove_var(AWS_SECRET_ACCESS_KEY);
-        env::remove_var(AWS_SESSION_TOKEN);
-        env::remove_var(AWS_CREDENTIAL_EXPIRATION);
+        let environment = VirtualEnvironment::new()
+            .with_var(AWS_ACCESS_KEY_ID, "id")
+            .with_var(AWS_SECRET_ACCESS_KEY, "secret")
+            .with_var(AWS_SESSION_TOKEN, "token")
+            .with_var(AWS_CREDENTIAL_EXPIRATION, now.to_rfc3339());
+        let result = EnvironmentProvider::with_environment(environment)
+            .credentials()
+            .wait();
         assert!(result.is_ok());
         let creds = result.ok().unwrap();
         assert_eq!(creds.aws_access_key_id(), "id");
@@ -325,16 +362,15 @@ mod tests {
 
     #[test]
     fn regression_test_rfc_3339_compat() {
-        let _guard = lock_env();
         // RFC 3339 expiration times with lower case 't' could not be parsed by earlier
         // implementations.
-        env::set_var(AWS_CREDENTIAL_EXPIRATION, "1996-12-19t16:39:57-08:00");
-        env::set_var(AWS_ACCESS_KEY_ID, "id");
-        env::set_var(AWS_SECRET_ACCESS_KEY, "secret");
-        let result = EnvironmentProvider::default().credentials().wait();
-        env::remove_var(AWS_CREDENTIAL_EXPIRATION);
-        env::remove_var(AWS_ACCESS_KEY_ID);
-        env::remove_var(AWS_SECRET_ACCESS_KEY);
+        let environment = VirtualEnvironment::new()
+            .with_var(AWS_CREDENTIAL_EXPIRATION, "1996-12-19t16:39:57-08:00")
+            .with_var(AWS_ACCESS_KEY_ID, "id")
+            .with_var(AWS_SECRET_ACCESS_KEY, "secret");
+        let result = EnvironmentProvider::with_environment(environment)
+            .credentials()
+            .wait();
 
         assert_eq!(
             result.unwrap().expires_at().unwrap().to_rfc3339(),
@@ -344,24 +380,15 @@ mod tests {
 
     #[test]
     fn alternative_prefix() {
-        // NOTE: not strictly neccessary here, since we are using a non-standard
-        // prefix, so we shouldn't collide with the other env interactions in
-        // the other tests.
-        let _guard = lock_env();
-
         let now = Utc::now();
-        let now_str = now.to_rfc3339();
-        env::set_var("MYAPP_ACCESS_KEY_ID", "id");
-        env::set_var("MYAPP_SECRET_ACCESS_KEY", "secret");
-        env::set_var("MYAPP_SESSION_TOKEN", "token");
-        env::set_var("MYAPP_CREDENTIAL_EXPIRATION", now_str);
-        let result = EnvironmentProvider::with_prefix("MYAPP")
-            .credentials()
-            .wait();
-        env::remove_var("MYAPP_ACCESS_KEY_ID");
-        env::remove_var("MYAPP_SECRET_ACCESS_KEY");
-        env::remove_var("MYAPP_SESSION_TOKEN");
-        env::remove_var("MYAPP_CREDENTIAL_EXPIRATION");
+        let environment = VirtualEnvironment::new()
+            .with_var("MYAPP_ACCESS_KEY_ID", "id")
+            .with_var("MYAPP_SECRET_ACCESS_KEY", "secret")
+            .with_var("MYAPP_SESSION_TOKEN", "token")
+            .with_var("MYAPP_CREDENTIAL_EXPIRATION", now.to_rfc3339());
+        let mut provider = EnvironmentProvider::with_prefix("MYAPP");
+        provider.set_environment(environment);
+        let result = provider.credentials().wait();
         assert!(result.is_ok());
         let creds = result.ok().unwrap();
         assert_eq!(creds.aws_access_key_id(), "id");
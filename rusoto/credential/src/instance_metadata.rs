@@ -1,8 +1,10 @@
-//! The Credentials Provider for an AWS Resource's IAM Role.
+//! The Credentials Provider for an AWS Resource's IAM Role, and a client for the rest of the
+//! instance metadata.
 
+use std::collections::BTreeMap;
 use std::time::Duration;
 
-use futures::future::{result, FutureResult};
+use futures::future::{join_all, result, FutureResult};
 use futures::{Future, Poll};
 use hyper::Uri;
 
@@ -13,6 +15,9 @@ use crate::{
 
 const AWS_CREDENTIALS_PROVIDER_IP: &str = "169.254.169.254";
 const AWS_CREDENTIALS_PROVIDER_PATH: &str = "latest/meta-data/iam/security-credentials";
+const INSTANCE_ID_PATH: &str = "latest/meta-data/instance-id";
+const IDENTITY_DOCUMENT_PATH: &str = "latest/dynamic/instance-identity/document";
+const TAGS_PATH: &str = "latest/meta-data/tags/instance";
 
 /// Provides AWS credentials from a resource's IAM role.
 ///
@@ -80,6 +85,16 @@ impl InstanceMetadataProvider {
     pub fn set_ip_addr_with_port(&mut self, ip: &str, port: &str) {
         self.metadata_ip_addr = format!("{}:{}", ip, port.to_string());
     }
+
+    /// A client for the rest of the instance metadata, with the timeout and address of this
+    /// provider.
+    pub fn metadata_client(&self) -> InstanceMetadataClient {
+        InstanceMetadataClient {
+            client: self.client.clone(),
+            timeout: self.timeout,
+            metadata_ip_addr: self.metadata_ip_addr.clone(),
+        }
+    }
 }
 
 impl Default for InstanceMetadataProvider {
@@ -151,6 +166,159 @@ impl ProvideAwsCredentials for InstanceMetadataProvider {
     }
 }
 
+/// Reads the metadata of the EC2 instance it runs on: its ID, its identity document, such as its
+/// region and account, and its tags.
+///
+/// Like `InstanceMetadataProvider`, the client has a default timeout of 30 seconds and asks
+/// 169.254.169.254, both of which can be changed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// extern crate futures;
+/// extern crate rusoto_credential;
+///
+/// use futures::Future;
+/// use rusoto_credential::InstanceMetadataClient;
+///
+/// fn main() {
+///   let client = InstanceMetadataClient::new();
+///   let document = client.identity_document().wait().unwrap();
+///   println!("{} runs in {}", document.instance_id, document.region);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct InstanceMetadataClient {
+    client: HttpClient,
+    timeout: Duration,
+    metadata_ip_addr: String,
+}
+
+impl InstanceMetadataClient {
+    /// Create a new client.
+    pub fn new() -> Self {
+        InstanceMetadataProvider::new().metadata_client()
+    }
+
+    /// Set the timeout on the client to the specified duration.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Allow overriding host and port of instance metadata service.
+    pub fn set_ip_addr_with_port(&mut self, ip: &str, port: &str) {
+        self.metadata_ip_addr = format!("{}:{}", ip, port);
+    }
+
+    /// The ID of the instance, e.g. `i-0123456789abcdef0`.
+    pub fn instance_id(&self) -> InstanceMetadataFuture<String> {
+        InstanceMetadataFuture::new(self.get(INSTANCE_ID_PATH).map(|id| id.trim().to_owned()))
+    }
+
+    /// The identity document of the instance.
+    pub fn identity_document(&self) -> InstanceMetadataFuture<InstanceIdentityDocument> {
+        InstanceMetadataFuture::new(
+            self.get(IDENTITY_DOCUMENT_PATH)
+                .and_then(|document| Ok(serde_json::from_str(&document)?)),
+        )
+    }
+
+    /// The name of the region the instance runs in, e.g. `eu-west-1`.
+    pub fn region(&self) -> InstanceMetadataFuture<String> {
+        InstanceMetadataFuture::new(self.identity_document().map(|document| document.region))
+    }
+
+    /// The tags of the instance.
+    ///
+    /// The tags are only in the instance metadata if the instance was launched or modified with
+    /// `InstanceMetadataTags` enabled; otherwise this fails with a 404 response code.
+    pub fn tags(&self) -> InstanceMetadataFuture<BTreeMap<String, String>> {
+        let client = self.clone();
+        let future = self.get(TAGS_PATH).and_then(move |keys| {
+            join_all(tag_keys(&keys).into_iter().map(move |key| {
+                client
+                    .get(&format!("{}/{}", TAGS_PATH, key))
+                    .map(move |value| (key, value))
+            }))
+            .map(|tags| tags.into_iter().collect())
+        });
+        InstanceMetadataFuture::new(future)
+    }
+
+    fn get(&self, path: &str) -> impl Future<Item = String, Error = CredentialsError> + Send {
+        let uri = format!("http://{}/{}", self.metadata_ip_addr, path)
+            .parse::<Uri>()
+            .map_err(CredentialsError::new);
+        let client = self.client.clone();
+        let timeout = self.timeout;
+        result(uri).and_then(move |uri| client.get(uri, timeout))
+    }
+}
+
+impl Default for InstanceMetadataClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The identity document of an EC2 instance, from
+/// `http://169.254.169.254/latest/dynamic/instance-identity/document`.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InstanceIdentityDocument {
+    /// The ID of the account which launched the instance.
+    pub account_id: String,
+    /// The architecture of the instance, e.g. `x86_64`.
+    pub architecture: String,
+    /// The availability zone the instance runs in, e.g. `eu-west-1a`.
+    pub availability_zone: String,
+    /// The ID of the AMI the instance was launched from.
+    pub image_id: String,
+    /// The ID of the instance.
+    pub instance_id: String,
+    /// The type of the instance, e.g. `t3.micro`.
+    pub instance_type: String,
+    /// The private IPv4 address of the instance.
+    pub private_ip: Option<String>,
+    /// The region the instance runs in, e.g. `eu-west-1`.
+    pub region: String,
+}
+
+/// Future returned from `InstanceMetadataClient`.
+pub struct InstanceMetadataFuture<T> {
+    inner: Box<dyn Future<Item = T, Error = CredentialsError> + Send>,
+}
+
+impl<T> InstanceMetadataFuture<T> {
+    fn new<F>(future: F) -> Self
+    where
+        F: Future<Item = T, Error = CredentialsError> + Send + 'static,
+    {
+        InstanceMetadataFuture {
+            inner: Box::new(future),
+        }
+    }
+}
+
+impl<T> Future for InstanceMetadataFuture<T> {
+    type Item = T;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+/// The tag keys listed one per line by the instance metadata service.
+fn tag_keys(listing: &str) -> Vec<String> {
+    listing
+        .lines()
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
 /// Gets the role name to get credentials for using the IAM Metadata Service (169.254.169.254).
 fn get_role_name(
     client: &HttpClient,
@@ -185,3 +353,50 @@ fn get_credentials_from_role(
 
     Ok(client.get(uri, timeout))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_identity_document() {
+        let document = r#"{
+            "accountId": "123456789012",
+            "architecture": "x86_64",
+            "availabilityZone": "eu-west-1b",
+            "billingProducts": null,
+            "devpayProductCodes": null,
+            "imageId": "ami-0123456789abcdef0",
+            "instanceId": "i-0123456789abcdef0",
+            "instanceType": "t3.micro",
+            "kernelId": null,
+            "marketplaceProductCodes": null,
+            "pendingTime": "2019-10-01T12:00:00Z",
+            "privateIp": "10.0.0.12",
+            "ramdiskId": null,
+            "region": "eu-west-1",
+            "version": "2017-09-30"
+        }"#;
+        let document: InstanceIdentityDocument = serde_json::from_str(document).unwrap();
+        assert_eq!(document.region, "eu-west-1");
+        assert_eq!(document.availability_zone, "eu-west-1b");
+        assert_eq!(document.instance_id, "i-0123456789abcdef0");
+        assert_eq!(document.private_ip, Some("10.0.0.12".to_owned()));
+    }
+
+    #[test]
+    fn lists_tag_keys() {
+        assert_eq!(tag_keys("Name\nteam\n\n"), vec!["Name", "team"]);
+        assert!(tag_keys("").is_empty());
+    }
+
+    #[test]
+    fn metadata_client_shares_provider_settings() {
+        let mut provider = InstanceMetadataProvider::new();
+        provider.set_timeout(Duration::from_secs(2));
+        provider.set_ip_addr_with_port("127.0.0.1", "8080");
+        let client = provider.metadata_client();
+        assert_eq!(client.timeout, Duration::from_secs(2));
+        assert_eq!(client.metadata_ip_addr, "127.0.0.1:8080");
+    }
+}
@@ -0,0 +1,360 @@
+//! The Credentials provider for devices authenticating with AWS IoT certificates.
+
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::future::{err, FutureResult};
+use futures::{Async, Future, Poll};
+use hyper::client::connect::{Connect, Destination};
+use hyper::client::HttpConnector;
+use hyper::{Body, Request};
+
+#[cfg(feature = "rustls")]
+use hyper_rustls::HttpsConnector;
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+use hyper_tls::HttpsConnector;
+
+use crate::request::{HttpClient, HttpClientFuture};
+use crate::{AwsCredentials, CredentialsError, ProvideAwsCredentials};
+
+/// The header naming the thing the certificate is attached to.
+const THING_NAME_HEADER: &str = "x-amzn-iot-thingname";
+
+/// A TLS connection opened by `IotIdentity::connect`.
+pub type IotConnection = <HttpsConnector<HttpConnector> as Connect>::Transport;
+
+/// The future returned by `IotIdentity::connect`.
+pub type IotConnectFuture = Box<dyn Future<Item = IotConnection, Error = io::Error> + Send>;
+
+/// The certificate and private key of an AWS IoT device.
+///
+/// Both are read from PEM, as downloaded from the AWS IoT console or created with
+/// `CreateKeysAndCertificate`. With the `native-tls` feature, the private key must be a PKCS #8
+/// key (`BEGIN PRIVATE KEY`); RSA keys can be converted with `openssl pkcs8 -topk8 -nocrypt`.
+#[derive(Clone)]
+pub struct IotIdentity {
+    certificate: Vec<u8>,
+    private_key: Vec<u8>,
+}
+
+impl IotIdentity {
+    /// Create an identity from a PEM encoded certificate (chain) and private key.
+    pub fn from_pem<C, K>(certificate: C, private_key: K) -> IotIdentity
+    where
+        C: Into<Vec<u8>>,
+        K: Into<Vec<u8>>,
+    {
+        IotIdentity {
+            certificate: certificate.into(),
+            private_key: private_key.into(),
+        }
+    }
+
+    /// Opens a TLS connection to `host` on `port`, authenticated with the identity, e.g. to talk
+    /// MQTT to the account's AWS IoT Core data endpoint on port 8883.
+    pub fn connect(&self, host: &str, port: u16) -> IotConnectFuture {
+        let destination = format!("https://{}:{}", host, port)
+            .parse()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))
+            .and_then(|uri| {
+                Destination::try_from_uri(uri)
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))
+            });
+        let connector = self
+            .connector()
+            .map_err(|error| io::Error::new(io::ErrorKind::Other, error));
+        match (destination, connector) {
+            (Ok(destination), Ok(connector)) => Box::new(
+                connector
+                    .connect(destination)
+                    .map(|(stream, _)| stream)
+                    .map_err(|error| io::Error::new(io::ErrorKind::Other, error)),
+            ),
+            (Err(error), _) | (_, Err(error)) => Box::new(err(error)),
+        }
+    }
+
+    #[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+    fn connector(&self) -> Result<HttpsConnector<HttpConnector>, CredentialsError> {
+        use native_tls_crate::{Identity, TlsConnector};
+
+        let identity = Identity::from_pkcs8(&self.certificate, &self.private_key)
+            .map_err(|error| CredentialsError::new(format!("Invalid IoT identity: {}", error)))?;
+        let tls = TlsConnector::builder()
+            .identity(identity)
+            .build()
+            .map_err(|error| {
+                CredentialsError::new(format!("Couldn't create NativeTlsClient: {}", error))
+            })?;
+        let mut http = HttpConnector::new(4);
+        http.enforce_http(false);
+        Ok(HttpsConnector::from((http, tls)))
+    }
+
+    #[cfg(feature = "rustls")]
+    fn connector(&self) -> Result<HttpsConnector<HttpConnector>, CredentialsError> {
+        use rustls_crate::internal::pemfile;
+        use rustls_crate::ClientConfig;
+
+        let certificates = pemfile::certs(&mut self.certificate.as_slice())
+            .map_err(|_| CredentialsError::new("Invalid IoT certificate"))?;
+        if certificates.is_empty() {
+            return Err(CredentialsError::new(
+                "No certificate found in IoT certificate",
+            ));
+        }
+        let mut keys = pemfile::pkcs8_private_keys(&mut self.private_key.as_slice())
+            .map_err(|_| CredentialsError::new("Invalid IoT private key"))?;
+        if keys.is_empty() {
+            keys = pemfile::rsa_private_keys(&mut self.private_key.as_slice())
+                .map_err(|_| CredentialsError::new("Invalid IoT private key"))?;
+        }
+        let key = keys
+            .into_iter()
+            .next()
+            .ok_or_else(|| CredentialsError::new("No private key found in IoT private key"))?;
+
+        let mut config = ClientConfig::new();
+        config
+            .root_store
+            .add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS);
+        config.set_single_client_cert(certificates, key);
+        let mut http = HttpConnector::new(4);
+        http.enforce_http(false);
+        Ok(HttpsConnector::from((http, config)))
+    }
+}
+
+impl fmt::Debug for IotIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IotIdentity")
+            .field("certificate", &String::from_utf8_lossy(&self.certificate))
+            .field("private_key", &"**********")
+            .finish()
+    }
+}
+
+/// Provides AWS credentials from the AWS IoT credential provider.
+///
+/// Devices registered with AWS IoT can exchange their X.509 certificate for temporary credentials
+/// of the IAM role a role alias points to. The certificate authenticates the device in a mutual
+/// TLS handshake with the account's credential provider endpoint, which is returned by
+/// `aws iot describe-endpoint --endpoint-type iot:CredentialProvider`.
+///
+/// If the IoT policy of the certificate uses `credentials-iot:ThingName`, the name of the thing
+/// the certificate is attached to must be sent as well, with `set_thing_name`.
+///
+/// The provider has a default timeout of 30 seconds. While it should work well for most setups,
+/// you can change the timeout using the `set_timeout` method.
+///
+/// The credentials expire after the duration configured on the role alias, one hour by default.
+/// Wrap the provider in an `AutoRefreshingProvider` to fetch new credentials before they do.
+///
+/// Requires the `native-tls` or the `rustls` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// extern crate rusoto_credential;
+///
+/// use std::fs;
+///
+/// use rusoto_credential::{AutoRefreshingProvider, IotCredentialsProvider, IotIdentity};
+///
+/// fn main() {
+///   let identity = IotIdentity::from_pem(
+///       fs::read("device.pem.crt").unwrap(),
+///       fs::read("private.pem.key").unwrap(),
+///   );
+///   let mut provider = IotCredentialsProvider::new(
+///       "c2k5fn3unmkdcd.credentials.iot.us-east-1.amazonaws.com",
+///       "DeviceRoleAlias",
+///       &identity,
+///   ).unwrap();
+///   provider.set_thing_name("thermostat-42");
+///
+///   let provider = AutoRefreshingProvider::new(provider).unwrap();
+///
+///   // ...
+/// }
+/// ```
+#[derive(Clone)]
+pub struct IotCredentialsProvider {
+    client: HttpClient<HttpsConnector<HttpConnector>>,
+    url: String,
+    thing_name: Option<String>,
+    timeout: Duration,
+}
+
+impl IotCredentialsProvider {
+    /// Create a new provider fetching the credentials of `role_alias` from the credential
+    /// provider `endpoint`, authenticating with `identity`.
+    pub fn new<E, R>(
+        endpoint: E,
+        role_alias: R,
+        identity: &IotIdentity,
+    ) -> Result<IotCredentialsProvider, CredentialsError>
+    where
+        E: AsRef<str>,
+        R: AsRef<str>,
+    {
+        let endpoint = endpoint.as_ref().trim_end_matches('/');
+        let endpoint = if endpoint.starts_with("https://") {
+            endpoint.to_owned()
+        } else {
+            format!("https://{}", endpoint)
+        };
+        Ok(IotCredentialsProvider {
+            client: HttpClient::from_connector(identity.connector()?),
+            url: format!(
+                "{}/role-aliases/{}/credentials",
+                endpoint,
+                role_alias.as_ref()
+            ),
+            thing_name: None,
+            timeout: Duration::from_secs(30),
+        })
+    }
+
+    /// Send the name of the thing the certificate is attached to.
+    pub fn set_thing_name<S>(&mut self, thing_name: S)
+    where
+        S: Into<String>,
+    {
+        self.thing_name = Some(thing_name.into());
+    }
+
+    /// Set the timeout on the provider to the specified duration.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// The URL credentials are fetched from.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn request(&self) -> Result<Request<Body>, CredentialsError> {
+        let mut request = Request::get(self.url.as_str());
+        if let Some(ref thing_name) = self.thing_name {
+            request.header(THING_NAME_HEADER, thing_name.as_str());
+        }
+        request.body(Body::empty()).map_err(|error| {
+            CredentialsError::new(format!(
+                "Error while building IoT credentials request for '{}': {}",
+                self.url, error
+            ))
+        })
+    }
+}
+
+impl fmt::Debug for IotCredentialsProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IotCredentialsProvider")
+            .field("url", &self.url)
+            .field("thing_name", &self.thing_name)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+/// Future returned from `IotCredentialsProvider`.
+pub struct IotCredentialsProviderFuture {
+    inner: IotCredentialsProviderFutureInner,
+}
+
+enum IotCredentialsProviderFutureInner {
+    Result(FutureResult<String, CredentialsError>),
+    Future(HttpClientFuture),
+}
+
+impl Future for IotCredentialsProviderFuture {
+    type Item = AwsCredentials;
+    type Error = CredentialsError;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        let resp = match self.inner {
+            IotCredentialsProviderFutureInner::Result(ref mut result) => {
+                try_ready!(result.poll())
+            }
+            IotCredentialsProviderFutureInner::Future(ref mut future) => {
+                try_ready!(future.poll())
+            }
+        };
+        let creds = parse_iot_credentials(&resp)?;
+        Ok(Async::Ready(creds))
+    }
+}
+
+impl ProvideAwsCredentials for IotCredentialsProvider {
+    type Future = IotCredentialsProviderFuture;
+
+    fn credentials(&self) -> Self::Future {
+        let inner = match self.request() {
+            Ok(request) => IotCredentialsProviderFutureInner::Future(
+                self.client.request(request, self.timeout),
+            ),
+            Err(e) => IotCredentialsProviderFutureInner::Result(err(e)),
+        };
+        IotCredentialsProviderFuture { inner }
+    }
+}
+
+#[derive(Deserialize)]
+struct IotCredentialsResponse {
+    credentials: IotCredentials,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IotCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: String,
+    expiration: DateTime<Utc>,
+}
+
+fn parse_iot_credentials(response: &str) -> Result<AwsCredentials, CredentialsError> {
+    let IotCredentialsResponse { credentials } = serde_json::from_str(response)?;
+    Ok(AwsCredentials::new(
+        credentials.access_key_id,
+        credentials.secret_access_key,
+        Some(credentials.session_token),
+        Some(credentials.expiration),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_credential_provider_response() {
+        let credentials = parse_iot_credentials(
+            r#"{"credentials":{"accessKeyId":"ASIAEXAMPLE","secretAccessKey":"secret",
+            "sessionToken":"token","expiration":"2019-11-01T10:00:00Z"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(credentials.aws_access_key_id(), "ASIAEXAMPLE");
+        assert_eq!(credentials.aws_secret_access_key(), "secret");
+        assert_eq!(credentials.token(), &Some("token".to_owned()));
+        assert_eq!(
+            credentials.expires_at().map(|expiry| expiry.to_rfc3339()),
+            Some("2019-11-01T10:00:00+00:00".to_owned())
+        );
+    }
+
+    #[test]
+    fn error_from_error_response() {
+        assert!(parse_iot_credentials(r#"{"message":"Access Denied"}"#).is_err());
+    }
+
+    #[test]
+    fn invalid_identity_is_rejected() {
+        let identity = IotIdentity::from_pem("not a certificate", "not a key");
+        assert!(IotCredentialsProvider::new("example.com", "alias", &identity).is_err());
+    }
+}
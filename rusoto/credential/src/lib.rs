@@ -12,31 +12,64 @@ extern crate dirs;
 #[macro_use]
 extern crate futures;
 extern crate hyper;
+#[cfg(feature = "rustls")]
+extern crate hyper_rustls;
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+extern crate hyper_tls;
+#[cfg(all(feature = "native-tls", not(feature = "rustls")))]
+extern crate native_tls_crate;
 extern crate regex;
+#[cfg(feature = "rustls")]
+extern crate rustls_crate;
 extern crate serde_json;
 #[macro_use]
 extern crate serde_derive;
 extern crate shlex;
+#[cfg(unix)]
+extern crate tokio_io;
 extern crate tokio_process;
 extern crate tokio_timer;
-
-pub use crate::container::{ContainerProvider, ContainerProviderFuture};
+#[cfg(unix)]
+extern crate tokio_uds;
+#[cfg(feature = "rustls")]
+extern crate webpki_roots;
+
+pub use crate::container::{
+    ContainerProvider, ContainerProviderFuture, TaskMetadataClient, TaskMetadataFuture,
+};
+pub use crate::endpoint::{EndpointCredentialsProvider, EndpointCredentialsProviderFuture};
+pub use crate::env::{Environment, SystemEnvironment, VirtualEnvironment};
 pub use crate::environment::{EnvironmentProvider, EnvironmentProviderFuture};
-pub use crate::instance_metadata::{InstanceMetadataProvider, InstanceMetadataProviderFuture};
-pub use crate::profile::{ProfileProvider, ProfileProviderFuture};
+pub use crate::instance_metadata::{
+    InstanceIdentityDocument, InstanceMetadataClient, InstanceMetadataFuture,
+    InstanceMetadataProvider, InstanceMetadataProviderFuture,
+};
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+pub use crate::iot::{
+    IotConnectFuture, IotConnection, IotCredentialsProvider, IotCredentialsProviderFuture,
+    IotIdentity,
+};
+pub use crate::profile::{
+    CredentialSource, ProfileProvider, ProfileProviderFuture, ProfileRole, RoleChain,
+    RoleChainSource,
+};
 pub use crate::static_provider::StaticProvider;
 
 pub mod claims;
 mod container;
+mod endpoint;
+mod env;
 mod environment;
 mod instance_metadata;
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+mod iot;
 mod profile;
 mod request;
 mod static_provider;
 pub(crate) mod test_utils;
+pub mod unix_socket;
 
 use std::collections::BTreeMap;
-use std::env::var as env_var;
 use std::error::Error;
 use std::fmt;
 use std::io::Error as IoError;
@@ -391,7 +424,9 @@ impl Future for DefaultCredentialsProviderFuture {
 /// 1. Environment variables: `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY`
 /// 2. `credential_process` command in the AWS config file, usually located at `~/.aws/config`.
 /// 3. AWS credentials file. Usually located at `~/.aws/credentials`.
-/// 4. IAM instance profile. Will only work if running on an EC2 instance with an instance profile/role.
+/// 4. ECS task role, through `ContainerProvider`.
+/// 5. A credentials endpoint, only if one was set with `set_endpoint_provider`.
+/// 6. IAM instance profile. Will only work if running on an EC2 instance with an instance profile/role.
 ///
 /// If the sources are exhausted without finding credentials, an error is returned.
 ///
@@ -430,6 +465,7 @@ pub struct ChainProvider {
     environment_provider: EnvironmentProvider,
     instance_metadata_provider: InstanceMetadataProvider,
     container_provider: ContainerProvider,
+    endpoint_provider: Option<EndpointCredentialsProvider>,
     profile_provider: Option<ProfileProvider>,
 }
 
@@ -438,6 +474,15 @@ impl ChainProvider {
     pub fn set_timeout(&mut self, duration: Duration) {
         self.instance_metadata_provider.set_timeout(duration);
         self.container_provider.set_timeout(duration);
+        if let Some(ref mut provider) = self.endpoint_provider {
+            provider.set_timeout(duration);
+        }
+    }
+
+    /// Also look for credentials at a credentials endpoint, after the container credentials and
+    /// before the instance profile.
+    pub fn set_endpoint_provider(&mut self, provider: EndpointCredentialsProvider) {
+        self.endpoint_provider = Some(provider);
     }
 }
 
@@ -462,6 +507,7 @@ impl ProvideAwsCredentials for ChainProvider {
         let profile_provider = self.profile_provider.clone();
         let instance_metadata_provider = self.instance_metadata_provider.clone();
         let container_provider = self.container_provider.clone();
+        let endpoint_provider = self.endpoint_provider.clone();
         let future = self
             .environment_provider
             .credentials()
@@ -470,6 +516,10 @@ impl ProvideAwsCredentials for ChainProvider {
                 None => Either::B(err(CredentialsError::new(""))),
             })
             .or_else(move |_| container_provider.credentials())
+            .or_else(move |_| match endpoint_provider {
+                Some(ref provider) => Either::A(provider.credentials()),
+                None => Either::B(err(CredentialsError::new(""))),
+            })
             .or_else(move |_| instance_metadata_provider.credentials())
             .or_else(|_| {
                 Err(CredentialsError::new(
@@ -490,6 +540,7 @@ impl ChainProvider {
             profile_provider: ProfileProvider::new().ok(),
             instance_metadata_provider: InstanceMetadataProvider::new(),
             container_provider: ContainerProvider::new(),
+            endpoint_provider: None,
         }
     }
 
@@ -500,6 +551,7 @@ impl ChainProvider {
             profile_provider: Some(profile_provider),
             instance_metadata_provider: InstanceMetadataProvider::new(),
             container_provider: ContainerProvider::new(),
+            endpoint_provider: None,
         }
     }
 }
@@ -510,30 +562,11 @@ impl Default for ChainProvider {
     }
 }
 
-/// This is a helper function as Option<T>::filter is not yet stable (see issue #45860).
-/// <https://github.com/rust-lang/rfcs/issues/2036> also affects the implementation of this.
-fn non_empty_env_var(name: &str) -> Option<String> {
-    match env_var(name) {
-        Ok(value) => {
-            if value.is_empty() {
-                None
-            } else {
-                Some(value)
-            }
-        }
-        Err(_) => None,
-    }
-}
-
 /// Parses the response from an AWS Metadata Service, either from an IAM Role, or a Container.
 fn parse_credentials_from_aws_service(response: &str) -> Result<AwsCredentials, CredentialsError> {
     Ok(serde_json::from_str::<AwsCredentials>(response)?)
 }
 
-#[cfg(test)]
-#[macro_use]
-extern crate lazy_static;
-
 #[cfg(test)]
 #[macro_use]
 extern crate quickcheck;
@@ -544,7 +577,7 @@ mod tests {
     use std::io::Read;
     use std::path::Path;
 
-    use crate::test_utils::{is_secret_hidden_behind_asterisks, lock_env, SECRET};
+    use crate::test_utils::{is_secret_hidden_behind_asterisks, SECRET};
     use futures::Future;
 
     use super::*;
@@ -568,11 +601,11 @@ mod tests {
 
     #[test]
     fn profile_provider_finds_right_credentials_in_file() {
-        let _guard = lock_env();
-        let profile_provider = ProfileProvider::with_configuration(
+        let mut profile_provider = ProfileProvider::with_configuration(
             "tests/sample-data/multiple_profile_credentials",
             "foo",
         );
+        profile_provider.set_environment(VirtualEnvironment::new());
 
         let credentials = profile_provider.credentials().wait().expect(
             "Failed to get credentials from profile provider using tests/sample-data/multiple_profile_credentials",
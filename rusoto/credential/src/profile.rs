@@ -6,15 +6,19 @@ use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::str::FromStr;
+use std::sync::Arc;
 
-use dirs::home_dir;
 use futures::future::{result, FutureResult};
 use futures::{Future, Poll};
 use regex::Regex;
 use tokio_process::{CommandExt, OutputAsync};
 use lazy_static::lazy_static;
 
-use crate::{non_empty_env_var, AwsCredentials, CredentialsError, ProvideAwsCredentials};
+use crate::env::non_empty_var;
+use crate::{
+    AwsCredentials, CredentialsError, Environment, ProvideAwsCredentials, SystemEnvironment,
+};
 
 const AWS_CONFIG_FILE: &str = "AWS_CONFIG_FILE";
 const AWS_PROFILE: &str = "AWS_PROFILE";
@@ -43,15 +47,29 @@ pub struct ProfileProvider {
     file_path: PathBuf,
     /// The Profile Path to parse out of the Credentials File.
     profile: String,
+    /// The environment the location of the config file is read from.
+    environment: Arc<dyn Environment>,
 }
 
 impl ProfileProvider {
     /// Create a new `ProfileProvider` for the default credentials file path and profile name.
     pub fn new() -> Result<ProfileProvider, CredentialsError> {
-        let profile_location = ProfileProvider::default_profile_location()?;
-        Ok(ProfileProvider::with_default_configuration(
-            profile_location,
-        ))
+        ProfileProvider::with_environment(SystemEnvironment)
+    }
+
+    /// Create a new `ProfileProvider` which reads the locations of the credentials and config
+    /// files and the profile name from `environment` instead of the environment of the process.
+    pub fn with_environment<E>(environment: E) -> Result<ProfileProvider, CredentialsError>
+    where
+        E: Environment + 'static,
+    {
+        let file_path = ProfileProvider::default_profile_location(&environment)?;
+        let profile = ProfileProvider::default_profile_name(&environment);
+        Ok(ProfileProvider {
+            file_path,
+            profile,
+            environment: Arc::new(environment),
+        })
     }
 
     /// Create a new `ProfileProvider` for the credentials file at the given path, using
@@ -64,6 +82,7 @@ impl ProfileProvider {
         ProfileProvider {
             file_path: file_path.into(),
             profile: profile.into(),
+            environment: Arc::new(SystemEnvironment),
         }
     }
 
@@ -74,7 +93,10 @@ impl ProfileProvider {
     where
         F: Into<PathBuf>,
     {
-        ProfileProvider::with_configuration(file_path, ProfileProvider::default_profile_name())
+        ProfileProvider::with_configuration(
+            file_path,
+            ProfileProvider::default_profile_name(&SystemEnvironment),
+        )
     }
 
     /// Attempts to resolve a region value associated with the current profile from
@@ -83,11 +105,19 @@ impl ProfileProvider {
     ///
     /// For a the ful region resolution chain, use the `Default` impl for `rusoto_core::Region`
     pub fn region() -> Result<Option<String>, CredentialsError> {
-        let location = ProfileProvider::default_config_location();
+        ProfileProvider::region_from_environment(&SystemEnvironment)
+    }
+
+    /// Like `ProfileProvider::region`, but reads the location of the config file and the profile
+    /// name from `environment` instead of the environment of the process.
+    pub fn region_from_environment(
+        environment: &dyn Environment,
+    ) -> Result<Option<String>, CredentialsError> {
+        let location = ProfileProvider::default_config_location(environment);
         location.map(|location| {
             parse_config_file(&location).and_then(|config| {
                 config
-                    .get(&ProfileProvider::default_profile_name())
+                    .get(&ProfileProvider::default_profile_name(environment))
                     .and_then(|props| props.get(REGION))
                     .map(std::borrow::ToOwned::to_owned)
             })
@@ -97,16 +127,18 @@ impl ProfileProvider {
     /// Default config file location:
     /// 1: if set and not empty, use the value from environment variable ```AWS_CONFIG_FILE```
     /// 2. otherwise return `~/.aws/config` (Linux/Mac) resp. `%USERPROFILE%\.aws\config` (Windows)
-    fn default_config_location() -> Result<PathBuf, CredentialsError> {
-        let env = non_empty_env_var(AWS_CONFIG_FILE);
+    fn default_config_location(environment: &dyn Environment) -> Result<PathBuf, CredentialsError> {
+        let env = non_empty_var(environment, AWS_CONFIG_FILE);
         match env {
             Some(path) => Ok(PathBuf::from(path)),
-            None => ProfileProvider::hardcoded_config_location(),
+            None => ProfileProvider::hardcoded_config_location(environment),
         }
     }
 
-    fn hardcoded_config_location() -> Result<PathBuf, CredentialsError> {
-        match home_dir() {
+    fn hardcoded_config_location(
+        environment: &dyn Environment,
+    ) -> Result<PathBuf, CredentialsError> {
+        match environment.home_dir() {
             Some(mut home_path) => {
                 home_path.push(".aws");
                 home_path.push("config");
@@ -119,16 +151,20 @@ impl ProfileProvider {
     /// Default credentials file location:
     /// 1. if set and not empty, use value from environment variable ```AWS_SHARED_CREDENTIALS_FILE```
     /// 2. otherwise return `~/.aws/credentials` (Linux/Mac) resp. `%USERPROFILE%\.aws\credentials` (Windows)
-    fn default_profile_location() -> Result<PathBuf, CredentialsError> {
-        let env = non_empty_env_var(AWS_SHARED_CREDENTIALS_FILE);
+    fn default_profile_location(
+        environment: &dyn Environment,
+    ) -> Result<PathBuf, CredentialsError> {
+        let env = non_empty_var(environment, AWS_SHARED_CREDENTIALS_FILE);
         match env {
             Some(path) => Ok(PathBuf::from(path)),
-            None => ProfileProvider::hardcoded_profile_location(),
+            None => ProfileProvider::hardcoded_profile_location(environment),
         }
     }
 
-    fn hardcoded_profile_location() -> Result<PathBuf, CredentialsError> {
-        match home_dir() {
+    fn hardcoded_profile_location(
+        environment: &dyn Environment,
+    ) -> Result<PathBuf, CredentialsError> {
+        match environment.home_dir() {
             Some(mut home_path) => {
                 home_path.push(".aws");
                 home_path.push("credentials");
@@ -142,8 +178,8 @@ impl ProfileProvider {
     /// 1. if set and not empty, use value from environment variable ```AWS_PROFILE```
     /// 2. otherwise return ```"default"```
     /// see https://docs.aws.amazon.com/sdk-for-java/v1/developer-guide/credentials.html.
-    fn default_profile_name() -> String {
-        non_empty_env_var(AWS_PROFILE).unwrap_or_else(|| DEFAULT.to_owned())
+    fn default_profile_name(environment: &dyn Environment) -> String {
+        non_empty_var(environment, AWS_PROFILE).unwrap_or_else(|| DEFAULT.to_owned())
     }
 
     /// Get a reference to the credentials file path.
@@ -171,6 +207,209 @@ impl ProfileProvider {
     {
         self.profile = profile.into();
     }
+
+    /// Set the environment the location of the config file is read from.
+    pub fn set_environment<E>(&mut self, environment: E)
+    where
+        E: Environment + 'static,
+    {
+        self.environment = Arc::new(environment);
+    }
+
+    fn config_credentials(&self) -> Option<AwsCredentials> {
+        let location = ProfileProvider::default_config_location(&*self.environment).ok()?;
+        let mut props = parse_config_file(&location)?.remove(&self.profile)?;
+        let token = props
+            .remove("aws_session_token")
+            .or_else(|| props.remove("aws_security_token"));
+        Some(AwsCredentials::new(
+            props.remove("aws_access_key_id")?,
+            props.remove("aws_secret_access_key")?,
+            token,
+            None,
+        ))
+    }
+
+    /// Get the environment the location of the config file is read from.
+    pub fn environment(&self) -> Arc<dyn Environment> {
+        self.environment.clone()
+    }
+
+    /// Resolves the roles the profile assumes through the `role_arn`, `source_profile` and
+    /// `credential_source` keys of the config file, or `None` if it doesn't assume a role.
+    ///
+    /// `ProfileProvider` itself can't assume roles, `rusoto_sts::StsProfileCredentialsProvider`
+    /// assumes the roles of the chain with STS.
+    pub fn role_chain(&self) -> Result<Option<RoleChain>, CredentialsError> {
+        let config = ProfileProvider::default_config_location(&*self.environment)
+            .ok()
+            .and_then(|location| parse_config_file(&location))
+            .unwrap_or_default();
+        let credentials = parse_credentials_file(self.file_path()).unwrap_or_default();
+        resolve_role_chain(&config, &credentials, &self.profile)
+    }
+}
+
+/// The provider the credentials of a role chain come from, as named by the `credential_source`
+/// key of a profile.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CredentialSource {
+    /// `Environment`, the variables read by `EnvironmentProvider`
+    Environment,
+    /// `Ec2InstanceMetadata`, the instance profile read by `InstanceMetadataProvider`
+    Ec2InstanceMetadata,
+    /// `EcsContainer`, the task role read by `ContainerProvider`
+    EcsContainer,
+}
+
+impl FromStr for CredentialSource {
+    type Err = CredentialsError;
+
+    fn from_str(s: &str) -> Result<CredentialSource, CredentialsError> {
+        match s {
+            "Environment" => Ok(CredentialSource::Environment),
+            "Ec2InstanceMetadata" => Ok(CredentialSource::Ec2InstanceMetadata),
+            "EcsContainer" => Ok(CredentialSource::EcsContainer),
+            _ => Err(CredentialsError::new(format!(
+                "Unsupported credential_source '{}'",
+                s
+            ))),
+        }
+    }
+}
+
+/// Where the credentials the first role of a chain is assumed with come from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RoleChainSource {
+    /// The static credentials or credential process of the named profile.
+    Profile(String),
+    /// The provider named by the `credential_source` of the last profile of the chain.
+    CredentialSource(CredentialSource),
+}
+
+/// A role assumed by a profile of the config file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileRole {
+    /// Name of the profile the role is configured in
+    pub profile: String,
+    /// ARN of the role, from `role_arn`
+    pub role_arn: String,
+    /// External ID passed when assuming the role, from `external_id`
+    pub external_id: Option<String>,
+    /// MFA device required to assume the role, from `mfa_serial`
+    pub mfa_serial: Option<String>,
+    /// Name of the role session, from `role_session_name`
+    pub role_session_name: Option<String>,
+    /// Duration of the role session, from `duration_seconds`
+    pub duration_seconds: Option<i64>,
+}
+
+/// The roles assumed one after the other to get the credentials of a profile.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoleChain {
+    /// Where the credentials the first role is assumed with come from
+    pub source: RoleChainSource,
+    /// The roles in the order they're assumed, each with the credentials of the one before. The
+    /// last one is the role of the profile itself.
+    pub roles: Vec<ProfileRole>,
+}
+
+fn has_static_credentials(
+    config: &HashMap<String, HashMap<String, String>>,
+    credentials: &HashMap<String, AwsCredentials>,
+    profile: &str,
+) -> bool {
+    credentials.contains_key(profile)
+        || config.get(profile).map_or(false, |props| {
+            props.contains_key("aws_access_key_id") && props.contains_key("aws_secret_access_key")
+        })
+}
+
+/// Follows the `source_profile` keys from `profile` until a profile without a role, or with a
+/// `credential_source`, is reached.
+///
+/// Like the AWS CLI, a profile may name itself as its source profile if it has static
+/// credentials, which are then used to assume its role. Any other cycle is an error.
+fn resolve_role_chain(
+    config: &HashMap<String, HashMap<String, String>>,
+    credentials: &HashMap<String, AwsCredentials>,
+    profile: &str,
+) -> Result<Option<RoleChain>, CredentialsError> {
+    let mut roles = Vec::new();
+    let mut visited: Vec<&str> = Vec::new();
+    let mut name = profile;
+
+    let source = loop {
+        let props = config.get(name);
+        let (props, role_arn) = match props.and_then(|p| p.get("role_arn").map(|r| (p, r))) {
+            Some(role) => role,
+            None if roles.is_empty() => return Ok(None),
+            None => {
+                let has_process =
+                    props.map_or(false, |props| props.contains_key("credential_process"));
+                if !has_process && !has_static_credentials(config, credentials, name) {
+                    return Err(CredentialsError::new(format!(
+                        "Source profile '{}' has no credentials",
+                        name
+                    )));
+                }
+                break RoleChainSource::Profile(name.to_owned());
+            }
+        };
+        if visited.contains(&name) {
+            visited.push(name);
+            return Err(CredentialsError::new(format!(
+                "Infinite loop in profile chain: {}",
+                visited.join(" -> ")
+            )));
+        }
+        visited.push(name);
+
+        let duration_seconds = match props.get("duration_seconds") {
+            Some(value) => Some(value.parse::<i64>().map_err(|_| {
+                CredentialsError::new(format!(
+                    "Invalid duration_seconds '{}' in profile '{}'",
+                    value, name
+                ))
+            })?),
+            None => None,
+        };
+        roles.push(ProfileRole {
+            profile: name.to_owned(),
+            role_arn: role_arn.to_owned(),
+            external_id: props.get("external_id").cloned(),
+            mfa_serial: props.get("mfa_serial").cloned(),
+            role_session_name: props.get("role_session_name").cloned(),
+            duration_seconds,
+        });
+
+        match (props.get("source_profile"), props.get("credential_source")) {
+            (Some(_), Some(_)) => {
+                return Err(CredentialsError::new(format!(
+                    "Profile '{}' has both source_profile and credential_source",
+                    name
+                )))
+            }
+            (None, None) => {
+                return Err(CredentialsError::new(format!(
+                    "Profile '{}' has a role_arn but neither source_profile nor credential_source",
+                    name
+                )))
+            }
+            (Some(source_profile), None) => {
+                if source_profile == name && has_static_credentials(config, credentials, name) {
+                    break RoleChainSource::Profile(name.to_owned());
+                }
+                name = source_profile;
+            }
+            (None, Some(credential_source)) => {
+                break RoleChainSource::CredentialSource(credential_source.parse()?);
+            }
+        }
+    };
+
+    roles.reverse();
+    Ok(Some(RoleChain { source, roles }))
 }
 
 /// Provides AWS credentials from a profile in a credentials file as a Future.
@@ -209,33 +448,40 @@ impl ProvideAwsCredentials for ProfileProvider {
     type Future = ProfileProviderFuture;
 
     fn credentials(&self) -> Self::Future {
-        let inner = match ProfileProvider::default_config_location().map(|location| {
-            parse_config_file(&location).and_then(|config| {
-                config
-                    .get(&self.profile)
-                    .and_then(|props| props.get("credential_process"))
-                    .map(std::borrow::ToOwned::to_owned)
-            })
-        }) {
-            Ok(Some(command)) => {
-                // credential_process is set, create the future
-                match parse_command_str(&command) {
-                    Ok(mut command) => ProfileProviderFutureInner::Future(command.output_async()),
-                    Err(err) => ProfileProviderFutureInner::Result(result(Err(err))),
+        // without a home directory there's no config file, but the credentials file may have
+        // been given explicitly
+        let inner =
+            match ProfileProvider::default_config_location(&*self.environment).map(|location| {
+                parse_config_file(&location).and_then(|config| {
+                    config
+                        .get(&self.profile)
+                        .and_then(|props| props.get("credential_process"))
+                        .map(std::borrow::ToOwned::to_owned)
+                })
+            }) {
+                Ok(Some(command)) => {
+                    // credential_process is set, create the future
+                    match parse_command_str(&command) {
+                        Ok(mut command) => {
+                            ProfileProviderFutureInner::Future(command.output_async())
+                        }
+                        Err(err) => ProfileProviderFutureInner::Result(result(Err(err))),
+                    }
                 }
-            }
-            Ok(None) => {
-                // credential_process is not set, parse the credentials file
-                ProfileProviderFutureInner::Result(result(
-                    parse_credentials_file(self.file_path()).and_then(|mut profiles| {
-                        profiles
-                            .remove(self.profile())
-                            .ok_or_else(|| CredentialsError::new("profile not found"))
-                    }),
-                ))
-            }
-            Err(err) => ProfileProviderFutureInner::Result(result(Err(err))),
-        };
+                Ok(None) | Err(_) => {
+                    // credential_process is not set, parse the credentials file
+                    let credentials =
+                        parse_credentials_file(self.file_path()).and_then(|mut profiles| {
+                            profiles
+                                .remove(self.profile())
+                                .ok_or_else(|| CredentialsError::new("profile not found"))
+                        });
+                    // like the AWS CLI, fall back to static credentials in the config file
+                    ProfileProviderFutureInner::Result(result(
+                        credentials.or_else(|err| self.config_credentials().ok_or(err)),
+                    ))
+                }
+            };
 
         ProfileProviderFuture { inner }
     }
@@ -425,12 +671,14 @@ fn parse_command_str(s: &str) -> Result<Command, CredentialsError> {
 #[cfg(test)]
 mod tests {
 
-    use std::env;
     use std::path::Path;
 
     use super::*;
-    use crate::test_utils::lock_env;
-    use crate::{CredentialsError, ProvideAwsCredentials};
+    use crate::{CredentialsError, ProvideAwsCredentials, VirtualEnvironment};
+
+    fn home_environment() -> VirtualEnvironment {
+        VirtualEnvironment::new().with_home_dir("/home/rusoto")
+    }
 
     #[test]
     fn parse_config_file_default_profile() {
@@ -543,11 +791,11 @@ mod tests {
 
     #[test]
     fn profile_provider_happy_path() {
-        let _guard = lock_env();
-        let provider = ProfileProvider::with_configuration(
+        let mut provider = ProfileProvider::with_configuration(
             "tests/sample-data/multiple_profile_credentials",
             "foo",
         );
+        provider.set_environment(home_environment());
         let result = provider.credentials().wait();
 
         assert!(result.is_ok());
@@ -559,39 +807,45 @@ mod tests {
 
     #[test]
     fn profile_provider_via_environment_variable() {
-        let _guard = lock_env();
         let credentials_path = "tests/sample-data/default_profile_credentials";
-        env::set_var(AWS_SHARED_CREDENTIALS_FILE, credentials_path);
-        let result = ProfileProvider::new();
+        let environment =
+            VirtualEnvironment::new().with_var(AWS_SHARED_CREDENTIALS_FILE, credentials_path);
+        let result = ProfileProvider::with_environment(environment);
         assert!(result.is_ok());
         let provider = result.unwrap();
         assert_eq!(provider.file_path().to_str().unwrap(), credentials_path);
-        env::remove_var(AWS_SHARED_CREDENTIALS_FILE);
     }
 
     #[test]
     fn profile_provider_profile_name_via_environment_variable() {
-        let _guard = lock_env();
         let credentials_path = "tests/sample-data/multiple_profile_credentials";
-        env::set_var(AWS_SHARED_CREDENTIALS_FILE, credentials_path);
-        env::set_var(AWS_PROFILE, "bar");
-        let result = ProfileProvider::new();
+        let environment = home_environment()
+            .with_var(AWS_SHARED_CREDENTIALS_FILE, credentials_path)
+            .with_var(AWS_PROFILE, "bar");
+        let result = ProfileProvider::with_environment(environment);
         assert!(result.is_ok());
         let provider = result.unwrap();
         assert_eq!(provider.file_path().to_str().unwrap(), credentials_path);
         let creds = provider.credentials().wait();
         assert_eq!(creds.unwrap().aws_access_key_id(), "bar_access_key");
-        env::remove_var(AWS_SHARED_CREDENTIALS_FILE);
-        env::remove_var(AWS_PROFILE);
+    }
+
+    #[test]
+    fn profile_provider_without_home_dir() {
+        let result = ProfileProvider::with_environment(VirtualEnvironment::new());
+        assert_eq!(
+            result.err(),
+            Some(CredentialsError::new("Failed to determine home directory."))
+        );
     }
 
     #[test]
     fn profile_provider_bad_profile() {
-        let _guard = lock_env();
-        let provider = ProfileProvider::with_configuration(
+        let mut provider = ProfileProvider::with_configuration(
             "tests/sample-data/multiple_profile_credentials",
             "not_a_profile",
         );
+        provider.set_environment(home_environment());
         let result = provider.credentials().wait();
 
         assert!(result.is_err());
@@ -603,12 +857,11 @@ mod tests {
 
     #[test]
     fn profile_provider_credential_process() {
-        let _guard = lock_env();
-        env::set_var(
+        let environment = home_environment().with_var(
             AWS_CONFIG_FILE,
             "tests/sample-data/credential_process_config",
         );
-        let provider = ProfileProvider::new().unwrap();
+        let provider = ProfileProvider::with_environment(environment).unwrap();
         let result = provider.credentials().wait();
 
         assert!(result.is_ok());
@@ -621,18 +874,15 @@ mod tests {
             "baz_session_token"
         );
         assert!(creds.expires_at().is_some());
-
-        env::remove_var(AWS_CONFIG_FILE);
     }
 
     #[test]
     fn profile_provider_credential_process_foo() {
-        let _guard = lock_env();
-        env::set_var(
+        let environment = home_environment().with_var(
             AWS_CONFIG_FILE,
             "tests/sample-data/credential_process_config",
         );
-        let mut provider = ProfileProvider::new().unwrap();
+        let mut provider = ProfileProvider::with_environment(environment).unwrap();
         provider.set_profile("foo");
         let result = provider.credentials().wait();
 
@@ -646,19 +896,117 @@ mod tests {
             "foo_session_token"
         );
         assert!(creds.expires_at().is_some());
-
-        env::remove_var(AWS_CONFIG_FILE);
     }
 
     #[test]
     fn profile_provider_profile_name() {
-        let _guard = lock_env();
-        let mut provider = ProfileProvider::new().unwrap();
+        let mut provider = ProfileProvider::with_environment(home_environment()).unwrap();
         assert_eq!(DEFAULT, provider.profile());
         provider.set_profile("foo");
         assert_eq!("foo", provider.profile());
     }
 
+    #[test]
+    fn region_from_environment() {
+        let environment = home_environment()
+            .with_var(AWS_CONFIG_FILE, "tests/sample-data/multiple_profile_config")
+            .with_var(AWS_PROFILE, "bar");
+        assert_eq!(
+            Ok(Some("us-east-4".to_owned())),
+            ProfileProvider::region_from_environment(&environment)
+        );
+    }
+
+    fn role_chain_provider(profile: &str) -> ProfileProvider {
+        let mut provider =
+            ProfileProvider::with_configuration("tests/sample-data/no_credentials", profile);
+        provider.set_environment(
+            home_environment().with_var(AWS_CONFIG_FILE, "tests/sample-data/role_chain_config"),
+        );
+        provider
+    }
+
+    #[test]
+    fn role_chain_follows_source_profiles() {
+        let chain = role_chain_provider("audit").role_chain().unwrap().unwrap();
+        assert_eq!(chain.source, RoleChainSource::Profile("default".to_owned()));
+        assert_eq!(
+            chain.roles,
+            vec![
+                ProfileRole {
+                    profile: "admin".to_owned(),
+                    role_arn: "arn:aws:iam::123456789012:role/admin".to_owned(),
+                    external_id: None,
+                    mfa_serial: Some("arn:aws:iam::123456789012:mfa/user".to_owned()),
+                    role_session_name: None,
+                    duration_seconds: Some(3600),
+                },
+                ProfileRole {
+                    profile: "audit".to_owned(),
+                    role_arn: "arn:aws:iam::210987654321:role/audit".to_owned(),
+                    external_id: Some("audit-id".to_owned()),
+                    mfa_serial: None,
+                    role_session_name: Some("audit-session".to_owned()),
+                    duration_seconds: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn role_chain_of_profile_without_role() {
+        assert_eq!(role_chain_provider("default").role_chain(), Ok(None));
+        assert_eq!(role_chain_provider("not_a_profile").role_chain(), Ok(None));
+    }
+
+    #[test]
+    fn role_chain_with_own_static_credentials() {
+        let chain = role_chain_provider("self").role_chain().unwrap().unwrap();
+        assert_eq!(chain.source, RoleChainSource::Profile("self".to_owned()));
+        assert_eq!(chain.roles.len(), 1);
+    }
+
+    #[test]
+    fn role_chain_with_credential_source() {
+        let chain = role_chain_provider("instance")
+            .role_chain()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            chain.source,
+            RoleChainSource::CredentialSource(CredentialSource::Ec2InstanceMetadata)
+        );
+    }
+
+    #[test]
+    fn role_chain_errors() {
+        assert_eq!(
+            role_chain_provider("loop-a").role_chain(),
+            Err(CredentialsError::new(
+                "Infinite loop in profile chain: loop-a -> loop-b -> loop-a"
+            ))
+        );
+        assert_eq!(
+            role_chain_provider("both-sources").role_chain(),
+            Err(CredentialsError::new(
+                "Profile 'both-sources' has both source_profile and credential_source"
+            ))
+        );
+        assert_eq!(
+            role_chain_provider("empty-source").role_chain(),
+            Err(CredentialsError::new(
+                "Source profile 'no-credentials' has no credentials"
+            ))
+        );
+    }
+
+    #[test]
+    fn profile_provider_static_credentials_in_config_file() {
+        let creds = role_chain_provider("self").credentials().wait().unwrap();
+        assert_eq!(creds.aws_access_key_id(), "self_access_key");
+        assert_eq!(creds.aws_secret_access_key(), "self_secret_key");
+    }
+
     #[test]
     fn existing_file_no_credentials() {
         let result = super::parse_credentials_file(Path::new("tests/sample-data/no_credentials"));
@@ -709,56 +1057,48 @@ mod tests {
 
     #[test]
     fn default_profile_name_from_env_var() {
-        let _guard = lock_env();
-        env::set_var(AWS_PROFILE, "bar");
-        assert_eq!("bar", ProfileProvider::default_profile_name());
-        env::remove_var(AWS_PROFILE);
+        let environment = VirtualEnvironment::new().with_var(AWS_PROFILE, "bar");
+        assert_eq!("bar", ProfileProvider::default_profile_name(&environment));
     }
 
     #[test]
     fn default_profile_name_from_empty_env_var() {
-        let _guard = lock_env();
-        env::set_var(AWS_PROFILE, "");
-        assert_eq!(DEFAULT, ProfileProvider::default_profile_name());
-        env::remove_var(AWS_PROFILE);
+        let environment = VirtualEnvironment::new().with_var(AWS_PROFILE, "");
+        assert_eq!(DEFAULT, ProfileProvider::default_profile_name(&environment));
     }
 
     #[test]
     fn default_profile_name() {
-        let _guard = lock_env();
-        env::remove_var(AWS_PROFILE);
-        assert_eq!(DEFAULT, ProfileProvider::default_profile_name());
+        assert_eq!(
+            DEFAULT,
+            ProfileProvider::default_profile_name(&VirtualEnvironment::new())
+        );
     }
 
     #[test]
     fn default_profile_location_from_env_var() {
-        let _guard = lock_env();
-        env::set_var(AWS_SHARED_CREDENTIALS_FILE, "bar");
+        let environment = VirtualEnvironment::new().with_var(AWS_SHARED_CREDENTIALS_FILE, "bar");
         assert_eq!(
             Ok(PathBuf::from("bar")),
-            ProfileProvider::default_profile_location()
+            ProfileProvider::default_profile_location(&environment)
         );
-        env::remove_var(AWS_SHARED_CREDENTIALS_FILE);
     }
 
     #[test]
     fn default_profile_location_from_empty_env_var() {
-        let _guard = lock_env();
-        env::set_var(AWS_SHARED_CREDENTIALS_FILE, "");
+        let environment = home_environment().with_var(AWS_SHARED_CREDENTIALS_FILE, "");
         assert_eq!(
-            ProfileProvider::hardcoded_profile_location(),
-            ProfileProvider::default_profile_location()
+            ProfileProvider::hardcoded_profile_location(&environment),
+            ProfileProvider::default_profile_location(&environment)
         );
-        env::remove_var(AWS_SHARED_CREDENTIALS_FILE);
     }
 
     #[test]
     fn default_profile_location() {
-        let _guard = lock_env();
-        env::remove_var(AWS_SHARED_CREDENTIALS_FILE);
+        let environment = home_environment();
         assert_eq!(
-            ProfileProvider::hardcoded_profile_location(),
-            ProfileProvider::default_profile_location()
+            Ok(PathBuf::from("/home/rusoto/.aws/credentials")),
+            ProfileProvider::default_profile_location(&environment)
         );
     }
 }
@@ -5,11 +5,20 @@ use std::time::Duration;
 
 use futures::stream::Concat2;
 use futures::{Async, Future, Poll, Stream};
+use hyper::client::connect::Connect;
 use hyper::client::{HttpConnector, ResponseFuture as HyperResponseFuture};
 use hyper::{Body, Client as HyperClient, Request, Uri};
 use tokio_timer::Timeout;
 
 use super::CredentialsError;
+#[cfg(unix)]
+use crate::unix_socket::UnixSocketConnector;
+
+/// The connector of `HttpClient::new`, which also connects to Unix domain sockets on Unix.
+#[cfg(unix)]
+type DefaultConnector = UnixSocketConnector<HttpConnector>;
+#[cfg(not(unix))]
+type DefaultConnector = HttpConnector;
 
 /// A future that will resolve to an `HttpResponse`.
 pub struct HttpClientFuture(ClientFutureInner);
@@ -85,15 +94,33 @@ impl Future for HttpClientFuture {
 
 /// Http client for use in a credentials provider.
 #[derive(Debug, Clone)]
-pub struct HttpClient {
-    inner: HyperClient<HttpConnector>,
+pub struct HttpClient<C = DefaultConnector> {
+    inner: HyperClient<C>,
 }
 
 impl HttpClient {
-    /// Create an http client.
+    /// Create an http client, which sends requests for `unix://` URIs to Unix domain sockets.
     pub fn new() -> HttpClient {
+        #[cfg(unix)]
+        let connector = UnixSocketConnector::new();
+        #[cfg(not(unix))]
+        let connector = HttpConnector::new(4);
+        HttpClient {
+            inner: HyperClient::builder().build(connector),
+        }
+    }
+}
+
+impl<C> HttpClient<C>
+where
+    C: Connect + Sync + 'static,
+    C::Transport: 'static,
+    C::Future: 'static,
+{
+    /// Create an http client making connections with `connector`.
+    pub fn from_connector(connector: C) -> HttpClient<C> {
         HttpClient {
-            inner: HyperClient::new(),
+            inner: HyperClient::builder().build(connector),
         }
     }
 
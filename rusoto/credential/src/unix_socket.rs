@@ -0,0 +1,228 @@
+//! Endpoints listening on Unix domain sockets.
+//!
+//! Local emulators and sidecars, such as credential vending proxies, sometimes listen on a Unix
+//! domain socket rather than a TCP port. Their endpoints are written `unix:///path/to/socket`,
+//! optionally followed by `:` and the path requests are sent for, e.g.
+//! `unix:///var/run/credentials.sock:/v1/credentials`.
+//!
+//! URIs need a host, so requests to those endpoints are made for URIs whose host is the
+//! hex-encoded path of the socket, e.g. `unix://2f746d702f612e736f636b/v1/credentials` for
+//! `/tmp/a.sock`. `UnixSocketConnector` connects to the socket such a URI names, and leaves
+//! other URIs to the connector it wraps.
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use hyper::Uri;
+
+/// The scheme of the endpoints and URIs of Unix domain sockets.
+pub const UNIX_SCHEME: &str = "unix";
+
+const ENDPOINT_PREFIX: &str = "unix://";
+
+/// Splits an endpoint of the form `unix:///path/to/socket[:/path]` into the path of the socket
+/// and the path requests are sent for, which is empty if the endpoint doesn't name one.
+///
+/// Returns `None` for endpoints which aren't Unix domain sockets.
+pub fn split_endpoint(endpoint: &str) -> Option<(&str, &str)> {
+    if !endpoint.starts_with(ENDPOINT_PREFIX) {
+        return None;
+    }
+    let socket = &endpoint[ENDPOINT_PREFIX.len()..];
+    if !socket.starts_with('/') {
+        return None;
+    }
+    match socket.find(":/") {
+        Some(index) => Some((&socket[..index], &socket[index + 1..])),
+        None => Some((socket, "")),
+    }
+}
+
+/// The host of the URIs of requests to the socket at `socket`: its hex-encoded path.
+pub fn socket_host(socket: &str) -> String {
+    socket.bytes().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// The path of the socket a URI with the `unix` scheme names.
+pub fn socket_path(uri: &Uri) -> Option<PathBuf> {
+    if uri.scheme_str() != Some(UNIX_SCHEME) {
+        return None;
+    }
+    decode_host(uri.host()?)
+}
+
+fn decode_host(host: &str) -> Option<PathBuf> {
+    if host.len() % 2 != 0 {
+        return None;
+    }
+    let bytes = (0..host.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(host.get(index..index + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    String::from_utf8(bytes).ok().map(PathBuf::from)
+}
+
+/// Parses `uri`, turning endpoints of Unix domain sockets into URIs `UnixSocketConnector`
+/// connects to.
+pub fn parse_uri(uri: &str) -> Result<Uri, <Uri as FromStr>::Err> {
+    match split_endpoint(uri) {
+        Some((socket, path)) => {
+            let path = if path.is_empty() { "/" } else { path };
+            format!("{}://{}{}", UNIX_SCHEME, socket_host(socket), path).parse()
+        }
+        None => uri.parse(),
+    }
+}
+
+#[cfg(unix)]
+pub use self::connector::{SocketStream, UnixSocketConnector};
+
+#[cfg(unix)]
+mod connector {
+    use std::io::{self, Read, Write};
+
+    use futures::{future, Future, Poll};
+    use hyper::client::connect::{Connect, Connected, Destination};
+    use hyper::client::HttpConnector;
+    use tokio_io::{AsyncRead, AsyncWrite};
+    use tokio_uds::UnixStream;
+
+    use super::{decode_host, UNIX_SCHEME};
+
+    /// A connector which connects to Unix domain sockets for URIs with the `unix` scheme, and
+    /// with the connector it wraps for other URIs.
+    #[derive(Clone, Debug)]
+    pub struct UnixSocketConnector<C = HttpConnector> {
+        inner: C,
+    }
+
+    impl UnixSocketConnector {
+        /// A connector which makes plain HTTP connections for URIs with other schemes.
+        pub fn new() -> UnixSocketConnector {
+            UnixSocketConnector::wrap(HttpConnector::new(4))
+        }
+    }
+
+    impl Default for UnixSocketConnector {
+        fn default() -> UnixSocketConnector {
+            UnixSocketConnector::new()
+        }
+    }
+
+    impl<C> UnixSocketConnector<C> {
+        /// A connector which leaves URIs with other schemes to `inner`.
+        pub fn wrap(inner: C) -> UnixSocketConnector<C> {
+            UnixSocketConnector { inner }
+        }
+    }
+
+    impl<C> Connect for UnixSocketConnector<C>
+    where
+        C: Connect,
+        C::Future: 'static,
+    {
+        type Transport = SocketStream<C::Transport>;
+        type Error = io::Error;
+        type Future =
+            Box<dyn Future<Item = (Self::Transport, Connected), Error = io::Error> + Send>;
+
+        fn connect(&self, dst: Destination) -> Self::Future {
+            if dst.scheme() != UNIX_SCHEME {
+                return Box::new(
+                    self.inner
+                        .connect(dst)
+                        .map(|(stream, connected)| (SocketStream::Inner(stream), connected))
+                        .map_err(|error| io::Error::new(io::ErrorKind::Other, error)),
+                );
+            }
+            match decode_host(dst.host()) {
+                Some(path) => Box::new(
+                    UnixStream::connect(path)
+                        .map(|stream| (SocketStream::Unix(stream), Connected::new())),
+                ),
+                None => Box::new(future::err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("Invalid Unix domain socket host: {}", dst.host()),
+                ))),
+            }
+        }
+    }
+
+    /// A connection made by `UnixSocketConnector`.
+    #[derive(Debug)]
+    pub enum SocketStream<T> {
+        /// A connection to a Unix domain socket
+        Unix(UnixStream),
+        /// A connection made by the wrapped connector
+        Inner(T),
+    }
+
+    impl<T: Read> Read for SocketStream<T> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match *self {
+                SocketStream::Unix(ref mut stream) => stream.read(buf),
+                SocketStream::Inner(ref mut stream) => stream.read(buf),
+            }
+        }
+    }
+
+    impl<T: Write> Write for SocketStream<T> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            match *self {
+                SocketStream::Unix(ref mut stream) => stream.write(buf),
+                SocketStream::Inner(ref mut stream) => stream.write(buf),
+            }
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            match *self {
+                SocketStream::Unix(ref mut stream) => stream.flush(),
+                SocketStream::Inner(ref mut stream) => stream.flush(),
+            }
+        }
+    }
+
+    impl<T: AsyncRead> AsyncRead for SocketStream<T> {}
+
+    impl<T: AsyncWrite> AsyncWrite for SocketStream<T> {
+        fn shutdown(&mut self) -> Poll<(), io::Error> {
+            match *self {
+                SocketStream::Unix(ref mut stream) => AsyncWrite::shutdown(stream),
+                SocketStream::Inner(ref mut stream) => stream.shutdown(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_endpoints() {
+        assert_eq!(
+            split_endpoint("unix:///var/run/sidecar.sock"),
+            Some(("/var/run/sidecar.sock", ""))
+        );
+        assert_eq!(
+            split_endpoint("unix:///var/run/sidecar.sock:/v1/credentials"),
+            Some(("/var/run/sidecar.sock", "/v1/credentials"))
+        );
+        assert_eq!(split_endpoint("unix://relative.sock"), None);
+        assert_eq!(split_endpoint("http://localhost:8080"), None);
+    }
+
+    #[test]
+    fn socket_uris_round_trip() {
+        let uri = parse_uri("unix:///tmp/a.sock:/v1/credentials?role=web").unwrap();
+        assert_eq!(
+            uri.to_string(),
+            "unix://2f746d702f612e736f636b/v1/credentials?role=web"
+        );
+        assert_eq!(socket_path(&uri), Some(PathBuf::from("/tmp/a.sock")));
+
+        let uri = parse_uri("http://localhost:8080/credentials").unwrap();
+        assert_eq!(uri.to_string(), "http://localhost:8080/credentials");
+        assert_eq!(socket_path(&uri), None);
+    }
+}
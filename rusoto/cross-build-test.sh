@@ -0,0 +1,20 @@
+#!/bin/sh
+# Builds the rustls flavoured stack for a cross compilation target and fails if openssl-sys
+# was pulled into the build.
+#
+# Usage: ./cross-build-test.sh <rust version> <target>
+
+set -e
+
+RUST_VERSION=$1
+TARGET=$2
+
+for D in credential signature core services/sts services/s3 services/dynamodb;
+do
+    (cd $D ; cargo +$RUST_VERSION build --target $TARGET --no-default-features --features=rustls )
+done
+
+if ls ../target/$TARGET/debug/build | grep -q '^openssl-sys-'; then
+    echo "openssl-sys was built for $TARGET"
+    exit 1
+fi
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Certificate Manager Private Certificate Authority clients.
+//!
+//! Makes a single read-only `ListCertificateAuthorities` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_acm_pca::{AcmPca, AcmPcaClient, ListCertificateAuthoritiesRequest};
+
+fn main() {
+    let client = AcmPcaClient::from_env();
+    match client
+        .list_certificate_authorities(ListCertificateAuthoritiesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListCertificateAuthorities succeeded"),
+        Err(error) => {
+            eprintln!("ListCertificateAuthorities failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Contains information about the certificate subject. The certificate can be one issued by your private certificate authority (CA) or it can be your private CA certificate. The <b>Subject</b> field in the certificate identifies the entity that owns or controls the public key in the certificate. The entity can be a user, computer, device, or service. The <b>Subject</b> must contain an X.500 distinguished name (DN). A DN is a sequence of relative distinguished names (RDNs). The RDNs are separated by commas in the certificate. The DN must be unique for each entity, but your private CA can issue more than one certificate with the same DN to the same entity. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -84,6 +85,230 @@ pub struct ASN1Subject {
     pub title: Option<String>,
 }
 
+/// <p>The actions that the specified AWS service principal can use. These include <code>IssueCertificate</code>, <code>GetCertificate</code>, and <code>ListPermissions</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionType {
+    /// <p><code>IssueCertificate</code></p>
+    IssueCertificate,
+    /// <p><code>GetCertificate</code></p>
+    GetCertificate,
+    /// <p><code>ListPermissions</code></p>
+    ListPermissions,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionType::IssueCertificate => "IssueCertificate",
+            ActionType::GetCertificate => "GetCertificate",
+            ActionType::ListPermissions => "ListPermissions",
+            ActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionType {
+    fn from(value: &'a str) -> ActionType {
+        match value {
+            "IssueCertificate" => ActionType::IssueCertificate,
+            "GetCertificate" => ActionType::GetCertificate,
+            "ListPermissions" => ActionType::ListPermissions,
+            _ => ActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionType {
+    fn from(value: String) -> ActionType {
+        ActionType::from(value.as_str())
+    }
+}
+
+impl From<ActionType> for String {
+    fn from(value: ActionType) -> String {
+        match value {
+            ActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ActionType::from(value))
+    }
+}
+
+/// <p>The format in which to create the report. This can be either <b>JSON</b> or <b>CSV</b>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuditReportResponseFormat {
+    /// <p><code>JSON</code></p>
+    Json,
+    /// <p><code>CSV</code></p>
+    Csv,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuditReportResponseFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditReportResponseFormat::Json => "JSON",
+            AuditReportResponseFormat::Csv => "CSV",
+            AuditReportResponseFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuditReportResponseFormat {
+    fn from(value: &'a str) -> AuditReportResponseFormat {
+        match value {
+            "JSON" => AuditReportResponseFormat::Json,
+            "CSV" => AuditReportResponseFormat::Csv,
+            _ => AuditReportResponseFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditReportResponseFormat {
+    fn from(value: String) -> AuditReportResponseFormat {
+        AuditReportResponseFormat::from(value.as_str())
+    }
+}
+
+impl From<AuditReportResponseFormat> for String {
+    fn from(value: AuditReportResponseFormat) -> String {
+        match value {
+            AuditReportResponseFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuditReportResponseFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AuditReportResponseFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditReportResponseFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuditReportResponseFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuditReportResponseFormat::from(value))
+    }
+}
+
+/// <p>Specifies whether report creation is in progress, has succeeded, or has failed.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuditReportStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>SUCCESS</code></p>
+    Success,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuditReportStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditReportStatus::Creating => "CREATING",
+            AuditReportStatus::Success => "SUCCESS",
+            AuditReportStatus::Failed => "FAILED",
+            AuditReportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuditReportStatus {
+    fn from(value: &'a str) -> AuditReportStatus {
+        match value {
+            "CREATING" => AuditReportStatus::Creating,
+            "SUCCESS" => AuditReportStatus::Success,
+            "FAILED" => AuditReportStatus::Failed,
+            _ => AuditReportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditReportStatus {
+    fn from(value: String) -> AuditReportStatus {
+        AuditReportStatus::from(value.as_str())
+    }
+}
+
+impl From<AuditReportStatus> for String {
+    fn from(value: AuditReportStatus) -> String {
+        match value {
+            AuditReportStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuditReportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AuditReportStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditReportStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuditReportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuditReportStatus::from(value))
+    }
+}
+
 /// <p>Contains information about your private certificate authority (CA). Your private CA can issue and revoke X.509 digital certificates. Digital certificates verify that the entity named in the certificate <b>Subject</b> field owns or controls the public key contained in the <b>Subject Public Key Info</b> field. Call the <a>CreateCertificateAuthority</a> action to create your private CA. You must then call the <a>GetCertificateAuthorityCertificate</a> action to retrieve a private CA certificate signing request (CSR). Sign the CSR with your ACM Private CA-hosted or on-premises root or subordinate CA certificate. Call the <a>ImportCertificateAuthorityCertificate</a> action to import the signed certificate into AWS Certificate Manager (ACM). </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -98,28 +323,33 @@ pub struct CertificateAuthority {
     pub certificate_authority_configuration: Option<CertificateAuthorityConfiguration>,
     /// <p>Date and time at which your private CA was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>Reason the request to create your private CA failed.</p>
     #[serde(rename = "FailureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_reason: Option<String>,
+    pub failure_reason: Option<FailureReason>,
     /// <p>Date and time at which your private CA was last updated.</p>
     #[serde(rename = "LastStateChangeAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_state_change_at: Option<f64>,
+    pub last_state_change_at: Option<EpochTimestamp>,
     /// <p>Date and time after which your private CA certificate is not valid.</p>
     #[serde(rename = "NotAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_after: Option<f64>,
+    pub not_after: Option<EpochTimestamp>,
     /// <p>Date and time before which your private CA certificate is not valid.</p>
     #[serde(rename = "NotBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_before: Option<f64>,
+    pub not_before: Option<EpochTimestamp>,
     /// <p>The period during which a deleted CA can be restored. For more information, see the <code>PermanentDeletionTimeInDays</code> parameter of the <a>DeleteCertificateAuthorityRequest</a> action. </p>
     #[serde(rename = "RestorableUntil")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub restorable_until: Option<f64>,
+    pub restorable_until: Option<EpochTimestamp>,
     /// <p>Information about the certificate revocation list (CRL) created and maintained by your private CA. </p>
     #[serde(rename = "RevocationConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -131,32 +361,198 @@ pub struct CertificateAuthority {
     /// <p>Status of your private CA.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CertificateAuthorityStatus>,
     /// <p>Type of your private CA.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<CertificateAuthorityType>,
 }
 
 /// <p>Contains configuration information for your private certificate authority (CA). This includes information about the class of public key algorithm and the key pair that your private CA creates when it issues a certificate. It also includes the signature algorithm that it uses when issuing certificates, and its X.500 distinguished name. You must specify this information when you call the <a>CreateCertificateAuthority</a> action. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CertificateAuthorityConfiguration {
     /// <p>Type of the public key algorithm and size, in bits, of the key pair that your CA creates when it issues a certificate. When you create a subordinate CA, you must use a key algorithm supported by the parent CA.</p>
     #[serde(rename = "KeyAlgorithm")]
-    pub key_algorithm: String,
+    pub key_algorithm: KeyAlgorithm,
     /// <p>Name of the algorithm your private CA uses to sign certificate requests.</p>
     #[serde(rename = "SigningAlgorithm")]
-    pub signing_algorithm: String,
+    pub signing_algorithm: SigningAlgorithm,
     /// <p>Structure that contains X.500 distinguished name information for your private CA.</p>
     #[serde(rename = "Subject")]
     pub subject: ASN1Subject,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p>Status of your private CA.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CertificateAuthorityStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>PENDING_CERTIFICATE</code></p>
+    PendingCertificate,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// <p><code>EXPIRED</code></p>
+    Expired,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CertificateAuthorityStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateAuthorityStatus::Creating => "CREATING",
+            CertificateAuthorityStatus::PendingCertificate => "PENDING_CERTIFICATE",
+            CertificateAuthorityStatus::Active => "ACTIVE",
+            CertificateAuthorityStatus::Deleted => "DELETED",
+            CertificateAuthorityStatus::Disabled => "DISABLED",
+            CertificateAuthorityStatus::Expired => "EXPIRED",
+            CertificateAuthorityStatus::Failed => "FAILED",
+            CertificateAuthorityStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CertificateAuthorityStatus {
+    fn from(value: &'a str) -> CertificateAuthorityStatus {
+        match value {
+            "CREATING" => CertificateAuthorityStatus::Creating,
+            "PENDING_CERTIFICATE" => CertificateAuthorityStatus::PendingCertificate,
+            "ACTIVE" => CertificateAuthorityStatus::Active,
+            "DELETED" => CertificateAuthorityStatus::Deleted,
+            "DISABLED" => CertificateAuthorityStatus::Disabled,
+            "EXPIRED" => CertificateAuthorityStatus::Expired,
+            "FAILED" => CertificateAuthorityStatus::Failed,
+            _ => CertificateAuthorityStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateAuthorityStatus {
+    fn from(value: String) -> CertificateAuthorityStatus {
+        CertificateAuthorityStatus::from(value.as_str())
+    }
+}
+
+impl From<CertificateAuthorityStatus> for String {
+    fn from(value: CertificateAuthorityStatus) -> String {
+        match value {
+            CertificateAuthorityStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CertificateAuthorityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CertificateAuthorityStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateAuthorityStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<CertificateAuthorityStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CertificateAuthorityStatus::from(value))
+    }
+}
+
+/// <p>Type of your private CA.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CertificateAuthorityType {
+    /// <p><code>ROOT</code></p>
+    Root,
+    /// <p><code>SUBORDINATE</code></p>
+    Subordinate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CertificateAuthorityType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateAuthorityType::Root => "ROOT",
+            CertificateAuthorityType::Subordinate => "SUBORDINATE",
+            CertificateAuthorityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CertificateAuthorityType {
+    fn from(value: &'a str) -> CertificateAuthorityType {
+        match value {
+            "ROOT" => CertificateAuthorityType::Root,
+            "SUBORDINATE" => CertificateAuthorityType::Subordinate,
+            _ => CertificateAuthorityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateAuthorityType {
+    fn from(value: String) -> CertificateAuthorityType {
+        CertificateAuthorityType::from(value.as_str())
+    }
+}
+
+impl From<CertificateAuthorityType> for String {
+    fn from(value: CertificateAuthorityType) -> String {
+        match value {
+            CertificateAuthorityType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CertificateAuthorityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CertificateAuthorityType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateAuthorityType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CertificateAuthorityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CertificateAuthorityType::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateCertificateAuthorityAuditReportRequest {
     /// <p>The format in which to create the report. This can be either <b>JSON</b> or <b>CSV</b>.</p>
     #[serde(rename = "AuditReportResponseFormat")]
-    pub audit_report_response_format: String,
+    pub audit_report_response_format: AuditReportResponseFormat,
     /// <p>The Amazon Resource Name (ARN) of the CA to be audited. This is of the form:</p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code>.</p>
     #[serde(rename = "CertificateAuthorityArn")]
     pub certificate_authority_arn: String,
@@ -165,6 +561,64 @@ pub struct CreateCertificateAuthorityAuditReportRequest {
     pub s3_bucket_name: String,
 }
 
+impl CreateCertificateAuthorityAuditReportRequest {
+    /// Returns a builder for `CreateCertificateAuthorityAuditReportRequest`, checking its required members when built.
+    pub fn builder() -> CreateCertificateAuthorityAuditReportRequestBuilder {
+        CreateCertificateAuthorityAuditReportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCertificateAuthorityAuditReportRequest`](struct.CreateCertificateAuthorityAuditReportRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCertificateAuthorityAuditReportRequestBuilder {
+    audit_report_response_format: Option<AuditReportResponseFormat>,
+    certificate_authority_arn: Option<String>,
+    s3_bucket_name: Option<String>,
+}
+
+impl CreateCertificateAuthorityAuditReportRequestBuilder {
+    /// Sets the [`audit_report_response_format`](struct.CreateCertificateAuthorityAuditReportRequest.html#structfield.audit_report_response_format) member.
+    pub fn audit_report_response_format<T: Into<AuditReportResponseFormat>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.audit_report_response_format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_authority_arn`](struct.CreateCertificateAuthorityAuditReportRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_bucket_name`](struct.CreateCertificateAuthorityAuditReportRequest.html#structfield.s3_bucket_name) member.
+    pub fn s3_bucket_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_bucket_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCertificateAuthorityAuditReportRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        CreateCertificateAuthorityAuditReportRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(CreateCertificateAuthorityAuditReportRequest {
+            audit_report_response_format: self.audit_report_response_format.ok_or(
+                ::rusoto_core::BuildError::missing("audit_report_response_format"),
+            )?,
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+            s3_bucket_name: self
+                .s3_bucket_name
+                .ok_or(::rusoto_core::BuildError::missing("s3_bucket_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCertificateAuthorityAuditReportResponse {
@@ -178,14 +632,14 @@ pub struct CreateCertificateAuthorityAuditReportResponse {
     pub s3_key: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateCertificateAuthorityRequest {
     /// <p>Name and bit size of the private key algorithm, the name of the signing algorithm, and X.500 certificate subject information.</p>
     #[serde(rename = "CertificateAuthorityConfiguration")]
     pub certificate_authority_configuration: CertificateAuthorityConfiguration,
     /// <p>The type of the certificate authority.</p>
     #[serde(rename = "CertificateAuthorityType")]
-    pub certificate_authority_type: String,
+    pub certificate_authority_type: CertificateAuthorityType,
     /// <p>Alphanumeric string that can be used to distinguish between calls to <b>CreateCertificateAuthority</b>. Idempotency tokens time out after five minutes. Therefore, if you call <b>CreateCertificateAuthority</b> multiple times with the same idempotency token within a five minute period, ACM Private CA recognizes that you are requesting only one certificate. As a result, ACM Private CA issues only one. If you change the idempotency token for each call, however, ACM Private CA recognizes that you are requesting multiple certificates.</p>
     #[serde(rename = "IdempotencyToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -200,6 +654,78 @@ pub struct CreateCertificateAuthorityRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateCertificateAuthorityRequest {
+    /// Returns a builder for `CreateCertificateAuthorityRequest`, checking its required members when built.
+    pub fn builder() -> CreateCertificateAuthorityRequestBuilder {
+        CreateCertificateAuthorityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCertificateAuthorityRequest`](struct.CreateCertificateAuthorityRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCertificateAuthorityRequestBuilder {
+    certificate_authority_configuration: Option<CertificateAuthorityConfiguration>,
+    certificate_authority_type: Option<CertificateAuthorityType>,
+    idempotency_token: Option<String>,
+    revocation_configuration: Option<RevocationConfiguration>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateCertificateAuthorityRequestBuilder {
+    /// Sets the [`certificate_authority_configuration`](struct.CreateCertificateAuthorityRequest.html#structfield.certificate_authority_configuration) member.
+    pub fn certificate_authority_configuration<T: Into<CertificateAuthorityConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.certificate_authority_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_authority_type`](struct.CreateCertificateAuthorityRequest.html#structfield.certificate_authority_type) member.
+    pub fn certificate_authority_type<T: Into<CertificateAuthorityType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.certificate_authority_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.CreateCertificateAuthorityRequest.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revocation_configuration`](struct.CreateCertificateAuthorityRequest.html#structfield.revocation_configuration) member.
+    pub fn revocation_configuration<T: Into<RevocationConfiguration>>(mut self, value: T) -> Self {
+        self.revocation_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateCertificateAuthorityRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCertificateAuthorityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCertificateAuthorityRequest, ::rusoto_core::BuildError> {
+        Ok(CreateCertificateAuthorityRequest {
+            certificate_authority_configuration: self.certificate_authority_configuration.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_configuration"),
+            )?,
+            certificate_authority_type: self.certificate_authority_type.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_type"),
+            )?,
+            idempotency_token: self.idempotency_token,
+            revocation_configuration: self.revocation_configuration,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCertificateAuthorityResponse {
@@ -213,7 +739,7 @@ pub struct CreateCertificateAuthorityResponse {
 pub struct CreatePermissionRequest {
     /// <p>The actions that the specified AWS service principal can use. These include <code>IssueCertificate</code>, <code>GetCertificate</code>, and <code>ListPermissions</code>.</p>
     #[serde(rename = "Actions")]
-    pub actions: Vec<String>,
+    pub actions: Vec<ActionType>,
     /// <p>The Amazon Resource Name (ARN) of the CA that grants the permissions. You can find the ARN by calling the <a>ListCertificateAuthorities</a> action. This must have the following form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code>. </p>
     #[serde(rename = "CertificateAuthorityArn")]
     pub certificate_authority_arn: String,
@@ -226,6 +752,66 @@ pub struct CreatePermissionRequest {
     pub source_account: Option<String>,
 }
 
+impl CreatePermissionRequest {
+    /// Returns a builder for `CreatePermissionRequest`, checking its required members when built.
+    pub fn builder() -> CreatePermissionRequestBuilder {
+        CreatePermissionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePermissionRequest`](struct.CreatePermissionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePermissionRequestBuilder {
+    actions: Option<Vec<ActionType>>,
+    certificate_authority_arn: Option<String>,
+    principal: Option<String>,
+    source_account: Option<String>,
+}
+
+impl CreatePermissionRequestBuilder {
+    /// Sets the [`actions`](struct.CreatePermissionRequest.html#structfield.actions) member.
+    pub fn actions<T: Into<Vec<ActionType>>>(mut self, value: T) -> Self {
+        self.actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_authority_arn`](struct.CreatePermissionRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.CreatePermissionRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_account`](struct.CreatePermissionRequest.html#structfield.source_account) member.
+    pub fn source_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_account = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePermissionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePermissionRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePermissionRequest {
+            actions: self
+                .actions
+                .ok_or(::rusoto_core::BuildError::missing("actions"))?,
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+            principal: self
+                .principal
+                .ok_or(::rusoto_core::BuildError::missing("principal"))?,
+            source_account: self.source_account,
+        })
+    }
+}
+
 /// <p>Contains configuration information for a certificate revocation list (CRL). Your private certificate authority (CA) creates base CRLs. Delta CRLs are not supported. You can enable CRLs for your new or an existing private CA by setting the <b>Enabled</b> parameter to <code>true</code>. Your private CA writes CRLs to an S3 bucket that you specify in the <b>S3BucketName</b> parameter. You can hide the name of your bucket by specifying a value for the <b>CustomCname</b> parameter. Your private CA copies the CNAME or the S3 bucket name to the <b>CRL Distribution Points</b> extension of each certificate it issues. Your S3 bucket policy must give write permission to ACM Private CA. </p> <p>Your private CA uses the value in the <b>ExpirationInDays</b> parameter to calculate the <b>nextUpdate</b> field in the CRL. The CRL is refreshed at 1/2 the age of next update or when a certificate is revoked. When a certificate is revoked, it is recorded in the next CRL that is generated and in the next audit report. Only time valid certificates are listed in the CRL. Expired certificates are not included. </p> <p>CRLs contain the following fields:</p> <ul> <li> <p> <b>Version</b>: The current version number defined in RFC 5280 is V2. The integer value is 0x1. </p> </li> <li> <p> <b>Signature Algorithm</b>: The name of the algorithm used to sign the CRL.</p> </li> <li> <p> <b>Issuer</b>: The X.500 distinguished name of your private CA that issued the CRL.</p> </li> <li> <p> <b>Last Update</b>: The issue date and time of this CRL.</p> </li> <li> <p> <b>Next Update</b>: The day and time by which the next CRL will be issued.</p> </li> <li> <p> <b>Revoked Certificates</b>: List of revoked certificates. Each list item contains the following information.</p> <ul> <li> <p> <b>Serial Number</b>: The serial number, in hexadecimal format, of the revoked certificate.</p> </li> <li> <p> <b>Revocation Date</b>: Date and time the certificate was revoked.</p> </li> <li> <p> <b>CRL Entry Extensions</b>: Optional extensions for the CRL entry.</p> <ul> <li> <p> <b>X509v3 CRL Reason Code</b>: Reason the certificate was revoked.</p> </li> </ul> </li> </ul> </li> <li> <p> <b>CRL Extensions</b>: Optional extensions for the CRL.</p> <ul> <li> <p> <b>X509v3 Authority Key Identifier</b>: Identifies the public key associated with the private key used to sign the certificate.</p> </li> <li> <p> <b>X509v3 CRL Number:</b>: Decimal sequence number for the CRL.</p> </li> </ul> </li> <li> <p> <b>Signature Algorithm</b>: Algorithm used by your private CA to sign the CRL.</p> </li> <li> <p> <b>Signature Value</b>: Signature computed over the CRL.</p> </li> </ul> <p>Certificate revocation lists created by ACM Private CA are DER-encoded. You can use the following OpenSSL command to list a CRL.</p> <p> <code>openssl crl -inform DER -text -in <i>crl_path</i> -noout</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CrlConfiguration {
@@ -257,6 +843,46 @@ pub struct DeleteCertificateAuthorityRequest {
     pub permanent_deletion_time_in_days: Option<i64>,
 }
 
+impl DeleteCertificateAuthorityRequest {
+    /// Returns a builder for `DeleteCertificateAuthorityRequest`, checking its required members when built.
+    pub fn builder() -> DeleteCertificateAuthorityRequestBuilder {
+        DeleteCertificateAuthorityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCertificateAuthorityRequest`](struct.DeleteCertificateAuthorityRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteCertificateAuthorityRequestBuilder {
+    certificate_authority_arn: Option<String>,
+    permanent_deletion_time_in_days: Option<i64>,
+}
+
+impl DeleteCertificateAuthorityRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.DeleteCertificateAuthorityRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permanent_deletion_time_in_days`](struct.DeleteCertificateAuthorityRequest.html#structfield.permanent_deletion_time_in_days) member.
+    pub fn permanent_deletion_time_in_days<T: Into<i64>>(mut self, value: T) -> Self {
+        self.permanent_deletion_time_in_days = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCertificateAuthorityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCertificateAuthorityRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteCertificateAuthorityRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+            permanent_deletion_time_in_days: self.permanent_deletion_time_in_days,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeletePermissionRequest {
     /// <p>The Amazon Resource Number (ARN) of the private CA that issued the permissions. You can find the CA's ARN by calling the <a>ListCertificateAuthorities</a> action. This must have the following form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code>. </p>
@@ -271,6 +897,56 @@ pub struct DeletePermissionRequest {
     pub source_account: Option<String>,
 }
 
+impl DeletePermissionRequest {
+    /// Returns a builder for `DeletePermissionRequest`, checking its required members when built.
+    pub fn builder() -> DeletePermissionRequestBuilder {
+        DeletePermissionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePermissionRequest`](struct.DeletePermissionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePermissionRequestBuilder {
+    certificate_authority_arn: Option<String>,
+    principal: Option<String>,
+    source_account: Option<String>,
+}
+
+impl DeletePermissionRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.DeletePermissionRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.DeletePermissionRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_account`](struct.DeletePermissionRequest.html#structfield.source_account) member.
+    pub fn source_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_account = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePermissionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeletePermissionRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePermissionRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+            principal: self
+                .principal
+                .ok_or(::rusoto_core::BuildError::missing("principal"))?,
+            source_account: self.source_account,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeCertificateAuthorityAuditReportRequest {
     /// <p>The report ID returned by calling the <a>CreateCertificateAuthorityAuditReport</a> action.</p>
@@ -281,17 +957,63 @@ pub struct DescribeCertificateAuthorityAuditReportRequest {
     pub certificate_authority_arn: String,
 }
 
+impl DescribeCertificateAuthorityAuditReportRequest {
+    /// Returns a builder for `DescribeCertificateAuthorityAuditReportRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCertificateAuthorityAuditReportRequestBuilder {
+        DescribeCertificateAuthorityAuditReportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCertificateAuthorityAuditReportRequest`](struct.DescribeCertificateAuthorityAuditReportRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCertificateAuthorityAuditReportRequestBuilder {
+    audit_report_id: Option<String>,
+    certificate_authority_arn: Option<String>,
+}
+
+impl DescribeCertificateAuthorityAuditReportRequestBuilder {
+    /// Sets the [`audit_report_id`](struct.DescribeCertificateAuthorityAuditReportRequest.html#structfield.audit_report_id) member.
+    pub fn audit_report_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.audit_report_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_authority_arn`](struct.DescribeCertificateAuthorityAuditReportRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCertificateAuthorityAuditReportRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeCertificateAuthorityAuditReportRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeCertificateAuthorityAuditReportRequest {
+            audit_report_id: self
+                .audit_report_id
+                .ok_or(::rusoto_core::BuildError::missing("audit_report_id"))?,
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeCertificateAuthorityAuditReportResponse {
     /// <p>Specifies whether report creation is in progress, has succeeded, or has failed.</p>
     #[serde(rename = "AuditReportStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub audit_report_status: Option<String>,
+    pub audit_report_status: Option<AuditReportStatus>,
     /// <p>The date and time at which the report was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>Name of the S3 bucket that contains the report.</p>
     #[serde(rename = "S3BucketName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -309,6 +1031,38 @@ pub struct DescribeCertificateAuthorityRequest {
     pub certificate_authority_arn: String,
 }
 
+impl DescribeCertificateAuthorityRequest {
+    /// Returns a builder for `DescribeCertificateAuthorityRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCertificateAuthorityRequestBuilder {
+        DescribeCertificateAuthorityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCertificateAuthorityRequest`](struct.DescribeCertificateAuthorityRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCertificateAuthorityRequestBuilder {
+    certificate_authority_arn: Option<String>,
+}
+
+impl DescribeCertificateAuthorityRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.DescribeCertificateAuthorityRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCertificateAuthorityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCertificateAuthorityRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeCertificateAuthorityRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeCertificateAuthorityResponse {
@@ -318,6 +1072,82 @@ pub struct DescribeCertificateAuthorityResponse {
     pub certificate_authority: Option<CertificateAuthority>,
 }
 
+/// <p>Reason the request to create your private CA failed.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FailureReason {
+    /// <p><code>REQUEST_TIMED_OUT</code></p>
+    RequestTimedOut,
+    /// <p><code>UNSUPPORTED_ALGORITHM</code></p>
+    UnsupportedAlgorithm,
+    /// <p><code>OTHER</code></p>
+    Other,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FailureReason {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailureReason::RequestTimedOut => "REQUEST_TIMED_OUT",
+            FailureReason::UnsupportedAlgorithm => "UNSUPPORTED_ALGORITHM",
+            FailureReason::Other => "OTHER",
+            FailureReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FailureReason {
+    fn from(value: &'a str) -> FailureReason {
+        match value {
+            "REQUEST_TIMED_OUT" => FailureReason::RequestTimedOut,
+            "UNSUPPORTED_ALGORITHM" => FailureReason::UnsupportedAlgorithm,
+            "OTHER" => FailureReason::Other,
+            _ => FailureReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailureReason {
+    fn from(value: String) -> FailureReason {
+        FailureReason::from(value.as_str())
+    }
+}
+
+impl From<FailureReason> for String {
+    fn from(value: FailureReason) -> String {
+        match value {
+            FailureReason::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FailureReason {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FailureReason {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FailureReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FailureReason::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetCertificateAuthorityCertificateRequest {
     /// <p>The Amazon Resource Name (ARN) of your private CA. This is of the form:</p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code>. </p>
@@ -325,6 +1155,39 @@ pub struct GetCertificateAuthorityCertificateRequest {
     pub certificate_authority_arn: String,
 }
 
+impl GetCertificateAuthorityCertificateRequest {
+    /// Returns a builder for `GetCertificateAuthorityCertificateRequest`, checking its required members when built.
+    pub fn builder() -> GetCertificateAuthorityCertificateRequestBuilder {
+        GetCertificateAuthorityCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCertificateAuthorityCertificateRequest`](struct.GetCertificateAuthorityCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCertificateAuthorityCertificateRequestBuilder {
+    certificate_authority_arn: Option<String>,
+}
+
+impl GetCertificateAuthorityCertificateRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.GetCertificateAuthorityCertificateRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCertificateAuthorityCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCertificateAuthorityCertificateRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetCertificateAuthorityCertificateRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCertificateAuthorityCertificateResponse {
@@ -345,6 +1208,38 @@ pub struct GetCertificateAuthorityCsrRequest {
     pub certificate_authority_arn: String,
 }
 
+impl GetCertificateAuthorityCsrRequest {
+    /// Returns a builder for `GetCertificateAuthorityCsrRequest`, checking its required members when built.
+    pub fn builder() -> GetCertificateAuthorityCsrRequestBuilder {
+        GetCertificateAuthorityCsrRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCertificateAuthorityCsrRequest`](struct.GetCertificateAuthorityCsrRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCertificateAuthorityCsrRequestBuilder {
+    certificate_authority_arn: Option<String>,
+}
+
+impl GetCertificateAuthorityCsrRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.GetCertificateAuthorityCsrRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCertificateAuthorityCsrRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCertificateAuthorityCsrRequest, ::rusoto_core::BuildError> {
+        Ok(GetCertificateAuthorityCsrRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCertificateAuthorityCsrResponse {
@@ -364,6 +1259,46 @@ pub struct GetCertificateRequest {
     pub certificate_authority_arn: String,
 }
 
+impl GetCertificateRequest {
+    /// Returns a builder for `GetCertificateRequest`, checking its required members when built.
+    pub fn builder() -> GetCertificateRequestBuilder {
+        GetCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCertificateRequest`](struct.GetCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCertificateRequestBuilder {
+    certificate_arn: Option<String>,
+    certificate_authority_arn: Option<String>,
+}
+
+impl GetCertificateRequestBuilder {
+    /// Sets the [`certificate_arn`](struct.GetCertificateRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_authority_arn`](struct.GetCertificateRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(GetCertificateRequest {
+            certificate_arn: self
+                .certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("certificate_arn"))?,
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCertificateResponse {
@@ -401,7 +1336,60 @@ pub struct ImportCertificateAuthorityCertificateRequest {
     pub certificate_chain: Option<bytes::Bytes>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl ImportCertificateAuthorityCertificateRequest {
+    /// Returns a builder for `ImportCertificateAuthorityCertificateRequest`, checking its required members when built.
+    pub fn builder() -> ImportCertificateAuthorityCertificateRequestBuilder {
+        ImportCertificateAuthorityCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ImportCertificateAuthorityCertificateRequest`](struct.ImportCertificateAuthorityCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct ImportCertificateAuthorityCertificateRequestBuilder {
+    certificate: Option<bytes::Bytes>,
+    certificate_authority_arn: Option<String>,
+    certificate_chain: Option<bytes::Bytes>,
+}
+
+impl ImportCertificateAuthorityCertificateRequestBuilder {
+    /// Sets the [`certificate`](struct.ImportCertificateAuthorityCertificateRequest.html#structfield.certificate) member.
+    pub fn certificate<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.certificate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_authority_arn`](struct.ImportCertificateAuthorityCertificateRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_chain`](struct.ImportCertificateAuthorityCertificateRequest.html#structfield.certificate_chain) member.
+    pub fn certificate_chain<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.certificate_chain = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportCertificateAuthorityCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        ImportCertificateAuthorityCertificateRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(ImportCertificateAuthorityCertificateRequest {
+            certificate: self
+                .certificate
+                .ok_or(::rusoto_core::BuildError::missing("certificate"))?,
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+            certificate_chain: self.certificate_chain,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct IssueCertificateRequest {
     /// <p>The Amazon Resource Name (ARN) that was returned when you called <a>CreateCertificateAuthority</a>. This must be of the form:</p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -420,7 +1408,7 @@ pub struct IssueCertificateRequest {
     pub idempotency_token: Option<String>,
     /// <p>The name of the algorithm that will be used to sign the certificate to be issued.</p>
     #[serde(rename = "SigningAlgorithm")]
-    pub signing_algorithm: String,
+    pub signing_algorithm: SigningAlgorithm,
     /// <p>Specifies a custom configuration template to use when issuing a certificate. If this parameter is not provided, ACM Private CA defaults to the <code>EndEntityCertificate/V1</code> template.</p> <p>The following service-owned <code>TemplateArn</code> values are supported by ACM Private CA: </p> <ul> <li> <p>arn:aws:acm-pca:::template/EndEntityCertificate/V1</p> </li> <li> <p>arn:aws:acm-pca:::template/SubordinateCACertificate_PathLen0/V1</p> </li> <li> <p>arn:aws:acm-pca:::template/SubordinateCACertificate_PathLen1/V1</p> </li> <li> <p>arn:aws:acm-pca:::template/SubordinateCACertificate_PathLen2/V1</p> </li> <li> <p>arn:aws:acm-pca:::template/SubordinateCACertificate_PathLen3/V1</p> </li> <li> <p>arn:aws:acm-pca:::template/RootCACertificate/V1</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/acm-pca/latest/userguide/UsingTemplates.html">Using Templates</a>.</p>
     #[serde(rename = "TemplateArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -430,6 +1418,82 @@ pub struct IssueCertificateRequest {
     pub validity: Validity,
 }
 
+impl IssueCertificateRequest {
+    /// Returns a builder for `IssueCertificateRequest`, checking its required members when built.
+    pub fn builder() -> IssueCertificateRequestBuilder {
+        IssueCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`IssueCertificateRequest`](struct.IssueCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct IssueCertificateRequestBuilder {
+    certificate_authority_arn: Option<String>,
+    csr: Option<bytes::Bytes>,
+    idempotency_token: Option<String>,
+    signing_algorithm: Option<SigningAlgorithm>,
+    template_arn: Option<String>,
+    validity: Option<Validity>,
+}
+
+impl IssueCertificateRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.IssueCertificateRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`csr`](struct.IssueCertificateRequest.html#structfield.csr) member.
+    pub fn csr<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.csr = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.IssueCertificateRequest.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`signing_algorithm`](struct.IssueCertificateRequest.html#structfield.signing_algorithm) member.
+    pub fn signing_algorithm<T: Into<SigningAlgorithm>>(mut self, value: T) -> Self {
+        self.signing_algorithm = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_arn`](struct.IssueCertificateRequest.html#structfield.template_arn) member.
+    pub fn template_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`validity`](struct.IssueCertificateRequest.html#structfield.validity) member.
+    pub fn validity<T: Into<Validity>>(mut self, value: T) -> Self {
+        self.validity = Some(value.into());
+        self
+    }
+
+    /// Builds the `IssueCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<IssueCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(IssueCertificateRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+            csr: self.csr.ok_or(::rusoto_core::BuildError::missing("csr"))?,
+            idempotency_token: self.idempotency_token,
+            signing_algorithm: self
+                .signing_algorithm
+                .ok_or(::rusoto_core::BuildError::missing("signing_algorithm"))?,
+            template_arn: self.template_arn,
+            validity: self
+                .validity
+                .ok_or(::rusoto_core::BuildError::missing("validity"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct IssueCertificateResponse {
@@ -439,6 +1503,86 @@ pub struct IssueCertificateResponse {
     pub certificate_arn: Option<String>,
 }
 
+/// <p>Type of the public key algorithm and size, in bits, of the key pair that your CA creates when it issues a certificate. When you create a subordinate CA, you must use a key algorithm supported by the parent CA.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeyAlgorithm {
+    /// <p><code>RSA_2048</code></p>
+    Rsa2048,
+    /// <p><code>RSA_4096</code></p>
+    Rsa4096,
+    /// <p><code>EC_prime256v1</code></p>
+    EcPrime256v1,
+    /// <p><code>EC_secp384r1</code></p>
+    EcSecp384r1,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl KeyAlgorithm {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyAlgorithm::Rsa2048 => "RSA_2048",
+            KeyAlgorithm::Rsa4096 => "RSA_4096",
+            KeyAlgorithm::EcPrime256v1 => "EC_prime256v1",
+            KeyAlgorithm::EcSecp384r1 => "EC_secp384r1",
+            KeyAlgorithm::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for KeyAlgorithm {
+    fn from(value: &'a str) -> KeyAlgorithm {
+        match value {
+            "RSA_2048" => KeyAlgorithm::Rsa2048,
+            "RSA_4096" => KeyAlgorithm::Rsa4096,
+            "EC_prime256v1" => KeyAlgorithm::EcPrime256v1,
+            "EC_secp384r1" => KeyAlgorithm::EcSecp384r1,
+            _ => KeyAlgorithm::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyAlgorithm {
+    fn from(value: String) -> KeyAlgorithm {
+        KeyAlgorithm::from(value.as_str())
+    }
+}
+
+impl From<KeyAlgorithm> for String {
+    fn from(value: KeyAlgorithm) -> String {
+        match value {
+            KeyAlgorithm::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for KeyAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for KeyAlgorithm {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyAlgorithm {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<KeyAlgorithm, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(KeyAlgorithm::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListCertificateAuthoritiesRequest {
     /// <p>Use this parameter when paginating results to specify the maximum number of items to return in the response on each page. If additional items exist beyond the number you specify, the <code>NextToken</code> element is sent in the response. Use this <code>NextToken</code> value in a subsequent request to retrieve additional items.</p>
@@ -451,6 +1595,44 @@ pub struct ListCertificateAuthoritiesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListCertificateAuthoritiesRequest {
+    /// Returns a builder for `ListCertificateAuthoritiesRequest`, checking its required members when built.
+    pub fn builder() -> ListCertificateAuthoritiesRequestBuilder {
+        ListCertificateAuthoritiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCertificateAuthoritiesRequest`](struct.ListCertificateAuthoritiesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCertificateAuthoritiesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListCertificateAuthoritiesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListCertificateAuthoritiesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListCertificateAuthoritiesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCertificateAuthoritiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListCertificateAuthoritiesRequest, ::rusoto_core::BuildError> {
+        Ok(ListCertificateAuthoritiesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListCertificateAuthoritiesResponse {
@@ -479,6 +1661,52 @@ pub struct ListPermissionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListPermissionsRequest {
+    /// Returns a builder for `ListPermissionsRequest`, checking its required members when built.
+    pub fn builder() -> ListPermissionsRequestBuilder {
+        ListPermissionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPermissionsRequest`](struct.ListPermissionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPermissionsRequestBuilder {
+    certificate_authority_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListPermissionsRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.ListPermissionsRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListPermissionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPermissionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPermissionsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPermissionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPermissionsRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPermissionsResponse {
@@ -507,6 +1735,52 @@ pub struct ListTagsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListTagsRequest {
+    /// Returns a builder for `ListTagsRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsRequestBuilder {
+        ListTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsRequest`](struct.ListTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsRequestBuilder {
+    certificate_authority_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListTagsRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.ListTagsRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListTagsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTagsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsResponse {
@@ -527,15 +1801,16 @@ pub struct Permission {
     /// <p>The private CA actions that can be performed by the designated AWS service.</p>
     #[serde(rename = "Actions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub actions: Option<Vec<String>>,
+    pub actions: Option<Vec<ActionType>>,
     /// <p>The Amazon Resource Number (ARN) of the private CA from which the permission was issued.</p>
     #[serde(rename = "CertificateAuthorityArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub certificate_authority_arn: Option<String>,
     /// <p>The time at which the permission was created.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The name of the policy that is associated with the permission.</p>
     #[serde(rename = "Policy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -557,6 +1832,38 @@ pub struct RestoreCertificateAuthorityRequest {
     pub certificate_authority_arn: String,
 }
 
+impl RestoreCertificateAuthorityRequest {
+    /// Returns a builder for `RestoreCertificateAuthorityRequest`, checking its required members when built.
+    pub fn builder() -> RestoreCertificateAuthorityRequestBuilder {
+        RestoreCertificateAuthorityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreCertificateAuthorityRequest`](struct.RestoreCertificateAuthorityRequest.html).
+#[derive(Default, Debug)]
+pub struct RestoreCertificateAuthorityRequestBuilder {
+    certificate_authority_arn: Option<String>,
+}
+
+impl RestoreCertificateAuthorityRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.RestoreCertificateAuthorityRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreCertificateAuthorityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreCertificateAuthorityRequest, ::rusoto_core::BuildError> {
+        Ok(RestoreCertificateAuthorityRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+        })
+    }
+}
+
 /// <p>Certificate revocation information used by the <a>CreateCertificateAuthority</a> and <a>UpdateCertificateAuthority</a> actions. Your private certificate authority (CA) can create and maintain a certificate revocation list (CRL). A CRL contains information about certificates revoked by your CA. For more information, see <a>RevokeCertificate</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RevocationConfiguration {
@@ -566,7 +1873,103 @@ pub struct RevocationConfiguration {
     pub crl_configuration: Option<CrlConfiguration>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p>Specifies why you revoked the certificate.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RevocationReason {
+    /// <p><code>UNSPECIFIED</code></p>
+    Unspecified,
+    /// <p><code>KEY_COMPROMISE</code></p>
+    KeyCompromise,
+    /// <p><code>CERTIFICATE_AUTHORITY_COMPROMISE</code></p>
+    CertificateAuthorityCompromise,
+    /// <p><code>AFFILIATION_CHANGED</code></p>
+    AffiliationChanged,
+    /// <p><code>SUPERSEDED</code></p>
+    Superseded,
+    /// <p><code>CESSATION_OF_OPERATION</code></p>
+    CessationOfOperation,
+    /// <p><code>PRIVILEGE_WITHDRAWN</code></p>
+    PrivilegeWithdrawn,
+    /// <p><code>A_A_COMPROMISE</code></p>
+    AACompromise,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RevocationReason {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RevocationReason::Unspecified => "UNSPECIFIED",
+            RevocationReason::KeyCompromise => "KEY_COMPROMISE",
+            RevocationReason::CertificateAuthorityCompromise => "CERTIFICATE_AUTHORITY_COMPROMISE",
+            RevocationReason::AffiliationChanged => "AFFILIATION_CHANGED",
+            RevocationReason::Superseded => "SUPERSEDED",
+            RevocationReason::CessationOfOperation => "CESSATION_OF_OPERATION",
+            RevocationReason::PrivilegeWithdrawn => "PRIVILEGE_WITHDRAWN",
+            RevocationReason::AACompromise => "A_A_COMPROMISE",
+            RevocationReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RevocationReason {
+    fn from(value: &'a str) -> RevocationReason {
+        match value {
+            "UNSPECIFIED" => RevocationReason::Unspecified,
+            "KEY_COMPROMISE" => RevocationReason::KeyCompromise,
+            "CERTIFICATE_AUTHORITY_COMPROMISE" => RevocationReason::CertificateAuthorityCompromise,
+            "AFFILIATION_CHANGED" => RevocationReason::AffiliationChanged,
+            "SUPERSEDED" => RevocationReason::Superseded,
+            "CESSATION_OF_OPERATION" => RevocationReason::CessationOfOperation,
+            "PRIVILEGE_WITHDRAWN" => RevocationReason::PrivilegeWithdrawn,
+            "A_A_COMPROMISE" => RevocationReason::AACompromise,
+            _ => RevocationReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RevocationReason {
+    fn from(value: String) -> RevocationReason {
+        RevocationReason::from(value.as_str())
+    }
+}
+
+impl From<RevocationReason> for String {
+    fn from(value: RevocationReason) -> String {
+        match value {
+            RevocationReason::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RevocationReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RevocationReason {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RevocationReason {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RevocationReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RevocationReason::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RevokeCertificateRequest {
     /// <p>Amazon Resource Name (ARN) of the private CA that issued the certificate to be revoked. This must be of the form:</p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -576,7 +1979,147 @@ pub struct RevokeCertificateRequest {
     pub certificate_serial: String,
     /// <p>Specifies why you revoked the certificate.</p>
     #[serde(rename = "RevocationReason")]
-    pub revocation_reason: String,
+    pub revocation_reason: RevocationReason,
+}
+
+impl RevokeCertificateRequest {
+    /// Returns a builder for `RevokeCertificateRequest`, checking its required members when built.
+    pub fn builder() -> RevokeCertificateRequestBuilder {
+        RevokeCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RevokeCertificateRequest`](struct.RevokeCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct RevokeCertificateRequestBuilder {
+    certificate_authority_arn: Option<String>,
+    certificate_serial: Option<String>,
+    revocation_reason: Option<RevocationReason>,
+}
+
+impl RevokeCertificateRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.RevokeCertificateRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_serial`](struct.RevokeCertificateRequest.html#structfield.certificate_serial) member.
+    pub fn certificate_serial<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_serial = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revocation_reason`](struct.RevokeCertificateRequest.html#structfield.revocation_reason) member.
+    pub fn revocation_reason<T: Into<RevocationReason>>(mut self, value: T) -> Self {
+        self.revocation_reason = Some(value.into());
+        self
+    }
+
+    /// Builds the `RevokeCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RevokeCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(RevokeCertificateRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+            certificate_serial: self
+                .certificate_serial
+                .ok_or(::rusoto_core::BuildError::missing("certificate_serial"))?,
+            revocation_reason: self
+                .revocation_reason
+                .ok_or(::rusoto_core::BuildError::missing("revocation_reason"))?,
+        })
+    }
+}
+
+/// <p>Name of the algorithm your private CA uses to sign certificate requests.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SigningAlgorithm {
+    /// <p><code>SHA256WITHECDSA</code></p>
+    Sha256withecdsa,
+    /// <p><code>SHA384WITHECDSA</code></p>
+    Sha384withecdsa,
+    /// <p><code>SHA512WITHECDSA</code></p>
+    Sha512withecdsa,
+    /// <p><code>SHA256WITHRSA</code></p>
+    Sha256withrsa,
+    /// <p><code>SHA384WITHRSA</code></p>
+    Sha384withrsa,
+    /// <p><code>SHA512WITHRSA</code></p>
+    Sha512withrsa,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SigningAlgorithm {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SigningAlgorithm::Sha256withecdsa => "SHA256WITHECDSA",
+            SigningAlgorithm::Sha384withecdsa => "SHA384WITHECDSA",
+            SigningAlgorithm::Sha512withecdsa => "SHA512WITHECDSA",
+            SigningAlgorithm::Sha256withrsa => "SHA256WITHRSA",
+            SigningAlgorithm::Sha384withrsa => "SHA384WITHRSA",
+            SigningAlgorithm::Sha512withrsa => "SHA512WITHRSA",
+            SigningAlgorithm::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SigningAlgorithm {
+    fn from(value: &'a str) -> SigningAlgorithm {
+        match value {
+            "SHA256WITHECDSA" => SigningAlgorithm::Sha256withecdsa,
+            "SHA384WITHECDSA" => SigningAlgorithm::Sha384withecdsa,
+            "SHA512WITHECDSA" => SigningAlgorithm::Sha512withecdsa,
+            "SHA256WITHRSA" => SigningAlgorithm::Sha256withrsa,
+            "SHA384WITHRSA" => SigningAlgorithm::Sha384withrsa,
+            "SHA512WITHRSA" => SigningAlgorithm::Sha512withrsa,
+            _ => SigningAlgorithm::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SigningAlgorithm {
+    fn from(value: String) -> SigningAlgorithm {
+        SigningAlgorithm::from(value.as_str())
+    }
+}
+
+impl From<SigningAlgorithm> for String {
+    fn from(value: SigningAlgorithm) -> String {
+        match value {
+            SigningAlgorithm::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SigningAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SigningAlgorithm {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SigningAlgorithm {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SigningAlgorithm, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SigningAlgorithm::from(value))
+    }
 }
 
 /// <p>Tags are labels that you can use to identify and organize your private CAs. Each tag consists of a key and an optional value. You can associate up to 50 tags with a private CA. To add one or more tags to a private CA, call the <a>TagCertificateAuthority</a> action. To remove a tag, call the <a>UntagCertificateAuthority</a> action. </p>
@@ -601,6 +2144,48 @@ pub struct TagCertificateAuthorityRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagCertificateAuthorityRequest {
+    /// Returns a builder for `TagCertificateAuthorityRequest`, checking its required members when built.
+    pub fn builder() -> TagCertificateAuthorityRequestBuilder {
+        TagCertificateAuthorityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagCertificateAuthorityRequest`](struct.TagCertificateAuthorityRequest.html).
+#[derive(Default, Debug)]
+pub struct TagCertificateAuthorityRequestBuilder {
+    certificate_authority_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagCertificateAuthorityRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.TagCertificateAuthorityRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagCertificateAuthorityRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagCertificateAuthorityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TagCertificateAuthorityRequest, ::rusoto_core::BuildError> {
+        Ok(TagCertificateAuthorityRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagCertificateAuthorityRequest {
     /// <p>The Amazon Resource Name (ARN) that was returned when you called <a>CreateCertificateAuthority</a>. This must be of the form: </p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
@@ -611,6 +2196,48 @@ pub struct UntagCertificateAuthorityRequest {
     pub tags: Vec<Tag>,
 }
 
+impl UntagCertificateAuthorityRequest {
+    /// Returns a builder for `UntagCertificateAuthorityRequest`, checking its required members when built.
+    pub fn builder() -> UntagCertificateAuthorityRequestBuilder {
+        UntagCertificateAuthorityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagCertificateAuthorityRequest`](struct.UntagCertificateAuthorityRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagCertificateAuthorityRequestBuilder {
+    certificate_authority_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl UntagCertificateAuthorityRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.UntagCertificateAuthorityRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.UntagCertificateAuthorityRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagCertificateAuthorityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UntagCertificateAuthorityRequest, ::rusoto_core::BuildError> {
+        Ok(UntagCertificateAuthorityRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateCertificateAuthorityRequest {
     /// <p>Amazon Resource Name (ARN) of the private CA that issued the certificate to be revoked. This must be of the form:</p> <p> <code>arn:aws:acm-pca:<i>region</i>:<i>account</i>:certificate-authority/<i>12345678-1234-1234-1234-123456789012</i> </code> </p>
@@ -623,20 +2250,152 @@ pub struct UpdateCertificateAuthorityRequest {
     /// <p>Status of your private CA.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CertificateAuthorityStatus>,
+}
+
+impl UpdateCertificateAuthorityRequest {
+    /// Returns a builder for `UpdateCertificateAuthorityRequest`, checking its required members when built.
+    pub fn builder() -> UpdateCertificateAuthorityRequestBuilder {
+        UpdateCertificateAuthorityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateCertificateAuthorityRequest`](struct.UpdateCertificateAuthorityRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateCertificateAuthorityRequestBuilder {
+    certificate_authority_arn: Option<String>,
+    revocation_configuration: Option<RevocationConfiguration>,
+    status: Option<CertificateAuthorityStatus>,
+}
+
+impl UpdateCertificateAuthorityRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.UpdateCertificateAuthorityRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revocation_configuration`](struct.UpdateCertificateAuthorityRequest.html#structfield.revocation_configuration) member.
+    pub fn revocation_configuration<T: Into<RevocationConfiguration>>(mut self, value: T) -> Self {
+        self.revocation_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.UpdateCertificateAuthorityRequest.html#structfield.status) member.
+    pub fn status<T: Into<CertificateAuthorityStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateCertificateAuthorityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateCertificateAuthorityRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateCertificateAuthorityRequest {
+            certificate_authority_arn: self.certificate_authority_arn.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_arn"),
+            )?,
+            revocation_configuration: self.revocation_configuration,
+            status: self.status,
+        })
+    }
 }
 
 /// <p>Length of time for which the certificate issued by your private certificate authority (CA), or by the private CA itself, is valid in days, months, or years. You can issue a certificate by calling the <a>IssueCertificate</a> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Validity {
     /// <p>Specifies whether the <code>Value</code> parameter represents days, months, or years.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: ValidityPeriodType,
     /// <p>Time period.</p>
     #[serde(rename = "Value")]
     pub value: i64,
 }
 
+/// <p>Specifies whether the <code>Value</code> parameter represents days, months, or years.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ValidityPeriodType {
+    /// <p><code>END_DATE</code></p>
+    EndDate,
+    /// <p><code>ABSOLUTE</code></p>
+    Absolute,
+    /// <p><code>DAYS</code></p>
+    Days,
+    /// <p><code>MONTHS</code></p>
+    Months,
+    /// <p><code>YEARS</code></p>
+    Years,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ValidityPeriodType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ValidityPeriodType::EndDate => "END_DATE",
+            ValidityPeriodType::Absolute => "ABSOLUTE",
+            ValidityPeriodType::Days => "DAYS",
+            ValidityPeriodType::Months => "MONTHS",
+            ValidityPeriodType::Years => "YEARS",
+            ValidityPeriodType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ValidityPeriodType {
+    fn from(value: &'a str) -> ValidityPeriodType {
+        match value {
+            "END_DATE" => ValidityPeriodType::EndDate,
+            "ABSOLUTE" => ValidityPeriodType::Absolute,
+            "DAYS" => ValidityPeriodType::Days,
+            "MONTHS" => ValidityPeriodType::Months,
+            "YEARS" => ValidityPeriodType::Years,
+            _ => ValidityPeriodType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ValidityPeriodType {
+    fn from(value: String) -> ValidityPeriodType {
+        ValidityPeriodType::from(value.as_str())
+    }
+}
+
+impl From<ValidityPeriodType> for String {
+    fn from(value: ValidityPeriodType) -> String {
+        match value {
+            ValidityPeriodType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ValidityPeriodType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ValidityPeriodType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ValidityPeriodType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ValidityPeriodType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ValidityPeriodType::from(value))
+    }
+}
+
 /// Errors returned by CreateCertificateAuthority
 #[derive(Debug, PartialEq)]
 pub enum CreateCertificateAuthorityError {
@@ -1995,6 +3754,141 @@ pub trait AcmPca {
         input: UpdateCertificateAuthorityRequest,
     ) -> RusotoFuture<(), UpdateCertificateAuthorityError>;
 }
+impl<T: ?Sized + AcmPca> AcmPca for ::std::sync::Arc<T> {
+    fn create_certificate_authority(
+        &self,
+        input: CreateCertificateAuthorityRequest,
+    ) -> RusotoFuture<CreateCertificateAuthorityResponse, CreateCertificateAuthorityError> {
+        (**self).create_certificate_authority(input)
+    }
+    fn create_certificate_authority_audit_report(
+        &self,
+        input: CreateCertificateAuthorityAuditReportRequest,
+    ) -> RusotoFuture<
+        CreateCertificateAuthorityAuditReportResponse,
+        CreateCertificateAuthorityAuditReportError,
+    > {
+        (**self).create_certificate_authority_audit_report(input)
+    }
+    fn create_permission(
+        &self,
+        input: CreatePermissionRequest,
+    ) -> RusotoFuture<(), CreatePermissionError> {
+        (**self).create_permission(input)
+    }
+    fn delete_certificate_authority(
+        &self,
+        input: DeleteCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), DeleteCertificateAuthorityError> {
+        (**self).delete_certificate_authority(input)
+    }
+    fn delete_permission(
+        &self,
+        input: DeletePermissionRequest,
+    ) -> RusotoFuture<(), DeletePermissionError> {
+        (**self).delete_permission(input)
+    }
+    fn describe_certificate_authority(
+        &self,
+        input: DescribeCertificateAuthorityRequest,
+    ) -> RusotoFuture<DescribeCertificateAuthorityResponse, DescribeCertificateAuthorityError> {
+        (**self).describe_certificate_authority(input)
+    }
+    fn describe_certificate_authority_audit_report(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+    ) -> RusotoFuture<
+        DescribeCertificateAuthorityAuditReportResponse,
+        DescribeCertificateAuthorityAuditReportError,
+    > {
+        (**self).describe_certificate_authority_audit_report(input)
+    }
+    fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> RusotoFuture<GetCertificateResponse, GetCertificateError> {
+        (**self).get_certificate(input)
+    }
+    fn get_certificate_authority_certificate(
+        &self,
+        input: GetCertificateAuthorityCertificateRequest,
+    ) -> RusotoFuture<
+        GetCertificateAuthorityCertificateResponse,
+        GetCertificateAuthorityCertificateError,
+    > {
+        (**self).get_certificate_authority_certificate(input)
+    }
+    fn get_certificate_authority_csr(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+    ) -> RusotoFuture<GetCertificateAuthorityCsrResponse, GetCertificateAuthorityCsrError> {
+        (**self).get_certificate_authority_csr(input)
+    }
+    fn import_certificate_authority_certificate(
+        &self,
+        input: ImportCertificateAuthorityCertificateRequest,
+    ) -> RusotoFuture<(), ImportCertificateAuthorityCertificateError> {
+        (**self).import_certificate_authority_certificate(input)
+    }
+    fn issue_certificate(
+        &self,
+        input: IssueCertificateRequest,
+    ) -> RusotoFuture<IssueCertificateResponse, IssueCertificateError> {
+        (**self).issue_certificate(input)
+    }
+    fn list_certificate_authorities(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<ListCertificateAuthoritiesResponse, ListCertificateAuthoritiesError> {
+        (**self).list_certificate_authorities(input)
+    }
+    fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> RusotoFuture<ListPermissionsResponse, ListPermissionsError> {
+        (**self).list_permissions(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn restore_certificate_authority(
+        &self,
+        input: RestoreCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), RestoreCertificateAuthorityError> {
+        (**self).restore_certificate_authority(input)
+    }
+    fn revoke_certificate(
+        &self,
+        input: RevokeCertificateRequest,
+    ) -> RusotoFuture<(), RevokeCertificateError> {
+        (**self).revoke_certificate(input)
+    }
+    fn tag_certificate_authority(
+        &self,
+        input: TagCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), TagCertificateAuthorityError> {
+        (**self).tag_certificate_authority(input)
+    }
+    fn untag_certificate_authority(
+        &self,
+        input: UntagCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), UntagCertificateAuthorityError> {
+        (**self).untag_certificate_authority(input)
+    }
+    fn update_certificate_authority(
+        &self,
+        input: UpdateCertificateAuthorityRequest,
+    ) -> RusotoFuture<(), UpdateCertificateAuthorityError> {
+        (**self).update_certificate_authority(input)
+    }
+}
+/// The error codes ACM-PCA retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the ACM-PCA API.
 #[derive(Clone)]
 pub struct AcmPcaClient {
@@ -2010,6 +3904,27 @@ impl AcmPcaClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ACM_PCA`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> AcmPcaClient {
+        Self::new(region::Region::from_env_for_service("ACM_PCA"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `acm-pca`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> AcmPcaClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("acm-pca", "ACM_PCA"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2028,7 +3943,49 @@ impl AcmPcaClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AcmPcaClient {
-        AcmPcaClient { client, region }
+        AcmPcaClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "acm-pca")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "acm-pca", "acm-pca", "ACM_PCA")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `AcmPca` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn AcmPca + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for AcmPcaClient {
+    fn service_name(&self) -> &'static str {
+        "ACM-PCA"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "acm-pca"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> AcmPcaClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Certificate Manager clients.
+//!
+//! Makes a single read-only `ListCertificates` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_acm::{Acm, AcmClient, ListCertificatesRequest};
+
+fn main() {
+    let client = AcmClient::from_env();
+    match client
+        .list_certificates(ListCertificatesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListCertificates succeeded"),
+        Err(error) => {
+            eprintln!("ListCertificates failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AddTagsToCertificateRequest {
@@ -33,6 +34,48 @@ pub struct AddTagsToCertificateRequest {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsToCertificateRequest {
+    /// Returns a builder for `AddTagsToCertificateRequest`, checking its required members when built.
+    pub fn builder() -> AddTagsToCertificateRequestBuilder {
+        AddTagsToCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsToCertificateRequest`](struct.AddTagsToCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct AddTagsToCertificateRequestBuilder {
+    certificate_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsToCertificateRequestBuilder {
+    /// Sets the [`certificate_arn`](struct.AddTagsToCertificateRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsToCertificateRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsToCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddTagsToCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(AddTagsToCertificateRequest {
+            certificate_arn: self
+                .certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("certificate_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>Contains metadata about an ACM certificate. This structure is returned in the response to a <a>DescribeCertificate</a> request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -47,8 +90,9 @@ pub struct CertificateDetail {
     pub certificate_authority_arn: Option<String>,
     /// <p>The time at which the certificate was requested. This value exists only when the certificate type is <code>AMAZON_ISSUED</code>. </p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The fully qualified domain name for the certificate, such as www.example.com or example.com.</p>
     #[serde(rename = "DomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -64,19 +108,21 @@ pub struct CertificateDetail {
     /// <p>The reason the certificate request failed. This value exists only when the certificate status is <code>FAILED</code>. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/troubleshooting.html#troubleshooting-failed">Certificate Request Failed</a> in the <i>AWS Certificate Manager User Guide</i>. </p>
     #[serde(rename = "FailureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_reason: Option<String>,
+    pub failure_reason: Option<FailureReason>,
     /// <p>The date and time at which the certificate was imported. This value exists only when the certificate type is <code>IMPORTED</code>. </p>
     #[serde(rename = "ImportedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub imported_at: Option<f64>,
+    pub imported_at: Option<EpochTimestamp>,
     /// <p>A list of ARNs for the AWS resources that are using the certificate. A certificate can be used by multiple AWS resources. </p>
     #[serde(rename = "InUseBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub in_use_by: Option<Vec<String>>,
     /// <p>The time at which the certificate was issued. This value exists only when the certificate type is <code>AMAZON_ISSUED</code>. </p>
     #[serde(rename = "IssuedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issued_at: Option<f64>,
+    pub issued_at: Option<EpochTimestamp>,
     /// <p>The name of the certificate authority that issued and signed the certificate.</p>
     #[serde(rename = "Issuer")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -84,19 +130,21 @@ pub struct CertificateDetail {
     /// <p>The algorithm that was used to generate the public-private key pair.</p>
     #[serde(rename = "KeyAlgorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_algorithm: Option<String>,
+    pub key_algorithm: Option<KeyAlgorithm>,
     /// <p>A list of Key Usage X.509 v3 extension objects. Each object is a string value that identifies the purpose of the public key contained in the certificate. Possible extension values include DIGITAL_SIGNATURE, KEY_ENCHIPHERMENT, NON_REPUDIATION, and more.</p>
     #[serde(rename = "KeyUsages")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key_usages: Option<Vec<KeyUsage>>,
     /// <p>The time after which the certificate is not valid.</p>
     #[serde(rename = "NotAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_after: Option<f64>,
+    pub not_after: Option<EpochTimestamp>,
     /// <p>The time before which the certificate is not valid.</p>
     #[serde(rename = "NotBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_before: Option<f64>,
+    pub not_before: Option<EpochTimestamp>,
     /// <p>Value that specifies whether to add the certificate to a transparency log. Certificate transparency makes it possible to detect SSL certificates that have been mistakenly or maliciously issued. A browser might respond to certificate that has not been logged by showing an error message. The logs are cryptographically secure. </p>
     #[serde(rename = "Options")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -104,7 +152,7 @@ pub struct CertificateDetail {
     /// <p>Specifies whether the certificate is eligible for renewal. At this time, only exported private certificates can be renewed with the <a>RenewCertificate</a> command.</p>
     #[serde(rename = "RenewalEligibility")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub renewal_eligibility: Option<String>,
+    pub renewal_eligibility: Option<RenewalEligibility>,
     /// <p>Contains information about the status of ACM's <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> for the certificate. This field exists only when the certificate type is <code>AMAZON_ISSUED</code>.</p>
     #[serde(rename = "RenewalSummary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -112,11 +160,12 @@ pub struct CertificateDetail {
     /// <p>The reason the certificate was revoked. This value exists only when the certificate status is <code>REVOKED</code>. </p>
     #[serde(rename = "RevocationReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub revocation_reason: Option<String>,
+    pub revocation_reason: Option<RevocationReason>,
     /// <p>The time at which the certificate was revoked. This value exists only when the certificate status is <code>REVOKED</code>. </p>
     #[serde(rename = "RevokedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub revoked_at: Option<f64>,
+    pub revoked_at: Option<EpochTimestamp>,
     /// <p>The serial number of the certificate.</p>
     #[serde(rename = "Serial")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -128,7 +177,7 @@ pub struct CertificateDetail {
     /// <p>The status of the certificate.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CertificateStatus>,
     /// <p>The name of the entity that is associated with the public key contained in the certificate.</p>
     #[serde(rename = "Subject")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -140,7 +189,7 @@ pub struct CertificateDetail {
     /// <p>The source of the certificate. For certificates provided by ACM, this value is <code>AMAZON_ISSUED</code>. For certificates that you imported with <a>ImportCertificate</a>, this value is <code>IMPORTED</code>. ACM does not provide <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> for imported certificates. For more information about the differences between certificates that you import and those that ACM provides, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/import-certificate.html">Importing Certificates</a> in the <i>AWS Certificate Manager User Guide</i>. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<CertificateType>,
 }
 
 /// <p>Structure that contains options for your certificate. Currently, you can use this only to specify whether to opt in to or out of certificate transparency logging. Some browsers require that public certificates issued for your domain be recorded in a log. Certificates that are not logged typically generate a browser error. Transparency makes it possible for you to detect SSL/TLS certificates that have been mistakenly or maliciously issued for your domain. For general information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-concepts.html#concept-transparency">Certificate Transparency Logging</a>. </p>
@@ -149,7 +198,100 @@ pub struct CertificateOptions {
     /// <p>You can opt out of certificate transparency logging by specifying the <code>DISABLED</code> option. Opt in by specifying <code>ENABLED</code>. </p>
     #[serde(rename = "CertificateTransparencyLoggingPreference")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_transparency_logging_preference: Option<String>,
+    pub certificate_transparency_logging_preference:
+        Option<CertificateTransparencyLoggingPreference>,
+}
+
+/// <p>The status of the certificate.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CertificateStatus {
+    /// <p><code>PENDING_VALIDATION</code></p>
+    PendingValidation,
+    /// <p><code>ISSUED</code></p>
+    Issued,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>EXPIRED</code></p>
+    Expired,
+    /// <p><code>VALIDATION_TIMED_OUT</code></p>
+    ValidationTimedOut,
+    /// <p><code>REVOKED</code></p>
+    Revoked,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CertificateStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateStatus::PendingValidation => "PENDING_VALIDATION",
+            CertificateStatus::Issued => "ISSUED",
+            CertificateStatus::Inactive => "INACTIVE",
+            CertificateStatus::Expired => "EXPIRED",
+            CertificateStatus::ValidationTimedOut => "VALIDATION_TIMED_OUT",
+            CertificateStatus::Revoked => "REVOKED",
+            CertificateStatus::Failed => "FAILED",
+            CertificateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CertificateStatus {
+    fn from(value: &'a str) -> CertificateStatus {
+        match value {
+            "PENDING_VALIDATION" => CertificateStatus::PendingValidation,
+            "ISSUED" => CertificateStatus::Issued,
+            "INACTIVE" => CertificateStatus::Inactive,
+            "EXPIRED" => CertificateStatus::Expired,
+            "VALIDATION_TIMED_OUT" => CertificateStatus::ValidationTimedOut,
+            "REVOKED" => CertificateStatus::Revoked,
+            "FAILED" => CertificateStatus::Failed,
+            _ => CertificateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateStatus {
+    fn from(value: String) -> CertificateStatus {
+        CertificateStatus::from(value.as_str())
+    }
+}
+
+impl From<CertificateStatus> for String {
+    fn from(value: CertificateStatus) -> String {
+        match value {
+            CertificateStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CertificateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CertificateStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CertificateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CertificateStatus::from(value))
+    }
 }
 
 /// <p>This structure is returned in the response object of <a>ListCertificates</a> action. </p>
@@ -166,6 +308,156 @@ pub struct CertificateSummary {
     pub domain_name: Option<String>,
 }
 
+/// <p>You can opt out of certificate transparency logging by specifying the <code>DISABLED</code> option. Opt in by specifying <code>ENABLED</code>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CertificateTransparencyLoggingPreference {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CertificateTransparencyLoggingPreference {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateTransparencyLoggingPreference::Enabled => "ENABLED",
+            CertificateTransparencyLoggingPreference::Disabled => "DISABLED",
+            CertificateTransparencyLoggingPreference::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CertificateTransparencyLoggingPreference {
+    fn from(value: &'a str) -> CertificateTransparencyLoggingPreference {
+        match value {
+            "ENABLED" => CertificateTransparencyLoggingPreference::Enabled,
+            "DISABLED" => CertificateTransparencyLoggingPreference::Disabled,
+            _ => CertificateTransparencyLoggingPreference::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateTransparencyLoggingPreference {
+    fn from(value: String) -> CertificateTransparencyLoggingPreference {
+        CertificateTransparencyLoggingPreference::from(value.as_str())
+    }
+}
+
+impl From<CertificateTransparencyLoggingPreference> for String {
+    fn from(value: CertificateTransparencyLoggingPreference) -> String {
+        match value {
+            CertificateTransparencyLoggingPreference::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CertificateTransparencyLoggingPreference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CertificateTransparencyLoggingPreference {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateTransparencyLoggingPreference {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<CertificateTransparencyLoggingPreference, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CertificateTransparencyLoggingPreference::from(value))
+    }
+}
+
+/// <p>The source of the certificate. For certificates provided by ACM, this value is <code>AMAZON_ISSUED</code>. For certificates that you imported with <a>ImportCertificate</a>, this value is <code>IMPORTED</code>. ACM does not provide <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> for imported certificates. For more information about the differences between certificates that you import and those that ACM provides, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/import-certificate.html">Importing Certificates</a> in the <i>AWS Certificate Manager User Guide</i>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CertificateType {
+    /// <p><code>IMPORTED</code></p>
+    Imported,
+    /// <p><code>AMAZON_ISSUED</code></p>
+    AmazonIssued,
+    /// <p><code>PRIVATE</code></p>
+    Private,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CertificateType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateType::Imported => "IMPORTED",
+            CertificateType::AmazonIssued => "AMAZON_ISSUED",
+            CertificateType::Private => "PRIVATE",
+            CertificateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CertificateType {
+    fn from(value: &'a str) -> CertificateType {
+        match value {
+            "IMPORTED" => CertificateType::Imported,
+            "AMAZON_ISSUED" => CertificateType::AmazonIssued,
+            "PRIVATE" => CertificateType::Private,
+            _ => CertificateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateType {
+    fn from(value: String) -> CertificateType {
+        CertificateType::from(value.as_str())
+    }
+}
+
+impl From<CertificateType> for String {
+    fn from(value: CertificateType) -> String {
+        match value {
+            CertificateType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CertificateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CertificateType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CertificateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CertificateType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteCertificateRequest {
     /// <p>String that contains the ARN of the ACM certificate to be deleted. This must be of the form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
@@ -173,6 +465,38 @@ pub struct DeleteCertificateRequest {
     pub certificate_arn: String,
 }
 
+impl DeleteCertificateRequest {
+    /// Returns a builder for `DeleteCertificateRequest`, checking its required members when built.
+    pub fn builder() -> DeleteCertificateRequestBuilder {
+        DeleteCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCertificateRequest`](struct.DeleteCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteCertificateRequestBuilder {
+    certificate_arn: Option<String>,
+}
+
+impl DeleteCertificateRequestBuilder {
+    /// Sets the [`certificate_arn`](struct.DeleteCertificateRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteCertificateRequest {
+            certificate_arn: self
+                .certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("certificate_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeCertificateRequest {
     /// <p>The Amazon Resource Name (ARN) of the ACM certificate. The ARN must have the following form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
@@ -180,6 +504,38 @@ pub struct DescribeCertificateRequest {
     pub certificate_arn: String,
 }
 
+impl DescribeCertificateRequest {
+    /// Returns a builder for `DescribeCertificateRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCertificateRequestBuilder {
+        DescribeCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCertificateRequest`](struct.DescribeCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCertificateRequestBuilder {
+    certificate_arn: Option<String>,
+}
+
+impl DescribeCertificateRequestBuilder {
+    /// Sets the [`certificate_arn`](struct.DescribeCertificateRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeCertificateRequest {
+            certificate_arn: self
+                .certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("certificate_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeCertificateResponse {
@@ -189,6 +545,82 @@ pub struct DescribeCertificateResponse {
     pub certificate: Option<CertificateDetail>,
 }
 
+/// <p><p>The validation status of the domain name. This can be one of the following values:</p> <ul> <li> <p> <code>PENDING_VALIDATION</code> </p> </li> <li> <p> <code/>SUCCESS</p> </li> <li> <p> <code/>FAILED</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DomainStatus {
+    /// <p><code>PENDING_VALIDATION</code></p>
+    PendingValidation,
+    /// <p><code>SUCCESS</code></p>
+    Success,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DomainStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainStatus::PendingValidation => "PENDING_VALIDATION",
+            DomainStatus::Success => "SUCCESS",
+            DomainStatus::Failed => "FAILED",
+            DomainStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DomainStatus {
+    fn from(value: &'a str) -> DomainStatus {
+        match value {
+            "PENDING_VALIDATION" => DomainStatus::PendingValidation,
+            "SUCCESS" => DomainStatus::Success,
+            "FAILED" => DomainStatus::Failed,
+            _ => DomainStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainStatus {
+    fn from(value: String) -> DomainStatus {
+        DomainStatus::from(value.as_str())
+    }
+}
+
+impl From<DomainStatus> for String {
+    fn from(value: DomainStatus) -> String {
+        match value {
+            DomainStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DomainStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DomainStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DomainStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DomainStatus::from(value))
+    }
+}
+
 /// <p>Contains information about the validation of each domain name in the certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -211,11 +643,11 @@ pub struct DomainValidation {
     /// <p>Specifies the domain validation method.</p>
     #[serde(rename = "ValidationMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_method: Option<String>,
+    pub validation_method: Option<ValidationMethod>,
     /// <p><p>The validation status of the domain name. This can be one of the following values:</p> <ul> <li> <p> <code>PENDING_VALIDATION</code> </p> </li> <li> <p> <code/>SUCCESS</p> </li> <li> <p> <code/>FAILED</p> </li> </ul></p>
     #[serde(rename = "ValidationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_status: Option<String>,
+    pub validation_status: Option<DomainStatus>,
 }
 
 /// <p>Contains information about the domain names that you want ACM to use to send you emails that enable you to validate domain ownership.</p>
@@ -244,6 +676,48 @@ pub struct ExportCertificateRequest {
     pub passphrase: bytes::Bytes,
 }
 
+impl ExportCertificateRequest {
+    /// Returns a builder for `ExportCertificateRequest`, checking its required members when built.
+    pub fn builder() -> ExportCertificateRequestBuilder {
+        ExportCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ExportCertificateRequest`](struct.ExportCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct ExportCertificateRequestBuilder {
+    certificate_arn: Option<String>,
+    passphrase: Option<bytes::Bytes>,
+}
+
+impl ExportCertificateRequestBuilder {
+    /// Sets the [`certificate_arn`](struct.ExportCertificateRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`passphrase`](struct.ExportCertificateRequest.html#structfield.passphrase) member.
+    pub fn passphrase<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.passphrase = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExportCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ExportCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(ExportCertificateRequest {
+            certificate_arn: self
+                .certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("certificate_arn"))?,
+            passphrase: self
+                .passphrase
+                .ok_or(::rusoto_core::BuildError::missing("passphrase"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ExportCertificateResponse {
@@ -268,28 +742,272 @@ pub struct ExtendedKeyUsage {
     /// <p>The name of an Extended Key Usage value.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<ExtendedKeyUsageName>,
     /// <p><p>An object identifier (OID) for the extension value. OIDs are strings of numbers separated by periods. The following OIDs are defined in RFC 3280 and RFC 5280. </p> <ul> <li> <p> <code>1.3.6.1.5.5.7.3.1 (TLS<em>WEB</em>SERVER<em>AUTHENTICATION)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.2 (TLS</em>WEB<em>CLIENT</em>AUTHENTICATION)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.3 (CODE<em>SIGNING)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.4 (EMAIL</em>PROTECTION)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.8 (TIME<em>STAMPING)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.9 (OCSP</em>SIGNING)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.5 (IPSEC<em>END</em>SYSTEM)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.6 (IPSEC<em>TUNNEL)</code> </p> </li> <li> <p> <code>1.3.6.1.5.5.7.3.7 (IPSEC</em>USER)</code> </p> </li> </ul></p>
     #[serde(rename = "OID")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub oid: Option<String>,
 }
 
+/// <p>The name of an Extended Key Usage value.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExtendedKeyUsageName {
+    /// <p><code>TLS_WEB_SERVER_AUTHENTICATION</code></p>
+    TlsWebServerAuthentication,
+    /// <p><code>TLS_WEB_CLIENT_AUTHENTICATION</code></p>
+    TlsWebClientAuthentication,
+    /// <p><code>CODE_SIGNING</code></p>
+    CodeSigning,
+    /// <p><code>EMAIL_PROTECTION</code></p>
+    EmailProtection,
+    /// <p><code>TIME_STAMPING</code></p>
+    TimeStamping,
+    /// <p><code>OCSP_SIGNING</code></p>
+    OcspSigning,
+    /// <p><code>IPSEC_END_SYSTEM</code></p>
+    IpsecEndSystem,
+    /// <p><code>IPSEC_TUNNEL</code></p>
+    IpsecTunnel,
+    /// <p><code>IPSEC_USER</code></p>
+    IpsecUser,
+    /// <p><code>ANY</code></p>
+    Any,
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>CUSTOM</code></p>
+    Custom,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExtendedKeyUsageName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExtendedKeyUsageName::TlsWebServerAuthentication => "TLS_WEB_SERVER_AUTHENTICATION",
+            ExtendedKeyUsageName::TlsWebClientAuthentication => "TLS_WEB_CLIENT_AUTHENTICATION",
+            ExtendedKeyUsageName::CodeSigning => "CODE_SIGNING",
+            ExtendedKeyUsageName::EmailProtection => "EMAIL_PROTECTION",
+            ExtendedKeyUsageName::TimeStamping => "TIME_STAMPING",
+            ExtendedKeyUsageName::OcspSigning => "OCSP_SIGNING",
+            ExtendedKeyUsageName::IpsecEndSystem => "IPSEC_END_SYSTEM",
+            ExtendedKeyUsageName::IpsecTunnel => "IPSEC_TUNNEL",
+            ExtendedKeyUsageName::IpsecUser => "IPSEC_USER",
+            ExtendedKeyUsageName::Any => "ANY",
+            ExtendedKeyUsageName::None => "NONE",
+            ExtendedKeyUsageName::Custom => "CUSTOM",
+            ExtendedKeyUsageName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExtendedKeyUsageName {
+    fn from(value: &'a str) -> ExtendedKeyUsageName {
+        match value {
+            "TLS_WEB_SERVER_AUTHENTICATION" => ExtendedKeyUsageName::TlsWebServerAuthentication,
+            "TLS_WEB_CLIENT_AUTHENTICATION" => ExtendedKeyUsageName::TlsWebClientAuthentication,
+            "CODE_SIGNING" => ExtendedKeyUsageName::CodeSigning,
+            "EMAIL_PROTECTION" => ExtendedKeyUsageName::EmailProtection,
+            "TIME_STAMPING" => ExtendedKeyUsageName::TimeStamping,
+            "OCSP_SIGNING" => ExtendedKeyUsageName::OcspSigning,
+            "IPSEC_END_SYSTEM" => ExtendedKeyUsageName::IpsecEndSystem,
+            "IPSEC_TUNNEL" => ExtendedKeyUsageName::IpsecTunnel,
+            "IPSEC_USER" => ExtendedKeyUsageName::IpsecUser,
+            "ANY" => ExtendedKeyUsageName::Any,
+            "NONE" => ExtendedKeyUsageName::None,
+            "CUSTOM" => ExtendedKeyUsageName::Custom,
+            _ => ExtendedKeyUsageName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExtendedKeyUsageName {
+    fn from(value: String) -> ExtendedKeyUsageName {
+        ExtendedKeyUsageName::from(value.as_str())
+    }
+}
+
+impl From<ExtendedKeyUsageName> for String {
+    fn from(value: ExtendedKeyUsageName) -> String {
+        match value {
+            ExtendedKeyUsageName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExtendedKeyUsageName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExtendedKeyUsageName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExtendedKeyUsageName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExtendedKeyUsageName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExtendedKeyUsageName::from(value))
+    }
+}
+
+/// <p>The reason the certificate request failed. This value exists only when the certificate status is <code>FAILED</code>. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/troubleshooting.html#troubleshooting-failed">Certificate Request Failed</a> in the <i>AWS Certificate Manager User Guide</i>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FailureReason {
+    /// <p><code>NO_AVAILABLE_CONTACTS</code></p>
+    NoAvailableContacts,
+    /// <p><code>ADDITIONAL_VERIFICATION_REQUIRED</code></p>
+    AdditionalVerificationRequired,
+    /// <p><code>DOMAIN_NOT_ALLOWED</code></p>
+    DomainNotAllowed,
+    /// <p><code>INVALID_PUBLIC_DOMAIN</code></p>
+    InvalidPublicDomain,
+    /// <p><code>DOMAIN_VALIDATION_DENIED</code></p>
+    DomainValidationDenied,
+    /// <p><code>CAA_ERROR</code></p>
+    CaaError,
+    /// <p><code>PCA_LIMIT_EXCEEDED</code></p>
+    PcaLimitExceeded,
+    /// <p><code>PCA_INVALID_ARN</code></p>
+    PcaInvalidArn,
+    /// <p><code>PCA_INVALID_STATE</code></p>
+    PcaInvalidState,
+    /// <p><code>PCA_REQUEST_FAILED</code></p>
+    PcaRequestFailed,
+    /// <p><code>PCA_NAME_CONSTRAINTS_VALIDATION</code></p>
+    PcaNameConstraintsValidation,
+    /// <p><code>PCA_RESOURCE_NOT_FOUND</code></p>
+    PcaResourceNotFound,
+    /// <p><code>PCA_INVALID_ARGS</code></p>
+    PcaInvalidArgs,
+    /// <p><code>PCA_INVALID_DURATION</code></p>
+    PcaInvalidDuration,
+    /// <p><code>PCA_ACCESS_DENIED</code></p>
+    PcaAccessDenied,
+    /// <p><code>SLR_NOT_FOUND</code></p>
+    SlrNotFound,
+    /// <p><code>OTHER</code></p>
+    Other,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FailureReason {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailureReason::NoAvailableContacts => "NO_AVAILABLE_CONTACTS",
+            FailureReason::AdditionalVerificationRequired => "ADDITIONAL_VERIFICATION_REQUIRED",
+            FailureReason::DomainNotAllowed => "DOMAIN_NOT_ALLOWED",
+            FailureReason::InvalidPublicDomain => "INVALID_PUBLIC_DOMAIN",
+            FailureReason::DomainValidationDenied => "DOMAIN_VALIDATION_DENIED",
+            FailureReason::CaaError => "CAA_ERROR",
+            FailureReason::PcaLimitExceeded => "PCA_LIMIT_EXCEEDED",
+            FailureReason::PcaInvalidArn => "PCA_INVALID_ARN",
+            FailureReason::PcaInvalidState => "PCA_INVALID_STATE",
+            FailureReason::PcaRequestFailed => "PCA_REQUEST_FAILED",
+            FailureReason::PcaNameConstraintsValidation => "PCA_NAME_CONSTRAINTS_VALIDATION",
+            FailureReason::PcaResourceNotFound => "PCA_RESOURCE_NOT_FOUND",
+            FailureReason::PcaInvalidArgs => "PCA_INVALID_ARGS",
+            FailureReason::PcaInvalidDuration => "PCA_INVALID_DURATION",
+            FailureReason::PcaAccessDenied => "PCA_ACCESS_DENIED",
+            FailureReason::SlrNotFound => "SLR_NOT_FOUND",
+            FailureReason::Other => "OTHER",
+            FailureReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FailureReason {
+    fn from(value: &'a str) -> FailureReason {
+        match value {
+            "NO_AVAILABLE_CONTACTS" => FailureReason::NoAvailableContacts,
+            "ADDITIONAL_VERIFICATION_REQUIRED" => FailureReason::AdditionalVerificationRequired,
+            "DOMAIN_NOT_ALLOWED" => FailureReason::DomainNotAllowed,
+            "INVALID_PUBLIC_DOMAIN" => FailureReason::InvalidPublicDomain,
+            "DOMAIN_VALIDATION_DENIED" => FailureReason::DomainValidationDenied,
+            "CAA_ERROR" => FailureReason::CaaError,
+            "PCA_LIMIT_EXCEEDED" => FailureReason::PcaLimitExceeded,
+            "PCA_INVALID_ARN" => FailureReason::PcaInvalidArn,
+            "PCA_INVALID_STATE" => FailureReason::PcaInvalidState,
+            "PCA_REQUEST_FAILED" => FailureReason::PcaRequestFailed,
+            "PCA_NAME_CONSTRAINTS_VALIDATION" => FailureReason::PcaNameConstraintsValidation,
+            "PCA_RESOURCE_NOT_FOUND" => FailureReason::PcaResourceNotFound,
+            "PCA_INVALID_ARGS" => FailureReason::PcaInvalidArgs,
+            "PCA_INVALID_DURATION" => FailureReason::PcaInvalidDuration,
+            "PCA_ACCESS_DENIED" => FailureReason::PcaAccessDenied,
+            "SLR_NOT_FOUND" => FailureReason::SlrNotFound,
+            "OTHER" => FailureReason::Other,
+            _ => FailureReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailureReason {
+    fn from(value: String) -> FailureReason {
+        FailureReason::from(value.as_str())
+    }
+}
+
+impl From<FailureReason> for String {
+    fn from(value: FailureReason) -> String {
+        match value {
+            FailureReason::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FailureReason {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FailureReason {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FailureReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FailureReason::from(value))
+    }
+}
+
 /// <p>This structure can be used in the <a>ListCertificates</a> action to filter the output of the certificate list. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct Filters {
     /// <p>Specify one or more <a>ExtendedKeyUsage</a> extension values.</p>
     #[serde(rename = "extendedKeyUsage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub extended_key_usage: Option<Vec<String>>,
+    pub extended_key_usage: Option<Vec<ExtendedKeyUsageName>>,
     /// <p>Specify one or more algorithms that can be used to generate key pairs.</p>
     #[serde(rename = "keyTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_types: Option<Vec<String>>,
+    pub key_types: Option<Vec<KeyAlgorithm>>,
     /// <p>Specify one or more <a>KeyUsage</a> extension values.</p>
     #[serde(rename = "keyUsage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_usage: Option<Vec<String>>,
+    pub key_usage: Option<Vec<KeyUsageName>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -299,6 +1017,36 @@ pub struct GetCertificateRequest {
     pub certificate_arn: String,
 }
 
+impl GetCertificateRequest {
+    /// Returns a builder for `GetCertificateRequest`, checking its required members when built.
+    pub fn builder() -> GetCertificateRequestBuilder {
+        GetCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCertificateRequest`](struct.GetCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCertificateRequestBuilder {
+    certificate_arn: Option<String>,
+}
+
+impl GetCertificateRequestBuilder {
+    /// Sets the [`certificate_arn`](struct.GetCertificateRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(GetCertificateRequest {
+            certificate_arn: self
+                .certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("certificate_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCertificateResponse {
@@ -345,6 +1093,64 @@ pub struct ImportCertificateRequest {
     pub private_key: bytes::Bytes,
 }
 
+impl ImportCertificateRequest {
+    /// Returns a builder for `ImportCertificateRequest`, checking its required members when built.
+    pub fn builder() -> ImportCertificateRequestBuilder {
+        ImportCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ImportCertificateRequest`](struct.ImportCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct ImportCertificateRequestBuilder {
+    certificate: Option<bytes::Bytes>,
+    certificate_arn: Option<String>,
+    certificate_chain: Option<bytes::Bytes>,
+    private_key: Option<bytes::Bytes>,
+}
+
+impl ImportCertificateRequestBuilder {
+    /// Sets the [`certificate`](struct.ImportCertificateRequest.html#structfield.certificate) member.
+    pub fn certificate<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.certificate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_arn`](struct.ImportCertificateRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_chain`](struct.ImportCertificateRequest.html#structfield.certificate_chain) member.
+    pub fn certificate_chain<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.certificate_chain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`private_key`](struct.ImportCertificateRequest.html#structfield.private_key) member.
+    pub fn private_key<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.private_key = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ImportCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(ImportCertificateRequest {
+            certificate: self
+                .certificate
+                .ok_or(::rusoto_core::BuildError::missing("certificate"))?,
+            certificate_arn: self.certificate_arn,
+            certificate_chain: self.certificate_chain,
+            private_key: self
+                .private_key
+                .ok_or(::rusoto_core::BuildError::missing("private_key"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ImportCertificateResponse {
@@ -354,6 +1160,98 @@ pub struct ImportCertificateResponse {
     pub certificate_arn: Option<String>,
 }
 
+/// <p>The algorithm that was used to generate the public-private key pair.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeyAlgorithm {
+    /// <p><code>RSA_1024</code></p>
+    Rsa1024,
+    /// <p><code>RSA_2048</code></p>
+    Rsa2048,
+    /// <p><code>RSA_3072</code></p>
+    Rsa3072,
+    /// <p><code>RSA_4096</code></p>
+    Rsa4096,
+    /// <p><code>EC_prime256v1</code></p>
+    EcPrime256v1,
+    /// <p><code>EC_secp384r1</code></p>
+    EcSecp384r1,
+    /// <p><code>EC_secp521r1</code></p>
+    EcSecp521r1,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl KeyAlgorithm {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyAlgorithm::Rsa1024 => "RSA_1024",
+            KeyAlgorithm::Rsa2048 => "RSA_2048",
+            KeyAlgorithm::Rsa3072 => "RSA_3072",
+            KeyAlgorithm::Rsa4096 => "RSA_4096",
+            KeyAlgorithm::EcPrime256v1 => "EC_prime256v1",
+            KeyAlgorithm::EcSecp384r1 => "EC_secp384r1",
+            KeyAlgorithm::EcSecp521r1 => "EC_secp521r1",
+            KeyAlgorithm::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for KeyAlgorithm {
+    fn from(value: &'a str) -> KeyAlgorithm {
+        match value {
+            "RSA_1024" => KeyAlgorithm::Rsa1024,
+            "RSA_2048" => KeyAlgorithm::Rsa2048,
+            "RSA_3072" => KeyAlgorithm::Rsa3072,
+            "RSA_4096" => KeyAlgorithm::Rsa4096,
+            "EC_prime256v1" => KeyAlgorithm::EcPrime256v1,
+            "EC_secp384r1" => KeyAlgorithm::EcSecp384r1,
+            "EC_secp521r1" => KeyAlgorithm::EcSecp521r1,
+            _ => KeyAlgorithm::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyAlgorithm {
+    fn from(value: String) -> KeyAlgorithm {
+        KeyAlgorithm::from(value.as_str())
+    }
+}
+
+impl From<KeyAlgorithm> for String {
+    fn from(value: KeyAlgorithm) -> String {
+        match value {
+            KeyAlgorithm::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for KeyAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for KeyAlgorithm {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyAlgorithm {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<KeyAlgorithm, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(KeyAlgorithm::from(value))
+    }
+}
+
 /// <p>The Key Usage X.509 v3 extension defines the purpose of the public key contained in the certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -361,7 +1259,115 @@ pub struct KeyUsage {
     /// <p>A string value that contains a Key Usage extension name.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<KeyUsageName>,
+}
+
+/// <p>Specify one or more <a>KeyUsage</a> extension values.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeyUsageName {
+    /// <p><code>DIGITAL_SIGNATURE</code></p>
+    DigitalSignature,
+    /// <p><code>NON_REPUDIATION</code></p>
+    NonRepudiation,
+    /// <p><code>KEY_ENCIPHERMENT</code></p>
+    KeyEncipherment,
+    /// <p><code>DATA_ENCIPHERMENT</code></p>
+    DataEncipherment,
+    /// <p><code>KEY_AGREEMENT</code></p>
+    KeyAgreement,
+    /// <p><code>CERTIFICATE_SIGNING</code></p>
+    CertificateSigning,
+    /// <p><code>CRL_SIGNING</code></p>
+    CrlSigning,
+    /// <p><code>ENCIPHER_ONLY</code></p>
+    EncipherOnly,
+    /// <p><code>DECIPHER_ONLY</code></p>
+    DecipherOnly,
+    /// <p><code>ANY</code></p>
+    Any,
+    /// <p><code>CUSTOM</code></p>
+    Custom,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl KeyUsageName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyUsageName::DigitalSignature => "DIGITAL_SIGNATURE",
+            KeyUsageName::NonRepudiation => "NON_REPUDIATION",
+            KeyUsageName::KeyEncipherment => "KEY_ENCIPHERMENT",
+            KeyUsageName::DataEncipherment => "DATA_ENCIPHERMENT",
+            KeyUsageName::KeyAgreement => "KEY_AGREEMENT",
+            KeyUsageName::CertificateSigning => "CERTIFICATE_SIGNING",
+            KeyUsageName::CrlSigning => "CRL_SIGNING",
+            KeyUsageName::EncipherOnly => "ENCIPHER_ONLY",
+            KeyUsageName::DecipherOnly => "DECIPHER_ONLY",
+            KeyUsageName::Any => "ANY",
+            KeyUsageName::Custom => "CUSTOM",
+            KeyUsageName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for KeyUsageName {
+    fn from(value: &'a str) -> KeyUsageName {
+        match value {
+            "DIGITAL_SIGNATURE" => KeyUsageName::DigitalSignature,
+            "NON_REPUDIATION" => KeyUsageName::NonRepudiation,
+            "KEY_ENCIPHERMENT" => KeyUsageName::KeyEncipherment,
+            "DATA_ENCIPHERMENT" => KeyUsageName::DataEncipherment,
+            "KEY_AGREEMENT" => KeyUsageName::KeyAgreement,
+            "CERTIFICATE_SIGNING" => KeyUsageName::CertificateSigning,
+            "CRL_SIGNING" => KeyUsageName::CrlSigning,
+            "ENCIPHER_ONLY" => KeyUsageName::EncipherOnly,
+            "DECIPHER_ONLY" => KeyUsageName::DecipherOnly,
+            "ANY" => KeyUsageName::Any,
+            "CUSTOM" => KeyUsageName::Custom,
+            _ => KeyUsageName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyUsageName {
+    fn from(value: String) -> KeyUsageName {
+        KeyUsageName::from(value.as_str())
+    }
+}
+
+impl From<KeyUsageName> for String {
+    fn from(value: KeyUsageName) -> String {
+        match value {
+            KeyUsageName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for KeyUsageName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for KeyUsageName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyUsageName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<KeyUsageName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(KeyUsageName::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -369,7 +1375,7 @@ pub struct ListCertificatesRequest {
     /// <p>Filter the certificate list by status value.</p>
     #[serde(rename = "CertificateStatuses")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_statuses: Option<Vec<String>>,
+    pub certificate_statuses: Option<Vec<CertificateStatus>>,
     /// <p>Filter the certificate list. For more information, see the <a>Filters</a> structure.</p>
     #[serde(rename = "Includes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -384,54 +1390,434 @@ pub struct ListCertificatesRequest {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListCertificatesResponse {
-    /// <p>A list of ACM certificates.</p>
-    #[serde(rename = "CertificateSummaryList")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_summary_list: Option<Vec<CertificateSummary>>,
-    /// <p>When the list is truncated, this value is present and contains the value to use for the <code>NextToken</code> parameter in a subsequent pagination request.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl ListCertificatesRequest {
+    /// Returns a builder for `ListCertificatesRequest`, checking its required members when built.
+    pub fn builder() -> ListCertificatesRequestBuilder {
+        ListCertificatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCertificatesRequest`](struct.ListCertificatesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCertificatesRequestBuilder {
+    certificate_statuses: Option<Vec<CertificateStatus>>,
+    includes: Option<Filters>,
+    max_items: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListCertificatesRequestBuilder {
+    /// Sets the [`certificate_statuses`](struct.ListCertificatesRequest.html#structfield.certificate_statuses) member.
+    pub fn certificate_statuses<T: Into<Vec<CertificateStatus>>>(mut self, value: T) -> Self {
+        self.certificate_statuses = Some(value.into());
+        self
+    }
+
+    /// Sets the [`includes`](struct.ListCertificatesRequest.html#structfield.includes) member.
+    pub fn includes<T: Into<Filters>>(mut self, value: T) -> Self {
+        self.includes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListCertificatesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListCertificatesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCertificatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListCertificatesRequest, ::rusoto_core::BuildError> {
+        Ok(ListCertificatesRequest {
+            certificate_statuses: self.certificate_statuses,
+            includes: self.includes,
+            max_items: self.max_items,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListCertificatesResponse {
+    /// <p>A list of ACM certificates.</p>
+    #[serde(rename = "CertificateSummaryList")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate_summary_list: Option<Vec<CertificateSummary>>,
+    /// <p>When the list is truncated, this value is present and contains the value to use for the <code>NextToken</code> parameter in a subsequent pagination request.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListTagsForCertificateRequest {
+    /// <p>String that contains the ARN of the ACM certificate for which you want to list the tags. This must have the following form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>. </p>
+    #[serde(rename = "CertificateArn")]
+    pub certificate_arn: String,
+}
+
+impl ListTagsForCertificateRequest {
+    /// Returns a builder for `ListTagsForCertificateRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForCertificateRequestBuilder {
+        ListTagsForCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForCertificateRequest`](struct.ListTagsForCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForCertificateRequestBuilder {
+    certificate_arn: Option<String>,
+}
+
+impl ListTagsForCertificateRequestBuilder {
+    /// Sets the [`certificate_arn`](struct.ListTagsForCertificateRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForCertificateRequest {
+            certificate_arn: self
+                .certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("certificate_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListTagsForCertificateResponse {
+    /// <p>The key-value pairs that define the applied tags.</p>
+    #[serde(rename = "Tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+/// <p>The type of DNS record. Currently this can be <code>CNAME</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordType {
+    /// <p><code>CNAME</code></p>
+    Cname,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RecordType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordType::Cname => "CNAME",
+            RecordType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RecordType {
+    fn from(value: &'a str) -> RecordType {
+        match value {
+            "CNAME" => RecordType::Cname,
+            _ => RecordType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordType {
+    fn from(value: String) -> RecordType {
+        RecordType::from(value.as_str())
+    }
+}
+
+impl From<RecordType> for String {
+    fn from(value: RecordType) -> String {
+        match value {
+            RecordType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RecordType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RecordType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RecordType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RecordType::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct RemoveTagsFromCertificateRequest {
+    /// <p>String that contains the ARN of the ACM Certificate with one or more tags that you want to remove. This must be of the form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>. </p>
+    #[serde(rename = "CertificateArn")]
+    pub certificate_arn: String,
+    /// <p>The key-value pair that defines the tag to remove.</p>
+    #[serde(rename = "Tags")]
+    pub tags: Vec<Tag>,
+}
+
+impl RemoveTagsFromCertificateRequest {
+    /// Returns a builder for `RemoveTagsFromCertificateRequest`, checking its required members when built.
+    pub fn builder() -> RemoveTagsFromCertificateRequestBuilder {
+        RemoveTagsFromCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsFromCertificateRequest`](struct.RemoveTagsFromCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsFromCertificateRequestBuilder {
+    certificate_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl RemoveTagsFromCertificateRequestBuilder {
+    /// Sets the [`certificate_arn`](struct.RemoveTagsFromCertificateRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RemoveTagsFromCertificateRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsFromCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveTagsFromCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsFromCertificateRequest {
+            certificate_arn: self
+                .certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("certificate_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct RenewCertificateRequest {
+    /// <p>String that contains the ARN of the ACM certificate to be renewed. This must be of the form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
+    #[serde(rename = "CertificateArn")]
+    pub certificate_arn: String,
+}
+
+impl RenewCertificateRequest {
+    /// Returns a builder for `RenewCertificateRequest`, checking its required members when built.
+    pub fn builder() -> RenewCertificateRequestBuilder {
+        RenewCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RenewCertificateRequest`](struct.RenewCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct RenewCertificateRequestBuilder {
+    certificate_arn: Option<String>,
+}
+
+impl RenewCertificateRequestBuilder {
+    /// Sets the [`certificate_arn`](struct.RenewCertificateRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RenewCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RenewCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(RenewCertificateRequest {
+            certificate_arn: self
+                .certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("certificate_arn"))?,
+        })
+    }
+}
+
+/// <p>Specifies whether the certificate is eligible for renewal. At this time, only exported private certificates can be renewed with the <a>RenewCertificate</a> command.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RenewalEligibility {
+    /// <p><code>ELIGIBLE</code></p>
+    Eligible,
+    /// <p><code>INELIGIBLE</code></p>
+    Ineligible,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RenewalEligibility {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RenewalEligibility::Eligible => "ELIGIBLE",
+            RenewalEligibility::Ineligible => "INELIGIBLE",
+            RenewalEligibility::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RenewalEligibility {
+    fn from(value: &'a str) -> RenewalEligibility {
+        match value {
+            "ELIGIBLE" => RenewalEligibility::Eligible,
+            "INELIGIBLE" => RenewalEligibility::Ineligible,
+            _ => RenewalEligibility::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RenewalEligibility {
+    fn from(value: String) -> RenewalEligibility {
+        RenewalEligibility::from(value.as_str())
+    }
+}
+
+impl From<RenewalEligibility> for String {
+    fn from(value: RenewalEligibility) -> String {
+        match value {
+            RenewalEligibility::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RenewalEligibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RenewalEligibility {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RenewalEligibility {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RenewalEligibility, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RenewalEligibility::from(value))
+    }
+}
+
+/// <p>The status of ACM's <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> of the certificate.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RenewalStatus {
+    /// <p><code>PENDING_AUTO_RENEWAL</code></p>
+    PendingAutoRenewal,
+    /// <p><code>PENDING_VALIDATION</code></p>
+    PendingValidation,
+    /// <p><code>SUCCESS</code></p>
+    Success,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RenewalStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RenewalStatus::PendingAutoRenewal => "PENDING_AUTO_RENEWAL",
+            RenewalStatus::PendingValidation => "PENDING_VALIDATION",
+            RenewalStatus::Success => "SUCCESS",
+            RenewalStatus::Failed => "FAILED",
+            RenewalStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RenewalStatus {
+    fn from(value: &'a str) -> RenewalStatus {
+        match value {
+            "PENDING_AUTO_RENEWAL" => RenewalStatus::PendingAutoRenewal,
+            "PENDING_VALIDATION" => RenewalStatus::PendingValidation,
+            "SUCCESS" => RenewalStatus::Success,
+            "FAILED" => RenewalStatus::Failed,
+            _ => RenewalStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RenewalStatus {
+    fn from(value: String) -> RenewalStatus {
+        RenewalStatus::from(value.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ListTagsForCertificateRequest {
-    /// <p>String that contains the ARN of the ACM certificate for which you want to list the tags. This must have the following form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>. </p>
-    #[serde(rename = "CertificateArn")]
-    pub certificate_arn: String,
+impl From<RenewalStatus> for String {
+    fn from(value: RenewalStatus) -> String {
+        match value {
+            RenewalStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListTagsForCertificateResponse {
-    /// <p>The key-value pairs that define the applied tags.</p>
-    #[serde(rename = "Tags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<Vec<Tag>>,
+impl fmt::Display for RenewalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct RemoveTagsFromCertificateRequest {
-    /// <p>String that contains the ARN of the ACM Certificate with one or more tags that you want to remove. This must be of the form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>. </p>
-    #[serde(rename = "CertificateArn")]
-    pub certificate_arn: String,
-    /// <p>The key-value pair that defines the tag to remove.</p>
-    #[serde(rename = "Tags")]
-    pub tags: Vec<Tag>,
+impl ::serde::Serialize for RenewalStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct RenewCertificateRequest {
-    /// <p>String that contains the ARN of the ACM certificate to be renewed. This must be of the form:</p> <p> <code>arn:aws:acm:region:123456789012:certificate/12345678-1234-1234-1234-123456789012</code> </p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
-    #[serde(rename = "CertificateArn")]
-    pub certificate_arn: String,
+impl<'de> ::serde::Deserialize<'de> for RenewalStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RenewalStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RenewalStatus::from(value))
+    }
 }
 
 /// <p>Contains information about the status of ACM's <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> for the certificate. This structure exists only when the certificate type is <code>AMAZON_ISSUED</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RenewalSummary {
     /// <p>Contains information about the validation of each domain name in the certificate, as it pertains to ACM's <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a>. This is different from the initial validation that occurs as a result of the <a>RequestCertificate</a> request. This field exists only when the certificate type is <code>AMAZON_ISSUED</code>.</p>
@@ -439,14 +1825,15 @@ pub struct RenewalSummary {
     pub domain_validation_options: Vec<DomainValidation>,
     /// <p>The status of ACM's <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-renewal.html">managed renewal</a> of the certificate.</p>
     #[serde(rename = "RenewalStatus")]
-    pub renewal_status: String,
+    pub renewal_status: RenewalStatus,
     /// <p>The reason that a renewal request was unsuccessful.</p>
     #[serde(rename = "RenewalStatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub renewal_status_reason: Option<String>,
+    pub renewal_status_reason: Option<FailureReason>,
     /// <p>The time at which the renewal summary was last updated.</p>
     #[serde(rename = "UpdatedAt")]
-    pub updated_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub updated_at: EpochTimestamp,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -477,7 +1864,90 @@ pub struct RequestCertificateRequest {
     /// <p>The method you want to use if you are requesting a public certificate to validate that you own or control domain. You can <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-validate-dns.html">validate with DNS</a> or <a href="https://docs.aws.amazon.com/acm/latest/userguide/gs-acm-validate-email.html">validate with email</a>. We recommend that you use DNS validation. </p>
     #[serde(rename = "ValidationMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_method: Option<String>,
+    pub validation_method: Option<ValidationMethod>,
+}
+
+impl RequestCertificateRequest {
+    /// Returns a builder for `RequestCertificateRequest`, checking its required members when built.
+    pub fn builder() -> RequestCertificateRequestBuilder {
+        RequestCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RequestCertificateRequest`](struct.RequestCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct RequestCertificateRequestBuilder {
+    certificate_authority_arn: Option<String>,
+    domain_name: Option<String>,
+    domain_validation_options: Option<Vec<DomainValidationOption>>,
+    idempotency_token: Option<String>,
+    options: Option<CertificateOptions>,
+    subject_alternative_names: Option<Vec<String>>,
+    validation_method: Option<ValidationMethod>,
+}
+
+impl RequestCertificateRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.RequestCertificateRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.RequestCertificateRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_validation_options`](struct.RequestCertificateRequest.html#structfield.domain_validation_options) member.
+    pub fn domain_validation_options<T: Into<Vec<DomainValidationOption>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.domain_validation_options = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.RequestCertificateRequest.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`options`](struct.RequestCertificateRequest.html#structfield.options) member.
+    pub fn options<T: Into<CertificateOptions>>(mut self, value: T) -> Self {
+        self.options = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subject_alternative_names`](struct.RequestCertificateRequest.html#structfield.subject_alternative_names) member.
+    pub fn subject_alternative_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subject_alternative_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`validation_method`](struct.RequestCertificateRequest.html#structfield.validation_method) member.
+    pub fn validation_method<T: Into<ValidationMethod>>(mut self, value: T) -> Self {
+        self.validation_method = Some(value.into());
+        self
+    }
+
+    /// Builds the `RequestCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RequestCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(RequestCertificateRequest {
+            certificate_authority_arn: self.certificate_authority_arn,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            domain_validation_options: self.domain_validation_options,
+            idempotency_token: self.idempotency_token,
+            options: self.options,
+            subject_alternative_names: self.subject_alternative_names,
+            validation_method: self.validation_method,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -502,8 +1972,60 @@ pub struct ResendValidationEmailRequest {
     pub validation_domain: String,
 }
 
+impl ResendValidationEmailRequest {
+    /// Returns a builder for `ResendValidationEmailRequest`, checking its required members when built.
+    pub fn builder() -> ResendValidationEmailRequestBuilder {
+        ResendValidationEmailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ResendValidationEmailRequest`](struct.ResendValidationEmailRequest.html).
+#[derive(Default, Debug)]
+pub struct ResendValidationEmailRequestBuilder {
+    certificate_arn: Option<String>,
+    domain: Option<String>,
+    validation_domain: Option<String>,
+}
+
+impl ResendValidationEmailRequestBuilder {
+    /// Sets the [`certificate_arn`](struct.ResendValidationEmailRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.ResendValidationEmailRequest.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`validation_domain`](struct.ResendValidationEmailRequest.html#structfield.validation_domain) member.
+    pub fn validation_domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.validation_domain = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResendValidationEmailRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResendValidationEmailRequest, ::rusoto_core::BuildError> {
+        Ok(ResendValidationEmailRequest {
+            certificate_arn: self
+                .certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("certificate_arn"))?,
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            validation_domain: self
+                .validation_domain
+                .ok_or(::rusoto_core::BuildError::missing("validation_domain"))?,
+        })
+    }
+}
+
 /// <p>Contains a DNS record value that you can use to can use to validate ownership or control of a domain. This is used by the <a>DescribeCertificate</a> action. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ResourceRecord {
     /// <p>The name of the DNS record to create in your domain. This is supplied by ACM.</p>
@@ -511,12 +2033,116 @@ pub struct ResourceRecord {
     pub name: String,
     /// <p>The type of DNS record. Currently this can be <code>CNAME</code>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: RecordType,
     /// <p>The value of the CNAME record to add to your DNS database. This is supplied by ACM.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+/// <p>The reason the certificate was revoked. This value exists only when the certificate status is <code>REVOKED</code>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RevocationReason {
+    /// <p><code>UNSPECIFIED</code></p>
+    Unspecified,
+    /// <p><code>KEY_COMPROMISE</code></p>
+    KeyCompromise,
+    /// <p><code>CA_COMPROMISE</code></p>
+    CaCompromise,
+    /// <p><code>AFFILIATION_CHANGED</code></p>
+    AffiliationChanged,
+    /// <p><code>SUPERCEDED</code></p>
+    Superceded,
+    /// <p><code>CESSATION_OF_OPERATION</code></p>
+    CessationOfOperation,
+    /// <p><code>CERTIFICATE_HOLD</code></p>
+    CertificateHold,
+    /// <p><code>REMOVE_FROM_CRL</code></p>
+    RemoveFromCrl,
+    /// <p><code>PRIVILEGE_WITHDRAWN</code></p>
+    PrivilegeWithdrawn,
+    /// <p><code>A_A_COMPROMISE</code></p>
+    AACompromise,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RevocationReason {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RevocationReason::Unspecified => "UNSPECIFIED",
+            RevocationReason::KeyCompromise => "KEY_COMPROMISE",
+            RevocationReason::CaCompromise => "CA_COMPROMISE",
+            RevocationReason::AffiliationChanged => "AFFILIATION_CHANGED",
+            RevocationReason::Superceded => "SUPERCEDED",
+            RevocationReason::CessationOfOperation => "CESSATION_OF_OPERATION",
+            RevocationReason::CertificateHold => "CERTIFICATE_HOLD",
+            RevocationReason::RemoveFromCrl => "REMOVE_FROM_CRL",
+            RevocationReason::PrivilegeWithdrawn => "PRIVILEGE_WITHDRAWN",
+            RevocationReason::AACompromise => "A_A_COMPROMISE",
+            RevocationReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RevocationReason {
+    fn from(value: &'a str) -> RevocationReason {
+        match value {
+            "UNSPECIFIED" => RevocationReason::Unspecified,
+            "KEY_COMPROMISE" => RevocationReason::KeyCompromise,
+            "CA_COMPROMISE" => RevocationReason::CaCompromise,
+            "AFFILIATION_CHANGED" => RevocationReason::AffiliationChanged,
+            "SUPERCEDED" => RevocationReason::Superceded,
+            "CESSATION_OF_OPERATION" => RevocationReason::CessationOfOperation,
+            "CERTIFICATE_HOLD" => RevocationReason::CertificateHold,
+            "REMOVE_FROM_CRL" => RevocationReason::RemoveFromCrl,
+            "PRIVILEGE_WITHDRAWN" => RevocationReason::PrivilegeWithdrawn,
+            "A_A_COMPROMISE" => RevocationReason::AACompromise,
+            _ => RevocationReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RevocationReason {
+    fn from(value: String) -> RevocationReason {
+        RevocationReason::from(value.as_str())
+    }
+}
+
+impl From<RevocationReason> for String {
+    fn from(value: RevocationReason) -> String {
+        match value {
+            RevocationReason::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RevocationReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RevocationReason {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RevocationReason {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RevocationReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RevocationReason::from(value))
+    }
+}
+
 /// <p>A key-value pair that identifies or specifies metadata about an ACM resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -539,6 +2165,120 @@ pub struct UpdateCertificateOptionsRequest {
     pub options: CertificateOptions,
 }
 
+impl UpdateCertificateOptionsRequest {
+    /// Returns a builder for `UpdateCertificateOptionsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateCertificateOptionsRequestBuilder {
+        UpdateCertificateOptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateCertificateOptionsRequest`](struct.UpdateCertificateOptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateCertificateOptionsRequestBuilder {
+    certificate_arn: Option<String>,
+    options: Option<CertificateOptions>,
+}
+
+impl UpdateCertificateOptionsRequestBuilder {
+    /// Sets the [`certificate_arn`](struct.UpdateCertificateOptionsRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`options`](struct.UpdateCertificateOptionsRequest.html#structfield.options) member.
+    pub fn options<T: Into<CertificateOptions>>(mut self, value: T) -> Self {
+        self.options = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateCertificateOptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateCertificateOptionsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateCertificateOptionsRequest {
+            certificate_arn: self
+                .certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("certificate_arn"))?,
+            options: self
+                .options
+                .ok_or(::rusoto_core::BuildError::missing("options"))?,
+        })
+    }
+}
+
+/// <p>Specifies the domain validation method.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ValidationMethod {
+    /// <p><code>EMAIL</code></p>
+    Email,
+    /// <p><code>DNS</code></p>
+    Dns,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ValidationMethod {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ValidationMethod::Email => "EMAIL",
+            ValidationMethod::Dns => "DNS",
+            ValidationMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ValidationMethod {
+    fn from(value: &'a str) -> ValidationMethod {
+        match value {
+            "EMAIL" => ValidationMethod::Email,
+            "DNS" => ValidationMethod::Dns,
+            _ => ValidationMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ValidationMethod {
+    fn from(value: String) -> ValidationMethod {
+        ValidationMethod::from(value.as_str())
+    }
+}
+
+impl From<ValidationMethod> for String {
+    fn from(value: ValidationMethod) -> String {
+        match value {
+            ValidationMethod::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ValidationMethod {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ValidationMethod {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ValidationMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ValidationMethod::from(value))
+    }
+}
+
 /// Errors returned by AddTagsToCertificate
 #[derive(Debug, PartialEq)]
 pub enum AddTagsToCertificateError {
@@ -1210,6 +2950,93 @@ pub trait Acm {
         input: UpdateCertificateOptionsRequest,
     ) -> RusotoFuture<(), UpdateCertificateOptionsError>;
 }
+impl<T: ?Sized + Acm> Acm for ::std::sync::Arc<T> {
+    fn add_tags_to_certificate(
+        &self,
+        input: AddTagsToCertificateRequest,
+    ) -> RusotoFuture<(), AddTagsToCertificateError> {
+        (**self).add_tags_to_certificate(input)
+    }
+    fn delete_certificate(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> RusotoFuture<(), DeleteCertificateError> {
+        (**self).delete_certificate(input)
+    }
+    fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> RusotoFuture<DescribeCertificateResponse, DescribeCertificateError> {
+        (**self).describe_certificate(input)
+    }
+    fn export_certificate(
+        &self,
+        input: ExportCertificateRequest,
+    ) -> RusotoFuture<ExportCertificateResponse, ExportCertificateError> {
+        (**self).export_certificate(input)
+    }
+    fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> RusotoFuture<GetCertificateResponse, GetCertificateError> {
+        (**self).get_certificate(input)
+    }
+    fn import_certificate(
+        &self,
+        input: ImportCertificateRequest,
+    ) -> RusotoFuture<ImportCertificateResponse, ImportCertificateError> {
+        (**self).import_certificate(input)
+    }
+    fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> RusotoFuture<ListCertificatesResponse, ListCertificatesError> {
+        (**self).list_certificates(input)
+    }
+    fn list_tags_for_certificate(
+        &self,
+        input: ListTagsForCertificateRequest,
+    ) -> RusotoFuture<ListTagsForCertificateResponse, ListTagsForCertificateError> {
+        (**self).list_tags_for_certificate(input)
+    }
+    fn remove_tags_from_certificate(
+        &self,
+        input: RemoveTagsFromCertificateRequest,
+    ) -> RusotoFuture<(), RemoveTagsFromCertificateError> {
+        (**self).remove_tags_from_certificate(input)
+    }
+    fn renew_certificate(
+        &self,
+        input: RenewCertificateRequest,
+    ) -> RusotoFuture<(), RenewCertificateError> {
+        (**self).renew_certificate(input)
+    }
+    fn request_certificate(
+        &self,
+        input: RequestCertificateRequest,
+    ) -> RusotoFuture<RequestCertificateResponse, RequestCertificateError> {
+        (**self).request_certificate(input)
+    }
+    fn resend_validation_email(
+        &self,
+        input: ResendValidationEmailRequest,
+    ) -> RusotoFuture<(), ResendValidationEmailError> {
+        (**self).resend_validation_email(input)
+    }
+    fn update_certificate_options(
+        &self,
+        input: UpdateCertificateOptionsRequest,
+    ) -> RusotoFuture<(), UpdateCertificateOptionsError> {
+        (**self).update_certificate_options(input)
+    }
+}
+/// The error codes ACM retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the ACM API.
 #[derive(Clone)]
 pub struct AcmClient {
@@ -1225,6 +3052,24 @@ impl AcmClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ACM`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> AcmClient {
+        Self::new(region::Region::from_env_for_service("ACM"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `acm`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> AcmClient {
+        Self::new_with_client(config.client().clone(), config.region_for("acm", "ACM"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1243,7 +3088,49 @@ impl AcmClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AcmClient {
-        AcmClient { client, region }
+        AcmClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "acm")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "acm", "acm", "ACM")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Acm` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Acm + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for AcmClient {
+    fn service_name(&self) -> &'static str {
+        "ACM"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "acm"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> AcmClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,40 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Alexa For Business clients.
+//!
+//! Makes a single read-only `ListBusinessReportSchedules` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_alexaforbusiness::{
+    AlexaForBusiness, AlexaForBusinessClient, ListBusinessReportSchedulesRequest,
+};
+
+fn main() {
+    let client = AlexaForBusinessClient::from_env();
+    match client
+        .list_business_report_schedules(ListBusinessReportSchedulesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListBusinessReportSchedules succeeded"),
+        Err(error) => {
+            eprintln!("ListBusinessReportSchedules failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>An address book with attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -66,6 +67,36 @@ pub struct ApproveSkillRequest {
     pub skill_id: String,
 }
 
+impl ApproveSkillRequest {
+    /// Returns a builder for `ApproveSkillRequest`, checking its required members when built.
+    pub fn builder() -> ApproveSkillRequestBuilder {
+        ApproveSkillRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ApproveSkillRequest`](struct.ApproveSkillRequest.html).
+#[derive(Default, Debug)]
+pub struct ApproveSkillRequestBuilder {
+    skill_id: Option<String>,
+}
+
+impl ApproveSkillRequestBuilder {
+    /// Sets the [`skill_id`](struct.ApproveSkillRequest.html#structfield.skill_id) member.
+    pub fn skill_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ApproveSkillRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ApproveSkillRequest, ::rusoto_core::BuildError> {
+        Ok(ApproveSkillRequest {
+            skill_id: self
+                .skill_id
+                .ok_or(::rusoto_core::BuildError::missing("skill_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ApproveSkillResponse {}
@@ -80,6 +111,49 @@ pub struct AssociateContactWithAddressBookRequest {
     pub contact_arn: String,
 }
 
+impl AssociateContactWithAddressBookRequest {
+    /// Returns a builder for `AssociateContactWithAddressBookRequest`, checking its required members when built.
+    pub fn builder() -> AssociateContactWithAddressBookRequestBuilder {
+        AssociateContactWithAddressBookRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateContactWithAddressBookRequest`](struct.AssociateContactWithAddressBookRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateContactWithAddressBookRequestBuilder {
+    address_book_arn: Option<String>,
+    contact_arn: Option<String>,
+}
+
+impl AssociateContactWithAddressBookRequestBuilder {
+    /// Sets the [`address_book_arn`](struct.AssociateContactWithAddressBookRequest.html#structfield.address_book_arn) member.
+    pub fn address_book_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.address_book_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`contact_arn`](struct.AssociateContactWithAddressBookRequest.html#structfield.contact_arn) member.
+    pub fn contact_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.contact_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateContactWithAddressBookRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateContactWithAddressBookRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AssociateContactWithAddressBookRequest {
+            address_book_arn: self
+                .address_book_arn
+                .ok_or(::rusoto_core::BuildError::missing("address_book_arn"))?,
+            contact_arn: self
+                .contact_arn
+                .ok_or(::rusoto_core::BuildError::missing("contact_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateContactWithAddressBookResponse {}
@@ -94,6 +168,49 @@ pub struct AssociateDeviceWithNetworkProfileRequest {
     pub network_profile_arn: String,
 }
 
+impl AssociateDeviceWithNetworkProfileRequest {
+    /// Returns a builder for `AssociateDeviceWithNetworkProfileRequest`, checking its required members when built.
+    pub fn builder() -> AssociateDeviceWithNetworkProfileRequestBuilder {
+        AssociateDeviceWithNetworkProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateDeviceWithNetworkProfileRequest`](struct.AssociateDeviceWithNetworkProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateDeviceWithNetworkProfileRequestBuilder {
+    device_arn: Option<String>,
+    network_profile_arn: Option<String>,
+}
+
+impl AssociateDeviceWithNetworkProfileRequestBuilder {
+    /// Sets the [`device_arn`](struct.AssociateDeviceWithNetworkProfileRequest.html#structfield.device_arn) member.
+    pub fn device_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_profile_arn`](struct.AssociateDeviceWithNetworkProfileRequest.html#structfield.network_profile_arn) member.
+    pub fn network_profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.network_profile_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateDeviceWithNetworkProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateDeviceWithNetworkProfileRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AssociateDeviceWithNetworkProfileRequest {
+            device_arn: self
+                .device_arn
+                .ok_or(::rusoto_core::BuildError::missing("device_arn"))?,
+            network_profile_arn: self
+                .network_profile_arn
+                .ok_or(::rusoto_core::BuildError::missing("network_profile_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateDeviceWithNetworkProfileResponse {}
@@ -110,6 +227,44 @@ pub struct AssociateDeviceWithRoomRequest {
     pub room_arn: Option<String>,
 }
 
+impl AssociateDeviceWithRoomRequest {
+    /// Returns a builder for `AssociateDeviceWithRoomRequest`, checking its required members when built.
+    pub fn builder() -> AssociateDeviceWithRoomRequestBuilder {
+        AssociateDeviceWithRoomRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateDeviceWithRoomRequest`](struct.AssociateDeviceWithRoomRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateDeviceWithRoomRequestBuilder {
+    device_arn: Option<String>,
+    room_arn: Option<String>,
+}
+
+impl AssociateDeviceWithRoomRequestBuilder {
+    /// Sets the [`device_arn`](struct.AssociateDeviceWithRoomRequest.html#structfield.device_arn) member.
+    pub fn device_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`room_arn`](struct.AssociateDeviceWithRoomRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateDeviceWithRoomRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateDeviceWithRoomRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateDeviceWithRoomRequest {
+            device_arn: self.device_arn,
+            room_arn: self.room_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateDeviceWithRoomResponse {}
@@ -126,6 +281,44 @@ pub struct AssociateSkillGroupWithRoomRequest {
     pub skill_group_arn: Option<String>,
 }
 
+impl AssociateSkillGroupWithRoomRequest {
+    /// Returns a builder for `AssociateSkillGroupWithRoomRequest`, checking its required members when built.
+    pub fn builder() -> AssociateSkillGroupWithRoomRequestBuilder {
+        AssociateSkillGroupWithRoomRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateSkillGroupWithRoomRequest`](struct.AssociateSkillGroupWithRoomRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateSkillGroupWithRoomRequestBuilder {
+    room_arn: Option<String>,
+    skill_group_arn: Option<String>,
+}
+
+impl AssociateSkillGroupWithRoomRequestBuilder {
+    /// Sets the [`room_arn`](struct.AssociateSkillGroupWithRoomRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_group_arn`](struct.AssociateSkillGroupWithRoomRequest.html#structfield.skill_group_arn) member.
+    pub fn skill_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_group_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateSkillGroupWithRoomRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateSkillGroupWithRoomRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateSkillGroupWithRoomRequest {
+            room_arn: self.room_arn,
+            skill_group_arn: self.skill_group_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateSkillGroupWithRoomResponse {}
@@ -141,6 +334,46 @@ pub struct AssociateSkillWithSkillGroupRequest {
     pub skill_id: String,
 }
 
+impl AssociateSkillWithSkillGroupRequest {
+    /// Returns a builder for `AssociateSkillWithSkillGroupRequest`, checking its required members when built.
+    pub fn builder() -> AssociateSkillWithSkillGroupRequestBuilder {
+        AssociateSkillWithSkillGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateSkillWithSkillGroupRequest`](struct.AssociateSkillWithSkillGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateSkillWithSkillGroupRequestBuilder {
+    skill_group_arn: Option<String>,
+    skill_id: Option<String>,
+}
+
+impl AssociateSkillWithSkillGroupRequestBuilder {
+    /// Sets the [`skill_group_arn`](struct.AssociateSkillWithSkillGroupRequest.html#structfield.skill_group_arn) member.
+    pub fn skill_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_id`](struct.AssociateSkillWithSkillGroupRequest.html#structfield.skill_id) member.
+    pub fn skill_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateSkillWithSkillGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateSkillWithSkillGroupRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateSkillWithSkillGroupRequest {
+            skill_group_arn: self.skill_group_arn,
+            skill_id: self
+                .skill_id
+                .ok_or(::rusoto_core::BuildError::missing("skill_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateSkillWithSkillGroupResponse {}
@@ -152,16 +385,48 @@ pub struct AssociateSkillWithUsersRequest {
     pub skill_id: String,
 }
 
+impl AssociateSkillWithUsersRequest {
+    /// Returns a builder for `AssociateSkillWithUsersRequest`, checking its required members when built.
+    pub fn builder() -> AssociateSkillWithUsersRequestBuilder {
+        AssociateSkillWithUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateSkillWithUsersRequest`](struct.AssociateSkillWithUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateSkillWithUsersRequestBuilder {
+    skill_id: Option<String>,
+}
+
+impl AssociateSkillWithUsersRequestBuilder {
+    /// Sets the [`skill_id`](struct.AssociateSkillWithUsersRequest.html#structfield.skill_id) member.
+    pub fn skill_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateSkillWithUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateSkillWithUsersRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateSkillWithUsersRequest {
+            skill_id: self
+                .skill_id
+                .ok_or(::rusoto_core::BuildError::missing("skill_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateSkillWithUsersResponse {}
 
 /// <p>The audio message. There is a 1 MB limit on the audio file input and the only supported format is MP3. To convert your MP3 audio files to an Alexa-friendly, </p> <p>required codec version (MPEG version 2) and bit rate (48 kbps), you might use converter software. One option for this is a command-line tool, FFmpeg. For more information, see <a href="https://www.ffmpeg.org/">FFmpeg</a>. The following command converts the provided &lt;input-file&gt; to an MP3 file that is played in the announcement:</p> <p> <code>ffmpeg -i &lt;input-file&gt; -ac 2 -codec:a libmp3lame -b:a 48k -ar 16000 &lt;output-file.mp3&gt;</code> </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Audio {
     /// <p>The locale of the audio message. Currently, en-US is supported.</p>
     #[serde(rename = "Locale")]
-    pub locale: String,
+    pub locale: Locale,
     /// <p>The location of the audio file. Currently, S3 URLs are supported. Only S3 locations comprised of safe characters are valid. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/UsingMetadata.html#Safe%20Characters">Safe Characters</a>.</p>
     #[serde(rename = "Location")]
     pub location: String,
@@ -173,8 +438,9 @@ pub struct Audio {
 pub struct BusinessReport {
     /// <p>The time of report delivery.</p>
     #[serde(rename = "DeliveryTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_time: Option<f64>,
+    pub delivery_time: Option<EpochTimestamp>,
     /// <p>The download link where a user can download the report.</p>
     #[serde(rename = "DownloadUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -182,7 +448,7 @@ pub struct BusinessReport {
     /// <p>The failure code.</p>
     #[serde(rename = "FailureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<BusinessReportFailureCode>,
     /// <p>The S3 location of the output reports.</p>
     #[serde(rename = "S3Location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -190,7 +456,7 @@ pub struct BusinessReport {
     /// <p>The status of the report generation execution (RUNNING, SUCCEEDED, or FAILED).</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<BusinessReportStatus>,
 }
 
 /// <p>The content range of the report.</p>
@@ -199,7 +465,231 @@ pub struct BusinessReportContentRange {
     /// <p>The interval of the content range.</p>
     #[serde(rename = "Interval")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interval: Option<String>,
+    pub interval: Option<BusinessReportInterval>,
+}
+
+/// <p>The failure code.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BusinessReportFailureCode {
+    /// <p><code>ACCESS_DENIED</code></p>
+    AccessDenied,
+    /// <p><code>NO_SUCH_BUCKET</code></p>
+    NoSuchBucket,
+    /// <p><code>INTERNAL_FAILURE</code></p>
+    InternalFailure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BusinessReportFailureCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BusinessReportFailureCode::AccessDenied => "ACCESS_DENIED",
+            BusinessReportFailureCode::NoSuchBucket => "NO_SUCH_BUCKET",
+            BusinessReportFailureCode::InternalFailure => "INTERNAL_FAILURE",
+            BusinessReportFailureCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BusinessReportFailureCode {
+    fn from(value: &'a str) -> BusinessReportFailureCode {
+        match value {
+            "ACCESS_DENIED" => BusinessReportFailureCode::AccessDenied,
+            "NO_SUCH_BUCKET" => BusinessReportFailureCode::NoSuchBucket,
+            "INTERNAL_FAILURE" => BusinessReportFailureCode::InternalFailure,
+            _ => BusinessReportFailureCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BusinessReportFailureCode {
+    fn from(value: String) -> BusinessReportFailureCode {
+        BusinessReportFailureCode::from(value.as_str())
+    }
+}
+
+impl From<BusinessReportFailureCode> for String {
+    fn from(value: BusinessReportFailureCode) -> String {
+        match value {
+            BusinessReportFailureCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BusinessReportFailureCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BusinessReportFailureCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BusinessReportFailureCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BusinessReportFailureCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BusinessReportFailureCode::from(value))
+    }
+}
+
+/// <p>The format of the generated report (individual CSV files or zipped files of individual files).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BusinessReportFormat {
+    /// <p><code>CSV</code></p>
+    Csv,
+    /// <p><code>CSV_ZIP</code></p>
+    CsvZip,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BusinessReportFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BusinessReportFormat::Csv => "CSV",
+            BusinessReportFormat::CsvZip => "CSV_ZIP",
+            BusinessReportFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BusinessReportFormat {
+    fn from(value: &'a str) -> BusinessReportFormat {
+        match value {
+            "CSV" => BusinessReportFormat::Csv,
+            "CSV_ZIP" => BusinessReportFormat::CsvZip,
+            _ => BusinessReportFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BusinessReportFormat {
+    fn from(value: String) -> BusinessReportFormat {
+        BusinessReportFormat::from(value.as_str())
+    }
+}
+
+impl From<BusinessReportFormat> for String {
+    fn from(value: BusinessReportFormat) -> String {
+        match value {
+            BusinessReportFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BusinessReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BusinessReportFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BusinessReportFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BusinessReportFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BusinessReportFormat::from(value))
+    }
+}
+
+/// <p>The interval of the content range.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BusinessReportInterval {
+    /// <p><code>ONE_DAY</code></p>
+    OneDay,
+    /// <p><code>ONE_WEEK</code></p>
+    OneWeek,
+    /// <p><code>THIRTY_DAYS</code></p>
+    ThirtyDays,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BusinessReportInterval {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BusinessReportInterval::OneDay => "ONE_DAY",
+            BusinessReportInterval::OneWeek => "ONE_WEEK",
+            BusinessReportInterval::ThirtyDays => "THIRTY_DAYS",
+            BusinessReportInterval::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BusinessReportInterval {
+    fn from(value: &'a str) -> BusinessReportInterval {
+        match value {
+            "ONE_DAY" => BusinessReportInterval::OneDay,
+            "ONE_WEEK" => BusinessReportInterval::OneWeek,
+            "THIRTY_DAYS" => BusinessReportInterval::ThirtyDays,
+            _ => BusinessReportInterval::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BusinessReportInterval {
+    fn from(value: String) -> BusinessReportInterval {
+        BusinessReportInterval::from(value.as_str())
+    }
+}
+
+impl From<BusinessReportInterval> for String {
+    fn from(value: BusinessReportInterval) -> String {
+        match value {
+            BusinessReportInterval::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BusinessReportInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BusinessReportInterval {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BusinessReportInterval {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BusinessReportInterval, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BusinessReportInterval::from(value))
+    }
 }
 
 /// <p>The recurrence of the reports.</p>
@@ -236,7 +726,7 @@ pub struct BusinessReportSchedule {
     /// <p>The format of the generated report (individual CSV files or zipped files of individual files).</p>
     #[serde(rename = "Format")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub format: Option<String>,
+    pub format: Option<BusinessReportFormat>,
     /// <p>The details of the last business report delivery for a specified time interval.</p>
     #[serde(rename = "LastBusinessReport")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -263,6 +753,82 @@ pub struct BusinessReportSchedule {
     pub schedule_name: Option<String>,
 }
 
+/// <p>The status of the report generation execution (RUNNING, SUCCEEDED, or FAILED).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BusinessReportStatus {
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BusinessReportStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BusinessReportStatus::Running => "RUNNING",
+            BusinessReportStatus::Succeeded => "SUCCEEDED",
+            BusinessReportStatus::Failed => "FAILED",
+            BusinessReportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BusinessReportStatus {
+    fn from(value: &'a str) -> BusinessReportStatus {
+        match value {
+            "RUNNING" => BusinessReportStatus::Running,
+            "SUCCEEDED" => BusinessReportStatus::Succeeded,
+            "FAILED" => BusinessReportStatus::Failed,
+            _ => BusinessReportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BusinessReportStatus {
+    fn from(value: String) -> BusinessReportStatus {
+        BusinessReportStatus::from(value.as_str())
+    }
+}
+
+impl From<BusinessReportStatus> for String {
+    fn from(value: BusinessReportStatus) -> String {
+        match value {
+            BusinessReportStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BusinessReportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BusinessReportStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BusinessReportStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BusinessReportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BusinessReportStatus::from(value))
+    }
+}
+
 /// <p>The skill store category that is shown. Alexa skills are assigned a specific skill category during creation, such as News, Social, and Sports.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -277,6 +843,82 @@ pub struct Category {
     pub category_name: Option<String>,
 }
 
+/// <p>The protocol, including SIP, SIPS, and H323.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CommsProtocol {
+    /// <p><code>SIP</code></p>
+    Sip,
+    /// <p><code>SIPS</code></p>
+    Sips,
+    /// <p><code>H323</code></p>
+    H323,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CommsProtocol {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CommsProtocol::Sip => "SIP",
+            CommsProtocol::Sips => "SIPS",
+            CommsProtocol::H323 => "H323",
+            CommsProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CommsProtocol {
+    fn from(value: &'a str) -> CommsProtocol {
+        match value {
+            "SIP" => CommsProtocol::Sip,
+            "SIPS" => CommsProtocol::Sips,
+            "H323" => CommsProtocol::H323,
+            _ => CommsProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CommsProtocol {
+    fn from(value: String) -> CommsProtocol {
+        CommsProtocol::from(value.as_str())
+    }
+}
+
+impl From<CommsProtocol> for String {
+    fn from(value: CommsProtocol) -> String {
+        match value {
+            CommsProtocol::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CommsProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CommsProtocol {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CommsProtocol {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CommsProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CommsProtocol::from(value))
+    }
+}
+
 /// <p>The default conference provider that is used if no other scheduled meetings are detected.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConferencePreference {
@@ -313,12 +955,188 @@ pub struct ConferenceProvider {
     /// <p>The type of conference providers.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ConferenceProviderType>,
+}
+
+/// <p>The type of conference providers.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConferenceProviderType {
+    /// <p><code>CHIME</code></p>
+    Chime,
+    /// <p><code>BLUEJEANS</code></p>
+    Bluejeans,
+    /// <p><code>FUZE</code></p>
+    Fuze,
+    /// <p><code>GOOGLE_HANGOUTS</code></p>
+    GoogleHangouts,
+    /// <p><code>POLYCOM</code></p>
+    Polycom,
+    /// <p><code>RINGCENTRAL</code></p>
+    Ringcentral,
+    /// <p><code>SKYPE_FOR_BUSINESS</code></p>
+    SkypeForBusiness,
+    /// <p><code>WEBEX</code></p>
+    Webex,
+    /// <p><code>ZOOM</code></p>
+    Zoom,
+    /// <p><code>CUSTOM</code></p>
+    Custom,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConferenceProviderType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConferenceProviderType::Chime => "CHIME",
+            ConferenceProviderType::Bluejeans => "BLUEJEANS",
+            ConferenceProviderType::Fuze => "FUZE",
+            ConferenceProviderType::GoogleHangouts => "GOOGLE_HANGOUTS",
+            ConferenceProviderType::Polycom => "POLYCOM",
+            ConferenceProviderType::Ringcentral => "RINGCENTRAL",
+            ConferenceProviderType::SkypeForBusiness => "SKYPE_FOR_BUSINESS",
+            ConferenceProviderType::Webex => "WEBEX",
+            ConferenceProviderType::Zoom => "ZOOM",
+            ConferenceProviderType::Custom => "CUSTOM",
+            ConferenceProviderType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>A contact with attributes.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl<'a> From<&'a str> for ConferenceProviderType {
+    fn from(value: &'a str) -> ConferenceProviderType {
+        match value {
+            "CHIME" => ConferenceProviderType::Chime,
+            "BLUEJEANS" => ConferenceProviderType::Bluejeans,
+            "FUZE" => ConferenceProviderType::Fuze,
+            "GOOGLE_HANGOUTS" => ConferenceProviderType::GoogleHangouts,
+            "POLYCOM" => ConferenceProviderType::Polycom,
+            "RINGCENTRAL" => ConferenceProviderType::Ringcentral,
+            "SKYPE_FOR_BUSINESS" => ConferenceProviderType::SkypeForBusiness,
+            "WEBEX" => ConferenceProviderType::Webex,
+            "ZOOM" => ConferenceProviderType::Zoom,
+            "CUSTOM" => ConferenceProviderType::Custom,
+            _ => ConferenceProviderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConferenceProviderType {
+    fn from(value: String) -> ConferenceProviderType {
+        ConferenceProviderType::from(value.as_str())
+    }
+}
+
+impl From<ConferenceProviderType> for String {
+    fn from(value: ConferenceProviderType) -> String {
+        match value {
+            ConferenceProviderType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConferenceProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConferenceProviderType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConferenceProviderType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConferenceProviderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConferenceProviderType::from(value))
+    }
+}
+
+/// <p>The latest available information about the connection status of a device. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionStatus {
+    /// <p><code>ONLINE</code></p>
+    Online,
+    /// <p><code>OFFLINE</code></p>
+    Offline,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConnectionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionStatus::Online => "ONLINE",
+            ConnectionStatus::Offline => "OFFLINE",
+            ConnectionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionStatus {
+    fn from(value: &'a str) -> ConnectionStatus {
+        match value {
+            "ONLINE" => ConnectionStatus::Online,
+            "OFFLINE" => ConnectionStatus::Offline,
+            _ => ConnectionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionStatus {
+    fn from(value: String) -> ConnectionStatus {
+        ConnectionStatus::from(value.as_str())
+    }
+}
+
+impl From<ConnectionStatus> for String {
+    fn from(value: ConnectionStatus) -> String {
+        match value {
+            ConnectionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConnectionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConnectionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConnectionStatus::from(value))
+    }
+}
+
+/// <p>A contact with attributes.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
 pub struct Contact {
     /// <p>The ARN of the contact.</p>
     #[serde(rename = "ContactArn")]
@@ -416,6 +1234,54 @@ pub struct CreateAddressBookRequest {
     pub name: String,
 }
 
+impl CreateAddressBookRequest {
+    /// Returns a builder for `CreateAddressBookRequest`, checking its required members when built.
+    pub fn builder() -> CreateAddressBookRequestBuilder {
+        CreateAddressBookRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAddressBookRequest`](struct.CreateAddressBookRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAddressBookRequestBuilder {
+    client_request_token: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateAddressBookRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateAddressBookRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateAddressBookRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateAddressBookRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAddressBookRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateAddressBookRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAddressBookRequest {
+            client_request_token: self.client_request_token,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateAddressBookResponse {
@@ -425,7 +1291,7 @@ pub struct CreateAddressBookResponse {
     pub address_book_arn: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateBusinessReportScheduleRequest {
     /// <p>The client request token.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -436,7 +1302,7 @@ pub struct CreateBusinessReportScheduleRequest {
     pub content_range: BusinessReportContentRange,
     /// <p>The format of the generated report (individual CSV files or zipped files of individual files).</p>
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: BusinessReportFormat,
     /// <p>The recurrence of the reports. If this isn't specified, the report will only be delivered one time when the API is called. </p>
     #[serde(rename = "Recurrence")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -455,6 +1321,88 @@ pub struct CreateBusinessReportScheduleRequest {
     pub schedule_name: Option<String>,
 }
 
+impl CreateBusinessReportScheduleRequest {
+    /// Returns a builder for `CreateBusinessReportScheduleRequest`, checking its required members when built.
+    pub fn builder() -> CreateBusinessReportScheduleRequestBuilder {
+        CreateBusinessReportScheduleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBusinessReportScheduleRequest`](struct.CreateBusinessReportScheduleRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateBusinessReportScheduleRequestBuilder {
+    client_request_token: Option<String>,
+    content_range: Option<BusinessReportContentRange>,
+    format: Option<BusinessReportFormat>,
+    recurrence: Option<BusinessReportRecurrence>,
+    s3_bucket_name: Option<String>,
+    s3_key_prefix: Option<String>,
+    schedule_name: Option<String>,
+}
+
+impl CreateBusinessReportScheduleRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateBusinessReportScheduleRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_range`](struct.CreateBusinessReportScheduleRequest.html#structfield.content_range) member.
+    pub fn content_range<T: Into<BusinessReportContentRange>>(mut self, value: T) -> Self {
+        self.content_range = Some(value.into());
+        self
+    }
+
+    /// Sets the [`format`](struct.CreateBusinessReportScheduleRequest.html#structfield.format) member.
+    pub fn format<T: Into<BusinessReportFormat>>(mut self, value: T) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recurrence`](struct.CreateBusinessReportScheduleRequest.html#structfield.recurrence) member.
+    pub fn recurrence<T: Into<BusinessReportRecurrence>>(mut self, value: T) -> Self {
+        self.recurrence = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_bucket_name`](struct.CreateBusinessReportScheduleRequest.html#structfield.s3_bucket_name) member.
+    pub fn s3_bucket_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_bucket_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_key_prefix`](struct.CreateBusinessReportScheduleRequest.html#structfield.s3_key_prefix) member.
+    pub fn s3_key_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_key_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule_name`](struct.CreateBusinessReportScheduleRequest.html#structfield.schedule_name) member.
+    pub fn schedule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBusinessReportScheduleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateBusinessReportScheduleRequest, ::rusoto_core::BuildError> {
+        Ok(CreateBusinessReportScheduleRequest {
+            client_request_token: self.client_request_token,
+            content_range: self
+                .content_range
+                .ok_or(::rusoto_core::BuildError::missing("content_range"))?,
+            format: self
+                .format
+                .ok_or(::rusoto_core::BuildError::missing("format"))?,
+            recurrence: self.recurrence,
+            s3_bucket_name: self.s3_bucket_name,
+            s3_key_prefix: self.s3_key_prefix,
+            schedule_name: self.schedule_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateBusinessReportScheduleResponse {
@@ -464,7 +1412,7 @@ pub struct CreateBusinessReportScheduleResponse {
     pub schedule_arn: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateConferenceProviderRequest {
     /// <p>The request token of the client.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -475,7 +1423,7 @@ pub struct CreateConferenceProviderRequest {
     pub conference_provider_name: String,
     /// <p>Represents a type within a list of predefined types.</p>
     #[serde(rename = "ConferenceProviderType")]
-    pub conference_provider_type: String,
+    pub conference_provider_type: ConferenceProviderType,
     /// <p>The IP endpoint and protocol for calling.</p>
     #[serde(rename = "IPDialIn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -489,6 +1437,82 @@ pub struct CreateConferenceProviderRequest {
     pub pstn_dial_in: Option<PSTNDialIn>,
 }
 
+impl CreateConferenceProviderRequest {
+    /// Returns a builder for `CreateConferenceProviderRequest`, checking its required members when built.
+    pub fn builder() -> CreateConferenceProviderRequestBuilder {
+        CreateConferenceProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConferenceProviderRequest`](struct.CreateConferenceProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateConferenceProviderRequestBuilder {
+    client_request_token: Option<String>,
+    conference_provider_name: Option<String>,
+    conference_provider_type: Option<ConferenceProviderType>,
+    ip_dial_in: Option<IPDialIn>,
+    meeting_setting: Option<MeetingSetting>,
+    pstn_dial_in: Option<PSTNDialIn>,
+}
+
+impl CreateConferenceProviderRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateConferenceProviderRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conference_provider_name`](struct.CreateConferenceProviderRequest.html#structfield.conference_provider_name) member.
+    pub fn conference_provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.conference_provider_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conference_provider_type`](struct.CreateConferenceProviderRequest.html#structfield.conference_provider_type) member.
+    pub fn conference_provider_type<T: Into<ConferenceProviderType>>(mut self, value: T) -> Self {
+        self.conference_provider_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ip_dial_in`](struct.CreateConferenceProviderRequest.html#structfield.ip_dial_in) member.
+    pub fn ip_dial_in<T: Into<IPDialIn>>(mut self, value: T) -> Self {
+        self.ip_dial_in = Some(value.into());
+        self
+    }
+
+    /// Sets the [`meeting_setting`](struct.CreateConferenceProviderRequest.html#structfield.meeting_setting) member.
+    pub fn meeting_setting<T: Into<MeetingSetting>>(mut self, value: T) -> Self {
+        self.meeting_setting = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pstn_dial_in`](struct.CreateConferenceProviderRequest.html#structfield.pstn_dial_in) member.
+    pub fn pstn_dial_in<T: Into<PSTNDialIn>>(mut self, value: T) -> Self {
+        self.pstn_dial_in = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConferenceProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateConferenceProviderRequest, ::rusoto_core::BuildError> {
+        Ok(CreateConferenceProviderRequest {
+            client_request_token: self.client_request_token,
+            conference_provider_name: self.conference_provider_name.ok_or(
+                ::rusoto_core::BuildError::missing("conference_provider_name"),
+            )?,
+            conference_provider_type: self.conference_provider_type.ok_or(
+                ::rusoto_core::BuildError::missing("conference_provider_type"),
+            )?,
+            ip_dial_in: self.ip_dial_in,
+            meeting_setting: self
+                .meeting_setting
+                .ok_or(::rusoto_core::BuildError::missing("meeting_setting"))?,
+            pstn_dial_in: self.pstn_dial_in,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateConferenceProviderResponse {
@@ -529,6 +1553,84 @@ pub struct CreateContactRequest {
     pub sip_addresses: Option<Vec<SipAddress>>,
 }
 
+impl CreateContactRequest {
+    /// Returns a builder for `CreateContactRequest`, checking its required members when built.
+    pub fn builder() -> CreateContactRequestBuilder {
+        CreateContactRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateContactRequest`](struct.CreateContactRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateContactRequestBuilder {
+    client_request_token: Option<String>,
+    display_name: Option<String>,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    phone_number: Option<String>,
+    phone_numbers: Option<Vec<PhoneNumber>>,
+    sip_addresses: Option<Vec<SipAddress>>,
+}
+
+impl CreateContactRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateContactRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.CreateContactRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`first_name`](struct.CreateContactRequest.html#structfield.first_name) member.
+    pub fn first_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.first_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`last_name`](struct.CreateContactRequest.html#structfield.last_name) member.
+    pub fn last_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.last_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`phone_number`](struct.CreateContactRequest.html#structfield.phone_number) member.
+    pub fn phone_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.phone_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`phone_numbers`](struct.CreateContactRequest.html#structfield.phone_numbers) member.
+    pub fn phone_numbers<T: Into<Vec<PhoneNumber>>>(mut self, value: T) -> Self {
+        self.phone_numbers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sip_addresses`](struct.CreateContactRequest.html#structfield.sip_addresses) member.
+    pub fn sip_addresses<T: Into<Vec<SipAddress>>>(mut self, value: T) -> Self {
+        self.sip_addresses = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateContactRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateContactRequest, ::rusoto_core::BuildError> {
+        Ok(CreateContactRequest {
+            client_request_token: self.client_request_token,
+            display_name: self.display_name,
+            first_name: self
+                .first_name
+                .ok_or(::rusoto_core::BuildError::missing("first_name"))?,
+            last_name: self.last_name,
+            phone_number: self.phone_number,
+            phone_numbers: self.phone_numbers,
+            sip_addresses: self.sip_addresses,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateContactResponse {
@@ -552,6 +1654,56 @@ pub struct CreateGatewayGroupRequest {
     pub name: String,
 }
 
+impl CreateGatewayGroupRequest {
+    /// Returns a builder for `CreateGatewayGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateGatewayGroupRequestBuilder {
+        CreateGatewayGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGatewayGroupRequest`](struct.CreateGatewayGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateGatewayGroupRequestBuilder {
+    client_request_token: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateGatewayGroupRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateGatewayGroupRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateGatewayGroupRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateGatewayGroupRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGatewayGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateGatewayGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateGatewayGroupRequest {
+            client_request_token: self
+                .client_request_token
+                .ok_or(::rusoto_core::BuildError::missing("client_request_token"))?,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateGatewayGroupResponse {
@@ -561,7 +1713,7 @@ pub struct CreateGatewayGroupResponse {
     pub gateway_group_arn: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateNetworkProfileRequest {
     /// <p>The ARN of the Private Certificate Authority (PCA) created in AWS Certificate Manager (ACM). This is used to issue certificates to the devices. </p>
     #[serde(rename = "CertificateAuthorityArn")]
@@ -580,7 +1732,7 @@ pub struct CreateNetworkProfileRequest {
     /// <p>The authentication standard that is used in the EAP framework. Currently, EAP_TLS is supported.</p>
     #[serde(rename = "EapMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub eap_method: Option<String>,
+    pub eap_method: Option<NetworkEapMethod>,
     /// <p>The name of the network profile associated with a device.</p>
     #[serde(rename = "NetworkProfileName")]
     pub network_profile_name: String,
@@ -590,7 +1742,7 @@ pub struct CreateNetworkProfileRequest {
     pub next_password: Option<String>,
     /// <p>The security type of the Wi-Fi network. This can be WPA2_ENTERPRISE, WPA2_PSK, WPA_PSK, WEP, or OPEN.</p>
     #[serde(rename = "SecurityType")]
-    pub security_type: String,
+    pub security_type: NetworkSecurityType,
     /// <p>The SSID of the Wi-Fi network.</p>
     #[serde(rename = "Ssid")]
     pub ssid: String,
@@ -600,6 +1752,116 @@ pub struct CreateNetworkProfileRequest {
     pub trust_anchors: Option<Vec<String>>,
 }
 
+impl CreateNetworkProfileRequest {
+    /// Returns a builder for `CreateNetworkProfileRequest`, checking its required members when built.
+    pub fn builder() -> CreateNetworkProfileRequestBuilder {
+        CreateNetworkProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateNetworkProfileRequest`](struct.CreateNetworkProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateNetworkProfileRequestBuilder {
+    certificate_authority_arn: Option<String>,
+    client_request_token: Option<String>,
+    current_password: Option<String>,
+    description: Option<String>,
+    eap_method: Option<NetworkEapMethod>,
+    network_profile_name: Option<String>,
+    next_password: Option<String>,
+    security_type: Option<NetworkSecurityType>,
+    ssid: Option<String>,
+    trust_anchors: Option<Vec<String>>,
+}
+
+impl CreateNetworkProfileRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.CreateNetworkProfileRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_request_token`](struct.CreateNetworkProfileRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_password`](struct.CreateNetworkProfileRequest.html#structfield.current_password) member.
+    pub fn current_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.current_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateNetworkProfileRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`eap_method`](struct.CreateNetworkProfileRequest.html#structfield.eap_method) member.
+    pub fn eap_method<T: Into<NetworkEapMethod>>(mut self, value: T) -> Self {
+        self.eap_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_profile_name`](struct.CreateNetworkProfileRequest.html#structfield.network_profile_name) member.
+    pub fn network_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.network_profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_password`](struct.CreateNetworkProfileRequest.html#structfield.next_password) member.
+    pub fn next_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_type`](struct.CreateNetworkProfileRequest.html#structfield.security_type) member.
+    pub fn security_type<T: Into<NetworkSecurityType>>(mut self, value: T) -> Self {
+        self.security_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssid`](struct.CreateNetworkProfileRequest.html#structfield.ssid) member.
+    pub fn ssid<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssid = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trust_anchors`](struct.CreateNetworkProfileRequest.html#structfield.trust_anchors) member.
+    pub fn trust_anchors<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.trust_anchors = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateNetworkProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateNetworkProfileRequest, ::rusoto_core::BuildError> {
+        Ok(CreateNetworkProfileRequest {
+            certificate_authority_arn: self.certificate_authority_arn,
+            client_request_token: self
+                .client_request_token
+                .ok_or(::rusoto_core::BuildError::missing("client_request_token"))?,
+            current_password: self.current_password,
+            description: self.description,
+            eap_method: self.eap_method,
+            network_profile_name: self
+                .network_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("network_profile_name"))?,
+            next_password: self.next_password,
+            security_type: self
+                .security_type
+                .ok_or(::rusoto_core::BuildError::missing("security_type"))?,
+            ssid: self
+                .ssid
+                .ok_or(::rusoto_core::BuildError::missing("ssid"))?,
+            trust_anchors: self.trust_anchors,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateNetworkProfileResponse {
@@ -609,7 +1871,7 @@ pub struct CreateNetworkProfileResponse {
     pub network_profile_arn: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateProfileRequest {
     /// <p>The valid address for the room.</p>
     #[serde(rename = "Address")]
@@ -620,7 +1882,7 @@ pub struct CreateProfileRequest {
     pub client_request_token: Option<String>,
     /// <p>The distance unit to be used by devices in the profile.</p>
     #[serde(rename = "DistanceUnit")]
-    pub distance_unit: String,
+    pub distance_unit: DistanceUnit,
     /// <p>The locale of the room profile.</p>
     #[serde(rename = "Locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -642,13 +1904,133 @@ pub struct CreateProfileRequest {
     pub setup_mode_disabled: Option<bool>,
     /// <p>The temperature unit to be used by devices in the profile.</p>
     #[serde(rename = "TemperatureUnit")]
-    pub temperature_unit: String,
+    pub temperature_unit: TemperatureUnit,
     /// <p>The time zone used by a room profile.</p>
     #[serde(rename = "Timezone")]
     pub timezone: String,
     /// <p>A wake word for Alexa, Echo, Amazon, or a computer.</p>
     #[serde(rename = "WakeWord")]
-    pub wake_word: String,
+    pub wake_word: WakeWord,
+}
+
+impl CreateProfileRequest {
+    /// Returns a builder for `CreateProfileRequest`, checking its required members when built.
+    pub fn builder() -> CreateProfileRequestBuilder {
+        CreateProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateProfileRequest`](struct.CreateProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateProfileRequestBuilder {
+    address: Option<String>,
+    client_request_token: Option<String>,
+    distance_unit: Option<DistanceUnit>,
+    locale: Option<String>,
+    max_volume_limit: Option<i64>,
+    pstn_enabled: Option<bool>,
+    profile_name: Option<String>,
+    setup_mode_disabled: Option<bool>,
+    temperature_unit: Option<TemperatureUnit>,
+    timezone: Option<String>,
+    wake_word: Option<WakeWord>,
+}
+
+impl CreateProfileRequestBuilder {
+    /// Sets the [`address`](struct.CreateProfileRequest.html#structfield.address) member.
+    pub fn address<T: Into<String>>(mut self, value: T) -> Self {
+        self.address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_request_token`](struct.CreateProfileRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`distance_unit`](struct.CreateProfileRequest.html#structfield.distance_unit) member.
+    pub fn distance_unit<T: Into<DistanceUnit>>(mut self, value: T) -> Self {
+        self.distance_unit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`locale`](struct.CreateProfileRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<String>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_volume_limit`](struct.CreateProfileRequest.html#structfield.max_volume_limit) member.
+    pub fn max_volume_limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_volume_limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pstn_enabled`](struct.CreateProfileRequest.html#structfield.pstn_enabled) member.
+    pub fn pstn_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.pstn_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`profile_name`](struct.CreateProfileRequest.html#structfield.profile_name) member.
+    pub fn profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`setup_mode_disabled`](struct.CreateProfileRequest.html#structfield.setup_mode_disabled) member.
+    pub fn setup_mode_disabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.setup_mode_disabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`temperature_unit`](struct.CreateProfileRequest.html#structfield.temperature_unit) member.
+    pub fn temperature_unit<T: Into<TemperatureUnit>>(mut self, value: T) -> Self {
+        self.temperature_unit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timezone`](struct.CreateProfileRequest.html#structfield.timezone) member.
+    pub fn timezone<T: Into<String>>(mut self, value: T) -> Self {
+        self.timezone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`wake_word`](struct.CreateProfileRequest.html#structfield.wake_word) member.
+    pub fn wake_word<T: Into<WakeWord>>(mut self, value: T) -> Self {
+        self.wake_word = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateProfileRequest, ::rusoto_core::BuildError> {
+        Ok(CreateProfileRequest {
+            address: self
+                .address
+                .ok_or(::rusoto_core::BuildError::missing("address"))?,
+            client_request_token: self.client_request_token,
+            distance_unit: self
+                .distance_unit
+                .ok_or(::rusoto_core::BuildError::missing("distance_unit"))?,
+            locale: self.locale,
+            max_volume_limit: self.max_volume_limit,
+            pstn_enabled: self.pstn_enabled,
+            profile_name: self
+                .profile_name
+                .ok_or(::rusoto_core::BuildError::missing("profile_name"))?,
+            setup_mode_disabled: self.setup_mode_disabled,
+            temperature_unit: self
+                .temperature_unit
+                .ok_or(::rusoto_core::BuildError::missing("temperature_unit"))?,
+            timezone: self
+                .timezone
+                .ok_or(::rusoto_core::BuildError::missing("timezone"))?,
+            wake_word: self
+                .wake_word
+                .ok_or(::rusoto_core::BuildError::missing("wake_word"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -687,6 +2069,76 @@ pub struct CreateRoomRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateRoomRequest {
+    /// Returns a builder for `CreateRoomRequest`, checking its required members when built.
+    pub fn builder() -> CreateRoomRequestBuilder {
+        CreateRoomRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRoomRequest`](struct.CreateRoomRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRoomRequestBuilder {
+    client_request_token: Option<String>,
+    description: Option<String>,
+    profile_arn: Option<String>,
+    provider_calendar_id: Option<String>,
+    room_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateRoomRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateRoomRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateRoomRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`profile_arn`](struct.CreateRoomRequest.html#structfield.profile_arn) member.
+    pub fn profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.profile_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_calendar_id`](struct.CreateRoomRequest.html#structfield.provider_calendar_id) member.
+    pub fn provider_calendar_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider_calendar_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`room_name`](struct.CreateRoomRequest.html#structfield.room_name) member.
+    pub fn room_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateRoomRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRoomRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateRoomRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRoomRequest {
+            client_request_token: self.client_request_token,
+            description: self.description,
+            profile_arn: self.profile_arn,
+            provider_calendar_id: self.provider_calendar_id,
+            room_name: self
+                .room_name
+                .ok_or(::rusoto_core::BuildError::missing("room_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRoomResponse {
@@ -711,21 +2163,69 @@ pub struct CreateSkillGroupRequest {
     pub skill_group_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CreateSkillGroupResponse {
-    /// <p>The ARN of the newly created skill group in the response.</p>
-    #[serde(rename = "SkillGroupArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub skill_group_arn: Option<String>,
+impl CreateSkillGroupRequest {
+    /// Returns a builder for `CreateSkillGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateSkillGroupRequestBuilder {
+        CreateSkillGroupRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct CreateUserRequest {
-    /// <p>A unique, user-specified identifier for this request that ensures idempotency. </p>
-    #[serde(rename = "ClientRequestToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_request_token: Option<String>,
+/// Builds a [`CreateSkillGroupRequest`](struct.CreateSkillGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSkillGroupRequestBuilder {
+    client_request_token: Option<String>,
+    description: Option<String>,
+    skill_group_name: Option<String>,
+}
+
+impl CreateSkillGroupRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateSkillGroupRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateSkillGroupRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_group_name`](struct.CreateSkillGroupRequest.html#structfield.skill_group_name) member.
+    pub fn skill_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSkillGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSkillGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSkillGroupRequest {
+            client_request_token: self.client_request_token,
+            description: self.description,
+            skill_group_name: self
+                .skill_group_name
+                .ok_or(::rusoto_core::BuildError::missing("skill_group_name"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateSkillGroupResponse {
+    /// <p>The ARN of the newly created skill group in the response.</p>
+    #[serde(rename = "SkillGroupArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_group_arn: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct CreateUserRequest {
+    /// <p>A unique, user-specified identifier for this request that ensures idempotency. </p>
+    #[serde(rename = "ClientRequestToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_request_token: Option<String>,
     /// <p>The email address for the user.</p>
     #[serde(rename = "Email")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -747,6 +2247,76 @@ pub struct CreateUserRequest {
     pub user_id: String,
 }
 
+impl CreateUserRequest {
+    /// Returns a builder for `CreateUserRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserRequestBuilder {
+        CreateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserRequest`](struct.CreateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserRequestBuilder {
+    client_request_token: Option<String>,
+    email: Option<String>,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    user_id: Option<String>,
+}
+
+impl CreateUserRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateUserRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email`](struct.CreateUserRequest.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`first_name`](struct.CreateUserRequest.html#structfield.first_name) member.
+    pub fn first_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.first_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`last_name`](struct.CreateUserRequest.html#structfield.last_name) member.
+    pub fn last_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.last_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateUserRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.CreateUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateUserRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserRequest {
+            client_request_token: self.client_request_token,
+            email: self.email,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            tags: self.tags,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateUserResponse {
@@ -763,6 +2333,38 @@ pub struct DeleteAddressBookRequest {
     pub address_book_arn: String,
 }
 
+impl DeleteAddressBookRequest {
+    /// Returns a builder for `DeleteAddressBookRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAddressBookRequestBuilder {
+        DeleteAddressBookRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAddressBookRequest`](struct.DeleteAddressBookRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAddressBookRequestBuilder {
+    address_book_arn: Option<String>,
+}
+
+impl DeleteAddressBookRequestBuilder {
+    /// Sets the [`address_book_arn`](struct.DeleteAddressBookRequest.html#structfield.address_book_arn) member.
+    pub fn address_book_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.address_book_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAddressBookRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAddressBookRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAddressBookRequest {
+            address_book_arn: self
+                .address_book_arn
+                .ok_or(::rusoto_core::BuildError::missing("address_book_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteAddressBookResponse {}
@@ -774,6 +2376,38 @@ pub struct DeleteBusinessReportScheduleRequest {
     pub schedule_arn: String,
 }
 
+impl DeleteBusinessReportScheduleRequest {
+    /// Returns a builder for `DeleteBusinessReportScheduleRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBusinessReportScheduleRequestBuilder {
+        DeleteBusinessReportScheduleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBusinessReportScheduleRequest`](struct.DeleteBusinessReportScheduleRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBusinessReportScheduleRequestBuilder {
+    schedule_arn: Option<String>,
+}
+
+impl DeleteBusinessReportScheduleRequestBuilder {
+    /// Sets the [`schedule_arn`](struct.DeleteBusinessReportScheduleRequest.html#structfield.schedule_arn) member.
+    pub fn schedule_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBusinessReportScheduleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteBusinessReportScheduleRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBusinessReportScheduleRequest {
+            schedule_arn: self
+                .schedule_arn
+                .ok_or(::rusoto_core::BuildError::missing("schedule_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteBusinessReportScheduleResponse {}
@@ -785,6 +2419,38 @@ pub struct DeleteConferenceProviderRequest {
     pub conference_provider_arn: String,
 }
 
+impl DeleteConferenceProviderRequest {
+    /// Returns a builder for `DeleteConferenceProviderRequest`, checking its required members when built.
+    pub fn builder() -> DeleteConferenceProviderRequestBuilder {
+        DeleteConferenceProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConferenceProviderRequest`](struct.DeleteConferenceProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteConferenceProviderRequestBuilder {
+    conference_provider_arn: Option<String>,
+}
+
+impl DeleteConferenceProviderRequestBuilder {
+    /// Sets the [`conference_provider_arn`](struct.DeleteConferenceProviderRequest.html#structfield.conference_provider_arn) member.
+    pub fn conference_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.conference_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConferenceProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteConferenceProviderRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteConferenceProviderRequest {
+            conference_provider_arn: self.conference_provider_arn.ok_or(
+                ::rusoto_core::BuildError::missing("conference_provider_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteConferenceProviderResponse {}
@@ -796,6 +2462,36 @@ pub struct DeleteContactRequest {
     pub contact_arn: String,
 }
 
+impl DeleteContactRequest {
+    /// Returns a builder for `DeleteContactRequest`, checking its required members when built.
+    pub fn builder() -> DeleteContactRequestBuilder {
+        DeleteContactRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteContactRequest`](struct.DeleteContactRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteContactRequestBuilder {
+    contact_arn: Option<String>,
+}
+
+impl DeleteContactRequestBuilder {
+    /// Sets the [`contact_arn`](struct.DeleteContactRequest.html#structfield.contact_arn) member.
+    pub fn contact_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.contact_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteContactRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteContactRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteContactRequest {
+            contact_arn: self
+                .contact_arn
+                .ok_or(::rusoto_core::BuildError::missing("contact_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteContactResponse {}
@@ -807,18 +2503,90 @@ pub struct DeleteDeviceRequest {
     pub device_arn: String,
 }
 
+impl DeleteDeviceRequest {
+    /// Returns a builder for `DeleteDeviceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDeviceRequestBuilder {
+        DeleteDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDeviceRequest`](struct.DeleteDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDeviceRequestBuilder {
+    device_arn: Option<String>,
+}
+
+impl DeleteDeviceRequestBuilder {
+    /// Sets the [`device_arn`](struct.DeleteDeviceRequest.html#structfield.device_arn) member.
+    pub fn device_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDeviceRequest {
+            device_arn: self
+                .device_arn
+                .ok_or(::rusoto_core::BuildError::missing("device_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDeviceResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDeviceUsageDataRequest {
     /// <p>The ARN of the device.</p>
     #[serde(rename = "DeviceArn")]
     pub device_arn: String,
     /// <p>The type of usage data to delete.</p>
     #[serde(rename = "DeviceUsageType")]
-    pub device_usage_type: String,
+    pub device_usage_type: DeviceUsageType,
+}
+
+impl DeleteDeviceUsageDataRequest {
+    /// Returns a builder for `DeleteDeviceUsageDataRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDeviceUsageDataRequestBuilder {
+        DeleteDeviceUsageDataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDeviceUsageDataRequest`](struct.DeleteDeviceUsageDataRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDeviceUsageDataRequestBuilder {
+    device_arn: Option<String>,
+    device_usage_type: Option<DeviceUsageType>,
+}
+
+impl DeleteDeviceUsageDataRequestBuilder {
+    /// Sets the [`device_arn`](struct.DeleteDeviceUsageDataRequest.html#structfield.device_arn) member.
+    pub fn device_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_usage_type`](struct.DeleteDeviceUsageDataRequest.html#structfield.device_usage_type) member.
+    pub fn device_usage_type<T: Into<DeviceUsageType>>(mut self, value: T) -> Self {
+        self.device_usage_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDeviceUsageDataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDeviceUsageDataRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDeviceUsageDataRequest {
+            device_arn: self
+                .device_arn
+                .ok_or(::rusoto_core::BuildError::missing("device_arn"))?,
+            device_usage_type: self
+                .device_usage_type
+                .ok_or(::rusoto_core::BuildError::missing("device_usage_type"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -832,6 +2600,38 @@ pub struct DeleteGatewayGroupRequest {
     pub gateway_group_arn: String,
 }
 
+impl DeleteGatewayGroupRequest {
+    /// Returns a builder for `DeleteGatewayGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteGatewayGroupRequestBuilder {
+        DeleteGatewayGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGatewayGroupRequest`](struct.DeleteGatewayGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteGatewayGroupRequestBuilder {
+    gateway_group_arn: Option<String>,
+}
+
+impl DeleteGatewayGroupRequestBuilder {
+    /// Sets the [`gateway_group_arn`](struct.DeleteGatewayGroupRequest.html#structfield.gateway_group_arn) member.
+    pub fn gateway_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_group_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGatewayGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteGatewayGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteGatewayGroupRequest {
+            gateway_group_arn: self
+                .gateway_group_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_group_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteGatewayGroupResponse {}
@@ -843,6 +2643,38 @@ pub struct DeleteNetworkProfileRequest {
     pub network_profile_arn: String,
 }
 
+impl DeleteNetworkProfileRequest {
+    /// Returns a builder for `DeleteNetworkProfileRequest`, checking its required members when built.
+    pub fn builder() -> DeleteNetworkProfileRequestBuilder {
+        DeleteNetworkProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteNetworkProfileRequest`](struct.DeleteNetworkProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteNetworkProfileRequestBuilder {
+    network_profile_arn: Option<String>,
+}
+
+impl DeleteNetworkProfileRequestBuilder {
+    /// Sets the [`network_profile_arn`](struct.DeleteNetworkProfileRequest.html#structfield.network_profile_arn) member.
+    pub fn network_profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.network_profile_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteNetworkProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteNetworkProfileRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteNetworkProfileRequest {
+            network_profile_arn: self
+                .network_profile_arn
+                .ok_or(::rusoto_core::BuildError::missing("network_profile_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteNetworkProfileResponse {}
@@ -855,6 +2687,34 @@ pub struct DeleteProfileRequest {
     pub profile_arn: Option<String>,
 }
 
+impl DeleteProfileRequest {
+    /// Returns a builder for `DeleteProfileRequest`, checking its required members when built.
+    pub fn builder() -> DeleteProfileRequestBuilder {
+        DeleteProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteProfileRequest`](struct.DeleteProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteProfileRequestBuilder {
+    profile_arn: Option<String>,
+}
+
+impl DeleteProfileRequestBuilder {
+    /// Sets the [`profile_arn`](struct.DeleteProfileRequest.html#structfield.profile_arn) member.
+    pub fn profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.profile_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteProfileRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteProfileRequest {
+            profile_arn: self.profile_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteProfileResponse {}
@@ -867,6 +2727,34 @@ pub struct DeleteRoomRequest {
     pub room_arn: Option<String>,
 }
 
+impl DeleteRoomRequest {
+    /// Returns a builder for `DeleteRoomRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRoomRequestBuilder {
+        DeleteRoomRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRoomRequest`](struct.DeleteRoomRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRoomRequestBuilder {
+    room_arn: Option<String>,
+}
+
+impl DeleteRoomRequestBuilder {
+    /// Sets the [`room_arn`](struct.DeleteRoomRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRoomRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteRoomRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRoomRequest {
+            room_arn: self.room_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRoomResponse {}
@@ -885,6 +2773,56 @@ pub struct DeleteRoomSkillParameterRequest {
     pub skill_id: String,
 }
 
+impl DeleteRoomSkillParameterRequest {
+    /// Returns a builder for `DeleteRoomSkillParameterRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRoomSkillParameterRequestBuilder {
+        DeleteRoomSkillParameterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRoomSkillParameterRequest`](struct.DeleteRoomSkillParameterRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRoomSkillParameterRequestBuilder {
+    parameter_key: Option<String>,
+    room_arn: Option<String>,
+    skill_id: Option<String>,
+}
+
+impl DeleteRoomSkillParameterRequestBuilder {
+    /// Sets the [`parameter_key`](struct.DeleteRoomSkillParameterRequest.html#structfield.parameter_key) member.
+    pub fn parameter_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`room_arn`](struct.DeleteRoomSkillParameterRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_id`](struct.DeleteRoomSkillParameterRequest.html#structfield.skill_id) member.
+    pub fn skill_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRoomSkillParameterRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRoomSkillParameterRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRoomSkillParameterRequest {
+            parameter_key: self
+                .parameter_key
+                .ok_or(::rusoto_core::BuildError::missing("parameter_key"))?,
+            room_arn: self.room_arn,
+            skill_id: self
+                .skill_id
+                .ok_or(::rusoto_core::BuildError::missing("skill_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRoomSkillParameterResponse {}
@@ -900,6 +2838,46 @@ pub struct DeleteSkillAuthorizationRequest {
     pub skill_id: String,
 }
 
+impl DeleteSkillAuthorizationRequest {
+    /// Returns a builder for `DeleteSkillAuthorizationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSkillAuthorizationRequestBuilder {
+        DeleteSkillAuthorizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSkillAuthorizationRequest`](struct.DeleteSkillAuthorizationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSkillAuthorizationRequestBuilder {
+    room_arn: Option<String>,
+    skill_id: Option<String>,
+}
+
+impl DeleteSkillAuthorizationRequestBuilder {
+    /// Sets the [`room_arn`](struct.DeleteSkillAuthorizationRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_id`](struct.DeleteSkillAuthorizationRequest.html#structfield.skill_id) member.
+    pub fn skill_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSkillAuthorizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSkillAuthorizationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSkillAuthorizationRequest {
+            room_arn: self.room_arn,
+            skill_id: self
+                .skill_id
+                .ok_or(::rusoto_core::BuildError::missing("skill_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSkillAuthorizationResponse {}
@@ -912,6 +2890,36 @@ pub struct DeleteSkillGroupRequest {
     pub skill_group_arn: Option<String>,
 }
 
+impl DeleteSkillGroupRequest {
+    /// Returns a builder for `DeleteSkillGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSkillGroupRequestBuilder {
+        DeleteSkillGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSkillGroupRequest`](struct.DeleteSkillGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSkillGroupRequestBuilder {
+    skill_group_arn: Option<String>,
+}
+
+impl DeleteSkillGroupRequestBuilder {
+    /// Sets the [`skill_group_arn`](struct.DeleteSkillGroupRequest.html#structfield.skill_group_arn) member.
+    pub fn skill_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_group_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSkillGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSkillGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSkillGroupRequest {
+            skill_group_arn: self.skill_group_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSkillGroupResponse {}
@@ -927,6 +2935,44 @@ pub struct DeleteUserRequest {
     pub user_arn: Option<String>,
 }
 
+impl DeleteUserRequest {
+    /// Returns a builder for `DeleteUserRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserRequestBuilder {
+        DeleteUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserRequest`](struct.DeleteUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserRequestBuilder {
+    enrollment_id: Option<String>,
+    user_arn: Option<String>,
+}
+
+impl DeleteUserRequestBuilder {
+    /// Sets the [`enrollment_id`](struct.DeleteUserRequest.html#structfield.enrollment_id) member.
+    pub fn enrollment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.enrollment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_arn`](struct.DeleteUserRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteUserRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserRequest {
+            enrollment_id: self
+                .enrollment_id
+                .ok_or(::rusoto_core::BuildError::missing("enrollment_id"))?,
+            user_arn: self.user_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteUserResponse {}
@@ -972,7 +3018,7 @@ pub struct Device {
     /// <p>The status of a device. If the status is not READY, check the DeviceStatusInfo value for details.</p>
     #[serde(rename = "DeviceStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_status: Option<String>,
+    pub device_status: Option<DeviceStatus>,
     /// <p>Detailed information about a device's status.</p>
     #[serde(rename = "DeviceStatusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1018,7 +3064,7 @@ pub struct DeviceData {
     /// <p>The status of a device.</p>
     #[serde(rename = "DeviceStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_status: Option<String>,
+    pub device_status: Option<DeviceStatus>,
     /// <p>Detailed information about a device's status.</p>
     #[serde(rename = "DeviceStatusInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1059,18 +3105,91 @@ pub struct DeviceData {
 pub struct DeviceEvent {
     /// <p>The time (in epoch) when the event occurred. </p>
     #[serde(rename = "Timestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<EpochTimestamp>,
     /// <p>The type of device event.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DeviceEventType>,
     /// <p>The value of the event.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+/// <p>The type of device event.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceEventType {
+    /// <p><code>CONNECTION_STATUS</code></p>
+    ConnectionStatus,
+    /// <p><code>DEVICE_STATUS</code></p>
+    DeviceStatus,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceEventType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceEventType::ConnectionStatus => "CONNECTION_STATUS",
+            DeviceEventType::DeviceStatus => "DEVICE_STATUS",
+            DeviceEventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeviceEventType {
+    fn from(value: &'a str) -> DeviceEventType {
+        match value {
+            "CONNECTION_STATUS" => DeviceEventType::ConnectionStatus,
+            "DEVICE_STATUS" => DeviceEventType::DeviceStatus,
+            _ => DeviceEventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceEventType {
+    fn from(value: String) -> DeviceEventType {
+        DeviceEventType::from(value.as_str())
+    }
+}
+
+impl From<DeviceEventType> for String {
+    fn from(value: DeviceEventType) -> String {
+        match value {
+            DeviceEventType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeviceEventType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceEventType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeviceEventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceEventType::from(value))
+    }
+}
+
 /// <p>Detailed information about a device's network profile.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1081,27 +3200,252 @@ pub struct DeviceNetworkProfileInfo {
     pub certificate_arn: Option<String>,
     /// <p>The time (in epoch) when the certificate expires.</p>
     #[serde(rename = "CertificateExpirationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_expiration_time: Option<f64>,
+    pub certificate_expiration_time: Option<EpochTimestamp>,
     /// <p>The ARN of the network profile associated with a device.</p>
     #[serde(rename = "NetworkProfileArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network_profile_arn: Option<String>,
 }
 
-/// <p>Details of a device’s status.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DeviceStatusDetail {
-    /// <p>The device status detail code.</p>
-    #[serde(rename = "Code")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
-    /// <p>The list of available features on the device.</p>
-    #[serde(rename = "Feature")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub feature: Option<String>,
-}
+/// <p>The status of a device. If the status is not READY, check the DeviceStatusInfo value for details.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceStatus {
+    /// <p><code>READY</code></p>
+    Ready,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>WAS_OFFLINE</code></p>
+    WasOffline,
+    /// <p><code>DEREGISTERED</code></p>
+    Deregistered,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceStatus::Ready => "READY",
+            DeviceStatus::Pending => "PENDING",
+            DeviceStatus::WasOffline => "WAS_OFFLINE",
+            DeviceStatus::Deregistered => "DEREGISTERED",
+            DeviceStatus::Failed => "FAILED",
+            DeviceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeviceStatus {
+    fn from(value: &'a str) -> DeviceStatus {
+        match value {
+            "READY" => DeviceStatus::Ready,
+            "PENDING" => DeviceStatus::Pending,
+            "WAS_OFFLINE" => DeviceStatus::WasOffline,
+            "DEREGISTERED" => DeviceStatus::Deregistered,
+            "FAILED" => DeviceStatus::Failed,
+            _ => DeviceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceStatus {
+    fn from(value: String) -> DeviceStatus {
+        DeviceStatus::from(value.as_str())
+    }
+}
+
+impl From<DeviceStatus> for String {
+    fn from(value: DeviceStatus) -> String {
+        match value {
+            DeviceStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeviceStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeviceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceStatus::from(value))
+    }
+}
+
+/// <p>Details of a device’s status.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeviceStatusDetail {
+    /// <p>The device status detail code.</p>
+    #[serde(rename = "Code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<DeviceStatusDetailCode>,
+    /// <p>The list of available features on the device.</p>
+    #[serde(rename = "Feature")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature: Option<Feature>,
+}
+
+/// <p>The device status detail code.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceStatusDetailCode {
+    /// <p><code>DEVICE_SOFTWARE_UPDATE_NEEDED</code></p>
+    DeviceSoftwareUpdateNeeded,
+    /// <p><code>DEVICE_WAS_OFFLINE</code></p>
+    DeviceWasOffline,
+    /// <p><code>CREDENTIALS_ACCESS_FAILURE</code></p>
+    CredentialsAccessFailure,
+    /// <p><code>TLS_VERSION_MISMATCH</code></p>
+    TlsVersionMismatch,
+    /// <p><code>ASSOCIATION_REJECTION</code></p>
+    AssociationRejection,
+    /// <p><code>AUTHENTICATION_FAILURE</code></p>
+    AuthenticationFailure,
+    /// <p><code>DHCP_FAILURE</code></p>
+    DhcpFailure,
+    /// <p><code>INTERNET_UNAVAILABLE</code></p>
+    InternetUnavailable,
+    /// <p><code>DNS_FAILURE</code></p>
+    DnsFailure,
+    /// <p><code>UNKNOWN_FAILURE</code></p>
+    UnknownFailure,
+    /// <p><code>CERTIFICATE_ISSUING_LIMIT_EXCEEDED</code></p>
+    CertificateIssuingLimitExceeded,
+    /// <p><code>INVALID_CERTIFICATE_AUTHORITY</code></p>
+    InvalidCertificateAuthority,
+    /// <p><code>NETWORK_PROFILE_NOT_FOUND</code></p>
+    NetworkProfileNotFound,
+    /// <p><code>INVALID_PASSWORD_STATE</code></p>
+    InvalidPasswordState,
+    /// <p><code>PASSWORD_NOT_FOUND</code></p>
+    PasswordNotFound,
+    /// <p><code>PASSWORD_MANAGER_ACCESS_DENIED</code></p>
+    PasswordManagerAccessDenied,
+    /// <p><code>CERTIFICATE_AUTHORITY_ACCESS_DENIED</code></p>
+    CertificateAuthorityAccessDenied,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceStatusDetailCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceStatusDetailCode::DeviceSoftwareUpdateNeeded => "DEVICE_SOFTWARE_UPDATE_NEEDED",
+            DeviceStatusDetailCode::DeviceWasOffline => "DEVICE_WAS_OFFLINE",
+            DeviceStatusDetailCode::CredentialsAccessFailure => "CREDENTIALS_ACCESS_FAILURE",
+            DeviceStatusDetailCode::TlsVersionMismatch => "TLS_VERSION_MISMATCH",
+            DeviceStatusDetailCode::AssociationRejection => "ASSOCIATION_REJECTION",
+            DeviceStatusDetailCode::AuthenticationFailure => "AUTHENTICATION_FAILURE",
+            DeviceStatusDetailCode::DhcpFailure => "DHCP_FAILURE",
+            DeviceStatusDetailCode::InternetUnavailable => "INTERNET_UNAVAILABLE",
+            DeviceStatusDetailCode::DnsFailure => "DNS_FAILURE",
+            DeviceStatusDetailCode::UnknownFailure => "UNKNOWN_FAILURE",
+            DeviceStatusDetailCode::CertificateIssuingLimitExceeded => {
+                "CERTIFICATE_ISSUING_LIMIT_EXCEEDED"
+            }
+            DeviceStatusDetailCode::InvalidCertificateAuthority => "INVALID_CERTIFICATE_AUTHORITY",
+            DeviceStatusDetailCode::NetworkProfileNotFound => "NETWORK_PROFILE_NOT_FOUND",
+            DeviceStatusDetailCode::InvalidPasswordState => "INVALID_PASSWORD_STATE",
+            DeviceStatusDetailCode::PasswordNotFound => "PASSWORD_NOT_FOUND",
+            DeviceStatusDetailCode::PasswordManagerAccessDenied => "PASSWORD_MANAGER_ACCESS_DENIED",
+            DeviceStatusDetailCode::CertificateAuthorityAccessDenied => {
+                "CERTIFICATE_AUTHORITY_ACCESS_DENIED"
+            }
+            DeviceStatusDetailCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeviceStatusDetailCode {
+    fn from(value: &'a str) -> DeviceStatusDetailCode {
+        match value {
+            "DEVICE_SOFTWARE_UPDATE_NEEDED" => DeviceStatusDetailCode::DeviceSoftwareUpdateNeeded,
+            "DEVICE_WAS_OFFLINE" => DeviceStatusDetailCode::DeviceWasOffline,
+            "CREDENTIALS_ACCESS_FAILURE" => DeviceStatusDetailCode::CredentialsAccessFailure,
+            "TLS_VERSION_MISMATCH" => DeviceStatusDetailCode::TlsVersionMismatch,
+            "ASSOCIATION_REJECTION" => DeviceStatusDetailCode::AssociationRejection,
+            "AUTHENTICATION_FAILURE" => DeviceStatusDetailCode::AuthenticationFailure,
+            "DHCP_FAILURE" => DeviceStatusDetailCode::DhcpFailure,
+            "INTERNET_UNAVAILABLE" => DeviceStatusDetailCode::InternetUnavailable,
+            "DNS_FAILURE" => DeviceStatusDetailCode::DnsFailure,
+            "UNKNOWN_FAILURE" => DeviceStatusDetailCode::UnknownFailure,
+            "CERTIFICATE_ISSUING_LIMIT_EXCEEDED" => {
+                DeviceStatusDetailCode::CertificateIssuingLimitExceeded
+            }
+            "INVALID_CERTIFICATE_AUTHORITY" => DeviceStatusDetailCode::InvalidCertificateAuthority,
+            "NETWORK_PROFILE_NOT_FOUND" => DeviceStatusDetailCode::NetworkProfileNotFound,
+            "INVALID_PASSWORD_STATE" => DeviceStatusDetailCode::InvalidPasswordState,
+            "PASSWORD_NOT_FOUND" => DeviceStatusDetailCode::PasswordNotFound,
+            "PASSWORD_MANAGER_ACCESS_DENIED" => DeviceStatusDetailCode::PasswordManagerAccessDenied,
+            "CERTIFICATE_AUTHORITY_ACCESS_DENIED" => {
+                DeviceStatusDetailCode::CertificateAuthorityAccessDenied
+            }
+            _ => DeviceStatusDetailCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceStatusDetailCode {
+    fn from(value: String) -> DeviceStatusDetailCode {
+        DeviceStatusDetailCode::from(value.as_str())
+    }
+}
+
+impl From<DeviceStatusDetailCode> for String {
+    fn from(value: DeviceStatusDetailCode) -> String {
+        match value {
+            DeviceStatusDetailCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceStatusDetailCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeviceStatusDetailCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceStatusDetailCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeviceStatusDetailCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceStatusDetailCode::from(value))
+    }
+}
 
 /// <p>Detailed information about a device's status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1110,13 +3454,81 @@ pub struct DeviceStatusInfo {
     /// <p>The latest available information about the connection status of a device. </p>
     #[serde(rename = "ConnectionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_status: Option<String>,
+    pub connection_status: Option<ConnectionStatus>,
     /// <p>One or more device status detail descriptions.</p>
     #[serde(rename = "DeviceStatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub device_status_details: Option<Vec<DeviceStatusDetail>>,
 }
 
+/// <p>The type of usage data to delete.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceUsageType {
+    /// <p><code>VOICE</code></p>
+    Voice,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceUsageType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceUsageType::Voice => "VOICE",
+            DeviceUsageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeviceUsageType {
+    fn from(value: &'a str) -> DeviceUsageType {
+        match value {
+            "VOICE" => DeviceUsageType::Voice,
+            _ => DeviceUsageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceUsageType {
+    fn from(value: String) -> DeviceUsageType {
+        DeviceUsageType::from(value.as_str())
+    }
+}
+
+impl From<DeviceUsageType> for String {
+    fn from(value: DeviceUsageType) -> String {
+        match value {
+            DeviceUsageType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceUsageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeviceUsageType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceUsageType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeviceUsageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceUsageType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DisassociateContactFromAddressBookRequest {
     /// <p>The ARN of the address from which to disassociate the contact.</p>
@@ -1127,6 +3539,49 @@ pub struct DisassociateContactFromAddressBookRequest {
     pub contact_arn: String,
 }
 
+impl DisassociateContactFromAddressBookRequest {
+    /// Returns a builder for `DisassociateContactFromAddressBookRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateContactFromAddressBookRequestBuilder {
+        DisassociateContactFromAddressBookRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateContactFromAddressBookRequest`](struct.DisassociateContactFromAddressBookRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateContactFromAddressBookRequestBuilder {
+    address_book_arn: Option<String>,
+    contact_arn: Option<String>,
+}
+
+impl DisassociateContactFromAddressBookRequestBuilder {
+    /// Sets the [`address_book_arn`](struct.DisassociateContactFromAddressBookRequest.html#structfield.address_book_arn) member.
+    pub fn address_book_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.address_book_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`contact_arn`](struct.DisassociateContactFromAddressBookRequest.html#structfield.contact_arn) member.
+    pub fn contact_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.contact_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateContactFromAddressBookRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateContactFromAddressBookRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociateContactFromAddressBookRequest {
+            address_book_arn: self
+                .address_book_arn
+                .ok_or(::rusoto_core::BuildError::missing("address_book_arn"))?,
+            contact_arn: self
+                .contact_arn
+                .ok_or(::rusoto_core::BuildError::missing("contact_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateContactFromAddressBookResponse {}
@@ -1139,6 +3594,36 @@ pub struct DisassociateDeviceFromRoomRequest {
     pub device_arn: Option<String>,
 }
 
+impl DisassociateDeviceFromRoomRequest {
+    /// Returns a builder for `DisassociateDeviceFromRoomRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateDeviceFromRoomRequestBuilder {
+        DisassociateDeviceFromRoomRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateDeviceFromRoomRequest`](struct.DisassociateDeviceFromRoomRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateDeviceFromRoomRequestBuilder {
+    device_arn: Option<String>,
+}
+
+impl DisassociateDeviceFromRoomRequestBuilder {
+    /// Sets the [`device_arn`](struct.DisassociateDeviceFromRoomRequest.html#structfield.device_arn) member.
+    pub fn device_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateDeviceFromRoomRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateDeviceFromRoomRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateDeviceFromRoomRequest {
+            device_arn: self.device_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateDeviceFromRoomResponse {}
@@ -1154,6 +3639,47 @@ pub struct DisassociateSkillFromSkillGroupRequest {
     pub skill_id: String,
 }
 
+impl DisassociateSkillFromSkillGroupRequest {
+    /// Returns a builder for `DisassociateSkillFromSkillGroupRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateSkillFromSkillGroupRequestBuilder {
+        DisassociateSkillFromSkillGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateSkillFromSkillGroupRequest`](struct.DisassociateSkillFromSkillGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateSkillFromSkillGroupRequestBuilder {
+    skill_group_arn: Option<String>,
+    skill_id: Option<String>,
+}
+
+impl DisassociateSkillFromSkillGroupRequestBuilder {
+    /// Sets the [`skill_group_arn`](struct.DisassociateSkillFromSkillGroupRequest.html#structfield.skill_group_arn) member.
+    pub fn skill_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_id`](struct.DisassociateSkillFromSkillGroupRequest.html#structfield.skill_id) member.
+    pub fn skill_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateSkillFromSkillGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateSkillFromSkillGroupRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociateSkillFromSkillGroupRequest {
+            skill_group_arn: self.skill_group_arn,
+            skill_id: self
+                .skill_id
+                .ok_or(::rusoto_core::BuildError::missing("skill_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateSkillFromSkillGroupResponse {}
@@ -1165,6 +3691,38 @@ pub struct DisassociateSkillFromUsersRequest {
     pub skill_id: String,
 }
 
+impl DisassociateSkillFromUsersRequest {
+    /// Returns a builder for `DisassociateSkillFromUsersRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateSkillFromUsersRequestBuilder {
+        DisassociateSkillFromUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateSkillFromUsersRequest`](struct.DisassociateSkillFromUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateSkillFromUsersRequestBuilder {
+    skill_id: Option<String>,
+}
+
+impl DisassociateSkillFromUsersRequestBuilder {
+    /// Sets the [`skill_id`](struct.DisassociateSkillFromUsersRequest.html#structfield.skill_id) member.
+    pub fn skill_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateSkillFromUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateSkillFromUsersRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateSkillFromUsersRequest {
+            skill_id: self
+                .skill_id
+                .ok_or(::rusoto_core::BuildError::missing("skill_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateSkillFromUsersResponse {}
@@ -1181,97 +3739,564 @@ pub struct DisassociateSkillGroupFromRoomRequest {
     pub skill_group_arn: Option<String>,
 }
 
+impl DisassociateSkillGroupFromRoomRequest {
+    /// Returns a builder for `DisassociateSkillGroupFromRoomRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateSkillGroupFromRoomRequestBuilder {
+        DisassociateSkillGroupFromRoomRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateSkillGroupFromRoomRequest`](struct.DisassociateSkillGroupFromRoomRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateSkillGroupFromRoomRequestBuilder {
+    room_arn: Option<String>,
+    skill_group_arn: Option<String>,
+}
+
+impl DisassociateSkillGroupFromRoomRequestBuilder {
+    /// Sets the [`room_arn`](struct.DisassociateSkillGroupFromRoomRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_group_arn`](struct.DisassociateSkillGroupFromRoomRequest.html#structfield.skill_group_arn) member.
+    pub fn skill_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_group_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateSkillGroupFromRoomRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateSkillGroupFromRoomRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociateSkillGroupFromRoomRequest {
+            room_arn: self.room_arn,
+            skill_group_arn: self.skill_group_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateSkillGroupFromRoomResponse {}
 
-/// <p>A filter name and value pair that is used to return a more specific list of results. Filters can be used to match a set of resources by various criteria.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct Filter {
-    /// <p>The key of a filter.</p>
-    #[serde(rename = "Key")]
-    pub key: String,
-    /// <p>The values of a filter.</p>
-    #[serde(rename = "Values")]
-    pub values: Vec<String>,
+/// <p>The distance unit to be used by devices in the profile.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DistanceUnit {
+    /// <p><code>METRIC</code></p>
+    Metric,
+    /// <p><code>IMPERIAL</code></p>
+    Imperial,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ForgetSmartHomeAppliancesRequest {
-    /// <p>The room that the appliances are associated with.</p>
-    #[serde(rename = "RoomArn")]
-    pub room_arn: String,
+impl DistanceUnit {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DistanceUnit::Metric => "METRIC",
+            DistanceUnit::Imperial => "IMPERIAL",
+            DistanceUnit::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ForgetSmartHomeAppliancesResponse {}
+impl<'a> From<&'a str> for DistanceUnit {
+    fn from(value: &'a str) -> DistanceUnit {
+        match value {
+            "METRIC" => DistanceUnit::Metric,
+            "IMPERIAL" => DistanceUnit::Imperial,
+            _ => DistanceUnit::Unknown(value.to_owned()),
+        }
+    }
+}
 
-/// <p>The details of the gateway. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Gateway {
-    /// <p>The ARN of the gateway.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The description of the gateway.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The ARN of the gateway group that the gateway is associated to.</p>
-    #[serde(rename = "GatewayGroupArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gateway_group_arn: Option<String>,
-    /// <p>The name of the gateway.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The software version of the gateway. The gateway automatically updates its software version during normal operation.</p>
-    #[serde(rename = "SoftwareVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub software_version: Option<String>,
+impl From<String> for DistanceUnit {
+    fn from(value: String) -> DistanceUnit {
+        DistanceUnit::from(value.as_str())
+    }
 }
 
-/// <p>The details of the gateway group.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GatewayGroup {
-    /// <p>The ARN of the gateway group.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The description of the gateway group.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The name of the gateway group.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+impl From<DistanceUnit> for String {
+    fn from(value: DistanceUnit) -> String {
+        match value {
+            DistanceUnit::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-/// <p>The summary of a gateway group.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GatewayGroupSummary {
-    /// <p>The ARN of the gateway group.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The description of the gateway group.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The name of the gateway group.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+impl fmt::Display for DistanceUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
-/// <p>The summary of a gateway.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl ::serde::Serialize for DistanceUnit {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DistanceUnit {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DistanceUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DistanceUnit::from(value))
+    }
+}
+
+/// <p>Whether the skill is enabled under the user's account, or if it requires linking to be used.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnablementType {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnablementType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnablementType::Enabled => "ENABLED",
+            EnablementType::Pending => "PENDING",
+            EnablementType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnablementType {
+    fn from(value: &'a str) -> EnablementType {
+        match value {
+            "ENABLED" => EnablementType::Enabled,
+            "PENDING" => EnablementType::Pending,
+            _ => EnablementType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnablementType {
+    fn from(value: String) -> EnablementType {
+        EnablementType::from(value.as_str())
+    }
+}
+
+impl From<EnablementType> for String {
+    fn from(value: EnablementType) -> String {
+        match value {
+            EnablementType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnablementType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EnablementType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnablementType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EnablementType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EnablementType::from(value))
+    }
+}
+
+/// <p>Whether the skill is enabled under the user's account, or if it requires linking to be used.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnablementTypeFilter {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnablementTypeFilter {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnablementTypeFilter::Enabled => "ENABLED",
+            EnablementTypeFilter::Pending => "PENDING",
+            EnablementTypeFilter::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnablementTypeFilter {
+    fn from(value: &'a str) -> EnablementTypeFilter {
+        match value {
+            "ENABLED" => EnablementTypeFilter::Enabled,
+            "PENDING" => EnablementTypeFilter::Pending,
+            _ => EnablementTypeFilter::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnablementTypeFilter {
+    fn from(value: String) -> EnablementTypeFilter {
+        EnablementTypeFilter::from(value.as_str())
+    }
+}
+
+impl From<EnablementTypeFilter> for String {
+    fn from(value: EnablementTypeFilter) -> String {
+        match value {
+            EnablementTypeFilter::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnablementTypeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EnablementTypeFilter {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnablementTypeFilter {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EnablementTypeFilter, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EnablementTypeFilter::from(value))
+    }
+}
+
+/// <p>The enrollment status of a user.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnrollmentStatus {
+    /// <p><code>INITIALIZED</code></p>
+    Initialized,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>REGISTERED</code></p>
+    Registered,
+    /// <p><code>DISASSOCIATING</code></p>
+    Disassociating,
+    /// <p><code>DEREGISTERING</code></p>
+    Deregistering,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnrollmentStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnrollmentStatus::Initialized => "INITIALIZED",
+            EnrollmentStatus::Pending => "PENDING",
+            EnrollmentStatus::Registered => "REGISTERED",
+            EnrollmentStatus::Disassociating => "DISASSOCIATING",
+            EnrollmentStatus::Deregistering => "DEREGISTERING",
+            EnrollmentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnrollmentStatus {
+    fn from(value: &'a str) -> EnrollmentStatus {
+        match value {
+            "INITIALIZED" => EnrollmentStatus::Initialized,
+            "PENDING" => EnrollmentStatus::Pending,
+            "REGISTERED" => EnrollmentStatus::Registered,
+            "DISASSOCIATING" => EnrollmentStatus::Disassociating,
+            "DEREGISTERING" => EnrollmentStatus::Deregistering,
+            _ => EnrollmentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnrollmentStatus {
+    fn from(value: String) -> EnrollmentStatus {
+        EnrollmentStatus::from(value.as_str())
+    }
+}
+
+impl From<EnrollmentStatus> for String {
+    fn from(value: EnrollmentStatus) -> String {
+        match value {
+            EnrollmentStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnrollmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EnrollmentStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnrollmentStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EnrollmentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EnrollmentStatus::from(value))
+    }
+}
+
+/// <p>The list of available features on the device.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Feature {
+    /// <p><code>BLUETOOTH</code></p>
+    Bluetooth,
+    /// <p><code>VOLUME</code></p>
+    Volume,
+    /// <p><code>NOTIFICATIONS</code></p>
+    Notifications,
+    /// <p><code>LISTS</code></p>
+    Lists,
+    /// <p><code>SKILLS</code></p>
+    Skills,
+    /// <p><code>NETWORK_PROFILE</code></p>
+    NetworkProfile,
+    /// <p><code>SETTINGS</code></p>
+    Settings,
+    /// <p><code>ALL</code></p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Feature {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Feature::Bluetooth => "BLUETOOTH",
+            Feature::Volume => "VOLUME",
+            Feature::Notifications => "NOTIFICATIONS",
+            Feature::Lists => "LISTS",
+            Feature::Skills => "SKILLS",
+            Feature::NetworkProfile => "NETWORK_PROFILE",
+            Feature::Settings => "SETTINGS",
+            Feature::All => "ALL",
+            Feature::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Feature {
+    fn from(value: &'a str) -> Feature {
+        match value {
+            "BLUETOOTH" => Feature::Bluetooth,
+            "VOLUME" => Feature::Volume,
+            "NOTIFICATIONS" => Feature::Notifications,
+            "LISTS" => Feature::Lists,
+            "SKILLS" => Feature::Skills,
+            "NETWORK_PROFILE" => Feature::NetworkProfile,
+            "SETTINGS" => Feature::Settings,
+            "ALL" => Feature::All,
+            _ => Feature::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Feature {
+    fn from(value: String) -> Feature {
+        Feature::from(value.as_str())
+    }
+}
+
+impl From<Feature> for String {
+    fn from(value: Feature) -> String {
+        match value {
+            Feature::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Feature {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Feature {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Feature, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Feature::from(value))
+    }
+}
+
+/// <p>A filter name and value pair that is used to return a more specific list of results. Filters can be used to match a set of resources by various criteria.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct Filter {
+    /// <p>The key of a filter.</p>
+    #[serde(rename = "Key")]
+    pub key: String,
+    /// <p>The values of a filter.</p>
+    #[serde(rename = "Values")]
+    pub values: Vec<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ForgetSmartHomeAppliancesRequest {
+    /// <p>The room that the appliances are associated with.</p>
+    #[serde(rename = "RoomArn")]
+    pub room_arn: String,
+}
+
+impl ForgetSmartHomeAppliancesRequest {
+    /// Returns a builder for `ForgetSmartHomeAppliancesRequest`, checking its required members when built.
+    pub fn builder() -> ForgetSmartHomeAppliancesRequestBuilder {
+        ForgetSmartHomeAppliancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ForgetSmartHomeAppliancesRequest`](struct.ForgetSmartHomeAppliancesRequest.html).
+#[derive(Default, Debug)]
+pub struct ForgetSmartHomeAppliancesRequestBuilder {
+    room_arn: Option<String>,
+}
+
+impl ForgetSmartHomeAppliancesRequestBuilder {
+    /// Sets the [`room_arn`](struct.ForgetSmartHomeAppliancesRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ForgetSmartHomeAppliancesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ForgetSmartHomeAppliancesRequest, ::rusoto_core::BuildError> {
+        Ok(ForgetSmartHomeAppliancesRequest {
+            room_arn: self
+                .room_arn
+                .ok_or(::rusoto_core::BuildError::missing("room_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ForgetSmartHomeAppliancesResponse {}
+
+/// <p>The details of the gateway. </p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Gateway {
+    /// <p>The ARN of the gateway.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The description of the gateway.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The ARN of the gateway group that the gateway is associated to.</p>
+    #[serde(rename = "GatewayGroupArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gateway_group_arn: Option<String>,
+    /// <p>The name of the gateway.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The software version of the gateway. The gateway automatically updates its software version during normal operation.</p>
+    #[serde(rename = "SoftwareVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub software_version: Option<String>,
+}
+
+/// <p>The details of the gateway group.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GatewayGroup {
+    /// <p>The ARN of the gateway group.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The description of the gateway group.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The name of the gateway group.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// <p>The summary of a gateway group.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GatewayGroupSummary {
+    /// <p>The ARN of the gateway group.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The description of the gateway group.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The name of the gateway group.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// <p>The summary of a gateway.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
 pub struct GatewaySummary {
     /// <p>The ARN of the gateway.</p>
     #[serde(rename = "Arn")]
@@ -1302,6 +4327,36 @@ pub struct GetAddressBookRequest {
     pub address_book_arn: String,
 }
 
+impl GetAddressBookRequest {
+    /// Returns a builder for `GetAddressBookRequest`, checking its required members when built.
+    pub fn builder() -> GetAddressBookRequestBuilder {
+        GetAddressBookRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAddressBookRequest`](struct.GetAddressBookRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAddressBookRequestBuilder {
+    address_book_arn: Option<String>,
+}
+
+impl GetAddressBookRequestBuilder {
+    /// Sets the [`address_book_arn`](struct.GetAddressBookRequest.html#structfield.address_book_arn) member.
+    pub fn address_book_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.address_book_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAddressBookRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetAddressBookRequest, ::rusoto_core::BuildError> {
+        Ok(GetAddressBookRequest {
+            address_book_arn: self
+                .address_book_arn
+                .ok_or(::rusoto_core::BuildError::missing("address_book_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAddressBookResponse {
@@ -1330,6 +4385,38 @@ pub struct GetConferenceProviderRequest {
     pub conference_provider_arn: String,
 }
 
+impl GetConferenceProviderRequest {
+    /// Returns a builder for `GetConferenceProviderRequest`, checking its required members when built.
+    pub fn builder() -> GetConferenceProviderRequestBuilder {
+        GetConferenceProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetConferenceProviderRequest`](struct.GetConferenceProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct GetConferenceProviderRequestBuilder {
+    conference_provider_arn: Option<String>,
+}
+
+impl GetConferenceProviderRequestBuilder {
+    /// Sets the [`conference_provider_arn`](struct.GetConferenceProviderRequest.html#structfield.conference_provider_arn) member.
+    pub fn conference_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.conference_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetConferenceProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetConferenceProviderRequest, ::rusoto_core::BuildError> {
+        Ok(GetConferenceProviderRequest {
+            conference_provider_arn: self.conference_provider_arn.ok_or(
+                ::rusoto_core::BuildError::missing("conference_provider_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetConferenceProviderResponse {
@@ -1346,13 +4433,43 @@ pub struct GetContactRequest {
     pub contact_arn: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetContactResponse {
-    /// <p>The details of the requested contact.</p>
-    #[serde(rename = "Contact")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact: Option<Contact>,
+impl GetContactRequest {
+    /// Returns a builder for `GetContactRequest`, checking its required members when built.
+    pub fn builder() -> GetContactRequestBuilder {
+        GetContactRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetContactRequest`](struct.GetContactRequest.html).
+#[derive(Default, Debug)]
+pub struct GetContactRequestBuilder {
+    contact_arn: Option<String>,
+}
+
+impl GetContactRequestBuilder {
+    /// Sets the [`contact_arn`](struct.GetContactRequest.html#structfield.contact_arn) member.
+    pub fn contact_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.contact_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetContactRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetContactRequest, ::rusoto_core::BuildError> {
+        Ok(GetContactRequest {
+            contact_arn: self
+                .contact_arn
+                .ok_or(::rusoto_core::BuildError::missing("contact_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetContactResponse {
+    /// <p>The details of the requested contact.</p>
+    #[serde(rename = "Contact")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact: Option<Contact>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1363,6 +4480,34 @@ pub struct GetDeviceRequest {
     pub device_arn: Option<String>,
 }
 
+impl GetDeviceRequest {
+    /// Returns a builder for `GetDeviceRequest`, checking its required members when built.
+    pub fn builder() -> GetDeviceRequestBuilder {
+        GetDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeviceRequest`](struct.GetDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDeviceRequestBuilder {
+    device_arn: Option<String>,
+}
+
+impl GetDeviceRequestBuilder {
+    /// Sets the [`device_arn`](struct.GetDeviceRequest.html#structfield.device_arn) member.
+    pub fn device_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(GetDeviceRequest {
+            device_arn: self.device_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDeviceResponse {
@@ -1379,6 +4524,36 @@ pub struct GetGatewayGroupRequest {
     pub gateway_group_arn: String,
 }
 
+impl GetGatewayGroupRequest {
+    /// Returns a builder for `GetGatewayGroupRequest`, checking its required members when built.
+    pub fn builder() -> GetGatewayGroupRequestBuilder {
+        GetGatewayGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGatewayGroupRequest`](struct.GetGatewayGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGatewayGroupRequestBuilder {
+    gateway_group_arn: Option<String>,
+}
+
+impl GetGatewayGroupRequestBuilder {
+    /// Sets the [`gateway_group_arn`](struct.GetGatewayGroupRequest.html#structfield.gateway_group_arn) member.
+    pub fn gateway_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_group_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGatewayGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGatewayGroupRequest, ::rusoto_core::BuildError> {
+        Ok(GetGatewayGroupRequest {
+            gateway_group_arn: self
+                .gateway_group_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_group_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetGatewayGroupResponse {
@@ -1394,6 +4569,36 @@ pub struct GetGatewayRequest {
     pub gateway_arn: String,
 }
 
+impl GetGatewayRequest {
+    /// Returns a builder for `GetGatewayRequest`, checking its required members when built.
+    pub fn builder() -> GetGatewayRequestBuilder {
+        GetGatewayRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGatewayRequest`](struct.GetGatewayRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGatewayRequestBuilder {
+    gateway_arn: Option<String>,
+}
+
+impl GetGatewayRequestBuilder {
+    /// Sets the [`gateway_arn`](struct.GetGatewayRequest.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGatewayRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGatewayRequest, ::rusoto_core::BuildError> {
+        Ok(GetGatewayRequest {
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetGatewayResponse {
@@ -1430,6 +4635,38 @@ pub struct GetNetworkProfileRequest {
     pub network_profile_arn: String,
 }
 
+impl GetNetworkProfileRequest {
+    /// Returns a builder for `GetNetworkProfileRequest`, checking its required members when built.
+    pub fn builder() -> GetNetworkProfileRequestBuilder {
+        GetNetworkProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetNetworkProfileRequest`](struct.GetNetworkProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct GetNetworkProfileRequestBuilder {
+    network_profile_arn: Option<String>,
+}
+
+impl GetNetworkProfileRequestBuilder {
+    /// Sets the [`network_profile_arn`](struct.GetNetworkProfileRequest.html#structfield.network_profile_arn) member.
+    pub fn network_profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.network_profile_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetNetworkProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetNetworkProfileRequest, ::rusoto_core::BuildError> {
+        Ok(GetNetworkProfileRequest {
+            network_profile_arn: self
+                .network_profile_arn
+                .ok_or(::rusoto_core::BuildError::missing("network_profile_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetNetworkProfileResponse {
@@ -1447,6 +4684,34 @@ pub struct GetProfileRequest {
     pub profile_arn: Option<String>,
 }
 
+impl GetProfileRequest {
+    /// Returns a builder for `GetProfileRequest`, checking its required members when built.
+    pub fn builder() -> GetProfileRequestBuilder {
+        GetProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetProfileRequest`](struct.GetProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct GetProfileRequestBuilder {
+    profile_arn: Option<String>,
+}
+
+impl GetProfileRequestBuilder {
+    /// Sets the [`profile_arn`](struct.GetProfileRequest.html#structfield.profile_arn) member.
+    pub fn profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.profile_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetProfileRequest, ::rusoto_core::BuildError> {
+        Ok(GetProfileRequest {
+            profile_arn: self.profile_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetProfileResponse {
@@ -1464,6 +4729,34 @@ pub struct GetRoomRequest {
     pub room_arn: Option<String>,
 }
 
+impl GetRoomRequest {
+    /// Returns a builder for `GetRoomRequest`, checking its required members when built.
+    pub fn builder() -> GetRoomRequestBuilder {
+        GetRoomRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRoomRequest`](struct.GetRoomRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRoomRequestBuilder {
+    room_arn: Option<String>,
+}
+
+impl GetRoomRequestBuilder {
+    /// Sets the [`room_arn`](struct.GetRoomRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRoomRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRoomRequest, ::rusoto_core::BuildError> {
+        Ok(GetRoomRequest {
+            room_arn: self.room_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRoomResponse {
@@ -1487,6 +4780,56 @@ pub struct GetRoomSkillParameterRequest {
     pub skill_id: String,
 }
 
+impl GetRoomSkillParameterRequest {
+    /// Returns a builder for `GetRoomSkillParameterRequest`, checking its required members when built.
+    pub fn builder() -> GetRoomSkillParameterRequestBuilder {
+        GetRoomSkillParameterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRoomSkillParameterRequest`](struct.GetRoomSkillParameterRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRoomSkillParameterRequestBuilder {
+    parameter_key: Option<String>,
+    room_arn: Option<String>,
+    skill_id: Option<String>,
+}
+
+impl GetRoomSkillParameterRequestBuilder {
+    /// Sets the [`parameter_key`](struct.GetRoomSkillParameterRequest.html#structfield.parameter_key) member.
+    pub fn parameter_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`room_arn`](struct.GetRoomSkillParameterRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_id`](struct.GetRoomSkillParameterRequest.html#structfield.skill_id) member.
+    pub fn skill_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRoomSkillParameterRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRoomSkillParameterRequest, ::rusoto_core::BuildError> {
+        Ok(GetRoomSkillParameterRequest {
+            parameter_key: self
+                .parameter_key
+                .ok_or(::rusoto_core::BuildError::missing("parameter_key"))?,
+            room_arn: self.room_arn,
+            skill_id: self
+                .skill_id
+                .ok_or(::rusoto_core::BuildError::missing("skill_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRoomSkillParameterResponse {
@@ -1504,6 +4847,34 @@ pub struct GetSkillGroupRequest {
     pub skill_group_arn: Option<String>,
 }
 
+impl GetSkillGroupRequest {
+    /// Returns a builder for `GetSkillGroupRequest`, checking its required members when built.
+    pub fn builder() -> GetSkillGroupRequestBuilder {
+        GetSkillGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSkillGroupRequest`](struct.GetSkillGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSkillGroupRequestBuilder {
+    skill_group_arn: Option<String>,
+}
+
+impl GetSkillGroupRequestBuilder {
+    /// Sets the [`skill_group_arn`](struct.GetSkillGroupRequest.html#structfield.skill_group_arn) member.
+    pub fn skill_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_group_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSkillGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSkillGroupRequest, ::rusoto_core::BuildError> {
+        Ok(GetSkillGroupRequest {
+            skill_group_arn: self.skill_group_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSkillGroupResponse {
@@ -1514,11 +4885,11 @@ pub struct GetSkillGroupResponse {
 }
 
 /// <p>The IP endpoint and protocol for calling.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IPDialIn {
     /// <p>The protocol, including SIP, SIPS, and H323.</p>
     #[serde(rename = "CommsProtocol")]
-    pub comms_protocol: String,
+    pub comms_protocol: CommsProtocol,
     /// <p>The IP address.</p>
     #[serde(rename = "Endpoint")]
     pub endpoint: String,
@@ -1536,6 +4907,44 @@ pub struct ListBusinessReportSchedulesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListBusinessReportSchedulesRequest {
+    /// Returns a builder for `ListBusinessReportSchedulesRequest`, checking its required members when built.
+    pub fn builder() -> ListBusinessReportSchedulesRequestBuilder {
+        ListBusinessReportSchedulesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListBusinessReportSchedulesRequest`](struct.ListBusinessReportSchedulesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListBusinessReportSchedulesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListBusinessReportSchedulesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListBusinessReportSchedulesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListBusinessReportSchedulesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBusinessReportSchedulesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListBusinessReportSchedulesRequest, ::rusoto_core::BuildError> {
+        Ok(ListBusinessReportSchedulesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListBusinessReportSchedulesResponse {
@@ -1561,6 +4970,44 @@ pub struct ListConferenceProvidersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListConferenceProvidersRequest {
+    /// Returns a builder for `ListConferenceProvidersRequest`, checking its required members when built.
+    pub fn builder() -> ListConferenceProvidersRequestBuilder {
+        ListConferenceProvidersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListConferenceProvidersRequest`](struct.ListConferenceProvidersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListConferenceProvidersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListConferenceProvidersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListConferenceProvidersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListConferenceProvidersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListConferenceProvidersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListConferenceProvidersRequest, ::rusoto_core::BuildError> {
+        Ok(ListConferenceProvidersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListConferenceProvidersResponse {
@@ -1582,7 +5029,7 @@ pub struct ListDeviceEventsRequest {
     /// <p>The event type to filter device events. If EventType isn't specified, this returns a list of all device events in reverse chronological order. If EventType is specified, this returns a list of device events for that EventType in reverse chronological order. </p>
     #[serde(rename = "EventType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type: Option<String>,
+    pub event_type: Option<DeviceEventType>,
     /// <p>The maximum number of results to include in the response. The default value is 50. If more results exist than the specified MaxResults value, a token is included in the response so that the remaining results can be retrieved. </p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1593,6 +5040,62 @@ pub struct ListDeviceEventsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDeviceEventsRequest {
+    /// Returns a builder for `ListDeviceEventsRequest`, checking its required members when built.
+    pub fn builder() -> ListDeviceEventsRequestBuilder {
+        ListDeviceEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeviceEventsRequest`](struct.ListDeviceEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDeviceEventsRequestBuilder {
+    device_arn: Option<String>,
+    event_type: Option<DeviceEventType>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDeviceEventsRequestBuilder {
+    /// Sets the [`device_arn`](struct.ListDeviceEventsRequest.html#structfield.device_arn) member.
+    pub fn device_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_type`](struct.ListDeviceEventsRequest.html#structfield.event_type) member.
+    pub fn event_type<T: Into<DeviceEventType>>(mut self, value: T) -> Self {
+        self.event_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDeviceEventsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDeviceEventsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeviceEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDeviceEventsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDeviceEventsRequest {
+            device_arn: self
+                .device_arn
+                .ok_or(::rusoto_core::BuildError::missing("device_arn"))?,
+            event_type: self.event_type,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDeviceEventsResponse {
@@ -1618,6 +5121,44 @@ pub struct ListGatewayGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListGatewayGroupsRequest {
+    /// Returns a builder for `ListGatewayGroupsRequest`, checking its required members when built.
+    pub fn builder() -> ListGatewayGroupsRequestBuilder {
+        ListGatewayGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGatewayGroupsRequest`](struct.ListGatewayGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGatewayGroupsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListGatewayGroupsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListGatewayGroupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListGatewayGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGatewayGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListGatewayGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(ListGatewayGroupsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGatewayGroupsResponse {
@@ -1647,6 +5188,50 @@ pub struct ListGatewaysRequest {
     pub next_token: Option<String>,
 }
 
+impl ListGatewaysRequest {
+    /// Returns a builder for `ListGatewaysRequest`, checking its required members when built.
+    pub fn builder() -> ListGatewaysRequestBuilder {
+        ListGatewaysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGatewaysRequest`](struct.ListGatewaysRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGatewaysRequestBuilder {
+    gateway_group_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListGatewaysRequestBuilder {
+    /// Sets the [`gateway_group_arn`](struct.ListGatewaysRequest.html#structfield.gateway_group_arn) member.
+    pub fn gateway_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListGatewaysRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListGatewaysRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGatewaysRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListGatewaysRequest, ::rusoto_core::BuildError> {
+        Ok(ListGatewaysRequest {
+            gateway_group_arn: self.gateway_group_arn,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGatewaysResponse {
@@ -1665,7 +5250,7 @@ pub struct ListSkillsRequest {
     /// <p>Whether the skill is enabled under the user's account, or if it requires linking to be used.</p>
     #[serde(rename = "EnablementType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enablement_type: Option<String>,
+    pub enablement_type: Option<EnablementTypeFilter>,
     /// <p>The maximum number of results to include in the response. If more results exist than the specified <code>MaxResults</code> value, a token is included in the response so that the remaining results can be retrieved. Required.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1681,7 +5266,67 @@ pub struct ListSkillsRequest {
     /// <p>Whether the skill is publicly available or is a private skill.</p>
     #[serde(rename = "SkillType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub skill_type: Option<String>,
+    pub skill_type: Option<SkillTypeFilter>,
+}
+
+impl ListSkillsRequest {
+    /// Returns a builder for `ListSkillsRequest`, checking its required members when built.
+    pub fn builder() -> ListSkillsRequestBuilder {
+        ListSkillsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSkillsRequest`](struct.ListSkillsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSkillsRequestBuilder {
+    enablement_type: Option<EnablementTypeFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    skill_group_arn: Option<String>,
+    skill_type: Option<SkillTypeFilter>,
+}
+
+impl ListSkillsRequestBuilder {
+    /// Sets the [`enablement_type`](struct.ListSkillsRequest.html#structfield.enablement_type) member.
+    pub fn enablement_type<T: Into<EnablementTypeFilter>>(mut self, value: T) -> Self {
+        self.enablement_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListSkillsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSkillsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_group_arn`](struct.ListSkillsRequest.html#structfield.skill_group_arn) member.
+    pub fn skill_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_type`](struct.ListSkillsRequest.html#structfield.skill_type) member.
+    pub fn skill_type<T: Into<SkillTypeFilter>>(mut self, value: T) -> Self {
+        self.skill_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSkillsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListSkillsRequest, ::rusoto_core::BuildError> {
+        Ok(ListSkillsRequest {
+            enablement_type: self.enablement_type,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            skill_group_arn: self.skill_group_arn,
+            skill_type: self.skill_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1709,6 +5354,44 @@ pub struct ListSkillsStoreCategoriesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListSkillsStoreCategoriesRequest {
+    /// Returns a builder for `ListSkillsStoreCategoriesRequest`, checking its required members when built.
+    pub fn builder() -> ListSkillsStoreCategoriesRequestBuilder {
+        ListSkillsStoreCategoriesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSkillsStoreCategoriesRequest`](struct.ListSkillsStoreCategoriesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSkillsStoreCategoriesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListSkillsStoreCategoriesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListSkillsStoreCategoriesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSkillsStoreCategoriesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSkillsStoreCategoriesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSkillsStoreCategoriesRequest, ::rusoto_core::BuildError> {
+        Ok(ListSkillsStoreCategoriesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSkillsStoreCategoriesResponse {
@@ -1737,6 +5420,55 @@ pub struct ListSkillsStoreSkillsByCategoryRequest {
     pub next_token: Option<String>,
 }
 
+impl ListSkillsStoreSkillsByCategoryRequest {
+    /// Returns a builder for `ListSkillsStoreSkillsByCategoryRequest`, checking its required members when built.
+    pub fn builder() -> ListSkillsStoreSkillsByCategoryRequestBuilder {
+        ListSkillsStoreSkillsByCategoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSkillsStoreSkillsByCategoryRequest`](struct.ListSkillsStoreSkillsByCategoryRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSkillsStoreSkillsByCategoryRequestBuilder {
+    category_id: Option<i64>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListSkillsStoreSkillsByCategoryRequestBuilder {
+    /// Sets the [`category_id`](struct.ListSkillsStoreSkillsByCategoryRequest.html#structfield.category_id) member.
+    pub fn category_id<T: Into<i64>>(mut self, value: T) -> Self {
+        self.category_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListSkillsStoreSkillsByCategoryRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSkillsStoreSkillsByCategoryRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSkillsStoreSkillsByCategoryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSkillsStoreSkillsByCategoryRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListSkillsStoreSkillsByCategoryRequest {
+            category_id: self
+                .category_id
+                .ok_or(::rusoto_core::BuildError::missing("category_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSkillsStoreSkillsByCategoryResponse {
@@ -1765,6 +5497,54 @@ pub struct ListSmartHomeAppliancesRequest {
     pub room_arn: String,
 }
 
+impl ListSmartHomeAppliancesRequest {
+    /// Returns a builder for `ListSmartHomeAppliancesRequest`, checking its required members when built.
+    pub fn builder() -> ListSmartHomeAppliancesRequestBuilder {
+        ListSmartHomeAppliancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSmartHomeAppliancesRequest`](struct.ListSmartHomeAppliancesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSmartHomeAppliancesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    room_arn: Option<String>,
+}
+
+impl ListSmartHomeAppliancesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListSmartHomeAppliancesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSmartHomeAppliancesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`room_arn`](struct.ListSmartHomeAppliancesRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSmartHomeAppliancesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSmartHomeAppliancesRequest, ::rusoto_core::BuildError> {
+        Ok(ListSmartHomeAppliancesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            room_arn: self
+                .room_arn
+                .ok_or(::rusoto_core::BuildError::missing("room_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSmartHomeAppliancesResponse {
@@ -1793,25 +5573,205 @@ pub struct ListTagsRequest {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListTagsResponse {
-    /// <p>The token returned to indicate that there is more data available.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>The tags requested for the specified resource.</p>
-    #[serde(rename = "Tags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<Vec<Tag>>,
+impl ListTagsRequest {
+    /// Returns a builder for `ListTagsRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsRequestBuilder {
+        ListTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsRequest`](struct.ListTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsRequestBuilder {
+    arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListTagsRequestBuilder {
+    /// Sets the [`arn`](struct.ListTagsRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListTagsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTagsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListTagsResponse {
+    /// <p>The token returned to indicate that there is more data available.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>The tags requested for the specified resource.</p>
+    #[serde(rename = "Tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+/// <p>The locale of the audio message. Currently, en-US is supported.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// <p><code>en-US</code></p>
+    EnUs,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Locale {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Locale::EnUs => "en-US",
+            Locale::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Locale {
+    fn from(value: &'a str) -> Locale {
+        match value {
+            "en-US" => Locale::EnUs,
+            _ => Locale::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Locale {
+    fn from(value: String) -> Locale {
+        Locale::from(value.as_str())
+    }
+}
+
+impl From<Locale> for String {
+    fn from(value: Locale) -> String {
+        match value {
+            Locale::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Locale, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Locale::from(value))
+    }
 }
 
 /// <p><p>The values that indicate whether a pin is always required (YES), never required (NO), or OPTIONAL.</p> <ul> <li> <p>If YES, Alexa will always ask for a meeting pin.</p> </li> <li> <p>If NO, Alexa will never ask for a meeting pin.</p> </li> <li> <p>If OPTIONAL, Alexa will ask if you have a meeting pin and if the customer responds with yes, it will ask for the meeting pin.</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MeetingSetting {
     /// <p>The values that indicate whether the pin is always required.</p>
     #[serde(rename = "RequirePin")]
-    pub require_pin: String,
+    pub require_pin: RequirePin,
+}
+
+/// <p>The authentication standard that is used in the EAP framework. Currently, EAP_TLS is supported.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NetworkEapMethod {
+    /// <p><code>EAP_TLS</code></p>
+    EapTls,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NetworkEapMethod {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkEapMethod::EapTls => "EAP_TLS",
+            NetworkEapMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NetworkEapMethod {
+    fn from(value: &'a str) -> NetworkEapMethod {
+        match value {
+            "EAP_TLS" => NetworkEapMethod::EapTls,
+            _ => NetworkEapMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkEapMethod {
+    fn from(value: String) -> NetworkEapMethod {
+        NetworkEapMethod::from(value.as_str())
+    }
+}
+
+impl From<NetworkEapMethod> for String {
+    fn from(value: NetworkEapMethod) -> String {
+        match value {
+            NetworkEapMethod::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NetworkEapMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NetworkEapMethod {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NetworkEapMethod {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NetworkEapMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NetworkEapMethod::from(value))
+    }
 }
 
 /// <p>The network profile associated with a device.</p>
@@ -1833,7 +5793,7 @@ pub struct NetworkProfile {
     /// <p>The authentication standard that is used in the EAP framework. Currently, EAP_TLS is supported. </p>
     #[serde(rename = "EapMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub eap_method: Option<String>,
+    pub eap_method: Option<NetworkEapMethod>,
     /// <p>The ARN of the network profile associated with a device.</p>
     #[serde(rename = "NetworkProfileArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1849,7 +5809,7 @@ pub struct NetworkProfile {
     /// <p>The security type of the Wi-Fi network. This can be WPA2_ENTERPRISE, WPA2_PSK, WPA_PSK, WEP, or OPEN.</p>
     #[serde(rename = "SecurityType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_type: Option<String>,
+    pub security_type: Option<NetworkSecurityType>,
     /// <p>The SSID of the Wi-Fi network.</p>
     #[serde(rename = "Ssid")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1875,7 +5835,7 @@ pub struct NetworkProfileData {
     /// <p>The authentication standard that is used in the EAP framework. Currently, EAP_TLS is supported.</p>
     #[serde(rename = "EapMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub eap_method: Option<String>,
+    pub eap_method: Option<NetworkEapMethod>,
     /// <p>The ARN of the network profile associated with a device.</p>
     #[serde(rename = "NetworkProfileArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1887,13 +5847,97 @@ pub struct NetworkProfileData {
     /// <p>The security type of the Wi-Fi network. This can be WPA2_ENTERPRISE, WPA2_PSK, WPA_PSK, WEP, or OPEN.</p>
     #[serde(rename = "SecurityType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_type: Option<String>,
+    pub security_type: Option<NetworkSecurityType>,
     /// <p>The SSID of the Wi-Fi network.</p>
     #[serde(rename = "Ssid")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ssid: Option<String>,
 }
 
+/// <p>The security type of the Wi-Fi network. This can be WPA2_ENTERPRISE, WPA2_PSK, WPA_PSK, WEP, or OPEN.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NetworkSecurityType {
+    /// <p><code>OPEN</code></p>
+    Open,
+    /// <p><code>WEP</code></p>
+    Wep,
+    /// <p><code>WPA_PSK</code></p>
+    WpaPsk,
+    /// <p><code>WPA2_PSK</code></p>
+    Wpa2Psk,
+    /// <p><code>WPA2_ENTERPRISE</code></p>
+    Wpa2Enterprise,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NetworkSecurityType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkSecurityType::Open => "OPEN",
+            NetworkSecurityType::Wep => "WEP",
+            NetworkSecurityType::WpaPsk => "WPA_PSK",
+            NetworkSecurityType::Wpa2Psk => "WPA2_PSK",
+            NetworkSecurityType::Wpa2Enterprise => "WPA2_ENTERPRISE",
+            NetworkSecurityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NetworkSecurityType {
+    fn from(value: &'a str) -> NetworkSecurityType {
+        match value {
+            "OPEN" => NetworkSecurityType::Open,
+            "WEP" => NetworkSecurityType::Wep,
+            "WPA_PSK" => NetworkSecurityType::WpaPsk,
+            "WPA2_PSK" => NetworkSecurityType::Wpa2Psk,
+            "WPA2_ENTERPRISE" => NetworkSecurityType::Wpa2Enterprise,
+            _ => NetworkSecurityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkSecurityType {
+    fn from(value: String) -> NetworkSecurityType {
+        NetworkSecurityType::from(value.as_str())
+    }
+}
+
+impl From<NetworkSecurityType> for String {
+    fn from(value: NetworkSecurityType) -> String {
+        match value {
+            NetworkSecurityType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NetworkSecurityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NetworkSecurityType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NetworkSecurityType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NetworkSecurityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NetworkSecurityType::from(value))
+    }
+}
+
 /// <p>The information for public switched telephone network (PSTN) conferencing.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PSTNDialIn {
@@ -1912,14 +5956,90 @@ pub struct PSTNDialIn {
 }
 
 /// <p>The phone number for the contact containing the raw number and phone number type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PhoneNumber {
     /// <p>The raw value of the phone number.</p>
     #[serde(rename = "Number")]
     pub number: String,
     /// <p>The type of the phone number.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: PhoneNumberType,
+}
+
+/// <p>The type of the phone number.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PhoneNumberType {
+    /// <p><code>MOBILE</code></p>
+    Mobile,
+    /// <p><code>WORK</code></p>
+    Work,
+    /// <p><code>HOME</code></p>
+    Home,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PhoneNumberType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberType::Mobile => "MOBILE",
+            PhoneNumberType::Work => "WORK",
+            PhoneNumberType::Home => "HOME",
+            PhoneNumberType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PhoneNumberType {
+    fn from(value: &'a str) -> PhoneNumberType {
+        match value {
+            "MOBILE" => PhoneNumberType::Mobile,
+            "WORK" => PhoneNumberType::Work,
+            "HOME" => PhoneNumberType::Home,
+            _ => PhoneNumberType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneNumberType {
+    fn from(value: String) -> PhoneNumberType {
+        PhoneNumberType::from(value.as_str())
+    }
+}
+
+impl From<PhoneNumberType> for String {
+    fn from(value: PhoneNumberType) -> String {
+        match value {
+            PhoneNumberType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PhoneNumberType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PhoneNumberType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PhoneNumberType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PhoneNumberType::from(value))
+    }
 }
 
 /// <p>A room profile with attributes.</p>
@@ -1937,7 +6057,7 @@ pub struct Profile {
     /// <p>The distance unit of a room profile.</p>
     #[serde(rename = "DistanceUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub distance_unit: Option<String>,
+    pub distance_unit: Option<DistanceUnit>,
     /// <p>Retrieves if the profile is default or not.</p>
     #[serde(rename = "IsDefault")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1969,7 +6089,7 @@ pub struct Profile {
     /// <p>The temperature unit of a room profile.</p>
     #[serde(rename = "TemperatureUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub temperature_unit: Option<String>,
+    pub temperature_unit: Option<TemperatureUnit>,
     /// <p>The time zone of a room profile.</p>
     #[serde(rename = "Timezone")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1977,7 +6097,7 @@ pub struct Profile {
     /// <p>The wake word of a room profile.</p>
     #[serde(rename = "WakeWord")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wake_word: Option<String>,
+    pub wake_word: Option<WakeWord>,
 }
 
 /// <p>The data of a room profile.</p>
@@ -1991,7 +6111,7 @@ pub struct ProfileData {
     /// <p>The distance unit of a room profile.</p>
     #[serde(rename = "DistanceUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub distance_unit: Option<String>,
+    pub distance_unit: Option<DistanceUnit>,
     /// <p>Retrieves if the profile data is default or not.</p>
     #[serde(rename = "IsDefault")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2011,7 +6131,7 @@ pub struct ProfileData {
     /// <p>The temperature unit of a room profile.</p>
     #[serde(rename = "TemperatureUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub temperature_unit: Option<String>,
+    pub temperature_unit: Option<TemperatureUnit>,
     /// <p>The timezone of a room profile.</p>
     #[serde(rename = "Timezone")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2019,7 +6139,7 @@ pub struct ProfileData {
     /// <p>The wake word of a room profile.</p>
     #[serde(rename = "WakeWord")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wake_word: Option<String>,
+    pub wake_word: Option<WakeWord>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2029,6 +6149,38 @@ pub struct PutConferencePreferenceRequest {
     pub conference_preference: ConferencePreference,
 }
 
+impl PutConferencePreferenceRequest {
+    /// Returns a builder for `PutConferencePreferenceRequest`, checking its required members when built.
+    pub fn builder() -> PutConferencePreferenceRequestBuilder {
+        PutConferencePreferenceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutConferencePreferenceRequest`](struct.PutConferencePreferenceRequest.html).
+#[derive(Default, Debug)]
+pub struct PutConferencePreferenceRequestBuilder {
+    conference_preference: Option<ConferencePreference>,
+}
+
+impl PutConferencePreferenceRequestBuilder {
+    /// Sets the [`conference_preference`](struct.PutConferencePreferenceRequest.html#structfield.conference_preference) member.
+    pub fn conference_preference<T: Into<ConferencePreference>>(mut self, value: T) -> Self {
+        self.conference_preference = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutConferencePreferenceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutConferencePreferenceRequest, ::rusoto_core::BuildError> {
+        Ok(PutConferencePreferenceRequest {
+            conference_preference: self
+                .conference_preference
+                .ok_or(::rusoto_core::BuildError::missing("conference_preference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutConferencePreferenceResponse {}
@@ -2048,6 +6200,54 @@ pub struct PutInvitationConfigurationRequest {
     pub private_skill_ids: Option<Vec<String>>,
 }
 
+impl PutInvitationConfigurationRequest {
+    /// Returns a builder for `PutInvitationConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> PutInvitationConfigurationRequestBuilder {
+        PutInvitationConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutInvitationConfigurationRequest`](struct.PutInvitationConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutInvitationConfigurationRequestBuilder {
+    contact_email: Option<String>,
+    organization_name: Option<String>,
+    private_skill_ids: Option<Vec<String>>,
+}
+
+impl PutInvitationConfigurationRequestBuilder {
+    /// Sets the [`contact_email`](struct.PutInvitationConfigurationRequest.html#structfield.contact_email) member.
+    pub fn contact_email<T: Into<String>>(mut self, value: T) -> Self {
+        self.contact_email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_name`](struct.PutInvitationConfigurationRequest.html#structfield.organization_name) member.
+    pub fn organization_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`private_skill_ids`](struct.PutInvitationConfigurationRequest.html#structfield.private_skill_ids) member.
+    pub fn private_skill_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.private_skill_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutInvitationConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutInvitationConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(PutInvitationConfigurationRequest {
+            contact_email: self.contact_email,
+            organization_name: self
+                .organization_name
+                .ok_or(::rusoto_core::BuildError::missing("organization_name"))?,
+            private_skill_ids: self.private_skill_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutInvitationConfigurationResponse {}
@@ -2066,6 +6266,56 @@ pub struct PutRoomSkillParameterRequest {
     pub skill_id: String,
 }
 
+impl PutRoomSkillParameterRequest {
+    /// Returns a builder for `PutRoomSkillParameterRequest`, checking its required members when built.
+    pub fn builder() -> PutRoomSkillParameterRequestBuilder {
+        PutRoomSkillParameterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutRoomSkillParameterRequest`](struct.PutRoomSkillParameterRequest.html).
+#[derive(Default, Debug)]
+pub struct PutRoomSkillParameterRequestBuilder {
+    room_arn: Option<String>,
+    room_skill_parameter: Option<RoomSkillParameter>,
+    skill_id: Option<String>,
+}
+
+impl PutRoomSkillParameterRequestBuilder {
+    /// Sets the [`room_arn`](struct.PutRoomSkillParameterRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`room_skill_parameter`](struct.PutRoomSkillParameterRequest.html#structfield.room_skill_parameter) member.
+    pub fn room_skill_parameter<T: Into<RoomSkillParameter>>(mut self, value: T) -> Self {
+        self.room_skill_parameter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_id`](struct.PutRoomSkillParameterRequest.html#structfield.skill_id) member.
+    pub fn skill_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRoomSkillParameterRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutRoomSkillParameterRequest, ::rusoto_core::BuildError> {
+        Ok(PutRoomSkillParameterRequest {
+            room_arn: self.room_arn,
+            room_skill_parameter: self
+                .room_skill_parameter
+                .ok_or(::rusoto_core::BuildError::missing("room_skill_parameter"))?,
+            skill_id: self
+                .skill_id
+                .ok_or(::rusoto_core::BuildError::missing("skill_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutRoomSkillParameterResponse {}
@@ -2084,6 +6334,59 @@ pub struct PutSkillAuthorizationRequest {
     pub skill_id: String,
 }
 
+impl PutSkillAuthorizationRequest {
+    /// Returns a builder for `PutSkillAuthorizationRequest`, checking its required members when built.
+    pub fn builder() -> PutSkillAuthorizationRequestBuilder {
+        PutSkillAuthorizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutSkillAuthorizationRequest`](struct.PutSkillAuthorizationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutSkillAuthorizationRequestBuilder {
+    authorization_result: Option<::std::collections::HashMap<String, String>>,
+    room_arn: Option<String>,
+    skill_id: Option<String>,
+}
+
+impl PutSkillAuthorizationRequestBuilder {
+    /// Sets the [`authorization_result`](struct.PutSkillAuthorizationRequest.html#structfield.authorization_result) member.
+    pub fn authorization_result<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.authorization_result = Some(value.into());
+        self
+    }
+
+    /// Sets the [`room_arn`](struct.PutSkillAuthorizationRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_id`](struct.PutSkillAuthorizationRequest.html#structfield.skill_id) member.
+    pub fn skill_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutSkillAuthorizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutSkillAuthorizationRequest, ::rusoto_core::BuildError> {
+        Ok(PutSkillAuthorizationRequest {
+            authorization_result: self
+                .authorization_result
+                .ok_or(::rusoto_core::BuildError::missing("authorization_result"))?,
+            room_arn: self.room_arn,
+            skill_id: self
+                .skill_id
+                .ok_or(::rusoto_core::BuildError::missing("skill_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutSkillAuthorizationResponse {}
@@ -2107,6 +6410,78 @@ pub struct RegisterAVSDeviceRequest {
     pub user_code: String,
 }
 
+impl RegisterAVSDeviceRequest {
+    /// Returns a builder for `RegisterAVSDeviceRequest`, checking its required members when built.
+    pub fn builder() -> RegisterAVSDeviceRequestBuilder {
+        RegisterAVSDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterAVSDeviceRequest`](struct.RegisterAVSDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterAVSDeviceRequestBuilder {
+    amazon_id: Option<String>,
+    client_id: Option<String>,
+    device_serial_number: Option<String>,
+    product_id: Option<String>,
+    user_code: Option<String>,
+}
+
+impl RegisterAVSDeviceRequestBuilder {
+    /// Sets the [`amazon_id`](struct.RegisterAVSDeviceRequest.html#structfield.amazon_id) member.
+    pub fn amazon_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.amazon_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.RegisterAVSDeviceRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_serial_number`](struct.RegisterAVSDeviceRequest.html#structfield.device_serial_number) member.
+    pub fn device_serial_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_serial_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_id`](struct.RegisterAVSDeviceRequest.html#structfield.product_id) member.
+    pub fn product_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_code`](struct.RegisterAVSDeviceRequest.html#structfield.user_code) member.
+    pub fn user_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterAVSDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterAVSDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterAVSDeviceRequest {
+            amazon_id: self
+                .amazon_id
+                .ok_or(::rusoto_core::BuildError::missing("amazon_id"))?,
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            device_serial_number: self
+                .device_serial_number
+                .ok_or(::rusoto_core::BuildError::missing("device_serial_number"))?,
+            product_id: self
+                .product_id
+                .ok_or(::rusoto_core::BuildError::missing("product_id"))?,
+            user_code: self
+                .user_code
+                .ok_or(::rusoto_core::BuildError::missing("user_code"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RegisterAVSDeviceResponse {
@@ -2123,26 +6498,172 @@ pub struct RejectSkillRequest {
     pub skill_id: String,
 }
 
+impl RejectSkillRequest {
+    /// Returns a builder for `RejectSkillRequest`, checking its required members when built.
+    pub fn builder() -> RejectSkillRequestBuilder {
+        RejectSkillRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RejectSkillRequest`](struct.RejectSkillRequest.html).
+#[derive(Default, Debug)]
+pub struct RejectSkillRequestBuilder {
+    skill_id: Option<String>,
+}
+
+impl RejectSkillRequestBuilder {
+    /// Sets the [`skill_id`](struct.RejectSkillRequest.html#structfield.skill_id) member.
+    pub fn skill_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RejectSkillRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RejectSkillRequest, ::rusoto_core::BuildError> {
+        Ok(RejectSkillRequest {
+            skill_id: self
+                .skill_id
+                .ok_or(::rusoto_core::BuildError::missing("skill_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RejectSkillResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ResolveRoomRequest {
-    /// <p>The ARN of the skill that was requested. Required.</p>
-    #[serde(rename = "SkillId")]
-    pub skill_id: String,
-    /// <p>The ARN of the user. Required.</p>
-    #[serde(rename = "UserId")]
-    pub user_id: String,
+/// <p>The values that indicate whether the pin is always required.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RequirePin {
+    /// <p><code>YES</code></p>
+    Yes,
+    /// <p><code>NO</code></p>
+    No,
+    /// <p><code>OPTIONAL</code></p>
+    Optional,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RequirePin {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequirePin::Yes => "YES",
+            RequirePin::No => "NO",
+            RequirePin::Optional => "OPTIONAL",
+            RequirePin::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ResolveRoomResponse {
-    /// <p>The ARN of the room from which the skill request was invoked.</p>
-    #[serde(rename = "RoomArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl<'a> From<&'a str> for RequirePin {
+    fn from(value: &'a str) -> RequirePin {
+        match value {
+            "YES" => RequirePin::Yes,
+            "NO" => RequirePin::No,
+            "OPTIONAL" => RequirePin::Optional,
+            _ => RequirePin::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequirePin {
+    fn from(value: String) -> RequirePin {
+        RequirePin::from(value.as_str())
+    }
+}
+
+impl From<RequirePin> for String {
+    fn from(value: RequirePin) -> String {
+        match value {
+            RequirePin::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RequirePin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RequirePin {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RequirePin {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RequirePin, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RequirePin::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ResolveRoomRequest {
+    /// <p>The ARN of the skill that was requested. Required.</p>
+    #[serde(rename = "SkillId")]
+    pub skill_id: String,
+    /// <p>The ARN of the user. Required.</p>
+    #[serde(rename = "UserId")]
+    pub user_id: String,
+}
+
+impl ResolveRoomRequest {
+    /// Returns a builder for `ResolveRoomRequest`, checking its required members when built.
+    pub fn builder() -> ResolveRoomRequestBuilder {
+        ResolveRoomRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ResolveRoomRequest`](struct.ResolveRoomRequest.html).
+#[derive(Default, Debug)]
+pub struct ResolveRoomRequestBuilder {
+    skill_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl ResolveRoomRequestBuilder {
+    /// Sets the [`skill_id`](struct.ResolveRoomRequest.html#structfield.skill_id) member.
+    pub fn skill_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.ResolveRoomRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResolveRoomRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ResolveRoomRequest, ::rusoto_core::BuildError> {
+        Ok(ResolveRoomRequest {
+            skill_id: self
+                .skill_id
+                .ok_or(::rusoto_core::BuildError::missing("skill_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ResolveRoomResponse {
+    /// <p>The ARN of the room from which the skill request was invoked.</p>
+    #[serde(rename = "RoomArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub room_arn: Option<String>,
     /// <p>The name of the room from which the skill request was invoked.</p>
     #[serde(rename = "RoomName")]
@@ -2166,6 +6687,44 @@ pub struct RevokeInvitationRequest {
     pub user_arn: Option<String>,
 }
 
+impl RevokeInvitationRequest {
+    /// Returns a builder for `RevokeInvitationRequest`, checking its required members when built.
+    pub fn builder() -> RevokeInvitationRequestBuilder {
+        RevokeInvitationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RevokeInvitationRequest`](struct.RevokeInvitationRequest.html).
+#[derive(Default, Debug)]
+pub struct RevokeInvitationRequestBuilder {
+    enrollment_id: Option<String>,
+    user_arn: Option<String>,
+}
+
+impl RevokeInvitationRequestBuilder {
+    /// Sets the [`enrollment_id`](struct.RevokeInvitationRequest.html#structfield.enrollment_id) member.
+    pub fn enrollment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.enrollment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_arn`](struct.RevokeInvitationRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RevokeInvitationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RevokeInvitationRequest, ::rusoto_core::BuildError> {
+        Ok(RevokeInvitationRequest {
+            enrollment_id: self.enrollment_id,
+            user_arn: self.user_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RevokeInvitationResponse {}
@@ -2257,6 +6816,60 @@ pub struct SearchAddressBooksRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl SearchAddressBooksRequest {
+    /// Returns a builder for `SearchAddressBooksRequest`, checking its required members when built.
+    pub fn builder() -> SearchAddressBooksRequestBuilder {
+        SearchAddressBooksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchAddressBooksRequest`](struct.SearchAddressBooksRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchAddressBooksRequestBuilder {
+    filters: Option<Vec<Filter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_criteria: Option<Vec<Sort>>,
+}
+
+impl SearchAddressBooksRequestBuilder {
+    /// Sets the [`filters`](struct.SearchAddressBooksRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.SearchAddressBooksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SearchAddressBooksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_criteria`](struct.SearchAddressBooksRequest.html#structfield.sort_criteria) member.
+    pub fn sort_criteria<T: Into<Vec<Sort>>>(mut self, value: T) -> Self {
+        self.sort_criteria = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchAddressBooksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SearchAddressBooksRequest, ::rusoto_core::BuildError> {
+        Ok(SearchAddressBooksRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_criteria: self.sort_criteria,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SearchAddressBooksResponse {
@@ -2294,6 +6907,58 @@ pub struct SearchContactsRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl SearchContactsRequest {
+    /// Returns a builder for `SearchContactsRequest`, checking its required members when built.
+    pub fn builder() -> SearchContactsRequestBuilder {
+        SearchContactsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchContactsRequest`](struct.SearchContactsRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchContactsRequestBuilder {
+    filters: Option<Vec<Filter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_criteria: Option<Vec<Sort>>,
+}
+
+impl SearchContactsRequestBuilder {
+    /// Sets the [`filters`](struct.SearchContactsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.SearchContactsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SearchContactsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_criteria`](struct.SearchContactsRequest.html#structfield.sort_criteria) member.
+    pub fn sort_criteria<T: Into<Vec<Sort>>>(mut self, value: T) -> Self {
+        self.sort_criteria = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchContactsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SearchContactsRequest, ::rusoto_core::BuildError> {
+        Ok(SearchContactsRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_criteria: self.sort_criteria,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SearchContactsResponse {
@@ -2331,6 +6996,58 @@ pub struct SearchDevicesRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl SearchDevicesRequest {
+    /// Returns a builder for `SearchDevicesRequest`, checking its required members when built.
+    pub fn builder() -> SearchDevicesRequestBuilder {
+        SearchDevicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchDevicesRequest`](struct.SearchDevicesRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchDevicesRequestBuilder {
+    filters: Option<Vec<Filter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_criteria: Option<Vec<Sort>>,
+}
+
+impl SearchDevicesRequestBuilder {
+    /// Sets the [`filters`](struct.SearchDevicesRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.SearchDevicesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SearchDevicesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_criteria`](struct.SearchDevicesRequest.html#structfield.sort_criteria) member.
+    pub fn sort_criteria<T: Into<Vec<Sort>>>(mut self, value: T) -> Self {
+        self.sort_criteria = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchDevicesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SearchDevicesRequest, ::rusoto_core::BuildError> {
+        Ok(SearchDevicesRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_criteria: self.sort_criteria,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SearchDevicesResponse {
@@ -2368,6 +7085,60 @@ pub struct SearchNetworkProfilesRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl SearchNetworkProfilesRequest {
+    /// Returns a builder for `SearchNetworkProfilesRequest`, checking its required members when built.
+    pub fn builder() -> SearchNetworkProfilesRequestBuilder {
+        SearchNetworkProfilesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchNetworkProfilesRequest`](struct.SearchNetworkProfilesRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchNetworkProfilesRequestBuilder {
+    filters: Option<Vec<Filter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_criteria: Option<Vec<Sort>>,
+}
+
+impl SearchNetworkProfilesRequestBuilder {
+    /// Sets the [`filters`](struct.SearchNetworkProfilesRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.SearchNetworkProfilesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SearchNetworkProfilesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_criteria`](struct.SearchNetworkProfilesRequest.html#structfield.sort_criteria) member.
+    pub fn sort_criteria<T: Into<Vec<Sort>>>(mut self, value: T) -> Self {
+        self.sort_criteria = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchNetworkProfilesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SearchNetworkProfilesRequest, ::rusoto_core::BuildError> {
+        Ok(SearchNetworkProfilesRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_criteria: self.sort_criteria,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SearchNetworkProfilesResponse {
@@ -2405,6 +7176,58 @@ pub struct SearchProfilesRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl SearchProfilesRequest {
+    /// Returns a builder for `SearchProfilesRequest`, checking its required members when built.
+    pub fn builder() -> SearchProfilesRequestBuilder {
+        SearchProfilesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchProfilesRequest`](struct.SearchProfilesRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchProfilesRequestBuilder {
+    filters: Option<Vec<Filter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_criteria: Option<Vec<Sort>>,
+}
+
+impl SearchProfilesRequestBuilder {
+    /// Sets the [`filters`](struct.SearchProfilesRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.SearchProfilesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SearchProfilesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_criteria`](struct.SearchProfilesRequest.html#structfield.sort_criteria) member.
+    pub fn sort_criteria<T: Into<Vec<Sort>>>(mut self, value: T) -> Self {
+        self.sort_criteria = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchProfilesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SearchProfilesRequest, ::rusoto_core::BuildError> {
+        Ok(SearchProfilesRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_criteria: self.sort_criteria,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SearchProfilesResponse {
@@ -2442,6 +7265,58 @@ pub struct SearchRoomsRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl SearchRoomsRequest {
+    /// Returns a builder for `SearchRoomsRequest`, checking its required members when built.
+    pub fn builder() -> SearchRoomsRequestBuilder {
+        SearchRoomsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchRoomsRequest`](struct.SearchRoomsRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchRoomsRequestBuilder {
+    filters: Option<Vec<Filter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_criteria: Option<Vec<Sort>>,
+}
+
+impl SearchRoomsRequestBuilder {
+    /// Sets the [`filters`](struct.SearchRoomsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.SearchRoomsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SearchRoomsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_criteria`](struct.SearchRoomsRequest.html#structfield.sort_criteria) member.
+    pub fn sort_criteria<T: Into<Vec<Sort>>>(mut self, value: T) -> Self {
+        self.sort_criteria = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchRoomsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SearchRoomsRequest, ::rusoto_core::BuildError> {
+        Ok(SearchRoomsRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_criteria: self.sort_criteria,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SearchRoomsResponse {
@@ -2479,6 +7354,60 @@ pub struct SearchSkillGroupsRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl SearchSkillGroupsRequest {
+    /// Returns a builder for `SearchSkillGroupsRequest`, checking its required members when built.
+    pub fn builder() -> SearchSkillGroupsRequestBuilder {
+        SearchSkillGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchSkillGroupsRequest`](struct.SearchSkillGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchSkillGroupsRequestBuilder {
+    filters: Option<Vec<Filter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_criteria: Option<Vec<Sort>>,
+}
+
+impl SearchSkillGroupsRequestBuilder {
+    /// Sets the [`filters`](struct.SearchSkillGroupsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.SearchSkillGroupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SearchSkillGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_criteria`](struct.SearchSkillGroupsRequest.html#structfield.sort_criteria) member.
+    pub fn sort_criteria<T: Into<Vec<Sort>>>(mut self, value: T) -> Self {
+        self.sort_criteria = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchSkillGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SearchSkillGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(SearchSkillGroupsRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_criteria: self.sort_criteria,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SearchSkillGroupsResponse {
@@ -2516,6 +7445,58 @@ pub struct SearchUsersRequest {
     pub sort_criteria: Option<Vec<Sort>>,
 }
 
+impl SearchUsersRequest {
+    /// Returns a builder for `SearchUsersRequest`, checking its required members when built.
+    pub fn builder() -> SearchUsersRequestBuilder {
+        SearchUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchUsersRequest`](struct.SearchUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchUsersRequestBuilder {
+    filters: Option<Vec<Filter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_criteria: Option<Vec<Sort>>,
+}
+
+impl SearchUsersRequestBuilder {
+    /// Sets the [`filters`](struct.SearchUsersRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.SearchUsersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SearchUsersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_criteria`](struct.SearchUsersRequest.html#structfield.sort_criteria) member.
+    pub fn sort_criteria<T: Into<Vec<Sort>>>(mut self, value: T) -> Self {
+        self.sort_criteria = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SearchUsersRequest, ::rusoto_core::BuildError> {
+        Ok(SearchUsersRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_criteria: self.sort_criteria,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SearchUsersResponse {
@@ -2550,6 +7531,66 @@ pub struct SendAnnouncementRequest {
     pub time_to_live_in_seconds: Option<i64>,
 }
 
+impl SendAnnouncementRequest {
+    /// Returns a builder for `SendAnnouncementRequest`, checking its required members when built.
+    pub fn builder() -> SendAnnouncementRequestBuilder {
+        SendAnnouncementRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendAnnouncementRequest`](struct.SendAnnouncementRequest.html).
+#[derive(Default, Debug)]
+pub struct SendAnnouncementRequestBuilder {
+    client_request_token: Option<String>,
+    content: Option<Content>,
+    room_filters: Option<Vec<Filter>>,
+    time_to_live_in_seconds: Option<i64>,
+}
+
+impl SendAnnouncementRequestBuilder {
+    /// Sets the [`client_request_token`](struct.SendAnnouncementRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content`](struct.SendAnnouncementRequest.html#structfield.content) member.
+    pub fn content<T: Into<Content>>(mut self, value: T) -> Self {
+        self.content = Some(value.into());
+        self
+    }
+
+    /// Sets the [`room_filters`](struct.SendAnnouncementRequest.html#structfield.room_filters) member.
+    pub fn room_filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.room_filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_to_live_in_seconds`](struct.SendAnnouncementRequest.html#structfield.time_to_live_in_seconds) member.
+    pub fn time_to_live_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.time_to_live_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendAnnouncementRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SendAnnouncementRequest, ::rusoto_core::BuildError> {
+        Ok(SendAnnouncementRequest {
+            client_request_token: self
+                .client_request_token
+                .ok_or(::rusoto_core::BuildError::missing("client_request_token"))?,
+            content: self
+                .content
+                .ok_or(::rusoto_core::BuildError::missing("content"))?,
+            room_filters: self
+                .room_filters
+                .ok_or(::rusoto_core::BuildError::missing("room_filters"))?,
+            time_to_live_in_seconds: self.time_to_live_in_seconds,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SendAnnouncementResponse {
@@ -2567,45 +7608,141 @@ pub struct SendInvitationRequest {
     pub user_arn: Option<String>,
 }
 
+impl SendInvitationRequest {
+    /// Returns a builder for `SendInvitationRequest`, checking its required members when built.
+    pub fn builder() -> SendInvitationRequestBuilder {
+        SendInvitationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendInvitationRequest`](struct.SendInvitationRequest.html).
+#[derive(Default, Debug)]
+pub struct SendInvitationRequestBuilder {
+    user_arn: Option<String>,
+}
+
+impl SendInvitationRequestBuilder {
+    /// Sets the [`user_arn`](struct.SendInvitationRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendInvitationRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SendInvitationRequest, ::rusoto_core::BuildError> {
+        Ok(SendInvitationRequest {
+            user_arn: self.user_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SendInvitationResponse {}
 
 /// <p>The SIP address for the contact containing the URI and SIP address type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SipAddress {
     /// <p>The type of the SIP address.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: SipType,
     /// <p>The URI for the SIP address.</p>
     #[serde(rename = "Uri")]
     pub uri: String,
 }
 
-/// <p>Granular information about the skill.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct SkillDetails {
-    /// <p>The details about what the skill supports organized as bullet points.</p>
-    #[serde(rename = "BulletPoints")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bullet_points: Option<Vec<String>>,
-    /// <p>The details about the developer that published the skill.</p>
-    #[serde(rename = "DeveloperInfo")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub developer_info: Option<DeveloperInfo>,
-    /// <p>The URL of the end user license agreement.</p>
-    #[serde(rename = "EndUserLicenseAgreement")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_user_license_agreement: Option<String>,
-    /// <p>The generic keywords associated with the skill that can be used to find a skill.</p>
-    #[serde(rename = "GenericKeywords")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub generic_keywords: Option<Vec<String>>,
-    /// <p>The phrase used to trigger the skill.</p>
-    #[serde(rename = "InvocationPhrase")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub invocation_phrase: Option<String>,
+/// <p>The type of the SIP address.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SipType {
+    /// <p><code>WORK</code></p>
+    Work,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SipType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SipType::Work => "WORK",
+            SipType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SipType {
+    fn from(value: &'a str) -> SipType {
+        match value {
+            "WORK" => SipType::Work,
+            _ => SipType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SipType {
+    fn from(value: String) -> SipType {
+        SipType::from(value.as_str())
+    }
+}
+
+impl From<SipType> for String {
+    fn from(value: SipType) -> String {
+        match value {
+            SipType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SipType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SipType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SipType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SipType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SipType::from(value))
+    }
+}
+
+/// <p>Granular information about the skill.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct SkillDetails {
+    /// <p>The details about what the skill supports organized as bullet points.</p>
+    #[serde(rename = "BulletPoints")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bullet_points: Option<Vec<String>>,
+    /// <p>The details about the developer that published the skill.</p>
+    #[serde(rename = "DeveloperInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub developer_info: Option<DeveloperInfo>,
+    /// <p>The URL of the end user license agreement.</p>
+    #[serde(rename = "EndUserLicenseAgreement")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_user_license_agreement: Option<String>,
+    /// <p>The generic keywords associated with the skill that can be used to find a skill.</p>
+    #[serde(rename = "GenericKeywords")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generic_keywords: Option<Vec<String>>,
+    /// <p>The phrase used to trigger the skill.</p>
+    #[serde(rename = "InvocationPhrase")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invocation_phrase: Option<String>,
     /// <p>The updates added in bullet points.</p>
     #[serde(rename = "NewInThisVersionBulletPoints")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2671,7 +7808,7 @@ pub struct SkillSummary {
     /// <p>Whether the skill is enabled under the user's account, or if it requires linking to be used.</p>
     #[serde(rename = "EnablementType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enablement_type: Option<String>,
+    pub enablement_type: Option<EnablementType>,
     /// <p>The ARN of the skill summary.</p>
     #[serde(rename = "SkillId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2683,13 +7820,161 @@ pub struct SkillSummary {
     /// <p>Whether the skill is publicly available or is a private skill.</p>
     #[serde(rename = "SkillType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub skill_type: Option<String>,
+    pub skill_type: Option<SkillType>,
     /// <p>Linking support for a skill.</p>
     #[serde(rename = "SupportsLinking")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub supports_linking: Option<bool>,
 }
 
+/// <p>Whether the skill is publicly available or is a private skill.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SkillType {
+    /// <p><code>PUBLIC</code></p>
+    Public,
+    /// <p><code>PRIVATE</code></p>
+    Private,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SkillType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SkillType::Public => "PUBLIC",
+            SkillType::Private => "PRIVATE",
+            SkillType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SkillType {
+    fn from(value: &'a str) -> SkillType {
+        match value {
+            "PUBLIC" => SkillType::Public,
+            "PRIVATE" => SkillType::Private,
+            _ => SkillType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SkillType {
+    fn from(value: String) -> SkillType {
+        SkillType::from(value.as_str())
+    }
+}
+
+impl From<SkillType> for String {
+    fn from(value: SkillType) -> String {
+        match value {
+            SkillType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SkillType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SkillType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SkillType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SkillType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SkillType::from(value))
+    }
+}
+
+/// <p>Whether the skill is publicly available or is a private skill.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SkillTypeFilter {
+    /// <p><code>PUBLIC</code></p>
+    Public,
+    /// <p><code>PRIVATE</code></p>
+    Private,
+    /// <p><code>ALL</code></p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SkillTypeFilter {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SkillTypeFilter::Public => "PUBLIC",
+            SkillTypeFilter::Private => "PRIVATE",
+            SkillTypeFilter::All => "ALL",
+            SkillTypeFilter::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SkillTypeFilter {
+    fn from(value: &'a str) -> SkillTypeFilter {
+        match value {
+            "PUBLIC" => SkillTypeFilter::Public,
+            "PRIVATE" => SkillTypeFilter::Private,
+            "ALL" => SkillTypeFilter::All,
+            _ => SkillTypeFilter::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SkillTypeFilter {
+    fn from(value: String) -> SkillTypeFilter {
+        SkillTypeFilter::from(value.as_str())
+    }
+}
+
+impl From<SkillTypeFilter> for String {
+    fn from(value: SkillTypeFilter) -> String {
+        match value {
+            SkillTypeFilter::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SkillTypeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SkillTypeFilter {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SkillTypeFilter {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SkillTypeFilter, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SkillTypeFilter::from(value))
+    }
+}
+
 /// <p>The detailed information about an Alexa skill.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2743,22 +8028,94 @@ pub struct SmartHomeAppliance {
 }
 
 /// <p>An object representing a sort criteria. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Sort {
     /// <p>The sort key of a sort object.</p>
     #[serde(rename = "Key")]
     pub key: String,
     /// <p>The sort value of a sort object.</p>
     #[serde(rename = "Value")]
-    pub value: String,
+    pub value: SortValue,
+}
+
+/// <p>The sort value of a sort object.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortValue {
+    /// <p><code>ASC</code></p>
+    Asc,
+    /// <p><code>DESC</code></p>
+    Desc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SortValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortValue::Asc => "ASC",
+            SortValue::Desc => "DESC",
+            SortValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SortValue {
+    fn from(value: &'a str) -> SortValue {
+        match value {
+            "ASC" => SortValue::Asc,
+            "DESC" => SortValue::Desc,
+            _ => SortValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortValue {
+    fn from(value: String) -> SortValue {
+        SortValue::from(value.as_str())
+    }
+}
+
+impl From<SortValue> for String {
+    fn from(value: SortValue) -> String {
+        match value {
+            SortValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SortValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SortValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SortValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SortValue::from(value))
+    }
 }
 
 /// <p>The SSML message. For more information, see <a href="https://developer.amazon.com/docs/custom-skills/speech-synthesis-markup-language-ssml-reference.html">SSML Reference</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Ssml {
     /// <p>The locale of the SSML message. Currently, en-US is supported.</p>
     #[serde(rename = "Locale")]
-    pub locale: String,
+    pub locale: Locale,
     /// <p>The value of the SSML message in the correct SSML format. The audio tag is not supported.</p>
     #[serde(rename = "Value")]
     pub value: String,
@@ -2772,13 +8129,59 @@ pub struct StartDeviceSyncRequest {
     pub device_arn: Option<String>,
     /// <p>Request structure to start the device sync. Required.</p>
     #[serde(rename = "Features")]
-    pub features: Vec<String>,
+    pub features: Vec<Feature>,
     /// <p>The ARN of the room with which the device to sync is associated. Required.</p>
     #[serde(rename = "RoomArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub room_arn: Option<String>,
 }
 
+impl StartDeviceSyncRequest {
+    /// Returns a builder for `StartDeviceSyncRequest`, checking its required members when built.
+    pub fn builder() -> StartDeviceSyncRequestBuilder {
+        StartDeviceSyncRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartDeviceSyncRequest`](struct.StartDeviceSyncRequest.html).
+#[derive(Default, Debug)]
+pub struct StartDeviceSyncRequestBuilder {
+    device_arn: Option<String>,
+    features: Option<Vec<Feature>>,
+    room_arn: Option<String>,
+}
+
+impl StartDeviceSyncRequestBuilder {
+    /// Sets the [`device_arn`](struct.StartDeviceSyncRequest.html#structfield.device_arn) member.
+    pub fn device_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`features`](struct.StartDeviceSyncRequest.html#structfield.features) member.
+    pub fn features<T: Into<Vec<Feature>>>(mut self, value: T) -> Self {
+        self.features = Some(value.into());
+        self
+    }
+
+    /// Sets the [`room_arn`](struct.StartDeviceSyncRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartDeviceSyncRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartDeviceSyncRequest, ::rusoto_core::BuildError> {
+        Ok(StartDeviceSyncRequest {
+            device_arn: self.device_arn,
+            features: self
+                .features
+                .ok_or(::rusoto_core::BuildError::missing("features"))?,
+            room_arn: self.room_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartDeviceSyncResponse {}
@@ -2790,6 +8193,39 @@ pub struct StartSmartHomeApplianceDiscoveryRequest {
     pub room_arn: String,
 }
 
+impl StartSmartHomeApplianceDiscoveryRequest {
+    /// Returns a builder for `StartSmartHomeApplianceDiscoveryRequest`, checking its required members when built.
+    pub fn builder() -> StartSmartHomeApplianceDiscoveryRequestBuilder {
+        StartSmartHomeApplianceDiscoveryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartSmartHomeApplianceDiscoveryRequest`](struct.StartSmartHomeApplianceDiscoveryRequest.html).
+#[derive(Default, Debug)]
+pub struct StartSmartHomeApplianceDiscoveryRequestBuilder {
+    room_arn: Option<String>,
+}
+
+impl StartSmartHomeApplianceDiscoveryRequestBuilder {
+    /// Sets the [`room_arn`](struct.StartSmartHomeApplianceDiscoveryRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartSmartHomeApplianceDiscoveryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartSmartHomeApplianceDiscoveryRequest, ::rusoto_core::BuildError>
+    {
+        Ok(StartSmartHomeApplianceDiscoveryRequest {
+            room_arn: self
+                .room_arn
+                .ok_or(::rusoto_core::BuildError::missing("room_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartSmartHomeApplianceDiscoveryResponse {}
@@ -2815,16 +8251,126 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`arn`](struct.TagResourceRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
 
+/// <p>The temperature unit to be used by devices in the profile.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TemperatureUnit {
+    /// <p><code>FAHRENHEIT</code></p>
+    Fahrenheit,
+    /// <p><code>CELSIUS</code></p>
+    Celsius,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TemperatureUnit {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TemperatureUnit::Fahrenheit => "FAHRENHEIT",
+            TemperatureUnit::Celsius => "CELSIUS",
+            TemperatureUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TemperatureUnit {
+    fn from(value: &'a str) -> TemperatureUnit {
+        match value {
+            "FAHRENHEIT" => TemperatureUnit::Fahrenheit,
+            "CELSIUS" => TemperatureUnit::Celsius,
+            _ => TemperatureUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TemperatureUnit {
+    fn from(value: String) -> TemperatureUnit {
+        TemperatureUnit::from(value.as_str())
+    }
+}
+
+impl From<TemperatureUnit> for String {
+    fn from(value: TemperatureUnit) -> String {
+        match value {
+            TemperatureUnit::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TemperatureUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TemperatureUnit {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TemperatureUnit {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TemperatureUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TemperatureUnit::from(value))
+    }
+}
+
 /// <p>The text message.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Text {
     /// <p>The locale of the text message. Currently, en-US is supported.</p>
     #[serde(rename = "Locale")]
-    pub locale: String,
+    pub locale: Locale,
     /// <p>The value of the text message.</p>
     #[serde(rename = "Value")]
     pub value: String,
@@ -2840,6 +8386,44 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`arn`](struct.UntagResourceRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -2859,6 +8443,54 @@ pub struct UpdateAddressBookRequest {
     pub name: Option<String>,
 }
 
+impl UpdateAddressBookRequest {
+    /// Returns a builder for `UpdateAddressBookRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAddressBookRequestBuilder {
+        UpdateAddressBookRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAddressBookRequest`](struct.UpdateAddressBookRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAddressBookRequestBuilder {
+    address_book_arn: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateAddressBookRequestBuilder {
+    /// Sets the [`address_book_arn`](struct.UpdateAddressBookRequest.html#structfield.address_book_arn) member.
+    pub fn address_book_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.address_book_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateAddressBookRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateAddressBookRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAddressBookRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAddressBookRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAddressBookRequest {
+            address_book_arn: self
+                .address_book_arn
+                .ok_or(::rusoto_core::BuildError::missing("address_book_arn"))?,
+            description: self.description,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateAddressBookResponse {}
@@ -2868,7 +8500,7 @@ pub struct UpdateBusinessReportScheduleRequest {
     /// <p>The format of the generated report (individual CSV files or zipped files of individual files).</p>
     #[serde(rename = "Format")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub format: Option<String>,
+    pub format: Option<BusinessReportFormat>,
     /// <p>The recurrence of the reports.</p>
     #[serde(rename = "Recurrence")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2890,18 +8522,90 @@ pub struct UpdateBusinessReportScheduleRequest {
     pub schedule_name: Option<String>,
 }
 
+impl UpdateBusinessReportScheduleRequest {
+    /// Returns a builder for `UpdateBusinessReportScheduleRequest`, checking its required members when built.
+    pub fn builder() -> UpdateBusinessReportScheduleRequestBuilder {
+        UpdateBusinessReportScheduleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateBusinessReportScheduleRequest`](struct.UpdateBusinessReportScheduleRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateBusinessReportScheduleRequestBuilder {
+    format: Option<BusinessReportFormat>,
+    recurrence: Option<BusinessReportRecurrence>,
+    s3_bucket_name: Option<String>,
+    s3_key_prefix: Option<String>,
+    schedule_arn: Option<String>,
+    schedule_name: Option<String>,
+}
+
+impl UpdateBusinessReportScheduleRequestBuilder {
+    /// Sets the [`format`](struct.UpdateBusinessReportScheduleRequest.html#structfield.format) member.
+    pub fn format<T: Into<BusinessReportFormat>>(mut self, value: T) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recurrence`](struct.UpdateBusinessReportScheduleRequest.html#structfield.recurrence) member.
+    pub fn recurrence<T: Into<BusinessReportRecurrence>>(mut self, value: T) -> Self {
+        self.recurrence = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_bucket_name`](struct.UpdateBusinessReportScheduleRequest.html#structfield.s3_bucket_name) member.
+    pub fn s3_bucket_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_bucket_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_key_prefix`](struct.UpdateBusinessReportScheduleRequest.html#structfield.s3_key_prefix) member.
+    pub fn s3_key_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_key_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule_arn`](struct.UpdateBusinessReportScheduleRequest.html#structfield.schedule_arn) member.
+    pub fn schedule_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule_name`](struct.UpdateBusinessReportScheduleRequest.html#structfield.schedule_name) member.
+    pub fn schedule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateBusinessReportScheduleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateBusinessReportScheduleRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateBusinessReportScheduleRequest {
+            format: self.format,
+            recurrence: self.recurrence,
+            s3_bucket_name: self.s3_bucket_name,
+            s3_key_prefix: self.s3_key_prefix,
+            schedule_arn: self
+                .schedule_arn
+                .ok_or(::rusoto_core::BuildError::missing("schedule_arn"))?,
+            schedule_name: self.schedule_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateBusinessReportScheduleResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateConferenceProviderRequest {
     /// <p>The ARN of the conference provider.</p>
     #[serde(rename = "ConferenceProviderArn")]
     pub conference_provider_arn: String,
     /// <p>The type of the conference provider.</p>
     #[serde(rename = "ConferenceProviderType")]
-    pub conference_provider_type: String,
+    pub conference_provider_type: ConferenceProviderType,
     /// <p>The IP endpoint and protocol for calling.</p>
     #[serde(rename = "IPDialIn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2915,6 +8619,74 @@ pub struct UpdateConferenceProviderRequest {
     pub pstn_dial_in: Option<PSTNDialIn>,
 }
 
+impl UpdateConferenceProviderRequest {
+    /// Returns a builder for `UpdateConferenceProviderRequest`, checking its required members when built.
+    pub fn builder() -> UpdateConferenceProviderRequestBuilder {
+        UpdateConferenceProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateConferenceProviderRequest`](struct.UpdateConferenceProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateConferenceProviderRequestBuilder {
+    conference_provider_arn: Option<String>,
+    conference_provider_type: Option<ConferenceProviderType>,
+    ip_dial_in: Option<IPDialIn>,
+    meeting_setting: Option<MeetingSetting>,
+    pstn_dial_in: Option<PSTNDialIn>,
+}
+
+impl UpdateConferenceProviderRequestBuilder {
+    /// Sets the [`conference_provider_arn`](struct.UpdateConferenceProviderRequest.html#structfield.conference_provider_arn) member.
+    pub fn conference_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.conference_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conference_provider_type`](struct.UpdateConferenceProviderRequest.html#structfield.conference_provider_type) member.
+    pub fn conference_provider_type<T: Into<ConferenceProviderType>>(mut self, value: T) -> Self {
+        self.conference_provider_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ip_dial_in`](struct.UpdateConferenceProviderRequest.html#structfield.ip_dial_in) member.
+    pub fn ip_dial_in<T: Into<IPDialIn>>(mut self, value: T) -> Self {
+        self.ip_dial_in = Some(value.into());
+        self
+    }
+
+    /// Sets the [`meeting_setting`](struct.UpdateConferenceProviderRequest.html#structfield.meeting_setting) member.
+    pub fn meeting_setting<T: Into<MeetingSetting>>(mut self, value: T) -> Self {
+        self.meeting_setting = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pstn_dial_in`](struct.UpdateConferenceProviderRequest.html#structfield.pstn_dial_in) member.
+    pub fn pstn_dial_in<T: Into<PSTNDialIn>>(mut self, value: T) -> Self {
+        self.pstn_dial_in = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateConferenceProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateConferenceProviderRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateConferenceProviderRequest {
+            conference_provider_arn: self.conference_provider_arn.ok_or(
+                ::rusoto_core::BuildError::missing("conference_provider_arn"),
+            )?,
+            conference_provider_type: self.conference_provider_type.ok_or(
+                ::rusoto_core::BuildError::missing("conference_provider_type"),
+            )?,
+            ip_dial_in: self.ip_dial_in,
+            meeting_setting: self
+                .meeting_setting
+                .ok_or(::rusoto_core::BuildError::missing("meeting_setting"))?,
+            pstn_dial_in: self.pstn_dial_in,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateConferenceProviderResponse {}
@@ -2950,6 +8722,84 @@ pub struct UpdateContactRequest {
     pub sip_addresses: Option<Vec<SipAddress>>,
 }
 
+impl UpdateContactRequest {
+    /// Returns a builder for `UpdateContactRequest`, checking its required members when built.
+    pub fn builder() -> UpdateContactRequestBuilder {
+        UpdateContactRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateContactRequest`](struct.UpdateContactRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateContactRequestBuilder {
+    contact_arn: Option<String>,
+    display_name: Option<String>,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    phone_number: Option<String>,
+    phone_numbers: Option<Vec<PhoneNumber>>,
+    sip_addresses: Option<Vec<SipAddress>>,
+}
+
+impl UpdateContactRequestBuilder {
+    /// Sets the [`contact_arn`](struct.UpdateContactRequest.html#structfield.contact_arn) member.
+    pub fn contact_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.contact_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.UpdateContactRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`first_name`](struct.UpdateContactRequest.html#structfield.first_name) member.
+    pub fn first_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.first_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`last_name`](struct.UpdateContactRequest.html#structfield.last_name) member.
+    pub fn last_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.last_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`phone_number`](struct.UpdateContactRequest.html#structfield.phone_number) member.
+    pub fn phone_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.phone_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`phone_numbers`](struct.UpdateContactRequest.html#structfield.phone_numbers) member.
+    pub fn phone_numbers<T: Into<Vec<PhoneNumber>>>(mut self, value: T) -> Self {
+        self.phone_numbers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sip_addresses`](struct.UpdateContactRequest.html#structfield.sip_addresses) member.
+    pub fn sip_addresses<T: Into<Vec<SipAddress>>>(mut self, value: T) -> Self {
+        self.sip_addresses = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateContactRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateContactRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateContactRequest {
+            contact_arn: self
+                .contact_arn
+                .ok_or(::rusoto_core::BuildError::missing("contact_arn"))?,
+            display_name: self.display_name,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            phone_number: self.phone_number,
+            phone_numbers: self.phone_numbers,
+            sip_addresses: self.sip_addresses,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateContactResponse {}
@@ -2966,6 +8816,42 @@ pub struct UpdateDeviceRequest {
     pub device_name: Option<String>,
 }
 
+impl UpdateDeviceRequest {
+    /// Returns a builder for `UpdateDeviceRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDeviceRequestBuilder {
+        UpdateDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDeviceRequest`](struct.UpdateDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDeviceRequestBuilder {
+    device_arn: Option<String>,
+    device_name: Option<String>,
+}
+
+impl UpdateDeviceRequestBuilder {
+    /// Sets the [`device_arn`](struct.UpdateDeviceRequest.html#structfield.device_arn) member.
+    pub fn device_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_name`](struct.UpdateDeviceRequest.html#structfield.device_name) member.
+    pub fn device_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDeviceRequest {
+            device_arn: self.device_arn,
+            device_name: self.device_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDeviceResponse {}
@@ -2985,6 +8871,54 @@ pub struct UpdateGatewayGroupRequest {
     pub name: Option<String>,
 }
 
+impl UpdateGatewayGroupRequest {
+    /// Returns a builder for `UpdateGatewayGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateGatewayGroupRequestBuilder {
+        UpdateGatewayGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGatewayGroupRequest`](struct.UpdateGatewayGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateGatewayGroupRequestBuilder {
+    description: Option<String>,
+    gateway_group_arn: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateGatewayGroupRequestBuilder {
+    /// Sets the [`description`](struct.UpdateGatewayGroupRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_group_arn`](struct.UpdateGatewayGroupRequest.html#structfield.gateway_group_arn) member.
+    pub fn gateway_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateGatewayGroupRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGatewayGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateGatewayGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateGatewayGroupRequest {
+            description: self.description,
+            gateway_group_arn: self
+                .gateway_group_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_group_arn"))?,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateGatewayGroupResponse {}
@@ -3008,6 +8942,60 @@ pub struct UpdateGatewayRequest {
     pub software_version: Option<String>,
 }
 
+impl UpdateGatewayRequest {
+    /// Returns a builder for `UpdateGatewayRequest`, checking its required members when built.
+    pub fn builder() -> UpdateGatewayRequestBuilder {
+        UpdateGatewayRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGatewayRequest`](struct.UpdateGatewayRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateGatewayRequestBuilder {
+    description: Option<String>,
+    gateway_arn: Option<String>,
+    name: Option<String>,
+    software_version: Option<String>,
+}
+
+impl UpdateGatewayRequestBuilder {
+    /// Sets the [`description`](struct.UpdateGatewayRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_arn`](struct.UpdateGatewayRequest.html#structfield.gateway_arn) member.
+    pub fn gateway_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateGatewayRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`software_version`](struct.UpdateGatewayRequest.html#structfield.software_version) member.
+    pub fn software_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.software_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGatewayRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateGatewayRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateGatewayRequest {
+            description: self.description,
+            gateway_arn: self
+                .gateway_arn
+                .ok_or(::rusoto_core::BuildError::missing("gateway_arn"))?,
+            name: self.name,
+            software_version: self.software_version,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateGatewayResponse {}
@@ -3043,6 +9031,86 @@ pub struct UpdateNetworkProfileRequest {
     pub trust_anchors: Option<Vec<String>>,
 }
 
+impl UpdateNetworkProfileRequest {
+    /// Returns a builder for `UpdateNetworkProfileRequest`, checking its required members when built.
+    pub fn builder() -> UpdateNetworkProfileRequestBuilder {
+        UpdateNetworkProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateNetworkProfileRequest`](struct.UpdateNetworkProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateNetworkProfileRequestBuilder {
+    certificate_authority_arn: Option<String>,
+    current_password: Option<String>,
+    description: Option<String>,
+    network_profile_arn: Option<String>,
+    network_profile_name: Option<String>,
+    next_password: Option<String>,
+    trust_anchors: Option<Vec<String>>,
+}
+
+impl UpdateNetworkProfileRequestBuilder {
+    /// Sets the [`certificate_authority_arn`](struct.UpdateNetworkProfileRequest.html#structfield.certificate_authority_arn) member.
+    pub fn certificate_authority_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_password`](struct.UpdateNetworkProfileRequest.html#structfield.current_password) member.
+    pub fn current_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.current_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateNetworkProfileRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_profile_arn`](struct.UpdateNetworkProfileRequest.html#structfield.network_profile_arn) member.
+    pub fn network_profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.network_profile_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_profile_name`](struct.UpdateNetworkProfileRequest.html#structfield.network_profile_name) member.
+    pub fn network_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.network_profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_password`](struct.UpdateNetworkProfileRequest.html#structfield.next_password) member.
+    pub fn next_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trust_anchors`](struct.UpdateNetworkProfileRequest.html#structfield.trust_anchors) member.
+    pub fn trust_anchors<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.trust_anchors = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateNetworkProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateNetworkProfileRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateNetworkProfileRequest {
+            certificate_authority_arn: self.certificate_authority_arn,
+            current_password: self.current_password,
+            description: self.description,
+            network_profile_arn: self
+                .network_profile_arn
+                .ok_or(::rusoto_core::BuildError::missing("network_profile_arn"))?,
+            network_profile_name: self.network_profile_name,
+            next_password: self.next_password,
+            trust_anchors: self.trust_anchors,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateNetworkProfileResponse {}
@@ -3056,7 +9124,7 @@ pub struct UpdateProfileRequest {
     /// <p>The updated distance unit for the room profile.</p>
     #[serde(rename = "DistanceUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub distance_unit: Option<String>,
+    pub distance_unit: Option<DistanceUnit>,
     /// <p>Sets the profile as default if selected. If this is missing, no update is done to the default status.</p>
     #[serde(rename = "IsDefault")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3088,7 +9156,7 @@ pub struct UpdateProfileRequest {
     /// <p>The updated temperature unit for the room profile.</p>
     #[serde(rename = "TemperatureUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub temperature_unit: Option<String>,
+    pub temperature_unit: Option<TemperatureUnit>,
     /// <p>The updated timezone for the room profile.</p>
     #[serde(rename = "Timezone")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3096,7 +9164,123 @@ pub struct UpdateProfileRequest {
     /// <p>The updated wake word for the room profile.</p>
     #[serde(rename = "WakeWord")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wake_word: Option<String>,
+    pub wake_word: Option<WakeWord>,
+}
+
+impl UpdateProfileRequest {
+    /// Returns a builder for `UpdateProfileRequest`, checking its required members when built.
+    pub fn builder() -> UpdateProfileRequestBuilder {
+        UpdateProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateProfileRequest`](struct.UpdateProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateProfileRequestBuilder {
+    address: Option<String>,
+    distance_unit: Option<DistanceUnit>,
+    is_default: Option<bool>,
+    locale: Option<String>,
+    max_volume_limit: Option<i64>,
+    pstn_enabled: Option<bool>,
+    profile_arn: Option<String>,
+    profile_name: Option<String>,
+    setup_mode_disabled: Option<bool>,
+    temperature_unit: Option<TemperatureUnit>,
+    timezone: Option<String>,
+    wake_word: Option<WakeWord>,
+}
+
+impl UpdateProfileRequestBuilder {
+    /// Sets the [`address`](struct.UpdateProfileRequest.html#structfield.address) member.
+    pub fn address<T: Into<String>>(mut self, value: T) -> Self {
+        self.address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`distance_unit`](struct.UpdateProfileRequest.html#structfield.distance_unit) member.
+    pub fn distance_unit<T: Into<DistanceUnit>>(mut self, value: T) -> Self {
+        self.distance_unit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`is_default`](struct.UpdateProfileRequest.html#structfield.is_default) member.
+    pub fn is_default<T: Into<bool>>(mut self, value: T) -> Self {
+        self.is_default = Some(value.into());
+        self
+    }
+
+    /// Sets the [`locale`](struct.UpdateProfileRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<String>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_volume_limit`](struct.UpdateProfileRequest.html#structfield.max_volume_limit) member.
+    pub fn max_volume_limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_volume_limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pstn_enabled`](struct.UpdateProfileRequest.html#structfield.pstn_enabled) member.
+    pub fn pstn_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.pstn_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`profile_arn`](struct.UpdateProfileRequest.html#structfield.profile_arn) member.
+    pub fn profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.profile_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`profile_name`](struct.UpdateProfileRequest.html#structfield.profile_name) member.
+    pub fn profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`setup_mode_disabled`](struct.UpdateProfileRequest.html#structfield.setup_mode_disabled) member.
+    pub fn setup_mode_disabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.setup_mode_disabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`temperature_unit`](struct.UpdateProfileRequest.html#structfield.temperature_unit) member.
+    pub fn temperature_unit<T: Into<TemperatureUnit>>(mut self, value: T) -> Self {
+        self.temperature_unit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timezone`](struct.UpdateProfileRequest.html#structfield.timezone) member.
+    pub fn timezone<T: Into<String>>(mut self, value: T) -> Self {
+        self.timezone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`wake_word`](struct.UpdateProfileRequest.html#structfield.wake_word) member.
+    pub fn wake_word<T: Into<WakeWord>>(mut self, value: T) -> Self {
+        self.wake_word = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateProfileRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateProfileRequest {
+            address: self.address,
+            distance_unit: self.distance_unit,
+            is_default: self.is_default,
+            locale: self.locale,
+            max_volume_limit: self.max_volume_limit,
+            pstn_enabled: self.pstn_enabled,
+            profile_arn: self.profile_arn,
+            profile_name: self.profile_name,
+            setup_mode_disabled: self.setup_mode_disabled,
+            temperature_unit: self.temperature_unit,
+            timezone: self.timezone,
+            wake_word: self.wake_word,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -3127,6 +9311,66 @@ pub struct UpdateRoomRequest {
     pub room_name: Option<String>,
 }
 
+impl UpdateRoomRequest {
+    /// Returns a builder for `UpdateRoomRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRoomRequestBuilder {
+        UpdateRoomRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRoomRequest`](struct.UpdateRoomRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRoomRequestBuilder {
+    description: Option<String>,
+    profile_arn: Option<String>,
+    provider_calendar_id: Option<String>,
+    room_arn: Option<String>,
+    room_name: Option<String>,
+}
+
+impl UpdateRoomRequestBuilder {
+    /// Sets the [`description`](struct.UpdateRoomRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`profile_arn`](struct.UpdateRoomRequest.html#structfield.profile_arn) member.
+    pub fn profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.profile_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_calendar_id`](struct.UpdateRoomRequest.html#structfield.provider_calendar_id) member.
+    pub fn provider_calendar_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider_calendar_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`room_arn`](struct.UpdateRoomRequest.html#structfield.room_arn) member.
+    pub fn room_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`room_name`](struct.UpdateRoomRequest.html#structfield.room_name) member.
+    pub fn room_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.room_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRoomRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateRoomRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRoomRequest {
+            description: self.description,
+            profile_arn: self.profile_arn,
+            provider_calendar_id: self.provider_calendar_id,
+            room_arn: self.room_arn,
+            room_name: self.room_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRoomResponse {}
@@ -3147,6 +9391,52 @@ pub struct UpdateSkillGroupRequest {
     pub skill_group_name: Option<String>,
 }
 
+impl UpdateSkillGroupRequest {
+    /// Returns a builder for `UpdateSkillGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSkillGroupRequestBuilder {
+        UpdateSkillGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSkillGroupRequest`](struct.UpdateSkillGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSkillGroupRequestBuilder {
+    description: Option<String>,
+    skill_group_arn: Option<String>,
+    skill_group_name: Option<String>,
+}
+
+impl UpdateSkillGroupRequestBuilder {
+    /// Sets the [`description`](struct.UpdateSkillGroupRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_group_arn`](struct.UpdateSkillGroupRequest.html#structfield.skill_group_arn) member.
+    pub fn skill_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skill_group_name`](struct.UpdateSkillGroupRequest.html#structfield.skill_group_name) member.
+    pub fn skill_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.skill_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSkillGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSkillGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSkillGroupRequest {
+            description: self.description,
+            skill_group_arn: self.skill_group_arn,
+            skill_group_name: self.skill_group_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateSkillGroupResponse {}
@@ -3166,7 +9456,7 @@ pub struct UserData {
     /// <p>The enrollment status of a user.</p>
     #[serde(rename = "EnrollmentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enrollment_status: Option<String>,
+    pub enrollment_status: Option<EnrollmentStatus>,
     /// <p>The first name of a user.</p>
     #[serde(rename = "FirstName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3181,6 +9471,86 @@ pub struct UserData {
     pub user_arn: Option<String>,
 }
 
+/// <p>A wake word for Alexa, Echo, Amazon, or a computer.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WakeWord {
+    /// <p><code>ALEXA</code></p>
+    Alexa,
+    /// <p><code>AMAZON</code></p>
+    Amazon,
+    /// <p><code>ECHO</code></p>
+    Echo,
+    /// <p><code>COMPUTER</code></p>
+    Computer,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WakeWord {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WakeWord::Alexa => "ALEXA",
+            WakeWord::Amazon => "AMAZON",
+            WakeWord::Echo => "ECHO",
+            WakeWord::Computer => "COMPUTER",
+            WakeWord::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WakeWord {
+    fn from(value: &'a str) -> WakeWord {
+        match value {
+            "ALEXA" => WakeWord::Alexa,
+            "AMAZON" => WakeWord::Amazon,
+            "ECHO" => WakeWord::Echo,
+            "COMPUTER" => WakeWord::Computer,
+            _ => WakeWord::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WakeWord {
+    fn from(value: String) -> WakeWord {
+        WakeWord::from(value.as_str())
+    }
+}
+
+impl From<WakeWord> for String {
+    fn from(value: WakeWord) -> String {
+        match value {
+            WakeWord::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WakeWord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WakeWord {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WakeWord {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WakeWord, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WakeWord::from(value))
+    }
+}
+
 /// Errors returned by ApproveSkill
 #[derive(Debug, PartialEq)]
 pub enum ApproveSkillError {
@@ -6666,564 +13036,1134 @@ impl Error for UpdateSkillGroupError {
         }
     }
 }
-/// Trait representing the capabilities of the Alexa For Business API. Alexa For Business clients implement this trait.
-pub trait AlexaForBusiness {
-    /// <p>Associates a skill with the organization under the customer's AWS account. If a skill is private, the user implicitly accepts access to this skill during enablement.</p>
+/// Trait representing the capabilities of the Alexa For Business API. Alexa For Business clients implement this trait.
+pub trait AlexaForBusiness {
+    /// <p>Associates a skill with the organization under the customer's AWS account. If a skill is private, the user implicitly accepts access to this skill during enablement.</p>
+    fn approve_skill(
+        &self,
+        input: ApproveSkillRequest,
+    ) -> RusotoFuture<ApproveSkillResponse, ApproveSkillError>;
+
+    /// <p>Associates a contact with a given address book.</p>
+    fn associate_contact_with_address_book(
+        &self,
+        input: AssociateContactWithAddressBookRequest,
+    ) -> RusotoFuture<AssociateContactWithAddressBookResponse, AssociateContactWithAddressBookError>;
+
+    /// <p>Associates a device with the specified network profile.</p>
+    fn associate_device_with_network_profile(
+        &self,
+        input: AssociateDeviceWithNetworkProfileRequest,
+    ) -> RusotoFuture<
+        AssociateDeviceWithNetworkProfileResponse,
+        AssociateDeviceWithNetworkProfileError,
+    >;
+
+    /// <p>Associates a device with a given room. This applies all the settings from the room profile to the device, and all the skills in any skill groups added to that room. This operation requires the device to be online, or else a manual sync is required. </p>
+    fn associate_device_with_room(
+        &self,
+        input: AssociateDeviceWithRoomRequest,
+    ) -> RusotoFuture<AssociateDeviceWithRoomResponse, AssociateDeviceWithRoomError>;
+
+    /// <p>Associates a skill group with a given room. This enables all skills in the associated skill group on all devices in the room.</p>
+    fn associate_skill_group_with_room(
+        &self,
+        input: AssociateSkillGroupWithRoomRequest,
+    ) -> RusotoFuture<AssociateSkillGroupWithRoomResponse, AssociateSkillGroupWithRoomError>;
+
+    /// <p>Associates a skill with a skill group.</p>
+    fn associate_skill_with_skill_group(
+        &self,
+        input: AssociateSkillWithSkillGroupRequest,
+    ) -> RusotoFuture<AssociateSkillWithSkillGroupResponse, AssociateSkillWithSkillGroupError>;
+
+    /// <p>Makes a private skill available for enrolled users to enable on their devices.</p>
+    fn associate_skill_with_users(
+        &self,
+        input: AssociateSkillWithUsersRequest,
+    ) -> RusotoFuture<AssociateSkillWithUsersResponse, AssociateSkillWithUsersError>;
+
+    /// <p>Creates an address book with the specified details.</p>
+    fn create_address_book(
+        &self,
+        input: CreateAddressBookRequest,
+    ) -> RusotoFuture<CreateAddressBookResponse, CreateAddressBookError>;
+
+    /// <p>Creates a recurring schedule for usage reports to deliver to the specified S3 location with a specified daily or weekly interval.</p>
+    fn create_business_report_schedule(
+        &self,
+        input: CreateBusinessReportScheduleRequest,
+    ) -> RusotoFuture<CreateBusinessReportScheduleResponse, CreateBusinessReportScheduleError>;
+
+    /// <p>Adds a new conference provider under the user's AWS account.</p>
+    fn create_conference_provider(
+        &self,
+        input: CreateConferenceProviderRequest,
+    ) -> RusotoFuture<CreateConferenceProviderResponse, CreateConferenceProviderError>;
+
+    /// <p>Creates a contact with the specified details.</p>
+    fn create_contact(
+        &self,
+        input: CreateContactRequest,
+    ) -> RusotoFuture<CreateContactResponse, CreateContactError>;
+
+    /// <p>Creates a gateway group with the specified details.</p>
+    fn create_gateway_group(
+        &self,
+        input: CreateGatewayGroupRequest,
+    ) -> RusotoFuture<CreateGatewayGroupResponse, CreateGatewayGroupError>;
+
+    /// <p>Creates a network profile with the specified details.</p>
+    fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> RusotoFuture<CreateNetworkProfileResponse, CreateNetworkProfileError>;
+
+    /// <p>Creates a new room profile with the specified details.</p>
+    fn create_profile(
+        &self,
+        input: CreateProfileRequest,
+    ) -> RusotoFuture<CreateProfileResponse, CreateProfileError>;
+
+    /// <p>Creates a room with the specified details.</p>
+    fn create_room(
+        &self,
+        input: CreateRoomRequest,
+    ) -> RusotoFuture<CreateRoomResponse, CreateRoomError>;
+
+    /// <p>Creates a skill group with a specified name and description.</p>
+    fn create_skill_group(
+        &self,
+        input: CreateSkillGroupRequest,
+    ) -> RusotoFuture<CreateSkillGroupResponse, CreateSkillGroupError>;
+
+    /// <p>Creates a user.</p>
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError>;
+
+    /// <p>Deletes an address book by the address book ARN.</p>
+    fn delete_address_book(
+        &self,
+        input: DeleteAddressBookRequest,
+    ) -> RusotoFuture<DeleteAddressBookResponse, DeleteAddressBookError>;
+
+    /// <p>Deletes the recurring report delivery schedule with the specified schedule ARN.</p>
+    fn delete_business_report_schedule(
+        &self,
+        input: DeleteBusinessReportScheduleRequest,
+    ) -> RusotoFuture<DeleteBusinessReportScheduleResponse, DeleteBusinessReportScheduleError>;
+
+    /// <p>Deletes a conference provider.</p>
+    fn delete_conference_provider(
+        &self,
+        input: DeleteConferenceProviderRequest,
+    ) -> RusotoFuture<DeleteConferenceProviderResponse, DeleteConferenceProviderError>;
+
+    /// <p>Deletes a contact by the contact ARN.</p>
+    fn delete_contact(
+        &self,
+        input: DeleteContactRequest,
+    ) -> RusotoFuture<DeleteContactResponse, DeleteContactError>;
+
+    /// <p>Removes a device from Alexa For Business.</p>
+    fn delete_device(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> RusotoFuture<DeleteDeviceResponse, DeleteDeviceError>;
+
+    /// <p>When this action is called for a specified shared device, it allows authorized users to delete the device's entire previous history of voice input data and associated response data. This action can be called once every 24 hours for a specific shared device.</p>
+    fn delete_device_usage_data(
+        &self,
+        input: DeleteDeviceUsageDataRequest,
+    ) -> RusotoFuture<DeleteDeviceUsageDataResponse, DeleteDeviceUsageDataError>;
+
+    /// <p>Deletes a gateway group.</p>
+    fn delete_gateway_group(
+        &self,
+        input: DeleteGatewayGroupRequest,
+    ) -> RusotoFuture<DeleteGatewayGroupResponse, DeleteGatewayGroupError>;
+
+    /// <p>Deletes a network profile by the network profile ARN.</p>
+    fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> RusotoFuture<DeleteNetworkProfileResponse, DeleteNetworkProfileError>;
+
+    /// <p>Deletes a room profile by the profile ARN.</p>
+    fn delete_profile(
+        &self,
+        input: DeleteProfileRequest,
+    ) -> RusotoFuture<DeleteProfileResponse, DeleteProfileError>;
+
+    /// <p>Deletes a room by the room ARN.</p>
+    fn delete_room(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> RusotoFuture<DeleteRoomResponse, DeleteRoomError>;
+
+    /// <p>Deletes room skill parameter details by room, skill, and parameter key ID.</p>
+    fn delete_room_skill_parameter(
+        &self,
+        input: DeleteRoomSkillParameterRequest,
+    ) -> RusotoFuture<DeleteRoomSkillParameterResponse, DeleteRoomSkillParameterError>;
+
+    /// <p>Unlinks a third-party account from a skill.</p>
+    fn delete_skill_authorization(
+        &self,
+        input: DeleteSkillAuthorizationRequest,
+    ) -> RusotoFuture<DeleteSkillAuthorizationResponse, DeleteSkillAuthorizationError>;
+
+    /// <p>Deletes a skill group by skill group ARN.</p>
+    fn delete_skill_group(
+        &self,
+        input: DeleteSkillGroupRequest,
+    ) -> RusotoFuture<DeleteSkillGroupResponse, DeleteSkillGroupError>;
+
+    /// <p>Deletes a specified user by user ARN and enrollment ARN.</p>
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError>;
+
+    /// <p>Disassociates a contact from a given address book.</p>
+    fn disassociate_contact_from_address_book(
+        &self,
+        input: DisassociateContactFromAddressBookRequest,
+    ) -> RusotoFuture<
+        DisassociateContactFromAddressBookResponse,
+        DisassociateContactFromAddressBookError,
+    >;
+
+    /// <p>Disassociates a device from its current room. The device continues to be connected to the Wi-Fi network and is still registered to the account. The device settings and skills are removed from the room.</p>
+    fn disassociate_device_from_room(
+        &self,
+        input: DisassociateDeviceFromRoomRequest,
+    ) -> RusotoFuture<DisassociateDeviceFromRoomResponse, DisassociateDeviceFromRoomError>;
+
+    /// <p>Disassociates a skill from a skill group.</p>
+    fn disassociate_skill_from_skill_group(
+        &self,
+        input: DisassociateSkillFromSkillGroupRequest,
+    ) -> RusotoFuture<DisassociateSkillFromSkillGroupResponse, DisassociateSkillFromSkillGroupError>;
+
+    /// <p>Makes a private skill unavailable for enrolled users and prevents them from enabling it on their devices.</p>
+    fn disassociate_skill_from_users(
+        &self,
+        input: DisassociateSkillFromUsersRequest,
+    ) -> RusotoFuture<DisassociateSkillFromUsersResponse, DisassociateSkillFromUsersError>;
+
+    /// <p>Disassociates a skill group from a specified room. This disables all skills in the skill group on all devices in the room.</p>
+    fn disassociate_skill_group_from_room(
+        &self,
+        input: DisassociateSkillGroupFromRoomRequest,
+    ) -> RusotoFuture<DisassociateSkillGroupFromRoomResponse, DisassociateSkillGroupFromRoomError>;
+
+    /// <p>Forgets smart home appliances associated to a room.</p>
+    fn forget_smart_home_appliances(
+        &self,
+        input: ForgetSmartHomeAppliancesRequest,
+    ) -> RusotoFuture<ForgetSmartHomeAppliancesResponse, ForgetSmartHomeAppliancesError>;
+
+    /// <p>Gets address the book details by the address book ARN.</p>
+    fn get_address_book(
+        &self,
+        input: GetAddressBookRequest,
+    ) -> RusotoFuture<GetAddressBookResponse, GetAddressBookError>;
+
+    /// <p>Retrieves the existing conference preferences.</p>
+    fn get_conference_preference(
+        &self,
+    ) -> RusotoFuture<GetConferencePreferenceResponse, GetConferencePreferenceError>;
+
+    /// <p>Gets details about a specific conference provider.</p>
+    fn get_conference_provider(
+        &self,
+        input: GetConferenceProviderRequest,
+    ) -> RusotoFuture<GetConferenceProviderResponse, GetConferenceProviderError>;
+
+    /// <p>Gets the contact details by the contact ARN.</p>
+    fn get_contact(
+        &self,
+        input: GetContactRequest,
+    ) -> RusotoFuture<GetContactResponse, GetContactError>;
+
+    /// <p>Gets the details of a device by device ARN.</p>
+    fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError>;
+
+    /// <p>Retrieves the details of a gateway.</p>
+    fn get_gateway(
+        &self,
+        input: GetGatewayRequest,
+    ) -> RusotoFuture<GetGatewayResponse, GetGatewayError>;
+
+    /// <p>Retrieves the details of a gateway group.</p>
+    fn get_gateway_group(
+        &self,
+        input: GetGatewayGroupRequest,
+    ) -> RusotoFuture<GetGatewayGroupResponse, GetGatewayGroupError>;
+
+    /// <p>Retrieves the configured values for the user enrollment invitation email template.</p>
+    fn get_invitation_configuration(
+        &self,
+    ) -> RusotoFuture<GetInvitationConfigurationResponse, GetInvitationConfigurationError>;
+
+    /// <p>Gets the network profile details by the network profile ARN.</p>
+    fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> RusotoFuture<GetNetworkProfileResponse, GetNetworkProfileError>;
+
+    /// <p>Gets the details of a room profile by profile ARN.</p>
+    fn get_profile(
+        &self,
+        input: GetProfileRequest,
+    ) -> RusotoFuture<GetProfileResponse, GetProfileError>;
+
+    /// <p>Gets room details by room ARN.</p>
+    fn get_room(&self, input: GetRoomRequest) -> RusotoFuture<GetRoomResponse, GetRoomError>;
+
+    /// <p>Gets room skill parameter details by room, skill, and parameter key ARN.</p>
+    fn get_room_skill_parameter(
+        &self,
+        input: GetRoomSkillParameterRequest,
+    ) -> RusotoFuture<GetRoomSkillParameterResponse, GetRoomSkillParameterError>;
+
+    /// <p>Gets skill group details by skill group ARN.</p>
+    fn get_skill_group(
+        &self,
+        input: GetSkillGroupRequest,
+    ) -> RusotoFuture<GetSkillGroupResponse, GetSkillGroupError>;
+
+    /// <p>Lists the details of the schedules that a user configured.</p>
+    fn list_business_report_schedules(
+        &self,
+        input: ListBusinessReportSchedulesRequest,
+    ) -> RusotoFuture<ListBusinessReportSchedulesResponse, ListBusinessReportSchedulesError>;
+
+    /// <p>Lists conference providers under a specific AWS account.</p>
+    fn list_conference_providers(
+        &self,
+        input: ListConferenceProvidersRequest,
+    ) -> RusotoFuture<ListConferenceProvidersResponse, ListConferenceProvidersError>;
+
+    /// <p>Lists the device event history, including device connection status, for up to 30 days.</p>
+    fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError>;
+
+    /// <p>Retrieves a list of gateway group summaries. Use GetGatewayGroup to retrieve details of a specific gateway group.</p>
+    fn list_gateway_groups(
+        &self,
+        input: ListGatewayGroupsRequest,
+    ) -> RusotoFuture<ListGatewayGroupsResponse, ListGatewayGroupsError>;
+
+    /// <p>Retrieves a list of gateway summaries. Use GetGateway to retrieve details of a specific gateway. An optional gateway group ARN can be provided to only retrieve gateway summaries of gateways that are associated with that gateway group ARN.</p>
+    fn list_gateways(
+        &self,
+        input: ListGatewaysRequest,
+    ) -> RusotoFuture<ListGatewaysResponse, ListGatewaysError>;
+
+    /// <p>Lists all enabled skills in a specific skill group.</p>
+    fn list_skills(
+        &self,
+        input: ListSkillsRequest,
+    ) -> RusotoFuture<ListSkillsResponse, ListSkillsError>;
+
+    /// <p>Lists all categories in the Alexa skill store.</p>
+    fn list_skills_store_categories(
+        &self,
+        input: ListSkillsStoreCategoriesRequest,
+    ) -> RusotoFuture<ListSkillsStoreCategoriesResponse, ListSkillsStoreCategoriesError>;
+
+    /// <p>Lists all skills in the Alexa skill store by category.</p>
+    fn list_skills_store_skills_by_category(
+        &self,
+        input: ListSkillsStoreSkillsByCategoryRequest,
+    ) -> RusotoFuture<ListSkillsStoreSkillsByCategoryResponse, ListSkillsStoreSkillsByCategoryError>;
+
+    /// <p>Lists all of the smart home appliances associated with a room.</p>
+    fn list_smart_home_appliances(
+        &self,
+        input: ListSmartHomeAppliancesRequest,
+    ) -> RusotoFuture<ListSmartHomeAppliancesResponse, ListSmartHomeAppliancesError>;
+
+    /// <p>Lists all tags for the specified resource.</p>
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError>;
+
+    /// <p>Sets the conference preferences on a specific conference provider at the account level.</p>
+    fn put_conference_preference(
+        &self,
+        input: PutConferencePreferenceRequest,
+    ) -> RusotoFuture<PutConferencePreferenceResponse, PutConferencePreferenceError>;
+
+    /// <p>Configures the email template for the user enrollment invitation with the specified attributes.</p>
+    fn put_invitation_configuration(
+        &self,
+        input: PutInvitationConfigurationRequest,
+    ) -> RusotoFuture<PutInvitationConfigurationResponse, PutInvitationConfigurationError>;
+
+    /// <p>Updates room skill parameter details by room, skill, and parameter key ID. Not all skills have a room skill parameter.</p>
+    fn put_room_skill_parameter(
+        &self,
+        input: PutRoomSkillParameterRequest,
+    ) -> RusotoFuture<PutRoomSkillParameterResponse, PutRoomSkillParameterError>;
+
+    /// <p>Links a user's account to a third-party skill provider. If this API operation is called by an assumed IAM role, the skill being linked must be a private skill. Also, the skill must be owned by the AWS account that assumed the IAM role.</p>
+    fn put_skill_authorization(
+        &self,
+        input: PutSkillAuthorizationRequest,
+    ) -> RusotoFuture<PutSkillAuthorizationResponse, PutSkillAuthorizationError>;
+
+    /// <p>Registers an Alexa-enabled device built by an Original Equipment Manufacturer (OEM) using Alexa Voice Service (AVS).</p>
+    fn register_avs_device(
+        &self,
+        input: RegisterAVSDeviceRequest,
+    ) -> RusotoFuture<RegisterAVSDeviceResponse, RegisterAVSDeviceError>;
+
+    /// <p>Disassociates a skill from the organization under a user's AWS account. If the skill is a private skill, it moves to an AcceptStatus of PENDING. Any private or public skill that is rejected can be added later by calling the ApproveSkill API. </p>
+    fn reject_skill(
+        &self,
+        input: RejectSkillRequest,
+    ) -> RusotoFuture<RejectSkillResponse, RejectSkillError>;
+
+    /// <p>Determines the details for the room from which a skill request was invoked. This operation is used by skill developers.</p>
+    fn resolve_room(
+        &self,
+        input: ResolveRoomRequest,
+    ) -> RusotoFuture<ResolveRoomResponse, ResolveRoomError>;
+
+    /// <p>Revokes an invitation and invalidates the enrollment URL.</p>
+    fn revoke_invitation(
+        &self,
+        input: RevokeInvitationRequest,
+    ) -> RusotoFuture<RevokeInvitationResponse, RevokeInvitationError>;
+
+    /// <p>Searches address books and lists the ones that meet a set of filter and sort criteria.</p>
+    fn search_address_books(
+        &self,
+        input: SearchAddressBooksRequest,
+    ) -> RusotoFuture<SearchAddressBooksResponse, SearchAddressBooksError>;
+
+    /// <p>Searches contacts and lists the ones that meet a set of filter and sort criteria.</p>
+    fn search_contacts(
+        &self,
+        input: SearchContactsRequest,
+    ) -> RusotoFuture<SearchContactsResponse, SearchContactsError>;
+
+    /// <p>Searches devices and lists the ones that meet a set of filter criteria.</p>
+    fn search_devices(
+        &self,
+        input: SearchDevicesRequest,
+    ) -> RusotoFuture<SearchDevicesResponse, SearchDevicesError>;
+
+    /// <p>Searches network profiles and lists the ones that meet a set of filter and sort criteria.</p>
+    fn search_network_profiles(
+        &self,
+        input: SearchNetworkProfilesRequest,
+    ) -> RusotoFuture<SearchNetworkProfilesResponse, SearchNetworkProfilesError>;
+
+    /// <p>Searches room profiles and lists the ones that meet a set of filter criteria.</p>
+    fn search_profiles(
+        &self,
+        input: SearchProfilesRequest,
+    ) -> RusotoFuture<SearchProfilesResponse, SearchProfilesError>;
+
+    /// <p>Searches rooms and lists the ones that meet a set of filter and sort criteria.</p>
+    fn search_rooms(
+        &self,
+        input: SearchRoomsRequest,
+    ) -> RusotoFuture<SearchRoomsResponse, SearchRoomsError>;
+
+    /// <p>Searches skill groups and lists the ones that meet a set of filter and sort criteria.</p>
+    fn search_skill_groups(
+        &self,
+        input: SearchSkillGroupsRequest,
+    ) -> RusotoFuture<SearchSkillGroupsResponse, SearchSkillGroupsError>;
+
+    /// <p>Searches users and lists the ones that meet a set of filter and sort criteria.</p>
+    fn search_users(
+        &self,
+        input: SearchUsersRequest,
+    ) -> RusotoFuture<SearchUsersResponse, SearchUsersError>;
+
+    /// <p>Triggers an asynchronous flow to send text, SSML, or audio announcements to rooms that are identified by a search or filter. </p>
+    fn send_announcement(
+        &self,
+        input: SendAnnouncementRequest,
+    ) -> RusotoFuture<SendAnnouncementResponse, SendAnnouncementError>;
+
+    /// <p>Sends an enrollment invitation email with a URL to a user. The URL is valid for 30 days or until you call this operation again, whichever comes first. </p>
+    fn send_invitation(
+        &self,
+        input: SendInvitationRequest,
+    ) -> RusotoFuture<SendInvitationResponse, SendInvitationError>;
+
+    /// <p><p>Resets a device and its account to the known default settings. This clears all information and settings set by previous users in the following ways:</p> <ul> <li> <p>Bluetooth - This unpairs all bluetooth devices paired with your echo device.</p> </li> <li> <p>Volume - This resets the echo device&#39;s volume to the default value.</p> </li> <li> <p>Notifications - This clears all notifications from your echo device.</p> </li> <li> <p>Lists - This clears all to-do items from your echo device.</p> </li> <li> <p>Settings - This internally syncs the room&#39;s profile (if the device is assigned to a room), contacts, address books, delegation access for account linking, and communications (if enabled on the room profile).</p> </li> </ul></p>
+    fn start_device_sync(
+        &self,
+        input: StartDeviceSyncRequest,
+    ) -> RusotoFuture<StartDeviceSyncResponse, StartDeviceSyncError>;
+
+    /// <p>Initiates the discovery of any smart home appliances associated with the room.</p>
+    fn start_smart_home_appliance_discovery(
+        &self,
+        input: StartSmartHomeApplianceDiscoveryRequest,
+    ) -> RusotoFuture<StartSmartHomeApplianceDiscoveryResponse, StartSmartHomeApplianceDiscoveryError>;
+
+    /// <p>Adds metadata tags to a specified resource.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError>;
+
+    /// <p>Removes metadata tags from a specified resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
+
+    /// <p>Updates address book details by the address book ARN.</p>
+    fn update_address_book(
+        &self,
+        input: UpdateAddressBookRequest,
+    ) -> RusotoFuture<UpdateAddressBookResponse, UpdateAddressBookError>;
+
+    /// <p>Updates the configuration of the report delivery schedule with the specified schedule ARN.</p>
+    fn update_business_report_schedule(
+        &self,
+        input: UpdateBusinessReportScheduleRequest,
+    ) -> RusotoFuture<UpdateBusinessReportScheduleResponse, UpdateBusinessReportScheduleError>;
+
+    /// <p>Updates an existing conference provider's settings.</p>
+    fn update_conference_provider(
+        &self,
+        input: UpdateConferenceProviderRequest,
+    ) -> RusotoFuture<UpdateConferenceProviderResponse, UpdateConferenceProviderError>;
+
+    /// <p>Updates the contact details by the contact ARN.</p>
+    fn update_contact(
+        &self,
+        input: UpdateContactRequest,
+    ) -> RusotoFuture<UpdateContactResponse, UpdateContactError>;
+
+    /// <p>Updates the device name by device ARN.</p>
+    fn update_device(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> RusotoFuture<UpdateDeviceResponse, UpdateDeviceError>;
+
+    /// <p>Updates the details of a gateway. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
+    fn update_gateway(
+        &self,
+        input: UpdateGatewayRequest,
+    ) -> RusotoFuture<UpdateGatewayResponse, UpdateGatewayError>;
+
+    /// <p>Updates the details of a gateway group. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
+    fn update_gateway_group(
+        &self,
+        input: UpdateGatewayGroupRequest,
+    ) -> RusotoFuture<UpdateGatewayGroupResponse, UpdateGatewayGroupError>;
+
+    /// <p>Updates a network profile by the network profile ARN.</p>
+    fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> RusotoFuture<UpdateNetworkProfileResponse, UpdateNetworkProfileError>;
+
+    /// <p>Updates an existing room profile by room profile ARN.</p>
+    fn update_profile(
+        &self,
+        input: UpdateProfileRequest,
+    ) -> RusotoFuture<UpdateProfileResponse, UpdateProfileError>;
+
+    /// <p>Updates room details by room ARN.</p>
+    fn update_room(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> RusotoFuture<UpdateRoomResponse, UpdateRoomError>;
+
+    /// <p>Updates skill group details by skill group ARN.</p>
+    fn update_skill_group(
+        &self,
+        input: UpdateSkillGroupRequest,
+    ) -> RusotoFuture<UpdateSkillGroupResponse, UpdateSkillGroupError>;
+}
+impl<T: ?Sized + AlexaForBusiness> AlexaForBusiness for ::std::sync::Arc<T> {
     fn approve_skill(
         &self,
         input: ApproveSkillRequest,
-    ) -> RusotoFuture<ApproveSkillResponse, ApproveSkillError>;
-
-    /// <p>Associates a contact with a given address book.</p>
+    ) -> RusotoFuture<ApproveSkillResponse, ApproveSkillError> {
+        (**self).approve_skill(input)
+    }
     fn associate_contact_with_address_book(
         &self,
         input: AssociateContactWithAddressBookRequest,
-    ) -> RusotoFuture<AssociateContactWithAddressBookResponse, AssociateContactWithAddressBookError>;
-
-    /// <p>Associates a device with the specified network profile.</p>
+    ) -> RusotoFuture<AssociateContactWithAddressBookResponse, AssociateContactWithAddressBookError>
+    {
+        (**self).associate_contact_with_address_book(input)
+    }
     fn associate_device_with_network_profile(
         &self,
         input: AssociateDeviceWithNetworkProfileRequest,
     ) -> RusotoFuture<
         AssociateDeviceWithNetworkProfileResponse,
         AssociateDeviceWithNetworkProfileError,
-    >;
-
-    /// <p>Associates a device with a given room. This applies all the settings from the room profile to the device, and all the skills in any skill groups added to that room. This operation requires the device to be online, or else a manual sync is required. </p>
+    > {
+        (**self).associate_device_with_network_profile(input)
+    }
     fn associate_device_with_room(
         &self,
         input: AssociateDeviceWithRoomRequest,
-    ) -> RusotoFuture<AssociateDeviceWithRoomResponse, AssociateDeviceWithRoomError>;
-
-    /// <p>Associates a skill group with a given room. This enables all skills in the associated skill group on all devices in the room.</p>
+    ) -> RusotoFuture<AssociateDeviceWithRoomResponse, AssociateDeviceWithRoomError> {
+        (**self).associate_device_with_room(input)
+    }
     fn associate_skill_group_with_room(
         &self,
         input: AssociateSkillGroupWithRoomRequest,
-    ) -> RusotoFuture<AssociateSkillGroupWithRoomResponse, AssociateSkillGroupWithRoomError>;
-
-    /// <p>Associates a skill with a skill group.</p>
+    ) -> RusotoFuture<AssociateSkillGroupWithRoomResponse, AssociateSkillGroupWithRoomError> {
+        (**self).associate_skill_group_with_room(input)
+    }
     fn associate_skill_with_skill_group(
         &self,
         input: AssociateSkillWithSkillGroupRequest,
-    ) -> RusotoFuture<AssociateSkillWithSkillGroupResponse, AssociateSkillWithSkillGroupError>;
-
-    /// <p>Makes a private skill available for enrolled users to enable on their devices.</p>
+    ) -> RusotoFuture<AssociateSkillWithSkillGroupResponse, AssociateSkillWithSkillGroupError> {
+        (**self).associate_skill_with_skill_group(input)
+    }
     fn associate_skill_with_users(
         &self,
         input: AssociateSkillWithUsersRequest,
-    ) -> RusotoFuture<AssociateSkillWithUsersResponse, AssociateSkillWithUsersError>;
-
-    /// <p>Creates an address book with the specified details.</p>
+    ) -> RusotoFuture<AssociateSkillWithUsersResponse, AssociateSkillWithUsersError> {
+        (**self).associate_skill_with_users(input)
+    }
     fn create_address_book(
         &self,
         input: CreateAddressBookRequest,
-    ) -> RusotoFuture<CreateAddressBookResponse, CreateAddressBookError>;
-
-    /// <p>Creates a recurring schedule for usage reports to deliver to the specified S3 location with a specified daily or weekly interval.</p>
+    ) -> RusotoFuture<CreateAddressBookResponse, CreateAddressBookError> {
+        (**self).create_address_book(input)
+    }
     fn create_business_report_schedule(
         &self,
         input: CreateBusinessReportScheduleRequest,
-    ) -> RusotoFuture<CreateBusinessReportScheduleResponse, CreateBusinessReportScheduleError>;
-
-    /// <p>Adds a new conference provider under the user's AWS account.</p>
+    ) -> RusotoFuture<CreateBusinessReportScheduleResponse, CreateBusinessReportScheduleError> {
+        (**self).create_business_report_schedule(input)
+    }
     fn create_conference_provider(
         &self,
         input: CreateConferenceProviderRequest,
-    ) -> RusotoFuture<CreateConferenceProviderResponse, CreateConferenceProviderError>;
-
-    /// <p>Creates a contact with the specified details.</p>
+    ) -> RusotoFuture<CreateConferenceProviderResponse, CreateConferenceProviderError> {
+        (**self).create_conference_provider(input)
+    }
     fn create_contact(
         &self,
         input: CreateContactRequest,
-    ) -> RusotoFuture<CreateContactResponse, CreateContactError>;
-
-    /// <p>Creates a gateway group with the specified details.</p>
+    ) -> RusotoFuture<CreateContactResponse, CreateContactError> {
+        (**self).create_contact(input)
+    }
     fn create_gateway_group(
         &self,
         input: CreateGatewayGroupRequest,
-    ) -> RusotoFuture<CreateGatewayGroupResponse, CreateGatewayGroupError>;
-
-    /// <p>Creates a network profile with the specified details.</p>
+    ) -> RusotoFuture<CreateGatewayGroupResponse, CreateGatewayGroupError> {
+        (**self).create_gateway_group(input)
+    }
     fn create_network_profile(
         &self,
         input: CreateNetworkProfileRequest,
-    ) -> RusotoFuture<CreateNetworkProfileResponse, CreateNetworkProfileError>;
-
-    /// <p>Creates a new room profile with the specified details.</p>
+    ) -> RusotoFuture<CreateNetworkProfileResponse, CreateNetworkProfileError> {
+        (**self).create_network_profile(input)
+    }
     fn create_profile(
         &self,
         input: CreateProfileRequest,
-    ) -> RusotoFuture<CreateProfileResponse, CreateProfileError>;
-
-    /// <p>Creates a room with the specified details.</p>
+    ) -> RusotoFuture<CreateProfileResponse, CreateProfileError> {
+        (**self).create_profile(input)
+    }
     fn create_room(
         &self,
         input: CreateRoomRequest,
-    ) -> RusotoFuture<CreateRoomResponse, CreateRoomError>;
-
-    /// <p>Creates a skill group with a specified name and description.</p>
+    ) -> RusotoFuture<CreateRoomResponse, CreateRoomError> {
+        (**self).create_room(input)
+    }
     fn create_skill_group(
         &self,
         input: CreateSkillGroupRequest,
-    ) -> RusotoFuture<CreateSkillGroupResponse, CreateSkillGroupError>;
-
-    /// <p>Creates a user.</p>
+    ) -> RusotoFuture<CreateSkillGroupResponse, CreateSkillGroupError> {
+        (**self).create_skill_group(input)
+    }
     fn create_user(
         &self,
         input: CreateUserRequest,
-    ) -> RusotoFuture<CreateUserResponse, CreateUserError>;
-
-    /// <p>Deletes an address book by the address book ARN.</p>
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
     fn delete_address_book(
         &self,
         input: DeleteAddressBookRequest,
-    ) -> RusotoFuture<DeleteAddressBookResponse, DeleteAddressBookError>;
-
-    /// <p>Deletes the recurring report delivery schedule with the specified schedule ARN.</p>
+    ) -> RusotoFuture<DeleteAddressBookResponse, DeleteAddressBookError> {
+        (**self).delete_address_book(input)
+    }
     fn delete_business_report_schedule(
         &self,
         input: DeleteBusinessReportScheduleRequest,
-    ) -> RusotoFuture<DeleteBusinessReportScheduleResponse, DeleteBusinessReportScheduleError>;
-
-    /// <p>Deletes a conference provider.</p>
+    ) -> RusotoFuture<DeleteBusinessReportScheduleResponse, DeleteBusinessReportScheduleError> {
+        (**self).delete_business_report_schedule(input)
+    }
     fn delete_conference_provider(
         &self,
         input: DeleteConferenceProviderRequest,
-    ) -> RusotoFuture<DeleteConferenceProviderResponse, DeleteConferenceProviderError>;
-
-    /// <p>Deletes a contact by the contact ARN.</p>
+    ) -> RusotoFuture<DeleteConferenceProviderResponse, DeleteConferenceProviderError> {
+        (**self).delete_conference_provider(input)
+    }
     fn delete_contact(
         &self,
         input: DeleteContactRequest,
-    ) -> RusotoFuture<DeleteContactResponse, DeleteContactError>;
-
-    /// <p>Removes a device from Alexa For Business.</p>
+    ) -> RusotoFuture<DeleteContactResponse, DeleteContactError> {
+        (**self).delete_contact(input)
+    }
     fn delete_device(
         &self,
         input: DeleteDeviceRequest,
-    ) -> RusotoFuture<DeleteDeviceResponse, DeleteDeviceError>;
-
-    /// <p>When this action is called for a specified shared device, it allows authorized users to delete the device's entire previous history of voice input data and associated response data. This action can be called once every 24 hours for a specific shared device.</p>
+    ) -> RusotoFuture<DeleteDeviceResponse, DeleteDeviceError> {
+        (**self).delete_device(input)
+    }
     fn delete_device_usage_data(
         &self,
         input: DeleteDeviceUsageDataRequest,
-    ) -> RusotoFuture<DeleteDeviceUsageDataResponse, DeleteDeviceUsageDataError>;
-
-    /// <p>Deletes a gateway group.</p>
+    ) -> RusotoFuture<DeleteDeviceUsageDataResponse, DeleteDeviceUsageDataError> {
+        (**self).delete_device_usage_data(input)
+    }
     fn delete_gateway_group(
         &self,
         input: DeleteGatewayGroupRequest,
-    ) -> RusotoFuture<DeleteGatewayGroupResponse, DeleteGatewayGroupError>;
-
-    /// <p>Deletes a network profile by the network profile ARN.</p>
+    ) -> RusotoFuture<DeleteGatewayGroupResponse, DeleteGatewayGroupError> {
+        (**self).delete_gateway_group(input)
+    }
     fn delete_network_profile(
         &self,
         input: DeleteNetworkProfileRequest,
-    ) -> RusotoFuture<DeleteNetworkProfileResponse, DeleteNetworkProfileError>;
-
-    /// <p>Deletes a room profile by the profile ARN.</p>
+    ) -> RusotoFuture<DeleteNetworkProfileResponse, DeleteNetworkProfileError> {
+        (**self).delete_network_profile(input)
+    }
     fn delete_profile(
         &self,
         input: DeleteProfileRequest,
-    ) -> RusotoFuture<DeleteProfileResponse, DeleteProfileError>;
-
-    /// <p>Deletes a room by the room ARN.</p>
+    ) -> RusotoFuture<DeleteProfileResponse, DeleteProfileError> {
+        (**self).delete_profile(input)
+    }
     fn delete_room(
         &self,
         input: DeleteRoomRequest,
-    ) -> RusotoFuture<DeleteRoomResponse, DeleteRoomError>;
-
-    /// <p>Deletes room skill parameter details by room, skill, and parameter key ID.</p>
+    ) -> RusotoFuture<DeleteRoomResponse, DeleteRoomError> {
+        (**self).delete_room(input)
+    }
     fn delete_room_skill_parameter(
         &self,
         input: DeleteRoomSkillParameterRequest,
-    ) -> RusotoFuture<DeleteRoomSkillParameterResponse, DeleteRoomSkillParameterError>;
-
-    /// <p>Unlinks a third-party account from a skill.</p>
+    ) -> RusotoFuture<DeleteRoomSkillParameterResponse, DeleteRoomSkillParameterError> {
+        (**self).delete_room_skill_parameter(input)
+    }
     fn delete_skill_authorization(
         &self,
         input: DeleteSkillAuthorizationRequest,
-    ) -> RusotoFuture<DeleteSkillAuthorizationResponse, DeleteSkillAuthorizationError>;
-
-    /// <p>Deletes a skill group by skill group ARN.</p>
+    ) -> RusotoFuture<DeleteSkillAuthorizationResponse, DeleteSkillAuthorizationError> {
+        (**self).delete_skill_authorization(input)
+    }
     fn delete_skill_group(
         &self,
         input: DeleteSkillGroupRequest,
-    ) -> RusotoFuture<DeleteSkillGroupResponse, DeleteSkillGroupError>;
-
-    /// <p>Deletes a specified user by user ARN and enrollment ARN.</p>
+    ) -> RusotoFuture<DeleteSkillGroupResponse, DeleteSkillGroupError> {
+        (**self).delete_skill_group(input)
+    }
     fn delete_user(
         &self,
         input: DeleteUserRequest,
-    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError>;
-
-    /// <p>Disassociates a contact from a given address book.</p>
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        (**self).delete_user(input)
+    }
     fn disassociate_contact_from_address_book(
         &self,
         input: DisassociateContactFromAddressBookRequest,
     ) -> RusotoFuture<
         DisassociateContactFromAddressBookResponse,
         DisassociateContactFromAddressBookError,
-    >;
-
-    /// <p>Disassociates a device from its current room. The device continues to be connected to the Wi-Fi network and is still registered to the account. The device settings and skills are removed from the room.</p>
+    > {
+        (**self).disassociate_contact_from_address_book(input)
+    }
     fn disassociate_device_from_room(
         &self,
         input: DisassociateDeviceFromRoomRequest,
-    ) -> RusotoFuture<DisassociateDeviceFromRoomResponse, DisassociateDeviceFromRoomError>;
-
-    /// <p>Disassociates a skill from a skill group.</p>
+    ) -> RusotoFuture<DisassociateDeviceFromRoomResponse, DisassociateDeviceFromRoomError> {
+        (**self).disassociate_device_from_room(input)
+    }
     fn disassociate_skill_from_skill_group(
         &self,
         input: DisassociateSkillFromSkillGroupRequest,
-    ) -> RusotoFuture<DisassociateSkillFromSkillGroupResponse, DisassociateSkillFromSkillGroupError>;
-
-    /// <p>Makes a private skill unavailable for enrolled users and prevents them from enabling it on their devices.</p>
+    ) -> RusotoFuture<DisassociateSkillFromSkillGroupResponse, DisassociateSkillFromSkillGroupError>
+    {
+        (**self).disassociate_skill_from_skill_group(input)
+    }
     fn disassociate_skill_from_users(
         &self,
         input: DisassociateSkillFromUsersRequest,
-    ) -> RusotoFuture<DisassociateSkillFromUsersResponse, DisassociateSkillFromUsersError>;
-
-    /// <p>Disassociates a skill group from a specified room. This disables all skills in the skill group on all devices in the room.</p>
+    ) -> RusotoFuture<DisassociateSkillFromUsersResponse, DisassociateSkillFromUsersError> {
+        (**self).disassociate_skill_from_users(input)
+    }
     fn disassociate_skill_group_from_room(
         &self,
         input: DisassociateSkillGroupFromRoomRequest,
-    ) -> RusotoFuture<DisassociateSkillGroupFromRoomResponse, DisassociateSkillGroupFromRoomError>;
-
-    /// <p>Forgets smart home appliances associated to a room.</p>
+    ) -> RusotoFuture<DisassociateSkillGroupFromRoomResponse, DisassociateSkillGroupFromRoomError>
+    {
+        (**self).disassociate_skill_group_from_room(input)
+    }
     fn forget_smart_home_appliances(
         &self,
         input: ForgetSmartHomeAppliancesRequest,
-    ) -> RusotoFuture<ForgetSmartHomeAppliancesResponse, ForgetSmartHomeAppliancesError>;
-
-    /// <p>Gets address the book details by the address book ARN.</p>
+    ) -> RusotoFuture<ForgetSmartHomeAppliancesResponse, ForgetSmartHomeAppliancesError> {
+        (**self).forget_smart_home_appliances(input)
+    }
     fn get_address_book(
         &self,
         input: GetAddressBookRequest,
-    ) -> RusotoFuture<GetAddressBookResponse, GetAddressBookError>;
-
-    /// <p>Retrieves the existing conference preferences.</p>
+    ) -> RusotoFuture<GetAddressBookResponse, GetAddressBookError> {
+        (**self).get_address_book(input)
+    }
     fn get_conference_preference(
         &self,
-    ) -> RusotoFuture<GetConferencePreferenceResponse, GetConferencePreferenceError>;
-
-    /// <p>Gets details about a specific conference provider.</p>
+    ) -> RusotoFuture<GetConferencePreferenceResponse, GetConferencePreferenceError> {
+        (**self).get_conference_preference()
+    }
     fn get_conference_provider(
         &self,
         input: GetConferenceProviderRequest,
-    ) -> RusotoFuture<GetConferenceProviderResponse, GetConferenceProviderError>;
-
-    /// <p>Gets the contact details by the contact ARN.</p>
+    ) -> RusotoFuture<GetConferenceProviderResponse, GetConferenceProviderError> {
+        (**self).get_conference_provider(input)
+    }
     fn get_contact(
         &self,
         input: GetContactRequest,
-    ) -> RusotoFuture<GetContactResponse, GetContactError>;
-
-    /// <p>Gets the details of a device by device ARN.</p>
+    ) -> RusotoFuture<GetContactResponse, GetContactError> {
+        (**self).get_contact(input)
+    }
     fn get_device(
         &self,
         input: GetDeviceRequest,
-    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError>;
-
-    /// <p>Retrieves the details of a gateway.</p>
+    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError> {
+        (**self).get_device(input)
+    }
     fn get_gateway(
         &self,
         input: GetGatewayRequest,
-    ) -> RusotoFuture<GetGatewayResponse, GetGatewayError>;
-
-    /// <p>Retrieves the details of a gateway group.</p>
+    ) -> RusotoFuture<GetGatewayResponse, GetGatewayError> {
+        (**self).get_gateway(input)
+    }
     fn get_gateway_group(
         &self,
         input: GetGatewayGroupRequest,
-    ) -> RusotoFuture<GetGatewayGroupResponse, GetGatewayGroupError>;
-
-    /// <p>Retrieves the configured values for the user enrollment invitation email template.</p>
+    ) -> RusotoFuture<GetGatewayGroupResponse, GetGatewayGroupError> {
+        (**self).get_gateway_group(input)
+    }
     fn get_invitation_configuration(
         &self,
-    ) -> RusotoFuture<GetInvitationConfigurationResponse, GetInvitationConfigurationError>;
-
-    /// <p>Gets the network profile details by the network profile ARN.</p>
+    ) -> RusotoFuture<GetInvitationConfigurationResponse, GetInvitationConfigurationError> {
+        (**self).get_invitation_configuration()
+    }
     fn get_network_profile(
         &self,
         input: GetNetworkProfileRequest,
-    ) -> RusotoFuture<GetNetworkProfileResponse, GetNetworkProfileError>;
-
-    /// <p>Gets the details of a room profile by profile ARN.</p>
+    ) -> RusotoFuture<GetNetworkProfileResponse, GetNetworkProfileError> {
+        (**self).get_network_profile(input)
+    }
     fn get_profile(
         &self,
         input: GetProfileRequest,
-    ) -> RusotoFuture<GetProfileResponse, GetProfileError>;
-
-    /// <p>Gets room details by room ARN.</p>
-    fn get_room(&self, input: GetRoomRequest) -> RusotoFuture<GetRoomResponse, GetRoomError>;
-
-    /// <p>Gets room skill parameter details by room, skill, and parameter key ARN.</p>
+    ) -> RusotoFuture<GetProfileResponse, GetProfileError> {
+        (**self).get_profile(input)
+    }
+    fn get_room(&self, input: GetRoomRequest) -> RusotoFuture<GetRoomResponse, GetRoomError> {
+        (**self).get_room(input)
+    }
     fn get_room_skill_parameter(
         &self,
         input: GetRoomSkillParameterRequest,
-    ) -> RusotoFuture<GetRoomSkillParameterResponse, GetRoomSkillParameterError>;
-
-    /// <p>Gets skill group details by skill group ARN.</p>
+    ) -> RusotoFuture<GetRoomSkillParameterResponse, GetRoomSkillParameterError> {
+        (**self).get_room_skill_parameter(input)
+    }
     fn get_skill_group(
         &self,
         input: GetSkillGroupRequest,
-    ) -> RusotoFuture<GetSkillGroupResponse, GetSkillGroupError>;
-
-    /// <p>Lists the details of the schedules that a user configured.</p>
+    ) -> RusotoFuture<GetSkillGroupResponse, GetSkillGroupError> {
+        (**self).get_skill_group(input)
+    }
     fn list_business_report_schedules(
         &self,
         input: ListBusinessReportSchedulesRequest,
-    ) -> RusotoFuture<ListBusinessReportSchedulesResponse, ListBusinessReportSchedulesError>;
-
-    /// <p>Lists conference providers under a specific AWS account.</p>
+    ) -> RusotoFuture<ListBusinessReportSchedulesResponse, ListBusinessReportSchedulesError> {
+        (**self).list_business_report_schedules(input)
+    }
     fn list_conference_providers(
         &self,
         input: ListConferenceProvidersRequest,
-    ) -> RusotoFuture<ListConferenceProvidersResponse, ListConferenceProvidersError>;
-
-    /// <p>Lists the device event history, including device connection status, for up to 30 days.</p>
+    ) -> RusotoFuture<ListConferenceProvidersResponse, ListConferenceProvidersError> {
+        (**self).list_conference_providers(input)
+    }
     fn list_device_events(
         &self,
         input: ListDeviceEventsRequest,
-    ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError>;
-
-    /// <p>Retrieves a list of gateway group summaries. Use GetGatewayGroup to retrieve details of a specific gateway group.</p>
+    ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError> {
+        (**self).list_device_events(input)
+    }
     fn list_gateway_groups(
         &self,
         input: ListGatewayGroupsRequest,
-    ) -> RusotoFuture<ListGatewayGroupsResponse, ListGatewayGroupsError>;
-
-    /// <p>Retrieves a list of gateway summaries. Use GetGateway to retrieve details of a specific gateway. An optional gateway group ARN can be provided to only retrieve gateway summaries of gateways that are associated with that gateway group ARN.</p>
+    ) -> RusotoFuture<ListGatewayGroupsResponse, ListGatewayGroupsError> {
+        (**self).list_gateway_groups(input)
+    }
     fn list_gateways(
         &self,
         input: ListGatewaysRequest,
-    ) -> RusotoFuture<ListGatewaysResponse, ListGatewaysError>;
-
-    /// <p>Lists all enabled skills in a specific skill group.</p>
+    ) -> RusotoFuture<ListGatewaysResponse, ListGatewaysError> {
+        (**self).list_gateways(input)
+    }
     fn list_skills(
         &self,
         input: ListSkillsRequest,
-    ) -> RusotoFuture<ListSkillsResponse, ListSkillsError>;
-
-    /// <p>Lists all categories in the Alexa skill store.</p>
+    ) -> RusotoFuture<ListSkillsResponse, ListSkillsError> {
+        (**self).list_skills(input)
+    }
     fn list_skills_store_categories(
         &self,
         input: ListSkillsStoreCategoriesRequest,
-    ) -> RusotoFuture<ListSkillsStoreCategoriesResponse, ListSkillsStoreCategoriesError>;
-
-    /// <p>Lists all skills in the Alexa skill store by category.</p>
+    ) -> RusotoFuture<ListSkillsStoreCategoriesResponse, ListSkillsStoreCategoriesError> {
+        (**self).list_skills_store_categories(input)
+    }
     fn list_skills_store_skills_by_category(
         &self,
         input: ListSkillsStoreSkillsByCategoryRequest,
-    ) -> RusotoFuture<ListSkillsStoreSkillsByCategoryResponse, ListSkillsStoreSkillsByCategoryError>;
-
-    /// <p>Lists all of the smart home appliances associated with a room.</p>
+    ) -> RusotoFuture<ListSkillsStoreSkillsByCategoryResponse, ListSkillsStoreSkillsByCategoryError>
+    {
+        (**self).list_skills_store_skills_by_category(input)
+    }
     fn list_smart_home_appliances(
         &self,
         input: ListSmartHomeAppliancesRequest,
-    ) -> RusotoFuture<ListSmartHomeAppliancesResponse, ListSmartHomeAppliancesError>;
-
-    /// <p>Lists all tags for the specified resource.</p>
-    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError>;
-
-    /// <p>Sets the conference preferences on a specific conference provider at the account level.</p>
+    ) -> RusotoFuture<ListSmartHomeAppliancesResponse, ListSmartHomeAppliancesError> {
+        (**self).list_smart_home_appliances(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
     fn put_conference_preference(
         &self,
         input: PutConferencePreferenceRequest,
-    ) -> RusotoFuture<PutConferencePreferenceResponse, PutConferencePreferenceError>;
-
-    /// <p>Configures the email template for the user enrollment invitation with the specified attributes.</p>
+    ) -> RusotoFuture<PutConferencePreferenceResponse, PutConferencePreferenceError> {
+        (**self).put_conference_preference(input)
+    }
     fn put_invitation_configuration(
         &self,
         input: PutInvitationConfigurationRequest,
-    ) -> RusotoFuture<PutInvitationConfigurationResponse, PutInvitationConfigurationError>;
-
-    /// <p>Updates room skill parameter details by room, skill, and parameter key ID. Not all skills have a room skill parameter.</p>
+    ) -> RusotoFuture<PutInvitationConfigurationResponse, PutInvitationConfigurationError> {
+        (**self).put_invitation_configuration(input)
+    }
     fn put_room_skill_parameter(
         &self,
         input: PutRoomSkillParameterRequest,
-    ) -> RusotoFuture<PutRoomSkillParameterResponse, PutRoomSkillParameterError>;
-
-    /// <p>Links a user's account to a third-party skill provider. If this API operation is called by an assumed IAM role, the skill being linked must be a private skill. Also, the skill must be owned by the AWS account that assumed the IAM role.</p>
+    ) -> RusotoFuture<PutRoomSkillParameterResponse, PutRoomSkillParameterError> {
+        (**self).put_room_skill_parameter(input)
+    }
     fn put_skill_authorization(
         &self,
         input: PutSkillAuthorizationRequest,
-    ) -> RusotoFuture<PutSkillAuthorizationResponse, PutSkillAuthorizationError>;
-
-    /// <p>Registers an Alexa-enabled device built by an Original Equipment Manufacturer (OEM) using Alexa Voice Service (AVS).</p>
+    ) -> RusotoFuture<PutSkillAuthorizationResponse, PutSkillAuthorizationError> {
+        (**self).put_skill_authorization(input)
+    }
     fn register_avs_device(
         &self,
         input: RegisterAVSDeviceRequest,
-    ) -> RusotoFuture<RegisterAVSDeviceResponse, RegisterAVSDeviceError>;
-
-    /// <p>Disassociates a skill from the organization under a user's AWS account. If the skill is a private skill, it moves to an AcceptStatus of PENDING. Any private or public skill that is rejected can be added later by calling the ApproveSkill API. </p>
+    ) -> RusotoFuture<RegisterAVSDeviceResponse, RegisterAVSDeviceError> {
+        (**self).register_avs_device(input)
+    }
     fn reject_skill(
         &self,
         input: RejectSkillRequest,
-    ) -> RusotoFuture<RejectSkillResponse, RejectSkillError>;
-
-    /// <p>Determines the details for the room from which a skill request was invoked. This operation is used by skill developers.</p>
+    ) -> RusotoFuture<RejectSkillResponse, RejectSkillError> {
+        (**self).reject_skill(input)
+    }
     fn resolve_room(
         &self,
         input: ResolveRoomRequest,
-    ) -> RusotoFuture<ResolveRoomResponse, ResolveRoomError>;
-
-    /// <p>Revokes an invitation and invalidates the enrollment URL.</p>
+    ) -> RusotoFuture<ResolveRoomResponse, ResolveRoomError> {
+        (**self).resolve_room(input)
+    }
     fn revoke_invitation(
         &self,
         input: RevokeInvitationRequest,
-    ) -> RusotoFuture<RevokeInvitationResponse, RevokeInvitationError>;
-
-    /// <p>Searches address books and lists the ones that meet a set of filter and sort criteria.</p>
+    ) -> RusotoFuture<RevokeInvitationResponse, RevokeInvitationError> {
+        (**self).revoke_invitation(input)
+    }
     fn search_address_books(
         &self,
         input: SearchAddressBooksRequest,
-    ) -> RusotoFuture<SearchAddressBooksResponse, SearchAddressBooksError>;
-
-    /// <p>Searches contacts and lists the ones that meet a set of filter and sort criteria.</p>
+    ) -> RusotoFuture<SearchAddressBooksResponse, SearchAddressBooksError> {
+        (**self).search_address_books(input)
+    }
     fn search_contacts(
         &self,
         input: SearchContactsRequest,
-    ) -> RusotoFuture<SearchContactsResponse, SearchContactsError>;
-
-    /// <p>Searches devices and lists the ones that meet a set of filter criteria.</p>
+    ) -> RusotoFuture<SearchContactsResponse, SearchContactsError> {
+        (**self).search_contacts(input)
+    }
     fn search_devices(
         &self,
         input: SearchDevicesRequest,
-    ) -> RusotoFuture<SearchDevicesResponse, SearchDevicesError>;
-
-    /// <p>Searches network profiles and lists the ones that meet a set of filter and sort criteria.</p>
+    ) -> RusotoFuture<SearchDevicesResponse, SearchDevicesError> {
+        (**self).search_devices(input)
+    }
     fn search_network_profiles(
         &self,
         input: SearchNetworkProfilesRequest,
-    ) -> RusotoFuture<SearchNetworkProfilesResponse, SearchNetworkProfilesError>;
-
-    /// <p>Searches room profiles and lists the ones that meet a set of filter criteria.</p>
+    ) -> RusotoFuture<SearchNetworkProfilesResponse, SearchNetworkProfilesError> {
+        (**self).search_network_profiles(input)
+    }
     fn search_profiles(
         &self,
         input: SearchProfilesRequest,
-    ) -> RusotoFuture<SearchProfilesResponse, SearchProfilesError>;
-
-    /// <p>Searches rooms and lists the ones that meet a set of filter and sort criteria.</p>
+    ) -> RusotoFuture<SearchProfilesResponse, SearchProfilesError> {
+        (**self).search_profiles(input)
+    }
     fn search_rooms(
         &self,
         input: SearchRoomsRequest,
-    ) -> RusotoFuture<SearchRoomsResponse, SearchRoomsError>;
-
-    /// <p>Searches skill groups and lists the ones that meet a set of filter and sort criteria.</p>
+    ) -> RusotoFuture<SearchRoomsResponse, SearchRoomsError> {
+        (**self).search_rooms(input)
+    }
     fn search_skill_groups(
         &self,
         input: SearchSkillGroupsRequest,
-    ) -> RusotoFuture<SearchSkillGroupsResponse, SearchSkillGroupsError>;
-
-    /// <p>Searches users and lists the ones that meet a set of filter and sort criteria.</p>
+    ) -> RusotoFuture<SearchSkillGroupsResponse, SearchSkillGroupsError> {
+        (**self).search_skill_groups(input)
+    }
     fn search_users(
         &self,
         input: SearchUsersRequest,
-    ) -> RusotoFuture<SearchUsersResponse, SearchUsersError>;
-
-    /// <p>Triggers an asynchronous flow to send text, SSML, or audio announcements to rooms that are identified by a search or filter. </p>
+    ) -> RusotoFuture<SearchUsersResponse, SearchUsersError> {
+        (**self).search_users(input)
+    }
     fn send_announcement(
         &self,
         input: SendAnnouncementRequest,
-    ) -> RusotoFuture<SendAnnouncementResponse, SendAnnouncementError>;
-
-    /// <p>Sends an enrollment invitation email with a URL to a user. The URL is valid for 30 days or until you call this operation again, whichever comes first. </p>
+    ) -> RusotoFuture<SendAnnouncementResponse, SendAnnouncementError> {
+        (**self).send_announcement(input)
+    }
     fn send_invitation(
         &self,
         input: SendInvitationRequest,
-    ) -> RusotoFuture<SendInvitationResponse, SendInvitationError>;
-
-    /// <p><p>Resets a device and its account to the known default settings. This clears all information and settings set by previous users in the following ways:</p> <ul> <li> <p>Bluetooth - This unpairs all bluetooth devices paired with your echo device.</p> </li> <li> <p>Volume - This resets the echo device&#39;s volume to the default value.</p> </li> <li> <p>Notifications - This clears all notifications from your echo device.</p> </li> <li> <p>Lists - This clears all to-do items from your echo device.</p> </li> <li> <p>Settings - This internally syncs the room&#39;s profile (if the device is assigned to a room), contacts, address books, delegation access for account linking, and communications (if enabled on the room profile).</p> </li> </ul></p>
+    ) -> RusotoFuture<SendInvitationResponse, SendInvitationError> {
+        (**self).send_invitation(input)
+    }
     fn start_device_sync(
         &self,
         input: StartDeviceSyncRequest,
-    ) -> RusotoFuture<StartDeviceSyncResponse, StartDeviceSyncError>;
-
-    /// <p>Initiates the discovery of any smart home appliances associated with the room.</p>
+    ) -> RusotoFuture<StartDeviceSyncResponse, StartDeviceSyncError> {
+        (**self).start_device_sync(input)
+    }
     fn start_smart_home_appliance_discovery(
         &self,
         input: StartSmartHomeApplianceDiscoveryRequest,
-    ) -> RusotoFuture<StartSmartHomeApplianceDiscoveryResponse, StartSmartHomeApplianceDiscoveryError>;
-
-    /// <p>Adds metadata tags to a specified resource.</p>
+    ) -> RusotoFuture<StartSmartHomeApplianceDiscoveryResponse, StartSmartHomeApplianceDiscoveryError>
+    {
+        (**self).start_smart_home_appliance_discovery(input)
+    }
     fn tag_resource(
         &self,
         input: TagResourceRequest,
-    ) -> RusotoFuture<TagResourceResponse, TagResourceError>;
-
-    /// <p>Removes metadata tags from a specified resource.</p>
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
-    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
-
-    /// <p>Updates address book details by the address book ARN.</p>
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
     fn update_address_book(
         &self,
         input: UpdateAddressBookRequest,
-    ) -> RusotoFuture<UpdateAddressBookResponse, UpdateAddressBookError>;
-
-    /// <p>Updates the configuration of the report delivery schedule with the specified schedule ARN.</p>
+    ) -> RusotoFuture<UpdateAddressBookResponse, UpdateAddressBookError> {
+        (**self).update_address_book(input)
+    }
     fn update_business_report_schedule(
         &self,
         input: UpdateBusinessReportScheduleRequest,
-    ) -> RusotoFuture<UpdateBusinessReportScheduleResponse, UpdateBusinessReportScheduleError>;
-
-    /// <p>Updates an existing conference provider's settings.</p>
+    ) -> RusotoFuture<UpdateBusinessReportScheduleResponse, UpdateBusinessReportScheduleError> {
+        (**self).update_business_report_schedule(input)
+    }
     fn update_conference_provider(
         &self,
         input: UpdateConferenceProviderRequest,
-    ) -> RusotoFuture<UpdateConferenceProviderResponse, UpdateConferenceProviderError>;
-
-    /// <p>Updates the contact details by the contact ARN.</p>
+    ) -> RusotoFuture<UpdateConferenceProviderResponse, UpdateConferenceProviderError> {
+        (**self).update_conference_provider(input)
+    }
     fn update_contact(
         &self,
         input: UpdateContactRequest,
-    ) -> RusotoFuture<UpdateContactResponse, UpdateContactError>;
-
-    /// <p>Updates the device name by device ARN.</p>
+    ) -> RusotoFuture<UpdateContactResponse, UpdateContactError> {
+        (**self).update_contact(input)
+    }
     fn update_device(
         &self,
         input: UpdateDeviceRequest,
-    ) -> RusotoFuture<UpdateDeviceResponse, UpdateDeviceError>;
-
-    /// <p>Updates the details of a gateway. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
+    ) -> RusotoFuture<UpdateDeviceResponse, UpdateDeviceError> {
+        (**self).update_device(input)
+    }
     fn update_gateway(
         &self,
         input: UpdateGatewayRequest,
-    ) -> RusotoFuture<UpdateGatewayResponse, UpdateGatewayError>;
-
-    /// <p>Updates the details of a gateway group. If any optional field is not provided, the existing corresponding value is left unmodified.</p>
+    ) -> RusotoFuture<UpdateGatewayResponse, UpdateGatewayError> {
+        (**self).update_gateway(input)
+    }
     fn update_gateway_group(
         &self,
         input: UpdateGatewayGroupRequest,
-    ) -> RusotoFuture<UpdateGatewayGroupResponse, UpdateGatewayGroupError>;
-
-    /// <p>Updates a network profile by the network profile ARN.</p>
+    ) -> RusotoFuture<UpdateGatewayGroupResponse, UpdateGatewayGroupError> {
+        (**self).update_gateway_group(input)
+    }
     fn update_network_profile(
         &self,
         input: UpdateNetworkProfileRequest,
-    ) -> RusotoFuture<UpdateNetworkProfileResponse, UpdateNetworkProfileError>;
-
-    /// <p>Updates an existing room profile by room profile ARN.</p>
+    ) -> RusotoFuture<UpdateNetworkProfileResponse, UpdateNetworkProfileError> {
+        (**self).update_network_profile(input)
+    }
     fn update_profile(
         &self,
         input: UpdateProfileRequest,
-    ) -> RusotoFuture<UpdateProfileResponse, UpdateProfileError>;
-
-    /// <p>Updates room details by room ARN.</p>
+    ) -> RusotoFuture<UpdateProfileResponse, UpdateProfileError> {
+        (**self).update_profile(input)
+    }
     fn update_room(
         &self,
         input: UpdateRoomRequest,
-    ) -> RusotoFuture<UpdateRoomResponse, UpdateRoomError>;
-
-    /// <p>Updates skill group details by skill group ARN.</p>
+    ) -> RusotoFuture<UpdateRoomResponse, UpdateRoomError> {
+        (**self).update_room(input)
+    }
     fn update_skill_group(
         &self,
         input: UpdateSkillGroupRequest,
-    ) -> RusotoFuture<UpdateSkillGroupResponse, UpdateSkillGroupError>;
+    ) -> RusotoFuture<UpdateSkillGroupResponse, UpdateSkillGroupError> {
+        (**self).update_skill_group(input)
+    }
 }
+/// The error codes Alexa For Business retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Alexa For Business API.
 #[derive(Clone)]
 pub struct AlexaForBusinessClient {
@@ -7239,6 +14179,27 @@ impl AlexaForBusinessClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ALEXAFORBUSINESS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> AlexaForBusinessClient {
+        Self::new(region::Region::from_env_for_service("ALEXAFORBUSINESS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `a4b`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> AlexaForBusinessClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("a4b", "ALEXAFORBUSINESS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -7257,7 +14218,54 @@ impl AlexaForBusinessClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AlexaForBusinessClient {
-        AlexaForBusinessClient { client, region }
+        AlexaForBusinessClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "a4b")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "a4b",
+            "a4b",
+            "ALEXAFORBUSINESS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `AlexaForBusiness` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn AlexaForBusiness + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for AlexaForBusinessClient {
+    fn service_name(&self) -> &'static str {
+        "Alexa For Business"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "a4b"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> AlexaForBusinessClient {
+        Self::new_with_client(client, region)
     }
 }
 
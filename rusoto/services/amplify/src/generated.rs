@@ -23,9 +23,10 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p> Amplify App represents different branches of a repository for building, deploying, and hosting. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct App {
     /// <p> ARN for the Amplify App. </p>
@@ -52,7 +53,8 @@ pub struct App {
     pub build_spec: Option<String>,
     /// <p> Create date / time for the Amplify App. </p>
     #[serde(rename = "createTime")]
-    pub create_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub create_time: EpochTimestamp,
     /// <p> Custom redirect / rewrite rules for the Amplify App. </p>
     #[serde(rename = "customRules")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -85,7 +87,7 @@ pub struct App {
     pub name: String,
     /// <p> Platform for the Amplify App. </p>
     #[serde(rename = "platform")]
-    pub platform: String,
+    pub platform: Platform,
     /// <p> Structure with Production Branch information. </p>
     #[serde(rename = "productionBranch")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -99,7 +101,8 @@ pub struct App {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p> Update date / time for the Amplify App. </p>
     #[serde(rename = "updateTime")]
-    pub update_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub update_time: EpochTimestamp,
 }
 
 /// <p> Structure with auto branch creation config. </p>
@@ -132,11 +135,11 @@ pub struct AutoBranchCreationConfig {
     /// <p> Stage for the auto created branch. </p>
     #[serde(rename = "stage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stage: Option<String>,
+    pub stage: Option<Stage>,
 }
 
 /// <p> Branch for an Amplify App, which maps to a 3rd party repository branch. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Branch {
     /// <p> Id of the active job for a branch, part of an Amplify App. </p>
@@ -162,7 +165,8 @@ pub struct Branch {
     pub build_spec: Option<String>,
     /// <p> Creation date and time for a branch, part of an Amplify App. </p>
     #[serde(rename = "createTime")]
-    pub create_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub create_time: EpochTimestamp,
     /// <p> Custom domains for a branch, part of an Amplify App. </p>
     #[serde(rename = "customDomains")]
     pub custom_domains: Vec<String>,
@@ -189,7 +193,7 @@ pub struct Branch {
     pub framework: String,
     /// <p> Stage for a branch, part of an Amplify App. </p>
     #[serde(rename = "stage")]
-    pub stage: String,
+    pub stage: Stage,
     /// <p> Tag for branch for Amplify App. </p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -206,7 +210,8 @@ pub struct Branch {
     pub ttl: String,
     /// <p> Last updated date and time for a branch, part of an Amplify App. </p>
     #[serde(rename = "updateTime")]
-    pub update_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub update_time: EpochTimestamp,
 }
 
 /// <p> Request structure used to create Apps in Amplify. </p>
@@ -270,7 +275,7 @@ pub struct CreateAppRequest {
     /// <p> Platform / framework for an Amplify App </p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p> Repository for an Amplify App </p>
     #[serde(rename = "repository")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -281,7 +286,171 @@ pub struct CreateAppRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl CreateAppRequest {
+    /// Returns a builder for `CreateAppRequest`, checking its required members when built.
+    pub fn builder() -> CreateAppRequestBuilder {
+        CreateAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAppRequest`](struct.CreateAppRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAppRequestBuilder {
+    access_token: Option<String>,
+    auto_branch_creation_config: Option<AutoBranchCreationConfig>,
+    auto_branch_creation_patterns: Option<Vec<String>>,
+    basic_auth_credentials: Option<String>,
+    build_spec: Option<String>,
+    custom_rules: Option<Vec<CustomRule>>,
+    description: Option<String>,
+    enable_auto_branch_creation: Option<bool>,
+    enable_basic_auth: Option<bool>,
+    enable_branch_auto_build: Option<bool>,
+    environment_variables: Option<::std::collections::HashMap<String, String>>,
+    iam_service_role_arn: Option<String>,
+    name: Option<String>,
+    oauth_token: Option<String>,
+    platform: Option<Platform>,
+    repository: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateAppRequestBuilder {
+    /// Sets the [`access_token`](struct.CreateAppRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_branch_creation_config`](struct.CreateAppRequest.html#structfield.auto_branch_creation_config) member.
+    pub fn auto_branch_creation_config<T: Into<AutoBranchCreationConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.auto_branch_creation_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_branch_creation_patterns`](struct.CreateAppRequest.html#structfield.auto_branch_creation_patterns) member.
+    pub fn auto_branch_creation_patterns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.auto_branch_creation_patterns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`basic_auth_credentials`](struct.CreateAppRequest.html#structfield.basic_auth_credentials) member.
+    pub fn basic_auth_credentials<T: Into<String>>(mut self, value: T) -> Self {
+        self.basic_auth_credentials = Some(value.into());
+        self
+    }
+
+    /// Sets the [`build_spec`](struct.CreateAppRequest.html#structfield.build_spec) member.
+    pub fn build_spec<T: Into<String>>(mut self, value: T) -> Self {
+        self.build_spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_rules`](struct.CreateAppRequest.html#structfield.custom_rules) member.
+    pub fn custom_rules<T: Into<Vec<CustomRule>>>(mut self, value: T) -> Self {
+        self.custom_rules = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateAppRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_auto_branch_creation`](struct.CreateAppRequest.html#structfield.enable_auto_branch_creation) member.
+    pub fn enable_auto_branch_creation<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_auto_branch_creation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_basic_auth`](struct.CreateAppRequest.html#structfield.enable_basic_auth) member.
+    pub fn enable_basic_auth<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_basic_auth = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_branch_auto_build`](struct.CreateAppRequest.html#structfield.enable_branch_auto_build) member.
+    pub fn enable_branch_auto_build<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_branch_auto_build = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_variables`](struct.CreateAppRequest.html#structfield.environment_variables) member.
+    pub fn environment_variables<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.environment_variables = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_service_role_arn`](struct.CreateAppRequest.html#structfield.iam_service_role_arn) member.
+    pub fn iam_service_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_service_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateAppRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`oauth_token`](struct.CreateAppRequest.html#structfield.oauth_token) member.
+    pub fn oauth_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.oauth_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform`](struct.CreateAppRequest.html#structfield.platform) member.
+    pub fn platform<T: Into<Platform>>(mut self, value: T) -> Self {
+        self.platform = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository`](struct.CreateAppRequest.html#structfield.repository) member.
+    pub fn repository<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateAppRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateAppRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAppRequest {
+            access_token: self.access_token,
+            auto_branch_creation_config: self.auto_branch_creation_config,
+            auto_branch_creation_patterns: self.auto_branch_creation_patterns,
+            basic_auth_credentials: self.basic_auth_credentials,
+            build_spec: self.build_spec,
+            custom_rules: self.custom_rules,
+            description: self.description,
+            enable_auto_branch_creation: self.enable_auto_branch_creation,
+            enable_basic_auth: self.enable_basic_auth,
+            enable_branch_auto_build: self.enable_branch_auto_build,
+            environment_variables: self.environment_variables,
+            iam_service_role_arn: self.iam_service_role_arn,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            oauth_token: self.oauth_token,
+            platform: self.platform,
+            repository: self.repository,
+            tags: self.tags,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateAppResult {
     #[serde(rename = "app")]
@@ -336,7 +505,7 @@ pub struct CreateBranchRequest {
     /// <p> Stage for the branch. </p>
     #[serde(rename = "stage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stage: Option<String>,
+    pub stage: Option<Stage>,
     /// <p> Tag for the branch. </p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -347,8 +516,147 @@ pub struct CreateBranchRequest {
     pub ttl: Option<String>,
 }
 
+impl CreateBranchRequest {
+    /// Returns a builder for `CreateBranchRequest`, checking its required members when built.
+    pub fn builder() -> CreateBranchRequestBuilder {
+        CreateBranchRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBranchRequest`](struct.CreateBranchRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateBranchRequestBuilder {
+    app_id: Option<String>,
+    basic_auth_credentials: Option<String>,
+    branch_name: Option<String>,
+    build_spec: Option<String>,
+    description: Option<String>,
+    display_name: Option<String>,
+    enable_auto_build: Option<bool>,
+    enable_basic_auth: Option<bool>,
+    enable_notification: Option<bool>,
+    environment_variables: Option<::std::collections::HashMap<String, String>>,
+    framework: Option<String>,
+    stage: Option<Stage>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    ttl: Option<String>,
+}
+
+impl CreateBranchRequestBuilder {
+    /// Sets the [`app_id`](struct.CreateBranchRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`basic_auth_credentials`](struct.CreateBranchRequest.html#structfield.basic_auth_credentials) member.
+    pub fn basic_auth_credentials<T: Into<String>>(mut self, value: T) -> Self {
+        self.basic_auth_credentials = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.CreateBranchRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`build_spec`](struct.CreateBranchRequest.html#structfield.build_spec) member.
+    pub fn build_spec<T: Into<String>>(mut self, value: T) -> Self {
+        self.build_spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateBranchRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.CreateBranchRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_auto_build`](struct.CreateBranchRequest.html#structfield.enable_auto_build) member.
+    pub fn enable_auto_build<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_auto_build = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_basic_auth`](struct.CreateBranchRequest.html#structfield.enable_basic_auth) member.
+    pub fn enable_basic_auth<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_basic_auth = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_notification`](struct.CreateBranchRequest.html#structfield.enable_notification) member.
+    pub fn enable_notification<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_variables`](struct.CreateBranchRequest.html#structfield.environment_variables) member.
+    pub fn environment_variables<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.environment_variables = Some(value.into());
+        self
+    }
+
+    /// Sets the [`framework`](struct.CreateBranchRequest.html#structfield.framework) member.
+    pub fn framework<T: Into<String>>(mut self, value: T) -> Self {
+        self.framework = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage`](struct.CreateBranchRequest.html#structfield.stage) member.
+    pub fn stage<T: Into<Stage>>(mut self, value: T) -> Self {
+        self.stage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateBranchRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ttl`](struct.CreateBranchRequest.html#structfield.ttl) member.
+    pub fn ttl<T: Into<String>>(mut self, value: T) -> Self {
+        self.ttl = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBranchRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateBranchRequest, ::rusoto_core::BuildError> {
+        Ok(CreateBranchRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            basic_auth_credentials: self.basic_auth_credentials,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            build_spec: self.build_spec,
+            description: self.description,
+            display_name: self.display_name,
+            enable_auto_build: self.enable_auto_build,
+            enable_basic_auth: self.enable_basic_auth,
+            enable_notification: self.enable_notification,
+            environment_variables: self.environment_variables,
+            framework: self.framework,
+            stage: self.stage,
+            tags: self.tags,
+            ttl: self.ttl,
+        })
+    }
+}
+
 /// <p> Result structure for create branch request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateBranchResult {
     /// <p> Branch structure for an Amplify App. </p>
@@ -371,6 +679,59 @@ pub struct CreateDeploymentRequest {
     pub file_map: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateDeploymentRequest {
+    /// Returns a builder for `CreateDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> CreateDeploymentRequestBuilder {
+        CreateDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDeploymentRequest`](struct.CreateDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDeploymentRequestBuilder {
+    app_id: Option<String>,
+    branch_name: Option<String>,
+    file_map: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateDeploymentRequestBuilder {
+    /// Sets the [`app_id`](struct.CreateDeploymentRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.CreateDeploymentRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_map`](struct.CreateDeploymentRequest.html#structfield.file_map) member.
+    pub fn file_map<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.file_map = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDeploymentRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            file_map: self.file_map,
+        })
+    }
+}
+
 /// <p> Result structure for create a new deployment. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -405,8 +766,68 @@ pub struct CreateDomainAssociationRequest {
     pub sub_domain_settings: Vec<SubDomainSetting>,
 }
 
+impl CreateDomainAssociationRequest {
+    /// Returns a builder for `CreateDomainAssociationRequest`, checking its required members when built.
+    pub fn builder() -> CreateDomainAssociationRequestBuilder {
+        CreateDomainAssociationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDomainAssociationRequest`](struct.CreateDomainAssociationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDomainAssociationRequestBuilder {
+    app_id: Option<String>,
+    domain_name: Option<String>,
+    enable_auto_sub_domain: Option<bool>,
+    sub_domain_settings: Option<Vec<SubDomainSetting>>,
+}
+
+impl CreateDomainAssociationRequestBuilder {
+    /// Sets the [`app_id`](struct.CreateDomainAssociationRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.CreateDomainAssociationRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_auto_sub_domain`](struct.CreateDomainAssociationRequest.html#structfield.enable_auto_sub_domain) member.
+    pub fn enable_auto_sub_domain<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_auto_sub_domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sub_domain_settings`](struct.CreateDomainAssociationRequest.html#structfield.sub_domain_settings) member.
+    pub fn sub_domain_settings<T: Into<Vec<SubDomainSetting>>>(mut self, value: T) -> Self {
+        self.sub_domain_settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDomainAssociationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDomainAssociationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDomainAssociationRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            enable_auto_sub_domain: self.enable_auto_sub_domain,
+            sub_domain_settings: self
+                .sub_domain_settings
+                .ok_or(::rusoto_core::BuildError::missing("sub_domain_settings"))?,
+        })
+    }
+}
+
 /// <p> Result structure for the create Domain Association request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDomainAssociationResult {
     /// <p> Domain Association structure. </p>
@@ -429,6 +850,54 @@ pub struct CreateWebhookRequest {
     pub description: Option<String>,
 }
 
+impl CreateWebhookRequest {
+    /// Returns a builder for `CreateWebhookRequest`, checking its required members when built.
+    pub fn builder() -> CreateWebhookRequestBuilder {
+        CreateWebhookRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateWebhookRequest`](struct.CreateWebhookRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateWebhookRequestBuilder {
+    app_id: Option<String>,
+    branch_name: Option<String>,
+    description: Option<String>,
+}
+
+impl CreateWebhookRequestBuilder {
+    /// Sets the [`app_id`](struct.CreateWebhookRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.CreateWebhookRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateWebhookRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateWebhookRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateWebhookRequest, ::rusoto_core::BuildError> {
+        Ok(CreateWebhookRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            description: self.description,
+        })
+    }
+}
+
 /// <p> Result structure for the create webhook request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -465,8 +934,38 @@ pub struct DeleteAppRequest {
     pub app_id: String,
 }
 
+impl DeleteAppRequest {
+    /// Returns a builder for `DeleteAppRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAppRequestBuilder {
+        DeleteAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAppRequest`](struct.DeleteAppRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAppRequestBuilder {
+    app_id: Option<String>,
+}
+
+impl DeleteAppRequestBuilder {
+    /// Sets the [`app_id`](struct.DeleteAppRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteAppRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAppRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+        })
+    }
+}
+
 /// <p> Result structure for an Amplify App delete request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteAppResult {
     #[serde(rename = "app")]
@@ -484,8 +983,48 @@ pub struct DeleteBranchRequest {
     pub branch_name: String,
 }
 
+impl DeleteBranchRequest {
+    /// Returns a builder for `DeleteBranchRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBranchRequestBuilder {
+        DeleteBranchRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBranchRequest`](struct.DeleteBranchRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBranchRequestBuilder {
+    app_id: Option<String>,
+    branch_name: Option<String>,
+}
+
+impl DeleteBranchRequestBuilder {
+    /// Sets the [`app_id`](struct.DeleteBranchRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.DeleteBranchRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBranchRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteBranchRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBranchRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+        })
+    }
+}
+
 /// <p> Result structure for delete branch request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteBranchResult {
     /// <p> Branch structure for an Amplify App. </p>
@@ -504,7 +1043,49 @@ pub struct DeleteDomainAssociationRequest {
     pub domain_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DeleteDomainAssociationRequest {
+    /// Returns a builder for `DeleteDomainAssociationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDomainAssociationRequestBuilder {
+        DeleteDomainAssociationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDomainAssociationRequest`](struct.DeleteDomainAssociationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDomainAssociationRequestBuilder {
+    app_id: Option<String>,
+    domain_name: Option<String>,
+}
+
+impl DeleteDomainAssociationRequestBuilder {
+    /// Sets the [`app_id`](struct.DeleteDomainAssociationRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DeleteDomainAssociationRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDomainAssociationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDomainAssociationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDomainAssociationRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDomainAssociationResult {
     #[serde(rename = "domainAssociation")]
@@ -525,8 +1106,58 @@ pub struct DeleteJobRequest {
     pub job_id: String,
 }
 
+impl DeleteJobRequest {
+    /// Returns a builder for `DeleteJobRequest`, checking its required members when built.
+    pub fn builder() -> DeleteJobRequestBuilder {
+        DeleteJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteJobRequest`](struct.DeleteJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteJobRequestBuilder {
+    app_id: Option<String>,
+    branch_name: Option<String>,
+    job_id: Option<String>,
+}
+
+impl DeleteJobRequestBuilder {
+    /// Sets the [`app_id`](struct.DeleteJobRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.DeleteJobRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.DeleteJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteJobRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteJobRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 /// <p> Result structure for the delete job request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteJobResult {
     #[serde(rename = "jobSummary")]
@@ -541,6 +1172,36 @@ pub struct DeleteWebhookRequest {
     pub webhook_id: String,
 }
 
+impl DeleteWebhookRequest {
+    /// Returns a builder for `DeleteWebhookRequest`, checking its required members when built.
+    pub fn builder() -> DeleteWebhookRequestBuilder {
+        DeleteWebhookRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteWebhookRequest`](struct.DeleteWebhookRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteWebhookRequestBuilder {
+    webhook_id: Option<String>,
+}
+
+impl DeleteWebhookRequestBuilder {
+    /// Sets the [`webhook_id`](struct.DeleteWebhookRequest.html#structfield.webhook_id) member.
+    pub fn webhook_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.webhook_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteWebhookRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteWebhookRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteWebhookRequest {
+            webhook_id: self
+                .webhook_id
+                .ok_or(::rusoto_core::BuildError::missing("webhook_id"))?,
+        })
+    }
+}
+
 /// <p> Result structure for the delete webhook request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -551,7 +1212,7 @@ pub struct DeleteWebhookResult {
 }
 
 /// <p> Structure for Domain Association, which associates a custom domain with an Amplify App. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DomainAssociation {
     /// <p> DNS Record for certificate verification. </p>
@@ -566,7 +1227,7 @@ pub struct DomainAssociation {
     pub domain_name: String,
     /// <p> Status fo the Domain Association. </p>
     #[serde(rename = "domainStatus")]
-    pub domain_status: String,
+    pub domain_status: DomainStatus,
     /// <p> Enables automated creation of Subdomains for branches. </p>
     #[serde(rename = "enableAutoSubDomain")]
     pub enable_auto_sub_domain: bool,
@@ -578,6 +1239,108 @@ pub struct DomainAssociation {
     pub sub_domains: Vec<SubDomain>,
 }
 
+/// <p> Status fo the Domain Association. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DomainStatus {
+    /// <p><code>PENDING_VERIFICATION</code></p>
+    PendingVerification,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>PENDING_DEPLOYMENT</code></p>
+    PendingDeployment,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>REQUESTING_CERTIFICATE</code></p>
+    RequestingCertificate,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DomainStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainStatus::PendingVerification => "PENDING_VERIFICATION",
+            DomainStatus::InProgress => "IN_PROGRESS",
+            DomainStatus::Available => "AVAILABLE",
+            DomainStatus::PendingDeployment => "PENDING_DEPLOYMENT",
+            DomainStatus::Failed => "FAILED",
+            DomainStatus::Creating => "CREATING",
+            DomainStatus::RequestingCertificate => "REQUESTING_CERTIFICATE",
+            DomainStatus::Updating => "UPDATING",
+            DomainStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DomainStatus {
+    fn from(value: &'a str) -> DomainStatus {
+        match value {
+            "PENDING_VERIFICATION" => DomainStatus::PendingVerification,
+            "IN_PROGRESS" => DomainStatus::InProgress,
+            "AVAILABLE" => DomainStatus::Available,
+            "PENDING_DEPLOYMENT" => DomainStatus::PendingDeployment,
+            "FAILED" => DomainStatus::Failed,
+            "CREATING" => DomainStatus::Creating,
+            "REQUESTING_CERTIFICATE" => DomainStatus::RequestingCertificate,
+            "UPDATING" => DomainStatus::Updating,
+            _ => DomainStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainStatus {
+    fn from(value: String) -> DomainStatus {
+        DomainStatus::from(value.as_str())
+    }
+}
+
+impl From<DomainStatus> for String {
+    fn from(value: DomainStatus) -> String {
+        match value {
+            DomainStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DomainStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DomainStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DomainStatus::from(value))
+    }
+}
+
 /// <p> Request structure for get App request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetAppRequest {
@@ -586,7 +1349,37 @@ pub struct GetAppRequest {
     pub app_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl GetAppRequest {
+    /// Returns a builder for `GetAppRequest`, checking its required members when built.
+    pub fn builder() -> GetAppRequestBuilder {
+        GetAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAppRequest`](struct.GetAppRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAppRequestBuilder {
+    app_id: Option<String>,
+}
+
+impl GetAppRequestBuilder {
+    /// Sets the [`app_id`](struct.GetAppRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetAppRequest, ::rusoto_core::BuildError> {
+        Ok(GetAppRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAppResult {
     #[serde(rename = "app")]
@@ -604,7 +1397,47 @@ pub struct GetBranchRequest {
     pub branch_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl GetBranchRequest {
+    /// Returns a builder for `GetBranchRequest`, checking its required members when built.
+    pub fn builder() -> GetBranchRequestBuilder {
+        GetBranchRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBranchRequest`](struct.GetBranchRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBranchRequestBuilder {
+    app_id: Option<String>,
+    branch_name: Option<String>,
+}
+
+impl GetBranchRequestBuilder {
+    /// Sets the [`app_id`](struct.GetBranchRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.GetBranchRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBranchRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetBranchRequest, ::rusoto_core::BuildError> {
+        Ok(GetBranchRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBranchResult {
     #[serde(rename = "branch")]
@@ -622,8 +1455,50 @@ pub struct GetDomainAssociationRequest {
     pub domain_name: String,
 }
 
-/// <p> Result structure for the get Domain Association request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl GetDomainAssociationRequest {
+    /// Returns a builder for `GetDomainAssociationRequest`, checking its required members when built.
+    pub fn builder() -> GetDomainAssociationRequestBuilder {
+        GetDomainAssociationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDomainAssociationRequest`](struct.GetDomainAssociationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDomainAssociationRequestBuilder {
+    app_id: Option<String>,
+    domain_name: Option<String>,
+}
+
+impl GetDomainAssociationRequestBuilder {
+    /// Sets the [`app_id`](struct.GetDomainAssociationRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.GetDomainAssociationRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDomainAssociationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDomainAssociationRequest, ::rusoto_core::BuildError> {
+        Ok(GetDomainAssociationRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
+/// <p> Result structure for the get Domain Association request. </p>
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDomainAssociationResult {
     /// <p> Domain Association structure. </p>
@@ -645,7 +1520,57 @@ pub struct GetJobRequest {
     pub job_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl GetJobRequest {
+    /// Returns a builder for `GetJobRequest`, checking its required members when built.
+    pub fn builder() -> GetJobRequestBuilder {
+        GetJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetJobRequest`](struct.GetJobRequest.html).
+#[derive(Default, Debug)]
+pub struct GetJobRequestBuilder {
+    app_id: Option<String>,
+    branch_name: Option<String>,
+    job_id: Option<String>,
+}
+
+impl GetJobRequestBuilder {
+    /// Sets the [`app_id`](struct.GetJobRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.GetJobRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.GetJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetJobRequest, ::rusoto_core::BuildError> {
+        Ok(GetJobRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetJobResult {
     #[serde(rename = "job")]
@@ -660,6 +1585,36 @@ pub struct GetWebhookRequest {
     pub webhook_id: String,
 }
 
+impl GetWebhookRequest {
+    /// Returns a builder for `GetWebhookRequest`, checking its required members when built.
+    pub fn builder() -> GetWebhookRequestBuilder {
+        GetWebhookRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetWebhookRequest`](struct.GetWebhookRequest.html).
+#[derive(Default, Debug)]
+pub struct GetWebhookRequestBuilder {
+    webhook_id: Option<String>,
+}
+
+impl GetWebhookRequestBuilder {
+    /// Sets the [`webhook_id`](struct.GetWebhookRequest.html#structfield.webhook_id) member.
+    pub fn webhook_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.webhook_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetWebhookRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetWebhookRequest, ::rusoto_core::BuildError> {
+        Ok(GetWebhookRequest {
+            webhook_id: self
+                .webhook_id
+                .ok_or(::rusoto_core::BuildError::missing("webhook_id"))?,
+        })
+    }
+}
+
 /// <p> Result structure for the get webhook request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -670,7 +1625,7 @@ pub struct GetWebhookResult {
 }
 
 /// <p> Structure for an execution job for an Amplify App. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Job {
     /// <p> Execution steps for an execution job, for an Amplify App. </p>
@@ -681,8 +1636,106 @@ pub struct Job {
     pub summary: JobSummary,
 }
 
+/// <p> Status for the Job. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobStatus {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>PROVISIONING</code></p>
+    Provisioning,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>SUCCEED</code></p>
+    Succeed,
+    /// <p><code>CANCELLING</code></p>
+    Cancelling,
+    /// <p><code>CANCELLED</code></p>
+    Cancelled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::Pending => "PENDING",
+            JobStatus::Provisioning => "PROVISIONING",
+            JobStatus::Running => "RUNNING",
+            JobStatus::Failed => "FAILED",
+            JobStatus::Succeed => "SUCCEED",
+            JobStatus::Cancelling => "CANCELLING",
+            JobStatus::Cancelled => "CANCELLED",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "PENDING" => JobStatus::Pending,
+            "PROVISIONING" => JobStatus::Provisioning,
+            "RUNNING" => JobStatus::Running,
+            "FAILED" => JobStatus::Failed,
+            "SUCCEED" => JobStatus::Succeed,
+            "CANCELLING" => JobStatus::Cancelling,
+            "CANCELLED" => JobStatus::Cancelled,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        JobStatus::from(value.as_str())
+    }
+}
+
+impl From<JobStatus> for String {
+    fn from(value: JobStatus) -> String {
+        match value {
+            JobStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobStatus::from(value))
+    }
+}
+
 /// <p> Structure for the summary of a Job. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct JobSummary {
     /// <p> Commit Id from 3rd party repository provider for the Job. </p>
@@ -693,11 +1746,13 @@ pub struct JobSummary {
     pub commit_message: String,
     /// <p> Commit date / time for the Job. </p>
     #[serde(rename = "commitTime")]
-    pub commit_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub commit_time: EpochTimestamp,
     /// <p> End date / time for the Job. </p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p> Arn for the Job. </p>
     #[serde(rename = "jobArn")]
     pub job_arn: String,
@@ -706,13 +1761,100 @@ pub struct JobSummary {
     pub job_id: String,
     /// <p> Type for the Job. \n "RELEASE": Manually released from source by using StartJob API. "RETRY": Manually retried by using StartJob API. "WEB_HOOK": Automatically triggered by WebHooks. </p>
     #[serde(rename = "jobType")]
-    pub job_type: String,
+    pub job_type: JobType,
     /// <p> Start date / time for the Job. </p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
     /// <p> Status for the Job. </p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: JobStatus,
+}
+
+/// <p> Type for the Job. \n "RELEASE": Manually released from source by using StartJob API. "RETRY": Manually retried by using StartJob API. "WEB_HOOK": Automatically triggered by WebHooks. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobType {
+    /// <p><code>RELEASE</code></p>
+    Release,
+    /// <p><code>RETRY</code></p>
+    Retry,
+    /// <p><code>MANUAL</code></p>
+    Manual,
+    /// <p><code>WEB_HOOK</code></p>
+    WebHook,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobType::Release => "RELEASE",
+            JobType::Retry => "RETRY",
+            JobType::Manual => "MANUAL",
+            JobType::WebHook => "WEB_HOOK",
+            JobType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobType {
+    fn from(value: &'a str) -> JobType {
+        match value {
+            "RELEASE" => JobType::Release,
+            "RETRY" => JobType::Retry,
+            "MANUAL" => JobType::Manual,
+            "WEB_HOOK" => JobType::WebHook,
+            _ => JobType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobType {
+    fn from(value: String) -> JobType {
+        JobType::from(value.as_str())
+    }
+}
+
+impl From<JobType> for String {
+    fn from(value: JobType) -> String {
+        match value {
+            JobType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobType::from(value))
+    }
 }
 
 /// <p> Request structure for an Amplify App list request. </p>
@@ -728,6 +1870,42 @@ pub struct ListAppsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListAppsRequest {
+    /// Returns a builder for `ListAppsRequest`, checking its required members when built.
+    pub fn builder() -> ListAppsRequestBuilder {
+        ListAppsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAppsRequest`](struct.ListAppsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAppsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAppsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListAppsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAppsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAppsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListAppsRequest, ::rusoto_core::BuildError> {
+        Ok(ListAppsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p> Result structure for an Amplify App list request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -757,6 +1935,52 @@ pub struct ListBranchesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListBranchesRequest {
+    /// Returns a builder for `ListBranchesRequest`, checking its required members when built.
+    pub fn builder() -> ListBranchesRequestBuilder {
+        ListBranchesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListBranchesRequest`](struct.ListBranchesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListBranchesRequestBuilder {
+    app_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListBranchesRequestBuilder {
+    /// Sets the [`app_id`](struct.ListBranchesRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListBranchesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListBranchesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBranchesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListBranchesRequest, ::rusoto_core::BuildError> {
+        Ok(ListBranchesRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p> Result structure for list branches request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -786,6 +2010,54 @@ pub struct ListDomainAssociationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDomainAssociationsRequest {
+    /// Returns a builder for `ListDomainAssociationsRequest`, checking its required members when built.
+    pub fn builder() -> ListDomainAssociationsRequestBuilder {
+        ListDomainAssociationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDomainAssociationsRequest`](struct.ListDomainAssociationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDomainAssociationsRequestBuilder {
+    app_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDomainAssociationsRequestBuilder {
+    /// Sets the [`app_id`](struct.ListDomainAssociationsRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDomainAssociationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDomainAssociationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDomainAssociationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDomainAssociationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDomainAssociationsRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p> Result structure for the list Domain Association request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -818,6 +2090,62 @@ pub struct ListJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListJobsRequest {
+    /// Returns a builder for `ListJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListJobsRequestBuilder {
+        ListJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListJobsRequest`](struct.ListJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListJobsRequestBuilder {
+    app_id: Option<String>,
+    branch_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListJobsRequestBuilder {
+    /// Sets the [`app_id`](struct.ListJobsRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.ListJobsRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListJobsRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p> Maximum number of records to list in a single response. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -839,6 +2167,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 /// <p> Response for list tags. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -865,6 +2225,52 @@ pub struct ListWebhooksRequest {
     pub next_token: Option<String>,
 }
 
+impl ListWebhooksRequest {
+    /// Returns a builder for `ListWebhooksRequest`, checking its required members when built.
+    pub fn builder() -> ListWebhooksRequestBuilder {
+        ListWebhooksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListWebhooksRequest`](struct.ListWebhooksRequest.html).
+#[derive(Default, Debug)]
+pub struct ListWebhooksRequestBuilder {
+    app_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListWebhooksRequestBuilder {
+    /// Sets the [`app_id`](struct.ListWebhooksRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListWebhooksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListWebhooksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListWebhooksRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListWebhooksRequest, ::rusoto_core::BuildError> {
+        Ok(ListWebhooksRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p> Result structure for the list webhooks request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -878,6 +2284,88 @@ pub struct ListWebhooksResult {
     pub webhooks: Vec<Webhook>,
 }
 
+/// <p> Platform for the Amplify App. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Platform {
+    /// <p><code>WEB</code></p>
+    Web,
+    /// <p><code>WEB_DYNAMIC</code></p>
+    WebDynamic,
+    /// <p><code>WEB_COMPUTE</code></p>
+    WebCompute,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Platform {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Platform::Web => "WEB",
+            Platform::WebDynamic => "WEB_DYNAMIC",
+            Platform::WebCompute => "WEB_COMPUTE",
+            Platform::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Platform {
+    fn from(value: &'a str) -> Platform {
+        match value {
+            "WEB" => Platform::Web,
+            "WEB_DYNAMIC" => Platform::WebDynamic,
+            "WEB_COMPUTE" => Platform::WebCompute,
+            _ => Platform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Platform {
+    fn from(value: String) -> Platform {
+        Platform::from(value.as_str())
+    }
+}
+
+impl From<Platform> for String {
+    fn from(value: Platform) -> String {
+        match value {
+            Platform::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Platform {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Platform {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Platform {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Platform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Platform::from(value))
+    }
+}
+
 /// <p> Structure with Production Branch information. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -888,8 +2376,9 @@ pub struct ProductionBranch {
     pub branch_name: Option<String>,
     /// <p> Last Deploy Time of Production Branch. </p>
     #[serde(rename = "lastDeployTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_deploy_time: Option<f64>,
+    pub last_deploy_time: Option<EpochTimestamp>,
     /// <p> Status of Production Branch. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -900,6 +2389,96 @@ pub struct ProductionBranch {
     pub thumbnail_url: Option<String>,
 }
 
+/// <p> Stage for the auto created branch. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Stage {
+    /// <p><code>PRODUCTION</code></p>
+    Production,
+    /// <p><code>BETA</code></p>
+    Beta,
+    /// <p><code>DEVELOPMENT</code></p>
+    Development,
+    /// <p><code>EXPERIMENTAL</code></p>
+    Experimental,
+    /// <p><code>PULL_REQUEST</code></p>
+    PullRequest,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Stage {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Stage::Production => "PRODUCTION",
+            Stage::Beta => "BETA",
+            Stage::Development => "DEVELOPMENT",
+            Stage::Experimental => "EXPERIMENTAL",
+            Stage::PullRequest => "PULL_REQUEST",
+            Stage::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Stage {
+    fn from(value: &'a str) -> Stage {
+        match value {
+            "PRODUCTION" => Stage::Production,
+            "BETA" => Stage::Beta,
+            "DEVELOPMENT" => Stage::Development,
+            "EXPERIMENTAL" => Stage::Experimental,
+            "PULL_REQUEST" => Stage::PullRequest,
+            _ => Stage::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Stage {
+    fn from(value: String) -> Stage {
+        Stage::from(value.as_str())
+    }
+}
+
+impl From<Stage> for String {
+    fn from(value: Stage) -> String {
+        match value {
+            Stage::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Stage {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Stage {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Stage {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Stage, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Stage::from(value))
+    }
+}
+
 /// <p> Request structure for start a deployment. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartDeploymentRequest {
@@ -919,8 +2498,64 @@ pub struct StartDeploymentRequest {
     pub source_url: Option<String>,
 }
 
+impl StartDeploymentRequest {
+    /// Returns a builder for `StartDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> StartDeploymentRequestBuilder {
+        StartDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartDeploymentRequest`](struct.StartDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct StartDeploymentRequestBuilder {
+    app_id: Option<String>,
+    branch_name: Option<String>,
+    job_id: Option<String>,
+    source_url: Option<String>,
+}
+
+impl StartDeploymentRequestBuilder {
+    /// Sets the [`app_id`](struct.StartDeploymentRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.StartDeploymentRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.StartDeploymentRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_url`](struct.StartDeploymentRequest.html#structfield.source_url) member.
+    pub fn source_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(StartDeploymentRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            job_id: self.job_id,
+            source_url: self.source_url,
+        })
+    }
+}
+
 /// <p> Result structure for start a deployment. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartDeploymentResult {
     /// <p> Summary for the Job. </p>
@@ -929,7 +2564,7 @@ pub struct StartDeploymentResult {
 }
 
 /// <p> Request structure for Start job request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct StartJobRequest {
     /// <p> Unique Id for an Amplify App. </p>
     #[serde(rename = "appId")]
@@ -947,8 +2582,9 @@ pub struct StartJobRequest {
     pub commit_message: Option<String>,
     /// <p> Commit date / time for the Job. </p>
     #[serde(rename = "commitTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub commit_time: Option<f64>,
+    pub commit_time: Option<EpochTimestamp>,
     /// <p> Unique Id for an existing job. Required for "RETRY" JobType. </p>
     #[serde(rename = "jobId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -959,11 +2595,101 @@ pub struct StartJobRequest {
     pub job_reason: Option<String>,
     /// <p> Type for the Job. Available JobTypes are: \n "RELEASE": Start a new job with the latest change from the specified branch. Only available for apps that have connected to a repository. "RETRY": Retry an existing job. JobId is required for this type of job. </p>
     #[serde(rename = "jobType")]
-    pub job_type: String,
+    pub job_type: JobType,
+}
+
+impl StartJobRequest {
+    /// Returns a builder for `StartJobRequest`, checking its required members when built.
+    pub fn builder() -> StartJobRequestBuilder {
+        StartJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartJobRequest`](struct.StartJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StartJobRequestBuilder {
+    app_id: Option<String>,
+    branch_name: Option<String>,
+    commit_id: Option<String>,
+    commit_message: Option<String>,
+    commit_time: Option<EpochTimestamp>,
+    job_id: Option<String>,
+    job_reason: Option<String>,
+    job_type: Option<JobType>,
+}
+
+impl StartJobRequestBuilder {
+    /// Sets the [`app_id`](struct.StartJobRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.StartJobRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_id`](struct.StartJobRequest.html#structfield.commit_id) member.
+    pub fn commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_message`](struct.StartJobRequest.html#structfield.commit_message) member.
+    pub fn commit_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_time`](struct.StartJobRequest.html#structfield.commit_time) member.
+    pub fn commit_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.commit_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.StartJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_reason`](struct.StartJobRequest.html#structfield.job_reason) member.
+    pub fn job_reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_reason = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_type`](struct.StartJobRequest.html#structfield.job_type) member.
+    pub fn job_type<T: Into<JobType>>(mut self, value: T) -> Self {
+        self.job_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartJobRequest, ::rusoto_core::BuildError> {
+        Ok(StartJobRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            commit_id: self.commit_id,
+            commit_message: self.commit_message,
+            commit_time: self.commit_time,
+            job_id: self.job_id,
+            job_reason: self.job_reason,
+            job_type: self
+                .job_type
+                .ok_or(::rusoto_core::BuildError::missing("job_type"))?,
+        })
+    }
 }
 
 /// <p> Result structure for run job request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartJobResult {
     /// <p> Summary for the Job. </p>
@@ -972,7 +2698,7 @@ pub struct StartJobResult {
 }
 
 /// <p> Structure for an execution step for an execution job, for an Amplify App. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Step {
     /// <p> URL to the artifact for the execution step. </p>
@@ -985,7 +2711,8 @@ pub struct Step {
     pub context: Option<String>,
     /// <p> End date/ time of the execution step. </p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p> URL to the logs for the execution step. </p>
     #[serde(rename = "logUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -996,10 +2723,11 @@ pub struct Step {
     pub screenshots: Option<::std::collections::HashMap<String, String>>,
     /// <p> Start date/ time of the execution step. </p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
     /// <p> Status of the execution step. </p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: JobStatus,
     /// <p> The reason for current step status. </p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1023,8 +2751,58 @@ pub struct StopJobRequest {
     pub job_id: String,
 }
 
+impl StopJobRequest {
+    /// Returns a builder for `StopJobRequest`, checking its required members when built.
+    pub fn builder() -> StopJobRequestBuilder {
+        StopJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopJobRequest`](struct.StopJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StopJobRequestBuilder {
+    app_id: Option<String>,
+    branch_name: Option<String>,
+    job_id: Option<String>,
+}
+
+impl StopJobRequestBuilder {
+    /// Sets the [`app_id`](struct.StopJobRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.StopJobRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.StopJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopJobRequest, ::rusoto_core::BuildError> {
+        Ok(StopJobRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 /// <p> Result structure for the stop job request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopJobResult {
     /// <p> Summary for the Job. </p>
@@ -1069,6 +2847,46 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p> Response for tag resource. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1085,6 +2903,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p> Response for untag resource. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1147,11 +3005,151 @@ pub struct UpdateAppRequest {
     /// <p> Platform for an Amplify App. </p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
+}
+
+impl UpdateAppRequest {
+    /// Returns a builder for `UpdateAppRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAppRequestBuilder {
+        UpdateAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAppRequest`](struct.UpdateAppRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAppRequestBuilder {
+    app_id: Option<String>,
+    auto_branch_creation_config: Option<AutoBranchCreationConfig>,
+    auto_branch_creation_patterns: Option<Vec<String>>,
+    basic_auth_credentials: Option<String>,
+    build_spec: Option<String>,
+    custom_rules: Option<Vec<CustomRule>>,
+    description: Option<String>,
+    enable_auto_branch_creation: Option<bool>,
+    enable_basic_auth: Option<bool>,
+    enable_branch_auto_build: Option<bool>,
+    environment_variables: Option<::std::collections::HashMap<String, String>>,
+    iam_service_role_arn: Option<String>,
+    name: Option<String>,
+    platform: Option<Platform>,
+}
+
+impl UpdateAppRequestBuilder {
+    /// Sets the [`app_id`](struct.UpdateAppRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_branch_creation_config`](struct.UpdateAppRequest.html#structfield.auto_branch_creation_config) member.
+    pub fn auto_branch_creation_config<T: Into<AutoBranchCreationConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.auto_branch_creation_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_branch_creation_patterns`](struct.UpdateAppRequest.html#structfield.auto_branch_creation_patterns) member.
+    pub fn auto_branch_creation_patterns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.auto_branch_creation_patterns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`basic_auth_credentials`](struct.UpdateAppRequest.html#structfield.basic_auth_credentials) member.
+    pub fn basic_auth_credentials<T: Into<String>>(mut self, value: T) -> Self {
+        self.basic_auth_credentials = Some(value.into());
+        self
+    }
+
+    /// Sets the [`build_spec`](struct.UpdateAppRequest.html#structfield.build_spec) member.
+    pub fn build_spec<T: Into<String>>(mut self, value: T) -> Self {
+        self.build_spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_rules`](struct.UpdateAppRequest.html#structfield.custom_rules) member.
+    pub fn custom_rules<T: Into<Vec<CustomRule>>>(mut self, value: T) -> Self {
+        self.custom_rules = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateAppRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_auto_branch_creation`](struct.UpdateAppRequest.html#structfield.enable_auto_branch_creation) member.
+    pub fn enable_auto_branch_creation<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_auto_branch_creation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_basic_auth`](struct.UpdateAppRequest.html#structfield.enable_basic_auth) member.
+    pub fn enable_basic_auth<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_basic_auth = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_branch_auto_build`](struct.UpdateAppRequest.html#structfield.enable_branch_auto_build) member.
+    pub fn enable_branch_auto_build<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_branch_auto_build = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_variables`](struct.UpdateAppRequest.html#structfield.environment_variables) member.
+    pub fn environment_variables<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.environment_variables = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_service_role_arn`](struct.UpdateAppRequest.html#structfield.iam_service_role_arn) member.
+    pub fn iam_service_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_service_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateAppRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform`](struct.UpdateAppRequest.html#structfield.platform) member.
+    pub fn platform<T: Into<Platform>>(mut self, value: T) -> Self {
+        self.platform = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateAppRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAppRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            auto_branch_creation_config: self.auto_branch_creation_config,
+            auto_branch_creation_patterns: self.auto_branch_creation_patterns,
+            basic_auth_credentials: self.basic_auth_credentials,
+            build_spec: self.build_spec,
+            custom_rules: self.custom_rules,
+            description: self.description,
+            enable_auto_branch_creation: self.enable_auto_branch_creation,
+            enable_basic_auth: self.enable_basic_auth,
+            enable_branch_auto_build: self.enable_branch_auto_build,
+            environment_variables: self.environment_variables,
+            iam_service_role_arn: self.iam_service_role_arn,
+            name: self.name,
+            platform: self.platform,
+        })
+    }
 }
 
 /// <p> Result structure for an Amplify App update request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateAppResult {
     /// <p> App structure for the updated App. </p>
@@ -1207,15 +3205,146 @@ pub struct UpdateBranchRequest {
     /// <p> Stage for the branch. </p>
     #[serde(rename = "stage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stage: Option<String>,
+    pub stage: Option<Stage>,
     /// <p> The content TTL for the website in seconds. </p>
     #[serde(rename = "ttl")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ttl: Option<String>,
 }
 
+impl UpdateBranchRequest {
+    /// Returns a builder for `UpdateBranchRequest`, checking its required members when built.
+    pub fn builder() -> UpdateBranchRequestBuilder {
+        UpdateBranchRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateBranchRequest`](struct.UpdateBranchRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateBranchRequestBuilder {
+    app_id: Option<String>,
+    basic_auth_credentials: Option<String>,
+    branch_name: Option<String>,
+    build_spec: Option<String>,
+    description: Option<String>,
+    display_name: Option<String>,
+    enable_auto_build: Option<bool>,
+    enable_basic_auth: Option<bool>,
+    enable_notification: Option<bool>,
+    environment_variables: Option<::std::collections::HashMap<String, String>>,
+    framework: Option<String>,
+    stage: Option<Stage>,
+    ttl: Option<String>,
+}
+
+impl UpdateBranchRequestBuilder {
+    /// Sets the [`app_id`](struct.UpdateBranchRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`basic_auth_credentials`](struct.UpdateBranchRequest.html#structfield.basic_auth_credentials) member.
+    pub fn basic_auth_credentials<T: Into<String>>(mut self, value: T) -> Self {
+        self.basic_auth_credentials = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.UpdateBranchRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`build_spec`](struct.UpdateBranchRequest.html#structfield.build_spec) member.
+    pub fn build_spec<T: Into<String>>(mut self, value: T) -> Self {
+        self.build_spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateBranchRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.UpdateBranchRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_auto_build`](struct.UpdateBranchRequest.html#structfield.enable_auto_build) member.
+    pub fn enable_auto_build<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_auto_build = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_basic_auth`](struct.UpdateBranchRequest.html#structfield.enable_basic_auth) member.
+    pub fn enable_basic_auth<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_basic_auth = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_notification`](struct.UpdateBranchRequest.html#structfield.enable_notification) member.
+    pub fn enable_notification<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_variables`](struct.UpdateBranchRequest.html#structfield.environment_variables) member.
+    pub fn environment_variables<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.environment_variables = Some(value.into());
+        self
+    }
+
+    /// Sets the [`framework`](struct.UpdateBranchRequest.html#structfield.framework) member.
+    pub fn framework<T: Into<String>>(mut self, value: T) -> Self {
+        self.framework = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage`](struct.UpdateBranchRequest.html#structfield.stage) member.
+    pub fn stage<T: Into<Stage>>(mut self, value: T) -> Self {
+        self.stage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ttl`](struct.UpdateBranchRequest.html#structfield.ttl) member.
+    pub fn ttl<T: Into<String>>(mut self, value: T) -> Self {
+        self.ttl = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateBranchRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateBranchRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateBranchRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            basic_auth_credentials: self.basic_auth_credentials,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            build_spec: self.build_spec,
+            description: self.description,
+            display_name: self.display_name,
+            enable_auto_build: self.enable_auto_build,
+            enable_basic_auth: self.enable_basic_auth,
+            enable_notification: self.enable_notification,
+            environment_variables: self.environment_variables,
+            framework: self.framework,
+            stage: self.stage,
+            ttl: self.ttl,
+        })
+    }
+}
+
 /// <p> Result structure for update branch request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateBranchResult {
     /// <p> Branch structure for an Amplify App. </p>
@@ -1241,8 +3370,68 @@ pub struct UpdateDomainAssociationRequest {
     pub sub_domain_settings: Vec<SubDomainSetting>,
 }
 
+impl UpdateDomainAssociationRequest {
+    /// Returns a builder for `UpdateDomainAssociationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDomainAssociationRequestBuilder {
+        UpdateDomainAssociationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDomainAssociationRequest`](struct.UpdateDomainAssociationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDomainAssociationRequestBuilder {
+    app_id: Option<String>,
+    domain_name: Option<String>,
+    enable_auto_sub_domain: Option<bool>,
+    sub_domain_settings: Option<Vec<SubDomainSetting>>,
+}
+
+impl UpdateDomainAssociationRequestBuilder {
+    /// Sets the [`app_id`](struct.UpdateDomainAssociationRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.UpdateDomainAssociationRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_auto_sub_domain`](struct.UpdateDomainAssociationRequest.html#structfield.enable_auto_sub_domain) member.
+    pub fn enable_auto_sub_domain<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_auto_sub_domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sub_domain_settings`](struct.UpdateDomainAssociationRequest.html#structfield.sub_domain_settings) member.
+    pub fn sub_domain_settings<T: Into<Vec<SubDomainSetting>>>(mut self, value: T) -> Self {
+        self.sub_domain_settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDomainAssociationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDomainAssociationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDomainAssociationRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            enable_auto_sub_domain: self.enable_auto_sub_domain,
+            sub_domain_settings: self
+                .sub_domain_settings
+                .ok_or(::rusoto_core::BuildError::missing("sub_domain_settings"))?,
+        })
+    }
+}
+
 /// <p> Result structure for the update Domain Association request. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDomainAssociationResult {
     /// <p> Domain Association structure. </p>
@@ -1266,6 +3455,52 @@ pub struct UpdateWebhookRequest {
     pub webhook_id: String,
 }
 
+impl UpdateWebhookRequest {
+    /// Returns a builder for `UpdateWebhookRequest`, checking its required members when built.
+    pub fn builder() -> UpdateWebhookRequestBuilder {
+        UpdateWebhookRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateWebhookRequest`](struct.UpdateWebhookRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateWebhookRequestBuilder {
+    branch_name: Option<String>,
+    description: Option<String>,
+    webhook_id: Option<String>,
+}
+
+impl UpdateWebhookRequestBuilder {
+    /// Sets the [`branch_name`](struct.UpdateWebhookRequest.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateWebhookRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`webhook_id`](struct.UpdateWebhookRequest.html#structfield.webhook_id) member.
+    pub fn webhook_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.webhook_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateWebhookRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateWebhookRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateWebhookRequest {
+            branch_name: self.branch_name,
+            description: self.description,
+            webhook_id: self
+                .webhook_id
+                .ok_or(::rusoto_core::BuildError::missing("webhook_id"))?,
+        })
+    }
+}
+
 /// <p> Result structure for the update webhook request. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1284,13 +3519,15 @@ pub struct Webhook {
     pub branch_name: String,
     /// <p> Create date / time for a webhook. </p>
     #[serde(rename = "createTime")]
-    pub create_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub create_time: EpochTimestamp,
     /// <p> Description for a webhook. </p>
     #[serde(rename = "description")]
     pub description: String,
     /// <p> Update date / time for a webhook. </p>
     #[serde(rename = "updateTime")]
-    pub update_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub update_time: EpochTimestamp,
     /// <p> ARN for the webhook. </p>
     #[serde(rename = "webhookArn")]
     pub webhook_arn: String,
@@ -3097,6 +5334,162 @@ pub trait Amplify {
         input: UpdateWebhookRequest,
     ) -> RusotoFuture<UpdateWebhookResult, UpdateWebhookError>;
 }
+impl<T: ?Sized + Amplify> Amplify for ::std::sync::Arc<T> {
+    fn create_app(&self, input: CreateAppRequest) -> RusotoFuture<CreateAppResult, CreateAppError> {
+        (**self).create_app(input)
+    }
+    fn create_branch(
+        &self,
+        input: CreateBranchRequest,
+    ) -> RusotoFuture<CreateBranchResult, CreateBranchError> {
+        (**self).create_branch(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResult, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_domain_association(
+        &self,
+        input: CreateDomainAssociationRequest,
+    ) -> RusotoFuture<CreateDomainAssociationResult, CreateDomainAssociationError> {
+        (**self).create_domain_association(input)
+    }
+    fn create_webhook(
+        &self,
+        input: CreateWebhookRequest,
+    ) -> RusotoFuture<CreateWebhookResult, CreateWebhookError> {
+        (**self).create_webhook(input)
+    }
+    fn delete_app(&self, input: DeleteAppRequest) -> RusotoFuture<DeleteAppResult, DeleteAppError> {
+        (**self).delete_app(input)
+    }
+    fn delete_branch(
+        &self,
+        input: DeleteBranchRequest,
+    ) -> RusotoFuture<DeleteBranchResult, DeleteBranchError> {
+        (**self).delete_branch(input)
+    }
+    fn delete_domain_association(
+        &self,
+        input: DeleteDomainAssociationRequest,
+    ) -> RusotoFuture<DeleteDomainAssociationResult, DeleteDomainAssociationError> {
+        (**self).delete_domain_association(input)
+    }
+    fn delete_job(&self, input: DeleteJobRequest) -> RusotoFuture<DeleteJobResult, DeleteJobError> {
+        (**self).delete_job(input)
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookRequest,
+    ) -> RusotoFuture<DeleteWebhookResult, DeleteWebhookError> {
+        (**self).delete_webhook(input)
+    }
+    fn get_app(&self, input: GetAppRequest) -> RusotoFuture<GetAppResult, GetAppError> {
+        (**self).get_app(input)
+    }
+    fn get_branch(&self, input: GetBranchRequest) -> RusotoFuture<GetBranchResult, GetBranchError> {
+        (**self).get_branch(input)
+    }
+    fn get_domain_association(
+        &self,
+        input: GetDomainAssociationRequest,
+    ) -> RusotoFuture<GetDomainAssociationResult, GetDomainAssociationError> {
+        (**self).get_domain_association(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResult, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_webhook(
+        &self,
+        input: GetWebhookRequest,
+    ) -> RusotoFuture<GetWebhookResult, GetWebhookError> {
+        (**self).get_webhook(input)
+    }
+    fn list_apps(&self, input: ListAppsRequest) -> RusotoFuture<ListAppsResult, ListAppsError> {
+        (**self).list_apps(input)
+    }
+    fn list_branches(
+        &self,
+        input: ListBranchesRequest,
+    ) -> RusotoFuture<ListBranchesResult, ListBranchesError> {
+        (**self).list_branches(input)
+    }
+    fn list_domain_associations(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> RusotoFuture<ListDomainAssociationsResult, ListDomainAssociationsError> {
+        (**self).list_domain_associations(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_webhooks(
+        &self,
+        input: ListWebhooksRequest,
+    ) -> RusotoFuture<ListWebhooksResult, ListWebhooksError> {
+        (**self).list_webhooks(input)
+    }
+    fn start_deployment(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> RusotoFuture<StartDeploymentResult, StartDeploymentError> {
+        (**self).start_deployment(input)
+    }
+    fn start_job(&self, input: StartJobRequest) -> RusotoFuture<StartJobResult, StartJobError> {
+        (**self).start_job(input)
+    }
+    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
+        (**self).stop_job(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_app(&self, input: UpdateAppRequest) -> RusotoFuture<UpdateAppResult, UpdateAppError> {
+        (**self).update_app(input)
+    }
+    fn update_branch(
+        &self,
+        input: UpdateBranchRequest,
+    ) -> RusotoFuture<UpdateBranchResult, UpdateBranchError> {
+        (**self).update_branch(input)
+    }
+    fn update_domain_association(
+        &self,
+        input: UpdateDomainAssociationRequest,
+    ) -> RusotoFuture<UpdateDomainAssociationResult, UpdateDomainAssociationError> {
+        (**self).update_domain_association(input)
+    }
+    fn update_webhook(
+        &self,
+        input: UpdateWebhookRequest,
+    ) -> RusotoFuture<UpdateWebhookResult, UpdateWebhookError> {
+        (**self).update_webhook(input)
+    }
+}
+/// The error codes Amplify retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amplify API.
 #[derive(Clone)]
 pub struct AmplifyClient {
@@ -3112,6 +5505,27 @@ impl AmplifyClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_AMPLIFY`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> AmplifyClient {
+        Self::new(region::Region::from_env_for_service("AMPLIFY"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `amplify`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> AmplifyClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("amplify", "AMPLIFY"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3130,7 +5544,49 @@ impl AmplifyClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AmplifyClient {
-        AmplifyClient { client, region }
+        AmplifyClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "amplify")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "amplify", "amplify", "AMPLIFY")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Amplify` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Amplify + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for AmplifyClient {
+    fn service_name(&self) -> &'static str {
+        "Amplify"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "amplify"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> AmplifyClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon API Gateway clients.
+//!
+//! Makes a single read-only `GetAccount` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_apigateway::{ApiGateway, ApiGatewayClient};
+
+fn main() {
+    let client = ApiGatewayClient::from_env();
+    match client.get_account().sync() {
+        Ok(_) => println!("GetAccount succeeded"),
+        Err(error) => {
+            eprintln!("GetAccount failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
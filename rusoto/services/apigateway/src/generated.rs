@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Access log settings, including the access log format and access log destination ARN.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -66,8 +67,9 @@ pub struct Account {
 pub struct ApiKey {
     /// <p>The timestamp when the API Key was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>An AWS Marketplace customer identifier , when integrating with the AWS SaaS Marketplace.</p>
     #[serde(rename = "customerId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -86,8 +88,9 @@ pub struct ApiKey {
     pub id: Option<String>,
     /// <p>The timestamp when the API Key was last updated.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the API Key.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -120,6 +123,84 @@ pub struct ApiKeyIds {
     pub warnings: Option<Vec<String>>,
 }
 
+/// <p>The source of the API key for metering requests according to a usage plan. Valid values are: <ul><li><code>HEADER</code> to read the API key from the <code>X-API-Key</code> header of a request. </li><li><code>AUTHORIZER</code> to read the API key from the <code>UsageIdentifierKey</code> from a custom authorizer.</li></ul> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApiKeySourceType {
+    /// <p><code>HEADER</code></p>
+    Header,
+    /// <p><code>AUTHORIZER</code></p>
+    Authorizer,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ApiKeySourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApiKeySourceType::Header => "HEADER",
+            ApiKeySourceType::Authorizer => "AUTHORIZER",
+            ApiKeySourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ApiKeySourceType {
+    fn from(value: &'a str) -> ApiKeySourceType {
+        match value {
+            "HEADER" => ApiKeySourceType::Header,
+            "AUTHORIZER" => ApiKeySourceType::Authorizer,
+            _ => ApiKeySourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApiKeySourceType {
+    fn from(value: String) -> ApiKeySourceType {
+        ApiKeySourceType::from(value.as_str())
+    }
+}
+
+impl From<ApiKeySourceType> for String {
+    fn from(value: ApiKeySourceType) -> String {
+        match value {
+            ApiKeySourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ApiKeySourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApiKeySourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApiKeySourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApiKeySourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ApiKeySourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ApiKeySourceType::from(value))
+    }
+}
+
 /// <p><p>Represents a collection of API keys as represented by an <a>ApiKeys</a> resource.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-api-keys.html">Use API Keys</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -137,6 +218,80 @@ pub struct ApiKeys {
     pub warnings: Option<Vec<String>>,
 }
 
+/// <p>A query parameter to specify the input format to imported API keys. Currently, only the <code>csv</code> format is supported.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApiKeysFormat {
+    /// <p><code>csv</code></p>
+    Csv,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ApiKeysFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApiKeysFormat::Csv => "csv",
+            ApiKeysFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ApiKeysFormat {
+    fn from(value: &'a str) -> ApiKeysFormat {
+        match value {
+            "csv" => ApiKeysFormat::Csv,
+            _ => ApiKeysFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApiKeysFormat {
+    fn from(value: String) -> ApiKeysFormat {
+        ApiKeysFormat::from(value.as_str())
+    }
+}
+
+impl From<ApiKeysFormat> for String {
+    fn from(value: ApiKeysFormat) -> String {
+        match value {
+            ApiKeysFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ApiKeysFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApiKeysFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ApiKeysFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApiKeysFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ApiKeysFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ApiKeysFormat::from(value))
+    }
+}
+
 /// <p>API stage name of the associated API stage in a usage plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ApiStage {
@@ -197,7 +352,89 @@ pub struct Authorizer {
     /// <p>The authorizer type. Valid values are <code>TOKEN</code> for a Lambda function using a single authorization token submitted in a custom header, <code>REQUEST</code> for a Lambda function using incoming request parameters, and <code>COGNITO_USER_POOLS</code> for using an Amazon Cognito user pool.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<AuthorizerType>,
+}
+
+/// <p>The authorizer type. Valid values are <code>TOKEN</code> for a Lambda function using a single authorization token submitted in a custom header, <code>REQUEST</code> for a Lambda function using incoming request parameters, and <code>COGNITO_USER_POOLS</code> for using an Amazon Cognito user pool.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthorizerType {
+    /// <p><code>TOKEN</code></p>
+    Token,
+    /// <p><code>REQUEST</code></p>
+    Request,
+    /// <p><code>COGNITO_USER_POOLS</code></p>
+    CognitoUserPools,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthorizerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthorizerType::Token => "TOKEN",
+            AuthorizerType::Request => "REQUEST",
+            AuthorizerType::CognitoUserPools => "COGNITO_USER_POOLS",
+            AuthorizerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthorizerType {
+    fn from(value: &'a str) -> AuthorizerType {
+        match value {
+            "TOKEN" => AuthorizerType::Token,
+            "REQUEST" => AuthorizerType::Request,
+            "COGNITO_USER_POOLS" => AuthorizerType::CognitoUserPools,
+            _ => AuthorizerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthorizerType {
+    fn from(value: String) -> AuthorizerType {
+        AuthorizerType::from(value.as_str())
+    }
+}
+
+impl From<AuthorizerType> for String {
+    fn from(value: AuthorizerType) -> String {
+        match value {
+            AuthorizerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthorizerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthorizerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthorizerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthorizerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthorizerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthorizerType::from(value))
+    }
 }
 
 /// <p><p>Represents a collection of <a>Authorizer</a> resources.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-use-lambda-authorizer.html">Use Lambda Function as Authorizer</a> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-integrate-with-cognito.html">Use Cognito User Pool as Authorizer</a> </div></p>
@@ -244,6 +481,198 @@ pub struct BasePathMappings {
     pub position: Option<String>,
 }
 
+/// <p>Returns the size of the CacheCluster.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CacheClusterSize {
+    /// <p><code>0.5</code></p>
+    V05,
+    /// <p><code>1.6</code></p>
+    V16,
+    /// <p><code>6.1</code></p>
+    V61,
+    /// <p><code>13.5</code></p>
+    V135,
+    /// <p><code>28.4</code></p>
+    V284,
+    /// <p><code>58.2</code></p>
+    V582,
+    /// <p><code>118</code></p>
+    V118,
+    /// <p><code>237</code></p>
+    V237,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CacheClusterSize {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CacheClusterSize::V05 => "0.5",
+            CacheClusterSize::V16 => "1.6",
+            CacheClusterSize::V61 => "6.1",
+            CacheClusterSize::V135 => "13.5",
+            CacheClusterSize::V284 => "28.4",
+            CacheClusterSize::V582 => "58.2",
+            CacheClusterSize::V118 => "118",
+            CacheClusterSize::V237 => "237",
+            CacheClusterSize::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CacheClusterSize {
+    fn from(value: &'a str) -> CacheClusterSize {
+        match value {
+            "0.5" => CacheClusterSize::V05,
+            "1.6" => CacheClusterSize::V16,
+            "6.1" => CacheClusterSize::V61,
+            "13.5" => CacheClusterSize::V135,
+            "28.4" => CacheClusterSize::V284,
+            "58.2" => CacheClusterSize::V582,
+            "118" => CacheClusterSize::V118,
+            "237" => CacheClusterSize::V237,
+            _ => CacheClusterSize::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CacheClusterSize {
+    fn from(value: String) -> CacheClusterSize {
+        CacheClusterSize::from(value.as_str())
+    }
+}
+
+impl From<CacheClusterSize> for String {
+    fn from(value: CacheClusterSize) -> String {
+        match value {
+            CacheClusterSize::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CacheClusterSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CacheClusterSize {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CacheClusterSize {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CacheClusterSize {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CacheClusterSize, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CacheClusterSize::from(value))
+    }
+}
+
+/// <p>Returns the status of the CacheCluster.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CacheClusterStatus {
+    /// <p><code>CREATE_IN_PROGRESS</code></p>
+    CreateInProgress,
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>DELETE_IN_PROGRESS</code></p>
+    DeleteInProgress,
+    /// <p><code>NOT_AVAILABLE</code></p>
+    NotAvailable,
+    /// <p><code>FLUSH_IN_PROGRESS</code></p>
+    FlushInProgress,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CacheClusterStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CacheClusterStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            CacheClusterStatus::Available => "AVAILABLE",
+            CacheClusterStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            CacheClusterStatus::NotAvailable => "NOT_AVAILABLE",
+            CacheClusterStatus::FlushInProgress => "FLUSH_IN_PROGRESS",
+            CacheClusterStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CacheClusterStatus {
+    fn from(value: &'a str) -> CacheClusterStatus {
+        match value {
+            "CREATE_IN_PROGRESS" => CacheClusterStatus::CreateInProgress,
+            "AVAILABLE" => CacheClusterStatus::Available,
+            "DELETE_IN_PROGRESS" => CacheClusterStatus::DeleteInProgress,
+            "NOT_AVAILABLE" => CacheClusterStatus::NotAvailable,
+            "FLUSH_IN_PROGRESS" => CacheClusterStatus::FlushInProgress,
+            _ => CacheClusterStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CacheClusterStatus {
+    fn from(value: String) -> CacheClusterStatus {
+        CacheClusterStatus::from(value.as_str())
+    }
+}
+
+impl From<CacheClusterStatus> for String {
+    fn from(value: CacheClusterStatus) -> String {
+        match value {
+            CacheClusterStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CacheClusterStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CacheClusterStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CacheClusterStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CacheClusterStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CacheClusterStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CacheClusterStatus::from(value))
+    }
+}
+
 /// <p>Configuration settings of a canary deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CanarySettings {
@@ -275,16 +704,18 @@ pub struct ClientCertificate {
     pub client_certificate_id: Option<String>,
     /// <p>The timestamp when the client certificate was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The description of the client certificate.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The timestamp when the client certificate will expire.</p>
     #[serde(rename = "expirationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_date: Option<f64>,
+    pub expiration_date: Option<EpochTimestamp>,
     /// <p>The PEM-encoded public key of the client certificate, which can be used to configure certificate authentication in the integration endpoint .</p>
     #[serde(rename = "pemEncodedCertificate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -308,6 +739,162 @@ pub struct ClientCertificates {
     pub position: Option<String>,
 }
 
+/// <p>The type of the network connection to the integration endpoint. The valid value is <code>INTERNET</code> for connections through the public routable internet or <code>VPC_LINK</code> for private connections between API Gateway and a network load balancer in a VPC. The default value is <code>INTERNET</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionType {
+    /// <p><code>INTERNET</code></p>
+    Internet,
+    /// <p><code>VPC_LINK</code></p>
+    VpcLink,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConnectionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionType::Internet => "INTERNET",
+            ConnectionType::VpcLink => "VPC_LINK",
+            ConnectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionType {
+    fn from(value: &'a str) -> ConnectionType {
+        match value {
+            "INTERNET" => ConnectionType::Internet,
+            "VPC_LINK" => ConnectionType::VpcLink,
+            _ => ConnectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionType {
+    fn from(value: String) -> ConnectionType {
+        ConnectionType::from(value.as_str())
+    }
+}
+
+impl From<ConnectionType> for String {
+    fn from(value: ConnectionType) -> String {
+        match value {
+            ConnectionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConnectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConnectionType::from(value))
+    }
+}
+
+/// <p>Specifies how to handle request payload content type conversions. Supported values are <code>CONVERT_TO_BINARY</code> and <code>CONVERT_TO_TEXT</code>, with the following behaviors:</p> <ul> <li><p><code>CONVERT_TO_BINARY</code>: Converts a request payload from a Base64-encoded string to the corresponding binary blob.</p></li> <li><p><code>CONVERT_TO_TEXT</code>: Converts a request payload from a binary blob to a Base64-encoded string.</p></li> </ul> <p>If this property is not defined, the request payload will be passed through from the method request to integration request without modification, provided that the <code>passthroughBehavior</code> is configured to support payload pass-through.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContentHandlingStrategy {
+    /// <p><code>CONVERT_TO_BINARY</code> - Converts a request payload from a Base64-encoded string to the corresponding binary blob.</p>
+    ConvertToBinary,
+    /// <p><code>CONVERT_TO_TEXT</code> - Converts a request payload from a binary blob to a Base64-encoded string.</p>
+    ConvertToText,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContentHandlingStrategy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentHandlingStrategy::ConvertToBinary => "CONVERT_TO_BINARY",
+            ContentHandlingStrategy::ConvertToText => "CONVERT_TO_TEXT",
+            ContentHandlingStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContentHandlingStrategy {
+    fn from(value: &'a str) -> ContentHandlingStrategy {
+        match value {
+            "CONVERT_TO_BINARY" => ContentHandlingStrategy::ConvertToBinary,
+            "CONVERT_TO_TEXT" => ContentHandlingStrategy::ConvertToText,
+            _ => ContentHandlingStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentHandlingStrategy {
+    fn from(value: String) -> ContentHandlingStrategy {
+        ContentHandlingStrategy::from(value.as_str())
+    }
+}
+
+impl From<ContentHandlingStrategy> for String {
+    fn from(value: ContentHandlingStrategy) -> String {
+        match value {
+            ContentHandlingStrategy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContentHandlingStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentHandlingStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentHandlingStrategy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentHandlingStrategy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContentHandlingStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContentHandlingStrategy::from(value))
+    }
+}
+
 /// <p>Request to create an <a>ApiKey</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateApiKeyRequest {
@@ -345,23 +932,107 @@ pub struct CreateApiKeyRequest {
     pub value: Option<String>,
 }
 
-/// <p>Request to add a new <a>Authorizer</a> to an existing <a>RestApi</a> resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct CreateAuthorizerRequest {
-    /// <p>Optional customer-defined field, used in OpenAPI imports and exports without functional impact.</p>
-    #[serde(rename = "authType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth_type: Option<String>,
-    /// <p>Specifies the required credentials as an IAM role for API Gateway to invoke the authorizer. To specify an IAM role for API Gateway to assume, use the role's Amazon Resource Name (ARN). To use resource-based permissions on the Lambda function, specify null.</p>
-    #[serde(rename = "authorizerCredentials")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_credentials: Option<String>,
-    /// <p>The TTL in seconds of cached authorizer results. If it equals 0, authorization caching is disabled. If it is greater than 0, API Gateway will cache authorizer responses. If this field is not set, the default value is 300. The maximum value is 3600, or 1 hour.</p>
-    #[serde(rename = "authorizerResultTtlInSeconds")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_result_ttl_in_seconds: Option<i64>,
-    /// <p>Specifies the authorizer's Uniform Resource Identifier (URI). For <code>TOKEN</code> or <code>REQUEST</code> authorizers, this must be a well-formed Lambda function URI, for example, <code>arn:aws:apigateway:us-west-2:lambda:path/2015-03-31/functions/arn:aws:lambda:us-west-2:{account_id}:function:{lambda_function_name}/invocations</code>. In general, the URI has this form <code>arn:aws:apigateway:{region}:lambda:path/{service_api}</code>, where <code>{region}</code> is the same as the region hosting the Lambda function, <code>path</code> indicates that the remaining substring in the URI should be treated as the path to the resource, including the initial <code>/</code>. For Lambda functions, this is usually of the form <code>/2015-03-31/functions/[FunctionARN]/invocations</code>.</p>
-    #[serde(rename = "authorizerUri")]
+impl CreateApiKeyRequest {
+    /// Returns a builder for `CreateApiKeyRequest`, checking its required members when built.
+    pub fn builder() -> CreateApiKeyRequestBuilder {
+        CreateApiKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApiKeyRequest`](struct.CreateApiKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateApiKeyRequestBuilder {
+    customer_id: Option<String>,
+    description: Option<String>,
+    enabled: Option<bool>,
+    generate_distinct_id: Option<bool>,
+    name: Option<String>,
+    stage_keys: Option<Vec<StageKey>>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    value: Option<String>,
+}
+
+impl CreateApiKeyRequestBuilder {
+    /// Sets the [`customer_id`](struct.CreateApiKeyRequest.html#structfield.customer_id) member.
+    pub fn customer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.customer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateApiKeyRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enabled`](struct.CreateApiKeyRequest.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`generate_distinct_id`](struct.CreateApiKeyRequest.html#structfield.generate_distinct_id) member.
+    pub fn generate_distinct_id<T: Into<bool>>(mut self, value: T) -> Self {
+        self.generate_distinct_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateApiKeyRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_keys`](struct.CreateApiKeyRequest.html#structfield.stage_keys) member.
+    pub fn stage_keys<T: Into<Vec<StageKey>>>(mut self, value: T) -> Self {
+        self.stage_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateApiKeyRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`value`](struct.CreateApiKeyRequest.html#structfield.value) member.
+    pub fn value<T: Into<String>>(mut self, value: T) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApiKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateApiKeyRequest, ::rusoto_core::BuildError> {
+        Ok(CreateApiKeyRequest {
+            customer_id: self.customer_id,
+            description: self.description,
+            enabled: self.enabled,
+            generate_distinct_id: self.generate_distinct_id,
+            name: self.name,
+            stage_keys: self.stage_keys,
+            tags: self.tags,
+            value: self.value,
+        })
+    }
+}
+
+/// <p>Request to add a new <a>Authorizer</a> to an existing <a>RestApi</a> resource.</p>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateAuthorizerRequest {
+    /// <p>Optional customer-defined field, used in OpenAPI imports and exports without functional impact.</p>
+    #[serde(rename = "authType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_type: Option<String>,
+    /// <p>Specifies the required credentials as an IAM role for API Gateway to invoke the authorizer. To specify an IAM role for API Gateway to assume, use the role's Amazon Resource Name (ARN). To use resource-based permissions on the Lambda function, specify null.</p>
+    #[serde(rename = "authorizerCredentials")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizer_credentials: Option<String>,
+    /// <p>The TTL in seconds of cached authorizer results. If it equals 0, authorization caching is disabled. If it is greater than 0, API Gateway will cache authorizer responses. If this field is not set, the default value is 300. The maximum value is 3600, or 1 hour.</p>
+    #[serde(rename = "authorizerResultTtlInSeconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizer_result_ttl_in_seconds: Option<i64>,
+    /// <p>Specifies the authorizer's Uniform Resource Identifier (URI). For <code>TOKEN</code> or <code>REQUEST</code> authorizers, this must be a well-formed Lambda function URI, for example, <code>arn:aws:apigateway:us-west-2:lambda:path/2015-03-31/functions/arn:aws:lambda:us-west-2:{account_id}:function:{lambda_function_name}/invocations</code>. In general, the URI has this form <code>arn:aws:apigateway:{region}:lambda:path/{service_api}</code>, where <code>{region}</code> is the same as the region hosting the Lambda function, <code>path</code> indicates that the remaining substring in the URI should be treated as the path to the resource, including the initial <code>/</code>. For Lambda functions, this is usually of the form <code>/2015-03-31/functions/[FunctionARN]/invocations</code>.</p>
+    #[serde(rename = "authorizerUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub authorizer_uri: Option<String>,
     /// <p>The identity source for which authorization is requested. <ul><li>For a <code>TOKEN</code> or <code>COGNITO_USER_POOLS</code> authorizer, this is required and specifies the request header mapping expression for the custom header holding the authorization token submitted by the client. For example, if the token header name is <code>Auth</code>, the header mapping expression is <code>method.request.header.Auth</code>.</li><li>For the <code>REQUEST</code> authorizer, this is required when authorization caching is enabled. The value is a comma-separated string of one or more mapping expressions of the specified request parameters. For example, if an <code>Auth</code> header, a <code>Name</code> query string parameter are defined as identity sources, this value is <code>method.request.header.Auth, method.request.querystring.Name</code>. These parameters will be used to derive the authorization caching key and to perform runtime validation of the <code>REQUEST</code> authorizer by verifying all of the identity-related request parameters are present, not null and non-empty. Only when this is true does the authorizer invoke the authorizer Lambda function, otherwise, it returns a 401 Unauthorized response without calling the Lambda function. The valid value is a string of comma-separated mapping expressions of the specified request parameters. When the authorization caching is not enabled, this property is optional.</li></ul></p>
@@ -384,7 +1055,115 @@ pub struct CreateAuthorizerRequest {
     pub rest_api_id: String,
     /// <p>[Required] The authorizer type. Valid values are <code>TOKEN</code> for a Lambda function using a single authorization token submitted in a custom header, <code>REQUEST</code> for a Lambda function using incoming request parameters, and <code>COGNITO_USER_POOLS</code> for using an Amazon Cognito user pool.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: AuthorizerType,
+}
+
+impl CreateAuthorizerRequest {
+    /// Returns a builder for `CreateAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> CreateAuthorizerRequestBuilder {
+        CreateAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAuthorizerRequest`](struct.CreateAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAuthorizerRequestBuilder {
+    auth_type: Option<String>,
+    authorizer_credentials: Option<String>,
+    authorizer_result_ttl_in_seconds: Option<i64>,
+    authorizer_uri: Option<String>,
+    identity_source: Option<String>,
+    identity_validation_expression: Option<String>,
+    name: Option<String>,
+    provider_ar_ns: Option<Vec<String>>,
+    rest_api_id: Option<String>,
+    type_: Option<AuthorizerType>,
+}
+
+impl CreateAuthorizerRequestBuilder {
+    /// Sets the [`auth_type`](struct.CreateAuthorizerRequest.html#structfield.auth_type) member.
+    pub fn auth_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.auth_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_credentials`](struct.CreateAuthorizerRequest.html#structfield.authorizer_credentials) member.
+    pub fn authorizer_credentials<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_credentials = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_result_ttl_in_seconds`](struct.CreateAuthorizerRequest.html#structfield.authorizer_result_ttl_in_seconds) member.
+    pub fn authorizer_result_ttl_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.authorizer_result_ttl_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_uri`](struct.CreateAuthorizerRequest.html#structfield.authorizer_uri) member.
+    pub fn authorizer_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_source`](struct.CreateAuthorizerRequest.html#structfield.identity_source) member.
+    pub fn identity_source<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_validation_expression`](struct.CreateAuthorizerRequest.html#structfield.identity_validation_expression) member.
+    pub fn identity_validation_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_validation_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateAuthorizerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_ar_ns`](struct.CreateAuthorizerRequest.html#structfield.provider_ar_ns) member.
+    pub fn provider_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.provider_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.CreateAuthorizerRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.CreateAuthorizerRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<AuthorizerType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAuthorizerRequest {
+            auth_type: self.auth_type,
+            authorizer_credentials: self.authorizer_credentials,
+            authorizer_result_ttl_in_seconds: self.authorizer_result_ttl_in_seconds,
+            authorizer_uri: self.authorizer_uri,
+            identity_source: self.identity_source,
+            identity_validation_expression: self.identity_validation_expression,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            provider_ar_ns: self.provider_ar_ns,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 /// <p>Requests API Gateway to create a new <a>BasePathMapping</a> resource.</p>
@@ -406,6 +1185,64 @@ pub struct CreateBasePathMappingRequest {
     pub stage: Option<String>,
 }
 
+impl CreateBasePathMappingRequest {
+    /// Returns a builder for `CreateBasePathMappingRequest`, checking its required members when built.
+    pub fn builder() -> CreateBasePathMappingRequestBuilder {
+        CreateBasePathMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBasePathMappingRequest`](struct.CreateBasePathMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateBasePathMappingRequestBuilder {
+    base_path: Option<String>,
+    domain_name: Option<String>,
+    rest_api_id: Option<String>,
+    stage: Option<String>,
+}
+
+impl CreateBasePathMappingRequestBuilder {
+    /// Sets the [`base_path`](struct.CreateBasePathMappingRequest.html#structfield.base_path) member.
+    pub fn base_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.base_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.CreateBasePathMappingRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.CreateBasePathMappingRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage`](struct.CreateBasePathMappingRequest.html#structfield.stage) member.
+    pub fn stage<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBasePathMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateBasePathMappingRequest, ::rusoto_core::BuildError> {
+        Ok(CreateBasePathMappingRequest {
+            base_path: self.base_path,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            stage: self.stage,
+        })
+    }
+}
+
 /// <p>Requests API Gateway to create a <a>Deployment</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateDeploymentRequest {
@@ -416,7 +1253,7 @@ pub struct CreateDeploymentRequest {
     /// <p>Specifies the cache cluster size for the <a>Stage</a> resource specified in the input, if a cache cluster is enabled.</p>
     #[serde(rename = "cacheClusterSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_cluster_size: Option<String>,
+    pub cache_cluster_size: Option<CacheClusterSize>,
     /// <p>The input configuration for the canary deployment when the deployment is a canary release deployment. </p>
     #[serde(rename = "canarySettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -446,8 +1283,107 @@ pub struct CreateDeploymentRequest {
     pub variables: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateDeploymentRequest {
+    /// Returns a builder for `CreateDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> CreateDeploymentRequestBuilder {
+        CreateDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDeploymentRequest`](struct.CreateDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDeploymentRequestBuilder {
+    cache_cluster_enabled: Option<bool>,
+    cache_cluster_size: Option<CacheClusterSize>,
+    canary_settings: Option<DeploymentCanarySettings>,
+    description: Option<String>,
+    rest_api_id: Option<String>,
+    stage_description: Option<String>,
+    stage_name: Option<String>,
+    tracing_enabled: Option<bool>,
+    variables: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateDeploymentRequestBuilder {
+    /// Sets the [`cache_cluster_enabled`](struct.CreateDeploymentRequest.html#structfield.cache_cluster_enabled) member.
+    pub fn cache_cluster_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.cache_cluster_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_cluster_size`](struct.CreateDeploymentRequest.html#structfield.cache_cluster_size) member.
+    pub fn cache_cluster_size<T: Into<CacheClusterSize>>(mut self, value: T) -> Self {
+        self.cache_cluster_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`canary_settings`](struct.CreateDeploymentRequest.html#structfield.canary_settings) member.
+    pub fn canary_settings<T: Into<DeploymentCanarySettings>>(mut self, value: T) -> Self {
+        self.canary_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateDeploymentRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.CreateDeploymentRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_description`](struct.CreateDeploymentRequest.html#structfield.stage_description) member.
+    pub fn stage_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.CreateDeploymentRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tracing_enabled`](struct.CreateDeploymentRequest.html#structfield.tracing_enabled) member.
+    pub fn tracing_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.tracing_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`variables`](struct.CreateDeploymentRequest.html#structfield.variables) member.
+    pub fn variables<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.variables = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDeploymentRequest {
+            cache_cluster_enabled: self.cache_cluster_enabled,
+            cache_cluster_size: self.cache_cluster_size,
+            canary_settings: self.canary_settings,
+            description: self.description,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            stage_description: self.stage_description,
+            stage_name: self.stage_name,
+            tracing_enabled: self.tracing_enabled,
+            variables: self.variables,
+        })
+    }
+}
+
 /// <p>Creates a new documentation part of a given API.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateDocumentationPartRequest {
     /// <p>[Required] The location of the targeted API entity of the to-be-created documentation part.</p>
     #[serde(rename = "location")]
@@ -460,6 +1396,58 @@ pub struct CreateDocumentationPartRequest {
     pub rest_api_id: String,
 }
 
+impl CreateDocumentationPartRequest {
+    /// Returns a builder for `CreateDocumentationPartRequest`, checking its required members when built.
+    pub fn builder() -> CreateDocumentationPartRequestBuilder {
+        CreateDocumentationPartRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDocumentationPartRequest`](struct.CreateDocumentationPartRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDocumentationPartRequestBuilder {
+    location: Option<DocumentationPartLocation>,
+    properties: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl CreateDocumentationPartRequestBuilder {
+    /// Sets the [`location`](struct.CreateDocumentationPartRequest.html#structfield.location) member.
+    pub fn location<T: Into<DocumentationPartLocation>>(mut self, value: T) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`properties`](struct.CreateDocumentationPartRequest.html#structfield.properties) member.
+    pub fn properties<T: Into<String>>(mut self, value: T) -> Self {
+        self.properties = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.CreateDocumentationPartRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDocumentationPartRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDocumentationPartRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDocumentationPartRequest {
+            location: self
+                .location
+                .ok_or(::rusoto_core::BuildError::missing("location"))?,
+            properties: self
+                .properties
+                .ok_or(::rusoto_core::BuildError::missing("properties"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Creates a new documentation version of a given API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateDocumentationVersionRequest {
@@ -479,6 +1467,64 @@ pub struct CreateDocumentationVersionRequest {
     pub stage_name: Option<String>,
 }
 
+impl CreateDocumentationVersionRequest {
+    /// Returns a builder for `CreateDocumentationVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateDocumentationVersionRequestBuilder {
+        CreateDocumentationVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDocumentationVersionRequest`](struct.CreateDocumentationVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDocumentationVersionRequestBuilder {
+    description: Option<String>,
+    documentation_version: Option<String>,
+    rest_api_id: Option<String>,
+    stage_name: Option<String>,
+}
+
+impl CreateDocumentationVersionRequestBuilder {
+    /// Sets the [`description`](struct.CreateDocumentationVersionRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`documentation_version`](struct.CreateDocumentationVersionRequest.html#structfield.documentation_version) member.
+    pub fn documentation_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.documentation_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.CreateDocumentationVersionRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.CreateDocumentationVersionRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDocumentationVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDocumentationVersionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDocumentationVersionRequest {
+            description: self.description,
+            documentation_version: self
+                .documentation_version
+                .ok_or(::rusoto_core::BuildError::missing("documentation_version"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            stage_name: self.stage_name,
+        })
+    }
+}
+
 /// <p>A request to create a new domain name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateDomainNameRequest {
@@ -520,13 +1566,125 @@ pub struct CreateDomainNameRequest {
     /// <p>The Transport Layer Security (TLS) version + cipher suite for this <a>DomainName</a>. The valid values are <code>TLS_1_0</code> and <code>TLS_1_2</code>.</p>
     #[serde(rename = "securityPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_policy: Option<String>,
+    pub security_policy: Option<SecurityPolicy>,
     /// <p>The key-value map of strings. The valid character set is [a-zA-Z+-=._:/]. The tag key can be up to 128 characters and must not start with <code>aws:</code>. The tag value can be up to 256 characters.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateDomainNameRequest {
+    /// Returns a builder for `CreateDomainNameRequest`, checking its required members when built.
+    pub fn builder() -> CreateDomainNameRequestBuilder {
+        CreateDomainNameRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDomainNameRequest`](struct.CreateDomainNameRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDomainNameRequestBuilder {
+    certificate_arn: Option<String>,
+    certificate_body: Option<String>,
+    certificate_chain: Option<String>,
+    certificate_name: Option<String>,
+    certificate_private_key: Option<String>,
+    domain_name: Option<String>,
+    endpoint_configuration: Option<EndpointConfiguration>,
+    regional_certificate_arn: Option<String>,
+    regional_certificate_name: Option<String>,
+    security_policy: Option<SecurityPolicy>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateDomainNameRequestBuilder {
+    /// Sets the [`certificate_arn`](struct.CreateDomainNameRequest.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_body`](struct.CreateDomainNameRequest.html#structfield.certificate_body) member.
+    pub fn certificate_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_chain`](struct.CreateDomainNameRequest.html#structfield.certificate_chain) member.
+    pub fn certificate_chain<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_chain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_name`](struct.CreateDomainNameRequest.html#structfield.certificate_name) member.
+    pub fn certificate_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_private_key`](struct.CreateDomainNameRequest.html#structfield.certificate_private_key) member.
+    pub fn certificate_private_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_private_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.CreateDomainNameRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_configuration`](struct.CreateDomainNameRequest.html#structfield.endpoint_configuration) member.
+    pub fn endpoint_configuration<T: Into<EndpointConfiguration>>(mut self, value: T) -> Self {
+        self.endpoint_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`regional_certificate_arn`](struct.CreateDomainNameRequest.html#structfield.regional_certificate_arn) member.
+    pub fn regional_certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.regional_certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`regional_certificate_name`](struct.CreateDomainNameRequest.html#structfield.regional_certificate_name) member.
+    pub fn regional_certificate_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.regional_certificate_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_policy`](struct.CreateDomainNameRequest.html#structfield.security_policy) member.
+    pub fn security_policy<T: Into<SecurityPolicy>>(mut self, value: T) -> Self {
+        self.security_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDomainNameRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDomainNameRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDomainNameRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDomainNameRequest {
+            certificate_arn: self.certificate_arn,
+            certificate_body: self.certificate_body,
+            certificate_chain: self.certificate_chain,
+            certificate_name: self.certificate_name,
+            certificate_private_key: self.certificate_private_key,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            endpoint_configuration: self.endpoint_configuration,
+            regional_certificate_arn: self.regional_certificate_arn,
+            regional_certificate_name: self.regional_certificate_name,
+            security_policy: self.security_policy,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p>Request to add a new <a>Model</a> to an existing <a>RestApi</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateModelRequest {
@@ -549,6 +1707,72 @@ pub struct CreateModelRequest {
     pub schema: Option<String>,
 }
 
+impl CreateModelRequest {
+    /// Returns a builder for `CreateModelRequest`, checking its required members when built.
+    pub fn builder() -> CreateModelRequestBuilder {
+        CreateModelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateModelRequest`](struct.CreateModelRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateModelRequestBuilder {
+    content_type: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+    rest_api_id: Option<String>,
+    schema: Option<String>,
+}
+
+impl CreateModelRequestBuilder {
+    /// Sets the [`content_type`](struct.CreateModelRequest.html#structfield.content_type) member.
+    pub fn content_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateModelRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateModelRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.CreateModelRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema`](struct.CreateModelRequest.html#structfield.schema) member.
+    pub fn schema<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateModelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateModelRequest, ::rusoto_core::BuildError> {
+        Ok(CreateModelRequest {
+            content_type: self
+                .content_type
+                .ok_or(::rusoto_core::BuildError::missing("content_type"))?,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            schema: self.schema,
+        })
+    }
+}
+
 /// <p>Creates a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateRequestValidatorRequest {
@@ -569,6 +1793,62 @@ pub struct CreateRequestValidatorRequest {
     pub validate_request_parameters: Option<bool>,
 }
 
+impl CreateRequestValidatorRequest {
+    /// Returns a builder for `CreateRequestValidatorRequest`, checking its required members when built.
+    pub fn builder() -> CreateRequestValidatorRequestBuilder {
+        CreateRequestValidatorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRequestValidatorRequest`](struct.CreateRequestValidatorRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRequestValidatorRequestBuilder {
+    name: Option<String>,
+    rest_api_id: Option<String>,
+    validate_request_body: Option<bool>,
+    validate_request_parameters: Option<bool>,
+}
+
+impl CreateRequestValidatorRequestBuilder {
+    /// Sets the [`name`](struct.CreateRequestValidatorRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.CreateRequestValidatorRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`validate_request_body`](struct.CreateRequestValidatorRequest.html#structfield.validate_request_body) member.
+    pub fn validate_request_body<T: Into<bool>>(mut self, value: T) -> Self {
+        self.validate_request_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`validate_request_parameters`](struct.CreateRequestValidatorRequest.html#structfield.validate_request_parameters) member.
+    pub fn validate_request_parameters<T: Into<bool>>(mut self, value: T) -> Self {
+        self.validate_request_parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRequestValidatorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateRequestValidatorRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRequestValidatorRequest {
+            name: self.name,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            validate_request_body: self.validate_request_body,
+            validate_request_parameters: self.validate_request_parameters,
+        })
+    }
+}
+
 /// <p>Requests API Gateway to create a <a>Resource</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateResourceRequest {
@@ -583,13 +1863,63 @@ pub struct CreateResourceRequest {
     pub rest_api_id: String,
 }
 
+impl CreateResourceRequest {
+    /// Returns a builder for `CreateResourceRequest`, checking its required members when built.
+    pub fn builder() -> CreateResourceRequestBuilder {
+        CreateResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateResourceRequest`](struct.CreateResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateResourceRequestBuilder {
+    parent_id: Option<String>,
+    path_part: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl CreateResourceRequestBuilder {
+    /// Sets the [`parent_id`](struct.CreateResourceRequest.html#structfield.parent_id) member.
+    pub fn parent_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_part`](struct.CreateResourceRequest.html#structfield.path_part) member.
+    pub fn path_part<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_part = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.CreateResourceRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateResourceRequest, ::rusoto_core::BuildError> {
+        Ok(CreateResourceRequest {
+            parent_id: self
+                .parent_id
+                .ok_or(::rusoto_core::BuildError::missing("parent_id"))?,
+            path_part: self
+                .path_part
+                .ok_or(::rusoto_core::BuildError::missing("path_part"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>The POST Request to add a new <a>RestApi</a> resource to your collection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateRestApiRequest {
     /// <p>The source of the API key for metering requests according to a usage plan. Valid values are: <ul><li><code>HEADER</code> to read the API key from the <code>X-API-Key</code> header of a request. </li><li><code>AUTHORIZER</code> to read the API key from the <code>UsageIdentifierKey</code> from a custom authorizer.</li></ul> </p>
     #[serde(rename = "apiKeySource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub api_key_source: Option<String>,
+    pub api_key_source: Option<ApiKeySourceType>,
     /// <p>The list of binary media types supported by the <a>RestApi</a>. By default, the <a>RestApi</a> supports only UTF-8-encoded text payloads.</p>
     #[serde(rename = "binaryMediaTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -627,34 +1957,136 @@ pub struct CreateRestApiRequest {
     pub version: Option<String>,
 }
 
-/// <p>Requests API Gateway to create a <a>Stage</a> resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct CreateStageRequest {
-    /// <p>Whether cache clustering is enabled for the stage.</p>
-    #[serde(rename = "cacheClusterEnabled")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_cluster_enabled: Option<bool>,
-    /// <p>The stage's cache cluster size.</p>
-    #[serde(rename = "cacheClusterSize")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_cluster_size: Option<String>,
-    /// <p>The canary deployment settings of this stage.</p>
-    #[serde(rename = "canarySettings")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub canary_settings: Option<CanarySettings>,
-    /// <p>[Required] The identifier of the <a>Deployment</a> resource for the <a>Stage</a> resource.</p>
-    #[serde(rename = "deploymentId")]
-    pub deployment_id: String,
-    /// <p>The description of the <a>Stage</a> resource.</p>
-    #[serde(rename = "description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The version of the associated API documentation.</p>
-    #[serde(rename = "documentationVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub documentation_version: Option<String>,
-    /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
-    #[serde(rename = "restApiId")]
+impl CreateRestApiRequest {
+    /// Returns a builder for `CreateRestApiRequest`, checking its required members when built.
+    pub fn builder() -> CreateRestApiRequestBuilder {
+        CreateRestApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRestApiRequest`](struct.CreateRestApiRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRestApiRequestBuilder {
+    api_key_source: Option<ApiKeySourceType>,
+    binary_media_types: Option<Vec<String>>,
+    clone_from: Option<String>,
+    description: Option<String>,
+    endpoint_configuration: Option<EndpointConfiguration>,
+    minimum_compression_size: Option<i64>,
+    name: Option<String>,
+    policy: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    version: Option<String>,
+}
+
+impl CreateRestApiRequestBuilder {
+    /// Sets the [`api_key_source`](struct.CreateRestApiRequest.html#structfield.api_key_source) member.
+    pub fn api_key_source<T: Into<ApiKeySourceType>>(mut self, value: T) -> Self {
+        self.api_key_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`binary_media_types`](struct.CreateRestApiRequest.html#structfield.binary_media_types) member.
+    pub fn binary_media_types<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.binary_media_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`clone_from`](struct.CreateRestApiRequest.html#structfield.clone_from) member.
+    pub fn clone_from<T: Into<String>>(mut self, value: T) -> Self {
+        self.clone_from = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateRestApiRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_configuration`](struct.CreateRestApiRequest.html#structfield.endpoint_configuration) member.
+    pub fn endpoint_configuration<T: Into<EndpointConfiguration>>(mut self, value: T) -> Self {
+        self.endpoint_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`minimum_compression_size`](struct.CreateRestApiRequest.html#structfield.minimum_compression_size) member.
+    pub fn minimum_compression_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.minimum_compression_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateRestApiRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.CreateRestApiRequest.html#structfield.policy) member.
+    pub fn policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateRestApiRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.CreateRestApiRequest.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRestApiRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateRestApiRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRestApiRequest {
+            api_key_source: self.api_key_source,
+            binary_media_types: self.binary_media_types,
+            clone_from: self.clone_from,
+            description: self.description,
+            endpoint_configuration: self.endpoint_configuration,
+            minimum_compression_size: self.minimum_compression_size,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            policy: self.policy,
+            tags: self.tags,
+            version: self.version,
+        })
+    }
+}
+
+/// <p>Requests API Gateway to create a <a>Stage</a> resource.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct CreateStageRequest {
+    /// <p>Whether cache clustering is enabled for the stage.</p>
+    #[serde(rename = "cacheClusterEnabled")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_cluster_enabled: Option<bool>,
+    /// <p>The stage's cache cluster size.</p>
+    #[serde(rename = "cacheClusterSize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_cluster_size: Option<CacheClusterSize>,
+    /// <p>The canary deployment settings of this stage.</p>
+    #[serde(rename = "canarySettings")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canary_settings: Option<CanarySettings>,
+    /// <p>[Required] The identifier of the <a>Deployment</a> resource for the <a>Stage</a> resource.</p>
+    #[serde(rename = "deploymentId")]
+    pub deployment_id: String,
+    /// <p>The description of the <a>Stage</a> resource.</p>
+    #[serde(rename = "description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The version of the associated API documentation.</p>
+    #[serde(rename = "documentationVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation_version: Option<String>,
+    /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
+    #[serde(rename = "restApiId")]
     pub rest_api_id: String,
     /// <p>[Required] The name for the <a>Stage</a> resource. Stage names can only contain alphanumeric characters, hyphens, and underscores. Maximum length is 128 characters.</p>
     #[serde(rename = "stageName")]
@@ -673,6 +2105,123 @@ pub struct CreateStageRequest {
     pub variables: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateStageRequest {
+    /// Returns a builder for `CreateStageRequest`, checking its required members when built.
+    pub fn builder() -> CreateStageRequestBuilder {
+        CreateStageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStageRequest`](struct.CreateStageRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateStageRequestBuilder {
+    cache_cluster_enabled: Option<bool>,
+    cache_cluster_size: Option<CacheClusterSize>,
+    canary_settings: Option<CanarySettings>,
+    deployment_id: Option<String>,
+    description: Option<String>,
+    documentation_version: Option<String>,
+    rest_api_id: Option<String>,
+    stage_name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    tracing_enabled: Option<bool>,
+    variables: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateStageRequestBuilder {
+    /// Sets the [`cache_cluster_enabled`](struct.CreateStageRequest.html#structfield.cache_cluster_enabled) member.
+    pub fn cache_cluster_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.cache_cluster_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_cluster_size`](struct.CreateStageRequest.html#structfield.cache_cluster_size) member.
+    pub fn cache_cluster_size<T: Into<CacheClusterSize>>(mut self, value: T) -> Self {
+        self.cache_cluster_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`canary_settings`](struct.CreateStageRequest.html#structfield.canary_settings) member.
+    pub fn canary_settings<T: Into<CanarySettings>>(mut self, value: T) -> Self {
+        self.canary_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_id`](struct.CreateStageRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateStageRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`documentation_version`](struct.CreateStageRequest.html#structfield.documentation_version) member.
+    pub fn documentation_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.documentation_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.CreateStageRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.CreateStageRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateStageRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tracing_enabled`](struct.CreateStageRequest.html#structfield.tracing_enabled) member.
+    pub fn tracing_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.tracing_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`variables`](struct.CreateStageRequest.html#structfield.variables) member.
+    pub fn variables<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.variables = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateStageRequest, ::rusoto_core::BuildError> {
+        Ok(CreateStageRequest {
+            cache_cluster_enabled: self.cache_cluster_enabled,
+            cache_cluster_size: self.cache_cluster_size,
+            canary_settings: self.canary_settings,
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+            description: self.description,
+            documentation_version: self.documentation_version,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+            tags: self.tags,
+            tracing_enabled: self.tracing_enabled,
+            variables: self.variables,
+        })
+    }
+}
+
 /// <p>The POST request to create a usage plan key for adding an existing API key to a usage plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateUsagePlanKeyRequest {
@@ -687,6 +2236,58 @@ pub struct CreateUsagePlanKeyRequest {
     pub usage_plan_id: String,
 }
 
+impl CreateUsagePlanKeyRequest {
+    /// Returns a builder for `CreateUsagePlanKeyRequest`, checking its required members when built.
+    pub fn builder() -> CreateUsagePlanKeyRequestBuilder {
+        CreateUsagePlanKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUsagePlanKeyRequest`](struct.CreateUsagePlanKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUsagePlanKeyRequestBuilder {
+    key_id: Option<String>,
+    key_type: Option<String>,
+    usage_plan_id: Option<String>,
+}
+
+impl CreateUsagePlanKeyRequestBuilder {
+    /// Sets the [`key_id`](struct.CreateUsagePlanKeyRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_type`](struct.CreateUsagePlanKeyRequest.html#structfield.key_type) member.
+    pub fn key_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`usage_plan_id`](struct.CreateUsagePlanKeyRequest.html#structfield.usage_plan_id) member.
+    pub fn usage_plan_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.usage_plan_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUsagePlanKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateUsagePlanKeyRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUsagePlanKeyRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            key_type: self
+                .key_type
+                .ok_or(::rusoto_core::BuildError::missing("key_type"))?,
+            usage_plan_id: self
+                .usage_plan_id
+                .ok_or(::rusoto_core::BuildError::missing("usage_plan_id"))?,
+        })
+    }
+}
+
 /// <p>The POST request to create a usage plan with the name, description, throttle limits and quota limits, as well as the associated API stages, specified in the payload.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateUsagePlanRequest {
@@ -715,6 +2316,76 @@ pub struct CreateUsagePlanRequest {
     pub throttle: Option<ThrottleSettings>,
 }
 
+impl CreateUsagePlanRequest {
+    /// Returns a builder for `CreateUsagePlanRequest`, checking its required members when built.
+    pub fn builder() -> CreateUsagePlanRequestBuilder {
+        CreateUsagePlanRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUsagePlanRequest`](struct.CreateUsagePlanRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUsagePlanRequestBuilder {
+    api_stages: Option<Vec<ApiStage>>,
+    description: Option<String>,
+    name: Option<String>,
+    quota: Option<QuotaSettings>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    throttle: Option<ThrottleSettings>,
+}
+
+impl CreateUsagePlanRequestBuilder {
+    /// Sets the [`api_stages`](struct.CreateUsagePlanRequest.html#structfield.api_stages) member.
+    pub fn api_stages<T: Into<Vec<ApiStage>>>(mut self, value: T) -> Self {
+        self.api_stages = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateUsagePlanRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateUsagePlanRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`quota`](struct.CreateUsagePlanRequest.html#structfield.quota) member.
+    pub fn quota<T: Into<QuotaSettings>>(mut self, value: T) -> Self {
+        self.quota = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateUsagePlanRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`throttle`](struct.CreateUsagePlanRequest.html#structfield.throttle) member.
+    pub fn throttle<T: Into<ThrottleSettings>>(mut self, value: T) -> Self {
+        self.throttle = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUsagePlanRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateUsagePlanRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUsagePlanRequest {
+            api_stages: self.api_stages,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            quota: self.quota,
+            tags: self.tags,
+            throttle: self.throttle,
+        })
+    }
+}
+
 /// <p>Creates a VPC link, under the caller's account in a selected region, in an asynchronous operation that typically takes 2-4 minutes to complete and become operational. The caller must have permissions to create and update VPC Endpoint services.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateVpcLinkRequest {
@@ -734,6 +2405,62 @@ pub struct CreateVpcLinkRequest {
     pub target_arns: Vec<String>,
 }
 
+impl CreateVpcLinkRequest {
+    /// Returns a builder for `CreateVpcLinkRequest`, checking its required members when built.
+    pub fn builder() -> CreateVpcLinkRequestBuilder {
+        CreateVpcLinkRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateVpcLinkRequest`](struct.CreateVpcLinkRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateVpcLinkRequestBuilder {
+    description: Option<String>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    target_arns: Option<Vec<String>>,
+}
+
+impl CreateVpcLinkRequestBuilder {
+    /// Sets the [`description`](struct.CreateVpcLinkRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateVpcLinkRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateVpcLinkRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_arns`](struct.CreateVpcLinkRequest.html#structfield.target_arns) member.
+    pub fn target_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.target_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateVpcLinkRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateVpcLinkRequest, ::rusoto_core::BuildError> {
+        Ok(CreateVpcLinkRequest {
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+            target_arns: self
+                .target_arns
+                .ok_or(::rusoto_core::BuildError::missing("target_arns"))?,
+        })
+    }
+}
+
 /// <p>A request to delete the <a>ApiKey</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteApiKeyRequest {
@@ -742,6 +2469,36 @@ pub struct DeleteApiKeyRequest {
     pub api_key: String,
 }
 
+impl DeleteApiKeyRequest {
+    /// Returns a builder for `DeleteApiKeyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApiKeyRequestBuilder {
+        DeleteApiKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApiKeyRequest`](struct.DeleteApiKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApiKeyRequestBuilder {
+    api_key: Option<String>,
+}
+
+impl DeleteApiKeyRequestBuilder {
+    /// Sets the [`api_key`](struct.DeleteApiKeyRequest.html#structfield.api_key) member.
+    pub fn api_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_key = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApiKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteApiKeyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteApiKeyRequest {
+            api_key: self
+                .api_key
+                .ok_or(::rusoto_core::BuildError::missing("api_key"))?,
+        })
+    }
+}
+
 /// <p>Request to delete an existing <a>Authorizer</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteAuthorizerRequest {
@@ -753,6 +2510,48 @@ pub struct DeleteAuthorizerRequest {
     pub rest_api_id: String,
 }
 
+impl DeleteAuthorizerRequest {
+    /// Returns a builder for `DeleteAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAuthorizerRequestBuilder {
+        DeleteAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAuthorizerRequest`](struct.DeleteAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAuthorizerRequestBuilder {
+    authorizer_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl DeleteAuthorizerRequestBuilder {
+    /// Sets the [`authorizer_id`](struct.DeleteAuthorizerRequest.html#structfield.authorizer_id) member.
+    pub fn authorizer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.DeleteAuthorizerRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAuthorizerRequest {
+            authorizer_id: self
+                .authorizer_id
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>A request to delete the <a>BasePathMapping</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteBasePathMappingRequest {
@@ -764,6 +2563,48 @@ pub struct DeleteBasePathMappingRequest {
     pub domain_name: String,
 }
 
+impl DeleteBasePathMappingRequest {
+    /// Returns a builder for `DeleteBasePathMappingRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBasePathMappingRequestBuilder {
+        DeleteBasePathMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBasePathMappingRequest`](struct.DeleteBasePathMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBasePathMappingRequestBuilder {
+    base_path: Option<String>,
+    domain_name: Option<String>,
+}
+
+impl DeleteBasePathMappingRequestBuilder {
+    /// Sets the [`base_path`](struct.DeleteBasePathMappingRequest.html#structfield.base_path) member.
+    pub fn base_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.base_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DeleteBasePathMappingRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBasePathMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteBasePathMappingRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBasePathMappingRequest {
+            base_path: self
+                .base_path
+                .ok_or(::rusoto_core::BuildError::missing("base_path"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// <p>A request to delete the <a>ClientCertificate</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteClientCertificateRequest {
@@ -772,6 +2613,38 @@ pub struct DeleteClientCertificateRequest {
     pub client_certificate_id: String,
 }
 
+impl DeleteClientCertificateRequest {
+    /// Returns a builder for `DeleteClientCertificateRequest`, checking its required members when built.
+    pub fn builder() -> DeleteClientCertificateRequestBuilder {
+        DeleteClientCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteClientCertificateRequest`](struct.DeleteClientCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteClientCertificateRequestBuilder {
+    client_certificate_id: Option<String>,
+}
+
+impl DeleteClientCertificateRequestBuilder {
+    /// Sets the [`client_certificate_id`](struct.DeleteClientCertificateRequest.html#structfield.client_certificate_id) member.
+    pub fn client_certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_certificate_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteClientCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteClientCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteClientCertificateRequest {
+            client_certificate_id: self
+                .client_certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("client_certificate_id"))?,
+        })
+    }
+}
+
 /// <p>Requests API Gateway to delete a <a>Deployment</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDeploymentRequest {
@@ -783,6 +2656,48 @@ pub struct DeleteDeploymentRequest {
     pub rest_api_id: String,
 }
 
+impl DeleteDeploymentRequest {
+    /// Returns a builder for `DeleteDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDeploymentRequestBuilder {
+        DeleteDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDeploymentRequest`](struct.DeleteDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDeploymentRequestBuilder {
+    deployment_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl DeleteDeploymentRequestBuilder {
+    /// Sets the [`deployment_id`](struct.DeleteDeploymentRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.DeleteDeploymentRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDeploymentRequest {
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Deletes an existing documentation part of an API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDocumentationPartRequest {
@@ -794,6 +2709,48 @@ pub struct DeleteDocumentationPartRequest {
     pub rest_api_id: String,
 }
 
+impl DeleteDocumentationPartRequest {
+    /// Returns a builder for `DeleteDocumentationPartRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDocumentationPartRequestBuilder {
+        DeleteDocumentationPartRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDocumentationPartRequest`](struct.DeleteDocumentationPartRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDocumentationPartRequestBuilder {
+    documentation_part_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl DeleteDocumentationPartRequestBuilder {
+    /// Sets the [`documentation_part_id`](struct.DeleteDocumentationPartRequest.html#structfield.documentation_part_id) member.
+    pub fn documentation_part_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.documentation_part_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.DeleteDocumentationPartRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDocumentationPartRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDocumentationPartRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDocumentationPartRequest {
+            documentation_part_id: self
+                .documentation_part_id
+                .ok_or(::rusoto_core::BuildError::missing("documentation_part_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Deletes an existing documentation version of an API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDocumentationVersionRequest {
@@ -805,6 +2762,48 @@ pub struct DeleteDocumentationVersionRequest {
     pub rest_api_id: String,
 }
 
+impl DeleteDocumentationVersionRequest {
+    /// Returns a builder for `DeleteDocumentationVersionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDocumentationVersionRequestBuilder {
+        DeleteDocumentationVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDocumentationVersionRequest`](struct.DeleteDocumentationVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDocumentationVersionRequestBuilder {
+    documentation_version: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl DeleteDocumentationVersionRequestBuilder {
+    /// Sets the [`documentation_version`](struct.DeleteDocumentationVersionRequest.html#structfield.documentation_version) member.
+    pub fn documentation_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.documentation_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.DeleteDocumentationVersionRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDocumentationVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDocumentationVersionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDocumentationVersionRequest {
+            documentation_version: self
+                .documentation_version
+                .ok_or(::rusoto_core::BuildError::missing("documentation_version"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>A request to delete the <a>DomainName</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDomainNameRequest {
@@ -813,17 +2812,91 @@ pub struct DeleteDomainNameRequest {
     pub domain_name: String,
 }
 
+impl DeleteDomainNameRequest {
+    /// Returns a builder for `DeleteDomainNameRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDomainNameRequestBuilder {
+        DeleteDomainNameRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDomainNameRequest`](struct.DeleteDomainNameRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDomainNameRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl DeleteDomainNameRequestBuilder {
+    /// Sets the [`domain_name`](struct.DeleteDomainNameRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDomainNameRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDomainNameRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDomainNameRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// <p>Clears any customization of a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a> and resets it with the default settings.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteGatewayResponseRequest {
     /// <p>[Required] <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p></p>
     #[serde(rename = "responseType")]
-    pub response_type: String,
+    pub response_type: GatewayResponseType,
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
     pub rest_api_id: String,
 }
 
+impl DeleteGatewayResponseRequest {
+    /// Returns a builder for `DeleteGatewayResponseRequest`, checking its required members when built.
+    pub fn builder() -> DeleteGatewayResponseRequestBuilder {
+        DeleteGatewayResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGatewayResponseRequest`](struct.DeleteGatewayResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteGatewayResponseRequestBuilder {
+    response_type: Option<GatewayResponseType>,
+    rest_api_id: Option<String>,
+}
+
+impl DeleteGatewayResponseRequestBuilder {
+    /// Sets the [`response_type`](struct.DeleteGatewayResponseRequest.html#structfield.response_type) member.
+    pub fn response_type<T: Into<GatewayResponseType>>(mut self, value: T) -> Self {
+        self.response_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.DeleteGatewayResponseRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGatewayResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteGatewayResponseRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteGatewayResponseRequest {
+            response_type: self
+                .response_type
+                .ok_or(::rusoto_core::BuildError::missing("response_type"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Represents a delete integration request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteIntegrationRequest {
@@ -838,10 +2911,62 @@ pub struct DeleteIntegrationRequest {
     pub rest_api_id: String,
 }
 
-/// <p>Represents a delete integration response request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DeleteIntegrationResponseRequest {
-    /// <p>[Required] Specifies a delete integration response request's HTTP method.</p>
+impl DeleteIntegrationRequest {
+    /// Returns a builder for `DeleteIntegrationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIntegrationRequestBuilder {
+        DeleteIntegrationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIntegrationRequest`](struct.DeleteIntegrationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIntegrationRequestBuilder {
+    http_method: Option<String>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl DeleteIntegrationRequestBuilder {
+    /// Sets the [`http_method`](struct.DeleteIntegrationRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DeleteIntegrationRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.DeleteIntegrationRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIntegrationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteIntegrationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIntegrationRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
+/// <p>Represents a delete integration response request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteIntegrationResponseRequest {
+    /// <p>[Required] Specifies a delete integration response request's HTTP method.</p>
     #[serde(rename = "httpMethod")]
     pub http_method: String,
     /// <p>[Required] Specifies a delete integration response request's resource identifier.</p>
@@ -855,6 +2980,68 @@ pub struct DeleteIntegrationResponseRequest {
     pub status_code: String,
 }
 
+impl DeleteIntegrationResponseRequest {
+    /// Returns a builder for `DeleteIntegrationResponseRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIntegrationResponseRequestBuilder {
+        DeleteIntegrationResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIntegrationResponseRequest`](struct.DeleteIntegrationResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIntegrationResponseRequestBuilder {
+    http_method: Option<String>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+    status_code: Option<String>,
+}
+
+impl DeleteIntegrationResponseRequestBuilder {
+    /// Sets the [`http_method`](struct.DeleteIntegrationResponseRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DeleteIntegrationResponseRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.DeleteIntegrationResponseRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_code`](struct.DeleteIntegrationResponseRequest.html#structfield.status_code) member.
+    pub fn status_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.status_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIntegrationResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteIntegrationResponseRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIntegrationResponseRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            status_code: self
+                .status_code
+                .ok_or(::rusoto_core::BuildError::missing("status_code"))?,
+        })
+    }
+}
+
 /// <p>Request to delete an existing <a>Method</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteMethodRequest {
@@ -869,6 +3056,56 @@ pub struct DeleteMethodRequest {
     pub rest_api_id: String,
 }
 
+impl DeleteMethodRequest {
+    /// Returns a builder for `DeleteMethodRequest`, checking its required members when built.
+    pub fn builder() -> DeleteMethodRequestBuilder {
+        DeleteMethodRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMethodRequest`](struct.DeleteMethodRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteMethodRequestBuilder {
+    http_method: Option<String>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl DeleteMethodRequestBuilder {
+    /// Sets the [`http_method`](struct.DeleteMethodRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DeleteMethodRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.DeleteMethodRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMethodRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteMethodRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteMethodRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>A request to delete an existing <a>MethodResponse</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteMethodResponseRequest {
@@ -886,6 +3123,68 @@ pub struct DeleteMethodResponseRequest {
     pub status_code: String,
 }
 
+impl DeleteMethodResponseRequest {
+    /// Returns a builder for `DeleteMethodResponseRequest`, checking its required members when built.
+    pub fn builder() -> DeleteMethodResponseRequestBuilder {
+        DeleteMethodResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMethodResponseRequest`](struct.DeleteMethodResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteMethodResponseRequestBuilder {
+    http_method: Option<String>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+    status_code: Option<String>,
+}
+
+impl DeleteMethodResponseRequestBuilder {
+    /// Sets the [`http_method`](struct.DeleteMethodResponseRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DeleteMethodResponseRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.DeleteMethodResponseRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_code`](struct.DeleteMethodResponseRequest.html#structfield.status_code) member.
+    pub fn status_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.status_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMethodResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteMethodResponseRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteMethodResponseRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            status_code: self
+                .status_code
+                .ok_or(::rusoto_core::BuildError::missing("status_code"))?,
+        })
+    }
+}
+
 /// <p>Request to delete an existing model in an existing <a>RestApi</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteModelRequest {
@@ -897,6 +3196,46 @@ pub struct DeleteModelRequest {
     pub rest_api_id: String,
 }
 
+impl DeleteModelRequest {
+    /// Returns a builder for `DeleteModelRequest`, checking its required members when built.
+    pub fn builder() -> DeleteModelRequestBuilder {
+        DeleteModelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteModelRequest`](struct.DeleteModelRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteModelRequestBuilder {
+    model_name: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl DeleteModelRequestBuilder {
+    /// Sets the [`model_name`](struct.DeleteModelRequest.html#structfield.model_name) member.
+    pub fn model_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.model_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.DeleteModelRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteModelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteModelRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteModelRequest {
+            model_name: self
+                .model_name
+                .ok_or(::rusoto_core::BuildError::missing("model_name"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Deletes a specified <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteRequestValidatorRequest {
@@ -908,6 +3247,48 @@ pub struct DeleteRequestValidatorRequest {
     pub rest_api_id: String,
 }
 
+impl DeleteRequestValidatorRequest {
+    /// Returns a builder for `DeleteRequestValidatorRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRequestValidatorRequestBuilder {
+        DeleteRequestValidatorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRequestValidatorRequest`](struct.DeleteRequestValidatorRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRequestValidatorRequestBuilder {
+    request_validator_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl DeleteRequestValidatorRequestBuilder {
+    /// Sets the [`request_validator_id`](struct.DeleteRequestValidatorRequest.html#structfield.request_validator_id) member.
+    pub fn request_validator_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.request_validator_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.DeleteRequestValidatorRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRequestValidatorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRequestValidatorRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRequestValidatorRequest {
+            request_validator_id: self
+                .request_validator_id
+                .ok_or(::rusoto_core::BuildError::missing("request_validator_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to delete a <a>Resource</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteResourceRequest {
@@ -919,6 +3300,46 @@ pub struct DeleteResourceRequest {
     pub rest_api_id: String,
 }
 
+impl DeleteResourceRequest {
+    /// Returns a builder for `DeleteResourceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteResourceRequestBuilder {
+        DeleteResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteResourceRequest`](struct.DeleteResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteResourceRequestBuilder {
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl DeleteResourceRequestBuilder {
+    /// Sets the [`resource_id`](struct.DeleteResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.DeleteResourceRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteResourceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteResourceRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to delete the specified API from your collection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteRestApiRequest {
@@ -927,6 +3348,36 @@ pub struct DeleteRestApiRequest {
     pub rest_api_id: String,
 }
 
+impl DeleteRestApiRequest {
+    /// Returns a builder for `DeleteRestApiRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRestApiRequestBuilder {
+        DeleteRestApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRestApiRequest`](struct.DeleteRestApiRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRestApiRequestBuilder {
+    rest_api_id: Option<String>,
+}
+
+impl DeleteRestApiRequestBuilder {
+    /// Sets the [`rest_api_id`](struct.DeleteRestApiRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRestApiRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteRestApiRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRestApiRequest {
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Requests API Gateway to delete a <a>Stage</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteStageRequest {
@@ -938,6 +3389,46 @@ pub struct DeleteStageRequest {
     pub stage_name: String,
 }
 
+impl DeleteStageRequest {
+    /// Returns a builder for `DeleteStageRequest`, checking its required members when built.
+    pub fn builder() -> DeleteStageRequestBuilder {
+        DeleteStageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStageRequest`](struct.DeleteStageRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteStageRequestBuilder {
+    rest_api_id: Option<String>,
+    stage_name: Option<String>,
+}
+
+impl DeleteStageRequestBuilder {
+    /// Sets the [`rest_api_id`](struct.DeleteStageRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.DeleteStageRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteStageRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteStageRequest {
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+        })
+    }
+}
+
 /// <p>The DELETE request to delete a usage plan key and remove the underlying API key from the associated usage plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteUsagePlanKeyRequest {
@@ -949,6 +3440,48 @@ pub struct DeleteUsagePlanKeyRequest {
     pub usage_plan_id: String,
 }
 
+impl DeleteUsagePlanKeyRequest {
+    /// Returns a builder for `DeleteUsagePlanKeyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUsagePlanKeyRequestBuilder {
+        DeleteUsagePlanKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUsagePlanKeyRequest`](struct.DeleteUsagePlanKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUsagePlanKeyRequestBuilder {
+    key_id: Option<String>,
+    usage_plan_id: Option<String>,
+}
+
+impl DeleteUsagePlanKeyRequestBuilder {
+    /// Sets the [`key_id`](struct.DeleteUsagePlanKeyRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`usage_plan_id`](struct.DeleteUsagePlanKeyRequest.html#structfield.usage_plan_id) member.
+    pub fn usage_plan_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.usage_plan_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUsagePlanKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteUsagePlanKeyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUsagePlanKeyRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            usage_plan_id: self
+                .usage_plan_id
+                .ok_or(::rusoto_core::BuildError::missing("usage_plan_id"))?,
+        })
+    }
+}
+
 /// <p>The DELETE request to delete a usage plan of a given plan Id.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteUsagePlanRequest {
@@ -957,6 +3490,36 @@ pub struct DeleteUsagePlanRequest {
     pub usage_plan_id: String,
 }
 
+impl DeleteUsagePlanRequest {
+    /// Returns a builder for `DeleteUsagePlanRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUsagePlanRequestBuilder {
+        DeleteUsagePlanRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUsagePlanRequest`](struct.DeleteUsagePlanRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUsagePlanRequestBuilder {
+    usage_plan_id: Option<String>,
+}
+
+impl DeleteUsagePlanRequestBuilder {
+    /// Sets the [`usage_plan_id`](struct.DeleteUsagePlanRequest.html#structfield.usage_plan_id) member.
+    pub fn usage_plan_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.usage_plan_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUsagePlanRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteUsagePlanRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUsagePlanRequest {
+            usage_plan_id: self
+                .usage_plan_id
+                .ok_or(::rusoto_core::BuildError::missing("usage_plan_id"))?,
+        })
+    }
+}
+
 /// <p>Deletes an existing <a>VpcLink</a> of a specified identifier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteVpcLinkRequest {
@@ -965,6 +3528,36 @@ pub struct DeleteVpcLinkRequest {
     pub vpc_link_id: String,
 }
 
+impl DeleteVpcLinkRequest {
+    /// Returns a builder for `DeleteVpcLinkRequest`, checking its required members when built.
+    pub fn builder() -> DeleteVpcLinkRequestBuilder {
+        DeleteVpcLinkRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVpcLinkRequest`](struct.DeleteVpcLinkRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteVpcLinkRequestBuilder {
+    vpc_link_id: Option<String>,
+}
+
+impl DeleteVpcLinkRequestBuilder {
+    /// Sets the [`vpc_link_id`](struct.DeleteVpcLinkRequest.html#structfield.vpc_link_id) member.
+    pub fn vpc_link_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpc_link_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVpcLinkRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteVpcLinkRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteVpcLinkRequest {
+            vpc_link_id: self
+                .vpc_link_id
+                .ok_or(::rusoto_core::BuildError::missing("vpc_link_id"))?,
+        })
+    }
+}
+
 /// <p><p>An immutable representation of a <a>RestApi</a> resource that can be called by users using <a>Stages</a>. A deployment must be associated with a <a>Stage</a> for it to be callable over the Internet.</p> <div class="remarks">To create a deployment, call <code>POST</code> on the <a>Deployments</a> resource of a <a>RestApi</a>. To view, update, or delete a deployment, call <code>GET</code>, <code>PATCH</code>, or <code>DELETE</code> on the specified deployment resource (<code>/restapis/{restapi<em>id}/deployments/{deployment</em>id}</code>).</div> <div class="seeAlso"><a>RestApi</a>, <a>Deployments</a>, <a>Stage</a>, <a href="https://docs.aws.amazon.com/cli/latest/reference/apigateway/get-deployment.html">AWS CLI</a>, <a href="https://aws.amazon.com/tools/">AWS SDKs</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -977,8 +3570,9 @@ pub struct Deployment {
     >,
     /// <p>The date and time that the deployment resource was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The description for the deployment resource.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1052,7 +3646,7 @@ pub struct DocumentationPartIds {
 }
 
 /// <p>Specifies the target API entity to which the documentation applies.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DocumentationPartLocation {
     /// <p>The HTTP verb of a method. It is a valid field for the API entity types of <code>METHOD</code>, <code>PATH_PARAMETER</code>, <code>QUERY_PARAMETER</code>, <code>REQUEST_HEADER</code>, <code>REQUEST_BODY</code>, <code>RESPONSE</code>, <code>RESPONSE_HEADER</code>, and <code>RESPONSE_BODY</code>. The default value is <code>*</code> for any method. When an applicable child entity inherits the content of an entity of the same type with more general specifications of the other <code>location</code> attributes, the child entity's <code>method</code> attribute must match that of the parent entity exactly.</p>
     #[serde(rename = "method")]
@@ -1072,7 +3666,125 @@ pub struct DocumentationPartLocation {
     pub status_code: Option<String>,
     /// <p>[Required] The type of API entity to which the documentation content applies. Valid values are <code>API</code>, <code>AUTHORIZER</code>, <code>MODEL</code>, <code>RESOURCE</code>, <code>METHOD</code>, <code>PATH_PARAMETER</code>, <code>QUERY_PARAMETER</code>, <code>REQUEST_HEADER</code>, <code>REQUEST_BODY</code>, <code>RESPONSE</code>, <code>RESPONSE_HEADER</code>, and <code>RESPONSE_BODY</code>. Content inheritance does not apply to any entity of the <code>API</code>, <code>AUTHORIZER</code>, <code>METHOD</code>, <code>MODEL</code>, <code>REQUEST_BODY</code>, or <code>RESOURCE</code> type.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: DocumentationPartType,
+}
+
+/// <p>[Required] The type of API entity to which the documentation content applies. Valid values are <code>API</code>, <code>AUTHORIZER</code>, <code>MODEL</code>, <code>RESOURCE</code>, <code>METHOD</code>, <code>PATH_PARAMETER</code>, <code>QUERY_PARAMETER</code>, <code>REQUEST_HEADER</code>, <code>REQUEST_BODY</code>, <code>RESPONSE</code>, <code>RESPONSE_HEADER</code>, and <code>RESPONSE_BODY</code>. Content inheritance does not apply to any entity of the <code>API</code>, <code>AUTHORIZER</code>, <code>METHOD</code>, <code>MODEL</code>, <code>REQUEST_BODY</code>, or <code>RESOURCE</code> type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DocumentationPartType {
+    /// <p><code>API</code></p>
+    Api,
+    /// <p><code>AUTHORIZER</code></p>
+    Authorizer,
+    /// <p><code>MODEL</code></p>
+    Model,
+    /// <p><code>RESOURCE</code></p>
+    Resource,
+    /// <p><code>METHOD</code></p>
+    Method,
+    /// <p><code>PATH_PARAMETER</code></p>
+    PathParameter,
+    /// <p><code>QUERY_PARAMETER</code></p>
+    QueryParameter,
+    /// <p><code>REQUEST_HEADER</code></p>
+    RequestHeader,
+    /// <p><code>REQUEST_BODY</code></p>
+    RequestBody,
+    /// <p><code>RESPONSE</code></p>
+    Response,
+    /// <p><code>RESPONSE_HEADER</code></p>
+    ResponseHeader,
+    /// <p><code>RESPONSE_BODY</code></p>
+    ResponseBody,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DocumentationPartType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DocumentationPartType::Api => "API",
+            DocumentationPartType::Authorizer => "AUTHORIZER",
+            DocumentationPartType::Model => "MODEL",
+            DocumentationPartType::Resource => "RESOURCE",
+            DocumentationPartType::Method => "METHOD",
+            DocumentationPartType::PathParameter => "PATH_PARAMETER",
+            DocumentationPartType::QueryParameter => "QUERY_PARAMETER",
+            DocumentationPartType::RequestHeader => "REQUEST_HEADER",
+            DocumentationPartType::RequestBody => "REQUEST_BODY",
+            DocumentationPartType::Response => "RESPONSE",
+            DocumentationPartType::ResponseHeader => "RESPONSE_HEADER",
+            DocumentationPartType::ResponseBody => "RESPONSE_BODY",
+            DocumentationPartType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DocumentationPartType {
+    fn from(value: &'a str) -> DocumentationPartType {
+        match value {
+            "API" => DocumentationPartType::Api,
+            "AUTHORIZER" => DocumentationPartType::Authorizer,
+            "MODEL" => DocumentationPartType::Model,
+            "RESOURCE" => DocumentationPartType::Resource,
+            "METHOD" => DocumentationPartType::Method,
+            "PATH_PARAMETER" => DocumentationPartType::PathParameter,
+            "QUERY_PARAMETER" => DocumentationPartType::QueryParameter,
+            "REQUEST_HEADER" => DocumentationPartType::RequestHeader,
+            "REQUEST_BODY" => DocumentationPartType::RequestBody,
+            "RESPONSE" => DocumentationPartType::Response,
+            "RESPONSE_HEADER" => DocumentationPartType::ResponseHeader,
+            "RESPONSE_BODY" => DocumentationPartType::ResponseBody,
+            _ => DocumentationPartType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DocumentationPartType {
+    fn from(value: String) -> DocumentationPartType {
+        DocumentationPartType::from(value.as_str())
+    }
+}
+
+impl From<DocumentationPartType> for String {
+    fn from(value: DocumentationPartType) -> String {
+        match value {
+            DocumentationPartType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DocumentationPartType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DocumentationPartType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DocumentationPartType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DocumentationPartType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DocumentationPartType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DocumentationPartType::from(value))
+    }
 }
 
 /// <p><p>The collection of documentation parts of an API.</p> <div class="remarks"/> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-documenting-api.html">Documenting an API</a>, <a>DocumentationPart</a> </div></p>
@@ -1094,8 +3806,9 @@ pub struct DocumentationParts {
 pub struct DocumentationVersion {
     /// <p>The date when the API documentation snapshot is created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The description of the API documentation snapshot.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1133,8 +3846,9 @@ pub struct DomainName {
     pub certificate_name: Option<String>,
     /// <p>The timestamp when the certificate that was used by edge-optimized endpoint for this domain name was uploaded.</p>
     #[serde(rename = "certificateUploadDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_upload_date: Option<f64>,
+    pub certificate_upload_date: Option<EpochTimestamp>,
     /// <p>The domain name of the Amazon CloudFront distribution associated with this custom domain name for an edge-optimized endpoint. You set up this association when adding a DNS record pointing the custom domain name to this distribution name. For more information about CloudFront distributions, see the <a href="https://aws.amazon.com/documentation/cloudfront/" target="_blank">Amazon CloudFront documentation</a>.</p>
     #[serde(rename = "distributionDomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1150,7 +3864,7 @@ pub struct DomainName {
     /// <p>The status of the <a>DomainName</a> migration. The valid values are <code>AVAILABLE</code> and <code>UPDATING</code>. If the status is <code>UPDATING</code>, the domain cannot be modified further until the existing operation is complete. If it is <code>AVAILABLE</code>, the domain can be updated.</p>
     #[serde(rename = "domainNameStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_name_status: Option<String>,
+    pub domain_name_status: Option<DomainNameStatus>,
     /// <p>An optional text message containing detailed information about status of the <a>DomainName</a> migration.</p>
     #[serde(rename = "domainNameStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1178,13 +3892,103 @@ pub struct DomainName {
     /// <p>The Transport Layer Security (TLS) version + cipher suite for this <a>DomainName</a>. The valid values are <code>TLS_1_0</code> and <code>TLS_1_2</code>.</p>
     #[serde(rename = "securityPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_policy: Option<String>,
+    pub security_policy: Option<SecurityPolicy>,
     /// <p>The collection of tags. Each tag element is associated with a given resource.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+/// <p>The status of the <a>DomainName</a> migration. The valid values are <code>AVAILABLE</code> and <code>UPDATING</code>. If the status is <code>UPDATING</code>, the domain cannot be modified further until the existing operation is complete. If it is <code>AVAILABLE</code>, the domain can be updated.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DomainNameStatus {
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>PENDING_CERTIFICATE_REIMPORT</code></p>
+    PendingCertificateReimport,
+    /// <p><code>PENDING_OWNERSHIP_VERIFICATION</code></p>
+    PendingOwnershipVerification,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DomainNameStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainNameStatus::Available => "AVAILABLE",
+            DomainNameStatus::Updating => "UPDATING",
+            DomainNameStatus::Pending => "PENDING",
+            DomainNameStatus::PendingCertificateReimport => "PENDING_CERTIFICATE_REIMPORT",
+            DomainNameStatus::PendingOwnershipVerification => "PENDING_OWNERSHIP_VERIFICATION",
+            DomainNameStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DomainNameStatus {
+    fn from(value: &'a str) -> DomainNameStatus {
+        match value {
+            "AVAILABLE" => DomainNameStatus::Available,
+            "UPDATING" => DomainNameStatus::Updating,
+            "PENDING" => DomainNameStatus::Pending,
+            "PENDING_CERTIFICATE_REIMPORT" => DomainNameStatus::PendingCertificateReimport,
+            "PENDING_OWNERSHIP_VERIFICATION" => DomainNameStatus::PendingOwnershipVerification,
+            _ => DomainNameStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainNameStatus {
+    fn from(value: String) -> DomainNameStatus {
+        DomainNameStatus::from(value.as_str())
+    }
+}
+
+impl From<DomainNameStatus> for String {
+    fn from(value: DomainNameStatus) -> String {
+        match value {
+            DomainNameStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DomainNameStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainNameStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainNameStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainNameStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DomainNameStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DomainNameStatus::from(value))
+    }
+}
+
 /// <p><p>Represents a collection of <a>DomainName</a> resources.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-custom-domains.html">Use Client-Side Certificate</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1204,35 +4008,159 @@ pub struct EndpointConfiguration {
     /// <p>A list of endpoint types of an API (<a>RestApi</a>) or its custom domain name (<a>DomainName</a>). For an edge-optimized API and its custom domain name, the endpoint type is <code>"EDGE"</code>. For a regional API and its custom domain name, the endpoint type is <code>REGIONAL</code>. For a private API, the endpoint type is <code>PRIVATE</code>.</p>
     #[serde(rename = "types")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub types: Option<Vec<String>>,
+    pub types: Option<Vec<EndpointType>>,
 }
 
-/// <p>The binary blob response to <a>GetExport</a>, which contains the generated SDK.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct ExportResponse {
-    /// <p>The binary blob response to <a>GetExport</a>, which contains the export.</p>
-    pub body: Option<bytes::Bytes>,
-    /// <p>The content-disposition header value in the HTTP response.</p>
-    pub content_disposition: Option<String>,
-    /// <p>The content-type header value in the HTTP response. This will correspond to a valid 'accept' type in the request.</p>
-    pub content_type: Option<String>,
+/// <p>The endpoint type. The valid values are <code>EDGE</code> for edge-optimized API setup, most suitable for mobile applications; <code>REGIONAL</code> for regional API endpoint setup, most suitable for calling from AWS Region; and <code>PRIVATE</code> for private APIs.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EndpointType {
+    /// <p><code>REGIONAL</code></p>
+    Regional,
+    /// <p><code>EDGE</code></p>
+    Edge,
+    /// <p><code>PRIVATE</code></p>
+    Private,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Request to flush authorizer cache entries on a specified stage.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct FlushStageAuthorizersCacheRequest {
-    /// <p>The string identifier of the associated <a>RestApi</a>.</p>
-    #[serde(rename = "restApiId")]
-    pub rest_api_id: String,
-    /// <p>The name of the stage to flush.</p>
-    #[serde(rename = "stageName")]
-    pub stage_name: String,
+impl EndpointType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EndpointType::Regional => "REGIONAL",
+            EndpointType::Edge => "EDGE",
+            EndpointType::Private => "PRIVATE",
+            EndpointType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Requests API Gateway to flush a stage's cache.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct FlushStageCacheRequest {
-    /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
+impl<'a> From<&'a str> for EndpointType {
+    fn from(value: &'a str) -> EndpointType {
+        match value {
+            "REGIONAL" => EndpointType::Regional,
+            "EDGE" => EndpointType::Edge,
+            "PRIVATE" => EndpointType::Private,
+            _ => EndpointType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EndpointType {
+    fn from(value: String) -> EndpointType {
+        EndpointType::from(value.as_str())
+    }
+}
+
+impl From<EndpointType> for String {
+    fn from(value: EndpointType) -> String {
+        match value {
+            EndpointType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EndpointType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EndpointType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EndpointType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EndpointType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EndpointType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EndpointType::from(value))
+    }
+}
+
+/// <p>The binary blob response to <a>GetExport</a>, which contains the generated SDK.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ExportResponse {
+    /// <p>The binary blob response to <a>GetExport</a>, which contains the export.</p>
+    pub body: Option<bytes::Bytes>,
+    /// <p>The content-disposition header value in the HTTP response.</p>
+    pub content_disposition: Option<String>,
+    /// <p>The content-type header value in the HTTP response. This will correspond to a valid 'accept' type in the request.</p>
+    pub content_type: Option<String>,
+}
+
+/// <p>Request to flush authorizer cache entries on a specified stage.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct FlushStageAuthorizersCacheRequest {
+    /// <p>The string identifier of the associated <a>RestApi</a>.</p>
+    #[serde(rename = "restApiId")]
+    pub rest_api_id: String,
+    /// <p>The name of the stage to flush.</p>
+    #[serde(rename = "stageName")]
+    pub stage_name: String,
+}
+
+impl FlushStageAuthorizersCacheRequest {
+    /// Returns a builder for `FlushStageAuthorizersCacheRequest`, checking its required members when built.
+    pub fn builder() -> FlushStageAuthorizersCacheRequestBuilder {
+        FlushStageAuthorizersCacheRequestBuilder::default()
+    }
+}
+
+/// Builds a [`FlushStageAuthorizersCacheRequest`](struct.FlushStageAuthorizersCacheRequest.html).
+#[derive(Default, Debug)]
+pub struct FlushStageAuthorizersCacheRequestBuilder {
+    rest_api_id: Option<String>,
+    stage_name: Option<String>,
+}
+
+impl FlushStageAuthorizersCacheRequestBuilder {
+    /// Sets the [`rest_api_id`](struct.FlushStageAuthorizersCacheRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.FlushStageAuthorizersCacheRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `FlushStageAuthorizersCacheRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<FlushStageAuthorizersCacheRequest, ::rusoto_core::BuildError> {
+        Ok(FlushStageAuthorizersCacheRequest {
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+        })
+    }
+}
+
+/// <p>Requests API Gateway to flush a stage's cache.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct FlushStageCacheRequest {
+    /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
     pub rest_api_id: String,
     /// <p>[Required] The name of the stage to flush its cache.</p>
@@ -1240,6 +4168,46 @@ pub struct FlushStageCacheRequest {
     pub stage_name: String,
 }
 
+impl FlushStageCacheRequest {
+    /// Returns a builder for `FlushStageCacheRequest`, checking its required members when built.
+    pub fn builder() -> FlushStageCacheRequestBuilder {
+        FlushStageCacheRequestBuilder::default()
+    }
+}
+
+/// Builds a [`FlushStageCacheRequest`](struct.FlushStageCacheRequest.html).
+#[derive(Default, Debug)]
+pub struct FlushStageCacheRequestBuilder {
+    rest_api_id: Option<String>,
+    stage_name: Option<String>,
+}
+
+impl FlushStageCacheRequestBuilder {
+    /// Sets the [`rest_api_id`](struct.FlushStageCacheRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.FlushStageCacheRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `FlushStageCacheRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<FlushStageCacheRequest, ::rusoto_core::BuildError> {
+        Ok(FlushStageCacheRequest {
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+        })
+    }
+}
+
 /// <p><p>A gateway response of a given response type and status code, with optional response parameters and mapping templates.</p> <div class="remarks"> For more information about valid gateway response types, see <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/supported-gateway-response-types.html">Gateway Response Types Supported by API Gateway</a> <div class="example"> <h4>Example: Get a Gateway Response of a given response type</h4> <h5>Request</h5> <p>This example shows how to get a gateway response of the <code>MISSING<em>AUTHENTICATION</em>TOKEN</code> type.</p> <pre><code>GET /restapis/o81lxisefl/gatewayresponses/MISSING<em>AUTHENTICATION</em>TOKEN HTTP/1.1 Host: beta-apigateway.us-east-1.amazonaws.com Content-Type: application/json X-Amz-Date: 20170503T202516Z Authorization: AWS4-HMAC-SHA256 Credential={access-key-id}/20170503/us-east-1/apigateway/aws4<em>request, SignedHeaders=content-type;host;x-amz-date, Signature=1b52460e3159c1a26cff29093855d50ea141c1c5b937528fecaf60f51129697a Cache-Control: no-cache Postman-Token: 3b2a1ce9-c848-2e26-2e2f-9c2caefbed45 </code></pre> <p>The response type is specified as a URL path.</p> <h5>Response</h5> <p>The successful operation returns the <code>200 OK</code> status code and a payload similar to the following:</p> <pre><code>{ &quot;</em>links&quot;: { &quot;curies&quot;: { &quot;href&quot;: &quot;http://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-gatewayresponse-{rel}.html&quot;, &quot;name&quot;: &quot;gatewayresponse&quot;, &quot;templated&quot;: true }, &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING<em>AUTHENTICATION</em>TOKEN&quot; }, &quot;gatewayresponse:delete&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING<em>AUTHENTICATION</em>TOKEN&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING</em>AUTHENTICATION<em>TOKEN&quot; } }, &quot;defaultResponse&quot;: false, &quot;responseParameters&quot;: { &quot;gatewayresponse.header.x-request-path&quot;: &quot;method.request.path.petId&quot;, &quot;gatewayresponse.header.Access-Control-Allow-Origin&quot;: &quot;&apos;a.b.c&apos;&quot;, &quot;gatewayresponse.header.x-request-query&quot;: &quot;method.request.querystring.q&quot;, &quot;gatewayresponse.header.x-request-header&quot;: &quot;method.request.header.Accept&quot; }, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{\n &quot;message&quot;: $context.error.messageString,\n &quot;type&quot;: &quot;$context.error.responseType&quot;,\n &quot;stage&quot;: &quot;$context.stage&quot;,\n &quot;resourcePath&quot;: &quot;$context.resourcePath&quot;,\n &quot;stageVariables.a&quot;: &quot;$stageVariables.a&quot;,\n &quot;statusCode&quot;: &quot;&apos;404&apos;&quot;\n}&quot; }, &quot;responseType&quot;: &quot;MISSING</em>AUTHENTICATION_TOKEN&quot;, &quot;statusCode&quot;: &quot;404&quot; }</code></pre> <p></p> </div> </div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/customize-gateway-responses.html">Customize Gateway Responses</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1259,13 +4227,167 @@ pub struct GatewayResponse {
     /// <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p>
     #[serde(rename = "responseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub response_type: Option<String>,
+    pub response_type: Option<GatewayResponseType>,
     /// <p>The HTTP status code for this <a>GatewayResponse</a>.</p>
     #[serde(rename = "statusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_code: Option<String>,
 }
 
+/// <p>[Required] <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GatewayResponseType {
+    /// <p><code>DEFAULT_4XX</code></p>
+    Default4xx,
+    /// <p><code>DEFAULT_5XX</code></p>
+    Default5xx,
+    /// <p><code>RESOURCE_NOT_FOUND</code></p>
+    ResourceNotFound,
+    /// <p><code>UNAUTHORIZED</code></p>
+    Unauthorized,
+    /// <p><code>INVALID_API_KEY</code></p>
+    InvalidApiKey,
+    /// <p><code>ACCESS_DENIED</code></p>
+    AccessDenied,
+    /// <p><code>AUTHORIZER_FAILURE</code></p>
+    AuthorizerFailure,
+    /// <p><code>AUTHORIZER_CONFIGURATION_ERROR</code></p>
+    AuthorizerConfigurationError,
+    /// <p><code>INVALID_SIGNATURE</code></p>
+    InvalidSignature,
+    /// <p><code>EXPIRED_TOKEN</code></p>
+    ExpiredToken,
+    /// <p><code>MISSING_AUTHENTICATION_TOKEN</code></p>
+    MissingAuthenticationToken,
+    /// <p><code>INTEGRATION_FAILURE</code></p>
+    IntegrationFailure,
+    /// <p><code>INTEGRATION_TIMEOUT</code></p>
+    IntegrationTimeout,
+    /// <p><code>API_CONFIGURATION_ERROR</code></p>
+    ApiConfigurationError,
+    /// <p><code>UNSUPPORTED_MEDIA_TYPE</code></p>
+    UnsupportedMediaType,
+    /// <p><code>BAD_REQUEST_PARAMETERS</code></p>
+    BadRequestParameters,
+    /// <p><code>BAD_REQUEST_BODY</code></p>
+    BadRequestBody,
+    /// <p><code>REQUEST_TOO_LARGE</code></p>
+    RequestTooLarge,
+    /// <p><code>THROTTLED</code></p>
+    Throttled,
+    /// <p><code>QUOTA_EXCEEDED</code></p>
+    QuotaExceeded,
+    /// <p><code>WAF_FILTERED</code></p>
+    WafFiltered,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl GatewayResponseType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GatewayResponseType::Default4xx => "DEFAULT_4XX",
+            GatewayResponseType::Default5xx => "DEFAULT_5XX",
+            GatewayResponseType::ResourceNotFound => "RESOURCE_NOT_FOUND",
+            GatewayResponseType::Unauthorized => "UNAUTHORIZED",
+            GatewayResponseType::InvalidApiKey => "INVALID_API_KEY",
+            GatewayResponseType::AccessDenied => "ACCESS_DENIED",
+            GatewayResponseType::AuthorizerFailure => "AUTHORIZER_FAILURE",
+            GatewayResponseType::AuthorizerConfigurationError => "AUTHORIZER_CONFIGURATION_ERROR",
+            GatewayResponseType::InvalidSignature => "INVALID_SIGNATURE",
+            GatewayResponseType::ExpiredToken => "EXPIRED_TOKEN",
+            GatewayResponseType::MissingAuthenticationToken => "MISSING_AUTHENTICATION_TOKEN",
+            GatewayResponseType::IntegrationFailure => "INTEGRATION_FAILURE",
+            GatewayResponseType::IntegrationTimeout => "INTEGRATION_TIMEOUT",
+            GatewayResponseType::ApiConfigurationError => "API_CONFIGURATION_ERROR",
+            GatewayResponseType::UnsupportedMediaType => "UNSUPPORTED_MEDIA_TYPE",
+            GatewayResponseType::BadRequestParameters => "BAD_REQUEST_PARAMETERS",
+            GatewayResponseType::BadRequestBody => "BAD_REQUEST_BODY",
+            GatewayResponseType::RequestTooLarge => "REQUEST_TOO_LARGE",
+            GatewayResponseType::Throttled => "THROTTLED",
+            GatewayResponseType::QuotaExceeded => "QUOTA_EXCEEDED",
+            GatewayResponseType::WafFiltered => "WAF_FILTERED",
+            GatewayResponseType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GatewayResponseType {
+    fn from(value: &'a str) -> GatewayResponseType {
+        match value {
+            "DEFAULT_4XX" => GatewayResponseType::Default4xx,
+            "DEFAULT_5XX" => GatewayResponseType::Default5xx,
+            "RESOURCE_NOT_FOUND" => GatewayResponseType::ResourceNotFound,
+            "UNAUTHORIZED" => GatewayResponseType::Unauthorized,
+            "INVALID_API_KEY" => GatewayResponseType::InvalidApiKey,
+            "ACCESS_DENIED" => GatewayResponseType::AccessDenied,
+            "AUTHORIZER_FAILURE" => GatewayResponseType::AuthorizerFailure,
+            "AUTHORIZER_CONFIGURATION_ERROR" => GatewayResponseType::AuthorizerConfigurationError,
+            "INVALID_SIGNATURE" => GatewayResponseType::InvalidSignature,
+            "EXPIRED_TOKEN" => GatewayResponseType::ExpiredToken,
+            "MISSING_AUTHENTICATION_TOKEN" => GatewayResponseType::MissingAuthenticationToken,
+            "INTEGRATION_FAILURE" => GatewayResponseType::IntegrationFailure,
+            "INTEGRATION_TIMEOUT" => GatewayResponseType::IntegrationTimeout,
+            "API_CONFIGURATION_ERROR" => GatewayResponseType::ApiConfigurationError,
+            "UNSUPPORTED_MEDIA_TYPE" => GatewayResponseType::UnsupportedMediaType,
+            "BAD_REQUEST_PARAMETERS" => GatewayResponseType::BadRequestParameters,
+            "BAD_REQUEST_BODY" => GatewayResponseType::BadRequestBody,
+            "REQUEST_TOO_LARGE" => GatewayResponseType::RequestTooLarge,
+            "THROTTLED" => GatewayResponseType::Throttled,
+            "QUOTA_EXCEEDED" => GatewayResponseType::QuotaExceeded,
+            "WAF_FILTERED" => GatewayResponseType::WafFiltered,
+            _ => GatewayResponseType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GatewayResponseType {
+    fn from(value: String) -> GatewayResponseType {
+        GatewayResponseType::from(value.as_str())
+    }
+}
+
+impl From<GatewayResponseType> for String {
+    fn from(value: GatewayResponseType) -> String {
+        match value {
+            GatewayResponseType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GatewayResponseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GatewayResponseType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GatewayResponseType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GatewayResponseType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<GatewayResponseType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(GatewayResponseType::from(value))
+    }
+}
+
 /// <p><p>The collection of the <a>GatewayResponse</a> instances of a <a>RestApi</a> as a <code>responseType</code>-to-<a>GatewayResponse</a> object map of key-value pairs. As such, pagination is not supported for querying this collection.</p> <div class="remarks"> For more information about valid gateway response types, see <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/supported-gateway-response-types.html">Gateway Response Types Supported by API Gateway</a> <div class="example"> <h4>Example: Get the collection of gateway responses of an API</h4> <h5>Request</h5> <p>This example request shows how to retrieve the <a>GatewayResponses</a> collection from an API.</p> <pre><code>GET /restapis/o81lxisefl/gatewayresponses HTTP/1.1 Host: beta-apigateway.us-east-1.amazonaws.com Content-Type: application/json X-Amz-Date: 20170503T220604Z Authorization: AWS4-HMAC-SHA256 Credential={access-key-id}/20170503/us-east-1/apigateway/aws4<em>request, SignedHeaders=content-type;host;x-amz-date, Signature=59b42fe54a76a5de8adf2c67baa6d39206f8e9ad49a1d77ccc6a5da3103a398a Cache-Control: no-cache Postman-Token: 5637af27-dc29-fc5c-9dfe-0645d52cb515 </code></pre> <p></p> <h5>Response</h5> <p>The successful operation returns the <code>200 OK</code> status code and a payload similar to the following:</p> <pre><code>{ &quot;</em>links&quot;: { &quot;curies&quot;: { &quot;href&quot;: &quot;http://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-gatewayresponse-{rel}.html&quot;, &quot;name&quot;: &quot;gatewayresponse&quot;, &quot;templated&quot;: true }, &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses&quot; }, &quot;first&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses&quot; }, &quot;gatewayresponse:by-type&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;item&quot;: [ { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION</em>FAILURE&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/RESOURCE<em>NOT</em>FOUND&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/REQUEST<em>TOO</em>LARGE&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/THROTTLED&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNSUPPORTED<em>MEDIA</em>TYPE&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER<em>CONFIGURATION</em>ERROR&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT<em>5XX&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT</em>4XX&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD<em>REQUEST</em>PARAMETERS&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD<em>REQUEST</em>BODY&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/EXPIRED<em>TOKEN&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/ACCESS</em>DENIED&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID<em>API</em>KEY&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNAUTHORIZED&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/API<em>CONFIGURATION</em>ERROR&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/QUOTA<em>EXCEEDED&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION</em>TIMEOUT&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING<em>AUTHENTICATION</em>TOKEN&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID<em>SIGNATURE&quot; }, { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER</em>FAILURE&quot; } ] }, &quot;<em>embedded&quot;: { &quot;item&quot;: [ { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION<em>FAILURE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION<em>FAILURE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;INTEGRATION</em>FAILURE&quot;, &quot;statusCode&quot;: &quot;504&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/RESOURCE</em>NOT<em>FOUND&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/RESOURCE<em>NOT</em>FOUND&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;RESOURCE<em>NOT</em>FOUND&quot;, &quot;statusCode&quot;: &quot;404&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/REQUEST</em>TOO<em>LARGE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/REQUEST<em>TOO</em>LARGE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;REQUEST<em>TOO</em>LARGE&quot;, &quot;statusCode&quot;: &quot;413&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/THROTTLED&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/THROTTLED&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;THROTTLED&quot;, &quot;statusCode&quot;: &quot;429&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNSUPPORTED</em>MEDIA<em>TYPE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNSUPPORTED<em>MEDIA</em>TYPE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;UNSUPPORTED<em>MEDIA</em>TYPE&quot;, &quot;statusCode&quot;: &quot;415&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER</em>CONFIGURATION<em>ERROR&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER<em>CONFIGURATION</em>ERROR&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;AUTHORIZER<em>CONFIGURATION</em>ERROR&quot;, &quot;statusCode&quot;: &quot;500&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT</em>5XX&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT</em>5XX&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;DEFAULT<em>5XX&quot; }, { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT<em>4XX&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/DEFAULT<em>4XX&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;DEFAULT</em>4XX&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD</em>REQUEST<em>PARAMETERS&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD<em>REQUEST</em>PARAMETERS&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;BAD<em>REQUEST</em>PARAMETERS&quot;, &quot;statusCode&quot;: &quot;400&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD</em>REQUEST<em>BODY&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/BAD<em>REQUEST</em>BODY&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;BAD<em>REQUEST</em>BODY&quot;, &quot;statusCode&quot;: &quot;400&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/EXPIRED</em>TOKEN&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/EXPIRED</em>TOKEN&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;EXPIRED<em>TOKEN&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/ACCESS<em>DENIED&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/ACCESS<em>DENIED&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;ACCESS</em>DENIED&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID</em>API<em>KEY&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID<em>API</em>KEY&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;INVALID<em>API</em>KEY&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNAUTHORIZED&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/UNAUTHORIZED&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;UNAUTHORIZED&quot;, &quot;statusCode&quot;: &quot;401&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/API</em>CONFIGURATION<em>ERROR&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/API<em>CONFIGURATION</em>ERROR&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;API<em>CONFIGURATION</em>ERROR&quot;, &quot;statusCode&quot;: &quot;500&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/QUOTA</em>EXCEEDED&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/QUOTA</em>EXCEEDED&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;QUOTA<em>EXCEEDED&quot;, &quot;statusCode&quot;: &quot;429&quot; }, { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION<em>TIMEOUT&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INTEGRATION<em>TIMEOUT&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;INTEGRATION</em>TIMEOUT&quot;, &quot;statusCode&quot;: &quot;504&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING</em>AUTHENTICATION<em>TOKEN&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/MISSING<em>AUTHENTICATION</em>TOKEN&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;MISSING<em>AUTHENTICATION</em>TOKEN&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID</em>SIGNATURE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response<em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/INVALID</em>SIGNATURE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;INVALID<em>SIGNATURE&quot;, &quot;statusCode&quot;: &quot;403&quot; }, { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER<em>FAILURE&quot; }, &quot;gatewayresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/{response</em>type}&quot;, &quot;templated&quot;: true }, &quot;gatewayresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/o81lxisefl/gatewayresponses/AUTHORIZER<em>FAILURE&quot; } }, &quot;defaultResponse&quot;: true, &quot;responseParameters&quot;: {}, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;{&quot;message&quot;:$context.error.messageString}&quot; }, &quot;responseType&quot;: &quot;AUTHORIZER</em>FAILURE&quot;, &quot;statusCode&quot;: &quot;500&quot; } ] } }</code></pre> <p></p> </div> </div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/customize-gateway-responses.html">Customize Gateway Responses</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1292,6 +4414,44 @@ pub struct GenerateClientCertificateRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl GenerateClientCertificateRequest {
+    /// Returns a builder for `GenerateClientCertificateRequest`, checking its required members when built.
+    pub fn builder() -> GenerateClientCertificateRequestBuilder {
+        GenerateClientCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GenerateClientCertificateRequest`](struct.GenerateClientCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct GenerateClientCertificateRequestBuilder {
+    description: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl GenerateClientCertificateRequestBuilder {
+    /// Sets the [`description`](struct.GenerateClientCertificateRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.GenerateClientCertificateRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `GenerateClientCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GenerateClientCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(GenerateClientCertificateRequest {
+            description: self.description,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p>Requests API Gateway to get information about the current <a>Account</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetAccountRequest {}
@@ -1308,6 +4468,44 @@ pub struct GetApiKeyRequest {
     pub include_value: Option<bool>,
 }
 
+impl GetApiKeyRequest {
+    /// Returns a builder for `GetApiKeyRequest`, checking its required members when built.
+    pub fn builder() -> GetApiKeyRequestBuilder {
+        GetApiKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetApiKeyRequest`](struct.GetApiKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetApiKeyRequestBuilder {
+    api_key: Option<String>,
+    include_value: Option<bool>,
+}
+
+impl GetApiKeyRequestBuilder {
+    /// Sets the [`api_key`](struct.GetApiKeyRequest.html#structfield.api_key) member.
+    pub fn api_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_value`](struct.GetApiKeyRequest.html#structfield.include_value) member.
+    pub fn include_value<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_value = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApiKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetApiKeyRequest, ::rusoto_core::BuildError> {
+        Ok(GetApiKeyRequest {
+            api_key: self
+                .api_key
+                .ok_or(::rusoto_core::BuildError::missing("api_key"))?,
+            include_value: self.include_value,
+        })
+    }
+}
+
 /// <p>A request to get information about the current <a>ApiKeys</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetApiKeysRequest {
@@ -1333,6 +4531,66 @@ pub struct GetApiKeysRequest {
     pub position: Option<String>,
 }
 
+impl GetApiKeysRequest {
+    /// Returns a builder for `GetApiKeysRequest`, checking its required members when built.
+    pub fn builder() -> GetApiKeysRequestBuilder {
+        GetApiKeysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetApiKeysRequest`](struct.GetApiKeysRequest.html).
+#[derive(Default, Debug)]
+pub struct GetApiKeysRequestBuilder {
+    customer_id: Option<String>,
+    include_values: Option<bool>,
+    limit: Option<i64>,
+    name_query: Option<String>,
+    position: Option<String>,
+}
+
+impl GetApiKeysRequestBuilder {
+    /// Sets the [`customer_id`](struct.GetApiKeysRequest.html#structfield.customer_id) member.
+    pub fn customer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.customer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_values`](struct.GetApiKeysRequest.html#structfield.include_values) member.
+    pub fn include_values<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetApiKeysRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_query`](struct.GetApiKeysRequest.html#structfield.name_query) member.
+    pub fn name_query<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_query = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetApiKeysRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApiKeysRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetApiKeysRequest, ::rusoto_core::BuildError> {
+        Ok(GetApiKeysRequest {
+            customer_id: self.customer_id,
+            include_values: self.include_values,
+            limit: self.limit,
+            name_query: self.name_query,
+            position: self.position,
+        })
+    }
+}
+
 /// <p>Request to describe an existing <a>Authorizer</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetAuthorizerRequest {
@@ -1344,6 +4602,46 @@ pub struct GetAuthorizerRequest {
     pub rest_api_id: String,
 }
 
+impl GetAuthorizerRequest {
+    /// Returns a builder for `GetAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> GetAuthorizerRequestBuilder {
+        GetAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAuthorizerRequest`](struct.GetAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAuthorizerRequestBuilder {
+    authorizer_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetAuthorizerRequestBuilder {
+    /// Sets the [`authorizer_id`](struct.GetAuthorizerRequest.html#structfield.authorizer_id) member.
+    pub fn authorizer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetAuthorizerRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(GetAuthorizerRequest {
+            authorizer_id: self
+                .authorizer_id
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to describe an existing <a>Authorizers</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetAuthorizersRequest {
@@ -1360,6 +4658,52 @@ pub struct GetAuthorizersRequest {
     pub rest_api_id: String,
 }
 
+impl GetAuthorizersRequest {
+    /// Returns a builder for `GetAuthorizersRequest`, checking its required members when built.
+    pub fn builder() -> GetAuthorizersRequestBuilder {
+        GetAuthorizersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAuthorizersRequest`](struct.GetAuthorizersRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAuthorizersRequestBuilder {
+    limit: Option<i64>,
+    position: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetAuthorizersRequestBuilder {
+    /// Sets the [`limit`](struct.GetAuthorizersRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetAuthorizersRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetAuthorizersRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAuthorizersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetAuthorizersRequest, ::rusoto_core::BuildError> {
+        Ok(GetAuthorizersRequest {
+            limit: self.limit,
+            position: self.position,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to describe a <a>BasePathMapping</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetBasePathMappingRequest {
@@ -1371,6 +4715,48 @@ pub struct GetBasePathMappingRequest {
     pub domain_name: String,
 }
 
+impl GetBasePathMappingRequest {
+    /// Returns a builder for `GetBasePathMappingRequest`, checking its required members when built.
+    pub fn builder() -> GetBasePathMappingRequestBuilder {
+        GetBasePathMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBasePathMappingRequest`](struct.GetBasePathMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBasePathMappingRequestBuilder {
+    base_path: Option<String>,
+    domain_name: Option<String>,
+}
+
+impl GetBasePathMappingRequestBuilder {
+    /// Sets the [`base_path`](struct.GetBasePathMappingRequest.html#structfield.base_path) member.
+    pub fn base_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.base_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.GetBasePathMappingRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBasePathMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetBasePathMappingRequest, ::rusoto_core::BuildError> {
+        Ok(GetBasePathMappingRequest {
+            base_path: self
+                .base_path
+                .ok_or(::rusoto_core::BuildError::missing("base_path"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// <p>A request to get information about a collection of <a>BasePathMapping</a> resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetBasePathMappingsRequest {
@@ -1387,6 +4773,54 @@ pub struct GetBasePathMappingsRequest {
     pub position: Option<String>,
 }
 
+impl GetBasePathMappingsRequest {
+    /// Returns a builder for `GetBasePathMappingsRequest`, checking its required members when built.
+    pub fn builder() -> GetBasePathMappingsRequestBuilder {
+        GetBasePathMappingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBasePathMappingsRequest`](struct.GetBasePathMappingsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBasePathMappingsRequestBuilder {
+    domain_name: Option<String>,
+    limit: Option<i64>,
+    position: Option<String>,
+}
+
+impl GetBasePathMappingsRequestBuilder {
+    /// Sets the [`domain_name`](struct.GetBasePathMappingsRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetBasePathMappingsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetBasePathMappingsRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBasePathMappingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetBasePathMappingsRequest, ::rusoto_core::BuildError> {
+        Ok(GetBasePathMappingsRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            limit: self.limit,
+            position: self.position,
+        })
+    }
+}
+
 /// <p>A request to get information about the current <a>ClientCertificate</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetClientCertificateRequest {
@@ -1395,6 +4829,38 @@ pub struct GetClientCertificateRequest {
     pub client_certificate_id: String,
 }
 
+impl GetClientCertificateRequest {
+    /// Returns a builder for `GetClientCertificateRequest`, checking its required members when built.
+    pub fn builder() -> GetClientCertificateRequestBuilder {
+        GetClientCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetClientCertificateRequest`](struct.GetClientCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetClientCertificateRequestBuilder {
+    client_certificate_id: Option<String>,
+}
+
+impl GetClientCertificateRequestBuilder {
+    /// Sets the [`client_certificate_id`](struct.GetClientCertificateRequest.html#structfield.client_certificate_id) member.
+    pub fn client_certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_certificate_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetClientCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetClientCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(GetClientCertificateRequest {
+            client_certificate_id: self
+                .client_certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("client_certificate_id"))?,
+        })
+    }
+}
+
 /// <p>A request to get information about a collection of <a>ClientCertificate</a> resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetClientCertificatesRequest {
@@ -1408,6 +4874,44 @@ pub struct GetClientCertificatesRequest {
     pub position: Option<String>,
 }
 
+impl GetClientCertificatesRequest {
+    /// Returns a builder for `GetClientCertificatesRequest`, checking its required members when built.
+    pub fn builder() -> GetClientCertificatesRequestBuilder {
+        GetClientCertificatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetClientCertificatesRequest`](struct.GetClientCertificatesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetClientCertificatesRequestBuilder {
+    limit: Option<i64>,
+    position: Option<String>,
+}
+
+impl GetClientCertificatesRequestBuilder {
+    /// Sets the [`limit`](struct.GetClientCertificatesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetClientCertificatesRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetClientCertificatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetClientCertificatesRequest, ::rusoto_core::BuildError> {
+        Ok(GetClientCertificatesRequest {
+            limit: self.limit,
+            position: self.position,
+        })
+    }
+}
+
 /// <p>Requests API Gateway to get information about a <a>Deployment</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetDeploymentRequest {
@@ -1423,6 +4927,54 @@ pub struct GetDeploymentRequest {
     pub rest_api_id: String,
 }
 
+impl GetDeploymentRequest {
+    /// Returns a builder for `GetDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> GetDeploymentRequestBuilder {
+        GetDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeploymentRequest`](struct.GetDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDeploymentRequestBuilder {
+    deployment_id: Option<String>,
+    embed: Option<Vec<String>>,
+    rest_api_id: Option<String>,
+}
+
+impl GetDeploymentRequestBuilder {
+    /// Sets the [`deployment_id`](struct.GetDeploymentRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`embed`](struct.GetDeploymentRequest.html#structfield.embed) member.
+    pub fn embed<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.embed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetDeploymentRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(GetDeploymentRequest {
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+            embed: self.embed,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Requests API Gateway to get information about a <a>Deployments</a> collection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetDeploymentsRequest {
@@ -1439,6 +4991,52 @@ pub struct GetDeploymentsRequest {
     pub rest_api_id: String,
 }
 
+impl GetDeploymentsRequest {
+    /// Returns a builder for `GetDeploymentsRequest`, checking its required members when built.
+    pub fn builder() -> GetDeploymentsRequestBuilder {
+        GetDeploymentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeploymentsRequest`](struct.GetDeploymentsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDeploymentsRequestBuilder {
+    limit: Option<i64>,
+    position: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetDeploymentsRequestBuilder {
+    /// Sets the [`limit`](struct.GetDeploymentsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetDeploymentsRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetDeploymentsRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeploymentsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDeploymentsRequest, ::rusoto_core::BuildError> {
+        Ok(GetDeploymentsRequest {
+            limit: self.limit,
+            position: self.position,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Gets a specified documentation part of a given API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetDocumentationPartRequest {
@@ -1450,9 +5048,51 @@ pub struct GetDocumentationPartRequest {
     pub rest_api_id: String,
 }
 
-/// <p>Gets the documentation parts of an API. The result may be filtered by the type, name, or path of API entities (targets).</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetDocumentationPartsRequest {
+impl GetDocumentationPartRequest {
+    /// Returns a builder for `GetDocumentationPartRequest`, checking its required members when built.
+    pub fn builder() -> GetDocumentationPartRequestBuilder {
+        GetDocumentationPartRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDocumentationPartRequest`](struct.GetDocumentationPartRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDocumentationPartRequestBuilder {
+    documentation_part_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetDocumentationPartRequestBuilder {
+    /// Sets the [`documentation_part_id`](struct.GetDocumentationPartRequest.html#structfield.documentation_part_id) member.
+    pub fn documentation_part_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.documentation_part_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetDocumentationPartRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDocumentationPartRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDocumentationPartRequest, ::rusoto_core::BuildError> {
+        Ok(GetDocumentationPartRequest {
+            documentation_part_id: self
+                .documentation_part_id
+                .ok_or(::rusoto_core::BuildError::missing("documentation_part_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
+/// <p>Gets the documentation parts of an API. The result may be filtered by the type, name, or path of API entities (targets).</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetDocumentationPartsRequest {
     /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1460,7 +5100,7 @@ pub struct GetDocumentationPartsRequest {
     /// <p>The status of the API documentation parts to retrieve. Valid values are <code>DOCUMENTED</code> for retrieving <a>DocumentationPart</a> resources with content and <code>UNDOCUMENTED</code> for <a>DocumentationPart</a> resources without content.</p>
     #[serde(rename = "locationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub location_status: Option<String>,
+    pub location_status: Option<LocationStatusType>,
     /// <p>The name of API entities of the to-be-retrieved documentation parts.</p>
     #[serde(rename = "nameQuery")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1479,7 +5119,87 @@ pub struct GetDocumentationPartsRequest {
     /// <p>The type of API entities of the to-be-retrieved documentation parts. </p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DocumentationPartType>,
+}
+
+impl GetDocumentationPartsRequest {
+    /// Returns a builder for `GetDocumentationPartsRequest`, checking its required members when built.
+    pub fn builder() -> GetDocumentationPartsRequestBuilder {
+        GetDocumentationPartsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDocumentationPartsRequest`](struct.GetDocumentationPartsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDocumentationPartsRequestBuilder {
+    limit: Option<i64>,
+    location_status: Option<LocationStatusType>,
+    name_query: Option<String>,
+    path: Option<String>,
+    position: Option<String>,
+    rest_api_id: Option<String>,
+    type_: Option<DocumentationPartType>,
+}
+
+impl GetDocumentationPartsRequestBuilder {
+    /// Sets the [`limit`](struct.GetDocumentationPartsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location_status`](struct.GetDocumentationPartsRequest.html#structfield.location_status) member.
+    pub fn location_status<T: Into<LocationStatusType>>(mut self, value: T) -> Self {
+        self.location_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_query`](struct.GetDocumentationPartsRequest.html#structfield.name_query) member.
+    pub fn name_query<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_query = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path`](struct.GetDocumentationPartsRequest.html#structfield.path) member.
+    pub fn path<T: Into<String>>(mut self, value: T) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetDocumentationPartsRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetDocumentationPartsRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.GetDocumentationPartsRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<DocumentationPartType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDocumentationPartsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDocumentationPartsRequest, ::rusoto_core::BuildError> {
+        Ok(GetDocumentationPartsRequest {
+            limit: self.limit,
+            location_status: self.location_status,
+            name_query: self.name_query,
+            path: self.path,
+            position: self.position,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            type_: self.type_,
+        })
+    }
 }
 
 /// <p>Gets a documentation snapshot of an API.</p>
@@ -1493,6 +5213,48 @@ pub struct GetDocumentationVersionRequest {
     pub rest_api_id: String,
 }
 
+impl GetDocumentationVersionRequest {
+    /// Returns a builder for `GetDocumentationVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetDocumentationVersionRequestBuilder {
+        GetDocumentationVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDocumentationVersionRequest`](struct.GetDocumentationVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDocumentationVersionRequestBuilder {
+    documentation_version: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetDocumentationVersionRequestBuilder {
+    /// Sets the [`documentation_version`](struct.GetDocumentationVersionRequest.html#structfield.documentation_version) member.
+    pub fn documentation_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.documentation_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetDocumentationVersionRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDocumentationVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDocumentationVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetDocumentationVersionRequest {
+            documentation_version: self
+                .documentation_version
+                .ok_or(::rusoto_core::BuildError::missing("documentation_version"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Gets the documentation versions of an API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetDocumentationVersionsRequest {
@@ -1509,6 +5271,54 @@ pub struct GetDocumentationVersionsRequest {
     pub rest_api_id: String,
 }
 
+impl GetDocumentationVersionsRequest {
+    /// Returns a builder for `GetDocumentationVersionsRequest`, checking its required members when built.
+    pub fn builder() -> GetDocumentationVersionsRequestBuilder {
+        GetDocumentationVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDocumentationVersionsRequest`](struct.GetDocumentationVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDocumentationVersionsRequestBuilder {
+    limit: Option<i64>,
+    position: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetDocumentationVersionsRequestBuilder {
+    /// Sets the [`limit`](struct.GetDocumentationVersionsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetDocumentationVersionsRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetDocumentationVersionsRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDocumentationVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDocumentationVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(GetDocumentationVersionsRequest {
+            limit: self.limit,
+            position: self.position,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to get the name of a <a>DomainName</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetDomainNameRequest {
@@ -1517,6 +5327,36 @@ pub struct GetDomainNameRequest {
     pub domain_name: String,
 }
 
+impl GetDomainNameRequest {
+    /// Returns a builder for `GetDomainNameRequest`, checking its required members when built.
+    pub fn builder() -> GetDomainNameRequestBuilder {
+        GetDomainNameRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDomainNameRequest`](struct.GetDomainNameRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDomainNameRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl GetDomainNameRequestBuilder {
+    /// Sets the [`domain_name`](struct.GetDomainNameRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDomainNameRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDomainNameRequest, ::rusoto_core::BuildError> {
+        Ok(GetDomainNameRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// <p>Request to describe a collection of <a>DomainName</a> resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetDomainNamesRequest {
@@ -1530,6 +5370,42 @@ pub struct GetDomainNamesRequest {
     pub position: Option<String>,
 }
 
+impl GetDomainNamesRequest {
+    /// Returns a builder for `GetDomainNamesRequest`, checking its required members when built.
+    pub fn builder() -> GetDomainNamesRequestBuilder {
+        GetDomainNamesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDomainNamesRequest`](struct.GetDomainNamesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDomainNamesRequestBuilder {
+    limit: Option<i64>,
+    position: Option<String>,
+}
+
+impl GetDomainNamesRequestBuilder {
+    /// Sets the [`limit`](struct.GetDomainNamesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetDomainNamesRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDomainNamesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDomainNamesRequest, ::rusoto_core::BuildError> {
+        Ok(GetDomainNamesRequest {
+            limit: self.limit,
+            position: self.position,
+        })
+    }
+}
+
 /// <p>Request a new export of a <a>RestApi</a> for a particular <a>Stage</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetExportRequest {
@@ -1552,17 +5428,128 @@ pub struct GetExportRequest {
     pub stage_name: String,
 }
 
+impl GetExportRequest {
+    /// Returns a builder for `GetExportRequest`, checking its required members when built.
+    pub fn builder() -> GetExportRequestBuilder {
+        GetExportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetExportRequest`](struct.GetExportRequest.html).
+#[derive(Default, Debug)]
+pub struct GetExportRequestBuilder {
+    accepts: Option<String>,
+    export_type: Option<String>,
+    parameters: Option<::std::collections::HashMap<String, String>>,
+    rest_api_id: Option<String>,
+    stage_name: Option<String>,
+}
+
+impl GetExportRequestBuilder {
+    /// Sets the [`accepts`](struct.GetExportRequest.html#structfield.accepts) member.
+    pub fn accepts<T: Into<String>>(mut self, value: T) -> Self {
+        self.accepts = Some(value.into());
+        self
+    }
+
+    /// Sets the [`export_type`](struct.GetExportRequest.html#structfield.export_type) member.
+    pub fn export_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.export_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.GetExportRequest.html#structfield.parameters) member.
+    pub fn parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetExportRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.GetExportRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetExportRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetExportRequest, ::rusoto_core::BuildError> {
+        Ok(GetExportRequest {
+            accepts: self.accepts,
+            export_type: self
+                .export_type
+                .ok_or(::rusoto_core::BuildError::missing("export_type"))?,
+            parameters: self.parameters,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+        })
+    }
+}
+
 /// <p>Gets a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetGatewayResponseRequest {
     /// <p>[Required] <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p></p>
     #[serde(rename = "responseType")]
-    pub response_type: String,
+    pub response_type: GatewayResponseType,
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
     pub rest_api_id: String,
 }
 
+impl GetGatewayResponseRequest {
+    /// Returns a builder for `GetGatewayResponseRequest`, checking its required members when built.
+    pub fn builder() -> GetGatewayResponseRequestBuilder {
+        GetGatewayResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGatewayResponseRequest`](struct.GetGatewayResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGatewayResponseRequestBuilder {
+    response_type: Option<GatewayResponseType>,
+    rest_api_id: Option<String>,
+}
+
+impl GetGatewayResponseRequestBuilder {
+    /// Sets the [`response_type`](struct.GetGatewayResponseRequest.html#structfield.response_type) member.
+    pub fn response_type<T: Into<GatewayResponseType>>(mut self, value: T) -> Self {
+        self.response_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetGatewayResponseRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGatewayResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetGatewayResponseRequest, ::rusoto_core::BuildError> {
+        Ok(GetGatewayResponseRequest {
+            response_type: self
+                .response_type
+                .ok_or(::rusoto_core::BuildError::missing("response_type"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Gets the <a>GatewayResponses</a> collection on the given <a>RestApi</a>. If an API developer has not added any definitions for gateway responses, the result will be the API Gateway-generated default <a>GatewayResponses</a> collection for the supported response types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetGatewayResponsesRequest {
@@ -1579,6 +5566,54 @@ pub struct GetGatewayResponsesRequest {
     pub rest_api_id: String,
 }
 
+impl GetGatewayResponsesRequest {
+    /// Returns a builder for `GetGatewayResponsesRequest`, checking its required members when built.
+    pub fn builder() -> GetGatewayResponsesRequestBuilder {
+        GetGatewayResponsesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGatewayResponsesRequest`](struct.GetGatewayResponsesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGatewayResponsesRequestBuilder {
+    limit: Option<i64>,
+    position: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetGatewayResponsesRequestBuilder {
+    /// Sets the [`limit`](struct.GetGatewayResponsesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetGatewayResponsesRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetGatewayResponsesRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGatewayResponsesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetGatewayResponsesRequest, ::rusoto_core::BuildError> {
+        Ok(GetGatewayResponsesRequest {
+            limit: self.limit,
+            position: self.position,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Represents a request to get the integration configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetIntegrationRequest {
@@ -1593,6 +5628,56 @@ pub struct GetIntegrationRequest {
     pub rest_api_id: String,
 }
 
+impl GetIntegrationRequest {
+    /// Returns a builder for `GetIntegrationRequest`, checking its required members when built.
+    pub fn builder() -> GetIntegrationRequestBuilder {
+        GetIntegrationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIntegrationRequest`](struct.GetIntegrationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIntegrationRequestBuilder {
+    http_method: Option<String>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetIntegrationRequestBuilder {
+    /// Sets the [`http_method`](struct.GetIntegrationRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.GetIntegrationRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetIntegrationRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIntegrationRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetIntegrationRequest, ::rusoto_core::BuildError> {
+        Ok(GetIntegrationRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Represents a get integration response request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetIntegrationResponseRequest {
@@ -1610,6 +5695,68 @@ pub struct GetIntegrationResponseRequest {
     pub status_code: String,
 }
 
+impl GetIntegrationResponseRequest {
+    /// Returns a builder for `GetIntegrationResponseRequest`, checking its required members when built.
+    pub fn builder() -> GetIntegrationResponseRequestBuilder {
+        GetIntegrationResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIntegrationResponseRequest`](struct.GetIntegrationResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIntegrationResponseRequestBuilder {
+    http_method: Option<String>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+    status_code: Option<String>,
+}
+
+impl GetIntegrationResponseRequestBuilder {
+    /// Sets the [`http_method`](struct.GetIntegrationResponseRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.GetIntegrationResponseRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetIntegrationResponseRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_code`](struct.GetIntegrationResponseRequest.html#structfield.status_code) member.
+    pub fn status_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.status_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIntegrationResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIntegrationResponseRequest, ::rusoto_core::BuildError> {
+        Ok(GetIntegrationResponseRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            status_code: self
+                .status_code
+                .ok_or(::rusoto_core::BuildError::missing("status_code"))?,
+        })
+    }
+}
+
 /// <p>Request to describe an existing <a>Method</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetMethodRequest {
@@ -1624,6 +5771,56 @@ pub struct GetMethodRequest {
     pub rest_api_id: String,
 }
 
+impl GetMethodRequest {
+    /// Returns a builder for `GetMethodRequest`, checking its required members when built.
+    pub fn builder() -> GetMethodRequestBuilder {
+        GetMethodRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetMethodRequest`](struct.GetMethodRequest.html).
+#[derive(Default, Debug)]
+pub struct GetMethodRequestBuilder {
+    http_method: Option<String>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetMethodRequestBuilder {
+    /// Sets the [`http_method`](struct.GetMethodRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.GetMethodRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetMethodRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMethodRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMethodRequest, ::rusoto_core::BuildError> {
+        Ok(GetMethodRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to describe a <a>MethodResponse</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetMethodResponseRequest {
@@ -1641,6 +5838,68 @@ pub struct GetMethodResponseRequest {
     pub status_code: String,
 }
 
+impl GetMethodResponseRequest {
+    /// Returns a builder for `GetMethodResponseRequest`, checking its required members when built.
+    pub fn builder() -> GetMethodResponseRequestBuilder {
+        GetMethodResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetMethodResponseRequest`](struct.GetMethodResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct GetMethodResponseRequestBuilder {
+    http_method: Option<String>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+    status_code: Option<String>,
+}
+
+impl GetMethodResponseRequestBuilder {
+    /// Sets the [`http_method`](struct.GetMethodResponseRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.GetMethodResponseRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetMethodResponseRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_code`](struct.GetMethodResponseRequest.html#structfield.status_code) member.
+    pub fn status_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.status_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMethodResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetMethodResponseRequest, ::rusoto_core::BuildError> {
+        Ok(GetMethodResponseRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            status_code: self
+                .status_code
+                .ok_or(::rusoto_core::BuildError::missing("status_code"))?,
+        })
+    }
+}
+
 /// <p>Request to list information about a model in an existing <a>RestApi</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetModelRequest {
@@ -1656,6 +5915,54 @@ pub struct GetModelRequest {
     pub rest_api_id: String,
 }
 
+impl GetModelRequest {
+    /// Returns a builder for `GetModelRequest`, checking its required members when built.
+    pub fn builder() -> GetModelRequestBuilder {
+        GetModelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetModelRequest`](struct.GetModelRequest.html).
+#[derive(Default, Debug)]
+pub struct GetModelRequestBuilder {
+    flatten: Option<bool>,
+    model_name: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetModelRequestBuilder {
+    /// Sets the [`flatten`](struct.GetModelRequest.html#structfield.flatten) member.
+    pub fn flatten<T: Into<bool>>(mut self, value: T) -> Self {
+        self.flatten = Some(value.into());
+        self
+    }
+
+    /// Sets the [`model_name`](struct.GetModelRequest.html#structfield.model_name) member.
+    pub fn model_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.model_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetModelRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetModelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetModelRequest, ::rusoto_core::BuildError> {
+        Ok(GetModelRequest {
+            flatten: self.flatten,
+            model_name: self
+                .model_name
+                .ok_or(::rusoto_core::BuildError::missing("model_name"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to generate a sample mapping template used to transform the payload.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetModelTemplateRequest {
@@ -1667,6 +5974,48 @@ pub struct GetModelTemplateRequest {
     pub rest_api_id: String,
 }
 
+impl GetModelTemplateRequest {
+    /// Returns a builder for `GetModelTemplateRequest`, checking its required members when built.
+    pub fn builder() -> GetModelTemplateRequestBuilder {
+        GetModelTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetModelTemplateRequest`](struct.GetModelTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetModelTemplateRequestBuilder {
+    model_name: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetModelTemplateRequestBuilder {
+    /// Sets the [`model_name`](struct.GetModelTemplateRequest.html#structfield.model_name) member.
+    pub fn model_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.model_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetModelTemplateRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetModelTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetModelTemplateRequest, ::rusoto_core::BuildError> {
+        Ok(GetModelTemplateRequest {
+            model_name: self
+                .model_name
+                .ok_or(::rusoto_core::BuildError::missing("model_name"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to list existing <a>Models</a> defined for a <a>RestApi</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetModelsRequest {
@@ -1683,6 +6032,52 @@ pub struct GetModelsRequest {
     pub rest_api_id: String,
 }
 
+impl GetModelsRequest {
+    /// Returns a builder for `GetModelsRequest`, checking its required members when built.
+    pub fn builder() -> GetModelsRequestBuilder {
+        GetModelsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetModelsRequest`](struct.GetModelsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetModelsRequestBuilder {
+    limit: Option<i64>,
+    position: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetModelsRequestBuilder {
+    /// Sets the [`limit`](struct.GetModelsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetModelsRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetModelsRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetModelsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetModelsRequest, ::rusoto_core::BuildError> {
+        Ok(GetModelsRequest {
+            limit: self.limit,
+            position: self.position,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Gets a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetRequestValidatorRequest {
@@ -1694,15 +6089,57 @@ pub struct GetRequestValidatorRequest {
     pub rest_api_id: String,
 }
 
-/// <p>Gets the <a>RequestValidators</a> collection of a given <a>RestApi</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetRequestValidatorsRequest {
-    /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
-    #[serde(rename = "limit")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    /// <p>The current pagination position in the paged result set.</p>
-    #[serde(rename = "position")]
+impl GetRequestValidatorRequest {
+    /// Returns a builder for `GetRequestValidatorRequest`, checking its required members when built.
+    pub fn builder() -> GetRequestValidatorRequestBuilder {
+        GetRequestValidatorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRequestValidatorRequest`](struct.GetRequestValidatorRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRequestValidatorRequestBuilder {
+    request_validator_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetRequestValidatorRequestBuilder {
+    /// Sets the [`request_validator_id`](struct.GetRequestValidatorRequest.html#structfield.request_validator_id) member.
+    pub fn request_validator_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.request_validator_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetRequestValidatorRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRequestValidatorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRequestValidatorRequest, ::rusoto_core::BuildError> {
+        Ok(GetRequestValidatorRequest {
+            request_validator_id: self
+                .request_validator_id
+                .ok_or(::rusoto_core::BuildError::missing("request_validator_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
+/// <p>Gets the <a>RequestValidators</a> collection of a given <a>RestApi</a>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetRequestValidatorsRequest {
+    /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
+    #[serde(rename = "limit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// <p>The current pagination position in the paged result set.</p>
+    #[serde(rename = "position")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub position: Option<String>,
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
@@ -1710,6 +6147,54 @@ pub struct GetRequestValidatorsRequest {
     pub rest_api_id: String,
 }
 
+impl GetRequestValidatorsRequest {
+    /// Returns a builder for `GetRequestValidatorsRequest`, checking its required members when built.
+    pub fn builder() -> GetRequestValidatorsRequestBuilder {
+        GetRequestValidatorsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRequestValidatorsRequest`](struct.GetRequestValidatorsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRequestValidatorsRequestBuilder {
+    limit: Option<i64>,
+    position: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetRequestValidatorsRequestBuilder {
+    /// Sets the [`limit`](struct.GetRequestValidatorsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetRequestValidatorsRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetRequestValidatorsRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRequestValidatorsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRequestValidatorsRequest, ::rusoto_core::BuildError> {
+        Ok(GetRequestValidatorsRequest {
+            limit: self.limit,
+            position: self.position,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to list information about a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetResourceRequest {
@@ -1725,6 +6210,54 @@ pub struct GetResourceRequest {
     pub rest_api_id: String,
 }
 
+impl GetResourceRequest {
+    /// Returns a builder for `GetResourceRequest`, checking its required members when built.
+    pub fn builder() -> GetResourceRequestBuilder {
+        GetResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourceRequest`](struct.GetResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResourceRequestBuilder {
+    embed: Option<Vec<String>>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetResourceRequestBuilder {
+    /// Sets the [`embed`](struct.GetResourceRequest.html#structfield.embed) member.
+    pub fn embed<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.embed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.GetResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetResourceRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetResourceRequest, ::rusoto_core::BuildError> {
+        Ok(GetResourceRequest {
+            embed: self.embed,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to list information about a collection of resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetResourcesRequest {
@@ -1745,6 +6278,60 @@ pub struct GetResourcesRequest {
     pub rest_api_id: String,
 }
 
+impl GetResourcesRequest {
+    /// Returns a builder for `GetResourcesRequest`, checking its required members when built.
+    pub fn builder() -> GetResourcesRequestBuilder {
+        GetResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourcesRequest`](struct.GetResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResourcesRequestBuilder {
+    embed: Option<Vec<String>>,
+    limit: Option<i64>,
+    position: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetResourcesRequestBuilder {
+    /// Sets the [`embed`](struct.GetResourcesRequest.html#structfield.embed) member.
+    pub fn embed<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.embed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetResourcesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetResourcesRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetResourcesRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(GetResourcesRequest {
+            embed: self.embed,
+            limit: self.limit,
+            position: self.position,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>The GET request to list an existing <a>RestApi</a> defined for your collection. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetRestApiRequest {
@@ -1753,6 +6340,36 @@ pub struct GetRestApiRequest {
     pub rest_api_id: String,
 }
 
+impl GetRestApiRequest {
+    /// Returns a builder for `GetRestApiRequest`, checking its required members when built.
+    pub fn builder() -> GetRestApiRequestBuilder {
+        GetRestApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRestApiRequest`](struct.GetRestApiRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRestApiRequestBuilder {
+    rest_api_id: Option<String>,
+}
+
+impl GetRestApiRequestBuilder {
+    /// Sets the [`rest_api_id`](struct.GetRestApiRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRestApiRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRestApiRequest, ::rusoto_core::BuildError> {
+        Ok(GetRestApiRequest {
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>The GET request to list existing <a>RestApis</a> defined for your collection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetRestApisRequest {
@@ -1766,6 +6383,42 @@ pub struct GetRestApisRequest {
     pub position: Option<String>,
 }
 
+impl GetRestApisRequest {
+    /// Returns a builder for `GetRestApisRequest`, checking its required members when built.
+    pub fn builder() -> GetRestApisRequestBuilder {
+        GetRestApisRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRestApisRequest`](struct.GetRestApisRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRestApisRequestBuilder {
+    limit: Option<i64>,
+    position: Option<String>,
+}
+
+impl GetRestApisRequestBuilder {
+    /// Sets the [`limit`](struct.GetRestApisRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetRestApisRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRestApisRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRestApisRequest, ::rusoto_core::BuildError> {
+        Ok(GetRestApisRequest {
+            limit: self.limit,
+            position: self.position,
+        })
+    }
+}
+
 /// <p>Request a new generated client SDK for a <a>RestApi</a> and <a>Stage</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetSdkRequest {
@@ -1784,6 +6437,67 @@ pub struct GetSdkRequest {
     pub stage_name: String,
 }
 
+impl GetSdkRequest {
+    /// Returns a builder for `GetSdkRequest`, checking its required members when built.
+    pub fn builder() -> GetSdkRequestBuilder {
+        GetSdkRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSdkRequest`](struct.GetSdkRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSdkRequestBuilder {
+    parameters: Option<::std::collections::HashMap<String, String>>,
+    rest_api_id: Option<String>,
+    sdk_type: Option<String>,
+    stage_name: Option<String>,
+}
+
+impl GetSdkRequestBuilder {
+    /// Sets the [`parameters`](struct.GetSdkRequest.html#structfield.parameters) member.
+    pub fn parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetSdkRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sdk_type`](struct.GetSdkRequest.html#structfield.sdk_type) member.
+    pub fn sdk_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.sdk_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.GetSdkRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSdkRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSdkRequest, ::rusoto_core::BuildError> {
+        Ok(GetSdkRequest {
+            parameters: self.parameters,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            sdk_type: self
+                .sdk_type
+                .ok_or(::rusoto_core::BuildError::missing("sdk_type"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+        })
+    }
+}
+
 /// <p>Get an <a>SdkType</a> instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetSdkTypeRequest {
@@ -1792,6 +6506,34 @@ pub struct GetSdkTypeRequest {
     pub id: String,
 }
 
+impl GetSdkTypeRequest {
+    /// Returns a builder for `GetSdkTypeRequest`, checking its required members when built.
+    pub fn builder() -> GetSdkTypeRequestBuilder {
+        GetSdkTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSdkTypeRequest`](struct.GetSdkTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSdkTypeRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetSdkTypeRequestBuilder {
+    /// Sets the [`id`](struct.GetSdkTypeRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSdkTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSdkTypeRequest, ::rusoto_core::BuildError> {
+        Ok(GetSdkTypeRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>Get the <a>SdkTypes</a> collection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetSdkTypesRequest {
@@ -1805,6 +6547,42 @@ pub struct GetSdkTypesRequest {
     pub position: Option<String>,
 }
 
+impl GetSdkTypesRequest {
+    /// Returns a builder for `GetSdkTypesRequest`, checking its required members when built.
+    pub fn builder() -> GetSdkTypesRequestBuilder {
+        GetSdkTypesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSdkTypesRequest`](struct.GetSdkTypesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSdkTypesRequestBuilder {
+    limit: Option<i64>,
+    position: Option<String>,
+}
+
+impl GetSdkTypesRequestBuilder {
+    /// Sets the [`limit`](struct.GetSdkTypesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetSdkTypesRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSdkTypesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSdkTypesRequest, ::rusoto_core::BuildError> {
+        Ok(GetSdkTypesRequest {
+            limit: self.limit,
+            position: self.position,
+        })
+    }
+}
+
 /// <p>Requests API Gateway to get information about a <a>Stage</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetStageRequest {
@@ -1816,6 +6594,46 @@ pub struct GetStageRequest {
     pub stage_name: String,
 }
 
+impl GetStageRequest {
+    /// Returns a builder for `GetStageRequest`, checking its required members when built.
+    pub fn builder() -> GetStageRequestBuilder {
+        GetStageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetStageRequest`](struct.GetStageRequest.html).
+#[derive(Default, Debug)]
+pub struct GetStageRequestBuilder {
+    rest_api_id: Option<String>,
+    stage_name: Option<String>,
+}
+
+impl GetStageRequestBuilder {
+    /// Sets the [`rest_api_id`](struct.GetStageRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.GetStageRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetStageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetStageRequest, ::rusoto_core::BuildError> {
+        Ok(GetStageRequest {
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+        })
+    }
+}
+
 /// <p>Requests API Gateway to get information about one or more <a>Stage</a> resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetStagesRequest {
@@ -1828,6 +6646,44 @@ pub struct GetStagesRequest {
     pub rest_api_id: String,
 }
 
+impl GetStagesRequest {
+    /// Returns a builder for `GetStagesRequest`, checking its required members when built.
+    pub fn builder() -> GetStagesRequestBuilder {
+        GetStagesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetStagesRequest`](struct.GetStagesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetStagesRequestBuilder {
+    deployment_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl GetStagesRequestBuilder {
+    /// Sets the [`deployment_id`](struct.GetStagesRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.GetStagesRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetStagesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetStagesRequest, ::rusoto_core::BuildError> {
+        Ok(GetStagesRequest {
+            deployment_id: self.deployment_id,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Gets the <a>Tags</a> collection for a given resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetTagsRequest {
@@ -1844,6 +6700,52 @@ pub struct GetTagsRequest {
     pub resource_arn: String,
 }
 
+impl GetTagsRequest {
+    /// Returns a builder for `GetTagsRequest`, checking its required members when built.
+    pub fn builder() -> GetTagsRequestBuilder {
+        GetTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTagsRequest`](struct.GetTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTagsRequestBuilder {
+    limit: Option<i64>,
+    position: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl GetTagsRequestBuilder {
+    /// Sets the [`limit`](struct.GetTagsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetTagsRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.GetTagsRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTagsRequest, ::rusoto_core::BuildError> {
+        Ok(GetTagsRequest {
+            limit: self.limit,
+            position: self.position,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 /// <p>The GET request to get a usage plan key of a given key identifier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetUsagePlanKeyRequest {
@@ -1855,6 +6757,46 @@ pub struct GetUsagePlanKeyRequest {
     pub usage_plan_id: String,
 }
 
+impl GetUsagePlanKeyRequest {
+    /// Returns a builder for `GetUsagePlanKeyRequest`, checking its required members when built.
+    pub fn builder() -> GetUsagePlanKeyRequestBuilder {
+        GetUsagePlanKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUsagePlanKeyRequest`](struct.GetUsagePlanKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUsagePlanKeyRequestBuilder {
+    key_id: Option<String>,
+    usage_plan_id: Option<String>,
+}
+
+impl GetUsagePlanKeyRequestBuilder {
+    /// Sets the [`key_id`](struct.GetUsagePlanKeyRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`usage_plan_id`](struct.GetUsagePlanKeyRequest.html#structfield.usage_plan_id) member.
+    pub fn usage_plan_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.usage_plan_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUsagePlanKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetUsagePlanKeyRequest, ::rusoto_core::BuildError> {
+        Ok(GetUsagePlanKeyRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            usage_plan_id: self
+                .usage_plan_id
+                .ok_or(::rusoto_core::BuildError::missing("usage_plan_id"))?,
+        })
+    }
+}
+
 /// <p>The GET request to get all the usage plan keys representing the API keys added to a specified usage plan.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetUsagePlanKeysRequest {
@@ -1875,6 +6817,62 @@ pub struct GetUsagePlanKeysRequest {
     pub usage_plan_id: String,
 }
 
+impl GetUsagePlanKeysRequest {
+    /// Returns a builder for `GetUsagePlanKeysRequest`, checking its required members when built.
+    pub fn builder() -> GetUsagePlanKeysRequestBuilder {
+        GetUsagePlanKeysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUsagePlanKeysRequest`](struct.GetUsagePlanKeysRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUsagePlanKeysRequestBuilder {
+    limit: Option<i64>,
+    name_query: Option<String>,
+    position: Option<String>,
+    usage_plan_id: Option<String>,
+}
+
+impl GetUsagePlanKeysRequestBuilder {
+    /// Sets the [`limit`](struct.GetUsagePlanKeysRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_query`](struct.GetUsagePlanKeysRequest.html#structfield.name_query) member.
+    pub fn name_query<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_query = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetUsagePlanKeysRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`usage_plan_id`](struct.GetUsagePlanKeysRequest.html#structfield.usage_plan_id) member.
+    pub fn usage_plan_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.usage_plan_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUsagePlanKeysRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetUsagePlanKeysRequest, ::rusoto_core::BuildError> {
+        Ok(GetUsagePlanKeysRequest {
+            limit: self.limit,
+            name_query: self.name_query,
+            position: self.position,
+            usage_plan_id: self
+                .usage_plan_id
+                .ok_or(::rusoto_core::BuildError::missing("usage_plan_id"))?,
+        })
+    }
+}
+
 /// <p>The GET request to get a usage plan of a given plan identifier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetUsagePlanRequest {
@@ -1883,6 +6881,36 @@ pub struct GetUsagePlanRequest {
     pub usage_plan_id: String,
 }
 
+impl GetUsagePlanRequest {
+    /// Returns a builder for `GetUsagePlanRequest`, checking its required members when built.
+    pub fn builder() -> GetUsagePlanRequestBuilder {
+        GetUsagePlanRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUsagePlanRequest`](struct.GetUsagePlanRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUsagePlanRequestBuilder {
+    usage_plan_id: Option<String>,
+}
+
+impl GetUsagePlanRequestBuilder {
+    /// Sets the [`usage_plan_id`](struct.GetUsagePlanRequest.html#structfield.usage_plan_id) member.
+    pub fn usage_plan_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.usage_plan_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUsagePlanRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetUsagePlanRequest, ::rusoto_core::BuildError> {
+        Ok(GetUsagePlanRequest {
+            usage_plan_id: self
+                .usage_plan_id
+                .ok_or(::rusoto_core::BuildError::missing("usage_plan_id"))?,
+        })
+    }
+}
+
 /// <p>The GET request to get all the usage plans of the caller's account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetUsagePlansRequest {
@@ -1900,6 +6928,50 @@ pub struct GetUsagePlansRequest {
     pub position: Option<String>,
 }
 
+impl GetUsagePlansRequest {
+    /// Returns a builder for `GetUsagePlansRequest`, checking its required members when built.
+    pub fn builder() -> GetUsagePlansRequestBuilder {
+        GetUsagePlansRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUsagePlansRequest`](struct.GetUsagePlansRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUsagePlansRequestBuilder {
+    key_id: Option<String>,
+    limit: Option<i64>,
+    position: Option<String>,
+}
+
+impl GetUsagePlansRequestBuilder {
+    /// Sets the [`key_id`](struct.GetUsagePlansRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetUsagePlansRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetUsagePlansRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUsagePlansRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetUsagePlansRequest, ::rusoto_core::BuildError> {
+        Ok(GetUsagePlansRequest {
+            key_id: self.key_id,
+            limit: self.limit,
+            position: self.position,
+        })
+    }
+}
+
 /// <p>The GET request to get the usage data of a usage plan in a specified time interval.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetUsageRequest {
@@ -1926,6 +6998,80 @@ pub struct GetUsageRequest {
     pub usage_plan_id: String,
 }
 
+impl GetUsageRequest {
+    /// Returns a builder for `GetUsageRequest`, checking its required members when built.
+    pub fn builder() -> GetUsageRequestBuilder {
+        GetUsageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUsageRequest`](struct.GetUsageRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUsageRequestBuilder {
+    end_date: Option<String>,
+    key_id: Option<String>,
+    limit: Option<i64>,
+    position: Option<String>,
+    start_date: Option<String>,
+    usage_plan_id: Option<String>,
+}
+
+impl GetUsageRequestBuilder {
+    /// Sets the [`end_date`](struct.GetUsageRequest.html#structfield.end_date) member.
+    pub fn end_date<T: Into<String>>(mut self, value: T) -> Self {
+        self.end_date = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.GetUsageRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetUsageRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetUsageRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_date`](struct.GetUsageRequest.html#structfield.start_date) member.
+    pub fn start_date<T: Into<String>>(mut self, value: T) -> Self {
+        self.start_date = Some(value.into());
+        self
+    }
+
+    /// Sets the [`usage_plan_id`](struct.GetUsageRequest.html#structfield.usage_plan_id) member.
+    pub fn usage_plan_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.usage_plan_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUsageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetUsageRequest, ::rusoto_core::BuildError> {
+        Ok(GetUsageRequest {
+            end_date: self
+                .end_date
+                .ok_or(::rusoto_core::BuildError::missing("end_date"))?,
+            key_id: self.key_id,
+            limit: self.limit,
+            position: self.position,
+            start_date: self
+                .start_date
+                .ok_or(::rusoto_core::BuildError::missing("start_date"))?,
+            usage_plan_id: self
+                .usage_plan_id
+                .ok_or(::rusoto_core::BuildError::missing("usage_plan_id"))?,
+        })
+    }
+}
+
 /// <p>Gets a specified VPC link under the caller's account in a region.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetVpcLinkRequest {
@@ -1934,10 +7080,40 @@ pub struct GetVpcLinkRequest {
     pub vpc_link_id: String,
 }
 
-/// <p>Gets the <a>VpcLinks</a> collection under the caller's account in a selected region.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetVpcLinksRequest {
-    /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
+impl GetVpcLinkRequest {
+    /// Returns a builder for `GetVpcLinkRequest`, checking its required members when built.
+    pub fn builder() -> GetVpcLinkRequestBuilder {
+        GetVpcLinkRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetVpcLinkRequest`](struct.GetVpcLinkRequest.html).
+#[derive(Default, Debug)]
+pub struct GetVpcLinkRequestBuilder {
+    vpc_link_id: Option<String>,
+}
+
+impl GetVpcLinkRequestBuilder {
+    /// Sets the [`vpc_link_id`](struct.GetVpcLinkRequest.html#structfield.vpc_link_id) member.
+    pub fn vpc_link_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpc_link_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetVpcLinkRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetVpcLinkRequest, ::rusoto_core::BuildError> {
+        Ok(GetVpcLinkRequest {
+            vpc_link_id: self
+                .vpc_link_id
+                .ok_or(::rusoto_core::BuildError::missing("vpc_link_id"))?,
+        })
+    }
+}
+
+/// <p>Gets the <a>VpcLinks</a> collection under the caller's account in a selected region.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetVpcLinksRequest {
+    /// <p>The maximum number of returned results per page. The default value is 25 and the maximum value is 500.</p>
     #[serde(rename = "limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i64>,
@@ -1947,8 +7123,44 @@ pub struct GetVpcLinksRequest {
     pub position: Option<String>,
 }
 
+impl GetVpcLinksRequest {
+    /// Returns a builder for `GetVpcLinksRequest`, checking its required members when built.
+    pub fn builder() -> GetVpcLinksRequestBuilder {
+        GetVpcLinksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetVpcLinksRequest`](struct.GetVpcLinksRequest.html).
+#[derive(Default, Debug)]
+pub struct GetVpcLinksRequestBuilder {
+    limit: Option<i64>,
+    position: Option<String>,
+}
+
+impl GetVpcLinksRequestBuilder {
+    /// Sets the [`limit`](struct.GetVpcLinksRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`position`](struct.GetVpcLinksRequest.html#structfield.position) member.
+    pub fn position<T: Into<String>>(mut self, value: T) -> Self {
+        self.position = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetVpcLinksRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetVpcLinksRequest, ::rusoto_core::BuildError> {
+        Ok(GetVpcLinksRequest {
+            limit: self.limit,
+            position: self.position,
+        })
+    }
+}
+
 /// <p>The POST request to import API keys from an external source, such as a CSV-formatted file.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ImportApiKeysRequest {
     /// <p>The payload of the POST request to import API keys. For the payload format, see <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-key-file-format.html">API Key File Format</a>.</p>
     #[serde(rename = "body")]
@@ -1964,7 +7176,55 @@ pub struct ImportApiKeysRequest {
     pub fail_on_warnings: Option<bool>,
     /// <p>A query parameter to specify the input format to imported API keys. Currently, only the <code>csv</code> format is supported.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: ApiKeysFormat,
+}
+
+impl ImportApiKeysRequest {
+    /// Returns a builder for `ImportApiKeysRequest`, checking its required members when built.
+    pub fn builder() -> ImportApiKeysRequestBuilder {
+        ImportApiKeysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ImportApiKeysRequest`](struct.ImportApiKeysRequest.html).
+#[derive(Default, Debug)]
+pub struct ImportApiKeysRequestBuilder {
+    body: Option<bytes::Bytes>,
+    fail_on_warnings: Option<bool>,
+    format: Option<ApiKeysFormat>,
+}
+
+impl ImportApiKeysRequestBuilder {
+    /// Sets the [`body`](struct.ImportApiKeysRequest.html#structfield.body) member.
+    pub fn body<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fail_on_warnings`](struct.ImportApiKeysRequest.html#structfield.fail_on_warnings) member.
+    pub fn fail_on_warnings<T: Into<bool>>(mut self, value: T) -> Self {
+        self.fail_on_warnings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`format`](struct.ImportApiKeysRequest.html#structfield.format) member.
+    pub fn format<T: Into<ApiKeysFormat>>(mut self, value: T) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportApiKeysRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ImportApiKeysRequest, ::rusoto_core::BuildError> {
+        Ok(ImportApiKeysRequest {
+            body: self
+                .body
+                .ok_or(::rusoto_core::BuildError::missing("body"))?,
+            fail_on_warnings: self.fail_on_warnings,
+            format: self
+                .format
+                .ok_or(::rusoto_core::BuildError::missing("format"))?,
+        })
+    }
 }
 
 /// <p>Import documentation parts from an external (e.g., OpenAPI) definition file. </p>
@@ -1985,12 +7245,70 @@ pub struct ImportDocumentationPartsRequest {
     /// <p>A query parameter to indicate whether to overwrite (<code>OVERWRITE</code>) any existing <a>DocumentationParts</a> definition or to merge (<code>MERGE</code>) the new definition into the existing one. The default value is <code>MERGE</code>.</p>
     #[serde(rename = "mode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
+    pub mode: Option<PutMode>,
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
     pub rest_api_id: String,
 }
 
+impl ImportDocumentationPartsRequest {
+    /// Returns a builder for `ImportDocumentationPartsRequest`, checking its required members when built.
+    pub fn builder() -> ImportDocumentationPartsRequestBuilder {
+        ImportDocumentationPartsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ImportDocumentationPartsRequest`](struct.ImportDocumentationPartsRequest.html).
+#[derive(Default, Debug)]
+pub struct ImportDocumentationPartsRequestBuilder {
+    body: Option<bytes::Bytes>,
+    fail_on_warnings: Option<bool>,
+    mode: Option<PutMode>,
+    rest_api_id: Option<String>,
+}
+
+impl ImportDocumentationPartsRequestBuilder {
+    /// Sets the [`body`](struct.ImportDocumentationPartsRequest.html#structfield.body) member.
+    pub fn body<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fail_on_warnings`](struct.ImportDocumentationPartsRequest.html#structfield.fail_on_warnings) member.
+    pub fn fail_on_warnings<T: Into<bool>>(mut self, value: T) -> Self {
+        self.fail_on_warnings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mode`](struct.ImportDocumentationPartsRequest.html#structfield.mode) member.
+    pub fn mode<T: Into<PutMode>>(mut self, value: T) -> Self {
+        self.mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.ImportDocumentationPartsRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportDocumentationPartsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ImportDocumentationPartsRequest, ::rusoto_core::BuildError> {
+        Ok(ImportDocumentationPartsRequest {
+            body: self
+                .body
+                .ok_or(::rusoto_core::BuildError::missing("body"))?,
+            fail_on_warnings: self.fail_on_warnings,
+            mode: self.mode,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>A POST request to import an API to API Gateway using an input of an API definition file.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ImportRestApiRequest {
@@ -2012,6 +7330,55 @@ pub struct ImportRestApiRequest {
     pub parameters: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl ImportRestApiRequest {
+    /// Returns a builder for `ImportRestApiRequest`, checking its required members when built.
+    pub fn builder() -> ImportRestApiRequestBuilder {
+        ImportRestApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ImportRestApiRequest`](struct.ImportRestApiRequest.html).
+#[derive(Default, Debug)]
+pub struct ImportRestApiRequestBuilder {
+    body: Option<bytes::Bytes>,
+    fail_on_warnings: Option<bool>,
+    parameters: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl ImportRestApiRequestBuilder {
+    /// Sets the [`body`](struct.ImportRestApiRequest.html#structfield.body) member.
+    pub fn body<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fail_on_warnings`](struct.ImportRestApiRequest.html#structfield.fail_on_warnings) member.
+    pub fn fail_on_warnings<T: Into<bool>>(mut self, value: T) -> Self {
+        self.fail_on_warnings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ImportRestApiRequest.html#structfield.parameters) member.
+    pub fn parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportRestApiRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ImportRestApiRequest, ::rusoto_core::BuildError> {
+        Ok(ImportRestApiRequest {
+            body: self
+                .body
+                .ok_or(::rusoto_core::BuildError::missing("body"))?,
+            fail_on_warnings: self.fail_on_warnings,
+            parameters: self.parameters,
+        })
+    }
+}
+
 /// <p><p>Represents an HTTP, HTTP<em>PROXY, AWS, AWS</em>PROXY, or Mock integration.</p> <div class="remarks">In the API Gateway console, the built-in Lambda integration is an AWS integration.</div> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-create-api.html">Creating an API</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2031,11 +7398,11 @@ pub struct Integration {
     /// <p>The type of the network connection to the integration endpoint. The valid value is <code>INTERNET</code> for connections through the public routable internet or <code>VPC_LINK</code> for private connections between API Gateway and a network load balancer in a VPC. The default value is <code>INTERNET</code>.</p>
     #[serde(rename = "connectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Specifies how to handle request payload content type conversions. Supported values are <code>CONVERT_TO_BINARY</code> and <code>CONVERT_TO_TEXT</code>, with the following behaviors:</p> <ul> <li><p><code>CONVERT_TO_BINARY</code>: Converts a request payload from a Base64-encoded string to the corresponding binary blob.</p></li> <li><p><code>CONVERT_TO_TEXT</code>: Converts a request payload from a binary blob to a Base64-encoded string.</p></li> </ul> <p>If this property is not defined, the request payload will be passed through from the method request to integration request without modification, provided that the <code>passthroughBehavior</code> is configured to support payload pass-through.</p>
     #[serde(rename = "contentHandling")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling: Option<String>,
+    pub content_handling: Option<ContentHandlingStrategy>,
     /// <p>Specifies the credentials required for the integration, if any. For AWS integrations, three options are available. To specify an IAM Role for API Gateway to assume, use the role's Amazon Resource Name (ARN). To require that the caller's identity be passed through from the request, specify the string <code>arn:aws:iam::\*:user/\*</code>. To use resource-based permissions on supported AWS services, specify null.</p>
     #[serde(rename = "credentials")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2067,7 +7434,7 @@ pub struct Integration {
     /// <p>Specifies an API method integration type. The valid value is one of the following:</p> <ul> <li><code>AWS</code>: for integrating the API method request with an AWS service action, including the Lambda function-invoking action. With the Lambda function-invoking action, this is referred to as the Lambda custom integration. With any other AWS service action, this is known as AWS integration.</li> <li><code>AWS_PROXY</code>: for integrating the API method request with the Lambda function-invoking action with the client request passed through as-is. This integration is also referred to as the Lambda proxy integration.</li> <li><code>HTTP</code>: for integrating the API method request with an HTTP endpoint, including a private HTTP endpoint within a VPC. This integration is also referred to as the HTTP custom integration.</li> <li><code>HTTP_PROXY</code>: for integrating the API method request with an HTTP endpoint, including a private HTTP endpoint within a VPC, with the client request passed through as-is. This is also referred to as the HTTP proxy integration.</li> <li><code>MOCK</code>: for integrating the API method request with API Gateway as a "loop-back" endpoint without invoking any backend.</li> </ul> <p>For the HTTP and HTTP proxy integrations, each integration can specify a protocol (<code>http/https</code>), port and path. Standard 80 and 443 ports are supported as well as custom ports above 1024. An HTTP or HTTP proxy integration with a <code>connectionType</code> of <code>VPC_LINK</code> is referred to as a private integration and uses a <a>VpcLink</a> to connect API Gateway to a network load balancer of a VPC.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<IntegrationType>,
     /// <p><p>Specifies Uniform Resource Identifier (URI) of the integration endpoint.</p> <ul> <li><p> For <code>HTTP</code> or <code>HTTP<em>PROXY</code> integrations, the URI must be a fully formed, encoded HTTP(S) URL according to the &lt;a target=&quot;</em>blank&quot; href=&quot;https://en.wikipedia.org/wiki/Uniform<em>Resource</em>Identifier&quot;&gt;RFC-3986 specification</a>, for either standard integration, where <code>connectionType</code> is not <code>VPC<em>LINK</code>, or private integration, where <code>connectionType</code> is <code>VPC</em>LINK</code>. For a private HTTP integration, the URI is not used for routing. </p> </li> <li><p> For <code>AWS</code> or <code>AWS<em>PROXY</code> integrations, the URI is of the form <code>arn:aws:apigateway:{region}:{subdomain.service|service}:path|action/{service</em>api}</code>. Here, <code>{Region}</code> is the API Gateway region (e.g., <code>us-east-1</code>); <code>{service}</code> is the name of the integrated AWS service (e.g., <code>s3</code>); and <code>{subdomain}</code> is a designated subdomain supported by certain AWS service for fast host-name lookup. <code>action</code> can be used for an AWS service action-based API, using an <code>Action={name}&amp;{p1}={v1}&amp;p2={v2}...</code> query string. The ensuing <code>{service<em>api}</code> refers to a supported action <code>{name}</code> plus any required input parameters. Alternatively, <code>path</code> can be used for an AWS service path-based API. The ensuing <code>service</em>api</code> refers to the path to an AWS service resource, including the region of the integrated AWS service, if applicable. For example, for integration with the S3 API of <code><a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTObjectGET.html">GetObject</a></code>, the <code>uri</code> can be either <code>arn:aws:apigateway:us-west-2:s3:action/GetObject&amp;Bucket={bucket}&amp;Key={key}</code> or <code>arn:aws:apigateway:us-west-2:s3:path/{bucket}/{key}</code></p> </li></ul></p>
     #[serde(rename = "uri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2081,7 +7448,7 @@ pub struct IntegrationResponse {
     /// <p>Specifies how to handle response payload content type conversions. Supported values are <code>CONVERT_TO_BINARY</code> and <code>CONVERT_TO_TEXT</code>, with the following behaviors:</p> <ul> <li><p><code>CONVERT_TO_BINARY</code>: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p></li> <li><p><code>CONVERT_TO_TEXT</code>: Converts a response payload from a binary blob to a Base64-encoded string.</p></li> </ul> <p>If this property is not defined, the response payload will be passed through from the integration response to the method response without modification.</p>
     #[serde(rename = "contentHandling")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling: Option<String>,
+    pub content_handling: Option<ContentHandlingStrategy>,
     /// <p>A key-value map specifying response parameters that are passed to the method response from the back end. The key is a method response header parameter name and the mapped value is an integration response header value, a static value enclosed within a pair of single quotes, or a JSON expression from the integration response body. The mapping key must match the pattern of <code>method.response.header.{name}</code>, where <code>name</code> is a valid and unique header name. The mapped non-static value must match the pattern of <code>integration.response.header.{name}</code> or <code>integration.response.body.{JSON-expression}</code>, where <code>name</code> is a valid and unique response header name and <code>JSON-expression</code> is a valid JSON expression without the <code>$</code> prefix.</p>
     #[serde(rename = "responseParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2100,6 +7467,174 @@ pub struct IntegrationResponse {
     pub status_code: Option<String>,
 }
 
+/// <p>The integration type. The valid value is <code>HTTP</code> for integrating an API method with an HTTP backend; <code>AWS</code> with any Amazon Web Services service endpoints; <code>MOCK</code> for testing without actually invoking the backend; <code>HTTP_PROXY</code> for integrating with the HTTP proxy integration; <code>AWS_PROXY</code> for integrating with the Lambda proxy integration. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IntegrationType {
+    /// <p><code>HTTP</code> - for integrating the API method request with an HTTP endpoint, including a private HTTP endpoint within a VPC. This integration is also referred to as the HTTP custom integration.</li> <li><code>HTTP_PROXY</code>: for integrating the API method request with an HTTP endpoint, including a private HTTP endpoint within a VPC, with the client request passed through as-is. This is also referred to as the HTTP proxy integration.</li> <li><code>MOCK</code>: for integrating the API method request with API Gateway as a "loop-back" endpoint without invoking any backend.</li> </ul> <p>For the HTTP and HTTP proxy integrations, each integration can specify a protocol (<code>http/https</code>), port and path. Standard 80 and 443 ports are supported as well as custom ports above 1024. An HTTP or HTTP proxy integration with a <code>connectionType</code> of <code>VPC_LINK</code> is referred to as a private integration and uses a <a>VpcLink</a> to connect API Gateway to a network load balancer of a VPC.</p>
+    Http,
+    /// <p><code>AWS</code> - for integrating the API method request with an AWS service action, including the Lambda function-invoking action. With the Lambda function-invoking action, this is referred to as the Lambda custom integration. With any other AWS service action, this is known as AWS integration.</li> <li><code>AWS_PROXY</code>: for integrating the API method request with the Lambda function-invoking action with the client request passed through as-is. This integration is also referred to as the Lambda proxy integration.</li> <li><code>HTTP</code>: for integrating the API method request with an HTTP endpoint, including a private HTTP endpoint within a VPC. This integration is also referred to as the HTTP custom integration.</li> <li><code>HTTP_PROXY</code>: for integrating the API method request with an HTTP endpoint, including a private HTTP endpoint within a VPC, with the client request passed through as-is. This is also referred to as the HTTP proxy integration.</li> <li><code>MOCK</code>: for integrating the API method request with API Gateway as a "loop-back" endpoint without invoking any backend.</li> </ul> <p>For the HTTP and HTTP proxy integrations, each integration can specify a protocol (<code>http/https</code>), port and path. Standard 80 and 443 ports are supported as well as custom ports above 1024. An HTTP or HTTP proxy integration with a <code>connectionType</code> of <code>VPC_LINK</code> is referred to as a private integration and uses a <a>VpcLink</a> to connect API Gateway to a network load balancer of a VPC.</p>
+    Aws,
+    /// <p><code>MOCK</code> - for integrating the API method request with API Gateway as a "loop-back" endpoint without invoking any backend.</li> </ul> <p>For the HTTP and HTTP proxy integrations, each integration can specify a protocol (<code>http/https</code>), port and path. Standard 80 and 443 ports are supported as well as custom ports above 1024. An HTTP or HTTP proxy integration with a <code>connectionType</code> of <code>VPC_LINK</code> is referred to as a private integration and uses a <a>VpcLink</a> to connect API Gateway to a network load balancer of a VPC.</p>
+    Mock,
+    /// <p><code>HTTP_PROXY</code> - for integrating the API method request with an HTTP endpoint, including a private HTTP endpoint within a VPC, with the client request passed through as-is. This is also referred to as the HTTP proxy integration.</li> <li><code>MOCK</code>: for integrating the API method request with API Gateway as a "loop-back" endpoint without invoking any backend.</li> </ul> <p>For the HTTP and HTTP proxy integrations, each integration can specify a protocol (<code>http/https</code>), port and path. Standard 80 and 443 ports are supported as well as custom ports above 1024. An HTTP or HTTP proxy integration with a <code>connectionType</code> of <code>VPC_LINK</code> is referred to as a private integration and uses a <a>VpcLink</a> to connect API Gateway to a network load balancer of a VPC.</p>
+    HttpProxy,
+    /// <p><code>AWS_PROXY</code> - for integrating the API method request with the Lambda function-invoking action with the client request passed through as-is. This integration is also referred to as the Lambda proxy integration.</li> <li><code>HTTP</code>: for integrating the API method request with an HTTP endpoint, including a private HTTP endpoint within a VPC. This integration is also referred to as the HTTP custom integration.</li> <li><code>HTTP_PROXY</code>: for integrating the API method request with an HTTP endpoint, including a private HTTP endpoint within a VPC, with the client request passed through as-is. This is also referred to as the HTTP proxy integration.</li> <li><code>MOCK</code>: for integrating the API method request with API Gateway as a "loop-back" endpoint without invoking any backend.</li> </ul> <p>For the HTTP and HTTP proxy integrations, each integration can specify a protocol (<code>http/https</code>), port and path. Standard 80 and 443 ports are supported as well as custom ports above 1024. An HTTP or HTTP proxy integration with a <code>connectionType</code> of <code>VPC_LINK</code> is referred to as a private integration and uses a <a>VpcLink</a> to connect API Gateway to a network load balancer of a VPC.</p>
+    AwsProxy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IntegrationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IntegrationType::Http => "HTTP",
+            IntegrationType::Aws => "AWS",
+            IntegrationType::Mock => "MOCK",
+            IntegrationType::HttpProxy => "HTTP_PROXY",
+            IntegrationType::AwsProxy => "AWS_PROXY",
+            IntegrationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IntegrationType {
+    fn from(value: &'a str) -> IntegrationType {
+        match value {
+            "HTTP" => IntegrationType::Http,
+            "AWS" => IntegrationType::Aws,
+            "MOCK" => IntegrationType::Mock,
+            "HTTP_PROXY" => IntegrationType::HttpProxy,
+            "AWS_PROXY" => IntegrationType::AwsProxy,
+            _ => IntegrationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IntegrationType {
+    fn from(value: String) -> IntegrationType {
+        IntegrationType::from(value.as_str())
+    }
+}
+
+impl From<IntegrationType> for String {
+    fn from(value: IntegrationType) -> String {
+        match value {
+            IntegrationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IntegrationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IntegrationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IntegrationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IntegrationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IntegrationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IntegrationType::from(value))
+    }
+}
+
+/// <p>The status of the API documentation parts to retrieve. Valid values are <code>DOCUMENTED</code> for retrieving <a>DocumentationPart</a> resources with content and <code>UNDOCUMENTED</code> for <a>DocumentationPart</a> resources without content.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LocationStatusType {
+    /// <p><code>DOCUMENTED</code></p>
+    Documented,
+    /// <p><code>UNDOCUMENTED</code></p>
+    Undocumented,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LocationStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LocationStatusType::Documented => "DOCUMENTED",
+            LocationStatusType::Undocumented => "UNDOCUMENTED",
+            LocationStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LocationStatusType {
+    fn from(value: &'a str) -> LocationStatusType {
+        match value {
+            "DOCUMENTED" => LocationStatusType::Documented,
+            "UNDOCUMENTED" => LocationStatusType::Undocumented,
+            _ => LocationStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LocationStatusType {
+    fn from(value: String) -> LocationStatusType {
+        LocationStatusType::from(value.as_str())
+    }
+}
+
+impl From<LocationStatusType> for String {
+    fn from(value: LocationStatusType) -> String {
+        match value {
+            LocationStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LocationStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LocationStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LocationStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LocationStatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LocationStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LocationStatusType::from(value))
+    }
+}
+
 /// <p><p> Represents a client-facing interface by which the client calls the API to access back-end resources. A <b>Method</b> resource is integrated with an <a>Integration</a> resource. Both consist of a request and one or more responses. The method request takes the client input that is passed to the back end through the integration request. A method response returns the output from the back end to the client through an integration response. A method request is embodied in a <b>Method</b> resource, whereas an integration request is embodied in an <a>Integration</a> resource. On the other hand, a method response is represented by a <a>MethodResponse</a> resource, whereas an integration response is represented by an <a>IntegrationResponse</a> resource. </p> <div class="remarks"> <p/> <h4>Example: Retrive the GET method on a specified resource</h4> <h5>Request</h5> <p>The following example request retrieves the information about the GET method on an API resource (<code>3kzxbg5sa2</code>) of an API (<code>fugvjdxtri</code>). </p> <pre><code>GET /restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET HTTP/1.1 Content-Type: application/json Host: apigateway.us-east-1.amazonaws.com X-Amz-Date: 20160603T210259Z Authorization: AWS4-HMAC-SHA256 Credential={access<em>key</em>ID}/20160603/us-east-1/apigateway/aws4<em>request, SignedHeaders=content-type;host;x-amz-date, Signature={sig4</em>hash}</code></pre> <h5>Response</h5> <p>The successful response returns a <code>200 OK</code> status code and a payload similar to the following:</p> <pre><code>{ &quot;<em>links&quot;: { &quot;curies&quot;: [ { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-integration-{rel}.html&quot;, &quot;name&quot;: &quot;integration&quot;, &quot;templated&quot;: true }, { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-integration-response-{rel}.html&quot;, &quot;name&quot;: &quot;integrationresponse&quot;, &quot;templated&quot;: true }, { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-method-{rel}.html&quot;, &quot;name&quot;: &quot;method&quot;, &quot;templated&quot;: true }, { &quot;href&quot;: &quot;https://docs.aws.amazon.com/apigateway/latest/developerguide/restapi-method-response-{rel}.html&quot;, &quot;name&quot;: &quot;methodresponse&quot;, &quot;templated&quot;: true } ], &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET&quot;, &quot;name&quot;: &quot;GET&quot;, &quot;title&quot;: &quot;GET&quot; }, &quot;integration:put&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;method:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET&quot; }, &quot;method:integration&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;method:responses&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot;, &quot;name&quot;: &quot;200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;method:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET&quot; }, &quot;methodresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/{status</em>code}&quot;, &quot;templated&quot;: true } }, &quot;apiKeyRequired&quot;: true, &quot;authorizationType&quot;: &quot;NONE&quot;, &quot;httpMethod&quot;: &quot;GET&quot;, &quot;<em>embedded&quot;: { &quot;method:integration&quot;: { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;integration:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;integration:responses&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/200&quot;, &quot;name&quot;: &quot;200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;integration:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration&quot; }, &quot;integrationresponse:put&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/{status<em>code}&quot;, &quot;templated&quot;: true } }, &quot;cacheKeyParameters&quot;: [], &quot;cacheNamespace&quot;: &quot;3kzxbg5sa2&quot;, &quot;credentials&quot;: &quot;arn:aws:iam::123456789012:role/apigAwsProxyRole&quot;, &quot;httpMethod&quot;: &quot;POST&quot;, &quot;passthroughBehavior&quot;: &quot;WHEN</em>NO<em>MATCH&quot;, &quot;requestParameters&quot;: { &quot;integration.request.header.Content-Type&quot;: &quot;&#39;application/x-amz-json-1.1&#39;&quot; }, &quot;requestTemplates&quot;: { &quot;application/json&quot;: &quot;{\n}&quot; }, &quot;type&quot;: &quot;AWS&quot;, &quot;uri&quot;: &quot;arn:aws:apigateway:us-east-1:kinesis:action/ListStreams&quot;, &quot;</em>embedded&quot;: { &quot;integration:responses&quot;: { &quot;<em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/200&quot;, &quot;name&quot;: &quot;200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;integrationresponse:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/200&quot; }, &quot;integrationresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/integration/responses/200&quot; } }, &quot;responseParameters&quot;: { &quot;method.response.header.Content-Type&quot;: &quot;&#39;application/xml&#39;&quot; }, &quot;responseTemplates&quot;: { &quot;application/json&quot;: &quot;$util.urlDecode(&quot;%3CkinesisStreams%3E%23foreach(%24stream%20in%20%24input.path(%27%24.StreamNames%27))%3Cstream%3E%3Cname%3E%24stream%3C%2Fname%3E%3C%2Fstream%3E%23end%3C%2FkinesisStreams%3E&quot;)&quot; }, &quot;statusCode&quot;: &quot;200&quot; } } }, &quot;method:responses&quot;: { &quot;</em>links&quot;: { &quot;self&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot;, &quot;name&quot;: &quot;200&quot;, &quot;title&quot;: &quot;200&quot; }, &quot;methodresponse:delete&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot; }, &quot;methodresponse:update&quot;: { &quot;href&quot;: &quot;/restapis/fugvjdxtri/resources/3kzxbg5sa2/methods/GET/responses/200&quot; } }, &quot;responseModels&quot;: { &quot;application/json&quot;: &quot;Empty&quot; }, &quot;responseParameters&quot;: { &quot;method.response.header.Content-Type&quot;: false }, &quot;statusCode&quot;: &quot;200&quot; } } }</code></pre> <p>In the example above, the response template for the <code>200 OK</code> response maps the JSON output from the <code>ListStreams</code> action in the back end to an XML output. The mapping template is URL-encoded as <code>%3CkinesisStreams%3E%23foreach(%24stream%20in%20%24input.path(%27%24.StreamNames%27))%3Cstream%3E%3Cname%3E%24stream%3C%2Fname%3E%3C%2Fstream%3E%23end%3C%2FkinesisStreams%3E</code> and the output is decoded using the <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-mapping-template-reference.html#util-templat-reference">$util.urlDecode()</a> helper function.</p> </div> <div class="seeAlso"> <a>MethodResponse</a>, <a>Integration</a>, <a>IntegrationResponse</a>, <a>Resource</a>, <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-method-settings.html">Set up an API&#39;s method</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2211,7 +7746,7 @@ pub struct MethodSetting {
     /// <p>Specifies how to handle unauthorized requests for cache invalidation. The PATCH path for this setting is <code>/{method_setting_key}/caching/unauthorizedCacheControlHeaderStrategy</code>, and the available values are <code>FAIL_WITH_403</code>, <code>SUCCEED_WITH_RESPONSE_HEADER</code>, <code>SUCCEED_WITHOUT_RESPONSE_HEADER</code>.</p>
     #[serde(rename = "unauthorizedCacheControlHeaderStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unauthorized_cache_control_header_strategy: Option<String>,
+    pub unauthorized_cache_control_header_strategy: Option<UnauthorizedCacheControlHeaderStrategy>,
 }
 
 /// <p>Represents a summary of a <a>Method</a> resource, given a particular date and time.</p>
@@ -2267,6 +7802,100 @@ pub struct Models {
     pub position: Option<String>,
 }
 
+/// <p> An update operation to be performed with this PATCH request. The valid value can be <code>add</code>, <code>remove</code>, <code>replace</code> or <code>copy</code>. Not all valid operations are supported for a given resource. Support of the operations depends on specific operational contexts. Attempts to apply an unsupported operation on a resource will return an error message.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Op {
+    /// <p><code>add</code></p>
+    Add,
+    /// <p><code>remove</code></p>
+    Remove,
+    /// <p><code>replace</code></p>
+    Replace,
+    /// <p><code>move</code></p>
+    Move,
+    /// <p><code>copy</code></p>
+    Copy,
+    /// <p><code>test</code></p>
+    Test,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Op {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Op::Add => "add",
+            Op::Remove => "remove",
+            Op::Replace => "replace",
+            Op::Move => "move",
+            Op::Copy => "copy",
+            Op::Test => "test",
+            Op::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Op {
+    fn from(value: &'a str) -> Op {
+        match value {
+            "add" => Op::Add,
+            "remove" => Op::Remove,
+            "replace" => Op::Replace,
+            "move" => Op::Move,
+            "copy" => Op::Copy,
+            "test" => Op::Test,
+            _ => Op::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Op {
+    fn from(value: String) -> Op {
+        Op::from(value.as_str())
+    }
+}
+
+impl From<Op> for String {
+    fn from(value: Op) -> String {
+        match value {
+            Op::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Op {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Op {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Op {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Op, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Op::from(value))
+    }
+}
+
 /// <p>A single patch operation to apply to the specified resource. Please refer to http://tools.ietf.org/html/rfc6902#section-4 for an explanation of how each operation is used.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PatchOperation {
@@ -2277,7 +7906,7 @@ pub struct PatchOperation {
     /// <p> An update operation to be performed with this PATCH request. The valid value can be <code>add</code>, <code>remove</code>, <code>replace</code> or <code>copy</code>. Not all valid operations are supported for a given resource. Support of the operations depends on specific operational contexts. Attempts to apply an unsupported operation on a resource will return an error message.</p>
     #[serde(rename = "op")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub op: Option<String>,
+    pub op: Option<Op>,
     /// <p>The <code>op</code> operation's target, as identified by a <a href="https://tools.ietf.org/html/draft-ietf-appsawg-json-pointer-08">JSON Pointer</a> value that references a location within the targeted resource. For example, if the target resource has an updateable property of <code>{"name":"value"}</code>, the path for this property is <code>/name</code>. If the <code>name</code> property value is a JSON object (e.g., <code>{"name": {"child/name": "child-value"}}</code>), the path for the <code>child/name</code> property will be <code>/name/child~1name</code>. Any slash ("/") character appearing in path names must be escaped with "~1", as shown in the example above. Each <code>op</code> operation can have only one <code>path</code> associated with it.</p>
     #[serde(rename = "path")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2289,7 +7918,7 @@ pub struct PatchOperation {
 }
 
 /// <p>Creates a customization of a <a>GatewayResponse</a> of a specified response type and status code on the given <a>RestApi</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutGatewayResponseRequest {
     /// <p><p>Response parameters (paths, query strings and headers) of the <a>GatewayResponse</a> as a string-to-string map of key-value pairs.</p></p>
     #[serde(rename = "responseParameters")]
@@ -2301,7 +7930,7 @@ pub struct PutGatewayResponseRequest {
     pub response_templates: Option<::std::collections::HashMap<String, String>>,
     /// <p>[Required] <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p></p>
     #[serde(rename = "responseType")]
-    pub response_type: String,
+    pub response_type: GatewayResponseType,
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
     pub rest_api_id: String,
@@ -2311,8 +7940,80 @@ pub struct PutGatewayResponseRequest {
     pub status_code: Option<String>,
 }
 
+impl PutGatewayResponseRequest {
+    /// Returns a builder for `PutGatewayResponseRequest`, checking its required members when built.
+    pub fn builder() -> PutGatewayResponseRequestBuilder {
+        PutGatewayResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutGatewayResponseRequest`](struct.PutGatewayResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct PutGatewayResponseRequestBuilder {
+    response_parameters: Option<::std::collections::HashMap<String, String>>,
+    response_templates: Option<::std::collections::HashMap<String, String>>,
+    response_type: Option<GatewayResponseType>,
+    rest_api_id: Option<String>,
+    status_code: Option<String>,
+}
+
+impl PutGatewayResponseRequestBuilder {
+    /// Sets the [`response_parameters`](struct.PutGatewayResponseRequest.html#structfield.response_parameters) member.
+    pub fn response_parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_templates`](struct.PutGatewayResponseRequest.html#structfield.response_templates) member.
+    pub fn response_templates<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_templates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_type`](struct.PutGatewayResponseRequest.html#structfield.response_type) member.
+    pub fn response_type<T: Into<GatewayResponseType>>(mut self, value: T) -> Self {
+        self.response_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.PutGatewayResponseRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_code`](struct.PutGatewayResponseRequest.html#structfield.status_code) member.
+    pub fn status_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.status_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutGatewayResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutGatewayResponseRequest, ::rusoto_core::BuildError> {
+        Ok(PutGatewayResponseRequest {
+            response_parameters: self.response_parameters,
+            response_templates: self.response_templates,
+            response_type: self
+                .response_type
+                .ok_or(::rusoto_core::BuildError::missing("response_type"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            status_code: self.status_code,
+        })
+    }
+}
+
 /// <p>Sets up a method's integration.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutIntegrationRequest {
     /// <p>An API-specific tag group of related cached parameters.</p>
     #[serde(rename = "cacheKeyParameters")]
@@ -2329,11 +8030,11 @@ pub struct PutIntegrationRequest {
     /// <p>The type of the network connection to the integration endpoint. The valid value is <code>INTERNET</code> for connections through the public routable internet or <code>VPC_LINK</code> for private connections between API Gateway and a network load balancer in a VPC. The default value is <code>INTERNET</code>.</p>
     #[serde(rename = "connectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Specifies how to handle request payload content type conversions. Supported values are <code>CONVERT_TO_BINARY</code> and <code>CONVERT_TO_TEXT</code>, with the following behaviors:</p> <ul> <li><p><code>CONVERT_TO_BINARY</code>: Converts a request payload from a Base64-encoded string to the corresponding binary blob.</p></li> <li><p><code>CONVERT_TO_TEXT</code>: Converts a request payload from a binary blob to a Base64-encoded string.</p></li> </ul> <p>If this property is not defined, the request payload will be passed through from the method request to integration request without modification, provided that the <code>passthroughBehavior</code> is configured to support payload pass-through.</p>
     #[serde(rename = "contentHandling")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling: Option<String>,
+    pub content_handling: Option<ContentHandlingStrategy>,
     /// <p>Specifies whether credentials are required for a put integration.</p>
     #[serde(rename = "credentials")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2369,20 +8070,182 @@ pub struct PutIntegrationRequest {
     pub timeout_in_millis: Option<i64>,
     /// <p>[Required] Specifies a put integration input's type.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: IntegrationType,
     /// <p><p>Specifies Uniform Resource Identifier (URI) of the integration endpoint.</p> <ul> <li><p> For <code>HTTP</code> or <code>HTTP<em>PROXY</code> integrations, the URI must be a fully formed, encoded HTTP(S) URL according to the &lt;a target=&quot;</em>blank&quot; href=&quot;https://en.wikipedia.org/wiki/Uniform<em>Resource</em>Identifier&quot;&gt;RFC-3986 specification</a>, for either standard integration, where <code>connectionType</code> is not <code>VPC<em>LINK</code>, or private integration, where <code>connectionType</code> is <code>VPC</em>LINK</code>. For a private HTTP integration, the URI is not used for routing. </p> </li> <li><p> For <code>AWS</code> or <code>AWS<em>PROXY</code> integrations, the URI is of the form <code>arn:aws:apigateway:{region}:{subdomain.service|service}:path|action/{service</em>api}</code>. Here, <code>{Region}</code> is the API Gateway region (e.g., <code>us-east-1</code>); <code>{service}</code> is the name of the integrated AWS service (e.g., <code>s3</code>); and <code>{subdomain}</code> is a designated subdomain supported by certain AWS service for fast host-name lookup. <code>action</code> can be used for an AWS service action-based API, using an <code>Action={name}&amp;{p1}={v1}&amp;p2={v2}...</code> query string. The ensuing <code>{service<em>api}</code> refers to a supported action <code>{name}</code> plus any required input parameters. Alternatively, <code>path</code> can be used for an AWS service path-based API. The ensuing <code>service</em>api</code> refers to the path to an AWS service resource, including the region of the integrated AWS service, if applicable. For example, for integration with the S3 API of <code><a href="https://docs.aws.amazon.com/AmazonS3/latest/API/RESTObjectGET.html">GetObject</a></code>, the <code>uri</code> can be either <code>arn:aws:apigateway:us-west-2:s3:action/GetObject&amp;Bucket={bucket}&amp;Key={key}</code> or <code>arn:aws:apigateway:us-west-2:s3:path/{bucket}/{key}</code></p> </li></ul></p>
     #[serde(rename = "uri")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub uri: Option<String>,
 }
 
+impl PutIntegrationRequest {
+    /// Returns a builder for `PutIntegrationRequest`, checking its required members when built.
+    pub fn builder() -> PutIntegrationRequestBuilder {
+        PutIntegrationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutIntegrationRequest`](struct.PutIntegrationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutIntegrationRequestBuilder {
+    cache_key_parameters: Option<Vec<String>>,
+    cache_namespace: Option<String>,
+    connection_id: Option<String>,
+    connection_type: Option<ConnectionType>,
+    content_handling: Option<ContentHandlingStrategy>,
+    credentials: Option<String>,
+    http_method: Option<String>,
+    integration_http_method: Option<String>,
+    passthrough_behavior: Option<String>,
+    request_parameters: Option<::std::collections::HashMap<String, String>>,
+    request_templates: Option<::std::collections::HashMap<String, String>>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+    timeout_in_millis: Option<i64>,
+    type_: Option<IntegrationType>,
+    uri: Option<String>,
+}
+
+impl PutIntegrationRequestBuilder {
+    /// Sets the [`cache_key_parameters`](struct.PutIntegrationRequest.html#structfield.cache_key_parameters) member.
+    pub fn cache_key_parameters<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cache_key_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_namespace`](struct.PutIntegrationRequest.html#structfield.cache_namespace) member.
+    pub fn cache_namespace<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_id`](struct.PutIntegrationRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_type`](struct.PutIntegrationRequest.html#structfield.connection_type) member.
+    pub fn connection_type<T: Into<ConnectionType>>(mut self, value: T) -> Self {
+        self.connection_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_handling`](struct.PutIntegrationRequest.html#structfield.content_handling) member.
+    pub fn content_handling<T: Into<ContentHandlingStrategy>>(mut self, value: T) -> Self {
+        self.content_handling = Some(value.into());
+        self
+    }
+
+    /// Sets the [`credentials`](struct.PutIntegrationRequest.html#structfield.credentials) member.
+    pub fn credentials<T: Into<String>>(mut self, value: T) -> Self {
+        self.credentials = Some(value.into());
+        self
+    }
+
+    /// Sets the [`http_method`](struct.PutIntegrationRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_http_method`](struct.PutIntegrationRequest.html#structfield.integration_http_method) member.
+    pub fn integration_http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`passthrough_behavior`](struct.PutIntegrationRequest.html#structfield.passthrough_behavior) member.
+    pub fn passthrough_behavior<T: Into<String>>(mut self, value: T) -> Self {
+        self.passthrough_behavior = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_parameters`](struct.PutIntegrationRequest.html#structfield.request_parameters) member.
+    pub fn request_parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_templates`](struct.PutIntegrationRequest.html#structfield.request_templates) member.
+    pub fn request_templates<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_templates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.PutIntegrationRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.PutIntegrationRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout_in_millis`](struct.PutIntegrationRequest.html#structfield.timeout_in_millis) member.
+    pub fn timeout_in_millis<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout_in_millis = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.PutIntegrationRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<IntegrationType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets the [`uri`](struct.PutIntegrationRequest.html#structfield.uri) member.
+    pub fn uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.uri = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutIntegrationRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutIntegrationRequest, ::rusoto_core::BuildError> {
+        Ok(PutIntegrationRequest {
+            cache_key_parameters: self.cache_key_parameters,
+            cache_namespace: self.cache_namespace,
+            connection_id: self.connection_id,
+            connection_type: self.connection_type,
+            content_handling: self.content_handling,
+            credentials: self.credentials,
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            integration_http_method: self.integration_http_method,
+            passthrough_behavior: self.passthrough_behavior,
+            request_parameters: self.request_parameters,
+            request_templates: self.request_templates,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            timeout_in_millis: self.timeout_in_millis,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+            uri: self.uri,
+        })
+    }
+}
+
 /// <p>Represents a put integration response request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutIntegrationResponseRequest {
     /// <p>Specifies how to handle response payload content type conversions. Supported values are <code>CONVERT_TO_BINARY</code> and <code>CONVERT_TO_TEXT</code>, with the following behaviors:</p> <ul> <li><p><code>CONVERT_TO_BINARY</code>: Converts a response payload from a Base64-encoded string to the corresponding binary blob.</p></li> <li><p><code>CONVERT_TO_TEXT</code>: Converts a response payload from a binary blob to a Base64-encoded string.</p></li> </ul> <p>If this property is not defined, the response payload will be passed through from the integration response to the method response without modification.</p>
     #[serde(rename = "contentHandling")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling: Option<String>,
+    pub content_handling: Option<ContentHandlingStrategy>,
     /// <p>[Required] Specifies a put integration response request's HTTP method.</p>
     #[serde(rename = "httpMethod")]
     pub http_method: String,
@@ -2409,6 +8272,106 @@ pub struct PutIntegrationResponseRequest {
     pub status_code: String,
 }
 
+impl PutIntegrationResponseRequest {
+    /// Returns a builder for `PutIntegrationResponseRequest`, checking its required members when built.
+    pub fn builder() -> PutIntegrationResponseRequestBuilder {
+        PutIntegrationResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutIntegrationResponseRequest`](struct.PutIntegrationResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct PutIntegrationResponseRequestBuilder {
+    content_handling: Option<ContentHandlingStrategy>,
+    http_method: Option<String>,
+    resource_id: Option<String>,
+    response_parameters: Option<::std::collections::HashMap<String, String>>,
+    response_templates: Option<::std::collections::HashMap<String, String>>,
+    rest_api_id: Option<String>,
+    selection_pattern: Option<String>,
+    status_code: Option<String>,
+}
+
+impl PutIntegrationResponseRequestBuilder {
+    /// Sets the [`content_handling`](struct.PutIntegrationResponseRequest.html#structfield.content_handling) member.
+    pub fn content_handling<T: Into<ContentHandlingStrategy>>(mut self, value: T) -> Self {
+        self.content_handling = Some(value.into());
+        self
+    }
+
+    /// Sets the [`http_method`](struct.PutIntegrationResponseRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.PutIntegrationResponseRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_parameters`](struct.PutIntegrationResponseRequest.html#structfield.response_parameters) member.
+    pub fn response_parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_templates`](struct.PutIntegrationResponseRequest.html#structfield.response_templates) member.
+    pub fn response_templates<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_templates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.PutIntegrationResponseRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`selection_pattern`](struct.PutIntegrationResponseRequest.html#structfield.selection_pattern) member.
+    pub fn selection_pattern<T: Into<String>>(mut self, value: T) -> Self {
+        self.selection_pattern = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_code`](struct.PutIntegrationResponseRequest.html#structfield.status_code) member.
+    pub fn status_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.status_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutIntegrationResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutIntegrationResponseRequest, ::rusoto_core::BuildError> {
+        Ok(PutIntegrationResponseRequest {
+            content_handling: self.content_handling,
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            response_parameters: self.response_parameters,
+            response_templates: self.response_templates,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            selection_pattern: self.selection_pattern,
+            status_code: self
+                .status_code
+                .ok_or(::rusoto_core::BuildError::missing("status_code"))?,
+        })
+    }
+}
+
 /// <p>Request to add a method to an existing <a>Resource</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutMethodRequest {
@@ -2454,6 +8417,128 @@ pub struct PutMethodRequest {
     pub rest_api_id: String,
 }
 
+impl PutMethodRequest {
+    /// Returns a builder for `PutMethodRequest`, checking its required members when built.
+    pub fn builder() -> PutMethodRequestBuilder {
+        PutMethodRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutMethodRequest`](struct.PutMethodRequest.html).
+#[derive(Default, Debug)]
+pub struct PutMethodRequestBuilder {
+    api_key_required: Option<bool>,
+    authorization_scopes: Option<Vec<String>>,
+    authorization_type: Option<String>,
+    authorizer_id: Option<String>,
+    http_method: Option<String>,
+    operation_name: Option<String>,
+    request_models: Option<::std::collections::HashMap<String, String>>,
+    request_parameters: Option<::std::collections::HashMap<String, bool>>,
+    request_validator_id: Option<String>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl PutMethodRequestBuilder {
+    /// Sets the [`api_key_required`](struct.PutMethodRequest.html#structfield.api_key_required) member.
+    pub fn api_key_required<T: Into<bool>>(mut self, value: T) -> Self {
+        self.api_key_required = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorization_scopes`](struct.PutMethodRequest.html#structfield.authorization_scopes) member.
+    pub fn authorization_scopes<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.authorization_scopes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorization_type`](struct.PutMethodRequest.html#structfield.authorization_type) member.
+    pub fn authorization_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorization_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_id`](struct.PutMethodRequest.html#structfield.authorizer_id) member.
+    pub fn authorizer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`http_method`](struct.PutMethodRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation_name`](struct.PutMethodRequest.html#structfield.operation_name) member.
+    pub fn operation_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_models`](struct.PutMethodRequest.html#structfield.request_models) member.
+    pub fn request_models<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_models = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_parameters`](struct.PutMethodRequest.html#structfield.request_parameters) member.
+    pub fn request_parameters<T: Into<::std::collections::HashMap<String, bool>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_validator_id`](struct.PutMethodRequest.html#structfield.request_validator_id) member.
+    pub fn request_validator_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.request_validator_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.PutMethodRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.PutMethodRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutMethodRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutMethodRequest, ::rusoto_core::BuildError> {
+        Ok(PutMethodRequest {
+            api_key_required: self.api_key_required,
+            authorization_scopes: self.authorization_scopes,
+            authorization_type: self
+                .authorization_type
+                .ok_or(::rusoto_core::BuildError::missing("authorization_type"))?,
+            authorizer_id: self.authorizer_id,
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            operation_name: self.operation_name,
+            request_models: self.request_models,
+            request_parameters: self.request_parameters,
+            request_validator_id: self.request_validator_id,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to add a <a>MethodResponse</a> to an existing <a>Method</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutMethodResponseRequest {
@@ -2479,32 +8564,343 @@ pub struct PutMethodResponseRequest {
     pub status_code: String,
 }
 
-/// <p>A PUT request to update an existing API, with external API definitions specified as the request body.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct PutRestApiRequest {
-    /// <p>[Required] The PUT request body containing external API definitions. Currently, only OpenAPI definition JSON/YAML files are supported. The maximum size of the API definition file is 2MB.</p>
-    #[serde(rename = "body")]
-    #[serde(
-        deserialize_with = "::rusoto_core::serialization::SerdeBlob::deserialize_blob",
-        serialize_with = "::rusoto_core::serialization::SerdeBlob::serialize_blob",
-        default
-    )]
-    pub body: bytes::Bytes,
-    /// <p>A query parameter to indicate whether to rollback the API update (<code>true</code>) or not (<code>false</code>) when a warning is encountered. The default value is <code>false</code>.</p>
-    #[serde(rename = "failOnWarnings")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub fail_on_warnings: Option<bool>,
-    /// <p>The <code>mode</code> query parameter to specify the update mode. Valid values are "merge" and "overwrite". By default, the update mode is "merge".</p>
-    #[serde(rename = "mode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
-    /// <p>Custom header parameters as part of the request. For example, to exclude <a>DocumentationParts</a> from an imported API, set <code>ignore=documentation</code> as a <code>parameters</code> value, as in the AWS CLI command of <code>aws apigateway import-rest-api --parameters ignore=documentation --body 'file:///path/to/imported-api-body.json'</code>.</p>
-    #[serde(rename = "parameters")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<::std::collections::HashMap<String, String>>,
-    /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
-    #[serde(rename = "restApiId")]
-    pub rest_api_id: String,
+impl PutMethodResponseRequest {
+    /// Returns a builder for `PutMethodResponseRequest`, checking its required members when built.
+    pub fn builder() -> PutMethodResponseRequestBuilder {
+        PutMethodResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutMethodResponseRequest`](struct.PutMethodResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct PutMethodResponseRequestBuilder {
+    http_method: Option<String>,
+    resource_id: Option<String>,
+    response_models: Option<::std::collections::HashMap<String, String>>,
+    response_parameters: Option<::std::collections::HashMap<String, bool>>,
+    rest_api_id: Option<String>,
+    status_code: Option<String>,
+}
+
+impl PutMethodResponseRequestBuilder {
+    /// Sets the [`http_method`](struct.PutMethodResponseRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.PutMethodResponseRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_models`](struct.PutMethodResponseRequest.html#structfield.response_models) member.
+    pub fn response_models<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_models = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_parameters`](struct.PutMethodResponseRequest.html#structfield.response_parameters) member.
+    pub fn response_parameters<T: Into<::std::collections::HashMap<String, bool>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.PutMethodResponseRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_code`](struct.PutMethodResponseRequest.html#structfield.status_code) member.
+    pub fn status_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.status_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutMethodResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutMethodResponseRequest, ::rusoto_core::BuildError> {
+        Ok(PutMethodResponseRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            response_models: self.response_models,
+            response_parameters: self.response_parameters,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            status_code: self
+                .status_code
+                .ok_or(::rusoto_core::BuildError::missing("status_code"))?,
+        })
+    }
+}
+
+/// <p>A query parameter to indicate whether to overwrite (<code>OVERWRITE</code>) any existing <a>DocumentationParts</a> definition or to merge (<code>MERGE</code>) the new definition into the existing one. The default value is <code>MERGE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PutMode {
+    /// <p><code>merge</code></p>
+    Merge,
+    /// <p><code>overwrite</code></p>
+    Overwrite,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PutMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PutMode::Merge => "merge",
+            PutMode::Overwrite => "overwrite",
+            PutMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PutMode {
+    fn from(value: &'a str) -> PutMode {
+        match value {
+            "merge" => PutMode::Merge,
+            "overwrite" => PutMode::Overwrite,
+            _ => PutMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PutMode {
+    fn from(value: String) -> PutMode {
+        PutMode::from(value.as_str())
+    }
+}
+
+impl From<PutMode> for String {
+    fn from(value: PutMode) -> String {
+        match value {
+            PutMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PutMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PutMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PutMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PutMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PutMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PutMode::from(value))
+    }
+}
+
+/// <p>A PUT request to update an existing API, with external API definitions specified as the request body.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct PutRestApiRequest {
+    /// <p>[Required] The PUT request body containing external API definitions. Currently, only OpenAPI definition JSON/YAML files are supported. The maximum size of the API definition file is 2MB.</p>
+    #[serde(rename = "body")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeBlob::deserialize_blob",
+        serialize_with = "::rusoto_core::serialization::SerdeBlob::serialize_blob",
+        default
+    )]
+    pub body: bytes::Bytes,
+    /// <p>A query parameter to indicate whether to rollback the API update (<code>true</code>) or not (<code>false</code>) when a warning is encountered. The default value is <code>false</code>.</p>
+    #[serde(rename = "failOnWarnings")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fail_on_warnings: Option<bool>,
+    /// <p>The <code>mode</code> query parameter to specify the update mode. Valid values are "merge" and "overwrite". By default, the update mode is "merge".</p>
+    #[serde(rename = "mode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<PutMode>,
+    /// <p>Custom header parameters as part of the request. For example, to exclude <a>DocumentationParts</a> from an imported API, set <code>ignore=documentation</code> as a <code>parameters</code> value, as in the AWS CLI command of <code>aws apigateway import-rest-api --parameters ignore=documentation --body 'file:///path/to/imported-api-body.json'</code>.</p>
+    #[serde(rename = "parameters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<::std::collections::HashMap<String, String>>,
+    /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
+    #[serde(rename = "restApiId")]
+    pub rest_api_id: String,
+}
+
+impl PutRestApiRequest {
+    /// Returns a builder for `PutRestApiRequest`, checking its required members when built.
+    pub fn builder() -> PutRestApiRequestBuilder {
+        PutRestApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutRestApiRequest`](struct.PutRestApiRequest.html).
+#[derive(Default, Debug)]
+pub struct PutRestApiRequestBuilder {
+    body: Option<bytes::Bytes>,
+    fail_on_warnings: Option<bool>,
+    mode: Option<PutMode>,
+    parameters: Option<::std::collections::HashMap<String, String>>,
+    rest_api_id: Option<String>,
+}
+
+impl PutRestApiRequestBuilder {
+    /// Sets the [`body`](struct.PutRestApiRequest.html#structfield.body) member.
+    pub fn body<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fail_on_warnings`](struct.PutRestApiRequest.html#structfield.fail_on_warnings) member.
+    pub fn fail_on_warnings<T: Into<bool>>(mut self, value: T) -> Self {
+        self.fail_on_warnings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mode`](struct.PutRestApiRequest.html#structfield.mode) member.
+    pub fn mode<T: Into<PutMode>>(mut self, value: T) -> Self {
+        self.mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.PutRestApiRequest.html#structfield.parameters) member.
+    pub fn parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.PutRestApiRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRestApiRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutRestApiRequest, ::rusoto_core::BuildError> {
+        Ok(PutRestApiRequest {
+            body: self
+                .body
+                .ok_or(::rusoto_core::BuildError::missing("body"))?,
+            fail_on_warnings: self.fail_on_warnings,
+            mode: self.mode,
+            parameters: self.parameters,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
+/// <p>The time period in which the limit applies. Valid values are "DAY", "WEEK" or "MONTH".</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QuotaPeriodType {
+    /// <p><code>DAY</code></p>
+    Day,
+    /// <p><code>WEEK</code></p>
+    Week,
+    /// <p><code>MONTH</code></p>
+    Month,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl QuotaPeriodType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QuotaPeriodType::Day => "DAY",
+            QuotaPeriodType::Week => "WEEK",
+            QuotaPeriodType::Month => "MONTH",
+            QuotaPeriodType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for QuotaPeriodType {
+    fn from(value: &'a str) -> QuotaPeriodType {
+        match value {
+            "DAY" => QuotaPeriodType::Day,
+            "WEEK" => QuotaPeriodType::Week,
+            "MONTH" => QuotaPeriodType::Month,
+            _ => QuotaPeriodType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QuotaPeriodType {
+    fn from(value: String) -> QuotaPeriodType {
+        QuotaPeriodType::from(value.as_str())
+    }
+}
+
+impl From<QuotaPeriodType> for String {
+    fn from(value: QuotaPeriodType) -> String {
+        match value {
+            QuotaPeriodType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for QuotaPeriodType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QuotaPeriodType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QuotaPeriodType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QuotaPeriodType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<QuotaPeriodType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(QuotaPeriodType::from(value))
+    }
 }
 
 /// <p>Quotas configured for a usage plan.</p>
@@ -2521,7 +8917,7 @@ pub struct QuotaSettings {
     /// <p>The time period in which the limit applies. Valid values are "DAY", "WEEK" or "MONTH".</p>
     #[serde(rename = "period")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub period: Option<String>,
+    pub period: Option<QuotaPeriodType>,
 }
 
 /// <p><p>A set of validation rules for incoming <a>Method</a> requests.</p> <div class="remarks"> <p>In OpenAPI, a <a>RequestValidator</a> of an API is defined by the <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-swagger-extensions.html#api-gateway-swagger-extensions-request-validators.requestValidator.html">x-amazon-apigateway-request-validators.requestValidator</a> object. It the referenced using the <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-swagger-extensions.html#api-gateway-swagger-extensions-request-validator">x-amazon-apigateway-request-validator</a> property.</p> </div> <div class="seeAlso"><a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-method-request-validation.html">Enable Basic Request Validation in API Gateway</a></div></p>
@@ -2605,15 +9001,16 @@ pub struct RestApi {
     /// <p>The source of the API key for metering requests according to a usage plan. Valid values are: <ul><li><code>HEADER</code> to read the API key from the <code>X-API-Key</code> header of a request. </li><li><code>AUTHORIZER</code> to read the API key from the <code>UsageIdentifierKey</code> from a custom authorizer.</li></ul> </p>
     #[serde(rename = "apiKeySource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub api_key_source: Option<String>,
+    pub api_key_source: Option<ApiKeySourceType>,
     /// <p>The list of binary media types supported by the <a>RestApi</a>. By default, the <a>RestApi</a> supports only UTF-8-encoded text payloads.</p>
     #[serde(rename = "binaryMediaTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub binary_media_types: Option<Vec<String>>,
     /// <p>The timestamp when the API was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The API's description.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2737,6 +9134,84 @@ pub struct SdkTypes {
     pub position: Option<String>,
 }
 
+/// <p>The Transport Layer Security (TLS) version + cipher suite for this <a>DomainName</a>. The valid values are <code>TLS_1_0</code> and <code>TLS_1_2</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SecurityPolicy {
+    /// <p><code>TLS_1_0</code></p>
+    Tls10,
+    /// <p><code>TLS_1_2</code></p>
+    Tls12,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SecurityPolicy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SecurityPolicy::Tls10 => "TLS_1_0",
+            SecurityPolicy::Tls12 => "TLS_1_2",
+            SecurityPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SecurityPolicy {
+    fn from(value: &'a str) -> SecurityPolicy {
+        match value {
+            "TLS_1_0" => SecurityPolicy::Tls10,
+            "TLS_1_2" => SecurityPolicy::Tls12,
+            _ => SecurityPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SecurityPolicy {
+    fn from(value: String) -> SecurityPolicy {
+        SecurityPolicy::from(value.as_str())
+    }
+}
+
+impl From<SecurityPolicy> for String {
+    fn from(value: SecurityPolicy) -> String {
+        match value {
+            SecurityPolicy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SecurityPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SecurityPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SecurityPolicy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SecurityPolicy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SecurityPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SecurityPolicy::from(value))
+    }
+}
+
 /// <p><p>Represents a unique identifier for a version of a deployed <a>RestApi</a> that is callable by users.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-deploy-api.html">Deploy an API</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2752,11 +9227,11 @@ pub struct Stage {
     /// <p>The size of the cache cluster for the stage, if enabled.</p>
     #[serde(rename = "cacheClusterSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_cluster_size: Option<String>,
+    pub cache_cluster_size: Option<CacheClusterSize>,
     /// <p>The status of the cache cluster for the stage, if enabled.</p>
     #[serde(rename = "cacheClusterStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cache_cluster_status: Option<String>,
+    pub cache_cluster_status: Option<CacheClusterStatus>,
     /// <p>Settings for the canary deployment in this stage.</p>
     #[serde(rename = "canarySettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2767,8 +9242,9 @@ pub struct Stage {
     pub client_certificate_id: Option<String>,
     /// <p>The timestamp when the stage was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The identifier of the <a>Deployment</a> that the stage points to.</p>
     #[serde(rename = "deploymentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2783,8 +9259,9 @@ pub struct Stage {
     pub documentation_version: Option<String>,
     /// <p>The timestamp when the stage last updated.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>A map that defines the method settings for a <a>Stage</a> resource. Keys (designated as <code>/{method_setting_key</code> below) are method paths defined as <code>{resource_path}/{http_method}</code> for an individual method override, or <code>/\*/\*</code> for overriding all methods in the stage. </p>
     #[serde(rename = "methodSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2845,6 +9322,46 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>The collection of tags. Each tag element is associated with a given resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2900,6 +9417,108 @@ pub struct TestInvokeAuthorizerRequest {
     pub stage_variables: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl TestInvokeAuthorizerRequest {
+    /// Returns a builder for `TestInvokeAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> TestInvokeAuthorizerRequestBuilder {
+        TestInvokeAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TestInvokeAuthorizerRequest`](struct.TestInvokeAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct TestInvokeAuthorizerRequestBuilder {
+    additional_context: Option<::std::collections::HashMap<String, String>>,
+    authorizer_id: Option<String>,
+    body: Option<String>,
+    headers: Option<::std::collections::HashMap<String, String>>,
+    multi_value_headers: Option<::std::collections::HashMap<String, Vec<String>>>,
+    path_with_query_string: Option<String>,
+    rest_api_id: Option<String>,
+    stage_variables: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TestInvokeAuthorizerRequestBuilder {
+    /// Sets the [`additional_context`](struct.TestInvokeAuthorizerRequest.html#structfield.additional_context) member.
+    pub fn additional_context<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.additional_context = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_id`](struct.TestInvokeAuthorizerRequest.html#structfield.authorizer_id) member.
+    pub fn authorizer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`body`](struct.TestInvokeAuthorizerRequest.html#structfield.body) member.
+    pub fn body<T: Into<String>>(mut self, value: T) -> Self {
+        self.body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`headers`](struct.TestInvokeAuthorizerRequest.html#structfield.headers) member.
+    pub fn headers<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.headers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_value_headers`](struct.TestInvokeAuthorizerRequest.html#structfield.multi_value_headers) member.
+    pub fn multi_value_headers<T: Into<::std::collections::HashMap<String, Vec<String>>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.multi_value_headers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_with_query_string`](struct.TestInvokeAuthorizerRequest.html#structfield.path_with_query_string) member.
+    pub fn path_with_query_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_with_query_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.TestInvokeAuthorizerRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_variables`](struct.TestInvokeAuthorizerRequest.html#structfield.stage_variables) member.
+    pub fn stage_variables<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.stage_variables = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestInvokeAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TestInvokeAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(TestInvokeAuthorizerRequest {
+            additional_context: self.additional_context,
+            authorizer_id: self
+                .authorizer_id
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_id"))?,
+            body: self.body,
+            headers: self.headers,
+            multi_value_headers: self.multi_value_headers,
+            path_with_query_string: self.path_with_query_string,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            stage_variables: self.stage_variables,
+        })
+    }
+}
+
 /// <p>Represents the response of the test invoke request for a custom <a>Authorizer</a></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2971,6 +9590,115 @@ pub struct TestInvokeMethodRequest {
     pub stage_variables: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl TestInvokeMethodRequest {
+    /// Returns a builder for `TestInvokeMethodRequest`, checking its required members when built.
+    pub fn builder() -> TestInvokeMethodRequestBuilder {
+        TestInvokeMethodRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TestInvokeMethodRequest`](struct.TestInvokeMethodRequest.html).
+#[derive(Default, Debug)]
+pub struct TestInvokeMethodRequestBuilder {
+    body: Option<String>,
+    client_certificate_id: Option<String>,
+    headers: Option<::std::collections::HashMap<String, String>>,
+    http_method: Option<String>,
+    multi_value_headers: Option<::std::collections::HashMap<String, Vec<String>>>,
+    path_with_query_string: Option<String>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+    stage_variables: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TestInvokeMethodRequestBuilder {
+    /// Sets the [`body`](struct.TestInvokeMethodRequest.html#structfield.body) member.
+    pub fn body<T: Into<String>>(mut self, value: T) -> Self {
+        self.body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_certificate_id`](struct.TestInvokeMethodRequest.html#structfield.client_certificate_id) member.
+    pub fn client_certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`headers`](struct.TestInvokeMethodRequest.html#structfield.headers) member.
+    pub fn headers<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.headers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`http_method`](struct.TestInvokeMethodRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_value_headers`](struct.TestInvokeMethodRequest.html#structfield.multi_value_headers) member.
+    pub fn multi_value_headers<T: Into<::std::collections::HashMap<String, Vec<String>>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.multi_value_headers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_with_query_string`](struct.TestInvokeMethodRequest.html#structfield.path_with_query_string) member.
+    pub fn path_with_query_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_with_query_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.TestInvokeMethodRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.TestInvokeMethodRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_variables`](struct.TestInvokeMethodRequest.html#structfield.stage_variables) member.
+    pub fn stage_variables<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.stage_variables = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestInvokeMethodRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TestInvokeMethodRequest, ::rusoto_core::BuildError> {
+        Ok(TestInvokeMethodRequest {
+            body: self.body,
+            client_certificate_id: self.client_certificate_id,
+            headers: self.headers,
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            multi_value_headers: self.multi_value_headers,
+            path_with_query_string: self.path_with_query_string,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            stage_variables: self.stage_variables,
+        })
+    }
+}
+
 /// <p><p>Represents the response of the test invoke request in the HTTP method.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/how-to-test-method.html#how-to-test-method-console">Test API using the API Gateway console</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3014,6 +9742,98 @@ pub struct ThrottleSettings {
     pub rate_limit: Option<f64>,
 }
 
+/// <p>Specifies how to handle unauthorized requests for cache invalidation. The PATCH path for this setting is <code>/{method_setting_key}/caching/unauthorizedCacheControlHeaderStrategy</code>, and the available values are <code>FAIL_WITH_403</code>, <code>SUCCEED_WITH_RESPONSE_HEADER</code>, <code>SUCCEED_WITHOUT_RESPONSE_HEADER</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnauthorizedCacheControlHeaderStrategy {
+    /// <p><code>FAIL_WITH_403</code></p>
+    FailWith403,
+    /// <p><code>SUCCEED_WITH_RESPONSE_HEADER</code></p>
+    SucceedWithResponseHeader,
+    /// <p><code>SUCCEED_WITHOUT_RESPONSE_HEADER</code></p>
+    SucceedWithoutResponseHeader,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UnauthorizedCacheControlHeaderStrategy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UnauthorizedCacheControlHeaderStrategy::FailWith403 => "FAIL_WITH_403",
+            UnauthorizedCacheControlHeaderStrategy::SucceedWithResponseHeader => {
+                "SUCCEED_WITH_RESPONSE_HEADER"
+            }
+            UnauthorizedCacheControlHeaderStrategy::SucceedWithoutResponseHeader => {
+                "SUCCEED_WITHOUT_RESPONSE_HEADER"
+            }
+            UnauthorizedCacheControlHeaderStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UnauthorizedCacheControlHeaderStrategy {
+    fn from(value: &'a str) -> UnauthorizedCacheControlHeaderStrategy {
+        match value {
+            "FAIL_WITH_403" => UnauthorizedCacheControlHeaderStrategy::FailWith403,
+            "SUCCEED_WITH_RESPONSE_HEADER" => {
+                UnauthorizedCacheControlHeaderStrategy::SucceedWithResponseHeader
+            }
+            "SUCCEED_WITHOUT_RESPONSE_HEADER" => {
+                UnauthorizedCacheControlHeaderStrategy::SucceedWithoutResponseHeader
+            }
+            _ => UnauthorizedCacheControlHeaderStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UnauthorizedCacheControlHeaderStrategy {
+    fn from(value: String) -> UnauthorizedCacheControlHeaderStrategy {
+        UnauthorizedCacheControlHeaderStrategy::from(value.as_str())
+    }
+}
+
+impl From<UnauthorizedCacheControlHeaderStrategy> for String {
+    fn from(value: UnauthorizedCacheControlHeaderStrategy) -> String {
+        match value {
+            UnauthorizedCacheControlHeaderStrategy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UnauthorizedCacheControlHeaderStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UnauthorizedCacheControlHeaderStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UnauthorizedCacheControlHeaderStrategy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UnauthorizedCacheControlHeaderStrategy {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<UnauthorizedCacheControlHeaderStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UnauthorizedCacheControlHeaderStrategy::from(value))
+    }
+}
+
 /// <p>Removes a tag from a given resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
@@ -3025,6 +9845,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p>Requests API Gateway to change information about the current <a>Account</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateAccountRequest {
@@ -3034,6 +9894,34 @@ pub struct UpdateAccountRequest {
     pub patch_operations: Option<Vec<PatchOperation>>,
 }
 
+impl UpdateAccountRequest {
+    /// Returns a builder for `UpdateAccountRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAccountRequestBuilder {
+        UpdateAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAccountRequest`](struct.UpdateAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAccountRequestBuilder {
+    patch_operations: Option<Vec<PatchOperation>>,
+}
+
+impl UpdateAccountRequestBuilder {
+    /// Sets the [`patch_operations`](struct.UpdateAccountRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateAccountRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAccountRequest {
+            patch_operations: self.patch_operations,
+        })
+    }
+}
+
 /// <p>A request to change information about an <a>ApiKey</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateApiKeyRequest {
@@ -3046,6 +9934,44 @@ pub struct UpdateApiKeyRequest {
     pub patch_operations: Option<Vec<PatchOperation>>,
 }
 
+impl UpdateApiKeyRequest {
+    /// Returns a builder for `UpdateApiKeyRequest`, checking its required members when built.
+    pub fn builder() -> UpdateApiKeyRequestBuilder {
+        UpdateApiKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateApiKeyRequest`](struct.UpdateApiKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateApiKeyRequestBuilder {
+    api_key: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+}
+
+impl UpdateApiKeyRequestBuilder {
+    /// Sets the [`api_key`](struct.UpdateApiKeyRequest.html#structfield.api_key) member.
+    pub fn api_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateApiKeyRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateApiKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateApiKeyRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateApiKeyRequest {
+            api_key: self
+                .api_key
+                .ok_or(::rusoto_core::BuildError::missing("api_key"))?,
+            patch_operations: self.patch_operations,
+        })
+    }
+}
+
 /// <p>Request to update an existing <a>Authorizer</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateAuthorizerRequest {
@@ -3061,12 +9987,62 @@ pub struct UpdateAuthorizerRequest {
     pub rest_api_id: String,
 }
 
-/// <p>A request to change information about the <a>BasePathMapping</a> resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateBasePathMappingRequest {
-    /// <p>[Required] The base path of the <a>BasePathMapping</a> resource to change.</p> <p>To specify an empty base path, set this parameter to <code>'(none)'</code>.</p>
-    #[serde(rename = "basePath")]
-    pub base_path: String,
+impl UpdateAuthorizerRequest {
+    /// Returns a builder for `UpdateAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAuthorizerRequestBuilder {
+        UpdateAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAuthorizerRequest`](struct.UpdateAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAuthorizerRequestBuilder {
+    authorizer_id: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+    rest_api_id: Option<String>,
+}
+
+impl UpdateAuthorizerRequestBuilder {
+    /// Sets the [`authorizer_id`](struct.UpdateAuthorizerRequest.html#structfield.authorizer_id) member.
+    pub fn authorizer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateAuthorizerRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateAuthorizerRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAuthorizerRequest {
+            authorizer_id: self
+                .authorizer_id
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_id"))?,
+            patch_operations: self.patch_operations,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
+/// <p>A request to change information about the <a>BasePathMapping</a> resource.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateBasePathMappingRequest {
+    /// <p>[Required] The base path of the <a>BasePathMapping</a> resource to change.</p> <p>To specify an empty base path, set this parameter to <code>'(none)'</code>.</p>
+    #[serde(rename = "basePath")]
+    pub base_path: String,
     /// <p>[Required] The domain name of the <a>BasePathMapping</a> resource to change.</p>
     #[serde(rename = "domainName")]
     pub domain_name: String,
@@ -3076,6 +10052,56 @@ pub struct UpdateBasePathMappingRequest {
     pub patch_operations: Option<Vec<PatchOperation>>,
 }
 
+impl UpdateBasePathMappingRequest {
+    /// Returns a builder for `UpdateBasePathMappingRequest`, checking its required members when built.
+    pub fn builder() -> UpdateBasePathMappingRequestBuilder {
+        UpdateBasePathMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateBasePathMappingRequest`](struct.UpdateBasePathMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateBasePathMappingRequestBuilder {
+    base_path: Option<String>,
+    domain_name: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+}
+
+impl UpdateBasePathMappingRequestBuilder {
+    /// Sets the [`base_path`](struct.UpdateBasePathMappingRequest.html#structfield.base_path) member.
+    pub fn base_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.base_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.UpdateBasePathMappingRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateBasePathMappingRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateBasePathMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateBasePathMappingRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateBasePathMappingRequest {
+            base_path: self
+                .base_path
+                .ok_or(::rusoto_core::BuildError::missing("base_path"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            patch_operations: self.patch_operations,
+        })
+    }
+}
+
 /// <p>A request to change information about an <a>ClientCertificate</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateClientCertificateRequest {
@@ -3088,6 +10114,46 @@ pub struct UpdateClientCertificateRequest {
     pub patch_operations: Option<Vec<PatchOperation>>,
 }
 
+impl UpdateClientCertificateRequest {
+    /// Returns a builder for `UpdateClientCertificateRequest`, checking its required members when built.
+    pub fn builder() -> UpdateClientCertificateRequestBuilder {
+        UpdateClientCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateClientCertificateRequest`](struct.UpdateClientCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateClientCertificateRequestBuilder {
+    client_certificate_id: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+}
+
+impl UpdateClientCertificateRequestBuilder {
+    /// Sets the [`client_certificate_id`](struct.UpdateClientCertificateRequest.html#structfield.client_certificate_id) member.
+    pub fn client_certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateClientCertificateRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateClientCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateClientCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateClientCertificateRequest {
+            client_certificate_id: self
+                .client_certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("client_certificate_id"))?,
+            patch_operations: self.patch_operations,
+        })
+    }
+}
+
 /// <p>Requests API Gateway to change information about a <a>Deployment</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateDeploymentRequest {
@@ -3103,6 +10169,56 @@ pub struct UpdateDeploymentRequest {
     pub rest_api_id: String,
 }
 
+impl UpdateDeploymentRequest {
+    /// Returns a builder for `UpdateDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDeploymentRequestBuilder {
+        UpdateDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDeploymentRequest`](struct.UpdateDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDeploymentRequestBuilder {
+    deployment_id: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+    rest_api_id: Option<String>,
+}
+
+impl UpdateDeploymentRequestBuilder {
+    /// Sets the [`deployment_id`](struct.UpdateDeploymentRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateDeploymentRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateDeploymentRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDeploymentRequest {
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+            patch_operations: self.patch_operations,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Updates an existing documentation part of a given API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateDocumentationPartRequest {
@@ -3118,6 +10234,56 @@ pub struct UpdateDocumentationPartRequest {
     pub rest_api_id: String,
 }
 
+impl UpdateDocumentationPartRequest {
+    /// Returns a builder for `UpdateDocumentationPartRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDocumentationPartRequestBuilder {
+        UpdateDocumentationPartRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDocumentationPartRequest`](struct.UpdateDocumentationPartRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDocumentationPartRequestBuilder {
+    documentation_part_id: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+    rest_api_id: Option<String>,
+}
+
+impl UpdateDocumentationPartRequestBuilder {
+    /// Sets the [`documentation_part_id`](struct.UpdateDocumentationPartRequest.html#structfield.documentation_part_id) member.
+    pub fn documentation_part_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.documentation_part_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateDocumentationPartRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateDocumentationPartRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDocumentationPartRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDocumentationPartRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDocumentationPartRequest {
+            documentation_part_id: self
+                .documentation_part_id
+                .ok_or(::rusoto_core::BuildError::missing("documentation_part_id"))?,
+            patch_operations: self.patch_operations,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Updates an existing documentation version of an API.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateDocumentationVersionRequest {
@@ -3133,6 +10299,56 @@ pub struct UpdateDocumentationVersionRequest {
     pub rest_api_id: String,
 }
 
+impl UpdateDocumentationVersionRequest {
+    /// Returns a builder for `UpdateDocumentationVersionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDocumentationVersionRequestBuilder {
+        UpdateDocumentationVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDocumentationVersionRequest`](struct.UpdateDocumentationVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDocumentationVersionRequestBuilder {
+    documentation_version: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+    rest_api_id: Option<String>,
+}
+
+impl UpdateDocumentationVersionRequestBuilder {
+    /// Sets the [`documentation_version`](struct.UpdateDocumentationVersionRequest.html#structfield.documentation_version) member.
+    pub fn documentation_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.documentation_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateDocumentationVersionRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateDocumentationVersionRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDocumentationVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDocumentationVersionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDocumentationVersionRequest {
+            documentation_version: self
+                .documentation_version
+                .ok_or(::rusoto_core::BuildError::missing("documentation_version"))?,
+            patch_operations: self.patch_operations,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>A request to change information about the <a>DomainName</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateDomainNameRequest {
@@ -3145,8 +10361,48 @@ pub struct UpdateDomainNameRequest {
     pub patch_operations: Option<Vec<PatchOperation>>,
 }
 
+impl UpdateDomainNameRequest {
+    /// Returns a builder for `UpdateDomainNameRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDomainNameRequestBuilder {
+        UpdateDomainNameRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDomainNameRequest`](struct.UpdateDomainNameRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDomainNameRequestBuilder {
+    domain_name: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+}
+
+impl UpdateDomainNameRequestBuilder {
+    /// Sets the [`domain_name`](struct.UpdateDomainNameRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateDomainNameRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDomainNameRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDomainNameRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDomainNameRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            patch_operations: self.patch_operations,
+        })
+    }
+}
+
 /// <p>Updates a <a>GatewayResponse</a> of a specified response type on the given <a>RestApi</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateGatewayResponseRequest {
     /// <p>A list of update operations to be applied to the specified resource and in the order specified in this list.</p>
     #[serde(rename = "patchOperations")]
@@ -3154,12 +10410,62 @@ pub struct UpdateGatewayResponseRequest {
     pub patch_operations: Option<Vec<PatchOperation>>,
     /// <p>[Required] <p>The response type of the associated <a>GatewayResponse</a>. Valid values are <ul><li>ACCESS_DENIED</li><li>API_CONFIGURATION_ERROR</li><li>AUTHORIZER_FAILURE</li><li> AUTHORIZER_CONFIGURATION_ERROR</li><li>BAD_REQUEST_PARAMETERS</li><li>BAD_REQUEST_BODY</li><li>DEFAULT_4XX</li><li>DEFAULT_5XX</li><li>EXPIRED_TOKEN</li><li>INVALID_SIGNATURE</li><li>INTEGRATION_FAILURE</li><li>INTEGRATION_TIMEOUT</li><li>INVALID_API_KEY</li><li>MISSING_AUTHENTICATION_TOKEN</li><li> QUOTA_EXCEEDED</li><li>REQUEST_TOO_LARGE</li><li>RESOURCE_NOT_FOUND</li><li>THROTTLED</li><li>UNAUTHORIZED</li><li>UNSUPPORTED_MEDIA_TYPE</li></ul> </p></p>
     #[serde(rename = "responseType")]
-    pub response_type: String,
+    pub response_type: GatewayResponseType,
     /// <p>[Required] The string identifier of the associated <a>RestApi</a>.</p>
     #[serde(rename = "restApiId")]
     pub rest_api_id: String,
 }
 
+impl UpdateGatewayResponseRequest {
+    /// Returns a builder for `UpdateGatewayResponseRequest`, checking its required members when built.
+    pub fn builder() -> UpdateGatewayResponseRequestBuilder {
+        UpdateGatewayResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGatewayResponseRequest`](struct.UpdateGatewayResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateGatewayResponseRequestBuilder {
+    patch_operations: Option<Vec<PatchOperation>>,
+    response_type: Option<GatewayResponseType>,
+    rest_api_id: Option<String>,
+}
+
+impl UpdateGatewayResponseRequestBuilder {
+    /// Sets the [`patch_operations`](struct.UpdateGatewayResponseRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_type`](struct.UpdateGatewayResponseRequest.html#structfield.response_type) member.
+    pub fn response_type<T: Into<GatewayResponseType>>(mut self, value: T) -> Self {
+        self.response_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateGatewayResponseRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGatewayResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateGatewayResponseRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateGatewayResponseRequest {
+            patch_operations: self.patch_operations,
+            response_type: self
+                .response_type
+                .ok_or(::rusoto_core::BuildError::missing("response_type"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Represents an update integration request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateIntegrationRequest {
@@ -3178,6 +10484,66 @@ pub struct UpdateIntegrationRequest {
     pub rest_api_id: String,
 }
 
+impl UpdateIntegrationRequest {
+    /// Returns a builder for `UpdateIntegrationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateIntegrationRequestBuilder {
+        UpdateIntegrationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateIntegrationRequest`](struct.UpdateIntegrationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateIntegrationRequestBuilder {
+    http_method: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl UpdateIntegrationRequestBuilder {
+    /// Sets the [`http_method`](struct.UpdateIntegrationRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateIntegrationRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.UpdateIntegrationRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateIntegrationRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateIntegrationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateIntegrationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateIntegrationRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            patch_operations: self.patch_operations,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Represents an update integration response request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateIntegrationResponseRequest {
@@ -3199,6 +10565,76 @@ pub struct UpdateIntegrationResponseRequest {
     pub status_code: String,
 }
 
+impl UpdateIntegrationResponseRequest {
+    /// Returns a builder for `UpdateIntegrationResponseRequest`, checking its required members when built.
+    pub fn builder() -> UpdateIntegrationResponseRequestBuilder {
+        UpdateIntegrationResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateIntegrationResponseRequest`](struct.UpdateIntegrationResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateIntegrationResponseRequestBuilder {
+    http_method: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+    status_code: Option<String>,
+}
+
+impl UpdateIntegrationResponseRequestBuilder {
+    /// Sets the [`http_method`](struct.UpdateIntegrationResponseRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateIntegrationResponseRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.UpdateIntegrationResponseRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateIntegrationResponseRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_code`](struct.UpdateIntegrationResponseRequest.html#structfield.status_code) member.
+    pub fn status_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.status_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateIntegrationResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateIntegrationResponseRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateIntegrationResponseRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            patch_operations: self.patch_operations,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            status_code: self
+                .status_code
+                .ok_or(::rusoto_core::BuildError::missing("status_code"))?,
+        })
+    }
+}
+
 /// <p>Request to update an existing <a>Method</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateMethodRequest {
@@ -3217,6 +10653,64 @@ pub struct UpdateMethodRequest {
     pub rest_api_id: String,
 }
 
+impl UpdateMethodRequest {
+    /// Returns a builder for `UpdateMethodRequest`, checking its required members when built.
+    pub fn builder() -> UpdateMethodRequestBuilder {
+        UpdateMethodRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateMethodRequest`](struct.UpdateMethodRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateMethodRequestBuilder {
+    http_method: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl UpdateMethodRequestBuilder {
+    /// Sets the [`http_method`](struct.UpdateMethodRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateMethodRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.UpdateMethodRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateMethodRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateMethodRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateMethodRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateMethodRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            patch_operations: self.patch_operations,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>A request to update an existing <a>MethodResponse</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateMethodResponseRequest {
@@ -3238,6 +10732,76 @@ pub struct UpdateMethodResponseRequest {
     pub status_code: String,
 }
 
+impl UpdateMethodResponseRequest {
+    /// Returns a builder for `UpdateMethodResponseRequest`, checking its required members when built.
+    pub fn builder() -> UpdateMethodResponseRequestBuilder {
+        UpdateMethodResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateMethodResponseRequest`](struct.UpdateMethodResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateMethodResponseRequestBuilder {
+    http_method: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+    status_code: Option<String>,
+}
+
+impl UpdateMethodResponseRequestBuilder {
+    /// Sets the [`http_method`](struct.UpdateMethodResponseRequest.html#structfield.http_method) member.
+    pub fn http_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.http_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateMethodResponseRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.UpdateMethodResponseRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateMethodResponseRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_code`](struct.UpdateMethodResponseRequest.html#structfield.status_code) member.
+    pub fn status_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.status_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateMethodResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateMethodResponseRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateMethodResponseRequest {
+            http_method: self
+                .http_method
+                .ok_or(::rusoto_core::BuildError::missing("http_method"))?,
+            patch_operations: self.patch_operations,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            status_code: self
+                .status_code
+                .ok_or(::rusoto_core::BuildError::missing("status_code"))?,
+        })
+    }
+}
+
 /// <p>Request to update an existing model in an existing <a>RestApi</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateModelRequest {
@@ -3253,6 +10817,54 @@ pub struct UpdateModelRequest {
     pub rest_api_id: String,
 }
 
+impl UpdateModelRequest {
+    /// Returns a builder for `UpdateModelRequest`, checking its required members when built.
+    pub fn builder() -> UpdateModelRequestBuilder {
+        UpdateModelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateModelRequest`](struct.UpdateModelRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateModelRequestBuilder {
+    model_name: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+    rest_api_id: Option<String>,
+}
+
+impl UpdateModelRequestBuilder {
+    /// Sets the [`model_name`](struct.UpdateModelRequest.html#structfield.model_name) member.
+    pub fn model_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.model_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateModelRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateModelRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateModelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateModelRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateModelRequest {
+            model_name: self
+                .model_name
+                .ok_or(::rusoto_core::BuildError::missing("model_name"))?,
+            patch_operations: self.patch_operations,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Updates a <a>RequestValidator</a> of a given <a>RestApi</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateRequestValidatorRequest {
@@ -3268,6 +10880,56 @@ pub struct UpdateRequestValidatorRequest {
     pub rest_api_id: String,
 }
 
+impl UpdateRequestValidatorRequest {
+    /// Returns a builder for `UpdateRequestValidatorRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRequestValidatorRequestBuilder {
+        UpdateRequestValidatorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRequestValidatorRequest`](struct.UpdateRequestValidatorRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRequestValidatorRequestBuilder {
+    patch_operations: Option<Vec<PatchOperation>>,
+    request_validator_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl UpdateRequestValidatorRequestBuilder {
+    /// Sets the [`patch_operations`](struct.UpdateRequestValidatorRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_validator_id`](struct.UpdateRequestValidatorRequest.html#structfield.request_validator_id) member.
+    pub fn request_validator_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.request_validator_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateRequestValidatorRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRequestValidatorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateRequestValidatorRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRequestValidatorRequest {
+            patch_operations: self.patch_operations,
+            request_validator_id: self
+                .request_validator_id
+                .ok_or(::rusoto_core::BuildError::missing("request_validator_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to change information about a <a>Resource</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateResourceRequest {
@@ -3283,6 +10945,54 @@ pub struct UpdateResourceRequest {
     pub rest_api_id: String,
 }
 
+impl UpdateResourceRequest {
+    /// Returns a builder for `UpdateResourceRequest`, checking its required members when built.
+    pub fn builder() -> UpdateResourceRequestBuilder {
+        UpdateResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateResourceRequest`](struct.UpdateResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateResourceRequestBuilder {
+    patch_operations: Option<Vec<PatchOperation>>,
+    resource_id: Option<String>,
+    rest_api_id: Option<String>,
+}
+
+impl UpdateResourceRequestBuilder {
+    /// Sets the [`patch_operations`](struct.UpdateResourceRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.UpdateResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateResourceRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateResourceRequest {
+            patch_operations: self.patch_operations,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Request to update an existing <a>RestApi</a> resource in your collection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateRestApiRequest {
@@ -3295,6 +11005,44 @@ pub struct UpdateRestApiRequest {
     pub rest_api_id: String,
 }
 
+impl UpdateRestApiRequest {
+    /// Returns a builder for `UpdateRestApiRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRestApiRequestBuilder {
+        UpdateRestApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRestApiRequest`](struct.UpdateRestApiRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRestApiRequestBuilder {
+    patch_operations: Option<Vec<PatchOperation>>,
+    rest_api_id: Option<String>,
+}
+
+impl UpdateRestApiRequestBuilder {
+    /// Sets the [`patch_operations`](struct.UpdateRestApiRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateRestApiRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRestApiRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateRestApiRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRestApiRequest {
+            patch_operations: self.patch_operations,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+        })
+    }
+}
+
 /// <p>Requests API Gateway to change information about a <a>Stage</a> resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateStageRequest {
@@ -3310,9 +11058,57 @@ pub struct UpdateStageRequest {
     pub stage_name: String,
 }
 
-/// <p>The PATCH request to update a usage plan of a given plan Id.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateUsagePlanRequest {
+impl UpdateStageRequest {
+    /// Returns a builder for `UpdateStageRequest`, checking its required members when built.
+    pub fn builder() -> UpdateStageRequestBuilder {
+        UpdateStageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateStageRequest`](struct.UpdateStageRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateStageRequestBuilder {
+    patch_operations: Option<Vec<PatchOperation>>,
+    rest_api_id: Option<String>,
+    stage_name: Option<String>,
+}
+
+impl UpdateStageRequestBuilder {
+    /// Sets the [`patch_operations`](struct.UpdateStageRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rest_api_id`](struct.UpdateStageRequest.html#structfield.rest_api_id) member.
+    pub fn rest_api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.rest_api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.UpdateStageRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateStageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateStageRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateStageRequest {
+            patch_operations: self.patch_operations,
+            rest_api_id: self
+                .rest_api_id
+                .ok_or(::rusoto_core::BuildError::missing("rest_api_id"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+        })
+    }
+}
+
+/// <p>The PATCH request to update a usage plan of a given plan Id.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateUsagePlanRequest {
     /// <p>A list of update operations to be applied to the specified resource and in the order specified in this list.</p>
     #[serde(rename = "patchOperations")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3322,6 +11118,44 @@ pub struct UpdateUsagePlanRequest {
     pub usage_plan_id: String,
 }
 
+impl UpdateUsagePlanRequest {
+    /// Returns a builder for `UpdateUsagePlanRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUsagePlanRequestBuilder {
+        UpdateUsagePlanRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUsagePlanRequest`](struct.UpdateUsagePlanRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUsagePlanRequestBuilder {
+    patch_operations: Option<Vec<PatchOperation>>,
+    usage_plan_id: Option<String>,
+}
+
+impl UpdateUsagePlanRequestBuilder {
+    /// Sets the [`patch_operations`](struct.UpdateUsagePlanRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`usage_plan_id`](struct.UpdateUsagePlanRequest.html#structfield.usage_plan_id) member.
+    pub fn usage_plan_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.usage_plan_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUsagePlanRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateUsagePlanRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUsagePlanRequest {
+            patch_operations: self.patch_operations,
+            usage_plan_id: self
+                .usage_plan_id
+                .ok_or(::rusoto_core::BuildError::missing("usage_plan_id"))?,
+        })
+    }
+}
+
 /// <p>The PATCH request to grant a temporary extension to the remaining quota of a usage plan associated with a specified API key.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateUsageRequest {
@@ -3337,6 +11171,54 @@ pub struct UpdateUsageRequest {
     pub usage_plan_id: String,
 }
 
+impl UpdateUsageRequest {
+    /// Returns a builder for `UpdateUsageRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUsageRequestBuilder {
+        UpdateUsageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUsageRequest`](struct.UpdateUsageRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUsageRequestBuilder {
+    key_id: Option<String>,
+    patch_operations: Option<Vec<PatchOperation>>,
+    usage_plan_id: Option<String>,
+}
+
+impl UpdateUsageRequestBuilder {
+    /// Sets the [`key_id`](struct.UpdateUsageRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`patch_operations`](struct.UpdateUsageRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`usage_plan_id`](struct.UpdateUsageRequest.html#structfield.usage_plan_id) member.
+    pub fn usage_plan_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.usage_plan_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUsageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateUsageRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUsageRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            patch_operations: self.patch_operations,
+            usage_plan_id: self
+                .usage_plan_id
+                .ok_or(::rusoto_core::BuildError::missing("usage_plan_id"))?,
+        })
+    }
+}
+
 /// <p>Updates an existing <a>VpcLink</a> of a specified identifier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateVpcLinkRequest {
@@ -3349,6 +11231,44 @@ pub struct UpdateVpcLinkRequest {
     pub vpc_link_id: String,
 }
 
+impl UpdateVpcLinkRequest {
+    /// Returns a builder for `UpdateVpcLinkRequest`, checking its required members when built.
+    pub fn builder() -> UpdateVpcLinkRequestBuilder {
+        UpdateVpcLinkRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateVpcLinkRequest`](struct.UpdateVpcLinkRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateVpcLinkRequestBuilder {
+    patch_operations: Option<Vec<PatchOperation>>,
+    vpc_link_id: Option<String>,
+}
+
+impl UpdateVpcLinkRequestBuilder {
+    /// Sets the [`patch_operations`](struct.UpdateVpcLinkRequest.html#structfield.patch_operations) member.
+    pub fn patch_operations<T: Into<Vec<PatchOperation>>>(mut self, value: T) -> Self {
+        self.patch_operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_link_id`](struct.UpdateVpcLinkRequest.html#structfield.vpc_link_id) member.
+    pub fn vpc_link_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpc_link_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateVpcLinkRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateVpcLinkRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateVpcLinkRequest {
+            patch_operations: self.patch_operations,
+            vpc_link_id: self
+                .vpc_link_id
+                .ok_or(::rusoto_core::BuildError::missing("vpc_link_id"))?,
+        })
+    }
+}
+
 /// <p><p>Represents the usage data of a usage plan.</p> <div class="remarks"/> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-api-usage-plans.html">Create and Use Usage Plans</a>, <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/api-gateway-create-usage-plans-with-console.html#api-gateway-usage-plan-manage-usage">Manage Usage in a Usage Plan</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3479,7 +11399,7 @@ pub struct VpcLink {
     /// <p>The status of the VPC link. The valid values are <code>AVAILABLE</code>, <code>PENDING</code>, <code>DELETING</code>, or <code>FAILED</code>. Deploying an API will wait if the status is <code>PENDING</code> and will fail if the status is <code>DELETING</code>. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<VpcLinkStatus>,
     /// <p>A description about the VPC link status.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3494,6 +11414,92 @@ pub struct VpcLink {
     pub target_arns: Option<Vec<String>>,
 }
 
+/// <p>The status of the VPC link. The valid values are <code>AVAILABLE</code>, <code>PENDING</code>, <code>DELETING</code>, or <code>FAILED</code>. Deploying an API will wait if the status is <code>PENDING</code> and will fail if the status is <code>DELETING</code>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VpcLinkStatus {
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VpcLinkStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VpcLinkStatus::Available => "AVAILABLE",
+            VpcLinkStatus::Pending => "PENDING",
+            VpcLinkStatus::Deleting => "DELETING",
+            VpcLinkStatus::Failed => "FAILED",
+            VpcLinkStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VpcLinkStatus {
+    fn from(value: &'a str) -> VpcLinkStatus {
+        match value {
+            "AVAILABLE" => VpcLinkStatus::Available,
+            "PENDING" => VpcLinkStatus::Pending,
+            "DELETING" => VpcLinkStatus::Deleting,
+            "FAILED" => VpcLinkStatus::Failed,
+            _ => VpcLinkStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VpcLinkStatus {
+    fn from(value: String) -> VpcLinkStatus {
+        VpcLinkStatus::from(value.as_str())
+    }
+}
+
+impl From<VpcLinkStatus> for String {
+    fn from(value: VpcLinkStatus) -> String {
+        match value {
+            VpcLinkStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VpcLinkStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VpcLinkStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VpcLinkStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VpcLinkStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VpcLinkStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VpcLinkStatus::from(value))
+    }
+}
+
 /// <p><p>The collection of VPC links under the caller&#39;s account in a region.</p> <div class="seeAlso"> <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/getting-started-with-private-integration.html">Getting Started with Private Integrations</a>, <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/set-up-private-integration.html">Set up Private Integrations</a> </div></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -10587,40 +18593,732 @@ pub trait ApiGateway {
         input: UpdateVpcLinkRequest,
     ) -> RusotoFuture<VpcLink, UpdateVpcLinkError>;
 }
-/// A client for the Amazon API Gateway API.
-#[derive(Clone)]
-pub struct ApiGatewayClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ApiGatewayClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ApiGatewayClient {
-        Self::new_with_client(Client::shared(), region)
+impl<T: ?Sized + ApiGateway> ApiGateway for ::std::sync::Arc<T> {
+    fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> RusotoFuture<ApiKey, CreateApiKeyError> {
+        (**self).create_api_key(input)
     }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ApiGatewayClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
+    fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, CreateAuthorizerError> {
+        (**self).create_authorizer(input)
     }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayClient {
-        ApiGatewayClient { client, region }
+    fn create_base_path_mapping(
+        &self,
+        input: CreateBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, CreateBasePathMappingError> {
+        (**self).create_base_path_mapping(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<Deployment, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_documentation_part(
+        &self,
+        input: CreateDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, CreateDocumentationPartError> {
+        (**self).create_documentation_part(input)
+    }
+    fn create_documentation_version(
+        &self,
+        input: CreateDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, CreateDocumentationVersionError> {
+        (**self).create_documentation_version(input)
+    }
+    fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> RusotoFuture<DomainName, CreateDomainNameError> {
+        (**self).create_domain_name(input)
+    }
+    fn create_model(&self, input: CreateModelRequest) -> RusotoFuture<Model, CreateModelError> {
+        (**self).create_model(input)
+    }
+    fn create_request_validator(
+        &self,
+        input: CreateRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, CreateRequestValidatorError> {
+        (**self).create_request_validator(input)
+    }
+    fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> RusotoFuture<Resource, CreateResourceError> {
+        (**self).create_resource(input)
+    }
+    fn create_rest_api(
+        &self,
+        input: CreateRestApiRequest,
+    ) -> RusotoFuture<RestApi, CreateRestApiError> {
+        (**self).create_rest_api(input)
+    }
+    fn create_stage(&self, input: CreateStageRequest) -> RusotoFuture<Stage, CreateStageError> {
+        (**self).create_stage(input)
+    }
+    fn create_usage_plan(
+        &self,
+        input: CreateUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, CreateUsagePlanError> {
+        (**self).create_usage_plan(input)
+    }
+    fn create_usage_plan_key(
+        &self,
+        input: CreateUsagePlanKeyRequest,
+    ) -> RusotoFuture<UsagePlanKey, CreateUsagePlanKeyError> {
+        (**self).create_usage_plan_key(input)
+    }
+    fn create_vpc_link(
+        &self,
+        input: CreateVpcLinkRequest,
+    ) -> RusotoFuture<VpcLink, CreateVpcLinkError> {
+        (**self).create_vpc_link(input)
+    }
+    fn delete_api_key(&self, input: DeleteApiKeyRequest) -> RusotoFuture<(), DeleteApiKeyError> {
+        (**self).delete_api_key(input)
+    }
+    fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> RusotoFuture<(), DeleteAuthorizerError> {
+        (**self).delete_authorizer(input)
+    }
+    fn delete_base_path_mapping(
+        &self,
+        input: DeleteBasePathMappingRequest,
+    ) -> RusotoFuture<(), DeleteBasePathMappingError> {
+        (**self).delete_base_path_mapping(input)
+    }
+    fn delete_client_certificate(
+        &self,
+        input: DeleteClientCertificateRequest,
+    ) -> RusotoFuture<(), DeleteClientCertificateError> {
+        (**self).delete_client_certificate(input)
+    }
+    fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> RusotoFuture<(), DeleteDeploymentError> {
+        (**self).delete_deployment(input)
+    }
+    fn delete_documentation_part(
+        &self,
+        input: DeleteDocumentationPartRequest,
+    ) -> RusotoFuture<(), DeleteDocumentationPartError> {
+        (**self).delete_documentation_part(input)
+    }
+    fn delete_documentation_version(
+        &self,
+        input: DeleteDocumentationVersionRequest,
+    ) -> RusotoFuture<(), DeleteDocumentationVersionError> {
+        (**self).delete_documentation_version(input)
+    }
+    fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> RusotoFuture<(), DeleteDomainNameError> {
+        (**self).delete_domain_name(input)
+    }
+    fn delete_gateway_response(
+        &self,
+        input: DeleteGatewayResponseRequest,
+    ) -> RusotoFuture<(), DeleteGatewayResponseError> {
+        (**self).delete_gateway_response(input)
+    }
+    fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationError> {
+        (**self).delete_integration(input)
+    }
+    fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationResponseError> {
+        (**self).delete_integration_response(input)
+    }
+    fn delete_method(&self, input: DeleteMethodRequest) -> RusotoFuture<(), DeleteMethodError> {
+        (**self).delete_method(input)
+    }
+    fn delete_method_response(
+        &self,
+        input: DeleteMethodResponseRequest,
+    ) -> RusotoFuture<(), DeleteMethodResponseError> {
+        (**self).delete_method_response(input)
+    }
+    fn delete_model(&self, input: DeleteModelRequest) -> RusotoFuture<(), DeleteModelError> {
+        (**self).delete_model(input)
+    }
+    fn delete_request_validator(
+        &self,
+        input: DeleteRequestValidatorRequest,
+    ) -> RusotoFuture<(), DeleteRequestValidatorError> {
+        (**self).delete_request_validator(input)
+    }
+    fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> RusotoFuture<(), DeleteResourceError> {
+        (**self).delete_resource(input)
+    }
+    fn delete_rest_api(&self, input: DeleteRestApiRequest) -> RusotoFuture<(), DeleteRestApiError> {
+        (**self).delete_rest_api(input)
+    }
+    fn delete_stage(&self, input: DeleteStageRequest) -> RusotoFuture<(), DeleteStageError> {
+        (**self).delete_stage(input)
+    }
+    fn delete_usage_plan(
+        &self,
+        input: DeleteUsagePlanRequest,
+    ) -> RusotoFuture<(), DeleteUsagePlanError> {
+        (**self).delete_usage_plan(input)
+    }
+    fn delete_usage_plan_key(
+        &self,
+        input: DeleteUsagePlanKeyRequest,
+    ) -> RusotoFuture<(), DeleteUsagePlanKeyError> {
+        (**self).delete_usage_plan_key(input)
+    }
+    fn delete_vpc_link(&self, input: DeleteVpcLinkRequest) -> RusotoFuture<(), DeleteVpcLinkError> {
+        (**self).delete_vpc_link(input)
+    }
+    fn flush_stage_authorizers_cache(
+        &self,
+        input: FlushStageAuthorizersCacheRequest,
+    ) -> RusotoFuture<(), FlushStageAuthorizersCacheError> {
+        (**self).flush_stage_authorizers_cache(input)
+    }
+    fn flush_stage_cache(
+        &self,
+        input: FlushStageCacheRequest,
+    ) -> RusotoFuture<(), FlushStageCacheError> {
+        (**self).flush_stage_cache(input)
+    }
+    fn generate_client_certificate(
+        &self,
+        input: GenerateClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, GenerateClientCertificateError> {
+        (**self).generate_client_certificate(input)
+    }
+    fn get_account(&self) -> RusotoFuture<Account, GetAccountError> {
+        (**self).get_account()
+    }
+    fn get_api_key(&self, input: GetApiKeyRequest) -> RusotoFuture<ApiKey, GetApiKeyError> {
+        (**self).get_api_key(input)
+    }
+    fn get_api_keys(&self, input: GetApiKeysRequest) -> RusotoFuture<ApiKeys, GetApiKeysError> {
+        (**self).get_api_keys(input)
+    }
+    fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, GetAuthorizerError> {
+        (**self).get_authorizer(input)
+    }
+    fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> RusotoFuture<Authorizers, GetAuthorizersError> {
+        (**self).get_authorizers(input)
+    }
+    fn get_base_path_mapping(
+        &self,
+        input: GetBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, GetBasePathMappingError> {
+        (**self).get_base_path_mapping(input)
+    }
+    fn get_base_path_mappings(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> RusotoFuture<BasePathMappings, GetBasePathMappingsError> {
+        (**self).get_base_path_mappings(input)
+    }
+    fn get_client_certificate(
+        &self,
+        input: GetClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, GetClientCertificateError> {
+        (**self).get_client_certificate(input)
+    }
+    fn get_client_certificates(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> RusotoFuture<ClientCertificates, GetClientCertificatesError> {
+        (**self).get_client_certificates(input)
+    }
+    fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> RusotoFuture<Deployment, GetDeploymentError> {
+        (**self).get_deployment(input)
+    }
+    fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> RusotoFuture<Deployments, GetDeploymentsError> {
+        (**self).get_deployments(input)
+    }
+    fn get_documentation_part(
+        &self,
+        input: GetDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, GetDocumentationPartError> {
+        (**self).get_documentation_part(input)
+    }
+    fn get_documentation_parts(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> RusotoFuture<DocumentationParts, GetDocumentationPartsError> {
+        (**self).get_documentation_parts(input)
+    }
+    fn get_documentation_version(
+        &self,
+        input: GetDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, GetDocumentationVersionError> {
+        (**self).get_documentation_version(input)
+    }
+    fn get_documentation_versions(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> RusotoFuture<DocumentationVersions, GetDocumentationVersionsError> {
+        (**self).get_documentation_versions(input)
+    }
+    fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> RusotoFuture<DomainName, GetDomainNameError> {
+        (**self).get_domain_name(input)
+    }
+    fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> RusotoFuture<DomainNames, GetDomainNamesError> {
+        (**self).get_domain_names(input)
+    }
+    fn get_export(&self, input: GetExportRequest) -> RusotoFuture<ExportResponse, GetExportError> {
+        (**self).get_export(input)
+    }
+    fn get_gateway_response(
+        &self,
+        input: GetGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, GetGatewayResponseError> {
+        (**self).get_gateway_response(input)
+    }
+    fn get_gateway_responses(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> RusotoFuture<GatewayResponses, GetGatewayResponsesError> {
+        (**self).get_gateway_responses(input)
+    }
+    fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> RusotoFuture<Integration, GetIntegrationError> {
+        (**self).get_integration(input)
+    }
+    fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, GetIntegrationResponseError> {
+        (**self).get_integration_response(input)
+    }
+    fn get_method(&self, input: GetMethodRequest) -> RusotoFuture<Method, GetMethodError> {
+        (**self).get_method(input)
+    }
+    fn get_method_response(
+        &self,
+        input: GetMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, GetMethodResponseError> {
+        (**self).get_method_response(input)
+    }
+    fn get_model(&self, input: GetModelRequest) -> RusotoFuture<Model, GetModelError> {
+        (**self).get_model(input)
+    }
+    fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> RusotoFuture<Template, GetModelTemplateError> {
+        (**self).get_model_template(input)
+    }
+    fn get_models(&self, input: GetModelsRequest) -> RusotoFuture<Models, GetModelsError> {
+        (**self).get_models(input)
+    }
+    fn get_request_validator(
+        &self,
+        input: GetRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, GetRequestValidatorError> {
+        (**self).get_request_validator(input)
+    }
+    fn get_request_validators(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> RusotoFuture<RequestValidators, GetRequestValidatorsError> {
+        (**self).get_request_validators(input)
+    }
+    fn get_resource(&self, input: GetResourceRequest) -> RusotoFuture<Resource, GetResourceError> {
+        (**self).get_resource(input)
+    }
+    fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> RusotoFuture<Resources, GetResourcesError> {
+        (**self).get_resources(input)
+    }
+    fn get_rest_api(&self, input: GetRestApiRequest) -> RusotoFuture<RestApi, GetRestApiError> {
+        (**self).get_rest_api(input)
+    }
+    fn get_rest_apis(&self, input: GetRestApisRequest) -> RusotoFuture<RestApis, GetRestApisError> {
+        (**self).get_rest_apis(input)
+    }
+    fn get_sdk(&self, input: GetSdkRequest) -> RusotoFuture<SdkResponse, GetSdkError> {
+        (**self).get_sdk(input)
+    }
+    fn get_sdk_type(&self, input: GetSdkTypeRequest) -> RusotoFuture<SdkType, GetSdkTypeError> {
+        (**self).get_sdk_type(input)
+    }
+    fn get_sdk_types(&self, input: GetSdkTypesRequest) -> RusotoFuture<SdkTypes, GetSdkTypesError> {
+        (**self).get_sdk_types(input)
+    }
+    fn get_stage(&self, input: GetStageRequest) -> RusotoFuture<Stage, GetStageError> {
+        (**self).get_stage(input)
+    }
+    fn get_stages(&self, input: GetStagesRequest) -> RusotoFuture<Stages, GetStagesError> {
+        (**self).get_stages(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<Tags, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn get_usage(&self, input: GetUsageRequest) -> RusotoFuture<Usage, GetUsageError> {
+        (**self).get_usage(input)
+    }
+    fn get_usage_plan(
+        &self,
+        input: GetUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, GetUsagePlanError> {
+        (**self).get_usage_plan(input)
+    }
+    fn get_usage_plan_key(
+        &self,
+        input: GetUsagePlanKeyRequest,
+    ) -> RusotoFuture<UsagePlanKey, GetUsagePlanKeyError> {
+        (**self).get_usage_plan_key(input)
+    }
+    fn get_usage_plan_keys(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> RusotoFuture<UsagePlanKeys, GetUsagePlanKeysError> {
+        (**self).get_usage_plan_keys(input)
+    }
+    fn get_usage_plans(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> RusotoFuture<UsagePlans, GetUsagePlansError> {
+        (**self).get_usage_plans(input)
+    }
+    fn get_vpc_link(&self, input: GetVpcLinkRequest) -> RusotoFuture<VpcLink, GetVpcLinkError> {
+        (**self).get_vpc_link(input)
+    }
+    fn get_vpc_links(&self, input: GetVpcLinksRequest) -> RusotoFuture<VpcLinks, GetVpcLinksError> {
+        (**self).get_vpc_links(input)
+    }
+    fn import_api_keys(
+        &self,
+        input: ImportApiKeysRequest,
+    ) -> RusotoFuture<ApiKeyIds, ImportApiKeysError> {
+        (**self).import_api_keys(input)
+    }
+    fn import_documentation_parts(
+        &self,
+        input: ImportDocumentationPartsRequest,
+    ) -> RusotoFuture<DocumentationPartIds, ImportDocumentationPartsError> {
+        (**self).import_documentation_parts(input)
+    }
+    fn import_rest_api(
+        &self,
+        input: ImportRestApiRequest,
+    ) -> RusotoFuture<RestApi, ImportRestApiError> {
+        (**self).import_rest_api(input)
+    }
+    fn put_gateway_response(
+        &self,
+        input: PutGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, PutGatewayResponseError> {
+        (**self).put_gateway_response(input)
+    }
+    fn put_integration(
+        &self,
+        input: PutIntegrationRequest,
+    ) -> RusotoFuture<Integration, PutIntegrationError> {
+        (**self).put_integration(input)
+    }
+    fn put_integration_response(
+        &self,
+        input: PutIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, PutIntegrationResponseError> {
+        (**self).put_integration_response(input)
+    }
+    fn put_method(&self, input: PutMethodRequest) -> RusotoFuture<Method, PutMethodError> {
+        (**self).put_method(input)
+    }
+    fn put_method_response(
+        &self,
+        input: PutMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, PutMethodResponseError> {
+        (**self).put_method_response(input)
+    }
+    fn put_rest_api(&self, input: PutRestApiRequest) -> RusotoFuture<RestApi, PutRestApiError> {
+        (**self).put_rest_api(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_invoke_authorizer(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> RusotoFuture<TestInvokeAuthorizerResponse, TestInvokeAuthorizerError> {
+        (**self).test_invoke_authorizer(input)
+    }
+    fn test_invoke_method(
+        &self,
+        input: TestInvokeMethodRequest,
+    ) -> RusotoFuture<TestInvokeMethodResponse, TestInvokeMethodError> {
+        (**self).test_invoke_method(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> RusotoFuture<Account, UpdateAccountError> {
+        (**self).update_account(input)
+    }
+    fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> RusotoFuture<ApiKey, UpdateApiKeyError> {
+        (**self).update_api_key(input)
+    }
+    fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> RusotoFuture<Authorizer, UpdateAuthorizerError> {
+        (**self).update_authorizer(input)
+    }
+    fn update_base_path_mapping(
+        &self,
+        input: UpdateBasePathMappingRequest,
+    ) -> RusotoFuture<BasePathMapping, UpdateBasePathMappingError> {
+        (**self).update_base_path_mapping(input)
+    }
+    fn update_client_certificate(
+        &self,
+        input: UpdateClientCertificateRequest,
+    ) -> RusotoFuture<ClientCertificate, UpdateClientCertificateError> {
+        (**self).update_client_certificate(input)
+    }
+    fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> RusotoFuture<Deployment, UpdateDeploymentError> {
+        (**self).update_deployment(input)
+    }
+    fn update_documentation_part(
+        &self,
+        input: UpdateDocumentationPartRequest,
+    ) -> RusotoFuture<DocumentationPart, UpdateDocumentationPartError> {
+        (**self).update_documentation_part(input)
+    }
+    fn update_documentation_version(
+        &self,
+        input: UpdateDocumentationVersionRequest,
+    ) -> RusotoFuture<DocumentationVersion, UpdateDocumentationVersionError> {
+        (**self).update_documentation_version(input)
+    }
+    fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> RusotoFuture<DomainName, UpdateDomainNameError> {
+        (**self).update_domain_name(input)
+    }
+    fn update_gateway_response(
+        &self,
+        input: UpdateGatewayResponseRequest,
+    ) -> RusotoFuture<GatewayResponse, UpdateGatewayResponseError> {
+        (**self).update_gateway_response(input)
+    }
+    fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> RusotoFuture<Integration, UpdateIntegrationError> {
+        (**self).update_integration(input)
+    }
+    fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> RusotoFuture<IntegrationResponse, UpdateIntegrationResponseError> {
+        (**self).update_integration_response(input)
+    }
+    fn update_method(&self, input: UpdateMethodRequest) -> RusotoFuture<Method, UpdateMethodError> {
+        (**self).update_method(input)
+    }
+    fn update_method_response(
+        &self,
+        input: UpdateMethodResponseRequest,
+    ) -> RusotoFuture<MethodResponse, UpdateMethodResponseError> {
+        (**self).update_method_response(input)
+    }
+    fn update_model(&self, input: UpdateModelRequest) -> RusotoFuture<Model, UpdateModelError> {
+        (**self).update_model(input)
+    }
+    fn update_request_validator(
+        &self,
+        input: UpdateRequestValidatorRequest,
+    ) -> RusotoFuture<RequestValidator, UpdateRequestValidatorError> {
+        (**self).update_request_validator(input)
+    }
+    fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> RusotoFuture<Resource, UpdateResourceError> {
+        (**self).update_resource(input)
+    }
+    fn update_rest_api(
+        &self,
+        input: UpdateRestApiRequest,
+    ) -> RusotoFuture<RestApi, UpdateRestApiError> {
+        (**self).update_rest_api(input)
+    }
+    fn update_stage(&self, input: UpdateStageRequest) -> RusotoFuture<Stage, UpdateStageError> {
+        (**self).update_stage(input)
+    }
+    fn update_usage(&self, input: UpdateUsageRequest) -> RusotoFuture<Usage, UpdateUsageError> {
+        (**self).update_usage(input)
+    }
+    fn update_usage_plan(
+        &self,
+        input: UpdateUsagePlanRequest,
+    ) -> RusotoFuture<UsagePlan, UpdateUsagePlanError> {
+        (**self).update_usage_plan(input)
+    }
+    fn update_vpc_link(
+        &self,
+        input: UpdateVpcLinkRequest,
+    ) -> RusotoFuture<VpcLink, UpdateVpcLinkError> {
+        (**self).update_vpc_link(input)
+    }
+}
+/// The error codes Amazon API Gateway retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
+/// A client for the Amazon API Gateway API.
+#[derive(Clone)]
+pub struct ApiGatewayClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ApiGatewayClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ApiGatewayClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_APIGATEWAY`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ApiGatewayClient {
+        Self::new(region::Region::from_env_for_service("APIGATEWAY"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `apigateway`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ApiGatewayClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("apigateway", "APIGATEWAY"),
+        )
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApiGatewayClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayClient {
+        ApiGatewayClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "apigateway")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "apigateway",
+            "apigateway",
+            "APIGATEWAY",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ApiGateway` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ApiGateway + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ApiGatewayClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon API Gateway"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "apigateway"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ApiGatewayClient {
+        Self::new_with_client(client, region)
     }
 }
 
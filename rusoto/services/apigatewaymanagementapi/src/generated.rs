@@ -22,32 +22,97 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteConnectionRequest {
     #[serde(rename = "ConnectionId")]
     pub connection_id: String,
 }
 
+impl DeleteConnectionRequest {
+    /// Returns a builder for `DeleteConnectionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteConnectionRequestBuilder {
+        DeleteConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConnectionRequest`](struct.DeleteConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteConnectionRequestBuilder {
+    connection_id: Option<String>,
+}
+
+impl DeleteConnectionRequestBuilder {
+    /// Sets the [`connection_id`](struct.DeleteConnectionRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteConnectionRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetConnectionRequest {
     #[serde(rename = "ConnectionId")]
     pub connection_id: String,
 }
 
+impl GetConnectionRequest {
+    /// Returns a builder for `GetConnectionRequest`, checking its required members when built.
+    pub fn builder() -> GetConnectionRequestBuilder {
+        GetConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetConnectionRequest`](struct.GetConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetConnectionRequestBuilder {
+    connection_id: Option<String>,
+}
+
+impl GetConnectionRequestBuilder {
+    /// Sets the [`connection_id`](struct.GetConnectionRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(GetConnectionRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetConnectionResponse {
     /// <p>The time in ISO 8601 format for when the connection was established.</p>
     #[serde(rename = "ConnectedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connected_at: Option<f64>,
+    pub connected_at: Option<EpochTimestamp>,
     #[serde(rename = "Identity")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub identity: Option<Identity>,
     /// <p>The time in ISO 8601 format for when the connection was last active.</p>
     #[serde(rename = "LastActiveAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_active_at: Option<f64>,
+    pub last_active_at: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -76,6 +141,48 @@ pub struct PostToConnectionRequest {
     pub data: bytes::Bytes,
 }
 
+impl PostToConnectionRequest {
+    /// Returns a builder for `PostToConnectionRequest`, checking its required members when built.
+    pub fn builder() -> PostToConnectionRequestBuilder {
+        PostToConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PostToConnectionRequest`](struct.PostToConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct PostToConnectionRequestBuilder {
+    connection_id: Option<String>,
+    data: Option<bytes::Bytes>,
+}
+
+impl PostToConnectionRequestBuilder {
+    /// Sets the [`connection_id`](struct.PostToConnectionRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data`](struct.PostToConnectionRequest.html#structfield.data) member.
+    pub fn data<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.data = Some(value.into());
+        self
+    }
+
+    /// Builds the `PostToConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PostToConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(PostToConnectionRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            data: self
+                .data
+                .ok_or(::rusoto_core::BuildError::missing("data"))?,
+        })
+    }
+}
+
 /// Errors returned by DeleteConnection
 #[derive(Debug, PartialEq)]
 pub enum DeleteConnectionError {
@@ -235,6 +342,33 @@ pub trait ApiGatewayManagementApi {
         input: PostToConnectionRequest,
     ) -> RusotoFuture<(), PostToConnectionError>;
 }
+impl<T: ?Sized + ApiGatewayManagementApi> ApiGatewayManagementApi for ::std::sync::Arc<T> {
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<(), DeleteConnectionError> {
+        (**self).delete_connection(input)
+    }
+    fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> RusotoFuture<GetConnectionResponse, GetConnectionError> {
+        (**self).get_connection(input)
+    }
+    fn post_to_connection(
+        &self,
+        input: PostToConnectionRequest,
+    ) -> RusotoFuture<(), PostToConnectionError> {
+        (**self).post_to_connection(input)
+    }
+}
+/// The error codes AmazonApiGatewayManagementApi retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AmazonApiGatewayManagementApi API.
 #[derive(Clone)]
 pub struct ApiGatewayManagementApiClient {
@@ -250,6 +384,29 @@ impl ApiGatewayManagementApiClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_APIGATEWAYMANAGEMENTAPI`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ApiGatewayManagementApiClient {
+        Self::new(region::Region::from_env_for_service(
+            "APIGATEWAYMANAGEMENTAPI",
+        ))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `execute-api`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ApiGatewayManagementApiClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("execute-api", "APIGATEWAYMANAGEMENTAPI"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -271,7 +428,54 @@ impl ApiGatewayManagementApiClient {
         client: Client,
         region: region::Region,
     ) -> ApiGatewayManagementApiClient {
-        ApiGatewayManagementApiClient { client, region }
+        ApiGatewayManagementApiClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "execute-api")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "execute-api",
+            "execute-api",
+            "APIGATEWAYMANAGEMENTAPI",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ApiGatewayManagementApi` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ApiGatewayManagementApi + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ApiGatewayManagementApiClient {
+    fn service_name(&self) -> &'static str {
+        "AmazonApiGatewayManagementApi"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "execute-api"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ApiGatewayManagementApiClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AmazonApiGatewayV2 clients.
+//!
+//! Makes a single read-only `GetApis` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_apigatewayv2::{ApiGatewayV2, ApiGatewayV2Client, GetApisRequest};
+
+fn main() {
+    let client = ApiGatewayV2Client::from_env();
+    match client.get_apis(GetApisRequest::default()).sync() {
+        Ok(_) => println!("GetApis succeeded"),
+        Err(error) => {
+            eprintln!("GetApis failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Settings for logging access in a stage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -39,7 +40,7 @@ pub struct AccessLogSettings {
 }
 
 /// <p>Represents an API.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Api {
     /// <p>The URI of the API, of the form {api-id}.execute-api.{region}.amazonaws.com. The
@@ -58,8 +59,9 @@ pub struct Api {
     pub api_key_selection_expression: Option<String>,
     /// <p>The timestamp when the API was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The description of the API.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -73,7 +75,7 @@ pub struct Api {
     pub name: String,
     /// <p>The API protocol: Currently only WEBSOCKET is supported.</p>
     #[serde(rename = "ProtocolType")]
-    pub protocol_type: String,
+    pub protocol_type: ProtocolType,
     /// <p>The route selection expression for the API.</p>
     #[serde(rename = "RouteSelectionExpression")]
     pub route_selection_expression: String,
@@ -112,6 +114,92 @@ pub struct ApiMapping {
     pub stage: String,
 }
 
+/// <p>The authorization type. For WebSocket APIs, valid values are NONE for open access, AWS_IAM for using AWS IAM permissions, and CUSTOM for using a Lambda authorizer. For HTTP APIs, valid values are NONE for open access, JWT for using JSON Web Tokens, AWS_IAM for using AWS IAM permissions, and CUSTOM for using a Lambda authorizer.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthorizationType {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>AWS_IAM</code></p>
+    AwsIam,
+    /// <p><code>CUSTOM</code></p>
+    Custom,
+    /// <p><code>JWT</code></p>
+    Jwt,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthorizationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthorizationType::None => "NONE",
+            AuthorizationType::AwsIam => "AWS_IAM",
+            AuthorizationType::Custom => "CUSTOM",
+            AuthorizationType::Jwt => "JWT",
+            AuthorizationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthorizationType {
+    fn from(value: &'a str) -> AuthorizationType {
+        match value {
+            "NONE" => AuthorizationType::None,
+            "AWS_IAM" => AuthorizationType::AwsIam,
+            "CUSTOM" => AuthorizationType::Custom,
+            "JWT" => AuthorizationType::Jwt,
+            _ => AuthorizationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthorizationType {
+    fn from(value: String) -> AuthorizationType {
+        AuthorizationType::from(value.as_str())
+    }
+}
+
+impl From<AuthorizationType> for String {
+    fn from(value: AuthorizationType) -> String {
+        match value {
+            AuthorizationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthorizationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthorizationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthorizationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthorizationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthorizationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthorizationType::from(value))
+    }
+}
+
 /// <p>Represents an authorizer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -138,7 +226,7 @@ pub struct Authorizer {
     /// Lambda function using incoming request parameters.</p>
     #[serde(rename = "AuthorizerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_type: Option<String>,
+    pub authorizer_type: Option<AuthorizerType>,
     /// <p>The authorizer's Uniform Resource Identifier (URI).
     /// ForREQUEST authorizers, this must be a
     /// well-formed Lambda function URI, for example,
@@ -186,6 +274,240 @@ pub struct Authorizer {
     pub provider_arns: Option<Vec<String>>,
 }
 
+/// <p>The authorizer type. Specify REQUEST for a Lambda function using incoming request parameters. Specify JWT to use JSON Web Tokens (supported only for HTTP APIs).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthorizerType {
+    /// <p><code>REQUEST</code></p>
+    Request,
+    /// <p><code>JWT</code></p>
+    Jwt,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthorizerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthorizerType::Request => "REQUEST",
+            AuthorizerType::Jwt => "JWT",
+            AuthorizerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthorizerType {
+    fn from(value: &'a str) -> AuthorizerType {
+        match value {
+            "REQUEST" => AuthorizerType::Request,
+            "JWT" => AuthorizerType::Jwt,
+            _ => AuthorizerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthorizerType {
+    fn from(value: String) -> AuthorizerType {
+        AuthorizerType::from(value.as_str())
+    }
+}
+
+impl From<AuthorizerType> for String {
+    fn from(value: AuthorizerType) -> String {
+        match value {
+            AuthorizerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthorizerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthorizerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthorizerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthorizerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthorizerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthorizerType::from(value))
+    }
+}
+
+/// <p>Represents a connection type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionType {
+    /// <p><code>INTERNET</code></p>
+    Internet,
+    /// <p><code>VPC_LINK</code></p>
+    VpcLink,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConnectionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionType::Internet => "INTERNET",
+            ConnectionType::VpcLink => "VPC_LINK",
+            ConnectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionType {
+    fn from(value: &'a str) -> ConnectionType {
+        match value {
+            "INTERNET" => ConnectionType::Internet,
+            "VPC_LINK" => ConnectionType::VpcLink,
+            _ => ConnectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionType {
+    fn from(value: String) -> ConnectionType {
+        ConnectionType::from(value.as_str())
+    }
+}
+
+impl From<ConnectionType> for String {
+    fn from(value: ConnectionType) -> String {
+        match value {
+            ConnectionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConnectionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConnectionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConnectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConnectionType::from(value))
+    }
+}
+
+/// <p>Specifies how to handle response payload content type conversions. Supported only for WebSocket APIs.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContentHandlingStrategy {
+    /// <p><code>CONVERT_TO_BINARY</code></p>
+    ConvertToBinary,
+    /// <p><code>CONVERT_TO_TEXT</code></p>
+    ConvertToText,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContentHandlingStrategy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentHandlingStrategy::ConvertToBinary => "CONVERT_TO_BINARY",
+            ContentHandlingStrategy::ConvertToText => "CONVERT_TO_TEXT",
+            ContentHandlingStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContentHandlingStrategy {
+    fn from(value: &'a str) -> ContentHandlingStrategy {
+        match value {
+            "CONVERT_TO_BINARY" => ContentHandlingStrategy::ConvertToBinary,
+            "CONVERT_TO_TEXT" => ContentHandlingStrategy::ConvertToText,
+            _ => ContentHandlingStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentHandlingStrategy {
+    fn from(value: String) -> ContentHandlingStrategy {
+        ContentHandlingStrategy::from(value.as_str())
+    }
+}
+
+impl From<ContentHandlingStrategy> for String {
+    fn from(value: ContentHandlingStrategy) -> String {
+        match value {
+            ContentHandlingStrategy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContentHandlingStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentHandlingStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentHandlingStrategy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentHandlingStrategy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContentHandlingStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContentHandlingStrategy::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateApiMappingRequest {
     /// <p>The API identifier.</p>
@@ -203,6 +525,66 @@ pub struct CreateApiMappingRequest {
     pub stage: String,
 }
 
+impl CreateApiMappingRequest {
+    /// Returns a builder for `CreateApiMappingRequest`, checking its required members when built.
+    pub fn builder() -> CreateApiMappingRequestBuilder {
+        CreateApiMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApiMappingRequest`](struct.CreateApiMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateApiMappingRequestBuilder {
+    api_id: Option<String>,
+    api_mapping_key: Option<String>,
+    domain_name: Option<String>,
+    stage: Option<String>,
+}
+
+impl CreateApiMappingRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateApiMappingRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`api_mapping_key`](struct.CreateApiMappingRequest.html#structfield.api_mapping_key) member.
+    pub fn api_mapping_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_mapping_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.CreateApiMappingRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage`](struct.CreateApiMappingRequest.html#structfield.stage) member.
+    pub fn stage<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApiMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateApiMappingRequest, ::rusoto_core::BuildError> {
+        Ok(CreateApiMappingRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            api_mapping_key: self.api_mapping_key,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            stage: self
+                .stage
+                .ok_or(::rusoto_core::BuildError::missing("stage"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateApiMappingResponse {
@@ -224,7 +606,7 @@ pub struct CreateApiMappingResponse {
     pub stage: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateApiRequest {
     /// <p>An API key selection expression. See <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-websocket-api-selection-expressions.html#apigateway-websocket-api-apikey-selection-expressions">API Key Selection Expressions</a>.</p>
     #[serde(rename = "ApiKeySelectionExpression")]
@@ -243,7 +625,7 @@ pub struct CreateApiRequest {
     pub name: String,
     /// <p>The API protocol: Currently only WEBSOCKET is supported.</p>
     #[serde(rename = "ProtocolType")]
-    pub protocol_type: String,
+    pub protocol_type: ProtocolType,
     /// <p>The route selection expression for the API.</p>
     #[serde(rename = "RouteSelectionExpression")]
     pub route_selection_expression: String,
@@ -257,6 +639,96 @@ pub struct CreateApiRequest {
     pub version: Option<String>,
 }
 
+impl CreateApiRequest {
+    /// Returns a builder for `CreateApiRequest`, checking its required members when built.
+    pub fn builder() -> CreateApiRequestBuilder {
+        CreateApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApiRequest`](struct.CreateApiRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateApiRequestBuilder {
+    api_key_selection_expression: Option<String>,
+    description: Option<String>,
+    disable_schema_validation: Option<bool>,
+    name: Option<String>,
+    protocol_type: Option<ProtocolType>,
+    route_selection_expression: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    version: Option<String>,
+}
+
+impl CreateApiRequestBuilder {
+    /// Sets the [`api_key_selection_expression`](struct.CreateApiRequest.html#structfield.api_key_selection_expression) member.
+    pub fn api_key_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_key_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateApiRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disable_schema_validation`](struct.CreateApiRequest.html#structfield.disable_schema_validation) member.
+    pub fn disable_schema_validation<T: Into<bool>>(mut self, value: T) -> Self {
+        self.disable_schema_validation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateApiRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`protocol_type`](struct.CreateApiRequest.html#structfield.protocol_type) member.
+    pub fn protocol_type<T: Into<ProtocolType>>(mut self, value: T) -> Self {
+        self.protocol_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_selection_expression`](struct.CreateApiRequest.html#structfield.route_selection_expression) member.
+    pub fn route_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateApiRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.CreateApiRequest.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApiRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateApiRequest, ::rusoto_core::BuildError> {
+        Ok(CreateApiRequest {
+            api_key_selection_expression: self.api_key_selection_expression,
+            description: self.description,
+            disable_schema_validation: self.disable_schema_validation,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            protocol_type: self
+                .protocol_type
+                .ok_or(::rusoto_core::BuildError::missing("protocol_type"))?,
+            route_selection_expression: self.route_selection_expression.ok_or(
+                ::rusoto_core::BuildError::missing("route_selection_expression"),
+            )?,
+            tags: self.tags,
+            version: self.version,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateApiResponse {
@@ -276,8 +748,9 @@ pub struct CreateApiResponse {
     pub api_key_selection_expression: Option<String>,
     /// <p>The timestamp when the API was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The description of the API.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -293,7 +766,7 @@ pub struct CreateApiResponse {
     /// <p>The API protocol: Currently only WEBSOCKET is supported.</p>
     #[serde(rename = "ProtocolType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol_type: Option<String>,
+    pub protocol_type: Option<ProtocolType>,
     /// <p>The route selection expression for the API.</p>
     #[serde(rename = "RouteSelectionExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -313,7 +786,7 @@ pub struct CreateApiResponse {
     pub warnings: Option<Vec<String>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateAuthorizerRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -335,7 +808,7 @@ pub struct CreateAuthorizerRequest {
     /// <p>The authorizer type. Currently the only valid value is REQUEST, for a
     /// Lambda function using incoming request parameters.</p>
     #[serde(rename = "AuthorizerType")]
-    pub authorizer_type: String,
+    pub authorizer_type: AuthorizerType,
     /// <p>The authorizer's Uniform Resource Identifier (URI). For
     /// REQUEST authorizers, this must be a
     /// well-formed Lambda function URI, for example,
@@ -381,6 +854,110 @@ pub struct CreateAuthorizerRequest {
     pub provider_arns: Option<Vec<String>>,
 }
 
+impl CreateAuthorizerRequest {
+    /// Returns a builder for `CreateAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> CreateAuthorizerRequestBuilder {
+        CreateAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAuthorizerRequest`](struct.CreateAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAuthorizerRequestBuilder {
+    api_id: Option<String>,
+    authorizer_credentials_arn: Option<String>,
+    authorizer_result_ttl_in_seconds: Option<i64>,
+    authorizer_type: Option<AuthorizerType>,
+    authorizer_uri: Option<String>,
+    identity_source: Option<Vec<String>>,
+    identity_validation_expression: Option<String>,
+    name: Option<String>,
+    provider_arns: Option<Vec<String>>,
+}
+
+impl CreateAuthorizerRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateAuthorizerRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_credentials_arn`](struct.CreateAuthorizerRequest.html#structfield.authorizer_credentials_arn) member.
+    pub fn authorizer_credentials_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_credentials_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_result_ttl_in_seconds`](struct.CreateAuthorizerRequest.html#structfield.authorizer_result_ttl_in_seconds) member.
+    pub fn authorizer_result_ttl_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.authorizer_result_ttl_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_type`](struct.CreateAuthorizerRequest.html#structfield.authorizer_type) member.
+    pub fn authorizer_type<T: Into<AuthorizerType>>(mut self, value: T) -> Self {
+        self.authorizer_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_uri`](struct.CreateAuthorizerRequest.html#structfield.authorizer_uri) member.
+    pub fn authorizer_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_source`](struct.CreateAuthorizerRequest.html#structfield.identity_source) member.
+    pub fn identity_source<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.identity_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_validation_expression`](struct.CreateAuthorizerRequest.html#structfield.identity_validation_expression) member.
+    pub fn identity_validation_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_validation_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateAuthorizerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_arns`](struct.CreateAuthorizerRequest.html#structfield.provider_arns) member.
+    pub fn provider_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.provider_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAuthorizerRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            authorizer_credentials_arn: self.authorizer_credentials_arn,
+            authorizer_result_ttl_in_seconds: self.authorizer_result_ttl_in_seconds,
+            authorizer_type: self
+                .authorizer_type
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_type"))?,
+            authorizer_uri: self
+                .authorizer_uri
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_uri"))?,
+            identity_source: self
+                .identity_source
+                .ok_or(::rusoto_core::BuildError::missing("identity_source"))?,
+            identity_validation_expression: self.identity_validation_expression,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            provider_arns: self.provider_arns,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateAuthorizerResponse {
@@ -406,7 +983,7 @@ pub struct CreateAuthorizerResponse {
     /// Lambda function using incoming request parameters.</p>
     #[serde(rename = "AuthorizerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_type: Option<String>,
+    pub authorizer_type: Option<AuthorizerType>,
     /// <p>The authorizer's Uniform Resource Identifier (URI).
     /// ForREQUEST authorizers, this must be a
     /// well-formed Lambda function URI, for example,
@@ -471,13 +1048,62 @@ pub struct CreateDeploymentRequest {
     pub stage_name: Option<String>,
 }
 
+impl CreateDeploymentRequest {
+    /// Returns a builder for `CreateDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> CreateDeploymentRequestBuilder {
+        CreateDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDeploymentRequest`](struct.CreateDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDeploymentRequestBuilder {
+    api_id: Option<String>,
+    description: Option<String>,
+    stage_name: Option<String>,
+}
+
+impl CreateDeploymentRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateDeploymentRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateDeploymentRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.CreateDeploymentRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDeploymentRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            description: self.description,
+            stage_name: self.stage_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDeploymentResponse {
     /// <p>The date and time when the Deployment resource was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The identifier for the deployment.</p>
     #[serde(rename = "DeploymentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -486,7 +1112,7 @@ pub struct CreateDeploymentResponse {
     /// SUCCEEDED.</p>
     #[serde(rename = "DeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_status: Option<String>,
+    pub deployment_status: Option<DeploymentStatus>,
     /// <p>May contain additional feedback on the status of an API deployment.</p>
     #[serde(rename = "DeploymentStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -512,6 +1138,57 @@ pub struct CreateDomainNameRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateDomainNameRequest {
+    /// Returns a builder for `CreateDomainNameRequest`, checking its required members when built.
+    pub fn builder() -> CreateDomainNameRequestBuilder {
+        CreateDomainNameRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDomainNameRequest`](struct.CreateDomainNameRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDomainNameRequestBuilder {
+    domain_name: Option<String>,
+    domain_name_configurations: Option<Vec<DomainNameConfiguration>>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateDomainNameRequestBuilder {
+    /// Sets the [`domain_name`](struct.CreateDomainNameRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name_configurations`](struct.CreateDomainNameRequest.html#structfield.domain_name_configurations) member.
+    pub fn domain_name_configurations<T: Into<Vec<DomainNameConfiguration>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.domain_name_configurations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDomainNameRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDomainNameRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDomainNameRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDomainNameRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            domain_name_configurations: self.domain_name_configurations,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDomainNameResponse {
@@ -533,7 +1210,7 @@ pub struct CreateDomainNameResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateIntegrationRequest {
     /// <p>The API identifier.</p>
     #[serde(rename = "ApiId")]
@@ -547,7 +1224,7 @@ pub struct CreateIntegrationRequest {
     /// internet.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Specifies how to handle response payload content type conversions. Supported
     /// values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the
     /// following behaviors:</p><p>
@@ -559,7 +1236,7 @@ pub struct CreateIntegrationRequest {
     /// modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>Specifies the credentials required for the integration, if any. For AWS
     /// integrations, three options are available. To specify an IAM Role for API Gateway to
     /// assume, use the role's Amazon Resource Name (ARN). To require that the caller's
@@ -595,7 +1272,7 @@ pub struct CreateIntegrationRequest {
     /// MOCK: for integrating the route or method request with API Gateway as a
     /// "loopback" endpoint without invoking any backend.</p>
     #[serde(rename = "IntegrationType")]
-    pub integration_type: String,
+    pub integration_type: IntegrationType,
     /// <p>For a Lambda proxy integration, this is the URI of the Lambda function.</p>
     #[serde(rename = "IntegrationUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -616,7 +1293,7 @@ pub struct CreateIntegrationRequest {
     /// Unsupported Media Type response.</p>
     #[serde(rename = "PassthroughBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub passthrough_behavior: Option<String>,
+    pub passthrough_behavior: Option<PassthroughBehavior>,
     /// <p>A key-value map specifying request parameters that are passed from the method
     /// request to the backend. The key is an integration request parameter name and the
     /// associated value is a method request parameter value or static value that must be
@@ -648,18 +1325,162 @@ pub struct CreateIntegrationRequest {
     pub timeout_in_millis: Option<i64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CreateIntegrationResponse {
-    /// <p>The connection ID.</p>
-    #[serde(rename = "ConnectionId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_id: Option<String>,
-    /// <p>The type of the network connection to the integration endpoint. Currently the only
-    /// valid value is INTERNET, for connections through the public routable
-    /// internet.</p>
-    #[serde(rename = "ConnectionType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl CreateIntegrationRequest {
+    /// Returns a builder for `CreateIntegrationRequest`, checking its required members when built.
+    pub fn builder() -> CreateIntegrationRequestBuilder {
+        CreateIntegrationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateIntegrationRequest`](struct.CreateIntegrationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateIntegrationRequestBuilder {
+    api_id: Option<String>,
+    connection_id: Option<String>,
+    connection_type: Option<ConnectionType>,
+    content_handling_strategy: Option<ContentHandlingStrategy>,
+    credentials_arn: Option<String>,
+    description: Option<String>,
+    integration_method: Option<String>,
+    integration_type: Option<IntegrationType>,
+    integration_uri: Option<String>,
+    passthrough_behavior: Option<PassthroughBehavior>,
+    request_parameters: Option<::std::collections::HashMap<String, String>>,
+    request_templates: Option<::std::collections::HashMap<String, String>>,
+    template_selection_expression: Option<String>,
+    timeout_in_millis: Option<i64>,
+}
+
+impl CreateIntegrationRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateIntegrationRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_id`](struct.CreateIntegrationRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_type`](struct.CreateIntegrationRequest.html#structfield.connection_type) member.
+    pub fn connection_type<T: Into<ConnectionType>>(mut self, value: T) -> Self {
+        self.connection_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_handling_strategy`](struct.CreateIntegrationRequest.html#structfield.content_handling_strategy) member.
+    pub fn content_handling_strategy<T: Into<ContentHandlingStrategy>>(mut self, value: T) -> Self {
+        self.content_handling_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`credentials_arn`](struct.CreateIntegrationRequest.html#structfield.credentials_arn) member.
+    pub fn credentials_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.credentials_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateIntegrationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_method`](struct.CreateIntegrationRequest.html#structfield.integration_method) member.
+    pub fn integration_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_type`](struct.CreateIntegrationRequest.html#structfield.integration_type) member.
+    pub fn integration_type<T: Into<IntegrationType>>(mut self, value: T) -> Self {
+        self.integration_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_uri`](struct.CreateIntegrationRequest.html#structfield.integration_uri) member.
+    pub fn integration_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`passthrough_behavior`](struct.CreateIntegrationRequest.html#structfield.passthrough_behavior) member.
+    pub fn passthrough_behavior<T: Into<PassthroughBehavior>>(mut self, value: T) -> Self {
+        self.passthrough_behavior = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_parameters`](struct.CreateIntegrationRequest.html#structfield.request_parameters) member.
+    pub fn request_parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_templates`](struct.CreateIntegrationRequest.html#structfield.request_templates) member.
+    pub fn request_templates<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_templates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_selection_expression`](struct.CreateIntegrationRequest.html#structfield.template_selection_expression) member.
+    pub fn template_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout_in_millis`](struct.CreateIntegrationRequest.html#structfield.timeout_in_millis) member.
+    pub fn timeout_in_millis<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout_in_millis = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateIntegrationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateIntegrationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateIntegrationRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            connection_id: self.connection_id,
+            connection_type: self.connection_type,
+            content_handling_strategy: self.content_handling_strategy,
+            credentials_arn: self.credentials_arn,
+            description: self.description,
+            integration_method: self.integration_method,
+            integration_type: self
+                .integration_type
+                .ok_or(::rusoto_core::BuildError::missing("integration_type"))?,
+            integration_uri: self.integration_uri,
+            passthrough_behavior: self.passthrough_behavior,
+            request_parameters: self.request_parameters,
+            request_templates: self.request_templates,
+            template_selection_expression: self.template_selection_expression,
+            timeout_in_millis: self.timeout_in_millis,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateIntegrationResponse {
+    /// <p>The connection ID.</p>
+    #[serde(rename = "ConnectionId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+    /// <p>The type of the network connection to the integration endpoint. Currently the only
+    /// valid value is INTERNET, for connections through the public routable
+    /// internet.</p>
+    #[serde(rename = "ConnectionType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub connection_type: Option<String>,
     /// <p>Specifies how to handle response payload content type conversions. Supported
     /// values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the
@@ -786,7 +1607,7 @@ pub struct CreateIntegrationResponseRequest {
     /// modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>The integration ID.</p>
     #[serde(rename = "IntegrationId")]
     pub integration_id: String,
@@ -819,6 +1640,96 @@ pub struct CreateIntegrationResponseRequest {
     pub template_selection_expression: Option<String>,
 }
 
+impl CreateIntegrationResponseRequest {
+    /// Returns a builder for `CreateIntegrationResponseRequest`, checking its required members when built.
+    pub fn builder() -> CreateIntegrationResponseRequestBuilder {
+        CreateIntegrationResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateIntegrationResponseRequest`](struct.CreateIntegrationResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateIntegrationResponseRequestBuilder {
+    api_id: Option<String>,
+    content_handling_strategy: Option<ContentHandlingStrategy>,
+    integration_id: Option<String>,
+    integration_response_key: Option<String>,
+    response_parameters: Option<::std::collections::HashMap<String, String>>,
+    response_templates: Option<::std::collections::HashMap<String, String>>,
+    template_selection_expression: Option<String>,
+}
+
+impl CreateIntegrationResponseRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateIntegrationResponseRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_handling_strategy`](struct.CreateIntegrationResponseRequest.html#structfield.content_handling_strategy) member.
+    pub fn content_handling_strategy<T: Into<ContentHandlingStrategy>>(mut self, value: T) -> Self {
+        self.content_handling_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_id`](struct.CreateIntegrationResponseRequest.html#structfield.integration_id) member.
+    pub fn integration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_response_key`](struct.CreateIntegrationResponseRequest.html#structfield.integration_response_key) member.
+    pub fn integration_response_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_response_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_parameters`](struct.CreateIntegrationResponseRequest.html#structfield.response_parameters) member.
+    pub fn response_parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_templates`](struct.CreateIntegrationResponseRequest.html#structfield.response_templates) member.
+    pub fn response_templates<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_templates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_selection_expression`](struct.CreateIntegrationResponseRequest.html#structfield.template_selection_expression) member.
+    pub fn template_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateIntegrationResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateIntegrationResponseRequest, ::rusoto_core::BuildError> {
+        Ok(CreateIntegrationResponseRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            content_handling_strategy: self.content_handling_strategy,
+            integration_id: self
+                .integration_id
+                .ok_or(::rusoto_core::BuildError::missing("integration_id"))?,
+            integration_response_key: self.integration_response_key.ok_or(
+                ::rusoto_core::BuildError::missing("integration_response_key"),
+            )?,
+            response_parameters: self.response_parameters,
+            response_templates: self.response_templates,
+            template_selection_expression: self.template_selection_expression,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateIntegrationResponseResponse {
@@ -833,7 +1744,7 @@ pub struct CreateIntegrationResponseResponse {
     /// modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>The integration response ID.</p>
     #[serde(rename = "IntegrationResponseId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -888,6 +1799,72 @@ pub struct CreateModelRequest {
     pub schema: String,
 }
 
+impl CreateModelRequest {
+    /// Returns a builder for `CreateModelRequest`, checking its required members when built.
+    pub fn builder() -> CreateModelRequestBuilder {
+        CreateModelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateModelRequest`](struct.CreateModelRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateModelRequestBuilder {
+    api_id: Option<String>,
+    content_type: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+    schema: Option<String>,
+}
+
+impl CreateModelRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateModelRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_type`](struct.CreateModelRequest.html#structfield.content_type) member.
+    pub fn content_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateModelRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateModelRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema`](struct.CreateModelRequest.html#structfield.schema) member.
+    pub fn schema<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateModelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateModelRequest, ::rusoto_core::BuildError> {
+        Ok(CreateModelRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            content_type: self.content_type,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            schema: self
+                .schema
+                .ok_or(::rusoto_core::BuildError::missing("schema"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateModelResponse {
@@ -934,7 +1911,7 @@ pub struct CreateRouteRequest {
     /// authorizer.</p>
     #[serde(rename = "AuthorizationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorization_type: Option<String>,
+    pub authorization_type: Option<AuthorizationType>,
     /// <p>The identifier of the Authorizer resource to be associated with this
     /// route, if the authorizationType is CUSTOM
     /// . The authorizer identifier is generated by API Gateway
@@ -971,6 +1948,134 @@ pub struct CreateRouteRequest {
     pub target: Option<String>,
 }
 
+impl CreateRouteRequest {
+    /// Returns a builder for `CreateRouteRequest`, checking its required members when built.
+    pub fn builder() -> CreateRouteRequestBuilder {
+        CreateRouteRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRouteRequest`](struct.CreateRouteRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRouteRequestBuilder {
+    api_id: Option<String>,
+    api_key_required: Option<bool>,
+    authorization_scopes: Option<Vec<String>>,
+    authorization_type: Option<AuthorizationType>,
+    authorizer_id: Option<String>,
+    model_selection_expression: Option<String>,
+    operation_name: Option<String>,
+    request_models: Option<::std::collections::HashMap<String, String>>,
+    request_parameters: Option<::std::collections::HashMap<String, ParameterConstraints>>,
+    route_key: Option<String>,
+    route_response_selection_expression: Option<String>,
+    target: Option<String>,
+}
+
+impl CreateRouteRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateRouteRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`api_key_required`](struct.CreateRouteRequest.html#structfield.api_key_required) member.
+    pub fn api_key_required<T: Into<bool>>(mut self, value: T) -> Self {
+        self.api_key_required = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorization_scopes`](struct.CreateRouteRequest.html#structfield.authorization_scopes) member.
+    pub fn authorization_scopes<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.authorization_scopes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorization_type`](struct.CreateRouteRequest.html#structfield.authorization_type) member.
+    pub fn authorization_type<T: Into<AuthorizationType>>(mut self, value: T) -> Self {
+        self.authorization_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_id`](struct.CreateRouteRequest.html#structfield.authorizer_id) member.
+    pub fn authorizer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`model_selection_expression`](struct.CreateRouteRequest.html#structfield.model_selection_expression) member.
+    pub fn model_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.model_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation_name`](struct.CreateRouteRequest.html#structfield.operation_name) member.
+    pub fn operation_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_models`](struct.CreateRouteRequest.html#structfield.request_models) member.
+    pub fn request_models<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_models = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_parameters`](struct.CreateRouteRequest.html#structfield.request_parameters) member.
+    pub fn request_parameters<
+        T: Into<::std::collections::HashMap<String, ParameterConstraints>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_key`](struct.CreateRouteRequest.html#structfield.route_key) member.
+    pub fn route_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_response_selection_expression`](struct.CreateRouteRequest.html#structfield.route_response_selection_expression) member.
+    pub fn route_response_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_response_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target`](struct.CreateRouteRequest.html#structfield.target) member.
+    pub fn target<T: Into<String>>(mut self, value: T) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRouteRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateRouteRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRouteRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            api_key_required: self.api_key_required,
+            authorization_scopes: self.authorization_scopes,
+            authorization_type: self.authorization_type,
+            authorizer_id: self.authorizer_id,
+            model_selection_expression: self.model_selection_expression,
+            operation_name: self.operation_name,
+            request_models: self.request_models,
+            request_parameters: self.request_parameters,
+            route_key: self
+                .route_key
+                .ok_or(::rusoto_core::BuildError::missing("route_key"))?,
+            route_response_selection_expression: self.route_response_selection_expression,
+            target: self.target,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRouteResponse {
@@ -1061,6 +2166,90 @@ pub struct CreateRouteResponseRequest {
     pub route_response_key: String,
 }
 
+impl CreateRouteResponseRequest {
+    /// Returns a builder for `CreateRouteResponseRequest`, checking its required members when built.
+    pub fn builder() -> CreateRouteResponseRequestBuilder {
+        CreateRouteResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRouteResponseRequest`](struct.CreateRouteResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRouteResponseRequestBuilder {
+    api_id: Option<String>,
+    model_selection_expression: Option<String>,
+    response_models: Option<::std::collections::HashMap<String, String>>,
+    response_parameters: Option<::std::collections::HashMap<String, ParameterConstraints>>,
+    route_id: Option<String>,
+    route_response_key: Option<String>,
+}
+
+impl CreateRouteResponseRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateRouteResponseRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`model_selection_expression`](struct.CreateRouteResponseRequest.html#structfield.model_selection_expression) member.
+    pub fn model_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.model_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_models`](struct.CreateRouteResponseRequest.html#structfield.response_models) member.
+    pub fn response_models<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_models = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_parameters`](struct.CreateRouteResponseRequest.html#structfield.response_parameters) member.
+    pub fn response_parameters<
+        T: Into<::std::collections::HashMap<String, ParameterConstraints>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_id`](struct.CreateRouteResponseRequest.html#structfield.route_id) member.
+    pub fn route_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_response_key`](struct.CreateRouteResponseRequest.html#structfield.route_response_key) member.
+    pub fn route_response_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_response_key = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRouteResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateRouteResponseRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRouteResponseRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            model_selection_expression: self.model_selection_expression,
+            response_models: self.response_models,
+            response_parameters: self.response_parameters,
+            route_id: self
+                .route_id
+                .ok_or(::rusoto_core::BuildError::missing("route_id"))?,
+            route_response_key: self
+                .route_response_key
+                .ok_or(::rusoto_core::BuildError::missing("route_response_key"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRouteResponseResponse {
@@ -1130,6 +2319,116 @@ pub struct CreateStageRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateStageRequest {
+    /// Returns a builder for `CreateStageRequest`, checking its required members when built.
+    pub fn builder() -> CreateStageRequestBuilder {
+        CreateStageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStageRequest`](struct.CreateStageRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateStageRequestBuilder {
+    access_log_settings: Option<AccessLogSettings>,
+    api_id: Option<String>,
+    client_certificate_id: Option<String>,
+    default_route_settings: Option<RouteSettings>,
+    deployment_id: Option<String>,
+    description: Option<String>,
+    route_settings: Option<::std::collections::HashMap<String, RouteSettings>>,
+    stage_name: Option<String>,
+    stage_variables: Option<::std::collections::HashMap<String, String>>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateStageRequestBuilder {
+    /// Sets the [`access_log_settings`](struct.CreateStageRequest.html#structfield.access_log_settings) member.
+    pub fn access_log_settings<T: Into<AccessLogSettings>>(mut self, value: T) -> Self {
+        self.access_log_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`api_id`](struct.CreateStageRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_certificate_id`](struct.CreateStageRequest.html#structfield.client_certificate_id) member.
+    pub fn client_certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_route_settings`](struct.CreateStageRequest.html#structfield.default_route_settings) member.
+    pub fn default_route_settings<T: Into<RouteSettings>>(mut self, value: T) -> Self {
+        self.default_route_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_id`](struct.CreateStageRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateStageRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_settings`](struct.CreateStageRequest.html#structfield.route_settings) member.
+    pub fn route_settings<T: Into<::std::collections::HashMap<String, RouteSettings>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.route_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.CreateStageRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_variables`](struct.CreateStageRequest.html#structfield.stage_variables) member.
+    pub fn stage_variables<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.stage_variables = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateStageRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateStageRequest, ::rusoto_core::BuildError> {
+        Ok(CreateStageRequest {
+            access_log_settings: self.access_log_settings,
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            client_certificate_id: self.client_certificate_id,
+            default_route_settings: self.default_route_settings,
+            deployment_id: self.deployment_id,
+            description: self.description,
+            route_settings: self.route_settings,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+            stage_variables: self.stage_variables,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateStageResponse {
@@ -1143,8 +2442,9 @@ pub struct CreateStageResponse {
     pub client_certificate_id: Option<String>,
     /// <p>The timestamp when the stage was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>Default route settings for the stage.</p>
     #[serde(rename = "DefaultRouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1160,8 +2460,9 @@ pub struct CreateStageResponse {
     pub description: Option<String>,
     /// <p>The timestamp when the stage was last updated.</p>
     #[serde(rename = "LastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>Route settings for the stage.</p>
     #[serde(rename = "RouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1192,6 +2493,48 @@ pub struct DeleteApiMappingRequest {
     pub domain_name: String,
 }
 
+impl DeleteApiMappingRequest {
+    /// Returns a builder for `DeleteApiMappingRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApiMappingRequestBuilder {
+        DeleteApiMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApiMappingRequest`](struct.DeleteApiMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApiMappingRequestBuilder {
+    api_mapping_id: Option<String>,
+    domain_name: Option<String>,
+}
+
+impl DeleteApiMappingRequestBuilder {
+    /// Sets the [`api_mapping_id`](struct.DeleteApiMappingRequest.html#structfield.api_mapping_id) member.
+    pub fn api_mapping_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_mapping_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DeleteApiMappingRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApiMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteApiMappingRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteApiMappingRequest {
+            api_mapping_id: self
+                .api_mapping_id
+                .ok_or(::rusoto_core::BuildError::missing("api_mapping_id"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteApiRequest {
     /// <p>The API identifier.</p>
@@ -1199,6 +2542,36 @@ pub struct DeleteApiRequest {
     pub api_id: String,
 }
 
+impl DeleteApiRequest {
+    /// Returns a builder for `DeleteApiRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApiRequestBuilder {
+        DeleteApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApiRequest`](struct.DeleteApiRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApiRequestBuilder {
+    api_id: Option<String>,
+}
+
+impl DeleteApiRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteApiRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApiRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteApiRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteApiRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteAuthorizerRequest {
     /// <p>The API identifier.</p>
@@ -1209,6 +2582,48 @@ pub struct DeleteAuthorizerRequest {
     pub authorizer_id: String,
 }
 
+impl DeleteAuthorizerRequest {
+    /// Returns a builder for `DeleteAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAuthorizerRequestBuilder {
+        DeleteAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAuthorizerRequest`](struct.DeleteAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAuthorizerRequestBuilder {
+    api_id: Option<String>,
+    authorizer_id: Option<String>,
+}
+
+impl DeleteAuthorizerRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteAuthorizerRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_id`](struct.DeleteAuthorizerRequest.html#structfield.authorizer_id) member.
+    pub fn authorizer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAuthorizerRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            authorizer_id: self
+                .authorizer_id
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDeploymentRequest {
     /// <p>The API identifier.</p>
@@ -1219,6 +2634,48 @@ pub struct DeleteDeploymentRequest {
     pub deployment_id: String,
 }
 
+impl DeleteDeploymentRequest {
+    /// Returns a builder for `DeleteDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDeploymentRequestBuilder {
+        DeleteDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDeploymentRequest`](struct.DeleteDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDeploymentRequestBuilder {
+    api_id: Option<String>,
+    deployment_id: Option<String>,
+}
+
+impl DeleteDeploymentRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteDeploymentRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_id`](struct.DeleteDeploymentRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDeploymentRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDomainNameRequest {
     /// <p>The domain name.</p>
@@ -1226,6 +2683,38 @@ pub struct DeleteDomainNameRequest {
     pub domain_name: String,
 }
 
+impl DeleteDomainNameRequest {
+    /// Returns a builder for `DeleteDomainNameRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDomainNameRequestBuilder {
+        DeleteDomainNameRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDomainNameRequest`](struct.DeleteDomainNameRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDomainNameRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl DeleteDomainNameRequestBuilder {
+    /// Sets the [`domain_name`](struct.DeleteDomainNameRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDomainNameRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDomainNameRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDomainNameRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteIntegrationRequest {
     /// <p>The API identifier.</p>
@@ -1236,6 +2725,48 @@ pub struct DeleteIntegrationRequest {
     pub integration_id: String,
 }
 
+impl DeleteIntegrationRequest {
+    /// Returns a builder for `DeleteIntegrationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIntegrationRequestBuilder {
+        DeleteIntegrationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIntegrationRequest`](struct.DeleteIntegrationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIntegrationRequestBuilder {
+    api_id: Option<String>,
+    integration_id: Option<String>,
+}
+
+impl DeleteIntegrationRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteIntegrationRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_id`](struct.DeleteIntegrationRequest.html#structfield.integration_id) member.
+    pub fn integration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIntegrationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteIntegrationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIntegrationRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            integration_id: self
+                .integration_id
+                .ok_or(::rusoto_core::BuildError::missing("integration_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteIntegrationResponseRequest {
     /// <p>The API identifier.</p>
@@ -1249,6 +2780,58 @@ pub struct DeleteIntegrationResponseRequest {
     pub integration_response_id: String,
 }
 
+impl DeleteIntegrationResponseRequest {
+    /// Returns a builder for `DeleteIntegrationResponseRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIntegrationResponseRequestBuilder {
+        DeleteIntegrationResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIntegrationResponseRequest`](struct.DeleteIntegrationResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIntegrationResponseRequestBuilder {
+    api_id: Option<String>,
+    integration_id: Option<String>,
+    integration_response_id: Option<String>,
+}
+
+impl DeleteIntegrationResponseRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteIntegrationResponseRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_id`](struct.DeleteIntegrationResponseRequest.html#structfield.integration_id) member.
+    pub fn integration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_response_id`](struct.DeleteIntegrationResponseRequest.html#structfield.integration_response_id) member.
+    pub fn integration_response_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_response_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIntegrationResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteIntegrationResponseRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIntegrationResponseRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            integration_id: self
+                .integration_id
+                .ok_or(::rusoto_core::BuildError::missing("integration_id"))?,
+            integration_response_id: self.integration_response_id.ok_or(
+                ::rusoto_core::BuildError::missing("integration_response_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteModelRequest {
     /// <p>The API identifier.</p>
@@ -1259,6 +2842,46 @@ pub struct DeleteModelRequest {
     pub model_id: String,
 }
 
+impl DeleteModelRequest {
+    /// Returns a builder for `DeleteModelRequest`, checking its required members when built.
+    pub fn builder() -> DeleteModelRequestBuilder {
+        DeleteModelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteModelRequest`](struct.DeleteModelRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteModelRequestBuilder {
+    api_id: Option<String>,
+    model_id: Option<String>,
+}
+
+impl DeleteModelRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteModelRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`model_id`](struct.DeleteModelRequest.html#structfield.model_id) member.
+    pub fn model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.model_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteModelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteModelRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteModelRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            model_id: self
+                .model_id
+                .ok_or(::rusoto_core::BuildError::missing("model_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteRouteRequest {
     /// <p>The API identifier.</p>
@@ -1269,6 +2892,46 @@ pub struct DeleteRouteRequest {
     pub route_id: String,
 }
 
+impl DeleteRouteRequest {
+    /// Returns a builder for `DeleteRouteRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRouteRequestBuilder {
+        DeleteRouteRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRouteRequest`](struct.DeleteRouteRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRouteRequestBuilder {
+    api_id: Option<String>,
+    route_id: Option<String>,
+}
+
+impl DeleteRouteRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteRouteRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_id`](struct.DeleteRouteRequest.html#structfield.route_id) member.
+    pub fn route_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRouteRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteRouteRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRouteRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            route_id: self
+                .route_id
+                .ok_or(::rusoto_core::BuildError::missing("route_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteRouteResponseRequest {
     /// <p>The API identifier.</p>
@@ -1282,14 +2945,106 @@ pub struct DeleteRouteResponseRequest {
     pub route_response_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DeleteStageRequest {
-    /// <p>The API identifier.</p>
-    #[serde(rename = "ApiId")]
-    pub api_id: String,
-    /// <p>The stage name.</p>
-    #[serde(rename = "StageName")]
-    pub stage_name: String,
+impl DeleteRouteResponseRequest {
+    /// Returns a builder for `DeleteRouteResponseRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRouteResponseRequestBuilder {
+        DeleteRouteResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRouteResponseRequest`](struct.DeleteRouteResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRouteResponseRequestBuilder {
+    api_id: Option<String>,
+    route_id: Option<String>,
+    route_response_id: Option<String>,
+}
+
+impl DeleteRouteResponseRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteRouteResponseRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_id`](struct.DeleteRouteResponseRequest.html#structfield.route_id) member.
+    pub fn route_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_response_id`](struct.DeleteRouteResponseRequest.html#structfield.route_response_id) member.
+    pub fn route_response_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_response_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRouteResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRouteResponseRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRouteResponseRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            route_id: self
+                .route_id
+                .ok_or(::rusoto_core::BuildError::missing("route_id"))?,
+            route_response_id: self
+                .route_response_id
+                .ok_or(::rusoto_core::BuildError::missing("route_response_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteStageRequest {
+    /// <p>The API identifier.</p>
+    #[serde(rename = "ApiId")]
+    pub api_id: String,
+    /// <p>The stage name.</p>
+    #[serde(rename = "StageName")]
+    pub stage_name: String,
+}
+
+impl DeleteStageRequest {
+    /// Returns a builder for `DeleteStageRequest`, checking its required members when built.
+    pub fn builder() -> DeleteStageRequestBuilder {
+        DeleteStageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStageRequest`](struct.DeleteStageRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteStageRequestBuilder {
+    api_id: Option<String>,
+    stage_name: Option<String>,
+}
+
+impl DeleteStageRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteStageRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.DeleteStageRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteStageRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteStageRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+        })
+    }
 }
 
 /// <p>An immutable representation of an API that can be called by users. A
@@ -1300,8 +3055,9 @@ pub struct DeleteStageRequest {
 pub struct Deployment {
     /// <p>The date and time when the Deployment resource was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The identifier for the deployment.</p>
     #[serde(rename = "DeploymentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1310,7 +3066,7 @@ pub struct Deployment {
     /// SUCCEEDED.</p>
     #[serde(rename = "DeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_status: Option<String>,
+    pub deployment_status: Option<DeploymentStatus>,
     /// <p>May contain additional feedback on the status of an API deployment.</p>
     #[serde(rename = "DeploymentStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1321,6 +3077,88 @@ pub struct Deployment {
     pub description: Option<String>,
 }
 
+/// <p>Represents a deployment status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentStatus {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>DEPLOYED</code></p>
+    Deployed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeploymentStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentStatus::Pending => "PENDING",
+            DeploymentStatus::Failed => "FAILED",
+            DeploymentStatus::Deployed => "DEPLOYED",
+            DeploymentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentStatus {
+    fn from(value: &'a str) -> DeploymentStatus {
+        match value {
+            "PENDING" => DeploymentStatus::Pending,
+            "FAILED" => DeploymentStatus::Failed,
+            "DEPLOYED" => DeploymentStatus::Deployed,
+            _ => DeploymentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentStatus {
+    fn from(value: String) -> DeploymentStatus {
+        DeploymentStatus::from(value.as_str())
+    }
+}
+
+impl From<DeploymentStatus> for String {
+    fn from(value: DeploymentStatus) -> String {
+        match value {
+            DeploymentStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeploymentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeploymentStatus::from(value))
+    }
+}
+
 /// <p>Represents a domain name.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1362,12 +3200,13 @@ pub struct DomainNameConfiguration {
     /// <p>The timestamp when the certificate that was used by edge-optimized endpoint for
     /// this domain name was uploaded.</p>
     #[serde(rename = "CertificateUploadDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_upload_date: Option<f64>,
+    pub certificate_upload_date: Option<EpochTimestamp>,
     /// <p>The status of the domain name migration. The valid values are AVAILABLE and UPDATING. If the status is UPDATING, the domain cannot be modified further until the existing operation is complete. If it is AVAILABLE, the domain can be updated.</p>
     #[serde(rename = "DomainNameStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_name_status: Option<String>,
+    pub domain_name_status: Option<DomainNameStatus>,
     /// <p>An optional text message containing detailed information about status of the domain name migration.</p>
     #[serde(rename = "DomainNameStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1375,7 +3214,7 @@ pub struct DomainNameConfiguration {
     /// <p>The endpoint type.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<EndpointType>,
     /// <p>The Amazon Route 53 Hosted Zone ID of the endpoint.</p>
     #[serde(rename = "HostedZoneId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1383,7 +3222,171 @@ pub struct DomainNameConfiguration {
     /// <p>The Transport Layer Security (TLS) version of the security policy for this domain name. The valid values are TLS_1_0 and TLS_1_2.</p>
     #[serde(rename = "SecurityPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_policy: Option<String>,
+    pub security_policy: Option<SecurityPolicy>,
+}
+
+/// <p>The status of the domain name migration. The valid values are AVAILABLE, UPDATING, PENDING_CERTIFICATE_REIMPORT, and PENDING_OWNERSHIP_VERIFICATION. If the status is UPDATING, the domain cannot be modified further until the existing operation is complete. If it is AVAILABLE, the domain can be updated.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DomainNameStatus {
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>PENDING_CERTIFICATE_REIMPORT</code></p>
+    PendingCertificateReimport,
+    /// <p><code>PENDING_OWNERSHIP_VERIFICATION</code></p>
+    PendingOwnershipVerification,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DomainNameStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainNameStatus::Available => "AVAILABLE",
+            DomainNameStatus::Updating => "UPDATING",
+            DomainNameStatus::PendingCertificateReimport => "PENDING_CERTIFICATE_REIMPORT",
+            DomainNameStatus::PendingOwnershipVerification => "PENDING_OWNERSHIP_VERIFICATION",
+            DomainNameStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DomainNameStatus {
+    fn from(value: &'a str) -> DomainNameStatus {
+        match value {
+            "AVAILABLE" => DomainNameStatus::Available,
+            "UPDATING" => DomainNameStatus::Updating,
+            "PENDING_CERTIFICATE_REIMPORT" => DomainNameStatus::PendingCertificateReimport,
+            "PENDING_OWNERSHIP_VERIFICATION" => DomainNameStatus::PendingOwnershipVerification,
+            _ => DomainNameStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainNameStatus {
+    fn from(value: String) -> DomainNameStatus {
+        DomainNameStatus::from(value.as_str())
+    }
+}
+
+impl From<DomainNameStatus> for String {
+    fn from(value: DomainNameStatus) -> String {
+        match value {
+            DomainNameStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DomainNameStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DomainNameStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DomainNameStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainNameStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DomainNameStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DomainNameStatus::from(value))
+    }
+}
+
+/// <p>Represents an endpoint type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EndpointType {
+    /// <p><code>REGIONAL</code></p>
+    Regional,
+    /// <p><code>EDGE</code></p>
+    Edge,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EndpointType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EndpointType::Regional => "REGIONAL",
+            EndpointType::Edge => "EDGE",
+            EndpointType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EndpointType {
+    fn from(value: &'a str) -> EndpointType {
+        match value {
+            "REGIONAL" => EndpointType::Regional,
+            "EDGE" => EndpointType::Edge,
+            _ => EndpointType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EndpointType {
+    fn from(value: String) -> EndpointType {
+        EndpointType::from(value.as_str())
+    }
+}
+
+impl From<EndpointType> for String {
+    fn from(value: EndpointType) -> String {
+        match value {
+            EndpointType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EndpointType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EndpointType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EndpointType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EndpointType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EndpointType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EndpointType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1396,6 +3399,46 @@ pub struct GetApiMappingRequest {
     pub domain_name: String,
 }
 
+impl GetApiMappingRequest {
+    /// Returns a builder for `GetApiMappingRequest`, checking its required members when built.
+    pub fn builder() -> GetApiMappingRequestBuilder {
+        GetApiMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetApiMappingRequest`](struct.GetApiMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct GetApiMappingRequestBuilder {
+    api_mapping_id: Option<String>,
+    domain_name: Option<String>,
+}
+
+impl GetApiMappingRequestBuilder {
+    /// Sets the [`api_mapping_id`](struct.GetApiMappingRequest.html#structfield.api_mapping_id) member.
+    pub fn api_mapping_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_mapping_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.GetApiMappingRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApiMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetApiMappingRequest, ::rusoto_core::BuildError> {
+        Ok(GetApiMappingRequest {
+            api_mapping_id: self
+                .api_mapping_id
+                .ok_or(::rusoto_core::BuildError::missing("api_mapping_id"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetApiMappingResponse {
@@ -1433,6 +3476,52 @@ pub struct GetApiMappingsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetApiMappingsRequest {
+    /// Returns a builder for `GetApiMappingsRequest`, checking its required members when built.
+    pub fn builder() -> GetApiMappingsRequestBuilder {
+        GetApiMappingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetApiMappingsRequest`](struct.GetApiMappingsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetApiMappingsRequestBuilder {
+    domain_name: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetApiMappingsRequestBuilder {
+    /// Sets the [`domain_name`](struct.GetApiMappingsRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetApiMappingsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetApiMappingsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApiMappingsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetApiMappingsRequest, ::rusoto_core::BuildError> {
+        Ok(GetApiMappingsRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetApiMappingsResponse {
@@ -1454,6 +3543,36 @@ pub struct GetApiRequest {
     pub api_id: String,
 }
 
+impl GetApiRequest {
+    /// Returns a builder for `GetApiRequest`, checking its required members when built.
+    pub fn builder() -> GetApiRequestBuilder {
+        GetApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetApiRequest`](struct.GetApiRequest.html).
+#[derive(Default, Debug)]
+pub struct GetApiRequestBuilder {
+    api_id: Option<String>,
+}
+
+impl GetApiRequestBuilder {
+    /// Sets the [`api_id`](struct.GetApiRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApiRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetApiRequest, ::rusoto_core::BuildError> {
+        Ok(GetApiRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetApiResponse {
@@ -1473,8 +3592,9 @@ pub struct GetApiResponse {
     pub api_key_selection_expression: Option<String>,
     /// <p>The timestamp when the API was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The description of the API.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1490,7 +3610,7 @@ pub struct GetApiResponse {
     /// <p>The API protocol: Currently only WEBSOCKET is supported.</p>
     #[serde(rename = "ProtocolType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol_type: Option<String>,
+    pub protocol_type: Option<ProtocolType>,
     /// <p>The route selection expression for the API.</p>
     #[serde(rename = "RouteSelectionExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1523,6 +3643,42 @@ pub struct GetApisRequest {
     pub next_token: Option<String>,
 }
 
+impl GetApisRequest {
+    /// Returns a builder for `GetApisRequest`, checking its required members when built.
+    pub fn builder() -> GetApisRequestBuilder {
+        GetApisRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetApisRequest`](struct.GetApisRequest.html).
+#[derive(Default, Debug)]
+pub struct GetApisRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetApisRequestBuilder {
+    /// Sets the [`max_results`](struct.GetApisRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetApisRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApisRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetApisRequest, ::rusoto_core::BuildError> {
+        Ok(GetApisRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetApisResponse {
@@ -1547,6 +3703,46 @@ pub struct GetAuthorizerRequest {
     pub authorizer_id: String,
 }
 
+impl GetAuthorizerRequest {
+    /// Returns a builder for `GetAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> GetAuthorizerRequestBuilder {
+        GetAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAuthorizerRequest`](struct.GetAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAuthorizerRequestBuilder {
+    api_id: Option<String>,
+    authorizer_id: Option<String>,
+}
+
+impl GetAuthorizerRequestBuilder {
+    /// Sets the [`api_id`](struct.GetAuthorizerRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_id`](struct.GetAuthorizerRequest.html#structfield.authorizer_id) member.
+    pub fn authorizer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(GetAuthorizerRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            authorizer_id: self
+                .authorizer_id
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAuthorizerResponse {
@@ -1572,7 +3768,7 @@ pub struct GetAuthorizerResponse {
     /// Lambda function using incoming request parameters.</p>
     #[serde(rename = "AuthorizerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_type: Option<String>,
+    pub authorizer_type: Option<AuthorizerType>,
     /// <p>The authorizer's Uniform Resource Identifier (URI).
     /// ForREQUEST authorizers, this must be a
     /// well-formed Lambda function URI, for example,
@@ -1637,6 +3833,52 @@ pub struct GetAuthorizersRequest {
     pub next_token: Option<String>,
 }
 
+impl GetAuthorizersRequest {
+    /// Returns a builder for `GetAuthorizersRequest`, checking its required members when built.
+    pub fn builder() -> GetAuthorizersRequestBuilder {
+        GetAuthorizersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAuthorizersRequest`](struct.GetAuthorizersRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAuthorizersRequestBuilder {
+    api_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetAuthorizersRequestBuilder {
+    /// Sets the [`api_id`](struct.GetAuthorizersRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetAuthorizersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetAuthorizersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAuthorizersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetAuthorizersRequest, ::rusoto_core::BuildError> {
+        Ok(GetAuthorizersRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAuthorizersResponse {
@@ -1661,13 +3903,54 @@ pub struct GetDeploymentRequest {
     pub deployment_id: String,
 }
 
+impl GetDeploymentRequest {
+    /// Returns a builder for `GetDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> GetDeploymentRequestBuilder {
+        GetDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeploymentRequest`](struct.GetDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDeploymentRequestBuilder {
+    api_id: Option<String>,
+    deployment_id: Option<String>,
+}
+
+impl GetDeploymentRequestBuilder {
+    /// Sets the [`api_id`](struct.GetDeploymentRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_id`](struct.GetDeploymentRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(GetDeploymentRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDeploymentResponse {
     /// <p>The date and time when the Deployment resource was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The identifier for the deployment.</p>
     #[serde(rename = "DeploymentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1676,7 +3959,7 @@ pub struct GetDeploymentResponse {
     /// SUCCEEDED.</p>
     #[serde(rename = "DeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_status: Option<String>,
+    pub deployment_status: Option<DeploymentStatus>,
     /// <p>May contain additional feedback on the status of an API deployment.</p>
     #[serde(rename = "DeploymentStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1703,6 +3986,52 @@ pub struct GetDeploymentsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetDeploymentsRequest {
+    /// Returns a builder for `GetDeploymentsRequest`, checking its required members when built.
+    pub fn builder() -> GetDeploymentsRequestBuilder {
+        GetDeploymentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeploymentsRequest`](struct.GetDeploymentsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDeploymentsRequestBuilder {
+    api_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetDeploymentsRequestBuilder {
+    /// Sets the [`api_id`](struct.GetDeploymentsRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetDeploymentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetDeploymentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeploymentsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDeploymentsRequest, ::rusoto_core::BuildError> {
+        Ok(GetDeploymentsRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDeploymentsResponse {
@@ -1724,13 +4053,43 @@ pub struct GetDomainNameRequest {
     pub domain_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetDomainNameResponse {
-    /// <p>The API mapping selection expression.</p>
-    #[serde(rename = "ApiMappingSelectionExpression")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub api_mapping_selection_expression: Option<String>,
+impl GetDomainNameRequest {
+    /// Returns a builder for `GetDomainNameRequest`, checking its required members when built.
+    pub fn builder() -> GetDomainNameRequestBuilder {
+        GetDomainNameRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDomainNameRequest`](struct.GetDomainNameRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDomainNameRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl GetDomainNameRequestBuilder {
+    /// Sets the [`domain_name`](struct.GetDomainNameRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDomainNameRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDomainNameRequest, ::rusoto_core::BuildError> {
+        Ok(GetDomainNameRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetDomainNameResponse {
+    /// <p>The API mapping selection expression.</p>
+    #[serde(rename = "ApiMappingSelectionExpression")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_mapping_selection_expression: Option<String>,
     /// <p>The name of the DomainName resource.</p>
     #[serde(rename = "DomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1758,6 +4117,42 @@ pub struct GetDomainNamesRequest {
     pub next_token: Option<String>,
 }
 
+impl GetDomainNamesRequest {
+    /// Returns a builder for `GetDomainNamesRequest`, checking its required members when built.
+    pub fn builder() -> GetDomainNamesRequestBuilder {
+        GetDomainNamesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDomainNamesRequest`](struct.GetDomainNamesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDomainNamesRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetDomainNamesRequestBuilder {
+    /// Sets the [`max_results`](struct.GetDomainNamesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetDomainNamesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDomainNamesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDomainNamesRequest, ::rusoto_core::BuildError> {
+        Ok(GetDomainNamesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDomainNamesResponse {
@@ -1782,6 +4177,46 @@ pub struct GetIntegrationRequest {
     pub integration_id: String,
 }
 
+impl GetIntegrationRequest {
+    /// Returns a builder for `GetIntegrationRequest`, checking its required members when built.
+    pub fn builder() -> GetIntegrationRequestBuilder {
+        GetIntegrationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIntegrationRequest`](struct.GetIntegrationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIntegrationRequestBuilder {
+    api_id: Option<String>,
+    integration_id: Option<String>,
+}
+
+impl GetIntegrationRequestBuilder {
+    /// Sets the [`api_id`](struct.GetIntegrationRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_id`](struct.GetIntegrationRequest.html#structfield.integration_id) member.
+    pub fn integration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIntegrationRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetIntegrationRequest, ::rusoto_core::BuildError> {
+        Ok(GetIntegrationRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            integration_id: self
+                .integration_id
+                .ok_or(::rusoto_core::BuildError::missing("integration_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetIntegrationResponse {
@@ -1917,6 +4352,58 @@ pub struct GetIntegrationResponseRequest {
     pub integration_response_id: String,
 }
 
+impl GetIntegrationResponseRequest {
+    /// Returns a builder for `GetIntegrationResponseRequest`, checking its required members when built.
+    pub fn builder() -> GetIntegrationResponseRequestBuilder {
+        GetIntegrationResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIntegrationResponseRequest`](struct.GetIntegrationResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIntegrationResponseRequestBuilder {
+    api_id: Option<String>,
+    integration_id: Option<String>,
+    integration_response_id: Option<String>,
+}
+
+impl GetIntegrationResponseRequestBuilder {
+    /// Sets the [`api_id`](struct.GetIntegrationResponseRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_id`](struct.GetIntegrationResponseRequest.html#structfield.integration_id) member.
+    pub fn integration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_response_id`](struct.GetIntegrationResponseRequest.html#structfield.integration_response_id) member.
+    pub fn integration_response_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_response_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIntegrationResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIntegrationResponseRequest, ::rusoto_core::BuildError> {
+        Ok(GetIntegrationResponseRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            integration_id: self
+                .integration_id
+                .ok_or(::rusoto_core::BuildError::missing("integration_id"))?,
+            integration_response_id: self.integration_response_id.ok_or(
+                ::rusoto_core::BuildError::missing("integration_response_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetIntegrationResponseResponse {
@@ -1931,7 +4418,7 @@ pub struct GetIntegrationResponseResponse {
     /// modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>The integration response ID.</p>
     #[serde(rename = "IntegrationResponseId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1983,6 +4470,64 @@ pub struct GetIntegrationResponsesRequest {
     pub next_token: Option<String>,
 }
 
+impl GetIntegrationResponsesRequest {
+    /// Returns a builder for `GetIntegrationResponsesRequest`, checking its required members when built.
+    pub fn builder() -> GetIntegrationResponsesRequestBuilder {
+        GetIntegrationResponsesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIntegrationResponsesRequest`](struct.GetIntegrationResponsesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIntegrationResponsesRequestBuilder {
+    api_id: Option<String>,
+    integration_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetIntegrationResponsesRequestBuilder {
+    /// Sets the [`api_id`](struct.GetIntegrationResponsesRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_id`](struct.GetIntegrationResponsesRequest.html#structfield.integration_id) member.
+    pub fn integration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetIntegrationResponsesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetIntegrationResponsesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIntegrationResponsesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIntegrationResponsesRequest, ::rusoto_core::BuildError> {
+        Ok(GetIntegrationResponsesRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            integration_id: self
+                .integration_id
+                .ok_or(::rusoto_core::BuildError::missing("integration_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetIntegrationResponsesResponse {
@@ -2013,6 +4558,52 @@ pub struct GetIntegrationsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetIntegrationsRequest {
+    /// Returns a builder for `GetIntegrationsRequest`, checking its required members when built.
+    pub fn builder() -> GetIntegrationsRequestBuilder {
+        GetIntegrationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIntegrationsRequest`](struct.GetIntegrationsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIntegrationsRequestBuilder {
+    api_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetIntegrationsRequestBuilder {
+    /// Sets the [`api_id`](struct.GetIntegrationsRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetIntegrationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetIntegrationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIntegrationsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetIntegrationsRequest, ::rusoto_core::BuildError> {
+        Ok(GetIntegrationsRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetIntegrationsResponse {
@@ -2037,6 +4628,46 @@ pub struct GetModelRequest {
     pub model_id: String,
 }
 
+impl GetModelRequest {
+    /// Returns a builder for `GetModelRequest`, checking its required members when built.
+    pub fn builder() -> GetModelRequestBuilder {
+        GetModelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetModelRequest`](struct.GetModelRequest.html).
+#[derive(Default, Debug)]
+pub struct GetModelRequestBuilder {
+    api_id: Option<String>,
+    model_id: Option<String>,
+}
+
+impl GetModelRequestBuilder {
+    /// Sets the [`api_id`](struct.GetModelRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`model_id`](struct.GetModelRequest.html#structfield.model_id) member.
+    pub fn model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.model_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetModelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetModelRequest, ::rusoto_core::BuildError> {
+        Ok(GetModelRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            model_id: self
+                .model_id
+                .ok_or(::rusoto_core::BuildError::missing("model_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetModelResponse {
@@ -2073,6 +4704,48 @@ pub struct GetModelTemplateRequest {
     pub model_id: String,
 }
 
+impl GetModelTemplateRequest {
+    /// Returns a builder for `GetModelTemplateRequest`, checking its required members when built.
+    pub fn builder() -> GetModelTemplateRequestBuilder {
+        GetModelTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetModelTemplateRequest`](struct.GetModelTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetModelTemplateRequestBuilder {
+    api_id: Option<String>,
+    model_id: Option<String>,
+}
+
+impl GetModelTemplateRequestBuilder {
+    /// Sets the [`api_id`](struct.GetModelTemplateRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`model_id`](struct.GetModelTemplateRequest.html#structfield.model_id) member.
+    pub fn model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.model_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetModelTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetModelTemplateRequest, ::rusoto_core::BuildError> {
+        Ok(GetModelTemplateRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            model_id: self
+                .model_id
+                .ok_or(::rusoto_core::BuildError::missing("model_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetModelTemplateResponse {
@@ -2098,6 +4771,52 @@ pub struct GetModelsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetModelsRequest {
+    /// Returns a builder for `GetModelsRequest`, checking its required members when built.
+    pub fn builder() -> GetModelsRequestBuilder {
+        GetModelsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetModelsRequest`](struct.GetModelsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetModelsRequestBuilder {
+    api_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetModelsRequestBuilder {
+    /// Sets the [`api_id`](struct.GetModelsRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetModelsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetModelsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetModelsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetModelsRequest, ::rusoto_core::BuildError> {
+        Ok(GetModelsRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetModelsResponse {
@@ -2122,6 +4841,46 @@ pub struct GetRouteRequest {
     pub route_id: String,
 }
 
+impl GetRouteRequest {
+    /// Returns a builder for `GetRouteRequest`, checking its required members when built.
+    pub fn builder() -> GetRouteRequestBuilder {
+        GetRouteRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRouteRequest`](struct.GetRouteRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRouteRequestBuilder {
+    api_id: Option<String>,
+    route_id: Option<String>,
+}
+
+impl GetRouteRequestBuilder {
+    /// Sets the [`api_id`](struct.GetRouteRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_id`](struct.GetRouteRequest.html#structfield.route_id) member.
+    pub fn route_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRouteRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRouteRequest, ::rusoto_core::BuildError> {
+        Ok(GetRouteRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            route_id: self
+                .route_id
+                .ok_or(::rusoto_core::BuildError::missing("route_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRouteResponse {
@@ -2200,6 +4959,58 @@ pub struct GetRouteResponseRequest {
     pub route_response_id: String,
 }
 
+impl GetRouteResponseRequest {
+    /// Returns a builder for `GetRouteResponseRequest`, checking its required members when built.
+    pub fn builder() -> GetRouteResponseRequestBuilder {
+        GetRouteResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRouteResponseRequest`](struct.GetRouteResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRouteResponseRequestBuilder {
+    api_id: Option<String>,
+    route_id: Option<String>,
+    route_response_id: Option<String>,
+}
+
+impl GetRouteResponseRequestBuilder {
+    /// Sets the [`api_id`](struct.GetRouteResponseRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_id`](struct.GetRouteResponseRequest.html#structfield.route_id) member.
+    pub fn route_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_response_id`](struct.GetRouteResponseRequest.html#structfield.route_response_id) member.
+    pub fn route_response_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_response_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRouteResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRouteResponseRequest, ::rusoto_core::BuildError> {
+        Ok(GetRouteResponseRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            route_id: self
+                .route_id
+                .ok_or(::rusoto_core::BuildError::missing("route_id"))?,
+            route_response_id: self
+                .route_response_id
+                .ok_or(::rusoto_core::BuildError::missing("route_response_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRouteResponseResponse {
@@ -2244,6 +5055,64 @@ pub struct GetRouteResponsesRequest {
     pub route_id: String,
 }
 
+impl GetRouteResponsesRequest {
+    /// Returns a builder for `GetRouteResponsesRequest`, checking its required members when built.
+    pub fn builder() -> GetRouteResponsesRequestBuilder {
+        GetRouteResponsesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRouteResponsesRequest`](struct.GetRouteResponsesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRouteResponsesRequestBuilder {
+    api_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+    route_id: Option<String>,
+}
+
+impl GetRouteResponsesRequestBuilder {
+    /// Sets the [`api_id`](struct.GetRouteResponsesRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetRouteResponsesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetRouteResponsesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_id`](struct.GetRouteResponsesRequest.html#structfield.route_id) member.
+    pub fn route_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRouteResponsesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRouteResponsesRequest, ::rusoto_core::BuildError> {
+        Ok(GetRouteResponsesRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            route_id: self
+                .route_id
+                .ok_or(::rusoto_core::BuildError::missing("route_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRouteResponsesResponse {
@@ -2274,6 +5143,52 @@ pub struct GetRoutesRequest {
     pub next_token: Option<String>,
 }
 
+impl GetRoutesRequest {
+    /// Returns a builder for `GetRoutesRequest`, checking its required members when built.
+    pub fn builder() -> GetRoutesRequestBuilder {
+        GetRoutesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRoutesRequest`](struct.GetRoutesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRoutesRequestBuilder {
+    api_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetRoutesRequestBuilder {
+    /// Sets the [`api_id`](struct.GetRoutesRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetRoutesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetRoutesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRoutesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRoutesRequest, ::rusoto_core::BuildError> {
+        Ok(GetRoutesRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRoutesResponse {
@@ -2298,6 +5213,46 @@ pub struct GetStageRequest {
     pub stage_name: String,
 }
 
+impl GetStageRequest {
+    /// Returns a builder for `GetStageRequest`, checking its required members when built.
+    pub fn builder() -> GetStageRequestBuilder {
+        GetStageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetStageRequest`](struct.GetStageRequest.html).
+#[derive(Default, Debug)]
+pub struct GetStageRequestBuilder {
+    api_id: Option<String>,
+    stage_name: Option<String>,
+}
+
+impl GetStageRequestBuilder {
+    /// Sets the [`api_id`](struct.GetStageRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.GetStageRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetStageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetStageRequest, ::rusoto_core::BuildError> {
+        Ok(GetStageRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetStageResponse {
@@ -2311,8 +5266,9 @@ pub struct GetStageResponse {
     pub client_certificate_id: Option<String>,
     /// <p>The timestamp when the stage was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>Default route settings for the stage.</p>
     #[serde(rename = "DefaultRouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2328,8 +5284,9 @@ pub struct GetStageResponse {
     pub description: Option<String>,
     /// <p>The timestamp when the stage was last updated.</p>
     #[serde(rename = "LastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>Route settings for the stage.</p>
     #[serde(rename = "RouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2366,6 +5323,52 @@ pub struct GetStagesRequest {
     pub next_token: Option<String>,
 }
 
+impl GetStagesRequest {
+    /// Returns a builder for `GetStagesRequest`, checking its required members when built.
+    pub fn builder() -> GetStagesRequestBuilder {
+        GetStagesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetStagesRequest`](struct.GetStagesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetStagesRequestBuilder {
+    api_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetStagesRequestBuilder {
+    /// Sets the [`api_id`](struct.GetStagesRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetStagesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetStagesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetStagesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetStagesRequest, ::rusoto_core::BuildError> {
+        Ok(GetStagesRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetStagesResponse {
@@ -2386,6 +5389,36 @@ pub struct GetTagsRequest {
     pub resource_arn: String,
 }
 
+impl GetTagsRequest {
+    /// Returns a builder for `GetTagsRequest`, checking its required members when built.
+    pub fn builder() -> GetTagsRequestBuilder {
+        GetTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTagsRequest`](struct.GetTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTagsRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl GetTagsRequestBuilder {
+    /// Sets the [`resource_arn`](struct.GetTagsRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTagsRequest, ::rusoto_core::BuildError> {
+        Ok(GetTagsRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTagsResponse {
@@ -2407,7 +5440,7 @@ pub struct Integration {
     /// internet.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Specifies how to handle response payload content type conversions. Supported
     /// values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the
     /// following behaviors:</p><p>
@@ -2419,7 +5452,7 @@ pub struct Integration {
     /// modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>Specifies the credentials required for the integration, if any. For AWS
     /// integrations, three options are available. To specify an IAM Role for API Gateway to
     /// assume, use the role's Amazon Resource Name (ARN). To require that the caller's
@@ -2464,7 +5497,7 @@ pub struct Integration {
     /// "loopback" endpoint without invoking any backend.</p>
     #[serde(rename = "IntegrationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub integration_type: Option<String>,
+    pub integration_type: Option<IntegrationType>,
     /// <p>For a Lambda proxy integration, this is the URI of the Lambda function.</p>
     #[serde(rename = "IntegrationUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2485,7 +5518,7 @@ pub struct Integration {
     /// Unsupported Media Type response.</p>
     #[serde(rename = "PassthroughBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub passthrough_behavior: Option<String>,
+    pub passthrough_behavior: Option<PassthroughBehavior>,
     /// <p>A key-value map specifying request parameters that are passed from the method
     /// request to the backend. The key is an integration request parameter name and the
     /// associated value is a method request parameter value or static value that must be
@@ -2532,7 +5565,7 @@ pub struct IntegrationResponse {
     /// modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>The integration response ID.</p>
     #[serde(rename = "IntegrationResponseId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2564,25 +5597,197 @@ pub struct IntegrationResponse {
     pub template_selection_expression: Option<String>,
 }
 
-/// <p>Represents a data model for an API. See <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/models-mappings.html">Create Models and Mapping Templates for Request and Response
-/// Mappings</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Model {
-    /// <p>The content-type for the model, for example, "application/json".</p>
-    #[serde(rename = "ContentType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_type: Option<String>,
-    /// <p>The description of the model.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The model identifier.</p>
-    #[serde(rename = "ModelId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub model_id: Option<String>,
-    /// <p>The name of the model. Must be alphanumeric.</p>
-    #[serde(rename = "Name")]
+/// <p>Represents an API method integration type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IntegrationType {
+    /// <p><code>AWS</code></p>
+    Aws,
+    /// <p><code>HTTP</code></p>
+    Http,
+    /// <p><code>MOCK</code></p>
+    Mock,
+    /// <p><code>HTTP_PROXY</code></p>
+    HttpProxy,
+    /// <p><code>AWS_PROXY</code></p>
+    AwsProxy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IntegrationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IntegrationType::Aws => "AWS",
+            IntegrationType::Http => "HTTP",
+            IntegrationType::Mock => "MOCK",
+            IntegrationType::HttpProxy => "HTTP_PROXY",
+            IntegrationType::AwsProxy => "AWS_PROXY",
+            IntegrationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IntegrationType {
+    fn from(value: &'a str) -> IntegrationType {
+        match value {
+            "AWS" => IntegrationType::Aws,
+            "HTTP" => IntegrationType::Http,
+            "MOCK" => IntegrationType::Mock,
+            "HTTP_PROXY" => IntegrationType::HttpProxy,
+            "AWS_PROXY" => IntegrationType::AwsProxy,
+            _ => IntegrationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IntegrationType {
+    fn from(value: String) -> IntegrationType {
+        IntegrationType::from(value.as_str())
+    }
+}
+
+impl From<IntegrationType> for String {
+    fn from(value: IntegrationType) -> String {
+        match value {
+            IntegrationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IntegrationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IntegrationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IntegrationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IntegrationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IntegrationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IntegrationType::from(value))
+    }
+}
+
+/// <p>The logging level.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoggingLevel {
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// <p><code>INFO</code></p>
+    Info,
+    /// <p><code>OFF</code></p>
+    Off,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoggingLevel {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoggingLevel::Error => "ERROR",
+            LoggingLevel::Info => "INFO",
+            LoggingLevel::Off => "OFF",
+            LoggingLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoggingLevel {
+    fn from(value: &'a str) -> LoggingLevel {
+        match value {
+            "ERROR" => LoggingLevel::Error,
+            "INFO" => LoggingLevel::Info,
+            "OFF" => LoggingLevel::Off,
+            _ => LoggingLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoggingLevel {
+    fn from(value: String) -> LoggingLevel {
+        LoggingLevel::from(value.as_str())
+    }
+}
+
+impl From<LoggingLevel> for String {
+    fn from(value: LoggingLevel) -> String {
+        match value {
+            LoggingLevel::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoggingLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoggingLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoggingLevel {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoggingLevel {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LoggingLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoggingLevel::from(value))
+    }
+}
+
+/// <p>Represents a data model for an API. See <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/models-mappings.html">Create Models and Mapping Templates for Request and Response
+/// Mappings</a>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Model {
+    /// <p>The content-type for the model, for example, "application/json".</p>
+    #[serde(rename = "ContentType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// <p>The description of the model.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The model identifier.</p>
+    #[serde(rename = "ModelId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<String>,
+    /// <p>The name of the model. Must be alphanumeric.</p>
+    #[serde(rename = "Name")]
     pub name: String,
     /// <p>The schema for the model. For application/json models, this should be JSON schema
     /// draft 4 model.</p>
@@ -2601,6 +5806,166 @@ pub struct ParameterConstraints {
     pub required: Option<bool>,
 }
 
+/// <p>Represents passthrough behavior for an integration response. Supported only for WebSocket APIs.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PassthroughBehavior {
+    /// <p><code>WHEN_NO_MATCH</code></p>
+    WhenNoMatch,
+    /// <p><code>NEVER</code></p>
+    Never,
+    /// <p><code>WHEN_NO_TEMPLATES</code></p>
+    WhenNoTemplates,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PassthroughBehavior {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PassthroughBehavior::WhenNoMatch => "WHEN_NO_MATCH",
+            PassthroughBehavior::Never => "NEVER",
+            PassthroughBehavior::WhenNoTemplates => "WHEN_NO_TEMPLATES",
+            PassthroughBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PassthroughBehavior {
+    fn from(value: &'a str) -> PassthroughBehavior {
+        match value {
+            "WHEN_NO_MATCH" => PassthroughBehavior::WhenNoMatch,
+            "NEVER" => PassthroughBehavior::Never,
+            "WHEN_NO_TEMPLATES" => PassthroughBehavior::WhenNoTemplates,
+            _ => PassthroughBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PassthroughBehavior {
+    fn from(value: String) -> PassthroughBehavior {
+        PassthroughBehavior::from(value.as_str())
+    }
+}
+
+impl From<PassthroughBehavior> for String {
+    fn from(value: PassthroughBehavior) -> String {
+        match value {
+            PassthroughBehavior::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PassthroughBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PassthroughBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PassthroughBehavior {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PassthroughBehavior {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PassthroughBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PassthroughBehavior::from(value))
+    }
+}
+
+/// Represents a protocol type.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProtocolType {
+    /// <p><code>WEBSOCKET</code></p>
+    Websocket,
+    /// <p><code>HTTP</code></p>
+    Http,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProtocolType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProtocolType::Websocket => "WEBSOCKET",
+            ProtocolType::Http => "HTTP",
+            ProtocolType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProtocolType {
+    fn from(value: &'a str) -> ProtocolType {
+        match value {
+            "WEBSOCKET" => ProtocolType::Websocket,
+            "HTTP" => ProtocolType::Http,
+            _ => ProtocolType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProtocolType {
+    fn from(value: String) -> ProtocolType {
+        ProtocolType::from(value.as_str())
+    }
+}
+
+impl From<ProtocolType> for String {
+    fn from(value: ProtocolType) -> String {
+        match value {
+            ProtocolType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProtocolType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProtocolType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProtocolType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProtocolType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProtocolType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProtocolType::from(value))
+    }
+}
+
 /// <p>Represents a route.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2625,7 +5990,7 @@ pub struct Route {
     /// authorizer</p>
     #[serde(rename = "AuthorizationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorization_type: Option<String>,
+    pub authorization_type: Option<AuthorizationType>,
     /// <p>The identifier of the Authorizer resource to be associated with this
     /// route, if the authorizationType is CUSTOM
     /// . The authorizer identifier is generated by API Gateway
@@ -2709,7 +6074,7 @@ pub struct RouteSettings {
     /// CloudWatch Logs.</p>
     #[serde(rename = "LoggingLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub logging_level: Option<String>,
+    pub logging_level: Option<LoggingLevel>,
     /// <p>Specifies the throttling burst limit.</p>
     #[serde(rename = "ThrottlingBurstLimit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2720,6 +6085,84 @@ pub struct RouteSettings {
     pub throttling_rate_limit: Option<f64>,
 }
 
+/// <p>The Transport Layer Security (TLS) version of the security policy for this domain name. The valid values are TLS_1_0 and TLS_1_2.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SecurityPolicy {
+    /// <p><code>TLS_1_0</code></p>
+    Tls10,
+    /// <p><code>TLS_1_2</code></p>
+    Tls12,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SecurityPolicy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SecurityPolicy::Tls10 => "TLS_1_0",
+            SecurityPolicy::Tls12 => "TLS_1_2",
+            SecurityPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SecurityPolicy {
+    fn from(value: &'a str) -> SecurityPolicy {
+        match value {
+            "TLS_1_0" => SecurityPolicy::Tls10,
+            "TLS_1_2" => SecurityPolicy::Tls12,
+            _ => SecurityPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SecurityPolicy {
+    fn from(value: String) -> SecurityPolicy {
+        SecurityPolicy::from(value.as_str())
+    }
+}
+
+impl From<SecurityPolicy> for String {
+    fn from(value: SecurityPolicy) -> String {
+        match value {
+            SecurityPolicy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SecurityPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SecurityPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SecurityPolicy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SecurityPolicy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SecurityPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SecurityPolicy::from(value))
+    }
+}
+
 /// <p>Represents an API stage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2734,8 +6177,9 @@ pub struct Stage {
     pub client_certificate_id: Option<String>,
     /// <p>The timestamp when the stage was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>Default route settings for the stage.</p>
     #[serde(rename = "DefaultRouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2751,8 +6195,9 @@ pub struct Stage {
     pub description: Option<String>,
     /// <p>The timestamp when the stage was last updated.</p>
     #[serde(rename = "LastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>Route settings for the stage.</p>
     #[serde(rename = "RouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2783,6 +6228,44 @@ pub struct TagResourceRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -2797,6 +6280,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateApiMappingRequest {
     /// <p>The API identifier.</p>
@@ -2818,6 +6341,74 @@ pub struct UpdateApiMappingRequest {
     pub stage: Option<String>,
 }
 
+impl UpdateApiMappingRequest {
+    /// Returns a builder for `UpdateApiMappingRequest`, checking its required members when built.
+    pub fn builder() -> UpdateApiMappingRequestBuilder {
+        UpdateApiMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateApiMappingRequest`](struct.UpdateApiMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateApiMappingRequestBuilder {
+    api_id: Option<String>,
+    api_mapping_id: Option<String>,
+    api_mapping_key: Option<String>,
+    domain_name: Option<String>,
+    stage: Option<String>,
+}
+
+impl UpdateApiMappingRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateApiMappingRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`api_mapping_id`](struct.UpdateApiMappingRequest.html#structfield.api_mapping_id) member.
+    pub fn api_mapping_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_mapping_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`api_mapping_key`](struct.UpdateApiMappingRequest.html#structfield.api_mapping_key) member.
+    pub fn api_mapping_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_mapping_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.UpdateApiMappingRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage`](struct.UpdateApiMappingRequest.html#structfield.stage) member.
+    pub fn stage<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateApiMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateApiMappingRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateApiMappingRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            api_mapping_id: self
+                .api_mapping_id
+                .ok_or(::rusoto_core::BuildError::missing("api_mapping_id"))?,
+            api_mapping_key: self.api_mapping_key,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            stage: self.stage,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateApiMappingResponse {
@@ -2870,27 +6461,106 @@ pub struct UpdateApiRequest {
     pub version: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateApiResponse {
-    /// <p>The URI of the API, of the form {api-id}.execute-api.{region}.amazonaws.com. The
-    /// stage name is typically appended to this URI to form a complete path to a deployed
-    /// API stage.</p>
-    #[serde(rename = "ApiEndpoint")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub api_endpoint: Option<String>,
-    /// <p>The API ID.</p>
-    #[serde(rename = "ApiId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub api_id: Option<String>,
-    /// <p>An API key selection expression. See <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-websocket-api-selection-expressions.html#apigateway-websocket-api-apikey-selection-expressions">API Key Selection Expressions</a>.</p>
-    #[serde(rename = "ApiKeySelectionExpression")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub api_key_selection_expression: Option<String>,
-    /// <p>The timestamp when the API was created.</p>
+impl UpdateApiRequest {
+    /// Returns a builder for `UpdateApiRequest`, checking its required members when built.
+    pub fn builder() -> UpdateApiRequestBuilder {
+        UpdateApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateApiRequest`](struct.UpdateApiRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateApiRequestBuilder {
+    api_id: Option<String>,
+    api_key_selection_expression: Option<String>,
+    description: Option<String>,
+    disable_schema_validation: Option<bool>,
+    name: Option<String>,
+    route_selection_expression: Option<String>,
+    version: Option<String>,
+}
+
+impl UpdateApiRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateApiRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`api_key_selection_expression`](struct.UpdateApiRequest.html#structfield.api_key_selection_expression) member.
+    pub fn api_key_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_key_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateApiRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disable_schema_validation`](struct.UpdateApiRequest.html#structfield.disable_schema_validation) member.
+    pub fn disable_schema_validation<T: Into<bool>>(mut self, value: T) -> Self {
+        self.disable_schema_validation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateApiRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_selection_expression`](struct.UpdateApiRequest.html#structfield.route_selection_expression) member.
+    pub fn route_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.UpdateApiRequest.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateApiRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateApiRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateApiRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            api_key_selection_expression: self.api_key_selection_expression,
+            description: self.description,
+            disable_schema_validation: self.disable_schema_validation,
+            name: self.name,
+            route_selection_expression: self.route_selection_expression,
+            version: self.version,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateApiResponse {
+    /// <p>The URI of the API, of the form {api-id}.execute-api.{region}.amazonaws.com. The
+    /// stage name is typically appended to this URI to form a complete path to a deployed
+    /// API stage.</p>
+    #[serde(rename = "ApiEndpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_endpoint: Option<String>,
+    /// <p>The API ID.</p>
+    #[serde(rename = "ApiId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_id: Option<String>,
+    /// <p>An API key selection expression. See <a href="https://docs.aws.amazon.com/apigateway/latest/developerguide/apigateway-websocket-api-selection-expressions.html#apigateway-websocket-api-apikey-selection-expressions">API Key Selection Expressions</a>.</p>
+    #[serde(rename = "ApiKeySelectionExpression")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key_selection_expression: Option<String>,
+    /// <p>The timestamp when the API was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The description of the API.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2906,7 +6576,7 @@ pub struct UpdateApiResponse {
     /// <p>The API protocol: Currently only WEBSOCKET is supported.</p>
     #[serde(rename = "ProtocolType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol_type: Option<String>,
+    pub protocol_type: Option<ProtocolType>,
     /// <p>The route selection expression for the API.</p>
     #[serde(rename = "RouteSelectionExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2952,7 +6622,7 @@ pub struct UpdateAuthorizerRequest {
     /// Lambda function using incoming request parameters.</p>
     #[serde(rename = "AuthorizerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_type: Option<String>,
+    pub authorizer_type: Option<AuthorizerType>,
     /// <p>The authorizer's Uniform Resource Identifier (URI). For
     /// REQUEST authorizers, this must be a
     /// well-formed Lambda function URI, for example,
@@ -3000,6 +6670,112 @@ pub struct UpdateAuthorizerRequest {
     pub provider_arns: Option<Vec<String>>,
 }
 
+impl UpdateAuthorizerRequest {
+    /// Returns a builder for `UpdateAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAuthorizerRequestBuilder {
+        UpdateAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAuthorizerRequest`](struct.UpdateAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAuthorizerRequestBuilder {
+    api_id: Option<String>,
+    authorizer_credentials_arn: Option<String>,
+    authorizer_id: Option<String>,
+    authorizer_result_ttl_in_seconds: Option<i64>,
+    authorizer_type: Option<AuthorizerType>,
+    authorizer_uri: Option<String>,
+    identity_source: Option<Vec<String>>,
+    identity_validation_expression: Option<String>,
+    name: Option<String>,
+    provider_arns: Option<Vec<String>>,
+}
+
+impl UpdateAuthorizerRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateAuthorizerRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_credentials_arn`](struct.UpdateAuthorizerRequest.html#structfield.authorizer_credentials_arn) member.
+    pub fn authorizer_credentials_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_credentials_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_id`](struct.UpdateAuthorizerRequest.html#structfield.authorizer_id) member.
+    pub fn authorizer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_result_ttl_in_seconds`](struct.UpdateAuthorizerRequest.html#structfield.authorizer_result_ttl_in_seconds) member.
+    pub fn authorizer_result_ttl_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.authorizer_result_ttl_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_type`](struct.UpdateAuthorizerRequest.html#structfield.authorizer_type) member.
+    pub fn authorizer_type<T: Into<AuthorizerType>>(mut self, value: T) -> Self {
+        self.authorizer_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_uri`](struct.UpdateAuthorizerRequest.html#structfield.authorizer_uri) member.
+    pub fn authorizer_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_source`](struct.UpdateAuthorizerRequest.html#structfield.identity_source) member.
+    pub fn identity_source<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.identity_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_validation_expression`](struct.UpdateAuthorizerRequest.html#structfield.identity_validation_expression) member.
+    pub fn identity_validation_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_validation_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateAuthorizerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_arns`](struct.UpdateAuthorizerRequest.html#structfield.provider_arns) member.
+    pub fn provider_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.provider_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAuthorizerRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            authorizer_credentials_arn: self.authorizer_credentials_arn,
+            authorizer_id: self
+                .authorizer_id
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_id"))?,
+            authorizer_result_ttl_in_seconds: self.authorizer_result_ttl_in_seconds,
+            authorizer_type: self.authorizer_type,
+            authorizer_uri: self.authorizer_uri,
+            identity_source: self.identity_source,
+            identity_validation_expression: self.identity_validation_expression,
+            name: self.name,
+            provider_arns: self.provider_arns,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateAuthorizerResponse {
@@ -3025,7 +6801,7 @@ pub struct UpdateAuthorizerResponse {
     /// Lambda function using incoming request parameters.</p>
     #[serde(rename = "AuthorizerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_type: Option<String>,
+    pub authorizer_type: Option<AuthorizerType>,
     /// <p>The authorizer's Uniform Resource Identifier (URI).
     /// ForREQUEST authorizers, this must be a
     /// well-formed Lambda function URI, for example,
@@ -3088,13 +6864,64 @@ pub struct UpdateDeploymentRequest {
     pub description: Option<String>,
 }
 
+impl UpdateDeploymentRequest {
+    /// Returns a builder for `UpdateDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDeploymentRequestBuilder {
+        UpdateDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDeploymentRequest`](struct.UpdateDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDeploymentRequestBuilder {
+    api_id: Option<String>,
+    deployment_id: Option<String>,
+    description: Option<String>,
+}
+
+impl UpdateDeploymentRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateDeploymentRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_id`](struct.UpdateDeploymentRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateDeploymentRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDeploymentRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+            description: self.description,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDeploymentResponse {
     /// <p>The date and time when the Deployment resource was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>The identifier for the deployment.</p>
     #[serde(rename = "DeploymentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3103,7 +6930,7 @@ pub struct UpdateDeploymentResponse {
     /// SUCCEEDED.</p>
     #[serde(rename = "DeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_status: Option<String>,
+    pub deployment_status: Option<DeploymentStatus>,
     /// <p>May contain additional feedback on the status of an API deployment.</p>
     #[serde(rename = "DeploymentStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3125,6 +6952,49 @@ pub struct UpdateDomainNameRequest {
     pub domain_name_configurations: Option<Vec<DomainNameConfiguration>>,
 }
 
+impl UpdateDomainNameRequest {
+    /// Returns a builder for `UpdateDomainNameRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDomainNameRequestBuilder {
+        UpdateDomainNameRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDomainNameRequest`](struct.UpdateDomainNameRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDomainNameRequestBuilder {
+    domain_name: Option<String>,
+    domain_name_configurations: Option<Vec<DomainNameConfiguration>>,
+}
+
+impl UpdateDomainNameRequestBuilder {
+    /// Sets the [`domain_name`](struct.UpdateDomainNameRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name_configurations`](struct.UpdateDomainNameRequest.html#structfield.domain_name_configurations) member.
+    pub fn domain_name_configurations<T: Into<Vec<DomainNameConfiguration>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.domain_name_configurations = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDomainNameRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDomainNameRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDomainNameRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            domain_name_configurations: self.domain_name_configurations,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDomainNameResponse {
@@ -3160,7 +7030,7 @@ pub struct UpdateIntegrationRequest {
     /// internet.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>Specifies how to handle response payload content type conversions. Supported
     /// values are CONVERT_TO_BINARY and CONVERT_TO_TEXT, with the
     /// following behaviors:</p><p>
@@ -3172,7 +7042,7 @@ pub struct UpdateIntegrationRequest {
     /// modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>Specifies the credentials required for the integration, if any. For AWS
     /// integrations, three options are available. To specify an IAM Role for API Gateway to
     /// assume, use the role's Amazon Resource Name (ARN). To require that the caller's
@@ -3212,7 +7082,7 @@ pub struct UpdateIntegrationRequest {
     /// "loopback" endpoint without invoking any backend.</p>
     #[serde(rename = "IntegrationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub integration_type: Option<String>,
+    pub integration_type: Option<IntegrationType>,
     /// <p>For a Lambda proxy integration, this is the URI of the Lambda function.</p>
     #[serde(rename = "IntegrationUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3233,7 +7103,7 @@ pub struct UpdateIntegrationRequest {
     /// Unsupported Media Type response.</p>
     #[serde(rename = "PassthroughBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub passthrough_behavior: Option<String>,
+    pub passthrough_behavior: Option<PassthroughBehavior>,
     /// <p>A key-value map specifying request parameters that are passed from the method
     /// request to the backend. The key is an integration request parameter name and the
     /// associated value is a method request parameter value or static value that must be
@@ -3265,6 +7135,158 @@ pub struct UpdateIntegrationRequest {
     pub timeout_in_millis: Option<i64>,
 }
 
+impl UpdateIntegrationRequest {
+    /// Returns a builder for `UpdateIntegrationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateIntegrationRequestBuilder {
+        UpdateIntegrationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateIntegrationRequest`](struct.UpdateIntegrationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateIntegrationRequestBuilder {
+    api_id: Option<String>,
+    connection_id: Option<String>,
+    connection_type: Option<ConnectionType>,
+    content_handling_strategy: Option<ContentHandlingStrategy>,
+    credentials_arn: Option<String>,
+    description: Option<String>,
+    integration_id: Option<String>,
+    integration_method: Option<String>,
+    integration_type: Option<IntegrationType>,
+    integration_uri: Option<String>,
+    passthrough_behavior: Option<PassthroughBehavior>,
+    request_parameters: Option<::std::collections::HashMap<String, String>>,
+    request_templates: Option<::std::collections::HashMap<String, String>>,
+    template_selection_expression: Option<String>,
+    timeout_in_millis: Option<i64>,
+}
+
+impl UpdateIntegrationRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateIntegrationRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_id`](struct.UpdateIntegrationRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_type`](struct.UpdateIntegrationRequest.html#structfield.connection_type) member.
+    pub fn connection_type<T: Into<ConnectionType>>(mut self, value: T) -> Self {
+        self.connection_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_handling_strategy`](struct.UpdateIntegrationRequest.html#structfield.content_handling_strategy) member.
+    pub fn content_handling_strategy<T: Into<ContentHandlingStrategy>>(mut self, value: T) -> Self {
+        self.content_handling_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`credentials_arn`](struct.UpdateIntegrationRequest.html#structfield.credentials_arn) member.
+    pub fn credentials_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.credentials_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateIntegrationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_id`](struct.UpdateIntegrationRequest.html#structfield.integration_id) member.
+    pub fn integration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_method`](struct.UpdateIntegrationRequest.html#structfield.integration_method) member.
+    pub fn integration_method<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_type`](struct.UpdateIntegrationRequest.html#structfield.integration_type) member.
+    pub fn integration_type<T: Into<IntegrationType>>(mut self, value: T) -> Self {
+        self.integration_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_uri`](struct.UpdateIntegrationRequest.html#structfield.integration_uri) member.
+    pub fn integration_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`passthrough_behavior`](struct.UpdateIntegrationRequest.html#structfield.passthrough_behavior) member.
+    pub fn passthrough_behavior<T: Into<PassthroughBehavior>>(mut self, value: T) -> Self {
+        self.passthrough_behavior = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_parameters`](struct.UpdateIntegrationRequest.html#structfield.request_parameters) member.
+    pub fn request_parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_templates`](struct.UpdateIntegrationRequest.html#structfield.request_templates) member.
+    pub fn request_templates<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_templates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_selection_expression`](struct.UpdateIntegrationRequest.html#structfield.template_selection_expression) member.
+    pub fn template_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout_in_millis`](struct.UpdateIntegrationRequest.html#structfield.timeout_in_millis) member.
+    pub fn timeout_in_millis<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout_in_millis = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateIntegrationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateIntegrationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateIntegrationRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            connection_id: self.connection_id,
+            connection_type: self.connection_type,
+            content_handling_strategy: self.content_handling_strategy,
+            credentials_arn: self.credentials_arn,
+            description: self.description,
+            integration_id: self
+                .integration_id
+                .ok_or(::rusoto_core::BuildError::missing("integration_id"))?,
+            integration_method: self.integration_method,
+            integration_type: self.integration_type,
+            integration_uri: self.integration_uri,
+            passthrough_behavior: self.passthrough_behavior,
+            request_parameters: self.request_parameters,
+            request_templates: self.request_templates,
+            template_selection_expression: self.template_selection_expression,
+            timeout_in_millis: self.timeout_in_millis,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateIntegrationResponse {
@@ -3403,7 +7425,7 @@ pub struct UpdateIntegrationResponseRequest {
     /// modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>The integration ID.</p>
     #[serde(rename = "IntegrationId")]
     pub integration_id: String,
@@ -3445,6 +7467,104 @@ pub struct UpdateIntegrationResponseRequest {
     pub template_selection_expression: Option<String>,
 }
 
+impl UpdateIntegrationResponseRequest {
+    /// Returns a builder for `UpdateIntegrationResponseRequest`, checking its required members when built.
+    pub fn builder() -> UpdateIntegrationResponseRequestBuilder {
+        UpdateIntegrationResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateIntegrationResponseRequest`](struct.UpdateIntegrationResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateIntegrationResponseRequestBuilder {
+    api_id: Option<String>,
+    content_handling_strategy: Option<ContentHandlingStrategy>,
+    integration_id: Option<String>,
+    integration_response_id: Option<String>,
+    integration_response_key: Option<String>,
+    response_parameters: Option<::std::collections::HashMap<String, String>>,
+    response_templates: Option<::std::collections::HashMap<String, String>>,
+    template_selection_expression: Option<String>,
+}
+
+impl UpdateIntegrationResponseRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateIntegrationResponseRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_handling_strategy`](struct.UpdateIntegrationResponseRequest.html#structfield.content_handling_strategy) member.
+    pub fn content_handling_strategy<T: Into<ContentHandlingStrategy>>(mut self, value: T) -> Self {
+        self.content_handling_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_id`](struct.UpdateIntegrationResponseRequest.html#structfield.integration_id) member.
+    pub fn integration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_response_id`](struct.UpdateIntegrationResponseRequest.html#structfield.integration_response_id) member.
+    pub fn integration_response_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_response_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`integration_response_key`](struct.UpdateIntegrationResponseRequest.html#structfield.integration_response_key) member.
+    pub fn integration_response_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.integration_response_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_parameters`](struct.UpdateIntegrationResponseRequest.html#structfield.response_parameters) member.
+    pub fn response_parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_templates`](struct.UpdateIntegrationResponseRequest.html#structfield.response_templates) member.
+    pub fn response_templates<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_templates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_selection_expression`](struct.UpdateIntegrationResponseRequest.html#structfield.template_selection_expression) member.
+    pub fn template_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateIntegrationResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateIntegrationResponseRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateIntegrationResponseRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            content_handling_strategy: self.content_handling_strategy,
+            integration_id: self
+                .integration_id
+                .ok_or(::rusoto_core::BuildError::missing("integration_id"))?,
+            integration_response_id: self.integration_response_id.ok_or(
+                ::rusoto_core::BuildError::missing("integration_response_id"),
+            )?,
+            integration_response_key: self.integration_response_key,
+            response_parameters: self.response_parameters,
+            response_templates: self.response_templates,
+            template_selection_expression: self.template_selection_expression,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateIntegrationResponseResponse {
@@ -3459,7 +7579,7 @@ pub struct UpdateIntegrationResponseResponse {
     /// modification.</p>
     #[serde(rename = "ContentHandlingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_handling_strategy: Option<String>,
+    pub content_handling_strategy: Option<ContentHandlingStrategy>,
     /// <p>The integration response ID.</p>
     #[serde(rename = "IntegrationResponseId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3519,6 +7639,78 @@ pub struct UpdateModelRequest {
     pub schema: Option<String>,
 }
 
+impl UpdateModelRequest {
+    /// Returns a builder for `UpdateModelRequest`, checking its required members when built.
+    pub fn builder() -> UpdateModelRequestBuilder {
+        UpdateModelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateModelRequest`](struct.UpdateModelRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateModelRequestBuilder {
+    api_id: Option<String>,
+    content_type: Option<String>,
+    description: Option<String>,
+    model_id: Option<String>,
+    name: Option<String>,
+    schema: Option<String>,
+}
+
+impl UpdateModelRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateModelRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_type`](struct.UpdateModelRequest.html#structfield.content_type) member.
+    pub fn content_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateModelRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`model_id`](struct.UpdateModelRequest.html#structfield.model_id) member.
+    pub fn model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.model_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateModelRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema`](struct.UpdateModelRequest.html#structfield.schema) member.
+    pub fn schema<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateModelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateModelRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateModelRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            content_type: self.content_type,
+            description: self.description,
+            model_id: self
+                .model_id
+                .ok_or(::rusoto_core::BuildError::missing("model_id"))?,
+            name: self.name,
+            schema: self.schema,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateModelResponse {
@@ -3565,7 +7757,7 @@ pub struct UpdateRouteRequest {
     /// authorizer.</p>
     #[serde(rename = "AuthorizationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorization_type: Option<String>,
+    pub authorization_type: Option<AuthorizationType>,
     /// <p>The identifier of the Authorizer resource to be associated with this
     /// route, if the authorizationType is CUSTOM
     /// . The authorizer identifier is generated by API Gateway
@@ -3606,6 +7798,142 @@ pub struct UpdateRouteRequest {
     pub target: Option<String>,
 }
 
+impl UpdateRouteRequest {
+    /// Returns a builder for `UpdateRouteRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRouteRequestBuilder {
+        UpdateRouteRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRouteRequest`](struct.UpdateRouteRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRouteRequestBuilder {
+    api_id: Option<String>,
+    api_key_required: Option<bool>,
+    authorization_scopes: Option<Vec<String>>,
+    authorization_type: Option<AuthorizationType>,
+    authorizer_id: Option<String>,
+    model_selection_expression: Option<String>,
+    operation_name: Option<String>,
+    request_models: Option<::std::collections::HashMap<String, String>>,
+    request_parameters: Option<::std::collections::HashMap<String, ParameterConstraints>>,
+    route_id: Option<String>,
+    route_key: Option<String>,
+    route_response_selection_expression: Option<String>,
+    target: Option<String>,
+}
+
+impl UpdateRouteRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateRouteRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`api_key_required`](struct.UpdateRouteRequest.html#structfield.api_key_required) member.
+    pub fn api_key_required<T: Into<bool>>(mut self, value: T) -> Self {
+        self.api_key_required = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorization_scopes`](struct.UpdateRouteRequest.html#structfield.authorization_scopes) member.
+    pub fn authorization_scopes<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.authorization_scopes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorization_type`](struct.UpdateRouteRequest.html#structfield.authorization_type) member.
+    pub fn authorization_type<T: Into<AuthorizationType>>(mut self, value: T) -> Self {
+        self.authorization_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_id`](struct.UpdateRouteRequest.html#structfield.authorizer_id) member.
+    pub fn authorizer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`model_selection_expression`](struct.UpdateRouteRequest.html#structfield.model_selection_expression) member.
+    pub fn model_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.model_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation_name`](struct.UpdateRouteRequest.html#structfield.operation_name) member.
+    pub fn operation_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_models`](struct.UpdateRouteRequest.html#structfield.request_models) member.
+    pub fn request_models<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_models = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_parameters`](struct.UpdateRouteRequest.html#structfield.request_parameters) member.
+    pub fn request_parameters<
+        T: Into<::std::collections::HashMap<String, ParameterConstraints>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_id`](struct.UpdateRouteRequest.html#structfield.route_id) member.
+    pub fn route_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_key`](struct.UpdateRouteRequest.html#structfield.route_key) member.
+    pub fn route_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_response_selection_expression`](struct.UpdateRouteRequest.html#structfield.route_response_selection_expression) member.
+    pub fn route_response_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_response_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target`](struct.UpdateRouteRequest.html#structfield.target) member.
+    pub fn target<T: Into<String>>(mut self, value: T) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRouteRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateRouteRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRouteRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            api_key_required: self.api_key_required,
+            authorization_scopes: self.authorization_scopes,
+            authorization_type: self.authorization_type,
+            authorizer_id: self.authorizer_id,
+            model_selection_expression: self.model_selection_expression,
+            operation_name: self.operation_name,
+            request_models: self.request_models,
+            request_parameters: self.request_parameters,
+            route_id: self
+                .route_id
+                .ok_or(::rusoto_core::BuildError::missing("route_id"))?,
+            route_key: self.route_key,
+            route_response_selection_expression: self.route_response_selection_expression,
+            target: self.target,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRouteResponse {
@@ -3700,6 +8028,98 @@ pub struct UpdateRouteResponseRequest {
     pub route_response_key: Option<String>,
 }
 
+impl UpdateRouteResponseRequest {
+    /// Returns a builder for `UpdateRouteResponseRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRouteResponseRequestBuilder {
+        UpdateRouteResponseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRouteResponseRequest`](struct.UpdateRouteResponseRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRouteResponseRequestBuilder {
+    api_id: Option<String>,
+    model_selection_expression: Option<String>,
+    response_models: Option<::std::collections::HashMap<String, String>>,
+    response_parameters: Option<::std::collections::HashMap<String, ParameterConstraints>>,
+    route_id: Option<String>,
+    route_response_id: Option<String>,
+    route_response_key: Option<String>,
+}
+
+impl UpdateRouteResponseRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateRouteResponseRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`model_selection_expression`](struct.UpdateRouteResponseRequest.html#structfield.model_selection_expression) member.
+    pub fn model_selection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.model_selection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_models`](struct.UpdateRouteResponseRequest.html#structfield.response_models) member.
+    pub fn response_models<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_models = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_parameters`](struct.UpdateRouteResponseRequest.html#structfield.response_parameters) member.
+    pub fn response_parameters<
+        T: Into<::std::collections::HashMap<String, ParameterConstraints>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.response_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_id`](struct.UpdateRouteResponseRequest.html#structfield.route_id) member.
+    pub fn route_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_response_id`](struct.UpdateRouteResponseRequest.html#structfield.route_response_id) member.
+    pub fn route_response_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_response_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_response_key`](struct.UpdateRouteResponseRequest.html#structfield.route_response_key) member.
+    pub fn route_response_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_response_key = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRouteResponseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateRouteResponseRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRouteResponseRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            model_selection_expression: self.model_selection_expression,
+            response_models: self.response_models,
+            response_parameters: self.response_parameters,
+            route_id: self
+                .route_id
+                .ok_or(::rusoto_core::BuildError::missing("route_id"))?,
+            route_response_id: self
+                .route_response_id
+                .ok_or(::rusoto_core::BuildError::missing("route_response_id"))?,
+            route_response_key: self.route_response_key,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRouteResponseResponse {
@@ -3765,6 +8185,108 @@ pub struct UpdateStageRequest {
     pub stage_variables: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl UpdateStageRequest {
+    /// Returns a builder for `UpdateStageRequest`, checking its required members when built.
+    pub fn builder() -> UpdateStageRequestBuilder {
+        UpdateStageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateStageRequest`](struct.UpdateStageRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateStageRequestBuilder {
+    access_log_settings: Option<AccessLogSettings>,
+    api_id: Option<String>,
+    client_certificate_id: Option<String>,
+    default_route_settings: Option<RouteSettings>,
+    deployment_id: Option<String>,
+    description: Option<String>,
+    route_settings: Option<::std::collections::HashMap<String, RouteSettings>>,
+    stage_name: Option<String>,
+    stage_variables: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl UpdateStageRequestBuilder {
+    /// Sets the [`access_log_settings`](struct.UpdateStageRequest.html#structfield.access_log_settings) member.
+    pub fn access_log_settings<T: Into<AccessLogSettings>>(mut self, value: T) -> Self {
+        self.access_log_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`api_id`](struct.UpdateStageRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_certificate_id`](struct.UpdateStageRequest.html#structfield.client_certificate_id) member.
+    pub fn client_certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_route_settings`](struct.UpdateStageRequest.html#structfield.default_route_settings) member.
+    pub fn default_route_settings<T: Into<RouteSettings>>(mut self, value: T) -> Self {
+        self.default_route_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_id`](struct.UpdateStageRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateStageRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_settings`](struct.UpdateStageRequest.html#structfield.route_settings) member.
+    pub fn route_settings<T: Into<::std::collections::HashMap<String, RouteSettings>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.route_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.UpdateStageRequest.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_variables`](struct.UpdateStageRequest.html#structfield.stage_variables) member.
+    pub fn stage_variables<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.stage_variables = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateStageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateStageRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateStageRequest {
+            access_log_settings: self.access_log_settings,
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            client_certificate_id: self.client_certificate_id,
+            default_route_settings: self.default_route_settings,
+            deployment_id: self.deployment_id,
+            description: self.description,
+            route_settings: self.route_settings,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+            stage_variables: self.stage_variables,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateStageResponse {
@@ -3778,8 +8300,9 @@ pub struct UpdateStageResponse {
     pub client_certificate_id: Option<String>,
     /// <p>The timestamp when the stage was created.</p>
     #[serde(rename = "CreatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>Default route settings for the stage.</p>
     #[serde(rename = "DefaultRouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3795,8 +8318,9 @@ pub struct UpdateStageResponse {
     pub description: Option<String>,
     /// <p>The timestamp when the stage was last updated.</p>
     #[serde(rename = "LastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>Route settings for the stage.</p>
     #[serde(rename = "RouteSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6978,40 +11502,438 @@ pub trait ApiGatewayV2 {
         input: UpdateStageRequest,
     ) -> RusotoFuture<UpdateStageResponse, UpdateStageError>;
 }
-/// A client for the AmazonApiGatewayV2 API.
-#[derive(Clone)]
-pub struct ApiGatewayV2Client {
-    client: Client,
-    region: region::Region,
-}
-
-impl ApiGatewayV2Client {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ApiGatewayV2Client {
-        Self::new_with_client(Client::shared(), region)
+impl<T: ?Sized + ApiGatewayV2> ApiGatewayV2 for ::std::sync::Arc<T> {
+    fn create_api(
+        &self,
+        input: CreateApiRequest,
+    ) -> RusotoFuture<CreateApiResponse, CreateApiError> {
+        (**self).create_api(input)
     }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> ApiGatewayV2Client
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
-    {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
+    fn create_api_mapping(
+        &self,
+        input: CreateApiMappingRequest,
+    ) -> RusotoFuture<CreateApiMappingResponse, CreateApiMappingError> {
+        (**self).create_api_mapping(input)
+    }
+    fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> RusotoFuture<CreateAuthorizerResponse, CreateAuthorizerError> {
+        (**self).create_authorizer(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResponse, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> RusotoFuture<CreateDomainNameResponse, CreateDomainNameError> {
+        (**self).create_domain_name(input)
+    }
+    fn create_integration(
+        &self,
+        input: CreateIntegrationRequest,
+    ) -> RusotoFuture<CreateIntegrationResponse, CreateIntegrationError> {
+        (**self).create_integration(input)
+    }
+    fn create_integration_response(
+        &self,
+        input: CreateIntegrationResponseRequest,
+    ) -> RusotoFuture<CreateIntegrationResponseResponse, CreateIntegrationResponseError> {
+        (**self).create_integration_response(input)
+    }
+    fn create_model(
+        &self,
+        input: CreateModelRequest,
+    ) -> RusotoFuture<CreateModelResponse, CreateModelError> {
+        (**self).create_model(input)
+    }
+    fn create_route(
+        &self,
+        input: CreateRouteRequest,
+    ) -> RusotoFuture<CreateRouteResponse, CreateRouteError> {
+        (**self).create_route(input)
+    }
+    fn create_route_response(
+        &self,
+        input: CreateRouteResponseRequest,
+    ) -> RusotoFuture<CreateRouteResponseResponse, CreateRouteResponseError> {
+        (**self).create_route_response(input)
+    }
+    fn create_stage(
+        &self,
+        input: CreateStageRequest,
+    ) -> RusotoFuture<CreateStageResponse, CreateStageError> {
+        (**self).create_stage(input)
+    }
+    fn delete_api(&self, input: DeleteApiRequest) -> RusotoFuture<(), DeleteApiError> {
+        (**self).delete_api(input)
+    }
+    fn delete_api_mapping(
+        &self,
+        input: DeleteApiMappingRequest,
+    ) -> RusotoFuture<(), DeleteApiMappingError> {
+        (**self).delete_api_mapping(input)
+    }
+    fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> RusotoFuture<(), DeleteAuthorizerError> {
+        (**self).delete_authorizer(input)
+    }
+    fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> RusotoFuture<(), DeleteDeploymentError> {
+        (**self).delete_deployment(input)
+    }
+    fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> RusotoFuture<(), DeleteDomainNameError> {
+        (**self).delete_domain_name(input)
+    }
+    fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationError> {
+        (**self).delete_integration(input)
+    }
+    fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> RusotoFuture<(), DeleteIntegrationResponseError> {
+        (**self).delete_integration_response(input)
+    }
+    fn delete_model(&self, input: DeleteModelRequest) -> RusotoFuture<(), DeleteModelError> {
+        (**self).delete_model(input)
+    }
+    fn delete_route(&self, input: DeleteRouteRequest) -> RusotoFuture<(), DeleteRouteError> {
+        (**self).delete_route(input)
+    }
+    fn delete_route_response(
+        &self,
+        input: DeleteRouteResponseRequest,
+    ) -> RusotoFuture<(), DeleteRouteResponseError> {
+        (**self).delete_route_response(input)
+    }
+    fn delete_stage(&self, input: DeleteStageRequest) -> RusotoFuture<(), DeleteStageError> {
+        (**self).delete_stage(input)
+    }
+    fn get_api(&self, input: GetApiRequest) -> RusotoFuture<GetApiResponse, GetApiError> {
+        (**self).get_api(input)
+    }
+    fn get_api_mapping(
+        &self,
+        input: GetApiMappingRequest,
+    ) -> RusotoFuture<GetApiMappingResponse, GetApiMappingError> {
+        (**self).get_api_mapping(input)
+    }
+    fn get_api_mappings(
+        &self,
+        input: GetApiMappingsRequest,
+    ) -> RusotoFuture<GetApiMappingsResponse, GetApiMappingsError> {
+        (**self).get_api_mappings(input)
+    }
+    fn get_apis(&self, input: GetApisRequest) -> RusotoFuture<GetApisResponse, GetApisError> {
+        (**self).get_apis(input)
+    }
+    fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> RusotoFuture<GetAuthorizerResponse, GetAuthorizerError> {
+        (**self).get_authorizer(input)
+    }
+    fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> RusotoFuture<GetAuthorizersResponse, GetAuthorizersError> {
+        (**self).get_authorizers(input)
+    }
+    fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> RusotoFuture<GetDeploymentResponse, GetDeploymentError> {
+        (**self).get_deployment(input)
+    }
+    fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> RusotoFuture<GetDeploymentsResponse, GetDeploymentsError> {
+        (**self).get_deployments(input)
+    }
+    fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> RusotoFuture<GetDomainNameResponse, GetDomainNameError> {
+        (**self).get_domain_name(input)
+    }
+    fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> RusotoFuture<GetDomainNamesResponse, GetDomainNamesError> {
+        (**self).get_domain_names(input)
+    }
+    fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> RusotoFuture<GetIntegrationResponse, GetIntegrationError> {
+        (**self).get_integration(input)
+    }
+    fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> RusotoFuture<GetIntegrationResponseResponse, GetIntegrationResponseError> {
+        (**self).get_integration_response(input)
+    }
+    fn get_integration_responses(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> RusotoFuture<GetIntegrationResponsesResponse, GetIntegrationResponsesError> {
+        (**self).get_integration_responses(input)
+    }
+    fn get_integrations(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> RusotoFuture<GetIntegrationsResponse, GetIntegrationsError> {
+        (**self).get_integrations(input)
+    }
+    fn get_model(&self, input: GetModelRequest) -> RusotoFuture<GetModelResponse, GetModelError> {
+        (**self).get_model(input)
+    }
+    fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> RusotoFuture<GetModelTemplateResponse, GetModelTemplateError> {
+        (**self).get_model_template(input)
+    }
+    fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> RusotoFuture<GetModelsResponse, GetModelsError> {
+        (**self).get_models(input)
+    }
+    fn get_route(&self, input: GetRouteRequest) -> RusotoFuture<GetRouteResponse, GetRouteError> {
+        (**self).get_route(input)
+    }
+    fn get_route_response(
+        &self,
+        input: GetRouteResponseRequest,
+    ) -> RusotoFuture<GetRouteResponseResponse, GetRouteResponseError> {
+        (**self).get_route_response(input)
+    }
+    fn get_route_responses(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> RusotoFuture<GetRouteResponsesResponse, GetRouteResponsesError> {
+        (**self).get_route_responses(input)
+    }
+    fn get_routes(
+        &self,
+        input: GetRoutesRequest,
+    ) -> RusotoFuture<GetRoutesResponse, GetRoutesError> {
+        (**self).get_routes(input)
+    }
+    fn get_stage(&self, input: GetStageRequest) -> RusotoFuture<GetStageResponse, GetStageError> {
+        (**self).get_stage(input)
+    }
+    fn get_stages(
+        &self,
+        input: GetStagesRequest,
+    ) -> RusotoFuture<GetStagesResponse, GetStagesError> {
+        (**self).get_stages(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_api(
+        &self,
+        input: UpdateApiRequest,
+    ) -> RusotoFuture<UpdateApiResponse, UpdateApiError> {
+        (**self).update_api(input)
+    }
+    fn update_api_mapping(
+        &self,
+        input: UpdateApiMappingRequest,
+    ) -> RusotoFuture<UpdateApiMappingResponse, UpdateApiMappingError> {
+        (**self).update_api_mapping(input)
+    }
+    fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> RusotoFuture<UpdateAuthorizerResponse, UpdateAuthorizerError> {
+        (**self).update_authorizer(input)
+    }
+    fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> RusotoFuture<UpdateDeploymentResponse, UpdateDeploymentError> {
+        (**self).update_deployment(input)
+    }
+    fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> RusotoFuture<UpdateDomainNameResponse, UpdateDomainNameError> {
+        (**self).update_domain_name(input)
+    }
+    fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> RusotoFuture<UpdateIntegrationResponse, UpdateIntegrationError> {
+        (**self).update_integration(input)
+    }
+    fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> RusotoFuture<UpdateIntegrationResponseResponse, UpdateIntegrationResponseError> {
+        (**self).update_integration_response(input)
+    }
+    fn update_model(
+        &self,
+        input: UpdateModelRequest,
+    ) -> RusotoFuture<UpdateModelResponse, UpdateModelError> {
+        (**self).update_model(input)
+    }
+    fn update_route(
+        &self,
+        input: UpdateRouteRequest,
+    ) -> RusotoFuture<UpdateRouteResponse, UpdateRouteError> {
+        (**self).update_route(input)
+    }
+    fn update_route_response(
+        &self,
+        input: UpdateRouteResponseRequest,
+    ) -> RusotoFuture<UpdateRouteResponseResponse, UpdateRouteResponseError> {
+        (**self).update_route_response(input)
+    }
+    fn update_stage(
+        &self,
+        input: UpdateStageRequest,
+    ) -> RusotoFuture<UpdateStageResponse, UpdateStageError> {
+        (**self).update_stage(input)
+    }
+}
+/// The error codes AmazonApiGatewayV2 retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
+/// A client for the AmazonApiGatewayV2 API.
+#[derive(Clone)]
+pub struct ApiGatewayV2Client {
+    client: Client,
+    region: region::Region,
+}
+
+impl ApiGatewayV2Client {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ApiGatewayV2Client {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_APIGATEWAYV2`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ApiGatewayV2Client {
+        Self::new(region::Region::from_env_for_service("APIGATEWAYV2"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `apigateway`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ApiGatewayV2Client {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("apigateway", "APIGATEWAYV2"),
+        )
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApiGatewayV2Client
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
             region,
         )
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayV2Client {
-        ApiGatewayV2Client { client, region }
+        ApiGatewayV2Client {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "apigateway")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "apigateway",
+            "apigateway",
+            "APIGATEWAYV2",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ApiGatewayV2` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ApiGatewayV2 + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ApiGatewayV2Client {
+    fn service_name(&self) -> &'static str {
+        "AmazonApiGatewayV2"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "apigateway"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ApiGatewayV2Client {
+        Self::new_with_client(client, region)
     }
 }
 
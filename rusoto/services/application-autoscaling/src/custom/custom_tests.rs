@@ -5,6 +5,18 @@ use crate::generated::*;
 use self::rusoto_mock::*;
 use rusoto_core::Region;
 
+fn register_scalable_target_request() -> RegisterScalableTargetRequest {
+    RegisterScalableTargetRequest {
+        max_capacity: Some(10),
+        min_capacity: Some(1),
+        resource_id: "table/my-table".to_owned(),
+        role_arn: None,
+        scalable_dimension: ScalableDimension::DynamodbTableReadCapacityUnits,
+        service_namespace: ServiceNamespace::Dynamodb,
+        suspended_state: None,
+    }
+}
+
 #[test]
 // regression test for #1002
 fn register_scalable_target_happy_path() {
@@ -13,7 +25,9 @@ fn register_scalable_target_happy_path() {
 
     let client =
         ApplicationAutoScalingClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
-    let result = client.register_scalable_target(Default::default()).sync();
+    let result = client
+        .register_scalable_target(register_scalable_target_request())
+        .sync();
 
     result.expect("Couldn't parse register_scalable_target");
 }
@@ -26,7 +40,9 @@ fn register_scalable_target_returs_empty_body() {
 
     let client =
         ApplicationAutoScalingClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
-    let result = client.register_scalable_target(Default::default()).sync();
+    let result = client
+        .register_scalable_target(register_scalable_target_request())
+        .sync();
 
     result.expect("Couldn't parse register_scalable_target");
 }
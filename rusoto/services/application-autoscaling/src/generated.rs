@@ -22,7 +22,84 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>Specifies whether the <code>ScalingAdjustment</code> value in a <a>StepAdjustment</a> is an absolute number or a percentage of the current capacity. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AdjustmentType {
+    /// <p><code>ChangeInCapacity</code></p>
+    ChangeInCapacity,
+    /// <p><code>PercentChangeInCapacity</code></p>
+    PercentChangeInCapacity,
+    /// <p><code>ExactCapacity</code></p>
+    ExactCapacity,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AdjustmentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdjustmentType::ChangeInCapacity => "ChangeInCapacity",
+            AdjustmentType::PercentChangeInCapacity => "PercentChangeInCapacity",
+            AdjustmentType::ExactCapacity => "ExactCapacity",
+            AdjustmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AdjustmentType {
+    fn from(value: &'a str) -> AdjustmentType {
+        match value {
+            "ChangeInCapacity" => AdjustmentType::ChangeInCapacity,
+            "PercentChangeInCapacity" => AdjustmentType::PercentChangeInCapacity,
+            "ExactCapacity" => AdjustmentType::ExactCapacity,
+            _ => AdjustmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdjustmentType {
+    fn from(value: String) -> AdjustmentType {
+        AdjustmentType::from(value.as_str())
+    }
+}
+
+impl From<AdjustmentType> for String {
+    fn from(value: AdjustmentType) -> String {
+        match value {
+            AdjustmentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AdjustmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AdjustmentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdjustmentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AdjustmentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AdjustmentType::from(value))
+    }
+}
+
 /// <p>Represents a CloudWatch alarm associated with a scaling policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -36,7 +113,7 @@ pub struct Alarm {
 }
 
 /// <p>Represents a CloudWatch metric of your choosing for a target tracking scaling policy to use with Application Auto Scaling.</p> <p>To create your customized metric specification:</p> <ul> <li> <p>Add values for each required parameter from CloudWatch. You can use an existing metric, or a new metric that you create. To use your own metric, you must first publish the metric to CloudWatch. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html">Publish Custom Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p> </li> <li> <p>Choose a metric that changes proportionally with capacity. The value of the metric should increase or decrease in inverse proportion to the number of capacity units. That is, the value of the metric should decrease when capacity increases. </p> </li> </ul> <p>For more information about CloudWatch, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/cloudwatch_concepts.html">Amazon CloudWatch Concepts</a>. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CustomizedMetricSpecification {
     /// <p>The dimensions of the metric. </p> <p>Conditional: If you published your metric with dimensions, you must specify the same dimensions in your scaling policy.</p>
     #[serde(rename = "Dimensions")]
@@ -50,14 +127,14 @@ pub struct CustomizedMetricSpecification {
     pub namespace: String,
     /// <p>The statistic of the metric.</p>
     #[serde(rename = "Statistic")]
-    pub statistic: String,
+    pub statistic: MetricStatistic,
     /// <p>The unit of the metric.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unit: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteScalingPolicyRequest {
     /// <p>The name of the scaling policy.</p>
     #[serde(rename = "PolicyName")]
@@ -67,54 +144,230 @@ pub struct DeleteScalingPolicyRequest {
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
+}
+
+impl DeleteScalingPolicyRequest {
+    /// Returns a builder for `DeleteScalingPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteScalingPolicyRequestBuilder {
+        DeleteScalingPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteScalingPolicyRequest`](struct.DeleteScalingPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteScalingPolicyRequestBuilder {
+    policy_name: Option<String>,
+    resource_id: Option<String>,
+    scalable_dimension: Option<ScalableDimension>,
+    service_namespace: Option<ServiceNamespace>,
+}
+
+impl DeleteScalingPolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.DeleteScalingPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DeleteScalingPolicyRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scalable_dimension`](struct.DeleteScalingPolicyRequest.html#structfield.scalable_dimension) member.
+    pub fn scalable_dimension<T: Into<ScalableDimension>>(mut self, value: T) -> Self {
+        self.scalable_dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespace`](struct.DeleteScalingPolicyRequest.html#structfield.service_namespace) member.
+    pub fn service_namespace<T: Into<ServiceNamespace>>(mut self, value: T) -> Self {
+        self.service_namespace = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteScalingPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteScalingPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteScalingPolicyRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            scalable_dimension: self
+                .scalable_dimension
+                .ok_or(::rusoto_core::BuildError::missing("scalable_dimension"))?,
+            service_namespace: self
+                .service_namespace
+                .ok_or(::rusoto_core::BuildError::missing("service_namespace"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteScalingPolicyResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteScheduledActionRequest {
     /// <p><p>The identifier of the resource associated with the scheduled action. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variants - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The name of the scheduled action.</p>
     #[serde(rename = "ScheduledActionName")]
     pub scheduled_action_name: String,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
+}
+
+impl DeleteScheduledActionRequest {
+    /// Returns a builder for `DeleteScheduledActionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteScheduledActionRequestBuilder {
+        DeleteScheduledActionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteScheduledActionRequest`](struct.DeleteScheduledActionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteScheduledActionRequestBuilder {
+    resource_id: Option<String>,
+    scalable_dimension: Option<ScalableDimension>,
+    scheduled_action_name: Option<String>,
+    service_namespace: Option<ServiceNamespace>,
+}
+
+impl DeleteScheduledActionRequestBuilder {
+    /// Sets the [`resource_id`](struct.DeleteScheduledActionRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scalable_dimension`](struct.DeleteScheduledActionRequest.html#structfield.scalable_dimension) member.
+    pub fn scalable_dimension<T: Into<ScalableDimension>>(mut self, value: T) -> Self {
+        self.scalable_dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduled_action_name`](struct.DeleteScheduledActionRequest.html#structfield.scheduled_action_name) member.
+    pub fn scheduled_action_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scheduled_action_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespace`](struct.DeleteScheduledActionRequest.html#structfield.service_namespace) member.
+    pub fn service_namespace<T: Into<ServiceNamespace>>(mut self, value: T) -> Self {
+        self.service_namespace = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteScheduledActionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteScheduledActionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteScheduledActionRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            scalable_dimension: self
+                .scalable_dimension
+                .ok_or(::rusoto_core::BuildError::missing("scalable_dimension"))?,
+            scheduled_action_name: self
+                .scheduled_action_name
+                .ok_or(::rusoto_core::BuildError::missing("scheduled_action_name"))?,
+            service_namespace: self
+                .service_namespace
+                .ok_or(::rusoto_core::BuildError::missing("service_namespace"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteScheduledActionResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DeregisterScalableTargetRequest {
     /// <p><p>The identifier of the resource associated with the scalable target. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variants - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension associated with the scalable target. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
+}
+
+impl DeregisterScalableTargetRequest {
+    /// Returns a builder for `DeregisterScalableTargetRequest`, checking its required members when built.
+    pub fn builder() -> DeregisterScalableTargetRequestBuilder {
+        DeregisterScalableTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterScalableTargetRequest`](struct.DeregisterScalableTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeregisterScalableTargetRequestBuilder {
+    resource_id: Option<String>,
+    scalable_dimension: Option<ScalableDimension>,
+    service_namespace: Option<ServiceNamespace>,
+}
+
+impl DeregisterScalableTargetRequestBuilder {
+    /// Sets the [`resource_id`](struct.DeregisterScalableTargetRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scalable_dimension`](struct.DeregisterScalableTargetRequest.html#structfield.scalable_dimension) member.
+    pub fn scalable_dimension<T: Into<ScalableDimension>>(mut self, value: T) -> Self {
+        self.scalable_dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespace`](struct.DeregisterScalableTargetRequest.html#structfield.service_namespace) member.
+    pub fn service_namespace<T: Into<ServiceNamespace>>(mut self, value: T) -> Self {
+        self.service_namespace = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterScalableTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterScalableTargetRequest, ::rusoto_core::BuildError> {
+        Ok(DeregisterScalableTargetRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            scalable_dimension: self
+                .scalable_dimension
+                .ok_or(::rusoto_core::BuildError::missing("scalable_dimension"))?,
+            service_namespace: self
+                .service_namespace
+                .ok_or(::rusoto_core::BuildError::missing("service_namespace"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeregisterScalableTargetResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeScalableTargetsRequest {
     /// <p>The maximum number of scalable targets. This value can be between 1 and 50. The default value is 50.</p> <p>If this parameter is used, the operation returns up to <code>MaxResults</code> results at a time, along with a <code>NextToken</code> value. To get the next set of results, include the <code>NextToken</code> value in a subsequent call. If this parameter is not used, the operation returns up to 50 results and a <code>NextToken</code> value, if applicable.</p>
     #[serde(rename = "MaxResults")]
@@ -131,10 +384,74 @@ pub struct DescribeScalableTargetsRequest {
     /// <p><p>The scalable dimension associated with the scalable target. This string consists of the service namespace, resource type, and scaling property. If you specify a scalable dimension, you must also specify a resource ID.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scalable_dimension: Option<String>,
+    pub scalable_dimension: Option<ScalableDimension>,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
+}
+
+impl DescribeScalableTargetsRequest {
+    /// Returns a builder for `DescribeScalableTargetsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeScalableTargetsRequestBuilder {
+        DescribeScalableTargetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeScalableTargetsRequest`](struct.DescribeScalableTargetsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeScalableTargetsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_ids: Option<Vec<String>>,
+    scalable_dimension: Option<ScalableDimension>,
+    service_namespace: Option<ServiceNamespace>,
+}
+
+impl DescribeScalableTargetsRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeScalableTargetsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeScalableTargetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_ids`](struct.DescribeScalableTargetsRequest.html#structfield.resource_ids) member.
+    pub fn resource_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scalable_dimension`](struct.DescribeScalableTargetsRequest.html#structfield.scalable_dimension) member.
+    pub fn scalable_dimension<T: Into<ScalableDimension>>(mut self, value: T) -> Self {
+        self.scalable_dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespace`](struct.DescribeScalableTargetsRequest.html#structfield.service_namespace) member.
+    pub fn service_namespace<T: Into<ServiceNamespace>>(mut self, value: T) -> Self {
+        self.service_namespace = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeScalableTargetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeScalableTargetsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeScalableTargetsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_ids: self.resource_ids,
+            scalable_dimension: self.scalable_dimension,
+            service_namespace: self
+                .service_namespace
+                .ok_or(::rusoto_core::BuildError::missing("service_namespace"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -150,7 +467,7 @@ pub struct DescribeScalableTargetsResponse {
     pub scalable_targets: Option<Vec<ScalableTarget>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeScalingActivitiesRequest {
     /// <p>The maximum number of scalable targets. This value can be between 1 and 50. The default value is 50.</p> <p>If this parameter is used, the operation returns up to <code>MaxResults</code> results at a time, along with a <code>NextToken</code> value. To get the next set of results, include the <code>NextToken</code> value in a subsequent call. If this parameter is not used, the operation returns up to 50 results and a <code>NextToken</code> value, if applicable.</p>
     #[serde(rename = "MaxResults")]
@@ -167,10 +484,74 @@ pub struct DescribeScalingActivitiesRequest {
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property. If you specify a scalable dimension, you must also specify a resource ID.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scalable_dimension: Option<String>,
+    pub scalable_dimension: Option<ScalableDimension>,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
+}
+
+impl DescribeScalingActivitiesRequest {
+    /// Returns a builder for `DescribeScalingActivitiesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeScalingActivitiesRequestBuilder {
+        DescribeScalingActivitiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeScalingActivitiesRequest`](struct.DescribeScalingActivitiesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeScalingActivitiesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_id: Option<String>,
+    scalable_dimension: Option<ScalableDimension>,
+    service_namespace: Option<ServiceNamespace>,
+}
+
+impl DescribeScalingActivitiesRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeScalingActivitiesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeScalingActivitiesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DescribeScalingActivitiesRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scalable_dimension`](struct.DescribeScalingActivitiesRequest.html#structfield.scalable_dimension) member.
+    pub fn scalable_dimension<T: Into<ScalableDimension>>(mut self, value: T) -> Self {
+        self.scalable_dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespace`](struct.DescribeScalingActivitiesRequest.html#structfield.service_namespace) member.
+    pub fn service_namespace<T: Into<ServiceNamespace>>(mut self, value: T) -> Self {
+        self.service_namespace = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeScalingActivitiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeScalingActivitiesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeScalingActivitiesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_id: self.resource_id,
+            scalable_dimension: self.scalable_dimension,
+            service_namespace: self
+                .service_namespace
+                .ok_or(::rusoto_core::BuildError::missing("service_namespace"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -186,7 +567,7 @@ pub struct DescribeScalingActivitiesResponse {
     pub scaling_activities: Option<Vec<ScalingActivity>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeScalingPoliciesRequest {
     /// <p>The maximum number of scalable targets. This value can be between 1 and 50. The default value is 50.</p> <p>If this parameter is used, the operation returns up to <code>MaxResults</code> results at a time, along with a <code>NextToken</code> value. To get the next set of results, include the <code>NextToken</code> value in a subsequent call. If this parameter is not used, the operation returns up to 50 results and a <code>NextToken</code> value, if applicable.</p>
     #[serde(rename = "MaxResults")]
@@ -207,10 +588,82 @@ pub struct DescribeScalingPoliciesRequest {
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property. If you specify a scalable dimension, you must also specify a resource ID.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scalable_dimension: Option<String>,
+    pub scalable_dimension: Option<ScalableDimension>,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
+}
+
+impl DescribeScalingPoliciesRequest {
+    /// Returns a builder for `DescribeScalingPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeScalingPoliciesRequestBuilder {
+        DescribeScalingPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeScalingPoliciesRequest`](struct.DescribeScalingPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeScalingPoliciesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    policy_names: Option<Vec<String>>,
+    resource_id: Option<String>,
+    scalable_dimension: Option<ScalableDimension>,
+    service_namespace: Option<ServiceNamespace>,
+}
+
+impl DescribeScalingPoliciesRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeScalingPoliciesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeScalingPoliciesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_names`](struct.DescribeScalingPoliciesRequest.html#structfield.policy_names) member.
+    pub fn policy_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DescribeScalingPoliciesRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scalable_dimension`](struct.DescribeScalingPoliciesRequest.html#structfield.scalable_dimension) member.
+    pub fn scalable_dimension<T: Into<ScalableDimension>>(mut self, value: T) -> Self {
+        self.scalable_dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespace`](struct.DescribeScalingPoliciesRequest.html#structfield.service_namespace) member.
+    pub fn service_namespace<T: Into<ServiceNamespace>>(mut self, value: T) -> Self {
+        self.service_namespace = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeScalingPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeScalingPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeScalingPoliciesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            policy_names: self.policy_names,
+            resource_id: self.resource_id,
+            scalable_dimension: self.scalable_dimension,
+            service_namespace: self
+                .service_namespace
+                .ok_or(::rusoto_core::BuildError::missing("service_namespace"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -226,7 +679,7 @@ pub struct DescribeScalingPoliciesResponse {
     pub scaling_policies: Option<Vec<ScalingPolicy>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeScheduledActionsRequest {
     /// <p>The maximum number of scheduled action results. This value can be between 1 and 50. The default value is 50.</p> <p>If this parameter is used, the operation returns up to <code>MaxResults</code> results at a time, along with a <code>NextToken</code> value. To get the next set of results, include the <code>NextToken</code> value in a subsequent call. If this parameter is not used, the operation returns up to 50 results and a <code>NextToken</code> value, if applicable.</p>
     #[serde(rename = "MaxResults")]
@@ -243,14 +696,86 @@ pub struct DescribeScheduledActionsRequest {
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property. If you specify a scalable dimension, you must also specify a resource ID.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scalable_dimension: Option<String>,
+    pub scalable_dimension: Option<ScalableDimension>,
     /// <p>The names of the scheduled actions to describe.</p>
     #[serde(rename = "ScheduledActionNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scheduled_action_names: Option<Vec<String>>,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
+}
+
+impl DescribeScheduledActionsRequest {
+    /// Returns a builder for `DescribeScheduledActionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeScheduledActionsRequestBuilder {
+        DescribeScheduledActionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeScheduledActionsRequest`](struct.DescribeScheduledActionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeScheduledActionsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_id: Option<String>,
+    scalable_dimension: Option<ScalableDimension>,
+    scheduled_action_names: Option<Vec<String>>,
+    service_namespace: Option<ServiceNamespace>,
+}
+
+impl DescribeScheduledActionsRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeScheduledActionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeScheduledActionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DescribeScheduledActionsRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scalable_dimension`](struct.DescribeScheduledActionsRequest.html#structfield.scalable_dimension) member.
+    pub fn scalable_dimension<T: Into<ScalableDimension>>(mut self, value: T) -> Self {
+        self.scalable_dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduled_action_names`](struct.DescribeScheduledActionsRequest.html#structfield.scheduled_action_names) member.
+    pub fn scheduled_action_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.scheduled_action_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespace`](struct.DescribeScheduledActionsRequest.html#structfield.service_namespace) member.
+    pub fn service_namespace<T: Into<ServiceNamespace>>(mut self, value: T) -> Self {
+        self.service_namespace = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeScheduledActionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeScheduledActionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeScheduledActionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_id: self.resource_id,
+            scalable_dimension: self.scalable_dimension,
+            scheduled_action_names: self.scheduled_action_names,
+            service_namespace: self
+                .service_namespace
+                .ok_or(::rusoto_core::BuildError::missing("service_namespace"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -266,6 +791,82 @@ pub struct DescribeScheduledActionsResponse {
     pub scheduled_actions: Option<Vec<ScheduledAction>>,
 }
 
+/// <p>The aggregation type for the CloudWatch metrics. Valid values are <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code>. If the aggregation type is null, the value is treated as <code>Average</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetricAggregationType {
+    /// <p><code>Average</code></p>
+    Average,
+    /// <p><code>Minimum</code></p>
+    Minimum,
+    /// <p><code>Maximum</code></p>
+    Maximum,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MetricAggregationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricAggregationType::Average => "Average",
+            MetricAggregationType::Minimum => "Minimum",
+            MetricAggregationType::Maximum => "Maximum",
+            MetricAggregationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MetricAggregationType {
+    fn from(value: &'a str) -> MetricAggregationType {
+        match value {
+            "Average" => MetricAggregationType::Average,
+            "Minimum" => MetricAggregationType::Minimum,
+            "Maximum" => MetricAggregationType::Maximum,
+            _ => MetricAggregationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricAggregationType {
+    fn from(value: String) -> MetricAggregationType {
+        MetricAggregationType::from(value.as_str())
+    }
+}
+
+impl From<MetricAggregationType> for String {
+    fn from(value: MetricAggregationType) -> String {
+        match value {
+            MetricAggregationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MetricAggregationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MetricAggregationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricAggregationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MetricAggregationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MetricAggregationType::from(value))
+    }
+}
+
 /// <p>Describes the dimension names and values associated with a metric.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetricDimension {
@@ -277,19 +878,387 @@ pub struct MetricDimension {
     pub value: String,
 }
 
+/// <p>The statistic of the metric.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetricStatistic {
+    /// <p><code>Average</code></p>
+    Average,
+    /// <p><code>Minimum</code></p>
+    Minimum,
+    /// <p><code>Maximum</code></p>
+    Maximum,
+    /// <p><code>SampleCount</code></p>
+    SampleCount,
+    /// <p><code>Sum</code></p>
+    Sum,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MetricStatistic {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricStatistic::Average => "Average",
+            MetricStatistic::Minimum => "Minimum",
+            MetricStatistic::Maximum => "Maximum",
+            MetricStatistic::SampleCount => "SampleCount",
+            MetricStatistic::Sum => "Sum",
+            MetricStatistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MetricStatistic {
+    fn from(value: &'a str) -> MetricStatistic {
+        match value {
+            "Average" => MetricStatistic::Average,
+            "Minimum" => MetricStatistic::Minimum,
+            "Maximum" => MetricStatistic::Maximum,
+            "SampleCount" => MetricStatistic::SampleCount,
+            "Sum" => MetricStatistic::Sum,
+            _ => MetricStatistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricStatistic {
+    fn from(value: String) -> MetricStatistic {
+        MetricStatistic::from(value.as_str())
+    }
+}
+
+impl From<MetricStatistic> for String {
+    fn from(value: MetricStatistic) -> String {
+        match value {
+            MetricStatistic::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MetricStatistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MetricStatistic {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricStatistic {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MetricStatistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MetricStatistic::from(value))
+    }
+}
+
+/// <p>The metric type. The <code>ALBRequestCountPerTarget</code> metric type applies only to Spot Fleet requests and ECS services.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetricType {
+    /// <p><code>DynamoDBReadCapacityUtilization</code></p>
+    DynamoDBReadCapacityUtilization,
+    /// <p><code>DynamoDBWriteCapacityUtilization</code></p>
+    DynamoDBWriteCapacityUtilization,
+    /// <p><code>ALBRequestCountPerTarget</code></p>
+    ALBRequestCountPerTarget,
+    /// <p><code>RDSReaderAverageCPUUtilization</code></p>
+    RDSReaderAverageCPUUtilization,
+    /// <p><code>RDSReaderAverageDatabaseConnections</code></p>
+    RDSReaderAverageDatabaseConnections,
+    /// <p><code>EC2SpotFleetRequestAverageCPUUtilization</code></p>
+    EC2SpotFleetRequestAverageCPUUtilization,
+    /// <p><code>EC2SpotFleetRequestAverageNetworkIn</code></p>
+    EC2SpotFleetRequestAverageNetworkIn,
+    /// <p><code>EC2SpotFleetRequestAverageNetworkOut</code></p>
+    EC2SpotFleetRequestAverageNetworkOut,
+    /// <p><code>SageMakerVariantInvocationsPerInstance</code></p>
+    SageMakerVariantInvocationsPerInstance,
+    /// <p><code>ECSServiceAverageCPUUtilization</code></p>
+    ECSServiceAverageCPUUtilization,
+    /// <p><code>ECSServiceAverageMemoryUtilization</code></p>
+    ECSServiceAverageMemoryUtilization,
+    /// <p><code>AppStreamAverageCapacityUtilization</code></p>
+    AppStreamAverageCapacityUtilization,
+    /// <p><code>ComprehendInferenceUtilization</code></p>
+    ComprehendInferenceUtilization,
+    /// <p><code>LambdaProvisionedConcurrencyUtilization</code></p>
+    LambdaProvisionedConcurrencyUtilization,
+    /// <p><code>CassandraReadCapacityUtilization</code></p>
+    CassandraReadCapacityUtilization,
+    /// <p><code>CassandraWriteCapacityUtilization</code></p>
+    CassandraWriteCapacityUtilization,
+    /// <p><code>KafkaBrokerStorageUtilization</code></p>
+    KafkaBrokerStorageUtilization,
+    /// <p><code>ElastiCachePrimaryEngineCPUUtilization</code></p>
+    ElastiCachePrimaryEngineCPUUtilization,
+    /// <p><code>ElastiCacheReplicaEngineCPUUtilization</code></p>
+    ElastiCacheReplicaEngineCPUUtilization,
+    /// <p><code>ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage</code></p>
+    ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage,
+    /// <p><code>NeptuneReaderAverageCPUUtilization</code></p>
+    NeptuneReaderAverageCPUUtilization,
+    /// <p><code>SageMakerVariantProvisionedConcurrencyUtilization</code></p>
+    SageMakerVariantProvisionedConcurrencyUtilization,
+    /// <p><code>ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage</code></p>
+    ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage,
+    /// <p><code>SageMakerInferenceComponentInvocationsPerCopy</code></p>
+    SageMakerInferenceComponentInvocationsPerCopy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MetricType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricType::DynamoDBReadCapacityUtilization => "DynamoDBReadCapacityUtilization",
+            MetricType::DynamoDBWriteCapacityUtilization => "DynamoDBWriteCapacityUtilization",
+            MetricType::ALBRequestCountPerTarget => "ALBRequestCountPerTarget",
+            MetricType::RDSReaderAverageCPUUtilization => "RDSReaderAverageCPUUtilization",
+            MetricType::RDSReaderAverageDatabaseConnections => {
+                "RDSReaderAverageDatabaseConnections"
+            }
+            MetricType::EC2SpotFleetRequestAverageCPUUtilization => {
+                "EC2SpotFleetRequestAverageCPUUtilization"
+            }
+            MetricType::EC2SpotFleetRequestAverageNetworkIn => {
+                "EC2SpotFleetRequestAverageNetworkIn"
+            }
+            MetricType::EC2SpotFleetRequestAverageNetworkOut => {
+                "EC2SpotFleetRequestAverageNetworkOut"
+            }
+            MetricType::SageMakerVariantInvocationsPerInstance => {
+                "SageMakerVariantInvocationsPerInstance"
+            }
+            MetricType::ECSServiceAverageCPUUtilization => "ECSServiceAverageCPUUtilization",
+            MetricType::ECSServiceAverageMemoryUtilization => "ECSServiceAverageMemoryUtilization",
+            MetricType::AppStreamAverageCapacityUtilization => {
+                "AppStreamAverageCapacityUtilization"
+            }
+            MetricType::ComprehendInferenceUtilization => "ComprehendInferenceUtilization",
+            MetricType::LambdaProvisionedConcurrencyUtilization => {
+                "LambdaProvisionedConcurrencyUtilization"
+            }
+            MetricType::CassandraReadCapacityUtilization => "CassandraReadCapacityUtilization",
+            MetricType::CassandraWriteCapacityUtilization => "CassandraWriteCapacityUtilization",
+            MetricType::KafkaBrokerStorageUtilization => "KafkaBrokerStorageUtilization",
+            MetricType::ElastiCachePrimaryEngineCPUUtilization => {
+                "ElastiCachePrimaryEngineCPUUtilization"
+            }
+            MetricType::ElastiCacheReplicaEngineCPUUtilization => {
+                "ElastiCacheReplicaEngineCPUUtilization"
+            }
+            MetricType::ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage => {
+                "ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage"
+            }
+            MetricType::NeptuneReaderAverageCPUUtilization => "NeptuneReaderAverageCPUUtilization",
+            MetricType::SageMakerVariantProvisionedConcurrencyUtilization => {
+                "SageMakerVariantProvisionedConcurrencyUtilization"
+            }
+            MetricType::ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage => {
+                "ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage"
+            }
+            MetricType::SageMakerInferenceComponentInvocationsPerCopy => {
+                "SageMakerInferenceComponentInvocationsPerCopy"
+            }
+            MetricType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MetricType {
+    fn from(value: &'a str) -> MetricType {
+        match value {
+            "DynamoDBReadCapacityUtilization" => MetricType::DynamoDBReadCapacityUtilization,
+            "DynamoDBWriteCapacityUtilization" => MetricType::DynamoDBWriteCapacityUtilization,
+            "ALBRequestCountPerTarget" => MetricType::ALBRequestCountPerTarget,
+            "RDSReaderAverageCPUUtilization" => MetricType::RDSReaderAverageCPUUtilization,
+            "RDSReaderAverageDatabaseConnections" => {
+                MetricType::RDSReaderAverageDatabaseConnections
+            }
+            "EC2SpotFleetRequestAverageCPUUtilization" => {
+                MetricType::EC2SpotFleetRequestAverageCPUUtilization
+            }
+            "EC2SpotFleetRequestAverageNetworkIn" => {
+                MetricType::EC2SpotFleetRequestAverageNetworkIn
+            }
+            "EC2SpotFleetRequestAverageNetworkOut" => {
+                MetricType::EC2SpotFleetRequestAverageNetworkOut
+            }
+            "SageMakerVariantInvocationsPerInstance" => {
+                MetricType::SageMakerVariantInvocationsPerInstance
+            }
+            "ECSServiceAverageCPUUtilization" => MetricType::ECSServiceAverageCPUUtilization,
+            "ECSServiceAverageMemoryUtilization" => MetricType::ECSServiceAverageMemoryUtilization,
+            "AppStreamAverageCapacityUtilization" => {
+                MetricType::AppStreamAverageCapacityUtilization
+            }
+            "ComprehendInferenceUtilization" => MetricType::ComprehendInferenceUtilization,
+            "LambdaProvisionedConcurrencyUtilization" => {
+                MetricType::LambdaProvisionedConcurrencyUtilization
+            }
+            "CassandraReadCapacityUtilization" => MetricType::CassandraReadCapacityUtilization,
+            "CassandraWriteCapacityUtilization" => MetricType::CassandraWriteCapacityUtilization,
+            "KafkaBrokerStorageUtilization" => MetricType::KafkaBrokerStorageUtilization,
+            "ElastiCachePrimaryEngineCPUUtilization" => {
+                MetricType::ElastiCachePrimaryEngineCPUUtilization
+            }
+            "ElastiCacheReplicaEngineCPUUtilization" => {
+                MetricType::ElastiCacheReplicaEngineCPUUtilization
+            }
+            "ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage" => {
+                MetricType::ElastiCacheDatabaseMemoryUsageCountedForEvictPercentage
+            }
+            "NeptuneReaderAverageCPUUtilization" => MetricType::NeptuneReaderAverageCPUUtilization,
+            "SageMakerVariantProvisionedConcurrencyUtilization" => {
+                MetricType::SageMakerVariantProvisionedConcurrencyUtilization
+            }
+            "ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage" => {
+                MetricType::ElastiCacheDatabaseCapacityUsageCountedForEvictPercentage
+            }
+            "SageMakerInferenceComponentInvocationsPerCopy" => {
+                MetricType::SageMakerInferenceComponentInvocationsPerCopy
+            }
+            _ => MetricType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricType {
+    fn from(value: String) -> MetricType {
+        MetricType::from(value.as_str())
+    }
+}
+
+impl From<MetricType> for String {
+    fn from(value: MetricType) -> String {
+        match value {
+            MetricType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MetricType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MetricType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MetricType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MetricType::from(value))
+    }
+}
+
+/// <p>The policy type. This parameter is required if you are creating a scaling policy.</p> <p>The following policy types are supported: </p> <p> <code>TargetTrackingScaling</code>—Not supported for Amazon EMR or AppStream</p> <p> <code>StepScaling</code>—Not supported for Amazon DynamoDB</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-step-scaling-policies.html">Step Scaling Policies for Application Auto Scaling</a> and <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-target-tracking.html">Target Tracking Scaling Policies for Application Auto Scaling</a> in the <i>Application Auto Scaling User Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyType {
+    /// <p><code>StepScaling</code></p>
+    StepScaling,
+    /// <p><code>TargetTrackingScaling</code></p>
+    TargetTrackingScaling,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PolicyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyType::StepScaling => "StepScaling",
+            PolicyType::TargetTrackingScaling => "TargetTrackingScaling",
+            PolicyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PolicyType {
+    fn from(value: &'a str) -> PolicyType {
+        match value {
+            "StepScaling" => PolicyType::StepScaling,
+            "TargetTrackingScaling" => PolicyType::TargetTrackingScaling,
+            _ => PolicyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyType {
+    fn from(value: String) -> PolicyType {
+        PolicyType::from(value.as_str())
+    }
+}
+
+impl From<PolicyType> for String {
+    fn from(value: PolicyType) -> String {
+        match value {
+            PolicyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PolicyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PolicyType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PolicyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PolicyType::from(value))
+    }
+}
+
 /// <p>Represents a predefined metric for a target tracking scaling policy to use with Application Auto Scaling.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PredefinedMetricSpecification {
     /// <p>The metric type. The <code>ALBRequestCountPerTarget</code> metric type applies only to Spot Fleet requests and ECS services.</p>
     #[serde(rename = "PredefinedMetricType")]
-    pub predefined_metric_type: String,
+    pub predefined_metric_type: MetricType,
     /// <p><p>Identifies the resource associated with the metric type. You can&#39;t specify a resource label unless the metric type is <code>ALBRequestCountPerTarget</code> and there is a target group attached to the Spot Fleet request or ECS service.</p> <p>The format is app/&lt;load-balancer-name&gt;/&lt;load-balancer-id&gt;/targetgroup/&lt;target-group-name&gt;/&lt;target-group-id&gt;, where:</p> <ul> <li> <p>app/&lt;load-balancer-name&gt;/&lt;load-balancer-id&gt; is the final portion of the load balancer ARN</p> </li> <li> <p>targetgroup/&lt;target-group-name&gt;/&lt;target-group-id&gt; is the final portion of the target group ARN.</p> </li> </ul></p>
     #[serde(rename = "ResourceLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_label: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutScalingPolicyRequest {
     /// <p>The name of the scaling policy.</p>
     #[serde(rename = "PolicyName")]
@@ -297,16 +1266,16 @@ pub struct PutScalingPolicyRequest {
     /// <p>The policy type. This parameter is required if you are creating a scaling policy.</p> <p>The following policy types are supported: </p> <p> <code>TargetTrackingScaling</code>—Not supported for Amazon EMR or AppStream</p> <p> <code>StepScaling</code>—Not supported for Amazon DynamoDB</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-step-scaling-policies.html">Step Scaling Policies for Application Auto Scaling</a> and <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-target-tracking.html">Target Tracking Scaling Policies for Application Auto Scaling</a> in the <i>Application Auto Scaling User Guide</i>.</p>
     #[serde(rename = "PolicyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy_type: Option<String>,
+    pub policy_type: Option<PolicyType>,
     /// <p><p>The identifier of the resource associated with the scaling policy. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variants - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>A step scaling policy.</p> <p>This parameter is required if you are creating a policy and the policy type is <code>StepScaling</code>.</p>
     #[serde(rename = "StepScalingPolicyConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -318,6 +1287,101 @@ pub struct PutScalingPolicyRequest {
         Option<TargetTrackingScalingPolicyConfiguration>,
 }
 
+impl PutScalingPolicyRequest {
+    /// Returns a builder for `PutScalingPolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutScalingPolicyRequestBuilder {
+        PutScalingPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutScalingPolicyRequest`](struct.PutScalingPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutScalingPolicyRequestBuilder {
+    policy_name: Option<String>,
+    policy_type: Option<PolicyType>,
+    resource_id: Option<String>,
+    scalable_dimension: Option<ScalableDimension>,
+    service_namespace: Option<ServiceNamespace>,
+    step_scaling_policy_configuration: Option<StepScalingPolicyConfiguration>,
+    target_tracking_scaling_policy_configuration: Option<TargetTrackingScalingPolicyConfiguration>,
+}
+
+impl PutScalingPolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.PutScalingPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_type`](struct.PutScalingPolicyRequest.html#structfield.policy_type) member.
+    pub fn policy_type<T: Into<PolicyType>>(mut self, value: T) -> Self {
+        self.policy_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.PutScalingPolicyRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scalable_dimension`](struct.PutScalingPolicyRequest.html#structfield.scalable_dimension) member.
+    pub fn scalable_dimension<T: Into<ScalableDimension>>(mut self, value: T) -> Self {
+        self.scalable_dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespace`](struct.PutScalingPolicyRequest.html#structfield.service_namespace) member.
+    pub fn service_namespace<T: Into<ServiceNamespace>>(mut self, value: T) -> Self {
+        self.service_namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`step_scaling_policy_configuration`](struct.PutScalingPolicyRequest.html#structfield.step_scaling_policy_configuration) member.
+    pub fn step_scaling_policy_configuration<T: Into<StepScalingPolicyConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.step_scaling_policy_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_tracking_scaling_policy_configuration`](struct.PutScalingPolicyRequest.html#structfield.target_tracking_scaling_policy_configuration) member.
+    pub fn target_tracking_scaling_policy_configuration<
+        T: Into<TargetTrackingScalingPolicyConfiguration>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.target_tracking_scaling_policy_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutScalingPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutScalingPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutScalingPolicyRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            policy_type: self.policy_type,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            scalable_dimension: self
+                .scalable_dimension
+                .ok_or(::rusoto_core::BuildError::missing("scalable_dimension"))?,
+            service_namespace: self
+                .service_namespace
+                .ok_or(::rusoto_core::BuildError::missing("service_namespace"))?,
+            step_scaling_policy_configuration: self.step_scaling_policy_configuration,
+            target_tracking_scaling_policy_configuration: self
+                .target_tracking_scaling_policy_configuration,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutScalingPolicyResponse {
@@ -330,18 +1394,19 @@ pub struct PutScalingPolicyResponse {
     pub policy_arn: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutScheduledActionRequest {
     /// <p>The date and time for the scheduled action to end.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p><p>The identifier of the resource associated with the scheduled action. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variants - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The new minimum and maximum capacity. You can set both values or just one. During the scheduled time, if the current capacity is below the minimum capacity, Application Auto Scaling scales out to the minimum capacity. If the current capacity is above the maximum capacity, Application Auto Scaling scales in to the maximum capacity.</p>
     #[serde(rename = "ScalableTargetAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -355,18 +1420,113 @@ pub struct PutScheduledActionRequest {
     pub scheduled_action_name: String,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>The date and time for the scheduled action to start.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
+}
+
+impl PutScheduledActionRequest {
+    /// Returns a builder for `PutScheduledActionRequest`, checking its required members when built.
+    pub fn builder() -> PutScheduledActionRequestBuilder {
+        PutScheduledActionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutScheduledActionRequest`](struct.PutScheduledActionRequest.html).
+#[derive(Default, Debug)]
+pub struct PutScheduledActionRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    resource_id: Option<String>,
+    scalable_dimension: Option<ScalableDimension>,
+    scalable_target_action: Option<ScalableTargetAction>,
+    schedule: Option<String>,
+    scheduled_action_name: Option<String>,
+    service_namespace: Option<ServiceNamespace>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl PutScheduledActionRequestBuilder {
+    /// Sets the [`end_time`](struct.PutScheduledActionRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.PutScheduledActionRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scalable_dimension`](struct.PutScheduledActionRequest.html#structfield.scalable_dimension) member.
+    pub fn scalable_dimension<T: Into<ScalableDimension>>(mut self, value: T) -> Self {
+        self.scalable_dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scalable_target_action`](struct.PutScheduledActionRequest.html#structfield.scalable_target_action) member.
+    pub fn scalable_target_action<T: Into<ScalableTargetAction>>(mut self, value: T) -> Self {
+        self.scalable_target_action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule`](struct.PutScheduledActionRequest.html#structfield.schedule) member.
+    pub fn schedule<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduled_action_name`](struct.PutScheduledActionRequest.html#structfield.scheduled_action_name) member.
+    pub fn scheduled_action_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scheduled_action_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespace`](struct.PutScheduledActionRequest.html#structfield.service_namespace) member.
+    pub fn service_namespace<T: Into<ServiceNamespace>>(mut self, value: T) -> Self {
+        self.service_namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.PutScheduledActionRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutScheduledActionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutScheduledActionRequest, ::rusoto_core::BuildError> {
+        Ok(PutScheduledActionRequest {
+            end_time: self.end_time,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            scalable_dimension: self
+                .scalable_dimension
+                .ok_or(::rusoto_core::BuildError::missing("scalable_dimension"))?,
+            scalable_target_action: self.scalable_target_action,
+            schedule: self.schedule,
+            scheduled_action_name: self
+                .scheduled_action_name
+                .ok_or(::rusoto_core::BuildError::missing("scheduled_action_name"))?,
+            service_namespace: self
+                .service_namespace
+                .ok_or(::rusoto_core::BuildError::missing("service_namespace"))?,
+            start_time: self.start_time,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutScheduledActionResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RegisterScalableTargetRequest {
     /// <p>The maximum value to scale to in response to a scale-out event. <code>MaxCapacity</code> is required to register a scalable target.</p>
     #[serde(rename = "MaxCapacity")]
@@ -385,27 +1545,324 @@ pub struct RegisterScalableTargetRequest {
     pub role_arn: Option<String>,
     /// <p><p>The scalable dimension associated with the scalable target. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>An embedded object that contains attributes and attribute values that are used to suspend and resume automatic scaling. Setting the value of an attribute to <code>true</code> suspends the specified scaling activities. Setting it to <code>false</code> (default) resumes the specified scaling activities. </p> <p> <b>Suspension Outcomes</b> </p> <ul> <li> <p>For <code>DynamicScalingInSuspended</code>, while a suspension is in effect, all scale-in activities that are triggered by a scaling policy are suspended.</p> </li> <li> <p>For <code>DynamicScalingOutSuspended</code>, while a suspension is in effect, all scale-out activities that are triggered by a scaling policy are suspended.</p> </li> <li> <p>For <code>ScheduledScalingSuspended</code>, while a suspension is in effect, all scaling activities that involve scheduled actions are suspended. </p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/application/userguide/application-auto-scaling-suspend-resume-scaling.html">Suspend and Resume Application Auto Scaling</a> in the <i>Application Auto Scaling User Guide</i>.</p>
     #[serde(rename = "SuspendedState")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub suspended_state: Option<SuspendedState>,
 }
 
+impl RegisterScalableTargetRequest {
+    /// Returns a builder for `RegisterScalableTargetRequest`, checking its required members when built.
+    pub fn builder() -> RegisterScalableTargetRequestBuilder {
+        RegisterScalableTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterScalableTargetRequest`](struct.RegisterScalableTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterScalableTargetRequestBuilder {
+    max_capacity: Option<i64>,
+    min_capacity: Option<i64>,
+    resource_id: Option<String>,
+    role_arn: Option<String>,
+    scalable_dimension: Option<ScalableDimension>,
+    service_namespace: Option<ServiceNamespace>,
+    suspended_state: Option<SuspendedState>,
+}
+
+impl RegisterScalableTargetRequestBuilder {
+    /// Sets the [`max_capacity`](struct.RegisterScalableTargetRequest.html#structfield.max_capacity) member.
+    pub fn max_capacity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`min_capacity`](struct.RegisterScalableTargetRequest.html#structfield.min_capacity) member.
+    pub fn min_capacity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.min_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.RegisterScalableTargetRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.RegisterScalableTargetRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scalable_dimension`](struct.RegisterScalableTargetRequest.html#structfield.scalable_dimension) member.
+    pub fn scalable_dimension<T: Into<ScalableDimension>>(mut self, value: T) -> Self {
+        self.scalable_dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespace`](struct.RegisterScalableTargetRequest.html#structfield.service_namespace) member.
+    pub fn service_namespace<T: Into<ServiceNamespace>>(mut self, value: T) -> Self {
+        self.service_namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`suspended_state`](struct.RegisterScalableTargetRequest.html#structfield.suspended_state) member.
+    pub fn suspended_state<T: Into<SuspendedState>>(mut self, value: T) -> Self {
+        self.suspended_state = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterScalableTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterScalableTargetRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterScalableTargetRequest {
+            max_capacity: self.max_capacity,
+            min_capacity: self.min_capacity,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            role_arn: self.role_arn,
+            scalable_dimension: self
+                .scalable_dimension
+                .ok_or(::rusoto_core::BuildError::missing("scalable_dimension"))?,
+            service_namespace: self
+                .service_namespace
+                .ok_or(::rusoto_core::BuildError::missing("service_namespace"))?,
+            suspended_state: self.suspended_state,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RegisterScalableTargetResponse {}
 
+/// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScalableDimension {
+    /// <p><code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p>
+    EcsServiceDesiredCount,
+    /// <p><code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p>
+    Ec2SpotFleetRequestTargetCapacity,
+    /// <p><code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p>
+    ElasticmapreduceInstancegroupInstanceCount,
+    /// <p><code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p>
+    AppstreamFleetDesiredCapacity,
+    /// <p><code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p>
+    DynamodbTableReadCapacityUnits,
+    /// <p><code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p>
+    DynamodbTableWriteCapacityUnits,
+    /// <p><code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p>
+    DynamodbIndexReadCapacityUnits,
+    /// <p><code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p>
+    DynamodbIndexWriteCapacityUnits,
+    /// <p><code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p>
+    RdsClusterReadReplicaCount,
+    /// <p><code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p>
+    SagemakerVariantDesiredInstanceCount,
+    /// <p><code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p>
+    CustomResourceResourceTypeProperty,
+    /// <p><code>comprehend:document-classifier-endpoint:DesiredInferenceUnits</code></p>
+    ComprehendDocumentClassifierEndpointDesiredInferenceUnits,
+    /// <p><code>comprehend:entity-recognizer-endpoint:DesiredInferenceUnits</code></p>
+    ComprehendEntityRecognizerEndpointDesiredInferenceUnits,
+    /// <p><code>lambda:function:ProvisionedConcurrency</code></p>
+    LambdaFunctionProvisionedConcurrency,
+    /// <p><code>cassandra:table:ReadCapacityUnits</code></p>
+    CassandraTableReadCapacityUnits,
+    /// <p><code>cassandra:table:WriteCapacityUnits</code></p>
+    CassandraTableWriteCapacityUnits,
+    /// <p><code>kafka:broker-storage:VolumeSize</code></p>
+    KafkaBrokerStorageVolumeSize,
+    /// <p><code>elasticache:replication-group:NodeGroups</code></p>
+    ElasticacheReplicationGroupNodeGroups,
+    /// <p><code>elasticache:replication-group:Replicas</code></p>
+    ElasticacheReplicationGroupReplicas,
+    /// <p><code>neptune:cluster:ReadReplicaCount</code></p>
+    NeptuneClusterReadReplicaCount,
+    /// <p><code>sagemaker:variant:DesiredProvisionedConcurrency</code></p>
+    SagemakerVariantDesiredProvisionedConcurrency,
+    /// <p><code>sagemaker:inference-component:DesiredCopyCount</code></p>
+    SagemakerInferenceComponentDesiredCopyCount,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScalableDimension {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalableDimension::EcsServiceDesiredCount => "ecs:service:DesiredCount",
+            ScalableDimension::Ec2SpotFleetRequestTargetCapacity => {
+                "ec2:spot-fleet-request:TargetCapacity"
+            }
+            ScalableDimension::ElasticmapreduceInstancegroupInstanceCount => {
+                "elasticmapreduce:instancegroup:InstanceCount"
+            }
+            ScalableDimension::AppstreamFleetDesiredCapacity => "appstream:fleet:DesiredCapacity",
+            ScalableDimension::DynamodbTableReadCapacityUnits => "dynamodb:table:ReadCapacityUnits",
+            ScalableDimension::DynamodbTableWriteCapacityUnits => {
+                "dynamodb:table:WriteCapacityUnits"
+            }
+            ScalableDimension::DynamodbIndexReadCapacityUnits => "dynamodb:index:ReadCapacityUnits",
+            ScalableDimension::DynamodbIndexWriteCapacityUnits => {
+                "dynamodb:index:WriteCapacityUnits"
+            }
+            ScalableDimension::RdsClusterReadReplicaCount => "rds:cluster:ReadReplicaCount",
+            ScalableDimension::SagemakerVariantDesiredInstanceCount => {
+                "sagemaker:variant:DesiredInstanceCount"
+            }
+            ScalableDimension::CustomResourceResourceTypeProperty => {
+                "custom-resource:ResourceType:Property"
+            }
+            ScalableDimension::ComprehendDocumentClassifierEndpointDesiredInferenceUnits => {
+                "comprehend:document-classifier-endpoint:DesiredInferenceUnits"
+            }
+            ScalableDimension::ComprehendEntityRecognizerEndpointDesiredInferenceUnits => {
+                "comprehend:entity-recognizer-endpoint:DesiredInferenceUnits"
+            }
+            ScalableDimension::LambdaFunctionProvisionedConcurrency => {
+                "lambda:function:ProvisionedConcurrency"
+            }
+            ScalableDimension::CassandraTableReadCapacityUnits => {
+                "cassandra:table:ReadCapacityUnits"
+            }
+            ScalableDimension::CassandraTableWriteCapacityUnits => {
+                "cassandra:table:WriteCapacityUnits"
+            }
+            ScalableDimension::KafkaBrokerStorageVolumeSize => "kafka:broker-storage:VolumeSize",
+            ScalableDimension::ElasticacheReplicationGroupNodeGroups => {
+                "elasticache:replication-group:NodeGroups"
+            }
+            ScalableDimension::ElasticacheReplicationGroupReplicas => {
+                "elasticache:replication-group:Replicas"
+            }
+            ScalableDimension::NeptuneClusterReadReplicaCount => "neptune:cluster:ReadReplicaCount",
+            ScalableDimension::SagemakerVariantDesiredProvisionedConcurrency => {
+                "sagemaker:variant:DesiredProvisionedConcurrency"
+            }
+            ScalableDimension::SagemakerInferenceComponentDesiredCopyCount => {
+                "sagemaker:inference-component:DesiredCopyCount"
+            }
+            ScalableDimension::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScalableDimension {
+    fn from(value: &'a str) -> ScalableDimension {
+        match value {
+            "ecs:service:DesiredCount" => ScalableDimension::EcsServiceDesiredCount,
+            "ec2:spot-fleet-request:TargetCapacity" => {
+                ScalableDimension::Ec2SpotFleetRequestTargetCapacity
+            }
+            "elasticmapreduce:instancegroup:InstanceCount" => {
+                ScalableDimension::ElasticmapreduceInstancegroupInstanceCount
+            }
+            "appstream:fleet:DesiredCapacity" => ScalableDimension::AppstreamFleetDesiredCapacity,
+            "dynamodb:table:ReadCapacityUnits" => ScalableDimension::DynamodbTableReadCapacityUnits,
+            "dynamodb:table:WriteCapacityUnits" => {
+                ScalableDimension::DynamodbTableWriteCapacityUnits
+            }
+            "dynamodb:index:ReadCapacityUnits" => ScalableDimension::DynamodbIndexReadCapacityUnits,
+            "dynamodb:index:WriteCapacityUnits" => {
+                ScalableDimension::DynamodbIndexWriteCapacityUnits
+            }
+            "rds:cluster:ReadReplicaCount" => ScalableDimension::RdsClusterReadReplicaCount,
+            "sagemaker:variant:DesiredInstanceCount" => {
+                ScalableDimension::SagemakerVariantDesiredInstanceCount
+            }
+            "custom-resource:ResourceType:Property" => {
+                ScalableDimension::CustomResourceResourceTypeProperty
+            }
+            "comprehend:document-classifier-endpoint:DesiredInferenceUnits" => {
+                ScalableDimension::ComprehendDocumentClassifierEndpointDesiredInferenceUnits
+            }
+            "comprehend:entity-recognizer-endpoint:DesiredInferenceUnits" => {
+                ScalableDimension::ComprehendEntityRecognizerEndpointDesiredInferenceUnits
+            }
+            "lambda:function:ProvisionedConcurrency" => {
+                ScalableDimension::LambdaFunctionProvisionedConcurrency
+            }
+            "cassandra:table:ReadCapacityUnits" => {
+                ScalableDimension::CassandraTableReadCapacityUnits
+            }
+            "cassandra:table:WriteCapacityUnits" => {
+                ScalableDimension::CassandraTableWriteCapacityUnits
+            }
+            "kafka:broker-storage:VolumeSize" => ScalableDimension::KafkaBrokerStorageVolumeSize,
+            "elasticache:replication-group:NodeGroups" => {
+                ScalableDimension::ElasticacheReplicationGroupNodeGroups
+            }
+            "elasticache:replication-group:Replicas" => {
+                ScalableDimension::ElasticacheReplicationGroupReplicas
+            }
+            "neptune:cluster:ReadReplicaCount" => ScalableDimension::NeptuneClusterReadReplicaCount,
+            "sagemaker:variant:DesiredProvisionedConcurrency" => {
+                ScalableDimension::SagemakerVariantDesiredProvisionedConcurrency
+            }
+            "sagemaker:inference-component:DesiredCopyCount" => {
+                ScalableDimension::SagemakerInferenceComponentDesiredCopyCount
+            }
+            _ => ScalableDimension::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalableDimension {
+    fn from(value: String) -> ScalableDimension {
+        ScalableDimension::from(value.as_str())
+    }
+}
+
+impl From<ScalableDimension> for String {
+    fn from(value: ScalableDimension) -> String {
+        match value {
+            ScalableDimension::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScalableDimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScalableDimension {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalableDimension {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ScalableDimension, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScalableDimension::from(value))
+    }
+}
+
 /// <p>Represents a scalable target.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ScalableTarget {
     /// <p>The Unix timestamp for when the scalable target was created.</p>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_time: EpochTimestamp,
     /// <p>The maximum value to scale to in response to a scale-out event.</p>
     #[serde(rename = "MaxCapacity")]
     pub max_capacity: i64,
@@ -420,10 +1877,10 @@ pub struct ScalableTarget {
     pub role_arn: String,
     /// <p><p>The scalable dimension associated with the scalable target. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     #[serde(rename = "SuspendedState")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub suspended_state: Option<SuspendedState>,
@@ -443,7 +1900,7 @@ pub struct ScalableTargetAction {
 }
 
 /// <p>Represents a scaling activity.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ScalingActivity {
     /// <p>The unique identifier of the scaling activity.</p>
@@ -461,31 +1918,121 @@ pub struct ScalingActivity {
     pub details: Option<String>,
     /// <p>The Unix timestamp for when the scaling activity ended.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p><p>The identifier of the resource associated with the scaling activity. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variants - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>The Unix timestamp for when the scaling activity began.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
     /// <p>Indicates the status of the scaling activity.</p>
     #[serde(rename = "StatusCode")]
-    pub status_code: String,
+    pub status_code: ScalingActivityStatusCode,
     /// <p>A simple message about the current status of the scaling activity.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_message: Option<String>,
 }
 
+/// <p>Indicates the status of the scaling activity.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScalingActivityStatusCode {
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>Successful</code></p>
+    Successful,
+    /// <p><code>Overridden</code></p>
+    Overridden,
+    /// <p><code>Unfulfilled</code></p>
+    Unfulfilled,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScalingActivityStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingActivityStatusCode::Pending => "Pending",
+            ScalingActivityStatusCode::InProgress => "InProgress",
+            ScalingActivityStatusCode::Successful => "Successful",
+            ScalingActivityStatusCode::Overridden => "Overridden",
+            ScalingActivityStatusCode::Unfulfilled => "Unfulfilled",
+            ScalingActivityStatusCode::Failed => "Failed",
+            ScalingActivityStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScalingActivityStatusCode {
+    fn from(value: &'a str) -> ScalingActivityStatusCode {
+        match value {
+            "Pending" => ScalingActivityStatusCode::Pending,
+            "InProgress" => ScalingActivityStatusCode::InProgress,
+            "Successful" => ScalingActivityStatusCode::Successful,
+            "Overridden" => ScalingActivityStatusCode::Overridden,
+            "Unfulfilled" => ScalingActivityStatusCode::Unfulfilled,
+            "Failed" => ScalingActivityStatusCode::Failed,
+            _ => ScalingActivityStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingActivityStatusCode {
+    fn from(value: String) -> ScalingActivityStatusCode {
+        ScalingActivityStatusCode::from(value.as_str())
+    }
+}
+
+impl From<ScalingActivityStatusCode> for String {
+    fn from(value: ScalingActivityStatusCode) -> String {
+        match value {
+            ScalingActivityStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScalingActivityStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScalingActivityStatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingActivityStatusCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ScalingActivityStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScalingActivityStatusCode::from(value))
+    }
+}
+
 /// <p>Represents a scaling policy to use with Application Auto Scaling.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ScalingPolicy {
     /// <p>The CloudWatch alarms associated with the scaling policy.</p>
@@ -494,7 +2041,8 @@ pub struct ScalingPolicy {
     pub alarms: Option<Vec<Alarm>>,
     /// <p>The Unix timestamp for when the scaling policy was created.</p>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_time: EpochTimestamp,
     /// <p>The Amazon Resource Name (ARN) of the scaling policy.</p>
     #[serde(rename = "PolicyARN")]
     pub policy_arn: String,
@@ -503,16 +2051,16 @@ pub struct ScalingPolicy {
     pub policy_name: String,
     /// <p>The scaling policy type.</p>
     #[serde(rename = "PolicyType")]
-    pub policy_type: String,
+    pub policy_type: PolicyType,
     /// <p><p>The identifier of the resource associated with the scaling policy. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variants - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>A step scaling policy.</p>
     #[serde(rename = "StepScalingPolicyConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -525,23 +2073,25 @@ pub struct ScalingPolicy {
 }
 
 /// <p>Represents a scheduled action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ScheduledAction {
     /// <p>The date and time that the scheduled action was created.</p>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_time: EpochTimestamp,
     /// <p>The date and time that the action is scheduled to end.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p><p>The identifier of the resource associated with the scaling policy. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>EMR cluster - The resource type is <code>instancegroup</code> and the unique identifier is the cluster ID and instance group ID. Example: <code>instancegroup/j-2EEZNYKUA1NTV/ig-1791Y4E1L8YI0</code>.</p> </li> <li> <p>AppStream 2.0 fleet - The resource type is <code>fleet</code> and the unique identifier is the fleet name. Example: <code>fleet/sample-fleet</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> <li> <p>Amazon SageMaker endpoint variants - The resource type is <code>variant</code> and the unique identifier is the resource ID. Example: <code>endpoint/my-end-point/variant/KMeansClustering</code>.</p> </li> <li> <p>Custom resources are not supported with a resource type. This parameter must specify the <code>OutputValue</code> from the CloudFormation template stack used to access the resources. The unique identifier is defined by the service provider. More information is available in our <a href="https://github.com/aws/aws-auto-scaling-custom-resource">GitHub repository</a>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension. This string consists of the service namespace, resource type, and scaling property.</p> <ul> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>elasticmapreduce:instancegroup:InstanceCount</code> - The instance count of an EMR Instance Group.</p> </li> <li> <p> <code>appstream:fleet:DesiredCapacity</code> - The desired capacity of an AppStream 2.0 fleet.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> <li> <p> <code>sagemaker:variant:DesiredInstanceCount</code> - The number of EC2 instances for an Amazon SageMaker model endpoint variant.</p> </li> <li> <p> <code>custom-resource:ResourceType:Property</code> - The scalable dimension for a custom resource provided by your own application or service.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scalable_dimension: Option<String>,
+    pub scalable_dimension: Option<ScalableDimension>,
     /// <p>The new minimum and maximum capacity. You can set both values or just one. During the scheduled time, if the current capacity is below the minimum capacity, Application Auto Scaling scales out to the minimum capacity. If the current capacity is above the maximum capacity, Application Auto Scaling scales in to the maximum capacity.</p>
     #[serde(rename = "ScalableTargetAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -557,11 +2107,132 @@ pub struct ScheduledAction {
     pub scheduled_action_name: String,
     /// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>The date and time that the action is scheduled to begin.</p>
     #[serde(rename = "StartTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<EpochTimestamp>,
+}
+
+/// <p>The namespace of the AWS service that provides the resource or <code>custom-resource</code> for a resource provided by your own application or service. For more information, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#genref-aws-service-namespaces">AWS Service Namespaces</a> in the <i>Amazon Web Services General Reference</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceNamespace {
+    /// <p><code>ecs</code></p>
+    Ecs,
+    /// <p><code>elasticmapreduce</code></p>
+    Elasticmapreduce,
+    /// <p><code>ec2</code></p>
+    Ec2,
+    /// <p><code>appstream</code></p>
+    Appstream,
+    /// <p><code>dynamodb</code></p>
+    Dynamodb,
+    /// <p><code>rds</code></p>
+    Rds,
+    /// <p><code>sagemaker</code></p>
+    Sagemaker,
+    /// <p><code>custom-resource</code></p>
+    CustomResource,
+    /// <p><code>comprehend</code></p>
+    Comprehend,
+    /// <p><code>lambda</code></p>
+    Lambda,
+    /// <p><code>cassandra</code></p>
+    Cassandra,
+    /// <p><code>kafka</code></p>
+    Kafka,
+    /// <p><code>elasticache</code></p>
+    Elasticache,
+    /// <p><code>neptune</code></p>
+    Neptune,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServiceNamespace {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceNamespace::Ecs => "ecs",
+            ServiceNamespace::Elasticmapreduce => "elasticmapreduce",
+            ServiceNamespace::Ec2 => "ec2",
+            ServiceNamespace::Appstream => "appstream",
+            ServiceNamespace::Dynamodb => "dynamodb",
+            ServiceNamespace::Rds => "rds",
+            ServiceNamespace::Sagemaker => "sagemaker",
+            ServiceNamespace::CustomResource => "custom-resource",
+            ServiceNamespace::Comprehend => "comprehend",
+            ServiceNamespace::Lambda => "lambda",
+            ServiceNamespace::Cassandra => "cassandra",
+            ServiceNamespace::Kafka => "kafka",
+            ServiceNamespace::Elasticache => "elasticache",
+            ServiceNamespace::Neptune => "neptune",
+            ServiceNamespace::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServiceNamespace {
+    fn from(value: &'a str) -> ServiceNamespace {
+        match value {
+            "ecs" => ServiceNamespace::Ecs,
+            "elasticmapreduce" => ServiceNamespace::Elasticmapreduce,
+            "ec2" => ServiceNamespace::Ec2,
+            "appstream" => ServiceNamespace::Appstream,
+            "dynamodb" => ServiceNamespace::Dynamodb,
+            "rds" => ServiceNamespace::Rds,
+            "sagemaker" => ServiceNamespace::Sagemaker,
+            "custom-resource" => ServiceNamespace::CustomResource,
+            "comprehend" => ServiceNamespace::Comprehend,
+            "lambda" => ServiceNamespace::Lambda,
+            "cassandra" => ServiceNamespace::Cassandra,
+            "kafka" => ServiceNamespace::Kafka,
+            "elasticache" => ServiceNamespace::Elasticache,
+            "neptune" => ServiceNamespace::Neptune,
+            _ => ServiceNamespace::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceNamespace {
+    fn from(value: String) -> ServiceNamespace {
+        ServiceNamespace::from(value.as_str())
+    }
+}
+
+impl From<ServiceNamespace> for String {
+    fn from(value: ServiceNamespace) -> String {
+        match value {
+            ServiceNamespace::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServiceNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ServiceNamespace {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceNamespace {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ServiceNamespace, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ServiceNamespace::from(value))
+    }
 }
 
 /// <p><p>Represents a step adjustment for a <a>StepScalingPolicyConfiguration</a>. Describes an adjustment based on the difference between the value of the aggregated CloudWatch metric and the breach threshold that you&#39;ve defined for the alarm. </p> <p>For the following examples, suppose that you have an alarm with a breach threshold of 50:</p> <ul> <li> <p>To trigger the adjustment when the metric is greater than or equal to 50 and less than 60, specify a lower bound of 0 and an upper bound of 10.</p> </li> <li> <p>To trigger the adjustment when the metric is greater than 40 and less than or equal to 50, specify a lower bound of -10 and an upper bound of 0.</p> </li> </ul> <p>There are a few rules for the step adjustments for your step policy:</p> <ul> <li> <p>The ranges of your step adjustments can&#39;t overlap or have a gap.</p> </li> <li> <p>At most one step adjustment can have a null lower bound. If one step adjustment has a negative lower bound, then there must be a step adjustment with a null lower bound.</p> </li> <li> <p>At most one step adjustment can have a null upper bound. If one step adjustment has a positive upper bound, then there must be a step adjustment with a null upper bound.</p> </li> <li> <p>The upper and lower bound can&#39;t be null in the same step adjustment.</p> </li> </ul></p>
@@ -586,7 +2257,7 @@ pub struct StepScalingPolicyConfiguration {
     /// <p>Specifies whether the <code>ScalingAdjustment</code> value in a <a>StepAdjustment</a> is an absolute number or a percentage of the current capacity. </p>
     #[serde(rename = "AdjustmentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub adjustment_type: Option<String>,
+    pub adjustment_type: Option<AdjustmentType>,
     /// <p>The amount of time, in seconds, after a scaling activity completes where previous trigger-related scaling activities can influence future scaling events.</p> <p>For scale-out policies, while the cooldown period is in effect, the capacity that has been added by the previous scale-out event that initiated the cooldown is calculated as part of the desired capacity for the next scale out. The intention is to continuously (but not excessively) scale out. For example, an alarm triggers a step scaling policy to scale out an Amazon ECS service by 2 tasks, the scaling activity completes successfully, and a cooldown period of 5 minutes starts. During the cooldown period, if the alarm triggers the same policy again but at a more aggressive step adjustment to scale out the service by 3 tasks, the 2 tasks that were added in the previous scale-out event are considered part of that capacity and only 1 additional task is added to the desired count.</p> <p>For scale-in policies, the cooldown period is used to block subsequent scale-in requests until it has expired. The intention is to scale in conservatively to protect your application's availability. However, if another alarm triggers a scale-out policy during the cooldown period after a scale-in, Application Auto Scaling scales out your scalable target immediately.</p>
     #[serde(rename = "Cooldown")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -594,7 +2265,7 @@ pub struct StepScalingPolicyConfiguration {
     /// <p>The aggregation type for the CloudWatch metrics. Valid values are <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code>. If the aggregation type is null, the value is treated as <code>Average</code>.</p>
     #[serde(rename = "MetricAggregationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metric_aggregation_type: Option<String>,
+    pub metric_aggregation_type: Option<MetricAggregationType>,
     /// <p>The minimum number to adjust your scalable dimension as a result of a scaling activity. If the adjustment type is <code>PercentChangeInCapacity</code>, the scaling policy changes the scalable dimension of the scalable target by this amount.</p> <p>For example, suppose that you create a step scaling policy to scale out an Amazon ECS service by 25 percent and you specify a <code>MinAdjustmentMagnitude</code> of 2. If the service has 4 tasks and the scaling policy is performed, 25 percent of 4 is 1. However, because you specified a <code>MinAdjustmentMagnitude</code> of 2, Application Auto Scaling scales out the service by 2 tasks.</p>
     #[serde(rename = "MinAdjustmentMagnitude")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1234,6 +2905,75 @@ pub trait ApplicationAutoScaling {
         input: RegisterScalableTargetRequest,
     ) -> RusotoFuture<RegisterScalableTargetResponse, RegisterScalableTargetError>;
 }
+impl<T: ?Sized + ApplicationAutoScaling> ApplicationAutoScaling for ::std::sync::Arc<T> {
+    fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyRequest,
+    ) -> RusotoFuture<DeleteScalingPolicyResponse, DeleteScalingPolicyError> {
+        (**self).delete_scaling_policy(input)
+    }
+    fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionRequest,
+    ) -> RusotoFuture<DeleteScheduledActionResponse, DeleteScheduledActionError> {
+        (**self).delete_scheduled_action(input)
+    }
+    fn deregister_scalable_target(
+        &self,
+        input: DeregisterScalableTargetRequest,
+    ) -> RusotoFuture<DeregisterScalableTargetResponse, DeregisterScalableTargetError> {
+        (**self).deregister_scalable_target(input)
+    }
+    fn describe_scalable_targets(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> RusotoFuture<DescribeScalableTargetsResponse, DescribeScalableTargetsError> {
+        (**self).describe_scalable_targets(input)
+    }
+    fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> RusotoFuture<DescribeScalingActivitiesResponse, DescribeScalingActivitiesError> {
+        (**self).describe_scaling_activities(input)
+    }
+    fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> RusotoFuture<DescribeScalingPoliciesResponse, DescribeScalingPoliciesError> {
+        (**self).describe_scaling_policies(input)
+    }
+    fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> RusotoFuture<DescribeScheduledActionsResponse, DescribeScheduledActionsError> {
+        (**self).describe_scheduled_actions(input)
+    }
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyRequest,
+    ) -> RusotoFuture<PutScalingPolicyResponse, PutScalingPolicyError> {
+        (**self).put_scaling_policy(input)
+    }
+    fn put_scheduled_action(
+        &self,
+        input: PutScheduledActionRequest,
+    ) -> RusotoFuture<PutScheduledActionResponse, PutScheduledActionError> {
+        (**self).put_scheduled_action(input)
+    }
+    fn register_scalable_target(
+        &self,
+        input: RegisterScalableTargetRequest,
+    ) -> RusotoFuture<RegisterScalableTargetResponse, RegisterScalableTargetError> {
+        (**self).register_scalable_target(input)
+    }
+}
+/// The error codes Application Auto Scaling retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Application Auto Scaling API.
 #[derive(Clone)]
 pub struct ApplicationAutoScalingClient {
@@ -1249,6 +2989,29 @@ impl ApplicationAutoScalingClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_APPLICATION_AUTOSCALING`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ApplicationAutoScalingClient {
+        Self::new(region::Region::from_env_for_service(
+            "APPLICATION_AUTOSCALING",
+        ))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `autoscaling`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ApplicationAutoScalingClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("autoscaling", "APPLICATION_AUTOSCALING"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1267,7 +3030,54 @@ impl ApplicationAutoScalingClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ApplicationAutoScalingClient {
-        ApplicationAutoScalingClient { client, region }
+        ApplicationAutoScalingClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "autoscaling")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "autoscaling",
+            "application-autoscaling",
+            "APPLICATION_AUTOSCALING",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ApplicationAutoScaling` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ApplicationAutoScaling + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ApplicationAutoScalingClient {
+    fn service_name(&self) -> &'static str {
+        "Application Auto Scaling"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "autoscaling"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ApplicationAutoScalingClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS App Mesh clients.
+//!
+//! Makes a single read-only `ListMeshes` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_appmesh::{AppMesh, AppMeshClient, ListMeshesInput};
+
+fn main() {
+    let client = AppMeshClient::from_env();
+    match client.list_meshes(ListMeshesInput::default()).sync() {
+        Ok(_) => println!("ListMeshes succeeded"),
+        Err(error) => {
+            eprintln!("ListMeshes failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
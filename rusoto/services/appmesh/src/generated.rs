@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>An object representing the access logging information for a virtual node.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -97,6 +98,60 @@ pub struct CreateMeshInput {
     pub tags: Option<Vec<TagRef>>,
 }
 
+impl CreateMeshInput {
+    /// Returns a builder for `CreateMeshInput`, checking its required members when built.
+    pub fn builder() -> CreateMeshInputBuilder {
+        CreateMeshInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateMeshInput`](struct.CreateMeshInput.html).
+#[derive(Default, Debug)]
+pub struct CreateMeshInputBuilder {
+    client_token: Option<String>,
+    mesh_name: Option<String>,
+    spec: Option<MeshSpec>,
+    tags: Option<Vec<TagRef>>,
+}
+
+impl CreateMeshInputBuilder {
+    /// Sets the [`client_token`](struct.CreateMeshInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.CreateMeshInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`spec`](struct.CreateMeshInput.html#structfield.spec) member.
+    pub fn spec<T: Into<MeshSpec>>(mut self, value: T) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateMeshInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<TagRef>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateMeshInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateMeshInput, ::rusoto_core::BuildError> {
+        Ok(CreateMeshInput {
+            client_token: self.client_token,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            spec: self.spec,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateMeshOutput {
@@ -133,7 +188,83 @@ pub struct CreateRouteInput {
     pub virtual_router_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl CreateRouteInput {
+    /// Returns a builder for `CreateRouteInput`, checking its required members when built.
+    pub fn builder() -> CreateRouteInputBuilder {
+        CreateRouteInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRouteInput`](struct.CreateRouteInput.html).
+#[derive(Default, Debug)]
+pub struct CreateRouteInputBuilder {
+    client_token: Option<String>,
+    mesh_name: Option<String>,
+    route_name: Option<String>,
+    spec: Option<RouteSpec>,
+    tags: Option<Vec<TagRef>>,
+    virtual_router_name: Option<String>,
+}
+
+impl CreateRouteInputBuilder {
+    /// Sets the [`client_token`](struct.CreateRouteInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.CreateRouteInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_name`](struct.CreateRouteInput.html#structfield.route_name) member.
+    pub fn route_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`spec`](struct.CreateRouteInput.html#structfield.spec) member.
+    pub fn spec<T: Into<RouteSpec>>(mut self, value: T) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateRouteInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<TagRef>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_router_name`](struct.CreateRouteInput.html#structfield.virtual_router_name) member.
+    pub fn virtual_router_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_router_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRouteInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateRouteInput, ::rusoto_core::BuildError> {
+        Ok(CreateRouteInput {
+            client_token: self.client_token,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            route_name: self
+                .route_name
+                .ok_or(::rusoto_core::BuildError::missing("route_name"))?,
+            spec: self
+                .spec
+                .ok_or(::rusoto_core::BuildError::missing("spec"))?,
+            tags: self.tags,
+            virtual_router_name: self
+                .virtual_router_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_router_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRouteOutput {
     /// <p>The full description of your mesh following the create call.</p>
@@ -166,7 +297,73 @@ pub struct CreateVirtualNodeInput {
     pub virtual_node_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl CreateVirtualNodeInput {
+    /// Returns a builder for `CreateVirtualNodeInput`, checking its required members when built.
+    pub fn builder() -> CreateVirtualNodeInputBuilder {
+        CreateVirtualNodeInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateVirtualNodeInput`](struct.CreateVirtualNodeInput.html).
+#[derive(Default, Debug)]
+pub struct CreateVirtualNodeInputBuilder {
+    client_token: Option<String>,
+    mesh_name: Option<String>,
+    spec: Option<VirtualNodeSpec>,
+    tags: Option<Vec<TagRef>>,
+    virtual_node_name: Option<String>,
+}
+
+impl CreateVirtualNodeInputBuilder {
+    /// Sets the [`client_token`](struct.CreateVirtualNodeInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.CreateVirtualNodeInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`spec`](struct.CreateVirtualNodeInput.html#structfield.spec) member.
+    pub fn spec<T: Into<VirtualNodeSpec>>(mut self, value: T) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateVirtualNodeInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<TagRef>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_node_name`](struct.CreateVirtualNodeInput.html#structfield.virtual_node_name) member.
+    pub fn virtual_node_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_node_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateVirtualNodeInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateVirtualNodeInput, ::rusoto_core::BuildError> {
+        Ok(CreateVirtualNodeInput {
+            client_token: self.client_token,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            spec: self
+                .spec
+                .ok_or(::rusoto_core::BuildError::missing("spec"))?,
+            tags: self.tags,
+            virtual_node_name: self
+                .virtual_node_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_node_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateVirtualNodeOutput {
     /// <p>The full description of your virtual node following the create call.</p>
@@ -199,7 +396,75 @@ pub struct CreateVirtualRouterInput {
     pub virtual_router_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl CreateVirtualRouterInput {
+    /// Returns a builder for `CreateVirtualRouterInput`, checking its required members when built.
+    pub fn builder() -> CreateVirtualRouterInputBuilder {
+        CreateVirtualRouterInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateVirtualRouterInput`](struct.CreateVirtualRouterInput.html).
+#[derive(Default, Debug)]
+pub struct CreateVirtualRouterInputBuilder {
+    client_token: Option<String>,
+    mesh_name: Option<String>,
+    spec: Option<VirtualRouterSpec>,
+    tags: Option<Vec<TagRef>>,
+    virtual_router_name: Option<String>,
+}
+
+impl CreateVirtualRouterInputBuilder {
+    /// Sets the [`client_token`](struct.CreateVirtualRouterInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.CreateVirtualRouterInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`spec`](struct.CreateVirtualRouterInput.html#structfield.spec) member.
+    pub fn spec<T: Into<VirtualRouterSpec>>(mut self, value: T) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateVirtualRouterInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<TagRef>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_router_name`](struct.CreateVirtualRouterInput.html#structfield.virtual_router_name) member.
+    pub fn virtual_router_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_router_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateVirtualRouterInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateVirtualRouterInput, ::rusoto_core::BuildError> {
+        Ok(CreateVirtualRouterInput {
+            client_token: self.client_token,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            spec: self
+                .spec
+                .ok_or(::rusoto_core::BuildError::missing("spec"))?,
+            tags: self.tags,
+            virtual_router_name: self
+                .virtual_router_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_router_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateVirtualRouterOutput {
     /// <p>The full description of your virtual router following the create call.</p>
@@ -232,7 +497,75 @@ pub struct CreateVirtualServiceInput {
     pub virtual_service_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl CreateVirtualServiceInput {
+    /// Returns a builder for `CreateVirtualServiceInput`, checking its required members when built.
+    pub fn builder() -> CreateVirtualServiceInputBuilder {
+        CreateVirtualServiceInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateVirtualServiceInput`](struct.CreateVirtualServiceInput.html).
+#[derive(Default, Debug)]
+pub struct CreateVirtualServiceInputBuilder {
+    client_token: Option<String>,
+    mesh_name: Option<String>,
+    spec: Option<VirtualServiceSpec>,
+    tags: Option<Vec<TagRef>>,
+    virtual_service_name: Option<String>,
+}
+
+impl CreateVirtualServiceInputBuilder {
+    /// Sets the [`client_token`](struct.CreateVirtualServiceInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.CreateVirtualServiceInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`spec`](struct.CreateVirtualServiceInput.html#structfield.spec) member.
+    pub fn spec<T: Into<VirtualServiceSpec>>(mut self, value: T) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateVirtualServiceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<TagRef>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_service_name`](struct.CreateVirtualServiceInput.html#structfield.virtual_service_name) member.
+    pub fn virtual_service_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_service_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateVirtualServiceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateVirtualServiceInput, ::rusoto_core::BuildError> {
+        Ok(CreateVirtualServiceInput {
+            client_token: self.client_token,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            spec: self
+                .spec
+                .ok_or(::rusoto_core::BuildError::missing("spec"))?,
+            tags: self.tags,
+            virtual_service_name: self
+                .virtual_service_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_service_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateVirtualServiceOutput {
     /// <p>The full description of your virtual service following the create call.</p>
@@ -247,6 +580,36 @@ pub struct DeleteMeshInput {
     pub mesh_name: String,
 }
 
+impl DeleteMeshInput {
+    /// Returns a builder for `DeleteMeshInput`, checking its required members when built.
+    pub fn builder() -> DeleteMeshInputBuilder {
+        DeleteMeshInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMeshInput`](struct.DeleteMeshInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteMeshInputBuilder {
+    mesh_name: Option<String>,
+}
+
+impl DeleteMeshInputBuilder {
+    /// Sets the [`mesh_name`](struct.DeleteMeshInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMeshInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteMeshInput, ::rusoto_core::BuildError> {
+        Ok(DeleteMeshInput {
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteMeshOutput {
@@ -268,7 +631,57 @@ pub struct DeleteRouteInput {
     pub virtual_router_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DeleteRouteInput {
+    /// Returns a builder for `DeleteRouteInput`, checking its required members when built.
+    pub fn builder() -> DeleteRouteInputBuilder {
+        DeleteRouteInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRouteInput`](struct.DeleteRouteInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteRouteInputBuilder {
+    mesh_name: Option<String>,
+    route_name: Option<String>,
+    virtual_router_name: Option<String>,
+}
+
+impl DeleteRouteInputBuilder {
+    /// Sets the [`mesh_name`](struct.DeleteRouteInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_name`](struct.DeleteRouteInput.html#structfield.route_name) member.
+    pub fn route_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_router_name`](struct.DeleteRouteInput.html#structfield.virtual_router_name) member.
+    pub fn virtual_router_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_router_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRouteInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteRouteInput, ::rusoto_core::BuildError> {
+        Ok(DeleteRouteInput {
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            route_name: self
+                .route_name
+                .ok_or(::rusoto_core::BuildError::missing("route_name"))?,
+            virtual_router_name: self
+                .virtual_router_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_router_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRouteOutput {
     /// <p>The route that was deleted.</p>
@@ -286,7 +699,47 @@ pub struct DeleteVirtualNodeInput {
     pub virtual_node_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DeleteVirtualNodeInput {
+    /// Returns a builder for `DeleteVirtualNodeInput`, checking its required members when built.
+    pub fn builder() -> DeleteVirtualNodeInputBuilder {
+        DeleteVirtualNodeInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVirtualNodeInput`](struct.DeleteVirtualNodeInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteVirtualNodeInputBuilder {
+    mesh_name: Option<String>,
+    virtual_node_name: Option<String>,
+}
+
+impl DeleteVirtualNodeInputBuilder {
+    /// Sets the [`mesh_name`](struct.DeleteVirtualNodeInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_node_name`](struct.DeleteVirtualNodeInput.html#structfield.virtual_node_name) member.
+    pub fn virtual_node_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_node_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVirtualNodeInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteVirtualNodeInput, ::rusoto_core::BuildError> {
+        Ok(DeleteVirtualNodeInput {
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            virtual_node_name: self
+                .virtual_node_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_node_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteVirtualNodeOutput {
     /// <p>The virtual node that was deleted.</p>
@@ -304,7 +757,49 @@ pub struct DeleteVirtualRouterInput {
     pub virtual_router_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DeleteVirtualRouterInput {
+    /// Returns a builder for `DeleteVirtualRouterInput`, checking its required members when built.
+    pub fn builder() -> DeleteVirtualRouterInputBuilder {
+        DeleteVirtualRouterInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVirtualRouterInput`](struct.DeleteVirtualRouterInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteVirtualRouterInputBuilder {
+    mesh_name: Option<String>,
+    virtual_router_name: Option<String>,
+}
+
+impl DeleteVirtualRouterInputBuilder {
+    /// Sets the [`mesh_name`](struct.DeleteVirtualRouterInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_router_name`](struct.DeleteVirtualRouterInput.html#structfield.virtual_router_name) member.
+    pub fn virtual_router_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_router_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVirtualRouterInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVirtualRouterInput, ::rusoto_core::BuildError> {
+        Ok(DeleteVirtualRouterInput {
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            virtual_router_name: self
+                .virtual_router_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_router_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteVirtualRouterOutput {
     /// <p>The virtual router that was deleted.</p>
@@ -322,7 +817,49 @@ pub struct DeleteVirtualServiceInput {
     pub virtual_service_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DeleteVirtualServiceInput {
+    /// Returns a builder for `DeleteVirtualServiceInput`, checking its required members when built.
+    pub fn builder() -> DeleteVirtualServiceInputBuilder {
+        DeleteVirtualServiceInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVirtualServiceInput`](struct.DeleteVirtualServiceInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteVirtualServiceInputBuilder {
+    mesh_name: Option<String>,
+    virtual_service_name: Option<String>,
+}
+
+impl DeleteVirtualServiceInputBuilder {
+    /// Sets the [`mesh_name`](struct.DeleteVirtualServiceInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_service_name`](struct.DeleteVirtualServiceInput.html#structfield.virtual_service_name) member.
+    pub fn virtual_service_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_service_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVirtualServiceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVirtualServiceInput, ::rusoto_core::BuildError> {
+        Ok(DeleteVirtualServiceInput {
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            virtual_service_name: self
+                .virtual_service_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_service_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteVirtualServiceOutput {
     /// <p>The virtual service that was deleted.</p>
@@ -337,6 +874,36 @@ pub struct DescribeMeshInput {
     pub mesh_name: String,
 }
 
+impl DescribeMeshInput {
+    /// Returns a builder for `DescribeMeshInput`, checking its required members when built.
+    pub fn builder() -> DescribeMeshInputBuilder {
+        DescribeMeshInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeMeshInput`](struct.DescribeMeshInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeMeshInputBuilder {
+    mesh_name: Option<String>,
+}
+
+impl DescribeMeshInputBuilder {
+    /// Sets the [`mesh_name`](struct.DescribeMeshInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeMeshInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeMeshInput, ::rusoto_core::BuildError> {
+        Ok(DescribeMeshInput {
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeMeshOutput {
@@ -358,7 +925,57 @@ pub struct DescribeRouteInput {
     pub virtual_router_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DescribeRouteInput {
+    /// Returns a builder for `DescribeRouteInput`, checking its required members when built.
+    pub fn builder() -> DescribeRouteInputBuilder {
+        DescribeRouteInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRouteInput`](struct.DescribeRouteInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeRouteInputBuilder {
+    mesh_name: Option<String>,
+    route_name: Option<String>,
+    virtual_router_name: Option<String>,
+}
+
+impl DescribeRouteInputBuilder {
+    /// Sets the [`mesh_name`](struct.DescribeRouteInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_name`](struct.DescribeRouteInput.html#structfield.route_name) member.
+    pub fn route_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_router_name`](struct.DescribeRouteInput.html#structfield.virtual_router_name) member.
+    pub fn virtual_router_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_router_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRouteInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeRouteInput, ::rusoto_core::BuildError> {
+        Ok(DescribeRouteInput {
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            route_name: self
+                .route_name
+                .ok_or(::rusoto_core::BuildError::missing("route_name"))?,
+            virtual_router_name: self
+                .virtual_router_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_router_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeRouteOutput {
     /// <p>The full description of your route.</p>
@@ -376,7 +993,49 @@ pub struct DescribeVirtualNodeInput {
     pub virtual_node_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DescribeVirtualNodeInput {
+    /// Returns a builder for `DescribeVirtualNodeInput`, checking its required members when built.
+    pub fn builder() -> DescribeVirtualNodeInputBuilder {
+        DescribeVirtualNodeInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeVirtualNodeInput`](struct.DescribeVirtualNodeInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeVirtualNodeInputBuilder {
+    mesh_name: Option<String>,
+    virtual_node_name: Option<String>,
+}
+
+impl DescribeVirtualNodeInputBuilder {
+    /// Sets the [`mesh_name`](struct.DescribeVirtualNodeInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_node_name`](struct.DescribeVirtualNodeInput.html#structfield.virtual_node_name) member.
+    pub fn virtual_node_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_node_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeVirtualNodeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeVirtualNodeInput, ::rusoto_core::BuildError> {
+        Ok(DescribeVirtualNodeInput {
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            virtual_node_name: self
+                .virtual_node_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_node_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeVirtualNodeOutput {
     /// <p>The full description of your virtual node.</p>
@@ -394,7 +1053,49 @@ pub struct DescribeVirtualRouterInput {
     pub virtual_router_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DescribeVirtualRouterInput {
+    /// Returns a builder for `DescribeVirtualRouterInput`, checking its required members when built.
+    pub fn builder() -> DescribeVirtualRouterInputBuilder {
+        DescribeVirtualRouterInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeVirtualRouterInput`](struct.DescribeVirtualRouterInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeVirtualRouterInputBuilder {
+    mesh_name: Option<String>,
+    virtual_router_name: Option<String>,
+}
+
+impl DescribeVirtualRouterInputBuilder {
+    /// Sets the [`mesh_name`](struct.DescribeVirtualRouterInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_router_name`](struct.DescribeVirtualRouterInput.html#structfield.virtual_router_name) member.
+    pub fn virtual_router_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_router_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeVirtualRouterInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeVirtualRouterInput, ::rusoto_core::BuildError> {
+        Ok(DescribeVirtualRouterInput {
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            virtual_router_name: self
+                .virtual_router_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_router_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeVirtualRouterOutput {
     /// <p>The full description of your virtual router.</p>
@@ -412,7 +1113,49 @@ pub struct DescribeVirtualServiceInput {
     pub virtual_service_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DescribeVirtualServiceInput {
+    /// Returns a builder for `DescribeVirtualServiceInput`, checking its required members when built.
+    pub fn builder() -> DescribeVirtualServiceInputBuilder {
+        DescribeVirtualServiceInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeVirtualServiceInput`](struct.DescribeVirtualServiceInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeVirtualServiceInputBuilder {
+    mesh_name: Option<String>,
+    virtual_service_name: Option<String>,
+}
+
+impl DescribeVirtualServiceInputBuilder {
+    /// Sets the [`mesh_name`](struct.DescribeVirtualServiceInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_service_name`](struct.DescribeVirtualServiceInput.html#structfield.virtual_service_name) member.
+    pub fn virtual_service_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_service_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeVirtualServiceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeVirtualServiceInput, ::rusoto_core::BuildError> {
+        Ok(DescribeVirtualServiceInput {
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            virtual_service_name: self
+                .virtual_service_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_service_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeVirtualServiceOutput {
     /// <p>The full description of your virtual service.</p>
@@ -435,15 +1178,93 @@ pub struct Duration {
     /// <p>The unit of time between retry attempts.</p>
     #[serde(rename = "unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<DurationUnit>,
     /// <p>The duration of time between retry attempts.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<i64>,
 }
 
+/// <p>The unit of time between retry attempts.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DurationUnit {
+    /// <p><code>s</code></p>
+    S,
+    /// <p><code>ms</code></p>
+    Ms,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DurationUnit {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DurationUnit::S => "s",
+            DurationUnit::Ms => "ms",
+            DurationUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DurationUnit {
+    fn from(value: &'a str) -> DurationUnit {
+        match value {
+            "s" => DurationUnit::S,
+            "ms" => DurationUnit::Ms,
+            _ => DurationUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DurationUnit {
+    fn from(value: String) -> DurationUnit {
+        DurationUnit::from(value.as_str())
+    }
+}
+
+impl From<DurationUnit> for String {
+    fn from(value: DurationUnit) -> String {
+        match value {
+            DurationUnit::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DurationUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DurationUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DurationUnit {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DurationUnit {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DurationUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DurationUnit::from(value))
+    }
+}
+
 /// <p>An object representing the egress filter rules for a service mesh.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EgressFilter {
     /// <p>The egress filter type. By default, the type is <code>DROP_ALL</code>, which allows
     /// egress only from virtual nodes to other defined resources in the service mesh (and any
@@ -451,7 +1272,89 @@ pub struct EgressFilter {
     /// type to <code>ALLOW_ALL</code> to allow egress to any endpoint inside or outside of the
     /// service mesh.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: EgressFilterType,
+}
+
+/// <p>The egress filter type. By default, the type is <code>DROP_ALL</code>, which allows
+/// egress only from virtual nodes to other defined resources in the service mesh (and any
+/// traffic to <code>*.amazonaws.com</code> for AWS API calls). You can set the egress filter
+/// type to <code>ALLOW_ALL</code> to allow egress to any endpoint inside or outside of the
+/// service mesh.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EgressFilterType {
+    /// <p><code>ALLOW_ALL</code></p>
+    AllowAll,
+    /// <p><code>DROP_ALL</code></p>
+    DropAll,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EgressFilterType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EgressFilterType::AllowAll => "ALLOW_ALL",
+            EgressFilterType::DropAll => "DROP_ALL",
+            EgressFilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EgressFilterType {
+    fn from(value: &'a str) -> EgressFilterType {
+        match value {
+            "ALLOW_ALL" => EgressFilterType::AllowAll,
+            "DROP_ALL" => EgressFilterType::DropAll,
+            _ => EgressFilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EgressFilterType {
+    fn from(value: String) -> EgressFilterType {
+        EgressFilterType::from(value.as_str())
+    }
+}
+
+impl From<EgressFilterType> for String {
+    fn from(value: EgressFilterType) -> String {
+        match value {
+            EgressFilterType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EgressFilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EgressFilterType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EgressFilterType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EgressFilterType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EgressFilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EgressFilterType::from(value))
+    }
 }
 
 /// <p>An object representing an access log file.</p>
@@ -498,7 +1401,7 @@ pub struct HeaderMatchMethod {
 }
 
 /// <p>An object representing the health check policy for a virtual node's listener.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HealthCheckPolicy {
     /// <p>The number of consecutive successful health checks that must occur before declaring
     /// listener healthy.</p>
@@ -519,7 +1422,7 @@ pub struct HealthCheckPolicy {
     pub port: Option<i64>,
     /// <p>The protocol for the health check request.</p>
     #[serde(rename = "protocol")]
-    pub protocol: String,
+    pub protocol: PortProtocol,
     /// <p>The amount of time to wait when receiving a response from the health check, in
     /// milliseconds.</p>
     #[serde(rename = "timeoutMillis")]
@@ -530,6 +1433,112 @@ pub struct HealthCheckPolicy {
     pub unhealthy_threshold: i64,
 }
 
+/// <p>The client request header method to match on.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HttpMethod {
+    /// <p><code>GET</code></p>
+    Get,
+    /// <p><code>HEAD</code></p>
+    Head,
+    /// <p><code>POST</code></p>
+    Post,
+    /// <p><code>PUT</code></p>
+    Put,
+    /// <p><code>DELETE</code></p>
+    Delete,
+    /// <p><code>CONNECT</code></p>
+    Connect,
+    /// <p><code>OPTIONS</code></p>
+    Options,
+    /// <p><code>TRACE</code></p>
+    Trace,
+    /// <p><code>PATCH</code></p>
+    Patch,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HttpMethod {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HttpMethod::Get => "GET",
+            HttpMethod::Head => "HEAD",
+            HttpMethod::Post => "POST",
+            HttpMethod::Put => "PUT",
+            HttpMethod::Delete => "DELETE",
+            HttpMethod::Connect => "CONNECT",
+            HttpMethod::Options => "OPTIONS",
+            HttpMethod::Trace => "TRACE",
+            HttpMethod::Patch => "PATCH",
+            HttpMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HttpMethod {
+    fn from(value: &'a str) -> HttpMethod {
+        match value {
+            "GET" => HttpMethod::Get,
+            "HEAD" => HttpMethod::Head,
+            "POST" => HttpMethod::Post,
+            "PUT" => HttpMethod::Put,
+            "DELETE" => HttpMethod::Delete,
+            "CONNECT" => HttpMethod::Connect,
+            "OPTIONS" => HttpMethod::Options,
+            "TRACE" => HttpMethod::Trace,
+            "PATCH" => HttpMethod::Patch,
+            _ => HttpMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HttpMethod {
+    fn from(value: String) -> HttpMethod {
+        HttpMethod::from(value.as_str())
+    }
+}
+
+impl From<HttpMethod> for String {
+    fn from(value: HttpMethod) -> String {
+        match value {
+            HttpMethod::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HttpMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HttpMethod {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HttpMethod {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HttpMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HttpMethod::from(value))
+    }
+}
+
 /// <p>An object that represents a retry policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HttpRetryPolicy {
@@ -569,7 +1578,7 @@ pub struct HttpRetryPolicy {
     /// <p>Specify a valid value.</p>
     #[serde(rename = "tcpRetryEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tcp_retry_events: Option<Vec<String>>,
+    pub tcp_retry_events: Option<Vec<TcpRetryPolicyEvent>>,
 }
 
 /// <p>An object representing the HTTP routing specification for a route.</p>
@@ -624,7 +1633,7 @@ pub struct HttpRouteMatch {
     /// <p>The client request header method to match on.</p>
     #[serde(rename = "method")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub method: Option<String>,
+    pub method: Option<HttpMethod>,
     /// <p>Specifies the path to match requests with. This parameter must always start with
     /// <code>/</code>, which by itself matches all requests to the virtual service name. You
     /// can also match for path-based routing of requests. For example, if your virtual service
@@ -636,7 +1645,85 @@ pub struct HttpRouteMatch {
     /// <p>The client request header scheme to match on.</p>
     #[serde(rename = "scheme")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheme: Option<String>,
+    pub scheme: Option<HttpScheme>,
+}
+
+/// <p>The client request header scheme to match on.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HttpScheme {
+    /// <p><code>http</code></p>
+    Http,
+    /// <p><code>https</code></p>
+    Https,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HttpScheme {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HttpScheme::Http => "http",
+            HttpScheme::Https => "https",
+            HttpScheme::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HttpScheme {
+    fn from(value: &'a str) -> HttpScheme {
+        match value {
+            "http" => HttpScheme::Http,
+            "https" => HttpScheme::Https,
+            _ => HttpScheme::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HttpScheme {
+    fn from(value: String) -> HttpScheme {
+        HttpScheme::from(value.as_str())
+    }
+}
+
+impl From<HttpScheme> for String {
+    fn from(value: HttpScheme) -> String {
+        match value {
+            HttpScheme::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HttpScheme {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HttpScheme {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HttpScheme {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HttpScheme {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HttpScheme, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HttpScheme::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -667,6 +1754,42 @@ pub struct ListMeshesInput {
     pub next_token: Option<String>,
 }
 
+impl ListMeshesInput {
+    /// Returns a builder for `ListMeshesInput`, checking its required members when built.
+    pub fn builder() -> ListMeshesInputBuilder {
+        ListMeshesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListMeshesInput`](struct.ListMeshesInput.html).
+#[derive(Default, Debug)]
+pub struct ListMeshesInputBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListMeshesInputBuilder {
+    /// Sets the [`limit`](struct.ListMeshesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListMeshesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListMeshesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListMeshesInput, ::rusoto_core::BuildError> {
+        Ok(ListMeshesInput {
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListMeshesOutput {
@@ -710,6 +1833,62 @@ pub struct ListRoutesInput {
     pub virtual_router_name: String,
 }
 
+impl ListRoutesInput {
+    /// Returns a builder for `ListRoutesInput`, checking its required members when built.
+    pub fn builder() -> ListRoutesInputBuilder {
+        ListRoutesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListRoutesInput`](struct.ListRoutesInput.html).
+#[derive(Default, Debug)]
+pub struct ListRoutesInputBuilder {
+    limit: Option<i64>,
+    mesh_name: Option<String>,
+    next_token: Option<String>,
+    virtual_router_name: Option<String>,
+}
+
+impl ListRoutesInputBuilder {
+    /// Sets the [`limit`](struct.ListRoutesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.ListRoutesInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListRoutesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_router_name`](struct.ListRoutesInput.html#structfield.virtual_router_name) member.
+    pub fn virtual_router_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_router_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRoutesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListRoutesInput, ::rusoto_core::BuildError> {
+        Ok(ListRoutesInput {
+            limit: self.limit,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            next_token: self.next_token,
+            virtual_router_name: self
+                .virtual_router_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_router_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRoutesOutput {
@@ -750,6 +1929,54 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceInput {
+    /// Returns a builder for `ListTagsForResourceInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceInputBuilder {
+        ListTagsForResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceInput`](struct.ListTagsForResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceInputBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceInputBuilder {
+    /// Sets the [`limit`](struct.ListTagsForResourceInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsForResourceInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceInput {
+            limit: self.limit,
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceOutput {
@@ -790,6 +2017,52 @@ pub struct ListVirtualNodesInput {
     pub next_token: Option<String>,
 }
 
+impl ListVirtualNodesInput {
+    /// Returns a builder for `ListVirtualNodesInput`, checking its required members when built.
+    pub fn builder() -> ListVirtualNodesInputBuilder {
+        ListVirtualNodesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListVirtualNodesInput`](struct.ListVirtualNodesInput.html).
+#[derive(Default, Debug)]
+pub struct ListVirtualNodesInputBuilder {
+    limit: Option<i64>,
+    mesh_name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListVirtualNodesInputBuilder {
+    /// Sets the [`limit`](struct.ListVirtualNodesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.ListVirtualNodesInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListVirtualNodesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVirtualNodesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListVirtualNodesInput, ::rusoto_core::BuildError> {
+        Ok(ListVirtualNodesInput {
+            limit: self.limit,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListVirtualNodesOutput {
@@ -830,6 +2103,54 @@ pub struct ListVirtualRoutersInput {
     pub next_token: Option<String>,
 }
 
+impl ListVirtualRoutersInput {
+    /// Returns a builder for `ListVirtualRoutersInput`, checking its required members when built.
+    pub fn builder() -> ListVirtualRoutersInputBuilder {
+        ListVirtualRoutersInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListVirtualRoutersInput`](struct.ListVirtualRoutersInput.html).
+#[derive(Default, Debug)]
+pub struct ListVirtualRoutersInputBuilder {
+    limit: Option<i64>,
+    mesh_name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListVirtualRoutersInputBuilder {
+    /// Sets the [`limit`](struct.ListVirtualRoutersInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.ListVirtualRoutersInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListVirtualRoutersInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVirtualRoutersInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListVirtualRoutersInput, ::rusoto_core::BuildError> {
+        Ok(ListVirtualRoutersInput {
+            limit: self.limit,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListVirtualRoutersOutput {
@@ -870,6 +2191,54 @@ pub struct ListVirtualServicesInput {
     pub next_token: Option<String>,
 }
 
+impl ListVirtualServicesInput {
+    /// Returns a builder for `ListVirtualServicesInput`, checking its required members when built.
+    pub fn builder() -> ListVirtualServicesInputBuilder {
+        ListVirtualServicesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListVirtualServicesInput`](struct.ListVirtualServicesInput.html).
+#[derive(Default, Debug)]
+pub struct ListVirtualServicesInputBuilder {
+    limit: Option<i64>,
+    mesh_name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListVirtualServicesInputBuilder {
+    /// Sets the [`limit`](struct.ListVirtualServicesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.ListVirtualServicesInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListVirtualServicesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVirtualServicesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListVirtualServicesInput, ::rusoto_core::BuildError> {
+        Ok(ListVirtualServicesInput {
+            limit: self.limit,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListVirtualServicesOutput {
@@ -886,7 +2255,7 @@ pub struct ListVirtualServicesOutput {
 }
 
 /// <p>An object representing a listener for a virtual node.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Listener {
     /// <p>The health check information for the listener.</p>
     #[serde(rename = "healthCheck")]
@@ -963,18 +2332,186 @@ pub struct MeshStatus {
     /// <p>The current mesh status.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<MeshStatusCode>,
+}
+
+/// <p>The current mesh status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MeshStatusCode {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MeshStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MeshStatusCode::Active => "ACTIVE",
+            MeshStatusCode::Inactive => "INACTIVE",
+            MeshStatusCode::Deleted => "DELETED",
+            MeshStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MeshStatusCode {
+    fn from(value: &'a str) -> MeshStatusCode {
+        match value {
+            "ACTIVE" => MeshStatusCode::Active,
+            "INACTIVE" => MeshStatusCode::Inactive,
+            "DELETED" => MeshStatusCode::Deleted,
+            _ => MeshStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MeshStatusCode {
+    fn from(value: String) -> MeshStatusCode {
+        MeshStatusCode::from(value.as_str())
+    }
+}
+
+impl From<MeshStatusCode> for String {
+    fn from(value: MeshStatusCode) -> String {
+        match value {
+            MeshStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MeshStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MeshStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MeshStatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MeshStatusCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MeshStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MeshStatusCode::from(value))
+    }
 }
 
 /// <p>An object representing a virtual node or virtual router listener port mapping.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PortMapping {
     /// <p>The port used for the port mapping.</p>
     #[serde(rename = "port")]
     pub port: i64,
     /// <p>The protocol used for the port mapping.</p>
     #[serde(rename = "protocol")]
-    pub protocol: String,
+    pub protocol: PortProtocol,
+}
+
+/// <p>The protocol for the health check request.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PortProtocol {
+    /// <p><code>http</code></p>
+    Http,
+    /// <p><code>tcp</code></p>
+    Tcp,
+    /// <p><code>http2</code></p>
+    Http2,
+    /// <p><code>grpc</code></p>
+    Grpc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PortProtocol {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PortProtocol::Http => "http",
+            PortProtocol::Tcp => "tcp",
+            PortProtocol::Http2 => "http2",
+            PortProtocol::Grpc => "grpc",
+            PortProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PortProtocol {
+    fn from(value: &'a str) -> PortProtocol {
+        match value {
+            "http" => PortProtocol::Http,
+            "tcp" => PortProtocol::Tcp,
+            "http2" => PortProtocol::Http2,
+            "grpc" => PortProtocol::Grpc,
+            _ => PortProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PortProtocol {
+    fn from(value: String) -> PortProtocol {
+        PortProtocol::from(value.as_str())
+    }
+}
+
+impl From<PortProtocol> for String {
+    fn from(value: PortProtocol) -> String {
+        match value {
+            PortProtocol::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PortProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PortProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PortProtocol {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PortProtocol {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PortProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PortProtocol::from(value))
+    }
 }
 
 /// <p>An object representing metadata for a resource.</p>
@@ -986,10 +2523,12 @@ pub struct ResourceMetadata {
     pub arn: String,
     /// <p>The Unix epoch timestamp in seconds for when the resource was created.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub created_at: EpochTimestamp,
     /// <p>The Unix epoch timestamp in seconds for when the resource was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
-    pub last_updated_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub last_updated_at: EpochTimestamp,
     /// <p>The unique identifier for the resource.</p>
     #[serde(rename = "uid")]
     pub uid: String,
@@ -1000,7 +2539,7 @@ pub struct ResourceMetadata {
 }
 
 /// <p>An object representing a route returned by a describe operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RouteData {
     /// <p>The name of the service mesh that the route resides in.</p>
@@ -1059,12 +2598,94 @@ pub struct RouteSpec {
 }
 
 /// <p>An object representing the current status of a route.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RouteStatus {
     /// <p>The current status for the route.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: RouteStatusCode,
+}
+
+/// <p>The current status for the route.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RouteStatusCode {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RouteStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RouteStatusCode::Active => "ACTIVE",
+            RouteStatusCode::Inactive => "INACTIVE",
+            RouteStatusCode::Deleted => "DELETED",
+            RouteStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RouteStatusCode {
+    fn from(value: &'a str) -> RouteStatusCode {
+        match value {
+            "ACTIVE" => RouteStatusCode::Active,
+            "INACTIVE" => RouteStatusCode::Inactive,
+            "DELETED" => RouteStatusCode::Deleted,
+            _ => RouteStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RouteStatusCode {
+    fn from(value: String) -> RouteStatusCode {
+        RouteStatusCode::from(value.as_str())
+    }
+}
+
+impl From<RouteStatusCode> for String {
+    fn from(value: RouteStatusCode) -> String {
+        match value {
+            RouteStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RouteStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RouteStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RouteStatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RouteStatusCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RouteStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RouteStatusCode::from(value))
+    }
 }
 
 /// <p>An object representing the service discovery information for a virtual node.</p>
@@ -1109,10 +2730,124 @@ pub struct TagResourceInput {
     pub tags: Vec<TagRef>,
 }
 
+impl TagResourceInput {
+    /// Returns a builder for `TagResourceInput`, checking its required members when built.
+    pub fn builder() -> TagResourceInputBuilder {
+        TagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceInput`](struct.TagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct TagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<TagRef>>,
+}
+
+impl TagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<TagRef>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceInput, ::rusoto_core::BuildError> {
+        Ok(TagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceOutput {}
 
+/// <p>Specify a valid value.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TcpRetryPolicyEvent {
+    /// <p><code>connection-error</code></p>
+    ConnectionError,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TcpRetryPolicyEvent {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TcpRetryPolicyEvent::ConnectionError => "connection-error",
+            TcpRetryPolicyEvent::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TcpRetryPolicyEvent {
+    fn from(value: &'a str) -> TcpRetryPolicyEvent {
+        match value {
+            "connection-error" => TcpRetryPolicyEvent::ConnectionError,
+            _ => TcpRetryPolicyEvent::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TcpRetryPolicyEvent {
+    fn from(value: String) -> TcpRetryPolicyEvent {
+        TcpRetryPolicyEvent::from(value.as_str())
+    }
+}
+
+impl From<TcpRetryPolicyEvent> for String {
+    fn from(value: TcpRetryPolicyEvent) -> String {
+        match value {
+            TcpRetryPolicyEvent::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TcpRetryPolicyEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TcpRetryPolicyEvent {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TcpRetryPolicyEvent {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TcpRetryPolicyEvent {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TcpRetryPolicyEvent, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TcpRetryPolicyEvent::from(value))
+    }
+}
+
 /// <p>An object representing the TCP routing specification for a route.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TcpRoute {
@@ -1141,6 +2876,46 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceInput {
+    /// Returns a builder for `UntagResourceInput`, checking its required members when built.
+    pub fn builder() -> UntagResourceInputBuilder {
+        UntagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceInput`](struct.UntagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceInput, ::rusoto_core::BuildError> {
+        Ok(UntagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceOutput {}
@@ -1161,6 +2936,52 @@ pub struct UpdateMeshInput {
     pub spec: Option<MeshSpec>,
 }
 
+impl UpdateMeshInput {
+    /// Returns a builder for `UpdateMeshInput`, checking its required members when built.
+    pub fn builder() -> UpdateMeshInputBuilder {
+        UpdateMeshInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateMeshInput`](struct.UpdateMeshInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateMeshInputBuilder {
+    client_token: Option<String>,
+    mesh_name: Option<String>,
+    spec: Option<MeshSpec>,
+}
+
+impl UpdateMeshInputBuilder {
+    /// Sets the [`client_token`](struct.UpdateMeshInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.UpdateMeshInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`spec`](struct.UpdateMeshInput.html#structfield.spec) member.
+    pub fn spec<T: Into<MeshSpec>>(mut self, value: T) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateMeshInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateMeshInput, ::rusoto_core::BuildError> {
+        Ok(UpdateMeshInput {
+            client_token: self.client_token,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            spec: self.spec,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateMeshOutput {
@@ -1189,7 +3010,75 @@ pub struct UpdateRouteInput {
     pub virtual_router_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl UpdateRouteInput {
+    /// Returns a builder for `UpdateRouteInput`, checking its required members when built.
+    pub fn builder() -> UpdateRouteInputBuilder {
+        UpdateRouteInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRouteInput`](struct.UpdateRouteInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateRouteInputBuilder {
+    client_token: Option<String>,
+    mesh_name: Option<String>,
+    route_name: Option<String>,
+    spec: Option<RouteSpec>,
+    virtual_router_name: Option<String>,
+}
+
+impl UpdateRouteInputBuilder {
+    /// Sets the [`client_token`](struct.UpdateRouteInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.UpdateRouteInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`route_name`](struct.UpdateRouteInput.html#structfield.route_name) member.
+    pub fn route_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.route_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`spec`](struct.UpdateRouteInput.html#structfield.spec) member.
+    pub fn spec<T: Into<RouteSpec>>(mut self, value: T) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_router_name`](struct.UpdateRouteInput.html#structfield.virtual_router_name) member.
+    pub fn virtual_router_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_router_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRouteInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateRouteInput, ::rusoto_core::BuildError> {
+        Ok(UpdateRouteInput {
+            client_token: self.client_token,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            route_name: self
+                .route_name
+                .ok_or(::rusoto_core::BuildError::missing("route_name"))?,
+            spec: self
+                .spec
+                .ok_or(::rusoto_core::BuildError::missing("spec"))?,
+            virtual_router_name: self
+                .virtual_router_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_router_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRouteOutput {
     /// <p>A full description of the route that was updated.</p>
@@ -1215,7 +3104,65 @@ pub struct UpdateVirtualNodeInput {
     pub virtual_node_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl UpdateVirtualNodeInput {
+    /// Returns a builder for `UpdateVirtualNodeInput`, checking its required members when built.
+    pub fn builder() -> UpdateVirtualNodeInputBuilder {
+        UpdateVirtualNodeInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateVirtualNodeInput`](struct.UpdateVirtualNodeInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateVirtualNodeInputBuilder {
+    client_token: Option<String>,
+    mesh_name: Option<String>,
+    spec: Option<VirtualNodeSpec>,
+    virtual_node_name: Option<String>,
+}
+
+impl UpdateVirtualNodeInputBuilder {
+    /// Sets the [`client_token`](struct.UpdateVirtualNodeInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.UpdateVirtualNodeInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`spec`](struct.UpdateVirtualNodeInput.html#structfield.spec) member.
+    pub fn spec<T: Into<VirtualNodeSpec>>(mut self, value: T) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_node_name`](struct.UpdateVirtualNodeInput.html#structfield.virtual_node_name) member.
+    pub fn virtual_node_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_node_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateVirtualNodeInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateVirtualNodeInput, ::rusoto_core::BuildError> {
+        Ok(UpdateVirtualNodeInput {
+            client_token: self.client_token,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            spec: self
+                .spec
+                .ok_or(::rusoto_core::BuildError::missing("spec"))?,
+            virtual_node_name: self
+                .virtual_node_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_node_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateVirtualNodeOutput {
     /// <p>A full description of the virtual node that was updated.</p>
@@ -1241,7 +3188,67 @@ pub struct UpdateVirtualRouterInput {
     pub virtual_router_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl UpdateVirtualRouterInput {
+    /// Returns a builder for `UpdateVirtualRouterInput`, checking its required members when built.
+    pub fn builder() -> UpdateVirtualRouterInputBuilder {
+        UpdateVirtualRouterInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateVirtualRouterInput`](struct.UpdateVirtualRouterInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateVirtualRouterInputBuilder {
+    client_token: Option<String>,
+    mesh_name: Option<String>,
+    spec: Option<VirtualRouterSpec>,
+    virtual_router_name: Option<String>,
+}
+
+impl UpdateVirtualRouterInputBuilder {
+    /// Sets the [`client_token`](struct.UpdateVirtualRouterInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.UpdateVirtualRouterInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`spec`](struct.UpdateVirtualRouterInput.html#structfield.spec) member.
+    pub fn spec<T: Into<VirtualRouterSpec>>(mut self, value: T) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_router_name`](struct.UpdateVirtualRouterInput.html#structfield.virtual_router_name) member.
+    pub fn virtual_router_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_router_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateVirtualRouterInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateVirtualRouterInput, ::rusoto_core::BuildError> {
+        Ok(UpdateVirtualRouterInput {
+            client_token: self.client_token,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            spec: self
+                .spec
+                .ok_or(::rusoto_core::BuildError::missing("spec"))?,
+            virtual_router_name: self
+                .virtual_router_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_router_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateVirtualRouterOutput {
     /// <p>A full description of the virtual router that was updated.</p>
@@ -1268,7 +3275,67 @@ pub struct UpdateVirtualServiceInput {
     pub virtual_service_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl UpdateVirtualServiceInput {
+    /// Returns a builder for `UpdateVirtualServiceInput`, checking its required members when built.
+    pub fn builder() -> UpdateVirtualServiceInputBuilder {
+        UpdateVirtualServiceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateVirtualServiceInput`](struct.UpdateVirtualServiceInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateVirtualServiceInputBuilder {
+    client_token: Option<String>,
+    mesh_name: Option<String>,
+    spec: Option<VirtualServiceSpec>,
+    virtual_service_name: Option<String>,
+}
+
+impl UpdateVirtualServiceInputBuilder {
+    /// Sets the [`client_token`](struct.UpdateVirtualServiceInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mesh_name`](struct.UpdateVirtualServiceInput.html#structfield.mesh_name) member.
+    pub fn mesh_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.mesh_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`spec`](struct.UpdateVirtualServiceInput.html#structfield.spec) member.
+    pub fn spec<T: Into<VirtualServiceSpec>>(mut self, value: T) -> Self {
+        self.spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_service_name`](struct.UpdateVirtualServiceInput.html#structfield.virtual_service_name) member.
+    pub fn virtual_service_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_service_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateVirtualServiceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateVirtualServiceInput, ::rusoto_core::BuildError> {
+        Ok(UpdateVirtualServiceInput {
+            client_token: self.client_token,
+            mesh_name: self
+                .mesh_name
+                .ok_or(::rusoto_core::BuildError::missing("mesh_name"))?,
+            spec: self
+                .spec
+                .ok_or(::rusoto_core::BuildError::missing("spec"))?,
+            virtual_service_name: self
+                .virtual_service_name
+                .ok_or(::rusoto_core::BuildError::missing("virtual_service_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateVirtualServiceOutput {
     /// <p>A full description of the virtual service that was updated.</p>
@@ -1277,7 +3344,7 @@ pub struct UpdateVirtualServiceOutput {
 }
 
 /// <p>An object representing a virtual node returned by a describe operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct VirtualNodeData {
     /// <p>The name of the service mesh that the virtual node resides in.</p>
@@ -1343,17 +3410,99 @@ pub struct VirtualNodeSpec {
     pub service_discovery: Option<ServiceDiscovery>,
 }
 
-/// <p>An object representing the current status of the virtual node.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct VirtualNodeStatus {
-    /// <p>The current status of the virtual node.</p>
-    #[serde(rename = "status")]
-    pub status: String,
+/// <p>An object representing the current status of the virtual node.</p>
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct VirtualNodeStatus {
+    /// <p>The current status of the virtual node.</p>
+    #[serde(rename = "status")]
+    pub status: VirtualNodeStatusCode,
+}
+
+/// <p>The current status of the virtual node.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VirtualNodeStatusCode {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VirtualNodeStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VirtualNodeStatusCode::Active => "ACTIVE",
+            VirtualNodeStatusCode::Inactive => "INACTIVE",
+            VirtualNodeStatusCode::Deleted => "DELETED",
+            VirtualNodeStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VirtualNodeStatusCode {
+    fn from(value: &'a str) -> VirtualNodeStatusCode {
+        match value {
+            "ACTIVE" => VirtualNodeStatusCode::Active,
+            "INACTIVE" => VirtualNodeStatusCode::Inactive,
+            "DELETED" => VirtualNodeStatusCode::Deleted,
+            _ => VirtualNodeStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VirtualNodeStatusCode {
+    fn from(value: String) -> VirtualNodeStatusCode {
+        VirtualNodeStatusCode::from(value.as_str())
+    }
+}
+
+impl From<VirtualNodeStatusCode> for String {
+    fn from(value: VirtualNodeStatusCode) -> String {
+        match value {
+            VirtualNodeStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VirtualNodeStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VirtualNodeStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VirtualNodeStatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VirtualNodeStatusCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VirtualNodeStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VirtualNodeStatusCode::from(value))
+    }
 }
 
 /// <p>An object representing a virtual router returned by a describe operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct VirtualRouterData {
     /// <p>The name of the service mesh that the virtual router resides in.</p>
@@ -1374,7 +3523,7 @@ pub struct VirtualRouterData {
 }
 
 /// <p>An object representing a virtual router listener.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VirtualRouterListener {
     #[serde(rename = "portMapping")]
     pub port_mapping: PortMapping,
@@ -1414,12 +3563,94 @@ pub struct VirtualRouterSpec {
 }
 
 /// <p>An object representing the status of a virtual router. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct VirtualRouterStatus {
     /// <p>The current status of the virtual router.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: VirtualRouterStatusCode,
+}
+
+/// <p>The current status of the virtual router.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VirtualRouterStatusCode {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VirtualRouterStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VirtualRouterStatusCode::Active => "ACTIVE",
+            VirtualRouterStatusCode::Inactive => "INACTIVE",
+            VirtualRouterStatusCode::Deleted => "DELETED",
+            VirtualRouterStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VirtualRouterStatusCode {
+    fn from(value: &'a str) -> VirtualRouterStatusCode {
+        match value {
+            "ACTIVE" => VirtualRouterStatusCode::Active,
+            "INACTIVE" => VirtualRouterStatusCode::Inactive,
+            "DELETED" => VirtualRouterStatusCode::Deleted,
+            _ => VirtualRouterStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VirtualRouterStatusCode {
+    fn from(value: String) -> VirtualRouterStatusCode {
+        VirtualRouterStatusCode::from(value.as_str())
+    }
+}
+
+impl From<VirtualRouterStatusCode> for String {
+    fn from(value: VirtualRouterStatusCode) -> String {
+        match value {
+            VirtualRouterStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VirtualRouterStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VirtualRouterStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VirtualRouterStatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VirtualRouterStatusCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VirtualRouterStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VirtualRouterStatusCode::from(value))
+    }
 }
 
 /// <p>An object representing a virtual service backend for a virtual node.</p>
@@ -1431,7 +3662,7 @@ pub struct VirtualServiceBackend {
 }
 
 /// <p>An object representing a virtual service returned by a describe operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct VirtualServiceData {
     /// <p>The name of the service mesh that the virtual service resides in.</p>
@@ -1489,12 +3720,94 @@ pub struct VirtualServiceSpec {
 }
 
 /// <p>An object representing the status of a virtual service.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct VirtualServiceStatus {
     /// <p>The current status of the virtual service.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: VirtualServiceStatusCode,
+}
+
+/// <p>The current status of the virtual service.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VirtualServiceStatusCode {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VirtualServiceStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VirtualServiceStatusCode::Active => "ACTIVE",
+            VirtualServiceStatusCode::Inactive => "INACTIVE",
+            VirtualServiceStatusCode::Deleted => "DELETED",
+            VirtualServiceStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VirtualServiceStatusCode {
+    fn from(value: &'a str) -> VirtualServiceStatusCode {
+        match value {
+            "ACTIVE" => VirtualServiceStatusCode::Active,
+            "INACTIVE" => VirtualServiceStatusCode::Inactive,
+            "DELETED" => VirtualServiceStatusCode::Deleted,
+            _ => VirtualServiceStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VirtualServiceStatusCode {
+    fn from(value: String) -> VirtualServiceStatusCode {
+        VirtualServiceStatusCode::from(value.as_str())
+    }
+}
+
+impl From<VirtualServiceStatusCode> for String {
+    fn from(value: VirtualServiceStatusCode) -> String {
+        match value {
+            VirtualServiceStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VirtualServiceStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VirtualServiceStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VirtualServiceStatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VirtualServiceStatusCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VirtualServiceStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VirtualServiceStatusCode::from(value))
+    }
 }
 
 /// <p>An object representing a target and its relative weight. Traffic is distributed across
@@ -3834,6 +6147,183 @@ pub trait AppMesh {
         input: UpdateVirtualServiceInput,
     ) -> RusotoFuture<UpdateVirtualServiceOutput, UpdateVirtualServiceError>;
 }
+impl<T: ?Sized + AppMesh> AppMesh for ::std::sync::Arc<T> {
+    fn create_mesh(
+        &self,
+        input: CreateMeshInput,
+    ) -> RusotoFuture<CreateMeshOutput, CreateMeshError> {
+        (**self).create_mesh(input)
+    }
+    fn create_route(
+        &self,
+        input: CreateRouteInput,
+    ) -> RusotoFuture<CreateRouteOutput, CreateRouteError> {
+        (**self).create_route(input)
+    }
+    fn create_virtual_node(
+        &self,
+        input: CreateVirtualNodeInput,
+    ) -> RusotoFuture<CreateVirtualNodeOutput, CreateVirtualNodeError> {
+        (**self).create_virtual_node(input)
+    }
+    fn create_virtual_router(
+        &self,
+        input: CreateVirtualRouterInput,
+    ) -> RusotoFuture<CreateVirtualRouterOutput, CreateVirtualRouterError> {
+        (**self).create_virtual_router(input)
+    }
+    fn create_virtual_service(
+        &self,
+        input: CreateVirtualServiceInput,
+    ) -> RusotoFuture<CreateVirtualServiceOutput, CreateVirtualServiceError> {
+        (**self).create_virtual_service(input)
+    }
+    fn delete_mesh(
+        &self,
+        input: DeleteMeshInput,
+    ) -> RusotoFuture<DeleteMeshOutput, DeleteMeshError> {
+        (**self).delete_mesh(input)
+    }
+    fn delete_route(
+        &self,
+        input: DeleteRouteInput,
+    ) -> RusotoFuture<DeleteRouteOutput, DeleteRouteError> {
+        (**self).delete_route(input)
+    }
+    fn delete_virtual_node(
+        &self,
+        input: DeleteVirtualNodeInput,
+    ) -> RusotoFuture<DeleteVirtualNodeOutput, DeleteVirtualNodeError> {
+        (**self).delete_virtual_node(input)
+    }
+    fn delete_virtual_router(
+        &self,
+        input: DeleteVirtualRouterInput,
+    ) -> RusotoFuture<DeleteVirtualRouterOutput, DeleteVirtualRouterError> {
+        (**self).delete_virtual_router(input)
+    }
+    fn delete_virtual_service(
+        &self,
+        input: DeleteVirtualServiceInput,
+    ) -> RusotoFuture<DeleteVirtualServiceOutput, DeleteVirtualServiceError> {
+        (**self).delete_virtual_service(input)
+    }
+    fn describe_mesh(
+        &self,
+        input: DescribeMeshInput,
+    ) -> RusotoFuture<DescribeMeshOutput, DescribeMeshError> {
+        (**self).describe_mesh(input)
+    }
+    fn describe_route(
+        &self,
+        input: DescribeRouteInput,
+    ) -> RusotoFuture<DescribeRouteOutput, DescribeRouteError> {
+        (**self).describe_route(input)
+    }
+    fn describe_virtual_node(
+        &self,
+        input: DescribeVirtualNodeInput,
+    ) -> RusotoFuture<DescribeVirtualNodeOutput, DescribeVirtualNodeError> {
+        (**self).describe_virtual_node(input)
+    }
+    fn describe_virtual_router(
+        &self,
+        input: DescribeVirtualRouterInput,
+    ) -> RusotoFuture<DescribeVirtualRouterOutput, DescribeVirtualRouterError> {
+        (**self).describe_virtual_router(input)
+    }
+    fn describe_virtual_service(
+        &self,
+        input: DescribeVirtualServiceInput,
+    ) -> RusotoFuture<DescribeVirtualServiceOutput, DescribeVirtualServiceError> {
+        (**self).describe_virtual_service(input)
+    }
+    fn list_meshes(
+        &self,
+        input: ListMeshesInput,
+    ) -> RusotoFuture<ListMeshesOutput, ListMeshesError> {
+        (**self).list_meshes(input)
+    }
+    fn list_routes(
+        &self,
+        input: ListRoutesInput,
+    ) -> RusotoFuture<ListRoutesOutput, ListRoutesError> {
+        (**self).list_routes(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_virtual_nodes(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> RusotoFuture<ListVirtualNodesOutput, ListVirtualNodesError> {
+        (**self).list_virtual_nodes(input)
+    }
+    fn list_virtual_routers(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> RusotoFuture<ListVirtualRoutersOutput, ListVirtualRoutersError> {
+        (**self).list_virtual_routers(input)
+    }
+    fn list_virtual_services(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> RusotoFuture<ListVirtualServicesOutput, ListVirtualServicesError> {
+        (**self).list_virtual_services(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_mesh(
+        &self,
+        input: UpdateMeshInput,
+    ) -> RusotoFuture<UpdateMeshOutput, UpdateMeshError> {
+        (**self).update_mesh(input)
+    }
+    fn update_route(
+        &self,
+        input: UpdateRouteInput,
+    ) -> RusotoFuture<UpdateRouteOutput, UpdateRouteError> {
+        (**self).update_route(input)
+    }
+    fn update_virtual_node(
+        &self,
+        input: UpdateVirtualNodeInput,
+    ) -> RusotoFuture<UpdateVirtualNodeOutput, UpdateVirtualNodeError> {
+        (**self).update_virtual_node(input)
+    }
+    fn update_virtual_router(
+        &self,
+        input: UpdateVirtualRouterInput,
+    ) -> RusotoFuture<UpdateVirtualRouterOutput, UpdateVirtualRouterError> {
+        (**self).update_virtual_router(input)
+    }
+    fn update_virtual_service(
+        &self,
+        input: UpdateVirtualServiceInput,
+    ) -> RusotoFuture<UpdateVirtualServiceOutput, UpdateVirtualServiceError> {
+        (**self).update_virtual_service(input)
+    }
+}
+/// The error codes AWS App Mesh retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the AWS App Mesh API.
 #[derive(Clone)]
 pub struct AppMeshClient {
@@ -3849,6 +6339,27 @@ impl AppMeshClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_APPMESH`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> AppMeshClient {
+        Self::new(region::Region::from_env_for_service("APPMESH"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `appmesh`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> AppMeshClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("appmesh", "APPMESH"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3867,7 +6378,49 @@ impl AppMeshClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AppMeshClient {
-        AppMeshClient { client, region }
+        AppMeshClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "appmesh")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "appmesh", "appmesh", "APPMESH")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `AppMesh` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn AppMesh + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for AppMeshClient {
+    fn service_name(&self) -> &'static str {
+        "AWS App Mesh"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "appmesh"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> AppMeshClient {
+        Self::new_with_client(client, region)
     }
 }
 
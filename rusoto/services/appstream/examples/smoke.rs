@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon AppStream clients.
+//!
+//! Makes a single read-only `DescribeDirectoryConfigs` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_appstream::{AppStream, AppStreamClient, DescribeDirectoryConfigsRequest};
+
+fn main() {
+    let client = AppStreamClient::from_env();
+    match client
+        .describe_directory_configs(DescribeDirectoryConfigsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeDirectoryConfigs succeeded"),
+        Err(error) => {
+            eprintln!("DescribeDirectoryConfigs failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,19 +22,180 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Describes an interface VPC endpoint (interface endpoint) that lets you create a private connection between the virtual private cloud (VPC) that you specify and AppStream 2.0. When you specify an interface endpoint for a stack, users of the stack can connect to AppStream 2.0 only through that endpoint. When you specify an interface endpoint for an image builder, administrators can connect to the image builder only through that endpoint.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AccessEndpoint {
     /// <p>The type of interface endpoint.</p>
     #[serde(rename = "EndpointType")]
-    pub endpoint_type: String,
+    pub endpoint_type: AccessEndpointType,
     /// <p>The identifier (ID) of the VPC in which the interface endpoint is used.</p>
     #[serde(rename = "VpceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vpce_id: Option<String>,
 }
 
+/// <p>The type of interface endpoint.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccessEndpointType {
+    /// <p><code>STREAMING</code></p>
+    Streaming,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AccessEndpointType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccessEndpointType::Streaming => "STREAMING",
+            AccessEndpointType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AccessEndpointType {
+    fn from(value: &'a str) -> AccessEndpointType {
+        match value {
+            "STREAMING" => AccessEndpointType::Streaming,
+            _ => AccessEndpointType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccessEndpointType {
+    fn from(value: String) -> AccessEndpointType {
+        AccessEndpointType::from(value.as_str())
+    }
+}
+
+impl From<AccessEndpointType> for String {
+    fn from(value: AccessEndpointType) -> String {
+        match value {
+            AccessEndpointType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AccessEndpointType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AccessEndpointType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccessEndpointType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AccessEndpointType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AccessEndpointType::from(value))
+    }
+}
+
+/// <p>The action that is enabled or disabled.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// <p><code>CLIPBOARD_COPY_FROM_LOCAL_DEVICE</code></p>
+    ClipboardCopyFromLocalDevice,
+    /// <p><code>CLIPBOARD_COPY_TO_LOCAL_DEVICE</code></p>
+    ClipboardCopyToLocalDevice,
+    /// <p><code>FILE_UPLOAD</code></p>
+    FileUpload,
+    /// <p><code>FILE_DOWNLOAD</code></p>
+    FileDownload,
+    /// <p><code>PRINTING_TO_LOCAL_DEVICE</code></p>
+    PrintingToLocalDevice,
+    /// <p><code>DOMAIN_PASSWORD_SIGNIN</code></p>
+    DomainPasswordSignin,
+    /// <p><code>DOMAIN_SMART_CARD_SIGNIN</code></p>
+    DomainSmartCardSignin,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Action {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Action::ClipboardCopyFromLocalDevice => "CLIPBOARD_COPY_FROM_LOCAL_DEVICE",
+            Action::ClipboardCopyToLocalDevice => "CLIPBOARD_COPY_TO_LOCAL_DEVICE",
+            Action::FileUpload => "FILE_UPLOAD",
+            Action::FileDownload => "FILE_DOWNLOAD",
+            Action::PrintingToLocalDevice => "PRINTING_TO_LOCAL_DEVICE",
+            Action::DomainPasswordSignin => "DOMAIN_PASSWORD_SIGNIN",
+            Action::DomainSmartCardSignin => "DOMAIN_SMART_CARD_SIGNIN",
+            Action::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Action {
+    fn from(value: &'a str) -> Action {
+        match value {
+            "CLIPBOARD_COPY_FROM_LOCAL_DEVICE" => Action::ClipboardCopyFromLocalDevice,
+            "CLIPBOARD_COPY_TO_LOCAL_DEVICE" => Action::ClipboardCopyToLocalDevice,
+            "FILE_UPLOAD" => Action::FileUpload,
+            "FILE_DOWNLOAD" => Action::FileDownload,
+            "PRINTING_TO_LOCAL_DEVICE" => Action::PrintingToLocalDevice,
+            "DOMAIN_PASSWORD_SIGNIN" => Action::DomainPasswordSignin,
+            "DOMAIN_SMART_CARD_SIGNIN" => Action::DomainSmartCardSignin,
+            _ => Action::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Action {
+    fn from(value: String) -> Action {
+        Action::from(value.as_str())
+    }
+}
+
+impl From<Action> for String {
+    fn from(value: Action) -> String {
+        match value {
+            Action::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Action {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Action {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Action, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Action::from(value))
+    }
+}
+
 /// <p>Describes an application in the application catalog.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -109,10 +270,130 @@ pub struct AssociateFleetRequest {
     pub stack_name: String,
 }
 
+impl AssociateFleetRequest {
+    /// Returns a builder for `AssociateFleetRequest`, checking its required members when built.
+    pub fn builder() -> AssociateFleetRequestBuilder {
+        AssociateFleetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateFleetRequest`](struct.AssociateFleetRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateFleetRequestBuilder {
+    fleet_name: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl AssociateFleetRequestBuilder {
+    /// Sets the [`fleet_name`](struct.AssociateFleetRequest.html#structfield.fleet_name) member.
+    pub fn fleet_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.AssociateFleetRequest.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateFleetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AssociateFleetRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateFleetRequest {
+            fleet_name: self
+                .fleet_name
+                .ok_or(::rusoto_core::BuildError::missing("fleet_name"))?,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateFleetResult {}
 
+/// <p>The authentication type for the user. You must specify USERPOOL. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthenticationType {
+    /// <p><code>API</code></p>
+    Api,
+    /// <p><code>SAML</code></p>
+    Saml,
+    /// <p><code>USERPOOL</code></p>
+    Userpool,
+    /// <p><code>AWS_AD</code></p>
+    AwsAd,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthenticationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthenticationType::Api => "API",
+            AuthenticationType::Saml => "SAML",
+            AuthenticationType::Userpool => "USERPOOL",
+            AuthenticationType::AwsAd => "AWS_AD",
+            AuthenticationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthenticationType {
+    fn from(value: &'a str) -> AuthenticationType {
+        match value {
+            "API" => AuthenticationType::Api,
+            "SAML" => AuthenticationType::Saml,
+            "USERPOOL" => AuthenticationType::Userpool,
+            "AWS_AD" => AuthenticationType::AwsAd,
+            _ => AuthenticationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthenticationType {
+    fn from(value: String) -> AuthenticationType {
+        AuthenticationType::from(value.as_str())
+    }
+}
+
+impl From<AuthenticationType> for String {
+    fn from(value: AuthenticationType) -> String {
+        match value {
+            AuthenticationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthenticationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AuthenticationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthenticationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthenticationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthenticationType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct BatchAssociateUserStackRequest {
     /// <p>The list of UserStackAssociation objects.</p>
@@ -120,6 +401,38 @@ pub struct BatchAssociateUserStackRequest {
     pub user_stack_associations: Vec<UserStackAssociation>,
 }
 
+impl BatchAssociateUserStackRequest {
+    /// Returns a builder for `BatchAssociateUserStackRequest`, checking its required members when built.
+    pub fn builder() -> BatchAssociateUserStackRequestBuilder {
+        BatchAssociateUserStackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchAssociateUserStackRequest`](struct.BatchAssociateUserStackRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchAssociateUserStackRequestBuilder {
+    user_stack_associations: Option<Vec<UserStackAssociation>>,
+}
+
+impl BatchAssociateUserStackRequestBuilder {
+    /// Sets the [`user_stack_associations`](struct.BatchAssociateUserStackRequest.html#structfield.user_stack_associations) member.
+    pub fn user_stack_associations<T: Into<Vec<UserStackAssociation>>>(mut self, value: T) -> Self {
+        self.user_stack_associations = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchAssociateUserStackRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchAssociateUserStackRequest, ::rusoto_core::BuildError> {
+        Ok(BatchAssociateUserStackRequest {
+            user_stack_associations: self.user_stack_associations.ok_or(
+                ::rusoto_core::BuildError::missing("user_stack_associations"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchAssociateUserStackResult {
@@ -136,6 +449,38 @@ pub struct BatchDisassociateUserStackRequest {
     pub user_stack_associations: Vec<UserStackAssociation>,
 }
 
+impl BatchDisassociateUserStackRequest {
+    /// Returns a builder for `BatchDisassociateUserStackRequest`, checking its required members when built.
+    pub fn builder() -> BatchDisassociateUserStackRequestBuilder {
+        BatchDisassociateUserStackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDisassociateUserStackRequest`](struct.BatchDisassociateUserStackRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDisassociateUserStackRequestBuilder {
+    user_stack_associations: Option<Vec<UserStackAssociation>>,
+}
+
+impl BatchDisassociateUserStackRequestBuilder {
+    /// Sets the [`user_stack_associations`](struct.BatchDisassociateUserStackRequest.html#structfield.user_stack_associations) member.
+    pub fn user_stack_associations<T: Into<Vec<UserStackAssociation>>>(mut self, value: T) -> Self {
+        self.user_stack_associations = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDisassociateUserStackRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDisassociateUserStackRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDisassociateUserStackRequest {
+            user_stack_associations: self.user_stack_associations.ok_or(
+                ::rusoto_core::BuildError::missing("user_stack_associations"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDisassociateUserStackResult {
@@ -191,6 +536,64 @@ pub struct CopyImageRequest {
     pub source_image_name: String,
 }
 
+impl CopyImageRequest {
+    /// Returns a builder for `CopyImageRequest`, checking its required members when built.
+    pub fn builder() -> CopyImageRequestBuilder {
+        CopyImageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CopyImageRequest`](struct.CopyImageRequest.html).
+#[derive(Default, Debug)]
+pub struct CopyImageRequestBuilder {
+    destination_image_description: Option<String>,
+    destination_image_name: Option<String>,
+    destination_region: Option<String>,
+    source_image_name: Option<String>,
+}
+
+impl CopyImageRequestBuilder {
+    /// Sets the [`destination_image_description`](struct.CopyImageRequest.html#structfield.destination_image_description) member.
+    pub fn destination_image_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_image_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_image_name`](struct.CopyImageRequest.html#structfield.destination_image_name) member.
+    pub fn destination_image_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_image_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_region`](struct.CopyImageRequest.html#structfield.destination_region) member.
+    pub fn destination_region<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_image_name`](struct.CopyImageRequest.html#structfield.source_image_name) member.
+    pub fn source_image_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_image_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyImageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CopyImageRequest, ::rusoto_core::BuildError> {
+        Ok(CopyImageRequest {
+            destination_image_description: self.destination_image_description,
+            destination_image_name: self
+                .destination_image_name
+                .ok_or(::rusoto_core::BuildError::missing("destination_image_name"))?,
+            destination_region: self
+                .destination_region
+                .ok_or(::rusoto_core::BuildError::missing("destination_region"))?,
+            source_image_name: self
+                .source_image_name
+                .ok_or(::rusoto_core::BuildError::missing("source_image_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CopyImageResponse {
@@ -213,6 +616,66 @@ pub struct CreateDirectoryConfigRequest {
     pub service_account_credentials: ServiceAccountCredentials,
 }
 
+impl CreateDirectoryConfigRequest {
+    /// Returns a builder for `CreateDirectoryConfigRequest`, checking its required members when built.
+    pub fn builder() -> CreateDirectoryConfigRequestBuilder {
+        CreateDirectoryConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDirectoryConfigRequest`](struct.CreateDirectoryConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDirectoryConfigRequestBuilder {
+    directory_name: Option<String>,
+    organizational_unit_distinguished_names: Option<Vec<String>>,
+    service_account_credentials: Option<ServiceAccountCredentials>,
+}
+
+impl CreateDirectoryConfigRequestBuilder {
+    /// Sets the [`directory_name`](struct.CreateDirectoryConfigRequest.html#structfield.directory_name) member.
+    pub fn directory_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organizational_unit_distinguished_names`](struct.CreateDirectoryConfigRequest.html#structfield.organizational_unit_distinguished_names) member.
+    pub fn organizational_unit_distinguished_names<T: Into<Vec<String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.organizational_unit_distinguished_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_account_credentials`](struct.CreateDirectoryConfigRequest.html#structfield.service_account_credentials) member.
+    pub fn service_account_credentials<T: Into<ServiceAccountCredentials>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.service_account_credentials = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDirectoryConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDirectoryConfigRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDirectoryConfigRequest {
+            directory_name: self
+                .directory_name
+                .ok_or(::rusoto_core::BuildError::missing("directory_name"))?,
+            organizational_unit_distinguished_names: self
+                .organizational_unit_distinguished_names
+                .ok_or(::rusoto_core::BuildError::missing(
+                "organizational_unit_distinguished_names",
+            ))?,
+            service_account_credentials: self.service_account_credentials.ok_or(
+                ::rusoto_core::BuildError::missing("service_account_credentials"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDirectoryConfigResult {
@@ -250,7 +713,7 @@ pub struct CreateFleetRequest {
     /// <p><p>The fleet type.</p> <dl> <dt>ALWAYS<em>ON</dt> <dd> <p>Provides users with instant-on access to their apps. You are charged for all running instances in your fleet, even if no users are streaming apps.</p> </dd> <dt>ON</em>DEMAND</dt> <dd> <p>Provide users with access to applications after they connect, which takes one to two minutes. You are charged for instance streaming when users are connected and a small hourly fee for instances that are not streaming apps.</p> </dd> </dl></p>
     #[serde(rename = "FleetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fleet_type: Option<String>,
+    pub fleet_type: Option<FleetType>,
     /// <p>The Amazon Resource Name (ARN) of the IAM role to apply to the fleet. To assume a role, a fleet instance calls the AWS Security Token Service (STS) <code>AssumeRole</code> API operation and passes the ARN of the role to use. The operation creates a new session with temporary credentials.</p>
     #[serde(rename = "IamRoleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -287,6 +750,160 @@ pub struct CreateFleetRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl CreateFleetRequest {
+    /// Returns a builder for `CreateFleetRequest`, checking its required members when built.
+    pub fn builder() -> CreateFleetRequestBuilder {
+        CreateFleetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFleetRequest`](struct.CreateFleetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFleetRequestBuilder {
+    compute_capacity: Option<ComputeCapacity>,
+    description: Option<String>,
+    disconnect_timeout_in_seconds: Option<i64>,
+    display_name: Option<String>,
+    domain_join_info: Option<DomainJoinInfo>,
+    enable_default_internet_access: Option<bool>,
+    fleet_type: Option<FleetType>,
+    iam_role_arn: Option<String>,
+    idle_disconnect_timeout_in_seconds: Option<i64>,
+    image_arn: Option<String>,
+    image_name: Option<String>,
+    instance_type: Option<String>,
+    max_user_duration_in_seconds: Option<i64>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl CreateFleetRequestBuilder {
+    /// Sets the [`compute_capacity`](struct.CreateFleetRequest.html#structfield.compute_capacity) member.
+    pub fn compute_capacity<T: Into<ComputeCapacity>>(mut self, value: T) -> Self {
+        self.compute_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateFleetRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disconnect_timeout_in_seconds`](struct.CreateFleetRequest.html#structfield.disconnect_timeout_in_seconds) member.
+    pub fn disconnect_timeout_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.disconnect_timeout_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.CreateFleetRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_join_info`](struct.CreateFleetRequest.html#structfield.domain_join_info) member.
+    pub fn domain_join_info<T: Into<DomainJoinInfo>>(mut self, value: T) -> Self {
+        self.domain_join_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_default_internet_access`](struct.CreateFleetRequest.html#structfield.enable_default_internet_access) member.
+    pub fn enable_default_internet_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_default_internet_access = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_type`](struct.CreateFleetRequest.html#structfield.fleet_type) member.
+    pub fn fleet_type<T: Into<FleetType>>(mut self, value: T) -> Self {
+        self.fleet_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_role_arn`](struct.CreateFleetRequest.html#structfield.iam_role_arn) member.
+    pub fn iam_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idle_disconnect_timeout_in_seconds`](struct.CreateFleetRequest.html#structfield.idle_disconnect_timeout_in_seconds) member.
+    pub fn idle_disconnect_timeout_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.idle_disconnect_timeout_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_arn`](struct.CreateFleetRequest.html#structfield.image_arn) member.
+    pub fn image_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_name`](struct.CreateFleetRequest.html#structfield.image_name) member.
+    pub fn image_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_type`](struct.CreateFleetRequest.html#structfield.instance_type) member.
+    pub fn instance_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_user_duration_in_seconds`](struct.CreateFleetRequest.html#structfield.max_user_duration_in_seconds) member.
+    pub fn max_user_duration_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_user_duration_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateFleetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateFleetRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.CreateFleetRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFleetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateFleetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateFleetRequest {
+            compute_capacity: self
+                .compute_capacity
+                .ok_or(::rusoto_core::BuildError::missing("compute_capacity"))?,
+            description: self.description,
+            disconnect_timeout_in_seconds: self.disconnect_timeout_in_seconds,
+            display_name: self.display_name,
+            domain_join_info: self.domain_join_info,
+            enable_default_internet_access: self.enable_default_internet_access,
+            fleet_type: self.fleet_type,
+            iam_role_arn: self.iam_role_arn,
+            idle_disconnect_timeout_in_seconds: self.idle_disconnect_timeout_in_seconds,
+            image_arn: self.image_arn,
+            image_name: self.image_name,
+            instance_type: self
+                .instance_type
+                .ok_or(::rusoto_core::BuildError::missing("instance_type"))?,
+            max_user_duration_in_seconds: self.max_user_duration_in_seconds,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateFleetResult {
@@ -350,6 +967,136 @@ pub struct CreateImageBuilderRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl CreateImageBuilderRequest {
+    /// Returns a builder for `CreateImageBuilderRequest`, checking its required members when built.
+    pub fn builder() -> CreateImageBuilderRequestBuilder {
+        CreateImageBuilderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateImageBuilderRequest`](struct.CreateImageBuilderRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateImageBuilderRequestBuilder {
+    access_endpoints: Option<Vec<AccessEndpoint>>,
+    appstream_agent_version: Option<String>,
+    description: Option<String>,
+    display_name: Option<String>,
+    domain_join_info: Option<DomainJoinInfo>,
+    enable_default_internet_access: Option<bool>,
+    iam_role_arn: Option<String>,
+    image_arn: Option<String>,
+    image_name: Option<String>,
+    instance_type: Option<String>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl CreateImageBuilderRequestBuilder {
+    /// Sets the [`access_endpoints`](struct.CreateImageBuilderRequest.html#structfield.access_endpoints) member.
+    pub fn access_endpoints<T: Into<Vec<AccessEndpoint>>>(mut self, value: T) -> Self {
+        self.access_endpoints = Some(value.into());
+        self
+    }
+
+    /// Sets the [`appstream_agent_version`](struct.CreateImageBuilderRequest.html#structfield.appstream_agent_version) member.
+    pub fn appstream_agent_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.appstream_agent_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateImageBuilderRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.CreateImageBuilderRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_join_info`](struct.CreateImageBuilderRequest.html#structfield.domain_join_info) member.
+    pub fn domain_join_info<T: Into<DomainJoinInfo>>(mut self, value: T) -> Self {
+        self.domain_join_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_default_internet_access`](struct.CreateImageBuilderRequest.html#structfield.enable_default_internet_access) member.
+    pub fn enable_default_internet_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_default_internet_access = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_role_arn`](struct.CreateImageBuilderRequest.html#structfield.iam_role_arn) member.
+    pub fn iam_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_arn`](struct.CreateImageBuilderRequest.html#structfield.image_arn) member.
+    pub fn image_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_name`](struct.CreateImageBuilderRequest.html#structfield.image_name) member.
+    pub fn image_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_type`](struct.CreateImageBuilderRequest.html#structfield.instance_type) member.
+    pub fn instance_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateImageBuilderRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateImageBuilderRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.CreateImageBuilderRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateImageBuilderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateImageBuilderRequest, ::rusoto_core::BuildError> {
+        Ok(CreateImageBuilderRequest {
+            access_endpoints: self.access_endpoints,
+            appstream_agent_version: self.appstream_agent_version,
+            description: self.description,
+            display_name: self.display_name,
+            domain_join_info: self.domain_join_info,
+            enable_default_internet_access: self.enable_default_internet_access,
+            iam_role_arn: self.iam_role_arn,
+            image_arn: self.image_arn,
+            image_name: self.image_name,
+            instance_type: self
+                .instance_type
+                .ok_or(::rusoto_core::BuildError::missing("instance_type"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateImageBuilderResult {
@@ -370,13 +1117,55 @@ pub struct CreateImageBuilderStreamingURLRequest {
     pub validity: Option<i64>,
 }
 
+impl CreateImageBuilderStreamingURLRequest {
+    /// Returns a builder for `CreateImageBuilderStreamingURLRequest`, checking its required members when built.
+    pub fn builder() -> CreateImageBuilderStreamingURLRequestBuilder {
+        CreateImageBuilderStreamingURLRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateImageBuilderStreamingURLRequest`](struct.CreateImageBuilderStreamingURLRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateImageBuilderStreamingURLRequestBuilder {
+    name: Option<String>,
+    validity: Option<i64>,
+}
+
+impl CreateImageBuilderStreamingURLRequestBuilder {
+    /// Sets the [`name`](struct.CreateImageBuilderStreamingURLRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`validity`](struct.CreateImageBuilderStreamingURLRequest.html#structfield.validity) member.
+    pub fn validity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.validity = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateImageBuilderStreamingURLRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateImageBuilderStreamingURLRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateImageBuilderStreamingURLRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            validity: self.validity,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateImageBuilderStreamingURLResult {
     /// <p>The elapsed time, in seconds after the Unix epoch, when this URL expires.</p>
     #[serde(rename = "Expires")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires: Option<f64>,
+    pub expires: Option<EpochTimestamp>,
     /// <p>The URL to start the AppStream 2.0 streaming session.</p>
     #[serde(rename = "StreamingURL")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -426,28 +1215,130 @@ pub struct CreateStackRequest {
     pub user_settings: Option<Vec<UserSetting>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CreateStackResult {
-    /// <p>Information about the stack.</p>
-    #[serde(rename = "Stack")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub stack: Option<Stack>,
+impl CreateStackRequest {
+    /// Returns a builder for `CreateStackRequest`, checking its required members when built.
+    pub fn builder() -> CreateStackRequestBuilder {
+        CreateStackRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct CreateStreamingURLRequest {
-    /// <p>The name of the application to launch after the session starts. This is the name that you specified as <b>Name</b> in the Image Assistant.</p>
-    #[serde(rename = "ApplicationId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub application_id: Option<String>,
-    /// <p>The name of the fleet.</p>
-    #[serde(rename = "FleetName")]
-    pub fleet_name: String,
-    /// <p>The session context. For more information, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/managing-stacks-fleets.html#managing-stacks-fleets-parameters">Session Context</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
-    #[serde(rename = "SessionContext")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub session_context: Option<String>,
+/// Builds a [`CreateStackRequest`](struct.CreateStackRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateStackRequestBuilder {
+    access_endpoints: Option<Vec<AccessEndpoint>>,
+    application_settings: Option<ApplicationSettings>,
+    description: Option<String>,
+    display_name: Option<String>,
+    feedback_url: Option<String>,
+    name: Option<String>,
+    redirect_url: Option<String>,
+    storage_connectors: Option<Vec<StorageConnector>>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    user_settings: Option<Vec<UserSetting>>,
+}
+
+impl CreateStackRequestBuilder {
+    /// Sets the [`access_endpoints`](struct.CreateStackRequest.html#structfield.access_endpoints) member.
+    pub fn access_endpoints<T: Into<Vec<AccessEndpoint>>>(mut self, value: T) -> Self {
+        self.access_endpoints = Some(value.into());
+        self
+    }
+
+    /// Sets the [`application_settings`](struct.CreateStackRequest.html#structfield.application_settings) member.
+    pub fn application_settings<T: Into<ApplicationSettings>>(mut self, value: T) -> Self {
+        self.application_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateStackRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.CreateStackRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`feedback_url`](struct.CreateStackRequest.html#structfield.feedback_url) member.
+    pub fn feedback_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.feedback_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateStackRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`redirect_url`](struct.CreateStackRequest.html#structfield.redirect_url) member.
+    pub fn redirect_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.redirect_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_connectors`](struct.CreateStackRequest.html#structfield.storage_connectors) member.
+    pub fn storage_connectors<T: Into<Vec<StorageConnector>>>(mut self, value: T) -> Self {
+        self.storage_connectors = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateStackRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_settings`](struct.CreateStackRequest.html#structfield.user_settings) member.
+    pub fn user_settings<T: Into<Vec<UserSetting>>>(mut self, value: T) -> Self {
+        self.user_settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStackRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateStackRequest, ::rusoto_core::BuildError> {
+        Ok(CreateStackRequest {
+            access_endpoints: self.access_endpoints,
+            application_settings: self.application_settings,
+            description: self.description,
+            display_name: self.display_name,
+            feedback_url: self.feedback_url,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            redirect_url: self.redirect_url,
+            storage_connectors: self.storage_connectors,
+            tags: self.tags,
+            user_settings: self.user_settings,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateStackResult {
+    /// <p>Information about the stack.</p>
+    #[serde(rename = "Stack")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stack: Option<Stack>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct CreateStreamingURLRequest {
+    /// <p>The name of the application to launch after the session starts. This is the name that you specified as <b>Name</b> in the Image Assistant.</p>
+    #[serde(rename = "ApplicationId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<String>,
+    /// <p>The name of the fleet.</p>
+    #[serde(rename = "FleetName")]
+    pub fleet_name: String,
+    /// <p>The session context. For more information, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/managing-stacks-fleets.html#managing-stacks-fleets-parameters">Session Context</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    #[serde(rename = "SessionContext")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_context: Option<String>,
     /// <p>The name of the stack.</p>
     #[serde(rename = "StackName")]
     pub stack_name: String,
@@ -460,13 +1351,90 @@ pub struct CreateStreamingURLRequest {
     pub validity: Option<i64>,
 }
 
+impl CreateStreamingURLRequest {
+    /// Returns a builder for `CreateStreamingURLRequest`, checking its required members when built.
+    pub fn builder() -> CreateStreamingURLRequestBuilder {
+        CreateStreamingURLRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStreamingURLRequest`](struct.CreateStreamingURLRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateStreamingURLRequestBuilder {
+    application_id: Option<String>,
+    fleet_name: Option<String>,
+    session_context: Option<String>,
+    stack_name: Option<String>,
+    user_id: Option<String>,
+    validity: Option<i64>,
+}
+
+impl CreateStreamingURLRequestBuilder {
+    /// Sets the [`application_id`](struct.CreateStreamingURLRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_name`](struct.CreateStreamingURLRequest.html#structfield.fleet_name) member.
+    pub fn fleet_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`session_context`](struct.CreateStreamingURLRequest.html#structfield.session_context) member.
+    pub fn session_context<T: Into<String>>(mut self, value: T) -> Self {
+        self.session_context = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.CreateStreamingURLRequest.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.CreateStreamingURLRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`validity`](struct.CreateStreamingURLRequest.html#structfield.validity) member.
+    pub fn validity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.validity = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStreamingURLRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateStreamingURLRequest, ::rusoto_core::BuildError> {
+        Ok(CreateStreamingURLRequest {
+            application_id: self.application_id,
+            fleet_name: self
+                .fleet_name
+                .ok_or(::rusoto_core::BuildError::missing("fleet_name"))?,
+            session_context: self.session_context,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+            validity: self.validity,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateStreamingURLResult {
     /// <p>The elapsed time, in seconds after the Unix epoch, when this URL expires.</p>
     #[serde(rename = "Expires")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires: Option<f64>,
+    pub expires: Option<EpochTimestamp>,
     /// <p>The URL to start the AppStream 2.0 streaming session.</p>
     #[serde(rename = "StreamingURL")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -486,14 +1454,14 @@ pub struct CreateUsageReportSubscriptionResult {
     /// <p>The schedule for generating usage reports.</p>
     #[serde(rename = "Schedule")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub schedule: Option<String>,
+    pub schedule: Option<UsageReportSchedule>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateUserRequest {
     /// <p>The authentication type for the user. You must specify USERPOOL. </p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p>The first name, or given name, of the user.</p>
     #[serde(rename = "FirstName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -505,12 +1473,76 @@ pub struct CreateUserRequest {
     /// <p><p>The action to take for the welcome email that is sent to a user after the user is created in the user pool. If you specify SUPPRESS, no email is sent. If you specify RESEND, do not specify the first name or last name of the user. If the value is null, the email is sent. </p> <note> <p>The temporary password in the welcome email is valid for only 7 days. If users don’t set their passwords within 7 days, you must send them a new welcome email.</p> </note></p>
     #[serde(rename = "MessageAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_action: Option<String>,
+    pub message_action: Option<MessageAction>,
     /// <p><p>The email address of the user.</p> <note> <p>Users&#39; email addresses are case-sensitive. During login, if they specify an email address that doesn&#39;t use the same capitalization as the email address specified when their user pool account was created, a &quot;user does not exist&quot; error message displays.</p> </note></p>
     #[serde(rename = "UserName")]
     pub user_name: String,
 }
 
+impl CreateUserRequest {
+    /// Returns a builder for `CreateUserRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserRequestBuilder {
+        CreateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserRequest`](struct.CreateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserRequestBuilder {
+    authentication_type: Option<AuthenticationType>,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    message_action: Option<MessageAction>,
+    user_name: Option<String>,
+}
+
+impl CreateUserRequestBuilder {
+    /// Sets the [`authentication_type`](struct.CreateUserRequest.html#structfield.authentication_type) member.
+    pub fn authentication_type<T: Into<AuthenticationType>>(mut self, value: T) -> Self {
+        self.authentication_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`first_name`](struct.CreateUserRequest.html#structfield.first_name) member.
+    pub fn first_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.first_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`last_name`](struct.CreateUserRequest.html#structfield.last_name) member.
+    pub fn last_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.last_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message_action`](struct.CreateUserRequest.html#structfield.message_action) member.
+    pub fn message_action<T: Into<MessageAction>>(mut self, value: T) -> Self {
+        self.message_action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.CreateUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateUserRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserRequest {
+            authentication_type: self
+                .authentication_type
+                .ok_or(::rusoto_core::BuildError::missing("authentication_type"))?,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            message_action: self.message_action,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateUserResult {}
@@ -522,6 +1554,38 @@ pub struct DeleteDirectoryConfigRequest {
     pub directory_name: String,
 }
 
+impl DeleteDirectoryConfigRequest {
+    /// Returns a builder for `DeleteDirectoryConfigRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDirectoryConfigRequestBuilder {
+        DeleteDirectoryConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDirectoryConfigRequest`](struct.DeleteDirectoryConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDirectoryConfigRequestBuilder {
+    directory_name: Option<String>,
+}
+
+impl DeleteDirectoryConfigRequestBuilder {
+    /// Sets the [`directory_name`](struct.DeleteDirectoryConfigRequest.html#structfield.directory_name) member.
+    pub fn directory_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDirectoryConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDirectoryConfigRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDirectoryConfigRequest {
+            directory_name: self
+                .directory_name
+                .ok_or(::rusoto_core::BuildError::missing("directory_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDirectoryConfigResult {}
@@ -533,6 +1597,36 @@ pub struct DeleteFleetRequest {
     pub name: String,
 }
 
+impl DeleteFleetRequest {
+    /// Returns a builder for `DeleteFleetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFleetRequestBuilder {
+        DeleteFleetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFleetRequest`](struct.DeleteFleetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFleetRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteFleetRequestBuilder {
+    /// Sets the [`name`](struct.DeleteFleetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFleetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteFleetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFleetRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteFleetResult {}
@@ -544,6 +1638,38 @@ pub struct DeleteImageBuilderRequest {
     pub name: String,
 }
 
+impl DeleteImageBuilderRequest {
+    /// Returns a builder for `DeleteImageBuilderRequest`, checking its required members when built.
+    pub fn builder() -> DeleteImageBuilderRequestBuilder {
+        DeleteImageBuilderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteImageBuilderRequest`](struct.DeleteImageBuilderRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteImageBuilderRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteImageBuilderRequestBuilder {
+    /// Sets the [`name`](struct.DeleteImageBuilderRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteImageBuilderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteImageBuilderRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteImageBuilderRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteImageBuilderResult {
@@ -563,6 +1689,48 @@ pub struct DeleteImagePermissionsRequest {
     pub shared_account_id: String,
 }
 
+impl DeleteImagePermissionsRequest {
+    /// Returns a builder for `DeleteImagePermissionsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteImagePermissionsRequestBuilder {
+        DeleteImagePermissionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteImagePermissionsRequest`](struct.DeleteImagePermissionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteImagePermissionsRequestBuilder {
+    name: Option<String>,
+    shared_account_id: Option<String>,
+}
+
+impl DeleteImagePermissionsRequestBuilder {
+    /// Sets the [`name`](struct.DeleteImagePermissionsRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shared_account_id`](struct.DeleteImagePermissionsRequest.html#structfield.shared_account_id) member.
+    pub fn shared_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.shared_account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteImagePermissionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteImagePermissionsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteImagePermissionsRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            shared_account_id: self
+                .shared_account_id
+                .ok_or(::rusoto_core::BuildError::missing("shared_account_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteImagePermissionsResult {}
@@ -574,6 +1742,36 @@ pub struct DeleteImageRequest {
     pub name: String,
 }
 
+impl DeleteImageRequest {
+    /// Returns a builder for `DeleteImageRequest`, checking its required members when built.
+    pub fn builder() -> DeleteImageRequestBuilder {
+        DeleteImageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteImageRequest`](struct.DeleteImageRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteImageRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteImageRequestBuilder {
+    /// Sets the [`name`](struct.DeleteImageRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteImageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteImageRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteImageRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteImageResult {
@@ -590,6 +1788,36 @@ pub struct DeleteStackRequest {
     pub name: String,
 }
 
+impl DeleteStackRequest {
+    /// Returns a builder for `DeleteStackRequest`, checking its required members when built.
+    pub fn builder() -> DeleteStackRequestBuilder {
+        DeleteStackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStackRequest`](struct.DeleteStackRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteStackRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteStackRequestBuilder {
+    /// Sets the [`name`](struct.DeleteStackRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStackRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteStackRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteStackRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteStackResult {}
@@ -601,16 +1829,56 @@ pub struct DeleteUsageReportSubscriptionRequest {}
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteUsageReportSubscriptionResult {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteUserRequest {
     /// <p>The authentication type for the user. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p><p>The email address of the user.</p> <note> <p>Users&#39; email addresses are case-sensitive.</p> </note></p>
     #[serde(rename = "UserName")]
     pub user_name: String,
 }
 
+impl DeleteUserRequest {
+    /// Returns a builder for `DeleteUserRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserRequestBuilder {
+        DeleteUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserRequest`](struct.DeleteUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserRequestBuilder {
+    authentication_type: Option<AuthenticationType>,
+    user_name: Option<String>,
+}
+
+impl DeleteUserRequestBuilder {
+    /// Sets the [`authentication_type`](struct.DeleteUserRequest.html#structfield.authentication_type) member.
+    pub fn authentication_type<T: Into<AuthenticationType>>(mut self, value: T) -> Self {
+        self.authentication_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DeleteUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteUserRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserRequest {
+            authentication_type: self
+                .authentication_type
+                .ok_or(::rusoto_core::BuildError::missing("authentication_type"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteUserResult {}
@@ -631,6 +1899,52 @@ pub struct DescribeDirectoryConfigsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeDirectoryConfigsRequest {
+    /// Returns a builder for `DescribeDirectoryConfigsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDirectoryConfigsRequestBuilder {
+        DescribeDirectoryConfigsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDirectoryConfigsRequest`](struct.DescribeDirectoryConfigsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDirectoryConfigsRequestBuilder {
+    directory_names: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeDirectoryConfigsRequestBuilder {
+    /// Sets the [`directory_names`](struct.DescribeDirectoryConfigsRequest.html#structfield.directory_names) member.
+    pub fn directory_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.directory_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeDirectoryConfigsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeDirectoryConfigsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDirectoryConfigsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDirectoryConfigsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDirectoryConfigsRequest {
+            directory_names: self.directory_names,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDirectoryConfigsResult {
@@ -656,6 +1970,42 @@ pub struct DescribeFleetsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeFleetsRequest {
+    /// Returns a builder for `DescribeFleetsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeFleetsRequestBuilder {
+        DescribeFleetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeFleetsRequest`](struct.DescribeFleetsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeFleetsRequestBuilder {
+    names: Option<Vec<String>>,
+    next_token: Option<String>,
+}
+
+impl DescribeFleetsRequestBuilder {
+    /// Sets the [`names`](struct.DescribeFleetsRequest.html#structfield.names) member.
+    pub fn names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeFleetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeFleetsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeFleetsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeFleetsRequest {
+            names: self.names,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeFleetsResult {
@@ -685,6 +2035,52 @@ pub struct DescribeImageBuildersRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeImageBuildersRequest {
+    /// Returns a builder for `DescribeImageBuildersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeImageBuildersRequestBuilder {
+        DescribeImageBuildersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeImageBuildersRequest`](struct.DescribeImageBuildersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeImageBuildersRequestBuilder {
+    max_results: Option<i64>,
+    names: Option<Vec<String>>,
+    next_token: Option<String>,
+}
+
+impl DescribeImageBuildersRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeImageBuildersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`names`](struct.DescribeImageBuildersRequest.html#structfield.names) member.
+    pub fn names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeImageBuildersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeImageBuildersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeImageBuildersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeImageBuildersRequest {
+            max_results: self.max_results,
+            names: self.names,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeImageBuildersResult {
@@ -717,6 +2113,62 @@ pub struct DescribeImagePermissionsRequest {
     pub shared_aws_account_ids: Option<Vec<String>>,
 }
 
+impl DescribeImagePermissionsRequest {
+    /// Returns a builder for `DescribeImagePermissionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeImagePermissionsRequestBuilder {
+        DescribeImagePermissionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeImagePermissionsRequest`](struct.DescribeImagePermissionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeImagePermissionsRequestBuilder {
+    max_results: Option<i64>,
+    name: Option<String>,
+    next_token: Option<String>,
+    shared_aws_account_ids: Option<Vec<String>>,
+}
+
+impl DescribeImagePermissionsRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeImagePermissionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DescribeImagePermissionsRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeImagePermissionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shared_aws_account_ids`](struct.DescribeImagePermissionsRequest.html#structfield.shared_aws_account_ids) member.
+    pub fn shared_aws_account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.shared_aws_account_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeImagePermissionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeImagePermissionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeImagePermissionsRequest {
+            max_results: self.max_results,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            next_token: self.next_token,
+            shared_aws_account_ids: self.shared_aws_account_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeImagePermissionsResult {
@@ -755,7 +2207,67 @@ pub struct DescribeImagesRequest {
     /// <p>The type of image (public, private, or shared) to describe. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<VisibilityType>,
+}
+
+impl DescribeImagesRequest {
+    /// Returns a builder for `DescribeImagesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeImagesRequestBuilder {
+        DescribeImagesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeImagesRequest`](struct.DescribeImagesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeImagesRequestBuilder {
+    arns: Option<Vec<String>>,
+    max_results: Option<i64>,
+    names: Option<Vec<String>>,
+    next_token: Option<String>,
+    type_: Option<VisibilityType>,
+}
+
+impl DescribeImagesRequestBuilder {
+    /// Sets the [`arns`](struct.DescribeImagesRequest.html#structfield.arns) member.
+    pub fn arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeImagesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`names`](struct.DescribeImagesRequest.html#structfield.names) member.
+    pub fn names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeImagesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.DescribeImagesRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<VisibilityType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeImagesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeImagesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeImagesRequest {
+            arns: self.arns,
+            max_results: self.max_results,
+            names: self.names,
+            next_token: self.next_token,
+            type_: self.type_,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -776,7 +2288,7 @@ pub struct DescribeSessionsRequest {
     /// <p>The authentication method. Specify <code>API</code> for a user authenticated using a streaming URL or <code>SAML</code> for a SAML federated user. The default is to authenticate users using a streaming URL.</p>
     #[serde(rename = "AuthenticationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_type: Option<String>,
+    pub authentication_type: Option<AuthenticationType>,
     /// <p>The name of the fleet. This value is case-sensitive.</p>
     #[serde(rename = "FleetName")]
     pub fleet_name: String,
@@ -797,12 +2309,86 @@ pub struct DescribeSessionsRequest {
     pub user_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DescribeSessionsResult {
-    /// <p>The pagination token to use to retrieve the next page of results for this operation. If there are no more pages, this value is null.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl DescribeSessionsRequest {
+    /// Returns a builder for `DescribeSessionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeSessionsRequestBuilder {
+        DescribeSessionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSessionsRequest`](struct.DescribeSessionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeSessionsRequestBuilder {
+    authentication_type: Option<AuthenticationType>,
+    fleet_name: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    stack_name: Option<String>,
+    user_id: Option<String>,
+}
+
+impl DescribeSessionsRequestBuilder {
+    /// Sets the [`authentication_type`](struct.DescribeSessionsRequest.html#structfield.authentication_type) member.
+    pub fn authentication_type<T: Into<AuthenticationType>>(mut self, value: T) -> Self {
+        self.authentication_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fleet_name`](struct.DescribeSessionsRequest.html#structfield.fleet_name) member.
+    pub fn fleet_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeSessionsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeSessionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DescribeSessionsRequest.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.DescribeSessionsRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSessionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSessionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeSessionsRequest {
+            authentication_type: self.authentication_type,
+            fleet_name: self
+                .fleet_name
+                .ok_or(::rusoto_core::BuildError::missing("fleet_name"))?,
+            limit: self.limit,
+            next_token: self.next_token,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+            user_id: self.user_id,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeSessionsResult {
+    /// <p>The pagination token to use to retrieve the next page of results for this operation. If there are no more pages, this value is null.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub next_token: Option<String>,
     /// <p>Information about the streaming sessions.</p>
     #[serde(rename = "Sessions")]
@@ -822,6 +2408,42 @@ pub struct DescribeStacksRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeStacksRequest {
+    /// Returns a builder for `DescribeStacksRequest`, checking its required members when built.
+    pub fn builder() -> DescribeStacksRequestBuilder {
+        DescribeStacksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStacksRequest`](struct.DescribeStacksRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeStacksRequestBuilder {
+    names: Option<Vec<String>>,
+    next_token: Option<String>,
+}
+
+impl DescribeStacksRequestBuilder {
+    /// Sets the [`names`](struct.DescribeStacksRequest.html#structfield.names) member.
+    pub fn names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeStacksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStacksRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeStacksRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeStacksRequest {
+            names: self.names,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeStacksResult {
@@ -847,6 +2469,45 @@ pub struct DescribeUsageReportSubscriptionsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeUsageReportSubscriptionsRequest {
+    /// Returns a builder for `DescribeUsageReportSubscriptionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUsageReportSubscriptionsRequestBuilder {
+        DescribeUsageReportSubscriptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUsageReportSubscriptionsRequest`](struct.DescribeUsageReportSubscriptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUsageReportSubscriptionsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeUsageReportSubscriptionsRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeUsageReportSubscriptionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeUsageReportSubscriptionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUsageReportSubscriptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeUsageReportSubscriptionsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeUsageReportSubscriptionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUsageReportSubscriptionsResult {
@@ -865,7 +2526,7 @@ pub struct DescribeUserStackAssociationsRequest {
     /// <p>The authentication type for the user who is associated with the stack. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_type: Option<String>,
+    pub authentication_type: Option<AuthenticationType>,
     /// <p>The maximum size of each page of results.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -884,6 +2545,69 @@ pub struct DescribeUserStackAssociationsRequest {
     pub user_name: Option<String>,
 }
 
+impl DescribeUserStackAssociationsRequest {
+    /// Returns a builder for `DescribeUserStackAssociationsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserStackAssociationsRequestBuilder {
+        DescribeUserStackAssociationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserStackAssociationsRequest`](struct.DescribeUserStackAssociationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserStackAssociationsRequestBuilder {
+    authentication_type: Option<AuthenticationType>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    stack_name: Option<String>,
+    user_name: Option<String>,
+}
+
+impl DescribeUserStackAssociationsRequestBuilder {
+    /// Sets the [`authentication_type`](struct.DescribeUserStackAssociationsRequest.html#structfield.authentication_type) member.
+    pub fn authentication_type<T: Into<AuthenticationType>>(mut self, value: T) -> Self {
+        self.authentication_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeUserStackAssociationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeUserStackAssociationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DescribeUserStackAssociationsRequest.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DescribeUserStackAssociationsRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserStackAssociationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeUserStackAssociationsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeUserStackAssociationsRequest {
+            authentication_type: self.authentication_type,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            stack_name: self.stack_name,
+            user_name: self.user_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUserStackAssociationsResult {
@@ -897,11 +2621,11 @@ pub struct DescribeUserStackAssociationsResult {
     pub user_stack_associations: Option<Vec<UserStackAssociation>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeUsersRequest {
     /// <p>The authentication type for the users in the user pool to describe. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p>The maximum size of each page of results.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -912,6 +2636,52 @@ pub struct DescribeUsersRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeUsersRequest {
+    /// Returns a builder for `DescribeUsersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUsersRequestBuilder {
+        DescribeUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUsersRequest`](struct.DescribeUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUsersRequestBuilder {
+    authentication_type: Option<AuthenticationType>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeUsersRequestBuilder {
+    /// Sets the [`authentication_type`](struct.DescribeUsersRequest.html#structfield.authentication_type) member.
+    pub fn authentication_type<T: Into<AuthenticationType>>(mut self, value: T) -> Self {
+        self.authentication_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeUsersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeUsersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeUsersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUsersRequest {
+            authentication_type: self
+                .authentication_type
+                .ok_or(::rusoto_core::BuildError::missing("authentication_type"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUsersResult {
@@ -931,8 +2701,9 @@ pub struct DescribeUsersResult {
 pub struct DirectoryConfig {
     /// <p>The time the directory configuration was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The fully qualified name of the directory (for example, corp.example.com).</p>
     #[serde(rename = "DirectoryName")]
     pub directory_name: String,
@@ -946,16 +2717,56 @@ pub struct DirectoryConfig {
     pub service_account_credentials: Option<ServiceAccountCredentials>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DisableUserRequest {
     /// <p>The authentication type for the user. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p><p>The email address of the user.</p> <note> <p>Users&#39; email addresses are case-sensitive.</p> </note></p>
     #[serde(rename = "UserName")]
     pub user_name: String,
 }
 
+impl DisableUserRequest {
+    /// Returns a builder for `DisableUserRequest`, checking its required members when built.
+    pub fn builder() -> DisableUserRequestBuilder {
+        DisableUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisableUserRequest`](struct.DisableUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DisableUserRequestBuilder {
+    authentication_type: Option<AuthenticationType>,
+    user_name: Option<String>,
+}
+
+impl DisableUserRequestBuilder {
+    /// Sets the [`authentication_type`](struct.DisableUserRequest.html#structfield.authentication_type) member.
+    pub fn authentication_type<T: Into<AuthenticationType>>(mut self, value: T) -> Self {
+        self.authentication_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DisableUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DisableUserRequest, ::rusoto_core::BuildError> {
+        Ok(DisableUserRequest {
+            authentication_type: self
+                .authentication_type
+                .ok_or(::rusoto_core::BuildError::missing("authentication_type"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisableUserResult {}
@@ -970,6 +2781,48 @@ pub struct DisassociateFleetRequest {
     pub stack_name: String,
 }
 
+impl DisassociateFleetRequest {
+    /// Returns a builder for `DisassociateFleetRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateFleetRequestBuilder {
+        DisassociateFleetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateFleetRequest`](struct.DisassociateFleetRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateFleetRequestBuilder {
+    fleet_name: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl DisassociateFleetRequestBuilder {
+    /// Sets the [`fleet_name`](struct.DisassociateFleetRequest.html#structfield.fleet_name) member.
+    pub fn fleet_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DisassociateFleetRequest.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateFleetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateFleetRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateFleetRequest {
+            fleet_name: self
+                .fleet_name
+                .ok_or(::rusoto_core::BuildError::missing("fleet_name"))?,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateFleetResult {}
@@ -987,16 +2840,56 @@ pub struct DomainJoinInfo {
     pub organizational_unit_distinguished_name: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct EnableUserRequest {
     /// <p>The authentication type for the user. You must specify USERPOOL.</p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p><p>The email address of the user.</p> <note> <p>Users&#39; email addresses are case-sensitive. During login, if they specify an email address that doesn&#39;t use the same capitalization as the email address specified when their user pool account was created, a &quot;user does not exist&quot; error message displays. </p> </note></p>
     #[serde(rename = "UserName")]
     pub user_name: String,
 }
 
+impl EnableUserRequest {
+    /// Returns a builder for `EnableUserRequest`, checking its required members when built.
+    pub fn builder() -> EnableUserRequestBuilder {
+        EnableUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableUserRequest`](struct.EnableUserRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableUserRequestBuilder {
+    authentication_type: Option<AuthenticationType>,
+    user_name: Option<String>,
+}
+
+impl EnableUserRequestBuilder {
+    /// Sets the [`authentication_type`](struct.EnableUserRequest.html#structfield.authentication_type) member.
+    pub fn authentication_type<T: Into<AuthenticationType>>(mut self, value: T) -> Self {
+        self.authentication_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.EnableUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<EnableUserRequest, ::rusoto_core::BuildError> {
+        Ok(EnableUserRequest {
+            authentication_type: self
+                .authentication_type
+                .ok_or(::rusoto_core::BuildError::missing("authentication_type"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct EnableUserResult {}
@@ -1008,12 +2901,42 @@ pub struct ExpireSessionRequest {
     pub session_id: String,
 }
 
+impl ExpireSessionRequest {
+    /// Returns a builder for `ExpireSessionRequest`, checking its required members when built.
+    pub fn builder() -> ExpireSessionRequestBuilder {
+        ExpireSessionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ExpireSessionRequest`](struct.ExpireSessionRequest.html).
+#[derive(Default, Debug)]
+pub struct ExpireSessionRequestBuilder {
+    session_id: Option<String>,
+}
+
+impl ExpireSessionRequestBuilder {
+    /// Sets the [`session_id`](struct.ExpireSessionRequest.html#structfield.session_id) member.
+    pub fn session_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.session_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExpireSessionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ExpireSessionRequest, ::rusoto_core::BuildError> {
+        Ok(ExpireSessionRequest {
+            session_id: self
+                .session_id
+                .ok_or(::rusoto_core::BuildError::missing("session_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ExpireSessionResult {}
 
 /// <p>Describes a fleet.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Fleet {
     /// <p>The Amazon Resource Name (ARN) for the fleet.</p>
@@ -1024,8 +2947,9 @@ pub struct Fleet {
     pub compute_capacity_status: ComputeCapacityStatus,
     /// <p>The time the fleet was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The description to display.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1053,7 +2977,7 @@ pub struct Fleet {
     /// <p><p>The fleet type.</p> <dl> <dt>ALWAYS<em>ON</dt> <dd> <p>Provides users with instant-on access to their apps. You are charged for all running instances in your fleet, even if no users are streaming apps.</p> </dd> <dt>ON</em>DEMAND</dt> <dd> <p>Provide users with access to applications after they connect, which takes one to two minutes. You are charged for instance streaming when users are connected and a small hourly fee for instances that are not streaming apps.</p> </dd> </dl></p>
     #[serde(rename = "FleetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fleet_type: Option<String>,
+    pub fleet_type: Option<FleetType>,
     /// <p>The ARN of the IAM role that is applied to the fleet. To assume a role, the fleet instance calls the AWS Security Token Service (STS) <code>AssumeRole</code> API operation and passes the ARN of the role to use. The operation creates a new session with temporary credentials.</p>
     #[serde(rename = "IamRoleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1082,13 +3006,109 @@ pub struct Fleet {
     pub name: String,
     /// <p>The current state for the fleet.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: FleetState,
     /// <p>The VPC configuration for the fleet.</p>
     #[serde(rename = "VpcConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vpc_config: Option<VpcConfig>,
 }
 
+/// <p>The fleet attribute.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FleetAttribute {
+    /// <p><code>VPC_CONFIGURATION</code></p>
+    VpcConfiguration,
+    /// <p><code>VPC_CONFIGURATION_SECURITY_GROUP_IDS</code></p>
+    VpcConfigurationSecurityGroupIds,
+    /// <p><code>DOMAIN_JOIN_INFO</code></p>
+    DomainJoinInfo,
+    /// <p><code>IAM_ROLE_ARN</code></p>
+    IamRoleArn,
+    /// <p><code>USB_DEVICE_FILTER_STRINGS</code></p>
+    UsbDeviceFilterStrings,
+    /// <p><code>SESSION_SCRIPT_S3_LOCATION</code></p>
+    SessionScriptS3Location,
+    /// <p><code>MAX_SESSIONS_PER_INSTANCE</code></p>
+    MaxSessionsPerInstance,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FleetAttribute {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetAttribute::VpcConfiguration => "VPC_CONFIGURATION",
+            FleetAttribute::VpcConfigurationSecurityGroupIds => {
+                "VPC_CONFIGURATION_SECURITY_GROUP_IDS"
+            }
+            FleetAttribute::DomainJoinInfo => "DOMAIN_JOIN_INFO",
+            FleetAttribute::IamRoleArn => "IAM_ROLE_ARN",
+            FleetAttribute::UsbDeviceFilterStrings => "USB_DEVICE_FILTER_STRINGS",
+            FleetAttribute::SessionScriptS3Location => "SESSION_SCRIPT_S3_LOCATION",
+            FleetAttribute::MaxSessionsPerInstance => "MAX_SESSIONS_PER_INSTANCE",
+            FleetAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FleetAttribute {
+    fn from(value: &'a str) -> FleetAttribute {
+        match value {
+            "VPC_CONFIGURATION" => FleetAttribute::VpcConfiguration,
+            "VPC_CONFIGURATION_SECURITY_GROUP_IDS" => {
+                FleetAttribute::VpcConfigurationSecurityGroupIds
+            }
+            "DOMAIN_JOIN_INFO" => FleetAttribute::DomainJoinInfo,
+            "IAM_ROLE_ARN" => FleetAttribute::IamRoleArn,
+            "USB_DEVICE_FILTER_STRINGS" => FleetAttribute::UsbDeviceFilterStrings,
+            "SESSION_SCRIPT_S3_LOCATION" => FleetAttribute::SessionScriptS3Location,
+            "MAX_SESSIONS_PER_INSTANCE" => FleetAttribute::MaxSessionsPerInstance,
+            _ => FleetAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetAttribute {
+    fn from(value: String) -> FleetAttribute {
+        FleetAttribute::from(value.as_str())
+    }
+}
+
+impl From<FleetAttribute> for String {
+    fn from(value: FleetAttribute) -> String {
+        match value {
+            FleetAttribute::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FleetAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FleetAttribute {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FleetAttribute {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FleetAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FleetAttribute::from(value))
+    }
+}
+
 /// <p>Describes a fleet error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1096,87 +3116,473 @@ pub struct FleetError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<FleetErrorCode>,
     /// <p>The error message.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
 }
 
-/// <p>Describes an image.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Image {
-    /// <p>The applications associated with the image.</p>
-    #[serde(rename = "Applications")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub applications: Option<Vec<Application>>,
-    /// <p>The version of the AppStream 2.0 agent to use for instances that are launched from this image. </p>
-    #[serde(rename = "AppstreamAgentVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub appstream_agent_version: Option<String>,
-    /// <p>The ARN of the image.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The ARN of the image from which this image was created.</p>
-    #[serde(rename = "BaseImageArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub base_image_arn: Option<String>,
-    /// <p>The time the image was created.</p>
-    #[serde(rename = "CreatedTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
-    /// <p>The description to display.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The image name to display.</p>
-    #[serde(rename = "DisplayName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
-    /// <p>The name of the image builder that was used to create the private image. If the image is shared, this value is null.</p>
-    #[serde(rename = "ImageBuilderName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_builder_name: Option<String>,
-    /// <p>Indicates whether an image builder can be launched from this image.</p>
-    #[serde(rename = "ImageBuilderSupported")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_builder_supported: Option<bool>,
-    /// <p>The permissions to provide to the destination AWS account for the specified image.</p>
-    #[serde(rename = "ImagePermissions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_permissions: Option<ImagePermissions>,
-    /// <p>The name of the image.</p>
-    #[serde(rename = "Name")]
-    pub name: String,
-    /// <p>The operating system platform of the image.</p>
-    #[serde(rename = "Platform")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
-    /// <p>The release date of the public base image. For private images, this date is the release date of the base image from which the image was created.</p>
-    #[serde(rename = "PublicBaseImageReleasedDate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub public_base_image_released_date: Option<f64>,
-    /// <p>The image starts in the <code>PENDING</code> state. If image creation succeeds, the state is <code>AVAILABLE</code>. If image creation fails, the state is <code>FAILED</code>.</p>
-    #[serde(rename = "State")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
-    /// <p>The reason why the last state change occurred.</p>
-    #[serde(rename = "StateChangeReason")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub state_change_reason: Option<ImageStateChangeReason>,
-    /// <p>Indicates whether the image is public or private.</p>
-    #[serde(rename = "Visibility")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub visibility: Option<String>,
+/// <p>The error code.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FleetErrorCode {
+    /// <p><code>IAM_SERVICE_ROLE_MISSING_ENI_DESCRIBE_ACTION</code></p>
+    IamServiceRoleMissingEniDescribeAction,
+    /// <p><code>IAM_SERVICE_ROLE_MISSING_ENI_CREATE_ACTION</code></p>
+    IamServiceRoleMissingEniCreateAction,
+    /// <p><code>IAM_SERVICE_ROLE_MISSING_ENI_DELETE_ACTION</code></p>
+    IamServiceRoleMissingEniDeleteAction,
+    /// <p><code>NETWORK_INTERFACE_LIMIT_EXCEEDED</code></p>
+    NetworkInterfaceLimitExceeded,
+    /// <p><code>INTERNAL_SERVICE_ERROR</code></p>
+    InternalServiceError,
+    /// <p><code>IAM_SERVICE_ROLE_IS_MISSING</code></p>
+    IamServiceRoleIsMissing,
+    /// <p><code>MACHINE_ROLE_IS_MISSING</code></p>
+    MachineRoleIsMissing,
+    /// <p><code>STS_DISABLED_IN_REGION</code></p>
+    StsDisabledInRegion,
+    /// <p><code>SUBNET_HAS_INSUFFICIENT_IP_ADDRESSES</code></p>
+    SubnetHasInsufficientIpAddresses,
+    /// <p><code>IAM_SERVICE_ROLE_MISSING_DESCRIBE_SUBNET_ACTION</code></p>
+    IamServiceRoleMissingDescribeSubnetAction,
+    /// <p><code>SUBNET_NOT_FOUND</code></p>
+    SubnetNotFound,
+    /// <p><code>IMAGE_NOT_FOUND</code></p>
+    ImageNotFound,
+    /// <p><code>INVALID_SUBNET_CONFIGURATION</code></p>
+    InvalidSubnetConfiguration,
+    /// <p><code>SECURITY_GROUPS_NOT_FOUND</code></p>
+    SecurityGroupsNotFound,
+    /// <p><code>IGW_NOT_ATTACHED</code></p>
+    IgwNotAttached,
+    /// <p><code>IAM_SERVICE_ROLE_MISSING_DESCRIBE_SECURITY_GROUPS_ACTION</code></p>
+    IamServiceRoleMissingDescribeSecurityGroupsAction,
+    /// <p><code>FLEET_STOPPED</code></p>
+    FleetStopped,
+    /// <p><code>FLEET_INSTANCE_PROVISIONING_FAILURE</code></p>
+    FleetInstanceProvisioningFailure,
+    /// <p><code>DOMAIN_JOIN_ERROR_FILE_NOT_FOUND</code></p>
+    DomainJoinErrorFileNotFound,
+    /// <p><code>DOMAIN_JOIN_ERROR_ACCESS_DENIED</code></p>
+    DomainJoinErrorAccessDenied,
+    /// <p><code>DOMAIN_JOIN_ERROR_LOGON_FAILURE</code></p>
+    DomainJoinErrorLogonFailure,
+    /// <p><code>DOMAIN_JOIN_ERROR_INVALID_PARAMETER</code></p>
+    DomainJoinErrorInvalidParameter,
+    /// <p><code>DOMAIN_JOIN_ERROR_MORE_DATA</code></p>
+    DomainJoinErrorMoreData,
+    /// <p><code>DOMAIN_JOIN_ERROR_NO_SUCH_DOMAIN</code></p>
+    DomainJoinErrorNoSuchDomain,
+    /// <p><code>DOMAIN_JOIN_ERROR_NOT_SUPPORTED</code></p>
+    DomainJoinErrorNotSupported,
+    /// <p><code>DOMAIN_JOIN_NERR_INVALID_WORKGROUP_NAME</code></p>
+    DomainJoinNerrInvalidWorkgroupName,
+    /// <p><code>DOMAIN_JOIN_NERR_WORKSTATION_NOT_STARTED</code></p>
+    DomainJoinNerrWorkstationNotStarted,
+    /// <p><code>DOMAIN_JOIN_ERROR_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED</code></p>
+    DomainJoinErrorDsMachineAccountQuotaExceeded,
+    /// <p><code>DOMAIN_JOIN_NERR_PASSWORD_EXPIRED</code></p>
+    DomainJoinNerrPasswordExpired,
+    /// <p><code>DOMAIN_JOIN_INTERNAL_SERVICE_ERROR</code></p>
+    DomainJoinInternalServiceError,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FleetErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetErrorCode::IamServiceRoleMissingEniDescribeAction => {
+                "IAM_SERVICE_ROLE_MISSING_ENI_DESCRIBE_ACTION"
+            }
+            FleetErrorCode::IamServiceRoleMissingEniCreateAction => {
+                "IAM_SERVICE_ROLE_MISSING_ENI_CREATE_ACTION"
+            }
+            FleetErrorCode::IamServiceRoleMissingEniDeleteAction => {
+                "IAM_SERVICE_ROLE_MISSING_ENI_DELETE_ACTION"
+            }
+            FleetErrorCode::NetworkInterfaceLimitExceeded => "NETWORK_INTERFACE_LIMIT_EXCEEDED",
+            FleetErrorCode::InternalServiceError => "INTERNAL_SERVICE_ERROR",
+            FleetErrorCode::IamServiceRoleIsMissing => "IAM_SERVICE_ROLE_IS_MISSING",
+            FleetErrorCode::MachineRoleIsMissing => "MACHINE_ROLE_IS_MISSING",
+            FleetErrorCode::StsDisabledInRegion => "STS_DISABLED_IN_REGION",
+            FleetErrorCode::SubnetHasInsufficientIpAddresses => {
+                "SUBNET_HAS_INSUFFICIENT_IP_ADDRESSES"
+            }
+            FleetErrorCode::IamServiceRoleMissingDescribeSubnetAction => {
+                "IAM_SERVICE_ROLE_MISSING_DESCRIBE_SUBNET_ACTION"
+            }
+            FleetErrorCode::SubnetNotFound => "SUBNET_NOT_FOUND",
+            FleetErrorCode::ImageNotFound => "IMAGE_NOT_FOUND",
+            FleetErrorCode::InvalidSubnetConfiguration => "INVALID_SUBNET_CONFIGURATION",
+            FleetErrorCode::SecurityGroupsNotFound => "SECURITY_GROUPS_NOT_FOUND",
+            FleetErrorCode::IgwNotAttached => "IGW_NOT_ATTACHED",
+            FleetErrorCode::IamServiceRoleMissingDescribeSecurityGroupsAction => {
+                "IAM_SERVICE_ROLE_MISSING_DESCRIBE_SECURITY_GROUPS_ACTION"
+            }
+            FleetErrorCode::FleetStopped => "FLEET_STOPPED",
+            FleetErrorCode::FleetInstanceProvisioningFailure => {
+                "FLEET_INSTANCE_PROVISIONING_FAILURE"
+            }
+            FleetErrorCode::DomainJoinErrorFileNotFound => "DOMAIN_JOIN_ERROR_FILE_NOT_FOUND",
+            FleetErrorCode::DomainJoinErrorAccessDenied => "DOMAIN_JOIN_ERROR_ACCESS_DENIED",
+            FleetErrorCode::DomainJoinErrorLogonFailure => "DOMAIN_JOIN_ERROR_LOGON_FAILURE",
+            FleetErrorCode::DomainJoinErrorInvalidParameter => {
+                "DOMAIN_JOIN_ERROR_INVALID_PARAMETER"
+            }
+            FleetErrorCode::DomainJoinErrorMoreData => "DOMAIN_JOIN_ERROR_MORE_DATA",
+            FleetErrorCode::DomainJoinErrorNoSuchDomain => "DOMAIN_JOIN_ERROR_NO_SUCH_DOMAIN",
+            FleetErrorCode::DomainJoinErrorNotSupported => "DOMAIN_JOIN_ERROR_NOT_SUPPORTED",
+            FleetErrorCode::DomainJoinNerrInvalidWorkgroupName => {
+                "DOMAIN_JOIN_NERR_INVALID_WORKGROUP_NAME"
+            }
+            FleetErrorCode::DomainJoinNerrWorkstationNotStarted => {
+                "DOMAIN_JOIN_NERR_WORKSTATION_NOT_STARTED"
+            }
+            FleetErrorCode::DomainJoinErrorDsMachineAccountQuotaExceeded => {
+                "DOMAIN_JOIN_ERROR_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED"
+            }
+            FleetErrorCode::DomainJoinNerrPasswordExpired => "DOMAIN_JOIN_NERR_PASSWORD_EXPIRED",
+            FleetErrorCode::DomainJoinInternalServiceError => "DOMAIN_JOIN_INTERNAL_SERVICE_ERROR",
+            FleetErrorCode::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Describes a virtual machine that is used to create an image. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ImageBuilder {
-    /// <p>The list of virtual private cloud (VPC) interface endpoint objects. Administrators can connect to the image builder only through the specified endpoints.</p>
+impl<'a> From<&'a str> for FleetErrorCode {
+    fn from(value: &'a str) -> FleetErrorCode {
+        match value {
+            "IAM_SERVICE_ROLE_MISSING_ENI_DESCRIBE_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingEniDescribeAction
+            }
+            "IAM_SERVICE_ROLE_MISSING_ENI_CREATE_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingEniCreateAction
+            }
+            "IAM_SERVICE_ROLE_MISSING_ENI_DELETE_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingEniDeleteAction
+            }
+            "NETWORK_INTERFACE_LIMIT_EXCEEDED" => FleetErrorCode::NetworkInterfaceLimitExceeded,
+            "INTERNAL_SERVICE_ERROR" => FleetErrorCode::InternalServiceError,
+            "IAM_SERVICE_ROLE_IS_MISSING" => FleetErrorCode::IamServiceRoleIsMissing,
+            "MACHINE_ROLE_IS_MISSING" => FleetErrorCode::MachineRoleIsMissing,
+            "STS_DISABLED_IN_REGION" => FleetErrorCode::StsDisabledInRegion,
+            "SUBNET_HAS_INSUFFICIENT_IP_ADDRESSES" => {
+                FleetErrorCode::SubnetHasInsufficientIpAddresses
+            }
+            "IAM_SERVICE_ROLE_MISSING_DESCRIBE_SUBNET_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingDescribeSubnetAction
+            }
+            "SUBNET_NOT_FOUND" => FleetErrorCode::SubnetNotFound,
+            "IMAGE_NOT_FOUND" => FleetErrorCode::ImageNotFound,
+            "INVALID_SUBNET_CONFIGURATION" => FleetErrorCode::InvalidSubnetConfiguration,
+            "SECURITY_GROUPS_NOT_FOUND" => FleetErrorCode::SecurityGroupsNotFound,
+            "IGW_NOT_ATTACHED" => FleetErrorCode::IgwNotAttached,
+            "IAM_SERVICE_ROLE_MISSING_DESCRIBE_SECURITY_GROUPS_ACTION" => {
+                FleetErrorCode::IamServiceRoleMissingDescribeSecurityGroupsAction
+            }
+            "FLEET_STOPPED" => FleetErrorCode::FleetStopped,
+            "FLEET_INSTANCE_PROVISIONING_FAILURE" => {
+                FleetErrorCode::FleetInstanceProvisioningFailure
+            }
+            "DOMAIN_JOIN_ERROR_FILE_NOT_FOUND" => FleetErrorCode::DomainJoinErrorFileNotFound,
+            "DOMAIN_JOIN_ERROR_ACCESS_DENIED" => FleetErrorCode::DomainJoinErrorAccessDenied,
+            "DOMAIN_JOIN_ERROR_LOGON_FAILURE" => FleetErrorCode::DomainJoinErrorLogonFailure,
+            "DOMAIN_JOIN_ERROR_INVALID_PARAMETER" => {
+                FleetErrorCode::DomainJoinErrorInvalidParameter
+            }
+            "DOMAIN_JOIN_ERROR_MORE_DATA" => FleetErrorCode::DomainJoinErrorMoreData,
+            "DOMAIN_JOIN_ERROR_NO_SUCH_DOMAIN" => FleetErrorCode::DomainJoinErrorNoSuchDomain,
+            "DOMAIN_JOIN_ERROR_NOT_SUPPORTED" => FleetErrorCode::DomainJoinErrorNotSupported,
+            "DOMAIN_JOIN_NERR_INVALID_WORKGROUP_NAME" => {
+                FleetErrorCode::DomainJoinNerrInvalidWorkgroupName
+            }
+            "DOMAIN_JOIN_NERR_WORKSTATION_NOT_STARTED" => {
+                FleetErrorCode::DomainJoinNerrWorkstationNotStarted
+            }
+            "DOMAIN_JOIN_ERROR_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED" => {
+                FleetErrorCode::DomainJoinErrorDsMachineAccountQuotaExceeded
+            }
+            "DOMAIN_JOIN_NERR_PASSWORD_EXPIRED" => FleetErrorCode::DomainJoinNerrPasswordExpired,
+            "DOMAIN_JOIN_INTERNAL_SERVICE_ERROR" => FleetErrorCode::DomainJoinInternalServiceError,
+            _ => FleetErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetErrorCode {
+    fn from(value: String) -> FleetErrorCode {
+        FleetErrorCode::from(value.as_str())
+    }
+}
+
+impl From<FleetErrorCode> for String {
+    fn from(value: FleetErrorCode) -> String {
+        match value {
+            FleetErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FleetErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FleetErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FleetErrorCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FleetErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FleetErrorCode::from(value))
+    }
+}
+
+/// <p>The current state for the fleet.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FleetState {
+    /// <p><code>STARTING</code></p>
+    Starting,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>STOPPING</code></p>
+    Stopping,
+    /// <p><code>STOPPED</code></p>
+    Stopped,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FleetState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetState::Starting => "STARTING",
+            FleetState::Running => "RUNNING",
+            FleetState::Stopping => "STOPPING",
+            FleetState::Stopped => "STOPPED",
+            FleetState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FleetState {
+    fn from(value: &'a str) -> FleetState {
+        match value {
+            "STARTING" => FleetState::Starting,
+            "RUNNING" => FleetState::Running,
+            "STOPPING" => FleetState::Stopping,
+            "STOPPED" => FleetState::Stopped,
+            _ => FleetState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetState {
+    fn from(value: String) -> FleetState {
+        FleetState::from(value.as_str())
+    }
+}
+
+impl From<FleetState> for String {
+    fn from(value: FleetState) -> String {
+        match value {
+            FleetState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FleetState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FleetState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FleetState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FleetState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FleetState::from(value))
+    }
+}
+
+/// <p><p>The fleet type.</p> <dl> <dt>ALWAYS<em>ON</dt> <dd> <p>Provides users with instant-on access to their apps. You are charged for all running instances in your fleet, even if no users are streaming apps.</p> </dd> <dt>ON</em>DEMAND</dt> <dd> <p>Provide users with access to applications after they connect, which takes one to two minutes. You are charged for instance streaming when users are connected and a small hourly fee for instances that are not streaming apps.</p> </dd> </dl></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FleetType {
+    /// <p><code>ALWAYS_ON</code></p>
+    AlwaysOn,
+    /// <p><code>ON_DEMAND</code></p>
+    OnDemand,
+    /// <p><code>ELASTIC</code></p>
+    Elastic,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FleetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FleetType::AlwaysOn => "ALWAYS_ON",
+            FleetType::OnDemand => "ON_DEMAND",
+            FleetType::Elastic => "ELASTIC",
+            FleetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FleetType {
+    fn from(value: &'a str) -> FleetType {
+        match value {
+            "ALWAYS_ON" => FleetType::AlwaysOn,
+            "ON_DEMAND" => FleetType::OnDemand,
+            "ELASTIC" => FleetType::Elastic,
+            _ => FleetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FleetType {
+    fn from(value: String) -> FleetType {
+        FleetType::from(value.as_str())
+    }
+}
+
+impl From<FleetType> for String {
+    fn from(value: FleetType) -> String {
+        match value {
+            FleetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FleetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FleetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FleetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FleetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FleetType::from(value))
+    }
+}
+
+/// <p>Describes an image.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Image {
+    /// <p>The applications associated with the image.</p>
+    #[serde(rename = "Applications")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applications: Option<Vec<Application>>,
+    /// <p>The version of the AppStream 2.0 agent to use for instances that are launched from this image. </p>
+    #[serde(rename = "AppstreamAgentVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub appstream_agent_version: Option<String>,
+    /// <p>The ARN of the image.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The ARN of the image from which this image was created.</p>
+    #[serde(rename = "BaseImageArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_image_arn: Option<String>,
+    /// <p>The time the image was created.</p>
+    #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<EpochTimestamp>,
+    /// <p>The description to display.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The image name to display.</p>
+    #[serde(rename = "DisplayName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// <p>The name of the image builder that was used to create the private image. If the image is shared, this value is null.</p>
+    #[serde(rename = "ImageBuilderName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_builder_name: Option<String>,
+    /// <p>Indicates whether an image builder can be launched from this image.</p>
+    #[serde(rename = "ImageBuilderSupported")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_builder_supported: Option<bool>,
+    /// <p>The permissions to provide to the destination AWS account for the specified image.</p>
+    #[serde(rename = "ImagePermissions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_permissions: Option<ImagePermissions>,
+    /// <p>The name of the image.</p>
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// <p>The operating system platform of the image.</p>
+    #[serde(rename = "Platform")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<PlatformType>,
+    /// <p>The release date of the public base image. For private images, this date is the release date of the base image from which the image was created.</p>
+    #[serde(rename = "PublicBaseImageReleasedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_base_image_released_date: Option<EpochTimestamp>,
+    /// <p>The image starts in the <code>PENDING</code> state. If image creation succeeds, the state is <code>AVAILABLE</code>. If image creation fails, the state is <code>FAILED</code>.</p>
+    #[serde(rename = "State")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<ImageState>,
+    /// <p>The reason why the last state change occurred.</p>
+    #[serde(rename = "StateChangeReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_change_reason: Option<ImageStateChangeReason>,
+    /// <p>Indicates whether the image is public or private.</p>
+    #[serde(rename = "Visibility")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<VisibilityType>,
+}
+
+/// <p>Describes a virtual machine that is used to create an image. </p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ImageBuilder {
+    /// <p>The list of virtual private cloud (VPC) interface endpoint objects. Administrators can connect to the image builder only through the specified endpoints.</p>
     #[serde(rename = "AccessEndpoints")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub access_endpoints: Option<Vec<AccessEndpoint>>,
@@ -1190,8 +3596,9 @@ pub struct ImageBuilder {
     pub arn: Option<String>,
     /// <p>The time stamp when the image builder was created.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>The description to display.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1233,11 +3640,11 @@ pub struct ImageBuilder {
     /// <p>The operating system platform of the image builder.</p>
     #[serde(rename = "Platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<PlatformType>,
     /// <p>The state of the image builder.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ImageBuilderState>,
     /// <p>The reason why the last state change occurred.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1248,6 +3655,114 @@ pub struct ImageBuilder {
     pub vpc_config: Option<VpcConfig>,
 }
 
+/// <p>The state of the image builder.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImageBuilderState {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>UPDATING_AGENT</code></p>
+    UpdatingAgent,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>STOPPING</code></p>
+    Stopping,
+    /// <p><code>STOPPED</code></p>
+    Stopped,
+    /// <p><code>REBOOTING</code></p>
+    Rebooting,
+    /// <p><code>SNAPSHOTTING</code></p>
+    Snapshotting,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>PENDING_QUALIFICATION</code></p>
+    PendingQualification,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ImageBuilderState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageBuilderState::Pending => "PENDING",
+            ImageBuilderState::UpdatingAgent => "UPDATING_AGENT",
+            ImageBuilderState::Running => "RUNNING",
+            ImageBuilderState::Stopping => "STOPPING",
+            ImageBuilderState::Stopped => "STOPPED",
+            ImageBuilderState::Rebooting => "REBOOTING",
+            ImageBuilderState::Snapshotting => "SNAPSHOTTING",
+            ImageBuilderState::Deleting => "DELETING",
+            ImageBuilderState::Failed => "FAILED",
+            ImageBuilderState::Updating => "UPDATING",
+            ImageBuilderState::PendingQualification => "PENDING_QUALIFICATION",
+            ImageBuilderState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ImageBuilderState {
+    fn from(value: &'a str) -> ImageBuilderState {
+        match value {
+            "PENDING" => ImageBuilderState::Pending,
+            "UPDATING_AGENT" => ImageBuilderState::UpdatingAgent,
+            "RUNNING" => ImageBuilderState::Running,
+            "STOPPING" => ImageBuilderState::Stopping,
+            "STOPPED" => ImageBuilderState::Stopped,
+            "REBOOTING" => ImageBuilderState::Rebooting,
+            "SNAPSHOTTING" => ImageBuilderState::Snapshotting,
+            "DELETING" => ImageBuilderState::Deleting,
+            "FAILED" => ImageBuilderState::Failed,
+            "UPDATING" => ImageBuilderState::Updating,
+            "PENDING_QUALIFICATION" => ImageBuilderState::PendingQualification,
+            _ => ImageBuilderState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageBuilderState {
+    fn from(value: String) -> ImageBuilderState {
+        ImageBuilderState::from(value.as_str())
+    }
+}
+
+impl From<ImageBuilderState> for String {
+    fn from(value: ImageBuilderState) -> String {
+        match value {
+            ImageBuilderState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ImageBuilderState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ImageBuilderState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageBuilderState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ImageBuilderState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ImageBuilderState::from(value))
+    }
+}
+
 /// <p>Describes the reason why the last image builder state change occurred.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1255,13 +3770,87 @@ pub struct ImageBuilderStateChangeReason {
     /// <p>The state change reason code.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<ImageBuilderStateChangeReasonCode>,
     /// <p>The state change reason message.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+/// <p>The state change reason code.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImageBuilderStateChangeReasonCode {
+    /// <p><code>INTERNAL_ERROR</code></p>
+    InternalError,
+    /// <p><code>IMAGE_UNAVAILABLE</code></p>
+    ImageUnavailable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ImageBuilderStateChangeReasonCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageBuilderStateChangeReasonCode::InternalError => "INTERNAL_ERROR",
+            ImageBuilderStateChangeReasonCode::ImageUnavailable => "IMAGE_UNAVAILABLE",
+            ImageBuilderStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ImageBuilderStateChangeReasonCode {
+    fn from(value: &'a str) -> ImageBuilderStateChangeReasonCode {
+        match value {
+            "INTERNAL_ERROR" => ImageBuilderStateChangeReasonCode::InternalError,
+            "IMAGE_UNAVAILABLE" => ImageBuilderStateChangeReasonCode::ImageUnavailable,
+            _ => ImageBuilderStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageBuilderStateChangeReasonCode {
+    fn from(value: String) -> ImageBuilderStateChangeReasonCode {
+        ImageBuilderStateChangeReasonCode::from(value.as_str())
+    }
+}
+
+impl From<ImageBuilderStateChangeReasonCode> for String {
+    fn from(value: ImageBuilderStateChangeReasonCode) -> String {
+        match value {
+            ImageBuilderStateChangeReasonCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ImageBuilderStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ImageBuilderStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageBuilderStateChangeReasonCode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ImageBuilderStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ImageBuilderStateChangeReasonCode::from(value))
+    }
+}
+
 /// <p>Describes the permissions for an image. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImagePermissions {
@@ -1275,32 +3864,202 @@ pub struct ImagePermissions {
     pub allow_image_builder: Option<bool>,
 }
 
-/// <p>Describes the reason why the last image state change occurred.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ImageStateChangeReason {
-    /// <p>The state change reason code.</p>
-    #[serde(rename = "Code")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
-    /// <p>The state change reason message.</p>
-    #[serde(rename = "Message")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
+/// <p>The image starts in the <code>PENDING</code> state. If image creation succeeds, the state is <code>AVAILABLE</code>. If image creation fails, the state is <code>FAILED</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImageState {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>COPYING</code></p>
+    Copying,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>IMPORTING</code></p>
+    Importing,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ImageState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageState::Pending => "PENDING",
+            ImageState::Available => "AVAILABLE",
+            ImageState::Failed => "FAILED",
+            ImageState::Copying => "COPYING",
+            ImageState::Deleting => "DELETING",
+            ImageState::Creating => "CREATING",
+            ImageState::Importing => "IMPORTING",
+            ImageState::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Describes the error that is returned when a usage report can't be generated.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct LastReportGenerationExecutionError {
-    /// <p>The error code for the error that is returned when a usage report can't be generated.</p>
-    #[serde(rename = "ErrorCode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
-    /// <p>The error message for the error that is returned when a usage report can't be generated.</p>
-    #[serde(rename = "ErrorMessage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_message: Option<String>,
+impl<'a> From<&'a str> for ImageState {
+    fn from(value: &'a str) -> ImageState {
+        match value {
+            "PENDING" => ImageState::Pending,
+            "AVAILABLE" => ImageState::Available,
+            "FAILED" => ImageState::Failed,
+            "COPYING" => ImageState::Copying,
+            "DELETING" => ImageState::Deleting,
+            "CREATING" => ImageState::Creating,
+            "IMPORTING" => ImageState::Importing,
+            _ => ImageState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageState {
+    fn from(value: String) -> ImageState {
+        ImageState::from(value.as_str())
+    }
+}
+
+impl From<ImageState> for String {
+    fn from(value: ImageState) -> String {
+        match value {
+            ImageState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ImageState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ImageState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ImageState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ImageState::from(value))
+    }
+}
+
+/// <p>Describes the reason why the last image state change occurred.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ImageStateChangeReason {
+    /// <p>The state change reason code.</p>
+    #[serde(rename = "Code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<ImageStateChangeReasonCode>,
+    /// <p>The state change reason message.</p>
+    #[serde(rename = "Message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// <p>The state change reason code.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImageStateChangeReasonCode {
+    /// <p><code>INTERNAL_ERROR</code></p>
+    InternalError,
+    /// <p><code>IMAGE_BUILDER_NOT_AVAILABLE</code></p>
+    ImageBuilderNotAvailable,
+    /// <p><code>IMAGE_COPY_FAILURE</code></p>
+    ImageCopyFailure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ImageStateChangeReasonCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageStateChangeReasonCode::InternalError => "INTERNAL_ERROR",
+            ImageStateChangeReasonCode::ImageBuilderNotAvailable => "IMAGE_BUILDER_NOT_AVAILABLE",
+            ImageStateChangeReasonCode::ImageCopyFailure => "IMAGE_COPY_FAILURE",
+            ImageStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ImageStateChangeReasonCode {
+    fn from(value: &'a str) -> ImageStateChangeReasonCode {
+        match value {
+            "INTERNAL_ERROR" => ImageStateChangeReasonCode::InternalError,
+            "IMAGE_BUILDER_NOT_AVAILABLE" => ImageStateChangeReasonCode::ImageBuilderNotAvailable,
+            "IMAGE_COPY_FAILURE" => ImageStateChangeReasonCode::ImageCopyFailure,
+            _ => ImageStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageStateChangeReasonCode {
+    fn from(value: String) -> ImageStateChangeReasonCode {
+        ImageStateChangeReasonCode::from(value.as_str())
+    }
+}
+
+impl From<ImageStateChangeReasonCode> for String {
+    fn from(value: ImageStateChangeReasonCode) -> String {
+        match value {
+            ImageStateChangeReasonCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ImageStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ImageStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageStateChangeReasonCode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ImageStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ImageStateChangeReasonCode::from(value))
+    }
+}
+
+/// <p>Describes the error that is returned when a usage report can't be generated.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct LastReportGenerationExecutionError {
+    /// <p>The error code for the error that is returned when a usage report can't be generated.</p>
+    #[serde(rename = "ErrorCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<UsageReportExecutionErrorCode>,
+    /// <p>The error message for the error that is returned when a usage report can't be generated.</p>
+    #[serde(rename = "ErrorMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1314,6 +4073,46 @@ pub struct ListAssociatedFleetsRequest {
     pub stack_name: String,
 }
 
+impl ListAssociatedFleetsRequest {
+    /// Returns a builder for `ListAssociatedFleetsRequest`, checking its required members when built.
+    pub fn builder() -> ListAssociatedFleetsRequestBuilder {
+        ListAssociatedFleetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAssociatedFleetsRequest`](struct.ListAssociatedFleetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAssociatedFleetsRequestBuilder {
+    next_token: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl ListAssociatedFleetsRequestBuilder {
+    /// Sets the [`next_token`](struct.ListAssociatedFleetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.ListAssociatedFleetsRequest.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAssociatedFleetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAssociatedFleetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListAssociatedFleetsRequest {
+            next_token: self.next_token,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAssociatedFleetsResult {
@@ -1338,6 +4137,46 @@ pub struct ListAssociatedStacksRequest {
     pub next_token: Option<String>,
 }
 
+impl ListAssociatedStacksRequest {
+    /// Returns a builder for `ListAssociatedStacksRequest`, checking its required members when built.
+    pub fn builder() -> ListAssociatedStacksRequestBuilder {
+        ListAssociatedStacksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAssociatedStacksRequest`](struct.ListAssociatedStacksRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAssociatedStacksRequestBuilder {
+    fleet_name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListAssociatedStacksRequestBuilder {
+    /// Sets the [`fleet_name`](struct.ListAssociatedStacksRequest.html#structfield.fleet_name) member.
+    pub fn fleet_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.fleet_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAssociatedStacksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAssociatedStacksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAssociatedStacksRequest, ::rusoto_core::BuildError> {
+        Ok(ListAssociatedStacksRequest {
+            fleet_name: self
+                .fleet_name
+                .ok_or(::rusoto_core::BuildError::missing("fleet_name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAssociatedStacksResult {
@@ -1358,6 +4197,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -1367,6 +4238,78 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+/// <p><p>The action to take for the welcome email that is sent to a user after the user is created in the user pool. If you specify SUPPRESS, no email is sent. If you specify RESEND, do not specify the first name or last name of the user. If the value is null, the email is sent. </p> <note> <p>The temporary password in the welcome email is valid for only 7 days. If users don’t set their passwords within 7 days, you must send them a new welcome email.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MessageAction {
+    /// <p><code>SUPPRESS</code></p>
+    Suppress,
+    /// <p><code>RESEND</code></p>
+    Resend,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MessageAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageAction::Suppress => "SUPPRESS",
+            MessageAction::Resend => "RESEND",
+            MessageAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MessageAction {
+    fn from(value: &'a str) -> MessageAction {
+        match value {
+            "SUPPRESS" => MessageAction::Suppress,
+            "RESEND" => MessageAction::Resend,
+            _ => MessageAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageAction {
+    fn from(value: String) -> MessageAction {
+        MessageAction::from(value.as_str())
+    }
+}
+
+impl From<MessageAction> for String {
+    fn from(value: MessageAction) -> String {
+        match value {
+            MessageAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MessageAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MessageAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MessageAction {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MessageAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MessageAction::from(value))
+    }
+}
+
 /// <p>Describes the network details of the fleet or image builder instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1381,6 +4324,162 @@ pub struct NetworkAccessConfiguration {
     pub eni_private_ip_address: Option<String>,
 }
 
+/// <p>Indicates whether the action is enabled or disabled.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Permission {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Permission {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permission::Enabled => "ENABLED",
+            Permission::Disabled => "DISABLED",
+            Permission::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Permission {
+    fn from(value: &'a str) -> Permission {
+        match value {
+            "ENABLED" => Permission::Enabled,
+            "DISABLED" => Permission::Disabled,
+            _ => Permission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Permission {
+    fn from(value: String) -> Permission {
+        Permission::from(value.as_str())
+    }
+}
+
+impl From<Permission> for String {
+    fn from(value: Permission) -> String {
+        match value {
+            Permission::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Permission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Permission::from(value))
+    }
+}
+
+/// <p>The operating system platform of the image.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlatformType {
+    /// <p><code>WINDOWS</code></p>
+    Windows,
+    /// <p><code>WINDOWS_SERVER_2016</code></p>
+    WindowsServer2016,
+    /// <p><code>WINDOWS_SERVER_2019</code></p>
+    WindowsServer2019,
+    /// <p><code>WINDOWS_SERVER_2022</code></p>
+    WindowsServer2022,
+    /// <p><code>AMAZON_LINUX2</code></p>
+    AmazonLinux2,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PlatformType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlatformType::Windows => "WINDOWS",
+            PlatformType::WindowsServer2016 => "WINDOWS_SERVER_2016",
+            PlatformType::WindowsServer2019 => "WINDOWS_SERVER_2019",
+            PlatformType::WindowsServer2022 => "WINDOWS_SERVER_2022",
+            PlatformType::AmazonLinux2 => "AMAZON_LINUX2",
+            PlatformType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PlatformType {
+    fn from(value: &'a str) -> PlatformType {
+        match value {
+            "WINDOWS" => PlatformType::Windows,
+            "WINDOWS_SERVER_2016" => PlatformType::WindowsServer2016,
+            "WINDOWS_SERVER_2019" => PlatformType::WindowsServer2019,
+            "WINDOWS_SERVER_2022" => PlatformType::WindowsServer2022,
+            "AMAZON_LINUX2" => PlatformType::AmazonLinux2,
+            _ => PlatformType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlatformType {
+    fn from(value: String) -> PlatformType {
+        PlatformType::from(value.as_str())
+    }
+}
+
+impl From<PlatformType> for String {
+    fn from(value: PlatformType) -> String {
+        match value {
+            PlatformType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PlatformType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PlatformType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlatformType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PlatformType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PlatformType::from(value))
+    }
+}
+
 /// <p>Describes a resource error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1388,15 +4487,16 @@ pub struct ResourceError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<FleetErrorCode>,
     /// <p>The error message.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
     /// <p>The time the error occurred.</p>
     #[serde(rename = "ErrorTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_timestamp: Option<f64>,
+    pub error_timestamp: Option<EpochTimestamp>,
 }
 
 /// <p>Describes the credentials for the service account used by the fleet or image builder to connect to the directory.</p>
@@ -1411,17 +4511,17 @@ pub struct ServiceAccountCredentials {
 }
 
 /// <p>Describes a streaming session.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Session {
     /// <p>The authentication method. The user is authenticated using a streaming URL (<code>API</code>) or SAML 2.0 federation (<code>SAML</code>).</p>
     #[serde(rename = "AuthenticationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_type: Option<String>,
+    pub authentication_type: Option<AuthenticationType>,
     /// <p>Specifies whether a user is connected to the streaming session.</p>
     #[serde(rename = "ConnectionState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_state: Option<String>,
+    pub connection_state: Option<SessionConnectionState>,
     /// <p>The name of the fleet for the streaming session.</p>
     #[serde(rename = "FleetName")]
     pub fleet_name: String,
@@ -1430,8 +4530,9 @@ pub struct Session {
     pub id: String,
     /// <p>The time when the streaming session is set to expire. This time is based on the <code>MaxUserDurationinSeconds</code> value, which determines the maximum length of time that a streaming session can run. A streaming session might end earlier than the time specified in <code>SessionMaxExpirationTime</code>, when the <code>DisconnectTimeOutInSeconds</code> elapses or the user chooses to end his or her session. If the <code>DisconnectTimeOutInSeconds</code> elapses, or the user chooses to end his or her session, the streaming instance is terminated and the streaming session ends.</p>
     #[serde(rename = "MaxExpirationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_expiration_time: Option<f64>,
+    pub max_expiration_time: Option<EpochTimestamp>,
     /// <p>The network details for the streaming session.</p>
     #[serde(rename = "NetworkAccessConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1441,65 +4542,215 @@ pub struct Session {
     pub stack_name: String,
     /// <p>The time when a streaming instance is dedicated for the user.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>The current state of the streaming session.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: SessionState,
     /// <p>The identifier of the user for whom the session was created.</p>
     #[serde(rename = "UserId")]
     pub user_id: String,
 }
 
-/// <p>Describes the permissions that are available to the specified AWS account for a shared image.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct SharedImagePermissions {
-    /// <p>Describes the permissions for a shared image.</p>
-    #[serde(rename = "imagePermissions")]
-    pub image_permissions: ImagePermissions,
-    /// <p>The 12-digit identifier of the AWS account with which the image is shared.</p>
-    #[serde(rename = "sharedAccountId")]
-    pub shared_account_id: String,
+/// <p>Specifies whether a user is connected to the streaming session.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SessionConnectionState {
+    /// <p><code>CONNECTED</code></p>
+    Connected,
+    /// <p><code>NOT_CONNECTED</code></p>
+    NotConnected,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Describes a stack.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Stack {
-    /// <p>The list of virtual private cloud (VPC) interface endpoint objects. Users of the stack can connect to AppStream 2.0 only through the specified endpoints. </p>
-    #[serde(rename = "AccessEndpoints")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_endpoints: Option<Vec<AccessEndpoint>>,
-    /// <p>The persistent application settings for users of the stack.</p>
-    #[serde(rename = "ApplicationSettings")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub application_settings: Option<ApplicationSettingsResponse>,
-    /// <p>The ARN of the stack.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The time the stack was created.</p>
-    #[serde(rename = "CreatedTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
-    /// <p>The description to display.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The stack name to display.</p>
-    #[serde(rename = "DisplayName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
-    /// <p>The URL that users are redirected to after they click the Send Feedback link. If no URL is specified, no Send Feedback link is displayed.</p>
-    #[serde(rename = "FeedbackURL")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub feedback_url: Option<String>,
-    /// <p>The name of the stack.</p>
-    #[serde(rename = "Name")]
-    pub name: String,
-    /// <p>The URL that users are redirected to after their streaming session ends.</p>
-    #[serde(rename = "RedirectURL")]
+impl SessionConnectionState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SessionConnectionState::Connected => "CONNECTED",
+            SessionConnectionState::NotConnected => "NOT_CONNECTED",
+            SessionConnectionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SessionConnectionState {
+    fn from(value: &'a str) -> SessionConnectionState {
+        match value {
+            "CONNECTED" => SessionConnectionState::Connected,
+            "NOT_CONNECTED" => SessionConnectionState::NotConnected,
+            _ => SessionConnectionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SessionConnectionState {
+    fn from(value: String) -> SessionConnectionState {
+        SessionConnectionState::from(value.as_str())
+    }
+}
+
+impl From<SessionConnectionState> for String {
+    fn from(value: SessionConnectionState) -> String {
+        match value {
+            SessionConnectionState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SessionConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SessionConnectionState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SessionConnectionState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SessionConnectionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SessionConnectionState::from(value))
+    }
+}
+
+/// <p>Possible values for the state of a streaming session.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SessionState {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>EXPIRED</code></p>
+    Expired,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SessionState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SessionState::Active => "ACTIVE",
+            SessionState::Pending => "PENDING",
+            SessionState::Expired => "EXPIRED",
+            SessionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SessionState {
+    fn from(value: &'a str) -> SessionState {
+        match value {
+            "ACTIVE" => SessionState::Active,
+            "PENDING" => SessionState::Pending,
+            "EXPIRED" => SessionState::Expired,
+            _ => SessionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SessionState {
+    fn from(value: String) -> SessionState {
+        SessionState::from(value.as_str())
+    }
+}
+
+impl From<SessionState> for String {
+    fn from(value: SessionState) -> String {
+        match value {
+            SessionState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SessionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SessionState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SessionState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SessionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SessionState::from(value))
+    }
+}
+
+/// <p>Describes the permissions that are available to the specified AWS account for a shared image.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct SharedImagePermissions {
+    /// <p>Describes the permissions for a shared image.</p>
+    #[serde(rename = "imagePermissions")]
+    pub image_permissions: ImagePermissions,
+    /// <p>The 12-digit identifier of the AWS account with which the image is shared.</p>
+    #[serde(rename = "sharedAccountId")]
+    pub shared_account_id: String,
+}
+
+/// <p>Describes a stack.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Stack {
+    /// <p>The list of virtual private cloud (VPC) interface endpoint objects. Users of the stack can connect to AppStream 2.0 only through the specified endpoints. </p>
+    #[serde(rename = "AccessEndpoints")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_endpoints: Option<Vec<AccessEndpoint>>,
+    /// <p>The persistent application settings for users of the stack.</p>
+    #[serde(rename = "ApplicationSettings")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_settings: Option<ApplicationSettingsResponse>,
+    /// <p>The ARN of the stack.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The time the stack was created.</p>
+    #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_time: Option<EpochTimestamp>,
+    /// <p>The description to display.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The stack name to display.</p>
+    #[serde(rename = "DisplayName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// <p>The URL that users are redirected to after they click the Send Feedback link. If no URL is specified, no Send Feedback link is displayed.</p>
+    #[serde(rename = "FeedbackURL")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feedback_url: Option<String>,
+    /// <p>The name of the stack.</p>
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// <p>The URL that users are redirected to after their streaming session ends.</p>
+    #[serde(rename = "RedirectURL")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub redirect_url: Option<String>,
     /// <p>The errors for the stack.</p>
@@ -1516,6 +4767,118 @@ pub struct Stack {
     pub user_settings: Option<Vec<UserSetting>>,
 }
 
+/// <p>The stack attributes to delete.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackAttribute {
+    /// <p><code>STORAGE_CONNECTORS</code></p>
+    StorageConnectors,
+    /// <p><code>STORAGE_CONNECTOR_HOMEFOLDERS</code></p>
+    StorageConnectorHomefolders,
+    /// <p><code>STORAGE_CONNECTOR_GOOGLE_DRIVE</code></p>
+    StorageConnectorGoogleDrive,
+    /// <p><code>STORAGE_CONNECTOR_ONE_DRIVE</code></p>
+    StorageConnectorOneDrive,
+    /// <p><code>REDIRECT_URL</code></p>
+    RedirectUrl,
+    /// <p><code>FEEDBACK_URL</code></p>
+    FeedbackUrl,
+    /// <p><code>THEME_NAME</code></p>
+    ThemeName,
+    /// <p><code>USER_SETTINGS</code></p>
+    UserSettings,
+    /// <p><code>EMBED_HOST_DOMAINS</code></p>
+    EmbedHostDomains,
+    /// <p><code>IAM_ROLE_ARN</code></p>
+    IamRoleArn,
+    /// <p><code>ACCESS_ENDPOINTS</code></p>
+    AccessEndpoints,
+    /// <p><code>STREAMING_EXPERIENCE_SETTINGS</code></p>
+    StreamingExperienceSettings,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackAttribute {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackAttribute::StorageConnectors => "STORAGE_CONNECTORS",
+            StackAttribute::StorageConnectorHomefolders => "STORAGE_CONNECTOR_HOMEFOLDERS",
+            StackAttribute::StorageConnectorGoogleDrive => "STORAGE_CONNECTOR_GOOGLE_DRIVE",
+            StackAttribute::StorageConnectorOneDrive => "STORAGE_CONNECTOR_ONE_DRIVE",
+            StackAttribute::RedirectUrl => "REDIRECT_URL",
+            StackAttribute::FeedbackUrl => "FEEDBACK_URL",
+            StackAttribute::ThemeName => "THEME_NAME",
+            StackAttribute::UserSettings => "USER_SETTINGS",
+            StackAttribute::EmbedHostDomains => "EMBED_HOST_DOMAINS",
+            StackAttribute::IamRoleArn => "IAM_ROLE_ARN",
+            StackAttribute::AccessEndpoints => "ACCESS_ENDPOINTS",
+            StackAttribute::StreamingExperienceSettings => "STREAMING_EXPERIENCE_SETTINGS",
+            StackAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackAttribute {
+    fn from(value: &'a str) -> StackAttribute {
+        match value {
+            "STORAGE_CONNECTORS" => StackAttribute::StorageConnectors,
+            "STORAGE_CONNECTOR_HOMEFOLDERS" => StackAttribute::StorageConnectorHomefolders,
+            "STORAGE_CONNECTOR_GOOGLE_DRIVE" => StackAttribute::StorageConnectorGoogleDrive,
+            "STORAGE_CONNECTOR_ONE_DRIVE" => StackAttribute::StorageConnectorOneDrive,
+            "REDIRECT_URL" => StackAttribute::RedirectUrl,
+            "FEEDBACK_URL" => StackAttribute::FeedbackUrl,
+            "THEME_NAME" => StackAttribute::ThemeName,
+            "USER_SETTINGS" => StackAttribute::UserSettings,
+            "EMBED_HOST_DOMAINS" => StackAttribute::EmbedHostDomains,
+            "IAM_ROLE_ARN" => StackAttribute::IamRoleArn,
+            "ACCESS_ENDPOINTS" => StackAttribute::AccessEndpoints,
+            "STREAMING_EXPERIENCE_SETTINGS" => StackAttribute::StreamingExperienceSettings,
+            _ => StackAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackAttribute {
+    fn from(value: String) -> StackAttribute {
+        StackAttribute::from(value.as_str())
+    }
+}
+
+impl From<StackAttribute> for String {
+    fn from(value: StackAttribute) -> String {
+        match value {
+            StackAttribute::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StackAttribute {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StackAttribute {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StackAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StackAttribute::from(value))
+    }
+}
+
 /// <p>Describes a stack error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1523,13 +4886,85 @@ pub struct StackError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<StackErrorCode>,
     /// <p>The error message.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
 }
 
+/// <p>The error code.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackErrorCode {
+    /// <p><code>STORAGE_CONNECTOR_ERROR</code></p>
+    StorageConnectorError,
+    /// <p><code>INTERNAL_SERVICE_ERROR</code></p>
+    InternalServiceError,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackErrorCode::StorageConnectorError => "STORAGE_CONNECTOR_ERROR",
+            StackErrorCode::InternalServiceError => "INTERNAL_SERVICE_ERROR",
+            StackErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackErrorCode {
+    fn from(value: &'a str) -> StackErrorCode {
+        match value {
+            "STORAGE_CONNECTOR_ERROR" => StackErrorCode::StorageConnectorError,
+            "INTERNAL_SERVICE_ERROR" => StackErrorCode::InternalServiceError,
+            _ => StackErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackErrorCode {
+    fn from(value: String) -> StackErrorCode {
+        StackErrorCode::from(value.as_str())
+    }
+}
+
+impl From<StackErrorCode> for String {
+    fn from(value: StackErrorCode) -> String {
+        match value {
+            StackErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StackErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StackErrorCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StackErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StackErrorCode::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartFleetRequest {
     /// <p>The name of the fleet.</p>
@@ -1537,6 +4972,36 @@ pub struct StartFleetRequest {
     pub name: String,
 }
 
+impl StartFleetRequest {
+    /// Returns a builder for `StartFleetRequest`, checking its required members when built.
+    pub fn builder() -> StartFleetRequestBuilder {
+        StartFleetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartFleetRequest`](struct.StartFleetRequest.html).
+#[derive(Default, Debug)]
+pub struct StartFleetRequestBuilder {
+    name: Option<String>,
+}
+
+impl StartFleetRequestBuilder {
+    /// Sets the [`name`](struct.StartFleetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartFleetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartFleetRequest, ::rusoto_core::BuildError> {
+        Ok(StartFleetRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartFleetResult {}
@@ -1552,6 +5017,46 @@ pub struct StartImageBuilderRequest {
     pub name: String,
 }
 
+impl StartImageBuilderRequest {
+    /// Returns a builder for `StartImageBuilderRequest`, checking its required members when built.
+    pub fn builder() -> StartImageBuilderRequestBuilder {
+        StartImageBuilderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartImageBuilderRequest`](struct.StartImageBuilderRequest.html).
+#[derive(Default, Debug)]
+pub struct StartImageBuilderRequestBuilder {
+    appstream_agent_version: Option<String>,
+    name: Option<String>,
+}
+
+impl StartImageBuilderRequestBuilder {
+    /// Sets the [`appstream_agent_version`](struct.StartImageBuilderRequest.html#structfield.appstream_agent_version) member.
+    pub fn appstream_agent_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.appstream_agent_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.StartImageBuilderRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartImageBuilderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartImageBuilderRequest, ::rusoto_core::BuildError> {
+        Ok(StartImageBuilderRequest {
+            appstream_agent_version: self.appstream_agent_version,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartImageBuilderResult {
@@ -1568,6 +5073,36 @@ pub struct StopFleetRequest {
     pub name: String,
 }
 
+impl StopFleetRequest {
+    /// Returns a builder for `StopFleetRequest`, checking its required members when built.
+    pub fn builder() -> StopFleetRequestBuilder {
+        StopFleetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopFleetRequest`](struct.StopFleetRequest.html).
+#[derive(Default, Debug)]
+pub struct StopFleetRequestBuilder {
+    name: Option<String>,
+}
+
+impl StopFleetRequestBuilder {
+    /// Sets the [`name`](struct.StopFleetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopFleetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopFleetRequest, ::rusoto_core::BuildError> {
+        Ok(StopFleetRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopFleetResult {}
@@ -1579,6 +5114,38 @@ pub struct StopImageBuilderRequest {
     pub name: String,
 }
 
+impl StopImageBuilderRequest {
+    /// Returns a builder for `StopImageBuilderRequest`, checking its required members when built.
+    pub fn builder() -> StopImageBuilderRequestBuilder {
+        StopImageBuilderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopImageBuilderRequest`](struct.StopImageBuilderRequest.html).
+#[derive(Default, Debug)]
+pub struct StopImageBuilderRequestBuilder {
+    name: Option<String>,
+}
+
+impl StopImageBuilderRequestBuilder {
+    /// Sets the [`name`](struct.StopImageBuilderRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopImageBuilderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopImageBuilderRequest, ::rusoto_core::BuildError> {
+        Ok(StopImageBuilderRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopImageBuilderResult {
@@ -1589,11 +5156,11 @@ pub struct StopImageBuilderResult {
 }
 
 /// <p>Describes a connector that enables persistent storage for users.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StorageConnector {
     /// <p>The type of storage connector.</p>
     #[serde(rename = "ConnectorType")]
-    pub connector_type: String,
+    pub connector_type: StorageConnectorType,
     /// <p>The names of the domains for the account.</p>
     #[serde(rename = "Domains")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1604,6 +5171,82 @@ pub struct StorageConnector {
     pub resource_identifier: Option<String>,
 }
 
+/// <p>The type of storage connector.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StorageConnectorType {
+    /// <p><code>HOMEFOLDERS</code></p>
+    Homefolders,
+    /// <p><code>GOOGLE_DRIVE</code></p>
+    GoogleDrive,
+    /// <p><code>ONE_DRIVE</code></p>
+    OneDrive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StorageConnectorType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StorageConnectorType::Homefolders => "HOMEFOLDERS",
+            StorageConnectorType::GoogleDrive => "GOOGLE_DRIVE",
+            StorageConnectorType::OneDrive => "ONE_DRIVE",
+            StorageConnectorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StorageConnectorType {
+    fn from(value: &'a str) -> StorageConnectorType {
+        match value {
+            "HOMEFOLDERS" => StorageConnectorType::Homefolders,
+            "GOOGLE_DRIVE" => StorageConnectorType::GoogleDrive,
+            "ONE_DRIVE" => StorageConnectorType::OneDrive,
+            _ => StorageConnectorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StorageConnectorType {
+    fn from(value: String) -> StorageConnectorType {
+        StorageConnectorType::from(value.as_str())
+    }
+}
+
+impl From<StorageConnectorType> for String {
+    fn from(value: StorageConnectorType) -> String {
+        match value {
+            StorageConnectorType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StorageConnectorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StorageConnectorType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StorageConnectorType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StorageConnectorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StorageConnectorType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
@@ -1614,9 +5257,49 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct TagResourceResponse {}
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct TagResourceResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
@@ -1628,6 +5311,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -1647,6 +5370,60 @@ pub struct UpdateDirectoryConfigRequest {
     pub service_account_credentials: Option<ServiceAccountCredentials>,
 }
 
+impl UpdateDirectoryConfigRequest {
+    /// Returns a builder for `UpdateDirectoryConfigRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDirectoryConfigRequestBuilder {
+        UpdateDirectoryConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDirectoryConfigRequest`](struct.UpdateDirectoryConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDirectoryConfigRequestBuilder {
+    directory_name: Option<String>,
+    organizational_unit_distinguished_names: Option<Vec<String>>,
+    service_account_credentials: Option<ServiceAccountCredentials>,
+}
+
+impl UpdateDirectoryConfigRequestBuilder {
+    /// Sets the [`directory_name`](struct.UpdateDirectoryConfigRequest.html#structfield.directory_name) member.
+    pub fn directory_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organizational_unit_distinguished_names`](struct.UpdateDirectoryConfigRequest.html#structfield.organizational_unit_distinguished_names) member.
+    pub fn organizational_unit_distinguished_names<T: Into<Vec<String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.organizational_unit_distinguished_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_account_credentials`](struct.UpdateDirectoryConfigRequest.html#structfield.service_account_credentials) member.
+    pub fn service_account_credentials<T: Into<ServiceAccountCredentials>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.service_account_credentials = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDirectoryConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDirectoryConfigRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDirectoryConfigRequest {
+            directory_name: self
+                .directory_name
+                .ok_or(::rusoto_core::BuildError::missing("directory_name"))?,
+            organizational_unit_distinguished_names: self.organizational_unit_distinguished_names,
+            service_account_credentials: self.service_account_credentials,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDirectoryConfigResult {
@@ -1661,7 +5438,7 @@ pub struct UpdateFleetRequest {
     /// <p>The fleet attributes to delete.</p>
     #[serde(rename = "AttributesToDelete")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes_to_delete: Option<Vec<String>>,
+    pub attributes_to_delete: Option<Vec<FleetAttribute>>,
     /// <p>The desired capacity for the fleet.</p>
     #[serde(rename = "ComputeCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1720,6 +5497,146 @@ pub struct UpdateFleetRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl UpdateFleetRequest {
+    /// Returns a builder for `UpdateFleetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFleetRequestBuilder {
+        UpdateFleetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFleetRequest`](struct.UpdateFleetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFleetRequestBuilder {
+    attributes_to_delete: Option<Vec<FleetAttribute>>,
+    compute_capacity: Option<ComputeCapacity>,
+    description: Option<String>,
+    disconnect_timeout_in_seconds: Option<i64>,
+    display_name: Option<String>,
+    domain_join_info: Option<DomainJoinInfo>,
+    enable_default_internet_access: Option<bool>,
+    iam_role_arn: Option<String>,
+    idle_disconnect_timeout_in_seconds: Option<i64>,
+    image_arn: Option<String>,
+    image_name: Option<String>,
+    instance_type: Option<String>,
+    max_user_duration_in_seconds: Option<i64>,
+    name: Option<String>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl UpdateFleetRequestBuilder {
+    /// Sets the [`attributes_to_delete`](struct.UpdateFleetRequest.html#structfield.attributes_to_delete) member.
+    pub fn attributes_to_delete<T: Into<Vec<FleetAttribute>>>(mut self, value: T) -> Self {
+        self.attributes_to_delete = Some(value.into());
+        self
+    }
+
+    /// Sets the [`compute_capacity`](struct.UpdateFleetRequest.html#structfield.compute_capacity) member.
+    pub fn compute_capacity<T: Into<ComputeCapacity>>(mut self, value: T) -> Self {
+        self.compute_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateFleetRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disconnect_timeout_in_seconds`](struct.UpdateFleetRequest.html#structfield.disconnect_timeout_in_seconds) member.
+    pub fn disconnect_timeout_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.disconnect_timeout_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.UpdateFleetRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_join_info`](struct.UpdateFleetRequest.html#structfield.domain_join_info) member.
+    pub fn domain_join_info<T: Into<DomainJoinInfo>>(mut self, value: T) -> Self {
+        self.domain_join_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_default_internet_access`](struct.UpdateFleetRequest.html#structfield.enable_default_internet_access) member.
+    pub fn enable_default_internet_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_default_internet_access = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_role_arn`](struct.UpdateFleetRequest.html#structfield.iam_role_arn) member.
+    pub fn iam_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idle_disconnect_timeout_in_seconds`](struct.UpdateFleetRequest.html#structfield.idle_disconnect_timeout_in_seconds) member.
+    pub fn idle_disconnect_timeout_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.idle_disconnect_timeout_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_arn`](struct.UpdateFleetRequest.html#structfield.image_arn) member.
+    pub fn image_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_name`](struct.UpdateFleetRequest.html#structfield.image_name) member.
+    pub fn image_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_type`](struct.UpdateFleetRequest.html#structfield.instance_type) member.
+    pub fn instance_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_user_duration_in_seconds`](struct.UpdateFleetRequest.html#structfield.max_user_duration_in_seconds) member.
+    pub fn max_user_duration_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_user_duration_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateFleetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.UpdateFleetRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFleetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateFleetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFleetRequest {
+            attributes_to_delete: self.attributes_to_delete,
+            compute_capacity: self.compute_capacity,
+            description: self.description,
+            disconnect_timeout_in_seconds: self.disconnect_timeout_in_seconds,
+            display_name: self.display_name,
+            domain_join_info: self.domain_join_info,
+            enable_default_internet_access: self.enable_default_internet_access,
+            iam_role_arn: self.iam_role_arn,
+            idle_disconnect_timeout_in_seconds: self.idle_disconnect_timeout_in_seconds,
+            image_arn: self.image_arn,
+            image_name: self.image_name,
+            instance_type: self.instance_type,
+            max_user_duration_in_seconds: self.max_user_duration_in_seconds,
+            name: self.name,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateFleetResult {
@@ -1742,6 +5659,58 @@ pub struct UpdateImagePermissionsRequest {
     pub shared_account_id: String,
 }
 
+impl UpdateImagePermissionsRequest {
+    /// Returns a builder for `UpdateImagePermissionsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateImagePermissionsRequestBuilder {
+        UpdateImagePermissionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateImagePermissionsRequest`](struct.UpdateImagePermissionsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateImagePermissionsRequestBuilder {
+    image_permissions: Option<ImagePermissions>,
+    name: Option<String>,
+    shared_account_id: Option<String>,
+}
+
+impl UpdateImagePermissionsRequestBuilder {
+    /// Sets the [`image_permissions`](struct.UpdateImagePermissionsRequest.html#structfield.image_permissions) member.
+    pub fn image_permissions<T: Into<ImagePermissions>>(mut self, value: T) -> Self {
+        self.image_permissions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateImagePermissionsRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shared_account_id`](struct.UpdateImagePermissionsRequest.html#structfield.shared_account_id) member.
+    pub fn shared_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.shared_account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateImagePermissionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateImagePermissionsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateImagePermissionsRequest {
+            image_permissions: self
+                .image_permissions
+                .ok_or(::rusoto_core::BuildError::missing("image_permissions"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            shared_account_id: self
+                .shared_account_id
+                .ok_or(::rusoto_core::BuildError::missing("shared_account_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateImagePermissionsResult {}
@@ -1759,7 +5728,7 @@ pub struct UpdateStackRequest {
     /// <p>The stack attributes to delete.</p>
     #[serde(rename = "AttributesToDelete")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes_to_delete: Option<Vec<String>>,
+    pub attributes_to_delete: Option<Vec<StackAttribute>>,
     /// <p>The description to display.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1789,6 +5758,108 @@ pub struct UpdateStackRequest {
     pub user_settings: Option<Vec<UserSetting>>,
 }
 
+impl UpdateStackRequest {
+    /// Returns a builder for `UpdateStackRequest`, checking its required members when built.
+    pub fn builder() -> UpdateStackRequestBuilder {
+        UpdateStackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateStackRequest`](struct.UpdateStackRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateStackRequestBuilder {
+    access_endpoints: Option<Vec<AccessEndpoint>>,
+    application_settings: Option<ApplicationSettings>,
+    attributes_to_delete: Option<Vec<StackAttribute>>,
+    description: Option<String>,
+    display_name: Option<String>,
+    feedback_url: Option<String>,
+    name: Option<String>,
+    redirect_url: Option<String>,
+    storage_connectors: Option<Vec<StorageConnector>>,
+    user_settings: Option<Vec<UserSetting>>,
+}
+
+impl UpdateStackRequestBuilder {
+    /// Sets the [`access_endpoints`](struct.UpdateStackRequest.html#structfield.access_endpoints) member.
+    pub fn access_endpoints<T: Into<Vec<AccessEndpoint>>>(mut self, value: T) -> Self {
+        self.access_endpoints = Some(value.into());
+        self
+    }
+
+    /// Sets the [`application_settings`](struct.UpdateStackRequest.html#structfield.application_settings) member.
+    pub fn application_settings<T: Into<ApplicationSettings>>(mut self, value: T) -> Self {
+        self.application_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attributes_to_delete`](struct.UpdateStackRequest.html#structfield.attributes_to_delete) member.
+    pub fn attributes_to_delete<T: Into<Vec<StackAttribute>>>(mut self, value: T) -> Self {
+        self.attributes_to_delete = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateStackRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.UpdateStackRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`feedback_url`](struct.UpdateStackRequest.html#structfield.feedback_url) member.
+    pub fn feedback_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.feedback_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateStackRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`redirect_url`](struct.UpdateStackRequest.html#structfield.redirect_url) member.
+    pub fn redirect_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.redirect_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_connectors`](struct.UpdateStackRequest.html#structfield.storage_connectors) member.
+    pub fn storage_connectors<T: Into<Vec<StorageConnector>>>(mut self, value: T) -> Self {
+        self.storage_connectors = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_settings`](struct.UpdateStackRequest.html#structfield.user_settings) member.
+    pub fn user_settings<T: Into<Vec<UserSetting>>>(mut self, value: T) -> Self {
+        self.user_settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateStackRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateStackRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateStackRequest {
+            access_endpoints: self.access_endpoints,
+            application_settings: self.application_settings,
+            attributes_to_delete: self.attributes_to_delete,
+            description: self.description,
+            display_name: self.display_name,
+            feedback_url: self.feedback_url,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            redirect_url: self.redirect_url,
+            storage_connectors: self.storage_connectors,
+            user_settings: self.user_settings,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateStackResult {
@@ -1798,14 +5869,161 @@ pub struct UpdateStackResult {
     pub stack: Option<Stack>,
 }
 
+/// <p>The error code for the error that is returned when a usage report can't be generated.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UsageReportExecutionErrorCode {
+    /// <p><code>RESOURCE_NOT_FOUND</code></p>
+    ResourceNotFound,
+    /// <p><code>ACCESS_DENIED</code></p>
+    AccessDenied,
+    /// <p><code>INTERNAL_SERVICE_ERROR</code></p>
+    InternalServiceError,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UsageReportExecutionErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UsageReportExecutionErrorCode::ResourceNotFound => "RESOURCE_NOT_FOUND",
+            UsageReportExecutionErrorCode::AccessDenied => "ACCESS_DENIED",
+            UsageReportExecutionErrorCode::InternalServiceError => "INTERNAL_SERVICE_ERROR",
+            UsageReportExecutionErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UsageReportExecutionErrorCode {
+    fn from(value: &'a str) -> UsageReportExecutionErrorCode {
+        match value {
+            "RESOURCE_NOT_FOUND" => UsageReportExecutionErrorCode::ResourceNotFound,
+            "ACCESS_DENIED" => UsageReportExecutionErrorCode::AccessDenied,
+            "INTERNAL_SERVICE_ERROR" => UsageReportExecutionErrorCode::InternalServiceError,
+            _ => UsageReportExecutionErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UsageReportExecutionErrorCode {
+    fn from(value: String) -> UsageReportExecutionErrorCode {
+        UsageReportExecutionErrorCode::from(value.as_str())
+    }
+}
+
+impl From<UsageReportExecutionErrorCode> for String {
+    fn from(value: UsageReportExecutionErrorCode) -> String {
+        match value {
+            UsageReportExecutionErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UsageReportExecutionErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UsageReportExecutionErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UsageReportExecutionErrorCode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<UsageReportExecutionErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UsageReportExecutionErrorCode::from(value))
+    }
+}
+
+/// <p>The schedule for generating usage reports.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UsageReportSchedule {
+    /// <p><code>DAILY</code></p>
+    Daily,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UsageReportSchedule {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UsageReportSchedule::Daily => "DAILY",
+            UsageReportSchedule::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UsageReportSchedule {
+    fn from(value: &'a str) -> UsageReportSchedule {
+        match value {
+            "DAILY" => UsageReportSchedule::Daily,
+            _ => UsageReportSchedule::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UsageReportSchedule {
+    fn from(value: String) -> UsageReportSchedule {
+        UsageReportSchedule::from(value.as_str())
+    }
+}
+
+impl From<UsageReportSchedule> for String {
+    fn from(value: UsageReportSchedule) -> String {
+        match value {
+            UsageReportSchedule::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UsageReportSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UsageReportSchedule {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UsageReportSchedule {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UsageReportSchedule, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UsageReportSchedule::from(value))
+    }
+}
+
 /// <p>Describes information about the usage report subscription.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UsageReportSubscription {
     /// <p>The time when the last usage report was generated.</p>
     #[serde(rename = "LastGeneratedReportDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_generated_report_date: Option<f64>,
+    pub last_generated_report_date: Option<EpochTimestamp>,
     /// <p>The Amazon S3 bucket where generated reports are stored.</p> <p>If you enabled on-instance session scripts and Amazon S3 logging for your session script configuration, AppStream 2.0 created an S3 bucket to store the script output. The bucket is unique to your account and Region. When you enable usage reporting in this case, AppStream 2.0 uses the same bucket to store your usage reports. If you haven't already enabled on-instance session scripts, when you enable usage reports, AppStream 2.0 creates a new S3 bucket.</p>
     #[serde(rename = "S3BucketName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1813,7 +6031,7 @@ pub struct UsageReportSubscription {
     /// <p>The schedule for generating usage reports.</p>
     #[serde(rename = "Schedule")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub schedule: Option<String>,
+    pub schedule: Option<UsageReportSchedule>,
     /// <p>The errors that were returned if usage reports couldn't be generated.</p>
     #[serde(rename = "SubscriptionErrors")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1821,7 +6039,7 @@ pub struct UsageReportSubscription {
 }
 
 /// <p>Describes a user in the user pool.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct User {
     /// <p>The ARN of the user.</p>
@@ -1830,11 +6048,12 @@ pub struct User {
     pub arn: Option<String>,
     /// <p>The authentication type for the user.</p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p>The date and time the user was created in the user pool.</p>
     #[serde(rename = "CreatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time: Option<f64>,
+    pub created_time: Option<EpochTimestamp>,
     /// <p>Specifies whether the user in the user pool is enabled.</p>
     #[serde(rename = "Enabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1858,22 +6077,22 @@ pub struct User {
 }
 
 /// <p>Describes an action and whether the action is enabled or disabled for users during their streaming sessions.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserSetting {
     /// <p>The action that is enabled or disabled.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: Action,
     /// <p>Indicates whether the action is enabled or disabled.</p>
     #[serde(rename = "Permission")]
-    pub permission: String,
+    pub permission: Permission,
 }
 
 /// <p>Describes a user in the user pool and the associated stack.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserStackAssociation {
     /// <p>The authentication type for the user.</p>
     #[serde(rename = "AuthenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p>Specifies whether a welcome email is sent to a user after the user is created in the user pool.</p>
     #[serde(rename = "SendEmailNotification")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1893,7 +6112,7 @@ pub struct UserStackAssociationError {
     /// <p>The error code for the error that is returned when a user can’t be associated with or disassociated from a stack.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<UserStackAssociationErrorCode>,
     /// <p>The error message for the error that is returned when a user can’t be associated with or disassociated from a stack.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1904,6 +6123,164 @@ pub struct UserStackAssociationError {
     pub user_stack_association: Option<UserStackAssociation>,
 }
 
+/// <p>The error code for the error that is returned when a user can’t be associated with or disassociated from a stack.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UserStackAssociationErrorCode {
+    /// <p><code>STACK_NOT_FOUND</code></p>
+    StackNotFound,
+    /// <p><code>USER_NAME_NOT_FOUND</code></p>
+    UserNameNotFound,
+    /// <p><code>DIRECTORY_NOT_FOUND</code></p>
+    DirectoryNotFound,
+    /// <p><code>INTERNAL_ERROR</code></p>
+    InternalError,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UserStackAssociationErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserStackAssociationErrorCode::StackNotFound => "STACK_NOT_FOUND",
+            UserStackAssociationErrorCode::UserNameNotFound => "USER_NAME_NOT_FOUND",
+            UserStackAssociationErrorCode::DirectoryNotFound => "DIRECTORY_NOT_FOUND",
+            UserStackAssociationErrorCode::InternalError => "INTERNAL_ERROR",
+            UserStackAssociationErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UserStackAssociationErrorCode {
+    fn from(value: &'a str) -> UserStackAssociationErrorCode {
+        match value {
+            "STACK_NOT_FOUND" => UserStackAssociationErrorCode::StackNotFound,
+            "USER_NAME_NOT_FOUND" => UserStackAssociationErrorCode::UserNameNotFound,
+            "DIRECTORY_NOT_FOUND" => UserStackAssociationErrorCode::DirectoryNotFound,
+            "INTERNAL_ERROR" => UserStackAssociationErrorCode::InternalError,
+            _ => UserStackAssociationErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserStackAssociationErrorCode {
+    fn from(value: String) -> UserStackAssociationErrorCode {
+        UserStackAssociationErrorCode::from(value.as_str())
+    }
+}
+
+impl From<UserStackAssociationErrorCode> for String {
+    fn from(value: UserStackAssociationErrorCode) -> String {
+        match value {
+            UserStackAssociationErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UserStackAssociationErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UserStackAssociationErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserStackAssociationErrorCode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<UserStackAssociationErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UserStackAssociationErrorCode::from(value))
+    }
+}
+
+/// <p>The type of image (public, private, or shared) to describe. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VisibilityType {
+    /// <p><code>PUBLIC</code></p>
+    Public,
+    /// <p><code>PRIVATE</code></p>
+    Private,
+    /// <p><code>SHARED</code></p>
+    Shared,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VisibilityType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VisibilityType::Public => "PUBLIC",
+            VisibilityType::Private => "PRIVATE",
+            VisibilityType::Shared => "SHARED",
+            VisibilityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VisibilityType {
+    fn from(value: &'a str) -> VisibilityType {
+        match value {
+            "PUBLIC" => VisibilityType::Public,
+            "PRIVATE" => VisibilityType::Private,
+            "SHARED" => VisibilityType::Shared,
+            _ => VisibilityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VisibilityType {
+    fn from(value: String) -> VisibilityType {
+        VisibilityType::from(value.as_str())
+    }
+}
+
+impl From<VisibilityType> for String {
+    fn from(value: VisibilityType) -> String {
+        match value {
+            VisibilityType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VisibilityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for VisibilityType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VisibilityType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VisibilityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VisibilityType::from(value))
+    }
+}
+
 /// <p>Describes VPC configuration information for fleets and image builders.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VpcConfig {
@@ -4172,267 +8549,555 @@ pub trait AppStream {
     fn batch_disassociate_user_stack(
         &self,
         input: BatchDisassociateUserStackRequest,
-    ) -> RusotoFuture<BatchDisassociateUserStackResult, BatchDisassociateUserStackError>;
-
-    /// <p>Copies the image within the same region or to a new region within the same AWS account. Note that any tags you added to the image will not be copied.</p>
+    ) -> RusotoFuture<BatchDisassociateUserStackResult, BatchDisassociateUserStackError>;
+
+    /// <p>Copies the image within the same region or to a new region within the same AWS account. Note that any tags you added to the image will not be copied.</p>
+    fn copy_image(
+        &self,
+        input: CopyImageRequest,
+    ) -> RusotoFuture<CopyImageResponse, CopyImageError>;
+
+    /// <p>Creates a Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
+    fn create_directory_config(
+        &self,
+        input: CreateDirectoryConfigRequest,
+    ) -> RusotoFuture<CreateDirectoryConfigResult, CreateDirectoryConfigError>;
+
+    /// <p>Creates a fleet. A fleet consists of streaming instances that run a specified image.</p>
+    fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> RusotoFuture<CreateFleetResult, CreateFleetError>;
+
+    /// <p>Creates an image builder. An image builder is a virtual machine that is used to create an image.</p> <p>The initial state of the builder is <code>PENDING</code>. When it is ready, the state is <code>RUNNING</code>.</p>
+    fn create_image_builder(
+        &self,
+        input: CreateImageBuilderRequest,
+    ) -> RusotoFuture<CreateImageBuilderResult, CreateImageBuilderError>;
+
+    /// <p>Creates a URL to start an image builder streaming session.</p>
+    fn create_image_builder_streaming_url(
+        &self,
+        input: CreateImageBuilderStreamingURLRequest,
+    ) -> RusotoFuture<CreateImageBuilderStreamingURLResult, CreateImageBuilderStreamingURLError>;
+
+    /// <p>Creates a stack to start streaming applications to users. A stack consists of an associated fleet, user access policies, and storage configurations. </p>
+    fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> RusotoFuture<CreateStackResult, CreateStackError>;
+
+    /// <p>Creates a temporary URL to start an AppStream 2.0 streaming session for the specified user. A streaming URL enables application streaming to be tested without user setup. </p>
+    fn create_streaming_url(
+        &self,
+        input: CreateStreamingURLRequest,
+    ) -> RusotoFuture<CreateStreamingURLResult, CreateStreamingURLError>;
+
+    /// <p>Creates a usage report subscription. Usage reports are generated daily.</p>
+    fn create_usage_report_subscription(
+        &self,
+    ) -> RusotoFuture<CreateUsageReportSubscriptionResult, CreateUsageReportSubscriptionError>;
+
+    /// <p>Creates a new user in the user pool.</p>
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResult, CreateUserError>;
+
+    /// <p>Deletes the specified Directory Config object from AppStream 2.0. This object includes the information required to join streaming instances to an Active Directory domain.</p>
+    fn delete_directory_config(
+        &self,
+        input: DeleteDirectoryConfigRequest,
+    ) -> RusotoFuture<DeleteDirectoryConfigResult, DeleteDirectoryConfigError>;
+
+    /// <p>Deletes the specified fleet.</p>
+    fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> RusotoFuture<DeleteFleetResult, DeleteFleetError>;
+
+    /// <p>Deletes the specified image. You cannot delete an image when it is in use. After you delete an image, you cannot provision new capacity using the image.</p>
+    fn delete_image(
+        &self,
+        input: DeleteImageRequest,
+    ) -> RusotoFuture<DeleteImageResult, DeleteImageError>;
+
+    /// <p>Deletes the specified image builder and releases the capacity.</p>
+    fn delete_image_builder(
+        &self,
+        input: DeleteImageBuilderRequest,
+    ) -> RusotoFuture<DeleteImageBuilderResult, DeleteImageBuilderError>;
+
+    /// <p>Deletes permissions for the specified private image. After you delete permissions for an image, AWS accounts to which you previously granted these permissions can no longer use the image.</p>
+    fn delete_image_permissions(
+        &self,
+        input: DeleteImagePermissionsRequest,
+    ) -> RusotoFuture<DeleteImagePermissionsResult, DeleteImagePermissionsError>;
+
+    /// <p>Deletes the specified stack. After the stack is deleted, the application streaming environment provided by the stack is no longer available to users. Also, any reservations made for application streaming sessions for the stack are released.</p>
+    fn delete_stack(
+        &self,
+        input: DeleteStackRequest,
+    ) -> RusotoFuture<DeleteStackResult, DeleteStackError>;
+
+    /// <p>Disables usage report generation.</p>
+    fn delete_usage_report_subscription(
+        &self,
+    ) -> RusotoFuture<DeleteUsageReportSubscriptionResult, DeleteUsageReportSubscriptionError>;
+
+    /// <p>Deletes a user from the user pool.</p>
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResult, DeleteUserError>;
+
+    /// <p>Retrieves a list that describes one or more specified Directory Config objects for AppStream 2.0, if the names for these objects are provided. Otherwise, all Directory Config objects in the account are described. These objects include the configuration information required to join fleets and image builders to Microsoft Active Directory domains. </p> <p>Although the response syntax in this topic includes the account password, this password is not returned in the actual response.</p>
+    fn describe_directory_configs(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> RusotoFuture<DescribeDirectoryConfigsResult, DescribeDirectoryConfigsError>;
+
+    /// <p>Retrieves a list that describes one or more specified fleets, if the fleet names are provided. Otherwise, all fleets in the account are described.</p>
+    fn describe_fleets(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> RusotoFuture<DescribeFleetsResult, DescribeFleetsError>;
+
+    /// <p>Retrieves a list that describes one or more specified image builders, if the image builder names are provided. Otherwise, all image builders in the account are described.</p>
+    fn describe_image_builders(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> RusotoFuture<DescribeImageBuildersResult, DescribeImageBuildersError>;
+
+    /// <p>Retrieves a list that describes the permissions for shared AWS account IDs on a private image that you own. </p>
+    fn describe_image_permissions(
+        &self,
+        input: DescribeImagePermissionsRequest,
+    ) -> RusotoFuture<DescribeImagePermissionsResult, DescribeImagePermissionsError>;
+
+    /// <p>Retrieves a list that describes one or more specified images, if the image names or image ARNs are provided. Otherwise, all images in the account are described.</p>
+    fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> RusotoFuture<DescribeImagesResult, DescribeImagesError>;
+
+    /// <p>Retrieves a list that describes the streaming sessions for a specified stack and fleet. If a UserId is provided for the stack and fleet, only streaming sessions for that user are described. If an authentication type is not provided, the default is to authenticate users using a streaming URL.</p>
+    fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> RusotoFuture<DescribeSessionsResult, DescribeSessionsError>;
+
+    /// <p>Retrieves a list that describes one or more specified stacks, if the stack names are provided. Otherwise, all stacks in the account are described.</p>
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError>;
+
+    /// <p>Retrieves a list that describes one or more usage report subscriptions.</p>
+    fn describe_usage_report_subscriptions(
+        &self,
+        input: DescribeUsageReportSubscriptionsRequest,
+    ) -> RusotoFuture<DescribeUsageReportSubscriptionsResult, DescribeUsageReportSubscriptionsError>;
+
+    /// <p><p>Retrieves a list that describes the UserStackAssociation objects. You must specify either or both of the following:</p> <ul> <li> <p>The stack name</p> </li> <li> <p>The user name (email address of the user associated with the stack) and the authentication type for the user</p> </li> </ul></p>
+    fn describe_user_stack_associations(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> RusotoFuture<DescribeUserStackAssociationsResult, DescribeUserStackAssociationsError>;
+
+    /// <p>Retrieves a list that describes one or more specified users in the user pool.</p>
+    fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> RusotoFuture<DescribeUsersResult, DescribeUsersError>;
+
+    /// <p>Disables the specified user in the user pool. Users can't sign in to AppStream 2.0 until they are re-enabled. This action does not delete the user. </p>
+    fn disable_user(
+        &self,
+        input: DisableUserRequest,
+    ) -> RusotoFuture<DisableUserResult, DisableUserError>;
+
+    /// <p>Disassociates the specified fleet from the specified stack.</p>
+    fn disassociate_fleet(
+        &self,
+        input: DisassociateFleetRequest,
+    ) -> RusotoFuture<DisassociateFleetResult, DisassociateFleetError>;
+
+    /// <p>Enables a user in the user pool. After being enabled, users can sign in to AppStream 2.0 and open applications from the stacks to which they are assigned.</p>
+    fn enable_user(
+        &self,
+        input: EnableUserRequest,
+    ) -> RusotoFuture<EnableUserResult, EnableUserError>;
+
+    /// <p>Immediately stops the specified streaming session.</p>
+    fn expire_session(
+        &self,
+        input: ExpireSessionRequest,
+    ) -> RusotoFuture<ExpireSessionResult, ExpireSessionError>;
+
+    /// <p>Retrieves the name of the fleet that is associated with the specified stack.</p>
+    fn list_associated_fleets(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> RusotoFuture<ListAssociatedFleetsResult, ListAssociatedFleetsError>;
+
+    /// <p>Retrieves the name of the stack with which the specified fleet is associated.</p>
+    fn list_associated_stacks(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> RusotoFuture<ListAssociatedStacksResult, ListAssociatedStacksError>;
+
+    /// <p>Retrieves a list of all tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
+
+    /// <p>Starts the specified fleet.</p>
+    fn start_fleet(
+        &self,
+        input: StartFleetRequest,
+    ) -> RusotoFuture<StartFleetResult, StartFleetError>;
+
+    /// <p>Starts the specified image builder.</p>
+    fn start_image_builder(
+        &self,
+        input: StartImageBuilderRequest,
+    ) -> RusotoFuture<StartImageBuilderResult, StartImageBuilderError>;
+
+    /// <p>Stops the specified fleet.</p>
+    fn stop_fleet(&self, input: StopFleetRequest) -> RusotoFuture<StopFleetResult, StopFleetError>;
+
+    /// <p>Stops the specified image builder.</p>
+    fn stop_image_builder(
+        &self,
+        input: StopImageBuilderRequest,
+    ) -> RusotoFuture<StopImageBuilderResult, StopImageBuilderError>;
+
+    /// <p>Adds or overwrites one or more tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>Each tag consists of a key and an optional value. If a resource already has a tag with the same key, this operation updates its value.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>. To disassociate tags from your resources, use <a>UntagResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError>;
+
+    /// <p>Disassociates one or more specified tags from the specified AppStream 2.0 resource.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
+
+    /// <p>Updates the specified Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
+    fn update_directory_config(
+        &self,
+        input: UpdateDirectoryConfigRequest,
+    ) -> RusotoFuture<UpdateDirectoryConfigResult, UpdateDirectoryConfigError>;
+
+    /// <p>Updates the specified fleet.</p> <p>If the fleet is in the <code>STOPPED</code> state, you can update any attribute except the fleet name. If the fleet is in the <code>RUNNING</code> state, you can update the <code>DisplayName</code>, <code>ComputeCapacity</code>, <code>ImageARN</code>, <code>ImageName</code>, <code>IdleDisconnectTimeoutInSeconds</code>, and <code>DisconnectTimeoutInSeconds</code> attributes. If the fleet is in the <code>STARTING</code> or <code>STOPPING</code> state, you can't update it.</p>
+    fn update_fleet(
+        &self,
+        input: UpdateFleetRequest,
+    ) -> RusotoFuture<UpdateFleetResult, UpdateFleetError>;
+
+    /// <p>Adds or updates permissions for the specified private image. </p>
+    fn update_image_permissions(
+        &self,
+        input: UpdateImagePermissionsRequest,
+    ) -> RusotoFuture<UpdateImagePermissionsResult, UpdateImagePermissionsError>;
+
+    /// <p>Updates the specified fields for the specified stack.</p>
+    fn update_stack(
+        &self,
+        input: UpdateStackRequest,
+    ) -> RusotoFuture<UpdateStackResult, UpdateStackError>;
+}
+impl<T: ?Sized + AppStream> AppStream for ::std::sync::Arc<T> {
+    fn associate_fleet(
+        &self,
+        input: AssociateFleetRequest,
+    ) -> RusotoFuture<AssociateFleetResult, AssociateFleetError> {
+        (**self).associate_fleet(input)
+    }
+    fn batch_associate_user_stack(
+        &self,
+        input: BatchAssociateUserStackRequest,
+    ) -> RusotoFuture<BatchAssociateUserStackResult, BatchAssociateUserStackError> {
+        (**self).batch_associate_user_stack(input)
+    }
+    fn batch_disassociate_user_stack(
+        &self,
+        input: BatchDisassociateUserStackRequest,
+    ) -> RusotoFuture<BatchDisassociateUserStackResult, BatchDisassociateUserStackError> {
+        (**self).batch_disassociate_user_stack(input)
+    }
     fn copy_image(
         &self,
         input: CopyImageRequest,
-    ) -> RusotoFuture<CopyImageResponse, CopyImageError>;
-
-    /// <p>Creates a Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
+    ) -> RusotoFuture<CopyImageResponse, CopyImageError> {
+        (**self).copy_image(input)
+    }
     fn create_directory_config(
         &self,
         input: CreateDirectoryConfigRequest,
-    ) -> RusotoFuture<CreateDirectoryConfigResult, CreateDirectoryConfigError>;
-
-    /// <p>Creates a fleet. A fleet consists of streaming instances that run a specified image.</p>
+    ) -> RusotoFuture<CreateDirectoryConfigResult, CreateDirectoryConfigError> {
+        (**self).create_directory_config(input)
+    }
     fn create_fleet(
         &self,
         input: CreateFleetRequest,
-    ) -> RusotoFuture<CreateFleetResult, CreateFleetError>;
-
-    /// <p>Creates an image builder. An image builder is a virtual machine that is used to create an image.</p> <p>The initial state of the builder is <code>PENDING</code>. When it is ready, the state is <code>RUNNING</code>.</p>
+    ) -> RusotoFuture<CreateFleetResult, CreateFleetError> {
+        (**self).create_fleet(input)
+    }
     fn create_image_builder(
         &self,
         input: CreateImageBuilderRequest,
-    ) -> RusotoFuture<CreateImageBuilderResult, CreateImageBuilderError>;
-
-    /// <p>Creates a URL to start an image builder streaming session.</p>
+    ) -> RusotoFuture<CreateImageBuilderResult, CreateImageBuilderError> {
+        (**self).create_image_builder(input)
+    }
     fn create_image_builder_streaming_url(
         &self,
         input: CreateImageBuilderStreamingURLRequest,
-    ) -> RusotoFuture<CreateImageBuilderStreamingURLResult, CreateImageBuilderStreamingURLError>;
-
-    /// <p>Creates a stack to start streaming applications to users. A stack consists of an associated fleet, user access policies, and storage configurations. </p>
+    ) -> RusotoFuture<CreateImageBuilderStreamingURLResult, CreateImageBuilderStreamingURLError>
+    {
+        (**self).create_image_builder_streaming_url(input)
+    }
     fn create_stack(
         &self,
         input: CreateStackRequest,
-    ) -> RusotoFuture<CreateStackResult, CreateStackError>;
-
-    /// <p>Creates a temporary URL to start an AppStream 2.0 streaming session for the specified user. A streaming URL enables application streaming to be tested without user setup. </p>
+    ) -> RusotoFuture<CreateStackResult, CreateStackError> {
+        (**self).create_stack(input)
+    }
     fn create_streaming_url(
         &self,
         input: CreateStreamingURLRequest,
-    ) -> RusotoFuture<CreateStreamingURLResult, CreateStreamingURLError>;
-
-    /// <p>Creates a usage report subscription. Usage reports are generated daily.</p>
+    ) -> RusotoFuture<CreateStreamingURLResult, CreateStreamingURLError> {
+        (**self).create_streaming_url(input)
+    }
     fn create_usage_report_subscription(
         &self,
-    ) -> RusotoFuture<CreateUsageReportSubscriptionResult, CreateUsageReportSubscriptionError>;
-
-    /// <p>Creates a new user in the user pool.</p>
+    ) -> RusotoFuture<CreateUsageReportSubscriptionResult, CreateUsageReportSubscriptionError> {
+        (**self).create_usage_report_subscription()
+    }
     fn create_user(
         &self,
         input: CreateUserRequest,
-    ) -> RusotoFuture<CreateUserResult, CreateUserError>;
-
-    /// <p>Deletes the specified Directory Config object from AppStream 2.0. This object includes the information required to join streaming instances to an Active Directory domain.</p>
+    ) -> RusotoFuture<CreateUserResult, CreateUserError> {
+        (**self).create_user(input)
+    }
     fn delete_directory_config(
         &self,
         input: DeleteDirectoryConfigRequest,
-    ) -> RusotoFuture<DeleteDirectoryConfigResult, DeleteDirectoryConfigError>;
-
-    /// <p>Deletes the specified fleet.</p>
+    ) -> RusotoFuture<DeleteDirectoryConfigResult, DeleteDirectoryConfigError> {
+        (**self).delete_directory_config(input)
+    }
     fn delete_fleet(
         &self,
         input: DeleteFleetRequest,
-    ) -> RusotoFuture<DeleteFleetResult, DeleteFleetError>;
-
-    /// <p>Deletes the specified image. You cannot delete an image when it is in use. After you delete an image, you cannot provision new capacity using the image.</p>
+    ) -> RusotoFuture<DeleteFleetResult, DeleteFleetError> {
+        (**self).delete_fleet(input)
+    }
     fn delete_image(
         &self,
         input: DeleteImageRequest,
-    ) -> RusotoFuture<DeleteImageResult, DeleteImageError>;
-
-    /// <p>Deletes the specified image builder and releases the capacity.</p>
+    ) -> RusotoFuture<DeleteImageResult, DeleteImageError> {
+        (**self).delete_image(input)
+    }
     fn delete_image_builder(
         &self,
         input: DeleteImageBuilderRequest,
-    ) -> RusotoFuture<DeleteImageBuilderResult, DeleteImageBuilderError>;
-
-    /// <p>Deletes permissions for the specified private image. After you delete permissions for an image, AWS accounts to which you previously granted these permissions can no longer use the image.</p>
+    ) -> RusotoFuture<DeleteImageBuilderResult, DeleteImageBuilderError> {
+        (**self).delete_image_builder(input)
+    }
     fn delete_image_permissions(
         &self,
         input: DeleteImagePermissionsRequest,
-    ) -> RusotoFuture<DeleteImagePermissionsResult, DeleteImagePermissionsError>;
-
-    /// <p>Deletes the specified stack. After the stack is deleted, the application streaming environment provided by the stack is no longer available to users. Also, any reservations made for application streaming sessions for the stack are released.</p>
+    ) -> RusotoFuture<DeleteImagePermissionsResult, DeleteImagePermissionsError> {
+        (**self).delete_image_permissions(input)
+    }
     fn delete_stack(
         &self,
         input: DeleteStackRequest,
-    ) -> RusotoFuture<DeleteStackResult, DeleteStackError>;
-
-    /// <p>Disables usage report generation.</p>
+    ) -> RusotoFuture<DeleteStackResult, DeleteStackError> {
+        (**self).delete_stack(input)
+    }
     fn delete_usage_report_subscription(
         &self,
-    ) -> RusotoFuture<DeleteUsageReportSubscriptionResult, DeleteUsageReportSubscriptionError>;
-
-    /// <p>Deletes a user from the user pool.</p>
+    ) -> RusotoFuture<DeleteUsageReportSubscriptionResult, DeleteUsageReportSubscriptionError> {
+        (**self).delete_usage_report_subscription()
+    }
     fn delete_user(
         &self,
         input: DeleteUserRequest,
-    ) -> RusotoFuture<DeleteUserResult, DeleteUserError>;
-
-    /// <p>Retrieves a list that describes one or more specified Directory Config objects for AppStream 2.0, if the names for these objects are provided. Otherwise, all Directory Config objects in the account are described. These objects include the configuration information required to join fleets and image builders to Microsoft Active Directory domains. </p> <p>Although the response syntax in this topic includes the account password, this password is not returned in the actual response.</p>
+    ) -> RusotoFuture<DeleteUserResult, DeleteUserError> {
+        (**self).delete_user(input)
+    }
     fn describe_directory_configs(
         &self,
         input: DescribeDirectoryConfigsRequest,
-    ) -> RusotoFuture<DescribeDirectoryConfigsResult, DescribeDirectoryConfigsError>;
-
-    /// <p>Retrieves a list that describes one or more specified fleets, if the fleet names are provided. Otherwise, all fleets in the account are described.</p>
+    ) -> RusotoFuture<DescribeDirectoryConfigsResult, DescribeDirectoryConfigsError> {
+        (**self).describe_directory_configs(input)
+    }
     fn describe_fleets(
         &self,
         input: DescribeFleetsRequest,
-    ) -> RusotoFuture<DescribeFleetsResult, DescribeFleetsError>;
-
-    /// <p>Retrieves a list that describes one or more specified image builders, if the image builder names are provided. Otherwise, all image builders in the account are described.</p>
+    ) -> RusotoFuture<DescribeFleetsResult, DescribeFleetsError> {
+        (**self).describe_fleets(input)
+    }
     fn describe_image_builders(
         &self,
         input: DescribeImageBuildersRequest,
-    ) -> RusotoFuture<DescribeImageBuildersResult, DescribeImageBuildersError>;
-
-    /// <p>Retrieves a list that describes the permissions for shared AWS account IDs on a private image that you own. </p>
+    ) -> RusotoFuture<DescribeImageBuildersResult, DescribeImageBuildersError> {
+        (**self).describe_image_builders(input)
+    }
     fn describe_image_permissions(
         &self,
         input: DescribeImagePermissionsRequest,
-    ) -> RusotoFuture<DescribeImagePermissionsResult, DescribeImagePermissionsError>;
-
-    /// <p>Retrieves a list that describes one or more specified images, if the image names or image ARNs are provided. Otherwise, all images in the account are described.</p>
+    ) -> RusotoFuture<DescribeImagePermissionsResult, DescribeImagePermissionsError> {
+        (**self).describe_image_permissions(input)
+    }
     fn describe_images(
         &self,
         input: DescribeImagesRequest,
-    ) -> RusotoFuture<DescribeImagesResult, DescribeImagesError>;
-
-    /// <p>Retrieves a list that describes the streaming sessions for a specified stack and fleet. If a UserId is provided for the stack and fleet, only streaming sessions for that user are described. If an authentication type is not provided, the default is to authenticate users using a streaming URL.</p>
+    ) -> RusotoFuture<DescribeImagesResult, DescribeImagesError> {
+        (**self).describe_images(input)
+    }
     fn describe_sessions(
         &self,
         input: DescribeSessionsRequest,
-    ) -> RusotoFuture<DescribeSessionsResult, DescribeSessionsError>;
-
-    /// <p>Retrieves a list that describes one or more specified stacks, if the stack names are provided. Otherwise, all stacks in the account are described.</p>
+    ) -> RusotoFuture<DescribeSessionsResult, DescribeSessionsError> {
+        (**self).describe_sessions(input)
+    }
     fn describe_stacks(
         &self,
         input: DescribeStacksRequest,
-    ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError>;
-
-    /// <p>Retrieves a list that describes one or more usage report subscriptions.</p>
+    ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError> {
+        (**self).describe_stacks(input)
+    }
     fn describe_usage_report_subscriptions(
         &self,
         input: DescribeUsageReportSubscriptionsRequest,
-    ) -> RusotoFuture<DescribeUsageReportSubscriptionsResult, DescribeUsageReportSubscriptionsError>;
-
-    /// <p><p>Retrieves a list that describes the UserStackAssociation objects. You must specify either or both of the following:</p> <ul> <li> <p>The stack name</p> </li> <li> <p>The user name (email address of the user associated with the stack) and the authentication type for the user</p> </li> </ul></p>
+    ) -> RusotoFuture<DescribeUsageReportSubscriptionsResult, DescribeUsageReportSubscriptionsError>
+    {
+        (**self).describe_usage_report_subscriptions(input)
+    }
     fn describe_user_stack_associations(
         &self,
         input: DescribeUserStackAssociationsRequest,
-    ) -> RusotoFuture<DescribeUserStackAssociationsResult, DescribeUserStackAssociationsError>;
-
-    /// <p>Retrieves a list that describes one or more specified users in the user pool.</p>
+    ) -> RusotoFuture<DescribeUserStackAssociationsResult, DescribeUserStackAssociationsError> {
+        (**self).describe_user_stack_associations(input)
+    }
     fn describe_users(
         &self,
         input: DescribeUsersRequest,
-    ) -> RusotoFuture<DescribeUsersResult, DescribeUsersError>;
-
-    /// <p>Disables the specified user in the user pool. Users can't sign in to AppStream 2.0 until they are re-enabled. This action does not delete the user. </p>
+    ) -> RusotoFuture<DescribeUsersResult, DescribeUsersError> {
+        (**self).describe_users(input)
+    }
     fn disable_user(
         &self,
         input: DisableUserRequest,
-    ) -> RusotoFuture<DisableUserResult, DisableUserError>;
-
-    /// <p>Disassociates the specified fleet from the specified stack.</p>
+    ) -> RusotoFuture<DisableUserResult, DisableUserError> {
+        (**self).disable_user(input)
+    }
     fn disassociate_fleet(
         &self,
         input: DisassociateFleetRequest,
-    ) -> RusotoFuture<DisassociateFleetResult, DisassociateFleetError>;
-
-    /// <p>Enables a user in the user pool. After being enabled, users can sign in to AppStream 2.0 and open applications from the stacks to which they are assigned.</p>
+    ) -> RusotoFuture<DisassociateFleetResult, DisassociateFleetError> {
+        (**self).disassociate_fleet(input)
+    }
     fn enable_user(
         &self,
         input: EnableUserRequest,
-    ) -> RusotoFuture<EnableUserResult, EnableUserError>;
-
-    /// <p>Immediately stops the specified streaming session.</p>
+    ) -> RusotoFuture<EnableUserResult, EnableUserError> {
+        (**self).enable_user(input)
+    }
     fn expire_session(
         &self,
         input: ExpireSessionRequest,
-    ) -> RusotoFuture<ExpireSessionResult, ExpireSessionError>;
-
-    /// <p>Retrieves the name of the fleet that is associated with the specified stack.</p>
+    ) -> RusotoFuture<ExpireSessionResult, ExpireSessionError> {
+        (**self).expire_session(input)
+    }
     fn list_associated_fleets(
         &self,
         input: ListAssociatedFleetsRequest,
-    ) -> RusotoFuture<ListAssociatedFleetsResult, ListAssociatedFleetsError>;
-
-    /// <p>Retrieves the name of the stack with which the specified fleet is associated.</p>
+    ) -> RusotoFuture<ListAssociatedFleetsResult, ListAssociatedFleetsError> {
+        (**self).list_associated_fleets(input)
+    }
     fn list_associated_stacks(
         &self,
         input: ListAssociatedStacksRequest,
-    ) -> RusotoFuture<ListAssociatedStacksResult, ListAssociatedStacksError>;
-
-    /// <p>Retrieves a list of all tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    ) -> RusotoFuture<ListAssociatedStacksResult, ListAssociatedStacksError> {
+        (**self).list_associated_stacks(input)
+    }
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
-    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
-
-    /// <p>Starts the specified fleet.</p>
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
     fn start_fleet(
         &self,
         input: StartFleetRequest,
-    ) -> RusotoFuture<StartFleetResult, StartFleetError>;
-
-    /// <p>Starts the specified image builder.</p>
+    ) -> RusotoFuture<StartFleetResult, StartFleetError> {
+        (**self).start_fleet(input)
+    }
     fn start_image_builder(
         &self,
         input: StartImageBuilderRequest,
-    ) -> RusotoFuture<StartImageBuilderResult, StartImageBuilderError>;
-
-    /// <p>Stops the specified fleet.</p>
-    fn stop_fleet(&self, input: StopFleetRequest) -> RusotoFuture<StopFleetResult, StopFleetError>;
-
-    /// <p>Stops the specified image builder.</p>
+    ) -> RusotoFuture<StartImageBuilderResult, StartImageBuilderError> {
+        (**self).start_image_builder(input)
+    }
+    fn stop_fleet(&self, input: StopFleetRequest) -> RusotoFuture<StopFleetResult, StopFleetError> {
+        (**self).stop_fleet(input)
+    }
     fn stop_image_builder(
         &self,
         input: StopImageBuilderRequest,
-    ) -> RusotoFuture<StopImageBuilderResult, StopImageBuilderError>;
-
-    /// <p>Adds or overwrites one or more tags for the specified AppStream 2.0 resource. You can tag AppStream 2.0 image builders, images, fleets, and stacks.</p> <p>Each tag consists of a key and an optional value. If a resource already has a tag with the same key, this operation updates its value.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>. To disassociate tags from your resources, use <a>UntagResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    ) -> RusotoFuture<StopImageBuilderResult, StopImageBuilderError> {
+        (**self).stop_image_builder(input)
+    }
     fn tag_resource(
         &self,
         input: TagResourceRequest,
-    ) -> RusotoFuture<TagResourceResponse, TagResourceError>;
-
-    /// <p>Disassociates one or more specified tags from the specified AppStream 2.0 resource.</p> <p>To list the current tags for your resources, use <a>ListTagsForResource</a>.</p> <p>For more information about tags, see <a href="https://docs.aws.amazon.com/appstream2/latest/developerguide/tagging-basic.html">Tagging Your Resources</a> in the <i>Amazon AppStream 2.0 Administration Guide</i>.</p>
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
-    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
-
-    /// <p>Updates the specified Directory Config object in AppStream 2.0. This object includes the configuration information required to join fleets and image builders to Microsoft Active Directory domains.</p>
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
     fn update_directory_config(
         &self,
         input: UpdateDirectoryConfigRequest,
-    ) -> RusotoFuture<UpdateDirectoryConfigResult, UpdateDirectoryConfigError>;
-
-    /// <p>Updates the specified fleet.</p> <p>If the fleet is in the <code>STOPPED</code> state, you can update any attribute except the fleet name. If the fleet is in the <code>RUNNING</code> state, you can update the <code>DisplayName</code>, <code>ComputeCapacity</code>, <code>ImageARN</code>, <code>ImageName</code>, <code>IdleDisconnectTimeoutInSeconds</code>, and <code>DisconnectTimeoutInSeconds</code> attributes. If the fleet is in the <code>STARTING</code> or <code>STOPPING</code> state, you can't update it.</p>
+    ) -> RusotoFuture<UpdateDirectoryConfigResult, UpdateDirectoryConfigError> {
+        (**self).update_directory_config(input)
+    }
     fn update_fleet(
         &self,
         input: UpdateFleetRequest,
-    ) -> RusotoFuture<UpdateFleetResult, UpdateFleetError>;
-
-    /// <p>Adds or updates permissions for the specified private image. </p>
+    ) -> RusotoFuture<UpdateFleetResult, UpdateFleetError> {
+        (**self).update_fleet(input)
+    }
     fn update_image_permissions(
         &self,
         input: UpdateImagePermissionsRequest,
-    ) -> RusotoFuture<UpdateImagePermissionsResult, UpdateImagePermissionsError>;
-
-    /// <p>Updates the specified fields for the specified stack.</p>
+    ) -> RusotoFuture<UpdateImagePermissionsResult, UpdateImagePermissionsError> {
+        (**self).update_image_permissions(input)
+    }
     fn update_stack(
         &self,
         input: UpdateStackRequest,
-    ) -> RusotoFuture<UpdateStackResult, UpdateStackError>;
+    ) -> RusotoFuture<UpdateStackResult, UpdateStackError> {
+        (**self).update_stack(input)
+    }
 }
+/// The error codes Amazon AppStream retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon AppStream API.
 #[derive(Clone)]
 pub struct AppStreamClient {
@@ -4448,6 +9113,27 @@ impl AppStreamClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_APPSTREAM`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> AppStreamClient {
+        Self::new(region::Region::from_env_for_service("APPSTREAM"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `appstream2`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> AppStreamClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("appstream2", "APPSTREAM"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4466,7 +9152,54 @@ impl AppStreamClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AppStreamClient {
-        AppStreamClient { client, region }
+        AppStreamClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "appstream2")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "appstream2",
+            "appstream",
+            "APPSTREAM",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `AppStream` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn AppStream + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for AppStreamClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon AppStream"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "appstream2"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> AppStreamClient {
+        Self::new_with_client(client, region)
     }
 }
 
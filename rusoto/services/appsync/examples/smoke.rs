@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS AppSync clients.
+//!
+//! Makes a single read-only `ListGraphqlApis` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_appsync::{AppSync, AppSyncClient, ListGraphqlApisRequest};
+
+fn main() {
+    let client = AppSyncClient::from_env();
+    match client
+        .list_graphql_apis(ListGraphqlApisRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListGraphqlApis succeeded"),
+        Err(error) => {
+            eprintln!("ListGraphqlApis failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Describes an additional authentication provider.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -30,7 +31,7 @@ pub struct AdditionalAuthenticationProvider {
     /// <p>The authentication type: API key, AWS IAM, OIDC, or Amazon Cognito user pools.</p>
     #[serde(rename = "authenticationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_type: Option<String>,
+    pub authentication_type: Option<AuthenticationType>,
     /// <p>The OpenID Connect configuration.</p>
     #[serde(rename = "openIDConnectConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -59,18 +60,182 @@ pub struct ApiKey {
     pub id: Option<String>,
 }
 
+/// <p>The authentication type: API key, AWS IAM, OIDC, or Amazon Cognito user pools.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthenticationType {
+    /// <p><code>API_KEY</code></p>
+    ApiKey,
+    /// <p><code>AWS_IAM</code></p>
+    AwsIam,
+    /// <p><code>AMAZON_COGNITO_USER_POOLS</code></p>
+    AmazonCognitoUserPools,
+    /// <p><code>OPENID_CONNECT</code></p>
+    OpenidConnect,
+    /// <p><code>AWS_LAMBDA</code></p>
+    AwsLambda,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthenticationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthenticationType::ApiKey => "API_KEY",
+            AuthenticationType::AwsIam => "AWS_IAM",
+            AuthenticationType::AmazonCognitoUserPools => "AMAZON_COGNITO_USER_POOLS",
+            AuthenticationType::OpenidConnect => "OPENID_CONNECT",
+            AuthenticationType::AwsLambda => "AWS_LAMBDA",
+            AuthenticationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthenticationType {
+    fn from(value: &'a str) -> AuthenticationType {
+        match value {
+            "API_KEY" => AuthenticationType::ApiKey,
+            "AWS_IAM" => AuthenticationType::AwsIam,
+            "AMAZON_COGNITO_USER_POOLS" => AuthenticationType::AmazonCognitoUserPools,
+            "OPENID_CONNECT" => AuthenticationType::OpenidConnect,
+            "AWS_LAMBDA" => AuthenticationType::AwsLambda,
+            _ => AuthenticationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthenticationType {
+    fn from(value: String) -> AuthenticationType {
+        AuthenticationType::from(value.as_str())
+    }
+}
+
+impl From<AuthenticationType> for String {
+    fn from(value: AuthenticationType) -> String {
+        match value {
+            AuthenticationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthenticationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthenticationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthenticationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthenticationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthenticationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthenticationType::from(value))
+    }
+}
+
 /// <p>The authorization config in case the HTTP endpoint requires authorization.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AuthorizationConfig {
     /// <p><p>The authorization type required by the HTTP endpoint.</p> <ul> <li> <p> <b>AWS_IAM</b>: The authorization type is Sigv4.</p> </li> </ul></p>
     #[serde(rename = "authorizationType")]
-    pub authorization_type: String,
+    pub authorization_type: AuthorizationType,
     /// <p>The AWS IAM settings.</p>
     #[serde(rename = "awsIamConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub aws_iam_config: Option<AwsIamConfig>,
 }
 
+/// <p><p>The authorization type required by the HTTP endpoint.</p> <ul> <li> <p> <b>AWS_IAM</b>: The authorization type is Sigv4.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthorizationType {
+    /// <p><code>AWS_IAM</code></p>
+    AwsIam,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthorizationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthorizationType::AwsIam => "AWS_IAM",
+            AuthorizationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthorizationType {
+    fn from(value: &'a str) -> AuthorizationType {
+        match value {
+            "AWS_IAM" => AuthorizationType::AwsIam,
+            _ => AuthorizationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthorizationType {
+    fn from(value: String) -> AuthorizationType {
+        AuthorizationType::from(value.as_str())
+    }
+}
+
+impl From<AuthorizationType> for String {
+    fn from(value: AuthorizationType) -> String {
+        match value {
+            AuthorizationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthorizationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthorizationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthorizationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthorizationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthorizationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthorizationType::from(value))
+    }
+}
+
 /// <p>The AWS IAM configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsIamConfig {
@@ -114,6 +279,52 @@ pub struct CreateApiKeyRequest {
     pub expires: Option<i64>,
 }
 
+impl CreateApiKeyRequest {
+    /// Returns a builder for `CreateApiKeyRequest`, checking its required members when built.
+    pub fn builder() -> CreateApiKeyRequestBuilder {
+        CreateApiKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApiKeyRequest`](struct.CreateApiKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateApiKeyRequestBuilder {
+    api_id: Option<String>,
+    description: Option<String>,
+    expires: Option<i64>,
+}
+
+impl CreateApiKeyRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateApiKeyRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateApiKeyRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expires`](struct.CreateApiKeyRequest.html#structfield.expires) member.
+    pub fn expires<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expires = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApiKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateApiKeyRequest, ::rusoto_core::BuildError> {
+        Ok(CreateApiKeyRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            description: self.description,
+            expires: self.expires,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateApiKeyResponse {
@@ -123,7 +334,7 @@ pub struct CreateApiKeyResponse {
     pub api_key: Option<ApiKey>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateDataSourceRequest {
     /// <p>The API ID for the GraphQL API for the <code>DataSource</code>.</p>
     #[serde(rename = "apiId")]
@@ -161,7 +372,121 @@ pub struct CreateDataSourceRequest {
     pub service_role_arn: Option<String>,
     /// <p>The type of the <code>DataSource</code>.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: DataSourceType,
+}
+
+impl CreateDataSourceRequest {
+    /// Returns a builder for `CreateDataSourceRequest`, checking its required members when built.
+    pub fn builder() -> CreateDataSourceRequestBuilder {
+        CreateDataSourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDataSourceRequest`](struct.CreateDataSourceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDataSourceRequestBuilder {
+    api_id: Option<String>,
+    description: Option<String>,
+    dynamodb_config: Option<DynamodbDataSourceConfig>,
+    elasticsearch_config: Option<ElasticsearchDataSourceConfig>,
+    http_config: Option<HttpDataSourceConfig>,
+    lambda_config: Option<LambdaDataSourceConfig>,
+    name: Option<String>,
+    relational_database_config: Option<RelationalDatabaseDataSourceConfig>,
+    service_role_arn: Option<String>,
+    type_: Option<DataSourceType>,
+}
+
+impl CreateDataSourceRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateDataSourceRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateDataSourceRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dynamodb_config`](struct.CreateDataSourceRequest.html#structfield.dynamodb_config) member.
+    pub fn dynamodb_config<T: Into<DynamodbDataSourceConfig>>(mut self, value: T) -> Self {
+        self.dynamodb_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`elasticsearch_config`](struct.CreateDataSourceRequest.html#structfield.elasticsearch_config) member.
+    pub fn elasticsearch_config<T: Into<ElasticsearchDataSourceConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.elasticsearch_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`http_config`](struct.CreateDataSourceRequest.html#structfield.http_config) member.
+    pub fn http_config<T: Into<HttpDataSourceConfig>>(mut self, value: T) -> Self {
+        self.http_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lambda_config`](struct.CreateDataSourceRequest.html#structfield.lambda_config) member.
+    pub fn lambda_config<T: Into<LambdaDataSourceConfig>>(mut self, value: T) -> Self {
+        self.lambda_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateDataSourceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_config`](struct.CreateDataSourceRequest.html#structfield.relational_database_config) member.
+    pub fn relational_database_config<T: Into<RelationalDatabaseDataSourceConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.relational_database_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role_arn`](struct.CreateDataSourceRequest.html#structfield.service_role_arn) member.
+    pub fn service_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.CreateDataSourceRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<DataSourceType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDataSourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDataSourceRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDataSourceRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            description: self.description,
+            dynamodb_config: self.dynamodb_config,
+            elasticsearch_config: self.elasticsearch_config,
+            http_config: self.http_config,
+            lambda_config: self.lambda_config,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            relational_database_config: self.relational_database_config,
+            service_role_arn: self.service_role_arn,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -200,6 +525,92 @@ pub struct CreateFunctionRequest {
     pub response_mapping_template: Option<String>,
 }
 
+impl CreateFunctionRequest {
+    /// Returns a builder for `CreateFunctionRequest`, checking its required members when built.
+    pub fn builder() -> CreateFunctionRequestBuilder {
+        CreateFunctionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFunctionRequest`](struct.CreateFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFunctionRequestBuilder {
+    api_id: Option<String>,
+    data_source_name: Option<String>,
+    description: Option<String>,
+    function_version: Option<String>,
+    name: Option<String>,
+    request_mapping_template: Option<String>,
+    response_mapping_template: Option<String>,
+}
+
+impl CreateFunctionRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateFunctionRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_source_name`](struct.CreateFunctionRequest.html#structfield.data_source_name) member.
+    pub fn data_source_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateFunctionRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_version`](struct.CreateFunctionRequest.html#structfield.function_version) member.
+    pub fn function_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateFunctionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_mapping_template`](struct.CreateFunctionRequest.html#structfield.request_mapping_template) member.
+    pub fn request_mapping_template<T: Into<String>>(mut self, value: T) -> Self {
+        self.request_mapping_template = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_mapping_template`](struct.CreateFunctionRequest.html#structfield.response_mapping_template) member.
+    pub fn response_mapping_template<T: Into<String>>(mut self, value: T) -> Self {
+        self.response_mapping_template = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateFunctionRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            data_source_name: self
+                .data_source_name
+                .ok_or(::rusoto_core::BuildError::missing("data_source_name"))?,
+            description: self.description,
+            function_version: self
+                .function_version
+                .ok_or(::rusoto_core::BuildError::missing("function_version"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            request_mapping_template: self.request_mapping_template.ok_or(
+                ::rusoto_core::BuildError::missing("request_mapping_template"),
+            )?,
+            response_mapping_template: self.response_mapping_template,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateFunctionResponse {
@@ -209,7 +620,7 @@ pub struct CreateFunctionResponse {
     pub function_configuration: Option<FunctionConfiguration>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateGraphqlApiRequest {
     /// <p>A list of additional authentication providers for the <code>GraphqlApi</code> API.</p>
     #[serde(rename = "additionalAuthenticationProviders")]
@@ -217,7 +628,7 @@ pub struct CreateGraphqlApiRequest {
     pub additional_authentication_providers: Option<Vec<AdditionalAuthenticationProvider>>,
     /// <p>The authentication type: API key, AWS IAM, OIDC, or Amazon Cognito user pools.</p>
     #[serde(rename = "authenticationType")]
-    pub authentication_type: String,
+    pub authentication_type: AuthenticationType,
     /// <p>The Amazon CloudWatch Logs configuration.</p>
     #[serde(rename = "logConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -239,6 +650,91 @@ pub struct CreateGraphqlApiRequest {
     pub user_pool_config: Option<UserPoolConfig>,
 }
 
+impl CreateGraphqlApiRequest {
+    /// Returns a builder for `CreateGraphqlApiRequest`, checking its required members when built.
+    pub fn builder() -> CreateGraphqlApiRequestBuilder {
+        CreateGraphqlApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGraphqlApiRequest`](struct.CreateGraphqlApiRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateGraphqlApiRequestBuilder {
+    additional_authentication_providers: Option<Vec<AdditionalAuthenticationProvider>>,
+    authentication_type: Option<AuthenticationType>,
+    log_config: Option<LogConfig>,
+    name: Option<String>,
+    open_id_connect_config: Option<OpenIDConnectConfig>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    user_pool_config: Option<UserPoolConfig>,
+}
+
+impl CreateGraphqlApiRequestBuilder {
+    /// Sets the [`additional_authentication_providers`](struct.CreateGraphqlApiRequest.html#structfield.additional_authentication_providers) member.
+    pub fn additional_authentication_providers<T: Into<Vec<AdditionalAuthenticationProvider>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.additional_authentication_providers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authentication_type`](struct.CreateGraphqlApiRequest.html#structfield.authentication_type) member.
+    pub fn authentication_type<T: Into<AuthenticationType>>(mut self, value: T) -> Self {
+        self.authentication_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_config`](struct.CreateGraphqlApiRequest.html#structfield.log_config) member.
+    pub fn log_config<T: Into<LogConfig>>(mut self, value: T) -> Self {
+        self.log_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateGraphqlApiRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`open_id_connect_config`](struct.CreateGraphqlApiRequest.html#structfield.open_id_connect_config) member.
+    pub fn open_id_connect_config<T: Into<OpenIDConnectConfig>>(mut self, value: T) -> Self {
+        self.open_id_connect_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateGraphqlApiRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_config`](struct.CreateGraphqlApiRequest.html#structfield.user_pool_config) member.
+    pub fn user_pool_config<T: Into<UserPoolConfig>>(mut self, value: T) -> Self {
+        self.user_pool_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGraphqlApiRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateGraphqlApiRequest, ::rusoto_core::BuildError> {
+        Ok(CreateGraphqlApiRequest {
+            additional_authentication_providers: self.additional_authentication_providers,
+            authentication_type: self
+                .authentication_type
+                .ok_or(::rusoto_core::BuildError::missing("authentication_type"))?,
+            log_config: self.log_config,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            open_id_connect_config: self.open_id_connect_config,
+            tags: self.tags,
+            user_pool_config: self.user_pool_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateGraphqlApiResponse {
@@ -263,7 +759,7 @@ pub struct CreateResolverRequest {
     /// <p><p>The resolver type.</p> <ul> <li> <p> <b>UNIT</b>: A UNIT resolver type. A UNIT resolver is the default resolver type. A UNIT resolver enables you to execute a GraphQL query against a single data source.</p> </li> <li> <p> <b>PIPELINE</b>: A PIPELINE resolver type. A PIPELINE resolver enables you to execute a series of <code>Function</code> in a serial manner. You can use a pipeline resolver to execute a GraphQL query against multiple data sources.</p> </li> </ul></p>
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub kind: Option<String>,
+    pub kind: Option<ResolverKind>,
     /// <p>The <code>PipelineConfig</code>.</p>
     #[serde(rename = "pipelineConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -280,6 +776,98 @@ pub struct CreateResolverRequest {
     pub type_name: String,
 }
 
+impl CreateResolverRequest {
+    /// Returns a builder for `CreateResolverRequest`, checking its required members when built.
+    pub fn builder() -> CreateResolverRequestBuilder {
+        CreateResolverRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateResolverRequest`](struct.CreateResolverRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateResolverRequestBuilder {
+    api_id: Option<String>,
+    data_source_name: Option<String>,
+    field_name: Option<String>,
+    kind: Option<ResolverKind>,
+    pipeline_config: Option<PipelineConfig>,
+    request_mapping_template: Option<String>,
+    response_mapping_template: Option<String>,
+    type_name: Option<String>,
+}
+
+impl CreateResolverRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateResolverRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_source_name`](struct.CreateResolverRequest.html#structfield.data_source_name) member.
+    pub fn data_source_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`field_name`](struct.CreateResolverRequest.html#structfield.field_name) member.
+    pub fn field_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.field_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kind`](struct.CreateResolverRequest.html#structfield.kind) member.
+    pub fn kind<T: Into<ResolverKind>>(mut self, value: T) -> Self {
+        self.kind = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_config`](struct.CreateResolverRequest.html#structfield.pipeline_config) member.
+    pub fn pipeline_config<T: Into<PipelineConfig>>(mut self, value: T) -> Self {
+        self.pipeline_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_mapping_template`](struct.CreateResolverRequest.html#structfield.request_mapping_template) member.
+    pub fn request_mapping_template<T: Into<String>>(mut self, value: T) -> Self {
+        self.request_mapping_template = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_mapping_template`](struct.CreateResolverRequest.html#structfield.response_mapping_template) member.
+    pub fn response_mapping_template<T: Into<String>>(mut self, value: T) -> Self {
+        self.response_mapping_template = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_name`](struct.CreateResolverRequest.html#structfield.type_name) member.
+    pub fn type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateResolverRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateResolverRequest, ::rusoto_core::BuildError> {
+        Ok(CreateResolverRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            data_source_name: self.data_source_name,
+            field_name: self
+                .field_name
+                .ok_or(::rusoto_core::BuildError::missing("field_name"))?,
+            kind: self.kind,
+            pipeline_config: self.pipeline_config,
+            request_mapping_template: self.request_mapping_template.ok_or(
+                ::rusoto_core::BuildError::missing("request_mapping_template"),
+            )?,
+            response_mapping_template: self.response_mapping_template,
+            type_name: self
+                .type_name
+                .ok_or(::rusoto_core::BuildError::missing("type_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateResolverResponse {
@@ -289,7 +877,7 @@ pub struct CreateResolverResponse {
     pub resolver: Option<Resolver>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateTypeRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -299,7 +887,57 @@ pub struct CreateTypeRequest {
     pub definition: String,
     /// <p>The type format: SDL or JSON.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: TypeDefinitionFormat,
+}
+
+impl CreateTypeRequest {
+    /// Returns a builder for `CreateTypeRequest`, checking its required members when built.
+    pub fn builder() -> CreateTypeRequestBuilder {
+        CreateTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTypeRequest`](struct.CreateTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTypeRequestBuilder {
+    api_id: Option<String>,
+    definition: Option<String>,
+    format: Option<TypeDefinitionFormat>,
+}
+
+impl CreateTypeRequestBuilder {
+    /// Sets the [`api_id`](struct.CreateTypeRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`definition`](struct.CreateTypeRequest.html#structfield.definition) member.
+    pub fn definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.definition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`format`](struct.CreateTypeRequest.html#structfield.format) member.
+    pub fn format<T: Into<TypeDefinitionFormat>>(mut self, value: T) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTypeRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTypeRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            definition: self
+                .definition
+                .ok_or(::rusoto_core::BuildError::missing("definition"))?,
+            format: self
+                .format
+                .ok_or(::rusoto_core::BuildError::missing("format"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -354,7 +992,187 @@ pub struct DataSource {
     /// <p><p>The type of the data source.</p> <ul> <li> <p> <b>AMAZON<em>DYNAMODB</b>: The data source is an Amazon DynamoDB table.</p> </li> <li> <p> <b>AMAZON</em>ELASTICSEARCH</b>: The data source is an Amazon Elasticsearch Service domain.</p> </li> <li> <p> <b>AWS<em>LAMBDA</b>: The data source is an AWS Lambda function.</p> </li> <li> <p> <b>NONE</b>: There is no data source. This type is used when you wish to invoke a GraphQL operation without connecting to a data source, such as performing data transformation with resolvers or triggering a subscription to be invoked from a mutation.</p> </li> <li> <p> <b>HTTP</b>: The data source is an HTTP endpoint.</p> </li> <li> <p> <b>RELATIONAL</em>DATABASE</b>: The data source is a relational database.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DataSourceType>,
+}
+
+/// <p>The type of the <code>DataSource</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DataSourceType {
+    /// <p><code>AWS_LAMBDA</code></p>
+    AwsLambda,
+    /// <p><code>AMAZON_DYNAMODB</code></p>
+    AmazonDynamodb,
+    /// <p><code>AMAZON_ELASTICSEARCH</code></p>
+    AmazonElasticsearch,
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>HTTP</code></p>
+    Http,
+    /// <p><code>RELATIONAL_DATABASE</code></p>
+    RelationalDatabase,
+    /// <p><code>AMAZON_OPENSEARCH_SERVICE</code></p>
+    AmazonOpensearchService,
+    /// <p><code>AMAZON_EVENTBRIDGE</code></p>
+    AmazonEventbridge,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DataSourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSourceType::AwsLambda => "AWS_LAMBDA",
+            DataSourceType::AmazonDynamodb => "AMAZON_DYNAMODB",
+            DataSourceType::AmazonElasticsearch => "AMAZON_ELASTICSEARCH",
+            DataSourceType::None => "NONE",
+            DataSourceType::Http => "HTTP",
+            DataSourceType::RelationalDatabase => "RELATIONAL_DATABASE",
+            DataSourceType::AmazonOpensearchService => "AMAZON_OPENSEARCH_SERVICE",
+            DataSourceType::AmazonEventbridge => "AMAZON_EVENTBRIDGE",
+            DataSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DataSourceType {
+    fn from(value: &'a str) -> DataSourceType {
+        match value {
+            "AWS_LAMBDA" => DataSourceType::AwsLambda,
+            "AMAZON_DYNAMODB" => DataSourceType::AmazonDynamodb,
+            "AMAZON_ELASTICSEARCH" => DataSourceType::AmazonElasticsearch,
+            "NONE" => DataSourceType::None,
+            "HTTP" => DataSourceType::Http,
+            "RELATIONAL_DATABASE" => DataSourceType::RelationalDatabase,
+            "AMAZON_OPENSEARCH_SERVICE" => DataSourceType::AmazonOpensearchService,
+            "AMAZON_EVENTBRIDGE" => DataSourceType::AmazonEventbridge,
+            _ => DataSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSourceType {
+    fn from(value: String) -> DataSourceType {
+        DataSourceType::from(value.as_str())
+    }
+}
+
+impl From<DataSourceType> for String {
+    fn from(value: DataSourceType) -> String {
+        match value {
+            DataSourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DataSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DataSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DataSourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DataSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DataSourceType::from(value))
+    }
+}
+
+/// <p>The action that you want your GraphQL API to take when a request that uses Amazon Cognito user pool authentication doesn't match the Amazon Cognito user pool configuration.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DefaultAction {
+    /// <p><code>ALLOW</code></p>
+    Allow,
+    /// <p><code>DENY</code></p>
+    Deny,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DefaultAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DefaultAction::Allow => "ALLOW",
+            DefaultAction::Deny => "DENY",
+            DefaultAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DefaultAction {
+    fn from(value: &'a str) -> DefaultAction {
+        match value {
+            "ALLOW" => DefaultAction::Allow,
+            "DENY" => DefaultAction::Deny,
+            _ => DefaultAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DefaultAction {
+    fn from(value: String) -> DefaultAction {
+        DefaultAction::from(value.as_str())
+    }
+}
+
+impl From<DefaultAction> for String {
+    fn from(value: DefaultAction) -> String {
+        match value {
+            DefaultAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DefaultAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DefaultAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DefaultAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DefaultAction {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DefaultAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DefaultAction::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -367,6 +1185,44 @@ pub struct DeleteApiKeyRequest {
     pub id: String,
 }
 
+impl DeleteApiKeyRequest {
+    /// Returns a builder for `DeleteApiKeyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApiKeyRequestBuilder {
+        DeleteApiKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApiKeyRequest`](struct.DeleteApiKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApiKeyRequestBuilder {
+    api_id: Option<String>,
+    id: Option<String>,
+}
+
+impl DeleteApiKeyRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteApiKeyRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DeleteApiKeyRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApiKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteApiKeyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteApiKeyRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteApiKeyResponse {}
@@ -381,6 +1237,48 @@ pub struct DeleteDataSourceRequest {
     pub name: String,
 }
 
+impl DeleteDataSourceRequest {
+    /// Returns a builder for `DeleteDataSourceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDataSourceRequestBuilder {
+        DeleteDataSourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDataSourceRequest`](struct.DeleteDataSourceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDataSourceRequestBuilder {
+    api_id: Option<String>,
+    name: Option<String>,
+}
+
+impl DeleteDataSourceRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteDataSourceRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DeleteDataSourceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDataSourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDataSourceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDataSourceRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDataSourceResponse {}
@@ -395,6 +1293,46 @@ pub struct DeleteFunctionRequest {
     pub function_id: String,
 }
 
+impl DeleteFunctionRequest {
+    /// Returns a builder for `DeleteFunctionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFunctionRequestBuilder {
+        DeleteFunctionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFunctionRequest`](struct.DeleteFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFunctionRequestBuilder {
+    api_id: Option<String>,
+    function_id: Option<String>,
+}
+
+impl DeleteFunctionRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteFunctionRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_id`](struct.DeleteFunctionRequest.html#structfield.function_id) member.
+    pub fn function_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFunctionRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            function_id: self
+                .function_id
+                .ok_or(::rusoto_core::BuildError::missing("function_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteFunctionResponse {}
@@ -406,6 +1344,38 @@ pub struct DeleteGraphqlApiRequest {
     pub api_id: String,
 }
 
+impl DeleteGraphqlApiRequest {
+    /// Returns a builder for `DeleteGraphqlApiRequest`, checking its required members when built.
+    pub fn builder() -> DeleteGraphqlApiRequestBuilder {
+        DeleteGraphqlApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGraphqlApiRequest`](struct.DeleteGraphqlApiRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteGraphqlApiRequestBuilder {
+    api_id: Option<String>,
+}
+
+impl DeleteGraphqlApiRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteGraphqlApiRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGraphqlApiRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteGraphqlApiRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteGraphqlApiRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteGraphqlApiResponse {}
@@ -423,6 +1393,56 @@ pub struct DeleteResolverRequest {
     pub type_name: String,
 }
 
+impl DeleteResolverRequest {
+    /// Returns a builder for `DeleteResolverRequest`, checking its required members when built.
+    pub fn builder() -> DeleteResolverRequestBuilder {
+        DeleteResolverRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteResolverRequest`](struct.DeleteResolverRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteResolverRequestBuilder {
+    api_id: Option<String>,
+    field_name: Option<String>,
+    type_name: Option<String>,
+}
+
+impl DeleteResolverRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteResolverRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`field_name`](struct.DeleteResolverRequest.html#structfield.field_name) member.
+    pub fn field_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.field_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_name`](struct.DeleteResolverRequest.html#structfield.type_name) member.
+    pub fn type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteResolverRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteResolverRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteResolverRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            field_name: self
+                .field_name
+                .ok_or(::rusoto_core::BuildError::missing("field_name"))?,
+            type_name: self
+                .type_name
+                .ok_or(::rusoto_core::BuildError::missing("type_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteResolverResponse {}
@@ -437,6 +1457,46 @@ pub struct DeleteTypeRequest {
     pub type_name: String,
 }
 
+impl DeleteTypeRequest {
+    /// Returns a builder for `DeleteTypeRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTypeRequestBuilder {
+        DeleteTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTypeRequest`](struct.DeleteTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTypeRequestBuilder {
+    api_id: Option<String>,
+    type_name: Option<String>,
+}
+
+impl DeleteTypeRequestBuilder {
+    /// Sets the [`api_id`](struct.DeleteTypeRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_name`](struct.DeleteTypeRequest.html#structfield.type_name) member.
+    pub fn type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTypeRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTypeRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            type_name: self
+                .type_name
+                .ok_or(::rusoto_core::BuildError::missing("type_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteTypeResponse {}
@@ -456,15 +1516,97 @@ pub struct DynamodbDataSourceConfig {
     pub use_caller_credentials: Option<bool>,
 }
 
-/// <p>Describes an Elasticsearch data source configuration.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ElasticsearchDataSourceConfig {
-    /// <p>The AWS Region.</p>
-    #[serde(rename = "awsRegion")]
-    pub aws_region: String,
-    /// <p>The endpoint.</p>
-    #[serde(rename = "endpoint")]
-    pub endpoint: String,
+/// <p>Describes an Elasticsearch data source configuration.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ElasticsearchDataSourceConfig {
+    /// <p>The AWS Region.</p>
+    #[serde(rename = "awsRegion")]
+    pub aws_region: String,
+    /// <p>The endpoint.</p>
+    #[serde(rename = "endpoint")]
+    pub endpoint: String,
+}
+
+/// <p><p>The field logging level. Values can be NONE, ERROR, or ALL. </p> <ul> <li> <p> <b>NONE</b>: No field-level logs are captured.</p> </li> <li> <p> <b>ERROR</b>: Logs the following information only for the fields that are in error:</p> <ul> <li> <p>The error section in the server response.</p> </li> <li> <p>Field-level errors.</p> </li> <li> <p>The generated request/response functions that got resolved for error fields.</p> </li> </ul> </li> <li> <p> <b>ALL</b>: The following information is logged for all fields in the query:</p> <ul> <li> <p>Field-level tracing information.</p> </li> <li> <p>The generated request/response functions that got resolved for each field.</p> </li> </ul> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FieldLogLevel {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// <p><code>ALL</code></p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FieldLogLevel {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FieldLogLevel::None => "NONE",
+            FieldLogLevel::Error => "ERROR",
+            FieldLogLevel::All => "ALL",
+            FieldLogLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FieldLogLevel {
+    fn from(value: &'a str) -> FieldLogLevel {
+        match value {
+            "NONE" => FieldLogLevel::None,
+            "ERROR" => FieldLogLevel::Error,
+            "ALL" => FieldLogLevel::All,
+            _ => FieldLogLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FieldLogLevel {
+    fn from(value: String) -> FieldLogLevel {
+        FieldLogLevel::from(value.as_str())
+    }
+}
+
+impl From<FieldLogLevel> for String {
+    fn from(value: FieldLogLevel) -> String {
+        match value {
+            FieldLogLevel::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FieldLogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FieldLogLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FieldLogLevel {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FieldLogLevel {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FieldLogLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FieldLogLevel::from(value))
+    }
 }
 
 /// <p>A function is a reusable entity. Multiple functions can be used to compose the resolver logic.</p>
@@ -515,6 +1657,46 @@ pub struct GetDataSourceRequest {
     pub name: String,
 }
 
+impl GetDataSourceRequest {
+    /// Returns a builder for `GetDataSourceRequest`, checking its required members when built.
+    pub fn builder() -> GetDataSourceRequestBuilder {
+        GetDataSourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDataSourceRequest`](struct.GetDataSourceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDataSourceRequestBuilder {
+    api_id: Option<String>,
+    name: Option<String>,
+}
+
+impl GetDataSourceRequestBuilder {
+    /// Sets the [`api_id`](struct.GetDataSourceRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetDataSourceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDataSourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDataSourceRequest, ::rusoto_core::BuildError> {
+        Ok(GetDataSourceRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDataSourceResponse {
@@ -534,6 +1716,46 @@ pub struct GetFunctionRequest {
     pub function_id: String,
 }
 
+impl GetFunctionRequest {
+    /// Returns a builder for `GetFunctionRequest`, checking its required members when built.
+    pub fn builder() -> GetFunctionRequestBuilder {
+        GetFunctionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFunctionRequest`](struct.GetFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFunctionRequestBuilder {
+    api_id: Option<String>,
+    function_id: Option<String>,
+}
+
+impl GetFunctionRequestBuilder {
+    /// Sets the [`api_id`](struct.GetFunctionRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_id`](struct.GetFunctionRequest.html#structfield.function_id) member.
+    pub fn function_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(GetFunctionRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            function_id: self
+                .function_id
+                .ok_or(::rusoto_core::BuildError::missing("function_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFunctionResponse {
@@ -550,6 +1772,36 @@ pub struct GetGraphqlApiRequest {
     pub api_id: String,
 }
 
+impl GetGraphqlApiRequest {
+    /// Returns a builder for `GetGraphqlApiRequest`, checking its required members when built.
+    pub fn builder() -> GetGraphqlApiRequestBuilder {
+        GetGraphqlApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGraphqlApiRequest`](struct.GetGraphqlApiRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGraphqlApiRequestBuilder {
+    api_id: Option<String>,
+}
+
+impl GetGraphqlApiRequestBuilder {
+    /// Sets the [`api_id`](struct.GetGraphqlApiRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGraphqlApiRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGraphqlApiRequest, ::rusoto_core::BuildError> {
+        Ok(GetGraphqlApiRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetGraphqlApiResponse {
@@ -559,20 +1811,70 @@ pub struct GetGraphqlApiResponse {
     pub graphql_api: Option<GraphqlApi>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetIntrospectionSchemaRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
     pub api_id: String,
     /// <p>The schema format: SDL or JSON.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: OutputType,
     /// <p>A flag that specifies whether the schema introspection should contain directives.</p>
     #[serde(rename = "includeDirectives")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub include_directives: Option<bool>,
 }
 
+impl GetIntrospectionSchemaRequest {
+    /// Returns a builder for `GetIntrospectionSchemaRequest`, checking its required members when built.
+    pub fn builder() -> GetIntrospectionSchemaRequestBuilder {
+        GetIntrospectionSchemaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIntrospectionSchemaRequest`](struct.GetIntrospectionSchemaRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIntrospectionSchemaRequestBuilder {
+    api_id: Option<String>,
+    format: Option<OutputType>,
+    include_directives: Option<bool>,
+}
+
+impl GetIntrospectionSchemaRequestBuilder {
+    /// Sets the [`api_id`](struct.GetIntrospectionSchemaRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`format`](struct.GetIntrospectionSchemaRequest.html#structfield.format) member.
+    pub fn format<T: Into<OutputType>>(mut self, value: T) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_directives`](struct.GetIntrospectionSchemaRequest.html#structfield.include_directives) member.
+    pub fn include_directives<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_directives = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIntrospectionSchemaRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIntrospectionSchemaRequest, ::rusoto_core::BuildError> {
+        Ok(GetIntrospectionSchemaRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            format: self
+                .format
+                .ok_or(::rusoto_core::BuildError::missing("format"))?,
+            include_directives: self.include_directives,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetIntrospectionSchemaResponse {
     /// <p>The schema, in GraphQL Schema Definition Language (SDL) format.</p> <p>For more information, see the <a href="http://graphql.org/learn/schema/">GraphQL SDL documentation</a>.</p>
@@ -592,6 +1894,56 @@ pub struct GetResolverRequest {
     pub type_name: String,
 }
 
+impl GetResolverRequest {
+    /// Returns a builder for `GetResolverRequest`, checking its required members when built.
+    pub fn builder() -> GetResolverRequestBuilder {
+        GetResolverRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResolverRequest`](struct.GetResolverRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResolverRequestBuilder {
+    api_id: Option<String>,
+    field_name: Option<String>,
+    type_name: Option<String>,
+}
+
+impl GetResolverRequestBuilder {
+    /// Sets the [`api_id`](struct.GetResolverRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`field_name`](struct.GetResolverRequest.html#structfield.field_name) member.
+    pub fn field_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.field_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_name`](struct.GetResolverRequest.html#structfield.type_name) member.
+    pub fn type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResolverRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetResolverRequest, ::rusoto_core::BuildError> {
+        Ok(GetResolverRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            field_name: self
+                .field_name
+                .ok_or(::rusoto_core::BuildError::missing("field_name"))?,
+            type_name: self
+                .type_name
+                .ok_or(::rusoto_core::BuildError::missing("type_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetResolverResponse {
@@ -608,6 +1960,38 @@ pub struct GetSchemaCreationStatusRequest {
     pub api_id: String,
 }
 
+impl GetSchemaCreationStatusRequest {
+    /// Returns a builder for `GetSchemaCreationStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetSchemaCreationStatusRequestBuilder {
+        GetSchemaCreationStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSchemaCreationStatusRequest`](struct.GetSchemaCreationStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSchemaCreationStatusRequestBuilder {
+    api_id: Option<String>,
+}
+
+impl GetSchemaCreationStatusRequestBuilder {
+    /// Sets the [`api_id`](struct.GetSchemaCreationStatusRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSchemaCreationStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSchemaCreationStatusRequest, ::rusoto_core::BuildError> {
+        Ok(GetSchemaCreationStatusRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSchemaCreationStatusResponse {
@@ -618,22 +2002,72 @@ pub struct GetSchemaCreationStatusResponse {
     /// <p>The current state of the schema (PROCESSING, FAILED, SUCCESS, or NOT_APPLICABLE). When the schema is in the ACTIVE state, you can add data.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SchemaStatus>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetTypeRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
     pub api_id: String,
     /// <p>The type format: SDL or JSON.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: TypeDefinitionFormat,
     /// <p>The type name.</p>
     #[serde(rename = "typeName")]
     pub type_name: String,
 }
 
+impl GetTypeRequest {
+    /// Returns a builder for `GetTypeRequest`, checking its required members when built.
+    pub fn builder() -> GetTypeRequestBuilder {
+        GetTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTypeRequest`](struct.GetTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTypeRequestBuilder {
+    api_id: Option<String>,
+    format: Option<TypeDefinitionFormat>,
+    type_name: Option<String>,
+}
+
+impl GetTypeRequestBuilder {
+    /// Sets the [`api_id`](struct.GetTypeRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`format`](struct.GetTypeRequest.html#structfield.format) member.
+    pub fn format<T: Into<TypeDefinitionFormat>>(mut self, value: T) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_name`](struct.GetTypeRequest.html#structfield.type_name) member.
+    pub fn type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTypeRequest, ::rusoto_core::BuildError> {
+        Ok(GetTypeRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            format: self
+                .format
+                .ok_or(::rusoto_core::BuildError::missing("format"))?,
+            type_name: self
+                .type_name
+                .ok_or(::rusoto_core::BuildError::missing("type_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTypeResponse {
@@ -662,7 +2096,7 @@ pub struct GraphqlApi {
     /// <p>The authentication type.</p>
     #[serde(rename = "authenticationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_type: Option<String>,
+    pub authentication_type: Option<AuthenticationType>,
     /// <p>The Amazon CloudWatch Logs configuration.</p>
     #[serde(rename = "logConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -725,6 +2159,52 @@ pub struct ListApiKeysRequest {
     pub next_token: Option<String>,
 }
 
+impl ListApiKeysRequest {
+    /// Returns a builder for `ListApiKeysRequest`, checking its required members when built.
+    pub fn builder() -> ListApiKeysRequestBuilder {
+        ListApiKeysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListApiKeysRequest`](struct.ListApiKeysRequest.html).
+#[derive(Default, Debug)]
+pub struct ListApiKeysRequestBuilder {
+    api_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListApiKeysRequestBuilder {
+    /// Sets the [`api_id`](struct.ListApiKeysRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListApiKeysRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListApiKeysRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApiKeysRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListApiKeysRequest, ::rusoto_core::BuildError> {
+        Ok(ListApiKeysRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListApiKeysResponse {
@@ -753,6 +2233,52 @@ pub struct ListDataSourcesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDataSourcesRequest {
+    /// Returns a builder for `ListDataSourcesRequest`, checking its required members when built.
+    pub fn builder() -> ListDataSourcesRequestBuilder {
+        ListDataSourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDataSourcesRequest`](struct.ListDataSourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDataSourcesRequestBuilder {
+    api_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDataSourcesRequestBuilder {
+    /// Sets the [`api_id`](struct.ListDataSourcesRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDataSourcesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDataSourcesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDataSourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDataSourcesRequest, ::rusoto_core::BuildError> {
+        Ok(ListDataSourcesRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDataSourcesResponse {
@@ -781,6 +2307,52 @@ pub struct ListFunctionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListFunctionsRequest {
+    /// Returns a builder for `ListFunctionsRequest`, checking its required members when built.
+    pub fn builder() -> ListFunctionsRequestBuilder {
+        ListFunctionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFunctionsRequest`](struct.ListFunctionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFunctionsRequestBuilder {
+    api_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListFunctionsRequestBuilder {
+    /// Sets the [`api_id`](struct.ListFunctionsRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListFunctionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListFunctionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFunctionsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListFunctionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListFunctionsRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListFunctionsResponse {
@@ -806,6 +2378,42 @@ pub struct ListGraphqlApisRequest {
     pub next_token: Option<String>,
 }
 
+impl ListGraphqlApisRequest {
+    /// Returns a builder for `ListGraphqlApisRequest`, checking its required members when built.
+    pub fn builder() -> ListGraphqlApisRequestBuilder {
+        ListGraphqlApisRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGraphqlApisRequest`](struct.ListGraphqlApisRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGraphqlApisRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListGraphqlApisRequestBuilder {
+    /// Sets the [`max_results`](struct.ListGraphqlApisRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListGraphqlApisRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGraphqlApisRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListGraphqlApisRequest, ::rusoto_core::BuildError> {
+        Ok(ListGraphqlApisRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGraphqlApisResponse {
@@ -837,6 +2445,64 @@ pub struct ListResolversByFunctionRequest {
     pub next_token: Option<String>,
 }
 
+impl ListResolversByFunctionRequest {
+    /// Returns a builder for `ListResolversByFunctionRequest`, checking its required members when built.
+    pub fn builder() -> ListResolversByFunctionRequestBuilder {
+        ListResolversByFunctionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResolversByFunctionRequest`](struct.ListResolversByFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResolversByFunctionRequestBuilder {
+    api_id: Option<String>,
+    function_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListResolversByFunctionRequestBuilder {
+    /// Sets the [`api_id`](struct.ListResolversByFunctionRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_id`](struct.ListResolversByFunctionRequest.html#structfield.function_id) member.
+    pub fn function_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListResolversByFunctionRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListResolversByFunctionRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResolversByFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListResolversByFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(ListResolversByFunctionRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            function_id: self
+                .function_id
+                .ok_or(::rusoto_core::BuildError::missing("function_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResolversByFunctionResponse {
@@ -868,6 +2534,62 @@ pub struct ListResolversRequest {
     pub type_name: String,
 }
 
+impl ListResolversRequest {
+    /// Returns a builder for `ListResolversRequest`, checking its required members when built.
+    pub fn builder() -> ListResolversRequestBuilder {
+        ListResolversRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResolversRequest`](struct.ListResolversRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResolversRequestBuilder {
+    api_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    type_name: Option<String>,
+}
+
+impl ListResolversRequestBuilder {
+    /// Sets the [`api_id`](struct.ListResolversRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListResolversRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListResolversRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_name`](struct.ListResolversRequest.html#structfield.type_name) member.
+    pub fn type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResolversRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListResolversRequest, ::rusoto_core::BuildError> {
+        Ok(ListResolversRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            type_name: self
+                .type_name
+                .ok_or(::rusoto_core::BuildError::missing("type_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResolversResponse {
@@ -888,6 +2610,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -897,14 +2651,14 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListTypesRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
     pub api_id: String,
     /// <p>The type format: SDL or JSON.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: TypeDefinitionFormat,
     /// <p>The maximum number of results you want the request to return.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -915,6 +2669,62 @@ pub struct ListTypesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListTypesRequest {
+    /// Returns a builder for `ListTypesRequest`, checking its required members when built.
+    pub fn builder() -> ListTypesRequestBuilder {
+        ListTypesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTypesRequest`](struct.ListTypesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTypesRequestBuilder {
+    api_id: Option<String>,
+    format: Option<TypeDefinitionFormat>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListTypesRequestBuilder {
+    /// Sets the [`api_id`](struct.ListTypesRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`format`](struct.ListTypesRequest.html#structfield.format) member.
+    pub fn format<T: Into<TypeDefinitionFormat>>(mut self, value: T) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListTypesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTypesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTypesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTypesRequest, ::rusoto_core::BuildError> {
+        Ok(ListTypesRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            format: self
+                .format
+                .ok_or(::rusoto_core::BuildError::missing("format"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTypesResponse {
@@ -929,7 +2739,7 @@ pub struct ListTypesResponse {
 }
 
 /// <p>The CloudWatch Logs configuration.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogConfig {
     /// <p>The service role that AWS AppSync will assume to publish to Amazon CloudWatch logs in your account. </p>
     #[serde(rename = "cloudWatchLogsRoleArn")]
@@ -940,7 +2750,7 @@ pub struct LogConfig {
     pub exclude_verbose_content: Option<bool>,
     /// <p><p>The field logging level. Values can be NONE, ERROR, or ALL. </p> <ul> <li> <p> <b>NONE</b>: No field-level logs are captured.</p> </li> <li> <p> <b>ERROR</b>: Logs the following information only for the fields that are in error:</p> <ul> <li> <p>The error section in the server response.</p> </li> <li> <p>Field-level errors.</p> </li> <li> <p>The generated request/response functions that got resolved for error fields.</p> </li> </ul> </li> <li> <p> <b>ALL</b>: The following information is logged for all fields in the query:</p> <ul> <li> <p>Field-level tracing information.</p> </li> <li> <p>The generated request/response functions that got resolved for each field.</p> </li> </ul> </li> </ul></p>
     #[serde(rename = "fieldLogLevel")]
-    pub field_log_level: String,
+    pub field_log_level: FieldLogLevel,
 }
 
 /// <p>Describes an OpenID Connect configuration.</p>
@@ -963,6 +2773,84 @@ pub struct OpenIDConnectConfig {
     pub issuer: String,
 }
 
+/// <p>The schema format: SDL or JSON.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OutputType {
+    /// <p><code>SDL</code></p>
+    Sdl,
+    /// <p><code>JSON</code></p>
+    Json,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OutputType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OutputType::Sdl => "SDL",
+            OutputType::Json => "JSON",
+            OutputType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OutputType {
+    fn from(value: &'a str) -> OutputType {
+        match value {
+            "SDL" => OutputType::Sdl,
+            "JSON" => OutputType::Json,
+            _ => OutputType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OutputType {
+    fn from(value: String) -> OutputType {
+        OutputType::from(value.as_str())
+    }
+}
+
+impl From<OutputType> for String {
+    fn from(value: OutputType) -> String {
+        match value {
+            OutputType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OutputType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OutputType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OutputType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OutputType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OutputType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OutputType::from(value))
+    }
+}
+
 /// <p>The pipeline configuration for a resolver of kind <code>PIPELINE</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PipelineConfig {
@@ -1007,7 +2895,83 @@ pub struct RelationalDatabaseDataSourceConfig {
     /// <p><p>Source type for the relational database.</p> <ul> <li> <p> <b>RDS<em>HTTP</em>ENDPOINT</b>: The relational database source type is an Amazon RDS HTTP endpoint.</p> </li> </ul></p>
     #[serde(rename = "relationalDatabaseSourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub relational_database_source_type: Option<String>,
+    pub relational_database_source_type: Option<RelationalDatabaseSourceType>,
+}
+
+/// <p><p>Source type for the relational database.</p> <ul> <li> <p> <b>RDS<em>HTTP</em>ENDPOINT</b>: The relational database source type is an Amazon RDS HTTP endpoint.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RelationalDatabaseSourceType {
+    /// <p><code>RDS_HTTP_ENDPOINT</code></p>
+    RdsHttpEndpoint,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RelationalDatabaseSourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelationalDatabaseSourceType::RdsHttpEndpoint => "RDS_HTTP_ENDPOINT",
+            RelationalDatabaseSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RelationalDatabaseSourceType {
+    fn from(value: &'a str) -> RelationalDatabaseSourceType {
+        match value {
+            "RDS_HTTP_ENDPOINT" => RelationalDatabaseSourceType::RdsHttpEndpoint,
+            _ => RelationalDatabaseSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelationalDatabaseSourceType {
+    fn from(value: String) -> RelationalDatabaseSourceType {
+        RelationalDatabaseSourceType::from(value.as_str())
+    }
+}
+
+impl From<RelationalDatabaseSourceType> for String {
+    fn from(value: RelationalDatabaseSourceType) -> String {
+        match value {
+            RelationalDatabaseSourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RelationalDatabaseSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RelationalDatabaseSourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RelationalDatabaseSourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RelationalDatabaseSourceType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<RelationalDatabaseSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RelationalDatabaseSourceType::from(value))
+    }
 }
 
 /// <p>Describes a resolver.</p>
@@ -1025,7 +2989,7 @@ pub struct Resolver {
     /// <p><p>The resolver type.</p> <ul> <li> <p> <b>UNIT</b>: A UNIT resolver type. A UNIT resolver is the default resolver type. A UNIT resolver enables you to execute a GraphQL query against a single data source.</p> </li> <li> <p> <b>PIPELINE</b>: A PIPELINE resolver type. A PIPELINE resolver enables you to execute a series of <code>Function</code> in a serial manner. You can use a pipeline resolver to execute a GraphQL query against multiple data sources.</p> </li> </ul></p>
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub kind: Option<String>,
+    pub kind: Option<ResolverKind>,
     /// <p>The <code>PipelineConfig</code>.</p>
     #[serde(rename = "pipelineConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1048,6 +3012,178 @@ pub struct Resolver {
     pub type_name: Option<String>,
 }
 
+/// <p><p>The resolver type.</p> <ul> <li> <p> <b>UNIT</b>: A UNIT resolver type. A UNIT resolver is the default resolver type. A UNIT resolver enables you to execute a GraphQL query against a single data source.</p> </li> <li> <p> <b>PIPELINE</b>: A PIPELINE resolver type. A PIPELINE resolver enables you to execute a series of <code>Function</code> in a serial manner. You can use a pipeline resolver to execute a GraphQL query against multiple data sources.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResolverKind {
+    /// <p><code>UNIT</code></p>
+    Unit,
+    /// <p><code>PIPELINE</code></p>
+    Pipeline,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResolverKind {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResolverKind::Unit => "UNIT",
+            ResolverKind::Pipeline => "PIPELINE",
+            ResolverKind::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResolverKind {
+    fn from(value: &'a str) -> ResolverKind {
+        match value {
+            "UNIT" => ResolverKind::Unit,
+            "PIPELINE" => ResolverKind::Pipeline,
+            _ => ResolverKind::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResolverKind {
+    fn from(value: String) -> ResolverKind {
+        ResolverKind::from(value.as_str())
+    }
+}
+
+impl From<ResolverKind> for String {
+    fn from(value: ResolverKind) -> String {
+        match value {
+            ResolverKind::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResolverKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResolverKind {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResolverKind {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResolverKind {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResolverKind, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResolverKind::from(value))
+    }
+}
+
+/// <p>The current state of the schema (PROCESSING, FAILED, SUCCESS, or NOT_APPLICABLE). When the schema is in the ACTIVE state, you can add data.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SchemaStatus {
+    /// <p><code>PROCESSING</code></p>
+    Processing,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>SUCCESS</code></p>
+    Success,
+    /// <p><code>NOT_APPLICABLE</code></p>
+    NotApplicable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SchemaStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SchemaStatus::Processing => "PROCESSING",
+            SchemaStatus::Active => "ACTIVE",
+            SchemaStatus::Deleting => "DELETING",
+            SchemaStatus::Failed => "FAILED",
+            SchemaStatus::Success => "SUCCESS",
+            SchemaStatus::NotApplicable => "NOT_APPLICABLE",
+            SchemaStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SchemaStatus {
+    fn from(value: &'a str) -> SchemaStatus {
+        match value {
+            "PROCESSING" => SchemaStatus::Processing,
+            "ACTIVE" => SchemaStatus::Active,
+            "DELETING" => SchemaStatus::Deleting,
+            "FAILED" => SchemaStatus::Failed,
+            "SUCCESS" => SchemaStatus::Success,
+            "NOT_APPLICABLE" => SchemaStatus::NotApplicable,
+            _ => SchemaStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SchemaStatus {
+    fn from(value: String) -> SchemaStatus {
+        SchemaStatus::from(value.as_str())
+    }
+}
+
+impl From<SchemaStatus> for String {
+    fn from(value: SchemaStatus) -> String {
+        match value {
+            SchemaStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SchemaStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SchemaStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SchemaStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SchemaStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SchemaStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartSchemaCreationRequest {
     /// <p>The API ID.</p>
@@ -1063,13 +3199,55 @@ pub struct StartSchemaCreationRequest {
     pub definition: bytes::Bytes,
 }
 
+impl StartSchemaCreationRequest {
+    /// Returns a builder for `StartSchemaCreationRequest`, checking its required members when built.
+    pub fn builder() -> StartSchemaCreationRequestBuilder {
+        StartSchemaCreationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartSchemaCreationRequest`](struct.StartSchemaCreationRequest.html).
+#[derive(Default, Debug)]
+pub struct StartSchemaCreationRequestBuilder {
+    api_id: Option<String>,
+    definition: Option<bytes::Bytes>,
+}
+
+impl StartSchemaCreationRequestBuilder {
+    /// Sets the [`api_id`](struct.StartSchemaCreationRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`definition`](struct.StartSchemaCreationRequest.html#structfield.definition) member.
+    pub fn definition<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.definition = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartSchemaCreationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartSchemaCreationRequest, ::rusoto_core::BuildError> {
+        Ok(StartSchemaCreationRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            definition: self
+                .definition
+                .ok_or(::rusoto_core::BuildError::missing("definition"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartSchemaCreationResponse {
     /// <p>The current state of the schema (PROCESSING, FAILED, SUCCESS, or NOT_APPLICABLE). When the schema is in the ACTIVE state, you can add data.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SchemaStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1082,6 +3260,46 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -1105,13 +3323,91 @@ pub struct Type {
     /// <p>The type format: SDL or JSON.</p>
     #[serde(rename = "format")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub format: Option<String>,
+    pub format: Option<TypeDefinitionFormat>,
     /// <p>The type name.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
+/// <p>The type format: SDL or JSON.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TypeDefinitionFormat {
+    /// <p><code>SDL</code></p>
+    Sdl,
+    /// <p><code>JSON</code></p>
+    Json,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TypeDefinitionFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TypeDefinitionFormat::Sdl => "SDL",
+            TypeDefinitionFormat::Json => "JSON",
+            TypeDefinitionFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TypeDefinitionFormat {
+    fn from(value: &'a str) -> TypeDefinitionFormat {
+        match value {
+            "SDL" => TypeDefinitionFormat::Sdl,
+            "JSON" => TypeDefinitionFormat::Json,
+            _ => TypeDefinitionFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TypeDefinitionFormat {
+    fn from(value: String) -> TypeDefinitionFormat {
+        TypeDefinitionFormat::from(value.as_str())
+    }
+}
+
+impl From<TypeDefinitionFormat> for String {
+    fn from(value: TypeDefinitionFormat) -> String {
+        match value {
+            TypeDefinitionFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TypeDefinitionFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TypeDefinitionFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TypeDefinitionFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TypeDefinitionFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TypeDefinitionFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TypeDefinitionFormat::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
     /// <p>The <code>GraphqlApi</code> ARN.</p>
@@ -1122,6 +3418,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -1144,6 +3480,60 @@ pub struct UpdateApiKeyRequest {
     pub id: String,
 }
 
+impl UpdateApiKeyRequest {
+    /// Returns a builder for `UpdateApiKeyRequest`, checking its required members when built.
+    pub fn builder() -> UpdateApiKeyRequestBuilder {
+        UpdateApiKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateApiKeyRequest`](struct.UpdateApiKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateApiKeyRequestBuilder {
+    api_id: Option<String>,
+    description: Option<String>,
+    expires: Option<i64>,
+    id: Option<String>,
+}
+
+impl UpdateApiKeyRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateApiKeyRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateApiKeyRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expires`](struct.UpdateApiKeyRequest.html#structfield.expires) member.
+    pub fn expires<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expires = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateApiKeyRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateApiKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateApiKeyRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateApiKeyRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            description: self.description,
+            expires: self.expires,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateApiKeyResponse {
@@ -1153,7 +3543,7 @@ pub struct UpdateApiKeyResponse {
     pub api_key: Option<ApiKey>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateDataSourceRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -1191,7 +3581,121 @@ pub struct UpdateDataSourceRequest {
     pub service_role_arn: Option<String>,
     /// <p>The new data source type.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: DataSourceType,
+}
+
+impl UpdateDataSourceRequest {
+    /// Returns a builder for `UpdateDataSourceRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDataSourceRequestBuilder {
+        UpdateDataSourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDataSourceRequest`](struct.UpdateDataSourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDataSourceRequestBuilder {
+    api_id: Option<String>,
+    description: Option<String>,
+    dynamodb_config: Option<DynamodbDataSourceConfig>,
+    elasticsearch_config: Option<ElasticsearchDataSourceConfig>,
+    http_config: Option<HttpDataSourceConfig>,
+    lambda_config: Option<LambdaDataSourceConfig>,
+    name: Option<String>,
+    relational_database_config: Option<RelationalDatabaseDataSourceConfig>,
+    service_role_arn: Option<String>,
+    type_: Option<DataSourceType>,
+}
+
+impl UpdateDataSourceRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateDataSourceRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateDataSourceRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dynamodb_config`](struct.UpdateDataSourceRequest.html#structfield.dynamodb_config) member.
+    pub fn dynamodb_config<T: Into<DynamodbDataSourceConfig>>(mut self, value: T) -> Self {
+        self.dynamodb_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`elasticsearch_config`](struct.UpdateDataSourceRequest.html#structfield.elasticsearch_config) member.
+    pub fn elasticsearch_config<T: Into<ElasticsearchDataSourceConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.elasticsearch_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`http_config`](struct.UpdateDataSourceRequest.html#structfield.http_config) member.
+    pub fn http_config<T: Into<HttpDataSourceConfig>>(mut self, value: T) -> Self {
+        self.http_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lambda_config`](struct.UpdateDataSourceRequest.html#structfield.lambda_config) member.
+    pub fn lambda_config<T: Into<LambdaDataSourceConfig>>(mut self, value: T) -> Self {
+        self.lambda_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateDataSourceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_config`](struct.UpdateDataSourceRequest.html#structfield.relational_database_config) member.
+    pub fn relational_database_config<T: Into<RelationalDatabaseDataSourceConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.relational_database_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role_arn`](struct.UpdateDataSourceRequest.html#structfield.service_role_arn) member.
+    pub fn service_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.UpdateDataSourceRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<DataSourceType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDataSourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDataSourceRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDataSourceRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            description: self.description,
+            dynamodb_config: self.dynamodb_config,
+            elasticsearch_config: self.elasticsearch_config,
+            http_config: self.http_config,
+            lambda_config: self.lambda_config,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            relational_database_config: self.relational_database_config,
+            service_role_arn: self.service_role_arn,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1233,6 +3737,102 @@ pub struct UpdateFunctionRequest {
     pub response_mapping_template: Option<String>,
 }
 
+impl UpdateFunctionRequest {
+    /// Returns a builder for `UpdateFunctionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFunctionRequestBuilder {
+        UpdateFunctionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFunctionRequest`](struct.UpdateFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFunctionRequestBuilder {
+    api_id: Option<String>,
+    data_source_name: Option<String>,
+    description: Option<String>,
+    function_id: Option<String>,
+    function_version: Option<String>,
+    name: Option<String>,
+    request_mapping_template: Option<String>,
+    response_mapping_template: Option<String>,
+}
+
+impl UpdateFunctionRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateFunctionRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_source_name`](struct.UpdateFunctionRequest.html#structfield.data_source_name) member.
+    pub fn data_source_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateFunctionRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_id`](struct.UpdateFunctionRequest.html#structfield.function_id) member.
+    pub fn function_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_version`](struct.UpdateFunctionRequest.html#structfield.function_version) member.
+    pub fn function_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateFunctionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_mapping_template`](struct.UpdateFunctionRequest.html#structfield.request_mapping_template) member.
+    pub fn request_mapping_template<T: Into<String>>(mut self, value: T) -> Self {
+        self.request_mapping_template = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_mapping_template`](struct.UpdateFunctionRequest.html#structfield.response_mapping_template) member.
+    pub fn response_mapping_template<T: Into<String>>(mut self, value: T) -> Self {
+        self.response_mapping_template = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFunctionRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            data_source_name: self
+                .data_source_name
+                .ok_or(::rusoto_core::BuildError::missing("data_source_name"))?,
+            description: self.description,
+            function_id: self
+                .function_id
+                .ok_or(::rusoto_core::BuildError::missing("function_id"))?,
+            function_version: self
+                .function_version
+                .ok_or(::rusoto_core::BuildError::missing("function_version"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            request_mapping_template: self.request_mapping_template.ok_or(
+                ::rusoto_core::BuildError::missing("request_mapping_template"),
+            )?,
+            response_mapping_template: self.response_mapping_template,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateFunctionResponse {
@@ -1254,7 +3854,7 @@ pub struct UpdateGraphqlApiRequest {
     /// <p>The new authentication type for the <code>GraphqlApi</code> object.</p>
     #[serde(rename = "authenticationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_type: Option<String>,
+    pub authentication_type: Option<AuthenticationType>,
     /// <p>The Amazon CloudWatch Logs configuration for the <code>GraphqlApi</code> object.</p>
     #[serde(rename = "logConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1272,6 +3872,91 @@ pub struct UpdateGraphqlApiRequest {
     pub user_pool_config: Option<UserPoolConfig>,
 }
 
+impl UpdateGraphqlApiRequest {
+    /// Returns a builder for `UpdateGraphqlApiRequest`, checking its required members when built.
+    pub fn builder() -> UpdateGraphqlApiRequestBuilder {
+        UpdateGraphqlApiRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGraphqlApiRequest`](struct.UpdateGraphqlApiRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateGraphqlApiRequestBuilder {
+    additional_authentication_providers: Option<Vec<AdditionalAuthenticationProvider>>,
+    api_id: Option<String>,
+    authentication_type: Option<AuthenticationType>,
+    log_config: Option<LogConfig>,
+    name: Option<String>,
+    open_id_connect_config: Option<OpenIDConnectConfig>,
+    user_pool_config: Option<UserPoolConfig>,
+}
+
+impl UpdateGraphqlApiRequestBuilder {
+    /// Sets the [`additional_authentication_providers`](struct.UpdateGraphqlApiRequest.html#structfield.additional_authentication_providers) member.
+    pub fn additional_authentication_providers<T: Into<Vec<AdditionalAuthenticationProvider>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.additional_authentication_providers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`api_id`](struct.UpdateGraphqlApiRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authentication_type`](struct.UpdateGraphqlApiRequest.html#structfield.authentication_type) member.
+    pub fn authentication_type<T: Into<AuthenticationType>>(mut self, value: T) -> Self {
+        self.authentication_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_config`](struct.UpdateGraphqlApiRequest.html#structfield.log_config) member.
+    pub fn log_config<T: Into<LogConfig>>(mut self, value: T) -> Self {
+        self.log_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateGraphqlApiRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`open_id_connect_config`](struct.UpdateGraphqlApiRequest.html#structfield.open_id_connect_config) member.
+    pub fn open_id_connect_config<T: Into<OpenIDConnectConfig>>(mut self, value: T) -> Self {
+        self.open_id_connect_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_config`](struct.UpdateGraphqlApiRequest.html#structfield.user_pool_config) member.
+    pub fn user_pool_config<T: Into<UserPoolConfig>>(mut self, value: T) -> Self {
+        self.user_pool_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGraphqlApiRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateGraphqlApiRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateGraphqlApiRequest {
+            additional_authentication_providers: self.additional_authentication_providers,
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            authentication_type: self.authentication_type,
+            log_config: self.log_config,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            open_id_connect_config: self.open_id_connect_config,
+            user_pool_config: self.user_pool_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateGraphqlApiResponse {
@@ -1296,7 +3981,7 @@ pub struct UpdateResolverRequest {
     /// <p><p>The resolver type.</p> <ul> <li> <p> <b>UNIT</b>: A UNIT resolver type. A UNIT resolver is the default resolver type. A UNIT resolver enables you to execute a GraphQL query against a single data source.</p> </li> <li> <p> <b>PIPELINE</b>: A PIPELINE resolver type. A PIPELINE resolver enables you to execute a series of <code>Function</code> in a serial manner. You can use a pipeline resolver to execute a GraphQL query against multiple data sources.</p> </li> </ul></p>
     #[serde(rename = "kind")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub kind: Option<String>,
+    pub kind: Option<ResolverKind>,
     /// <p>The <code>PipelineConfig</code>.</p>
     #[serde(rename = "pipelineConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1313,6 +3998,98 @@ pub struct UpdateResolverRequest {
     pub type_name: String,
 }
 
+impl UpdateResolverRequest {
+    /// Returns a builder for `UpdateResolverRequest`, checking its required members when built.
+    pub fn builder() -> UpdateResolverRequestBuilder {
+        UpdateResolverRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateResolverRequest`](struct.UpdateResolverRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateResolverRequestBuilder {
+    api_id: Option<String>,
+    data_source_name: Option<String>,
+    field_name: Option<String>,
+    kind: Option<ResolverKind>,
+    pipeline_config: Option<PipelineConfig>,
+    request_mapping_template: Option<String>,
+    response_mapping_template: Option<String>,
+    type_name: Option<String>,
+}
+
+impl UpdateResolverRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateResolverRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_source_name`](struct.UpdateResolverRequest.html#structfield.data_source_name) member.
+    pub fn data_source_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`field_name`](struct.UpdateResolverRequest.html#structfield.field_name) member.
+    pub fn field_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.field_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kind`](struct.UpdateResolverRequest.html#structfield.kind) member.
+    pub fn kind<T: Into<ResolverKind>>(mut self, value: T) -> Self {
+        self.kind = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_config`](struct.UpdateResolverRequest.html#structfield.pipeline_config) member.
+    pub fn pipeline_config<T: Into<PipelineConfig>>(mut self, value: T) -> Self {
+        self.pipeline_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_mapping_template`](struct.UpdateResolverRequest.html#structfield.request_mapping_template) member.
+    pub fn request_mapping_template<T: Into<String>>(mut self, value: T) -> Self {
+        self.request_mapping_template = Some(value.into());
+        self
+    }
+
+    /// Sets the [`response_mapping_template`](struct.UpdateResolverRequest.html#structfield.response_mapping_template) member.
+    pub fn response_mapping_template<T: Into<String>>(mut self, value: T) -> Self {
+        self.response_mapping_template = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_name`](struct.UpdateResolverRequest.html#structfield.type_name) member.
+    pub fn type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateResolverRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateResolverRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateResolverRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            data_source_name: self.data_source_name,
+            field_name: self
+                .field_name
+                .ok_or(::rusoto_core::BuildError::missing("field_name"))?,
+            kind: self.kind,
+            pipeline_config: self.pipeline_config,
+            request_mapping_template: self.request_mapping_template.ok_or(
+                ::rusoto_core::BuildError::missing("request_mapping_template"),
+            )?,
+            response_mapping_template: self.response_mapping_template,
+            type_name: self
+                .type_name
+                .ok_or(::rusoto_core::BuildError::missing("type_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateResolverResponse {
@@ -1322,7 +4099,7 @@ pub struct UpdateResolverResponse {
     pub resolver: Option<Resolver>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateTypeRequest {
     /// <p>The API ID.</p>
     #[serde(rename = "apiId")]
@@ -1333,12 +4110,70 @@ pub struct UpdateTypeRequest {
     pub definition: Option<String>,
     /// <p>The new type format: SDL or JSON.</p>
     #[serde(rename = "format")]
-    pub format: String,
+    pub format: TypeDefinitionFormat,
     /// <p>The new type name.</p>
     #[serde(rename = "typeName")]
     pub type_name: String,
 }
 
+impl UpdateTypeRequest {
+    /// Returns a builder for `UpdateTypeRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTypeRequestBuilder {
+        UpdateTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTypeRequest`](struct.UpdateTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTypeRequestBuilder {
+    api_id: Option<String>,
+    definition: Option<String>,
+    format: Option<TypeDefinitionFormat>,
+    type_name: Option<String>,
+}
+
+impl UpdateTypeRequestBuilder {
+    /// Sets the [`api_id`](struct.UpdateTypeRequest.html#structfield.api_id) member.
+    pub fn api_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`definition`](struct.UpdateTypeRequest.html#structfield.definition) member.
+    pub fn definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.definition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`format`](struct.UpdateTypeRequest.html#structfield.format) member.
+    pub fn format<T: Into<TypeDefinitionFormat>>(mut self, value: T) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_name`](struct.UpdateTypeRequest.html#structfield.type_name) member.
+    pub fn type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateTypeRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTypeRequest {
+            api_id: self
+                .api_id
+                .ok_or(::rusoto_core::BuildError::missing("api_id"))?,
+            definition: self.definition,
+            format: self
+                .format
+                .ok_or(::rusoto_core::BuildError::missing("format"))?,
+            type_name: self
+                .type_name
+                .ok_or(::rusoto_core::BuildError::missing("type_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateTypeResponse {
@@ -1349,7 +4184,7 @@ pub struct UpdateTypeResponse {
 }
 
 /// <p>Describes an Amazon Cognito user pool configuration.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserPoolConfig {
     /// <p>A regular expression for validating the incoming Amazon Cognito user pool app client ID.</p>
     #[serde(rename = "appIdClientRegex")]
@@ -1360,7 +4195,7 @@ pub struct UserPoolConfig {
     pub aws_region: String,
     /// <p>The action that you want your GraphQL API to take when a request that uses Amazon Cognito user pool authentication doesn't match the Amazon Cognito user pool configuration.</p>
     #[serde(rename = "defaultAction")]
-    pub default_action: String,
+    pub default_action: DefaultAction,
     /// <p>The user pool ID.</p>
     #[serde(rename = "userPoolId")]
     pub user_pool_id: String,
@@ -3611,6 +6446,228 @@ pub trait AppSync {
         input: UpdateTypeRequest,
     ) -> RusotoFuture<UpdateTypeResponse, UpdateTypeError>;
 }
+impl<T: ?Sized + AppSync> AppSync for ::std::sync::Arc<T> {
+    fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> RusotoFuture<CreateApiKeyResponse, CreateApiKeyError> {
+        (**self).create_api_key(input)
+    }
+    fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> RusotoFuture<CreateDataSourceResponse, CreateDataSourceError> {
+        (**self).create_data_source(input)
+    }
+    fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> RusotoFuture<CreateFunctionResponse, CreateFunctionError> {
+        (**self).create_function(input)
+    }
+    fn create_graphql_api(
+        &self,
+        input: CreateGraphqlApiRequest,
+    ) -> RusotoFuture<CreateGraphqlApiResponse, CreateGraphqlApiError> {
+        (**self).create_graphql_api(input)
+    }
+    fn create_resolver(
+        &self,
+        input: CreateResolverRequest,
+    ) -> RusotoFuture<CreateResolverResponse, CreateResolverError> {
+        (**self).create_resolver(input)
+    }
+    fn create_type(
+        &self,
+        input: CreateTypeRequest,
+    ) -> RusotoFuture<CreateTypeResponse, CreateTypeError> {
+        (**self).create_type(input)
+    }
+    fn delete_api_key(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> RusotoFuture<DeleteApiKeyResponse, DeleteApiKeyError> {
+        (**self).delete_api_key(input)
+    }
+    fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> RusotoFuture<DeleteDataSourceResponse, DeleteDataSourceError> {
+        (**self).delete_data_source(input)
+    }
+    fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> RusotoFuture<DeleteFunctionResponse, DeleteFunctionError> {
+        (**self).delete_function(input)
+    }
+    fn delete_graphql_api(
+        &self,
+        input: DeleteGraphqlApiRequest,
+    ) -> RusotoFuture<DeleteGraphqlApiResponse, DeleteGraphqlApiError> {
+        (**self).delete_graphql_api(input)
+    }
+    fn delete_resolver(
+        &self,
+        input: DeleteResolverRequest,
+    ) -> RusotoFuture<DeleteResolverResponse, DeleteResolverError> {
+        (**self).delete_resolver(input)
+    }
+    fn delete_type(
+        &self,
+        input: DeleteTypeRequest,
+    ) -> RusotoFuture<DeleteTypeResponse, DeleteTypeError> {
+        (**self).delete_type(input)
+    }
+    fn get_data_source(
+        &self,
+        input: GetDataSourceRequest,
+    ) -> RusotoFuture<GetDataSourceResponse, GetDataSourceError> {
+        (**self).get_data_source(input)
+    }
+    fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> RusotoFuture<GetFunctionResponse, GetFunctionError> {
+        (**self).get_function(input)
+    }
+    fn get_graphql_api(
+        &self,
+        input: GetGraphqlApiRequest,
+    ) -> RusotoFuture<GetGraphqlApiResponse, GetGraphqlApiError> {
+        (**self).get_graphql_api(input)
+    }
+    fn get_introspection_schema(
+        &self,
+        input: GetIntrospectionSchemaRequest,
+    ) -> RusotoFuture<GetIntrospectionSchemaResponse, GetIntrospectionSchemaError> {
+        (**self).get_introspection_schema(input)
+    }
+    fn get_resolver(
+        &self,
+        input: GetResolverRequest,
+    ) -> RusotoFuture<GetResolverResponse, GetResolverError> {
+        (**self).get_resolver(input)
+    }
+    fn get_schema_creation_status(
+        &self,
+        input: GetSchemaCreationStatusRequest,
+    ) -> RusotoFuture<GetSchemaCreationStatusResponse, GetSchemaCreationStatusError> {
+        (**self).get_schema_creation_status(input)
+    }
+    fn get_type(&self, input: GetTypeRequest) -> RusotoFuture<GetTypeResponse, GetTypeError> {
+        (**self).get_type(input)
+    }
+    fn list_api_keys(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> RusotoFuture<ListApiKeysResponse, ListApiKeysError> {
+        (**self).list_api_keys(input)
+    }
+    fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> RusotoFuture<ListDataSourcesResponse, ListDataSourcesError> {
+        (**self).list_data_sources(input)
+    }
+    fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> RusotoFuture<ListFunctionsResponse, ListFunctionsError> {
+        (**self).list_functions(input)
+    }
+    fn list_graphql_apis(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> RusotoFuture<ListGraphqlApisResponse, ListGraphqlApisError> {
+        (**self).list_graphql_apis(input)
+    }
+    fn list_resolvers(
+        &self,
+        input: ListResolversRequest,
+    ) -> RusotoFuture<ListResolversResponse, ListResolversError> {
+        (**self).list_resolvers(input)
+    }
+    fn list_resolvers_by_function(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> RusotoFuture<ListResolversByFunctionResponse, ListResolversByFunctionError> {
+        (**self).list_resolvers_by_function(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_types(
+        &self,
+        input: ListTypesRequest,
+    ) -> RusotoFuture<ListTypesResponse, ListTypesError> {
+        (**self).list_types(input)
+    }
+    fn start_schema_creation(
+        &self,
+        input: StartSchemaCreationRequest,
+    ) -> RusotoFuture<StartSchemaCreationResponse, StartSchemaCreationError> {
+        (**self).start_schema_creation(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> RusotoFuture<UpdateApiKeyResponse, UpdateApiKeyError> {
+        (**self).update_api_key(input)
+    }
+    fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> RusotoFuture<UpdateDataSourceResponse, UpdateDataSourceError> {
+        (**self).update_data_source(input)
+    }
+    fn update_function(
+        &self,
+        input: UpdateFunctionRequest,
+    ) -> RusotoFuture<UpdateFunctionResponse, UpdateFunctionError> {
+        (**self).update_function(input)
+    }
+    fn update_graphql_api(
+        &self,
+        input: UpdateGraphqlApiRequest,
+    ) -> RusotoFuture<UpdateGraphqlApiResponse, UpdateGraphqlApiError> {
+        (**self).update_graphql_api(input)
+    }
+    fn update_resolver(
+        &self,
+        input: UpdateResolverRequest,
+    ) -> RusotoFuture<UpdateResolverResponse, UpdateResolverError> {
+        (**self).update_resolver(input)
+    }
+    fn update_type(
+        &self,
+        input: UpdateTypeRequest,
+    ) -> RusotoFuture<UpdateTypeResponse, UpdateTypeError> {
+        (**self).update_type(input)
+    }
+}
+/// The error codes AWSAppSync retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWSAppSync API.
 #[derive(Clone)]
 pub struct AppSyncClient {
@@ -3626,6 +6683,27 @@ impl AppSyncClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_APPSYNC`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> AppSyncClient {
+        Self::new(region::Region::from_env_for_service("APPSYNC"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `appsync`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> AppSyncClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("appsync", "APPSYNC"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3644,7 +6722,49 @@ impl AppSyncClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AppSyncClient {
-        AppSyncClient { client, region }
+        AppSyncClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "appsync")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "appsync", "appsync", "APPSYNC")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `AppSync` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn AppSync + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for AppSyncClient {
+    fn service_name(&self) -> &'static str {
+        "AWSAppSync"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "appsync"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> AppSyncClient {
+        Self::new_with_client(client, region)
     }
 }
 
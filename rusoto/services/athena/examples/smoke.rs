@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Athena clients.
+//!
+//! Makes a single read-only `ListNamedQueries` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_athena::{Athena, AthenaClient, ListNamedQueriesInput};
+
+fn main() {
+    let client = AthenaClient::from_env();
+    match client
+        .list_named_queries(ListNamedQueriesInput::default())
+        .sync()
+    {
+        Ok(_) => println!("ListNamedQueries succeeded"),
+        Err(error) => {
+            eprintln!("ListNamedQueries failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
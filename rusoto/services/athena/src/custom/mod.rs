@@ -1 +1,2 @@
-
+/// Running queries and reading their results as typed rows
+pub mod query;
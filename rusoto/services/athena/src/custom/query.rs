@@ -0,0 +1,498 @@
+//! Running queries and reading their results as typed rows.
+//!
+//! Athena runs queries asynchronously: `StartQueryExecution` only queues the query, which then
+//! has to be polled with `GetQueryExecution` until it succeeds, and its results are read a page
+//! at a time with `GetQueryResults`, every value as a string. `AthenaQuery` does all three,
+//! converting each value according to the type of its column.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use futures::{Future, Stream};
+//! use rusoto_athena::query::{AthenaQuery, Value};
+//! use rusoto_athena::AthenaClient;
+//! use rusoto_core::Region;
+//!
+//! let client = AthenaClient::new(Region::UsEast1);
+//! let rows = AthenaQuery::new("SELECT status, count(*) AS requests FROM logs GROUP BY status")
+//!     .database("web")
+//!     .output_location("s3://my-athena-results/")
+//!     .rows(&client)
+//!     .collect()
+//!     .wait()
+//!     .unwrap();
+//! for row in rows {
+//!     if let Some(&Value::Integer(requests)) = row.get("requests") {
+//!         println!("{:?}: {}", row.get("status"), requests);
+//!     }
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::{stream, Future, Stream};
+use rusoto_core::waiter::{poll_until, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    Athena, ColumnInfo, Datum, GetQueryExecutionError, GetQueryExecutionInput,
+    GetQueryResultsError, GetQueryResultsInput, QueryExecution, QueryExecutionContext,
+    QueryExecutionState, ResultConfiguration, Row, StartQueryExecutionError,
+    StartQueryExecutionInput,
+};
+
+/// The most rows `GetQueryResults` returns per page.
+const MAX_PAGE_SIZE: i64 = 1000;
+
+/// A value of a query result, converted according to the type of its column.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// A `NULL`
+    Null,
+    /// A `boolean`
+    Boolean(bool),
+    /// A `tinyint`, `smallint`, `integer` or `bigint`
+    Integer(i64),
+    /// A `real`, `float` or `double`
+    Double(f64),
+    /// A `decimal`, kept as its exact decimal representation
+    Decimal(String),
+    /// A `varchar`, `char` or `string`
+    String(String),
+    /// A `date`, formatted as `YYYY-MM-DD`
+    Date(String),
+    /// A `timestamp`, formatted as `YYYY-MM-DD HH:MM:SS.fff`
+    Timestamp(String),
+    /// A value of any other type, e.g. an `array`, `map` or `row`, as Athena formats it
+    Other(String),
+}
+
+impl Value {
+    /// Converts the value of a column of type `type_` from its text representation.
+    fn parse(type_: &str, datum: &Datum) -> Result<Value, String> {
+        let text = match datum.var_char_value {
+            Some(ref text) => text,
+            None => return Ok(Value::Null),
+        };
+        let invalid = || format!("invalid {} value: {:?}", type_, text);
+        let value = match type_.to_ascii_lowercase().as_str() {
+            "boolean" => Value::Boolean(text.parse().map_err(|_| invalid())?),
+            "tinyint" | "smallint" | "integer" | "int" | "bigint" => {
+                Value::Integer(text.parse().map_err(|_| invalid())?)
+            }
+            "real" | "float" | "double" => Value::Double(text.parse().map_err(|_| invalid())?),
+            "decimal" => Value::Decimal(text.clone()),
+            "varchar" | "char" | "string" => Value::String(text.clone()),
+            "date" => Value::Date(text.clone()),
+            "timestamp" => Value::Timestamp(text.clone()),
+            _ => Value::Other(text.clone()),
+        };
+        Ok(value)
+    }
+
+    /// Whether the value is `NULL`.
+    pub fn is_null(&self) -> bool {
+        *self == Value::Null
+    }
+
+    /// The text of a string, decimal, date, timestamp or other value.
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            Value::Decimal(ref text)
+            | Value::String(ref text)
+            | Value::Date(ref text)
+            | Value::Timestamp(ref text)
+            | Value::Other(ref text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+/// A row of a query result.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueryRow {
+    columns: Arc<Vec<ColumnInfo>>,
+    values: Vec<Value>,
+}
+
+impl QueryRow {
+    /// The columns of the result.
+    pub fn columns(&self) -> &[ColumnInfo] {
+        &self.columns
+    }
+
+    /// The values of the row, in the order of the columns.
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+
+    /// The value of the column named `name`.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.columns
+            .iter()
+            .position(|column| column.name == name)
+            .and_then(|index| self.values.get(index))
+    }
+
+    /// The values of the row, in the order of the columns.
+    pub fn into_values(self) -> Vec<Value> {
+        self.values
+    }
+}
+
+/// Errors returned by `AthenaQuery`.
+#[derive(Debug, PartialEq)]
+pub enum AthenaQueryError {
+    /// Starting the query failed.
+    StartQueryExecution(StartQueryExecutionError),
+    /// Polling the query failed.
+    GetQueryExecution(GetQueryExecutionError),
+    /// Reading the results failed.
+    GetQueryResults(GetQueryResultsError),
+    /// The query failed or was cancelled.
+    QueryFailed {
+        /// The ID of the query execution
+        query_execution_id: String,
+        /// `Failed` or `Cancelled`
+        state: QueryExecutionState,
+        /// Why the query failed, e.g. a syntax error
+        reason: Option<String>,
+    },
+    /// The query was still running when the waiter gave up.
+    Timeout(String),
+}
+
+impl fmt::Display for AthenaQueryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AthenaQueryError::QueryFailed {
+                ref query_execution_id,
+                ref state,
+                ref reason,
+            } => write!(
+                f,
+                "Query {} {}: {}",
+                query_execution_id,
+                state,
+                reason.as_ref().map_or("no reason given", String::as_str)
+            ),
+            AthenaQueryError::Timeout(ref query_execution_id) => {
+                write!(f, "{}: {}", self.description(), query_execution_id)
+            }
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for AthenaQueryError {
+    fn description(&self) -> &str {
+        match *self {
+            AthenaQueryError::StartQueryExecution(ref cause) => cause.description(),
+            AthenaQueryError::GetQueryExecution(ref cause) => cause.description(),
+            AthenaQueryError::GetQueryResults(ref cause) => cause.description(),
+            AthenaQueryError::QueryFailed { .. } => "Query failed",
+            AthenaQueryError::Timeout(_) => "Query still running",
+        }
+    }
+}
+
+/// A stream of the rows of a query result, as returned by `AthenaQuery::rows`.
+pub type QueryRowStream =
+    Box<dyn Stream<Item = QueryRow, Error = RusotoError<AthenaQueryError>> + Send>;
+
+/// A query to run with Athena.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AthenaQuery {
+    input: StartQueryExecutionInput,
+    waiter: WaiterConfig,
+}
+
+impl AthenaQuery {
+    /// Creates a query running `query_string`, polled every second for up to 30 minutes.
+    pub fn new(query_string: &str) -> AthenaQuery {
+        AthenaQuery {
+            input: StartQueryExecutionInput {
+                query_string: query_string.to_owned(),
+                ..Default::default()
+            },
+            waiter: WaiterConfig::new(Duration::from_secs(1), 1800),
+        }
+    }
+
+    /// Sets the database the query runs in.
+    pub fn database(mut self, database: &str) -> AthenaQuery {
+        self.input.query_execution_context = Some(QueryExecutionContext {
+            database: Some(database.to_owned()),
+        });
+        self
+    }
+
+    /// Sets the S3 location the results are written to, e.g. `s3://my-athena-results/`.
+    /// Required unless the work group has one.
+    pub fn output_location(mut self, output_location: &str) -> AthenaQuery {
+        self.input.result_configuration = Some(ResultConfiguration {
+            output_location: Some(output_location.to_owned()),
+            ..self.input.result_configuration.unwrap_or_default()
+        });
+        self
+    }
+
+    /// Sets the work group the query runs in.
+    pub fn work_group(mut self, work_group: &str) -> AthenaQuery {
+        self.input.work_group = Some(work_group.to_owned());
+        self
+    }
+
+    /// Sets how often, and for how long, the query is polled.
+    pub fn waiter(mut self, waiter: WaiterConfig) -> AthenaQuery {
+        self.waiter = waiter;
+        self
+    }
+
+    /// Starts the query, returning the ID of its execution.
+    pub fn start<C>(&self, client: &C) -> RusotoFuture<String, AthenaQueryError>
+    where
+        C: Athena + Clone + Send + 'static,
+    {
+        let future = client
+            .start_query_execution(self.input.clone())
+            .map_err(|e| e.map_service(AthenaQueryError::StartQueryExecution))
+            .and_then(|output| {
+                output
+                    .query_execution_id
+                    .ok_or_else(|| RusotoError::ParseError("Missing QueryExecutionId".to_owned()))
+            });
+        RusotoFuture::from_future(future)
+    }
+
+    /// Starts the query and waits for it to succeed, returning its execution.
+    pub fn run<C>(&self, client: &C) -> RusotoFuture<QueryExecution, AthenaQueryError>
+    where
+        C: Athena + Clone + Send + 'static,
+    {
+        let client = client.clone();
+        let waiter = self.waiter.clone();
+        let future = self
+            .start(&client)
+            .and_then(move |id| wait_for_query(client, id, waiter));
+        RusotoFuture::from_future(future)
+    }
+
+    /// Starts the query, waits for it to succeed and streams the rows of its result.
+    pub fn rows<C>(&self, client: &C) -> QueryRowStream
+    where
+        C: Athena + Clone + Send + 'static,
+    {
+        let client = client.clone();
+        Box::new(
+            self.run(&client)
+                .map(move |execution| {
+                    query_results(&client, execution.query_execution_id.unwrap_or_default())
+                })
+                .flatten_stream(),
+        )
+    }
+}
+
+/// Polls the query execution `query_execution_id` until it succeeds.
+pub fn wait_for_query<C>(
+    client: C,
+    query_execution_id: String,
+    waiter: WaiterConfig,
+) -> RusotoFuture<QueryExecution, AthenaQueryError>
+where
+    C: Athena + Clone + Send + 'static,
+{
+    let timeout = RusotoError::Service(AthenaQueryError::Timeout(query_execution_id.clone()));
+    let future = poll_until(
+        waiter,
+        move || {
+            let query_execution_id = query_execution_id.clone();
+            client
+                .get_query_execution(GetQueryExecutionInput {
+                    query_execution_id: query_execution_id.clone(),
+                })
+                .map_err(|e| e.map_service(AthenaQueryError::GetQueryExecution))
+                .and_then(move |output| {
+                    let execution = output.query_execution.unwrap_or_default();
+                    let status = execution.status.clone().unwrap_or_default();
+                    match status.state {
+                        Some(QueryExecutionState::Succeeded) => Ok(Some(execution)),
+                        Some(state @ QueryExecutionState::Failed)
+                        | Some(state @ QueryExecutionState::Cancelled) => {
+                            Err(RusotoError::Service(AthenaQueryError::QueryFailed {
+                                query_execution_id,
+                                state,
+                                reason: status.state_change_reason,
+                            }))
+                        }
+                        _ => Ok(None),
+                    }
+                })
+        },
+        timeout,
+    );
+    RusotoFuture::from_future(future)
+}
+
+/// Streams the rows of the result of the query execution `query_execution_id`, which must have
+/// succeeded, fetching further pages as the stream is consumed.
+pub fn query_results<C>(client: &C, query_execution_id: String) -> QueryRowStream
+where
+    C: Athena + Clone + Send + 'static,
+{
+    let client = client.clone();
+    let pages = stream::unfold(Some(None), move |next_token: Option<Option<String>>| {
+        let next_token = next_token?;
+        let first_page = next_token.is_none();
+        let request = GetQueryResultsInput {
+            query_execution_id: query_execution_id.clone(),
+            max_results: Some(MAX_PAGE_SIZE),
+            next_token,
+        };
+        Some(
+            client
+                .get_query_results(request)
+                .map_err(|e| e.map_service(AthenaQueryError::GetQueryResults))
+                .and_then(move |output| {
+                    let result_set = output.result_set.unwrap_or_default();
+                    let columns = result_set
+                        .result_set_metadata
+                        .and_then(|metadata| metadata.column_info)
+                        .unwrap_or_default();
+                    let rows = typed_rows(columns, result_set.rows.unwrap_or_default(), first_page)
+                        .map_err(RusotoError::ParseError)?;
+                    Ok((rows, output.next_token.map(Some)))
+                }),
+        )
+    });
+    Box::new(pages.map(stream::iter_ok).flatten())
+}
+
+/// Converts the rows of a page. The first row of the first page of the result of a `SELECT`
+/// holds the names of the columns, and is skipped.
+fn typed_rows(
+    columns: Vec<ColumnInfo>,
+    rows: Vec<Row>,
+    first_page: bool,
+) -> Result<Vec<QueryRow>, String> {
+    let columns = Arc::new(columns);
+    let mut rows = rows.into_iter().peekable();
+    if first_page && rows.peek().map_or(false, |row| is_header(&columns, row)) {
+        rows.next();
+    }
+    rows.map(|row| {
+        let data = row.data.unwrap_or_default();
+        let values = columns
+            .iter()
+            .zip(data.iter().chain(std::iter::repeat(&Datum::default())))
+            .map(|(column, datum)| Value::parse(&column.type_, datum))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(QueryRow {
+            columns: columns.clone(),
+            values,
+        })
+    })
+    .collect()
+}
+
+fn is_header(columns: &[ColumnInfo], row: &Row) -> bool {
+    let data = row.data.as_ref().map_or(&[][..], Vec::as_slice);
+    !columns.is_empty()
+        && data.len() == columns.len()
+        && columns
+            .iter()
+            .zip(data)
+            .all(|(column, datum)| datum.var_char_value.as_ref() == Some(&column.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str, type_: &str) -> ColumnInfo {
+        ColumnInfo {
+            name: name.to_owned(),
+            type_: type_.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn row(values: &[Option<&str>]) -> Row {
+        Row {
+            data: Some(
+                values
+                    .iter()
+                    .map(|value| Datum {
+                        var_char_value: value.map(str::to_owned),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn converts_values_by_column_type() {
+        let columns = vec![
+            column("status", "varchar"),
+            column("requests", "bigint"),
+            column("ratio", "double"),
+            column("ok", "boolean"),
+            column("day", "date"),
+            column("tags", "array"),
+        ];
+        let rows = vec![
+            row(&[
+                Some("status"),
+                Some("requests"),
+                Some("ratio"),
+                Some("ok"),
+                Some("day"),
+                Some("tags"),
+            ]),
+            row(&[
+                Some("200"),
+                Some("1024"),
+                Some("0.5"),
+                Some("true"),
+                Some("2019-10-01"),
+                Some("[a, b]"),
+            ]),
+            row(&[Some("500"), None]),
+        ];
+
+        let rows = typed_rows(columns, rows, true).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0].values(),
+            &[
+                Value::String("200".to_owned()),
+                Value::Integer(1024),
+                Value::Double(0.5),
+                Value::Boolean(true),
+                Value::Date("2019-10-01".to_owned()),
+                Value::Other("[a, b]".to_owned()),
+            ][..]
+        );
+        assert_eq!(rows[1].get("requests"), Some(&Value::Null));
+        assert!(rows[1].get("ok").unwrap().is_null());
+        assert_eq!(rows[1].get("missing"), None);
+    }
+
+    #[test]
+    fn keeps_first_rows_of_later_pages() {
+        let columns = vec![column("name", "varchar")];
+        let rows = typed_rows(columns, vec![row(&[Some("name")])], false).unwrap();
+        assert_eq!(rows[0].get("name"), Some(&Value::String("name".to_owned())));
+    }
+
+    #[test]
+    fn reports_malformed_values() {
+        let columns = vec![column("count", "integer")];
+        let err = typed_rows(columns, vec![row(&[Some("many")])], false).unwrap_err();
+        assert_eq!(err, "invalid integer value: \"many\"");
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct BatchGetNamedQueryInput {
@@ -30,6 +31,38 @@ pub struct BatchGetNamedQueryInput {
     pub named_query_ids: Vec<String>,
 }
 
+impl BatchGetNamedQueryInput {
+    /// Returns a builder for `BatchGetNamedQueryInput`, checking its required members when built.
+    pub fn builder() -> BatchGetNamedQueryInputBuilder {
+        BatchGetNamedQueryInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetNamedQueryInput`](struct.BatchGetNamedQueryInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetNamedQueryInputBuilder {
+    named_query_ids: Option<Vec<String>>,
+}
+
+impl BatchGetNamedQueryInputBuilder {
+    /// Sets the [`named_query_ids`](struct.BatchGetNamedQueryInput.html#structfield.named_query_ids) member.
+    pub fn named_query_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.named_query_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetNamedQueryInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetNamedQueryInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetNamedQueryInput {
+            named_query_ids: self
+                .named_query_ids
+                .ok_or(::rusoto_core::BuildError::missing("named_query_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetNamedQueryOutput {
@@ -50,6 +83,38 @@ pub struct BatchGetQueryExecutionInput {
     pub query_execution_ids: Vec<String>,
 }
 
+impl BatchGetQueryExecutionInput {
+    /// Returns a builder for `BatchGetQueryExecutionInput`, checking its required members when built.
+    pub fn builder() -> BatchGetQueryExecutionInputBuilder {
+        BatchGetQueryExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetQueryExecutionInput`](struct.BatchGetQueryExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetQueryExecutionInputBuilder {
+    query_execution_ids: Option<Vec<String>>,
+}
+
+impl BatchGetQueryExecutionInputBuilder {
+    /// Sets the [`query_execution_ids`](struct.BatchGetQueryExecutionInput.html#structfield.query_execution_ids) member.
+    pub fn query_execution_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.query_execution_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetQueryExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetQueryExecutionInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetQueryExecutionInput {
+            query_execution_ids: self
+                .query_execution_ids
+                .ok_or(::rusoto_core::BuildError::missing("query_execution_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetQueryExecutionOutput {
@@ -85,7 +150,7 @@ pub struct ColumnInfo {
     /// <p>Indicates the column's nullable status.</p>
     #[serde(rename = "Nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub nullable: Option<String>,
+    pub nullable: Option<ColumnNullable>,
     /// <p>For <code>DECIMAL</code> data types, specifies the total number of digits, up to 38. For performance reasons, we recommend up to 18 digits.</p>
     #[serde(rename = "Precision")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -107,6 +172,82 @@ pub struct ColumnInfo {
     pub type_: String,
 }
 
+/// <p>Indicates the column's nullable status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ColumnNullable {
+    /// <p><code>NOT_NULL</code></p>
+    NotNull,
+    /// <p><code>NULLABLE</code></p>
+    Nullable,
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ColumnNullable {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ColumnNullable::NotNull => "NOT_NULL",
+            ColumnNullable::Nullable => "NULLABLE",
+            ColumnNullable::UnknownValue => "UNKNOWN",
+            ColumnNullable::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ColumnNullable {
+    fn from(value: &'a str) -> ColumnNullable {
+        match value {
+            "NOT_NULL" => ColumnNullable::NotNull,
+            "NULLABLE" => ColumnNullable::Nullable,
+            "UNKNOWN" => ColumnNullable::UnknownValue,
+            _ => ColumnNullable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ColumnNullable {
+    fn from(value: String) -> ColumnNullable {
+        ColumnNullable::from(value.as_str())
+    }
+}
+
+impl From<ColumnNullable> for String {
+    fn from(value: ColumnNullable) -> String {
+        match value {
+            ColumnNullable::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ColumnNullable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ColumnNullable {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ColumnNullable {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ColumnNullable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ColumnNullable::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateNamedQueryInput {
     /// <p><p>A unique case-sensitive string used to ensure the request to create the query is idempotent (executes only once). If another <code>CreateNamedQuery</code> request is received, the same response is returned and another query is not created. If a parameter has changed, for example, the <code>QueryString</code>, an error is returned.</p> <important> <p>This token is listed as not required because AWS SDKs (for example the AWS SDK for Java) auto-generate the token for users. If you are not using the AWS SDK or the AWS CLI, you must provide this token or the action will fail.</p> </important></p>
@@ -132,6 +273,80 @@ pub struct CreateNamedQueryInput {
     pub work_group: Option<String>,
 }
 
+impl CreateNamedQueryInput {
+    /// Returns a builder for `CreateNamedQueryInput`, checking its required members when built.
+    pub fn builder() -> CreateNamedQueryInputBuilder {
+        CreateNamedQueryInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateNamedQueryInput`](struct.CreateNamedQueryInput.html).
+#[derive(Default, Debug)]
+pub struct CreateNamedQueryInputBuilder {
+    client_request_token: Option<String>,
+    database: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+    query_string: Option<String>,
+    work_group: Option<String>,
+}
+
+impl CreateNamedQueryInputBuilder {
+    /// Sets the [`client_request_token`](struct.CreateNamedQueryInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database`](struct.CreateNamedQueryInput.html#structfield.database) member.
+    pub fn database<T: Into<String>>(mut self, value: T) -> Self {
+        self.database = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateNamedQueryInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateNamedQueryInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_string`](struct.CreateNamedQueryInput.html#structfield.query_string) member.
+    pub fn query_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`work_group`](struct.CreateNamedQueryInput.html#structfield.work_group) member.
+    pub fn work_group<T: Into<String>>(mut self, value: T) -> Self {
+        self.work_group = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateNamedQueryInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateNamedQueryInput, ::rusoto_core::BuildError> {
+        Ok(CreateNamedQueryInput {
+            client_request_token: self.client_request_token,
+            database: self
+                .database
+                .ok_or(::rusoto_core::BuildError::missing("database"))?,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            query_string: self
+                .query_string
+                .ok_or(::rusoto_core::BuildError::missing("query_string"))?,
+            work_group: self.work_group,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateNamedQueryOutput {
@@ -160,6 +375,60 @@ pub struct CreateWorkGroupInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateWorkGroupInput {
+    /// Returns a builder for `CreateWorkGroupInput`, checking its required members when built.
+    pub fn builder() -> CreateWorkGroupInputBuilder {
+        CreateWorkGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateWorkGroupInput`](struct.CreateWorkGroupInput.html).
+#[derive(Default, Debug)]
+pub struct CreateWorkGroupInputBuilder {
+    configuration: Option<WorkGroupConfiguration>,
+    description: Option<String>,
+    name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateWorkGroupInputBuilder {
+    /// Sets the [`configuration`](struct.CreateWorkGroupInput.html#structfield.configuration) member.
+    pub fn configuration<T: Into<WorkGroupConfiguration>>(mut self, value: T) -> Self {
+        self.configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateWorkGroupInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateWorkGroupInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateWorkGroupInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateWorkGroupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateWorkGroupInput, ::rusoto_core::BuildError> {
+        Ok(CreateWorkGroupInput {
+            configuration: self.configuration,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateWorkGroupOutput {}
@@ -181,6 +450,36 @@ pub struct DeleteNamedQueryInput {
     pub named_query_id: String,
 }
 
+impl DeleteNamedQueryInput {
+    /// Returns a builder for `DeleteNamedQueryInput`, checking its required members when built.
+    pub fn builder() -> DeleteNamedQueryInputBuilder {
+        DeleteNamedQueryInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteNamedQueryInput`](struct.DeleteNamedQueryInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteNamedQueryInputBuilder {
+    named_query_id: Option<String>,
+}
+
+impl DeleteNamedQueryInputBuilder {
+    /// Sets the [`named_query_id`](struct.DeleteNamedQueryInput.html#structfield.named_query_id) member.
+    pub fn named_query_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.named_query_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteNamedQueryInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteNamedQueryInput, ::rusoto_core::BuildError> {
+        Ok(DeleteNamedQueryInput {
+            named_query_id: self
+                .named_query_id
+                .ok_or(::rusoto_core::BuildError::missing("named_query_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteNamedQueryOutput {}
@@ -196,22 +495,136 @@ pub struct DeleteWorkGroupInput {
     pub work_group: String,
 }
 
+impl DeleteWorkGroupInput {
+    /// Returns a builder for `DeleteWorkGroupInput`, checking its required members when built.
+    pub fn builder() -> DeleteWorkGroupInputBuilder {
+        DeleteWorkGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteWorkGroupInput`](struct.DeleteWorkGroupInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteWorkGroupInputBuilder {
+    recursive_delete_option: Option<bool>,
+    work_group: Option<String>,
+}
+
+impl DeleteWorkGroupInputBuilder {
+    /// Sets the [`recursive_delete_option`](struct.DeleteWorkGroupInput.html#structfield.recursive_delete_option) member.
+    pub fn recursive_delete_option<T: Into<bool>>(mut self, value: T) -> Self {
+        self.recursive_delete_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`work_group`](struct.DeleteWorkGroupInput.html#structfield.work_group) member.
+    pub fn work_group<T: Into<String>>(mut self, value: T) -> Self {
+        self.work_group = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteWorkGroupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteWorkGroupInput, ::rusoto_core::BuildError> {
+        Ok(DeleteWorkGroupInput {
+            recursive_delete_option: self.recursive_delete_option,
+            work_group: self
+                .work_group
+                .ok_or(::rusoto_core::BuildError::missing("work_group"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteWorkGroupOutput {}
 
 /// <p>If query results are encrypted in Amazon S3, indicates the encryption option used (for example, <code>SSE-KMS</code> or <code>CSE-KMS</code>) and key information.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptionConfiguration {
     /// <p>Indicates whether Amazon S3 server-side encryption with Amazon S3-managed keys (<code>SSE-S3</code>), server-side encryption with KMS-managed keys (<code>SSE-KMS</code>), or client-side encryption with KMS-managed keys (CSE-KMS) is used.</p> <p>If a query runs in a workgroup and the workgroup overrides client-side settings, then the workgroup's setting for encryption is used. It specifies whether query results must be encrypted, for all queries that run in this workgroup. </p>
     #[serde(rename = "EncryptionOption")]
-    pub encryption_option: String,
+    pub encryption_option: EncryptionOption,
     /// <p>For <code>SSE-KMS</code> and <code>CSE-KMS</code>, this is the KMS key ARN or ID.</p>
     #[serde(rename = "KmsKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key: Option<String>,
 }
 
+/// <p>Indicates whether Amazon S3 server-side encryption with Amazon S3-managed keys (<code>SSE-S3</code>), server-side encryption with KMS-managed keys (<code>SSE-KMS</code>), or client-side encryption with KMS-managed keys (CSE-KMS) is used.</p> <p>If a query runs in a workgroup and the workgroup overrides client-side settings, then the workgroup's setting for encryption is used. It specifies whether query results must be encrypted, for all queries that run in this workgroup. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EncryptionOption {
+    /// <p><code>SSE_S3</code></p>
+    SseS3,
+    /// <p><code>SSE_KMS</code></p>
+    SseKms,
+    /// <p><code>CSE_KMS</code></p>
+    CseKms,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EncryptionOption {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionOption::SseS3 => "SSE_S3",
+            EncryptionOption::SseKms => "SSE_KMS",
+            EncryptionOption::CseKms => "CSE_KMS",
+            EncryptionOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionOption {
+    fn from(value: &'a str) -> EncryptionOption {
+        match value {
+            "SSE_S3" => EncryptionOption::SseS3,
+            "SSE_KMS" => EncryptionOption::SseKms,
+            "CSE_KMS" => EncryptionOption::CseKms,
+            _ => EncryptionOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionOption {
+    fn from(value: String) -> EncryptionOption {
+        EncryptionOption::from(value.as_str())
+    }
+}
+
+impl From<EncryptionOption> for String {
+    fn from(value: EncryptionOption) -> String {
+        match value {
+            EncryptionOption::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncryptionOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EncryptionOption {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionOption {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EncryptionOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EncryptionOption::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetNamedQueryInput {
     /// <p>The unique ID of the query. Use <a>ListNamedQueries</a> to get query IDs.</p>
@@ -219,6 +632,36 @@ pub struct GetNamedQueryInput {
     pub named_query_id: String,
 }
 
+impl GetNamedQueryInput {
+    /// Returns a builder for `GetNamedQueryInput`, checking its required members when built.
+    pub fn builder() -> GetNamedQueryInputBuilder {
+        GetNamedQueryInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetNamedQueryInput`](struct.GetNamedQueryInput.html).
+#[derive(Default, Debug)]
+pub struct GetNamedQueryInputBuilder {
+    named_query_id: Option<String>,
+}
+
+impl GetNamedQueryInputBuilder {
+    /// Sets the [`named_query_id`](struct.GetNamedQueryInput.html#structfield.named_query_id) member.
+    pub fn named_query_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.named_query_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetNamedQueryInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetNamedQueryInput, ::rusoto_core::BuildError> {
+        Ok(GetNamedQueryInput {
+            named_query_id: self
+                .named_query_id
+                .ok_or(::rusoto_core::BuildError::missing("named_query_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetNamedQueryOutput {
@@ -235,6 +678,36 @@ pub struct GetQueryExecutionInput {
     pub query_execution_id: String,
 }
 
+impl GetQueryExecutionInput {
+    /// Returns a builder for `GetQueryExecutionInput`, checking its required members when built.
+    pub fn builder() -> GetQueryExecutionInputBuilder {
+        GetQueryExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetQueryExecutionInput`](struct.GetQueryExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct GetQueryExecutionInputBuilder {
+    query_execution_id: Option<String>,
+}
+
+impl GetQueryExecutionInputBuilder {
+    /// Sets the [`query_execution_id`](struct.GetQueryExecutionInput.html#structfield.query_execution_id) member.
+    pub fn query_execution_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_execution_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetQueryExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetQueryExecutionInput, ::rusoto_core::BuildError> {
+        Ok(GetQueryExecutionInput {
+            query_execution_id: self
+                .query_execution_id
+                .ok_or(::rusoto_core::BuildError::missing("query_execution_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetQueryExecutionOutput {
@@ -259,6 +732,52 @@ pub struct GetQueryResultsInput {
     pub query_execution_id: String,
 }
 
+impl GetQueryResultsInput {
+    /// Returns a builder for `GetQueryResultsInput`, checking its required members when built.
+    pub fn builder() -> GetQueryResultsInputBuilder {
+        GetQueryResultsInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetQueryResultsInput`](struct.GetQueryResultsInput.html).
+#[derive(Default, Debug)]
+pub struct GetQueryResultsInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    query_execution_id: Option<String>,
+}
+
+impl GetQueryResultsInputBuilder {
+    /// Sets the [`max_results`](struct.GetQueryResultsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetQueryResultsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_execution_id`](struct.GetQueryResultsInput.html#structfield.query_execution_id) member.
+    pub fn query_execution_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_execution_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetQueryResultsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetQueryResultsInput, ::rusoto_core::BuildError> {
+        Ok(GetQueryResultsInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            query_execution_id: self
+                .query_execution_id
+                .ok_or(::rusoto_core::BuildError::missing("query_execution_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetQueryResultsOutput {
@@ -283,6 +802,36 @@ pub struct GetWorkGroupInput {
     pub work_group: String,
 }
 
+impl GetWorkGroupInput {
+    /// Returns a builder for `GetWorkGroupInput`, checking its required members when built.
+    pub fn builder() -> GetWorkGroupInputBuilder {
+        GetWorkGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetWorkGroupInput`](struct.GetWorkGroupInput.html).
+#[derive(Default, Debug)]
+pub struct GetWorkGroupInputBuilder {
+    work_group: Option<String>,
+}
+
+impl GetWorkGroupInputBuilder {
+    /// Sets the [`work_group`](struct.GetWorkGroupInput.html#structfield.work_group) member.
+    pub fn work_group<T: Into<String>>(mut self, value: T) -> Self {
+        self.work_group = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetWorkGroupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetWorkGroupInput, ::rusoto_core::BuildError> {
+        Ok(GetWorkGroupInput {
+            work_group: self
+                .work_group
+                .ok_or(::rusoto_core::BuildError::missing("work_group"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetWorkGroupOutput {
@@ -308,6 +857,50 @@ pub struct ListNamedQueriesInput {
     pub work_group: Option<String>,
 }
 
+impl ListNamedQueriesInput {
+    /// Returns a builder for `ListNamedQueriesInput`, checking its required members when built.
+    pub fn builder() -> ListNamedQueriesInputBuilder {
+        ListNamedQueriesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListNamedQueriesInput`](struct.ListNamedQueriesInput.html).
+#[derive(Default, Debug)]
+pub struct ListNamedQueriesInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    work_group: Option<String>,
+}
+
+impl ListNamedQueriesInputBuilder {
+    /// Sets the [`max_results`](struct.ListNamedQueriesInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListNamedQueriesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`work_group`](struct.ListNamedQueriesInput.html#structfield.work_group) member.
+    pub fn work_group<T: Into<String>>(mut self, value: T) -> Self {
+        self.work_group = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListNamedQueriesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListNamedQueriesInput, ::rusoto_core::BuildError> {
+        Ok(ListNamedQueriesInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            work_group: self.work_group,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListNamedQueriesOutput {
@@ -337,6 +930,52 @@ pub struct ListQueryExecutionsInput {
     pub work_group: Option<String>,
 }
 
+impl ListQueryExecutionsInput {
+    /// Returns a builder for `ListQueryExecutionsInput`, checking its required members when built.
+    pub fn builder() -> ListQueryExecutionsInputBuilder {
+        ListQueryExecutionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListQueryExecutionsInput`](struct.ListQueryExecutionsInput.html).
+#[derive(Default, Debug)]
+pub struct ListQueryExecutionsInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    work_group: Option<String>,
+}
+
+impl ListQueryExecutionsInputBuilder {
+    /// Sets the [`max_results`](struct.ListQueryExecutionsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListQueryExecutionsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`work_group`](struct.ListQueryExecutionsInput.html#structfield.work_group) member.
+    pub fn work_group<T: Into<String>>(mut self, value: T) -> Self {
+        self.work_group = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListQueryExecutionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListQueryExecutionsInput, ::rusoto_core::BuildError> {
+        Ok(ListQueryExecutionsInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            work_group: self.work_group,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListQueryExecutionsOutput {
@@ -365,6 +1004,54 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceInput {
+    /// Returns a builder for `ListTagsForResourceInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceInputBuilder {
+        ListTagsForResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceInput`](struct.ListTagsForResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceInputBuilder {
+    /// Sets the [`max_results`](struct.ListTagsForResourceInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsForResourceInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceOutput {
@@ -390,6 +1077,42 @@ pub struct ListWorkGroupsInput {
     pub next_token: Option<String>,
 }
 
+impl ListWorkGroupsInput {
+    /// Returns a builder for `ListWorkGroupsInput`, checking its required members when built.
+    pub fn builder() -> ListWorkGroupsInputBuilder {
+        ListWorkGroupsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListWorkGroupsInput`](struct.ListWorkGroupsInput.html).
+#[derive(Default, Debug)]
+pub struct ListWorkGroupsInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListWorkGroupsInputBuilder {
+    /// Sets the [`max_results`](struct.ListWorkGroupsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListWorkGroupsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListWorkGroupsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListWorkGroupsInput, ::rusoto_core::BuildError> {
+        Ok(ListWorkGroupsInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListWorkGroupsOutput {
@@ -453,7 +1176,7 @@ pub struct QueryExecution {
     /// <p>The type of query statement that was run. <code>DDL</code> indicates DDL query statements. <code>DML</code> indicates DML (Data Manipulation Language) query statements, such as <code>CREATE TABLE AS SELECT</code>. <code>UTILITY</code> indicates query statements other than DDL and DML, such as <code>SHOW CREATE TABLE</code>, or <code>DESCRIBE &lt;table&gt;</code>.</p>
     #[serde(rename = "StatementType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub statement_type: Option<String>,
+    pub statement_type: Option<StatementType>,
     /// <p>The location of a manifest file that tracks file locations generated by the query, the amount of data scanned by the query, and the amount of time that it took the query to run.</p>
     #[serde(rename = "Statistics")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -477,6 +1200,90 @@ pub struct QueryExecutionContext {
     pub database: Option<String>,
 }
 
+/// <p>The state of query execution. <code>QUEUED</code> state is listed but is not used by Athena and is reserved for future use. <code>RUNNING</code> indicates that the query has been submitted to the service, and Athena will execute the query as soon as resources are available. <code>SUCCEEDED</code> indicates that the query completed without errors. <code>FAILED</code> indicates that the query experienced an error and did not complete processing. <code>CANCELLED</code> indicates that a user input interrupted query execution. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QueryExecutionState {
+    /// <p><code>QUEUED</code></p>
+    Queued,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>CANCELLED</code></p>
+    Cancelled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl QueryExecutionState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueryExecutionState::Queued => "QUEUED",
+            QueryExecutionState::Running => "RUNNING",
+            QueryExecutionState::Succeeded => "SUCCEEDED",
+            QueryExecutionState::Failed => "FAILED",
+            QueryExecutionState::Cancelled => "CANCELLED",
+            QueryExecutionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for QueryExecutionState {
+    fn from(value: &'a str) -> QueryExecutionState {
+        match value {
+            "QUEUED" => QueryExecutionState::Queued,
+            "RUNNING" => QueryExecutionState::Running,
+            "SUCCEEDED" => QueryExecutionState::Succeeded,
+            "FAILED" => QueryExecutionState::Failed,
+            "CANCELLED" => QueryExecutionState::Cancelled,
+            _ => QueryExecutionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueryExecutionState {
+    fn from(value: String) -> QueryExecutionState {
+        QueryExecutionState::from(value.as_str())
+    }
+}
+
+impl From<QueryExecutionState> for String {
+    fn from(value: QueryExecutionState) -> String {
+        match value {
+            QueryExecutionState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for QueryExecutionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for QueryExecutionState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QueryExecutionState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<QueryExecutionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(QueryExecutionState::from(value))
+    }
+}
+
 /// <p>The location of a manifest file that tracks file locations generated by the query, the amount of data scanned by the query, and the amount of time that it took the query to run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -501,20 +1308,22 @@ pub struct QueryExecutionStatistics {
 pub struct QueryExecutionStatus {
     /// <p>The date and time that the query completed.</p>
     #[serde(rename = "CompletionDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_date_time: Option<f64>,
+    pub completion_date_time: Option<EpochTimestamp>,
     /// <p>The state of query execution. <code>QUEUED</code> state is listed but is not used by Athena and is reserved for future use. <code>RUNNING</code> indicates that the query has been submitted to the service, and Athena will execute the query as soon as resources are available. <code>SUCCEEDED</code> indicates that the query completed without errors. <code>FAILED</code> indicates that the query experienced an error and did not complete processing. <code>CANCELLED</code> indicates that a user input interrupted query execution. </p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<QueryExecutionState>,
     /// <p>Further detail about the status of the query.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state_change_reason: Option<String>,
     /// <p>The date and time that the query was submitted.</p>
     #[serde(rename = "SubmissionDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submission_date_time: Option<f64>,
+    pub submission_date_time: Option<EpochTimestamp>,
 }
 
 /// <p>The location in Amazon S3 where query results are stored and the encryption option, if any, used for query results. These are known as "client-side settings". If workgroup settings override client-side settings, then the query uses the workgroup settings.</p>
@@ -608,6 +1417,70 @@ pub struct StartQueryExecutionInput {
     pub work_group: Option<String>,
 }
 
+impl StartQueryExecutionInput {
+    /// Returns a builder for `StartQueryExecutionInput`, checking its required members when built.
+    pub fn builder() -> StartQueryExecutionInputBuilder {
+        StartQueryExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`StartQueryExecutionInput`](struct.StartQueryExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct StartQueryExecutionInputBuilder {
+    client_request_token: Option<String>,
+    query_execution_context: Option<QueryExecutionContext>,
+    query_string: Option<String>,
+    result_configuration: Option<ResultConfiguration>,
+    work_group: Option<String>,
+}
+
+impl StartQueryExecutionInputBuilder {
+    /// Sets the [`client_request_token`](struct.StartQueryExecutionInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_execution_context`](struct.StartQueryExecutionInput.html#structfield.query_execution_context) member.
+    pub fn query_execution_context<T: Into<QueryExecutionContext>>(mut self, value: T) -> Self {
+        self.query_execution_context = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_string`](struct.StartQueryExecutionInput.html#structfield.query_string) member.
+    pub fn query_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`result_configuration`](struct.StartQueryExecutionInput.html#structfield.result_configuration) member.
+    pub fn result_configuration<T: Into<ResultConfiguration>>(mut self, value: T) -> Self {
+        self.result_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`work_group`](struct.StartQueryExecutionInput.html#structfield.work_group) member.
+    pub fn work_group<T: Into<String>>(mut self, value: T) -> Self {
+        self.work_group = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartQueryExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartQueryExecutionInput, ::rusoto_core::BuildError> {
+        Ok(StartQueryExecutionInput {
+            client_request_token: self.client_request_token,
+            query_execution_context: self.query_execution_context,
+            query_string: self
+                .query_string
+                .ok_or(::rusoto_core::BuildError::missing("query_string"))?,
+            result_configuration: self.result_configuration,
+            work_group: self.work_group,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartQueryExecutionOutput {
@@ -617,6 +1490,82 @@ pub struct StartQueryExecutionOutput {
     pub query_execution_id: Option<String>,
 }
 
+/// <p>The type of query statement that was run. <code>DDL</code> indicates DDL query statements. <code>DML</code> indicates DML (Data Manipulation Language) query statements, such as <code>CREATE TABLE AS SELECT</code>. <code>UTILITY</code> indicates query statements other than DDL and DML, such as <code>SHOW CREATE TABLE</code>, or <code>DESCRIBE &lt;table&gt;</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StatementType {
+    /// <p><code>DDL</code></p>
+    Ddl,
+    /// <p><code>DML</code></p>
+    Dml,
+    /// <p><code>UTILITY</code></p>
+    Utility,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StatementType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatementType::Ddl => "DDL",
+            StatementType::Dml => "DML",
+            StatementType::Utility => "UTILITY",
+            StatementType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StatementType {
+    fn from(value: &'a str) -> StatementType {
+        match value {
+            "DDL" => StatementType::Ddl,
+            "DML" => StatementType::Dml,
+            "UTILITY" => StatementType::Utility,
+            _ => StatementType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatementType {
+    fn from(value: String) -> StatementType {
+        StatementType::from(value.as_str())
+    }
+}
+
+impl From<StatementType> for String {
+    fn from(value: StatementType) -> String {
+        match value {
+            StatementType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StatementType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StatementType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StatementType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StatementType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StatementType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StopQueryExecutionInput {
     /// <p>The unique ID of the query execution to stop.</p>
@@ -624,6 +1573,38 @@ pub struct StopQueryExecutionInput {
     pub query_execution_id: String,
 }
 
+impl StopQueryExecutionInput {
+    /// Returns a builder for `StopQueryExecutionInput`, checking its required members when built.
+    pub fn builder() -> StopQueryExecutionInputBuilder {
+        StopQueryExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`StopQueryExecutionInput`](struct.StopQueryExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct StopQueryExecutionInputBuilder {
+    query_execution_id: Option<String>,
+}
+
+impl StopQueryExecutionInputBuilder {
+    /// Sets the [`query_execution_id`](struct.StopQueryExecutionInput.html#structfield.query_execution_id) member.
+    pub fn query_execution_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_execution_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopQueryExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopQueryExecutionInput, ::rusoto_core::BuildError> {
+        Ok(StopQueryExecutionInput {
+            query_execution_id: self
+                .query_execution_id
+                .ok_or(::rusoto_core::BuildError::missing("query_execution_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopQueryExecutionOutput {}
@@ -651,6 +1632,46 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceInput {
+    /// Returns a builder for `TagResourceInput`, checking its required members when built.
+    pub fn builder() -> TagResourceInputBuilder {
+        TagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceInput`](struct.TagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct TagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceInput, ::rusoto_core::BuildError> {
+        Ok(TagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceOutput {}
@@ -701,6 +1722,46 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceInput {
+    /// Returns a builder for `UntagResourceInput`, checking its required members when built.
+    pub fn builder() -> UntagResourceInputBuilder {
+        UntagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceInput`](struct.UntagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceInput, ::rusoto_core::BuildError> {
+        Ok(UntagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceOutput {}
@@ -718,12 +1779,69 @@ pub struct UpdateWorkGroupInput {
     /// <p>The workgroup state that will be updated for the given workgroup.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<WorkGroupState>,
     /// <p>The specified workgroup that will be updated.</p>
     #[serde(rename = "WorkGroup")]
     pub work_group: String,
 }
 
+impl UpdateWorkGroupInput {
+    /// Returns a builder for `UpdateWorkGroupInput`, checking its required members when built.
+    pub fn builder() -> UpdateWorkGroupInputBuilder {
+        UpdateWorkGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateWorkGroupInput`](struct.UpdateWorkGroupInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateWorkGroupInputBuilder {
+    configuration_updates: Option<WorkGroupConfigurationUpdates>,
+    description: Option<String>,
+    state: Option<WorkGroupState>,
+    work_group: Option<String>,
+}
+
+impl UpdateWorkGroupInputBuilder {
+    /// Sets the [`configuration_updates`](struct.UpdateWorkGroupInput.html#structfield.configuration_updates) member.
+    pub fn configuration_updates<T: Into<WorkGroupConfigurationUpdates>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.configuration_updates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateWorkGroupInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state`](struct.UpdateWorkGroupInput.html#structfield.state) member.
+    pub fn state<T: Into<WorkGroupState>>(mut self, value: T) -> Self {
+        self.state = Some(value.into());
+        self
+    }
+
+    /// Sets the [`work_group`](struct.UpdateWorkGroupInput.html#structfield.work_group) member.
+    pub fn work_group<T: Into<String>>(mut self, value: T) -> Self {
+        self.work_group = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateWorkGroupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateWorkGroupInput, ::rusoto_core::BuildError> {
+        Ok(UpdateWorkGroupInput {
+            configuration_updates: self.configuration_updates,
+            description: self.description,
+            state: self.state,
+            work_group: self
+                .work_group
+                .ok_or(::rusoto_core::BuildError::missing("work_group"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateWorkGroupOutput {}
@@ -738,8 +1856,9 @@ pub struct WorkGroup {
     pub configuration: Option<WorkGroupConfiguration>,
     /// <p>The date and time the workgroup was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The workgroup description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -750,7 +1869,7 @@ pub struct WorkGroup {
     /// <p>The state of the workgroup: ENABLED or DISABLED.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<WorkGroupState>,
 }
 
 /// <p>The configuration of the workgroup, which includes the location in Amazon S3 where query results are stored, the encryption option, if any, used for query results, whether the Amazon CloudWatch Metrics are enabled for the workgroup and whether workgroup settings override query settings, and the data usage limits for the amount of data scanned per query or per workgroup. The workgroup settings override is specified in EnforceWorkGroupConfiguration (true/false) in the WorkGroupConfiguration. See <a>WorkGroupConfiguration$EnforceWorkGroupConfiguration</a>. </p>
@@ -807,14 +1926,87 @@ pub struct WorkGroupConfigurationUpdates {
     pub result_configuration_updates: Option<ResultConfigurationUpdates>,
 }
 
+/// <p>The workgroup state that will be updated for the given workgroup.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkGroupState {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkGroupState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkGroupState::Enabled => "ENABLED",
+            WorkGroupState::Disabled => "DISABLED",
+            WorkGroupState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkGroupState {
+    fn from(value: &'a str) -> WorkGroupState {
+        match value {
+            "ENABLED" => WorkGroupState::Enabled,
+            "DISABLED" => WorkGroupState::Disabled,
+            _ => WorkGroupState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkGroupState {
+    fn from(value: String) -> WorkGroupState {
+        WorkGroupState::from(value.as_str())
+    }
+}
+
+impl From<WorkGroupState> for String {
+    fn from(value: WorkGroupState) -> String {
+        match value {
+            WorkGroupState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WorkGroupState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WorkGroupState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkGroupState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WorkGroupState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkGroupState::from(value))
+    }
+}
+
 /// <p>The summary information for the workgroup, which includes its name, state, description, and the date and time it was created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct WorkGroupSummary {
     /// <p>The workgroup creation date and time.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The workgroup description.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -826,7 +2018,7 @@ pub struct WorkGroupSummary {
     /// <p>The state of the workgroup.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<WorkGroupState>,
 }
 
 /// Errors returned by BatchGetNamedQuery
@@ -1716,6 +2908,129 @@ pub trait Athena {
         input: UpdateWorkGroupInput,
     ) -> RusotoFuture<UpdateWorkGroupOutput, UpdateWorkGroupError>;
 }
+impl<T: ?Sized + Athena> Athena for ::std::sync::Arc<T> {
+    fn batch_get_named_query(
+        &self,
+        input: BatchGetNamedQueryInput,
+    ) -> RusotoFuture<BatchGetNamedQueryOutput, BatchGetNamedQueryError> {
+        (**self).batch_get_named_query(input)
+    }
+    fn batch_get_query_execution(
+        &self,
+        input: BatchGetQueryExecutionInput,
+    ) -> RusotoFuture<BatchGetQueryExecutionOutput, BatchGetQueryExecutionError> {
+        (**self).batch_get_query_execution(input)
+    }
+    fn create_named_query(
+        &self,
+        input: CreateNamedQueryInput,
+    ) -> RusotoFuture<CreateNamedQueryOutput, CreateNamedQueryError> {
+        (**self).create_named_query(input)
+    }
+    fn create_work_group(
+        &self,
+        input: CreateWorkGroupInput,
+    ) -> RusotoFuture<CreateWorkGroupOutput, CreateWorkGroupError> {
+        (**self).create_work_group(input)
+    }
+    fn delete_named_query(
+        &self,
+        input: DeleteNamedQueryInput,
+    ) -> RusotoFuture<DeleteNamedQueryOutput, DeleteNamedQueryError> {
+        (**self).delete_named_query(input)
+    }
+    fn delete_work_group(
+        &self,
+        input: DeleteWorkGroupInput,
+    ) -> RusotoFuture<DeleteWorkGroupOutput, DeleteWorkGroupError> {
+        (**self).delete_work_group(input)
+    }
+    fn get_named_query(
+        &self,
+        input: GetNamedQueryInput,
+    ) -> RusotoFuture<GetNamedQueryOutput, GetNamedQueryError> {
+        (**self).get_named_query(input)
+    }
+    fn get_query_execution(
+        &self,
+        input: GetQueryExecutionInput,
+    ) -> RusotoFuture<GetQueryExecutionOutput, GetQueryExecutionError> {
+        (**self).get_query_execution(input)
+    }
+    fn get_query_results(
+        &self,
+        input: GetQueryResultsInput,
+    ) -> RusotoFuture<GetQueryResultsOutput, GetQueryResultsError> {
+        (**self).get_query_results(input)
+    }
+    fn get_work_group(
+        &self,
+        input: GetWorkGroupInput,
+    ) -> RusotoFuture<GetWorkGroupOutput, GetWorkGroupError> {
+        (**self).get_work_group(input)
+    }
+    fn list_named_queries(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> RusotoFuture<ListNamedQueriesOutput, ListNamedQueriesError> {
+        (**self).list_named_queries(input)
+    }
+    fn list_query_executions(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> RusotoFuture<ListQueryExecutionsOutput, ListQueryExecutionsError> {
+        (**self).list_query_executions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_work_groups(
+        &self,
+        input: ListWorkGroupsInput,
+    ) -> RusotoFuture<ListWorkGroupsOutput, ListWorkGroupsError> {
+        (**self).list_work_groups(input)
+    }
+    fn start_query_execution(
+        &self,
+        input: StartQueryExecutionInput,
+    ) -> RusotoFuture<StartQueryExecutionOutput, StartQueryExecutionError> {
+        (**self).start_query_execution(input)
+    }
+    fn stop_query_execution(
+        &self,
+        input: StopQueryExecutionInput,
+    ) -> RusotoFuture<StopQueryExecutionOutput, StopQueryExecutionError> {
+        (**self).stop_query_execution(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_work_group(
+        &self,
+        input: UpdateWorkGroupInput,
+    ) -> RusotoFuture<UpdateWorkGroupOutput, UpdateWorkGroupError> {
+        (**self).update_work_group(input)
+    }
+}
+/// The error codes Amazon Athena retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the Amazon Athena API.
 #[derive(Clone)]
 pub struct AthenaClient {
@@ -1731,6 +3046,27 @@ impl AthenaClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ATHENA`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> AthenaClient {
+        Self::new(region::Region::from_env_for_service("ATHENA"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `athena`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> AthenaClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("athena", "ATHENA"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1749,7 +3085,49 @@ impl AthenaClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AthenaClient {
-        AthenaClient { client, region }
+        AthenaClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "athena")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "athena", "athena", "ATHENA")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Athena` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Athena + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for AthenaClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Athena"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "athena"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> AthenaClient {
+        Self::new_with_client(client, region)
     }
 }
 
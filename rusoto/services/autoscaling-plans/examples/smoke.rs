@@ -0,0 +1,40 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Auto Scaling Plans clients.
+//!
+//! Makes a single read-only `DescribeScalingPlans` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_autoscaling_plans::{
+    AutoscalingPlans, AutoscalingPlansClient, DescribeScalingPlansRequest,
+};
+
+fn main() {
+    let client = AutoscalingPlansClient::from_env();
+    match client
+        .describe_scaling_plans(DescribeScalingPlansRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeScalingPlans succeeded"),
+        Err(error) => {
+            eprintln!("DescribeScalingPlans failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Represents an application source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -49,6 +50,58 @@ pub struct CreateScalingPlanRequest {
     pub scaling_plan_name: String,
 }
 
+impl CreateScalingPlanRequest {
+    /// Returns a builder for `CreateScalingPlanRequest`, checking its required members when built.
+    pub fn builder() -> CreateScalingPlanRequestBuilder {
+        CreateScalingPlanRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateScalingPlanRequest`](struct.CreateScalingPlanRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateScalingPlanRequestBuilder {
+    application_source: Option<ApplicationSource>,
+    scaling_instructions: Option<Vec<ScalingInstruction>>,
+    scaling_plan_name: Option<String>,
+}
+
+impl CreateScalingPlanRequestBuilder {
+    /// Sets the [`application_source`](struct.CreateScalingPlanRequest.html#structfield.application_source) member.
+    pub fn application_source<T: Into<ApplicationSource>>(mut self, value: T) -> Self {
+        self.application_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_instructions`](struct.CreateScalingPlanRequest.html#structfield.scaling_instructions) member.
+    pub fn scaling_instructions<T: Into<Vec<ScalingInstruction>>>(mut self, value: T) -> Self {
+        self.scaling_instructions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_plan_name`](struct.CreateScalingPlanRequest.html#structfield.scaling_plan_name) member.
+    pub fn scaling_plan_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scaling_plan_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateScalingPlanRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateScalingPlanRequest, ::rusoto_core::BuildError> {
+        Ok(CreateScalingPlanRequest {
+            application_source: self
+                .application_source
+                .ok_or(::rusoto_core::BuildError::missing("application_source"))?,
+            scaling_instructions: self
+                .scaling_instructions
+                .ok_or(::rusoto_core::BuildError::missing("scaling_instructions"))?,
+            scaling_plan_name: self
+                .scaling_plan_name
+                .ok_or(::rusoto_core::BuildError::missing("scaling_plan_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateScalingPlanResponse {
@@ -58,7 +111,7 @@ pub struct CreateScalingPlanResponse {
 }
 
 /// <p>Represents a CloudWatch metric of your choosing that can be used for predictive scaling. </p> <p>For predictive scaling to work with a customized load metric specification, AWS Auto Scaling needs access to the <code>Sum</code> and <code>Average</code> statistics that CloudWatch computes from metric data. Statistics are calculations used to aggregate data over specified time periods.</p> <p>When you choose a load metric, make sure that the required <code>Sum</code> and <code>Average</code> statistics for your metric are available in CloudWatch and that they provide relevant data for predictive scaling. The <code>Sum</code> statistic must represent the total load on the resource, and the <code>Average</code> statistic must represent the average load per capacity unit of the resource. For example, there is a metric that counts the number of requests processed by your Auto Scaling group. If the <code>Sum</code> statistic represents the total request count processed by the group, then the <code>Average</code> statistic for the specified metric must represent the average request count processed by each instance of the group.</p> <p>For information about terminology, available metrics, or how to publish new metrics, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/cloudwatch_concepts.html">Amazon CloudWatch Concepts</a> in the <i>Amazon CloudWatch User Guide</i>. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CustomizedLoadMetricSpecification {
     /// <p>The dimensions of the metric.</p> <p>Conditional: If you published your metric with dimensions, you must specify the same dimensions in your customized load metric specification.</p>
     #[serde(rename = "Dimensions")]
@@ -72,7 +125,7 @@ pub struct CustomizedLoadMetricSpecification {
     pub namespace: String,
     /// <p>The statistic of the metric. Currently, the value must always be <code>Sum</code>. </p>
     #[serde(rename = "Statistic")]
-    pub statistic: String,
+    pub statistic: MetricStatistic,
     /// <p>The unit of the metric.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -80,7 +133,7 @@ pub struct CustomizedLoadMetricSpecification {
 }
 
 /// <p>Represents a CloudWatch metric of your choosing that can be used for dynamic scaling as part of a target tracking scaling policy. </p> <p>To create your customized scaling metric specification:</p> <ul> <li> <p>Add values for each required parameter from CloudWatch. You can use an existing metric, or a new metric that you create. To use your own metric, you must first publish the metric to CloudWatch. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html">Publish Custom Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p> </li> <li> <p>Choose a metric that changes proportionally with capacity. The value of the metric should increase or decrease in inverse proportion to the number of capacity units. That is, the value of the metric should decrease when capacity increases. </p> </li> </ul> <p>For more information about CloudWatch, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/cloudwatch_concepts.html">Amazon CloudWatch Concepts</a>. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CustomizedScalingMetricSpecification {
     /// <p>The dimensions of the metric.</p> <p>Conditional: If you published your metric with dimensions, you must specify the same dimensions in your customized scaling metric specification.</p>
     #[serde(rename = "Dimensions")]
@@ -94,7 +147,7 @@ pub struct CustomizedScalingMetricSpecification {
     pub namespace: String,
     /// <p>The statistic of the metric.</p>
     #[serde(rename = "Statistic")]
-    pub statistic: String,
+    pub statistic: MetricStatistic,
     /// <p>The unit of the metric. </p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -107,8 +160,9 @@ pub struct CustomizedScalingMetricSpecification {
 pub struct Datapoint {
     /// <p>The time stamp for the data point in UTC format.</p>
     #[serde(rename = "Timestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<EpochTimestamp>,
     /// <p>The value of the data point.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -125,6 +179,48 @@ pub struct DeleteScalingPlanRequest {
     pub scaling_plan_version: i64,
 }
 
+impl DeleteScalingPlanRequest {
+    /// Returns a builder for `DeleteScalingPlanRequest`, checking its required members when built.
+    pub fn builder() -> DeleteScalingPlanRequestBuilder {
+        DeleteScalingPlanRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteScalingPlanRequest`](struct.DeleteScalingPlanRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteScalingPlanRequestBuilder {
+    scaling_plan_name: Option<String>,
+    scaling_plan_version: Option<i64>,
+}
+
+impl DeleteScalingPlanRequestBuilder {
+    /// Sets the [`scaling_plan_name`](struct.DeleteScalingPlanRequest.html#structfield.scaling_plan_name) member.
+    pub fn scaling_plan_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scaling_plan_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_plan_version`](struct.DeleteScalingPlanRequest.html#structfield.scaling_plan_version) member.
+    pub fn scaling_plan_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.scaling_plan_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteScalingPlanRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteScalingPlanRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteScalingPlanRequest {
+            scaling_plan_name: self
+                .scaling_plan_name
+                .ok_or(::rusoto_core::BuildError::missing("scaling_plan_name"))?,
+            scaling_plan_version: self
+                .scaling_plan_version
+                .ok_or(::rusoto_core::BuildError::missing("scaling_plan_version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteScalingPlanResponse {}
@@ -147,6 +243,64 @@ pub struct DescribeScalingPlanResourcesRequest {
     pub scaling_plan_version: i64,
 }
 
+impl DescribeScalingPlanResourcesRequest {
+    /// Returns a builder for `DescribeScalingPlanResourcesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeScalingPlanResourcesRequestBuilder {
+        DescribeScalingPlanResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeScalingPlanResourcesRequest`](struct.DescribeScalingPlanResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeScalingPlanResourcesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    scaling_plan_name: Option<String>,
+    scaling_plan_version: Option<i64>,
+}
+
+impl DescribeScalingPlanResourcesRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeScalingPlanResourcesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeScalingPlanResourcesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_plan_name`](struct.DescribeScalingPlanResourcesRequest.html#structfield.scaling_plan_name) member.
+    pub fn scaling_plan_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scaling_plan_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_plan_version`](struct.DescribeScalingPlanResourcesRequest.html#structfield.scaling_plan_version) member.
+    pub fn scaling_plan_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.scaling_plan_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeScalingPlanResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeScalingPlanResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeScalingPlanResourcesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            scaling_plan_name: self
+                .scaling_plan_name
+                .ok_or(::rusoto_core::BuildError::missing("scaling_plan_name"))?,
+            scaling_plan_version: self
+                .scaling_plan_version
+                .ok_or(::rusoto_core::BuildError::missing("scaling_plan_version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeScalingPlanResourcesResponse {
@@ -184,6 +338,68 @@ pub struct DescribeScalingPlansRequest {
     pub scaling_plan_version: Option<i64>,
 }
 
+impl DescribeScalingPlansRequest {
+    /// Returns a builder for `DescribeScalingPlansRequest`, checking its required members when built.
+    pub fn builder() -> DescribeScalingPlansRequestBuilder {
+        DescribeScalingPlansRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeScalingPlansRequest`](struct.DescribeScalingPlansRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeScalingPlansRequestBuilder {
+    application_sources: Option<Vec<ApplicationSource>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    scaling_plan_names: Option<Vec<String>>,
+    scaling_plan_version: Option<i64>,
+}
+
+impl DescribeScalingPlansRequestBuilder {
+    /// Sets the [`application_sources`](struct.DescribeScalingPlansRequest.html#structfield.application_sources) member.
+    pub fn application_sources<T: Into<Vec<ApplicationSource>>>(mut self, value: T) -> Self {
+        self.application_sources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeScalingPlansRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeScalingPlansRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_plan_names`](struct.DescribeScalingPlansRequest.html#structfield.scaling_plan_names) member.
+    pub fn scaling_plan_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.scaling_plan_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_plan_version`](struct.DescribeScalingPlansRequest.html#structfield.scaling_plan_version) member.
+    pub fn scaling_plan_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.scaling_plan_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeScalingPlansRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeScalingPlansRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeScalingPlansRequest {
+            application_sources: self.application_sources,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            scaling_plan_names: self.scaling_plan_names,
+            scaling_plan_version: self.scaling_plan_version,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeScalingPlansResponse {
@@ -197,20 +413,101 @@ pub struct DescribeScalingPlansResponse {
     pub scaling_plans: Option<Vec<ScalingPlan>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p><p>The type of forecast data to get.</p> <ul> <li> <p> <code>LoadForecast</code>: The load metric forecast. </p> </li> <li> <p> <code>CapacityForecast</code>: The capacity forecast. </p> </li> <li> <p> <code>ScheduledActionMinCapacity</code>: The minimum capacity for each scheduled scaling action. This data is calculated as the larger of two values: the capacity forecast or the minimum capacity in the scaling instruction.</p> </li> <li> <p> <code>ScheduledActionMaxCapacity</code>: The maximum capacity for each scheduled scaling action. The calculation used is determined by the predictive scaling maximum capacity behavior setting in the scaling instruction.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ForecastDataType {
+    /// <p><code>CapacityForecast</code> - The capacity forecast.</p>
+    CapacityForecast,
+    /// <p><code>LoadForecast</code> - The load metric forecast.</p>
+    LoadForecast,
+    /// <p><code>ScheduledActionMinCapacity</code> - The minimum capacity for each scheduled scaling action. This data is calculated as the larger of two values: the capacity forecast or the minimum capacity in the scaling instruction.</p>
+    ScheduledActionMinCapacity,
+    /// <p><code>ScheduledActionMaxCapacity</code> - The maximum capacity for each scheduled scaling action. The calculation used is determined by the predictive scaling maximum capacity behavior setting in the scaling instruction.</p>
+    ScheduledActionMaxCapacity,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ForecastDataType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ForecastDataType::CapacityForecast => "CapacityForecast",
+            ForecastDataType::LoadForecast => "LoadForecast",
+            ForecastDataType::ScheduledActionMinCapacity => "ScheduledActionMinCapacity",
+            ForecastDataType::ScheduledActionMaxCapacity => "ScheduledActionMaxCapacity",
+            ForecastDataType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ForecastDataType {
+    fn from(value: &'a str) -> ForecastDataType {
+        match value {
+            "CapacityForecast" => ForecastDataType::CapacityForecast,
+            "LoadForecast" => ForecastDataType::LoadForecast,
+            "ScheduledActionMinCapacity" => ForecastDataType::ScheduledActionMinCapacity,
+            "ScheduledActionMaxCapacity" => ForecastDataType::ScheduledActionMaxCapacity,
+            _ => ForecastDataType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ForecastDataType {
+    fn from(value: String) -> ForecastDataType {
+        ForecastDataType::from(value.as_str())
+    }
+}
+
+impl From<ForecastDataType> for String {
+    fn from(value: ForecastDataType) -> String {
+        match value {
+            ForecastDataType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ForecastDataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ForecastDataType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ForecastDataType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ForecastDataType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ForecastDataType::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetScalingPlanResourceForecastDataRequest {
     /// <p>The exclusive end time of the time range for the forecast data to get. The maximum time duration between the start and end time is seven days. </p> <p>Although this parameter can accept a date and time that is more than two days in the future, the availability of forecast data has limits. AWS Auto Scaling only issues forecasts for periods of two days in advance.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p><p>The type of forecast data to get.</p> <ul> <li> <p> <code>LoadForecast</code>: The load metric forecast. </p> </li> <li> <p> <code>CapacityForecast</code>: The capacity forecast. </p> </li> <li> <p> <code>ScheduledActionMinCapacity</code>: The minimum capacity for each scheduled scaling action. This data is calculated as the larger of two values: the capacity forecast or the minimum capacity in the scaling instruction.</p> </li> <li> <p> <code>ScheduledActionMaxCapacity</code>: The maximum capacity for each scheduled scaling action. The calculation used is determined by the predictive scaling maximum capacity behavior setting in the scaling instruction.</p> </li> </ul></p>
     #[serde(rename = "ForecastDataType")]
-    pub forecast_data_type: String,
+    pub forecast_data_type: ForecastDataType,
     /// <p><p>The ID of the resource. This string consists of the resource type and unique identifier. </p> <ul> <li> <p>Auto Scaling group - The resource type is <code>autoScalingGroup</code> and the unique identifier is the name of the Auto Scaling group. Example: <code>autoScalingGroup/my-asg</code>.</p> </li> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p>The scalable dimension for the resource.</p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The name of the scaling plan.</p>
     #[serde(rename = "ScalingPlanName")]
     pub scaling_plan_name: String,
@@ -219,10 +516,114 @@ pub struct GetScalingPlanResourceForecastDataRequest {
     pub scaling_plan_version: i64,
     /// <p>The namespace of the AWS service.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>The inclusive start time of the time range for the forecast data to get. The date and time can be at most 56 days before the current date and time. </p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
+}
+
+impl GetScalingPlanResourceForecastDataRequest {
+    /// Returns a builder for `GetScalingPlanResourceForecastDataRequest`, checking its required members when built.
+    pub fn builder() -> GetScalingPlanResourceForecastDataRequestBuilder {
+        GetScalingPlanResourceForecastDataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetScalingPlanResourceForecastDataRequest`](struct.GetScalingPlanResourceForecastDataRequest.html).
+#[derive(Default, Debug)]
+pub struct GetScalingPlanResourceForecastDataRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    forecast_data_type: Option<ForecastDataType>,
+    resource_id: Option<String>,
+    scalable_dimension: Option<ScalableDimension>,
+    scaling_plan_name: Option<String>,
+    scaling_plan_version: Option<i64>,
+    service_namespace: Option<ServiceNamespace>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl GetScalingPlanResourceForecastDataRequestBuilder {
+    /// Sets the [`end_time`](struct.GetScalingPlanResourceForecastDataRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`forecast_data_type`](struct.GetScalingPlanResourceForecastDataRequest.html#structfield.forecast_data_type) member.
+    pub fn forecast_data_type<T: Into<ForecastDataType>>(mut self, value: T) -> Self {
+        self.forecast_data_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.GetScalingPlanResourceForecastDataRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scalable_dimension`](struct.GetScalingPlanResourceForecastDataRequest.html#structfield.scalable_dimension) member.
+    pub fn scalable_dimension<T: Into<ScalableDimension>>(mut self, value: T) -> Self {
+        self.scalable_dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_plan_name`](struct.GetScalingPlanResourceForecastDataRequest.html#structfield.scaling_plan_name) member.
+    pub fn scaling_plan_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scaling_plan_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_plan_version`](struct.GetScalingPlanResourceForecastDataRequest.html#structfield.scaling_plan_version) member.
+    pub fn scaling_plan_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.scaling_plan_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespace`](struct.GetScalingPlanResourceForecastDataRequest.html#structfield.service_namespace) member.
+    pub fn service_namespace<T: Into<ServiceNamespace>>(mut self, value: T) -> Self {
+        self.service_namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetScalingPlanResourceForecastDataRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetScalingPlanResourceForecastDataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetScalingPlanResourceForecastDataRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetScalingPlanResourceForecastDataRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            forecast_data_type: self
+                .forecast_data_type
+                .ok_or(::rusoto_core::BuildError::missing("forecast_data_type"))?,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            scalable_dimension: self
+                .scalable_dimension
+                .ok_or(::rusoto_core::BuildError::missing("scalable_dimension"))?,
+            scaling_plan_name: self
+                .scaling_plan_name
+                .ok_or(::rusoto_core::BuildError::missing("scaling_plan_name"))?,
+            scaling_plan_version: self
+                .scaling_plan_version
+                .ok_or(::rusoto_core::BuildError::missing("scaling_plan_version"))?,
+            service_namespace: self
+                .service_namespace
+                .ok_or(::rusoto_core::BuildError::missing("service_namespace"))?,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -233,6 +634,86 @@ pub struct GetScalingPlanResourceForecastDataResponse {
     pub datapoints: Vec<Datapoint>,
 }
 
+/// <p>The metric type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadMetricType {
+    /// <p><code>ASGTotalCPUUtilization</code></p>
+    ASGTotalCPUUtilization,
+    /// <p><code>ASGTotalNetworkIn</code></p>
+    ASGTotalNetworkIn,
+    /// <p><code>ASGTotalNetworkOut</code></p>
+    ASGTotalNetworkOut,
+    /// <p><code>ALBTargetGroupRequestCount</code></p>
+    ALBTargetGroupRequestCount,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadMetricType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadMetricType::ASGTotalCPUUtilization => "ASGTotalCPUUtilization",
+            LoadMetricType::ASGTotalNetworkIn => "ASGTotalNetworkIn",
+            LoadMetricType::ASGTotalNetworkOut => "ASGTotalNetworkOut",
+            LoadMetricType::ALBTargetGroupRequestCount => "ALBTargetGroupRequestCount",
+            LoadMetricType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoadMetricType {
+    fn from(value: &'a str) -> LoadMetricType {
+        match value {
+            "ASGTotalCPUUtilization" => LoadMetricType::ASGTotalCPUUtilization,
+            "ASGTotalNetworkIn" => LoadMetricType::ASGTotalNetworkIn,
+            "ASGTotalNetworkOut" => LoadMetricType::ASGTotalNetworkOut,
+            "ALBTargetGroupRequestCount" => LoadMetricType::ALBTargetGroupRequestCount,
+            _ => LoadMetricType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadMetricType {
+    fn from(value: String) -> LoadMetricType {
+        LoadMetricType::from(value.as_str())
+    }
+}
+
+impl From<LoadMetricType> for String {
+    fn from(value: LoadMetricType) -> String {
+        match value {
+            LoadMetricType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadMetricType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LoadMetricType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadMetricType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LoadMetricType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoadMetricType::from(value))
+    }
+}
+
 /// <p>Represents a dimension for a customized metric.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetricDimension {
@@ -244,12 +725,164 @@ pub struct MetricDimension {
     pub value: String,
 }
 
+/// <p>The statistic of the metric. Currently, the value must always be <code>Sum</code>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetricStatistic {
+    /// <p><code>Average</code></p>
+    Average,
+    /// <p><code>Minimum</code></p>
+    Minimum,
+    /// <p><code>Maximum</code></p>
+    Maximum,
+    /// <p><code>SampleCount</code></p>
+    SampleCount,
+    /// <p><code>Sum</code></p>
+    Sum,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MetricStatistic {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricStatistic::Average => "Average",
+            MetricStatistic::Minimum => "Minimum",
+            MetricStatistic::Maximum => "Maximum",
+            MetricStatistic::SampleCount => "SampleCount",
+            MetricStatistic::Sum => "Sum",
+            MetricStatistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MetricStatistic {
+    fn from(value: &'a str) -> MetricStatistic {
+        match value {
+            "Average" => MetricStatistic::Average,
+            "Minimum" => MetricStatistic::Minimum,
+            "Maximum" => MetricStatistic::Maximum,
+            "SampleCount" => MetricStatistic::SampleCount,
+            "Sum" => MetricStatistic::Sum,
+            _ => MetricStatistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricStatistic {
+    fn from(value: String) -> MetricStatistic {
+        MetricStatistic::from(value.as_str())
+    }
+}
+
+impl From<MetricStatistic> for String {
+    fn from(value: MetricStatistic) -> String {
+        match value {
+            MetricStatistic::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MetricStatistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MetricStatistic {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricStatistic {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MetricStatistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MetricStatistic::from(value))
+    }
+}
+
+/// <p>The type of scaling policy.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyType {
+    /// <p><code>TargetTrackingScaling</code></p>
+    TargetTrackingScaling,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PolicyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyType::TargetTrackingScaling => "TargetTrackingScaling",
+            PolicyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PolicyType {
+    fn from(value: &'a str) -> PolicyType {
+        match value {
+            "TargetTrackingScaling" => PolicyType::TargetTrackingScaling,
+            _ => PolicyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyType {
+    fn from(value: String) -> PolicyType {
+        PolicyType::from(value.as_str())
+    }
+}
+
+impl From<PolicyType> for String {
+    fn from(value: PolicyType) -> String {
+        match value {
+            PolicyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PolicyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PolicyType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PolicyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PolicyType::from(value))
+    }
+}
+
 /// <p>Represents a predefined metric that can be used for predictive scaling. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PredefinedLoadMetricSpecification {
     /// <p>The metric type.</p>
     #[serde(rename = "PredefinedLoadMetricType")]
-    pub predefined_load_metric_type: String,
+    pub predefined_load_metric_type: LoadMetricType,
     /// <p><p>Identifies the resource associated with the metric type. You can&#39;t specify a resource label unless the metric type is <code>ALBRequestCountPerTarget</code> and there is a target group for an Application Load Balancer attached to the Auto Scaling group.</p> <p>The format is app/&lt;load-balancer-name&gt;/&lt;load-balancer-id&gt;/targetgroup/&lt;target-group-name&gt;/&lt;target-group-id&gt;, where:</p> <ul> <li> <p>app/&lt;load-balancer-name&gt;/&lt;load-balancer-id&gt; is the final portion of the load balancer ARN.</p> </li> <li> <p>targetgroup/&lt;target-group-name&gt;/&lt;target-group-id&gt; is the final portion of the target group ARN.</p> </li> </ul></p>
     #[serde(rename = "ResourceLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -257,19 +890,293 @@ pub struct PredefinedLoadMetricSpecification {
 }
 
 /// <p>Represents a predefined metric that can be used for dynamic scaling as part of a target tracking scaling policy.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PredefinedScalingMetricSpecification {
     /// <p>The metric type. The <code>ALBRequestCountPerTarget</code> metric type applies only to Auto Scaling groups, Spot Fleet requests, and ECS services.</p>
     #[serde(rename = "PredefinedScalingMetricType")]
-    pub predefined_scaling_metric_type: String,
+    pub predefined_scaling_metric_type: ScalingMetricType,
     /// <p><p>Identifies the resource associated with the metric type. You can&#39;t specify a resource label unless the metric type is <code>ALBRequestCountPerTarget</code> and there is a target group for an Application Load Balancer attached to the Auto Scaling group, Spot Fleet request, or ECS service.</p> <p>The format is app/&lt;load-balancer-name&gt;/&lt;load-balancer-id&gt;/targetgroup/&lt;target-group-name&gt;/&lt;target-group-id&gt;, where:</p> <ul> <li> <p>app/&lt;load-balancer-name&gt;/&lt;load-balancer-id&gt; is the final portion of the load balancer ARN.</p> </li> <li> <p>targetgroup/&lt;target-group-name&gt;/&lt;target-group-id&gt; is the final portion of the target group ARN.</p> </li> </ul></p>
     #[serde(rename = "ResourceLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_label: Option<String>,
 }
 
+/// <p>Defines the behavior that should be applied if the forecast capacity approaches or exceeds the maximum capacity specified for the resource. The default value is <code>SetForecastCapacityToMaxCapacity</code>.</p> <p>The following are possible values:</p> <ul> <li> <p> <code>SetForecastCapacityToMaxCapacity</code> - AWS Auto Scaling cannot scale resource capacity higher than the maximum capacity. The maximum capacity is enforced as a hard limit. </p> </li> <li> <p> <code>SetMaxCapacityToForecastCapacity</code> - AWS Auto Scaling may scale resource capacity higher than the maximum capacity to equal but not exceed forecast capacity.</p> </li> <li> <p> <code>SetMaxCapacityAboveForecastCapacity</code> - AWS Auto Scaling may scale resource capacity higher than the maximum capacity by a specified buffer value. The intention is to give the target tracking scaling policy extra capacity if unexpected traffic occurs. </p> </li> </ul> <p>Only valid when configuring predictive scaling.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PredictiveScalingMaxCapacityBehavior {
+    /// <p><code>SetForecastCapacityToMaxCapacity</code> - AWS Auto Scaling cannot scale resource capacity higher than the maximum capacity. The maximum capacity is enforced as a hard limit.</p>
+    SetForecastCapacityToMaxCapacity,
+    /// <p><code>SetMaxCapacityToForecastCapacity</code> - AWS Auto Scaling may scale resource capacity higher than the maximum capacity to equal but not exceed forecast capacity.</p>
+    SetMaxCapacityToForecastCapacity,
+    /// <p><code>SetMaxCapacityAboveForecastCapacity</code> - AWS Auto Scaling may scale resource capacity higher than the maximum capacity by a specified buffer value. The intention is to give the target tracking scaling policy extra capacity if unexpected traffic occurs.</p>
+    SetMaxCapacityAboveForecastCapacity,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PredictiveScalingMaxCapacityBehavior {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PredictiveScalingMaxCapacityBehavior::SetForecastCapacityToMaxCapacity => {
+                "SetForecastCapacityToMaxCapacity"
+            }
+            PredictiveScalingMaxCapacityBehavior::SetMaxCapacityToForecastCapacity => {
+                "SetMaxCapacityToForecastCapacity"
+            }
+            PredictiveScalingMaxCapacityBehavior::SetMaxCapacityAboveForecastCapacity => {
+                "SetMaxCapacityAboveForecastCapacity"
+            }
+            PredictiveScalingMaxCapacityBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PredictiveScalingMaxCapacityBehavior {
+    fn from(value: &'a str) -> PredictiveScalingMaxCapacityBehavior {
+        match value {
+            "SetForecastCapacityToMaxCapacity" => {
+                PredictiveScalingMaxCapacityBehavior::SetForecastCapacityToMaxCapacity
+            }
+            "SetMaxCapacityToForecastCapacity" => {
+                PredictiveScalingMaxCapacityBehavior::SetMaxCapacityToForecastCapacity
+            }
+            "SetMaxCapacityAboveForecastCapacity" => {
+                PredictiveScalingMaxCapacityBehavior::SetMaxCapacityAboveForecastCapacity
+            }
+            _ => PredictiveScalingMaxCapacityBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PredictiveScalingMaxCapacityBehavior {
+    fn from(value: String) -> PredictiveScalingMaxCapacityBehavior {
+        PredictiveScalingMaxCapacityBehavior::from(value.as_str())
+    }
+}
+
+impl From<PredictiveScalingMaxCapacityBehavior> for String {
+    fn from(value: PredictiveScalingMaxCapacityBehavior) -> String {
+        match value {
+            PredictiveScalingMaxCapacityBehavior::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PredictiveScalingMaxCapacityBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PredictiveScalingMaxCapacityBehavior {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PredictiveScalingMaxCapacityBehavior {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<PredictiveScalingMaxCapacityBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PredictiveScalingMaxCapacityBehavior::from(value))
+    }
+}
+
+/// <p>The predictive scaling mode. The default value is <code>ForecastAndScale</code>. Otherwise, AWS Auto Scaling forecasts capacity but does not create any scheduled scaling actions based on the capacity forecast. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PredictiveScalingMode {
+    /// <p><code>ForecastAndScale</code></p>
+    ForecastAndScale,
+    /// <p><code>ForecastOnly</code></p>
+    ForecastOnly,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PredictiveScalingMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PredictiveScalingMode::ForecastAndScale => "ForecastAndScale",
+            PredictiveScalingMode::ForecastOnly => "ForecastOnly",
+            PredictiveScalingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PredictiveScalingMode {
+    fn from(value: &'a str) -> PredictiveScalingMode {
+        match value {
+            "ForecastAndScale" => PredictiveScalingMode::ForecastAndScale,
+            "ForecastOnly" => PredictiveScalingMode::ForecastOnly,
+            _ => PredictiveScalingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PredictiveScalingMode {
+    fn from(value: String) -> PredictiveScalingMode {
+        PredictiveScalingMode::from(value.as_str())
+    }
+}
+
+impl From<PredictiveScalingMode> for String {
+    fn from(value: PredictiveScalingMode) -> String {
+        match value {
+            PredictiveScalingMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PredictiveScalingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PredictiveScalingMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PredictiveScalingMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PredictiveScalingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PredictiveScalingMode::from(value))
+    }
+}
+
+/// <p>The scalable dimension for the resource.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScalableDimension {
+    /// <p><code>autoscaling:autoScalingGroup:DesiredCapacity</code> - The desired capacity of an Auto Scaling group.</p>
+    AutoscalingAutoScalingGroupDesiredCapacity,
+    /// <p><code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p>
+    EcsServiceDesiredCount,
+    /// <p><code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p>
+    Ec2SpotFleetRequestTargetCapacity,
+    /// <p><code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p>
+    RdsClusterReadReplicaCount,
+    /// <p><code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p>
+    DynamodbTableReadCapacityUnits,
+    /// <p><code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p>
+    DynamodbTableWriteCapacityUnits,
+    /// <p><code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p>
+    DynamodbIndexReadCapacityUnits,
+    /// <p><code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p>
+    DynamodbIndexWriteCapacityUnits,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScalableDimension {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalableDimension::AutoscalingAutoScalingGroupDesiredCapacity => {
+                "autoscaling:autoScalingGroup:DesiredCapacity"
+            }
+            ScalableDimension::EcsServiceDesiredCount => "ecs:service:DesiredCount",
+            ScalableDimension::Ec2SpotFleetRequestTargetCapacity => {
+                "ec2:spot-fleet-request:TargetCapacity"
+            }
+            ScalableDimension::RdsClusterReadReplicaCount => "rds:cluster:ReadReplicaCount",
+            ScalableDimension::DynamodbTableReadCapacityUnits => "dynamodb:table:ReadCapacityUnits",
+            ScalableDimension::DynamodbTableWriteCapacityUnits => {
+                "dynamodb:table:WriteCapacityUnits"
+            }
+            ScalableDimension::DynamodbIndexReadCapacityUnits => "dynamodb:index:ReadCapacityUnits",
+            ScalableDimension::DynamodbIndexWriteCapacityUnits => {
+                "dynamodb:index:WriteCapacityUnits"
+            }
+            ScalableDimension::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScalableDimension {
+    fn from(value: &'a str) -> ScalableDimension {
+        match value {
+            "autoscaling:autoScalingGroup:DesiredCapacity" => {
+                ScalableDimension::AutoscalingAutoScalingGroupDesiredCapacity
+            }
+            "ecs:service:DesiredCount" => ScalableDimension::EcsServiceDesiredCount,
+            "ec2:spot-fleet-request:TargetCapacity" => {
+                ScalableDimension::Ec2SpotFleetRequestTargetCapacity
+            }
+            "rds:cluster:ReadReplicaCount" => ScalableDimension::RdsClusterReadReplicaCount,
+            "dynamodb:table:ReadCapacityUnits" => ScalableDimension::DynamodbTableReadCapacityUnits,
+            "dynamodb:table:WriteCapacityUnits" => {
+                ScalableDimension::DynamodbTableWriteCapacityUnits
+            }
+            "dynamodb:index:ReadCapacityUnits" => ScalableDimension::DynamodbIndexReadCapacityUnits,
+            "dynamodb:index:WriteCapacityUnits" => {
+                ScalableDimension::DynamodbIndexWriteCapacityUnits
+            }
+            _ => ScalableDimension::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalableDimension {
+    fn from(value: String) -> ScalableDimension {
+        ScalableDimension::from(value.as_str())
+    }
+}
+
+impl From<ScalableDimension> for String {
+    fn from(value: ScalableDimension) -> String {
+        match value {
+            ScalableDimension::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScalableDimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScalableDimension {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalableDimension {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ScalableDimension, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScalableDimension::from(value))
+    }
+}
+
 /// <p>Describes a scaling instruction for a scalable resource.</p> <p>The scaling instruction is used in combination with a scaling plan, which is a set of instructions for configuring dynamic scaling and predictive scaling for the scalable resources in your application. Each scaling instruction applies to one resource.</p> <p>AWS Auto Scaling creates target tracking scaling policies based on the scaling instructions. Target tracking scaling policies adjust the capacity of your scalable resource as required to maintain resource utilization at the target value that you specified. </p> <p>AWS Auto Scaling also configures predictive scaling for your Amazon EC2 Auto Scaling groups using a subset of parameters, including the load metric, the scaling metric, the target value for the scaling metric, the predictive scaling mode (forecast and scale or forecast only), and the desired behavior when the forecast capacity exceeds the maximum capacity of the resource. With predictive scaling, AWS Auto Scaling generates forecasts with traffic predictions for the two days ahead and schedules scaling actions that proactively add and remove resource capacity to match the forecast. </p> <p>We recommend waiting a minimum of 24 hours after creating an Auto Scaling group to configure predictive scaling. At minimum, there must be 24 hours of historical data to generate a forecast.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/plans/userguide/auto-scaling-getting-started.html">Getting Started with AWS Auto Scaling</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScalingInstruction {
     /// <p>The customized load metric to use for predictive scaling. This parameter or a <b>PredefinedLoadMetricSpecification</b> is required when configuring predictive scaling, and cannot be used otherwise. </p>
     #[serde(rename = "CustomizedLoadMetricSpecification")]
@@ -292,7 +1199,7 @@ pub struct ScalingInstruction {
     /// <p>Defines the behavior that should be applied if the forecast capacity approaches or exceeds the maximum capacity specified for the resource. The default value is <code>SetForecastCapacityToMaxCapacity</code>.</p> <p>The following are possible values:</p> <ul> <li> <p> <code>SetForecastCapacityToMaxCapacity</code> - AWS Auto Scaling cannot scale resource capacity higher than the maximum capacity. The maximum capacity is enforced as a hard limit. </p> </li> <li> <p> <code>SetMaxCapacityToForecastCapacity</code> - AWS Auto Scaling may scale resource capacity higher than the maximum capacity to equal but not exceed forecast capacity.</p> </li> <li> <p> <code>SetMaxCapacityAboveForecastCapacity</code> - AWS Auto Scaling may scale resource capacity higher than the maximum capacity by a specified buffer value. The intention is to give the target tracking scaling policy extra capacity if unexpected traffic occurs. </p> </li> </ul> <p>Only valid when configuring predictive scaling.</p>
     #[serde(rename = "PredictiveScalingMaxCapacityBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub predictive_scaling_max_capacity_behavior: Option<String>,
+    pub predictive_scaling_max_capacity_behavior: Option<PredictiveScalingMaxCapacityBehavior>,
     /// <p>The size of the capacity buffer to use when the forecast capacity is close to or exceeds the maximum capacity. The value is specified as a percentage relative to the forecast capacity. For example, if the buffer is 10, this means a 10 percent buffer, such that if the forecast capacity is 50, and the maximum capacity is 40, then the effective maximum capacity is 55.</p> <p>Only valid when configuring predictive scaling. Required if the <b>PredictiveScalingMaxCapacityBehavior</b> is set to <code>SetMaxCapacityAboveForecastCapacity</code>, and cannot be used otherwise.</p> <p>The range is 1-100.</p>
     #[serde(rename = "PredictiveScalingMaxCapacityBuffer")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -300,31 +1207,171 @@ pub struct ScalingInstruction {
     /// <p>The predictive scaling mode. The default value is <code>ForecastAndScale</code>. Otherwise, AWS Auto Scaling forecasts capacity but does not create any scheduled scaling actions based on the capacity forecast. </p>
     #[serde(rename = "PredictiveScalingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub predictive_scaling_mode: Option<String>,
+    pub predictive_scaling_mode: Option<PredictiveScalingMode>,
     /// <p><p>The ID of the resource. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>Auto Scaling group - The resource type is <code>autoScalingGroup</code> and the unique identifier is the name of the Auto Scaling group. Example: <code>autoScalingGroup/my-asg</code>.</p> </li> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> </ul></p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p><p>The scalable dimension associated with the resource.</p> <ul> <li> <p> <code>autoscaling:autoScalingGroup:DesiredCapacity</code> - The desired capacity of an Auto Scaling group.</p> </li> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>Controls whether a resource's externally created scaling policies are kept or replaced. </p> <p>The default value is <code>KeepExternalPolicies</code>. If the parameter is set to <code>ReplaceExternalPolicies</code>, any scaling policies that are external to AWS Auto Scaling are deleted and new target tracking scaling policies created. </p> <p>Only valid when configuring dynamic scaling. </p> <p>Condition: The number of existing policies to be replaced must be less than or equal to 50. If there are more than 50 policies to be replaced, AWS Auto Scaling keeps all existing policies and does not create new ones.</p>
     #[serde(rename = "ScalingPolicyUpdateBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scaling_policy_update_behavior: Option<String>,
+    pub scaling_policy_update_behavior: Option<ScalingPolicyUpdateBehavior>,
     /// <p>The amount of time, in seconds, to buffer the run time of scheduled scaling actions when scaling out. For example, if the forecast says to add capacity at 10:00 AM, and the buffer time is 5 minutes, then the run time of the corresponding scheduled scaling action will be 9:55 AM. The intention is to give resources time to be provisioned. For example, it can take a few minutes to launch an EC2 instance. The actual amount of time required depends on several factors, such as the size of the instance and whether there are startup scripts to complete. </p> <p>The value must be less than the forecast interval duration of 3600 seconds (60 minutes). The default is 300 seconds. </p> <p>Only valid when configuring predictive scaling. </p>
     #[serde(rename = "ScheduledActionBufferTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scheduled_action_buffer_time: Option<i64>,
     /// <p>The namespace of the AWS service.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
     /// <p>The structure that defines new target tracking configurations (up to 10). Each of these structures includes a specific scaling metric and a target value for the metric, along with various parameters to use with dynamic scaling. </p> <p>With predictive scaling and dynamic scaling, the resource scales based on the target tracking configuration that provides the largest capacity for both scale in and scale out. </p> <p>Condition: The scaling metric must be unique across target tracking configurations.</p>
     #[serde(rename = "TargetTrackingConfigurations")]
     pub target_tracking_configurations: Vec<TargetTrackingConfiguration>,
 }
 
+/// <p>The metric type. The <code>ALBRequestCountPerTarget</code> metric type applies only to Auto Scaling groups, Spot Fleet requests, and ECS services.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScalingMetricType {
+    /// <p><code>ASGAverageCPUUtilization</code></p>
+    ASGAverageCPUUtilization,
+    /// <p><code>ASGAverageNetworkIn</code></p>
+    ASGAverageNetworkIn,
+    /// <p><code>ASGAverageNetworkOut</code></p>
+    ASGAverageNetworkOut,
+    /// <p><code>DynamoDBReadCapacityUtilization</code></p>
+    DynamoDBReadCapacityUtilization,
+    /// <p><code>DynamoDBWriteCapacityUtilization</code></p>
+    DynamoDBWriteCapacityUtilization,
+    /// <p><code>ECSServiceAverageCPUUtilization</code></p>
+    ECSServiceAverageCPUUtilization,
+    /// <p><code>ECSServiceAverageMemoryUtilization</code></p>
+    ECSServiceAverageMemoryUtilization,
+    /// <p><code>ALBRequestCountPerTarget</code></p>
+    ALBRequestCountPerTarget,
+    /// <p><code>RDSReaderAverageCPUUtilization</code></p>
+    RDSReaderAverageCPUUtilization,
+    /// <p><code>RDSReaderAverageDatabaseConnections</code></p>
+    RDSReaderAverageDatabaseConnections,
+    /// <p><code>EC2SpotFleetRequestAverageCPUUtilization</code></p>
+    EC2SpotFleetRequestAverageCPUUtilization,
+    /// <p><code>EC2SpotFleetRequestAverageNetworkIn</code></p>
+    EC2SpotFleetRequestAverageNetworkIn,
+    /// <p><code>EC2SpotFleetRequestAverageNetworkOut</code></p>
+    EC2SpotFleetRequestAverageNetworkOut,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScalingMetricType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingMetricType::ASGAverageCPUUtilization => "ASGAverageCPUUtilization",
+            ScalingMetricType::ASGAverageNetworkIn => "ASGAverageNetworkIn",
+            ScalingMetricType::ASGAverageNetworkOut => "ASGAverageNetworkOut",
+            ScalingMetricType::DynamoDBReadCapacityUtilization => "DynamoDBReadCapacityUtilization",
+            ScalingMetricType::DynamoDBWriteCapacityUtilization => {
+                "DynamoDBWriteCapacityUtilization"
+            }
+            ScalingMetricType::ECSServiceAverageCPUUtilization => "ECSServiceAverageCPUUtilization",
+            ScalingMetricType::ECSServiceAverageMemoryUtilization => {
+                "ECSServiceAverageMemoryUtilization"
+            }
+            ScalingMetricType::ALBRequestCountPerTarget => "ALBRequestCountPerTarget",
+            ScalingMetricType::RDSReaderAverageCPUUtilization => "RDSReaderAverageCPUUtilization",
+            ScalingMetricType::RDSReaderAverageDatabaseConnections => {
+                "RDSReaderAverageDatabaseConnections"
+            }
+            ScalingMetricType::EC2SpotFleetRequestAverageCPUUtilization => {
+                "EC2SpotFleetRequestAverageCPUUtilization"
+            }
+            ScalingMetricType::EC2SpotFleetRequestAverageNetworkIn => {
+                "EC2SpotFleetRequestAverageNetworkIn"
+            }
+            ScalingMetricType::EC2SpotFleetRequestAverageNetworkOut => {
+                "EC2SpotFleetRequestAverageNetworkOut"
+            }
+            ScalingMetricType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScalingMetricType {
+    fn from(value: &'a str) -> ScalingMetricType {
+        match value {
+            "ASGAverageCPUUtilization" => ScalingMetricType::ASGAverageCPUUtilization,
+            "ASGAverageNetworkIn" => ScalingMetricType::ASGAverageNetworkIn,
+            "ASGAverageNetworkOut" => ScalingMetricType::ASGAverageNetworkOut,
+            "DynamoDBReadCapacityUtilization" => ScalingMetricType::DynamoDBReadCapacityUtilization,
+            "DynamoDBWriteCapacityUtilization" => {
+                ScalingMetricType::DynamoDBWriteCapacityUtilization
+            }
+            "ECSServiceAverageCPUUtilization" => ScalingMetricType::ECSServiceAverageCPUUtilization,
+            "ECSServiceAverageMemoryUtilization" => {
+                ScalingMetricType::ECSServiceAverageMemoryUtilization
+            }
+            "ALBRequestCountPerTarget" => ScalingMetricType::ALBRequestCountPerTarget,
+            "RDSReaderAverageCPUUtilization" => ScalingMetricType::RDSReaderAverageCPUUtilization,
+            "RDSReaderAverageDatabaseConnections" => {
+                ScalingMetricType::RDSReaderAverageDatabaseConnections
+            }
+            "EC2SpotFleetRequestAverageCPUUtilization" => {
+                ScalingMetricType::EC2SpotFleetRequestAverageCPUUtilization
+            }
+            "EC2SpotFleetRequestAverageNetworkIn" => {
+                ScalingMetricType::EC2SpotFleetRequestAverageNetworkIn
+            }
+            "EC2SpotFleetRequestAverageNetworkOut" => {
+                ScalingMetricType::EC2SpotFleetRequestAverageNetworkOut
+            }
+            _ => ScalingMetricType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingMetricType {
+    fn from(value: String) -> ScalingMetricType {
+        ScalingMetricType::from(value.as_str())
+    }
+}
+
+impl From<ScalingMetricType> for String {
+    fn from(value: ScalingMetricType) -> String {
+        match value {
+            ScalingMetricType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScalingMetricType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScalingMetricType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingMetricType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ScalingMetricType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScalingMetricType::from(value))
+    }
+}
+
 /// <p>Represents a scaling plan.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ScalingPlan {
     /// <p>The application source.</p>
@@ -332,8 +1379,9 @@ pub struct ScalingPlan {
     pub application_source: ApplicationSource,
     /// <p>The Unix time stamp when the scaling plan was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The scaling instructions.</p>
     #[serde(rename = "ScalingInstructions")]
     pub scaling_instructions: Vec<ScalingInstruction>,
@@ -345,19 +1393,20 @@ pub struct ScalingPlan {
     pub scaling_plan_version: i64,
     /// <p><p>The status of the scaling plan.</p> <ul> <li> <p> <code>Active</code> - The scaling plan is active.</p> </li> <li> <p> <code>ActiveWithProblems</code> - The scaling plan is active, but the scaling configuration for one or more resources could not be applied.</p> </li> <li> <p> <code>CreationInProgress</code> - The scaling plan is being created.</p> </li> <li> <p> <code>CreationFailed</code> - The scaling plan could not be created.</p> </li> <li> <p> <code>DeletionInProgress</code> - The scaling plan is being deleted.</p> </li> <li> <p> <code>DeletionFailed</code> - The scaling plan could not be deleted.</p> </li> <li> <p> <code>UpdateInProgress</code> - The scaling plan is being updated.</p> </li> <li> <p> <code>UpdateFailed</code> - The scaling plan could not be updated.</p> </li> </ul></p>
     #[serde(rename = "StatusCode")]
-    pub status_code: String,
+    pub status_code: ScalingPlanStatusCode,
     /// <p>A simple message about the current status of the scaling plan.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_message: Option<String>,
     /// <p>The Unix time stamp when the scaling plan entered the current status.</p>
     #[serde(rename = "StatusStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_start_time: Option<f64>,
+    pub status_start_time: Option<EpochTimestamp>,
 }
 
 /// <p>Represents a scalable resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ScalingPlanResource {
     /// <p><p>The ID of the resource. This string consists of the resource type and unique identifier.</p> <ul> <li> <p>Auto Scaling group - The resource type is <code>autoScalingGroup</code> and the unique identifier is the name of the Auto Scaling group. Example: <code>autoScalingGroup/my-asg</code>.</p> </li> <li> <p>ECS service - The resource type is <code>service</code> and the unique identifier is the cluster name and service name. Example: <code>service/default/sample-webapp</code>.</p> </li> <li> <p>Spot Fleet request - The resource type is <code>spot-fleet-request</code> and the unique identifier is the Spot Fleet request ID. Example: <code>spot-fleet-request/sfr-73fbd2ce-aa30-494c-8788-1cee4EXAMPLE</code>.</p> </li> <li> <p>DynamoDB table - The resource type is <code>table</code> and the unique identifier is the resource ID. Example: <code>table/my-table</code>.</p> </li> <li> <p>DynamoDB global secondary index - The resource type is <code>index</code> and the unique identifier is the resource ID. Example: <code>table/my-table/index/my-table-index</code>.</p> </li> <li> <p>Aurora DB cluster - The resource type is <code>cluster</code> and the unique identifier is the cluster name. Example: <code>cluster:my-db-cluster</code>.</p> </li> </ul></p>
@@ -365,7 +1414,7 @@ pub struct ScalingPlanResource {
     pub resource_id: String,
     /// <p><p>The scalable dimension for the resource.</p> <ul> <li> <p> <code>autoscaling:autoScalingGroup:DesiredCapacity</code> - The desired capacity of an Auto Scaling group.</p> </li> <li> <p> <code>ecs:service:DesiredCount</code> - The desired task count of an ECS service.</p> </li> <li> <p> <code>ec2:spot-fleet-request:TargetCapacity</code> - The target capacity of a Spot Fleet request.</p> </li> <li> <p> <code>dynamodb:table:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:table:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB table.</p> </li> <li> <p> <code>dynamodb:index:ReadCapacityUnits</code> - The provisioned read capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>dynamodb:index:WriteCapacityUnits</code> - The provisioned write capacity for a DynamoDB global secondary index.</p> </li> <li> <p> <code>rds:cluster:ReadReplicaCount</code> - The count of Aurora Replicas in an Aurora DB cluster. Available for Aurora MySQL-compatible edition and Aurora PostgreSQL-compatible edition.</p> </li> </ul></p>
     #[serde(rename = "ScalableDimension")]
-    pub scalable_dimension: String,
+    pub scalable_dimension: ScalableDimension,
     /// <p>The name of the scaling plan.</p>
     #[serde(rename = "ScalingPlanName")]
     pub scaling_plan_name: String,
@@ -378,18 +1427,114 @@ pub struct ScalingPlanResource {
     pub scaling_policies: Option<Vec<ScalingPolicy>>,
     /// <p><p>The scaling status of the resource.</p> <ul> <li> <p> <code>Active</code> - The scaling configuration is active.</p> </li> <li> <p> <code>Inactive</code> - The scaling configuration is not active because the scaling plan is being created or the scaling configuration could not be applied. Check the status message for more information.</p> </li> <li> <p> <code>PartiallyActive</code> - The scaling configuration is partially active because the scaling plan is being created or deleted or the scaling configuration could not be fully applied. Check the status message for more information.</p> </li> </ul></p>
     #[serde(rename = "ScalingStatusCode")]
-    pub scaling_status_code: String,
+    pub scaling_status_code: ScalingStatusCode,
     /// <p>A simple message about the current scaling status of the resource.</p>
     #[serde(rename = "ScalingStatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scaling_status_message: Option<String>,
     /// <p>The namespace of the AWS service.</p>
     #[serde(rename = "ServiceNamespace")]
-    pub service_namespace: String,
+    pub service_namespace: ServiceNamespace,
+}
+
+/// <p><p>The status of the scaling plan.</p> <ul> <li> <p> <code>Active</code> - The scaling plan is active.</p> </li> <li> <p> <code>ActiveWithProblems</code> - The scaling plan is active, but the scaling configuration for one or more resources could not be applied.</p> </li> <li> <p> <code>CreationInProgress</code> - The scaling plan is being created.</p> </li> <li> <p> <code>CreationFailed</code> - The scaling plan could not be created.</p> </li> <li> <p> <code>DeletionInProgress</code> - The scaling plan is being deleted.</p> </li> <li> <p> <code>DeletionFailed</code> - The scaling plan could not be deleted.</p> </li> <li> <p> <code>UpdateInProgress</code> - The scaling plan is being updated.</p> </li> <li> <p> <code>UpdateFailed</code> - The scaling plan could not be updated.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScalingPlanStatusCode {
+    /// <p><code>Active</code> - The scaling plan is active.</p>
+    Active,
+    /// <p><code>ActiveWithProblems</code> - The scaling plan is active, but the scaling configuration for one or more resources could not be applied.</p>
+    ActiveWithProblems,
+    /// <p><code>CreationInProgress</code> - The scaling plan is being created.</p>
+    CreationInProgress,
+    /// <p><code>CreationFailed</code> - The scaling plan could not be created.</p>
+    CreationFailed,
+    /// <p><code>DeletionInProgress</code> - The scaling plan is being deleted.</p>
+    DeletionInProgress,
+    /// <p><code>DeletionFailed</code> - The scaling plan could not be deleted.</p>
+    DeletionFailed,
+    /// <p><code>UpdateInProgress</code> - The scaling plan is being updated.</p>
+    UpdateInProgress,
+    /// <p><code>UpdateFailed</code> - The scaling plan could not be updated.</p>
+    UpdateFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScalingPlanStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingPlanStatusCode::Active => "Active",
+            ScalingPlanStatusCode::ActiveWithProblems => "ActiveWithProblems",
+            ScalingPlanStatusCode::CreationInProgress => "CreationInProgress",
+            ScalingPlanStatusCode::CreationFailed => "CreationFailed",
+            ScalingPlanStatusCode::DeletionInProgress => "DeletionInProgress",
+            ScalingPlanStatusCode::DeletionFailed => "DeletionFailed",
+            ScalingPlanStatusCode::UpdateInProgress => "UpdateInProgress",
+            ScalingPlanStatusCode::UpdateFailed => "UpdateFailed",
+            ScalingPlanStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScalingPlanStatusCode {
+    fn from(value: &'a str) -> ScalingPlanStatusCode {
+        match value {
+            "Active" => ScalingPlanStatusCode::Active,
+            "ActiveWithProblems" => ScalingPlanStatusCode::ActiveWithProblems,
+            "CreationInProgress" => ScalingPlanStatusCode::CreationInProgress,
+            "CreationFailed" => ScalingPlanStatusCode::CreationFailed,
+            "DeletionInProgress" => ScalingPlanStatusCode::DeletionInProgress,
+            "DeletionFailed" => ScalingPlanStatusCode::DeletionFailed,
+            "UpdateInProgress" => ScalingPlanStatusCode::UpdateInProgress,
+            "UpdateFailed" => ScalingPlanStatusCode::UpdateFailed,
+            _ => ScalingPlanStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingPlanStatusCode {
+    fn from(value: String) -> ScalingPlanStatusCode {
+        ScalingPlanStatusCode::from(value.as_str())
+    }
+}
+
+impl From<ScalingPlanStatusCode> for String {
+    fn from(value: ScalingPlanStatusCode) -> String {
+        match value {
+            ScalingPlanStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScalingPlanStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScalingPlanStatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingPlanStatusCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ScalingPlanStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScalingPlanStatusCode::from(value))
+    }
 }
 
 /// <p>Represents a scaling policy.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ScalingPolicy {
     /// <p>The name of the scaling policy.</p>
@@ -397,13 +1542,247 @@ pub struct ScalingPolicy {
     pub policy_name: String,
     /// <p>The type of scaling policy.</p>
     #[serde(rename = "PolicyType")]
-    pub policy_type: String,
+    pub policy_type: PolicyType,
     /// <p>The target tracking scaling policy. Includes support for predefined or customized metrics.</p>
     #[serde(rename = "TargetTrackingConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_tracking_configuration: Option<TargetTrackingConfiguration>,
 }
 
+/// <p>Controls whether a resource's externally created scaling policies are kept or replaced. </p> <p>The default value is <code>KeepExternalPolicies</code>. If the parameter is set to <code>ReplaceExternalPolicies</code>, any scaling policies that are external to AWS Auto Scaling are deleted and new target tracking scaling policies created. </p> <p>Only valid when configuring dynamic scaling. </p> <p>Condition: The number of existing policies to be replaced must be less than or equal to 50. If there are more than 50 policies to be replaced, AWS Auto Scaling keeps all existing policies and does not create new ones.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScalingPolicyUpdateBehavior {
+    /// <p><code>KeepExternalPolicies</code></p>
+    KeepExternalPolicies,
+    /// <p><code>ReplaceExternalPolicies</code></p>
+    ReplaceExternalPolicies,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScalingPolicyUpdateBehavior {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingPolicyUpdateBehavior::KeepExternalPolicies => "KeepExternalPolicies",
+            ScalingPolicyUpdateBehavior::ReplaceExternalPolicies => "ReplaceExternalPolicies",
+            ScalingPolicyUpdateBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScalingPolicyUpdateBehavior {
+    fn from(value: &'a str) -> ScalingPolicyUpdateBehavior {
+        match value {
+            "KeepExternalPolicies" => ScalingPolicyUpdateBehavior::KeepExternalPolicies,
+            "ReplaceExternalPolicies" => ScalingPolicyUpdateBehavior::ReplaceExternalPolicies,
+            _ => ScalingPolicyUpdateBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingPolicyUpdateBehavior {
+    fn from(value: String) -> ScalingPolicyUpdateBehavior {
+        ScalingPolicyUpdateBehavior::from(value.as_str())
+    }
+}
+
+impl From<ScalingPolicyUpdateBehavior> for String {
+    fn from(value: ScalingPolicyUpdateBehavior) -> String {
+        match value {
+            ScalingPolicyUpdateBehavior::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScalingPolicyUpdateBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScalingPolicyUpdateBehavior {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingPolicyUpdateBehavior {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ScalingPolicyUpdateBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScalingPolicyUpdateBehavior::from(value))
+    }
+}
+
+/// <p><p>The scaling status of the resource.</p> <ul> <li> <p> <code>Active</code> - The scaling configuration is active.</p> </li> <li> <p> <code>Inactive</code> - The scaling configuration is not active because the scaling plan is being created or the scaling configuration could not be applied. Check the status message for more information.</p> </li> <li> <p> <code>PartiallyActive</code> - The scaling configuration is partially active because the scaling plan is being created or deleted or the scaling configuration could not be fully applied. Check the status message for more information.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScalingStatusCode {
+    /// <p><code>Inactive</code> - The scaling configuration is not active because the scaling plan is being created or the scaling configuration could not be applied. Check the status message for more information.</p>
+    Inactive,
+    /// <p><code>PartiallyActive</code> - The scaling configuration is partially active because the scaling plan is being created or deleted or the scaling configuration could not be fully applied. Check the status message for more information.</p>
+    PartiallyActive,
+    /// <p><code>Active</code> - The scaling configuration is active.</p>
+    Active,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScalingStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingStatusCode::Inactive => "Inactive",
+            ScalingStatusCode::PartiallyActive => "PartiallyActive",
+            ScalingStatusCode::Active => "Active",
+            ScalingStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScalingStatusCode {
+    fn from(value: &'a str) -> ScalingStatusCode {
+        match value {
+            "Inactive" => ScalingStatusCode::Inactive,
+            "PartiallyActive" => ScalingStatusCode::PartiallyActive,
+            "Active" => ScalingStatusCode::Active,
+            _ => ScalingStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingStatusCode {
+    fn from(value: String) -> ScalingStatusCode {
+        ScalingStatusCode::from(value.as_str())
+    }
+}
+
+impl From<ScalingStatusCode> for String {
+    fn from(value: ScalingStatusCode) -> String {
+        match value {
+            ScalingStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScalingStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScalingStatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingStatusCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ScalingStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScalingStatusCode::from(value))
+    }
+}
+
+/// <p>The namespace of the AWS service.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceNamespace {
+    /// <p><code>autoscaling</code></p>
+    Autoscaling,
+    /// <p><code>ecs</code></p>
+    Ecs,
+    /// <p><code>ec2</code></p>
+    Ec2,
+    /// <p><code>rds</code></p>
+    Rds,
+    /// <p><code>dynamodb</code></p>
+    Dynamodb,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServiceNamespace {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceNamespace::Autoscaling => "autoscaling",
+            ServiceNamespace::Ecs => "ecs",
+            ServiceNamespace::Ec2 => "ec2",
+            ServiceNamespace::Rds => "rds",
+            ServiceNamespace::Dynamodb => "dynamodb",
+            ServiceNamespace::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServiceNamespace {
+    fn from(value: &'a str) -> ServiceNamespace {
+        match value {
+            "autoscaling" => ServiceNamespace::Autoscaling,
+            "ecs" => ServiceNamespace::Ecs,
+            "ec2" => ServiceNamespace::Ec2,
+            "rds" => ServiceNamespace::Rds,
+            "dynamodb" => ServiceNamespace::Dynamodb,
+            _ => ServiceNamespace::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceNamespace {
+    fn from(value: String) -> ServiceNamespace {
+        ServiceNamespace::from(value.as_str())
+    }
+}
+
+impl From<ServiceNamespace> for String {
+    fn from(value: ServiceNamespace) -> String {
+        match value {
+            ServiceNamespace::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServiceNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ServiceNamespace {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceNamespace {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ServiceNamespace, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ServiceNamespace::from(value))
+    }
+}
+
 /// <p>Represents a tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TagFilter {
@@ -467,6 +1846,64 @@ pub struct UpdateScalingPlanRequest {
     pub scaling_plan_version: i64,
 }
 
+impl UpdateScalingPlanRequest {
+    /// Returns a builder for `UpdateScalingPlanRequest`, checking its required members when built.
+    pub fn builder() -> UpdateScalingPlanRequestBuilder {
+        UpdateScalingPlanRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateScalingPlanRequest`](struct.UpdateScalingPlanRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateScalingPlanRequestBuilder {
+    application_source: Option<ApplicationSource>,
+    scaling_instructions: Option<Vec<ScalingInstruction>>,
+    scaling_plan_name: Option<String>,
+    scaling_plan_version: Option<i64>,
+}
+
+impl UpdateScalingPlanRequestBuilder {
+    /// Sets the [`application_source`](struct.UpdateScalingPlanRequest.html#structfield.application_source) member.
+    pub fn application_source<T: Into<ApplicationSource>>(mut self, value: T) -> Self {
+        self.application_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_instructions`](struct.UpdateScalingPlanRequest.html#structfield.scaling_instructions) member.
+    pub fn scaling_instructions<T: Into<Vec<ScalingInstruction>>>(mut self, value: T) -> Self {
+        self.scaling_instructions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_plan_name`](struct.UpdateScalingPlanRequest.html#structfield.scaling_plan_name) member.
+    pub fn scaling_plan_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scaling_plan_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_plan_version`](struct.UpdateScalingPlanRequest.html#structfield.scaling_plan_version) member.
+    pub fn scaling_plan_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.scaling_plan_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateScalingPlanRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateScalingPlanRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateScalingPlanRequest {
+            application_source: self.application_source,
+            scaling_instructions: self.scaling_instructions,
+            scaling_plan_name: self
+                .scaling_plan_name
+                .ok_or(::rusoto_core::BuildError::missing("scaling_plan_name"))?,
+            scaling_plan_version: self
+                .scaling_plan_version
+                .ok_or(::rusoto_core::BuildError::missing("scaling_plan_version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateScalingPlanResponse {}
@@ -788,6 +2225,54 @@ pub trait AutoscalingPlans {
         input: UpdateScalingPlanRequest,
     ) -> RusotoFuture<UpdateScalingPlanResponse, UpdateScalingPlanError>;
 }
+impl<T: ?Sized + AutoscalingPlans> AutoscalingPlans for ::std::sync::Arc<T> {
+    fn create_scaling_plan(
+        &self,
+        input: CreateScalingPlanRequest,
+    ) -> RusotoFuture<CreateScalingPlanResponse, CreateScalingPlanError> {
+        (**self).create_scaling_plan(input)
+    }
+    fn delete_scaling_plan(
+        &self,
+        input: DeleteScalingPlanRequest,
+    ) -> RusotoFuture<DeleteScalingPlanResponse, DeleteScalingPlanError> {
+        (**self).delete_scaling_plan(input)
+    }
+    fn describe_scaling_plan_resources(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> RusotoFuture<DescribeScalingPlanResourcesResponse, DescribeScalingPlanResourcesError> {
+        (**self).describe_scaling_plan_resources(input)
+    }
+    fn describe_scaling_plans(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> RusotoFuture<DescribeScalingPlansResponse, DescribeScalingPlansError> {
+        (**self).describe_scaling_plans(input)
+    }
+    fn get_scaling_plan_resource_forecast_data(
+        &self,
+        input: GetScalingPlanResourceForecastDataRequest,
+    ) -> RusotoFuture<
+        GetScalingPlanResourceForecastDataResponse,
+        GetScalingPlanResourceForecastDataError,
+    > {
+        (**self).get_scaling_plan_resource_forecast_data(input)
+    }
+    fn update_scaling_plan(
+        &self,
+        input: UpdateScalingPlanRequest,
+    ) -> RusotoFuture<UpdateScalingPlanResponse, UpdateScalingPlanError> {
+        (**self).update_scaling_plan(input)
+    }
+}
+/// The error codes AWS Auto Scaling Plans retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Auto Scaling Plans API.
 #[derive(Clone)]
 pub struct AutoscalingPlansClient {
@@ -803,6 +2288,27 @@ impl AutoscalingPlansClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_AUTOSCALING_PLANS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> AutoscalingPlansClient {
+        Self::new(region::Region::from_env_for_service("AUTOSCALING_PLANS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `autoscaling`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> AutoscalingPlansClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("autoscaling", "AUTOSCALING_PLANS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -821,7 +2327,54 @@ impl AutoscalingPlansClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AutoscalingPlansClient {
-        AutoscalingPlansClient { client, region }
+        AutoscalingPlansClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "autoscaling")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "autoscaling",
+            "autoscaling-plans",
+            "AUTOSCALING_PLANS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `AutoscalingPlans` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn AutoscalingPlans + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for AutoscalingPlansClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Auto Scaling Plans"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "autoscaling"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> AutoscalingPlansClient {
+        Self::new_with_client(client, region)
     }
 }
 
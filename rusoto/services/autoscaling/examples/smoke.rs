@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Auto Scaling clients.
+//!
+//! Makes a single read-only `DescribeAccountLimits` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_autoscaling::{Autoscaling, AutoscalingClient};
+
+fn main() {
+    let client = AutoscalingClient::from_env();
+    match client.describe_account_limits().sync() {
+        Ok(_) => println!("DescribeAccountLimits succeeded"),
+        Err(error) => {
+            eprintln!("DescribeAccountLimits failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
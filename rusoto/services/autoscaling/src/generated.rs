@@ -28,15 +28,16 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
 use xml::EventReader;
 
-struct ActivitiesDeserializer;
+pub(crate) struct ActivitiesDeserializer;
 impl ActivitiesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Activity>, XmlParseError> {
@@ -58,10 +59,10 @@ pub struct ActivitiesType {
     pub next_token: Option<String>,
 }
 
-struct ActivitiesTypeDeserializer;
+pub(crate) struct ActivitiesTypeDeserializer;
 impl ActivitiesTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ActivitiesType, XmlParseError> {
@@ -81,7 +82,7 @@ impl ActivitiesTypeDeserializer {
     }
 }
 /// <p>Describes scaling activity, which is a long-running process that represents a change to your Auto Scaling group, such as changing its size or replacing an instance.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Activity {
     /// <p>The ID of the activity.</p>
     pub activity_id: String,
@@ -94,66 +95,88 @@ pub struct Activity {
     /// <p>The details about the activity.</p>
     pub details: Option<String>,
     /// <p>The end time of the activity.</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>A value between 0 and 100 that indicates the progress of the activity.</p>
     pub progress: Option<i64>,
     /// <p>The start time of the activity.</p>
-    pub start_time: String,
+    pub start_time: IsoTimestamp,
     /// <p>The current status of the activity.</p>
-    pub status_code: String,
+    pub status_code: ScalingActivityStatusCode,
     /// <p>A friendly, more verbose description of the activity status.</p>
     pub status_message: Option<String>,
 }
 
-struct ActivityDeserializer;
+pub(crate) struct ActivityDeserializer;
 impl ActivityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Activity, XmlParseError> {
-        deserialize_elements::<_, Activity, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "ActivityId" => {
-                    obj.activity_id = XmlStringDeserializer::deserialize("ActivityId", stack)?;
-                }
-                "AutoScalingGroupName" => {
-                    obj.auto_scaling_group_name =
-                        XmlStringMaxLen255Deserializer::deserialize("AutoScalingGroupName", stack)?;
-                }
-                "Cause" => {
-                    obj.cause = XmlStringMaxLen1023Deserializer::deserialize("Cause", stack)?;
-                }
-                "Description" => {
-                    obj.description =
-                        Some(XmlStringDeserializer::deserialize("Description", stack)?);
-                }
-                "Details" => {
-                    obj.details = Some(XmlStringDeserializer::deserialize("Details", stack)?);
-                }
-                "EndTime" => {
-                    obj.end_time = Some(TimestampTypeDeserializer::deserialize("EndTime", stack)?);
-                }
-                "Progress" => {
-                    obj.progress = Some(ProgressDeserializer::deserialize("Progress", stack)?);
-                }
-                "StartTime" => {
-                    obj.start_time = TimestampTypeDeserializer::deserialize("StartTime", stack)?;
-                }
-                "StatusCode" => {
-                    obj.status_code =
-                        ScalingActivityStatusCodeDeserializer::deserialize("StatusCode", stack)?;
-                }
-                "StatusMessage" => {
-                    obj.status_message = Some(XmlStringMaxLen255Deserializer::deserialize(
-                        "StatusMessage",
-                        stack,
-                    )?);
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            Activity {
+                activity_id: Default::default(),
+                auto_scaling_group_name: Default::default(),
+                cause: Default::default(),
+                description: Default::default(),
+                details: Default::default(),
+                end_time: Default::default(),
+                progress: Default::default(),
+                start_time: Default::default(),
+                status_code: ScalingActivityStatusCode::Unknown(String::new()),
+                status_message: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "ActivityId" => {
+                        obj.activity_id = XmlStringDeserializer::deserialize("ActivityId", stack)?;
+                    }
+                    "AutoScalingGroupName" => {
+                        obj.auto_scaling_group_name = XmlStringMaxLen255Deserializer::deserialize(
+                            "AutoScalingGroupName",
+                            stack,
+                        )?;
+                    }
+                    "Cause" => {
+                        obj.cause = XmlStringMaxLen1023Deserializer::deserialize("Cause", stack)?;
+                    }
+                    "Description" => {
+                        obj.description =
+                            Some(XmlStringDeserializer::deserialize("Description", stack)?);
+                    }
+                    "Details" => {
+                        obj.details = Some(XmlStringDeserializer::deserialize("Details", stack)?);
+                    }
+                    "EndTime" => {
+                        obj.end_time =
+                            Some(TimestampTypeDeserializer::deserialize("EndTime", stack)?);
+                    }
+                    "Progress" => {
+                        obj.progress = Some(ProgressDeserializer::deserialize("Progress", stack)?);
+                    }
+                    "StartTime" => {
+                        obj.start_time =
+                            TimestampTypeDeserializer::deserialize("StartTime", stack)?;
+                    }
+                    "StatusCode" => {
+                        obj.status_code = ScalingActivityStatusCodeDeserializer::deserialize(
+                            "StatusCode",
+                            stack,
+                        )?;
+                    }
+                    "StatusMessage" => {
+                        obj.status_message = Some(XmlStringMaxLen255Deserializer::deserialize(
+                            "StatusMessage",
+                            stack,
+                        )?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -174,10 +197,10 @@ pub struct ActivityType {
     pub activity: Option<Activity>,
 }
 
-struct ActivityTypeDeserializer;
+pub(crate) struct ActivityTypeDeserializer;
 impl ActivityTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ActivityType, XmlParseError> {
@@ -199,10 +222,10 @@ pub struct AdjustmentType {
     pub adjustment_type: Option<String>,
 }
 
-struct AdjustmentTypeDeserializer;
+pub(crate) struct AdjustmentTypeDeserializer;
 impl AdjustmentTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AdjustmentType, XmlParseError> {
@@ -220,10 +243,10 @@ impl AdjustmentTypeDeserializer {
         })
     }
 }
-struct AdjustmentTypesDeserializer;
+pub(crate) struct AdjustmentTypesDeserializer;
 impl AdjustmentTypesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AdjustmentType>, XmlParseError> {
@@ -246,10 +269,13 @@ pub struct Alarm {
     pub alarm_name: Option<String>,
 }
 
-struct AlarmDeserializer;
+pub(crate) struct AlarmDeserializer;
 impl AlarmDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Alarm, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Alarm, XmlParseError> {
         deserialize_elements::<_, Alarm, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "AlarmARN" => {
@@ -267,10 +293,10 @@ impl AlarmDeserializer {
         })
     }
 }
-struct AlarmsDeserializer;
+pub(crate) struct AlarmsDeserializer;
 impl AlarmsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Alarm>, XmlParseError> {
@@ -284,10 +310,13 @@ impl AlarmsDeserializer {
         })
     }
 }
-struct AsciiStringMaxLen255Deserializer;
+pub(crate) struct AsciiStringMaxLen255Deserializer;
 impl AsciiStringMaxLen255Deserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -295,10 +324,13 @@ impl AsciiStringMaxLen255Deserializer {
         Ok(obj)
     }
 }
-struct AssociatePublicIpAddressDeserializer;
+pub(crate) struct AssociatePublicIpAddressDeserializer;
 impl AssociatePublicIpAddressDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -314,6 +346,44 @@ pub struct AttachInstancesQuery {
     pub instance_ids: Option<Vec<String>>,
 }
 
+impl AttachInstancesQuery {
+    /// Returns a builder for `AttachInstancesQuery`, checking its required members when built.
+    pub fn builder() -> AttachInstancesQueryBuilder {
+        AttachInstancesQueryBuilder::default()
+    }
+}
+
+/// Builds a [`AttachInstancesQuery`](struct.AttachInstancesQuery.html).
+#[derive(Default, Debug)]
+pub struct AttachInstancesQueryBuilder {
+    auto_scaling_group_name: Option<String>,
+    instance_ids: Option<Vec<String>>,
+}
+
+impl AttachInstancesQueryBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.AttachInstancesQuery.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_ids`](struct.AttachInstancesQuery.html#structfield.instance_ids) member.
+    pub fn instance_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachInstancesQuery`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AttachInstancesQuery, ::rusoto_core::BuildError> {
+        Ok(AttachInstancesQuery {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            instance_ids: self.instance_ids,
+        })
+    }
+}
+
 /// Serialize `AttachInstancesQuery` contents to a `SignedRequest`.
 struct AttachInstancesQuerySerializer;
 impl AttachInstancesQuerySerializer {
@@ -340,10 +410,10 @@ impl AttachInstancesQuerySerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AttachLoadBalancerTargetGroupsResultType {}
 
-struct AttachLoadBalancerTargetGroupsResultTypeDeserializer;
+pub(crate) struct AttachLoadBalancerTargetGroupsResultTypeDeserializer;
 impl AttachLoadBalancerTargetGroupsResultTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AttachLoadBalancerTargetGroupsResultType, XmlParseError> {
@@ -364,6 +434,48 @@ pub struct AttachLoadBalancerTargetGroupsType {
     pub target_group_ar_ns: Vec<String>,
 }
 
+impl AttachLoadBalancerTargetGroupsType {
+    /// Returns a builder for `AttachLoadBalancerTargetGroupsType`, checking its required members when built.
+    pub fn builder() -> AttachLoadBalancerTargetGroupsTypeBuilder {
+        AttachLoadBalancerTargetGroupsTypeBuilder::default()
+    }
+}
+
+/// Builds a [`AttachLoadBalancerTargetGroupsType`](struct.AttachLoadBalancerTargetGroupsType.html).
+#[derive(Default, Debug)]
+pub struct AttachLoadBalancerTargetGroupsTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    target_group_ar_ns: Option<Vec<String>>,
+}
+
+impl AttachLoadBalancerTargetGroupsTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.AttachLoadBalancerTargetGroupsType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_group_ar_ns`](struct.AttachLoadBalancerTargetGroupsType.html#structfield.target_group_ar_ns) member.
+    pub fn target_group_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.target_group_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachLoadBalancerTargetGroupsType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachLoadBalancerTargetGroupsType, ::rusoto_core::BuildError> {
+        Ok(AttachLoadBalancerTargetGroupsType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            target_group_ar_ns: self
+                .target_group_ar_ns
+                .ok_or(::rusoto_core::BuildError::missing("target_group_ar_ns"))?,
+        })
+    }
+}
+
 /// Serialize `AttachLoadBalancerTargetGroupsType` contents to a `SignedRequest`.
 struct AttachLoadBalancerTargetGroupsTypeSerializer;
 impl AttachLoadBalancerTargetGroupsTypeSerializer {
@@ -388,10 +500,10 @@ impl AttachLoadBalancerTargetGroupsTypeSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AttachLoadBalancersResultType {}
 
-struct AttachLoadBalancersResultTypeDeserializer;
+pub(crate) struct AttachLoadBalancersResultTypeDeserializer;
 impl AttachLoadBalancersResultTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AttachLoadBalancersResultType, XmlParseError> {
@@ -412,6 +524,48 @@ pub struct AttachLoadBalancersType {
     pub load_balancer_names: Vec<String>,
 }
 
+impl AttachLoadBalancersType {
+    /// Returns a builder for `AttachLoadBalancersType`, checking its required members when built.
+    pub fn builder() -> AttachLoadBalancersTypeBuilder {
+        AttachLoadBalancersTypeBuilder::default()
+    }
+}
+
+/// Builds a [`AttachLoadBalancersType`](struct.AttachLoadBalancersType.html).
+#[derive(Default, Debug)]
+pub struct AttachLoadBalancersTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    load_balancer_names: Option<Vec<String>>,
+}
+
+impl AttachLoadBalancersTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.AttachLoadBalancersType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_names`](struct.AttachLoadBalancersType.html#structfield.load_balancer_names) member.
+    pub fn load_balancer_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.load_balancer_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachLoadBalancersType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachLoadBalancersType, ::rusoto_core::BuildError> {
+        Ok(AttachLoadBalancersType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            load_balancer_names: self
+                .load_balancer_names
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_names"))?,
+        })
+    }
+}
+
 /// Serialize `AttachLoadBalancersType` contents to a `SignedRequest`.
 struct AttachLoadBalancersTypeSerializer;
 impl AttachLoadBalancersTypeSerializer {
@@ -443,7 +597,7 @@ pub struct AutoScalingGroup {
     /// <p>One or more Availability Zones for the group.</p>
     pub availability_zones: Vec<String>,
     /// <p>The date and time the group was created.</p>
-    pub created_time: String,
+    pub created_time: IsoTimestamp,
     /// <p>The amount of time, in seconds, after a scaling activity completes before another scaling activity can start.</p>
     pub default_cooldown: i64,
     /// <p>The desired size of the group.</p>
@@ -488,10 +642,10 @@ pub struct AutoScalingGroup {
     pub vpc_zone_identifier: Option<String>,
 }
 
-struct AutoScalingGroupDeserializer;
+pub(crate) struct AutoScalingGroupDeserializer;
 impl AutoScalingGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AutoScalingGroup, XmlParseError> {
@@ -640,10 +794,13 @@ impl AutoScalingGroupDeserializer {
         })
     }
 }
-struct AutoScalingGroupDesiredCapacityDeserializer;
+pub(crate) struct AutoScalingGroupDesiredCapacityDeserializer;
 impl AutoScalingGroupDesiredCapacityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -651,10 +808,13 @@ impl AutoScalingGroupDesiredCapacityDeserializer {
         Ok(obj)
     }
 }
-struct AutoScalingGroupMaxSizeDeserializer;
+pub(crate) struct AutoScalingGroupMaxSizeDeserializer;
 impl AutoScalingGroupMaxSizeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -662,10 +822,13 @@ impl AutoScalingGroupMaxSizeDeserializer {
         Ok(obj)
     }
 }
-struct AutoScalingGroupMinSizeDeserializer;
+pub(crate) struct AutoScalingGroupMinSizeDeserializer;
 impl AutoScalingGroupMinSizeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -695,6 +858,52 @@ pub struct AutoScalingGroupNamesType {
     pub next_token: Option<String>,
 }
 
+impl AutoScalingGroupNamesType {
+    /// Returns a builder for `AutoScalingGroupNamesType`, checking its required members when built.
+    pub fn builder() -> AutoScalingGroupNamesTypeBuilder {
+        AutoScalingGroupNamesTypeBuilder::default()
+    }
+}
+
+/// Builds a [`AutoScalingGroupNamesType`](struct.AutoScalingGroupNamesType.html).
+#[derive(Default, Debug)]
+pub struct AutoScalingGroupNamesTypeBuilder {
+    auto_scaling_group_names: Option<Vec<String>>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl AutoScalingGroupNamesTypeBuilder {
+    /// Sets the [`auto_scaling_group_names`](struct.AutoScalingGroupNamesType.html#structfield.auto_scaling_group_names) member.
+    pub fn auto_scaling_group_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.AutoScalingGroupNamesType.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.AutoScalingGroupNamesType.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `AutoScalingGroupNamesType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AutoScalingGroupNamesType, ::rusoto_core::BuildError> {
+        Ok(AutoScalingGroupNamesType {
+            auto_scaling_group_names: self.auto_scaling_group_names,
+            max_records: self.max_records,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `AutoScalingGroupNamesType` contents to a `SignedRequest`.
 struct AutoScalingGroupNamesTypeSerializer;
 impl AutoScalingGroupNamesTypeSerializer {
@@ -720,10 +929,10 @@ impl AutoScalingGroupNamesTypeSerializer {
     }
 }
 
-struct AutoScalingGroupsDeserializer;
+pub(crate) struct AutoScalingGroupsDeserializer;
 impl AutoScalingGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AutoScalingGroup>, XmlParseError> {
@@ -745,10 +954,10 @@ pub struct AutoScalingGroupsType {
     pub next_token: Option<String>,
 }
 
-struct AutoScalingGroupsTypeDeserializer;
+pub(crate) struct AutoScalingGroupsTypeDeserializer;
 impl AutoScalingGroupsTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AutoScalingGroupsType, XmlParseError> {
@@ -791,10 +1000,10 @@ pub struct AutoScalingInstanceDetails {
     pub protected_from_scale_in: bool,
 }
 
-struct AutoScalingInstanceDetailsDeserializer;
+pub(crate) struct AutoScalingInstanceDetailsDeserializer;
 impl AutoScalingInstanceDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AutoScalingInstanceDetails, XmlParseError> {
@@ -852,10 +1061,10 @@ impl AutoScalingInstanceDetailsDeserializer {
         )
     }
 }
-struct AutoScalingInstancesDeserializer;
+pub(crate) struct AutoScalingInstancesDeserializer;
 impl AutoScalingInstancesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AutoScalingInstanceDetails>, XmlParseError> {
@@ -879,10 +1088,10 @@ pub struct AutoScalingInstancesType {
     pub next_token: Option<String>,
 }
 
-struct AutoScalingInstancesTypeDeserializer;
+pub(crate) struct AutoScalingInstancesTypeDeserializer;
 impl AutoScalingInstancesTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AutoScalingInstancesType, XmlParseError> {
@@ -910,10 +1119,10 @@ impl AutoScalingInstancesTypeDeserializer {
         )
     }
 }
-struct AutoScalingNotificationTypesDeserializer;
+pub(crate) struct AutoScalingNotificationTypesDeserializer;
 impl AutoScalingNotificationTypesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -941,10 +1150,10 @@ impl AutoScalingNotificationTypesSerializer {
     }
 }
 
-struct AvailabilityZonesDeserializer;
+pub(crate) struct AvailabilityZonesDeserializer;
 impl AvailabilityZonesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -978,10 +1187,10 @@ pub struct BatchDeleteScheduledActionAnswer {
     pub failed_scheduled_actions: Option<Vec<FailedScheduledUpdateGroupActionRequest>>,
 }
 
-struct BatchDeleteScheduledActionAnswerDeserializer;
+pub(crate) struct BatchDeleteScheduledActionAnswerDeserializer;
 impl BatchDeleteScheduledActionAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<BatchDeleteScheduledActionAnswer, XmlParseError> {
@@ -1013,6 +1222,48 @@ pub struct BatchDeleteScheduledActionType {
     pub scheduled_action_names: Vec<String>,
 }
 
+impl BatchDeleteScheduledActionType {
+    /// Returns a builder for `BatchDeleteScheduledActionType`, checking its required members when built.
+    pub fn builder() -> BatchDeleteScheduledActionTypeBuilder {
+        BatchDeleteScheduledActionTypeBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDeleteScheduledActionType`](struct.BatchDeleteScheduledActionType.html).
+#[derive(Default, Debug)]
+pub struct BatchDeleteScheduledActionTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    scheduled_action_names: Option<Vec<String>>,
+}
+
+impl BatchDeleteScheduledActionTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.BatchDeleteScheduledActionType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduled_action_names`](struct.BatchDeleteScheduledActionType.html#structfield.scheduled_action_names) member.
+    pub fn scheduled_action_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.scheduled_action_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDeleteScheduledActionType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDeleteScheduledActionType, ::rusoto_core::BuildError> {
+        Ok(BatchDeleteScheduledActionType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            scheduled_action_names: self
+                .scheduled_action_names
+                .ok_or(::rusoto_core::BuildError::missing("scheduled_action_names"))?,
+        })
+    }
+}
+
 /// Serialize `BatchDeleteScheduledActionType` contents to a `SignedRequest`.
 struct BatchDeleteScheduledActionTypeSerializer;
 impl BatchDeleteScheduledActionTypeSerializer {
@@ -1040,10 +1291,10 @@ pub struct BatchPutScheduledUpdateGroupActionAnswer {
     pub failed_scheduled_update_group_actions: Option<Vec<FailedScheduledUpdateGroupActionRequest>>,
 }
 
-struct BatchPutScheduledUpdateGroupActionAnswerDeserializer;
+pub(crate) struct BatchPutScheduledUpdateGroupActionAnswerDeserializer;
 impl BatchPutScheduledUpdateGroupActionAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<BatchPutScheduledUpdateGroupActionAnswer, XmlParseError> {
@@ -1077,6 +1328,52 @@ pub struct BatchPutScheduledUpdateGroupActionType {
     pub scheduled_update_group_actions: Vec<ScheduledUpdateGroupActionRequest>,
 }
 
+impl BatchPutScheduledUpdateGroupActionType {
+    /// Returns a builder for `BatchPutScheduledUpdateGroupActionType`, checking its required members when built.
+    pub fn builder() -> BatchPutScheduledUpdateGroupActionTypeBuilder {
+        BatchPutScheduledUpdateGroupActionTypeBuilder::default()
+    }
+}
+
+/// Builds a [`BatchPutScheduledUpdateGroupActionType`](struct.BatchPutScheduledUpdateGroupActionType.html).
+#[derive(Default, Debug)]
+pub struct BatchPutScheduledUpdateGroupActionTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    scheduled_update_group_actions: Option<Vec<ScheduledUpdateGroupActionRequest>>,
+}
+
+impl BatchPutScheduledUpdateGroupActionTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.BatchPutScheduledUpdateGroupActionType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduled_update_group_actions`](struct.BatchPutScheduledUpdateGroupActionType.html#structfield.scheduled_update_group_actions) member.
+    pub fn scheduled_update_group_actions<T: Into<Vec<ScheduledUpdateGroupActionRequest>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.scheduled_update_group_actions = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchPutScheduledUpdateGroupActionType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchPutScheduledUpdateGroupActionType, ::rusoto_core::BuildError>
+    {
+        Ok(BatchPutScheduledUpdateGroupActionType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            scheduled_update_group_actions: self.scheduled_update_group_actions.ok_or(
+                ::rusoto_core::BuildError::missing("scheduled_update_group_actions"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `BatchPutScheduledUpdateGroupActionType` contents to a `SignedRequest`.
 struct BatchPutScheduledUpdateGroupActionTypeSerializer;
 impl BatchPutScheduledUpdateGroupActionTypeSerializer {
@@ -1098,10 +1395,13 @@ impl BatchPutScheduledUpdateGroupActionTypeSerializer {
     }
 }
 
-struct BlockDeviceEbsDeleteOnTerminationDeserializer;
+pub(crate) struct BlockDeviceEbsDeleteOnTerminationDeserializer;
 impl BlockDeviceEbsDeleteOnTerminationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1109,10 +1409,13 @@ impl BlockDeviceEbsDeleteOnTerminationDeserializer {
         Ok(obj)
     }
 }
-struct BlockDeviceEbsEncryptedDeserializer;
+pub(crate) struct BlockDeviceEbsEncryptedDeserializer;
 impl BlockDeviceEbsEncryptedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1120,10 +1423,13 @@ impl BlockDeviceEbsEncryptedDeserializer {
         Ok(obj)
     }
 }
-struct BlockDeviceEbsIopsDeserializer;
+pub(crate) struct BlockDeviceEbsIopsDeserializer;
 impl BlockDeviceEbsIopsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1131,10 +1437,13 @@ impl BlockDeviceEbsIopsDeserializer {
         Ok(obj)
     }
 }
-struct BlockDeviceEbsVolumeSizeDeserializer;
+pub(crate) struct BlockDeviceEbsVolumeSizeDeserializer;
 impl BlockDeviceEbsVolumeSizeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1142,10 +1451,13 @@ impl BlockDeviceEbsVolumeSizeDeserializer {
         Ok(obj)
     }
 }
-struct BlockDeviceEbsVolumeTypeDeserializer;
+pub(crate) struct BlockDeviceEbsVolumeTypeDeserializer;
 impl BlockDeviceEbsVolumeTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1166,10 +1478,10 @@ pub struct BlockDeviceMapping {
     pub virtual_name: Option<String>,
 }
 
-struct BlockDeviceMappingDeserializer;
+pub(crate) struct BlockDeviceMappingDeserializer;
 impl BlockDeviceMappingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<BlockDeviceMapping, XmlParseError> {
@@ -1220,10 +1532,10 @@ impl BlockDeviceMappingSerializer {
     }
 }
 
-struct BlockDeviceMappingsDeserializer;
+pub(crate) struct BlockDeviceMappingsDeserializer;
 impl BlockDeviceMappingsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<BlockDeviceMapping>, XmlParseError> {
@@ -1251,10 +1563,10 @@ impl BlockDeviceMappingsSerializer {
     }
 }
 
-struct ClassicLinkVPCSecurityGroupsDeserializer;
+pub(crate) struct ClassicLinkVPCSecurityGroupsDeserializer;
 impl ClassicLinkVPCSecurityGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -1285,10 +1597,10 @@ impl ClassicLinkVPCSecurityGroupsSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CompleteLifecycleActionAnswer {}
 
-struct CompleteLifecycleActionAnswerDeserializer;
+pub(crate) struct CompleteLifecycleActionAnswerDeserializer;
 impl CompleteLifecycleActionAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CompleteLifecycleActionAnswer, XmlParseError> {
@@ -1315,6 +1627,74 @@ pub struct CompleteLifecycleActionType {
     pub lifecycle_hook_name: String,
 }
 
+impl CompleteLifecycleActionType {
+    /// Returns a builder for `CompleteLifecycleActionType`, checking its required members when built.
+    pub fn builder() -> CompleteLifecycleActionTypeBuilder {
+        CompleteLifecycleActionTypeBuilder::default()
+    }
+}
+
+/// Builds a [`CompleteLifecycleActionType`](struct.CompleteLifecycleActionType.html).
+#[derive(Default, Debug)]
+pub struct CompleteLifecycleActionTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    instance_id: Option<String>,
+    lifecycle_action_result: Option<String>,
+    lifecycle_action_token: Option<String>,
+    lifecycle_hook_name: Option<String>,
+}
+
+impl CompleteLifecycleActionTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.CompleteLifecycleActionType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.CompleteLifecycleActionType.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_action_result`](struct.CompleteLifecycleActionType.html#structfield.lifecycle_action_result) member.
+    pub fn lifecycle_action_result<T: Into<String>>(mut self, value: T) -> Self {
+        self.lifecycle_action_result = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_action_token`](struct.CompleteLifecycleActionType.html#structfield.lifecycle_action_token) member.
+    pub fn lifecycle_action_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.lifecycle_action_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_hook_name`](struct.CompleteLifecycleActionType.html#structfield.lifecycle_hook_name) member.
+    pub fn lifecycle_hook_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.lifecycle_hook_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CompleteLifecycleActionType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CompleteLifecycleActionType, ::rusoto_core::BuildError> {
+        Ok(CompleteLifecycleActionType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            instance_id: self.instance_id,
+            lifecycle_action_result: self.lifecycle_action_result.ok_or(
+                ::rusoto_core::BuildError::missing("lifecycle_action_result"),
+            )?,
+            lifecycle_action_token: self.lifecycle_action_token,
+            lifecycle_hook_name: self
+                .lifecycle_hook_name
+                .ok_or(::rusoto_core::BuildError::missing("lifecycle_hook_name"))?,
+        })
+    }
+}
+
 /// Serialize `CompleteLifecycleActionType` contents to a `SignedRequest`.
 struct CompleteLifecycleActionTypeSerializer;
 impl CompleteLifecycleActionTypeSerializer {
@@ -1348,10 +1728,13 @@ impl CompleteLifecycleActionTypeSerializer {
     }
 }
 
-struct CooldownDeserializer;
+pub(crate) struct CooldownDeserializer;
 impl CooldownDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1405,6 +1788,205 @@ pub struct CreateAutoScalingGroupType {
     pub vpc_zone_identifier: Option<String>,
 }
 
+impl CreateAutoScalingGroupType {
+    /// Returns a builder for `CreateAutoScalingGroupType`, checking its required members when built.
+    pub fn builder() -> CreateAutoScalingGroupTypeBuilder {
+        CreateAutoScalingGroupTypeBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAutoScalingGroupType`](struct.CreateAutoScalingGroupType.html).
+#[derive(Default, Debug)]
+pub struct CreateAutoScalingGroupTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    availability_zones: Option<Vec<String>>,
+    default_cooldown: Option<i64>,
+    desired_capacity: Option<i64>,
+    health_check_grace_period: Option<i64>,
+    health_check_type: Option<String>,
+    instance_id: Option<String>,
+    launch_configuration_name: Option<String>,
+    launch_template: Option<LaunchTemplateSpecification>,
+    lifecycle_hook_specification_list: Option<Vec<LifecycleHookSpecification>>,
+    load_balancer_names: Option<Vec<String>>,
+    max_size: Option<i64>,
+    min_size: Option<i64>,
+    mixed_instances_policy: Option<MixedInstancesPolicy>,
+    new_instances_protected_from_scale_in: Option<bool>,
+    placement_group: Option<String>,
+    service_linked_role_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_group_ar_ns: Option<Vec<String>>,
+    termination_policies: Option<Vec<String>>,
+    vpc_zone_identifier: Option<String>,
+}
+
+impl CreateAutoScalingGroupTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.CreateAutoScalingGroupType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zones`](struct.CreateAutoScalingGroupType.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_cooldown`](struct.CreateAutoScalingGroupType.html#structfield.default_cooldown) member.
+    pub fn default_cooldown<T: Into<i64>>(mut self, value: T) -> Self {
+        self.default_cooldown = Some(value.into());
+        self
+    }
+
+    /// Sets the [`desired_capacity`](struct.CreateAutoScalingGroupType.html#structfield.desired_capacity) member.
+    pub fn desired_capacity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.desired_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_grace_period`](struct.CreateAutoScalingGroupType.html#structfield.health_check_grace_period) member.
+    pub fn health_check_grace_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.health_check_grace_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_type`](struct.CreateAutoScalingGroupType.html#structfield.health_check_type) member.
+    pub fn health_check_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_check_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.CreateAutoScalingGroupType.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`launch_configuration_name`](struct.CreateAutoScalingGroupType.html#structfield.launch_configuration_name) member.
+    pub fn launch_configuration_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.launch_configuration_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`launch_template`](struct.CreateAutoScalingGroupType.html#structfield.launch_template) member.
+    pub fn launch_template<T: Into<LaunchTemplateSpecification>>(mut self, value: T) -> Self {
+        self.launch_template = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_hook_specification_list`](struct.CreateAutoScalingGroupType.html#structfield.lifecycle_hook_specification_list) member.
+    pub fn lifecycle_hook_specification_list<T: Into<Vec<LifecycleHookSpecification>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.lifecycle_hook_specification_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_names`](struct.CreateAutoScalingGroupType.html#structfield.load_balancer_names) member.
+    pub fn load_balancer_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.load_balancer_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_size`](struct.CreateAutoScalingGroupType.html#structfield.max_size) member.
+    pub fn max_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`min_size`](struct.CreateAutoScalingGroupType.html#structfield.min_size) member.
+    pub fn min_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.min_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mixed_instances_policy`](struct.CreateAutoScalingGroupType.html#structfield.mixed_instances_policy) member.
+    pub fn mixed_instances_policy<T: Into<MixedInstancesPolicy>>(mut self, value: T) -> Self {
+        self.mixed_instances_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_instances_protected_from_scale_in`](struct.CreateAutoScalingGroupType.html#structfield.new_instances_protected_from_scale_in) member.
+    pub fn new_instances_protected_from_scale_in<T: Into<bool>>(mut self, value: T) -> Self {
+        self.new_instances_protected_from_scale_in = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_group`](struct.CreateAutoScalingGroupType.html#structfield.placement_group) member.
+    pub fn placement_group<T: Into<String>>(mut self, value: T) -> Self {
+        self.placement_group = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_linked_role_arn`](struct.CreateAutoScalingGroupType.html#structfield.service_linked_role_arn) member.
+    pub fn service_linked_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_linked_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateAutoScalingGroupType.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_group_ar_ns`](struct.CreateAutoScalingGroupType.html#structfield.target_group_ar_ns) member.
+    pub fn target_group_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.target_group_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`termination_policies`](struct.CreateAutoScalingGroupType.html#structfield.termination_policies) member.
+    pub fn termination_policies<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.termination_policies = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_zone_identifier`](struct.CreateAutoScalingGroupType.html#structfield.vpc_zone_identifier) member.
+    pub fn vpc_zone_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpc_zone_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAutoScalingGroupType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateAutoScalingGroupType, ::rusoto_core::BuildError> {
+        Ok(CreateAutoScalingGroupType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            availability_zones: self.availability_zones,
+            default_cooldown: self.default_cooldown,
+            desired_capacity: self.desired_capacity,
+            health_check_grace_period: self.health_check_grace_period,
+            health_check_type: self.health_check_type,
+            instance_id: self.instance_id,
+            launch_configuration_name: self.launch_configuration_name,
+            launch_template: self.launch_template,
+            lifecycle_hook_specification_list: self.lifecycle_hook_specification_list,
+            load_balancer_names: self.load_balancer_names,
+            max_size: self
+                .max_size
+                .ok_or(::rusoto_core::BuildError::missing("max_size"))?,
+            min_size: self
+                .min_size
+                .ok_or(::rusoto_core::BuildError::missing("min_size"))?,
+            mixed_instances_policy: self.mixed_instances_policy,
+            new_instances_protected_from_scale_in: self.new_instances_protected_from_scale_in,
+            placement_group: self.placement_group,
+            service_linked_role_arn: self.service_linked_role_arn,
+            tags: self.tags,
+            target_group_ar_ns: self.target_group_ar_ns,
+            termination_policies: self.termination_policies,
+            vpc_zone_identifier: self.vpc_zone_identifier,
+        })
+    }
+}
+
 /// Serialize `CreateAutoScalingGroupType` contents to a `SignedRequest`.
 struct CreateAutoScalingGroupTypeSerializer;
 impl CreateAutoScalingGroupTypeSerializer {
@@ -1557,39 +2139,207 @@ pub struct CreateLaunchConfigurationType {
     pub user_data: Option<String>,
 }
 
-/// Serialize `CreateLaunchConfigurationType` contents to a `SignedRequest`.
-struct CreateLaunchConfigurationTypeSerializer;
-impl CreateLaunchConfigurationTypeSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &CreateLaunchConfigurationType) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl CreateLaunchConfigurationType {
+    /// Returns a builder for `CreateLaunchConfigurationType`, checking its required members when built.
+    pub fn builder() -> CreateLaunchConfigurationTypeBuilder {
+        CreateLaunchConfigurationTypeBuilder::default()
+    }
+}
 
-        if let Some(ref field_value) = obj.associate_public_ip_address {
-            params.put(
-                &format!("{}{}", prefix, "AssociatePublicIpAddress"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.block_device_mappings {
-            BlockDeviceMappingsSerializer::serialize(
-                params,
-                &format!("{}{}", prefix, "BlockDeviceMappings"),
-                field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.classic_link_vpc_id {
-            params.put(&format!("{}{}", prefix, "ClassicLinkVPCId"), &field_value);
-        }
-        if let Some(ref field_value) = obj.classic_link_vpc_security_groups {
-            ClassicLinkVPCSecurityGroupsSerializer::serialize(
-                params,
-                &format!("{}{}", prefix, "ClassicLinkVPCSecurityGroups"),
-                field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.ebs_optimized {
+/// Builds a [`CreateLaunchConfigurationType`](struct.CreateLaunchConfigurationType.html).
+#[derive(Default, Debug)]
+pub struct CreateLaunchConfigurationTypeBuilder {
+    associate_public_ip_address: Option<bool>,
+    block_device_mappings: Option<Vec<BlockDeviceMapping>>,
+    classic_link_vpc_id: Option<String>,
+    classic_link_vpc_security_groups: Option<Vec<String>>,
+    ebs_optimized: Option<bool>,
+    iam_instance_profile: Option<String>,
+    image_id: Option<String>,
+    instance_id: Option<String>,
+    instance_monitoring: Option<InstanceMonitoring>,
+    instance_type: Option<String>,
+    kernel_id: Option<String>,
+    key_name: Option<String>,
+    launch_configuration_name: Option<String>,
+    placement_tenancy: Option<String>,
+    ramdisk_id: Option<String>,
+    security_groups: Option<Vec<String>>,
+    spot_price: Option<String>,
+    user_data: Option<String>,
+}
+
+impl CreateLaunchConfigurationTypeBuilder {
+    /// Sets the [`associate_public_ip_address`](struct.CreateLaunchConfigurationType.html#structfield.associate_public_ip_address) member.
+    pub fn associate_public_ip_address<T: Into<bool>>(mut self, value: T) -> Self {
+        self.associate_public_ip_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`block_device_mappings`](struct.CreateLaunchConfigurationType.html#structfield.block_device_mappings) member.
+    pub fn block_device_mappings<T: Into<Vec<BlockDeviceMapping>>>(mut self, value: T) -> Self {
+        self.block_device_mappings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`classic_link_vpc_id`](struct.CreateLaunchConfigurationType.html#structfield.classic_link_vpc_id) member.
+    pub fn classic_link_vpc_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.classic_link_vpc_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`classic_link_vpc_security_groups`](struct.CreateLaunchConfigurationType.html#structfield.classic_link_vpc_security_groups) member.
+    pub fn classic_link_vpc_security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.classic_link_vpc_security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ebs_optimized`](struct.CreateLaunchConfigurationType.html#structfield.ebs_optimized) member.
+    pub fn ebs_optimized<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ebs_optimized = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_instance_profile`](struct.CreateLaunchConfigurationType.html#structfield.iam_instance_profile) member.
+    pub fn iam_instance_profile<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_instance_profile = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_id`](struct.CreateLaunchConfigurationType.html#structfield.image_id) member.
+    pub fn image_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.CreateLaunchConfigurationType.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_monitoring`](struct.CreateLaunchConfigurationType.html#structfield.instance_monitoring) member.
+    pub fn instance_monitoring<T: Into<InstanceMonitoring>>(mut self, value: T) -> Self {
+        self.instance_monitoring = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_type`](struct.CreateLaunchConfigurationType.html#structfield.instance_type) member.
+    pub fn instance_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kernel_id`](struct.CreateLaunchConfigurationType.html#structfield.kernel_id) member.
+    pub fn kernel_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kernel_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_name`](struct.CreateLaunchConfigurationType.html#structfield.key_name) member.
+    pub fn key_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`launch_configuration_name`](struct.CreateLaunchConfigurationType.html#structfield.launch_configuration_name) member.
+    pub fn launch_configuration_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.launch_configuration_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_tenancy`](struct.CreateLaunchConfigurationType.html#structfield.placement_tenancy) member.
+    pub fn placement_tenancy<T: Into<String>>(mut self, value: T) -> Self {
+        self.placement_tenancy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ramdisk_id`](struct.CreateLaunchConfigurationType.html#structfield.ramdisk_id) member.
+    pub fn ramdisk_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ramdisk_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_groups`](struct.CreateLaunchConfigurationType.html#structfield.security_groups) member.
+    pub fn security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`spot_price`](struct.CreateLaunchConfigurationType.html#structfield.spot_price) member.
+    pub fn spot_price<T: Into<String>>(mut self, value: T) -> Self {
+        self.spot_price = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_data`](struct.CreateLaunchConfigurationType.html#structfield.user_data) member.
+    pub fn user_data<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_data = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLaunchConfigurationType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLaunchConfigurationType, ::rusoto_core::BuildError> {
+        Ok(CreateLaunchConfigurationType {
+            associate_public_ip_address: self.associate_public_ip_address,
+            block_device_mappings: self.block_device_mappings,
+            classic_link_vpc_id: self.classic_link_vpc_id,
+            classic_link_vpc_security_groups: self.classic_link_vpc_security_groups,
+            ebs_optimized: self.ebs_optimized,
+            iam_instance_profile: self.iam_instance_profile,
+            image_id: self.image_id,
+            instance_id: self.instance_id,
+            instance_monitoring: self.instance_monitoring,
+            instance_type: self.instance_type,
+            kernel_id: self.kernel_id,
+            key_name: self.key_name,
+            launch_configuration_name: self.launch_configuration_name.ok_or(
+                ::rusoto_core::BuildError::missing("launch_configuration_name"),
+            )?,
+            placement_tenancy: self.placement_tenancy,
+            ramdisk_id: self.ramdisk_id,
+            security_groups: self.security_groups,
+            spot_price: self.spot_price,
+            user_data: self.user_data,
+        })
+    }
+}
+
+/// Serialize `CreateLaunchConfigurationType` contents to a `SignedRequest`.
+struct CreateLaunchConfigurationTypeSerializer;
+impl CreateLaunchConfigurationTypeSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &CreateLaunchConfigurationType) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.associate_public_ip_address {
+            params.put(
+                &format!("{}{}", prefix, "AssociatePublicIpAddress"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.block_device_mappings {
+            BlockDeviceMappingsSerializer::serialize(
+                params,
+                &format!("{}{}", prefix, "BlockDeviceMappings"),
+                field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.classic_link_vpc_id {
+            params.put(&format!("{}{}", prefix, "ClassicLinkVPCId"), &field_value);
+        }
+        if let Some(ref field_value) = obj.classic_link_vpc_security_groups {
+            ClassicLinkVPCSecurityGroupsSerializer::serialize(
+                params,
+                &format!("{}{}", prefix, "ClassicLinkVPCSecurityGroups"),
+                field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.ebs_optimized {
             params.put(&format!("{}{}", prefix, "EbsOptimized"), &field_value);
         }
         if let Some(ref field_value) = obj.iam_instance_profile {
@@ -1649,6 +2399,36 @@ pub struct CreateOrUpdateTagsType {
     pub tags: Vec<Tag>,
 }
 
+impl CreateOrUpdateTagsType {
+    /// Returns a builder for `CreateOrUpdateTagsType`, checking its required members when built.
+    pub fn builder() -> CreateOrUpdateTagsTypeBuilder {
+        CreateOrUpdateTagsTypeBuilder::default()
+    }
+}
+
+/// Builds a [`CreateOrUpdateTagsType`](struct.CreateOrUpdateTagsType.html).
+#[derive(Default, Debug)]
+pub struct CreateOrUpdateTagsTypeBuilder {
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateOrUpdateTagsTypeBuilder {
+    /// Sets the [`tags`](struct.CreateOrUpdateTagsType.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateOrUpdateTagsType`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateOrUpdateTagsType, ::rusoto_core::BuildError> {
+        Ok(CreateOrUpdateTagsType {
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `CreateOrUpdateTagsType` contents to a `SignedRequest`.
 struct CreateOrUpdateTagsTypeSerializer;
 impl CreateOrUpdateTagsTypeSerializer {
@@ -1663,7 +2443,7 @@ impl CreateOrUpdateTagsTypeSerializer {
 }
 
 /// <p>Represents a CloudWatch metric of your choosing for a target tracking scaling policy to use with Amazon EC2 Auto Scaling.</p> <p>To create your customized metric specification:</p> <ul> <li> <p>Add values for each required parameter from CloudWatch. You can use an existing metric, or a new metric that you create. To use your own metric, you must first publish the metric to CloudWatch. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html">Publish Custom Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p> </li> <li> <p>Choose a metric that changes proportionally with capacity. The value of the metric should increase or decrease in inverse proportion to the number of capacity units. That is, the value of the metric should decrease when capacity increases. </p> </li> </ul> <p>For more information about CloudWatch, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/cloudwatch_concepts.html">Amazon CloudWatch Concepts</a>. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CustomizedMetricSpecification {
     /// <p>The dimensions of the metric.</p> <p>Conditional: If you published your metric with dimensions, you must specify the same dimensions in your scaling policy.</p>
     pub dimensions: Option<Vec<MetricDimension>>,
@@ -1672,21 +2452,28 @@ pub struct CustomizedMetricSpecification {
     /// <p>The namespace of the metric.</p>
     pub namespace: String,
     /// <p>The statistic of the metric.</p>
-    pub statistic: String,
+    pub statistic: MetricStatistic,
     /// <p>The unit of the metric.</p>
     pub unit: Option<String>,
 }
 
-struct CustomizedMetricSpecificationDeserializer;
+pub(crate) struct CustomizedMetricSpecificationDeserializer;
 impl CustomizedMetricSpecificationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CustomizedMetricSpecification, XmlParseError> {
-        deserialize_elements::<_, CustomizedMetricSpecification, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            CustomizedMetricSpecification {
+                dimensions: Default::default(),
+                metric_name: Default::default(),
+                namespace: Default::default(),
+                statistic: MetricStatistic::Unknown(String::new()),
+                unit: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "Dimensions" => {
@@ -1749,6 +2536,46 @@ pub struct DeleteAutoScalingGroupType {
     pub force_delete: Option<bool>,
 }
 
+impl DeleteAutoScalingGroupType {
+    /// Returns a builder for `DeleteAutoScalingGroupType`, checking its required members when built.
+    pub fn builder() -> DeleteAutoScalingGroupTypeBuilder {
+        DeleteAutoScalingGroupTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAutoScalingGroupType`](struct.DeleteAutoScalingGroupType.html).
+#[derive(Default, Debug)]
+pub struct DeleteAutoScalingGroupTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    force_delete: Option<bool>,
+}
+
+impl DeleteAutoScalingGroupTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DeleteAutoScalingGroupType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_delete`](struct.DeleteAutoScalingGroupType.html#structfield.force_delete) member.
+    pub fn force_delete<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_delete = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAutoScalingGroupType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAutoScalingGroupType, ::rusoto_core::BuildError> {
+        Ok(DeleteAutoScalingGroupType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            force_delete: self.force_delete,
+        })
+    }
+}
+
 /// Serialize `DeleteAutoScalingGroupType` contents to a `SignedRequest`.
 struct DeleteAutoScalingGroupTypeSerializer;
 impl DeleteAutoScalingGroupTypeSerializer {
@@ -1771,10 +2598,10 @@ impl DeleteAutoScalingGroupTypeSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteLifecycleHookAnswer {}
 
-struct DeleteLifecycleHookAnswerDeserializer;
+pub(crate) struct DeleteLifecycleHookAnswerDeserializer;
 impl DeleteLifecycleHookAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteLifecycleHookAnswer, XmlParseError> {
@@ -1795,6 +2622,48 @@ pub struct DeleteLifecycleHookType {
     pub lifecycle_hook_name: String,
 }
 
+impl DeleteLifecycleHookType {
+    /// Returns a builder for `DeleteLifecycleHookType`, checking its required members when built.
+    pub fn builder() -> DeleteLifecycleHookTypeBuilder {
+        DeleteLifecycleHookTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLifecycleHookType`](struct.DeleteLifecycleHookType.html).
+#[derive(Default, Debug)]
+pub struct DeleteLifecycleHookTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    lifecycle_hook_name: Option<String>,
+}
+
+impl DeleteLifecycleHookTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DeleteLifecycleHookType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_hook_name`](struct.DeleteLifecycleHookType.html#structfield.lifecycle_hook_name) member.
+    pub fn lifecycle_hook_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.lifecycle_hook_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLifecycleHookType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLifecycleHookType, ::rusoto_core::BuildError> {
+        Ok(DeleteLifecycleHookType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            lifecycle_hook_name: self
+                .lifecycle_hook_name
+                .ok_or(::rusoto_core::BuildError::missing("lifecycle_hook_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteLifecycleHookType` contents to a `SignedRequest`.
 struct DeleteLifecycleHookTypeSerializer;
 impl DeleteLifecycleHookTypeSerializer {
@@ -1823,6 +2692,48 @@ pub struct DeleteNotificationConfigurationType {
     pub topic_arn: String,
 }
 
+impl DeleteNotificationConfigurationType {
+    /// Returns a builder for `DeleteNotificationConfigurationType`, checking its required members when built.
+    pub fn builder() -> DeleteNotificationConfigurationTypeBuilder {
+        DeleteNotificationConfigurationTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteNotificationConfigurationType`](struct.DeleteNotificationConfigurationType.html).
+#[derive(Default, Debug)]
+pub struct DeleteNotificationConfigurationTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    topic_arn: Option<String>,
+}
+
+impl DeleteNotificationConfigurationTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DeleteNotificationConfigurationType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_arn`](struct.DeleteNotificationConfigurationType.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteNotificationConfigurationType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteNotificationConfigurationType, ::rusoto_core::BuildError> {
+        Ok(DeleteNotificationConfigurationType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            topic_arn: self
+                .topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("topic_arn"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteNotificationConfigurationType` contents to a `SignedRequest`.
 struct DeleteNotificationConfigurationTypeSerializer;
 impl DeleteNotificationConfigurationTypeSerializer {
@@ -1848,6 +2759,44 @@ pub struct DeletePolicyType {
     pub policy_name: String,
 }
 
+impl DeletePolicyType {
+    /// Returns a builder for `DeletePolicyType`, checking its required members when built.
+    pub fn builder() -> DeletePolicyTypeBuilder {
+        DeletePolicyTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePolicyType`](struct.DeletePolicyType.html).
+#[derive(Default, Debug)]
+pub struct DeletePolicyTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl DeletePolicyTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DeletePolicyType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.DeletePolicyType.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePolicyType`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePolicyType, ::rusoto_core::BuildError> {
+        Ok(DeletePolicyType {
+            auto_scaling_group_name: self.auto_scaling_group_name,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeletePolicyType` contents to a `SignedRequest`.
 struct DeletePolicyTypeSerializer;
 impl DeletePolicyTypeSerializer {
@@ -1875,6 +2824,48 @@ pub struct DeleteScheduledActionType {
     pub scheduled_action_name: String,
 }
 
+impl DeleteScheduledActionType {
+    /// Returns a builder for `DeleteScheduledActionType`, checking its required members when built.
+    pub fn builder() -> DeleteScheduledActionTypeBuilder {
+        DeleteScheduledActionTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteScheduledActionType`](struct.DeleteScheduledActionType.html).
+#[derive(Default, Debug)]
+pub struct DeleteScheduledActionTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    scheduled_action_name: Option<String>,
+}
+
+impl DeleteScheduledActionTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DeleteScheduledActionType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduled_action_name`](struct.DeleteScheduledActionType.html#structfield.scheduled_action_name) member.
+    pub fn scheduled_action_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scheduled_action_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteScheduledActionType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteScheduledActionType, ::rusoto_core::BuildError> {
+        Ok(DeleteScheduledActionType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            scheduled_action_name: self
+                .scheduled_action_name
+                .ok_or(::rusoto_core::BuildError::missing("scheduled_action_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteScheduledActionType` contents to a `SignedRequest`.
 struct DeleteScheduledActionTypeSerializer;
 impl DeleteScheduledActionTypeSerializer {
@@ -1901,6 +2892,36 @@ pub struct DeleteTagsType {
     pub tags: Vec<Tag>,
 }
 
+impl DeleteTagsType {
+    /// Returns a builder for `DeleteTagsType`, checking its required members when built.
+    pub fn builder() -> DeleteTagsTypeBuilder {
+        DeleteTagsTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTagsType`](struct.DeleteTagsType.html).
+#[derive(Default, Debug)]
+pub struct DeleteTagsTypeBuilder {
+    tags: Option<Vec<Tag>>,
+}
+
+impl DeleteTagsTypeBuilder {
+    /// Sets the [`tags`](struct.DeleteTagsType.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTagsType`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTagsType, ::rusoto_core::BuildError> {
+        Ok(DeleteTagsType {
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteTagsType` contents to a `SignedRequest`.
 struct DeleteTagsTypeSerializer;
 impl DeleteTagsTypeSerializer {
@@ -1926,10 +2947,10 @@ pub struct DescribeAccountLimitsAnswer {
     pub number_of_launch_configurations: Option<i64>,
 }
 
-struct DescribeAccountLimitsAnswerDeserializer;
+pub(crate) struct DescribeAccountLimitsAnswerDeserializer;
 impl DescribeAccountLimitsAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAccountLimitsAnswer, XmlParseError> {
@@ -1979,10 +3000,10 @@ pub struct DescribeAdjustmentTypesAnswer {
     pub adjustment_types: Option<Vec<AdjustmentType>>,
 }
 
-struct DescribeAdjustmentTypesAnswerDeserializer;
+pub(crate) struct DescribeAdjustmentTypesAnswerDeserializer;
 impl DescribeAdjustmentTypesAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAdjustmentTypesAnswer, XmlParseError> {
@@ -2013,6 +3034,52 @@ pub struct DescribeAutoScalingInstancesType {
     pub next_token: Option<String>,
 }
 
+impl DescribeAutoScalingInstancesType {
+    /// Returns a builder for `DescribeAutoScalingInstancesType`, checking its required members when built.
+    pub fn builder() -> DescribeAutoScalingInstancesTypeBuilder {
+        DescribeAutoScalingInstancesTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAutoScalingInstancesType`](struct.DescribeAutoScalingInstancesType.html).
+#[derive(Default, Debug)]
+pub struct DescribeAutoScalingInstancesTypeBuilder {
+    instance_ids: Option<Vec<String>>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeAutoScalingInstancesTypeBuilder {
+    /// Sets the [`instance_ids`](struct.DescribeAutoScalingInstancesType.html#structfield.instance_ids) member.
+    pub fn instance_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeAutoScalingInstancesType.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeAutoScalingInstancesType.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAutoScalingInstancesType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAutoScalingInstancesType, ::rusoto_core::BuildError> {
+        Ok(DescribeAutoScalingInstancesType {
+            instance_ids: self.instance_ids,
+            max_records: self.max_records,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `DescribeAutoScalingInstancesType` contents to a `SignedRequest`.
 struct DescribeAutoScalingInstancesTypeSerializer;
 impl DescribeAutoScalingInstancesTypeSerializer {
@@ -2044,10 +3111,10 @@ pub struct DescribeAutoScalingNotificationTypesAnswer {
     pub auto_scaling_notification_types: Option<Vec<String>>,
 }
 
-struct DescribeAutoScalingNotificationTypesAnswerDeserializer;
+pub(crate) struct DescribeAutoScalingNotificationTypesAnswerDeserializer;
 impl DescribeAutoScalingNotificationTypesAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAutoScalingNotificationTypesAnswer, XmlParseError> {
@@ -2077,10 +3144,10 @@ pub struct DescribeLifecycleHookTypesAnswer {
     pub lifecycle_hook_types: Option<Vec<String>>,
 }
 
-struct DescribeLifecycleHookTypesAnswerDeserializer;
+pub(crate) struct DescribeLifecycleHookTypesAnswerDeserializer;
 impl DescribeLifecycleHookTypesAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeLifecycleHookTypesAnswer, XmlParseError> {
@@ -2110,10 +3177,10 @@ pub struct DescribeLifecycleHooksAnswer {
     pub lifecycle_hooks: Option<Vec<LifecycleHook>>,
 }
 
-struct DescribeLifecycleHooksAnswerDeserializer;
+pub(crate) struct DescribeLifecycleHooksAnswerDeserializer;
 impl DescribeLifecycleHooksAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeLifecycleHooksAnswer, XmlParseError> {
@@ -2142,6 +3209,46 @@ pub struct DescribeLifecycleHooksType {
     pub lifecycle_hook_names: Option<Vec<String>>,
 }
 
+impl DescribeLifecycleHooksType {
+    /// Returns a builder for `DescribeLifecycleHooksType`, checking its required members when built.
+    pub fn builder() -> DescribeLifecycleHooksTypeBuilder {
+        DescribeLifecycleHooksTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLifecycleHooksType`](struct.DescribeLifecycleHooksType.html).
+#[derive(Default, Debug)]
+pub struct DescribeLifecycleHooksTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    lifecycle_hook_names: Option<Vec<String>>,
+}
+
+impl DescribeLifecycleHooksTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DescribeLifecycleHooksType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_hook_names`](struct.DescribeLifecycleHooksType.html#structfield.lifecycle_hook_names) member.
+    pub fn lifecycle_hook_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.lifecycle_hook_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLifecycleHooksType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLifecycleHooksType, ::rusoto_core::BuildError> {
+        Ok(DescribeLifecycleHooksType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            lifecycle_hook_names: self.lifecycle_hook_names,
+        })
+    }
+}
+
 /// Serialize `DescribeLifecycleHooksType` contents to a `SignedRequest`.
 struct DescribeLifecycleHooksTypeSerializer;
 impl DescribeLifecycleHooksTypeSerializer {
@@ -2175,6 +3282,55 @@ pub struct DescribeLoadBalancerTargetGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeLoadBalancerTargetGroupsRequest {
+    /// Returns a builder for `DescribeLoadBalancerTargetGroupsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeLoadBalancerTargetGroupsRequestBuilder {
+        DescribeLoadBalancerTargetGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLoadBalancerTargetGroupsRequest`](struct.DescribeLoadBalancerTargetGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeLoadBalancerTargetGroupsRequestBuilder {
+    auto_scaling_group_name: Option<String>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeLoadBalancerTargetGroupsRequestBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DescribeLoadBalancerTargetGroupsRequest.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeLoadBalancerTargetGroupsRequest.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeLoadBalancerTargetGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLoadBalancerTargetGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLoadBalancerTargetGroupsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeLoadBalancerTargetGroupsRequest {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            max_records: self.max_records,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `DescribeLoadBalancerTargetGroupsRequest` contents to a `SignedRequest`.
 struct DescribeLoadBalancerTargetGroupsRequestSerializer;
 impl DescribeLoadBalancerTargetGroupsRequestSerializer {
@@ -2205,10 +3361,10 @@ pub struct DescribeLoadBalancerTargetGroupsResponse {
     pub next_token: Option<String>,
 }
 
-struct DescribeLoadBalancerTargetGroupsResponseDeserializer;
+pub(crate) struct DescribeLoadBalancerTargetGroupsResponseDeserializer;
 impl DescribeLoadBalancerTargetGroupsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeLoadBalancerTargetGroupsResponse, XmlParseError> {
@@ -2246,6 +3402,54 @@ pub struct DescribeLoadBalancersRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeLoadBalancersRequest {
+    /// Returns a builder for `DescribeLoadBalancersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeLoadBalancersRequestBuilder {
+        DescribeLoadBalancersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLoadBalancersRequest`](struct.DescribeLoadBalancersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeLoadBalancersRequestBuilder {
+    auto_scaling_group_name: Option<String>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeLoadBalancersRequestBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DescribeLoadBalancersRequest.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeLoadBalancersRequest.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeLoadBalancersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLoadBalancersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLoadBalancersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeLoadBalancersRequest {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            max_records: self.max_records,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `DescribeLoadBalancersRequest` contents to a `SignedRequest`.
 struct DescribeLoadBalancersRequestSerializer;
 impl DescribeLoadBalancersRequestSerializer {
@@ -2276,10 +3480,10 @@ pub struct DescribeLoadBalancersResponse {
     pub next_token: Option<String>,
 }
 
-struct DescribeLoadBalancersResponseDeserializer;
+pub(crate) struct DescribeLoadBalancersResponseDeserializer;
 impl DescribeLoadBalancersResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeLoadBalancersResponse, XmlParseError> {
@@ -2312,10 +3516,10 @@ pub struct DescribeMetricCollectionTypesAnswer {
     pub metrics: Option<Vec<MetricCollectionType>>,
 }
 
-struct DescribeMetricCollectionTypesAnswerDeserializer;
+pub(crate) struct DescribeMetricCollectionTypesAnswerDeserializer;
 impl DescribeMetricCollectionTypesAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeMetricCollectionTypesAnswer, XmlParseError> {
@@ -2352,10 +3556,10 @@ pub struct DescribeNotificationConfigurationsAnswer {
     pub notification_configurations: Vec<NotificationConfiguration>,
 }
 
-struct DescribeNotificationConfigurationsAnswerDeserializer;
+pub(crate) struct DescribeNotificationConfigurationsAnswerDeserializer;
 impl DescribeNotificationConfigurationsAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeNotificationConfigurationsAnswer, XmlParseError> {
@@ -2393,6 +3597,53 @@ pub struct DescribeNotificationConfigurationsType {
     pub next_token: Option<String>,
 }
 
+impl DescribeNotificationConfigurationsType {
+    /// Returns a builder for `DescribeNotificationConfigurationsType`, checking its required members when built.
+    pub fn builder() -> DescribeNotificationConfigurationsTypeBuilder {
+        DescribeNotificationConfigurationsTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeNotificationConfigurationsType`](struct.DescribeNotificationConfigurationsType.html).
+#[derive(Default, Debug)]
+pub struct DescribeNotificationConfigurationsTypeBuilder {
+    auto_scaling_group_names: Option<Vec<String>>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeNotificationConfigurationsTypeBuilder {
+    /// Sets the [`auto_scaling_group_names`](struct.DescribeNotificationConfigurationsType.html#structfield.auto_scaling_group_names) member.
+    pub fn auto_scaling_group_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeNotificationConfigurationsType.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeNotificationConfigurationsType.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeNotificationConfigurationsType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeNotificationConfigurationsType, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeNotificationConfigurationsType {
+            auto_scaling_group_names: self.auto_scaling_group_names,
+            max_records: self.max_records,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `DescribeNotificationConfigurationsType` contents to a `SignedRequest`.
 struct DescribeNotificationConfigurationsTypeSerializer;
 impl DescribeNotificationConfigurationsTypeSerializer {
@@ -2432,6 +3683,66 @@ pub struct DescribePoliciesType {
     pub policy_types: Option<Vec<String>>,
 }
 
+impl DescribePoliciesType {
+    /// Returns a builder for `DescribePoliciesType`, checking its required members when built.
+    pub fn builder() -> DescribePoliciesTypeBuilder {
+        DescribePoliciesTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePoliciesType`](struct.DescribePoliciesType.html).
+#[derive(Default, Debug)]
+pub struct DescribePoliciesTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+    policy_names: Option<Vec<String>>,
+    policy_types: Option<Vec<String>>,
+}
+
+impl DescribePoliciesTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DescribePoliciesType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribePoliciesType.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribePoliciesType.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_names`](struct.DescribePoliciesType.html#structfield.policy_names) member.
+    pub fn policy_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_types`](struct.DescribePoliciesType.html#structfield.policy_types) member.
+    pub fn policy_types<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_types = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePoliciesType`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribePoliciesType, ::rusoto_core::BuildError> {
+        Ok(DescribePoliciesType {
+            auto_scaling_group_name: self.auto_scaling_group_name,
+            max_records: self.max_records,
+            next_token: self.next_token,
+            policy_names: self.policy_names,
+            policy_types: self.policy_types,
+        })
+    }
+}
+
 /// Serialize `DescribePoliciesType` contents to a `SignedRequest`.
 struct DescribePoliciesTypeSerializer;
 impl DescribePoliciesTypeSerializer {
@@ -2482,6 +3793,60 @@ pub struct DescribeScalingActivitiesType {
     pub next_token: Option<String>,
 }
 
+impl DescribeScalingActivitiesType {
+    /// Returns a builder for `DescribeScalingActivitiesType`, checking its required members when built.
+    pub fn builder() -> DescribeScalingActivitiesTypeBuilder {
+        DescribeScalingActivitiesTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeScalingActivitiesType`](struct.DescribeScalingActivitiesType.html).
+#[derive(Default, Debug)]
+pub struct DescribeScalingActivitiesTypeBuilder {
+    activity_ids: Option<Vec<String>>,
+    auto_scaling_group_name: Option<String>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeScalingActivitiesTypeBuilder {
+    /// Sets the [`activity_ids`](struct.DescribeScalingActivitiesType.html#structfield.activity_ids) member.
+    pub fn activity_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.activity_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_scaling_group_name`](struct.DescribeScalingActivitiesType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeScalingActivitiesType.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeScalingActivitiesType.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeScalingActivitiesType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeScalingActivitiesType, ::rusoto_core::BuildError> {
+        Ok(DescribeScalingActivitiesType {
+            activity_ids: self.activity_ids,
+            auto_scaling_group_name: self.auto_scaling_group_name,
+            max_records: self.max_records,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `DescribeScalingActivitiesType` contents to a `SignedRequest`.
 struct DescribeScalingActivitiesTypeSerializer;
 impl DescribeScalingActivitiesTypeSerializer {
@@ -2518,7 +3883,7 @@ pub struct DescribeScheduledActionsType {
     /// <p>The name of the Auto Scaling group.</p>
     pub auto_scaling_group_name: Option<String>,
     /// <p>The latest scheduled start time to return. If scheduled action names are provided, this parameter is ignored.</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>The maximum number of items to return with this call. The default value is <code>50</code> and the maximum value is <code>100</code>.</p>
     pub max_records: Option<i64>,
     /// <p>The token for the next set of items to return. (You received this token from a previous call.)</p>
@@ -2526,7 +3891,77 @@ pub struct DescribeScheduledActionsType {
     /// <p>The names of one or more scheduled actions. You can specify up to 50 actions. If you omit this parameter, all scheduled actions are described. If you specify an unknown scheduled action, it is ignored with no error.</p>
     pub scheduled_action_names: Option<Vec<String>>,
     /// <p>The earliest scheduled start time to return. If scheduled action names are provided, this parameter is ignored.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
+}
+
+impl DescribeScheduledActionsType {
+    /// Returns a builder for `DescribeScheduledActionsType`, checking its required members when built.
+    pub fn builder() -> DescribeScheduledActionsTypeBuilder {
+        DescribeScheduledActionsTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeScheduledActionsType`](struct.DescribeScheduledActionsType.html).
+#[derive(Default, Debug)]
+pub struct DescribeScheduledActionsTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    end_time: Option<IsoTimestamp>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+    scheduled_action_names: Option<Vec<String>>,
+    start_time: Option<IsoTimestamp>,
+}
+
+impl DescribeScheduledActionsTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DescribeScheduledActionsType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.DescribeScheduledActionsType.html#structfield.end_time) member.
+    pub fn end_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeScheduledActionsType.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeScheduledActionsType.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduled_action_names`](struct.DescribeScheduledActionsType.html#structfield.scheduled_action_names) member.
+    pub fn scheduled_action_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.scheduled_action_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.DescribeScheduledActionsType.html#structfield.start_time) member.
+    pub fn start_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeScheduledActionsType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeScheduledActionsType, ::rusoto_core::BuildError> {
+        Ok(DescribeScheduledActionsType {
+            auto_scaling_group_name: self.auto_scaling_group_name,
+            end_time: self.end_time,
+            max_records: self.max_records,
+            next_token: self.next_token,
+            scheduled_action_names: self.scheduled_action_names,
+            start_time: self.start_time,
+        })
+    }
 }
 
 /// Serialize `DescribeScheduledActionsType` contents to a `SignedRequest`.
@@ -2576,6 +4011,50 @@ pub struct DescribeTagsType {
     pub next_token: Option<String>,
 }
 
+impl DescribeTagsType {
+    /// Returns a builder for `DescribeTagsType`, checking its required members when built.
+    pub fn builder() -> DescribeTagsTypeBuilder {
+        DescribeTagsTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTagsType`](struct.DescribeTagsType.html).
+#[derive(Default, Debug)]
+pub struct DescribeTagsTypeBuilder {
+    filters: Option<Vec<Filter>>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeTagsTypeBuilder {
+    /// Sets the [`filters`](struct.DescribeTagsType.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeTagsType.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeTagsType.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTagsType`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTagsType, ::rusoto_core::BuildError> {
+        Ok(DescribeTagsType {
+            filters: self.filters,
+            max_records: self.max_records,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `DescribeTagsType` contents to a `SignedRequest`.
 struct DescribeTagsTypeSerializer;
 impl DescribeTagsTypeSerializer {
@@ -2603,10 +4082,10 @@ pub struct DescribeTerminationPolicyTypesAnswer {
     pub termination_policy_types: Option<Vec<String>>,
 }
 
-struct DescribeTerminationPolicyTypesAnswerDeserializer;
+pub(crate) struct DescribeTerminationPolicyTypesAnswerDeserializer;
 impl DescribeTerminationPolicyTypesAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeTerminationPolicyTypesAnswer, XmlParseError> {
@@ -2636,10 +4115,10 @@ pub struct DetachInstancesAnswer {
     pub activities: Option<Vec<Activity>>,
 }
 
-struct DetachInstancesAnswerDeserializer;
+pub(crate) struct DetachInstancesAnswerDeserializer;
 impl DetachInstancesAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DetachInstancesAnswer, XmlParseError> {
@@ -2666,6 +4145,54 @@ pub struct DetachInstancesQuery {
     pub should_decrement_desired_capacity: bool,
 }
 
+impl DetachInstancesQuery {
+    /// Returns a builder for `DetachInstancesQuery`, checking its required members when built.
+    pub fn builder() -> DetachInstancesQueryBuilder {
+        DetachInstancesQueryBuilder::default()
+    }
+}
+
+/// Builds a [`DetachInstancesQuery`](struct.DetachInstancesQuery.html).
+#[derive(Default, Debug)]
+pub struct DetachInstancesQueryBuilder {
+    auto_scaling_group_name: Option<String>,
+    instance_ids: Option<Vec<String>>,
+    should_decrement_desired_capacity: Option<bool>,
+}
+
+impl DetachInstancesQueryBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DetachInstancesQuery.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_ids`](struct.DetachInstancesQuery.html#structfield.instance_ids) member.
+    pub fn instance_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`should_decrement_desired_capacity`](struct.DetachInstancesQuery.html#structfield.should_decrement_desired_capacity) member.
+    pub fn should_decrement_desired_capacity<T: Into<bool>>(mut self, value: T) -> Self {
+        self.should_decrement_desired_capacity = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachInstancesQuery`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetachInstancesQuery, ::rusoto_core::BuildError> {
+        Ok(DetachInstancesQuery {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            instance_ids: self.instance_ids,
+            should_decrement_desired_capacity: self.should_decrement_desired_capacity.ok_or(
+                ::rusoto_core::BuildError::missing("should_decrement_desired_capacity"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DetachInstancesQuery` contents to a `SignedRequest`.
 struct DetachInstancesQuerySerializer;
 impl DetachInstancesQuerySerializer {
@@ -2696,10 +4223,10 @@ impl DetachInstancesQuerySerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DetachLoadBalancerTargetGroupsResultType {}
 
-struct DetachLoadBalancerTargetGroupsResultTypeDeserializer;
+pub(crate) struct DetachLoadBalancerTargetGroupsResultTypeDeserializer;
 impl DetachLoadBalancerTargetGroupsResultTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DetachLoadBalancerTargetGroupsResultType, XmlParseError> {
@@ -2720,6 +4247,48 @@ pub struct DetachLoadBalancerTargetGroupsType {
     pub target_group_ar_ns: Vec<String>,
 }
 
+impl DetachLoadBalancerTargetGroupsType {
+    /// Returns a builder for `DetachLoadBalancerTargetGroupsType`, checking its required members when built.
+    pub fn builder() -> DetachLoadBalancerTargetGroupsTypeBuilder {
+        DetachLoadBalancerTargetGroupsTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DetachLoadBalancerTargetGroupsType`](struct.DetachLoadBalancerTargetGroupsType.html).
+#[derive(Default, Debug)]
+pub struct DetachLoadBalancerTargetGroupsTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    target_group_ar_ns: Option<Vec<String>>,
+}
+
+impl DetachLoadBalancerTargetGroupsTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DetachLoadBalancerTargetGroupsType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_group_ar_ns`](struct.DetachLoadBalancerTargetGroupsType.html#structfield.target_group_ar_ns) member.
+    pub fn target_group_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.target_group_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachLoadBalancerTargetGroupsType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetachLoadBalancerTargetGroupsType, ::rusoto_core::BuildError> {
+        Ok(DetachLoadBalancerTargetGroupsType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            target_group_ar_ns: self
+                .target_group_ar_ns
+                .ok_or(::rusoto_core::BuildError::missing("target_group_ar_ns"))?,
+        })
+    }
+}
+
 /// Serialize `DetachLoadBalancerTargetGroupsType` contents to a `SignedRequest`.
 struct DetachLoadBalancerTargetGroupsTypeSerializer;
 impl DetachLoadBalancerTargetGroupsTypeSerializer {
@@ -2744,10 +4313,10 @@ impl DetachLoadBalancerTargetGroupsTypeSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DetachLoadBalancersResultType {}
 
-struct DetachLoadBalancersResultTypeDeserializer;
+pub(crate) struct DetachLoadBalancersResultTypeDeserializer;
 impl DetachLoadBalancersResultTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DetachLoadBalancersResultType, XmlParseError> {
@@ -2768,6 +4337,48 @@ pub struct DetachLoadBalancersType {
     pub load_balancer_names: Vec<String>,
 }
 
+impl DetachLoadBalancersType {
+    /// Returns a builder for `DetachLoadBalancersType`, checking its required members when built.
+    pub fn builder() -> DetachLoadBalancersTypeBuilder {
+        DetachLoadBalancersTypeBuilder::default()
+    }
+}
+
+/// Builds a [`DetachLoadBalancersType`](struct.DetachLoadBalancersType.html).
+#[derive(Default, Debug)]
+pub struct DetachLoadBalancersTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    load_balancer_names: Option<Vec<String>>,
+}
+
+impl DetachLoadBalancersTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DetachLoadBalancersType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_names`](struct.DetachLoadBalancersType.html#structfield.load_balancer_names) member.
+    pub fn load_balancer_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.load_balancer_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachLoadBalancersType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetachLoadBalancersType, ::rusoto_core::BuildError> {
+        Ok(DetachLoadBalancersType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            load_balancer_names: self
+                .load_balancer_names
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_names"))?,
+        })
+    }
+}
+
 /// Serialize `DetachLoadBalancersType` contents to a `SignedRequest`.
 struct DetachLoadBalancersTypeSerializer;
 impl DetachLoadBalancersTypeSerializer {
@@ -2797,6 +4408,46 @@ pub struct DisableMetricsCollectionQuery {
     pub metrics: Option<Vec<String>>,
 }
 
+impl DisableMetricsCollectionQuery {
+    /// Returns a builder for `DisableMetricsCollectionQuery`, checking its required members when built.
+    pub fn builder() -> DisableMetricsCollectionQueryBuilder {
+        DisableMetricsCollectionQueryBuilder::default()
+    }
+}
+
+/// Builds a [`DisableMetricsCollectionQuery`](struct.DisableMetricsCollectionQuery.html).
+#[derive(Default, Debug)]
+pub struct DisableMetricsCollectionQueryBuilder {
+    auto_scaling_group_name: Option<String>,
+    metrics: Option<Vec<String>>,
+}
+
+impl DisableMetricsCollectionQueryBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.DisableMetricsCollectionQuery.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metrics`](struct.DisableMetricsCollectionQuery.html#structfield.metrics) member.
+    pub fn metrics<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.metrics = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableMetricsCollectionQuery`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisableMetricsCollectionQuery, ::rusoto_core::BuildError> {
+        Ok(DisableMetricsCollectionQuery {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            metrics: self.metrics,
+        })
+    }
+}
+
 /// Serialize `DisableMetricsCollectionQuery` contents to a `SignedRequest`.
 struct DisableMetricsCollectionQuerySerializer;
 impl DisableMetricsCollectionQuerySerializer {
@@ -2816,10 +4467,13 @@ impl DisableMetricsCollectionQuerySerializer {
     }
 }
 
-struct DisableScaleInDeserializer;
+pub(crate) struct DisableScaleInDeserializer;
 impl DisableScaleInDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2844,10 +4498,13 @@ pub struct Ebs {
     pub volume_type: Option<String>,
 }
 
-struct EbsDeserializer;
+pub(crate) struct EbsDeserializer;
 impl EbsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Ebs, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Ebs, XmlParseError> {
         deserialize_elements::<_, Ebs, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "DeleteOnTermination" => {
@@ -2924,10 +4581,13 @@ impl EbsSerializer {
     }
 }
 
-struct EbsOptimizedDeserializer;
+pub(crate) struct EbsOptimizedDeserializer;
 impl EbsOptimizedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2945,6 +4605,56 @@ pub struct EnableMetricsCollectionQuery {
     pub metrics: Option<Vec<String>>,
 }
 
+impl EnableMetricsCollectionQuery {
+    /// Returns a builder for `EnableMetricsCollectionQuery`, checking its required members when built.
+    pub fn builder() -> EnableMetricsCollectionQueryBuilder {
+        EnableMetricsCollectionQueryBuilder::default()
+    }
+}
+
+/// Builds a [`EnableMetricsCollectionQuery`](struct.EnableMetricsCollectionQuery.html).
+#[derive(Default, Debug)]
+pub struct EnableMetricsCollectionQueryBuilder {
+    auto_scaling_group_name: Option<String>,
+    granularity: Option<String>,
+    metrics: Option<Vec<String>>,
+}
+
+impl EnableMetricsCollectionQueryBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.EnableMetricsCollectionQuery.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`granularity`](struct.EnableMetricsCollectionQuery.html#structfield.granularity) member.
+    pub fn granularity<T: Into<String>>(mut self, value: T) -> Self {
+        self.granularity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metrics`](struct.EnableMetricsCollectionQuery.html#structfield.metrics) member.
+    pub fn metrics<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.metrics = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableMetricsCollectionQuery`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EnableMetricsCollectionQuery, ::rusoto_core::BuildError> {
+        Ok(EnableMetricsCollectionQuery {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            granularity: self
+                .granularity
+                .ok_or(::rusoto_core::BuildError::missing("granularity"))?,
+            metrics: self.metrics,
+        })
+    }
+}
+
 /// Serialize `EnableMetricsCollectionQuery` contents to a `SignedRequest`.
 struct EnableMetricsCollectionQuerySerializer;
 impl EnableMetricsCollectionQuerySerializer {
@@ -2974,10 +4684,10 @@ pub struct EnabledMetric {
     pub metric: Option<String>,
 }
 
-struct EnabledMetricDeserializer;
+pub(crate) struct EnabledMetricDeserializer;
 impl EnabledMetricDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EnabledMetric, XmlParseError> {
@@ -3000,10 +4710,10 @@ impl EnabledMetricDeserializer {
         })
     }
 }
-struct EnabledMetricsDeserializer;
+pub(crate) struct EnabledMetricsDeserializer;
 impl EnabledMetricsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EnabledMetric>, XmlParseError> {
@@ -3023,10 +4733,10 @@ pub struct EnterStandbyAnswer {
     pub activities: Option<Vec<Activity>>,
 }
 
-struct EnterStandbyAnswerDeserializer;
+pub(crate) struct EnterStandbyAnswerDeserializer;
 impl EnterStandbyAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EnterStandbyAnswer, XmlParseError> {
@@ -3053,6 +4763,54 @@ pub struct EnterStandbyQuery {
     pub should_decrement_desired_capacity: bool,
 }
 
+impl EnterStandbyQuery {
+    /// Returns a builder for `EnterStandbyQuery`, checking its required members when built.
+    pub fn builder() -> EnterStandbyQueryBuilder {
+        EnterStandbyQueryBuilder::default()
+    }
+}
+
+/// Builds a [`EnterStandbyQuery`](struct.EnterStandbyQuery.html).
+#[derive(Default, Debug)]
+pub struct EnterStandbyQueryBuilder {
+    auto_scaling_group_name: Option<String>,
+    instance_ids: Option<Vec<String>>,
+    should_decrement_desired_capacity: Option<bool>,
+}
+
+impl EnterStandbyQueryBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.EnterStandbyQuery.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_ids`](struct.EnterStandbyQuery.html#structfield.instance_ids) member.
+    pub fn instance_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`should_decrement_desired_capacity`](struct.EnterStandbyQuery.html#structfield.should_decrement_desired_capacity) member.
+    pub fn should_decrement_desired_capacity<T: Into<bool>>(mut self, value: T) -> Self {
+        self.should_decrement_desired_capacity = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnterStandbyQuery`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<EnterStandbyQuery, ::rusoto_core::BuildError> {
+        Ok(EnterStandbyQuery {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            instance_ids: self.instance_ids,
+            should_decrement_desired_capacity: self.should_decrement_desired_capacity.ok_or(
+                ::rusoto_core::BuildError::missing("should_decrement_desired_capacity"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `EnterStandbyQuery` contents to a `SignedRequest`.
 struct EnterStandbyQuerySerializer;
 impl EnterStandbyQuerySerializer {
@@ -3080,10 +4838,13 @@ impl EnterStandbyQuerySerializer {
     }
 }
 
-struct EstimatedInstanceWarmupDeserializer;
+pub(crate) struct EstimatedInstanceWarmupDeserializer;
 impl EstimatedInstanceWarmupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3105,6 +4866,68 @@ pub struct ExecutePolicyType {
     pub policy_name: String,
 }
 
+impl ExecutePolicyType {
+    /// Returns a builder for `ExecutePolicyType`, checking its required members when built.
+    pub fn builder() -> ExecutePolicyTypeBuilder {
+        ExecutePolicyTypeBuilder::default()
+    }
+}
+
+/// Builds a [`ExecutePolicyType`](struct.ExecutePolicyType.html).
+#[derive(Default, Debug)]
+pub struct ExecutePolicyTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    breach_threshold: Option<f64>,
+    honor_cooldown: Option<bool>,
+    metric_value: Option<f64>,
+    policy_name: Option<String>,
+}
+
+impl ExecutePolicyTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.ExecutePolicyType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`breach_threshold`](struct.ExecutePolicyType.html#structfield.breach_threshold) member.
+    pub fn breach_threshold<T: Into<f64>>(mut self, value: T) -> Self {
+        self.breach_threshold = Some(value.into());
+        self
+    }
+
+    /// Sets the [`honor_cooldown`](struct.ExecutePolicyType.html#structfield.honor_cooldown) member.
+    pub fn honor_cooldown<T: Into<bool>>(mut self, value: T) -> Self {
+        self.honor_cooldown = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_value`](struct.ExecutePolicyType.html#structfield.metric_value) member.
+    pub fn metric_value<T: Into<f64>>(mut self, value: T) -> Self {
+        self.metric_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.ExecutePolicyType.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExecutePolicyType`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ExecutePolicyType, ::rusoto_core::BuildError> {
+        Ok(ExecutePolicyType {
+            auto_scaling_group_name: self.auto_scaling_group_name,
+            breach_threshold: self.breach_threshold,
+            honor_cooldown: self.honor_cooldown,
+            metric_value: self.metric_value,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// Serialize `ExecutePolicyType` contents to a `SignedRequest`.
 struct ExecutePolicyTypeSerializer;
 impl ExecutePolicyTypeSerializer {
@@ -3139,10 +4962,10 @@ pub struct ExitStandbyAnswer {
     pub activities: Option<Vec<Activity>>,
 }
 
-struct ExitStandbyAnswerDeserializer;
+pub(crate) struct ExitStandbyAnswerDeserializer;
 impl ExitStandbyAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ExitStandbyAnswer, XmlParseError> {
@@ -3167,6 +4990,44 @@ pub struct ExitStandbyQuery {
     pub instance_ids: Option<Vec<String>>,
 }
 
+impl ExitStandbyQuery {
+    /// Returns a builder for `ExitStandbyQuery`, checking its required members when built.
+    pub fn builder() -> ExitStandbyQueryBuilder {
+        ExitStandbyQueryBuilder::default()
+    }
+}
+
+/// Builds a [`ExitStandbyQuery`](struct.ExitStandbyQuery.html).
+#[derive(Default, Debug)]
+pub struct ExitStandbyQueryBuilder {
+    auto_scaling_group_name: Option<String>,
+    instance_ids: Option<Vec<String>>,
+}
+
+impl ExitStandbyQueryBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.ExitStandbyQuery.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_ids`](struct.ExitStandbyQuery.html#structfield.instance_ids) member.
+    pub fn instance_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExitStandbyQuery`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ExitStandbyQuery, ::rusoto_core::BuildError> {
+        Ok(ExitStandbyQuery {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            instance_ids: self.instance_ids,
+        })
+    }
+}
+
 /// Serialize `ExitStandbyQuery` contents to a `SignedRequest`.
 struct ExitStandbyQuerySerializer;
 impl ExitStandbyQuerySerializer {
@@ -3201,10 +5062,10 @@ pub struct FailedScheduledUpdateGroupActionRequest {
     pub scheduled_action_name: String,
 }
 
-struct FailedScheduledUpdateGroupActionRequestDeserializer;
+pub(crate) struct FailedScheduledUpdateGroupActionRequestDeserializer;
 impl FailedScheduledUpdateGroupActionRequestDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FailedScheduledUpdateGroupActionRequest, XmlParseError> {
@@ -3236,10 +5097,10 @@ impl FailedScheduledUpdateGroupActionRequestDeserializer {
         )
     }
 }
-struct FailedScheduledUpdateGroupActionRequestsDeserializer;
+pub(crate) struct FailedScheduledUpdateGroupActionRequestsDeserializer;
 impl FailedScheduledUpdateGroupActionRequestsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<FailedScheduledUpdateGroupActionRequest>, XmlParseError> {
@@ -3295,10 +5156,13 @@ impl FiltersSerializer {
     }
 }
 
-struct GlobalTimeoutDeserializer;
+pub(crate) struct GlobalTimeoutDeserializer;
 impl GlobalTimeoutDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3306,10 +5170,13 @@ impl GlobalTimeoutDeserializer {
         Ok(obj)
     }
 }
-struct HealthCheckGracePeriodDeserializer;
+pub(crate) struct HealthCheckGracePeriodDeserializer;
 impl HealthCheckGracePeriodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3317,10 +5184,13 @@ impl HealthCheckGracePeriodDeserializer {
         Ok(obj)
     }
 }
-struct HeartbeatTimeoutDeserializer;
+pub(crate) struct HeartbeatTimeoutDeserializer;
 impl HeartbeatTimeoutDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3329,7 +5199,7 @@ impl HeartbeatTimeoutDeserializer {
     }
 }
 /// <p>Describes an EC2 instance.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Instance {
     /// <p>The Availability Zone in which the instance is running.</p>
     pub availability_zone: String,
@@ -3342,58 +5212,73 @@ pub struct Instance {
     /// <p>The launch template for the instance.</p>
     pub launch_template: Option<LaunchTemplateSpecification>,
     /// <p>A description of the current lifecycle state. The <code>Quarantined</code> state is not used.</p>
-    pub lifecycle_state: String,
+    pub lifecycle_state: LifecycleState,
     /// <p>Indicates whether the instance is protected from termination by Amazon EC2 Auto Scaling when scaling in.</p>
     pub protected_from_scale_in: bool,
 }
 
-struct InstanceDeserializer;
+pub(crate) struct InstanceDeserializer;
 impl InstanceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Instance, XmlParseError> {
-        deserialize_elements::<_, Instance, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "AvailabilityZone" => {
-                    obj.availability_zone =
-                        XmlStringMaxLen255Deserializer::deserialize("AvailabilityZone", stack)?;
-                }
-                "HealthStatus" => {
-                    obj.health_status =
-                        XmlStringMaxLen32Deserializer::deserialize("HealthStatus", stack)?;
-                }
-                "InstanceId" => {
-                    obj.instance_id =
-                        XmlStringMaxLen19Deserializer::deserialize("InstanceId", stack)?;
-                }
-                "LaunchConfigurationName" => {
-                    obj.launch_configuration_name =
-                        Some(XmlStringMaxLen255Deserializer::deserialize(
-                            "LaunchConfigurationName",
-                            stack,
-                        )?);
-                }
-                "LaunchTemplate" => {
-                    obj.launch_template =
-                        Some(LaunchTemplateSpecificationDeserializer::deserialize(
-                            "LaunchTemplate",
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            Instance {
+                availability_zone: Default::default(),
+                health_status: Default::default(),
+                instance_id: Default::default(),
+                launch_configuration_name: Default::default(),
+                launch_template: Default::default(),
+                lifecycle_state: LifecycleState::Unknown(String::new()),
+                protected_from_scale_in: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "AvailabilityZone" => {
+                        obj.availability_zone =
+                            XmlStringMaxLen255Deserializer::deserialize("AvailabilityZone", stack)?;
+                    }
+                    "HealthStatus" => {
+                        obj.health_status =
+                            XmlStringMaxLen32Deserializer::deserialize("HealthStatus", stack)?;
+                    }
+                    "InstanceId" => {
+                        obj.instance_id =
+                            XmlStringMaxLen19Deserializer::deserialize("InstanceId", stack)?;
+                    }
+                    "LaunchConfigurationName" => {
+                        obj.launch_configuration_name =
+                            Some(XmlStringMaxLen255Deserializer::deserialize(
+                                "LaunchConfigurationName",
+                                stack,
+                            )?);
+                    }
+                    "LaunchTemplate" => {
+                        obj.launch_template =
+                            Some(LaunchTemplateSpecificationDeserializer::deserialize(
+                                "LaunchTemplate",
+                                stack,
+                            )?);
+                    }
+                    "LifecycleState" => {
+                        obj.lifecycle_state =
+                            LifecycleStateDeserializer::deserialize("LifecycleState", stack)?;
+                    }
+                    "ProtectedFromScaleIn" => {
+                        obj.protected_from_scale_in = InstanceProtectedDeserializer::deserialize(
+                            "ProtectedFromScaleIn",
                             stack,
-                        )?);
-                }
-                "LifecycleState" => {
-                    obj.lifecycle_state =
-                        LifecycleStateDeserializer::deserialize("LifecycleState", stack)?;
-                }
-                "ProtectedFromScaleIn" => {
-                    obj.protected_from_scale_in =
-                        InstanceProtectedDeserializer::deserialize("ProtectedFromScaleIn", stack)?;
+                        )?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -3415,10 +5300,10 @@ pub struct InstanceMonitoring {
     pub enabled: Option<bool>,
 }
 
-struct InstanceMonitoringDeserializer;
+pub(crate) struct InstanceMonitoringDeserializer;
 impl InstanceMonitoringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<InstanceMonitoring, XmlParseError> {
@@ -3451,10 +5336,13 @@ impl InstanceMonitoringSerializer {
     }
 }
 
-struct InstanceProtectedDeserializer;
+pub(crate) struct InstanceProtectedDeserializer;
 impl InstanceProtectedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3462,10 +5350,10 @@ impl InstanceProtectedDeserializer {
         Ok(obj)
     }
 }
-struct InstancesDeserializer;
+pub(crate) struct InstancesDeserializer;
 impl InstancesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Instance>, XmlParseError> {
@@ -3496,10 +5384,10 @@ pub struct InstancesDistribution {
     pub spot_max_price: Option<String>,
 }
 
-struct InstancesDistributionDeserializer;
+pub(crate) struct InstancesDistributionDeserializer;
 impl InstancesDistributionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<InstancesDistribution, XmlParseError> {
@@ -3605,7 +5493,7 @@ pub struct LaunchConfiguration {
     /// <p>The IDs of one or more security groups for the VPC specified in <code>ClassicLinkVPCId</code>. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/vpc-classiclink.html">ClassicLink</a> in the <i>Amazon EC2 User Guide for Linux Instances</i> and <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/asg-in-vpc.html#as-ClassicLink">Linking EC2-Classic Instances to a VPC</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
     pub classic_link_vpc_security_groups: Option<Vec<String>>,
     /// <p>The creation date and time for the launch configuration.</p>
-    pub created_time: String,
+    pub created_time: IsoTimestamp,
     /// <p>Specifies whether the launch configuration is optimized for EBS I/O (<code>true</code>) or not (<code>false</code>). </p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/EBSOptimized.html">Amazon EBS-Optimized Instances</a> in the <i>Amazon EC2 User Guide for Linux Instances</i>.</p>
     pub ebs_optimized: Option<bool>,
     /// <p>The name or the Amazon Resource Name (ARN) of the instance profile associated with the IAM role for the instance. The instance profile contains the IAM role. </p> <p>For more information, see <a href="https://docs.aws.amazon.com/autoscaling/ec2/userguide/us-iam-role.html">IAM Role for Applications That Run on Amazon EC2 Instances</a> in the <i>Amazon EC2 Auto Scaling User Guide</i>.</p>
@@ -3636,10 +5524,10 @@ pub struct LaunchConfiguration {
     pub user_data: Option<String>,
 }
 
-struct LaunchConfigurationDeserializer;
+pub(crate) struct LaunchConfigurationDeserializer;
 impl LaunchConfigurationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LaunchConfiguration, XmlParseError> {
@@ -3759,10 +5647,42 @@ pub struct LaunchConfigurationNameType {
     pub launch_configuration_name: String,
 }
 
-/// Serialize `LaunchConfigurationNameType` contents to a `SignedRequest`.
-struct LaunchConfigurationNameTypeSerializer;
-impl LaunchConfigurationNameTypeSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &LaunchConfigurationNameType) {
+impl LaunchConfigurationNameType {
+    /// Returns a builder for `LaunchConfigurationNameType`, checking its required members when built.
+    pub fn builder() -> LaunchConfigurationNameTypeBuilder {
+        LaunchConfigurationNameTypeBuilder::default()
+    }
+}
+
+/// Builds a [`LaunchConfigurationNameType`](struct.LaunchConfigurationNameType.html).
+#[derive(Default, Debug)]
+pub struct LaunchConfigurationNameTypeBuilder {
+    launch_configuration_name: Option<String>,
+}
+
+impl LaunchConfigurationNameTypeBuilder {
+    /// Sets the [`launch_configuration_name`](struct.LaunchConfigurationNameType.html#structfield.launch_configuration_name) member.
+    pub fn launch_configuration_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.launch_configuration_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `LaunchConfigurationNameType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<LaunchConfigurationNameType, ::rusoto_core::BuildError> {
+        Ok(LaunchConfigurationNameType {
+            launch_configuration_name: self.launch_configuration_name.ok_or(
+                ::rusoto_core::BuildError::missing("launch_configuration_name"),
+            )?,
+        })
+    }
+}
+
+/// Serialize `LaunchConfigurationNameType` contents to a `SignedRequest`.
+struct LaunchConfigurationNameTypeSerializer;
+impl LaunchConfigurationNameTypeSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &LaunchConfigurationNameType) {
         let mut prefix = name.to_string();
         if prefix != "" {
             prefix.push_str(".");
@@ -3796,6 +5716,52 @@ pub struct LaunchConfigurationNamesType {
     pub next_token: Option<String>,
 }
 
+impl LaunchConfigurationNamesType {
+    /// Returns a builder for `LaunchConfigurationNamesType`, checking its required members when built.
+    pub fn builder() -> LaunchConfigurationNamesTypeBuilder {
+        LaunchConfigurationNamesTypeBuilder::default()
+    }
+}
+
+/// Builds a [`LaunchConfigurationNamesType`](struct.LaunchConfigurationNamesType.html).
+#[derive(Default, Debug)]
+pub struct LaunchConfigurationNamesTypeBuilder {
+    launch_configuration_names: Option<Vec<String>>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl LaunchConfigurationNamesTypeBuilder {
+    /// Sets the [`launch_configuration_names`](struct.LaunchConfigurationNamesType.html#structfield.launch_configuration_names) member.
+    pub fn launch_configuration_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.launch_configuration_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.LaunchConfigurationNamesType.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.LaunchConfigurationNamesType.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `LaunchConfigurationNamesType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<LaunchConfigurationNamesType, ::rusoto_core::BuildError> {
+        Ok(LaunchConfigurationNamesType {
+            launch_configuration_names: self.launch_configuration_names,
+            max_records: self.max_records,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `LaunchConfigurationNamesType` contents to a `SignedRequest`.
 struct LaunchConfigurationNamesTypeSerializer;
 impl LaunchConfigurationNamesTypeSerializer {
@@ -3821,10 +5787,10 @@ impl LaunchConfigurationNamesTypeSerializer {
     }
 }
 
-struct LaunchConfigurationsDeserializer;
+pub(crate) struct LaunchConfigurationsDeserializer;
 impl LaunchConfigurationsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LaunchConfiguration>, XmlParseError> {
@@ -3848,10 +5814,10 @@ pub struct LaunchConfigurationsType {
     pub next_token: Option<String>,
 }
 
-struct LaunchConfigurationsTypeDeserializer;
+pub(crate) struct LaunchConfigurationsTypeDeserializer;
 impl LaunchConfigurationsTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LaunchConfigurationsType, XmlParseError> {
@@ -3888,10 +5854,10 @@ pub struct LaunchTemplate {
     pub overrides: Option<Vec<LaunchTemplateOverrides>>,
 }
 
-struct LaunchTemplateDeserializer;
+pub(crate) struct LaunchTemplateDeserializer;
 impl LaunchTemplateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LaunchTemplate, XmlParseError> {
@@ -3942,10 +5908,13 @@ impl LaunchTemplateSerializer {
     }
 }
 
-struct LaunchTemplateNameDeserializer;
+pub(crate) struct LaunchTemplateNameDeserializer;
 impl LaunchTemplateNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3960,10 +5929,10 @@ pub struct LaunchTemplateOverrides {
     pub instance_type: Option<String>,
 }
 
-struct LaunchTemplateOverridesDeserializer;
+pub(crate) struct LaunchTemplateOverridesDeserializer;
 impl LaunchTemplateOverridesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LaunchTemplateOverrides, XmlParseError> {
@@ -4012,10 +5981,10 @@ pub struct LaunchTemplateSpecification {
     pub version: Option<String>,
 }
 
-struct LaunchTemplateSpecificationDeserializer;
+pub(crate) struct LaunchTemplateSpecificationDeserializer;
 impl LaunchTemplateSpecificationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LaunchTemplateSpecification, XmlParseError> {
@@ -4071,10 +6040,13 @@ impl LaunchTemplateSpecificationSerializer {
     }
 }
 
-struct LifecycleActionResultDeserializer;
+pub(crate) struct LifecycleActionResultDeserializer;
 impl LifecycleActionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4105,10 +6077,10 @@ pub struct LifecycleHook {
     pub role_arn: Option<String>,
 }
 
-struct LifecycleHookDeserializer;
+pub(crate) struct LifecycleHookDeserializer;
 impl LifecycleHookDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LifecycleHook, XmlParseError> {
@@ -4254,10 +6226,10 @@ impl LifecycleHookSpecificationsSerializer {
     }
 }
 
-struct LifecycleHooksDeserializer;
+pub(crate) struct LifecycleHooksDeserializer;
 impl LifecycleHooksDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LifecycleHook>, XmlParseError> {
@@ -4271,21 +6243,170 @@ impl LifecycleHooksDeserializer {
         })
     }
 }
-struct LifecycleStateDeserializer;
+pub(crate) struct LifecycleStateDeserializer;
 impl LifecycleStateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<LifecycleState, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct LifecycleTransitionDeserializer;
+/// <p>A description of the current lifecycle state. The <code>Quarantined</code> state is not used.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LifecycleState {
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>Pending:Wait</code></p>
+    PendingWait,
+    /// <p><code>Pending:Proceed</code></p>
+    PendingProceed,
+    /// <p><code>Quarantined</code></p>
+    Quarantined,
+    /// <p><code>InService</code></p>
+    InService,
+    /// <p><code>Terminating</code></p>
+    Terminating,
+    /// <p><code>Terminating:Wait</code></p>
+    TerminatingWait,
+    /// <p><code>Terminating:Proceed</code></p>
+    TerminatingProceed,
+    /// <p><code>Terminated</code></p>
+    Terminated,
+    /// <p><code>Detaching</code></p>
+    Detaching,
+    /// <p><code>Detached</code></p>
+    Detached,
+    /// <p><code>EnteringStandby</code></p>
+    EnteringStandby,
+    /// <p><code>Standby</code></p>
+    Standby,
+    /// <p><code>Warmed:Pending</code></p>
+    WarmedPending,
+    /// <p><code>Warmed:Pending:Wait</code></p>
+    WarmedPendingWait,
+    /// <p><code>Warmed:Pending:Proceed</code></p>
+    WarmedPendingProceed,
+    /// <p><code>Warmed:Terminating</code></p>
+    WarmedTerminating,
+    /// <p><code>Warmed:Terminating:Wait</code></p>
+    WarmedTerminatingWait,
+    /// <p><code>Warmed:Terminating:Proceed</code></p>
+    WarmedTerminatingProceed,
+    /// <p><code>Warmed:Terminated</code></p>
+    WarmedTerminated,
+    /// <p><code>Warmed:Stopped</code></p>
+    WarmedStopped,
+    /// <p><code>Warmed:Running</code></p>
+    WarmedRunning,
+    /// <p><code>Warmed:Hibernated</code></p>
+    WarmedHibernated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LifecycleState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LifecycleState::Pending => "Pending",
+            LifecycleState::PendingWait => "Pending:Wait",
+            LifecycleState::PendingProceed => "Pending:Proceed",
+            LifecycleState::Quarantined => "Quarantined",
+            LifecycleState::InService => "InService",
+            LifecycleState::Terminating => "Terminating",
+            LifecycleState::TerminatingWait => "Terminating:Wait",
+            LifecycleState::TerminatingProceed => "Terminating:Proceed",
+            LifecycleState::Terminated => "Terminated",
+            LifecycleState::Detaching => "Detaching",
+            LifecycleState::Detached => "Detached",
+            LifecycleState::EnteringStandby => "EnteringStandby",
+            LifecycleState::Standby => "Standby",
+            LifecycleState::WarmedPending => "Warmed:Pending",
+            LifecycleState::WarmedPendingWait => "Warmed:Pending:Wait",
+            LifecycleState::WarmedPendingProceed => "Warmed:Pending:Proceed",
+            LifecycleState::WarmedTerminating => "Warmed:Terminating",
+            LifecycleState::WarmedTerminatingWait => "Warmed:Terminating:Wait",
+            LifecycleState::WarmedTerminatingProceed => "Warmed:Terminating:Proceed",
+            LifecycleState::WarmedTerminated => "Warmed:Terminated",
+            LifecycleState::WarmedStopped => "Warmed:Stopped",
+            LifecycleState::WarmedRunning => "Warmed:Running",
+            LifecycleState::WarmedHibernated => "Warmed:Hibernated",
+            LifecycleState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LifecycleState {
+    fn from(value: &'a str) -> LifecycleState {
+        match value {
+            "Pending" => LifecycleState::Pending,
+            "Pending:Wait" => LifecycleState::PendingWait,
+            "Pending:Proceed" => LifecycleState::PendingProceed,
+            "Quarantined" => LifecycleState::Quarantined,
+            "InService" => LifecycleState::InService,
+            "Terminating" => LifecycleState::Terminating,
+            "Terminating:Wait" => LifecycleState::TerminatingWait,
+            "Terminating:Proceed" => LifecycleState::TerminatingProceed,
+            "Terminated" => LifecycleState::Terminated,
+            "Detaching" => LifecycleState::Detaching,
+            "Detached" => LifecycleState::Detached,
+            "EnteringStandby" => LifecycleState::EnteringStandby,
+            "Standby" => LifecycleState::Standby,
+            "Warmed:Pending" => LifecycleState::WarmedPending,
+            "Warmed:Pending:Wait" => LifecycleState::WarmedPendingWait,
+            "Warmed:Pending:Proceed" => LifecycleState::WarmedPendingProceed,
+            "Warmed:Terminating" => LifecycleState::WarmedTerminating,
+            "Warmed:Terminating:Wait" => LifecycleState::WarmedTerminatingWait,
+            "Warmed:Terminating:Proceed" => LifecycleState::WarmedTerminatingProceed,
+            "Warmed:Terminated" => LifecycleState::WarmedTerminated,
+            "Warmed:Stopped" => LifecycleState::WarmedStopped,
+            "Warmed:Running" => LifecycleState::WarmedRunning,
+            "Warmed:Hibernated" => LifecycleState::WarmedHibernated,
+            _ => LifecycleState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LifecycleState {
+    fn from(value: String) -> LifecycleState {
+        LifecycleState::from(value.as_str())
+    }
+}
+
+impl From<LifecycleState> for String {
+    fn from(value: LifecycleState) -> String {
+        match value {
+            LifecycleState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LifecycleState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LifecycleState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct LifecycleTransitionDeserializer;
 impl LifecycleTransitionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4293,10 +6414,10 @@ impl LifecycleTransitionDeserializer {
         Ok(obj)
     }
 }
-struct LoadBalancerNamesDeserializer;
+pub(crate) struct LoadBalancerNamesDeserializer;
 impl LoadBalancerNamesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -4333,10 +6454,10 @@ pub struct LoadBalancerState {
     pub state: Option<String>,
 }
 
-struct LoadBalancerStateDeserializer;
+pub(crate) struct LoadBalancerStateDeserializer;
 impl LoadBalancerStateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoadBalancerState, XmlParseError> {
@@ -4357,10 +6478,10 @@ impl LoadBalancerStateDeserializer {
         })
     }
 }
-struct LoadBalancerStatesDeserializer;
+pub(crate) struct LoadBalancerStatesDeserializer;
 impl LoadBalancerStatesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LoadBalancerState>, XmlParseError> {
@@ -4383,10 +6504,10 @@ pub struct LoadBalancerTargetGroupState {
     pub state: Option<String>,
 }
 
-struct LoadBalancerTargetGroupStateDeserializer;
+pub(crate) struct LoadBalancerTargetGroupStateDeserializer;
 impl LoadBalancerTargetGroupStateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoadBalancerTargetGroupState, XmlParseError> {
@@ -4413,10 +6534,10 @@ impl LoadBalancerTargetGroupStateDeserializer {
         )
     }
 }
-struct LoadBalancerTargetGroupStatesDeserializer;
+pub(crate) struct LoadBalancerTargetGroupStatesDeserializer;
 impl LoadBalancerTargetGroupStatesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LoadBalancerTargetGroupState>, XmlParseError> {
@@ -4432,10 +6553,13 @@ impl LoadBalancerTargetGroupStatesDeserializer {
         })
     }
 }
-struct MaxNumberOfAutoScalingGroupsDeserializer;
+pub(crate) struct MaxNumberOfAutoScalingGroupsDeserializer;
 impl MaxNumberOfAutoScalingGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4443,10 +6567,13 @@ impl MaxNumberOfAutoScalingGroupsDeserializer {
         Ok(obj)
     }
 }
-struct MaxNumberOfLaunchConfigurationsDeserializer;
+pub(crate) struct MaxNumberOfLaunchConfigurationsDeserializer;
 impl MaxNumberOfLaunchConfigurationsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4461,10 +6588,10 @@ pub struct MetricCollectionType {
     pub metric: Option<String>,
 }
 
-struct MetricCollectionTypeDeserializer;
+pub(crate) struct MetricCollectionTypeDeserializer;
 impl MetricCollectionTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MetricCollectionType, XmlParseError> {
@@ -4481,10 +6608,10 @@ impl MetricCollectionTypeDeserializer {
         })
     }
 }
-struct MetricCollectionTypesDeserializer;
+pub(crate) struct MetricCollectionTypesDeserializer;
 impl MetricCollectionTypesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<MetricCollectionType>, XmlParseError> {
@@ -4509,10 +6636,10 @@ pub struct MetricDimension {
     pub value: String,
 }
 
-struct MetricDimensionDeserializer;
+pub(crate) struct MetricDimensionDeserializer;
 impl MetricDimensionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MetricDimension, XmlParseError> {
@@ -4545,10 +6672,13 @@ impl MetricDimensionSerializer {
     }
 }
 
-struct MetricDimensionNameDeserializer;
+pub(crate) struct MetricDimensionNameDeserializer;
 impl MetricDimensionNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4556,10 +6686,13 @@ impl MetricDimensionNameDeserializer {
         Ok(obj)
     }
 }
-struct MetricDimensionValueDeserializer;
+pub(crate) struct MetricDimensionValueDeserializer;
 impl MetricDimensionValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4567,10 +6700,10 @@ impl MetricDimensionValueDeserializer {
         Ok(obj)
     }
 }
-struct MetricDimensionsDeserializer;
+pub(crate) struct MetricDimensionsDeserializer;
 impl MetricDimensionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<MetricDimension>, XmlParseError> {
@@ -4603,10 +6736,10 @@ pub struct MetricGranularityType {
     pub granularity: Option<String>,
 }
 
-struct MetricGranularityTypeDeserializer;
+pub(crate) struct MetricGranularityTypeDeserializer;
 impl MetricGranularityTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MetricGranularityType, XmlParseError> {
@@ -4624,10 +6757,10 @@ impl MetricGranularityTypeDeserializer {
         })
     }
 }
-struct MetricGranularityTypesDeserializer;
+pub(crate) struct MetricGranularityTypesDeserializer;
 impl MetricGranularityTypesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<MetricGranularityType>, XmlParseError> {
@@ -4643,10 +6776,13 @@ impl MetricGranularityTypesDeserializer {
         })
     }
 }
-struct MetricNameDeserializer;
+pub(crate) struct MetricNameDeserializer;
 impl MetricNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4654,10 +6790,13 @@ impl MetricNameDeserializer {
         Ok(obj)
     }
 }
-struct MetricNamespaceDeserializer;
+pub(crate) struct MetricNamespaceDeserializer;
 impl MetricNamespaceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4665,10 +6804,13 @@ impl MetricNamespaceDeserializer {
         Ok(obj)
     }
 }
-struct MetricScaleDeserializer;
+pub(crate) struct MetricScaleDeserializer;
 impl MetricScaleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4676,32 +6818,179 @@ impl MetricScaleDeserializer {
         Ok(obj)
     }
 }
-struct MetricStatisticDeserializer;
+pub(crate) struct MetricStatisticDeserializer;
 impl MetricStatisticDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<MetricStatistic, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct MetricTypeDeserializer;
+/// <p>The statistic of the metric.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetricStatistic {
+    /// <p><code>Average</code></p>
+    Average,
+    /// <p><code>Minimum</code></p>
+    Minimum,
+    /// <p><code>Maximum</code></p>
+    Maximum,
+    /// <p><code>SampleCount</code></p>
+    SampleCount,
+    /// <p><code>Sum</code></p>
+    Sum,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MetricStatistic {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricStatistic::Average => "Average",
+            MetricStatistic::Minimum => "Minimum",
+            MetricStatistic::Maximum => "Maximum",
+            MetricStatistic::SampleCount => "SampleCount",
+            MetricStatistic::Sum => "Sum",
+            MetricStatistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MetricStatistic {
+    fn from(value: &'a str) -> MetricStatistic {
+        match value {
+            "Average" => MetricStatistic::Average,
+            "Minimum" => MetricStatistic::Minimum,
+            "Maximum" => MetricStatistic::Maximum,
+            "SampleCount" => MetricStatistic::SampleCount,
+            "Sum" => MetricStatistic::Sum,
+            _ => MetricStatistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricStatistic {
+    fn from(value: String) -> MetricStatistic {
+        MetricStatistic::from(value.as_str())
+    }
+}
+
+impl From<MetricStatistic> for String {
+    fn from(value: MetricStatistic) -> String {
+        match value {
+            MetricStatistic::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MetricStatistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricStatistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct MetricTypeDeserializer;
 impl MetricTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<MetricType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct MetricUnitDeserializer;
+/// <p>The metric type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetricType {
+    /// <p><code>ASGAverageCPUUtilization</code></p>
+    ASGAverageCPUUtilization,
+    /// <p><code>ASGAverageNetworkIn</code></p>
+    ASGAverageNetworkIn,
+    /// <p><code>ASGAverageNetworkOut</code></p>
+    ASGAverageNetworkOut,
+    /// <p><code>ALBRequestCountPerTarget</code></p>
+    ALBRequestCountPerTarget,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MetricType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricType::ASGAverageCPUUtilization => "ASGAverageCPUUtilization",
+            MetricType::ASGAverageNetworkIn => "ASGAverageNetworkIn",
+            MetricType::ASGAverageNetworkOut => "ASGAverageNetworkOut",
+            MetricType::ALBRequestCountPerTarget => "ALBRequestCountPerTarget",
+            MetricType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MetricType {
+    fn from(value: &'a str) -> MetricType {
+        match value {
+            "ASGAverageCPUUtilization" => MetricType::ASGAverageCPUUtilization,
+            "ASGAverageNetworkIn" => MetricType::ASGAverageNetworkIn,
+            "ASGAverageNetworkOut" => MetricType::ASGAverageNetworkOut,
+            "ALBRequestCountPerTarget" => MetricType::ALBRequestCountPerTarget,
+            _ => MetricType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricType {
+    fn from(value: String) -> MetricType {
+        MetricType::from(value.as_str())
+    }
+}
+
+impl From<MetricType> for String {
+    fn from(value: MetricType) -> String {
+        match value {
+            MetricType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MetricType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MetricType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct MetricUnitDeserializer;
 impl MetricUnitDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4721,10 +7010,13 @@ impl MetricsSerializer {
     }
 }
 
-struct MinAdjustmentMagnitudeDeserializer;
+pub(crate) struct MinAdjustmentMagnitudeDeserializer;
 impl MinAdjustmentMagnitudeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4732,10 +7024,13 @@ impl MinAdjustmentMagnitudeDeserializer {
         Ok(obj)
     }
 }
-struct MinAdjustmentStepDeserializer;
+pub(crate) struct MinAdjustmentStepDeserializer;
 impl MinAdjustmentStepDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4743,10 +7038,13 @@ impl MinAdjustmentStepDeserializer {
         Ok(obj)
     }
 }
-struct MixedInstanceSpotPriceDeserializer;
+pub(crate) struct MixedInstanceSpotPriceDeserializer;
 impl MixedInstanceSpotPriceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4763,10 +7061,10 @@ pub struct MixedInstancesPolicy {
     pub launch_template: Option<LaunchTemplate>,
 }
 
-struct MixedInstancesPolicyDeserializer;
+pub(crate) struct MixedInstancesPolicyDeserializer;
 impl MixedInstancesPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MixedInstancesPolicy, XmlParseError> {
@@ -4818,10 +7116,13 @@ impl MixedInstancesPolicySerializer {
     }
 }
 
-struct MonitoringEnabledDeserializer;
+pub(crate) struct MonitoringEnabledDeserializer;
 impl MonitoringEnabledDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4829,10 +7130,13 @@ impl MonitoringEnabledDeserializer {
         Ok(obj)
     }
 }
-struct NoDeviceDeserializer;
+pub(crate) struct NoDeviceDeserializer;
 impl NoDeviceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4851,10 +7155,10 @@ pub struct NotificationConfiguration {
     pub topic_arn: Option<String>,
 }
 
-struct NotificationConfigurationDeserializer;
+pub(crate) struct NotificationConfigurationDeserializer;
 impl NotificationConfigurationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<NotificationConfiguration, XmlParseError> {
@@ -4886,10 +7190,10 @@ impl NotificationConfigurationDeserializer {
         )
     }
 }
-struct NotificationConfigurationsDeserializer;
+pub(crate) struct NotificationConfigurationsDeserializer;
 impl NotificationConfigurationsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<NotificationConfiguration>, XmlParseError> {
@@ -4905,10 +7209,13 @@ impl NotificationConfigurationsDeserializer {
         })
     }
 }
-struct NumberOfAutoScalingGroupsDeserializer;
+pub(crate) struct NumberOfAutoScalingGroupsDeserializer;
 impl NumberOfAutoScalingGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4916,10 +7223,13 @@ impl NumberOfAutoScalingGroupsDeserializer {
         Ok(obj)
     }
 }
-struct NumberOfLaunchConfigurationsDeserializer;
+pub(crate) struct NumberOfLaunchConfigurationsDeserializer;
 impl NumberOfLaunchConfigurationsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4927,10 +7237,13 @@ impl NumberOfLaunchConfigurationsDeserializer {
         Ok(obj)
     }
 }
-struct OnDemandBaseCapacityDeserializer;
+pub(crate) struct OnDemandBaseCapacityDeserializer;
 impl OnDemandBaseCapacityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4938,10 +7251,13 @@ impl OnDemandBaseCapacityDeserializer {
         Ok(obj)
     }
 }
-struct OnDemandPercentageAboveBaseCapacityDeserializer;
+pub(crate) struct OnDemandPercentageAboveBaseCapacityDeserializer;
 impl OnDemandPercentageAboveBaseCapacityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4949,10 +7265,10 @@ impl OnDemandPercentageAboveBaseCapacityDeserializer {
         Ok(obj)
     }
 }
-struct OverridesDeserializer;
+pub(crate) struct OverridesDeserializer;
 impl OverridesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LaunchTemplateOverrides>, XmlParseError> {
@@ -4988,10 +7304,10 @@ pub struct PoliciesType {
     pub scaling_policies: Option<Vec<ScalingPolicy>>,
 }
 
-struct PoliciesTypeDeserializer;
+pub(crate) struct PoliciesTypeDeserializer;
 impl PoliciesTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PoliciesType, XmlParseError> {
@@ -5020,10 +7336,10 @@ pub struct PolicyARNType {
     pub policy_arn: Option<String>,
 }
 
-struct PolicyARNTypeDeserializer;
+pub(crate) struct PolicyARNTypeDeserializer;
 impl PolicyARNTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PolicyARNType, XmlParseError> {
@@ -5044,10 +7360,13 @@ impl PolicyARNTypeDeserializer {
         })
     }
 }
-struct PolicyIncrementDeserializer;
+pub(crate) struct PolicyIncrementDeserializer;
 impl PolicyIncrementDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5079,24 +7398,28 @@ impl PolicyTypesSerializer {
 }
 
 /// <p>Represents a predefined metric for a target tracking scaling policy to use with Amazon EC2 Auto Scaling.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PredefinedMetricSpecification {
     /// <p>The metric type.</p>
-    pub predefined_metric_type: String,
+    pub predefined_metric_type: MetricType,
     /// <p>Identifies the resource associated with the metric type. The following predefined metrics are available:</p> <ul> <li> <p> <code>ASGAverageCPUUtilization</code> - Average CPU utilization of the Auto Scaling group.</p> </li> <li> <p> <code>ASGAverageNetworkIn</code> - Average number of bytes received on all network interfaces by the Auto Scaling group.</p> </li> <li> <p> <code>ASGAverageNetworkOut</code> - Average number of bytes sent out on all network interfaces by the Auto Scaling group.</p> </li> <li> <p> <code>ALBRequestCountPerTarget</code> - Number of requests completed per target in an Application Load Balancer target group.</p> </li> </ul> <p>For predefined metric types <code>ASGAverageCPUUtilization</code>, <code>ASGAverageNetworkIn</code>, and <code>ASGAverageNetworkOut</code>, the parameter must not be specified as the resource associated with the metric type is the Auto Scaling group. For predefined metric type <code>ALBRequestCountPerTarget</code>, the parameter must be specified in the format: <code>app/<i>load-balancer-name</i>/<i>load-balancer-id</i>/targetgroup/<i>target-group-name</i>/<i>target-group-id</i> </code>, where <code>app/<i>load-balancer-name</i>/<i>load-balancer-id</i> </code> is the final portion of the load balancer ARN, and <code>targetgroup/<i>target-group-name</i>/<i>target-group-id</i> </code> is the final portion of the target group ARN. The target group must be attached to the Auto Scaling group.</p>
     pub resource_label: Option<String>,
 }
 
-struct PredefinedMetricSpecificationDeserializer;
+pub(crate) struct PredefinedMetricSpecificationDeserializer;
 impl PredefinedMetricSpecificationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PredefinedMetricSpecification, XmlParseError> {
-        deserialize_elements::<_, PredefinedMetricSpecification, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            PredefinedMetricSpecification {
+                predefined_metric_type: MetricType::Unknown(String::new()),
+                resource_label: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "PredefinedMetricType" => {
@@ -5154,10 +7477,10 @@ pub struct ProcessType {
     pub process_name: String,
 }
 
-struct ProcessTypeDeserializer;
+pub(crate) struct ProcessTypeDeserializer;
 impl ProcessTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ProcessType, XmlParseError> {
@@ -5173,10 +7496,10 @@ impl ProcessTypeDeserializer {
         })
     }
 }
-struct ProcessesDeserializer;
+pub(crate) struct ProcessesDeserializer;
 impl ProcessesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ProcessType>, XmlParseError> {
@@ -5196,10 +7519,10 @@ pub struct ProcessesType {
     pub processes: Option<Vec<ProcessType>>,
 }
 
-struct ProcessesTypeDeserializer;
+pub(crate) struct ProcessesTypeDeserializer;
 impl ProcessesTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ProcessesType, XmlParseError> {
@@ -5216,10 +7539,13 @@ impl ProcessesTypeDeserializer {
         })
     }
 }
-struct ProgressDeserializer;
+pub(crate) struct ProgressDeserializer;
 impl ProgressDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5227,10 +7553,13 @@ impl ProgressDeserializer {
         Ok(obj)
     }
 }
-struct PropagateAtLaunchDeserializer;
+pub(crate) struct PropagateAtLaunchDeserializer;
 impl PropagateAtLaunchDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5241,10 +7570,10 @@ impl PropagateAtLaunchDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PutLifecycleHookAnswer {}
 
-struct PutLifecycleHookAnswerDeserializer;
+pub(crate) struct PutLifecycleHookAnswerDeserializer;
 impl PutLifecycleHookAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PutLifecycleHookAnswer, XmlParseError> {
@@ -5277,6 +7606,94 @@ pub struct PutLifecycleHookType {
     pub role_arn: Option<String>,
 }
 
+impl PutLifecycleHookType {
+    /// Returns a builder for `PutLifecycleHookType`, checking its required members when built.
+    pub fn builder() -> PutLifecycleHookTypeBuilder {
+        PutLifecycleHookTypeBuilder::default()
+    }
+}
+
+/// Builds a [`PutLifecycleHookType`](struct.PutLifecycleHookType.html).
+#[derive(Default, Debug)]
+pub struct PutLifecycleHookTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    default_result: Option<String>,
+    heartbeat_timeout: Option<i64>,
+    lifecycle_hook_name: Option<String>,
+    lifecycle_transition: Option<String>,
+    notification_metadata: Option<String>,
+    notification_target_arn: Option<String>,
+    role_arn: Option<String>,
+}
+
+impl PutLifecycleHookTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.PutLifecycleHookType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_result`](struct.PutLifecycleHookType.html#structfield.default_result) member.
+    pub fn default_result<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_result = Some(value.into());
+        self
+    }
+
+    /// Sets the [`heartbeat_timeout`](struct.PutLifecycleHookType.html#structfield.heartbeat_timeout) member.
+    pub fn heartbeat_timeout<T: Into<i64>>(mut self, value: T) -> Self {
+        self.heartbeat_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_hook_name`](struct.PutLifecycleHookType.html#structfield.lifecycle_hook_name) member.
+    pub fn lifecycle_hook_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.lifecycle_hook_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_transition`](struct.PutLifecycleHookType.html#structfield.lifecycle_transition) member.
+    pub fn lifecycle_transition<T: Into<String>>(mut self, value: T) -> Self {
+        self.lifecycle_transition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_metadata`](struct.PutLifecycleHookType.html#structfield.notification_metadata) member.
+    pub fn notification_metadata<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_target_arn`](struct.PutLifecycleHookType.html#structfield.notification_target_arn) member.
+    pub fn notification_target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification_target_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.PutLifecycleHookType.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutLifecycleHookType`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutLifecycleHookType, ::rusoto_core::BuildError> {
+        Ok(PutLifecycleHookType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            default_result: self.default_result,
+            heartbeat_timeout: self.heartbeat_timeout,
+            lifecycle_hook_name: self
+                .lifecycle_hook_name
+                .ok_or(::rusoto_core::BuildError::missing("lifecycle_hook_name"))?,
+            lifecycle_transition: self.lifecycle_transition,
+            notification_metadata: self.notification_metadata,
+            notification_target_arn: self.notification_target_arn,
+            role_arn: self.role_arn,
+        })
+    }
+}
+
 /// Serialize `PutLifecycleHookType` contents to a `SignedRequest`.
 struct PutLifecycleHookTypeSerializer;
 impl PutLifecycleHookTypeSerializer {
@@ -5334,6 +7751,58 @@ pub struct PutNotificationConfigurationType {
     pub topic_arn: String,
 }
 
+impl PutNotificationConfigurationType {
+    /// Returns a builder for `PutNotificationConfigurationType`, checking its required members when built.
+    pub fn builder() -> PutNotificationConfigurationTypeBuilder {
+        PutNotificationConfigurationTypeBuilder::default()
+    }
+}
+
+/// Builds a [`PutNotificationConfigurationType`](struct.PutNotificationConfigurationType.html).
+#[derive(Default, Debug)]
+pub struct PutNotificationConfigurationTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    notification_types: Option<Vec<String>>,
+    topic_arn: Option<String>,
+}
+
+impl PutNotificationConfigurationTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.PutNotificationConfigurationType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_types`](struct.PutNotificationConfigurationType.html#structfield.notification_types) member.
+    pub fn notification_types<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.notification_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_arn`](struct.PutNotificationConfigurationType.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutNotificationConfigurationType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutNotificationConfigurationType, ::rusoto_core::BuildError> {
+        Ok(PutNotificationConfigurationType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            notification_types: self
+                .notification_types
+                .ok_or(::rusoto_core::BuildError::missing("notification_types"))?,
+            topic_arn: self
+                .topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("topic_arn"))?,
+        })
+    }
+}
+
 /// Serialize `PutNotificationConfigurationType` contents to a `SignedRequest`.
 struct PutNotificationConfigurationTypeSerializer;
 impl PutNotificationConfigurationTypeSerializer {
@@ -5384,6 +7853,129 @@ pub struct PutScalingPolicyType {
     pub target_tracking_configuration: Option<TargetTrackingConfiguration>,
 }
 
+impl PutScalingPolicyType {
+    /// Returns a builder for `PutScalingPolicyType`, checking its required members when built.
+    pub fn builder() -> PutScalingPolicyTypeBuilder {
+        PutScalingPolicyTypeBuilder::default()
+    }
+}
+
+/// Builds a [`PutScalingPolicyType`](struct.PutScalingPolicyType.html).
+#[derive(Default, Debug)]
+pub struct PutScalingPolicyTypeBuilder {
+    adjustment_type: Option<String>,
+    auto_scaling_group_name: Option<String>,
+    cooldown: Option<i64>,
+    estimated_instance_warmup: Option<i64>,
+    metric_aggregation_type: Option<String>,
+    min_adjustment_magnitude: Option<i64>,
+    min_adjustment_step: Option<i64>,
+    policy_name: Option<String>,
+    policy_type: Option<String>,
+    scaling_adjustment: Option<i64>,
+    step_adjustments: Option<Vec<StepAdjustment>>,
+    target_tracking_configuration: Option<TargetTrackingConfiguration>,
+}
+
+impl PutScalingPolicyTypeBuilder {
+    /// Sets the [`adjustment_type`](struct.PutScalingPolicyType.html#structfield.adjustment_type) member.
+    pub fn adjustment_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.adjustment_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_scaling_group_name`](struct.PutScalingPolicyType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cooldown`](struct.PutScalingPolicyType.html#structfield.cooldown) member.
+    pub fn cooldown<T: Into<i64>>(mut self, value: T) -> Self {
+        self.cooldown = Some(value.into());
+        self
+    }
+
+    /// Sets the [`estimated_instance_warmup`](struct.PutScalingPolicyType.html#structfield.estimated_instance_warmup) member.
+    pub fn estimated_instance_warmup<T: Into<i64>>(mut self, value: T) -> Self {
+        self.estimated_instance_warmup = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_aggregation_type`](struct.PutScalingPolicyType.html#structfield.metric_aggregation_type) member.
+    pub fn metric_aggregation_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_aggregation_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`min_adjustment_magnitude`](struct.PutScalingPolicyType.html#structfield.min_adjustment_magnitude) member.
+    pub fn min_adjustment_magnitude<T: Into<i64>>(mut self, value: T) -> Self {
+        self.min_adjustment_magnitude = Some(value.into());
+        self
+    }
+
+    /// Sets the [`min_adjustment_step`](struct.PutScalingPolicyType.html#structfield.min_adjustment_step) member.
+    pub fn min_adjustment_step<T: Into<i64>>(mut self, value: T) -> Self {
+        self.min_adjustment_step = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.PutScalingPolicyType.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_type`](struct.PutScalingPolicyType.html#structfield.policy_type) member.
+    pub fn policy_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_adjustment`](struct.PutScalingPolicyType.html#structfield.scaling_adjustment) member.
+    pub fn scaling_adjustment<T: Into<i64>>(mut self, value: T) -> Self {
+        self.scaling_adjustment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`step_adjustments`](struct.PutScalingPolicyType.html#structfield.step_adjustments) member.
+    pub fn step_adjustments<T: Into<Vec<StepAdjustment>>>(mut self, value: T) -> Self {
+        self.step_adjustments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_tracking_configuration`](struct.PutScalingPolicyType.html#structfield.target_tracking_configuration) member.
+    pub fn target_tracking_configuration<T: Into<TargetTrackingConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.target_tracking_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutScalingPolicyType`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutScalingPolicyType, ::rusoto_core::BuildError> {
+        Ok(PutScalingPolicyType {
+            adjustment_type: self.adjustment_type,
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            cooldown: self.cooldown,
+            estimated_instance_warmup: self.estimated_instance_warmup,
+            metric_aggregation_type: self.metric_aggregation_type,
+            min_adjustment_magnitude: self.min_adjustment_magnitude,
+            min_adjustment_step: self.min_adjustment_step,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            policy_type: self.policy_type,
+            scaling_adjustment: self.scaling_adjustment,
+            step_adjustments: self.step_adjustments,
+            target_tracking_configuration: self.target_tracking_configuration,
+        })
+    }
+}
+
 /// Serialize `PutScalingPolicyType` contents to a `SignedRequest`.
 struct PutScalingPolicyTypeSerializer;
 impl PutScalingPolicyTypeSerializer {
@@ -5455,7 +8047,7 @@ pub struct PutScheduledUpdateGroupActionType {
     /// <p>The number of EC2 instances that should be running in the Auto Scaling group.</p>
     pub desired_capacity: Option<i64>,
     /// <p>The date and time for the recurring schedule to end. Amazon EC2 Auto Scaling does not perform the action after this time. </p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>The maximum number of instances in the Auto Scaling group.</p>
     pub max_size: Option<i64>,
     /// <p>The minimum number of instances in the Auto Scaling group.</p>
@@ -5465,9 +8057,107 @@ pub struct PutScheduledUpdateGroupActionType {
     /// <p>The name of this scaling action.</p>
     pub scheduled_action_name: String,
     /// <p>The date and time for this action to start, in YYYY-MM-DDThh:mm:ssZ format in UTC/GMT only and in quotes (for example, <code>"2019-06-01T00:00:00Z"</code>).</p> <p>If you specify <code>Recurrence</code> and <code>StartTime</code>, Amazon EC2 Auto Scaling performs the action at this time, and then performs the action based on the specified recurrence.</p> <p>If you try to schedule your action in the past, Amazon EC2 Auto Scaling returns an error message.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
     /// <p>This parameter is no longer used.</p>
-    pub time: Option<String>,
+    pub time: Option<IsoTimestamp>,
+}
+
+impl PutScheduledUpdateGroupActionType {
+    /// Returns a builder for `PutScheduledUpdateGroupActionType`, checking its required members when built.
+    pub fn builder() -> PutScheduledUpdateGroupActionTypeBuilder {
+        PutScheduledUpdateGroupActionTypeBuilder::default()
+    }
+}
+
+/// Builds a [`PutScheduledUpdateGroupActionType`](struct.PutScheduledUpdateGroupActionType.html).
+#[derive(Default, Debug)]
+pub struct PutScheduledUpdateGroupActionTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    desired_capacity: Option<i64>,
+    end_time: Option<IsoTimestamp>,
+    max_size: Option<i64>,
+    min_size: Option<i64>,
+    recurrence: Option<String>,
+    scheduled_action_name: Option<String>,
+    start_time: Option<IsoTimestamp>,
+    time: Option<IsoTimestamp>,
+}
+
+impl PutScheduledUpdateGroupActionTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.PutScheduledUpdateGroupActionType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`desired_capacity`](struct.PutScheduledUpdateGroupActionType.html#structfield.desired_capacity) member.
+    pub fn desired_capacity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.desired_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.PutScheduledUpdateGroupActionType.html#structfield.end_time) member.
+    pub fn end_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_size`](struct.PutScheduledUpdateGroupActionType.html#structfield.max_size) member.
+    pub fn max_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`min_size`](struct.PutScheduledUpdateGroupActionType.html#structfield.min_size) member.
+    pub fn min_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.min_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recurrence`](struct.PutScheduledUpdateGroupActionType.html#structfield.recurrence) member.
+    pub fn recurrence<T: Into<String>>(mut self, value: T) -> Self {
+        self.recurrence = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduled_action_name`](struct.PutScheduledUpdateGroupActionType.html#structfield.scheduled_action_name) member.
+    pub fn scheduled_action_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scheduled_action_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.PutScheduledUpdateGroupActionType.html#structfield.start_time) member.
+    pub fn start_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time`](struct.PutScheduledUpdateGroupActionType.html#structfield.time) member.
+    pub fn time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.time = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutScheduledUpdateGroupActionType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutScheduledUpdateGroupActionType, ::rusoto_core::BuildError> {
+        Ok(PutScheduledUpdateGroupActionType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            desired_capacity: self.desired_capacity,
+            end_time: self.end_time,
+            max_size: self.max_size,
+            min_size: self.min_size,
+            recurrence: self.recurrence,
+            scheduled_action_name: self
+                .scheduled_action_name
+                .ok_or(::rusoto_core::BuildError::missing("scheduled_action_name"))?,
+            start_time: self.start_time,
+            time: self.time,
+        })
+    }
 }
 
 /// Serialize `PutScheduledUpdateGroupActionType` contents to a `SignedRequest`.
@@ -5514,10 +8204,10 @@ impl PutScheduledUpdateGroupActionTypeSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct RecordLifecycleActionHeartbeatAnswer {}
 
-struct RecordLifecycleActionHeartbeatAnswerDeserializer;
+pub(crate) struct RecordLifecycleActionHeartbeatAnswerDeserializer;
 impl RecordLifecycleActionHeartbeatAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RecordLifecycleActionHeartbeatAnswer, XmlParseError> {
@@ -5542,20 +8232,78 @@ pub struct RecordLifecycleActionHeartbeatType {
     pub lifecycle_hook_name: String,
 }
 
-/// Serialize `RecordLifecycleActionHeartbeatType` contents to a `SignedRequest`.
-struct RecordLifecycleActionHeartbeatTypeSerializer;
-impl RecordLifecycleActionHeartbeatTypeSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &RecordLifecycleActionHeartbeatType) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl RecordLifecycleActionHeartbeatType {
+    /// Returns a builder for `RecordLifecycleActionHeartbeatType`, checking its required members when built.
+    pub fn builder() -> RecordLifecycleActionHeartbeatTypeBuilder {
+        RecordLifecycleActionHeartbeatTypeBuilder::default()
+    }
+}
 
-        params.put(
-            &format!("{}{}", prefix, "AutoScalingGroupName"),
-            &obj.auto_scaling_group_name,
-        );
-        if let Some(ref field_value) = obj.instance_id {
+/// Builds a [`RecordLifecycleActionHeartbeatType`](struct.RecordLifecycleActionHeartbeatType.html).
+#[derive(Default, Debug)]
+pub struct RecordLifecycleActionHeartbeatTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    instance_id: Option<String>,
+    lifecycle_action_token: Option<String>,
+    lifecycle_hook_name: Option<String>,
+}
+
+impl RecordLifecycleActionHeartbeatTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.RecordLifecycleActionHeartbeatType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.RecordLifecycleActionHeartbeatType.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_action_token`](struct.RecordLifecycleActionHeartbeatType.html#structfield.lifecycle_action_token) member.
+    pub fn lifecycle_action_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.lifecycle_action_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_hook_name`](struct.RecordLifecycleActionHeartbeatType.html#structfield.lifecycle_hook_name) member.
+    pub fn lifecycle_hook_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.lifecycle_hook_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RecordLifecycleActionHeartbeatType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RecordLifecycleActionHeartbeatType, ::rusoto_core::BuildError> {
+        Ok(RecordLifecycleActionHeartbeatType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            instance_id: self.instance_id,
+            lifecycle_action_token: self.lifecycle_action_token,
+            lifecycle_hook_name: self
+                .lifecycle_hook_name
+                .ok_or(::rusoto_core::BuildError::missing("lifecycle_hook_name"))?,
+        })
+    }
+}
+
+/// Serialize `RecordLifecycleActionHeartbeatType` contents to a `SignedRequest`.
+struct RecordLifecycleActionHeartbeatTypeSerializer;
+impl RecordLifecycleActionHeartbeatTypeSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &RecordLifecycleActionHeartbeatType) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        params.put(
+            &format!("{}{}", prefix, "AutoScalingGroupName"),
+            &obj.auto_scaling_group_name,
+        );
+        if let Some(ref field_value) = obj.instance_id {
             params.put(&format!("{}{}", prefix, "InstanceId"), &field_value);
         }
         if let Some(ref field_value) = obj.lifecycle_action_token {
@@ -5571,10 +8319,13 @@ impl RecordLifecycleActionHeartbeatTypeSerializer {
     }
 }
 
-struct ResourceNameDeserializer;
+pub(crate) struct ResourceNameDeserializer;
 impl ResourceNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5582,21 +8333,139 @@ impl ResourceNameDeserializer {
         Ok(obj)
     }
 }
-struct ScalingActivityStatusCodeDeserializer;
+pub(crate) struct ScalingActivityStatusCodeDeserializer;
 impl ScalingActivityStatusCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ScalingActivityStatusCode, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ScalingPoliciesDeserializer;
+/// <p>The current status of the activity.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScalingActivityStatusCode {
+    /// <p><code>PendingSpotBidPlacement</code></p>
+    PendingSpotBidPlacement,
+    /// <p><code>WaitingForSpotInstanceRequestId</code></p>
+    WaitingForSpotInstanceRequestId,
+    /// <p><code>WaitingForSpotInstanceId</code></p>
+    WaitingForSpotInstanceId,
+    /// <p><code>WaitingForInstanceId</code></p>
+    WaitingForInstanceId,
+    /// <p><code>PreInService</code></p>
+    PreInService,
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>WaitingForELBConnectionDraining</code></p>
+    WaitingForELBConnectionDraining,
+    /// <p><code>MidLifecycleAction</code></p>
+    MidLifecycleAction,
+    /// <p><code>WaitingForInstanceWarmup</code></p>
+    WaitingForInstanceWarmup,
+    /// <p><code>Successful</code></p>
+    Successful,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Cancelled</code></p>
+    Cancelled,
+    /// <p><code>WaitingForConnectionDraining</code></p>
+    WaitingForConnectionDraining,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScalingActivityStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingActivityStatusCode::PendingSpotBidPlacement => "PendingSpotBidPlacement",
+            ScalingActivityStatusCode::WaitingForSpotInstanceRequestId => {
+                "WaitingForSpotInstanceRequestId"
+            }
+            ScalingActivityStatusCode::WaitingForSpotInstanceId => "WaitingForSpotInstanceId",
+            ScalingActivityStatusCode::WaitingForInstanceId => "WaitingForInstanceId",
+            ScalingActivityStatusCode::PreInService => "PreInService",
+            ScalingActivityStatusCode::InProgress => "InProgress",
+            ScalingActivityStatusCode::WaitingForELBConnectionDraining => {
+                "WaitingForELBConnectionDraining"
+            }
+            ScalingActivityStatusCode::MidLifecycleAction => "MidLifecycleAction",
+            ScalingActivityStatusCode::WaitingForInstanceWarmup => "WaitingForInstanceWarmup",
+            ScalingActivityStatusCode::Successful => "Successful",
+            ScalingActivityStatusCode::Failed => "Failed",
+            ScalingActivityStatusCode::Cancelled => "Cancelled",
+            ScalingActivityStatusCode::WaitingForConnectionDraining => {
+                "WaitingForConnectionDraining"
+            }
+            ScalingActivityStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScalingActivityStatusCode {
+    fn from(value: &'a str) -> ScalingActivityStatusCode {
+        match value {
+            "PendingSpotBidPlacement" => ScalingActivityStatusCode::PendingSpotBidPlacement,
+            "WaitingForSpotInstanceRequestId" => {
+                ScalingActivityStatusCode::WaitingForSpotInstanceRequestId
+            }
+            "WaitingForSpotInstanceId" => ScalingActivityStatusCode::WaitingForSpotInstanceId,
+            "WaitingForInstanceId" => ScalingActivityStatusCode::WaitingForInstanceId,
+            "PreInService" => ScalingActivityStatusCode::PreInService,
+            "InProgress" => ScalingActivityStatusCode::InProgress,
+            "WaitingForELBConnectionDraining" => {
+                ScalingActivityStatusCode::WaitingForELBConnectionDraining
+            }
+            "MidLifecycleAction" => ScalingActivityStatusCode::MidLifecycleAction,
+            "WaitingForInstanceWarmup" => ScalingActivityStatusCode::WaitingForInstanceWarmup,
+            "Successful" => ScalingActivityStatusCode::Successful,
+            "Failed" => ScalingActivityStatusCode::Failed,
+            "Cancelled" => ScalingActivityStatusCode::Cancelled,
+            "WaitingForConnectionDraining" => {
+                ScalingActivityStatusCode::WaitingForConnectionDraining
+            }
+            _ => ScalingActivityStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingActivityStatusCode {
+    fn from(value: String) -> ScalingActivityStatusCode {
+        ScalingActivityStatusCode::from(value.as_str())
+    }
+}
+
+impl From<ScalingActivityStatusCode> for String {
+    fn from(value: ScalingActivityStatusCode) -> String {
+        match value {
+            ScalingActivityStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScalingActivityStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScalingActivityStatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ScalingPoliciesDeserializer;
 impl ScalingPoliciesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ScalingPolicy>, XmlParseError> {
@@ -5643,10 +8512,10 @@ pub struct ScalingPolicy {
     pub target_tracking_configuration: Option<TargetTrackingConfiguration>,
 }
 
-struct ScalingPolicyDeserializer;
+pub(crate) struct ScalingPolicyDeserializer;
 impl ScalingPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ScalingPolicy, XmlParseError> {
@@ -5745,6 +8614,44 @@ pub struct ScalingProcessQuery {
     pub scaling_processes: Option<Vec<String>>,
 }
 
+impl ScalingProcessQuery {
+    /// Returns a builder for `ScalingProcessQuery`, checking its required members when built.
+    pub fn builder() -> ScalingProcessQueryBuilder {
+        ScalingProcessQueryBuilder::default()
+    }
+}
+
+/// Builds a [`ScalingProcessQuery`](struct.ScalingProcessQuery.html).
+#[derive(Default, Debug)]
+pub struct ScalingProcessQueryBuilder {
+    auto_scaling_group_name: Option<String>,
+    scaling_processes: Option<Vec<String>>,
+}
+
+impl ScalingProcessQueryBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.ScalingProcessQuery.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_processes`](struct.ScalingProcessQuery.html#structfield.scaling_processes) member.
+    pub fn scaling_processes<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.scaling_processes = Some(value.into());
+        self
+    }
+
+    /// Builds the `ScalingProcessQuery`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ScalingProcessQuery, ::rusoto_core::BuildError> {
+        Ok(ScalingProcessQuery {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            scaling_processes: self.scaling_processes,
+        })
+    }
+}
+
 /// Serialize `ScalingProcessQuery` contents to a `SignedRequest`.
 struct ScalingProcessQuerySerializer;
 impl ScalingProcessQuerySerializer {
@@ -5787,10 +8694,10 @@ pub struct ScheduledActionsType {
     pub scheduled_update_group_actions: Option<Vec<ScheduledUpdateGroupAction>>,
 }
 
-struct ScheduledActionsTypeDeserializer;
+pub(crate) struct ScheduledActionsTypeDeserializer;
 impl ScheduledActionsTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ScheduledActionsType, XmlParseError> {
@@ -5821,7 +8728,7 @@ pub struct ScheduledUpdateGroupAction {
     /// <p>The number of instances you prefer to maintain in the group.</p>
     pub desired_capacity: Option<i64>,
     /// <p>The date and time in UTC for the recurring schedule to end. For example, <code>"2019-06-01T00:00:00Z"</code>. </p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>The maximum number of instances in the Auto Scaling group.</p>
     pub max_size: Option<i64>,
     /// <p>The minimum number of instances in the Auto Scaling group.</p>
@@ -5833,15 +8740,15 @@ pub struct ScheduledUpdateGroupAction {
     /// <p>The name of the scheduled action.</p>
     pub scheduled_action_name: Option<String>,
     /// <p>The date and time in UTC for this action to start. For example, <code>"2019-06-01T00:00:00Z"</code>. </p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
     /// <p>This parameter is no longer used.</p>
-    pub time: Option<String>,
+    pub time: Option<IsoTimestamp>,
 }
 
-struct ScheduledUpdateGroupActionDeserializer;
+pub(crate) struct ScheduledUpdateGroupActionDeserializer;
 impl ScheduledUpdateGroupActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ScheduledUpdateGroupAction, XmlParseError> {
@@ -5917,7 +8824,7 @@ pub struct ScheduledUpdateGroupActionRequest {
     /// <p>The number of EC2 instances that should be running in the group.</p>
     pub desired_capacity: Option<i64>,
     /// <p>The date and time for the recurring schedule to end. Amazon EC2 Auto Scaling does not perform the action after this time.</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>The maximum number of instances in the Auto Scaling group.</p>
     pub max_size: Option<i64>,
     /// <p>The minimum number of instances in the Auto Scaling group.</p>
@@ -5927,7 +8834,7 @@ pub struct ScheduledUpdateGroupActionRequest {
     /// <p>The name of the scaling action.</p>
     pub scheduled_action_name: String,
     /// <p>The date and time for the action to start, in YYYY-MM-DDThh:mm:ssZ format in UTC/GMT only and in quotes (for example, <code>"2019-06-01T00:00:00Z"</code>).</p> <p>If you specify <code>Recurrence</code> and <code>StartTime</code>, Amazon EC2 Auto Scaling performs the action at this time, and then performs the action based on the specified recurrence.</p> <p>If you try to schedule the action in the past, Amazon EC2 Auto Scaling returns an error message.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
 }
 
 /// Serialize `ScheduledUpdateGroupActionRequest` contents to a `SignedRequest`.
@@ -5975,10 +8882,10 @@ impl ScheduledUpdateGroupActionRequestsSerializer {
     }
 }
 
-struct ScheduledUpdateGroupActionsDeserializer;
+pub(crate) struct ScheduledUpdateGroupActionsDeserializer;
 impl ScheduledUpdateGroupActionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ScheduledUpdateGroupAction>, XmlParseError> {
@@ -5994,10 +8901,10 @@ impl ScheduledUpdateGroupActionsDeserializer {
         })
     }
 }
-struct SecurityGroupsDeserializer;
+pub(crate) struct SecurityGroupsDeserializer;
 impl SecurityGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6033,6 +8940,54 @@ pub struct SetDesiredCapacityType {
     pub honor_cooldown: Option<bool>,
 }
 
+impl SetDesiredCapacityType {
+    /// Returns a builder for `SetDesiredCapacityType`, checking its required members when built.
+    pub fn builder() -> SetDesiredCapacityTypeBuilder {
+        SetDesiredCapacityTypeBuilder::default()
+    }
+}
+
+/// Builds a [`SetDesiredCapacityType`](struct.SetDesiredCapacityType.html).
+#[derive(Default, Debug)]
+pub struct SetDesiredCapacityTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    desired_capacity: Option<i64>,
+    honor_cooldown: Option<bool>,
+}
+
+impl SetDesiredCapacityTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.SetDesiredCapacityType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`desired_capacity`](struct.SetDesiredCapacityType.html#structfield.desired_capacity) member.
+    pub fn desired_capacity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.desired_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`honor_cooldown`](struct.SetDesiredCapacityType.html#structfield.honor_cooldown) member.
+    pub fn honor_cooldown<T: Into<bool>>(mut self, value: T) -> Self {
+        self.honor_cooldown = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetDesiredCapacityType`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetDesiredCapacityType, ::rusoto_core::BuildError> {
+        Ok(SetDesiredCapacityType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            desired_capacity: self
+                .desired_capacity
+                .ok_or(::rusoto_core::BuildError::missing("desired_capacity"))?,
+            honor_cooldown: self.honor_cooldown,
+        })
+    }
+}
+
 /// Serialize `SetDesiredCapacityType` contents to a `SignedRequest`.
 struct SetDesiredCapacityTypeSerializer;
 impl SetDesiredCapacityTypeSerializer {
@@ -6066,6 +9021,54 @@ pub struct SetInstanceHealthQuery {
     pub should_respect_grace_period: Option<bool>,
 }
 
+impl SetInstanceHealthQuery {
+    /// Returns a builder for `SetInstanceHealthQuery`, checking its required members when built.
+    pub fn builder() -> SetInstanceHealthQueryBuilder {
+        SetInstanceHealthQueryBuilder::default()
+    }
+}
+
+/// Builds a [`SetInstanceHealthQuery`](struct.SetInstanceHealthQuery.html).
+#[derive(Default, Debug)]
+pub struct SetInstanceHealthQueryBuilder {
+    health_status: Option<String>,
+    instance_id: Option<String>,
+    should_respect_grace_period: Option<bool>,
+}
+
+impl SetInstanceHealthQueryBuilder {
+    /// Sets the [`health_status`](struct.SetInstanceHealthQuery.html#structfield.health_status) member.
+    pub fn health_status<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.SetInstanceHealthQuery.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`should_respect_grace_period`](struct.SetInstanceHealthQuery.html#structfield.should_respect_grace_period) member.
+    pub fn should_respect_grace_period<T: Into<bool>>(mut self, value: T) -> Self {
+        self.should_respect_grace_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetInstanceHealthQuery`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetInstanceHealthQuery, ::rusoto_core::BuildError> {
+        Ok(SetInstanceHealthQuery {
+            health_status: self
+                .health_status
+                .ok_or(::rusoto_core::BuildError::missing("health_status"))?,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            should_respect_grace_period: self.should_respect_grace_period,
+        })
+    }
+}
+
 /// Serialize `SetInstanceHealthQuery` contents to a `SignedRequest`.
 struct SetInstanceHealthQuerySerializer;
 impl SetInstanceHealthQuerySerializer {
@@ -6089,10 +9092,10 @@ impl SetInstanceHealthQuerySerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetInstanceProtectionAnswer {}
 
-struct SetInstanceProtectionAnswerDeserializer;
+pub(crate) struct SetInstanceProtectionAnswerDeserializer;
 impl SetInstanceProtectionAnswerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetInstanceProtectionAnswer, XmlParseError> {
@@ -6115,6 +9118,58 @@ pub struct SetInstanceProtectionQuery {
     pub protected_from_scale_in: bool,
 }
 
+impl SetInstanceProtectionQuery {
+    /// Returns a builder for `SetInstanceProtectionQuery`, checking its required members when built.
+    pub fn builder() -> SetInstanceProtectionQueryBuilder {
+        SetInstanceProtectionQueryBuilder::default()
+    }
+}
+
+/// Builds a [`SetInstanceProtectionQuery`](struct.SetInstanceProtectionQuery.html).
+#[derive(Default, Debug)]
+pub struct SetInstanceProtectionQueryBuilder {
+    auto_scaling_group_name: Option<String>,
+    instance_ids: Option<Vec<String>>,
+    protected_from_scale_in: Option<bool>,
+}
+
+impl SetInstanceProtectionQueryBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.SetInstanceProtectionQuery.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_ids`](struct.SetInstanceProtectionQuery.html#structfield.instance_ids) member.
+    pub fn instance_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`protected_from_scale_in`](struct.SetInstanceProtectionQuery.html#structfield.protected_from_scale_in) member.
+    pub fn protected_from_scale_in<T: Into<bool>>(mut self, value: T) -> Self {
+        self.protected_from_scale_in = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetInstanceProtectionQuery`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetInstanceProtectionQuery, ::rusoto_core::BuildError> {
+        Ok(SetInstanceProtectionQuery {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            instance_ids: self
+                .instance_ids
+                .ok_or(::rusoto_core::BuildError::missing("instance_ids"))?,
+            protected_from_scale_in: self.protected_from_scale_in.ok_or(
+                ::rusoto_core::BuildError::missing("protected_from_scale_in"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `SetInstanceProtectionQuery` contents to a `SignedRequest`.
 struct SetInstanceProtectionQuerySerializer;
 impl SetInstanceProtectionQuerySerializer {
@@ -6140,10 +9195,13 @@ impl SetInstanceProtectionQuerySerializer {
     }
 }
 
-struct SpotInstancePoolsDeserializer;
+pub(crate) struct SpotInstancePoolsDeserializer;
 impl SpotInstancePoolsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -6151,10 +9209,13 @@ impl SpotInstancePoolsDeserializer {
         Ok(obj)
     }
 }
-struct SpotPriceDeserializer;
+pub(crate) struct SpotPriceDeserializer;
 impl SpotPriceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6173,10 +9234,10 @@ pub struct StepAdjustment {
     pub scaling_adjustment: i64,
 }
 
-struct StepAdjustmentDeserializer;
+pub(crate) struct StepAdjustmentDeserializer;
 impl StepAdjustmentDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StepAdjustment, XmlParseError> {
@@ -6233,10 +9294,10 @@ impl StepAdjustmentSerializer {
     }
 }
 
-struct StepAdjustmentsDeserializer;
+pub(crate) struct StepAdjustmentsDeserializer;
 impl StepAdjustmentsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<StepAdjustment>, XmlParseError> {
@@ -6271,10 +9332,10 @@ pub struct SuspendedProcess {
     pub suspension_reason: Option<String>,
 }
 
-struct SuspendedProcessDeserializer;
+pub(crate) struct SuspendedProcessDeserializer;
 impl SuspendedProcessDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SuspendedProcess, XmlParseError> {
@@ -6298,10 +9359,10 @@ impl SuspendedProcessDeserializer {
         })
     }
 }
-struct SuspendedProcessesDeserializer;
+pub(crate) struct SuspendedProcessesDeserializer;
 impl SuspendedProcessesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SuspendedProcess>, XmlParseError> {
@@ -6370,10 +9431,10 @@ pub struct TagDescription {
     pub value: Option<String>,
 }
 
-struct TagDescriptionDeserializer;
+pub(crate) struct TagDescriptionDeserializer;
 impl TagDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TagDescription, XmlParseError> {
@@ -6405,10 +9466,10 @@ impl TagDescriptionDeserializer {
         })
     }
 }
-struct TagDescriptionListDeserializer;
+pub(crate) struct TagDescriptionListDeserializer;
 impl TagDescriptionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TagDescription>, XmlParseError> {
@@ -6422,10 +9483,13 @@ impl TagDescriptionListDeserializer {
         })
     }
 }
-struct TagKeyDeserializer;
+pub(crate) struct TagKeyDeserializer;
 impl TagKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6433,10 +9497,13 @@ impl TagKeyDeserializer {
         Ok(obj)
     }
 }
-struct TagValueDeserializer;
+pub(crate) struct TagValueDeserializer;
 impl TagValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6464,10 +9531,10 @@ pub struct TagsType {
     pub tags: Option<Vec<TagDescription>>,
 }
 
-struct TagsTypeDeserializer;
+pub(crate) struct TagsTypeDeserializer;
 impl TagsTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TagsType, XmlParseError> {
@@ -6487,10 +9554,10 @@ impl TagsTypeDeserializer {
         })
     }
 }
-struct TargetGroupARNsDeserializer;
+pub(crate) struct TargetGroupARNsDeserializer;
 impl TargetGroupARNsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6531,10 +9598,10 @@ pub struct TargetTrackingConfiguration {
     pub target_value: f64,
 }
 
-struct TargetTrackingConfigurationDeserializer;
+pub(crate) struct TargetTrackingConfigurationDeserializer;
 impl TargetTrackingConfigurationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TargetTrackingConfiguration, XmlParseError> {
@@ -6613,6 +9680,49 @@ pub struct TerminateInstanceInAutoScalingGroupType {
     pub should_decrement_desired_capacity: bool,
 }
 
+impl TerminateInstanceInAutoScalingGroupType {
+    /// Returns a builder for `TerminateInstanceInAutoScalingGroupType`, checking its required members when built.
+    pub fn builder() -> TerminateInstanceInAutoScalingGroupTypeBuilder {
+        TerminateInstanceInAutoScalingGroupTypeBuilder::default()
+    }
+}
+
+/// Builds a [`TerminateInstanceInAutoScalingGroupType`](struct.TerminateInstanceInAutoScalingGroupType.html).
+#[derive(Default, Debug)]
+pub struct TerminateInstanceInAutoScalingGroupTypeBuilder {
+    instance_id: Option<String>,
+    should_decrement_desired_capacity: Option<bool>,
+}
+
+impl TerminateInstanceInAutoScalingGroupTypeBuilder {
+    /// Sets the [`instance_id`](struct.TerminateInstanceInAutoScalingGroupType.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`should_decrement_desired_capacity`](struct.TerminateInstanceInAutoScalingGroupType.html#structfield.should_decrement_desired_capacity) member.
+    pub fn should_decrement_desired_capacity<T: Into<bool>>(mut self, value: T) -> Self {
+        self.should_decrement_desired_capacity = Some(value.into());
+        self
+    }
+
+    /// Builds the `TerminateInstanceInAutoScalingGroupType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TerminateInstanceInAutoScalingGroupType, ::rusoto_core::BuildError>
+    {
+        Ok(TerminateInstanceInAutoScalingGroupType {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            should_decrement_desired_capacity: self.should_decrement_desired_capacity.ok_or(
+                ::rusoto_core::BuildError::missing("should_decrement_desired_capacity"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `TerminateInstanceInAutoScalingGroupType` contents to a `SignedRequest`.
 struct TerminateInstanceInAutoScalingGroupTypeSerializer;
 impl TerminateInstanceInAutoScalingGroupTypeSerializer {
@@ -6630,10 +9740,10 @@ impl TerminateInstanceInAutoScalingGroupTypeSerializer {
     }
 }
 
-struct TerminationPoliciesDeserializer;
+pub(crate) struct TerminationPoliciesDeserializer;
 impl TerminationPoliciesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6661,12 +9771,15 @@ impl TerminationPoliciesSerializer {
     }
 }
 
-struct TimestampTypeDeserializer;
+pub(crate) struct TimestampTypeDeserializer;
 impl TimestampTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6708,6 +9821,158 @@ pub struct UpdateAutoScalingGroupType {
     pub vpc_zone_identifier: Option<String>,
 }
 
+impl UpdateAutoScalingGroupType {
+    /// Returns a builder for `UpdateAutoScalingGroupType`, checking its required members when built.
+    pub fn builder() -> UpdateAutoScalingGroupTypeBuilder {
+        UpdateAutoScalingGroupTypeBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAutoScalingGroupType`](struct.UpdateAutoScalingGroupType.html).
+#[derive(Default, Debug)]
+pub struct UpdateAutoScalingGroupTypeBuilder {
+    auto_scaling_group_name: Option<String>,
+    availability_zones: Option<Vec<String>>,
+    default_cooldown: Option<i64>,
+    desired_capacity: Option<i64>,
+    health_check_grace_period: Option<i64>,
+    health_check_type: Option<String>,
+    launch_configuration_name: Option<String>,
+    launch_template: Option<LaunchTemplateSpecification>,
+    max_size: Option<i64>,
+    min_size: Option<i64>,
+    mixed_instances_policy: Option<MixedInstancesPolicy>,
+    new_instances_protected_from_scale_in: Option<bool>,
+    placement_group: Option<String>,
+    service_linked_role_arn: Option<String>,
+    termination_policies: Option<Vec<String>>,
+    vpc_zone_identifier: Option<String>,
+}
+
+impl UpdateAutoScalingGroupTypeBuilder {
+    /// Sets the [`auto_scaling_group_name`](struct.UpdateAutoScalingGroupType.html#structfield.auto_scaling_group_name) member.
+    pub fn auto_scaling_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zones`](struct.UpdateAutoScalingGroupType.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_cooldown`](struct.UpdateAutoScalingGroupType.html#structfield.default_cooldown) member.
+    pub fn default_cooldown<T: Into<i64>>(mut self, value: T) -> Self {
+        self.default_cooldown = Some(value.into());
+        self
+    }
+
+    /// Sets the [`desired_capacity`](struct.UpdateAutoScalingGroupType.html#structfield.desired_capacity) member.
+    pub fn desired_capacity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.desired_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_grace_period`](struct.UpdateAutoScalingGroupType.html#structfield.health_check_grace_period) member.
+    pub fn health_check_grace_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.health_check_grace_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_type`](struct.UpdateAutoScalingGroupType.html#structfield.health_check_type) member.
+    pub fn health_check_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_check_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`launch_configuration_name`](struct.UpdateAutoScalingGroupType.html#structfield.launch_configuration_name) member.
+    pub fn launch_configuration_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.launch_configuration_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`launch_template`](struct.UpdateAutoScalingGroupType.html#structfield.launch_template) member.
+    pub fn launch_template<T: Into<LaunchTemplateSpecification>>(mut self, value: T) -> Self {
+        self.launch_template = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_size`](struct.UpdateAutoScalingGroupType.html#structfield.max_size) member.
+    pub fn max_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`min_size`](struct.UpdateAutoScalingGroupType.html#structfield.min_size) member.
+    pub fn min_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.min_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mixed_instances_policy`](struct.UpdateAutoScalingGroupType.html#structfield.mixed_instances_policy) member.
+    pub fn mixed_instances_policy<T: Into<MixedInstancesPolicy>>(mut self, value: T) -> Self {
+        self.mixed_instances_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_instances_protected_from_scale_in`](struct.UpdateAutoScalingGroupType.html#structfield.new_instances_protected_from_scale_in) member.
+    pub fn new_instances_protected_from_scale_in<T: Into<bool>>(mut self, value: T) -> Self {
+        self.new_instances_protected_from_scale_in = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_group`](struct.UpdateAutoScalingGroupType.html#structfield.placement_group) member.
+    pub fn placement_group<T: Into<String>>(mut self, value: T) -> Self {
+        self.placement_group = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_linked_role_arn`](struct.UpdateAutoScalingGroupType.html#structfield.service_linked_role_arn) member.
+    pub fn service_linked_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_linked_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`termination_policies`](struct.UpdateAutoScalingGroupType.html#structfield.termination_policies) member.
+    pub fn termination_policies<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.termination_policies = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_zone_identifier`](struct.UpdateAutoScalingGroupType.html#structfield.vpc_zone_identifier) member.
+    pub fn vpc_zone_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpc_zone_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAutoScalingGroupType`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAutoScalingGroupType, ::rusoto_core::BuildError> {
+        Ok(UpdateAutoScalingGroupType {
+            auto_scaling_group_name: self.auto_scaling_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("auto_scaling_group_name"),
+            )?,
+            availability_zones: self.availability_zones,
+            default_cooldown: self.default_cooldown,
+            desired_capacity: self.desired_capacity,
+            health_check_grace_period: self.health_check_grace_period,
+            health_check_type: self.health_check_type,
+            launch_configuration_name: self.launch_configuration_name,
+            launch_template: self.launch_template,
+            max_size: self.max_size,
+            min_size: self.min_size,
+            mixed_instances_policy: self.mixed_instances_policy,
+            new_instances_protected_from_scale_in: self.new_instances_protected_from_scale_in,
+            placement_group: self.placement_group,
+            service_linked_role_arn: self.service_linked_role_arn,
+            termination_policies: self.termination_policies,
+            vpc_zone_identifier: self.vpc_zone_identifier,
+        })
+    }
+}
+
 /// Serialize `UpdateAutoScalingGroupType` contents to a `SignedRequest`.
 struct UpdateAutoScalingGroupTypeSerializer;
 impl UpdateAutoScalingGroupTypeSerializer {
@@ -6808,10 +10073,13 @@ impl ValuesSerializer {
     }
 }
 
-struct XmlStringDeserializer;
+pub(crate) struct XmlStringDeserializer;
 impl XmlStringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6819,10 +10087,13 @@ impl XmlStringDeserializer {
         Ok(obj)
     }
 }
-struct XmlStringMaxLen1023Deserializer;
+pub(crate) struct XmlStringMaxLen1023Deserializer;
 impl XmlStringMaxLen1023Deserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6830,10 +10101,13 @@ impl XmlStringMaxLen1023Deserializer {
         Ok(obj)
     }
 }
-struct XmlStringMaxLen1600Deserializer;
+pub(crate) struct XmlStringMaxLen1600Deserializer;
 impl XmlStringMaxLen1600Deserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6841,10 +10115,13 @@ impl XmlStringMaxLen1600Deserializer {
         Ok(obj)
     }
 }
-struct XmlStringMaxLen19Deserializer;
+pub(crate) struct XmlStringMaxLen19Deserializer;
 impl XmlStringMaxLen19Deserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6852,10 +10129,13 @@ impl XmlStringMaxLen19Deserializer {
         Ok(obj)
     }
 }
-struct XmlStringMaxLen2047Deserializer;
+pub(crate) struct XmlStringMaxLen2047Deserializer;
 impl XmlStringMaxLen2047Deserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6863,10 +10143,13 @@ impl XmlStringMaxLen2047Deserializer {
         Ok(obj)
     }
 }
-struct XmlStringMaxLen255Deserializer;
+pub(crate) struct XmlStringMaxLen255Deserializer;
 impl XmlStringMaxLen255Deserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6874,10 +10157,13 @@ impl XmlStringMaxLen255Deserializer {
         Ok(obj)
     }
 }
-struct XmlStringMaxLen32Deserializer;
+pub(crate) struct XmlStringMaxLen32Deserializer;
 impl XmlStringMaxLen32Deserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6885,10 +10171,13 @@ impl XmlStringMaxLen32Deserializer {
         Ok(obj)
     }
 }
-struct XmlStringMaxLen511Deserializer;
+pub(crate) struct XmlStringMaxLen511Deserializer;
 impl XmlStringMaxLen511Deserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6896,10 +10185,13 @@ impl XmlStringMaxLen511Deserializer {
         Ok(obj)
     }
 }
-struct XmlStringMaxLen64Deserializer;
+pub(crate) struct XmlStringMaxLen64Deserializer;
 impl XmlStringMaxLen64Deserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6907,10 +10199,13 @@ impl XmlStringMaxLen64Deserializer {
         Ok(obj)
     }
 }
-struct XmlStringUserDataDeserializer;
+pub(crate) struct XmlStringUserDataDeserializer;
 impl XmlStringUserDataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -10256,6 +13551,334 @@ pub trait Autoscaling {
         input: UpdateAutoScalingGroupType,
     ) -> RusotoFuture<(), UpdateAutoScalingGroupError>;
 }
+impl<T: ?Sized + Autoscaling> Autoscaling for ::std::sync::Arc<T> {
+    fn attach_instances(
+        &self,
+        input: AttachInstancesQuery,
+    ) -> RusotoFuture<(), AttachInstancesError> {
+        (**self).attach_instances(input)
+    }
+    fn attach_load_balancer_target_groups(
+        &self,
+        input: AttachLoadBalancerTargetGroupsType,
+    ) -> RusotoFuture<AttachLoadBalancerTargetGroupsResultType, AttachLoadBalancerTargetGroupsError>
+    {
+        (**self).attach_load_balancer_target_groups(input)
+    }
+    fn attach_load_balancers(
+        &self,
+        input: AttachLoadBalancersType,
+    ) -> RusotoFuture<AttachLoadBalancersResultType, AttachLoadBalancersError> {
+        (**self).attach_load_balancers(input)
+    }
+    fn batch_delete_scheduled_action(
+        &self,
+        input: BatchDeleteScheduledActionType,
+    ) -> RusotoFuture<BatchDeleteScheduledActionAnswer, BatchDeleteScheduledActionError> {
+        (**self).batch_delete_scheduled_action(input)
+    }
+    fn batch_put_scheduled_update_group_action(
+        &self,
+        input: BatchPutScheduledUpdateGroupActionType,
+    ) -> RusotoFuture<
+        BatchPutScheduledUpdateGroupActionAnswer,
+        BatchPutScheduledUpdateGroupActionError,
+    > {
+        (**self).batch_put_scheduled_update_group_action(input)
+    }
+    fn complete_lifecycle_action(
+        &self,
+        input: CompleteLifecycleActionType,
+    ) -> RusotoFuture<CompleteLifecycleActionAnswer, CompleteLifecycleActionError> {
+        (**self).complete_lifecycle_action(input)
+    }
+    fn create_auto_scaling_group(
+        &self,
+        input: CreateAutoScalingGroupType,
+    ) -> RusotoFuture<(), CreateAutoScalingGroupError> {
+        (**self).create_auto_scaling_group(input)
+    }
+    fn create_launch_configuration(
+        &self,
+        input: CreateLaunchConfigurationType,
+    ) -> RusotoFuture<(), CreateLaunchConfigurationError> {
+        (**self).create_launch_configuration(input)
+    }
+    fn create_or_update_tags(
+        &self,
+        input: CreateOrUpdateTagsType,
+    ) -> RusotoFuture<(), CreateOrUpdateTagsError> {
+        (**self).create_or_update_tags(input)
+    }
+    fn delete_auto_scaling_group(
+        &self,
+        input: DeleteAutoScalingGroupType,
+    ) -> RusotoFuture<(), DeleteAutoScalingGroupError> {
+        (**self).delete_auto_scaling_group(input)
+    }
+    fn delete_launch_configuration(
+        &self,
+        input: LaunchConfigurationNameType,
+    ) -> RusotoFuture<(), DeleteLaunchConfigurationError> {
+        (**self).delete_launch_configuration(input)
+    }
+    fn delete_lifecycle_hook(
+        &self,
+        input: DeleteLifecycleHookType,
+    ) -> RusotoFuture<DeleteLifecycleHookAnswer, DeleteLifecycleHookError> {
+        (**self).delete_lifecycle_hook(input)
+    }
+    fn delete_notification_configuration(
+        &self,
+        input: DeleteNotificationConfigurationType,
+    ) -> RusotoFuture<(), DeleteNotificationConfigurationError> {
+        (**self).delete_notification_configuration(input)
+    }
+    fn delete_policy(&self, input: DeletePolicyType) -> RusotoFuture<(), DeletePolicyError> {
+        (**self).delete_policy(input)
+    }
+    fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionType,
+    ) -> RusotoFuture<(), DeleteScheduledActionError> {
+        (**self).delete_scheduled_action(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsType) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_account_limits(
+        &self,
+    ) -> RusotoFuture<DescribeAccountLimitsAnswer, DescribeAccountLimitsError> {
+        (**self).describe_account_limits()
+    }
+    fn describe_adjustment_types(
+        &self,
+    ) -> RusotoFuture<DescribeAdjustmentTypesAnswer, DescribeAdjustmentTypesError> {
+        (**self).describe_adjustment_types()
+    }
+    fn describe_auto_scaling_groups(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> RusotoFuture<AutoScalingGroupsType, DescribeAutoScalingGroupsError> {
+        (**self).describe_auto_scaling_groups(input)
+    }
+    fn describe_auto_scaling_instances(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> RusotoFuture<AutoScalingInstancesType, DescribeAutoScalingInstancesError> {
+        (**self).describe_auto_scaling_instances(input)
+    }
+    fn describe_auto_scaling_notification_types(
+        &self,
+    ) -> RusotoFuture<
+        DescribeAutoScalingNotificationTypesAnswer,
+        DescribeAutoScalingNotificationTypesError,
+    > {
+        (**self).describe_auto_scaling_notification_types()
+    }
+    fn describe_launch_configurations(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> RusotoFuture<LaunchConfigurationsType, DescribeLaunchConfigurationsError> {
+        (**self).describe_launch_configurations(input)
+    }
+    fn describe_lifecycle_hook_types(
+        &self,
+    ) -> RusotoFuture<DescribeLifecycleHookTypesAnswer, DescribeLifecycleHookTypesError> {
+        (**self).describe_lifecycle_hook_types()
+    }
+    fn describe_lifecycle_hooks(
+        &self,
+        input: DescribeLifecycleHooksType,
+    ) -> RusotoFuture<DescribeLifecycleHooksAnswer, DescribeLifecycleHooksError> {
+        (**self).describe_lifecycle_hooks(input)
+    }
+    fn describe_load_balancer_target_groups(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> RusotoFuture<DescribeLoadBalancerTargetGroupsResponse, DescribeLoadBalancerTargetGroupsError>
+    {
+        (**self).describe_load_balancer_target_groups(input)
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> RusotoFuture<DescribeLoadBalancersResponse, DescribeLoadBalancersError> {
+        (**self).describe_load_balancers(input)
+    }
+    fn describe_metric_collection_types(
+        &self,
+    ) -> RusotoFuture<DescribeMetricCollectionTypesAnswer, DescribeMetricCollectionTypesError> {
+        (**self).describe_metric_collection_types()
+    }
+    fn describe_notification_configurations(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> RusotoFuture<
+        DescribeNotificationConfigurationsAnswer,
+        DescribeNotificationConfigurationsError,
+    > {
+        (**self).describe_notification_configurations(input)
+    }
+    fn describe_policies(
+        &self,
+        input: DescribePoliciesType,
+    ) -> RusotoFuture<PoliciesType, DescribePoliciesError> {
+        (**self).describe_policies(input)
+    }
+    fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> RusotoFuture<ActivitiesType, DescribeScalingActivitiesError> {
+        (**self).describe_scaling_activities(input)
+    }
+    fn describe_scaling_process_types(
+        &self,
+    ) -> RusotoFuture<ProcessesType, DescribeScalingProcessTypesError> {
+        (**self).describe_scaling_process_types()
+    }
+    fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> RusotoFuture<ScheduledActionsType, DescribeScheduledActionsError> {
+        (**self).describe_scheduled_actions(input)
+    }
+    fn describe_tags(&self, input: DescribeTagsType) -> RusotoFuture<TagsType, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_termination_policy_types(
+        &self,
+    ) -> RusotoFuture<DescribeTerminationPolicyTypesAnswer, DescribeTerminationPolicyTypesError>
+    {
+        (**self).describe_termination_policy_types()
+    }
+    fn detach_instances(
+        &self,
+        input: DetachInstancesQuery,
+    ) -> RusotoFuture<DetachInstancesAnswer, DetachInstancesError> {
+        (**self).detach_instances(input)
+    }
+    fn detach_load_balancer_target_groups(
+        &self,
+        input: DetachLoadBalancerTargetGroupsType,
+    ) -> RusotoFuture<DetachLoadBalancerTargetGroupsResultType, DetachLoadBalancerTargetGroupsError>
+    {
+        (**self).detach_load_balancer_target_groups(input)
+    }
+    fn detach_load_balancers(
+        &self,
+        input: DetachLoadBalancersType,
+    ) -> RusotoFuture<DetachLoadBalancersResultType, DetachLoadBalancersError> {
+        (**self).detach_load_balancers(input)
+    }
+    fn disable_metrics_collection(
+        &self,
+        input: DisableMetricsCollectionQuery,
+    ) -> RusotoFuture<(), DisableMetricsCollectionError> {
+        (**self).disable_metrics_collection(input)
+    }
+    fn enable_metrics_collection(
+        &self,
+        input: EnableMetricsCollectionQuery,
+    ) -> RusotoFuture<(), EnableMetricsCollectionError> {
+        (**self).enable_metrics_collection(input)
+    }
+    fn enter_standby(
+        &self,
+        input: EnterStandbyQuery,
+    ) -> RusotoFuture<EnterStandbyAnswer, EnterStandbyError> {
+        (**self).enter_standby(input)
+    }
+    fn execute_policy(&self, input: ExecutePolicyType) -> RusotoFuture<(), ExecutePolicyError> {
+        (**self).execute_policy(input)
+    }
+    fn exit_standby(
+        &self,
+        input: ExitStandbyQuery,
+    ) -> RusotoFuture<ExitStandbyAnswer, ExitStandbyError> {
+        (**self).exit_standby(input)
+    }
+    fn put_lifecycle_hook(
+        &self,
+        input: PutLifecycleHookType,
+    ) -> RusotoFuture<PutLifecycleHookAnswer, PutLifecycleHookError> {
+        (**self).put_lifecycle_hook(input)
+    }
+    fn put_notification_configuration(
+        &self,
+        input: PutNotificationConfigurationType,
+    ) -> RusotoFuture<(), PutNotificationConfigurationError> {
+        (**self).put_notification_configuration(input)
+    }
+    fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyType,
+    ) -> RusotoFuture<PolicyARNType, PutScalingPolicyError> {
+        (**self).put_scaling_policy(input)
+    }
+    fn put_scheduled_update_group_action(
+        &self,
+        input: PutScheduledUpdateGroupActionType,
+    ) -> RusotoFuture<(), PutScheduledUpdateGroupActionError> {
+        (**self).put_scheduled_update_group_action(input)
+    }
+    fn record_lifecycle_action_heartbeat(
+        &self,
+        input: RecordLifecycleActionHeartbeatType,
+    ) -> RusotoFuture<RecordLifecycleActionHeartbeatAnswer, RecordLifecycleActionHeartbeatError>
+    {
+        (**self).record_lifecycle_action_heartbeat(input)
+    }
+    fn resume_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> RusotoFuture<(), ResumeProcessesError> {
+        (**self).resume_processes(input)
+    }
+    fn set_desired_capacity(
+        &self,
+        input: SetDesiredCapacityType,
+    ) -> RusotoFuture<(), SetDesiredCapacityError> {
+        (**self).set_desired_capacity(input)
+    }
+    fn set_instance_health(
+        &self,
+        input: SetInstanceHealthQuery,
+    ) -> RusotoFuture<(), SetInstanceHealthError> {
+        (**self).set_instance_health(input)
+    }
+    fn set_instance_protection(
+        &self,
+        input: SetInstanceProtectionQuery,
+    ) -> RusotoFuture<SetInstanceProtectionAnswer, SetInstanceProtectionError> {
+        (**self).set_instance_protection(input)
+    }
+    fn suspend_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> RusotoFuture<(), SuspendProcessesError> {
+        (**self).suspend_processes(input)
+    }
+    fn terminate_instance_in_auto_scaling_group(
+        &self,
+        input: TerminateInstanceInAutoScalingGroupType,
+    ) -> RusotoFuture<ActivityType, TerminateInstanceInAutoScalingGroupError> {
+        (**self).terminate_instance_in_auto_scaling_group(input)
+    }
+    fn update_auto_scaling_group(
+        &self,
+        input: UpdateAutoScalingGroupType,
+    ) -> RusotoFuture<(), UpdateAutoScalingGroupError> {
+        (**self).update_auto_scaling_group(input)
+    }
+}
+/// The error codes Auto Scaling retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Auto Scaling API.
 #[derive(Clone)]
 pub struct AutoscalingClient {
@@ -10271,6 +13894,27 @@ impl AutoscalingClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_AUTOSCALING`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> AutoscalingClient {
+        Self::new(region::Region::from_env_for_service("AUTOSCALING"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `autoscaling`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> AutoscalingClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("autoscaling", "AUTOSCALING"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -10289,7 +13933,54 @@ impl AutoscalingClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AutoscalingClient {
-        AutoscalingClient { client, region }
+        AutoscalingClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "autoscaling")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "autoscaling",
+            "autoscaling",
+            "AUTOSCALING",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Autoscaling` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Autoscaling + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for AutoscalingClient {
+    fn service_name(&self) -> &'static str {
+        "Auto Scaling"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "autoscaling"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> AutoscalingClient {
+        Self::new_with_client(client, region)
     }
 }
 
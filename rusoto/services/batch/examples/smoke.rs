@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Batch clients.
+//!
+//! Makes a single read-only `ListJobs` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_batch::{Batch, BatchClient, ListJobsRequest};
+
+fn main() {
+    let client = BatchClient::from_env();
+    match client.list_jobs(ListJobsRequest::default()).sync() {
+        Ok(_) => println!("ListJobs succeeded"),
+        Err(error) => {
+            eprintln!("ListJobs failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
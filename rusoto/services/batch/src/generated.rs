@@ -22,7 +22,86 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The type of the job dependency.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ArrayJobDependency {
+    /// <p><code>N_TO_N</code></p>
+    NToN,
+    /// <p><code>SEQUENTIAL</code></p>
+    Sequential,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ArrayJobDependency {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArrayJobDependency::NToN => "N_TO_N",
+            ArrayJobDependency::Sequential => "SEQUENTIAL",
+            ArrayJobDependency::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ArrayJobDependency {
+    fn from(value: &'a str) -> ArrayJobDependency {
+        match value {
+            "N_TO_N" => ArrayJobDependency::NToN,
+            "SEQUENTIAL" => ArrayJobDependency::Sequential,
+            _ => ArrayJobDependency::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArrayJobDependency {
+    fn from(value: String) -> ArrayJobDependency {
+        ArrayJobDependency::from(value.as_str())
+    }
+}
+
+impl From<ArrayJobDependency> for String {
+    fn from(value: ArrayJobDependency) -> String {
+        match value {
+            ArrayJobDependency::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ArrayJobDependency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ArrayJobDependency {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ArrayJobDependency {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArrayJobDependency {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ArrayJobDependency, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ArrayJobDependency::from(value))
+    }
+}
+
 /// <p>An object representing an AWS Batch array job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ArrayProperties {
@@ -116,6 +195,342 @@ pub struct AttemptDetail {
     pub stopped_at: Option<i64>,
 }
 
+/// <p>The state of the compute environment. The valid values are <code>ENABLED</code> or <code>DISABLED</code>. </p> <p>If the state is <code>ENABLED</code>, then the AWS Batch scheduler can attempt to place jobs from an associated job queue on the compute resources within the environment. If the compute environment is managed, then it can scale its instances out or in automatically, based on the job queue demand.</p> <p>If the state is <code>DISABLED</code>, then the AWS Batch scheduler does not attempt to place jobs within the environment. Jobs in a <code>STARTING</code> or <code>RUNNING</code> state continue to progress normally. Managed compute environments in the <code>DISABLED</code> state do not scale out. However, they scale in to <code>minvCpus</code> value after instances become idle.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CEState {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CEState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CEState::Enabled => "ENABLED",
+            CEState::Disabled => "DISABLED",
+            CEState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CEState {
+    fn from(value: &'a str) -> CEState {
+        match value {
+            "ENABLED" => CEState::Enabled,
+            "DISABLED" => CEState::Disabled,
+            _ => CEState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CEState {
+    fn from(value: String) -> CEState {
+        CEState::from(value.as_str())
+    }
+}
+
+impl From<CEState> for String {
+    fn from(value: CEState) -> String {
+        match value {
+            CEState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CEState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CEState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CEState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CEState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CEState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CEState::from(value))
+    }
+}
+
+/// <p>The current status of the compute environment (for example, <code>CREATING</code> or <code>VALID</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CEStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// <p><code>VALID</code></p>
+    Valid,
+    /// <p><code>INVALID</code></p>
+    Invalid,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CEStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CEStatus::Creating => "CREATING",
+            CEStatus::Updating => "UPDATING",
+            CEStatus::Deleting => "DELETING",
+            CEStatus::Deleted => "DELETED",
+            CEStatus::Valid => "VALID",
+            CEStatus::Invalid => "INVALID",
+            CEStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CEStatus {
+    fn from(value: &'a str) -> CEStatus {
+        match value {
+            "CREATING" => CEStatus::Creating,
+            "UPDATING" => CEStatus::Updating,
+            "DELETING" => CEStatus::Deleting,
+            "DELETED" => CEStatus::Deleted,
+            "VALID" => CEStatus::Valid,
+            "INVALID" => CEStatus::Invalid,
+            _ => CEStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CEStatus {
+    fn from(value: String) -> CEStatus {
+        CEStatus::from(value.as_str())
+    }
+}
+
+impl From<CEStatus> for String {
+    fn from(value: CEStatus) -> String {
+        match value {
+            CEStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CEStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CEStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CEStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CEStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CEStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CEStatus::from(value))
+    }
+}
+
+/// <p>The type of the compute environment.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CEType {
+    /// <p><code>MANAGED</code></p>
+    Managed,
+    /// <p><code>UNMANAGED</code></p>
+    Unmanaged,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CEType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CEType::Managed => "MANAGED",
+            CEType::Unmanaged => "UNMANAGED",
+            CEType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CEType {
+    fn from(value: &'a str) -> CEType {
+        match value {
+            "MANAGED" => CEType::Managed,
+            "UNMANAGED" => CEType::Unmanaged,
+            _ => CEType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CEType {
+    fn from(value: String) -> CEType {
+        CEType::from(value.as_str())
+    }
+}
+
+impl From<CEType> for String {
+    fn from(value: CEType) -> String {
+        match value {
+            CEType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CEType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CEType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CEType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CEType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CEType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CEType::from(value))
+    }
+}
+
+/// <p>The type of compute environment: EC2 or SPOT.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CRType {
+    /// <p><code>EC2</code></p>
+    Ec2,
+    /// <p><code>SPOT</code></p>
+    Spot,
+    /// <p><code>FARGATE</code></p>
+    Fargate,
+    /// <p><code>FARGATE_SPOT</code></p>
+    FargateSpot,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CRType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CRType::Ec2 => "EC2",
+            CRType::Spot => "SPOT",
+            CRType::Fargate => "FARGATE",
+            CRType::FargateSpot => "FARGATE_SPOT",
+            CRType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CRType {
+    fn from(value: &'a str) -> CRType {
+        match value {
+            "EC2" => CRType::Ec2,
+            "SPOT" => CRType::Spot,
+            "FARGATE" => CRType::Fargate,
+            "FARGATE_SPOT" => CRType::FargateSpot,
+            _ => CRType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CRType {
+    fn from(value: String) -> CRType {
+        CRType::from(value.as_str())
+    }
+}
+
+impl From<CRType> for String {
+    fn from(value: CRType) -> String {
+        match value {
+            CRType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CRType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CRType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CRType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CRType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CRType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CRType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CancelJobRequest {
     /// <p>The AWS Batch job ID of the job to cancel.</p>
@@ -126,6 +541,46 @@ pub struct CancelJobRequest {
     pub reason: String,
 }
 
+impl CancelJobRequest {
+    /// Returns a builder for `CancelJobRequest`, checking its required members when built.
+    pub fn builder() -> CancelJobRequestBuilder {
+        CancelJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelJobRequest`](struct.CancelJobRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelJobRequestBuilder {
+    job_id: Option<String>,
+    reason: Option<String>,
+}
+
+impl CancelJobRequestBuilder {
+    /// Sets the [`job_id`](struct.CancelJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reason`](struct.CancelJobRequest.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelJobRequest, ::rusoto_core::BuildError> {
+        Ok(CancelJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            reason: self
+                .reason
+                .ok_or(::rusoto_core::BuildError::missing("reason"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelJobResponse {}
@@ -154,11 +609,11 @@ pub struct ComputeEnvironmentDetail {
     /// <p>The state of the compute environment. The valid values are <code>ENABLED</code> or <code>DISABLED</code>. </p> <p>If the state is <code>ENABLED</code>, then the AWS Batch scheduler can attempt to place jobs from an associated job queue on the compute resources within the environment. If the compute environment is managed, then it can scale its instances out or in automatically, based on the job queue demand.</p> <p>If the state is <code>DISABLED</code>, then the AWS Batch scheduler does not attempt to place jobs within the environment. Jobs in a <code>STARTING</code> or <code>RUNNING</code> state continue to progress normally. Managed compute environments in the <code>DISABLED</code> state do not scale out. However, they scale in to <code>minvCpus</code> value after instances become idle.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CEState>,
     /// <p>The current status of the compute environment (for example, <code>CREATING</code> or <code>VALID</code>).</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CEStatus>,
     /// <p>A short, human-readable string to provide additional details about the current status of the compute environment.</p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -166,7 +621,7 @@ pub struct ComputeEnvironmentDetail {
     /// <p>The type of the compute environment.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<CEType>,
 }
 
 /// <p>The order in which compute environments are tried for job placement within a queue. Compute environments are tried in ascending order. For example, if two compute environments are associated with a job queue, the compute environment with a lower order integer value is tried for job placement first.</p>
@@ -181,7 +636,7 @@ pub struct ComputeEnvironmentOrder {
 }
 
 /// <p>An object representing an AWS Batch compute resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ComputeResource {
     /// <p>The maximum percentage that a Spot Instance price can be when compared with the On-Demand price for that instance type before instances are launched. For example, if your maximum percentage is 20%, then the Spot price must be below 20% of the current On-Demand price for that EC2 instance. You always pay the lowest (market) price and never more than your maximum percentage. If you leave this field empty, the default value is 100% of the On-Demand price.</p>
     #[serde(rename = "bidPercentage")]
@@ -236,7 +691,7 @@ pub struct ComputeResource {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The type of compute environment: EC2 or SPOT.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: CRType,
 }
 
 /// <p>An object representing the attributes of a compute environment that can be updated.</p>
@@ -454,7 +909,7 @@ pub struct ContainerSummary {
     pub reason: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateComputeEnvironmentRequest {
     /// <p>The name for your compute environment. Up to 128 letters (uppercase and lowercase), numbers, hyphens, and underscores are allowed.</p>
     #[serde(rename = "computeEnvironmentName")]
@@ -469,10 +924,78 @@ pub struct CreateComputeEnvironmentRequest {
     /// <p>The state of the compute environment. If the state is <code>ENABLED</code>, then the compute environment accepts jobs from a queue and can scale out automatically based on queues.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CEState>,
     /// <p>The type of the compute environment. For more information, see <a href="https://docs.aws.amazon.com/batch/latest/userguide/compute_environments.html">Compute Environments</a> in the <i>AWS Batch User Guide</i>.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: CEType,
+}
+
+impl CreateComputeEnvironmentRequest {
+    /// Returns a builder for `CreateComputeEnvironmentRequest`, checking its required members when built.
+    pub fn builder() -> CreateComputeEnvironmentRequestBuilder {
+        CreateComputeEnvironmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateComputeEnvironmentRequest`](struct.CreateComputeEnvironmentRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateComputeEnvironmentRequestBuilder {
+    compute_environment_name: Option<String>,
+    compute_resources: Option<ComputeResource>,
+    service_role: Option<String>,
+    state: Option<CEState>,
+    type_: Option<CEType>,
+}
+
+impl CreateComputeEnvironmentRequestBuilder {
+    /// Sets the [`compute_environment_name`](struct.CreateComputeEnvironmentRequest.html#structfield.compute_environment_name) member.
+    pub fn compute_environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.compute_environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`compute_resources`](struct.CreateComputeEnvironmentRequest.html#structfield.compute_resources) member.
+    pub fn compute_resources<T: Into<ComputeResource>>(mut self, value: T) -> Self {
+        self.compute_resources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role`](struct.CreateComputeEnvironmentRequest.html#structfield.service_role) member.
+    pub fn service_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state`](struct.CreateComputeEnvironmentRequest.html#structfield.state) member.
+    pub fn state<T: Into<CEState>>(mut self, value: T) -> Self {
+        self.state = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.CreateComputeEnvironmentRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<CEType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateComputeEnvironmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateComputeEnvironmentRequest, ::rusoto_core::BuildError> {
+        Ok(CreateComputeEnvironmentRequest {
+            compute_environment_name: self.compute_environment_name.ok_or(
+                ::rusoto_core::BuildError::missing("compute_environment_name"),
+            )?,
+            compute_resources: self.compute_resources,
+            service_role: self
+                .service_role
+                .ok_or(::rusoto_core::BuildError::missing("service_role"))?,
+            state: self.state,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -502,7 +1025,68 @@ pub struct CreateJobQueueRequest {
     /// <p>The state of the job queue. If the job queue state is <code>ENABLED</code>, it is able to accept jobs.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<JQState>,
+}
+
+impl CreateJobQueueRequest {
+    /// Returns a builder for `CreateJobQueueRequest`, checking its required members when built.
+    pub fn builder() -> CreateJobQueueRequestBuilder {
+        CreateJobQueueRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateJobQueueRequest`](struct.CreateJobQueueRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateJobQueueRequestBuilder {
+    compute_environment_order: Option<Vec<ComputeEnvironmentOrder>>,
+    job_queue_name: Option<String>,
+    priority: Option<i64>,
+    state: Option<JQState>,
+}
+
+impl CreateJobQueueRequestBuilder {
+    /// Sets the [`compute_environment_order`](struct.CreateJobQueueRequest.html#structfield.compute_environment_order) member.
+    pub fn compute_environment_order<T: Into<Vec<ComputeEnvironmentOrder>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.compute_environment_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_queue_name`](struct.CreateJobQueueRequest.html#structfield.job_queue_name) member.
+    pub fn job_queue_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_queue_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`priority`](struct.CreateJobQueueRequest.html#structfield.priority) member.
+    pub fn priority<T: Into<i64>>(mut self, value: T) -> Self {
+        self.priority = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state`](struct.CreateJobQueueRequest.html#structfield.state) member.
+    pub fn state<T: Into<JQState>>(mut self, value: T) -> Self {
+        self.state = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateJobQueueRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateJobQueueRequest, ::rusoto_core::BuildError> {
+        Ok(CreateJobQueueRequest {
+            compute_environment_order: self.compute_environment_order.ok_or(
+                ::rusoto_core::BuildError::missing("compute_environment_order"),
+            )?,
+            job_queue_name: self
+                .job_queue_name
+                .ok_or(::rusoto_core::BuildError::missing("job_queue_name"))?,
+            priority: self
+                .priority
+                .ok_or(::rusoto_core::BuildError::missing("priority"))?,
+            state: self.state,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -523,6 +1107,38 @@ pub struct DeleteComputeEnvironmentRequest {
     pub compute_environment: String,
 }
 
+impl DeleteComputeEnvironmentRequest {
+    /// Returns a builder for `DeleteComputeEnvironmentRequest`, checking its required members when built.
+    pub fn builder() -> DeleteComputeEnvironmentRequestBuilder {
+        DeleteComputeEnvironmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteComputeEnvironmentRequest`](struct.DeleteComputeEnvironmentRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteComputeEnvironmentRequestBuilder {
+    compute_environment: Option<String>,
+}
+
+impl DeleteComputeEnvironmentRequestBuilder {
+    /// Sets the [`compute_environment`](struct.DeleteComputeEnvironmentRequest.html#structfield.compute_environment) member.
+    pub fn compute_environment<T: Into<String>>(mut self, value: T) -> Self {
+        self.compute_environment = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteComputeEnvironmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteComputeEnvironmentRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteComputeEnvironmentRequest {
+            compute_environment: self
+                .compute_environment
+                .ok_or(::rusoto_core::BuildError::missing("compute_environment"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteComputeEnvironmentResponse {}
@@ -534,6 +1150,36 @@ pub struct DeleteJobQueueRequest {
     pub job_queue: String,
 }
 
+impl DeleteJobQueueRequest {
+    /// Returns a builder for `DeleteJobQueueRequest`, checking its required members when built.
+    pub fn builder() -> DeleteJobQueueRequestBuilder {
+        DeleteJobQueueRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteJobQueueRequest`](struct.DeleteJobQueueRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteJobQueueRequestBuilder {
+    job_queue: Option<String>,
+}
+
+impl DeleteJobQueueRequestBuilder {
+    /// Sets the [`job_queue`](struct.DeleteJobQueueRequest.html#structfield.job_queue) member.
+    pub fn job_queue<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_queue = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteJobQueueRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteJobQueueRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteJobQueueRequest {
+            job_queue: self
+                .job_queue
+                .ok_or(::rusoto_core::BuildError::missing("job_queue"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteJobQueueResponse {}
@@ -545,6 +1191,38 @@ pub struct DeregisterJobDefinitionRequest {
     pub job_definition: String,
 }
 
+impl DeregisterJobDefinitionRequest {
+    /// Returns a builder for `DeregisterJobDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> DeregisterJobDefinitionRequestBuilder {
+        DeregisterJobDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterJobDefinitionRequest`](struct.DeregisterJobDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeregisterJobDefinitionRequestBuilder {
+    job_definition: Option<String>,
+}
+
+impl DeregisterJobDefinitionRequestBuilder {
+    /// Sets the [`job_definition`](struct.DeregisterJobDefinitionRequest.html#structfield.job_definition) member.
+    pub fn job_definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_definition = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterJobDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterJobDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(DeregisterJobDefinitionRequest {
+            job_definition: self
+                .job_definition
+                .ok_or(::rusoto_core::BuildError::missing("job_definition"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeregisterJobDefinitionResponse {}
@@ -565,6 +1243,52 @@ pub struct DescribeComputeEnvironmentsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeComputeEnvironmentsRequest {
+    /// Returns a builder for `DescribeComputeEnvironmentsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeComputeEnvironmentsRequestBuilder {
+        DescribeComputeEnvironmentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeComputeEnvironmentsRequest`](struct.DescribeComputeEnvironmentsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeComputeEnvironmentsRequestBuilder {
+    compute_environments: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeComputeEnvironmentsRequestBuilder {
+    /// Sets the [`compute_environments`](struct.DescribeComputeEnvironmentsRequest.html#structfield.compute_environments) member.
+    pub fn compute_environments<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.compute_environments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeComputeEnvironmentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeComputeEnvironmentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeComputeEnvironmentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeComputeEnvironmentsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeComputeEnvironmentsRequest {
+            compute_environments: self.compute_environments,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeComputeEnvironmentsResponse {
@@ -602,6 +1326,68 @@ pub struct DescribeJobDefinitionsRequest {
     pub status: Option<String>,
 }
 
+impl DescribeJobDefinitionsRequest {
+    /// Returns a builder for `DescribeJobDefinitionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeJobDefinitionsRequestBuilder {
+        DescribeJobDefinitionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeJobDefinitionsRequest`](struct.DescribeJobDefinitionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeJobDefinitionsRequestBuilder {
+    job_definition_name: Option<String>,
+    job_definitions: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    status: Option<String>,
+}
+
+impl DescribeJobDefinitionsRequestBuilder {
+    /// Sets the [`job_definition_name`](struct.DescribeJobDefinitionsRequest.html#structfield.job_definition_name) member.
+    pub fn job_definition_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_definition_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_definitions`](struct.DescribeJobDefinitionsRequest.html#structfield.job_definitions) member.
+    pub fn job_definitions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.job_definitions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeJobDefinitionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeJobDefinitionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.DescribeJobDefinitionsRequest.html#structfield.status) member.
+    pub fn status<T: Into<String>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeJobDefinitionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeJobDefinitionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeJobDefinitionsRequest {
+            job_definition_name: self.job_definition_name,
+            job_definitions: self.job_definitions,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            status: self.status,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeJobDefinitionsResponse {
@@ -631,6 +1417,52 @@ pub struct DescribeJobQueuesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeJobQueuesRequest {
+    /// Returns a builder for `DescribeJobQueuesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeJobQueuesRequestBuilder {
+        DescribeJobQueuesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeJobQueuesRequest`](struct.DescribeJobQueuesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeJobQueuesRequestBuilder {
+    job_queues: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeJobQueuesRequestBuilder {
+    /// Sets the [`job_queues`](struct.DescribeJobQueuesRequest.html#structfield.job_queues) member.
+    pub fn job_queues<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.job_queues = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeJobQueuesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeJobQueuesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeJobQueuesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeJobQueuesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeJobQueuesRequest {
+            job_queues: self.job_queues,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeJobQueuesResponse {
@@ -651,6 +1483,36 @@ pub struct DescribeJobsRequest {
     pub jobs: Vec<String>,
 }
 
+impl DescribeJobsRequest {
+    /// Returns a builder for `DescribeJobsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeJobsRequestBuilder {
+        DescribeJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeJobsRequest`](struct.DescribeJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeJobsRequestBuilder {
+    jobs: Option<Vec<String>>,
+}
+
+impl DescribeJobsRequestBuilder {
+    /// Sets the [`jobs`](struct.DescribeJobsRequest.html#structfield.jobs) member.
+    pub fn jobs<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.jobs = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeJobsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeJobsRequest {
+            jobs: self
+                .jobs
+                .ok_or(::rusoto_core::BuildError::missing("jobs"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeJobsResponse {
@@ -673,7 +1535,89 @@ pub struct Device {
     /// <p>The explicit permissions to provide to the container for the device. By default, the container has permissions for <code>read</code>, <code>write</code>, and <code>mknod</code> for the device.</p>
     #[serde(rename = "permissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Vec<String>>,
+    pub permissions: Option<Vec<DeviceCgroupPermission>>,
+}
+
+/// <p>The explicit permissions to provide to the container for the device. By default, the container has permissions for <code>read</code>, <code>write</code>, and <code>mknod</code> for the device.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceCgroupPermission {
+    /// <p><code>READ</code></p>
+    Read,
+    /// <p><code>WRITE</code></p>
+    Write,
+    /// <p><code>MKNOD</code></p>
+    Mknod,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceCgroupPermission {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceCgroupPermission::Read => "READ",
+            DeviceCgroupPermission::Write => "WRITE",
+            DeviceCgroupPermission::Mknod => "MKNOD",
+            DeviceCgroupPermission::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeviceCgroupPermission {
+    fn from(value: &'a str) -> DeviceCgroupPermission {
+        match value {
+            "READ" => DeviceCgroupPermission::Read,
+            "WRITE" => DeviceCgroupPermission::Write,
+            "MKNOD" => DeviceCgroupPermission::Mknod,
+            _ => DeviceCgroupPermission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceCgroupPermission {
+    fn from(value: String) -> DeviceCgroupPermission {
+        DeviceCgroupPermission::from(value.as_str())
+    }
+}
+
+impl From<DeviceCgroupPermission> for String {
+    fn from(value: DeviceCgroupPermission) -> String {
+        match value {
+            DeviceCgroupPermission::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceCgroupPermission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceCgroupPermission {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceCgroupPermission {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceCgroupPermission {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeviceCgroupPermission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceCgroupPermission::from(value))
+    }
 }
 
 /// <p>Determine whether your data volume persists on the host container instance and where it is stored. If this parameter is empty, then the Docker daemon assigns a host path for your data volume, but the data is not guaranteed to persist after the containers associated with it stop running.</p>
@@ -685,6 +1629,178 @@ pub struct Host {
     pub source_path: Option<String>,
 }
 
+/// <p>The state of the job queue. If the job queue state is <code>ENABLED</code>, it is able to accept jobs.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JQState {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JQState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JQState::Enabled => "ENABLED",
+            JQState::Disabled => "DISABLED",
+            JQState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JQState {
+    fn from(value: &'a str) -> JQState {
+        match value {
+            "ENABLED" => JQState::Enabled,
+            "DISABLED" => JQState::Disabled,
+            _ => JQState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JQState {
+    fn from(value: String) -> JQState {
+        JQState::from(value.as_str())
+    }
+}
+
+impl From<JQState> for String {
+    fn from(value: JQState) -> String {
+        match value {
+            JQState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JQState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JQState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JQState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JQState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JQState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JQState::from(value))
+    }
+}
+
+/// <p>The status of the job queue (for example, <code>CREATING</code> or <code>VALID</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JQStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// <p><code>VALID</code></p>
+    Valid,
+    /// <p><code>INVALID</code></p>
+    Invalid,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JQStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JQStatus::Creating => "CREATING",
+            JQStatus::Updating => "UPDATING",
+            JQStatus::Deleting => "DELETING",
+            JQStatus::Deleted => "DELETED",
+            JQStatus::Valid => "VALID",
+            JQStatus::Invalid => "INVALID",
+            JQStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JQStatus {
+    fn from(value: &'a str) -> JQStatus {
+        match value {
+            "CREATING" => JQStatus::Creating,
+            "UPDATING" => JQStatus::Updating,
+            "DELETING" => JQStatus::Deleting,
+            "DELETED" => JQStatus::Deleted,
+            "VALID" => JQStatus::Valid,
+            "INVALID" => JQStatus::Invalid,
+            _ => JQStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JQStatus {
+    fn from(value: String) -> JQStatus {
+        JQStatus::from(value.as_str())
+    }
+}
+
+impl From<JQStatus> for String {
+    fn from(value: JQStatus) -> String {
+        match value {
+            JQStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JQStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JQStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JQStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JQStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JQStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JQStatus::from(value))
+    }
+}
+
 /// <p>An object representing an AWS Batch job definition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -727,6 +1843,84 @@ pub struct JobDefinition {
     pub type_: String,
 }
 
+/// <p>The type of job definition.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobDefinitionType {
+    /// <p><code>container</code></p>
+    Container,
+    /// <p><code>multinode</code></p>
+    Multinode,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobDefinitionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobDefinitionType::Container => "container",
+            JobDefinitionType::Multinode => "multinode",
+            JobDefinitionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobDefinitionType {
+    fn from(value: &'a str) -> JobDefinitionType {
+        match value {
+            "container" => JobDefinitionType::Container,
+            "multinode" => JobDefinitionType::Multinode,
+            _ => JobDefinitionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobDefinitionType {
+    fn from(value: String) -> JobDefinitionType {
+        JobDefinitionType::from(value.as_str())
+    }
+}
+
+impl From<JobDefinitionType> for String {
+    fn from(value: JobDefinitionType) -> String {
+        match value {
+            JobDefinitionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobDefinitionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobDefinitionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobDefinitionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobDefinitionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobDefinitionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobDefinitionType::from(value))
+    }
+}
+
 /// <p>An object representing an AWS Batch job dependency.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JobDependency {
@@ -737,11 +1931,11 @@ pub struct JobDependency {
     /// <p>The type of the job dependency.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ArrayJobDependency>,
 }
 
 /// <p>An object representing an AWS Batch job.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct JobDetail {
     /// <p>The array properties of the job, if it is an array job.</p>
@@ -797,7 +1991,7 @@ pub struct JobDetail {
     pub started_at: i64,
     /// <p><p>The current status for the job. </p> <note> <p>If your jobs do not progress to <code>STARTING</code>, see <a href="https://docs.aws.amazon.com/batch/latest/userguide/troubleshooting.html#job_stuck_in_runnable">Jobs Stuck in RUNNABLE Status</a> in the troubleshooting section of the <i>AWS Batch User Guide</i>.</p> </note></p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: JobStatus,
     /// <p>A short, human-readable string to provide additional details about the current status of the job. </p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -813,7 +2007,7 @@ pub struct JobDetail {
 }
 
 /// <p>An object representing the details of an AWS Batch job queue.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct JobQueueDetail {
     /// <p>The compute environments that are attached to the job queue and the order in which job placement is preferred. Compute environments are selected for job placement in ascending order.</p>
@@ -830,17 +2024,115 @@ pub struct JobQueueDetail {
     pub priority: i64,
     /// <p>Describes the ability of the queue to accept new jobs.</p>
     #[serde(rename = "state")]
-    pub state: String,
+    pub state: JQState,
     /// <p>The status of the job queue (for example, <code>CREATING</code> or <code>VALID</code>).</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JQStatus>,
     /// <p>A short, human-readable string to provide additional details about the current status of the job queue.</p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_reason: Option<String>,
 }
 
+/// <p><p>The current status for the job. </p> <note> <p>If your jobs do not progress to <code>STARTING</code>, see <a href="https://docs.aws.amazon.com/batch/latest/userguide/troubleshooting.html#job_stuck_in_runnable">Jobs Stuck in RUNNABLE Status</a> in the troubleshooting section of the <i>AWS Batch User Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobStatus {
+    /// <p><code>SUBMITTED</code></p>
+    Submitted,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>RUNNABLE</code></p>
+    Runnable,
+    /// <p><code>STARTING</code></p>
+    Starting,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::Submitted => "SUBMITTED",
+            JobStatus::Pending => "PENDING",
+            JobStatus::Runnable => "RUNNABLE",
+            JobStatus::Starting => "STARTING",
+            JobStatus::Running => "RUNNING",
+            JobStatus::Succeeded => "SUCCEEDED",
+            JobStatus::Failed => "FAILED",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "SUBMITTED" => JobStatus::Submitted,
+            "PENDING" => JobStatus::Pending,
+            "RUNNABLE" => JobStatus::Runnable,
+            "STARTING" => JobStatus::Starting,
+            "RUNNING" => JobStatus::Running,
+            "SUCCEEDED" => JobStatus::Succeeded,
+            "FAILED" => JobStatus::Failed,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        JobStatus::from(value.as_str())
+    }
+}
+
+impl From<JobStatus> for String {
+    fn from(value: JobStatus) -> String {
+        match value {
+            JobStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobStatus::from(value))
+    }
+}
+
 /// <p>An object representing summary details of a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -874,7 +2166,7 @@ pub struct JobSummary {
     /// <p>The current status for the job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobStatus>,
     /// <p>A short, human-readable string to provide additional details about the current status of the job.</p>
     #[serde(rename = "statusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -946,7 +2238,7 @@ pub struct ListJobsRequest {
     /// <p>The job status with which to filter jobs in the specified queue. If you do not specify a status, only <code>RUNNING</code> jobs are returned.</p>
     #[serde(rename = "jobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>The maximum number of results returned by <code>ListJobs</code> in paginated output. When this parameter is used, <code>ListJobs</code> only returns <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. The remaining results of the initial request can be seen by sending another <code>ListJobs</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 100. If this parameter is not used, then <code>ListJobs</code> returns up to 100 results and a <code>nextToken</code> value if applicable.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -961,6 +2253,74 @@ pub struct ListJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListJobsRequest {
+    /// Returns a builder for `ListJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListJobsRequestBuilder {
+        ListJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListJobsRequest`](struct.ListJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListJobsRequestBuilder {
+    array_job_id: Option<String>,
+    job_queue: Option<String>,
+    job_status: Option<JobStatus>,
+    max_results: Option<i64>,
+    multi_node_job_id: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListJobsRequestBuilder {
+    /// Sets the [`array_job_id`](struct.ListJobsRequest.html#structfield.array_job_id) member.
+    pub fn array_job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.array_job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_queue`](struct.ListJobsRequest.html#structfield.job_queue) member.
+    pub fn job_queue<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_queue = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_status`](struct.ListJobsRequest.html#structfield.job_status) member.
+    pub fn job_status<T: Into<JobStatus>>(mut self, value: T) -> Self {
+        self.job_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_node_job_id`](struct.ListJobsRequest.html#structfield.multi_node_job_id) member.
+    pub fn multi_node_job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.multi_node_job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListJobsRequest {
+            array_job_id: self.array_job_id,
+            job_queue: self.job_queue,
+            job_status: self.job_status,
+            max_results: self.max_results,
+            multi_node_job_id: self.multi_node_job_id,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListJobsResponse {
@@ -1091,7 +2451,7 @@ pub struct NodeRangeProperty {
     pub target_nodes: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RegisterJobDefinitionRequest {
     /// <p>An object with various properties specific to single-node container-based jobs. If the job definition's <code>type</code> parameter is <code>container</code>, then you must specify either <code>containerProperties</code> or <code>nodeProperties</code>.</p>
     #[serde(rename = "containerProperties")]
@@ -1118,7 +2478,92 @@ pub struct RegisterJobDefinitionRequest {
     pub timeout: Option<JobTimeout>,
     /// <p>The type of job definition.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: JobDefinitionType,
+}
+
+impl RegisterJobDefinitionRequest {
+    /// Returns a builder for `RegisterJobDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> RegisterJobDefinitionRequestBuilder {
+        RegisterJobDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterJobDefinitionRequest`](struct.RegisterJobDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterJobDefinitionRequestBuilder {
+    container_properties: Option<ContainerProperties>,
+    job_definition_name: Option<String>,
+    node_properties: Option<NodeProperties>,
+    parameters: Option<::std::collections::HashMap<String, String>>,
+    retry_strategy: Option<RetryStrategy>,
+    timeout: Option<JobTimeout>,
+    type_: Option<JobDefinitionType>,
+}
+
+impl RegisterJobDefinitionRequestBuilder {
+    /// Sets the [`container_properties`](struct.RegisterJobDefinitionRequest.html#structfield.container_properties) member.
+    pub fn container_properties<T: Into<ContainerProperties>>(mut self, value: T) -> Self {
+        self.container_properties = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_definition_name`](struct.RegisterJobDefinitionRequest.html#structfield.job_definition_name) member.
+    pub fn job_definition_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_definition_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_properties`](struct.RegisterJobDefinitionRequest.html#structfield.node_properties) member.
+    pub fn node_properties<T: Into<NodeProperties>>(mut self, value: T) -> Self {
+        self.node_properties = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.RegisterJobDefinitionRequest.html#structfield.parameters) member.
+    pub fn parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retry_strategy`](struct.RegisterJobDefinitionRequest.html#structfield.retry_strategy) member.
+    pub fn retry_strategy<T: Into<RetryStrategy>>(mut self, value: T) -> Self {
+        self.retry_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout`](struct.RegisterJobDefinitionRequest.html#structfield.timeout) member.
+    pub fn timeout<T: Into<JobTimeout>>(mut self, value: T) -> Self {
+        self.timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.RegisterJobDefinitionRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<JobDefinitionType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterJobDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterJobDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterJobDefinitionRequest {
+            container_properties: self.container_properties,
+            job_definition_name: self
+                .job_definition_name
+                .ok_or(::rusoto_core::BuildError::missing("job_definition_name"))?,
+            node_properties: self.node_properties,
+            parameters: self.parameters,
+            retry_strategy: self.retry_strategy,
+            timeout: self.timeout,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1136,16 +2581,98 @@ pub struct RegisterJobDefinitionResponse {
 }
 
 /// <p>The type and amount of a resource to assign to a container. Currently, the only supported resource type is <code>GPU</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceRequirement {
     /// <p>The type of resource to assign to a container. Currently, the only supported resource type is <code>GPU</code>.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ResourceType,
     /// <p>The number of physical GPUs to reserve for the container. The number of GPUs reserved for all containers in a job should not exceed the number of available GPUs on the compute resource that the job is launched on.</p>
     #[serde(rename = "value")]
     pub value: String,
 }
 
+/// <p>The type of resource to assign to a container. Currently, the only supported resource type is <code>GPU</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// <p><code>GPU</code></p>
+    Gpu,
+    /// <p><code>VCPU</code></p>
+    Vcpu,
+    /// <p><code>MEMORY</code></p>
+    Memory,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Gpu => "GPU",
+            ResourceType::Vcpu => "VCPU",
+            ResourceType::Memory => "MEMORY",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "GPU" => ResourceType::Gpu,
+            "VCPU" => ResourceType::Vcpu,
+            "MEMORY" => ResourceType::Memory,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        ResourceType::from(value.as_str())
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(value: ResourceType) -> String {
+        match value {
+            ResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceType::from(value))
+    }
+}
+
 /// <p>The retry strategy associated with a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RetryStrategy {
@@ -1196,6 +2723,115 @@ pub struct SubmitJobRequest {
     pub timeout: Option<JobTimeout>,
 }
 
+impl SubmitJobRequest {
+    /// Returns a builder for `SubmitJobRequest`, checking its required members when built.
+    pub fn builder() -> SubmitJobRequestBuilder {
+        SubmitJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SubmitJobRequest`](struct.SubmitJobRequest.html).
+#[derive(Default, Debug)]
+pub struct SubmitJobRequestBuilder {
+    array_properties: Option<ArrayProperties>,
+    container_overrides: Option<ContainerOverrides>,
+    depends_on: Option<Vec<JobDependency>>,
+    job_definition: Option<String>,
+    job_name: Option<String>,
+    job_queue: Option<String>,
+    node_overrides: Option<NodeOverrides>,
+    parameters: Option<::std::collections::HashMap<String, String>>,
+    retry_strategy: Option<RetryStrategy>,
+    timeout: Option<JobTimeout>,
+}
+
+impl SubmitJobRequestBuilder {
+    /// Sets the [`array_properties`](struct.SubmitJobRequest.html#structfield.array_properties) member.
+    pub fn array_properties<T: Into<ArrayProperties>>(mut self, value: T) -> Self {
+        self.array_properties = Some(value.into());
+        self
+    }
+
+    /// Sets the [`container_overrides`](struct.SubmitJobRequest.html#structfield.container_overrides) member.
+    pub fn container_overrides<T: Into<ContainerOverrides>>(mut self, value: T) -> Self {
+        self.container_overrides = Some(value.into());
+        self
+    }
+
+    /// Sets the [`depends_on`](struct.SubmitJobRequest.html#structfield.depends_on) member.
+    pub fn depends_on<T: Into<Vec<JobDependency>>>(mut self, value: T) -> Self {
+        self.depends_on = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_definition`](struct.SubmitJobRequest.html#structfield.job_definition) member.
+    pub fn job_definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_definition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_name`](struct.SubmitJobRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_queue`](struct.SubmitJobRequest.html#structfield.job_queue) member.
+    pub fn job_queue<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_queue = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_overrides`](struct.SubmitJobRequest.html#structfield.node_overrides) member.
+    pub fn node_overrides<T: Into<NodeOverrides>>(mut self, value: T) -> Self {
+        self.node_overrides = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.SubmitJobRequest.html#structfield.parameters) member.
+    pub fn parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retry_strategy`](struct.SubmitJobRequest.html#structfield.retry_strategy) member.
+    pub fn retry_strategy<T: Into<RetryStrategy>>(mut self, value: T) -> Self {
+        self.retry_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout`](struct.SubmitJobRequest.html#structfield.timeout) member.
+    pub fn timeout<T: Into<JobTimeout>>(mut self, value: T) -> Self {
+        self.timeout = Some(value.into());
+        self
+    }
+
+    /// Builds the `SubmitJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SubmitJobRequest, ::rusoto_core::BuildError> {
+        Ok(SubmitJobRequest {
+            array_properties: self.array_properties,
+            container_overrides: self.container_overrides,
+            depends_on: self.depends_on,
+            job_definition: self
+                .job_definition
+                .ok_or(::rusoto_core::BuildError::missing("job_definition"))?,
+            job_name: self
+                .job_name
+                .ok_or(::rusoto_core::BuildError::missing("job_name"))?,
+            job_queue: self
+                .job_queue
+                .ok_or(::rusoto_core::BuildError::missing("job_queue"))?,
+            node_overrides: self.node_overrides,
+            parameters: self.parameters,
+            retry_strategy: self.retry_strategy,
+            timeout: self.timeout,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SubmitJobResponse {
@@ -1217,6 +2853,46 @@ pub struct TerminateJobRequest {
     pub reason: String,
 }
 
+impl TerminateJobRequest {
+    /// Returns a builder for `TerminateJobRequest`, checking its required members when built.
+    pub fn builder() -> TerminateJobRequestBuilder {
+        TerminateJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TerminateJobRequest`](struct.TerminateJobRequest.html).
+#[derive(Default, Debug)]
+pub struct TerminateJobRequestBuilder {
+    job_id: Option<String>,
+    reason: Option<String>,
+}
+
+impl TerminateJobRequestBuilder {
+    /// Sets the [`job_id`](struct.TerminateJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reason`](struct.TerminateJobRequest.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Builds the `TerminateJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TerminateJobRequest, ::rusoto_core::BuildError> {
+        Ok(TerminateJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            reason: self
+                .reason
+                .ok_or(::rusoto_core::BuildError::missing("reason"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TerminateJobResponse {}
@@ -1251,7 +2927,63 @@ pub struct UpdateComputeEnvironmentRequest {
     /// <p>The state of the compute environment. Compute environments in the <code>ENABLED</code> state can accept jobs from a queue and scale in or out automatically based on the workload demand of its associated queues.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CEState>,
+}
+
+impl UpdateComputeEnvironmentRequest {
+    /// Returns a builder for `UpdateComputeEnvironmentRequest`, checking its required members when built.
+    pub fn builder() -> UpdateComputeEnvironmentRequestBuilder {
+        UpdateComputeEnvironmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateComputeEnvironmentRequest`](struct.UpdateComputeEnvironmentRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateComputeEnvironmentRequestBuilder {
+    compute_environment: Option<String>,
+    compute_resources: Option<ComputeResourceUpdate>,
+    service_role: Option<String>,
+    state: Option<CEState>,
+}
+
+impl UpdateComputeEnvironmentRequestBuilder {
+    /// Sets the [`compute_environment`](struct.UpdateComputeEnvironmentRequest.html#structfield.compute_environment) member.
+    pub fn compute_environment<T: Into<String>>(mut self, value: T) -> Self {
+        self.compute_environment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`compute_resources`](struct.UpdateComputeEnvironmentRequest.html#structfield.compute_resources) member.
+    pub fn compute_resources<T: Into<ComputeResourceUpdate>>(mut self, value: T) -> Self {
+        self.compute_resources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role`](struct.UpdateComputeEnvironmentRequest.html#structfield.service_role) member.
+    pub fn service_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state`](struct.UpdateComputeEnvironmentRequest.html#structfield.state) member.
+    pub fn state<T: Into<CEState>>(mut self, value: T) -> Self {
+        self.state = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateComputeEnvironmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateComputeEnvironmentRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateComputeEnvironmentRequest {
+            compute_environment: self
+                .compute_environment
+                .ok_or(::rusoto_core::BuildError::missing("compute_environment"))?,
+            compute_resources: self.compute_resources,
+            service_role: self.service_role,
+            state: self.state,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1283,7 +3015,64 @@ pub struct UpdateJobQueueRequest {
     /// <p>Describes the queue's ability to accept new jobs.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<JQState>,
+}
+
+impl UpdateJobQueueRequest {
+    /// Returns a builder for `UpdateJobQueueRequest`, checking its required members when built.
+    pub fn builder() -> UpdateJobQueueRequestBuilder {
+        UpdateJobQueueRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateJobQueueRequest`](struct.UpdateJobQueueRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateJobQueueRequestBuilder {
+    compute_environment_order: Option<Vec<ComputeEnvironmentOrder>>,
+    job_queue: Option<String>,
+    priority: Option<i64>,
+    state: Option<JQState>,
+}
+
+impl UpdateJobQueueRequestBuilder {
+    /// Sets the [`compute_environment_order`](struct.UpdateJobQueueRequest.html#structfield.compute_environment_order) member.
+    pub fn compute_environment_order<T: Into<Vec<ComputeEnvironmentOrder>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.compute_environment_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_queue`](struct.UpdateJobQueueRequest.html#structfield.job_queue) member.
+    pub fn job_queue<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_queue = Some(value.into());
+        self
+    }
+
+    /// Sets the [`priority`](struct.UpdateJobQueueRequest.html#structfield.priority) member.
+    pub fn priority<T: Into<i64>>(mut self, value: T) -> Self {
+        self.priority = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state`](struct.UpdateJobQueueRequest.html#structfield.state) member.
+    pub fn state<T: Into<JQState>>(mut self, value: T) -> Self {
+        self.state = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateJobQueueRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateJobQueueRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateJobQueueRequest {
+            compute_environment_order: self.compute_environment_order,
+            job_queue: self
+                .job_queue
+                .ok_or(::rusoto_core::BuildError::missing("job_queue"))?,
+            priority: self.priority,
+            state: self.state,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2021,6 +3810,108 @@ pub trait Batch {
         input: UpdateJobQueueRequest,
     ) -> RusotoFuture<UpdateJobQueueResponse, UpdateJobQueueError>;
 }
+impl<T: ?Sized + Batch> Batch for ::std::sync::Arc<T> {
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_compute_environment(
+        &self,
+        input: CreateComputeEnvironmentRequest,
+    ) -> RusotoFuture<CreateComputeEnvironmentResponse, CreateComputeEnvironmentError> {
+        (**self).create_compute_environment(input)
+    }
+    fn create_job_queue(
+        &self,
+        input: CreateJobQueueRequest,
+    ) -> RusotoFuture<CreateJobQueueResponse, CreateJobQueueError> {
+        (**self).create_job_queue(input)
+    }
+    fn delete_compute_environment(
+        &self,
+        input: DeleteComputeEnvironmentRequest,
+    ) -> RusotoFuture<DeleteComputeEnvironmentResponse, DeleteComputeEnvironmentError> {
+        (**self).delete_compute_environment(input)
+    }
+    fn delete_job_queue(
+        &self,
+        input: DeleteJobQueueRequest,
+    ) -> RusotoFuture<DeleteJobQueueResponse, DeleteJobQueueError> {
+        (**self).delete_job_queue(input)
+    }
+    fn deregister_job_definition(
+        &self,
+        input: DeregisterJobDefinitionRequest,
+    ) -> RusotoFuture<DeregisterJobDefinitionResponse, DeregisterJobDefinitionError> {
+        (**self).deregister_job_definition(input)
+    }
+    fn describe_compute_environments(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> RusotoFuture<DescribeComputeEnvironmentsResponse, DescribeComputeEnvironmentsError> {
+        (**self).describe_compute_environments(input)
+    }
+    fn describe_job_definitions(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> RusotoFuture<DescribeJobDefinitionsResponse, DescribeJobDefinitionsError> {
+        (**self).describe_job_definitions(input)
+    }
+    fn describe_job_queues(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> RusotoFuture<DescribeJobQueuesResponse, DescribeJobQueuesError> {
+        (**self).describe_job_queues(input)
+    }
+    fn describe_jobs(
+        &self,
+        input: DescribeJobsRequest,
+    ) -> RusotoFuture<DescribeJobsResponse, DescribeJobsError> {
+        (**self).describe_jobs(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn register_job_definition(
+        &self,
+        input: RegisterJobDefinitionRequest,
+    ) -> RusotoFuture<RegisterJobDefinitionResponse, RegisterJobDefinitionError> {
+        (**self).register_job_definition(input)
+    }
+    fn submit_job(
+        &self,
+        input: SubmitJobRequest,
+    ) -> RusotoFuture<SubmitJobResponse, SubmitJobError> {
+        (**self).submit_job(input)
+    }
+    fn terminate_job(
+        &self,
+        input: TerminateJobRequest,
+    ) -> RusotoFuture<TerminateJobResponse, TerminateJobError> {
+        (**self).terminate_job(input)
+    }
+    fn update_compute_environment(
+        &self,
+        input: UpdateComputeEnvironmentRequest,
+    ) -> RusotoFuture<UpdateComputeEnvironmentResponse, UpdateComputeEnvironmentError> {
+        (**self).update_compute_environment(input)
+    }
+    fn update_job_queue(
+        &self,
+        input: UpdateJobQueueRequest,
+    ) -> RusotoFuture<UpdateJobQueueResponse, UpdateJobQueueError> {
+        (**self).update_job_queue(input)
+    }
+}
+/// The error codes AWS Batch retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Batch API.
 #[derive(Clone)]
 pub struct BatchClient {
@@ -2036,6 +3927,24 @@ impl BatchClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_BATCH`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> BatchClient {
+        Self::new(region::Region::from_env_for_service("BATCH"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `batch`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> BatchClient {
+        Self::new_with_client(config.client().clone(), config.region_for("batch", "BATCH"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2054,7 +3963,49 @@ impl BatchClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> BatchClient {
-        BatchClient { client, region }
+        BatchClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "batch")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "batch", "batch", "BATCH")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Batch` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Batch + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for BatchClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Batch"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "batch"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> BatchClient {
+        Self::new_with_client(client, region)
     }
 }
 
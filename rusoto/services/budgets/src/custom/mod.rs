@@ -1 +1,3 @@
-
+/// Helpers for sending budget alerts to SNS topics
+#[cfg(feature = "notifications")]
+pub mod notifications;
@@ -0,0 +1,295 @@
+//! Helpers for wiring budget alerts to SNS topics.
+//!
+//! Budgets can only publish to a topic whose access policy allows the `budgets.amazonaws.com`
+//! service principal to call `SNS:Publish`. `create_budget_with_sns_notifications` takes care of
+//! adding that statement before the budget and its notifications are created.
+//!
+//! This module is only available with the `notifications` feature enabled.
+
+use std::error::Error;
+use std::fmt;
+
+use futures::Future;
+use rusoto_core::{RusotoError, RusotoFuture};
+use rusoto_sns::{
+    GetTopicAttributesError, GetTopicAttributesInput, SetTopicAttributesError,
+    SetTopicAttributesInput, Sns,
+};
+use serde_json::{json, Map, Value};
+
+use crate::generated::{
+    Budgets, ComparisonOperator, CreateBudgetError, CreateBudgetRequest, CreateBudgetResponse,
+    Notification, NotificationType, NotificationWithSubscribers, Subscriber, SubscriptionType,
+    ThresholdType,
+};
+
+const BUDGETS_PUBLISH_SID: &str = "AWSBudgetsSNSPublishingPermissions";
+
+/// Errors returned by `create_budget_with_sns_notifications`.
+#[derive(Debug, PartialEq)]
+pub enum BudgetNotificationSetupError {
+    /// Reading the topic's current access policy failed.
+    GetTopicAttributes(GetTopicAttributesError),
+    /// Updating the topic's access policy failed.
+    SetTopicAttributes(SetTopicAttributesError),
+    /// Creating the budget failed.
+    CreateBudget(CreateBudgetError),
+    /// The topic's existing access policy could not be parsed.
+    InvalidTopicPolicy(String),
+}
+
+impl fmt::Display for BudgetNotificationSetupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for BudgetNotificationSetupError {
+    fn description(&self) -> &str {
+        match *self {
+            BudgetNotificationSetupError::GetTopicAttributes(ref cause) => cause.description(),
+            BudgetNotificationSetupError::SetTopicAttributes(ref cause) => cause.description(),
+            BudgetNotificationSetupError::CreateBudget(ref cause) => cause.description(),
+            BudgetNotificationSetupError::InvalidTopicPolicy(ref cause) => cause,
+        }
+    }
+}
+
+/// Builds one notification per threshold, each alerting the SNS topic `topic_arn` once spend
+/// exceeds the given percentage of the budgeted amount.
+///
+/// `notification_type` is either `NotificationType::Actual` or `NotificationType::Forecasted`.
+///
+/// # Examples
+///
+/// ```
+/// use rusoto_budgets::notifications::sns_notifications;
+/// use rusoto_budgets::NotificationType;
+///
+/// let notifications = sns_notifications(
+///     "arn:aws:sns:us-east-1:123456789012:budget-alerts",
+///     NotificationType::Actual,
+///     &[50.0, 80.0, 100.0],
+/// );
+/// assert_eq!(notifications.len(), 3);
+/// ```
+pub fn sns_notifications(
+    topic_arn: &str,
+    notification_type: NotificationType,
+    thresholds: &[f64],
+) -> Vec<NotificationWithSubscribers> {
+    thresholds
+        .iter()
+        .map(|threshold| NotificationWithSubscribers {
+            notification: Notification {
+                comparison_operator: ComparisonOperator::GreaterThan,
+                notification_type: notification_type.clone(),
+                threshold: *threshold,
+                threshold_type: Some(ThresholdType::Percentage),
+                notification_state: None,
+            },
+            subscribers: vec![Subscriber {
+                address: topic_arn.to_owned(),
+                subscription_type: SubscriptionType::Sns,
+            }],
+        })
+        .collect()
+}
+
+/// Adds a statement allowing `budgets.amazonaws.com` to publish to `topic_arn` to an SNS topic
+/// access policy, returning the updated policy document.
+///
+/// Any existing statement with the same `Sid` is replaced, so this can safely be applied more
+/// than once. When `policy` is `None` a new policy document is created.
+pub fn allow_budgets_to_publish(
+    policy: Option<&str>,
+    topic_arn: &str,
+) -> Result<String, serde_json::Error> {
+    let mut policy: Map<String, Value> = match policy {
+        Some(policy) if !policy.trim().is_empty() => serde_json::from_str(policy)?,
+        _ => {
+            let mut policy = Map::new();
+            policy.insert("Version".to_owned(), json!("2008-10-17"));
+            policy
+        }
+    };
+
+    let statement = json!({
+        "Sid": BUDGETS_PUBLISH_SID,
+        "Effect": "Allow",
+        "Principal": { "Service": "budgets.amazonaws.com" },
+        "Action": "SNS:Publish",
+        "Resource": topic_arn,
+    });
+
+    // a policy with a single statement may hold it as an object rather than an array
+    let mut statements = match policy.remove("Statement") {
+        Some(Value::Array(statements)) => statements,
+        Some(statement) => vec![statement],
+        None => Vec::new(),
+    };
+    statements.retain(|s| s["Sid"] != BUDGETS_PUBLISH_SID);
+    statements.push(statement);
+    policy.insert("Statement".to_owned(), Value::Array(statements));
+
+    Ok(Value::Object(policy).to_string())
+}
+
+/// Creates a budget which notifies the SNS topic `topic_arn` once actual spend exceeds each of
+/// the given percentage `thresholds`.
+///
+/// The topic's access policy is updated first so that AWS Budgets is allowed to publish to it.
+/// Notifications already present on `input` are kept.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_budgets::notifications::create_budget_with_sns_notifications;
+/// use rusoto_budgets::{Budget, BudgetType, BudgetsClient, CreateBudgetRequest, Spend, TimeUnit};
+/// use rusoto_core::Region;
+/// use rusoto_sns::SnsClient;
+///
+/// let budget = CreateBudgetRequest::builder()
+///     .account_id("123456789012")
+///     .budget(Budget {
+///         budget_name: "monthly".to_owned(),
+///         budget_type: BudgetType::Cost,
+///         time_unit: TimeUnit::Monthly,
+///         budget_limit: Some(Spend {
+///             amount: "100".to_owned(),
+///             unit: "USD".to_owned(),
+///         }),
+///         calculated_spend: None,
+///         cost_filters: None,
+///         cost_types: None,
+///         last_updated_time: None,
+///         planned_budget_limits: None,
+///         time_period: None,
+///     })
+///     .build()
+///     .unwrap();
+///
+/// create_budget_with_sns_notifications(
+///     &BudgetsClient::new(Region::UsEast1),
+///     &SnsClient::new(Region::UsEast1),
+///     budget,
+///     "arn:aws:sns:us-east-1:123456789012:budget-alerts",
+///     &[80.0, 100.0],
+/// )
+/// .sync()
+/// .unwrap();
+/// ```
+pub fn create_budget_with_sns_notifications<B, S>(
+    budgets: &B,
+    sns: &S,
+    mut input: CreateBudgetRequest,
+    topic_arn: &str,
+    thresholds: &[f64],
+) -> RusotoFuture<CreateBudgetResponse, BudgetNotificationSetupError>
+where
+    B: Budgets + Clone + Send + 'static,
+    S: Sns + Clone + Send + 'static,
+{
+    input
+        .notifications_with_subscribers
+        .get_or_insert_with(Vec::new)
+        .extend(sns_notifications(
+            topic_arn,
+            NotificationType::Actual,
+            thresholds,
+        ));
+
+    let budgets = budgets.clone();
+    let set_sns = sns.clone();
+    let topic_arn = topic_arn.to_owned();
+
+    let future = sns
+        .get_topic_attributes(GetTopicAttributesInput {
+            topic_arn: topic_arn.clone(),
+        })
+        .map_err(|e| e.map_service(BudgetNotificationSetupError::GetTopicAttributes))
+        .and_then(move |response| {
+            let attributes = response.attributes.unwrap_or_default();
+            let policy =
+                allow_budgets_to_publish(attributes.get("Policy").map(String::as_str), &topic_arn)
+                    .map_err(|e| {
+                        RusotoError::Service(BudgetNotificationSetupError::InvalidTopicPolicy(
+                            e.to_string(),
+                        ))
+                    })?;
+            Ok(SetTopicAttributesInput {
+                topic_arn,
+                attribute_name: "Policy".to_owned(),
+                attribute_value: Some(policy),
+            })
+        })
+        .and_then(move |set_policy| {
+            set_sns
+                .set_topic_attributes(set_policy)
+                .map_err(|e| e.map_service(BudgetNotificationSetupError::SetTopicAttributes))
+        })
+        .and_then(move |_| {
+            budgets
+                .create_budget(input)
+                .map_err(|e| e.map_service(BudgetNotificationSetupError::CreateBudget))
+        });
+
+    RusotoFuture::from_future(future)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOPIC_ARN: &str = "arn:aws:sns:us-east-1:123456789012:budget-alerts";
+
+    #[test]
+    fn creates_policy_when_none_exists() {
+        let policy: Value =
+            serde_json::from_str(&allow_budgets_to_publish(None, TOPIC_ARN).unwrap()).unwrap();
+
+        assert_eq!(policy["Statement"][0]["Sid"], BUDGETS_PUBLISH_SID);
+        assert_eq!(
+            policy["Statement"][0]["Principal"]["Service"],
+            "budgets.amazonaws.com"
+        );
+        assert_eq!(policy["Statement"][0]["Resource"], TOPIC_ARN);
+    }
+
+    #[test]
+    fn keeps_existing_statements_and_is_idempotent() {
+        let existing = r#"{
+            "Version": "2008-10-17",
+            "Statement": {"Sid": "__default_statement_ID", "Effect": "Allow"}
+        }"#;
+        let once = allow_budgets_to_publish(Some(existing), TOPIC_ARN).unwrap();
+        let twice = allow_budgets_to_publish(Some(&once), TOPIC_ARN).unwrap();
+        let policy: Value = serde_json::from_str(&twice).unwrap();
+
+        let statements = policy["Statement"].as_array().unwrap();
+        assert_eq!(statements.len(), 2);
+        assert_eq!(statements[0]["Sid"], "__default_statement_ID");
+        assert_eq!(statements[1]["Sid"], BUDGETS_PUBLISH_SID);
+    }
+
+    #[test]
+    fn builds_a_notification_per_threshold() {
+        let notifications =
+            sns_notifications(TOPIC_ARN, NotificationType::Forecasted, &[50.0, 100.0]);
+
+        assert_eq!(notifications.len(), 2);
+        assert_eq!(
+            notifications[1].notification.threshold_type,
+            Some(ThresholdType::Percentage)
+        );
+        assert_eq!(
+            notifications[1].notification.notification_type,
+            NotificationType::Forecasted
+        );
+        assert_eq!(
+            notifications[1].subscribers[0].subscription_type,
+            SubscriptionType::Sns
+        );
+        assert_eq!(notifications[1].subscribers[0].address, TOPIC_ARN);
+    }
+}
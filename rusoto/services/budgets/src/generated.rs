@@ -22,9 +22,10 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Represents the output of the <code>CreateBudget</code> operation. The content consists of the detailed metadata and data file information, and the current status of the <code>budget</code> object.</p> <p>This is the ARN pattern for a budget: </p> <p> <code>arn:aws:budgetservice::AccountId:budget/budgetName</code> </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Budget {
     /// <p>The total amount of cost, usage, RI utilization, or RI coverage that you want to track with your budget.</p> <p> <code>BudgetLimit</code> is required for cost or usage budgets, but optional for RI utilization or coverage budgets. RI utilization or coverage budgets default to <code>100</code>, which is the only valid value for RI utilization or coverage budgets. You can't use <code>BudgetLimit</code> with <code>PlannedBudgetLimits</code> for <code>CreateBudget</code> and <code>UpdateBudget</code> actions. </p>
     #[serde(rename = "BudgetLimit")]
@@ -35,7 +36,7 @@ pub struct Budget {
     pub budget_name: String,
     /// <p>Whether this budget tracks costs, usage, RI utilization, or RI coverage.</p>
     #[serde(rename = "BudgetType")]
-    pub budget_type: String,
+    pub budget_type: BudgetType,
     /// <p>The actual and forecasted cost or usage that the budget tracks.</p>
     #[serde(rename = "CalculatedSpend")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -50,8 +51,9 @@ pub struct Budget {
     pub cost_types: Option<CostTypes>,
     /// <p>The last time that you updated this budget.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<EpochTimestamp>,
     /// <p>A map containing multiple <code>BudgetLimit</code>, including current or future limits.</p> <p> <code>PlannedBudgetLimits</code> is available for cost or usage budget and supports monthly and quarterly <code>TimeUnit</code>. </p> <p>For monthly budgets, provide 12 months of <code>PlannedBudgetLimits</code> values. This must start from the current month and include the next 11 months. The <code>key</code> is the start of the month, <code>UTC</code> in epoch seconds. </p> <p>For quarterly budgets, provide 4 quarters of <code>PlannedBudgetLimits</code> value entries in standard calendar quarter increments. This must start from the current quarter and include the next 3 quarters. The <code>key</code> is the start of the quarter, <code>UTC</code> in epoch seconds. </p> <p>If the planned budget expires before 12 months for monthly or 4 quarters for quarterly, provide the <code>PlannedBudgetLimits</code> values only for the remaining periods.</p> <p>If the budget begins at a date in the future, provide <code>PlannedBudgetLimits</code> values from the start date of the budget. </p> <p>After all of the <code>BudgetLimit</code> values in <code>PlannedBudgetLimits</code> are used, the budget continues to use the last limit as the <code>BudgetLimit</code>. At that point, the planned budget provides the same experience as a fixed budget. </p> <p> <code>DescribeBudget</code> and <code>DescribeBudgets</code> response along with <code>PlannedBudgetLimits</code> will also contain <code>BudgetLimit</code> representing the current month or quarter limit present in <code>PlannedBudgetLimits</code>. This only applies to budgets created with <code>PlannedBudgetLimits</code>. Budgets created without <code>PlannedBudgetLimits</code> will only contain <code>BudgetLimit</code>, and no <code>PlannedBudgetLimits</code>.</p>
     #[serde(rename = "PlannedBudgetLimits")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -62,7 +64,7 @@ pub struct Budget {
     pub time_period: Option<TimePeriod>,
     /// <p>The length of time until a budget resets the actual and forecasted spend. <code>DAILY</code> is available only for <code>RI_UTILIZATION</code> and <code>RI_COVERAGE</code> budgets.</p>
     #[serde(rename = "TimeUnit")]
-    pub time_unit: String,
+    pub time_unit: TimeUnit,
 }
 
 /// <p>A history of the state of a budget at the end of the budget's specified time period.</p>
@@ -74,7 +76,7 @@ pub struct BudgetPerformanceHistory {
     pub budget_name: Option<String>,
     #[serde(rename = "BudgetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub budget_type: Option<String>,
+    pub budget_type: Option<BudgetType>,
     /// <p>A list of amounts of cost or usage that you created budgets for, compared to your actual costs or usage.</p>
     #[serde(rename = "BudgetedAndActualAmountsList")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -89,7 +91,95 @@ pub struct BudgetPerformanceHistory {
     pub cost_types: Option<CostTypes>,
     #[serde(rename = "TimeUnit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_unit: Option<String>,
+    pub time_unit: Option<TimeUnit>,
+}
+
+/// <p> The type of a budget. It must be one of the following types: </p> <p> <code>COST</code>, <code>USAGE</code>, <code>RI_UTILIZATION</code>, <code>RI_COVERAGE</code>, <code>SAVINGS_PLANS_UTILIZATION</code>, or <code>SAVINGS_PLANS_COVERAGE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BudgetType {
+    /// <p><code>USAGE</code></p>
+    Usage,
+    /// <p><code>COST</code></p>
+    Cost,
+    /// <p><code>RI_UTILIZATION</code></p>
+    RiUtilization,
+    /// <p><code>RI_COVERAGE</code></p>
+    RiCoverage,
+    /// <p><code>SAVINGS_PLANS_UTILIZATION</code></p>
+    SavingsPlansUtilization,
+    /// <p><code>SAVINGS_PLANS_COVERAGE</code></p>
+    SavingsPlansCoverage,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BudgetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BudgetType::Usage => "USAGE",
+            BudgetType::Cost => "COST",
+            BudgetType::RiUtilization => "RI_UTILIZATION",
+            BudgetType::RiCoverage => "RI_COVERAGE",
+            BudgetType::SavingsPlansUtilization => "SAVINGS_PLANS_UTILIZATION",
+            BudgetType::SavingsPlansCoverage => "SAVINGS_PLANS_COVERAGE",
+            BudgetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BudgetType {
+    fn from(value: &'a str) -> BudgetType {
+        match value {
+            "USAGE" => BudgetType::Usage,
+            "COST" => BudgetType::Cost,
+            "RI_UTILIZATION" => BudgetType::RiUtilization,
+            "RI_COVERAGE" => BudgetType::RiCoverage,
+            "SAVINGS_PLANS_UTILIZATION" => BudgetType::SavingsPlansUtilization,
+            "SAVINGS_PLANS_COVERAGE" => BudgetType::SavingsPlansCoverage,
+            _ => BudgetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BudgetType {
+    fn from(value: String) -> BudgetType {
+        BudgetType::from(value.as_str())
+    }
+}
+
+impl From<BudgetType> for String {
+    fn from(value: BudgetType) -> String {
+        match value {
+            BudgetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BudgetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BudgetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BudgetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BudgetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BudgetType::from(value))
+    }
 }
 
 /// <p>The amount of cost or usage that you created the budget for, compared to your actual costs or usage.</p>
@@ -122,6 +212,82 @@ pub struct CalculatedSpend {
     pub forecasted_spend: Option<Spend>,
 }
 
+/// <p> The comparison operator of a notification. Currently, the service supports the following operators:</p> <p> <code>GREATER_THAN</code>, <code>LESS_THAN</code>, <code>EQUAL_TO</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonOperator {
+    /// <p><code>GREATER_THAN</code></p>
+    GreaterThan,
+    /// <p><code>LESS_THAN</code></p>
+    LessThan,
+    /// <p><code>EQUAL_TO</code></p>
+    EqualTo,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::GreaterThan => "GREATER_THAN",
+            ComparisonOperator::LessThan => "LESS_THAN",
+            ComparisonOperator::EqualTo => "EQUAL_TO",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "GREATER_THAN" => ComparisonOperator::GreaterThan,
+            "LESS_THAN" => ComparisonOperator::LessThan,
+            "EQUAL_TO" => ComparisonOperator::EqualTo,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        ComparisonOperator::from(value.as_str())
+    }
+}
+
+impl From<ComparisonOperator> for String {
+    fn from(value: ComparisonOperator) -> String {
+        match value {
+            ComparisonOperator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ComparisonOperator::from(value))
+    }
+}
+
 /// <p>The types of cost that are included in a <code>COST</code> budget, such as tax and subscriptions.</p> <p> <code>USAGE</code>, <code>RI_UTILIZATION</code>, and <code>RI_COVERAGE</code> budgets do not have <code>CostTypes</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CostTypes {
@@ -172,7 +338,7 @@ pub struct CostTypes {
 }
 
 /// <p> Request of CreateBudget </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateBudgetRequest {
     /// <p>The <code>accountId</code> that is associated with the budget.</p>
     #[serde(rename = "AccountId")]
@@ -186,13 +352,64 @@ pub struct CreateBudgetRequest {
     pub notifications_with_subscribers: Option<Vec<NotificationWithSubscribers>>,
 }
 
+impl CreateBudgetRequest {
+    /// Returns a builder for `CreateBudgetRequest`, checking its required members when built.
+    pub fn builder() -> CreateBudgetRequestBuilder {
+        CreateBudgetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBudgetRequest`](struct.CreateBudgetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateBudgetRequestBuilder {
+    account_id: Option<String>,
+    budget: Option<Budget>,
+    notifications_with_subscribers: Option<Vec<NotificationWithSubscribers>>,
+}
+
+impl CreateBudgetRequestBuilder {
+    /// Sets the [`account_id`](struct.CreateBudgetRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`budget`](struct.CreateBudgetRequest.html#structfield.budget) member.
+    pub fn budget<T: Into<Budget>>(mut self, value: T) -> Self {
+        self.budget = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notifications_with_subscribers`](struct.CreateBudgetRequest.html#structfield.notifications_with_subscribers) member.
+    pub fn notifications_with_subscribers<T: Into<Vec<NotificationWithSubscribers>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.notifications_with_subscribers = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBudgetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateBudgetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateBudgetRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            budget: self
+                .budget
+                .ok_or(::rusoto_core::BuildError::missing("budget"))?,
+            notifications_with_subscribers: self.notifications_with_subscribers,
+        })
+    }
+}
+
 /// <p> Response of CreateBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateBudgetResponse {}
 
 /// <p> Request of CreateNotification </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateNotificationRequest {
     /// <p>The <code>accountId</code> that is associated with the budget that you want to create a notification for.</p>
     #[serde(rename = "AccountId")]
@@ -208,13 +425,75 @@ pub struct CreateNotificationRequest {
     pub subscribers: Vec<Subscriber>,
 }
 
+impl CreateNotificationRequest {
+    /// Returns a builder for `CreateNotificationRequest`, checking its required members when built.
+    pub fn builder() -> CreateNotificationRequestBuilder {
+        CreateNotificationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateNotificationRequest`](struct.CreateNotificationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateNotificationRequestBuilder {
+    account_id: Option<String>,
+    budget_name: Option<String>,
+    notification: Option<Notification>,
+    subscribers: Option<Vec<Subscriber>>,
+}
+
+impl CreateNotificationRequestBuilder {
+    /// Sets the [`account_id`](struct.CreateNotificationRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`budget_name`](struct.CreateNotificationRequest.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification`](struct.CreateNotificationRequest.html#structfield.notification) member.
+    pub fn notification<T: Into<Notification>>(mut self, value: T) -> Self {
+        self.notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscribers`](struct.CreateNotificationRequest.html#structfield.subscribers) member.
+    pub fn subscribers<T: Into<Vec<Subscriber>>>(mut self, value: T) -> Self {
+        self.subscribers = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateNotificationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateNotificationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateNotificationRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+            notification: self
+                .notification
+                .ok_or(::rusoto_core::BuildError::missing("notification"))?,
+            subscribers: self
+                .subscribers
+                .ok_or(::rusoto_core::BuildError::missing("subscribers"))?,
+        })
+    }
+}
+
 /// <p> Response of CreateNotification </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateNotificationResponse {}
 
 /// <p> Request of CreateSubscriber </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateSubscriberRequest {
     /// <p>The <code>accountId</code> that is associated with the budget that you want to create a subscriber for.</p>
     #[serde(rename = "AccountId")]
@@ -230,6 +509,68 @@ pub struct CreateSubscriberRequest {
     pub subscriber: Subscriber,
 }
 
+impl CreateSubscriberRequest {
+    /// Returns a builder for `CreateSubscriberRequest`, checking its required members when built.
+    pub fn builder() -> CreateSubscriberRequestBuilder {
+        CreateSubscriberRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSubscriberRequest`](struct.CreateSubscriberRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSubscriberRequestBuilder {
+    account_id: Option<String>,
+    budget_name: Option<String>,
+    notification: Option<Notification>,
+    subscriber: Option<Subscriber>,
+}
+
+impl CreateSubscriberRequestBuilder {
+    /// Sets the [`account_id`](struct.CreateSubscriberRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`budget_name`](struct.CreateSubscriberRequest.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification`](struct.CreateSubscriberRequest.html#structfield.notification) member.
+    pub fn notification<T: Into<Notification>>(mut self, value: T) -> Self {
+        self.notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscriber`](struct.CreateSubscriberRequest.html#structfield.subscriber) member.
+    pub fn subscriber<T: Into<Subscriber>>(mut self, value: T) -> Self {
+        self.subscriber = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSubscriberRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSubscriberRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSubscriberRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+            notification: self
+                .notification
+                .ok_or(::rusoto_core::BuildError::missing("notification"))?,
+            subscriber: self
+                .subscriber
+                .ok_or(::rusoto_core::BuildError::missing("subscriber"))?,
+        })
+    }
+}
+
 /// <p> Response of CreateSubscriber </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -246,13 +587,53 @@ pub struct DeleteBudgetRequest {
     pub budget_name: String,
 }
 
+impl DeleteBudgetRequest {
+    /// Returns a builder for `DeleteBudgetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBudgetRequestBuilder {
+        DeleteBudgetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBudgetRequest`](struct.DeleteBudgetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBudgetRequestBuilder {
+    account_id: Option<String>,
+    budget_name: Option<String>,
+}
+
+impl DeleteBudgetRequestBuilder {
+    /// Sets the [`account_id`](struct.DeleteBudgetRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`budget_name`](struct.DeleteBudgetRequest.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBudgetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteBudgetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBudgetRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+        })
+    }
+}
+
 /// <p> Response of DeleteBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteBudgetResponse {}
 
 /// <p> Request of DeleteNotification </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteNotificationRequest {
     /// <p>The <code>accountId</code> that is associated with the budget whose notification you want to delete.</p>
     #[serde(rename = "AccountId")]
@@ -265,13 +646,65 @@ pub struct DeleteNotificationRequest {
     pub notification: Notification,
 }
 
+impl DeleteNotificationRequest {
+    /// Returns a builder for `DeleteNotificationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteNotificationRequestBuilder {
+        DeleteNotificationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteNotificationRequest`](struct.DeleteNotificationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteNotificationRequestBuilder {
+    account_id: Option<String>,
+    budget_name: Option<String>,
+    notification: Option<Notification>,
+}
+
+impl DeleteNotificationRequestBuilder {
+    /// Sets the [`account_id`](struct.DeleteNotificationRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`budget_name`](struct.DeleteNotificationRequest.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification`](struct.DeleteNotificationRequest.html#structfield.notification) member.
+    pub fn notification<T: Into<Notification>>(mut self, value: T) -> Self {
+        self.notification = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteNotificationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteNotificationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteNotificationRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+            notification: self
+                .notification
+                .ok_or(::rusoto_core::BuildError::missing("notification"))?,
+        })
+    }
+}
+
 /// <p> Response of DeleteNotification </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteNotificationResponse {}
 
 /// <p> Request of DeleteSubscriber </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteSubscriberRequest {
     /// <p>The <code>accountId</code> that is associated with the budget whose subscriber you want to delete.</p>
     #[serde(rename = "AccountId")]
@@ -287,6 +720,68 @@ pub struct DeleteSubscriberRequest {
     pub subscriber: Subscriber,
 }
 
+impl DeleteSubscriberRequest {
+    /// Returns a builder for `DeleteSubscriberRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSubscriberRequestBuilder {
+        DeleteSubscriberRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSubscriberRequest`](struct.DeleteSubscriberRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSubscriberRequestBuilder {
+    account_id: Option<String>,
+    budget_name: Option<String>,
+    notification: Option<Notification>,
+    subscriber: Option<Subscriber>,
+}
+
+impl DeleteSubscriberRequestBuilder {
+    /// Sets the [`account_id`](struct.DeleteSubscriberRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`budget_name`](struct.DeleteSubscriberRequest.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification`](struct.DeleteSubscriberRequest.html#structfield.notification) member.
+    pub fn notification<T: Into<Notification>>(mut self, value: T) -> Self {
+        self.notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscriber`](struct.DeleteSubscriberRequest.html#structfield.subscriber) member.
+    pub fn subscriber<T: Into<Subscriber>>(mut self, value: T) -> Self {
+        self.subscriber = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSubscriberRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSubscriberRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSubscriberRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+            notification: self
+                .notification
+                .ok_or(::rusoto_core::BuildError::missing("notification"))?,
+            subscriber: self
+                .subscriber
+                .ok_or(::rusoto_core::BuildError::missing("subscriber"))?,
+        })
+    }
+}
+
 /// <p> Response of DeleteSubscriber </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -310,6 +805,73 @@ pub struct DescribeBudgetPerformanceHistoryRequest {
     pub time_period: Option<TimePeriod>,
 }
 
+impl DescribeBudgetPerformanceHistoryRequest {
+    /// Returns a builder for `DescribeBudgetPerformanceHistoryRequest`, checking its required members when built.
+    pub fn builder() -> DescribeBudgetPerformanceHistoryRequestBuilder {
+        DescribeBudgetPerformanceHistoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBudgetPerformanceHistoryRequest`](struct.DescribeBudgetPerformanceHistoryRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeBudgetPerformanceHistoryRequestBuilder {
+    account_id: Option<String>,
+    budget_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    time_period: Option<TimePeriod>,
+}
+
+impl DescribeBudgetPerformanceHistoryRequestBuilder {
+    /// Sets the [`account_id`](struct.DescribeBudgetPerformanceHistoryRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`budget_name`](struct.DescribeBudgetPerformanceHistoryRequest.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeBudgetPerformanceHistoryRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeBudgetPerformanceHistoryRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_period`](struct.DescribeBudgetPerformanceHistoryRequest.html#structfield.time_period) member.
+    pub fn time_period<T: Into<TimePeriod>>(mut self, value: T) -> Self {
+        self.time_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBudgetPerformanceHistoryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeBudgetPerformanceHistoryRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeBudgetPerformanceHistoryRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            time_period: self.time_period,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeBudgetPerformanceHistoryResponse {
@@ -333,6 +895,46 @@ pub struct DescribeBudgetRequest {
     pub budget_name: String,
 }
 
+impl DescribeBudgetRequest {
+    /// Returns a builder for `DescribeBudgetRequest`, checking its required members when built.
+    pub fn builder() -> DescribeBudgetRequestBuilder {
+        DescribeBudgetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBudgetRequest`](struct.DescribeBudgetRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeBudgetRequestBuilder {
+    account_id: Option<String>,
+    budget_name: Option<String>,
+}
+
+impl DescribeBudgetRequestBuilder {
+    /// Sets the [`account_id`](struct.DescribeBudgetRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`budget_name`](struct.DescribeBudgetRequest.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBudgetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeBudgetRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeBudgetRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+        })
+    }
+}
+
 /// <p> Response of DescribeBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -359,6 +961,52 @@ pub struct DescribeBudgetsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeBudgetsRequest {
+    /// Returns a builder for `DescribeBudgetsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeBudgetsRequestBuilder {
+        DescribeBudgetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBudgetsRequest`](struct.DescribeBudgetsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeBudgetsRequestBuilder {
+    account_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeBudgetsRequestBuilder {
+    /// Sets the [`account_id`](struct.DescribeBudgetsRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeBudgetsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeBudgetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBudgetsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeBudgetsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeBudgetsRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p> Response of DescribeBudgets </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -392,6 +1040,65 @@ pub struct DescribeNotificationsForBudgetRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeNotificationsForBudgetRequest {
+    /// Returns a builder for `DescribeNotificationsForBudgetRequest`, checking its required members when built.
+    pub fn builder() -> DescribeNotificationsForBudgetRequestBuilder {
+        DescribeNotificationsForBudgetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeNotificationsForBudgetRequest`](struct.DescribeNotificationsForBudgetRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeNotificationsForBudgetRequestBuilder {
+    account_id: Option<String>,
+    budget_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeNotificationsForBudgetRequestBuilder {
+    /// Sets the [`account_id`](struct.DescribeNotificationsForBudgetRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`budget_name`](struct.DescribeNotificationsForBudgetRequest.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeNotificationsForBudgetRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeNotificationsForBudgetRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeNotificationsForBudgetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeNotificationsForBudgetRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeNotificationsForBudgetRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p> Response of GetNotificationsForBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -407,7 +1114,7 @@ pub struct DescribeNotificationsForBudgetResponse {
 }
 
 /// <p> Request of DescribeSubscribersForNotification </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeSubscribersForNotificationRequest {
     /// <p>The <code>accountId</code> that is associated with the budget whose subscribers you want descriptions of.</p>
     #[serde(rename = "AccountId")]
@@ -428,6 +1135,75 @@ pub struct DescribeSubscribersForNotificationRequest {
     pub notification: Notification,
 }
 
+impl DescribeSubscribersForNotificationRequest {
+    /// Returns a builder for `DescribeSubscribersForNotificationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeSubscribersForNotificationRequestBuilder {
+        DescribeSubscribersForNotificationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSubscribersForNotificationRequest`](struct.DescribeSubscribersForNotificationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeSubscribersForNotificationRequestBuilder {
+    account_id: Option<String>,
+    budget_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    notification: Option<Notification>,
+}
+
+impl DescribeSubscribersForNotificationRequestBuilder {
+    /// Sets the [`account_id`](struct.DescribeSubscribersForNotificationRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`budget_name`](struct.DescribeSubscribersForNotificationRequest.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeSubscribersForNotificationRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeSubscribersForNotificationRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification`](struct.DescribeSubscribersForNotificationRequest.html#structfield.notification) member.
+    pub fn notification<T: Into<Notification>>(mut self, value: T) -> Self {
+        self.notification = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSubscribersForNotificationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSubscribersForNotificationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeSubscribersForNotificationRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            notification: self
+                .notification
+                .ok_or(::rusoto_core::BuildError::missing("notification"))?,
+        })
+    }
+}
+
 /// <p> Response of DescribeSubscribersForNotification </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -443,29 +1219,173 @@ pub struct DescribeSubscribersForNotificationResponse {
 }
 
 /// <p><p>A notification that is associated with a budget. A budget can have up to five notifications. </p> <p>Each notification must have at least one subscriber. A notification can have one SNS subscriber and up to 10 email subscribers, for a total of 11 subscribers.</p> <p>For example, if you have a budget for 200 dollars and you want to be notified when you go over 160 dollars, create a notification with the following parameters:</p> <ul> <li> <p>A notificationType of <code>ACTUAL</code> </p> </li> <li> <p>A <code>thresholdType</code> of <code>PERCENTAGE</code> </p> </li> <li> <p>A <code>comparisonOperator</code> of <code>GREATER_THAN</code> </p> </li> <li> <p>A notification <code>threshold</code> of <code>80</code> </p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Notification {
     /// <p>The comparison that is used for this notification.</p>
     #[serde(rename = "ComparisonOperator")]
-    pub comparison_operator: String,
+    pub comparison_operator: ComparisonOperator,
     /// <p>Whether this notification is in alarm. If a budget notification is in the <code>ALARM</code> state, you have passed the set threshold for the budget.</p>
     #[serde(rename = "NotificationState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub notification_state: Option<String>,
+    pub notification_state: Option<NotificationState>,
     /// <p>Whether the notification is for how much you have spent (<code>ACTUAL</code>) or for how much you're forecasted to spend (<code>FORECASTED</code>).</p>
     #[serde(rename = "NotificationType")]
-    pub notification_type: String,
+    pub notification_type: NotificationType,
     /// <p>The threshold that is associated with a notification. Thresholds are always a percentage.</p>
     #[serde(rename = "Threshold")]
     pub threshold: f64,
     /// <p>The type of threshold for a notification. For <code>ABSOLUTE_VALUE</code> thresholds, AWS notifies you when you go over or are forecasted to go over your total cost threshold. For <code>PERCENTAGE</code> thresholds, AWS notifies you when you go over or are forecasted to go over a certain percentage of your forecasted spend. For example, if you have a budget for 200 dollars and you have a <code>PERCENTAGE</code> threshold of 80%, AWS notifies you when you go over 160 dollars.</p>
     #[serde(rename = "ThresholdType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub threshold_type: Option<String>,
+    pub threshold_type: Option<ThresholdType>,
+}
+
+/// <p>Whether this notification is in alarm. If a budget notification is in the <code>ALARM</code> state, you have passed the set threshold for the budget.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NotificationState {
+    /// <p><code>OK</code></p>
+    Ok,
+    /// <p><code>ALARM</code></p>
+    Alarm,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NotificationState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotificationState::Ok => "OK",
+            NotificationState::Alarm => "ALARM",
+            NotificationState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NotificationState {
+    fn from(value: &'a str) -> NotificationState {
+        match value {
+            "OK" => NotificationState::Ok,
+            "ALARM" => NotificationState::Alarm,
+            _ => NotificationState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotificationState {
+    fn from(value: String) -> NotificationState {
+        NotificationState::from(value.as_str())
+    }
+}
+
+impl From<NotificationState> for String {
+    fn from(value: NotificationState) -> String {
+        match value {
+            NotificationState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NotificationState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NotificationState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NotificationState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NotificationState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NotificationState::from(value))
+    }
+}
+
+/// <p> The type of a notification. It must be ACTUAL or FORECASTED.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NotificationType {
+    /// <p><code>ACTUAL</code></p>
+    Actual,
+    /// <p><code>FORECASTED</code></p>
+    Forecasted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NotificationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotificationType::Actual => "ACTUAL",
+            NotificationType::Forecasted => "FORECASTED",
+            NotificationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NotificationType {
+    fn from(value: &'a str) -> NotificationType {
+        match value {
+            "ACTUAL" => NotificationType::Actual,
+            "FORECASTED" => NotificationType::Forecasted,
+            _ => NotificationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotificationType {
+    fn from(value: String) -> NotificationType {
+        NotificationType::from(value.as_str())
+    }
+}
+
+impl From<NotificationType> for String {
+    fn from(value: NotificationType) -> String {
+        match value {
+            NotificationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NotificationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NotificationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NotificationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NotificationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NotificationType::from(value))
+    }
 }
 
 /// <p>A notification with subscribers. A notification can have one SNS subscriber and up to 10 email subscribers, for a total of 11 subscribers.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct NotificationWithSubscribers {
     /// <p>The notification that is associated with a budget.</p>
     #[serde(rename = "Notification")]
@@ -487,14 +1407,158 @@ pub struct Spend {
 }
 
 /// <p><p>The subscriber to a budget notification. The subscriber consists of a subscription type and either an Amazon SNS topic or an email address.</p> <p>For example, an email subscriber would have the following parameters:</p> <ul> <li> <p>A <code>subscriptionType</code> of <code>EMAIL</code> </p> </li> <li> <p>An <code>address</code> of <code>example@example.com</code> </p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Subscriber {
     /// <p>The address that AWS sends budget notifications to, either an SNS topic or an email.</p> <p>AWS validates the address for a <code>CreateSubscriber</code> request with the <code>.*</code> regex.</p>
     #[serde(rename = "Address")]
     pub address: String,
     /// <p>The type of notification that AWS sends to a subscriber.</p>
     #[serde(rename = "SubscriptionType")]
-    pub subscription_type: String,
+    pub subscription_type: SubscriptionType,
+}
+
+/// <p> The subscription type of the subscriber. It can be SMS or EMAIL.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SubscriptionType {
+    /// <p><code>SNS</code></p>
+    Sns,
+    /// <p><code>EMAIL</code></p>
+    Email,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SubscriptionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SubscriptionType::Sns => "SNS",
+            SubscriptionType::Email => "EMAIL",
+            SubscriptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SubscriptionType {
+    fn from(value: &'a str) -> SubscriptionType {
+        match value {
+            "SNS" => SubscriptionType::Sns,
+            "EMAIL" => SubscriptionType::Email,
+            _ => SubscriptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SubscriptionType {
+    fn from(value: String) -> SubscriptionType {
+        SubscriptionType::from(value.as_str())
+    }
+}
+
+impl From<SubscriptionType> for String {
+    fn from(value: SubscriptionType) -> String {
+        match value {
+            SubscriptionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SubscriptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SubscriptionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SubscriptionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SubscriptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SubscriptionType::from(value))
+    }
+}
+
+/// <p> The type of threshold for a notification.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ThresholdType {
+    /// <p><code>PERCENTAGE</code></p>
+    Percentage,
+    /// <p><code>ABSOLUTE_VALUE</code></p>
+    AbsoluteValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ThresholdType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThresholdType::Percentage => "PERCENTAGE",
+            ThresholdType::AbsoluteValue => "ABSOLUTE_VALUE",
+            ThresholdType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ThresholdType {
+    fn from(value: &'a str) -> ThresholdType {
+        match value {
+            "PERCENTAGE" => ThresholdType::Percentage,
+            "ABSOLUTE_VALUE" => ThresholdType::AbsoluteValue,
+            _ => ThresholdType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThresholdType {
+    fn from(value: String) -> ThresholdType {
+        ThresholdType::from(value.as_str())
+    }
+}
+
+impl From<ThresholdType> for String {
+    fn from(value: ThresholdType) -> String {
+        match value {
+            ThresholdType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ThresholdType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ThresholdType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThresholdType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ThresholdType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ThresholdType::from(value))
+    }
 }
 
 /// <p>The period of time that is covered by a budget. The period has a start date and an end date. The start date must come before the end date. There are no restrictions on the end date. </p>
@@ -502,16 +1566,98 @@ pub struct Subscriber {
 pub struct TimePeriod {
     /// <p>The end date for a budget. If you didn't specify an end date, AWS set your end date to <code>06/15/87 00:00 UTC</code>. The defaults are the same for the AWS Billing and Cost Management console and the API.</p> <p>After the end date, AWS deletes the budget and all associated notifications and subscribers. You can change your end date with the <code>UpdateBudget</code> operation.</p>
     #[serde(rename = "End")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end: Option<f64>,
+    pub end: Option<EpochTimestamp>,
     /// <p>The start date for a budget. If you created your budget and didn't specify a start date, AWS defaults to the start of your chosen time period (DAILY, MONTHLY, QUARTERLY, or ANNUALLY). For example, if you created your budget on January 24, 2018, chose <code>DAILY</code>, and didn't set a start date, AWS set your start date to <code>01/24/18 00:00 UTC</code>. If you chose <code>MONTHLY</code>, AWS set your start date to <code>01/01/18 00:00 UTC</code>. The defaults are the same for the AWS Billing and Cost Management console and the API.</p> <p>You can change your start date with the <code>UpdateBudget</code> operation.</p>
     #[serde(rename = "Start")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start: Option<f64>,
+    pub start: Option<EpochTimestamp>,
+}
+
+/// <p> The time unit of the budget, such as MONTHLY or QUARTERLY.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+    /// <p><code>DAILY</code></p>
+    Daily,
+    /// <p><code>MONTHLY</code></p>
+    Monthly,
+    /// <p><code>QUARTERLY</code></p>
+    Quarterly,
+    /// <p><code>ANNUALLY</code></p>
+    Annually,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TimeUnit {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TimeUnit::Daily => "DAILY",
+            TimeUnit::Monthly => "MONTHLY",
+            TimeUnit::Quarterly => "QUARTERLY",
+            TimeUnit::Annually => "ANNUALLY",
+            TimeUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TimeUnit {
+    fn from(value: &'a str) -> TimeUnit {
+        match value {
+            "DAILY" => TimeUnit::Daily,
+            "MONTHLY" => TimeUnit::Monthly,
+            "QUARTERLY" => TimeUnit::Quarterly,
+            "ANNUALLY" => TimeUnit::Annually,
+            _ => TimeUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TimeUnit {
+    fn from(value: String) -> TimeUnit {
+        TimeUnit::from(value.as_str())
+    }
+}
+
+impl From<TimeUnit> for String {
+    fn from(value: TimeUnit) -> String {
+        match value {
+            TimeUnit::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TimeUnit {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TimeUnit {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TimeUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TimeUnit::from(value))
+    }
 }
 
 /// <p> Request of UpdateBudget </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateBudgetRequest {
     /// <p>The <code>accountId</code> that is associated with the budget that you want to update.</p>
     #[serde(rename = "AccountId")]
@@ -521,13 +1667,53 @@ pub struct UpdateBudgetRequest {
     pub new_budget: Budget,
 }
 
+impl UpdateBudgetRequest {
+    /// Returns a builder for `UpdateBudgetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateBudgetRequestBuilder {
+        UpdateBudgetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateBudgetRequest`](struct.UpdateBudgetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateBudgetRequestBuilder {
+    account_id: Option<String>,
+    new_budget: Option<Budget>,
+}
+
+impl UpdateBudgetRequestBuilder {
+    /// Sets the [`account_id`](struct.UpdateBudgetRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_budget`](struct.UpdateBudgetRequest.html#structfield.new_budget) member.
+    pub fn new_budget<T: Into<Budget>>(mut self, value: T) -> Self {
+        self.new_budget = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateBudgetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateBudgetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateBudgetRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            new_budget: self
+                .new_budget
+                .ok_or(::rusoto_core::BuildError::missing("new_budget"))?,
+        })
+    }
+}
+
 /// <p> Response of UpdateBudget </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateBudgetResponse {}
 
 /// <p> Request of UpdateNotification </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateNotificationRequest {
     /// <p>The <code>accountId</code> that is associated with the budget whose notification you want to update.</p>
     #[serde(rename = "AccountId")]
@@ -543,13 +1729,75 @@ pub struct UpdateNotificationRequest {
     pub old_notification: Notification,
 }
 
+impl UpdateNotificationRequest {
+    /// Returns a builder for `UpdateNotificationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateNotificationRequestBuilder {
+        UpdateNotificationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateNotificationRequest`](struct.UpdateNotificationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateNotificationRequestBuilder {
+    account_id: Option<String>,
+    budget_name: Option<String>,
+    new_notification: Option<Notification>,
+    old_notification: Option<Notification>,
+}
+
+impl UpdateNotificationRequestBuilder {
+    /// Sets the [`account_id`](struct.UpdateNotificationRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`budget_name`](struct.UpdateNotificationRequest.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_notification`](struct.UpdateNotificationRequest.html#structfield.new_notification) member.
+    pub fn new_notification<T: Into<Notification>>(mut self, value: T) -> Self {
+        self.new_notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`old_notification`](struct.UpdateNotificationRequest.html#structfield.old_notification) member.
+    pub fn old_notification<T: Into<Notification>>(mut self, value: T) -> Self {
+        self.old_notification = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateNotificationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateNotificationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateNotificationRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+            new_notification: self
+                .new_notification
+                .ok_or(::rusoto_core::BuildError::missing("new_notification"))?,
+            old_notification: self
+                .old_notification
+                .ok_or(::rusoto_core::BuildError::missing("old_notification"))?,
+        })
+    }
+}
+
 /// <p> Response of UpdateNotification </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateNotificationResponse {}
 
 /// <p> Request of UpdateSubscriber </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateSubscriberRequest {
     /// <p>The <code>accountId</code> that is associated with the budget whose subscriber you want to update.</p>
     #[serde(rename = "AccountId")]
@@ -568,6 +1816,78 @@ pub struct UpdateSubscriberRequest {
     pub old_subscriber: Subscriber,
 }
 
+impl UpdateSubscriberRequest {
+    /// Returns a builder for `UpdateSubscriberRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSubscriberRequestBuilder {
+        UpdateSubscriberRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSubscriberRequest`](struct.UpdateSubscriberRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSubscriberRequestBuilder {
+    account_id: Option<String>,
+    budget_name: Option<String>,
+    new_subscriber: Option<Subscriber>,
+    notification: Option<Notification>,
+    old_subscriber: Option<Subscriber>,
+}
+
+impl UpdateSubscriberRequestBuilder {
+    /// Sets the [`account_id`](struct.UpdateSubscriberRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`budget_name`](struct.UpdateSubscriberRequest.html#structfield.budget_name) member.
+    pub fn budget_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.budget_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_subscriber`](struct.UpdateSubscriberRequest.html#structfield.new_subscriber) member.
+    pub fn new_subscriber<T: Into<Subscriber>>(mut self, value: T) -> Self {
+        self.new_subscriber = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification`](struct.UpdateSubscriberRequest.html#structfield.notification) member.
+    pub fn notification<T: Into<Notification>>(mut self, value: T) -> Self {
+        self.notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`old_subscriber`](struct.UpdateSubscriberRequest.html#structfield.old_subscriber) member.
+    pub fn old_subscriber<T: Into<Subscriber>>(mut self, value: T) -> Self {
+        self.old_subscriber = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSubscriberRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSubscriberRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSubscriberRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            budget_name: self
+                .budget_name
+                .ok_or(::rusoto_core::BuildError::missing("budget_name"))?,
+            new_subscriber: self
+                .new_subscriber
+                .ok_or(::rusoto_core::BuildError::missing("new_subscriber"))?,
+            notification: self
+                .notification
+                .ok_or(::rusoto_core::BuildError::missing("notification"))?,
+            old_subscriber: self
+                .old_subscriber
+                .ok_or(::rusoto_core::BuildError::missing("old_subscriber"))?,
+        })
+    }
+}
+
 /// <p> Response of UpdateSubscriber </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1422,6 +2742,104 @@ pub trait Budgets {
         input: UpdateSubscriberRequest,
     ) -> RusotoFuture<UpdateSubscriberResponse, UpdateSubscriberError>;
 }
+impl<T: ?Sized + Budgets> Budgets for ::std::sync::Arc<T> {
+    fn create_budget(
+        &self,
+        input: CreateBudgetRequest,
+    ) -> RusotoFuture<CreateBudgetResponse, CreateBudgetError> {
+        (**self).create_budget(input)
+    }
+    fn create_notification(
+        &self,
+        input: CreateNotificationRequest,
+    ) -> RusotoFuture<CreateNotificationResponse, CreateNotificationError> {
+        (**self).create_notification(input)
+    }
+    fn create_subscriber(
+        &self,
+        input: CreateSubscriberRequest,
+    ) -> RusotoFuture<CreateSubscriberResponse, CreateSubscriberError> {
+        (**self).create_subscriber(input)
+    }
+    fn delete_budget(
+        &self,
+        input: DeleteBudgetRequest,
+    ) -> RusotoFuture<DeleteBudgetResponse, DeleteBudgetError> {
+        (**self).delete_budget(input)
+    }
+    fn delete_notification(
+        &self,
+        input: DeleteNotificationRequest,
+    ) -> RusotoFuture<DeleteNotificationResponse, DeleteNotificationError> {
+        (**self).delete_notification(input)
+    }
+    fn delete_subscriber(
+        &self,
+        input: DeleteSubscriberRequest,
+    ) -> RusotoFuture<DeleteSubscriberResponse, DeleteSubscriberError> {
+        (**self).delete_subscriber(input)
+    }
+    fn describe_budget(
+        &self,
+        input: DescribeBudgetRequest,
+    ) -> RusotoFuture<DescribeBudgetResponse, DescribeBudgetError> {
+        (**self).describe_budget(input)
+    }
+    fn describe_budget_performance_history(
+        &self,
+        input: DescribeBudgetPerformanceHistoryRequest,
+    ) -> RusotoFuture<DescribeBudgetPerformanceHistoryResponse, DescribeBudgetPerformanceHistoryError>
+    {
+        (**self).describe_budget_performance_history(input)
+    }
+    fn describe_budgets(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> RusotoFuture<DescribeBudgetsResponse, DescribeBudgetsError> {
+        (**self).describe_budgets(input)
+    }
+    fn describe_notifications_for_budget(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> RusotoFuture<DescribeNotificationsForBudgetResponse, DescribeNotificationsForBudgetError>
+    {
+        (**self).describe_notifications_for_budget(input)
+    }
+    fn describe_subscribers_for_notification(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> RusotoFuture<
+        DescribeSubscribersForNotificationResponse,
+        DescribeSubscribersForNotificationError,
+    > {
+        (**self).describe_subscribers_for_notification(input)
+    }
+    fn update_budget(
+        &self,
+        input: UpdateBudgetRequest,
+    ) -> RusotoFuture<UpdateBudgetResponse, UpdateBudgetError> {
+        (**self).update_budget(input)
+    }
+    fn update_notification(
+        &self,
+        input: UpdateNotificationRequest,
+    ) -> RusotoFuture<UpdateNotificationResponse, UpdateNotificationError> {
+        (**self).update_notification(input)
+    }
+    fn update_subscriber(
+        &self,
+        input: UpdateSubscriberRequest,
+    ) -> RusotoFuture<UpdateSubscriberResponse, UpdateSubscriberError> {
+        (**self).update_subscriber(input)
+    }
+}
+/// The error codes AWSBudgets retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWSBudgets API.
 #[derive(Clone)]
 pub struct BudgetsClient {
@@ -1437,6 +2855,27 @@ impl BudgetsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_BUDGETS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> BudgetsClient {
+        Self::new(region::Region::from_env_for_service("BUDGETS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `budgets`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> BudgetsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("budgets", "BUDGETS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1455,7 +2894,49 @@ impl BudgetsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> BudgetsClient {
-        BudgetsClient { client, region }
+        BudgetsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "budgets")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "budgets", "budgets", "BUDGETS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Budgets` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Budgets + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for BudgetsClient {
+    fn service_name(&self) -> &'static str {
+        "AWSBudgets"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "budgets"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> BudgetsClient {
+        Self::new_with_client(client, region)
     }
 }
 
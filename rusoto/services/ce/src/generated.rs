@@ -22,7 +22,156 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The account scope that you want recommendations for. <code>PAYER</code> means that AWS includes the master account and any member accounts when it calculates its recommendations. <code>LINKED</code> means that AWS includes only member accounts when it calculates its recommendations.</p> <p>Valid values are <code>PAYER</code> and <code>LINKED</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccountScope {
+    /// <p><code>PAYER</code></p>
+    Payer,
+    /// <p><code>LINKED</code></p>
+    Linked,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AccountScope {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountScope::Payer => "PAYER",
+            AccountScope::Linked => "LINKED",
+            AccountScope::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AccountScope {
+    fn from(value: &'a str) -> AccountScope {
+        match value {
+            "PAYER" => AccountScope::Payer,
+            "LINKED" => AccountScope::Linked,
+            _ => AccountScope::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountScope {
+    fn from(value: String) -> AccountScope {
+        AccountScope::from(value.as_str())
+    }
+}
+
+impl From<AccountScope> for String {
+    fn from(value: AccountScope) -> String {
+        match value {
+            AccountScope::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AccountScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AccountScope {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccountScope {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AccountScope, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AccountScope::from(value))
+    }
+}
+
+/// <p><p>The context for the call to <code>GetDimensionValues</code>. This can be <code>RESERVATIONS</code> or <code>COST<em>AND</em>USAGE</code>. The default value is <code>COST<em>AND</em>USAGE</code>. If the context is set to <code>RESERVATIONS</code>, the resulting dimension values can be used in the <code>GetReservationUtilization</code> operation. If the context is set to <code>COST<em>AND</em>USAGE</code>, the resulting dimension values can be used in the <code>GetCostAndUsage</code> operation.</p> <p>If you set the context to <code>COST<em>AND</em>USAGE</code>, you can use the following dimensions for searching:</p> <ul> <li> <p>AZ - The Availability Zone. An example is <code>us-east-1a</code>.</p> </li> <li> <p>DATABASE<em>ENGINE - The Amazon Relational Database Service database. Examples are Aurora or MySQL.</p> </li> <li> <p>INSTANCE</em>TYPE - The type of Amazon EC2 instance. An example is <code>m4.xlarge</code>.</p> </li> <li> <p>LEGAL<em>ENTITY</em>NAME - The name of the organization that sells you AWS services, such as Amazon Web Services.</p> </li> <li> <p>LINKED<em>ACCOUNT - The description in the attribute map that includes the full name of the member account. The value field contains the AWS ID of the member account.</p> </li> <li> <p>OPERATING</em>SYSTEM - The operating system. Examples are Windows or Linux.</p> </li> <li> <p>OPERATION - The action performed. Examples include <code>RunInstance</code> and <code>CreateBucket</code>.</p> </li> <li> <p>PLATFORM - The Amazon EC2 operating system. Examples are Windows or Linux.</p> </li> <li> <p>PURCHASE<em>TYPE - The reservation type of the purchase to which this usage is related. Examples include On-Demand Instances and Standard Reserved Instances.</p> </li> <li> <p>SERVICE - The AWS service such as Amazon DynamoDB.</p> </li> <li> <p>USAGE</em>TYPE - The type of usage. An example is DataTransfer-In-Bytes. The response for the <code>GetDimensionValues</code> operation includes a unit attribute. Examples include GB and Hrs.</p> </li> <li> <p>USAGE<em>TYPE</em>GROUP - The grouping of common usage types. An example is Amazon EC2: CloudWatch – Alarms. The response for this operation includes a unit attribute.</p> </li> <li> <p>RECORD<em>TYPE - The different types of charges such as RI fees, usage costs, tax refunds, and credits.</p> </li> </ul> <p>If you set the context to <code>RESERVATIONS</code>, you can use the following dimensions for searching:</p> <ul> <li> <p>AZ - The Availability Zone. An example is <code>us-east-1a</code>.</p> </li> <li> <p>CACHE</em>ENGINE - The Amazon ElastiCache operating system. Examples are Windows or Linux.</p> </li> <li> <p>DEPLOYMENT<em>OPTION - The scope of Amazon Relational Database Service deployments. Valid values are <code>SingleAZ</code> and <code>MultiAZ</code>.</p> </li> <li> <p>INSTANCE</em>TYPE - The type of Amazon EC2 instance. An example is <code>m4.xlarge</code>.</p> </li> <li> <p>LINKED_ACCOUNT - The description in the attribute map that includes the full name of the member account. The value field contains the AWS ID of the member account.</p> </li> <li> <p>PLATFORM - The Amazon EC2 operating system. Examples are Windows or Linux.</p> </li> <li> <p>REGION - The AWS Region.</p> </li> <li> <p>SCOPE (Utilization only) - The scope of a Reserved Instance (RI). Values are regional or a single Availability Zone.</p> </li> <li> <p>TAG (Coverage only) - The tags that are associated with a Reserved Instance (RI).</p> </li> <li> <p>TENANCY - The tenancy of a resource. Examples are shared or dedicated.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Context {
+    /// <p><code>COST_AND_USAGE</code></p>
+    CostAndUsage,
+    /// <p><code>RESERVATIONS</code></p>
+    Reservations,
+    /// <p><code>SAVINGS_PLANS</code></p>
+    SavingsPlans,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Context {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Context::CostAndUsage => "COST_AND_USAGE",
+            Context::Reservations => "RESERVATIONS",
+            Context::SavingsPlans => "SAVINGS_PLANS",
+            Context::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Context {
+    fn from(value: &'a str) -> Context {
+        match value {
+            "COST_AND_USAGE" => Context::CostAndUsage,
+            "RESERVATIONS" => Context::Reservations,
+            "SAVINGS_PLANS" => Context::SavingsPlans,
+            _ => Context::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Context {
+    fn from(value: String) -> Context {
+        Context::from(value.as_str())
+    }
+}
+
+impl From<Context> for String {
+    fn from(value: Context) -> String {
+        match value {
+            Context::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Context {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Context {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Context, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Context::from(value))
+    }
+}
+
 /// <p>The amount of instance usage that a reservation covered.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -166,13 +315,213 @@ pub struct DateInterval {
     pub start: String,
 }
 
+/// <p>The names of the metadata types that you can use to filter and group your results. For example, <code>AZ</code> returns a list of Availability Zones.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Dimension {
+    /// <p><code>AZ</code></p>
+    Az,
+    /// <p><code>INSTANCE_TYPE</code></p>
+    InstanceType,
+    /// <p><code>LINKED_ACCOUNT</code></p>
+    LinkedAccount,
+    /// <p><code>LINKED_ACCOUNT_NAME</code></p>
+    LinkedAccountName,
+    /// <p><code>OPERATION</code></p>
+    Operation,
+    /// <p><code>PURCHASE_TYPE</code></p>
+    PurchaseType,
+    /// <p><code>REGION</code></p>
+    Region,
+    /// <p><code>SERVICE</code></p>
+    Service,
+    /// <p><code>SERVICE_CODE</code></p>
+    ServiceCode,
+    /// <p><code>USAGE_TYPE</code></p>
+    UsageType,
+    /// <p><code>USAGE_TYPE_GROUP</code></p>
+    UsageTypeGroup,
+    /// <p><code>RECORD_TYPE</code></p>
+    RecordType,
+    /// <p><code>OPERATING_SYSTEM</code></p>
+    OperatingSystem,
+    /// <p><code>TENANCY</code></p>
+    Tenancy,
+    /// <p><code>SCOPE</code></p>
+    Scope,
+    /// <p><code>PLATFORM</code></p>
+    Platform,
+    /// <p><code>SUBSCRIPTION_ID</code></p>
+    SubscriptionId,
+    /// <p><code>LEGAL_ENTITY_NAME</code></p>
+    LegalEntityName,
+    /// <p><code>DEPLOYMENT_OPTION</code></p>
+    DeploymentOption,
+    /// <p><code>DATABASE_ENGINE</code></p>
+    DatabaseEngine,
+    /// <p><code>CACHE_ENGINE</code></p>
+    CacheEngine,
+    /// <p><code>INSTANCE_TYPE_FAMILY</code></p>
+    InstanceTypeFamily,
+    /// <p><code>BILLING_ENTITY</code></p>
+    BillingEntity,
+    /// <p><code>RESERVATION_ID</code></p>
+    ReservationId,
+    /// <p><code>RESOURCE_ID</code></p>
+    ResourceId,
+    /// <p><code>RIGHTSIZING_TYPE</code></p>
+    RightsizingType,
+    /// <p><code>SAVINGS_PLANS_TYPE</code></p>
+    SavingsPlansType,
+    /// <p><code>SAVINGS_PLAN_ARN</code></p>
+    SavingsPlanArn,
+    /// <p><code>PAYMENT_OPTION</code></p>
+    PaymentOption,
+    /// <p><code>AGREEMENT_END_DATE_TIME_AFTER</code></p>
+    AgreementEndDateTimeAfter,
+    /// <p><code>AGREEMENT_END_DATE_TIME_BEFORE</code></p>
+    AgreementEndDateTimeBefore,
+    /// <p><code>INVOICING_ENTITY</code></p>
+    InvoicingEntity,
+    /// <p><code>ANOMALY_TOTAL_IMPACT_ABSOLUTE</code></p>
+    AnomalyTotalImpactAbsolute,
+    /// <p><code>ANOMALY_TOTAL_IMPACT_PERCENTAGE</code></p>
+    AnomalyTotalImpactPercentage,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Dimension {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Dimension::Az => "AZ",
+            Dimension::InstanceType => "INSTANCE_TYPE",
+            Dimension::LinkedAccount => "LINKED_ACCOUNT",
+            Dimension::LinkedAccountName => "LINKED_ACCOUNT_NAME",
+            Dimension::Operation => "OPERATION",
+            Dimension::PurchaseType => "PURCHASE_TYPE",
+            Dimension::Region => "REGION",
+            Dimension::Service => "SERVICE",
+            Dimension::ServiceCode => "SERVICE_CODE",
+            Dimension::UsageType => "USAGE_TYPE",
+            Dimension::UsageTypeGroup => "USAGE_TYPE_GROUP",
+            Dimension::RecordType => "RECORD_TYPE",
+            Dimension::OperatingSystem => "OPERATING_SYSTEM",
+            Dimension::Tenancy => "TENANCY",
+            Dimension::Scope => "SCOPE",
+            Dimension::Platform => "PLATFORM",
+            Dimension::SubscriptionId => "SUBSCRIPTION_ID",
+            Dimension::LegalEntityName => "LEGAL_ENTITY_NAME",
+            Dimension::DeploymentOption => "DEPLOYMENT_OPTION",
+            Dimension::DatabaseEngine => "DATABASE_ENGINE",
+            Dimension::CacheEngine => "CACHE_ENGINE",
+            Dimension::InstanceTypeFamily => "INSTANCE_TYPE_FAMILY",
+            Dimension::BillingEntity => "BILLING_ENTITY",
+            Dimension::ReservationId => "RESERVATION_ID",
+            Dimension::ResourceId => "RESOURCE_ID",
+            Dimension::RightsizingType => "RIGHTSIZING_TYPE",
+            Dimension::SavingsPlansType => "SAVINGS_PLANS_TYPE",
+            Dimension::SavingsPlanArn => "SAVINGS_PLAN_ARN",
+            Dimension::PaymentOption => "PAYMENT_OPTION",
+            Dimension::AgreementEndDateTimeAfter => "AGREEMENT_END_DATE_TIME_AFTER",
+            Dimension::AgreementEndDateTimeBefore => "AGREEMENT_END_DATE_TIME_BEFORE",
+            Dimension::InvoicingEntity => "INVOICING_ENTITY",
+            Dimension::AnomalyTotalImpactAbsolute => "ANOMALY_TOTAL_IMPACT_ABSOLUTE",
+            Dimension::AnomalyTotalImpactPercentage => "ANOMALY_TOTAL_IMPACT_PERCENTAGE",
+            Dimension::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Dimension {
+    fn from(value: &'a str) -> Dimension {
+        match value {
+            "AZ" => Dimension::Az,
+            "INSTANCE_TYPE" => Dimension::InstanceType,
+            "LINKED_ACCOUNT" => Dimension::LinkedAccount,
+            "LINKED_ACCOUNT_NAME" => Dimension::LinkedAccountName,
+            "OPERATION" => Dimension::Operation,
+            "PURCHASE_TYPE" => Dimension::PurchaseType,
+            "REGION" => Dimension::Region,
+            "SERVICE" => Dimension::Service,
+            "SERVICE_CODE" => Dimension::ServiceCode,
+            "USAGE_TYPE" => Dimension::UsageType,
+            "USAGE_TYPE_GROUP" => Dimension::UsageTypeGroup,
+            "RECORD_TYPE" => Dimension::RecordType,
+            "OPERATING_SYSTEM" => Dimension::OperatingSystem,
+            "TENANCY" => Dimension::Tenancy,
+            "SCOPE" => Dimension::Scope,
+            "PLATFORM" => Dimension::Platform,
+            "SUBSCRIPTION_ID" => Dimension::SubscriptionId,
+            "LEGAL_ENTITY_NAME" => Dimension::LegalEntityName,
+            "DEPLOYMENT_OPTION" => Dimension::DeploymentOption,
+            "DATABASE_ENGINE" => Dimension::DatabaseEngine,
+            "CACHE_ENGINE" => Dimension::CacheEngine,
+            "INSTANCE_TYPE_FAMILY" => Dimension::InstanceTypeFamily,
+            "BILLING_ENTITY" => Dimension::BillingEntity,
+            "RESERVATION_ID" => Dimension::ReservationId,
+            "RESOURCE_ID" => Dimension::ResourceId,
+            "RIGHTSIZING_TYPE" => Dimension::RightsizingType,
+            "SAVINGS_PLANS_TYPE" => Dimension::SavingsPlansType,
+            "SAVINGS_PLAN_ARN" => Dimension::SavingsPlanArn,
+            "PAYMENT_OPTION" => Dimension::PaymentOption,
+            "AGREEMENT_END_DATE_TIME_AFTER" => Dimension::AgreementEndDateTimeAfter,
+            "AGREEMENT_END_DATE_TIME_BEFORE" => Dimension::AgreementEndDateTimeBefore,
+            "INVOICING_ENTITY" => Dimension::InvoicingEntity,
+            "ANOMALY_TOTAL_IMPACT_ABSOLUTE" => Dimension::AnomalyTotalImpactAbsolute,
+            "ANOMALY_TOTAL_IMPACT_PERCENTAGE" => Dimension::AnomalyTotalImpactPercentage,
+            _ => Dimension::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Dimension {
+    fn from(value: String) -> Dimension {
+        Dimension::from(value.as_str())
+    }
+}
+
+impl From<Dimension> for String {
+    fn from(value: Dimension) -> String {
+        match value {
+            Dimension::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Dimension {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Dimension {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Dimension, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Dimension::from(value))
+    }
+}
+
 /// <p>The metadata that you can use to filter and group your results. You can use <code>GetDimensionValues</code> to find specific values.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DimensionValues {
     /// <p>The names of the metadata types that you can use to filter and group your results. For example, <code>AZ</code> returns a list of Availability Zones.</p>
     #[serde(rename = "Key")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
+    pub key: Option<Dimension>,
     /// <p>The metadata values that you can use to filter and group your results. You can use <code>GetDimensionValues</code> to find specific values.</p> <p>Valid values for the <code>SERVICE</code> dimension are <code>Amazon Elastic Compute Cloud - Compute</code>, <code>Amazon Elasticsearch Service</code>, <code>Amazon ElastiCache</code>, <code>Amazon Redshift</code>, and <code>Amazon Relational Database Service</code>.</p>
     #[serde(rename = "Values")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -297,7 +646,7 @@ pub struct EC2Specification {
     /// <p>Whether you want a recommendation for standard or convertible reservations.</p>
     #[serde(rename = "OfferingClass")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub offering_class: Option<String>,
+    pub offering_class: Option<OfferingClass>,
 }
 
 /// <p>Details about the Amazon ES instances that AWS recommends that you purchase.</p>
@@ -412,7 +761,7 @@ pub struct GetCostAndUsageRequest {
     /// <p>Sets the AWS cost granularity to <code>MONTHLY</code> or <code>DAILY</code>. If <code>Granularity</code> isn't set, the response object doesn't include the <code>Granularity</code>, either <code>MONTHLY</code> or <code>DAILY</code>. </p> <p>The <code>GetCostAndUsageRequest</code> operation supports only <code>DAILY</code> and <code>MONTHLY</code> granularities.</p>
     #[serde(rename = "Granularity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub granularity: Option<String>,
+    pub granularity: Option<Granularity>,
     /// <p>You can group AWS costs using up to two different groups, either dimensions, tag keys, or both.</p> <p>When you group by tag key, you get all tag values, including empty strings.</p> <p>Valid values are <code>AZ</code>, <code>INSTANCE_TYPE</code>, <code>LEGAL_ENTITY_NAME</code>, <code>LINKED_ACCOUNT</code>, <code>OPERATION</code>, <code>PLATFORM</code>, <code>PURCHASE_TYPE</code>, <code>SERVICE</code>, <code>TAGS</code>, <code>TENANCY</code>, and <code>USAGE_TYPE</code>.</p>
     #[serde(rename = "GroupBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -430,6 +779,76 @@ pub struct GetCostAndUsageRequest {
     pub time_period: DateInterval,
 }
 
+impl GetCostAndUsageRequest {
+    /// Returns a builder for `GetCostAndUsageRequest`, checking its required members when built.
+    pub fn builder() -> GetCostAndUsageRequestBuilder {
+        GetCostAndUsageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCostAndUsageRequest`](struct.GetCostAndUsageRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCostAndUsageRequestBuilder {
+    filter: Option<Expression>,
+    granularity: Option<Granularity>,
+    group_by: Option<Vec<GroupDefinition>>,
+    metrics: Option<Vec<String>>,
+    next_page_token: Option<String>,
+    time_period: Option<DateInterval>,
+}
+
+impl GetCostAndUsageRequestBuilder {
+    /// Sets the [`filter`](struct.GetCostAndUsageRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<Expression>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`granularity`](struct.GetCostAndUsageRequest.html#structfield.granularity) member.
+    pub fn granularity<T: Into<Granularity>>(mut self, value: T) -> Self {
+        self.granularity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_by`](struct.GetCostAndUsageRequest.html#structfield.group_by) member.
+    pub fn group_by<T: Into<Vec<GroupDefinition>>>(mut self, value: T) -> Self {
+        self.group_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metrics`](struct.GetCostAndUsageRequest.html#structfield.metrics) member.
+    pub fn metrics<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.metrics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.GetCostAndUsageRequest.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_period`](struct.GetCostAndUsageRequest.html#structfield.time_period) member.
+    pub fn time_period<T: Into<DateInterval>>(mut self, value: T) -> Self {
+        self.time_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCostAndUsageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetCostAndUsageRequest, ::rusoto_core::BuildError> {
+        Ok(GetCostAndUsageRequest {
+            filter: self.filter,
+            granularity: self.granularity,
+            group_by: self.group_by,
+            metrics: self.metrics,
+            next_page_token: self.next_page_token,
+            time_period: self
+                .time_period
+                .ok_or(::rusoto_core::BuildError::missing("time_period"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCostAndUsageResponse {
@@ -447,7 +866,7 @@ pub struct GetCostAndUsageResponse {
     pub results_by_time: Option<Vec<ResultByTime>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetCostForecastRequest {
     /// <p>The filters that you want to use to filter your forecast. Cost Explorer API supports all of the Cost Explorer filters.</p>
     #[serde(rename = "Filter")]
@@ -455,10 +874,10 @@ pub struct GetCostForecastRequest {
     pub filter: Option<Expression>,
     /// <p>How granular you want the forecast to be. You can get 3 months of <code>DAILY</code> forecasts or 12 months of <code>MONTHLY</code> forecasts.</p> <p>The <code>GetCostForecast</code> operation supports only <code>DAILY</code> and <code>MONTHLY</code> granularities.</p>
     #[serde(rename = "Granularity")]
-    pub granularity: String,
+    pub granularity: Granularity,
     /// <p><p>Which metric Cost Explorer uses to create your forecast. For more information about blended and unblended rates, see <a href="https://aws.amazon.com/premiumsupport/knowledge-center/blended-rates-intro/">Why does the &quot;blended&quot; annotation appear on some line items in my bill?</a>. </p> <p>Valid values for a <code>GetCostForecast</code> call are the following:</p> <ul> <li> <p>AMORTIZED<em>COST</p> </li> <li> <p>BLENDED</em>COST</p> </li> <li> <p>NET<em>AMORTIZED</em>COST</p> </li> <li> <p>NET<em>UNBLENDED</em>COST</p> </li> <li> <p>UNBLENDED_COST</p> </li> </ul></p>
     #[serde(rename = "Metric")]
-    pub metric: String,
+    pub metric: Metric,
     /// <p>Cost Explorer always returns the mean forecast as a single point. You can request a prediction interval around the mean by specifying a confidence level. The higher the confidence level, the more confident Cost Explorer is about the actual value falling in the prediction interval. Higher confidence levels result in wider prediction intervals.</p>
     #[serde(rename = "PredictionIntervalLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -468,6 +887,72 @@ pub struct GetCostForecastRequest {
     pub time_period: DateInterval,
 }
 
+impl GetCostForecastRequest {
+    /// Returns a builder for `GetCostForecastRequest`, checking its required members when built.
+    pub fn builder() -> GetCostForecastRequestBuilder {
+        GetCostForecastRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCostForecastRequest`](struct.GetCostForecastRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCostForecastRequestBuilder {
+    filter: Option<Expression>,
+    granularity: Option<Granularity>,
+    metric: Option<Metric>,
+    prediction_interval_level: Option<i64>,
+    time_period: Option<DateInterval>,
+}
+
+impl GetCostForecastRequestBuilder {
+    /// Sets the [`filter`](struct.GetCostForecastRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<Expression>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`granularity`](struct.GetCostForecastRequest.html#structfield.granularity) member.
+    pub fn granularity<T: Into<Granularity>>(mut self, value: T) -> Self {
+        self.granularity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric`](struct.GetCostForecastRequest.html#structfield.metric) member.
+    pub fn metric<T: Into<Metric>>(mut self, value: T) -> Self {
+        self.metric = Some(value.into());
+        self
+    }
+
+    /// Sets the [`prediction_interval_level`](struct.GetCostForecastRequest.html#structfield.prediction_interval_level) member.
+    pub fn prediction_interval_level<T: Into<i64>>(mut self, value: T) -> Self {
+        self.prediction_interval_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_period`](struct.GetCostForecastRequest.html#structfield.time_period) member.
+    pub fn time_period<T: Into<DateInterval>>(mut self, value: T) -> Self {
+        self.time_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCostForecastRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetCostForecastRequest, ::rusoto_core::BuildError> {
+        Ok(GetCostForecastRequest {
+            filter: self.filter,
+            granularity: self
+                .granularity
+                .ok_or(::rusoto_core::BuildError::missing("granularity"))?,
+            metric: self
+                .metric
+                .ok_or(::rusoto_core::BuildError::missing("metric"))?,
+            prediction_interval_level: self.prediction_interval_level,
+            time_period: self
+                .time_period
+                .ok_or(::rusoto_core::BuildError::missing("time_period"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCostForecastResponse {
@@ -481,15 +966,15 @@ pub struct GetCostForecastResponse {
     pub total: Option<MetricValue>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetDimensionValuesRequest {
     /// <p><p>The context for the call to <code>GetDimensionValues</code>. This can be <code>RESERVATIONS</code> or <code>COST<em>AND</em>USAGE</code>. The default value is <code>COST<em>AND</em>USAGE</code>. If the context is set to <code>RESERVATIONS</code>, the resulting dimension values can be used in the <code>GetReservationUtilization</code> operation. If the context is set to <code>COST<em>AND</em>USAGE</code>, the resulting dimension values can be used in the <code>GetCostAndUsage</code> operation.</p> <p>If you set the context to <code>COST<em>AND</em>USAGE</code>, you can use the following dimensions for searching:</p> <ul> <li> <p>AZ - The Availability Zone. An example is <code>us-east-1a</code>.</p> </li> <li> <p>DATABASE<em>ENGINE - The Amazon Relational Database Service database. Examples are Aurora or MySQL.</p> </li> <li> <p>INSTANCE</em>TYPE - The type of Amazon EC2 instance. An example is <code>m4.xlarge</code>.</p> </li> <li> <p>LEGAL<em>ENTITY</em>NAME - The name of the organization that sells you AWS services, such as Amazon Web Services.</p> </li> <li> <p>LINKED<em>ACCOUNT - The description in the attribute map that includes the full name of the member account. The value field contains the AWS ID of the member account.</p> </li> <li> <p>OPERATING</em>SYSTEM - The operating system. Examples are Windows or Linux.</p> </li> <li> <p>OPERATION - The action performed. Examples include <code>RunInstance</code> and <code>CreateBucket</code>.</p> </li> <li> <p>PLATFORM - The Amazon EC2 operating system. Examples are Windows or Linux.</p> </li> <li> <p>PURCHASE<em>TYPE - The reservation type of the purchase to which this usage is related. Examples include On-Demand Instances and Standard Reserved Instances.</p> </li> <li> <p>SERVICE - The AWS service such as Amazon DynamoDB.</p> </li> <li> <p>USAGE</em>TYPE - The type of usage. An example is DataTransfer-In-Bytes. The response for the <code>GetDimensionValues</code> operation includes a unit attribute. Examples include GB and Hrs.</p> </li> <li> <p>USAGE<em>TYPE</em>GROUP - The grouping of common usage types. An example is Amazon EC2: CloudWatch – Alarms. The response for this operation includes a unit attribute.</p> </li> <li> <p>RECORD<em>TYPE - The different types of charges such as RI fees, usage costs, tax refunds, and credits.</p> </li> </ul> <p>If you set the context to <code>RESERVATIONS</code>, you can use the following dimensions for searching:</p> <ul> <li> <p>AZ - The Availability Zone. An example is <code>us-east-1a</code>.</p> </li> <li> <p>CACHE</em>ENGINE - The Amazon ElastiCache operating system. Examples are Windows or Linux.</p> </li> <li> <p>DEPLOYMENT<em>OPTION - The scope of Amazon Relational Database Service deployments. Valid values are <code>SingleAZ</code> and <code>MultiAZ</code>.</p> </li> <li> <p>INSTANCE</em>TYPE - The type of Amazon EC2 instance. An example is <code>m4.xlarge</code>.</p> </li> <li> <p>LINKED_ACCOUNT - The description in the attribute map that includes the full name of the member account. The value field contains the AWS ID of the member account.</p> </li> <li> <p>PLATFORM - The Amazon EC2 operating system. Examples are Windows or Linux.</p> </li> <li> <p>REGION - The AWS Region.</p> </li> <li> <p>SCOPE (Utilization only) - The scope of a Reserved Instance (RI). Values are regional or a single Availability Zone.</p> </li> <li> <p>TAG (Coverage only) - The tags that are associated with a Reserved Instance (RI).</p> </li> <li> <p>TENANCY - The tenancy of a resource. Examples are shared or dedicated.</p> </li> </ul></p>
     #[serde(rename = "Context")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub context: Option<String>,
+    pub context: Option<Context>,
     /// <p>The name of the dimension. Each <code>Dimension</code> is available for a different <code>Context</code>. For more information, see <code>Context</code>.</p>
     #[serde(rename = "Dimension")]
-    pub dimension: String,
+    pub dimension: Dimension,
     /// <p>The token to retrieve the next set of results. AWS provides the token when the response from a previous call has more results than the maximum page size.</p>
     #[serde(rename = "NextPageToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -503,6 +988,72 @@ pub struct GetDimensionValuesRequest {
     pub time_period: DateInterval,
 }
 
+impl GetDimensionValuesRequest {
+    /// Returns a builder for `GetDimensionValuesRequest`, checking its required members when built.
+    pub fn builder() -> GetDimensionValuesRequestBuilder {
+        GetDimensionValuesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDimensionValuesRequest`](struct.GetDimensionValuesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDimensionValuesRequestBuilder {
+    context: Option<Context>,
+    dimension: Option<Dimension>,
+    next_page_token: Option<String>,
+    search_string: Option<String>,
+    time_period: Option<DateInterval>,
+}
+
+impl GetDimensionValuesRequestBuilder {
+    /// Sets the [`context`](struct.GetDimensionValuesRequest.html#structfield.context) member.
+    pub fn context<T: Into<Context>>(mut self, value: T) -> Self {
+        self.context = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dimension`](struct.GetDimensionValuesRequest.html#structfield.dimension) member.
+    pub fn dimension<T: Into<Dimension>>(mut self, value: T) -> Self {
+        self.dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.GetDimensionValuesRequest.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`search_string`](struct.GetDimensionValuesRequest.html#structfield.search_string) member.
+    pub fn search_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.search_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_period`](struct.GetDimensionValuesRequest.html#structfield.time_period) member.
+    pub fn time_period<T: Into<DateInterval>>(mut self, value: T) -> Self {
+        self.time_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDimensionValuesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDimensionValuesRequest, ::rusoto_core::BuildError> {
+        Ok(GetDimensionValuesRequest {
+            context: self.context,
+            dimension: self
+                .dimension
+                .ok_or(::rusoto_core::BuildError::missing("dimension"))?,
+            next_page_token: self.next_page_token,
+            search_string: self.search_string,
+            time_period: self
+                .time_period
+                .ok_or(::rusoto_core::BuildError::missing("time_period"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDimensionValuesResponse {
@@ -531,7 +1082,7 @@ pub struct GetReservationCoverageRequest {
     /// <p>The granularity of the AWS cost data for the reservation. Valid values are <code>MONTHLY</code> and <code>DAILY</code>.</p> <p>If <code>GroupBy</code> is set, <code>Granularity</code> can't be set. If <code>Granularity</code> isn't set, the response object doesn't include <code>Granularity</code>, either <code>MONTHLY</code> or <code>DAILY</code>.</p> <p>The <code>GetReservationCoverage</code> operation supports only <code>DAILY</code> and <code>MONTHLY</code> granularities.</p>
     #[serde(rename = "Granularity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub granularity: Option<String>,
+    pub granularity: Option<Granularity>,
     /// <p><p>You can group the data by the following attributes:</p> <ul> <li> <p>AZ</p> </li> <li> <p>CACHE<em>ENGINE</p> </li> <li> <p>DATABASE</em>ENGINE</p> </li> <li> <p>DEPLOYMENT<em>OPTION</p> </li> <li> <p>INSTANCE</em>TYPE</p> </li> <li> <p>LINKED<em>ACCOUNT</p> </li> <li> <p>OPERATING</em>SYSTEM</p> </li> <li> <p>PLATFORM</p> </li> <li> <p>REGION</p> </li> <li> <p>TENANCY</p> </li> </ul></p>
     #[serde(rename = "GroupBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -549,6 +1100,78 @@ pub struct GetReservationCoverageRequest {
     pub time_period: DateInterval,
 }
 
+impl GetReservationCoverageRequest {
+    /// Returns a builder for `GetReservationCoverageRequest`, checking its required members when built.
+    pub fn builder() -> GetReservationCoverageRequestBuilder {
+        GetReservationCoverageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetReservationCoverageRequest`](struct.GetReservationCoverageRequest.html).
+#[derive(Default, Debug)]
+pub struct GetReservationCoverageRequestBuilder {
+    filter: Option<Expression>,
+    granularity: Option<Granularity>,
+    group_by: Option<Vec<GroupDefinition>>,
+    metrics: Option<Vec<String>>,
+    next_page_token: Option<String>,
+    time_period: Option<DateInterval>,
+}
+
+impl GetReservationCoverageRequestBuilder {
+    /// Sets the [`filter`](struct.GetReservationCoverageRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<Expression>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`granularity`](struct.GetReservationCoverageRequest.html#structfield.granularity) member.
+    pub fn granularity<T: Into<Granularity>>(mut self, value: T) -> Self {
+        self.granularity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_by`](struct.GetReservationCoverageRequest.html#structfield.group_by) member.
+    pub fn group_by<T: Into<Vec<GroupDefinition>>>(mut self, value: T) -> Self {
+        self.group_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metrics`](struct.GetReservationCoverageRequest.html#structfield.metrics) member.
+    pub fn metrics<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.metrics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.GetReservationCoverageRequest.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_period`](struct.GetReservationCoverageRequest.html#structfield.time_period) member.
+    pub fn time_period<T: Into<DateInterval>>(mut self, value: T) -> Self {
+        self.time_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetReservationCoverageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetReservationCoverageRequest, ::rusoto_core::BuildError> {
+        Ok(GetReservationCoverageRequest {
+            filter: self.filter,
+            granularity: self.granularity,
+            group_by: self.group_by,
+            metrics: self.metrics,
+            next_page_token: self.next_page_token,
+            time_period: self
+                .time_period
+                .ok_or(::rusoto_core::BuildError::missing("time_period"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetReservationCoverageResponse {
@@ -574,11 +1197,11 @@ pub struct GetReservationPurchaseRecommendationRequest {
     /// <p>The account scope that you want recommendations for. <code>PAYER</code> means that AWS includes the master account and any member accounts when it calculates its recommendations. <code>LINKED</code> means that AWS includes only member accounts when it calculates its recommendations.</p> <p>Valid values are <code>PAYER</code> and <code>LINKED</code>.</p>
     #[serde(rename = "AccountScope")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_scope: Option<String>,
+    pub account_scope: Option<AccountScope>,
     /// <p>The number of previous days that you want AWS to consider when it calculates your recommendations.</p>
     #[serde(rename = "LookbackPeriodInDays")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lookback_period_in_days: Option<String>,
+    pub lookback_period_in_days: Option<LookbackPeriodInDays>,
     /// <p>The pagination token that indicates the next set of results that you want to retrieve.</p>
     #[serde(rename = "NextPageToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -590,7 +1213,7 @@ pub struct GetReservationPurchaseRecommendationRequest {
     /// <p>The reservation purchase option that you want recommendations for.</p>
     #[serde(rename = "PaymentOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment_option: Option<String>,
+    pub payment_option: Option<PaymentOption>,
     /// <p>The specific service that you want recommendations for.</p>
     #[serde(rename = "Service")]
     pub service: String,
@@ -601,7 +1224,104 @@ pub struct GetReservationPurchaseRecommendationRequest {
     /// <p>The reservation term that you want recommendations for.</p>
     #[serde(rename = "TermInYears")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub term_in_years: Option<String>,
+    pub term_in_years: Option<TermInYears>,
+}
+
+impl GetReservationPurchaseRecommendationRequest {
+    /// Returns a builder for `GetReservationPurchaseRecommendationRequest`, checking its required members when built.
+    pub fn builder() -> GetReservationPurchaseRecommendationRequestBuilder {
+        GetReservationPurchaseRecommendationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetReservationPurchaseRecommendationRequest`](struct.GetReservationPurchaseRecommendationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetReservationPurchaseRecommendationRequestBuilder {
+    account_id: Option<String>,
+    account_scope: Option<AccountScope>,
+    lookback_period_in_days: Option<LookbackPeriodInDays>,
+    next_page_token: Option<String>,
+    page_size: Option<i64>,
+    payment_option: Option<PaymentOption>,
+    service: Option<String>,
+    service_specification: Option<ServiceSpecification>,
+    term_in_years: Option<TermInYears>,
+}
+
+impl GetReservationPurchaseRecommendationRequestBuilder {
+    /// Sets the [`account_id`](struct.GetReservationPurchaseRecommendationRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`account_scope`](struct.GetReservationPurchaseRecommendationRequest.html#structfield.account_scope) member.
+    pub fn account_scope<T: Into<AccountScope>>(mut self, value: T) -> Self {
+        self.account_scope = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lookback_period_in_days`](struct.GetReservationPurchaseRecommendationRequest.html#structfield.lookback_period_in_days) member.
+    pub fn lookback_period_in_days<T: Into<LookbackPeriodInDays>>(mut self, value: T) -> Self {
+        self.lookback_period_in_days = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.GetReservationPurchaseRecommendationRequest.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.GetReservationPurchaseRecommendationRequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`payment_option`](struct.GetReservationPurchaseRecommendationRequest.html#structfield.payment_option) member.
+    pub fn payment_option<T: Into<PaymentOption>>(mut self, value: T) -> Self {
+        self.payment_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service`](struct.GetReservationPurchaseRecommendationRequest.html#structfield.service) member.
+    pub fn service<T: Into<String>>(mut self, value: T) -> Self {
+        self.service = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_specification`](struct.GetReservationPurchaseRecommendationRequest.html#structfield.service_specification) member.
+    pub fn service_specification<T: Into<ServiceSpecification>>(mut self, value: T) -> Self {
+        self.service_specification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`term_in_years`](struct.GetReservationPurchaseRecommendationRequest.html#structfield.term_in_years) member.
+    pub fn term_in_years<T: Into<TermInYears>>(mut self, value: T) -> Self {
+        self.term_in_years = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetReservationPurchaseRecommendationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetReservationPurchaseRecommendationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetReservationPurchaseRecommendationRequest {
+            account_id: self.account_id,
+            account_scope: self.account_scope,
+            lookback_period_in_days: self.lookback_period_in_days,
+            next_page_token: self.next_page_token,
+            page_size: self.page_size,
+            payment_option: self.payment_option,
+            service: self
+                .service
+                .ok_or(::rusoto_core::BuildError::missing("service"))?,
+            service_specification: self.service_specification,
+            term_in_years: self.term_in_years,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -630,7 +1350,7 @@ pub struct GetReservationUtilizationRequest {
     /// <p>If <code>GroupBy</code> is set, <code>Granularity</code> can't be set. If <code>Granularity</code> isn't set, the response object doesn't include <code>Granularity</code>, either <code>MONTHLY</code> or <code>DAILY</code>. If both <code>GroupBy</code> and <code>Granularity</code> aren't set, <code>GetReservationUtilization</code> defaults to <code>DAILY</code>.</p> <p>The <code>GetReservationUtilization</code> operation supports only <code>DAILY</code> and <code>MONTHLY</code> granularities.</p>
     #[serde(rename = "Granularity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub granularity: Option<String>,
+    pub granularity: Option<Granularity>,
     /// <p>Groups only by <code>SUBSCRIPTION_ID</code>. Metadata is included.</p>
     #[serde(rename = "GroupBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -644,6 +1364,70 @@ pub struct GetReservationUtilizationRequest {
     pub time_period: DateInterval,
 }
 
+impl GetReservationUtilizationRequest {
+    /// Returns a builder for `GetReservationUtilizationRequest`, checking its required members when built.
+    pub fn builder() -> GetReservationUtilizationRequestBuilder {
+        GetReservationUtilizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetReservationUtilizationRequest`](struct.GetReservationUtilizationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetReservationUtilizationRequestBuilder {
+    filter: Option<Expression>,
+    granularity: Option<Granularity>,
+    group_by: Option<Vec<GroupDefinition>>,
+    next_page_token: Option<String>,
+    time_period: Option<DateInterval>,
+}
+
+impl GetReservationUtilizationRequestBuilder {
+    /// Sets the [`filter`](struct.GetReservationUtilizationRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<Expression>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`granularity`](struct.GetReservationUtilizationRequest.html#structfield.granularity) member.
+    pub fn granularity<T: Into<Granularity>>(mut self, value: T) -> Self {
+        self.granularity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_by`](struct.GetReservationUtilizationRequest.html#structfield.group_by) member.
+    pub fn group_by<T: Into<Vec<GroupDefinition>>>(mut self, value: T) -> Self {
+        self.group_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.GetReservationUtilizationRequest.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_period`](struct.GetReservationUtilizationRequest.html#structfield.time_period) member.
+    pub fn time_period<T: Into<DateInterval>>(mut self, value: T) -> Self {
+        self.time_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetReservationUtilizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetReservationUtilizationRequest, ::rusoto_core::BuildError> {
+        Ok(GetReservationUtilizationRequest {
+            filter: self.filter,
+            granularity: self.granularity,
+            group_by: self.group_by,
+            next_page_token: self.next_page_token,
+            time_period: self
+                .time_period
+                .ok_or(::rusoto_core::BuildError::missing("time_period"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetReservationUtilizationResponse {
@@ -678,6 +1462,62 @@ pub struct GetRightsizingRecommendationRequest {
     pub service: String,
 }
 
+impl GetRightsizingRecommendationRequest {
+    /// Returns a builder for `GetRightsizingRecommendationRequest`, checking its required members when built.
+    pub fn builder() -> GetRightsizingRecommendationRequestBuilder {
+        GetRightsizingRecommendationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRightsizingRecommendationRequest`](struct.GetRightsizingRecommendationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRightsizingRecommendationRequestBuilder {
+    filter: Option<Expression>,
+    next_page_token: Option<String>,
+    page_size: Option<i64>,
+    service: Option<String>,
+}
+
+impl GetRightsizingRecommendationRequestBuilder {
+    /// Sets the [`filter`](struct.GetRightsizingRecommendationRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<Expression>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_page_token`](struct.GetRightsizingRecommendationRequest.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.GetRightsizingRecommendationRequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service`](struct.GetRightsizingRecommendationRequest.html#structfield.service) member.
+    pub fn service<T: Into<String>>(mut self, value: T) -> Self {
+        self.service = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRightsizingRecommendationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRightsizingRecommendationRequest, ::rusoto_core::BuildError> {
+        Ok(GetRightsizingRecommendationRequest {
+            filter: self.filter,
+            next_page_token: self.next_page_token,
+            page_size: self.page_size,
+            service: self
+                .service
+                .ok_or(::rusoto_core::BuildError::missing("service"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRightsizingRecommendationResponse {
@@ -718,6 +1558,60 @@ pub struct GetTagsRequest {
     pub time_period: DateInterval,
 }
 
+impl GetTagsRequest {
+    /// Returns a builder for `GetTagsRequest`, checking its required members when built.
+    pub fn builder() -> GetTagsRequestBuilder {
+        GetTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTagsRequest`](struct.GetTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTagsRequestBuilder {
+    next_page_token: Option<String>,
+    search_string: Option<String>,
+    tag_key: Option<String>,
+    time_period: Option<DateInterval>,
+}
+
+impl GetTagsRequestBuilder {
+    /// Sets the [`next_page_token`](struct.GetTagsRequest.html#structfield.next_page_token) member.
+    pub fn next_page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`search_string`](struct.GetTagsRequest.html#structfield.search_string) member.
+    pub fn search_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.search_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_key`](struct.GetTagsRequest.html#structfield.tag_key) member.
+    pub fn tag_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.tag_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_period`](struct.GetTagsRequest.html#structfield.time_period) member.
+    pub fn time_period<T: Into<DateInterval>>(mut self, value: T) -> Self {
+        self.time_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTagsRequest, ::rusoto_core::BuildError> {
+        Ok(GetTagsRequest {
+            next_page_token: self.next_page_token,
+            search_string: self.search_string,
+            tag_key: self.tag_key,
+            time_period: self
+                .time_period
+                .ok_or(::rusoto_core::BuildError::missing("time_period"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTagsResponse {
@@ -736,7 +1630,7 @@ pub struct GetTagsResponse {
     pub total_size: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetUsageForecastRequest {
     /// <p>The filters that you want to use to filter your forecast. Cost Explorer API supports all of the Cost Explorer filters.</p>
     #[serde(rename = "Filter")]
@@ -744,10 +1638,10 @@ pub struct GetUsageForecastRequest {
     pub filter: Option<Expression>,
     /// <p>How granular you want the forecast to be. You can get 3 months of <code>DAILY</code> forecasts or 12 months of <code>MONTHLY</code> forecasts.</p> <p>The <code>GetUsageForecast</code> operation supports only <code>DAILY</code> and <code>MONTHLY</code> granularities.</p>
     #[serde(rename = "Granularity")]
-    pub granularity: String,
+    pub granularity: Granularity,
     /// <p><p>Which metric Cost Explorer uses to create your forecast.</p> <p>Valid values for a <code>GetUsageForecast</code> call are the following:</p> <ul> <li> <p>USAGE<em>QUANTITY</p> </li> <li> <p>NORMALIZED</em>USAGE_AMOUNT</p> </li> </ul></p>
     #[serde(rename = "Metric")]
-    pub metric: String,
+    pub metric: Metric,
     /// <p>Cost Explorer always returns the mean forecast as a single point. You can request a prediction interval around the mean by specifying a confidence level. The higher the confidence level, the more confident Cost Explorer is about the actual value falling in the prediction interval. Higher confidence levels result in wider prediction intervals.</p>
     #[serde(rename = "PredictionIntervalLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -757,6 +1651,74 @@ pub struct GetUsageForecastRequest {
     pub time_period: DateInterval,
 }
 
+impl GetUsageForecastRequest {
+    /// Returns a builder for `GetUsageForecastRequest`, checking its required members when built.
+    pub fn builder() -> GetUsageForecastRequestBuilder {
+        GetUsageForecastRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUsageForecastRequest`](struct.GetUsageForecastRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUsageForecastRequestBuilder {
+    filter: Option<Expression>,
+    granularity: Option<Granularity>,
+    metric: Option<Metric>,
+    prediction_interval_level: Option<i64>,
+    time_period: Option<DateInterval>,
+}
+
+impl GetUsageForecastRequestBuilder {
+    /// Sets the [`filter`](struct.GetUsageForecastRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<Expression>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`granularity`](struct.GetUsageForecastRequest.html#structfield.granularity) member.
+    pub fn granularity<T: Into<Granularity>>(mut self, value: T) -> Self {
+        self.granularity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric`](struct.GetUsageForecastRequest.html#structfield.metric) member.
+    pub fn metric<T: Into<Metric>>(mut self, value: T) -> Self {
+        self.metric = Some(value.into());
+        self
+    }
+
+    /// Sets the [`prediction_interval_level`](struct.GetUsageForecastRequest.html#structfield.prediction_interval_level) member.
+    pub fn prediction_interval_level<T: Into<i64>>(mut self, value: T) -> Self {
+        self.prediction_interval_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_period`](struct.GetUsageForecastRequest.html#structfield.time_period) member.
+    pub fn time_period<T: Into<DateInterval>>(mut self, value: T) -> Self {
+        self.time_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUsageForecastRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetUsageForecastRequest, ::rusoto_core::BuildError> {
+        Ok(GetUsageForecastRequest {
+            filter: self.filter,
+            granularity: self
+                .granularity
+                .ok_or(::rusoto_core::BuildError::missing("granularity"))?,
+            metric: self
+                .metric
+                .ok_or(::rusoto_core::BuildError::missing("metric"))?,
+            prediction_interval_level: self.prediction_interval_level,
+            time_period: self
+                .time_period
+                .ok_or(::rusoto_core::BuildError::missing("time_period"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetUsageForecastResponse {
@@ -770,57 +1732,377 @@ pub struct GetUsageForecastResponse {
     pub total: Option<MetricValue>,
 }
 
-/// <p>One level of grouped data in the results.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Group {
-    /// <p>The keys that are included in this group.</p>
-    #[serde(rename = "Keys")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub keys: Option<Vec<String>>,
-    /// <p>The metrics that are included in this group.</p>
-    #[serde(rename = "Metrics")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metrics: Option<::std::collections::HashMap<String, MetricValue>>,
+/// <p>Sets the AWS cost granularity to <code>MONTHLY</code> or <code>DAILY</code>. If <code>Granularity</code> isn't set, the response object doesn't include the <code>Granularity</code>, either <code>MONTHLY</code> or <code>DAILY</code>. </p> <p>The <code>GetCostAndUsageRequest</code> operation supports only <code>DAILY</code> and <code>MONTHLY</code> granularities.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Granularity {
+    /// <p><code>DAILY</code></p>
+    Daily,
+    /// <p><code>MONTHLY</code></p>
+    Monthly,
+    /// <p><code>HOURLY</code></p>
+    Hourly,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Granularity {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Granularity::Daily => "DAILY",
+            Granularity::Monthly => "MONTHLY",
+            Granularity::Hourly => "HOURLY",
+            Granularity::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Granularity {
+    fn from(value: &'a str) -> Granularity {
+        match value {
+            "DAILY" => Granularity::Daily,
+            "MONTHLY" => Granularity::Monthly,
+            "HOURLY" => Granularity::Hourly,
+            _ => Granularity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Granularity {
+    fn from(value: String) -> Granularity {
+        Granularity::from(value.as_str())
+    }
+}
+
+impl From<Granularity> for String {
+    fn from(value: Granularity) -> String {
+        match value {
+            Granularity::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Granularity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Granularity {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Granularity {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Granularity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Granularity::from(value))
+    }
+}
+
+/// <p>One level of grouped data in the results.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Group {
+    /// <p>The keys that are included in this group.</p>
+    #[serde(rename = "Keys")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keys: Option<Vec<String>>,
+    /// <p>The metrics that are included in this group.</p>
+    #[serde(rename = "Metrics")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<::std::collections::HashMap<String, MetricValue>>,
+}
+
+/// <p>Represents a group when you specify a group by criteria or in the response to a query with a specific grouping.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupDefinition {
+    /// <p>The string that represents a key for a specified group.</p>
+    #[serde(rename = "Key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// <p>The string that represents the type of group.</p>
+    #[serde(rename = "Type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<GroupDefinitionType>,
+}
+
+/// <p>The string that represents the type of group.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GroupDefinitionType {
+    /// <p><code>DIMENSION</code></p>
+    Dimension,
+    /// <p><code>TAG</code></p>
+    Tag,
+    /// <p><code>COST_CATEGORY</code></p>
+    CostCategory,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl GroupDefinitionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GroupDefinitionType::Dimension => "DIMENSION",
+            GroupDefinitionType::Tag => "TAG",
+            GroupDefinitionType::CostCategory => "COST_CATEGORY",
+            GroupDefinitionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GroupDefinitionType {
+    fn from(value: &'a str) -> GroupDefinitionType {
+        match value {
+            "DIMENSION" => GroupDefinitionType::Dimension,
+            "TAG" => GroupDefinitionType::Tag,
+            "COST_CATEGORY" => GroupDefinitionType::CostCategory,
+            _ => GroupDefinitionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GroupDefinitionType {
+    fn from(value: String) -> GroupDefinitionType {
+        GroupDefinitionType::from(value.as_str())
+    }
+}
+
+impl From<GroupDefinitionType> for String {
+    fn from(value: GroupDefinitionType) -> String {
+        match value {
+            GroupDefinitionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GroupDefinitionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for GroupDefinitionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GroupDefinitionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<GroupDefinitionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(GroupDefinitionType::from(value))
+    }
+}
+
+/// <p>Details about the instances that AWS recommends that you purchase.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct InstanceDetails {
+    /// <p>The Amazon EC2 instances that AWS recommends that you purchase.</p>
+    #[serde(rename = "EC2InstanceDetails")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ec2_instance_details: Option<EC2InstanceDetails>,
+    /// <p>The Amazon ES instances that AWS recommends that you purchase.</p>
+    #[serde(rename = "ESInstanceDetails")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub es_instance_details: Option<ESInstanceDetails>,
+    /// <p>The ElastiCache instances that AWS recommends that you purchase.</p>
+    #[serde(rename = "ElastiCacheInstanceDetails")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elasti_cache_instance_details: Option<ElastiCacheInstanceDetails>,
+    /// <p>The Amazon RDS instances that AWS recommends that you purchase.</p>
+    #[serde(rename = "RDSInstanceDetails")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rds_instance_details: Option<RDSInstanceDetails>,
+    /// <p>The Amazon Redshift instances that AWS recommends that you purchase.</p>
+    #[serde(rename = "RedshiftInstanceDetails")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redshift_instance_details: Option<RedshiftInstanceDetails>,
+}
+
+/// <p>The number of previous days that you want AWS to consider when it calculates your recommendations.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LookbackPeriodInDays {
+    /// <p><code>SEVEN_DAYS</code></p>
+    SevenDays,
+    /// <p><code>THIRTY_DAYS</code></p>
+    ThirtyDays,
+    /// <p><code>SIXTY_DAYS</code></p>
+    SixtyDays,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LookbackPeriodInDays {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LookbackPeriodInDays::SevenDays => "SEVEN_DAYS",
+            LookbackPeriodInDays::ThirtyDays => "THIRTY_DAYS",
+            LookbackPeriodInDays::SixtyDays => "SIXTY_DAYS",
+            LookbackPeriodInDays::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LookbackPeriodInDays {
+    fn from(value: &'a str) -> LookbackPeriodInDays {
+        match value {
+            "SEVEN_DAYS" => LookbackPeriodInDays::SevenDays,
+            "THIRTY_DAYS" => LookbackPeriodInDays::ThirtyDays,
+            "SIXTY_DAYS" => LookbackPeriodInDays::SixtyDays,
+            _ => LookbackPeriodInDays::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LookbackPeriodInDays {
+    fn from(value: String) -> LookbackPeriodInDays {
+        LookbackPeriodInDays::from(value.as_str())
+    }
+}
+
+impl From<LookbackPeriodInDays> for String {
+    fn from(value: LookbackPeriodInDays) -> String {
+        match value {
+            LookbackPeriodInDays::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LookbackPeriodInDays {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LookbackPeriodInDays {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LookbackPeriodInDays {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LookbackPeriodInDays, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LookbackPeriodInDays::from(value))
+    }
+}
+
+/// <p><p>Which metric Cost Explorer uses to create your forecast. For more information about blended and unblended rates, see <a href="https://aws.amazon.com/premiumsupport/knowledge-center/blended-rates-intro/">Why does the &quot;blended&quot; annotation appear on some line items in my bill?</a>. </p> <p>Valid values for a <code>GetCostForecast</code> call are the following:</p> <ul> <li> <p>AMORTIZED<em>COST</p> </li> <li> <p>BLENDED</em>COST</p> </li> <li> <p>NET<em>AMORTIZED</em>COST</p> </li> <li> <p>NET<em>UNBLENDED</em>COST</p> </li> <li> <p>UNBLENDED_COST</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Metric {
+    /// <p><code>BLENDED_COST</code></p>
+    BlendedCost,
+    /// <p><code>UNBLENDED_COST</code></p>
+    UnblendedCost,
+    /// <p><code>AMORTIZED_COST</code></p>
+    AmortizedCost,
+    /// <p><code>NET_UNBLENDED_COST</code></p>
+    NetUnblendedCost,
+    /// <p><code>NET_AMORTIZED_COST</code></p>
+    NetAmortizedCost,
+    /// <p><code>USAGE_QUANTITY</code></p>
+    UsageQuantity,
+    /// <p><code>NORMALIZED_USAGE_AMOUNT</code></p>
+    NormalizedUsageAmount,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Metric {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Metric::BlendedCost => "BLENDED_COST",
+            Metric::UnblendedCost => "UNBLENDED_COST",
+            Metric::AmortizedCost => "AMORTIZED_COST",
+            Metric::NetUnblendedCost => "NET_UNBLENDED_COST",
+            Metric::NetAmortizedCost => "NET_AMORTIZED_COST",
+            Metric::UsageQuantity => "USAGE_QUANTITY",
+            Metric::NormalizedUsageAmount => "NORMALIZED_USAGE_AMOUNT",
+            Metric::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Metric {
+    fn from(value: &'a str) -> Metric {
+        match value {
+            "BLENDED_COST" => Metric::BlendedCost,
+            "UNBLENDED_COST" => Metric::UnblendedCost,
+            "AMORTIZED_COST" => Metric::AmortizedCost,
+            "NET_UNBLENDED_COST" => Metric::NetUnblendedCost,
+            "NET_AMORTIZED_COST" => Metric::NetAmortizedCost,
+            "USAGE_QUANTITY" => Metric::UsageQuantity,
+            "NORMALIZED_USAGE_AMOUNT" => Metric::NormalizedUsageAmount,
+            _ => Metric::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Metric {
+    fn from(value: String) -> Metric {
+        Metric::from(value.as_str())
+    }
+}
+
+impl From<Metric> for String {
+    fn from(value: Metric) -> String {
+        match value {
+            Metric::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-/// <p>Represents a group when you specify a group by criteria or in the response to a query with a specific grouping.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct GroupDefinition {
-    /// <p>The string that represents a key for a specified group.</p>
-    #[serde(rename = "Key")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
-    /// <p>The string that represents the type of group.</p>
-    #[serde(rename = "Type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
-/// <p>Details about the instances that AWS recommends that you purchase.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct InstanceDetails {
-    /// <p>The Amazon EC2 instances that AWS recommends that you purchase.</p>
-    #[serde(rename = "EC2InstanceDetails")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ec2_instance_details: Option<EC2InstanceDetails>,
-    /// <p>The Amazon ES instances that AWS recommends that you purchase.</p>
-    #[serde(rename = "ESInstanceDetails")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub es_instance_details: Option<ESInstanceDetails>,
-    /// <p>The ElastiCache instances that AWS recommends that you purchase.</p>
-    #[serde(rename = "ElastiCacheInstanceDetails")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub elasti_cache_instance_details: Option<ElastiCacheInstanceDetails>,
-    /// <p>The Amazon RDS instances that AWS recommends that you purchase.</p>
-    #[serde(rename = "RDSInstanceDetails")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub rds_instance_details: Option<RDSInstanceDetails>,
-    /// <p>The Amazon Redshift instances that AWS recommends that you purchase.</p>
-    #[serde(rename = "RedshiftInstanceDetails")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub redshift_instance_details: Option<RedshiftInstanceDetails>,
+impl ::serde::Serialize for Metric {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Metric {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Metric, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Metric::from(value))
+    }
 }
 
 /// <p>The aggregated value for a metric.</p>
@@ -847,6 +2129,166 @@ pub struct ModifyRecommendationDetail {
     pub target_instances: Option<Vec<TargetInstance>>,
 }
 
+/// <p>Whether you want a recommendation for standard or convertible reservations.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OfferingClass {
+    /// <p><code>STANDARD</code></p>
+    Standard,
+    /// <p><code>CONVERTIBLE</code></p>
+    Convertible,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OfferingClass {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OfferingClass::Standard => "STANDARD",
+            OfferingClass::Convertible => "CONVERTIBLE",
+            OfferingClass::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OfferingClass {
+    fn from(value: &'a str) -> OfferingClass {
+        match value {
+            "STANDARD" => OfferingClass::Standard,
+            "CONVERTIBLE" => OfferingClass::Convertible,
+            _ => OfferingClass::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OfferingClass {
+    fn from(value: String) -> OfferingClass {
+        OfferingClass::from(value.as_str())
+    }
+}
+
+impl From<OfferingClass> for String {
+    fn from(value: OfferingClass) -> String {
+        match value {
+            OfferingClass::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OfferingClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OfferingClass {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OfferingClass {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OfferingClass, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OfferingClass::from(value))
+    }
+}
+
+/// <p>The reservation purchase option that you want recommendations for.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PaymentOption {
+    /// <p><code>NO_UPFRONT</code></p>
+    NoUpfront,
+    /// <p><code>PARTIAL_UPFRONT</code></p>
+    PartialUpfront,
+    /// <p><code>ALL_UPFRONT</code></p>
+    AllUpfront,
+    /// <p><code>LIGHT_UTILIZATION</code></p>
+    LightUtilization,
+    /// <p><code>MEDIUM_UTILIZATION</code></p>
+    MediumUtilization,
+    /// <p><code>HEAVY_UTILIZATION</code></p>
+    HeavyUtilization,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PaymentOption {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PaymentOption::NoUpfront => "NO_UPFRONT",
+            PaymentOption::PartialUpfront => "PARTIAL_UPFRONT",
+            PaymentOption::AllUpfront => "ALL_UPFRONT",
+            PaymentOption::LightUtilization => "LIGHT_UTILIZATION",
+            PaymentOption::MediumUtilization => "MEDIUM_UTILIZATION",
+            PaymentOption::HeavyUtilization => "HEAVY_UTILIZATION",
+            PaymentOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PaymentOption {
+    fn from(value: &'a str) -> PaymentOption {
+        match value {
+            "NO_UPFRONT" => PaymentOption::NoUpfront,
+            "PARTIAL_UPFRONT" => PaymentOption::PartialUpfront,
+            "ALL_UPFRONT" => PaymentOption::AllUpfront,
+            "LIGHT_UTILIZATION" => PaymentOption::LightUtilization,
+            "MEDIUM_UTILIZATION" => PaymentOption::MediumUtilization,
+            "HEAVY_UTILIZATION" => PaymentOption::HeavyUtilization,
+            _ => PaymentOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PaymentOption {
+    fn from(value: String) -> PaymentOption {
+        PaymentOption::from(value.as_str())
+    }
+}
+
+impl From<PaymentOption> for String {
+    fn from(value: PaymentOption) -> String {
+        match value {
+            PaymentOption::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PaymentOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PaymentOption {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PaymentOption {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PaymentOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PaymentOption::from(value))
+    }
+}
+
 /// <p>Details about the Amazon RDS instances that AWS recommends that you purchase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -998,15 +2440,15 @@ pub struct ReservationPurchaseRecommendation {
     /// <p>The account scope that AWS recommends that you purchase this instance for. For example, you can purchase this reservation for an entire organization in AWS Organizations.</p>
     #[serde(rename = "AccountScope")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_scope: Option<String>,
+    pub account_scope: Option<AccountScope>,
     /// <p>How many days of previous usage that AWS considers when making this recommendation.</p>
     #[serde(rename = "LookbackPeriodInDays")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lookback_period_in_days: Option<String>,
+    pub lookback_period_in_days: Option<LookbackPeriodInDays>,
     /// <p>The payment option for the reservation. For example, <code>AllUpfront</code> or <code>NoUpfront</code>.</p>
     #[serde(rename = "PaymentOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub payment_option: Option<String>,
+    pub payment_option: Option<PaymentOption>,
     /// <p>Details about the recommended purchases.</p>
     #[serde(rename = "RecommendationDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1022,7 +2464,7 @@ pub struct ReservationPurchaseRecommendation {
     /// <p>The term of the reservation that you want recommendations for, in years.</p>
     #[serde(rename = "TermInYears")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub term_in_years: Option<String>,
+    pub term_in_years: Option<TermInYears>,
 }
 
 /// <p>Details about your recommended reservation purchase.</p>
@@ -1222,7 +2664,7 @@ pub struct RightsizingRecommendation {
     /// <p>Recommendation to either terminate or modify the resource.</p>
     #[serde(rename = "RightsizingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rightsizing_type: Option<String>,
+    pub rightsizing_type: Option<RightsizingType>,
     /// <p>Details for termination recommendations.</p>
     #[serde(rename = "TerminateRecommendationDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1240,7 +2682,7 @@ pub struct RightsizingRecommendationMetadata {
     /// <p> How many days of previous usage that Amazon Web Services considers when making this recommendation.</p>
     #[serde(rename = "LookbackPeriodInDays")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lookback_period_in_days: Option<String>,
+    pub lookback_period_in_days: Option<LookbackPeriodInDays>,
     /// <p> The ID for this specific recommendation.</p>
     #[serde(rename = "RecommendationId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1269,6 +2711,78 @@ pub struct RightsizingRecommendationSummary {
     pub total_recommendation_count: Option<String>,
 }
 
+/// <p>Recommendation to either terminate or modify the resource.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RightsizingType {
+    /// <p><code>TERMINATE</code></p>
+    Terminate,
+    /// <p><code>MODIFY</code></p>
+    Modify,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RightsizingType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RightsizingType::Terminate => "TERMINATE",
+            RightsizingType::Modify => "MODIFY",
+            RightsizingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RightsizingType {
+    fn from(value: &'a str) -> RightsizingType {
+        match value {
+            "TERMINATE" => RightsizingType::Terminate,
+            "MODIFY" => RightsizingType::Modify,
+            _ => RightsizingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RightsizingType {
+    fn from(value: String) -> RightsizingType {
+        RightsizingType::from(value.as_str())
+    }
+}
+
+impl From<RightsizingType> for String {
+    fn from(value: RightsizingType) -> String {
+        match value {
+            RightsizingType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RightsizingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RightsizingType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RightsizingType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RightsizingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RightsizingType::from(value))
+    }
+}
+
 /// <p>Hardware specifications for the service that you want recommendations for.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ServiceSpecification {
@@ -1321,6 +2835,78 @@ pub struct TargetInstance {
     pub resource_details: Option<ResourceDetails>,
 }
 
+/// <p>The reservation term that you want recommendations for.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TermInYears {
+    /// <p><code>ONE_YEAR</code></p>
+    OneYear,
+    /// <p><code>THREE_YEARS</code></p>
+    ThreeYears,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TermInYears {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TermInYears::OneYear => "ONE_YEAR",
+            TermInYears::ThreeYears => "THREE_YEARS",
+            TermInYears::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TermInYears {
+    fn from(value: &'a str) -> TermInYears {
+        match value {
+            "ONE_YEAR" => TermInYears::OneYear,
+            "THREE_YEARS" => TermInYears::ThreeYears,
+            _ => TermInYears::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TermInYears {
+    fn from(value: String) -> TermInYears {
+        TermInYears::from(value.as_str())
+    }
+}
+
+impl From<TermInYears> for String {
+    fn from(value: TermInYears) -> String {
+        match value {
+            TermInYears::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TermInYears {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TermInYears {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TermInYears {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TermInYears, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TermInYears::from(value))
+    }
+}
+
 /// <p> Details on termination recommendation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1866,6 +3452,69 @@ pub trait CostExplorer {
         input: GetUsageForecastRequest,
     ) -> RusotoFuture<GetUsageForecastResponse, GetUsageForecastError>;
 }
+impl<T: ?Sized + CostExplorer> CostExplorer for ::std::sync::Arc<T> {
+    fn get_cost_and_usage(
+        &self,
+        input: GetCostAndUsageRequest,
+    ) -> RusotoFuture<GetCostAndUsageResponse, GetCostAndUsageError> {
+        (**self).get_cost_and_usage(input)
+    }
+    fn get_cost_forecast(
+        &self,
+        input: GetCostForecastRequest,
+    ) -> RusotoFuture<GetCostForecastResponse, GetCostForecastError> {
+        (**self).get_cost_forecast(input)
+    }
+    fn get_dimension_values(
+        &self,
+        input: GetDimensionValuesRequest,
+    ) -> RusotoFuture<GetDimensionValuesResponse, GetDimensionValuesError> {
+        (**self).get_dimension_values(input)
+    }
+    fn get_reservation_coverage(
+        &self,
+        input: GetReservationCoverageRequest,
+    ) -> RusotoFuture<GetReservationCoverageResponse, GetReservationCoverageError> {
+        (**self).get_reservation_coverage(input)
+    }
+    fn get_reservation_purchase_recommendation(
+        &self,
+        input: GetReservationPurchaseRecommendationRequest,
+    ) -> RusotoFuture<
+        GetReservationPurchaseRecommendationResponse,
+        GetReservationPurchaseRecommendationError,
+    > {
+        (**self).get_reservation_purchase_recommendation(input)
+    }
+    fn get_reservation_utilization(
+        &self,
+        input: GetReservationUtilizationRequest,
+    ) -> RusotoFuture<GetReservationUtilizationResponse, GetReservationUtilizationError> {
+        (**self).get_reservation_utilization(input)
+    }
+    fn get_rightsizing_recommendation(
+        &self,
+        input: GetRightsizingRecommendationRequest,
+    ) -> RusotoFuture<GetRightsizingRecommendationResponse, GetRightsizingRecommendationError> {
+        (**self).get_rightsizing_recommendation(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn get_usage_forecast(
+        &self,
+        input: GetUsageForecastRequest,
+    ) -> RusotoFuture<GetUsageForecastResponse, GetUsageForecastError> {
+        (**self).get_usage_forecast(input)
+    }
+}
+/// The error codes AWS Cost Explorer retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Cost Explorer API.
 #[derive(Clone)]
 pub struct CostExplorerClient {
@@ -1881,6 +3530,24 @@ impl CostExplorerClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CostExplorerClient {
+        Self::new(region::Region::from_env_for_service("CE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `ce`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CostExplorerClient {
+        Self::new_with_client(config.client().clone(), config.region_for("ce", "CE"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1899,7 +3566,49 @@ impl CostExplorerClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CostExplorerClient {
-        CostExplorerClient { client, region }
+        CostExplorerClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "ce")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "ce", "ce", "CE")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CostExplorer` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CostExplorer + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CostExplorerClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Cost Explorer"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "ce"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CostExplorerClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Chime clients.
+//!
+//! Makes a single read-only `ListAccounts` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_chime::{Chime, ChimeClient, ListAccountsRequest};
+
+fn main() {
+    let client = ChimeClient::from_env();
+    match client.list_accounts(ListAccountsRequest::default()).sync() {
+        Ok(_) => println!("ListAccounts succeeded"),
+        Err(error) => {
+            eprintln!("ListAccounts failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! Outgoing events sent by Amazon Chime to chat bots.
+//!
+//! A bot with an `OutboundEventsHTTPSEndpoint` receives a JSON event whenever it is invited to,
+//! mentioned in or removed from a chat room. Each request carries a `Chime-Signature` header,
+//! an HMAC-SHA256 of the `Chime-Request-Timestamp` header followed by the request body, keyed
+//! with the bot's security token. `parse_event` checks that signature before deserializing the
+//! event, so forged callbacks are rejected.
+
+use std::error::Error;
+use std::fmt;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// The header carrying the signature of an outgoing event.
+pub const SIGNATURE_HEADER: &str = "Chime-Signature";
+
+/// The header carrying the timestamp the signature was computed with.
+pub const TIMESTAMP_HEADER: &str = "Chime-Request-Timestamp";
+
+/// What triggered an outgoing event.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum BotEventType {
+    /// The bot was invited to a chat room.
+    Invite,
+    /// The bot was mentioned in a message.
+    Mention,
+    /// The bot was removed from a chat room.
+    Remove,
+}
+
+/// The user whose action triggered an event.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EventSender {
+    /// The sender's identifier, e.g. an email address
+    #[serde(rename = "SenderId")]
+    pub sender_id: String,
+    /// The kind of identifier, e.g. `EmailId`
+    #[serde(rename = "SenderIdType")]
+    pub sender_id_type: String,
+}
+
+/// The conversation an event happened in.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EventDiscussion {
+    /// The room or conversation ID
+    #[serde(rename = "DiscussionId")]
+    pub discussion_id: String,
+    /// The kind of discussion, `Room` or `Conversation`
+    #[serde(rename = "DiscussionType")]
+    pub discussion_type: String,
+}
+
+/// An endpoint the bot can post replies to.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InboundHttpsEndpoint {
+    /// The endpoint type, e.g. `Persist`
+    #[serde(rename = "EndpointType")]
+    pub endpoint_type: String,
+    /// The webhook URL accepting replies
+    #[serde(rename = "Url")]
+    pub url: String,
+}
+
+/// An event sent to a bot's outgoing events endpoint.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BotEvent {
+    /// What triggered the event
+    #[serde(rename = "EventType")]
+    pub event_type: BotEventType,
+    /// When the event happened, in ISO 8601 format
+    #[serde(rename = "EventTimestamp")]
+    pub event_timestamp: String,
+    /// Who triggered the event
+    #[serde(rename = "Sender")]
+    pub sender: EventSender,
+    /// Where the event happened
+    #[serde(rename = "Discussion")]
+    pub discussion: EventDiscussion,
+    /// Where replies to the event can be posted
+    #[serde(rename = "InboundHttpsEndpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub inbound_https_endpoint: Option<InboundHttpsEndpoint>,
+    /// The message mentioning the bot, for `Mention` events
+    #[serde(rename = "Message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Errors returned when verifying or parsing an outgoing event.
+#[derive(Debug, PartialEq)]
+pub enum EventError {
+    /// The signature isn't valid base64.
+    MalformedSignature(String),
+    /// The signature doesn't match the request, which may have been forged or tampered with.
+    InvalidSignature(String),
+    /// The body isn't a valid event.
+    InvalidEvent(String),
+}
+
+impl fmt::Display for EventError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for EventError {
+    fn description(&self) -> &str {
+        match *self {
+            EventError::MalformedSignature(ref cause) => cause,
+            EventError::InvalidSignature(ref cause) => cause,
+            EventError::InvalidEvent(ref cause) => cause,
+        }
+    }
+}
+
+fn mac(security_token: &str, timestamp: &str, body: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_varkey(security_token.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.input(timestamp.as_bytes());
+    mac.input(body);
+    mac
+}
+
+/// Computes the `Chime-Signature` of an event body sent at `timestamp`.
+///
+/// This is mainly useful to test bots locally, by signing requests the same way Amazon Chime
+/// does.
+pub fn sign(security_token: &str, timestamp: &str, body: &[u8]) -> String {
+    base64::encode(&mac(security_token, timestamp, body).result().code())
+}
+
+/// Checks that `signature` was computed by Amazon Chime for `body` sent at `timestamp`.
+///
+/// The comparison is done in constant time.
+pub fn verify(
+    security_token: &str,
+    timestamp: &str,
+    body: &[u8],
+    signature: &str,
+) -> Result<(), EventError> {
+    let signature = base64::decode(signature.trim())
+        .map_err(|e| EventError::MalformedSignature(e.to_string()))?;
+    mac(security_token, timestamp, body)
+        .verify(&signature)
+        .map_err(|_| EventError::InvalidSignature("Event signature does not match".to_owned()))
+}
+
+/// Verifies the signature of an outgoing event, then deserializes it.
+///
+/// # Examples
+///
+/// ```
+/// use rusoto_chime::events::{parse_event, sign, BotEventType};
+///
+/// let body = br#"{
+///     "Sender": {"SenderId": "user@example.com", "SenderIdType": "EmailId"},
+///     "Discussion": {"DiscussionId": "room-id", "DiscussionType": "Room"},
+///     "EventType": "Invite",
+///     "EventTimestamp": "2019-04-04T21:27:52.736Z"
+/// }"#;
+/// let timestamp = "2019-04-04T21:27:52.736Z";
+/// let signature = sign("security-token", timestamp, body);
+///
+/// let event = parse_event("security-token", timestamp, body, &signature).unwrap();
+/// assert_eq!(event.event_type, BotEventType::Invite);
+/// ```
+pub fn parse_event(
+    security_token: &str,
+    timestamp: &str,
+    body: &[u8],
+    signature: &str,
+) -> Result<BotEvent, EventError> {
+    verify(security_token, timestamp, body, signature)?;
+    serde_json::from_slice(body).map_err(|e| EventError::InvalidEvent(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: &str = "security-token";
+    const TIMESTAMP: &str = "2019-04-04T21:30:43.181Z";
+    const MENTION: &[u8] = br#"{
+        "Sender": {"SenderId": "user@example.com", "SenderIdType": "EmailId"},
+        "Discussion": {
+            "DiscussionId": "abcdef12-g34h-56i7-j8kl-mn9opqr012st",
+            "DiscussionType": "Room"
+        },
+        "EventType": "Mention",
+        "InboundHttpsEndpoint": {
+            "EndpointType": "ShortLived",
+            "Url": "https://hooks.chime.aws/example"
+        },
+        "EventTimestamp": "2019-04-04T21:30:43.181Z",
+        "Message": "@bot hello"
+    }"#;
+
+    #[test]
+    fn parses_signed_mention() {
+        let signature = sign(TOKEN, TIMESTAMP, MENTION);
+        let event = parse_event(TOKEN, TIMESTAMP, MENTION, &signature).unwrap();
+
+        assert_eq!(event.event_type, BotEventType::Mention);
+        assert_eq!(event.sender.sender_id, "user@example.com");
+        assert_eq!(event.discussion.discussion_type, "Room");
+        assert_eq!(event.message, Some("@bot hello".to_owned()));
+        assert_eq!(
+            event.inbound_https_endpoint.unwrap().url,
+            "https://hooks.chime.aws/example"
+        );
+    }
+
+    #[test]
+    fn rejects_tampered_requests() {
+        let signature = sign(TOKEN, TIMESTAMP, MENTION);
+
+        assert!(verify("other-token", TIMESTAMP, MENTION, &signature).is_err());
+        assert!(verify(TOKEN, "2019-04-04T21:30:44.000Z", MENTION, &signature).is_err());
+        assert!(verify(TOKEN, TIMESTAMP, b"{}", &signature).is_err());
+        match verify(TOKEN, TIMESTAMP, MENTION, "not base64!") {
+            Err(EventError::MalformedSignature(_)) => {}
+            other => panic!("expected a malformed signature, got {:?}", other),
+        }
+    }
+}
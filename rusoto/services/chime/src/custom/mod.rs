@@ -1 +1,2 @@
-
+/// Typed outgoing bot events and verification of their signatures
+pub mod events;
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>The Amazon Chime account details. An AWS account can have multiple Amazon Chime accounts.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -34,25 +35,26 @@ pub struct Account {
     /// <p>The Amazon Chime account type. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p>
     #[serde(rename = "AccountType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_type: Option<String>,
+    pub account_type: Option<AccountType>,
     /// <p>The AWS account ID.</p>
     #[serde(rename = "AwsAccountId")]
     pub aws_account_id: String,
     /// <p>The Amazon Chime account creation timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The default license for the Amazon Chime account.</p>
     #[serde(rename = "DefaultLicense")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_license: Option<String>,
+    pub default_license: Option<License>,
     /// <p>The Amazon Chime account name.</p>
     #[serde(rename = "Name")]
     pub name: String,
     /// <p>Supported licenses for the Amazon Chime account.</p>
     #[serde(rename = "SupportedLicenses")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_licenses: Option<Vec<String>>,
+    pub supported_licenses: Option<Vec<License>>,
 }
 
 /// <p>Settings related to the Amazon Chime account. This includes settings that start or stop remote control of shared screens, or start or stop the dial-out option in the Amazon Chime web application. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
@@ -68,6 +70,92 @@ pub struct AccountSettings {
     pub enable_dial_out: Option<bool>,
 }
 
+/// <p>The Amazon Chime account type. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccountType {
+    /// <p><code>Team</code></p>
+    Team,
+    /// <p><code>EnterpriseDirectory</code></p>
+    EnterpriseDirectory,
+    /// <p><code>EnterpriseLWA</code></p>
+    EnterpriseLWA,
+    /// <p><code>EnterpriseOIDC</code></p>
+    EnterpriseOIDC,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AccountType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountType::Team => "Team",
+            AccountType::EnterpriseDirectory => "EnterpriseDirectory",
+            AccountType::EnterpriseLWA => "EnterpriseLWA",
+            AccountType::EnterpriseOIDC => "EnterpriseOIDC",
+            AccountType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AccountType {
+    fn from(value: &'a str) -> AccountType {
+        match value {
+            "Team" => AccountType::Team,
+            "EnterpriseDirectory" => AccountType::EnterpriseDirectory,
+            "EnterpriseLWA" => AccountType::EnterpriseLWA,
+            "EnterpriseOIDC" => AccountType::EnterpriseOIDC,
+            _ => AccountType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountType {
+    fn from(value: String) -> AccountType {
+        AccountType::from(value.as_str())
+    }
+}
+
+impl From<AccountType> for String {
+    fn from(value: AccountType) -> String {
+        match value {
+            AccountType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AccountType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccountType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AccountType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccountType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AccountType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AccountType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociatePhoneNumberWithUserRequest {
     /// <p>The Amazon Chime account ID.</p>
@@ -81,6 +169,58 @@ pub struct AssociatePhoneNumberWithUserRequest {
     pub user_id: String,
 }
 
+impl AssociatePhoneNumberWithUserRequest {
+    /// Returns a builder for `AssociatePhoneNumberWithUserRequest`, checking its required members when built.
+    pub fn builder() -> AssociatePhoneNumberWithUserRequestBuilder {
+        AssociatePhoneNumberWithUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociatePhoneNumberWithUserRequest`](struct.AssociatePhoneNumberWithUserRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociatePhoneNumberWithUserRequestBuilder {
+    account_id: Option<String>,
+    e164_phone_number: Option<String>,
+    user_id: Option<String>,
+}
+
+impl AssociatePhoneNumberWithUserRequestBuilder {
+    /// Sets the [`account_id`](struct.AssociatePhoneNumberWithUserRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`e164_phone_number`](struct.AssociatePhoneNumberWithUserRequest.html#structfield.e164_phone_number) member.
+    pub fn e164_phone_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.e164_phone_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.AssociatePhoneNumberWithUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociatePhoneNumberWithUserRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociatePhoneNumberWithUserRequest, ::rusoto_core::BuildError> {
+        Ok(AssociatePhoneNumberWithUserRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            e164_phone_number: self
+                .e164_phone_number
+                .ok_or(::rusoto_core::BuildError::missing("e164_phone_number"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociatePhoneNumberWithUserResponse {}
@@ -96,6 +236,49 @@ pub struct AssociatePhoneNumbersWithVoiceConnectorRequest {
     pub voice_connector_id: String,
 }
 
+impl AssociatePhoneNumbersWithVoiceConnectorRequest {
+    /// Returns a builder for `AssociatePhoneNumbersWithVoiceConnectorRequest`, checking its required members when built.
+    pub fn builder() -> AssociatePhoneNumbersWithVoiceConnectorRequestBuilder {
+        AssociatePhoneNumbersWithVoiceConnectorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociatePhoneNumbersWithVoiceConnectorRequest`](struct.AssociatePhoneNumbersWithVoiceConnectorRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociatePhoneNumbersWithVoiceConnectorRequestBuilder {
+    e164_phone_numbers: Option<Vec<String>>,
+    voice_connector_id: Option<String>,
+}
+
+impl AssociatePhoneNumbersWithVoiceConnectorRequestBuilder {
+    /// Sets the [`e164_phone_numbers`](struct.AssociatePhoneNumbersWithVoiceConnectorRequest.html#structfield.e164_phone_numbers) member.
+    pub fn e164_phone_numbers<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.e164_phone_numbers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`voice_connector_id`](struct.AssociatePhoneNumbersWithVoiceConnectorRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociatePhoneNumbersWithVoiceConnectorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        AssociatePhoneNumbersWithVoiceConnectorRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(AssociatePhoneNumbersWithVoiceConnectorRequest {
+            e164_phone_numbers: self.e164_phone_numbers,
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociatePhoneNumbersWithVoiceConnectorResponse {
@@ -112,6 +295,38 @@ pub struct BatchDeletePhoneNumberRequest {
     pub phone_number_ids: Vec<String>,
 }
 
+impl BatchDeletePhoneNumberRequest {
+    /// Returns a builder for `BatchDeletePhoneNumberRequest`, checking its required members when built.
+    pub fn builder() -> BatchDeletePhoneNumberRequestBuilder {
+        BatchDeletePhoneNumberRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDeletePhoneNumberRequest`](struct.BatchDeletePhoneNumberRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDeletePhoneNumberRequestBuilder {
+    phone_number_ids: Option<Vec<String>>,
+}
+
+impl BatchDeletePhoneNumberRequestBuilder {
+    /// Sets the [`phone_number_ids`](struct.BatchDeletePhoneNumberRequest.html#structfield.phone_number_ids) member.
+    pub fn phone_number_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.phone_number_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDeletePhoneNumberRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDeletePhoneNumberRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDeletePhoneNumberRequest {
+            phone_number_ids: self
+                .phone_number_ids
+                .ok_or(::rusoto_core::BuildError::missing("phone_number_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDeletePhoneNumberResponse {
@@ -131,6 +346,48 @@ pub struct BatchSuspendUserRequest {
     pub user_id_list: Vec<String>,
 }
 
+impl BatchSuspendUserRequest {
+    /// Returns a builder for `BatchSuspendUserRequest`, checking its required members when built.
+    pub fn builder() -> BatchSuspendUserRequestBuilder {
+        BatchSuspendUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchSuspendUserRequest`](struct.BatchSuspendUserRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchSuspendUserRequestBuilder {
+    account_id: Option<String>,
+    user_id_list: Option<Vec<String>>,
+}
+
+impl BatchSuspendUserRequestBuilder {
+    /// Sets the [`account_id`](struct.BatchSuspendUserRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id_list`](struct.BatchSuspendUserRequest.html#structfield.user_id_list) member.
+    pub fn user_id_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.user_id_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchSuspendUserRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchSuspendUserRequest, ::rusoto_core::BuildError> {
+        Ok(BatchSuspendUserRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            user_id_list: self
+                .user_id_list
+                .ok_or(::rusoto_core::BuildError::missing("user_id_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchSuspendUserResponse {
@@ -150,6 +407,48 @@ pub struct BatchUnsuspendUserRequest {
     pub user_id_list: Vec<String>,
 }
 
+impl BatchUnsuspendUserRequest {
+    /// Returns a builder for `BatchUnsuspendUserRequest`, checking its required members when built.
+    pub fn builder() -> BatchUnsuspendUserRequestBuilder {
+        BatchUnsuspendUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchUnsuspendUserRequest`](struct.BatchUnsuspendUserRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchUnsuspendUserRequestBuilder {
+    account_id: Option<String>,
+    user_id_list: Option<Vec<String>>,
+}
+
+impl BatchUnsuspendUserRequestBuilder {
+    /// Sets the [`account_id`](struct.BatchUnsuspendUserRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id_list`](struct.BatchUnsuspendUserRequest.html#structfield.user_id_list) member.
+    pub fn user_id_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.user_id_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchUnsuspendUserRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchUnsuspendUserRequest, ::rusoto_core::BuildError> {
+        Ok(BatchUnsuspendUserRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            user_id_list: self
+                .user_id_list
+                .ok_or(::rusoto_core::BuildError::missing("user_id_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchUnsuspendUserResponse {
@@ -166,6 +465,41 @@ pub struct BatchUpdatePhoneNumberRequest {
     pub update_phone_number_request_items: Vec<UpdatePhoneNumberRequestItem>,
 }
 
+impl BatchUpdatePhoneNumberRequest {
+    /// Returns a builder for `BatchUpdatePhoneNumberRequest`, checking its required members when built.
+    pub fn builder() -> BatchUpdatePhoneNumberRequestBuilder {
+        BatchUpdatePhoneNumberRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchUpdatePhoneNumberRequest`](struct.BatchUpdatePhoneNumberRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchUpdatePhoneNumberRequestBuilder {
+    update_phone_number_request_items: Option<Vec<UpdatePhoneNumberRequestItem>>,
+}
+
+impl BatchUpdatePhoneNumberRequestBuilder {
+    /// Sets the [`update_phone_number_request_items`](struct.BatchUpdatePhoneNumberRequest.html#structfield.update_phone_number_request_items) member.
+    pub fn update_phone_number_request_items<T: Into<Vec<UpdatePhoneNumberRequestItem>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.update_phone_number_request_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchUpdatePhoneNumberRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchUpdatePhoneNumberRequest, ::rusoto_core::BuildError> {
+        Ok(BatchUpdatePhoneNumberRequest {
+            update_phone_number_request_items: self.update_phone_number_request_items.ok_or(
+                ::rusoto_core::BuildError::missing("update_phone_number_request_items"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchUpdatePhoneNumberResponse {
@@ -185,6 +519,49 @@ pub struct BatchUpdateUserRequest {
     pub update_user_request_items: Vec<UpdateUserRequestItem>,
 }
 
+impl BatchUpdateUserRequest {
+    /// Returns a builder for `BatchUpdateUserRequest`, checking its required members when built.
+    pub fn builder() -> BatchUpdateUserRequestBuilder {
+        BatchUpdateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchUpdateUserRequest`](struct.BatchUpdateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchUpdateUserRequestBuilder {
+    account_id: Option<String>,
+    update_user_request_items: Option<Vec<UpdateUserRequestItem>>,
+}
+
+impl BatchUpdateUserRequestBuilder {
+    /// Sets the [`account_id`](struct.BatchUpdateUserRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_user_request_items`](struct.BatchUpdateUserRequest.html#structfield.update_user_request_items) member.
+    pub fn update_user_request_items<T: Into<Vec<UpdateUserRequestItem>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.update_user_request_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchUpdateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchUpdateUserRequest, ::rusoto_core::BuildError> {
+        Ok(BatchUpdateUserRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            update_user_request_items: self.update_user_request_items.ok_or(
+                ::rusoto_core::BuildError::missing("update_user_request_items"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchUpdateUserResponse {
@@ -209,11 +586,12 @@ pub struct Bot {
     /// <p>The bot type.</p>
     #[serde(rename = "BotType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bot_type: Option<String>,
+    pub bot_type: Option<BotType>,
     /// <p>The bot creation timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>When true, the bot is stopped from running in your account.</p>
     #[serde(rename = "Disabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -228,14 +606,89 @@ pub struct Bot {
     pub security_token: Option<String>,
     /// <p>The updated bot timestamp, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<f64>,
+    pub updated_timestamp: Option<EpochTimestamp>,
     /// <p>The unique ID for the bot user.</p>
     #[serde(rename = "UserId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<String>,
 }
 
+/// <p>The bot type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BotType {
+    /// <p><code>ChatBot</code></p>
+    ChatBot,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BotType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BotType::ChatBot => "ChatBot",
+            BotType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BotType {
+    fn from(value: &'a str) -> BotType {
+        match value {
+            "ChatBot" => BotType::ChatBot,
+            _ => BotType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BotType {
+    fn from(value: String) -> BotType {
+        BotType::from(value.as_str())
+    }
+}
+
+impl From<BotType> for String {
+    fn from(value: BotType) -> String {
+        match value {
+            BotType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BotType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BotType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BotType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BotType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BotType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BotType::from(value))
+    }
+}
+
 /// <p>The Amazon Chime Business Calling settings for the administrator's AWS account. Includes any Amazon S3 buckets designated for storing call detail records.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BusinessCallingSettings {
@@ -252,6 +705,36 @@ pub struct CreateAccountRequest {
     pub name: String,
 }
 
+impl CreateAccountRequest {
+    /// Returns a builder for `CreateAccountRequest`, checking its required members when built.
+    pub fn builder() -> CreateAccountRequestBuilder {
+        CreateAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAccountRequest`](struct.CreateAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAccountRequestBuilder {
+    name: Option<String>,
+}
+
+impl CreateAccountRequestBuilder {
+    /// Sets the [`name`](struct.CreateAccountRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateAccountRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAccountRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateAccountResponse {
@@ -275,6 +758,54 @@ pub struct CreateBotRequest {
     pub domain: Option<String>,
 }
 
+impl CreateBotRequest {
+    /// Returns a builder for `CreateBotRequest`, checking its required members when built.
+    pub fn builder() -> CreateBotRequestBuilder {
+        CreateBotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBotRequest`](struct.CreateBotRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateBotRequestBuilder {
+    account_id: Option<String>,
+    display_name: Option<String>,
+    domain: Option<String>,
+}
+
+impl CreateBotRequestBuilder {
+    /// Sets the [`account_id`](struct.CreateBotRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_name`](struct.CreateBotRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.CreateBotRequest.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBotRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateBotRequest, ::rusoto_core::BuildError> {
+        Ok(CreateBotRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            display_name: self
+                .display_name
+                .ok_or(::rusoto_core::BuildError::missing("display_name"))?,
+            domain: self.domain,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateBotResponse {
@@ -284,14 +815,56 @@ pub struct CreateBotResponse {
     pub bot: Option<Bot>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreatePhoneNumberOrderRequest {
     /// <p>List of phone numbers, in E.164 format.</p>
     #[serde(rename = "E164PhoneNumbers")]
     pub e164_phone_numbers: Vec<String>,
     /// <p>The phone number product type.</p>
     #[serde(rename = "ProductType")]
-    pub product_type: String,
+    pub product_type: PhoneNumberProductType,
+}
+
+impl CreatePhoneNumberOrderRequest {
+    /// Returns a builder for `CreatePhoneNumberOrderRequest`, checking its required members when built.
+    pub fn builder() -> CreatePhoneNumberOrderRequestBuilder {
+        CreatePhoneNumberOrderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePhoneNumberOrderRequest`](struct.CreatePhoneNumberOrderRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePhoneNumberOrderRequestBuilder {
+    e164_phone_numbers: Option<Vec<String>>,
+    product_type: Option<PhoneNumberProductType>,
+}
+
+impl CreatePhoneNumberOrderRequestBuilder {
+    /// Sets the [`e164_phone_numbers`](struct.CreatePhoneNumberOrderRequest.html#structfield.e164_phone_numbers) member.
+    pub fn e164_phone_numbers<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.e164_phone_numbers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_type`](struct.CreatePhoneNumberOrderRequest.html#structfield.product_type) member.
+    pub fn product_type<T: Into<PhoneNumberProductType>>(mut self, value: T) -> Self {
+        self.product_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePhoneNumberOrderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePhoneNumberOrderRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePhoneNumberOrderRequest {
+            e164_phone_numbers: self
+                .e164_phone_numbers
+                .ok_or(::rusoto_core::BuildError::missing("e164_phone_numbers"))?,
+            product_type: self
+                .product_type
+                .ok_or(::rusoto_core::BuildError::missing("product_type"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -313,6 +886,48 @@ pub struct CreateVoiceConnectorRequest {
     pub require_encryption: bool,
 }
 
+impl CreateVoiceConnectorRequest {
+    /// Returns a builder for `CreateVoiceConnectorRequest`, checking its required members when built.
+    pub fn builder() -> CreateVoiceConnectorRequestBuilder {
+        CreateVoiceConnectorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateVoiceConnectorRequest`](struct.CreateVoiceConnectorRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateVoiceConnectorRequestBuilder {
+    name: Option<String>,
+    require_encryption: Option<bool>,
+}
+
+impl CreateVoiceConnectorRequestBuilder {
+    /// Sets the [`name`](struct.CreateVoiceConnectorRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`require_encryption`](struct.CreateVoiceConnectorRequest.html#structfield.require_encryption) member.
+    pub fn require_encryption<T: Into<bool>>(mut self, value: T) -> Self {
+        self.require_encryption = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateVoiceConnectorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateVoiceConnectorRequest, ::rusoto_core::BuildError> {
+        Ok(CreateVoiceConnectorRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            require_encryption: self
+                .require_encryption
+                .ok_or(::rusoto_core::BuildError::missing("require_encryption"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateVoiceConnectorResponse {
@@ -342,6 +957,36 @@ pub struct DeleteAccountRequest {
     pub account_id: String,
 }
 
+impl DeleteAccountRequest {
+    /// Returns a builder for `DeleteAccountRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAccountRequestBuilder {
+        DeleteAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAccountRequest`](struct.DeleteAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAccountRequestBuilder {
+    account_id: Option<String>,
+}
+
+impl DeleteAccountRequestBuilder {
+    /// Sets the [`account_id`](struct.DeleteAccountRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteAccountRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAccountRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteAccountResponse {}
@@ -356,6 +1001,48 @@ pub struct DeleteEventsConfigurationRequest {
     pub bot_id: String,
 }
 
+impl DeleteEventsConfigurationRequest {
+    /// Returns a builder for `DeleteEventsConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteEventsConfigurationRequestBuilder {
+        DeleteEventsConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEventsConfigurationRequest`](struct.DeleteEventsConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteEventsConfigurationRequestBuilder {
+    account_id: Option<String>,
+    bot_id: Option<String>,
+}
+
+impl DeleteEventsConfigurationRequestBuilder {
+    /// Sets the [`account_id`](struct.DeleteEventsConfigurationRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_id`](struct.DeleteEventsConfigurationRequest.html#structfield.bot_id) member.
+    pub fn bot_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEventsConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteEventsConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteEventsConfigurationRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            bot_id: self
+                .bot_id
+                .ok_or(::rusoto_core::BuildError::missing("bot_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeletePhoneNumberRequest {
     /// <p>The phone number ID.</p>
@@ -363,6 +1050,38 @@ pub struct DeletePhoneNumberRequest {
     pub phone_number_id: String,
 }
 
+impl DeletePhoneNumberRequest {
+    /// Returns a builder for `DeletePhoneNumberRequest`, checking its required members when built.
+    pub fn builder() -> DeletePhoneNumberRequestBuilder {
+        DeletePhoneNumberRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePhoneNumberRequest`](struct.DeletePhoneNumberRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePhoneNumberRequestBuilder {
+    phone_number_id: Option<String>,
+}
+
+impl DeletePhoneNumberRequestBuilder {
+    /// Sets the [`phone_number_id`](struct.DeletePhoneNumberRequest.html#structfield.phone_number_id) member.
+    pub fn phone_number_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.phone_number_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePhoneNumberRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeletePhoneNumberRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePhoneNumberRequest {
+            phone_number_id: self
+                .phone_number_id
+                .ok_or(::rusoto_core::BuildError::missing("phone_number_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteVoiceConnectorOriginationRequest {
     /// <p>The Amazon Chime Voice Connector ID.</p>
@@ -370,17 +1089,82 @@ pub struct DeleteVoiceConnectorOriginationRequest {
     pub voice_connector_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DeleteVoiceConnectorRequest {
-    /// <p>The Amazon Chime Voice Connector ID.</p>
-    #[serde(rename = "VoiceConnectorId")]
-    pub voice_connector_id: String,
+impl DeleteVoiceConnectorOriginationRequest {
+    /// Returns a builder for `DeleteVoiceConnectorOriginationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteVoiceConnectorOriginationRequestBuilder {
+        DeleteVoiceConnectorOriginationRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DeleteVoiceConnectorTerminationCredentialsRequest {
-    /// <p>The RFC2617 compliant username associated with the SIP credentials, in US-ASCII format.</p>
-    #[serde(rename = "Usernames")]
+/// Builds a [`DeleteVoiceConnectorOriginationRequest`](struct.DeleteVoiceConnectorOriginationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteVoiceConnectorOriginationRequestBuilder {
+    voice_connector_id: Option<String>,
+}
+
+impl DeleteVoiceConnectorOriginationRequestBuilder {
+    /// Sets the [`voice_connector_id`](struct.DeleteVoiceConnectorOriginationRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVoiceConnectorOriginationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVoiceConnectorOriginationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteVoiceConnectorOriginationRequest {
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteVoiceConnectorRequest {
+    /// <p>The Amazon Chime Voice Connector ID.</p>
+    #[serde(rename = "VoiceConnectorId")]
+    pub voice_connector_id: String,
+}
+
+impl DeleteVoiceConnectorRequest {
+    /// Returns a builder for `DeleteVoiceConnectorRequest`, checking its required members when built.
+    pub fn builder() -> DeleteVoiceConnectorRequestBuilder {
+        DeleteVoiceConnectorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVoiceConnectorRequest`](struct.DeleteVoiceConnectorRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteVoiceConnectorRequestBuilder {
+    voice_connector_id: Option<String>,
+}
+
+impl DeleteVoiceConnectorRequestBuilder {
+    /// Sets the [`voice_connector_id`](struct.DeleteVoiceConnectorRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVoiceConnectorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVoiceConnectorRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteVoiceConnectorRequest {
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteVoiceConnectorTerminationCredentialsRequest {
+    /// <p>The RFC2617 compliant username associated with the SIP credentials, in US-ASCII format.</p>
+    #[serde(rename = "Usernames")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub usernames: Option<Vec<String>>,
     /// <p>The Amazon Chime Voice Connector ID.</p>
@@ -388,6 +1172,49 @@ pub struct DeleteVoiceConnectorTerminationCredentialsRequest {
     pub voice_connector_id: String,
 }
 
+impl DeleteVoiceConnectorTerminationCredentialsRequest {
+    /// Returns a builder for `DeleteVoiceConnectorTerminationCredentialsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteVoiceConnectorTerminationCredentialsRequestBuilder {
+        DeleteVoiceConnectorTerminationCredentialsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVoiceConnectorTerminationCredentialsRequest`](struct.DeleteVoiceConnectorTerminationCredentialsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteVoiceConnectorTerminationCredentialsRequestBuilder {
+    usernames: Option<Vec<String>>,
+    voice_connector_id: Option<String>,
+}
+
+impl DeleteVoiceConnectorTerminationCredentialsRequestBuilder {
+    /// Sets the [`usernames`](struct.DeleteVoiceConnectorTerminationCredentialsRequest.html#structfield.usernames) member.
+    pub fn usernames<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.usernames = Some(value.into());
+        self
+    }
+
+    /// Sets the [`voice_connector_id`](struct.DeleteVoiceConnectorTerminationCredentialsRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVoiceConnectorTerminationCredentialsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DeleteVoiceConnectorTerminationCredentialsRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DeleteVoiceConnectorTerminationCredentialsRequest {
+            usernames: self.usernames,
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteVoiceConnectorTerminationRequest {
     /// <p>The Amazon Chime Voice Connector ID.</p>
@@ -395,6 +1222,39 @@ pub struct DeleteVoiceConnectorTerminationRequest {
     pub voice_connector_id: String,
 }
 
+impl DeleteVoiceConnectorTerminationRequest {
+    /// Returns a builder for `DeleteVoiceConnectorTerminationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteVoiceConnectorTerminationRequestBuilder {
+        DeleteVoiceConnectorTerminationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVoiceConnectorTerminationRequest`](struct.DeleteVoiceConnectorTerminationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteVoiceConnectorTerminationRequestBuilder {
+    voice_connector_id: Option<String>,
+}
+
+impl DeleteVoiceConnectorTerminationRequestBuilder {
+    /// Sets the [`voice_connector_id`](struct.DeleteVoiceConnectorTerminationRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVoiceConnectorTerminationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVoiceConnectorTerminationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteVoiceConnectorTerminationRequest {
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DisassociatePhoneNumberFromUserRequest {
     /// <p>The Amazon Chime account ID.</p>
@@ -405,6 +1265,49 @@ pub struct DisassociatePhoneNumberFromUserRequest {
     pub user_id: String,
 }
 
+impl DisassociatePhoneNumberFromUserRequest {
+    /// Returns a builder for `DisassociatePhoneNumberFromUserRequest`, checking its required members when built.
+    pub fn builder() -> DisassociatePhoneNumberFromUserRequestBuilder {
+        DisassociatePhoneNumberFromUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociatePhoneNumberFromUserRequest`](struct.DisassociatePhoneNumberFromUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociatePhoneNumberFromUserRequestBuilder {
+    account_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl DisassociatePhoneNumberFromUserRequestBuilder {
+    /// Sets the [`account_id`](struct.DisassociatePhoneNumberFromUserRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.DisassociatePhoneNumberFromUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociatePhoneNumberFromUserRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociatePhoneNumberFromUserRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociatePhoneNumberFromUserRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociatePhoneNumberFromUserResponse {}
@@ -420,6 +1323,49 @@ pub struct DisassociatePhoneNumbersFromVoiceConnectorRequest {
     pub voice_connector_id: String,
 }
 
+impl DisassociatePhoneNumbersFromVoiceConnectorRequest {
+    /// Returns a builder for `DisassociatePhoneNumbersFromVoiceConnectorRequest`, checking its required members when built.
+    pub fn builder() -> DisassociatePhoneNumbersFromVoiceConnectorRequestBuilder {
+        DisassociatePhoneNumbersFromVoiceConnectorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociatePhoneNumbersFromVoiceConnectorRequest`](struct.DisassociatePhoneNumbersFromVoiceConnectorRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociatePhoneNumbersFromVoiceConnectorRequestBuilder {
+    e164_phone_numbers: Option<Vec<String>>,
+    voice_connector_id: Option<String>,
+}
+
+impl DisassociatePhoneNumbersFromVoiceConnectorRequestBuilder {
+    /// Sets the [`e164_phone_numbers`](struct.DisassociatePhoneNumbersFromVoiceConnectorRequest.html#structfield.e164_phone_numbers) member.
+    pub fn e164_phone_numbers<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.e164_phone_numbers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`voice_connector_id`](struct.DisassociatePhoneNumbersFromVoiceConnectorRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociatePhoneNumbersFromVoiceConnectorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DisassociatePhoneNumbersFromVoiceConnectorRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DisassociatePhoneNumbersFromVoiceConnectorRequest {
+            e164_phone_numbers: self.e164_phone_numbers,
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociatePhoneNumbersFromVoiceConnectorResponse {
@@ -429,6 +1375,222 @@ pub struct DisassociatePhoneNumbersFromVoiceConnectorResponse {
     pub phone_number_errors: Option<Vec<PhoneNumberError>>,
 }
 
+/// <p>The status of the invite email.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EmailStatus {
+    /// <p><code>NotSent</code></p>
+    NotSent,
+    /// <p><code>Sent</code></p>
+    Sent,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EmailStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EmailStatus::NotSent => "NotSent",
+            EmailStatus::Sent => "Sent",
+            EmailStatus::Failed => "Failed",
+            EmailStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EmailStatus {
+    fn from(value: &'a str) -> EmailStatus {
+        match value {
+            "NotSent" => EmailStatus::NotSent,
+            "Sent" => EmailStatus::Sent,
+            "Failed" => EmailStatus::Failed,
+            _ => EmailStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EmailStatus {
+    fn from(value: String) -> EmailStatus {
+        EmailStatus::from(value.as_str())
+    }
+}
+
+impl From<EmailStatus> for String {
+    fn from(value: EmailStatus) -> String {
+        match value {
+            EmailStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EmailStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EmailStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EmailStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EmailStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EmailStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EmailStatus::from(value))
+    }
+}
+
+/// <p>The error code.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    /// <p><code>BadRequest</code></p>
+    BadRequest,
+    /// <p><code>Conflict</code></p>
+    Conflict,
+    /// <p><code>Forbidden</code></p>
+    Forbidden,
+    /// <p><code>NotFound</code></p>
+    NotFound,
+    /// <p><code>PreconditionFailed</code></p>
+    PreconditionFailed,
+    /// <p><code>ResourceLimitExceeded</code></p>
+    ResourceLimitExceeded,
+    /// <p><code>ServiceFailure</code></p>
+    ServiceFailure,
+    /// <p><code>AccessDenied</code></p>
+    AccessDenied,
+    /// <p><code>ServiceUnavailable</code></p>
+    ServiceUnavailable,
+    /// <p><code>Throttled</code></p>
+    Throttled,
+    /// <p><code>Throttling</code></p>
+    Throttling,
+    /// <p><code>Unauthorized</code></p>
+    Unauthorized,
+    /// <p><code>Unprocessable</code></p>
+    Unprocessable,
+    /// <p><code>VoiceConnectorGroupAssociationsExist</code></p>
+    VoiceConnectorGroupAssociationsExist,
+    /// <p><code>PhoneNumberAssociationsExist</code></p>
+    PhoneNumberAssociationsExist,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::BadRequest => "BadRequest",
+            ErrorCode::Conflict => "Conflict",
+            ErrorCode::Forbidden => "Forbidden",
+            ErrorCode::NotFound => "NotFound",
+            ErrorCode::PreconditionFailed => "PreconditionFailed",
+            ErrorCode::ResourceLimitExceeded => "ResourceLimitExceeded",
+            ErrorCode::ServiceFailure => "ServiceFailure",
+            ErrorCode::AccessDenied => "AccessDenied",
+            ErrorCode::ServiceUnavailable => "ServiceUnavailable",
+            ErrorCode::Throttled => "Throttled",
+            ErrorCode::Throttling => "Throttling",
+            ErrorCode::Unauthorized => "Unauthorized",
+            ErrorCode::Unprocessable => "Unprocessable",
+            ErrorCode::VoiceConnectorGroupAssociationsExist => {
+                "VoiceConnectorGroupAssociationsExist"
+            }
+            ErrorCode::PhoneNumberAssociationsExist => "PhoneNumberAssociationsExist",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "BadRequest" => ErrorCode::BadRequest,
+            "Conflict" => ErrorCode::Conflict,
+            "Forbidden" => ErrorCode::Forbidden,
+            "NotFound" => ErrorCode::NotFound,
+            "PreconditionFailed" => ErrorCode::PreconditionFailed,
+            "ResourceLimitExceeded" => ErrorCode::ResourceLimitExceeded,
+            "ServiceFailure" => ErrorCode::ServiceFailure,
+            "AccessDenied" => ErrorCode::AccessDenied,
+            "ServiceUnavailable" => ErrorCode::ServiceUnavailable,
+            "Throttled" => ErrorCode::Throttled,
+            "Throttling" => ErrorCode::Throttling,
+            "Unauthorized" => ErrorCode::Unauthorized,
+            "Unprocessable" => ErrorCode::Unprocessable,
+            "VoiceConnectorGroupAssociationsExist" => {
+                ErrorCode::VoiceConnectorGroupAssociationsExist
+            }
+            "PhoneNumberAssociationsExist" => ErrorCode::PhoneNumberAssociationsExist,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        ErrorCode::from(value.as_str())
+    }
+}
+
+impl From<ErrorCode> for String {
+    fn from(value: ErrorCode) -> String {
+        match value {
+            ErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ErrorCode::from(value))
+    }
+}
+
 /// <p>The configuration that allows a bot to receive outgoing events. Can be either an HTTPS endpoint or a Lambda function ARN.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -454,6 +1616,36 @@ pub struct GetAccountRequest {
     pub account_id: String,
 }
 
+impl GetAccountRequest {
+    /// Returns a builder for `GetAccountRequest`, checking its required members when built.
+    pub fn builder() -> GetAccountRequestBuilder {
+        GetAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAccountRequest`](struct.GetAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAccountRequestBuilder {
+    account_id: Option<String>,
+}
+
+impl GetAccountRequestBuilder {
+    /// Sets the [`account_id`](struct.GetAccountRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetAccountRequest, ::rusoto_core::BuildError> {
+        Ok(GetAccountRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAccountResponse {
@@ -470,6 +1662,38 @@ pub struct GetAccountSettingsRequest {
     pub account_id: String,
 }
 
+impl GetAccountSettingsRequest {
+    /// Returns a builder for `GetAccountSettingsRequest`, checking its required members when built.
+    pub fn builder() -> GetAccountSettingsRequestBuilder {
+        GetAccountSettingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAccountSettingsRequest`](struct.GetAccountSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAccountSettingsRequestBuilder {
+    account_id: Option<String>,
+}
+
+impl GetAccountSettingsRequestBuilder {
+    /// Sets the [`account_id`](struct.GetAccountSettingsRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAccountSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAccountSettingsRequest, ::rusoto_core::BuildError> {
+        Ok(GetAccountSettingsRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAccountSettingsResponse {
@@ -489,6 +1713,46 @@ pub struct GetBotRequest {
     pub bot_id: String,
 }
 
+impl GetBotRequest {
+    /// Returns a builder for `GetBotRequest`, checking its required members when built.
+    pub fn builder() -> GetBotRequestBuilder {
+        GetBotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBotRequest`](struct.GetBotRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBotRequestBuilder {
+    account_id: Option<String>,
+    bot_id: Option<String>,
+}
+
+impl GetBotRequestBuilder {
+    /// Sets the [`account_id`](struct.GetBotRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_id`](struct.GetBotRequest.html#structfield.bot_id) member.
+    pub fn bot_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBotRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetBotRequest, ::rusoto_core::BuildError> {
+        Ok(GetBotRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            bot_id: self
+                .bot_id
+                .ok_or(::rusoto_core::BuildError::missing("bot_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBotResponse {
@@ -508,6 +1772,48 @@ pub struct GetEventsConfigurationRequest {
     pub bot_id: String,
 }
 
+impl GetEventsConfigurationRequest {
+    /// Returns a builder for `GetEventsConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> GetEventsConfigurationRequestBuilder {
+        GetEventsConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetEventsConfigurationRequest`](struct.GetEventsConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetEventsConfigurationRequestBuilder {
+    account_id: Option<String>,
+    bot_id: Option<String>,
+}
+
+impl GetEventsConfigurationRequestBuilder {
+    /// Sets the [`account_id`](struct.GetEventsConfigurationRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_id`](struct.GetEventsConfigurationRequest.html#structfield.bot_id) member.
+    pub fn bot_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetEventsConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetEventsConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(GetEventsConfigurationRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            bot_id: self
+                .bot_id
+                .ok_or(::rusoto_core::BuildError::missing("bot_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetEventsConfigurationResponse {
@@ -537,6 +1843,38 @@ pub struct GetPhoneNumberOrderRequest {
     pub phone_number_order_id: String,
 }
 
+impl GetPhoneNumberOrderRequest {
+    /// Returns a builder for `GetPhoneNumberOrderRequest`, checking its required members when built.
+    pub fn builder() -> GetPhoneNumberOrderRequestBuilder {
+        GetPhoneNumberOrderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPhoneNumberOrderRequest`](struct.GetPhoneNumberOrderRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPhoneNumberOrderRequestBuilder {
+    phone_number_order_id: Option<String>,
+}
+
+impl GetPhoneNumberOrderRequestBuilder {
+    /// Sets the [`phone_number_order_id`](struct.GetPhoneNumberOrderRequest.html#structfield.phone_number_order_id) member.
+    pub fn phone_number_order_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.phone_number_order_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPhoneNumberOrderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetPhoneNumberOrderRequest, ::rusoto_core::BuildError> {
+        Ok(GetPhoneNumberOrderRequest {
+            phone_number_order_id: self
+                .phone_number_order_id
+                .ok_or(::rusoto_core::BuildError::missing("phone_number_order_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPhoneNumberOrderResponse {
@@ -553,6 +1891,36 @@ pub struct GetPhoneNumberRequest {
     pub phone_number_id: String,
 }
 
+impl GetPhoneNumberRequest {
+    /// Returns a builder for `GetPhoneNumberRequest`, checking its required members when built.
+    pub fn builder() -> GetPhoneNumberRequestBuilder {
+        GetPhoneNumberRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPhoneNumberRequest`](struct.GetPhoneNumberRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPhoneNumberRequestBuilder {
+    phone_number_id: Option<String>,
+}
+
+impl GetPhoneNumberRequestBuilder {
+    /// Sets the [`phone_number_id`](struct.GetPhoneNumberRequest.html#structfield.phone_number_id) member.
+    pub fn phone_number_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.phone_number_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPhoneNumberRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetPhoneNumberRequest, ::rusoto_core::BuildError> {
+        Ok(GetPhoneNumberRequest {
+            phone_number_id: self
+                .phone_number_id
+                .ok_or(::rusoto_core::BuildError::missing("phone_number_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPhoneNumberResponse {
@@ -572,6 +1940,46 @@ pub struct GetUserRequest {
     pub user_id: String,
 }
 
+impl GetUserRequest {
+    /// Returns a builder for `GetUserRequest`, checking its required members when built.
+    pub fn builder() -> GetUserRequestBuilder {
+        GetUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUserRequest`](struct.GetUserRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUserRequestBuilder {
+    account_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl GetUserRequestBuilder {
+    /// Sets the [`account_id`](struct.GetUserRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.GetUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetUserRequest, ::rusoto_core::BuildError> {
+        Ok(GetUserRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetUserResponse {
@@ -591,6 +1999,46 @@ pub struct GetUserSettingsRequest {
     pub user_id: String,
 }
 
+impl GetUserSettingsRequest {
+    /// Returns a builder for `GetUserSettingsRequest`, checking its required members when built.
+    pub fn builder() -> GetUserSettingsRequestBuilder {
+        GetUserSettingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUserSettingsRequest`](struct.GetUserSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUserSettingsRequestBuilder {
+    account_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl GetUserSettingsRequestBuilder {
+    /// Sets the [`account_id`](struct.GetUserSettingsRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.GetUserSettingsRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUserSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetUserSettingsRequest, ::rusoto_core::BuildError> {
+        Ok(GetUserSettingsRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetUserSettingsResponse {
@@ -607,6 +2055,38 @@ pub struct GetVoiceConnectorOriginationRequest {
     pub voice_connector_id: String,
 }
 
+impl GetVoiceConnectorOriginationRequest {
+    /// Returns a builder for `GetVoiceConnectorOriginationRequest`, checking its required members when built.
+    pub fn builder() -> GetVoiceConnectorOriginationRequestBuilder {
+        GetVoiceConnectorOriginationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetVoiceConnectorOriginationRequest`](struct.GetVoiceConnectorOriginationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetVoiceConnectorOriginationRequestBuilder {
+    voice_connector_id: Option<String>,
+}
+
+impl GetVoiceConnectorOriginationRequestBuilder {
+    /// Sets the [`voice_connector_id`](struct.GetVoiceConnectorOriginationRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetVoiceConnectorOriginationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetVoiceConnectorOriginationRequest, ::rusoto_core::BuildError> {
+        Ok(GetVoiceConnectorOriginationRequest {
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetVoiceConnectorOriginationResponse {
@@ -623,7 +2103,39 @@ pub struct GetVoiceConnectorRequest {
     pub voice_connector_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl GetVoiceConnectorRequest {
+    /// Returns a builder for `GetVoiceConnectorRequest`, checking its required members when built.
+    pub fn builder() -> GetVoiceConnectorRequestBuilder {
+        GetVoiceConnectorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetVoiceConnectorRequest`](struct.GetVoiceConnectorRequest.html).
+#[derive(Default, Debug)]
+pub struct GetVoiceConnectorRequestBuilder {
+    voice_connector_id: Option<String>,
+}
+
+impl GetVoiceConnectorRequestBuilder {
+    /// Sets the [`voice_connector_id`](struct.GetVoiceConnectorRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetVoiceConnectorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetVoiceConnectorRequest, ::rusoto_core::BuildError> {
+        Ok(GetVoiceConnectorRequest {
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetVoiceConnectorResponse {
     /// <p>The Amazon Chime Voice Connector details.</p>
@@ -639,6 +2151,39 @@ pub struct GetVoiceConnectorTerminationHealthRequest {
     pub voice_connector_id: String,
 }
 
+impl GetVoiceConnectorTerminationHealthRequest {
+    /// Returns a builder for `GetVoiceConnectorTerminationHealthRequest`, checking its required members when built.
+    pub fn builder() -> GetVoiceConnectorTerminationHealthRequestBuilder {
+        GetVoiceConnectorTerminationHealthRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetVoiceConnectorTerminationHealthRequest`](struct.GetVoiceConnectorTerminationHealthRequest.html).
+#[derive(Default, Debug)]
+pub struct GetVoiceConnectorTerminationHealthRequestBuilder {
+    voice_connector_id: Option<String>,
+}
+
+impl GetVoiceConnectorTerminationHealthRequestBuilder {
+    /// Sets the [`voice_connector_id`](struct.GetVoiceConnectorTerminationHealthRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetVoiceConnectorTerminationHealthRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetVoiceConnectorTerminationHealthRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetVoiceConnectorTerminationHealthRequest {
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetVoiceConnectorTerminationHealthResponse {
@@ -655,6 +2200,38 @@ pub struct GetVoiceConnectorTerminationRequest {
     pub voice_connector_id: String,
 }
 
+impl GetVoiceConnectorTerminationRequest {
+    /// Returns a builder for `GetVoiceConnectorTerminationRequest`, checking its required members when built.
+    pub fn builder() -> GetVoiceConnectorTerminationRequestBuilder {
+        GetVoiceConnectorTerminationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetVoiceConnectorTerminationRequest`](struct.GetVoiceConnectorTerminationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetVoiceConnectorTerminationRequestBuilder {
+    voice_connector_id: Option<String>,
+}
+
+impl GetVoiceConnectorTerminationRequestBuilder {
+    /// Sets the [`voice_connector_id`](struct.GetVoiceConnectorTerminationRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetVoiceConnectorTerminationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetVoiceConnectorTerminationRequest, ::rusoto_core::BuildError> {
+        Ok(GetVoiceConnectorTerminationRequest {
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetVoiceConnectorTerminationResponse {
@@ -675,7 +2252,7 @@ pub struct Invite {
     /// <p>The status of the invite email.</p>
     #[serde(rename = "EmailStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub email_status: Option<String>,
+    pub email_status: Option<EmailStatus>,
     /// <p>The invite ID.</p>
     #[serde(rename = "InviteId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -683,7 +2260,89 @@ pub struct Invite {
     /// <p>The status of the invite.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<InviteStatus>,
+}
+
+/// <p>The status of the invite.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InviteStatus {
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>Accepted</code></p>
+    Accepted,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InviteStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InviteStatus::Pending => "Pending",
+            InviteStatus::Accepted => "Accepted",
+            InviteStatus::Failed => "Failed",
+            InviteStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InviteStatus {
+    fn from(value: &'a str) -> InviteStatus {
+        match value {
+            "Pending" => InviteStatus::Pending,
+            "Accepted" => InviteStatus::Accepted,
+            "Failed" => InviteStatus::Failed,
+            _ => InviteStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InviteStatus {
+    fn from(value: String) -> InviteStatus {
+        InviteStatus::from(value.as_str())
+    }
+}
+
+impl From<InviteStatus> for String {
+    fn from(value: InviteStatus) -> String {
+        match value {
+            InviteStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InviteStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InviteStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InviteStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InviteStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InviteStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InviteStatus::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -696,6 +2355,46 @@ pub struct InviteUsersRequest {
     pub user_email_list: Vec<String>,
 }
 
+impl InviteUsersRequest {
+    /// Returns a builder for `InviteUsersRequest`, checking its required members when built.
+    pub fn builder() -> InviteUsersRequestBuilder {
+        InviteUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InviteUsersRequest`](struct.InviteUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct InviteUsersRequestBuilder {
+    account_id: Option<String>,
+    user_email_list: Option<Vec<String>>,
+}
+
+impl InviteUsersRequestBuilder {
+    /// Sets the [`account_id`](struct.InviteUsersRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_email_list`](struct.InviteUsersRequest.html#structfield.user_email_list) member.
+    pub fn user_email_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.user_email_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `InviteUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<InviteUsersRequest, ::rusoto_core::BuildError> {
+        Ok(InviteUsersRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            user_email_list: self
+                .user_email_list
+                .ok_or(::rusoto_core::BuildError::missing("user_email_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct InviteUsersResponse {
@@ -705,6 +2404,92 @@ pub struct InviteUsersResponse {
     pub invites: Option<Vec<Invite>>,
 }
 
+/// <p>The default license for the Amazon Chime account.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum License {
+    /// <p><code>Basic</code></p>
+    Basic,
+    /// <p><code>Plus</code></p>
+    Plus,
+    /// <p><code>Pro</code></p>
+    Pro,
+    /// <p><code>ProTrial</code></p>
+    ProTrial,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl License {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            License::Basic => "Basic",
+            License::Plus => "Plus",
+            License::Pro => "Pro",
+            License::ProTrial => "ProTrial",
+            License::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for License {
+    fn from(value: &'a str) -> License {
+        match value {
+            "Basic" => License::Basic,
+            "Plus" => License::Plus,
+            "Pro" => License::Pro,
+            "ProTrial" => License::ProTrial,
+            _ => License::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for License {
+    fn from(value: String) -> License {
+        License::from(value.as_str())
+    }
+}
+
+impl From<License> for String {
+    fn from(value: License) -> String {
+        match value {
+            License::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for License {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for License {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for License {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for License {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<License, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(License::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListAccountsRequest {
     /// <p>The maximum number of results to return in a single call. Defaults to 100.</p>
@@ -725,6 +2510,58 @@ pub struct ListAccountsRequest {
     pub user_email: Option<String>,
 }
 
+impl ListAccountsRequest {
+    /// Returns a builder for `ListAccountsRequest`, checking its required members when built.
+    pub fn builder() -> ListAccountsRequestBuilder {
+        ListAccountsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAccountsRequest`](struct.ListAccountsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAccountsRequestBuilder {
+    max_results: Option<i64>,
+    name: Option<String>,
+    next_token: Option<String>,
+    user_email: Option<String>,
+}
+
+impl ListAccountsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListAccountsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.ListAccountsRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAccountsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_email`](struct.ListAccountsRequest.html#structfield.user_email) member.
+    pub fn user_email<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_email = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAccountsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListAccountsRequest, ::rusoto_core::BuildError> {
+        Ok(ListAccountsRequest {
+            max_results: self.max_results,
+            name: self.name,
+            next_token: self.next_token,
+            user_email: self.user_email,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAccountsResponse {
@@ -753,6 +2590,52 @@ pub struct ListBotsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListBotsRequest {
+    /// Returns a builder for `ListBotsRequest`, checking its required members when built.
+    pub fn builder() -> ListBotsRequestBuilder {
+        ListBotsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListBotsRequest`](struct.ListBotsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListBotsRequestBuilder {
+    account_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListBotsRequestBuilder {
+    /// Sets the [`account_id`](struct.ListBotsRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListBotsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListBotsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBotsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListBotsRequest, ::rusoto_core::BuildError> {
+        Ok(ListBotsRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListBotsResponse {
@@ -778,6 +2661,44 @@ pub struct ListPhoneNumberOrdersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListPhoneNumberOrdersRequest {
+    /// Returns a builder for `ListPhoneNumberOrdersRequest`, checking its required members when built.
+    pub fn builder() -> ListPhoneNumberOrdersRequestBuilder {
+        ListPhoneNumberOrdersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPhoneNumberOrdersRequest`](struct.ListPhoneNumberOrdersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPhoneNumberOrdersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListPhoneNumberOrdersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListPhoneNumberOrdersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPhoneNumberOrdersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPhoneNumberOrdersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPhoneNumberOrdersRequest, ::rusoto_core::BuildError> {
+        Ok(ListPhoneNumberOrdersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPhoneNumberOrdersResponse {
@@ -796,7 +2717,7 @@ pub struct ListPhoneNumbersRequest {
     /// <p>The filter to use to limit the number of results.</p>
     #[serde(rename = "FilterName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_name: Option<String>,
+    pub filter_name: Option<PhoneNumberAssociationName>,
     /// <p>The value to use for the filter.</p>
     #[serde(rename = "FilterValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -812,11 +2733,81 @@ pub struct ListPhoneNumbersRequest {
     /// <p>The phone number product type.</p>
     #[serde(rename = "ProductType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
+    pub product_type: Option<PhoneNumberProductType>,
     /// <p>The phone number status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PhoneNumberStatus>,
+}
+
+impl ListPhoneNumbersRequest {
+    /// Returns a builder for `ListPhoneNumbersRequest`, checking its required members when built.
+    pub fn builder() -> ListPhoneNumbersRequestBuilder {
+        ListPhoneNumbersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPhoneNumbersRequest`](struct.ListPhoneNumbersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPhoneNumbersRequestBuilder {
+    filter_name: Option<PhoneNumberAssociationName>,
+    filter_value: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    product_type: Option<PhoneNumberProductType>,
+    status: Option<PhoneNumberStatus>,
+}
+
+impl ListPhoneNumbersRequestBuilder {
+    /// Sets the [`filter_name`](struct.ListPhoneNumbersRequest.html#structfield.filter_name) member.
+    pub fn filter_name<T: Into<PhoneNumberAssociationName>>(mut self, value: T) -> Self {
+        self.filter_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_value`](struct.ListPhoneNumbersRequest.html#structfield.filter_value) member.
+    pub fn filter_value<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListPhoneNumbersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPhoneNumbersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_type`](struct.ListPhoneNumbersRequest.html#structfield.product_type) member.
+    pub fn product_type<T: Into<PhoneNumberProductType>>(mut self, value: T) -> Self {
+        self.product_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListPhoneNumbersRequest.html#structfield.status) member.
+    pub fn status<T: Into<PhoneNumberStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPhoneNumbersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPhoneNumbersRequest, ::rusoto_core::BuildError> {
+        Ok(ListPhoneNumbersRequest {
+            filter_name: self.filter_name,
+            filter_value: self.filter_value,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            product_type: self.product_type,
+            status: self.status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -851,6 +2842,60 @@ pub struct ListUsersRequest {
     pub user_email: Option<String>,
 }
 
+impl ListUsersRequest {
+    /// Returns a builder for `ListUsersRequest`, checking its required members when built.
+    pub fn builder() -> ListUsersRequestBuilder {
+        ListUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUsersRequest`](struct.ListUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUsersRequestBuilder {
+    account_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    user_email: Option<String>,
+}
+
+impl ListUsersRequestBuilder {
+    /// Sets the [`account_id`](struct.ListUsersRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListUsersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUsersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_email`](struct.ListUsersRequest.html#structfield.user_email) member.
+    pub fn user_email<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_email = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListUsersRequest, ::rusoto_core::BuildError> {
+        Ok(ListUsersRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            user_email: self.user_email,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListUsersResponse {
@@ -871,6 +2916,41 @@ pub struct ListVoiceConnectorTerminationCredentialsRequest {
     pub voice_connector_id: String,
 }
 
+impl ListVoiceConnectorTerminationCredentialsRequest {
+    /// Returns a builder for `ListVoiceConnectorTerminationCredentialsRequest`, checking its required members when built.
+    pub fn builder() -> ListVoiceConnectorTerminationCredentialsRequestBuilder {
+        ListVoiceConnectorTerminationCredentialsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListVoiceConnectorTerminationCredentialsRequest`](struct.ListVoiceConnectorTerminationCredentialsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListVoiceConnectorTerminationCredentialsRequestBuilder {
+    voice_connector_id: Option<String>,
+}
+
+impl ListVoiceConnectorTerminationCredentialsRequestBuilder {
+    /// Sets the [`voice_connector_id`](struct.ListVoiceConnectorTerminationCredentialsRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVoiceConnectorTerminationCredentialsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        ListVoiceConnectorTerminationCredentialsRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(ListVoiceConnectorTerminationCredentialsRequest {
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListVoiceConnectorTerminationCredentialsResponse {
@@ -892,6 +2972,44 @@ pub struct ListVoiceConnectorsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListVoiceConnectorsRequest {
+    /// Returns a builder for `ListVoiceConnectorsRequest`, checking its required members when built.
+    pub fn builder() -> ListVoiceConnectorsRequestBuilder {
+        ListVoiceConnectorsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListVoiceConnectorsRequest`](struct.ListVoiceConnectorsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListVoiceConnectorsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListVoiceConnectorsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListVoiceConnectorsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListVoiceConnectorsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVoiceConnectorsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListVoiceConnectorsRequest, ::rusoto_core::BuildError> {
+        Ok(ListVoiceConnectorsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListVoiceConnectorsResponse {
@@ -915,6 +3033,46 @@ pub struct LogoutUserRequest {
     pub user_id: String,
 }
 
+impl LogoutUserRequest {
+    /// Returns a builder for `LogoutUserRequest`, checking its required members when built.
+    pub fn builder() -> LogoutUserRequestBuilder {
+        LogoutUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`LogoutUserRequest`](struct.LogoutUserRequest.html).
+#[derive(Default, Debug)]
+pub struct LogoutUserRequestBuilder {
+    account_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl LogoutUserRequestBuilder {
+    /// Sets the [`account_id`](struct.LogoutUserRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.LogoutUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `LogoutUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<LogoutUserRequest, ::rusoto_core::BuildError> {
+        Ok(LogoutUserRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct LogoutUserResponse {}
@@ -930,30 +3088,112 @@ pub struct OrderedPhoneNumber {
     /// <p>The phone number status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OrderedPhoneNumberStatus>,
 }
 
-/// <p>Origination settings enable your SIP hosts to receive inbound calls using your Amazon Chime Voice Connector.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Origination {
-    /// <p>When origination settings are disabled, inbound calls are not enabled for your Amazon Chime Voice Connector.</p>
-    #[serde(rename = "Disabled")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled: Option<bool>,
-    /// <p>The call distribution properties defined for your SIP hosts. Valid range: Minimum value of 1. Maximum value of 20.</p>
-    #[serde(rename = "Routes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub routes: Option<Vec<OriginationRoute>>,
+/// <p>The phone number status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrderedPhoneNumberStatus {
+    /// <p><code>Processing</code></p>
+    Processing,
+    /// <p><code>Acquired</code></p>
+    Acquired,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Origination routes define call distribution properties for your SIP hosts to receive inbound calls using your Amazon Chime Voice Connector. Limit: 10 origination routes per Amazon Chime Voice Connector.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct OriginationRoute {
-    /// <p>The FODN or IP address to contact for origination traffic.</p>
-    #[serde(rename = "Host")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub host: Option<String>,
-    /// <p>The designated origination route port. Defaults to 5060.</p>
+impl OrderedPhoneNumberStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderedPhoneNumberStatus::Processing => "Processing",
+            OrderedPhoneNumberStatus::Acquired => "Acquired",
+            OrderedPhoneNumberStatus::Failed => "Failed",
+            OrderedPhoneNumberStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OrderedPhoneNumberStatus {
+    fn from(value: &'a str) -> OrderedPhoneNumberStatus {
+        match value {
+            "Processing" => OrderedPhoneNumberStatus::Processing,
+            "Acquired" => OrderedPhoneNumberStatus::Acquired,
+            "Failed" => OrderedPhoneNumberStatus::Failed,
+            _ => OrderedPhoneNumberStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderedPhoneNumberStatus {
+    fn from(value: String) -> OrderedPhoneNumberStatus {
+        OrderedPhoneNumberStatus::from(value.as_str())
+    }
+}
+
+impl From<OrderedPhoneNumberStatus> for String {
+    fn from(value: OrderedPhoneNumberStatus) -> String {
+        match value {
+            OrderedPhoneNumberStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OrderedPhoneNumberStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrderedPhoneNumberStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrderedPhoneNumberStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderedPhoneNumberStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OrderedPhoneNumberStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrderedPhoneNumberStatus::from(value))
+    }
+}
+
+/// <p>Origination settings enable your SIP hosts to receive inbound calls using your Amazon Chime Voice Connector.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Origination {
+    /// <p>When origination settings are disabled, inbound calls are not enabled for your Amazon Chime Voice Connector.</p>
+    #[serde(rename = "Disabled")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    /// <p>The call distribution properties defined for your SIP hosts. Valid range: Minimum value of 1. Maximum value of 20.</p>
+    #[serde(rename = "Routes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routes: Option<Vec<OriginationRoute>>,
+}
+
+/// <p>Origination routes define call distribution properties for your SIP hosts to receive inbound calls using your Amazon Chime Voice Connector. Limit: 10 origination routes per Amazon Chime Voice Connector.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OriginationRoute {
+    /// <p>The FODN or IP address to contact for origination traffic.</p>
+    #[serde(rename = "Host")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// <p>The designated origination route port. Defaults to 5060.</p>
     #[serde(rename = "Port")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub port: Option<i64>,
@@ -964,13 +3204,91 @@ pub struct OriginationRoute {
     /// <p>The protocol to use for the origination route. Encryption-enabled Amazon Chime Voice Connectors use TCP protocol by default.</p>
     #[serde(rename = "Protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<OriginationRouteProtocol>,
     /// <p>The weight associated with the host. If hosts are equal in priority, calls are distributed among them based on their relative weight.</p>
     #[serde(rename = "Weight")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub weight: Option<i64>,
 }
 
+/// <p>The protocol to use for the origination route. Encryption-enabled Amazon Chime Voice Connectors use TCP protocol by default.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OriginationRouteProtocol {
+    /// <p><code>TCP</code></p>
+    Tcp,
+    /// <p><code>UDP</code></p>
+    Udp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OriginationRouteProtocol {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OriginationRouteProtocol::Tcp => "TCP",
+            OriginationRouteProtocol::Udp => "UDP",
+            OriginationRouteProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OriginationRouteProtocol {
+    fn from(value: &'a str) -> OriginationRouteProtocol {
+        match value {
+            "TCP" => OriginationRouteProtocol::Tcp,
+            "UDP" => OriginationRouteProtocol::Udp,
+            _ => OriginationRouteProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OriginationRouteProtocol {
+    fn from(value: String) -> OriginationRouteProtocol {
+        OriginationRouteProtocol::from(value.as_str())
+    }
+}
+
+impl From<OriginationRouteProtocol> for String {
+    fn from(value: OriginationRouteProtocol) -> String {
+        match value {
+            OriginationRouteProtocol::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OriginationRouteProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OriginationRouteProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OriginationRouteProtocol {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OriginationRouteProtocol {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OriginationRouteProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OriginationRouteProtocol::from(value))
+    }
+}
+
 /// <p>A phone number used for Amazon Chime Business Calling or an Amazon Chime Voice Connector.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -985,12 +3303,14 @@ pub struct PhoneNumber {
     pub capabilities: Option<PhoneNumberCapabilities>,
     /// <p>The phone number creation timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The deleted phone number timestamp, in ISO 8601 format.</p>
     #[serde(rename = "DeletionTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_timestamp: Option<f64>,
+    pub deletion_timestamp: Option<EpochTimestamp>,
     /// <p>The phone number, in E.164 format.</p>
     #[serde(rename = "E164PhoneNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1002,19 +3322,20 @@ pub struct PhoneNumber {
     /// <p>The phone number product type.</p>
     #[serde(rename = "ProductType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
+    pub product_type: Option<PhoneNumberProductType>,
     /// <p>The phone number status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PhoneNumberStatus>,
     /// <p>The phone number type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<PhoneNumberType>,
     /// <p>The updated phone number timestamp, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<f64>,
+    pub updated_timestamp: Option<EpochTimestamp>,
 }
 
 /// <p>The phone number associations, such as Amazon Chime account ID, Amazon Chime user ID, or Amazon Chime Voice Connector ID.</p>
@@ -1023,18 +3344,111 @@ pub struct PhoneNumber {
 pub struct PhoneNumberAssociation {
     /// <p>The timestamp of the phone number association, in ISO 8601 format.</p>
     #[serde(rename = "AssociatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub associated_timestamp: Option<f64>,
+    pub associated_timestamp: Option<EpochTimestamp>,
     /// <p>Defines the association with an Amazon Chime account ID, user ID, or Amazon Chime Voice Connector ID.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<PhoneNumberAssociationName>,
     /// <p>Contains the ID for the entity specified in Name.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+/// <p>The filter to use to limit the number of results.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PhoneNumberAssociationName {
+    /// <p><code>AccountId</code></p>
+    AccountId,
+    /// <p><code>UserId</code></p>
+    UserId,
+    /// <p><code>VoiceConnectorId</code></p>
+    VoiceConnectorId,
+    /// <p><code>VoiceConnectorGroupId</code></p>
+    VoiceConnectorGroupId,
+    /// <p><code>SipRuleId</code></p>
+    SipRuleId,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PhoneNumberAssociationName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberAssociationName::AccountId => "AccountId",
+            PhoneNumberAssociationName::UserId => "UserId",
+            PhoneNumberAssociationName::VoiceConnectorId => "VoiceConnectorId",
+            PhoneNumberAssociationName::VoiceConnectorGroupId => "VoiceConnectorGroupId",
+            PhoneNumberAssociationName::SipRuleId => "SipRuleId",
+            PhoneNumberAssociationName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PhoneNumberAssociationName {
+    fn from(value: &'a str) -> PhoneNumberAssociationName {
+        match value {
+            "AccountId" => PhoneNumberAssociationName::AccountId,
+            "UserId" => PhoneNumberAssociationName::UserId,
+            "VoiceConnectorId" => PhoneNumberAssociationName::VoiceConnectorId,
+            "VoiceConnectorGroupId" => PhoneNumberAssociationName::VoiceConnectorGroupId,
+            "SipRuleId" => PhoneNumberAssociationName::SipRuleId,
+            _ => PhoneNumberAssociationName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneNumberAssociationName {
+    fn from(value: String) -> PhoneNumberAssociationName {
+        PhoneNumberAssociationName::from(value.as_str())
+    }
+}
+
+impl From<PhoneNumberAssociationName> for String {
+    fn from(value: PhoneNumberAssociationName) -> String {
+        match value {
+            PhoneNumberAssociationName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PhoneNumberAssociationName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PhoneNumberAssociationName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PhoneNumberAssociationName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberAssociationName {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<PhoneNumberAssociationName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PhoneNumberAssociationName::from(value))
+    }
+}
+
 /// <p>The phone number capabilities, such as enabled inbound and outbound calling and text messaging.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1072,7 +3486,7 @@ pub struct PhoneNumberError {
     /// <p>The error code.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>The error message.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1089,8 +3503,9 @@ pub struct PhoneNumberError {
 pub struct PhoneNumberOrder {
     /// <p>The phone number order creation timestamp, in ISO 8601 format.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The ordered phone number details, such as the phone number in E.164 format and the phone number status.</p>
     #[serde(rename = "OrderedPhoneNumbers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1102,1340 +3517,1704 @@ pub struct PhoneNumberOrder {
     /// <p>The phone number order product type.</p>
     #[serde(rename = "ProductType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
+    pub product_type: Option<PhoneNumberProductType>,
     /// <p>The status of the phone number order.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PhoneNumberOrderStatus>,
     /// <p>The updated phone number order timestamp, in ISO 8601 format.</p>
     #[serde(rename = "UpdatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<f64>,
+    pub updated_timestamp: Option<EpochTimestamp>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct PutEventsConfigurationRequest {
-    /// <p>The Amazon Chime account ID.</p>
-    #[serde(rename = "AccountId")]
-    pub account_id: String,
-    /// <p>The bot ID.</p>
-    #[serde(rename = "BotId")]
-    pub bot_id: String,
-    /// <p>Lambda function ARN that allows the bot to receive outgoing events.</p>
-    #[serde(rename = "LambdaFunctionArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub lambda_function_arn: Option<String>,
-    /// <p>HTTPS endpoint that allows the bot to receive outgoing events.</p>
-    #[serde(rename = "OutboundEventsHTTPSEndpoint")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub outbound_events_https_endpoint: Option<String>,
+/// <p>The status of the phone number order.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PhoneNumberOrderStatus {
+    /// <p><code>Processing</code></p>
+    Processing,
+    /// <p><code>Successful</code></p>
+    Successful,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Partial</code></p>
+    Partial,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct PutEventsConfigurationResponse {
-    #[serde(rename = "EventsConfiguration")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub events_configuration: Option<EventsConfiguration>,
+impl PhoneNumberOrderStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberOrderStatus::Processing => "Processing",
+            PhoneNumberOrderStatus::Successful => "Successful",
+            PhoneNumberOrderStatus::Failed => "Failed",
+            PhoneNumberOrderStatus::Partial => "Partial",
+            PhoneNumberOrderStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct PutVoiceConnectorOriginationRequest {
-    /// <p>The origination setting details to add.</p>
-    #[serde(rename = "Origination")]
-    pub origination: Origination,
-    /// <p>The Amazon Chime Voice Connector ID.</p>
-    #[serde(rename = "VoiceConnectorId")]
-    pub voice_connector_id: String,
+impl<'a> From<&'a str> for PhoneNumberOrderStatus {
+    fn from(value: &'a str) -> PhoneNumberOrderStatus {
+        match value {
+            "Processing" => PhoneNumberOrderStatus::Processing,
+            "Successful" => PhoneNumberOrderStatus::Successful,
+            "Failed" => PhoneNumberOrderStatus::Failed,
+            "Partial" => PhoneNumberOrderStatus::Partial,
+            _ => PhoneNumberOrderStatus::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct PutVoiceConnectorOriginationResponse {
-    /// <p>The updated origination setting details.</p>
-    #[serde(rename = "Origination")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub origination: Option<Origination>,
+impl From<String> for PhoneNumberOrderStatus {
+    fn from(value: String) -> PhoneNumberOrderStatus {
+        PhoneNumberOrderStatus::from(value.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct PutVoiceConnectorTerminationCredentialsRequest {
-    /// <p>The termination SIP credentials.</p>
-    #[serde(rename = "Credentials")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub credentials: Option<Vec<Credential>>,
-    /// <p>The Amazon Chime Voice Connector ID.</p>
-    #[serde(rename = "VoiceConnectorId")]
-    pub voice_connector_id: String,
+impl From<PhoneNumberOrderStatus> for String {
+    fn from(value: PhoneNumberOrderStatus) -> String {
+        match value {
+            PhoneNumberOrderStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct PutVoiceConnectorTerminationRequest {
-    /// <p>The termination setting details to add.</p>
-    #[serde(rename = "Termination")]
-    pub termination: Termination,
-    /// <p>The Amazon Chime Voice Connector ID.</p>
-    #[serde(rename = "VoiceConnectorId")]
-    pub voice_connector_id: String,
+impl fmt::Display for PhoneNumberOrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct PutVoiceConnectorTerminationResponse {
-    /// <p>The updated termination setting details.</p>
-    #[serde(rename = "Termination")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub termination: Option<Termination>,
+impl ::rusoto_core::param::ToParam for PhoneNumberOrderStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct RegenerateSecurityTokenRequest {
-    /// <p>The Amazon Chime account ID.</p>
-    #[serde(rename = "AccountId")]
-    pub account_id: String,
-    /// <p>The bot ID.</p>
-    #[serde(rename = "BotId")]
-    pub bot_id: String,
+impl ::serde::Serialize for PhoneNumberOrderStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct RegenerateSecurityTokenResponse {
-    #[serde(rename = "Bot")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bot: Option<Bot>,
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberOrderStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PhoneNumberOrderStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PhoneNumberOrderStatus::from(value))
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ResetPersonalPINRequest {
-    /// <p>The Amazon Chime account ID.</p>
-    #[serde(rename = "AccountId")]
-    pub account_id: String,
-    /// <p>The user ID.</p>
-    #[serde(rename = "UserId")]
-    pub user_id: String,
+/// <p>The phone number product type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PhoneNumberProductType {
+    /// <p><code>BusinessCalling</code></p>
+    BusinessCalling,
+    /// <p><code>VoiceConnector</code></p>
+    VoiceConnector,
+    /// <p><code>SipMediaApplicationDialIn</code></p>
+    SipMediaApplicationDialIn,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ResetPersonalPINResponse {
-    /// <p>The user details and new personal meeting PIN.</p>
-    #[serde(rename = "User")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user: Option<User>,
+impl PhoneNumberProductType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberProductType::BusinessCalling => "BusinessCalling",
+            PhoneNumberProductType::VoiceConnector => "VoiceConnector",
+            PhoneNumberProductType::SipMediaApplicationDialIn => "SipMediaApplicationDialIn",
+            PhoneNumberProductType::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct RestorePhoneNumberRequest {
-    /// <p>The phone number.</p>
-    #[serde(rename = "PhoneNumberId")]
-    pub phone_number_id: String,
+impl<'a> From<&'a str> for PhoneNumberProductType {
+    fn from(value: &'a str) -> PhoneNumberProductType {
+        match value {
+            "BusinessCalling" => PhoneNumberProductType::BusinessCalling,
+            "VoiceConnector" => PhoneNumberProductType::VoiceConnector,
+            "SipMediaApplicationDialIn" => PhoneNumberProductType::SipMediaApplicationDialIn,
+            _ => PhoneNumberProductType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct RestorePhoneNumberResponse {
-    /// <p>The phone number details.</p>
-    #[serde(rename = "PhoneNumber")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_number: Option<PhoneNumber>,
+impl From<String> for PhoneNumberProductType {
+    fn from(value: String) -> PhoneNumberProductType {
+        PhoneNumberProductType::from(value.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct SearchAvailablePhoneNumbersRequest {
-    /// <p>The area code used to filter results.</p>
-    #[serde(rename = "AreaCode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub area_code: Option<String>,
-    /// <p>The city used to filter results.</p>
-    #[serde(rename = "City")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub city: Option<String>,
-    /// <p>The country used to filter results.</p>
-    #[serde(rename = "Country")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub country: Option<String>,
-    /// <p>The maximum number of results to return in a single call.</p>
-    #[serde(rename = "MaxResults")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_results: Option<i64>,
-    /// <p>The token to use to retrieve the next page of results.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>The state used to filter results.</p>
-    #[serde(rename = "State")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
-    /// <p>The toll-free prefix that you use to filter results.</p>
-    #[serde(rename = "TollFreePrefix")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub toll_free_prefix: Option<String>,
+impl From<PhoneNumberProductType> for String {
+    fn from(value: PhoneNumberProductType) -> String {
+        match value {
+            PhoneNumberProductType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct SearchAvailablePhoneNumbersResponse {
-    /// <p>List of phone numbers, in E.164 format.</p>
-    #[serde(rename = "E164PhoneNumbers")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub e164_phone_numbers: Option<Vec<String>>,
+impl fmt::Display for PhoneNumberProductType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
-/// <p>Settings that allow management of telephony permissions for an Amazon Chime user, such as inbound and outbound calling and text messaging.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct TelephonySettings {
-    /// <p>Allows or denies inbound calling.</p>
-    #[serde(rename = "InboundCalling")]
-    pub inbound_calling: bool,
-    /// <p>Allows or denies outbound calling.</p>
-    #[serde(rename = "OutboundCalling")]
-    pub outbound_calling: bool,
-    /// <p>Allows or denies SMS messaging.</p>
-    #[serde(rename = "SMS")]
-    pub sms: bool,
+impl ::rusoto_core::param::ToParam for PhoneNumberProductType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>Termination settings enable your SIP hosts to make outbound calls using your Amazon Chime Voice Connector.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Termination {
-    /// <p>The countries to which calls are allowed.</p>
-    #[serde(rename = "CallingRegions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub calling_regions: Option<Vec<String>>,
-    /// <p>The IP addresses allowed to make calls, in CIDR format.</p>
-    #[serde(rename = "CidrAllowedList")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cidr_allowed_list: Option<Vec<String>>,
-    /// <p>The limit on calls per second. Max value based on account service limit. Default value of 1.</p>
-    #[serde(rename = "CpsLimit")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cps_limit: Option<i64>,
-    /// <p>The default caller ID phone number.</p>
-    #[serde(rename = "DefaultPhoneNumber")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_phone_number: Option<String>,
-    /// <p>When termination settings are disabled, outbound calls can not be made.</p>
-    #[serde(rename = "Disabled")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled: Option<bool>,
+impl ::serde::Serialize for PhoneNumberProductType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-/// <p>The termination health details, including the source IP address and timestamp of the last successful SIP <code>OPTIONS</code> message from your SIP infrastructure.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct TerminationHealth {
-    /// <p>The source IP address.</p>
-    #[serde(rename = "Source")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<String>,
-    /// <p>The timestamp, in ISO 8601 format.</p>
-    #[serde(rename = "Timestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberProductType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PhoneNumberProductType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PhoneNumberProductType::from(value))
+    }
+}
+
+/// <p>The phone number status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PhoneNumberStatus {
+    /// <p><code>AcquireInProgress</code></p>
+    AcquireInProgress,
+    /// <p><code>AcquireFailed</code></p>
+    AcquireFailed,
+    /// <p><code>Unassigned</code></p>
+    Unassigned,
+    /// <p><code>Assigned</code></p>
+    Assigned,
+    /// <p><code>ReleaseInProgress</code></p>
+    ReleaseInProgress,
+    /// <p><code>DeleteInProgress</code></p>
+    DeleteInProgress,
+    /// <p><code>ReleaseFailed</code></p>
+    ReleaseFailed,
+    /// <p><code>DeleteFailed</code></p>
+    DeleteFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PhoneNumberStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberStatus::AcquireInProgress => "AcquireInProgress",
+            PhoneNumberStatus::AcquireFailed => "AcquireFailed",
+            PhoneNumberStatus::Unassigned => "Unassigned",
+            PhoneNumberStatus::Assigned => "Assigned",
+            PhoneNumberStatus::ReleaseInProgress => "ReleaseInProgress",
+            PhoneNumberStatus::DeleteInProgress => "DeleteInProgress",
+            PhoneNumberStatus::ReleaseFailed => "ReleaseFailed",
+            PhoneNumberStatus::DeleteFailed => "DeleteFailed",
+            PhoneNumberStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateAccountRequest {
-    /// <p>The Amazon Chime account ID.</p>
-    #[serde(rename = "AccountId")]
-    pub account_id: String,
-    /// <p>The new name for the specified Amazon Chime account.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+impl<'a> From<&'a str> for PhoneNumberStatus {
+    fn from(value: &'a str) -> PhoneNumberStatus {
+        match value {
+            "AcquireInProgress" => PhoneNumberStatus::AcquireInProgress,
+            "AcquireFailed" => PhoneNumberStatus::AcquireFailed,
+            "Unassigned" => PhoneNumberStatus::Unassigned,
+            "Assigned" => PhoneNumberStatus::Assigned,
+            "ReleaseInProgress" => PhoneNumberStatus::ReleaseInProgress,
+            "DeleteInProgress" => PhoneNumberStatus::DeleteInProgress,
+            "ReleaseFailed" => PhoneNumberStatus::ReleaseFailed,
+            "DeleteFailed" => PhoneNumberStatus::DeleteFailed,
+            _ => PhoneNumberStatus::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateAccountResponse {
-    /// <p>The updated Amazon Chime account details.</p>
-    #[serde(rename = "Account")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub account: Option<Account>,
+impl From<String> for PhoneNumberStatus {
+    fn from(value: String) -> PhoneNumberStatus {
+        PhoneNumberStatus::from(value.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateAccountSettingsRequest {
-    /// <p>The Amazon Chime account ID.</p>
-    #[serde(rename = "AccountId")]
-    pub account_id: String,
-    /// <p>The Amazon Chime account settings to update.</p>
-    #[serde(rename = "AccountSettings")]
-    pub account_settings: AccountSettings,
+impl From<PhoneNumberStatus> for String {
+    fn from(value: PhoneNumberStatus) -> String {
+        match value {
+            PhoneNumberStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateAccountSettingsResponse {}
+impl fmt::Display for PhoneNumberStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PhoneNumberStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PhoneNumberStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PhoneNumberStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PhoneNumberStatus::from(value))
+    }
+}
+
+/// <p>The phone number type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PhoneNumberType {
+    /// <p><code>Local</code></p>
+    Local,
+    /// <p><code>TollFree</code></p>
+    TollFree,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PhoneNumberType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneNumberType::Local => "Local",
+            PhoneNumberType::TollFree => "TollFree",
+            PhoneNumberType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PhoneNumberType {
+    fn from(value: &'a str) -> PhoneNumberType {
+        match value {
+            "Local" => PhoneNumberType::Local,
+            "TollFree" => PhoneNumberType::TollFree,
+            _ => PhoneNumberType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneNumberType {
+    fn from(value: String) -> PhoneNumberType {
+        PhoneNumberType::from(value.as_str())
+    }
+}
+
+impl From<PhoneNumberType> for String {
+    fn from(value: PhoneNumberType) -> String {
+        match value {
+            PhoneNumberType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PhoneNumberType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PhoneNumberType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PhoneNumberType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PhoneNumberType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PhoneNumberType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PhoneNumberType::from(value))
+    }
+}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateBotRequest {
+pub struct PutEventsConfigurationRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
     pub account_id: String,
     /// <p>The bot ID.</p>
     #[serde(rename = "BotId")]
     pub bot_id: String,
-    /// <p>When true, stops the specified bot from running in your account.</p>
-    #[serde(rename = "Disabled")]
+    /// <p>Lambda function ARN that allows the bot to receive outgoing events.</p>
+    #[serde(rename = "LambdaFunctionArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub disabled: Option<bool>,
+    pub lambda_function_arn: Option<String>,
+    /// <p>HTTPS endpoint that allows the bot to receive outgoing events.</p>
+    #[serde(rename = "OutboundEventsHTTPSEndpoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_events_https_endpoint: Option<String>,
+}
+
+impl PutEventsConfigurationRequest {
+    /// Returns a builder for `PutEventsConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> PutEventsConfigurationRequestBuilder {
+        PutEventsConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutEventsConfigurationRequest`](struct.PutEventsConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutEventsConfigurationRequestBuilder {
+    account_id: Option<String>,
+    bot_id: Option<String>,
+    lambda_function_arn: Option<String>,
+    outbound_events_https_endpoint: Option<String>,
+}
+
+impl PutEventsConfigurationRequestBuilder {
+    /// Sets the [`account_id`](struct.PutEventsConfigurationRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_id`](struct.PutEventsConfigurationRequest.html#structfield.bot_id) member.
+    pub fn bot_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lambda_function_arn`](struct.PutEventsConfigurationRequest.html#structfield.lambda_function_arn) member.
+    pub fn lambda_function_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.lambda_function_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`outbound_events_https_endpoint`](struct.PutEventsConfigurationRequest.html#structfield.outbound_events_https_endpoint) member.
+    pub fn outbound_events_https_endpoint<T: Into<String>>(mut self, value: T) -> Self {
+        self.outbound_events_https_endpoint = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutEventsConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutEventsConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(PutEventsConfigurationRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            bot_id: self
+                .bot_id
+                .ok_or(::rusoto_core::BuildError::missing("bot_id"))?,
+            lambda_function_arn: self.lambda_function_arn,
+            outbound_events_https_endpoint: self.outbound_events_https_endpoint,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
-pub struct UpdateBotResponse {
-    /// <p>The updated bot details.</p>
-    #[serde(rename = "Bot")]
+pub struct PutEventsConfigurationResponse {
+    #[serde(rename = "EventsConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bot: Option<Bot>,
+    pub events_configuration: Option<EventsConfiguration>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateGlobalSettingsRequest {
-    /// <p>The Amazon Chime Business Calling settings.</p>
-    #[serde(rename = "BusinessCalling")]
-    pub business_calling: BusinessCallingSettings,
-    /// <p>The Amazon Chime Voice Connector settings.</p>
-    #[serde(rename = "VoiceConnector")]
-    pub voice_connector: VoiceConnectorSettings,
+pub struct PutVoiceConnectorOriginationRequest {
+    /// <p>The origination setting details to add.</p>
+    #[serde(rename = "Origination")]
+    pub origination: Origination,
+    /// <p>The Amazon Chime Voice Connector ID.</p>
+    #[serde(rename = "VoiceConnectorId")]
+    pub voice_connector_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdatePhoneNumberRequest {
-    /// <p>The phone number ID.</p>
-    #[serde(rename = "PhoneNumberId")]
-    pub phone_number_id: String,
-    /// <p>The product type.</p>
-    #[serde(rename = "ProductType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
+impl PutVoiceConnectorOriginationRequest {
+    /// Returns a builder for `PutVoiceConnectorOriginationRequest`, checking its required members when built.
+    pub fn builder() -> PutVoiceConnectorOriginationRequestBuilder {
+        PutVoiceConnectorOriginationRequestBuilder::default()
+    }
 }
 
-/// <p>The phone number ID and product type fields to update, used with the <a>BatchUpdatePhoneNumber</a> and <a>UpdatePhoneNumber</a> actions.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdatePhoneNumberRequestItem {
-    /// <p>The phone number ID to update.</p>
-    #[serde(rename = "PhoneNumberId")]
-    pub phone_number_id: String,
-    /// <p>The product type to update.</p>
-    #[serde(rename = "ProductType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub product_type: Option<String>,
+/// Builds a [`PutVoiceConnectorOriginationRequest`](struct.PutVoiceConnectorOriginationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutVoiceConnectorOriginationRequestBuilder {
+    origination: Option<Origination>,
+    voice_connector_id: Option<String>,
+}
+
+impl PutVoiceConnectorOriginationRequestBuilder {
+    /// Sets the [`origination`](struct.PutVoiceConnectorOriginationRequest.html#structfield.origination) member.
+    pub fn origination<T: Into<Origination>>(mut self, value: T) -> Self {
+        self.origination = Some(value.into());
+        self
+    }
+
+    /// Sets the [`voice_connector_id`](struct.PutVoiceConnectorOriginationRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutVoiceConnectorOriginationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutVoiceConnectorOriginationRequest, ::rusoto_core::BuildError> {
+        Ok(PutVoiceConnectorOriginationRequest {
+            origination: self
+                .origination
+                .ok_or(::rusoto_core::BuildError::missing("origination"))?,
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
-pub struct UpdatePhoneNumberResponse {
-    /// <p>The updated phone number details.</p>
-    #[serde(rename = "PhoneNumber")]
+pub struct PutVoiceConnectorOriginationResponse {
+    /// <p>The updated origination setting details.</p>
+    #[serde(rename = "Origination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub phone_number: Option<PhoneNumber>,
+    pub origination: Option<Origination>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateUserRequest {
-    /// <p>The Amazon Chime account ID.</p>
-    #[serde(rename = "AccountId")]
-    pub account_id: String,
-    /// <p>The user license type to update. This must be a supported license type for the Amazon Chime account that the user belongs to.</p>
-    #[serde(rename = "LicenseType")]
+pub struct PutVoiceConnectorTerminationCredentialsRequest {
+    /// <p>The termination SIP credentials.</p>
+    #[serde(rename = "Credentials")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
-    /// <p>The user ID.</p>
-    #[serde(rename = "UserId")]
-    pub user_id: String,
+    pub credentials: Option<Vec<Credential>>,
+    /// <p>The Amazon Chime Voice Connector ID.</p>
+    #[serde(rename = "VoiceConnectorId")]
+    pub voice_connector_id: String,
+}
+
+impl PutVoiceConnectorTerminationCredentialsRequest {
+    /// Returns a builder for `PutVoiceConnectorTerminationCredentialsRequest`, checking its required members when built.
+    pub fn builder() -> PutVoiceConnectorTerminationCredentialsRequestBuilder {
+        PutVoiceConnectorTerminationCredentialsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutVoiceConnectorTerminationCredentialsRequest`](struct.PutVoiceConnectorTerminationCredentialsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutVoiceConnectorTerminationCredentialsRequestBuilder {
+    credentials: Option<Vec<Credential>>,
+    voice_connector_id: Option<String>,
+}
+
+impl PutVoiceConnectorTerminationCredentialsRequestBuilder {
+    /// Sets the [`credentials`](struct.PutVoiceConnectorTerminationCredentialsRequest.html#structfield.credentials) member.
+    pub fn credentials<T: Into<Vec<Credential>>>(mut self, value: T) -> Self {
+        self.credentials = Some(value.into());
+        self
+    }
+
+    /// Sets the [`voice_connector_id`](struct.PutVoiceConnectorTerminationCredentialsRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutVoiceConnectorTerminationCredentialsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        PutVoiceConnectorTerminationCredentialsRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(PutVoiceConnectorTerminationCredentialsRequest {
+            credentials: self.credentials,
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
 }
 
-/// <p>The user ID and user fields to update, used with the <a>BatchUpdateUser</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateUserRequestItem {
-    /// <p>The user license type.</p>
-    #[serde(rename = "LicenseType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
-    /// <p>The user ID.</p>
-    #[serde(rename = "UserId")]
-    pub user_id: String,
+pub struct PutVoiceConnectorTerminationRequest {
+    /// <p>The termination setting details to add.</p>
+    #[serde(rename = "Termination")]
+    pub termination: Termination,
+    /// <p>The Amazon Chime Voice Connector ID.</p>
+    #[serde(rename = "VoiceConnectorId")]
+    pub voice_connector_id: String,
+}
+
+impl PutVoiceConnectorTerminationRequest {
+    /// Returns a builder for `PutVoiceConnectorTerminationRequest`, checking its required members when built.
+    pub fn builder() -> PutVoiceConnectorTerminationRequestBuilder {
+        PutVoiceConnectorTerminationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutVoiceConnectorTerminationRequest`](struct.PutVoiceConnectorTerminationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutVoiceConnectorTerminationRequestBuilder {
+    termination: Option<Termination>,
+    voice_connector_id: Option<String>,
+}
+
+impl PutVoiceConnectorTerminationRequestBuilder {
+    /// Sets the [`termination`](struct.PutVoiceConnectorTerminationRequest.html#structfield.termination) member.
+    pub fn termination<T: Into<Termination>>(mut self, value: T) -> Self {
+        self.termination = Some(value.into());
+        self
+    }
+
+    /// Sets the [`voice_connector_id`](struct.PutVoiceConnectorTerminationRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutVoiceConnectorTerminationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutVoiceConnectorTerminationRequest, ::rusoto_core::BuildError> {
+        Ok(PutVoiceConnectorTerminationRequest {
+            termination: self
+                .termination
+                .ok_or(::rusoto_core::BuildError::missing("termination"))?,
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
-pub struct UpdateUserResponse {
-    /// <p>The updated user details.</p>
-    #[serde(rename = "User")]
+pub struct PutVoiceConnectorTerminationResponse {
+    /// <p>The updated termination setting details.</p>
+    #[serde(rename = "Termination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user: Option<User>,
+    pub termination: Option<Termination>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateUserSettingsRequest {
+pub struct RegenerateSecurityTokenRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
     pub account_id: String,
-    /// <p>The user ID.</p>
-    #[serde(rename = "UserId")]
-    pub user_id: String,
-    /// <p>The user settings to update.</p>
-    #[serde(rename = "UserSettings")]
-    pub user_settings: UserSettings,
+    /// <p>The bot ID.</p>
+    #[serde(rename = "BotId")]
+    pub bot_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateVoiceConnectorRequest {
-    /// <p>The name of the Amazon Chime Voice Connector.</p>
-    #[serde(rename = "Name")]
-    pub name: String,
-    /// <p>When enabled, requires encryption for the Amazon Chime Voice Connector.</p>
-    #[serde(rename = "RequireEncryption")]
-    pub require_encryption: bool,
-    /// <p>The Amazon Chime Voice Connector ID.</p>
-    #[serde(rename = "VoiceConnectorId")]
-    pub voice_connector_id: String,
+impl RegenerateSecurityTokenRequest {
+    /// Returns a builder for `RegenerateSecurityTokenRequest`, checking its required members when built.
+    pub fn builder() -> RegenerateSecurityTokenRequestBuilder {
+        RegenerateSecurityTokenRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegenerateSecurityTokenRequest`](struct.RegenerateSecurityTokenRequest.html).
+#[derive(Default, Debug)]
+pub struct RegenerateSecurityTokenRequestBuilder {
+    account_id: Option<String>,
+    bot_id: Option<String>,
+}
+
+impl RegenerateSecurityTokenRequestBuilder {
+    /// Sets the [`account_id`](struct.RegenerateSecurityTokenRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_id`](struct.RegenerateSecurityTokenRequest.html#structfield.bot_id) member.
+    pub fn bot_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegenerateSecurityTokenRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegenerateSecurityTokenRequest, ::rusoto_core::BuildError> {
+        Ok(RegenerateSecurityTokenRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            bot_id: self
+                .bot_id
+                .ok_or(::rusoto_core::BuildError::missing("bot_id"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
-pub struct UpdateVoiceConnectorResponse {
-    /// <p>The Amazon Chime Voice Connector details.</p>
-    #[serde(rename = "VoiceConnector")]
+pub struct RegenerateSecurityTokenResponse {
+    #[serde(rename = "Bot")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub voice_connector: Option<VoiceConnector>,
+    pub bot: Option<Bot>,
 }
 
-/// <p>The user on the Amazon Chime account.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct User {
+/// <p>The user registration status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RegistrationStatus {
+    /// <p><code>Unregistered</code></p>
+    Unregistered,
+    /// <p><code>Registered</code></p>
+    Registered,
+    /// <p><code>Suspended</code></p>
+    Suspended,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RegistrationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RegistrationStatus::Unregistered => "Unregistered",
+            RegistrationStatus::Registered => "Registered",
+            RegistrationStatus::Suspended => "Suspended",
+            RegistrationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RegistrationStatus {
+    fn from(value: &'a str) -> RegistrationStatus {
+        match value {
+            "Unregistered" => RegistrationStatus::Unregistered,
+            "Registered" => RegistrationStatus::Registered,
+            "Suspended" => RegistrationStatus::Suspended,
+            _ => RegistrationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RegistrationStatus {
+    fn from(value: String) -> RegistrationStatus {
+        RegistrationStatus::from(value.as_str())
+    }
+}
+
+impl From<RegistrationStatus> for String {
+    fn from(value: RegistrationStatus) -> String {
+        match value {
+            RegistrationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RegistrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RegistrationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RegistrationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RegistrationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RegistrationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RegistrationStatus::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ResetPersonalPINRequest {
     /// <p>The Amazon Chime account ID.</p>
     #[serde(rename = "AccountId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_id: Option<String>,
-    /// <p>The display name of the user.</p>
-    #[serde(rename = "DisplayName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
-    /// <p>Date and time when the user is invited to the Amazon Chime account, in ISO 8601 format.</p>
-    #[serde(rename = "InvitedOn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub invited_on: Option<f64>,
-    /// <p>The license type for the user.</p>
-    #[serde(rename = "LicenseType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_type: Option<String>,
-    /// <p>The user's personal meeting PIN.</p>
-    #[serde(rename = "PersonalPIN")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub personal_pin: Option<String>,
-    /// <p>The primary email address of the user.</p>
-    #[serde(rename = "PrimaryEmail")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub primary_email: Option<String>,
-    /// <p>The primary phone number associated with the user.</p>
-    #[serde(rename = "PrimaryProvisionedNumber")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub primary_provisioned_number: Option<String>,
-    /// <p>Date and time when the user is registered, in ISO 8601 format.</p>
-    #[serde(rename = "RegisteredOn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub registered_on: Option<f64>,
+    pub account_id: String,
     /// <p>The user ID.</p>
     #[serde(rename = "UserId")]
     pub user_id: String,
-    /// <p>The user invite status.</p>
-    #[serde(rename = "UserInvitationStatus")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_invitation_status: Option<String>,
-    /// <p>The user registration status.</p>
-    #[serde(rename = "UserRegistrationStatus")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_registration_status: Option<String>,
 }
 
-/// <p>The list of errors returned when errors are encountered during the <a>BatchSuspendUser</a>, <a>BatchUnsuspendUser</a>, or <a>BatchUpdateUser</a> actions. This includes user IDs, error codes, and error messages.</p>
+impl ResetPersonalPINRequest {
+    /// Returns a builder for `ResetPersonalPINRequest`, checking its required members when built.
+    pub fn builder() -> ResetPersonalPINRequestBuilder {
+        ResetPersonalPINRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ResetPersonalPINRequest`](struct.ResetPersonalPINRequest.html).
+#[derive(Default, Debug)]
+pub struct ResetPersonalPINRequestBuilder {
+    account_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl ResetPersonalPINRequestBuilder {
+    /// Sets the [`account_id`](struct.ResetPersonalPINRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.ResetPersonalPINRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetPersonalPINRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResetPersonalPINRequest, ::rusoto_core::BuildError> {
+        Ok(ResetPersonalPINRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
-pub struct UserError {
-    /// <p>The error code.</p>
-    #[serde(rename = "ErrorCode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
-    /// <p>The error message.</p>
-    #[serde(rename = "ErrorMessage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_message: Option<String>,
-    /// <p>The user ID for which the action failed.</p>
-    #[serde(rename = "UserId")]
+pub struct ResetPersonalPINResponse {
+    /// <p>The user details and new personal meeting PIN.</p>
+    #[serde(rename = "User")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_id: Option<String>,
+    pub user: Option<User>,
 }
 
-/// <p>Settings associated with an Amazon Chime user, including inbound and outbound calling and text messaging.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct UserSettings {
-    /// <p>The telephony settings associated with the user.</p>
-    #[serde(rename = "Telephony")]
-    pub telephony: TelephonySettings,
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct RestorePhoneNumberRequest {
+    /// <p>The phone number.</p>
+    #[serde(rename = "PhoneNumberId")]
+    pub phone_number_id: String,
+}
+
+impl RestorePhoneNumberRequest {
+    /// Returns a builder for `RestorePhoneNumberRequest`, checking its required members when built.
+    pub fn builder() -> RestorePhoneNumberRequestBuilder {
+        RestorePhoneNumberRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RestorePhoneNumberRequest`](struct.RestorePhoneNumberRequest.html).
+#[derive(Default, Debug)]
+pub struct RestorePhoneNumberRequestBuilder {
+    phone_number_id: Option<String>,
+}
+
+impl RestorePhoneNumberRequestBuilder {
+    /// Sets the [`phone_number_id`](struct.RestorePhoneNumberRequest.html#structfield.phone_number_id) member.
+    pub fn phone_number_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.phone_number_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestorePhoneNumberRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestorePhoneNumberRequest, ::rusoto_core::BuildError> {
+        Ok(RestorePhoneNumberRequest {
+            phone_number_id: self
+                .phone_number_id
+                .ok_or(::rusoto_core::BuildError::missing("phone_number_id"))?,
+        })
+    }
 }
 
-/// <p>The Amazon Chime Voice Connector configuration, including outbound host name and encryption settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
-pub struct VoiceConnector {
-    /// <p>The Amazon Chime Voice Connector creation timestamp, in ISO 8601 format.</p>
-    #[serde(rename = "CreatedTimestamp")]
+pub struct RestorePhoneNumberResponse {
+    /// <p>The phone number details.</p>
+    #[serde(rename = "PhoneNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
-    /// <p>The name of the Amazon Chime Voice Connector.</p>
-    #[serde(rename = "Name")]
+    pub phone_number: Option<PhoneNumber>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct SearchAvailablePhoneNumbersRequest {
+    /// <p>The area code used to filter results.</p>
+    #[serde(rename = "AreaCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The outbound host name for the Amazon Chime Voice Connector.</p>
-    #[serde(rename = "OutboundHostName")]
+    pub area_code: Option<String>,
+    /// <p>The city used to filter results.</p>
+    #[serde(rename = "City")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub outbound_host_name: Option<String>,
-    /// <p>Designates whether encryption is required for the Amazon Chime Voice Connector.</p>
-    #[serde(rename = "RequireEncryption")]
+    pub city: Option<String>,
+    /// <p>The country used to filter results.</p>
+    #[serde(rename = "Country")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub require_encryption: Option<bool>,
-    /// <p>The updated Amazon Chime Voice Connector timestamp, in ISO 8601 format.</p>
-    #[serde(rename = "UpdatedTimestamp")]
+    pub country: Option<String>,
+    /// <p>The maximum number of results to return in a single call.</p>
+    #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_timestamp: Option<f64>,
-    /// <p>The Amazon Chime Voice Connector ID.</p>
-    #[serde(rename = "VoiceConnectorId")]
+    pub max_results: Option<i64>,
+    /// <p>The token to use to retrieve the next page of results.</p>
+    #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub voice_connector_id: Option<String>,
+    pub next_token: Option<String>,
+    /// <p>The state used to filter results.</p>
+    #[serde(rename = "State")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// <p>The toll-free prefix that you use to filter results.</p>
+    #[serde(rename = "TollFreePrefix")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub toll_free_prefix: Option<String>,
 }
 
-/// <p>The Amazon Chime Voice Connector settings. Includes any Amazon S3 buckets designated for storing call detail records.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct VoiceConnectorSettings {
-    /// <p>The Amazon S3 bucket designated for call detail record storage.</p>
-    #[serde(rename = "CdrBucket")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cdr_bucket: Option<String>,
+impl SearchAvailablePhoneNumbersRequest {
+    /// Returns a builder for `SearchAvailablePhoneNumbersRequest`, checking its required members when built.
+    pub fn builder() -> SearchAvailablePhoneNumbersRequestBuilder {
+        SearchAvailablePhoneNumbersRequestBuilder::default()
+    }
 }
 
-/// Errors returned by AssociatePhoneNumberWithUser
-#[derive(Debug, PartialEq)]
-pub enum AssociatePhoneNumberWithUserError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
-    Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
-    /// <p>The service encountered an unexpected error.</p>
-    ServiceFailure(String),
-    /// <p>The service is currently unavailable.</p>
-    ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
-    /// <p>The client is not currently authorized to make the request.</p>
-    UnauthorizedClient(String),
+/// Builds a [`SearchAvailablePhoneNumbersRequest`](struct.SearchAvailablePhoneNumbersRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchAvailablePhoneNumbersRequestBuilder {
+    area_code: Option<String>,
+    city: Option<String>,
+    country: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    state: Option<String>,
+    toll_free_prefix: Option<String>,
 }
 
-impl AssociatePhoneNumberWithUserError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<AssociatePhoneNumberWithUserError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::BadRequest(
-                        err.msg,
-                    ))
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::Forbidden(
-                        err.msg,
-                    ))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::NotFound(
-                        err.msg,
-                    ))
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::ServiceFailure(
-                        err.msg,
-                    ))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumberWithUserError::ServiceUnavailable(err.msg),
-                    )
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumberWithUserError::ThrottledClient(err.msg),
-                    )
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumberWithUserError::UnauthorizedClient(err.msg),
-                    )
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
+impl SearchAvailablePhoneNumbersRequestBuilder {
+    /// Sets the [`area_code`](struct.SearchAvailablePhoneNumbersRequest.html#structfield.area_code) member.
+    pub fn area_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.area_code = Some(value.into());
+        self
     }
-}
-impl fmt::Display for AssociatePhoneNumberWithUserError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+
+    /// Sets the [`city`](struct.SearchAvailablePhoneNumbersRequest.html#structfield.city) member.
+    pub fn city<T: Into<String>>(mut self, value: T) -> Self {
+        self.city = Some(value.into());
+        self
     }
-}
-impl Error for AssociatePhoneNumberWithUserError {
-    fn description(&self) -> &str {
-        match *self {
-            AssociatePhoneNumberWithUserError::BadRequest(ref cause) => cause,
-            AssociatePhoneNumberWithUserError::Forbidden(ref cause) => cause,
-            AssociatePhoneNumberWithUserError::NotFound(ref cause) => cause,
-            AssociatePhoneNumberWithUserError::ServiceFailure(ref cause) => cause,
-            AssociatePhoneNumberWithUserError::ServiceUnavailable(ref cause) => cause,
-            AssociatePhoneNumberWithUserError::ThrottledClient(ref cause) => cause,
-            AssociatePhoneNumberWithUserError::UnauthorizedClient(ref cause) => cause,
-        }
+
+    /// Sets the [`country`](struct.SearchAvailablePhoneNumbersRequest.html#structfield.country) member.
+    pub fn country<T: Into<String>>(mut self, value: T) -> Self {
+        self.country = Some(value.into());
+        self
     }
-}
-/// Errors returned by AssociatePhoneNumbersWithVoiceConnector
-#[derive(Debug, PartialEq)]
-pub enum AssociatePhoneNumbersWithVoiceConnectorError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
-    Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
-    /// <p>The service encountered an unexpected error.</p>
-    ServiceFailure(String),
-    /// <p>The service is currently unavailable.</p>
-    ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
-    /// <p>The client is not currently authorized to make the request.</p>
-    UnauthorizedClient(String),
-}
 
-impl AssociatePhoneNumbersWithVoiceConnectorError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<AssociatePhoneNumbersWithVoiceConnectorError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::BadRequest(err.msg),
-                    )
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::Forbidden(err.msg),
-                    )
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::NotFound(err.msg),
-                    )
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::ServiceFailure(err.msg),
-                    )
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::ServiceUnavailable(err.msg),
-                    )
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::ThrottledClient(err.msg),
-                    )
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::UnauthorizedClient(err.msg),
-                    )
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
+    /// Sets the [`max_results`](struct.SearchAvailablePhoneNumbersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
     }
-}
-impl fmt::Display for AssociatePhoneNumbersWithVoiceConnectorError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+
+    /// Sets the [`next_token`](struct.SearchAvailablePhoneNumbersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
     }
-}
-impl Error for AssociatePhoneNumbersWithVoiceConnectorError {
-    fn description(&self) -> &str {
-        match *self {
-            AssociatePhoneNumbersWithVoiceConnectorError::BadRequest(ref cause) => cause,
-            AssociatePhoneNumbersWithVoiceConnectorError::Forbidden(ref cause) => cause,
-            AssociatePhoneNumbersWithVoiceConnectorError::NotFound(ref cause) => cause,
-            AssociatePhoneNumbersWithVoiceConnectorError::ServiceFailure(ref cause) => cause,
-            AssociatePhoneNumbersWithVoiceConnectorError::ServiceUnavailable(ref cause) => cause,
-            AssociatePhoneNumbersWithVoiceConnectorError::ThrottledClient(ref cause) => cause,
-            AssociatePhoneNumbersWithVoiceConnectorError::UnauthorizedClient(ref cause) => cause,
-        }
+
+    /// Sets the [`state`](struct.SearchAvailablePhoneNumbersRequest.html#structfield.state) member.
+    pub fn state<T: Into<String>>(mut self, value: T) -> Self {
+        self.state = Some(value.into());
+        self
     }
-}
-/// Errors returned by BatchDeletePhoneNumber
-#[derive(Debug, PartialEq)]
-pub enum BatchDeletePhoneNumberError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
-    Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
-    /// <p>The service encountered an unexpected error.</p>
-    ServiceFailure(String),
-    /// <p>The service is currently unavailable.</p>
-    ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
-    /// <p>The client is not currently authorized to make the request.</p>
-    UnauthorizedClient(String),
-}
 
-impl BatchDeletePhoneNumberError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<BatchDeletePhoneNumberError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::BadRequest(err.msg))
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::NotFound(err.msg))
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::ServiceFailure(
-                        err.msg,
-                    ))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::ThrottledClient(
-                        err.msg,
-                    ))
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
+    /// Sets the [`toll_free_prefix`](struct.SearchAvailablePhoneNumbersRequest.html#structfield.toll_free_prefix) member.
+    pub fn toll_free_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.toll_free_prefix = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchAvailablePhoneNumbersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SearchAvailablePhoneNumbersRequest, ::rusoto_core::BuildError> {
+        Ok(SearchAvailablePhoneNumbersRequest {
+            area_code: self.area_code,
+            city: self.city,
+            country: self.country,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            state: self.state,
+            toll_free_prefix: self.toll_free_prefix,
+        })
     }
 }
-impl fmt::Display for BatchDeletePhoneNumberError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct SearchAvailablePhoneNumbersResponse {
+    /// <p>List of phone numbers, in E.164 format.</p>
+    #[serde(rename = "E164PhoneNumbers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e164_phone_numbers: Option<Vec<String>>,
 }
-impl Error for BatchDeletePhoneNumberError {
-    fn description(&self) -> &str {
-        match *self {
-            BatchDeletePhoneNumberError::BadRequest(ref cause) => cause,
-            BatchDeletePhoneNumberError::Forbidden(ref cause) => cause,
-            BatchDeletePhoneNumberError::NotFound(ref cause) => cause,
-            BatchDeletePhoneNumberError::ServiceFailure(ref cause) => cause,
-            BatchDeletePhoneNumberError::ServiceUnavailable(ref cause) => cause,
-            BatchDeletePhoneNumberError::ThrottledClient(ref cause) => cause,
-            BatchDeletePhoneNumberError::UnauthorizedClient(ref cause) => cause,
-        }
-    }
+
+/// <p>Settings that allow management of telephony permissions for an Amazon Chime user, such as inbound and outbound calling and text messaging.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TelephonySettings {
+    /// <p>Allows or denies inbound calling.</p>
+    #[serde(rename = "InboundCalling")]
+    pub inbound_calling: bool,
+    /// <p>Allows or denies outbound calling.</p>
+    #[serde(rename = "OutboundCalling")]
+    pub outbound_calling: bool,
+    /// <p>Allows or denies SMS messaging.</p>
+    #[serde(rename = "SMS")]
+    pub sms: bool,
 }
-/// Errors returned by BatchSuspendUser
-#[derive(Debug, PartialEq)]
-pub enum BatchSuspendUserError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
-    Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
-    /// <p>The service encountered an unexpected error.</p>
-    ServiceFailure(String),
-    /// <p>The service is currently unavailable.</p>
-    ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
-    /// <p>The client is not currently authorized to make the request.</p>
-    UnauthorizedClient(String),
+
+/// <p>Termination settings enable your SIP hosts to make outbound calls using your Amazon Chime Voice Connector.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Termination {
+    /// <p>The countries to which calls are allowed.</p>
+    #[serde(rename = "CallingRegions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calling_regions: Option<Vec<String>>,
+    /// <p>The IP addresses allowed to make calls, in CIDR format.</p>
+    #[serde(rename = "CidrAllowedList")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cidr_allowed_list: Option<Vec<String>>,
+    /// <p>The limit on calls per second. Max value based on account service limit. Default value of 1.</p>
+    #[serde(rename = "CpsLimit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cps_limit: Option<i64>,
+    /// <p>The default caller ID phone number.</p>
+    #[serde(rename = "DefaultPhoneNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_phone_number: Option<String>,
+    /// <p>When termination settings are disabled, outbound calls can not be made.</p>
+    #[serde(rename = "Disabled")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
 }
 
-impl BatchSuspendUserError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<BatchSuspendUserError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(BatchSuspendUserError::BadRequest(err.msg))
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(BatchSuspendUserError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(BatchSuspendUserError::NotFound(err.msg))
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(BatchSuspendUserError::ServiceFailure(err.msg))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchSuspendUserError::ServiceUnavailable(err.msg))
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(BatchSuspendUserError::ThrottledClient(err.msg))
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchSuspendUserError::UnauthorizedClient(err.msg))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
-    }
+/// <p>The termination health details, including the source IP address and timestamp of the last successful SIP <code>OPTIONS</code> message from your SIP infrastructure.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct TerminationHealth {
+    /// <p>The source IP address.</p>
+    #[serde(rename = "Source")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// <p>The timestamp, in ISO 8601 format.</p>
+    #[serde(rename = "Timestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<EpochTimestamp>,
 }
-impl fmt::Display for BatchSuspendUserError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateAccountRequest {
+    /// <p>The Amazon Chime account ID.</p>
+    #[serde(rename = "AccountId")]
+    pub account_id: String,
+    /// <p>The new name for the specified Amazon Chime account.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
-impl Error for BatchSuspendUserError {
-    fn description(&self) -> &str {
-        match *self {
-            BatchSuspendUserError::BadRequest(ref cause) => cause,
-            BatchSuspendUserError::Forbidden(ref cause) => cause,
-            BatchSuspendUserError::NotFound(ref cause) => cause,
-            BatchSuspendUserError::ServiceFailure(ref cause) => cause,
-            BatchSuspendUserError::ServiceUnavailable(ref cause) => cause,
-            BatchSuspendUserError::ThrottledClient(ref cause) => cause,
-            BatchSuspendUserError::UnauthorizedClient(ref cause) => cause,
-        }
+
+impl UpdateAccountRequest {
+    /// Returns a builder for `UpdateAccountRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAccountRequestBuilder {
+        UpdateAccountRequestBuilder::default()
     }
 }
-/// Errors returned by BatchUnsuspendUser
-#[derive(Debug, PartialEq)]
-pub enum BatchUnsuspendUserError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
-    Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
-    /// <p>The service encountered an unexpected error.</p>
-    ServiceFailure(String),
-    /// <p>The service is currently unavailable.</p>
-    ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
-    /// <p>The client is not currently authorized to make the request.</p>
-    UnauthorizedClient(String),
+
+/// Builds a [`UpdateAccountRequest`](struct.UpdateAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAccountRequestBuilder {
+    account_id: Option<String>,
+    name: Option<String>,
 }
 
-impl BatchUnsuspendUserError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<BatchUnsuspendUserError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::BadRequest(err.msg))
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::NotFound(err.msg))
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::ServiceFailure(err.msg))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::ServiceUnavailable(
-                        err.msg,
-                    ))
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::ThrottledClient(err.msg))
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::UnauthorizedClient(
-                        err.msg,
-                    ))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
+impl UpdateAccountRequestBuilder {
+    /// Sets the [`account_id`](struct.UpdateAccountRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
     }
-}
-impl fmt::Display for BatchUnsuspendUserError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+
+    /// Sets the [`name`](struct.UpdateAccountRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateAccountRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAccountRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            name: self.name,
+        })
     }
 }
-impl Error for BatchUnsuspendUserError {
-    fn description(&self) -> &str {
-        match *self {
-            BatchUnsuspendUserError::BadRequest(ref cause) => cause,
-            BatchUnsuspendUserError::Forbidden(ref cause) => cause,
-            BatchUnsuspendUserError::NotFound(ref cause) => cause,
-            BatchUnsuspendUserError::ServiceFailure(ref cause) => cause,
-            BatchUnsuspendUserError::ServiceUnavailable(ref cause) => cause,
-            BatchUnsuspendUserError::ThrottledClient(ref cause) => cause,
-            BatchUnsuspendUserError::UnauthorizedClient(ref cause) => cause,
-        }
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateAccountResponse {
+    /// <p>The updated Amazon Chime account details.</p>
+    #[serde(rename = "Account")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<Account>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateAccountSettingsRequest {
+    /// <p>The Amazon Chime account ID.</p>
+    #[serde(rename = "AccountId")]
+    pub account_id: String,
+    /// <p>The Amazon Chime account settings to update.</p>
+    #[serde(rename = "AccountSettings")]
+    pub account_settings: AccountSettings,
+}
+
+impl UpdateAccountSettingsRequest {
+    /// Returns a builder for `UpdateAccountSettingsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAccountSettingsRequestBuilder {
+        UpdateAccountSettingsRequestBuilder::default()
     }
 }
-/// Errors returned by BatchUpdatePhoneNumber
-#[derive(Debug, PartialEq)]
-pub enum BatchUpdatePhoneNumberError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
-    Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
-    /// <p>The service encountered an unexpected error.</p>
-    ServiceFailure(String),
-    /// <p>The service is currently unavailable.</p>
-    ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
-    /// <p>The client is not currently authorized to make the request.</p>
-    UnauthorizedClient(String),
+
+/// Builds a [`UpdateAccountSettingsRequest`](struct.UpdateAccountSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAccountSettingsRequestBuilder {
+    account_id: Option<String>,
+    account_settings: Option<AccountSettings>,
 }
 
-impl BatchUpdatePhoneNumberError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<BatchUpdatePhoneNumberError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::BadRequest(err.msg))
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::NotFound(err.msg))
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::ServiceFailure(
-                        err.msg,
-                    ))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::ThrottledClient(
-                        err.msg,
-                    ))
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
+impl UpdateAccountSettingsRequestBuilder {
+    /// Sets the [`account_id`](struct.UpdateAccountSettingsRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
     }
-}
-impl fmt::Display for BatchUpdatePhoneNumberError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+
+    /// Sets the [`account_settings`](struct.UpdateAccountSettingsRequest.html#structfield.account_settings) member.
+    pub fn account_settings<T: Into<AccountSettings>>(mut self, value: T) -> Self {
+        self.account_settings = Some(value.into());
+        self
     }
-}
-impl Error for BatchUpdatePhoneNumberError {
-    fn description(&self) -> &str {
-        match *self {
-            BatchUpdatePhoneNumberError::BadRequest(ref cause) => cause,
-            BatchUpdatePhoneNumberError::Forbidden(ref cause) => cause,
-            BatchUpdatePhoneNumberError::NotFound(ref cause) => cause,
-            BatchUpdatePhoneNumberError::ServiceFailure(ref cause) => cause,
-            BatchUpdatePhoneNumberError::ServiceUnavailable(ref cause) => cause,
-            BatchUpdatePhoneNumberError::ThrottledClient(ref cause) => cause,
-            BatchUpdatePhoneNumberError::UnauthorizedClient(ref cause) => cause,
-        }
+
+    /// Builds the `UpdateAccountSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAccountSettingsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAccountSettingsRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            account_settings: self
+                .account_settings
+                .ok_or(::rusoto_core::BuildError::missing("account_settings"))?,
+        })
     }
 }
-/// Errors returned by BatchUpdateUser
-#[derive(Debug, PartialEq)]
-pub enum BatchUpdateUserError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
-    Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
-    /// <p>The service encountered an unexpected error.</p>
-    ServiceFailure(String),
-    /// <p>The service is currently unavailable.</p>
-    ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
-    /// <p>The client is not currently authorized to make the request.</p>
-    UnauthorizedClient(String),
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateAccountSettingsResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateBotRequest {
+    /// <p>The Amazon Chime account ID.</p>
+    #[serde(rename = "AccountId")]
+    pub account_id: String,
+    /// <p>The bot ID.</p>
+    #[serde(rename = "BotId")]
+    pub bot_id: String,
+    /// <p>When true, stops the specified bot from running in your account.</p>
+    #[serde(rename = "Disabled")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
 }
 
-impl BatchUpdateUserError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<BatchUpdateUserError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(BatchUpdateUserError::BadRequest(err.msg))
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(BatchUpdateUserError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(BatchUpdateUserError::NotFound(err.msg))
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(BatchUpdateUserError::ServiceFailure(err.msg))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUpdateUserError::ServiceUnavailable(err.msg))
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(BatchUpdateUserError::ThrottledClient(err.msg))
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchUpdateUserError::UnauthorizedClient(err.msg))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
+impl UpdateBotRequest {
+    /// Returns a builder for `UpdateBotRequest`, checking its required members when built.
+    pub fn builder() -> UpdateBotRequestBuilder {
+        UpdateBotRequestBuilder::default()
     }
 }
-impl fmt::Display for BatchUpdateUserError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+
+/// Builds a [`UpdateBotRequest`](struct.UpdateBotRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateBotRequestBuilder {
+    account_id: Option<String>,
+    bot_id: Option<String>,
+    disabled: Option<bool>,
+}
+
+impl UpdateBotRequestBuilder {
+    /// Sets the [`account_id`](struct.UpdateBotRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_id`](struct.UpdateBotRequest.html#structfield.bot_id) member.
+    pub fn bot_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disabled`](struct.UpdateBotRequest.html#structfield.disabled) member.
+    pub fn disabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.disabled = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateBotRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateBotRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateBotRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            bot_id: self
+                .bot_id
+                .ok_or(::rusoto_core::BuildError::missing("bot_id"))?,
+            disabled: self.disabled,
+        })
     }
 }
-impl Error for BatchUpdateUserError {
-    fn description(&self) -> &str {
-        match *self {
-            BatchUpdateUserError::BadRequest(ref cause) => cause,
-            BatchUpdateUserError::Forbidden(ref cause) => cause,
-            BatchUpdateUserError::NotFound(ref cause) => cause,
-            BatchUpdateUserError::ServiceFailure(ref cause) => cause,
-            BatchUpdateUserError::ServiceUnavailable(ref cause) => cause,
-            BatchUpdateUserError::ThrottledClient(ref cause) => cause,
-            BatchUpdateUserError::UnauthorizedClient(ref cause) => cause,
-        }
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateBotResponse {
+    /// <p>The updated bot details.</p>
+    #[serde(rename = "Bot")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot: Option<Bot>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateGlobalSettingsRequest {
+    /// <p>The Amazon Chime Business Calling settings.</p>
+    #[serde(rename = "BusinessCalling")]
+    pub business_calling: BusinessCallingSettings,
+    /// <p>The Amazon Chime Voice Connector settings.</p>
+    #[serde(rename = "VoiceConnector")]
+    pub voice_connector: VoiceConnectorSettings,
+}
+
+impl UpdateGlobalSettingsRequest {
+    /// Returns a builder for `UpdateGlobalSettingsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateGlobalSettingsRequestBuilder {
+        UpdateGlobalSettingsRequestBuilder::default()
     }
 }
-/// Errors returned by CreateAccount
-#[derive(Debug, PartialEq)]
-pub enum CreateAccountError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
-    Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
-    /// <p>The service encountered an unexpected error.</p>
-    ServiceFailure(String),
-    /// <p>The service is currently unavailable.</p>
-    ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
-    /// <p>The client is not currently authorized to make the request.</p>
-    UnauthorizedClient(String),
+
+/// Builds a [`UpdateGlobalSettingsRequest`](struct.UpdateGlobalSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateGlobalSettingsRequestBuilder {
+    business_calling: Option<BusinessCallingSettings>,
+    voice_connector: Option<VoiceConnectorSettings>,
 }
 
-impl CreateAccountError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateAccountError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(CreateAccountError::BadRequest(err.msg))
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(CreateAccountError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(CreateAccountError::NotFound(err.msg))
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(CreateAccountError::ServiceFailure(err.msg))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAccountError::ServiceUnavailable(err.msg))
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(CreateAccountError::ThrottledClient(err.msg))
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateAccountError::UnauthorizedClient(err.msg))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
+impl UpdateGlobalSettingsRequestBuilder {
+    /// Sets the [`business_calling`](struct.UpdateGlobalSettingsRequest.html#structfield.business_calling) member.
+    pub fn business_calling<T: Into<BusinessCallingSettings>>(mut self, value: T) -> Self {
+        self.business_calling = Some(value.into());
+        self
+    }
+
+    /// Sets the [`voice_connector`](struct.UpdateGlobalSettingsRequest.html#structfield.voice_connector) member.
+    pub fn voice_connector<T: Into<VoiceConnectorSettings>>(mut self, value: T) -> Self {
+        self.voice_connector = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGlobalSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateGlobalSettingsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateGlobalSettingsRequest {
+            business_calling: self
+                .business_calling
+                .ok_or(::rusoto_core::BuildError::missing("business_calling"))?,
+            voice_connector: self
+                .voice_connector
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector"))?,
+        })
     }
 }
-impl fmt::Display for CreateAccountError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdatePhoneNumberRequest {
+    /// <p>The phone number ID.</p>
+    #[serde(rename = "PhoneNumberId")]
+    pub phone_number_id: String,
+    /// <p>The product type.</p>
+    #[serde(rename = "ProductType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<PhoneNumberProductType>,
+}
+
+impl UpdatePhoneNumberRequest {
+    /// Returns a builder for `UpdatePhoneNumberRequest`, checking its required members when built.
+    pub fn builder() -> UpdatePhoneNumberRequestBuilder {
+        UpdatePhoneNumberRequestBuilder::default()
     }
 }
-impl Error for CreateAccountError {
-    fn description(&self) -> &str {
-        match *self {
-            CreateAccountError::BadRequest(ref cause) => cause,
-            CreateAccountError::Forbidden(ref cause) => cause,
-            CreateAccountError::NotFound(ref cause) => cause,
-            CreateAccountError::ServiceFailure(ref cause) => cause,
-            CreateAccountError::ServiceUnavailable(ref cause) => cause,
-            CreateAccountError::ThrottledClient(ref cause) => cause,
-            CreateAccountError::UnauthorizedClient(ref cause) => cause,
-        }
+
+/// Builds a [`UpdatePhoneNumberRequest`](struct.UpdatePhoneNumberRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdatePhoneNumberRequestBuilder {
+    phone_number_id: Option<String>,
+    product_type: Option<PhoneNumberProductType>,
+}
+
+impl UpdatePhoneNumberRequestBuilder {
+    /// Sets the [`phone_number_id`](struct.UpdatePhoneNumberRequest.html#structfield.phone_number_id) member.
+    pub fn phone_number_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.phone_number_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_type`](struct.UpdatePhoneNumberRequest.html#structfield.product_type) member.
+    pub fn product_type<T: Into<PhoneNumberProductType>>(mut self, value: T) -> Self {
+        self.product_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePhoneNumberRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdatePhoneNumberRequest, ::rusoto_core::BuildError> {
+        Ok(UpdatePhoneNumberRequest {
+            phone_number_id: self
+                .phone_number_id
+                .ok_or(::rusoto_core::BuildError::missing("phone_number_id"))?,
+            product_type: self.product_type,
+        })
     }
 }
-/// Errors returned by CreateBot
-#[derive(Debug, PartialEq)]
-pub enum CreateBotError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
-    Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
-    /// <p>The request exceeds the resource limit.</p>
-    ResourceLimitExceeded(String),
-    /// <p>The service encountered an unexpected error.</p>
-    ServiceFailure(String),
-    /// <p>The service is currently unavailable.</p>
-    ServiceUnavailable(String),
-    /// <p>The client is not currently authorized to make the request.</p>
-    UnauthorizedClient(String),
+
+/// <p>The phone number ID and product type fields to update, used with the <a>BatchUpdatePhoneNumber</a> and <a>UpdatePhoneNumber</a> actions.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdatePhoneNumberRequestItem {
+    /// <p>The phone number ID to update.</p>
+    #[serde(rename = "PhoneNumberId")]
+    pub phone_number_id: String,
+    /// <p>The product type to update.</p>
+    #[serde(rename = "ProductType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<PhoneNumberProductType>,
 }
 
-impl CreateBotError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateBotError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(CreateBotError::BadRequest(err.msg))
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(CreateBotError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(CreateBotError::NotFound(err.msg))
-                }
-                "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateBotError::ResourceLimitExceeded(err.msg))
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(CreateBotError::ServiceFailure(err.msg))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBotError::ServiceUnavailable(err.msg))
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateBotError::UnauthorizedClient(err.msg))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
-    }
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdatePhoneNumberResponse {
+    /// <p>The updated phone number details.</p>
+    #[serde(rename = "PhoneNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<PhoneNumber>,
 }
-impl fmt::Display for CreateBotError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateUserRequest {
+    /// <p>The Amazon Chime account ID.</p>
+    #[serde(rename = "AccountId")]
+    pub account_id: String,
+    /// <p>The user license type to update. This must be a supported license type for the Amazon Chime account that the user belongs to.</p>
+    #[serde(rename = "LicenseType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_type: Option<License>,
+    /// <p>The user ID.</p>
+    #[serde(rename = "UserId")]
+    pub user_id: String,
 }
-impl Error for CreateBotError {
-    fn description(&self) -> &str {
-        match *self {
-            CreateBotError::BadRequest(ref cause) => cause,
-            CreateBotError::Forbidden(ref cause) => cause,
-            CreateBotError::NotFound(ref cause) => cause,
-            CreateBotError::ResourceLimitExceeded(ref cause) => cause,
-            CreateBotError::ServiceFailure(ref cause) => cause,
-            CreateBotError::ServiceUnavailable(ref cause) => cause,
-            CreateBotError::UnauthorizedClient(ref cause) => cause,
-        }
+
+impl UpdateUserRequest {
+    /// Returns a builder for `UpdateUserRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserRequestBuilder {
+        UpdateUserRequestBuilder::default()
     }
 }
-/// Errors returned by CreatePhoneNumberOrder
-#[derive(Debug, PartialEq)]
-pub enum CreatePhoneNumberOrderError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
-    Forbidden(String),
-    /// <p>The service encountered an unexpected error.</p>
-    ServiceFailure(String),
-    /// <p>The service is currently unavailable.</p>
-    ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
-    /// <p>The client is not currently authorized to make the request.</p>
-    UnauthorizedClient(String),
+
+/// Builds a [`UpdateUserRequest`](struct.UpdateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserRequestBuilder {
+    account_id: Option<String>,
+    license_type: Option<License>,
+    user_id: Option<String>,
 }
 
-impl CreatePhoneNumberOrderError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreatePhoneNumberOrderError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::BadRequest(err.msg))
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::Forbidden(err.msg))
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::ServiceFailure(
-                        err.msg,
-                    ))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::ServiceUnavailable(
-                        err.msg,
-                    ))
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::ThrottledClient(
-                        err.msg,
-                    ))
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::UnauthorizedClient(
-                        err.msg,
-                    ))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
+impl UpdateUserRequestBuilder {
+    /// Sets the [`account_id`](struct.UpdateUserRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
     }
-}
-impl fmt::Display for CreatePhoneNumberOrderError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+
+    /// Sets the [`license_type`](struct.UpdateUserRequest.html#structfield.license_type) member.
+    pub fn license_type<T: Into<License>>(mut self, value: T) -> Self {
+        self.license_type = Some(value.into());
+        self
     }
-}
-impl Error for CreatePhoneNumberOrderError {
-    fn description(&self) -> &str {
-        match *self {
-            CreatePhoneNumberOrderError::BadRequest(ref cause) => cause,
-            CreatePhoneNumberOrderError::Forbidden(ref cause) => cause,
-            CreatePhoneNumberOrderError::ServiceFailure(ref cause) => cause,
-            CreatePhoneNumberOrderError::ServiceUnavailable(ref cause) => cause,
-            CreatePhoneNumberOrderError::ThrottledClient(ref cause) => cause,
-            CreatePhoneNumberOrderError::UnauthorizedClient(ref cause) => cause,
-        }
+
+    /// Sets the [`user_id`](struct.UpdateUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateUserRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            license_type: self.license_type,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
     }
 }
-/// Errors returned by CreateVoiceConnector
-#[derive(Debug, PartialEq)]
-pub enum CreateVoiceConnectorError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
-    Forbidden(String),
-    /// <p>The service encountered an unexpected error.</p>
-    ServiceFailure(String),
-    /// <p>The service is currently unavailable.</p>
-    ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
-    /// <p>The client is not currently authorized to make the request.</p>
-    UnauthorizedClient(String),
+
+/// <p>The user ID and user fields to update, used with the <a>BatchUpdateUser</a> action.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateUserRequestItem {
+    /// <p>The user license type.</p>
+    #[serde(rename = "LicenseType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_type: Option<License>,
+    /// <p>The user ID.</p>
+    #[serde(rename = "UserId")]
+    pub user_id: String,
 }
 
-impl CreateVoiceConnectorError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateVoiceConnectorError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::BadRequest(err.msg))
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::Forbidden(err.msg))
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ServiceFailure(err.msg))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ThrottledClient(
-                        err.msg,
-                    ))
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::UnauthorizedClient(
-                        err.msg,
-                    ))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
-    }
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateUserResponse {
+    /// <p>The updated user details.</p>
+    #[serde(rename = "User")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
 }
-impl fmt::Display for CreateVoiceConnectorError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateUserSettingsRequest {
+    /// <p>The Amazon Chime account ID.</p>
+    #[serde(rename = "AccountId")]
+    pub account_id: String,
+    /// <p>The user ID.</p>
+    #[serde(rename = "UserId")]
+    pub user_id: String,
+    /// <p>The user settings to update.</p>
+    #[serde(rename = "UserSettings")]
+    pub user_settings: UserSettings,
 }
-impl Error for CreateVoiceConnectorError {
-    fn description(&self) -> &str {
-        match *self {
-            CreateVoiceConnectorError::BadRequest(ref cause) => cause,
-            CreateVoiceConnectorError::Forbidden(ref cause) => cause,
-            CreateVoiceConnectorError::ServiceFailure(ref cause) => cause,
-            CreateVoiceConnectorError::ServiceUnavailable(ref cause) => cause,
-            CreateVoiceConnectorError::ThrottledClient(ref cause) => cause,
-            CreateVoiceConnectorError::UnauthorizedClient(ref cause) => cause,
-        }
+
+impl UpdateUserSettingsRequest {
+    /// Returns a builder for `UpdateUserSettingsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserSettingsRequestBuilder {
+        UpdateUserSettingsRequestBuilder::default()
     }
 }
-/// Errors returned by DeleteAccount
-#[derive(Debug, PartialEq)]
-pub enum DeleteAccountError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
+
+/// Builds a [`UpdateUserSettingsRequest`](struct.UpdateUserSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserSettingsRequestBuilder {
+    account_id: Option<String>,
+    user_id: Option<String>,
+    user_settings: Option<UserSettings>,
+}
+
+impl UpdateUserSettingsRequestBuilder {
+    /// Sets the [`account_id`](struct.UpdateUserSettingsRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.UpdateUserSettingsRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_settings`](struct.UpdateUserSettingsRequest.html#structfield.user_settings) member.
+    pub fn user_settings<T: Into<UserSettings>>(mut self, value: T) -> Self {
+        self.user_settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateUserSettingsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserSettingsRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+            user_settings: self
+                .user_settings
+                .ok_or(::rusoto_core::BuildError::missing("user_settings"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateVoiceConnectorRequest {
+    /// <p>The name of the Amazon Chime Voice Connector.</p>
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// <p>When enabled, requires encryption for the Amazon Chime Voice Connector.</p>
+    #[serde(rename = "RequireEncryption")]
+    pub require_encryption: bool,
+    /// <p>The Amazon Chime Voice Connector ID.</p>
+    #[serde(rename = "VoiceConnectorId")]
+    pub voice_connector_id: String,
+}
+
+impl UpdateVoiceConnectorRequest {
+    /// Returns a builder for `UpdateVoiceConnectorRequest`, checking its required members when built.
+    pub fn builder() -> UpdateVoiceConnectorRequestBuilder {
+        UpdateVoiceConnectorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateVoiceConnectorRequest`](struct.UpdateVoiceConnectorRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateVoiceConnectorRequestBuilder {
+    name: Option<String>,
+    require_encryption: Option<bool>,
+    voice_connector_id: Option<String>,
+}
+
+impl UpdateVoiceConnectorRequestBuilder {
+    /// Sets the [`name`](struct.UpdateVoiceConnectorRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`require_encryption`](struct.UpdateVoiceConnectorRequest.html#structfield.require_encryption) member.
+    pub fn require_encryption<T: Into<bool>>(mut self, value: T) -> Self {
+        self.require_encryption = Some(value.into());
+        self
+    }
+
+    /// Sets the [`voice_connector_id`](struct.UpdateVoiceConnectorRequest.html#structfield.voice_connector_id) member.
+    pub fn voice_connector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_connector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateVoiceConnectorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateVoiceConnectorRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateVoiceConnectorRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            require_encryption: self
+                .require_encryption
+                .ok_or(::rusoto_core::BuildError::missing("require_encryption"))?,
+            voice_connector_id: self
+                .voice_connector_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_connector_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateVoiceConnectorResponse {
+    /// <p>The Amazon Chime Voice Connector details.</p>
+    #[serde(rename = "VoiceConnector")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_connector: Option<VoiceConnector>,
+}
+
+/// <p>The user on the Amazon Chime account.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct User {
+    /// <p>The Amazon Chime account ID.</p>
+    #[serde(rename = "AccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// <p>The display name of the user.</p>
+    #[serde(rename = "DisplayName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// <p>Date and time when the user is invited to the Amazon Chime account, in ISO 8601 format.</p>
+    #[serde(rename = "InvitedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invited_on: Option<EpochTimestamp>,
+    /// <p>The license type for the user.</p>
+    #[serde(rename = "LicenseType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_type: Option<License>,
+    /// <p>The user's personal meeting PIN.</p>
+    #[serde(rename = "PersonalPIN")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_pin: Option<String>,
+    /// <p>The primary email address of the user.</p>
+    #[serde(rename = "PrimaryEmail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_email: Option<String>,
+    /// <p>The primary phone number associated with the user.</p>
+    #[serde(rename = "PrimaryProvisionedNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_provisioned_number: Option<String>,
+    /// <p>Date and time when the user is registered, in ISO 8601 format.</p>
+    #[serde(rename = "RegisteredOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registered_on: Option<EpochTimestamp>,
+    /// <p>The user ID.</p>
+    #[serde(rename = "UserId")]
+    pub user_id: String,
+    /// <p>The user invite status.</p>
+    #[serde(rename = "UserInvitationStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_invitation_status: Option<InviteStatus>,
+    /// <p>The user registration status.</p>
+    #[serde(rename = "UserRegistrationStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_registration_status: Option<RegistrationStatus>,
+}
+
+/// <p>The list of errors returned when errors are encountered during the <a>BatchSuspendUser</a>, <a>BatchUnsuspendUser</a>, or <a>BatchUpdateUser</a> actions. This includes user IDs, error codes, and error messages.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UserError {
+    /// <p>The error code.</p>
+    #[serde(rename = "ErrorCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<ErrorCode>,
+    /// <p>The error message.</p>
+    #[serde(rename = "ErrorMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+    /// <p>The user ID for which the action failed.</p>
+    #[serde(rename = "UserId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+/// <p>Settings associated with an Amazon Chime user, including inbound and outbound calling and text messaging.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserSettings {
+    /// <p>The telephony settings associated with the user.</p>
+    #[serde(rename = "Telephony")]
+    pub telephony: TelephonySettings,
+}
+
+/// <p>The Amazon Chime Voice Connector configuration, including outbound host name and encryption settings.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct VoiceConnector {
+    /// <p>The Amazon Chime Voice Connector creation timestamp, in ISO 8601 format.</p>
+    #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_timestamp: Option<EpochTimestamp>,
+    /// <p>The name of the Amazon Chime Voice Connector.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The outbound host name for the Amazon Chime Voice Connector.</p>
+    #[serde(rename = "OutboundHostName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outbound_host_name: Option<String>,
+    /// <p>Designates whether encryption is required for the Amazon Chime Voice Connector.</p>
+    #[serde(rename = "RequireEncryption")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub require_encryption: Option<bool>,
+    /// <p>The updated Amazon Chime Voice Connector timestamp, in ISO 8601 format.</p>
+    #[serde(rename = "UpdatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_timestamp: Option<EpochTimestamp>,
+    /// <p>The Amazon Chime Voice Connector ID.</p>
+    #[serde(rename = "VoiceConnectorId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_connector_id: Option<String>,
+}
+
+/// <p>The Amazon Chime Voice Connector settings. Includes any Amazon S3 buckets designated for storing call detail records.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoiceConnectorSettings {
+    /// <p>The Amazon S3 bucket designated for call detail record storage.</p>
+    #[serde(rename = "CdrBucket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cdr_bucket: Option<String>,
+}
+
+/// Errors returned by AssociatePhoneNumberWithUser
+#[derive(Debug, PartialEq)]
+pub enum AssociatePhoneNumberWithUserError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
     /// <p>One or more of the resources in the request does not exist in the system.</p>
@@ -2448,37 +5227,48 @@ pub enum DeleteAccountError {
     ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
-    /// <p>The request was well-formed but was unable to be followed due to semantic errors.</p>
-    UnprocessableEntity(String),
 }
 
-impl DeleteAccountError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteAccountError> {
+impl AssociatePhoneNumberWithUserError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<AssociatePhoneNumberWithUserError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAccountError::BadRequest(err.msg))
+                    return RusotoError::Service(AssociatePhoneNumberWithUserError::BadRequest(
+                        err.msg,
+                    ))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAccountError::Forbidden(err.msg))
+                    return RusotoError::Service(AssociatePhoneNumberWithUserError::Forbidden(
+                        err.msg,
+                    ))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAccountError::NotFound(err.msg))
+                    return RusotoError::Service(AssociatePhoneNumberWithUserError::NotFound(
+                        err.msg,
+                    ))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteAccountError::ServiceFailure(err.msg))
+                    return RusotoError::Service(AssociatePhoneNumberWithUserError::ServiceFailure(
+                        err.msg,
+                    ))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(
+                        AssociatePhoneNumberWithUserError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteAccountError::ThrottledClient(err.msg))
+                    return RusotoError::Service(
+                        AssociatePhoneNumberWithUserError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteAccountError::UnauthorizedClient(err.msg))
-                }
-                "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeleteAccountError::UnprocessableEntity(err.msg))
+                    return RusotoError::Service(
+                        AssociatePhoneNumberWithUserError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2487,72 +5277,82 @@ impl DeleteAccountError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for DeleteAccountError {
+impl fmt::Display for AssociatePhoneNumberWithUserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for DeleteAccountError {
+impl Error for AssociatePhoneNumberWithUserError {
     fn description(&self) -> &str {
         match *self {
-            DeleteAccountError::BadRequest(ref cause) => cause,
-            DeleteAccountError::Forbidden(ref cause) => cause,
-            DeleteAccountError::NotFound(ref cause) => cause,
-            DeleteAccountError::ServiceFailure(ref cause) => cause,
-            DeleteAccountError::ServiceUnavailable(ref cause) => cause,
-            DeleteAccountError::ThrottledClient(ref cause) => cause,
-            DeleteAccountError::UnauthorizedClient(ref cause) => cause,
-            DeleteAccountError::UnprocessableEntity(ref cause) => cause,
+            AssociatePhoneNumberWithUserError::BadRequest(ref cause) => cause,
+            AssociatePhoneNumberWithUserError::Forbidden(ref cause) => cause,
+            AssociatePhoneNumberWithUserError::NotFound(ref cause) => cause,
+            AssociatePhoneNumberWithUserError::ServiceFailure(ref cause) => cause,
+            AssociatePhoneNumberWithUserError::ServiceUnavailable(ref cause) => cause,
+            AssociatePhoneNumberWithUserError::ThrottledClient(ref cause) => cause,
+            AssociatePhoneNumberWithUserError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by DeleteEventsConfiguration
+/// Errors returned by AssociatePhoneNumbersWithVoiceConnector
 #[derive(Debug, PartialEq)]
-pub enum DeleteEventsConfigurationError {
+pub enum AssociatePhoneNumbersWithVoiceConnectorError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
-    /// <p>The request exceeds the resource limit.</p>
-    ResourceLimitExceeded(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
     ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
 }
 
-impl DeleteEventsConfigurationError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteEventsConfigurationError> {
+impl AssociatePhoneNumbersWithVoiceConnectorError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<AssociatePhoneNumbersWithVoiceConnectorError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEventsConfigurationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        AssociatePhoneNumbersWithVoiceConnectorError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteEventsConfigurationError::Forbidden(err.msg))
+                    return RusotoError::Service(
+                        AssociatePhoneNumbersWithVoiceConnectorError::Forbidden(err.msg),
+                    )
                 }
-                "ResourceLimitExceededException" => {
+                "NotFoundException" => {
                     return RusotoError::Service(
-                        DeleteEventsConfigurationError::ResourceLimitExceeded(err.msg),
+                        AssociatePhoneNumbersWithVoiceConnectorError::NotFound(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteEventsConfigurationError::ServiceFailure(
-                        err.msg,
-                    ))
-                }
-                "ServiceUnavailableException" => {
                     return RusotoError::Service(
-                        DeleteEventsConfigurationError::ServiceUnavailable(err.msg),
+                        AssociatePhoneNumbersWithVoiceConnectorError::ServiceFailure(err.msg),
+                    )
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(
+                        AssociatePhoneNumbersWithVoiceConnectorError::ServiceUnavailable(err.msg),
+                    )
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(
+                        AssociatePhoneNumbersWithVoiceConnectorError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(
-                        DeleteEventsConfigurationError::UnauthorizedClient(err.msg),
+                        AssociatePhoneNumbersWithVoiceConnectorError::UnauthorizedClient(err.msg),
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
@@ -2562,26 +5362,27 @@ impl DeleteEventsConfigurationError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for DeleteEventsConfigurationError {
+impl fmt::Display for AssociatePhoneNumbersWithVoiceConnectorError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for DeleteEventsConfigurationError {
+impl Error for AssociatePhoneNumbersWithVoiceConnectorError {
     fn description(&self) -> &str {
         match *self {
-            DeleteEventsConfigurationError::BadRequest(ref cause) => cause,
-            DeleteEventsConfigurationError::Forbidden(ref cause) => cause,
-            DeleteEventsConfigurationError::ResourceLimitExceeded(ref cause) => cause,
-            DeleteEventsConfigurationError::ServiceFailure(ref cause) => cause,
-            DeleteEventsConfigurationError::ServiceUnavailable(ref cause) => cause,
-            DeleteEventsConfigurationError::UnauthorizedClient(ref cause) => cause,
+            AssociatePhoneNumbersWithVoiceConnectorError::BadRequest(ref cause) => cause,
+            AssociatePhoneNumbersWithVoiceConnectorError::Forbidden(ref cause) => cause,
+            AssociatePhoneNumbersWithVoiceConnectorError::NotFound(ref cause) => cause,
+            AssociatePhoneNumbersWithVoiceConnectorError::ServiceFailure(ref cause) => cause,
+            AssociatePhoneNumbersWithVoiceConnectorError::ServiceUnavailable(ref cause) => cause,
+            AssociatePhoneNumbersWithVoiceConnectorError::ThrottledClient(ref cause) => cause,
+            AssociatePhoneNumbersWithVoiceConnectorError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by DeletePhoneNumber
+/// Errors returned by BatchDeletePhoneNumber
 #[derive(Debug, PartialEq)]
-pub enum DeletePhoneNumberError {
+pub enum BatchDeletePhoneNumberError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -2598,32 +5399,36 @@ pub enum DeletePhoneNumberError {
     UnauthorizedClient(String),
 }
 
-impl DeletePhoneNumberError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeletePhoneNumberError> {
+impl BatchDeletePhoneNumberError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<BatchDeletePhoneNumberError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::Service(BatchDeletePhoneNumberError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::Service(BatchDeletePhoneNumberError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::Service(BatchDeletePhoneNumberError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::Service(BatchDeletePhoneNumberError::ServiceFailure(
+                        err.msg,
+                    ))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::ServiceUnavailable(
+                    return RusotoError::Service(BatchDeletePhoneNumberError::ServiceUnavailable(
                         err.msg,
                     ))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::Service(BatchDeletePhoneNumberError::ThrottledClient(
+                        err.msg,
+                    ))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::UnauthorizedClient(
+                    return RusotoError::Service(BatchDeletePhoneNumberError::UnauthorizedClient(
                         err.msg,
                     ))
                 }
@@ -2634,27 +5439,27 @@ impl DeletePhoneNumberError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for DeletePhoneNumberError {
+impl fmt::Display for BatchDeletePhoneNumberError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for DeletePhoneNumberError {
+impl Error for BatchDeletePhoneNumberError {
     fn description(&self) -> &str {
         match *self {
-            DeletePhoneNumberError::BadRequest(ref cause) => cause,
-            DeletePhoneNumberError::Forbidden(ref cause) => cause,
-            DeletePhoneNumberError::NotFound(ref cause) => cause,
-            DeletePhoneNumberError::ServiceFailure(ref cause) => cause,
-            DeletePhoneNumberError::ServiceUnavailable(ref cause) => cause,
-            DeletePhoneNumberError::ThrottledClient(ref cause) => cause,
-            DeletePhoneNumberError::UnauthorizedClient(ref cause) => cause,
+            BatchDeletePhoneNumberError::BadRequest(ref cause) => cause,
+            BatchDeletePhoneNumberError::Forbidden(ref cause) => cause,
+            BatchDeletePhoneNumberError::NotFound(ref cause) => cause,
+            BatchDeletePhoneNumberError::ServiceFailure(ref cause) => cause,
+            BatchDeletePhoneNumberError::ServiceUnavailable(ref cause) => cause,
+            BatchDeletePhoneNumberError::ThrottledClient(ref cause) => cause,
+            BatchDeletePhoneNumberError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by DeleteVoiceConnector
+/// Errors returned by BatchSuspendUser
 #[derive(Debug, PartialEq)]
-pub enum DeleteVoiceConnectorError {
+pub enum BatchSuspendUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -2671,36 +5476,30 @@ pub enum DeleteVoiceConnectorError {
     UnauthorizedClient(String),
 }
 
-impl DeleteVoiceConnectorError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteVoiceConnectorError> {
+impl BatchSuspendUserError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<BatchSuspendUserError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::Service(BatchSuspendUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::Service(BatchSuspendUserError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::NotFound(err.msg))
+                    return RusotoError::Service(BatchSuspendUserError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::Service(BatchSuspendUserError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(BatchSuspendUserError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(BatchSuspendUserError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(BatchSuspendUserError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2709,27 +5508,27 @@ impl DeleteVoiceConnectorError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for DeleteVoiceConnectorError {
+impl fmt::Display for BatchSuspendUserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for DeleteVoiceConnectorError {
+impl Error for BatchSuspendUserError {
     fn description(&self) -> &str {
         match *self {
-            DeleteVoiceConnectorError::BadRequest(ref cause) => cause,
-            DeleteVoiceConnectorError::Forbidden(ref cause) => cause,
-            DeleteVoiceConnectorError::NotFound(ref cause) => cause,
-            DeleteVoiceConnectorError::ServiceFailure(ref cause) => cause,
-            DeleteVoiceConnectorError::ServiceUnavailable(ref cause) => cause,
-            DeleteVoiceConnectorError::ThrottledClient(ref cause) => cause,
-            DeleteVoiceConnectorError::UnauthorizedClient(ref cause) => cause,
+            BatchSuspendUserError::BadRequest(ref cause) => cause,
+            BatchSuspendUserError::Forbidden(ref cause) => cause,
+            BatchSuspendUserError::NotFound(ref cause) => cause,
+            BatchSuspendUserError::ServiceFailure(ref cause) => cause,
+            BatchSuspendUserError::ServiceUnavailable(ref cause) => cause,
+            BatchSuspendUserError::ThrottledClient(ref cause) => cause,
+            BatchSuspendUserError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by DeleteVoiceConnectorOrigination
+/// Errors returned by BatchUnsuspendUser
 #[derive(Debug, PartialEq)]
-pub enum DeleteVoiceConnectorOriginationError {
+pub enum BatchUnsuspendUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -2746,46 +5545,34 @@ pub enum DeleteVoiceConnectorOriginationError {
     UnauthorizedClient(String),
 }
 
-impl DeleteVoiceConnectorOriginationError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<DeleteVoiceConnectorOriginationError> {
+impl BatchUnsuspendUserError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<BatchUnsuspendUserError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(BatchUnsuspendUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(BatchUnsuspendUserError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(BatchUnsuspendUserError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorOriginationError::ServiceFailure(err.msg),
-                    )
+                    return RusotoError::Service(BatchUnsuspendUserError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
-                    )
+                    return RusotoError::Service(BatchUnsuspendUserError::ServiceUnavailable(
+                        err.msg,
+                    ))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorOriginationError::ThrottledClient(err.msg),
-                    )
+                    return RusotoError::Service(BatchUnsuspendUserError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
-                    )
+                    return RusotoError::Service(BatchUnsuspendUserError::UnauthorizedClient(
+                        err.msg,
+                    ))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2794,27 +5581,27 @@ impl DeleteVoiceConnectorOriginationError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for DeleteVoiceConnectorOriginationError {
+impl fmt::Display for BatchUnsuspendUserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for DeleteVoiceConnectorOriginationError {
+impl Error for BatchUnsuspendUserError {
     fn description(&self) -> &str {
         match *self {
-            DeleteVoiceConnectorOriginationError::BadRequest(ref cause) => cause,
-            DeleteVoiceConnectorOriginationError::Forbidden(ref cause) => cause,
-            DeleteVoiceConnectorOriginationError::NotFound(ref cause) => cause,
-            DeleteVoiceConnectorOriginationError::ServiceFailure(ref cause) => cause,
-            DeleteVoiceConnectorOriginationError::ServiceUnavailable(ref cause) => cause,
-            DeleteVoiceConnectorOriginationError::ThrottledClient(ref cause) => cause,
-            DeleteVoiceConnectorOriginationError::UnauthorizedClient(ref cause) => cause,
+            BatchUnsuspendUserError::BadRequest(ref cause) => cause,
+            BatchUnsuspendUserError::Forbidden(ref cause) => cause,
+            BatchUnsuspendUserError::NotFound(ref cause) => cause,
+            BatchUnsuspendUserError::ServiceFailure(ref cause) => cause,
+            BatchUnsuspendUserError::ServiceUnavailable(ref cause) => cause,
+            BatchUnsuspendUserError::ThrottledClient(ref cause) => cause,
+            BatchUnsuspendUserError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by DeleteVoiceConnectorTermination
+/// Errors returned by BatchUpdatePhoneNumber
 #[derive(Debug, PartialEq)]
-pub enum DeleteVoiceConnectorTerminationError {
+pub enum BatchUpdatePhoneNumberError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -2831,46 +5618,38 @@ pub enum DeleteVoiceConnectorTerminationError {
     UnauthorizedClient(String),
 }
 
-impl DeleteVoiceConnectorTerminationError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<DeleteVoiceConnectorTerminationError> {
+impl BatchUpdatePhoneNumberError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<BatchUpdatePhoneNumberError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(BatchUpdatePhoneNumberError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(BatchUpdatePhoneNumberError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(BatchUpdatePhoneNumberError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationError::ServiceFailure(err.msg),
-                    )
+                    return RusotoError::Service(BatchUpdatePhoneNumberError::ServiceFailure(
+                        err.msg,
+                    ))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
-                    )
+                    return RusotoError::Service(BatchUpdatePhoneNumberError::ServiceUnavailable(
+                        err.msg,
+                    ))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationError::ThrottledClient(err.msg),
-                    )
+                    return RusotoError::Service(BatchUpdatePhoneNumberError::ThrottledClient(
+                        err.msg,
+                    ))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
-                    )
+                    return RusotoError::Service(BatchUpdatePhoneNumberError::UnauthorizedClient(
+                        err.msg,
+                    ))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2879,27 +5658,27 @@ impl DeleteVoiceConnectorTerminationError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for DeleteVoiceConnectorTerminationError {
+impl fmt::Display for BatchUpdatePhoneNumberError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for DeleteVoiceConnectorTerminationError {
+impl Error for BatchUpdatePhoneNumberError {
     fn description(&self) -> &str {
         match *self {
-            DeleteVoiceConnectorTerminationError::BadRequest(ref cause) => cause,
-            DeleteVoiceConnectorTerminationError::Forbidden(ref cause) => cause,
-            DeleteVoiceConnectorTerminationError::NotFound(ref cause) => cause,
-            DeleteVoiceConnectorTerminationError::ServiceFailure(ref cause) => cause,
-            DeleteVoiceConnectorTerminationError::ServiceUnavailable(ref cause) => cause,
-            DeleteVoiceConnectorTerminationError::ThrottledClient(ref cause) => cause,
-            DeleteVoiceConnectorTerminationError::UnauthorizedClient(ref cause) => cause,
+            BatchUpdatePhoneNumberError::BadRequest(ref cause) => cause,
+            BatchUpdatePhoneNumberError::Forbidden(ref cause) => cause,
+            BatchUpdatePhoneNumberError::NotFound(ref cause) => cause,
+            BatchUpdatePhoneNumberError::ServiceFailure(ref cause) => cause,
+            BatchUpdatePhoneNumberError::ServiceUnavailable(ref cause) => cause,
+            BatchUpdatePhoneNumberError::ThrottledClient(ref cause) => cause,
+            BatchUpdatePhoneNumberError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by DeleteVoiceConnectorTerminationCredentials
+/// Errors returned by BatchUpdateUser
 #[derive(Debug, PartialEq)]
-pub enum DeleteVoiceConnectorTerminationCredentialsError {
+pub enum BatchUpdateUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -2916,50 +5695,30 @@ pub enum DeleteVoiceConnectorTerminationCredentialsError {
     UnauthorizedClient(String),
 }
 
-impl DeleteVoiceConnectorTerminationCredentialsError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<DeleteVoiceConnectorTerminationCredentialsError> {
+impl BatchUpdateUserError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<BatchUpdateUserError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
-                    )
+                    return RusotoError::Service(BatchUpdateUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
-                    )
+                    return RusotoError::Service(BatchUpdateUserError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
-                    )
+                    return RusotoError::Service(BatchUpdateUserError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
-                    )
+                    return RusotoError::Service(BatchUpdateUserError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::ServiceUnavailable(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::Service(BatchUpdateUserError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
-                    )
+                    return RusotoError::Service(BatchUpdateUserError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::UnauthorizedClient(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::Service(BatchUpdateUserError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2968,27 +5727,27 @@ impl DeleteVoiceConnectorTerminationCredentialsError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for DeleteVoiceConnectorTerminationCredentialsError {
+impl fmt::Display for BatchUpdateUserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for DeleteVoiceConnectorTerminationCredentialsError {
+impl Error for BatchUpdateUserError {
     fn description(&self) -> &str {
         match *self {
-            DeleteVoiceConnectorTerminationCredentialsError::BadRequest(ref cause) => cause,
-            DeleteVoiceConnectorTerminationCredentialsError::Forbidden(ref cause) => cause,
-            DeleteVoiceConnectorTerminationCredentialsError::NotFound(ref cause) => cause,
-            DeleteVoiceConnectorTerminationCredentialsError::ServiceFailure(ref cause) => cause,
-            DeleteVoiceConnectorTerminationCredentialsError::ServiceUnavailable(ref cause) => cause,
-            DeleteVoiceConnectorTerminationCredentialsError::ThrottledClient(ref cause) => cause,
-            DeleteVoiceConnectorTerminationCredentialsError::UnauthorizedClient(ref cause) => cause,
+            BatchUpdateUserError::BadRequest(ref cause) => cause,
+            BatchUpdateUserError::Forbidden(ref cause) => cause,
+            BatchUpdateUserError::NotFound(ref cause) => cause,
+            BatchUpdateUserError::ServiceFailure(ref cause) => cause,
+            BatchUpdateUserError::ServiceUnavailable(ref cause) => cause,
+            BatchUpdateUserError::ThrottledClient(ref cause) => cause,
+            BatchUpdateUserError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by DisassociatePhoneNumberFromUser
+/// Errors returned by CreateAccount
 #[derive(Debug, PartialEq)]
-pub enum DisassociatePhoneNumberFromUserError {
+pub enum CreateAccountError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -3005,46 +5764,30 @@ pub enum DisassociatePhoneNumberFromUserError {
     UnauthorizedClient(String),
 }
 
-impl DisassociatePhoneNumberFromUserError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<DisassociatePhoneNumberFromUserError> {
+impl CreateAccountError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateAccountError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(CreateAccountError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(CreateAccountError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(CreateAccountError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumberFromUserError::ServiceFailure(err.msg),
-                    )
+                    return RusotoError::Service(CreateAccountError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumberFromUserError::ServiceUnavailable(err.msg),
-                    )
+                    return RusotoError::Service(CreateAccountError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumberFromUserError::ThrottledClient(err.msg),
-                    )
+                    return RusotoError::Service(CreateAccountError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumberFromUserError::UnauthorizedClient(err.msg),
-                    )
+                    return RusotoError::Service(CreateAccountError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3053,87 +5796,67 @@ impl DisassociatePhoneNumberFromUserError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for DisassociatePhoneNumberFromUserError {
+impl fmt::Display for CreateAccountError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for DisassociatePhoneNumberFromUserError {
+impl Error for CreateAccountError {
     fn description(&self) -> &str {
         match *self {
-            DisassociatePhoneNumberFromUserError::BadRequest(ref cause) => cause,
-            DisassociatePhoneNumberFromUserError::Forbidden(ref cause) => cause,
-            DisassociatePhoneNumberFromUserError::NotFound(ref cause) => cause,
-            DisassociatePhoneNumberFromUserError::ServiceFailure(ref cause) => cause,
-            DisassociatePhoneNumberFromUserError::ServiceUnavailable(ref cause) => cause,
-            DisassociatePhoneNumberFromUserError::ThrottledClient(ref cause) => cause,
-            DisassociatePhoneNumberFromUserError::UnauthorizedClient(ref cause) => cause,
+            CreateAccountError::BadRequest(ref cause) => cause,
+            CreateAccountError::Forbidden(ref cause) => cause,
+            CreateAccountError::NotFound(ref cause) => cause,
+            CreateAccountError::ServiceFailure(ref cause) => cause,
+            CreateAccountError::ServiceUnavailable(ref cause) => cause,
+            CreateAccountError::ThrottledClient(ref cause) => cause,
+            CreateAccountError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by DisassociatePhoneNumbersFromVoiceConnector
+/// Errors returned by CreateBot
 #[derive(Debug, PartialEq)]
-pub enum DisassociatePhoneNumbersFromVoiceConnectorError {
+pub enum CreateBotError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
     /// <p>One or more of the resources in the request does not exist in the system.</p>
     NotFound(String),
+    /// <p>The request exceeds the resource limit.</p>
+    ResourceLimitExceeded(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
     ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
 }
 
-impl DisassociatePhoneNumbersFromVoiceConnectorError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<DisassociatePhoneNumbersFromVoiceConnectorError> {
+impl CreateBotError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateBotError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::BadRequest(err.msg),
-                    )
+                    return RusotoError::Service(CreateBotError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::Forbidden(err.msg),
-                    )
+                    return RusotoError::Service(CreateBotError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::NotFound(err.msg),
-                    )
+                    return RusotoError::Service(CreateBotError::NotFound(err.msg))
+                }
+                "ResourceLimitExceededException" => {
+                    return RusotoError::Service(CreateBotError::ResourceLimitExceeded(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::ServiceFailure(err.msg),
-                    )
+                    return RusotoError::Service(CreateBotError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::ServiceUnavailable(
-                            err.msg,
-                        ),
-                    )
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::ThrottledClient(err.msg),
-                    )
+                    return RusotoError::Service(CreateBotError::ServiceUnavailable(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::UnauthorizedClient(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::Service(CreateBotError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3142,33 +5865,31 @@ impl DisassociatePhoneNumbersFromVoiceConnectorError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for DisassociatePhoneNumbersFromVoiceConnectorError {
+impl fmt::Display for CreateBotError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for DisassociatePhoneNumbersFromVoiceConnectorError {
+impl Error for CreateBotError {
     fn description(&self) -> &str {
         match *self {
-            DisassociatePhoneNumbersFromVoiceConnectorError::BadRequest(ref cause) => cause,
-            DisassociatePhoneNumbersFromVoiceConnectorError::Forbidden(ref cause) => cause,
-            DisassociatePhoneNumbersFromVoiceConnectorError::NotFound(ref cause) => cause,
-            DisassociatePhoneNumbersFromVoiceConnectorError::ServiceFailure(ref cause) => cause,
-            DisassociatePhoneNumbersFromVoiceConnectorError::ServiceUnavailable(ref cause) => cause,
-            DisassociatePhoneNumbersFromVoiceConnectorError::ThrottledClient(ref cause) => cause,
-            DisassociatePhoneNumbersFromVoiceConnectorError::UnauthorizedClient(ref cause) => cause,
+            CreateBotError::BadRequest(ref cause) => cause,
+            CreateBotError::Forbidden(ref cause) => cause,
+            CreateBotError::NotFound(ref cause) => cause,
+            CreateBotError::ResourceLimitExceeded(ref cause) => cause,
+            CreateBotError::ServiceFailure(ref cause) => cause,
+            CreateBotError::ServiceUnavailable(ref cause) => cause,
+            CreateBotError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by GetAccount
+/// Errors returned by CreatePhoneNumberOrder
 #[derive(Debug, PartialEq)]
-pub enum GetAccountError {
+pub enum CreatePhoneNumberOrderError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
@@ -3179,30 +5900,35 @@ pub enum GetAccountError {
     UnauthorizedClient(String),
 }
 
-impl GetAccountError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetAccountError> {
+impl CreatePhoneNumberOrderError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreatePhoneNumberOrderError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAccountError::BadRequest(err.msg))
+                    return RusotoError::Service(CreatePhoneNumberOrderError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetAccountError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(GetAccountError::NotFound(err.msg))
+                    return RusotoError::Service(CreatePhoneNumberOrderError::Forbidden(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetAccountError::ServiceFailure(err.msg))
+                    return RusotoError::Service(CreatePhoneNumberOrderError::ServiceFailure(
+                        err.msg,
+                    ))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(CreatePhoneNumberOrderError::ServiceUnavailable(
+                        err.msg,
+                    ))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetAccountError::ThrottledClient(err.msg))
+                    return RusotoError::Service(CreatePhoneNumberOrderError::ThrottledClient(
+                        err.msg,
+                    ))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(CreatePhoneNumberOrderError::UnauthorizedClient(
+                        err.msg,
+                    ))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3211,33 +5937,30 @@ impl GetAccountError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetAccountError {
+impl fmt::Display for CreatePhoneNumberOrderError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetAccountError {
+impl Error for CreatePhoneNumberOrderError {
     fn description(&self) -> &str {
         match *self {
-            GetAccountError::BadRequest(ref cause) => cause,
-            GetAccountError::Forbidden(ref cause) => cause,
-            GetAccountError::NotFound(ref cause) => cause,
-            GetAccountError::ServiceFailure(ref cause) => cause,
-            GetAccountError::ServiceUnavailable(ref cause) => cause,
-            GetAccountError::ThrottledClient(ref cause) => cause,
-            GetAccountError::UnauthorizedClient(ref cause) => cause,
+            CreatePhoneNumberOrderError::BadRequest(ref cause) => cause,
+            CreatePhoneNumberOrderError::Forbidden(ref cause) => cause,
+            CreatePhoneNumberOrderError::ServiceFailure(ref cause) => cause,
+            CreatePhoneNumberOrderError::ServiceUnavailable(ref cause) => cause,
+            CreatePhoneNumberOrderError::ThrottledClient(ref cause) => cause,
+            CreatePhoneNumberOrderError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by GetAccountSettings
+/// Errors returned by CreateVoiceConnector
 #[derive(Debug, PartialEq)]
-pub enum GetAccountSettingsError {
+pub enum CreateVoiceConnectorError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
@@ -3248,32 +5971,31 @@ pub enum GetAccountSettingsError {
     UnauthorizedClient(String),
 }
 
-impl GetAccountSettingsError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetAccountSettingsError> {
+impl CreateVoiceConnectorError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateVoiceConnectorError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAccountSettingsError::BadRequest(err.msg))
+                    return RusotoError::Service(CreateVoiceConnectorError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetAccountSettingsError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(GetAccountSettingsError::NotFound(err.msg))
+                    return RusotoError::Service(CreateVoiceConnectorError::Forbidden(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::Service(CreateVoiceConnectorError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ServiceUnavailable(
+                    return RusotoError::Service(CreateVoiceConnectorError::ServiceUnavailable(
                         err.msg,
                     ))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::Service(CreateVoiceConnectorError::ThrottledClient(
+                        err.msg,
+                    ))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetAccountSettingsError::UnauthorizedClient(
+                    return RusotoError::Service(CreateVoiceConnectorError::UnauthorizedClient(
                         err.msg,
                     ))
                 }
@@ -3284,27 +6006,26 @@ impl GetAccountSettingsError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetAccountSettingsError {
+impl fmt::Display for CreateVoiceConnectorError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetAccountSettingsError {
+impl Error for CreateVoiceConnectorError {
     fn description(&self) -> &str {
         match *self {
-            GetAccountSettingsError::BadRequest(ref cause) => cause,
-            GetAccountSettingsError::Forbidden(ref cause) => cause,
-            GetAccountSettingsError::NotFound(ref cause) => cause,
-            GetAccountSettingsError::ServiceFailure(ref cause) => cause,
-            GetAccountSettingsError::ServiceUnavailable(ref cause) => cause,
-            GetAccountSettingsError::ThrottledClient(ref cause) => cause,
-            GetAccountSettingsError::UnauthorizedClient(ref cause) => cause,
+            CreateVoiceConnectorError::BadRequest(ref cause) => cause,
+            CreateVoiceConnectorError::Forbidden(ref cause) => cause,
+            CreateVoiceConnectorError::ServiceFailure(ref cause) => cause,
+            CreateVoiceConnectorError::ServiceUnavailable(ref cause) => cause,
+            CreateVoiceConnectorError::ThrottledClient(ref cause) => cause,
+            CreateVoiceConnectorError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by GetBot
+/// Errors returned by DeleteAccount
 #[derive(Debug, PartialEq)]
-pub enum GetBotError {
+pub enum DeleteAccountError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -3315,29 +6036,41 @@ pub enum GetBotError {
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
     ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
+    /// <p>The request was well-formed but was unable to be followed due to semantic errors.</p>
+    UnprocessableEntity(String),
 }
 
-impl GetBotError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBotError> {
+impl DeleteAccountError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteAccountError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotError::BadRequest(err.msg))
+                    return RusotoError::Service(DeleteAccountError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetBotError::Forbidden(err.msg))
+                    return RusotoError::Service(DeleteAccountError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(DeleteAccountError::NotFound(err.msg))
                 }
-                "NotFoundException" => return RusotoError::Service(GetBotError::NotFound(err.msg)),
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetBotError::ServiceFailure(err.msg))
+                    return RusotoError::Service(DeleteAccountError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBotError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(DeleteAccountError::ServiceUnavailable(err.msg))
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(DeleteAccountError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetBotError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(DeleteAccountError::UnauthorizedClient(err.msg))
+                }
+                "UnprocessableEntityException" => {
+                    return RusotoError::Service(DeleteAccountError::UnprocessableEntity(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3346,32 +6079,32 @@ impl GetBotError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetBotError {
+impl fmt::Display for DeleteAccountError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetBotError {
+impl Error for DeleteAccountError {
     fn description(&self) -> &str {
         match *self {
-            GetBotError::BadRequest(ref cause) => cause,
-            GetBotError::Forbidden(ref cause) => cause,
-            GetBotError::NotFound(ref cause) => cause,
-            GetBotError::ServiceFailure(ref cause) => cause,
-            GetBotError::ServiceUnavailable(ref cause) => cause,
-            GetBotError::UnauthorizedClient(ref cause) => cause,
+            DeleteAccountError::BadRequest(ref cause) => cause,
+            DeleteAccountError::Forbidden(ref cause) => cause,
+            DeleteAccountError::NotFound(ref cause) => cause,
+            DeleteAccountError::ServiceFailure(ref cause) => cause,
+            DeleteAccountError::ServiceUnavailable(ref cause) => cause,
+            DeleteAccountError::ThrottledClient(ref cause) => cause,
+            DeleteAccountError::UnauthorizedClient(ref cause) => cause,
+            DeleteAccountError::UnprocessableEntity(ref cause) => cause,
         }
     }
 }
-/// Errors returned by GetEventsConfiguration
+/// Errors returned by DeleteEventsConfiguration
 #[derive(Debug, PartialEq)]
-pub enum GetEventsConfigurationError {
+pub enum DeleteEventsConfigurationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
     /// <p>The request exceeds the resource limit.</p>
     ResourceLimitExceeded(String),
     /// <p>The service encountered an unexpected error.</p>
@@ -3382,38 +6115,37 @@ pub enum GetEventsConfigurationError {
     UnauthorizedClient(String),
 }
 
-impl GetEventsConfigurationError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetEventsConfigurationError> {
+impl DeleteEventsConfigurationError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteEventsConfigurationError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::BadRequest(err.msg))
+                    return RusotoError::Service(DeleteEventsConfigurationError::BadRequest(
+                        err.msg,
+                    ))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::NotFound(err.msg))
+                    return RusotoError::Service(DeleteEventsConfigurationError::Forbidden(err.msg))
                 }
                 "ResourceLimitExceededException" => {
                     return RusotoError::Service(
-                        GetEventsConfigurationError::ResourceLimitExceeded(err.msg),
+                        DeleteEventsConfigurationError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::ServiceFailure(
+                    return RusotoError::Service(DeleteEventsConfigurationError::ServiceFailure(
                         err.msg,
                     ))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        DeleteEventsConfigurationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        DeleteEventsConfigurationError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3422,31 +6154,32 @@ impl GetEventsConfigurationError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetEventsConfigurationError {
+impl fmt::Display for DeleteEventsConfigurationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetEventsConfigurationError {
+impl Error for DeleteEventsConfigurationError {
     fn description(&self) -> &str {
         match *self {
-            GetEventsConfigurationError::BadRequest(ref cause) => cause,
-            GetEventsConfigurationError::Forbidden(ref cause) => cause,
-            GetEventsConfigurationError::NotFound(ref cause) => cause,
-            GetEventsConfigurationError::ResourceLimitExceeded(ref cause) => cause,
-            GetEventsConfigurationError::ServiceFailure(ref cause) => cause,
-            GetEventsConfigurationError::ServiceUnavailable(ref cause) => cause,
-            GetEventsConfigurationError::UnauthorizedClient(ref cause) => cause,
+            DeleteEventsConfigurationError::BadRequest(ref cause) => cause,
+            DeleteEventsConfigurationError::Forbidden(ref cause) => cause,
+            DeleteEventsConfigurationError::ResourceLimitExceeded(ref cause) => cause,
+            DeleteEventsConfigurationError::ServiceFailure(ref cause) => cause,
+            DeleteEventsConfigurationError::ServiceUnavailable(ref cause) => cause,
+            DeleteEventsConfigurationError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by GetGlobalSettings
+/// Errors returned by DeletePhoneNumber
 #[derive(Debug, PartialEq)]
-pub enum GetGlobalSettingsError {
+pub enum DeletePhoneNumberError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
@@ -3457,29 +6190,32 @@ pub enum GetGlobalSettingsError {
     UnauthorizedClient(String),
 }
 
-impl GetGlobalSettingsError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetGlobalSettingsError> {
+impl DeletePhoneNumberError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeletePhoneNumberError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::BadRequest(err.msg))
+                    return RusotoError::Service(DeletePhoneNumberError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::Forbidden(err.msg))
+                    return RusotoError::Service(DeletePhoneNumberError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(DeletePhoneNumberError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::Service(DeletePhoneNumberError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::ServiceUnavailable(
+                    return RusotoError::Service(DeletePhoneNumberError::ServiceUnavailable(
                         err.msg,
                     ))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::Service(DeletePhoneNumberError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::UnauthorizedClient(
+                    return RusotoError::Service(DeletePhoneNumberError::UnauthorizedClient(
                         err.msg,
                     ))
                 }
@@ -3490,26 +6226,27 @@ impl GetGlobalSettingsError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetGlobalSettingsError {
+impl fmt::Display for DeletePhoneNumberError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetGlobalSettingsError {
+impl Error for DeletePhoneNumberError {
     fn description(&self) -> &str {
         match *self {
-            GetGlobalSettingsError::BadRequest(ref cause) => cause,
-            GetGlobalSettingsError::Forbidden(ref cause) => cause,
-            GetGlobalSettingsError::ServiceFailure(ref cause) => cause,
-            GetGlobalSettingsError::ServiceUnavailable(ref cause) => cause,
-            GetGlobalSettingsError::ThrottledClient(ref cause) => cause,
-            GetGlobalSettingsError::UnauthorizedClient(ref cause) => cause,
+            DeletePhoneNumberError::BadRequest(ref cause) => cause,
+            DeletePhoneNumberError::Forbidden(ref cause) => cause,
+            DeletePhoneNumberError::NotFound(ref cause) => cause,
+            DeletePhoneNumberError::ServiceFailure(ref cause) => cause,
+            DeletePhoneNumberError::ServiceUnavailable(ref cause) => cause,
+            DeletePhoneNumberError::ThrottledClient(ref cause) => cause,
+            DeletePhoneNumberError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by GetPhoneNumber
+/// Errors returned by DeleteVoiceConnector
 #[derive(Debug, PartialEq)]
-pub enum GetPhoneNumberError {
+pub enum DeleteVoiceConnectorError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -3526,30 +6263,36 @@ pub enum GetPhoneNumberError {
     UnauthorizedClient(String),
 }
 
-impl GetPhoneNumberError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetPhoneNumberError> {
+impl DeleteVoiceConnectorError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteVoiceConnectorError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPhoneNumberError::NotFound(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetPhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPhoneNumberError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorError::ServiceUnavailable(
+                        err.msg,
+                    ))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetPhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorError::ThrottledClient(
+                        err.msg,
+                    ))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetPhoneNumberError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorError::UnauthorizedClient(
+                        err.msg,
+                    ))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3558,27 +6301,27 @@ impl GetPhoneNumberError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetPhoneNumberError {
+impl fmt::Display for DeleteVoiceConnectorError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetPhoneNumberError {
+impl Error for DeleteVoiceConnectorError {
     fn description(&self) -> &str {
         match *self {
-            GetPhoneNumberError::BadRequest(ref cause) => cause,
-            GetPhoneNumberError::Forbidden(ref cause) => cause,
-            GetPhoneNumberError::NotFound(ref cause) => cause,
-            GetPhoneNumberError::ServiceFailure(ref cause) => cause,
-            GetPhoneNumberError::ServiceUnavailable(ref cause) => cause,
-            GetPhoneNumberError::ThrottledClient(ref cause) => cause,
-            GetPhoneNumberError::UnauthorizedClient(ref cause) => cause,
+            DeleteVoiceConnectorError::BadRequest(ref cause) => cause,
+            DeleteVoiceConnectorError::Forbidden(ref cause) => cause,
+            DeleteVoiceConnectorError::NotFound(ref cause) => cause,
+            DeleteVoiceConnectorError::ServiceFailure(ref cause) => cause,
+            DeleteVoiceConnectorError::ServiceUnavailable(ref cause) => cause,
+            DeleteVoiceConnectorError::ThrottledClient(ref cause) => cause,
+            DeleteVoiceConnectorError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by GetPhoneNumberOrder
+/// Errors returned by DeleteVoiceConnectorOrigination
 #[derive(Debug, PartialEq)]
-pub enum GetPhoneNumberOrderError {
+pub enum DeleteVoiceConnectorOriginationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -3595,34 +6338,46 @@ pub enum GetPhoneNumberOrderError {
     UnauthorizedClient(String),
 }
 
-impl GetPhoneNumberOrderError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetPhoneNumberOrderError> {
+impl DeleteVoiceConnectorOriginationError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DeleteVoiceConnectorOriginationError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::BadRequest(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::BadRequest(
+                        err.msg,
+                    ))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::Forbidden(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::Forbidden(
+                        err.msg,
+                    ))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::NotFound(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::NotFound(
+                        err.msg,
+                    ))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::ServiceFailure(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorOriginationError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::ThrottledClient(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorOriginationError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3631,27 +6386,27 @@ impl GetPhoneNumberOrderError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetPhoneNumberOrderError {
+impl fmt::Display for DeleteVoiceConnectorOriginationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetPhoneNumberOrderError {
+impl Error for DeleteVoiceConnectorOriginationError {
     fn description(&self) -> &str {
         match *self {
-            GetPhoneNumberOrderError::BadRequest(ref cause) => cause,
-            GetPhoneNumberOrderError::Forbidden(ref cause) => cause,
-            GetPhoneNumberOrderError::NotFound(ref cause) => cause,
-            GetPhoneNumberOrderError::ServiceFailure(ref cause) => cause,
-            GetPhoneNumberOrderError::ServiceUnavailable(ref cause) => cause,
-            GetPhoneNumberOrderError::ThrottledClient(ref cause) => cause,
-            GetPhoneNumberOrderError::UnauthorizedClient(ref cause) => cause,
-        }
-    }
-}
-/// Errors returned by GetUser
+            DeleteVoiceConnectorOriginationError::BadRequest(ref cause) => cause,
+            DeleteVoiceConnectorOriginationError::Forbidden(ref cause) => cause,
+            DeleteVoiceConnectorOriginationError::NotFound(ref cause) => cause,
+            DeleteVoiceConnectorOriginationError::ServiceFailure(ref cause) => cause,
+            DeleteVoiceConnectorOriginationError::ServiceUnavailable(ref cause) => cause,
+            DeleteVoiceConnectorOriginationError::ThrottledClient(ref cause) => cause,
+            DeleteVoiceConnectorOriginationError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by DeleteVoiceConnectorTermination
 #[derive(Debug, PartialEq)]
-pub enum GetUserError {
+pub enum DeleteVoiceConnectorTerminationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -3668,30 +6423,46 @@ pub enum GetUserError {
     UnauthorizedClient(String),
 }
 
-impl GetUserError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetUserError> {
+impl DeleteVoiceConnectorTerminationError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DeleteVoiceConnectorTerminationError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUserError::BadRequest(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::BadRequest(
+                        err.msg,
+                    ))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetUserError::Forbidden(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::Forbidden(
+                        err.msg,
+                    ))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUserError::NotFound(err.msg))
+                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::NotFound(
+                        err.msg,
+                    ))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetUserError::ServiceFailure(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorTerminationError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetUserError::ThrottledClient(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorTerminationError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3700,27 +6471,27 @@ impl GetUserError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetUserError {
+impl fmt::Display for DeleteVoiceConnectorTerminationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetUserError {
+impl Error for DeleteVoiceConnectorTerminationError {
     fn description(&self) -> &str {
         match *self {
-            GetUserError::BadRequest(ref cause) => cause,
-            GetUserError::Forbidden(ref cause) => cause,
-            GetUserError::NotFound(ref cause) => cause,
-            GetUserError::ServiceFailure(ref cause) => cause,
-            GetUserError::ServiceUnavailable(ref cause) => cause,
-            GetUserError::ThrottledClient(ref cause) => cause,
-            GetUserError::UnauthorizedClient(ref cause) => cause,
+            DeleteVoiceConnectorTerminationError::BadRequest(ref cause) => cause,
+            DeleteVoiceConnectorTerminationError::Forbidden(ref cause) => cause,
+            DeleteVoiceConnectorTerminationError::NotFound(ref cause) => cause,
+            DeleteVoiceConnectorTerminationError::ServiceFailure(ref cause) => cause,
+            DeleteVoiceConnectorTerminationError::ServiceUnavailable(ref cause) => cause,
+            DeleteVoiceConnectorTerminationError::ThrottledClient(ref cause) => cause,
+            DeleteVoiceConnectorTerminationError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by GetUserSettings
+/// Errors returned by DeleteVoiceConnectorTerminationCredentials
 #[derive(Debug, PartialEq)]
-pub enum GetUserSettingsError {
+pub enum DeleteVoiceConnectorTerminationCredentialsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -3737,30 +6508,50 @@ pub enum GetUserSettingsError {
     UnauthorizedClient(String),
 }
 
-impl GetUserSettingsError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetUserSettingsError> {
+impl DeleteVoiceConnectorTerminationCredentialsError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DeleteVoiceConnectorTerminationCredentialsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUserSettingsError::BadRequest(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetUserSettingsError::Forbidden(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUserSettingsError::NotFound(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetUserSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetUserSettingsError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorTerminationCredentialsError::ServiceUnavailable(
+                            err.msg,
+                        ),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetUserSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetUserSettingsError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(
+                        DeleteVoiceConnectorTerminationCredentialsError::UnauthorizedClient(
+                            err.msg,
+                        ),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3769,27 +6560,27 @@ impl GetUserSettingsError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetUserSettingsError {
+impl fmt::Display for DeleteVoiceConnectorTerminationCredentialsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetUserSettingsError {
+impl Error for DeleteVoiceConnectorTerminationCredentialsError {
     fn description(&self) -> &str {
         match *self {
-            GetUserSettingsError::BadRequest(ref cause) => cause,
-            GetUserSettingsError::Forbidden(ref cause) => cause,
-            GetUserSettingsError::NotFound(ref cause) => cause,
-            GetUserSettingsError::ServiceFailure(ref cause) => cause,
-            GetUserSettingsError::ServiceUnavailable(ref cause) => cause,
-            GetUserSettingsError::ThrottledClient(ref cause) => cause,
-            GetUserSettingsError::UnauthorizedClient(ref cause) => cause,
+            DeleteVoiceConnectorTerminationCredentialsError::BadRequest(ref cause) => cause,
+            DeleteVoiceConnectorTerminationCredentialsError::Forbidden(ref cause) => cause,
+            DeleteVoiceConnectorTerminationCredentialsError::NotFound(ref cause) => cause,
+            DeleteVoiceConnectorTerminationCredentialsError::ServiceFailure(ref cause) => cause,
+            DeleteVoiceConnectorTerminationCredentialsError::ServiceUnavailable(ref cause) => cause,
+            DeleteVoiceConnectorTerminationCredentialsError::ThrottledClient(ref cause) => cause,
+            DeleteVoiceConnectorTerminationCredentialsError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by GetVoiceConnector
+/// Errors returned by DisassociatePhoneNumberFromUser
 #[derive(Debug, PartialEq)]
-pub enum GetVoiceConnectorError {
+pub enum DisassociatePhoneNumberFromUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -3806,34 +6597,46 @@ pub enum GetVoiceConnectorError {
     UnauthorizedClient(String),
 }
 
-impl GetVoiceConnectorError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetVoiceConnectorError> {
+impl DisassociatePhoneNumberFromUserError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<DisassociatePhoneNumberFromUserError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::BadRequest(
+                        err.msg,
+                    ))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::Forbidden(
+                        err.msg,
+                    ))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::NotFound(err.msg))
+                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::NotFound(
+                        err.msg,
+                    ))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::Service(
+                        DisassociatePhoneNumberFromUserError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        DisassociatePhoneNumberFromUserError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::ThrottledClient(err.msg))
+                    return RusotoError::Service(
+                        DisassociatePhoneNumberFromUserError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        DisassociatePhoneNumberFromUserError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3842,27 +6645,27 @@ impl GetVoiceConnectorError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetVoiceConnectorError {
+impl fmt::Display for DisassociatePhoneNumberFromUserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetVoiceConnectorError {
+impl Error for DisassociatePhoneNumberFromUserError {
     fn description(&self) -> &str {
         match *self {
-            GetVoiceConnectorError::BadRequest(ref cause) => cause,
-            GetVoiceConnectorError::Forbidden(ref cause) => cause,
-            GetVoiceConnectorError::NotFound(ref cause) => cause,
-            GetVoiceConnectorError::ServiceFailure(ref cause) => cause,
-            GetVoiceConnectorError::ServiceUnavailable(ref cause) => cause,
-            GetVoiceConnectorError::ThrottledClient(ref cause) => cause,
-            GetVoiceConnectorError::UnauthorizedClient(ref cause) => cause,
+            DisassociatePhoneNumberFromUserError::BadRequest(ref cause) => cause,
+            DisassociatePhoneNumberFromUserError::Forbidden(ref cause) => cause,
+            DisassociatePhoneNumberFromUserError::NotFound(ref cause) => cause,
+            DisassociatePhoneNumberFromUserError::ServiceFailure(ref cause) => cause,
+            DisassociatePhoneNumberFromUserError::ServiceUnavailable(ref cause) => cause,
+            DisassociatePhoneNumberFromUserError::ThrottledClient(ref cause) => cause,
+            DisassociatePhoneNumberFromUserError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by GetVoiceConnectorOrigination
+/// Errors returned by DisassociatePhoneNumbersFromVoiceConnector
 #[derive(Debug, PartialEq)]
-pub enum GetVoiceConnectorOriginationError {
+pub enum DisassociatePhoneNumbersFromVoiceConnectorError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -3879,45 +6682,49 @@ pub enum GetVoiceConnectorOriginationError {
     UnauthorizedClient(String),
 }
 
-impl GetVoiceConnectorOriginationError {
+impl DisassociatePhoneNumbersFromVoiceConnectorError {
     pub fn from_response(
         res: BufferedHttpResponse,
-    ) -> RusotoError<GetVoiceConnectorOriginationError> {
+    ) -> RusotoError<DisassociatePhoneNumbersFromVoiceConnectorError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        DisassociatePhoneNumbersFromVoiceConnectorError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        DisassociatePhoneNumbersFromVoiceConnectorError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        DisassociatePhoneNumbersFromVoiceConnectorError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        DisassociatePhoneNumbersFromVoiceConnectorError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(
-                        GetVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
+                        DisassociatePhoneNumbersFromVoiceConnectorError::ServiceUnavailable(
+                            err.msg,
+                        ),
                     )
                 }
                 "ThrottledClientException" => {
                     return RusotoError::Service(
-                        GetVoiceConnectorOriginationError::ThrottledClient(err.msg),
+                        DisassociatePhoneNumbersFromVoiceConnectorError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(
-                        GetVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
+                        DisassociatePhoneNumbersFromVoiceConnectorError::UnauthorizedClient(
+                            err.msg,
+                        ),
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
@@ -3927,27 +6734,27 @@ impl GetVoiceConnectorOriginationError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetVoiceConnectorOriginationError {
+impl fmt::Display for DisassociatePhoneNumbersFromVoiceConnectorError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetVoiceConnectorOriginationError {
+impl Error for DisassociatePhoneNumbersFromVoiceConnectorError {
     fn description(&self) -> &str {
         match *self {
-            GetVoiceConnectorOriginationError::BadRequest(ref cause) => cause,
-            GetVoiceConnectorOriginationError::Forbidden(ref cause) => cause,
-            GetVoiceConnectorOriginationError::NotFound(ref cause) => cause,
-            GetVoiceConnectorOriginationError::ServiceFailure(ref cause) => cause,
-            GetVoiceConnectorOriginationError::ServiceUnavailable(ref cause) => cause,
-            GetVoiceConnectorOriginationError::ThrottledClient(ref cause) => cause,
-            GetVoiceConnectorOriginationError::UnauthorizedClient(ref cause) => cause,
+            DisassociatePhoneNumbersFromVoiceConnectorError::BadRequest(ref cause) => cause,
+            DisassociatePhoneNumbersFromVoiceConnectorError::Forbidden(ref cause) => cause,
+            DisassociatePhoneNumbersFromVoiceConnectorError::NotFound(ref cause) => cause,
+            DisassociatePhoneNumbersFromVoiceConnectorError::ServiceFailure(ref cause) => cause,
+            DisassociatePhoneNumbersFromVoiceConnectorError::ServiceUnavailable(ref cause) => cause,
+            DisassociatePhoneNumbersFromVoiceConnectorError::ThrottledClient(ref cause) => cause,
+            DisassociatePhoneNumbersFromVoiceConnectorError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by GetVoiceConnectorTermination
+/// Errors returned by GetAccount
 #[derive(Debug, PartialEq)]
-pub enum GetVoiceConnectorTerminationError {
+pub enum GetAccountError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -3964,46 +6771,30 @@ pub enum GetVoiceConnectorTerminationError {
     UnauthorizedClient(String),
 }
 
-impl GetVoiceConnectorTerminationError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<GetVoiceConnectorTerminationError> {
+impl GetAccountError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetAccountError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(GetAccountError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(GetAccountError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(GetAccountError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(GetAccountError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
-                    )
+                    return RusotoError::Service(GetAccountError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationError::ThrottledClient(err.msg),
-                    )
+                    return RusotoError::Service(GetAccountError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
-                    )
+                    return RusotoError::Service(GetAccountError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4012,27 +6803,27 @@ impl GetVoiceConnectorTerminationError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetVoiceConnectorTerminationError {
+impl fmt::Display for GetAccountError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetVoiceConnectorTerminationError {
+impl Error for GetAccountError {
     fn description(&self) -> &str {
         match *self {
-            GetVoiceConnectorTerminationError::BadRequest(ref cause) => cause,
-            GetVoiceConnectorTerminationError::Forbidden(ref cause) => cause,
-            GetVoiceConnectorTerminationError::NotFound(ref cause) => cause,
-            GetVoiceConnectorTerminationError::ServiceFailure(ref cause) => cause,
-            GetVoiceConnectorTerminationError::ServiceUnavailable(ref cause) => cause,
-            GetVoiceConnectorTerminationError::ThrottledClient(ref cause) => cause,
-            GetVoiceConnectorTerminationError::UnauthorizedClient(ref cause) => cause,
+            GetAccountError::BadRequest(ref cause) => cause,
+            GetAccountError::Forbidden(ref cause) => cause,
+            GetAccountError::NotFound(ref cause) => cause,
+            GetAccountError::ServiceFailure(ref cause) => cause,
+            GetAccountError::ServiceUnavailable(ref cause) => cause,
+            GetAccountError::ThrottledClient(ref cause) => cause,
+            GetAccountError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by GetVoiceConnectorTerminationHealth
+/// Errors returned by GetAccountSettings
 #[derive(Debug, PartialEq)]
-pub enum GetVoiceConnectorTerminationHealthError {
+pub enum GetAccountSettingsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -4049,46 +6840,34 @@ pub enum GetVoiceConnectorTerminationHealthError {
     UnauthorizedClient(String),
 }
 
-impl GetVoiceConnectorTerminationHealthError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<GetVoiceConnectorTerminationHealthError> {
+impl GetAccountSettingsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetAccountSettingsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationHealthError::BadRequest(err.msg),
-                    )
+                    return RusotoError::Service(GetAccountSettingsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationHealthError::Forbidden(err.msg),
-                    )
+                    return RusotoError::Service(GetAccountSettingsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationHealthError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(GetAccountSettingsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationHealthError::ServiceFailure(err.msg),
-                    )
+                    return RusotoError::Service(GetAccountSettingsError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationHealthError::ServiceUnavailable(err.msg),
-                    )
+                    return RusotoError::Service(GetAccountSettingsError::ServiceUnavailable(
+                        err.msg,
+                    ))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationHealthError::ThrottledClient(err.msg),
-                    )
+                    return RusotoError::Service(GetAccountSettingsError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationHealthError::UnauthorizedClient(err.msg),
-                    )
+                    return RusotoError::Service(GetAccountSettingsError::UnauthorizedClient(
+                        err.msg,
+                    ))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4097,27 +6876,27 @@ impl GetVoiceConnectorTerminationHealthError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for GetVoiceConnectorTerminationHealthError {
+impl fmt::Display for GetAccountSettingsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for GetVoiceConnectorTerminationHealthError {
+impl Error for GetAccountSettingsError {
     fn description(&self) -> &str {
         match *self {
-            GetVoiceConnectorTerminationHealthError::BadRequest(ref cause) => cause,
-            GetVoiceConnectorTerminationHealthError::Forbidden(ref cause) => cause,
-            GetVoiceConnectorTerminationHealthError::NotFound(ref cause) => cause,
-            GetVoiceConnectorTerminationHealthError::ServiceFailure(ref cause) => cause,
-            GetVoiceConnectorTerminationHealthError::ServiceUnavailable(ref cause) => cause,
-            GetVoiceConnectorTerminationHealthError::ThrottledClient(ref cause) => cause,
-            GetVoiceConnectorTerminationHealthError::UnauthorizedClient(ref cause) => cause,
+            GetAccountSettingsError::BadRequest(ref cause) => cause,
+            GetAccountSettingsError::Forbidden(ref cause) => cause,
+            GetAccountSettingsError::NotFound(ref cause) => cause,
+            GetAccountSettingsError::ServiceFailure(ref cause) => cause,
+            GetAccountSettingsError::ServiceUnavailable(ref cause) => cause,
+            GetAccountSettingsError::ThrottledClient(ref cause) => cause,
+            GetAccountSettingsError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by InviteUsers
+/// Errors returned by GetBot
 #[derive(Debug, PartialEq)]
-pub enum InviteUsersError {
+pub enum GetBotError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -4128,36 +6907,29 @@ pub enum InviteUsersError {
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
     ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
 }
 
-impl InviteUsersError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<InviteUsersError> {
+impl GetBotError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetBotError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(InviteUsersError::BadRequest(err.msg))
+                    return RusotoError::Service(GetBotError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(InviteUsersError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(InviteUsersError::NotFound(err.msg))
+                    return RusotoError::Service(GetBotError::Forbidden(err.msg))
                 }
+                "NotFoundException" => return RusotoError::Service(GetBotError::NotFound(err.msg)),
                 "ServiceFailureException" => {
-                    return RusotoError::Service(InviteUsersError::ServiceFailure(err.msg))
+                    return RusotoError::Service(GetBotError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InviteUsersError::ServiceUnavailable(err.msg))
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(InviteUsersError::ThrottledClient(err.msg))
+                    return RusotoError::Service(GetBotError::ServiceUnavailable(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(InviteUsersError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(GetBotError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4166,67 +6938,74 @@ impl InviteUsersError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for InviteUsersError {
+impl fmt::Display for GetBotError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for InviteUsersError {
+impl Error for GetBotError {
     fn description(&self) -> &str {
         match *self {
-            InviteUsersError::BadRequest(ref cause) => cause,
-            InviteUsersError::Forbidden(ref cause) => cause,
-            InviteUsersError::NotFound(ref cause) => cause,
-            InviteUsersError::ServiceFailure(ref cause) => cause,
-            InviteUsersError::ServiceUnavailable(ref cause) => cause,
-            InviteUsersError::ThrottledClient(ref cause) => cause,
-            InviteUsersError::UnauthorizedClient(ref cause) => cause,
+            GetBotError::BadRequest(ref cause) => cause,
+            GetBotError::Forbidden(ref cause) => cause,
+            GetBotError::NotFound(ref cause) => cause,
+            GetBotError::ServiceFailure(ref cause) => cause,
+            GetBotError::ServiceUnavailable(ref cause) => cause,
+            GetBotError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by ListAccounts
+/// Errors returned by GetEventsConfiguration
 #[derive(Debug, PartialEq)]
-pub enum ListAccountsError {
+pub enum GetEventsConfigurationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
     /// <p>One or more of the resources in the request does not exist in the system.</p>
     NotFound(String),
+    /// <p>The request exceeds the resource limit.</p>
+    ResourceLimitExceeded(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
     ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
 }
 
-impl ListAccountsError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAccountsError> {
+impl GetEventsConfigurationError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetEventsConfigurationError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAccountsError::BadRequest(err.msg))
+                    return RusotoError::Service(GetEventsConfigurationError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAccountsError::Forbidden(err.msg))
+                    return RusotoError::Service(GetEventsConfigurationError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAccountsError::NotFound(err.msg))
+                    return RusotoError::Service(GetEventsConfigurationError::NotFound(err.msg))
+                }
+                "ResourceLimitExceededException" => {
+                    return RusotoError::Service(
+                        GetEventsConfigurationError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListAccountsError::ServiceFailure(err.msg))
+                    return RusotoError::Service(GetEventsConfigurationError::ServiceFailure(
+                        err.msg,
+                    ))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAccountsError::ServiceUnavailable(err.msg))
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(ListAccountsError::ThrottledClient(err.msg))
+                    return RusotoError::Service(GetEventsConfigurationError::ServiceUnavailable(
+                        err.msg,
+                    ))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListAccountsError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(GetEventsConfigurationError::UnauthorizedClient(
+                        err.msg,
+                    ))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4235,62 +7014,66 @@ impl ListAccountsError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for ListAccountsError {
+impl fmt::Display for GetEventsConfigurationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for ListAccountsError {
+impl Error for GetEventsConfigurationError {
     fn description(&self) -> &str {
         match *self {
-            ListAccountsError::BadRequest(ref cause) => cause,
-            ListAccountsError::Forbidden(ref cause) => cause,
-            ListAccountsError::NotFound(ref cause) => cause,
-            ListAccountsError::ServiceFailure(ref cause) => cause,
-            ListAccountsError::ServiceUnavailable(ref cause) => cause,
-            ListAccountsError::ThrottledClient(ref cause) => cause,
-            ListAccountsError::UnauthorizedClient(ref cause) => cause,
+            GetEventsConfigurationError::BadRequest(ref cause) => cause,
+            GetEventsConfigurationError::Forbidden(ref cause) => cause,
+            GetEventsConfigurationError::NotFound(ref cause) => cause,
+            GetEventsConfigurationError::ResourceLimitExceeded(ref cause) => cause,
+            GetEventsConfigurationError::ServiceFailure(ref cause) => cause,
+            GetEventsConfigurationError::ServiceUnavailable(ref cause) => cause,
+            GetEventsConfigurationError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by ListBots
+/// Errors returned by GetGlobalSettings
 #[derive(Debug, PartialEq)]
-pub enum ListBotsError {
+pub enum GetGlobalSettingsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
     ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
 }
 
-impl ListBotsError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListBotsError> {
+impl GetGlobalSettingsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetGlobalSettingsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBotsError::BadRequest(err.msg))
+                    return RusotoError::Service(GetGlobalSettingsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListBotsError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(ListBotsError::NotFound(err.msg))
+                    return RusotoError::Service(GetGlobalSettingsError::Forbidden(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListBotsError::ServiceFailure(err.msg))
+                    return RusotoError::Service(GetGlobalSettingsError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBotsError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(GetGlobalSettingsError::ServiceUnavailable(
+                        err.msg,
+                    ))
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(GetGlobalSettingsError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListBotsError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(GetGlobalSettingsError::UnauthorizedClient(
+                        err.msg,
+                    ))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4299,30 +7082,32 @@ impl ListBotsError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for ListBotsError {
+impl fmt::Display for GetGlobalSettingsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for ListBotsError {
+impl Error for GetGlobalSettingsError {
     fn description(&self) -> &str {
         match *self {
-            ListBotsError::BadRequest(ref cause) => cause,
-            ListBotsError::Forbidden(ref cause) => cause,
-            ListBotsError::NotFound(ref cause) => cause,
-            ListBotsError::ServiceFailure(ref cause) => cause,
-            ListBotsError::ServiceUnavailable(ref cause) => cause,
-            ListBotsError::UnauthorizedClient(ref cause) => cause,
+            GetGlobalSettingsError::BadRequest(ref cause) => cause,
+            GetGlobalSettingsError::Forbidden(ref cause) => cause,
+            GetGlobalSettingsError::ServiceFailure(ref cause) => cause,
+            GetGlobalSettingsError::ServiceUnavailable(ref cause) => cause,
+            GetGlobalSettingsError::ThrottledClient(ref cause) => cause,
+            GetGlobalSettingsError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by ListPhoneNumberOrders
+/// Errors returned by GetPhoneNumber
 #[derive(Debug, PartialEq)]
-pub enum ListPhoneNumberOrdersError {
+pub enum GetPhoneNumberError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
@@ -4333,35 +7118,30 @@ pub enum ListPhoneNumberOrdersError {
     UnauthorizedClient(String),
 }
 
-impl ListPhoneNumberOrdersError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListPhoneNumberOrdersError> {
+impl GetPhoneNumberError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetPhoneNumberError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::BadRequest(err.msg))
+                    return RusotoError::Service(GetPhoneNumberError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::Forbidden(err.msg))
+                    return RusotoError::Service(GetPhoneNumberError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(GetPhoneNumberError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(GetPhoneNumberError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(GetPhoneNumberError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(GetPhoneNumberError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(GetPhoneNumberError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4370,30 +7150,33 @@ impl ListPhoneNumberOrdersError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for ListPhoneNumberOrdersError {
+impl fmt::Display for GetPhoneNumberError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for ListPhoneNumberOrdersError {
+impl Error for GetPhoneNumberError {
     fn description(&self) -> &str {
         match *self {
-            ListPhoneNumberOrdersError::BadRequest(ref cause) => cause,
-            ListPhoneNumberOrdersError::Forbidden(ref cause) => cause,
-            ListPhoneNumberOrdersError::ServiceFailure(ref cause) => cause,
-            ListPhoneNumberOrdersError::ServiceUnavailable(ref cause) => cause,
-            ListPhoneNumberOrdersError::ThrottledClient(ref cause) => cause,
-            ListPhoneNumberOrdersError::UnauthorizedClient(ref cause) => cause,
+            GetPhoneNumberError::BadRequest(ref cause) => cause,
+            GetPhoneNumberError::Forbidden(ref cause) => cause,
+            GetPhoneNumberError::NotFound(ref cause) => cause,
+            GetPhoneNumberError::ServiceFailure(ref cause) => cause,
+            GetPhoneNumberError::ServiceUnavailable(ref cause) => cause,
+            GetPhoneNumberError::ThrottledClient(ref cause) => cause,
+            GetPhoneNumberError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by ListPhoneNumbers
+/// Errors returned by GetPhoneNumberOrder
 #[derive(Debug, PartialEq)]
-pub enum ListPhoneNumbersError {
+pub enum GetPhoneNumberOrderError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
@@ -4404,27 +7187,34 @@ pub enum ListPhoneNumbersError {
     UnauthorizedClient(String),
 }
 
-impl ListPhoneNumbersError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListPhoneNumbersError> {
+impl GetPhoneNumberOrderError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetPhoneNumberOrderError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::BadRequest(err.msg))
+                    return RusotoError::Service(GetPhoneNumberOrderError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::Forbidden(err.msg))
+                    return RusotoError::Service(GetPhoneNumberOrderError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(GetPhoneNumberOrderError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ServiceFailure(err.msg))
+                    return RusotoError::Service(GetPhoneNumberOrderError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(GetPhoneNumberOrderError::ServiceUnavailable(
+                        err.msg,
+                    ))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ThrottledClient(err.msg))
+                    return RusotoError::Service(GetPhoneNumberOrderError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(GetPhoneNumberOrderError::UnauthorizedClient(
+                        err.msg,
+                    ))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4433,26 +7223,27 @@ impl ListPhoneNumbersError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for ListPhoneNumbersError {
+impl fmt::Display for GetPhoneNumberOrderError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for ListPhoneNumbersError {
+impl Error for GetPhoneNumberOrderError {
     fn description(&self) -> &str {
         match *self {
-            ListPhoneNumbersError::BadRequest(ref cause) => cause,
-            ListPhoneNumbersError::Forbidden(ref cause) => cause,
-            ListPhoneNumbersError::ServiceFailure(ref cause) => cause,
-            ListPhoneNumbersError::ServiceUnavailable(ref cause) => cause,
-            ListPhoneNumbersError::ThrottledClient(ref cause) => cause,
-            ListPhoneNumbersError::UnauthorizedClient(ref cause) => cause,
+            GetPhoneNumberOrderError::BadRequest(ref cause) => cause,
+            GetPhoneNumberOrderError::Forbidden(ref cause) => cause,
+            GetPhoneNumberOrderError::NotFound(ref cause) => cause,
+            GetPhoneNumberOrderError::ServiceFailure(ref cause) => cause,
+            GetPhoneNumberOrderError::ServiceUnavailable(ref cause) => cause,
+            GetPhoneNumberOrderError::ThrottledClient(ref cause) => cause,
+            GetPhoneNumberOrderError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by ListUsers
+/// Errors returned by GetUser
 #[derive(Debug, PartialEq)]
-pub enum ListUsersError {
+pub enum GetUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -4469,30 +7260,30 @@ pub enum ListUsersError {
     UnauthorizedClient(String),
 }
 
-impl ListUsersError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListUsersError> {
+impl GetUserError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetUserError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListUsersError::BadRequest(err.msg))
+                    return RusotoError::Service(GetUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListUsersError::Forbidden(err.msg))
+                    return RusotoError::Service(GetUserError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListUsersError::NotFound(err.msg))
+                    return RusotoError::Service(GetUserError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListUsersError::ServiceFailure(err.msg))
+                    return RusotoError::Service(GetUserError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListUsersError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(GetUserError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListUsersError::ThrottledClient(err.msg))
+                    return RusotoError::Service(GetUserError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListUsersError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(GetUserError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4501,27 +7292,27 @@ impl ListUsersError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for ListUsersError {
+impl fmt::Display for GetUserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for ListUsersError {
+impl Error for GetUserError {
     fn description(&self) -> &str {
         match *self {
-            ListUsersError::BadRequest(ref cause) => cause,
-            ListUsersError::Forbidden(ref cause) => cause,
-            ListUsersError::NotFound(ref cause) => cause,
-            ListUsersError::ServiceFailure(ref cause) => cause,
-            ListUsersError::ServiceUnavailable(ref cause) => cause,
-            ListUsersError::ThrottledClient(ref cause) => cause,
-            ListUsersError::UnauthorizedClient(ref cause) => cause,
+            GetUserError::BadRequest(ref cause) => cause,
+            GetUserError::Forbidden(ref cause) => cause,
+            GetUserError::NotFound(ref cause) => cause,
+            GetUserError::ServiceFailure(ref cause) => cause,
+            GetUserError::ServiceUnavailable(ref cause) => cause,
+            GetUserError::ThrottledClient(ref cause) => cause,
+            GetUserError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by ListVoiceConnectorTerminationCredentials
+/// Errors returned by GetUserSettings
 #[derive(Debug, PartialEq)]
-pub enum ListVoiceConnectorTerminationCredentialsError {
+pub enum GetUserSettingsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -4538,46 +7329,30 @@ pub enum ListVoiceConnectorTerminationCredentialsError {
     UnauthorizedClient(String),
 }
 
-impl ListVoiceConnectorTerminationCredentialsError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<ListVoiceConnectorTerminationCredentialsError> {
+impl GetUserSettingsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetUserSettingsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
-                    )
+                    return RusotoError::Service(GetUserSettingsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
-                    )
+                    return RusotoError::Service(GetUserSettingsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
-                    )
+                    return RusotoError::Service(GetUserSettingsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
-                    )
+                    return RusotoError::Service(GetUserSettingsError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::ServiceUnavailable(err.msg),
-                    )
+                    return RusotoError::Service(GetUserSettingsError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
-                    )
+                    return RusotoError::Service(GetUserSettingsError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::UnauthorizedClient(err.msg),
-                    )
+                    return RusotoError::Service(GetUserSettingsError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4586,31 +7361,33 @@ impl ListVoiceConnectorTerminationCredentialsError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for ListVoiceConnectorTerminationCredentialsError {
+impl fmt::Display for GetUserSettingsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for ListVoiceConnectorTerminationCredentialsError {
+impl Error for GetUserSettingsError {
     fn description(&self) -> &str {
         match *self {
-            ListVoiceConnectorTerminationCredentialsError::BadRequest(ref cause) => cause,
-            ListVoiceConnectorTerminationCredentialsError::Forbidden(ref cause) => cause,
-            ListVoiceConnectorTerminationCredentialsError::NotFound(ref cause) => cause,
-            ListVoiceConnectorTerminationCredentialsError::ServiceFailure(ref cause) => cause,
-            ListVoiceConnectorTerminationCredentialsError::ServiceUnavailable(ref cause) => cause,
-            ListVoiceConnectorTerminationCredentialsError::ThrottledClient(ref cause) => cause,
-            ListVoiceConnectorTerminationCredentialsError::UnauthorizedClient(ref cause) => cause,
+            GetUserSettingsError::BadRequest(ref cause) => cause,
+            GetUserSettingsError::Forbidden(ref cause) => cause,
+            GetUserSettingsError::NotFound(ref cause) => cause,
+            GetUserSettingsError::ServiceFailure(ref cause) => cause,
+            GetUserSettingsError::ServiceUnavailable(ref cause) => cause,
+            GetUserSettingsError::ThrottledClient(ref cause) => cause,
+            GetUserSettingsError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by ListVoiceConnectors
+/// Errors returned by GetVoiceConnector
 #[derive(Debug, PartialEq)]
-pub enum ListVoiceConnectorsError {
+pub enum GetVoiceConnectorError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
@@ -4621,29 +7398,32 @@ pub enum ListVoiceConnectorsError {
     UnauthorizedClient(String),
 }
 
-impl ListVoiceConnectorsError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListVoiceConnectorsError> {
+impl GetVoiceConnectorError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetVoiceConnectorError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::BadRequest(err.msg))
+                    return RusotoError::Service(GetVoiceConnectorError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::Forbidden(err.msg))
+                    return RusotoError::Service(GetVoiceConnectorError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(GetVoiceConnectorError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::ServiceFailure(err.msg))
+                    return RusotoError::Service(GetVoiceConnectorError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::ServiceUnavailable(
+                    return RusotoError::Service(GetVoiceConnectorError::ServiceUnavailable(
                         err.msg,
                     ))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::ThrottledClient(err.msg))
+                    return RusotoError::Service(GetVoiceConnectorError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::UnauthorizedClient(
+                    return RusotoError::Service(GetVoiceConnectorError::UnauthorizedClient(
                         err.msg,
                     ))
                 }
@@ -4654,26 +7434,27 @@ impl ListVoiceConnectorsError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for ListVoiceConnectorsError {
+impl fmt::Display for GetVoiceConnectorError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for ListVoiceConnectorsError {
+impl Error for GetVoiceConnectorError {
     fn description(&self) -> &str {
         match *self {
-            ListVoiceConnectorsError::BadRequest(ref cause) => cause,
-            ListVoiceConnectorsError::Forbidden(ref cause) => cause,
-            ListVoiceConnectorsError::ServiceFailure(ref cause) => cause,
-            ListVoiceConnectorsError::ServiceUnavailable(ref cause) => cause,
-            ListVoiceConnectorsError::ThrottledClient(ref cause) => cause,
-            ListVoiceConnectorsError::UnauthorizedClient(ref cause) => cause,
+            GetVoiceConnectorError::BadRequest(ref cause) => cause,
+            GetVoiceConnectorError::Forbidden(ref cause) => cause,
+            GetVoiceConnectorError::NotFound(ref cause) => cause,
+            GetVoiceConnectorError::ServiceFailure(ref cause) => cause,
+            GetVoiceConnectorError::ServiceUnavailable(ref cause) => cause,
+            GetVoiceConnectorError::ThrottledClient(ref cause) => cause,
+            GetVoiceConnectorError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by LogoutUser
+/// Errors returned by GetVoiceConnectorOrigination
 #[derive(Debug, PartialEq)]
-pub enum LogoutUserError {
+pub enum GetVoiceConnectorOriginationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -4690,30 +7471,46 @@ pub enum LogoutUserError {
     UnauthorizedClient(String),
 }
 
-impl LogoutUserError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<LogoutUserError> {
+impl GetVoiceConnectorOriginationError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<GetVoiceConnectorOriginationError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(LogoutUserError::BadRequest(err.msg))
+                    return RusotoError::Service(GetVoiceConnectorOriginationError::BadRequest(
+                        err.msg,
+                    ))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(LogoutUserError::Forbidden(err.msg))
+                    return RusotoError::Service(GetVoiceConnectorOriginationError::Forbidden(
+                        err.msg,
+                    ))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(LogoutUserError::NotFound(err.msg))
+                    return RusotoError::Service(GetVoiceConnectorOriginationError::NotFound(
+                        err.msg,
+                    ))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(LogoutUserError::ServiceFailure(err.msg))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(LogoutUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(GetVoiceConnectorOriginationError::ServiceFailure(
+                        err.msg,
+                    ))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(
+                        GetVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(LogoutUserError::ThrottledClient(err.msg))
+                    return RusotoError::Service(
+                        GetVoiceConnectorOriginationError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(LogoutUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(
+                        GetVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4722,75 +7519,83 @@ impl LogoutUserError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for LogoutUserError {
+impl fmt::Display for GetVoiceConnectorOriginationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for LogoutUserError {
+impl Error for GetVoiceConnectorOriginationError {
     fn description(&self) -> &str {
         match *self {
-            LogoutUserError::BadRequest(ref cause) => cause,
-            LogoutUserError::Forbidden(ref cause) => cause,
-            LogoutUserError::NotFound(ref cause) => cause,
-            LogoutUserError::ServiceFailure(ref cause) => cause,
-            LogoutUserError::ServiceUnavailable(ref cause) => cause,
-            LogoutUserError::ThrottledClient(ref cause) => cause,
-            LogoutUserError::UnauthorizedClient(ref cause) => cause,
+            GetVoiceConnectorOriginationError::BadRequest(ref cause) => cause,
+            GetVoiceConnectorOriginationError::Forbidden(ref cause) => cause,
+            GetVoiceConnectorOriginationError::NotFound(ref cause) => cause,
+            GetVoiceConnectorOriginationError::ServiceFailure(ref cause) => cause,
+            GetVoiceConnectorOriginationError::ServiceUnavailable(ref cause) => cause,
+            GetVoiceConnectorOriginationError::ThrottledClient(ref cause) => cause,
+            GetVoiceConnectorOriginationError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by PutEventsConfiguration
+/// Errors returned by GetVoiceConnectorTermination
 #[derive(Debug, PartialEq)]
-pub enum PutEventsConfigurationError {
+pub enum GetVoiceConnectorTerminationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
     /// <p>One or more of the resources in the request does not exist in the system.</p>
     NotFound(String),
-    /// <p>The request exceeds the resource limit.</p>
-    ResourceLimitExceeded(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
     ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
 }
 
-impl PutEventsConfigurationError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutEventsConfigurationError> {
+impl GetVoiceConnectorTerminationError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<GetVoiceConnectorTerminationError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::BadRequest(err.msg))
+                    return RusotoError::Service(GetVoiceConnectorTerminationError::BadRequest(
+                        err.msg,
+                    ))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::Forbidden(err.msg))
+                    return RusotoError::Service(GetVoiceConnectorTerminationError::Forbidden(
+                        err.msg,
+                    ))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::NotFound(err.msg))
-                }
-                "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        PutEventsConfigurationError::ResourceLimitExceeded(err.msg),
-                    )
+                    return RusotoError::Service(GetVoiceConnectorTerminationError::NotFound(
+                        err.msg,
+                    ))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::ServiceFailure(
+                    return RusotoError::Service(GetVoiceConnectorTerminationError::ServiceFailure(
                         err.msg,
                     ))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        GetVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
+                    )
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(
+                        GetVoiceConnectorTerminationError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        GetVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4799,27 +7604,27 @@ impl PutEventsConfigurationError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for PutEventsConfigurationError {
+impl fmt::Display for GetVoiceConnectorTerminationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for PutEventsConfigurationError {
+impl Error for GetVoiceConnectorTerminationError {
     fn description(&self) -> &str {
         match *self {
-            PutEventsConfigurationError::BadRequest(ref cause) => cause,
-            PutEventsConfigurationError::Forbidden(ref cause) => cause,
-            PutEventsConfigurationError::NotFound(ref cause) => cause,
-            PutEventsConfigurationError::ResourceLimitExceeded(ref cause) => cause,
-            PutEventsConfigurationError::ServiceFailure(ref cause) => cause,
-            PutEventsConfigurationError::ServiceUnavailable(ref cause) => cause,
-            PutEventsConfigurationError::UnauthorizedClient(ref cause) => cause,
+            GetVoiceConnectorTerminationError::BadRequest(ref cause) => cause,
+            GetVoiceConnectorTerminationError::Forbidden(ref cause) => cause,
+            GetVoiceConnectorTerminationError::NotFound(ref cause) => cause,
+            GetVoiceConnectorTerminationError::ServiceFailure(ref cause) => cause,
+            GetVoiceConnectorTerminationError::ServiceUnavailable(ref cause) => cause,
+            GetVoiceConnectorTerminationError::ThrottledClient(ref cause) => cause,
+            GetVoiceConnectorTerminationError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by PutVoiceConnectorOrigination
+/// Errors returned by GetVoiceConnectorTerminationHealth
 #[derive(Debug, PartialEq)]
-pub enum PutVoiceConnectorOriginationError {
+pub enum GetVoiceConnectorTerminationHealthError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -4836,45 +7641,45 @@ pub enum PutVoiceConnectorOriginationError {
     UnauthorizedClient(String),
 }
 
-impl PutVoiceConnectorOriginationError {
+impl GetVoiceConnectorTerminationHealthError {
     pub fn from_response(
         res: BufferedHttpResponse,
-    ) -> RusotoError<PutVoiceConnectorOriginationError> {
+    ) -> RusotoError<GetVoiceConnectorTerminationHealthError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        GetVoiceConnectorTerminationHealthError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        GetVoiceConnectorTerminationHealthError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::NotFound(
+                    return RusotoError::Service(GetVoiceConnectorTerminationHealthError::NotFound(
                         err.msg,
                     ))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        GetVoiceConnectorTerminationHealthError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
                     return RusotoError::Service(
-                        PutVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
+                        GetVoiceConnectorTerminationHealthError::ServiceUnavailable(err.msg),
                     )
                 }
                 "ThrottledClientException" => {
                     return RusotoError::Service(
-                        PutVoiceConnectorOriginationError::ThrottledClient(err.msg),
+                        GetVoiceConnectorTerminationHealthError::ThrottledClient(err.msg),
                     )
                 }
                 "UnauthorizedClientException" => {
                     return RusotoError::Service(
-                        PutVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
+                        GetVoiceConnectorTerminationHealthError::UnauthorizedClient(err.msg),
                     )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
@@ -4884,27 +7689,27 @@ impl PutVoiceConnectorOriginationError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for PutVoiceConnectorOriginationError {
+impl fmt::Display for GetVoiceConnectorTerminationHealthError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for PutVoiceConnectorOriginationError {
+impl Error for GetVoiceConnectorTerminationHealthError {
     fn description(&self) -> &str {
         match *self {
-            PutVoiceConnectorOriginationError::BadRequest(ref cause) => cause,
-            PutVoiceConnectorOriginationError::Forbidden(ref cause) => cause,
-            PutVoiceConnectorOriginationError::NotFound(ref cause) => cause,
-            PutVoiceConnectorOriginationError::ServiceFailure(ref cause) => cause,
-            PutVoiceConnectorOriginationError::ServiceUnavailable(ref cause) => cause,
-            PutVoiceConnectorOriginationError::ThrottledClient(ref cause) => cause,
-            PutVoiceConnectorOriginationError::UnauthorizedClient(ref cause) => cause,
+            GetVoiceConnectorTerminationHealthError::BadRequest(ref cause) => cause,
+            GetVoiceConnectorTerminationHealthError::Forbidden(ref cause) => cause,
+            GetVoiceConnectorTerminationHealthError::NotFound(ref cause) => cause,
+            GetVoiceConnectorTerminationHealthError::ServiceFailure(ref cause) => cause,
+            GetVoiceConnectorTerminationHealthError::ServiceUnavailable(ref cause) => cause,
+            GetVoiceConnectorTerminationHealthError::ThrottledClient(ref cause) => cause,
+            GetVoiceConnectorTerminationHealthError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by PutVoiceConnectorTermination
+/// Errors returned by InviteUsers
 #[derive(Debug, PartialEq)]
-pub enum PutVoiceConnectorTerminationError {
+pub enum InviteUsersError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -4921,46 +7726,30 @@ pub enum PutVoiceConnectorTerminationError {
     UnauthorizedClient(String),
 }
 
-impl PutVoiceConnectorTerminationError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<PutVoiceConnectorTerminationError> {
+impl InviteUsersError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<InviteUsersError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(InviteUsersError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(InviteUsersError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::NotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(InviteUsersError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(InviteUsersError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
-                    )
+                    return RusotoError::Service(InviteUsersError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationError::ThrottledClient(err.msg),
-                    )
+                    return RusotoError::Service(InviteUsersError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
-                    )
+                    return RusotoError::Service(InviteUsersError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4969,27 +7758,27 @@ impl PutVoiceConnectorTerminationError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for PutVoiceConnectorTerminationError {
+impl fmt::Display for InviteUsersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for PutVoiceConnectorTerminationError {
+impl Error for InviteUsersError {
     fn description(&self) -> &str {
         match *self {
-            PutVoiceConnectorTerminationError::BadRequest(ref cause) => cause,
-            PutVoiceConnectorTerminationError::Forbidden(ref cause) => cause,
-            PutVoiceConnectorTerminationError::NotFound(ref cause) => cause,
-            PutVoiceConnectorTerminationError::ServiceFailure(ref cause) => cause,
-            PutVoiceConnectorTerminationError::ServiceUnavailable(ref cause) => cause,
-            PutVoiceConnectorTerminationError::ThrottledClient(ref cause) => cause,
-            PutVoiceConnectorTerminationError::UnauthorizedClient(ref cause) => cause,
+            InviteUsersError::BadRequest(ref cause) => cause,
+            InviteUsersError::Forbidden(ref cause) => cause,
+            InviteUsersError::NotFound(ref cause) => cause,
+            InviteUsersError::ServiceFailure(ref cause) => cause,
+            InviteUsersError::ServiceUnavailable(ref cause) => cause,
+            InviteUsersError::ThrottledClient(ref cause) => cause,
+            InviteUsersError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by PutVoiceConnectorTerminationCredentials
+/// Errors returned by ListAccounts
 #[derive(Debug, PartialEq)]
-pub enum PutVoiceConnectorTerminationCredentialsError {
+pub enum ListAccountsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -5006,46 +7795,30 @@ pub enum PutVoiceConnectorTerminationCredentialsError {
     UnauthorizedClient(String),
 }
 
-impl PutVoiceConnectorTerminationCredentialsError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<PutVoiceConnectorTerminationCredentialsError> {
+impl ListAccountsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAccountsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
-                    )
+                    return RusotoError::Service(ListAccountsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
-                    )
+                    return RusotoError::Service(ListAccountsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
-                    )
+                    return RusotoError::Service(ListAccountsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
-                    )
+                    return RusotoError::Service(ListAccountsError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::ServiceUnavailable(err.msg),
-                    )
+                    return RusotoError::Service(ListAccountsError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
-                    )
+                    return RusotoError::Service(ListAccountsError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::UnauthorizedClient(err.msg),
-                    )
+                    return RusotoError::Service(ListAccountsError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5054,27 +7827,27 @@ impl PutVoiceConnectorTerminationCredentialsError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for PutVoiceConnectorTerminationCredentialsError {
+impl fmt::Display for ListAccountsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for PutVoiceConnectorTerminationCredentialsError {
+impl Error for ListAccountsError {
     fn description(&self) -> &str {
         match *self {
-            PutVoiceConnectorTerminationCredentialsError::BadRequest(ref cause) => cause,
-            PutVoiceConnectorTerminationCredentialsError::Forbidden(ref cause) => cause,
-            PutVoiceConnectorTerminationCredentialsError::NotFound(ref cause) => cause,
-            PutVoiceConnectorTerminationCredentialsError::ServiceFailure(ref cause) => cause,
-            PutVoiceConnectorTerminationCredentialsError::ServiceUnavailable(ref cause) => cause,
-            PutVoiceConnectorTerminationCredentialsError::ThrottledClient(ref cause) => cause,
-            PutVoiceConnectorTerminationCredentialsError::UnauthorizedClient(ref cause) => cause,
+            ListAccountsError::BadRequest(ref cause) => cause,
+            ListAccountsError::Forbidden(ref cause) => cause,
+            ListAccountsError::NotFound(ref cause) => cause,
+            ListAccountsError::ServiceFailure(ref cause) => cause,
+            ListAccountsError::ServiceUnavailable(ref cause) => cause,
+            ListAccountsError::ThrottledClient(ref cause) => cause,
+            ListAccountsError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by RegenerateSecurityToken
+/// Errors returned by ListBots
 #[derive(Debug, PartialEq)]
-pub enum RegenerateSecurityTokenError {
+pub enum ListBotsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -5089,33 +7862,27 @@ pub enum RegenerateSecurityTokenError {
     UnauthorizedClient(String),
 }
 
-impl RegenerateSecurityTokenError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RegenerateSecurityTokenError> {
+impl ListBotsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListBotsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::BadRequest(err.msg))
+                    return RusotoError::Service(ListBotsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::Forbidden(err.msg))
+                    return RusotoError::Service(ListBotsError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::NotFound(err.msg))
+                    return RusotoError::Service(ListBotsError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(ListBotsError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(ListBotsError::ServiceUnavailable(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(ListBotsError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5124,32 +7891,30 @@ impl RegenerateSecurityTokenError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for RegenerateSecurityTokenError {
+impl fmt::Display for ListBotsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for RegenerateSecurityTokenError {
+impl Error for ListBotsError {
     fn description(&self) -> &str {
         match *self {
-            RegenerateSecurityTokenError::BadRequest(ref cause) => cause,
-            RegenerateSecurityTokenError::Forbidden(ref cause) => cause,
-            RegenerateSecurityTokenError::NotFound(ref cause) => cause,
-            RegenerateSecurityTokenError::ServiceFailure(ref cause) => cause,
-            RegenerateSecurityTokenError::ServiceUnavailable(ref cause) => cause,
-            RegenerateSecurityTokenError::UnauthorizedClient(ref cause) => cause,
+            ListBotsError::BadRequest(ref cause) => cause,
+            ListBotsError::Forbidden(ref cause) => cause,
+            ListBotsError::NotFound(ref cause) => cause,
+            ListBotsError::ServiceFailure(ref cause) => cause,
+            ListBotsError::ServiceUnavailable(ref cause) => cause,
+            ListBotsError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by ResetPersonalPIN
+/// Errors returned by ListPhoneNumberOrders
 #[derive(Debug, PartialEq)]
-pub enum ResetPersonalPINError {
+pub enum ListPhoneNumberOrdersError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
@@ -5160,30 +7925,35 @@ pub enum ResetPersonalPINError {
     UnauthorizedClient(String),
 }
 
-impl ResetPersonalPINError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ResetPersonalPINError> {
+impl ListPhoneNumberOrdersError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListPhoneNumberOrdersError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ResetPersonalPINError::BadRequest(err.msg))
+                    return RusotoError::Service(ListPhoneNumberOrdersError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ResetPersonalPINError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(ResetPersonalPINError::NotFound(err.msg))
+                    return RusotoError::Service(ListPhoneNumberOrdersError::Forbidden(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ResetPersonalPINError::ServiceFailure(err.msg))
+                    return RusotoError::Service(ListPhoneNumberOrdersError::ServiceFailure(
+                        err.msg,
+                    ))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ResetPersonalPINError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(ListPhoneNumberOrdersError::ServiceUnavailable(
+                        err.msg,
+                    ))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ResetPersonalPINError::ThrottledClient(err.msg))
+                    return RusotoError::Service(ListPhoneNumberOrdersError::ThrottledClient(
+                        err.msg,
+                    ))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ResetPersonalPINError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(ListPhoneNumberOrdersError::UnauthorizedClient(
+                        err.msg,
+                    ))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5192,33 +7962,30 @@ impl ResetPersonalPINError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for ResetPersonalPINError {
+impl fmt::Display for ListPhoneNumberOrdersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for ResetPersonalPINError {
+impl Error for ListPhoneNumberOrdersError {
     fn description(&self) -> &str {
         match *self {
-            ResetPersonalPINError::BadRequest(ref cause) => cause,
-            ResetPersonalPINError::Forbidden(ref cause) => cause,
-            ResetPersonalPINError::NotFound(ref cause) => cause,
-            ResetPersonalPINError::ServiceFailure(ref cause) => cause,
-            ResetPersonalPINError::ServiceUnavailable(ref cause) => cause,
-            ResetPersonalPINError::ThrottledClient(ref cause) => cause,
-            ResetPersonalPINError::UnauthorizedClient(ref cause) => cause,
+            ListPhoneNumberOrdersError::BadRequest(ref cause) => cause,
+            ListPhoneNumberOrdersError::Forbidden(ref cause) => cause,
+            ListPhoneNumberOrdersError::ServiceFailure(ref cause) => cause,
+            ListPhoneNumberOrdersError::ServiceUnavailable(ref cause) => cause,
+            ListPhoneNumberOrdersError::ThrottledClient(ref cause) => cause,
+            ListPhoneNumberOrdersError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by RestorePhoneNumber
+/// Errors returned by ListPhoneNumbers
 #[derive(Debug, PartialEq)]
-pub enum RestorePhoneNumberError {
+pub enum ListPhoneNumbersError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
@@ -5229,34 +7996,27 @@ pub enum RestorePhoneNumberError {
     UnauthorizedClient(String),
 }
 
-impl RestorePhoneNumberError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RestorePhoneNumberError> {
+impl ListPhoneNumbersError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListPhoneNumbersError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::Service(ListPhoneNumbersError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::Service(ListPhoneNumbersError::Forbidden(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::Service(ListPhoneNumbersError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(ListPhoneNumbersError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::Service(ListPhoneNumbersError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(ListPhoneNumbersError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5265,31 +8025,32 @@ impl RestorePhoneNumberError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for RestorePhoneNumberError {
+impl fmt::Display for ListPhoneNumbersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for RestorePhoneNumberError {
+impl Error for ListPhoneNumbersError {
     fn description(&self) -> &str {
         match *self {
-            RestorePhoneNumberError::BadRequest(ref cause) => cause,
-            RestorePhoneNumberError::Forbidden(ref cause) => cause,
-            RestorePhoneNumberError::NotFound(ref cause) => cause,
-            RestorePhoneNumberError::ServiceFailure(ref cause) => cause,
-            RestorePhoneNumberError::ServiceUnavailable(ref cause) => cause,
-            RestorePhoneNumberError::ThrottledClient(ref cause) => cause,
-            RestorePhoneNumberError::UnauthorizedClient(ref cause) => cause,
+            ListPhoneNumbersError::BadRequest(ref cause) => cause,
+            ListPhoneNumbersError::Forbidden(ref cause) => cause,
+            ListPhoneNumbersError::ServiceFailure(ref cause) => cause,
+            ListPhoneNumbersError::ServiceUnavailable(ref cause) => cause,
+            ListPhoneNumbersError::ThrottledClient(ref cause) => cause,
+            ListPhoneNumbersError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by SearchAvailablePhoneNumbers
+/// Errors returned by ListUsers
 #[derive(Debug, PartialEq)]
-pub enum SearchAvailablePhoneNumbersError {
+pub enum ListUsersError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
@@ -5300,41 +8061,30 @@ pub enum SearchAvailablePhoneNumbersError {
     UnauthorizedClient(String),
 }
 
-impl SearchAvailablePhoneNumbersError {
-    pub fn from_response(
-        res: BufferedHttpResponse,
-    ) -> RusotoError<SearchAvailablePhoneNumbersError> {
+impl ListUsersError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListUsersError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(ListUsersError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::Forbidden(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(ListUsersError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(ListUsersError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(ListUsersError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        SearchAvailablePhoneNumbersError::ServiceUnavailable(err.msg),
-                    )
+                    return RusotoError::Service(ListUsersError::ServiceUnavailable(err.msg))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(ListUsersError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        SearchAvailablePhoneNumbersError::UnauthorizedClient(err.msg),
-                    )
+                    return RusotoError::Service(ListUsersError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5343,26 +8093,27 @@ impl SearchAvailablePhoneNumbersError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for SearchAvailablePhoneNumbersError {
+impl fmt::Display for ListUsersError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for SearchAvailablePhoneNumbersError {
+impl Error for ListUsersError {
     fn description(&self) -> &str {
         match *self {
-            SearchAvailablePhoneNumbersError::BadRequest(ref cause) => cause,
-            SearchAvailablePhoneNumbersError::Forbidden(ref cause) => cause,
-            SearchAvailablePhoneNumbersError::ServiceFailure(ref cause) => cause,
-            SearchAvailablePhoneNumbersError::ServiceUnavailable(ref cause) => cause,
-            SearchAvailablePhoneNumbersError::ThrottledClient(ref cause) => cause,
-            SearchAvailablePhoneNumbersError::UnauthorizedClient(ref cause) => cause,
+            ListUsersError::BadRequest(ref cause) => cause,
+            ListUsersError::Forbidden(ref cause) => cause,
+            ListUsersError::NotFound(ref cause) => cause,
+            ListUsersError::ServiceFailure(ref cause) => cause,
+            ListUsersError::ServiceUnavailable(ref cause) => cause,
+            ListUsersError::ThrottledClient(ref cause) => cause,
+            ListUsersError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by UpdateAccount
+/// Errors returned by ListVoiceConnectorTerminationCredentials
 #[derive(Debug, PartialEq)]
-pub enum UpdateAccountError {
+pub enum ListVoiceConnectorTerminationCredentialsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -5379,30 +8130,46 @@ pub enum UpdateAccountError {
     UnauthorizedClient(String),
 }
 
-impl UpdateAccountError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateAccountError> {
+impl ListVoiceConnectorTerminationCredentialsError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<ListVoiceConnectorTerminationCredentialsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAccountError::BadRequest(err.msg))
+                    return RusotoError::Service(
+                        ListVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateAccountError::Forbidden(err.msg))
+                    return RusotoError::Service(
+                        ListVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAccountError::NotFound(err.msg))
+                    return RusotoError::Service(
+                        ListVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateAccountError::ServiceFailure(err.msg))
+                    return RusotoError::Service(
+                        ListVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(
+                        ListVoiceConnectorTerminationCredentialsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateAccountError::ThrottledClient(err.msg))
+                    return RusotoError::Service(
+                        ListVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(
+                        ListVoiceConnectorTerminationCredentialsError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5411,35 +8178,31 @@ impl UpdateAccountError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for UpdateAccountError {
+impl fmt::Display for ListVoiceConnectorTerminationCredentialsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for UpdateAccountError {
+impl Error for ListVoiceConnectorTerminationCredentialsError {
     fn description(&self) -> &str {
         match *self {
-            UpdateAccountError::BadRequest(ref cause) => cause,
-            UpdateAccountError::Forbidden(ref cause) => cause,
-            UpdateAccountError::NotFound(ref cause) => cause,
-            UpdateAccountError::ServiceFailure(ref cause) => cause,
-            UpdateAccountError::ServiceUnavailable(ref cause) => cause,
-            UpdateAccountError::ThrottledClient(ref cause) => cause,
-            UpdateAccountError::UnauthorizedClient(ref cause) => cause,
-        }
-    }
+            ListVoiceConnectorTerminationCredentialsError::BadRequest(ref cause) => cause,
+            ListVoiceConnectorTerminationCredentialsError::Forbidden(ref cause) => cause,
+            ListVoiceConnectorTerminationCredentialsError::NotFound(ref cause) => cause,
+            ListVoiceConnectorTerminationCredentialsError::ServiceFailure(ref cause) => cause,
+            ListVoiceConnectorTerminationCredentialsError::ServiceUnavailable(ref cause) => cause,
+            ListVoiceConnectorTerminationCredentialsError::ThrottledClient(ref cause) => cause,
+            ListVoiceConnectorTerminationCredentialsError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
 }
-/// Errors returned by UpdateAccountSettings
+/// Errors returned by ListVoiceConnectors
 #[derive(Debug, PartialEq)]
-pub enum UpdateAccountSettingsError {
+pub enum ListVoiceConnectorsError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
-    /// <p>The request could not be processed because of conflict in the current state of the resource.</p>
-    Conflict(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
@@ -5450,39 +8213,29 @@ pub enum UpdateAccountSettingsError {
     UnauthorizedClient(String),
 }
 
-impl UpdateAccountSettingsError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateAccountSettingsError> {
+impl ListVoiceConnectorsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListVoiceConnectorsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::BadRequest(err.msg))
-                }
-                "ConflictException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::Conflict(err.msg))
+                    return RusotoError::Service(ListVoiceConnectorsError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::NotFound(err.msg))
+                    return RusotoError::Service(ListVoiceConnectorsError::Forbidden(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ServiceFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(ListVoiceConnectorsError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ServiceUnavailable(
+                    return RusotoError::Service(ListVoiceConnectorsError::ServiceUnavailable(
                         err.msg,
                     ))
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ThrottledClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(ListVoiceConnectorsError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::UnauthorizedClient(
+                    return RusotoError::Service(ListVoiceConnectorsError::UnauthorizedClient(
                         err.msg,
                     ))
                 }
@@ -5493,28 +8246,26 @@ impl UpdateAccountSettingsError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for UpdateAccountSettingsError {
+impl fmt::Display for ListVoiceConnectorsError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for UpdateAccountSettingsError {
+impl Error for ListVoiceConnectorsError {
     fn description(&self) -> &str {
         match *self {
-            UpdateAccountSettingsError::BadRequest(ref cause) => cause,
-            UpdateAccountSettingsError::Conflict(ref cause) => cause,
-            UpdateAccountSettingsError::Forbidden(ref cause) => cause,
-            UpdateAccountSettingsError::NotFound(ref cause) => cause,
-            UpdateAccountSettingsError::ServiceFailure(ref cause) => cause,
-            UpdateAccountSettingsError::ServiceUnavailable(ref cause) => cause,
-            UpdateAccountSettingsError::ThrottledClient(ref cause) => cause,
-            UpdateAccountSettingsError::UnauthorizedClient(ref cause) => cause,
+            ListVoiceConnectorsError::BadRequest(ref cause) => cause,
+            ListVoiceConnectorsError::Forbidden(ref cause) => cause,
+            ListVoiceConnectorsError::ServiceFailure(ref cause) => cause,
+            ListVoiceConnectorsError::ServiceUnavailable(ref cause) => cause,
+            ListVoiceConnectorsError::ThrottledClient(ref cause) => cause,
+            ListVoiceConnectorsError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by UpdateBot
+/// Errors returned by LogoutUser
 #[derive(Debug, PartialEq)]
-pub enum UpdateBotError {
+pub enum LogoutUserError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -5525,31 +8276,36 @@ pub enum UpdateBotError {
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
     ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
 }
 
-impl UpdateBotError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateBotError> {
+impl LogoutUserError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<LogoutUserError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBotError::BadRequest(err.msg))
+                    return RusotoError::Service(LogoutUserError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateBotError::Forbidden(err.msg))
+                    return RusotoError::Service(LogoutUserError::Forbidden(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBotError::NotFound(err.msg))
+                    return RusotoError::Service(LogoutUserError::NotFound(err.msg))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateBotError::ServiceFailure(err.msg))
+                    return RusotoError::Service(LogoutUserError::ServiceFailure(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBotError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(LogoutUserError::ServiceUnavailable(err.msg))
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(LogoutUserError::ThrottledClient(err.msg))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateBotError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(LogoutUserError::UnauthorizedClient(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5558,65 +8314,73 @@ impl UpdateBotError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for UpdateBotError {
+impl fmt::Display for LogoutUserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for UpdateBotError {
+impl Error for LogoutUserError {
     fn description(&self) -> &str {
         match *self {
-            UpdateBotError::BadRequest(ref cause) => cause,
-            UpdateBotError::Forbidden(ref cause) => cause,
-            UpdateBotError::NotFound(ref cause) => cause,
-            UpdateBotError::ServiceFailure(ref cause) => cause,
-            UpdateBotError::ServiceUnavailable(ref cause) => cause,
-            UpdateBotError::UnauthorizedClient(ref cause) => cause,
+            LogoutUserError::BadRequest(ref cause) => cause,
+            LogoutUserError::Forbidden(ref cause) => cause,
+            LogoutUserError::NotFound(ref cause) => cause,
+            LogoutUserError::ServiceFailure(ref cause) => cause,
+            LogoutUserError::ServiceUnavailable(ref cause) => cause,
+            LogoutUserError::ThrottledClient(ref cause) => cause,
+            LogoutUserError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by UpdateGlobalSettings
+/// Errors returned by PutEventsConfiguration
 #[derive(Debug, PartialEq)]
-pub enum UpdateGlobalSettingsError {
+pub enum PutEventsConfigurationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
     Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
+    /// <p>The request exceeds the resource limit.</p>
+    ResourceLimitExceeded(String),
     /// <p>The service encountered an unexpected error.</p>
     ServiceFailure(String),
     /// <p>The service is currently unavailable.</p>
     ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
     /// <p>The client is not currently authorized to make the request.</p>
     UnauthorizedClient(String),
 }
 
-impl UpdateGlobalSettingsError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateGlobalSettingsError> {
+impl PutEventsConfigurationError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutEventsConfigurationError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::BadRequest(err.msg))
+                    return RusotoError::Service(PutEventsConfigurationError::BadRequest(err.msg))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::Forbidden(err.msg))
+                    return RusotoError::Service(PutEventsConfigurationError::Forbidden(err.msg))
                 }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ServiceFailure(err.msg))
+                "NotFoundException" => {
+                    return RusotoError::Service(PutEventsConfigurationError::NotFound(err.msg))
                 }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ServiceUnavailable(
+                "ResourceLimitExceededException" => {
+                    return RusotoError::Service(
+                        PutEventsConfigurationError::ResourceLimitExceeded(err.msg),
+                    )
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(PutEventsConfigurationError::ServiceFailure(
                         err.msg,
                     ))
                 }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ThrottledClient(
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(PutEventsConfigurationError::ServiceUnavailable(
                         err.msg,
                     ))
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::UnauthorizedClient(
+                    return RusotoError::Service(PutEventsConfigurationError::UnauthorizedClient(
                         err.msg,
                     ))
                 }
@@ -5627,26 +8391,27 @@ impl UpdateGlobalSettingsError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for UpdateGlobalSettingsError {
+impl fmt::Display for PutEventsConfigurationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for UpdateGlobalSettingsError {
+impl Error for PutEventsConfigurationError {
     fn description(&self) -> &str {
         match *self {
-            UpdateGlobalSettingsError::BadRequest(ref cause) => cause,
-            UpdateGlobalSettingsError::Forbidden(ref cause) => cause,
-            UpdateGlobalSettingsError::ServiceFailure(ref cause) => cause,
-            UpdateGlobalSettingsError::ServiceUnavailable(ref cause) => cause,
-            UpdateGlobalSettingsError::ThrottledClient(ref cause) => cause,
-            UpdateGlobalSettingsError::UnauthorizedClient(ref cause) => cause,
+            PutEventsConfigurationError::BadRequest(ref cause) => cause,
+            PutEventsConfigurationError::Forbidden(ref cause) => cause,
+            PutEventsConfigurationError::NotFound(ref cause) => cause,
+            PutEventsConfigurationError::ResourceLimitExceeded(ref cause) => cause,
+            PutEventsConfigurationError::ServiceFailure(ref cause) => cause,
+            PutEventsConfigurationError::ServiceUnavailable(ref cause) => cause,
+            PutEventsConfigurationError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by UpdatePhoneNumber
+/// Errors returned by PutVoiceConnectorOrigination
 #[derive(Debug, PartialEq)]
-pub enum UpdatePhoneNumberError {
+pub enum PutVoiceConnectorOriginationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -5663,34 +8428,46 @@ pub enum UpdatePhoneNumberError {
     UnauthorizedClient(String),
 }
 
-impl UpdatePhoneNumberError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdatePhoneNumberError> {
+impl PutVoiceConnectorOriginationError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<PutVoiceConnectorOriginationError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::Service(PutVoiceConnectorOriginationError::BadRequest(
+                        err.msg,
+                    ))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::Service(PutVoiceConnectorOriginationError::Forbidden(
+                        err.msg,
+                    ))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::Service(PutVoiceConnectorOriginationError::NotFound(
+                        err.msg,
+                    ))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::ServiceFailure(err.msg))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::ServiceUnavailable(
+                    return RusotoError::Service(PutVoiceConnectorOriginationError::ServiceFailure(
                         err.msg,
                     ))
                 }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(
+                        PutVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
+                    )
+                }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::Service(
+                        PutVoiceConnectorOriginationError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::UnauthorizedClient(
-                        err.msg,
-                    ))
+                    return RusotoError::Service(
+                        PutVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5699,27 +8476,27 @@ impl UpdatePhoneNumberError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for UpdatePhoneNumberError {
+impl fmt::Display for PutVoiceConnectorOriginationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for UpdatePhoneNumberError {
+impl Error for PutVoiceConnectorOriginationError {
     fn description(&self) -> &str {
         match *self {
-            UpdatePhoneNumberError::BadRequest(ref cause) => cause,
-            UpdatePhoneNumberError::Forbidden(ref cause) => cause,
-            UpdatePhoneNumberError::NotFound(ref cause) => cause,
-            UpdatePhoneNumberError::ServiceFailure(ref cause) => cause,
-            UpdatePhoneNumberError::ServiceUnavailable(ref cause) => cause,
-            UpdatePhoneNumberError::ThrottledClient(ref cause) => cause,
-            UpdatePhoneNumberError::UnauthorizedClient(ref cause) => cause,
+            PutVoiceConnectorOriginationError::BadRequest(ref cause) => cause,
+            PutVoiceConnectorOriginationError::Forbidden(ref cause) => cause,
+            PutVoiceConnectorOriginationError::NotFound(ref cause) => cause,
+            PutVoiceConnectorOriginationError::ServiceFailure(ref cause) => cause,
+            PutVoiceConnectorOriginationError::ServiceUnavailable(ref cause) => cause,
+            PutVoiceConnectorOriginationError::ThrottledClient(ref cause) => cause,
+            PutVoiceConnectorOriginationError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by UpdateUser
+/// Errors returned by PutVoiceConnectorTermination
 #[derive(Debug, PartialEq)]
-pub enum UpdateUserError {
+pub enum PutVoiceConnectorTerminationError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -5736,30 +8513,46 @@ pub enum UpdateUserError {
     UnauthorizedClient(String),
 }
 
-impl UpdateUserError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateUserError> {
+impl PutVoiceConnectorTerminationError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<PutVoiceConnectorTerminationError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUserError::BadRequest(err.msg))
+                    return RusotoError::Service(PutVoiceConnectorTerminationError::BadRequest(
+                        err.msg,
+                    ))
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateUserError::Forbidden(err.msg))
+                    return RusotoError::Service(PutVoiceConnectorTerminationError::Forbidden(
+                        err.msg,
+                    ))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUserError::NotFound(err.msg))
+                    return RusotoError::Service(PutVoiceConnectorTerminationError::NotFound(
+                        err.msg,
+                    ))
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateUserError::ServiceFailure(err.msg))
+                    return RusotoError::Service(PutVoiceConnectorTerminationError::ServiceFailure(
+                        err.msg,
+                    ))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::Service(
+                        PutVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateUserError::ThrottledClient(err.msg))
+                    return RusotoError::Service(
+                        PutVoiceConnectorTerminationError::ThrottledClient(err.msg),
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::Service(
+                        PutVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5768,27 +8561,250 @@ impl UpdateUserError {
         return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for UpdateUserError {
+impl fmt::Display for PutVoiceConnectorTerminationError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.description())
     }
 }
-impl Error for UpdateUserError {
+impl Error for PutVoiceConnectorTerminationError {
     fn description(&self) -> &str {
         match *self {
-            UpdateUserError::BadRequest(ref cause) => cause,
-            UpdateUserError::Forbidden(ref cause) => cause,
-            UpdateUserError::NotFound(ref cause) => cause,
-            UpdateUserError::ServiceFailure(ref cause) => cause,
-            UpdateUserError::ServiceUnavailable(ref cause) => cause,
-            UpdateUserError::ThrottledClient(ref cause) => cause,
-            UpdateUserError::UnauthorizedClient(ref cause) => cause,
+            PutVoiceConnectorTerminationError::BadRequest(ref cause) => cause,
+            PutVoiceConnectorTerminationError::Forbidden(ref cause) => cause,
+            PutVoiceConnectorTerminationError::NotFound(ref cause) => cause,
+            PutVoiceConnectorTerminationError::ServiceFailure(ref cause) => cause,
+            PutVoiceConnectorTerminationError::ServiceUnavailable(ref cause) => cause,
+            PutVoiceConnectorTerminationError::ThrottledClient(ref cause) => cause,
+            PutVoiceConnectorTerminationError::UnauthorizedClient(ref cause) => cause,
         }
     }
 }
-/// Errors returned by UpdateUserSettings
+/// Errors returned by PutVoiceConnectorTerminationCredentials
 #[derive(Debug, PartialEq)]
-pub enum UpdateUserSettingsError {
+pub enum PutVoiceConnectorTerminationCredentialsError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
+    Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
+    /// <p>The service encountered an unexpected error.</p>
+    ServiceFailure(String),
+    /// <p>The service is currently unavailable.</p>
+    ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
+    /// <p>The client is not currently authorized to make the request.</p>
+    UnauthorizedClient(String),
+}
+
+impl PutVoiceConnectorTerminationCredentialsError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<PutVoiceConnectorTerminationCredentialsError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(
+                        PutVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
+                    )
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(
+                        PutVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
+                    )
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(
+                        PutVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
+                    )
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(
+                        PutVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
+                    )
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(
+                        PutVoiceConnectorTerminationCredentialsError::ServiceUnavailable(err.msg),
+                    )
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(
+                        PutVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
+                    )
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(
+                        PutVoiceConnectorTerminationCredentialsError::UnauthorizedClient(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for PutVoiceConnectorTerminationCredentialsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for PutVoiceConnectorTerminationCredentialsError {
+    fn description(&self) -> &str {
+        match *self {
+            PutVoiceConnectorTerminationCredentialsError::BadRequest(ref cause) => cause,
+            PutVoiceConnectorTerminationCredentialsError::Forbidden(ref cause) => cause,
+            PutVoiceConnectorTerminationCredentialsError::NotFound(ref cause) => cause,
+            PutVoiceConnectorTerminationCredentialsError::ServiceFailure(ref cause) => cause,
+            PutVoiceConnectorTerminationCredentialsError::ServiceUnavailable(ref cause) => cause,
+            PutVoiceConnectorTerminationCredentialsError::ThrottledClient(ref cause) => cause,
+            PutVoiceConnectorTerminationCredentialsError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by RegenerateSecurityToken
+#[derive(Debug, PartialEq)]
+pub enum RegenerateSecurityTokenError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
+    Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
+    /// <p>The service encountered an unexpected error.</p>
+    ServiceFailure(String),
+    /// <p>The service is currently unavailable.</p>
+    ServiceUnavailable(String),
+    /// <p>The client is not currently authorized to make the request.</p>
+    UnauthorizedClient(String),
+}
+
+impl RegenerateSecurityTokenError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RegenerateSecurityTokenError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(RegenerateSecurityTokenError::BadRequest(err.msg))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(RegenerateSecurityTokenError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(RegenerateSecurityTokenError::NotFound(err.msg))
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(RegenerateSecurityTokenError::ServiceFailure(
+                        err.msg,
+                    ))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(RegenerateSecurityTokenError::ServiceUnavailable(
+                        err.msg,
+                    ))
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(RegenerateSecurityTokenError::UnauthorizedClient(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for RegenerateSecurityTokenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for RegenerateSecurityTokenError {
+    fn description(&self) -> &str {
+        match *self {
+            RegenerateSecurityTokenError::BadRequest(ref cause) => cause,
+            RegenerateSecurityTokenError::Forbidden(ref cause) => cause,
+            RegenerateSecurityTokenError::NotFound(ref cause) => cause,
+            RegenerateSecurityTokenError::ServiceFailure(ref cause) => cause,
+            RegenerateSecurityTokenError::ServiceUnavailable(ref cause) => cause,
+            RegenerateSecurityTokenError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by ResetPersonalPIN
+#[derive(Debug, PartialEq)]
+pub enum ResetPersonalPINError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
+    Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
+    /// <p>The service encountered an unexpected error.</p>
+    ServiceFailure(String),
+    /// <p>The service is currently unavailable.</p>
+    ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
+    /// <p>The client is not currently authorized to make the request.</p>
+    UnauthorizedClient(String),
+}
+
+impl ResetPersonalPINError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ResetPersonalPINError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(ResetPersonalPINError::BadRequest(err.msg))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(ResetPersonalPINError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(ResetPersonalPINError::NotFound(err.msg))
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(ResetPersonalPINError::ServiceFailure(err.msg))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(ResetPersonalPINError::ServiceUnavailable(err.msg))
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(ResetPersonalPINError::ThrottledClient(err.msg))
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(ResetPersonalPINError::UnauthorizedClient(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for ResetPersonalPINError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for ResetPersonalPINError {
+    fn description(&self) -> &str {
+        match *self {
+            ResetPersonalPINError::BadRequest(ref cause) => cause,
+            ResetPersonalPINError::Forbidden(ref cause) => cause,
+            ResetPersonalPINError::NotFound(ref cause) => cause,
+            ResetPersonalPINError::ServiceFailure(ref cause) => cause,
+            ResetPersonalPINError::ServiceUnavailable(ref cause) => cause,
+            ResetPersonalPINError::ThrottledClient(ref cause) => cause,
+            ResetPersonalPINError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by RestorePhoneNumber
+#[derive(Debug, PartialEq)]
+pub enum RestorePhoneNumberError {
     /// <p>The input parameters don't match the service's restrictions.</p>
     BadRequest(String),
     /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
@@ -5805,487 +8821,1423 @@ pub enum UpdateUserSettingsError {
     UnauthorizedClient(String),
 }
 
-impl UpdateUserSettingsError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateUserSettingsError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::BadRequest(err.msg))
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::NotFound(err.msg))
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::ServiceFailure(err.msg))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::ServiceUnavailable(
-                        err.msg,
-                    ))
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::ThrottledClient(err.msg))
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::UnauthorizedClient(
-                        err.msg,
-                    ))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
-    }
-}
-impl fmt::Display for UpdateUserSettingsError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-impl Error for UpdateUserSettingsError {
-    fn description(&self) -> &str {
-        match *self {
-            UpdateUserSettingsError::BadRequest(ref cause) => cause,
-            UpdateUserSettingsError::Forbidden(ref cause) => cause,
-            UpdateUserSettingsError::NotFound(ref cause) => cause,
-            UpdateUserSettingsError::ServiceFailure(ref cause) => cause,
-            UpdateUserSettingsError::ServiceUnavailable(ref cause) => cause,
-            UpdateUserSettingsError::ThrottledClient(ref cause) => cause,
-            UpdateUserSettingsError::UnauthorizedClient(ref cause) => cause,
-        }
-    }
-}
-/// Errors returned by UpdateVoiceConnector
-#[derive(Debug, PartialEq)]
-pub enum UpdateVoiceConnectorError {
-    /// <p>The input parameters don't match the service's restrictions.</p>
-    BadRequest(String),
-    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
-    Forbidden(String),
-    /// <p>One or more of the resources in the request does not exist in the system.</p>
-    NotFound(String),
-    /// <p>The service encountered an unexpected error.</p>
-    ServiceFailure(String),
-    /// <p>The service is currently unavailable.</p>
-    ServiceUnavailable(String),
-    /// <p>The client exceeded its request rate limit.</p>
-    ThrottledClient(String),
-    /// <p>The client is not currently authorized to make the request.</p>
-    UnauthorizedClient(String),
-}
+impl RestorePhoneNumberError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RestorePhoneNumberError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(RestorePhoneNumberError::BadRequest(err.msg))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(RestorePhoneNumberError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(RestorePhoneNumberError::NotFound(err.msg))
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(RestorePhoneNumberError::ServiceFailure(err.msg))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(RestorePhoneNumberError::ServiceUnavailable(
+                        err.msg,
+                    ))
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(RestorePhoneNumberError::ThrottledClient(err.msg))
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(RestorePhoneNumberError::UnauthorizedClient(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for RestorePhoneNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for RestorePhoneNumberError {
+    fn description(&self) -> &str {
+        match *self {
+            RestorePhoneNumberError::BadRequest(ref cause) => cause,
+            RestorePhoneNumberError::Forbidden(ref cause) => cause,
+            RestorePhoneNumberError::NotFound(ref cause) => cause,
+            RestorePhoneNumberError::ServiceFailure(ref cause) => cause,
+            RestorePhoneNumberError::ServiceUnavailable(ref cause) => cause,
+            RestorePhoneNumberError::ThrottledClient(ref cause) => cause,
+            RestorePhoneNumberError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by SearchAvailablePhoneNumbers
+#[derive(Debug, PartialEq)]
+pub enum SearchAvailablePhoneNumbersError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
+    Forbidden(String),
+    /// <p>The service encountered an unexpected error.</p>
+    ServiceFailure(String),
+    /// <p>The service is currently unavailable.</p>
+    ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
+    /// <p>The client is not currently authorized to make the request.</p>
+    UnauthorizedClient(String),
+}
+
+impl SearchAvailablePhoneNumbersError {
+    pub fn from_response(
+        res: BufferedHttpResponse,
+    ) -> RusotoError<SearchAvailablePhoneNumbersError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(SearchAvailablePhoneNumbersError::BadRequest(
+                        err.msg,
+                    ))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(SearchAvailablePhoneNumbersError::Forbidden(
+                        err.msg,
+                    ))
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(SearchAvailablePhoneNumbersError::ServiceFailure(
+                        err.msg,
+                    ))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(
+                        SearchAvailablePhoneNumbersError::ServiceUnavailable(err.msg),
+                    )
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(SearchAvailablePhoneNumbersError::ThrottledClient(
+                        err.msg,
+                    ))
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(
+                        SearchAvailablePhoneNumbersError::UnauthorizedClient(err.msg),
+                    )
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for SearchAvailablePhoneNumbersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for SearchAvailablePhoneNumbersError {
+    fn description(&self) -> &str {
+        match *self {
+            SearchAvailablePhoneNumbersError::BadRequest(ref cause) => cause,
+            SearchAvailablePhoneNumbersError::Forbidden(ref cause) => cause,
+            SearchAvailablePhoneNumbersError::ServiceFailure(ref cause) => cause,
+            SearchAvailablePhoneNumbersError::ServiceUnavailable(ref cause) => cause,
+            SearchAvailablePhoneNumbersError::ThrottledClient(ref cause) => cause,
+            SearchAvailablePhoneNumbersError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdateAccount
+#[derive(Debug, PartialEq)]
+pub enum UpdateAccountError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
+    Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
+    /// <p>The service encountered an unexpected error.</p>
+    ServiceFailure(String),
+    /// <p>The service is currently unavailable.</p>
+    ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
+    /// <p>The client is not currently authorized to make the request.</p>
+    UnauthorizedClient(String),
+}
+
+impl UpdateAccountError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateAccountError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(UpdateAccountError::BadRequest(err.msg))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(UpdateAccountError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(UpdateAccountError::NotFound(err.msg))
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(UpdateAccountError::ServiceFailure(err.msg))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(UpdateAccountError::ServiceUnavailable(err.msg))
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(UpdateAccountError::ThrottledClient(err.msg))
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(UpdateAccountError::UnauthorizedClient(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdateAccountError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdateAccountError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateAccountError::BadRequest(ref cause) => cause,
+            UpdateAccountError::Forbidden(ref cause) => cause,
+            UpdateAccountError::NotFound(ref cause) => cause,
+            UpdateAccountError::ServiceFailure(ref cause) => cause,
+            UpdateAccountError::ServiceUnavailable(ref cause) => cause,
+            UpdateAccountError::ThrottledClient(ref cause) => cause,
+            UpdateAccountError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdateAccountSettings
+#[derive(Debug, PartialEq)]
+pub enum UpdateAccountSettingsError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The request could not be processed because of conflict in the current state of the resource.</p>
+    Conflict(String),
+    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
+    Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
+    /// <p>The service encountered an unexpected error.</p>
+    ServiceFailure(String),
+    /// <p>The service is currently unavailable.</p>
+    ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
+    /// <p>The client is not currently authorized to make the request.</p>
+    UnauthorizedClient(String),
+}
+
+impl UpdateAccountSettingsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateAccountSettingsError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(UpdateAccountSettingsError::BadRequest(err.msg))
+                }
+                "ConflictException" => {
+                    return RusotoError::Service(UpdateAccountSettingsError::Conflict(err.msg))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(UpdateAccountSettingsError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(UpdateAccountSettingsError::NotFound(err.msg))
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(UpdateAccountSettingsError::ServiceFailure(
+                        err.msg,
+                    ))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(UpdateAccountSettingsError::ServiceUnavailable(
+                        err.msg,
+                    ))
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(UpdateAccountSettingsError::ThrottledClient(
+                        err.msg,
+                    ))
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(UpdateAccountSettingsError::UnauthorizedClient(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdateAccountSettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdateAccountSettingsError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateAccountSettingsError::BadRequest(ref cause) => cause,
+            UpdateAccountSettingsError::Conflict(ref cause) => cause,
+            UpdateAccountSettingsError::Forbidden(ref cause) => cause,
+            UpdateAccountSettingsError::NotFound(ref cause) => cause,
+            UpdateAccountSettingsError::ServiceFailure(ref cause) => cause,
+            UpdateAccountSettingsError::ServiceUnavailable(ref cause) => cause,
+            UpdateAccountSettingsError::ThrottledClient(ref cause) => cause,
+            UpdateAccountSettingsError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdateBot
+#[derive(Debug, PartialEq)]
+pub enum UpdateBotError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
+    Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
+    /// <p>The service encountered an unexpected error.</p>
+    ServiceFailure(String),
+    /// <p>The service is currently unavailable.</p>
+    ServiceUnavailable(String),
+    /// <p>The client is not currently authorized to make the request.</p>
+    UnauthorizedClient(String),
+}
+
+impl UpdateBotError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateBotError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(UpdateBotError::BadRequest(err.msg))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(UpdateBotError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(UpdateBotError::NotFound(err.msg))
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(UpdateBotError::ServiceFailure(err.msg))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(UpdateBotError::ServiceUnavailable(err.msg))
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(UpdateBotError::UnauthorizedClient(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdateBotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdateBotError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateBotError::BadRequest(ref cause) => cause,
+            UpdateBotError::Forbidden(ref cause) => cause,
+            UpdateBotError::NotFound(ref cause) => cause,
+            UpdateBotError::ServiceFailure(ref cause) => cause,
+            UpdateBotError::ServiceUnavailable(ref cause) => cause,
+            UpdateBotError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdateGlobalSettings
+#[derive(Debug, PartialEq)]
+pub enum UpdateGlobalSettingsError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
+    Forbidden(String),
+    /// <p>The service encountered an unexpected error.</p>
+    ServiceFailure(String),
+    /// <p>The service is currently unavailable.</p>
+    ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
+    /// <p>The client is not currently authorized to make the request.</p>
+    UnauthorizedClient(String),
+}
+
+impl UpdateGlobalSettingsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateGlobalSettingsError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(UpdateGlobalSettingsError::BadRequest(err.msg))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(UpdateGlobalSettingsError::Forbidden(err.msg))
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(UpdateGlobalSettingsError::ServiceFailure(err.msg))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(UpdateGlobalSettingsError::ServiceUnavailable(
+                        err.msg,
+                    ))
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(UpdateGlobalSettingsError::ThrottledClient(
+                        err.msg,
+                    ))
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(UpdateGlobalSettingsError::UnauthorizedClient(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdateGlobalSettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdateGlobalSettingsError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateGlobalSettingsError::BadRequest(ref cause) => cause,
+            UpdateGlobalSettingsError::Forbidden(ref cause) => cause,
+            UpdateGlobalSettingsError::ServiceFailure(ref cause) => cause,
+            UpdateGlobalSettingsError::ServiceUnavailable(ref cause) => cause,
+            UpdateGlobalSettingsError::ThrottledClient(ref cause) => cause,
+            UpdateGlobalSettingsError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdatePhoneNumber
+#[derive(Debug, PartialEq)]
+pub enum UpdatePhoneNumberError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
+    Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
+    /// <p>The service encountered an unexpected error.</p>
+    ServiceFailure(String),
+    /// <p>The service is currently unavailable.</p>
+    ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
+    /// <p>The client is not currently authorized to make the request.</p>
+    UnauthorizedClient(String),
+}
+
+impl UpdatePhoneNumberError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdatePhoneNumberError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(UpdatePhoneNumberError::BadRequest(err.msg))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(UpdatePhoneNumberError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(UpdatePhoneNumberError::NotFound(err.msg))
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(UpdatePhoneNumberError::ServiceFailure(err.msg))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(UpdatePhoneNumberError::ServiceUnavailable(
+                        err.msg,
+                    ))
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(UpdatePhoneNumberError::ThrottledClient(err.msg))
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(UpdatePhoneNumberError::UnauthorizedClient(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdatePhoneNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdatePhoneNumberError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdatePhoneNumberError::BadRequest(ref cause) => cause,
+            UpdatePhoneNumberError::Forbidden(ref cause) => cause,
+            UpdatePhoneNumberError::NotFound(ref cause) => cause,
+            UpdatePhoneNumberError::ServiceFailure(ref cause) => cause,
+            UpdatePhoneNumberError::ServiceUnavailable(ref cause) => cause,
+            UpdatePhoneNumberError::ThrottledClient(ref cause) => cause,
+            UpdatePhoneNumberError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdateUser
+#[derive(Debug, PartialEq)]
+pub enum UpdateUserError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
+    Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
+    /// <p>The service encountered an unexpected error.</p>
+    ServiceFailure(String),
+    /// <p>The service is currently unavailable.</p>
+    ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
+    /// <p>The client is not currently authorized to make the request.</p>
+    UnauthorizedClient(String),
+}
+
+impl UpdateUserError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateUserError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(UpdateUserError::BadRequest(err.msg))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(UpdateUserError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(UpdateUserError::NotFound(err.msg))
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(UpdateUserError::ServiceFailure(err.msg))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(UpdateUserError::ServiceUnavailable(err.msg))
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(UpdateUserError::ThrottledClient(err.msg))
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(UpdateUserError::UnauthorizedClient(err.msg))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdateUserError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdateUserError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateUserError::BadRequest(ref cause) => cause,
+            UpdateUserError::Forbidden(ref cause) => cause,
+            UpdateUserError::NotFound(ref cause) => cause,
+            UpdateUserError::ServiceFailure(ref cause) => cause,
+            UpdateUserError::ServiceUnavailable(ref cause) => cause,
+            UpdateUserError::ThrottledClient(ref cause) => cause,
+            UpdateUserError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdateUserSettings
+#[derive(Debug, PartialEq)]
+pub enum UpdateUserSettingsError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
+    Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
+    /// <p>The service encountered an unexpected error.</p>
+    ServiceFailure(String),
+    /// <p>The service is currently unavailable.</p>
+    ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
+    /// <p>The client is not currently authorized to make the request.</p>
+    UnauthorizedClient(String),
+}
+
+impl UpdateUserSettingsError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateUserSettingsError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(UpdateUserSettingsError::BadRequest(err.msg))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(UpdateUserSettingsError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(UpdateUserSettingsError::NotFound(err.msg))
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(UpdateUserSettingsError::ServiceFailure(err.msg))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(UpdateUserSettingsError::ServiceUnavailable(
+                        err.msg,
+                    ))
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(UpdateUserSettingsError::ThrottledClient(err.msg))
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(UpdateUserSettingsError::UnauthorizedClient(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdateUserSettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdateUserSettingsError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateUserSettingsError::BadRequest(ref cause) => cause,
+            UpdateUserSettingsError::Forbidden(ref cause) => cause,
+            UpdateUserSettingsError::NotFound(ref cause) => cause,
+            UpdateUserSettingsError::ServiceFailure(ref cause) => cause,
+            UpdateUserSettingsError::ServiceUnavailable(ref cause) => cause,
+            UpdateUserSettingsError::ThrottledClient(ref cause) => cause,
+            UpdateUserSettingsError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Errors returned by UpdateVoiceConnector
+#[derive(Debug, PartialEq)]
+pub enum UpdateVoiceConnectorError {
+    /// <p>The input parameters don't match the service's restrictions.</p>
+    BadRequest(String),
+    /// <p>The client is permanently forbidden from making the request. For example, when a user tries to create an account from an unsupported Region.</p>
+    Forbidden(String),
+    /// <p>One or more of the resources in the request does not exist in the system.</p>
+    NotFound(String),
+    /// <p>The service encountered an unexpected error.</p>
+    ServiceFailure(String),
+    /// <p>The service is currently unavailable.</p>
+    ServiceUnavailable(String),
+    /// <p>The client exceeded its request rate limit.</p>
+    ThrottledClient(String),
+    /// <p>The client is not currently authorized to make the request.</p>
+    UnauthorizedClient(String),
+}
+
+impl UpdateVoiceConnectorError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateVoiceConnectorError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(UpdateVoiceConnectorError::BadRequest(err.msg))
+                }
+                "ForbiddenException" => {
+                    return RusotoError::Service(UpdateVoiceConnectorError::Forbidden(err.msg))
+                }
+                "NotFoundException" => {
+                    return RusotoError::Service(UpdateVoiceConnectorError::NotFound(err.msg))
+                }
+                "ServiceFailureException" => {
+                    return RusotoError::Service(UpdateVoiceConnectorError::ServiceFailure(err.msg))
+                }
+                "ServiceUnavailableException" => {
+                    return RusotoError::Service(UpdateVoiceConnectorError::ServiceUnavailable(
+                        err.msg,
+                    ))
+                }
+                "ThrottledClientException" => {
+                    return RusotoError::Service(UpdateVoiceConnectorError::ThrottledClient(
+                        err.msg,
+                    ))
+                }
+                "UnauthorizedClientException" => {
+                    return RusotoError::Service(UpdateVoiceConnectorError::UnauthorizedClient(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for UpdateVoiceConnectorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for UpdateVoiceConnectorError {
+    fn description(&self) -> &str {
+        match *self {
+            UpdateVoiceConnectorError::BadRequest(ref cause) => cause,
+            UpdateVoiceConnectorError::Forbidden(ref cause) => cause,
+            UpdateVoiceConnectorError::NotFound(ref cause) => cause,
+            UpdateVoiceConnectorError::ServiceFailure(ref cause) => cause,
+            UpdateVoiceConnectorError::ServiceUnavailable(ref cause) => cause,
+            UpdateVoiceConnectorError::ThrottledClient(ref cause) => cause,
+            UpdateVoiceConnectorError::UnauthorizedClient(ref cause) => cause,
+        }
+    }
+}
+/// Trait representing the capabilities of the Amazon Chime API. Amazon Chime clients implement this trait.
+pub trait Chime {
+    /// <p>Associates a phone number with the specified Amazon Chime user.</p>
+    fn associate_phone_number_with_user(
+        &self,
+        input: AssociatePhoneNumberWithUserRequest,
+    ) -> RusotoFuture<AssociatePhoneNumberWithUserResponse, AssociatePhoneNumberWithUserError>;
+
+    /// <p>Associates a phone number with the specified Amazon Chime Voice Connector.</p>
+    fn associate_phone_numbers_with_voice_connector(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorRequest,
+    ) -> RusotoFuture<
+        AssociatePhoneNumbersWithVoiceConnectorResponse,
+        AssociatePhoneNumbersWithVoiceConnectorError,
+    >;
+
+    /// <p>Moves phone numbers into the <b>Deletion queue</b>. Phone numbers must be disassociated from any users or Amazon Chime Voice Connectors before they can be deleted.</p> <p>Phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
+    fn batch_delete_phone_number(
+        &self,
+        input: BatchDeletePhoneNumberRequest,
+    ) -> RusotoFuture<BatchDeletePhoneNumberResponse, BatchDeletePhoneNumberError>;
+
+    /// <p>Suspends up to 50 users from a <code>Team</code> or <code>EnterpriseLWA</code> Amazon Chime account. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Users suspended from a <code>Team</code> account are dissasociated from the account, but they can continue to use Amazon Chime as free users. To remove the suspension from suspended <code>Team</code> account users, invite them to the <code>Team</code> account again. You can use the <a>InviteUsers</a> action to do so.</p> <p>Users suspended from an <code>EnterpriseLWA</code> account are immediately signed out of Amazon Chime and can no longer sign in. To remove the suspension from suspended <code>EnterpriseLWA</code> account users, use the <a>BatchUnsuspendUser</a> action. </p> <p>To sign out users without suspending them, use the <a>LogoutUser</a> action.</p>
+    fn batch_suspend_user(
+        &self,
+        input: BatchSuspendUserRequest,
+    ) -> RusotoFuture<BatchSuspendUserResponse, BatchSuspendUserError>;
+
+    /// <p>Removes the suspension from up to 50 previously suspended users for the specified Amazon Chime <code>EnterpriseLWA</code> account. Only users on <code>EnterpriseLWA</code> accounts can be unsuspended using this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Previously suspended users who are unsuspended using this action are returned to <code>Registered</code> status. Users who are not previously suspended are ignored.</p>
+    fn batch_unsuspend_user(
+        &self,
+        input: BatchUnsuspendUserRequest,
+    ) -> RusotoFuture<BatchUnsuspendUserResponse, BatchUnsuspendUserError>;
+
+    /// <p>Updates phone number product types. Choose from Amazon Chime Business Calling and Amazon Chime Voice Connector product types. For toll-free numbers, you can use only the Amazon Chime Voice Connector product type.</p>
+    fn batch_update_phone_number(
+        &self,
+        input: BatchUpdatePhoneNumberRequest,
+    ) -> RusotoFuture<BatchUpdatePhoneNumberResponse, BatchUpdatePhoneNumberError>;
+
+    /// <p>Updates user details within the <a>UpdateUserRequestItem</a> object for up to 20 users for the specified Amazon Chime account. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
+    fn batch_update_user(
+        &self,
+        input: BatchUpdateUserRequest,
+    ) -> RusotoFuture<BatchUpdateUserResponse, BatchUpdateUserError>;
+
+    /// <p>Creates an Amazon Chime account under the administrator's AWS account. Only <code>Team</code> account types are currently supported for this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> RusotoFuture<CreateAccountResponse, CreateAccountError>;
+
+    /// <p>Creates a bot for an Amazon Chime Enterprise account.</p>
+    fn create_bot(
+        &self,
+        input: CreateBotRequest,
+    ) -> RusotoFuture<CreateBotResponse, CreateBotError>;
+
+    /// <p>Creates an order for phone numbers to be provisioned. Choose from Amazon Chime Business Calling and Amazon Chime Voice Connector product types. For toll-free numbers, you can use only the Amazon Chime Voice Connector product type.</p>
+    fn create_phone_number_order(
+        &self,
+        input: CreatePhoneNumberOrderRequest,
+    ) -> RusotoFuture<CreatePhoneNumberOrderResponse, CreatePhoneNumberOrderError>;
+
+    /// <p>Creates an Amazon Chime Voice Connector under the administrator's AWS account. Enabling <a>CreateVoiceConnectorRequest$RequireEncryption</a> configures your Amazon Chime Voice Connector to use TLS transport for SIP signaling and Secure RTP (SRTP) for media. Inbound calls use TLS transport, and unencrypted outbound calls are blocked.</p>
+    fn create_voice_connector(
+        &self,
+        input: CreateVoiceConnectorRequest,
+    ) -> RusotoFuture<CreateVoiceConnectorResponse, CreateVoiceConnectorError>;
+
+    /// <p>Deletes the specified Amazon Chime account. You must suspend all users before deleting a <code>Team</code> account. You can use the <a>BatchSuspendUser</a> action to do so.</p> <p>For <code>EnterpriseLWA</code> and <code>EnterpriseAD</code> accounts, you must release the claimed domains for your Amazon Chime account before deletion. As soon as you release the domain, all users under that account are suspended.</p> <p>Deleted accounts appear in your <code>Disabled</code> accounts list for 90 days. To restore a deleted account from your <code>Disabled</code> accounts list, you must contact AWS Support.</p> <p>After 90 days, deleted accounts are permanently removed from your <code>Disabled</code> accounts list.</p>
+    fn delete_account(
+        &self,
+        input: DeleteAccountRequest,
+    ) -> RusotoFuture<DeleteAccountResponse, DeleteAccountError>;
+
+    /// <p>Deletes the events configuration that allows a bot to receive outgoing events.</p>
+    fn delete_events_configuration(
+        &self,
+        input: DeleteEventsConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteEventsConfigurationError>;
+
+    /// <p>Moves the specified phone number into the <b>Deletion queue</b>. A phone number must be disassociated from any users or Amazon Chime Voice Connectors before it can be deleted.</p> <p>Deleted phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
+    fn delete_phone_number(
+        &self,
+        input: DeletePhoneNumberRequest,
+    ) -> RusotoFuture<(), DeletePhoneNumberError>;
+
+    /// <p>Deletes the specified Amazon Chime Voice Connector. Any phone numbers assigned to the Amazon Chime Voice Connector must be unassigned from it before it can be deleted.</p>
+    fn delete_voice_connector(
+        &self,
+        input: DeleteVoiceConnectorRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorError>;
+
+    /// <p>Deletes the origination settings for the specified Amazon Chime Voice Connector.</p>
+    fn delete_voice_connector_origination(
+        &self,
+        input: DeleteVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorOriginationError>;
+
+    /// <p>Deletes the termination settings for the specified Amazon Chime Voice Connector.</p>
+    fn delete_voice_connector_termination(
+        &self,
+        input: DeleteVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationError>;
+
+    /// <p>Deletes the specified SIP credentials used by your equipment to authenticate during call termination.</p>
+    fn delete_voice_connector_termination_credentials(
+        &self,
+        input: DeleteVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationCredentialsError>;
+
+    /// <p>Disassociates the primary provisioned phone number from the specified Amazon Chime user.</p>
+    fn disassociate_phone_number_from_user(
+        &self,
+        input: DisassociatePhoneNumberFromUserRequest,
+    ) -> RusotoFuture<DisassociatePhoneNumberFromUserResponse, DisassociatePhoneNumberFromUserError>;
+
+    /// <p>Disassociates the specified phone number from the specified Amazon Chime Voice Connector.</p>
+    fn disassociate_phone_numbers_from_voice_connector(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
+    ) -> RusotoFuture<
+        DisassociatePhoneNumbersFromVoiceConnectorResponse,
+        DisassociatePhoneNumbersFromVoiceConnectorError,
+    >;
+
+    /// <p>Retrieves details for the specified Amazon Chime account, such as account type and supported licenses.</p>
+    fn get_account(
+        &self,
+        input: GetAccountRequest,
+    ) -> RusotoFuture<GetAccountResponse, GetAccountError>;
+
+    /// <p>Retrieves account settings for the specified Amazon Chime account ID, such as remote control and dial out settings. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    fn get_account_settings(
+        &self,
+        input: GetAccountSettingsRequest,
+    ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError>;
+
+    /// <p>Retrieves details for the specified bot, such as bot email address, bot type, status, and display name.</p>
+    fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError>;
+
+    /// <p>Gets details for an events configuration that allows a bot to receive outgoing events, such as an HTTPS endpoint or Lambda function ARN. </p>
+    fn get_events_configuration(
+        &self,
+        input: GetEventsConfigurationRequest,
+    ) -> RusotoFuture<GetEventsConfigurationResponse, GetEventsConfigurationError>;
+
+    /// <p>Retrieves global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
+    fn get_global_settings(
+        &self,
+    ) -> RusotoFuture<GetGlobalSettingsResponse, GetGlobalSettingsError>;
+
+    /// <p>Retrieves details for the specified phone number ID, such as associations, capabilities, and product type.</p>
+    fn get_phone_number(
+        &self,
+        input: GetPhoneNumberRequest,
+    ) -> RusotoFuture<GetPhoneNumberResponse, GetPhoneNumberError>;
+
+    /// <p>Retrieves details for the specified phone number order, such as order creation timestamp, phone numbers in E.164 format, product type, and order status.</p>
+    fn get_phone_number_order(
+        &self,
+        input: GetPhoneNumberOrderRequest,
+    ) -> RusotoFuture<GetPhoneNumberOrderResponse, GetPhoneNumberOrderError>;
+
+    /// <p>Retrieves details for the specified user ID, such as primary email address, license type, and personal meeting PIN.</p> <p>To retrieve user details with an email address instead of a user ID, use the <a>ListUsers</a> action, and then filter by email address.</p>
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError>;
+
+    /// <p>Retrieves settings for the specified user ID, such as any associated phone number settings.</p>
+    fn get_user_settings(
+        &self,
+        input: GetUserSettingsRequest,
+    ) -> RusotoFuture<GetUserSettingsResponse, GetUserSettingsError>;
+
+    /// <p>Retrieves details for the specified Amazon Chime Voice Connector, such as timestamps, name, outbound host, and encryption requirements.</p>
+    fn get_voice_connector(
+        &self,
+        input: GetVoiceConnectorRequest,
+    ) -> RusotoFuture<GetVoiceConnectorResponse, GetVoiceConnectorError>;
+
+    /// <p>Retrieves origination setting details for the specified Amazon Chime Voice Connector.</p>
+    fn get_voice_connector_origination(
+        &self,
+        input: GetVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<GetVoiceConnectorOriginationResponse, GetVoiceConnectorOriginationError>;
+
+    /// <p>Retrieves termination setting details for the specified Amazon Chime Voice Connector.</p>
+    fn get_voice_connector_termination(
+        &self,
+        input: GetVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<GetVoiceConnectorTerminationResponse, GetVoiceConnectorTerminationError>;
+
+    /// <p>Retrieves information about the last time a SIP <code>OPTIONS</code> ping was received from your SIP infrastructure for the specified Amazon Chime Voice Connector.</p>
+    fn get_voice_connector_termination_health(
+        &self,
+        input: GetVoiceConnectorTerminationHealthRequest,
+    ) -> RusotoFuture<
+        GetVoiceConnectorTerminationHealthResponse,
+        GetVoiceConnectorTerminationHealthError,
+    >;
+
+    /// <p>Sends email invites to as many as 50 users, inviting them to the specified Amazon Chime <code>Team</code> account. Only <code>Team</code> account types are currently supported for this action. </p>
+    fn invite_users(
+        &self,
+        input: InviteUsersRequest,
+    ) -> RusotoFuture<InviteUsersResponse, InviteUsersError>;
+
+    /// <p>Lists the Amazon Chime accounts under the administrator's AWS account. You can filter accounts by account name prefix. To find out which Amazon Chime account a user belongs to, you can filter by the user's email address, which returns one account result.</p>
+    fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> RusotoFuture<ListAccountsResponse, ListAccountsError>;
+
+    /// <p>Lists the bots associated with the administrator's Amazon Chime Enterprise account ID.</p>
+    fn list_bots(&self, input: ListBotsRequest) -> RusotoFuture<ListBotsResponse, ListBotsError>;
+
+    /// <p>Lists the phone number orders for the administrator's Amazon Chime account.</p>
+    fn list_phone_number_orders(
+        &self,
+        input: ListPhoneNumberOrdersRequest,
+    ) -> RusotoFuture<ListPhoneNumberOrdersResponse, ListPhoneNumberOrdersError>;
+
+    /// <p>Lists the phone numbers for the specified Amazon Chime account, Amazon Chime user, or Amazon Chime Voice Connector.</p>
+    fn list_phone_numbers(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> RusotoFuture<ListPhoneNumbersResponse, ListPhoneNumbersError>;
+
+    /// <p>Lists the users that belong to the specified Amazon Chime account. You can specify an email address to list only the user that the email address belongs to.</p>
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError>;
+
+    /// <p>Lists the SIP credentials for the specified Amazon Chime Voice Connector.</p>
+    fn list_voice_connector_termination_credentials(
+        &self,
+        input: ListVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<
+        ListVoiceConnectorTerminationCredentialsResponse,
+        ListVoiceConnectorTerminationCredentialsError,
+    >;
+
+    /// <p>Lists the Amazon Chime Voice Connectors for the administrator's AWS account.</p>
+    fn list_voice_connectors(
+        &self,
+        input: ListVoiceConnectorsRequest,
+    ) -> RusotoFuture<ListVoiceConnectorsResponse, ListVoiceConnectorsError>;
+
+    /// <p>Logs out the specified user from all of the devices they are currently logged into.</p>
+    fn logout_user(
+        &self,
+        input: LogoutUserRequest,
+    ) -> RusotoFuture<LogoutUserResponse, LogoutUserError>;
+
+    /// <p>Creates an events configuration that allows a bot to receive outgoing events sent by Amazon Chime. Choose either an HTTPS endpoint or a Lambda function ARN. For more information, see <a>Bot</a>.</p>
+    fn put_events_configuration(
+        &self,
+        input: PutEventsConfigurationRequest,
+    ) -> RusotoFuture<PutEventsConfigurationResponse, PutEventsConfigurationError>;
+
+    /// <p>Adds origination settings for the specified Amazon Chime Voice Connector.</p>
+    fn put_voice_connector_origination(
+        &self,
+        input: PutVoiceConnectorOriginationRequest,
+    ) -> RusotoFuture<PutVoiceConnectorOriginationResponse, PutVoiceConnectorOriginationError>;
+
+    /// <p>Adds termination settings for the specified Amazon Chime Voice Connector.</p>
+    fn put_voice_connector_termination(
+        &self,
+        input: PutVoiceConnectorTerminationRequest,
+    ) -> RusotoFuture<PutVoiceConnectorTerminationResponse, PutVoiceConnectorTerminationError>;
+
+    /// <p>Adds termination SIP credentials for the specified Amazon Chime Voice Connector.</p>
+    fn put_voice_connector_termination_credentials(
+        &self,
+        input: PutVoiceConnectorTerminationCredentialsRequest,
+    ) -> RusotoFuture<(), PutVoiceConnectorTerminationCredentialsError>;
+
+    /// <p>Regenerates the security token for a bot.</p>
+    fn regenerate_security_token(
+        &self,
+        input: RegenerateSecurityTokenRequest,
+    ) -> RusotoFuture<RegenerateSecurityTokenResponse, RegenerateSecurityTokenError>;
+
+    /// <p>Resets the personal meeting PIN for the specified user on an Amazon Chime account. Returns the <a>User</a> object with the updated personal meeting PIN.</p>
+    fn reset_personal_pin(
+        &self,
+        input: ResetPersonalPINRequest,
+    ) -> RusotoFuture<ResetPersonalPINResponse, ResetPersonalPINError>;
 
-impl UpdateVoiceConnectorError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateVoiceConnectorError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::BadRequest(err.msg))
-                }
-                "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::Forbidden(err.msg))
-                }
-                "NotFoundException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::NotFound(err.msg))
-                }
-                "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::ServiceFailure(err.msg))
-                }
-                "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::ServiceUnavailable(
-                        err.msg,
-                    ))
-                }
-                "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::ThrottledClient(
-                        err.msg,
-                    ))
-                }
-                "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::UnauthorizedClient(
-                        err.msg,
-                    ))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
-        }
-        return RusotoError::Unknown(res);
-    }
-}
-impl fmt::Display for UpdateVoiceConnectorError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-impl Error for UpdateVoiceConnectorError {
-    fn description(&self) -> &str {
-        match *self {
-            UpdateVoiceConnectorError::BadRequest(ref cause) => cause,
-            UpdateVoiceConnectorError::Forbidden(ref cause) => cause,
-            UpdateVoiceConnectorError::NotFound(ref cause) => cause,
-            UpdateVoiceConnectorError::ServiceFailure(ref cause) => cause,
-            UpdateVoiceConnectorError::ServiceUnavailable(ref cause) => cause,
-            UpdateVoiceConnectorError::ThrottledClient(ref cause) => cause,
-            UpdateVoiceConnectorError::UnauthorizedClient(ref cause) => cause,
-        }
-    }
+    /// <p>Moves a phone number from the <b>Deletion queue</b> back into the phone number <b>Inventory</b>.</p>
+    fn restore_phone_number(
+        &self,
+        input: RestorePhoneNumberRequest,
+    ) -> RusotoFuture<RestorePhoneNumberResponse, RestorePhoneNumberError>;
+
+    /// <p>Searches phone numbers that can be ordered.</p>
+    fn search_available_phone_numbers(
+        &self,
+        input: SearchAvailablePhoneNumbersRequest,
+    ) -> RusotoFuture<SearchAvailablePhoneNumbersResponse, SearchAvailablePhoneNumbersError>;
+
+    /// <p>Updates account details for the specified Amazon Chime account. Currently, only account name updates are supported for this action.</p>
+    fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> RusotoFuture<UpdateAccountResponse, UpdateAccountError>;
+
+    /// <p>Updates the settings for the specified Amazon Chime account. You can update settings for remote control of shared screens, or for the dial-out option. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    fn update_account_settings(
+        &self,
+        input: UpdateAccountSettingsRequest,
+    ) -> RusotoFuture<UpdateAccountSettingsResponse, UpdateAccountSettingsError>;
+
+    /// <p>Updates the status of the specified bot, such as starting or stopping the bot from running in your Amazon Chime Enterprise account.</p>
+    fn update_bot(
+        &self,
+        input: UpdateBotRequest,
+    ) -> RusotoFuture<UpdateBotResponse, UpdateBotError>;
+
+    /// <p>Updates global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
+    fn update_global_settings(
+        &self,
+        input: UpdateGlobalSettingsRequest,
+    ) -> RusotoFuture<(), UpdateGlobalSettingsError>;
+
+    /// <p>Updates phone number details, such as product type, for the specified phone number ID. For toll-free numbers, you can use only the Amazon Chime Voice Connector product type.</p>
+    fn update_phone_number(
+        &self,
+        input: UpdatePhoneNumberRequest,
+    ) -> RusotoFuture<UpdatePhoneNumberResponse, UpdatePhoneNumberError>;
+
+    /// <p>Updates user details for a specified user ID. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError>;
+
+    /// <p>Updates the settings for the specified user, such as phone number settings.</p>
+    fn update_user_settings(
+        &self,
+        input: UpdateUserSettingsRequest,
+    ) -> RusotoFuture<(), UpdateUserSettingsError>;
+
+    /// <p>Updates details for the specified Amazon Chime Voice Connector.</p>
+    fn update_voice_connector(
+        &self,
+        input: UpdateVoiceConnectorRequest,
+    ) -> RusotoFuture<UpdateVoiceConnectorResponse, UpdateVoiceConnectorError>;
 }
-/// Trait representing the capabilities of the Amazon Chime API. Amazon Chime clients implement this trait.
-pub trait Chime {
-    /// <p>Associates a phone number with the specified Amazon Chime user.</p>
+impl<T: ?Sized + Chime> Chime for ::std::sync::Arc<T> {
     fn associate_phone_number_with_user(
         &self,
         input: AssociatePhoneNumberWithUserRequest,
-    ) -> RusotoFuture<AssociatePhoneNumberWithUserResponse, AssociatePhoneNumberWithUserError>;
-
-    /// <p>Associates a phone number with the specified Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<AssociatePhoneNumberWithUserResponse, AssociatePhoneNumberWithUserError> {
+        (**self).associate_phone_number_with_user(input)
+    }
     fn associate_phone_numbers_with_voice_connector(
         &self,
         input: AssociatePhoneNumbersWithVoiceConnectorRequest,
     ) -> RusotoFuture<
         AssociatePhoneNumbersWithVoiceConnectorResponse,
         AssociatePhoneNumbersWithVoiceConnectorError,
-    >;
-
-    /// <p>Moves phone numbers into the <b>Deletion queue</b>. Phone numbers must be disassociated from any users or Amazon Chime Voice Connectors before they can be deleted.</p> <p>Phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
+    > {
+        (**self).associate_phone_numbers_with_voice_connector(input)
+    }
     fn batch_delete_phone_number(
         &self,
         input: BatchDeletePhoneNumberRequest,
-    ) -> RusotoFuture<BatchDeletePhoneNumberResponse, BatchDeletePhoneNumberError>;
-
-    /// <p>Suspends up to 50 users from a <code>Team</code> or <code>EnterpriseLWA</code> Amazon Chime account. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Users suspended from a <code>Team</code> account are dissasociated from the account, but they can continue to use Amazon Chime as free users. To remove the suspension from suspended <code>Team</code> account users, invite them to the <code>Team</code> account again. You can use the <a>InviteUsers</a> action to do so.</p> <p>Users suspended from an <code>EnterpriseLWA</code> account are immediately signed out of Amazon Chime and can no longer sign in. To remove the suspension from suspended <code>EnterpriseLWA</code> account users, use the <a>BatchUnsuspendUser</a> action. </p> <p>To sign out users without suspending them, use the <a>LogoutUser</a> action.</p>
+    ) -> RusotoFuture<BatchDeletePhoneNumberResponse, BatchDeletePhoneNumberError> {
+        (**self).batch_delete_phone_number(input)
+    }
     fn batch_suspend_user(
         &self,
         input: BatchSuspendUserRequest,
-    ) -> RusotoFuture<BatchSuspendUserResponse, BatchSuspendUserError>;
-
-    /// <p>Removes the suspension from up to 50 previously suspended users for the specified Amazon Chime <code>EnterpriseLWA</code> account. Only users on <code>EnterpriseLWA</code> accounts can be unsuspended using this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p> <p>Previously suspended users who are unsuspended using this action are returned to <code>Registered</code> status. Users who are not previously suspended are ignored.</p>
+    ) -> RusotoFuture<BatchSuspendUserResponse, BatchSuspendUserError> {
+        (**self).batch_suspend_user(input)
+    }
     fn batch_unsuspend_user(
         &self,
         input: BatchUnsuspendUserRequest,
-    ) -> RusotoFuture<BatchUnsuspendUserResponse, BatchUnsuspendUserError>;
-
-    /// <p>Updates phone number product types. Choose from Amazon Chime Business Calling and Amazon Chime Voice Connector product types. For toll-free numbers, you can use only the Amazon Chime Voice Connector product type.</p>
+    ) -> RusotoFuture<BatchUnsuspendUserResponse, BatchUnsuspendUserError> {
+        (**self).batch_unsuspend_user(input)
+    }
     fn batch_update_phone_number(
         &self,
         input: BatchUpdatePhoneNumberRequest,
-    ) -> RusotoFuture<BatchUpdatePhoneNumberResponse, BatchUpdatePhoneNumberError>;
-
-    /// <p>Updates user details within the <a>UpdateUserRequestItem</a> object for up to 20 users for the specified Amazon Chime account. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
+    ) -> RusotoFuture<BatchUpdatePhoneNumberResponse, BatchUpdatePhoneNumberError> {
+        (**self).batch_update_phone_number(input)
+    }
     fn batch_update_user(
         &self,
         input: BatchUpdateUserRequest,
-    ) -> RusotoFuture<BatchUpdateUserResponse, BatchUpdateUserError>;
-
-    /// <p>Creates an Amazon Chime account under the administrator's AWS account. Only <code>Team</code> account types are currently supported for this action. For more information about different account types, see <a href="https://docs.aws.amazon.com/chime/latest/ag/manage-chime-account.html">Managing Your Amazon Chime Accounts</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    ) -> RusotoFuture<BatchUpdateUserResponse, BatchUpdateUserError> {
+        (**self).batch_update_user(input)
+    }
     fn create_account(
         &self,
         input: CreateAccountRequest,
-    ) -> RusotoFuture<CreateAccountResponse, CreateAccountError>;
-
-    /// <p>Creates a bot for an Amazon Chime Enterprise account.</p>
+    ) -> RusotoFuture<CreateAccountResponse, CreateAccountError> {
+        (**self).create_account(input)
+    }
     fn create_bot(
         &self,
         input: CreateBotRequest,
-    ) -> RusotoFuture<CreateBotResponse, CreateBotError>;
-
-    /// <p>Creates an order for phone numbers to be provisioned. Choose from Amazon Chime Business Calling and Amazon Chime Voice Connector product types. For toll-free numbers, you can use only the Amazon Chime Voice Connector product type.</p>
+    ) -> RusotoFuture<CreateBotResponse, CreateBotError> {
+        (**self).create_bot(input)
+    }
     fn create_phone_number_order(
         &self,
         input: CreatePhoneNumberOrderRequest,
-    ) -> RusotoFuture<CreatePhoneNumberOrderResponse, CreatePhoneNumberOrderError>;
-
-    /// <p>Creates an Amazon Chime Voice Connector under the administrator's AWS account. Enabling <a>CreateVoiceConnectorRequest$RequireEncryption</a> configures your Amazon Chime Voice Connector to use TLS transport for SIP signaling and Secure RTP (SRTP) for media. Inbound calls use TLS transport, and unencrypted outbound calls are blocked.</p>
+    ) -> RusotoFuture<CreatePhoneNumberOrderResponse, CreatePhoneNumberOrderError> {
+        (**self).create_phone_number_order(input)
+    }
     fn create_voice_connector(
         &self,
         input: CreateVoiceConnectorRequest,
-    ) -> RusotoFuture<CreateVoiceConnectorResponse, CreateVoiceConnectorError>;
-
-    /// <p>Deletes the specified Amazon Chime account. You must suspend all users before deleting a <code>Team</code> account. You can use the <a>BatchSuspendUser</a> action to do so.</p> <p>For <code>EnterpriseLWA</code> and <code>EnterpriseAD</code> accounts, you must release the claimed domains for your Amazon Chime account before deletion. As soon as you release the domain, all users under that account are suspended.</p> <p>Deleted accounts appear in your <code>Disabled</code> accounts list for 90 days. To restore a deleted account from your <code>Disabled</code> accounts list, you must contact AWS Support.</p> <p>After 90 days, deleted accounts are permanently removed from your <code>Disabled</code> accounts list.</p>
+    ) -> RusotoFuture<CreateVoiceConnectorResponse, CreateVoiceConnectorError> {
+        (**self).create_voice_connector(input)
+    }
     fn delete_account(
         &self,
         input: DeleteAccountRequest,
-    ) -> RusotoFuture<DeleteAccountResponse, DeleteAccountError>;
-
-    /// <p>Deletes the events configuration that allows a bot to receive outgoing events.</p>
+    ) -> RusotoFuture<DeleteAccountResponse, DeleteAccountError> {
+        (**self).delete_account(input)
+    }
     fn delete_events_configuration(
         &self,
         input: DeleteEventsConfigurationRequest,
-    ) -> RusotoFuture<(), DeleteEventsConfigurationError>;
-
-    /// <p>Moves the specified phone number into the <b>Deletion queue</b>. A phone number must be disassociated from any users or Amazon Chime Voice Connectors before it can be deleted.</p> <p>Deleted phone numbers remain in the <b>Deletion queue</b> for 7 days before they are deleted permanently.</p>
+    ) -> RusotoFuture<(), DeleteEventsConfigurationError> {
+        (**self).delete_events_configuration(input)
+    }
     fn delete_phone_number(
         &self,
         input: DeletePhoneNumberRequest,
-    ) -> RusotoFuture<(), DeletePhoneNumberError>;
-
-    /// <p>Deletes the specified Amazon Chime Voice Connector. Any phone numbers assigned to the Amazon Chime Voice Connector must be unassigned from it before it can be deleted.</p>
+    ) -> RusotoFuture<(), DeletePhoneNumberError> {
+        (**self).delete_phone_number(input)
+    }
     fn delete_voice_connector(
         &self,
         input: DeleteVoiceConnectorRequest,
-    ) -> RusotoFuture<(), DeleteVoiceConnectorError>;
-
-    /// <p>Deletes the origination settings for the specified Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<(), DeleteVoiceConnectorError> {
+        (**self).delete_voice_connector(input)
+    }
     fn delete_voice_connector_origination(
         &self,
         input: DeleteVoiceConnectorOriginationRequest,
-    ) -> RusotoFuture<(), DeleteVoiceConnectorOriginationError>;
-
-    /// <p>Deletes the termination settings for the specified Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<(), DeleteVoiceConnectorOriginationError> {
+        (**self).delete_voice_connector_origination(input)
+    }
     fn delete_voice_connector_termination(
         &self,
         input: DeleteVoiceConnectorTerminationRequest,
-    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationError>;
-
-    /// <p>Deletes the specified SIP credentials used by your equipment to authenticate during call termination.</p>
+    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationError> {
+        (**self).delete_voice_connector_termination(input)
+    }
     fn delete_voice_connector_termination_credentials(
         &self,
         input: DeleteVoiceConnectorTerminationCredentialsRequest,
-    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationCredentialsError>;
-
-    /// <p>Disassociates the primary provisioned phone number from the specified Amazon Chime user.</p>
+    ) -> RusotoFuture<(), DeleteVoiceConnectorTerminationCredentialsError> {
+        (**self).delete_voice_connector_termination_credentials(input)
+    }
     fn disassociate_phone_number_from_user(
         &self,
         input: DisassociatePhoneNumberFromUserRequest,
-    ) -> RusotoFuture<DisassociatePhoneNumberFromUserResponse, DisassociatePhoneNumberFromUserError>;
-
-    /// <p>Disassociates the specified phone number from the specified Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<DisassociatePhoneNumberFromUserResponse, DisassociatePhoneNumberFromUserError>
+    {
+        (**self).disassociate_phone_number_from_user(input)
+    }
     fn disassociate_phone_numbers_from_voice_connector(
         &self,
         input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
     ) -> RusotoFuture<
         DisassociatePhoneNumbersFromVoiceConnectorResponse,
         DisassociatePhoneNumbersFromVoiceConnectorError,
-    >;
-
-    /// <p>Retrieves details for the specified Amazon Chime account, such as account type and supported licenses.</p>
+    > {
+        (**self).disassociate_phone_numbers_from_voice_connector(input)
+    }
     fn get_account(
         &self,
         input: GetAccountRequest,
-    ) -> RusotoFuture<GetAccountResponse, GetAccountError>;
-
-    /// <p>Retrieves account settings for the specified Amazon Chime account ID, such as remote control and dial out settings. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    ) -> RusotoFuture<GetAccountResponse, GetAccountError> {
+        (**self).get_account(input)
+    }
     fn get_account_settings(
         &self,
         input: GetAccountSettingsRequest,
-    ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError>;
-
-    /// <p>Retrieves details for the specified bot, such as bot email address, bot type, status, and display name.</p>
-    fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError>;
-
-    /// <p>Gets details for an events configuration that allows a bot to receive outgoing events, such as an HTTPS endpoint or Lambda function ARN. </p>
+    ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError> {
+        (**self).get_account_settings(input)
+    }
+    fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError> {
+        (**self).get_bot(input)
+    }
     fn get_events_configuration(
         &self,
         input: GetEventsConfigurationRequest,
-    ) -> RusotoFuture<GetEventsConfigurationResponse, GetEventsConfigurationError>;
-
-    /// <p>Retrieves global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
+    ) -> RusotoFuture<GetEventsConfigurationResponse, GetEventsConfigurationError> {
+        (**self).get_events_configuration(input)
+    }
     fn get_global_settings(
         &self,
-    ) -> RusotoFuture<GetGlobalSettingsResponse, GetGlobalSettingsError>;
-
-    /// <p>Retrieves details for the specified phone number ID, such as associations, capabilities, and product type.</p>
+    ) -> RusotoFuture<GetGlobalSettingsResponse, GetGlobalSettingsError> {
+        (**self).get_global_settings()
+    }
     fn get_phone_number(
         &self,
         input: GetPhoneNumberRequest,
-    ) -> RusotoFuture<GetPhoneNumberResponse, GetPhoneNumberError>;
-
-    /// <p>Retrieves details for the specified phone number order, such as order creation timestamp, phone numbers in E.164 format, product type, and order status.</p>
+    ) -> RusotoFuture<GetPhoneNumberResponse, GetPhoneNumberError> {
+        (**self).get_phone_number(input)
+    }
     fn get_phone_number_order(
         &self,
         input: GetPhoneNumberOrderRequest,
-    ) -> RusotoFuture<GetPhoneNumberOrderResponse, GetPhoneNumberOrderError>;
-
-    /// <p>Retrieves details for the specified user ID, such as primary email address, license type, and personal meeting PIN.</p> <p>To retrieve user details with an email address instead of a user ID, use the <a>ListUsers</a> action, and then filter by email address.</p>
-    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError>;
-
-    /// <p>Retrieves settings for the specified user ID, such as any associated phone number settings.</p>
+    ) -> RusotoFuture<GetPhoneNumberOrderResponse, GetPhoneNumberOrderError> {
+        (**self).get_phone_number_order(input)
+    }
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
+        (**self).get_user(input)
+    }
     fn get_user_settings(
         &self,
         input: GetUserSettingsRequest,
-    ) -> RusotoFuture<GetUserSettingsResponse, GetUserSettingsError>;
-
-    /// <p>Retrieves details for the specified Amazon Chime Voice Connector, such as timestamps, name, outbound host, and encryption requirements.</p>
+    ) -> RusotoFuture<GetUserSettingsResponse, GetUserSettingsError> {
+        (**self).get_user_settings(input)
+    }
     fn get_voice_connector(
         &self,
         input: GetVoiceConnectorRequest,
-    ) -> RusotoFuture<GetVoiceConnectorResponse, GetVoiceConnectorError>;
-
-    /// <p>Retrieves origination setting details for the specified Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<GetVoiceConnectorResponse, GetVoiceConnectorError> {
+        (**self).get_voice_connector(input)
+    }
     fn get_voice_connector_origination(
         &self,
         input: GetVoiceConnectorOriginationRequest,
-    ) -> RusotoFuture<GetVoiceConnectorOriginationResponse, GetVoiceConnectorOriginationError>;
-
-    /// <p>Retrieves termination setting details for the specified Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<GetVoiceConnectorOriginationResponse, GetVoiceConnectorOriginationError> {
+        (**self).get_voice_connector_origination(input)
+    }
     fn get_voice_connector_termination(
         &self,
         input: GetVoiceConnectorTerminationRequest,
-    ) -> RusotoFuture<GetVoiceConnectorTerminationResponse, GetVoiceConnectorTerminationError>;
-
-    /// <p>Retrieves information about the last time a SIP <code>OPTIONS</code> ping was received from your SIP infrastructure for the specified Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<GetVoiceConnectorTerminationResponse, GetVoiceConnectorTerminationError> {
+        (**self).get_voice_connector_termination(input)
+    }
     fn get_voice_connector_termination_health(
         &self,
         input: GetVoiceConnectorTerminationHealthRequest,
     ) -> RusotoFuture<
         GetVoiceConnectorTerminationHealthResponse,
         GetVoiceConnectorTerminationHealthError,
-    >;
-
-    /// <p>Sends email invites to as many as 50 users, inviting them to the specified Amazon Chime <code>Team</code> account. Only <code>Team</code> account types are currently supported for this action. </p>
+    > {
+        (**self).get_voice_connector_termination_health(input)
+    }
     fn invite_users(
         &self,
         input: InviteUsersRequest,
-    ) -> RusotoFuture<InviteUsersResponse, InviteUsersError>;
-
-    /// <p>Lists the Amazon Chime accounts under the administrator's AWS account. You can filter accounts by account name prefix. To find out which Amazon Chime account a user belongs to, you can filter by the user's email address, which returns one account result.</p>
+    ) -> RusotoFuture<InviteUsersResponse, InviteUsersError> {
+        (**self).invite_users(input)
+    }
     fn list_accounts(
         &self,
         input: ListAccountsRequest,
-    ) -> RusotoFuture<ListAccountsResponse, ListAccountsError>;
-
-    /// <p>Lists the bots associated with the administrator's Amazon Chime Enterprise account ID.</p>
-    fn list_bots(&self, input: ListBotsRequest) -> RusotoFuture<ListBotsResponse, ListBotsError>;
-
-    /// <p>Lists the phone number orders for the administrator's Amazon Chime account.</p>
+    ) -> RusotoFuture<ListAccountsResponse, ListAccountsError> {
+        (**self).list_accounts(input)
+    }
+    fn list_bots(&self, input: ListBotsRequest) -> RusotoFuture<ListBotsResponse, ListBotsError> {
+        (**self).list_bots(input)
+    }
     fn list_phone_number_orders(
         &self,
         input: ListPhoneNumberOrdersRequest,
-    ) -> RusotoFuture<ListPhoneNumberOrdersResponse, ListPhoneNumberOrdersError>;
-
-    /// <p>Lists the phone numbers for the specified Amazon Chime account, Amazon Chime user, or Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<ListPhoneNumberOrdersResponse, ListPhoneNumberOrdersError> {
+        (**self).list_phone_number_orders(input)
+    }
     fn list_phone_numbers(
         &self,
         input: ListPhoneNumbersRequest,
-    ) -> RusotoFuture<ListPhoneNumbersResponse, ListPhoneNumbersError>;
-
-    /// <p>Lists the users that belong to the specified Amazon Chime account. You can specify an email address to list only the user that the email address belongs to.</p>
+    ) -> RusotoFuture<ListPhoneNumbersResponse, ListPhoneNumbersError> {
+        (**self).list_phone_numbers(input)
+    }
     fn list_users(
         &self,
         input: ListUsersRequest,
-    ) -> RusotoFuture<ListUsersResponse, ListUsersError>;
-
-    /// <p>Lists the SIP credentials for the specified Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
     fn list_voice_connector_termination_credentials(
         &self,
         input: ListVoiceConnectorTerminationCredentialsRequest,
     ) -> RusotoFuture<
         ListVoiceConnectorTerminationCredentialsResponse,
         ListVoiceConnectorTerminationCredentialsError,
-    >;
-
-    /// <p>Lists the Amazon Chime Voice Connectors for the administrator's AWS account.</p>
+    > {
+        (**self).list_voice_connector_termination_credentials(input)
+    }
     fn list_voice_connectors(
         &self,
         input: ListVoiceConnectorsRequest,
-    ) -> RusotoFuture<ListVoiceConnectorsResponse, ListVoiceConnectorsError>;
-
-    /// <p>Logs out the specified user from all of the devices they are currently logged into.</p>
+    ) -> RusotoFuture<ListVoiceConnectorsResponse, ListVoiceConnectorsError> {
+        (**self).list_voice_connectors(input)
+    }
     fn logout_user(
         &self,
         input: LogoutUserRequest,
-    ) -> RusotoFuture<LogoutUserResponse, LogoutUserError>;
-
-    /// <p>Creates an events configuration that allows a bot to receive outgoing events sent by Amazon Chime. Choose either an HTTPS endpoint or a Lambda function ARN. For more information, see <a>Bot</a>.</p>
+    ) -> RusotoFuture<LogoutUserResponse, LogoutUserError> {
+        (**self).logout_user(input)
+    }
     fn put_events_configuration(
         &self,
         input: PutEventsConfigurationRequest,
-    ) -> RusotoFuture<PutEventsConfigurationResponse, PutEventsConfigurationError>;
-
-    /// <p>Adds origination settings for the specified Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<PutEventsConfigurationResponse, PutEventsConfigurationError> {
+        (**self).put_events_configuration(input)
+    }
     fn put_voice_connector_origination(
         &self,
         input: PutVoiceConnectorOriginationRequest,
-    ) -> RusotoFuture<PutVoiceConnectorOriginationResponse, PutVoiceConnectorOriginationError>;
-
-    /// <p>Adds termination settings for the specified Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<PutVoiceConnectorOriginationResponse, PutVoiceConnectorOriginationError> {
+        (**self).put_voice_connector_origination(input)
+    }
     fn put_voice_connector_termination(
         &self,
         input: PutVoiceConnectorTerminationRequest,
-    ) -> RusotoFuture<PutVoiceConnectorTerminationResponse, PutVoiceConnectorTerminationError>;
-
-    /// <p>Adds termination SIP credentials for the specified Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<PutVoiceConnectorTerminationResponse, PutVoiceConnectorTerminationError> {
+        (**self).put_voice_connector_termination(input)
+    }
     fn put_voice_connector_termination_credentials(
         &self,
         input: PutVoiceConnectorTerminationCredentialsRequest,
-    ) -> RusotoFuture<(), PutVoiceConnectorTerminationCredentialsError>;
-
-    /// <p>Regenerates the security token for a bot.</p>
+    ) -> RusotoFuture<(), PutVoiceConnectorTerminationCredentialsError> {
+        (**self).put_voice_connector_termination_credentials(input)
+    }
     fn regenerate_security_token(
         &self,
         input: RegenerateSecurityTokenRequest,
-    ) -> RusotoFuture<RegenerateSecurityTokenResponse, RegenerateSecurityTokenError>;
-
-    /// <p>Resets the personal meeting PIN for the specified user on an Amazon Chime account. Returns the <a>User</a> object with the updated personal meeting PIN.</p>
+    ) -> RusotoFuture<RegenerateSecurityTokenResponse, RegenerateSecurityTokenError> {
+        (**self).regenerate_security_token(input)
+    }
     fn reset_personal_pin(
         &self,
         input: ResetPersonalPINRequest,
-    ) -> RusotoFuture<ResetPersonalPINResponse, ResetPersonalPINError>;
-
-    /// <p>Moves a phone number from the <b>Deletion queue</b> back into the phone number <b>Inventory</b>.</p>
+    ) -> RusotoFuture<ResetPersonalPINResponse, ResetPersonalPINError> {
+        (**self).reset_personal_pin(input)
+    }
     fn restore_phone_number(
         &self,
         input: RestorePhoneNumberRequest,
-    ) -> RusotoFuture<RestorePhoneNumberResponse, RestorePhoneNumberError>;
-
-    /// <p>Searches phone numbers that can be ordered.</p>
+    ) -> RusotoFuture<RestorePhoneNumberResponse, RestorePhoneNumberError> {
+        (**self).restore_phone_number(input)
+    }
     fn search_available_phone_numbers(
         &self,
         input: SearchAvailablePhoneNumbersRequest,
-    ) -> RusotoFuture<SearchAvailablePhoneNumbersResponse, SearchAvailablePhoneNumbersError>;
-
-    /// <p>Updates account details for the specified Amazon Chime account. Currently, only account name updates are supported for this action.</p>
+    ) -> RusotoFuture<SearchAvailablePhoneNumbersResponse, SearchAvailablePhoneNumbersError> {
+        (**self).search_available_phone_numbers(input)
+    }
     fn update_account(
         &self,
         input: UpdateAccountRequest,
-    ) -> RusotoFuture<UpdateAccountResponse, UpdateAccountError>;
-
-    /// <p>Updates the settings for the specified Amazon Chime account. You can update settings for remote control of shared screens, or for the dial-out option. For more information about these settings, see <a href="https://docs.aws.amazon.com/chime/latest/ag/policies.html">Use the Policies Page</a> in the <i>Amazon Chime Administration Guide</i>.</p>
+    ) -> RusotoFuture<UpdateAccountResponse, UpdateAccountError> {
+        (**self).update_account(input)
+    }
     fn update_account_settings(
         &self,
         input: UpdateAccountSettingsRequest,
-    ) -> RusotoFuture<UpdateAccountSettingsResponse, UpdateAccountSettingsError>;
-
-    /// <p>Updates the status of the specified bot, such as starting or stopping the bot from running in your Amazon Chime Enterprise account.</p>
+    ) -> RusotoFuture<UpdateAccountSettingsResponse, UpdateAccountSettingsError> {
+        (**self).update_account_settings(input)
+    }
     fn update_bot(
         &self,
         input: UpdateBotRequest,
-    ) -> RusotoFuture<UpdateBotResponse, UpdateBotError>;
-
-    /// <p>Updates global settings for the administrator's AWS account, such as Amazon Chime Business Calling and Amazon Chime Voice Connector settings.</p>
+    ) -> RusotoFuture<UpdateBotResponse, UpdateBotError> {
+        (**self).update_bot(input)
+    }
     fn update_global_settings(
         &self,
         input: UpdateGlobalSettingsRequest,
-    ) -> RusotoFuture<(), UpdateGlobalSettingsError>;
-
-    /// <p>Updates phone number details, such as product type, for the specified phone number ID. For toll-free numbers, you can use only the Amazon Chime Voice Connector product type.</p>
+    ) -> RusotoFuture<(), UpdateGlobalSettingsError> {
+        (**self).update_global_settings(input)
+    }
     fn update_phone_number(
         &self,
         input: UpdatePhoneNumberRequest,
-    ) -> RusotoFuture<UpdatePhoneNumberResponse, UpdatePhoneNumberError>;
-
-    /// <p>Updates user details for a specified user ID. Currently, only <code>LicenseType</code> updates are supported for this action.</p>
+    ) -> RusotoFuture<UpdatePhoneNumberResponse, UpdatePhoneNumberError> {
+        (**self).update_phone_number(input)
+    }
     fn update_user(
         &self,
         input: UpdateUserRequest,
-    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError>;
-
-    /// <p>Updates the settings for the specified user, such as phone number settings.</p>
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
     fn update_user_settings(
         &self,
         input: UpdateUserSettingsRequest,
-    ) -> RusotoFuture<(), UpdateUserSettingsError>;
-
-    /// <p>Updates details for the specified Amazon Chime Voice Connector.</p>
+    ) -> RusotoFuture<(), UpdateUserSettingsError> {
+        (**self).update_user_settings(input)
+    }
     fn update_voice_connector(
         &self,
         input: UpdateVoiceConnectorRequest,
-    ) -> RusotoFuture<UpdateVoiceConnectorResponse, UpdateVoiceConnectorError>;
+    ) -> RusotoFuture<UpdateVoiceConnectorResponse, UpdateVoiceConnectorError> {
+        (**self).update_voice_connector(input)
+    }
 }
+/// The error codes Amazon Chime retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Chime API.
 #[derive(Clone)]
 pub struct ChimeClient {
@@ -6301,6 +10253,24 @@ impl ChimeClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CHIME`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ChimeClient {
+        Self::new(region::Region::from_env_for_service("CHIME"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `chime`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ChimeClient {
+        Self::new_with_client(config.client().clone(), config.region_for("chime", "CHIME"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6319,7 +10289,49 @@ impl ChimeClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ChimeClient {
-        ChimeClient { client, region }
+        ChimeClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "chime")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "chime", "chime", "CHIME")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Chime` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Chime + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ChimeClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Chime"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "chime"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ChimeClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -16,13 +16,16 @@
 //!
 //! If you're using the service, you're probably looking for [ChimeClient](struct.ChimeClient.html) and [Chime](trait.Chime.html).
 
+extern crate base64;
 extern crate bytes;
 extern crate futures;
+extern crate hmac;
 extern crate rusoto_core;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate serde_json;
+extern crate sha2;
 
 mod generated;
 mod custom;
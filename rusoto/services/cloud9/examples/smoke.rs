@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Cloud9 clients.
+//!
+//! Makes a single read-only `ListEnvironments` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_cloud9::{Cloud9, Cloud9Client, ListEnvironmentsRequest};
+
+fn main() {
+    let client = Cloud9Client::from_env();
+    match client
+        .list_environments(ListEnvironmentsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListEnvironments succeeded"),
+        Err(error) => {
+            eprintln!("ListEnvironments failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateEnvironmentEC2Request {
@@ -53,6 +54,88 @@ pub struct CreateEnvironmentEC2Request {
     pub subnet_id: Option<String>,
 }
 
+impl CreateEnvironmentEC2Request {
+    /// Returns a builder for `CreateEnvironmentEC2Request`, checking its required members when built.
+    pub fn builder() -> CreateEnvironmentEC2RequestBuilder {
+        CreateEnvironmentEC2RequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateEnvironmentEC2Request`](struct.CreateEnvironmentEC2Request.html).
+#[derive(Default, Debug)]
+pub struct CreateEnvironmentEC2RequestBuilder {
+    automatic_stop_time_minutes: Option<i64>,
+    client_request_token: Option<String>,
+    description: Option<String>,
+    instance_type: Option<String>,
+    name: Option<String>,
+    owner_arn: Option<String>,
+    subnet_id: Option<String>,
+}
+
+impl CreateEnvironmentEC2RequestBuilder {
+    /// Sets the [`automatic_stop_time_minutes`](struct.CreateEnvironmentEC2Request.html#structfield.automatic_stop_time_minutes) member.
+    pub fn automatic_stop_time_minutes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.automatic_stop_time_minutes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_request_token`](struct.CreateEnvironmentEC2Request.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateEnvironmentEC2Request.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_type`](struct.CreateEnvironmentEC2Request.html#structfield.instance_type) member.
+    pub fn instance_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateEnvironmentEC2Request.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`owner_arn`](struct.CreateEnvironmentEC2Request.html#structfield.owner_arn) member.
+    pub fn owner_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.owner_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_id`](struct.CreateEnvironmentEC2Request.html#structfield.subnet_id) member.
+    pub fn subnet_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subnet_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateEnvironmentEC2Request`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateEnvironmentEC2Request, ::rusoto_core::BuildError> {
+        Ok(CreateEnvironmentEC2Request {
+            automatic_stop_time_minutes: self.automatic_stop_time_minutes,
+            client_request_token: self.client_request_token,
+            description: self.description,
+            instance_type: self
+                .instance_type
+                .ok_or(::rusoto_core::BuildError::missing("instance_type"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            owner_arn: self.owner_arn,
+            subnet_id: self.subnet_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateEnvironmentEC2Result {
@@ -62,19 +145,71 @@ pub struct CreateEnvironmentEC2Result {
     pub environment_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateEnvironmentMembershipRequest {
     /// <p>The ID of the environment that contains the environment member you want to add.</p>
     #[serde(rename = "environmentId")]
     pub environment_id: String,
     /// <p><p>The type of environment member permissions you want to associate with this environment member. Available values include:</p> <ul> <li> <p> <code>read-only</code>: Has read-only access to the environment.</p> </li> <li> <p> <code>read-write</code>: Has read-write access to the environment.</p> </li> </ul></p>
     #[serde(rename = "permissions")]
-    pub permissions: String,
+    pub permissions: MemberPermissions,
     /// <p>The Amazon Resource Name (ARN) of the environment member you want to add.</p>
     #[serde(rename = "userArn")]
     pub user_arn: String,
 }
 
+impl CreateEnvironmentMembershipRequest {
+    /// Returns a builder for `CreateEnvironmentMembershipRequest`, checking its required members when built.
+    pub fn builder() -> CreateEnvironmentMembershipRequestBuilder {
+        CreateEnvironmentMembershipRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateEnvironmentMembershipRequest`](struct.CreateEnvironmentMembershipRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateEnvironmentMembershipRequestBuilder {
+    environment_id: Option<String>,
+    permissions: Option<MemberPermissions>,
+    user_arn: Option<String>,
+}
+
+impl CreateEnvironmentMembershipRequestBuilder {
+    /// Sets the [`environment_id`](struct.CreateEnvironmentMembershipRequest.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permissions`](struct.CreateEnvironmentMembershipRequest.html#structfield.permissions) member.
+    pub fn permissions<T: Into<MemberPermissions>>(mut self, value: T) -> Self {
+        self.permissions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_arn`](struct.CreateEnvironmentMembershipRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateEnvironmentMembershipRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateEnvironmentMembershipRequest, ::rusoto_core::BuildError> {
+        Ok(CreateEnvironmentMembershipRequest {
+            environment_id: self
+                .environment_id
+                .ok_or(::rusoto_core::BuildError::missing("environment_id"))?,
+            permissions: self
+                .permissions
+                .ok_or(::rusoto_core::BuildError::missing("permissions"))?,
+            user_arn: self
+                .user_arn
+                .ok_or(::rusoto_core::BuildError::missing("user_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateEnvironmentMembershipResult {
@@ -94,6 +229,48 @@ pub struct DeleteEnvironmentMembershipRequest {
     pub user_arn: String,
 }
 
+impl DeleteEnvironmentMembershipRequest {
+    /// Returns a builder for `DeleteEnvironmentMembershipRequest`, checking its required members when built.
+    pub fn builder() -> DeleteEnvironmentMembershipRequestBuilder {
+        DeleteEnvironmentMembershipRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEnvironmentMembershipRequest`](struct.DeleteEnvironmentMembershipRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteEnvironmentMembershipRequestBuilder {
+    environment_id: Option<String>,
+    user_arn: Option<String>,
+}
+
+impl DeleteEnvironmentMembershipRequestBuilder {
+    /// Sets the [`environment_id`](struct.DeleteEnvironmentMembershipRequest.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_arn`](struct.DeleteEnvironmentMembershipRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEnvironmentMembershipRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteEnvironmentMembershipRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteEnvironmentMembershipRequest {
+            environment_id: self
+                .environment_id
+                .ok_or(::rusoto_core::BuildError::missing("environment_id"))?,
+            user_arn: self
+                .user_arn
+                .ok_or(::rusoto_core::BuildError::missing("user_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteEnvironmentMembershipResult {}
@@ -105,6 +282,38 @@ pub struct DeleteEnvironmentRequest {
     pub environment_id: String,
 }
 
+impl DeleteEnvironmentRequest {
+    /// Returns a builder for `DeleteEnvironmentRequest`, checking its required members when built.
+    pub fn builder() -> DeleteEnvironmentRequestBuilder {
+        DeleteEnvironmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEnvironmentRequest`](struct.DeleteEnvironmentRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteEnvironmentRequestBuilder {
+    environment_id: Option<String>,
+}
+
+impl DeleteEnvironmentRequestBuilder {
+    /// Sets the [`environment_id`](struct.DeleteEnvironmentRequest.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEnvironmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteEnvironmentRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteEnvironmentRequest {
+            environment_id: self
+                .environment_id
+                .ok_or(::rusoto_core::BuildError::missing("environment_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteEnvironmentResult {}
@@ -126,13 +335,76 @@ pub struct DescribeEnvironmentMembershipsRequest {
     /// <p>The type of environment member permissions to get information about. Available values include:</p> <ul> <li> <p> <code>owner</code>: Owns the environment.</p> </li> <li> <p> <code>read-only</code>: Has read-only access to the environment.</p> </li> <li> <p> <code>read-write</code>: Has read-write access to the environment.</p> </li> </ul> <p>If no value is specified, information about all environment members are returned.</p>
     #[serde(rename = "permissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Vec<String>>,
+    pub permissions: Option<Vec<Permissions>>,
     /// <p>The Amazon Resource Name (ARN) of an individual environment member to get information about. If no value is specified, information about all environment members are returned.</p>
     #[serde(rename = "userArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_arn: Option<String>,
 }
 
+impl DescribeEnvironmentMembershipsRequest {
+    /// Returns a builder for `DescribeEnvironmentMembershipsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEnvironmentMembershipsRequestBuilder {
+        DescribeEnvironmentMembershipsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEnvironmentMembershipsRequest`](struct.DescribeEnvironmentMembershipsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEnvironmentMembershipsRequestBuilder {
+    environment_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    permissions: Option<Vec<Permissions>>,
+    user_arn: Option<String>,
+}
+
+impl DescribeEnvironmentMembershipsRequestBuilder {
+    /// Sets the [`environment_id`](struct.DescribeEnvironmentMembershipsRequest.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeEnvironmentMembershipsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeEnvironmentMembershipsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permissions`](struct.DescribeEnvironmentMembershipsRequest.html#structfield.permissions) member.
+    pub fn permissions<T: Into<Vec<Permissions>>>(mut self, value: T) -> Self {
+        self.permissions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_arn`](struct.DescribeEnvironmentMembershipsRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEnvironmentMembershipsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEnvironmentMembershipsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeEnvironmentMembershipsRequest {
+            environment_id: self.environment_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            permissions: self.permissions,
+            user_arn: self.user_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEnvironmentMembershipsResult {
@@ -153,6 +425,38 @@ pub struct DescribeEnvironmentStatusRequest {
     pub environment_id: String,
 }
 
+impl DescribeEnvironmentStatusRequest {
+    /// Returns a builder for `DescribeEnvironmentStatusRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEnvironmentStatusRequestBuilder {
+        DescribeEnvironmentStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEnvironmentStatusRequest`](struct.DescribeEnvironmentStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEnvironmentStatusRequestBuilder {
+    environment_id: Option<String>,
+}
+
+impl DescribeEnvironmentStatusRequestBuilder {
+    /// Sets the [`environment_id`](struct.DescribeEnvironmentStatusRequest.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEnvironmentStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEnvironmentStatusRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEnvironmentStatusRequest {
+            environment_id: self
+                .environment_id
+                .ok_or(::rusoto_core::BuildError::missing("environment_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEnvironmentStatusResult {
@@ -163,7 +467,7 @@ pub struct DescribeEnvironmentStatusResult {
     /// <p><p>The status of the environment. Available values include:</p> <ul> <li> <p> <code>connecting</code>: The environment is connecting.</p> </li> <li> <p> <code>creating</code>: The environment is being created.</p> </li> <li> <p> <code>deleting</code>: The environment is being deleted.</p> </li> <li> <p> <code>error</code>: The environment is in an error state.</p> </li> <li> <p> <code>ready</code>: The environment is ready.</p> </li> <li> <p> <code>stopped</code>: The environment is stopped.</p> </li> <li> <p> <code>stopping</code>: The environment is stopping.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EnvironmentStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -173,6 +477,38 @@ pub struct DescribeEnvironmentsRequest {
     pub environment_ids: Vec<String>,
 }
 
+impl DescribeEnvironmentsRequest {
+    /// Returns a builder for `DescribeEnvironmentsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEnvironmentsRequestBuilder {
+        DescribeEnvironmentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEnvironmentsRequest`](struct.DescribeEnvironmentsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEnvironmentsRequestBuilder {
+    environment_ids: Option<Vec<String>>,
+}
+
+impl DescribeEnvironmentsRequestBuilder {
+    /// Sets the [`environment_ids`](struct.DescribeEnvironmentsRequest.html#structfield.environment_ids) member.
+    pub fn environment_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.environment_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEnvironmentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEnvironmentsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEnvironmentsRequest {
+            environment_ids: self
+                .environment_ids
+                .ok_or(::rusoto_core::BuildError::missing("environment_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEnvironmentsResult {
@@ -213,7 +549,7 @@ pub struct Environment {
     /// <p><p>The type of environment. Valid values include the following:</p> <ul> <li> <p> <code>ec2</code>: An Amazon Elastic Compute Cloud (Amazon EC2) instance connects to the environment.</p> </li> <li> <p> <code>ssh</code>: Your own server connects to the environment.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EnvironmentType>,
 }
 
 /// <p>Information about the current creation or deletion lifecycle state of an AWS Cloud9 development environment.</p>
@@ -231,7 +567,93 @@ pub struct EnvironmentLifecycle {
     /// <p><p>The current creation or deletion lifecycle state of the environment.</p> <ul> <li> <p> <code>CREATED</code>: The environment was successfully created.</p> </li> <li> <p> <code>DELETE_FAILED</code>: The environment failed to delete.</p> </li> <li> <p> <code>DELETING</code>: The environment is in the process of being deleted.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EnvironmentLifecycleStatus>,
+}
+
+/// <p><p>The current creation or deletion lifecycle state of the environment.</p> <ul> <li> <p> <code>CREATED</code>: The environment was successfully created.</p> </li> <li> <p> <code>DELETE_FAILED</code>: The environment failed to delete.</p> </li> <li> <p> <code>DELETING</code>: The environment is in the process of being deleted.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnvironmentLifecycleStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>CREATED</code> - The environment was successfully created.</p>
+    Created,
+    /// <p><code>CREATE_FAILED</code></p>
+    CreateFailed,
+    /// <p><code>DELETING</code> - The environment is in the process of being deleted.</p>
+    Deleting,
+    /// <p><code>DELETE_FAILED</code> - The environment failed to delete.</p>
+    DeleteFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnvironmentLifecycleStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentLifecycleStatus::Creating => "CREATING",
+            EnvironmentLifecycleStatus::Created => "CREATED",
+            EnvironmentLifecycleStatus::CreateFailed => "CREATE_FAILED",
+            EnvironmentLifecycleStatus::Deleting => "DELETING",
+            EnvironmentLifecycleStatus::DeleteFailed => "DELETE_FAILED",
+            EnvironmentLifecycleStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentLifecycleStatus {
+    fn from(value: &'a str) -> EnvironmentLifecycleStatus {
+        match value {
+            "CREATING" => EnvironmentLifecycleStatus::Creating,
+            "CREATED" => EnvironmentLifecycleStatus::Created,
+            "CREATE_FAILED" => EnvironmentLifecycleStatus::CreateFailed,
+            "DELETING" => EnvironmentLifecycleStatus::Deleting,
+            "DELETE_FAILED" => EnvironmentLifecycleStatus::DeleteFailed,
+            _ => EnvironmentLifecycleStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentLifecycleStatus {
+    fn from(value: String) -> EnvironmentLifecycleStatus {
+        EnvironmentLifecycleStatus::from(value.as_str())
+    }
+}
+
+impl From<EnvironmentLifecycleStatus> for String {
+    fn from(value: EnvironmentLifecycleStatus) -> String {
+        match value {
+            EnvironmentLifecycleStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnvironmentLifecycleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EnvironmentLifecycleStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnvironmentLifecycleStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<EnvironmentLifecycleStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EnvironmentLifecycleStatus::from(value))
+    }
 }
 
 /// <p>Information about an environment member for an AWS Cloud9 development environment.</p>
@@ -244,12 +666,13 @@ pub struct EnvironmentMember {
     pub environment_id: Option<String>,
     /// <p>The time, expressed in epoch time format, when the environment member last opened the environment.</p>
     #[serde(rename = "lastAccess")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_access: Option<f64>,
+    pub last_access: Option<EpochTimestamp>,
     /// <p><p>The type of environment member permissions associated with this environment member. Available values include:</p> <ul> <li> <p> <code>owner</code>: Owns the environment.</p> </li> <li> <p> <code>read-only</code>: Has read-only access to the environment.</p> </li> <li> <p> <code>read-write</code>: Has read-write access to the environment.</p> </li> </ul></p>
     #[serde(rename = "permissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<String>,
+    pub permissions: Option<Permissions>,
     /// <p>The Amazon Resource Name (ARN) of the environment member.</p>
     #[serde(rename = "userArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -260,6 +683,170 @@ pub struct EnvironmentMember {
     pub user_id: Option<String>,
 }
 
+/// <p><p>The status of the environment. Available values include:</p> <ul> <li> <p> <code>connecting</code>: The environment is connecting.</p> </li> <li> <p> <code>creating</code>: The environment is being created.</p> </li> <li> <p> <code>deleting</code>: The environment is being deleted.</p> </li> <li> <p> <code>error</code>: The environment is in an error state.</p> </li> <li> <p> <code>ready</code>: The environment is ready.</p> </li> <li> <p> <code>stopped</code>: The environment is stopped.</p> </li> <li> <p> <code>stopping</code>: The environment is stopping.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnvironmentStatus {
+    /// <p><code>error</code> - The environment is in an error state.</p>
+    Error,
+    /// <p><code>creating</code> - The environment is being created.</p>
+    Creating,
+    /// <p><code>connecting</code> - The environment is connecting.</p>
+    Connecting,
+    /// <p><code>ready</code> - The environment is ready.</p>
+    Ready,
+    /// <p><code>stopping</code> - The environment is stopping.</p>
+    Stopping,
+    /// <p><code>stopped</code> - The environment is stopped.</p>
+    Stopped,
+    /// <p><code>deleting</code> - The environment is being deleted.</p>
+    Deleting,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnvironmentStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentStatus::Error => "error",
+            EnvironmentStatus::Creating => "creating",
+            EnvironmentStatus::Connecting => "connecting",
+            EnvironmentStatus::Ready => "ready",
+            EnvironmentStatus::Stopping => "stopping",
+            EnvironmentStatus::Stopped => "stopped",
+            EnvironmentStatus::Deleting => "deleting",
+            EnvironmentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentStatus {
+    fn from(value: &'a str) -> EnvironmentStatus {
+        match value {
+            "error" => EnvironmentStatus::Error,
+            "creating" => EnvironmentStatus::Creating,
+            "connecting" => EnvironmentStatus::Connecting,
+            "ready" => EnvironmentStatus::Ready,
+            "stopping" => EnvironmentStatus::Stopping,
+            "stopped" => EnvironmentStatus::Stopped,
+            "deleting" => EnvironmentStatus::Deleting,
+            _ => EnvironmentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentStatus {
+    fn from(value: String) -> EnvironmentStatus {
+        EnvironmentStatus::from(value.as_str())
+    }
+}
+
+impl From<EnvironmentStatus> for String {
+    fn from(value: EnvironmentStatus) -> String {
+        match value {
+            EnvironmentStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnvironmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EnvironmentStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnvironmentStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EnvironmentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EnvironmentStatus::from(value))
+    }
+}
+
+/// <p><p>The type of environment. Valid values include the following:</p> <ul> <li> <p> <code>ec2</code>: An Amazon Elastic Compute Cloud (Amazon EC2) instance connects to the environment.</p> </li> <li> <p> <code>ssh</code>: Your own server connects to the environment.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnvironmentType {
+    /// <p><code>ssh</code> - Your own server connects to the environment.</p>
+    Ssh,
+    /// <p><code>ec2</code> - An Amazon Elastic Compute Cloud (Amazon EC2) instance connects to the environment.</p>
+    Ec2,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnvironmentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentType::Ssh => "ssh",
+            EnvironmentType::Ec2 => "ec2",
+            EnvironmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentType {
+    fn from(value: &'a str) -> EnvironmentType {
+        match value {
+            "ssh" => EnvironmentType::Ssh,
+            "ec2" => EnvironmentType::Ec2,
+            _ => EnvironmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentType {
+    fn from(value: String) -> EnvironmentType {
+        EnvironmentType::from(value.as_str())
+    }
+}
+
+impl From<EnvironmentType> for String {
+    fn from(value: EnvironmentType) -> String {
+        match value {
+            EnvironmentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnvironmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EnvironmentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnvironmentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EnvironmentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EnvironmentType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListEnvironmentsRequest {
     /// <p>The maximum number of environments to get identifiers for.</p>
@@ -272,6 +859,44 @@ pub struct ListEnvironmentsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListEnvironmentsRequest {
+    /// Returns a builder for `ListEnvironmentsRequest`, checking its required members when built.
+    pub fn builder() -> ListEnvironmentsRequestBuilder {
+        ListEnvironmentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListEnvironmentsRequest`](struct.ListEnvironmentsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListEnvironmentsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListEnvironmentsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListEnvironmentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListEnvironmentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListEnvironmentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListEnvironmentsRequest, ::rusoto_core::BuildError> {
+        Ok(ListEnvironmentsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListEnvironmentsResult {
@@ -285,19 +910,219 @@ pub struct ListEnvironmentsResult {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p><p>The type of environment member permissions you want to associate with this environment member. Available values include:</p> <ul> <li> <p> <code>read-only</code>: Has read-only access to the environment.</p> </li> <li> <p> <code>read-write</code>: Has read-write access to the environment.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MemberPermissions {
+    /// <p><code>read-write</code> - Has read-write access to the environment.</p>
+    ReadWrite,
+    /// <p><code>read-only</code> - Has read-only access to the environment.</p>
+    ReadOnly,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MemberPermissions {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MemberPermissions::ReadWrite => "read-write",
+            MemberPermissions::ReadOnly => "read-only",
+            MemberPermissions::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MemberPermissions {
+    fn from(value: &'a str) -> MemberPermissions {
+        match value {
+            "read-write" => MemberPermissions::ReadWrite,
+            "read-only" => MemberPermissions::ReadOnly,
+            _ => MemberPermissions::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MemberPermissions {
+    fn from(value: String) -> MemberPermissions {
+        MemberPermissions::from(value.as_str())
+    }
+}
+
+impl From<MemberPermissions> for String {
+    fn from(value: MemberPermissions) -> String {
+        match value {
+            MemberPermissions::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MemberPermissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MemberPermissions {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MemberPermissions {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MemberPermissions, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MemberPermissions::from(value))
+    }
+}
+
+/// <p>The type of environment member permissions to get information about. Available values include:</p> <ul> <li> <p> <code>owner</code>: Owns the environment.</p> </li> <li> <p> <code>read-only</code>: Has read-only access to the environment.</p> </li> <li> <p> <code>read-write</code>: Has read-write access to the environment.</p> </li> </ul> <p>If no value is specified, information about all environment members are returned.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Permissions {
+    /// <p><code>owner</code> - Owns the environment.</p>
+    Owner,
+    /// <p><code>read-write</code> - Has read-write access to the environment.</p>
+    ReadWrite,
+    /// <p><code>read-only</code> - Has read-only access to the environment.</p>
+    ReadOnly,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Permissions {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permissions::Owner => "owner",
+            Permissions::ReadWrite => "read-write",
+            Permissions::ReadOnly => "read-only",
+            Permissions::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Permissions {
+    fn from(value: &'a str) -> Permissions {
+        match value {
+            "owner" => Permissions::Owner,
+            "read-write" => Permissions::ReadWrite,
+            "read-only" => Permissions::ReadOnly,
+            _ => Permissions::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Permissions {
+    fn from(value: String) -> Permissions {
+        Permissions::from(value.as_str())
+    }
+}
+
+impl From<Permissions> for String {
+    fn from(value: Permissions) -> String {
+        match value {
+            Permissions::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Permissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Permissions {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Permissions {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Permissions, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Permissions::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateEnvironmentMembershipRequest {
     /// <p>The ID of the environment for the environment member whose settings you want to change.</p>
     #[serde(rename = "environmentId")]
     pub environment_id: String,
     /// <p><p>The replacement type of environment member permissions you want to associate with this environment member. Available values include:</p> <ul> <li> <p> <code>read-only</code>: Has read-only access to the environment.</p> </li> <li> <p> <code>read-write</code>: Has read-write access to the environment.</p> </li> </ul></p>
     #[serde(rename = "permissions")]
-    pub permissions: String,
+    pub permissions: MemberPermissions,
     /// <p>The Amazon Resource Name (ARN) of the environment member whose settings you want to change.</p>
     #[serde(rename = "userArn")]
     pub user_arn: String,
 }
 
+impl UpdateEnvironmentMembershipRequest {
+    /// Returns a builder for `UpdateEnvironmentMembershipRequest`, checking its required members when built.
+    pub fn builder() -> UpdateEnvironmentMembershipRequestBuilder {
+        UpdateEnvironmentMembershipRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateEnvironmentMembershipRequest`](struct.UpdateEnvironmentMembershipRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateEnvironmentMembershipRequestBuilder {
+    environment_id: Option<String>,
+    permissions: Option<MemberPermissions>,
+    user_arn: Option<String>,
+}
+
+impl UpdateEnvironmentMembershipRequestBuilder {
+    /// Sets the [`environment_id`](struct.UpdateEnvironmentMembershipRequest.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permissions`](struct.UpdateEnvironmentMembershipRequest.html#structfield.permissions) member.
+    pub fn permissions<T: Into<MemberPermissions>>(mut self, value: T) -> Self {
+        self.permissions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_arn`](struct.UpdateEnvironmentMembershipRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateEnvironmentMembershipRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateEnvironmentMembershipRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateEnvironmentMembershipRequest {
+            environment_id: self
+                .environment_id
+                .ok_or(::rusoto_core::BuildError::missing("environment_id"))?,
+            permissions: self
+                .permissions
+                .ok_or(::rusoto_core::BuildError::missing("permissions"))?,
+            user_arn: self
+                .user_arn
+                .ok_or(::rusoto_core::BuildError::missing("user_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateEnvironmentMembershipResult {
@@ -322,6 +1147,54 @@ pub struct UpdateEnvironmentRequest {
     pub name: Option<String>,
 }
 
+impl UpdateEnvironmentRequest {
+    /// Returns a builder for `UpdateEnvironmentRequest`, checking its required members when built.
+    pub fn builder() -> UpdateEnvironmentRequestBuilder {
+        UpdateEnvironmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateEnvironmentRequest`](struct.UpdateEnvironmentRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateEnvironmentRequestBuilder {
+    description: Option<String>,
+    environment_id: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateEnvironmentRequestBuilder {
+    /// Sets the [`description`](struct.UpdateEnvironmentRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_id`](struct.UpdateEnvironmentRequest.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateEnvironmentRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateEnvironmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateEnvironmentRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateEnvironmentRequest {
+            description: self.description,
+            environment_id: self
+                .environment_id
+                .ok_or(::rusoto_core::BuildError::missing("environment_id"))?,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateEnvironmentResult {}
@@ -1164,6 +2037,76 @@ pub trait Cloud9 {
         input: UpdateEnvironmentMembershipRequest,
     ) -> RusotoFuture<UpdateEnvironmentMembershipResult, UpdateEnvironmentMembershipError>;
 }
+impl<T: ?Sized + Cloud9> Cloud9 for ::std::sync::Arc<T> {
+    fn create_environment_ec2(
+        &self,
+        input: CreateEnvironmentEC2Request,
+    ) -> RusotoFuture<CreateEnvironmentEC2Result, CreateEnvironmentEC2Error> {
+        (**self).create_environment_ec2(input)
+    }
+    fn create_environment_membership(
+        &self,
+        input: CreateEnvironmentMembershipRequest,
+    ) -> RusotoFuture<CreateEnvironmentMembershipResult, CreateEnvironmentMembershipError> {
+        (**self).create_environment_membership(input)
+    }
+    fn delete_environment(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> RusotoFuture<DeleteEnvironmentResult, DeleteEnvironmentError> {
+        (**self).delete_environment(input)
+    }
+    fn delete_environment_membership(
+        &self,
+        input: DeleteEnvironmentMembershipRequest,
+    ) -> RusotoFuture<DeleteEnvironmentMembershipResult, DeleteEnvironmentMembershipError> {
+        (**self).delete_environment_membership(input)
+    }
+    fn describe_environment_memberships(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentMembershipsResult, DescribeEnvironmentMembershipsError>
+    {
+        (**self).describe_environment_memberships(input)
+    }
+    fn describe_environment_status(
+        &self,
+        input: DescribeEnvironmentStatusRequest,
+    ) -> RusotoFuture<DescribeEnvironmentStatusResult, DescribeEnvironmentStatusError> {
+        (**self).describe_environment_status(input)
+    }
+    fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentsResult, DescribeEnvironmentsError> {
+        (**self).describe_environments(input)
+    }
+    fn list_environments(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> RusotoFuture<ListEnvironmentsResult, ListEnvironmentsError> {
+        (**self).list_environments(input)
+    }
+    fn update_environment(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> RusotoFuture<UpdateEnvironmentResult, UpdateEnvironmentError> {
+        (**self).update_environment(input)
+    }
+    fn update_environment_membership(
+        &self,
+        input: UpdateEnvironmentMembershipRequest,
+    ) -> RusotoFuture<UpdateEnvironmentMembershipResult, UpdateEnvironmentMembershipError> {
+        (**self).update_environment_membership(input)
+    }
+}
+/// The error codes AWS Cloud9 retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the AWS Cloud9 API.
 #[derive(Clone)]
 pub struct Cloud9Client {
@@ -1179,6 +2122,27 @@ impl Cloud9Client {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CLOUD9`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> Cloud9Client {
+        Self::new(region::Region::from_env_for_service("CLOUD9"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `cloud9`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> Cloud9Client {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("cloud9", "CLOUD9"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1197,7 +2161,49 @@ impl Cloud9Client {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> Cloud9Client {
-        Cloud9Client { client, region }
+        Cloud9Client {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "cloud9")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "cloud9", "cloud9", "CLOUD9")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Cloud9` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Cloud9 + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for Cloud9Client {
+    fn service_name(&self) -> &'static str {
+        "AWS Cloud9"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "cloud9"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> Cloud9Client {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,40 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon CloudDirectory clients.
+//!
+//! Makes a single read-only `ListDevelopmentSchemaArns` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_clouddirectory::{
+    CloudDirectory, CloudDirectoryClient, ListDevelopmentSchemaArnsRequest,
+};
+
+fn main() {
+    let client = CloudDirectoryClient::from_env();
+    match client
+        .list_development_schema_arns(ListDevelopmentSchemaArnsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListDevelopmentSchemaArns succeeded"),
+        Err(error) => {
+            eprintln!("ListDevelopmentSchemaArns failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
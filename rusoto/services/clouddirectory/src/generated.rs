@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AddFacetToObjectRequest {
@@ -40,6 +41,66 @@ pub struct AddFacetToObjectRequest {
     pub schema_facet: SchemaFacet,
 }
 
+impl AddFacetToObjectRequest {
+    /// Returns a builder for `AddFacetToObjectRequest`, checking its required members when built.
+    pub fn builder() -> AddFacetToObjectRequestBuilder {
+        AddFacetToObjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddFacetToObjectRequest`](struct.AddFacetToObjectRequest.html).
+#[derive(Default, Debug)]
+pub struct AddFacetToObjectRequestBuilder {
+    directory_arn: Option<String>,
+    object_attribute_list: Option<Vec<AttributeKeyAndValue>>,
+    object_reference: Option<ObjectReference>,
+    schema_facet: Option<SchemaFacet>,
+}
+
+impl AddFacetToObjectRequestBuilder {
+    /// Sets the [`directory_arn`](struct.AddFacetToObjectRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_attribute_list`](struct.AddFacetToObjectRequest.html#structfield.object_attribute_list) member.
+    pub fn object_attribute_list<T: Into<Vec<AttributeKeyAndValue>>>(mut self, value: T) -> Self {
+        self.object_attribute_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.AddFacetToObjectRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_facet`](struct.AddFacetToObjectRequest.html#structfield.schema_facet) member.
+    pub fn schema_facet<T: Into<SchemaFacet>>(mut self, value: T) -> Self {
+        self.schema_facet = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddFacetToObjectRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddFacetToObjectRequest, ::rusoto_core::BuildError> {
+        Ok(AddFacetToObjectRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            object_attribute_list: self.object_attribute_list,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+            schema_facet: self
+                .schema_facet
+                .ok_or(::rusoto_core::BuildError::missing("schema_facet"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddFacetToObjectResponse {}
@@ -54,6 +115,46 @@ pub struct ApplySchemaRequest {
     pub published_schema_arn: String,
 }
 
+impl ApplySchemaRequest {
+    /// Returns a builder for `ApplySchemaRequest`, checking its required members when built.
+    pub fn builder() -> ApplySchemaRequestBuilder {
+        ApplySchemaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ApplySchemaRequest`](struct.ApplySchemaRequest.html).
+#[derive(Default, Debug)]
+pub struct ApplySchemaRequestBuilder {
+    directory_arn: Option<String>,
+    published_schema_arn: Option<String>,
+}
+
+impl ApplySchemaRequestBuilder {
+    /// Sets the [`directory_arn`](struct.ApplySchemaRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`published_schema_arn`](struct.ApplySchemaRequest.html#structfield.published_schema_arn) member.
+    pub fn published_schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.published_schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ApplySchemaRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ApplySchemaRequest, ::rusoto_core::BuildError> {
+        Ok(ApplySchemaRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            published_schema_arn: self
+                .published_schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("published_schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ApplySchemaResponse {
@@ -83,6 +184,66 @@ pub struct AttachObjectRequest {
     pub parent_reference: ObjectReference,
 }
 
+impl AttachObjectRequest {
+    /// Returns a builder for `AttachObjectRequest`, checking its required members when built.
+    pub fn builder() -> AttachObjectRequestBuilder {
+        AttachObjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachObjectRequest`](struct.AttachObjectRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachObjectRequestBuilder {
+    child_reference: Option<ObjectReference>,
+    directory_arn: Option<String>,
+    link_name: Option<String>,
+    parent_reference: Option<ObjectReference>,
+}
+
+impl AttachObjectRequestBuilder {
+    /// Sets the [`child_reference`](struct.AttachObjectRequest.html#structfield.child_reference) member.
+    pub fn child_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.child_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.AttachObjectRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`link_name`](struct.AttachObjectRequest.html#structfield.link_name) member.
+    pub fn link_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.link_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_reference`](struct.AttachObjectRequest.html#structfield.parent_reference) member.
+    pub fn parent_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.parent_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachObjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AttachObjectRequest, ::rusoto_core::BuildError> {
+        Ok(AttachObjectRequest {
+            child_reference: self
+                .child_reference
+                .ok_or(::rusoto_core::BuildError::missing("child_reference"))?,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            link_name: self
+                .link_name
+                .ok_or(::rusoto_core::BuildError::missing("link_name"))?,
+            parent_reference: self
+                .parent_reference
+                .ok_or(::rusoto_core::BuildError::missing("parent_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AttachObjectResponse {
@@ -105,6 +266,56 @@ pub struct AttachPolicyRequest {
     pub policy_reference: ObjectReference,
 }
 
+impl AttachPolicyRequest {
+    /// Returns a builder for `AttachPolicyRequest`, checking its required members when built.
+    pub fn builder() -> AttachPolicyRequestBuilder {
+        AttachPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachPolicyRequest`](struct.AttachPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachPolicyRequestBuilder {
+    directory_arn: Option<String>,
+    object_reference: Option<ObjectReference>,
+    policy_reference: Option<ObjectReference>,
+}
+
+impl AttachPolicyRequestBuilder {
+    /// Sets the [`directory_arn`](struct.AttachPolicyRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.AttachPolicyRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_reference`](struct.AttachPolicyRequest.html#structfield.policy_reference) member.
+    pub fn policy_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.policy_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AttachPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(AttachPolicyRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+            policy_reference: self
+                .policy_reference
+                .ok_or(::rusoto_core::BuildError::missing("policy_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AttachPolicyResponse {}
@@ -122,6 +333,56 @@ pub struct AttachToIndexRequest {
     pub target_reference: ObjectReference,
 }
 
+impl AttachToIndexRequest {
+    /// Returns a builder for `AttachToIndexRequest`, checking its required members when built.
+    pub fn builder() -> AttachToIndexRequestBuilder {
+        AttachToIndexRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachToIndexRequest`](struct.AttachToIndexRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachToIndexRequestBuilder {
+    directory_arn: Option<String>,
+    index_reference: Option<ObjectReference>,
+    target_reference: Option<ObjectReference>,
+}
+
+impl AttachToIndexRequestBuilder {
+    /// Sets the [`directory_arn`](struct.AttachToIndexRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`index_reference`](struct.AttachToIndexRequest.html#structfield.index_reference) member.
+    pub fn index_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.index_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_reference`](struct.AttachToIndexRequest.html#structfield.target_reference) member.
+    pub fn target_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.target_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachToIndexRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AttachToIndexRequest, ::rusoto_core::BuildError> {
+        Ok(AttachToIndexRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            index_reference: self
+                .index_reference
+                .ok_or(::rusoto_core::BuildError::missing("index_reference"))?,
+            target_reference: self
+                .target_reference
+                .ok_or(::rusoto_core::BuildError::missing("target_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AttachToIndexResponse {
@@ -150,6 +411,76 @@ pub struct AttachTypedLinkRequest {
     pub typed_link_facet: TypedLinkSchemaAndFacetName,
 }
 
+impl AttachTypedLinkRequest {
+    /// Returns a builder for `AttachTypedLinkRequest`, checking its required members when built.
+    pub fn builder() -> AttachTypedLinkRequestBuilder {
+        AttachTypedLinkRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachTypedLinkRequest`](struct.AttachTypedLinkRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachTypedLinkRequestBuilder {
+    attributes: Option<Vec<AttributeNameAndValue>>,
+    directory_arn: Option<String>,
+    source_object_reference: Option<ObjectReference>,
+    target_object_reference: Option<ObjectReference>,
+    typed_link_facet: Option<TypedLinkSchemaAndFacetName>,
+}
+
+impl AttachTypedLinkRequestBuilder {
+    /// Sets the [`attributes`](struct.AttachTypedLinkRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<Vec<AttributeNameAndValue>>>(mut self, value: T) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.AttachTypedLinkRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_object_reference`](struct.AttachTypedLinkRequest.html#structfield.source_object_reference) member.
+    pub fn source_object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.source_object_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_object_reference`](struct.AttachTypedLinkRequest.html#structfield.target_object_reference) member.
+    pub fn target_object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.target_object_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`typed_link_facet`](struct.AttachTypedLinkRequest.html#structfield.typed_link_facet) member.
+    pub fn typed_link_facet<T: Into<TypedLinkSchemaAndFacetName>>(mut self, value: T) -> Self {
+        self.typed_link_facet = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachTypedLinkRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AttachTypedLinkRequest, ::rusoto_core::BuildError> {
+        Ok(AttachTypedLinkRequest {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            source_object_reference: self.source_object_reference.ok_or(
+                ::rusoto_core::BuildError::missing("source_object_reference"),
+            )?,
+            target_object_reference: self.target_object_reference.ok_or(
+                ::rusoto_core::BuildError::missing("target_object_reference"),
+            )?,
+            typed_link_facet: self
+                .typed_link_facet
+                .ok_or(::rusoto_core::BuildError::missing("typed_link_facet"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AttachTypedLinkResponse {
@@ -881,7 +1212,133 @@ pub struct BatchReadException {
     /// <p>A type of exception, such as <code>InvalidArnException</code>.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<BatchReadExceptionType>,
+}
+
+/// <p>A type of exception, such as <code>InvalidArnException</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BatchReadExceptionType {
+    /// <p><code>ValidationException</code></p>
+    ValidationException,
+    /// <p><code>InvalidArnException</code></p>
+    InvalidArnException,
+    /// <p><code>ResourceNotFoundException</code></p>
+    ResourceNotFoundException,
+    /// <p><code>InvalidNextTokenException</code></p>
+    InvalidNextTokenException,
+    /// <p><code>AccessDeniedException</code></p>
+    AccessDeniedException,
+    /// <p><code>NotNodeException</code></p>
+    NotNodeException,
+    /// <p><code>FacetValidationException</code></p>
+    FacetValidationException,
+    /// <p><code>CannotListParentOfRootException</code></p>
+    CannotListParentOfRootException,
+    /// <p><code>NotIndexException</code></p>
+    NotIndexException,
+    /// <p><code>NotPolicyException</code></p>
+    NotPolicyException,
+    /// <p><code>DirectoryNotEnabledException</code></p>
+    DirectoryNotEnabledException,
+    /// <p><code>LimitExceededException</code></p>
+    LimitExceededException,
+    /// <p><code>InternalServiceException</code></p>
+    InternalServiceException,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BatchReadExceptionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BatchReadExceptionType::ValidationException => "ValidationException",
+            BatchReadExceptionType::InvalidArnException => "InvalidArnException",
+            BatchReadExceptionType::ResourceNotFoundException => "ResourceNotFoundException",
+            BatchReadExceptionType::InvalidNextTokenException => "InvalidNextTokenException",
+            BatchReadExceptionType::AccessDeniedException => "AccessDeniedException",
+            BatchReadExceptionType::NotNodeException => "NotNodeException",
+            BatchReadExceptionType::FacetValidationException => "FacetValidationException",
+            BatchReadExceptionType::CannotListParentOfRootException => {
+                "CannotListParentOfRootException"
+            }
+            BatchReadExceptionType::NotIndexException => "NotIndexException",
+            BatchReadExceptionType::NotPolicyException => "NotPolicyException",
+            BatchReadExceptionType::DirectoryNotEnabledException => "DirectoryNotEnabledException",
+            BatchReadExceptionType::LimitExceededException => "LimitExceededException",
+            BatchReadExceptionType::InternalServiceException => "InternalServiceException",
+            BatchReadExceptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BatchReadExceptionType {
+    fn from(value: &'a str) -> BatchReadExceptionType {
+        match value {
+            "ValidationException" => BatchReadExceptionType::ValidationException,
+            "InvalidArnException" => BatchReadExceptionType::InvalidArnException,
+            "ResourceNotFoundException" => BatchReadExceptionType::ResourceNotFoundException,
+            "InvalidNextTokenException" => BatchReadExceptionType::InvalidNextTokenException,
+            "AccessDeniedException" => BatchReadExceptionType::AccessDeniedException,
+            "NotNodeException" => BatchReadExceptionType::NotNodeException,
+            "FacetValidationException" => BatchReadExceptionType::FacetValidationException,
+            "CannotListParentOfRootException" => {
+                BatchReadExceptionType::CannotListParentOfRootException
+            }
+            "NotIndexException" => BatchReadExceptionType::NotIndexException,
+            "NotPolicyException" => BatchReadExceptionType::NotPolicyException,
+            "DirectoryNotEnabledException" => BatchReadExceptionType::DirectoryNotEnabledException,
+            "LimitExceededException" => BatchReadExceptionType::LimitExceededException,
+            "InternalServiceException" => BatchReadExceptionType::InternalServiceException,
+            _ => BatchReadExceptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BatchReadExceptionType {
+    fn from(value: String) -> BatchReadExceptionType {
+        BatchReadExceptionType::from(value.as_str())
+    }
+}
+
+impl From<BatchReadExceptionType> for String {
+    fn from(value: BatchReadExceptionType) -> String {
+        match value {
+            BatchReadExceptionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BatchReadExceptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BatchReadExceptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BatchReadExceptionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BatchReadExceptionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BatchReadExceptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BatchReadExceptionType::from(value))
+    }
 }
 
 /// <p>Represents the output of a <code>BatchRead</code> operation.</p>
@@ -963,7 +1420,7 @@ pub struct BatchReadRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a>. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -972,6 +1429,54 @@ pub struct BatchReadRequest {
     pub operations: Vec<BatchReadOperation>,
 }
 
+impl BatchReadRequest {
+    /// Returns a builder for `BatchReadRequest`, checking its required members when built.
+    pub fn builder() -> BatchReadRequestBuilder {
+        BatchReadRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchReadRequest`](struct.BatchReadRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchReadRequestBuilder {
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    operations: Option<Vec<BatchReadOperation>>,
+}
+
+impl BatchReadRequestBuilder {
+    /// Sets the [`consistency_level`](struct.BatchReadRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.BatchReadRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operations`](struct.BatchReadRequest.html#structfield.operations) member.
+    pub fn operations<T: Into<Vec<BatchReadOperation>>>(mut self, value: T) -> Self {
+        self.operations = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchReadRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchReadRequest, ::rusoto_core::BuildError> {
+        Ok(BatchReadRequest {
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            operations: self
+                .operations
+                .ok_or(::rusoto_core::BuildError::missing("operations"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchReadResponse {
@@ -1236,6 +1741,46 @@ pub struct BatchWriteRequest {
     pub operations: Vec<BatchWriteOperation>,
 }
 
+impl BatchWriteRequest {
+    /// Returns a builder for `BatchWriteRequest`, checking its required members when built.
+    pub fn builder() -> BatchWriteRequestBuilder {
+        BatchWriteRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchWriteRequest`](struct.BatchWriteRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchWriteRequestBuilder {
+    directory_arn: Option<String>,
+    operations: Option<Vec<BatchWriteOperation>>,
+}
+
+impl BatchWriteRequestBuilder {
+    /// Sets the [`directory_arn`](struct.BatchWriteRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operations`](struct.BatchWriteRequest.html#structfield.operations) member.
+    pub fn operations<T: Into<Vec<BatchWriteOperation>>>(mut self, value: T) -> Self {
+        self.operations = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchWriteRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchWriteRequest, ::rusoto_core::BuildError> {
+        Ok(BatchWriteRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            operations: self
+                .operations
+                .ok_or(::rusoto_core::BuildError::missing("operations"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchWriteResponse {
@@ -1245,6 +1790,84 @@ pub struct BatchWriteResponse {
     pub responses: Option<Vec<BatchWriteOperationResponse>>,
 }
 
+/// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConsistencyLevel {
+    /// <p><code>SERIALIZABLE</code></p>
+    Serializable,
+    /// <p><code>EVENTUAL</code></p>
+    Eventual,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConsistencyLevel {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConsistencyLevel::Serializable => "SERIALIZABLE",
+            ConsistencyLevel::Eventual => "EVENTUAL",
+            ConsistencyLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConsistencyLevel {
+    fn from(value: &'a str) -> ConsistencyLevel {
+        match value {
+            "SERIALIZABLE" => ConsistencyLevel::Serializable,
+            "EVENTUAL" => ConsistencyLevel::Eventual,
+            _ => ConsistencyLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConsistencyLevel {
+    fn from(value: String) -> ConsistencyLevel {
+        ConsistencyLevel::from(value.as_str())
+    }
+}
+
+impl From<ConsistencyLevel> for String {
+    fn from(value: ConsistencyLevel) -> String {
+        match value {
+            ConsistencyLevel::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConsistencyLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConsistencyLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConsistencyLevel {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConsistencyLevel {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConsistencyLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConsistencyLevel::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateDirectoryRequest {
     /// <p>The name of the <a>Directory</a>. Should be unique per account, per region.</p>
@@ -1255,6 +1878,46 @@ pub struct CreateDirectoryRequest {
     pub schema_arn: String,
 }
 
+impl CreateDirectoryRequest {
+    /// Returns a builder for `CreateDirectoryRequest`, checking its required members when built.
+    pub fn builder() -> CreateDirectoryRequestBuilder {
+        CreateDirectoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDirectoryRequest`](struct.CreateDirectoryRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDirectoryRequestBuilder {
+    name: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl CreateDirectoryRequestBuilder {
+    /// Sets the [`name`](struct.CreateDirectoryRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.CreateDirectoryRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDirectoryRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDirectoryRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDirectoryRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDirectoryResponse {
@@ -1281,19 +1944,83 @@ pub struct CreateFacetRequest {
     /// <p>There are two different styles that you can define on any given facet, <code>Static</code> and <code>Dynamic</code>. For static facets, all attributes must be defined in the schema. For dynamic facets, attributes can be defined during data plane operations.</p>
     #[serde(rename = "FacetStyle")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub facet_style: Option<String>,
+    pub facet_style: Option<FacetStyle>,
     /// <p>The name of the <a>Facet</a>, which is unique for a given schema.</p>
     #[serde(rename = "Name")]
     pub name: String,
     /// <p><p>Specifies whether a given object created from this facet is of type node, leaf node, policy or index.</p> <ul> <li> <p>Node: Can have multiple children but one parent.</p> </li> </ul> <ul> <li> <p>Leaf node: Cannot have children but can have multiple parents.</p> </li> </ul> <ul> <li> <p>Policy: Allows you to store a policy document and policy type. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directory.html#key_concepts_policies">Policies</a>.</p> </li> </ul> <ul> <li> <p>Index: Can be created with the Index API.</p> </li> </ul></p>
     #[serde(rename = "ObjectType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_type: Option<String>,
+    pub object_type: Option<ObjectType>,
     /// <p>The schema ARN in which the new <a>Facet</a> will be created. For more information, see <a>arns</a>.</p>
     #[serde(rename = "SchemaArn")]
     pub schema_arn: String,
 }
 
+impl CreateFacetRequest {
+    /// Returns a builder for `CreateFacetRequest`, checking its required members when built.
+    pub fn builder() -> CreateFacetRequestBuilder {
+        CreateFacetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFacetRequest`](struct.CreateFacetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFacetRequestBuilder {
+    attributes: Option<Vec<FacetAttribute>>,
+    facet_style: Option<FacetStyle>,
+    name: Option<String>,
+    object_type: Option<ObjectType>,
+    schema_arn: Option<String>,
+}
+
+impl CreateFacetRequestBuilder {
+    /// Sets the [`attributes`](struct.CreateFacetRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<Vec<FacetAttribute>>>(mut self, value: T) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`facet_style`](struct.CreateFacetRequest.html#structfield.facet_style) member.
+    pub fn facet_style<T: Into<FacetStyle>>(mut self, value: T) -> Self {
+        self.facet_style = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateFacetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_type`](struct.CreateFacetRequest.html#structfield.object_type) member.
+    pub fn object_type<T: Into<ObjectType>>(mut self, value: T) -> Self {
+        self.object_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.CreateFacetRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFacetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateFacetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateFacetRequest {
+            attributes: self.attributes,
+            facet_style: self.facet_style,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            object_type: self.object_type,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateFacetResponse {}
@@ -1319,6 +2046,72 @@ pub struct CreateIndexRequest {
     pub parent_reference: Option<ObjectReference>,
 }
 
+impl CreateIndexRequest {
+    /// Returns a builder for `CreateIndexRequest`, checking its required members when built.
+    pub fn builder() -> CreateIndexRequestBuilder {
+        CreateIndexRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateIndexRequest`](struct.CreateIndexRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateIndexRequestBuilder {
+    directory_arn: Option<String>,
+    is_unique: Option<bool>,
+    link_name: Option<String>,
+    ordered_indexed_attribute_list: Option<Vec<AttributeKey>>,
+    parent_reference: Option<ObjectReference>,
+}
+
+impl CreateIndexRequestBuilder {
+    /// Sets the [`directory_arn`](struct.CreateIndexRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`is_unique`](struct.CreateIndexRequest.html#structfield.is_unique) member.
+    pub fn is_unique<T: Into<bool>>(mut self, value: T) -> Self {
+        self.is_unique = Some(value.into());
+        self
+    }
+
+    /// Sets the [`link_name`](struct.CreateIndexRequest.html#structfield.link_name) member.
+    pub fn link_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.link_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ordered_indexed_attribute_list`](struct.CreateIndexRequest.html#structfield.ordered_indexed_attribute_list) member.
+    pub fn ordered_indexed_attribute_list<T: Into<Vec<AttributeKey>>>(mut self, value: T) -> Self {
+        self.ordered_indexed_attribute_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_reference`](struct.CreateIndexRequest.html#structfield.parent_reference) member.
+    pub fn parent_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.parent_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateIndexRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateIndexRequest, ::rusoto_core::BuildError> {
+        Ok(CreateIndexRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            is_unique: self
+                .is_unique
+                .ok_or(::rusoto_core::BuildError::missing("is_unique"))?,
+            link_name: self.link_name,
+            ordered_indexed_attribute_list: self.ordered_indexed_attribute_list.ok_or(
+                ::rusoto_core::BuildError::missing("ordered_indexed_attribute_list"),
+            )?,
+            parent_reference: self.parent_reference,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateIndexResponse {
@@ -1350,20 +2143,114 @@ pub struct CreateObjectRequest {
     pub schema_facets: Vec<SchemaFacet>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CreateObjectResponse {
-    /// <p>The identifier that is associated with the object.</p>
-    #[serde(rename = "ObjectIdentifier")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_identifier: Option<String>,
+impl CreateObjectRequest {
+    /// Returns a builder for `CreateObjectRequest`, checking its required members when built.
+    pub fn builder() -> CreateObjectRequestBuilder {
+        CreateObjectRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct CreateSchemaRequest {
-    /// <p>The name that is associated with the schema. This is unique to each account and in each region.</p>
-    #[serde(rename = "Name")]
-    pub name: String,
+/// Builds a [`CreateObjectRequest`](struct.CreateObjectRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateObjectRequestBuilder {
+    directory_arn: Option<String>,
+    link_name: Option<String>,
+    object_attribute_list: Option<Vec<AttributeKeyAndValue>>,
+    parent_reference: Option<ObjectReference>,
+    schema_facets: Option<Vec<SchemaFacet>>,
+}
+
+impl CreateObjectRequestBuilder {
+    /// Sets the [`directory_arn`](struct.CreateObjectRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`link_name`](struct.CreateObjectRequest.html#structfield.link_name) member.
+    pub fn link_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.link_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_attribute_list`](struct.CreateObjectRequest.html#structfield.object_attribute_list) member.
+    pub fn object_attribute_list<T: Into<Vec<AttributeKeyAndValue>>>(mut self, value: T) -> Self {
+        self.object_attribute_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_reference`](struct.CreateObjectRequest.html#structfield.parent_reference) member.
+    pub fn parent_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.parent_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_facets`](struct.CreateObjectRequest.html#structfield.schema_facets) member.
+    pub fn schema_facets<T: Into<Vec<SchemaFacet>>>(mut self, value: T) -> Self {
+        self.schema_facets = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateObjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateObjectRequest, ::rusoto_core::BuildError> {
+        Ok(CreateObjectRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            link_name: self.link_name,
+            object_attribute_list: self.object_attribute_list,
+            parent_reference: self.parent_reference,
+            schema_facets: self
+                .schema_facets
+                .ok_or(::rusoto_core::BuildError::missing("schema_facets"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateObjectResponse {
+    /// <p>The identifier that is associated with the object.</p>
+    #[serde(rename = "ObjectIdentifier")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_identifier: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct CreateSchemaRequest {
+    /// <p>The name that is associated with the schema. This is unique to each account and in each region.</p>
+    #[serde(rename = "Name")]
+    pub name: String,
+}
+
+impl CreateSchemaRequest {
+    /// Returns a builder for `CreateSchemaRequest`, checking its required members when built.
+    pub fn builder() -> CreateSchemaRequestBuilder {
+        CreateSchemaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSchemaRequest`](struct.CreateSchemaRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSchemaRequestBuilder {
+    name: Option<String>,
+}
+
+impl CreateSchemaRequestBuilder {
+    /// Sets the [`name`](struct.CreateSchemaRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSchemaRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateSchemaRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSchemaRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1385,6 +2272,48 @@ pub struct CreateTypedLinkFacetRequest {
     pub schema_arn: String,
 }
 
+impl CreateTypedLinkFacetRequest {
+    /// Returns a builder for `CreateTypedLinkFacetRequest`, checking its required members when built.
+    pub fn builder() -> CreateTypedLinkFacetRequestBuilder {
+        CreateTypedLinkFacetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTypedLinkFacetRequest`](struct.CreateTypedLinkFacetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTypedLinkFacetRequestBuilder {
+    facet: Option<TypedLinkFacet>,
+    schema_arn: Option<String>,
+}
+
+impl CreateTypedLinkFacetRequestBuilder {
+    /// Sets the [`facet`](struct.CreateTypedLinkFacetRequest.html#structfield.facet) member.
+    pub fn facet<T: Into<TypedLinkFacet>>(mut self, value: T) -> Self {
+        self.facet = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.CreateTypedLinkFacetRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTypedLinkFacetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateTypedLinkFacetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTypedLinkFacetRequest {
+            facet: self
+                .facet
+                .ok_or(::rusoto_core::BuildError::missing("facet"))?,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateTypedLinkFacetResponse {}
@@ -1396,6 +2325,36 @@ pub struct DeleteDirectoryRequest {
     pub directory_arn: String,
 }
 
+impl DeleteDirectoryRequest {
+    /// Returns a builder for `DeleteDirectoryRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDirectoryRequestBuilder {
+        DeleteDirectoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDirectoryRequest`](struct.DeleteDirectoryRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDirectoryRequestBuilder {
+    directory_arn: Option<String>,
+}
+
+impl DeleteDirectoryRequestBuilder {
+    /// Sets the [`directory_arn`](struct.DeleteDirectoryRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDirectoryRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDirectoryRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDirectoryRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDirectoryResponse {
@@ -1414,6 +2373,46 @@ pub struct DeleteFacetRequest {
     pub schema_arn: String,
 }
 
+impl DeleteFacetRequest {
+    /// Returns a builder for `DeleteFacetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFacetRequestBuilder {
+        DeleteFacetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFacetRequest`](struct.DeleteFacetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFacetRequestBuilder {
+    name: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl DeleteFacetRequestBuilder {
+    /// Sets the [`name`](struct.DeleteFacetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.DeleteFacetRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFacetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteFacetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFacetRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteFacetResponse {}
@@ -1428,6 +2427,46 @@ pub struct DeleteObjectRequest {
     pub object_reference: ObjectReference,
 }
 
+impl DeleteObjectRequest {
+    /// Returns a builder for `DeleteObjectRequest`, checking its required members when built.
+    pub fn builder() -> DeleteObjectRequestBuilder {
+        DeleteObjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteObjectRequest`](struct.DeleteObjectRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteObjectRequestBuilder {
+    directory_arn: Option<String>,
+    object_reference: Option<ObjectReference>,
+}
+
+impl DeleteObjectRequestBuilder {
+    /// Sets the [`directory_arn`](struct.DeleteObjectRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.DeleteObjectRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteObjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteObjectRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteObjectRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteObjectResponse {}
@@ -1439,6 +2478,36 @@ pub struct DeleteSchemaRequest {
     pub schema_arn: String,
 }
 
+impl DeleteSchemaRequest {
+    /// Returns a builder for `DeleteSchemaRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSchemaRequestBuilder {
+        DeleteSchemaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSchemaRequest`](struct.DeleteSchemaRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSchemaRequestBuilder {
+    schema_arn: Option<String>,
+}
+
+impl DeleteSchemaRequestBuilder {
+    /// Sets the [`schema_arn`](struct.DeleteSchemaRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSchemaRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteSchemaRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSchemaRequest {
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSchemaResponse {
@@ -1458,6 +2527,48 @@ pub struct DeleteTypedLinkFacetRequest {
     pub schema_arn: String,
 }
 
+impl DeleteTypedLinkFacetRequest {
+    /// Returns a builder for `DeleteTypedLinkFacetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTypedLinkFacetRequestBuilder {
+        DeleteTypedLinkFacetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTypedLinkFacetRequest`](struct.DeleteTypedLinkFacetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTypedLinkFacetRequestBuilder {
+    name: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl DeleteTypedLinkFacetRequestBuilder {
+    /// Sets the [`name`](struct.DeleteTypedLinkFacetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.DeleteTypedLinkFacetRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTypedLinkFacetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteTypedLinkFacetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTypedLinkFacetRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteTypedLinkFacetResponse {}
@@ -1475,6 +2586,56 @@ pub struct DetachFromIndexRequest {
     pub target_reference: ObjectReference,
 }
 
+impl DetachFromIndexRequest {
+    /// Returns a builder for `DetachFromIndexRequest`, checking its required members when built.
+    pub fn builder() -> DetachFromIndexRequestBuilder {
+        DetachFromIndexRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachFromIndexRequest`](struct.DetachFromIndexRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachFromIndexRequestBuilder {
+    directory_arn: Option<String>,
+    index_reference: Option<ObjectReference>,
+    target_reference: Option<ObjectReference>,
+}
+
+impl DetachFromIndexRequestBuilder {
+    /// Sets the [`directory_arn`](struct.DetachFromIndexRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`index_reference`](struct.DetachFromIndexRequest.html#structfield.index_reference) member.
+    pub fn index_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.index_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_reference`](struct.DetachFromIndexRequest.html#structfield.target_reference) member.
+    pub fn target_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.target_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachFromIndexRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetachFromIndexRequest, ::rusoto_core::BuildError> {
+        Ok(DetachFromIndexRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            index_reference: self
+                .index_reference
+                .ok_or(::rusoto_core::BuildError::missing("index_reference"))?,
+            target_reference: self
+                .target_reference
+                .ok_or(::rusoto_core::BuildError::missing("target_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetachFromIndexResponse {
@@ -1497,6 +2658,56 @@ pub struct DetachObjectRequest {
     pub parent_reference: ObjectReference,
 }
 
+impl DetachObjectRequest {
+    /// Returns a builder for `DetachObjectRequest`, checking its required members when built.
+    pub fn builder() -> DetachObjectRequestBuilder {
+        DetachObjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachObjectRequest`](struct.DetachObjectRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachObjectRequestBuilder {
+    directory_arn: Option<String>,
+    link_name: Option<String>,
+    parent_reference: Option<ObjectReference>,
+}
+
+impl DetachObjectRequestBuilder {
+    /// Sets the [`directory_arn`](struct.DetachObjectRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`link_name`](struct.DetachObjectRequest.html#structfield.link_name) member.
+    pub fn link_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.link_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_reference`](struct.DetachObjectRequest.html#structfield.parent_reference) member.
+    pub fn parent_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.parent_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachObjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetachObjectRequest, ::rusoto_core::BuildError> {
+        Ok(DetachObjectRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            link_name: self
+                .link_name
+                .ok_or(::rusoto_core::BuildError::missing("link_name"))?,
+            parent_reference: self
+                .parent_reference
+                .ok_or(::rusoto_core::BuildError::missing("parent_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetachObjectResponse {
@@ -1519,6 +2730,56 @@ pub struct DetachPolicyRequest {
     pub policy_reference: ObjectReference,
 }
 
+impl DetachPolicyRequest {
+    /// Returns a builder for `DetachPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DetachPolicyRequestBuilder {
+        DetachPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachPolicyRequest`](struct.DetachPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachPolicyRequestBuilder {
+    directory_arn: Option<String>,
+    object_reference: Option<ObjectReference>,
+    policy_reference: Option<ObjectReference>,
+}
+
+impl DetachPolicyRequestBuilder {
+    /// Sets the [`directory_arn`](struct.DetachPolicyRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.DetachPolicyRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_reference`](struct.DetachPolicyRequest.html#structfield.policy_reference) member.
+    pub fn policy_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.policy_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetachPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DetachPolicyRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+            policy_reference: self
+                .policy_reference
+                .ok_or(::rusoto_core::BuildError::missing("policy_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetachPolicyResponse {}
@@ -1533,14 +2794,55 @@ pub struct DetachTypedLinkRequest {
     pub typed_link_specifier: TypedLinkSpecifier,
 }
 
+impl DetachTypedLinkRequest {
+    /// Returns a builder for `DetachTypedLinkRequest`, checking its required members when built.
+    pub fn builder() -> DetachTypedLinkRequestBuilder {
+        DetachTypedLinkRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachTypedLinkRequest`](struct.DetachTypedLinkRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachTypedLinkRequestBuilder {
+    directory_arn: Option<String>,
+    typed_link_specifier: Option<TypedLinkSpecifier>,
+}
+
+impl DetachTypedLinkRequestBuilder {
+    /// Sets the [`directory_arn`](struct.DetachTypedLinkRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`typed_link_specifier`](struct.DetachTypedLinkRequest.html#structfield.typed_link_specifier) member.
+    pub fn typed_link_specifier<T: Into<TypedLinkSpecifier>>(mut self, value: T) -> Self {
+        self.typed_link_specifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachTypedLinkRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetachTypedLinkRequest, ::rusoto_core::BuildError> {
+        Ok(DetachTypedLinkRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            typed_link_specifier: self
+                .typed_link_specifier
+                .ok_or(::rusoto_core::BuildError::missing("typed_link_specifier"))?,
+        })
+    }
+}
+
 /// <p>Directory structure that includes the directory name and directory ARN.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Directory {
     /// <p>The date and time when the directory was created.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the directory. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1552,7 +2854,89 @@ pub struct Directory {
     /// <p>The state of the directory. Can be either <code>Enabled</code>, <code>Disabled</code>, or <code>Deleted</code>.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DirectoryState>,
+}
+
+/// <p>The state of the directory. Can be either <code>Enabled</code>, <code>Disabled</code>, or <code>Deleted</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DirectoryState {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DirectoryState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectoryState::Enabled => "ENABLED",
+            DirectoryState::Disabled => "DISABLED",
+            DirectoryState::Deleted => "DELETED",
+            DirectoryState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DirectoryState {
+    fn from(value: &'a str) -> DirectoryState {
+        match value {
+            "ENABLED" => DirectoryState::Enabled,
+            "DISABLED" => DirectoryState::Disabled,
+            "DELETED" => DirectoryState::Deleted,
+            _ => DirectoryState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectoryState {
+    fn from(value: String) -> DirectoryState {
+        DirectoryState::from(value.as_str())
+    }
+}
+
+impl From<DirectoryState> for String {
+    fn from(value: DirectoryState) -> String {
+        match value {
+            DirectoryState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DirectoryState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DirectoryState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DirectoryState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectoryState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DirectoryState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DirectoryState::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1562,6 +2946,38 @@ pub struct DisableDirectoryRequest {
     pub directory_arn: String,
 }
 
+impl DisableDirectoryRequest {
+    /// Returns a builder for `DisableDirectoryRequest`, checking its required members when built.
+    pub fn builder() -> DisableDirectoryRequestBuilder {
+        DisableDirectoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisableDirectoryRequest`](struct.DisableDirectoryRequest.html).
+#[derive(Default, Debug)]
+pub struct DisableDirectoryRequestBuilder {
+    directory_arn: Option<String>,
+}
+
+impl DisableDirectoryRequestBuilder {
+    /// Sets the [`directory_arn`](struct.DisableDirectoryRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableDirectoryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisableDirectoryRequest, ::rusoto_core::BuildError> {
+        Ok(DisableDirectoryRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisableDirectoryResponse {
@@ -1577,6 +2993,36 @@ pub struct EnableDirectoryRequest {
     pub directory_arn: String,
 }
 
+impl EnableDirectoryRequest {
+    /// Returns a builder for `EnableDirectoryRequest`, checking its required members when built.
+    pub fn builder() -> EnableDirectoryRequestBuilder {
+        EnableDirectoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableDirectoryRequest`](struct.EnableDirectoryRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableDirectoryRequestBuilder {
+    directory_arn: Option<String>,
+}
+
+impl EnableDirectoryRequestBuilder {
+    /// Sets the [`directory_arn`](struct.EnableDirectoryRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableDirectoryRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<EnableDirectoryRequest, ::rusoto_core::BuildError> {
+        Ok(EnableDirectoryRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct EnableDirectoryResponse {
@@ -1592,7 +3038,7 @@ pub struct Facet {
     /// <p>There are two different styles that you can define on any given facet, <code>Static</code> and <code>Dynamic</code>. For static facets, all attributes must be defined in the schema. For dynamic facets, attributes can be defined during data plane operations.</p>
     #[serde(rename = "FacetStyle")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub facet_style: Option<String>,
+    pub facet_style: Option<FacetStyle>,
     /// <p>The name of the <a>Facet</a>.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1600,7 +3046,7 @@ pub struct Facet {
     /// <p>The object type that is associated with the facet. See <a>CreateFacetRequest$ObjectType</a> for more details.</p>
     #[serde(rename = "ObjectType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_type: Option<String>,
+    pub object_type: Option<ObjectType>,
 }
 
 /// <p>An attribute that is associated with the <a>Facet</a>.</p>
@@ -1620,11 +3066,11 @@ pub struct FacetAttribute {
     /// <p>The required behavior of the <code>FacetAttribute</code>.</p>
     #[serde(rename = "RequiredBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub required_behavior: Option<String>,
+    pub required_behavior: Option<RequiredAttributeBehavior>,
 }
 
 /// <p>A facet attribute definition. See <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_attributereferences.html">Attribute References</a> for more information.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FacetAttributeDefinition {
     /// <p>The default value of the attribute (if configured).</p>
     #[serde(rename = "DefaultValue")]
@@ -1640,7 +3086,7 @@ pub struct FacetAttributeDefinition {
     pub rules: Option<::std::collections::HashMap<String, Rule>>,
     /// <p>The type of the attribute.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: FacetAttributeType,
 }
 
 /// <p>The facet attribute reference that specifies the attribute definition that contains the attribute facet name and attribute name.</p>
@@ -1654,31 +3100,235 @@ pub struct FacetAttributeReference {
     pub target_facet_name: String,
 }
 
-/// <p>A structure that contains information used to update an attribute.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct FacetAttributeUpdate {
-    /// <p>The action to perform when updating the attribute.</p>
-    #[serde(rename = "Action")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
-    /// <p>The attribute to update.</p>
-    #[serde(rename = "Attribute")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute: Option<FacetAttribute>,
+/// <p>The type of the attribute.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FacetAttributeType {
+    /// <p><code>STRING</code></p>
+    String,
+    /// <p><code>BINARY</code></p>
+    Binary,
+    /// <p><code>BOOLEAN</code></p>
+    Boolean,
+    /// <p><code>NUMBER</code></p>
+    Number,
+    /// <p><code>DATETIME</code></p>
+    Datetime,
+    /// <p><code>VARIANT</code></p>
+    Variant,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FacetAttributeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FacetAttributeType::String => "STRING",
+            FacetAttributeType::Binary => "BINARY",
+            FacetAttributeType::Boolean => "BOOLEAN",
+            FacetAttributeType::Number => "NUMBER",
+            FacetAttributeType::Datetime => "DATETIME",
+            FacetAttributeType::Variant => "VARIANT",
+            FacetAttributeType::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetAppliedSchemaVersionRequest {
-    /// <p>The ARN of the applied schema.</p>
-    #[serde(rename = "SchemaArn")]
-    pub schema_arn: String,
+impl<'a> From<&'a str> for FacetAttributeType {
+    fn from(value: &'a str) -> FacetAttributeType {
+        match value {
+            "STRING" => FacetAttributeType::String,
+            "BINARY" => FacetAttributeType::Binary,
+            "BOOLEAN" => FacetAttributeType::Boolean,
+            "NUMBER" => FacetAttributeType::Number,
+            "DATETIME" => FacetAttributeType::Datetime,
+            "VARIANT" => FacetAttributeType::Variant,
+            _ => FacetAttributeType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetAppliedSchemaVersionResponse {
-    /// <p>Current applied schema ARN, including the minor version in use if one was provided.</p>
-    #[serde(rename = "AppliedSchemaArn")]
+impl From<String> for FacetAttributeType {
+    fn from(value: String) -> FacetAttributeType {
+        FacetAttributeType::from(value.as_str())
+    }
+}
+
+impl From<FacetAttributeType> for String {
+    fn from(value: FacetAttributeType) -> String {
+        match value {
+            FacetAttributeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FacetAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FacetAttributeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FacetAttributeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FacetAttributeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FacetAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FacetAttributeType::from(value))
+    }
+}
+
+/// <p>A structure that contains information used to update an attribute.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct FacetAttributeUpdate {
+    /// <p>The action to perform when updating the attribute.</p>
+    #[serde(rename = "Action")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<UpdateActionType>,
+    /// <p>The attribute to update.</p>
+    #[serde(rename = "Attribute")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute: Option<FacetAttribute>,
+}
+
+/// <p>There are two different styles that you can define on any given facet, <code>Static</code> and <code>Dynamic</code>. For static facets, all attributes must be defined in the schema. For dynamic facets, attributes can be defined during data plane operations.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FacetStyle {
+    /// <p><code>STATIC</code></p>
+    Static,
+    /// <p><code>DYNAMIC</code></p>
+    Dynamic,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FacetStyle {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FacetStyle::Static => "STATIC",
+            FacetStyle::Dynamic => "DYNAMIC",
+            FacetStyle::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FacetStyle {
+    fn from(value: &'a str) -> FacetStyle {
+        match value {
+            "STATIC" => FacetStyle::Static,
+            "DYNAMIC" => FacetStyle::Dynamic,
+            _ => FacetStyle::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FacetStyle {
+    fn from(value: String) -> FacetStyle {
+        FacetStyle::from(value.as_str())
+    }
+}
+
+impl From<FacetStyle> for String {
+    fn from(value: FacetStyle) -> String {
+        match value {
+            FacetStyle::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FacetStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FacetStyle {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FacetStyle {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FacetStyle {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FacetStyle, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FacetStyle::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetAppliedSchemaVersionRequest {
+    /// <p>The ARN of the applied schema.</p>
+    #[serde(rename = "SchemaArn")]
+    pub schema_arn: String,
+}
+
+impl GetAppliedSchemaVersionRequest {
+    /// Returns a builder for `GetAppliedSchemaVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetAppliedSchemaVersionRequestBuilder {
+        GetAppliedSchemaVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAppliedSchemaVersionRequest`](struct.GetAppliedSchemaVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAppliedSchemaVersionRequestBuilder {
+    schema_arn: Option<String>,
+}
+
+impl GetAppliedSchemaVersionRequestBuilder {
+    /// Sets the [`schema_arn`](struct.GetAppliedSchemaVersionRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAppliedSchemaVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAppliedSchemaVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetAppliedSchemaVersionRequest {
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetAppliedSchemaVersionResponse {
+    /// <p>Current applied schema ARN, including the minor version in use if one was provided.</p>
+    #[serde(rename = "AppliedSchemaArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub applied_schema_arn: Option<String>,
 }
@@ -1690,6 +3340,36 @@ pub struct GetDirectoryRequest {
     pub directory_arn: String,
 }
 
+impl GetDirectoryRequest {
+    /// Returns a builder for `GetDirectoryRequest`, checking its required members when built.
+    pub fn builder() -> GetDirectoryRequestBuilder {
+        GetDirectoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDirectoryRequest`](struct.GetDirectoryRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDirectoryRequestBuilder {
+    directory_arn: Option<String>,
+}
+
+impl GetDirectoryRequestBuilder {
+    /// Sets the [`directory_arn`](struct.GetDirectoryRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDirectoryRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDirectoryRequest, ::rusoto_core::BuildError> {
+        Ok(GetDirectoryRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDirectoryResponse {
@@ -1708,6 +3388,46 @@ pub struct GetFacetRequest {
     pub schema_arn: String,
 }
 
+impl GetFacetRequest {
+    /// Returns a builder for `GetFacetRequest`, checking its required members when built.
+    pub fn builder() -> GetFacetRequestBuilder {
+        GetFacetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFacetRequest`](struct.GetFacetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFacetRequestBuilder {
+    name: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl GetFacetRequestBuilder {
+    /// Sets the [`name`](struct.GetFacetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.GetFacetRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFacetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetFacetRequest, ::rusoto_core::BuildError> {
+        Ok(GetFacetRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFacetResponse {
@@ -1725,7 +3445,7 @@ pub struct GetLinkAttributesRequest {
     /// <p>The consistency level at which to retrieve the attributes on a typed link.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the Directory where the typed link resides. For more information, see <a>arns</a> or <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_links.html#directory_objects_links_typedlink">Typed Links</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -1734,6 +3454,66 @@ pub struct GetLinkAttributesRequest {
     pub typed_link_specifier: TypedLinkSpecifier,
 }
 
+impl GetLinkAttributesRequest {
+    /// Returns a builder for `GetLinkAttributesRequest`, checking its required members when built.
+    pub fn builder() -> GetLinkAttributesRequestBuilder {
+        GetLinkAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLinkAttributesRequest`](struct.GetLinkAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLinkAttributesRequestBuilder {
+    attribute_names: Option<Vec<String>>,
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    typed_link_specifier: Option<TypedLinkSpecifier>,
+}
+
+impl GetLinkAttributesRequestBuilder {
+    /// Sets the [`attribute_names`](struct.GetLinkAttributesRequest.html#structfield.attribute_names) member.
+    pub fn attribute_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`consistency_level`](struct.GetLinkAttributesRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.GetLinkAttributesRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`typed_link_specifier`](struct.GetLinkAttributesRequest.html#structfield.typed_link_specifier) member.
+    pub fn typed_link_specifier<T: Into<TypedLinkSpecifier>>(mut self, value: T) -> Self {
+        self.typed_link_specifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLinkAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLinkAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(GetLinkAttributesRequest {
+            attribute_names: self
+                .attribute_names
+                .ok_or(::rusoto_core::BuildError::missing("attribute_names"))?,
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            typed_link_specifier: self
+                .typed_link_specifier
+                .ok_or(::rusoto_core::BuildError::missing("typed_link_specifier"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLinkAttributesResponse {
@@ -1751,7 +3531,7 @@ pub struct GetObjectAttributesRequest {
     /// <p>The consistency level at which to retrieve the attributes on an object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where the object resides.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -1763,6 +3543,76 @@ pub struct GetObjectAttributesRequest {
     pub schema_facet: SchemaFacet,
 }
 
+impl GetObjectAttributesRequest {
+    /// Returns a builder for `GetObjectAttributesRequest`, checking its required members when built.
+    pub fn builder() -> GetObjectAttributesRequestBuilder {
+        GetObjectAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetObjectAttributesRequest`](struct.GetObjectAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetObjectAttributesRequestBuilder {
+    attribute_names: Option<Vec<String>>,
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    object_reference: Option<ObjectReference>,
+    schema_facet: Option<SchemaFacet>,
+}
+
+impl GetObjectAttributesRequestBuilder {
+    /// Sets the [`attribute_names`](struct.GetObjectAttributesRequest.html#structfield.attribute_names) member.
+    pub fn attribute_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`consistency_level`](struct.GetObjectAttributesRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.GetObjectAttributesRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.GetObjectAttributesRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_facet`](struct.GetObjectAttributesRequest.html#structfield.schema_facet) member.
+    pub fn schema_facet<T: Into<SchemaFacet>>(mut self, value: T) -> Self {
+        self.schema_facet = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetObjectAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetObjectAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(GetObjectAttributesRequest {
+            attribute_names: self
+                .attribute_names
+                .ok_or(::rusoto_core::BuildError::missing("attribute_names"))?,
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+            schema_facet: self
+                .schema_facet
+                .ok_or(::rusoto_core::BuildError::missing("schema_facet"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetObjectAttributesResponse {
@@ -1777,7 +3627,7 @@ pub struct GetObjectInformationRequest {
     /// <p>The consistency level at which to retrieve the object information.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The ARN of the directory being retrieved.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -1786,6 +3636,56 @@ pub struct GetObjectInformationRequest {
     pub object_reference: ObjectReference,
 }
 
+impl GetObjectInformationRequest {
+    /// Returns a builder for `GetObjectInformationRequest`, checking its required members when built.
+    pub fn builder() -> GetObjectInformationRequestBuilder {
+        GetObjectInformationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetObjectInformationRequest`](struct.GetObjectInformationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetObjectInformationRequestBuilder {
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    object_reference: Option<ObjectReference>,
+}
+
+impl GetObjectInformationRequestBuilder {
+    /// Sets the [`consistency_level`](struct.GetObjectInformationRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.GetObjectInformationRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.GetObjectInformationRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetObjectInformationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetObjectInformationRequest, ::rusoto_core::BuildError> {
+        Ok(GetObjectInformationRequest {
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetObjectInformationResponse {
@@ -1806,6 +3706,36 @@ pub struct GetSchemaAsJsonRequest {
     pub schema_arn: String,
 }
 
+impl GetSchemaAsJsonRequest {
+    /// Returns a builder for `GetSchemaAsJsonRequest`, checking its required members when built.
+    pub fn builder() -> GetSchemaAsJsonRequestBuilder {
+        GetSchemaAsJsonRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSchemaAsJsonRequest`](struct.GetSchemaAsJsonRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSchemaAsJsonRequestBuilder {
+    schema_arn: Option<String>,
+}
+
+impl GetSchemaAsJsonRequestBuilder {
+    /// Sets the [`schema_arn`](struct.GetSchemaAsJsonRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSchemaAsJsonRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSchemaAsJsonRequest, ::rusoto_core::BuildError> {
+        Ok(GetSchemaAsJsonRequest {
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSchemaAsJsonResponse {
@@ -1829,6 +3759,48 @@ pub struct GetTypedLinkFacetInformationRequest {
     pub schema_arn: String,
 }
 
+impl GetTypedLinkFacetInformationRequest {
+    /// Returns a builder for `GetTypedLinkFacetInformationRequest`, checking its required members when built.
+    pub fn builder() -> GetTypedLinkFacetInformationRequestBuilder {
+        GetTypedLinkFacetInformationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTypedLinkFacetInformationRequest`](struct.GetTypedLinkFacetInformationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTypedLinkFacetInformationRequestBuilder {
+    name: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl GetTypedLinkFacetInformationRequestBuilder {
+    /// Sets the [`name`](struct.GetTypedLinkFacetInformationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.GetTypedLinkFacetInformationRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTypedLinkFacetInformationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetTypedLinkFacetInformationRequest, ::rusoto_core::BuildError> {
+        Ok(GetTypedLinkFacetInformationRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTypedLinkFacetInformationResponse {
@@ -1858,7 +3830,7 @@ pub struct LinkAttributeAction {
     /// <p>A type that can be either <code>UPDATE_OR_CREATE</code> or <code>DELETE</code>.</p>
     #[serde(rename = "AttributeActionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute_action_type: Option<String>,
+    pub attribute_action_type: Option<UpdateActionType>,
     /// <p>The value that you want to update to.</p>
     #[serde(rename = "AttributeUpdateValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1897,6 +3869,62 @@ pub struct ListAppliedSchemaArnsRequest {
     pub schema_arn: Option<String>,
 }
 
+impl ListAppliedSchemaArnsRequest {
+    /// Returns a builder for `ListAppliedSchemaArnsRequest`, checking its required members when built.
+    pub fn builder() -> ListAppliedSchemaArnsRequestBuilder {
+        ListAppliedSchemaArnsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAppliedSchemaArnsRequest`](struct.ListAppliedSchemaArnsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAppliedSchemaArnsRequestBuilder {
+    directory_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl ListAppliedSchemaArnsRequestBuilder {
+    /// Sets the [`directory_arn`](struct.ListAppliedSchemaArnsRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAppliedSchemaArnsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAppliedSchemaArnsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.ListAppliedSchemaArnsRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAppliedSchemaArnsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAppliedSchemaArnsRequest, ::rusoto_core::BuildError> {
+        Ok(ListAppliedSchemaArnsRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            schema_arn: self.schema_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAppliedSchemaArnsResponse {
@@ -1915,7 +3943,7 @@ pub struct ListAttachedIndicesRequest {
     /// <p>The consistency level to use for this operation.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The ARN of the directory.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -1932,6 +3960,72 @@ pub struct ListAttachedIndicesRequest {
     pub target_reference: ObjectReference,
 }
 
+impl ListAttachedIndicesRequest {
+    /// Returns a builder for `ListAttachedIndicesRequest`, checking its required members when built.
+    pub fn builder() -> ListAttachedIndicesRequestBuilder {
+        ListAttachedIndicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAttachedIndicesRequest`](struct.ListAttachedIndicesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAttachedIndicesRequestBuilder {
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    target_reference: Option<ObjectReference>,
+}
+
+impl ListAttachedIndicesRequestBuilder {
+    /// Sets the [`consistency_level`](struct.ListAttachedIndicesRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.ListAttachedIndicesRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAttachedIndicesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAttachedIndicesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_reference`](struct.ListAttachedIndicesRequest.html#structfield.target_reference) member.
+    pub fn target_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.target_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAttachedIndicesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAttachedIndicesRequest, ::rusoto_core::BuildError> {
+        Ok(ListAttachedIndicesRequest {
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            target_reference: self
+                .target_reference
+                .ok_or(::rusoto_core::BuildError::missing("target_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAttachedIndicesResponse {
@@ -1957,6 +4051,44 @@ pub struct ListDevelopmentSchemaArnsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDevelopmentSchemaArnsRequest {
+    /// Returns a builder for `ListDevelopmentSchemaArnsRequest`, checking its required members when built.
+    pub fn builder() -> ListDevelopmentSchemaArnsRequestBuilder {
+        ListDevelopmentSchemaArnsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDevelopmentSchemaArnsRequest`](struct.ListDevelopmentSchemaArnsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDevelopmentSchemaArnsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDevelopmentSchemaArnsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListDevelopmentSchemaArnsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDevelopmentSchemaArnsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDevelopmentSchemaArnsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDevelopmentSchemaArnsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDevelopmentSchemaArnsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDevelopmentSchemaArnsResponse {
@@ -1983,24 +4115,68 @@ pub struct ListDirectoriesRequest {
     /// <p>The state of the directories in the list. Can be either Enabled, Disabled, or Deleted.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DirectoryState>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListDirectoriesResponse {
-    /// <p>Lists all directories that are associated with your account in pagination fashion.</p>
-    #[serde(rename = "Directories")]
-    pub directories: Vec<Directory>,
-    /// <p>The pagination token.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl ListDirectoriesRequest {
+    /// Returns a builder for `ListDirectoriesRequest`, checking its required members when built.
+    pub fn builder() -> ListDirectoriesRequestBuilder {
+        ListDirectoriesRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ListFacetAttributesRequest {
-    /// <p>The maximum number of results to retrieve.</p>
+/// Builds a [`ListDirectoriesRequest`](struct.ListDirectoriesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDirectoriesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    state: Option<DirectoryState>,
+}
+
+impl ListDirectoriesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListDirectoriesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDirectoriesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state`](struct.ListDirectoriesRequest.html#structfield.state) member.
+    pub fn state<T: Into<DirectoryState>>(mut self, value: T) -> Self {
+        self.state = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDirectoriesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDirectoriesRequest, ::rusoto_core::BuildError> {
+        Ok(ListDirectoriesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            state: self.state,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListDirectoriesResponse {
+    /// <p>Lists all directories that are associated with your account in pagination fashion.</p>
+    #[serde(rename = "Directories")]
+    pub directories: Vec<Directory>,
+    /// <p>The pagination token.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListFacetAttributesRequest {
+    /// <p>The maximum number of results to retrieve.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_results: Option<i64>,
@@ -2016,6 +4192,64 @@ pub struct ListFacetAttributesRequest {
     pub schema_arn: String,
 }
 
+impl ListFacetAttributesRequest {
+    /// Returns a builder for `ListFacetAttributesRequest`, checking its required members when built.
+    pub fn builder() -> ListFacetAttributesRequestBuilder {
+        ListFacetAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFacetAttributesRequest`](struct.ListFacetAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFacetAttributesRequestBuilder {
+    max_results: Option<i64>,
+    name: Option<String>,
+    next_token: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl ListFacetAttributesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListFacetAttributesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.ListFacetAttributesRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListFacetAttributesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.ListFacetAttributesRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFacetAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListFacetAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(ListFacetAttributesRequest {
+            max_results: self.max_results,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            next_token: self.next_token,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListFacetAttributesResponse {
@@ -2044,6 +4278,52 @@ pub struct ListFacetNamesRequest {
     pub schema_arn: String,
 }
 
+impl ListFacetNamesRequest {
+    /// Returns a builder for `ListFacetNamesRequest`, checking its required members when built.
+    pub fn builder() -> ListFacetNamesRequestBuilder {
+        ListFacetNamesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFacetNamesRequest`](struct.ListFacetNamesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFacetNamesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl ListFacetNamesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListFacetNamesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListFacetNamesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.ListFacetNamesRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFacetNamesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListFacetNamesRequest, ::rusoto_core::BuildError> {
+        Ok(ListFacetNamesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListFacetNamesResponse {
@@ -2062,7 +4342,7 @@ pub struct ListIncomingTypedLinksRequest {
     /// <p>The consistency level to execute the request at.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) of the directory where you want to list the typed links.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2087,6 +4367,91 @@ pub struct ListIncomingTypedLinksRequest {
     pub object_reference: ObjectReference,
 }
 
+impl ListIncomingTypedLinksRequest {
+    /// Returns a builder for `ListIncomingTypedLinksRequest`, checking its required members when built.
+    pub fn builder() -> ListIncomingTypedLinksRequestBuilder {
+        ListIncomingTypedLinksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListIncomingTypedLinksRequest`](struct.ListIncomingTypedLinksRequest.html).
+#[derive(Default, Debug)]
+pub struct ListIncomingTypedLinksRequestBuilder {
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    filter_attribute_ranges: Option<Vec<TypedLinkAttributeRange>>,
+    filter_typed_link: Option<TypedLinkSchemaAndFacetName>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    object_reference: Option<ObjectReference>,
+}
+
+impl ListIncomingTypedLinksRequestBuilder {
+    /// Sets the [`consistency_level`](struct.ListIncomingTypedLinksRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.ListIncomingTypedLinksRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_attribute_ranges`](struct.ListIncomingTypedLinksRequest.html#structfield.filter_attribute_ranges) member.
+    pub fn filter_attribute_ranges<T: Into<Vec<TypedLinkAttributeRange>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.filter_attribute_ranges = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_typed_link`](struct.ListIncomingTypedLinksRequest.html#structfield.filter_typed_link) member.
+    pub fn filter_typed_link<T: Into<TypedLinkSchemaAndFacetName>>(mut self, value: T) -> Self {
+        self.filter_typed_link = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListIncomingTypedLinksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListIncomingTypedLinksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.ListIncomingTypedLinksRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListIncomingTypedLinksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListIncomingTypedLinksRequest, ::rusoto_core::BuildError> {
+        Ok(ListIncomingTypedLinksRequest {
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            filter_attribute_ranges: self.filter_attribute_ranges,
+            filter_typed_link: self.filter_typed_link,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListIncomingTypedLinksResponse {
@@ -2105,7 +4470,7 @@ pub struct ListIndexRequest {
     /// <p>The consistency level to execute the request at.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The ARN of the directory that the index exists in.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2126,6 +4491,81 @@ pub struct ListIndexRequest {
     pub ranges_on_indexed_values: Option<Vec<ObjectAttributeRange>>,
 }
 
+impl ListIndexRequest {
+    /// Returns a builder for `ListIndexRequest`, checking its required members when built.
+    pub fn builder() -> ListIndexRequestBuilder {
+        ListIndexRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListIndexRequest`](struct.ListIndexRequest.html).
+#[derive(Default, Debug)]
+pub struct ListIndexRequestBuilder {
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    index_reference: Option<ObjectReference>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    ranges_on_indexed_values: Option<Vec<ObjectAttributeRange>>,
+}
+
+impl ListIndexRequestBuilder {
+    /// Sets the [`consistency_level`](struct.ListIndexRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.ListIndexRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`index_reference`](struct.ListIndexRequest.html#structfield.index_reference) member.
+    pub fn index_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.index_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListIndexRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListIndexRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ranges_on_indexed_values`](struct.ListIndexRequest.html#structfield.ranges_on_indexed_values) member.
+    pub fn ranges_on_indexed_values<T: Into<Vec<ObjectAttributeRange>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.ranges_on_indexed_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListIndexRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListIndexRequest, ::rusoto_core::BuildError> {
+        Ok(ListIndexRequest {
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            index_reference: self
+                .index_reference
+                .ok_or(::rusoto_core::BuildError::missing("index_reference"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            ranges_on_indexed_values: self.ranges_on_indexed_values,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListIndexResponse {
@@ -2155,6 +4595,52 @@ pub struct ListManagedSchemaArnsRequest {
     pub schema_arn: Option<String>,
 }
 
+impl ListManagedSchemaArnsRequest {
+    /// Returns a builder for `ListManagedSchemaArnsRequest`, checking its required members when built.
+    pub fn builder() -> ListManagedSchemaArnsRequestBuilder {
+        ListManagedSchemaArnsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListManagedSchemaArnsRequest`](struct.ListManagedSchemaArnsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListManagedSchemaArnsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl ListManagedSchemaArnsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListManagedSchemaArnsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListManagedSchemaArnsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.ListManagedSchemaArnsRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListManagedSchemaArnsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListManagedSchemaArnsRequest, ::rusoto_core::BuildError> {
+        Ok(ListManagedSchemaArnsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            schema_arn: self.schema_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListManagedSchemaArnsResponse {
@@ -2173,7 +4659,7 @@ pub struct ListObjectAttributesRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where the object resides. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2194,6 +4680,80 @@ pub struct ListObjectAttributesRequest {
     pub object_reference: ObjectReference,
 }
 
+impl ListObjectAttributesRequest {
+    /// Returns a builder for `ListObjectAttributesRequest`, checking its required members when built.
+    pub fn builder() -> ListObjectAttributesRequestBuilder {
+        ListObjectAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListObjectAttributesRequest`](struct.ListObjectAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListObjectAttributesRequestBuilder {
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    facet_filter: Option<SchemaFacet>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    object_reference: Option<ObjectReference>,
+}
+
+impl ListObjectAttributesRequestBuilder {
+    /// Sets the [`consistency_level`](struct.ListObjectAttributesRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.ListObjectAttributesRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`facet_filter`](struct.ListObjectAttributesRequest.html#structfield.facet_filter) member.
+    pub fn facet_filter<T: Into<SchemaFacet>>(mut self, value: T) -> Self {
+        self.facet_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListObjectAttributesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListObjectAttributesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.ListObjectAttributesRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListObjectAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListObjectAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(ListObjectAttributesRequest {
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            facet_filter: self.facet_filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListObjectAttributesResponse {
@@ -2212,7 +4772,7 @@ pub struct ListObjectChildrenRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where the object resides. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2229,6 +4789,72 @@ pub struct ListObjectChildrenRequest {
     pub object_reference: ObjectReference,
 }
 
+impl ListObjectChildrenRequest {
+    /// Returns a builder for `ListObjectChildrenRequest`, checking its required members when built.
+    pub fn builder() -> ListObjectChildrenRequestBuilder {
+        ListObjectChildrenRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListObjectChildrenRequest`](struct.ListObjectChildrenRequest.html).
+#[derive(Default, Debug)]
+pub struct ListObjectChildrenRequestBuilder {
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    object_reference: Option<ObjectReference>,
+}
+
+impl ListObjectChildrenRequestBuilder {
+    /// Sets the [`consistency_level`](struct.ListObjectChildrenRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.ListObjectChildrenRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListObjectChildrenRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListObjectChildrenRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.ListObjectChildrenRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListObjectChildrenRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListObjectChildrenRequest, ::rusoto_core::BuildError> {
+        Ok(ListObjectChildrenRequest {
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListObjectChildrenResponse {
@@ -2260,6 +4886,64 @@ pub struct ListObjectParentPathsRequest {
     pub object_reference: ObjectReference,
 }
 
+impl ListObjectParentPathsRequest {
+    /// Returns a builder for `ListObjectParentPathsRequest`, checking its required members when built.
+    pub fn builder() -> ListObjectParentPathsRequestBuilder {
+        ListObjectParentPathsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListObjectParentPathsRequest`](struct.ListObjectParentPathsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListObjectParentPathsRequestBuilder {
+    directory_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    object_reference: Option<ObjectReference>,
+}
+
+impl ListObjectParentPathsRequestBuilder {
+    /// Sets the [`directory_arn`](struct.ListObjectParentPathsRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListObjectParentPathsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListObjectParentPathsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.ListObjectParentPathsRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListObjectParentPathsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListObjectParentPathsRequest, ::rusoto_core::BuildError> {
+        Ok(ListObjectParentPathsRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListObjectParentPathsResponse {
@@ -2278,7 +4962,7 @@ pub struct ListObjectParentsRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where the object resides. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2299,6 +4983,80 @@ pub struct ListObjectParentsRequest {
     pub object_reference: ObjectReference,
 }
 
+impl ListObjectParentsRequest {
+    /// Returns a builder for `ListObjectParentsRequest`, checking its required members when built.
+    pub fn builder() -> ListObjectParentsRequestBuilder {
+        ListObjectParentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListObjectParentsRequest`](struct.ListObjectParentsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListObjectParentsRequestBuilder {
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    include_all_links_to_each_parent: Option<bool>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    object_reference: Option<ObjectReference>,
+}
+
+impl ListObjectParentsRequestBuilder {
+    /// Sets the [`consistency_level`](struct.ListObjectParentsRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.ListObjectParentsRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_all_links_to_each_parent`](struct.ListObjectParentsRequest.html#structfield.include_all_links_to_each_parent) member.
+    pub fn include_all_links_to_each_parent<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_all_links_to_each_parent = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListObjectParentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListObjectParentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.ListObjectParentsRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListObjectParentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListObjectParentsRequest, ::rusoto_core::BuildError> {
+        Ok(ListObjectParentsRequest {
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            include_all_links_to_each_parent: self.include_all_links_to_each_parent,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListObjectParentsResponse {
@@ -2321,7 +5079,7 @@ pub struct ListObjectPoliciesRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where objects reside. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2338,6 +5096,72 @@ pub struct ListObjectPoliciesRequest {
     pub object_reference: ObjectReference,
 }
 
+impl ListObjectPoliciesRequest {
+    /// Returns a builder for `ListObjectPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListObjectPoliciesRequestBuilder {
+        ListObjectPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListObjectPoliciesRequest`](struct.ListObjectPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListObjectPoliciesRequestBuilder {
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    object_reference: Option<ObjectReference>,
+}
+
+impl ListObjectPoliciesRequestBuilder {
+    /// Sets the [`consistency_level`](struct.ListObjectPoliciesRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.ListObjectPoliciesRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListObjectPoliciesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListObjectPoliciesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.ListObjectPoliciesRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListObjectPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListObjectPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListObjectPoliciesRequest {
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListObjectPoliciesResponse {
@@ -2356,7 +5180,7 @@ pub struct ListOutgoingTypedLinksRequest {
     /// <p>The consistency level to execute the request at.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) of the directory where you want to list the typed links.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2381,6 +5205,91 @@ pub struct ListOutgoingTypedLinksRequest {
     pub object_reference: ObjectReference,
 }
 
+impl ListOutgoingTypedLinksRequest {
+    /// Returns a builder for `ListOutgoingTypedLinksRequest`, checking its required members when built.
+    pub fn builder() -> ListOutgoingTypedLinksRequestBuilder {
+        ListOutgoingTypedLinksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListOutgoingTypedLinksRequest`](struct.ListOutgoingTypedLinksRequest.html).
+#[derive(Default, Debug)]
+pub struct ListOutgoingTypedLinksRequestBuilder {
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    filter_attribute_ranges: Option<Vec<TypedLinkAttributeRange>>,
+    filter_typed_link: Option<TypedLinkSchemaAndFacetName>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    object_reference: Option<ObjectReference>,
+}
+
+impl ListOutgoingTypedLinksRequestBuilder {
+    /// Sets the [`consistency_level`](struct.ListOutgoingTypedLinksRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.ListOutgoingTypedLinksRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_attribute_ranges`](struct.ListOutgoingTypedLinksRequest.html#structfield.filter_attribute_ranges) member.
+    pub fn filter_attribute_ranges<T: Into<Vec<TypedLinkAttributeRange>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.filter_attribute_ranges = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_typed_link`](struct.ListOutgoingTypedLinksRequest.html#structfield.filter_typed_link) member.
+    pub fn filter_typed_link<T: Into<TypedLinkSchemaAndFacetName>>(mut self, value: T) -> Self {
+        self.filter_typed_link = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListOutgoingTypedLinksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListOutgoingTypedLinksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.ListOutgoingTypedLinksRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOutgoingTypedLinksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListOutgoingTypedLinksRequest, ::rusoto_core::BuildError> {
+        Ok(ListOutgoingTypedLinksRequest {
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            filter_attribute_ranges: self.filter_attribute_ranges,
+            filter_typed_link: self.filter_typed_link,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListOutgoingTypedLinksResponse {
@@ -2399,7 +5308,7 @@ pub struct ListPolicyAttachmentsRequest {
     /// <p>Represents the manner and timing in which the successful write or update of an object is reflected in a subsequent read operation of that same object.</p>
     #[serde(rename = "ConsistencyLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistency_level: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Directory</a> where objects reside. For more information, see <a>arns</a>.</p>
     #[serde(rename = "DirectoryArn")]
     pub directory_arn: String,
@@ -2416,25 +5325,91 @@ pub struct ListPolicyAttachmentsRequest {
     pub policy_reference: ObjectReference,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListPolicyAttachmentsResponse {
-    /// <p>The pagination token.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>A list of <code>ObjectIdentifiers</code> to which the policy is attached.</p>
-    #[serde(rename = "ObjectIdentifiers")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_identifiers: Option<Vec<String>>,
+impl ListPolicyAttachmentsRequest {
+    /// Returns a builder for `ListPolicyAttachmentsRequest`, checking its required members when built.
+    pub fn builder() -> ListPolicyAttachmentsRequestBuilder {
+        ListPolicyAttachmentsRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ListPublishedSchemaArnsRequest {
-    /// <p>The maximum number of results to retrieve.</p>
-    #[serde(rename = "MaxResults")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub max_results: Option<i64>,
+/// Builds a [`ListPolicyAttachmentsRequest`](struct.ListPolicyAttachmentsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPolicyAttachmentsRequestBuilder {
+    consistency_level: Option<ConsistencyLevel>,
+    directory_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    policy_reference: Option<ObjectReference>,
+}
+
+impl ListPolicyAttachmentsRequestBuilder {
+    /// Sets the [`consistency_level`](struct.ListPolicyAttachmentsRequest.html#structfield.consistency_level) member.
+    pub fn consistency_level<T: Into<ConsistencyLevel>>(mut self, value: T) -> Self {
+        self.consistency_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.ListPolicyAttachmentsRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListPolicyAttachmentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPolicyAttachmentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_reference`](struct.ListPolicyAttachmentsRequest.html#structfield.policy_reference) member.
+    pub fn policy_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.policy_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPolicyAttachmentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPolicyAttachmentsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPolicyAttachmentsRequest {
+            consistency_level: self.consistency_level,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            policy_reference: self
+                .policy_reference
+                .ok_or(::rusoto_core::BuildError::missing("policy_reference"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListPolicyAttachmentsResponse {
+    /// <p>The pagination token.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>A list of <code>ObjectIdentifiers</code> to which the policy is attached.</p>
+    #[serde(rename = "ObjectIdentifiers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_identifiers: Option<Vec<String>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListPublishedSchemaArnsRequest {
+    /// <p>The maximum number of results to retrieve.</p>
+    #[serde(rename = "MaxResults")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<i64>,
     /// <p>The pagination token.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2445,6 +5420,52 @@ pub struct ListPublishedSchemaArnsRequest {
     pub schema_arn: Option<String>,
 }
 
+impl ListPublishedSchemaArnsRequest {
+    /// Returns a builder for `ListPublishedSchemaArnsRequest`, checking its required members when built.
+    pub fn builder() -> ListPublishedSchemaArnsRequestBuilder {
+        ListPublishedSchemaArnsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPublishedSchemaArnsRequest`](struct.ListPublishedSchemaArnsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPublishedSchemaArnsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl ListPublishedSchemaArnsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListPublishedSchemaArnsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPublishedSchemaArnsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.ListPublishedSchemaArnsRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPublishedSchemaArnsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPublishedSchemaArnsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPublishedSchemaArnsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            schema_arn: self.schema_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPublishedSchemaArnsResponse {
@@ -2473,6 +5494,54 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`max_results`](struct.ListTagsForResourceRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsForResourceRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -2504,6 +5573,64 @@ pub struct ListTypedLinkFacetAttributesRequest {
     pub schema_arn: String,
 }
 
+impl ListTypedLinkFacetAttributesRequest {
+    /// Returns a builder for `ListTypedLinkFacetAttributesRequest`, checking its required members when built.
+    pub fn builder() -> ListTypedLinkFacetAttributesRequestBuilder {
+        ListTypedLinkFacetAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTypedLinkFacetAttributesRequest`](struct.ListTypedLinkFacetAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTypedLinkFacetAttributesRequestBuilder {
+    max_results: Option<i64>,
+    name: Option<String>,
+    next_token: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl ListTypedLinkFacetAttributesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListTypedLinkFacetAttributesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.ListTypedLinkFacetAttributesRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTypedLinkFacetAttributesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.ListTypedLinkFacetAttributesRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTypedLinkFacetAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTypedLinkFacetAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(ListTypedLinkFacetAttributesRequest {
+            max_results: self.max_results,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            next_token: self.next_token,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTypedLinkFacetAttributesResponse {
@@ -2532,6 +5659,54 @@ pub struct ListTypedLinkFacetNamesRequest {
     pub schema_arn: String,
 }
 
+impl ListTypedLinkFacetNamesRequest {
+    /// Returns a builder for `ListTypedLinkFacetNamesRequest`, checking its required members when built.
+    pub fn builder() -> ListTypedLinkFacetNamesRequestBuilder {
+        ListTypedLinkFacetNamesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTypedLinkFacetNamesRequest`](struct.ListTypedLinkFacetNamesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTypedLinkFacetNamesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl ListTypedLinkFacetNamesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListTypedLinkFacetNamesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTypedLinkFacetNamesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.ListTypedLinkFacetNamesRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTypedLinkFacetNamesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTypedLinkFacetNamesRequest, ::rusoto_core::BuildError> {
+        Ok(ListTypedLinkFacetNamesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTypedLinkFacetNamesResponse {
@@ -2563,6 +5738,62 @@ pub struct LookupPolicyRequest {
     pub object_reference: ObjectReference,
 }
 
+impl LookupPolicyRequest {
+    /// Returns a builder for `LookupPolicyRequest`, checking its required members when built.
+    pub fn builder() -> LookupPolicyRequestBuilder {
+        LookupPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`LookupPolicyRequest`](struct.LookupPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct LookupPolicyRequestBuilder {
+    directory_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    object_reference: Option<ObjectReference>,
+}
+
+impl LookupPolicyRequestBuilder {
+    /// Sets the [`directory_arn`](struct.LookupPolicyRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.LookupPolicyRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.LookupPolicyRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.LookupPolicyRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `LookupPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<LookupPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(LookupPolicyRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct LookupPolicyResponse {
@@ -2582,7 +5813,7 @@ pub struct ObjectAttributeAction {
     /// <p>A type that can be either <code>Update</code> or <code>Delete</code>.</p>
     #[serde(rename = "ObjectAttributeActionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_attribute_action_type: Option<String>,
+    pub object_attribute_action_type: Option<UpdateActionType>,
     /// <p>The value that you want to update to.</p>
     #[serde(rename = "ObjectAttributeUpdateValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2638,6 +5869,92 @@ pub struct ObjectReference {
     pub selector: Option<String>,
 }
 
+/// <p><p>Specifies whether a given object created from this facet is of type node, leaf node, policy or index.</p> <ul> <li> <p>Node: Can have multiple children but one parent.</p> </li> </ul> <ul> <li> <p>Leaf node: Cannot have children but can have multiple parents.</p> </li> </ul> <ul> <li> <p>Policy: Allows you to store a policy document and policy type. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/key_concepts_directory.html#key_concepts_policies">Policies</a>.</p> </li> </ul> <ul> <li> <p>Index: Can be created with the Index API.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ObjectType {
+    /// <p><code>NODE</code></p>
+    Node,
+    /// <p><code>LEAF_NODE</code></p>
+    LeafNode,
+    /// <p><code>POLICY</code></p>
+    Policy,
+    /// <p><code>INDEX</code></p>
+    Index,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ObjectType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectType::Node => "NODE",
+            ObjectType::LeafNode => "LEAF_NODE",
+            ObjectType::Policy => "POLICY",
+            ObjectType::Index => "INDEX",
+            ObjectType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ObjectType {
+    fn from(value: &'a str) -> ObjectType {
+        match value {
+            "NODE" => ObjectType::Node,
+            "LEAF_NODE" => ObjectType::LeafNode,
+            "POLICY" => ObjectType::Policy,
+            "INDEX" => ObjectType::Index,
+            _ => ObjectType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectType {
+    fn from(value: String) -> ObjectType {
+        ObjectType::from(value.as_str())
+    }
+}
+
+impl From<ObjectType> for String {
+    fn from(value: ObjectType) -> String {
+        match value {
+            ObjectType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ObjectType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ObjectType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ObjectType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ObjectType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ObjectType::from(value))
+    }
+}
+
 /// <p>Returns the path to the <code>ObjectIdentifiers</code> that is associated with the directory.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2702,6 +6019,62 @@ pub struct PublishSchemaRequest {
     pub version: String,
 }
 
+impl PublishSchemaRequest {
+    /// Returns a builder for `PublishSchemaRequest`, checking its required members when built.
+    pub fn builder() -> PublishSchemaRequestBuilder {
+        PublishSchemaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PublishSchemaRequest`](struct.PublishSchemaRequest.html).
+#[derive(Default, Debug)]
+pub struct PublishSchemaRequestBuilder {
+    development_schema_arn: Option<String>,
+    minor_version: Option<String>,
+    name: Option<String>,
+    version: Option<String>,
+}
+
+impl PublishSchemaRequestBuilder {
+    /// Sets the [`development_schema_arn`](struct.PublishSchemaRequest.html#structfield.development_schema_arn) member.
+    pub fn development_schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.development_schema_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`minor_version`](struct.PublishSchemaRequest.html#structfield.minor_version) member.
+    pub fn minor_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.minor_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.PublishSchemaRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.PublishSchemaRequest.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `PublishSchemaRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PublishSchemaRequest, ::rusoto_core::BuildError> {
+        Ok(PublishSchemaRequest {
+            development_schema_arn: self
+                .development_schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("development_schema_arn"))?,
+            minor_version: self.minor_version,
+            name: self.name,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PublishSchemaResponse {
@@ -2721,6 +6094,48 @@ pub struct PutSchemaFromJsonRequest {
     pub schema_arn: String,
 }
 
+impl PutSchemaFromJsonRequest {
+    /// Returns a builder for `PutSchemaFromJsonRequest`, checking its required members when built.
+    pub fn builder() -> PutSchemaFromJsonRequestBuilder {
+        PutSchemaFromJsonRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutSchemaFromJsonRequest`](struct.PutSchemaFromJsonRequest.html).
+#[derive(Default, Debug)]
+pub struct PutSchemaFromJsonRequestBuilder {
+    document: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl PutSchemaFromJsonRequestBuilder {
+    /// Sets the [`document`](struct.PutSchemaFromJsonRequest.html#structfield.document) member.
+    pub fn document<T: Into<String>>(mut self, value: T) -> Self {
+        self.document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.PutSchemaFromJsonRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutSchemaFromJsonRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutSchemaFromJsonRequest, ::rusoto_core::BuildError> {
+        Ok(PutSchemaFromJsonRequest {
+            document: self
+                .document
+                .ok_or(::rusoto_core::BuildError::missing("document"))?,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutSchemaFromJsonResponse {
@@ -2730,6 +6145,96 @@ pub struct PutSchemaFromJsonResponse {
     pub arn: Option<String>,
 }
 
+/// <p>The inclusive or exclusive range end.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RangeMode {
+    /// <p><code>FIRST</code></p>
+    First,
+    /// <p><code>LAST</code></p>
+    Last,
+    /// <p><code>LAST_BEFORE_MISSING_VALUES</code></p>
+    LastBeforeMissingValues,
+    /// <p><code>INCLUSIVE</code></p>
+    Inclusive,
+    /// <p><code>EXCLUSIVE</code></p>
+    Exclusive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RangeMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RangeMode::First => "FIRST",
+            RangeMode::Last => "LAST",
+            RangeMode::LastBeforeMissingValues => "LAST_BEFORE_MISSING_VALUES",
+            RangeMode::Inclusive => "INCLUSIVE",
+            RangeMode::Exclusive => "EXCLUSIVE",
+            RangeMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RangeMode {
+    fn from(value: &'a str) -> RangeMode {
+        match value {
+            "FIRST" => RangeMode::First,
+            "LAST" => RangeMode::Last,
+            "LAST_BEFORE_MISSING_VALUES" => RangeMode::LastBeforeMissingValues,
+            "INCLUSIVE" => RangeMode::Inclusive,
+            "EXCLUSIVE" => RangeMode::Exclusive,
+            _ => RangeMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RangeMode {
+    fn from(value: String) -> RangeMode {
+        RangeMode::from(value.as_str())
+    }
+}
+
+impl From<RangeMode> for String {
+    fn from(value: RangeMode) -> String {
+        match value {
+            RangeMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RangeMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RangeMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RangeMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RangeMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RangeMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RangeMode::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RemoveFacetFromObjectRequest {
     /// <p>The ARN of the directory in which the object resides.</p>
@@ -2743,43 +6248,259 @@ pub struct RemoveFacetFromObjectRequest {
     pub schema_facet: SchemaFacet,
 }
 
+impl RemoveFacetFromObjectRequest {
+    /// Returns a builder for `RemoveFacetFromObjectRequest`, checking its required members when built.
+    pub fn builder() -> RemoveFacetFromObjectRequestBuilder {
+        RemoveFacetFromObjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveFacetFromObjectRequest`](struct.RemoveFacetFromObjectRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveFacetFromObjectRequestBuilder {
+    directory_arn: Option<String>,
+    object_reference: Option<ObjectReference>,
+    schema_facet: Option<SchemaFacet>,
+}
+
+impl RemoveFacetFromObjectRequestBuilder {
+    /// Sets the [`directory_arn`](struct.RemoveFacetFromObjectRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.RemoveFacetFromObjectRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_facet`](struct.RemoveFacetFromObjectRequest.html#structfield.schema_facet) member.
+    pub fn schema_facet<T: Into<SchemaFacet>>(mut self, value: T) -> Self {
+        self.schema_facet = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveFacetFromObjectRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveFacetFromObjectRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveFacetFromObjectRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+            schema_facet: self
+                .schema_facet
+                .ok_or(::rusoto_core::BuildError::missing("schema_facet"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RemoveFacetFromObjectResponse {}
 
-/// <p>Contains an Amazon Resource Name (ARN) and parameters that are associated with the rule.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Rule {
-    /// <p>The minimum and maximum parameters that are associated with the rule.</p>
-    #[serde(rename = "Parameters")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<::std::collections::HashMap<String, String>>,
-    /// <p>The type of attribute validation rule.</p>
-    #[serde(rename = "Type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+/// <p>The required behavior of the <code>FacetAttribute</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RequiredAttributeBehavior {
+    /// <p><code>REQUIRED_ALWAYS</code></p>
+    RequiredAlways,
+    /// <p><code>NOT_REQUIRED</code></p>
+    NotRequired,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>A facet.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct SchemaFacet {
-    /// <p>The name of the facet.</p>
-    #[serde(rename = "FacetName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub facet_name: Option<String>,
-    /// <p>The ARN of the schema that contains the facet with no minor component. See <a>arns</a> and <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_inplaceschemaupgrade.html">In-Place Schema Upgrade</a> for a description of when to provide minor versions.</p>
-    #[serde(rename = "SchemaArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub schema_arn: Option<String>,
+impl RequiredAttributeBehavior {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequiredAttributeBehavior::RequiredAlways => "REQUIRED_ALWAYS",
+            RequiredAttributeBehavior::NotRequired => "NOT_REQUIRED",
+            RequiredAttributeBehavior::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>The tag structure that contains a tag key and value.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Tag {
-    /// <p>The key that is associated with the tag.</p>
-    #[serde(rename = "Key")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
+impl<'a> From<&'a str> for RequiredAttributeBehavior {
+    fn from(value: &'a str) -> RequiredAttributeBehavior {
+        match value {
+            "REQUIRED_ALWAYS" => RequiredAttributeBehavior::RequiredAlways,
+            "NOT_REQUIRED" => RequiredAttributeBehavior::NotRequired,
+            _ => RequiredAttributeBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequiredAttributeBehavior {
+    fn from(value: String) -> RequiredAttributeBehavior {
+        RequiredAttributeBehavior::from(value.as_str())
+    }
+}
+
+impl From<RequiredAttributeBehavior> for String {
+    fn from(value: RequiredAttributeBehavior) -> String {
+        match value {
+            RequiredAttributeBehavior::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RequiredAttributeBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RequiredAttributeBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RequiredAttributeBehavior {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RequiredAttributeBehavior {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RequiredAttributeBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RequiredAttributeBehavior::from(value))
+    }
+}
+
+/// <p>Contains an Amazon Resource Name (ARN) and parameters that are associated with the rule.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rule {
+    /// <p>The minimum and maximum parameters that are associated with the rule.</p>
+    #[serde(rename = "Parameters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<::std::collections::HashMap<String, String>>,
+    /// <p>The type of attribute validation rule.</p>
+    #[serde(rename = "Type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<RuleType>,
+}
+
+/// <p>The type of attribute validation rule.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RuleType {
+    /// <p><code>BINARY_LENGTH</code></p>
+    BinaryLength,
+    /// <p><code>NUMBER_COMPARISON</code></p>
+    NumberComparison,
+    /// <p><code>STRING_FROM_SET</code></p>
+    StringFromSet,
+    /// <p><code>STRING_LENGTH</code></p>
+    StringLength,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RuleType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RuleType::BinaryLength => "BINARY_LENGTH",
+            RuleType::NumberComparison => "NUMBER_COMPARISON",
+            RuleType::StringFromSet => "STRING_FROM_SET",
+            RuleType::StringLength => "STRING_LENGTH",
+            RuleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RuleType {
+    fn from(value: &'a str) -> RuleType {
+        match value {
+            "BINARY_LENGTH" => RuleType::BinaryLength,
+            "NUMBER_COMPARISON" => RuleType::NumberComparison,
+            "STRING_FROM_SET" => RuleType::StringFromSet,
+            "STRING_LENGTH" => RuleType::StringLength,
+            _ => RuleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RuleType {
+    fn from(value: String) -> RuleType {
+        RuleType::from(value.as_str())
+    }
+}
+
+impl From<RuleType> for String {
+    fn from(value: RuleType) -> String {
+        match value {
+            RuleType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RuleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RuleType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for RuleType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RuleType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RuleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RuleType::from(value))
+    }
+}
+
+/// <p>A facet.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaFacet {
+    /// <p>The name of the facet.</p>
+    #[serde(rename = "FacetName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facet_name: Option<String>,
+    /// <p>The ARN of the schema that contains the facet with no minor component. See <a>arns</a> and <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/schemas_inplaceschemaupgrade.html">In-Place Schema Upgrade</a> for a description of when to provide minor versions.</p>
+    #[serde(rename = "SchemaArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_arn: Option<String>,
+}
+
+/// <p>The tag structure that contains a tag key and value.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tag {
+    /// <p>The key that is associated with the tag.</p>
+    #[serde(rename = "Key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
     /// <p>The value that is associated with the tag.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2796,6 +6517,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -2818,8 +6579,9 @@ pub struct TypedAttributeValue {
     pub boolean_value: Option<bool>,
     /// <p>A date and time value.</p>
     #[serde(rename = "DatetimeValue")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub datetime_value: Option<f64>,
+    pub datetime_value: Option<EpochTimestamp>,
     /// <p>A number data value.</p>
     #[serde(rename = "NumberValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2831,18 +6593,18 @@ pub struct TypedAttributeValue {
 }
 
 /// <p>A range of attribute values. For more information, see <a href="https://docs.aws.amazon.com/clouddirectory/latest/developerguide/directory_objects_range_filters.html">Range Filters</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct TypedAttributeValueRange {
     /// <p>The inclusive or exclusive range end.</p>
     #[serde(rename = "EndMode")]
-    pub end_mode: String,
+    pub end_mode: RangeMode,
     /// <p>The attribute value to terminate the range at.</p>
     #[serde(rename = "EndValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end_value: Option<TypedAttributeValue>,
     /// <p>The inclusive or exclusive range start.</p>
     #[serde(rename = "StartMode")]
-    pub start_mode: String,
+    pub start_mode: RangeMode,
     /// <p>The value to start the range at.</p>
     #[serde(rename = "StartValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2850,7 +6612,7 @@ pub struct TypedAttributeValueRange {
 }
 
 /// <p>A typed link attribute definition.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TypedLinkAttributeDefinition {
     /// <p>The default value of the attribute (if configured).</p>
     #[serde(rename = "DefaultValue")]
@@ -2865,18 +6627,18 @@ pub struct TypedLinkAttributeDefinition {
     pub name: String,
     /// <p>The required behavior of the <code>TypedLinkAttributeDefinition</code>.</p>
     #[serde(rename = "RequiredBehavior")]
-    pub required_behavior: String,
+    pub required_behavior: RequiredAttributeBehavior,
     /// <p>Validation rules that are attached to the attribute definition.</p>
     #[serde(rename = "Rules")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rules: Option<::std::collections::HashMap<String, Rule>>,
     /// <p>The type of the attribute.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: FacetAttributeType,
 }
 
 /// <p>Identifies the range of attributes that are used by a specified filter.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct TypedLinkAttributeRange {
     /// <p>The unique name of the typed link attribute.</p>
     #[serde(rename = "AttributeName")]
@@ -2902,11 +6664,11 @@ pub struct TypedLinkFacet {
 }
 
 /// <p>A typed link facet attribute update.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct TypedLinkFacetAttributeUpdate {
     /// <p>The action to perform when updating the attribute.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: UpdateActionType,
     /// <p>The attribute to update.</p>
     #[serde(rename = "Attribute")]
     pub attribute: TypedLinkAttributeDefinition,
@@ -2950,10 +6712,128 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
 
+/// <p>The action to perform when updating the attribute.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateActionType {
+    /// <p><code>CREATE_OR_UPDATE</code></p>
+    CreateOrUpdate,
+    /// <p><code>DELETE</code></p>
+    Delete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UpdateActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateActionType::CreateOrUpdate => "CREATE_OR_UPDATE",
+            UpdateActionType::Delete => "DELETE",
+            UpdateActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UpdateActionType {
+    fn from(value: &'a str) -> UpdateActionType {
+        match value {
+            "CREATE_OR_UPDATE" => UpdateActionType::CreateOrUpdate,
+            "DELETE" => UpdateActionType::Delete,
+            _ => UpdateActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateActionType {
+    fn from(value: String) -> UpdateActionType {
+        UpdateActionType::from(value.as_str())
+    }
+}
+
+impl From<UpdateActionType> for String {
+    fn from(value: UpdateActionType) -> String {
+        match value {
+            UpdateActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateActionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UpdateActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UpdateActionType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateFacetRequest {
     /// <p>List of attributes that need to be updated in a given schema <a>Facet</a>. Each attribute is followed by <code>AttributeAction</code>, which specifies the type of update operation to perform. </p>
@@ -2966,12 +6846,68 @@ pub struct UpdateFacetRequest {
     /// <p>The object type that is associated with the facet. See <a>CreateFacetRequest$ObjectType</a> for more details.</p>
     #[serde(rename = "ObjectType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub object_type: Option<String>,
+    pub object_type: Option<ObjectType>,
     /// <p>The Amazon Resource Name (ARN) that is associated with the <a>Facet</a>. For more information, see <a>arns</a>.</p>
     #[serde(rename = "SchemaArn")]
     pub schema_arn: String,
 }
 
+impl UpdateFacetRequest {
+    /// Returns a builder for `UpdateFacetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFacetRequestBuilder {
+        UpdateFacetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFacetRequest`](struct.UpdateFacetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFacetRequestBuilder {
+    attribute_updates: Option<Vec<FacetAttributeUpdate>>,
+    name: Option<String>,
+    object_type: Option<ObjectType>,
+    schema_arn: Option<String>,
+}
+
+impl UpdateFacetRequestBuilder {
+    /// Sets the [`attribute_updates`](struct.UpdateFacetRequest.html#structfield.attribute_updates) member.
+    pub fn attribute_updates<T: Into<Vec<FacetAttributeUpdate>>>(mut self, value: T) -> Self {
+        self.attribute_updates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateFacetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_type`](struct.UpdateFacetRequest.html#structfield.object_type) member.
+    pub fn object_type<T: Into<ObjectType>>(mut self, value: T) -> Self {
+        self.object_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.UpdateFacetRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFacetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateFacetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFacetRequest {
+            attribute_updates: self.attribute_updates,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            object_type: self.object_type,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateFacetResponse {}
@@ -2989,6 +6925,58 @@ pub struct UpdateLinkAttributesRequest {
     pub typed_link_specifier: TypedLinkSpecifier,
 }
 
+impl UpdateLinkAttributesRequest {
+    /// Returns a builder for `UpdateLinkAttributesRequest`, checking its required members when built.
+    pub fn builder() -> UpdateLinkAttributesRequestBuilder {
+        UpdateLinkAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateLinkAttributesRequest`](struct.UpdateLinkAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateLinkAttributesRequestBuilder {
+    attribute_updates: Option<Vec<LinkAttributeUpdate>>,
+    directory_arn: Option<String>,
+    typed_link_specifier: Option<TypedLinkSpecifier>,
+}
+
+impl UpdateLinkAttributesRequestBuilder {
+    /// Sets the [`attribute_updates`](struct.UpdateLinkAttributesRequest.html#structfield.attribute_updates) member.
+    pub fn attribute_updates<T: Into<Vec<LinkAttributeUpdate>>>(mut self, value: T) -> Self {
+        self.attribute_updates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.UpdateLinkAttributesRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`typed_link_specifier`](struct.UpdateLinkAttributesRequest.html#structfield.typed_link_specifier) member.
+    pub fn typed_link_specifier<T: Into<TypedLinkSpecifier>>(mut self, value: T) -> Self {
+        self.typed_link_specifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateLinkAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateLinkAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateLinkAttributesRequest {
+            attribute_updates: self
+                .attribute_updates
+                .ok_or(::rusoto_core::BuildError::missing("attribute_updates"))?,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            typed_link_specifier: self
+                .typed_link_specifier
+                .ok_or(::rusoto_core::BuildError::missing("typed_link_specifier"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateLinkAttributesResponse {}
@@ -3006,6 +6994,58 @@ pub struct UpdateObjectAttributesRequest {
     pub object_reference: ObjectReference,
 }
 
+impl UpdateObjectAttributesRequest {
+    /// Returns a builder for `UpdateObjectAttributesRequest`, checking its required members when built.
+    pub fn builder() -> UpdateObjectAttributesRequestBuilder {
+        UpdateObjectAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateObjectAttributesRequest`](struct.UpdateObjectAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateObjectAttributesRequestBuilder {
+    attribute_updates: Option<Vec<ObjectAttributeUpdate>>,
+    directory_arn: Option<String>,
+    object_reference: Option<ObjectReference>,
+}
+
+impl UpdateObjectAttributesRequestBuilder {
+    /// Sets the [`attribute_updates`](struct.UpdateObjectAttributesRequest.html#structfield.attribute_updates) member.
+    pub fn attribute_updates<T: Into<Vec<ObjectAttributeUpdate>>>(mut self, value: T) -> Self {
+        self.attribute_updates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_arn`](struct.UpdateObjectAttributesRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_reference`](struct.UpdateObjectAttributesRequest.html#structfield.object_reference) member.
+    pub fn object_reference<T: Into<ObjectReference>>(mut self, value: T) -> Self {
+        self.object_reference = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateObjectAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateObjectAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateObjectAttributesRequest {
+            attribute_updates: self
+                .attribute_updates
+                .ok_or(::rusoto_core::BuildError::missing("attribute_updates"))?,
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            object_reference: self
+                .object_reference
+                .ok_or(::rusoto_core::BuildError::missing("object_reference"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateObjectAttributesResponse {
@@ -3025,6 +7065,46 @@ pub struct UpdateSchemaRequest {
     pub schema_arn: String,
 }
 
+impl UpdateSchemaRequest {
+    /// Returns a builder for `UpdateSchemaRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSchemaRequestBuilder {
+        UpdateSchemaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSchemaRequest`](struct.UpdateSchemaRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSchemaRequestBuilder {
+    name: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl UpdateSchemaRequestBuilder {
+    /// Sets the [`name`](struct.UpdateSchemaRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.UpdateSchemaRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSchemaRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateSchemaRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSchemaRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateSchemaResponse {
@@ -3050,6 +7130,71 @@ pub struct UpdateTypedLinkFacetRequest {
     pub schema_arn: String,
 }
 
+impl UpdateTypedLinkFacetRequest {
+    /// Returns a builder for `UpdateTypedLinkFacetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTypedLinkFacetRequestBuilder {
+        UpdateTypedLinkFacetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTypedLinkFacetRequest`](struct.UpdateTypedLinkFacetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTypedLinkFacetRequestBuilder {
+    attribute_updates: Option<Vec<TypedLinkFacetAttributeUpdate>>,
+    identity_attribute_order: Option<Vec<String>>,
+    name: Option<String>,
+    schema_arn: Option<String>,
+}
+
+impl UpdateTypedLinkFacetRequestBuilder {
+    /// Sets the [`attribute_updates`](struct.UpdateTypedLinkFacetRequest.html#structfield.attribute_updates) member.
+    pub fn attribute_updates<T: Into<Vec<TypedLinkFacetAttributeUpdate>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attribute_updates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_attribute_order`](struct.UpdateTypedLinkFacetRequest.html#structfield.identity_attribute_order) member.
+    pub fn identity_attribute_order<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.identity_attribute_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateTypedLinkFacetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_arn`](struct.UpdateTypedLinkFacetRequest.html#structfield.schema_arn) member.
+    pub fn schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTypedLinkFacetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateTypedLinkFacetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTypedLinkFacetRequest {
+            attribute_updates: self
+                .attribute_updates
+                .ok_or(::rusoto_core::BuildError::missing("attribute_updates"))?,
+            identity_attribute_order: self.identity_attribute_order.ok_or(
+                ::rusoto_core::BuildError::missing("identity_attribute_order"),
+            )?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            schema_arn: self
+                .schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateTypedLinkFacetResponse {}
@@ -3068,6 +7213,56 @@ pub struct UpgradeAppliedSchemaRequest {
     pub published_schema_arn: String,
 }
 
+impl UpgradeAppliedSchemaRequest {
+    /// Returns a builder for `UpgradeAppliedSchemaRequest`, checking its required members when built.
+    pub fn builder() -> UpgradeAppliedSchemaRequestBuilder {
+        UpgradeAppliedSchemaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpgradeAppliedSchemaRequest`](struct.UpgradeAppliedSchemaRequest.html).
+#[derive(Default, Debug)]
+pub struct UpgradeAppliedSchemaRequestBuilder {
+    directory_arn: Option<String>,
+    dry_run: Option<bool>,
+    published_schema_arn: Option<String>,
+}
+
+impl UpgradeAppliedSchemaRequestBuilder {
+    /// Sets the [`directory_arn`](struct.UpgradeAppliedSchemaRequest.html#structfield.directory_arn) member.
+    pub fn directory_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dry_run`](struct.UpgradeAppliedSchemaRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`published_schema_arn`](struct.UpgradeAppliedSchemaRequest.html#structfield.published_schema_arn) member.
+    pub fn published_schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.published_schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpgradeAppliedSchemaRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpgradeAppliedSchemaRequest, ::rusoto_core::BuildError> {
+        Ok(UpgradeAppliedSchemaRequest {
+            directory_arn: self
+                .directory_arn
+                .ok_or(::rusoto_core::BuildError::missing("directory_arn"))?,
+            dry_run: self.dry_run,
+            published_schema_arn: self
+                .published_schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("published_schema_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpgradeAppliedSchemaResponse {
@@ -3098,10 +7293,70 @@ pub struct UpgradePublishedSchemaRequest {
     pub published_schema_arn: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpgradePublishedSchemaResponse {
-    /// <p>The ARN of the upgraded schema that is returned as part of the response.</p>
+impl UpgradePublishedSchemaRequest {
+    /// Returns a builder for `UpgradePublishedSchemaRequest`, checking its required members when built.
+    pub fn builder() -> UpgradePublishedSchemaRequestBuilder {
+        UpgradePublishedSchemaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpgradePublishedSchemaRequest`](struct.UpgradePublishedSchemaRequest.html).
+#[derive(Default, Debug)]
+pub struct UpgradePublishedSchemaRequestBuilder {
+    development_schema_arn: Option<String>,
+    dry_run: Option<bool>,
+    minor_version: Option<String>,
+    published_schema_arn: Option<String>,
+}
+
+impl UpgradePublishedSchemaRequestBuilder {
+    /// Sets the [`development_schema_arn`](struct.UpgradePublishedSchemaRequest.html#structfield.development_schema_arn) member.
+    pub fn development_schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.development_schema_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dry_run`](struct.UpgradePublishedSchemaRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`minor_version`](struct.UpgradePublishedSchemaRequest.html#structfield.minor_version) member.
+    pub fn minor_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.minor_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`published_schema_arn`](struct.UpgradePublishedSchemaRequest.html#structfield.published_schema_arn) member.
+    pub fn published_schema_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.published_schema_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpgradePublishedSchemaRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpgradePublishedSchemaRequest, ::rusoto_core::BuildError> {
+        Ok(UpgradePublishedSchemaRequest {
+            development_schema_arn: self
+                .development_schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("development_schema_arn"))?,
+            dry_run: self.dry_run,
+            minor_version: self
+                .minor_version
+                .ok_or(::rusoto_core::BuildError::missing("minor_version"))?,
+            published_schema_arn: self
+                .published_schema_arn
+                .ok_or(::rusoto_core::BuildError::missing("published_schema_arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpgradePublishedSchemaResponse {
+    /// <p>The ARN of the upgraded schema that is returned as part of the response.</p>
     #[serde(rename = "UpgradedSchemaArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub upgraded_schema_arn: Option<String>,
@@ -8686,6 +12941,408 @@ pub trait CloudDirectory {
         input: UpgradePublishedSchemaRequest,
     ) -> RusotoFuture<UpgradePublishedSchemaResponse, UpgradePublishedSchemaError>;
 }
+impl<T: ?Sized + CloudDirectory> CloudDirectory for ::std::sync::Arc<T> {
+    fn add_facet_to_object(
+        &self,
+        input: AddFacetToObjectRequest,
+    ) -> RusotoFuture<AddFacetToObjectResponse, AddFacetToObjectError> {
+        (**self).add_facet_to_object(input)
+    }
+    fn apply_schema(
+        &self,
+        input: ApplySchemaRequest,
+    ) -> RusotoFuture<ApplySchemaResponse, ApplySchemaError> {
+        (**self).apply_schema(input)
+    }
+    fn attach_object(
+        &self,
+        input: AttachObjectRequest,
+    ) -> RusotoFuture<AttachObjectResponse, AttachObjectError> {
+        (**self).attach_object(input)
+    }
+    fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> RusotoFuture<AttachPolicyResponse, AttachPolicyError> {
+        (**self).attach_policy(input)
+    }
+    fn attach_to_index(
+        &self,
+        input: AttachToIndexRequest,
+    ) -> RusotoFuture<AttachToIndexResponse, AttachToIndexError> {
+        (**self).attach_to_index(input)
+    }
+    fn attach_typed_link(
+        &self,
+        input: AttachTypedLinkRequest,
+    ) -> RusotoFuture<AttachTypedLinkResponse, AttachTypedLinkError> {
+        (**self).attach_typed_link(input)
+    }
+    fn batch_read(
+        &self,
+        input: BatchReadRequest,
+    ) -> RusotoFuture<BatchReadResponse, BatchReadError> {
+        (**self).batch_read(input)
+    }
+    fn batch_write(
+        &self,
+        input: BatchWriteRequest,
+    ) -> RusotoFuture<BatchWriteResponse, BatchWriteError> {
+        (**self).batch_write(input)
+    }
+    fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> RusotoFuture<CreateDirectoryResponse, CreateDirectoryError> {
+        (**self).create_directory(input)
+    }
+    fn create_facet(
+        &self,
+        input: CreateFacetRequest,
+    ) -> RusotoFuture<CreateFacetResponse, CreateFacetError> {
+        (**self).create_facet(input)
+    }
+    fn create_index(
+        &self,
+        input: CreateIndexRequest,
+    ) -> RusotoFuture<CreateIndexResponse, CreateIndexError> {
+        (**self).create_index(input)
+    }
+    fn create_object(
+        &self,
+        input: CreateObjectRequest,
+    ) -> RusotoFuture<CreateObjectResponse, CreateObjectError> {
+        (**self).create_object(input)
+    }
+    fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> RusotoFuture<CreateSchemaResponse, CreateSchemaError> {
+        (**self).create_schema(input)
+    }
+    fn create_typed_link_facet(
+        &self,
+        input: CreateTypedLinkFacetRequest,
+    ) -> RusotoFuture<CreateTypedLinkFacetResponse, CreateTypedLinkFacetError> {
+        (**self).create_typed_link_facet(input)
+    }
+    fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> RusotoFuture<DeleteDirectoryResponse, DeleteDirectoryError> {
+        (**self).delete_directory(input)
+    }
+    fn delete_facet(
+        &self,
+        input: DeleteFacetRequest,
+    ) -> RusotoFuture<DeleteFacetResponse, DeleteFacetError> {
+        (**self).delete_facet(input)
+    }
+    fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> RusotoFuture<DeleteObjectResponse, DeleteObjectError> {
+        (**self).delete_object(input)
+    }
+    fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> RusotoFuture<DeleteSchemaResponse, DeleteSchemaError> {
+        (**self).delete_schema(input)
+    }
+    fn delete_typed_link_facet(
+        &self,
+        input: DeleteTypedLinkFacetRequest,
+    ) -> RusotoFuture<DeleteTypedLinkFacetResponse, DeleteTypedLinkFacetError> {
+        (**self).delete_typed_link_facet(input)
+    }
+    fn detach_from_index(
+        &self,
+        input: DetachFromIndexRequest,
+    ) -> RusotoFuture<DetachFromIndexResponse, DetachFromIndexError> {
+        (**self).detach_from_index(input)
+    }
+    fn detach_object(
+        &self,
+        input: DetachObjectRequest,
+    ) -> RusotoFuture<DetachObjectResponse, DetachObjectError> {
+        (**self).detach_object(input)
+    }
+    fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> RusotoFuture<DetachPolicyResponse, DetachPolicyError> {
+        (**self).detach_policy(input)
+    }
+    fn detach_typed_link(
+        &self,
+        input: DetachTypedLinkRequest,
+    ) -> RusotoFuture<(), DetachTypedLinkError> {
+        (**self).detach_typed_link(input)
+    }
+    fn disable_directory(
+        &self,
+        input: DisableDirectoryRequest,
+    ) -> RusotoFuture<DisableDirectoryResponse, DisableDirectoryError> {
+        (**self).disable_directory(input)
+    }
+    fn enable_directory(
+        &self,
+        input: EnableDirectoryRequest,
+    ) -> RusotoFuture<EnableDirectoryResponse, EnableDirectoryError> {
+        (**self).enable_directory(input)
+    }
+    fn get_applied_schema_version(
+        &self,
+        input: GetAppliedSchemaVersionRequest,
+    ) -> RusotoFuture<GetAppliedSchemaVersionResponse, GetAppliedSchemaVersionError> {
+        (**self).get_applied_schema_version(input)
+    }
+    fn get_directory(
+        &self,
+        input: GetDirectoryRequest,
+    ) -> RusotoFuture<GetDirectoryResponse, GetDirectoryError> {
+        (**self).get_directory(input)
+    }
+    fn get_facet(&self, input: GetFacetRequest) -> RusotoFuture<GetFacetResponse, GetFacetError> {
+        (**self).get_facet(input)
+    }
+    fn get_link_attributes(
+        &self,
+        input: GetLinkAttributesRequest,
+    ) -> RusotoFuture<GetLinkAttributesResponse, GetLinkAttributesError> {
+        (**self).get_link_attributes(input)
+    }
+    fn get_object_attributes(
+        &self,
+        input: GetObjectAttributesRequest,
+    ) -> RusotoFuture<GetObjectAttributesResponse, GetObjectAttributesError> {
+        (**self).get_object_attributes(input)
+    }
+    fn get_object_information(
+        &self,
+        input: GetObjectInformationRequest,
+    ) -> RusotoFuture<GetObjectInformationResponse, GetObjectInformationError> {
+        (**self).get_object_information(input)
+    }
+    fn get_schema_as_json(
+        &self,
+        input: GetSchemaAsJsonRequest,
+    ) -> RusotoFuture<GetSchemaAsJsonResponse, GetSchemaAsJsonError> {
+        (**self).get_schema_as_json(input)
+    }
+    fn get_typed_link_facet_information(
+        &self,
+        input: GetTypedLinkFacetInformationRequest,
+    ) -> RusotoFuture<GetTypedLinkFacetInformationResponse, GetTypedLinkFacetInformationError> {
+        (**self).get_typed_link_facet_information(input)
+    }
+    fn list_applied_schema_arns(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> RusotoFuture<ListAppliedSchemaArnsResponse, ListAppliedSchemaArnsError> {
+        (**self).list_applied_schema_arns(input)
+    }
+    fn list_attached_indices(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> RusotoFuture<ListAttachedIndicesResponse, ListAttachedIndicesError> {
+        (**self).list_attached_indices(input)
+    }
+    fn list_development_schema_arns(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> RusotoFuture<ListDevelopmentSchemaArnsResponse, ListDevelopmentSchemaArnsError> {
+        (**self).list_development_schema_arns(input)
+    }
+    fn list_directories(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> RusotoFuture<ListDirectoriesResponse, ListDirectoriesError> {
+        (**self).list_directories(input)
+    }
+    fn list_facet_attributes(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> RusotoFuture<ListFacetAttributesResponse, ListFacetAttributesError> {
+        (**self).list_facet_attributes(input)
+    }
+    fn list_facet_names(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> RusotoFuture<ListFacetNamesResponse, ListFacetNamesError> {
+        (**self).list_facet_names(input)
+    }
+    fn list_incoming_typed_links(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> RusotoFuture<ListIncomingTypedLinksResponse, ListIncomingTypedLinksError> {
+        (**self).list_incoming_typed_links(input)
+    }
+    fn list_index(
+        &self,
+        input: ListIndexRequest,
+    ) -> RusotoFuture<ListIndexResponse, ListIndexError> {
+        (**self).list_index(input)
+    }
+    fn list_managed_schema_arns(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> RusotoFuture<ListManagedSchemaArnsResponse, ListManagedSchemaArnsError> {
+        (**self).list_managed_schema_arns(input)
+    }
+    fn list_object_attributes(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> RusotoFuture<ListObjectAttributesResponse, ListObjectAttributesError> {
+        (**self).list_object_attributes(input)
+    }
+    fn list_object_children(
+        &self,
+        input: ListObjectChildrenRequest,
+    ) -> RusotoFuture<ListObjectChildrenResponse, ListObjectChildrenError> {
+        (**self).list_object_children(input)
+    }
+    fn list_object_parent_paths(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> RusotoFuture<ListObjectParentPathsResponse, ListObjectParentPathsError> {
+        (**self).list_object_parent_paths(input)
+    }
+    fn list_object_parents(
+        &self,
+        input: ListObjectParentsRequest,
+    ) -> RusotoFuture<ListObjectParentsResponse, ListObjectParentsError> {
+        (**self).list_object_parents(input)
+    }
+    fn list_object_policies(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> RusotoFuture<ListObjectPoliciesResponse, ListObjectPoliciesError> {
+        (**self).list_object_policies(input)
+    }
+    fn list_outgoing_typed_links(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> RusotoFuture<ListOutgoingTypedLinksResponse, ListOutgoingTypedLinksError> {
+        (**self).list_outgoing_typed_links(input)
+    }
+    fn list_policy_attachments(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> RusotoFuture<ListPolicyAttachmentsResponse, ListPolicyAttachmentsError> {
+        (**self).list_policy_attachments(input)
+    }
+    fn list_published_schema_arns(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> RusotoFuture<ListPublishedSchemaArnsResponse, ListPublishedSchemaArnsError> {
+        (**self).list_published_schema_arns(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_typed_link_facet_attributes(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> RusotoFuture<ListTypedLinkFacetAttributesResponse, ListTypedLinkFacetAttributesError> {
+        (**self).list_typed_link_facet_attributes(input)
+    }
+    fn list_typed_link_facet_names(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> RusotoFuture<ListTypedLinkFacetNamesResponse, ListTypedLinkFacetNamesError> {
+        (**self).list_typed_link_facet_names(input)
+    }
+    fn lookup_policy(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> RusotoFuture<LookupPolicyResponse, LookupPolicyError> {
+        (**self).lookup_policy(input)
+    }
+    fn publish_schema(
+        &self,
+        input: PublishSchemaRequest,
+    ) -> RusotoFuture<PublishSchemaResponse, PublishSchemaError> {
+        (**self).publish_schema(input)
+    }
+    fn put_schema_from_json(
+        &self,
+        input: PutSchemaFromJsonRequest,
+    ) -> RusotoFuture<PutSchemaFromJsonResponse, PutSchemaFromJsonError> {
+        (**self).put_schema_from_json(input)
+    }
+    fn remove_facet_from_object(
+        &self,
+        input: RemoveFacetFromObjectRequest,
+    ) -> RusotoFuture<RemoveFacetFromObjectResponse, RemoveFacetFromObjectError> {
+        (**self).remove_facet_from_object(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_facet(
+        &self,
+        input: UpdateFacetRequest,
+    ) -> RusotoFuture<UpdateFacetResponse, UpdateFacetError> {
+        (**self).update_facet(input)
+    }
+    fn update_link_attributes(
+        &self,
+        input: UpdateLinkAttributesRequest,
+    ) -> RusotoFuture<UpdateLinkAttributesResponse, UpdateLinkAttributesError> {
+        (**self).update_link_attributes(input)
+    }
+    fn update_object_attributes(
+        &self,
+        input: UpdateObjectAttributesRequest,
+    ) -> RusotoFuture<UpdateObjectAttributesResponse, UpdateObjectAttributesError> {
+        (**self).update_object_attributes(input)
+    }
+    fn update_schema(
+        &self,
+        input: UpdateSchemaRequest,
+    ) -> RusotoFuture<UpdateSchemaResponse, UpdateSchemaError> {
+        (**self).update_schema(input)
+    }
+    fn update_typed_link_facet(
+        &self,
+        input: UpdateTypedLinkFacetRequest,
+    ) -> RusotoFuture<UpdateTypedLinkFacetResponse, UpdateTypedLinkFacetError> {
+        (**self).update_typed_link_facet(input)
+    }
+    fn upgrade_applied_schema(
+        &self,
+        input: UpgradeAppliedSchemaRequest,
+    ) -> RusotoFuture<UpgradeAppliedSchemaResponse, UpgradeAppliedSchemaError> {
+        (**self).upgrade_applied_schema(input)
+    }
+    fn upgrade_published_schema(
+        &self,
+        input: UpgradePublishedSchemaRequest,
+    ) -> RusotoFuture<UpgradePublishedSchemaResponse, UpgradePublishedSchemaError> {
+        (**self).upgrade_published_schema(input)
+    }
+}
+/// The error codes Amazon CloudDirectory retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon CloudDirectory API.
 #[derive(Clone)]
 pub struct CloudDirectoryClient {
@@ -8701,6 +13358,27 @@ impl CloudDirectoryClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CLOUDDIRECTORY`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CloudDirectoryClient {
+        Self::new(region::Region::from_env_for_service("CLOUDDIRECTORY"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `clouddirectory`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CloudDirectoryClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("clouddirectory", "CLOUDDIRECTORY"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -8719,7 +13397,54 @@ impl CloudDirectoryClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudDirectoryClient {
-        CloudDirectoryClient { client, region }
+        CloudDirectoryClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "clouddirectory")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "clouddirectory",
+            "clouddirectory",
+            "CLOUDDIRECTORY",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CloudDirectory` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CloudDirectory + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CloudDirectoryClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon CloudDirectory"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "clouddirectory"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CloudDirectoryClient {
+        Self::new_with_client(client, region)
     }
 }
 
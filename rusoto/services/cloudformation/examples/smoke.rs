@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS CloudFormation clients.
+//!
+//! Makes a single read-only `ListExports` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_cloudformation::{CloudFormation, CloudFormationClient, ListExportsInput};
+
+fn main() {
+    let client = CloudFormationClient::from_env();
+    match client.list_exports(ListExportsInput::default()).sync() {
+        Ok(_) => println!("ListExports succeeded"),
+        Err(error) => {
+            eprintln!("ListExports failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 extern crate rusoto_mock;
 
-use crate::generated::{CloudFormation, CloudFormationClient, ListStacksInput};
+use crate::generated::{CloudFormation, CloudFormationClient, ListStacksInput, StackStatus};
 
 use self::rusoto_mock::*;
 use rusoto_core::param::Params;
@@ -65,10 +65,7 @@ fn should_serialize_list_parameters_in_request_body() {
             }
         });
 
-    let filters = vec![
-        "CREATE_IN_PROGRESS".to_owned(),
-        "DELETE_COMPLETE".to_owned(),
-    ];
+    let filters = vec![StackStatus::CreateInProgress, StackStatus::DeleteComplete];
     let request = ListStacksInput {
         stack_status_filter: Some(filters),
         ..Default::default()
@@ -0,0 +1,757 @@
+//! Deploying stacks with change sets, like `aws cloudformation deploy`.
+//!
+//! `deploy_stack` creates a change set of the right type, `CREATE` for a stack which doesn't
+//! exist yet and `UPDATE` otherwise, waits until CloudFormation has worked out the changes,
+//! executes it and polls the stack until the deployment is over. Progress is reported as a
+//! stream of `DeployEvent`s, including the events of the stack as they happen.
+//!
+//! When the deployment fails and the stack rolls back, the stream fails with
+//! `DeployError::Failed`, listing why each resource failed.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use futures::Stream;
+//! use rusoto_cloudformation::deploy::{DeployConfig, DeployEvent, DeployExt};
+//! use rusoto_cloudformation::{Capability, CloudFormationClient, CreateChangeSetInput};
+//! use rusoto_core::{Region, RusotoFuture};
+//!
+//! let client = CloudFormationClient::new(Region::UsEast1);
+//! let input = CreateChangeSetInput {
+//!     stack_name: "web".to_owned(),
+//!     template_body: Some(std::fs::read_to_string("web.yaml").unwrap()),
+//!     capabilities: Some(vec![Capability::CapabilityIam]),
+//!     ..Default::default()
+//! };
+//! let deployment = client
+//!     .deploy_stack(input, DeployConfig::default())
+//!     .for_each(|event| {
+//!         match event {
+//!             DeployEvent::StackEvent(event) => println!(
+//!                 "{:?} {:?} {:?}",
+//!                 event.logical_resource_id, event.resource_status, event.resource_status_reason
+//!             ),
+//!             DeployEvent::NoChanges => println!("No changes to deploy"),
+//!             DeployEvent::Deployed(stack) => println!("Deployed {}", stack.stack_name),
+//!             _ => {}
+//!         }
+//!         Ok(())
+//!     });
+//! RusotoFuture::from_future(deployment).sync().unwrap();
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::{future, stream, Future, Stream};
+use rusoto_core::waiter::{poll_until, sleep, WaiterConfig};
+use rusoto_core::RusotoError;
+
+use crate::generated::{
+    Change, ChangeSetStatus, ChangeSetType, CloudFormation, CreateChangeSetError,
+    CreateChangeSetInput, DeleteChangeSetInput, DescribeChangeSetError, DescribeChangeSetInput,
+    DescribeChangeSetOutput, DescribeStackEventsError, DescribeStackEventsInput,
+    DescribeStacksError, DescribeStacksInput, ExecuteChangeSetError, ExecuteChangeSetInput,
+    ResourceStatus, Stack, StackEvent, StackStatus,
+};
+
+/// The events of a deployment, ending with `NoChanges` or `Deployed` unless it fails.
+pub type DeployStream =
+    Box<dyn Stream<Item = DeployEvent, Error = RusotoError<DeployError>> + Send>;
+
+type DeployFuture<T> = Box<dyn Future<Item = T, Error = RusotoError<DeployError>> + Send>;
+
+/// How to deploy a stack.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeployConfig {
+    /// How often, and for how long, to poll the change set and then the stack
+    pub waiter: WaiterConfig,
+    /// Whether to execute the change set. When false, the stream ends once it is created.
+    pub execute: bool,
+    /// Whether to fail with `DeployError::EmptyChangeSet` when the stack is already up to date
+    pub fail_on_empty_change_set: bool,
+}
+
+impl Default for DeployConfig {
+    /// Polls every 5 seconds for up to an hour, and executes the change set.
+    fn default() -> DeployConfig {
+        DeployConfig {
+            waiter: WaiterConfig::new(Duration::from_secs(5), 720),
+            execute: true,
+            fail_on_empty_change_set: false,
+        }
+    }
+}
+
+/// The progress of a deployment.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DeployEvent {
+    /// The change set was created.
+    ChangeSetCreated {
+        /// The ARN of the change set
+        change_set_id: String,
+        /// Whether the change set creates the stack or updates it
+        change_set_type: ChangeSetType,
+        /// The changes executing the change set makes
+        changes: Vec<Change>,
+    },
+    /// An event of the stack, in the order they happened.
+    StackEvent(StackEvent),
+    /// The stack is already up to date. The empty change set was deleted.
+    NoChanges,
+    /// The stack was created or updated.
+    Deployed(Stack),
+}
+
+/// What made a resource fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FailureKind {
+    /// A resource with the same name already exists.
+    AlreadyExists,
+    /// CloudFormation, or the role it used, isn't allowed to manage the resource.
+    AccessDenied,
+    /// A quota of the account has been reached.
+    LimitExceeded,
+    /// A property of the resource is missing or invalid.
+    InvalidProperty,
+    /// The operation was cancelled because another resource failed.
+    Cancelled,
+    /// Any other reason.
+    Other,
+}
+
+/// A resource which failed during a deployment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceFailure {
+    /// The logical ID of the resource in the template
+    pub logical_resource_id: Option<String>,
+    /// The type of the resource, e.g. `AWS::S3::Bucket`
+    pub resource_type: Option<String>,
+    /// The status of the resource, e.g. `CREATE_FAILED`
+    pub resource_status: ResourceStatus,
+    /// The reason CloudFormation gave
+    pub reason: String,
+    /// What made the resource fail, worked out from the reason
+    pub kind: FailureKind,
+}
+
+impl ResourceFailure {
+    /// The failure reported by `event`, if its resource failed.
+    pub fn from_event(event: &StackEvent) -> Option<ResourceFailure> {
+        let status = event.resource_status.as_ref()?;
+        if !status.as_str().ends_with("_FAILED") {
+            return None;
+        }
+        let reason = event.resource_status_reason.clone().unwrap_or_default();
+        Some(ResourceFailure {
+            logical_resource_id: event.logical_resource_id.clone(),
+            resource_type: event.resource_type.clone(),
+            resource_status: status.clone(),
+            kind: classify_failure(&reason),
+            reason,
+        })
+    }
+}
+
+impl fmt::Display for ResourceFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: {}",
+            self.logical_resource_id
+                .as_ref()
+                .map_or("?", String::as_str),
+            self.resource_status,
+            self.reason
+        )
+    }
+}
+
+/// Works out what made a resource fail from the reason CloudFormation gave.
+pub fn classify_failure(reason: &str) -> FailureKind {
+    let lowercase = reason.to_lowercase();
+    let mentions = |needle: &str| lowercase.contains(needle);
+    if mentions("cancelled") {
+        FailureKind::Cancelled
+    } else if mentions("already exists") {
+        FailureKind::AlreadyExists
+    } else if mentions("not authorized") || mentions("accessdenied") || mentions("access denied") {
+        FailureKind::AccessDenied
+    } else if mentions("limitexceeded") || mentions("limit exceeded") || mentions("maximum number")
+    {
+        FailureKind::LimitExceeded
+    } else if mentions("property validation failure")
+        || mentions("unsupported property")
+        || mentions("invalid")
+        || mentions("required property")
+    {
+        FailureKind::InvalidProperty
+    } else {
+        FailureKind::Other
+    }
+}
+
+/// Errors returned while deploying a stack.
+#[derive(Debug, PartialEq)]
+pub enum DeployError {
+    /// Describing the stack failed.
+    DescribeStacks(DescribeStacksError),
+    /// Creating the change set failed.
+    CreateChangeSet(CreateChangeSetError),
+    /// Describing the change set failed.
+    DescribeChangeSet(DescribeChangeSetError),
+    /// Executing the change set failed.
+    ExecuteChangeSet(ExecuteChangeSetError),
+    /// Describing the events of the stack failed.
+    DescribeStackEvents(DescribeStackEventsError),
+    /// The stack doesn't exist any more.
+    StackNotFound(String),
+    /// CloudFormation couldn't create the change set, e.g. because the template is invalid.
+    ChangeSetFailed(String),
+    /// The stack is already up to date, and `fail_on_empty_change_set` is set.
+    EmptyChangeSet(String),
+    /// The deployment failed.
+    Failed {
+        /// The status the stack ended in, e.g. `UPDATE_ROLLBACK_COMPLETE`
+        stack_status: StackStatus,
+        /// The resources which failed, in the order they did
+        failures: Vec<ResourceFailure>,
+    },
+    /// The deployment didn't finish within the configured number of attempts.
+    TimedOut(String),
+}
+
+impl fmt::Display for DeployError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DeployError::Failed {
+                ref stack_status,
+                ref failures,
+            } => {
+                write!(f, "Deployment failed: the stack is {}", stack_status)?;
+                // the first failure which wasn't cancelled is usually the cause of the others
+                let cause = failures
+                    .iter()
+                    .find(|failure| failure.kind != FailureKind::Cancelled)
+                    .or_else(|| failures.first());
+                if let Some(cause) = cause {
+                    write!(f, ", e.g. {}", cause)?;
+                }
+                Ok(())
+            }
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for DeployError {
+    fn description(&self) -> &str {
+        match *self {
+            DeployError::DescribeStacks(ref cause) => cause.description(),
+            DeployError::CreateChangeSet(ref cause) => cause.description(),
+            DeployError::DescribeChangeSet(ref cause) => cause.description(),
+            DeployError::ExecuteChangeSet(ref cause) => cause.description(),
+            DeployError::DescribeStackEvents(ref cause) => cause.description(),
+            DeployError::StackNotFound(ref cause) => cause,
+            DeployError::ChangeSetFailed(ref cause) => cause,
+            DeployError::EmptyChangeSet(ref cause) => cause,
+            DeployError::Failed { .. } => "Deployment failed",
+            DeployError::TimedOut(ref cause) => cause,
+        }
+    }
+}
+
+/// Whether a stack in `status` was deployed (`Some(true)`), failed to (`Some(false)`), or is
+/// still being deployed (`None`).
+pub fn stack_outcome(status: &str) -> Option<bool> {
+    match status {
+        "CREATE_COMPLETE" | "UPDATE_COMPLETE" | "IMPORT_COMPLETE" => Some(true),
+        _ if status.ends_with("_IN_PROGRESS") => None,
+        _ => Some(false),
+    }
+}
+
+/// Extension methods which deploy stacks.
+pub trait DeployExt: CloudFormation + Clone + Send + Sized + 'static {
+    /// Creates and executes a change set deploying `input`, and streams the events of the stack
+    /// until it is deployed.
+    ///
+    /// `change_set_type` is worked out from whether the stack exists, and the change set is named
+    /// `rusoto-deploy-<seconds since the Unix epoch>` if `change_set_name` is empty. Must be run
+    /// on a Tokio runtime.
+    fn deploy_stack(&self, input: CreateChangeSetInput, config: DeployConfig) -> DeployStream {
+        let client = self.clone();
+        Box::new(
+            change_set_type(self, input.stack_name.clone())
+                .and_then(move |change_set_type| deploy(client, input, change_set_type, config))
+                .flatten_stream(),
+        )
+    }
+}
+
+impl<T: CloudFormation + Clone + Send + Sized + 'static> DeployExt for T {}
+
+fn change_set_type<C: CloudFormation>(
+    client: &C,
+    stack_name: String,
+) -> DeployFuture<ChangeSetType> {
+    Box::new(
+        client
+            .describe_stacks(DescribeStacksInput {
+                stack_name: Some(stack_name),
+                ..Default::default()
+            })
+            .then(|result| match result {
+                // a stack whose first change set wasn't executed is only under review
+                Ok(output) => match output.stacks.unwrap_or_default().first() {
+                    Some(stack) if stack.stack_status != StackStatus::ReviewInProgress => {
+                        Ok(ChangeSetType::Update)
+                    }
+                    _ => Ok(ChangeSetType::Create),
+                },
+                Err(RusotoError::Unknown(ref response))
+                    if response.status.as_u16() == 400
+                        && response.body_as_str().contains("does not exist") =>
+                {
+                    Ok(ChangeSetType::Create)
+                }
+                Err(e) => Err(e.map_service(DeployError::DescribeStacks)),
+            }),
+    )
+}
+
+fn deploy<C: CloudFormation + Clone + Send + 'static>(
+    client: C,
+    mut input: CreateChangeSetInput,
+    change_set_type: ChangeSetType,
+    config: DeployConfig,
+) -> DeployFuture<DeployStream> {
+    if input.change_set_name.is_empty() {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_secs())
+            .unwrap_or(0);
+        input.change_set_name = format!("rusoto-deploy-{}", seconds);
+    }
+    input.change_set_type = Some(change_set_type.clone());
+    let stack_name = input.stack_name.clone();
+    let change_set_name = input.change_set_name.clone();
+    let DeployConfig {
+        waiter,
+        execute,
+        fail_on_empty_change_set,
+    } = config;
+
+    let future = client
+        .create_change_set(input)
+        .map_err(|e| e.map_service(DeployError::CreateChangeSet))
+        .and_then(move |output| {
+            let change_set_id = output.id.unwrap_or(change_set_name);
+            wait_for_change_set(&client, &stack_name, &change_set_id, waiter.clone()).and_then(
+                move |output| -> DeployFuture<DeployStream> {
+                    if is_empty_change_set(&output) {
+                        let reason = output.status_reason.unwrap_or_default();
+                        return skip_empty_change_set(
+                            &client,
+                            &stack_name,
+                            &change_set_id,
+                            reason,
+                            fail_on_empty_change_set,
+                        );
+                    }
+                    Box::new(
+                        change_set_changes(&client, &stack_name, &change_set_id, output).and_then(
+                            move |changes| -> DeployFuture<DeployStream> {
+                                let created = stream::once(Ok(DeployEvent::ChangeSetCreated {
+                                    change_set_id: change_set_id.clone(),
+                                    change_set_type,
+                                    changes,
+                                }));
+                                if !execute {
+                                    return Box::new(future::ok(Box::new(created) as DeployStream));
+                                }
+                                Box::new(
+                                    execute_change_set(client, stack_name, change_set_id, waiter)
+                                        .map(|watch| {
+                                            Box::new(created.chain(watch)) as DeployStream
+                                        }),
+                                )
+                            },
+                        ),
+                    )
+                },
+            )
+        });
+    Box::new(future)
+}
+
+/// Whether a change set failed only because it doesn't change anything.
+fn is_empty_change_set(output: &DescribeChangeSetOutput) -> bool {
+    output.status == Some(ChangeSetStatus::Failed)
+        && output.status_reason.as_ref().map_or(false, |reason| {
+            reason.contains("didn't contain changes")
+                || reason.contains("No updates are to be performed")
+        })
+}
+
+/// Polls `DescribeChangeSet` until the change set is created, or turns out to be empty.
+fn wait_for_change_set<C: CloudFormation + Clone + Send + 'static>(
+    client: &C,
+    stack_name: &str,
+    change_set_id: &str,
+    waiter: WaiterConfig,
+) -> DeployFuture<DescribeChangeSetOutput> {
+    let timeout = RusotoError::Service(DeployError::TimedOut(format!(
+        "Change set {} was not created",
+        change_set_id
+    )));
+    let client = client.clone();
+    let stack_name = stack_name.to_owned();
+    let change_set_id = change_set_id.to_owned();
+    poll_until(
+        waiter,
+        move || {
+            client
+                .describe_change_set(DescribeChangeSetInput {
+                    change_set_name: change_set_id.clone(),
+                    stack_name: Some(stack_name.clone()),
+                    next_token: None,
+                })
+                .map_err(|e| e.map_service(DeployError::DescribeChangeSet))
+                .and_then(|output| match output.status {
+                    Some(ChangeSetStatus::CreateComplete) => Ok(Some(output)),
+                    Some(ChangeSetStatus::Failed) if is_empty_change_set(&output) => {
+                        Ok(Some(output))
+                    }
+                    Some(ChangeSetStatus::Failed) => Err(RusotoError::Service(
+                        DeployError::ChangeSetFailed(output.status_reason.unwrap_or_default()),
+                    )),
+                    _ => Ok(None),
+                })
+        },
+        timeout,
+    )
+}
+
+/// The changes of a created change set, reading the pages after `output`.
+fn change_set_changes<C: CloudFormation + Clone + Send + 'static>(
+    client: &C,
+    stack_name: &str,
+    change_set_id: &str,
+    output: DescribeChangeSetOutput,
+) -> DeployFuture<Vec<Change>> {
+    let client = client.clone();
+    let stack_name = stack_name.to_owned();
+    let change_set_id = change_set_id.to_owned();
+    let first_page = output.changes.unwrap_or_default();
+    let next_pages = stream::unfold(output.next_token, move |token| {
+        let token = token?;
+        Some(
+            client
+                .describe_change_set(DescribeChangeSetInput {
+                    change_set_name: change_set_id.clone(),
+                    stack_name: Some(stack_name.clone()),
+                    next_token: Some(token),
+                })
+                .map_err(|e| e.map_service(DeployError::DescribeChangeSet))
+                .map(|output| (output.changes.unwrap_or_default(), output.next_token)),
+        )
+    });
+    Box::new(next_pages.concat2().map(move |next_pages| {
+        let mut changes = first_page;
+        changes.extend(next_pages);
+        changes
+    }))
+}
+
+/// Deletes an empty change set, which would otherwise count towards the change sets of the
+/// stack, and reports that there's nothing to deploy.
+fn skip_empty_change_set<C: CloudFormation>(
+    client: &C,
+    stack_name: &str,
+    change_set_id: &str,
+    reason: String,
+    fail_on_empty_change_set: bool,
+) -> DeployFuture<DeployStream> {
+    let deleted = client
+        .delete_change_set(DeleteChangeSetInput {
+            change_set_name: change_set_id.to_owned(),
+            stack_name: Some(stack_name.to_owned()),
+        })
+        .then(|_| Ok(()));
+    Box::new(deleted.and_then(move |_| {
+        if fail_on_empty_change_set {
+            Err(RusotoError::Service(DeployError::EmptyChangeSet(reason)))
+        } else {
+            Ok(Box::new(stream::once(Ok(DeployEvent::NoChanges))) as DeployStream)
+        }
+    }))
+}
+
+/// Executes the change set, then watches the stack from its latest event on.
+fn execute_change_set<C: CloudFormation + Clone + Send + 'static>(
+    client: C,
+    stack_name: String,
+    change_set_id: String,
+    waiter: WaiterConfig,
+) -> DeployFuture<DeployStream> {
+    Box::new(
+        stack_events_since(&client, &stack_name, None).and_then(move |(_, last_event_id)| {
+            client
+                .execute_change_set(ExecuteChangeSetInput {
+                    change_set_name: change_set_id,
+                    stack_name: Some(stack_name.clone()),
+                    ..Default::default()
+                })
+                .map_err(|e| e.map_service(DeployError::ExecuteChangeSet))
+                .map(move |_| watch_stack(client, stack_name, last_event_id, waiter))
+        }),
+    )
+}
+
+/// The events of the stack after `last_event_id`, oldest first, and the ID of its latest event.
+///
+/// Only the latest page of events is read, which holds the last 100 events.
+fn stack_events_since<C: CloudFormation>(
+    client: &C,
+    stack_name: &str,
+    last_event_id: Option<String>,
+) -> impl Future<Item = (Vec<StackEvent>, Option<String>), Error = RusotoError<DeployError>> {
+    client
+        .describe_stack_events(DescribeStackEventsInput {
+            stack_name: Some(stack_name.to_owned()),
+            next_token: None,
+        })
+        .map_err(|e| e.map_service(DeployError::DescribeStackEvents))
+        .map(move |output| {
+            let events = output.stack_events.unwrap_or_default();
+            let latest = events
+                .first()
+                .map(|event| event.event_id.clone())
+                .or_else(|| last_event_id.clone());
+            (
+                new_events(events, last_event_id.as_ref().map(String::as_str)),
+                latest,
+            )
+        })
+}
+
+/// The events, newest first as `DescribeStackEvents` lists them, after `last_event_id`, oldest
+/// first.
+fn new_events(events: Vec<StackEvent>, last_event_id: Option<&str>) -> Vec<StackEvent> {
+    let mut new = events
+        .into_iter()
+        .take_while(|event| Some(event.event_id.as_str()) != last_event_id)
+        .collect::<Vec<_>>();
+    new.reverse();
+    new
+}
+
+fn describe_stack<C: CloudFormation>(
+    client: &C,
+    stack_name: String,
+) -> impl Future<Item = Stack, Error = RusotoError<DeployError>> {
+    client
+        .describe_stacks(DescribeStacksInput {
+            stack_name: Some(stack_name.clone()),
+            ..Default::default()
+        })
+        .map_err(|e| e.map_service(DeployError::DescribeStacks))
+        .and_then(move |output| {
+            output
+                .stacks
+                .unwrap_or_default()
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    RusotoError::Service(DeployError::StackNotFound(format!(
+                        "Stack {} does not exist",
+                        stack_name
+                    )))
+                })
+        })
+}
+
+type WatchFuture = DeployFuture<(Vec<DeployEvent>, WatchState)>;
+
+enum WatchState {
+    Polling {
+        last_event_id: Option<String>,
+        attempts: usize,
+        failures: Vec<ResourceFailure>,
+    },
+    Failed(DeployError),
+    Done,
+}
+
+/// Polls the stack until it is deployed, streaming its new events on every attempt.
+fn watch_stack<C: CloudFormation + Clone + Send + 'static>(
+    client: C,
+    stack_name: String,
+    last_event_id: Option<String>,
+    waiter: WaiterConfig,
+) -> DeployStream {
+    let initial = WatchState::Polling {
+        last_event_id,
+        attempts: 0,
+        failures: Vec::new(),
+    };
+    let batches = stream::unfold(initial, move |state| -> Option<WatchFuture> {
+        let (last_event_id, attempts, mut failures) = match state {
+            WatchState::Polling {
+                last_event_id,
+                attempts,
+                failures,
+            } => (last_event_id, attempts, failures),
+            // the events which led to the failure were streamed first
+            WatchState::Failed(error) => {
+                return Some(Box::new(future::err(RusotoError::Service(error))));
+            }
+            WatchState::Done => return None,
+        };
+        if attempts >= waiter.max_attempts {
+            return Some(Box::new(future::err(RusotoError::Service(
+                DeployError::TimedOut(format!("Stack {} was not deployed", stack_name)),
+            ))));
+        }
+        let delay: DeployFuture<()> = if attempts == 0 {
+            Box::new(future::ok(()))
+        } else {
+            Box::new(sleep(waiter.delay))
+        };
+        let client = client.clone();
+        let stack_name = stack_name.clone();
+        Some(Box::new(delay.and_then(move |_| {
+            stack_events_since(&client, &stack_name, last_event_id).and_then(
+                move |(events, last_event_id)| {
+                    describe_stack(&client, stack_name).map(move |stack| {
+                        failures.extend(events.iter().filter_map(ResourceFailure::from_event));
+                        let mut batch = events
+                            .into_iter()
+                            .map(DeployEvent::StackEvent)
+                            .collect::<Vec<_>>();
+                        let next = match stack_outcome(stack.stack_status.as_str()) {
+                            None => WatchState::Polling {
+                                last_event_id,
+                                attempts: attempts + 1,
+                                failures,
+                            },
+                            Some(true) => {
+                                batch.push(DeployEvent::Deployed(stack));
+                                WatchState::Done
+                            }
+                            Some(false) => WatchState::Failed(DeployError::Failed {
+                                stack_status: stack.stack_status,
+                                failures,
+                            }),
+                        };
+                        (batch, next)
+                    })
+                },
+            )
+        })))
+    });
+    Box::new(batches.map(stream::iter_ok).flatten())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(id: &str, status: &str, reason: &str) -> StackEvent {
+        StackEvent {
+            event_id: id.to_owned(),
+            logical_resource_id: Some("Bucket".to_owned()),
+            resource_status: Some(status.into()),
+            resource_status_reason: Some(reason.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn new_events_are_oldest_first() {
+        let events = vec![
+            event("3", "CREATE_COMPLETE", ""),
+            event("2", "CREATE_IN_PROGRESS", ""),
+            event("1", "REVIEW_IN_PROGRESS", ""),
+        ];
+        let ids = |events: Vec<StackEvent>| {
+            events
+                .into_iter()
+                .map(|event| event.event_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(new_events(events.clone(), Some("1"))), vec!["2", "3"]);
+        assert_eq!(
+            ids(new_events(events.clone(), Some("3"))),
+            Vec::<String>::new()
+        );
+        assert_eq!(ids(new_events(events, None)), vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn failures_are_classified() {
+        let failure = |reason| {
+            ResourceFailure::from_event(&event("1", "CREATE_FAILED", reason))
+                .unwrap()
+                .kind
+        };
+        assert_eq!(
+            failure("my-bucket already exists in stack arn:aws:cloudformation:..."),
+            FailureKind::AlreadyExists
+        );
+        assert_eq!(
+            failure("User: arn:aws:iam::123:user/ci is not authorized to perform: s3:CreateBucket"),
+            FailureKind::AccessDenied
+        );
+        assert_eq!(
+            failure("Resource creation cancelled"),
+            FailureKind::Cancelled
+        );
+        assert_eq!(
+            failure("Property validation failure: [Encountered unsupported properties]"),
+            FailureKind::InvalidProperty
+        );
+        assert_eq!(failure("Internal Failure"), FailureKind::Other);
+        assert_eq!(
+            ResourceFailure::from_event(&event("2", "CREATE_COMPLETE", "")),
+            None
+        );
+    }
+
+    #[test]
+    fn stack_outcomes() {
+        assert_eq!(stack_outcome("UPDATE_COMPLETE"), Some(true));
+        assert_eq!(stack_outcome("UPDATE_COMPLETE_CLEANUP_IN_PROGRESS"), None);
+        assert_eq!(stack_outcome("REVIEW_IN_PROGRESS"), None);
+        assert_eq!(stack_outcome("UPDATE_ROLLBACK_COMPLETE"), Some(false));
+        assert_eq!(stack_outcome("ROLLBACK_COMPLETE"), Some(false));
+    }
+
+    #[test]
+    fn failed_deployments_name_their_cause() {
+        let error = DeployError::Failed {
+            stack_status: StackStatus::RollbackComplete,
+            failures: vec![
+                ResourceFailure::from_event(&event(
+                    "1",
+                    "CREATE_FAILED",
+                    "Resource creation cancelled",
+                ))
+                .unwrap(),
+                ResourceFailure::from_event(&event(
+                    "2",
+                    "CREATE_FAILED",
+                    "my-bucket already exists",
+                ))
+                .unwrap(),
+            ],
+        };
+        assert_eq!(
+            error.to_string(),
+            "Deployment failed: the stack is ROLLBACK_COMPLETE, \
+             e.g. Bucket CREATE_FAILED: my-bucket already exists"
+        );
+    }
+}
@@ -1,2 +1,5 @@
+/// Deploying stacks with change sets, streaming their events
+pub mod deploy;
+
 #[cfg(test)]
 mod custom_tests;
@@ -28,15 +28,19 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
 use xml::EventReader;
 
-struct AccountDeserializer;
+pub(crate) struct AccountDeserializer;
 impl AccountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -48,15 +52,15 @@ impl AccountDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AccountGateResult {
     /// <p><p>The status of the account gate function.</p> <ul> <li> <p> <code>SUCCEEDED</code>: The account gate function has determined that the account and region passes any requirements for a stack set operation to occur. AWS CloudFormation proceeds with the stack operation in that account and region. </p> </li> <li> <p> <code>FAILED</code>: The account gate function has determined that the account and region does not meet the requirements for a stack set operation to occur. AWS CloudFormation cancels the stack set operation in that account and region, and sets the stack set operation result status for that account and region to <code>FAILED</code>. </p> </li> <li> <p> <code>SKIPPED</code>: AWS CloudFormation has skipped calling the account gate function for this account and region, for one of the following reasons:</p> <ul> <li> <p>An account gate function has not been specified for the account and region. AWS CloudFormation proceeds with the stack set operation in this account and region.</p> </li> <li> <p>The <code>AWSCloudFormationStackSetExecutionRole</code> of the stack set adminstration account lacks permissions to invoke the function. AWS CloudFormation proceeds with the stack set operation in this account and region.</p> </li> <li> <p>Either no action is necessary, or no action is possible, on the stack. AWS CloudFormation skips the stack set operation in this account and region.</p> </li> </ul> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<AccountGateStatus>,
     /// <p>The reason for the account gate status assigned to this account and region for the stack set operation.</p>
     pub status_reason: Option<String>,
 }
 
-struct AccountGateResultDeserializer;
+pub(crate) struct AccountGateResultDeserializer;
 impl AccountGateResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccountGateResult, XmlParseError> {
@@ -77,21 +81,90 @@ impl AccountGateResultDeserializer {
         })
     }
 }
-struct AccountGateStatusDeserializer;
+pub(crate) struct AccountGateStatusDeserializer;
 impl AccountGateStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AccountGateStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct AccountGateStatusReasonDeserializer;
+/// <p><p>The status of the account gate function.</p> <ul> <li> <p> <code>SUCCEEDED</code>: The account gate function has determined that the account and region passes any requirements for a stack set operation to occur. AWS CloudFormation proceeds with the stack operation in that account and region. </p> </li> <li> <p> <code>FAILED</code>: The account gate function has determined that the account and region does not meet the requirements for a stack set operation to occur. AWS CloudFormation cancels the stack set operation in that account and region, and sets the stack set operation result status for that account and region to <code>FAILED</code>. </p> </li> <li> <p> <code>SKIPPED</code>: AWS CloudFormation has skipped calling the account gate function for this account and region, for one of the following reasons:</p> <ul> <li> <p>An account gate function has not been specified for the account and region. AWS CloudFormation proceeds with the stack set operation in this account and region.</p> </li> <li> <p>The <code>AWSCloudFormationStackSetExecutionRole</code> of the stack set adminstration account lacks permissions to invoke the function. AWS CloudFormation proceeds with the stack set operation in this account and region.</p> </li> <li> <p>Either no action is necessary, or no action is possible, on the stack. AWS CloudFormation skips the stack set operation in this account and region.</p> </li> </ul> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccountGateStatus {
+    /// <p><code>SUCCEEDED</code> - The account gate function has determined that the account and region passes any requirements for a stack set operation to occur. AWS CloudFormation proceeds with the stack operation in that account and region.</p>
+    Succeeded,
+    /// <p><code>FAILED</code> - The account gate function has determined that the account and region does not meet the requirements for a stack set operation to occur. AWS CloudFormation cancels the stack set operation in that account and region, and sets the stack set operation result status for that account and region to <code>FAILED</code>.</p>
+    Failed,
+    /// <p><code>SKIPPED</code> - AWS CloudFormation has skipped calling the account gate function for this account and region, for one of the following reasons:</p>
+    Skipped,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AccountGateStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountGateStatus::Succeeded => "SUCCEEDED",
+            AccountGateStatus::Failed => "FAILED",
+            AccountGateStatus::Skipped => "SKIPPED",
+            AccountGateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AccountGateStatus {
+    fn from(value: &'a str) -> AccountGateStatus {
+        match value {
+            "SUCCEEDED" => AccountGateStatus::Succeeded,
+            "FAILED" => AccountGateStatus::Failed,
+            "SKIPPED" => AccountGateStatus::Skipped,
+            _ => AccountGateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountGateStatus {
+    fn from(value: String) -> AccountGateStatus {
+        AccountGateStatus::from(value.as_str())
+    }
+}
+
+impl From<AccountGateStatus> for String {
+    fn from(value: AccountGateStatus) -> String {
+        match value {
+            AccountGateStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AccountGateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AccountGateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct AccountGateStatusReasonDeserializer;
 impl AccountGateStatusReasonDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -108,10 +181,10 @@ pub struct AccountLimit {
     pub value: Option<i64>,
 }
 
-struct AccountLimitDeserializer;
+pub(crate) struct AccountLimitDeserializer;
 impl AccountLimitDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccountLimit, XmlParseError> {
@@ -129,10 +202,10 @@ impl AccountLimitDeserializer {
         })
     }
 }
-struct AccountLimitListDeserializer;
+pub(crate) struct AccountLimitListDeserializer;
 impl AccountLimitListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AccountLimit>, XmlParseError> {
@@ -158,10 +231,13 @@ impl AccountListSerializer {
     }
 }
 
-struct AllowedValueDeserializer;
+pub(crate) struct AllowedValueDeserializer;
 impl AllowedValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -169,10 +245,10 @@ impl AllowedValueDeserializer {
         Ok(obj)
     }
 }
-struct AllowedValuesDeserializer;
+pub(crate) struct AllowedValuesDeserializer;
 impl AllowedValuesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -186,10 +262,13 @@ impl AllowedValuesDeserializer {
         })
     }
 }
-struct ArnDeserializer;
+pub(crate) struct ArnDeserializer;
 impl ArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -197,10 +276,13 @@ impl ArnDeserializer {
         Ok(obj)
     }
 }
-struct BoxedIntegerDeserializer;
+pub(crate) struct BoxedIntegerDeserializer;
 impl BoxedIntegerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -217,6 +299,44 @@ pub struct CancelUpdateStackInput {
     pub stack_name: String,
 }
 
+impl CancelUpdateStackInput {
+    /// Returns a builder for `CancelUpdateStackInput`, checking its required members when built.
+    pub fn builder() -> CancelUpdateStackInputBuilder {
+        CancelUpdateStackInputBuilder::default()
+    }
+}
+
+/// Builds a [`CancelUpdateStackInput`](struct.CancelUpdateStackInput.html).
+#[derive(Default, Debug)]
+pub struct CancelUpdateStackInputBuilder {
+    client_request_token: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl CancelUpdateStackInputBuilder {
+    /// Sets the [`client_request_token`](struct.CancelUpdateStackInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.CancelUpdateStackInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelUpdateStackInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelUpdateStackInput, ::rusoto_core::BuildError> {
+        Ok(CancelUpdateStackInput {
+            client_request_token: self.client_request_token,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 /// Serialize `CancelUpdateStackInput` contents to a `SignedRequest`.
 struct CancelUpdateStackInputSerializer;
 impl CancelUpdateStackInputSerializer {
@@ -233,13 +353,13 @@ impl CancelUpdateStackInputSerializer {
     }
 }
 
-struct CapabilitiesDeserializer;
+pub(crate) struct CapabilitiesDeserializer;
 impl CapabilitiesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<Capability>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "member" {
                 obj.push(CapabilityDeserializer::deserialize("member", stack)?);
@@ -254,7 +374,7 @@ impl CapabilitiesDeserializer {
 /// Serialize `Capabilities` contents to a `SignedRequest`.
 struct CapabilitiesSerializer;
 impl CapabilitiesSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<Capability>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -262,10 +382,13 @@ impl CapabilitiesSerializer {
     }
 }
 
-struct CapabilitiesReasonDeserializer;
+pub(crate) struct CapabilitiesReasonDeserializer;
 impl CapabilitiesReasonDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -273,21 +396,90 @@ impl CapabilitiesReasonDeserializer {
         Ok(obj)
     }
 }
-struct CapabilityDeserializer;
+pub(crate) struct CapabilityDeserializer;
 impl CapabilityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Capability, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct CausingEntityDeserializer;
+/// <p><p>In some cases, you must explicity acknowledge that your stack template contains certain capabilities in order for AWS CloudFormation to create the stack.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stacks, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some template contain macros. Macros perform custom processing on templates; this can include simple actions like find-and-replace operations, all the way to extensive transformations of entire templates. Because of this, users typically create a change set from the processed template, so that they can review the changes resulting from the macros before actually creating the stack. If your stack template contains one or more macros, and you choose to create a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.</p> <note> <p>This capacity does not apply to creating change sets, and specifying it when creating change sets has no effect.</p> <p>Also, change sets do not currently support nested stacks. If you want to create a stack from a stack template that contains macros <i>and</i> nested stacks, you must create or update the stack directly from the template using the <a>CreateStack</a> or <a>UpdateStack</a> action, and specifying this capability.</p> </note> <p>For more information on macros, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Capability {
+    /// <p><code>CAPABILITY_IAM</code></p>
+    CapabilityIam,
+    /// <p><code>CAPABILITY_NAMED_IAM</code></p>
+    CapabilityNamedIam,
+    /// <p><code>CAPABILITY_AUTO_EXPAND</code></p>
+    CapabilityAutoExpand,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Capability {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Capability::CapabilityIam => "CAPABILITY_IAM",
+            Capability::CapabilityNamedIam => "CAPABILITY_NAMED_IAM",
+            Capability::CapabilityAutoExpand => "CAPABILITY_AUTO_EXPAND",
+            Capability::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Capability {
+    fn from(value: &'a str) -> Capability {
+        match value {
+            "CAPABILITY_IAM" => Capability::CapabilityIam,
+            "CAPABILITY_NAMED_IAM" => Capability::CapabilityNamedIam,
+            "CAPABILITY_AUTO_EXPAND" => Capability::CapabilityAutoExpand,
+            _ => Capability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Capability {
+    fn from(value: String) -> Capability {
+        Capability::from(value.as_str())
+    }
+}
+
+impl From<Capability> for String {
+    fn from(value: Capability) -> String {
+        match value {
+            Capability::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Capability {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct CausingEntityDeserializer;
 impl CausingEntityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -301,13 +493,16 @@ pub struct Change {
     /// <p>A <code>ResourceChange</code> structure that describes the resource and action that AWS CloudFormation will perform.</p>
     pub resource_change: Option<ResourceChange>,
     /// <p>The type of entity that AWS CloudFormation changes. Currently, the only entity type is <code>Resource</code>.</p>
-    pub type_: Option<String>,
+    pub type_: Option<ChangeType>,
 }
 
-struct ChangeDeserializer;
+pub(crate) struct ChangeDeserializer;
 impl ChangeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Change, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Change, XmlParseError> {
         deserialize_elements::<_, Change, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "ResourceChange" => {
@@ -325,21 +520,98 @@ impl ChangeDeserializer {
         })
     }
 }
-struct ChangeActionDeserializer;
+pub(crate) struct ChangeActionDeserializer;
 impl ChangeActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ChangeAction, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ChangeSetIdDeserializer;
+/// <p>The action that AWS CloudFormation takes on the resource, such as <code>Add</code> (adds a new resource), <code>Modify</code> (changes a resource), or <code>Remove</code> (deletes a resource).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeAction {
+    /// <p><code>Add</code></p>
+    Add,
+    /// <p><code>Modify</code></p>
+    Modify,
+    /// <p><code>Remove</code></p>
+    Remove,
+    /// <p><code>Import</code></p>
+    Import,
+    /// <p><code>Dynamic</code></p>
+    Dynamic,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeAction::Add => "Add",
+            ChangeAction::Modify => "Modify",
+            ChangeAction::Remove => "Remove",
+            ChangeAction::Import => "Import",
+            ChangeAction::Dynamic => "Dynamic",
+            ChangeAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeAction {
+    fn from(value: &'a str) -> ChangeAction {
+        match value {
+            "Add" => ChangeAction::Add,
+            "Modify" => ChangeAction::Modify,
+            "Remove" => ChangeAction::Remove,
+            "Import" => ChangeAction::Import,
+            "Dynamic" => ChangeAction::Dynamic,
+            _ => ChangeAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeAction {
+    fn from(value: String) -> ChangeAction {
+        ChangeAction::from(value.as_str())
+    }
+}
+
+impl From<ChangeAction> for String {
+    fn from(value: ChangeAction) -> String {
+        match value {
+            ChangeAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ChangeSetIdDeserializer;
 impl ChangeSetIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -347,10 +619,13 @@ impl ChangeSetIdDeserializer {
         Ok(obj)
     }
 }
-struct ChangeSetNameDeserializer;
+pub(crate) struct ChangeSetNameDeserializer;
 impl ChangeSetNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -358,21 +633,110 @@ impl ChangeSetNameDeserializer {
         Ok(obj)
     }
 }
-struct ChangeSetStatusDeserializer;
+pub(crate) struct ChangeSetStatusDeserializer;
 impl ChangeSetStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ChangeSetStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ChangeSetStatusReasonDeserializer;
+/// <p>The state of the change set, such as <code>CREATE_IN_PROGRESS</code>, <code>CREATE_COMPLETE</code>, or <code>FAILED</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeSetStatus {
+    /// <p><code>CREATE_PENDING</code></p>
+    CreatePending,
+    /// <p><code>CREATE_IN_PROGRESS</code></p>
+    CreateInProgress,
+    /// <p><code>CREATE_COMPLETE</code></p>
+    CreateComplete,
+    /// <p><code>DELETE_PENDING</code></p>
+    DeletePending,
+    /// <p><code>DELETE_IN_PROGRESS</code></p>
+    DeleteInProgress,
+    /// <p><code>DELETE_COMPLETE</code></p>
+    DeleteComplete,
+    /// <p><code>DELETE_FAILED</code></p>
+    DeleteFailed,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeSetStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeSetStatus::CreatePending => "CREATE_PENDING",
+            ChangeSetStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            ChangeSetStatus::CreateComplete => "CREATE_COMPLETE",
+            ChangeSetStatus::DeletePending => "DELETE_PENDING",
+            ChangeSetStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            ChangeSetStatus::DeleteComplete => "DELETE_COMPLETE",
+            ChangeSetStatus::DeleteFailed => "DELETE_FAILED",
+            ChangeSetStatus::Failed => "FAILED",
+            ChangeSetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeSetStatus {
+    fn from(value: &'a str) -> ChangeSetStatus {
+        match value {
+            "CREATE_PENDING" => ChangeSetStatus::CreatePending,
+            "CREATE_IN_PROGRESS" => ChangeSetStatus::CreateInProgress,
+            "CREATE_COMPLETE" => ChangeSetStatus::CreateComplete,
+            "DELETE_PENDING" => ChangeSetStatus::DeletePending,
+            "DELETE_IN_PROGRESS" => ChangeSetStatus::DeleteInProgress,
+            "DELETE_COMPLETE" => ChangeSetStatus::DeleteComplete,
+            "DELETE_FAILED" => ChangeSetStatus::DeleteFailed,
+            "FAILED" => ChangeSetStatus::Failed,
+            _ => ChangeSetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeSetStatus {
+    fn from(value: String) -> ChangeSetStatus {
+        ChangeSetStatus::from(value.as_str())
+    }
+}
+
+impl From<ChangeSetStatus> for String {
+    fn from(value: ChangeSetStatus) -> String {
+        match value {
+            ChangeSetStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeSetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeSetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ChangeSetStatusReasonDeserializer;
 impl ChangeSetStatusReasonDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -380,10 +744,10 @@ impl ChangeSetStatusReasonDeserializer {
         Ok(obj)
     }
 }
-struct ChangeSetSummariesDeserializer;
+pub(crate) struct ChangeSetSummariesDeserializer;
 impl ChangeSetSummariesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ChangeSetSummary>, XmlParseError> {
@@ -405,25 +769,25 @@ pub struct ChangeSetSummary {
     /// <p>The name of the change set.</p>
     pub change_set_name: Option<String>,
     /// <p>The start time when the change set was created, in UTC.</p>
-    pub creation_time: Option<String>,
+    pub creation_time: Option<IsoTimestamp>,
     /// <p>Descriptive information about the change set.</p>
     pub description: Option<String>,
     /// <p>If the change set execution status is <code>AVAILABLE</code>, you can execute the change set. If you can’t execute the change set, the status indicates why. For example, a change set might be in an <code>UNAVAILABLE</code> state because AWS CloudFormation is still creating it or in an <code>OBSOLETE</code> state because the stack was already updated.</p>
-    pub execution_status: Option<String>,
+    pub execution_status: Option<ExecutionStatus>,
     /// <p>The ID of the stack with which the change set is associated.</p>
     pub stack_id: Option<String>,
     /// <p>The name of the stack with which the change set is associated.</p>
     pub stack_name: Option<String>,
     /// <p>The state of the change set, such as <code>CREATE_IN_PROGRESS</code>, <code>CREATE_COMPLETE</code>, or <code>FAILED</code>.</p>
-    pub status: Option<String>,
+    pub status: Option<ChangeSetStatus>,
     /// <p>A description of the change set's status. For example, if your change set is in the <code>FAILED</code> state, AWS CloudFormation shows the error message.</p>
     pub status_reason: Option<String>,
 }
 
-struct ChangeSetSummaryDeserializer;
+pub(crate) struct ChangeSetSummaryDeserializer;
 impl ChangeSetSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ChangeSetSummary, XmlParseError> {
@@ -476,32 +840,227 @@ impl ChangeSetSummaryDeserializer {
         })
     }
 }
-struct ChangeSourceDeserializer;
+/// <p>The type of change set operation. To create a change set for a new stack, specify <code>CREATE</code>. To create a change set for an existing stack, specify <code>UPDATE</code>.</p> <p>If you create a change set for a new stack, AWS Cloudformation creates a stack with a unique stack ID, but no template or resources. The stack will be in the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-describing-stacks.html#d0e11995"> <code>REVIEW_IN_PROGRESS</code> </a> state until you execute the change set.</p> <p>By default, AWS CloudFormation specifies <code>UPDATE</code>. You can't use the <code>UPDATE</code> type to create a change set for a new stack or the <code>CREATE</code> type to create a change set for an existing stack.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeSetType {
+    /// <p><code>CREATE</code></p>
+    Create,
+    /// <p><code>UPDATE</code></p>
+    Update,
+    /// <p><code>IMPORT</code></p>
+    Import,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeSetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeSetType::Create => "CREATE",
+            ChangeSetType::Update => "UPDATE",
+            ChangeSetType::Import => "IMPORT",
+            ChangeSetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeSetType {
+    fn from(value: &'a str) -> ChangeSetType {
+        match value {
+            "CREATE" => ChangeSetType::Create,
+            "UPDATE" => ChangeSetType::Update,
+            "IMPORT" => ChangeSetType::Import,
+            _ => ChangeSetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeSetType {
+    fn from(value: String) -> ChangeSetType {
+        ChangeSetType::from(value.as_str())
+    }
+}
+
+impl From<ChangeSetType> for String {
+    fn from(value: ChangeSetType) -> String {
+        match value {
+            ChangeSetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeSetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeSetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ChangeSourceDeserializer;
 impl ChangeSourceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ChangeSource, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ChangeTypeDeserializer;
+/// <p><p>The group to which the <code>CausingEntity</code> value belongs. There are five entity groups:</p> <ul> <li> <p> <code>ResourceReference</code> entities are <code>Ref</code> intrinsic functions that refer to resources in the template, such as <code>{ &quot;Ref&quot; : &quot;MyEC2InstanceResource&quot; }</code>.</p> </li> <li> <p> <code>ParameterReference</code> entities are <code>Ref</code> intrinsic functions that get template parameter values, such as <code>{ &quot;Ref&quot; : &quot;MyPasswordParameter&quot; }</code>.</p> </li> <li> <p> <code>ResourceAttribute</code> entities are <code>Fn::GetAtt</code> intrinsic functions that get resource attribute values, such as <code>{ &quot;Fn::GetAtt&quot; : [ &quot;MyEC2InstanceResource&quot;, &quot;PublicDnsName&quot; ] }</code>.</p> </li> <li> <p> <code>DirectModification</code> entities are changes that are made directly to the template.</p> </li> <li> <p> <code>Automatic</code> entities are <code>AWS::CloudFormation::Stack</code> resource types, which are also known as nested stacks. If you made no changes to the <code>AWS::CloudFormation::Stack</code> resource, AWS CloudFormation sets the <code>ChangeSource</code> to <code>Automatic</code> because the nested stack&#39;s template might have changed. Changes to a nested stack&#39;s template aren&#39;t visible to AWS CloudFormation until you run an update on the parent stack.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeSource {
+    /// <p><code>ResourceReference</code></p>
+    ResourceReference,
+    /// <p><code>ParameterReference</code></p>
+    ParameterReference,
+    /// <p><code>ResourceAttribute</code></p>
+    ResourceAttribute,
+    /// <p><code>DirectModification</code></p>
+    DirectModification,
+    /// <p><code>Automatic</code></p>
+    Automatic,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeSource {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeSource::ResourceReference => "ResourceReference",
+            ChangeSource::ParameterReference => "ParameterReference",
+            ChangeSource::ResourceAttribute => "ResourceAttribute",
+            ChangeSource::DirectModification => "DirectModification",
+            ChangeSource::Automatic => "Automatic",
+            ChangeSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeSource {
+    fn from(value: &'a str) -> ChangeSource {
+        match value {
+            "ResourceReference" => ChangeSource::ResourceReference,
+            "ParameterReference" => ChangeSource::ParameterReference,
+            "ResourceAttribute" => ChangeSource::ResourceAttribute,
+            "DirectModification" => ChangeSource::DirectModification,
+            "Automatic" => ChangeSource::Automatic,
+            _ => ChangeSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeSource {
+    fn from(value: String) -> ChangeSource {
+        ChangeSource::from(value.as_str())
+    }
+}
+
+impl From<ChangeSource> for String {
+    fn from(value: ChangeSource) -> String {
+        match value {
+            ChangeSource::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeSource {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ChangeTypeDeserializer;
 impl ChangeTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ChangeType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ChangesDeserializer;
+/// <p>The type of entity that AWS CloudFormation changes. Currently, the only entity type is <code>Resource</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeType {
+    /// <p><code>Resource</code></p>
+    Resource,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeType::Resource => "Resource",
+            ChangeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeType {
+    fn from(value: &'a str) -> ChangeType {
+        match value {
+            "Resource" => ChangeType::Resource,
+            _ => ChangeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeType {
+    fn from(value: String) -> ChangeType {
+        ChangeType::from(value.as_str())
+    }
+}
+
+impl From<ChangeType> for String {
+    fn from(value: ChangeType) -> String {
+        match value {
+            ChangeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ChangesDeserializer;
 impl ChangesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Change>, XmlParseError> {
@@ -515,10 +1074,13 @@ impl ChangesDeserializer {
         })
     }
 }
-struct ClientRequestTokenDeserializer;
+pub(crate) struct ClientRequestTokenDeserializer;
 impl ClientRequestTokenDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -539,6 +1101,62 @@ pub struct ContinueUpdateRollbackInput {
     pub stack_name: String,
 }
 
+impl ContinueUpdateRollbackInput {
+    /// Returns a builder for `ContinueUpdateRollbackInput`, checking its required members when built.
+    pub fn builder() -> ContinueUpdateRollbackInputBuilder {
+        ContinueUpdateRollbackInputBuilder::default()
+    }
+}
+
+/// Builds a [`ContinueUpdateRollbackInput`](struct.ContinueUpdateRollbackInput.html).
+#[derive(Default, Debug)]
+pub struct ContinueUpdateRollbackInputBuilder {
+    client_request_token: Option<String>,
+    resources_to_skip: Option<Vec<String>>,
+    role_arn: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl ContinueUpdateRollbackInputBuilder {
+    /// Sets the [`client_request_token`](struct.ContinueUpdateRollbackInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resources_to_skip`](struct.ContinueUpdateRollbackInput.html#structfield.resources_to_skip) member.
+    pub fn resources_to_skip<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resources_to_skip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.ContinueUpdateRollbackInput.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.ContinueUpdateRollbackInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ContinueUpdateRollbackInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ContinueUpdateRollbackInput, ::rusoto_core::BuildError> {
+        Ok(ContinueUpdateRollbackInput {
+            client_request_token: self.client_request_token,
+            resources_to_skip: self.resources_to_skip,
+            role_arn: self.role_arn,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 /// Serialize `ContinueUpdateRollbackInput` contents to a `SignedRequest`.
 struct ContinueUpdateRollbackInputSerializer;
 impl ContinueUpdateRollbackInputSerializer {
@@ -569,10 +1187,10 @@ impl ContinueUpdateRollbackInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ContinueUpdateRollbackOutput {}
 
-struct ContinueUpdateRollbackOutputDeserializer;
+pub(crate) struct ContinueUpdateRollbackOutputDeserializer;
 impl ContinueUpdateRollbackOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ContinueUpdateRollbackOutput, XmlParseError> {
@@ -589,11 +1207,11 @@ impl ContinueUpdateRollbackOutputDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateChangeSetInput {
     /// <p><p>In some cases, you must explicity acknowledge that your stack template contains certain capabilities in order for AWS CloudFormation to create the stack.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stacks, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some template contain macros. Macros perform custom processing on templates; this can include simple actions like find-and-replace operations, all the way to extensive transformations of entire templates. Because of this, users typically create a change set from the processed template, so that they can review the changes resulting from the macros before actually creating the stack. If your stack template contains one or more macros, and you choose to create a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.</p> <note> <p>This capacity does not apply to creating change sets, and specifying it when creating change sets has no effect.</p> <p>Also, change sets do not currently support nested stacks. If you want to create a stack from a stack template that contains macros <i>and</i> nested stacks, you must create or update the stack directly from the template using the <a>CreateStack</a> or <a>UpdateStack</a> action, and specifying this capability.</p> </note> <p>For more information on macros, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> </li> </ul></p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>The name of the change set. The name must be unique among all change sets that are associated with the specified stack.</p> <p>A change set name can contain only alphanumeric, case sensitive characters and hyphens. It must start with an alphabetic character and cannot exceed 128 characters.</p>
     pub change_set_name: String,
     /// <p>The type of change set operation. To create a change set for a new stack, specify <code>CREATE</code>. To create a change set for an existing stack, specify <code>UPDATE</code>.</p> <p>If you create a change set for a new stack, AWS Cloudformation creates a stack with a unique stack ID, but no template or resources. The stack will be in the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-describing-stacks.html#d0e11995"> <code>REVIEW_IN_PROGRESS</code> </a> state until you execute the change set.</p> <p>By default, AWS CloudFormation specifies <code>UPDATE</code>. You can't use the <code>UPDATE</code> type to create a change set for a new stack or the <code>CREATE</code> type to create a change set for an existing stack.</p>
-    pub change_set_type: Option<String>,
+    pub change_set_type: Option<ChangeSetType>,
     /// <p>A unique identifier for this <code>CreateChangeSet</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to create another change set with the same name. You might retry <code>CreateChangeSet</code> requests to ensure that AWS CloudFormation successfully received them.</p>
     pub client_token: Option<String>,
     /// <p>A description to help you identify this change set.</p>
@@ -620,18 +1238,162 @@ pub struct CreateChangeSetInput {
     pub use_previous_template: Option<bool>,
 }
 
-/// Serialize `CreateChangeSetInput` contents to a `SignedRequest`.
-struct CreateChangeSetInputSerializer;
-impl CreateChangeSetInputSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &CreateChangeSetInput) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl CreateChangeSetInput {
+    /// Returns a builder for `CreateChangeSetInput`, checking its required members when built.
+    pub fn builder() -> CreateChangeSetInputBuilder {
+        CreateChangeSetInputBuilder::default()
+    }
+}
 
-        if let Some(ref field_value) = obj.capabilities {
-            CapabilitiesSerializer::serialize(
-                params,
+/// Builds a [`CreateChangeSetInput`](struct.CreateChangeSetInput.html).
+#[derive(Default, Debug)]
+pub struct CreateChangeSetInputBuilder {
+    capabilities: Option<Vec<Capability>>,
+    change_set_name: Option<String>,
+    change_set_type: Option<ChangeSetType>,
+    client_token: Option<String>,
+    description: Option<String>,
+    notification_ar_ns: Option<Vec<String>>,
+    parameters: Option<Vec<Parameter>>,
+    resource_types: Option<Vec<String>>,
+    role_arn: Option<String>,
+    rollback_configuration: Option<RollbackConfiguration>,
+    stack_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    template_body: Option<String>,
+    template_url: Option<String>,
+    use_previous_template: Option<bool>,
+}
+
+impl CreateChangeSetInputBuilder {
+    /// Sets the [`capabilities`](struct.CreateChangeSetInput.html#structfield.capabilities) member.
+    pub fn capabilities<T: Into<Vec<Capability>>>(mut self, value: T) -> Self {
+        self.capabilities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`change_set_name`](struct.CreateChangeSetInput.html#structfield.change_set_name) member.
+    pub fn change_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`change_set_type`](struct.CreateChangeSetInput.html#structfield.change_set_type) member.
+    pub fn change_set_type<T: Into<ChangeSetType>>(mut self, value: T) -> Self {
+        self.change_set_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_token`](struct.CreateChangeSetInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateChangeSetInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_ar_ns`](struct.CreateChangeSetInput.html#structfield.notification_ar_ns) member.
+    pub fn notification_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.notification_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.CreateChangeSetInput.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_types`](struct.CreateChangeSetInput.html#structfield.resource_types) member.
+    pub fn resource_types<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateChangeSetInput.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rollback_configuration`](struct.CreateChangeSetInput.html#structfield.rollback_configuration) member.
+    pub fn rollback_configuration<T: Into<RollbackConfiguration>>(mut self, value: T) -> Self {
+        self.rollback_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.CreateChangeSetInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateChangeSetInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_body`](struct.CreateChangeSetInput.html#structfield.template_body) member.
+    pub fn template_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_url`](struct.CreateChangeSetInput.html#structfield.template_url) member.
+    pub fn template_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_previous_template`](struct.CreateChangeSetInput.html#structfield.use_previous_template) member.
+    pub fn use_previous_template<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_previous_template = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateChangeSetInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateChangeSetInput, ::rusoto_core::BuildError> {
+        Ok(CreateChangeSetInput {
+            capabilities: self.capabilities,
+            change_set_name: self
+                .change_set_name
+                .ok_or(::rusoto_core::BuildError::missing("change_set_name"))?,
+            change_set_type: self.change_set_type,
+            client_token: self.client_token,
+            description: self.description,
+            notification_ar_ns: self.notification_ar_ns,
+            parameters: self.parameters,
+            resource_types: self.resource_types,
+            role_arn: self.role_arn,
+            rollback_configuration: self.rollback_configuration,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+            tags: self.tags,
+            template_body: self.template_body,
+            template_url: self.template_url,
+            use_previous_template: self.use_previous_template,
+        })
+    }
+}
+
+/// Serialize `CreateChangeSetInput` contents to a `SignedRequest`.
+struct CreateChangeSetInputSerializer;
+impl CreateChangeSetInputSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &CreateChangeSetInput) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.capabilities {
+            CapabilitiesSerializer::serialize(
+                params,
                 &format!("{}{}", prefix, "Capabilities"),
                 field_value,
             );
@@ -708,10 +1470,10 @@ pub struct CreateChangeSetOutput {
     pub stack_id: Option<String>,
 }
 
-struct CreateChangeSetOutputDeserializer;
+pub(crate) struct CreateChangeSetOutputDeserializer;
 impl CreateChangeSetOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateChangeSetOutput, XmlParseError> {
@@ -733,7 +1495,7 @@ impl CreateChangeSetOutputDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateStackInput {
     /// <p><p>In some cases, you must explicity acknowledge that your stack template contains certain capabilities in order for AWS CloudFormation to create the stack.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stacks, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some template contain macros. Macros perform custom processing on templates; this can include simple actions like find-and-replace operations, all the way to extensive transformations of entire templates. Because of this, users typically create a change set from the processed template, so that they can review the changes resulting from the macros before actually creating the stack. If your stack template contains one or more macros, and you choose to create a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.</p> <p>Change sets do not currently support nested stacks. If you want to create a stack from a stack template that contains macros <i>and</i> nested stacks, you must create the stack directly from the template using this capability.</p> <important> <p>You should only create stacks directly from a stack template that contains macros if you know what processing the macro performs.</p> <p>Each macro relies on an underlying Lambda service function for processing stack templates. Be aware that the Lambda function owner can update the function operation without AWS CloudFormation being notified.</p> </important> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> </li> </ul></p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>A unique identifier for this <code>CreateStack</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to create a stack with the same name. You might retry <code>CreateStack</code> requests to ensure that AWS CloudFormation successfully received them.</p> <p>All events triggered by a given stack operation are assigned the same client request token, which you can use to track operations. For example, if you execute a <code>CreateStack</code> operation with the token <code>token1</code>, then all the <code>StackEvents</code> generated by that operation will have <code>ClientRequestToken</code> set as <code>token1</code>.</p> <p>In the console, stack operations display the client request token on the Events tab. Stack operations that are initiated from the console use the token format <i>Console-StackOperation-ID</i>, which helps you easily identify the stack operation . For example, if you create a stack using the console, each stack event would be assigned the same token in the following format: <code>Console-CreateStack-7f59c3cf-00d2-40c7-b2ff-e75db0987002</code>. </p>
     pub client_request_token: Option<String>,
     /// <p>Set to <code>true</code> to disable rollback of the stack if stack creation failed. You can specify either <code>DisableRollback</code> or <code>OnFailure</code>, but not both.</p> <p>Default: <code>false</code> </p>
@@ -743,7 +1505,7 @@ pub struct CreateStackInput {
     /// <p>The Simple Notification Service (SNS) topic ARNs to publish stack related events. You can find your SNS topic ARNs using the SNS console or your Command Line Interface (CLI).</p>
     pub notification_ar_ns: Option<Vec<String>>,
     /// <p>Determines what action will be taken if stack creation fails. This must be one of: DO_NOTHING, ROLLBACK, or DELETE. You can specify either <code>OnFailure</code> or <code>DisableRollback</code>, but not both.</p> <p>Default: <code>ROLLBACK</code> </p>
-    pub on_failure: Option<String>,
+    pub on_failure: Option<OnFailure>,
     /// <p>A list of <code>Parameter</code> structures that specify input parameters for the stack. For more information, see the <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/APIReference/API_Parameter.html">Parameter</a> data type.</p>
     pub parameters: Option<Vec<Parameter>>,
     /// <p>The template resource types that you have permissions to work with for this create stack action, such as <code>AWS::EC2::Instance</code>, <code>AWS::EC2::*</code>, or <code>Custom::MyCustomInstance</code>. Use the following syntax to describe template resource types: <code>AWS::*</code> (for all AWS resource), <code>Custom::*</code> (for all custom resources), <code>Custom::<i>logical_ID</i> </code> (for a specific custom resource), <code>AWS::<i>service_name</i>::*</code> (for all resources of a particular AWS service), and <code>AWS::<i>service_name</i>::<i>resource_logical_ID</i> </code> (for a specific AWS resource).</p> <p>If the list of resource types doesn't include a resource that you're creating, the stack creation fails. By default, AWS CloudFormation grants permissions to all resource types. AWS Identity and Access Management (IAM) uses this parameter for AWS CloudFormation-specific condition keys in IAM policies. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html">Controlling Access with AWS Identity and Access Management</a>.</p>
@@ -768,6 +1530,164 @@ pub struct CreateStackInput {
     pub timeout_in_minutes: Option<i64>,
 }
 
+impl CreateStackInput {
+    /// Returns a builder for `CreateStackInput`, checking its required members when built.
+    pub fn builder() -> CreateStackInputBuilder {
+        CreateStackInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStackInput`](struct.CreateStackInput.html).
+#[derive(Default, Debug)]
+pub struct CreateStackInputBuilder {
+    capabilities: Option<Vec<Capability>>,
+    client_request_token: Option<String>,
+    disable_rollback: Option<bool>,
+    enable_termination_protection: Option<bool>,
+    notification_ar_ns: Option<Vec<String>>,
+    on_failure: Option<OnFailure>,
+    parameters: Option<Vec<Parameter>>,
+    resource_types: Option<Vec<String>>,
+    role_arn: Option<String>,
+    rollback_configuration: Option<RollbackConfiguration>,
+    stack_name: Option<String>,
+    stack_policy_body: Option<String>,
+    stack_policy_url: Option<String>,
+    tags: Option<Vec<Tag>>,
+    template_body: Option<String>,
+    template_url: Option<String>,
+    timeout_in_minutes: Option<i64>,
+}
+
+impl CreateStackInputBuilder {
+    /// Sets the [`capabilities`](struct.CreateStackInput.html#structfield.capabilities) member.
+    pub fn capabilities<T: Into<Vec<Capability>>>(mut self, value: T) -> Self {
+        self.capabilities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_request_token`](struct.CreateStackInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disable_rollback`](struct.CreateStackInput.html#structfield.disable_rollback) member.
+    pub fn disable_rollback<T: Into<bool>>(mut self, value: T) -> Self {
+        self.disable_rollback = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_termination_protection`](struct.CreateStackInput.html#structfield.enable_termination_protection) member.
+    pub fn enable_termination_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_termination_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_ar_ns`](struct.CreateStackInput.html#structfield.notification_ar_ns) member.
+    pub fn notification_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.notification_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`on_failure`](struct.CreateStackInput.html#structfield.on_failure) member.
+    pub fn on_failure<T: Into<OnFailure>>(mut self, value: T) -> Self {
+        self.on_failure = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.CreateStackInput.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_types`](struct.CreateStackInput.html#structfield.resource_types) member.
+    pub fn resource_types<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateStackInput.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rollback_configuration`](struct.CreateStackInput.html#structfield.rollback_configuration) member.
+    pub fn rollback_configuration<T: Into<RollbackConfiguration>>(mut self, value: T) -> Self {
+        self.rollback_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.CreateStackInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_policy_body`](struct.CreateStackInput.html#structfield.stack_policy_body) member.
+    pub fn stack_policy_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_policy_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_policy_url`](struct.CreateStackInput.html#structfield.stack_policy_url) member.
+    pub fn stack_policy_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_policy_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateStackInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_body`](struct.CreateStackInput.html#structfield.template_body) member.
+    pub fn template_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_url`](struct.CreateStackInput.html#structfield.template_url) member.
+    pub fn template_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout_in_minutes`](struct.CreateStackInput.html#structfield.timeout_in_minutes) member.
+    pub fn timeout_in_minutes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout_in_minutes = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStackInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateStackInput, ::rusoto_core::BuildError> {
+        Ok(CreateStackInput {
+            capabilities: self.capabilities,
+            client_request_token: self.client_request_token,
+            disable_rollback: self.disable_rollback,
+            enable_termination_protection: self.enable_termination_protection,
+            notification_ar_ns: self.notification_ar_ns,
+            on_failure: self.on_failure,
+            parameters: self.parameters,
+            resource_types: self.resource_types,
+            role_arn: self.role_arn,
+            rollback_configuration: self.rollback_configuration,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+            stack_policy_body: self.stack_policy_body,
+            stack_policy_url: self.stack_policy_url,
+            tags: self.tags,
+            template_body: self.template_body,
+            template_url: self.template_url,
+            timeout_in_minutes: self.timeout_in_minutes,
+        })
+    }
+}
+
 /// Serialize `CreateStackInput` contents to a `SignedRequest`.
 struct CreateStackInputSerializer;
 impl CreateStackInputSerializer {
@@ -868,6 +1788,85 @@ pub struct CreateStackInstancesInput {
     pub stack_set_name: String,
 }
 
+impl CreateStackInstancesInput {
+    /// Returns a builder for `CreateStackInstancesInput`, checking its required members when built.
+    pub fn builder() -> CreateStackInstancesInputBuilder {
+        CreateStackInstancesInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStackInstancesInput`](struct.CreateStackInstancesInput.html).
+#[derive(Default, Debug)]
+pub struct CreateStackInstancesInputBuilder {
+    accounts: Option<Vec<String>>,
+    operation_id: Option<String>,
+    operation_preferences: Option<StackSetOperationPreferences>,
+    parameter_overrides: Option<Vec<Parameter>>,
+    regions: Option<Vec<String>>,
+    stack_set_name: Option<String>,
+}
+
+impl CreateStackInstancesInputBuilder {
+    /// Sets the [`accounts`](struct.CreateStackInstancesInput.html#structfield.accounts) member.
+    pub fn accounts<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.accounts = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation_id`](struct.CreateStackInstancesInput.html#structfield.operation_id) member.
+    pub fn operation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation_preferences`](struct.CreateStackInstancesInput.html#structfield.operation_preferences) member.
+    pub fn operation_preferences<T: Into<StackSetOperationPreferences>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.operation_preferences = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_overrides`](struct.CreateStackInstancesInput.html#structfield.parameter_overrides) member.
+    pub fn parameter_overrides<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameter_overrides = Some(value.into());
+        self
+    }
+
+    /// Sets the [`regions`](struct.CreateStackInstancesInput.html#structfield.regions) member.
+    pub fn regions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.regions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_set_name`](struct.CreateStackInstancesInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStackInstancesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateStackInstancesInput, ::rusoto_core::BuildError> {
+        Ok(CreateStackInstancesInput {
+            accounts: self
+                .accounts
+                .ok_or(::rusoto_core::BuildError::missing("accounts"))?,
+            operation_id: self.operation_id,
+            operation_preferences: self.operation_preferences,
+            parameter_overrides: self.parameter_overrides,
+            regions: self
+                .regions
+                .ok_or(::rusoto_core::BuildError::missing("regions"))?,
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateStackInstancesInput` contents to a `SignedRequest`.
 struct CreateStackInstancesInputSerializer;
 impl CreateStackInstancesInputSerializer {
@@ -882,9 +1881,11 @@ impl CreateStackInstancesInputSerializer {
             &format!("{}{}", prefix, "Accounts"),
             &obj.accounts,
         );
-        if let Some(ref field_value) = obj.operation_id {
-            params.put(&format!("{}{}", prefix, "OperationId"), &field_value);
-        }
+        let field_value = obj
+            .operation_id
+            .clone()
+            .unwrap_or_else(rusoto_core::idempotency::generate_token);
+        params.put(&format!("{}{}", prefix, "OperationId"), &field_value);
         if let Some(ref field_value) = obj.operation_preferences {
             StackSetOperationPreferencesSerializer::serialize(
                 params,
@@ -913,10 +1914,10 @@ pub struct CreateStackInstancesOutput {
     pub operation_id: Option<String>,
 }
 
-struct CreateStackInstancesOutputDeserializer;
+pub(crate) struct CreateStackInstancesOutputDeserializer;
 impl CreateStackInstancesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateStackInstancesOutput, XmlParseError> {
@@ -945,10 +1946,10 @@ pub struct CreateStackOutput {
     pub stack_id: Option<String>,
 }
 
-struct CreateStackOutputDeserializer;
+pub(crate) struct CreateStackOutputDeserializer;
 impl CreateStackOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateStackOutput, XmlParseError> {
@@ -968,7 +1969,7 @@ pub struct CreateStackSetInput {
     /// <p>The Amazon Resource Number (ARN) of the IAM role to use to create this stack set. </p> <p>Specify an IAM role only if you are using customized administrator roles to control which users or groups can manage specific stack sets within the same administrator account. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-prereqs.html">Prerequisites: Granting Permissions for Stack Set Operations</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     pub administration_role_arn: Option<String>,
     /// <p><p>In some cases, you must explicity acknowledge that your stack set template contains certain capabilities in order for AWS CloudFormation to create the stack set and related stack instances.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stack sets, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some templates contain macros. If your stack template contains one or more macros, and you choose to create a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> <note> <p>Stack sets do not currently support macros in stack templates. (This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.) Even if you specify this capability, if you include a macro in your template the stack set operation will fail.</p> </note> </li> </ul></p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>A unique identifier for this <code>CreateStackSet</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to create another stack set with the same name. You might retry <code>CreateStackSet</code> requests to ensure that AWS CloudFormation successfully received them.</p> <p>If you don't specify an operation ID, the SDK generates one automatically. </p>
     pub client_request_token: Option<String>,
     /// <p>A description of the stack set. You can use the description to identify the stack set's purpose or other important information.</p>
@@ -987,6 +1988,108 @@ pub struct CreateStackSetInput {
     pub template_url: Option<String>,
 }
 
+impl CreateStackSetInput {
+    /// Returns a builder for `CreateStackSetInput`, checking its required members when built.
+    pub fn builder() -> CreateStackSetInputBuilder {
+        CreateStackSetInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStackSetInput`](struct.CreateStackSetInput.html).
+#[derive(Default, Debug)]
+pub struct CreateStackSetInputBuilder {
+    administration_role_arn: Option<String>,
+    capabilities: Option<Vec<Capability>>,
+    client_request_token: Option<String>,
+    description: Option<String>,
+    execution_role_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+    stack_set_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    template_body: Option<String>,
+    template_url: Option<String>,
+}
+
+impl CreateStackSetInputBuilder {
+    /// Sets the [`administration_role_arn`](struct.CreateStackSetInput.html#structfield.administration_role_arn) member.
+    pub fn administration_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.administration_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`capabilities`](struct.CreateStackSetInput.html#structfield.capabilities) member.
+    pub fn capabilities<T: Into<Vec<Capability>>>(mut self, value: T) -> Self {
+        self.capabilities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_request_token`](struct.CreateStackSetInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateStackSetInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_role_name`](struct.CreateStackSetInput.html#structfield.execution_role_name) member.
+    pub fn execution_role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.execution_role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.CreateStackSetInput.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_set_name`](struct.CreateStackSetInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateStackSetInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_body`](struct.CreateStackSetInput.html#structfield.template_body) member.
+    pub fn template_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_url`](struct.CreateStackSetInput.html#structfield.template_url) member.
+    pub fn template_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStackSetInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateStackSetInput, ::rusoto_core::BuildError> {
+        Ok(CreateStackSetInput {
+            administration_role_arn: self.administration_role_arn,
+            capabilities: self.capabilities,
+            client_request_token: self.client_request_token,
+            description: self.description,
+            execution_role_name: self.execution_role_name,
+            parameters: self.parameters,
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+            tags: self.tags,
+            template_body: self.template_body,
+            template_url: self.template_url,
+        })
+    }
+}
+
 /// Serialize `CreateStackSetInput` contents to a `SignedRequest`.
 struct CreateStackSetInputSerializer;
 impl CreateStackSetInputSerializer {
@@ -1009,9 +2112,11 @@ impl CreateStackSetInputSerializer {
                 field_value,
             );
         }
-        if let Some(ref field_value) = obj.client_request_token {
-            params.put(&format!("{}{}", prefix, "ClientRequestToken"), &field_value);
-        }
+        let field_value = obj
+            .client_request_token
+            .clone()
+            .unwrap_or_else(rusoto_core::idempotency::generate_token);
+        params.put(&format!("{}{}", prefix, "ClientRequestToken"), &field_value);
         if let Some(ref field_value) = obj.description {
             params.put(&format!("{}{}", prefix, "Description"), &field_value);
         }
@@ -1047,10 +2152,10 @@ pub struct CreateStackSetOutput {
     pub stack_set_id: Option<String>,
 }
 
-struct CreateStackSetOutputDeserializer;
+pub(crate) struct CreateStackSetOutputDeserializer;
 impl CreateStackSetOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateStackSetOutput, XmlParseError> {
@@ -1066,12 +2171,15 @@ impl CreateStackSetOutputDeserializer {
         })
     }
 }
-struct CreationTimeDeserializer;
+pub(crate) struct CreationTimeDeserializer;
 impl CreationTimeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1086,6 +2194,44 @@ pub struct DeleteChangeSetInput {
     pub stack_name: Option<String>,
 }
 
+impl DeleteChangeSetInput {
+    /// Returns a builder for `DeleteChangeSetInput`, checking its required members when built.
+    pub fn builder() -> DeleteChangeSetInputBuilder {
+        DeleteChangeSetInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteChangeSetInput`](struct.DeleteChangeSetInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteChangeSetInputBuilder {
+    change_set_name: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl DeleteChangeSetInputBuilder {
+    /// Sets the [`change_set_name`](struct.DeleteChangeSetInput.html#structfield.change_set_name) member.
+    pub fn change_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DeleteChangeSetInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteChangeSetInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteChangeSetInput, ::rusoto_core::BuildError> {
+        Ok(DeleteChangeSetInput {
+            change_set_name: self
+                .change_set_name
+                .ok_or(::rusoto_core::BuildError::missing("change_set_name"))?,
+            stack_name: self.stack_name,
+        })
+    }
+}
+
 /// Serialize `DeleteChangeSetInput` contents to a `SignedRequest`.
 struct DeleteChangeSetInputSerializer;
 impl DeleteChangeSetInputSerializer {
@@ -1109,10 +2255,10 @@ impl DeleteChangeSetInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteChangeSetOutput {}
 
-struct DeleteChangeSetOutputDeserializer;
+pub(crate) struct DeleteChangeSetOutputDeserializer;
 impl DeleteChangeSetOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteChangeSetOutput, XmlParseError> {
@@ -1138,6 +2284,60 @@ pub struct DeleteStackInput {
     pub stack_name: String,
 }
 
+impl DeleteStackInput {
+    /// Returns a builder for `DeleteStackInput`, checking its required members when built.
+    pub fn builder() -> DeleteStackInputBuilder {
+        DeleteStackInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStackInput`](struct.DeleteStackInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteStackInputBuilder {
+    client_request_token: Option<String>,
+    retain_resources: Option<Vec<String>>,
+    role_arn: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl DeleteStackInputBuilder {
+    /// Sets the [`client_request_token`](struct.DeleteStackInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retain_resources`](struct.DeleteStackInput.html#structfield.retain_resources) member.
+    pub fn retain_resources<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.retain_resources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.DeleteStackInput.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DeleteStackInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStackInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteStackInput, ::rusoto_core::BuildError> {
+        Ok(DeleteStackInput {
+            client_request_token: self.client_request_token,
+            retain_resources: self.retain_resources,
+            role_arn: self.role_arn,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteStackInput` contents to a `SignedRequest`.
 struct DeleteStackInputSerializer;
 impl DeleteStackInputSerializer {
@@ -1180,6 +2380,87 @@ pub struct DeleteStackInstancesInput {
     pub stack_set_name: String,
 }
 
+impl DeleteStackInstancesInput {
+    /// Returns a builder for `DeleteStackInstancesInput`, checking its required members when built.
+    pub fn builder() -> DeleteStackInstancesInputBuilder {
+        DeleteStackInstancesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStackInstancesInput`](struct.DeleteStackInstancesInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteStackInstancesInputBuilder {
+    accounts: Option<Vec<String>>,
+    operation_id: Option<String>,
+    operation_preferences: Option<StackSetOperationPreferences>,
+    regions: Option<Vec<String>>,
+    retain_stacks: Option<bool>,
+    stack_set_name: Option<String>,
+}
+
+impl DeleteStackInstancesInputBuilder {
+    /// Sets the [`accounts`](struct.DeleteStackInstancesInput.html#structfield.accounts) member.
+    pub fn accounts<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.accounts = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation_id`](struct.DeleteStackInstancesInput.html#structfield.operation_id) member.
+    pub fn operation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation_preferences`](struct.DeleteStackInstancesInput.html#structfield.operation_preferences) member.
+    pub fn operation_preferences<T: Into<StackSetOperationPreferences>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.operation_preferences = Some(value.into());
+        self
+    }
+
+    /// Sets the [`regions`](struct.DeleteStackInstancesInput.html#structfield.regions) member.
+    pub fn regions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.regions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retain_stacks`](struct.DeleteStackInstancesInput.html#structfield.retain_stacks) member.
+    pub fn retain_stacks<T: Into<bool>>(mut self, value: T) -> Self {
+        self.retain_stacks = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_set_name`](struct.DeleteStackInstancesInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStackInstancesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteStackInstancesInput, ::rusoto_core::BuildError> {
+        Ok(DeleteStackInstancesInput {
+            accounts: self
+                .accounts
+                .ok_or(::rusoto_core::BuildError::missing("accounts"))?,
+            operation_id: self.operation_id,
+            operation_preferences: self.operation_preferences,
+            regions: self
+                .regions
+                .ok_or(::rusoto_core::BuildError::missing("regions"))?,
+            retain_stacks: self
+                .retain_stacks
+                .ok_or(::rusoto_core::BuildError::missing("retain_stacks"))?,
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteStackInstancesInput` contents to a `SignedRequest`.
 struct DeleteStackInstancesInputSerializer;
 impl DeleteStackInstancesInputSerializer {
@@ -1194,9 +2475,11 @@ impl DeleteStackInstancesInputSerializer {
             &format!("{}{}", prefix, "Accounts"),
             &obj.accounts,
         );
-        if let Some(ref field_value) = obj.operation_id {
-            params.put(&format!("{}{}", prefix, "OperationId"), &field_value);
-        }
+        let field_value = obj
+            .operation_id
+            .clone()
+            .unwrap_or_else(rusoto_core::idempotency::generate_token);
+        params.put(&format!("{}{}", prefix, "OperationId"), &field_value);
         if let Some(ref field_value) = obj.operation_preferences {
             StackSetOperationPreferencesSerializer::serialize(
                 params,
@@ -1219,10 +2502,10 @@ pub struct DeleteStackInstancesOutput {
     pub operation_id: Option<String>,
 }
 
-struct DeleteStackInstancesOutputDeserializer;
+pub(crate) struct DeleteStackInstancesOutputDeserializer;
 impl DeleteStackInstancesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteStackInstancesOutput, XmlParseError> {
@@ -1250,6 +2533,36 @@ pub struct DeleteStackSetInput {
     pub stack_set_name: String,
 }
 
+impl DeleteStackSetInput {
+    /// Returns a builder for `DeleteStackSetInput`, checking its required members when built.
+    pub fn builder() -> DeleteStackSetInputBuilder {
+        DeleteStackSetInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStackSetInput`](struct.DeleteStackSetInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteStackSetInputBuilder {
+    stack_set_name: Option<String>,
+}
+
+impl DeleteStackSetInputBuilder {
+    /// Sets the [`stack_set_name`](struct.DeleteStackSetInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStackSetInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteStackSetInput, ::rusoto_core::BuildError> {
+        Ok(DeleteStackSetInput {
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteStackSetInput` contents to a `SignedRequest`.
 struct DeleteStackSetInputSerializer;
 impl DeleteStackSetInputSerializer {
@@ -1269,10 +2582,10 @@ impl DeleteStackSetInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteStackSetOutput {}
 
-struct DeleteStackSetOutputDeserializer;
+pub(crate) struct DeleteStackSetOutputDeserializer;
 impl DeleteStackSetOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteStackSetOutput, XmlParseError> {
@@ -1285,12 +2598,15 @@ impl DeleteStackSetOutputDeserializer {
         Ok(obj)
     }
 }
-struct DeletionTimeDeserializer;
+pub(crate) struct DeletionTimeDeserializer;
 impl DeletionTimeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1303,6 +2619,36 @@ pub struct DescribeAccountLimitsInput {
     pub next_token: Option<String>,
 }
 
+impl DescribeAccountLimitsInput {
+    /// Returns a builder for `DescribeAccountLimitsInput`, checking its required members when built.
+    pub fn builder() -> DescribeAccountLimitsInputBuilder {
+        DescribeAccountLimitsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAccountLimitsInput`](struct.DescribeAccountLimitsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeAccountLimitsInputBuilder {
+    next_token: Option<String>,
+}
+
+impl DescribeAccountLimitsInputBuilder {
+    /// Sets the [`next_token`](struct.DescribeAccountLimitsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAccountLimitsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAccountLimitsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeAccountLimitsInput {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `DescribeAccountLimitsInput` contents to a `SignedRequest`.
 struct DescribeAccountLimitsInputSerializer;
 impl DescribeAccountLimitsInputSerializer {
@@ -1327,10 +2673,10 @@ pub struct DescribeAccountLimitsOutput {
     pub next_token: Option<String>,
 }
 
-struct DescribeAccountLimitsOutputDeserializer;
+pub(crate) struct DescribeAccountLimitsOutputDeserializer;
 impl DescribeAccountLimitsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAccountLimitsOutput, XmlParseError> {
@@ -1366,6 +2712,52 @@ pub struct DescribeChangeSetInput {
     pub stack_name: Option<String>,
 }
 
+impl DescribeChangeSetInput {
+    /// Returns a builder for `DescribeChangeSetInput`, checking its required members when built.
+    pub fn builder() -> DescribeChangeSetInputBuilder {
+        DescribeChangeSetInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeChangeSetInput`](struct.DescribeChangeSetInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeChangeSetInputBuilder {
+    change_set_name: Option<String>,
+    next_token: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl DescribeChangeSetInputBuilder {
+    /// Sets the [`change_set_name`](struct.DescribeChangeSetInput.html#structfield.change_set_name) member.
+    pub fn change_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeChangeSetInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DescribeChangeSetInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeChangeSetInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeChangeSetInput, ::rusoto_core::BuildError> {
+        Ok(DescribeChangeSetInput {
+            change_set_name: self
+                .change_set_name
+                .ok_or(::rusoto_core::BuildError::missing("change_set_name"))?,
+            next_token: self.next_token,
+            stack_name: self.stack_name,
+        })
+    }
+}
+
 /// Serialize `DescribeChangeSetInput` contents to a `SignedRequest`.
 struct DescribeChangeSetInputSerializer;
 impl DescribeChangeSetInputSerializer {
@@ -1392,7 +2784,7 @@ impl DescribeChangeSetInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DescribeChangeSetOutput {
     /// <p>If you execute the change set, the list of capabilities that were explicitly acknowledged when the change set was created.</p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>The ARN of the change set.</p>
     pub change_set_id: Option<String>,
     /// <p>The name of the change set.</p>
@@ -1400,11 +2792,11 @@ pub struct DescribeChangeSetOutput {
     /// <p>A list of <code>Change</code> structures that describes the resources AWS CloudFormation changes if you execute the change set.</p>
     pub changes: Option<Vec<Change>>,
     /// <p>The start time when the change set was created, in UTC.</p>
-    pub creation_time: Option<String>,
+    pub creation_time: Option<IsoTimestamp>,
     /// <p>Information about the change set.</p>
     pub description: Option<String>,
     /// <p>If the change set execution status is <code>AVAILABLE</code>, you can execute the change set. If you can’t execute the change set, the status indicates why. For example, a change set might be in an <code>UNAVAILABLE</code> state because AWS CloudFormation is still creating it or in an <code>OBSOLETE</code> state because the stack was already updated.</p>
-    pub execution_status: Option<String>,
+    pub execution_status: Option<ExecutionStatus>,
     /// <p>If the output exceeds 1 MB, a string that identifies the next page of changes. If there is no additional page, this value is null.</p>
     pub next_token: Option<String>,
     /// <p>The ARNs of the Amazon Simple Notification Service (Amazon SNS) topics that will be associated with the stack if you execute the change set.</p>
@@ -1418,17 +2810,17 @@ pub struct DescribeChangeSetOutput {
     /// <p>The name of the stack that is associated with the change set.</p>
     pub stack_name: Option<String>,
     /// <p>The current status of the change set, such as <code>CREATE_IN_PROGRESS</code>, <code>CREATE_COMPLETE</code>, or <code>FAILED</code>.</p>
-    pub status: Option<String>,
+    pub status: Option<ChangeSetStatus>,
     /// <p>A description of the change set's status. For example, if your attempt to create a change set failed, AWS CloudFormation shows the error message.</p>
     pub status_reason: Option<String>,
     /// <p>If you execute the change set, the tags that will be associated with the stack.</p>
     pub tags: Option<Vec<Tag>>,
 }
 
-struct DescribeChangeSetOutputDeserializer;
+pub(crate) struct DescribeChangeSetOutputDeserializer;
 impl DescribeChangeSetOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeChangeSetOutput, XmlParseError> {
@@ -1529,6 +2921,39 @@ pub struct DescribeStackDriftDetectionStatusInput {
     pub stack_drift_detection_id: String,
 }
 
+impl DescribeStackDriftDetectionStatusInput {
+    /// Returns a builder for `DescribeStackDriftDetectionStatusInput`, checking its required members when built.
+    pub fn builder() -> DescribeStackDriftDetectionStatusInputBuilder {
+        DescribeStackDriftDetectionStatusInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStackDriftDetectionStatusInput`](struct.DescribeStackDriftDetectionStatusInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStackDriftDetectionStatusInputBuilder {
+    stack_drift_detection_id: Option<String>,
+}
+
+impl DescribeStackDriftDetectionStatusInputBuilder {
+    /// Sets the [`stack_drift_detection_id`](struct.DescribeStackDriftDetectionStatusInput.html#structfield.stack_drift_detection_id) member.
+    pub fn stack_drift_detection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_drift_detection_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStackDriftDetectionStatusInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStackDriftDetectionStatusInput, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeStackDriftDetectionStatusInput {
+            stack_drift_detection_id: self.stack_drift_detection_id.ok_or(
+                ::rusoto_core::BuildError::missing("stack_drift_detection_id"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DescribeStackDriftDetectionStatusInput` contents to a `SignedRequest`.
 struct DescribeStackDriftDetectionStatusInputSerializer;
 impl DescribeStackDriftDetectionStatusInputSerializer {
@@ -1545,10 +2970,10 @@ impl DescribeStackDriftDetectionStatusInputSerializer {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DescribeStackDriftDetectionStatusOutput {
     /// <p><p>The status of the stack drift detection operation.</p> <ul> <li> <p> <code>DETECTION<em>COMPLETE</code>: The stack drift detection operation has successfully completed for all resources in the stack that support drift detection. (Resources that do not currently support stack detection remain unchecked.)</p> <p>If you specified logical resource IDs for AWS CloudFormation to use as a filter for the stack drift detection operation, only the resources with those logical IDs are checked for drift.</p> </li> <li> <p> <code>DETECTION</em>FAILED</code>: The stack drift detection operation has failed for at least one resource in the stack. Results will be available for resources on which AWS CloudFormation successfully completed drift detection.</p> </li> <li> <p> <code>DETECTION<em>IN</em>PROGRESS</code>: The stack drift detection operation is currently in progress.</p> </li> </ul></p>
-    pub detection_status: String,
+    pub detection_status: StackDriftDetectionStatus,
     /// <p>The reason the stack drift detection operation has its current status.</p>
     pub detection_status_reason: Option<String>,
     /// <p>Total number of stack resources that have drifted. This is NULL until the drift detection operation reaches a status of <code>DETECTION_COMPLETE</code>. This value will be 0 for stacks whose drift status is <code>IN_SYNC</code>.</p>
@@ -1556,23 +2981,32 @@ pub struct DescribeStackDriftDetectionStatusOutput {
     /// <p>The ID of the drift detection results of this operation. </p> <p>AWS CloudFormation generates new results, with a new drift detection ID, each time this operation is run. However, the number of reports AWS CloudFormation retains for any given stack, and for how long, may vary.</p>
     pub stack_drift_detection_id: String,
     /// <p><p>Status of the stack&#39;s actual configuration compared to its expected configuration. </p> <ul> <li> <p> <code>DRIFTED</code>: The stack differs from its expected template configuration. A stack is considered to have drifted if one or more of its resources have drifted.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the stack differs from its expected template configuration.</p> </li> <li> <p> <code>IN</em>SYNC</code>: The stack&#39;s actual configuration matches its expected template configuration.</p> </li> <li> <p> <code>UNKNOWN</code>: This value is reserved for future use.</p> </li> </ul></p>
-    pub stack_drift_status: Option<String>,
+    pub stack_drift_status: Option<StackDriftStatus>,
     /// <p>The ID of the stack.</p>
     pub stack_id: String,
     /// <p>Time at which the stack drift detection operation was initiated.</p>
-    pub timestamp: String,
+    pub timestamp: IsoTimestamp,
 }
 
-struct DescribeStackDriftDetectionStatusOutputDeserializer;
+pub(crate) struct DescribeStackDriftDetectionStatusOutputDeserializer;
 impl DescribeStackDriftDetectionStatusOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeStackDriftDetectionStatusOutput, XmlParseError> {
-        deserialize_elements::<_, DescribeStackDriftDetectionStatusOutput, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DescribeStackDriftDetectionStatusOutput {
+                detection_status: StackDriftDetectionStatus::Unknown(String::new()),
+                detection_status_reason: Default::default(),
+                drifted_stack_resource_count: Default::default(),
+                stack_drift_detection_id: Default::default(),
+                stack_drift_status: Default::default(),
+                stack_id: Default::default(),
+                timestamp: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "DetectionStatus" => {
@@ -1630,8 +3064,46 @@ pub struct DescribeStackEventsInput {
     pub stack_name: Option<String>,
 }
 
-/// Serialize `DescribeStackEventsInput` contents to a `SignedRequest`.
-struct DescribeStackEventsInputSerializer;
+impl DescribeStackEventsInput {
+    /// Returns a builder for `DescribeStackEventsInput`, checking its required members when built.
+    pub fn builder() -> DescribeStackEventsInputBuilder {
+        DescribeStackEventsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStackEventsInput`](struct.DescribeStackEventsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStackEventsInputBuilder {
+    next_token: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl DescribeStackEventsInputBuilder {
+    /// Sets the [`next_token`](struct.DescribeStackEventsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DescribeStackEventsInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStackEventsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStackEventsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStackEventsInput {
+            next_token: self.next_token,
+            stack_name: self.stack_name,
+        })
+    }
+}
+
+/// Serialize `DescribeStackEventsInput` contents to a `SignedRequest`.
+struct DescribeStackEventsInputSerializer;
 impl DescribeStackEventsInputSerializer {
     fn serialize(params: &mut Params, name: &str, obj: &DescribeStackEventsInput) {
         let mut prefix = name.to_string();
@@ -1657,10 +3129,10 @@ pub struct DescribeStackEventsOutput {
     pub stack_events: Option<Vec<StackEvent>>,
 }
 
-struct DescribeStackEventsOutputDeserializer;
+pub(crate) struct DescribeStackEventsOutputDeserializer;
 impl DescribeStackEventsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeStackEventsOutput, XmlParseError> {
@@ -1695,6 +3167,58 @@ pub struct DescribeStackInstanceInput {
     pub stack_set_name: String,
 }
 
+impl DescribeStackInstanceInput {
+    /// Returns a builder for `DescribeStackInstanceInput`, checking its required members when built.
+    pub fn builder() -> DescribeStackInstanceInputBuilder {
+        DescribeStackInstanceInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStackInstanceInput`](struct.DescribeStackInstanceInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStackInstanceInputBuilder {
+    stack_instance_account: Option<String>,
+    stack_instance_region: Option<String>,
+    stack_set_name: Option<String>,
+}
+
+impl DescribeStackInstanceInputBuilder {
+    /// Sets the [`stack_instance_account`](struct.DescribeStackInstanceInput.html#structfield.stack_instance_account) member.
+    pub fn stack_instance_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_instance_account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_instance_region`](struct.DescribeStackInstanceInput.html#structfield.stack_instance_region) member.
+    pub fn stack_instance_region<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_instance_region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_set_name`](struct.DescribeStackInstanceInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStackInstanceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStackInstanceInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStackInstanceInput {
+            stack_instance_account: self
+                .stack_instance_account
+                .ok_or(::rusoto_core::BuildError::missing("stack_instance_account"))?,
+            stack_instance_region: self
+                .stack_instance_region
+                .ok_or(::rusoto_core::BuildError::missing("stack_instance_region"))?,
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeStackInstanceInput` contents to a `SignedRequest`.
 struct DescribeStackInstanceInputSerializer;
 impl DescribeStackInstanceInputSerializer {
@@ -1725,10 +3249,10 @@ pub struct DescribeStackInstanceOutput {
     pub stack_instance: Option<StackInstance>,
 }
 
-struct DescribeStackInstanceOutputDeserializer;
+pub(crate) struct DescribeStackInstanceOutputDeserializer;
 impl DescribeStackInstanceOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeStackInstanceOutput, XmlParseError> {
@@ -1759,7 +3283,66 @@ pub struct DescribeStackResourceDriftsInput {
     /// <p>The name of the stack for which you want drift information.</p>
     pub stack_name: String,
     /// <p><p>The resource drift status values to use as filters for the resource drift results returned.</p> <ul> <li> <p> <code>DELETED</code>: The resource differs from its expected template configuration in that the resource has been deleted.</p> </li> <li> <p> <code>MODIFIED</code>: One or more resource properties differ from their expected template values.</p> </li> <li> <p> <code>IN<em>SYNC</code>: The resources&#39;s actual configuration matches its expected template configuration.</p> </li> <li> <p> <code>NOT</em>CHECKED</code>: AWS CloudFormation does not currently return this value.</p> </li> </ul></p>
-    pub stack_resource_drift_status_filters: Option<Vec<String>>,
+    pub stack_resource_drift_status_filters: Option<Vec<StackResourceDriftStatus>>,
+}
+
+impl DescribeStackResourceDriftsInput {
+    /// Returns a builder for `DescribeStackResourceDriftsInput`, checking its required members when built.
+    pub fn builder() -> DescribeStackResourceDriftsInputBuilder {
+        DescribeStackResourceDriftsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStackResourceDriftsInput`](struct.DescribeStackResourceDriftsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStackResourceDriftsInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    stack_name: Option<String>,
+    stack_resource_drift_status_filters: Option<Vec<StackResourceDriftStatus>>,
+}
+
+impl DescribeStackResourceDriftsInputBuilder {
+    /// Sets the [`max_results`](struct.DescribeStackResourceDriftsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeStackResourceDriftsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DescribeStackResourceDriftsInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_resource_drift_status_filters`](struct.DescribeStackResourceDriftsInput.html#structfield.stack_resource_drift_status_filters) member.
+    pub fn stack_resource_drift_status_filters<T: Into<Vec<StackResourceDriftStatus>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.stack_resource_drift_status_filters = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStackResourceDriftsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStackResourceDriftsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStackResourceDriftsInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+            stack_resource_drift_status_filters: self.stack_resource_drift_status_filters,
+        })
+    }
 }
 
 /// Serialize `DescribeStackResourceDriftsInput` contents to a `SignedRequest`.
@@ -1796,10 +3379,10 @@ pub struct DescribeStackResourceDriftsOutput {
     pub stack_resource_drifts: Vec<StackResourceDrift>,
 }
 
-struct DescribeStackResourceDriftsOutputDeserializer;
+pub(crate) struct DescribeStackResourceDriftsOutputDeserializer;
 impl DescribeStackResourceDriftsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeStackResourceDriftsOutput, XmlParseError> {
@@ -1836,6 +3419,48 @@ pub struct DescribeStackResourceInput {
     pub stack_name: String,
 }
 
+impl DescribeStackResourceInput {
+    /// Returns a builder for `DescribeStackResourceInput`, checking its required members when built.
+    pub fn builder() -> DescribeStackResourceInputBuilder {
+        DescribeStackResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStackResourceInput`](struct.DescribeStackResourceInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStackResourceInputBuilder {
+    logical_resource_id: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl DescribeStackResourceInputBuilder {
+    /// Sets the [`logical_resource_id`](struct.DescribeStackResourceInput.html#structfield.logical_resource_id) member.
+    pub fn logical_resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.logical_resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DescribeStackResourceInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStackResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStackResourceInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStackResourceInput {
+            logical_resource_id: self
+                .logical_resource_id
+                .ok_or(::rusoto_core::BuildError::missing("logical_resource_id"))?,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeStackResourceInput` contents to a `SignedRequest`.
 struct DescribeStackResourceInputSerializer;
 impl DescribeStackResourceInputSerializer {
@@ -1860,10 +3485,10 @@ pub struct DescribeStackResourceOutput {
     pub stack_resource_detail: Option<StackResourceDetail>,
 }
 
-struct DescribeStackResourceOutputDeserializer;
+pub(crate) struct DescribeStackResourceOutputDeserializer;
 impl DescribeStackResourceOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeStackResourceOutput, XmlParseError> {
@@ -1897,6 +3522,52 @@ pub struct DescribeStackResourcesInput {
     pub stack_name: Option<String>,
 }
 
+impl DescribeStackResourcesInput {
+    /// Returns a builder for `DescribeStackResourcesInput`, checking its required members when built.
+    pub fn builder() -> DescribeStackResourcesInputBuilder {
+        DescribeStackResourcesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStackResourcesInput`](struct.DescribeStackResourcesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStackResourcesInputBuilder {
+    logical_resource_id: Option<String>,
+    physical_resource_id: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl DescribeStackResourcesInputBuilder {
+    /// Sets the [`logical_resource_id`](struct.DescribeStackResourcesInput.html#structfield.logical_resource_id) member.
+    pub fn logical_resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.logical_resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`physical_resource_id`](struct.DescribeStackResourcesInput.html#structfield.physical_resource_id) member.
+    pub fn physical_resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.physical_resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DescribeStackResourcesInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStackResourcesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStackResourcesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStackResourcesInput {
+            logical_resource_id: self.logical_resource_id,
+            physical_resource_id: self.physical_resource_id,
+            stack_name: self.stack_name,
+        })
+    }
+}
+
 /// Serialize `DescribeStackResourcesInput` contents to a `SignedRequest`.
 struct DescribeStackResourcesInputSerializer;
 impl DescribeStackResourcesInputSerializer {
@@ -1925,10 +3596,10 @@ pub struct DescribeStackResourcesOutput {
     pub stack_resources: Option<Vec<StackResource>>,
 }
 
-struct DescribeStackResourcesOutputDeserializer;
+pub(crate) struct DescribeStackResourcesOutputDeserializer;
 impl DescribeStackResourcesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeStackResourcesOutput, XmlParseError> {
@@ -1955,6 +3626,36 @@ pub struct DescribeStackSetInput {
     pub stack_set_name: String,
 }
 
+impl DescribeStackSetInput {
+    /// Returns a builder for `DescribeStackSetInput`, checking its required members when built.
+    pub fn builder() -> DescribeStackSetInputBuilder {
+        DescribeStackSetInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStackSetInput`](struct.DescribeStackSetInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStackSetInputBuilder {
+    stack_set_name: Option<String>,
+}
+
+impl DescribeStackSetInputBuilder {
+    /// Sets the [`stack_set_name`](struct.DescribeStackSetInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStackSetInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeStackSetInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStackSetInput {
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeStackSetInput` contents to a `SignedRequest`.
 struct DescribeStackSetInputSerializer;
 impl DescribeStackSetInputSerializer {
@@ -1979,6 +3680,48 @@ pub struct DescribeStackSetOperationInput {
     pub stack_set_name: String,
 }
 
+impl DescribeStackSetOperationInput {
+    /// Returns a builder for `DescribeStackSetOperationInput`, checking its required members when built.
+    pub fn builder() -> DescribeStackSetOperationInputBuilder {
+        DescribeStackSetOperationInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStackSetOperationInput`](struct.DescribeStackSetOperationInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStackSetOperationInputBuilder {
+    operation_id: Option<String>,
+    stack_set_name: Option<String>,
+}
+
+impl DescribeStackSetOperationInputBuilder {
+    /// Sets the [`operation_id`](struct.DescribeStackSetOperationInput.html#structfield.operation_id) member.
+    pub fn operation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_set_name`](struct.DescribeStackSetOperationInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStackSetOperationInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStackSetOperationInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStackSetOperationInput {
+            operation_id: self
+                .operation_id
+                .ok_or(::rusoto_core::BuildError::missing("operation_id"))?,
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeStackSetOperationInput` contents to a `SignedRequest`.
 struct DescribeStackSetOperationInputSerializer;
 impl DescribeStackSetOperationInputSerializer {
@@ -2002,10 +3745,10 @@ pub struct DescribeStackSetOperationOutput {
     pub stack_set_operation: Option<StackSetOperation>,
 }
 
-struct DescribeStackSetOperationOutputDeserializer;
+pub(crate) struct DescribeStackSetOperationOutputDeserializer;
 impl DescribeStackSetOperationOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeStackSetOperationOutput, XmlParseError> {
@@ -2033,10 +3776,10 @@ pub struct DescribeStackSetOutput {
     pub stack_set: Option<StackSet>,
 }
 
-struct DescribeStackSetOutputDeserializer;
+pub(crate) struct DescribeStackSetOutputDeserializer;
 impl DescribeStackSetOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeStackSetOutput, XmlParseError> {
@@ -2060,6 +3803,42 @@ pub struct DescribeStacksInput {
     pub stack_name: Option<String>,
 }
 
+impl DescribeStacksInput {
+    /// Returns a builder for `DescribeStacksInput`, checking its required members when built.
+    pub fn builder() -> DescribeStacksInputBuilder {
+        DescribeStacksInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStacksInput`](struct.DescribeStacksInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStacksInputBuilder {
+    next_token: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl DescribeStacksInputBuilder {
+    /// Sets the [`next_token`](struct.DescribeStacksInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DescribeStacksInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStacksInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeStacksInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStacksInput {
+            next_token: self.next_token,
+            stack_name: self.stack_name,
+        })
+    }
+}
+
 /// Serialize `DescribeStacksInput` contents to a `SignedRequest`.
 struct DescribeStacksInputSerializer;
 impl DescribeStacksInputSerializer {
@@ -2087,10 +3866,10 @@ pub struct DescribeStacksOutput {
     pub stacks: Option<Vec<Stack>>,
 }
 
-struct DescribeStacksOutputDeserializer;
+pub(crate) struct DescribeStacksOutputDeserializer;
 impl DescribeStacksOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeStacksOutput, XmlParseError> {
@@ -2110,10 +3889,13 @@ impl DescribeStacksOutputDeserializer {
         })
     }
 }
-struct DescriptionDeserializer;
+pub(crate) struct DescriptionDeserializer;
 impl DescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2129,6 +3911,44 @@ pub struct DetectStackDriftInput {
     pub stack_name: String,
 }
 
+impl DetectStackDriftInput {
+    /// Returns a builder for `DetectStackDriftInput`, checking its required members when built.
+    pub fn builder() -> DetectStackDriftInputBuilder {
+        DetectStackDriftInputBuilder::default()
+    }
+}
+
+/// Builds a [`DetectStackDriftInput`](struct.DetectStackDriftInput.html).
+#[derive(Default, Debug)]
+pub struct DetectStackDriftInputBuilder {
+    logical_resource_ids: Option<Vec<String>>,
+    stack_name: Option<String>,
+}
+
+impl DetectStackDriftInputBuilder {
+    /// Sets the [`logical_resource_ids`](struct.DetectStackDriftInput.html#structfield.logical_resource_ids) member.
+    pub fn logical_resource_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.logical_resource_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DetectStackDriftInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectStackDriftInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetectStackDriftInput, ::rusoto_core::BuildError> {
+        Ok(DetectStackDriftInput {
+            logical_resource_ids: self.logical_resource_ids,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 /// Serialize `DetectStackDriftInput` contents to a `SignedRequest`.
 struct DetectStackDriftInputSerializer;
 impl DetectStackDriftInputSerializer {
@@ -2155,10 +3975,10 @@ pub struct DetectStackDriftOutput {
     pub stack_drift_detection_id: String,
 }
 
-struct DetectStackDriftOutputDeserializer;
+pub(crate) struct DetectStackDriftOutputDeserializer;
 impl DetectStackDriftOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DetectStackDriftOutput, XmlParseError> {
@@ -2184,6 +4004,48 @@ pub struct DetectStackResourceDriftInput {
     pub stack_name: String,
 }
 
+impl DetectStackResourceDriftInput {
+    /// Returns a builder for `DetectStackResourceDriftInput`, checking its required members when built.
+    pub fn builder() -> DetectStackResourceDriftInputBuilder {
+        DetectStackResourceDriftInputBuilder::default()
+    }
+}
+
+/// Builds a [`DetectStackResourceDriftInput`](struct.DetectStackResourceDriftInput.html).
+#[derive(Default, Debug)]
+pub struct DetectStackResourceDriftInputBuilder {
+    logical_resource_id: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl DetectStackResourceDriftInputBuilder {
+    /// Sets the [`logical_resource_id`](struct.DetectStackResourceDriftInput.html#structfield.logical_resource_id) member.
+    pub fn logical_resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.logical_resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.DetectStackResourceDriftInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectStackResourceDriftInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetectStackResourceDriftInput, ::rusoto_core::BuildError> {
+        Ok(DetectStackResourceDriftInput {
+            logical_resource_id: self
+                .logical_resource_id
+                .ok_or(::rusoto_core::BuildError::missing("logical_resource_id"))?,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 /// Serialize `DetectStackResourceDriftInput` contents to a `SignedRequest`.
 struct DetectStackResourceDriftInputSerializer;
 impl DetectStackResourceDriftInputSerializer {
@@ -2201,22 +4063,36 @@ impl DetectStackResourceDriftInputSerializer {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DetectStackResourceDriftOutput {
     /// <p>Information about whether the resource's actual configuration has drifted from its expected template configuration, including actual and expected property values and any differences detected.</p>
     pub stack_resource_drift: StackResourceDrift,
 }
 
-struct DetectStackResourceDriftOutputDeserializer;
+pub(crate) struct DetectStackResourceDriftOutputDeserializer;
 impl DetectStackResourceDriftOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DetectStackResourceDriftOutput, XmlParseError> {
-        deserialize_elements::<_, DetectStackResourceDriftOutput, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DetectStackResourceDriftOutput {
+                stack_resource_drift: StackResourceDrift {
+                    actual_properties: Default::default(),
+                    expected_properties: Default::default(),
+                    logical_resource_id: Default::default(),
+                    physical_resource_id: Default::default(),
+                    physical_resource_id_context: Default::default(),
+                    property_differences: Default::default(),
+                    resource_type: Default::default(),
+                    stack_id: Default::default(),
+                    stack_resource_drift_status: StackResourceDriftStatus::Unknown(String::new()),
+                    timestamp: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "StackResourceDrift" => {
@@ -2232,21 +4108,90 @@ impl DetectStackResourceDriftOutputDeserializer {
         )
     }
 }
-struct DifferenceTypeDeserializer;
+pub(crate) struct DifferenceTypeDeserializer;
 impl DifferenceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<DifferenceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct DisableRollbackDeserializer;
+/// <p><p>The type of property difference.</p> <ul> <li> <p> <code>ADD</code>: A value has been added to a resource property that is an array or list data type.</p> </li> <li> <p> <code>REMOVE</code>: The property has been removed from the current resource configuration.</p> </li> <li> <p> <code>NOT_EQUAL</code>: The current property value differs from its expected value (as defined in the stack template and any values specified as template parameters).</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DifferenceType {
+    /// <p><code>ADD</code> - A value has been added to a resource property that is an array or list data type.</p>
+    Add,
+    /// <p><code>REMOVE</code> - The property has been removed from the current resource configuration.</p>
+    Remove,
+    /// <p><code>NOT_EQUAL</code> - The current property value differs from its expected value (as defined in the stack template and any values specified as template parameters).</p>
+    NotEqual,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DifferenceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DifferenceType::Add => "ADD",
+            DifferenceType::Remove => "REMOVE",
+            DifferenceType::NotEqual => "NOT_EQUAL",
+            DifferenceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DifferenceType {
+    fn from(value: &'a str) -> DifferenceType {
+        match value {
+            "ADD" => DifferenceType::Add,
+            "REMOVE" => DifferenceType::Remove,
+            "NOT_EQUAL" => DifferenceType::NotEqual,
+            _ => DifferenceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DifferenceType {
+    fn from(value: String) -> DifferenceType {
+        DifferenceType::from(value.as_str())
+    }
+}
+
+impl From<DifferenceType> for String {
+    fn from(value: DifferenceType) -> String {
+        match value {
+            DifferenceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DifferenceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DifferenceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct DisableRollbackDeserializer;
 impl DisableRollbackDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2254,10 +4199,13 @@ impl DisableRollbackDeserializer {
         Ok(obj)
     }
 }
-struct EnableTerminationProtectionDeserializer;
+pub(crate) struct EnableTerminationProtectionDeserializer;
 impl EnableTerminationProtectionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2276,6 +4224,52 @@ pub struct EstimateTemplateCostInput {
     pub template_url: Option<String>,
 }
 
+impl EstimateTemplateCostInput {
+    /// Returns a builder for `EstimateTemplateCostInput`, checking its required members when built.
+    pub fn builder() -> EstimateTemplateCostInputBuilder {
+        EstimateTemplateCostInputBuilder::default()
+    }
+}
+
+/// Builds a [`EstimateTemplateCostInput`](struct.EstimateTemplateCostInput.html).
+#[derive(Default, Debug)]
+pub struct EstimateTemplateCostInputBuilder {
+    parameters: Option<Vec<Parameter>>,
+    template_body: Option<String>,
+    template_url: Option<String>,
+}
+
+impl EstimateTemplateCostInputBuilder {
+    /// Sets the [`parameters`](struct.EstimateTemplateCostInput.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_body`](struct.EstimateTemplateCostInput.html#structfield.template_body) member.
+    pub fn template_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_url`](struct.EstimateTemplateCostInput.html#structfield.template_url) member.
+    pub fn template_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `EstimateTemplateCostInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EstimateTemplateCostInput, ::rusoto_core::BuildError> {
+        Ok(EstimateTemplateCostInput {
+            parameters: self.parameters,
+            template_body: self.template_body,
+            template_url: self.template_url,
+        })
+    }
+}
+
 /// Serialize `EstimateTemplateCostInput` contents to a `SignedRequest`.
 struct EstimateTemplateCostInputSerializer;
 impl EstimateTemplateCostInputSerializer {
@@ -2308,10 +4302,10 @@ pub struct EstimateTemplateCostOutput {
     pub url: Option<String>,
 }
 
-struct EstimateTemplateCostOutputDeserializer;
+pub(crate) struct EstimateTemplateCostOutputDeserializer;
 impl EstimateTemplateCostOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EstimateTemplateCostOutput, XmlParseError> {
@@ -2330,21 +4324,86 @@ impl EstimateTemplateCostOutputDeserializer {
         )
     }
 }
-struct EvaluationTypeDeserializer;
+pub(crate) struct EvaluationTypeDeserializer;
 impl EvaluationTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EvaluationType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct EventIdDeserializer;
+/// <p>Indicates whether AWS CloudFormation can determine the target value, and whether the target value will change before you execute a change set.</p> <p>For <code>Static</code> evaluations, AWS CloudFormation can determine that the target value will change, and its value. For example, if you directly modify the <code>InstanceType</code> property of an EC2 instance, AWS CloudFormation knows that this property value will change, and its value, so this is a <code>Static</code> evaluation.</p> <p>For <code>Dynamic</code> evaluations, cannot determine the target value because it depends on the result of an intrinsic function, such as a <code>Ref</code> or <code>Fn::GetAtt</code> intrinsic function, when the stack is updated. For example, if your template includes a reference to a resource that is conditionally recreated, the value of the reference (the physical ID of the resource) might change, depending on if the resource is recreated. If the resource is recreated, it will have a new physical ID, so all references to that resource will also be updated.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EvaluationType {
+    /// <p><code>Static</code></p>
+    Static,
+    /// <p><code>Dynamic</code></p>
+    Dynamic,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EvaluationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EvaluationType::Static => "Static",
+            EvaluationType::Dynamic => "Dynamic",
+            EvaluationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EvaluationType {
+    fn from(value: &'a str) -> EvaluationType {
+        match value {
+            "Static" => EvaluationType::Static,
+            "Dynamic" => EvaluationType::Dynamic,
+            _ => EvaluationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EvaluationType {
+    fn from(value: String) -> EvaluationType {
+        EvaluationType::from(value.as_str())
+    }
+}
+
+impl From<EvaluationType> for String {
+    fn from(value: EvaluationType) -> String {
+        match value {
+            EvaluationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EvaluationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EvaluationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct EventIdDeserializer;
 impl EventIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2363,6 +4422,52 @@ pub struct ExecuteChangeSetInput {
     pub stack_name: Option<String>,
 }
 
+impl ExecuteChangeSetInput {
+    /// Returns a builder for `ExecuteChangeSetInput`, checking its required members when built.
+    pub fn builder() -> ExecuteChangeSetInputBuilder {
+        ExecuteChangeSetInputBuilder::default()
+    }
+}
+
+/// Builds a [`ExecuteChangeSetInput`](struct.ExecuteChangeSetInput.html).
+#[derive(Default, Debug)]
+pub struct ExecuteChangeSetInputBuilder {
+    change_set_name: Option<String>,
+    client_request_token: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl ExecuteChangeSetInputBuilder {
+    /// Sets the [`change_set_name`](struct.ExecuteChangeSetInput.html#structfield.change_set_name) member.
+    pub fn change_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_request_token`](struct.ExecuteChangeSetInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.ExecuteChangeSetInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExecuteChangeSetInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ExecuteChangeSetInput, ::rusoto_core::BuildError> {
+        Ok(ExecuteChangeSetInput {
+            change_set_name: self
+                .change_set_name
+                .ok_or(::rusoto_core::BuildError::missing("change_set_name"))?,
+            client_request_token: self.client_request_token,
+            stack_name: self.stack_name,
+        })
+    }
+}
+
 /// Serialize `ExecuteChangeSetInput` contents to a `SignedRequest`.
 struct ExecuteChangeSetInputSerializer;
 impl ExecuteChangeSetInputSerializer {
@@ -2389,10 +4494,10 @@ impl ExecuteChangeSetInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ExecuteChangeSetOutput {}
 
-struct ExecuteChangeSetOutputDeserializer;
+pub(crate) struct ExecuteChangeSetOutputDeserializer;
 impl ExecuteChangeSetOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ExecuteChangeSetOutput, XmlParseError> {
@@ -2405,10 +4510,13 @@ impl ExecuteChangeSetOutputDeserializer {
         Ok(obj)
     }
 }
-struct ExecutionRoleNameDeserializer;
+pub(crate) struct ExecutionRoleNameDeserializer;
 impl ExecutionRoleNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2416,35 +4524,116 @@ impl ExecutionRoleNameDeserializer {
         Ok(obj)
     }
 }
-struct ExecutionStatusDeserializer;
+pub(crate) struct ExecutionStatusDeserializer;
 impl ExecutionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ExecutionStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-/// <p>The <code>Export</code> structure describes the exported output values for a stack.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct Export {
-    /// <p>The stack that contains the exported output name and value.</p>
-    pub exporting_stack_id: Option<String>,
-    /// <p>The name of exported output value. Use this name and the <code>Fn::ImportValue</code> function to import the associated value into other stacks. The name is defined in the <code>Export</code> field in the associated stack's <code>Outputs</code> section.</p>
-    pub name: Option<String>,
-    /// <p>The value of the exported output, such as a resource physical ID. This value is defined in the <code>Export</code> field in the associated stack's <code>Outputs</code> section.</p>
-    pub value: Option<String>,
+/// <p>If the change set execution status is <code>AVAILABLE</code>, you can execute the change set. If you can’t execute the change set, the status indicates why. For example, a change set might be in an <code>UNAVAILABLE</code> state because AWS CloudFormation is still creating it or in an <code>OBSOLETE</code> state because the stack was already updated.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExecutionStatus {
+    /// <p><code>UNAVAILABLE</code></p>
+    Unavailable,
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>EXECUTE_IN_PROGRESS</code></p>
+    ExecuteInProgress,
+    /// <p><code>EXECUTE_COMPLETE</code></p>
+    ExecuteComplete,
+    /// <p><code>EXECUTE_FAILED</code></p>
+    ExecuteFailed,
+    /// <p><code>OBSOLETE</code></p>
+    Obsolete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExecutionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionStatus::Unavailable => "UNAVAILABLE",
+            ExecutionStatus::Available => "AVAILABLE",
+            ExecutionStatus::ExecuteInProgress => "EXECUTE_IN_PROGRESS",
+            ExecutionStatus::ExecuteComplete => "EXECUTE_COMPLETE",
+            ExecutionStatus::ExecuteFailed => "EXECUTE_FAILED",
+            ExecutionStatus::Obsolete => "OBSOLETE",
+            ExecutionStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-struct ExportDeserializer;
-impl ExportDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Export, XmlParseError> {
-        deserialize_elements::<_, Export, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "ExportingStackId" => {
+impl<'a> From<&'a str> for ExecutionStatus {
+    fn from(value: &'a str) -> ExecutionStatus {
+        match value {
+            "UNAVAILABLE" => ExecutionStatus::Unavailable,
+            "AVAILABLE" => ExecutionStatus::Available,
+            "EXECUTE_IN_PROGRESS" => ExecutionStatus::ExecuteInProgress,
+            "EXECUTE_COMPLETE" => ExecutionStatus::ExecuteComplete,
+            "EXECUTE_FAILED" => ExecutionStatus::ExecuteFailed,
+            "OBSOLETE" => ExecutionStatus::Obsolete,
+            _ => ExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExecutionStatus {
+    fn from(value: String) -> ExecutionStatus {
+        ExecutionStatus::from(value.as_str())
+    }
+}
+
+impl From<ExecutionStatus> for String {
+    fn from(value: ExecutionStatus) -> String {
+        match value {
+            ExecutionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// <p>The <code>Export</code> structure describes the exported output values for a stack.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Export {
+    /// <p>The stack that contains the exported output name and value.</p>
+    pub exporting_stack_id: Option<String>,
+    /// <p>The name of exported output value. Use this name and the <code>Fn::ImportValue</code> function to import the associated value into other stacks. The name is defined in the <code>Export</code> field in the associated stack's <code>Outputs</code> section.</p>
+    pub name: Option<String>,
+    /// <p>The value of the exported output, such as a resource physical ID. This value is defined in the <code>Export</code> field in the associated stack's <code>Outputs</code> section.</p>
+    pub value: Option<String>,
+}
+
+pub(crate) struct ExportDeserializer;
+impl ExportDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Export, XmlParseError> {
+        deserialize_elements::<_, Export, _>(tag_name, stack, |name, stack, obj| {
+            match name {
+                "ExportingStackId" => {
                     obj.exporting_stack_id =
                         Some(StackIdDeserializer::deserialize("ExportingStackId", stack)?);
                 }
@@ -2460,10 +4649,13 @@ impl ExportDeserializer {
         })
     }
 }
-struct ExportNameDeserializer;
+pub(crate) struct ExportNameDeserializer;
 impl ExportNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2471,10 +4663,13 @@ impl ExportNameDeserializer {
         Ok(obj)
     }
 }
-struct ExportValueDeserializer;
+pub(crate) struct ExportValueDeserializer;
 impl ExportValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2482,10 +4677,10 @@ impl ExportValueDeserializer {
         Ok(obj)
     }
 }
-struct ExportsDeserializer;
+pub(crate) struct ExportsDeserializer;
 impl ExportsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Export>, XmlParseError> {
@@ -2499,10 +4694,13 @@ impl ExportsDeserializer {
         })
     }
 }
-struct FailureToleranceCountDeserializer;
+pub(crate) struct FailureToleranceCountDeserializer;
 impl FailureToleranceCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2510,10 +4708,13 @@ impl FailureToleranceCountDeserializer {
         Ok(obj)
     }
 }
-struct FailureTolerancePercentageDeserializer;
+pub(crate) struct FailureTolerancePercentageDeserializer;
 impl FailureTolerancePercentageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2528,6 +4729,36 @@ pub struct GetStackPolicyInput {
     pub stack_name: String,
 }
 
+impl GetStackPolicyInput {
+    /// Returns a builder for `GetStackPolicyInput`, checking its required members when built.
+    pub fn builder() -> GetStackPolicyInputBuilder {
+        GetStackPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetStackPolicyInput`](struct.GetStackPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct GetStackPolicyInputBuilder {
+    stack_name: Option<String>,
+}
+
+impl GetStackPolicyInputBuilder {
+    /// Sets the [`stack_name`](struct.GetStackPolicyInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetStackPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetStackPolicyInput, ::rusoto_core::BuildError> {
+        Ok(GetStackPolicyInput {
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 /// Serialize `GetStackPolicyInput` contents to a `SignedRequest`.
 struct GetStackPolicyInputSerializer;
 impl GetStackPolicyInputSerializer {
@@ -2548,10 +4779,10 @@ pub struct GetStackPolicyOutput {
     pub stack_policy_body: Option<String>,
 }
 
-struct GetStackPolicyOutputDeserializer;
+pub(crate) struct GetStackPolicyOutputDeserializer;
 impl GetStackPolicyOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetStackPolicyOutput, XmlParseError> {
@@ -2577,7 +4808,51 @@ pub struct GetTemplateInput {
     /// <p>The name or the unique stack ID that is associated with the stack, which are not always interchangeable:</p> <ul> <li> <p>Running stacks: You can specify either the stack's name or its unique stack ID.</p> </li> <li> <p>Deleted stacks: You must specify the unique stack ID.</p> </li> </ul> <p>Default: There is no default value.</p>
     pub stack_name: Option<String>,
     /// <p>For templates that include transforms, the stage of the template that AWS CloudFormation returns. To get the user-submitted template, specify <code>Original</code>. To get the template after AWS CloudFormation has processed all transforms, specify <code>Processed</code>. </p> <p>If the template doesn't include transforms, <code>Original</code> and <code>Processed</code> return the same template. By default, AWS CloudFormation specifies <code>Original</code>. </p>
-    pub template_stage: Option<String>,
+    pub template_stage: Option<TemplateStage>,
+}
+
+impl GetTemplateInput {
+    /// Returns a builder for `GetTemplateInput`, checking its required members when built.
+    pub fn builder() -> GetTemplateInputBuilder {
+        GetTemplateInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetTemplateInput`](struct.GetTemplateInput.html).
+#[derive(Default, Debug)]
+pub struct GetTemplateInputBuilder {
+    change_set_name: Option<String>,
+    stack_name: Option<String>,
+    template_stage: Option<TemplateStage>,
+}
+
+impl GetTemplateInputBuilder {
+    /// Sets the [`change_set_name`](struct.GetTemplateInput.html#structfield.change_set_name) member.
+    pub fn change_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.change_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.GetTemplateInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_stage`](struct.GetTemplateInput.html#structfield.template_stage) member.
+    pub fn template_stage<T: Into<TemplateStage>>(mut self, value: T) -> Self {
+        self.template_stage = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTemplateInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTemplateInput, ::rusoto_core::BuildError> {
+        Ok(GetTemplateInput {
+            change_set_name: self.change_set_name,
+            stack_name: self.stack_name,
+            template_stage: self.template_stage,
+        })
+    }
 }
 
 /// Serialize `GetTemplateInput` contents to a `SignedRequest`.
@@ -2605,15 +4880,15 @@ impl GetTemplateInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetTemplateOutput {
     /// <p>The stage of the template that you can retrieve. For stacks, the <code>Original</code> and <code>Processed</code> templates are always available. For change sets, the <code>Original</code> template is always available. After AWS CloudFormation finishes creating the change set, the <code>Processed</code> template becomes available.</p>
-    pub stages_available: Option<Vec<String>>,
+    pub stages_available: Option<Vec<TemplateStage>>,
     /// <p>Structure containing the template body. (For more information, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-anatomy.html">Template Anatomy</a> in the AWS CloudFormation User Guide.)</p> <p>AWS CloudFormation returns the same template that was used when the stack was created.</p>
     pub template_body: Option<String>,
 }
 
-struct GetTemplateOutputDeserializer;
+pub(crate) struct GetTemplateOutputDeserializer;
 impl GetTemplateOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetTemplateOutput, XmlParseError> {
@@ -2649,6 +4924,60 @@ pub struct GetTemplateSummaryInput {
     pub template_url: Option<String>,
 }
 
+impl GetTemplateSummaryInput {
+    /// Returns a builder for `GetTemplateSummaryInput`, checking its required members when built.
+    pub fn builder() -> GetTemplateSummaryInputBuilder {
+        GetTemplateSummaryInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetTemplateSummaryInput`](struct.GetTemplateSummaryInput.html).
+#[derive(Default, Debug)]
+pub struct GetTemplateSummaryInputBuilder {
+    stack_name: Option<String>,
+    stack_set_name: Option<String>,
+    template_body: Option<String>,
+    template_url: Option<String>,
+}
+
+impl GetTemplateSummaryInputBuilder {
+    /// Sets the [`stack_name`](struct.GetTemplateSummaryInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_set_name`](struct.GetTemplateSummaryInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_body`](struct.GetTemplateSummaryInput.html#structfield.template_body) member.
+    pub fn template_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_url`](struct.GetTemplateSummaryInput.html#structfield.template_url) member.
+    pub fn template_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTemplateSummaryInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetTemplateSummaryInput, ::rusoto_core::BuildError> {
+        Ok(GetTemplateSummaryInput {
+            stack_name: self.stack_name,
+            stack_set_name: self.stack_set_name,
+            template_body: self.template_body,
+            template_url: self.template_url,
+        })
+    }
+}
+
 /// Serialize `GetTemplateSummaryInput` contents to a `SignedRequest`.
 struct GetTemplateSummaryInputSerializer;
 impl GetTemplateSummaryInputSerializer {
@@ -2677,7 +5006,7 @@ impl GetTemplateSummaryInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetTemplateSummaryOutput {
     /// <p>The capabilities found within the template. If your template contains IAM resources, you must specify the CAPABILITY_IAM or CAPABILITY_NAMED_IAM value for this parameter when you use the <a>CreateStack</a> or <a>UpdateStack</a> actions with your template; otherwise, those actions return an InsufficientCapabilities error.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>The list of resources that generated the values in the <code>Capabilities</code> response element.</p>
     pub capabilities_reason: Option<String>,
     /// <p>A list of the transforms that are declared in the template.</p>
@@ -2694,10 +5023,10 @@ pub struct GetTemplateSummaryOutput {
     pub version: Option<String>,
 }
 
-struct GetTemplateSummaryOutputDeserializer;
+pub(crate) struct GetTemplateSummaryOutputDeserializer;
 impl GetTemplateSummaryOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetTemplateSummaryOutput, XmlParseError> {
@@ -2750,10 +5079,10 @@ impl GetTemplateSummaryOutputDeserializer {
         )
     }
 }
-struct ImportsDeserializer;
+pub(crate) struct ImportsDeserializer;
 impl ImportsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -2767,10 +5096,13 @@ impl ImportsDeserializer {
         })
     }
 }
-struct KeyDeserializer;
+pub(crate) struct KeyDeserializer;
 impl KeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2778,21 +5110,27 @@ impl KeyDeserializer {
         Ok(obj)
     }
 }
-struct LastUpdatedTimeDeserializer;
+pub(crate) struct LastUpdatedTimeDeserializer;
 impl LastUpdatedTimeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct LimitNameDeserializer;
+pub(crate) struct LimitNameDeserializer;
 impl LimitNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2800,10 +5138,13 @@ impl LimitNameDeserializer {
         Ok(obj)
     }
 }
-struct LimitValueDeserializer;
+pub(crate) struct LimitValueDeserializer;
 impl LimitValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2820,6 +5161,44 @@ pub struct ListChangeSetsInput {
     pub stack_name: String,
 }
 
+impl ListChangeSetsInput {
+    /// Returns a builder for `ListChangeSetsInput`, checking its required members when built.
+    pub fn builder() -> ListChangeSetsInputBuilder {
+        ListChangeSetsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListChangeSetsInput`](struct.ListChangeSetsInput.html).
+#[derive(Default, Debug)]
+pub struct ListChangeSetsInputBuilder {
+    next_token: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl ListChangeSetsInputBuilder {
+    /// Sets the [`next_token`](struct.ListChangeSetsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.ListChangeSetsInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListChangeSetsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListChangeSetsInput, ::rusoto_core::BuildError> {
+        Ok(ListChangeSetsInput {
+            next_token: self.next_token,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListChangeSetsInput` contents to a `SignedRequest`.
 struct ListChangeSetsInputSerializer;
 impl ListChangeSetsInputSerializer {
@@ -2845,10 +5224,10 @@ pub struct ListChangeSetsOutput {
     pub summaries: Option<Vec<ChangeSetSummary>>,
 }
 
-struct ListChangeSetsOutputDeserializer;
+pub(crate) struct ListChangeSetsOutputDeserializer;
 impl ListChangeSetsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListChangeSetsOutput, XmlParseError> {
@@ -2874,6 +5253,34 @@ pub struct ListExportsInput {
     pub next_token: Option<String>,
 }
 
+impl ListExportsInput {
+    /// Returns a builder for `ListExportsInput`, checking its required members when built.
+    pub fn builder() -> ListExportsInputBuilder {
+        ListExportsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListExportsInput`](struct.ListExportsInput.html).
+#[derive(Default, Debug)]
+pub struct ListExportsInputBuilder {
+    next_token: Option<String>,
+}
+
+impl ListExportsInputBuilder {
+    /// Sets the [`next_token`](struct.ListExportsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListExportsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListExportsInput, ::rusoto_core::BuildError> {
+        Ok(ListExportsInput {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListExportsInput` contents to a `SignedRequest`.
 struct ListExportsInputSerializer;
 impl ListExportsInputSerializer {
@@ -2897,10 +5304,10 @@ pub struct ListExportsOutput {
     pub next_token: Option<String>,
 }
 
-struct ListExportsOutputDeserializer;
+pub(crate) struct ListExportsOutputDeserializer;
 impl ListExportsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListExportsOutput, XmlParseError> {
@@ -2928,6 +5335,44 @@ pub struct ListImportsInput {
     pub next_token: Option<String>,
 }
 
+impl ListImportsInput {
+    /// Returns a builder for `ListImportsInput`, checking its required members when built.
+    pub fn builder() -> ListImportsInputBuilder {
+        ListImportsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListImportsInput`](struct.ListImportsInput.html).
+#[derive(Default, Debug)]
+pub struct ListImportsInputBuilder {
+    export_name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListImportsInputBuilder {
+    /// Sets the [`export_name`](struct.ListImportsInput.html#structfield.export_name) member.
+    pub fn export_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.export_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListImportsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListImportsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListImportsInput, ::rusoto_core::BuildError> {
+        Ok(ListImportsInput {
+            export_name: self
+                .export_name
+                .ok_or(::rusoto_core::BuildError::missing("export_name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListImportsInput` contents to a `SignedRequest`.
 struct ListImportsInputSerializer;
 impl ListImportsInputSerializer {
@@ -2952,10 +5397,10 @@ pub struct ListImportsOutput {
     pub next_token: Option<String>,
 }
 
-struct ListImportsOutputDeserializer;
+pub(crate) struct ListImportsOutputDeserializer;
 impl ListImportsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListImportsOutput, XmlParseError> {
@@ -2989,6 +5434,70 @@ pub struct ListStackInstancesInput {
     pub stack_set_name: String,
 }
 
+impl ListStackInstancesInput {
+    /// Returns a builder for `ListStackInstancesInput`, checking its required members when built.
+    pub fn builder() -> ListStackInstancesInputBuilder {
+        ListStackInstancesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStackInstancesInput`](struct.ListStackInstancesInput.html).
+#[derive(Default, Debug)]
+pub struct ListStackInstancesInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    stack_instance_account: Option<String>,
+    stack_instance_region: Option<String>,
+    stack_set_name: Option<String>,
+}
+
+impl ListStackInstancesInputBuilder {
+    /// Sets the [`max_results`](struct.ListStackInstancesInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListStackInstancesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_instance_account`](struct.ListStackInstancesInput.html#structfield.stack_instance_account) member.
+    pub fn stack_instance_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_instance_account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_instance_region`](struct.ListStackInstancesInput.html#structfield.stack_instance_region) member.
+    pub fn stack_instance_region<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_instance_region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_set_name`](struct.ListStackInstancesInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStackInstancesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListStackInstancesInput, ::rusoto_core::BuildError> {
+        Ok(ListStackInstancesInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            stack_instance_account: self.stack_instance_account,
+            stack_instance_region: self.stack_instance_region,
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListStackInstancesInput` contents to a `SignedRequest`.
 struct ListStackInstancesInputSerializer;
 impl ListStackInstancesInputSerializer {
@@ -3031,10 +5540,10 @@ pub struct ListStackInstancesOutput {
     pub summaries: Option<Vec<StackInstanceSummary>>,
 }
 
-struct ListStackInstancesOutputDeserializer;
+pub(crate) struct ListStackInstancesOutputDeserializer;
 impl ListStackInstancesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListStackInstancesOutput, XmlParseError> {
@@ -3068,6 +5577,46 @@ pub struct ListStackResourcesInput {
     pub stack_name: String,
 }
 
+impl ListStackResourcesInput {
+    /// Returns a builder for `ListStackResourcesInput`, checking its required members when built.
+    pub fn builder() -> ListStackResourcesInputBuilder {
+        ListStackResourcesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStackResourcesInput`](struct.ListStackResourcesInput.html).
+#[derive(Default, Debug)]
+pub struct ListStackResourcesInputBuilder {
+    next_token: Option<String>,
+    stack_name: Option<String>,
+}
+
+impl ListStackResourcesInputBuilder {
+    /// Sets the [`next_token`](struct.ListStackResourcesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.ListStackResourcesInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStackResourcesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListStackResourcesInput, ::rusoto_core::BuildError> {
+        Ok(ListStackResourcesInput {
+            next_token: self.next_token,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListStackResourcesInput` contents to a `SignedRequest`.
 struct ListStackResourcesInputSerializer;
 impl ListStackResourcesInputSerializer {
@@ -3093,10 +5642,10 @@ pub struct ListStackResourcesOutput {
     pub stack_resource_summaries: Option<Vec<StackResourceSummary>>,
 }
 
-struct ListStackResourcesOutputDeserializer;
+pub(crate) struct ListStackResourcesOutputDeserializer;
 impl ListStackResourcesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListStackResourcesOutput, XmlParseError> {
@@ -3136,6 +5685,64 @@ pub struct ListStackSetOperationResultsInput {
     pub stack_set_name: String,
 }
 
+impl ListStackSetOperationResultsInput {
+    /// Returns a builder for `ListStackSetOperationResultsInput`, checking its required members when built.
+    pub fn builder() -> ListStackSetOperationResultsInputBuilder {
+        ListStackSetOperationResultsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStackSetOperationResultsInput`](struct.ListStackSetOperationResultsInput.html).
+#[derive(Default, Debug)]
+pub struct ListStackSetOperationResultsInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    operation_id: Option<String>,
+    stack_set_name: Option<String>,
+}
+
+impl ListStackSetOperationResultsInputBuilder {
+    /// Sets the [`max_results`](struct.ListStackSetOperationResultsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListStackSetOperationResultsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation_id`](struct.ListStackSetOperationResultsInput.html#structfield.operation_id) member.
+    pub fn operation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_set_name`](struct.ListStackSetOperationResultsInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStackSetOperationResultsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListStackSetOperationResultsInput, ::rusoto_core::BuildError> {
+        Ok(ListStackSetOperationResultsInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            operation_id: self
+                .operation_id
+                .ok_or(::rusoto_core::BuildError::missing("operation_id"))?,
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListStackSetOperationResultsInput` contents to a `SignedRequest`.
 struct ListStackSetOperationResultsInputSerializer;
 impl ListStackSetOperationResultsInputSerializer {
@@ -3167,10 +5774,10 @@ pub struct ListStackSetOperationResultsOutput {
     pub summaries: Option<Vec<StackSetOperationResultSummary>>,
 }
 
-struct ListStackSetOperationResultsOutputDeserializer;
+pub(crate) struct ListStackSetOperationResultsOutputDeserializer;
 impl ListStackSetOperationResultsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListStackSetOperationResultsOutput, XmlParseError> {
@@ -3208,6 +5815,54 @@ pub struct ListStackSetOperationsInput {
     pub stack_set_name: String,
 }
 
+impl ListStackSetOperationsInput {
+    /// Returns a builder for `ListStackSetOperationsInput`, checking its required members when built.
+    pub fn builder() -> ListStackSetOperationsInputBuilder {
+        ListStackSetOperationsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStackSetOperationsInput`](struct.ListStackSetOperationsInput.html).
+#[derive(Default, Debug)]
+pub struct ListStackSetOperationsInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    stack_set_name: Option<String>,
+}
+
+impl ListStackSetOperationsInputBuilder {
+    /// Sets the [`max_results`](struct.ListStackSetOperationsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListStackSetOperationsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_set_name`](struct.ListStackSetOperationsInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStackSetOperationsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListStackSetOperationsInput, ::rusoto_core::BuildError> {
+        Ok(ListStackSetOperationsInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListStackSetOperationsInput` contents to a `SignedRequest`.
 struct ListStackSetOperationsInputSerializer;
 impl ListStackSetOperationsInputSerializer {
@@ -3238,10 +5893,10 @@ pub struct ListStackSetOperationsOutput {
     pub summaries: Option<Vec<StackSetOperationSummary>>,
 }
 
-struct ListStackSetOperationsOutputDeserializer;
+pub(crate) struct ListStackSetOperationsOutputDeserializer;
 impl ListStackSetOperationsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListStackSetOperationsOutput, XmlParseError> {
@@ -3276,7 +5931,51 @@ pub struct ListStackSetsInput {
     /// <p>If the previous paginated request didn't return all of the remaining results, the response object's <code>NextToken</code> parameter value is set to a token. To retrieve the next set of results, call <code>ListStackSets</code> again and assign that token to the request object's <code>NextToken</code> parameter. If there are no remaining results, the previous response object's <code>NextToken</code> parameter is set to <code>null</code>.</p>
     pub next_token: Option<String>,
     /// <p>The status of the stack sets that you want to get summary information about.</p>
-    pub status: Option<String>,
+    pub status: Option<StackSetStatus>,
+}
+
+impl ListStackSetsInput {
+    /// Returns a builder for `ListStackSetsInput`, checking its required members when built.
+    pub fn builder() -> ListStackSetsInputBuilder {
+        ListStackSetsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStackSetsInput`](struct.ListStackSetsInput.html).
+#[derive(Default, Debug)]
+pub struct ListStackSetsInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    status: Option<StackSetStatus>,
+}
+
+impl ListStackSetsInputBuilder {
+    /// Sets the [`max_results`](struct.ListStackSetsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListStackSetsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListStackSetsInput.html#structfield.status) member.
+    pub fn status<T: Into<StackSetStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStackSetsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListStackSetsInput, ::rusoto_core::BuildError> {
+        Ok(ListStackSetsInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            status: self.status,
+        })
+    }
 }
 
 /// Serialize `ListStackSetsInput` contents to a `SignedRequest`.
@@ -3308,10 +6007,10 @@ pub struct ListStackSetsOutput {
     pub summaries: Option<Vec<StackSetSummary>>,
 }
 
-struct ListStackSetsOutputDeserializer;
+pub(crate) struct ListStackSetsOutputDeserializer;
 impl ListStackSetsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListStackSetsOutput, XmlParseError> {
@@ -3337,7 +6036,43 @@ pub struct ListStacksInput {
     /// <p>A string that identifies the next page of stacks that you want to retrieve.</p>
     pub next_token: Option<String>,
     /// <p>Stack status to use as a filter. Specify one or more stack status codes to list only stacks with the specified status codes. For a complete list of stack status codes, see the <code>StackStatus</code> parameter of the <a>Stack</a> data type.</p>
-    pub stack_status_filter: Option<Vec<String>>,
+    pub stack_status_filter: Option<Vec<StackStatus>>,
+}
+
+impl ListStacksInput {
+    /// Returns a builder for `ListStacksInput`, checking its required members when built.
+    pub fn builder() -> ListStacksInputBuilder {
+        ListStacksInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStacksInput`](struct.ListStacksInput.html).
+#[derive(Default, Debug)]
+pub struct ListStacksInputBuilder {
+    next_token: Option<String>,
+    stack_status_filter: Option<Vec<StackStatus>>,
+}
+
+impl ListStacksInputBuilder {
+    /// Sets the [`next_token`](struct.ListStacksInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_status_filter`](struct.ListStacksInput.html#structfield.stack_status_filter) member.
+    pub fn stack_status_filter<T: Into<Vec<StackStatus>>>(mut self, value: T) -> Self {
+        self.stack_status_filter = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStacksInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListStacksInput, ::rusoto_core::BuildError> {
+        Ok(ListStacksInput {
+            next_token: self.next_token,
+            stack_status_filter: self.stack_status_filter,
+        })
+    }
 }
 
 /// Serialize `ListStacksInput` contents to a `SignedRequest`.
@@ -3371,10 +6106,10 @@ pub struct ListStacksOutput {
     pub stack_summaries: Option<Vec<StackSummary>>,
 }
 
-struct ListStacksOutputDeserializer;
+pub(crate) struct ListStacksOutputDeserializer;
 impl ListStacksOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListStacksOutput, XmlParseError> {
@@ -3394,10 +6129,13 @@ impl ListStacksOutputDeserializer {
         })
     }
 }
-struct LogicalResourceIdDeserializer;
+pub(crate) struct LogicalResourceIdDeserializer;
 impl LogicalResourceIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3417,10 +6155,13 @@ impl LogicalResourceIdsSerializer {
     }
 }
 
-struct MaxConcurrentCountDeserializer;
+pub(crate) struct MaxConcurrentCountDeserializer;
 impl MaxConcurrentCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3428,10 +6169,13 @@ impl MaxConcurrentCountDeserializer {
         Ok(obj)
     }
 }
-struct MaxConcurrentPercentageDeserializer;
+pub(crate) struct MaxConcurrentPercentageDeserializer;
 impl MaxConcurrentPercentageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3439,10 +6183,13 @@ impl MaxConcurrentPercentageDeserializer {
         Ok(obj)
     }
 }
-struct MetadataDeserializer;
+pub(crate) struct MetadataDeserializer;
 impl MetadataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3450,10 +6197,13 @@ impl MetadataDeserializer {
         Ok(obj)
     }
 }
-struct MonitoringTimeInMinutesDeserializer;
+pub(crate) struct MonitoringTimeInMinutesDeserializer;
 impl MonitoringTimeInMinutesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3461,10 +6211,13 @@ impl MonitoringTimeInMinutesDeserializer {
         Ok(obj)
     }
 }
-struct NextTokenDeserializer;
+pub(crate) struct NextTokenDeserializer;
 impl NextTokenDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3472,10 +6225,13 @@ impl NextTokenDeserializer {
         Ok(obj)
     }
 }
-struct NoEchoDeserializer;
+pub(crate) struct NoEchoDeserializer;
 impl NoEchoDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3483,10 +6239,13 @@ impl NoEchoDeserializer {
         Ok(obj)
     }
 }
-struct NotificationARNDeserializer;
+pub(crate) struct NotificationARNDeserializer;
 impl NotificationARNDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3494,10 +6253,10 @@ impl NotificationARNDeserializer {
         Ok(obj)
     }
 }
-struct NotificationARNsDeserializer;
+pub(crate) struct NotificationARNsDeserializer;
 impl NotificationARNsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -3523,25 +6282,91 @@ impl NotificationARNsSerializer {
     }
 }
 
-/// <p>The Output data type.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct Output {
-    /// <p>User defined description associated with the output.</p>
-    pub description: Option<String>,
-    /// <p>The name of the export associated with the output.</p>
-    pub export_name: Option<String>,
-    /// <p>The key associated with the output.</p>
-    pub output_key: Option<String>,
-    /// <p>The value associated with the output.</p>
-    pub output_value: Option<String>,
+/// <p>Determines what action will be taken if stack creation fails. This must be one of: DO_NOTHING, ROLLBACK, or DELETE. You can specify either <code>OnFailure</code> or <code>DisableRollback</code>, but not both.</p> <p>Default: <code>ROLLBACK</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OnFailure {
+    /// <p><code>DO_NOTHING</code></p>
+    DoNothing,
+    /// <p><code>ROLLBACK</code></p>
+    Rollback,
+    /// <p><code>DELETE</code></p>
+    Delete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-struct OutputDeserializer;
-impl OutputDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Output, XmlParseError> {
-        deserialize_elements::<_, Output, _>(tag_name, stack, |name, stack, obj| {
-            match name {
+impl OnFailure {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OnFailure::DoNothing => "DO_NOTHING",
+            OnFailure::Rollback => "ROLLBACK",
+            OnFailure::Delete => "DELETE",
+            OnFailure::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OnFailure {
+    fn from(value: &'a str) -> OnFailure {
+        match value {
+            "DO_NOTHING" => OnFailure::DoNothing,
+            "ROLLBACK" => OnFailure::Rollback,
+            "DELETE" => OnFailure::Delete,
+            _ => OnFailure::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OnFailure {
+    fn from(value: String) -> OnFailure {
+        OnFailure::from(value.as_str())
+    }
+}
+
+impl From<OnFailure> for String {
+    fn from(value: OnFailure) -> String {
+        match value {
+            OnFailure::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OnFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OnFailure {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// <p>The Output data type.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Output {
+    /// <p>User defined description associated with the output.</p>
+    pub description: Option<String>,
+    /// <p>The name of the export associated with the output.</p>
+    pub export_name: Option<String>,
+    /// <p>The key associated with the output.</p>
+    pub output_key: Option<String>,
+    /// <p>The value associated with the output.</p>
+    pub output_value: Option<String>,
+}
+
+pub(crate) struct OutputDeserializer;
+impl OutputDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Output, XmlParseError> {
+        deserialize_elements::<_, Output, _>(tag_name, stack, |name, stack, obj| {
+            match name {
                 "Description" => {
                     obj.description =
                         Some(DescriptionDeserializer::deserialize("Description", stack)?);
@@ -3563,10 +6388,13 @@ impl OutputDeserializer {
         })
     }
 }
-struct OutputKeyDeserializer;
+pub(crate) struct OutputKeyDeserializer;
 impl OutputKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3574,10 +6402,13 @@ impl OutputKeyDeserializer {
         Ok(obj)
     }
 }
-struct OutputValueDeserializer;
+pub(crate) struct OutputValueDeserializer;
 impl OutputValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3585,10 +6416,10 @@ impl OutputValueDeserializer {
         Ok(obj)
     }
 }
-struct OutputsDeserializer;
+pub(crate) struct OutputsDeserializer;
 impl OutputsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Output>, XmlParseError> {
@@ -3615,10 +6446,10 @@ pub struct Parameter {
     pub use_previous_value: Option<bool>,
 }
 
-struct ParameterDeserializer;
+pub(crate) struct ParameterDeserializer;
 impl ParameterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Parameter, XmlParseError> {
@@ -3686,10 +6517,10 @@ pub struct ParameterConstraints {
     pub allowed_values: Option<Vec<String>>,
 }
 
-struct ParameterConstraintsDeserializer;
+pub(crate) struct ParameterConstraintsDeserializer;
 impl ParameterConstraintsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ParameterConstraints, XmlParseError> {
@@ -3723,10 +6554,10 @@ pub struct ParameterDeclaration {
     pub parameter_type: Option<String>,
 }
 
-struct ParameterDeclarationDeserializer;
+pub(crate) struct ParameterDeclarationDeserializer;
 impl ParameterDeclarationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ParameterDeclaration, XmlParseError> {
@@ -3770,10 +6601,10 @@ impl ParameterDeclarationDeserializer {
         })
     }
 }
-struct ParameterDeclarationsDeserializer;
+pub(crate) struct ParameterDeclarationsDeserializer;
 impl ParameterDeclarationsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ParameterDeclaration>, XmlParseError> {
@@ -3789,10 +6620,13 @@ impl ParameterDeclarationsDeserializer {
         })
     }
 }
-struct ParameterKeyDeserializer;
+pub(crate) struct ParameterKeyDeserializer;
 impl ParameterKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3800,10 +6634,13 @@ impl ParameterKeyDeserializer {
         Ok(obj)
     }
 }
-struct ParameterTypeDeserializer;
+pub(crate) struct ParameterTypeDeserializer;
 impl ParameterTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3811,10 +6648,13 @@ impl ParameterTypeDeserializer {
         Ok(obj)
     }
 }
-struct ParameterValueDeserializer;
+pub(crate) struct ParameterValueDeserializer;
 impl ParameterValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3822,10 +6662,10 @@ impl ParameterValueDeserializer {
         Ok(obj)
     }
 }
-struct ParametersDeserializer;
+pub(crate) struct ParametersDeserializer;
 impl ParametersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Parameter>, XmlParseError> {
@@ -3851,10 +6691,13 @@ impl ParametersSerializer {
     }
 }
 
-struct PhysicalResourceIdDeserializer;
+pub(crate) struct PhysicalResourceIdDeserializer;
 impl PhysicalResourceIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3862,10 +6705,10 @@ impl PhysicalResourceIdDeserializer {
         Ok(obj)
     }
 }
-struct PhysicalResourceIdContextDeserializer;
+pub(crate) struct PhysicalResourceIdContextDeserializer;
 impl PhysicalResourceIdContextDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PhysicalResourceIdContextKeyValuePair>, XmlParseError> {
@@ -3892,10 +6735,10 @@ pub struct PhysicalResourceIdContextKeyValuePair {
     pub value: String,
 }
 
-struct PhysicalResourceIdContextKeyValuePairDeserializer;
+pub(crate) struct PhysicalResourceIdContextKeyValuePairDeserializer;
 impl PhysicalResourceIdContextKeyValuePairDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PhysicalResourceIdContextKeyValuePair, XmlParseError> {
@@ -3917,10 +6760,13 @@ impl PhysicalResourceIdContextKeyValuePairDeserializer {
         )
     }
 }
-struct PropertiesDeserializer;
+pub(crate) struct PropertiesDeserializer;
 impl PropertiesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3929,53 +6775,63 @@ impl PropertiesDeserializer {
     }
 }
 /// <p>Information about a resource property whose actual value differs from its expected value, as defined in the stack template and any values specified as template parameters. These will be present only for resources whose <code>StackResourceDriftStatus</code> is <code>MODIFIED</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PropertyDifference {
     /// <p>The actual property value of the resource property.</p>
     pub actual_value: String,
     /// <p><p>The type of property difference.</p> <ul> <li> <p> <code>ADD</code>: A value has been added to a resource property that is an array or list data type.</p> </li> <li> <p> <code>REMOVE</code>: The property has been removed from the current resource configuration.</p> </li> <li> <p> <code>NOT_EQUAL</code>: The current property value differs from its expected value (as defined in the stack template and any values specified as template parameters).</p> </li> </ul></p>
-    pub difference_type: String,
+    pub difference_type: DifferenceType,
     /// <p>The expected property value of the resource property, as defined in the stack template and any values specified as template parameters.</p>
     pub expected_value: String,
     /// <p>The fully-qualified path to the resource property.</p>
     pub property_path: String,
 }
 
-struct PropertyDifferenceDeserializer;
+pub(crate) struct PropertyDifferenceDeserializer;
 impl PropertyDifferenceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PropertyDifference, XmlParseError> {
-        deserialize_elements::<_, PropertyDifference, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "ActualValue" => {
-                    obj.actual_value =
-                        PropertyValueDeserializer::deserialize("ActualValue", stack)?;
-                }
-                "DifferenceType" => {
-                    obj.difference_type =
-                        DifferenceTypeDeserializer::deserialize("DifferenceType", stack)?;
-                }
-                "ExpectedValue" => {
-                    obj.expected_value =
-                        PropertyValueDeserializer::deserialize("ExpectedValue", stack)?;
-                }
-                "PropertyPath" => {
-                    obj.property_path =
-                        PropertyPathDeserializer::deserialize("PropertyPath", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            PropertyDifference {
+                actual_value: Default::default(),
+                difference_type: DifferenceType::Unknown(String::new()),
+                expected_value: Default::default(),
+                property_path: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "ActualValue" => {
+                        obj.actual_value =
+                            PropertyValueDeserializer::deserialize("ActualValue", stack)?;
+                    }
+                    "DifferenceType" => {
+                        obj.difference_type =
+                            DifferenceTypeDeserializer::deserialize("DifferenceType", stack)?;
+                    }
+                    "ExpectedValue" => {
+                        obj.expected_value =
+                            PropertyValueDeserializer::deserialize("ExpectedValue", stack)?;
+                    }
+                    "PropertyPath" => {
+                        obj.property_path =
+                            PropertyPathDeserializer::deserialize("PropertyPath", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct PropertyDifferencesDeserializer;
+pub(crate) struct PropertyDifferencesDeserializer;
 impl PropertyDifferencesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PropertyDifference>, XmlParseError> {
@@ -3991,10 +6847,13 @@ impl PropertyDifferencesDeserializer {
         })
     }
 }
-struct PropertyNameDeserializer;
+pub(crate) struct PropertyNameDeserializer;
 impl PropertyNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4002,10 +6861,13 @@ impl PropertyNameDeserializer {
         Ok(obj)
     }
 }
-struct PropertyPathDeserializer;
+pub(crate) struct PropertyPathDeserializer;
 impl PropertyPathDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4013,10 +6875,13 @@ impl PropertyPathDeserializer {
         Ok(obj)
     }
 }
-struct PropertyValueDeserializer;
+pub(crate) struct PropertyValueDeserializer;
 impl PropertyValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4024,10 +6889,13 @@ impl PropertyValueDeserializer {
         Ok(obj)
     }
 }
-struct ReasonDeserializer;
+pub(crate) struct ReasonDeserializer;
 impl ReasonDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4035,10 +6903,13 @@ impl ReasonDeserializer {
         Ok(obj)
     }
 }
-struct RegionDeserializer;
+pub(crate) struct RegionDeserializer;
 impl RegionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4046,10 +6917,10 @@ impl RegionDeserializer {
         Ok(obj)
     }
 }
-struct RegionListDeserializer;
+pub(crate) struct RegionListDeserializer;
 impl RegionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -4075,44 +6946,258 @@ impl RegionListSerializer {
     }
 }
 
-struct ReplacementDeserializer;
+pub(crate) struct ReplacementDeserializer;
 impl ReplacementDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Replacement, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct RequiresRecreationDeserializer;
+/// <p>For the <code>Modify</code> action, indicates whether AWS CloudFormation will replace the resource by creating a new one and deleting the old one. This value depends on the value of the <code>RequiresRecreation</code> property in the <code>ResourceTargetDefinition</code> structure. For example, if the <code>RequiresRecreation</code> field is <code>Always</code> and the <code>Evaluation</code> field is <code>Static</code>, <code>Replacement</code> is <code>True</code>. If the <code>RequiresRecreation</code> field is <code>Always</code> and the <code>Evaluation</code> field is <code>Dynamic</code>, <code>Replacement</code> is <code>Conditionally</code>.</p> <p>If you have multiple changes with different <code>RequiresRecreation</code> values, the <code>Replacement</code> value depends on the change with the most impact. A <code>RequiresRecreation</code> value of <code>Always</code> has the most impact, followed by <code>Conditionally</code>, and then <code>Never</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Replacement {
+    /// <p><code>True</code></p>
+    True,
+    /// <p><code>False</code></p>
+    False,
+    /// <p><code>Conditional</code></p>
+    Conditional,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Replacement {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Replacement::True => "True",
+            Replacement::False => "False",
+            Replacement::Conditional => "Conditional",
+            Replacement::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Replacement {
+    fn from(value: &'a str) -> Replacement {
+        match value {
+            "True" => Replacement::True,
+            "False" => Replacement::False,
+            "Conditional" => Replacement::Conditional,
+            _ => Replacement::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Replacement {
+    fn from(value: String) -> Replacement {
+        Replacement::from(value.as_str())
+    }
+}
+
+impl From<Replacement> for String {
+    fn from(value: Replacement) -> String {
+        match value {
+            Replacement::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Replacement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Replacement {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct RequiresRecreationDeserializer;
 impl RequiresRecreationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<RequiresRecreation, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ResourceAttributeDeserializer;
+/// <p>If the <code>Attribute</code> value is <code>Properties</code>, indicates whether a change to this property causes the resource to be recreated. The value can be <code>Never</code>, <code>Always</code>, or <code>Conditionally</code>. To determine the conditions for a <code>Conditionally</code> recreation, see the update behavior for that <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-template-resource-type-ref.html">property</a> in the AWS CloudFormation User Guide.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RequiresRecreation {
+    /// <p><code>Never</code></p>
+    Never,
+    /// <p><code>Conditionally</code></p>
+    Conditionally,
+    /// <p><code>Always</code></p>
+    Always,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RequiresRecreation {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RequiresRecreation::Never => "Never",
+            RequiresRecreation::Conditionally => "Conditionally",
+            RequiresRecreation::Always => "Always",
+            RequiresRecreation::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RequiresRecreation {
+    fn from(value: &'a str) -> RequiresRecreation {
+        match value {
+            "Never" => RequiresRecreation::Never,
+            "Conditionally" => RequiresRecreation::Conditionally,
+            "Always" => RequiresRecreation::Always,
+            _ => RequiresRecreation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RequiresRecreation {
+    fn from(value: String) -> RequiresRecreation {
+        RequiresRecreation::from(value.as_str())
+    }
+}
+
+impl From<RequiresRecreation> for String {
+    fn from(value: RequiresRecreation) -> String {
+        match value {
+            RequiresRecreation::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RequiresRecreation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RequiresRecreation {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ResourceAttributeDeserializer;
 impl ResourceAttributeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ResourceAttribute, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>For the <code>Modify</code> action, indicates which resource attribute is triggering this update, such as a change in the resource attribute's <code>Metadata</code>, <code>Properties</code>, or <code>Tags</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceAttribute {
+    /// <p><code>Properties</code></p>
+    Properties,
+    /// <p><code>Metadata</code></p>
+    Metadata,
+    /// <p><code>CreationPolicy</code></p>
+    CreationPolicy,
+    /// <p><code>UpdatePolicy</code></p>
+    UpdatePolicy,
+    /// <p><code>DeletionPolicy</code></p>
+    DeletionPolicy,
+    /// <p><code>UpdateReplacePolicy</code></p>
+    UpdateReplacePolicy,
+    /// <p><code>Tags</code></p>
+    Tags,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceAttribute {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceAttribute::Properties => "Properties",
+            ResourceAttribute::Metadata => "Metadata",
+            ResourceAttribute::CreationPolicy => "CreationPolicy",
+            ResourceAttribute::UpdatePolicy => "UpdatePolicy",
+            ResourceAttribute::DeletionPolicy => "DeletionPolicy",
+            ResourceAttribute::UpdateReplacePolicy => "UpdateReplacePolicy",
+            ResourceAttribute::Tags => "Tags",
+            ResourceAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceAttribute {
+    fn from(value: &'a str) -> ResourceAttribute {
+        match value {
+            "Properties" => ResourceAttribute::Properties,
+            "Metadata" => ResourceAttribute::Metadata,
+            "CreationPolicy" => ResourceAttribute::CreationPolicy,
+            "UpdatePolicy" => ResourceAttribute::UpdatePolicy,
+            "DeletionPolicy" => ResourceAttribute::DeletionPolicy,
+            "UpdateReplacePolicy" => ResourceAttribute::UpdateReplacePolicy,
+            "Tags" => ResourceAttribute::Tags,
+            _ => ResourceAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceAttribute {
+    fn from(value: String) -> ResourceAttribute {
+        ResourceAttribute::from(value.as_str())
+    }
+}
+
+impl From<ResourceAttribute> for String {
+    fn from(value: ResourceAttribute) -> String {
+        match value {
+            ResourceAttribute::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>The <code>ResourceChange</code> structure describes the resource and the action that AWS CloudFormation will perform on it if you execute this change set.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ResourceChange {
     /// <p>The action that AWS CloudFormation takes on the resource, such as <code>Add</code> (adds a new resource), <code>Modify</code> (changes a resource), or <code>Remove</code> (deletes a resource).</p>
-    pub action: Option<String>,
+    pub action: Option<ChangeAction>,
     /// <p>For the <code>Modify</code> action, a list of <code>ResourceChangeDetail</code> structures that describes the changes that AWS CloudFormation will make to the resource. </p>
     pub details: Option<Vec<ResourceChangeDetail>>,
     /// <p>The resource's logical ID, which is defined in the stack's template.</p>
@@ -4120,17 +7205,17 @@ pub struct ResourceChange {
     /// <p>The resource's physical ID (resource name). Resources that you are adding don't have physical IDs because they haven't been created.</p>
     pub physical_resource_id: Option<String>,
     /// <p>For the <code>Modify</code> action, indicates whether AWS CloudFormation will replace the resource by creating a new one and deleting the old one. This value depends on the value of the <code>RequiresRecreation</code> property in the <code>ResourceTargetDefinition</code> structure. For example, if the <code>RequiresRecreation</code> field is <code>Always</code> and the <code>Evaluation</code> field is <code>Static</code>, <code>Replacement</code> is <code>True</code>. If the <code>RequiresRecreation</code> field is <code>Always</code> and the <code>Evaluation</code> field is <code>Dynamic</code>, <code>Replacement</code> is <code>Conditionally</code>.</p> <p>If you have multiple changes with different <code>RequiresRecreation</code> values, the <code>Replacement</code> value depends on the change with the most impact. A <code>RequiresRecreation</code> value of <code>Always</code> has the most impact, followed by <code>Conditionally</code>, and then <code>Never</code>.</p>
-    pub replacement: Option<String>,
+    pub replacement: Option<Replacement>,
     /// <p>The type of AWS CloudFormation resource, such as <code>AWS::S3::Bucket</code>.</p>
     pub resource_type: Option<String>,
     /// <p>For the <code>Modify</code> action, indicates which resource attribute is triggering this update, such as a change in the resource attribute's <code>Metadata</code>, <code>Properties</code>, or <code>Tags</code>.</p>
-    pub scope: Option<Vec<String>>,
+    pub scope: Option<Vec<ResourceAttribute>>,
 }
 
-struct ResourceChangeDeserializer;
+pub(crate) struct ResourceChangeDeserializer;
 impl ResourceChangeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourceChange, XmlParseError> {
@@ -4183,17 +7268,17 @@ pub struct ResourceChangeDetail {
     /// <p>The identity of the entity that triggered this change. This entity is a member of the group that is specified by the <code>ChangeSource</code> field. For example, if you modified the value of the <code>KeyPairName</code> parameter, the <code>CausingEntity</code> is the name of the parameter (<code>KeyPairName</code>).</p> <p>If the <code>ChangeSource</code> value is <code>DirectModification</code>, no value is given for <code>CausingEntity</code>.</p>
     pub causing_entity: Option<String>,
     /// <p><p>The group to which the <code>CausingEntity</code> value belongs. There are five entity groups:</p> <ul> <li> <p> <code>ResourceReference</code> entities are <code>Ref</code> intrinsic functions that refer to resources in the template, such as <code>{ &quot;Ref&quot; : &quot;MyEC2InstanceResource&quot; }</code>.</p> </li> <li> <p> <code>ParameterReference</code> entities are <code>Ref</code> intrinsic functions that get template parameter values, such as <code>{ &quot;Ref&quot; : &quot;MyPasswordParameter&quot; }</code>.</p> </li> <li> <p> <code>ResourceAttribute</code> entities are <code>Fn::GetAtt</code> intrinsic functions that get resource attribute values, such as <code>{ &quot;Fn::GetAtt&quot; : [ &quot;MyEC2InstanceResource&quot;, &quot;PublicDnsName&quot; ] }</code>.</p> </li> <li> <p> <code>DirectModification</code> entities are changes that are made directly to the template.</p> </li> <li> <p> <code>Automatic</code> entities are <code>AWS::CloudFormation::Stack</code> resource types, which are also known as nested stacks. If you made no changes to the <code>AWS::CloudFormation::Stack</code> resource, AWS CloudFormation sets the <code>ChangeSource</code> to <code>Automatic</code> because the nested stack&#39;s template might have changed. Changes to a nested stack&#39;s template aren&#39;t visible to AWS CloudFormation until you run an update on the parent stack.</p> </li> </ul></p>
-    pub change_source: Option<String>,
+    pub change_source: Option<ChangeSource>,
     /// <p>Indicates whether AWS CloudFormation can determine the target value, and whether the target value will change before you execute a change set.</p> <p>For <code>Static</code> evaluations, AWS CloudFormation can determine that the target value will change, and its value. For example, if you directly modify the <code>InstanceType</code> property of an EC2 instance, AWS CloudFormation knows that this property value will change, and its value, so this is a <code>Static</code> evaluation.</p> <p>For <code>Dynamic</code> evaluations, cannot determine the target value because it depends on the result of an intrinsic function, such as a <code>Ref</code> or <code>Fn::GetAtt</code> intrinsic function, when the stack is updated. For example, if your template includes a reference to a resource that is conditionally recreated, the value of the reference (the physical ID of the resource) might change, depending on if the resource is recreated. If the resource is recreated, it will have a new physical ID, so all references to that resource will also be updated.</p>
-    pub evaluation: Option<String>,
+    pub evaluation: Option<EvaluationType>,
     /// <p>A <code>ResourceTargetDefinition</code> structure that describes the field that AWS CloudFormation will change and whether the resource will be recreated.</p>
     pub target: Option<ResourceTargetDefinition>,
 }
 
-struct ResourceChangeDetailDeserializer;
+pub(crate) struct ResourceChangeDetailDeserializer;
 impl ResourceChangeDetailDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourceChangeDetail, XmlParseError> {
@@ -4228,10 +7313,10 @@ impl ResourceChangeDetailDeserializer {
         })
     }
 }
-struct ResourceChangeDetailsDeserializer;
+pub(crate) struct ResourceChangeDetailsDeserializer;
 impl ResourceChangeDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ResourceChangeDetail>, XmlParseError> {
@@ -4247,10 +7332,13 @@ impl ResourceChangeDetailsDeserializer {
         })
     }
 }
-struct ResourcePropertiesDeserializer;
+pub(crate) struct ResourcePropertiesDeserializer;
 impl ResourcePropertiesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4258,21 +7346,225 @@ impl ResourcePropertiesDeserializer {
         Ok(obj)
     }
 }
-struct ResourceStatusDeserializer;
+/// <p>The status of the signal, which is either success or failure. A failure signal causes AWS CloudFormation to immediately fail the stack creation or update.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceSignalStatus {
+    /// <p><code>SUCCESS</code></p>
+    Success,
+    /// <p><code>FAILURE</code></p>
+    Failure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceSignalStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceSignalStatus::Success => "SUCCESS",
+            ResourceSignalStatus::Failure => "FAILURE",
+            ResourceSignalStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceSignalStatus {
+    fn from(value: &'a str) -> ResourceSignalStatus {
+        match value {
+            "SUCCESS" => ResourceSignalStatus::Success,
+            "FAILURE" => ResourceSignalStatus::Failure,
+            _ => ResourceSignalStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceSignalStatus {
+    fn from(value: String) -> ResourceSignalStatus {
+        ResourceSignalStatus::from(value.as_str())
+    }
+}
+
+impl From<ResourceSignalStatus> for String {
+    fn from(value: ResourceSignalStatus) -> String {
+        match value {
+            ResourceSignalStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceSignalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceSignalStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ResourceStatusDeserializer;
 impl ResourceStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ResourceStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ResourceStatusReasonDeserializer;
+/// <p>Current status of the resource.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceStatus {
+    /// <p><code>CREATE_IN_PROGRESS</code></p>
+    CreateInProgress,
+    /// <p><code>CREATE_FAILED</code></p>
+    CreateFailed,
+    /// <p><code>CREATE_COMPLETE</code></p>
+    CreateComplete,
+    /// <p><code>DELETE_IN_PROGRESS</code></p>
+    DeleteInProgress,
+    /// <p><code>DELETE_FAILED</code></p>
+    DeleteFailed,
+    /// <p><code>DELETE_COMPLETE</code></p>
+    DeleteComplete,
+    /// <p><code>DELETE_SKIPPED</code></p>
+    DeleteSkipped,
+    /// <p><code>UPDATE_IN_PROGRESS</code></p>
+    UpdateInProgress,
+    /// <p><code>UPDATE_FAILED</code></p>
+    UpdateFailed,
+    /// <p><code>UPDATE_COMPLETE</code></p>
+    UpdateComplete,
+    /// <p><code>IMPORT_FAILED</code></p>
+    ImportFailed,
+    /// <p><code>IMPORT_COMPLETE</code></p>
+    ImportComplete,
+    /// <p><code>IMPORT_IN_PROGRESS</code></p>
+    ImportInProgress,
+    /// <p><code>IMPORT_ROLLBACK_IN_PROGRESS</code></p>
+    ImportRollbackInProgress,
+    /// <p><code>IMPORT_ROLLBACK_FAILED</code></p>
+    ImportRollbackFailed,
+    /// <p><code>IMPORT_ROLLBACK_COMPLETE</code></p>
+    ImportRollbackComplete,
+    /// <p><code>UPDATE_ROLLBACK_IN_PROGRESS</code></p>
+    UpdateRollbackInProgress,
+    /// <p><code>UPDATE_ROLLBACK_COMPLETE</code></p>
+    UpdateRollbackComplete,
+    /// <p><code>UPDATE_ROLLBACK_FAILED</code></p>
+    UpdateRollbackFailed,
+    /// <p><code>ROLLBACK_IN_PROGRESS</code></p>
+    RollbackInProgress,
+    /// <p><code>ROLLBACK_COMPLETE</code></p>
+    RollbackComplete,
+    /// <p><code>ROLLBACK_FAILED</code></p>
+    RollbackFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            ResourceStatus::CreateFailed => "CREATE_FAILED",
+            ResourceStatus::CreateComplete => "CREATE_COMPLETE",
+            ResourceStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            ResourceStatus::DeleteFailed => "DELETE_FAILED",
+            ResourceStatus::DeleteComplete => "DELETE_COMPLETE",
+            ResourceStatus::DeleteSkipped => "DELETE_SKIPPED",
+            ResourceStatus::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            ResourceStatus::UpdateFailed => "UPDATE_FAILED",
+            ResourceStatus::UpdateComplete => "UPDATE_COMPLETE",
+            ResourceStatus::ImportFailed => "IMPORT_FAILED",
+            ResourceStatus::ImportComplete => "IMPORT_COMPLETE",
+            ResourceStatus::ImportInProgress => "IMPORT_IN_PROGRESS",
+            ResourceStatus::ImportRollbackInProgress => "IMPORT_ROLLBACK_IN_PROGRESS",
+            ResourceStatus::ImportRollbackFailed => "IMPORT_ROLLBACK_FAILED",
+            ResourceStatus::ImportRollbackComplete => "IMPORT_ROLLBACK_COMPLETE",
+            ResourceStatus::UpdateRollbackInProgress => "UPDATE_ROLLBACK_IN_PROGRESS",
+            ResourceStatus::UpdateRollbackComplete => "UPDATE_ROLLBACK_COMPLETE",
+            ResourceStatus::UpdateRollbackFailed => "UPDATE_ROLLBACK_FAILED",
+            ResourceStatus::RollbackInProgress => "ROLLBACK_IN_PROGRESS",
+            ResourceStatus::RollbackComplete => "ROLLBACK_COMPLETE",
+            ResourceStatus::RollbackFailed => "ROLLBACK_FAILED",
+            ResourceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceStatus {
+    fn from(value: &'a str) -> ResourceStatus {
+        match value {
+            "CREATE_IN_PROGRESS" => ResourceStatus::CreateInProgress,
+            "CREATE_FAILED" => ResourceStatus::CreateFailed,
+            "CREATE_COMPLETE" => ResourceStatus::CreateComplete,
+            "DELETE_IN_PROGRESS" => ResourceStatus::DeleteInProgress,
+            "DELETE_FAILED" => ResourceStatus::DeleteFailed,
+            "DELETE_COMPLETE" => ResourceStatus::DeleteComplete,
+            "DELETE_SKIPPED" => ResourceStatus::DeleteSkipped,
+            "UPDATE_IN_PROGRESS" => ResourceStatus::UpdateInProgress,
+            "UPDATE_FAILED" => ResourceStatus::UpdateFailed,
+            "UPDATE_COMPLETE" => ResourceStatus::UpdateComplete,
+            "IMPORT_FAILED" => ResourceStatus::ImportFailed,
+            "IMPORT_COMPLETE" => ResourceStatus::ImportComplete,
+            "IMPORT_IN_PROGRESS" => ResourceStatus::ImportInProgress,
+            "IMPORT_ROLLBACK_IN_PROGRESS" => ResourceStatus::ImportRollbackInProgress,
+            "IMPORT_ROLLBACK_FAILED" => ResourceStatus::ImportRollbackFailed,
+            "IMPORT_ROLLBACK_COMPLETE" => ResourceStatus::ImportRollbackComplete,
+            "UPDATE_ROLLBACK_IN_PROGRESS" => ResourceStatus::UpdateRollbackInProgress,
+            "UPDATE_ROLLBACK_COMPLETE" => ResourceStatus::UpdateRollbackComplete,
+            "UPDATE_ROLLBACK_FAILED" => ResourceStatus::UpdateRollbackFailed,
+            "ROLLBACK_IN_PROGRESS" => ResourceStatus::RollbackInProgress,
+            "ROLLBACK_COMPLETE" => ResourceStatus::RollbackComplete,
+            "ROLLBACK_FAILED" => ResourceStatus::RollbackFailed,
+            _ => ResourceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceStatus {
+    fn from(value: String) -> ResourceStatus {
+        ResourceStatus::from(value.as_str())
+    }
+}
+
+impl From<ResourceStatus> for String {
+    fn from(value: ResourceStatus) -> String {
+        match value {
+            ResourceStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ResourceStatusReasonDeserializer;
 impl ResourceStatusReasonDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4284,17 +7576,17 @@ impl ResourceStatusReasonDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ResourceTargetDefinition {
     /// <p>Indicates which resource attribute is triggering this update, such as a change in the resource attribute's <code>Metadata</code>, <code>Properties</code>, or <code>Tags</code>.</p>
-    pub attribute: Option<String>,
+    pub attribute: Option<ResourceAttribute>,
     /// <p>If the <code>Attribute</code> value is <code>Properties</code>, the name of the property. For all other attributes, the value is null.</p>
     pub name: Option<String>,
     /// <p>If the <code>Attribute</code> value is <code>Properties</code>, indicates whether a change to this property causes the resource to be recreated. The value can be <code>Never</code>, <code>Always</code>, or <code>Conditionally</code>. To determine the conditions for a <code>Conditionally</code> recreation, see the update behavior for that <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-template-resource-type-ref.html">property</a> in the AWS CloudFormation User Guide.</p>
-    pub requires_recreation: Option<String>,
+    pub requires_recreation: Option<RequiresRecreation>,
 }
 
-struct ResourceTargetDefinitionDeserializer;
+pub(crate) struct ResourceTargetDefinitionDeserializer;
 impl ResourceTargetDefinitionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourceTargetDefinition, XmlParseError> {
@@ -4326,10 +7618,13 @@ impl ResourceTargetDefinitionDeserializer {
         )
     }
 }
-struct ResourceTypeDeserializer;
+pub(crate) struct ResourceTypeDeserializer;
 impl ResourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4337,10 +7632,10 @@ impl ResourceTypeDeserializer {
         Ok(obj)
     }
 }
-struct ResourceTypesDeserializer;
+pub(crate) struct ResourceTypesDeserializer;
 impl ResourceTypesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -4388,10 +7683,13 @@ impl RetainResourcesSerializer {
     }
 }
 
-struct RetainStacksNullableDeserializer;
+pub(crate) struct RetainStacksNullableDeserializer;
 impl RetainStacksNullableDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4399,10 +7697,13 @@ impl RetainStacksNullableDeserializer {
         Ok(obj)
     }
 }
-struct RoleARNDeserializer;
+pub(crate) struct RoleARNDeserializer;
 impl RoleARNDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4419,10 +7720,10 @@ pub struct RollbackConfiguration {
     pub rollback_triggers: Option<Vec<RollbackTrigger>>,
 }
 
-struct RollbackConfigurationDeserializer;
+pub(crate) struct RollbackConfigurationDeserializer;
 impl RollbackConfigurationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RollbackConfiguration, XmlParseError> {
@@ -4481,10 +7782,10 @@ pub struct RollbackTrigger {
     pub type_: String,
 }
 
-struct RollbackTriggerDeserializer;
+pub(crate) struct RollbackTriggerDeserializer;
 impl RollbackTriggerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RollbackTrigger, XmlParseError> {
@@ -4517,10 +7818,10 @@ impl RollbackTriggerSerializer {
     }
 }
 
-struct RollbackTriggersDeserializer;
+pub(crate) struct RollbackTriggersDeserializer;
 impl RollbackTriggersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<RollbackTrigger>, XmlParseError> {
@@ -4546,13 +7847,13 @@ impl RollbackTriggersSerializer {
     }
 }
 
-struct ScopeDeserializer;
+pub(crate) struct ScopeDeserializer;
 impl ScopeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<ResourceAttribute>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "member" {
                 obj.push(ResourceAttributeDeserializer::deserialize("member", stack)?);
@@ -4574,6 +7875,52 @@ pub struct SetStackPolicyInput {
     pub stack_policy_url: Option<String>,
 }
 
+impl SetStackPolicyInput {
+    /// Returns a builder for `SetStackPolicyInput`, checking its required members when built.
+    pub fn builder() -> SetStackPolicyInputBuilder {
+        SetStackPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetStackPolicyInput`](struct.SetStackPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct SetStackPolicyInputBuilder {
+    stack_name: Option<String>,
+    stack_policy_body: Option<String>,
+    stack_policy_url: Option<String>,
+}
+
+impl SetStackPolicyInputBuilder {
+    /// Sets the [`stack_name`](struct.SetStackPolicyInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_policy_body`](struct.SetStackPolicyInput.html#structfield.stack_policy_body) member.
+    pub fn stack_policy_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_policy_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_policy_url`](struct.SetStackPolicyInput.html#structfield.stack_policy_url) member.
+    pub fn stack_policy_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_policy_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetStackPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetStackPolicyInput, ::rusoto_core::BuildError> {
+        Ok(SetStackPolicyInput {
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+            stack_policy_body: self.stack_policy_body,
+            stack_policy_url: self.stack_policy_url,
+        })
+    }
+}
+
 /// Serialize `SetStackPolicyInput` contents to a `SignedRequest`.
 struct SetStackPolicyInputSerializer;
 impl SetStackPolicyInputSerializer {
@@ -4594,18 +7941,78 @@ impl SetStackPolicyInputSerializer {
 }
 
 /// <p>The input for the <a>SignalResource</a> action.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SignalResourceInput {
     /// <p>The logical ID of the resource that you want to signal. The logical ID is the name of the resource that given in the template.</p>
     pub logical_resource_id: String,
     /// <p>The stack name or unique stack ID that includes the resource that you want to signal.</p>
     pub stack_name: String,
     /// <p>The status of the signal, which is either success or failure. A failure signal causes AWS CloudFormation to immediately fail the stack creation or update.</p>
-    pub status: String,
+    pub status: ResourceSignalStatus,
     /// <p>A unique ID of the signal. When you signal Amazon EC2 instances or Auto Scaling groups, specify the instance ID that you are signaling as the unique ID. If you send multiple signals to a single resource (such as signaling a wait condition), each signal requires a different unique ID.</p>
     pub unique_id: String,
 }
 
+impl SignalResourceInput {
+    /// Returns a builder for `SignalResourceInput`, checking its required members when built.
+    pub fn builder() -> SignalResourceInputBuilder {
+        SignalResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`SignalResourceInput`](struct.SignalResourceInput.html).
+#[derive(Default, Debug)]
+pub struct SignalResourceInputBuilder {
+    logical_resource_id: Option<String>,
+    stack_name: Option<String>,
+    status: Option<ResourceSignalStatus>,
+    unique_id: Option<String>,
+}
+
+impl SignalResourceInputBuilder {
+    /// Sets the [`logical_resource_id`](struct.SignalResourceInput.html#structfield.logical_resource_id) member.
+    pub fn logical_resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.logical_resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.SignalResourceInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.SignalResourceInput.html#structfield.status) member.
+    pub fn status<T: Into<ResourceSignalStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unique_id`](struct.SignalResourceInput.html#structfield.unique_id) member.
+    pub fn unique_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.unique_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SignalResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SignalResourceInput, ::rusoto_core::BuildError> {
+        Ok(SignalResourceInput {
+            logical_resource_id: self
+                .logical_resource_id
+                .ok_or(::rusoto_core::BuildError::missing("logical_resource_id"))?,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+            status: self
+                .status
+                .ok_or(::rusoto_core::BuildError::missing("status"))?,
+            unique_id: self
+                .unique_id
+                .ok_or(::rusoto_core::BuildError::missing("unique_id"))?,
+        })
+    }
+}
+
 /// Serialize `SignalResourceInput` contents to a `SignedRequest`.
 struct SignalResourceInputSerializer;
 impl SignalResourceInputSerializer {
@@ -4626,16 +8033,16 @@ impl SignalResourceInputSerializer {
 }
 
 /// <p>The Stack data type.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Stack {
     /// <p>The capabilities allowed in the stack.</p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>The unique ID of the change set.</p>
     pub change_set_id: Option<String>,
     /// <p>The time at which the stack was created.</p>
-    pub creation_time: String,
+    pub creation_time: IsoTimestamp,
     /// <p>The time the stack was deleted.</p>
-    pub deletion_time: Option<String>,
+    pub deletion_time: Option<IsoTimestamp>,
     /// <p>A user-defined description associated with the stack.</p>
     pub description: Option<String>,
     /// <p><p>Boolean to enable or disable rollback on stack creation failures:</p> <ul> <li> <p> <code>true</code>: disable rollback</p> </li> <li> <p> <code>false</code>: enable rollback</p> </li> </ul></p>
@@ -4645,7 +8052,7 @@ pub struct Stack {
     /// <p>Whether termination protection is enabled for the stack.</p> <p> For <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-nested-stacks.html">nested stacks</a>, termination protection is set on the root stack and cannot be changed directly on the nested stack. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-protect-stacks.html">Protecting a Stack From Being Deleted</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     pub enable_termination_protection: Option<bool>,
     /// <p>The time the stack was last updated. This field will only be returned if the stack has been updated at least once.</p>
-    pub last_updated_time: Option<String>,
+    pub last_updated_time: Option<IsoTimestamp>,
     /// <p>SNS topic ARNs to which stack related events are published.</p>
     pub notification_ar_ns: Option<Vec<String>>,
     /// <p>A list of output structures.</p>
@@ -4665,7 +8072,7 @@ pub struct Stack {
     /// <p>The name associated with the stack.</p>
     pub stack_name: String,
     /// <p>Current status of the stack.</p>
-    pub stack_status: String,
+    pub stack_status: StackStatus,
     /// <p>Success/failure message associated with the stack status.</p>
     pub stack_status_reason: Option<String>,
     /// <p>A list of <code>Tag</code>s that specify information about the stack.</p>
@@ -4674,127 +8081,163 @@ pub struct Stack {
     pub timeout_in_minutes: Option<i64>,
 }
 
-struct StackDeserializer;
+pub(crate) struct StackDeserializer;
 impl StackDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Stack, XmlParseError> {
-        deserialize_elements::<_, Stack, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Capabilities" => {
-                    obj.capabilities.get_or_insert(vec![]).extend(
-                        CapabilitiesDeserializer::deserialize("Capabilities", stack)?,
-                    );
-                }
-                "ChangeSetId" => {
-                    obj.change_set_id =
-                        Some(ChangeSetIdDeserializer::deserialize("ChangeSetId", stack)?);
-                }
-                "CreationTime" => {
-                    obj.creation_time =
-                        CreationTimeDeserializer::deserialize("CreationTime", stack)?;
-                }
-                "DeletionTime" => {
-                    obj.deletion_time = Some(DeletionTimeDeserializer::deserialize(
-                        "DeletionTime",
-                        stack,
-                    )?);
-                }
-                "Description" => {
-                    obj.description =
-                        Some(DescriptionDeserializer::deserialize("Description", stack)?);
-                }
-                "DisableRollback" => {
-                    obj.disable_rollback = Some(DisableRollbackDeserializer::deserialize(
-                        "DisableRollback",
-                        stack,
-                    )?);
-                }
-                "DriftInformation" => {
-                    obj.drift_information = Some(StackDriftInformationDeserializer::deserialize(
-                        "DriftInformation",
-                        stack,
-                    )?);
-                }
-                "EnableTerminationProtection" => {
-                    obj.enable_termination_protection =
-                        Some(EnableTerminationProtectionDeserializer::deserialize(
-                            "EnableTerminationProtection",
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Stack, XmlParseError> {
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            Stack {
+                capabilities: Default::default(),
+                change_set_id: Default::default(),
+                creation_time: Default::default(),
+                deletion_time: Default::default(),
+                description: Default::default(),
+                disable_rollback: Default::default(),
+                drift_information: Default::default(),
+                enable_termination_protection: Default::default(),
+                last_updated_time: Default::default(),
+                notification_ar_ns: Default::default(),
+                outputs: Default::default(),
+                parameters: Default::default(),
+                parent_id: Default::default(),
+                role_arn: Default::default(),
+                rollback_configuration: Default::default(),
+                root_id: Default::default(),
+                stack_id: Default::default(),
+                stack_name: Default::default(),
+                stack_status: StackStatus::Unknown(String::new()),
+                stack_status_reason: Default::default(),
+                tags: Default::default(),
+                timeout_in_minutes: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Capabilities" => {
+                        obj.capabilities.get_or_insert(vec![]).extend(
+                            CapabilitiesDeserializer::deserialize("Capabilities", stack)?,
+                        );
+                    }
+                    "ChangeSetId" => {
+                        obj.change_set_id =
+                            Some(ChangeSetIdDeserializer::deserialize("ChangeSetId", stack)?);
+                    }
+                    "CreationTime" => {
+                        obj.creation_time =
+                            CreationTimeDeserializer::deserialize("CreationTime", stack)?;
+                    }
+                    "DeletionTime" => {
+                        obj.deletion_time = Some(DeletionTimeDeserializer::deserialize(
+                            "DeletionTime",
                             stack,
                         )?);
-                }
-                "LastUpdatedTime" => {
-                    obj.last_updated_time = Some(LastUpdatedTimeDeserializer::deserialize(
-                        "LastUpdatedTime",
-                        stack,
-                    )?);
-                }
-                "NotificationARNs" => {
-                    obj.notification_ar_ns.get_or_insert(vec![]).extend(
-                        NotificationARNsDeserializer::deserialize("NotificationARNs", stack)?,
-                    );
-                }
-                "Outputs" => {
-                    obj.outputs
-                        .get_or_insert(vec![])
-                        .extend(OutputsDeserializer::deserialize("Outputs", stack)?);
-                }
-                "Parameters" => {
-                    obj.parameters
-                        .get_or_insert(vec![])
-                        .extend(ParametersDeserializer::deserialize("Parameters", stack)?);
-                }
-                "ParentId" => {
-                    obj.parent_id = Some(StackIdDeserializer::deserialize("ParentId", stack)?);
-                }
-                "RoleARN" => {
-                    obj.role_arn = Some(RoleARNDeserializer::deserialize("RoleARN", stack)?);
-                }
-                "RollbackConfiguration" => {
-                    obj.rollback_configuration =
-                        Some(RollbackConfigurationDeserializer::deserialize(
-                            "RollbackConfiguration",
+                    }
+                    "Description" => {
+                        obj.description =
+                            Some(DescriptionDeserializer::deserialize("Description", stack)?);
+                    }
+                    "DisableRollback" => {
+                        obj.disable_rollback = Some(DisableRollbackDeserializer::deserialize(
+                            "DisableRollback",
                             stack,
                         )?);
+                    }
+                    "DriftInformation" => {
+                        obj.drift_information =
+                            Some(StackDriftInformationDeserializer::deserialize(
+                                "DriftInformation",
+                                stack,
+                            )?);
+                    }
+                    "EnableTerminationProtection" => {
+                        obj.enable_termination_protection =
+                            Some(EnableTerminationProtectionDeserializer::deserialize(
+                                "EnableTerminationProtection",
+                                stack,
+                            )?);
+                    }
+                    "LastUpdatedTime" => {
+                        obj.last_updated_time = Some(LastUpdatedTimeDeserializer::deserialize(
+                            "LastUpdatedTime",
+                            stack,
+                        )?);
+                    }
+                    "NotificationARNs" => {
+                        obj.notification_ar_ns.get_or_insert(vec![]).extend(
+                            NotificationARNsDeserializer::deserialize("NotificationARNs", stack)?,
+                        );
+                    }
+                    "Outputs" => {
+                        obj.outputs
+                            .get_or_insert(vec![])
+                            .extend(OutputsDeserializer::deserialize("Outputs", stack)?);
+                    }
+                    "Parameters" => {
+                        obj.parameters
+                            .get_or_insert(vec![])
+                            .extend(ParametersDeserializer::deserialize("Parameters", stack)?);
+                    }
+                    "ParentId" => {
+                        obj.parent_id = Some(StackIdDeserializer::deserialize("ParentId", stack)?);
+                    }
+                    "RoleARN" => {
+                        obj.role_arn = Some(RoleARNDeserializer::deserialize("RoleARN", stack)?);
+                    }
+                    "RollbackConfiguration" => {
+                        obj.rollback_configuration =
+                            Some(RollbackConfigurationDeserializer::deserialize(
+                                "RollbackConfiguration",
+                                stack,
+                            )?);
+                    }
+                    "RootId" => {
+                        obj.root_id = Some(StackIdDeserializer::deserialize("RootId", stack)?);
+                    }
+                    "StackId" => {
+                        obj.stack_id = Some(StackIdDeserializer::deserialize("StackId", stack)?);
+                    }
+                    "StackName" => {
+                        obj.stack_name = StackNameDeserializer::deserialize("StackName", stack)?;
+                    }
+                    "StackStatus" => {
+                        obj.stack_status =
+                            StackStatusDeserializer::deserialize("StackStatus", stack)?;
+                    }
+                    "StackStatusReason" => {
+                        obj.stack_status_reason = Some(StackStatusReasonDeserializer::deserialize(
+                            "StackStatusReason",
+                            stack,
+                        )?);
+                    }
+                    "Tags" => {
+                        obj.tags
+                            .get_or_insert(vec![])
+                            .extend(TagsDeserializer::deserialize("Tags", stack)?);
+                    }
+                    "TimeoutInMinutes" => {
+                        obj.timeout_in_minutes = Some(TimeoutMinutesDeserializer::deserialize(
+                            "TimeoutInMinutes",
+                            stack,
+                        )?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                "RootId" => {
-                    obj.root_id = Some(StackIdDeserializer::deserialize("RootId", stack)?);
-                }
-                "StackId" => {
-                    obj.stack_id = Some(StackIdDeserializer::deserialize("StackId", stack)?);
-                }
-                "StackName" => {
-                    obj.stack_name = StackNameDeserializer::deserialize("StackName", stack)?;
-                }
-                "StackStatus" => {
-                    obj.stack_status = StackStatusDeserializer::deserialize("StackStatus", stack)?;
-                }
-                "StackStatusReason" => {
-                    obj.stack_status_reason = Some(StackStatusReasonDeserializer::deserialize(
-                        "StackStatusReason",
-                        stack,
-                    )?);
-                }
-                "Tags" => {
-                    obj.tags
-                        .get_or_insert(vec![])
-                        .extend(TagsDeserializer::deserialize("Tags", stack)?);
-                }
-                "TimeoutInMinutes" => {
-                    obj.timeout_in_minutes = Some(TimeoutMinutesDeserializer::deserialize(
-                        "TimeoutInMinutes",
-                        stack,
-                    )?);
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct StackDriftDetectionIdDeserializer;
+pub(crate) struct StackDriftDetectionIdDeserializer;
 impl StackDriftDetectionIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4802,21 +8245,90 @@ impl StackDriftDetectionIdDeserializer {
         Ok(obj)
     }
 }
-struct StackDriftDetectionStatusDeserializer;
+pub(crate) struct StackDriftDetectionStatusDeserializer;
 impl StackDriftDetectionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackDriftDetectionStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct StackDriftDetectionStatusReasonDeserializer;
+/// <p><p>The status of the stack drift detection operation.</p> <ul> <li> <p> <code>DETECTION<em>COMPLETE</code>: The stack drift detection operation has successfully completed for all resources in the stack that support drift detection. (Resources that do not currently support stack detection remain unchecked.)</p> <p>If you specified logical resource IDs for AWS CloudFormation to use as a filter for the stack drift detection operation, only the resources with those logical IDs are checked for drift.</p> </li> <li> <p> <code>DETECTION</em>FAILED</code>: The stack drift detection operation has failed for at least one resource in the stack. Results will be available for resources on which AWS CloudFormation successfully completed drift detection.</p> </li> <li> <p> <code>DETECTION<em>IN</em>PROGRESS</code>: The stack drift detection operation is currently in progress.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackDriftDetectionStatus {
+    /// <p><code>DETECTION_IN_PROGRESS</code></p>
+    DetectionInProgress,
+    /// <p><code>DETECTION_FAILED</code></p>
+    DetectionFailed,
+    /// <p><code>DETECTION_COMPLETE</code></p>
+    DetectionComplete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackDriftDetectionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackDriftDetectionStatus::DetectionInProgress => "DETECTION_IN_PROGRESS",
+            StackDriftDetectionStatus::DetectionFailed => "DETECTION_FAILED",
+            StackDriftDetectionStatus::DetectionComplete => "DETECTION_COMPLETE",
+            StackDriftDetectionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackDriftDetectionStatus {
+    fn from(value: &'a str) -> StackDriftDetectionStatus {
+        match value {
+            "DETECTION_IN_PROGRESS" => StackDriftDetectionStatus::DetectionInProgress,
+            "DETECTION_FAILED" => StackDriftDetectionStatus::DetectionFailed,
+            "DETECTION_COMPLETE" => StackDriftDetectionStatus::DetectionComplete,
+            _ => StackDriftDetectionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackDriftDetectionStatus {
+    fn from(value: String) -> StackDriftDetectionStatus {
+        StackDriftDetectionStatus::from(value.as_str())
+    }
+}
+
+impl From<StackDriftDetectionStatus> for String {
+    fn from(value: StackDriftDetectionStatus) -> String {
+        match value {
+            StackDriftDetectionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackDriftDetectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackDriftDetectionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct StackDriftDetectionStatusReasonDeserializer;
 impl StackDriftDetectionStatusReasonDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4825,58 +8337,70 @@ impl StackDriftDetectionStatusReasonDeserializer {
     }
 }
 /// <p>Contains information about whether the stack's actual configuration differs, or has <i>drifted</i>, from its expected configuration, as defined in the stack template and any values specified as template parameters. A stack is considered to have drifted if one or more of its resources have drifted.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StackDriftInformation {
     /// <p>Most recent time when a drift detection operation was initiated on the stack, or any of its individual resources that support drift detection.</p>
-    pub last_check_timestamp: Option<String>,
+    pub last_check_timestamp: Option<IsoTimestamp>,
     /// <p><p>Status of the stack&#39;s actual configuration compared to its expected template configuration. </p> <ul> <li> <p> <code>DRIFTED</code>: The stack differs from its expected template configuration. A stack is considered to have drifted if one or more of its resources have drifted.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the stack differs from its expected template configuration.</p> </li> <li> <p> <code>IN</em>SYNC</code>: The stack&#39;s actual configuration matches its expected template configuration.</p> </li> <li> <p> <code>UNKNOWN</code>: This value is reserved for future use.</p> </li> </ul></p>
-    pub stack_drift_status: String,
+    pub stack_drift_status: StackDriftStatus,
 }
 
-struct StackDriftInformationDeserializer;
+pub(crate) struct StackDriftInformationDeserializer;
 impl StackDriftInformationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackDriftInformation, XmlParseError> {
-        deserialize_elements::<_, StackDriftInformation, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "LastCheckTimestamp" => {
-                    obj.last_check_timestamp = Some(TimestampDeserializer::deserialize(
-                        "LastCheckTimestamp",
-                        stack,
-                    )?);
-                }
-                "StackDriftStatus" => {
-                    obj.stack_drift_status =
-                        StackDriftStatusDeserializer::deserialize("StackDriftStatus", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            StackDriftInformation {
+                last_check_timestamp: Default::default(),
+                stack_drift_status: StackDriftStatus::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "LastCheckTimestamp" => {
+                        obj.last_check_timestamp = Some(TimestampDeserializer::deserialize(
+                            "LastCheckTimestamp",
+                            stack,
+                        )?);
+                    }
+                    "StackDriftStatus" => {
+                        obj.stack_drift_status =
+                            StackDriftStatusDeserializer::deserialize("StackDriftStatus", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 /// <p>Contains information about whether the stack's actual configuration differs, or has <i>drifted</i>, from its expected configuration, as defined in the stack template and any values specified as template parameters. A stack is considered to have drifted if one or more of its resources have drifted.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StackDriftInformationSummary {
     /// <p>Most recent time when a drift detection operation was initiated on the stack, or any of its individual resources that support drift detection.</p>
-    pub last_check_timestamp: Option<String>,
+    pub last_check_timestamp: Option<IsoTimestamp>,
     /// <p><p>Status of the stack&#39;s actual configuration compared to its expected template configuration. </p> <ul> <li> <p> <code>DRIFTED</code>: The stack differs from its expected template configuration. A stack is considered to have drifted if one or more of its resources have drifted.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the stack differs from its expected template configuration.</p> </li> <li> <p> <code>IN</em>SYNC</code>: The stack&#39;s actual configuration matches its expected template configuration.</p> </li> <li> <p> <code>UNKNOWN</code>: This value is reserved for future use.</p> </li> </ul></p>
-    pub stack_drift_status: String,
+    pub stack_drift_status: StackDriftStatus,
 }
 
-struct StackDriftInformationSummaryDeserializer;
+pub(crate) struct StackDriftInformationSummaryDeserializer;
 impl StackDriftInformationSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackDriftInformationSummary, XmlParseError> {
-        deserialize_elements::<_, StackDriftInformationSummary, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            StackDriftInformationSummary {
+                last_check_timestamp: Default::default(),
+                stack_drift_status: StackDriftStatus::Unknown(String::new()),
+            },
             |name, stack, obj| {
                 match name {
                     "LastCheckTimestamp" => {
@@ -4896,17 +8420,87 @@ impl StackDriftInformationSummaryDeserializer {
         )
     }
 }
-struct StackDriftStatusDeserializer;
+pub(crate) struct StackDriftStatusDeserializer;
 impl StackDriftStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackDriftStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p><p>Status of the stack&#39;s actual configuration compared to its expected configuration. </p> <ul> <li> <p> <code>DRIFTED</code>: The stack differs from its expected template configuration. A stack is considered to have drifted if one or more of its resources have drifted.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the stack differs from its expected template configuration.</p> </li> <li> <p> <code>IN</em>SYNC</code>: The stack&#39;s actual configuration matches its expected template configuration.</p> </li> <li> <p> <code>UNKNOWN</code>: This value is reserved for future use.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackDriftStatus {
+    /// <p><code>DRIFTED</code> - The stack differs from its expected template configuration. A stack is considered to have drifted if one or more of its resources have drifted.</p>
+    Drifted,
+    /// <p><code>IN_SYNC</code></p>
+    InSync,
+    /// <p><code>UNKNOWN</code> - This value is reserved for future use.</p>
+    UnknownValue,
+    /// <p><code>NOT_CHECKED</code></p>
+    NotChecked,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackDriftStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackDriftStatus::Drifted => "DRIFTED",
+            StackDriftStatus::InSync => "IN_SYNC",
+            StackDriftStatus::UnknownValue => "UNKNOWN",
+            StackDriftStatus::NotChecked => "NOT_CHECKED",
+            StackDriftStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackDriftStatus {
+    fn from(value: &'a str) -> StackDriftStatus {
+        match value {
+            "DRIFTED" => StackDriftStatus::Drifted,
+            "IN_SYNC" => StackDriftStatus::InSync,
+            "UNKNOWN" => StackDriftStatus::UnknownValue,
+            "NOT_CHECKED" => StackDriftStatus::NotChecked,
+            _ => StackDriftStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackDriftStatus {
+    fn from(value: String) -> StackDriftStatus {
+        StackDriftStatus::from(value.as_str())
+    }
+}
+
+impl From<StackDriftStatus> for String {
+    fn from(value: StackDriftStatus) -> String {
+        match value {
+            StackDriftStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackDriftStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackDriftStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>The StackEvent data type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct StackEvent {
@@ -4921,7 +8515,7 @@ pub struct StackEvent {
     /// <p>BLOB of the properties used to create the resource.</p>
     pub resource_properties: Option<String>,
     /// <p>Current status of the resource.</p>
-    pub resource_status: Option<String>,
+    pub resource_status: Option<ResourceStatus>,
     /// <p>Success/failure message associated with the resource.</p>
     pub resource_status_reason: Option<String>,
     /// <p>Type of resource. (For more information, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-template-resource-type-ref.html"> AWS Resource Types Reference</a> in the AWS CloudFormation User Guide.)</p>
@@ -4931,13 +8525,13 @@ pub struct StackEvent {
     /// <p>The name associated with a stack.</p>
     pub stack_name: String,
     /// <p>Time the status was updated.</p>
-    pub timestamp: String,
+    pub timestamp: IsoTimestamp,
 }
 
-struct StackEventDeserializer;
+pub(crate) struct StackEventDeserializer;
 impl StackEventDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackEvent, XmlParseError> {
@@ -5004,10 +8598,10 @@ impl StackEventDeserializer {
         })
     }
 }
-struct StackEventsDeserializer;
+pub(crate) struct StackEventsDeserializer;
 impl StackEventsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<StackEvent>, XmlParseError> {
@@ -5021,10 +8615,13 @@ impl StackEventsDeserializer {
         })
     }
 }
-struct StackIdDeserializer;
+pub(crate) struct StackIdDeserializer;
 impl StackIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5046,15 +8643,15 @@ pub struct StackInstance {
     /// <p>The name or unique ID of the stack set that the stack instance is associated with.</p>
     pub stack_set_id: Option<String>,
     /// <p><p>The status of the stack instance, in terms of its synchronization with its associated stack set.</p> <ul> <li> <p> <code>INOPERABLE</code>: A <code>DeleteStackInstances</code> operation has failed and left the stack in an unstable state. Stacks in this state are excluded from further <code>UpdateStackSet</code> operations. You might need to perform a <code>DeleteStackInstances</code> operation, with <code>RetainStacks</code> set to <code>true</code>, to delete the stack instance, and then delete the stack manually.</p> </li> <li> <p> <code>OUTDATED</code>: The stack isn&#39;t currently up to date with the stack set because:</p> <ul> <li> <p>The associated stack failed during a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation. </p> </li> <li> <p>The stack was part of a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation that failed or was stopped before the stack was created or updated. </p> </li> </ul> </li> <li> <p> <code>CURRENT</code>: The stack is currently up to date with the stack set.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<StackInstanceStatus>,
     /// <p>The explanation for the specific status code that is assigned to this stack instance.</p>
     pub status_reason: Option<String>,
 }
 
-struct StackInstanceDeserializer;
+pub(crate) struct StackInstanceDeserializer;
 impl StackInstanceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackInstance, XmlParseError> {
@@ -5093,21 +8690,87 @@ impl StackInstanceDeserializer {
         })
     }
 }
-struct StackInstanceStatusDeserializer;
+pub(crate) struct StackInstanceStatusDeserializer;
 impl StackInstanceStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackInstanceStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct StackInstanceSummariesDeserializer;
+/// <p><p>The status of the stack instance, in terms of its synchronization with its associated stack set.</p> <ul> <li> <p> <code>INOPERABLE</code>: A <code>DeleteStackInstances</code> operation has failed and left the stack in an unstable state. Stacks in this state are excluded from further <code>UpdateStackSet</code> operations. You might need to perform a <code>DeleteStackInstances</code> operation, with <code>RetainStacks</code> set to <code>true</code>, to delete the stack instance, and then delete the stack manually.</p> </li> <li> <p> <code>OUTDATED</code>: The stack isn&#39;t currently up to date with the stack set because:</p> <ul> <li> <p>The associated stack failed during a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation. </p> </li> <li> <p>The stack was part of a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation that failed or was stopped before the stack was created or updated. </p> </li> </ul> </li> <li> <p> <code>CURRENT</code>: The stack is currently up to date with the stack set.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackInstanceStatus {
+    /// <p><code>CURRENT</code> - The stack is currently up to date with the stack set.</p>
+    Current,
+    /// <p><code>OUTDATED</code> - The stack isn&#39;t currently up to date with the stack set because:</p>
+    Outdated,
+    /// <p><code>INOPERABLE</code> - A <code>DeleteStackInstances</code> operation has failed and left the stack in an unstable state. Stacks in this state are excluded from further <code>UpdateStackSet</code> operations. You might need to perform a <code>DeleteStackInstances</code> operation, with <code>RetainStacks</code> set to <code>true</code>, to delete the stack instance, and then delete the stack manually.</p>
+    Inoperable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackInstanceStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackInstanceStatus::Current => "CURRENT",
+            StackInstanceStatus::Outdated => "OUTDATED",
+            StackInstanceStatus::Inoperable => "INOPERABLE",
+            StackInstanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackInstanceStatus {
+    fn from(value: &'a str) -> StackInstanceStatus {
+        match value {
+            "CURRENT" => StackInstanceStatus::Current,
+            "OUTDATED" => StackInstanceStatus::Outdated,
+            "INOPERABLE" => StackInstanceStatus::Inoperable,
+            _ => StackInstanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackInstanceStatus {
+    fn from(value: String) -> StackInstanceStatus {
+        StackInstanceStatus::from(value.as_str())
+    }
+}
+
+impl From<StackInstanceStatus> for String {
+    fn from(value: StackInstanceStatus) -> String {
+        match value {
+            StackInstanceStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackInstanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackInstanceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct StackInstanceSummariesDeserializer;
 impl StackInstanceSummariesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<StackInstanceSummary>, XmlParseError> {
@@ -5135,15 +8798,15 @@ pub struct StackInstanceSummary {
     /// <p>The name or unique ID of the stack set that the stack instance is associated with.</p>
     pub stack_set_id: Option<String>,
     /// <p><p>The status of the stack instance, in terms of its synchronization with its associated stack set.</p> <ul> <li> <p> <code>INOPERABLE</code>: A <code>DeleteStackInstances</code> operation has failed and left the stack in an unstable state. Stacks in this state are excluded from further <code>UpdateStackSet</code> operations. You might need to perform a <code>DeleteStackInstances</code> operation, with <code>RetainStacks</code> set to <code>true</code>, to delete the stack instance, and then delete the stack manually.</p> </li> <li> <p> <code>OUTDATED</code>: The stack isn&#39;t currently up to date with the stack set because:</p> <ul> <li> <p>The associated stack failed during a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation. </p> </li> <li> <p>The stack was part of a <code>CreateStackSet</code> or <code>UpdateStackSet</code> operation that failed or was stopped before the stack was created or updated. </p> </li> </ul> </li> <li> <p> <code>CURRENT</code>: The stack is currently up to date with the stack set.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<StackInstanceStatus>,
     /// <p>The explanation for the specific status code assigned to this stack instance.</p>
     pub status_reason: Option<String>,
 }
 
-struct StackInstanceSummaryDeserializer;
+pub(crate) struct StackInstanceSummaryDeserializer;
 impl StackInstanceSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackInstanceSummary, XmlParseError> {
@@ -5177,10 +8840,13 @@ impl StackInstanceSummaryDeserializer {
         })
     }
 }
-struct StackNameDeserializer;
+pub(crate) struct StackNameDeserializer;
 impl StackNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5188,10 +8854,13 @@ impl StackNameDeserializer {
         Ok(obj)
     }
 }
-struct StackPolicyBodyDeserializer;
+pub(crate) struct StackPolicyBodyDeserializer;
 impl StackPolicyBodyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5200,7 +8869,7 @@ impl StackPolicyBodyDeserializer {
     }
 }
 /// <p>The StackResource data type.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StackResource {
     /// <p>User defined description associated with the resource.</p>
     pub description: Option<String>,
@@ -5211,7 +8880,7 @@ pub struct StackResource {
     /// <p>The name or unique identifier that corresponds to a physical instance ID of a resource supported by AWS CloudFormation.</p>
     pub physical_resource_id: Option<String>,
     /// <p>Current status of the resource.</p>
-    pub resource_status: String,
+    pub resource_status: ResourceStatus,
     /// <p>Success/failure message associated with the resource.</p>
     pub resource_status_reason: Option<String>,
     /// <p>Type of resource. (For more information, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-template-resource-type-ref.html"> AWS Resource Types Reference</a> in the AWS CloudFormation User Guide.)</p>
@@ -5221,78 +8890,96 @@ pub struct StackResource {
     /// <p>The name associated with the stack.</p>
     pub stack_name: Option<String>,
     /// <p>Time the status was updated.</p>
-    pub timestamp: String,
+    pub timestamp: IsoTimestamp,
 }
 
-struct StackResourceDeserializer;
+pub(crate) struct StackResourceDeserializer;
 impl StackResourceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackResource, XmlParseError> {
-        deserialize_elements::<_, StackResource, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Description" => {
-                    obj.description =
-                        Some(DescriptionDeserializer::deserialize("Description", stack)?);
-                }
-                "DriftInformation" => {
-                    obj.drift_information =
-                        Some(StackResourceDriftInformationDeserializer::deserialize(
-                            "DriftInformation",
-                            stack,
-                        )?);
-                }
-                "LogicalResourceId" => {
-                    obj.logical_resource_id =
-                        LogicalResourceIdDeserializer::deserialize("LogicalResourceId", stack)?;
-                }
-                "PhysicalResourceId" => {
-                    obj.physical_resource_id = Some(PhysicalResourceIdDeserializer::deserialize(
-                        "PhysicalResourceId",
-                        stack,
-                    )?);
-                }
-                "ResourceStatus" => {
-                    obj.resource_status =
-                        ResourceStatusDeserializer::deserialize("ResourceStatus", stack)?;
-                }
-                "ResourceStatusReason" => {
-                    obj.resource_status_reason =
-                        Some(ResourceStatusReasonDeserializer::deserialize(
-                            "ResourceStatusReason",
-                            stack,
-                        )?);
-                }
-                "ResourceType" => {
-                    obj.resource_type =
-                        ResourceTypeDeserializer::deserialize("ResourceType", stack)?;
-                }
-                "StackId" => {
-                    obj.stack_id = Some(StackIdDeserializer::deserialize("StackId", stack)?);
-                }
-                "StackName" => {
-                    obj.stack_name = Some(StackNameDeserializer::deserialize("StackName", stack)?);
-                }
-                "Timestamp" => {
-                    obj.timestamp = TimestampDeserializer::deserialize("Timestamp", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            StackResource {
+                description: Default::default(),
+                drift_information: Default::default(),
+                logical_resource_id: Default::default(),
+                physical_resource_id: Default::default(),
+                resource_status: ResourceStatus::Unknown(String::new()),
+                resource_status_reason: Default::default(),
+                resource_type: Default::default(),
+                stack_id: Default::default(),
+                stack_name: Default::default(),
+                timestamp: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Description" => {
+                        obj.description =
+                            Some(DescriptionDeserializer::deserialize("Description", stack)?);
+                    }
+                    "DriftInformation" => {
+                        obj.drift_information =
+                            Some(StackResourceDriftInformationDeserializer::deserialize(
+                                "DriftInformation",
+                                stack,
+                            )?);
+                    }
+                    "LogicalResourceId" => {
+                        obj.logical_resource_id =
+                            LogicalResourceIdDeserializer::deserialize("LogicalResourceId", stack)?;
+                    }
+                    "PhysicalResourceId" => {
+                        obj.physical_resource_id =
+                            Some(PhysicalResourceIdDeserializer::deserialize(
+                                "PhysicalResourceId",
+                                stack,
+                            )?);
+                    }
+                    "ResourceStatus" => {
+                        obj.resource_status =
+                            ResourceStatusDeserializer::deserialize("ResourceStatus", stack)?;
+                    }
+                    "ResourceStatusReason" => {
+                        obj.resource_status_reason =
+                            Some(ResourceStatusReasonDeserializer::deserialize(
+                                "ResourceStatusReason",
+                                stack,
+                            )?);
+                    }
+                    "ResourceType" => {
+                        obj.resource_type =
+                            ResourceTypeDeserializer::deserialize("ResourceType", stack)?;
+                    }
+                    "StackId" => {
+                        obj.stack_id = Some(StackIdDeserializer::deserialize("StackId", stack)?);
+                    }
+                    "StackName" => {
+                        obj.stack_name =
+                            Some(StackNameDeserializer::deserialize("StackName", stack)?);
+                    }
+                    "Timestamp" => {
+                        obj.timestamp = TimestampDeserializer::deserialize("Timestamp", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 /// <p>Contains detailed information about the specified stack resource.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StackResourceDetail {
     /// <p>User defined description associated with the resource.</p>
     pub description: Option<String>,
     /// <p>Information about whether the resource's actual configuration differs, or has <i>drifted</i>, from its expected configuration, as defined in the stack template and any values specified as template parameters. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p>
     pub drift_information: Option<StackResourceDriftInformation>,
     /// <p>Time the status was updated.</p>
-    pub last_updated_timestamp: String,
+    pub last_updated_timestamp: IsoTimestamp,
     /// <p>The logical name of the resource specified in the template.</p>
     pub logical_resource_id: String,
     /// <p>The content of the <code>Metadata</code> attribute declared for the resource. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-attribute-metadata.html">Metadata Attribute</a> in the AWS CloudFormation User Guide.</p>
@@ -5300,7 +8987,7 @@ pub struct StackResourceDetail {
     /// <p>The name or unique identifier that corresponds to a physical instance ID of a resource supported by AWS CloudFormation.</p>
     pub physical_resource_id: Option<String>,
     /// <p>Current status of the resource.</p>
-    pub resource_status: String,
+    pub resource_status: ResourceStatus,
     /// <p>Success/failure message associated with the resource.</p>
     pub resource_status_reason: Option<String>,
     /// <p>Type of resource. ((For more information, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-template-resource-type-ref.html"> AWS Resource Types Reference</a> in the AWS CloudFormation User Guide.)</p>
@@ -5311,72 +8998,91 @@ pub struct StackResourceDetail {
     pub stack_name: Option<String>,
 }
 
-struct StackResourceDetailDeserializer;
+pub(crate) struct StackResourceDetailDeserializer;
 impl StackResourceDetailDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackResourceDetail, XmlParseError> {
-        deserialize_elements::<_, StackResourceDetail, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Description" => {
-                    obj.description =
-                        Some(DescriptionDeserializer::deserialize("Description", stack)?);
-                }
-                "DriftInformation" => {
-                    obj.drift_information =
-                        Some(StackResourceDriftInformationDeserializer::deserialize(
-                            "DriftInformation",
-                            stack,
-                        )?);
-                }
-                "LastUpdatedTimestamp" => {
-                    obj.last_updated_timestamp =
-                        TimestampDeserializer::deserialize("LastUpdatedTimestamp", stack)?;
-                }
-                "LogicalResourceId" => {
-                    obj.logical_resource_id =
-                        LogicalResourceIdDeserializer::deserialize("LogicalResourceId", stack)?;
-                }
-                "Metadata" => {
-                    obj.metadata = Some(MetadataDeserializer::deserialize("Metadata", stack)?);
-                }
-                "PhysicalResourceId" => {
-                    obj.physical_resource_id = Some(PhysicalResourceIdDeserializer::deserialize(
-                        "PhysicalResourceId",
-                        stack,
-                    )?);
-                }
-                "ResourceStatus" => {
-                    obj.resource_status =
-                        ResourceStatusDeserializer::deserialize("ResourceStatus", stack)?;
-                }
-                "ResourceStatusReason" => {
-                    obj.resource_status_reason =
-                        Some(ResourceStatusReasonDeserializer::deserialize(
-                            "ResourceStatusReason",
-                            stack,
-                        )?);
-                }
-                "ResourceType" => {
-                    obj.resource_type =
-                        ResourceTypeDeserializer::deserialize("ResourceType", stack)?;
-                }
-                "StackId" => {
-                    obj.stack_id = Some(StackIdDeserializer::deserialize("StackId", stack)?);
-                }
-                "StackName" => {
-                    obj.stack_name = Some(StackNameDeserializer::deserialize("StackName", stack)?);
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            StackResourceDetail {
+                description: Default::default(),
+                drift_information: Default::default(),
+                last_updated_timestamp: Default::default(),
+                logical_resource_id: Default::default(),
+                metadata: Default::default(),
+                physical_resource_id: Default::default(),
+                resource_status: ResourceStatus::Unknown(String::new()),
+                resource_status_reason: Default::default(),
+                resource_type: Default::default(),
+                stack_id: Default::default(),
+                stack_name: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Description" => {
+                        obj.description =
+                            Some(DescriptionDeserializer::deserialize("Description", stack)?);
+                    }
+                    "DriftInformation" => {
+                        obj.drift_information =
+                            Some(StackResourceDriftInformationDeserializer::deserialize(
+                                "DriftInformation",
+                                stack,
+                            )?);
+                    }
+                    "LastUpdatedTimestamp" => {
+                        obj.last_updated_timestamp =
+                            TimestampDeserializer::deserialize("LastUpdatedTimestamp", stack)?;
+                    }
+                    "LogicalResourceId" => {
+                        obj.logical_resource_id =
+                            LogicalResourceIdDeserializer::deserialize("LogicalResourceId", stack)?;
+                    }
+                    "Metadata" => {
+                        obj.metadata = Some(MetadataDeserializer::deserialize("Metadata", stack)?);
+                    }
+                    "PhysicalResourceId" => {
+                        obj.physical_resource_id =
+                            Some(PhysicalResourceIdDeserializer::deserialize(
+                                "PhysicalResourceId",
+                                stack,
+                            )?);
+                    }
+                    "ResourceStatus" => {
+                        obj.resource_status =
+                            ResourceStatusDeserializer::deserialize("ResourceStatus", stack)?;
+                    }
+                    "ResourceStatusReason" => {
+                        obj.resource_status_reason =
+                            Some(ResourceStatusReasonDeserializer::deserialize(
+                                "ResourceStatusReason",
+                                stack,
+                            )?);
+                    }
+                    "ResourceType" => {
+                        obj.resource_type =
+                            ResourceTypeDeserializer::deserialize("ResourceType", stack)?;
+                    }
+                    "StackId" => {
+                        obj.stack_id = Some(StackIdDeserializer::deserialize("StackId", stack)?);
+                    }
+                    "StackName" => {
+                        obj.stack_name =
+                            Some(StackNameDeserializer::deserialize("StackName", stack)?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 /// <p>Contains the drift information for a resource that has been checked for drift. This includes actual and expected property values for resources in which AWS CloudFormation has detected drift. Only resource properties explicitly defined in the stack template are checked for drift. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p> <p>Resources that do not currently support drift detection cannot be checked. For a list of resources that support drift detection, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>.</p> <p>Use <a>DetectStackResourceDrift</a> to detect drift on individual resources, or <a>DetectStackDrift</a> to detect drift on all resources in a given stack that support drift detection.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StackResourceDrift {
     /// <p>A JSON structure containing the actual property values of the stack resource.</p> <p>For resources whose <code>StackResourceDriftStatus</code> is <code>DELETED</code>, this structure will not be present. </p>
     pub actual_properties: Option<String>,
@@ -5395,97 +9101,121 @@ pub struct StackResourceDrift {
     /// <p>The ID of the stack.</p>
     pub stack_id: String,
     /// <p><p>Status of the resource&#39;s actual configuration compared to its expected configuration</p> <ul> <li> <p> <code>DELETED</code>: The resource differs from its expected template configuration because the resource has been deleted.</p> </li> <li> <p> <code>MODIFIED</code>: One or more resource properties differ from their expected values (as defined in the stack template and any values specified as template parameters).</p> </li> <li> <p> <code>IN<em>SYNC</code>: The resources&#39;s actual configuration matches its expected template configuration.</p> </li> <li> <p> <code>NOT</em>CHECKED</code>: AWS CloudFormation does not currently return this value.</p> </li> </ul></p>
-    pub stack_resource_drift_status: String,
+    pub stack_resource_drift_status: StackResourceDriftStatus,
     /// <p>Time at which AWS CloudFormation performed drift detection on the stack resource.</p>
-    pub timestamp: String,
+    pub timestamp: IsoTimestamp,
 }
 
-struct StackResourceDriftDeserializer;
+pub(crate) struct StackResourceDriftDeserializer;
 impl StackResourceDriftDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackResourceDrift, XmlParseError> {
-        deserialize_elements::<_, StackResourceDrift, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "ActualProperties" => {
-                    obj.actual_properties = Some(PropertiesDeserializer::deserialize(
-                        "ActualProperties",
-                        stack,
-                    )?);
-                }
-                "ExpectedProperties" => {
-                    obj.expected_properties = Some(PropertiesDeserializer::deserialize(
-                        "ExpectedProperties",
-                        stack,
-                    )?);
-                }
-                "LogicalResourceId" => {
-                    obj.logical_resource_id =
-                        LogicalResourceIdDeserializer::deserialize("LogicalResourceId", stack)?;
-                }
-                "PhysicalResourceId" => {
-                    obj.physical_resource_id = Some(PhysicalResourceIdDeserializer::deserialize(
-                        "PhysicalResourceId",
-                        stack,
-                    )?);
-                }
-                "PhysicalResourceIdContext" => {
-                    obj.physical_resource_id_context
-                        .get_or_insert(vec![])
-                        .extend(PhysicalResourceIdContextDeserializer::deserialize(
-                            "PhysicalResourceIdContext",
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            StackResourceDrift {
+                actual_properties: Default::default(),
+                expected_properties: Default::default(),
+                logical_resource_id: Default::default(),
+                physical_resource_id: Default::default(),
+                physical_resource_id_context: Default::default(),
+                property_differences: Default::default(),
+                resource_type: Default::default(),
+                stack_id: Default::default(),
+                stack_resource_drift_status: StackResourceDriftStatus::Unknown(String::new()),
+                timestamp: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "ActualProperties" => {
+                        obj.actual_properties = Some(PropertiesDeserializer::deserialize(
+                            "ActualProperties",
                             stack,
                         )?);
-                }
-                "PropertyDifferences" => {
-                    obj.property_differences.get_or_insert(vec![]).extend(
-                        PropertyDifferencesDeserializer::deserialize("PropertyDifferences", stack)?,
-                    );
-                }
-                "ResourceType" => {
-                    obj.resource_type =
-                        ResourceTypeDeserializer::deserialize("ResourceType", stack)?;
-                }
-                "StackId" => {
-                    obj.stack_id = StackIdDeserializer::deserialize("StackId", stack)?;
-                }
-                "StackResourceDriftStatus" => {
-                    obj.stack_resource_drift_status =
-                        StackResourceDriftStatusDeserializer::deserialize(
-                            "StackResourceDriftStatus",
+                    }
+                    "ExpectedProperties" => {
+                        obj.expected_properties = Some(PropertiesDeserializer::deserialize(
+                            "ExpectedProperties",
                             stack,
-                        )?;
-                }
-                "Timestamp" => {
-                    obj.timestamp = TimestampDeserializer::deserialize("Timestamp", stack)?;
+                        )?);
+                    }
+                    "LogicalResourceId" => {
+                        obj.logical_resource_id =
+                            LogicalResourceIdDeserializer::deserialize("LogicalResourceId", stack)?;
+                    }
+                    "PhysicalResourceId" => {
+                        obj.physical_resource_id =
+                            Some(PhysicalResourceIdDeserializer::deserialize(
+                                "PhysicalResourceId",
+                                stack,
+                            )?);
+                    }
+                    "PhysicalResourceIdContext" => {
+                        obj.physical_resource_id_context
+                            .get_or_insert(vec![])
+                            .extend(PhysicalResourceIdContextDeserializer::deserialize(
+                                "PhysicalResourceIdContext",
+                                stack,
+                            )?);
+                    }
+                    "PropertyDifferences" => {
+                        obj.property_differences.get_or_insert(vec![]).extend(
+                            PropertyDifferencesDeserializer::deserialize(
+                                "PropertyDifferences",
+                                stack,
+                            )?,
+                        );
+                    }
+                    "ResourceType" => {
+                        obj.resource_type =
+                            ResourceTypeDeserializer::deserialize("ResourceType", stack)?;
+                    }
+                    "StackId" => {
+                        obj.stack_id = StackIdDeserializer::deserialize("StackId", stack)?;
+                    }
+                    "StackResourceDriftStatus" => {
+                        obj.stack_resource_drift_status =
+                            StackResourceDriftStatusDeserializer::deserialize(
+                                "StackResourceDriftStatus",
+                                stack,
+                            )?;
+                    }
+                    "Timestamp" => {
+                        obj.timestamp = TimestampDeserializer::deserialize("Timestamp", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 /// <p>Contains information about whether the resource's actual configuration differs, or has <i>drifted</i>, from its expected configuration.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StackResourceDriftInformation {
     /// <p>When AWS CloudFormation last checked if the resource had drifted from its expected configuration.</p>
-    pub last_check_timestamp: Option<String>,
+    pub last_check_timestamp: Option<IsoTimestamp>,
     /// <p><p>Status of the resource&#39;s actual configuration compared to its expected configuration</p> <ul> <li> <p> <code>DELETED</code>: The resource differs from its expected configuration in that it has been deleted.</p> </li> <li> <p> <code>MODIFIED</code>: The resource differs from its expected configuration.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the resource differs from its expected configuration.</p> <p>Any resources that do not currently support drift detection have a status of <code>NOT</em>CHECKED</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>. </p> </li> <li> <p> <code>IN_SYNC</code>: The resources&#39;s actual configuration matches its expected configuration.</p> </li> </ul></p>
-    pub stack_resource_drift_status: String,
+    pub stack_resource_drift_status: StackResourceDriftStatus,
 }
 
-struct StackResourceDriftInformationDeserializer;
+pub(crate) struct StackResourceDriftInformationDeserializer;
 impl StackResourceDriftInformationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackResourceDriftInformation, XmlParseError> {
-        deserialize_elements::<_, StackResourceDriftInformation, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            StackResourceDriftInformation {
+                last_check_timestamp: Default::default(),
+                stack_resource_drift_status: StackResourceDriftStatus::Unknown(String::new()),
+            },
             |name, stack, obj| {
                 match name {
                     "LastCheckTimestamp" => {
@@ -5509,24 +9239,28 @@ impl StackResourceDriftInformationDeserializer {
     }
 }
 /// <p>Summarizes information about whether the resource's actual configuration differs, or has <i>drifted</i>, from its expected configuration.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StackResourceDriftInformationSummary {
     /// <p>When AWS CloudFormation last checked if the resource had drifted from its expected configuration.</p>
-    pub last_check_timestamp: Option<String>,
+    pub last_check_timestamp: Option<IsoTimestamp>,
     /// <p><p>Status of the resource&#39;s actual configuration compared to its expected configuration</p> <ul> <li> <p> <code>DELETED</code>: The resource differs from its expected configuration in that it has been deleted.</p> </li> <li> <p> <code>MODIFIED</code>: The resource differs from its expected configuration.</p> </li> <li> <p> <code>NOT<em>CHECKED</code>: AWS CloudFormation has not checked if the resource differs from its expected configuration.</p> <p>Any resources that do not currently support drift detection have a status of <code>NOT</em>CHECKED</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift-resource-list.html">Resources that Support Drift Detection</a>. If you performed an <a>ContinueUpdateRollback</a> operation on a stack, any resources included in <code>ResourcesToSkip</code> will also have a status of <code>NOT<em>CHECKED</code>. For more information on skipping resources during rollback operations, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-updating-stacks-continueupdaterollback.html">Continue Rolling Back an Update</a> in the AWS CloudFormation User Guide.</p> </li> <li> <p> <code>IN</em>SYNC</code>: The resources&#39;s actual configuration matches its expected configuration.</p> </li> </ul></p>
-    pub stack_resource_drift_status: String,
+    pub stack_resource_drift_status: StackResourceDriftStatus,
 }
 
-struct StackResourceDriftInformationSummaryDeserializer;
+pub(crate) struct StackResourceDriftInformationSummaryDeserializer;
 impl StackResourceDriftInformationSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackResourceDriftInformationSummary, XmlParseError> {
-        deserialize_elements::<_, StackResourceDriftInformationSummary, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            StackResourceDriftInformationSummary {
+                last_check_timestamp: Default::default(),
+                stack_resource_drift_status: StackResourceDriftStatus::Unknown(String::new()),
+            },
             |name, stack, obj| {
                 match name {
                     "LastCheckTimestamp" => {
@@ -5549,22 +9283,92 @@ impl StackResourceDriftInformationSummaryDeserializer {
         )
     }
 }
-struct StackResourceDriftStatusDeserializer;
+pub(crate) struct StackResourceDriftStatusDeserializer;
 impl StackResourceDriftStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackResourceDriftStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 
+/// <p><p>The resource drift status values to use as filters for the resource drift results returned.</p> <ul> <li> <p> <code>DELETED</code>: The resource differs from its expected template configuration in that the resource has been deleted.</p> </li> <li> <p> <code>MODIFIED</code>: One or more resource properties differ from their expected template values.</p> </li> <li> <p> <code>IN<em>SYNC</code>: The resources&#39;s actual configuration matches its expected template configuration.</p> </li> <li> <p> <code>NOT</em>CHECKED</code>: AWS CloudFormation does not currently return this value.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackResourceDriftStatus {
+    /// <p><code>IN_SYNC</code> - The resources&#39;s actual configuration matches its expected configuration.</p>
+    InSync,
+    /// <p><code>MODIFIED</code> - One or more resource properties differ from their expected template values.</p>
+    Modified,
+    /// <p><code>DELETED</code> - The resource differs from its expected template configuration in that the resource has been deleted.</p>
+    Deleted,
+    /// <p><code>NOT_CHECKED</code></p>
+    NotChecked,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackResourceDriftStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackResourceDriftStatus::InSync => "IN_SYNC",
+            StackResourceDriftStatus::Modified => "MODIFIED",
+            StackResourceDriftStatus::Deleted => "DELETED",
+            StackResourceDriftStatus::NotChecked => "NOT_CHECKED",
+            StackResourceDriftStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackResourceDriftStatus {
+    fn from(value: &'a str) -> StackResourceDriftStatus {
+        match value {
+            "IN_SYNC" => StackResourceDriftStatus::InSync,
+            "MODIFIED" => StackResourceDriftStatus::Modified,
+            "DELETED" => StackResourceDriftStatus::Deleted,
+            "NOT_CHECKED" => StackResourceDriftStatus::NotChecked,
+            _ => StackResourceDriftStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackResourceDriftStatus {
+    fn from(value: String) -> StackResourceDriftStatus {
+        StackResourceDriftStatus::from(value.as_str())
+    }
+}
+
+impl From<StackResourceDriftStatus> for String {
+    fn from(value: StackResourceDriftStatus) -> String {
+        match value {
+            StackResourceDriftStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackResourceDriftStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackResourceDriftStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// Serialize `StackResourceDriftStatusFilters` contents to a `SignedRequest`.
 struct StackResourceDriftStatusFiltersSerializer;
 impl StackResourceDriftStatusFiltersSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<StackResourceDriftStatus>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -5572,10 +9376,10 @@ impl StackResourceDriftStatusFiltersSerializer {
     }
 }
 
-struct StackResourceDriftsDeserializer;
+pub(crate) struct StackResourceDriftsDeserializer;
 impl StackResourceDriftsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<StackResourceDrift>, XmlParseError> {
@@ -5591,10 +9395,10 @@ impl StackResourceDriftsDeserializer {
         })
     }
 }
-struct StackResourceSummariesDeserializer;
+pub(crate) struct StackResourceSummariesDeserializer;
 impl StackResourceSummariesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<StackResourceSummary>, XmlParseError> {
@@ -5611,80 +9415,94 @@ impl StackResourceSummariesDeserializer {
     }
 }
 /// <p>Contains high-level information about the specified stack resource.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StackResourceSummary {
     /// <p>Information about whether the resource's actual configuration differs, or has <i>drifted</i>, from its expected configuration, as defined in the stack template and any values specified as template parameters. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p>
     pub drift_information: Option<StackResourceDriftInformationSummary>,
     /// <p>Time the status was updated.</p>
-    pub last_updated_timestamp: String,
+    pub last_updated_timestamp: IsoTimestamp,
     /// <p>The logical name of the resource specified in the template.</p>
     pub logical_resource_id: String,
     /// <p>The name or unique identifier that corresponds to a physical instance ID of the resource.</p>
     pub physical_resource_id: Option<String>,
     /// <p>Current status of the resource.</p>
-    pub resource_status: String,
+    pub resource_status: ResourceStatus,
     /// <p>Success/failure message associated with the resource.</p>
     pub resource_status_reason: Option<String>,
     /// <p>Type of resource. (For more information, go to <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-template-resource-type-ref.html"> AWS Resource Types Reference</a> in the AWS CloudFormation User Guide.)</p>
     pub resource_type: String,
 }
 
-struct StackResourceSummaryDeserializer;
+pub(crate) struct StackResourceSummaryDeserializer;
 impl StackResourceSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackResourceSummary, XmlParseError> {
-        deserialize_elements::<_, StackResourceSummary, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "DriftInformation" => {
-                    obj.drift_information = Some(
-                        StackResourceDriftInformationSummaryDeserializer::deserialize(
-                            "DriftInformation",
-                            stack,
-                        )?,
-                    );
-                }
-                "LastUpdatedTimestamp" => {
-                    obj.last_updated_timestamp =
-                        TimestampDeserializer::deserialize("LastUpdatedTimestamp", stack)?;
-                }
-                "LogicalResourceId" => {
-                    obj.logical_resource_id =
-                        LogicalResourceIdDeserializer::deserialize("LogicalResourceId", stack)?;
-                }
-                "PhysicalResourceId" => {
-                    obj.physical_resource_id = Some(PhysicalResourceIdDeserializer::deserialize(
-                        "PhysicalResourceId",
-                        stack,
-                    )?);
-                }
-                "ResourceStatus" => {
-                    obj.resource_status =
-                        ResourceStatusDeserializer::deserialize("ResourceStatus", stack)?;
-                }
-                "ResourceStatusReason" => {
-                    obj.resource_status_reason =
-                        Some(ResourceStatusReasonDeserializer::deserialize(
-                            "ResourceStatusReason",
-                            stack,
-                        )?);
-                }
-                "ResourceType" => {
-                    obj.resource_type =
-                        ResourceTypeDeserializer::deserialize("ResourceType", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            StackResourceSummary {
+                drift_information: Default::default(),
+                last_updated_timestamp: Default::default(),
+                logical_resource_id: Default::default(),
+                physical_resource_id: Default::default(),
+                resource_status: ResourceStatus::Unknown(String::new()),
+                resource_status_reason: Default::default(),
+                resource_type: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "DriftInformation" => {
+                        obj.drift_information = Some(
+                            StackResourceDriftInformationSummaryDeserializer::deserialize(
+                                "DriftInformation",
+                                stack,
+                            )?,
+                        );
+                    }
+                    "LastUpdatedTimestamp" => {
+                        obj.last_updated_timestamp =
+                            TimestampDeserializer::deserialize("LastUpdatedTimestamp", stack)?;
+                    }
+                    "LogicalResourceId" => {
+                        obj.logical_resource_id =
+                            LogicalResourceIdDeserializer::deserialize("LogicalResourceId", stack)?;
+                    }
+                    "PhysicalResourceId" => {
+                        obj.physical_resource_id =
+                            Some(PhysicalResourceIdDeserializer::deserialize(
+                                "PhysicalResourceId",
+                                stack,
+                            )?);
+                    }
+                    "ResourceStatus" => {
+                        obj.resource_status =
+                            ResourceStatusDeserializer::deserialize("ResourceStatus", stack)?;
+                    }
+                    "ResourceStatusReason" => {
+                        obj.resource_status_reason =
+                            Some(ResourceStatusReasonDeserializer::deserialize(
+                                "ResourceStatusReason",
+                                stack,
+                            )?);
+                    }
+                    "ResourceType" => {
+                        obj.resource_type =
+                            ResourceTypeDeserializer::deserialize("ResourceType", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct StackResourcesDeserializer;
+pub(crate) struct StackResourcesDeserializer;
 impl StackResourcesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<StackResource>, XmlParseError> {
@@ -5704,7 +9522,7 @@ pub struct StackSet {
     /// <p>The Amazon Resource Number (ARN) of the IAM role used to create or update the stack set.</p> <p>Use customized administrator roles to control which users or groups can manage specific stack sets within the same administrator account. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-prereqs.html">Prerequisites: Granting Permissions for Stack Set Operations</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     pub administration_role_arn: Option<String>,
     /// <p>The capabilities that are allowed in the stack set. Some stack set templates might include resources that can affect permissions in your AWS account—for example, by creating new AWS Identity and Access Management (IAM) users. For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates.</a> </p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>A description of the stack set that you specify when the stack set is created or updated.</p>
     pub description: Option<String>,
     /// <p>The name of the IAM execution role used to create or update the stack set. </p> <p>Use customized execution roles to control which stack resources users and groups can include in their stack sets. </p>
@@ -5718,17 +9536,17 @@ pub struct StackSet {
     /// <p>The name that's associated with the stack set.</p>
     pub stack_set_name: Option<String>,
     /// <p>The status of the stack set.</p>
-    pub status: Option<String>,
+    pub status: Option<StackSetStatus>,
     /// <p>A list of tags that specify information about the stack set. A maximum number of 50 tags can be specified.</p>
     pub tags: Option<Vec<Tag>>,
     /// <p>The structure that contains the body of the template that was used to create or update the stack set.</p>
     pub template_body: Option<String>,
 }
 
-struct StackSetDeserializer;
+pub(crate) struct StackSetDeserializer;
 impl StackSetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackSet, XmlParseError> {
@@ -5794,10 +9612,13 @@ impl StackSetDeserializer {
         })
     }
 }
-struct StackSetARNDeserializer;
+pub(crate) struct StackSetARNDeserializer;
 impl StackSetARNDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5805,10 +9626,13 @@ impl StackSetARNDeserializer {
         Ok(obj)
     }
 }
-struct StackSetIdDeserializer;
+pub(crate) struct StackSetIdDeserializer;
 impl StackSetIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5816,10 +9640,13 @@ impl StackSetIdDeserializer {
         Ok(obj)
     }
 }
-struct StackSetNameDeserializer;
+pub(crate) struct StackSetNameDeserializer;
 impl StackSetNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5831,13 +9658,13 @@ impl StackSetNameDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct StackSetOperation {
     /// <p>The type of stack set operation: <code>CREATE</code>, <code>UPDATE</code>, or <code>DELETE</code>. Create and delete operations affect only the specified stack set instances that are associated with the specified stack set. Update operations affect both the stack set itself, as well as <i>all</i> associated stack set instances.</p>
-    pub action: Option<String>,
+    pub action: Option<StackSetOperationAction>,
     /// <p>The Amazon Resource Number (ARN) of the IAM role used to perform this stack set operation. </p> <p>Use customized administrator roles to control which users or groups can manage specific stack sets within the same administrator account. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-prereqs.html">Define Permissions for Multiple Administrators</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     pub administration_role_arn: Option<String>,
     /// <p>The time at which the operation was initiated. Note that the creation times for the stack set operation might differ from the creation time of the individual stacks themselves. This is because AWS CloudFormation needs to perform preparatory work for the operation, such as dispatching the work to the requested regions, before actually creating the first stacks.</p>
-    pub creation_timestamp: Option<String>,
+    pub creation_timestamp: Option<IsoTimestamp>,
     /// <p>The time at which the stack set operation ended, across all accounts and regions specified. Note that this doesn't necessarily mean that the stack set operation was successful, or even attempted, in each account or region.</p>
-    pub end_timestamp: Option<String>,
+    pub end_timestamp: Option<IsoTimestamp>,
     /// <p>The name of the IAM execution role used to create or update the stack set.</p> <p>Use customized execution roles to control which stack resources users and groups can include in their stack sets. </p>
     pub execution_role_name: Option<String>,
     /// <p>The unique ID of a stack set operation.</p>
@@ -5849,13 +9676,13 @@ pub struct StackSetOperation {
     /// <p>The ID of the stack set.</p>
     pub stack_set_id: Option<String>,
     /// <p><p>The status of the operation. </p> <ul> <li> <p> <code>FAILED</code>: The operation exceeded the specified failure tolerance. The failure tolerance value that you&#39;ve set for an operation is applied for each region during stack create and update operations. If the number of failed stacks within a region exceeds the failure tolerance, the status of the operation in the region is set to <code>FAILED</code>. This in turn sets the status of the operation as a whole to <code>FAILED</code>, and AWS CloudFormation cancels the operation in any remaining regions.</p> </li> <li> <p> <code>RUNNING</code>: The operation is currently being performed.</p> </li> <li> <p> <code>STOPPED</code>: The user has cancelled the operation.</p> </li> <li> <p> <code>STOPPING</code>: The operation is in the process of stopping, at user request. </p> </li> <li> <p> <code>SUCCEEDED</code>: The operation completed creating or updating all the specified stacks without exceeding the failure tolerance for the operation.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<StackSetOperationStatus>,
 }
 
-struct StackSetOperationDeserializer;
+pub(crate) struct StackSetOperationDeserializer;
 impl StackSetOperationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackSetOperation, XmlParseError> {
@@ -5922,17 +9749,87 @@ impl StackSetOperationDeserializer {
         })
     }
 }
-struct StackSetOperationActionDeserializer;
+pub(crate) struct StackSetOperationActionDeserializer;
 impl StackSetOperationActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackSetOperationAction, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The type of stack set operation: <code>CREATE</code>, <code>UPDATE</code>, or <code>DELETE</code>. Create and delete operations affect only the specified stack set instances that are associated with the specified stack set. Update operations affect both the stack set itself, as well as <i>all</i> associated stack set instances.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackSetOperationAction {
+    /// <p><code>CREATE</code></p>
+    Create,
+    /// <p><code>UPDATE</code></p>
+    Update,
+    /// <p><code>DELETE</code></p>
+    Delete,
+    /// <p><code>DETECT_DRIFT</code></p>
+    DetectDrift,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackSetOperationAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackSetOperationAction::Create => "CREATE",
+            StackSetOperationAction::Update => "UPDATE",
+            StackSetOperationAction::Delete => "DELETE",
+            StackSetOperationAction::DetectDrift => "DETECT_DRIFT",
+            StackSetOperationAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackSetOperationAction {
+    fn from(value: &'a str) -> StackSetOperationAction {
+        match value {
+            "CREATE" => StackSetOperationAction::Create,
+            "UPDATE" => StackSetOperationAction::Update,
+            "DELETE" => StackSetOperationAction::Delete,
+            "DETECT_DRIFT" => StackSetOperationAction::DetectDrift,
+            _ => StackSetOperationAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackSetOperationAction {
+    fn from(value: String) -> StackSetOperationAction {
+        StackSetOperationAction::from(value.as_str())
+    }
+}
+
+impl From<StackSetOperationAction> for String {
+    fn from(value: StackSetOperationAction) -> String {
+        match value {
+            StackSetOperationAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackSetOperationAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackSetOperationAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>The user-specified preferences for how AWS CloudFormation performs a stack set operation. </p> <p>For more information on maximum concurrent accounts and failure tolerance, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-concepts.html#stackset-ops-options">Stack set operation options</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct StackSetOperationPreferences {
@@ -5948,10 +9845,10 @@ pub struct StackSetOperationPreferences {
     pub region_order: Option<Vec<String>>,
 }
 
-struct StackSetOperationPreferencesDeserializer;
+pub(crate) struct StackSetOperationPreferencesDeserializer;
 impl StackSetOperationPreferencesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackSetOperationPreferences, XmlParseError> {
@@ -6041,21 +9938,95 @@ impl StackSetOperationPreferencesSerializer {
     }
 }
 
-struct StackSetOperationResultStatusDeserializer;
+pub(crate) struct StackSetOperationResultStatusDeserializer;
 impl StackSetOperationResultStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackSetOperationResultStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct StackSetOperationResultSummariesDeserializer;
+/// <p><p>The result status of the stack set operation for the given account in the given region.</p> <ul> <li> <p> <code>CANCELLED</code>: The operation in the specified account and region has been cancelled. This is either because a user has stopped the stack set operation, or because the failure tolerance of the stack set operation has been exceeded.</p> </li> <li> <p> <code>FAILED</code>: The operation in the specified account and region failed. </p> <p>If the stack set operation fails in enough accounts within a region, the failure tolerance for the stack set operation as a whole might be exceeded. </p> </li> <li> <p> <code>RUNNING</code>: The operation in the specified account and region is currently in progress.</p> </li> <li> <p> <code>PENDING</code>: The operation in the specified account and region has yet to start. </p> </li> <li> <p> <code>SUCCEEDED</code>: The operation in the specified account and region completed successfully.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackSetOperationResultStatus {
+    /// <p><code>PENDING</code> - The operation in the specified account and region has yet to start.</p>
+    Pending,
+    /// <p><code>RUNNING</code> - The operation in the specified account and region is currently in progress.</p>
+    Running,
+    /// <p><code>SUCCEEDED</code> - The operation in the specified account and region completed successfully.</p>
+    Succeeded,
+    /// <p><code>FAILED</code> - The operation in the specified account and region failed.</p>
+    Failed,
+    /// <p><code>CANCELLED</code> - The operation in the specified account and region has been cancelled. This is either because a user has stopped the stack set operation, or because the failure tolerance of the stack set operation has been exceeded.</p>
+    Cancelled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackSetOperationResultStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackSetOperationResultStatus::Pending => "PENDING",
+            StackSetOperationResultStatus::Running => "RUNNING",
+            StackSetOperationResultStatus::Succeeded => "SUCCEEDED",
+            StackSetOperationResultStatus::Failed => "FAILED",
+            StackSetOperationResultStatus::Cancelled => "CANCELLED",
+            StackSetOperationResultStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackSetOperationResultStatus {
+    fn from(value: &'a str) -> StackSetOperationResultStatus {
+        match value {
+            "PENDING" => StackSetOperationResultStatus::Pending,
+            "RUNNING" => StackSetOperationResultStatus::Running,
+            "SUCCEEDED" => StackSetOperationResultStatus::Succeeded,
+            "FAILED" => StackSetOperationResultStatus::Failed,
+            "CANCELLED" => StackSetOperationResultStatus::Cancelled,
+            _ => StackSetOperationResultStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackSetOperationResultStatus {
+    fn from(value: String) -> StackSetOperationResultStatus {
+        StackSetOperationResultStatus::from(value.as_str())
+    }
+}
+
+impl From<StackSetOperationResultStatus> for String {
+    fn from(value: StackSetOperationResultStatus) -> String {
+        match value {
+            StackSetOperationResultStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackSetOperationResultStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackSetOperationResultStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct StackSetOperationResultSummariesDeserializer;
 impl StackSetOperationResultSummariesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<StackSetOperationResultSummary>, XmlParseError> {
@@ -6081,15 +10052,15 @@ pub struct StackSetOperationResultSummary {
     /// <p>The name of the AWS region for this operation result.</p>
     pub region: Option<String>,
     /// <p><p>The result status of the stack set operation for the given account in the given region.</p> <ul> <li> <p> <code>CANCELLED</code>: The operation in the specified account and region has been cancelled. This is either because a user has stopped the stack set operation, or because the failure tolerance of the stack set operation has been exceeded.</p> </li> <li> <p> <code>FAILED</code>: The operation in the specified account and region failed. </p> <p>If the stack set operation fails in enough accounts within a region, the failure tolerance for the stack set operation as a whole might be exceeded. </p> </li> <li> <p> <code>RUNNING</code>: The operation in the specified account and region is currently in progress.</p> </li> <li> <p> <code>PENDING</code>: The operation in the specified account and region has yet to start. </p> </li> <li> <p> <code>SUCCEEDED</code>: The operation in the specified account and region completed successfully.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<StackSetOperationResultStatus>,
     /// <p>The reason for the assigned result status.</p>
     pub status_reason: Option<String>,
 }
 
-struct StackSetOperationResultSummaryDeserializer;
+pub(crate) struct StackSetOperationResultSummaryDeserializer;
 impl StackSetOperationResultSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackSetOperationResultSummary, XmlParseError> {
@@ -6126,21 +10097,99 @@ impl StackSetOperationResultSummaryDeserializer {
         )
     }
 }
-struct StackSetOperationStatusDeserializer;
+pub(crate) struct StackSetOperationStatusDeserializer;
 impl StackSetOperationStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackSetOperationStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct StackSetOperationSummariesDeserializer;
+/// <p><p>The status of the operation. </p> <ul> <li> <p> <code>FAILED</code>: The operation exceeded the specified failure tolerance. The failure tolerance value that you&#39;ve set for an operation is applied for each region during stack create and update operations. If the number of failed stacks within a region exceeds the failure tolerance, the status of the operation in the region is set to <code>FAILED</code>. This in turn sets the status of the operation as a whole to <code>FAILED</code>, and AWS CloudFormation cancels the operation in any remaining regions.</p> </li> <li> <p> <code>RUNNING</code>: The operation is currently being performed.</p> </li> <li> <p> <code>STOPPED</code>: The user has cancelled the operation.</p> </li> <li> <p> <code>STOPPING</code>: The operation is in the process of stopping, at user request. </p> </li> <li> <p> <code>SUCCEEDED</code>: The operation completed creating or updating all the specified stacks without exceeding the failure tolerance for the operation.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackSetOperationStatus {
+    /// <p><code>RUNNING</code> - The operation is currently being performed.</p>
+    Running,
+    /// <p><code>SUCCEEDED</code> - The operation completed creating or updating all the specified stacks without exceeding the failure tolerance for the operation.</p>
+    Succeeded,
+    /// <p><code>FAILED</code> - The operation exceeded the specified failure tolerance. The failure tolerance value that you&#39;ve set for an operation is applied for each region during stack create and update operations. If the number of failed stacks within a region exceeds the failure tolerance, the status of the operation in the region is set to <code>FAILED</code>. This in turn sets the status of the operation as a whole to <code>FAILED</code>, and AWS CloudFormation cancels the operation in any remaining regions.</p>
+    Failed,
+    /// <p><code>STOPPING</code> - The operation is in the process of stopping, at user request.</p>
+    Stopping,
+    /// <p><code>STOPPED</code> - The user has cancelled the operation.</p>
+    Stopped,
+    /// <p><code>QUEUED</code></p>
+    Queued,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackSetOperationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackSetOperationStatus::Running => "RUNNING",
+            StackSetOperationStatus::Succeeded => "SUCCEEDED",
+            StackSetOperationStatus::Failed => "FAILED",
+            StackSetOperationStatus::Stopping => "STOPPING",
+            StackSetOperationStatus::Stopped => "STOPPED",
+            StackSetOperationStatus::Queued => "QUEUED",
+            StackSetOperationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackSetOperationStatus {
+    fn from(value: &'a str) -> StackSetOperationStatus {
+        match value {
+            "RUNNING" => StackSetOperationStatus::Running,
+            "SUCCEEDED" => StackSetOperationStatus::Succeeded,
+            "FAILED" => StackSetOperationStatus::Failed,
+            "STOPPING" => StackSetOperationStatus::Stopping,
+            "STOPPED" => StackSetOperationStatus::Stopped,
+            "QUEUED" => StackSetOperationStatus::Queued,
+            _ => StackSetOperationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackSetOperationStatus {
+    fn from(value: String) -> StackSetOperationStatus {
+        StackSetOperationStatus::from(value.as_str())
+    }
+}
+
+impl From<StackSetOperationStatus> for String {
+    fn from(value: StackSetOperationStatus) -> String {
+        match value {
+            StackSetOperationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackSetOperationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackSetOperationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct StackSetOperationSummariesDeserializer;
 impl StackSetOperationSummariesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<StackSetOperationSummary>, XmlParseError> {
@@ -6160,21 +10209,21 @@ impl StackSetOperationSummariesDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct StackSetOperationSummary {
     /// <p>The type of operation: <code>CREATE</code>, <code>UPDATE</code>, or <code>DELETE</code>. Create and delete operations affect only the specified stack instances that are associated with the specified stack set. Update operations affect both the stack set itself as well as <i>all</i> associated stack set instances.</p>
-    pub action: Option<String>,
+    pub action: Option<StackSetOperationAction>,
     /// <p>The time at which the operation was initiated. Note that the creation times for the stack set operation might differ from the creation time of the individual stacks themselves. This is because AWS CloudFormation needs to perform preparatory work for the operation, such as dispatching the work to the requested regions, before actually creating the first stacks.</p>
-    pub creation_timestamp: Option<String>,
+    pub creation_timestamp: Option<IsoTimestamp>,
     /// <p>The time at which the stack set operation ended, across all accounts and regions specified. Note that this doesn't necessarily mean that the stack set operation was successful, or even attempted, in each account or region.</p>
-    pub end_timestamp: Option<String>,
+    pub end_timestamp: Option<IsoTimestamp>,
     /// <p>The unique ID of the stack set operation.</p>
     pub operation_id: Option<String>,
     /// <p><p>The overall status of the operation.</p> <ul> <li> <p> <code>FAILED</code>: The operation exceeded the specified failure tolerance. The failure tolerance value that you&#39;ve set for an operation is applied for each region during stack create and update operations. If the number of failed stacks within a region exceeds the failure tolerance, the status of the operation in the region is set to <code>FAILED</code>. This in turn sets the status of the operation as a whole to <code>FAILED</code>, and AWS CloudFormation cancels the operation in any remaining regions.</p> </li> <li> <p> <code>RUNNING</code>: The operation is currently being performed.</p> </li> <li> <p> <code>STOPPED</code>: The user has cancelled the operation.</p> </li> <li> <p> <code>STOPPING</code>: The operation is in the process of stopping, at user request. </p> </li> <li> <p> <code>SUCCEEDED</code>: The operation completed creating or updating all the specified stacks without exceeding the failure tolerance for the operation.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<StackSetOperationStatus>,
 }
 
-struct StackSetOperationSummaryDeserializer;
+pub(crate) struct StackSetOperationSummaryDeserializer;
 impl StackSetOperationSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackSetOperationSummary, XmlParseError> {
@@ -6216,21 +10265,83 @@ impl StackSetOperationSummaryDeserializer {
         )
     }
 }
-struct StackSetStatusDeserializer;
+pub(crate) struct StackSetStatusDeserializer;
 impl StackSetStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackSetStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct StackSetSummariesDeserializer;
+/// <p>The status of the stack sets that you want to get summary information about.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackSetStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackSetStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackSetStatus::Active => "ACTIVE",
+            StackSetStatus::Deleted => "DELETED",
+            StackSetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackSetStatus {
+    fn from(value: &'a str) -> StackSetStatus {
+        match value {
+            "ACTIVE" => StackSetStatus::Active,
+            "DELETED" => StackSetStatus::Deleted,
+            _ => StackSetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackSetStatus {
+    fn from(value: String) -> StackSetStatus {
+        StackSetStatus::from(value.as_str())
+    }
+}
+
+impl From<StackSetStatus> for String {
+    fn from(value: StackSetStatus) -> String {
+        match value {
+            StackSetStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackSetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackSetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct StackSetSummariesDeserializer;
 impl StackSetSummariesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<StackSetSummary>, XmlParseError> {
@@ -6254,13 +10365,13 @@ pub struct StackSetSummary {
     /// <p>The name of the stack set.</p>
     pub stack_set_name: Option<String>,
     /// <p>The status of the stack set.</p>
-    pub status: Option<String>,
+    pub status: Option<StackSetStatus>,
 }
 
-struct StackSetSummaryDeserializer;
+pub(crate) struct StackSetSummaryDeserializer;
 impl StackSetSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackSetSummary, XmlParseError> {
@@ -6289,22 +10400,172 @@ impl StackSetSummaryDeserializer {
         })
     }
 }
-struct StackStatusDeserializer;
+pub(crate) struct StackStatusDeserializer;
 impl StackStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StackStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
-        Ok(obj)
+        Ok(obj)
+    }
+}
+
+/// <p>Stack status to use as a filter. Specify one or more stack status codes to list only stacks with the specified status codes. For a complete list of stack status codes, see the <code>StackStatus</code> parameter of the <a>Stack</a> data type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackStatus {
+    /// <p><code>CREATE_IN_PROGRESS</code></p>
+    CreateInProgress,
+    /// <p><code>CREATE_FAILED</code></p>
+    CreateFailed,
+    /// <p><code>CREATE_COMPLETE</code></p>
+    CreateComplete,
+    /// <p><code>ROLLBACK_IN_PROGRESS</code></p>
+    RollbackInProgress,
+    /// <p><code>ROLLBACK_FAILED</code></p>
+    RollbackFailed,
+    /// <p><code>ROLLBACK_COMPLETE</code></p>
+    RollbackComplete,
+    /// <p><code>DELETE_IN_PROGRESS</code></p>
+    DeleteInProgress,
+    /// <p><code>DELETE_FAILED</code></p>
+    DeleteFailed,
+    /// <p><code>DELETE_COMPLETE</code></p>
+    DeleteComplete,
+    /// <p><code>UPDATE_IN_PROGRESS</code></p>
+    UpdateInProgress,
+    /// <p><code>UPDATE_COMPLETE_CLEANUP_IN_PROGRESS</code></p>
+    UpdateCompleteCleanupInProgress,
+    /// <p><code>UPDATE_COMPLETE</code></p>
+    UpdateComplete,
+    /// <p><code>UPDATE_FAILED</code></p>
+    UpdateFailed,
+    /// <p><code>UPDATE_ROLLBACK_IN_PROGRESS</code></p>
+    UpdateRollbackInProgress,
+    /// <p><code>UPDATE_ROLLBACK_FAILED</code></p>
+    UpdateRollbackFailed,
+    /// <p><code>UPDATE_ROLLBACK_COMPLETE_CLEANUP_IN_PROGRESS</code></p>
+    UpdateRollbackCompleteCleanupInProgress,
+    /// <p><code>UPDATE_ROLLBACK_COMPLETE</code></p>
+    UpdateRollbackComplete,
+    /// <p><code>REVIEW_IN_PROGRESS</code></p>
+    ReviewInProgress,
+    /// <p><code>IMPORT_IN_PROGRESS</code></p>
+    ImportInProgress,
+    /// <p><code>IMPORT_COMPLETE</code></p>
+    ImportComplete,
+    /// <p><code>IMPORT_ROLLBACK_IN_PROGRESS</code></p>
+    ImportRollbackInProgress,
+    /// <p><code>IMPORT_ROLLBACK_FAILED</code></p>
+    ImportRollbackFailed,
+    /// <p><code>IMPORT_ROLLBACK_COMPLETE</code></p>
+    ImportRollbackComplete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            StackStatus::CreateFailed => "CREATE_FAILED",
+            StackStatus::CreateComplete => "CREATE_COMPLETE",
+            StackStatus::RollbackInProgress => "ROLLBACK_IN_PROGRESS",
+            StackStatus::RollbackFailed => "ROLLBACK_FAILED",
+            StackStatus::RollbackComplete => "ROLLBACK_COMPLETE",
+            StackStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            StackStatus::DeleteFailed => "DELETE_FAILED",
+            StackStatus::DeleteComplete => "DELETE_COMPLETE",
+            StackStatus::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            StackStatus::UpdateCompleteCleanupInProgress => "UPDATE_COMPLETE_CLEANUP_IN_PROGRESS",
+            StackStatus::UpdateComplete => "UPDATE_COMPLETE",
+            StackStatus::UpdateFailed => "UPDATE_FAILED",
+            StackStatus::UpdateRollbackInProgress => "UPDATE_ROLLBACK_IN_PROGRESS",
+            StackStatus::UpdateRollbackFailed => "UPDATE_ROLLBACK_FAILED",
+            StackStatus::UpdateRollbackCompleteCleanupInProgress => {
+                "UPDATE_ROLLBACK_COMPLETE_CLEANUP_IN_PROGRESS"
+            }
+            StackStatus::UpdateRollbackComplete => "UPDATE_ROLLBACK_COMPLETE",
+            StackStatus::ReviewInProgress => "REVIEW_IN_PROGRESS",
+            StackStatus::ImportInProgress => "IMPORT_IN_PROGRESS",
+            StackStatus::ImportComplete => "IMPORT_COMPLETE",
+            StackStatus::ImportRollbackInProgress => "IMPORT_ROLLBACK_IN_PROGRESS",
+            StackStatus::ImportRollbackFailed => "IMPORT_ROLLBACK_FAILED",
+            StackStatus::ImportRollbackComplete => "IMPORT_ROLLBACK_COMPLETE",
+            StackStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackStatus {
+    fn from(value: &'a str) -> StackStatus {
+        match value {
+            "CREATE_IN_PROGRESS" => StackStatus::CreateInProgress,
+            "CREATE_FAILED" => StackStatus::CreateFailed,
+            "CREATE_COMPLETE" => StackStatus::CreateComplete,
+            "ROLLBACK_IN_PROGRESS" => StackStatus::RollbackInProgress,
+            "ROLLBACK_FAILED" => StackStatus::RollbackFailed,
+            "ROLLBACK_COMPLETE" => StackStatus::RollbackComplete,
+            "DELETE_IN_PROGRESS" => StackStatus::DeleteInProgress,
+            "DELETE_FAILED" => StackStatus::DeleteFailed,
+            "DELETE_COMPLETE" => StackStatus::DeleteComplete,
+            "UPDATE_IN_PROGRESS" => StackStatus::UpdateInProgress,
+            "UPDATE_COMPLETE_CLEANUP_IN_PROGRESS" => StackStatus::UpdateCompleteCleanupInProgress,
+            "UPDATE_COMPLETE" => StackStatus::UpdateComplete,
+            "UPDATE_FAILED" => StackStatus::UpdateFailed,
+            "UPDATE_ROLLBACK_IN_PROGRESS" => StackStatus::UpdateRollbackInProgress,
+            "UPDATE_ROLLBACK_FAILED" => StackStatus::UpdateRollbackFailed,
+            "UPDATE_ROLLBACK_COMPLETE_CLEANUP_IN_PROGRESS" => {
+                StackStatus::UpdateRollbackCompleteCleanupInProgress
+            }
+            "UPDATE_ROLLBACK_COMPLETE" => StackStatus::UpdateRollbackComplete,
+            "REVIEW_IN_PROGRESS" => StackStatus::ReviewInProgress,
+            "IMPORT_IN_PROGRESS" => StackStatus::ImportInProgress,
+            "IMPORT_COMPLETE" => StackStatus::ImportComplete,
+            "IMPORT_ROLLBACK_IN_PROGRESS" => StackStatus::ImportRollbackInProgress,
+            "IMPORT_ROLLBACK_FAILED" => StackStatus::ImportRollbackFailed,
+            "IMPORT_ROLLBACK_COMPLETE" => StackStatus::ImportRollbackComplete,
+            _ => StackStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackStatus {
+    fn from(value: String) -> StackStatus {
+        StackStatus::from(value.as_str())
+    }
+}
+
+impl From<StackStatus> for String {
+    fn from(value: StackStatus) -> String {
+        match value {
+            StackStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StackStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
     }
 }
 
 /// Serialize `StackStatusFilter` contents to a `SignedRequest`.
 struct StackStatusFilterSerializer;
 impl StackStatusFilterSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<StackStatus>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -6312,10 +10573,13 @@ impl StackStatusFilterSerializer {
     }
 }
 
-struct StackStatusReasonDeserializer;
+pub(crate) struct StackStatusReasonDeserializer;
 impl StackStatusReasonDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6323,10 +10587,10 @@ impl StackStatusReasonDeserializer {
         Ok(obj)
     }
 }
-struct StackSummariesDeserializer;
+pub(crate) struct StackSummariesDeserializer;
 impl StackSummariesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<StackSummary>, XmlParseError> {
@@ -6341,16 +10605,16 @@ impl StackSummariesDeserializer {
     }
 }
 /// <p>The StackSummary Data Type</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StackSummary {
     /// <p>The time the stack was created.</p>
-    pub creation_time: String,
+    pub creation_time: IsoTimestamp,
     /// <p>The time the stack was deleted.</p>
-    pub deletion_time: Option<String>,
+    pub deletion_time: Option<IsoTimestamp>,
     /// <p>Summarizes information on whether a stack's actual configuration differs, or has <i>drifted</i>, from it's expected configuration, as defined in the stack template and any values specified as template parameters. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-stack-drift.html">Detecting Unregulated Configuration Changes to Stacks and Resources</a>.</p>
     pub drift_information: Option<StackDriftInformationSummary>,
     /// <p>The time the stack was last updated. This field will only be returned if the stack has been updated at least once.</p>
-    pub last_updated_time: Option<String>,
+    pub last_updated_time: Option<IsoTimestamp>,
     /// <p>For nested stacks--stacks created as resources for another stack--the stack ID of the direct parent of this stack. For the first level of nested stacks, the root stack is also the parent stack.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-nested-stacks.html">Working with Nested Stacks</a> in the <i>AWS CloudFormation User Guide</i>.</p>
     pub parent_id: Option<String>,
     /// <p>For nested stacks--stacks created as resources for another stack--the stack ID of the the top-level stack to which the nested stack ultimately belongs.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-cfn-nested-stacks.html">Working with Nested Stacks</a> in the <i>AWS CloudFormation User Guide</i>.</p>
@@ -6360,82 +10624,101 @@ pub struct StackSummary {
     /// <p>The name associated with the stack.</p>
     pub stack_name: String,
     /// <p>The current status of the stack.</p>
-    pub stack_status: String,
+    pub stack_status: StackStatus,
     /// <p>Success/Failure message associated with the stack status.</p>
     pub stack_status_reason: Option<String>,
     /// <p>The template description of the template used to create the stack.</p>
     pub template_description: Option<String>,
 }
 
-struct StackSummaryDeserializer;
+pub(crate) struct StackSummaryDeserializer;
 impl StackSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StackSummary, XmlParseError> {
-        deserialize_elements::<_, StackSummary, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "CreationTime" => {
-                    obj.creation_time =
-                        CreationTimeDeserializer::deserialize("CreationTime", stack)?;
-                }
-                "DeletionTime" => {
-                    obj.deletion_time = Some(DeletionTimeDeserializer::deserialize(
-                        "DeletionTime",
-                        stack,
-                    )?);
-                }
-                "DriftInformation" => {
-                    obj.drift_information =
-                        Some(StackDriftInformationSummaryDeserializer::deserialize(
-                            "DriftInformation",
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            StackSummary {
+                creation_time: Default::default(),
+                deletion_time: Default::default(),
+                drift_information: Default::default(),
+                last_updated_time: Default::default(),
+                parent_id: Default::default(),
+                root_id: Default::default(),
+                stack_id: Default::default(),
+                stack_name: Default::default(),
+                stack_status: StackStatus::Unknown(String::new()),
+                stack_status_reason: Default::default(),
+                template_description: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "CreationTime" => {
+                        obj.creation_time =
+                            CreationTimeDeserializer::deserialize("CreationTime", stack)?;
+                    }
+                    "DeletionTime" => {
+                        obj.deletion_time = Some(DeletionTimeDeserializer::deserialize(
+                            "DeletionTime",
                             stack,
                         )?);
+                    }
+                    "DriftInformation" => {
+                        obj.drift_information =
+                            Some(StackDriftInformationSummaryDeserializer::deserialize(
+                                "DriftInformation",
+                                stack,
+                            )?);
+                    }
+                    "LastUpdatedTime" => {
+                        obj.last_updated_time = Some(LastUpdatedTimeDeserializer::deserialize(
+                            "LastUpdatedTime",
+                            stack,
+                        )?);
+                    }
+                    "ParentId" => {
+                        obj.parent_id = Some(StackIdDeserializer::deserialize("ParentId", stack)?);
+                    }
+                    "RootId" => {
+                        obj.root_id = Some(StackIdDeserializer::deserialize("RootId", stack)?);
+                    }
+                    "StackId" => {
+                        obj.stack_id = Some(StackIdDeserializer::deserialize("StackId", stack)?);
+                    }
+                    "StackName" => {
+                        obj.stack_name = StackNameDeserializer::deserialize("StackName", stack)?;
+                    }
+                    "StackStatus" => {
+                        obj.stack_status =
+                            StackStatusDeserializer::deserialize("StackStatus", stack)?;
+                    }
+                    "StackStatusReason" => {
+                        obj.stack_status_reason = Some(StackStatusReasonDeserializer::deserialize(
+                            "StackStatusReason",
+                            stack,
+                        )?);
+                    }
+                    "TemplateDescription" => {
+                        obj.template_description =
+                            Some(TemplateDescriptionDeserializer::deserialize(
+                                "TemplateDescription",
+                                stack,
+                            )?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                "LastUpdatedTime" => {
-                    obj.last_updated_time = Some(LastUpdatedTimeDeserializer::deserialize(
-                        "LastUpdatedTime",
-                        stack,
-                    )?);
-                }
-                "ParentId" => {
-                    obj.parent_id = Some(StackIdDeserializer::deserialize("ParentId", stack)?);
-                }
-                "RootId" => {
-                    obj.root_id = Some(StackIdDeserializer::deserialize("RootId", stack)?);
-                }
-                "StackId" => {
-                    obj.stack_id = Some(StackIdDeserializer::deserialize("StackId", stack)?);
-                }
-                "StackName" => {
-                    obj.stack_name = StackNameDeserializer::deserialize("StackName", stack)?;
-                }
-                "StackStatus" => {
-                    obj.stack_status = StackStatusDeserializer::deserialize("StackStatus", stack)?;
-                }
-                "StackStatusReason" => {
-                    obj.stack_status_reason = Some(StackStatusReasonDeserializer::deserialize(
-                        "StackStatusReason",
-                        stack,
-                    )?);
-                }
-                "TemplateDescription" => {
-                    obj.template_description = Some(TemplateDescriptionDeserializer::deserialize(
-                        "TemplateDescription",
-                        stack,
-                    )?);
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct StacksDeserializer;
+pub(crate) struct StacksDeserializer;
 impl StacksDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Stack>, XmlParseError> {
@@ -6449,13 +10732,13 @@ impl StacksDeserializer {
         })
     }
 }
-struct StageListDeserializer;
+pub(crate) struct StageListDeserializer;
 impl StageListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<TemplateStage>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "member" {
                 obj.push(TemplateStageDeserializer::deserialize("member", stack)?);
@@ -6474,6 +10757,48 @@ pub struct StopStackSetOperationInput {
     pub stack_set_name: String,
 }
 
+impl StopStackSetOperationInput {
+    /// Returns a builder for `StopStackSetOperationInput`, checking its required members when built.
+    pub fn builder() -> StopStackSetOperationInputBuilder {
+        StopStackSetOperationInputBuilder::default()
+    }
+}
+
+/// Builds a [`StopStackSetOperationInput`](struct.StopStackSetOperationInput.html).
+#[derive(Default, Debug)]
+pub struct StopStackSetOperationInputBuilder {
+    operation_id: Option<String>,
+    stack_set_name: Option<String>,
+}
+
+impl StopStackSetOperationInputBuilder {
+    /// Sets the [`operation_id`](struct.StopStackSetOperationInput.html#structfield.operation_id) member.
+    pub fn operation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_set_name`](struct.StopStackSetOperationInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopStackSetOperationInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopStackSetOperationInput, ::rusoto_core::BuildError> {
+        Ok(StopStackSetOperationInput {
+            operation_id: self
+                .operation_id
+                .ok_or(::rusoto_core::BuildError::missing("operation_id"))?,
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `StopStackSetOperationInput` contents to a `SignedRequest`.
 struct StopStackSetOperationInputSerializer;
 impl StopStackSetOperationInputSerializer {
@@ -6494,10 +10819,10 @@ impl StopStackSetOperationInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct StopStackSetOperationOutput {}
 
-struct StopStackSetOperationOutputDeserializer;
+pub(crate) struct StopStackSetOperationOutputDeserializer;
 impl StopStackSetOperationOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StopStackSetOperationOutput, XmlParseError> {
@@ -6519,10 +10844,13 @@ pub struct Tag {
     pub value: String,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -6552,10 +10880,13 @@ impl TagSerializer {
     }
 }
 
-struct TagKeyDeserializer;
+pub(crate) struct TagKeyDeserializer;
 impl TagKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6563,10 +10894,13 @@ impl TagKeyDeserializer {
         Ok(obj)
     }
 }
-struct TagValueDeserializer;
+pub(crate) struct TagValueDeserializer;
 impl TagValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6574,10 +10908,10 @@ impl TagValueDeserializer {
         Ok(obj)
     }
 }
-struct TagsDeserializer;
+pub(crate) struct TagsDeserializer;
 impl TagsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -6603,10 +10937,13 @@ impl TagsSerializer {
     }
 }
 
-struct TemplateBodyDeserializer;
+pub(crate) struct TemplateBodyDeserializer;
 impl TemplateBodyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6614,10 +10951,13 @@ impl TemplateBodyDeserializer {
         Ok(obj)
     }
 }
-struct TemplateDescriptionDeserializer;
+pub(crate) struct TemplateDescriptionDeserializer;
 impl TemplateDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6638,10 +10978,10 @@ pub struct TemplateParameter {
     pub parameter_key: Option<String>,
 }
 
-struct TemplateParameterDeserializer;
+pub(crate) struct TemplateParameterDeserializer;
 impl TemplateParameterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TemplateParameter, XmlParseError> {
@@ -6672,10 +11012,10 @@ impl TemplateParameterDeserializer {
         })
     }
 }
-struct TemplateParametersDeserializer;
+pub(crate) struct TemplateParametersDeserializer;
 impl TemplateParametersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TemplateParameter>, XmlParseError> {
@@ -6689,21 +11029,86 @@ impl TemplateParametersDeserializer {
         })
     }
 }
-struct TemplateStageDeserializer;
+pub(crate) struct TemplateStageDeserializer;
 impl TemplateStageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TemplateStage, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct TimeoutMinutesDeserializer;
+/// <p>For templates that include transforms, the stage of the template that AWS CloudFormation returns. To get the user-submitted template, specify <code>Original</code>. To get the template after AWS CloudFormation has processed all transforms, specify <code>Processed</code>. </p> <p>If the template doesn't include transforms, <code>Original</code> and <code>Processed</code> return the same template. By default, AWS CloudFormation specifies <code>Original</code>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TemplateStage {
+    /// <p><code>Original</code></p>
+    Original,
+    /// <p><code>Processed</code></p>
+    Processed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TemplateStage {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TemplateStage::Original => "Original",
+            TemplateStage::Processed => "Processed",
+            TemplateStage::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TemplateStage {
+    fn from(value: &'a str) -> TemplateStage {
+        match value {
+            "Original" => TemplateStage::Original,
+            "Processed" => TemplateStage::Processed,
+            _ => TemplateStage::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TemplateStage {
+    fn from(value: String) -> TemplateStage {
+        TemplateStage::from(value.as_str())
+    }
+}
+
+impl From<TemplateStage> for String {
+    fn from(value: TemplateStage) -> String {
+        match value {
+            TemplateStage::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TemplateStage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TemplateStage {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct TimeoutMinutesDeserializer;
 impl TimeoutMinutesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -6711,21 +11116,27 @@ impl TimeoutMinutesDeserializer {
         Ok(obj)
     }
 }
-struct TimestampDeserializer;
+pub(crate) struct TimestampDeserializer;
 impl TimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct TransformNameDeserializer;
+pub(crate) struct TransformNameDeserializer;
 impl TransformNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6733,10 +11144,10 @@ impl TransformNameDeserializer {
         Ok(obj)
     }
 }
-struct TransformsListDeserializer;
+pub(crate) struct TransformsListDeserializer;
 impl TransformsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6750,10 +11161,13 @@ impl TransformsListDeserializer {
         })
     }
 }
-struct TypeDeserializer;
+pub(crate) struct TypeDeserializer;
 impl TypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6765,7 +11179,7 @@ impl TypeDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateStackInput {
     /// <p><p>In some cases, you must explicity acknowledge that your stack template contains certain capabilities in order for AWS CloudFormation to update the stack.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stacks, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some template contain macros. Macros perform custom processing on templates; this can include simple actions like find-and-replace operations, all the way to extensive transformations of entire templates. Because of this, users typically create a change set from the processed template, so that they can review the changes resulting from the macros before actually updating the stack. If your stack template contains one or more macros, and you choose to update a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.</p> <p>Change sets do not currently support nested stacks. If you want to update a stack from a stack template that contains macros <i>and</i> nested stacks, you must update the stack directly from the template using this capability.</p> <important> <p>You should only update stacks directly from a stack template that contains macros if you know what processing the macro performs.</p> <p>Each macro relies on an underlying Lambda service function for processing stack templates. Be aware that the Lambda function owner can update the function operation without AWS CloudFormation being notified.</p> </important> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> </li> </ul></p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>A unique identifier for this <code>UpdateStack</code> request. Specify this token if you plan to retry requests so that AWS CloudFormation knows that you're not attempting to update a stack with the same name. You might retry <code>UpdateStack</code> requests to ensure that AWS CloudFormation successfully received them.</p> <p>All events triggered by a given stack operation are assigned the same client request token, which you can use to track operations. For example, if you execute a <code>CreateStack</code> operation with the token <code>token1</code>, then all the <code>StackEvents</code> generated by that operation will have <code>ClientRequestToken</code> set as <code>token1</code>.</p> <p>In the console, stack operations display the client request token on the Events tab. Stack operations that are initiated from the console use the token format <i>Console-StackOperation-ID</i>, which helps you easily identify the stack operation . For example, if you create a stack using the console, each stack event would be assigned the same token in the following format: <code>Console-CreateStack-7f59c3cf-00d2-40c7-b2ff-e75db0987002</code>. </p>
     pub client_request_token: Option<String>,
     /// <p>Amazon Simple Notification Service topic Amazon Resource Names (ARNs) that AWS CloudFormation associates with the stack. Specify an empty list to remove all notification topics.</p>
@@ -6798,6 +11212,156 @@ pub struct UpdateStackInput {
     pub use_previous_template: Option<bool>,
 }
 
+impl UpdateStackInput {
+    /// Returns a builder for `UpdateStackInput`, checking its required members when built.
+    pub fn builder() -> UpdateStackInputBuilder {
+        UpdateStackInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateStackInput`](struct.UpdateStackInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateStackInputBuilder {
+    capabilities: Option<Vec<Capability>>,
+    client_request_token: Option<String>,
+    notification_ar_ns: Option<Vec<String>>,
+    parameters: Option<Vec<Parameter>>,
+    resource_types: Option<Vec<String>>,
+    role_arn: Option<String>,
+    rollback_configuration: Option<RollbackConfiguration>,
+    stack_name: Option<String>,
+    stack_policy_body: Option<String>,
+    stack_policy_during_update_body: Option<String>,
+    stack_policy_during_update_url: Option<String>,
+    stack_policy_url: Option<String>,
+    tags: Option<Vec<Tag>>,
+    template_body: Option<String>,
+    template_url: Option<String>,
+    use_previous_template: Option<bool>,
+}
+
+impl UpdateStackInputBuilder {
+    /// Sets the [`capabilities`](struct.UpdateStackInput.html#structfield.capabilities) member.
+    pub fn capabilities<T: Into<Vec<Capability>>>(mut self, value: T) -> Self {
+        self.capabilities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_request_token`](struct.UpdateStackInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_ar_ns`](struct.UpdateStackInput.html#structfield.notification_ar_ns) member.
+    pub fn notification_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.notification_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.UpdateStackInput.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_types`](struct.UpdateStackInput.html#structfield.resource_types) member.
+    pub fn resource_types<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.UpdateStackInput.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rollback_configuration`](struct.UpdateStackInput.html#structfield.rollback_configuration) member.
+    pub fn rollback_configuration<T: Into<RollbackConfiguration>>(mut self, value: T) -> Self {
+        self.rollback_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.UpdateStackInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_policy_body`](struct.UpdateStackInput.html#structfield.stack_policy_body) member.
+    pub fn stack_policy_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_policy_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_policy_during_update_body`](struct.UpdateStackInput.html#structfield.stack_policy_during_update_body) member.
+    pub fn stack_policy_during_update_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_policy_during_update_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_policy_during_update_url`](struct.UpdateStackInput.html#structfield.stack_policy_during_update_url) member.
+    pub fn stack_policy_during_update_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_policy_during_update_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_policy_url`](struct.UpdateStackInput.html#structfield.stack_policy_url) member.
+    pub fn stack_policy_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_policy_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.UpdateStackInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_body`](struct.UpdateStackInput.html#structfield.template_body) member.
+    pub fn template_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_url`](struct.UpdateStackInput.html#structfield.template_url) member.
+    pub fn template_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_previous_template`](struct.UpdateStackInput.html#structfield.use_previous_template) member.
+    pub fn use_previous_template<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_previous_template = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateStackInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateStackInput, ::rusoto_core::BuildError> {
+        Ok(UpdateStackInput {
+            capabilities: self.capabilities,
+            client_request_token: self.client_request_token,
+            notification_ar_ns: self.notification_ar_ns,
+            parameters: self.parameters,
+            resource_types: self.resource_types,
+            role_arn: self.role_arn,
+            rollback_configuration: self.rollback_configuration,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+            stack_policy_body: self.stack_policy_body,
+            stack_policy_during_update_body: self.stack_policy_during_update_body,
+            stack_policy_during_update_url: self.stack_policy_during_update_url,
+            stack_policy_url: self.stack_policy_url,
+            tags: self.tags,
+            template_body: self.template_body,
+            template_url: self.template_url,
+            use_previous_template: self.use_previous_template,
+        })
+    }
+}
+
 /// Serialize `UpdateStackInput` contents to a `SignedRequest`.
 struct UpdateStackInputSerializer;
 impl UpdateStackInputSerializer {
@@ -6901,6 +11465,85 @@ pub struct UpdateStackInstancesInput {
     pub stack_set_name: String,
 }
 
+impl UpdateStackInstancesInput {
+    /// Returns a builder for `UpdateStackInstancesInput`, checking its required members when built.
+    pub fn builder() -> UpdateStackInstancesInputBuilder {
+        UpdateStackInstancesInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateStackInstancesInput`](struct.UpdateStackInstancesInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateStackInstancesInputBuilder {
+    accounts: Option<Vec<String>>,
+    operation_id: Option<String>,
+    operation_preferences: Option<StackSetOperationPreferences>,
+    parameter_overrides: Option<Vec<Parameter>>,
+    regions: Option<Vec<String>>,
+    stack_set_name: Option<String>,
+}
+
+impl UpdateStackInstancesInputBuilder {
+    /// Sets the [`accounts`](struct.UpdateStackInstancesInput.html#structfield.accounts) member.
+    pub fn accounts<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.accounts = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation_id`](struct.UpdateStackInstancesInput.html#structfield.operation_id) member.
+    pub fn operation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation_preferences`](struct.UpdateStackInstancesInput.html#structfield.operation_preferences) member.
+    pub fn operation_preferences<T: Into<StackSetOperationPreferences>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.operation_preferences = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_overrides`](struct.UpdateStackInstancesInput.html#structfield.parameter_overrides) member.
+    pub fn parameter_overrides<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameter_overrides = Some(value.into());
+        self
+    }
+
+    /// Sets the [`regions`](struct.UpdateStackInstancesInput.html#structfield.regions) member.
+    pub fn regions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.regions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_set_name`](struct.UpdateStackInstancesInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateStackInstancesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateStackInstancesInput, ::rusoto_core::BuildError> {
+        Ok(UpdateStackInstancesInput {
+            accounts: self
+                .accounts
+                .ok_or(::rusoto_core::BuildError::missing("accounts"))?,
+            operation_id: self.operation_id,
+            operation_preferences: self.operation_preferences,
+            parameter_overrides: self.parameter_overrides,
+            regions: self
+                .regions
+                .ok_or(::rusoto_core::BuildError::missing("regions"))?,
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateStackInstancesInput` contents to a `SignedRequest`.
 struct UpdateStackInstancesInputSerializer;
 impl UpdateStackInstancesInputSerializer {
@@ -6915,9 +11558,11 @@ impl UpdateStackInstancesInputSerializer {
             &format!("{}{}", prefix, "Accounts"),
             &obj.accounts,
         );
-        if let Some(ref field_value) = obj.operation_id {
-            params.put(&format!("{}{}", prefix, "OperationId"), &field_value);
-        }
+        let field_value = obj
+            .operation_id
+            .clone()
+            .unwrap_or_else(rusoto_core::idempotency::generate_token);
+        params.put(&format!("{}{}", prefix, "OperationId"), &field_value);
         if let Some(ref field_value) = obj.operation_preferences {
             StackSetOperationPreferencesSerializer::serialize(
                 params,
@@ -6946,10 +11591,10 @@ pub struct UpdateStackInstancesOutput {
     pub operation_id: Option<String>,
 }
 
-struct UpdateStackInstancesOutputDeserializer;
+pub(crate) struct UpdateStackInstancesOutputDeserializer;
 impl UpdateStackInstancesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateStackInstancesOutput, XmlParseError> {
@@ -6978,10 +11623,10 @@ pub struct UpdateStackOutput {
     pub stack_id: Option<String>,
 }
 
-struct UpdateStackOutputDeserializer;
+pub(crate) struct UpdateStackOutputDeserializer;
 impl UpdateStackOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateStackOutput, XmlParseError> {
@@ -7003,7 +11648,7 @@ pub struct UpdateStackSetInput {
     /// <p>The Amazon Resource Number (ARN) of the IAM role to use to update this stack set.</p> <p>Specify an IAM role only if you are using customized administrator roles to control which users or groups can manage specific stack sets within the same administrator account. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/stacksets-prereqs.html">Granting Permissions for Stack Set Operations</a> in the <i>AWS CloudFormation User Guide</i>.</p> <p>If you specified a customized administrator role when you created the stack set, you must specify a customized administrator role, even if it is the same customized administrator role used with this stack set previously.</p>
     pub administration_role_arn: Option<String>,
     /// <p><p>In some cases, you must explicity acknowledge that your stack template contains certain capabilities in order for AWS CloudFormation to update the stack set and its associated stack instances.</p> <ul> <li> <p> <code>CAPABILITY<em>IAM</code> and <code>CAPABILITY</em>NAMED<em>IAM</code> </p> <p>Some stack templates might include resources that can affect permissions in your AWS account; for example, by creating new AWS Identity and Access Management (IAM) users. For those stacks sets, you must explicitly acknowledge this by specifying one of these capabilities.</p> <p>The following IAM resources require you to specify either the <code>CAPABILITY</em>IAM</code> or <code>CAPABILITY<em>NAMED</em>IAM</code> capability.</p> <ul> <li> <p>If you have IAM resources, you can specify either capability. </p> </li> <li> <p>If you have IAM resources with custom names, you <i>must</i> specify <code>CAPABILITY<em>NAMED</em>IAM</code>. </p> </li> <li> <p>If you don&#39;t specify either of these capabilities, AWS CloudFormation returns an <code>InsufficientCapabilities</code> error.</p> </li> </ul> <p>If your stack template contains these resources, we recommend that you review all permissions associated with them and edit their permissions if necessary.</p> <ul> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-accesskey.html"> AWS::IAM::AccessKey</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-group.html"> AWS::IAM::Group</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-instanceprofile.html"> AWS::IAM::InstanceProfile</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-policy.html"> AWS::IAM::Policy</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-resource-iam-role.html"> AWS::IAM::Role</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-user.html"> AWS::IAM::User</a> </p> </li> <li> <p> <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/aws-properties-iam-addusertogroup.html"> AWS::IAM::UserToGroupAddition</a> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p> </li> <li> <p> <code>CAPABILITY<em>AUTO</em>EXPAND</code> </p> <p>Some templates contain macros. If your stack template contains one or more macros, and you choose to update a stack directly from the processed template, without first reviewing the resulting changes in a change set, you must acknowledge this capability. For more information, see <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/template-macros.html">Using AWS CloudFormation Macros to Perform Custom Processing on Templates</a>.</p> <important> <p>Stack sets do not currently support macros in stack templates. (This includes the <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/create-reusable-transform-function-snippets-and-add-to-your-template-with-aws-include-transform.html">AWS::Include</a> and <a href="http://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/transform-aws-serverless.html">AWS::Serverless</a> transforms, which are macros hosted by AWS CloudFormation.) Even if you specify this capability, if you include a macro in your template the stack set operation will fail.</p> </important> </li> </ul></p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>A brief description of updates that you are making.</p>
     pub description: Option<String>,
     /// <p>The name of the IAM execution role to use to update the stack set. If you do not specify an execution role, AWS CloudFormation uses the <code>AWSCloudFormationStackSetExecutionRole</code> role for the stack set operation.</p> <p>Specify an IAM role only if you are using customized execution roles to control which stack resources users and groups can include in their stack sets. </p> <p> If you specify a customized execution role, AWS CloudFormation uses that role to update the stack. If you do not specify a customized execution role, AWS CloudFormation performs the update using the role previously associated with the stack set, so long as you have permissions to perform operations on the stack set.</p>
@@ -7028,6 +11673,143 @@ pub struct UpdateStackSetInput {
     pub use_previous_template: Option<bool>,
 }
 
+impl UpdateStackSetInput {
+    /// Returns a builder for `UpdateStackSetInput`, checking its required members when built.
+    pub fn builder() -> UpdateStackSetInputBuilder {
+        UpdateStackSetInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateStackSetInput`](struct.UpdateStackSetInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateStackSetInputBuilder {
+    accounts: Option<Vec<String>>,
+    administration_role_arn: Option<String>,
+    capabilities: Option<Vec<Capability>>,
+    description: Option<String>,
+    execution_role_name: Option<String>,
+    operation_id: Option<String>,
+    operation_preferences: Option<StackSetOperationPreferences>,
+    parameters: Option<Vec<Parameter>>,
+    regions: Option<Vec<String>>,
+    stack_set_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    template_body: Option<String>,
+    template_url: Option<String>,
+    use_previous_template: Option<bool>,
+}
+
+impl UpdateStackSetInputBuilder {
+    /// Sets the [`accounts`](struct.UpdateStackSetInput.html#structfield.accounts) member.
+    pub fn accounts<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.accounts = Some(value.into());
+        self
+    }
+
+    /// Sets the [`administration_role_arn`](struct.UpdateStackSetInput.html#structfield.administration_role_arn) member.
+    pub fn administration_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.administration_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`capabilities`](struct.UpdateStackSetInput.html#structfield.capabilities) member.
+    pub fn capabilities<T: Into<Vec<Capability>>>(mut self, value: T) -> Self {
+        self.capabilities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateStackSetInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_role_name`](struct.UpdateStackSetInput.html#structfield.execution_role_name) member.
+    pub fn execution_role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.execution_role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation_id`](struct.UpdateStackSetInput.html#structfield.operation_id) member.
+    pub fn operation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation_preferences`](struct.UpdateStackSetInput.html#structfield.operation_preferences) member.
+    pub fn operation_preferences<T: Into<StackSetOperationPreferences>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.operation_preferences = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.UpdateStackSetInput.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`regions`](struct.UpdateStackSetInput.html#structfield.regions) member.
+    pub fn regions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.regions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_set_name`](struct.UpdateStackSetInput.html#structfield.stack_set_name) member.
+    pub fn stack_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.UpdateStackSetInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_body`](struct.UpdateStackSetInput.html#structfield.template_body) member.
+    pub fn template_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_url`](struct.UpdateStackSetInput.html#structfield.template_url) member.
+    pub fn template_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_previous_template`](struct.UpdateStackSetInput.html#structfield.use_previous_template) member.
+    pub fn use_previous_template<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_previous_template = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateStackSetInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateStackSetInput, ::rusoto_core::BuildError> {
+        Ok(UpdateStackSetInput {
+            accounts: self.accounts,
+            administration_role_arn: self.administration_role_arn,
+            capabilities: self.capabilities,
+            description: self.description,
+            execution_role_name: self.execution_role_name,
+            operation_id: self.operation_id,
+            operation_preferences: self.operation_preferences,
+            parameters: self.parameters,
+            regions: self.regions,
+            stack_set_name: self
+                .stack_set_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_set_name"))?,
+            tags: self.tags,
+            template_body: self.template_body,
+            template_url: self.template_url,
+            use_previous_template: self.use_previous_template,
+        })
+    }
+}
+
 /// Serialize `UpdateStackSetInput` contents to a `SignedRequest`.
 struct UpdateStackSetInputSerializer;
 impl UpdateStackSetInputSerializer {
@@ -7063,9 +11845,11 @@ impl UpdateStackSetInputSerializer {
         if let Some(ref field_value) = obj.execution_role_name {
             params.put(&format!("{}{}", prefix, "ExecutionRoleName"), &field_value);
         }
-        if let Some(ref field_value) = obj.operation_id {
-            params.put(&format!("{}{}", prefix, "OperationId"), &field_value);
-        }
+        let field_value = obj
+            .operation_id
+            .clone()
+            .unwrap_or_else(rusoto_core::idempotency::generate_token);
+        params.put(&format!("{}{}", prefix, "OperationId"), &field_value);
         if let Some(ref field_value) = obj.operation_preferences {
             StackSetOperationPreferencesSerializer::serialize(
                 params,
@@ -7115,10 +11899,10 @@ pub struct UpdateStackSetOutput {
     pub operation_id: Option<String>,
 }
 
-struct UpdateStackSetOutputDeserializer;
+pub(crate) struct UpdateStackSetOutputDeserializer;
 impl UpdateStackSetOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateStackSetOutput, XmlParseError> {
@@ -7144,6 +11928,48 @@ pub struct UpdateTerminationProtectionInput {
     pub stack_name: String,
 }
 
+impl UpdateTerminationProtectionInput {
+    /// Returns a builder for `UpdateTerminationProtectionInput`, checking its required members when built.
+    pub fn builder() -> UpdateTerminationProtectionInputBuilder {
+        UpdateTerminationProtectionInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTerminationProtectionInput`](struct.UpdateTerminationProtectionInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateTerminationProtectionInputBuilder {
+    enable_termination_protection: Option<bool>,
+    stack_name: Option<String>,
+}
+
+impl UpdateTerminationProtectionInputBuilder {
+    /// Sets the [`enable_termination_protection`](struct.UpdateTerminationProtectionInput.html#structfield.enable_termination_protection) member.
+    pub fn enable_termination_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_termination_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_name`](struct.UpdateTerminationProtectionInput.html#structfield.stack_name) member.
+    pub fn stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTerminationProtectionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateTerminationProtectionInput, ::rusoto_core::BuildError> {
+        Ok(UpdateTerminationProtectionInput {
+            enable_termination_protection: self.enable_termination_protection.ok_or(
+                ::rusoto_core::BuildError::missing("enable_termination_protection"),
+            )?,
+            stack_name: self
+                .stack_name
+                .ok_or(::rusoto_core::BuildError::missing("stack_name"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateTerminationProtectionInput` contents to a `SignedRequest`.
 struct UpdateTerminationProtectionInputSerializer;
 impl UpdateTerminationProtectionInputSerializer {
@@ -7167,10 +11993,10 @@ pub struct UpdateTerminationProtectionOutput {
     pub stack_id: Option<String>,
 }
 
-struct UpdateTerminationProtectionOutputDeserializer;
+pub(crate) struct UpdateTerminationProtectionOutputDeserializer;
 impl UpdateTerminationProtectionOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateTerminationProtectionOutput, XmlParseError> {
@@ -7189,10 +12015,13 @@ impl UpdateTerminationProtectionOutputDeserializer {
         )
     }
 }
-struct UrlDeserializer;
+pub(crate) struct UrlDeserializer;
 impl UrlDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7200,10 +12029,13 @@ impl UrlDeserializer {
         Ok(obj)
     }
 }
-struct UsePreviousValueDeserializer;
+pub(crate) struct UsePreviousValueDeserializer;
 impl UsePreviousValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -7220,6 +12052,42 @@ pub struct ValidateTemplateInput {
     pub template_url: Option<String>,
 }
 
+impl ValidateTemplateInput {
+    /// Returns a builder for `ValidateTemplateInput`, checking its required members when built.
+    pub fn builder() -> ValidateTemplateInputBuilder {
+        ValidateTemplateInputBuilder::default()
+    }
+}
+
+/// Builds a [`ValidateTemplateInput`](struct.ValidateTemplateInput.html).
+#[derive(Default, Debug)]
+pub struct ValidateTemplateInputBuilder {
+    template_body: Option<String>,
+    template_url: Option<String>,
+}
+
+impl ValidateTemplateInputBuilder {
+    /// Sets the [`template_body`](struct.ValidateTemplateInput.html#structfield.template_body) member.
+    pub fn template_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_url`](struct.ValidateTemplateInput.html#structfield.template_url) member.
+    pub fn template_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `ValidateTemplateInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ValidateTemplateInput, ::rusoto_core::BuildError> {
+        Ok(ValidateTemplateInput {
+            template_body: self.template_body,
+            template_url: self.template_url,
+        })
+    }
+}
+
 /// Serialize `ValidateTemplateInput` contents to a `SignedRequest`.
 struct ValidateTemplateInputSerializer;
 impl ValidateTemplateInputSerializer {
@@ -7242,7 +12110,7 @@ impl ValidateTemplateInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ValidateTemplateOutput {
     /// <p>The capabilities found within the template. If your template contains IAM resources, you must specify the CAPABILITY_IAM or CAPABILITY_NAMED_IAM value for this parameter when you use the <a>CreateStack</a> or <a>UpdateStack</a> actions with your template; otherwise, those actions return an InsufficientCapabilities error.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/AWSCloudFormation/latest/UserGuide/using-iam-template.html#capabilities">Acknowledging IAM Resources in AWS CloudFormation Templates</a>.</p>
-    pub capabilities: Option<Vec<String>>,
+    pub capabilities: Option<Vec<Capability>>,
     /// <p>The list of resources that generated the values in the <code>Capabilities</code> response element.</p>
     pub capabilities_reason: Option<String>,
     /// <p>A list of the transforms that are declared in the template.</p>
@@ -7253,10 +12121,10 @@ pub struct ValidateTemplateOutput {
     pub parameters: Option<Vec<TemplateParameter>>,
 }
 
-struct ValidateTemplateOutputDeserializer;
+pub(crate) struct ValidateTemplateOutputDeserializer;
 impl ValidateTemplateOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ValidateTemplateOutput, XmlParseError> {
@@ -7293,10 +12161,13 @@ impl ValidateTemplateOutputDeserializer {
         })
     }
 }
-struct ValueDeserializer;
+pub(crate) struct ValueDeserializer;
 impl ValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7304,10 +12175,13 @@ impl ValueDeserializer {
         Ok(obj)
     }
 }
-struct VersionDeserializer;
+pub(crate) struct VersionDeserializer;
 impl VersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -9790,6 +14664,280 @@ pub trait CloudFormation {
         input: ValidateTemplateInput,
     ) -> RusotoFuture<ValidateTemplateOutput, ValidateTemplateError>;
 }
+impl<T: ?Sized + CloudFormation> CloudFormation for ::std::sync::Arc<T> {
+    fn cancel_update_stack(
+        &self,
+        input: CancelUpdateStackInput,
+    ) -> RusotoFuture<(), CancelUpdateStackError> {
+        (**self).cancel_update_stack(input)
+    }
+    fn continue_update_rollback(
+        &self,
+        input: ContinueUpdateRollbackInput,
+    ) -> RusotoFuture<ContinueUpdateRollbackOutput, ContinueUpdateRollbackError> {
+        (**self).continue_update_rollback(input)
+    }
+    fn create_change_set(
+        &self,
+        input: CreateChangeSetInput,
+    ) -> RusotoFuture<CreateChangeSetOutput, CreateChangeSetError> {
+        (**self).create_change_set(input)
+    }
+    fn create_stack(
+        &self,
+        input: CreateStackInput,
+    ) -> RusotoFuture<CreateStackOutput, CreateStackError> {
+        (**self).create_stack(input)
+    }
+    fn create_stack_instances(
+        &self,
+        input: CreateStackInstancesInput,
+    ) -> RusotoFuture<CreateStackInstancesOutput, CreateStackInstancesError> {
+        (**self).create_stack_instances(input)
+    }
+    fn create_stack_set(
+        &self,
+        input: CreateStackSetInput,
+    ) -> RusotoFuture<CreateStackSetOutput, CreateStackSetError> {
+        (**self).create_stack_set(input)
+    }
+    fn delete_change_set(
+        &self,
+        input: DeleteChangeSetInput,
+    ) -> RusotoFuture<DeleteChangeSetOutput, DeleteChangeSetError> {
+        (**self).delete_change_set(input)
+    }
+    fn delete_stack(&self, input: DeleteStackInput) -> RusotoFuture<(), DeleteStackError> {
+        (**self).delete_stack(input)
+    }
+    fn delete_stack_instances(
+        &self,
+        input: DeleteStackInstancesInput,
+    ) -> RusotoFuture<DeleteStackInstancesOutput, DeleteStackInstancesError> {
+        (**self).delete_stack_instances(input)
+    }
+    fn delete_stack_set(
+        &self,
+        input: DeleteStackSetInput,
+    ) -> RusotoFuture<DeleteStackSetOutput, DeleteStackSetError> {
+        (**self).delete_stack_set(input)
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        (**self).describe_account_limits(input)
+    }
+    fn describe_change_set(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> RusotoFuture<DescribeChangeSetOutput, DescribeChangeSetError> {
+        (**self).describe_change_set(input)
+    }
+    fn describe_stack_drift_detection_status(
+        &self,
+        input: DescribeStackDriftDetectionStatusInput,
+    ) -> RusotoFuture<DescribeStackDriftDetectionStatusOutput, DescribeStackDriftDetectionStatusError>
+    {
+        (**self).describe_stack_drift_detection_status(input)
+    }
+    fn describe_stack_events(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> RusotoFuture<DescribeStackEventsOutput, DescribeStackEventsError> {
+        (**self).describe_stack_events(input)
+    }
+    fn describe_stack_instance(
+        &self,
+        input: DescribeStackInstanceInput,
+    ) -> RusotoFuture<DescribeStackInstanceOutput, DescribeStackInstanceError> {
+        (**self).describe_stack_instance(input)
+    }
+    fn describe_stack_resource(
+        &self,
+        input: DescribeStackResourceInput,
+    ) -> RusotoFuture<DescribeStackResourceOutput, DescribeStackResourceError> {
+        (**self).describe_stack_resource(input)
+    }
+    fn describe_stack_resource_drifts(
+        &self,
+        input: DescribeStackResourceDriftsInput,
+    ) -> RusotoFuture<DescribeStackResourceDriftsOutput, DescribeStackResourceDriftsError> {
+        (**self).describe_stack_resource_drifts(input)
+    }
+    fn describe_stack_resources(
+        &self,
+        input: DescribeStackResourcesInput,
+    ) -> RusotoFuture<DescribeStackResourcesOutput, DescribeStackResourcesError> {
+        (**self).describe_stack_resources(input)
+    }
+    fn describe_stack_set(
+        &self,
+        input: DescribeStackSetInput,
+    ) -> RusotoFuture<DescribeStackSetOutput, DescribeStackSetError> {
+        (**self).describe_stack_set(input)
+    }
+    fn describe_stack_set_operation(
+        &self,
+        input: DescribeStackSetOperationInput,
+    ) -> RusotoFuture<DescribeStackSetOperationOutput, DescribeStackSetOperationError> {
+        (**self).describe_stack_set_operation(input)
+    }
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksInput,
+    ) -> RusotoFuture<DescribeStacksOutput, DescribeStacksError> {
+        (**self).describe_stacks(input)
+    }
+    fn detect_stack_drift(
+        &self,
+        input: DetectStackDriftInput,
+    ) -> RusotoFuture<DetectStackDriftOutput, DetectStackDriftError> {
+        (**self).detect_stack_drift(input)
+    }
+    fn detect_stack_resource_drift(
+        &self,
+        input: DetectStackResourceDriftInput,
+    ) -> RusotoFuture<DetectStackResourceDriftOutput, DetectStackResourceDriftError> {
+        (**self).detect_stack_resource_drift(input)
+    }
+    fn estimate_template_cost(
+        &self,
+        input: EstimateTemplateCostInput,
+    ) -> RusotoFuture<EstimateTemplateCostOutput, EstimateTemplateCostError> {
+        (**self).estimate_template_cost(input)
+    }
+    fn execute_change_set(
+        &self,
+        input: ExecuteChangeSetInput,
+    ) -> RusotoFuture<ExecuteChangeSetOutput, ExecuteChangeSetError> {
+        (**self).execute_change_set(input)
+    }
+    fn get_stack_policy(
+        &self,
+        input: GetStackPolicyInput,
+    ) -> RusotoFuture<GetStackPolicyOutput, GetStackPolicyError> {
+        (**self).get_stack_policy(input)
+    }
+    fn get_template(
+        &self,
+        input: GetTemplateInput,
+    ) -> RusotoFuture<GetTemplateOutput, GetTemplateError> {
+        (**self).get_template(input)
+    }
+    fn get_template_summary(
+        &self,
+        input: GetTemplateSummaryInput,
+    ) -> RusotoFuture<GetTemplateSummaryOutput, GetTemplateSummaryError> {
+        (**self).get_template_summary(input)
+    }
+    fn list_change_sets(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> RusotoFuture<ListChangeSetsOutput, ListChangeSetsError> {
+        (**self).list_change_sets(input)
+    }
+    fn list_exports(
+        &self,
+        input: ListExportsInput,
+    ) -> RusotoFuture<ListExportsOutput, ListExportsError> {
+        (**self).list_exports(input)
+    }
+    fn list_imports(
+        &self,
+        input: ListImportsInput,
+    ) -> RusotoFuture<ListImportsOutput, ListImportsError> {
+        (**self).list_imports(input)
+    }
+    fn list_stack_instances(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> RusotoFuture<ListStackInstancesOutput, ListStackInstancesError> {
+        (**self).list_stack_instances(input)
+    }
+    fn list_stack_resources(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> RusotoFuture<ListStackResourcesOutput, ListStackResourcesError> {
+        (**self).list_stack_resources(input)
+    }
+    fn list_stack_set_operation_results(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> RusotoFuture<ListStackSetOperationResultsOutput, ListStackSetOperationResultsError> {
+        (**self).list_stack_set_operation_results(input)
+    }
+    fn list_stack_set_operations(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> RusotoFuture<ListStackSetOperationsOutput, ListStackSetOperationsError> {
+        (**self).list_stack_set_operations(input)
+    }
+    fn list_stack_sets(
+        &self,
+        input: ListStackSetsInput,
+    ) -> RusotoFuture<ListStackSetsOutput, ListStackSetsError> {
+        (**self).list_stack_sets(input)
+    }
+    fn list_stacks(
+        &self,
+        input: ListStacksInput,
+    ) -> RusotoFuture<ListStacksOutput, ListStacksError> {
+        (**self).list_stacks(input)
+    }
+    fn set_stack_policy(
+        &self,
+        input: SetStackPolicyInput,
+    ) -> RusotoFuture<(), SetStackPolicyError> {
+        (**self).set_stack_policy(input)
+    }
+    fn signal_resource(&self, input: SignalResourceInput) -> RusotoFuture<(), SignalResourceError> {
+        (**self).signal_resource(input)
+    }
+    fn stop_stack_set_operation(
+        &self,
+        input: StopStackSetOperationInput,
+    ) -> RusotoFuture<StopStackSetOperationOutput, StopStackSetOperationError> {
+        (**self).stop_stack_set_operation(input)
+    }
+    fn update_stack(
+        &self,
+        input: UpdateStackInput,
+    ) -> RusotoFuture<UpdateStackOutput, UpdateStackError> {
+        (**self).update_stack(input)
+    }
+    fn update_stack_instances(
+        &self,
+        input: UpdateStackInstancesInput,
+    ) -> RusotoFuture<UpdateStackInstancesOutput, UpdateStackInstancesError> {
+        (**self).update_stack_instances(input)
+    }
+    fn update_stack_set(
+        &self,
+        input: UpdateStackSetInput,
+    ) -> RusotoFuture<UpdateStackSetOutput, UpdateStackSetError> {
+        (**self).update_stack_set(input)
+    }
+    fn update_termination_protection(
+        &self,
+        input: UpdateTerminationProtectionInput,
+    ) -> RusotoFuture<UpdateTerminationProtectionOutput, UpdateTerminationProtectionError> {
+        (**self).update_termination_protection(input)
+    }
+    fn validate_template(
+        &self,
+        input: ValidateTemplateInput,
+    ) -> RusotoFuture<ValidateTemplateOutput, ValidateTemplateError> {
+        (**self).validate_template(input)
+    }
+}
+/// The error codes AWS CloudFormation retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS CloudFormation API.
 #[derive(Clone)]
 pub struct CloudFormationClient {
@@ -9805,6 +14953,27 @@ impl CloudFormationClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CLOUDFORMATION`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CloudFormationClient {
+        Self::new(region::Region::from_env_for_service("CLOUDFORMATION"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `cloudformation`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CloudFormationClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("cloudformation", "CLOUDFORMATION"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -9823,7 +14992,54 @@ impl CloudFormationClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudFormationClient {
-        CloudFormationClient { client, region }
+        CloudFormationClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "cloudformation")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "cloudformation",
+            "cloudformation",
+            "CLOUDFORMATION",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CloudFormation` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CloudFormation + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CloudFormationClient {
+    fn service_name(&self) -> &'static str {
+        "AWS CloudFormation"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "cloudformation"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CloudFormationClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -10411,7 +15627,15 @@ impl CloudFormation for CloudFormationClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DescribeStackDriftDetectionStatusOutput::default();
+                    result = DescribeStackDriftDetectionStatusOutput {
+                        detection_status: StackDriftDetectionStatus::Unknown(String::new()),
+                        detection_status_reason: Default::default(),
+                        drifted_stack_resource_count: Default::default(),
+                        stack_drift_detection_id: Default::default(),
+                        stack_drift_status: Default::default(),
+                        stack_id: Default::default(),
+                        timestamp: Default::default(),
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -10902,7 +16126,22 @@ impl CloudFormation for CloudFormationClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DetectStackResourceDriftOutput::default();
+                    result = DetectStackResourceDriftOutput {
+                        stack_resource_drift: StackResourceDrift {
+                            actual_properties: Default::default(),
+                            expected_properties: Default::default(),
+                            logical_resource_id: Default::default(),
+                            physical_resource_id: Default::default(),
+                            physical_resource_id_context: Default::default(),
+                            property_differences: Default::default(),
+                            resource_type: Default::default(),
+                            stack_id: Default::default(),
+                            stack_resource_drift_status: StackResourceDriftStatus::Unknown(
+                                String::new(),
+                            ),
+                            timestamp: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
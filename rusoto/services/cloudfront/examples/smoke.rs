@@ -0,0 +1,42 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon CloudFront clients.
+//!
+//! Makes a single read-only `ListCloudFrontOriginAccessIdentities` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_cloudfront::{
+    CloudFront, CloudFrontClient, ListCloudFrontOriginAccessIdentitiesRequest,
+};
+
+fn main() {
+    let client = CloudFrontClient::from_env();
+    match client
+        .list_cloud_front_origin_access_identities(
+            ListCloudFrontOriginAccessIdentitiesRequest::default(),
+        )
+        .sync()
+    {
+        Ok(_) => println!("ListCloudFrontOriginAccessIdentities succeeded"),
+        Err(error) => {
+            eprintln!("ListCloudFrontOriginAccessIdentities failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -28,6 +28,7 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use std::io::Write;
 use std::str::FromStr;
 use xml;
@@ -46,10 +47,10 @@ pub struct ActiveTrustedSigners {
     pub quantity: i64,
 }
 
-struct ActiveTrustedSignersDeserializer;
+pub(crate) struct ActiveTrustedSignersDeserializer;
 impl ActiveTrustedSignersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ActiveTrustedSigners, XmlParseError> {
@@ -72,10 +73,10 @@ impl ActiveTrustedSignersDeserializer {
         })
     }
 }
-struct AliasListDeserializer;
+pub(crate) struct AliasListDeserializer;
 impl AliasListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -119,10 +120,10 @@ pub struct Aliases {
     pub quantity: i64,
 }
 
-struct AliasesDeserializer;
+pub(crate) struct AliasesDeserializer;
 impl AliasesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Aliases, XmlParseError> {
@@ -173,15 +174,15 @@ impl AliasesSerializer {
 pub struct AllowedMethods {
     pub cached_methods: Option<CachedMethods>,
     /// <p>A complex type that contains the HTTP methods that you want CloudFront to process and forward to your origin.</p>
-    pub items: Vec<String>,
+    pub items: Vec<Method>,
     /// <p>The number of HTTP methods that you want CloudFront to forward to your origin. Valid values are 2 (for <code>GET</code> and <code>HEAD</code> requests), 3 (for <code>GET</code>, <code>HEAD</code>, and <code>OPTIONS</code> requests) and 7 (for <code>GET, HEAD, OPTIONS, PUT, PATCH, POST</code>, and <code>DELETE</code> requests).</p>
     pub quantity: i64,
 }
 
-struct AllowedMethodsDeserializer;
+pub(crate) struct AllowedMethodsDeserializer;
 impl AllowedMethodsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AllowedMethods, XmlParseError> {
@@ -233,10 +234,10 @@ impl AllowedMethodsSerializer {
     }
 }
 
-struct AwsAccountNumberListDeserializer;
+pub(crate) struct AwsAccountNumberListDeserializer;
 impl AwsAccountNumberListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -271,10 +272,13 @@ impl AwsAccountNumberListSerializer {
     }
 }
 
-struct BooleanDeserializer;
+pub(crate) struct BooleanDeserializer;
 impl BooleanDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -304,7 +308,7 @@ impl BooleanSerializer {
 }
 
 /// <p>A complex type that describes how CloudFront processes requests.</p> <p>You must create at least as many cache behaviors (including the default cache behavior) as you have origins if you want CloudFront to distribute objects from all of the origins. Each cache behavior specifies the one origin from which you want CloudFront to get objects. If you have two origins and only the default cache behavior, the default cache behavior will cause CloudFront to get objects from one of the origins, but the other origin is never used.</p> <p>For the current limit on the number of cache behaviors that you can add to a distribution, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_cloudfront">Amazon CloudFront Limits</a> in the <i>AWS General Reference</i>.</p> <p>If you don't want to specify any cache behaviors, include only an empty <code>CacheBehaviors</code> element. Don't include an empty <code>CacheBehavior</code> element, or CloudFront returns a <code>MalformedXML</code> error.</p> <p>To delete all cache behaviors in an existing distribution, update the distribution configuration and include only an empty <code>CacheBehaviors</code> element.</p> <p>To add, change, or remove one or more cache behaviors, update the distribution configuration and specify all of the cache behaviors that you want to include in the updated distribution.</p> <p>For more information about cache behaviors, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/distribution-web-values-specify.html#DownloadDistValuesCacheBehavior">Cache Behaviors</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CacheBehavior {
     pub allowed_methods: Option<AllowedMethods>,
     /// <p>Whether you want CloudFront to automatically compress certain files for this cache behavior. If so, specify true; if not, specify false. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/ServingCompressedFiles.html">Serving Compressed Files</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
@@ -330,78 +334,105 @@ pub struct CacheBehavior {
     /// <p>A complex type that specifies the AWS accounts, if any, that you want to allow to create signed URLs for private content.</p> <p>If you want to require signed URLs in requests for objects in the target origin that match the <code>PathPattern</code> for this cache behavior, specify <code>true</code> for <code>Enabled</code>, and specify the applicable values for <code>Quantity</code> and <code>Items</code>. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon Amazon CloudFront Developer Guide</i>.</p> <p>If you don't want to require signed URLs in requests for objects that match <code>PathPattern</code>, specify <code>false</code> for <code>Enabled</code> and <code>0</code> for <code>Quantity</code>. Omit <code>Items</code>.</p> <p>To add, change, or remove one or more trusted signers, change <code>Enabled</code> to <code>true</code> (if it's currently <code>false</code>), change <code>Quantity</code> as applicable, and specify all of the trusted signers that you want to include in the updated distribution.</p>
     pub trusted_signers: TrustedSigners,
     /// <p><p>The protocol that viewers can use to access the files in the origin specified by <code>TargetOriginId</code> when a request matches the path pattern in <code>PathPattern</code>. You can specify the following options:</p> <ul> <li> <p> <code>allow-all</code>: Viewers can use HTTP or HTTPS.</p> </li> <li> <p> <code>redirect-to-https</code>: If a viewer submits an HTTP request, CloudFront returns an HTTP status code of 301 (Moved Permanently) to the viewer along with the HTTPS URL. The viewer then resubmits the request using the new URL. </p> </li> <li> <p> <code>https-only</code>: If a viewer sends an HTTP request, CloudFront returns an HTTP status code of 403 (Forbidden). </p> </li> </ul> <p>For more information about requiring the HTTPS protocol, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/SecureConnections.html">Using an HTTPS Connection to Access Your Objects</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <note> <p>The only way to guarantee that viewers retrieve an object that was fetched from the origin using HTTPS is never to use any other protocol to fetch the object. If you have recently changed from HTTP to HTTPS, we recommend that you clear your objects&#39; cache because cached objects are protocol agnostic. That means that an edge location will return an object from the cache regardless of whether the current request protocol matches the protocol used previously. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/Expiration.html">Specifying How Long Objects and Errors Stay in a CloudFront Edge Cache (Expiration)</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </note></p>
-    pub viewer_protocol_policy: String,
+    pub viewer_protocol_policy: ViewerProtocolPolicy,
 }
 
-struct CacheBehaviorDeserializer;
+pub(crate) struct CacheBehaviorDeserializer;
 impl CacheBehaviorDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheBehavior, XmlParseError> {
-        deserialize_elements::<_, CacheBehavior, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "AllowedMethods" => {
-                    obj.allowed_methods = Some(AllowedMethodsDeserializer::deserialize(
-                        "AllowedMethods",
-                        stack,
-                    )?);
-                }
-                "Compress" => {
-                    obj.compress = Some(BooleanDeserializer::deserialize("Compress", stack)?);
-                }
-                "DefaultTTL" => {
-                    obj.default_ttl = Some(LongDeserializer::deserialize("DefaultTTL", stack)?);
-                }
-                "FieldLevelEncryptionId" => {
-                    obj.field_level_encryption_id = Some(StringDeserializer::deserialize(
-                        "FieldLevelEncryptionId",
-                        stack,
-                    )?);
-                }
-                "ForwardedValues" => {
-                    obj.forwarded_values =
-                        ForwardedValuesDeserializer::deserialize("ForwardedValues", stack)?;
-                }
-                "LambdaFunctionAssociations" => {
-                    obj.lambda_function_associations =
-                        Some(LambdaFunctionAssociationsDeserializer::deserialize(
-                            "LambdaFunctionAssociations",
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            CacheBehavior {
+                allowed_methods: Default::default(),
+                compress: Default::default(),
+                default_ttl: Default::default(),
+                field_level_encryption_id: Default::default(),
+                forwarded_values: ForwardedValues {
+                    cookies: CookiePreference {
+                        forward: ItemSelection::Unknown(String::new()),
+                        whitelisted_names: Default::default(),
+                    },
+                    headers: Default::default(),
+                    query_string: Default::default(),
+                    query_string_cache_keys: Default::default(),
+                },
+                lambda_function_associations: Default::default(),
+                max_ttl: Default::default(),
+                min_ttl: Default::default(),
+                path_pattern: Default::default(),
+                smooth_streaming: Default::default(),
+                target_origin_id: Default::default(),
+                trusted_signers: Default::default(),
+                viewer_protocol_policy: ViewerProtocolPolicy::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "AllowedMethods" => {
+                        obj.allowed_methods = Some(AllowedMethodsDeserializer::deserialize(
+                            "AllowedMethods",
                             stack,
                         )?);
+                    }
+                    "Compress" => {
+                        obj.compress = Some(BooleanDeserializer::deserialize("Compress", stack)?);
+                    }
+                    "DefaultTTL" => {
+                        obj.default_ttl = Some(LongDeserializer::deserialize("DefaultTTL", stack)?);
+                    }
+                    "FieldLevelEncryptionId" => {
+                        obj.field_level_encryption_id = Some(StringDeserializer::deserialize(
+                            "FieldLevelEncryptionId",
+                            stack,
+                        )?);
+                    }
+                    "ForwardedValues" => {
+                        obj.forwarded_values =
+                            ForwardedValuesDeserializer::deserialize("ForwardedValues", stack)?;
+                    }
+                    "LambdaFunctionAssociations" => {
+                        obj.lambda_function_associations =
+                            Some(LambdaFunctionAssociationsDeserializer::deserialize(
+                                "LambdaFunctionAssociations",
+                                stack,
+                            )?);
+                    }
+                    "MaxTTL" => {
+                        obj.max_ttl = Some(LongDeserializer::deserialize("MaxTTL", stack)?);
+                    }
+                    "MinTTL" => {
+                        obj.min_ttl = LongDeserializer::deserialize("MinTTL", stack)?;
+                    }
+                    "PathPattern" => {
+                        obj.path_pattern = StringDeserializer::deserialize("PathPattern", stack)?;
+                    }
+                    "SmoothStreaming" => {
+                        obj.smooth_streaming =
+                            Some(BooleanDeserializer::deserialize("SmoothStreaming", stack)?);
+                    }
+                    "TargetOriginId" => {
+                        obj.target_origin_id =
+                            StringDeserializer::deserialize("TargetOriginId", stack)?;
+                    }
+                    "TrustedSigners" => {
+                        obj.trusted_signers =
+                            TrustedSignersDeserializer::deserialize("TrustedSigners", stack)?;
+                    }
+                    "ViewerProtocolPolicy" => {
+                        obj.viewer_protocol_policy = ViewerProtocolPolicyDeserializer::deserialize(
+                            "ViewerProtocolPolicy",
+                            stack,
+                        )?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                "MaxTTL" => {
-                    obj.max_ttl = Some(LongDeserializer::deserialize("MaxTTL", stack)?);
-                }
-                "MinTTL" => {
-                    obj.min_ttl = LongDeserializer::deserialize("MinTTL", stack)?;
-                }
-                "PathPattern" => {
-                    obj.path_pattern = StringDeserializer::deserialize("PathPattern", stack)?;
-                }
-                "SmoothStreaming" => {
-                    obj.smooth_streaming =
-                        Some(BooleanDeserializer::deserialize("SmoothStreaming", stack)?);
-                }
-                "TargetOriginId" => {
-                    obj.target_origin_id =
-                        StringDeserializer::deserialize("TargetOriginId", stack)?;
-                }
-                "TrustedSigners" => {
-                    obj.trusted_signers =
-                        TrustedSignersDeserializer::deserialize("TrustedSigners", stack)?;
-                }
-                "ViewerProtocolPolicy" => {
-                    obj.viewer_protocol_policy = ViewerProtocolPolicyDeserializer::deserialize(
-                        "ViewerProtocolPolicy",
-                        stack,
-                    )?;
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -503,10 +534,10 @@ impl CacheBehaviorSerializer {
     }
 }
 
-struct CacheBehaviorListDeserializer;
+pub(crate) struct CacheBehaviorListDeserializer;
 impl CacheBehaviorListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CacheBehavior>, XmlParseError> {
@@ -553,10 +584,10 @@ pub struct CacheBehaviors {
     pub quantity: i64,
 }
 
-struct CacheBehaviorsDeserializer;
+pub(crate) struct CacheBehaviorsDeserializer;
 impl CacheBehaviorsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheBehaviors, XmlParseError> {
@@ -606,15 +637,15 @@ impl CacheBehaviorsSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CachedMethods {
     /// <p>A complex type that contains the HTTP methods that you want CloudFront to cache responses to.</p>
-    pub items: Vec<String>,
+    pub items: Vec<Method>,
     /// <p>The number of HTTP methods for which you want CloudFront to cache responses. Valid values are <code>2</code> (for caching responses to <code>GET</code> and <code>HEAD</code> requests) and <code>3</code> (for caching responses to <code>GET</code>, <code>HEAD</code>, and <code>OPTIONS</code> requests).</p>
     pub quantity: i64,
 }
 
-struct CachedMethodsDeserializer;
+pub(crate) struct CachedMethodsDeserializer;
 impl CachedMethodsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CachedMethods, XmlParseError> {
@@ -668,10 +699,10 @@ pub struct CloudFrontOriginAccessIdentity {
     pub s3_canonical_user_id: String,
 }
 
-struct CloudFrontOriginAccessIdentityDeserializer;
+pub(crate) struct CloudFrontOriginAccessIdentityDeserializer;
 impl CloudFrontOriginAccessIdentityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CloudFrontOriginAccessIdentity, XmlParseError> {
@@ -711,10 +742,10 @@ pub struct CloudFrontOriginAccessIdentityConfig {
     pub comment: String,
 }
 
-struct CloudFrontOriginAccessIdentityConfigDeserializer;
+pub(crate) struct CloudFrontOriginAccessIdentityConfigDeserializer;
 impl CloudFrontOriginAccessIdentityConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CloudFrontOriginAccessIdentityConfig, XmlParseError> {
@@ -783,10 +814,10 @@ pub struct CloudFrontOriginAccessIdentityList {
     pub quantity: i64,
 }
 
-struct CloudFrontOriginAccessIdentityListDeserializer;
+pub(crate) struct CloudFrontOriginAccessIdentityListDeserializer;
 impl CloudFrontOriginAccessIdentityListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CloudFrontOriginAccessIdentityList, XmlParseError> {
@@ -836,10 +867,10 @@ pub struct CloudFrontOriginAccessIdentitySummary {
     pub s3_canonical_user_id: String,
 }
 
-struct CloudFrontOriginAccessIdentitySummaryDeserializer;
+pub(crate) struct CloudFrontOriginAccessIdentitySummaryDeserializer;
 impl CloudFrontOriginAccessIdentitySummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CloudFrontOriginAccessIdentitySummary, XmlParseError> {
@@ -865,10 +896,10 @@ impl CloudFrontOriginAccessIdentitySummaryDeserializer {
         )
     }
 }
-struct CloudFrontOriginAccessIdentitySummaryListDeserializer;
+pub(crate) struct CloudFrontOriginAccessIdentitySummaryListDeserializer;
 impl CloudFrontOriginAccessIdentitySummaryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CloudFrontOriginAccessIdentitySummary>, XmlParseError> {
@@ -888,38 +919,47 @@ impl CloudFrontOriginAccessIdentitySummaryListDeserializer {
     }
 }
 /// <p>A field-level encryption content type profile. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ContentTypeProfile {
     /// <p>The content type for a field-level encryption content type-profile mapping. </p>
     pub content_type: String,
     /// <p>The format for a field-level encryption content type-profile mapping. </p>
-    pub format: String,
+    pub format: Format,
     /// <p>The profile ID for a field-level encryption content type-profile mapping. </p>
     pub profile_id: Option<String>,
 }
 
-struct ContentTypeProfileDeserializer;
+pub(crate) struct ContentTypeProfileDeserializer;
 impl ContentTypeProfileDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ContentTypeProfile, XmlParseError> {
-        deserialize_elements::<_, ContentTypeProfile, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "ContentType" => {
-                    obj.content_type = StringDeserializer::deserialize("ContentType", stack)?;
-                }
-                "Format" => {
-                    obj.format = FormatDeserializer::deserialize("Format", stack)?;
-                }
-                "ProfileId" => {
-                    obj.profile_id = Some(StringDeserializer::deserialize("ProfileId", stack)?);
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            ContentTypeProfile {
+                content_type: Default::default(),
+                format: Format::Unknown(String::new()),
+                profile_id: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "ContentType" => {
+                        obj.content_type = StringDeserializer::deserialize("ContentType", stack)?;
+                    }
+                    "Format" => {
+                        obj.format = FormatDeserializer::deserialize("Format", stack)?;
+                    }
+                    "ProfileId" => {
+                        obj.profile_id = Some(StringDeserializer::deserialize("ProfileId", stack)?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -968,10 +1008,10 @@ pub struct ContentTypeProfileConfig {
     pub forward_when_content_type_is_unknown: bool,
 }
 
-struct ContentTypeProfileConfigDeserializer;
+pub(crate) struct ContentTypeProfileConfigDeserializer;
 impl ContentTypeProfileConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ContentTypeProfileConfig, XmlParseError> {
@@ -1029,10 +1069,10 @@ impl ContentTypeProfileConfigSerializer {
     }
 }
 
-struct ContentTypeProfileListDeserializer;
+pub(crate) struct ContentTypeProfileListDeserializer;
 impl ContentTypeProfileListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ContentTypeProfile>, XmlParseError> {
@@ -1079,10 +1119,10 @@ pub struct ContentTypeProfiles {
     pub quantity: i64,
 }
 
-struct ContentTypeProfilesDeserializer;
+pub(crate) struct ContentTypeProfilesDeserializer;
 impl ContentTypeProfilesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ContentTypeProfiles, XmlParseError> {
@@ -1128,10 +1168,10 @@ impl ContentTypeProfilesSerializer {
     }
 }
 
-struct CookieNameListDeserializer;
+pub(crate) struct CookieNameListDeserializer;
 impl CookieNameListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -1175,10 +1215,10 @@ pub struct CookieNames {
     pub quantity: i64,
 }
 
-struct CookieNamesDeserializer;
+pub(crate) struct CookieNamesDeserializer;
 impl CookieNamesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CookieNames, XmlParseError> {
@@ -1225,36 +1265,44 @@ impl CookieNamesSerializer {
 }
 
 /// <p>A complex type that specifies whether you want CloudFront to forward cookies to the origin and, if so, which ones. For more information about forwarding cookies to the origin, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/Cookies.html">How CloudFront Forwards, Caches, and Logs Cookies</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CookiePreference {
     /// <p>Specifies which cookies to forward to the origin for this cache behavior: all, none, or the list of cookies specified in the <code>WhitelistedNames</code> complex type.</p> <p>Amazon S3 doesn't process cookies. When the cache behavior is forwarding requests to an Amazon S3 origin, specify none for the <code>Forward</code> element. </p>
-    pub forward: String,
+    pub forward: ItemSelection,
     /// <p>Required if you specify <code>whitelist</code> for the value of <code>Forward:</code>. A complex type that specifies how many different cookies you want CloudFront to forward to the origin for this cache behavior and, if you want to forward selected cookies, the names of those cookies.</p> <p>If you specify <code>all</code> or none for the value of <code>Forward</code>, omit <code>WhitelistedNames</code>. If you change the value of <code>Forward</code> from <code>whitelist</code> to all or none and you don't delete the <code>WhitelistedNames</code> element and its child elements, CloudFront deletes them automatically.</p> <p>For the current limit on the number of cookie names that you can whitelist for each cache behavior, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_cloudfront">Amazon CloudFront Limits</a> in the <i>AWS General Reference</i>.</p>
     pub whitelisted_names: Option<CookieNames>,
 }
 
-struct CookiePreferenceDeserializer;
+pub(crate) struct CookiePreferenceDeserializer;
 impl CookiePreferenceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CookiePreference, XmlParseError> {
-        deserialize_elements::<_, CookiePreference, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Forward" => {
-                    obj.forward = ItemSelectionDeserializer::deserialize("Forward", stack)?;
-                }
-                "WhitelistedNames" => {
-                    obj.whitelisted_names = Some(CookieNamesDeserializer::deserialize(
-                        "WhitelistedNames",
-                        stack,
-                    )?);
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            CookiePreference {
+                forward: ItemSelection::Unknown(String::new()),
+                whitelisted_names: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Forward" => {
+                        obj.forward = ItemSelectionDeserializer::deserialize("Forward", stack)?;
+                    }
+                    "WhitelistedNames" => {
+                        obj.whitelisted_names = Some(CookieNamesDeserializer::deserialize(
+                            "WhitelistedNames",
+                            stack,
+                        )?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -1290,6 +1338,46 @@ pub struct CreateCloudFrontOriginAccessIdentityRequest {
     pub cloud_front_origin_access_identity_config: CloudFrontOriginAccessIdentityConfig,
 }
 
+impl CreateCloudFrontOriginAccessIdentityRequest {
+    /// Returns a builder for `CreateCloudFrontOriginAccessIdentityRequest`, checking its required members when built.
+    pub fn builder() -> CreateCloudFrontOriginAccessIdentityRequestBuilder {
+        CreateCloudFrontOriginAccessIdentityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCloudFrontOriginAccessIdentityRequest`](struct.CreateCloudFrontOriginAccessIdentityRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCloudFrontOriginAccessIdentityRequestBuilder {
+    cloud_front_origin_access_identity_config: Option<CloudFrontOriginAccessIdentityConfig>,
+}
+
+impl CreateCloudFrontOriginAccessIdentityRequestBuilder {
+    /// Sets the [`cloud_front_origin_access_identity_config`](struct.CreateCloudFrontOriginAccessIdentityRequest.html#structfield.cloud_front_origin_access_identity_config) member.
+    pub fn cloud_front_origin_access_identity_config<
+        T: Into<CloudFrontOriginAccessIdentityConfig>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cloud_front_origin_access_identity_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCloudFrontOriginAccessIdentityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCloudFrontOriginAccessIdentityRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateCloudFrontOriginAccessIdentityRequest {
+            cloud_front_origin_access_identity_config: self
+                .cloud_front_origin_access_identity_config
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "cloud_front_origin_access_identity_config",
+                ))?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateCloudFrontOriginAccessIdentityResult {
@@ -1301,10 +1389,10 @@ pub struct CreateCloudFrontOriginAccessIdentityResult {
     pub location: Option<String>,
 }
 
-struct CreateCloudFrontOriginAccessIdentityResultDeserializer;
+pub(crate) struct CreateCloudFrontOriginAccessIdentityResultDeserializer;
 impl CreateCloudFrontOriginAccessIdentityResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateCloudFrontOriginAccessIdentityResult, XmlParseError> {
@@ -1320,12 +1408,44 @@ impl CreateCloudFrontOriginAccessIdentityResultDeserializer {
     }
 }
 /// <p>The request to create a new distribution.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreateDistributionRequest {
     /// <p>The distribution's configuration information.</p>
     pub distribution_config: DistributionConfig,
 }
 
+impl CreateDistributionRequest {
+    /// Returns a builder for `CreateDistributionRequest`, checking its required members when built.
+    pub fn builder() -> CreateDistributionRequestBuilder {
+        CreateDistributionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDistributionRequest`](struct.CreateDistributionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDistributionRequestBuilder {
+    distribution_config: Option<DistributionConfig>,
+}
+
+impl CreateDistributionRequestBuilder {
+    /// Sets the [`distribution_config`](struct.CreateDistributionRequest.html#structfield.distribution_config) member.
+    pub fn distribution_config<T: Into<DistributionConfig>>(mut self, value: T) -> Self {
+        self.distribution_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDistributionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDistributionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDistributionRequest {
+            distribution_config: self
+                .distribution_config
+                .ok_or(::rusoto_core::BuildError::missing("distribution_config"))?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateDistributionResult {
@@ -1337,10 +1457,10 @@ pub struct CreateDistributionResult {
     pub location: Option<String>,
 }
 
-struct CreateDistributionResultDeserializer;
+pub(crate) struct CreateDistributionResultDeserializer;
 impl CreateDistributionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDistributionResult, XmlParseError> {
@@ -1354,12 +1474,47 @@ impl CreateDistributionResultDeserializer {
     }
 }
 /// <p>The request to create a new distribution with tags. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreateDistributionWithTagsRequest {
     /// <p>The distribution's configuration information. </p>
     pub distribution_config_with_tags: DistributionConfigWithTags,
 }
 
+impl CreateDistributionWithTagsRequest {
+    /// Returns a builder for `CreateDistributionWithTagsRequest`, checking its required members when built.
+    pub fn builder() -> CreateDistributionWithTagsRequestBuilder {
+        CreateDistributionWithTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDistributionWithTagsRequest`](struct.CreateDistributionWithTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDistributionWithTagsRequestBuilder {
+    distribution_config_with_tags: Option<DistributionConfigWithTags>,
+}
+
+impl CreateDistributionWithTagsRequestBuilder {
+    /// Sets the [`distribution_config_with_tags`](struct.CreateDistributionWithTagsRequest.html#structfield.distribution_config_with_tags) member.
+    pub fn distribution_config_with_tags<T: Into<DistributionConfigWithTags>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.distribution_config_with_tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDistributionWithTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDistributionWithTagsRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDistributionWithTagsRequest {
+            distribution_config_with_tags: self.distribution_config_with_tags.ok_or(
+                ::rusoto_core::BuildError::missing("distribution_config_with_tags"),
+            )?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateDistributionWithTagsResult {
@@ -1371,10 +1526,10 @@ pub struct CreateDistributionWithTagsResult {
     pub location: Option<String>,
 }
 
-struct CreateDistributionWithTagsResultDeserializer;
+pub(crate) struct CreateDistributionWithTagsResultDeserializer;
 impl CreateDistributionWithTagsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDistributionWithTagsResult, XmlParseError> {
@@ -1393,6 +1548,42 @@ pub struct CreateFieldLevelEncryptionConfigRequest {
     pub field_level_encryption_config: FieldLevelEncryptionConfig,
 }
 
+impl CreateFieldLevelEncryptionConfigRequest {
+    /// Returns a builder for `CreateFieldLevelEncryptionConfigRequest`, checking its required members when built.
+    pub fn builder() -> CreateFieldLevelEncryptionConfigRequestBuilder {
+        CreateFieldLevelEncryptionConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFieldLevelEncryptionConfigRequest`](struct.CreateFieldLevelEncryptionConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFieldLevelEncryptionConfigRequestBuilder {
+    field_level_encryption_config: Option<FieldLevelEncryptionConfig>,
+}
+
+impl CreateFieldLevelEncryptionConfigRequestBuilder {
+    /// Sets the [`field_level_encryption_config`](struct.CreateFieldLevelEncryptionConfigRequest.html#structfield.field_level_encryption_config) member.
+    pub fn field_level_encryption_config<T: Into<FieldLevelEncryptionConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.field_level_encryption_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFieldLevelEncryptionConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateFieldLevelEncryptionConfigRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateFieldLevelEncryptionConfigRequest {
+            field_level_encryption_config: self.field_level_encryption_config.ok_or(
+                ::rusoto_core::BuildError::missing("field_level_encryption_config"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateFieldLevelEncryptionConfigResult {
     /// <p>The current version of the field level encryption configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
@@ -1403,10 +1594,10 @@ pub struct CreateFieldLevelEncryptionConfigResult {
     pub location: Option<String>,
 }
 
-struct CreateFieldLevelEncryptionConfigResultDeserializer;
+pub(crate) struct CreateFieldLevelEncryptionConfigResultDeserializer;
 impl CreateFieldLevelEncryptionConfigResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateFieldLevelEncryptionConfigResult, XmlParseError> {
@@ -1425,6 +1616,44 @@ pub struct CreateFieldLevelEncryptionProfileRequest {
     pub field_level_encryption_profile_config: FieldLevelEncryptionProfileConfig,
 }
 
+impl CreateFieldLevelEncryptionProfileRequest {
+    /// Returns a builder for `CreateFieldLevelEncryptionProfileRequest`, checking its required members when built.
+    pub fn builder() -> CreateFieldLevelEncryptionProfileRequestBuilder {
+        CreateFieldLevelEncryptionProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFieldLevelEncryptionProfileRequest`](struct.CreateFieldLevelEncryptionProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFieldLevelEncryptionProfileRequestBuilder {
+    field_level_encryption_profile_config: Option<FieldLevelEncryptionProfileConfig>,
+}
+
+impl CreateFieldLevelEncryptionProfileRequestBuilder {
+    /// Sets the [`field_level_encryption_profile_config`](struct.CreateFieldLevelEncryptionProfileRequest.html#structfield.field_level_encryption_profile_config) member.
+    pub fn field_level_encryption_profile_config<T: Into<FieldLevelEncryptionProfileConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.field_level_encryption_profile_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFieldLevelEncryptionProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateFieldLevelEncryptionProfileRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateFieldLevelEncryptionProfileRequest {
+            field_level_encryption_profile_config: self
+                .field_level_encryption_profile_config
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "field_level_encryption_profile_config",
+                ))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateFieldLevelEncryptionProfileResult {
     /// <p>The current version of the field level encryption profile. For example: <code>E2QWRUHAPOMQZL</code>.</p>
@@ -1435,10 +1664,10 @@ pub struct CreateFieldLevelEncryptionProfileResult {
     pub location: Option<String>,
 }
 
-struct CreateFieldLevelEncryptionProfileResultDeserializer;
+pub(crate) struct CreateFieldLevelEncryptionProfileResultDeserializer;
 impl CreateFieldLevelEncryptionProfileResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateFieldLevelEncryptionProfileResult, XmlParseError> {
@@ -1462,6 +1691,48 @@ pub struct CreateInvalidationRequest {
     pub invalidation_batch: InvalidationBatch,
 }
 
+impl CreateInvalidationRequest {
+    /// Returns a builder for `CreateInvalidationRequest`, checking its required members when built.
+    pub fn builder() -> CreateInvalidationRequestBuilder {
+        CreateInvalidationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateInvalidationRequest`](struct.CreateInvalidationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateInvalidationRequestBuilder {
+    distribution_id: Option<String>,
+    invalidation_batch: Option<InvalidationBatch>,
+}
+
+impl CreateInvalidationRequestBuilder {
+    /// Sets the [`distribution_id`](struct.CreateInvalidationRequest.html#structfield.distribution_id) member.
+    pub fn distribution_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.distribution_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`invalidation_batch`](struct.CreateInvalidationRequest.html#structfield.invalidation_batch) member.
+    pub fn invalidation_batch<T: Into<InvalidationBatch>>(mut self, value: T) -> Self {
+        self.invalidation_batch = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateInvalidationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateInvalidationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateInvalidationRequest {
+            distribution_id: self
+                .distribution_id
+                .ok_or(::rusoto_core::BuildError::missing("distribution_id"))?,
+            invalidation_batch: self
+                .invalidation_batch
+                .ok_or(::rusoto_core::BuildError::missing("invalidation_batch"))?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateInvalidationResult {
@@ -1471,10 +1742,10 @@ pub struct CreateInvalidationResult {
     pub location: Option<String>,
 }
 
-struct CreateInvalidationResultDeserializer;
+pub(crate) struct CreateInvalidationResultDeserializer;
 impl CreateInvalidationResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateInvalidationResult, XmlParseError> {
@@ -1493,6 +1764,36 @@ pub struct CreatePublicKeyRequest {
     pub public_key_config: PublicKeyConfig,
 }
 
+impl CreatePublicKeyRequest {
+    /// Returns a builder for `CreatePublicKeyRequest`, checking its required members when built.
+    pub fn builder() -> CreatePublicKeyRequestBuilder {
+        CreatePublicKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePublicKeyRequest`](struct.CreatePublicKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePublicKeyRequestBuilder {
+    public_key_config: Option<PublicKeyConfig>,
+}
+
+impl CreatePublicKeyRequestBuilder {
+    /// Sets the [`public_key_config`](struct.CreatePublicKeyRequest.html#structfield.public_key_config) member.
+    pub fn public_key_config<T: Into<PublicKeyConfig>>(mut self, value: T) -> Self {
+        self.public_key_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePublicKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePublicKeyRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePublicKeyRequest {
+            public_key_config: self
+                .public_key_config
+                .ok_or(::rusoto_core::BuildError::missing("public_key_config"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreatePublicKeyResult {
     /// <p>The current version of the public key. For example: <code>E2QWRUHAPOMQZL</code>.</p>
@@ -1503,10 +1804,10 @@ pub struct CreatePublicKeyResult {
     pub public_key: Option<PublicKey>,
 }
 
-struct CreatePublicKeyResultDeserializer;
+pub(crate) struct CreatePublicKeyResultDeserializer;
 impl CreatePublicKeyResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreatePublicKeyResult, XmlParseError> {
@@ -1523,6 +1824,41 @@ pub struct CreateStreamingDistributionRequest {
     pub streaming_distribution_config: StreamingDistributionConfig,
 }
 
+impl CreateStreamingDistributionRequest {
+    /// Returns a builder for `CreateStreamingDistributionRequest`, checking its required members when built.
+    pub fn builder() -> CreateStreamingDistributionRequestBuilder {
+        CreateStreamingDistributionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStreamingDistributionRequest`](struct.CreateStreamingDistributionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateStreamingDistributionRequestBuilder {
+    streaming_distribution_config: Option<StreamingDistributionConfig>,
+}
+
+impl CreateStreamingDistributionRequestBuilder {
+    /// Sets the [`streaming_distribution_config`](struct.CreateStreamingDistributionRequest.html#structfield.streaming_distribution_config) member.
+    pub fn streaming_distribution_config<T: Into<StreamingDistributionConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.streaming_distribution_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStreamingDistributionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateStreamingDistributionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateStreamingDistributionRequest {
+            streaming_distribution_config: self.streaming_distribution_config.ok_or(
+                ::rusoto_core::BuildError::missing("streaming_distribution_config"),
+            )?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateStreamingDistributionResult {
@@ -1534,10 +1870,10 @@ pub struct CreateStreamingDistributionResult {
     pub streaming_distribution: Option<StreamingDistribution>,
 }
 
-struct CreateStreamingDistributionResultDeserializer;
+pub(crate) struct CreateStreamingDistributionResultDeserializer;
 impl CreateStreamingDistributionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateStreamingDistributionResult, XmlParseError> {
@@ -1557,6 +1893,44 @@ pub struct CreateStreamingDistributionWithTagsRequest {
     pub streaming_distribution_config_with_tags: StreamingDistributionConfigWithTags,
 }
 
+impl CreateStreamingDistributionWithTagsRequest {
+    /// Returns a builder for `CreateStreamingDistributionWithTagsRequest`, checking its required members when built.
+    pub fn builder() -> CreateStreamingDistributionWithTagsRequestBuilder {
+        CreateStreamingDistributionWithTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStreamingDistributionWithTagsRequest`](struct.CreateStreamingDistributionWithTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateStreamingDistributionWithTagsRequestBuilder {
+    streaming_distribution_config_with_tags: Option<StreamingDistributionConfigWithTags>,
+}
+
+impl CreateStreamingDistributionWithTagsRequestBuilder {
+    /// Sets the [`streaming_distribution_config_with_tags`](struct.CreateStreamingDistributionWithTagsRequest.html#structfield.streaming_distribution_config_with_tags) member.
+    pub fn streaming_distribution_config_with_tags<T: Into<StreamingDistributionConfigWithTags>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.streaming_distribution_config_with_tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStreamingDistributionWithTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateStreamingDistributionWithTagsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateStreamingDistributionWithTagsRequest {
+            streaming_distribution_config_with_tags: self
+                .streaming_distribution_config_with_tags
+                .ok_or(::rusoto_core::BuildError::missing(
+                "streaming_distribution_config_with_tags",
+            ))?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateStreamingDistributionWithTagsResult {
@@ -1568,10 +1942,10 @@ pub struct CreateStreamingDistributionWithTagsResult {
     pub streaming_distribution: Option<StreamingDistribution>,
 }
 
-struct CreateStreamingDistributionWithTagsResultDeserializer;
+pub(crate) struct CreateStreamingDistributionWithTagsResultDeserializer;
 impl CreateStreamingDistributionWithTagsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateStreamingDistributionWithTagsResult, XmlParseError> {
@@ -1597,10 +1971,10 @@ pub struct CustomErrorResponse {
     pub response_page_path: Option<String>,
 }
 
-struct CustomErrorResponseDeserializer;
+pub(crate) struct CustomErrorResponseDeserializer;
 impl CustomErrorResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CustomErrorResponse, XmlParseError> {
@@ -1674,10 +2048,10 @@ impl CustomErrorResponseSerializer {
     }
 }
 
-struct CustomErrorResponseListDeserializer;
+pub(crate) struct CustomErrorResponseListDeserializer;
 impl CustomErrorResponseListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CustomErrorResponse>, XmlParseError> {
@@ -1724,10 +2098,10 @@ pub struct CustomErrorResponses {
     pub quantity: i64,
 }
 
-struct CustomErrorResponsesDeserializer;
+pub(crate) struct CustomErrorResponsesDeserializer;
 impl CustomErrorResponsesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CustomErrorResponses, XmlParseError> {
@@ -1782,10 +2156,10 @@ pub struct CustomHeaders {
     pub quantity: i64,
 }
 
-struct CustomHeadersDeserializer;
+pub(crate) struct CustomHeadersDeserializer;
 impl CustomHeadersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CustomHeaders, XmlParseError> {
@@ -1832,7 +2206,7 @@ impl CustomHeadersSerializer {
 }
 
 /// <p>A customer origin or an Amazon S3 bucket configured as a website endpoint.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CustomOriginConfig {
     /// <p>The HTTP port the custom origin listens on.</p>
     pub http_port: i64,
@@ -1841,56 +2215,69 @@ pub struct CustomOriginConfig {
     /// <p>You can create a custom keep-alive timeout. All timeout units are in seconds. The default keep-alive timeout is 5 seconds, but you can configure custom timeout lengths using the CloudFront API. The minimum timeout length is 1 second; the maximum is 60 seconds.</p> <p>If you need to increase the maximum time limit, contact the <a href="https://console.aws.amazon.com/support/home#/">AWS Support Center</a>.</p>
     pub origin_keepalive_timeout: Option<i64>,
     /// <p>The origin protocol policy to apply to your origin.</p>
-    pub origin_protocol_policy: String,
+    pub origin_protocol_policy: OriginProtocolPolicy,
     /// <p>You can create a custom origin read timeout. All timeout units are in seconds. The default origin read timeout is 30 seconds, but you can configure custom timeout lengths using the CloudFront API. The minimum timeout length is 4 seconds; the maximum is 60 seconds.</p> <p>If you need to increase the maximum time limit, contact the <a href="https://console.aws.amazon.com/support/home#/">AWS Support Center</a>.</p>
     pub origin_read_timeout: Option<i64>,
     /// <p>The SSL/TLS protocols that you want CloudFront to use when communicating with your origin over HTTPS.</p>
     pub origin_ssl_protocols: Option<OriginSslProtocols>,
 }
 
-struct CustomOriginConfigDeserializer;
+pub(crate) struct CustomOriginConfigDeserializer;
 impl CustomOriginConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CustomOriginConfig, XmlParseError> {
-        deserialize_elements::<_, CustomOriginConfig, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "HTTPPort" => {
-                    obj.http_port = IntegerDeserializer::deserialize("HTTPPort", stack)?;
-                }
-                "HTTPSPort" => {
-                    obj.https_port = IntegerDeserializer::deserialize("HTTPSPort", stack)?;
-                }
-                "OriginKeepaliveTimeout" => {
-                    obj.origin_keepalive_timeout = Some(IntegerDeserializer::deserialize(
-                        "OriginKeepaliveTimeout",
-                        stack,
-                    )?);
-                }
-                "OriginProtocolPolicy" => {
-                    obj.origin_protocol_policy = OriginProtocolPolicyDeserializer::deserialize(
-                        "OriginProtocolPolicy",
-                        stack,
-                    )?;
-                }
-                "OriginReadTimeout" => {
-                    obj.origin_read_timeout = Some(IntegerDeserializer::deserialize(
-                        "OriginReadTimeout",
-                        stack,
-                    )?);
-                }
-                "OriginSslProtocols" => {
-                    obj.origin_ssl_protocols = Some(OriginSslProtocolsDeserializer::deserialize(
-                        "OriginSslProtocols",
-                        stack,
-                    )?);
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            CustomOriginConfig {
+                http_port: Default::default(),
+                https_port: Default::default(),
+                origin_keepalive_timeout: Default::default(),
+                origin_protocol_policy: OriginProtocolPolicy::Unknown(String::new()),
+                origin_read_timeout: Default::default(),
+                origin_ssl_protocols: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "HTTPPort" => {
+                        obj.http_port = IntegerDeserializer::deserialize("HTTPPort", stack)?;
+                    }
+                    "HTTPSPort" => {
+                        obj.https_port = IntegerDeserializer::deserialize("HTTPSPort", stack)?;
+                    }
+                    "OriginKeepaliveTimeout" => {
+                        obj.origin_keepalive_timeout = Some(IntegerDeserializer::deserialize(
+                            "OriginKeepaliveTimeout",
+                            stack,
+                        )?);
+                    }
+                    "OriginProtocolPolicy" => {
+                        obj.origin_protocol_policy = OriginProtocolPolicyDeserializer::deserialize(
+                            "OriginProtocolPolicy",
+                            stack,
+                        )?;
+                    }
+                    "OriginReadTimeout" => {
+                        obj.origin_read_timeout = Some(IntegerDeserializer::deserialize(
+                            "OriginReadTimeout",
+                            stack,
+                        )?);
+                    }
+                    "OriginSslProtocols" => {
+                        obj.origin_ssl_protocols =
+                            Some(OriginSslProtocolsDeserializer::deserialize(
+                                "OriginSslProtocols",
+                                stack,
+                            )?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -1950,7 +2337,7 @@ impl CustomOriginConfigSerializer {
 }
 
 /// <p>A complex type that describes the default cache behavior if you don't specify a <code>CacheBehavior</code> element or if files don't match any of the values of <code>PathPattern</code> in <code>CacheBehavior</code> elements. You must create exactly one default cache behavior.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DefaultCacheBehavior {
     pub allowed_methods: Option<AllowedMethods>,
     /// <p>Whether you want CloudFront to automatically compress certain files for this cache behavior. If so, specify <code>true</code>; if not, specify <code>false</code>. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/ServingCompressedFiles.html">Serving Compressed Files</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
@@ -1973,75 +2360,101 @@ pub struct DefaultCacheBehavior {
     /// <p>A complex type that specifies the AWS accounts, if any, that you want to allow to create signed URLs for private content.</p> <p>If you want to require signed URLs in requests for objects in the target origin that match the <code>PathPattern</code> for this cache behavior, specify <code>true</code> for <code>Enabled</code>, and specify the applicable values for <code>Quantity</code> and <code>Items</code>. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon Amazon CloudFront Developer Guide</i>.</p> <p>If you don't want to require signed URLs in requests for objects that match <code>PathPattern</code>, specify <code>false</code> for <code>Enabled</code> and <code>0</code> for <code>Quantity</code>. Omit <code>Items</code>.</p> <p>To add, change, or remove one or more trusted signers, change <code>Enabled</code> to <code>true</code> (if it's currently <code>false</code>), change <code>Quantity</code> as applicable, and specify all of the trusted signers that you want to include in the updated distribution.</p>
     pub trusted_signers: TrustedSigners,
     /// <p><p>The protocol that viewers can use to access the files in the origin specified by <code>TargetOriginId</code> when a request matches the path pattern in <code>PathPattern</code>. You can specify the following options:</p> <ul> <li> <p> <code>allow-all</code>: Viewers can use HTTP or HTTPS.</p> </li> <li> <p> <code>redirect-to-https</code>: If a viewer submits an HTTP request, CloudFront returns an HTTP status code of 301 (Moved Permanently) to the viewer along with the HTTPS URL. The viewer then resubmits the request using the new URL.</p> </li> <li> <p> <code>https-only</code>: If a viewer sends an HTTP request, CloudFront returns an HTTP status code of 403 (Forbidden).</p> </li> </ul> <p>For more information about requiring the HTTPS protocol, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/SecureConnections.html">Using an HTTPS Connection to Access Your Objects</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <note> <p>The only way to guarantee that viewers retrieve an object that was fetched from the origin using HTTPS is never to use any other protocol to fetch the object. If you have recently changed from HTTP to HTTPS, we recommend that you clear your objects&#39; cache because cached objects are protocol agnostic. That means that an edge location will return an object from the cache regardless of whether the current request protocol matches the protocol used previously. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/Expiration.html">Specifying How Long Objects and Errors Stay in a CloudFront Edge Cache (Expiration)</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </note></p>
-    pub viewer_protocol_policy: String,
+    pub viewer_protocol_policy: ViewerProtocolPolicy,
 }
 
-struct DefaultCacheBehaviorDeserializer;
+pub(crate) struct DefaultCacheBehaviorDeserializer;
 impl DefaultCacheBehaviorDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DefaultCacheBehavior, XmlParseError> {
-        deserialize_elements::<_, DefaultCacheBehavior, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "AllowedMethods" => {
-                    obj.allowed_methods = Some(AllowedMethodsDeserializer::deserialize(
-                        "AllowedMethods",
-                        stack,
-                    )?);
-                }
-                "Compress" => {
-                    obj.compress = Some(BooleanDeserializer::deserialize("Compress", stack)?);
-                }
-                "DefaultTTL" => {
-                    obj.default_ttl = Some(LongDeserializer::deserialize("DefaultTTL", stack)?);
-                }
-                "FieldLevelEncryptionId" => {
-                    obj.field_level_encryption_id = Some(StringDeserializer::deserialize(
-                        "FieldLevelEncryptionId",
-                        stack,
-                    )?);
-                }
-                "ForwardedValues" => {
-                    obj.forwarded_values =
-                        ForwardedValuesDeserializer::deserialize("ForwardedValues", stack)?;
-                }
-                "LambdaFunctionAssociations" => {
-                    obj.lambda_function_associations =
-                        Some(LambdaFunctionAssociationsDeserializer::deserialize(
-                            "LambdaFunctionAssociations",
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            DefaultCacheBehavior {
+                allowed_methods: Default::default(),
+                compress: Default::default(),
+                default_ttl: Default::default(),
+                field_level_encryption_id: Default::default(),
+                forwarded_values: ForwardedValues {
+                    cookies: CookiePreference {
+                        forward: ItemSelection::Unknown(String::new()),
+                        whitelisted_names: Default::default(),
+                    },
+                    headers: Default::default(),
+                    query_string: Default::default(),
+                    query_string_cache_keys: Default::default(),
+                },
+                lambda_function_associations: Default::default(),
+                max_ttl: Default::default(),
+                min_ttl: Default::default(),
+                smooth_streaming: Default::default(),
+                target_origin_id: Default::default(),
+                trusted_signers: Default::default(),
+                viewer_protocol_policy: ViewerProtocolPolicy::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "AllowedMethods" => {
+                        obj.allowed_methods = Some(AllowedMethodsDeserializer::deserialize(
+                            "AllowedMethods",
                             stack,
                         )?);
+                    }
+                    "Compress" => {
+                        obj.compress = Some(BooleanDeserializer::deserialize("Compress", stack)?);
+                    }
+                    "DefaultTTL" => {
+                        obj.default_ttl = Some(LongDeserializer::deserialize("DefaultTTL", stack)?);
+                    }
+                    "FieldLevelEncryptionId" => {
+                        obj.field_level_encryption_id = Some(StringDeserializer::deserialize(
+                            "FieldLevelEncryptionId",
+                            stack,
+                        )?);
+                    }
+                    "ForwardedValues" => {
+                        obj.forwarded_values =
+                            ForwardedValuesDeserializer::deserialize("ForwardedValues", stack)?;
+                    }
+                    "LambdaFunctionAssociations" => {
+                        obj.lambda_function_associations =
+                            Some(LambdaFunctionAssociationsDeserializer::deserialize(
+                                "LambdaFunctionAssociations",
+                                stack,
+                            )?);
+                    }
+                    "MaxTTL" => {
+                        obj.max_ttl = Some(LongDeserializer::deserialize("MaxTTL", stack)?);
+                    }
+                    "MinTTL" => {
+                        obj.min_ttl = LongDeserializer::deserialize("MinTTL", stack)?;
+                    }
+                    "SmoothStreaming" => {
+                        obj.smooth_streaming =
+                            Some(BooleanDeserializer::deserialize("SmoothStreaming", stack)?);
+                    }
+                    "TargetOriginId" => {
+                        obj.target_origin_id =
+                            StringDeserializer::deserialize("TargetOriginId", stack)?;
+                    }
+                    "TrustedSigners" => {
+                        obj.trusted_signers =
+                            TrustedSignersDeserializer::deserialize("TrustedSigners", stack)?;
+                    }
+                    "ViewerProtocolPolicy" => {
+                        obj.viewer_protocol_policy = ViewerProtocolPolicyDeserializer::deserialize(
+                            "ViewerProtocolPolicy",
+                            stack,
+                        )?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                "MaxTTL" => {
-                    obj.max_ttl = Some(LongDeserializer::deserialize("MaxTTL", stack)?);
-                }
-                "MinTTL" => {
-                    obj.min_ttl = LongDeserializer::deserialize("MinTTL", stack)?;
-                }
-                "SmoothStreaming" => {
-                    obj.smooth_streaming =
-                        Some(BooleanDeserializer::deserialize("SmoothStreaming", stack)?);
-                }
-                "TargetOriginId" => {
-                    obj.target_origin_id =
-                        StringDeserializer::deserialize("TargetOriginId", stack)?;
-                }
-                "TrustedSigners" => {
-                    obj.trusted_signers =
-                        TrustedSignersDeserializer::deserialize("TrustedSigners", stack)?;
-                }
-                "ViewerProtocolPolicy" => {
-                    obj.viewer_protocol_policy = ViewerProtocolPolicyDeserializer::deserialize(
-                        "ViewerProtocolPolicy",
-                        stack,
-                    )?;
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -2146,6 +2559,45 @@ pub struct DeleteCloudFrontOriginAccessIdentityRequest {
     pub if_match: Option<String>,
 }
 
+impl DeleteCloudFrontOriginAccessIdentityRequest {
+    /// Returns a builder for `DeleteCloudFrontOriginAccessIdentityRequest`, checking its required members when built.
+    pub fn builder() -> DeleteCloudFrontOriginAccessIdentityRequestBuilder {
+        DeleteCloudFrontOriginAccessIdentityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCloudFrontOriginAccessIdentityRequest`](struct.DeleteCloudFrontOriginAccessIdentityRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteCloudFrontOriginAccessIdentityRequestBuilder {
+    id: Option<String>,
+    if_match: Option<String>,
+}
+
+impl DeleteCloudFrontOriginAccessIdentityRequestBuilder {
+    /// Sets the [`id`](struct.DeleteCloudFrontOriginAccessIdentityRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`if_match`](struct.DeleteCloudFrontOriginAccessIdentityRequest.html#structfield.if_match) member.
+    pub fn if_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_match = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCloudFrontOriginAccessIdentityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCloudFrontOriginAccessIdentityRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteCloudFrontOriginAccessIdentityRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            if_match: self.if_match,
+        })
+    }
+}
+
 /// <p>This action deletes a web distribution. To delete a web distribution using the CloudFront API, perform the following steps.</p> <p> <b>To delete a web distribution using the CloudFront API:</b> </p> <ol> <li> <p>Disable the web distribution </p> </li> <li> <p>Submit a <code>GET Distribution Config</code> request to get the current configuration and the <code>Etag</code> header for the distribution.</p> </li> <li> <p>Update the XML document that was returned in the response to your <code>GET Distribution Config</code> request to change the value of <code>Enabled</code> to <code>false</code>.</p> </li> <li> <p>Submit a <code>PUT Distribution Config</code> request to update the configuration for your distribution. In the request body, include the XML document that you updated in Step 3. Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Distribution Config</code> request in Step 2.</p> </li> <li> <p>Review the response to the <code>PUT Distribution Config</code> request to confirm that the distribution was successfully disabled.</p> </li> <li> <p>Submit a <code>GET Distribution</code> request to confirm that your changes have propagated. When propagation is complete, the value of <code>Status</code> is <code>Deployed</code>.</p> </li> <li> <p>Submit a <code>DELETE Distribution</code> request. Set the value of the HTTP <code>If-Match</code> header to the value of the <code>ETag</code> header that CloudFront returned when you submitted the <code>GET Distribution Config</code> request in Step 6.</p> </li> <li> <p>Review the response to your <code>DELETE Distribution</code> request to confirm that the distribution was successfully deleted.</p> </li> </ol> <p>For information about deleting a distribution using the CloudFront console, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/HowToDeleteDistribution.html">Deleting a Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteDistributionRequest {
@@ -2155,6 +2607,44 @@ pub struct DeleteDistributionRequest {
     pub if_match: Option<String>,
 }
 
+impl DeleteDistributionRequest {
+    /// Returns a builder for `DeleteDistributionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDistributionRequestBuilder {
+        DeleteDistributionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDistributionRequest`](struct.DeleteDistributionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDistributionRequestBuilder {
+    id: Option<String>,
+    if_match: Option<String>,
+}
+
+impl DeleteDistributionRequestBuilder {
+    /// Sets the [`id`](struct.DeleteDistributionRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`if_match`](struct.DeleteDistributionRequest.html#structfield.if_match) member.
+    pub fn if_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_match = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDistributionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDistributionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDistributionRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            if_match: self.if_match,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteFieldLevelEncryptionConfigRequest {
     /// <p>The ID of the configuration you want to delete from CloudFront.</p>
@@ -2163,6 +2653,45 @@ pub struct DeleteFieldLevelEncryptionConfigRequest {
     pub if_match: Option<String>,
 }
 
+impl DeleteFieldLevelEncryptionConfigRequest {
+    /// Returns a builder for `DeleteFieldLevelEncryptionConfigRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFieldLevelEncryptionConfigRequestBuilder {
+        DeleteFieldLevelEncryptionConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFieldLevelEncryptionConfigRequest`](struct.DeleteFieldLevelEncryptionConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFieldLevelEncryptionConfigRequestBuilder {
+    id: Option<String>,
+    if_match: Option<String>,
+}
+
+impl DeleteFieldLevelEncryptionConfigRequestBuilder {
+    /// Sets the [`id`](struct.DeleteFieldLevelEncryptionConfigRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`if_match`](struct.DeleteFieldLevelEncryptionConfigRequest.html#structfield.if_match) member.
+    pub fn if_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_match = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFieldLevelEncryptionConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteFieldLevelEncryptionConfigRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteFieldLevelEncryptionConfigRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            if_match: self.if_match,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteFieldLevelEncryptionProfileRequest {
     /// <p>Request the ID of the profile you want to delete from CloudFront.</p>
@@ -2171,6 +2700,45 @@ pub struct DeleteFieldLevelEncryptionProfileRequest {
     pub if_match: Option<String>,
 }
 
+impl DeleteFieldLevelEncryptionProfileRequest {
+    /// Returns a builder for `DeleteFieldLevelEncryptionProfileRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFieldLevelEncryptionProfileRequestBuilder {
+        DeleteFieldLevelEncryptionProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFieldLevelEncryptionProfileRequest`](struct.DeleteFieldLevelEncryptionProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFieldLevelEncryptionProfileRequestBuilder {
+    id: Option<String>,
+    if_match: Option<String>,
+}
+
+impl DeleteFieldLevelEncryptionProfileRequestBuilder {
+    /// Sets the [`id`](struct.DeleteFieldLevelEncryptionProfileRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`if_match`](struct.DeleteFieldLevelEncryptionProfileRequest.html#structfield.if_match) member.
+    pub fn if_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_match = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFieldLevelEncryptionProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteFieldLevelEncryptionProfileRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteFieldLevelEncryptionProfileRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            if_match: self.if_match,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeletePublicKeyRequest {
     /// <p>The ID of the public key you want to remove from CloudFront.</p>
@@ -2179,6 +2747,42 @@ pub struct DeletePublicKeyRequest {
     pub if_match: Option<String>,
 }
 
+impl DeletePublicKeyRequest {
+    /// Returns a builder for `DeletePublicKeyRequest`, checking its required members when built.
+    pub fn builder() -> DeletePublicKeyRequestBuilder {
+        DeletePublicKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePublicKeyRequest`](struct.DeletePublicKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePublicKeyRequestBuilder {
+    id: Option<String>,
+    if_match: Option<String>,
+}
+
+impl DeletePublicKeyRequestBuilder {
+    /// Sets the [`id`](struct.DeletePublicKeyRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`if_match`](struct.DeletePublicKeyRequest.html#structfield.if_match) member.
+    pub fn if_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_match = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePublicKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePublicKeyRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePublicKeyRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            if_match: self.if_match,
+        })
+    }
+}
+
 /// <p>The request to delete a streaming distribution.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteStreamingDistributionRequest {
@@ -2188,8 +2792,46 @@ pub struct DeleteStreamingDistributionRequest {
     pub if_match: Option<String>,
 }
 
+impl DeleteStreamingDistributionRequest {
+    /// Returns a builder for `DeleteStreamingDistributionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteStreamingDistributionRequestBuilder {
+        DeleteStreamingDistributionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStreamingDistributionRequest`](struct.DeleteStreamingDistributionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteStreamingDistributionRequestBuilder {
+    id: Option<String>,
+    if_match: Option<String>,
+}
+
+impl DeleteStreamingDistributionRequestBuilder {
+    /// Sets the [`id`](struct.DeleteStreamingDistributionRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`if_match`](struct.DeleteStreamingDistributionRequest.html#structfield.if_match) member.
+    pub fn if_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_match = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStreamingDistributionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteStreamingDistributionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteStreamingDistributionRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            if_match: self.if_match,
+        })
+    }
+}
+
 /// <p>The distribution's information.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Distribution {
     /// <p>The ARN (Amazon Resource Name) for the distribution. For example: <code>arn:aws:cloudfront::123456789012:distribution/EDFDVBD632BHDS5</code>, where <code>123456789012</code> is your AWS account ID.</p>
     pub arn: String,
@@ -2204,58 +2846,115 @@ pub struct Distribution {
     /// <p>The number of invalidation batches currently in progress. </p>
     pub in_progress_invalidation_batches: i64,
     /// <p>The date and time the distribution was last modified. </p>
-    pub last_modified_time: String,
+    pub last_modified_time: IsoTimestamp,
     /// <p>This response element indicates the current status of the distribution. When the status is <code>Deployed</code>, the distribution's information is fully propagated to all CloudFront edge locations. </p>
     pub status: String,
 }
 
-struct DistributionDeserializer;
+pub(crate) struct DistributionDeserializer;
 impl DistributionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Distribution, XmlParseError> {
-        deserialize_elements::<_, Distribution, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "ARN" => {
-                    obj.arn = StringDeserializer::deserialize("ARN", stack)?;
-                }
-                "ActiveTrustedSigners" => {
-                    obj.active_trusted_signers = ActiveTrustedSignersDeserializer::deserialize(
-                        "ActiveTrustedSigners",
-                        stack,
-                    )?;
-                }
-                "DistributionConfig" => {
-                    obj.distribution_config =
-                        DistributionConfigDeserializer::deserialize("DistributionConfig", stack)?;
-                }
-                "DomainName" => {
-                    obj.domain_name = StringDeserializer::deserialize("DomainName", stack)?;
-                }
-                "Id" => {
-                    obj.id = StringDeserializer::deserialize("Id", stack)?;
-                }
-                "InProgressInvalidationBatches" => {
-                    obj.in_progress_invalidation_batches =
-                        IntegerDeserializer::deserialize("InProgressInvalidationBatches", stack)?;
-                }
-                "LastModifiedTime" => {
-                    obj.last_modified_time =
-                        TimestampDeserializer::deserialize("LastModifiedTime", stack)?;
-                }
-                "Status" => {
-                    obj.status = StringDeserializer::deserialize("Status", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            Distribution {
+                arn: Default::default(),
+                active_trusted_signers: Default::default(),
+                distribution_config: DistributionConfig {
+                    aliases: Default::default(),
+                    cache_behaviors: Default::default(),
+                    caller_reference: Default::default(),
+                    comment: Default::default(),
+                    custom_error_responses: Default::default(),
+                    default_cache_behavior: DefaultCacheBehavior {
+                        allowed_methods: Default::default(),
+                        compress: Default::default(),
+                        default_ttl: Default::default(),
+                        field_level_encryption_id: Default::default(),
+                        forwarded_values: ForwardedValues {
+                            cookies: CookiePreference {
+                                forward: ItemSelection::Unknown(String::new()),
+                                whitelisted_names: Default::default(),
+                            },
+                            headers: Default::default(),
+                            query_string: Default::default(),
+                            query_string_cache_keys: Default::default(),
+                        },
+                        lambda_function_associations: Default::default(),
+                        max_ttl: Default::default(),
+                        min_ttl: Default::default(),
+                        smooth_streaming: Default::default(),
+                        target_origin_id: Default::default(),
+                        trusted_signers: Default::default(),
+                        viewer_protocol_policy: ViewerProtocolPolicy::Unknown(String::new()),
+                    },
+                    default_root_object: Default::default(),
+                    enabled: Default::default(),
+                    http_version: Default::default(),
+                    is_ipv6_enabled: Default::default(),
+                    logging: Default::default(),
+                    origin_groups: Default::default(),
+                    origins: Default::default(),
+                    price_class: Default::default(),
+                    restrictions: Default::default(),
+                    viewer_certificate: Default::default(),
+                    web_acl_id: Default::default(),
+                },
+                domain_name: Default::default(),
+                id: Default::default(),
+                in_progress_invalidation_batches: Default::default(),
+                last_modified_time: Default::default(),
+                status: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "ARN" => {
+                        obj.arn = StringDeserializer::deserialize("ARN", stack)?;
+                    }
+                    "ActiveTrustedSigners" => {
+                        obj.active_trusted_signers = ActiveTrustedSignersDeserializer::deserialize(
+                            "ActiveTrustedSigners",
+                            stack,
+                        )?;
+                    }
+                    "DistributionConfig" => {
+                        obj.distribution_config = DistributionConfigDeserializer::deserialize(
+                            "DistributionConfig",
+                            stack,
+                        )?;
+                    }
+                    "DomainName" => {
+                        obj.domain_name = StringDeserializer::deserialize("DomainName", stack)?;
+                    }
+                    "Id" => {
+                        obj.id = StringDeserializer::deserialize("Id", stack)?;
+                    }
+                    "InProgressInvalidationBatches" => {
+                        obj.in_progress_invalidation_batches = IntegerDeserializer::deserialize(
+                            "InProgressInvalidationBatches",
+                            stack,
+                        )?;
+                    }
+                    "LastModifiedTime" => {
+                        obj.last_modified_time =
+                            TimestampDeserializer::deserialize("LastModifiedTime", stack)?;
+                    }
+                    "Status" => {
+                        obj.status = StringDeserializer::deserialize("Status", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 /// <p>A distribution configuration.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DistributionConfig {
     /// <p>A complex type that contains information about CNAMEs (alternate domain names), if any, for this distribution.</p>
     pub aliases: Option<Aliases>,
@@ -2274,7 +2973,7 @@ pub struct DistributionConfig {
     /// <p>From this field, you can enable or disable the selected distribution.</p>
     pub enabled: bool,
     /// <p>(Optional) Specify the maximum HTTP version that you want viewers to use to communicate with CloudFront. The default value for new web distributions is http2. Viewers that don't support HTTP/2 automatically use an earlier HTTP version.</p> <p>For viewers and CloudFront to use HTTP/2, viewers must support TLS 1.2 or later, and must support Server Name Identification (SNI).</p> <p>In general, configuring CloudFront to communicate with viewers using HTTP/2 reduces latency. You can improve performance by optimizing for HTTP/2. For more information, do an Internet search for "http/2 optimization." </p>
-    pub http_version: Option<String>,
+    pub http_version: Option<HttpVersion>,
     /// <p>If you want CloudFront to respond to IPv6 DNS requests with an IPv6 address for your distribution, specify <code>true</code>. If you specify <code>false</code>, CloudFront responds to IPv6 DNS requests with the DNS response code <code>NOERROR</code> and with no IP addresses. This allows viewers to submit a second request, for an IPv4 address for your distribution. </p> <p>In general, you should enable IPv6 if you have users on IPv6 networks who want to access your content. However, if you're using signed URLs or signed cookies to restrict access to your content, and if you're using a custom policy that includes the <code>IpAddress</code> parameter to restrict the IP addresses that can access your content, don't enable IPv6. If you want to restrict access to some content by IP address and not restrict access to other content (or restrict access but not by IP address), you can create two distributions. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/private-content-creating-signed-url-custom-policy.html">Creating a Signed URL Using a Custom Policy</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <p>If you're using an Amazon Route 53 alias resource record set to route traffic to your CloudFront distribution, you need to create a second alias resource record set when both of the following are true:</p> <ul> <li> <p>You enable IPv6 for the distribution</p> </li> <li> <p>You're using alternate domain names in the URLs for your objects</p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/Route53/latest/DeveloperGuide/routing-to-cloudfront-distribution.html">Routing Traffic to an Amazon CloudFront Web Distribution by Using Your Domain Name</a> in the <i>Amazon Route 53 Developer Guide</i>.</p> <p>If you created a CNAME resource record set, either with Amazon Route 53 or with another DNS service, you don't need to make any changes. A CNAME record will route traffic to your distribution regardless of the IP address format of the viewer request.</p>
     pub is_ipv6_enabled: Option<bool>,
     /// <p>A complex type that controls whether access logs are written for the distribution.</p> <p>For more information about logging, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/AccessLogs.html">Access Logs</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
@@ -2284,7 +2983,7 @@ pub struct DistributionConfig {
     /// <p>A complex type that contains information about origins for this distribution. </p>
     pub origins: Origins,
     /// <p>The price class that corresponds with the maximum price that you want to pay for CloudFront service. If you specify <code>PriceClass_All</code>, CloudFront responds to requests for your objects from all CloudFront edge locations.</p> <p>If you specify a price class other than <code>PriceClass_All</code>, CloudFront serves your objects from the CloudFront edge location that has the lowest latency among the edge locations in your price class. Viewers who are in or near regions that are excluded from your specified price class may encounter slower performance.</p> <p>For more information about price classes, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PriceClass.html">Choosing the Price Class for a CloudFront Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>. For information about CloudFront pricing, including how price classes (such as Price Class 100) map to CloudFront regions, see <a href="https://aws.amazon.com/cloudfront/pricing/">Amazon CloudFront Pricing</a>. For price class information, scroll down to see the table at the bottom of the page.</p>
-    pub price_class: Option<String>,
+    pub price_class: Option<PriceClass>,
     /// <p><p/></p>
     pub restrictions: Option<Restrictions>,
     /// <p><p/></p>
@@ -2293,94 +2992,139 @@ pub struct DistributionConfig {
     pub web_acl_id: Option<String>,
 }
 
-struct DistributionConfigDeserializer;
+pub(crate) struct DistributionConfigDeserializer;
 impl DistributionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DistributionConfig, XmlParseError> {
-        deserialize_elements::<_, DistributionConfig, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Aliases" => {
-                    obj.aliases = Some(AliasesDeserializer::deserialize("Aliases", stack)?);
-                }
-                "CacheBehaviors" => {
-                    obj.cache_behaviors = Some(CacheBehaviorsDeserializer::deserialize(
-                        "CacheBehaviors",
-                        stack,
-                    )?);
-                }
-                "CallerReference" => {
-                    obj.caller_reference =
-                        StringDeserializer::deserialize("CallerReference", stack)?;
-                }
-                "Comment" => {
-                    obj.comment = StringDeserializer::deserialize("Comment", stack)?;
-                }
-                "CustomErrorResponses" => {
-                    obj.custom_error_responses =
-                        Some(CustomErrorResponsesDeserializer::deserialize(
-                            "CustomErrorResponses",
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            DistributionConfig {
+                aliases: Default::default(),
+                cache_behaviors: Default::default(),
+                caller_reference: Default::default(),
+                comment: Default::default(),
+                custom_error_responses: Default::default(),
+                default_cache_behavior: DefaultCacheBehavior {
+                    allowed_methods: Default::default(),
+                    compress: Default::default(),
+                    default_ttl: Default::default(),
+                    field_level_encryption_id: Default::default(),
+                    forwarded_values: ForwardedValues {
+                        cookies: CookiePreference {
+                            forward: ItemSelection::Unknown(String::new()),
+                            whitelisted_names: Default::default(),
+                        },
+                        headers: Default::default(),
+                        query_string: Default::default(),
+                        query_string_cache_keys: Default::default(),
+                    },
+                    lambda_function_associations: Default::default(),
+                    max_ttl: Default::default(),
+                    min_ttl: Default::default(),
+                    smooth_streaming: Default::default(),
+                    target_origin_id: Default::default(),
+                    trusted_signers: Default::default(),
+                    viewer_protocol_policy: ViewerProtocolPolicy::Unknown(String::new()),
+                },
+                default_root_object: Default::default(),
+                enabled: Default::default(),
+                http_version: Default::default(),
+                is_ipv6_enabled: Default::default(),
+                logging: Default::default(),
+                origin_groups: Default::default(),
+                origins: Default::default(),
+                price_class: Default::default(),
+                restrictions: Default::default(),
+                viewer_certificate: Default::default(),
+                web_acl_id: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Aliases" => {
+                        obj.aliases = Some(AliasesDeserializer::deserialize("Aliases", stack)?);
+                    }
+                    "CacheBehaviors" => {
+                        obj.cache_behaviors = Some(CacheBehaviorsDeserializer::deserialize(
+                            "CacheBehaviors",
                             stack,
                         )?);
+                    }
+                    "CallerReference" => {
+                        obj.caller_reference =
+                            StringDeserializer::deserialize("CallerReference", stack)?;
+                    }
+                    "Comment" => {
+                        obj.comment = StringDeserializer::deserialize("Comment", stack)?;
+                    }
+                    "CustomErrorResponses" => {
+                        obj.custom_error_responses =
+                            Some(CustomErrorResponsesDeserializer::deserialize(
+                                "CustomErrorResponses",
+                                stack,
+                            )?);
+                    }
+                    "DefaultCacheBehavior" => {
+                        obj.default_cache_behavior = DefaultCacheBehaviorDeserializer::deserialize(
+                            "DefaultCacheBehavior",
+                            stack,
+                        )?;
+                    }
+                    "DefaultRootObject" => {
+                        obj.default_root_object =
+                            Some(StringDeserializer::deserialize("DefaultRootObject", stack)?);
+                    }
+                    "Enabled" => {
+                        obj.enabled = BooleanDeserializer::deserialize("Enabled", stack)?;
+                    }
+                    "HttpVersion" => {
+                        obj.http_version =
+                            Some(HttpVersionDeserializer::deserialize("HttpVersion", stack)?);
+                    }
+                    "IsIPV6Enabled" => {
+                        obj.is_ipv6_enabled =
+                            Some(BooleanDeserializer::deserialize("IsIPV6Enabled", stack)?);
+                    }
+                    "Logging" => {
+                        obj.logging =
+                            Some(LoggingConfigDeserializer::deserialize("Logging", stack)?);
+                    }
+                    "OriginGroups" => {
+                        obj.origin_groups = Some(OriginGroupsDeserializer::deserialize(
+                            "OriginGroups",
+                            stack,
+                        )?);
+                    }
+                    "Origins" => {
+                        obj.origins = OriginsDeserializer::deserialize("Origins", stack)?;
+                    }
+                    "PriceClass" => {
+                        obj.price_class =
+                            Some(PriceClassDeserializer::deserialize("PriceClass", stack)?);
+                    }
+                    "Restrictions" => {
+                        obj.restrictions = Some(RestrictionsDeserializer::deserialize(
+                            "Restrictions",
+                            stack,
+                        )?);
+                    }
+                    "ViewerCertificate" => {
+                        obj.viewer_certificate = Some(ViewerCertificateDeserializer::deserialize(
+                            "ViewerCertificate",
+                            stack,
+                        )?);
+                    }
+                    "WebACLId" => {
+                        obj.web_acl_id = Some(StringDeserializer::deserialize("WebACLId", stack)?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                "DefaultCacheBehavior" => {
-                    obj.default_cache_behavior = DefaultCacheBehaviorDeserializer::deserialize(
-                        "DefaultCacheBehavior",
-                        stack,
-                    )?;
-                }
-                "DefaultRootObject" => {
-                    obj.default_root_object =
-                        Some(StringDeserializer::deserialize("DefaultRootObject", stack)?);
-                }
-                "Enabled" => {
-                    obj.enabled = BooleanDeserializer::deserialize("Enabled", stack)?;
-                }
-                "HttpVersion" => {
-                    obj.http_version =
-                        Some(HttpVersionDeserializer::deserialize("HttpVersion", stack)?);
-                }
-                "IsIPV6Enabled" => {
-                    obj.is_ipv6_enabled =
-                        Some(BooleanDeserializer::deserialize("IsIPV6Enabled", stack)?);
-                }
-                "Logging" => {
-                    obj.logging = Some(LoggingConfigDeserializer::deserialize("Logging", stack)?);
-                }
-                "OriginGroups" => {
-                    obj.origin_groups = Some(OriginGroupsDeserializer::deserialize(
-                        "OriginGroups",
-                        stack,
-                    )?);
-                }
-                "Origins" => {
-                    obj.origins = OriginsDeserializer::deserialize("Origins", stack)?;
-                }
-                "PriceClass" => {
-                    obj.price_class =
-                        Some(PriceClassDeserializer::deserialize("PriceClass", stack)?);
-                }
-                "Restrictions" => {
-                    obj.restrictions = Some(RestrictionsDeserializer::deserialize(
-                        "Restrictions",
-                        stack,
-                    )?);
-                }
-                "ViewerCertificate" => {
-                    obj.viewer_certificate = Some(ViewerCertificateDeserializer::deserialize(
-                        "ViewerCertificate",
-                        stack,
-                    )?);
-                }
-                "WebACLId" => {
-                    obj.web_acl_id = Some(StringDeserializer::deserialize("WebACLId", stack)?);
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -2486,7 +3230,7 @@ impl DistributionConfigSerializer {
 }
 
 /// <p>A distribution Configuration and a list of tags to be associated with the distribution.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DistributionConfigWithTags {
     /// <p>A distribution configuration.</p>
     pub distribution_config: DistributionConfig,
@@ -2533,10 +3277,10 @@ pub struct DistributionList {
     pub quantity: i64,
 }
 
-struct DistributionListDeserializer;
+pub(crate) struct DistributionListDeserializer;
 impl DistributionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DistributionList, XmlParseError> {
@@ -2569,7 +3313,7 @@ impl DistributionListDeserializer {
     }
 }
 /// <p>A summary of the information about a CloudFront distribution.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DistributionSummary {
     /// <p>The ARN (Amazon Resource Name) for the distribution. For example: <code>arn:aws:cloudfront::123456789012:distribution/EDFDVBD632BHDS5</code>, where <code>123456789012</code> is your AWS account ID.</p>
     pub arn: String,
@@ -2588,19 +3332,19 @@ pub struct DistributionSummary {
     /// <p>Whether the distribution is enabled to accept user requests for content.</p>
     pub enabled: bool,
     /// <p> Specify the maximum HTTP version that you want viewers to use to communicate with CloudFront. The default value for new web distributions is <code>http2</code>. Viewers that don't support <code>HTTP/2</code> will automatically use an earlier version.</p>
-    pub http_version: String,
+    pub http_version: HttpVersion,
     /// <p>The identifier for the distribution. For example: <code>EDFDVBD632BHDS5</code>.</p>
     pub id: String,
     /// <p>Whether CloudFront responds to IPv6 DNS requests with an IPv6 address for your distribution.</p>
     pub is_ipv6_enabled: bool,
     /// <p>The date and time the distribution was last modified.</p>
-    pub last_modified_time: String,
+    pub last_modified_time: IsoTimestamp,
     /// <p> A complex type that contains information about origin groups for this distribution.</p>
     pub origin_groups: Option<OriginGroups>,
     /// <p>A complex type that contains information about origins for this distribution.</p>
     pub origins: Origins,
     /// <p>A complex type that contains information about price class for this streaming distribution. </p>
-    pub price_class: String,
+    pub price_class: PriceClass,
     /// <p><p/></p>
     pub restrictions: Restrictions,
     /// <p>The current status of the distribution. When the status is <code>Deployed</code>, the distribution's information is propagated to all CloudFront edge locations.</p>
@@ -2611,95 +3355,149 @@ pub struct DistributionSummary {
     pub web_acl_id: String,
 }
 
-struct DistributionSummaryDeserializer;
+pub(crate) struct DistributionSummaryDeserializer;
 impl DistributionSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DistributionSummary, XmlParseError> {
-        deserialize_elements::<_, DistributionSummary, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "ARN" => {
-                    obj.arn = StringDeserializer::deserialize("ARN", stack)?;
-                }
-                "Aliases" => {
-                    obj.aliases = AliasesDeserializer::deserialize("Aliases", stack)?;
-                }
-                "CacheBehaviors" => {
-                    obj.cache_behaviors =
-                        CacheBehaviorsDeserializer::deserialize("CacheBehaviors", stack)?;
-                }
-                "Comment" => {
-                    obj.comment = StringDeserializer::deserialize("Comment", stack)?;
-                }
-                "CustomErrorResponses" => {
-                    obj.custom_error_responses = CustomErrorResponsesDeserializer::deserialize(
-                        "CustomErrorResponses",
-                        stack,
-                    )?;
-                }
-                "DefaultCacheBehavior" => {
-                    obj.default_cache_behavior = DefaultCacheBehaviorDeserializer::deserialize(
-                        "DefaultCacheBehavior",
-                        stack,
-                    )?;
-                }
-                "DomainName" => {
-                    obj.domain_name = StringDeserializer::deserialize("DomainName", stack)?;
-                }
-                "Enabled" => {
-                    obj.enabled = BooleanDeserializer::deserialize("Enabled", stack)?;
-                }
-                "HttpVersion" => {
-                    obj.http_version = HttpVersionDeserializer::deserialize("HttpVersion", stack)?;
-                }
-                "Id" => {
-                    obj.id = StringDeserializer::deserialize("Id", stack)?;
-                }
-                "IsIPV6Enabled" => {
-                    obj.is_ipv6_enabled = BooleanDeserializer::deserialize("IsIPV6Enabled", stack)?;
-                }
-                "LastModifiedTime" => {
-                    obj.last_modified_time =
-                        TimestampDeserializer::deserialize("LastModifiedTime", stack)?;
-                }
-                "OriginGroups" => {
-                    obj.origin_groups = Some(OriginGroupsDeserializer::deserialize(
-                        "OriginGroups",
-                        stack,
-                    )?);
-                }
-                "Origins" => {
-                    obj.origins = OriginsDeserializer::deserialize("Origins", stack)?;
-                }
-                "PriceClass" => {
-                    obj.price_class = PriceClassDeserializer::deserialize("PriceClass", stack)?;
-                }
-                "Restrictions" => {
-                    obj.restrictions =
-                        RestrictionsDeserializer::deserialize("Restrictions", stack)?;
-                }
-                "Status" => {
-                    obj.status = StringDeserializer::deserialize("Status", stack)?;
-                }
-                "ViewerCertificate" => {
-                    obj.viewer_certificate =
-                        ViewerCertificateDeserializer::deserialize("ViewerCertificate", stack)?;
-                }
-                "WebACLId" => {
-                    obj.web_acl_id = StringDeserializer::deserialize("WebACLId", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            DistributionSummary {
+                arn: Default::default(),
+                aliases: Default::default(),
+                cache_behaviors: Default::default(),
+                comment: Default::default(),
+                custom_error_responses: Default::default(),
+                default_cache_behavior: DefaultCacheBehavior {
+                    allowed_methods: Default::default(),
+                    compress: Default::default(),
+                    default_ttl: Default::default(),
+                    field_level_encryption_id: Default::default(),
+                    forwarded_values: ForwardedValues {
+                        cookies: CookiePreference {
+                            forward: ItemSelection::Unknown(String::new()),
+                            whitelisted_names: Default::default(),
+                        },
+                        headers: Default::default(),
+                        query_string: Default::default(),
+                        query_string_cache_keys: Default::default(),
+                    },
+                    lambda_function_associations: Default::default(),
+                    max_ttl: Default::default(),
+                    min_ttl: Default::default(),
+                    smooth_streaming: Default::default(),
+                    target_origin_id: Default::default(),
+                    trusted_signers: Default::default(),
+                    viewer_protocol_policy: ViewerProtocolPolicy::Unknown(String::new()),
+                },
+                domain_name: Default::default(),
+                enabled: Default::default(),
+                http_version: HttpVersion::Unknown(String::new()),
+                id: Default::default(),
+                is_ipv6_enabled: Default::default(),
+                last_modified_time: Default::default(),
+                origin_groups: Default::default(),
+                origins: Default::default(),
+                price_class: PriceClass::Unknown(String::new()),
+                restrictions: Restrictions {
+                    geo_restriction: GeoRestriction {
+                        items: Default::default(),
+                        quantity: Default::default(),
+                        restriction_type: GeoRestrictionType::Unknown(String::new()),
+                    },
+                },
+                status: Default::default(),
+                viewer_certificate: Default::default(),
+                web_acl_id: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "ARN" => {
+                        obj.arn = StringDeserializer::deserialize("ARN", stack)?;
+                    }
+                    "Aliases" => {
+                        obj.aliases = AliasesDeserializer::deserialize("Aliases", stack)?;
+                    }
+                    "CacheBehaviors" => {
+                        obj.cache_behaviors =
+                            CacheBehaviorsDeserializer::deserialize("CacheBehaviors", stack)?;
+                    }
+                    "Comment" => {
+                        obj.comment = StringDeserializer::deserialize("Comment", stack)?;
+                    }
+                    "CustomErrorResponses" => {
+                        obj.custom_error_responses = CustomErrorResponsesDeserializer::deserialize(
+                            "CustomErrorResponses",
+                            stack,
+                        )?;
+                    }
+                    "DefaultCacheBehavior" => {
+                        obj.default_cache_behavior = DefaultCacheBehaviorDeserializer::deserialize(
+                            "DefaultCacheBehavior",
+                            stack,
+                        )?;
+                    }
+                    "DomainName" => {
+                        obj.domain_name = StringDeserializer::deserialize("DomainName", stack)?;
+                    }
+                    "Enabled" => {
+                        obj.enabled = BooleanDeserializer::deserialize("Enabled", stack)?;
+                    }
+                    "HttpVersion" => {
+                        obj.http_version =
+                            HttpVersionDeserializer::deserialize("HttpVersion", stack)?;
+                    }
+                    "Id" => {
+                        obj.id = StringDeserializer::deserialize("Id", stack)?;
+                    }
+                    "IsIPV6Enabled" => {
+                        obj.is_ipv6_enabled =
+                            BooleanDeserializer::deserialize("IsIPV6Enabled", stack)?;
+                    }
+                    "LastModifiedTime" => {
+                        obj.last_modified_time =
+                            TimestampDeserializer::deserialize("LastModifiedTime", stack)?;
+                    }
+                    "OriginGroups" => {
+                        obj.origin_groups = Some(OriginGroupsDeserializer::deserialize(
+                            "OriginGroups",
+                            stack,
+                        )?);
+                    }
+                    "Origins" => {
+                        obj.origins = OriginsDeserializer::deserialize("Origins", stack)?;
+                    }
+                    "PriceClass" => {
+                        obj.price_class = PriceClassDeserializer::deserialize("PriceClass", stack)?;
+                    }
+                    "Restrictions" => {
+                        obj.restrictions =
+                            RestrictionsDeserializer::deserialize("Restrictions", stack)?;
+                    }
+                    "Status" => {
+                        obj.status = StringDeserializer::deserialize("Status", stack)?;
+                    }
+                    "ViewerCertificate" => {
+                        obj.viewer_certificate =
+                            ViewerCertificateDeserializer::deserialize("ViewerCertificate", stack)?;
+                    }
+                    "WebACLId" => {
+                        obj.web_acl_id = StringDeserializer::deserialize("WebACLId", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct DistributionSummaryListDeserializer;
+pub(crate) struct DistributionSummaryListDeserializer;
 impl DistributionSummaryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DistributionSummary>, XmlParseError> {
@@ -2725,10 +3523,10 @@ pub struct EncryptionEntities {
     pub quantity: i64,
 }
 
-struct EncryptionEntitiesDeserializer;
+pub(crate) struct EncryptionEntitiesDeserializer;
 impl EncryptionEntitiesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EncryptionEntities, XmlParseError> {
@@ -2785,10 +3583,10 @@ pub struct EncryptionEntity {
     pub public_key_id: String,
 }
 
-struct EncryptionEntityDeserializer;
+pub(crate) struct EncryptionEntityDeserializer;
 impl EncryptionEntityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EncryptionEntity, XmlParseError> {
@@ -2840,10 +3638,10 @@ impl EncryptionEntitySerializer {
     }
 }
 
-struct EncryptionEntityListDeserializer;
+pub(crate) struct EncryptionEntityListDeserializer;
 impl EncryptionEntityListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EncryptionEntity>, XmlParseError> {
@@ -2881,12 +3679,15 @@ impl EncryptionEntityListSerializer {
     }
 }
 
-struct EventTypeDeserializer;
+pub(crate) struct EventTypeDeserializer;
 impl EventTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EventType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2899,7 +3700,7 @@ impl EventTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &EventType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -2913,6 +3714,73 @@ impl EventTypeSerializer {
     }
 }
 
+/// <p><p>Specifies the event type that triggers a Lambda function invocation. You can specify the following values:</p> <ul> <li> <p> <code>viewer-request</code>: The function executes when CloudFront receives a request from a viewer and before it checks to see whether the requested object is in the edge cache. </p> </li> <li> <p> <code>origin-request</code>: The function executes only when CloudFront forwards a request to your origin. When the requested object is in the edge cache, the function doesn&#39;t execute.</p> </li> <li> <p> <code>origin-response</code>: The function executes after CloudFront receives a response from the origin and before it caches the object in the response. When the requested object is in the edge cache, the function doesn&#39;t execute.</p> </li> <li> <p> <code>viewer-response</code>: The function executes before CloudFront returns the requested object to the viewer. The function executes regardless of whether the object was already in the edge cache.</p> <p>If the origin returns an HTTP status code other than HTTP 200 (OK), the function doesn&#39;t execute.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventType {
+    /// <p><code>viewer-request</code> - The function executes when CloudFront receives a request from a viewer and before it checks to see whether the requested object is in the edge cache.</p>
+    ViewerRequest,
+    /// <p><code>viewer-response</code> - The function executes before CloudFront returns the requested object to the viewer. The function executes regardless of whether the object was already in the edge cache.</p>
+    ViewerResponse,
+    /// <p><code>origin-request</code> - The function executes only when CloudFront forwards a request to your origin. When the requested object is in the edge cache, the function doesn&#39;t execute.</p>
+    OriginRequest,
+    /// <p><code>origin-response</code> - The function executes after CloudFront receives a response from the origin and before it caches the object in the response. When the requested object is in the edge cache, the function doesn&#39;t execute.</p>
+    OriginResponse,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::ViewerRequest => "viewer-request",
+            EventType::ViewerResponse => "viewer-response",
+            EventType::OriginRequest => "origin-request",
+            EventType::OriginResponse => "origin-response",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "viewer-request" => EventType::ViewerRequest,
+            "viewer-response" => EventType::ViewerResponse,
+            "origin-request" => EventType::OriginRequest,
+            "origin-response" => EventType::OriginResponse,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        EventType::from(value.as_str())
+    }
+}
+
+impl From<EventType> for String {
+    fn from(value: EventType) -> String {
+        match value {
+            EventType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A complex data type that includes the profile configurations and other options specified for field-level encryption. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct FieldLevelEncryption {
@@ -2921,13 +3789,13 @@ pub struct FieldLevelEncryption {
     /// <p>The configuration ID for a field-level encryption configuration which includes a set of profiles that specify certain selected data fields to be encrypted by specific public keys.</p>
     pub id: String,
     /// <p>The last time the field-level encryption configuration was changed. </p>
-    pub last_modified_time: String,
+    pub last_modified_time: IsoTimestamp,
 }
 
-struct FieldLevelEncryptionDeserializer;
+pub(crate) struct FieldLevelEncryptionDeserializer;
 impl FieldLevelEncryptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FieldLevelEncryption, XmlParseError> {
@@ -2966,10 +3834,10 @@ pub struct FieldLevelEncryptionConfig {
     pub query_arg_profile_config: Option<QueryArgProfileConfig>,
 }
 
-struct FieldLevelEncryptionConfigDeserializer;
+pub(crate) struct FieldLevelEncryptionConfigDeserializer;
 impl FieldLevelEncryptionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FieldLevelEncryptionConfig, XmlParseError> {
@@ -3064,10 +3932,10 @@ pub struct FieldLevelEncryptionList {
     pub quantity: i64,
 }
 
-struct FieldLevelEncryptionListDeserializer;
+pub(crate) struct FieldLevelEncryptionListDeserializer;
 impl FieldLevelEncryptionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FieldLevelEncryptionList, XmlParseError> {
@@ -3108,13 +3976,13 @@ pub struct FieldLevelEncryptionProfile {
     /// <p>The ID for a field-level encryption profile configuration which includes a set of profiles that specify certain selected data fields to be encrypted by specific public keys.</p>
     pub id: String,
     /// <p>The last time the field-level encryption profile was updated.</p>
-    pub last_modified_time: String,
+    pub last_modified_time: IsoTimestamp,
 }
 
-struct FieldLevelEncryptionProfileDeserializer;
+pub(crate) struct FieldLevelEncryptionProfileDeserializer;
 impl FieldLevelEncryptionProfileDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FieldLevelEncryptionProfile, XmlParseError> {
@@ -3157,10 +4025,10 @@ pub struct FieldLevelEncryptionProfileConfig {
     pub name: String,
 }
 
-struct FieldLevelEncryptionProfileConfigDeserializer;
+pub(crate) struct FieldLevelEncryptionProfileConfigDeserializer;
 impl FieldLevelEncryptionProfileConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FieldLevelEncryptionProfileConfig, XmlParseError> {
@@ -3247,10 +4115,10 @@ pub struct FieldLevelEncryptionProfileList {
     pub quantity: i64,
 }
 
-struct FieldLevelEncryptionProfileListDeserializer;
+pub(crate) struct FieldLevelEncryptionProfileListDeserializer;
 impl FieldLevelEncryptionProfileListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FieldLevelEncryptionProfileList, XmlParseError> {
@@ -3293,15 +4161,15 @@ pub struct FieldLevelEncryptionProfileSummary {
     /// <p>ID for the field-level encryption profile summary.</p>
     pub id: String,
     /// <p>The time when the the field-level encryption profile summary was last updated.</p>
-    pub last_modified_time: String,
+    pub last_modified_time: IsoTimestamp,
     /// <p>Name for the field-level encryption profile summary.</p>
     pub name: String,
 }
 
-struct FieldLevelEncryptionProfileSummaryDeserializer;
+pub(crate) struct FieldLevelEncryptionProfileSummaryDeserializer;
 impl FieldLevelEncryptionProfileSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FieldLevelEncryptionProfileSummary, XmlParseError> {
@@ -3336,10 +4204,10 @@ impl FieldLevelEncryptionProfileSummaryDeserializer {
         )
     }
 }
-struct FieldLevelEncryptionProfileSummaryListDeserializer;
+pub(crate) struct FieldLevelEncryptionProfileSummaryListDeserializer;
 impl FieldLevelEncryptionProfileSummaryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<FieldLevelEncryptionProfileSummary>, XmlParseError> {
@@ -3366,15 +4234,15 @@ pub struct FieldLevelEncryptionSummary {
     /// <p>The unique ID of a field-level encryption item.</p>
     pub id: String,
     /// <p>The last time that the summary of field-level encryption items was modified.</p>
-    pub last_modified_time: String,
+    pub last_modified_time: IsoTimestamp,
     /// <p> A summary of a query argument-profile mapping. </p>
     pub query_arg_profile_config: Option<QueryArgProfileConfig>,
 }
 
-struct FieldLevelEncryptionSummaryDeserializer;
+pub(crate) struct FieldLevelEncryptionSummaryDeserializer;
 impl FieldLevelEncryptionSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FieldLevelEncryptionSummary, XmlParseError> {
@@ -3414,10 +4282,10 @@ impl FieldLevelEncryptionSummaryDeserializer {
         )
     }
 }
-struct FieldLevelEncryptionSummaryListDeserializer;
+pub(crate) struct FieldLevelEncryptionSummaryListDeserializer;
 impl FieldLevelEncryptionSummaryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<FieldLevelEncryptionSummary>, XmlParseError> {
@@ -3434,10 +4302,10 @@ impl FieldLevelEncryptionSummaryListDeserializer {
         })
     }
 }
-struct FieldPatternListDeserializer;
+pub(crate) struct FieldPatternListDeserializer;
 impl FieldPatternListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -3481,10 +4349,10 @@ pub struct FieldPatterns {
     pub quantity: i64,
 }
 
-struct FieldPatternsDeserializer;
+pub(crate) struct FieldPatternsDeserializer;
 impl FieldPatternsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FieldPatterns, XmlParseError> {
@@ -3530,12 +4398,15 @@ impl FieldPatternsSerializer {
     }
 }
 
-struct FormatDeserializer;
+pub(crate) struct FormatDeserializer;
 impl FormatDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Format, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3548,7 +4419,7 @@ impl FormatSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &Format,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3562,8 +4433,63 @@ impl FormatSerializer {
     }
 }
 
+/// <p>The format for a field-level encryption content type-profile mapping. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// <p><code>URLEncoded</code></p>
+    URLEncoded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Format {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Format::URLEncoded => "URLEncoded",
+            Format::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Format {
+    fn from(value: &'a str) -> Format {
+        match value {
+            "URLEncoded" => Format::URLEncoded,
+            _ => Format::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Format {
+    fn from(value: String) -> Format {
+        Format::from(value.as_str())
+    }
+}
+
+impl From<Format> for String {
+    fn from(value: Format) -> String {
+        match value {
+            Format::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Format {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A complex type that specifies how CloudFront handles query strings and cookies.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ForwardedValues {
     /// <p>A complex type that specifies whether you want CloudFront to forward cookies to the origin and, if so, which ones. For more information about forwarding cookies to the origin, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/Cookies.html">How CloudFront Forwards, Caches, and Logs Cookies</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
     pub cookies: CookiePreference,
@@ -3575,35 +4501,48 @@ pub struct ForwardedValues {
     pub query_string_cache_keys: Option<QueryStringCacheKeys>,
 }
 
-struct ForwardedValuesDeserializer;
+pub(crate) struct ForwardedValuesDeserializer;
 impl ForwardedValuesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ForwardedValues, XmlParseError> {
-        deserialize_elements::<_, ForwardedValues, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Cookies" => {
-                    obj.cookies = CookiePreferenceDeserializer::deserialize("Cookies", stack)?;
-                }
-                "Headers" => {
-                    obj.headers = Some(HeadersDeserializer::deserialize("Headers", stack)?);
-                }
-                "QueryString" => {
-                    obj.query_string = BooleanDeserializer::deserialize("QueryString", stack)?;
-                }
-                "QueryStringCacheKeys" => {
-                    obj.query_string_cache_keys =
-                        Some(QueryStringCacheKeysDeserializer::deserialize(
-                            "QueryStringCacheKeys",
-                            stack,
-                        )?);
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            ForwardedValues {
+                cookies: CookiePreference {
+                    forward: ItemSelection::Unknown(String::new()),
+                    whitelisted_names: Default::default(),
+                },
+                headers: Default::default(),
+                query_string: Default::default(),
+                query_string_cache_keys: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Cookies" => {
+                        obj.cookies = CookiePreferenceDeserializer::deserialize("Cookies", stack)?;
+                    }
+                    "Headers" => {
+                        obj.headers = Some(HeadersDeserializer::deserialize("Headers", stack)?);
+                    }
+                    "QueryString" => {
+                        obj.query_string = BooleanDeserializer::deserialize("QueryString", stack)?;
+                    }
+                    "QueryStringCacheKeys" => {
+                        obj.query_string_cache_keys =
+                            Some(QueryStringCacheKeysDeserializer::deserialize(
+                                "QueryStringCacheKeys",
+                                stack,
+                            )?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -3637,41 +4576,50 @@ impl ForwardedValuesSerializer {
 }
 
 /// <p>A complex type that controls the countries in which your content is distributed. CloudFront determines the location of your users using <code>MaxMind</code> GeoIP databases. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GeoRestriction {
     /// <p> A complex type that contains a <code>Location</code> element for each country in which you want CloudFront either to distribute your content (<code>whitelist</code>) or not distribute your content (<code>blacklist</code>).</p> <p>The <code>Location</code> element is a two-letter, uppercase country code for a country that you want to include in your <code>blacklist</code> or <code>whitelist</code>. Include one <code>Location</code> element for each country.</p> <p>CloudFront and <code>MaxMind</code> both use <code>ISO 3166</code> country codes. For the current list of countries and the corresponding codes, see <code>ISO 3166-1-alpha-2</code> code on the <i>International Organization for Standardization</i> website. You can also refer to the country list on the CloudFront console, which includes both country names and codes.</p>
     pub items: Option<Vec<String>>,
     /// <p>When geo restriction is <code>enabled</code>, this is the number of countries in your <code>whitelist</code> or <code>blacklist</code>. Otherwise, when it is not enabled, <code>Quantity</code> is <code>0</code>, and you can omit <code>Items</code>.</p>
     pub quantity: i64,
     /// <p><p>The method that you want to use to restrict distribution of your content by country:</p> <ul> <li> <p> <code>none</code>: No geo restriction is enabled, meaning access to content is not restricted by client geo location.</p> </li> <li> <p> <code>blacklist</code>: The <code>Location</code> elements specify the countries in which you don&#39;t want CloudFront to distribute your content.</p> </li> <li> <p> <code>whitelist</code>: The <code>Location</code> elements specify the countries in which you want CloudFront to distribute your content.</p> </li> </ul></p>
-    pub restriction_type: String,
+    pub restriction_type: GeoRestrictionType,
 }
 
-struct GeoRestrictionDeserializer;
+pub(crate) struct GeoRestrictionDeserializer;
 impl GeoRestrictionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GeoRestriction, XmlParseError> {
-        deserialize_elements::<_, GeoRestriction, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Items" => {
-                    obj.items
-                        .get_or_insert(vec![])
-                        .extend(LocationListDeserializer::deserialize("Items", stack)?);
-                }
-                "Quantity" => {
-                    obj.quantity = IntegerDeserializer::deserialize("Quantity", stack)?;
-                }
-                "RestrictionType" => {
-                    obj.restriction_type =
-                        GeoRestrictionTypeDeserializer::deserialize("RestrictionType", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            GeoRestriction {
+                items: Default::default(),
+                quantity: Default::default(),
+                restriction_type: GeoRestrictionType::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Items" => {
+                        obj.items
+                            .get_or_insert(vec![])
+                            .extend(LocationListDeserializer::deserialize("Items", stack)?);
+                    }
+                    "Quantity" => {
+                        obj.quantity = IntegerDeserializer::deserialize("Quantity", stack)?;
+                    }
+                    "RestrictionType" => {
+                        obj.restriction_type =
+                            GeoRestrictionTypeDeserializer::deserialize("RestrictionType", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -3706,12 +4654,15 @@ impl GeoRestrictionSerializer {
     }
 }
 
-struct GeoRestrictionTypeDeserializer;
+pub(crate) struct GeoRestrictionTypeDeserializer;
 impl GeoRestrictionTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<GeoRestrictionType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3724,7 +4675,7 @@ impl GeoRestrictionTypeSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &GeoRestrictionType,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -3738,28 +4689,124 @@ impl GeoRestrictionTypeSerializer {
     }
 }
 
-/// <p>The origin access identity's configuration information. For more information, see <a>CloudFrontOriginAccessIdentityConfigComplexType</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct GetCloudFrontOriginAccessIdentityConfigRequest {
-    /// <p>The identity's ID. </p>
-    pub id: String,
+/// <p><p>The method that you want to use to restrict distribution of your content by country:</p> <ul> <li> <p> <code>none</code>: No geo restriction is enabled, meaning access to content is not restricted by client geo location.</p> </li> <li> <p> <code>blacklist</code>: The <code>Location</code> elements specify the countries in which you don&#39;t want CloudFront to distribute your content.</p> </li> <li> <p> <code>whitelist</code>: The <code>Location</code> elements specify the countries in which you want CloudFront to distribute your content.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GeoRestrictionType {
+    /// <p><code>blacklist</code> - The <code>Location</code> elements specify the countries in which you don&#39;t want CloudFront to distribute your content.</p>
+    Blacklist,
+    /// <p><code>whitelist</code> - The <code>Location</code> elements specify the countries in which you want CloudFront to distribute your content.</p>
+    Whitelist,
+    /// <p><code>none</code> - No geo restriction is enabled, meaning access to content is not restricted by client geo location.</p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>The returned result of the corresponding request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct GetCloudFrontOriginAccessIdentityConfigResult {
-    /// <p>The origin access identity's configuration information. </p>
-    pub cloud_front_origin_access_identity_config: Option<CloudFrontOriginAccessIdentityConfig>,
-    /// <p>The current version of the configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
-    pub e_tag: Option<String>,
+impl GeoRestrictionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GeoRestrictionType::Blacklist => "blacklist",
+            GeoRestrictionType::Whitelist => "whitelist",
+            GeoRestrictionType::None => "none",
+            GeoRestrictionType::Unknown(ref value) => value,
+        }
+    }
 }
 
-struct GetCloudFrontOriginAccessIdentityConfigResultDeserializer;
-impl GetCloudFrontOriginAccessIdentityConfigResultDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
-        tag_name: &str,
-        stack: &mut T,
+impl<'a> From<&'a str> for GeoRestrictionType {
+    fn from(value: &'a str) -> GeoRestrictionType {
+        match value {
+            "blacklist" => GeoRestrictionType::Blacklist,
+            "whitelist" => GeoRestrictionType::Whitelist,
+            "none" => GeoRestrictionType::None,
+            _ => GeoRestrictionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GeoRestrictionType {
+    fn from(value: String) -> GeoRestrictionType {
+        GeoRestrictionType::from(value.as_str())
+    }
+}
+
+impl From<GeoRestrictionType> for String {
+    fn from(value: GeoRestrictionType) -> String {
+        match value {
+            GeoRestrictionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GeoRestrictionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GeoRestrictionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// <p>The origin access identity's configuration information. For more information, see <a>CloudFrontOriginAccessIdentityConfigComplexType</a>.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct GetCloudFrontOriginAccessIdentityConfigRequest {
+    /// <p>The identity's ID. </p>
+    pub id: String,
+}
+
+impl GetCloudFrontOriginAccessIdentityConfigRequest {
+    /// Returns a builder for `GetCloudFrontOriginAccessIdentityConfigRequest`, checking its required members when built.
+    pub fn builder() -> GetCloudFrontOriginAccessIdentityConfigRequestBuilder {
+        GetCloudFrontOriginAccessIdentityConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCloudFrontOriginAccessIdentityConfigRequest`](struct.GetCloudFrontOriginAccessIdentityConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCloudFrontOriginAccessIdentityConfigRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetCloudFrontOriginAccessIdentityConfigRequestBuilder {
+    /// Sets the [`id`](struct.GetCloudFrontOriginAccessIdentityConfigRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCloudFrontOriginAccessIdentityConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        GetCloudFrontOriginAccessIdentityConfigRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(GetCloudFrontOriginAccessIdentityConfigRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
+/// <p>The returned result of the corresponding request.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct GetCloudFrontOriginAccessIdentityConfigResult {
+    /// <p>The origin access identity's configuration information. </p>
+    pub cloud_front_origin_access_identity_config: Option<CloudFrontOriginAccessIdentityConfig>,
+    /// <p>The current version of the configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
+    pub e_tag: Option<String>,
+}
+
+pub(crate) struct GetCloudFrontOriginAccessIdentityConfigResultDeserializer;
+impl GetCloudFrontOriginAccessIdentityConfigResultDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
     ) -> Result<GetCloudFrontOriginAccessIdentityConfigResult, XmlParseError> {
         Ok(GetCloudFrontOriginAccessIdentityConfigResult {
             cloud_front_origin_access_identity_config: Some(
@@ -3779,6 +4826,37 @@ pub struct GetCloudFrontOriginAccessIdentityRequest {
     pub id: String,
 }
 
+impl GetCloudFrontOriginAccessIdentityRequest {
+    /// Returns a builder for `GetCloudFrontOriginAccessIdentityRequest`, checking its required members when built.
+    pub fn builder() -> GetCloudFrontOriginAccessIdentityRequestBuilder {
+        GetCloudFrontOriginAccessIdentityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCloudFrontOriginAccessIdentityRequest`](struct.GetCloudFrontOriginAccessIdentityRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCloudFrontOriginAccessIdentityRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetCloudFrontOriginAccessIdentityRequestBuilder {
+    /// Sets the [`id`](struct.GetCloudFrontOriginAccessIdentityRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCloudFrontOriginAccessIdentityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCloudFrontOriginAccessIdentityRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetCloudFrontOriginAccessIdentityRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetCloudFrontOriginAccessIdentityResult {
@@ -3788,10 +4866,10 @@ pub struct GetCloudFrontOriginAccessIdentityResult {
     pub e_tag: Option<String>,
 }
 
-struct GetCloudFrontOriginAccessIdentityResultDeserializer;
+pub(crate) struct GetCloudFrontOriginAccessIdentityResultDeserializer;
 impl GetCloudFrontOriginAccessIdentityResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetCloudFrontOriginAccessIdentityResult, XmlParseError> {
@@ -3813,6 +4891,36 @@ pub struct GetDistributionConfigRequest {
     pub id: String,
 }
 
+impl GetDistributionConfigRequest {
+    /// Returns a builder for `GetDistributionConfigRequest`, checking its required members when built.
+    pub fn builder() -> GetDistributionConfigRequestBuilder {
+        GetDistributionConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDistributionConfigRequest`](struct.GetDistributionConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDistributionConfigRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetDistributionConfigRequestBuilder {
+    /// Sets the [`id`](struct.GetDistributionConfigRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDistributionConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDistributionConfigRequest, ::rusoto_core::BuildError> {
+        Ok(GetDistributionConfigRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetDistributionConfigResult {
@@ -3822,10 +4930,10 @@ pub struct GetDistributionConfigResult {
     pub e_tag: Option<String>,
 }
 
-struct GetDistributionConfigResultDeserializer;
+pub(crate) struct GetDistributionConfigResultDeserializer;
 impl GetDistributionConfigResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetDistributionConfigResult, XmlParseError> {
@@ -3845,6 +4953,34 @@ pub struct GetDistributionRequest {
     pub id: String,
 }
 
+impl GetDistributionRequest {
+    /// Returns a builder for `GetDistributionRequest`, checking its required members when built.
+    pub fn builder() -> GetDistributionRequestBuilder {
+        GetDistributionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDistributionRequest`](struct.GetDistributionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDistributionRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetDistributionRequestBuilder {
+    /// Sets the [`id`](struct.GetDistributionRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDistributionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDistributionRequest, ::rusoto_core::BuildError> {
+        Ok(GetDistributionRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetDistributionResult {
@@ -3854,10 +4990,10 @@ pub struct GetDistributionResult {
     pub e_tag: Option<String>,
 }
 
-struct GetDistributionResultDeserializer;
+pub(crate) struct GetDistributionResultDeserializer;
 impl GetDistributionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetDistributionResult, XmlParseError> {
@@ -3876,6 +5012,37 @@ pub struct GetFieldLevelEncryptionConfigRequest {
     pub id: String,
 }
 
+impl GetFieldLevelEncryptionConfigRequest {
+    /// Returns a builder for `GetFieldLevelEncryptionConfigRequest`, checking its required members when built.
+    pub fn builder() -> GetFieldLevelEncryptionConfigRequestBuilder {
+        GetFieldLevelEncryptionConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFieldLevelEncryptionConfigRequest`](struct.GetFieldLevelEncryptionConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFieldLevelEncryptionConfigRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetFieldLevelEncryptionConfigRequestBuilder {
+    /// Sets the [`id`](struct.GetFieldLevelEncryptionConfigRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFieldLevelEncryptionConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetFieldLevelEncryptionConfigRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetFieldLevelEncryptionConfigRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetFieldLevelEncryptionConfigResult {
     /// <p>The current version of the field level encryption configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
@@ -3884,10 +5051,10 @@ pub struct GetFieldLevelEncryptionConfigResult {
     pub field_level_encryption_config: Option<FieldLevelEncryptionConfig>,
 }
 
-struct GetFieldLevelEncryptionConfigResultDeserializer;
+pub(crate) struct GetFieldLevelEncryptionConfigResultDeserializer;
 impl GetFieldLevelEncryptionConfigResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetFieldLevelEncryptionConfigResult, XmlParseError> {
@@ -3908,6 +5075,37 @@ pub struct GetFieldLevelEncryptionProfileConfigRequest {
     pub id: String,
 }
 
+impl GetFieldLevelEncryptionProfileConfigRequest {
+    /// Returns a builder for `GetFieldLevelEncryptionProfileConfigRequest`, checking its required members when built.
+    pub fn builder() -> GetFieldLevelEncryptionProfileConfigRequestBuilder {
+        GetFieldLevelEncryptionProfileConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFieldLevelEncryptionProfileConfigRequest`](struct.GetFieldLevelEncryptionProfileConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFieldLevelEncryptionProfileConfigRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetFieldLevelEncryptionProfileConfigRequestBuilder {
+    /// Sets the [`id`](struct.GetFieldLevelEncryptionProfileConfigRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFieldLevelEncryptionProfileConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetFieldLevelEncryptionProfileConfigRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetFieldLevelEncryptionProfileConfigRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetFieldLevelEncryptionProfileConfigResult {
     /// <p>The current version of the field-level encryption profile configuration result. For example: <code>E2QWRUHAPOMQZL</code>.</p>
@@ -3916,10 +5114,10 @@ pub struct GetFieldLevelEncryptionProfileConfigResult {
     pub field_level_encryption_profile_config: Option<FieldLevelEncryptionProfileConfig>,
 }
 
-struct GetFieldLevelEncryptionProfileConfigResultDeserializer;
+pub(crate) struct GetFieldLevelEncryptionProfileConfigResultDeserializer;
 impl GetFieldLevelEncryptionProfileConfigResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetFieldLevelEncryptionProfileConfigResult, XmlParseError> {
@@ -3940,6 +5138,37 @@ pub struct GetFieldLevelEncryptionProfileRequest {
     pub id: String,
 }
 
+impl GetFieldLevelEncryptionProfileRequest {
+    /// Returns a builder for `GetFieldLevelEncryptionProfileRequest`, checking its required members when built.
+    pub fn builder() -> GetFieldLevelEncryptionProfileRequestBuilder {
+        GetFieldLevelEncryptionProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFieldLevelEncryptionProfileRequest`](struct.GetFieldLevelEncryptionProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFieldLevelEncryptionProfileRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetFieldLevelEncryptionProfileRequestBuilder {
+    /// Sets the [`id`](struct.GetFieldLevelEncryptionProfileRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFieldLevelEncryptionProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetFieldLevelEncryptionProfileRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetFieldLevelEncryptionProfileRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetFieldLevelEncryptionProfileResult {
     /// <p>The current version of the field level encryption profile. For example: <code>E2QWRUHAPOMQZL</code>.</p>
@@ -3948,10 +5177,10 @@ pub struct GetFieldLevelEncryptionProfileResult {
     pub field_level_encryption_profile: Option<FieldLevelEncryptionProfile>,
 }
 
-struct GetFieldLevelEncryptionProfileResultDeserializer;
+pub(crate) struct GetFieldLevelEncryptionProfileResultDeserializer;
 impl GetFieldLevelEncryptionProfileResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetFieldLevelEncryptionProfileResult, XmlParseError> {
@@ -3972,6 +5201,36 @@ pub struct GetFieldLevelEncryptionRequest {
     pub id: String,
 }
 
+impl GetFieldLevelEncryptionRequest {
+    /// Returns a builder for `GetFieldLevelEncryptionRequest`, checking its required members when built.
+    pub fn builder() -> GetFieldLevelEncryptionRequestBuilder {
+        GetFieldLevelEncryptionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFieldLevelEncryptionRequest`](struct.GetFieldLevelEncryptionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFieldLevelEncryptionRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetFieldLevelEncryptionRequestBuilder {
+    /// Sets the [`id`](struct.GetFieldLevelEncryptionRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFieldLevelEncryptionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetFieldLevelEncryptionRequest, ::rusoto_core::BuildError> {
+        Ok(GetFieldLevelEncryptionRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetFieldLevelEncryptionResult {
     /// <p>The current version of the field level encryption configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
@@ -3980,10 +5239,10 @@ pub struct GetFieldLevelEncryptionResult {
     pub field_level_encryption: Option<FieldLevelEncryption>,
 }
 
-struct GetFieldLevelEncryptionResultDeserializer;
+pub(crate) struct GetFieldLevelEncryptionResultDeserializer;
 impl GetFieldLevelEncryptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetFieldLevelEncryptionResult, XmlParseError> {
@@ -4005,6 +5264,44 @@ pub struct GetInvalidationRequest {
     pub id: String,
 }
 
+impl GetInvalidationRequest {
+    /// Returns a builder for `GetInvalidationRequest`, checking its required members when built.
+    pub fn builder() -> GetInvalidationRequestBuilder {
+        GetInvalidationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInvalidationRequest`](struct.GetInvalidationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInvalidationRequestBuilder {
+    distribution_id: Option<String>,
+    id: Option<String>,
+}
+
+impl GetInvalidationRequestBuilder {
+    /// Sets the [`distribution_id`](struct.GetInvalidationRequest.html#structfield.distribution_id) member.
+    pub fn distribution_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.distribution_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.GetInvalidationRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInvalidationRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetInvalidationRequest, ::rusoto_core::BuildError> {
+        Ok(GetInvalidationRequest {
+            distribution_id: self
+                .distribution_id
+                .ok_or(::rusoto_core::BuildError::missing("distribution_id"))?,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetInvalidationResult {
@@ -4012,10 +5309,10 @@ pub struct GetInvalidationResult {
     pub invalidation: Option<Invalidation>,
 }
 
-struct GetInvalidationResultDeserializer;
+pub(crate) struct GetInvalidationResultDeserializer;
 impl GetInvalidationResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetInvalidationResult, XmlParseError> {
@@ -4034,6 +5331,36 @@ pub struct GetPublicKeyConfigRequest {
     pub id: String,
 }
 
+impl GetPublicKeyConfigRequest {
+    /// Returns a builder for `GetPublicKeyConfigRequest`, checking its required members when built.
+    pub fn builder() -> GetPublicKeyConfigRequestBuilder {
+        GetPublicKeyConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPublicKeyConfigRequest`](struct.GetPublicKeyConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPublicKeyConfigRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetPublicKeyConfigRequestBuilder {
+    /// Sets the [`id`](struct.GetPublicKeyConfigRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPublicKeyConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetPublicKeyConfigRequest, ::rusoto_core::BuildError> {
+        Ok(GetPublicKeyConfigRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetPublicKeyConfigResult {
     /// <p>The current version of the public key configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
@@ -4042,10 +5369,10 @@ pub struct GetPublicKeyConfigResult {
     pub public_key_config: Option<PublicKeyConfig>,
 }
 
-struct GetPublicKeyConfigResultDeserializer;
+pub(crate) struct GetPublicKeyConfigResultDeserializer;
 impl GetPublicKeyConfigResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetPublicKeyConfigResult, XmlParseError> {
@@ -4064,6 +5391,34 @@ pub struct GetPublicKeyRequest {
     pub id: String,
 }
 
+impl GetPublicKeyRequest {
+    /// Returns a builder for `GetPublicKeyRequest`, checking its required members when built.
+    pub fn builder() -> GetPublicKeyRequestBuilder {
+        GetPublicKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPublicKeyRequest`](struct.GetPublicKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPublicKeyRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetPublicKeyRequestBuilder {
+    /// Sets the [`id`](struct.GetPublicKeyRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPublicKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetPublicKeyRequest, ::rusoto_core::BuildError> {
+        Ok(GetPublicKeyRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetPublicKeyResult {
     /// <p>The current version of the public key. For example: <code>E2QWRUHAPOMQZL</code>.</p>
@@ -4072,10 +5427,10 @@ pub struct GetPublicKeyResult {
     pub public_key: Option<PublicKey>,
 }
 
-struct GetPublicKeyResultDeserializer;
+pub(crate) struct GetPublicKeyResultDeserializer;
 impl GetPublicKeyResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetPublicKeyResult, XmlParseError> {
@@ -4092,6 +5447,37 @@ pub struct GetStreamingDistributionConfigRequest {
     pub id: String,
 }
 
+impl GetStreamingDistributionConfigRequest {
+    /// Returns a builder for `GetStreamingDistributionConfigRequest`, checking its required members when built.
+    pub fn builder() -> GetStreamingDistributionConfigRequestBuilder {
+        GetStreamingDistributionConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetStreamingDistributionConfigRequest`](struct.GetStreamingDistributionConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct GetStreamingDistributionConfigRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetStreamingDistributionConfigRequestBuilder {
+    /// Sets the [`id`](struct.GetStreamingDistributionConfigRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetStreamingDistributionConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetStreamingDistributionConfigRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetStreamingDistributionConfigRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetStreamingDistributionConfigResult {
@@ -4101,10 +5487,10 @@ pub struct GetStreamingDistributionConfigResult {
     pub streaming_distribution_config: Option<StreamingDistributionConfig>,
 }
 
-struct GetStreamingDistributionConfigResultDeserializer;
+pub(crate) struct GetStreamingDistributionConfigResultDeserializer;
 impl GetStreamingDistributionConfigResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetStreamingDistributionConfigResult, XmlParseError> {
@@ -4126,6 +5512,36 @@ pub struct GetStreamingDistributionRequest {
     pub id: String,
 }
 
+impl GetStreamingDistributionRequest {
+    /// Returns a builder for `GetStreamingDistributionRequest`, checking its required members when built.
+    pub fn builder() -> GetStreamingDistributionRequestBuilder {
+        GetStreamingDistributionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetStreamingDistributionRequest`](struct.GetStreamingDistributionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetStreamingDistributionRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetStreamingDistributionRequestBuilder {
+    /// Sets the [`id`](struct.GetStreamingDistributionRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetStreamingDistributionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetStreamingDistributionRequest, ::rusoto_core::BuildError> {
+        Ok(GetStreamingDistributionRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetStreamingDistributionResult {
@@ -4135,10 +5551,10 @@ pub struct GetStreamingDistributionResult {
     pub streaming_distribution: Option<StreamingDistribution>,
 }
 
-struct GetStreamingDistributionResultDeserializer;
+pub(crate) struct GetStreamingDistributionResultDeserializer;
 impl GetStreamingDistributionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetStreamingDistributionResult, XmlParseError> {
@@ -4151,10 +5567,10 @@ impl GetStreamingDistributionResultDeserializer {
         })
     }
 }
-struct HeaderListDeserializer;
+pub(crate) struct HeaderListDeserializer;
 impl HeaderListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -4198,10 +5614,10 @@ pub struct Headers {
     pub quantity: i64,
 }
 
-struct HeadersDeserializer;
+pub(crate) struct HeadersDeserializer;
 impl HeadersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Headers, XmlParseError> {
@@ -4247,12 +5663,15 @@ impl HeadersSerializer {
     }
 }
 
-struct HttpVersionDeserializer;
+pub(crate) struct HttpVersionDeserializer;
 impl HttpVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<HttpVersion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4265,7 +5684,7 @@ impl HttpVersionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &HttpVersion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -4279,10 +5698,72 @@ impl HttpVersionSerializer {
     }
 }
 
-struct IntegerDeserializer;
+/// <p>(Optional) Specify the maximum HTTP version that you want viewers to use to communicate with CloudFront. The default value for new web distributions is http2. Viewers that don't support HTTP/2 automatically use an earlier HTTP version.</p> <p>For viewers and CloudFront to use HTTP/2, viewers must support TLS 1.2 or later, and must support Server Name Identification (SNI).</p> <p>In general, configuring CloudFront to communicate with viewers using HTTP/2 reduces latency. You can improve performance by optimizing for HTTP/2. For more information, do an Internet search for "http/2 optimization." </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HttpVersion {
+    /// <p><code>http1.1</code></p>
+    Http11,
+    /// <p><code>http2</code></p>
+    Http2,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HttpVersion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HttpVersion::Http11 => "http1.1",
+            HttpVersion::Http2 => "http2",
+            HttpVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HttpVersion {
+    fn from(value: &'a str) -> HttpVersion {
+        match value {
+            "http1.1" => HttpVersion::Http11,
+            "http2" => HttpVersion::Http2,
+            _ => HttpVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HttpVersion {
+    fn from(value: String) -> HttpVersion {
+        HttpVersion::from(value.as_str())
+    }
+}
+
+impl From<HttpVersion> for String {
+    fn from(value: HttpVersion) -> String {
+        match value {
+            HttpVersion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HttpVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HttpVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct IntegerDeserializer;
 impl IntegerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4315,7 +5796,7 @@ impl IntegerSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Invalidation {
     /// <p>The date and time the invalidation request was first made. </p>
-    pub create_time: String,
+    pub create_time: IsoTimestamp,
     /// <p>The identifier for the invalidation request. For example: <code>IDFDVBD632BHDS5</code>.</p>
     pub id: String,
     /// <p>The current invalidation information for the batch request. </p>
@@ -4324,10 +5805,10 @@ pub struct Invalidation {
     pub status: String,
 }
 
-struct InvalidationDeserializer;
+pub(crate) struct InvalidationDeserializer;
 impl InvalidationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Invalidation, XmlParseError> {
@@ -4361,10 +5842,10 @@ pub struct InvalidationBatch {
     pub paths: Paths,
 }
 
-struct InvalidationBatchDeserializer;
+pub(crate) struct InvalidationBatchDeserializer;
 impl InvalidationBatchDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<InvalidationBatch, XmlParseError> {
@@ -4424,10 +5905,10 @@ pub struct InvalidationList {
     pub quantity: i64,
 }
 
-struct InvalidationListDeserializer;
+pub(crate) struct InvalidationListDeserializer;
 impl InvalidationListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<InvalidationList, XmlParseError> {
@@ -4463,17 +5944,17 @@ impl InvalidationListDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct InvalidationSummary {
     /// <p>The time that an invalidation request was created.</p>
-    pub create_time: String,
+    pub create_time: IsoTimestamp,
     /// <p>The unique ID for an invalidation request.</p>
     pub id: String,
     /// <p>The status of an invalidation request.</p>
     pub status: String,
 }
 
-struct InvalidationSummaryDeserializer;
+pub(crate) struct InvalidationSummaryDeserializer;
 impl InvalidationSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<InvalidationSummary, XmlParseError> {
@@ -4494,10 +5975,10 @@ impl InvalidationSummaryDeserializer {
         })
     }
 }
-struct InvalidationSummaryListDeserializer;
+pub(crate) struct InvalidationSummaryListDeserializer;
 impl InvalidationSummaryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<InvalidationSummary>, XmlParseError> {
@@ -4514,12 +5995,15 @@ impl InvalidationSummaryListDeserializer {
         })
     }
 }
-struct ItemSelectionDeserializer;
+pub(crate) struct ItemSelectionDeserializer;
 impl ItemSelectionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ItemSelection, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4532,7 +6016,7 @@ impl ItemSelectionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ItemSelection,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -4546,10 +6030,73 @@ impl ItemSelectionSerializer {
     }
 }
 
-struct KeyPairIdListDeserializer;
+/// <p>Specifies which cookies to forward to the origin for this cache behavior: all, none, or the list of cookies specified in the <code>WhitelistedNames</code> complex type.</p> <p>Amazon S3 doesn't process cookies. When the cache behavior is forwarding requests to an Amazon S3 origin, specify none for the <code>Forward</code> element. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ItemSelection {
+    /// <p><code>none</code></p>
+    None,
+    /// <p><code>whitelist</code></p>
+    Whitelist,
+    /// <p><code>all</code></p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ItemSelection {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ItemSelection::None => "none",
+            ItemSelection::Whitelist => "whitelist",
+            ItemSelection::All => "all",
+            ItemSelection::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ItemSelection {
+    fn from(value: &'a str) -> ItemSelection {
+        match value {
+            "none" => ItemSelection::None,
+            "whitelist" => ItemSelection::Whitelist,
+            "all" => ItemSelection::All,
+            _ => ItemSelection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ItemSelection {
+    fn from(value: String) -> ItemSelection {
+        ItemSelection::from(value.as_str())
+    }
+}
+
+impl From<ItemSelection> for String {
+    fn from(value: ItemSelection) -> String {
+        match value {
+            ItemSelection::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ItemSelection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ItemSelection {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct KeyPairIdListDeserializer;
 impl KeyPairIdListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -4572,10 +6119,10 @@ pub struct KeyPairIds {
     pub quantity: i64,
 }
 
-struct KeyPairIdsDeserializer;
+pub(crate) struct KeyPairIdsDeserializer;
 impl KeyPairIdsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<KeyPairIds, XmlParseError> {
@@ -4595,10 +6142,13 @@ impl KeyPairIdsDeserializer {
         })
     }
 }
-struct LambdaFunctionARNDeserializer;
+pub(crate) struct LambdaFunctionARNDeserializer;
 impl LambdaFunctionARNDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4628,26 +6178,31 @@ impl LambdaFunctionARNSerializer {
 }
 
 /// <p>A complex type that contains a Lambda function association.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LambdaFunctionAssociation {
     /// <p><p>Specifies the event type that triggers a Lambda function invocation. You can specify the following values:</p> <ul> <li> <p> <code>viewer-request</code>: The function executes when CloudFront receives a request from a viewer and before it checks to see whether the requested object is in the edge cache. </p> </li> <li> <p> <code>origin-request</code>: The function executes only when CloudFront forwards a request to your origin. When the requested object is in the edge cache, the function doesn&#39;t execute.</p> </li> <li> <p> <code>origin-response</code>: The function executes after CloudFront receives a response from the origin and before it caches the object in the response. When the requested object is in the edge cache, the function doesn&#39;t execute.</p> </li> <li> <p> <code>viewer-response</code>: The function executes before CloudFront returns the requested object to the viewer. The function executes regardless of whether the object was already in the edge cache.</p> <p>If the origin returns an HTTP status code other than HTTP 200 (OK), the function doesn&#39;t execute.</p> </li> </ul></p>
-    pub event_type: String,
+    pub event_type: EventType,
     /// <p>A flag that allows a Lambda function to have read access to the body content. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/lambda-include-body-access.html">Accessing the Request Body by Choosing the Include Body Option</a> in the Amazon CloudFront Developer Guide.</p>
     pub include_body: Option<bool>,
     /// <p>The ARN of the Lambda function. You must specify the ARN of a function version; you can't specify a Lambda alias or $LATEST.</p>
     pub lambda_function_arn: String,
 }
 
-struct LambdaFunctionAssociationDeserializer;
+pub(crate) struct LambdaFunctionAssociationDeserializer;
 impl LambdaFunctionAssociationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LambdaFunctionAssociation, XmlParseError> {
-        deserialize_elements::<_, LambdaFunctionAssociation, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            LambdaFunctionAssociation {
+                event_type: EventType::Unknown(String::new()),
+                include_body: Default::default(),
+                lambda_function_arn: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "EventType" => {
@@ -4705,10 +6260,10 @@ impl LambdaFunctionAssociationSerializer {
     }
 }
 
-struct LambdaFunctionAssociationListDeserializer;
+pub(crate) struct LambdaFunctionAssociationListDeserializer;
 impl LambdaFunctionAssociationListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LambdaFunctionAssociation>, XmlParseError> {
@@ -4759,10 +6314,10 @@ pub struct LambdaFunctionAssociations {
     pub quantity: i64,
 }
 
-struct LambdaFunctionAssociationsDeserializer;
+pub(crate) struct LambdaFunctionAssociationsDeserializer;
 impl LambdaFunctionAssociationsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LambdaFunctionAssociations, XmlParseError> {
@@ -4821,17 +6376,56 @@ pub struct ListCloudFrontOriginAccessIdentitiesRequest {
     pub max_items: Option<String>,
 }
 
-/// <p>The returned result of the corresponding request. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct ListCloudFrontOriginAccessIdentitiesResult {
+impl ListCloudFrontOriginAccessIdentitiesRequest {
+    /// Returns a builder for `ListCloudFrontOriginAccessIdentitiesRequest`, checking its required members when built.
+    pub fn builder() -> ListCloudFrontOriginAccessIdentitiesRequestBuilder {
+        ListCloudFrontOriginAccessIdentitiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCloudFrontOriginAccessIdentitiesRequest`](struct.ListCloudFrontOriginAccessIdentitiesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCloudFrontOriginAccessIdentitiesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<String>,
+}
+
+impl ListCloudFrontOriginAccessIdentitiesRequestBuilder {
+    /// Sets the [`marker`](struct.ListCloudFrontOriginAccessIdentitiesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListCloudFrontOriginAccessIdentitiesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCloudFrontOriginAccessIdentitiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListCloudFrontOriginAccessIdentitiesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListCloudFrontOriginAccessIdentitiesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
+/// <p>The returned result of the corresponding request. </p>
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ListCloudFrontOriginAccessIdentitiesResult {
     /// <p>The <code>CloudFrontOriginAccessIdentityList</code> type. </p>
     pub cloud_front_origin_access_identity_list: Option<CloudFrontOriginAccessIdentityList>,
 }
 
-struct ListCloudFrontOriginAccessIdentitiesResultDeserializer;
+pub(crate) struct ListCloudFrontOriginAccessIdentitiesResultDeserializer;
 impl ListCloudFrontOriginAccessIdentitiesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListCloudFrontOriginAccessIdentitiesResult, XmlParseError> {
@@ -4857,6 +6451,54 @@ pub struct ListDistributionsByWebACLIdRequest {
     pub web_acl_id: String,
 }
 
+impl ListDistributionsByWebACLIdRequest {
+    /// Returns a builder for `ListDistributionsByWebACLIdRequest`, checking its required members when built.
+    pub fn builder() -> ListDistributionsByWebACLIdRequestBuilder {
+        ListDistributionsByWebACLIdRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDistributionsByWebACLIdRequest`](struct.ListDistributionsByWebACLIdRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDistributionsByWebACLIdRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<String>,
+    web_acl_id: Option<String>,
+}
+
+impl ListDistributionsByWebACLIdRequestBuilder {
+    /// Sets the [`marker`](struct.ListDistributionsByWebACLIdRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListDistributionsByWebACLIdRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`web_acl_id`](struct.ListDistributionsByWebACLIdRequest.html#structfield.web_acl_id) member.
+    pub fn web_acl_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.web_acl_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDistributionsByWebACLIdRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDistributionsByWebACLIdRequest, ::rusoto_core::BuildError> {
+        Ok(ListDistributionsByWebACLIdRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            web_acl_id: self
+                .web_acl_id
+                .ok_or(::rusoto_core::BuildError::missing("web_acl_id"))?,
+        })
+    }
+}
+
 /// <p>The response to a request to list the distributions that are associated with a specified AWS WAF web ACL. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListDistributionsByWebACLIdResult {
@@ -4864,10 +6506,10 @@ pub struct ListDistributionsByWebACLIdResult {
     pub distribution_list: Option<DistributionList>,
 }
 
-struct ListDistributionsByWebACLIdResultDeserializer;
+pub(crate) struct ListDistributionsByWebACLIdResultDeserializer;
 impl ListDistributionsByWebACLIdResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListDistributionsByWebACLIdResult, XmlParseError> {
@@ -4889,6 +6531,44 @@ pub struct ListDistributionsRequest {
     pub max_items: Option<String>,
 }
 
+impl ListDistributionsRequest {
+    /// Returns a builder for `ListDistributionsRequest`, checking its required members when built.
+    pub fn builder() -> ListDistributionsRequestBuilder {
+        ListDistributionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDistributionsRequest`](struct.ListDistributionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDistributionsRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<String>,
+}
+
+impl ListDistributionsRequestBuilder {
+    /// Sets the [`marker`](struct.ListDistributionsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListDistributionsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDistributionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDistributionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDistributionsRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListDistributionsResult {
@@ -4896,10 +6576,10 @@ pub struct ListDistributionsResult {
     pub distribution_list: Option<DistributionList>,
 }
 
-struct ListDistributionsResultDeserializer;
+pub(crate) struct ListDistributionsResultDeserializer;
 impl ListDistributionsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListDistributionsResult, XmlParseError> {
@@ -4920,16 +6600,55 @@ pub struct ListFieldLevelEncryptionConfigsRequest {
     pub max_items: Option<String>,
 }
 
+impl ListFieldLevelEncryptionConfigsRequest {
+    /// Returns a builder for `ListFieldLevelEncryptionConfigsRequest`, checking its required members when built.
+    pub fn builder() -> ListFieldLevelEncryptionConfigsRequestBuilder {
+        ListFieldLevelEncryptionConfigsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFieldLevelEncryptionConfigsRequest`](struct.ListFieldLevelEncryptionConfigsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFieldLevelEncryptionConfigsRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<String>,
+}
+
+impl ListFieldLevelEncryptionConfigsRequestBuilder {
+    /// Sets the [`marker`](struct.ListFieldLevelEncryptionConfigsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListFieldLevelEncryptionConfigsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFieldLevelEncryptionConfigsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListFieldLevelEncryptionConfigsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListFieldLevelEncryptionConfigsRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListFieldLevelEncryptionConfigsResult {
     /// <p>Returns a list of all field-level encryption configurations that have been created in CloudFront for this account.</p>
     pub field_level_encryption_list: Option<FieldLevelEncryptionList>,
 }
 
-struct ListFieldLevelEncryptionConfigsResultDeserializer;
+pub(crate) struct ListFieldLevelEncryptionConfigsResultDeserializer;
 impl ListFieldLevelEncryptionConfigsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListFieldLevelEncryptionConfigsResult, XmlParseError> {
@@ -4950,16 +6669,55 @@ pub struct ListFieldLevelEncryptionProfilesRequest {
     pub max_items: Option<String>,
 }
 
+impl ListFieldLevelEncryptionProfilesRequest {
+    /// Returns a builder for `ListFieldLevelEncryptionProfilesRequest`, checking its required members when built.
+    pub fn builder() -> ListFieldLevelEncryptionProfilesRequestBuilder {
+        ListFieldLevelEncryptionProfilesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFieldLevelEncryptionProfilesRequest`](struct.ListFieldLevelEncryptionProfilesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFieldLevelEncryptionProfilesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<String>,
+}
+
+impl ListFieldLevelEncryptionProfilesRequestBuilder {
+    /// Sets the [`marker`](struct.ListFieldLevelEncryptionProfilesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListFieldLevelEncryptionProfilesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFieldLevelEncryptionProfilesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListFieldLevelEncryptionProfilesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListFieldLevelEncryptionProfilesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListFieldLevelEncryptionProfilesResult {
     /// <p>Returns a list of the field-level encryption profiles that have been created in CloudFront for this account.</p>
     pub field_level_encryption_profile_list: Option<FieldLevelEncryptionProfileList>,
 }
 
-struct ListFieldLevelEncryptionProfilesResultDeserializer;
+pub(crate) struct ListFieldLevelEncryptionProfilesResultDeserializer;
 impl ListFieldLevelEncryptionProfilesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListFieldLevelEncryptionProfilesResult, XmlParseError> {
@@ -4985,6 +6743,54 @@ pub struct ListInvalidationsRequest {
     pub max_items: Option<String>,
 }
 
+impl ListInvalidationsRequest {
+    /// Returns a builder for `ListInvalidationsRequest`, checking its required members when built.
+    pub fn builder() -> ListInvalidationsRequestBuilder {
+        ListInvalidationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListInvalidationsRequest`](struct.ListInvalidationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListInvalidationsRequestBuilder {
+    distribution_id: Option<String>,
+    marker: Option<String>,
+    max_items: Option<String>,
+}
+
+impl ListInvalidationsRequestBuilder {
+    /// Sets the [`distribution_id`](struct.ListInvalidationsRequest.html#structfield.distribution_id) member.
+    pub fn distribution_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.distribution_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListInvalidationsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListInvalidationsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListInvalidationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListInvalidationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListInvalidationsRequest {
+            distribution_id: self
+                .distribution_id
+                .ok_or(::rusoto_core::BuildError::missing("distribution_id"))?,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListInvalidationsResult {
@@ -4992,10 +6798,10 @@ pub struct ListInvalidationsResult {
     pub invalidation_list: Option<InvalidationList>,
 }
 
-struct ListInvalidationsResultDeserializer;
+pub(crate) struct ListInvalidationsResultDeserializer;
 impl ListInvalidationsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListInvalidationsResult, XmlParseError> {
@@ -5016,16 +6822,52 @@ pub struct ListPublicKeysRequest {
     pub max_items: Option<String>,
 }
 
+impl ListPublicKeysRequest {
+    /// Returns a builder for `ListPublicKeysRequest`, checking its required members when built.
+    pub fn builder() -> ListPublicKeysRequestBuilder {
+        ListPublicKeysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPublicKeysRequest`](struct.ListPublicKeysRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPublicKeysRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<String>,
+}
+
+impl ListPublicKeysRequestBuilder {
+    /// Sets the [`marker`](struct.ListPublicKeysRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListPublicKeysRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPublicKeysRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPublicKeysRequest, ::rusoto_core::BuildError> {
+        Ok(ListPublicKeysRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListPublicKeysResult {
     /// <p>Returns a list of all public keys that have been added to CloudFront for this account.</p>
     pub public_key_list: Option<PublicKeyList>,
 }
 
-struct ListPublicKeysResultDeserializer;
+pub(crate) struct ListPublicKeysResultDeserializer;
 impl ListPublicKeysResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListPublicKeysResult, XmlParseError> {
@@ -5047,6 +6889,44 @@ pub struct ListStreamingDistributionsRequest {
     pub max_items: Option<String>,
 }
 
+impl ListStreamingDistributionsRequest {
+    /// Returns a builder for `ListStreamingDistributionsRequest`, checking its required members when built.
+    pub fn builder() -> ListStreamingDistributionsRequestBuilder {
+        ListStreamingDistributionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListStreamingDistributionsRequest`](struct.ListStreamingDistributionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListStreamingDistributionsRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<String>,
+}
+
+impl ListStreamingDistributionsRequestBuilder {
+    /// Sets the [`marker`](struct.ListStreamingDistributionsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListStreamingDistributionsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStreamingDistributionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListStreamingDistributionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListStreamingDistributionsRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListStreamingDistributionsResult {
@@ -5054,10 +6934,10 @@ pub struct ListStreamingDistributionsResult {
     pub streaming_distribution_list: Option<StreamingDistributionList>,
 }
 
-struct ListStreamingDistributionsResultDeserializer;
+pub(crate) struct ListStreamingDistributionsResultDeserializer;
 impl ListStreamingDistributionsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListStreamingDistributionsResult, XmlParseError> {
@@ -5077,6 +6957,38 @@ pub struct ListTagsForResourceRequest {
     pub resource: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource`](struct.ListTagsForResourceRequest.html#structfield.resource) member.
+    pub fn resource<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource: self
+                .resource
+                .ok_or(::rusoto_core::BuildError::missing("resource"))?,
+        })
+    }
+}
+
 /// <p> The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListTagsForResourceResult {
@@ -5084,10 +6996,10 @@ pub struct ListTagsForResourceResult {
     pub tags: Tags,
 }
 
-struct ListTagsForResourceResultDeserializer;
+pub(crate) struct ListTagsForResourceResultDeserializer;
 impl ListTagsForResourceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListTagsForResourceResult, XmlParseError> {
@@ -5097,10 +7009,10 @@ impl ListTagsForResourceResultDeserializer {
         })
     }
 }
-struct LocationListDeserializer;
+pub(crate) struct LocationListDeserializer;
 impl LocationListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -5148,10 +7060,10 @@ pub struct LoggingConfig {
     pub prefix: String,
 }
 
-struct LoggingConfigDeserializer;
+pub(crate) struct LoggingConfigDeserializer;
 impl LoggingConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoggingConfig, XmlParseError> {
@@ -5217,10 +7129,13 @@ impl LoggingConfigSerializer {
     }
 }
 
-struct LongDeserializer;
+pub(crate) struct LongDeserializer;
 impl LongDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5249,12 +7164,15 @@ impl LongSerializer {
     }
 }
 
-struct MethodDeserializer;
+pub(crate) struct MethodDeserializer;
 impl MethodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Method, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5267,7 +7185,7 @@ impl MethodSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &Method,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5281,13 +7199,92 @@ impl MethodSerializer {
     }
 }
 
-struct MethodsListDeserializer;
+/// <p>A complex type that contains the HTTP methods that you want CloudFront to process and forward to your origin.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Method {
+    /// <p><code>GET</code></p>
+    Get,
+    /// <p><code>HEAD</code></p>
+    Head,
+    /// <p><code>POST</code></p>
+    Post,
+    /// <p><code>PUT</code></p>
+    Put,
+    /// <p><code>PATCH</code></p>
+    Patch,
+    /// <p><code>OPTIONS</code></p>
+    Options,
+    /// <p><code>DELETE</code></p>
+    Delete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Method {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Method::Get => "GET",
+            Method::Head => "HEAD",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Patch => "PATCH",
+            Method::Options => "OPTIONS",
+            Method::Delete => "DELETE",
+            Method::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Method {
+    fn from(value: &'a str) -> Method {
+        match value {
+            "GET" => Method::Get,
+            "HEAD" => Method::Head,
+            "POST" => Method::Post,
+            "PUT" => Method::Put,
+            "PATCH" => Method::Patch,
+            "OPTIONS" => Method::Options,
+            "DELETE" => Method::Delete,
+            _ => Method::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Method {
+    fn from(value: String) -> Method {
+        Method::from(value.as_str())
+    }
+}
+
+impl From<Method> for String {
+    fn from(value: Method) -> String {
+        match value {
+            Method::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Method {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct MethodsListDeserializer;
 impl MethodsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<Method>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "Method" {
                 obj.push(MethodDeserializer::deserialize("Method", stack)?);
@@ -5305,7 +7302,7 @@ impl MethodsListSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &Vec<String>,
+        obj: &Vec<Method>,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5319,12 +7316,15 @@ impl MethodsListSerializer {
     }
 }
 
-struct MinimumProtocolVersionDeserializer;
+pub(crate) struct MinimumProtocolVersionDeserializer;
 impl MinimumProtocolVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<MinimumProtocolVersion, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5337,7 +7337,7 @@ impl MinimumProtocolVersionSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &MinimumProtocolVersion,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5351,6 +7351,77 @@ impl MinimumProtocolVersionSerializer {
     }
 }
 
+/// <p>Specify the security policy that you want CloudFront to use for HTTPS connections. A security policy determines two settings:</p> <ul> <li> <p>The minimum SSL/TLS protocol that CloudFront uses to communicate with viewers</p> </li> <li> <p>The cipher that CloudFront uses to encrypt the content that it returns to viewers</p> </li> </ul> <note> <p>On the CloudFront console, this setting is called <b>Security policy</b>.</p> </note> <p>We recommend that you specify <code>TLSv1.1_2016</code> unless your users are using browsers or devices that do not support TLSv1.1 or later.</p> <p>When both of the following are true, you must specify <code>TLSv1</code> or later for the security policy: </p> <ul> <li> <p>You're using a custom certificate: you specified a value for <code>ACMCertificateArn</code> or for <code>IAMCertificateId</code> </p> </li> <li> <p>You're using SNI: you specified <code>sni-only</code> for <code>SSLSupportMethod</code> </p> </li> </ul> <p>If you specify <code>true</code> for <code>CloudFrontDefaultCertificate</code>, CloudFront automatically sets the security policy to <code>TLSv1</code> regardless of the value that you specify for <code>MinimumProtocolVersion</code>.</p> <p>For information about the relationship between the security policy that you choose and the protocols and ciphers that CloudFront uses to communicate with viewers, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/secure-connections-supported-viewer-protocols-ciphers.html#secure-connections-supported-ciphers"> Supported SSL/TLS Protocols and Ciphers for Communication Between Viewers and CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MinimumProtocolVersion {
+    /// <p><code>SSLv3</code></p>
+    SSLv3,
+    /// <p><code>TLSv1</code></p>
+    TLSv1,
+    /// <p><code>TLSv1_2016</code></p>
+    TLSv12016,
+    /// <p><code>TLSv1.1_2016</code></p>
+    TLSv112016,
+    /// <p><code>TLSv1.2_2018</code></p>
+    TLSv122018,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MinimumProtocolVersion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MinimumProtocolVersion::SSLv3 => "SSLv3",
+            MinimumProtocolVersion::TLSv1 => "TLSv1",
+            MinimumProtocolVersion::TLSv12016 => "TLSv1_2016",
+            MinimumProtocolVersion::TLSv112016 => "TLSv1.1_2016",
+            MinimumProtocolVersion::TLSv122018 => "TLSv1.2_2018",
+            MinimumProtocolVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MinimumProtocolVersion {
+    fn from(value: &'a str) -> MinimumProtocolVersion {
+        match value {
+            "SSLv3" => MinimumProtocolVersion::SSLv3,
+            "TLSv1" => MinimumProtocolVersion::TLSv1,
+            "TLSv1_2016" => MinimumProtocolVersion::TLSv12016,
+            "TLSv1.1_2016" => MinimumProtocolVersion::TLSv112016,
+            "TLSv1.2_2018" => MinimumProtocolVersion::TLSv122018,
+            _ => MinimumProtocolVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MinimumProtocolVersion {
+    fn from(value: String) -> MinimumProtocolVersion {
+        MinimumProtocolVersion::from(value.as_str())
+    }
+}
+
+impl From<MinimumProtocolVersion> for String {
+    fn from(value: MinimumProtocolVersion) -> String {
+        match value {
+            MinimumProtocolVersion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MinimumProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MinimumProtocolVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A complex type that describes the Amazon S3 bucket, HTTP server (for example, a web server), Amazon MediaStore, or other server from which CloudFront gets your files. This can also be an origin group, if you've created an origin group. You must specify at least one origin or origin group.</p> <p>For the current limit on the number of origins or origin groups that you can specify for a distribution, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_cloudfront">Amazon CloudFront Limits</a> in the <i>AWS General Reference</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Origin {
@@ -5368,10 +7439,13 @@ pub struct Origin {
     pub s3_origin_config: Option<S3OriginConfig>,
 }
 
-struct OriginDeserializer;
+pub(crate) struct OriginDeserializer;
 impl OriginDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Origin, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Origin, XmlParseError> {
         deserialize_elements::<_, Origin, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "CustomHeaders" => {
@@ -5462,10 +7536,10 @@ pub struct OriginCustomHeader {
     pub header_value: String,
 }
 
-struct OriginCustomHeaderDeserializer;
+pub(crate) struct OriginCustomHeaderDeserializer;
 impl OriginCustomHeaderDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OriginCustomHeader, XmlParseError> {
@@ -5512,10 +7586,10 @@ impl OriginCustomHeaderSerializer {
     }
 }
 
-struct OriginCustomHeadersListDeserializer;
+pub(crate) struct OriginCustomHeadersListDeserializer;
 impl OriginCustomHeadersListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OriginCustomHeader>, XmlParseError> {
@@ -5564,10 +7638,10 @@ pub struct OriginGroup {
     pub members: OriginGroupMembers,
 }
 
-struct OriginGroupDeserializer;
+pub(crate) struct OriginGroupDeserializer;
 impl OriginGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OriginGroup, XmlParseError> {
@@ -5627,10 +7701,10 @@ pub struct OriginGroupFailoverCriteria {
     pub status_codes: StatusCodes,
 }
 
-struct OriginGroupFailoverCriteriaDeserializer;
+pub(crate) struct OriginGroupFailoverCriteriaDeserializer;
 impl OriginGroupFailoverCriteriaDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OriginGroupFailoverCriteria, XmlParseError> {
@@ -5668,10 +7742,10 @@ impl OriginGroupFailoverCriteriaSerializer {
     }
 }
 
-struct OriginGroupListDeserializer;
+pub(crate) struct OriginGroupListDeserializer;
 impl OriginGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OriginGroup>, XmlParseError> {
@@ -5713,10 +7787,10 @@ pub struct OriginGroupMember {
     pub origin_id: String,
 }
 
-struct OriginGroupMemberDeserializer;
+pub(crate) struct OriginGroupMemberDeserializer;
 impl OriginGroupMemberDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OriginGroupMember, XmlParseError> {
@@ -5754,10 +7828,10 @@ impl OriginGroupMemberSerializer {
     }
 }
 
-struct OriginGroupMemberListDeserializer;
+pub(crate) struct OriginGroupMemberListDeserializer;
 impl OriginGroupMemberListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OriginGroupMember>, XmlParseError> {
@@ -5804,10 +7878,10 @@ pub struct OriginGroupMembers {
     pub quantity: i64,
 }
 
-struct OriginGroupMembersDeserializer;
+pub(crate) struct OriginGroupMembersDeserializer;
 impl OriginGroupMembersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OriginGroupMembers, XmlParseError> {
@@ -5861,10 +7935,10 @@ pub struct OriginGroups {
     pub quantity: i64,
 }
 
-struct OriginGroupsDeserializer;
+pub(crate) struct OriginGroupsDeserializer;
 impl OriginGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OriginGroups, XmlParseError> {
@@ -5910,10 +7984,10 @@ impl OriginGroupsSerializer {
     }
 }
 
-struct OriginListDeserializer;
+pub(crate) struct OriginListDeserializer;
 impl OriginListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Origin>, XmlParseError> {
@@ -5948,12 +8022,15 @@ impl OriginListSerializer {
     }
 }
 
-struct OriginProtocolPolicyDeserializer;
+pub(crate) struct OriginProtocolPolicyDeserializer;
 impl OriginProtocolPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<OriginProtocolPolicy, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5966,7 +8043,7 @@ impl OriginProtocolPolicySerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &OriginProtocolPolicy,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -5980,19 +8057,82 @@ impl OriginProtocolPolicySerializer {
     }
 }
 
+/// <p>The origin protocol policy to apply to your origin.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OriginProtocolPolicy {
+    /// <p><code>http-only</code></p>
+    HttpOnly,
+    /// <p><code>match-viewer</code></p>
+    MatchViewer,
+    /// <p><code>https-only</code></p>
+    HttpsOnly,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OriginProtocolPolicy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OriginProtocolPolicy::HttpOnly => "http-only",
+            OriginProtocolPolicy::MatchViewer => "match-viewer",
+            OriginProtocolPolicy::HttpsOnly => "https-only",
+            OriginProtocolPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OriginProtocolPolicy {
+    fn from(value: &'a str) -> OriginProtocolPolicy {
+        match value {
+            "http-only" => OriginProtocolPolicy::HttpOnly,
+            "match-viewer" => OriginProtocolPolicy::MatchViewer,
+            "https-only" => OriginProtocolPolicy::HttpsOnly,
+            _ => OriginProtocolPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OriginProtocolPolicy {
+    fn from(value: String) -> OriginProtocolPolicy {
+        OriginProtocolPolicy::from(value.as_str())
+    }
+}
+
+impl From<OriginProtocolPolicy> for String {
+    fn from(value: OriginProtocolPolicy) -> String {
+        match value {
+            OriginProtocolPolicy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OriginProtocolPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OriginProtocolPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A complex type that contains information about the SSL/TLS protocols that CloudFront can use when establishing an HTTPS connection with your origin. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct OriginSslProtocols {
     /// <p>A list that contains allowed SSL/TLS protocols for this distribution.</p>
-    pub items: Vec<String>,
+    pub items: Vec<SslProtocol>,
     /// <p>The number of SSL/TLS protocols that you want to allow CloudFront to use when establishing an HTTPS connection with this origin. </p>
     pub quantity: i64,
 }
 
-struct OriginSslProtocolsDeserializer;
+pub(crate) struct OriginSslProtocolsDeserializer;
 impl OriginSslProtocolsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OriginSslProtocols, XmlParseError> {
@@ -6044,10 +8184,10 @@ pub struct Origins {
     pub quantity: i64,
 }
 
-struct OriginsDeserializer;
+pub(crate) struct OriginsDeserializer;
 impl OriginsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Origins, XmlParseError> {
@@ -6090,10 +8230,10 @@ impl OriginsSerializer {
     }
 }
 
-struct PathListDeserializer;
+pub(crate) struct PathListDeserializer;
 impl PathListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6137,10 +8277,13 @@ pub struct Paths {
     pub quantity: i64,
 }
 
-struct PathsDeserializer;
+pub(crate) struct PathsDeserializer;
 impl PathsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Paths, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Paths, XmlParseError> {
         deserialize_elements::<_, Paths, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Items" => {
@@ -6183,12 +8326,15 @@ impl PathsSerializer {
     }
 }
 
-struct PriceClassDeserializer;
+pub(crate) struct PriceClassDeserializer;
 impl PriceClassDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PriceClass, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6201,7 +8347,7 @@ impl PriceClassSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &PriceClass,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6215,21 +8361,84 @@ impl PriceClassSerializer {
     }
 }
 
+/// <p>The price class that corresponds with the maximum price that you want to pay for CloudFront service. If you specify <code>PriceClass_All</code>, CloudFront responds to requests for your objects from all CloudFront edge locations.</p> <p>If you specify a price class other than <code>PriceClass_All</code>, CloudFront serves your objects from the CloudFront edge location that has the lowest latency among the edge locations in your price class. Viewers who are in or near regions that are excluded from your specified price class may encounter slower performance.</p> <p>For more information about price classes, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PriceClass.html">Choosing the Price Class for a CloudFront Distribution</a> in the <i>Amazon CloudFront Developer Guide</i>. For information about CloudFront pricing, including how price classes (such as Price Class 100) map to CloudFront regions, see <a href="https://aws.amazon.com/cloudfront/pricing/">Amazon CloudFront Pricing</a>. For price class information, scroll down to see the table at the bottom of the page.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PriceClass {
+    /// <p><code>PriceClass_100</code></p>
+    PriceClass100,
+    /// <p><code>PriceClass_200</code></p>
+    PriceClass200,
+    /// <p><code>PriceClass_All</code></p>
+    PriceClassAll,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PriceClass {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PriceClass::PriceClass100 => "PriceClass_100",
+            PriceClass::PriceClass200 => "PriceClass_200",
+            PriceClass::PriceClassAll => "PriceClass_All",
+            PriceClass::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PriceClass {
+    fn from(value: &'a str) -> PriceClass {
+        match value {
+            "PriceClass_100" => PriceClass::PriceClass100,
+            "PriceClass_200" => PriceClass::PriceClass200,
+            "PriceClass_All" => PriceClass::PriceClassAll,
+            _ => PriceClass::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PriceClass {
+    fn from(value: String) -> PriceClass {
+        PriceClass::from(value.as_str())
+    }
+}
+
+impl From<PriceClass> for String {
+    fn from(value: PriceClass) -> String {
+        match value {
+            PriceClass::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PriceClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PriceClass {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A complex data type of public keys you add to CloudFront to use with features like field-level encryption.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PublicKey {
     /// <p>A time you added a public key to CloudFront.</p>
-    pub created_time: String,
+    pub created_time: IsoTimestamp,
     /// <p>A unique ID assigned to a public key you've added to CloudFront.</p>
     pub id: String,
     /// <p>A complex data type for a public key you add to CloudFront to use with features like field-level encryption.</p>
     pub public_key_config: PublicKeyConfig,
 }
 
-struct PublicKeyDeserializer;
+pub(crate) struct PublicKeyDeserializer;
 impl PublicKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PublicKey, XmlParseError> {
@@ -6264,10 +8473,10 @@ pub struct PublicKeyConfig {
     pub name: String,
 }
 
-struct PublicKeyConfigDeserializer;
+pub(crate) struct PublicKeyConfigDeserializer;
 impl PublicKeyConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PublicKeyConfig, XmlParseError> {
@@ -6348,10 +8557,10 @@ pub struct PublicKeyList {
     pub quantity: i64,
 }
 
-struct PublicKeyListDeserializer;
+pub(crate) struct PublicKeyListDeserializer;
 impl PublicKeyListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PublicKeyList, XmlParseError> {
@@ -6383,7 +8592,7 @@ pub struct PublicKeySummary {
     /// <p> Comment for public key information summary. </p>
     pub comment: Option<String>,
     /// <p> Creation time for public key information summary. </p>
-    pub created_time: String,
+    pub created_time: IsoTimestamp,
     /// <p> Encoded key for public key information summary. </p>
     pub encoded_key: String,
     /// <p> ID for public key information summary. </p>
@@ -6392,10 +8601,10 @@ pub struct PublicKeySummary {
     pub name: String,
 }
 
-struct PublicKeySummaryDeserializer;
+pub(crate) struct PublicKeySummaryDeserializer;
 impl PublicKeySummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PublicKeySummary, XmlParseError> {
@@ -6422,10 +8631,10 @@ impl PublicKeySummaryDeserializer {
         })
     }
 }
-struct PublicKeySummaryListDeserializer;
+pub(crate) struct PublicKeySummaryListDeserializer;
 impl PublicKeySummaryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PublicKeySummary>, XmlParseError> {
@@ -6451,10 +8660,10 @@ pub struct QueryArgProfile {
     pub query_arg: String,
 }
 
-struct QueryArgProfileDeserializer;
+pub(crate) struct QueryArgProfileDeserializer;
 impl QueryArgProfileDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<QueryArgProfile, XmlParseError> {
@@ -6510,10 +8719,10 @@ pub struct QueryArgProfileConfig {
     pub query_arg_profiles: Option<QueryArgProfiles>,
 }
 
-struct QueryArgProfileConfigDeserializer;
+pub(crate) struct QueryArgProfileConfigDeserializer;
 impl QueryArgProfileConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<QueryArgProfileConfig, XmlParseError> {
@@ -6566,10 +8775,10 @@ impl QueryArgProfileConfigSerializer {
     }
 }
 
-struct QueryArgProfileListDeserializer;
+pub(crate) struct QueryArgProfileListDeserializer;
 impl QueryArgProfileListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<QueryArgProfile>, XmlParseError> {
@@ -6616,10 +8825,10 @@ pub struct QueryArgProfiles {
     pub quantity: i64,
 }
 
-struct QueryArgProfilesDeserializer;
+pub(crate) struct QueryArgProfilesDeserializer;
 impl QueryArgProfilesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<QueryArgProfiles, XmlParseError> {
@@ -6673,10 +8882,10 @@ pub struct QueryStringCacheKeys {
     pub quantity: i64,
 }
 
-struct QueryStringCacheKeysDeserializer;
+pub(crate) struct QueryStringCacheKeysDeserializer;
 impl QueryStringCacheKeysDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<QueryStringCacheKeys, XmlParseError> {
@@ -6722,10 +8931,10 @@ impl QueryStringCacheKeysSerializer {
     }
 }
 
-struct QueryStringCacheKeysListDeserializer;
+pub(crate) struct QueryStringCacheKeysListDeserializer;
 impl QueryStringCacheKeysListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6781,28 +8990,39 @@ impl ResourceARNSerializer {
 }
 
 /// <p>A complex type that identifies ways in which you want to restrict distribution of your content.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Restrictions {
     pub geo_restriction: GeoRestriction,
 }
 
-struct RestrictionsDeserializer;
+pub(crate) struct RestrictionsDeserializer;
 impl RestrictionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Restrictions, XmlParseError> {
-        deserialize_elements::<_, Restrictions, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "GeoRestriction" => {
-                    obj.geo_restriction =
-                        GeoRestrictionDeserializer::deserialize("GeoRestriction", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            Restrictions {
+                geo_restriction: GeoRestriction {
+                    items: Default::default(),
+                    quantity: Default::default(),
+                    restriction_type: GeoRestrictionType::Unknown(String::new()),
+                },
+            },
+            |name, stack, obj| {
+                match name {
+                    "GeoRestriction" => {
+                        obj.geo_restriction =
+                            GeoRestrictionDeserializer::deserialize("GeoRestriction", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -6832,10 +9052,10 @@ pub struct S3Origin {
     pub origin_access_identity: String,
 }
 
-struct S3OriginDeserializer;
+pub(crate) struct S3OriginDeserializer;
 impl S3OriginDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<S3Origin, XmlParseError> {
@@ -6890,10 +9110,10 @@ pub struct S3OriginConfig {
     pub origin_access_identity: String,
 }
 
-struct S3OriginConfigDeserializer;
+pub(crate) struct S3OriginConfigDeserializer;
 impl S3OriginConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<S3OriginConfig, XmlParseError> {
@@ -6932,12 +9152,15 @@ impl S3OriginConfigSerializer {
     }
 }
 
-struct SSLSupportMethodDeserializer;
+pub(crate) struct SSLSupportMethodDeserializer;
 impl SSLSupportMethodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SSLSupportMethod, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6950,7 +9173,7 @@ impl SSLSupportMethodSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &SSLSupportMethod,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -6964,6 +9187,65 @@ impl SSLSupportMethodSerializer {
     }
 }
 
+/// <p>If you specify a value for <a>ViewerCertificate$ACMCertificateArn</a> or for <a>ViewerCertificate$IAMCertificateId</a>, you must also specify how you want CloudFront to serve HTTPS requests: using a method that works for all clients or one that works for most clients:</p> <ul> <li> <p> <code>vip</code>: CloudFront uses dedicated IP addresses for your content and can respond to HTTPS requests from any viewer. However, you will incur additional monthly charges.</p> </li> <li> <p> <code>sni-only</code>: CloudFront can respond to HTTPS requests from viewers that support Server Name Indication (SNI). All modern browsers support SNI, but some browsers still in use don't support SNI. If some of your users' browsers don't support SNI, we recommend that you do one of the following:</p> <ul> <li> <p>Use the <code>vip</code> option (dedicated IP addresses) instead of <code>sni-only</code>.</p> </li> <li> <p>Use the CloudFront SSL/TLS certificate instead of a custom certificate. This requires that you use the CloudFront domain name of your distribution in the URLs for your objects, for example, <code>https://d111111abcdef8.cloudfront.net/logo.png</code>.</p> </li> <li> <p>If you can control which browser your users use, upgrade the browser to one that supports SNI.</p> </li> <li> <p>Use HTTP instead of HTTPS.</p> </li> </ul> </li> </ul> <p>Don't specify a value for <code>SSLSupportMethod</code> if you specified <code>&lt;CloudFrontDefaultCertificate&gt;true&lt;CloudFrontDefaultCertificate&gt;</code>.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/SecureConnections.html#CNAMEsAndHTTPS.html">Using Alternate Domain Names and HTTPS</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SSLSupportMethod {
+    /// <p><code>sni-only</code> - CloudFront can respond to HTTPS requests from viewers that support Server Name Indication (SNI). All modern browsers support SNI, but some browsers still in use don't support SNI. If some of your users' browsers don't support SNI, we recommend that you do one of the following:</p>
+    SniOnly,
+    /// <p><code>vip</code> - CloudFront uses dedicated IP addresses for your content and can respond to HTTPS requests from any viewer. However, you will incur additional monthly charges.</p>
+    Vip,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SSLSupportMethod {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SSLSupportMethod::SniOnly => "sni-only",
+            SSLSupportMethod::Vip => "vip",
+            SSLSupportMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SSLSupportMethod {
+    fn from(value: &'a str) -> SSLSupportMethod {
+        match value {
+            "sni-only" => SSLSupportMethod::SniOnly,
+            "vip" => SSLSupportMethod::Vip,
+            _ => SSLSupportMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SSLSupportMethod {
+    fn from(value: String) -> SSLSupportMethod {
+        SSLSupportMethod::from(value.as_str())
+    }
+}
+
+impl From<SSLSupportMethod> for String {
+    fn from(value: SSLSupportMethod) -> String {
+        match value {
+            SSLSupportMethod::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SSLSupportMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SSLSupportMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A complex type that lists the AWS accounts that were included in the <code>TrustedSigners</code> complex type, as well as their active CloudFront key pair IDs, if any. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Signer {
@@ -6973,10 +9255,13 @@ pub struct Signer {
     pub key_pair_ids: Option<KeyPairIds>,
 }
 
-struct SignerDeserializer;
+pub(crate) struct SignerDeserializer;
 impl SignerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Signer, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Signer, XmlParseError> {
         deserialize_elements::<_, Signer, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "AwsAccountNumber" => {
@@ -6993,10 +9278,10 @@ impl SignerDeserializer {
         })
     }
 }
-struct SignerListDeserializer;
+pub(crate) struct SignerListDeserializer;
 impl SignerListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Signer>, XmlParseError> {
@@ -7010,12 +9295,15 @@ impl SignerListDeserializer {
         })
     }
 }
-struct SslProtocolDeserializer;
+pub(crate) struct SslProtocolDeserializer;
 impl SslProtocolDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SslProtocol, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7028,7 +9316,7 @@ impl SslProtocolSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &SslProtocol,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -7042,13 +9330,80 @@ impl SslProtocolSerializer {
     }
 }
 
-struct SslProtocolsListDeserializer;
+/// <p>A list that contains allowed SSL/TLS protocols for this distribution.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SslProtocol {
+    /// <p><code>SSLv3</code></p>
+    SSLv3,
+    /// <p><code>TLSv1</code></p>
+    TLSv1,
+    /// <p><code>TLSv1.1</code></p>
+    TLSv11,
+    /// <p><code>TLSv1.2</code></p>
+    TLSv12,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SslProtocol {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SslProtocol::SSLv3 => "SSLv3",
+            SslProtocol::TLSv1 => "TLSv1",
+            SslProtocol::TLSv11 => "TLSv1.1",
+            SslProtocol::TLSv12 => "TLSv1.2",
+            SslProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SslProtocol {
+    fn from(value: &'a str) -> SslProtocol {
+        match value {
+            "SSLv3" => SslProtocol::SSLv3,
+            "TLSv1" => SslProtocol::TLSv1,
+            "TLSv1.1" => SslProtocol::TLSv11,
+            "TLSv1.2" => SslProtocol::TLSv12,
+            _ => SslProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SslProtocol {
+    fn from(value: String) -> SslProtocol {
+        SslProtocol::from(value.as_str())
+    }
+}
+
+impl From<SslProtocol> for String {
+    fn from(value: SslProtocol) -> String {
+        match value {
+            SslProtocol::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SslProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SslProtocol {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct SslProtocolsListDeserializer;
 impl SslProtocolsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<SslProtocol>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "SslProtocol" {
                 obj.push(SslProtocolDeserializer::deserialize("SslProtocol", stack)?);
@@ -7066,7 +9421,7 @@ impl SslProtocolsListSerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &Vec<String>,
+        obj: &Vec<SslProtocol>,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -7080,10 +9435,10 @@ impl SslProtocolsListSerializer {
     }
 }
 
-struct StatusCodeListDeserializer;
+pub(crate) struct StatusCodeListDeserializer;
 impl StatusCodeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<i64>, XmlParseError> {
@@ -7127,10 +9482,10 @@ pub struct StatusCodes {
     pub quantity: i64,
 }
 
-struct StatusCodesDeserializer;
+pub(crate) struct StatusCodesDeserializer;
 impl StatusCodesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StatusCodes, XmlParseError> {
@@ -7185,17 +9540,17 @@ pub struct StreamingDistribution {
     /// <p>The identifier for the RTMP distribution. For example: <code>EGTXBD79EXAMPLE</code>.</p>
     pub id: String,
     /// <p>The date and time that the distribution was last modified. </p>
-    pub last_modified_time: Option<String>,
+    pub last_modified_time: Option<IsoTimestamp>,
     /// <p>The current status of the RTMP distribution. When the status is <code>Deployed</code>, the distribution's information is propagated to all CloudFront edge locations.</p>
     pub status: String,
     /// <p>The current configuration information for the RTMP distribution.</p>
     pub streaming_distribution_config: StreamingDistributionConfig,
 }
 
-struct StreamingDistributionDeserializer;
+pub(crate) struct StreamingDistributionDeserializer;
 impl StreamingDistributionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StreamingDistribution, XmlParseError> {
@@ -7252,17 +9607,17 @@ pub struct StreamingDistributionConfig {
     /// <p>A complex type that controls whether access logs are written for the streaming distribution. </p>
     pub logging: Option<StreamingLoggingConfig>,
     /// <p>A complex type that contains information about price class for this streaming distribution. </p>
-    pub price_class: Option<String>,
+    pub price_class: Option<PriceClass>,
     /// <p>A complex type that contains information about the Amazon S3 bucket from which you want CloudFront to get your media files for distribution. </p>
     pub s3_origin: S3Origin,
     /// <p>A complex type that specifies any AWS accounts that you want to permit to create signed URLs for private content. If you want the distribution to use signed URLs, include this element; if you want the distribution to use public URLs, remove this element. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/PrivateContent.html">Serving Private Content through CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>. </p>
     pub trusted_signers: TrustedSigners,
 }
 
-struct StreamingDistributionConfigDeserializer;
+pub(crate) struct StreamingDistributionConfigDeserializer;
 impl StreamingDistributionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StreamingDistributionConfig, XmlParseError> {
@@ -7406,10 +9761,10 @@ pub struct StreamingDistributionList {
     pub quantity: i64,
 }
 
-struct StreamingDistributionListDeserializer;
+pub(crate) struct StreamingDistributionListDeserializer;
 impl StreamingDistributionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StreamingDistributionList, XmlParseError> {
@@ -7449,7 +9804,7 @@ impl StreamingDistributionListDeserializer {
     }
 }
 /// <p> A summary of the information for an Amazon CloudFront streaming distribution.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StreamingDistributionSummary {
     /// <p> The ARN (Amazon Resource Name) for the streaming distribution. For example: <code>arn:aws:cloudfront::123456789012:streaming-distribution/EDFDVBD632BHDS5</code>, where <code>123456789012</code> is your AWS account ID.</p>
     pub arn: String,
@@ -7464,9 +9819,9 @@ pub struct StreamingDistributionSummary {
     /// <p>The identifier for the distribution, for example, <code>EDFDVBD632BHDS5</code>.</p>
     pub id: String,
     /// <p>The date and time the distribution was last modified.</p>
-    pub last_modified_time: String,
+    pub last_modified_time: IsoTimestamp,
     /// <p><p/></p>
-    pub price_class: String,
+    pub price_class: PriceClass,
     /// <p>A complex type that contains information about the Amazon S3 bucket from which you want CloudFront to get your media files for distribution.</p>
     pub s3_origin: S3Origin,
     /// <p> Indicates the current status of the distribution. When the status is <code>Deployed</code>, the distribution's information is fully propagated throughout the Amazon CloudFront system.</p>
@@ -7475,16 +9830,29 @@ pub struct StreamingDistributionSummary {
     pub trusted_signers: TrustedSigners,
 }
 
-struct StreamingDistributionSummaryDeserializer;
+pub(crate) struct StreamingDistributionSummaryDeserializer;
 impl StreamingDistributionSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StreamingDistributionSummary, XmlParseError> {
-        deserialize_elements::<_, StreamingDistributionSummary, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            StreamingDistributionSummary {
+                arn: Default::default(),
+                aliases: Default::default(),
+                comment: Default::default(),
+                domain_name: Default::default(),
+                enabled: Default::default(),
+                id: Default::default(),
+                last_modified_time: Default::default(),
+                price_class: PriceClass::Unknown(String::new()),
+                s3_origin: Default::default(),
+                status: Default::default(),
+                trusted_signers: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "ARN" => {
@@ -7529,10 +9897,10 @@ impl StreamingDistributionSummaryDeserializer {
         )
     }
 }
-struct StreamingDistributionSummaryListDeserializer;
+pub(crate) struct StreamingDistributionSummaryListDeserializer;
 impl StreamingDistributionSummaryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<StreamingDistributionSummary>, XmlParseError> {
@@ -7560,10 +9928,10 @@ pub struct StreamingLoggingConfig {
     pub prefix: String,
 }
 
-struct StreamingLoggingConfigDeserializer;
+pub(crate) struct StreamingLoggingConfigDeserializer;
 impl StreamingLoggingConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StreamingLoggingConfig, XmlParseError> {
@@ -7619,10 +9987,13 @@ impl StreamingLoggingConfigSerializer {
     }
 }
 
-struct StringDeserializer;
+pub(crate) struct StringDeserializer;
 impl StringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7660,10 +10031,13 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -7709,10 +10083,13 @@ impl TagSerializer {
     }
 }
 
-struct TagKeyDeserializer;
+pub(crate) struct TagKeyDeserializer;
 impl TagKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7787,10 +10164,10 @@ impl TagKeysSerializer {
     }
 }
 
-struct TagListDeserializer;
+pub(crate) struct TagListDeserializer;
 impl TagListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -7834,10 +10211,53 @@ pub struct TagResourceRequest {
     pub tags: Tags,
 }
 
-struct TagValueDeserializer;
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource: Option<String>,
+    tags: Option<Tags>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource`](struct.TagResourceRequest.html#structfield.resource) member.
+    pub fn resource<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Tags>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource: self
+                .resource
+                .ok_or(::rusoto_core::BuildError::missing("resource"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
+pub(crate) struct TagValueDeserializer;
 impl TagValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7873,10 +10293,13 @@ pub struct Tags {
     pub items: Option<Vec<Tag>>,
 }
 
-struct TagsDeserializer;
+pub(crate) struct TagsDeserializer;
 impl TagsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tags, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tags, XmlParseError> {
         deserialize_elements::<_, Tags, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Items" => {
@@ -7910,12 +10333,15 @@ impl TagsSerializer {
     }
 }
 
-struct TimestampDeserializer;
+pub(crate) struct TimestampDeserializer;
 impl TimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7932,10 +10358,10 @@ pub struct TrustedSigners {
     pub quantity: i64,
 }
 
-struct TrustedSignersDeserializer;
+pub(crate) struct TrustedSignersDeserializer;
 impl TrustedSignersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TrustedSigners, XmlParseError> {
@@ -7999,6 +10425,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: TagKeys,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource: Option<String>,
+    tag_keys: Option<TagKeys>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource`](struct.UntagResourceRequest.html#structfield.resource) member.
+    pub fn resource<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<TagKeys>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource: self
+                .resource
+                .ok_or(::rusoto_core::BuildError::missing("resource"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p>The request to update an origin access identity.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateCloudFrontOriginAccessIdentityRequest {
@@ -8010,6 +10476,62 @@ pub struct UpdateCloudFrontOriginAccessIdentityRequest {
     pub if_match: Option<String>,
 }
 
+impl UpdateCloudFrontOriginAccessIdentityRequest {
+    /// Returns a builder for `UpdateCloudFrontOriginAccessIdentityRequest`, checking its required members when built.
+    pub fn builder() -> UpdateCloudFrontOriginAccessIdentityRequestBuilder {
+        UpdateCloudFrontOriginAccessIdentityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateCloudFrontOriginAccessIdentityRequest`](struct.UpdateCloudFrontOriginAccessIdentityRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateCloudFrontOriginAccessIdentityRequestBuilder {
+    cloud_front_origin_access_identity_config: Option<CloudFrontOriginAccessIdentityConfig>,
+    id: Option<String>,
+    if_match: Option<String>,
+}
+
+impl UpdateCloudFrontOriginAccessIdentityRequestBuilder {
+    /// Sets the [`cloud_front_origin_access_identity_config`](struct.UpdateCloudFrontOriginAccessIdentityRequest.html#structfield.cloud_front_origin_access_identity_config) member.
+    pub fn cloud_front_origin_access_identity_config<
+        T: Into<CloudFrontOriginAccessIdentityConfig>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cloud_front_origin_access_identity_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateCloudFrontOriginAccessIdentityRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`if_match`](struct.UpdateCloudFrontOriginAccessIdentityRequest.html#structfield.if_match) member.
+    pub fn if_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_match = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateCloudFrontOriginAccessIdentityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateCloudFrontOriginAccessIdentityRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateCloudFrontOriginAccessIdentityRequest {
+            cloud_front_origin_access_identity_config: self
+                .cloud_front_origin_access_identity_config
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "cloud_front_origin_access_identity_config",
+                ))?,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            if_match: self.if_match,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateCloudFrontOriginAccessIdentityResult {
@@ -8019,10 +10541,10 @@ pub struct UpdateCloudFrontOriginAccessIdentityResult {
     pub e_tag: Option<String>,
 }
 
-struct UpdateCloudFrontOriginAccessIdentityResultDeserializer;
+pub(crate) struct UpdateCloudFrontOriginAccessIdentityResultDeserializer;
 impl UpdateCloudFrontOriginAccessIdentityResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateCloudFrontOriginAccessIdentityResult, XmlParseError> {
@@ -8038,7 +10560,7 @@ impl UpdateCloudFrontOriginAccessIdentityResultDeserializer {
     }
 }
 /// <p>The request to update a distribution.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UpdateDistributionRequest {
     /// <p>The distribution's configuration information.</p>
     pub distribution_config: DistributionConfig,
@@ -8048,6 +10570,54 @@ pub struct UpdateDistributionRequest {
     pub if_match: Option<String>,
 }
 
+impl UpdateDistributionRequest {
+    /// Returns a builder for `UpdateDistributionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDistributionRequestBuilder {
+        UpdateDistributionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDistributionRequest`](struct.UpdateDistributionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDistributionRequestBuilder {
+    distribution_config: Option<DistributionConfig>,
+    id: Option<String>,
+    if_match: Option<String>,
+}
+
+impl UpdateDistributionRequestBuilder {
+    /// Sets the [`distribution_config`](struct.UpdateDistributionRequest.html#structfield.distribution_config) member.
+    pub fn distribution_config<T: Into<DistributionConfig>>(mut self, value: T) -> Self {
+        self.distribution_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateDistributionRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`if_match`](struct.UpdateDistributionRequest.html#structfield.if_match) member.
+    pub fn if_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_match = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDistributionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDistributionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDistributionRequest {
+            distribution_config: self
+                .distribution_config
+                .ok_or(::rusoto_core::BuildError::missing("distribution_config"))?,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            if_match: self.if_match,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateDistributionResult {
@@ -8057,10 +10627,10 @@ pub struct UpdateDistributionResult {
     pub e_tag: Option<String>,
 }
 
-struct UpdateDistributionResultDeserializer;
+pub(crate) struct UpdateDistributionResultDeserializer;
 impl UpdateDistributionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateDistributionResult, XmlParseError> {
@@ -8083,6 +10653,58 @@ pub struct UpdateFieldLevelEncryptionConfigRequest {
     pub if_match: Option<String>,
 }
 
+impl UpdateFieldLevelEncryptionConfigRequest {
+    /// Returns a builder for `UpdateFieldLevelEncryptionConfigRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFieldLevelEncryptionConfigRequestBuilder {
+        UpdateFieldLevelEncryptionConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFieldLevelEncryptionConfigRequest`](struct.UpdateFieldLevelEncryptionConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFieldLevelEncryptionConfigRequestBuilder {
+    field_level_encryption_config: Option<FieldLevelEncryptionConfig>,
+    id: Option<String>,
+    if_match: Option<String>,
+}
+
+impl UpdateFieldLevelEncryptionConfigRequestBuilder {
+    /// Sets the [`field_level_encryption_config`](struct.UpdateFieldLevelEncryptionConfigRequest.html#structfield.field_level_encryption_config) member.
+    pub fn field_level_encryption_config<T: Into<FieldLevelEncryptionConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.field_level_encryption_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateFieldLevelEncryptionConfigRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`if_match`](struct.UpdateFieldLevelEncryptionConfigRequest.html#structfield.if_match) member.
+    pub fn if_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_match = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFieldLevelEncryptionConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateFieldLevelEncryptionConfigRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateFieldLevelEncryptionConfigRequest {
+            field_level_encryption_config: self.field_level_encryption_config.ok_or(
+                ::rusoto_core::BuildError::missing("field_level_encryption_config"),
+            )?,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            if_match: self.if_match,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateFieldLevelEncryptionConfigResult {
     /// <p>The value of the <code>ETag</code> header that you received when updating the configuration. For example: <code>E2QWRUHAPOMQZL</code>.</p>
@@ -8091,10 +10713,10 @@ pub struct UpdateFieldLevelEncryptionConfigResult {
     pub field_level_encryption: Option<FieldLevelEncryption>,
 }
 
-struct UpdateFieldLevelEncryptionConfigResultDeserializer;
+pub(crate) struct UpdateFieldLevelEncryptionConfigResultDeserializer;
 impl UpdateFieldLevelEncryptionConfigResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateFieldLevelEncryptionConfigResult, XmlParseError> {
@@ -8117,6 +10739,60 @@ pub struct UpdateFieldLevelEncryptionProfileRequest {
     pub if_match: Option<String>,
 }
 
+impl UpdateFieldLevelEncryptionProfileRequest {
+    /// Returns a builder for `UpdateFieldLevelEncryptionProfileRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFieldLevelEncryptionProfileRequestBuilder {
+        UpdateFieldLevelEncryptionProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFieldLevelEncryptionProfileRequest`](struct.UpdateFieldLevelEncryptionProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFieldLevelEncryptionProfileRequestBuilder {
+    field_level_encryption_profile_config: Option<FieldLevelEncryptionProfileConfig>,
+    id: Option<String>,
+    if_match: Option<String>,
+}
+
+impl UpdateFieldLevelEncryptionProfileRequestBuilder {
+    /// Sets the [`field_level_encryption_profile_config`](struct.UpdateFieldLevelEncryptionProfileRequest.html#structfield.field_level_encryption_profile_config) member.
+    pub fn field_level_encryption_profile_config<T: Into<FieldLevelEncryptionProfileConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.field_level_encryption_profile_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateFieldLevelEncryptionProfileRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`if_match`](struct.UpdateFieldLevelEncryptionProfileRequest.html#structfield.if_match) member.
+    pub fn if_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_match = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFieldLevelEncryptionProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateFieldLevelEncryptionProfileRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateFieldLevelEncryptionProfileRequest {
+            field_level_encryption_profile_config: self
+                .field_level_encryption_profile_config
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "field_level_encryption_profile_config",
+                ))?,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            if_match: self.if_match,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateFieldLevelEncryptionProfileResult {
     /// <p>The result of the field-level encryption profile request. </p>
@@ -8125,10 +10801,10 @@ pub struct UpdateFieldLevelEncryptionProfileResult {
     pub field_level_encryption_profile: Option<FieldLevelEncryptionProfile>,
 }
 
-struct UpdateFieldLevelEncryptionProfileResultDeserializer;
+pub(crate) struct UpdateFieldLevelEncryptionProfileResultDeserializer;
 impl UpdateFieldLevelEncryptionProfileResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateFieldLevelEncryptionProfileResult, XmlParseError> {
@@ -8153,6 +10829,52 @@ pub struct UpdatePublicKeyRequest {
     pub public_key_config: PublicKeyConfig,
 }
 
+impl UpdatePublicKeyRequest {
+    /// Returns a builder for `UpdatePublicKeyRequest`, checking its required members when built.
+    pub fn builder() -> UpdatePublicKeyRequestBuilder {
+        UpdatePublicKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePublicKeyRequest`](struct.UpdatePublicKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdatePublicKeyRequestBuilder {
+    id: Option<String>,
+    if_match: Option<String>,
+    public_key_config: Option<PublicKeyConfig>,
+}
+
+impl UpdatePublicKeyRequestBuilder {
+    /// Sets the [`id`](struct.UpdatePublicKeyRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`if_match`](struct.UpdatePublicKeyRequest.html#structfield.if_match) member.
+    pub fn if_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_match = Some(value.into());
+        self
+    }
+
+    /// Sets the [`public_key_config`](struct.UpdatePublicKeyRequest.html#structfield.public_key_config) member.
+    pub fn public_key_config<T: Into<PublicKeyConfig>>(mut self, value: T) -> Self {
+        self.public_key_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePublicKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdatePublicKeyRequest, ::rusoto_core::BuildError> {
+        Ok(UpdatePublicKeyRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            if_match: self.if_match,
+            public_key_config: self
+                .public_key_config
+                .ok_or(::rusoto_core::BuildError::missing("public_key_config"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdatePublicKeyResult {
     /// <p>The current version of the update public key result. For example: <code>E2QWRUHAPOMQZL</code>.</p>
@@ -8161,10 +10883,10 @@ pub struct UpdatePublicKeyResult {
     pub public_key: Option<PublicKey>,
 }
 
-struct UpdatePublicKeyResultDeserializer;
+pub(crate) struct UpdatePublicKeyResultDeserializer;
 impl UpdatePublicKeyResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdatePublicKeyResult, XmlParseError> {
@@ -8185,6 +10907,57 @@ pub struct UpdateStreamingDistributionRequest {
     pub streaming_distribution_config: StreamingDistributionConfig,
 }
 
+impl UpdateStreamingDistributionRequest {
+    /// Returns a builder for `UpdateStreamingDistributionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateStreamingDistributionRequestBuilder {
+        UpdateStreamingDistributionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateStreamingDistributionRequest`](struct.UpdateStreamingDistributionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateStreamingDistributionRequestBuilder {
+    id: Option<String>,
+    if_match: Option<String>,
+    streaming_distribution_config: Option<StreamingDistributionConfig>,
+}
+
+impl UpdateStreamingDistributionRequestBuilder {
+    /// Sets the [`id`](struct.UpdateStreamingDistributionRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`if_match`](struct.UpdateStreamingDistributionRequest.html#structfield.if_match) member.
+    pub fn if_match<T: Into<String>>(mut self, value: T) -> Self {
+        self.if_match = Some(value.into());
+        self
+    }
+
+    /// Sets the [`streaming_distribution_config`](struct.UpdateStreamingDistributionRequest.html#structfield.streaming_distribution_config) member.
+    pub fn streaming_distribution_config<T: Into<StreamingDistributionConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.streaming_distribution_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateStreamingDistributionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateStreamingDistributionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateStreamingDistributionRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            if_match: self.if_match,
+            streaming_distribution_config: self.streaming_distribution_config.ok_or(
+                ::rusoto_core::BuildError::missing("streaming_distribution_config"),
+            )?,
+        })
+    }
+}
+
 /// <p>The returned result of the corresponding request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateStreamingDistributionResult {
@@ -8194,10 +10967,10 @@ pub struct UpdateStreamingDistributionResult {
     pub streaming_distribution: Option<StreamingDistribution>,
 }
 
-struct UpdateStreamingDistributionResultDeserializer;
+pub(crate) struct UpdateStreamingDistributionResultDeserializer;
 impl UpdateStreamingDistributionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateStreamingDistributionResult, XmlParseError> {
@@ -8220,15 +10993,15 @@ pub struct ViewerCertificate {
     /// <p>For information about how and when to use <code>IAMCertificateId</code>, see <a>ViewerCertificate</a>.</p>
     pub iam_certificate_id: Option<String>,
     /// <p>Specify the security policy that you want CloudFront to use for HTTPS connections. A security policy determines two settings:</p> <ul> <li> <p>The minimum SSL/TLS protocol that CloudFront uses to communicate with viewers</p> </li> <li> <p>The cipher that CloudFront uses to encrypt the content that it returns to viewers</p> </li> </ul> <note> <p>On the CloudFront console, this setting is called <b>Security policy</b>.</p> </note> <p>We recommend that you specify <code>TLSv1.1_2016</code> unless your users are using browsers or devices that do not support TLSv1.1 or later.</p> <p>When both of the following are true, you must specify <code>TLSv1</code> or later for the security policy: </p> <ul> <li> <p>You're using a custom certificate: you specified a value for <code>ACMCertificateArn</code> or for <code>IAMCertificateId</code> </p> </li> <li> <p>You're using SNI: you specified <code>sni-only</code> for <code>SSLSupportMethod</code> </p> </li> </ul> <p>If you specify <code>true</code> for <code>CloudFrontDefaultCertificate</code>, CloudFront automatically sets the security policy to <code>TLSv1</code> regardless of the value that you specify for <code>MinimumProtocolVersion</code>.</p> <p>For information about the relationship between the security policy that you choose and the protocols and ciphers that CloudFront uses to communicate with viewers, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/secure-connections-supported-viewer-protocols-ciphers.html#secure-connections-supported-ciphers"> Supported SSL/TLS Protocols and Ciphers for Communication Between Viewers and CloudFront</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
-    pub minimum_protocol_version: Option<String>,
+    pub minimum_protocol_version: Option<MinimumProtocolVersion>,
     /// <p>If you specify a value for <a>ViewerCertificate$ACMCertificateArn</a> or for <a>ViewerCertificate$IAMCertificateId</a>, you must also specify how you want CloudFront to serve HTTPS requests: using a method that works for all clients or one that works for most clients:</p> <ul> <li> <p> <code>vip</code>: CloudFront uses dedicated IP addresses for your content and can respond to HTTPS requests from any viewer. However, you will incur additional monthly charges.</p> </li> <li> <p> <code>sni-only</code>: CloudFront can respond to HTTPS requests from viewers that support Server Name Indication (SNI). All modern browsers support SNI, but some browsers still in use don't support SNI. If some of your users' browsers don't support SNI, we recommend that you do one of the following:</p> <ul> <li> <p>Use the <code>vip</code> option (dedicated IP addresses) instead of <code>sni-only</code>.</p> </li> <li> <p>Use the CloudFront SSL/TLS certificate instead of a custom certificate. This requires that you use the CloudFront domain name of your distribution in the URLs for your objects, for example, <code>https://d111111abcdef8.cloudfront.net/logo.png</code>.</p> </li> <li> <p>If you can control which browser your users use, upgrade the browser to one that supports SNI.</p> </li> <li> <p>Use HTTP instead of HTTPS.</p> </li> </ul> </li> </ul> <p>Don't specify a value for <code>SSLSupportMethod</code> if you specified <code>&lt;CloudFrontDefaultCertificate&gt;true&lt;CloudFrontDefaultCertificate&gt;</code>.</p> <p>For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/SecureConnections.html#CNAMEsAndHTTPS.html">Using Alternate Domain Names and HTTPS</a> in the <i>Amazon CloudFront Developer Guide</i>.</p>
-    pub ssl_support_method: Option<String>,
+    pub ssl_support_method: Option<SSLSupportMethod>,
 }
 
-struct ViewerCertificateDeserializer;
+pub(crate) struct ViewerCertificateDeserializer;
 impl ViewerCertificateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ViewerCertificate, XmlParseError> {
@@ -8328,12 +11101,15 @@ impl ViewerCertificateSerializer {
     }
 }
 
-struct ViewerProtocolPolicyDeserializer;
+pub(crate) struct ViewerProtocolPolicyDeserializer;
 impl ViewerProtocolPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ViewerProtocolPolicy, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -8346,7 +11122,7 @@ impl ViewerProtocolPolicySerializer {
     pub fn serialize<W>(
         mut writer: &mut EventWriter<W>,
         name: &str,
-        obj: &String,
+        obj: &ViewerProtocolPolicy,
     ) -> Result<(), xml::writer::Error>
     where
         W: Write,
@@ -8360,6 +11136,69 @@ impl ViewerProtocolPolicySerializer {
     }
 }
 
+/// <p><p>The protocol that viewers can use to access the files in the origin specified by <code>TargetOriginId</code> when a request matches the path pattern in <code>PathPattern</code>. You can specify the following options:</p> <ul> <li> <p> <code>allow-all</code>: Viewers can use HTTP or HTTPS.</p> </li> <li> <p> <code>redirect-to-https</code>: If a viewer submits an HTTP request, CloudFront returns an HTTP status code of 301 (Moved Permanently) to the viewer along with the HTTPS URL. The viewer then resubmits the request using the new URL. </p> </li> <li> <p> <code>https-only</code>: If a viewer sends an HTTP request, CloudFront returns an HTTP status code of 403 (Forbidden). </p> </li> </ul> <p>For more information about requiring the HTTPS protocol, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/SecureConnections.html">Using an HTTPS Connection to Access Your Objects</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> <note> <p>The only way to guarantee that viewers retrieve an object that was fetched from the origin using HTTPS is never to use any other protocol to fetch the object. If you have recently changed from HTTP to HTTPS, we recommend that you clear your objects&#39; cache because cached objects are protocol agnostic. That means that an edge location will return an object from the cache regardless of whether the current request protocol matches the protocol used previously. For more information, see <a href="http://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/Expiration.html">Specifying How Long Objects and Errors Stay in a CloudFront Edge Cache (Expiration)</a> in the <i>Amazon CloudFront Developer Guide</i>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ViewerProtocolPolicy {
+    /// <p><code>allow-all</code> - Viewers can use HTTP or HTTPS.</p>
+    AllowAll,
+    /// <p><code>https-only</code> - If a viewer sends an HTTP request, CloudFront returns an HTTP status code of 403 (Forbidden).</p>
+    HttpsOnly,
+    /// <p><code>redirect-to-https</code> - If a viewer submits an HTTP request, CloudFront returns an HTTP status code of 301 (Moved Permanently) to the viewer along with the HTTPS URL. The viewer then resubmits the request using the new URL.</p>
+    RedirectToHttps,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ViewerProtocolPolicy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ViewerProtocolPolicy::AllowAll => "allow-all",
+            ViewerProtocolPolicy::HttpsOnly => "https-only",
+            ViewerProtocolPolicy::RedirectToHttps => "redirect-to-https",
+            ViewerProtocolPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ViewerProtocolPolicy {
+    fn from(value: &'a str) -> ViewerProtocolPolicy {
+        match value {
+            "allow-all" => ViewerProtocolPolicy::AllowAll,
+            "https-only" => ViewerProtocolPolicy::HttpsOnly,
+            "redirect-to-https" => ViewerProtocolPolicy::RedirectToHttps,
+            _ => ViewerProtocolPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ViewerProtocolPolicy {
+    fn from(value: String) -> ViewerProtocolPolicy {
+        ViewerProtocolPolicy::from(value.as_str())
+    }
+}
+
+impl From<ViewerProtocolPolicy> for String {
+    fn from(value: ViewerProtocolPolicy) -> String {
+        match value {
+            ViewerProtocolPolicy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ViewerProtocolPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ViewerProtocolPolicy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// Errors returned by CreateCloudFrontOriginAccessIdentity
 #[derive(Debug, PartialEq)]
 pub enum CreateCloudFrontOriginAccessIdentityError {
@@ -12080,6 +14919,306 @@ pub trait CloudFront {
         input: UpdateStreamingDistributionRequest,
     ) -> RusotoFuture<UpdateStreamingDistributionResult, UpdateStreamingDistributionError>;
 }
+impl<T: ?Sized + CloudFront> CloudFront for ::std::sync::Arc<T> {
+    fn create_cloud_front_origin_access_identity(
+        &self,
+        input: CreateCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<
+        CreateCloudFrontOriginAccessIdentityResult,
+        CreateCloudFrontOriginAccessIdentityError,
+    > {
+        (**self).create_cloud_front_origin_access_identity(input)
+    }
+    fn create_distribution(
+        &self,
+        input: CreateDistributionRequest,
+    ) -> RusotoFuture<CreateDistributionResult, CreateDistributionError> {
+        (**self).create_distribution(input)
+    }
+    fn create_distribution_with_tags(
+        &self,
+        input: CreateDistributionWithTagsRequest,
+    ) -> RusotoFuture<CreateDistributionWithTagsResult, CreateDistributionWithTagsError> {
+        (**self).create_distribution_with_tags(input)
+    }
+    fn create_field_level_encryption_config(
+        &self,
+        input: CreateFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<CreateFieldLevelEncryptionConfigResult, CreateFieldLevelEncryptionConfigError>
+    {
+        (**self).create_field_level_encryption_config(input)
+    }
+    fn create_field_level_encryption_profile(
+        &self,
+        input: CreateFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<CreateFieldLevelEncryptionProfileResult, CreateFieldLevelEncryptionProfileError>
+    {
+        (**self).create_field_level_encryption_profile(input)
+    }
+    fn create_invalidation(
+        &self,
+        input: CreateInvalidationRequest,
+    ) -> RusotoFuture<CreateInvalidationResult, CreateInvalidationError> {
+        (**self).create_invalidation(input)
+    }
+    fn create_public_key(
+        &self,
+        input: CreatePublicKeyRequest,
+    ) -> RusotoFuture<CreatePublicKeyResult, CreatePublicKeyError> {
+        (**self).create_public_key(input)
+    }
+    fn create_streaming_distribution(
+        &self,
+        input: CreateStreamingDistributionRequest,
+    ) -> RusotoFuture<CreateStreamingDistributionResult, CreateStreamingDistributionError> {
+        (**self).create_streaming_distribution(input)
+    }
+    fn create_streaming_distribution_with_tags(
+        &self,
+        input: CreateStreamingDistributionWithTagsRequest,
+    ) -> RusotoFuture<
+        CreateStreamingDistributionWithTagsResult,
+        CreateStreamingDistributionWithTagsError,
+    > {
+        (**self).create_streaming_distribution_with_tags(input)
+    }
+    fn delete_cloud_front_origin_access_identity(
+        &self,
+        input: DeleteCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<(), DeleteCloudFrontOriginAccessIdentityError> {
+        (**self).delete_cloud_front_origin_access_identity(input)
+    }
+    fn delete_distribution(
+        &self,
+        input: DeleteDistributionRequest,
+    ) -> RusotoFuture<(), DeleteDistributionError> {
+        (**self).delete_distribution(input)
+    }
+    fn delete_field_level_encryption_config(
+        &self,
+        input: DeleteFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<(), DeleteFieldLevelEncryptionConfigError> {
+        (**self).delete_field_level_encryption_config(input)
+    }
+    fn delete_field_level_encryption_profile(
+        &self,
+        input: DeleteFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<(), DeleteFieldLevelEncryptionProfileError> {
+        (**self).delete_field_level_encryption_profile(input)
+    }
+    fn delete_public_key(
+        &self,
+        input: DeletePublicKeyRequest,
+    ) -> RusotoFuture<(), DeletePublicKeyError> {
+        (**self).delete_public_key(input)
+    }
+    fn delete_streaming_distribution(
+        &self,
+        input: DeleteStreamingDistributionRequest,
+    ) -> RusotoFuture<(), DeleteStreamingDistributionError> {
+        (**self).delete_streaming_distribution(input)
+    }
+    fn get_cloud_front_origin_access_identity(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<GetCloudFrontOriginAccessIdentityResult, GetCloudFrontOriginAccessIdentityError>
+    {
+        (**self).get_cloud_front_origin_access_identity(input)
+    }
+    fn get_cloud_front_origin_access_identity_config(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityConfigRequest,
+    ) -> RusotoFuture<
+        GetCloudFrontOriginAccessIdentityConfigResult,
+        GetCloudFrontOriginAccessIdentityConfigError,
+    > {
+        (**self).get_cloud_front_origin_access_identity_config(input)
+    }
+    fn get_distribution(
+        &self,
+        input: GetDistributionRequest,
+    ) -> RusotoFuture<GetDistributionResult, GetDistributionError> {
+        (**self).get_distribution(input)
+    }
+    fn get_distribution_config(
+        &self,
+        input: GetDistributionConfigRequest,
+    ) -> RusotoFuture<GetDistributionConfigResult, GetDistributionConfigError> {
+        (**self).get_distribution_config(input)
+    }
+    fn get_field_level_encryption(
+        &self,
+        input: GetFieldLevelEncryptionRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionResult, GetFieldLevelEncryptionError> {
+        (**self).get_field_level_encryption(input)
+    }
+    fn get_field_level_encryption_config(
+        &self,
+        input: GetFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionConfigResult, GetFieldLevelEncryptionConfigError> {
+        (**self).get_field_level_encryption_config(input)
+    }
+    fn get_field_level_encryption_profile(
+        &self,
+        input: GetFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<GetFieldLevelEncryptionProfileResult, GetFieldLevelEncryptionProfileError>
+    {
+        (**self).get_field_level_encryption_profile(input)
+    }
+    fn get_field_level_encryption_profile_config(
+        &self,
+        input: GetFieldLevelEncryptionProfileConfigRequest,
+    ) -> RusotoFuture<
+        GetFieldLevelEncryptionProfileConfigResult,
+        GetFieldLevelEncryptionProfileConfigError,
+    > {
+        (**self).get_field_level_encryption_profile_config(input)
+    }
+    fn get_invalidation(
+        &self,
+        input: GetInvalidationRequest,
+    ) -> RusotoFuture<GetInvalidationResult, GetInvalidationError> {
+        (**self).get_invalidation(input)
+    }
+    fn get_public_key(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> RusotoFuture<GetPublicKeyResult, GetPublicKeyError> {
+        (**self).get_public_key(input)
+    }
+    fn get_public_key_config(
+        &self,
+        input: GetPublicKeyConfigRequest,
+    ) -> RusotoFuture<GetPublicKeyConfigResult, GetPublicKeyConfigError> {
+        (**self).get_public_key_config(input)
+    }
+    fn get_streaming_distribution(
+        &self,
+        input: GetStreamingDistributionRequest,
+    ) -> RusotoFuture<GetStreamingDistributionResult, GetStreamingDistributionError> {
+        (**self).get_streaming_distribution(input)
+    }
+    fn get_streaming_distribution_config(
+        &self,
+        input: GetStreamingDistributionConfigRequest,
+    ) -> RusotoFuture<GetStreamingDistributionConfigResult, GetStreamingDistributionConfigError>
+    {
+        (**self).get_streaming_distribution_config(input)
+    }
+    fn list_cloud_front_origin_access_identities(
+        &self,
+        input: ListCloudFrontOriginAccessIdentitiesRequest,
+    ) -> RusotoFuture<
+        ListCloudFrontOriginAccessIdentitiesResult,
+        ListCloudFrontOriginAccessIdentitiesError,
+    > {
+        (**self).list_cloud_front_origin_access_identities(input)
+    }
+    fn list_distributions(
+        &self,
+        input: ListDistributionsRequest,
+    ) -> RusotoFuture<ListDistributionsResult, ListDistributionsError> {
+        (**self).list_distributions(input)
+    }
+    fn list_distributions_by_web_acl_id(
+        &self,
+        input: ListDistributionsByWebACLIdRequest,
+    ) -> RusotoFuture<ListDistributionsByWebACLIdResult, ListDistributionsByWebACLIdError> {
+        (**self).list_distributions_by_web_acl_id(input)
+    }
+    fn list_field_level_encryption_configs(
+        &self,
+        input: ListFieldLevelEncryptionConfigsRequest,
+    ) -> RusotoFuture<ListFieldLevelEncryptionConfigsResult, ListFieldLevelEncryptionConfigsError>
+    {
+        (**self).list_field_level_encryption_configs(input)
+    }
+    fn list_field_level_encryption_profiles(
+        &self,
+        input: ListFieldLevelEncryptionProfilesRequest,
+    ) -> RusotoFuture<ListFieldLevelEncryptionProfilesResult, ListFieldLevelEncryptionProfilesError>
+    {
+        (**self).list_field_level_encryption_profiles(input)
+    }
+    fn list_invalidations(
+        &self,
+        input: ListInvalidationsRequest,
+    ) -> RusotoFuture<ListInvalidationsResult, ListInvalidationsError> {
+        (**self).list_invalidations(input)
+    }
+    fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> RusotoFuture<ListPublicKeysResult, ListPublicKeysError> {
+        (**self).list_public_keys(input)
+    }
+    fn list_streaming_distributions(
+        &self,
+        input: ListStreamingDistributionsRequest,
+    ) -> RusotoFuture<ListStreamingDistributionsResult, ListStreamingDistributionsError> {
+        (**self).list_streaming_distributions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cloud_front_origin_access_identity(
+        &self,
+        input: UpdateCloudFrontOriginAccessIdentityRequest,
+    ) -> RusotoFuture<
+        UpdateCloudFrontOriginAccessIdentityResult,
+        UpdateCloudFrontOriginAccessIdentityError,
+    > {
+        (**self).update_cloud_front_origin_access_identity(input)
+    }
+    fn update_distribution(
+        &self,
+        input: UpdateDistributionRequest,
+    ) -> RusotoFuture<UpdateDistributionResult, UpdateDistributionError> {
+        (**self).update_distribution(input)
+    }
+    fn update_field_level_encryption_config(
+        &self,
+        input: UpdateFieldLevelEncryptionConfigRequest,
+    ) -> RusotoFuture<UpdateFieldLevelEncryptionConfigResult, UpdateFieldLevelEncryptionConfigError>
+    {
+        (**self).update_field_level_encryption_config(input)
+    }
+    fn update_field_level_encryption_profile(
+        &self,
+        input: UpdateFieldLevelEncryptionProfileRequest,
+    ) -> RusotoFuture<UpdateFieldLevelEncryptionProfileResult, UpdateFieldLevelEncryptionProfileError>
+    {
+        (**self).update_field_level_encryption_profile(input)
+    }
+    fn update_public_key(
+        &self,
+        input: UpdatePublicKeyRequest,
+    ) -> RusotoFuture<UpdatePublicKeyResult, UpdatePublicKeyError> {
+        (**self).update_public_key(input)
+    }
+    fn update_streaming_distribution(
+        &self,
+        input: UpdateStreamingDistributionRequest,
+    ) -> RusotoFuture<UpdateStreamingDistributionResult, UpdateStreamingDistributionError> {
+        (**self).update_streaming_distribution(input)
+    }
+}
+/// The error codes CloudFront retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the CloudFront API.
 #[derive(Clone)]
 pub struct CloudFrontClient {
@@ -12095,6 +15234,27 @@ impl CloudFrontClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CLOUDFRONT`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CloudFrontClient {
+        Self::new(region::Region::from_env_for_service("CLOUDFRONT"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `cloudfront`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CloudFrontClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("cloudfront", "CLOUDFRONT"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -12113,7 +15273,54 @@ impl CloudFrontClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudFrontClient {
-        CloudFrontClient { client, region }
+        CloudFrontClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "cloudfront")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "cloudfront",
+            "cloudfront",
+            "CLOUDFRONT",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CloudFront` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CloudFront + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CloudFrontClient {
+    fn service_name(&self) -> &'static str {
+        "CloudFront"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "cloudfront"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CloudFrontClient {
+        Self::new_with_client(client, region)
     }
 }
 
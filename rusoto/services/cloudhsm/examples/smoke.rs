@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon CloudHSM clients.
+//!
+//! Makes a single read-only `ListAvailableZones` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_cloudhsm::{CloudHsm, CloudHsmClient};
+
+fn main() {
+    let client = CloudHsmClient::from_env();
+    match client.list_available_zones().sync() {
+        Ok(_) => println!("ListAvailableZones succeeded"),
+        Err(error) => {
+            eprintln!("ListAvailableZones failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
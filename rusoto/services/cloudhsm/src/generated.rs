@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AddTagsToResourceRequest {
@@ -33,6 +34,48 @@ pub struct AddTagsToResourceRequest {
     pub tag_list: Vec<Tag>,
 }
 
+impl AddTagsToResourceRequest {
+    /// Returns a builder for `AddTagsToResourceRequest`, checking its required members when built.
+    pub fn builder() -> AddTagsToResourceRequestBuilder {
+        AddTagsToResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsToResourceRequest`](struct.AddTagsToResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct AddTagsToResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_list: Option<Vec<Tag>>,
+}
+
+impl AddTagsToResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.AddTagsToResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_list`](struct.AddTagsToResourceRequest.html#structfield.tag_list) member.
+    pub fn tag_list<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tag_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsToResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddTagsToResourceRequest, ::rusoto_core::BuildError> {
+        Ok(AddTagsToResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_list: self
+                .tag_list
+                .ok_or(::rusoto_core::BuildError::missing("tag_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddTagsToResourceResponse {
@@ -41,6 +84,154 @@ pub struct AddTagsToResourceResponse {
     pub status: String,
 }
 
+/// <p>The client version.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClientVersion {
+    /// <p><code>5.1</code></p>
+    V51,
+    /// <p><code>5.3</code></p>
+    V53,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ClientVersion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClientVersion::V51 => "5.1",
+            ClientVersion::V53 => "5.3",
+            ClientVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ClientVersion {
+    fn from(value: &'a str) -> ClientVersion {
+        match value {
+            "5.1" => ClientVersion::V51,
+            "5.3" => ClientVersion::V53,
+            _ => ClientVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClientVersion {
+    fn from(value: String) -> ClientVersion {
+        ClientVersion::from(value.as_str())
+    }
+}
+
+impl From<ClientVersion> for String {
+    fn from(value: ClientVersion) -> String {
+        match value {
+            ClientVersion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ClientVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ClientVersion {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClientVersion {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ClientVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ClientVersion::from(value))
+    }
+}
+
+/// <p>The state of the high-availability partition group.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CloudHsmObjectState {
+    /// <p><code>READY</code></p>
+    Ready,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>DEGRADED</code></p>
+    Degraded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CloudHsmObjectState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudHsmObjectState::Ready => "READY",
+            CloudHsmObjectState::Updating => "UPDATING",
+            CloudHsmObjectState::Degraded => "DEGRADED",
+            CloudHsmObjectState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CloudHsmObjectState {
+    fn from(value: &'a str) -> CloudHsmObjectState {
+        match value {
+            "READY" => CloudHsmObjectState::Ready,
+            "UPDATING" => CloudHsmObjectState::Updating,
+            "DEGRADED" => CloudHsmObjectState::Degraded,
+            _ => CloudHsmObjectState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudHsmObjectState {
+    fn from(value: String) -> CloudHsmObjectState {
+        CloudHsmObjectState::from(value.as_str())
+    }
+}
+
+impl From<CloudHsmObjectState> for String {
+    fn from(value: CloudHsmObjectState) -> String {
+        match value {
+            CloudHsmObjectState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CloudHsmObjectState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CloudHsmObjectState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CloudHsmObjectState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CloudHsmObjectState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CloudHsmObjectState::from(value))
+    }
+}
+
 /// <p>Contains the inputs for the <a>CreateHapgRequest</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateHapgRequest {
@@ -49,6 +240,36 @@ pub struct CreateHapgRequest {
     pub label: String,
 }
 
+impl CreateHapgRequest {
+    /// Returns a builder for `CreateHapgRequest`, checking its required members when built.
+    pub fn builder() -> CreateHapgRequestBuilder {
+        CreateHapgRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateHapgRequest`](struct.CreateHapgRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateHapgRequestBuilder {
+    label: Option<String>,
+}
+
+impl CreateHapgRequestBuilder {
+    /// Sets the [`label`](struct.CreateHapgRequest.html#structfield.label) member.
+    pub fn label<T: Into<String>>(mut self, value: T) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateHapgRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateHapgRequest, ::rusoto_core::BuildError> {
+        Ok(CreateHapgRequest {
+            label: self
+                .label
+                .ok_or(::rusoto_core::BuildError::missing("label"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of the <a>CreateHAPartitionGroup</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -60,7 +281,7 @@ pub struct CreateHapgResponse {
 }
 
 /// <p>Contains the inputs for the <code>CreateHsm</code> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateHsmRequest {
     /// <p>A user-defined token to ensure idempotence. Subsequent calls to this operation with the same token will be ignored.</p>
     #[serde(rename = "ClientToken")]
@@ -84,13 +305,105 @@ pub struct CreateHsmRequest {
     #[serde(rename = "SubnetId")]
     pub subnet_id: String,
     #[serde(rename = "SubscriptionType")]
-    pub subscription_type: String,
+    pub subscription_type: SubscriptionType,
     /// <p>The IP address for the syslog monitoring server. The AWS CloudHSM service only supports one syslog monitoring server.</p>
     #[serde(rename = "SyslogIp")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub syslog_ip: Option<String>,
 }
 
+impl CreateHsmRequest {
+    /// Returns a builder for `CreateHsmRequest`, checking its required members when built.
+    pub fn builder() -> CreateHsmRequestBuilder {
+        CreateHsmRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateHsmRequest`](struct.CreateHsmRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateHsmRequestBuilder {
+    client_token: Option<String>,
+    eni_ip: Option<String>,
+    external_id: Option<String>,
+    iam_role_arn: Option<String>,
+    ssh_key: Option<String>,
+    subnet_id: Option<String>,
+    subscription_type: Option<SubscriptionType>,
+    syslog_ip: Option<String>,
+}
+
+impl CreateHsmRequestBuilder {
+    /// Sets the [`client_token`](struct.CreateHsmRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`eni_ip`](struct.CreateHsmRequest.html#structfield.eni_ip) member.
+    pub fn eni_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.eni_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`external_id`](struct.CreateHsmRequest.html#structfield.external_id) member.
+    pub fn external_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.external_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_role_arn`](struct.CreateHsmRequest.html#structfield.iam_role_arn) member.
+    pub fn iam_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_key`](struct.CreateHsmRequest.html#structfield.ssh_key) member.
+    pub fn ssh_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_id`](struct.CreateHsmRequest.html#structfield.subnet_id) member.
+    pub fn subnet_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subnet_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_type`](struct.CreateHsmRequest.html#structfield.subscription_type) member.
+    pub fn subscription_type<T: Into<SubscriptionType>>(mut self, value: T) -> Self {
+        self.subscription_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`syslog_ip`](struct.CreateHsmRequest.html#structfield.syslog_ip) member.
+    pub fn syslog_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.syslog_ip = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateHsmRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateHsmRequest, ::rusoto_core::BuildError> {
+        Ok(CreateHsmRequest {
+            client_token: self.client_token,
+            eni_ip: self.eni_ip,
+            external_id: self.external_id,
+            iam_role_arn: self
+                .iam_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("iam_role_arn"))?,
+            ssh_key: self
+                .ssh_key
+                .ok_or(::rusoto_core::BuildError::missing("ssh_key"))?,
+            subnet_id: self
+                .subnet_id
+                .ok_or(::rusoto_core::BuildError::missing("subnet_id"))?,
+            subscription_type: self
+                .subscription_type
+                .ok_or(::rusoto_core::BuildError::missing("subscription_type"))?,
+            syslog_ip: self.syslog_ip,
+        })
+    }
+}
+
 /// <p>Contains the output of the <code>CreateHsm</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -113,6 +426,46 @@ pub struct CreateLunaClientRequest {
     pub label: Option<String>,
 }
 
+impl CreateLunaClientRequest {
+    /// Returns a builder for `CreateLunaClientRequest`, checking its required members when built.
+    pub fn builder() -> CreateLunaClientRequestBuilder {
+        CreateLunaClientRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLunaClientRequest`](struct.CreateLunaClientRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLunaClientRequestBuilder {
+    certificate: Option<String>,
+    label: Option<String>,
+}
+
+impl CreateLunaClientRequestBuilder {
+    /// Sets the [`certificate`](struct.CreateLunaClientRequest.html#structfield.certificate) member.
+    pub fn certificate<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`label`](struct.CreateLunaClientRequest.html#structfield.label) member.
+    pub fn label<T: Into<String>>(mut self, value: T) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLunaClientRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLunaClientRequest, ::rusoto_core::BuildError> {
+        Ok(CreateLunaClientRequest {
+            certificate: self
+                .certificate
+                .ok_or(::rusoto_core::BuildError::missing("certificate"))?,
+            label: self.label,
+        })
+    }
+}
+
 /// <p>Contains the output of the <a>CreateLunaClient</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -131,6 +484,36 @@ pub struct DeleteHapgRequest {
     pub hapg_arn: String,
 }
 
+impl DeleteHapgRequest {
+    /// Returns a builder for `DeleteHapgRequest`, checking its required members when built.
+    pub fn builder() -> DeleteHapgRequestBuilder {
+        DeleteHapgRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteHapgRequest`](struct.DeleteHapgRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteHapgRequestBuilder {
+    hapg_arn: Option<String>,
+}
+
+impl DeleteHapgRequestBuilder {
+    /// Sets the [`hapg_arn`](struct.DeleteHapgRequest.html#structfield.hapg_arn) member.
+    pub fn hapg_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.hapg_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteHapgRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteHapgRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteHapgRequest {
+            hapg_arn: self
+                .hapg_arn
+                .ok_or(::rusoto_core::BuildError::missing("hapg_arn"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of the <a>DeleteHapg</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -148,6 +531,36 @@ pub struct DeleteHsmRequest {
     pub hsm_arn: String,
 }
 
+impl DeleteHsmRequest {
+    /// Returns a builder for `DeleteHsmRequest`, checking its required members when built.
+    pub fn builder() -> DeleteHsmRequestBuilder {
+        DeleteHsmRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteHsmRequest`](struct.DeleteHsmRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteHsmRequestBuilder {
+    hsm_arn: Option<String>,
+}
+
+impl DeleteHsmRequestBuilder {
+    /// Sets the [`hsm_arn`](struct.DeleteHsmRequest.html#structfield.hsm_arn) member.
+    pub fn hsm_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteHsmRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteHsmRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteHsmRequest {
+            hsm_arn: self
+                .hsm_arn
+                .ok_or(::rusoto_core::BuildError::missing("hsm_arn"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of the <a>DeleteHsm</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -164,6 +577,38 @@ pub struct DeleteLunaClientRequest {
     pub client_arn: String,
 }
 
+impl DeleteLunaClientRequest {
+    /// Returns a builder for `DeleteLunaClientRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLunaClientRequestBuilder {
+        DeleteLunaClientRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLunaClientRequest`](struct.DeleteLunaClientRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLunaClientRequestBuilder {
+    client_arn: Option<String>,
+}
+
+impl DeleteLunaClientRequestBuilder {
+    /// Sets the [`client_arn`](struct.DeleteLunaClientRequest.html#structfield.client_arn) member.
+    pub fn client_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLunaClientRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLunaClientRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLunaClientRequest {
+            client_arn: self
+                .client_arn
+                .ok_or(::rusoto_core::BuildError::missing("client_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteLunaClientResponse {
@@ -180,6 +625,36 @@ pub struct DescribeHapgRequest {
     pub hapg_arn: String,
 }
 
+impl DescribeHapgRequest {
+    /// Returns a builder for `DescribeHapgRequest`, checking its required members when built.
+    pub fn builder() -> DescribeHapgRequestBuilder {
+        DescribeHapgRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeHapgRequest`](struct.DescribeHapgRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeHapgRequestBuilder {
+    hapg_arn: Option<String>,
+}
+
+impl DescribeHapgRequestBuilder {
+    /// Sets the [`hapg_arn`](struct.DescribeHapgRequest.html#structfield.hapg_arn) member.
+    pub fn hapg_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.hapg_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeHapgRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeHapgRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeHapgRequest {
+            hapg_arn: self
+                .hapg_arn
+                .ok_or(::rusoto_core::BuildError::missing("hapg_arn"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of the <a>DescribeHapg</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -219,7 +694,7 @@ pub struct DescribeHapgResponse {
     /// <p>The state of the high-availability partition group.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CloudHsmObjectState>,
 }
 
 /// <p>Contains the inputs for the <a>DescribeHsm</a> operation.</p>
@@ -235,6 +710,42 @@ pub struct DescribeHsmRequest {
     pub hsm_serial_number: Option<String>,
 }
 
+impl DescribeHsmRequest {
+    /// Returns a builder for `DescribeHsmRequest`, checking its required members when built.
+    pub fn builder() -> DescribeHsmRequestBuilder {
+        DescribeHsmRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeHsmRequest`](struct.DescribeHsmRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeHsmRequestBuilder {
+    hsm_arn: Option<String>,
+    hsm_serial_number: Option<String>,
+}
+
+impl DescribeHsmRequestBuilder {
+    /// Sets the [`hsm_arn`](struct.DescribeHsmRequest.html#structfield.hsm_arn) member.
+    pub fn hsm_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_serial_number`](struct.DescribeHsmRequest.html#structfield.hsm_serial_number) member.
+    pub fn hsm_serial_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_serial_number = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeHsmRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeHsmRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeHsmRequest {
+            hsm_arn: self.hsm_arn,
+            hsm_serial_number: self.hsm_serial_number,
+        })
+    }
+}
+
 /// <p>Contains the output of the <a>DescribeHsm</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -294,7 +805,7 @@ pub struct DescribeHsmResponse {
     /// <p>The status of the HSM.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<HsmStatus>,
     /// <p>Contains additional information about the status of the HSM.</p>
     #[serde(rename = "StatusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -313,7 +824,7 @@ pub struct DescribeHsmResponse {
     pub subscription_start_date: Option<String>,
     #[serde(rename = "SubscriptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription_type: Option<String>,
+    pub subscription_type: Option<SubscriptionType>,
     /// <p>The name of the HSM vendor.</p>
     #[serde(rename = "VendorName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -336,6 +847,44 @@ pub struct DescribeLunaClientRequest {
     pub client_arn: Option<String>,
 }
 
+impl DescribeLunaClientRequest {
+    /// Returns a builder for `DescribeLunaClientRequest`, checking its required members when built.
+    pub fn builder() -> DescribeLunaClientRequestBuilder {
+        DescribeLunaClientRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLunaClientRequest`](struct.DescribeLunaClientRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeLunaClientRequestBuilder {
+    certificate_fingerprint: Option<String>,
+    client_arn: Option<String>,
+}
+
+impl DescribeLunaClientRequestBuilder {
+    /// Sets the [`certificate_fingerprint`](struct.DescribeLunaClientRequest.html#structfield.certificate_fingerprint) member.
+    pub fn certificate_fingerprint<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_fingerprint = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_arn`](struct.DescribeLunaClientRequest.html#structfield.client_arn) member.
+    pub fn client_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLunaClientRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLunaClientRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeLunaClientRequest {
+            certificate_fingerprint: self.certificate_fingerprint,
+            client_arn: self.client_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeLunaClientResponse {
@@ -361,19 +910,69 @@ pub struct DescribeLunaClientResponse {
     pub last_modified_timestamp: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetConfigRequest {
     /// <p>The ARN of the client.</p>
     #[serde(rename = "ClientArn")]
     pub client_arn: String,
     /// <p>The client version.</p>
     #[serde(rename = "ClientVersion")]
-    pub client_version: String,
+    pub client_version: ClientVersion,
     /// <p>A list of ARNs that identify the high-availability partition groups that are associated with the client.</p>
     #[serde(rename = "HapgList")]
     pub hapg_list: Vec<String>,
 }
 
+impl GetConfigRequest {
+    /// Returns a builder for `GetConfigRequest`, checking its required members when built.
+    pub fn builder() -> GetConfigRequestBuilder {
+        GetConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetConfigRequest`](struct.GetConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct GetConfigRequestBuilder {
+    client_arn: Option<String>,
+    client_version: Option<ClientVersion>,
+    hapg_list: Option<Vec<String>>,
+}
+
+impl GetConfigRequestBuilder {
+    /// Sets the [`client_arn`](struct.GetConfigRequest.html#structfield.client_arn) member.
+    pub fn client_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_version`](struct.GetConfigRequest.html#structfield.client_version) member.
+    pub fn client_version<T: Into<ClientVersion>>(mut self, value: T) -> Self {
+        self.client_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hapg_list`](struct.GetConfigRequest.html#structfield.hapg_list) member.
+    pub fn hapg_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.hapg_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetConfigRequest, ::rusoto_core::BuildError> {
+        Ok(GetConfigRequest {
+            client_arn: self
+                .client_arn
+                .ok_or(::rusoto_core::BuildError::missing("client_arn"))?,
+            client_version: self
+                .client_version
+                .ok_or(::rusoto_core::BuildError::missing("client_version"))?,
+            hapg_list: self
+                .hapg_list
+                .ok_or(::rusoto_core::BuildError::missing("hapg_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetConfigResponse {
@@ -391,6 +990,98 @@ pub struct GetConfigResponse {
     pub config_type: Option<String>,
 }
 
+/// <p>The status of the HSM.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HsmStatus {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>SUSPENDED</code></p>
+    Suspended,
+    /// <p><code>TERMINATING</code></p>
+    Terminating,
+    /// <p><code>TERMINATED</code></p>
+    Terminated,
+    /// <p><code>DEGRADED</code></p>
+    Degraded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HsmStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HsmStatus::Pending => "PENDING",
+            HsmStatus::Running => "RUNNING",
+            HsmStatus::Updating => "UPDATING",
+            HsmStatus::Suspended => "SUSPENDED",
+            HsmStatus::Terminating => "TERMINATING",
+            HsmStatus::Terminated => "TERMINATED",
+            HsmStatus::Degraded => "DEGRADED",
+            HsmStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HsmStatus {
+    fn from(value: &'a str) -> HsmStatus {
+        match value {
+            "PENDING" => HsmStatus::Pending,
+            "RUNNING" => HsmStatus::Running,
+            "UPDATING" => HsmStatus::Updating,
+            "SUSPENDED" => HsmStatus::Suspended,
+            "TERMINATING" => HsmStatus::Terminating,
+            "TERMINATED" => HsmStatus::Terminated,
+            "DEGRADED" => HsmStatus::Degraded,
+            _ => HsmStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HsmStatus {
+    fn from(value: String) -> HsmStatus {
+        HsmStatus::from(value.as_str())
+    }
+}
+
+impl From<HsmStatus> for String {
+    fn from(value: HsmStatus) -> String {
+        match value {
+            HsmStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HsmStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HsmStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HsmStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HsmStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HsmStatus::from(value))
+    }
+}
+
 /// <p>Contains the inputs for the <a>ListAvailableZones</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListAvailableZonesRequest {}
@@ -412,6 +1103,34 @@ pub struct ListHapgsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListHapgsRequest {
+    /// Returns a builder for `ListHapgsRequest`, checking its required members when built.
+    pub fn builder() -> ListHapgsRequestBuilder {
+        ListHapgsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListHapgsRequest`](struct.ListHapgsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListHapgsRequestBuilder {
+    next_token: Option<String>,
+}
+
+impl ListHapgsRequestBuilder {
+    /// Sets the [`next_token`](struct.ListHapgsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListHapgsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListHapgsRequest, ::rusoto_core::BuildError> {
+        Ok(ListHapgsRequest {
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListHapgsResponse {
@@ -432,6 +1151,34 @@ pub struct ListHsmsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListHsmsRequest {
+    /// Returns a builder for `ListHsmsRequest`, checking its required members when built.
+    pub fn builder() -> ListHsmsRequestBuilder {
+        ListHsmsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListHsmsRequest`](struct.ListHsmsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListHsmsRequestBuilder {
+    next_token: Option<String>,
+}
+
+impl ListHsmsRequestBuilder {
+    /// Sets the [`next_token`](struct.ListHsmsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListHsmsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListHsmsRequest, ::rusoto_core::BuildError> {
+        Ok(ListHsmsRequest {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Contains the output of the <code>ListHsms</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -454,6 +1201,34 @@ pub struct ListLunaClientsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListLunaClientsRequest {
+    /// Returns a builder for `ListLunaClientsRequest`, checking its required members when built.
+    pub fn builder() -> ListLunaClientsRequestBuilder {
+        ListLunaClientsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListLunaClientsRequest`](struct.ListLunaClientsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListLunaClientsRequestBuilder {
+    next_token: Option<String>,
+}
+
+impl ListLunaClientsRequestBuilder {
+    /// Sets the [`next_token`](struct.ListLunaClientsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLunaClientsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListLunaClientsRequest, ::rusoto_core::BuildError> {
+        Ok(ListLunaClientsRequest {
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLunaClientsResponse {
@@ -473,6 +1248,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -496,6 +1303,52 @@ pub struct ModifyHapgRequest {
     pub partition_serial_list: Option<Vec<String>>,
 }
 
+impl ModifyHapgRequest {
+    /// Returns a builder for `ModifyHapgRequest`, checking its required members when built.
+    pub fn builder() -> ModifyHapgRequestBuilder {
+        ModifyHapgRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyHapgRequest`](struct.ModifyHapgRequest.html).
+#[derive(Default, Debug)]
+pub struct ModifyHapgRequestBuilder {
+    hapg_arn: Option<String>,
+    label: Option<String>,
+    partition_serial_list: Option<Vec<String>>,
+}
+
+impl ModifyHapgRequestBuilder {
+    /// Sets the [`hapg_arn`](struct.ModifyHapgRequest.html#structfield.hapg_arn) member.
+    pub fn hapg_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.hapg_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`label`](struct.ModifyHapgRequest.html#structfield.label) member.
+    pub fn label<T: Into<String>>(mut self, value: T) -> Self {
+        self.label = Some(value.into());
+        self
+    }
+
+    /// Sets the [`partition_serial_list`](struct.ModifyHapgRequest.html#structfield.partition_serial_list) member.
+    pub fn partition_serial_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.partition_serial_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyHapgRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ModifyHapgRequest, ::rusoto_core::BuildError> {
+        Ok(ModifyHapgRequest {
+            hapg_arn: self
+                .hapg_arn
+                .ok_or(::rusoto_core::BuildError::missing("hapg_arn"))?,
+            label: self.label,
+            partition_serial_list: self.partition_serial_list,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ModifyHapgResponse {
@@ -533,6 +1386,76 @@ pub struct ModifyHsmRequest {
     pub syslog_ip: Option<String>,
 }
 
+impl ModifyHsmRequest {
+    /// Returns a builder for `ModifyHsmRequest`, checking its required members when built.
+    pub fn builder() -> ModifyHsmRequestBuilder {
+        ModifyHsmRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyHsmRequest`](struct.ModifyHsmRequest.html).
+#[derive(Default, Debug)]
+pub struct ModifyHsmRequestBuilder {
+    eni_ip: Option<String>,
+    external_id: Option<String>,
+    hsm_arn: Option<String>,
+    iam_role_arn: Option<String>,
+    subnet_id: Option<String>,
+    syslog_ip: Option<String>,
+}
+
+impl ModifyHsmRequestBuilder {
+    /// Sets the [`eni_ip`](struct.ModifyHsmRequest.html#structfield.eni_ip) member.
+    pub fn eni_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.eni_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`external_id`](struct.ModifyHsmRequest.html#structfield.external_id) member.
+    pub fn external_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.external_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_arn`](struct.ModifyHsmRequest.html#structfield.hsm_arn) member.
+    pub fn hsm_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_role_arn`](struct.ModifyHsmRequest.html#structfield.iam_role_arn) member.
+    pub fn iam_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_id`](struct.ModifyHsmRequest.html#structfield.subnet_id) member.
+    pub fn subnet_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subnet_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`syslog_ip`](struct.ModifyHsmRequest.html#structfield.syslog_ip) member.
+    pub fn syslog_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.syslog_ip = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyHsmRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ModifyHsmRequest, ::rusoto_core::BuildError> {
+        Ok(ModifyHsmRequest {
+            eni_ip: self.eni_ip,
+            external_id: self.external_id,
+            hsm_arn: self
+                .hsm_arn
+                .ok_or(::rusoto_core::BuildError::missing("hsm_arn"))?,
+            iam_role_arn: self.iam_role_arn,
+            subnet_id: self.subnet_id,
+            syslog_ip: self.syslog_ip,
+        })
+    }
+}
+
 /// <p>Contains the output of the <a>ModifyHsm</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -553,6 +1476,48 @@ pub struct ModifyLunaClientRequest {
     pub client_arn: String,
 }
 
+impl ModifyLunaClientRequest {
+    /// Returns a builder for `ModifyLunaClientRequest`, checking its required members when built.
+    pub fn builder() -> ModifyLunaClientRequestBuilder {
+        ModifyLunaClientRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyLunaClientRequest`](struct.ModifyLunaClientRequest.html).
+#[derive(Default, Debug)]
+pub struct ModifyLunaClientRequestBuilder {
+    certificate: Option<String>,
+    client_arn: Option<String>,
+}
+
+impl ModifyLunaClientRequestBuilder {
+    /// Sets the [`certificate`](struct.ModifyLunaClientRequest.html#structfield.certificate) member.
+    pub fn certificate<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_arn`](struct.ModifyLunaClientRequest.html#structfield.client_arn) member.
+    pub fn client_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyLunaClientRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyLunaClientRequest, ::rusoto_core::BuildError> {
+        Ok(ModifyLunaClientRequest {
+            certificate: self
+                .certificate
+                .ok_or(::rusoto_core::BuildError::missing("certificate"))?,
+            client_arn: self
+                .client_arn
+                .ok_or(::rusoto_core::BuildError::missing("client_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ModifyLunaClientResponse {
@@ -572,6 +1537,48 @@ pub struct RemoveTagsFromResourceRequest {
     pub tag_key_list: Vec<String>,
 }
 
+impl RemoveTagsFromResourceRequest {
+    /// Returns a builder for `RemoveTagsFromResourceRequest`, checking its required members when built.
+    pub fn builder() -> RemoveTagsFromResourceRequestBuilder {
+        RemoveTagsFromResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsFromResourceRequest`](struct.RemoveTagsFromResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsFromResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_key_list: Option<Vec<String>>,
+}
+
+impl RemoveTagsFromResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.RemoveTagsFromResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_key_list`](struct.RemoveTagsFromResourceRequest.html#structfield.tag_key_list) member.
+    pub fn tag_key_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_key_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsFromResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveTagsFromResourceRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsFromResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_key_list: self
+                .tag_key_list
+                .ok_or(::rusoto_core::BuildError::missing("tag_key_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RemoveTagsFromResourceResponse {
@@ -580,6 +1587,74 @@ pub struct RemoveTagsFromResourceResponse {
     pub status: String,
 }
 
+/// <p>Specifies the type of subscription for the HSM.</p> <ul> <li> <p> <b>PRODUCTION</b> - The HSM is being used in a production environment.</p> </li> <li> <p> <b>TRIAL</b> - The HSM is being used in a product trial.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SubscriptionType {
+    /// <p><code>PRODUCTION</code></p>
+    Production,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SubscriptionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SubscriptionType::Production => "PRODUCTION",
+            SubscriptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SubscriptionType {
+    fn from(value: &'a str) -> SubscriptionType {
+        match value {
+            "PRODUCTION" => SubscriptionType::Production,
+            _ => SubscriptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SubscriptionType {
+    fn from(value: String) -> SubscriptionType {
+        SubscriptionType::from(value.as_str())
+    }
+}
+
+impl From<SubscriptionType> for String {
+    fn from(value: SubscriptionType) -> String {
+        match value {
+            SubscriptionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SubscriptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SubscriptionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SubscriptionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SubscriptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SubscriptionType::from(value))
+    }
+}
+
 /// <p>A key-value pair that identifies or specifies metadata about an AWS CloudHSM resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -1605,6 +2680,131 @@ pub trait CloudHsm {
         input: RemoveTagsFromResourceRequest,
     ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError>;
 }
+impl<T: ?Sized + CloudHsm> CloudHsm for ::std::sync::Arc<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResponse, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn create_hapg(
+        &self,
+        input: CreateHapgRequest,
+    ) -> RusotoFuture<CreateHapgResponse, CreateHapgError> {
+        (**self).create_hapg(input)
+    }
+    fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> RusotoFuture<CreateHsmResponse, CreateHsmError> {
+        (**self).create_hsm(input)
+    }
+    fn create_luna_client(
+        &self,
+        input: CreateLunaClientRequest,
+    ) -> RusotoFuture<CreateLunaClientResponse, CreateLunaClientError> {
+        (**self).create_luna_client(input)
+    }
+    fn delete_hapg(
+        &self,
+        input: DeleteHapgRequest,
+    ) -> RusotoFuture<DeleteHapgResponse, DeleteHapgError> {
+        (**self).delete_hapg(input)
+    }
+    fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> RusotoFuture<DeleteHsmResponse, DeleteHsmError> {
+        (**self).delete_hsm(input)
+    }
+    fn delete_luna_client(
+        &self,
+        input: DeleteLunaClientRequest,
+    ) -> RusotoFuture<DeleteLunaClientResponse, DeleteLunaClientError> {
+        (**self).delete_luna_client(input)
+    }
+    fn describe_hapg(
+        &self,
+        input: DescribeHapgRequest,
+    ) -> RusotoFuture<DescribeHapgResponse, DescribeHapgError> {
+        (**self).describe_hapg(input)
+    }
+    fn describe_hsm(
+        &self,
+        input: DescribeHsmRequest,
+    ) -> RusotoFuture<DescribeHsmResponse, DescribeHsmError> {
+        (**self).describe_hsm(input)
+    }
+    fn describe_luna_client(
+        &self,
+        input: DescribeLunaClientRequest,
+    ) -> RusotoFuture<DescribeLunaClientResponse, DescribeLunaClientError> {
+        (**self).describe_luna_client(input)
+    }
+    fn get_config(
+        &self,
+        input: GetConfigRequest,
+    ) -> RusotoFuture<GetConfigResponse, GetConfigError> {
+        (**self).get_config(input)
+    }
+    fn list_available_zones(
+        &self,
+    ) -> RusotoFuture<ListAvailableZonesResponse, ListAvailableZonesError> {
+        (**self).list_available_zones()
+    }
+    fn list_hapgs(
+        &self,
+        input: ListHapgsRequest,
+    ) -> RusotoFuture<ListHapgsResponse, ListHapgsError> {
+        (**self).list_hapgs(input)
+    }
+    fn list_hsms(&self, input: ListHsmsRequest) -> RusotoFuture<ListHsmsResponse, ListHsmsError> {
+        (**self).list_hsms(input)
+    }
+    fn list_luna_clients(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> RusotoFuture<ListLunaClientsResponse, ListLunaClientsError> {
+        (**self).list_luna_clients(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_hapg(
+        &self,
+        input: ModifyHapgRequest,
+    ) -> RusotoFuture<ModifyHapgResponse, ModifyHapgError> {
+        (**self).modify_hapg(input)
+    }
+    fn modify_hsm(
+        &self,
+        input: ModifyHsmRequest,
+    ) -> RusotoFuture<ModifyHsmResponse, ModifyHsmError> {
+        (**self).modify_hsm(input)
+    }
+    fn modify_luna_client(
+        &self,
+        input: ModifyLunaClientRequest,
+    ) -> RusotoFuture<ModifyLunaClientResponse, ModifyLunaClientError> {
+        (**self).modify_luna_client(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+}
+/// The error codes CloudHSM retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the CloudHSM API.
 #[derive(Clone)]
 pub struct CloudHsmClient {
@@ -1620,6 +2820,27 @@ impl CloudHsmClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CLOUDHSM`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CloudHsmClient {
+        Self::new(region::Region::from_env_for_service("CLOUDHSM"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `cloudhsm`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CloudHsmClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("cloudhsm", "CLOUDHSM"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1638,7 +2859,54 @@ impl CloudHsmClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmClient {
-        CloudHsmClient { client, region }
+        CloudHsmClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "cloudhsm")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "cloudhsm",
+            "cloudhsm",
+            "CLOUDHSM",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CloudHsm` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CloudHsm + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CloudHsmClient {
+    fn service_name(&self) -> &'static str {
+        "CloudHSM"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "cloudhsm"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CloudHsmClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS CloudHSM V2 clients.
+//!
+//! Makes a single read-only `DescribeBackups` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_cloudhsmv2::{CloudHsmv2, CloudHsmv2Client, DescribeBackupsRequest};
+
+fn main() {
+    let client = CloudHsmv2Client::from_env();
+    match client
+        .describe_backups(DescribeBackupsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeBackups succeeded"),
+        Err(error) => {
+            eprintln!("DescribeBackups failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
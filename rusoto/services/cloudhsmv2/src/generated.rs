@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Contains information about a backup of an AWS CloudHSM cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -33,22 +34,25 @@ pub struct Backup {
     /// <p>The state of the backup.</p>
     #[serde(rename = "BackupState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_state: Option<String>,
+    pub backup_state: Option<BackupState>,
     /// <p>The identifier (ID) of the cluster that was backed up.</p>
     #[serde(rename = "ClusterId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cluster_id: Option<String>,
     #[serde(rename = "CopyTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub copy_timestamp: Option<f64>,
+    pub copy_timestamp: Option<EpochTimestamp>,
     /// <p>The date and time when the backup was created.</p>
     #[serde(rename = "CreateTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_timestamp: Option<f64>,
+    pub create_timestamp: Option<EpochTimestamp>,
     /// <p>The date and time when the backup will be permanently deleted.</p>
     #[serde(rename = "DeleteTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delete_timestamp: Option<f64>,
+    pub delete_timestamp: Option<EpochTimestamp>,
     #[serde(rename = "SourceBackup")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_backup: Option<String>,
@@ -60,6 +64,154 @@ pub struct Backup {
     pub source_region: Option<String>,
 }
 
+/// <p>The cluster's backup policy.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BackupPolicy {
+    /// <p><code>DEFAULT</code></p>
+    Default,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BackupPolicy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupPolicy::Default => "DEFAULT",
+            BackupPolicy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BackupPolicy {
+    fn from(value: &'a str) -> BackupPolicy {
+        match value {
+            "DEFAULT" => BackupPolicy::Default,
+            _ => BackupPolicy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupPolicy {
+    fn from(value: String) -> BackupPolicy {
+        BackupPolicy::from(value.as_str())
+    }
+}
+
+impl From<BackupPolicy> for String {
+    fn from(value: BackupPolicy) -> String {
+        match value {
+            BackupPolicy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BackupPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BackupPolicy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupPolicy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BackupPolicy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BackupPolicy::from(value))
+    }
+}
+
+/// <p>The state of the backup.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BackupState {
+    /// <p><code>CREATE_IN_PROGRESS</code></p>
+    CreateInProgress,
+    /// <p><code>READY</code></p>
+    Ready,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// <p><code>PENDING_DELETION</code></p>
+    PendingDeletion,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BackupState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupState::CreateInProgress => "CREATE_IN_PROGRESS",
+            BackupState::Ready => "READY",
+            BackupState::Deleted => "DELETED",
+            BackupState::PendingDeletion => "PENDING_DELETION",
+            BackupState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BackupState {
+    fn from(value: &'a str) -> BackupState {
+        match value {
+            "CREATE_IN_PROGRESS" => BackupState::CreateInProgress,
+            "READY" => BackupState::Ready,
+            "DELETED" => BackupState::Deleted,
+            "PENDING_DELETION" => BackupState::PendingDeletion,
+            _ => BackupState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupState {
+    fn from(value: String) -> BackupState {
+        BackupState::from(value.as_str())
+    }
+}
+
+impl From<BackupState> for String {
+    fn from(value: BackupState) -> String {
+        match value {
+            BackupState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BackupState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BackupState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BackupState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BackupState::from(value))
+    }
+}
+
 /// <p>Contains one or more certificates or a certificate signing request (CSR).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -93,7 +245,7 @@ pub struct Cluster {
     /// <p>The cluster's backup policy.</p>
     #[serde(rename = "BackupPolicy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_policy: Option<String>,
+    pub backup_policy: Option<BackupPolicy>,
     /// <p>Contains one or more certificates or a certificate signing request (CSR).</p>
     #[serde(rename = "Certificates")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -104,8 +256,9 @@ pub struct Cluster {
     pub cluster_id: Option<String>,
     /// <p>The date and time when the cluster was created.</p>
     #[serde(rename = "CreateTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_timestamp: Option<f64>,
+    pub create_timestamp: Option<EpochTimestamp>,
     /// <p>The type of HSM that the cluster contains.</p>
     #[serde(rename = "HsmType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -129,7 +282,7 @@ pub struct Cluster {
     /// <p>The cluster's state.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ClusterState>,
     /// <p>A description of the cluster's state.</p>
     #[serde(rename = "StateMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -144,6 +297,106 @@ pub struct Cluster {
     pub vpc_id: Option<String>,
 }
 
+/// <p>The cluster's state.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClusterState {
+    /// <p><code>CREATE_IN_PROGRESS</code></p>
+    CreateInProgress,
+    /// <p><code>UNINITIALIZED</code></p>
+    Uninitialized,
+    /// <p><code>INITIALIZE_IN_PROGRESS</code></p>
+    InitializeInProgress,
+    /// <p><code>INITIALIZED</code></p>
+    Initialized,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>UPDATE_IN_PROGRESS</code></p>
+    UpdateInProgress,
+    /// <p><code>DELETE_IN_PROGRESS</code></p>
+    DeleteInProgress,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// <p><code>DEGRADED</code></p>
+    Degraded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ClusterState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterState::CreateInProgress => "CREATE_IN_PROGRESS",
+            ClusterState::Uninitialized => "UNINITIALIZED",
+            ClusterState::InitializeInProgress => "INITIALIZE_IN_PROGRESS",
+            ClusterState::Initialized => "INITIALIZED",
+            ClusterState::Active => "ACTIVE",
+            ClusterState::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            ClusterState::DeleteInProgress => "DELETE_IN_PROGRESS",
+            ClusterState::Deleted => "DELETED",
+            ClusterState::Degraded => "DEGRADED",
+            ClusterState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ClusterState {
+    fn from(value: &'a str) -> ClusterState {
+        match value {
+            "CREATE_IN_PROGRESS" => ClusterState::CreateInProgress,
+            "UNINITIALIZED" => ClusterState::Uninitialized,
+            "INITIALIZE_IN_PROGRESS" => ClusterState::InitializeInProgress,
+            "INITIALIZED" => ClusterState::Initialized,
+            "ACTIVE" => ClusterState::Active,
+            "UPDATE_IN_PROGRESS" => ClusterState::UpdateInProgress,
+            "DELETE_IN_PROGRESS" => ClusterState::DeleteInProgress,
+            "DELETED" => ClusterState::Deleted,
+            "DEGRADED" => ClusterState::Degraded,
+            _ => ClusterState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterState {
+    fn from(value: String) -> ClusterState {
+        ClusterState::from(value.as_str())
+    }
+}
+
+impl From<ClusterState> for String {
+    fn from(value: ClusterState) -> String {
+        match value {
+            ClusterState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ClusterState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ClusterState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ClusterState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ClusterState::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CopyBackupToRegionRequest {
     /// <p>The ID of the backup that will be copied to the destination region. </p>
@@ -154,6 +407,48 @@ pub struct CopyBackupToRegionRequest {
     pub destination_region: String,
 }
 
+impl CopyBackupToRegionRequest {
+    /// Returns a builder for `CopyBackupToRegionRequest`, checking its required members when built.
+    pub fn builder() -> CopyBackupToRegionRequestBuilder {
+        CopyBackupToRegionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CopyBackupToRegionRequest`](struct.CopyBackupToRegionRequest.html).
+#[derive(Default, Debug)]
+pub struct CopyBackupToRegionRequestBuilder {
+    backup_id: Option<String>,
+    destination_region: Option<String>,
+}
+
+impl CopyBackupToRegionRequestBuilder {
+    /// Sets the [`backup_id`](struct.CopyBackupToRegionRequest.html#structfield.backup_id) member.
+    pub fn backup_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_region`](struct.CopyBackupToRegionRequest.html#structfield.destination_region) member.
+    pub fn destination_region<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_region = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyBackupToRegionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CopyBackupToRegionRequest, ::rusoto_core::BuildError> {
+        Ok(CopyBackupToRegionRequest {
+            backup_id: self
+                .backup_id
+                .ok_or(::rusoto_core::BuildError::missing("backup_id"))?,
+            destination_region: self
+                .destination_region
+                .ok_or(::rusoto_core::BuildError::missing("destination_region"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CopyBackupToRegionResponse {
@@ -177,6 +472,54 @@ pub struct CreateClusterRequest {
     pub subnet_ids: Vec<String>,
 }
 
+impl CreateClusterRequest {
+    /// Returns a builder for `CreateClusterRequest`, checking its required members when built.
+    pub fn builder() -> CreateClusterRequestBuilder {
+        CreateClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateClusterRequest`](struct.CreateClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateClusterRequestBuilder {
+    hsm_type: Option<String>,
+    source_backup_id: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+}
+
+impl CreateClusterRequestBuilder {
+    /// Sets the [`hsm_type`](struct.CreateClusterRequest.html#structfield.hsm_type) member.
+    pub fn hsm_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_backup_id`](struct.CreateClusterRequest.html#structfield.source_backup_id) member.
+    pub fn source_backup_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_backup_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.CreateClusterRequest.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateClusterRequest, ::rusoto_core::BuildError> {
+        Ok(CreateClusterRequest {
+            hsm_type: self
+                .hsm_type
+                .ok_or(::rusoto_core::BuildError::missing("hsm_type"))?,
+            source_backup_id: self.source_backup_id,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateClusterResponse {
@@ -200,6 +543,54 @@ pub struct CreateHsmRequest {
     pub ip_address: Option<String>,
 }
 
+impl CreateHsmRequest {
+    /// Returns a builder for `CreateHsmRequest`, checking its required members when built.
+    pub fn builder() -> CreateHsmRequestBuilder {
+        CreateHsmRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateHsmRequest`](struct.CreateHsmRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateHsmRequestBuilder {
+    availability_zone: Option<String>,
+    cluster_id: Option<String>,
+    ip_address: Option<String>,
+}
+
+impl CreateHsmRequestBuilder {
+    /// Sets the [`availability_zone`](struct.CreateHsmRequest.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_id`](struct.CreateHsmRequest.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ip_address`](struct.CreateHsmRequest.html#structfield.ip_address) member.
+    pub fn ip_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.ip_address = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateHsmRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateHsmRequest, ::rusoto_core::BuildError> {
+        Ok(CreateHsmRequest {
+            availability_zone: self
+                .availability_zone
+                .ok_or(::rusoto_core::BuildError::missing("availability_zone"))?,
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            ip_address: self.ip_address,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateHsmResponse {
@@ -216,6 +607,36 @@ pub struct DeleteBackupRequest {
     pub backup_id: String,
 }
 
+impl DeleteBackupRequest {
+    /// Returns a builder for `DeleteBackupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBackupRequestBuilder {
+        DeleteBackupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBackupRequest`](struct.DeleteBackupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBackupRequestBuilder {
+    backup_id: Option<String>,
+}
+
+impl DeleteBackupRequestBuilder {
+    /// Sets the [`backup_id`](struct.DeleteBackupRequest.html#structfield.backup_id) member.
+    pub fn backup_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBackupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteBackupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBackupRequest {
+            backup_id: self
+                .backup_id
+                .ok_or(::rusoto_core::BuildError::missing("backup_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteBackupResponse {
@@ -232,6 +653,36 @@ pub struct DeleteClusterRequest {
     pub cluster_id: String,
 }
 
+impl DeleteClusterRequest {
+    /// Returns a builder for `DeleteClusterRequest`, checking its required members when built.
+    pub fn builder() -> DeleteClusterRequestBuilder {
+        DeleteClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteClusterRequest`](struct.DeleteClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteClusterRequestBuilder {
+    cluster_id: Option<String>,
+}
+
+impl DeleteClusterRequestBuilder {
+    /// Sets the [`cluster_id`](struct.DeleteClusterRequest.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteClusterRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteClusterRequest {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteClusterResponse {
@@ -260,6 +711,60 @@ pub struct DeleteHsmRequest {
     pub hsm_id: Option<String>,
 }
 
+impl DeleteHsmRequest {
+    /// Returns a builder for `DeleteHsmRequest`, checking its required members when built.
+    pub fn builder() -> DeleteHsmRequestBuilder {
+        DeleteHsmRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteHsmRequest`](struct.DeleteHsmRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteHsmRequestBuilder {
+    cluster_id: Option<String>,
+    eni_id: Option<String>,
+    eni_ip: Option<String>,
+    hsm_id: Option<String>,
+}
+
+impl DeleteHsmRequestBuilder {
+    /// Sets the [`cluster_id`](struct.DeleteHsmRequest.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`eni_id`](struct.DeleteHsmRequest.html#structfield.eni_id) member.
+    pub fn eni_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.eni_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`eni_ip`](struct.DeleteHsmRequest.html#structfield.eni_ip) member.
+    pub fn eni_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.eni_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_id`](struct.DeleteHsmRequest.html#structfield.hsm_id) member.
+    pub fn hsm_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteHsmRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteHsmRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteHsmRequest {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            eni_id: self.eni_id,
+            eni_ip: self.eni_ip,
+            hsm_id: self.hsm_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteHsmResponse {
@@ -288,6 +793,61 @@ pub struct DescribeBackupsRequest {
     pub sort_ascending: Option<bool>,
 }
 
+impl DescribeBackupsRequest {
+    /// Returns a builder for `DescribeBackupsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeBackupsRequestBuilder {
+        DescribeBackupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBackupsRequest`](struct.DescribeBackupsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeBackupsRequestBuilder {
+    filters: Option<::std::collections::HashMap<String, Vec<String>>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_ascending: Option<bool>,
+}
+
+impl DescribeBackupsRequestBuilder {
+    /// Sets the [`filters`](struct.DescribeBackupsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<::std::collections::HashMap<String, Vec<String>>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeBackupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeBackupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_ascending`](struct.DescribeBackupsRequest.html#structfield.sort_ascending) member.
+    pub fn sort_ascending<T: Into<bool>>(mut self, value: T) -> Self {
+        self.sort_ascending = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBackupsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeBackupsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeBackupsRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_ascending: self.sort_ascending,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeBackupsResponse {
@@ -317,6 +877,55 @@ pub struct DescribeClustersRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeClustersRequest {
+    /// Returns a builder for `DescribeClustersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeClustersRequestBuilder {
+        DescribeClustersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClustersRequest`](struct.DescribeClustersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeClustersRequestBuilder {
+    filters: Option<::std::collections::HashMap<String, Vec<String>>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeClustersRequestBuilder {
+    /// Sets the [`filters`](struct.DescribeClustersRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<::std::collections::HashMap<String, Vec<String>>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeClustersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeClustersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClustersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClustersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeClustersRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeClustersResponse {
@@ -334,8 +943,9 @@ pub struct DescribeClustersResponse {
 #[cfg_attr(test, derive(Serialize))]
 pub struct DestinationBackup {
     #[serde(rename = "CreateTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_timestamp: Option<f64>,
+    pub create_timestamp: Option<EpochTimestamp>,
     #[serde(rename = "SourceBackup")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_backup: Option<String>,
@@ -373,7 +983,7 @@ pub struct Hsm {
     /// <p>The HSM's state.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<HsmState>,
     /// <p>A description of the HSM's state.</p>
     #[serde(rename = "StateMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -384,6 +994,90 @@ pub struct Hsm {
     pub subnet_id: Option<String>,
 }
 
+/// <p>The HSM's state.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HsmState {
+    /// <p><code>CREATE_IN_PROGRESS</code></p>
+    CreateInProgress,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DEGRADED</code></p>
+    Degraded,
+    /// <p><code>DELETE_IN_PROGRESS</code></p>
+    DeleteInProgress,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HsmState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HsmState::CreateInProgress => "CREATE_IN_PROGRESS",
+            HsmState::Active => "ACTIVE",
+            HsmState::Degraded => "DEGRADED",
+            HsmState::DeleteInProgress => "DELETE_IN_PROGRESS",
+            HsmState::Deleted => "DELETED",
+            HsmState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HsmState {
+    fn from(value: &'a str) -> HsmState {
+        match value {
+            "CREATE_IN_PROGRESS" => HsmState::CreateInProgress,
+            "ACTIVE" => HsmState::Active,
+            "DEGRADED" => HsmState::Degraded,
+            "DELETE_IN_PROGRESS" => HsmState::DeleteInProgress,
+            "DELETED" => HsmState::Deleted,
+            _ => HsmState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HsmState {
+    fn from(value: String) -> HsmState {
+        HsmState::from(value.as_str())
+    }
+}
+
+impl From<HsmState> for String {
+    fn from(value: HsmState) -> String {
+        match value {
+            HsmState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HsmState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HsmState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HsmState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HsmState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HsmState::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct InitializeClusterRequest {
     /// <p>The identifier (ID) of the cluster that you are claiming. To find the cluster ID, use <a>DescribeClusters</a>.</p>
@@ -397,13 +1091,65 @@ pub struct InitializeClusterRequest {
     pub trust_anchor: String,
 }
 
+impl InitializeClusterRequest {
+    /// Returns a builder for `InitializeClusterRequest`, checking its required members when built.
+    pub fn builder() -> InitializeClusterRequestBuilder {
+        InitializeClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InitializeClusterRequest`](struct.InitializeClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct InitializeClusterRequestBuilder {
+    cluster_id: Option<String>,
+    signed_cert: Option<String>,
+    trust_anchor: Option<String>,
+}
+
+impl InitializeClusterRequestBuilder {
+    /// Sets the [`cluster_id`](struct.InitializeClusterRequest.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`signed_cert`](struct.InitializeClusterRequest.html#structfield.signed_cert) member.
+    pub fn signed_cert<T: Into<String>>(mut self, value: T) -> Self {
+        self.signed_cert = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trust_anchor`](struct.InitializeClusterRequest.html#structfield.trust_anchor) member.
+    pub fn trust_anchor<T: Into<String>>(mut self, value: T) -> Self {
+        self.trust_anchor = Some(value.into());
+        self
+    }
+
+    /// Builds the `InitializeClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<InitializeClusterRequest, ::rusoto_core::BuildError> {
+        Ok(InitializeClusterRequest {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            signed_cert: self
+                .signed_cert
+                .ok_or(::rusoto_core::BuildError::missing("signed_cert"))?,
+            trust_anchor: self
+                .trust_anchor
+                .ok_or(::rusoto_core::BuildError::missing("trust_anchor"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct InitializeClusterResponse {
     /// <p>The cluster's state.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ClusterState>,
     /// <p>A description of the cluster's state.</p>
     #[serde(rename = "StateMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -425,6 +1171,52 @@ pub struct ListTagsRequest {
     pub resource_id: String,
 }
 
+impl ListTagsRequest {
+    /// Returns a builder for `ListTagsRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsRequestBuilder {
+        ListTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsRequest`](struct.ListTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl ListTagsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListTagsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.ListTagsRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTagsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsResponse {
@@ -444,6 +1236,36 @@ pub struct RestoreBackupRequest {
     pub backup_id: String,
 }
 
+impl RestoreBackupRequest {
+    /// Returns a builder for `RestoreBackupRequest`, checking its required members when built.
+    pub fn builder() -> RestoreBackupRequestBuilder {
+        RestoreBackupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreBackupRequest`](struct.RestoreBackupRequest.html).
+#[derive(Default, Debug)]
+pub struct RestoreBackupRequestBuilder {
+    backup_id: Option<String>,
+}
+
+impl RestoreBackupRequestBuilder {
+    /// Sets the [`backup_id`](struct.RestoreBackupRequest.html#structfield.backup_id) member.
+    pub fn backup_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreBackupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RestoreBackupRequest, ::rusoto_core::BuildError> {
+        Ok(RestoreBackupRequest {
+            backup_id: self
+                .backup_id
+                .ok_or(::rusoto_core::BuildError::missing("backup_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RestoreBackupResponse {
@@ -474,6 +1296,46 @@ pub struct TagResourceRequest {
     pub tag_list: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_id: Option<String>,
+    tag_list: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_id`](struct.TagResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_list`](struct.TagResourceRequest.html#structfield.tag_list) member.
+    pub fn tag_list<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tag_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tag_list: self
+                .tag_list
+                .ok_or(::rusoto_core::BuildError::missing("tag_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -488,6 +1350,46 @@ pub struct UntagResourceRequest {
     pub tag_key_list: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_id: Option<String>,
+    tag_key_list: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_id`](struct.UntagResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_key_list`](struct.UntagResourceRequest.html#structfield.tag_key_list) member.
+    pub fn tag_key_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_key_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tag_key_list: self
+                .tag_key_list
+                .ok_or(::rusoto_core::BuildError::missing("tag_key_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -1364,6 +2266,90 @@ pub trait CloudHsmv2 {
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
 }
+impl<T: ?Sized + CloudHsmv2> CloudHsmv2 for ::std::sync::Arc<T> {
+    fn copy_backup_to_region(
+        &self,
+        input: CopyBackupToRegionRequest,
+    ) -> RusotoFuture<CopyBackupToRegionResponse, CopyBackupToRegionError> {
+        (**self).copy_backup_to_region(input)
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> RusotoFuture<CreateHsmResponse, CreateHsmError> {
+        (**self).create_hsm(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> RusotoFuture<DeleteHsmResponse, DeleteHsmError> {
+        (**self).delete_hsm(input)
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        (**self).describe_backups(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn initialize_cluster(
+        &self,
+        input: InitializeClusterRequest,
+    ) -> RusotoFuture<InitializeClusterResponse, InitializeClusterError> {
+        (**self).initialize_cluster(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn restore_backup(
+        &self,
+        input: RestoreBackupRequest,
+    ) -> RusotoFuture<RestoreBackupResponse, RestoreBackupError> {
+        (**self).restore_backup(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// The error codes CloudHSM V2 retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the CloudHSM V2 API.
 #[derive(Clone)]
 pub struct CloudHsmv2Client {
@@ -1379,6 +2365,27 @@ impl CloudHsmv2Client {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CLOUDHSMV2`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CloudHsmv2Client {
+        Self::new(region::Region::from_env_for_service("CLOUDHSMV2"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `cloudhsmv2`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CloudHsmv2Client {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("cloudhsmv2", "CLOUDHSMV2"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1397,7 +2404,54 @@ impl CloudHsmv2Client {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmv2Client {
-        CloudHsmv2Client { client, region }
+        CloudHsmv2Client {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "cloudhsmv2")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "cloudhsmv2",
+            "cloudhsm",
+            "CLOUDHSMV2",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CloudHsmv2` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CloudHsmv2 + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CloudHsmv2Client {
+    fn service_name(&self) -> &'static str {
+        "CloudHSM V2"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "cloudhsmv2"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CloudHsmv2Client {
+        Self::new_with_client(client, region)
     }
 }
 
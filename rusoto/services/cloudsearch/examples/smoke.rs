@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon CloudSearch clients.
+//!
+//! Makes a single read-only `ListDomainNames` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_cloudsearch::{CloudSearch, CloudSearchClient};
+
+fn main() {
+    let client = CloudSearchClient::from_env();
+    match client.list_domain_names().sync() {
+        Ok(_) => println!("ListDomainNames succeeded"),
+        Err(error) => {
+            eprintln!("ListDomainNames failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -28,15 +28,19 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
 use xml::EventReader;
 
-struct APIVersionDeserializer;
+pub(crate) struct APIVersionDeserializer;
 impl APIVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -44,10 +48,13 @@ impl APIVersionDeserializer {
         Ok(obj)
     }
 }
-struct ARNDeserializer;
+pub(crate) struct ARNDeserializer;
 impl ARNDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -56,49 +63,133 @@ impl ARNDeserializer {
     }
 }
 /// <p>The configured access rules for the domain's document and search endpoints, and the current status of those rules.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AccessPoliciesStatus {
     pub options: String,
     pub status: OptionStatus,
 }
 
-struct AccessPoliciesStatusDeserializer;
+pub(crate) struct AccessPoliciesStatusDeserializer;
 impl AccessPoliciesStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccessPoliciesStatus, XmlParseError> {
-        deserialize_elements::<_, AccessPoliciesStatus, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Options" => {
-                    obj.options = PolicyDocumentDeserializer::deserialize("Options", stack)?;
-                }
-                "Status" => {
-                    obj.status = OptionStatusDeserializer::deserialize("Status", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            AccessPoliciesStatus {
+                options: Default::default(),
+                status: OptionStatus {
+                    creation_date: Default::default(),
+                    pending_deletion: Default::default(),
+                    state: OptionState::Unknown(String::new()),
+                    update_date: Default::default(),
+                    update_version: Default::default(),
+                },
+            },
+            |name, stack, obj| {
+                match name {
+                    "Options" => {
+                        obj.options = PolicyDocumentDeserializer::deserialize("Options", stack)?;
+                    }
+                    "Status" => {
+                        obj.status = OptionStatusDeserializer::deserialize("Status", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct AlgorithmicStemmingDeserializer;
+pub(crate) struct AlgorithmicStemmingDeserializer;
 impl AlgorithmicStemmingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AlgorithmicStemming, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The level of algorithmic stemming to perform: <code>none</code>, <code>minimal</code>, <code>light</code>, or <code>full</code>. The available levels vary depending on the language. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/text-processing.html#text-processing-settings" target="_blank">Language Specific Text Processing Settings</a> in the <i>Amazon CloudSearch Developer Guide</i> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AlgorithmicStemming {
+    /// <p><code>none</code></p>
+    None,
+    /// <p><code>minimal</code></p>
+    Minimal,
+    /// <p><code>light</code></p>
+    Light,
+    /// <p><code>full</code></p>
+    Full,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AlgorithmicStemming {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AlgorithmicStemming::None => "none",
+            AlgorithmicStemming::Minimal => "minimal",
+            AlgorithmicStemming::Light => "light",
+            AlgorithmicStemming::Full => "full",
+            AlgorithmicStemming::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AlgorithmicStemming {
+    fn from(value: &'a str) -> AlgorithmicStemming {
+        match value {
+            "none" => AlgorithmicStemming::None,
+            "minimal" => AlgorithmicStemming::Minimal,
+            "light" => AlgorithmicStemming::Light,
+            "full" => AlgorithmicStemming::Full,
+            _ => AlgorithmicStemming::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AlgorithmicStemming {
+    fn from(value: String) -> AlgorithmicStemming {
+        AlgorithmicStemming::from(value.as_str())
+    }
+}
+
+impl From<AlgorithmicStemming> for String {
+    fn from(value: AlgorithmicStemming) -> String {
+        match value {
+            AlgorithmicStemming::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AlgorithmicStemming {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AlgorithmicStemming {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Synonyms, stopwords, and stemming options for an analysis scheme. Includes tokenization dictionary for Japanese.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AnalysisOptions {
     /// <p>The level of algorithmic stemming to perform: <code>none</code>, <code>minimal</code>, <code>light</code>, or <code>full</code>. The available levels vary depending on the language. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/text-processing.html#text-processing-settings" target="_blank">Language Specific Text Processing Settings</a> in the <i>Amazon CloudSearch Developer Guide</i> </p>
-    pub algorithmic_stemming: Option<String>,
+    pub algorithmic_stemming: Option<AlgorithmicStemming>,
     /// <p>A JSON array that contains a collection of terms, tokens, readings and part of speech for Japanese Tokenizaiton. The Japanese tokenization dictionary enables you to override the default tokenization for selected terms. This is only valid for Japanese language fields.</p>
     pub japanese_tokenization_dictionary: Option<String>,
     /// <p>A JSON object that contains a collection of string:value pairs that each map a term to its stem. For example, <code>{"term1": "stem1", "term2": "stem2", "term3": "stem3"}</code>. The stemming dictionary is applied in addition to any algorithmic stemming. This enables you to override the results of the algorithmic stemming to correct specific cases of overstemming or understemming. The maximum size of a stemming dictionary is 500 KB.</p>
@@ -109,10 +200,10 @@ pub struct AnalysisOptions {
     pub synonyms: Option<String>,
 }
 
-struct AnalysisOptionsDeserializer;
+pub(crate) struct AnalysisOptionsDeserializer;
 impl AnalysisOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AnalysisOptions, XmlParseError> {
@@ -183,42 +274,52 @@ impl AnalysisOptionsSerializer {
 }
 
 /// <p>Configuration information for an analysis scheme. Each analysis scheme has a unique name and specifies the language of the text to be processed. The following options can be configured for an analysis scheme: <code>Synonyms</code>, <code>Stopwords</code>, <code>StemmingDictionary</code>, <code>JapaneseTokenizationDictionary</code> and <code>AlgorithmicStemming</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AnalysisScheme {
     pub analysis_options: Option<AnalysisOptions>,
-    pub analysis_scheme_language: String,
+    pub analysis_scheme_language: AnalysisSchemeLanguage,
     pub analysis_scheme_name: String,
 }
 
-struct AnalysisSchemeDeserializer;
+pub(crate) struct AnalysisSchemeDeserializer;
 impl AnalysisSchemeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AnalysisScheme, XmlParseError> {
-        deserialize_elements::<_, AnalysisScheme, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "AnalysisOptions" => {
-                    obj.analysis_options = Some(AnalysisOptionsDeserializer::deserialize(
-                        "AnalysisOptions",
-                        stack,
-                    )?);
-                }
-                "AnalysisSchemeLanguage" => {
-                    obj.analysis_scheme_language = AnalysisSchemeLanguageDeserializer::deserialize(
-                        "AnalysisSchemeLanguage",
-                        stack,
-                    )?;
-                }
-                "AnalysisSchemeName" => {
-                    obj.analysis_scheme_name =
-                        StandardNameDeserializer::deserialize("AnalysisSchemeName", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            AnalysisScheme {
+                analysis_options: Default::default(),
+                analysis_scheme_language: AnalysisSchemeLanguage::Unknown(String::new()),
+                analysis_scheme_name: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "AnalysisOptions" => {
+                        obj.analysis_options = Some(AnalysisOptionsDeserializer::deserialize(
+                            "AnalysisOptions",
+                            stack,
+                        )?);
+                    }
+                    "AnalysisSchemeLanguage" => {
+                        obj.analysis_scheme_language =
+                            AnalysisSchemeLanguageDeserializer::deserialize(
+                                "AnalysisSchemeLanguage",
+                                stack,
+                            )?;
+                    }
+                    "AnalysisSchemeName" => {
+                        obj.analysis_scheme_name =
+                            StandardNameDeserializer::deserialize("AnalysisSchemeName", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -249,49 +350,261 @@ impl AnalysisSchemeSerializer {
     }
 }
 
-struct AnalysisSchemeLanguageDeserializer;
+pub(crate) struct AnalysisSchemeLanguageDeserializer;
 impl AnalysisSchemeLanguageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AnalysisSchemeLanguage, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>An <a href="http://tools.ietf.org/html/rfc4646" target="_blank">IETF RFC 4646</a> language code or <code>mul</code> for multiple languages.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AnalysisSchemeLanguage {
+    /// <p><code>ar</code></p>
+    Ar,
+    /// <p><code>bg</code></p>
+    Bg,
+    /// <p><code>ca</code></p>
+    Ca,
+    /// <p><code>cs</code></p>
+    Cs,
+    /// <p><code>da</code></p>
+    Da,
+    /// <p><code>de</code></p>
+    De,
+    /// <p><code>el</code></p>
+    El,
+    /// <p><code>en</code></p>
+    En,
+    /// <p><code>es</code></p>
+    Es,
+    /// <p><code>eu</code></p>
+    Eu,
+    /// <p><code>fa</code></p>
+    Fa,
+    /// <p><code>fi</code></p>
+    Fi,
+    /// <p><code>fr</code></p>
+    Fr,
+    /// <p><code>ga</code></p>
+    Ga,
+    /// <p><code>gl</code></p>
+    Gl,
+    /// <p><code>he</code></p>
+    He,
+    /// <p><code>hi</code></p>
+    Hi,
+    /// <p><code>hu</code></p>
+    Hu,
+    /// <p><code>hy</code></p>
+    Hy,
+    /// <p><code>id</code></p>
+    Id,
+    /// <p><code>it</code></p>
+    It,
+    /// <p><code>ja</code></p>
+    Ja,
+    /// <p><code>ko</code></p>
+    Ko,
+    /// <p><code>lv</code></p>
+    Lv,
+    /// <p><code>mul</code></p>
+    Mul,
+    /// <p><code>nl</code></p>
+    Nl,
+    /// <p><code>no</code></p>
+    No,
+    /// <p><code>pt</code></p>
+    Pt,
+    /// <p><code>ro</code></p>
+    Ro,
+    /// <p><code>ru</code></p>
+    Ru,
+    /// <p><code>sv</code></p>
+    Sv,
+    /// <p><code>th</code></p>
+    Th,
+    /// <p><code>tr</code></p>
+    Tr,
+    /// <p><code>zh-Hans</code></p>
+    ZhHans,
+    /// <p><code>zh-Hant</code></p>
+    ZhHant,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AnalysisSchemeLanguage {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AnalysisSchemeLanguage::Ar => "ar",
+            AnalysisSchemeLanguage::Bg => "bg",
+            AnalysisSchemeLanguage::Ca => "ca",
+            AnalysisSchemeLanguage::Cs => "cs",
+            AnalysisSchemeLanguage::Da => "da",
+            AnalysisSchemeLanguage::De => "de",
+            AnalysisSchemeLanguage::El => "el",
+            AnalysisSchemeLanguage::En => "en",
+            AnalysisSchemeLanguage::Es => "es",
+            AnalysisSchemeLanguage::Eu => "eu",
+            AnalysisSchemeLanguage::Fa => "fa",
+            AnalysisSchemeLanguage::Fi => "fi",
+            AnalysisSchemeLanguage::Fr => "fr",
+            AnalysisSchemeLanguage::Ga => "ga",
+            AnalysisSchemeLanguage::Gl => "gl",
+            AnalysisSchemeLanguage::He => "he",
+            AnalysisSchemeLanguage::Hi => "hi",
+            AnalysisSchemeLanguage::Hu => "hu",
+            AnalysisSchemeLanguage::Hy => "hy",
+            AnalysisSchemeLanguage::Id => "id",
+            AnalysisSchemeLanguage::It => "it",
+            AnalysisSchemeLanguage::Ja => "ja",
+            AnalysisSchemeLanguage::Ko => "ko",
+            AnalysisSchemeLanguage::Lv => "lv",
+            AnalysisSchemeLanguage::Mul => "mul",
+            AnalysisSchemeLanguage::Nl => "nl",
+            AnalysisSchemeLanguage::No => "no",
+            AnalysisSchemeLanguage::Pt => "pt",
+            AnalysisSchemeLanguage::Ro => "ro",
+            AnalysisSchemeLanguage::Ru => "ru",
+            AnalysisSchemeLanguage::Sv => "sv",
+            AnalysisSchemeLanguage::Th => "th",
+            AnalysisSchemeLanguage::Tr => "tr",
+            AnalysisSchemeLanguage::ZhHans => "zh-Hans",
+            AnalysisSchemeLanguage::ZhHant => "zh-Hant",
+            AnalysisSchemeLanguage::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AnalysisSchemeLanguage {
+    fn from(value: &'a str) -> AnalysisSchemeLanguage {
+        match value {
+            "ar" => AnalysisSchemeLanguage::Ar,
+            "bg" => AnalysisSchemeLanguage::Bg,
+            "ca" => AnalysisSchemeLanguage::Ca,
+            "cs" => AnalysisSchemeLanguage::Cs,
+            "da" => AnalysisSchemeLanguage::Da,
+            "de" => AnalysisSchemeLanguage::De,
+            "el" => AnalysisSchemeLanguage::El,
+            "en" => AnalysisSchemeLanguage::En,
+            "es" => AnalysisSchemeLanguage::Es,
+            "eu" => AnalysisSchemeLanguage::Eu,
+            "fa" => AnalysisSchemeLanguage::Fa,
+            "fi" => AnalysisSchemeLanguage::Fi,
+            "fr" => AnalysisSchemeLanguage::Fr,
+            "ga" => AnalysisSchemeLanguage::Ga,
+            "gl" => AnalysisSchemeLanguage::Gl,
+            "he" => AnalysisSchemeLanguage::He,
+            "hi" => AnalysisSchemeLanguage::Hi,
+            "hu" => AnalysisSchemeLanguage::Hu,
+            "hy" => AnalysisSchemeLanguage::Hy,
+            "id" => AnalysisSchemeLanguage::Id,
+            "it" => AnalysisSchemeLanguage::It,
+            "ja" => AnalysisSchemeLanguage::Ja,
+            "ko" => AnalysisSchemeLanguage::Ko,
+            "lv" => AnalysisSchemeLanguage::Lv,
+            "mul" => AnalysisSchemeLanguage::Mul,
+            "nl" => AnalysisSchemeLanguage::Nl,
+            "no" => AnalysisSchemeLanguage::No,
+            "pt" => AnalysisSchemeLanguage::Pt,
+            "ro" => AnalysisSchemeLanguage::Ro,
+            "ru" => AnalysisSchemeLanguage::Ru,
+            "sv" => AnalysisSchemeLanguage::Sv,
+            "th" => AnalysisSchemeLanguage::Th,
+            "tr" => AnalysisSchemeLanguage::Tr,
+            "zh-Hans" => AnalysisSchemeLanguage::ZhHans,
+            "zh-Hant" => AnalysisSchemeLanguage::ZhHant,
+            _ => AnalysisSchemeLanguage::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AnalysisSchemeLanguage {
+    fn from(value: String) -> AnalysisSchemeLanguage {
+        AnalysisSchemeLanguage::from(value.as_str())
+    }
+}
+
+impl From<AnalysisSchemeLanguage> for String {
+    fn from(value: AnalysisSchemeLanguage) -> String {
+        match value {
+            AnalysisSchemeLanguage::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AnalysisSchemeLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AnalysisSchemeLanguage {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>The status and configuration of an <code>AnalysisScheme</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AnalysisSchemeStatus {
     pub options: AnalysisScheme,
     pub status: OptionStatus,
 }
 
-struct AnalysisSchemeStatusDeserializer;
+pub(crate) struct AnalysisSchemeStatusDeserializer;
 impl AnalysisSchemeStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AnalysisSchemeStatus, XmlParseError> {
-        deserialize_elements::<_, AnalysisSchemeStatus, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Options" => {
-                    obj.options = AnalysisSchemeDeserializer::deserialize("Options", stack)?;
-                }
-                "Status" => {
-                    obj.status = OptionStatusDeserializer::deserialize("Status", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            AnalysisSchemeStatus {
+                options: AnalysisScheme {
+                    analysis_options: Default::default(),
+                    analysis_scheme_language: AnalysisSchemeLanguage::Unknown(String::new()),
+                    analysis_scheme_name: Default::default(),
+                },
+                status: OptionStatus {
+                    creation_date: Default::default(),
+                    pending_deletion: Default::default(),
+                    state: OptionState::Unknown(String::new()),
+                    update_date: Default::default(),
+                    update_version: Default::default(),
+                },
+            },
+            |name, stack, obj| {
+                match name {
+                    "Options" => {
+                        obj.options = AnalysisSchemeDeserializer::deserialize("Options", stack)?;
+                    }
+                    "Status" => {
+                        obj.status = OptionStatusDeserializer::deserialize("Status", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct AnalysisSchemeStatusListDeserializer;
+pub(crate) struct AnalysisSchemeStatusListDeserializer;
 impl AnalysisSchemeStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AnalysisSchemeStatus>, XmlParseError> {
@@ -308,23 +621,33 @@ impl AnalysisSchemeStatusListDeserializer {
     }
 }
 /// <p>The status and configuration of the domain's availability options.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AvailabilityOptionsStatus {
     /// <p>The availability options configured for the domain.</p>
     pub options: bool,
     pub status: OptionStatus,
 }
 
-struct AvailabilityOptionsStatusDeserializer;
+pub(crate) struct AvailabilityOptionsStatusDeserializer;
 impl AvailabilityOptionsStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AvailabilityOptionsStatus, XmlParseError> {
-        deserialize_elements::<_, AvailabilityOptionsStatus, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            AvailabilityOptionsStatus {
+                options: Default::default(),
+                status: OptionStatus {
+                    creation_date: Default::default(),
+                    pending_deletion: Default::default(),
+                    state: OptionState::Unknown(String::new()),
+                    update_date: Default::default(),
+                    update_version: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "Options" => {
@@ -340,10 +663,13 @@ impl AvailabilityOptionsStatusDeserializer {
         )
     }
 }
-struct BooleanDeserializer;
+pub(crate) struct BooleanDeserializer;
 impl BooleanDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -357,6 +683,36 @@ pub struct BuildSuggestersRequest {
     pub domain_name: String,
 }
 
+impl BuildSuggestersRequest {
+    /// Returns a builder for `BuildSuggestersRequest`, checking its required members when built.
+    pub fn builder() -> BuildSuggestersRequestBuilder {
+        BuildSuggestersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BuildSuggestersRequest`](struct.BuildSuggestersRequest.html).
+#[derive(Default, Debug)]
+pub struct BuildSuggestersRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl BuildSuggestersRequestBuilder {
+    /// Sets the [`domain_name`](struct.BuildSuggestersRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `BuildSuggestersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BuildSuggestersRequest, ::rusoto_core::BuildError> {
+        Ok(BuildSuggestersRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `BuildSuggestersRequest` contents to a `SignedRequest`.
 struct BuildSuggestersRequestSerializer;
 impl BuildSuggestersRequestSerializer {
@@ -376,10 +732,10 @@ pub struct BuildSuggestersResponse {
     pub field_names: Option<Vec<String>>,
 }
 
-struct BuildSuggestersResponseDeserializer;
+pub(crate) struct BuildSuggestersResponseDeserializer;
 impl BuildSuggestersResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<BuildSuggestersResponse, XmlParseError> {
@@ -407,6 +763,36 @@ pub struct CreateDomainRequest {
     pub domain_name: String,
 }
 
+impl CreateDomainRequest {
+    /// Returns a builder for `CreateDomainRequest`, checking its required members when built.
+    pub fn builder() -> CreateDomainRequestBuilder {
+        CreateDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDomainRequest`](struct.CreateDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDomainRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl CreateDomainRequestBuilder {
+    /// Sets the [`domain_name`](struct.CreateDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDomainRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDomainRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateDomainRequest` contents to a `SignedRequest`.
 struct CreateDomainRequestSerializer;
 impl CreateDomainRequestSerializer {
@@ -426,10 +812,10 @@ pub struct CreateDomainResponse {
     pub domain_status: Option<DomainStatus>,
 }
 
-struct CreateDomainResponseDeserializer;
+pub(crate) struct CreateDomainResponseDeserializer;
 impl CreateDomainResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDomainResponse, XmlParseError> {
@@ -462,10 +848,10 @@ pub struct DateArrayOptions {
     pub source_fields: Option<String>,
 }
 
-struct DateArrayOptionsDeserializer;
+pub(crate) struct DateArrayOptionsDeserializer;
 impl DateArrayOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DateArrayOptions, XmlParseError> {
@@ -543,10 +929,10 @@ pub struct DateOptions {
     pub source_field: Option<String>,
 }
 
-struct DateOptionsDeserializer;
+pub(crate) struct DateOptionsDeserializer;
 impl DateOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DateOptions, XmlParseError> {
@@ -614,12 +1000,54 @@ impl DateOptionsSerializer {
 }
 
 /// <p>Container for the parameters to the <code><a>DefineAnalysisScheme</a></code> operation. Specifies the name of the domain you want to update and the analysis scheme configuration.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DefineAnalysisSchemeRequest {
     pub analysis_scheme: AnalysisScheme,
     pub domain_name: String,
 }
 
+impl DefineAnalysisSchemeRequest {
+    /// Returns a builder for `DefineAnalysisSchemeRequest`, checking its required members when built.
+    pub fn builder() -> DefineAnalysisSchemeRequestBuilder {
+        DefineAnalysisSchemeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DefineAnalysisSchemeRequest`](struct.DefineAnalysisSchemeRequest.html).
+#[derive(Default, Debug)]
+pub struct DefineAnalysisSchemeRequestBuilder {
+    analysis_scheme: Option<AnalysisScheme>,
+    domain_name: Option<String>,
+}
+
+impl DefineAnalysisSchemeRequestBuilder {
+    /// Sets the [`analysis_scheme`](struct.DefineAnalysisSchemeRequest.html#structfield.analysis_scheme) member.
+    pub fn analysis_scheme<T: Into<AnalysisScheme>>(mut self, value: T) -> Self {
+        self.analysis_scheme = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DefineAnalysisSchemeRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DefineAnalysisSchemeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DefineAnalysisSchemeRequest, ::rusoto_core::BuildError> {
+        Ok(DefineAnalysisSchemeRequest {
+            analysis_scheme: self
+                .analysis_scheme
+                .ok_or(::rusoto_core::BuildError::missing("analysis_scheme"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `DefineAnalysisSchemeRequest` contents to a `SignedRequest`.
 struct DefineAnalysisSchemeRequestSerializer;
 impl DefineAnalysisSchemeRequestSerializer {
@@ -639,21 +1067,37 @@ impl DefineAnalysisSchemeRequestSerializer {
 }
 
 /// <p>The result of a <code><a>DefineAnalysisScheme</a></code> request. Contains the status of the newly-configured analysis scheme.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DefineAnalysisSchemeResponse {
     pub analysis_scheme: AnalysisSchemeStatus,
 }
 
-struct DefineAnalysisSchemeResponseDeserializer;
+pub(crate) struct DefineAnalysisSchemeResponseDeserializer;
 impl DefineAnalysisSchemeResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DefineAnalysisSchemeResponse, XmlParseError> {
-        deserialize_elements::<_, DefineAnalysisSchemeResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DefineAnalysisSchemeResponse {
+                analysis_scheme: AnalysisSchemeStatus {
+                    options: AnalysisScheme {
+                        analysis_options: Default::default(),
+                        analysis_scheme_language: AnalysisSchemeLanguage::Unknown(String::new()),
+                        analysis_scheme_name: Default::default(),
+                    },
+                    status: OptionStatus {
+                        creation_date: Default::default(),
+                        pending_deletion: Default::default(),
+                        state: OptionState::Unknown(String::new()),
+                        update_date: Default::default(),
+                        update_version: Default::default(),
+                    },
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "AnalysisScheme" => {
@@ -674,6 +1118,48 @@ pub struct DefineExpressionRequest {
     pub expression: Expression,
 }
 
+impl DefineExpressionRequest {
+    /// Returns a builder for `DefineExpressionRequest`, checking its required members when built.
+    pub fn builder() -> DefineExpressionRequestBuilder {
+        DefineExpressionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DefineExpressionRequest`](struct.DefineExpressionRequest.html).
+#[derive(Default, Debug)]
+pub struct DefineExpressionRequestBuilder {
+    domain_name: Option<String>,
+    expression: Option<Expression>,
+}
+
+impl DefineExpressionRequestBuilder {
+    /// Sets the [`domain_name`](struct.DefineExpressionRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression`](struct.DefineExpressionRequest.html#structfield.expression) member.
+    pub fn expression<T: Into<Expression>>(mut self, value: T) -> Self {
+        self.expression = Some(value.into());
+        self
+    }
+
+    /// Builds the `DefineExpressionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DefineExpressionRequest, ::rusoto_core::BuildError> {
+        Ok(DefineExpressionRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            expression: self
+                .expression
+                .ok_or(::rusoto_core::BuildError::missing("expression"))?,
+        })
+    }
+}
+
 /// Serialize `DefineExpressionRequest` contents to a `SignedRequest`.
 struct DefineExpressionRequestSerializer;
 impl DefineExpressionRequestSerializer {
@@ -693,21 +1179,33 @@ impl DefineExpressionRequestSerializer {
 }
 
 /// <p>The result of a <code>DefineExpression</code> request. Contains the status of the newly-configured expression.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DefineExpressionResponse {
     pub expression: ExpressionStatus,
 }
 
-struct DefineExpressionResponseDeserializer;
+pub(crate) struct DefineExpressionResponseDeserializer;
 impl DefineExpressionResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DefineExpressionResponse, XmlParseError> {
-        deserialize_elements::<_, DefineExpressionResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DefineExpressionResponse {
+                expression: ExpressionStatus {
+                    options: Default::default(),
+                    status: OptionStatus {
+                        creation_date: Default::default(),
+                        pending_deletion: Default::default(),
+                        state: OptionState::Unknown(String::new()),
+                        update_date: Default::default(),
+                        update_version: Default::default(),
+                    },
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "Expression" => {
@@ -722,13 +1220,55 @@ impl DefineExpressionResponseDeserializer {
     }
 }
 /// <p>Container for the parameters to the <code><a>DefineIndexField</a></code> operation. Specifies the name of the domain you want to update and the index field configuration.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DefineIndexFieldRequest {
     pub domain_name: String,
     /// <p>The index field and field options you want to configure. </p>
     pub index_field: IndexField,
 }
 
+impl DefineIndexFieldRequest {
+    /// Returns a builder for `DefineIndexFieldRequest`, checking its required members when built.
+    pub fn builder() -> DefineIndexFieldRequestBuilder {
+        DefineIndexFieldRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DefineIndexFieldRequest`](struct.DefineIndexFieldRequest.html).
+#[derive(Default, Debug)]
+pub struct DefineIndexFieldRequestBuilder {
+    domain_name: Option<String>,
+    index_field: Option<IndexField>,
+}
+
+impl DefineIndexFieldRequestBuilder {
+    /// Sets the [`domain_name`](struct.DefineIndexFieldRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`index_field`](struct.DefineIndexFieldRequest.html#structfield.index_field) member.
+    pub fn index_field<T: Into<IndexField>>(mut self, value: T) -> Self {
+        self.index_field = Some(value.into());
+        self
+    }
+
+    /// Builds the `DefineIndexFieldRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DefineIndexFieldRequest, ::rusoto_core::BuildError> {
+        Ok(DefineIndexFieldRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            index_field: self
+                .index_field
+                .ok_or(::rusoto_core::BuildError::missing("index_field"))?,
+        })
+    }
+}
+
 /// Serialize `DefineIndexFieldRequest` contents to a `SignedRequest`.
 struct DefineIndexFieldRequestSerializer;
 impl DefineIndexFieldRequestSerializer {
@@ -748,21 +1288,47 @@ impl DefineIndexFieldRequestSerializer {
 }
 
 /// <p>The result of a <code><a>DefineIndexField</a></code> request. Contains the status of the newly-configured index field.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DefineIndexFieldResponse {
     pub index_field: IndexFieldStatus,
 }
 
-struct DefineIndexFieldResponseDeserializer;
+pub(crate) struct DefineIndexFieldResponseDeserializer;
 impl DefineIndexFieldResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DefineIndexFieldResponse, XmlParseError> {
-        deserialize_elements::<_, DefineIndexFieldResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DefineIndexFieldResponse {
+                index_field: IndexFieldStatus {
+                    options: IndexField {
+                        date_array_options: Default::default(),
+                        date_options: Default::default(),
+                        double_array_options: Default::default(),
+                        double_options: Default::default(),
+                        index_field_name: Default::default(),
+                        index_field_type: IndexFieldType::Unknown(String::new()),
+                        int_array_options: Default::default(),
+                        int_options: Default::default(),
+                        lat_lon_options: Default::default(),
+                        literal_array_options: Default::default(),
+                        literal_options: Default::default(),
+                        text_array_options: Default::default(),
+                        text_options: Default::default(),
+                    },
+                    status: OptionStatus {
+                        creation_date: Default::default(),
+                        pending_deletion: Default::default(),
+                        state: OptionState::Unknown(String::new()),
+                        update_date: Default::default(),
+                        update_version: Default::default(),
+                    },
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "IndexField" => {
@@ -783,6 +1349,46 @@ pub struct DefineSuggesterRequest {
     pub suggester: Suggester,
 }
 
+impl DefineSuggesterRequest {
+    /// Returns a builder for `DefineSuggesterRequest`, checking its required members when built.
+    pub fn builder() -> DefineSuggesterRequestBuilder {
+        DefineSuggesterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DefineSuggesterRequest`](struct.DefineSuggesterRequest.html).
+#[derive(Default, Debug)]
+pub struct DefineSuggesterRequestBuilder {
+    domain_name: Option<String>,
+    suggester: Option<Suggester>,
+}
+
+impl DefineSuggesterRequestBuilder {
+    /// Sets the [`domain_name`](struct.DefineSuggesterRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`suggester`](struct.DefineSuggesterRequest.html#structfield.suggester) member.
+    pub fn suggester<T: Into<Suggester>>(mut self, value: T) -> Self {
+        self.suggester = Some(value.into());
+        self
+    }
+
+    /// Builds the `DefineSuggesterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DefineSuggesterRequest, ::rusoto_core::BuildError> {
+        Ok(DefineSuggesterRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            suggester: self
+                .suggester
+                .ok_or(::rusoto_core::BuildError::missing("suggester"))?,
+        })
+    }
+}
+
 /// Serialize `DefineSuggesterRequest` contents to a `SignedRequest`.
 struct DefineSuggesterRequestSerializer;
 impl DefineSuggesterRequestSerializer {
@@ -802,21 +1408,33 @@ impl DefineSuggesterRequestSerializer {
 }
 
 /// <p>The result of a <code>DefineSuggester</code> request. Contains the status of the newly-configured suggester.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DefineSuggesterResponse {
     pub suggester: SuggesterStatus,
 }
 
-struct DefineSuggesterResponseDeserializer;
+pub(crate) struct DefineSuggesterResponseDeserializer;
 impl DefineSuggesterResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DefineSuggesterResponse, XmlParseError> {
-        deserialize_elements::<_, DefineSuggesterResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DefineSuggesterResponse {
+                suggester: SuggesterStatus {
+                    options: Default::default(),
+                    status: OptionStatus {
+                        creation_date: Default::default(),
+                        pending_deletion: Default::default(),
+                        state: OptionState::Unknown(String::new()),
+                        update_date: Default::default(),
+                        update_version: Default::default(),
+                    },
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "Suggester" => {
@@ -838,6 +1456,48 @@ pub struct DeleteAnalysisSchemeRequest {
     pub domain_name: String,
 }
 
+impl DeleteAnalysisSchemeRequest {
+    /// Returns a builder for `DeleteAnalysisSchemeRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAnalysisSchemeRequestBuilder {
+        DeleteAnalysisSchemeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAnalysisSchemeRequest`](struct.DeleteAnalysisSchemeRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAnalysisSchemeRequestBuilder {
+    analysis_scheme_name: Option<String>,
+    domain_name: Option<String>,
+}
+
+impl DeleteAnalysisSchemeRequestBuilder {
+    /// Sets the [`analysis_scheme_name`](struct.DeleteAnalysisSchemeRequest.html#structfield.analysis_scheme_name) member.
+    pub fn analysis_scheme_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.analysis_scheme_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DeleteAnalysisSchemeRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAnalysisSchemeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAnalysisSchemeRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAnalysisSchemeRequest {
+            analysis_scheme_name: self
+                .analysis_scheme_name
+                .ok_or(::rusoto_core::BuildError::missing("analysis_scheme_name"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteAnalysisSchemeRequest` contents to a `SignedRequest`.
 struct DeleteAnalysisSchemeRequestSerializer;
 impl DeleteAnalysisSchemeRequestSerializer {
@@ -856,22 +1516,38 @@ impl DeleteAnalysisSchemeRequestSerializer {
 }
 
 /// <p>The result of a <code>DeleteAnalysisScheme</code> request. Contains the status of the deleted analysis scheme.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DeleteAnalysisSchemeResponse {
     /// <p>The status of the analysis scheme being deleted.</p>
     pub analysis_scheme: AnalysisSchemeStatus,
 }
 
-struct DeleteAnalysisSchemeResponseDeserializer;
+pub(crate) struct DeleteAnalysisSchemeResponseDeserializer;
 impl DeleteAnalysisSchemeResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteAnalysisSchemeResponse, XmlParseError> {
-        deserialize_elements::<_, DeleteAnalysisSchemeResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DeleteAnalysisSchemeResponse {
+                analysis_scheme: AnalysisSchemeStatus {
+                    options: AnalysisScheme {
+                        analysis_options: Default::default(),
+                        analysis_scheme_language: AnalysisSchemeLanguage::Unknown(String::new()),
+                        analysis_scheme_name: Default::default(),
+                    },
+                    status: OptionStatus {
+                        creation_date: Default::default(),
+                        pending_deletion: Default::default(),
+                        state: OptionState::Unknown(String::new()),
+                        update_date: Default::default(),
+                        update_version: Default::default(),
+                    },
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "AnalysisScheme" => {
@@ -892,6 +1568,36 @@ pub struct DeleteDomainRequest {
     pub domain_name: String,
 }
 
+impl DeleteDomainRequest {
+    /// Returns a builder for `DeleteDomainRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDomainRequestBuilder {
+        DeleteDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDomainRequest`](struct.DeleteDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDomainRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl DeleteDomainRequestBuilder {
+    /// Sets the [`domain_name`](struct.DeleteDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDomainRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDomainRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteDomainRequest` contents to a `SignedRequest`.
 struct DeleteDomainRequestSerializer;
 impl DeleteDomainRequestSerializer {
@@ -911,10 +1617,10 @@ pub struct DeleteDomainResponse {
     pub domain_status: Option<DomainStatus>,
 }
 
-struct DeleteDomainResponseDeserializer;
+pub(crate) struct DeleteDomainResponseDeserializer;
 impl DeleteDomainResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDomainResponse, XmlParseError> {
@@ -940,6 +1646,48 @@ pub struct DeleteExpressionRequest {
     pub expression_name: String,
 }
 
+impl DeleteExpressionRequest {
+    /// Returns a builder for `DeleteExpressionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteExpressionRequestBuilder {
+        DeleteExpressionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteExpressionRequest`](struct.DeleteExpressionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteExpressionRequestBuilder {
+    domain_name: Option<String>,
+    expression_name: Option<String>,
+}
+
+impl DeleteExpressionRequestBuilder {
+    /// Sets the [`domain_name`](struct.DeleteExpressionRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_name`](struct.DeleteExpressionRequest.html#structfield.expression_name) member.
+    pub fn expression_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.expression_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteExpressionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteExpressionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteExpressionRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            expression_name: self
+                .expression_name
+                .ok_or(::rusoto_core::BuildError::missing("expression_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteExpressionRequest` contents to a `SignedRequest`.
 struct DeleteExpressionRequestSerializer;
 impl DeleteExpressionRequestSerializer {
@@ -958,22 +1706,34 @@ impl DeleteExpressionRequestSerializer {
 }
 
 /// <p>The result of a <code><a>DeleteExpression</a></code> request. Specifies the expression being deleted.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DeleteExpressionResponse {
     /// <p>The status of the expression being deleted.</p>
     pub expression: ExpressionStatus,
 }
 
-struct DeleteExpressionResponseDeserializer;
+pub(crate) struct DeleteExpressionResponseDeserializer;
 impl DeleteExpressionResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteExpressionResponse, XmlParseError> {
-        deserialize_elements::<_, DeleteExpressionResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DeleteExpressionResponse {
+                expression: ExpressionStatus {
+                    options: Default::default(),
+                    status: OptionStatus {
+                        creation_date: Default::default(),
+                        pending_deletion: Default::default(),
+                        state: OptionState::Unknown(String::new()),
+                        update_date: Default::default(),
+                        update_version: Default::default(),
+                    },
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "Expression" => {
@@ -995,6 +1755,48 @@ pub struct DeleteIndexFieldRequest {
     pub index_field_name: String,
 }
 
+impl DeleteIndexFieldRequest {
+    /// Returns a builder for `DeleteIndexFieldRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIndexFieldRequestBuilder {
+        DeleteIndexFieldRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIndexFieldRequest`](struct.DeleteIndexFieldRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIndexFieldRequestBuilder {
+    domain_name: Option<String>,
+    index_field_name: Option<String>,
+}
+
+impl DeleteIndexFieldRequestBuilder {
+    /// Sets the [`domain_name`](struct.DeleteIndexFieldRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`index_field_name`](struct.DeleteIndexFieldRequest.html#structfield.index_field_name) member.
+    pub fn index_field_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.index_field_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIndexFieldRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteIndexFieldRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIndexFieldRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            index_field_name: self
+                .index_field_name
+                .ok_or(::rusoto_core::BuildError::missing("index_field_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteIndexFieldRequest` contents to a `SignedRequest`.
 struct DeleteIndexFieldRequestSerializer;
 impl DeleteIndexFieldRequestSerializer {
@@ -1013,22 +1815,48 @@ impl DeleteIndexFieldRequestSerializer {
 }
 
 /// <p>The result of a <code><a>DeleteIndexField</a></code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DeleteIndexFieldResponse {
     /// <p>The status of the index field being deleted.</p>
     pub index_field: IndexFieldStatus,
 }
 
-struct DeleteIndexFieldResponseDeserializer;
+pub(crate) struct DeleteIndexFieldResponseDeserializer;
 impl DeleteIndexFieldResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteIndexFieldResponse, XmlParseError> {
-        deserialize_elements::<_, DeleteIndexFieldResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DeleteIndexFieldResponse {
+                index_field: IndexFieldStatus {
+                    options: IndexField {
+                        date_array_options: Default::default(),
+                        date_options: Default::default(),
+                        double_array_options: Default::default(),
+                        double_options: Default::default(),
+                        index_field_name: Default::default(),
+                        index_field_type: IndexFieldType::Unknown(String::new()),
+                        int_array_options: Default::default(),
+                        int_options: Default::default(),
+                        lat_lon_options: Default::default(),
+                        literal_array_options: Default::default(),
+                        literal_options: Default::default(),
+                        text_array_options: Default::default(),
+                        text_options: Default::default(),
+                    },
+                    status: OptionStatus {
+                        creation_date: Default::default(),
+                        pending_deletion: Default::default(),
+                        state: OptionState::Unknown(String::new()),
+                        update_date: Default::default(),
+                        update_version: Default::default(),
+                    },
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "IndexField" => {
@@ -1050,6 +1878,46 @@ pub struct DeleteSuggesterRequest {
     pub suggester_name: String,
 }
 
+impl DeleteSuggesterRequest {
+    /// Returns a builder for `DeleteSuggesterRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSuggesterRequestBuilder {
+        DeleteSuggesterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSuggesterRequest`](struct.DeleteSuggesterRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSuggesterRequestBuilder {
+    domain_name: Option<String>,
+    suggester_name: Option<String>,
+}
+
+impl DeleteSuggesterRequestBuilder {
+    /// Sets the [`domain_name`](struct.DeleteSuggesterRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`suggester_name`](struct.DeleteSuggesterRequest.html#structfield.suggester_name) member.
+    pub fn suggester_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.suggester_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSuggesterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteSuggesterRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSuggesterRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            suggester_name: self
+                .suggester_name
+                .ok_or(::rusoto_core::BuildError::missing("suggester_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteSuggesterRequest` contents to a `SignedRequest`.
 struct DeleteSuggesterRequestSerializer;
 impl DeleteSuggesterRequestSerializer {
@@ -1068,22 +1936,34 @@ impl DeleteSuggesterRequestSerializer {
 }
 
 /// <p>The result of a <code>DeleteSuggester</code> request. Contains the status of the deleted suggester.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DeleteSuggesterResponse {
     /// <p>The status of the suggester being deleted.</p>
     pub suggester: SuggesterStatus,
 }
 
-struct DeleteSuggesterResponseDeserializer;
+pub(crate) struct DeleteSuggesterResponseDeserializer;
 impl DeleteSuggesterResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteSuggesterResponse, XmlParseError> {
-        deserialize_elements::<_, DeleteSuggesterResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DeleteSuggesterResponse {
+                suggester: SuggesterStatus {
+                    options: Default::default(),
+                    status: OptionStatus {
+                        creation_date: Default::default(),
+                        pending_deletion: Default::default(),
+                        state: OptionState::Unknown(String::new()),
+                        update_date: Default::default(),
+                        update_version: Default::default(),
+                    },
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "Suggester" => {
@@ -1108,6 +1988,54 @@ pub struct DescribeAnalysisSchemesRequest {
     pub domain_name: String,
 }
 
+impl DescribeAnalysisSchemesRequest {
+    /// Returns a builder for `DescribeAnalysisSchemesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAnalysisSchemesRequestBuilder {
+        DescribeAnalysisSchemesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAnalysisSchemesRequest`](struct.DescribeAnalysisSchemesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAnalysisSchemesRequestBuilder {
+    analysis_scheme_names: Option<Vec<String>>,
+    deployed: Option<bool>,
+    domain_name: Option<String>,
+}
+
+impl DescribeAnalysisSchemesRequestBuilder {
+    /// Sets the [`analysis_scheme_names`](struct.DescribeAnalysisSchemesRequest.html#structfield.analysis_scheme_names) member.
+    pub fn analysis_scheme_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.analysis_scheme_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployed`](struct.DescribeAnalysisSchemesRequest.html#structfield.deployed) member.
+    pub fn deployed<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deployed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DescribeAnalysisSchemesRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAnalysisSchemesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAnalysisSchemesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAnalysisSchemesRequest {
+            analysis_scheme_names: self.analysis_scheme_names,
+            deployed: self.deployed,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeAnalysisSchemesRequest` contents to a `SignedRequest`.
 struct DescribeAnalysisSchemesRequestSerializer;
 impl DescribeAnalysisSchemesRequestSerializer {
@@ -1138,10 +2066,10 @@ pub struct DescribeAnalysisSchemesResponse {
     pub analysis_schemes: Vec<AnalysisSchemeStatus>,
 }
 
-struct DescribeAnalysisSchemesResponseDeserializer;
+pub(crate) struct DescribeAnalysisSchemesResponseDeserializer;
 impl DescribeAnalysisSchemesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAnalysisSchemesResponse, XmlParseError> {
@@ -1174,6 +2102,46 @@ pub struct DescribeAvailabilityOptionsRequest {
     pub domain_name: String,
 }
 
+impl DescribeAvailabilityOptionsRequest {
+    /// Returns a builder for `DescribeAvailabilityOptionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAvailabilityOptionsRequestBuilder {
+        DescribeAvailabilityOptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAvailabilityOptionsRequest`](struct.DescribeAvailabilityOptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAvailabilityOptionsRequestBuilder {
+    deployed: Option<bool>,
+    domain_name: Option<String>,
+}
+
+impl DescribeAvailabilityOptionsRequestBuilder {
+    /// Sets the [`deployed`](struct.DescribeAvailabilityOptionsRequest.html#structfield.deployed) member.
+    pub fn deployed<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deployed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DescribeAvailabilityOptionsRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAvailabilityOptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAvailabilityOptionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAvailabilityOptionsRequest {
+            deployed: self.deployed,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeAvailabilityOptionsRequest` contents to a `SignedRequest`.
 struct DescribeAvailabilityOptionsRequestSerializer;
 impl DescribeAvailabilityOptionsRequestSerializer {
@@ -1197,10 +2165,10 @@ pub struct DescribeAvailabilityOptionsResponse {
     pub availability_options: Option<AvailabilityOptionsStatus>,
 }
 
-struct DescribeAvailabilityOptionsResponseDeserializer;
+pub(crate) struct DescribeAvailabilityOptionsResponseDeserializer;
 impl DescribeAvailabilityOptionsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAvailabilityOptionsResponse, XmlParseError> {
@@ -1230,6 +2198,34 @@ pub struct DescribeDomainsRequest {
     pub domain_names: Option<Vec<String>>,
 }
 
+impl DescribeDomainsRequest {
+    /// Returns a builder for `DescribeDomainsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDomainsRequestBuilder {
+        DescribeDomainsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDomainsRequest`](struct.DescribeDomainsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDomainsRequestBuilder {
+    domain_names: Option<Vec<String>>,
+}
+
+impl DescribeDomainsRequestBuilder {
+    /// Sets the [`domain_names`](struct.DescribeDomainsRequest.html#structfield.domain_names) member.
+    pub fn domain_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.domain_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDomainsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeDomainsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDomainsRequest {
+            domain_names: self.domain_names,
+        })
+    }
+}
+
 /// Serialize `DescribeDomainsRequest` contents to a `SignedRequest`.
 struct DescribeDomainsRequestSerializer;
 impl DescribeDomainsRequestSerializer {
@@ -1255,10 +2251,10 @@ pub struct DescribeDomainsResponse {
     pub domain_status_list: Vec<DomainStatus>,
 }
 
-struct DescribeDomainsResponseDeserializer;
+pub(crate) struct DescribeDomainsResponseDeserializer;
 impl DescribeDomainsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeDomainsResponse, XmlParseError> {
@@ -1292,6 +2288,54 @@ pub struct DescribeExpressionsRequest {
     pub expression_names: Option<Vec<String>>,
 }
 
+impl DescribeExpressionsRequest {
+    /// Returns a builder for `DescribeExpressionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeExpressionsRequestBuilder {
+        DescribeExpressionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeExpressionsRequest`](struct.DescribeExpressionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeExpressionsRequestBuilder {
+    deployed: Option<bool>,
+    domain_name: Option<String>,
+    expression_names: Option<Vec<String>>,
+}
+
+impl DescribeExpressionsRequestBuilder {
+    /// Sets the [`deployed`](struct.DescribeExpressionsRequest.html#structfield.deployed) member.
+    pub fn deployed<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deployed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DescribeExpressionsRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_names`](struct.DescribeExpressionsRequest.html#structfield.expression_names) member.
+    pub fn expression_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.expression_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeExpressionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeExpressionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeExpressionsRequest {
+            deployed: self.deployed,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            expression_names: self.expression_names,
+        })
+    }
+}
+
 /// Serialize `DescribeExpressionsRequest` contents to a `SignedRequest`.
 struct DescribeExpressionsRequestSerializer;
 impl DescribeExpressionsRequestSerializer {
@@ -1322,10 +2366,10 @@ pub struct DescribeExpressionsResponse {
     pub expressions: Vec<ExpressionStatus>,
 }
 
-struct DescribeExpressionsResponseDeserializer;
+pub(crate) struct DescribeExpressionsResponseDeserializer;
 impl DescribeExpressionsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeExpressionsResponse, XmlParseError> {
@@ -1359,6 +2403,54 @@ pub struct DescribeIndexFieldsRequest {
     pub field_names: Option<Vec<String>>,
 }
 
+impl DescribeIndexFieldsRequest {
+    /// Returns a builder for `DescribeIndexFieldsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeIndexFieldsRequestBuilder {
+        DescribeIndexFieldsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeIndexFieldsRequest`](struct.DescribeIndexFieldsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeIndexFieldsRequestBuilder {
+    deployed: Option<bool>,
+    domain_name: Option<String>,
+    field_names: Option<Vec<String>>,
+}
+
+impl DescribeIndexFieldsRequestBuilder {
+    /// Sets the [`deployed`](struct.DescribeIndexFieldsRequest.html#structfield.deployed) member.
+    pub fn deployed<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deployed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DescribeIndexFieldsRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`field_names`](struct.DescribeIndexFieldsRequest.html#structfield.field_names) member.
+    pub fn field_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.field_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeIndexFieldsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeIndexFieldsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeIndexFieldsRequest {
+            deployed: self.deployed,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            field_names: self.field_names,
+        })
+    }
+}
+
 /// Serialize `DescribeIndexFieldsRequest` contents to a `SignedRequest`.
 struct DescribeIndexFieldsRequestSerializer;
 impl DescribeIndexFieldsRequestSerializer {
@@ -1389,10 +2481,10 @@ pub struct DescribeIndexFieldsResponse {
     pub index_fields: Vec<IndexFieldStatus>,
 }
 
-struct DescribeIndexFieldsResponseDeserializer;
+pub(crate) struct DescribeIndexFieldsResponseDeserializer;
 impl DescribeIndexFieldsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeIndexFieldsResponse, XmlParseError> {
@@ -1421,6 +2513,38 @@ pub struct DescribeScalingParametersRequest {
     pub domain_name: String,
 }
 
+impl DescribeScalingParametersRequest {
+    /// Returns a builder for `DescribeScalingParametersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeScalingParametersRequestBuilder {
+        DescribeScalingParametersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeScalingParametersRequest`](struct.DescribeScalingParametersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeScalingParametersRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl DescribeScalingParametersRequestBuilder {
+    /// Sets the [`domain_name`](struct.DescribeScalingParametersRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeScalingParametersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeScalingParametersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeScalingParametersRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeScalingParametersRequest` contents to a `SignedRequest`.
 struct DescribeScalingParametersRequestSerializer;
 impl DescribeScalingParametersRequestSerializer {
@@ -1435,21 +2559,33 @@ impl DescribeScalingParametersRequestSerializer {
 }
 
 /// <p>The result of a <code>DescribeScalingParameters</code> request. Contains the scaling parameters configured for the domain specified in the request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DescribeScalingParametersResponse {
     pub scaling_parameters: ScalingParametersStatus,
 }
 
-struct DescribeScalingParametersResponseDeserializer;
+pub(crate) struct DescribeScalingParametersResponseDeserializer;
 impl DescribeScalingParametersResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeScalingParametersResponse, XmlParseError> {
-        deserialize_elements::<_, DescribeScalingParametersResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DescribeScalingParametersResponse {
+                scaling_parameters: ScalingParametersStatus {
+                    options: Default::default(),
+                    status: OptionStatus {
+                        creation_date: Default::default(),
+                        pending_deletion: Default::default(),
+                        state: OptionState::Unknown(String::new()),
+                        update_date: Default::default(),
+                        update_version: Default::default(),
+                    },
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "ScalingParameters" => {
@@ -1474,6 +2610,47 @@ pub struct DescribeServiceAccessPoliciesRequest {
     pub domain_name: String,
 }
 
+impl DescribeServiceAccessPoliciesRequest {
+    /// Returns a builder for `DescribeServiceAccessPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeServiceAccessPoliciesRequestBuilder {
+        DescribeServiceAccessPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeServiceAccessPoliciesRequest`](struct.DescribeServiceAccessPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeServiceAccessPoliciesRequestBuilder {
+    deployed: Option<bool>,
+    domain_name: Option<String>,
+}
+
+impl DescribeServiceAccessPoliciesRequestBuilder {
+    /// Sets the [`deployed`](struct.DescribeServiceAccessPoliciesRequest.html#structfield.deployed) member.
+    pub fn deployed<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deployed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DescribeServiceAccessPoliciesRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeServiceAccessPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeServiceAccessPoliciesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeServiceAccessPoliciesRequest {
+            deployed: self.deployed,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeServiceAccessPoliciesRequest` contents to a `SignedRequest`.
 struct DescribeServiceAccessPoliciesRequestSerializer;
 impl DescribeServiceAccessPoliciesRequestSerializer {
@@ -1491,22 +2668,34 @@ impl DescribeServiceAccessPoliciesRequestSerializer {
 }
 
 /// <p>The result of a <code>DescribeServiceAccessPolicies</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DescribeServiceAccessPoliciesResponse {
     /// <p>The access rules configured for the domain specified in the request.</p>
     pub access_policies: AccessPoliciesStatus,
 }
 
-struct DescribeServiceAccessPoliciesResponseDeserializer;
+pub(crate) struct DescribeServiceAccessPoliciesResponseDeserializer;
 impl DescribeServiceAccessPoliciesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeServiceAccessPoliciesResponse, XmlParseError> {
-        deserialize_elements::<_, DescribeServiceAccessPoliciesResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            DescribeServiceAccessPoliciesResponse {
+                access_policies: AccessPoliciesStatus {
+                    options: Default::default(),
+                    status: OptionStatus {
+                        creation_date: Default::default(),
+                        pending_deletion: Default::default(),
+                        state: OptionState::Unknown(String::new()),
+                        update_date: Default::default(),
+                        update_version: Default::default(),
+                    },
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "AccessPolicies" => {
@@ -1531,6 +2720,54 @@ pub struct DescribeSuggestersRequest {
     pub suggester_names: Option<Vec<String>>,
 }
 
+impl DescribeSuggestersRequest {
+    /// Returns a builder for `DescribeSuggestersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeSuggestersRequestBuilder {
+        DescribeSuggestersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSuggestersRequest`](struct.DescribeSuggestersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeSuggestersRequestBuilder {
+    deployed: Option<bool>,
+    domain_name: Option<String>,
+    suggester_names: Option<Vec<String>>,
+}
+
+impl DescribeSuggestersRequestBuilder {
+    /// Sets the [`deployed`](struct.DescribeSuggestersRequest.html#structfield.deployed) member.
+    pub fn deployed<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deployed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DescribeSuggestersRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`suggester_names`](struct.DescribeSuggestersRequest.html#structfield.suggester_names) member.
+    pub fn suggester_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.suggester_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSuggestersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSuggestersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeSuggestersRequest {
+            deployed: self.deployed,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            suggester_names: self.suggester_names,
+        })
+    }
+}
+
 /// Serialize `DescribeSuggestersRequest` contents to a `SignedRequest`.
 struct DescribeSuggestersRequestSerializer;
 impl DescribeSuggestersRequestSerializer {
@@ -1561,10 +2798,10 @@ pub struct DescribeSuggestersResponse {
     pub suggesters: Vec<SuggesterStatus>,
 }
 
-struct DescribeSuggestersResponseDeserializer;
+pub(crate) struct DescribeSuggestersResponseDeserializer;
 impl DescribeSuggestersResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeSuggestersResponse, XmlParseError> {
@@ -1591,17 +2828,17 @@ impl DescribeSuggestersResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DocumentSuggesterOptions {
     /// <p>The level of fuzziness allowed when suggesting matches for a string: <code>none</code>, <code>low</code>, or <code>high</code>. With none, the specified string is treated as an exact prefix. With low, suggestions must differ from the specified string by no more than one character. With high, suggestions can differ by up to two characters. The default is none. </p>
-    pub fuzzy_matching: Option<String>,
+    pub fuzzy_matching: Option<SuggesterFuzzyMatching>,
     /// <p>An expression that computes a score for each suggestion to control how they are sorted. The scores are rounded to the nearest integer, with a floor of 0 and a ceiling of 2^31-1. A document's relevance score is not computed for suggestions, so sort expressions cannot reference the <code>_score</code> value. To sort suggestions using a numeric field or existing expression, simply specify the name of the field or expression. If no expression is configured for the suggester, the suggestions are sorted with the closest matches listed first.</p>
     pub sort_expression: Option<String>,
     /// <p>The name of the index field you want to use for suggestions. </p>
     pub source_field: String,
 }
 
-struct DocumentSuggesterOptionsDeserializer;
+pub(crate) struct DocumentSuggesterOptionsDeserializer;
 impl DocumentSuggesterOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DocumentSuggesterOptions, XmlParseError> {
@@ -1651,10 +2888,13 @@ impl DocumentSuggesterOptionsSerializer {
     }
 }
 
-struct DomainIdDeserializer;
+pub(crate) struct DomainIdDeserializer;
 impl DomainIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1662,10 +2902,13 @@ impl DomainIdDeserializer {
         Ok(obj)
     }
 }
-struct DomainNameDeserializer;
+pub(crate) struct DomainNameDeserializer;
 impl DomainNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1685,10 +2928,10 @@ impl DomainNameListSerializer {
     }
 }
 
-struct DomainNameMapDeserializer;
+pub(crate) struct DomainNameMapDeserializer;
 impl DomainNameMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
@@ -1735,10 +2978,10 @@ pub struct DomainStatus {
     pub search_service: Option<ServiceEndpoint>,
 }
 
-struct DomainStatusDeserializer;
+pub(crate) struct DomainStatusDeserializer;
 impl DomainStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DomainStatus, XmlParseError> {
@@ -1805,10 +3048,10 @@ impl DomainStatusDeserializer {
         })
     }
 }
-struct DomainStatusListDeserializer;
+pub(crate) struct DomainStatusListDeserializer;
 impl DomainStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DomainStatus>, XmlParseError> {
@@ -1822,10 +3065,13 @@ impl DomainStatusListDeserializer {
         })
     }
 }
-struct DoubleDeserializer;
+pub(crate) struct DoubleDeserializer;
 impl DoubleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1848,10 +3094,10 @@ pub struct DoubleArrayOptions {
     pub source_fields: Option<String>,
 }
 
-struct DoubleArrayOptionsDeserializer;
+pub(crate) struct DoubleArrayOptionsDeserializer;
 impl DoubleArrayOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DoubleArrayOptions, XmlParseError> {
@@ -1930,10 +3176,10 @@ pub struct DoubleOptions {
     pub source_field: Option<String>,
 }
 
-struct DoubleOptionsDeserializer;
+pub(crate) struct DoubleOptionsDeserializer;
 impl DoubleOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DoubleOptions, XmlParseError> {
@@ -2000,10 +3246,13 @@ impl DoubleOptionsSerializer {
     }
 }
 
-struct DynamicFieldNameDeserializer;
+pub(crate) struct DynamicFieldNameDeserializer;
 impl DynamicFieldNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2030,10 +3279,10 @@ pub struct Expression {
     pub expression_value: String,
 }
 
-struct ExpressionDeserializer;
+pub(crate) struct ExpressionDeserializer;
 impl ExpressionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Expression, XmlParseError> {
@@ -2075,38 +3324,52 @@ impl ExpressionSerializer {
 }
 
 /// <p>The value of an <code>Expression</code> and its current status.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ExpressionStatus {
     /// <p>The expression that is evaluated for sorting while processing a search request.</p>
     pub options: Expression,
     pub status: OptionStatus,
 }
 
-struct ExpressionStatusDeserializer;
+pub(crate) struct ExpressionStatusDeserializer;
 impl ExpressionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ExpressionStatus, XmlParseError> {
-        deserialize_elements::<_, ExpressionStatus, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Options" => {
-                    obj.options = ExpressionDeserializer::deserialize("Options", stack)?;
-                }
-                "Status" => {
-                    obj.status = OptionStatusDeserializer::deserialize("Status", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            ExpressionStatus {
+                options: Default::default(),
+                status: OptionStatus {
+                    creation_date: Default::default(),
+                    pending_deletion: Default::default(),
+                    state: OptionState::Unknown(String::new()),
+                    update_date: Default::default(),
+                    update_version: Default::default(),
+                },
+            },
+            |name, stack, obj| {
+                match name {
+                    "Options" => {
+                        obj.options = ExpressionDeserializer::deserialize("Options", stack)?;
+                    }
+                    "Status" => {
+                        obj.status = OptionStatusDeserializer::deserialize("Status", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct ExpressionStatusListDeserializer;
+pub(crate) struct ExpressionStatusListDeserializer;
 impl ExpressionStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ExpressionStatus>, XmlParseError> {
@@ -2120,10 +3383,13 @@ impl ExpressionStatusListDeserializer {
         })
     }
 }
-struct ExpressionValueDeserializer;
+pub(crate) struct ExpressionValueDeserializer;
 impl ExpressionValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2131,10 +3397,13 @@ impl ExpressionValueDeserializer {
         Ok(obj)
     }
 }
-struct FieldNameDeserializer;
+pub(crate) struct FieldNameDeserializer;
 impl FieldNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2142,10 +3411,13 @@ impl FieldNameDeserializer {
         Ok(obj)
     }
 }
-struct FieldNameCommaListDeserializer;
+pub(crate) struct FieldNameCommaListDeserializer;
 impl FieldNameCommaListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2153,10 +3425,10 @@ impl FieldNameCommaListDeserializer {
         Ok(obj)
     }
 }
-struct FieldNameListDeserializer;
+pub(crate) struct FieldNameListDeserializer;
 impl FieldNameListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -2170,10 +3442,13 @@ impl FieldNameListDeserializer {
         })
     }
 }
-struct FieldValueDeserializer;
+pub(crate) struct FieldValueDeserializer;
 impl FieldValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2187,6 +3462,36 @@ pub struct IndexDocumentsRequest {
     pub domain_name: String,
 }
 
+impl IndexDocumentsRequest {
+    /// Returns a builder for `IndexDocumentsRequest`, checking its required members when built.
+    pub fn builder() -> IndexDocumentsRequestBuilder {
+        IndexDocumentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`IndexDocumentsRequest`](struct.IndexDocumentsRequest.html).
+#[derive(Default, Debug)]
+pub struct IndexDocumentsRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl IndexDocumentsRequestBuilder {
+    /// Sets the [`domain_name`](struct.IndexDocumentsRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `IndexDocumentsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<IndexDocumentsRequest, ::rusoto_core::BuildError> {
+        Ok(IndexDocumentsRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `IndexDocumentsRequest` contents to a `SignedRequest`.
 struct IndexDocumentsRequestSerializer;
 impl IndexDocumentsRequestSerializer {
@@ -2207,10 +3512,10 @@ pub struct IndexDocumentsResponse {
     pub field_names: Option<Vec<String>>,
 }
 
-struct IndexDocumentsResponseDeserializer;
+pub(crate) struct IndexDocumentsResponseDeserializer;
 impl IndexDocumentsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<IndexDocumentsResponse, XmlParseError> {
@@ -2228,7 +3533,7 @@ impl IndexDocumentsResponseDeserializer {
     }
 }
 /// <p>Configuration information for a field in the index, including its name, type, and options. The supported options depend on the <code><a>IndexFieldType</a></code>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct IndexField {
     pub date_array_options: Option<DateArrayOptions>,
     pub date_options: Option<DateOptions>,
@@ -2236,7 +3541,7 @@ pub struct IndexField {
     pub double_options: Option<DoubleOptions>,
     /// <p>A string that represents the name of an index field. CloudSearch supports regular index fields as well as dynamic fields. A dynamic field's name defines a pattern that begins or ends with a wildcard. Any document fields that don't map to a regular index field but do match a dynamic field's pattern are configured with the dynamic field's indexing options. </p> <p>Regular field names begin with a letter and can contain the following characters: a-z (lowercase), 0-9, and _ (underscore). Dynamic field names must begin or end with a wildcard (*). The wildcard can also be the only character in a dynamic field name. Multiple wildcards, and wildcards embedded within a string are not supported. </p> <p>The name <code>score</code> is reserved and cannot be used as a field name. To reference a document's ID, you can use the name <code>_id</code>. </p>
     pub index_field_name: String,
-    pub index_field_type: String,
+    pub index_field_type: IndexFieldType,
     pub int_array_options: Option<IntArrayOptions>,
     pub int_options: Option<IntOptions>,
     pub lat_lon_options: Option<LatLonOptions>,
@@ -2246,87 +3551,108 @@ pub struct IndexField {
     pub text_options: Option<TextOptions>,
 }
 
-struct IndexFieldDeserializer;
+pub(crate) struct IndexFieldDeserializer;
 impl IndexFieldDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<IndexField, XmlParseError> {
-        deserialize_elements::<_, IndexField, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "DateArrayOptions" => {
-                    obj.date_array_options = Some(DateArrayOptionsDeserializer::deserialize(
-                        "DateArrayOptions",
-                        stack,
-                    )?);
-                }
-                "DateOptions" => {
-                    obj.date_options =
-                        Some(DateOptionsDeserializer::deserialize("DateOptions", stack)?);
-                }
-                "DoubleArrayOptions" => {
-                    obj.double_array_options = Some(DoubleArrayOptionsDeserializer::deserialize(
-                        "DoubleArrayOptions",
-                        stack,
-                    )?);
-                }
-                "DoubleOptions" => {
-                    obj.double_options = Some(DoubleOptionsDeserializer::deserialize(
-                        "DoubleOptions",
-                        stack,
-                    )?);
-                }
-                "IndexFieldName" => {
-                    obj.index_field_name =
-                        DynamicFieldNameDeserializer::deserialize("IndexFieldName", stack)?;
-                }
-                "IndexFieldType" => {
-                    obj.index_field_type =
-                        IndexFieldTypeDeserializer::deserialize("IndexFieldType", stack)?;
-                }
-                "IntArrayOptions" => {
-                    obj.int_array_options = Some(IntArrayOptionsDeserializer::deserialize(
-                        "IntArrayOptions",
-                        stack,
-                    )?);
-                }
-                "IntOptions" => {
-                    obj.int_options =
-                        Some(IntOptionsDeserializer::deserialize("IntOptions", stack)?);
-                }
-                "LatLonOptions" => {
-                    obj.lat_lon_options = Some(LatLonOptionsDeserializer::deserialize(
-                        "LatLonOptions",
-                        stack,
-                    )?);
-                }
-                "LiteralArrayOptions" => {
-                    obj.literal_array_options = Some(LiteralArrayOptionsDeserializer::deserialize(
-                        "LiteralArrayOptions",
-                        stack,
-                    )?);
-                }
-                "LiteralOptions" => {
-                    obj.literal_options = Some(LiteralOptionsDeserializer::deserialize(
-                        "LiteralOptions",
-                        stack,
-                    )?);
-                }
-                "TextArrayOptions" => {
-                    obj.text_array_options = Some(TextArrayOptionsDeserializer::deserialize(
-                        "TextArrayOptions",
-                        stack,
-                    )?);
-                }
-                "TextOptions" => {
-                    obj.text_options =
-                        Some(TextOptionsDeserializer::deserialize("TextOptions", stack)?);
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            IndexField {
+                date_array_options: Default::default(),
+                date_options: Default::default(),
+                double_array_options: Default::default(),
+                double_options: Default::default(),
+                index_field_name: Default::default(),
+                index_field_type: IndexFieldType::Unknown(String::new()),
+                int_array_options: Default::default(),
+                int_options: Default::default(),
+                lat_lon_options: Default::default(),
+                literal_array_options: Default::default(),
+                literal_options: Default::default(),
+                text_array_options: Default::default(),
+                text_options: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "DateArrayOptions" => {
+                        obj.date_array_options = Some(DateArrayOptionsDeserializer::deserialize(
+                            "DateArrayOptions",
+                            stack,
+                        )?);
+                    }
+                    "DateOptions" => {
+                        obj.date_options =
+                            Some(DateOptionsDeserializer::deserialize("DateOptions", stack)?);
+                    }
+                    "DoubleArrayOptions" => {
+                        obj.double_array_options =
+                            Some(DoubleArrayOptionsDeserializer::deserialize(
+                                "DoubleArrayOptions",
+                                stack,
+                            )?);
+                    }
+                    "DoubleOptions" => {
+                        obj.double_options = Some(DoubleOptionsDeserializer::deserialize(
+                            "DoubleOptions",
+                            stack,
+                        )?);
+                    }
+                    "IndexFieldName" => {
+                        obj.index_field_name =
+                            DynamicFieldNameDeserializer::deserialize("IndexFieldName", stack)?;
+                    }
+                    "IndexFieldType" => {
+                        obj.index_field_type =
+                            IndexFieldTypeDeserializer::deserialize("IndexFieldType", stack)?;
+                    }
+                    "IntArrayOptions" => {
+                        obj.int_array_options = Some(IntArrayOptionsDeserializer::deserialize(
+                            "IntArrayOptions",
+                            stack,
+                        )?);
+                    }
+                    "IntOptions" => {
+                        obj.int_options =
+                            Some(IntOptionsDeserializer::deserialize("IntOptions", stack)?);
+                    }
+                    "LatLonOptions" => {
+                        obj.lat_lon_options = Some(LatLonOptionsDeserializer::deserialize(
+                            "LatLonOptions",
+                            stack,
+                        )?);
+                    }
+                    "LiteralArrayOptions" => {
+                        obj.literal_array_options =
+                            Some(LiteralArrayOptionsDeserializer::deserialize(
+                                "LiteralArrayOptions",
+                                stack,
+                            )?);
+                    }
+                    "LiteralOptions" => {
+                        obj.literal_options = Some(LiteralOptionsDeserializer::deserialize(
+                            "LiteralOptions",
+                            stack,
+                        )?);
+                    }
+                    "TextArrayOptions" => {
+                        obj.text_array_options = Some(TextArrayOptionsDeserializer::deserialize(
+                            "TextArrayOptions",
+                            stack,
+                        )?);
+                    }
+                    "TextOptions" => {
+                        obj.text_options =
+                            Some(TextOptionsDeserializer::deserialize("TextOptions", stack)?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -2428,37 +3754,65 @@ impl IndexFieldSerializer {
 }
 
 /// <p>The value of an <code>IndexField</code> and its current status.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct IndexFieldStatus {
     pub options: IndexField,
     pub status: OptionStatus,
 }
 
-struct IndexFieldStatusDeserializer;
+pub(crate) struct IndexFieldStatusDeserializer;
 impl IndexFieldStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<IndexFieldStatus, XmlParseError> {
-        deserialize_elements::<_, IndexFieldStatus, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Options" => {
-                    obj.options = IndexFieldDeserializer::deserialize("Options", stack)?;
-                }
-                "Status" => {
-                    obj.status = OptionStatusDeserializer::deserialize("Status", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            IndexFieldStatus {
+                options: IndexField {
+                    date_array_options: Default::default(),
+                    date_options: Default::default(),
+                    double_array_options: Default::default(),
+                    double_options: Default::default(),
+                    index_field_name: Default::default(),
+                    index_field_type: IndexFieldType::Unknown(String::new()),
+                    int_array_options: Default::default(),
+                    int_options: Default::default(),
+                    lat_lon_options: Default::default(),
+                    literal_array_options: Default::default(),
+                    literal_options: Default::default(),
+                    text_array_options: Default::default(),
+                    text_options: Default::default(),
+                },
+                status: OptionStatus {
+                    creation_date: Default::default(),
+                    pending_deletion: Default::default(),
+                    state: OptionState::Unknown(String::new()),
+                    update_date: Default::default(),
+                    update_version: Default::default(),
+                },
+            },
+            |name, stack, obj| {
+                match name {
+                    "Options" => {
+                        obj.options = IndexFieldDeserializer::deserialize("Options", stack)?;
+                    }
+                    "Status" => {
+                        obj.status = OptionStatusDeserializer::deserialize("Status", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct IndexFieldStatusListDeserializer;
+pub(crate) struct IndexFieldStatusListDeserializer;
 impl IndexFieldStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<IndexFieldStatus>, XmlParseError> {
@@ -2472,21 +3826,122 @@ impl IndexFieldStatusListDeserializer {
         })
     }
 }
-struct IndexFieldTypeDeserializer;
+pub(crate) struct IndexFieldTypeDeserializer;
 impl IndexFieldTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IndexFieldType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct InstanceCountDeserializer;
+/// <p>The type of field. The valid options for a field depend on the field type. For more information about the supported field types, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/configuring-index-fields.html" target="_blank">Configuring Index Fields</a> in the <i>Amazon CloudSearch Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IndexFieldType {
+    /// <p><code>int</code></p>
+    Int,
+    /// <p><code>double</code></p>
+    Double,
+    /// <p><code>literal</code></p>
+    Literal,
+    /// <p><code>text</code></p>
+    Text,
+    /// <p><code>date</code></p>
+    Date,
+    /// <p><code>latlon</code></p>
+    Latlon,
+    /// <p><code>int-array</code></p>
+    IntArray,
+    /// <p><code>double-array</code></p>
+    DoubleArray,
+    /// <p><code>literal-array</code></p>
+    LiteralArray,
+    /// <p><code>text-array</code></p>
+    TextArray,
+    /// <p><code>date-array</code></p>
+    DateArray,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IndexFieldType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IndexFieldType::Int => "int",
+            IndexFieldType::Double => "double",
+            IndexFieldType::Literal => "literal",
+            IndexFieldType::Text => "text",
+            IndexFieldType::Date => "date",
+            IndexFieldType::Latlon => "latlon",
+            IndexFieldType::IntArray => "int-array",
+            IndexFieldType::DoubleArray => "double-array",
+            IndexFieldType::LiteralArray => "literal-array",
+            IndexFieldType::TextArray => "text-array",
+            IndexFieldType::DateArray => "date-array",
+            IndexFieldType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IndexFieldType {
+    fn from(value: &'a str) -> IndexFieldType {
+        match value {
+            "int" => IndexFieldType::Int,
+            "double" => IndexFieldType::Double,
+            "literal" => IndexFieldType::Literal,
+            "text" => IndexFieldType::Text,
+            "date" => IndexFieldType::Date,
+            "latlon" => IndexFieldType::Latlon,
+            "int-array" => IndexFieldType::IntArray,
+            "double-array" => IndexFieldType::DoubleArray,
+            "literal-array" => IndexFieldType::LiteralArray,
+            "text-array" => IndexFieldType::TextArray,
+            "date-array" => IndexFieldType::DateArray,
+            _ => IndexFieldType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IndexFieldType {
+    fn from(value: String) -> IndexFieldType {
+        IndexFieldType::from(value.as_str())
+    }
+}
+
+impl From<IndexFieldType> for String {
+    fn from(value: IndexFieldType) -> String {
+        match value {
+            IndexFieldType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IndexFieldType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IndexFieldType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct InstanceCountDeserializer;
 impl InstanceCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2509,10 +3964,10 @@ pub struct IntArrayOptions {
     pub source_fields: Option<String>,
 }
 
-struct IntArrayOptionsDeserializer;
+pub(crate) struct IntArrayOptionsDeserializer;
 impl IntArrayOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<IntArrayOptions, XmlParseError> {
@@ -2590,10 +4045,10 @@ pub struct IntOptions {
     pub source_field: Option<String>,
 }
 
-struct IntOptionsDeserializer;
+pub(crate) struct IntOptionsDeserializer;
 impl IntOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<IntOptions, XmlParseError> {
@@ -2675,10 +4130,10 @@ pub struct LatLonOptions {
     pub source_field: Option<String>,
 }
 
-struct LatLonOptionsDeserializer;
+pub(crate) struct LatLonOptionsDeserializer;
 impl LatLonOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LatLonOptions, XmlParseError> {
@@ -2751,10 +4206,13 @@ pub struct Limits {
     pub maximum_replication_count: i64,
 }
 
-struct LimitsDeserializer;
+pub(crate) struct LimitsDeserializer;
 impl LimitsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Limits, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Limits, XmlParseError> {
         deserialize_elements::<_, Limits, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "MaximumPartitionCount" => {
@@ -2783,10 +4241,10 @@ pub struct ListDomainNamesResponse {
     pub domain_names: Option<::std::collections::HashMap<String, String>>,
 }
 
-struct ListDomainNamesResponseDeserializer;
+pub(crate) struct ListDomainNamesResponseDeserializer;
 impl ListDomainNamesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListDomainNamesResponse, XmlParseError> {
@@ -2823,10 +4281,10 @@ pub struct LiteralArrayOptions {
     pub source_fields: Option<String>,
 }
 
-struct LiteralArrayOptionsDeserializer;
+pub(crate) struct LiteralArrayOptionsDeserializer;
 impl LiteralArrayOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LiteralArrayOptions, XmlParseError> {
@@ -2904,10 +4362,10 @@ pub struct LiteralOptions {
     pub source_field: Option<String>,
 }
 
-struct LiteralOptionsDeserializer;
+pub(crate) struct LiteralOptionsDeserializer;
 impl LiteralOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LiteralOptions, XmlParseError> {
@@ -2974,10 +4432,13 @@ impl LiteralOptionsSerializer {
     }
 }
 
-struct LongDeserializer;
+pub(crate) struct LongDeserializer;
 impl LongDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2985,10 +4446,13 @@ impl LongDeserializer {
         Ok(obj)
     }
 }
-struct MaximumPartitionCountDeserializer;
+pub(crate) struct MaximumPartitionCountDeserializer;
 impl MaximumPartitionCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2996,10 +4460,13 @@ impl MaximumPartitionCountDeserializer {
         Ok(obj)
     }
 }
-struct MaximumReplicationCountDeserializer;
+pub(crate) struct MaximumReplicationCountDeserializer;
 impl MaximumReplicationCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3007,10 +4474,13 @@ impl MaximumReplicationCountDeserializer {
         Ok(obj)
     }
 }
-struct MultiAZDeserializer;
+pub(crate) struct MultiAZDeserializer;
 impl MultiAZDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3018,70 +4488,154 @@ impl MultiAZDeserializer {
         Ok(obj)
     }
 }
-struct OptionStateDeserializer;
+pub(crate) struct OptionStateDeserializer;
 impl OptionStateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<OptionState, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The state of processing a change to an option. One of:</p> <ul> <li>RequiresIndexDocuments: The option's latest value will not be deployed until <a>IndexDocuments</a> has been called and indexing is complete.</li> <li>Processing: The option's latest value is in the process of being activated.</li> <li>Active: The option's latest value is fully deployed. </li> <li>FailedToValidate: The option value is not compatible with the domain's data and cannot be used to index the data. You must either modify the option value or update or remove the incompatible documents.</li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OptionState {
+    /// <p><code>RequiresIndexDocuments</code> - the option&#39;s latest value will not be deployed until <a>IndexDocuments</a> has been called and indexing is complete.</li> <li> <code>Processing</code>: the option&#39;s latest value is in the process of being activated. </li> <li> <code>Active</code>: the option&#39;s latest value is completely deployed.</li> <li> <code>FailedToValidate</code>: the option value is not compatible with the domain&#39;s data and cannot be used to index the data. You must either modify the option value or update or remove the incompatible documents.</li> </ul></p>
+    RequiresIndexDocuments,
+    /// <p><code>Processing</code> - the option&#39;s latest value is in the process of being activated. </li> <li> <code>Active</code>: the option&#39;s latest value is completely deployed.</li> <li> <code>FailedToValidate</code>: the option value is not compatible with the domain&#39;s data and cannot be used to index the data. You must either modify the option value or update or remove the incompatible documents.</li> </ul></p>
+    Processing,
+    /// <p><code>Active</code> - the option&#39;s latest value is completely deployed.</li> <li> <code>FailedToValidate</code>: the option value is not compatible with the domain&#39;s data and cannot be used to index the data. You must either modify the option value or update or remove the incompatible documents.</li> </ul></p>
+    Active,
+    /// <p><code>FailedToValidate</code> - the option value is not compatible with the domain&#39;s data and cannot be used to index the data. You must either modify the option value or update or remove the incompatible documents.</li> </ul></p>
+    FailedToValidate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OptionState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OptionState::RequiresIndexDocuments => "RequiresIndexDocuments",
+            OptionState::Processing => "Processing",
+            OptionState::Active => "Active",
+            OptionState::FailedToValidate => "FailedToValidate",
+            OptionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OptionState {
+    fn from(value: &'a str) -> OptionState {
+        match value {
+            "RequiresIndexDocuments" => OptionState::RequiresIndexDocuments,
+            "Processing" => OptionState::Processing,
+            "Active" => OptionState::Active,
+            "FailedToValidate" => OptionState::FailedToValidate,
+            _ => OptionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OptionState {
+    fn from(value: String) -> OptionState {
+        OptionState::from(value.as_str())
+    }
+}
+
+impl From<OptionState> for String {
+    fn from(value: OptionState) -> String {
+        match value {
+            OptionState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OptionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OptionState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>The status of domain configuration option.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct OptionStatus {
     /// <p>A timestamp for when this option was created.</p>
-    pub creation_date: String,
+    pub creation_date: IsoTimestamp,
     /// <p>Indicates that the option will be deleted once processing is complete.</p>
     pub pending_deletion: Option<bool>,
     /// <p><p>The state of processing a change to an option. Possible values:</p> <ul> <li> <code>RequiresIndexDocuments</code>: the option&#39;s latest value will not be deployed until <a>IndexDocuments</a> has been called and indexing is complete.</li> <li> <code>Processing</code>: the option&#39;s latest value is in the process of being activated. </li> <li> <code>Active</code>: the option&#39;s latest value is completely deployed.</li> <li> <code>FailedToValidate</code>: the option value is not compatible with the domain&#39;s data and cannot be used to index the data. You must either modify the option value or update or remove the incompatible documents.</li> </ul></p>
-    pub state: String,
+    pub state: OptionState,
     /// <p>A timestamp for when this option was last updated.</p>
-    pub update_date: String,
+    pub update_date: IsoTimestamp,
     /// <p>A unique integer that indicates when this option was last updated.</p>
     pub update_version: Option<i64>,
 }
 
-struct OptionStatusDeserializer;
+pub(crate) struct OptionStatusDeserializer;
 impl OptionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OptionStatus, XmlParseError> {
-        deserialize_elements::<_, OptionStatus, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "CreationDate" => {
-                    obj.creation_date =
-                        UpdateTimestampDeserializer::deserialize("CreationDate", stack)?;
-                }
-                "PendingDeletion" => {
-                    obj.pending_deletion =
-                        Some(BooleanDeserializer::deserialize("PendingDeletion", stack)?);
-                }
-                "State" => {
-                    obj.state = OptionStateDeserializer::deserialize("State", stack)?;
-                }
-                "UpdateDate" => {
-                    obj.update_date =
-                        UpdateTimestampDeserializer::deserialize("UpdateDate", stack)?;
-                }
-                "UpdateVersion" => {
-                    obj.update_version =
-                        Some(UIntValueDeserializer::deserialize("UpdateVersion", stack)?);
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            OptionStatus {
+                creation_date: Default::default(),
+                pending_deletion: Default::default(),
+                state: OptionState::Unknown(String::new()),
+                update_date: Default::default(),
+                update_version: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "CreationDate" => {
+                        obj.creation_date =
+                            UpdateTimestampDeserializer::deserialize("CreationDate", stack)?;
+                    }
+                    "PendingDeletion" => {
+                        obj.pending_deletion =
+                            Some(BooleanDeserializer::deserialize("PendingDeletion", stack)?);
+                    }
+                    "State" => {
+                        obj.state = OptionStateDeserializer::deserialize("State", stack)?;
+                    }
+                    "UpdateDate" => {
+                        obj.update_date =
+                            UpdateTimestampDeserializer::deserialize("UpdateDate", stack)?;
+                    }
+                    "UpdateVersion" => {
+                        obj.update_version =
+                            Some(UIntValueDeserializer::deserialize("UpdateVersion", stack)?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct PartitionCountDeserializer;
+pub(crate) struct PartitionCountDeserializer;
 impl PartitionCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3089,21 +4643,162 @@ impl PartitionCountDeserializer {
         Ok(obj)
     }
 }
-struct PartitionInstanceTypeDeserializer;
+pub(crate) struct PartitionInstanceTypeDeserializer;
 impl PartitionInstanceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PartitionInstanceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct PolicyDocumentDeserializer;
+/// <p>The instance type (such as <code>search.m1.small</code>) on which an index partition is hosted.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PartitionInstanceType {
+    /// <p><code>search.m1.small</code></p>
+    SearchM1Small,
+    /// <p><code>search.m1.large</code></p>
+    SearchM1Large,
+    /// <p><code>search.m2.xlarge</code></p>
+    SearchM2Xlarge,
+    /// <p><code>search.m2.2xlarge</code></p>
+    SearchM22xlarge,
+    /// <p><code>search.m3.medium</code></p>
+    SearchM3Medium,
+    /// <p><code>search.m3.large</code></p>
+    SearchM3Large,
+    /// <p><code>search.m3.xlarge</code></p>
+    SearchM3Xlarge,
+    /// <p><code>search.m3.2xlarge</code></p>
+    SearchM32xlarge,
+    /// <p><code>search.small</code></p>
+    SearchSmall,
+    /// <p><code>search.medium</code></p>
+    SearchMedium,
+    /// <p><code>search.large</code></p>
+    SearchLarge,
+    /// <p><code>search.xlarge</code></p>
+    SearchXlarge,
+    /// <p><code>search.2xlarge</code></p>
+    Search2xlarge,
+    /// <p><code>search.previousgeneration.small</code></p>
+    SearchPreviousgenerationSmall,
+    /// <p><code>search.previousgeneration.large</code></p>
+    SearchPreviousgenerationLarge,
+    /// <p><code>search.previousgeneration.xlarge</code></p>
+    SearchPreviousgenerationXlarge,
+    /// <p><code>search.previousgeneration.2xlarge</code></p>
+    SearchPreviousgeneration2xlarge,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PartitionInstanceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PartitionInstanceType::SearchM1Small => "search.m1.small",
+            PartitionInstanceType::SearchM1Large => "search.m1.large",
+            PartitionInstanceType::SearchM2Xlarge => "search.m2.xlarge",
+            PartitionInstanceType::SearchM22xlarge => "search.m2.2xlarge",
+            PartitionInstanceType::SearchM3Medium => "search.m3.medium",
+            PartitionInstanceType::SearchM3Large => "search.m3.large",
+            PartitionInstanceType::SearchM3Xlarge => "search.m3.xlarge",
+            PartitionInstanceType::SearchM32xlarge => "search.m3.2xlarge",
+            PartitionInstanceType::SearchSmall => "search.small",
+            PartitionInstanceType::SearchMedium => "search.medium",
+            PartitionInstanceType::SearchLarge => "search.large",
+            PartitionInstanceType::SearchXlarge => "search.xlarge",
+            PartitionInstanceType::Search2xlarge => "search.2xlarge",
+            PartitionInstanceType::SearchPreviousgenerationSmall => {
+                "search.previousgeneration.small"
+            }
+            PartitionInstanceType::SearchPreviousgenerationLarge => {
+                "search.previousgeneration.large"
+            }
+            PartitionInstanceType::SearchPreviousgenerationXlarge => {
+                "search.previousgeneration.xlarge"
+            }
+            PartitionInstanceType::SearchPreviousgeneration2xlarge => {
+                "search.previousgeneration.2xlarge"
+            }
+            PartitionInstanceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PartitionInstanceType {
+    fn from(value: &'a str) -> PartitionInstanceType {
+        match value {
+            "search.m1.small" => PartitionInstanceType::SearchM1Small,
+            "search.m1.large" => PartitionInstanceType::SearchM1Large,
+            "search.m2.xlarge" => PartitionInstanceType::SearchM2Xlarge,
+            "search.m2.2xlarge" => PartitionInstanceType::SearchM22xlarge,
+            "search.m3.medium" => PartitionInstanceType::SearchM3Medium,
+            "search.m3.large" => PartitionInstanceType::SearchM3Large,
+            "search.m3.xlarge" => PartitionInstanceType::SearchM3Xlarge,
+            "search.m3.2xlarge" => PartitionInstanceType::SearchM32xlarge,
+            "search.small" => PartitionInstanceType::SearchSmall,
+            "search.medium" => PartitionInstanceType::SearchMedium,
+            "search.large" => PartitionInstanceType::SearchLarge,
+            "search.xlarge" => PartitionInstanceType::SearchXlarge,
+            "search.2xlarge" => PartitionInstanceType::Search2xlarge,
+            "search.previousgeneration.small" => {
+                PartitionInstanceType::SearchPreviousgenerationSmall
+            }
+            "search.previousgeneration.large" => {
+                PartitionInstanceType::SearchPreviousgenerationLarge
+            }
+            "search.previousgeneration.xlarge" => {
+                PartitionInstanceType::SearchPreviousgenerationXlarge
+            }
+            "search.previousgeneration.2xlarge" => {
+                PartitionInstanceType::SearchPreviousgeneration2xlarge
+            }
+            _ => PartitionInstanceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PartitionInstanceType {
+    fn from(value: String) -> PartitionInstanceType {
+        PartitionInstanceType::from(value.as_str())
+    }
+}
+
+impl From<PartitionInstanceType> for String {
+    fn from(value: PartitionInstanceType) -> String {
+        match value {
+            PartitionInstanceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PartitionInstanceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PartitionInstanceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct PolicyDocumentDeserializer;
 impl PolicyDocumentDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3115,17 +4810,17 @@ impl PolicyDocumentDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ScalingParameters {
     /// <p>The instance type that you want to preconfigure for your domain. For example, <code>search.m1.small</code>.</p>
-    pub desired_instance_type: Option<String>,
+    pub desired_instance_type: Option<PartitionInstanceType>,
     /// <p>The number of partitions you want to preconfigure for your domain. Only valid when you select <code>m2.2xlarge</code> as the desired instance type.</p>
     pub desired_partition_count: Option<i64>,
     /// <p>The number of replicas you want to preconfigure for each index partition.</p>
     pub desired_replication_count: Option<i64>,
 }
 
-struct ScalingParametersDeserializer;
+pub(crate) struct ScalingParametersDeserializer;
 impl ScalingParametersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ScalingParameters, XmlParseError> {
@@ -3188,22 +4883,32 @@ impl ScalingParametersSerializer {
 }
 
 /// <p>The status and configuration of a search domain's scaling parameters. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ScalingParametersStatus {
     pub options: ScalingParameters,
     pub status: OptionStatus,
 }
 
-struct ScalingParametersStatusDeserializer;
+pub(crate) struct ScalingParametersStatusDeserializer;
 impl ScalingParametersStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ScalingParametersStatus, XmlParseError> {
-        deserialize_elements::<_, ScalingParametersStatus, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            ScalingParametersStatus {
+                options: Default::default(),
+                status: OptionStatus {
+                    creation_date: Default::default(),
+                    pending_deletion: Default::default(),
+                    state: OptionState::Unknown(String::new()),
+                    update_date: Default::default(),
+                    update_version: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "Options" => {
@@ -3219,10 +4924,13 @@ impl ScalingParametersStatusDeserializer {
         )
     }
 }
-struct SearchInstanceTypeDeserializer;
+pub(crate) struct SearchInstanceTypeDeserializer;
 impl SearchInstanceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3236,10 +4944,10 @@ pub struct ServiceEndpoint {
     pub endpoint: Option<String>,
 }
 
-struct ServiceEndpointDeserializer;
+pub(crate) struct ServiceEndpointDeserializer;
 impl ServiceEndpointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ServiceEndpoint, XmlParseError> {
@@ -3254,10 +4962,13 @@ impl ServiceEndpointDeserializer {
         })
     }
 }
-struct ServiceUrlDeserializer;
+pub(crate) struct ServiceUrlDeserializer;
 impl ServiceUrlDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3265,10 +4976,13 @@ impl ServiceUrlDeserializer {
         Ok(obj)
     }
 }
-struct StandardNameDeserializer;
+pub(crate) struct StandardNameDeserializer;
 impl StandardNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3288,10 +5002,13 @@ impl StandardNameListSerializer {
     }
 }
 
-struct StringDeserializer;
+pub(crate) struct StringDeserializer;
 impl StringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3306,10 +5023,10 @@ pub struct Suggester {
     pub suggester_name: String,
 }
 
-struct SuggesterDeserializer;
+pub(crate) struct SuggesterDeserializer;
 impl SuggesterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Suggester, XmlParseError> {
@@ -3354,49 +5071,129 @@ impl SuggesterSerializer {
     }
 }
 
-struct SuggesterFuzzyMatchingDeserializer;
+pub(crate) struct SuggesterFuzzyMatchingDeserializer;
 impl SuggesterFuzzyMatchingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SuggesterFuzzyMatching, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The level of fuzziness allowed when suggesting matches for a string: <code>none</code>, <code>low</code>, or <code>high</code>. With none, the specified string is treated as an exact prefix. With low, suggestions must differ from the specified string by no more than one character. With high, suggestions can differ by up to two characters. The default is none. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SuggesterFuzzyMatching {
+    /// <p><code>none</code></p>
+    None,
+    /// <p><code>low</code></p>
+    Low,
+    /// <p><code>high</code></p>
+    High,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SuggesterFuzzyMatching {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SuggesterFuzzyMatching::None => "none",
+            SuggesterFuzzyMatching::Low => "low",
+            SuggesterFuzzyMatching::High => "high",
+            SuggesterFuzzyMatching::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SuggesterFuzzyMatching {
+    fn from(value: &'a str) -> SuggesterFuzzyMatching {
+        match value {
+            "none" => SuggesterFuzzyMatching::None,
+            "low" => SuggesterFuzzyMatching::Low,
+            "high" => SuggesterFuzzyMatching::High,
+            _ => SuggesterFuzzyMatching::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SuggesterFuzzyMatching {
+    fn from(value: String) -> SuggesterFuzzyMatching {
+        SuggesterFuzzyMatching::from(value.as_str())
+    }
+}
+
+impl From<SuggesterFuzzyMatching> for String {
+    fn from(value: SuggesterFuzzyMatching) -> String {
+        match value {
+            SuggesterFuzzyMatching::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SuggesterFuzzyMatching {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SuggesterFuzzyMatching {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>The value of a <code>Suggester</code> and its current status.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SuggesterStatus {
     pub options: Suggester,
     pub status: OptionStatus,
 }
 
-struct SuggesterStatusDeserializer;
+pub(crate) struct SuggesterStatusDeserializer;
 impl SuggesterStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SuggesterStatus, XmlParseError> {
-        deserialize_elements::<_, SuggesterStatus, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Options" => {
-                    obj.options = SuggesterDeserializer::deserialize("Options", stack)?;
-                }
-                "Status" => {
-                    obj.status = OptionStatusDeserializer::deserialize("Status", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            SuggesterStatus {
+                options: Default::default(),
+                status: OptionStatus {
+                    creation_date: Default::default(),
+                    pending_deletion: Default::default(),
+                    state: OptionState::Unknown(String::new()),
+                    update_date: Default::default(),
+                    update_version: Default::default(),
+                },
+            },
+            |name, stack, obj| {
+                match name {
+                    "Options" => {
+                        obj.options = SuggesterDeserializer::deserialize("Options", stack)?;
+                    }
+                    "Status" => {
+                        obj.status = OptionStatusDeserializer::deserialize("Status", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct SuggesterStatusListDeserializer;
+pub(crate) struct SuggesterStatusListDeserializer;
 impl SuggesterStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SuggesterStatus>, XmlParseError> {
@@ -3425,10 +5222,10 @@ pub struct TextArrayOptions {
     pub source_fields: Option<String>,
 }
 
-struct TextArrayOptionsDeserializer;
+pub(crate) struct TextArrayOptionsDeserializer;
 impl TextArrayOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TextArrayOptions, XmlParseError> {
@@ -3506,10 +5303,10 @@ pub struct TextOptions {
     pub source_field: Option<String>,
 }
 
-struct TextOptionsDeserializer;
+pub(crate) struct TextOptionsDeserializer;
 impl TextOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TextOptions, XmlParseError> {
@@ -3576,10 +5373,13 @@ impl TextOptionsSerializer {
     }
 }
 
-struct UIntValueDeserializer;
+pub(crate) struct UIntValueDeserializer;
 impl UIntValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3595,6 +5395,48 @@ pub struct UpdateAvailabilityOptionsRequest {
     pub multi_az: bool,
 }
 
+impl UpdateAvailabilityOptionsRequest {
+    /// Returns a builder for `UpdateAvailabilityOptionsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAvailabilityOptionsRequestBuilder {
+        UpdateAvailabilityOptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAvailabilityOptionsRequest`](struct.UpdateAvailabilityOptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAvailabilityOptionsRequestBuilder {
+    domain_name: Option<String>,
+    multi_az: Option<bool>,
+}
+
+impl UpdateAvailabilityOptionsRequestBuilder {
+    /// Sets the [`domain_name`](struct.UpdateAvailabilityOptionsRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.UpdateAvailabilityOptionsRequest.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAvailabilityOptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAvailabilityOptionsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAvailabilityOptionsRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            multi_az: self
+                .multi_az
+                .ok_or(::rusoto_core::BuildError::missing("multi_az"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateAvailabilityOptionsRequest` contents to a `SignedRequest`.
 struct UpdateAvailabilityOptionsRequestSerializer;
 impl UpdateAvailabilityOptionsRequestSerializer {
@@ -3616,10 +5458,10 @@ pub struct UpdateAvailabilityOptionsResponse {
     pub availability_options: Option<AvailabilityOptionsStatus>,
 }
 
-struct UpdateAvailabilityOptionsResponseDeserializer;
+pub(crate) struct UpdateAvailabilityOptionsResponseDeserializer;
 impl UpdateAvailabilityOptionsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateAvailabilityOptionsResponse, XmlParseError> {
@@ -3649,6 +5491,48 @@ pub struct UpdateScalingParametersRequest {
     pub scaling_parameters: ScalingParameters,
 }
 
+impl UpdateScalingParametersRequest {
+    /// Returns a builder for `UpdateScalingParametersRequest`, checking its required members when built.
+    pub fn builder() -> UpdateScalingParametersRequestBuilder {
+        UpdateScalingParametersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateScalingParametersRequest`](struct.UpdateScalingParametersRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateScalingParametersRequestBuilder {
+    domain_name: Option<String>,
+    scaling_parameters: Option<ScalingParameters>,
+}
+
+impl UpdateScalingParametersRequestBuilder {
+    /// Sets the [`domain_name`](struct.UpdateScalingParametersRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_parameters`](struct.UpdateScalingParametersRequest.html#structfield.scaling_parameters) member.
+    pub fn scaling_parameters<T: Into<ScalingParameters>>(mut self, value: T) -> Self {
+        self.scaling_parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateScalingParametersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateScalingParametersRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateScalingParametersRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            scaling_parameters: self
+                .scaling_parameters
+                .ok_or(::rusoto_core::BuildError::missing("scaling_parameters"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateScalingParametersRequest` contents to a `SignedRequest`.
 struct UpdateScalingParametersRequestSerializer;
 impl UpdateScalingParametersRequestSerializer {
@@ -3668,21 +5552,33 @@ impl UpdateScalingParametersRequestSerializer {
 }
 
 /// <p>The result of a <code>UpdateScalingParameters</code> request. Contains the status of the newly-configured scaling parameters.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UpdateScalingParametersResponse {
     pub scaling_parameters: ScalingParametersStatus,
 }
 
-struct UpdateScalingParametersResponseDeserializer;
+pub(crate) struct UpdateScalingParametersResponseDeserializer;
 impl UpdateScalingParametersResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateScalingParametersResponse, XmlParseError> {
-        deserialize_elements::<_, UpdateScalingParametersResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            UpdateScalingParametersResponse {
+                scaling_parameters: ScalingParametersStatus {
+                    options: Default::default(),
+                    status: OptionStatus {
+                        creation_date: Default::default(),
+                        pending_deletion: Default::default(),
+                        state: OptionState::Unknown(String::new()),
+                        update_date: Default::default(),
+                        update_version: Default::default(),
+                    },
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "ScalingParameters" => {
@@ -3706,6 +5602,48 @@ pub struct UpdateServiceAccessPoliciesRequest {
     pub domain_name: String,
 }
 
+impl UpdateServiceAccessPoliciesRequest {
+    /// Returns a builder for `UpdateServiceAccessPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> UpdateServiceAccessPoliciesRequestBuilder {
+        UpdateServiceAccessPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateServiceAccessPoliciesRequest`](struct.UpdateServiceAccessPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateServiceAccessPoliciesRequestBuilder {
+    access_policies: Option<String>,
+    domain_name: Option<String>,
+}
+
+impl UpdateServiceAccessPoliciesRequestBuilder {
+    /// Sets the [`access_policies`](struct.UpdateServiceAccessPoliciesRequest.html#structfield.access_policies) member.
+    pub fn access_policies<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_policies = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.UpdateServiceAccessPoliciesRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateServiceAccessPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateServiceAccessPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateServiceAccessPoliciesRequest {
+            access_policies: self
+                .access_policies
+                .ok_or(::rusoto_core::BuildError::missing("access_policies"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateServiceAccessPoliciesRequest` contents to a `SignedRequest`.
 struct UpdateServiceAccessPoliciesRequestSerializer;
 impl UpdateServiceAccessPoliciesRequestSerializer {
@@ -3724,22 +5662,34 @@ impl UpdateServiceAccessPoliciesRequestSerializer {
 }
 
 /// <p>The result of an <code>UpdateServiceAccessPolicies</code> request. Contains the new access policies.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UpdateServiceAccessPoliciesResponse {
     /// <p>The access rules configured for the domain.</p>
     pub access_policies: AccessPoliciesStatus,
 }
 
-struct UpdateServiceAccessPoliciesResponseDeserializer;
+pub(crate) struct UpdateServiceAccessPoliciesResponseDeserializer;
 impl UpdateServiceAccessPoliciesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateServiceAccessPoliciesResponse, XmlParseError> {
-        deserialize_elements::<_, UpdateServiceAccessPoliciesResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            UpdateServiceAccessPoliciesResponse {
+                access_policies: AccessPoliciesStatus {
+                    options: Default::default(),
+                    status: OptionStatus {
+                        creation_date: Default::default(),
+                        pending_deletion: Default::default(),
+                        state: OptionState::Unknown(String::new()),
+                        update_date: Default::default(),
+                        update_version: Default::default(),
+                    },
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "AccessPolicies" => {
@@ -3753,21 +5703,27 @@ impl UpdateServiceAccessPoliciesResponseDeserializer {
         )
     }
 }
-struct UpdateTimestampDeserializer;
+pub(crate) struct UpdateTimestampDeserializer;
 impl UpdateTimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct WordDeserializer;
+pub(crate) struct WordDeserializer;
 impl WordDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5584,6 +7540,157 @@ pub trait CloudSearch {
         input: UpdateServiceAccessPoliciesRequest,
     ) -> RusotoFuture<UpdateServiceAccessPoliciesResponse, UpdateServiceAccessPoliciesError>;
 }
+impl<T: ?Sized + CloudSearch> CloudSearch for ::std::sync::Arc<T> {
+    fn build_suggesters(
+        &self,
+        input: BuildSuggestersRequest,
+    ) -> RusotoFuture<BuildSuggestersResponse, BuildSuggestersError> {
+        (**self).build_suggesters(input)
+    }
+    fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> RusotoFuture<CreateDomainResponse, CreateDomainError> {
+        (**self).create_domain(input)
+    }
+    fn define_analysis_scheme(
+        &self,
+        input: DefineAnalysisSchemeRequest,
+    ) -> RusotoFuture<DefineAnalysisSchemeResponse, DefineAnalysisSchemeError> {
+        (**self).define_analysis_scheme(input)
+    }
+    fn define_expression(
+        &self,
+        input: DefineExpressionRequest,
+    ) -> RusotoFuture<DefineExpressionResponse, DefineExpressionError> {
+        (**self).define_expression(input)
+    }
+    fn define_index_field(
+        &self,
+        input: DefineIndexFieldRequest,
+    ) -> RusotoFuture<DefineIndexFieldResponse, DefineIndexFieldError> {
+        (**self).define_index_field(input)
+    }
+    fn define_suggester(
+        &self,
+        input: DefineSuggesterRequest,
+    ) -> RusotoFuture<DefineSuggesterResponse, DefineSuggesterError> {
+        (**self).define_suggester(input)
+    }
+    fn delete_analysis_scheme(
+        &self,
+        input: DeleteAnalysisSchemeRequest,
+    ) -> RusotoFuture<DeleteAnalysisSchemeResponse, DeleteAnalysisSchemeError> {
+        (**self).delete_analysis_scheme(input)
+    }
+    fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> RusotoFuture<DeleteDomainResponse, DeleteDomainError> {
+        (**self).delete_domain(input)
+    }
+    fn delete_expression(
+        &self,
+        input: DeleteExpressionRequest,
+    ) -> RusotoFuture<DeleteExpressionResponse, DeleteExpressionError> {
+        (**self).delete_expression(input)
+    }
+    fn delete_index_field(
+        &self,
+        input: DeleteIndexFieldRequest,
+    ) -> RusotoFuture<DeleteIndexFieldResponse, DeleteIndexFieldError> {
+        (**self).delete_index_field(input)
+    }
+    fn delete_suggester(
+        &self,
+        input: DeleteSuggesterRequest,
+    ) -> RusotoFuture<DeleteSuggesterResponse, DeleteSuggesterError> {
+        (**self).delete_suggester(input)
+    }
+    fn describe_analysis_schemes(
+        &self,
+        input: DescribeAnalysisSchemesRequest,
+    ) -> RusotoFuture<DescribeAnalysisSchemesResponse, DescribeAnalysisSchemesError> {
+        (**self).describe_analysis_schemes(input)
+    }
+    fn describe_availability_options(
+        &self,
+        input: DescribeAvailabilityOptionsRequest,
+    ) -> RusotoFuture<DescribeAvailabilityOptionsResponse, DescribeAvailabilityOptionsError> {
+        (**self).describe_availability_options(input)
+    }
+    fn describe_domains(
+        &self,
+        input: DescribeDomainsRequest,
+    ) -> RusotoFuture<DescribeDomainsResponse, DescribeDomainsError> {
+        (**self).describe_domains(input)
+    }
+    fn describe_expressions(
+        &self,
+        input: DescribeExpressionsRequest,
+    ) -> RusotoFuture<DescribeExpressionsResponse, DescribeExpressionsError> {
+        (**self).describe_expressions(input)
+    }
+    fn describe_index_fields(
+        &self,
+        input: DescribeIndexFieldsRequest,
+    ) -> RusotoFuture<DescribeIndexFieldsResponse, DescribeIndexFieldsError> {
+        (**self).describe_index_fields(input)
+    }
+    fn describe_scaling_parameters(
+        &self,
+        input: DescribeScalingParametersRequest,
+    ) -> RusotoFuture<DescribeScalingParametersResponse, DescribeScalingParametersError> {
+        (**self).describe_scaling_parameters(input)
+    }
+    fn describe_service_access_policies(
+        &self,
+        input: DescribeServiceAccessPoliciesRequest,
+    ) -> RusotoFuture<DescribeServiceAccessPoliciesResponse, DescribeServiceAccessPoliciesError>
+    {
+        (**self).describe_service_access_policies(input)
+    }
+    fn describe_suggesters(
+        &self,
+        input: DescribeSuggestersRequest,
+    ) -> RusotoFuture<DescribeSuggestersResponse, DescribeSuggestersError> {
+        (**self).describe_suggesters(input)
+    }
+    fn index_documents(
+        &self,
+        input: IndexDocumentsRequest,
+    ) -> RusotoFuture<IndexDocumentsResponse, IndexDocumentsError> {
+        (**self).index_documents(input)
+    }
+    fn list_domain_names(&self) -> RusotoFuture<ListDomainNamesResponse, ListDomainNamesError> {
+        (**self).list_domain_names()
+    }
+    fn update_availability_options(
+        &self,
+        input: UpdateAvailabilityOptionsRequest,
+    ) -> RusotoFuture<UpdateAvailabilityOptionsResponse, UpdateAvailabilityOptionsError> {
+        (**self).update_availability_options(input)
+    }
+    fn update_scaling_parameters(
+        &self,
+        input: UpdateScalingParametersRequest,
+    ) -> RusotoFuture<UpdateScalingParametersResponse, UpdateScalingParametersError> {
+        (**self).update_scaling_parameters(input)
+    }
+    fn update_service_access_policies(
+        &self,
+        input: UpdateServiceAccessPoliciesRequest,
+    ) -> RusotoFuture<UpdateServiceAccessPoliciesResponse, UpdateServiceAccessPoliciesError> {
+        (**self).update_service_access_policies(input)
+    }
+}
+/// The error codes Amazon CloudSearch retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["BandwidthLimitExceeded"],
+        transient: &[],
+    };
+
 /// A client for the Amazon CloudSearch API.
 #[derive(Clone)]
 pub struct CloudSearchClient {
@@ -5599,6 +7706,27 @@ impl CloudSearchClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CLOUDSEARCH`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CloudSearchClient {
+        Self::new(region::Region::from_env_for_service("CLOUDSEARCH"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `cloudsearch`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CloudSearchClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("cloudsearch", "CLOUDSEARCH"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5617,7 +7745,54 @@ impl CloudSearchClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudSearchClient {
-        CloudSearchClient { client, region }
+        CloudSearchClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "cloudsearch")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "cloudsearch",
+            "cloudsearch",
+            "CLOUDSEARCH",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CloudSearch` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CloudSearch + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CloudSearchClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon CloudSearch"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "cloudsearch"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CloudSearchClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -5751,7 +7926,24 @@ impl CloudSearch for CloudSearchClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DefineAnalysisSchemeResponse::default();
+                    result = DefineAnalysisSchemeResponse {
+                        analysis_scheme: AnalysisSchemeStatus {
+                            options: AnalysisScheme {
+                                analysis_options: Default::default(),
+                                analysis_scheme_language: AnalysisSchemeLanguage::Unknown(
+                                    String::new(),
+                                ),
+                                analysis_scheme_name: Default::default(),
+                            },
+                            status: OptionStatus {
+                                creation_date: Default::default(),
+                                pending_deletion: Default::default(),
+                                state: OptionState::Unknown(String::new()),
+                                update_date: Default::default(),
+                                update_version: Default::default(),
+                            },
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -5802,7 +7994,18 @@ impl CloudSearch for CloudSearchClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DefineExpressionResponse::default();
+                    result = DefineExpressionResponse {
+                        expression: ExpressionStatus {
+                            options: Default::default(),
+                            status: OptionStatus {
+                                creation_date: Default::default(),
+                                pending_deletion: Default::default(),
+                                state: OptionState::Unknown(String::new()),
+                                update_date: Default::default(),
+                                update_version: Default::default(),
+                            },
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -5853,7 +8056,32 @@ impl CloudSearch for CloudSearchClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DefineIndexFieldResponse::default();
+                    result = DefineIndexFieldResponse {
+                        index_field: IndexFieldStatus {
+                            options: IndexField {
+                                date_array_options: Default::default(),
+                                date_options: Default::default(),
+                                double_array_options: Default::default(),
+                                double_options: Default::default(),
+                                index_field_name: Default::default(),
+                                index_field_type: IndexFieldType::Unknown(String::new()),
+                                int_array_options: Default::default(),
+                                int_options: Default::default(),
+                                lat_lon_options: Default::default(),
+                                literal_array_options: Default::default(),
+                                literal_options: Default::default(),
+                                text_array_options: Default::default(),
+                                text_options: Default::default(),
+                            },
+                            status: OptionStatus {
+                                creation_date: Default::default(),
+                                pending_deletion: Default::default(),
+                                state: OptionState::Unknown(String::new()),
+                                update_date: Default::default(),
+                                update_version: Default::default(),
+                            },
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -5904,7 +8132,18 @@ impl CloudSearch for CloudSearchClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DefineSuggesterResponse::default();
+                    result = DefineSuggesterResponse {
+                        suggester: SuggesterStatus {
+                            options: Default::default(),
+                            status: OptionStatus {
+                                creation_date: Default::default(),
+                                pending_deletion: Default::default(),
+                                state: OptionState::Unknown(String::new()),
+                                update_date: Default::default(),
+                                update_version: Default::default(),
+                            },
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -5954,7 +8193,24 @@ impl CloudSearch for CloudSearchClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DeleteAnalysisSchemeResponse::default();
+                    result = DeleteAnalysisSchemeResponse {
+                        analysis_scheme: AnalysisSchemeStatus {
+                            options: AnalysisScheme {
+                                analysis_options: Default::default(),
+                                analysis_scheme_language: AnalysisSchemeLanguage::Unknown(
+                                    String::new(),
+                                ),
+                                analysis_scheme_name: Default::default(),
+                            },
+                            status: OptionStatus {
+                                creation_date: Default::default(),
+                                pending_deletion: Default::default(),
+                                state: OptionState::Unknown(String::new()),
+                                update_date: Default::default(),
+                                update_version: Default::default(),
+                            },
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -6056,7 +8312,18 @@ impl CloudSearch for CloudSearchClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DeleteExpressionResponse::default();
+                    result = DeleteExpressionResponse {
+                        expression: ExpressionStatus {
+                            options: Default::default(),
+                            status: OptionStatus {
+                                creation_date: Default::default(),
+                                pending_deletion: Default::default(),
+                                state: OptionState::Unknown(String::new()),
+                                update_date: Default::default(),
+                                update_version: Default::default(),
+                            },
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -6107,7 +8374,32 @@ impl CloudSearch for CloudSearchClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DeleteIndexFieldResponse::default();
+                    result = DeleteIndexFieldResponse {
+                        index_field: IndexFieldStatus {
+                            options: IndexField {
+                                date_array_options: Default::default(),
+                                date_options: Default::default(),
+                                double_array_options: Default::default(),
+                                double_options: Default::default(),
+                                index_field_name: Default::default(),
+                                index_field_type: IndexFieldType::Unknown(String::new()),
+                                int_array_options: Default::default(),
+                                int_options: Default::default(),
+                                lat_lon_options: Default::default(),
+                                literal_array_options: Default::default(),
+                                literal_options: Default::default(),
+                                text_array_options: Default::default(),
+                                text_options: Default::default(),
+                            },
+                            status: OptionStatus {
+                                creation_date: Default::default(),
+                                pending_deletion: Default::default(),
+                                state: OptionState::Unknown(String::new()),
+                                update_date: Default::default(),
+                                update_version: Default::default(),
+                            },
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -6158,7 +8450,18 @@ impl CloudSearch for CloudSearchClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DeleteSuggesterResponse::default();
+                    result = DeleteSuggesterResponse {
+                        suggester: SuggesterStatus {
+                            options: Default::default(),
+                            status: OptionStatus {
+                                creation_date: Default::default(),
+                                pending_deletion: Default::default(),
+                                state: OptionState::Unknown(String::new()),
+                                update_date: Default::default(),
+                                update_version: Default::default(),
+                            },
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -6453,7 +8756,18 @@ impl CloudSearch for CloudSearchClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DescribeScalingParametersResponse::default();
+                    result = DescribeScalingParametersResponse {
+                        scaling_parameters: ScalingParametersStatus {
+                            options: Default::default(),
+                            status: OptionStatus {
+                                creation_date: Default::default(),
+                                pending_deletion: Default::default(),
+                                state: OptionState::Unknown(String::new()),
+                                update_date: Default::default(),
+                                update_version: Default::default(),
+                            },
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -6502,7 +8816,18 @@ impl CloudSearch for CloudSearchClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = DescribeServiceAccessPoliciesResponse::default();
+                    result = DescribeServiceAccessPoliciesResponse {
+                        access_policies: AccessPoliciesStatus {
+                            options: Default::default(),
+                            status: OptionStatus {
+                                creation_date: Default::default(),
+                                pending_deletion: Default::default(),
+                                state: OptionState::Unknown(String::new()),
+                                update_date: Default::default(),
+                                update_version: Default::default(),
+                            },
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -6748,7 +9073,18 @@ impl CloudSearch for CloudSearchClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = UpdateScalingParametersResponse::default();
+                    result = UpdateScalingParametersResponse {
+                        scaling_parameters: ScalingParametersStatus {
+                            options: Default::default(),
+                            status: OptionStatus {
+                                creation_date: Default::default(),
+                                pending_deletion: Default::default(),
+                                state: OptionState::Unknown(String::new()),
+                                update_date: Default::default(),
+                                update_version: Default::default(),
+                            },
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -6796,7 +9132,18 @@ impl CloudSearch for CloudSearchClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = UpdateServiceAccessPoliciesResponse::default();
+                    result = UpdateServiceAccessPoliciesResponse {
+                        access_policies: AccessPoliciesStatus {
+                            options: Default::default(),
+                            status: OptionStatus {
+                                creation_date: Default::default(),
+                                pending_deletion: Default::default(),
+                                state: OptionState::Unknown(String::new()),
+                                update_date: Default::default(),
+                                update_version: Default::default(),
+                            },
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 /// <p>A container for facet information. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -47,6 +48,84 @@ pub struct BucketInfo {
     pub buckets: Option<Vec<Bucket>>,
 }
 
+/// <p><p>The format of the batch you are uploading. Amazon CloudSearch supports two document batch formats:</p> <ul> <li>application/json</li> <li>application/xml</li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContentType {
+    /// <p><code>application/json</code></p>
+    ApplicationJson,
+    /// <p><code>application/xml</code></p>
+    ApplicationXml,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentType::ApplicationJson => "application/json",
+            ContentType::ApplicationXml => "application/xml",
+            ContentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContentType {
+    fn from(value: &'a str) -> ContentType {
+        match value {
+            "application/json" => ContentType::ApplicationJson,
+            "application/xml" => ContentType::ApplicationXml,
+            _ => ContentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentType {
+    fn from(value: String) -> ContentType {
+        ContentType::from(value.as_str())
+    }
+}
+
+impl From<ContentType> for String {
+    fn from(value: ContentType) -> String {
+        match value {
+            ContentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContentType::from(value))
+    }
+}
+
 /// <p>A warning returned by the document service when an issue is discovered while processing an upload request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -139,6 +218,92 @@ pub struct Hits {
     pub start: Option<i64>,
 }
 
+/// <p><p>Specifies which query parser to use to process the request. If <code>queryParser</code> is not specified, Amazon CloudSearch uses the <code>simple</code> query parser. </p> <p>Amazon CloudSearch supports four query parsers:</p> <ul> <li> <code>simple</code>: perform simple searches of <code>text</code> and <code>text-array</code> fields. By default, the <code>simple</code> query parser searches all <code>text</code> and <code>text-array</code> fields. You can specify which fields to search by with the <code>queryOptions</code> parameter. If you prefix a search term with a plus sign (+) documents must contain the term to be considered a match. (This is the default, unless you configure the default operator with the <code>queryOptions</code> parameter.) You can use the <code>-</code> (NOT), <code>|</code> (OR), and <code>*</code> (wildcard) operators to exclude particular terms, find results that match any of the specified terms, or search for a prefix. To search for a phrase rather than individual terms, enclose the phrase in double quotes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/searching-text.html">Searching for Text</a> in the <i>Amazon CloudSearch Developer Guide</i>. </li> <li> <code>structured</code>: perform advanced searches by combining multiple expressions to define the search criteria. You can also search within particular fields, search for values and ranges of values, and use advanced options such as term boosting, <code>matchall</code>, and <code>near</code>. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/searching-compound-queries.html">Constructing Compound Queries</a> in the <i>Amazon CloudSearch Developer Guide</i>. </li> <li> <code>lucene</code>: search using the Apache Lucene query parser syntax. For more information, see <a href="http://lucene.apache.org/core/4_6_0/queryparser/org/apache/lucene/queryparser/classic/package-summary.html#package_description">Apache Lucene Query Parser Syntax</a>. </li> <li> <code>dismax</code>: search using the simplified subset of the Apache Lucene query parser syntax defined by the DisMax query parser. For more information, see <a href="http://wiki.apache.org/solr/DisMaxQParserPlugin#Query_Syntax">DisMax Query Parser Syntax</a>. </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QueryParser {
+    /// <p><code>simple</code> - perform simple searches of <code>text</code> and <code>text-array</code> fields. By default, the <code>simple</code> query parser searches all <code>text</code> and <code>text-array</code> fields. You can specify which fields to search by with the <code>queryOptions</code> parameter. If you prefix a search term with a plus sign (+) documents must contain the term to be considered a match. (This is the default, unless you configure the default operator with the <code>queryOptions</code> parameter.) You can use the <code>-</code> (NOT), <code>|</code> (OR), and <code>*</code> (wildcard) operators to exclude particular terms, find results that match any of the specified terms, or search for a prefix. To search for a phrase rather than individual terms, enclose the phrase in double quotes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/searching-text.html">Searching for Text</a> in the <i>Amazon CloudSearch Developer Guide</i>. </li> <li> <code>structured</code>: perform advanced searches by combining multiple expressions to define the search criteria. You can also search within particular fields, search for values and ranges of values, and use advanced options such as term boosting, <code>matchall</code>, and <code>near</code>. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/searching-compound-queries.html">Constructing Compound Queries</a> in the <i>Amazon CloudSearch Developer Guide</i>. </li> <li> <code>lucene</code>: search using the Apache Lucene query parser syntax. For more information, see <a href="http://lucene.apache.org/core/4_6_0/queryparser/org/apache/lucene/queryparser/classic/package-summary.html#package_description">Apache Lucene Query Parser Syntax</a>. </li> <li> <code>dismax</code>: search using the simplified subset of the Apache Lucene query parser syntax defined by the DisMax query parser. For more information, see <a href="http://wiki.apache.org/solr/DisMaxQParserPlugin#Query_Syntax">DisMax Query Parser Syntax</a>. </li> </ul></p>
+    Simple,
+    /// <p><code>structured</code> - perform advanced searches by combining multiple expressions to define the search criteria. You can also search within particular fields, search for values and ranges of values, and use advanced options such as term boosting, <code>matchall</code>, and <code>near</code>. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/searching-compound-queries.html">Constructing Compound Queries</a> in the <i>Amazon CloudSearch Developer Guide</i>. </li> <li> <code>lucene</code>: search using the Apache Lucene query parser syntax. For more information, see <a href="http://lucene.apache.org/core/4_6_0/queryparser/org/apache/lucene/queryparser/classic/package-summary.html#package_description">Apache Lucene Query Parser Syntax</a>. </li> <li> <code>dismax</code>: search using the simplified subset of the Apache Lucene query parser syntax defined by the DisMax query parser. For more information, see <a href="http://wiki.apache.org/solr/DisMaxQParserPlugin#Query_Syntax">DisMax Query Parser Syntax</a>. </li> </ul></p>
+    Structured,
+    /// <p><code>lucene</code> - search using the Apache Lucene query parser syntax. For more information, see <a href="http://lucene.apache.org/core/4_6_0/queryparser/org/apache/lucene/queryparser/classic/package-summary.html#package_description">Apache Lucene Query Parser Syntax</a>. </li> <li> <code>dismax</code>: search using the simplified subset of the Apache Lucene query parser syntax defined by the DisMax query parser. For more information, see <a href="http://wiki.apache.org/solr/DisMaxQParserPlugin#Query_Syntax">DisMax Query Parser Syntax</a>. </li> </ul></p>
+    Lucene,
+    /// <p><code>dismax</code> - search using the simplified subset of the Apache Lucene query parser syntax defined by the DisMax query parser. For more information, see <a href="http://wiki.apache.org/solr/DisMaxQParserPlugin#Query_Syntax">DisMax Query Parser Syntax</a>. </li> </ul></p>
+    Dismax,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl QueryParser {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueryParser::Simple => "simple",
+            QueryParser::Structured => "structured",
+            QueryParser::Lucene => "lucene",
+            QueryParser::Dismax => "dismax",
+            QueryParser::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for QueryParser {
+    fn from(value: &'a str) -> QueryParser {
+        match value {
+            "simple" => QueryParser::Simple,
+            "structured" => QueryParser::Structured,
+            "lucene" => QueryParser::Lucene,
+            "dismax" => QueryParser::Dismax,
+            _ => QueryParser::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueryParser {
+    fn from(value: String) -> QueryParser {
+        QueryParser::from(value.as_str())
+    }
+}
+
+impl From<QueryParser> for String {
+    fn from(value: QueryParser) -> String {
+        match value {
+            QueryParser::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for QueryParser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QueryParser {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QueryParser {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QueryParser {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<QueryParser, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(QueryParser::from(value))
+    }
+}
+
 /// <p>Container for the parameters to the <code>Search</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SearchRequest {
@@ -176,7 +341,7 @@ pub struct SearchRequest {
     /// <p><p>Specifies which query parser to use to process the request. If <code>queryParser</code> is not specified, Amazon CloudSearch uses the <code>simple</code> query parser. </p> <p>Amazon CloudSearch supports four query parsers:</p> <ul> <li> <code>simple</code>: perform simple searches of <code>text</code> and <code>text-array</code> fields. By default, the <code>simple</code> query parser searches all <code>text</code> and <code>text-array</code> fields. You can specify which fields to search by with the <code>queryOptions</code> parameter. If you prefix a search term with a plus sign (+) documents must contain the term to be considered a match. (This is the default, unless you configure the default operator with the <code>queryOptions</code> parameter.) You can use the <code>-</code> (NOT), <code>|</code> (OR), and <code>*</code> (wildcard) operators to exclude particular terms, find results that match any of the specified terms, or search for a prefix. To search for a phrase rather than individual terms, enclose the phrase in double quotes. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/searching-text.html">Searching for Text</a> in the <i>Amazon CloudSearch Developer Guide</i>. </li> <li> <code>structured</code>: perform advanced searches by combining multiple expressions to define the search criteria. You can also search within particular fields, search for values and ranges of values, and use advanced options such as term boosting, <code>matchall</code>, and <code>near</code>. For more information, see <a href="http://docs.aws.amazon.com/cloudsearch/latest/developerguide/searching-compound-queries.html">Constructing Compound Queries</a> in the <i>Amazon CloudSearch Developer Guide</i>. </li> <li> <code>lucene</code>: search using the Apache Lucene query parser syntax. For more information, see <a href="http://lucene.apache.org/core/4_6_0/queryparser/org/apache/lucene/queryparser/classic/package-summary.html#package_description">Apache Lucene Query Parser Syntax</a>. </li> <li> <code>dismax</code>: search using the simplified subset of the Apache Lucene query parser syntax defined by the DisMax query parser. For more information, see <a href="http://wiki.apache.org/solr/DisMaxQParserPlugin#Query_Syntax">DisMax Query Parser Syntax</a>. </li> </ul></p>
     #[serde(rename = "queryParser")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub query_parser: Option<String>,
+    pub query_parser: Option<QueryParser>,
     /// <p>Specifies the field and expression values to include in the response. Multiple fields or expressions are specified as a comma-separated list. By default, a search response includes all return enabled fields (<code>_all_fields</code>). To return only the document IDs for the matching documents, specify <code>_no_fields</code>. To retrieve the relevance score calculated for each document, specify <code>_score</code>. </p>
     #[serde(rename = "return")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -199,6 +364,140 @@ pub struct SearchRequest {
     pub stats: Option<String>,
 }
 
+impl SearchRequest {
+    /// Returns a builder for `SearchRequest`, checking its required members when built.
+    pub fn builder() -> SearchRequestBuilder {
+        SearchRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchRequest`](struct.SearchRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchRequestBuilder {
+    cursor: Option<String>,
+    expr: Option<String>,
+    facet: Option<String>,
+    filter_query: Option<String>,
+    highlight: Option<String>,
+    partial: Option<bool>,
+    query: Option<String>,
+    query_options: Option<String>,
+    query_parser: Option<QueryParser>,
+    return_: Option<String>,
+    size: Option<i64>,
+    sort: Option<String>,
+    start: Option<i64>,
+    stats: Option<String>,
+}
+
+impl SearchRequestBuilder {
+    /// Sets the [`cursor`](struct.SearchRequest.html#structfield.cursor) member.
+    pub fn cursor<T: Into<String>>(mut self, value: T) -> Self {
+        self.cursor = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expr`](struct.SearchRequest.html#structfield.expr) member.
+    pub fn expr<T: Into<String>>(mut self, value: T) -> Self {
+        self.expr = Some(value.into());
+        self
+    }
+
+    /// Sets the [`facet`](struct.SearchRequest.html#structfield.facet) member.
+    pub fn facet<T: Into<String>>(mut self, value: T) -> Self {
+        self.facet = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_query`](struct.SearchRequest.html#structfield.filter_query) member.
+    pub fn filter_query<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_query = Some(value.into());
+        self
+    }
+
+    /// Sets the [`highlight`](struct.SearchRequest.html#structfield.highlight) member.
+    pub fn highlight<T: Into<String>>(mut self, value: T) -> Self {
+        self.highlight = Some(value.into());
+        self
+    }
+
+    /// Sets the [`partial`](struct.SearchRequest.html#structfield.partial) member.
+    pub fn partial<T: Into<bool>>(mut self, value: T) -> Self {
+        self.partial = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query`](struct.SearchRequest.html#structfield.query) member.
+    pub fn query<T: Into<String>>(mut self, value: T) -> Self {
+        self.query = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_options`](struct.SearchRequest.html#structfield.query_options) member.
+    pub fn query_options<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_options = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_parser`](struct.SearchRequest.html#structfield.query_parser) member.
+    pub fn query_parser<T: Into<QueryParser>>(mut self, value: T) -> Self {
+        self.query_parser = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_`](struct.SearchRequest.html#structfield.return_) member.
+    pub fn return_<T: Into<String>>(mut self, value: T) -> Self {
+        self.return_ = Some(value.into());
+        self
+    }
+
+    /// Sets the [`size`](struct.SearchRequest.html#structfield.size) member.
+    pub fn size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort`](struct.SearchRequest.html#structfield.sort) member.
+    pub fn sort<T: Into<String>>(mut self, value: T) -> Self {
+        self.sort = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start`](struct.SearchRequest.html#structfield.start) member.
+    pub fn start<T: Into<i64>>(mut self, value: T) -> Self {
+        self.start = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stats`](struct.SearchRequest.html#structfield.stats) member.
+    pub fn stats<T: Into<String>>(mut self, value: T) -> Self {
+        self.stats = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SearchRequest, ::rusoto_core::BuildError> {
+        Ok(SearchRequest {
+            cursor: self.cursor,
+            expr: self.expr,
+            facet: self.facet,
+            filter_query: self.filter_query,
+            highlight: self.highlight,
+            partial: self.partial,
+            query: self
+                .query
+                .ok_or(::rusoto_core::BuildError::missing("query"))?,
+            query_options: self.query_options,
+            query_parser: self.query_parser,
+            return_: self.return_,
+            size: self.size,
+            sort: self.sort,
+            start: self.start,
+            stats: self.stats,
+        })
+    }
+}
+
 /// <p>The result of a <code>Search</code> request. Contains the documents that match the specified search criteria and any requested fields, highlights, and facet information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -268,6 +567,54 @@ pub struct SuggestRequest {
     pub suggester: String,
 }
 
+impl SuggestRequest {
+    /// Returns a builder for `SuggestRequest`, checking its required members when built.
+    pub fn builder() -> SuggestRequestBuilder {
+        SuggestRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SuggestRequest`](struct.SuggestRequest.html).
+#[derive(Default, Debug)]
+pub struct SuggestRequestBuilder {
+    query: Option<String>,
+    size: Option<i64>,
+    suggester: Option<String>,
+}
+
+impl SuggestRequestBuilder {
+    /// Sets the [`query`](struct.SuggestRequest.html#structfield.query) member.
+    pub fn query<T: Into<String>>(mut self, value: T) -> Self {
+        self.query = Some(value.into());
+        self
+    }
+
+    /// Sets the [`size`](struct.SuggestRequest.html#structfield.size) member.
+    pub fn size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`suggester`](struct.SuggestRequest.html#structfield.suggester) member.
+    pub fn suggester<T: Into<String>>(mut self, value: T) -> Self {
+        self.suggester = Some(value.into());
+        self
+    }
+
+    /// Builds the `SuggestRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SuggestRequest, ::rusoto_core::BuildError> {
+        Ok(SuggestRequest {
+            query: self
+                .query
+                .ok_or(::rusoto_core::BuildError::missing("query"))?,
+            size: self.size,
+            suggester: self
+                .suggester
+                .ok_or(::rusoto_core::BuildError::missing("suggester"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>Suggest</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -315,11 +662,11 @@ pub struct SuggestionMatch {
 }
 
 /// <p>Container for the parameters to the <code>UploadDocuments</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UploadDocumentsRequest {
     /// <p><p>The format of the batch you are uploading. Amazon CloudSearch supports two document batch formats:</p> <ul> <li>application/json</li> <li>application/xml</li> </ul></p>
     #[serde(rename = "contentType")]
-    pub content_type: String,
+    pub content_type: ContentType,
     /// <p>A batch of documents formatted in JSON or HTML.</p>
     #[serde(rename = "documents")]
     #[serde(
@@ -330,6 +677,46 @@ pub struct UploadDocumentsRequest {
     pub documents: bytes::Bytes,
 }
 
+impl UploadDocumentsRequest {
+    /// Returns a builder for `UploadDocumentsRequest`, checking its required members when built.
+    pub fn builder() -> UploadDocumentsRequestBuilder {
+        UploadDocumentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UploadDocumentsRequest`](struct.UploadDocumentsRequest.html).
+#[derive(Default, Debug)]
+pub struct UploadDocumentsRequestBuilder {
+    content_type: Option<ContentType>,
+    documents: Option<bytes::Bytes>,
+}
+
+impl UploadDocumentsRequestBuilder {
+    /// Sets the [`content_type`](struct.UploadDocumentsRequest.html#structfield.content_type) member.
+    pub fn content_type<T: Into<ContentType>>(mut self, value: T) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`documents`](struct.UploadDocumentsRequest.html#structfield.documents) member.
+    pub fn documents<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.documents = Some(value.into());
+        self
+    }
+
+    /// Builds the `UploadDocumentsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UploadDocumentsRequest, ::rusoto_core::BuildError> {
+        Ok(UploadDocumentsRequest {
+            content_type: self
+                .content_type
+                .ok_or(::rusoto_core::BuildError::missing("content_type"))?,
+            documents: self
+                .documents
+                .ok_or(::rusoto_core::BuildError::missing("documents"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to an <code>UploadDocuments</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -461,6 +848,27 @@ pub trait CloudSearchDomain {
         input: UploadDocumentsRequest,
     ) -> RusotoFuture<UploadDocumentsResponse, UploadDocumentsError>;
 }
+impl<T: ?Sized + CloudSearchDomain> CloudSearchDomain for ::std::sync::Arc<T> {
+    fn search(&self, input: SearchRequest) -> RusotoFuture<SearchResponse, SearchError> {
+        (**self).search(input)
+    }
+    fn suggest(&self, input: SuggestRequest) -> RusotoFuture<SuggestResponse, SuggestError> {
+        (**self).suggest(input)
+    }
+    fn upload_documents(
+        &self,
+        input: UploadDocumentsRequest,
+    ) -> RusotoFuture<UploadDocumentsResponse, UploadDocumentsError> {
+        (**self).upload_documents(input)
+    }
+}
+/// The error codes Amazon CloudSearch Domain retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon CloudSearch Domain API.
 #[derive(Clone)]
 pub struct CloudSearchDomainClient {
@@ -476,6 +884,27 @@ impl CloudSearchDomainClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CLOUDSEARCHDOMAIN`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CloudSearchDomainClient {
+        Self::new(region::Region::from_env_for_service("CLOUDSEARCHDOMAIN"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `cloudsearchdomain`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CloudSearchDomainClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("cloudsearchdomain", "CLOUDSEARCHDOMAIN"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -494,7 +923,54 @@ impl CloudSearchDomainClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudSearchDomainClient {
-        CloudSearchDomainClient { client, region }
+        CloudSearchDomainClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "cloudsearchdomain")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "cloudsearchdomain",
+            "cloudsearch",
+            "CLOUDSEARCHDOMAIN",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CloudSearchDomain` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CloudSearchDomain + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CloudSearchDomainClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon CloudSearch Domain"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "cloudsearchdomain"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CloudSearchDomainClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -623,7 +1099,7 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
         let encoded = Some(input.documents.to_owned());
         request.set_payload(encoded);
-        request.add_header("Content-Type", &input.content_type);
+        request.add_header("Content-Type", input.content_type.as_str());
         let mut params = Params::new();
         params.put("format", "sdk");
         request.set_params(params);
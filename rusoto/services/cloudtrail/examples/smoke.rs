@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS CloudTrail clients.
+//!
+//! Makes a single read-only `ListPublicKeys` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_cloudtrail::{CloudTrail, CloudTrailClient, ListPublicKeysRequest};
+
+fn main() {
+    let client = CloudTrailClient::from_env();
+    match client
+        .list_public_keys(ListPublicKeysRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListPublicKeys succeeded"),
+        Err(error) => {
+            eprintln!("ListPublicKeys failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
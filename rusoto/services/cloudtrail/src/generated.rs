@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Specifies the tags to add to a trail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -35,6 +36,44 @@ pub struct AddTagsRequest {
     pub tags_list: Option<Vec<Tag>>,
 }
 
+impl AddTagsRequest {
+    /// Returns a builder for `AddTagsRequest`, checking its required members when built.
+    pub fn builder() -> AddTagsRequestBuilder {
+        AddTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsRequest`](struct.AddTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct AddTagsRequestBuilder {
+    resource_id: Option<String>,
+    tags_list: Option<Vec<Tag>>,
+}
+
+impl AddTagsRequestBuilder {
+    /// Sets the [`resource_id`](struct.AddTagsRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags_list`](struct.AddTagsRequest.html#structfield.tags_list) member.
+    pub fn tags_list<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddTagsRequest, ::rusoto_core::BuildError> {
+        Ok(AddTagsRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tags_list: self.tags_list,
+        })
+    }
+}
+
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -87,6 +126,118 @@ pub struct CreateTrailRequest {
     pub sns_topic_name: Option<String>,
 }
 
+impl CreateTrailRequest {
+    /// Returns a builder for `CreateTrailRequest`, checking its required members when built.
+    pub fn builder() -> CreateTrailRequestBuilder {
+        CreateTrailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTrailRequest`](struct.CreateTrailRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTrailRequestBuilder {
+    cloud_watch_logs_log_group_arn: Option<String>,
+    cloud_watch_logs_role_arn: Option<String>,
+    enable_log_file_validation: Option<bool>,
+    include_global_service_events: Option<bool>,
+    is_multi_region_trail: Option<bool>,
+    is_organization_trail: Option<bool>,
+    kms_key_id: Option<String>,
+    name: Option<String>,
+    s3_bucket_name: Option<String>,
+    s3_key_prefix: Option<String>,
+    sns_topic_name: Option<String>,
+}
+
+impl CreateTrailRequestBuilder {
+    /// Sets the [`cloud_watch_logs_log_group_arn`](struct.CreateTrailRequest.html#structfield.cloud_watch_logs_log_group_arn) member.
+    pub fn cloud_watch_logs_log_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cloud_watch_logs_log_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cloud_watch_logs_role_arn`](struct.CreateTrailRequest.html#structfield.cloud_watch_logs_role_arn) member.
+    pub fn cloud_watch_logs_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cloud_watch_logs_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_log_file_validation`](struct.CreateTrailRequest.html#structfield.enable_log_file_validation) member.
+    pub fn enable_log_file_validation<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_log_file_validation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_global_service_events`](struct.CreateTrailRequest.html#structfield.include_global_service_events) member.
+    pub fn include_global_service_events<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_global_service_events = Some(value.into());
+        self
+    }
+
+    /// Sets the [`is_multi_region_trail`](struct.CreateTrailRequest.html#structfield.is_multi_region_trail) member.
+    pub fn is_multi_region_trail<T: Into<bool>>(mut self, value: T) -> Self {
+        self.is_multi_region_trail = Some(value.into());
+        self
+    }
+
+    /// Sets the [`is_organization_trail`](struct.CreateTrailRequest.html#structfield.is_organization_trail) member.
+    pub fn is_organization_trail<T: Into<bool>>(mut self, value: T) -> Self {
+        self.is_organization_trail = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateTrailRequest.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateTrailRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_bucket_name`](struct.CreateTrailRequest.html#structfield.s3_bucket_name) member.
+    pub fn s3_bucket_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_bucket_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_key_prefix`](struct.CreateTrailRequest.html#structfield.s3_key_prefix) member.
+    pub fn s3_key_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_key_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_name`](struct.CreateTrailRequest.html#structfield.sns_topic_name) member.
+    pub fn sns_topic_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTrailRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTrailRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTrailRequest {
+            cloud_watch_logs_log_group_arn: self.cloud_watch_logs_log_group_arn,
+            cloud_watch_logs_role_arn: self.cloud_watch_logs_role_arn,
+            enable_log_file_validation: self.enable_log_file_validation,
+            include_global_service_events: self.include_global_service_events,
+            is_multi_region_trail: self.is_multi_region_trail,
+            is_organization_trail: self.is_organization_trail,
+            kms_key_id: self.kms_key_id,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            s3_bucket_name: self
+                .s3_bucket_name
+                .ok_or(::rusoto_core::BuildError::missing("s3_bucket_name"))?,
+            s3_key_prefix: self.s3_key_prefix,
+            sns_topic_name: self.sns_topic_name,
+        })
+    }
+}
+
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -162,6 +313,36 @@ pub struct DeleteTrailRequest {
     pub name: String,
 }
 
+impl DeleteTrailRequest {
+    /// Returns a builder for `DeleteTrailRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTrailRequestBuilder {
+        DeleteTrailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTrailRequest`](struct.DeleteTrailRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTrailRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteTrailRequestBuilder {
+    /// Sets the [`name`](struct.DeleteTrailRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTrailRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTrailRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTrailRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -180,6 +361,42 @@ pub struct DescribeTrailsRequest {
     pub trail_name_list: Option<Vec<String>>,
 }
 
+impl DescribeTrailsRequest {
+    /// Returns a builder for `DescribeTrailsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTrailsRequestBuilder {
+        DescribeTrailsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTrailsRequest`](struct.DescribeTrailsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTrailsRequestBuilder {
+    include_shadow_trails: Option<bool>,
+    trail_name_list: Option<Vec<String>>,
+}
+
+impl DescribeTrailsRequestBuilder {
+    /// Sets the [`include_shadow_trails`](struct.DescribeTrailsRequest.html#structfield.include_shadow_trails) member.
+    pub fn include_shadow_trails<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_shadow_trails = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trail_name_list`](struct.DescribeTrailsRequest.html#structfield.trail_name_list) member.
+    pub fn trail_name_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.trail_name_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTrailsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTrailsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTrailsRequest {
+            include_shadow_trails: self.include_shadow_trails,
+            trail_name_list: self.trail_name_list,
+        })
+    }
+}
+
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -216,8 +433,9 @@ pub struct Event {
     pub event_source: Option<String>,
     /// <p>The date and time of the event returned.</p>
     #[serde(rename = "EventTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_time: Option<f64>,
+    pub event_time: Option<EpochTimestamp>,
     /// <p>Information about whether the event is a write event or a read event. </p>
     #[serde(rename = "ReadOnly")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -246,7 +464,7 @@ pub struct EventSelector {
     /// <p>Specify if you want your trail to log read-only events, write-only events, or all. For example, the EC2 <code>GetConsoleOutput</code> is a read-only API operation and <code>RunInstances</code> is a write-only API operation.</p> <p> By default, the value is <code>All</code>.</p>
     #[serde(rename = "ReadWriteType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub read_write_type: Option<String>,
+    pub read_write_type: Option<ReadWriteType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -256,6 +474,38 @@ pub struct GetEventSelectorsRequest {
     pub trail_name: String,
 }
 
+impl GetEventSelectorsRequest {
+    /// Returns a builder for `GetEventSelectorsRequest`, checking its required members when built.
+    pub fn builder() -> GetEventSelectorsRequestBuilder {
+        GetEventSelectorsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetEventSelectorsRequest`](struct.GetEventSelectorsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetEventSelectorsRequestBuilder {
+    trail_name: Option<String>,
+}
+
+impl GetEventSelectorsRequestBuilder {
+    /// Sets the [`trail_name`](struct.GetEventSelectorsRequest.html#structfield.trail_name) member.
+    pub fn trail_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.trail_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetEventSelectorsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetEventSelectorsRequest, ::rusoto_core::BuildError> {
+        Ok(GetEventSelectorsRequest {
+            trail_name: self
+                .trail_name
+                .ok_or(::rusoto_core::BuildError::missing("trail_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetEventSelectorsResponse {
@@ -277,6 +527,36 @@ pub struct GetTrailStatusRequest {
     pub name: String,
 }
 
+impl GetTrailStatusRequest {
+    /// Returns a builder for `GetTrailStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetTrailStatusRequestBuilder {
+        GetTrailStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTrailStatusRequest`](struct.GetTrailStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTrailStatusRequestBuilder {
+    name: Option<String>,
+}
+
+impl GetTrailStatusRequestBuilder {
+    /// Sets the [`name`](struct.GetTrailStatusRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTrailStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTrailStatusRequest, ::rusoto_core::BuildError> {
+        Ok(GetTrailStatusRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -291,8 +571,9 @@ pub struct GetTrailStatusResponse {
     pub latest_cloud_watch_logs_delivery_error: Option<String>,
     /// <p>Displays the most recent date and time when CloudTrail delivered logs to CloudWatch Logs.</p>
     #[serde(rename = "LatestCloudWatchLogsDeliveryTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_cloud_watch_logs_delivery_time: Option<f64>,
+    pub latest_cloud_watch_logs_delivery_time: Option<EpochTimestamp>,
     /// <p>This field is deprecated.</p>
     #[serde(rename = "LatestDeliveryAttemptSucceeded")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -307,16 +588,18 @@ pub struct GetTrailStatusResponse {
     pub latest_delivery_error: Option<String>,
     /// <p>Specifies the date and time that CloudTrail last delivered log files to an account's Amazon S3 bucket.</p>
     #[serde(rename = "LatestDeliveryTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_delivery_time: Option<f64>,
+    pub latest_delivery_time: Option<EpochTimestamp>,
     /// <p><p>Displays any Amazon S3 error that CloudTrail encountered when attempting to deliver a digest file to the designated bucket. For more information see the topic <a href="http://docs.aws.amazon.com/AmazonS3/latest/API/ErrorResponses.html">Error Responses</a> in the Amazon S3 API Reference. </p> <note> <p>This error occurs only when there is a problem with the destination S3 bucket and will not occur for timeouts. To resolve the issue, create a new bucket and call <code>UpdateTrail</code> to specify the new bucket, or fix the existing objects so that CloudTrail can again write to the bucket.</p> </note></p>
     #[serde(rename = "LatestDigestDeliveryError")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latest_digest_delivery_error: Option<String>,
     /// <p>Specifies the date and time that CloudTrail last delivered a digest file to an account's Amazon S3 bucket.</p>
     #[serde(rename = "LatestDigestDeliveryTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_digest_delivery_time: Option<f64>,
+    pub latest_digest_delivery_time: Option<EpochTimestamp>,
     /// <p>This field is deprecated.</p>
     #[serde(rename = "LatestNotificationAttemptSucceeded")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -331,16 +614,19 @@ pub struct GetTrailStatusResponse {
     pub latest_notification_error: Option<String>,
     /// <p>Specifies the date and time of the most recent Amazon SNS notification that CloudTrail has written a new log file to an account's Amazon S3 bucket.</p>
     #[serde(rename = "LatestNotificationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_notification_time: Option<f64>,
+    pub latest_notification_time: Option<EpochTimestamp>,
     /// <p>Specifies the most recent date and time when CloudTrail started recording API calls for an AWS account.</p>
     #[serde(rename = "StartLoggingTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_logging_time: Option<f64>,
+    pub start_logging_time: Option<EpochTimestamp>,
     /// <p>Specifies the most recent date and time when CloudTrail stopped recording API calls for an AWS account.</p>
     #[serde(rename = "StopLoggingTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_logging_time: Option<f64>,
+    pub stop_logging_time: Option<EpochTimestamp>,
     /// <p>This field is deprecated.</p>
     #[serde(rename = "TimeLoggingStarted")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -356,16 +642,62 @@ pub struct GetTrailStatusResponse {
 pub struct ListPublicKeysRequest {
     /// <p>Optionally specifies, in UTC, the end of the time range to look up public keys for CloudTrail digest files. If not specified, the current time is used.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>Reserved for future use.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_token: Option<String>,
     /// <p>Optionally specifies, in UTC, the start of the time range to look up public keys for CloudTrail digest files. If not specified, the current time is used, and the current public key is returned.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
+}
+
+impl ListPublicKeysRequest {
+    /// Returns a builder for `ListPublicKeysRequest`, checking its required members when built.
+    pub fn builder() -> ListPublicKeysRequestBuilder {
+        ListPublicKeysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPublicKeysRequest`](struct.ListPublicKeysRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPublicKeysRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    next_token: Option<String>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl ListPublicKeysRequestBuilder {
+    /// Sets the [`end_time`](struct.ListPublicKeysRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPublicKeysRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.ListPublicKeysRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPublicKeysRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPublicKeysRequest, ::rusoto_core::BuildError> {
+        Ok(ListPublicKeysRequest {
+            end_time: self.end_time,
+            next_token: self.next_token,
+            start_time: self.start_time,
+        })
+    }
 }
 
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
@@ -394,6 +726,44 @@ pub struct ListTagsRequest {
     pub resource_id_list: Vec<String>,
 }
 
+impl ListTagsRequest {
+    /// Returns a builder for `ListTagsRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsRequestBuilder {
+        ListTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsRequest`](struct.ListTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsRequestBuilder {
+    next_token: Option<String>,
+    resource_id_list: Option<Vec<String>>,
+}
+
+impl ListTagsRequestBuilder {
+    /// Sets the [`next_token`](struct.ListTagsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id_list`](struct.ListTagsRequest.html#structfield.resource_id_list) member.
+    pub fn resource_id_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_id_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTagsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsRequest {
+            next_token: self.next_token,
+            resource_id_list: self
+                .resource_id_list
+                .ok_or(::rusoto_core::BuildError::missing("resource_id_list"))?,
+        })
+    }
+}
+
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -409,23 +779,120 @@ pub struct ListTagsResponse {
 }
 
 /// <p>Specifies an attribute and value that filter the events returned.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct LookupAttribute {
     /// <p>Specifies an attribute on which to filter the events returned.</p>
     #[serde(rename = "AttributeKey")]
-    pub attribute_key: String,
+    pub attribute_key: LookupAttributeKey,
     /// <p>Specifies a value for the specified AttributeKey.</p>
     #[serde(rename = "AttributeValue")]
     pub attribute_value: String,
 }
 
+/// <p>Specifies an attribute on which to filter the events returned.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LookupAttributeKey {
+    /// <p><code>EventId</code></p>
+    EventId,
+    /// <p><code>EventName</code></p>
+    EventName,
+    /// <p><code>ReadOnly</code></p>
+    ReadOnly,
+    /// <p><code>Username</code></p>
+    Username,
+    /// <p><code>ResourceType</code></p>
+    ResourceType,
+    /// <p><code>ResourceName</code></p>
+    ResourceName,
+    /// <p><code>EventSource</code></p>
+    EventSource,
+    /// <p><code>AccessKeyId</code></p>
+    AccessKeyId,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LookupAttributeKey {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LookupAttributeKey::EventId => "EventId",
+            LookupAttributeKey::EventName => "EventName",
+            LookupAttributeKey::ReadOnly => "ReadOnly",
+            LookupAttributeKey::Username => "Username",
+            LookupAttributeKey::ResourceType => "ResourceType",
+            LookupAttributeKey::ResourceName => "ResourceName",
+            LookupAttributeKey::EventSource => "EventSource",
+            LookupAttributeKey::AccessKeyId => "AccessKeyId",
+            LookupAttributeKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LookupAttributeKey {
+    fn from(value: &'a str) -> LookupAttributeKey {
+        match value {
+            "EventId" => LookupAttributeKey::EventId,
+            "EventName" => LookupAttributeKey::EventName,
+            "ReadOnly" => LookupAttributeKey::ReadOnly,
+            "Username" => LookupAttributeKey::Username,
+            "ResourceType" => LookupAttributeKey::ResourceType,
+            "ResourceName" => LookupAttributeKey::ResourceName,
+            "EventSource" => LookupAttributeKey::EventSource,
+            "AccessKeyId" => LookupAttributeKey::AccessKeyId,
+            _ => LookupAttributeKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LookupAttributeKey {
+    fn from(value: String) -> LookupAttributeKey {
+        LookupAttributeKey::from(value.as_str())
+    }
+}
+
+impl From<LookupAttributeKey> for String {
+    fn from(value: LookupAttributeKey) -> String {
+        match value {
+            LookupAttributeKey::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LookupAttributeKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LookupAttributeKey {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LookupAttributeKey {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LookupAttributeKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LookupAttributeKey::from(value))
+    }
+}
+
 /// <p>Contains a request for LookupEvents.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct LookupEventsRequest {
     /// <p>Specifies that only events that occur before or at the specified time are returned. If the specified end time is before the specified start time, an error is returned.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>Contains a list of lookup attributes. Currently the list can contain only one item.</p>
     #[serde(rename = "LookupAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -440,8 +907,69 @@ pub struct LookupEventsRequest {
     pub next_token: Option<String>,
     /// <p>Specifies that only events that occur after or at the specified time are returned. If the specified start time is after the specified end time, an error is returned.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
+}
+
+impl LookupEventsRequest {
+    /// Returns a builder for `LookupEventsRequest`, checking its required members when built.
+    pub fn builder() -> LookupEventsRequestBuilder {
+        LookupEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`LookupEventsRequest`](struct.LookupEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct LookupEventsRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    lookup_attributes: Option<Vec<LookupAttribute>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl LookupEventsRequestBuilder {
+    /// Sets the [`end_time`](struct.LookupEventsRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lookup_attributes`](struct.LookupEventsRequest.html#structfield.lookup_attributes) member.
+    pub fn lookup_attributes<T: Into<Vec<LookupAttribute>>>(mut self, value: T) -> Self {
+        self.lookup_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.LookupEventsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.LookupEventsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.LookupEventsRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `LookupEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<LookupEventsRequest, ::rusoto_core::BuildError> {
+        Ok(LookupEventsRequest {
+            end_time: self.end_time,
+            lookup_attributes: self.lookup_attributes,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            start_time: self.start_time,
+        })
+    }
 }
 
 /// <p>Contains a response to a LookupEvents action.</p>
@@ -468,12 +996,14 @@ pub struct PublicKey {
     pub fingerprint: Option<String>,
     /// <p>The ending time of validity of the public key.</p>
     #[serde(rename = "ValidityEndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validity_end_time: Option<f64>,
+    pub validity_end_time: Option<EpochTimestamp>,
     /// <p>The starting time of validity of the public key.</p>
     #[serde(rename = "ValidityStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validity_start_time: Option<f64>,
+    pub validity_start_time: Option<EpochTimestamp>,
     /// <p>The DER encoded public key value in PKCS#1 format.</p>
     #[serde(rename = "Value")]
     #[serde(
@@ -495,6 +1025,48 @@ pub struct PutEventSelectorsRequest {
     pub trail_name: String,
 }
 
+impl PutEventSelectorsRequest {
+    /// Returns a builder for `PutEventSelectorsRequest`, checking its required members when built.
+    pub fn builder() -> PutEventSelectorsRequestBuilder {
+        PutEventSelectorsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutEventSelectorsRequest`](struct.PutEventSelectorsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutEventSelectorsRequestBuilder {
+    event_selectors: Option<Vec<EventSelector>>,
+    trail_name: Option<String>,
+}
+
+impl PutEventSelectorsRequestBuilder {
+    /// Sets the [`event_selectors`](struct.PutEventSelectorsRequest.html#structfield.event_selectors) member.
+    pub fn event_selectors<T: Into<Vec<EventSelector>>>(mut self, value: T) -> Self {
+        self.event_selectors = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trail_name`](struct.PutEventSelectorsRequest.html#structfield.trail_name) member.
+    pub fn trail_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.trail_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutEventSelectorsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutEventSelectorsRequest, ::rusoto_core::BuildError> {
+        Ok(PutEventSelectorsRequest {
+            event_selectors: self
+                .event_selectors
+                .ok_or(::rusoto_core::BuildError::missing("event_selectors"))?,
+            trail_name: self
+                .trail_name
+                .ok_or(::rusoto_core::BuildError::missing("trail_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutEventSelectorsResponse {
@@ -508,6 +1080,82 @@ pub struct PutEventSelectorsResponse {
     pub trail_arn: Option<String>,
 }
 
+/// <p>Specify if you want your trail to log read-only events, write-only events, or all. For example, the EC2 <code>GetConsoleOutput</code> is a read-only API operation and <code>RunInstances</code> is a write-only API operation.</p> <p> By default, the value is <code>All</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReadWriteType {
+    /// <p><code>ReadOnly</code></p>
+    ReadOnly,
+    /// <p><code>WriteOnly</code></p>
+    WriteOnly,
+    /// <p><code>All</code></p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReadWriteType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReadWriteType::ReadOnly => "ReadOnly",
+            ReadWriteType::WriteOnly => "WriteOnly",
+            ReadWriteType::All => "All",
+            ReadWriteType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReadWriteType {
+    fn from(value: &'a str) -> ReadWriteType {
+        match value {
+            "ReadOnly" => ReadWriteType::ReadOnly,
+            "WriteOnly" => ReadWriteType::WriteOnly,
+            "All" => ReadWriteType::All,
+            _ => ReadWriteType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReadWriteType {
+    fn from(value: String) -> ReadWriteType {
+        ReadWriteType::from(value.as_str())
+    }
+}
+
+impl From<ReadWriteType> for String {
+    fn from(value: ReadWriteType) -> String {
+        match value {
+            ReadWriteType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReadWriteType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReadWriteType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReadWriteType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReadWriteType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReadWriteType::from(value))
+    }
+}
+
 /// <p>Specifies the tags to remove from a trail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RemoveTagsRequest {
@@ -520,6 +1168,44 @@ pub struct RemoveTagsRequest {
     pub tags_list: Option<Vec<Tag>>,
 }
 
+impl RemoveTagsRequest {
+    /// Returns a builder for `RemoveTagsRequest`, checking its required members when built.
+    pub fn builder() -> RemoveTagsRequestBuilder {
+        RemoveTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsRequest`](struct.RemoveTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsRequestBuilder {
+    resource_id: Option<String>,
+    tags_list: Option<Vec<Tag>>,
+}
+
+impl RemoveTagsRequestBuilder {
+    /// Sets the [`resource_id`](struct.RemoveTagsRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags_list`](struct.RemoveTagsRequest.html#structfield.tags_list) member.
+    pub fn tags_list<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RemoveTagsRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tags_list: self.tags_list,
+        })
+    }
+}
+
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -561,6 +1247,36 @@ pub struct StartLoggingRequest {
     pub name: String,
 }
 
+impl StartLoggingRequest {
+    /// Returns a builder for `StartLoggingRequest`, checking its required members when built.
+    pub fn builder() -> StartLoggingRequestBuilder {
+        StartLoggingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartLoggingRequest`](struct.StartLoggingRequest.html).
+#[derive(Default, Debug)]
+pub struct StartLoggingRequestBuilder {
+    name: Option<String>,
+}
+
+impl StartLoggingRequestBuilder {
+    /// Sets the [`name`](struct.StartLoggingRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartLoggingRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartLoggingRequest, ::rusoto_core::BuildError> {
+        Ok(StartLoggingRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -574,6 +1290,36 @@ pub struct StopLoggingRequest {
     pub name: String,
 }
 
+impl StopLoggingRequest {
+    /// Returns a builder for `StopLoggingRequest`, checking its required members when built.
+    pub fn builder() -> StopLoggingRequestBuilder {
+        StopLoggingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopLoggingRequest`](struct.StopLoggingRequest.html).
+#[derive(Default, Debug)]
+pub struct StopLoggingRequestBuilder {
+    name: Option<String>,
+}
+
+impl StopLoggingRequestBuilder {
+    /// Sets the [`name`](struct.StopLoggingRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopLoggingRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopLoggingRequest, ::rusoto_core::BuildError> {
+        Ok(StopLoggingRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -701,6 +1447,116 @@ pub struct UpdateTrailRequest {
     pub sns_topic_name: Option<String>,
 }
 
+impl UpdateTrailRequest {
+    /// Returns a builder for `UpdateTrailRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTrailRequestBuilder {
+        UpdateTrailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTrailRequest`](struct.UpdateTrailRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTrailRequestBuilder {
+    cloud_watch_logs_log_group_arn: Option<String>,
+    cloud_watch_logs_role_arn: Option<String>,
+    enable_log_file_validation: Option<bool>,
+    include_global_service_events: Option<bool>,
+    is_multi_region_trail: Option<bool>,
+    is_organization_trail: Option<bool>,
+    kms_key_id: Option<String>,
+    name: Option<String>,
+    s3_bucket_name: Option<String>,
+    s3_key_prefix: Option<String>,
+    sns_topic_name: Option<String>,
+}
+
+impl UpdateTrailRequestBuilder {
+    /// Sets the [`cloud_watch_logs_log_group_arn`](struct.UpdateTrailRequest.html#structfield.cloud_watch_logs_log_group_arn) member.
+    pub fn cloud_watch_logs_log_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cloud_watch_logs_log_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cloud_watch_logs_role_arn`](struct.UpdateTrailRequest.html#structfield.cloud_watch_logs_role_arn) member.
+    pub fn cloud_watch_logs_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cloud_watch_logs_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_log_file_validation`](struct.UpdateTrailRequest.html#structfield.enable_log_file_validation) member.
+    pub fn enable_log_file_validation<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_log_file_validation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_global_service_events`](struct.UpdateTrailRequest.html#structfield.include_global_service_events) member.
+    pub fn include_global_service_events<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_global_service_events = Some(value.into());
+        self
+    }
+
+    /// Sets the [`is_multi_region_trail`](struct.UpdateTrailRequest.html#structfield.is_multi_region_trail) member.
+    pub fn is_multi_region_trail<T: Into<bool>>(mut self, value: T) -> Self {
+        self.is_multi_region_trail = Some(value.into());
+        self
+    }
+
+    /// Sets the [`is_organization_trail`](struct.UpdateTrailRequest.html#structfield.is_organization_trail) member.
+    pub fn is_organization_trail<T: Into<bool>>(mut self, value: T) -> Self {
+        self.is_organization_trail = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.UpdateTrailRequest.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateTrailRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_bucket_name`](struct.UpdateTrailRequest.html#structfield.s3_bucket_name) member.
+    pub fn s3_bucket_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_bucket_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_key_prefix`](struct.UpdateTrailRequest.html#structfield.s3_key_prefix) member.
+    pub fn s3_key_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_key_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_name`](struct.UpdateTrailRequest.html#structfield.sns_topic_name) member.
+    pub fn sns_topic_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTrailRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateTrailRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTrailRequest {
+            cloud_watch_logs_log_group_arn: self.cloud_watch_logs_log_group_arn,
+            cloud_watch_logs_role_arn: self.cloud_watch_logs_role_arn,
+            enable_log_file_validation: self.enable_log_file_validation,
+            include_global_service_events: self.include_global_service_events,
+            is_multi_region_trail: self.is_multi_region_trail,
+            is_organization_trail: self.is_organization_trail,
+            kms_key_id: self.kms_key_id,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            s3_bucket_name: self.s3_bucket_name,
+            s3_key_prefix: self.s3_key_prefix,
+            sns_topic_name: self.sns_topic_name,
+        })
+    }
+}
+
 /// <p>Returns the objects or data listed below if successful. Otherwise, returns an error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2021,6 +2877,93 @@ pub trait CloudTrail {
         input: UpdateTrailRequest,
     ) -> RusotoFuture<UpdateTrailResponse, UpdateTrailError>;
 }
+impl<T: ?Sized + CloudTrail> CloudTrail for ::std::sync::Arc<T> {
+    fn add_tags(&self, input: AddTagsRequest) -> RusotoFuture<AddTagsResponse, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_trail(
+        &self,
+        input: CreateTrailRequest,
+    ) -> RusotoFuture<CreateTrailResponse, CreateTrailError> {
+        (**self).create_trail(input)
+    }
+    fn delete_trail(
+        &self,
+        input: DeleteTrailRequest,
+    ) -> RusotoFuture<DeleteTrailResponse, DeleteTrailError> {
+        (**self).delete_trail(input)
+    }
+    fn describe_trails(
+        &self,
+        input: DescribeTrailsRequest,
+    ) -> RusotoFuture<DescribeTrailsResponse, DescribeTrailsError> {
+        (**self).describe_trails(input)
+    }
+    fn get_event_selectors(
+        &self,
+        input: GetEventSelectorsRequest,
+    ) -> RusotoFuture<GetEventSelectorsResponse, GetEventSelectorsError> {
+        (**self).get_event_selectors(input)
+    }
+    fn get_trail_status(
+        &self,
+        input: GetTrailStatusRequest,
+    ) -> RusotoFuture<GetTrailStatusResponse, GetTrailStatusError> {
+        (**self).get_trail_status(input)
+    }
+    fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> RusotoFuture<ListPublicKeysResponse, ListPublicKeysError> {
+        (**self).list_public_keys(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn lookup_events(
+        &self,
+        input: LookupEventsRequest,
+    ) -> RusotoFuture<LookupEventsResponse, LookupEventsError> {
+        (**self).lookup_events(input)
+    }
+    fn put_event_selectors(
+        &self,
+        input: PutEventSelectorsRequest,
+    ) -> RusotoFuture<PutEventSelectorsResponse, PutEventSelectorsError> {
+        (**self).put_event_selectors(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> RusotoFuture<RemoveTagsResponse, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn start_logging(
+        &self,
+        input: StartLoggingRequest,
+    ) -> RusotoFuture<StartLoggingResponse, StartLoggingError> {
+        (**self).start_logging(input)
+    }
+    fn stop_logging(
+        &self,
+        input: StopLoggingRequest,
+    ) -> RusotoFuture<StopLoggingResponse, StopLoggingError> {
+        (**self).stop_logging(input)
+    }
+    fn update_trail(
+        &self,
+        input: UpdateTrailRequest,
+    ) -> RusotoFuture<UpdateTrailResponse, UpdateTrailError> {
+        (**self).update_trail(input)
+    }
+}
+/// The error codes CloudTrail retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the CloudTrail API.
 #[derive(Clone)]
 pub struct CloudTrailClient {
@@ -2036,6 +2979,27 @@ impl CloudTrailClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CLOUDTRAIL`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CloudTrailClient {
+        Self::new(region::Region::from_env_for_service("CLOUDTRAIL"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `cloudtrail`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CloudTrailClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("cloudtrail", "CLOUDTRAIL"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2054,7 +3018,54 @@ impl CloudTrailClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudTrailClient {
-        CloudTrailClient { client, region }
+        CloudTrailClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "cloudtrail")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "cloudtrail",
+            "cloudtrail",
+            "CLOUDTRAIL",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CloudTrail` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CloudTrail + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CloudTrailClient {
+    fn service_name(&self) -> &'static str {
+        "CloudTrail"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "cloudtrail"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CloudTrailClient {
+        Self::new_with_client(client, region)
     }
 }
 
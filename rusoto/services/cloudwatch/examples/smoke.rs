@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon CloudWatch clients.
+//!
+//! Makes a single read-only `ListDashboards` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_cloudwatch::{CloudWatch, CloudWatchClient, ListDashboardsInput};
+
+fn main() {
+    let client = CloudWatchClient::from_env();
+    match client
+        .list_dashboards(ListDashboardsInput::default())
+        .sync()
+    {
+        Ok(_) => println!("ListDashboards succeeded"),
+        Err(error) => {
+            eprintln!("ListDashboards failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
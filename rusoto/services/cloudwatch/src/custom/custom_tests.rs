@@ -1,6 +1,11 @@
 extern crate rusoto_mock;
 
-use crate::generated::{CloudWatch, CloudWatchClient, Dimension, MetricDatum, PutMetricDataInput};
+use std::sync::{Arc, Mutex};
+
+use crate::custom::metrics::{metric_buffer, MetricBufferConfig, MetricBufferSummary};
+use crate::generated::{
+    CloudWatch, CloudWatchClient, Dimension, MetricDatum, PutMetricDataInput, StandardUnit,
+};
 
 use self::rusoto_mock::*;
 use rusoto_core::param::Params;
@@ -54,7 +59,7 @@ fn should_serialize_complex_metric_data_params() {
         metric_name: "buffers".to_string(),
         statistic_values: None,
         timestamp: None,
-        unit: Some("Bytes".to_string()),
+        unit: Some(StandardUnit::Bytes),
         value: Some(1.0),
         ..Default::default()
     }];
@@ -67,3 +72,58 @@ fn should_serialize_complex_metric_data_params() {
     let response = client.put_metric_data(request).sync().unwrap();
     println!("{:#?}", response);
 }
+
+#[test]
+fn should_send_aggregated_metric_data_when_the_buffer_is_dropped() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = requests.clone();
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body("")
+        .with_request_checker(move |request: &SignedRequest| {
+            let params: Params = match request.payload {
+                Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                    serde_urlencoded::from_bytes(buffer).unwrap()
+                }
+                _ => panic!("expected a buffered payload"),
+            };
+            recorded.lock().unwrap().push(params);
+        });
+    let client = CloudWatchClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let (metrics, sender) = metric_buffer(client, MetricBufferConfig::new("MyApp"));
+    for latency in &[12.0, 18.5, 9.5] {
+        metrics
+            .record("Latency", &[("Operation", "GetItem")], *latency, None)
+            .unwrap();
+    }
+    metrics.record("Errors", &[], 1.0, Some("Count")).unwrap();
+    drop(metrics);
+
+    let summary = sender.sync().unwrap();
+    assert_eq!(
+        summary,
+        MetricBufferSummary {
+            batches: 1,
+            datums: 2,
+            samples: 4,
+        }
+    );
+
+    let requests = requests.lock().unwrap();
+    let param = |name: &str| requests[0].get(name).cloned().and_then(|value| value);
+    assert_eq!(param("Namespace"), Some("MyApp".to_owned()));
+    assert_eq!(
+        param("MetricData.member.1.StatisticValues.SampleCount"),
+        Some("3".to_owned())
+    );
+    assert_eq!(
+        param("MetricData.member.1.StatisticValues.Sum"),
+        Some("40".to_owned())
+    );
+    assert_eq!(param("MetricData.member.1.Value"), None);
+    assert_eq!(
+        param("MetricData.member.2.MetricName"),
+        Some("Errors".to_owned())
+    );
+    assert_eq!(param("MetricData.member.2.Value"), Some("1".to_owned()));
+}
@@ -0,0 +1,642 @@
+//! Buffering metric data for `PutMetricData`.
+//!
+//! `PutMetricData` accepts at most 20 datums and 40 KB per call, and calling it for every sample
+//! of a busy metric is slow and gets throttled. `metric_buffer` returns a `MetricBuffer` handle
+//! and a future which aggregates the samples recorded through it into statistic sets, one per
+//! metric, dimensions, unit and storage resolution, and sends them in batches once a batch is
+//! full or the flush interval elapsed, retrying throttled calls. The future completes once every
+//! handle is dropped and the remaining data is sent.
+//!
+//! `gzip_put_metric_data` makes a client compress the payload of its `PutMetricData` calls,
+//! which is many times smaller than the form encoded datums.
+
+use std::error::Error;
+use std::fmt;
+use std::io::Write;
+use std::mem;
+use std::time::Duration;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use futures::future::{self, Loop};
+use futures::sync::mpsc;
+use futures::{Future, Stream};
+use rusoto_core::batch::{backoff, batched, is_transient, Batcher};
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::waiter::sleep;
+use rusoto_core::{Client, RusotoError, RusotoFuture};
+
+use crate::generated::{
+    CloudWatch, Dimension, MetricDatum, PutMetricDataError, PutMetricDataInput, StandardUnit,
+    StatisticSet,
+};
+
+/// The maximum number of datums in a single `PutMetricData` call.
+pub const MAX_BATCH_DATUMS: usize = 20;
+
+/// The maximum size of the form encoded payload of a single `PutMetricData` call.
+pub const MAX_BATCH_BYTES: usize = 40 * 1024;
+
+/// Payloads smaller than this are sent uncompressed by `gzip_put_metric_data`.
+const MIN_COMPRESSED_BYTES: usize = 1024;
+
+/// The size of the parameters of a `PutMetricData` call besides its datums and namespace.
+const REQUEST_OVERHEAD: usize = "Action=PutMetricData&Version=2010-08-01&Namespace=".len();
+
+/// Controls how `metric_buffer` aggregates and sends metric data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricBufferConfig {
+    /// The namespace of the metrics
+    pub namespace: String,
+    /// Maximum number of datums per call
+    pub max_batch_datums: usize,
+    /// Maximum estimated size of the uncompressed payload of a call
+    pub max_batch_bytes: usize,
+    /// How long samples are aggregated before they are sent, unless their batch fills up first
+    pub flush_interval: Duration,
+    /// Number of attempts made for each batch before the buffer fails
+    pub max_attempts: u32,
+    /// Delay before the first retry of a throttled call, doubled for every subsequent retry
+    pub base_delay: Duration,
+}
+
+impl MetricBufferConfig {
+    /// Creates a configuration sending metrics to `namespace`, with the limits of `PutMetricData`
+    /// and a flush interval of a minute, the resolution of standard metrics.
+    pub fn new<N>(namespace: N) -> MetricBufferConfig
+    where
+        N: Into<String>,
+    {
+        MetricBufferConfig {
+            namespace: namespace.into(),
+            max_batch_datums: MAX_BATCH_DATUMS,
+            max_batch_bytes: MAX_BATCH_BYTES,
+            flush_interval: Duration::from_secs(60),
+            max_attempts: 5,
+            base_delay: Duration::from_millis(200),
+        }
+    }
+}
+
+/// Statistics of a finished `metric_buffer` future.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MetricBufferSummary {
+    /// Number of `PutMetricData` calls which succeeded
+    pub batches: usize,
+    /// Number of datums sent
+    pub datums: usize,
+    /// Number of datums put into the buffer, before aggregation
+    pub samples: usize,
+}
+
+/// Errors returned by the `metric_buffer` future.
+#[derive(Debug, PartialEq)]
+pub enum MetricBufferError {
+    /// Sending a batch failed
+    PutMetricData(PutMetricDataError),
+}
+
+impl fmt::Display for MetricBufferError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for MetricBufferError {
+    fn description(&self) -> &str {
+        match *self {
+            MetricBufferError::PutMetricData(ref cause) => cause.description(),
+        }
+    }
+}
+
+/// A handle putting metric data into the buffer of a `metric_buffer` future.
+///
+/// Clones put data into the same buffer.
+#[derive(Clone, Debug)]
+pub struct MetricBuffer {
+    sender: mpsc::UnboundedSender<MetricDatum>,
+}
+
+impl MetricBuffer {
+    /// Records a sample of the metric `metric_name` with `dimensions`.
+    ///
+    /// Fails, returning the datum, once the buffer's future has failed or was dropped.
+    pub fn record(
+        &self,
+        metric_name: &str,
+        dimensions: &[(&str, &str)],
+        value: f64,
+        unit: Option<&str>,
+    ) -> Result<(), MetricDatum> {
+        self.put(MetricDatum {
+            metric_name: metric_name.to_owned(),
+            dimensions: if dimensions.is_empty() {
+                None
+            } else {
+                Some(
+                    dimensions
+                        .iter()
+                        .map(|&(name, value)| Dimension {
+                            name: name.to_owned(),
+                            value: value.to_owned(),
+                        })
+                        .collect(),
+                )
+            },
+            value: Some(value),
+            unit: unit.map(StandardUnit::from),
+            ..Default::default()
+        })
+    }
+
+    /// Puts a datum into the buffer.
+    ///
+    /// Datums with a single value and no timestamp are aggregated with the other samples of
+    /// their metric, dimensions, unit and storage resolution. Others, e.g. with a timestamp or
+    /// their own statistic set, are sent as they are.
+    ///
+    /// Fails, returning the datum, once the buffer's future has failed or was dropped.
+    pub fn put(&self, datum: MetricDatum) -> Result<(), MetricDatum> {
+        self.sender
+            .unbounded_send(datum)
+            .map_err(|error| error.into_inner())
+    }
+}
+
+/// Returns a buffer accepting metric data, and a future sending it to the namespace named in
+/// `config`.
+///
+/// The future must be run for data to be sent. It resolves once every handle of the buffer is
+/// dropped and all data put into it until then is sent, and fails if a batch can't be sent.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rusoto_cloudwatch::metrics::{gzip_put_metric_data, metric_buffer, MetricBufferConfig};
+/// use rusoto_cloudwatch::CloudWatchClient;
+/// use rusoto_core::{Client, Region};
+///
+/// let client = CloudWatchClient::new_with_client(
+///     gzip_put_metric_data(Client::shared()),
+///     Region::UsEast1,
+/// );
+/// let (metrics, sender) = metric_buffer(client, MetricBufferConfig::new("MyApp"));
+///
+/// for latency in &[12.0, 18.5, 9.25] {
+///     metrics
+///         .record("Latency", &[("Operation", "GetItem")], *latency, Some("Milliseconds"))
+///         .expect("buffer failed");
+/// }
+///
+/// // dropping the last handle lets the future finish once all data is sent
+/// drop(metrics);
+/// let summary = sender.sync().expect("failed to send metrics");
+/// println!("sent {} samples in {} datums", summary.samples, summary.datums);
+/// ```
+pub fn metric_buffer<C>(
+    client: C,
+    config: MetricBufferConfig,
+) -> (
+    MetricBuffer,
+    RusotoFuture<MetricBufferSummary, MetricBufferError>,
+)
+where
+    C: CloudWatch + Clone + Send + 'static,
+{
+    let (sender, receiver) = mpsc::unbounded();
+    let batches = batched::<_, _, RusotoError<MetricBufferError>>(
+        receiver,
+        DatumBatcher::new(&config),
+        config.flush_interval,
+    );
+
+    let future = batches.fold(
+        MetricBufferSummary::default(),
+        move |mut summary, (batch, samples)| {
+            let datums = batch.len();
+            put_batch(client.clone(), config.clone(), batch).map(move |()| {
+                summary.batches += 1;
+                summary.datums += datums;
+                summary.samples += samples;
+                summary
+            })
+        },
+    );
+
+    (MetricBuffer { sender }, RusotoFuture::from_future(future))
+}
+
+/// Makes `client` compress the payload of `PutMetricData` calls of at least 1 KB with gzip.
+///
+/// The payload is compressed before the request is signed, and sent with a
+/// `Content-Encoding: gzip` header. Other requests are sent as they are.
+pub fn gzip_put_metric_data(client: Client) -> Client {
+    client.with_unsigned_request_interceptor(compress_put_metric_data)
+}
+
+fn compress_put_metric_data(request: &mut SignedRequest) {
+    let compressed = match request.payload {
+        Some(SignedRequestPayload::Buffer(ref payload))
+            if payload.len() >= MIN_COMPRESSED_BYTES && is_put_metric_data(payload) =>
+        {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            match encoder.write_all(payload).and_then(|()| encoder.finish()) {
+                Ok(compressed) => compressed,
+                Err(_) => return,
+            }
+        }
+        _ => return,
+    };
+    request.set_payload(Some(compressed));
+    request.add_header("Content-Encoding", "gzip");
+}
+
+fn is_put_metric_data(payload: &[u8]) -> bool {
+    payload
+        .split(|&byte| byte == b'&')
+        .any(|param| param == &b"Action=PutMetricData"[..])
+}
+
+/// The length of `value` once form encoded.
+fn encoded_len(value: &str) -> usize {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'*' => 1,
+            _ => 3,
+        })
+        .sum()
+}
+
+/// An estimate of the size of the parameters of `datum` in the payload of `PutMetricData`,
+/// assuming the longest index in its member names and the longest numbers.
+fn estimated_size(datum: &MetricDatum) -> usize {
+    const NUMBER: usize = 24;
+    let param = |name: &str, value: usize| "MetricData.member.NN.=&".len() + name.len() + value;
+
+    let mut size = param("MetricName", encoded_len(&datum.metric_name));
+    for dimension in datum.dimensions.iter().flatten() {
+        size += param("Dimensions.member.NN.Name", encoded_len(&dimension.name));
+        size += param("Dimensions.member.NN.Value", encoded_len(&dimension.value));
+    }
+    if let Some(ref unit) = datum.unit {
+        size += param("Unit", encoded_len(unit.as_str()));
+    }
+    if datum.value.is_some() {
+        size += param("Value", NUMBER);
+    }
+    if datum.statistic_values.is_some() {
+        size += param("StatisticValues.SampleCount", NUMBER)
+            + param("StatisticValues.Maximum", NUMBER)
+            + param("StatisticValues.Minimum", NUMBER)
+            + param("StatisticValues.Sum", NUMBER);
+    }
+    if datum.storage_resolution.is_some() {
+        size += param("StorageResolution", 2);
+    }
+    if datum.timestamp.is_some() {
+        size += param("Timestamp", NUMBER);
+    }
+    for _ in datum.values.iter().flatten() {
+        size += param("Values.member.NNN", NUMBER);
+    }
+    for _ in datum.counts.iter().flatten() {
+        size += param("Counts.member.NNN", NUMBER);
+    }
+    size
+}
+
+/// What samples are aggregated by: their metric, dimensions, unit and storage resolution.
+#[derive(Clone, Debug, PartialEq)]
+struct AggregationKey {
+    metric_name: String,
+    dimensions: Vec<(String, String)>,
+    unit: Option<StandardUnit>,
+    storage_resolution: Option<i64>,
+}
+
+impl AggregationKey {
+    /// The key of `datum`, unless it has a timestamp or more than a single value.
+    fn of(datum: &MetricDatum) -> Option<AggregationKey> {
+        if datum.value.is_none()
+            || datum.timestamp.is_some()
+            || datum.statistic_values.is_some()
+            || datum.values.is_some()
+            || datum.counts.is_some()
+        {
+            return None;
+        }
+        let mut dimensions = datum
+            .dimensions
+            .iter()
+            .flatten()
+            .map(|dimension| (dimension.name.clone(), dimension.value.clone()))
+            .collect::<Vec<_>>();
+        dimensions.sort();
+        Some(AggregationKey {
+            metric_name: datum.metric_name.clone(),
+            dimensions,
+            unit: datum.unit.clone(),
+            storage_resolution: datum.storage_resolution,
+        })
+    }
+}
+
+/// A datum of a batch, with the samples aggregated into it.
+struct Entry {
+    key: Option<AggregationKey>,
+    datum: MetricDatum,
+    size: usize,
+}
+
+impl Entry {
+    fn new(datum: MetricDatum) -> Entry {
+        Entry {
+            key: AggregationKey::of(&datum),
+            size: estimated_size(&datum),
+            datum,
+        }
+    }
+
+    /// Aggregates the single value `value` into the datum.
+    fn aggregate(&mut self, value: f64) {
+        let statistics = match (self.datum.value.take(), self.datum.statistic_values.take()) {
+            (_, Some(statistics)) => StatisticSet {
+                maximum: statistics.maximum.max(value),
+                minimum: statistics.minimum.min(value),
+                sample_count: statistics.sample_count + 1.0,
+                sum: statistics.sum + value,
+            },
+            (Some(first), None) => StatisticSet {
+                maximum: first.max(value),
+                minimum: first.min(value),
+                sample_count: 2.0,
+                sum: first + value,
+            },
+            (None, None) => StatisticSet {
+                maximum: value,
+                minimum: value,
+                sample_count: 1.0,
+                sum: value,
+            },
+        };
+        self.datum.statistic_values = Some(statistics);
+        self.size = estimated_size(&self.datum);
+    }
+}
+
+/// Aggregates datums into batches within the limits of `PutMetricData`, batched with the number
+/// of samples aggregated into them.
+struct DatumBatcher {
+    entries: Vec<Entry>,
+    samples: usize,
+    pending_bytes: usize,
+    max_datums: usize,
+    max_bytes: usize,
+}
+
+impl DatumBatcher {
+    fn new(config: &MetricBufferConfig) -> DatumBatcher {
+        DatumBatcher {
+            entries: Vec::new(),
+            samples: 0,
+            pending_bytes: 0,
+            max_datums: config.max_batch_datums.max(1),
+            max_bytes: config
+                .max_batch_bytes
+                .saturating_sub(REQUEST_OVERHEAD + encoded_len(&config.namespace)),
+        }
+    }
+
+    fn take_batch(&mut self) -> (Vec<MetricDatum>, usize) {
+        self.pending_bytes = 0;
+        let entries = mem::replace(&mut self.entries, Vec::new());
+        let batch = entries.into_iter().map(|entry| entry.datum).collect();
+        (batch, mem::replace(&mut self.samples, 0))
+    }
+}
+
+impl Batcher for DatumBatcher {
+    type Item = MetricDatum;
+    type Batch = (Vec<MetricDatum>, usize);
+
+    /// Adds `datum` to the pending batch, returning the batch once it's full.
+    fn push(&mut self, datum: MetricDatum) -> Option<(Vec<MetricDatum>, usize)> {
+        let key = AggregationKey::of(&datum);
+        let existing = key.as_ref().and_then(|key| {
+            self.entries
+                .iter()
+                .position(|entry| entry.key.as_ref() == Some(key))
+        });
+        if let (Some(index), Some(value)) = (existing, datum.value) {
+            let entry = &mut self.entries[index];
+            let previous_size = entry.size;
+            entry.aggregate(value);
+            self.pending_bytes = self.pending_bytes - previous_size + entry.size;
+            self.samples += 1;
+            return None;
+        }
+
+        let entry = Entry::new(datum);
+        let batch = if !self.entries.is_empty() && self.pending_bytes + entry.size > self.max_bytes
+        {
+            Some(self.take_batch())
+        } else {
+            None
+        };
+        self.pending_bytes += entry.size;
+        self.entries.push(entry);
+        self.samples += 1;
+        // a batch split off for its size is followed by a datum which doesn't fill the next one,
+        // unless batches hold a single datum
+        match batch {
+            Some(batch) => Some(batch),
+            None if self.entries.len() >= self.max_datums => Some(self.take_batch()),
+            None => None,
+        }
+    }
+
+    fn flush(&mut self) -> Option<(Vec<MetricDatum>, usize)> {
+        if self.entries.is_empty() {
+            None
+        } else {
+            Some(self.take_batch())
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+type PutAttempt =
+    Box<dyn Future<Item = Loop<(), u32>, Error = RusotoError<MetricBufferError>> + Send>;
+
+/// Sends a batch, retrying throttled calls.
+fn put_batch<C>(
+    client: C,
+    config: MetricBufferConfig,
+    batch: Vec<MetricDatum>,
+) -> impl Future<Item = (), Error = RusotoError<MetricBufferError>> + Send
+where
+    C: CloudWatch + Clone + Send + 'static,
+{
+    future::loop_fn(0, move |attempts| {
+        let attempts = attempts + 1;
+        let request = PutMetricDataInput {
+            metric_data: batch.clone(),
+            namespace: config.namespace.clone(),
+        };
+        let last_attempt = attempts >= config.max_attempts;
+        let base_delay = config.base_delay;
+
+        client.put_metric_data(request).then(move |result| {
+            let next: PutAttempt = match result {
+                Ok(()) => Box::new(future::ok(Loop::Break(()))),
+                Err(ref err) if is_throttled(err) && !last_attempt => {
+                    let delay = backoff(base_delay, attempts);
+                    Box::new(sleep(delay).map(move |_| Loop::Continue(attempts)))
+                }
+                Err(err) => Box::new(future::err(
+                    err.map_service(MetricBufferError::PutMetricData),
+                )),
+            };
+            next
+        })
+    })
+}
+
+fn is_throttled(err: &RusotoError<PutMetricDataError>) -> bool {
+    match *err {
+        RusotoError::Service(PutMetricDataError::InternalServiceFault(_)) => true,
+        ref err => is_transient(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::timestamp::from_iso8601;
+    use rusoto_core::Region;
+
+    fn sample(metric_name: &str, dimension: &str, value: f64) -> MetricDatum {
+        MetricDatum {
+            metric_name: metric_name.to_owned(),
+            dimensions: Some(vec![Dimension {
+                name: "Operation".to_owned(),
+                value: dimension.to_owned(),
+            }]),
+            value: Some(value),
+            unit: Some(StandardUnit::Milliseconds),
+            ..Default::default()
+        }
+    }
+
+    fn batcher(max_batch_datums: usize, max_batch_bytes: usize) -> DatumBatcher {
+        DatumBatcher::new(&MetricBufferConfig {
+            max_batch_datums,
+            max_batch_bytes,
+            ..MetricBufferConfig::new("MyApp")
+        })
+    }
+
+    #[test]
+    fn aggregates_samples_into_statistic_sets() {
+        let mut batcher = batcher(MAX_BATCH_DATUMS, MAX_BATCH_BYTES);
+        for value in &[12.0, 18.5, 9.5] {
+            assert_eq!(batcher.push(sample("Latency", "GetItem", *value)), None);
+        }
+        assert_eq!(batcher.push(sample("Latency", "PutItem", 30.0)), None);
+        let mut timestamped = sample("Latency", "GetItem", 1.0);
+        timestamped.timestamp = Some(from_iso8601("2019-10-01T12:00:00Z".to_owned()).unwrap());
+        assert_eq!(batcher.push(timestamped.clone()), None);
+
+        let (batch, samples) = batcher.flush().unwrap();
+        assert_eq!(samples, 5);
+        assert_eq!(batch.len(), 3);
+        assert_eq!(batch[0].value, None);
+        assert_eq!(
+            batch[0].statistic_values,
+            Some(StatisticSet {
+                maximum: 18.5,
+                minimum: 9.5,
+                sample_count: 3.0,
+                sum: 40.0,
+            })
+        );
+        assert_eq!(batch[1].value, Some(30.0));
+        assert_eq!(batch[1].statistic_values, None);
+        assert_eq!(batch[2], timestamped);
+    }
+
+    #[test]
+    fn flushes_full_batches() {
+        let mut batcher = batcher(2, MAX_BATCH_BYTES);
+        assert_eq!(batcher.push(sample("Latency", "GetItem", 1.0)), None);
+        assert_eq!(batcher.push(sample("Latency", "GetItem", 2.0)), None);
+        let (batch, samples) = batcher.push(sample("Latency", "PutItem", 3.0)).unwrap();
+        assert_eq!((batch.len(), samples), (2, 3));
+        assert!(batcher.entries.is_empty());
+    }
+
+    #[test]
+    fn splits_batches_by_size() {
+        let datum = sample("Latency", "GetItem", 1.0);
+        let size = estimated_size(&datum);
+        let mut batcher = batcher(MAX_BATCH_DATUMS, REQUEST_OVERHEAD + 5 + size * 2);
+        assert_eq!(batcher.push(sample("Latency", "A", 1.0)), None);
+        assert_eq!(batcher.push(sample("Latency", "B", 1.0)), None);
+        let (batch, _) = batcher.push(sample("Latency", "C", 1.0)).unwrap();
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batcher.entries.len(), 1);
+    }
+
+    #[test]
+    fn estimates_the_encoded_size() {
+        assert_eq!(encoded_len("Latency"), 7);
+        assert_eq!(encoded_len("a b/c"), 9);
+        let datum = sample("Latency", "GetItem", 1.0);
+        let params = vec![
+            "MetricData.member.1.MetricName=Latency".to_owned(),
+            "MetricData.member.1.Dimensions.member.1.Name=Operation".to_owned(),
+            "MetricData.member.1.Dimensions.member.1.Value=GetItem".to_owned(),
+            "MetricData.member.1.Unit=Milliseconds".to_owned(),
+            "MetricData.member.1.Value=1".to_owned(),
+        ];
+        let actual = params.iter().map(|param| param.len() + 1).sum::<usize>();
+        assert!(estimated_size(&datum) >= actual);
+        assert!(estimated_size(&datum) < actual * 2);
+    }
+
+    #[test]
+    fn compresses_put_metric_data_payloads() {
+        let payload = format!(
+            "Action=PutMetricData&MetricData.member.1.MetricName={}&Namespace=MyApp",
+            "x".repeat(2000)
+        );
+        let mut request = SignedRequest::new("POST", "monitoring", &Region::UsEast1, "/");
+        request.set_payload(Some(payload.clone()));
+        compress_put_metric_data(&mut request);
+        assert_eq!(
+            request.headers.get("content-encoding"),
+            Some(&vec![b"gzip".to_vec()])
+        );
+        match request.payload {
+            Some(SignedRequestPayload::Buffer(ref compressed)) => {
+                assert!(compressed.len() < payload.len() / 10)
+            }
+            _ => panic!("payload is not a buffer"),
+        }
+
+        let mut small = SignedRequest::new("POST", "monitoring", &Region::UsEast1, "/");
+        small.set_payload(Some("Action=PutMetricData&Namespace=MyApp"));
+        compress_put_metric_data(&mut small);
+        assert!(small.headers.get("content-encoding").is_none());
+
+        let mut other = SignedRequest::new("POST", "monitoring", &Region::UsEast1, "/");
+        other.set_payload(Some(payload.replace("PutMetricData", "ListMetrics")));
+        compress_put_metric_data(&mut other);
+        assert!(other.headers.get("content-encoding").is_none());
+    }
+}
@@ -1,2 +1,5 @@
+/// Buffering, aggregating and compressing metric data for `PutMetricData`
+pub mod metrics;
+
 #[cfg(test)]
 mod custom_tests;
@@ -28,15 +28,19 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
 use xml::EventReader;
 
-struct ActionsEnabledDeserializer;
+pub(crate) struct ActionsEnabledDeserializer;
 impl ActionsEnabledDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -44,10 +48,13 @@ impl ActionsEnabledDeserializer {
         Ok(obj)
     }
 }
-struct AlarmArnDeserializer;
+pub(crate) struct AlarmArnDeserializer;
 impl AlarmArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -55,10 +62,13 @@ impl AlarmArnDeserializer {
         Ok(obj)
     }
 }
-struct AlarmDescriptionDeserializer;
+pub(crate) struct AlarmDescriptionDeserializer;
 impl AlarmDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -74,17 +84,17 @@ pub struct AlarmHistoryItem {
     /// <p>Data about the alarm, in JSON format.</p>
     pub history_data: Option<String>,
     /// <p>The type of alarm history item.</p>
-    pub history_item_type: Option<String>,
+    pub history_item_type: Option<HistoryItemType>,
     /// <p>A summary of the alarm history, in text format.</p>
     pub history_summary: Option<String>,
     /// <p>The time stamp for the alarm history item.</p>
-    pub timestamp: Option<String>,
+    pub timestamp: Option<IsoTimestamp>,
 }
 
-struct AlarmHistoryItemDeserializer;
+pub(crate) struct AlarmHistoryItemDeserializer;
 impl AlarmHistoryItemDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AlarmHistoryItem, XmlParseError> {
@@ -118,10 +128,10 @@ impl AlarmHistoryItemDeserializer {
         })
     }
 }
-struct AlarmHistoryItemsDeserializer;
+pub(crate) struct AlarmHistoryItemsDeserializer;
 impl AlarmHistoryItemsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AlarmHistoryItem>, XmlParseError> {
@@ -135,10 +145,13 @@ impl AlarmHistoryItemsDeserializer {
         })
     }
 }
-struct AlarmNameDeserializer;
+pub(crate) struct AlarmNameDeserializer;
 impl AlarmNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -173,10 +186,10 @@ pub struct AnomalyDetector {
     pub stat: Option<String>,
 }
 
-struct AnomalyDetectorDeserializer;
+pub(crate) struct AnomalyDetectorDeserializer;
 impl AnomalyDetectorDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AnomalyDetector, XmlParseError> {
@@ -219,10 +232,10 @@ pub struct AnomalyDetectorConfiguration {
     pub metric_timezone: Option<String>,
 }
 
-struct AnomalyDetectorConfigurationDeserializer;
+pub(crate) struct AnomalyDetectorConfigurationDeserializer;
 impl AnomalyDetectorConfigurationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AnomalyDetectorConfiguration, XmlParseError> {
@@ -276,10 +289,10 @@ impl AnomalyDetectorConfigurationSerializer {
     }
 }
 
-struct AnomalyDetectorExcludedTimeRangesDeserializer;
+pub(crate) struct AnomalyDetectorExcludedTimeRangesDeserializer;
 impl AnomalyDetectorExcludedTimeRangesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Range>, XmlParseError> {
@@ -305,10 +318,13 @@ impl AnomalyDetectorExcludedTimeRangesSerializer {
     }
 }
 
-struct AnomalyDetectorMetricTimezoneDeserializer;
+pub(crate) struct AnomalyDetectorMetricTimezoneDeserializer;
 impl AnomalyDetectorMetricTimezoneDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -316,10 +332,10 @@ impl AnomalyDetectorMetricTimezoneDeserializer {
         Ok(obj)
     }
 }
-struct AnomalyDetectorsDeserializer;
+pub(crate) struct AnomalyDetectorsDeserializer;
 impl AnomalyDetectorsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AnomalyDetector>, XmlParseError> {
@@ -333,18 +349,104 @@ impl AnomalyDetectorsDeserializer {
         })
     }
 }
-struct ComparisonOperatorDeserializer;
+pub(crate) struct ComparisonOperatorDeserializer;
 impl ComparisonOperatorDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ComparisonOperator, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 
+/// <p>The arithmetic operation to use when comparing the specified statistic and threshold. The specified statistic value is used as the first operand.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonOperator {
+    /// <p><code>GreaterThanOrEqualToThreshold</code></p>
+    GreaterThanOrEqualToThreshold,
+    /// <p><code>GreaterThanThreshold</code></p>
+    GreaterThanThreshold,
+    /// <p><code>LessThanThreshold</code></p>
+    LessThanThreshold,
+    /// <p><code>LessThanOrEqualToThreshold</code></p>
+    LessThanOrEqualToThreshold,
+    /// <p><code>LessThanLowerOrGreaterThanUpperThreshold</code></p>
+    LessThanLowerOrGreaterThanUpperThreshold,
+    /// <p><code>LessThanLowerThreshold</code></p>
+    LessThanLowerThreshold,
+    /// <p><code>GreaterThanUpperThreshold</code></p>
+    GreaterThanUpperThreshold,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::GreaterThanOrEqualToThreshold => "GreaterThanOrEqualToThreshold",
+            ComparisonOperator::GreaterThanThreshold => "GreaterThanThreshold",
+            ComparisonOperator::LessThanThreshold => "LessThanThreshold",
+            ComparisonOperator::LessThanOrEqualToThreshold => "LessThanOrEqualToThreshold",
+            ComparisonOperator::LessThanLowerOrGreaterThanUpperThreshold => {
+                "LessThanLowerOrGreaterThanUpperThreshold"
+            }
+            ComparisonOperator::LessThanLowerThreshold => "LessThanLowerThreshold",
+            ComparisonOperator::GreaterThanUpperThreshold => "GreaterThanUpperThreshold",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "GreaterThanOrEqualToThreshold" => ComparisonOperator::GreaterThanOrEqualToThreshold,
+            "GreaterThanThreshold" => ComparisonOperator::GreaterThanThreshold,
+            "LessThanThreshold" => ComparisonOperator::LessThanThreshold,
+            "LessThanOrEqualToThreshold" => ComparisonOperator::LessThanOrEqualToThreshold,
+            "LessThanLowerOrGreaterThanUpperThreshold" => {
+                ComparisonOperator::LessThanLowerOrGreaterThanUpperThreshold
+            }
+            "LessThanLowerThreshold" => ComparisonOperator::LessThanLowerThreshold,
+            "GreaterThanUpperThreshold" => ComparisonOperator::GreaterThanUpperThreshold,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        ComparisonOperator::from(value.as_str())
+    }
+}
+
+impl From<ComparisonOperator> for String {
+    fn from(value: ComparisonOperator) -> String {
+        match value {
+            ComparisonOperator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// Serialize `Counts` contents to a `SignedRequest`.
 struct CountsSerializer;
 impl CountsSerializer {
@@ -356,10 +458,13 @@ impl CountsSerializer {
     }
 }
 
-struct DashboardArnDeserializer;
+pub(crate) struct DashboardArnDeserializer;
 impl DashboardArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -367,10 +472,13 @@ impl DashboardArnDeserializer {
         Ok(obj)
     }
 }
-struct DashboardBodyDeserializer;
+pub(crate) struct DashboardBodyDeserializer;
 impl DashboardBodyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -378,10 +486,10 @@ impl DashboardBodyDeserializer {
         Ok(obj)
     }
 }
-struct DashboardEntriesDeserializer;
+pub(crate) struct DashboardEntriesDeserializer;
 impl DashboardEntriesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DashboardEntry>, XmlParseError> {
@@ -403,15 +511,15 @@ pub struct DashboardEntry {
     /// <p>The name of the dashboard.</p>
     pub dashboard_name: Option<String>,
     /// <p>The time stamp of when the dashboard was last modified, either by an API call or through the console. This number is expressed as the number of milliseconds since Jan 1, 1970 00:00:00 UTC.</p>
-    pub last_modified: Option<String>,
+    pub last_modified: Option<IsoTimestamp>,
     /// <p>The size of the dashboard, in bytes.</p>
     pub size: Option<i64>,
 }
 
-struct DashboardEntryDeserializer;
+pub(crate) struct DashboardEntryDeserializer;
 impl DashboardEntryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DashboardEntry, XmlParseError> {
@@ -444,10 +552,13 @@ impl DashboardEntryDeserializer {
         })
     }
 }
-struct DashboardNameDeserializer;
+pub(crate) struct DashboardNameDeserializer;
 impl DashboardNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -476,10 +587,10 @@ pub struct DashboardValidationMessage {
     pub message: Option<String>,
 }
 
-struct DashboardValidationMessageDeserializer;
+pub(crate) struct DashboardValidationMessageDeserializer;
 impl DashboardValidationMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DashboardValidationMessage, XmlParseError> {
@@ -501,10 +612,10 @@ impl DashboardValidationMessageDeserializer {
         )
     }
 }
-struct DashboardValidationMessagesDeserializer;
+pub(crate) struct DashboardValidationMessagesDeserializer;
 impl DashboardValidationMessagesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DashboardValidationMessage>, XmlParseError> {
@@ -520,10 +631,13 @@ impl DashboardValidationMessagesDeserializer {
         })
     }
 }
-struct DataPathDeserializer;
+pub(crate) struct DataPathDeserializer;
 impl DataPathDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -547,15 +661,15 @@ pub struct Datapoint {
     /// <p>The sum of the metric values for the data point.</p>
     pub sum: Option<f64>,
     /// <p>The time stamp used for the data point.</p>
-    pub timestamp: Option<String>,
+    pub timestamp: Option<IsoTimestamp>,
     /// <p>The standard unit for the data point.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
 }
 
-struct DatapointDeserializer;
+pub(crate) struct DatapointDeserializer;
 impl DatapointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Datapoint, XmlParseError> {
@@ -597,10 +711,13 @@ impl DatapointDeserializer {
         })
     }
 }
-struct DatapointValueDeserializer;
+pub(crate) struct DatapointValueDeserializer;
 impl DatapointValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -608,10 +725,10 @@ impl DatapointValueDeserializer {
         Ok(obj)
     }
 }
-struct DatapointValueMapDeserializer;
+pub(crate) struct DatapointValueMapDeserializer;
 impl DatapointValueMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, f64>, XmlParseError> {
@@ -631,10 +748,10 @@ impl DatapointValueMapDeserializer {
         Ok(obj)
     }
 }
-struct DatapointValuesDeserializer;
+pub(crate) struct DatapointValuesDeserializer;
 impl DatapointValuesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<f64>, XmlParseError> {
@@ -648,10 +765,10 @@ impl DatapointValuesDeserializer {
         })
     }
 }
-struct DatapointsDeserializer;
+pub(crate) struct DatapointsDeserializer;
 impl DatapointsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Datapoint>, XmlParseError> {
@@ -665,10 +782,13 @@ impl DatapointsDeserializer {
         })
     }
 }
-struct DatapointsToAlarmDeserializer;
+pub(crate) struct DatapointsToAlarmDeserializer;
 impl DatapointsToAlarmDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -682,6 +802,36 @@ pub struct DeleteAlarmsInput {
     pub alarm_names: Vec<String>,
 }
 
+impl DeleteAlarmsInput {
+    /// Returns a builder for `DeleteAlarmsInput`, checking its required members when built.
+    pub fn builder() -> DeleteAlarmsInputBuilder {
+        DeleteAlarmsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAlarmsInput`](struct.DeleteAlarmsInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteAlarmsInputBuilder {
+    alarm_names: Option<Vec<String>>,
+}
+
+impl DeleteAlarmsInputBuilder {
+    /// Sets the [`alarm_names`](struct.DeleteAlarmsInput.html#structfield.alarm_names) member.
+    pub fn alarm_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.alarm_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAlarmsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteAlarmsInput, ::rusoto_core::BuildError> {
+        Ok(DeleteAlarmsInput {
+            alarm_names: self
+                .alarm_names
+                .ok_or(::rusoto_core::BuildError::missing("alarm_names"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteAlarmsInput` contents to a `SignedRequest`.
 struct DeleteAlarmsInputSerializer;
 impl DeleteAlarmsInputSerializer {
@@ -711,6 +861,66 @@ pub struct DeleteAnomalyDetectorInput {
     pub stat: String,
 }
 
+impl DeleteAnomalyDetectorInput {
+    /// Returns a builder for `DeleteAnomalyDetectorInput`, checking its required members when built.
+    pub fn builder() -> DeleteAnomalyDetectorInputBuilder {
+        DeleteAnomalyDetectorInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAnomalyDetectorInput`](struct.DeleteAnomalyDetectorInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteAnomalyDetectorInputBuilder {
+    dimensions: Option<Vec<Dimension>>,
+    metric_name: Option<String>,
+    namespace: Option<String>,
+    stat: Option<String>,
+}
+
+impl DeleteAnomalyDetectorInputBuilder {
+    /// Sets the [`dimensions`](struct.DeleteAnomalyDetectorInput.html#structfield.dimensions) member.
+    pub fn dimensions<T: Into<Vec<Dimension>>>(mut self, value: T) -> Self {
+        self.dimensions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.DeleteAnomalyDetectorInput.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`namespace`](struct.DeleteAnomalyDetectorInput.html#structfield.namespace) member.
+    pub fn namespace<T: Into<String>>(mut self, value: T) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stat`](struct.DeleteAnomalyDetectorInput.html#structfield.stat) member.
+    pub fn stat<T: Into<String>>(mut self, value: T) -> Self {
+        self.stat = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAnomalyDetectorInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAnomalyDetectorInput, ::rusoto_core::BuildError> {
+        Ok(DeleteAnomalyDetectorInput {
+            dimensions: self.dimensions,
+            metric_name: self
+                .metric_name
+                .ok_or(::rusoto_core::BuildError::missing("metric_name"))?,
+            namespace: self
+                .namespace
+                .ok_or(::rusoto_core::BuildError::missing("namespace"))?,
+            stat: self
+                .stat
+                .ok_or(::rusoto_core::BuildError::missing("stat"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteAnomalyDetectorInput` contents to a `SignedRequest`.
 struct DeleteAnomalyDetectorInputSerializer;
 impl DeleteAnomalyDetectorInputSerializer {
@@ -736,10 +946,10 @@ impl DeleteAnomalyDetectorInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteAnomalyDetectorOutput {}
 
-struct DeleteAnomalyDetectorOutputDeserializer;
+pub(crate) struct DeleteAnomalyDetectorOutputDeserializer;
 impl DeleteAnomalyDetectorOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteAnomalyDetectorOutput, XmlParseError> {
@@ -758,6 +968,36 @@ pub struct DeleteDashboardsInput {
     pub dashboard_names: Vec<String>,
 }
 
+impl DeleteDashboardsInput {
+    /// Returns a builder for `DeleteDashboardsInput`, checking its required members when built.
+    pub fn builder() -> DeleteDashboardsInputBuilder {
+        DeleteDashboardsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDashboardsInput`](struct.DeleteDashboardsInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteDashboardsInputBuilder {
+    dashboard_names: Option<Vec<String>>,
+}
+
+impl DeleteDashboardsInputBuilder {
+    /// Sets the [`dashboard_names`](struct.DeleteDashboardsInput.html#structfield.dashboard_names) member.
+    pub fn dashboard_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.dashboard_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDashboardsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDashboardsInput, ::rusoto_core::BuildError> {
+        Ok(DeleteDashboardsInput {
+            dashboard_names: self
+                .dashboard_names
+                .ok_or(::rusoto_core::BuildError::missing("dashboard_names"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteDashboardsInput` contents to a `SignedRequest`.
 struct DeleteDashboardsInputSerializer;
 impl DeleteDashboardsInputSerializer {
@@ -778,10 +1018,10 @@ impl DeleteDashboardsInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteDashboardsOutput {}
 
-struct DeleteDashboardsOutputDeserializer;
+pub(crate) struct DeleteDashboardsOutputDeserializer;
 impl DeleteDashboardsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDashboardsOutput, XmlParseError> {
@@ -799,15 +1039,85 @@ pub struct DescribeAlarmHistoryInput {
     /// <p>The name of the alarm.</p>
     pub alarm_name: Option<String>,
     /// <p>The ending date to retrieve alarm history.</p>
-    pub end_date: Option<String>,
+    pub end_date: Option<IsoTimestamp>,
     /// <p>The type of alarm histories to retrieve.</p>
-    pub history_item_type: Option<String>,
+    pub history_item_type: Option<HistoryItemType>,
     /// <p>The maximum number of alarm history records to retrieve.</p>
     pub max_records: Option<i64>,
     /// <p>The token returned by a previous call to indicate that there is more data available.</p>
     pub next_token: Option<String>,
     /// <p>The starting date to retrieve alarm history.</p>
-    pub start_date: Option<String>,
+    pub start_date: Option<IsoTimestamp>,
+}
+
+impl DescribeAlarmHistoryInput {
+    /// Returns a builder for `DescribeAlarmHistoryInput`, checking its required members when built.
+    pub fn builder() -> DescribeAlarmHistoryInputBuilder {
+        DescribeAlarmHistoryInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAlarmHistoryInput`](struct.DescribeAlarmHistoryInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeAlarmHistoryInputBuilder {
+    alarm_name: Option<String>,
+    end_date: Option<IsoTimestamp>,
+    history_item_type: Option<HistoryItemType>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+    start_date: Option<IsoTimestamp>,
+}
+
+impl DescribeAlarmHistoryInputBuilder {
+    /// Sets the [`alarm_name`](struct.DescribeAlarmHistoryInput.html#structfield.alarm_name) member.
+    pub fn alarm_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.alarm_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_date`](struct.DescribeAlarmHistoryInput.html#structfield.end_date) member.
+    pub fn end_date<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.end_date = Some(value.into());
+        self
+    }
+
+    /// Sets the [`history_item_type`](struct.DescribeAlarmHistoryInput.html#structfield.history_item_type) member.
+    pub fn history_item_type<T: Into<HistoryItemType>>(mut self, value: T) -> Self {
+        self.history_item_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeAlarmHistoryInput.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeAlarmHistoryInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_date`](struct.DescribeAlarmHistoryInput.html#structfield.start_date) member.
+    pub fn start_date<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.start_date = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAlarmHistoryInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAlarmHistoryInput, ::rusoto_core::BuildError> {
+        Ok(DescribeAlarmHistoryInput {
+            alarm_name: self.alarm_name,
+            end_date: self.end_date,
+            history_item_type: self.history_item_type,
+            max_records: self.max_records,
+            next_token: self.next_token,
+            start_date: self.start_date,
+        })
+    }
 }
 
 /// Serialize `DescribeAlarmHistoryInput` contents to a `SignedRequest`.
@@ -848,10 +1158,10 @@ pub struct DescribeAlarmHistoryOutput {
     pub next_token: Option<String>,
 }
 
-struct DescribeAlarmHistoryOutputDeserializer;
+pub(crate) struct DescribeAlarmHistoryOutputDeserializer;
 impl DescribeAlarmHistoryOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAlarmHistoryOutput, XmlParseError> {
@@ -889,9 +1199,91 @@ pub struct DescribeAlarmsForMetricInput {
     /// <p>The period, in seconds, over which the statistic is applied.</p>
     pub period: Option<i64>,
     /// <p>The statistic for the metric, other than percentiles. For percentile statistics, use <code>ExtendedStatistics</code>.</p>
-    pub statistic: Option<String>,
+    pub statistic: Option<Statistic>,
     /// <p>The unit for the metric.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
+}
+
+impl DescribeAlarmsForMetricInput {
+    /// Returns a builder for `DescribeAlarmsForMetricInput`, checking its required members when built.
+    pub fn builder() -> DescribeAlarmsForMetricInputBuilder {
+        DescribeAlarmsForMetricInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAlarmsForMetricInput`](struct.DescribeAlarmsForMetricInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeAlarmsForMetricInputBuilder {
+    dimensions: Option<Vec<Dimension>>,
+    extended_statistic: Option<String>,
+    metric_name: Option<String>,
+    namespace: Option<String>,
+    period: Option<i64>,
+    statistic: Option<Statistic>,
+    unit: Option<StandardUnit>,
+}
+
+impl DescribeAlarmsForMetricInputBuilder {
+    /// Sets the [`dimensions`](struct.DescribeAlarmsForMetricInput.html#structfield.dimensions) member.
+    pub fn dimensions<T: Into<Vec<Dimension>>>(mut self, value: T) -> Self {
+        self.dimensions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`extended_statistic`](struct.DescribeAlarmsForMetricInput.html#structfield.extended_statistic) member.
+    pub fn extended_statistic<T: Into<String>>(mut self, value: T) -> Self {
+        self.extended_statistic = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.DescribeAlarmsForMetricInput.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`namespace`](struct.DescribeAlarmsForMetricInput.html#structfield.namespace) member.
+    pub fn namespace<T: Into<String>>(mut self, value: T) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`period`](struct.DescribeAlarmsForMetricInput.html#structfield.period) member.
+    pub fn period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statistic`](struct.DescribeAlarmsForMetricInput.html#structfield.statistic) member.
+    pub fn statistic<T: Into<Statistic>>(mut self, value: T) -> Self {
+        self.statistic = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unit`](struct.DescribeAlarmsForMetricInput.html#structfield.unit) member.
+    pub fn unit<T: Into<StandardUnit>>(mut self, value: T) -> Self {
+        self.unit = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAlarmsForMetricInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAlarmsForMetricInput, ::rusoto_core::BuildError> {
+        Ok(DescribeAlarmsForMetricInput {
+            dimensions: self.dimensions,
+            extended_statistic: self.extended_statistic,
+            metric_name: self
+                .metric_name
+                .ok_or(::rusoto_core::BuildError::missing("metric_name"))?,
+            namespace: self
+                .namespace
+                .ok_or(::rusoto_core::BuildError::missing("namespace"))?,
+            period: self.period,
+            statistic: self.statistic,
+            unit: self.unit,
+        })
+    }
 }
 
 /// Serialize `DescribeAlarmsForMetricInput` contents to a `SignedRequest`.
@@ -933,10 +1325,10 @@ pub struct DescribeAlarmsForMetricOutput {
     pub metric_alarms: Option<Vec<MetricAlarm>>,
 }
 
-struct DescribeAlarmsForMetricOutputDeserializer;
+pub(crate) struct DescribeAlarmsForMetricOutputDeserializer;
 impl DescribeAlarmsForMetricOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAlarmsForMetricOutput, XmlParseError> {
@@ -970,7 +1362,75 @@ pub struct DescribeAlarmsInput {
     /// <p>The token returned by a previous call to indicate that there is more data available.</p>
     pub next_token: Option<String>,
     /// <p>The state value to be used in matching alarms.</p>
-    pub state_value: Option<String>,
+    pub state_value: Option<StateValue>,
+}
+
+impl DescribeAlarmsInput {
+    /// Returns a builder for `DescribeAlarmsInput`, checking its required members when built.
+    pub fn builder() -> DescribeAlarmsInputBuilder {
+        DescribeAlarmsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAlarmsInput`](struct.DescribeAlarmsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeAlarmsInputBuilder {
+    action_prefix: Option<String>,
+    alarm_name_prefix: Option<String>,
+    alarm_names: Option<Vec<String>>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+    state_value: Option<StateValue>,
+}
+
+impl DescribeAlarmsInputBuilder {
+    /// Sets the [`action_prefix`](struct.DescribeAlarmsInput.html#structfield.action_prefix) member.
+    pub fn action_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.action_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`alarm_name_prefix`](struct.DescribeAlarmsInput.html#structfield.alarm_name_prefix) member.
+    pub fn alarm_name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.alarm_name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`alarm_names`](struct.DescribeAlarmsInput.html#structfield.alarm_names) member.
+    pub fn alarm_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.alarm_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeAlarmsInput.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeAlarmsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state_value`](struct.DescribeAlarmsInput.html#structfield.state_value) member.
+    pub fn state_value<T: Into<StateValue>>(mut self, value: T) -> Self {
+        self.state_value = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAlarmsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeAlarmsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeAlarmsInput {
+            action_prefix: self.action_prefix,
+            alarm_name_prefix: self.alarm_name_prefix,
+            alarm_names: self.alarm_names,
+            max_records: self.max_records,
+            next_token: self.next_token,
+            state_value: self.state_value,
+        })
+    }
 }
 
 /// Serialize `DescribeAlarmsInput` contents to a `SignedRequest`.
@@ -1015,10 +1475,10 @@ pub struct DescribeAlarmsOutput {
     pub next_token: Option<String>,
 }
 
-struct DescribeAlarmsOutputDeserializer;
+pub(crate) struct DescribeAlarmsOutputDeserializer;
 impl DescribeAlarmsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAlarmsOutput, XmlParseError> {
@@ -1052,6 +1512,68 @@ pub struct DescribeAnomalyDetectorsInput {
     pub next_token: Option<String>,
 }
 
+impl DescribeAnomalyDetectorsInput {
+    /// Returns a builder for `DescribeAnomalyDetectorsInput`, checking its required members when built.
+    pub fn builder() -> DescribeAnomalyDetectorsInputBuilder {
+        DescribeAnomalyDetectorsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAnomalyDetectorsInput`](struct.DescribeAnomalyDetectorsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeAnomalyDetectorsInputBuilder {
+    dimensions: Option<Vec<Dimension>>,
+    max_results: Option<i64>,
+    metric_name: Option<String>,
+    namespace: Option<String>,
+    next_token: Option<String>,
+}
+
+impl DescribeAnomalyDetectorsInputBuilder {
+    /// Sets the [`dimensions`](struct.DescribeAnomalyDetectorsInput.html#structfield.dimensions) member.
+    pub fn dimensions<T: Into<Vec<Dimension>>>(mut self, value: T) -> Self {
+        self.dimensions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeAnomalyDetectorsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.DescribeAnomalyDetectorsInput.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`namespace`](struct.DescribeAnomalyDetectorsInput.html#structfield.namespace) member.
+    pub fn namespace<T: Into<String>>(mut self, value: T) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeAnomalyDetectorsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAnomalyDetectorsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAnomalyDetectorsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeAnomalyDetectorsInput {
+            dimensions: self.dimensions,
+            max_results: self.max_results,
+            metric_name: self.metric_name,
+            namespace: self.namespace,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `DescribeAnomalyDetectorsInput` contents to a `SignedRequest`.
 struct DescribeAnomalyDetectorsInputSerializer;
 impl DescribeAnomalyDetectorsInputSerializer {
@@ -1091,10 +1613,10 @@ pub struct DescribeAnomalyDetectorsOutput {
     pub next_token: Option<String>,
 }
 
-struct DescribeAnomalyDetectorsOutputDeserializer;
+pub(crate) struct DescribeAnomalyDetectorsOutputDeserializer;
 impl DescribeAnomalyDetectorsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAnomalyDetectorsOutput, XmlParseError> {
@@ -1128,10 +1650,10 @@ pub struct Dimension {
     pub value: String,
 }
 
-struct DimensionDeserializer;
+pub(crate) struct DimensionDeserializer;
 impl DimensionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Dimension, XmlParseError> {
@@ -1200,10 +1722,13 @@ impl DimensionFiltersSerializer {
     }
 }
 
-struct DimensionNameDeserializer;
+pub(crate) struct DimensionNameDeserializer;
 impl DimensionNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1211,10 +1736,13 @@ impl DimensionNameDeserializer {
         Ok(obj)
     }
 }
-struct DimensionValueDeserializer;
+pub(crate) struct DimensionValueDeserializer;
 impl DimensionValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1222,10 +1750,10 @@ impl DimensionValueDeserializer {
         Ok(obj)
     }
 }
-struct DimensionsDeserializer;
+pub(crate) struct DimensionsDeserializer;
 impl DimensionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Dimension>, XmlParseError> {
@@ -1257,6 +1785,38 @@ pub struct DisableAlarmActionsInput {
     pub alarm_names: Vec<String>,
 }
 
+impl DisableAlarmActionsInput {
+    /// Returns a builder for `DisableAlarmActionsInput`, checking its required members when built.
+    pub fn builder() -> DisableAlarmActionsInputBuilder {
+        DisableAlarmActionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DisableAlarmActionsInput`](struct.DisableAlarmActionsInput.html).
+#[derive(Default, Debug)]
+pub struct DisableAlarmActionsInputBuilder {
+    alarm_names: Option<Vec<String>>,
+}
+
+impl DisableAlarmActionsInputBuilder {
+    /// Sets the [`alarm_names`](struct.DisableAlarmActionsInput.html#structfield.alarm_names) member.
+    pub fn alarm_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.alarm_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableAlarmActionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisableAlarmActionsInput, ::rusoto_core::BuildError> {
+        Ok(DisableAlarmActionsInput {
+            alarm_names: self
+                .alarm_names
+                .ok_or(::rusoto_core::BuildError::missing("alarm_names"))?,
+        })
+    }
+}
+
 /// Serialize `DisableAlarmActionsInput` contents to a `SignedRequest`.
 struct DisableAlarmActionsInputSerializer;
 impl DisableAlarmActionsInputSerializer {
@@ -1280,6 +1840,38 @@ pub struct EnableAlarmActionsInput {
     pub alarm_names: Vec<String>,
 }
 
+impl EnableAlarmActionsInput {
+    /// Returns a builder for `EnableAlarmActionsInput`, checking its required members when built.
+    pub fn builder() -> EnableAlarmActionsInputBuilder {
+        EnableAlarmActionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`EnableAlarmActionsInput`](struct.EnableAlarmActionsInput.html).
+#[derive(Default, Debug)]
+pub struct EnableAlarmActionsInputBuilder {
+    alarm_names: Option<Vec<String>>,
+}
+
+impl EnableAlarmActionsInputBuilder {
+    /// Sets the [`alarm_names`](struct.EnableAlarmActionsInput.html#structfield.alarm_names) member.
+    pub fn alarm_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.alarm_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableAlarmActionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EnableAlarmActionsInput, ::rusoto_core::BuildError> {
+        Ok(EnableAlarmActionsInput {
+            alarm_names: self
+                .alarm_names
+                .ok_or(::rusoto_core::BuildError::missing("alarm_names"))?,
+        })
+    }
+}
+
 /// Serialize `EnableAlarmActionsInput` contents to a `SignedRequest`.
 struct EnableAlarmActionsInputSerializer;
 impl EnableAlarmActionsInputSerializer {
@@ -1297,10 +1889,13 @@ impl EnableAlarmActionsInputSerializer {
     }
 }
 
-struct EvaluateLowSampleCountPercentileDeserializer;
+pub(crate) struct EvaluateLowSampleCountPercentileDeserializer;
 impl EvaluateLowSampleCountPercentileDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1308,10 +1903,13 @@ impl EvaluateLowSampleCountPercentileDeserializer {
         Ok(obj)
     }
 }
-struct EvaluationPeriodsDeserializer;
+pub(crate) struct EvaluationPeriodsDeserializer;
 impl EvaluationPeriodsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1319,10 +1917,13 @@ impl EvaluationPeriodsDeserializer {
         Ok(obj)
     }
 }
-struct ExtendedStatisticDeserializer;
+pub(crate) struct ExtendedStatisticDeserializer;
 impl ExtendedStatisticDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1348,6 +1949,36 @@ pub struct GetDashboardInput {
     pub dashboard_name: String,
 }
 
+impl GetDashboardInput {
+    /// Returns a builder for `GetDashboardInput`, checking its required members when built.
+    pub fn builder() -> GetDashboardInputBuilder {
+        GetDashboardInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetDashboardInput`](struct.GetDashboardInput.html).
+#[derive(Default, Debug)]
+pub struct GetDashboardInputBuilder {
+    dashboard_name: Option<String>,
+}
+
+impl GetDashboardInputBuilder {
+    /// Sets the [`dashboard_name`](struct.GetDashboardInput.html#structfield.dashboard_name) member.
+    pub fn dashboard_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dashboard_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDashboardInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDashboardInput, ::rusoto_core::BuildError> {
+        Ok(GetDashboardInput {
+            dashboard_name: self
+                .dashboard_name
+                .ok_or(::rusoto_core::BuildError::missing("dashboard_name"))?,
+        })
+    }
+}
+
 /// Serialize `GetDashboardInput` contents to a `SignedRequest`.
 struct GetDashboardInputSerializer;
 impl GetDashboardInputSerializer {
@@ -1374,10 +2005,10 @@ pub struct GetDashboardOutput {
     pub dashboard_name: Option<String>,
 }
 
-struct GetDashboardOutputDeserializer;
+pub(crate) struct GetDashboardOutputDeserializer;
 impl GetDashboardOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetDashboardOutput, XmlParseError> {
@@ -1410,7 +2041,7 @@ impl GetDashboardOutputDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetMetricDataInput {
     /// <p>The time stamp indicating the latest data to be returned.</p> <p>The value specified is exclusive; results include data points up to the specified time stamp.</p> <p>For better performance, specify <code>StartTime</code> and <code>EndTime</code> values that align with the value of the metric's <code>Period</code> and sync up with the beginning and end of an hour. For example, if the <code>Period</code> of a metric is 5 minutes, specifying 12:05 or 12:30 as <code>EndTime</code> can get a faster response from CloudWatch than setting 12:07 or 12:29 as the <code>EndTime</code>.</p>
-    pub end_time: String,
+    pub end_time: IsoTimestamp,
     /// <p>The maximum number of data points the request should return before paginating. If you omit this, the default of 100,800 is used.</p>
     pub max_datapoints: Option<i64>,
     /// <p>The metric queries to be returned. A single <code>GetMetricData</code> call can include as many as 100 <code>MetricDataQuery</code> structures. Each of these structures can specify either a metric to retrieve, or a math expression to perform on retrieved data. </p>
@@ -1418,9 +2049,83 @@ pub struct GetMetricDataInput {
     /// <p>Include this value, if it was returned by the previous call, to get the next set of data points.</p>
     pub next_token: Option<String>,
     /// <p>The order in which data points should be returned. <code>TimestampDescending</code> returns the newest data first and paginates when the <code>MaxDatapoints</code> limit is reached. <code>TimestampAscending</code> returns the oldest data first and paginates when the <code>MaxDatapoints</code> limit is reached.</p>
-    pub scan_by: Option<String>,
+    pub scan_by: Option<ScanBy>,
     /// <p>The time stamp indicating the earliest data to be returned.</p> <p>The value specified is inclusive; results include data points with the specified time stamp. </p> <p>CloudWatch rounds the specified time stamp as follows:</p> <ul> <li> <p>Start time less than 15 days ago - Round down to the nearest whole minute. For example, 12:32:34 is rounded down to 12:32:00.</p> </li> <li> <p>Start time between 15 and 63 days ago - Round down to the nearest 5-minute clock interval. For example, 12:32:34 is rounded down to 12:30:00.</p> </li> <li> <p>Start time greater than 63 days ago - Round down to the nearest 1-hour clock interval. For example, 12:32:34 is rounded down to 12:00:00.</p> </li> </ul> <p>If you set <code>Period</code> to 5, 10, or 30, the start time of your request is rounded down to the nearest time that corresponds to even 5-, 10-, or 30-second divisions of a minute. For example, if you make a query at (HH:mm:ss) 01:05:23 for the previous 10-second period, the start time of your request is rounded down and you receive data from 01:05:10 to 01:05:20. If you make a query at 15:07:17 for the previous 5 minutes of data, using a period of 5 seconds, you receive data timestamped between 15:02:15 and 15:07:15. </p> <p>For better performance, specify <code>StartTime</code> and <code>EndTime</code> values that align with the value of the metric's <code>Period</code> and sync up with the beginning and end of an hour. For example, if the <code>Period</code> of a metric is 5 minutes, specifying 12:05 or 12:30 as <code>StartTime</code> can get a faster response from CloudWatch than setting 12:07 or 12:29 as the <code>StartTime</code>.</p>
-    pub start_time: String,
+    pub start_time: IsoTimestamp,
+}
+
+impl GetMetricDataInput {
+    /// Returns a builder for `GetMetricDataInput`, checking its required members when built.
+    pub fn builder() -> GetMetricDataInputBuilder {
+        GetMetricDataInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetMetricDataInput`](struct.GetMetricDataInput.html).
+#[derive(Default, Debug)]
+pub struct GetMetricDataInputBuilder {
+    end_time: Option<IsoTimestamp>,
+    max_datapoints: Option<i64>,
+    metric_data_queries: Option<Vec<MetricDataQuery>>,
+    next_token: Option<String>,
+    scan_by: Option<ScanBy>,
+    start_time: Option<IsoTimestamp>,
+}
+
+impl GetMetricDataInputBuilder {
+    /// Sets the [`end_time`](struct.GetMetricDataInput.html#structfield.end_time) member.
+    pub fn end_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_datapoints`](struct.GetMetricDataInput.html#structfield.max_datapoints) member.
+    pub fn max_datapoints<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_datapoints = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_data_queries`](struct.GetMetricDataInput.html#structfield.metric_data_queries) member.
+    pub fn metric_data_queries<T: Into<Vec<MetricDataQuery>>>(mut self, value: T) -> Self {
+        self.metric_data_queries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetMetricDataInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scan_by`](struct.GetMetricDataInput.html#structfield.scan_by) member.
+    pub fn scan_by<T: Into<ScanBy>>(mut self, value: T) -> Self {
+        self.scan_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetMetricDataInput.html#structfield.start_time) member.
+    pub fn start_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMetricDataInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMetricDataInput, ::rusoto_core::BuildError> {
+        Ok(GetMetricDataInput {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            max_datapoints: self.max_datapoints,
+            metric_data_queries: self
+                .metric_data_queries
+                .ok_or(::rusoto_core::BuildError::missing("metric_data_queries"))?,
+            next_token: self.next_token,
+            scan_by: self.scan_by,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+        })
+    }
 }
 
 /// Serialize `GetMetricDataInput` contents to a `SignedRequest`.
@@ -1461,10 +2166,10 @@ pub struct GetMetricDataOutput {
     pub next_token: Option<String>,
 }
 
-struct GetMetricDataOutputDeserializer;
+pub(crate) struct GetMetricDataOutputDeserializer;
 impl GetMetricDataOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetMetricDataOutput, XmlParseError> {
@@ -1494,7 +2199,7 @@ pub struct GetMetricStatisticsInput {
     /// <p>The dimensions. If the metric contains multiple dimensions, you must include a value for each dimension. CloudWatch treats each unique combination of dimensions as a separate metric. If a specific combination of dimensions was not published, you can't retrieve statistics for it. You must specify the same dimensions that were used when the metrics were created. For an example, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/cloudwatch_concepts.html#dimension-combinations">Dimension Combinations</a> in the <i>Amazon CloudWatch User Guide</i>. For more information about specifying dimensions, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html">Publishing Metrics</a> in the <i>Amazon CloudWatch User Guide</i>.</p>
     pub dimensions: Option<Vec<Dimension>>,
     /// <p>The time stamp that determines the last data point to return.</p> <p>The value specified is exclusive; results include data points up to the specified time stamp. The time stamp must be in ISO 8601 UTC format (for example, 2016-10-10T23:00:00Z).</p>
-    pub end_time: String,
+    pub end_time: IsoTimestamp,
     /// <p>The percentile statistics. Specify values between p0.0 and p100. When calling <code>GetMetricStatistics</code>, you must specify either <code>Statistics</code> or <code>ExtendedStatistics</code>, but not both. Percentile statistics are not available for metrics when any of the metric values are negative numbers.</p>
     pub extended_statistics: Option<Vec<String>>,
     /// <p>The name of the metric, with or without spaces.</p>
@@ -1504,30 +2209,134 @@ pub struct GetMetricStatisticsInput {
     /// <p><p>The granularity, in seconds, of the returned data points. For metrics with regular resolution, a period can be as short as one minute (60 seconds) and must be a multiple of 60. For high-resolution metrics that are collected at intervals of less than one minute, the period can be 1, 5, 10, 30, 60, or any multiple of 60. High-resolution metrics are those metrics stored by a <code>PutMetricData</code> call that includes a <code>StorageResolution</code> of 1 second.</p> <p>If the <code>StartTime</code> parameter specifies a time stamp that is greater than 3 hours ago, you must specify the period as follows or no data points in that time range is returned:</p> <ul> <li> <p>Start time between 3 hours and 15 days ago - Use a multiple of 60 seconds (1 minute).</p> </li> <li> <p>Start time between 15 and 63 days ago - Use a multiple of 300 seconds (5 minutes).</p> </li> <li> <p>Start time greater than 63 days ago - Use a multiple of 3600 seconds (1 hour).</p> </li> </ul></p>
     pub period: i64,
     /// <p>The time stamp that determines the first data point to return. Start times are evaluated relative to the time that CloudWatch receives the request.</p> <p>The value specified is inclusive; results include data points with the specified time stamp. The time stamp must be in ISO 8601 UTC format (for example, 2016-10-03T23:00:00Z).</p> <p>CloudWatch rounds the specified time stamp as follows:</p> <ul> <li> <p>Start time less than 15 days ago - Round down to the nearest whole minute. For example, 12:32:34 is rounded down to 12:32:00.</p> </li> <li> <p>Start time between 15 and 63 days ago - Round down to the nearest 5-minute clock interval. For example, 12:32:34 is rounded down to 12:30:00.</p> </li> <li> <p>Start time greater than 63 days ago - Round down to the nearest 1-hour clock interval. For example, 12:32:34 is rounded down to 12:00:00.</p> </li> </ul> <p>If you set <code>Period</code> to 5, 10, or 30, the start time of your request is rounded down to the nearest time that corresponds to even 5-, 10-, or 30-second divisions of a minute. For example, if you make a query at (HH:mm:ss) 01:05:23 for the previous 10-second period, the start time of your request is rounded down and you receive data from 01:05:10 to 01:05:20. If you make a query at 15:07:17 for the previous 5 minutes of data, using a period of 5 seconds, you receive data timestamped between 15:02:15 and 15:07:15. </p>
-    pub start_time: String,
+    pub start_time: IsoTimestamp,
     /// <p>The metric statistics, other than percentile. For percentile statistics, use <code>ExtendedStatistics</code>. When calling <code>GetMetricStatistics</code>, you must specify either <code>Statistics</code> or <code>ExtendedStatistics</code>, but not both.</p>
-    pub statistics: Option<Vec<String>>,
+    pub statistics: Option<Vec<Statistic>>,
     /// <p>The unit for a given metric. If you omit <code>Unit</code>, all data that was collected with any unit is returned, along with the corresponding units that were specified when the data was reported to CloudWatch. If you specify a unit, the operation returns only data data that was collected with that unit specified. If you specify a unit that does not match the data collected, the results of the operation are null. CloudWatch does not perform unit conversions.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
 }
 
-/// Serialize `GetMetricStatisticsInput` contents to a `SignedRequest`.
-struct GetMetricStatisticsInputSerializer;
-impl GetMetricStatisticsInputSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &GetMetricStatisticsInput) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl GetMetricStatisticsInput {
+    /// Returns a builder for `GetMetricStatisticsInput`, checking its required members when built.
+    pub fn builder() -> GetMetricStatisticsInputBuilder {
+        GetMetricStatisticsInputBuilder::default()
+    }
+}
 
-        if let Some(ref field_value) = obj.dimensions {
-            DimensionsSerializer::serialize(
-                params,
-                &format!("{}{}", prefix, "Dimensions"),
-                field_value,
-            );
-        }
-        params.put(&format!("{}{}", prefix, "EndTime"), &obj.end_time);
+/// Builds a [`GetMetricStatisticsInput`](struct.GetMetricStatisticsInput.html).
+#[derive(Default, Debug)]
+pub struct GetMetricStatisticsInputBuilder {
+    dimensions: Option<Vec<Dimension>>,
+    end_time: Option<IsoTimestamp>,
+    extended_statistics: Option<Vec<String>>,
+    metric_name: Option<String>,
+    namespace: Option<String>,
+    period: Option<i64>,
+    start_time: Option<IsoTimestamp>,
+    statistics: Option<Vec<Statistic>>,
+    unit: Option<StandardUnit>,
+}
+
+impl GetMetricStatisticsInputBuilder {
+    /// Sets the [`dimensions`](struct.GetMetricStatisticsInput.html#structfield.dimensions) member.
+    pub fn dimensions<T: Into<Vec<Dimension>>>(mut self, value: T) -> Self {
+        self.dimensions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.GetMetricStatisticsInput.html#structfield.end_time) member.
+    pub fn end_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`extended_statistics`](struct.GetMetricStatisticsInput.html#structfield.extended_statistics) member.
+    pub fn extended_statistics<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.extended_statistics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.GetMetricStatisticsInput.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`namespace`](struct.GetMetricStatisticsInput.html#structfield.namespace) member.
+    pub fn namespace<T: Into<String>>(mut self, value: T) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`period`](struct.GetMetricStatisticsInput.html#structfield.period) member.
+    pub fn period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetMetricStatisticsInput.html#structfield.start_time) member.
+    pub fn start_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statistics`](struct.GetMetricStatisticsInput.html#structfield.statistics) member.
+    pub fn statistics<T: Into<Vec<Statistic>>>(mut self, value: T) -> Self {
+        self.statistics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unit`](struct.GetMetricStatisticsInput.html#structfield.unit) member.
+    pub fn unit<T: Into<StandardUnit>>(mut self, value: T) -> Self {
+        self.unit = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMetricStatisticsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetMetricStatisticsInput, ::rusoto_core::BuildError> {
+        Ok(GetMetricStatisticsInput {
+            dimensions: self.dimensions,
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            extended_statistics: self.extended_statistics,
+            metric_name: self
+                .metric_name
+                .ok_or(::rusoto_core::BuildError::missing("metric_name"))?,
+            namespace: self
+                .namespace
+                .ok_or(::rusoto_core::BuildError::missing("namespace"))?,
+            period: self
+                .period
+                .ok_or(::rusoto_core::BuildError::missing("period"))?,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+            statistics: self.statistics,
+            unit: self.unit,
+        })
+    }
+}
+
+/// Serialize `GetMetricStatisticsInput` contents to a `SignedRequest`.
+struct GetMetricStatisticsInputSerializer;
+impl GetMetricStatisticsInputSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &GetMetricStatisticsInput) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.dimensions {
+            DimensionsSerializer::serialize(
+                params,
+                &format!("{}{}", prefix, "Dimensions"),
+                field_value,
+            );
+        }
+        params.put(&format!("{}{}", prefix, "EndTime"), &obj.end_time);
         if let Some(ref field_value) = obj.extended_statistics {
             ExtendedStatisticsSerializer::serialize(
                 params,
@@ -1560,10 +2369,10 @@ pub struct GetMetricStatisticsOutput {
     pub label: Option<String>,
 }
 
-struct GetMetricStatisticsOutputDeserializer;
+pub(crate) struct GetMetricStatisticsOutputDeserializer;
 impl GetMetricStatisticsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetMetricStatisticsOutput, XmlParseError> {
@@ -1595,6 +2404,46 @@ pub struct GetMetricWidgetImageInput {
     pub output_format: Option<String>,
 }
 
+impl GetMetricWidgetImageInput {
+    /// Returns a builder for `GetMetricWidgetImageInput`, checking its required members when built.
+    pub fn builder() -> GetMetricWidgetImageInputBuilder {
+        GetMetricWidgetImageInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetMetricWidgetImageInput`](struct.GetMetricWidgetImageInput.html).
+#[derive(Default, Debug)]
+pub struct GetMetricWidgetImageInputBuilder {
+    metric_widget: Option<String>,
+    output_format: Option<String>,
+}
+
+impl GetMetricWidgetImageInputBuilder {
+    /// Sets the [`metric_widget`](struct.GetMetricWidgetImageInput.html#structfield.metric_widget) member.
+    pub fn metric_widget<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_widget = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_format`](struct.GetMetricWidgetImageInput.html#structfield.output_format) member.
+    pub fn output_format<T: Into<String>>(mut self, value: T) -> Self {
+        self.output_format = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMetricWidgetImageInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetMetricWidgetImageInput, ::rusoto_core::BuildError> {
+        Ok(GetMetricWidgetImageInput {
+            metric_widget: self
+                .metric_widget
+                .ok_or(::rusoto_core::BuildError::missing("metric_widget"))?,
+            output_format: self.output_format,
+        })
+    }
+}
+
 /// Serialize `GetMetricWidgetImageInput` contents to a `SignedRequest`.
 struct GetMetricWidgetImageInputSerializer;
 impl GetMetricWidgetImageInputSerializer {
@@ -1617,10 +2466,10 @@ pub struct GetMetricWidgetImageOutput {
     pub metric_widget_image: Option<bytes::Bytes>,
 }
 
-struct GetMetricWidgetImageOutputDeserializer;
+pub(crate) struct GetMetricWidgetImageOutputDeserializer;
 impl GetMetricWidgetImageOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetMetricWidgetImageOutput, XmlParseError> {
@@ -1642,10 +2491,13 @@ impl GetMetricWidgetImageOutputDeserializer {
         )
     }
 }
-struct HistoryDataDeserializer;
+pub(crate) struct HistoryDataDeserializer;
 impl HistoryDataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1653,21 +2505,90 @@ impl HistoryDataDeserializer {
         Ok(obj)
     }
 }
-struct HistoryItemTypeDeserializer;
+pub(crate) struct HistoryItemTypeDeserializer;
 impl HistoryItemTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<HistoryItemType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct HistorySummaryDeserializer;
+/// <p>The type of alarm history item.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HistoryItemType {
+    /// <p><code>ConfigurationUpdate</code></p>
+    ConfigurationUpdate,
+    /// <p><code>StateUpdate</code></p>
+    StateUpdate,
+    /// <p><code>Action</code></p>
+    Action,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HistoryItemType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HistoryItemType::ConfigurationUpdate => "ConfigurationUpdate",
+            HistoryItemType::StateUpdate => "StateUpdate",
+            HistoryItemType::Action => "Action",
+            HistoryItemType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HistoryItemType {
+    fn from(value: &'a str) -> HistoryItemType {
+        match value {
+            "ConfigurationUpdate" => HistoryItemType::ConfigurationUpdate,
+            "StateUpdate" => HistoryItemType::StateUpdate,
+            "Action" => HistoryItemType::Action,
+            _ => HistoryItemType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HistoryItemType {
+    fn from(value: String) -> HistoryItemType {
+        HistoryItemType::from(value.as_str())
+    }
+}
+
+impl From<HistoryItemType> for String {
+    fn from(value: HistoryItemType) -> String {
+        match value {
+            HistoryItemType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HistoryItemType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HistoryItemType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct HistorySummaryDeserializer;
 impl HistorySummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1675,12 +2596,15 @@ impl HistorySummaryDeserializer {
         Ok(obj)
     }
 }
-struct LastModifiedDeserializer;
+pub(crate) struct LastModifiedDeserializer;
 impl LastModifiedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1694,6 +2618,42 @@ pub struct ListDashboardsInput {
     pub next_token: Option<String>,
 }
 
+impl ListDashboardsInput {
+    /// Returns a builder for `ListDashboardsInput`, checking its required members when built.
+    pub fn builder() -> ListDashboardsInputBuilder {
+        ListDashboardsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListDashboardsInput`](struct.ListDashboardsInput.html).
+#[derive(Default, Debug)]
+pub struct ListDashboardsInputBuilder {
+    dashboard_name_prefix: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListDashboardsInputBuilder {
+    /// Sets the [`dashboard_name_prefix`](struct.ListDashboardsInput.html#structfield.dashboard_name_prefix) member.
+    pub fn dashboard_name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.dashboard_name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDashboardsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDashboardsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDashboardsInput, ::rusoto_core::BuildError> {
+        Ok(ListDashboardsInput {
+            dashboard_name_prefix: self.dashboard_name_prefix,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListDashboardsInput` contents to a `SignedRequest`.
 struct ListDashboardsInputSerializer;
 impl ListDashboardsInputSerializer {
@@ -1723,10 +2683,10 @@ pub struct ListDashboardsOutput {
     pub next_token: Option<String>,
 }
 
-struct ListDashboardsOutputDeserializer;
+pub(crate) struct ListDashboardsOutputDeserializer;
 impl ListDashboardsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListDashboardsOutput, XmlParseError> {
@@ -1758,6 +2718,58 @@ pub struct ListMetricsInput {
     pub next_token: Option<String>,
 }
 
+impl ListMetricsInput {
+    /// Returns a builder for `ListMetricsInput`, checking its required members when built.
+    pub fn builder() -> ListMetricsInputBuilder {
+        ListMetricsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListMetricsInput`](struct.ListMetricsInput.html).
+#[derive(Default, Debug)]
+pub struct ListMetricsInputBuilder {
+    dimensions: Option<Vec<DimensionFilter>>,
+    metric_name: Option<String>,
+    namespace: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListMetricsInputBuilder {
+    /// Sets the [`dimensions`](struct.ListMetricsInput.html#structfield.dimensions) member.
+    pub fn dimensions<T: Into<Vec<DimensionFilter>>>(mut self, value: T) -> Self {
+        self.dimensions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.ListMetricsInput.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`namespace`](struct.ListMetricsInput.html#structfield.namespace) member.
+    pub fn namespace<T: Into<String>>(mut self, value: T) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListMetricsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListMetricsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListMetricsInput, ::rusoto_core::BuildError> {
+        Ok(ListMetricsInput {
+            dimensions: self.dimensions,
+            metric_name: self.metric_name,
+            namespace: self.namespace,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListMetricsInput` contents to a `SignedRequest`.
 struct ListMetricsInputSerializer;
 impl ListMetricsInputSerializer {
@@ -1794,10 +2806,10 @@ pub struct ListMetricsOutput {
     pub next_token: Option<String>,
 }
 
-struct ListMetricsOutputDeserializer;
+pub(crate) struct ListMetricsOutputDeserializer;
 impl ListMetricsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListMetricsOutput, XmlParseError> {
@@ -1823,6 +2835,38 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceInput {
+    /// Returns a builder for `ListTagsForResourceInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceInputBuilder {
+        ListTagsForResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceInput`](struct.ListTagsForResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceInputBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 /// Serialize `ListTagsForResourceInput` contents to a `SignedRequest`.
 struct ListTagsForResourceInputSerializer;
 impl ListTagsForResourceInputSerializer {
@@ -1842,10 +2886,10 @@ pub struct ListTagsForResourceOutput {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct ListTagsForResourceOutputDeserializer;
+pub(crate) struct ListTagsForResourceOutputDeserializer;
 impl ListTagsForResourceOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListTagsForResourceOutput, XmlParseError> {
@@ -1866,10 +2910,13 @@ impl ListTagsForResourceOutputDeserializer {
         )
     }
 }
-struct MessageDeserializer;
+pub(crate) struct MessageDeserializer;
 impl MessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1886,10 +2933,10 @@ pub struct MessageData {
     pub value: Option<String>,
 }
 
-struct MessageDataDeserializer;
+pub(crate) struct MessageDataDeserializer;
 impl MessageDataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MessageData, XmlParseError> {
@@ -1907,10 +2954,13 @@ impl MessageDataDeserializer {
         })
     }
 }
-struct MessageDataCodeDeserializer;
+pub(crate) struct MessageDataCodeDeserializer;
 impl MessageDataCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1918,10 +2968,13 @@ impl MessageDataCodeDeserializer {
         Ok(obj)
     }
 }
-struct MessageDataValueDeserializer;
+pub(crate) struct MessageDataValueDeserializer;
 impl MessageDataValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1940,10 +2993,13 @@ pub struct Metric {
     pub namespace: Option<String>,
 }
 
-struct MetricDeserializer;
+pub(crate) struct MetricDeserializer;
 impl MetricDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Metric, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Metric, XmlParseError> {
         deserialize_elements::<_, Metric, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Dimensions" => {
@@ -2000,13 +3056,13 @@ pub struct MetricAlarm {
     /// <p>The Amazon Resource Name (ARN) of the alarm.</p>
     pub alarm_arn: Option<String>,
     /// <p>The time stamp of the last update to the alarm configuration.</p>
-    pub alarm_configuration_updated_timestamp: Option<String>,
+    pub alarm_configuration_updated_timestamp: Option<IsoTimestamp>,
     /// <p>The description of the alarm.</p>
     pub alarm_description: Option<String>,
     /// <p>The name of the alarm.</p>
     pub alarm_name: Option<String>,
     /// <p>The arithmetic operation to use when comparing the specified statistic and threshold. The specified statistic value is used as the first operand.</p>
-    pub comparison_operator: Option<String>,
+    pub comparison_operator: Option<ComparisonOperator>,
     /// <p>The number of datapoints that must be breaching to trigger the alarm.</p>
     pub datapoints_to_alarm: Option<i64>,
     /// <p>The dimensions for the metric associated with the alarm.</p>
@@ -2034,11 +3090,11 @@ pub struct MetricAlarm {
     /// <p>An explanation for the alarm state, in JSON format.</p>
     pub state_reason_data: Option<String>,
     /// <p>The time stamp of the last update to the alarm state.</p>
-    pub state_updated_timestamp: Option<String>,
+    pub state_updated_timestamp: Option<IsoTimestamp>,
     /// <p>The state value for the alarm.</p>
-    pub state_value: Option<String>,
+    pub state_value: Option<StateValue>,
     /// <p>The statistic for the metric associated with the alarm, other than percentile. For percentile statistics, use <code>ExtendedStatistic</code>.</p>
-    pub statistic: Option<String>,
+    pub statistic: Option<Statistic>,
     /// <p>The value to compare with the specified statistic.</p>
     pub threshold: Option<f64>,
     /// <p>In an alarm based on an anomaly detection model, this is the ID of the <code>ANOMALY_DETECTION_BAND</code> function used as the threshold for the alarm.</p>
@@ -2046,13 +3102,13 @@ pub struct MetricAlarm {
     /// <p>Sets how this alarm is to handle missing data points. If this parameter is omitted, the default behavior of <code>missing</code> is used.</p>
     pub treat_missing_data: Option<String>,
     /// <p>The unit of the metric associated with the alarm.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
 }
 
-struct MetricAlarmDeserializer;
+pub(crate) struct MetricAlarmDeserializer;
 impl MetricAlarmDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MetricAlarm, XmlParseError> {
@@ -2196,10 +3252,10 @@ impl MetricAlarmDeserializer {
         })
     }
 }
-struct MetricAlarmsDeserializer;
+pub(crate) struct MetricAlarmsDeserializer;
 impl MetricAlarmsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<MetricAlarm>, XmlParseError> {
@@ -2225,10 +3281,10 @@ impl MetricDataSerializer {
     }
 }
 
-struct MetricDataQueriesDeserializer;
+pub(crate) struct MetricDataQueriesDeserializer;
 impl MetricDataQueriesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<MetricDataQuery>, XmlParseError> {
@@ -2269,10 +3325,10 @@ pub struct MetricDataQuery {
     pub return_data: Option<bool>,
 }
 
-struct MetricDataQueryDeserializer;
+pub(crate) struct MetricDataQueryDeserializer;
 impl MetricDataQueryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MetricDataQuery, XmlParseError> {
@@ -2344,17 +3400,17 @@ pub struct MetricDataResult {
     /// <p>A list of messages with additional information about the data returned.</p>
     pub messages: Option<Vec<MessageData>>,
     /// <p>The status of the returned data. <code>Complete</code> indicates that all data points in the requested time range were returned. <code>PartialData</code> means that an incomplete set of data points were returned. You can use the <code>NextToken</code> value that was returned and repeat your request to get more data points. <code>NextToken</code> is not returned if you are performing a math expression. <code>InternalError</code> indicates that an error occurred. Retry your request using <code>NextToken</code>, if present.</p>
-    pub status_code: Option<String>,
+    pub status_code: Option<StatusCode>,
     /// <p>The timestamps for the data points, formatted in Unix timestamp format. The number of timestamps always matches the number of values and the value for Timestamps[x] is Values[x].</p>
-    pub timestamps: Option<Vec<String>>,
+    pub timestamps: Option<Vec<IsoTimestamp>>,
     /// <p>The data points for the metric corresponding to <code>Timestamps</code>. The number of values always matches the number of timestamps and the timestamp for Values[x] is Timestamps[x].</p>
     pub values: Option<Vec<f64>>,
 }
 
-struct MetricDataResultDeserializer;
+pub(crate) struct MetricDataResultDeserializer;
 impl MetricDataResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MetricDataResult, XmlParseError> {
@@ -2391,10 +3447,10 @@ impl MetricDataResultDeserializer {
         })
     }
 }
-struct MetricDataResultMessagesDeserializer;
+pub(crate) struct MetricDataResultMessagesDeserializer;
 impl MetricDataResultMessagesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<MessageData>, XmlParseError> {
@@ -2408,10 +3464,10 @@ impl MetricDataResultMessagesDeserializer {
         })
     }
 }
-struct MetricDataResultsDeserializer;
+pub(crate) struct MetricDataResultsDeserializer;
 impl MetricDataResultsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<MetricDataResult>, XmlParseError> {
@@ -2439,9 +3495,9 @@ pub struct MetricDatum {
     /// <p>Valid values are 1 and 60. Setting this to 1 specifies this metric as a high-resolution metric, so that CloudWatch stores the metric with sub-minute resolution down to one second. Setting this to 60 specifies this metric as a regular-resolution metric, which CloudWatch stores at 1-minute resolution. Currently, high resolution is available only for custom metrics. For more information about high-resolution metrics, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/publishingMetrics.html#high-resolution-metrics">High-Resolution Metrics</a> in the <i>Amazon CloudWatch User Guide</i>. </p> <p>This field is optional, if you do not specify it the default of 60 is used.</p>
     pub storage_resolution: Option<i64>,
     /// <p>The time the metric data was received, expressed as the number of milliseconds since Jan 1, 1970 00:00:00 UTC.</p>
-    pub timestamp: Option<String>,
+    pub timestamp: Option<IsoTimestamp>,
     /// <p>When you are using a <code>Put</code> operation, this defines what unit you want to use when storing the metric.</p> <p>In a <code>Get</code> operation, this displays the unit that is used for the metric.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
     /// <p>The value for the metric.</p> <p>Although the parameter accepts numbers of type Double, CloudWatch rejects values that are either too small or too large. Values must be in the range of 8.515920e-109 to 1.174271e+108 (Base 10) or 2e-360 to 2e360 (Base 2). In addition, special values (for example, NaN, +Infinity, -Infinity) are not supported.</p>
     pub value: Option<f64>,
     /// <p>Array of numbers representing the values for the metric during the period. Each unique value is listed just once in this array, and the corresponding number in the <code>Counts</code> array specifies the number of times that value occurred during the period. You can include up to 150 unique values in each <code>PutMetricData</code> action that specifies a <code>Values</code> array.</p> <p>Although the <code>Values</code> array accepts numbers of type <code>Double</code>, CloudWatch rejects values that are either too small or too large. Values must be in the range of 8.515920e-109 to 1.174271e+108 (Base 10) or 2e-360 to 2e360 (Base 2). In addition, special values (for example, NaN, +Infinity, -Infinity) are not supported.</p>
@@ -2493,10 +3549,13 @@ impl MetricDatumSerializer {
     }
 }
 
-struct MetricExpressionDeserializer;
+pub(crate) struct MetricExpressionDeserializer;
 impl MetricExpressionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2504,10 +3563,13 @@ impl MetricExpressionDeserializer {
         Ok(obj)
     }
 }
-struct MetricIdDeserializer;
+pub(crate) struct MetricIdDeserializer;
 impl MetricIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2515,10 +3577,13 @@ impl MetricIdDeserializer {
         Ok(obj)
     }
 }
-struct MetricLabelDeserializer;
+pub(crate) struct MetricLabelDeserializer;
 impl MetricLabelDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2526,10 +3591,13 @@ impl MetricLabelDeserializer {
         Ok(obj)
     }
 }
-struct MetricNameDeserializer;
+pub(crate) struct MetricNameDeserializer;
 impl MetricNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2547,13 +3615,13 @@ pub struct MetricStat {
     /// <p>The statistic to return. It can include any CloudWatch statistic or extended statistic.</p>
     pub stat: String,
     /// <p>When you are using a <code>Put</code> operation, this defines what unit you want to use when storing the metric.</p> <p>In a <code>Get</code> operation, if you omit <code>Unit</code> then all data that was collected with any unit is returned, along with the corresponding units that were specified when the data was reported to CloudWatch. If you specify a unit, the operation returns only data data that was collected with that unit specified. If you specify a unit that does not match the data collected, the results of the operation are null. CloudWatch does not perform unit conversions.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
 }
 
-struct MetricStatDeserializer;
+pub(crate) struct MetricStatDeserializer;
 impl MetricStatDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MetricStat, XmlParseError> {
@@ -2596,10 +3664,10 @@ impl MetricStatSerializer {
     }
 }
 
-struct MetricWidgetImageDeserializer;
+pub(crate) struct MetricWidgetImageDeserializer;
 impl MetricWidgetImageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<bytes::Bytes, XmlParseError> {
@@ -2610,10 +3678,10 @@ impl MetricWidgetImageDeserializer {
         Ok(obj)
     }
 }
-struct MetricsDeserializer;
+pub(crate) struct MetricsDeserializer;
 impl MetricsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Metric>, XmlParseError> {
@@ -2627,10 +3695,13 @@ impl MetricsDeserializer {
         })
     }
 }
-struct NamespaceDeserializer;
+pub(crate) struct NamespaceDeserializer;
 impl NamespaceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2638,10 +3709,13 @@ impl NamespaceDeserializer {
         Ok(obj)
     }
 }
-struct NextTokenDeserializer;
+pub(crate) struct NextTokenDeserializer;
 impl NextTokenDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2649,10 +3723,13 @@ impl NextTokenDeserializer {
         Ok(obj)
     }
 }
-struct PeriodDeserializer;
+pub(crate) struct PeriodDeserializer;
 impl PeriodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2674,6 +3751,74 @@ pub struct PutAnomalyDetectorInput {
     pub stat: String,
 }
 
+impl PutAnomalyDetectorInput {
+    /// Returns a builder for `PutAnomalyDetectorInput`, checking its required members when built.
+    pub fn builder() -> PutAnomalyDetectorInputBuilder {
+        PutAnomalyDetectorInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutAnomalyDetectorInput`](struct.PutAnomalyDetectorInput.html).
+#[derive(Default, Debug)]
+pub struct PutAnomalyDetectorInputBuilder {
+    configuration: Option<AnomalyDetectorConfiguration>,
+    dimensions: Option<Vec<Dimension>>,
+    metric_name: Option<String>,
+    namespace: Option<String>,
+    stat: Option<String>,
+}
+
+impl PutAnomalyDetectorInputBuilder {
+    /// Sets the [`configuration`](struct.PutAnomalyDetectorInput.html#structfield.configuration) member.
+    pub fn configuration<T: Into<AnomalyDetectorConfiguration>>(mut self, value: T) -> Self {
+        self.configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dimensions`](struct.PutAnomalyDetectorInput.html#structfield.dimensions) member.
+    pub fn dimensions<T: Into<Vec<Dimension>>>(mut self, value: T) -> Self {
+        self.dimensions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.PutAnomalyDetectorInput.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`namespace`](struct.PutAnomalyDetectorInput.html#structfield.namespace) member.
+    pub fn namespace<T: Into<String>>(mut self, value: T) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stat`](struct.PutAnomalyDetectorInput.html#structfield.stat) member.
+    pub fn stat<T: Into<String>>(mut self, value: T) -> Self {
+        self.stat = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutAnomalyDetectorInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutAnomalyDetectorInput, ::rusoto_core::BuildError> {
+        Ok(PutAnomalyDetectorInput {
+            configuration: self.configuration,
+            dimensions: self.dimensions,
+            metric_name: self
+                .metric_name
+                .ok_or(::rusoto_core::BuildError::missing("metric_name"))?,
+            namespace: self
+                .namespace
+                .ok_or(::rusoto_core::BuildError::missing("namespace"))?,
+            stat: self
+                .stat
+                .ok_or(::rusoto_core::BuildError::missing("stat"))?,
+        })
+    }
+}
+
 /// Serialize `PutAnomalyDetectorInput` contents to a `SignedRequest`.
 struct PutAnomalyDetectorInputSerializer;
 impl PutAnomalyDetectorInputSerializer {
@@ -2706,10 +3851,10 @@ impl PutAnomalyDetectorInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PutAnomalyDetectorOutput {}
 
-struct PutAnomalyDetectorOutputDeserializer;
+pub(crate) struct PutAnomalyDetectorOutputDeserializer;
 impl PutAnomalyDetectorOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PutAnomalyDetectorOutput, XmlParseError> {
@@ -2730,6 +3875,46 @@ pub struct PutDashboardInput {
     pub dashboard_name: String,
 }
 
+impl PutDashboardInput {
+    /// Returns a builder for `PutDashboardInput`, checking its required members when built.
+    pub fn builder() -> PutDashboardInputBuilder {
+        PutDashboardInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutDashboardInput`](struct.PutDashboardInput.html).
+#[derive(Default, Debug)]
+pub struct PutDashboardInputBuilder {
+    dashboard_body: Option<String>,
+    dashboard_name: Option<String>,
+}
+
+impl PutDashboardInputBuilder {
+    /// Sets the [`dashboard_body`](struct.PutDashboardInput.html#structfield.dashboard_body) member.
+    pub fn dashboard_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.dashboard_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dashboard_name`](struct.PutDashboardInput.html#structfield.dashboard_name) member.
+    pub fn dashboard_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dashboard_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutDashboardInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutDashboardInput, ::rusoto_core::BuildError> {
+        Ok(PutDashboardInput {
+            dashboard_body: self
+                .dashboard_body
+                .ok_or(::rusoto_core::BuildError::missing("dashboard_body"))?,
+            dashboard_name: self
+                .dashboard_name
+                .ok_or(::rusoto_core::BuildError::missing("dashboard_name"))?,
+        })
+    }
+}
+
 /// Serialize `PutDashboardInput` contents to a `SignedRequest`.
 struct PutDashboardInputSerializer;
 impl PutDashboardInputSerializer {
@@ -2756,10 +3941,10 @@ pub struct PutDashboardOutput {
     pub dashboard_validation_messages: Option<Vec<DashboardValidationMessage>>,
 }
 
-struct PutDashboardOutputDeserializer;
+pub(crate) struct PutDashboardOutputDeserializer;
 impl PutDashboardOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PutDashboardOutput, XmlParseError> {
@@ -2779,7 +3964,7 @@ impl PutDashboardOutputDeserializer {
         })
     }
 }
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PutMetricAlarmInput {
     /// <p>Indicates whether actions should be executed during any changes to the alarm state. The default is <code>TRUE</code>.</p>
     pub actions_enabled: Option<bool>,
@@ -2790,7 +3975,7 @@ pub struct PutMetricAlarmInput {
     /// <p>The name for the alarm. This name must be unique within your AWS account.</p>
     pub alarm_name: String,
     /// <p> The arithmetic operation to use when comparing the specified statistic and threshold. The specified statistic value is used as the first operand.</p> <p>The values <code>LessThanLowerOrGreaterThanUpperThreshold</code>, <code>LessThanLowerThreshold</code>, and <code>GreaterThanUpperThreshold</code> are used only for alarms based on anomaly detection models.</p>
-    pub comparison_operator: String,
+    pub comparison_operator: ComparisonOperator,
     /// <p>The number of datapoints that must be breaching to trigger the alarm. This is used only if you are setting an "M out of N" alarm. In that case, this value is the M. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/AlarmThatSendsEmail.html#alarm-evaluation">Evaluating an Alarm</a> in the <i>Amazon CloudWatch User Guide</i>.</p>
     pub datapoints_to_alarm: Option<i64>,
     /// <p>The dimensions for the metric specified in <code>MetricName</code>.</p>
@@ -2814,7 +3999,7 @@ pub struct PutMetricAlarmInput {
     /// <p>The length, in seconds, used each time the metric specified in <code>MetricName</code> is evaluated. Valid values are 10, 30, and any multiple of 60.</p> <p> <code>Period</code> is required for alarms based on static thresholds. If you are creating an alarm based on a metric math expression, you specify the period for each metric within the objects in the <code>Metrics</code> array.</p> <p>Be sure to specify 10 or 30 only for metrics that are stored by a <code>PutMetricData</code> call with a <code>StorageResolution</code> of 1. If you specify a period of 10 or 30 for a metric that does not have sub-minute resolution, the alarm still attempts to gather data at the period rate that you specify. In this case, it does not receive data for the attempts that do not correspond to a one-minute data resolution, and the alarm may often lapse into INSUFFICENT_DATA status. Specifying 10 or 30 also sets this alarm as a high-resolution alarm, which has a higher charge than other alarms. For more information about pricing, see <a href="https://aws.amazon.com/cloudwatch/pricing/">Amazon CloudWatch Pricing</a>.</p> <p>An alarm's total current evaluation period can be no longer than one day, so <code>Period</code> multiplied by <code>EvaluationPeriods</code> cannot be more than 86,400 seconds.</p>
     pub period: Option<i64>,
     /// <p>The statistic for the metric specified in <code>MetricName</code>, other than percentile. For percentile statistics, use <code>ExtendedStatistic</code>. When you call <code>PutMetricAlarm</code> and specify a <code>MetricName</code>, you must specify either <code>Statistic</code> or <code>ExtendedStatistic,</code> but not both.</p>
-    pub statistic: Option<String>,
+    pub statistic: Option<Statistic>,
     /// <p>A list of key-value pairs to associate with the alarm. You can associate as many as 50 tags with an alarm.</p> <p>Tags can help you organize and categorize your resources. You can also use them to scope user permissions, by granting a user permission to access or change only resources with certain tag values.</p>
     pub tags: Option<Vec<Tag>>,
     /// <p>The value against which the specified statistic is compared.</p> <p>This parameter is required for alarms based on static thresholds, but should not be used for alarms based on anomaly detection models.</p>
@@ -2824,7 +4009,209 @@ pub struct PutMetricAlarmInput {
     /// <p> Sets how this alarm is to handle missing data points. If <code>TreatMissingData</code> is omitted, the default behavior of <code>missing</code> is used. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/monitoring/AlarmThatSendsEmail.html#alarms-and-missing-data">Configuring How CloudWatch Alarms Treats Missing Data</a>.</p> <p>Valid Values: <code>breaching | notBreaching | ignore | missing</code> </p>
     pub treat_missing_data: Option<String>,
     /// <p>The unit of measure for the statistic. For example, the units for the Amazon EC2 NetworkIn metric are Bytes because NetworkIn tracks the number of bytes that an instance receives on all network interfaces. You can also specify a unit when you create a custom metric. Units help provide conceptual meaning to your data. Metric data points that specify a unit of measure, such as Percent, are aggregated separately.</p> <p>If you don't specify <code>Unit</code>, CloudWatch retrieves all unit types that have been published for the metric and attempts to evaluate the alarm. Usually metrics are published with only one unit, so the alarm will work as intended.</p> <p>However, if the metric is published with multiple types of units and you don't specify a unit, the alarm's behavior is not defined and will behave un-predictably.</p> <p>We recommend omitting <code>Unit</code> so that you don't inadvertently specify an incorrect unit that is not published for this metric. Doing so causes the alarm to be stuck in the <code>INSUFFICIENT DATA</code> state.</p>
-    pub unit: Option<String>,
+    pub unit: Option<StandardUnit>,
+}
+
+impl PutMetricAlarmInput {
+    /// Returns a builder for `PutMetricAlarmInput`, checking its required members when built.
+    pub fn builder() -> PutMetricAlarmInputBuilder {
+        PutMetricAlarmInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutMetricAlarmInput`](struct.PutMetricAlarmInput.html).
+#[derive(Default, Debug)]
+pub struct PutMetricAlarmInputBuilder {
+    actions_enabled: Option<bool>,
+    alarm_actions: Option<Vec<String>>,
+    alarm_description: Option<String>,
+    alarm_name: Option<String>,
+    comparison_operator: Option<ComparisonOperator>,
+    datapoints_to_alarm: Option<i64>,
+    dimensions: Option<Vec<Dimension>>,
+    evaluate_low_sample_count_percentile: Option<String>,
+    evaluation_periods: Option<i64>,
+    extended_statistic: Option<String>,
+    insufficient_data_actions: Option<Vec<String>>,
+    metric_name: Option<String>,
+    metrics: Option<Vec<MetricDataQuery>>,
+    namespace: Option<String>,
+    ok_actions: Option<Vec<String>>,
+    period: Option<i64>,
+    statistic: Option<Statistic>,
+    tags: Option<Vec<Tag>>,
+    threshold: Option<f64>,
+    threshold_metric_id: Option<String>,
+    treat_missing_data: Option<String>,
+    unit: Option<StandardUnit>,
+}
+
+impl PutMetricAlarmInputBuilder {
+    /// Sets the [`actions_enabled`](struct.PutMetricAlarmInput.html#structfield.actions_enabled) member.
+    pub fn actions_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.actions_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`alarm_actions`](struct.PutMetricAlarmInput.html#structfield.alarm_actions) member.
+    pub fn alarm_actions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.alarm_actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`alarm_description`](struct.PutMetricAlarmInput.html#structfield.alarm_description) member.
+    pub fn alarm_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.alarm_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`alarm_name`](struct.PutMetricAlarmInput.html#structfield.alarm_name) member.
+    pub fn alarm_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.alarm_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`comparison_operator`](struct.PutMetricAlarmInput.html#structfield.comparison_operator) member.
+    pub fn comparison_operator<T: Into<ComparisonOperator>>(mut self, value: T) -> Self {
+        self.comparison_operator = Some(value.into());
+        self
+    }
+
+    /// Sets the [`datapoints_to_alarm`](struct.PutMetricAlarmInput.html#structfield.datapoints_to_alarm) member.
+    pub fn datapoints_to_alarm<T: Into<i64>>(mut self, value: T) -> Self {
+        self.datapoints_to_alarm = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dimensions`](struct.PutMetricAlarmInput.html#structfield.dimensions) member.
+    pub fn dimensions<T: Into<Vec<Dimension>>>(mut self, value: T) -> Self {
+        self.dimensions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`evaluate_low_sample_count_percentile`](struct.PutMetricAlarmInput.html#structfield.evaluate_low_sample_count_percentile) member.
+    pub fn evaluate_low_sample_count_percentile<T: Into<String>>(mut self, value: T) -> Self {
+        self.evaluate_low_sample_count_percentile = Some(value.into());
+        self
+    }
+
+    /// Sets the [`evaluation_periods`](struct.PutMetricAlarmInput.html#structfield.evaluation_periods) member.
+    pub fn evaluation_periods<T: Into<i64>>(mut self, value: T) -> Self {
+        self.evaluation_periods = Some(value.into());
+        self
+    }
+
+    /// Sets the [`extended_statistic`](struct.PutMetricAlarmInput.html#structfield.extended_statistic) member.
+    pub fn extended_statistic<T: Into<String>>(mut self, value: T) -> Self {
+        self.extended_statistic = Some(value.into());
+        self
+    }
+
+    /// Sets the [`insufficient_data_actions`](struct.PutMetricAlarmInput.html#structfield.insufficient_data_actions) member.
+    pub fn insufficient_data_actions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.insufficient_data_actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.PutMetricAlarmInput.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metrics`](struct.PutMetricAlarmInput.html#structfield.metrics) member.
+    pub fn metrics<T: Into<Vec<MetricDataQuery>>>(mut self, value: T) -> Self {
+        self.metrics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`namespace`](struct.PutMetricAlarmInput.html#structfield.namespace) member.
+    pub fn namespace<T: Into<String>>(mut self, value: T) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ok_actions`](struct.PutMetricAlarmInput.html#structfield.ok_actions) member.
+    pub fn ok_actions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.ok_actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`period`](struct.PutMetricAlarmInput.html#structfield.period) member.
+    pub fn period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statistic`](struct.PutMetricAlarmInput.html#structfield.statistic) member.
+    pub fn statistic<T: Into<Statistic>>(mut self, value: T) -> Self {
+        self.statistic = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.PutMetricAlarmInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`threshold`](struct.PutMetricAlarmInput.html#structfield.threshold) member.
+    pub fn threshold<T: Into<f64>>(mut self, value: T) -> Self {
+        self.threshold = Some(value.into());
+        self
+    }
+
+    /// Sets the [`threshold_metric_id`](struct.PutMetricAlarmInput.html#structfield.threshold_metric_id) member.
+    pub fn threshold_metric_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.threshold_metric_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`treat_missing_data`](struct.PutMetricAlarmInput.html#structfield.treat_missing_data) member.
+    pub fn treat_missing_data<T: Into<String>>(mut self, value: T) -> Self {
+        self.treat_missing_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unit`](struct.PutMetricAlarmInput.html#structfield.unit) member.
+    pub fn unit<T: Into<StandardUnit>>(mut self, value: T) -> Self {
+        self.unit = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutMetricAlarmInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutMetricAlarmInput, ::rusoto_core::BuildError> {
+        Ok(PutMetricAlarmInput {
+            actions_enabled: self.actions_enabled,
+            alarm_actions: self.alarm_actions,
+            alarm_description: self.alarm_description,
+            alarm_name: self
+                .alarm_name
+                .ok_or(::rusoto_core::BuildError::missing("alarm_name"))?,
+            comparison_operator: self
+                .comparison_operator
+                .ok_or(::rusoto_core::BuildError::missing("comparison_operator"))?,
+            datapoints_to_alarm: self.datapoints_to_alarm,
+            dimensions: self.dimensions,
+            evaluate_low_sample_count_percentile: self.evaluate_low_sample_count_percentile,
+            evaluation_periods: self
+                .evaluation_periods
+                .ok_or(::rusoto_core::BuildError::missing("evaluation_periods"))?,
+            extended_statistic: self.extended_statistic,
+            insufficient_data_actions: self.insufficient_data_actions,
+            metric_name: self.metric_name,
+            metrics: self.metrics,
+            namespace: self.namespace,
+            ok_actions: self.ok_actions,
+            period: self.period,
+            statistic: self.statistic,
+            tags: self.tags,
+            threshold: self.threshold,
+            threshold_metric_id: self.threshold_metric_id,
+            treat_missing_data: self.treat_missing_data,
+            unit: self.unit,
+        })
+    }
 }
 
 /// Serialize `PutMetricAlarmInput` contents to a `SignedRequest`.
@@ -2936,6 +4323,46 @@ pub struct PutMetricDataInput {
     pub namespace: String,
 }
 
+impl PutMetricDataInput {
+    /// Returns a builder for `PutMetricDataInput`, checking its required members when built.
+    pub fn builder() -> PutMetricDataInputBuilder {
+        PutMetricDataInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutMetricDataInput`](struct.PutMetricDataInput.html).
+#[derive(Default, Debug)]
+pub struct PutMetricDataInputBuilder {
+    metric_data: Option<Vec<MetricDatum>>,
+    namespace: Option<String>,
+}
+
+impl PutMetricDataInputBuilder {
+    /// Sets the [`metric_data`](struct.PutMetricDataInput.html#structfield.metric_data) member.
+    pub fn metric_data<T: Into<Vec<MetricDatum>>>(mut self, value: T) -> Self {
+        self.metric_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`namespace`](struct.PutMetricDataInput.html#structfield.namespace) member.
+    pub fn namespace<T: Into<String>>(mut self, value: T) -> Self {
+        self.namespace = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutMetricDataInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutMetricDataInput, ::rusoto_core::BuildError> {
+        Ok(PutMetricDataInput {
+            metric_data: self
+                .metric_data
+                .ok_or(::rusoto_core::BuildError::missing("metric_data"))?,
+            namespace: self
+                .namespace
+                .ok_or(::rusoto_core::BuildError::missing("namespace"))?,
+        })
+    }
+}
+
 /// Serialize `PutMetricDataInput` contents to a `SignedRequest`.
 struct PutMetricDataInputSerializer;
 impl PutMetricDataInputSerializer {
@@ -2958,15 +4385,18 @@ impl PutMetricDataInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Range {
     /// <p>The end time of the range to exclude. The format is <code>yyyy-MM-dd'T'HH:mm:ss</code>. For example, <code>2019-07-01T23:59:59</code>.</p>
-    pub end_time: String,
+    pub end_time: IsoTimestamp,
     /// <p>The start time of the range to exclude. The format is <code>yyyy-MM-dd'T'HH:mm:ss</code>. For example, <code>2019-07-01T23:59:59</code>.</p>
-    pub start_time: String,
+    pub start_time: IsoTimestamp,
 }
 
-struct RangeDeserializer;
+pub(crate) struct RangeDeserializer;
 impl RangeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Range, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Range, XmlParseError> {
         deserialize_elements::<_, Range, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "EndTime" => {
@@ -2996,10 +4426,10 @@ impl RangeSerializer {
     }
 }
 
-struct ResourceListDeserializer;
+pub(crate) struct ResourceListDeserializer;
 impl ResourceListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -3025,10 +4455,13 @@ impl ResourceListSerializer {
     }
 }
 
-struct ResourceNameDeserializer;
+pub(crate) struct ResourceNameDeserializer;
 impl ResourceNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3036,10 +4469,13 @@ impl ResourceNameDeserializer {
         Ok(obj)
     }
 }
-struct ReturnDataDeserializer;
+pub(crate) struct ReturnDataDeserializer;
 impl ReturnDataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3047,7 +4483,66 @@ impl ReturnDataDeserializer {
         Ok(obj)
     }
 }
-#[derive(Default, Debug, Clone, PartialEq)]
+/// <p>The order in which data points should be returned. <code>TimestampDescending</code> returns the newest data first and paginates when the <code>MaxDatapoints</code> limit is reached. <code>TimestampAscending</code> returns the oldest data first and paginates when the <code>MaxDatapoints</code> limit is reached.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScanBy {
+    /// <p><code>TimestampDescending</code></p>
+    TimestampDescending,
+    /// <p><code>TimestampAscending</code></p>
+    TimestampAscending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScanBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScanBy::TimestampDescending => "TimestampDescending",
+            ScanBy::TimestampAscending => "TimestampAscending",
+            ScanBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScanBy {
+    fn from(value: &'a str) -> ScanBy {
+        match value {
+            "TimestampDescending" => ScanBy::TimestampDescending,
+            "TimestampAscending" => ScanBy::TimestampAscending,
+            _ => ScanBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScanBy {
+    fn from(value: String) -> ScanBy {
+        ScanBy::from(value.as_str())
+    }
+}
+
+impl From<ScanBy> for String {
+    fn from(value: ScanBy) -> String {
+        match value {
+            ScanBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScanBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScanBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct SetAlarmStateInput {
     /// <p>The name for the alarm. This name must be unique within the AWS account. The maximum length is 255 characters.</p>
     pub alarm_name: String,
@@ -3056,7 +4551,65 @@ pub struct SetAlarmStateInput {
     /// <p>The reason that this alarm is set to this specific state, in JSON format.</p>
     pub state_reason_data: Option<String>,
     /// <p>The value of the state.</p>
-    pub state_value: String,
+    pub state_value: StateValue,
+}
+
+impl SetAlarmStateInput {
+    /// Returns a builder for `SetAlarmStateInput`, checking its required members when built.
+    pub fn builder() -> SetAlarmStateInputBuilder {
+        SetAlarmStateInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetAlarmStateInput`](struct.SetAlarmStateInput.html).
+#[derive(Default, Debug)]
+pub struct SetAlarmStateInputBuilder {
+    alarm_name: Option<String>,
+    state_reason: Option<String>,
+    state_reason_data: Option<String>,
+    state_value: Option<StateValue>,
+}
+
+impl SetAlarmStateInputBuilder {
+    /// Sets the [`alarm_name`](struct.SetAlarmStateInput.html#structfield.alarm_name) member.
+    pub fn alarm_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.alarm_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state_reason`](struct.SetAlarmStateInput.html#structfield.state_reason) member.
+    pub fn state_reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.state_reason = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state_reason_data`](struct.SetAlarmStateInput.html#structfield.state_reason_data) member.
+    pub fn state_reason_data<T: Into<String>>(mut self, value: T) -> Self {
+        self.state_reason_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state_value`](struct.SetAlarmStateInput.html#structfield.state_value) member.
+    pub fn state_value<T: Into<StateValue>>(mut self, value: T) -> Self {
+        self.state_value = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetAlarmStateInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetAlarmStateInput, ::rusoto_core::BuildError> {
+        Ok(SetAlarmStateInput {
+            alarm_name: self
+                .alarm_name
+                .ok_or(::rusoto_core::BuildError::missing("alarm_name"))?,
+            state_reason: self
+                .state_reason
+                .ok_or(::rusoto_core::BuildError::missing("state_reason"))?,
+            state_reason_data: self.state_reason_data,
+            state_value: self
+                .state_value
+                .ok_or(::rusoto_core::BuildError::missing("state_value"))?,
+        })
+    }
 }
 
 /// Serialize `SetAlarmStateInput` contents to a `SignedRequest`.
@@ -3077,83 +4630,397 @@ impl SetAlarmStateInputSerializer {
     }
 }
 
-struct SizeDeserializer;
-impl SizeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
-        end_element(tag_name, stack)?;
-
-        Ok(obj)
+pub(crate) struct SizeDeserializer;
+impl SizeDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+pub(crate) struct StandardUnitDeserializer;
+impl StandardUnitDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StandardUnit, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+/// <p>The standard unit for the data point.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StandardUnit {
+    /// <p><code>Seconds</code></p>
+    Seconds,
+    /// <p><code>Microseconds</code></p>
+    Microseconds,
+    /// <p><code>Milliseconds</code></p>
+    Milliseconds,
+    /// <p><code>Bytes</code></p>
+    Bytes,
+    /// <p><code>Kilobytes</code></p>
+    Kilobytes,
+    /// <p><code>Megabytes</code></p>
+    Megabytes,
+    /// <p><code>Gigabytes</code></p>
+    Gigabytes,
+    /// <p><code>Terabytes</code></p>
+    Terabytes,
+    /// <p><code>Bits</code></p>
+    Bits,
+    /// <p><code>Kilobits</code></p>
+    Kilobits,
+    /// <p><code>Megabits</code></p>
+    Megabits,
+    /// <p><code>Gigabits</code></p>
+    Gigabits,
+    /// <p><code>Terabits</code></p>
+    Terabits,
+    /// <p><code>Percent</code></p>
+    Percent,
+    /// <p><code>Count</code></p>
+    Count,
+    /// <p><code>Bytes/Second</code></p>
+    BytesSecond,
+    /// <p><code>Kilobytes/Second</code></p>
+    KilobytesSecond,
+    /// <p><code>Megabytes/Second</code></p>
+    MegabytesSecond,
+    /// <p><code>Gigabytes/Second</code></p>
+    GigabytesSecond,
+    /// <p><code>Terabytes/Second</code></p>
+    TerabytesSecond,
+    /// <p><code>Bits/Second</code></p>
+    BitsSecond,
+    /// <p><code>Kilobits/Second</code></p>
+    KilobitsSecond,
+    /// <p><code>Megabits/Second</code></p>
+    MegabitsSecond,
+    /// <p><code>Gigabits/Second</code></p>
+    GigabitsSecond,
+    /// <p><code>Terabits/Second</code></p>
+    TerabitsSecond,
+    /// <p><code>Count/Second</code></p>
+    CountSecond,
+    /// <p><code>None</code></p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StandardUnit {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StandardUnit::Seconds => "Seconds",
+            StandardUnit::Microseconds => "Microseconds",
+            StandardUnit::Milliseconds => "Milliseconds",
+            StandardUnit::Bytes => "Bytes",
+            StandardUnit::Kilobytes => "Kilobytes",
+            StandardUnit::Megabytes => "Megabytes",
+            StandardUnit::Gigabytes => "Gigabytes",
+            StandardUnit::Terabytes => "Terabytes",
+            StandardUnit::Bits => "Bits",
+            StandardUnit::Kilobits => "Kilobits",
+            StandardUnit::Megabits => "Megabits",
+            StandardUnit::Gigabits => "Gigabits",
+            StandardUnit::Terabits => "Terabits",
+            StandardUnit::Percent => "Percent",
+            StandardUnit::Count => "Count",
+            StandardUnit::BytesSecond => "Bytes/Second",
+            StandardUnit::KilobytesSecond => "Kilobytes/Second",
+            StandardUnit::MegabytesSecond => "Megabytes/Second",
+            StandardUnit::GigabytesSecond => "Gigabytes/Second",
+            StandardUnit::TerabytesSecond => "Terabytes/Second",
+            StandardUnit::BitsSecond => "Bits/Second",
+            StandardUnit::KilobitsSecond => "Kilobits/Second",
+            StandardUnit::MegabitsSecond => "Megabits/Second",
+            StandardUnit::GigabitsSecond => "Gigabits/Second",
+            StandardUnit::TerabitsSecond => "Terabits/Second",
+            StandardUnit::CountSecond => "Count/Second",
+            StandardUnit::None => "None",
+            StandardUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StandardUnit {
+    fn from(value: &'a str) -> StandardUnit {
+        match value {
+            "Seconds" => StandardUnit::Seconds,
+            "Microseconds" => StandardUnit::Microseconds,
+            "Milliseconds" => StandardUnit::Milliseconds,
+            "Bytes" => StandardUnit::Bytes,
+            "Kilobytes" => StandardUnit::Kilobytes,
+            "Megabytes" => StandardUnit::Megabytes,
+            "Gigabytes" => StandardUnit::Gigabytes,
+            "Terabytes" => StandardUnit::Terabytes,
+            "Bits" => StandardUnit::Bits,
+            "Kilobits" => StandardUnit::Kilobits,
+            "Megabits" => StandardUnit::Megabits,
+            "Gigabits" => StandardUnit::Gigabits,
+            "Terabits" => StandardUnit::Terabits,
+            "Percent" => StandardUnit::Percent,
+            "Count" => StandardUnit::Count,
+            "Bytes/Second" => StandardUnit::BytesSecond,
+            "Kilobytes/Second" => StandardUnit::KilobytesSecond,
+            "Megabytes/Second" => StandardUnit::MegabytesSecond,
+            "Gigabytes/Second" => StandardUnit::GigabytesSecond,
+            "Terabytes/Second" => StandardUnit::TerabytesSecond,
+            "Bits/Second" => StandardUnit::BitsSecond,
+            "Kilobits/Second" => StandardUnit::KilobitsSecond,
+            "Megabits/Second" => StandardUnit::MegabitsSecond,
+            "Gigabits/Second" => StandardUnit::GigabitsSecond,
+            "Terabits/Second" => StandardUnit::TerabitsSecond,
+            "Count/Second" => StandardUnit::CountSecond,
+            "None" => StandardUnit::None,
+            _ => StandardUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StandardUnit {
+    fn from(value: String) -> StandardUnit {
+        StandardUnit::from(value.as_str())
+    }
+}
+
+impl From<StandardUnit> for String {
+    fn from(value: StandardUnit) -> String {
+        match value {
+            StandardUnit::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StandardUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StandardUnit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct StatDeserializer;
+impl StatDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+pub(crate) struct StateReasonDeserializer;
+impl StateReasonDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+pub(crate) struct StateReasonDataDeserializer;
+impl StateReasonDataDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+pub(crate) struct StateValueDeserializer;
+impl StateValueDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StateValue, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+/// <p>The state value to be used in matching alarms.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StateValue {
+    /// <p><code>OK</code></p>
+    Ok,
+    /// <p><code>ALARM</code></p>
+    Alarm,
+    /// <p><code>INSUFFICIENT_DATA</code></p>
+    InsufficientData,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StateValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StateValue::Ok => "OK",
+            StateValue::Alarm => "ALARM",
+            StateValue::InsufficientData => "INSUFFICIENT_DATA",
+            StateValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StateValue {
+    fn from(value: &'a str) -> StateValue {
+        match value {
+            "OK" => StateValue::Ok,
+            "ALARM" => StateValue::Alarm,
+            "INSUFFICIENT_DATA" => StateValue::InsufficientData,
+            _ => StateValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StateValue {
+    fn from(value: String) -> StateValue {
+        StateValue::from(value.as_str())
+    }
+}
+
+impl From<StateValue> for String {
+    fn from(value: StateValue) -> String {
+        match value {
+            StateValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StateValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StateValue {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
     }
 }
-struct StandardUnitDeserializer;
-impl StandardUnitDeserializer {
+
+pub(crate) struct StatisticDeserializer;
+impl StatisticDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Statistic, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct StatDeserializer;
-impl StatDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+/// <p>The statistic for the metric, other than percentiles. For percentile statistics, use <code>ExtendedStatistics</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Statistic {
+    /// <p><code>SampleCount</code></p>
+    SampleCount,
+    /// <p><code>Average</code></p>
+    Average,
+    /// <p><code>Sum</code></p>
+    Sum,
+    /// <p><code>Minimum</code></p>
+    Minimum,
+    /// <p><code>Maximum</code></p>
+    Maximum,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Statistic {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Statistic::SampleCount => "SampleCount",
+            Statistic::Average => "Average",
+            Statistic::Sum => "Sum",
+            Statistic::Minimum => "Minimum",
+            Statistic::Maximum => "Maximum",
+            Statistic::Unknown(ref value) => value,
+        }
+    }
+}
 
-        Ok(obj)
+impl<'a> From<&'a str> for Statistic {
+    fn from(value: &'a str) -> Statistic {
+        match value {
+            "SampleCount" => Statistic::SampleCount,
+            "Average" => Statistic::Average,
+            "Sum" => Statistic::Sum,
+            "Minimum" => Statistic::Minimum,
+            "Maximum" => Statistic::Maximum,
+            _ => Statistic::Unknown(value.to_owned()),
+        }
     }
 }
-struct StateReasonDeserializer;
-impl StateReasonDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl From<String> for Statistic {
+    fn from(value: String) -> Statistic {
+        Statistic::from(value.as_str())
     }
 }
-struct StateReasonDataDeserializer;
-impl StateReasonDataDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl From<Statistic> for String {
+    fn from(value: Statistic) -> String {
+        match value {
+            Statistic::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
     }
 }
-struct StateValueDeserializer;
-impl StateValueDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl fmt::Display for Statistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
     }
 }
-struct StatisticDeserializer;
-impl StatisticDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl ::rusoto_core::param::ToParam for Statistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
     }
 }
+
 /// <p>Represents a set of statistics that describes a specific metric. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct StatisticSet {
@@ -3186,7 +5053,7 @@ impl StatisticSetSerializer {
 /// Serialize `Statistics` contents to a `SignedRequest`.
 struct StatisticsSerializer;
 impl StatisticsSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<Statistic>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -3194,17 +5061,87 @@ impl StatisticsSerializer {
     }
 }
 
-struct StatusCodeDeserializer;
+pub(crate) struct StatusCodeDeserializer;
 impl StatusCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StatusCode, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The status of the returned data. <code>Complete</code> indicates that all data points in the requested time range were returned. <code>PartialData</code> means that an incomplete set of data points were returned. You can use the <code>NextToken</code> value that was returned and repeat your request to get more data points. <code>NextToken</code> is not returned if you are performing a math expression. <code>InternalError</code> indicates that an error occurred. Retry your request using <code>NextToken</code>, if present.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StatusCode {
+    /// <p><code>Complete</code></p>
+    Complete,
+    /// <p><code>InternalError</code></p>
+    InternalError,
+    /// <p><code>PartialData</code></p>
+    PartialData,
+    /// <p><code>Forbidden</code></p>
+    Forbidden,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusCode::Complete => "Complete",
+            StatusCode::InternalError => "InternalError",
+            StatusCode::PartialData => "PartialData",
+            StatusCode::Forbidden => "Forbidden",
+            StatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StatusCode {
+    fn from(value: &'a str) -> StatusCode {
+        match value {
+            "Complete" => StatusCode::Complete,
+            "InternalError" => StatusCode::InternalError,
+            "PartialData" => StatusCode::PartialData,
+            "Forbidden" => StatusCode::Forbidden,
+            _ => StatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusCode {
+    fn from(value: String) -> StatusCode {
+        StatusCode::from(value.as_str())
+    }
+}
+
+impl From<StatusCode> for String {
+    fn from(value: StatusCode) -> String {
+        match value {
+            StatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A key-value pair associated with a CloudWatch resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Tag {
@@ -3214,10 +5151,13 @@ pub struct Tag {
     pub value: String,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -3247,10 +5187,13 @@ impl TagSerializer {
     }
 }
 
-struct TagKeyDeserializer;
+pub(crate) struct TagKeyDeserializer;
 impl TagKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3270,10 +5213,10 @@ impl TagKeyListSerializer {
     }
 }
 
-struct TagListDeserializer;
+pub(crate) struct TagListDeserializer;
 impl TagListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -3307,6 +5250,46 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceInput {
+    /// Returns a builder for `TagResourceInput`, checking its required members when built.
+    pub fn builder() -> TagResourceInputBuilder {
+        TagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceInput`](struct.TagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct TagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceInput, ::rusoto_core::BuildError> {
+        Ok(TagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `TagResourceInput` contents to a `SignedRequest`.
 struct TagResourceInputSerializer;
 impl TagResourceInputSerializer {
@@ -3324,10 +5307,10 @@ impl TagResourceInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct TagResourceOutput {}
 
-struct TagResourceOutputDeserializer;
+pub(crate) struct TagResourceOutputDeserializer;
 impl TagResourceOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TagResourceOutput, XmlParseError> {
@@ -3340,10 +5323,13 @@ impl TagResourceOutputDeserializer {
         Ok(obj)
     }
 }
-struct TagValueDeserializer;
+pub(crate) struct TagValueDeserializer;
 impl TagValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3351,10 +5337,13 @@ impl TagValueDeserializer {
         Ok(obj)
     }
 }
-struct ThresholdDeserializer;
+pub(crate) struct ThresholdDeserializer;
 impl ThresholdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3362,24 +5351,27 @@ impl ThresholdDeserializer {
         Ok(obj)
     }
 }
-struct TimestampDeserializer;
+pub(crate) struct TimestampDeserializer;
 impl TimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct TimestampsDeserializer;
+pub(crate) struct TimestampsDeserializer;
 impl TimestampsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<IsoTimestamp>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "member" {
                 obj.push(TimestampDeserializer::deserialize("member", stack)?);
@@ -3390,10 +5382,13 @@ impl TimestampsDeserializer {
         })
     }
 }
-struct TreatMissingDataDeserializer;
+pub(crate) struct TreatMissingDataDeserializer;
 impl TreatMissingDataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3409,6 +5404,46 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceInput {
+    /// Returns a builder for `UntagResourceInput`, checking its required members when built.
+    pub fn builder() -> UntagResourceInputBuilder {
+        UntagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceInput`](struct.UntagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceInput, ::rusoto_core::BuildError> {
+        Ok(UntagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// Serialize `UntagResourceInput` contents to a `SignedRequest`.
 struct UntagResourceInputSerializer;
 impl UntagResourceInputSerializer {
@@ -3426,10 +5461,10 @@ impl UntagResourceInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UntagResourceOutput {}
 
-struct UntagResourceOutputDeserializer;
+pub(crate) struct UntagResourceOutputDeserializer;
 impl UntagResourceOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UntagResourceOutput, XmlParseError> {
@@ -4874,6 +6909,144 @@ pub trait CloudWatch {
         input: UntagResourceInput,
     ) -> RusotoFuture<UntagResourceOutput, UntagResourceError>;
 }
+impl<T: ?Sized + CloudWatch> CloudWatch for ::std::sync::Arc<T> {
+    fn delete_alarms(&self, input: DeleteAlarmsInput) -> RusotoFuture<(), DeleteAlarmsError> {
+        (**self).delete_alarms(input)
+    }
+    fn delete_anomaly_detector(
+        &self,
+        input: DeleteAnomalyDetectorInput,
+    ) -> RusotoFuture<DeleteAnomalyDetectorOutput, DeleteAnomalyDetectorError> {
+        (**self).delete_anomaly_detector(input)
+    }
+    fn delete_dashboards(
+        &self,
+        input: DeleteDashboardsInput,
+    ) -> RusotoFuture<DeleteDashboardsOutput, DeleteDashboardsError> {
+        (**self).delete_dashboards(input)
+    }
+    fn describe_alarm_history(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> RusotoFuture<DescribeAlarmHistoryOutput, DescribeAlarmHistoryError> {
+        (**self).describe_alarm_history(input)
+    }
+    fn describe_alarms(
+        &self,
+        input: DescribeAlarmsInput,
+    ) -> RusotoFuture<DescribeAlarmsOutput, DescribeAlarmsError> {
+        (**self).describe_alarms(input)
+    }
+    fn describe_alarms_for_metric(
+        &self,
+        input: DescribeAlarmsForMetricInput,
+    ) -> RusotoFuture<DescribeAlarmsForMetricOutput, DescribeAlarmsForMetricError> {
+        (**self).describe_alarms_for_metric(input)
+    }
+    fn describe_anomaly_detectors(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> RusotoFuture<DescribeAnomalyDetectorsOutput, DescribeAnomalyDetectorsError> {
+        (**self).describe_anomaly_detectors(input)
+    }
+    fn disable_alarm_actions(
+        &self,
+        input: DisableAlarmActionsInput,
+    ) -> RusotoFuture<(), DisableAlarmActionsError> {
+        (**self).disable_alarm_actions(input)
+    }
+    fn enable_alarm_actions(
+        &self,
+        input: EnableAlarmActionsInput,
+    ) -> RusotoFuture<(), EnableAlarmActionsError> {
+        (**self).enable_alarm_actions(input)
+    }
+    fn get_dashboard(
+        &self,
+        input: GetDashboardInput,
+    ) -> RusotoFuture<GetDashboardOutput, GetDashboardError> {
+        (**self).get_dashboard(input)
+    }
+    fn get_metric_data(
+        &self,
+        input: GetMetricDataInput,
+    ) -> RusotoFuture<GetMetricDataOutput, GetMetricDataError> {
+        (**self).get_metric_data(input)
+    }
+    fn get_metric_statistics(
+        &self,
+        input: GetMetricStatisticsInput,
+    ) -> RusotoFuture<GetMetricStatisticsOutput, GetMetricStatisticsError> {
+        (**self).get_metric_statistics(input)
+    }
+    fn get_metric_widget_image(
+        &self,
+        input: GetMetricWidgetImageInput,
+    ) -> RusotoFuture<GetMetricWidgetImageOutput, GetMetricWidgetImageError> {
+        (**self).get_metric_widget_image(input)
+    }
+    fn list_dashboards(
+        &self,
+        input: ListDashboardsInput,
+    ) -> RusotoFuture<ListDashboardsOutput, ListDashboardsError> {
+        (**self).list_dashboards(input)
+    }
+    fn list_metrics(
+        &self,
+        input: ListMetricsInput,
+    ) -> RusotoFuture<ListMetricsOutput, ListMetricsError> {
+        (**self).list_metrics(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_anomaly_detector(
+        &self,
+        input: PutAnomalyDetectorInput,
+    ) -> RusotoFuture<PutAnomalyDetectorOutput, PutAnomalyDetectorError> {
+        (**self).put_anomaly_detector(input)
+    }
+    fn put_dashboard(
+        &self,
+        input: PutDashboardInput,
+    ) -> RusotoFuture<PutDashboardOutput, PutDashboardError> {
+        (**self).put_dashboard(input)
+    }
+    fn put_metric_alarm(
+        &self,
+        input: PutMetricAlarmInput,
+    ) -> RusotoFuture<(), PutMetricAlarmError> {
+        (**self).put_metric_alarm(input)
+    }
+    fn put_metric_data(&self, input: PutMetricDataInput) -> RusotoFuture<(), PutMetricDataError> {
+        (**self).put_metric_data(input)
+    }
+    fn set_alarm_state(&self, input: SetAlarmStateInput) -> RusotoFuture<(), SetAlarmStateError> {
+        (**self).set_alarm_state(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// The error codes CloudWatch retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the CloudWatch API.
 #[derive(Clone)]
 pub struct CloudWatchClient {
@@ -4889,6 +7062,27 @@ impl CloudWatchClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CLOUDWATCH`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CloudWatchClient {
+        Self::new(region::Region::from_env_for_service("CLOUDWATCH"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `monitoring`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CloudWatchClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("monitoring", "CLOUDWATCH"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4907,7 +7101,54 @@ impl CloudWatchClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchClient {
-        CloudWatchClient { client, region }
+        CloudWatchClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "monitoring")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "monitoring",
+            "monitoring",
+            "CLOUDWATCH",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CloudWatch` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CloudWatch + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CloudWatchClient {
+    fn service_name(&self) -> &'static str {
+        "CloudWatch"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "monitoring"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CloudWatchClient {
+        Self::new_with_client(client, region)
     }
 }
 
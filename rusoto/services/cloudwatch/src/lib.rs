@@ -17,6 +17,7 @@
 //! If you're using the service, you're probably looking for [CloudWatchClient](struct.CloudWatchClient.html) and [CloudWatch](trait.CloudWatch.html).
 
 extern crate bytes;
+extern crate flate2;
 extern crate futures;
 extern crate rusoto_core;
 extern crate serde_urlencoded;
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS CodeBuild clients.
+//!
+//! Makes a single read-only `ListBuilds` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_codebuild::{CodeBuild, CodeBuildClient, ListBuildsInput};
+
+fn main() {
+    let client = CodeBuildClient::from_env();
+    match client.list_builds(ListBuildsInput::default()).sync() {
+        Ok(_) => println!("ListBuilds succeeded"),
+        Err(error) => {
+            eprintln!("ListBuilds failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,7 +22,304 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>Along with <code>path</code> and <code>name</code>, the pattern that AWS CodeBuild uses to determine the name and location to store the output artifact:</p> <ul> <li> <p>If <code>type</code> is set to <code>CODEPIPELINE</code>, AWS CodePipeline ignores this value if specified. This is because AWS CodePipeline manages its build output names instead of AWS CodeBuild.</p> </li> <li> <p>If <code>type</code> is set to <code>NO_ARTIFACTS</code>, this value is ignored if specified, because no build output is produced.</p> </li> <li> <p>If <code>type</code> is set to <code>S3</code>, valid values include:</p> <ul> <li> <p> <code>BUILD_ID</code>: Include the build ID in the location of the build output artifact.</p> </li> <li> <p> <code>NONE</code>: Do not include the build ID. This is the default if <code>namespaceType</code> is not specified.</p> </li> </ul> </li> </ul> <p>For example, if <code>path</code> is set to <code>MyArtifacts</code>, <code>namespaceType</code> is set to <code>BUILD_ID</code>, and <code>name</code> is set to <code>MyArtifact.zip</code>, the output artifact is stored in <code>MyArtifacts/<i>build-ID</i>/MyArtifact.zip</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ArtifactNamespace {
+    /// <p><code>NONE</code> - Do not include the build ID. This is the default if <code>namespaceType</code> is not specified.</p>
+    None,
+    /// <p><code>BUILD_ID</code> - Include the build ID in the location of the build output artifact.</p>
+    BuildId,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ArtifactNamespace {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactNamespace::None => "NONE",
+            ArtifactNamespace::BuildId => "BUILD_ID",
+            ArtifactNamespace::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactNamespace {
+    fn from(value: &'a str) -> ArtifactNamespace {
+        match value {
+            "NONE" => ArtifactNamespace::None,
+            "BUILD_ID" => ArtifactNamespace::BuildId,
+            _ => ArtifactNamespace::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactNamespace {
+    fn from(value: String) -> ArtifactNamespace {
+        ArtifactNamespace::from(value.as_str())
+    }
+}
+
+impl From<ArtifactNamespace> for String {
+    fn from(value: ArtifactNamespace) -> String {
+        match value {
+            ArtifactNamespace::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ArtifactNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ArtifactNamespace {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactNamespace {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ArtifactNamespace, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ArtifactNamespace::from(value))
+    }
+}
+
+/// <p><p>The type of build output artifact to create:</p> <ul> <li> <p>If <code>type</code> is set to <code>CODEPIPELINE</code>, AWS CodePipeline ignores this value if specified. This is because AWS CodePipeline manages its build output artifacts instead of AWS CodeBuild.</p> </li> <li> <p>If <code>type</code> is set to <code>NO_ARTIFACTS</code>, this value is ignored if specified, because no build output is produced.</p> </li> <li> <p>If <code>type</code> is set to <code>S3</code>, valid values include:</p> <ul> <li> <p> <code>NONE</code>: AWS CodeBuild creates in the output bucket a folder that contains the build output. This is the default if <code>packaging</code> is not specified.</p> </li> <li> <p> <code>ZIP</code>: AWS CodeBuild creates in the output bucket a ZIP file that contains the build output.</p> </li> </ul> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ArtifactPackaging {
+    /// <p><code>NONE</code> - AWS CodeBuild creates in the output bucket a folder that contains the build output. This is the default if <code>packaging</code> is not specified.</p>
+    None,
+    /// <p><code>ZIP</code> - AWS CodeBuild creates in the output bucket a ZIP file that contains the build output.</p>
+    Zip,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ArtifactPackaging {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactPackaging::None => "NONE",
+            ArtifactPackaging::Zip => "ZIP",
+            ArtifactPackaging::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactPackaging {
+    fn from(value: &'a str) -> ArtifactPackaging {
+        match value {
+            "NONE" => ArtifactPackaging::None,
+            "ZIP" => ArtifactPackaging::Zip,
+            _ => ArtifactPackaging::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactPackaging {
+    fn from(value: String) -> ArtifactPackaging {
+        ArtifactPackaging::from(value.as_str())
+    }
+}
+
+impl From<ArtifactPackaging> for String {
+    fn from(value: ArtifactPackaging) -> String {
+        match value {
+            ArtifactPackaging::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ArtifactPackaging {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ArtifactPackaging {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactPackaging {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ArtifactPackaging, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ArtifactPackaging::from(value))
+    }
+}
+
+/// <p><p>The type of build output artifact. Valid values include:</p> <ul> <li> <p> <code>CODEPIPELINE</code>: The build project has build output generated through AWS CodePipeline.</p> </li> <li> <p> <code>NO_ARTIFACTS</code>: The build project does not produce any build output.</p> </li> <li> <p> <code>S3</code>: The build project stores build output in Amazon Simple Storage Service (Amazon S3).</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ArtifactsType {
+    /// <p><code>CODEPIPELINE</code> - The build project has build output generated through AWS CodePipeline.</p>
+    Codepipeline,
+    /// <p><code>S3</code> - The build project stores build output in Amazon Simple Storage Service (Amazon S3).</p>
+    S3,
+    /// <p><code>NO_ARTIFACTS</code> - The build project does not produce any build output.</p>
+    NoArtifacts,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ArtifactsType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactsType::Codepipeline => "CODEPIPELINE",
+            ArtifactsType::S3 => "S3",
+            ArtifactsType::NoArtifacts => "NO_ARTIFACTS",
+            ArtifactsType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactsType {
+    fn from(value: &'a str) -> ArtifactsType {
+        match value {
+            "CODEPIPELINE" => ArtifactsType::Codepipeline,
+            "S3" => ArtifactsType::S3,
+            "NO_ARTIFACTS" => ArtifactsType::NoArtifacts,
+            _ => ArtifactsType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactsType {
+    fn from(value: String) -> ArtifactsType {
+        ArtifactsType::from(value.as_str())
+    }
+}
+
+impl From<ArtifactsType> for String {
+    fn from(value: ArtifactsType) -> String {
+        match value {
+            ArtifactsType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ArtifactsType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ArtifactsType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactsType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ArtifactsType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ArtifactsType::from(value))
+    }
+}
+
+/// <p> The type of authentication used to connect to a GitHub, GitHub Enterprise, or Bitbucket repository. An OAUTH connection is not supported by the API and must be created using the AWS CodeBuild console. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthType {
+    /// <p><code>OAUTH</code></p>
+    Oauth,
+    /// <p><code>BASIC_AUTH</code></p>
+    BasicAuth,
+    /// <p><code>PERSONAL_ACCESS_TOKEN</code></p>
+    PersonalAccessToken,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthType::Oauth => "OAUTH",
+            AuthType::BasicAuth => "BASIC_AUTH",
+            AuthType::PersonalAccessToken => "PERSONAL_ACCESS_TOKEN",
+            AuthType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthType {
+    fn from(value: &'a str) -> AuthType {
+        match value {
+            "OAUTH" => AuthType::Oauth,
+            "BASIC_AUTH" => AuthType::BasicAuth,
+            "PERSONAL_ACCESS_TOKEN" => AuthType::PersonalAccessToken,
+            _ => AuthType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthType {
+    fn from(value: String) -> AuthType {
+        AuthType::from(value.as_str())
+    }
+}
+
+impl From<AuthType> for String {
+    fn from(value: AuthType) -> String {
+        match value {
+            AuthType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AuthType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct BatchDeleteBuildsInput {
     /// <p>The IDs of the builds to delete.</p>
@@ -30,6 +327,34 @@ pub struct BatchDeleteBuildsInput {
     pub ids: Vec<String>,
 }
 
+impl BatchDeleteBuildsInput {
+    /// Returns a builder for `BatchDeleteBuildsInput`, checking its required members when built.
+    pub fn builder() -> BatchDeleteBuildsInputBuilder {
+        BatchDeleteBuildsInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDeleteBuildsInput`](struct.BatchDeleteBuildsInput.html).
+#[derive(Default, Debug)]
+pub struct BatchDeleteBuildsInputBuilder {
+    ids: Option<Vec<String>>,
+}
+
+impl BatchDeleteBuildsInputBuilder {
+    /// Sets the [`ids`](struct.BatchDeleteBuildsInput.html#structfield.ids) member.
+    pub fn ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDeleteBuildsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchDeleteBuildsInput, ::rusoto_core::BuildError> {
+        Ok(BatchDeleteBuildsInput {
+            ids: self.ids.ok_or(::rusoto_core::BuildError::missing("ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDeleteBuildsOutput {
@@ -50,6 +375,34 @@ pub struct BatchGetBuildsInput {
     pub ids: Vec<String>,
 }
 
+impl BatchGetBuildsInput {
+    /// Returns a builder for `BatchGetBuildsInput`, checking its required members when built.
+    pub fn builder() -> BatchGetBuildsInputBuilder {
+        BatchGetBuildsInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetBuildsInput`](struct.BatchGetBuildsInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetBuildsInputBuilder {
+    ids: Option<Vec<String>>,
+}
+
+impl BatchGetBuildsInputBuilder {
+    /// Sets the [`ids`](struct.BatchGetBuildsInput.html#structfield.ids) member.
+    pub fn ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetBuildsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchGetBuildsInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetBuildsInput {
+            ids: self.ids.ok_or(::rusoto_core::BuildError::missing("ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetBuildsOutput {
@@ -70,6 +423,36 @@ pub struct BatchGetProjectsInput {
     pub names: Vec<String>,
 }
 
+impl BatchGetProjectsInput {
+    /// Returns a builder for `BatchGetProjectsInput`, checking its required members when built.
+    pub fn builder() -> BatchGetProjectsInputBuilder {
+        BatchGetProjectsInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetProjectsInput`](struct.BatchGetProjectsInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetProjectsInputBuilder {
+    names: Option<Vec<String>>,
+}
+
+impl BatchGetProjectsInputBuilder {
+    /// Sets the [`names`](struct.BatchGetProjectsInput.html#structfield.names) member.
+    pub fn names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.names = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetProjectsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchGetProjectsInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetProjectsInput {
+            names: self
+                .names
+                .ok_or(::rusoto_core::BuildError::missing("names"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetProjectsOutput {
@@ -102,7 +485,7 @@ pub struct Build {
     /// <p><p>The current status of the build. Valid values include:</p> <ul> <li> <p> <code>FAILED</code>: The build failed.</p> </li> <li> <p> <code>FAULT</code>: The build faulted.</p> </li> <li> <p> <code>IN<em>PROGRESS</code>: The build is still in progress.</p> </li> <li> <p> <code>STOPPED</code>: The build stopped.</p> </li> <li> <p> <code>SUCCEEDED</code>: The build succeeded.</p> </li> <li> <p> <code>TIMED</em>OUT</code>: The build timed out.</p> </li> </ul></p>
     #[serde(rename = "buildStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub build_status: Option<String>,
+    pub build_status: Option<StatusType>,
     /// <p>Information about the cache for the build.</p>
     #[serde(rename = "cache")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -117,8 +500,9 @@ pub struct Build {
     pub encryption_key: Option<String>,
     /// <p>When the build process ended, expressed in Unix time format.</p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>Information about the build environment for this build.</p>
     #[serde(rename = "environment")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -181,8 +565,9 @@ pub struct Build {
     pub source_version: Option<String>,
     /// <p>When the build process started, expressed in Unix time format.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>How long, in minutes, for AWS CodeBuild to wait before timing out this build if it does not get marked as completed.</p>
     #[serde(rename = "timeoutInMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -251,24 +636,286 @@ pub struct BuildPhase {
     pub duration_in_seconds: Option<i64>,
     /// <p>When the build phase ended, expressed in Unix time format.</p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p><p>The current status of the build phase. Valid values include:</p> <ul> <li> <p> <code>FAILED</code>: The build phase failed.</p> </li> <li> <p> <code>FAULT</code>: The build phase faulted.</p> </li> <li> <p> <code>IN<em>PROGRESS</code>: The build phase is still in progress.</p> </li> <li> <p> <code>QUEUED</code>: The build has been submitted and is queued behind other submitted builds.</p> </li> <li> <p> <code>STOPPED</code>: The build phase stopped.</p> </li> <li> <p> <code>SUCCEEDED</code>: The build phase succeeded.</p> </li> <li> <p> <code>TIMED</em>OUT</code>: The build phase timed out.</p> </li> </ul></p>
     #[serde(rename = "phaseStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub phase_status: Option<String>,
+    pub phase_status: Option<StatusType>,
     /// <p><p>The name of the build phase. Valid values include:</p> <ul> <li> <p> <code>BUILD</code>: Core build activities typically occur in this build phase.</p> </li> <li> <p> <code>COMPLETED</code>: The build has been completed.</p> </li> <li> <p> <code>DOWNLOAD<em>SOURCE</code>: Source code is being downloaded in this build phase.</p> </li> <li> <p> <code>FINALIZING</code>: The build process is completing in this build phase.</p> </li> <li> <p> <code>INSTALL</code>: Installation activities typically occur in this build phase.</p> </li> <li> <p> <code>POST</em>BUILD</code>: Post-build activities typically occur in this build phase.</p> </li> <li> <p> <code>PRE<em>BUILD</code>: Pre-build activities typically occur in this build phase.</p> </li> <li> <p> <code>PROVISIONING</code>: The build environment is being set up.</p> </li> <li> <p> <code>QUEUED</code>: The build has been submitted and is queued behind other submitted builds.</p> </li> <li> <p> <code>SUBMITTED</code>: The build has been submitted.</p> </li> <li> <p> <code>UPLOAD</em>ARTIFACTS</code>: Build output artifacts are being uploaded to the output location.</p> </li> </ul></p>
     #[serde(rename = "phaseType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub phase_type: Option<String>,
+    pub phase_type: Option<BuildPhaseType>,
     /// <p>When the build phase started, expressed in Unix time format.</p>
     #[serde(rename = "startTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<EpochTimestamp>,
+}
+
+/// <p><p>The name of the build phase. Valid values include:</p> <ul> <li> <p> <code>BUILD</code>: Core build activities typically occur in this build phase.</p> </li> <li> <p> <code>COMPLETED</code>: The build has been completed.</p> </li> <li> <p> <code>DOWNLOAD<em>SOURCE</code>: Source code is being downloaded in this build phase.</p> </li> <li> <p> <code>FINALIZING</code>: The build process is completing in this build phase.</p> </li> <li> <p> <code>INSTALL</code>: Installation activities typically occur in this build phase.</p> </li> <li> <p> <code>POST</em>BUILD</code>: Post-build activities typically occur in this build phase.</p> </li> <li> <p> <code>PRE<em>BUILD</code>: Pre-build activities typically occur in this build phase.</p> </li> <li> <p> <code>PROVISIONING</code>: The build environment is being set up.</p> </li> <li> <p> <code>QUEUED</code>: The build has been submitted and is queued behind other submitted builds.</p> </li> <li> <p> <code>SUBMITTED</code>: The build has been submitted.</p> </li> <li> <p> <code>UPLOAD</em>ARTIFACTS</code>: Build output artifacts are being uploaded to the output location.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BuildPhaseType {
+    /// <p><code>SUBMITTED</code> - The build has been submitted.</p>
+    Submitted,
+    /// <p><code>QUEUED</code> - The build has been submitted and is queued behind other submitted builds.</p>
+    Queued,
+    /// <p><code>PROVISIONING</code> - The build environment is being set up.</p>
+    Provisioning,
+    /// <p><code>DOWNLOAD_SOURCE</code></p>
+    DownloadSource,
+    /// <p><code>INSTALL</code> - Installation activities typically occur in this build phase.</p>
+    Install,
+    /// <p><code>PRE_BUILD</code></p>
+    PreBuild,
+    /// <p><code>BUILD</code> - Core build activities typically occur in this build phase.</p>
+    Build,
+    /// <p><code>POST_BUILD</code></p>
+    PostBuild,
+    /// <p><code>UPLOAD_ARTIFACTS</code></p>
+    UploadArtifacts,
+    /// <p><code>FINALIZING</code> - The build process is completing in this build phase.</p>
+    Finalizing,
+    /// <p><code>COMPLETED</code> - The build has been completed.</p>
+    Completed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BuildPhaseType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BuildPhaseType::Submitted => "SUBMITTED",
+            BuildPhaseType::Queued => "QUEUED",
+            BuildPhaseType::Provisioning => "PROVISIONING",
+            BuildPhaseType::DownloadSource => "DOWNLOAD_SOURCE",
+            BuildPhaseType::Install => "INSTALL",
+            BuildPhaseType::PreBuild => "PRE_BUILD",
+            BuildPhaseType::Build => "BUILD",
+            BuildPhaseType::PostBuild => "POST_BUILD",
+            BuildPhaseType::UploadArtifacts => "UPLOAD_ARTIFACTS",
+            BuildPhaseType::Finalizing => "FINALIZING",
+            BuildPhaseType::Completed => "COMPLETED",
+            BuildPhaseType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BuildPhaseType {
+    fn from(value: &'a str) -> BuildPhaseType {
+        match value {
+            "SUBMITTED" => BuildPhaseType::Submitted,
+            "QUEUED" => BuildPhaseType::Queued,
+            "PROVISIONING" => BuildPhaseType::Provisioning,
+            "DOWNLOAD_SOURCE" => BuildPhaseType::DownloadSource,
+            "INSTALL" => BuildPhaseType::Install,
+            "PRE_BUILD" => BuildPhaseType::PreBuild,
+            "BUILD" => BuildPhaseType::Build,
+            "POST_BUILD" => BuildPhaseType::PostBuild,
+            "UPLOAD_ARTIFACTS" => BuildPhaseType::UploadArtifacts,
+            "FINALIZING" => BuildPhaseType::Finalizing,
+            "COMPLETED" => BuildPhaseType::Completed,
+            _ => BuildPhaseType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BuildPhaseType {
+    fn from(value: String) -> BuildPhaseType {
+        BuildPhaseType::from(value.as_str())
+    }
+}
+
+impl From<BuildPhaseType> for String {
+    fn from(value: BuildPhaseType) -> String {
+        match value {
+            BuildPhaseType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BuildPhaseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BuildPhaseType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BuildPhaseType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BuildPhaseType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BuildPhaseType::from(value))
+    }
+}
+
+/// <p><p> If you use a <code>LOCAL</code> cache, the local cache mode. You can use one or more local cache modes at the same time. </p> <ul> <li> <p> <code>LOCAL<em>SOURCE</em>CACHE</code> mode caches Git metadata for primary and secondary sources. After the cache is created, subsequent builds pull only the change between commits. This mode is a good choice for projects with a clean working directory and a source that is a large Git repository. If you choose this option and your project does not use a Git repository (GitHub, GitHub Enterprise, or Bitbucket), the option is ignored. </p> </li> <li> <p> <code>LOCAL<em>DOCKER</em>LAYER<em>CACHE</code> mode caches existing Docker layers. This mode is a good choice for projects that build or pull large Docker images. It can prevent the performance issues caused by pulling large Docker images down from the network. </p> <note> <ul> <li> <p> You can use a Docker layer cache in the Linux environment only. </p> </li> <li> <p> The <code>privileged</code> flag must be set so that your project has the required Docker permissions. </p> </li> <li> <p> You should consider the security implications before you use a Docker layer cache. </p> </li> </ul> </note> </li> </ul> <ul> <li> <p> <code>LOCAL</em>CUSTOM_CACHE</code> mode caches directories you specify in the buildspec file. This mode is a good choice if your build scenario is not suited to one of the other three local cache modes. If you use a custom cache: </p> <ul> <li> <p> Only directories can be specified for caching. You cannot specify individual files. </p> </li> <li> <p> Symlinks are used to reference cached directories. </p> </li> <li> <p> Cached directories are linked to your build before it downloads its project sources. Cached items are overriden if a source item has the same name. Directories are specified using cache paths in the buildspec file. </p> </li> </ul> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CacheMode {
+    /// <p><code>LOCAL_DOCKER_LAYER_CACHE</code></p>
+    LocalDockerLayerCache,
+    /// <p><code>LOCAL_SOURCE_CACHE</code></p>
+    LocalSourceCache,
+    /// <p><code>LOCAL_CUSTOM_CACHE</code></p>
+    LocalCustomCache,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CacheMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CacheMode::LocalDockerLayerCache => "LOCAL_DOCKER_LAYER_CACHE",
+            CacheMode::LocalSourceCache => "LOCAL_SOURCE_CACHE",
+            CacheMode::LocalCustomCache => "LOCAL_CUSTOM_CACHE",
+            CacheMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CacheMode {
+    fn from(value: &'a str) -> CacheMode {
+        match value {
+            "LOCAL_DOCKER_LAYER_CACHE" => CacheMode::LocalDockerLayerCache,
+            "LOCAL_SOURCE_CACHE" => CacheMode::LocalSourceCache,
+            "LOCAL_CUSTOM_CACHE" => CacheMode::LocalCustomCache,
+            _ => CacheMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CacheMode {
+    fn from(value: String) -> CacheMode {
+        CacheMode::from(value.as_str())
+    }
+}
+
+impl From<CacheMode> for String {
+    fn from(value: CacheMode) -> String {
+        match value {
+            CacheMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CacheMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CacheMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CacheMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CacheMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CacheMode::from(value))
+    }
+}
+
+/// <p><p>The type of cache used by the build project. Valid values include:</p> <ul> <li> <p> <code>NO_CACHE</code>: The build project does not use any cache.</p> </li> <li> <p> <code>S3</code>: The build project reads and writes from and to S3.</p> </li> <li> <p> <code>LOCAL</code>: The build project stores a cache locally on a build host that is only available to that build host.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CacheType {
+    /// <p><code>NO_CACHE</code> - The build project does not use any cache.</p>
+    NoCache,
+    /// <p><code>S3</code> - The build project reads and writes from and to S3.</p>
+    S3,
+    /// <p><code>LOCAL</code> - The build project stores a cache locally on a build host that is only available to that build host.</p>
+    Local,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CacheType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CacheType::NoCache => "NO_CACHE",
+            CacheType::S3 => "S3",
+            CacheType::Local => "LOCAL",
+            CacheType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CacheType {
+    fn from(value: &'a str) -> CacheType {
+        match value {
+            "NO_CACHE" => CacheType::NoCache,
+            "S3" => CacheType::S3,
+            "LOCAL" => CacheType::Local,
+            _ => CacheType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CacheType {
+    fn from(value: String) -> CacheType {
+        CacheType::from(value.as_str())
+    }
+}
+
+impl From<CacheType> for String {
+    fn from(value: CacheType) -> String {
+        match value {
+            CacheType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CacheType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CacheType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CacheType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CacheType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CacheType::from(value))
+    }
 }
 
 /// <p> Information about Amazon CloudWatch Logs for a build project. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CloudWatchLogsConfig {
     /// <p> The group name of the logs in Amazon CloudWatch Logs. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/Working-with-log-groups-and-streams.html">Working with Log Groups and Log Streams</a>. </p>
     #[serde(rename = "groupName")]
@@ -276,14 +923,118 @@ pub struct CloudWatchLogsConfig {
     pub group_name: Option<String>,
     /// <p><p>The current status of the logs in Amazon CloudWatch Logs for a build project. Valid values are:</p> <ul> <li> <p> <code>ENABLED</code>: Amazon CloudWatch Logs are enabled for this build project.</p> </li> <li> <p> <code>DISABLED</code>: Amazon CloudWatch Logs are not enabled for this build project.</p> </li> </ul></p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: LogsConfigStatusType,
     /// <p> The prefix of the stream name of the Amazon CloudWatch Logs. For more information, see <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/Working-with-log-groups-and-streams.html">Working with Log Groups and Log Streams</a>. </p>
     #[serde(rename = "streamName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream_name: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p><p>Information about the compute resources the build project uses. Available values include:</p> <ul> <li> <p> <code>BUILD<em>GENERAL1</em>SMALL</code>: Use up to 3 GB memory and 2 vCPUs for builds.</p> </li> <li> <p> <code>BUILD<em>GENERAL1</em>MEDIUM</code>: Use up to 7 GB memory and 4 vCPUs for builds.</p> </li> <li> <p> <code>BUILD<em>GENERAL1</em>LARGE</code>: Use up to 15 GB memory and 8 vCPUs for builds.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComputeType {
+    /// <p><code>BUILD_GENERAL1_SMALL</code></p>
+    BuildGeneral1Small,
+    /// <p><code>BUILD_GENERAL1_MEDIUM</code></p>
+    BuildGeneral1Medium,
+    /// <p><code>BUILD_GENERAL1_LARGE</code></p>
+    BuildGeneral1Large,
+    /// <p><code>BUILD_GENERAL1_XLARGE</code></p>
+    BuildGeneral1Xlarge,
+    /// <p><code>BUILD_GENERAL1_2XLARGE</code></p>
+    BuildGeneral12xlarge,
+    /// <p><code>BUILD_LAMBDA_1GB</code></p>
+    BuildLambda1gb,
+    /// <p><code>BUILD_LAMBDA_2GB</code></p>
+    BuildLambda2gb,
+    /// <p><code>BUILD_LAMBDA_4GB</code></p>
+    BuildLambda4gb,
+    /// <p><code>BUILD_LAMBDA_8GB</code></p>
+    BuildLambda8gb,
+    /// <p><code>BUILD_LAMBDA_10GB</code></p>
+    BuildLambda10gb,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComputeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComputeType::BuildGeneral1Small => "BUILD_GENERAL1_SMALL",
+            ComputeType::BuildGeneral1Medium => "BUILD_GENERAL1_MEDIUM",
+            ComputeType::BuildGeneral1Large => "BUILD_GENERAL1_LARGE",
+            ComputeType::BuildGeneral1Xlarge => "BUILD_GENERAL1_XLARGE",
+            ComputeType::BuildGeneral12xlarge => "BUILD_GENERAL1_2XLARGE",
+            ComputeType::BuildLambda1gb => "BUILD_LAMBDA_1GB",
+            ComputeType::BuildLambda2gb => "BUILD_LAMBDA_2GB",
+            ComputeType::BuildLambda4gb => "BUILD_LAMBDA_4GB",
+            ComputeType::BuildLambda8gb => "BUILD_LAMBDA_8GB",
+            ComputeType::BuildLambda10gb => "BUILD_LAMBDA_10GB",
+            ComputeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComputeType {
+    fn from(value: &'a str) -> ComputeType {
+        match value {
+            "BUILD_GENERAL1_SMALL" => ComputeType::BuildGeneral1Small,
+            "BUILD_GENERAL1_MEDIUM" => ComputeType::BuildGeneral1Medium,
+            "BUILD_GENERAL1_LARGE" => ComputeType::BuildGeneral1Large,
+            "BUILD_GENERAL1_XLARGE" => ComputeType::BuildGeneral1Xlarge,
+            "BUILD_GENERAL1_2XLARGE" => ComputeType::BuildGeneral12xlarge,
+            "BUILD_LAMBDA_1GB" => ComputeType::BuildLambda1gb,
+            "BUILD_LAMBDA_2GB" => ComputeType::BuildLambda2gb,
+            "BUILD_LAMBDA_4GB" => ComputeType::BuildLambda4gb,
+            "BUILD_LAMBDA_8GB" => ComputeType::BuildLambda8gb,
+            "BUILD_LAMBDA_10GB" => ComputeType::BuildLambda10gb,
+            _ => ComputeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComputeType {
+    fn from(value: String) -> ComputeType {
+        ComputeType::from(value.as_str())
+    }
+}
+
+impl From<ComputeType> for String {
+    fn from(value: ComputeType) -> String {
+        match value {
+            ComputeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComputeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ComputeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComputeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ComputeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ComputeType::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateProjectInput {
     /// <p>Information about the build output artifacts for the build project.</p>
     #[serde(rename = "artifacts")]
@@ -354,6 +1105,183 @@ pub struct CreateProjectInput {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl CreateProjectInput {
+    /// Returns a builder for `CreateProjectInput`, checking its required members when built.
+    pub fn builder() -> CreateProjectInputBuilder {
+        CreateProjectInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateProjectInput`](struct.CreateProjectInput.html).
+#[derive(Default, Debug)]
+pub struct CreateProjectInputBuilder {
+    artifacts: Option<ProjectArtifacts>,
+    badge_enabled: Option<bool>,
+    cache: Option<ProjectCache>,
+    description: Option<String>,
+    encryption_key: Option<String>,
+    environment: Option<ProjectEnvironment>,
+    logs_config: Option<LogsConfig>,
+    name: Option<String>,
+    queued_timeout_in_minutes: Option<i64>,
+    secondary_artifacts: Option<Vec<ProjectArtifacts>>,
+    secondary_source_versions: Option<Vec<ProjectSourceVersion>>,
+    secondary_sources: Option<Vec<ProjectSource>>,
+    service_role: Option<String>,
+    source: Option<ProjectSource>,
+    source_version: Option<String>,
+    tags: Option<Vec<Tag>>,
+    timeout_in_minutes: Option<i64>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl CreateProjectInputBuilder {
+    /// Sets the [`artifacts`](struct.CreateProjectInput.html#structfield.artifacts) member.
+    pub fn artifacts<T: Into<ProjectArtifacts>>(mut self, value: T) -> Self {
+        self.artifacts = Some(value.into());
+        self
+    }
+
+    /// Sets the [`badge_enabled`](struct.CreateProjectInput.html#structfield.badge_enabled) member.
+    pub fn badge_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.badge_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache`](struct.CreateProjectInput.html#structfield.cache) member.
+    pub fn cache<T: Into<ProjectCache>>(mut self, value: T) -> Self {
+        self.cache = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateProjectInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`encryption_key`](struct.CreateProjectInput.html#structfield.encryption_key) member.
+    pub fn encryption_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.encryption_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment`](struct.CreateProjectInput.html#structfield.environment) member.
+    pub fn environment<T: Into<ProjectEnvironment>>(mut self, value: T) -> Self {
+        self.environment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logs_config`](struct.CreateProjectInput.html#structfield.logs_config) member.
+    pub fn logs_config<T: Into<LogsConfig>>(mut self, value: T) -> Self {
+        self.logs_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateProjectInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queued_timeout_in_minutes`](struct.CreateProjectInput.html#structfield.queued_timeout_in_minutes) member.
+    pub fn queued_timeout_in_minutes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.queued_timeout_in_minutes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secondary_artifacts`](struct.CreateProjectInput.html#structfield.secondary_artifacts) member.
+    pub fn secondary_artifacts<T: Into<Vec<ProjectArtifacts>>>(mut self, value: T) -> Self {
+        self.secondary_artifacts = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secondary_source_versions`](struct.CreateProjectInput.html#structfield.secondary_source_versions) member.
+    pub fn secondary_source_versions<T: Into<Vec<ProjectSourceVersion>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.secondary_source_versions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secondary_sources`](struct.CreateProjectInput.html#structfield.secondary_sources) member.
+    pub fn secondary_sources<T: Into<Vec<ProjectSource>>>(mut self, value: T) -> Self {
+        self.secondary_sources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role`](struct.CreateProjectInput.html#structfield.service_role) member.
+    pub fn service_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.CreateProjectInput.html#structfield.source) member.
+    pub fn source<T: Into<ProjectSource>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_version`](struct.CreateProjectInput.html#structfield.source_version) member.
+    pub fn source_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateProjectInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout_in_minutes`](struct.CreateProjectInput.html#structfield.timeout_in_minutes) member.
+    pub fn timeout_in_minutes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout_in_minutes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.CreateProjectInput.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateProjectInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateProjectInput, ::rusoto_core::BuildError> {
+        Ok(CreateProjectInput {
+            artifacts: self
+                .artifacts
+                .ok_or(::rusoto_core::BuildError::missing("artifacts"))?,
+            badge_enabled: self.badge_enabled,
+            cache: self.cache,
+            description: self.description,
+            encryption_key: self.encryption_key,
+            environment: self
+                .environment
+                .ok_or(::rusoto_core::BuildError::missing("environment"))?,
+            logs_config: self.logs_config,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            queued_timeout_in_minutes: self.queued_timeout_in_minutes,
+            secondary_artifacts: self.secondary_artifacts,
+            secondary_source_versions: self.secondary_source_versions,
+            secondary_sources: self.secondary_sources,
+            service_role: self
+                .service_role
+                .ok_or(::rusoto_core::BuildError::missing("service_role"))?,
+            source: self
+                .source
+                .ok_or(::rusoto_core::BuildError::missing("source"))?,
+            source_version: self.source_version,
+            tags: self.tags,
+            timeout_in_minutes: self.timeout_in_minutes,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateProjectOutput {
@@ -378,6 +1306,52 @@ pub struct CreateWebhookInput {
     pub project_name: String,
 }
 
+impl CreateWebhookInput {
+    /// Returns a builder for `CreateWebhookInput`, checking its required members when built.
+    pub fn builder() -> CreateWebhookInputBuilder {
+        CreateWebhookInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateWebhookInput`](struct.CreateWebhookInput.html).
+#[derive(Default, Debug)]
+pub struct CreateWebhookInputBuilder {
+    branch_filter: Option<String>,
+    filter_groups: Option<Vec<Vec<WebhookFilter>>>,
+    project_name: Option<String>,
+}
+
+impl CreateWebhookInputBuilder {
+    /// Sets the [`branch_filter`](struct.CreateWebhookInput.html#structfield.branch_filter) member.
+    pub fn branch_filter<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_groups`](struct.CreateWebhookInput.html#structfield.filter_groups) member.
+    pub fn filter_groups<T: Into<Vec<Vec<WebhookFilter>>>>(mut self, value: T) -> Self {
+        self.filter_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.CreateWebhookInput.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateWebhookInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateWebhookInput, ::rusoto_core::BuildError> {
+        Ok(CreateWebhookInput {
+            branch_filter: self.branch_filter,
+            filter_groups: self.filter_groups,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateWebhookOutput {
@@ -387,6 +1361,74 @@ pub struct CreateWebhookOutput {
     pub webhook: Option<Webhook>,
 }
 
+/// <p> The service that created the credentials to access a private Docker registry. The valid value, SECRETS_MANAGER, is for AWS Secrets Manager. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CredentialProviderType {
+    /// <p><code>SECRETS_MANAGER</code></p>
+    SecretsManager,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CredentialProviderType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CredentialProviderType::SecretsManager => "SECRETS_MANAGER",
+            CredentialProviderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CredentialProviderType {
+    fn from(value: &'a str) -> CredentialProviderType {
+        match value {
+            "SECRETS_MANAGER" => CredentialProviderType::SecretsManager,
+            _ => CredentialProviderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CredentialProviderType {
+    fn from(value: String) -> CredentialProviderType {
+        CredentialProviderType::from(value.as_str())
+    }
+}
+
+impl From<CredentialProviderType> for String {
+    fn from(value: CredentialProviderType) -> String {
+        match value {
+            CredentialProviderType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CredentialProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CredentialProviderType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CredentialProviderType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CredentialProviderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CredentialProviderType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteProjectInput {
     /// <p>The name of the build project.</p>
@@ -394,6 +1436,36 @@ pub struct DeleteProjectInput {
     pub name: String,
 }
 
+impl DeleteProjectInput {
+    /// Returns a builder for `DeleteProjectInput`, checking its required members when built.
+    pub fn builder() -> DeleteProjectInputBuilder {
+        DeleteProjectInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteProjectInput`](struct.DeleteProjectInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteProjectInputBuilder {
+    name: Option<String>,
+}
+
+impl DeleteProjectInputBuilder {
+    /// Sets the [`name`](struct.DeleteProjectInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteProjectInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteProjectInput, ::rusoto_core::BuildError> {
+        Ok(DeleteProjectInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteProjectOutput {}
@@ -405,6 +1477,36 @@ pub struct DeleteSourceCredentialsInput {
     pub arn: String,
 }
 
+impl DeleteSourceCredentialsInput {
+    /// Returns a builder for `DeleteSourceCredentialsInput`, checking its required members when built.
+    pub fn builder() -> DeleteSourceCredentialsInputBuilder {
+        DeleteSourceCredentialsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSourceCredentialsInput`](struct.DeleteSourceCredentialsInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteSourceCredentialsInputBuilder {
+    arn: Option<String>,
+}
+
+impl DeleteSourceCredentialsInputBuilder {
+    /// Sets the [`arn`](struct.DeleteSourceCredentialsInput.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSourceCredentialsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSourceCredentialsInput, ::rusoto_core::BuildError> {
+        Ok(DeleteSourceCredentialsInput {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSourceCredentialsOutput {
@@ -421,6 +1523,36 @@ pub struct DeleteWebhookInput {
     pub project_name: String,
 }
 
+impl DeleteWebhookInput {
+    /// Returns a builder for `DeleteWebhookInput`, checking its required members when built.
+    pub fn builder() -> DeleteWebhookInputBuilder {
+        DeleteWebhookInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteWebhookInput`](struct.DeleteWebhookInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteWebhookInputBuilder {
+    project_name: Option<String>,
+}
+
+impl DeleteWebhookInputBuilder {
+    /// Sets the [`project_name`](struct.DeleteWebhookInput.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteWebhookInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteWebhookInput, ::rusoto_core::BuildError> {
+        Ok(DeleteWebhookInput {
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteWebhookOutput {}
@@ -454,7 +1586,7 @@ pub struct EnvironmentLanguage {
     /// <p>The programming language for the Docker images.</p>
     #[serde(rename = "language")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
+    pub language: Option<LanguageType>,
 }
 
 /// <p>A set of Docker images that are related by platform and are managed by AWS CodeBuild.</p>
@@ -468,7 +1600,99 @@ pub struct EnvironmentPlatform {
     /// <p>The platform's name.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<PlatformType>,
+}
+
+/// <p>The type of build environment to use for related builds.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnvironmentType {
+    /// <p><code>WINDOWS_CONTAINER</code></p>
+    WindowsContainer,
+    /// <p><code>LINUX_CONTAINER</code></p>
+    LinuxContainer,
+    /// <p><code>LINUX_GPU_CONTAINER</code></p>
+    LinuxGpuContainer,
+    /// <p><code>ARM_CONTAINER</code></p>
+    ArmContainer,
+    /// <p><code>WINDOWS_SERVER_2019_CONTAINER</code></p>
+    WindowsServer2019Container,
+    /// <p><code>LINUX_LAMBDA_CONTAINER</code></p>
+    LinuxLambdaContainer,
+    /// <p><code>ARM_LAMBDA_CONTAINER</code></p>
+    ArmLambdaContainer,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnvironmentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentType::WindowsContainer => "WINDOWS_CONTAINER",
+            EnvironmentType::LinuxContainer => "LINUX_CONTAINER",
+            EnvironmentType::LinuxGpuContainer => "LINUX_GPU_CONTAINER",
+            EnvironmentType::ArmContainer => "ARM_CONTAINER",
+            EnvironmentType::WindowsServer2019Container => "WINDOWS_SERVER_2019_CONTAINER",
+            EnvironmentType::LinuxLambdaContainer => "LINUX_LAMBDA_CONTAINER",
+            EnvironmentType::ArmLambdaContainer => "ARM_LAMBDA_CONTAINER",
+            EnvironmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentType {
+    fn from(value: &'a str) -> EnvironmentType {
+        match value {
+            "WINDOWS_CONTAINER" => EnvironmentType::WindowsContainer,
+            "LINUX_CONTAINER" => EnvironmentType::LinuxContainer,
+            "LINUX_GPU_CONTAINER" => EnvironmentType::LinuxGpuContainer,
+            "ARM_CONTAINER" => EnvironmentType::ArmContainer,
+            "WINDOWS_SERVER_2019_CONTAINER" => EnvironmentType::WindowsServer2019Container,
+            "LINUX_LAMBDA_CONTAINER" => EnvironmentType::LinuxLambdaContainer,
+            "ARM_LAMBDA_CONTAINER" => EnvironmentType::ArmLambdaContainer,
+            _ => EnvironmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentType {
+    fn from(value: String) -> EnvironmentType {
+        EnvironmentType::from(value.as_str())
+    }
+}
+
+impl From<EnvironmentType> for String {
+    fn from(value: EnvironmentType) -> String {
+        match value {
+            EnvironmentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnvironmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EnvironmentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnvironmentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EnvironmentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EnvironmentType::from(value))
+    }
 }
 
 /// <p>Information about an environment variable for a build project or a build.</p>
@@ -480,12 +1704,88 @@ pub struct EnvironmentVariable {
     /// <p><p>The type of environment variable. Valid values include:</p> <ul> <li> <p> <code>PARAMETER_STORE</code>: An environment variable stored in Amazon EC2 Systems Manager Parameter Store.</p> </li> <li> <p> <code>PLAINTEXT</code>: An environment variable in plaintext format.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EnvironmentVariableType>,
     /// <p><p>The value of the environment variable.</p> <important> <p>We strongly discourage the use of environment variables to store sensitive values, especially AWS secret key IDs and secret access keys. Environment variables can be displayed in plain text using the AWS CodeBuild console and the AWS Command Line Interface (AWS CLI).</p> </important></p>
     #[serde(rename = "value")]
     pub value: String,
 }
 
+/// <p><p>The type of environment variable. Valid values include:</p> <ul> <li> <p> <code>PARAMETER_STORE</code>: An environment variable stored in Amazon EC2 Systems Manager Parameter Store.</p> </li> <li> <p> <code>PLAINTEXT</code>: An environment variable in plaintext format.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnvironmentVariableType {
+    /// <p><code>PLAINTEXT</code> - An environment variable in plaintext format.</p>
+    Plaintext,
+    /// <p><code>PARAMETER_STORE</code> - An environment variable stored in Amazon EC2 Systems Manager Parameter Store.</p>
+    ParameterStore,
+    /// <p><code>SECRETS_MANAGER</code></p>
+    SecretsManager,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnvironmentVariableType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentVariableType::Plaintext => "PLAINTEXT",
+            EnvironmentVariableType::ParameterStore => "PARAMETER_STORE",
+            EnvironmentVariableType::SecretsManager => "SECRETS_MANAGER",
+            EnvironmentVariableType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentVariableType {
+    fn from(value: &'a str) -> EnvironmentVariableType {
+        match value {
+            "PLAINTEXT" => EnvironmentVariableType::Plaintext,
+            "PARAMETER_STORE" => EnvironmentVariableType::ParameterStore,
+            "SECRETS_MANAGER" => EnvironmentVariableType::SecretsManager,
+            _ => EnvironmentVariableType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentVariableType {
+    fn from(value: String) -> EnvironmentVariableType {
+        EnvironmentVariableType::from(value.as_str())
+    }
+}
+
+impl From<EnvironmentVariableType> for String {
+    fn from(value: EnvironmentVariableType) -> String {
+        match value {
+            EnvironmentVariableType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnvironmentVariableType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EnvironmentVariableType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnvironmentVariableType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EnvironmentVariableType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EnvironmentVariableType::from(value))
+    }
+}
+
 /// <p> Information about the Git submodules configuration for an AWS CodeBuild build project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GitSubmodulesConfig {
@@ -494,14 +1794,86 @@ pub struct GitSubmodulesConfig {
     pub fetch_submodules: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p> The type of credentials AWS CodeBuild uses to pull images in your build. There are two valid values: </p> <ul> <li> <p> <code>CODEBUILD</code> specifies that AWS CodeBuild uses its own credentials. This requires that you modify your ECR repository policy to trust AWS CodeBuild's service principal. </p> </li> <li> <p> <code>SERVICE_ROLE</code> specifies that AWS CodeBuild uses your build project's service role. </p> </li> </ul> <p> When you use a cross-account or private registry image, you must use SERVICE_ROLE credentials. When you use an AWS CodeBuild curated image, you must use CODEBUILD credentials. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImagePullCredentialsType {
+    /// <p><code>CODEBUILD</code></p>
+    Codebuild,
+    /// <p><code>SERVICE_ROLE</code></p>
+    ServiceRole,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ImagePullCredentialsType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImagePullCredentialsType::Codebuild => "CODEBUILD",
+            ImagePullCredentialsType::ServiceRole => "SERVICE_ROLE",
+            ImagePullCredentialsType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ImagePullCredentialsType {
+    fn from(value: &'a str) -> ImagePullCredentialsType {
+        match value {
+            "CODEBUILD" => ImagePullCredentialsType::Codebuild,
+            "SERVICE_ROLE" => ImagePullCredentialsType::ServiceRole,
+            _ => ImagePullCredentialsType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImagePullCredentialsType {
+    fn from(value: String) -> ImagePullCredentialsType {
+        ImagePullCredentialsType::from(value.as_str())
+    }
+}
+
+impl From<ImagePullCredentialsType> for String {
+    fn from(value: ImagePullCredentialsType) -> String {
+        match value {
+            ImagePullCredentialsType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ImagePullCredentialsType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ImagePullCredentialsType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImagePullCredentialsType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ImagePullCredentialsType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ImagePullCredentialsType::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ImportSourceCredentialsInput {
     /// <p> The type of authentication used to connect to a GitHub, GitHub Enterprise, or Bitbucket repository. An OAUTH connection is not supported by the API and must be created using the AWS CodeBuild console. </p>
     #[serde(rename = "authType")]
-    pub auth_type: String,
+    pub auth_type: AuthType,
     /// <p> The source provider used for this project. </p>
     #[serde(rename = "serverType")]
-    pub server_type: String,
+    pub server_type: ServerType,
     /// <p> Set to <code>false</code> to prevent overwriting the repository source credentials. Set to <code>true</code> to overwrite the repository source credentials. The default value is <code>true</code>. </p>
     #[serde(rename = "shouldOverwrite")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -515,6 +1887,74 @@ pub struct ImportSourceCredentialsInput {
     pub username: Option<String>,
 }
 
+impl ImportSourceCredentialsInput {
+    /// Returns a builder for `ImportSourceCredentialsInput`, checking its required members when built.
+    pub fn builder() -> ImportSourceCredentialsInputBuilder {
+        ImportSourceCredentialsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ImportSourceCredentialsInput`](struct.ImportSourceCredentialsInput.html).
+#[derive(Default, Debug)]
+pub struct ImportSourceCredentialsInputBuilder {
+    auth_type: Option<AuthType>,
+    server_type: Option<ServerType>,
+    should_overwrite: Option<bool>,
+    token: Option<String>,
+    username: Option<String>,
+}
+
+impl ImportSourceCredentialsInputBuilder {
+    /// Sets the [`auth_type`](struct.ImportSourceCredentialsInput.html#structfield.auth_type) member.
+    pub fn auth_type<T: Into<AuthType>>(mut self, value: T) -> Self {
+        self.auth_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_type`](struct.ImportSourceCredentialsInput.html#structfield.server_type) member.
+    pub fn server_type<T: Into<ServerType>>(mut self, value: T) -> Self {
+        self.server_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`should_overwrite`](struct.ImportSourceCredentialsInput.html#structfield.should_overwrite) member.
+    pub fn should_overwrite<T: Into<bool>>(mut self, value: T) -> Self {
+        self.should_overwrite = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token`](struct.ImportSourceCredentialsInput.html#structfield.token) member.
+    pub fn token<T: Into<String>>(mut self, value: T) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.ImportSourceCredentialsInput.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportSourceCredentialsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ImportSourceCredentialsInput, ::rusoto_core::BuildError> {
+        Ok(ImportSourceCredentialsInput {
+            auth_type: self
+                .auth_type
+                .ok_or(::rusoto_core::BuildError::missing("auth_type"))?,
+            server_type: self
+                .server_type
+                .ok_or(::rusoto_core::BuildError::missing("server_type"))?,
+            should_overwrite: self.should_overwrite,
+            token: self
+                .token
+                .ok_or(::rusoto_core::BuildError::missing("token"))?,
+            username: self.username,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ImportSourceCredentialsOutput {
@@ -531,10 +1971,146 @@ pub struct InvalidateProjectCacheInput {
     pub project_name: String,
 }
 
+impl InvalidateProjectCacheInput {
+    /// Returns a builder for `InvalidateProjectCacheInput`, checking its required members when built.
+    pub fn builder() -> InvalidateProjectCacheInputBuilder {
+        InvalidateProjectCacheInputBuilder::default()
+    }
+}
+
+/// Builds a [`InvalidateProjectCacheInput`](struct.InvalidateProjectCacheInput.html).
+#[derive(Default, Debug)]
+pub struct InvalidateProjectCacheInputBuilder {
+    project_name: Option<String>,
+}
+
+impl InvalidateProjectCacheInputBuilder {
+    /// Sets the [`project_name`](struct.InvalidateProjectCacheInput.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `InvalidateProjectCacheInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<InvalidateProjectCacheInput, ::rusoto_core::BuildError> {
+        Ok(InvalidateProjectCacheInput {
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct InvalidateProjectCacheOutput {}
 
+/// <p>The programming language for the Docker images.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LanguageType {
+    /// <p><code>JAVA</code></p>
+    Java,
+    /// <p><code>PYTHON</code></p>
+    Python,
+    /// <p><code>NODE_JS</code></p>
+    NodeJs,
+    /// <p><code>RUBY</code></p>
+    Ruby,
+    /// <p><code>GOLANG</code></p>
+    Golang,
+    /// <p><code>DOCKER</code></p>
+    Docker,
+    /// <p><code>ANDROID</code></p>
+    Android,
+    /// <p><code>DOTNET</code></p>
+    Dotnet,
+    /// <p><code>BASE</code></p>
+    Base,
+    /// <p><code>PHP</code></p>
+    Php,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LanguageType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LanguageType::Java => "JAVA",
+            LanguageType::Python => "PYTHON",
+            LanguageType::NodeJs => "NODE_JS",
+            LanguageType::Ruby => "RUBY",
+            LanguageType::Golang => "GOLANG",
+            LanguageType::Docker => "DOCKER",
+            LanguageType::Android => "ANDROID",
+            LanguageType::Dotnet => "DOTNET",
+            LanguageType::Base => "BASE",
+            LanguageType::Php => "PHP",
+            LanguageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LanguageType {
+    fn from(value: &'a str) -> LanguageType {
+        match value {
+            "JAVA" => LanguageType::Java,
+            "PYTHON" => LanguageType::Python,
+            "NODE_JS" => LanguageType::NodeJs,
+            "RUBY" => LanguageType::Ruby,
+            "GOLANG" => LanguageType::Golang,
+            "DOCKER" => LanguageType::Docker,
+            "ANDROID" => LanguageType::Android,
+            "DOTNET" => LanguageType::Dotnet,
+            "BASE" => LanguageType::Base,
+            "PHP" => LanguageType::Php,
+            _ => LanguageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LanguageType {
+    fn from(value: String) -> LanguageType {
+        LanguageType::from(value.as_str())
+    }
+}
+
+impl From<LanguageType> for String {
+    fn from(value: LanguageType) -> String {
+        match value {
+            LanguageType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LanguageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LanguageType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LanguageType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LanguageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LanguageType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListBuildsForProjectInput {
     /// <p>During a previous call, if there are more than 100 items in the list, only the first 100 items are returned, along with a unique string called a <i>next token</i>. To get the next batch of items in the list, call this operation again, adding the next token to the call. To get all of the items in the list, keep calling this operation with each subsequent next token that is returned, until no more next tokens are returned.</p>
@@ -547,7 +2123,55 @@ pub struct ListBuildsForProjectInput {
     /// <p><p>The order to list build IDs. Valid values include:</p> <ul> <li> <p> <code>ASCENDING</code>: List the build IDs in ascending order by build ID.</p> </li> <li> <p> <code>DESCENDING</code>: List the build IDs in descending order by build ID.</p> </li> </ul></p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrderType>,
+}
+
+impl ListBuildsForProjectInput {
+    /// Returns a builder for `ListBuildsForProjectInput`, checking its required members when built.
+    pub fn builder() -> ListBuildsForProjectInputBuilder {
+        ListBuildsForProjectInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListBuildsForProjectInput`](struct.ListBuildsForProjectInput.html).
+#[derive(Default, Debug)]
+pub struct ListBuildsForProjectInputBuilder {
+    next_token: Option<String>,
+    project_name: Option<String>,
+    sort_order: Option<SortOrderType>,
+}
+
+impl ListBuildsForProjectInputBuilder {
+    /// Sets the [`next_token`](struct.ListBuildsForProjectInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.ListBuildsForProjectInput.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_order`](struct.ListBuildsForProjectInput.html#structfield.sort_order) member.
+    pub fn sort_order<T: Into<SortOrderType>>(mut self, value: T) -> Self {
+        self.sort_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBuildsForProjectInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListBuildsForProjectInput, ::rusoto_core::BuildError> {
+        Ok(ListBuildsForProjectInput {
+            next_token: self.next_token,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+            sort_order: self.sort_order,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -572,7 +2196,43 @@ pub struct ListBuildsInput {
     /// <p><p>The order to list build IDs. Valid values include:</p> <ul> <li> <p> <code>ASCENDING</code>: List the build IDs in ascending order by build ID.</p> </li> <li> <p> <code>DESCENDING</code>: List the build IDs in descending order by build ID.</p> </li> </ul></p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrderType>,
+}
+
+impl ListBuildsInput {
+    /// Returns a builder for `ListBuildsInput`, checking its required members when built.
+    pub fn builder() -> ListBuildsInputBuilder {
+        ListBuildsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListBuildsInput`](struct.ListBuildsInput.html).
+#[derive(Default, Debug)]
+pub struct ListBuildsInputBuilder {
+    next_token: Option<String>,
+    sort_order: Option<SortOrderType>,
+}
+
+impl ListBuildsInputBuilder {
+    /// Sets the [`next_token`](struct.ListBuildsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_order`](struct.ListBuildsInput.html#structfield.sort_order) member.
+    pub fn sort_order<T: Into<SortOrderType>>(mut self, value: T) -> Self {
+        self.sort_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBuildsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListBuildsInput, ::rusoto_core::BuildError> {
+        Ok(ListBuildsInput {
+            next_token: self.next_token,
+            sort_order: self.sort_order,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -609,11 +2269,55 @@ pub struct ListProjectsInput {
     /// <p>The criterion to be used to list build project names. Valid values include:</p> <ul> <li> <p> <code>CREATED_TIME</code>: List based on when each build project was created.</p> </li> <li> <p> <code>LAST_MODIFIED_TIME</code>: List based on when information about each build project was last changed.</p> </li> <li> <p> <code>NAME</code>: List based on each build project's name.</p> </li> </ul> <p>Use <code>sortOrder</code> to specify in what order to list the build project names based on the preceding criteria.</p>
     #[serde(rename = "sortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<ProjectSortByType>,
     /// <p>The order in which to list build projects. Valid values include:</p> <ul> <li> <p> <code>ASCENDING</code>: List in ascending order.</p> </li> <li> <p> <code>DESCENDING</code>: List in descending order.</p> </li> </ul> <p>Use <code>sortBy</code> to specify the criterion to be used to list build project names.</p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrderType>,
+}
+
+impl ListProjectsInput {
+    /// Returns a builder for `ListProjectsInput`, checking its required members when built.
+    pub fn builder() -> ListProjectsInputBuilder {
+        ListProjectsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListProjectsInput`](struct.ListProjectsInput.html).
+#[derive(Default, Debug)]
+pub struct ListProjectsInputBuilder {
+    next_token: Option<String>,
+    sort_by: Option<ProjectSortByType>,
+    sort_order: Option<SortOrderType>,
+}
+
+impl ListProjectsInputBuilder {
+    /// Sets the [`next_token`](struct.ListProjectsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_by`](struct.ListProjectsInput.html#structfield.sort_by) member.
+    pub fn sort_by<T: Into<ProjectSortByType>>(mut self, value: T) -> Self {
+        self.sort_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_order`](struct.ListProjectsInput.html#structfield.sort_order) member.
+    pub fn sort_order<T: Into<SortOrderType>>(mut self, value: T) -> Self {
+        self.sort_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListProjectsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListProjectsInput, ::rusoto_core::BuildError> {
+        Ok(ListProjectsInput {
+            next_token: self.next_token,
+            sort_by: self.sort_by,
+            sort_order: self.sort_order,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -654,6 +2358,78 @@ pub struct LogsConfig {
     pub s_3_logs: Option<S3LogsConfig>,
 }
 
+/// <p><p>The current status of the logs in Amazon CloudWatch Logs for a build project. Valid values are:</p> <ul> <li> <p> <code>ENABLED</code>: Amazon CloudWatch Logs are enabled for this build project.</p> </li> <li> <p> <code>DISABLED</code>: Amazon CloudWatch Logs are not enabled for this build project.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LogsConfigStatusType {
+    /// <p><code>ENABLED</code> - Amazon CloudWatch Logs are enabled for this build project.</p>
+    Enabled,
+    /// <p><code>DISABLED</code> - Amazon CloudWatch Logs are not enabled for this build project.</p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LogsConfigStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogsConfigStatusType::Enabled => "ENABLED",
+            LogsConfigStatusType::Disabled => "DISABLED",
+            LogsConfigStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LogsConfigStatusType {
+    fn from(value: &'a str) -> LogsConfigStatusType {
+        match value {
+            "ENABLED" => LogsConfigStatusType::Enabled,
+            "DISABLED" => LogsConfigStatusType::Disabled,
+            _ => LogsConfigStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogsConfigStatusType {
+    fn from(value: String) -> LogsConfigStatusType {
+        LogsConfigStatusType::from(value.as_str())
+    }
+}
+
+impl From<LogsConfigStatusType> for String {
+    fn from(value: LogsConfigStatusType) -> String {
+        match value {
+            LogsConfigStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LogsConfigStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LogsConfigStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogsConfigStatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LogsConfigStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LogsConfigStatusType::from(value))
+    }
+}
+
 /// <p>Information about build logs in Amazon CloudWatch Logs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -712,6 +2488,86 @@ pub struct PhaseContext {
     pub status_code: Option<String>,
 }
 
+/// <p>The platform's name.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlatformType {
+    /// <p><code>DEBIAN</code></p>
+    Debian,
+    /// <p><code>AMAZON_LINUX</code></p>
+    AmazonLinux,
+    /// <p><code>UBUNTU</code></p>
+    Ubuntu,
+    /// <p><code>WINDOWS_SERVER</code></p>
+    WindowsServer,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PlatformType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlatformType::Debian => "DEBIAN",
+            PlatformType::AmazonLinux => "AMAZON_LINUX",
+            PlatformType::Ubuntu => "UBUNTU",
+            PlatformType::WindowsServer => "WINDOWS_SERVER",
+            PlatformType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PlatformType {
+    fn from(value: &'a str) -> PlatformType {
+        match value {
+            "DEBIAN" => PlatformType::Debian,
+            "AMAZON_LINUX" => PlatformType::AmazonLinux,
+            "UBUNTU" => PlatformType::Ubuntu,
+            "WINDOWS_SERVER" => PlatformType::WindowsServer,
+            _ => PlatformType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlatformType {
+    fn from(value: String) -> PlatformType {
+        PlatformType::from(value.as_str())
+    }
+}
+
+impl From<PlatformType> for String {
+    fn from(value: PlatformType) -> String {
+        match value {
+            PlatformType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PlatformType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PlatformType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlatformType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PlatformType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PlatformType::from(value))
+    }
+}
+
 /// <p>Information about a build project.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -734,8 +2590,9 @@ pub struct Project {
     pub cache: Option<ProjectCache>,
     /// <p>When the build project was created, expressed in Unix time format.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>A description that makes the build project easy to identify.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -750,8 +2607,9 @@ pub struct Project {
     pub environment: Option<ProjectEnvironment>,
     /// <p>When the build project's settings were last modified, expressed in Unix time format.</p>
     #[serde(rename = "lastModified")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<EpochTimestamp>,
     /// <p> Information about logs for the build project. A project can create logs in Amazon CloudWatch Logs, an S3 bucket, or both. </p>
     #[serde(rename = "logsConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -807,7 +2665,7 @@ pub struct Project {
 }
 
 /// <p>Information about the build output artifacts for the build project.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProjectArtifacts {
     /// <p> An identifier for this artifact definition. </p>
     #[serde(rename = "artifactIdentifier")]
@@ -828,7 +2686,7 @@ pub struct ProjectArtifacts {
     /// <p>Along with <code>path</code> and <code>name</code>, the pattern that AWS CodeBuild uses to determine the name and location to store the output artifact:</p> <ul> <li> <p>If <code>type</code> is set to <code>CODEPIPELINE</code>, AWS CodePipeline ignores this value if specified. This is because AWS CodePipeline manages its build output names instead of AWS CodeBuild.</p> </li> <li> <p>If <code>type</code> is set to <code>NO_ARTIFACTS</code>, this value is ignored if specified, because no build output is produced.</p> </li> <li> <p>If <code>type</code> is set to <code>S3</code>, valid values include:</p> <ul> <li> <p> <code>BUILD_ID</code>: Include the build ID in the location of the build output artifact.</p> </li> <li> <p> <code>NONE</code>: Do not include the build ID. This is the default if <code>namespaceType</code> is not specified.</p> </li> </ul> </li> </ul> <p>For example, if <code>path</code> is set to <code>MyArtifacts</code>, <code>namespaceType</code> is set to <code>BUILD_ID</code>, and <code>name</code> is set to <code>MyArtifact.zip</code>, the output artifact is stored in <code>MyArtifacts/<i>build-ID</i>/MyArtifact.zip</code>.</p>
     #[serde(rename = "namespaceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub namespace_type: Option<String>,
+    pub namespace_type: Option<ArtifactNamespace>,
     /// <p> If this flag is set, a name specified in the build spec file overrides the artifact name. The name specified in a build spec file is calculated at build time and uses the Shell Command Language. For example, you can append a date and time to your artifact name so that it is always unique. </p>
     #[serde(rename = "overrideArtifactName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -836,14 +2694,14 @@ pub struct ProjectArtifacts {
     /// <p><p>The type of build output artifact to create:</p> <ul> <li> <p>If <code>type</code> is set to <code>CODEPIPELINE</code>, AWS CodePipeline ignores this value if specified. This is because AWS CodePipeline manages its build output artifacts instead of AWS CodeBuild.</p> </li> <li> <p>If <code>type</code> is set to <code>NO_ARTIFACTS</code>, this value is ignored if specified, because no build output is produced.</p> </li> <li> <p>If <code>type</code> is set to <code>S3</code>, valid values include:</p> <ul> <li> <p> <code>NONE</code>: AWS CodeBuild creates in the output bucket a folder that contains the build output. This is the default if <code>packaging</code> is not specified.</p> </li> <li> <p> <code>ZIP</code>: AWS CodeBuild creates in the output bucket a ZIP file that contains the build output.</p> </li> </ul> </li> </ul></p>
     #[serde(rename = "packaging")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub packaging: Option<String>,
+    pub packaging: Option<ArtifactPackaging>,
     /// <p>Along with <code>namespaceType</code> and <code>name</code>, the pattern that AWS CodeBuild uses to name and store the output artifact:</p> <ul> <li> <p>If <code>type</code> is set to <code>CODEPIPELINE</code>, AWS CodePipeline ignores this value if specified. This is because AWS CodePipeline manages its build output names instead of AWS CodeBuild.</p> </li> <li> <p>If <code>type</code> is set to <code>NO_ARTIFACTS</code>, this value is ignored if specified, because no build output is produced.</p> </li> <li> <p>If <code>type</code> is set to <code>S3</code>, this is the path to the output artifact. If <code>path</code> is not specified, <code>path</code> is not used.</p> </li> </ul> <p>For example, if <code>path</code> is set to <code>MyArtifacts</code>, <code>namespaceType</code> is set to <code>NONE</code>, and <code>name</code> is set to <code>MyArtifact.zip</code>, the output artifact is stored in the output bucket at <code>MyArtifacts/MyArtifact.zip</code>.</p>
     #[serde(rename = "path")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
     /// <p><p>The type of build output artifact. Valid values include:</p> <ul> <li> <p> <code>CODEPIPELINE</code>: The build project has build output generated through AWS CodePipeline.</p> </li> <li> <p> <code>NO_ARTIFACTS</code>: The build project does not produce any build output.</p> </li> <li> <p> <code>S3</code>: The build project stores build output in Amazon Simple Storage Service (Amazon S3).</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ArtifactsType,
 }
 
 /// <p>Information about the build badge for the build project.</p>
@@ -861,7 +2719,7 @@ pub struct ProjectBadge {
 }
 
 /// <p>Information about the cache for the build project.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProjectCache {
     /// <p><p>Information about the cache location: </p> <ul> <li> <p> <code>NO_CACHE</code> or <code>LOCAL</code>: This value is ignored.</p> </li> <li> <p> <code>S3</code>: This is the S3 bucket name/prefix.</p> </li> </ul></p>
     #[serde(rename = "location")]
@@ -870,14 +2728,14 @@ pub struct ProjectCache {
     /// <p><p> If you use a <code>LOCAL</code> cache, the local cache mode. You can use one or more local cache modes at the same time. </p> <ul> <li> <p> <code>LOCAL<em>SOURCE</em>CACHE</code> mode caches Git metadata for primary and secondary sources. After the cache is created, subsequent builds pull only the change between commits. This mode is a good choice for projects with a clean working directory and a source that is a large Git repository. If you choose this option and your project does not use a Git repository (GitHub, GitHub Enterprise, or Bitbucket), the option is ignored. </p> </li> <li> <p> <code>LOCAL<em>DOCKER</em>LAYER<em>CACHE</code> mode caches existing Docker layers. This mode is a good choice for projects that build or pull large Docker images. It can prevent the performance issues caused by pulling large Docker images down from the network. </p> <note> <ul> <li> <p> You can use a Docker layer cache in the Linux environment only. </p> </li> <li> <p> The <code>privileged</code> flag must be set so that your project has the required Docker permissions. </p> </li> <li> <p> You should consider the security implications before you use a Docker layer cache. </p> </li> </ul> </note> </li> </ul> <ul> <li> <p> <code>LOCAL</em>CUSTOM_CACHE</code> mode caches directories you specify in the buildspec file. This mode is a good choice if your build scenario is not suited to one of the other three local cache modes. If you use a custom cache: </p> <ul> <li> <p> Only directories can be specified for caching. You cannot specify individual files. </p> </li> <li> <p> Symlinks are used to reference cached directories. </p> </li> <li> <p> Cached directories are linked to your build before it downloads its project sources. Cached items are overriden if a source item has the same name. Directories are specified using cache paths in the buildspec file. </p> </li> </ul> </li> </ul></p>
     #[serde(rename = "modes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub modes: Option<Vec<String>>,
+    pub modes: Option<Vec<CacheMode>>,
     /// <p><p>The type of cache used by the build project. Valid values include:</p> <ul> <li> <p> <code>NO_CACHE</code>: The build project does not use any cache.</p> </li> <li> <p> <code>S3</code>: The build project reads and writes from and to S3.</p> </li> <li> <p> <code>LOCAL</code>: The build project stores a cache locally on a build host that is only available to that build host.</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: CacheType,
 }
 
 /// <p>Information about the build environment of the build project.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProjectEnvironment {
     /// <p>The certificate to use with this build project.</p>
     #[serde(rename = "certificate")]
@@ -885,7 +2743,7 @@ pub struct ProjectEnvironment {
     pub certificate: Option<String>,
     /// <p><p>Information about the compute resources the build project uses. Available values include:</p> <ul> <li> <p> <code>BUILD<em>GENERAL1</em>SMALL</code>: Use up to 3 GB memory and 2 vCPUs for builds.</p> </li> <li> <p> <code>BUILD<em>GENERAL1</em>MEDIUM</code>: Use up to 7 GB memory and 4 vCPUs for builds.</p> </li> <li> <p> <code>BUILD<em>GENERAL1</em>LARGE</code>: Use up to 15 GB memory and 8 vCPUs for builds.</p> </li> </ul></p>
     #[serde(rename = "computeType")]
-    pub compute_type: String,
+    pub compute_type: ComputeType,
     /// <p>A set of environment variables to make available to builds for this build project.</p>
     #[serde(rename = "environmentVariables")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -896,7 +2754,7 @@ pub struct ProjectEnvironment {
     /// <p> The type of credentials AWS CodeBuild uses to pull images in your build. There are two valid values: </p> <ul> <li> <p> <code>CODEBUILD</code> specifies that AWS CodeBuild uses its own credentials. This requires that you modify your ECR repository policy to trust AWS CodeBuild's service principal. </p> </li> <li> <p> <code>SERVICE_ROLE</code> specifies that AWS CodeBuild uses your build project's service role. </p> </li> </ul> <p> When you use a cross-account or private registry image, you must use SERVICE_ROLE credentials. When you use an AWS CodeBuild curated image, you must use CODEBUILD credentials. </p>
     #[serde(rename = "imagePullCredentialsType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_pull_credentials_type: Option<String>,
+    pub image_pull_credentials_type: Option<ImagePullCredentialsType>,
     /// <p>Enables running the Docker daemon inside a Docker container. Set to true only if the build project is used to build Docker images. Otherwise, a build that attempts to interact with the Docker daemon fails.</p> <p>You can initialize the Docker daemon during the install phase of your build by adding one of the following sets of commands to the install phase of your buildspec file:</p> <p>If the operating system's base image is Ubuntu Linux:</p> <p> <code>- nohup /usr/local/bin/dockerd --host=unix:///var/run/docker.sock --host=tcp://0.0.0.0:2375 --storage-driver=overlay&amp;</code> </p> <p> <code>- timeout 15 sh -c "until docker info; do echo .; sleep 1; done"</code> </p> <p>If the operating system's base image is Alpine Linux and the previous command does not work, add the <code>-t</code> argument to <code>timeout</code>:</p> <p> <code>- nohup /usr/local/bin/dockerd --host=unix:///var/run/docker.sock --host=tcp://0.0.0.0:2375 --storage-driver=overlay&amp;</code> </p> <p> <code>- timeout -t 15 sh -c "until docker info; do echo .; sleep 1; done"</code> </p>
     #[serde(rename = "privilegedMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -907,11 +2765,87 @@ pub struct ProjectEnvironment {
     pub registry_credential: Option<RegistryCredential>,
     /// <p>The type of build environment to use for related builds.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: EnvironmentType,
+}
+
+/// <p>The criterion to be used to list build project names. Valid values include:</p> <ul> <li> <p> <code>CREATED_TIME</code>: List based on when each build project was created.</p> </li> <li> <p> <code>LAST_MODIFIED_TIME</code>: List based on when information about each build project was last changed.</p> </li> <li> <p> <code>NAME</code>: List based on each build project's name.</p> </li> </ul> <p>Use <code>sortOrder</code> to specify in what order to list the build project names based on the preceding criteria.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProjectSortByType {
+    /// <p><code>NAME</code> - List based on each build project's name.</p>
+    Name,
+    /// <p><code>CREATED_TIME</code> - List based on when each build project was created.</p>
+    CreatedTime,
+    /// <p><code>LAST_MODIFIED_TIME</code> - List based on when information about each build project was last changed.</p>
+    LastModifiedTime,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProjectSortByType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProjectSortByType::Name => "NAME",
+            ProjectSortByType::CreatedTime => "CREATED_TIME",
+            ProjectSortByType::LastModifiedTime => "LAST_MODIFIED_TIME",
+            ProjectSortByType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProjectSortByType {
+    fn from(value: &'a str) -> ProjectSortByType {
+        match value {
+            "NAME" => ProjectSortByType::Name,
+            "CREATED_TIME" => ProjectSortByType::CreatedTime,
+            "LAST_MODIFIED_TIME" => ProjectSortByType::LastModifiedTime,
+            _ => ProjectSortByType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProjectSortByType {
+    fn from(value: String) -> ProjectSortByType {
+        ProjectSortByType::from(value.as_str())
+    }
+}
+
+impl From<ProjectSortByType> for String {
+    fn from(value: ProjectSortByType) -> String {
+        match value {
+            ProjectSortByType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectSortByType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProjectSortByType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProjectSortByType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProjectSortByType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProjectSortByType::from(value))
+    }
 }
 
 /// <p>Information about the build input source code for the build project.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProjectSource {
     /// <p>Information about the authorization settings for AWS CodeBuild to access the source code to be built.</p> <p>This information is for the AWS CodeBuild console's use only. Your code should not get or set this information directly.</p>
     #[serde(rename = "auth")]
@@ -947,7 +2881,7 @@ pub struct ProjectSource {
     pub source_identifier: Option<String>,
     /// <p><p>The type of repository that contains the source code to be built. Valid values include:</p> <ul> <li> <p> <code>BITBUCKET</code>: The source code is in a Bitbucket repository.</p> </li> <li> <p> <code>CODECOMMIT</code>: The source code is in an AWS CodeCommit repository.</p> </li> <li> <p> <code>CODEPIPELINE</code>: The source code settings are specified in the source action of a pipeline in AWS CodePipeline.</p> </li> <li> <p> <code>GITHUB</code>: The source code is in a GitHub repository.</p> </li> <li> <p> <code>NO_SOURCE</code>: The project does not have input source code.</p> </li> <li> <p> <code>S3</code>: The source code is in an Amazon Simple Storage Service (Amazon S3) input bucket.</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: SourceType,
 }
 
 /// <p> A source identifier and its corresponding version. </p>
@@ -962,18 +2896,18 @@ pub struct ProjectSourceVersion {
 }
 
 /// <p> Information about credentials that provide access to a private Docker registry. When this is set: </p> <ul> <li> <p> <code>imagePullCredentialsType</code> must be set to <code>SERVICE_ROLE</code>. </p> </li> <li> <p> images cannot be curated or an Amazon ECR image.</p> </li> </ul> <p> For more information, see <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/sample-private-registry.html">Private Registry with AWS Secrets Manager Sample for AWS CodeBuild</a>. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RegistryCredential {
     /// <p><p> The Amazon Resource Name (ARN) or name of credentials created using AWS Secrets Manager. </p> <note> <p> The <code>credential</code> can use the name of the credentials only if they exist in your current region. </p> </note></p>
     #[serde(rename = "credential")]
     pub credential: String,
     /// <p> The service that created the credentials to access a private Docker registry. The valid value, SECRETS_MANAGER, is for AWS Secrets Manager. </p>
     #[serde(rename = "credentialProvider")]
-    pub credential_provider: String,
+    pub credential_provider: CredentialProviderType,
 }
 
 /// <p> Information about S3 logs for a build project. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3LogsConfig {
     /// <p> Set to true if you do not want your S3 build log output encrypted. By default S3 build logs are encrypted. </p>
     #[serde(rename = "encryptionDisabled")]
@@ -985,11 +2919,159 @@ pub struct S3LogsConfig {
     pub location: Option<String>,
     /// <p><p>The current status of the S3 build logs. Valid values are:</p> <ul> <li> <p> <code>ENABLED</code>: S3 build logs are enabled for this build project.</p> </li> <li> <p> <code>DISABLED</code>: S3 build logs are not enabled for this build project.</p> </li> </ul></p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: LogsConfigStatusType,
+}
+
+/// <p> The source provider used for this project. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServerType {
+    /// <p><code>GITHUB</code></p>
+    Github,
+    /// <p><code>BITBUCKET</code></p>
+    Bitbucket,
+    /// <p><code>GITHUB_ENTERPRISE</code></p>
+    GithubEnterprise,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServerType::Github => "GITHUB",
+            ServerType::Bitbucket => "BITBUCKET",
+            ServerType::GithubEnterprise => "GITHUB_ENTERPRISE",
+            ServerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServerType {
+    fn from(value: &'a str) -> ServerType {
+        match value {
+            "GITHUB" => ServerType::Github,
+            "BITBUCKET" => ServerType::Bitbucket,
+            "GITHUB_ENTERPRISE" => ServerType::GithubEnterprise,
+            _ => ServerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServerType {
+    fn from(value: String) -> ServerType {
+        ServerType::from(value.as_str())
+    }
+}
+
+impl From<ServerType> for String {
+    fn from(value: ServerType) -> String {
+        match value {
+            ServerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ServerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ServerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ServerType::from(value))
+    }
+}
+
+/// <p><p>The order to list build IDs. Valid values include:</p> <ul> <li> <p> <code>ASCENDING</code>: List the build IDs in ascending order by build ID.</p> </li> <li> <p> <code>DESCENDING</code>: List the build IDs in descending order by build ID.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrderType {
+    /// <p><code>ASCENDING</code> - List the build IDs in ascending order by build ID.</p>
+    Ascending,
+    /// <p><code>DESCENDING</code> - List the build IDs in descending order by build ID.</p>
+    Descending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SortOrderType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrderType::Ascending => "ASCENDING",
+            SortOrderType::Descending => "DESCENDING",
+            SortOrderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SortOrderType {
+    fn from(value: &'a str) -> SortOrderType {
+        match value {
+            "ASCENDING" => SortOrderType::Ascending,
+            "DESCENDING" => SortOrderType::Descending,
+            _ => SortOrderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrderType {
+    fn from(value: String) -> SortOrderType {
+        SortOrderType::from(value.as_str())
+    }
+}
+
+impl From<SortOrderType> for String {
+    fn from(value: SortOrderType) -> String {
+        match value {
+            SortOrderType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SortOrderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SortOrderType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrderType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SortOrderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SortOrderType::from(value))
+    }
 }
 
 /// <p>Information about the authorization settings for AWS CodeBuild to access the source code to be built.</p> <p>This information is for the AWS CodeBuild console's use only. Your code should not get or set this information directly.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SourceAuth {
     /// <p>The resource value that applies to the specified authorization type.</p>
     #[serde(rename = "resource")]
@@ -997,7 +3079,75 @@ pub struct SourceAuth {
     pub resource: Option<String>,
     /// <p><note> <p> This data type is deprecated and is no longer accurate or used. </p> </note> <p>The authorization type to use. The only valid value is <code>OAUTH</code>, which represents the OAuth authorization type.</p></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: SourceAuthType,
+}
+
+/// <p><note> <p> This data type is deprecated and is no longer accurate or used. </p> </note> <p>The authorization type to use. The only valid value is <code>OAUTH</code>, which represents the OAuth authorization type.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceAuthType {
+    /// <p><code>OAUTH</code></p>
+    Oauth,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SourceAuthType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceAuthType::Oauth => "OAUTH",
+            SourceAuthType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceAuthType {
+    fn from(value: &'a str) -> SourceAuthType {
+        match value {
+            "OAUTH" => SourceAuthType::Oauth,
+            _ => SourceAuthType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceAuthType {
+    fn from(value: String) -> SourceAuthType {
+        SourceAuthType::from(value.as_str())
+    }
+}
+
+impl From<SourceAuthType> for String {
+    fn from(value: SourceAuthType) -> String {
+        match value {
+            SourceAuthType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SourceAuthType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SourceAuthType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SourceAuthType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SourceAuthType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SourceAuthType::from(value))
+    }
 }
 
 /// <p> Information about the credentials for a GitHub, GitHub Enterprise, or Bitbucket repository. </p>
@@ -1011,11 +3161,103 @@ pub struct SourceCredentialsInfo {
     /// <p> The type of authentication used by the credentials. Valid options are OAUTH, BASIC_AUTH, or PERSONAL_ACCESS_TOKEN. </p>
     #[serde(rename = "authType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth_type: Option<String>,
+    pub auth_type: Option<AuthType>,
     /// <p> The type of source provider. The valid options are GITHUB, GITHUB_ENTERPRISE, or BITBUCKET. </p>
     #[serde(rename = "serverType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub server_type: Option<String>,
+    pub server_type: Option<ServerType>,
+}
+
+/// <p><p>The type of repository that contains the source code to be built. Valid values include:</p> <ul> <li> <p> <code>BITBUCKET</code>: The source code is in a Bitbucket repository.</p> </li> <li> <p> <code>CODECOMMIT</code>: The source code is in an AWS CodeCommit repository.</p> </li> <li> <p> <code>CODEPIPELINE</code>: The source code settings are specified in the source action of a pipeline in AWS CodePipeline.</p> </li> <li> <p> <code>GITHUB</code>: The source code is in a GitHub repository.</p> </li> <li> <p> <code>NO_SOURCE</code>: The project does not have input source code.</p> </li> <li> <p> <code>S3</code>: The source code is in an Amazon Simple Storage Service (Amazon S3) input bucket.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceType {
+    /// <p><code>CODECOMMIT</code> - The source code is in an AWS CodeCommit repository.</p>
+    Codecommit,
+    /// <p><code>CODEPIPELINE</code> - The source code settings are specified in the source action of a pipeline in AWS CodePipeline.</p>
+    Codepipeline,
+    /// <p><code>GITHUB</code> - The source code is in a GitHub repository.</p>
+    Github,
+    /// <p><code>S3</code> - The source code is in an Amazon Simple Storage Service (Amazon S3) input bucket.</p>
+    S3,
+    /// <p><code>BITBUCKET</code> - The source code is in a Bitbucket repository.</p>
+    Bitbucket,
+    /// <p><code>GITHUB_ENTERPRISE</code></p>
+    GithubEnterprise,
+    /// <p><code>NO_SOURCE</code> - The project does not have input source code.</p>
+    NoSource,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::Codecommit => "CODECOMMIT",
+            SourceType::Codepipeline => "CODEPIPELINE",
+            SourceType::Github => "GITHUB",
+            SourceType::S3 => "S3",
+            SourceType::Bitbucket => "BITBUCKET",
+            SourceType::GithubEnterprise => "GITHUB_ENTERPRISE",
+            SourceType::NoSource => "NO_SOURCE",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "CODECOMMIT" => SourceType::Codecommit,
+            "CODEPIPELINE" => SourceType::Codepipeline,
+            "GITHUB" => SourceType::Github,
+            "S3" => SourceType::S3,
+            "BITBUCKET" => SourceType::Bitbucket,
+            "GITHUB_ENTERPRISE" => SourceType::GithubEnterprise,
+            "NO_SOURCE" => SourceType::NoSource,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        SourceType::from(value.as_str())
+    }
+}
+
+impl From<SourceType> for String {
+    fn from(value: SourceType) -> String {
+        match value {
+            SourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SourceType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1039,11 +3281,11 @@ pub struct StartBuildInput {
     /// <p>The name of a compute type for this build that overrides the one specified in the build project.</p>
     #[serde(rename = "computeTypeOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_type_override: Option<String>,
+    pub compute_type_override: Option<ComputeType>,
     /// <p>A container type for this build that overrides the one specified in the build project.</p>
     #[serde(rename = "environmentTypeOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub environment_type_override: Option<String>,
+    pub environment_type_override: Option<EnvironmentType>,
     /// <p>A set of environment variables that overrides, for this build only, the latest ones already defined in the build project.</p>
     #[serde(rename = "environmentVariablesOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1067,7 +3309,7 @@ pub struct StartBuildInput {
     /// <p> The type of credentials AWS CodeBuild uses to pull images in your build. There are two valid values: </p> <ul> <li> <p> <code>CODEBUILD</code> specifies that AWS CodeBuild uses its own credentials. This requires that you modify your ECR repository policy to trust AWS CodeBuild's service principal.</p> </li> <li> <p> <code>SERVICE_ROLE</code> specifies that AWS CodeBuild uses your build project's service role. </p> </li> </ul> <p> When using a cross-account or private registry image, you must use SERVICE_ROLE credentials. When using an AWS CodeBuild curated image, you must use CODEBUILD credentials. </p>
     #[serde(rename = "imagePullCredentialsTypeOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_pull_credentials_type_override: Option<String>,
+    pub image_pull_credentials_type_override: Option<ImagePullCredentialsType>,
     /// <p>Enable this flag to override the insecure SSL setting that is specified in the build project. The insecure SSL setting determines whether to ignore SSL warnings while connecting to the project source code. This override applies only if the build's source is GitHub Enterprise.</p>
     #[serde(rename = "insecureSslOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1122,7 +3364,7 @@ pub struct StartBuildInput {
     /// <p>A source input type, for this build, that overrides the source input defined in the build project.</p>
     #[serde(rename = "sourceTypeOverride")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type_override: Option<String>,
+    pub source_type_override: Option<SourceType>,
     /// <p>A version of the build input to be built, for this build only. If not specified, the latest version is used. If specified, must be one of:</p> <ul> <li> <p>For AWS CodeCommit: the commit ID to use.</p> </li> <li> <p>For GitHub: the commit ID, pull request ID, branch name, or tag name that corresponds to the version of the source code you want to build. If a pull request ID is specified, it must use the format <code>pr/pull-request-ID</code> (for example <code>pr/25</code>). If a branch name is specified, the branch's HEAD commit ID is used. If not specified, the default branch's HEAD commit ID is used.</p> </li> <li> <p>For Bitbucket: the commit ID, branch name, or tag name that corresponds to the version of the source code you want to build. If a branch name is specified, the branch's HEAD commit ID is used. If not specified, the default branch's HEAD commit ID is used.</p> </li> <li> <p>For Amazon Simple Storage Service (Amazon S3): the version ID of the object that represents the build input ZIP file to use.</p> </li> </ul> <p> If <code>sourceVersion</code> is specified at the project level, then this <code>sourceVersion</code> (at the build level) takes precedence. </p> <p> For more information, see <a href="https://docs.aws.amazon.com/codebuild/latest/userguide/sample-source-version.html">Source Version Sample with CodeBuild</a> in the <i>AWS CodeBuild User Guide</i>. </p>
     #[serde(rename = "sourceVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1133,6 +3375,267 @@ pub struct StartBuildInput {
     pub timeout_in_minutes_override: Option<i64>,
 }
 
+impl StartBuildInput {
+    /// Returns a builder for `StartBuildInput`, checking its required members when built.
+    pub fn builder() -> StartBuildInputBuilder {
+        StartBuildInputBuilder::default()
+    }
+}
+
+/// Builds a [`StartBuildInput`](struct.StartBuildInput.html).
+#[derive(Default, Debug)]
+pub struct StartBuildInputBuilder {
+    artifacts_override: Option<ProjectArtifacts>,
+    buildspec_override: Option<String>,
+    cache_override: Option<ProjectCache>,
+    certificate_override: Option<String>,
+    compute_type_override: Option<ComputeType>,
+    environment_type_override: Option<EnvironmentType>,
+    environment_variables_override: Option<Vec<EnvironmentVariable>>,
+    git_clone_depth_override: Option<i64>,
+    git_submodules_config_override: Option<GitSubmodulesConfig>,
+    idempotency_token: Option<String>,
+    image_override: Option<String>,
+    image_pull_credentials_type_override: Option<ImagePullCredentialsType>,
+    insecure_ssl_override: Option<bool>,
+    logs_config_override: Option<LogsConfig>,
+    privileged_mode_override: Option<bool>,
+    project_name: Option<String>,
+    queued_timeout_in_minutes_override: Option<i64>,
+    registry_credential_override: Option<RegistryCredential>,
+    report_build_status_override: Option<bool>,
+    secondary_artifacts_override: Option<Vec<ProjectArtifacts>>,
+    secondary_sources_override: Option<Vec<ProjectSource>>,
+    secondary_sources_version_override: Option<Vec<ProjectSourceVersion>>,
+    service_role_override: Option<String>,
+    source_auth_override: Option<SourceAuth>,
+    source_location_override: Option<String>,
+    source_type_override: Option<SourceType>,
+    source_version: Option<String>,
+    timeout_in_minutes_override: Option<i64>,
+}
+
+impl StartBuildInputBuilder {
+    /// Sets the [`artifacts_override`](struct.StartBuildInput.html#structfield.artifacts_override) member.
+    pub fn artifacts_override<T: Into<ProjectArtifacts>>(mut self, value: T) -> Self {
+        self.artifacts_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`buildspec_override`](struct.StartBuildInput.html#structfield.buildspec_override) member.
+    pub fn buildspec_override<T: Into<String>>(mut self, value: T) -> Self {
+        self.buildspec_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_override`](struct.StartBuildInput.html#structfield.cache_override) member.
+    pub fn cache_override<T: Into<ProjectCache>>(mut self, value: T) -> Self {
+        self.cache_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_override`](struct.StartBuildInput.html#structfield.certificate_override) member.
+    pub fn certificate_override<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`compute_type_override`](struct.StartBuildInput.html#structfield.compute_type_override) member.
+    pub fn compute_type_override<T: Into<ComputeType>>(mut self, value: T) -> Self {
+        self.compute_type_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_type_override`](struct.StartBuildInput.html#structfield.environment_type_override) member.
+    pub fn environment_type_override<T: Into<EnvironmentType>>(mut self, value: T) -> Self {
+        self.environment_type_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_variables_override`](struct.StartBuildInput.html#structfield.environment_variables_override) member.
+    pub fn environment_variables_override<T: Into<Vec<EnvironmentVariable>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.environment_variables_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`git_clone_depth_override`](struct.StartBuildInput.html#structfield.git_clone_depth_override) member.
+    pub fn git_clone_depth_override<T: Into<i64>>(mut self, value: T) -> Self {
+        self.git_clone_depth_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`git_submodules_config_override`](struct.StartBuildInput.html#structfield.git_submodules_config_override) member.
+    pub fn git_submodules_config_override<T: Into<GitSubmodulesConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.git_submodules_config_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idempotency_token`](struct.StartBuildInput.html#structfield.idempotency_token) member.
+    pub fn idempotency_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.idempotency_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_override`](struct.StartBuildInput.html#structfield.image_override) member.
+    pub fn image_override<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_pull_credentials_type_override`](struct.StartBuildInput.html#structfield.image_pull_credentials_type_override) member.
+    pub fn image_pull_credentials_type_override<T: Into<ImagePullCredentialsType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.image_pull_credentials_type_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`insecure_ssl_override`](struct.StartBuildInput.html#structfield.insecure_ssl_override) member.
+    pub fn insecure_ssl_override<T: Into<bool>>(mut self, value: T) -> Self {
+        self.insecure_ssl_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logs_config_override`](struct.StartBuildInput.html#structfield.logs_config_override) member.
+    pub fn logs_config_override<T: Into<LogsConfig>>(mut self, value: T) -> Self {
+        self.logs_config_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`privileged_mode_override`](struct.StartBuildInput.html#structfield.privileged_mode_override) member.
+    pub fn privileged_mode_override<T: Into<bool>>(mut self, value: T) -> Self {
+        self.privileged_mode_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.StartBuildInput.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queued_timeout_in_minutes_override`](struct.StartBuildInput.html#structfield.queued_timeout_in_minutes_override) member.
+    pub fn queued_timeout_in_minutes_override<T: Into<i64>>(mut self, value: T) -> Self {
+        self.queued_timeout_in_minutes_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_credential_override`](struct.StartBuildInput.html#structfield.registry_credential_override) member.
+    pub fn registry_credential_override<T: Into<RegistryCredential>>(mut self, value: T) -> Self {
+        self.registry_credential_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`report_build_status_override`](struct.StartBuildInput.html#structfield.report_build_status_override) member.
+    pub fn report_build_status_override<T: Into<bool>>(mut self, value: T) -> Self {
+        self.report_build_status_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secondary_artifacts_override`](struct.StartBuildInput.html#structfield.secondary_artifacts_override) member.
+    pub fn secondary_artifacts_override<T: Into<Vec<ProjectArtifacts>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.secondary_artifacts_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secondary_sources_override`](struct.StartBuildInput.html#structfield.secondary_sources_override) member.
+    pub fn secondary_sources_override<T: Into<Vec<ProjectSource>>>(mut self, value: T) -> Self {
+        self.secondary_sources_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secondary_sources_version_override`](struct.StartBuildInput.html#structfield.secondary_sources_version_override) member.
+    pub fn secondary_sources_version_override<T: Into<Vec<ProjectSourceVersion>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.secondary_sources_version_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role_override`](struct.StartBuildInput.html#structfield.service_role_override) member.
+    pub fn service_role_override<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_auth_override`](struct.StartBuildInput.html#structfield.source_auth_override) member.
+    pub fn source_auth_override<T: Into<SourceAuth>>(mut self, value: T) -> Self {
+        self.source_auth_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_location_override`](struct.StartBuildInput.html#structfield.source_location_override) member.
+    pub fn source_location_override<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_location_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type_override`](struct.StartBuildInput.html#structfield.source_type_override) member.
+    pub fn source_type_override<T: Into<SourceType>>(mut self, value: T) -> Self {
+        self.source_type_override = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_version`](struct.StartBuildInput.html#structfield.source_version) member.
+    pub fn source_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout_in_minutes_override`](struct.StartBuildInput.html#structfield.timeout_in_minutes_override) member.
+    pub fn timeout_in_minutes_override<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout_in_minutes_override = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartBuildInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartBuildInput, ::rusoto_core::BuildError> {
+        Ok(StartBuildInput {
+            artifacts_override: self.artifacts_override,
+            buildspec_override: self.buildspec_override,
+            cache_override: self.cache_override,
+            certificate_override: self.certificate_override,
+            compute_type_override: self.compute_type_override,
+            environment_type_override: self.environment_type_override,
+            environment_variables_override: self.environment_variables_override,
+            git_clone_depth_override: self.git_clone_depth_override,
+            git_submodules_config_override: self.git_submodules_config_override,
+            idempotency_token: self.idempotency_token,
+            image_override: self.image_override,
+            image_pull_credentials_type_override: self.image_pull_credentials_type_override,
+            insecure_ssl_override: self.insecure_ssl_override,
+            logs_config_override: self.logs_config_override,
+            privileged_mode_override: self.privileged_mode_override,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+            queued_timeout_in_minutes_override: self.queued_timeout_in_minutes_override,
+            registry_credential_override: self.registry_credential_override,
+            report_build_status_override: self.report_build_status_override,
+            secondary_artifacts_override: self.secondary_artifacts_override,
+            secondary_sources_override: self.secondary_sources_override,
+            secondary_sources_version_override: self.secondary_sources_version_override,
+            service_role_override: self.service_role_override,
+            source_auth_override: self.source_auth_override,
+            source_location_override: self.source_location_override,
+            source_type_override: self.source_type_override,
+            source_version: self.source_version,
+            timeout_in_minutes_override: self.timeout_in_minutes_override,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartBuildOutput {
@@ -1142,6 +3645,94 @@ pub struct StartBuildOutput {
     pub build: Option<Build>,
 }
 
+/// <p><p>The current status of the build. Valid values include:</p> <ul> <li> <p> <code>FAILED</code>: The build failed.</p> </li> <li> <p> <code>FAULT</code>: The build faulted.</p> </li> <li> <p> <code>IN<em>PROGRESS</code>: The build is still in progress.</p> </li> <li> <p> <code>STOPPED</code>: The build stopped.</p> </li> <li> <p> <code>SUCCEEDED</code>: The build succeeded.</p> </li> <li> <p> <code>TIMED</em>OUT</code>: The build timed out.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StatusType {
+    /// <p><code>SUCCEEDED</code> - The build succeeded.</p>
+    Succeeded,
+    /// <p><code>FAILED</code> - The build failed.</p>
+    Failed,
+    /// <p><code>FAULT</code> - The build faulted.</p>
+    Fault,
+    /// <p><code>TIMED_OUT</code></p>
+    TimedOut,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>STOPPED</code> - The build stopped.</p>
+    Stopped,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusType::Succeeded => "SUCCEEDED",
+            StatusType::Failed => "FAILED",
+            StatusType::Fault => "FAULT",
+            StatusType::TimedOut => "TIMED_OUT",
+            StatusType::InProgress => "IN_PROGRESS",
+            StatusType::Stopped => "STOPPED",
+            StatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StatusType {
+    fn from(value: &'a str) -> StatusType {
+        match value {
+            "SUCCEEDED" => StatusType::Succeeded,
+            "FAILED" => StatusType::Failed,
+            "FAULT" => StatusType::Fault,
+            "TIMED_OUT" => StatusType::TimedOut,
+            "IN_PROGRESS" => StatusType::InProgress,
+            "STOPPED" => StatusType::Stopped,
+            _ => StatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusType {
+    fn from(value: String) -> StatusType {
+        StatusType::from(value.as_str())
+    }
+}
+
+impl From<StatusType> for String {
+    fn from(value: StatusType) -> String {
+        match value {
+            StatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StatusType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StopBuildInput {
     /// <p>The ID of the build.</p>
@@ -1149,6 +3740,34 @@ pub struct StopBuildInput {
     pub id: String,
 }
 
+impl StopBuildInput {
+    /// Returns a builder for `StopBuildInput`, checking its required members when built.
+    pub fn builder() -> StopBuildInputBuilder {
+        StopBuildInputBuilder::default()
+    }
+}
+
+/// Builds a [`StopBuildInput`](struct.StopBuildInput.html).
+#[derive(Default, Debug)]
+pub struct StopBuildInputBuilder {
+    id: Option<String>,
+}
+
+impl StopBuildInputBuilder {
+    /// Sets the [`id`](struct.StopBuildInput.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopBuildInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopBuildInput, ::rusoto_core::BuildError> {
+        Ok(StopBuildInput {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopBuildOutput {
@@ -1246,6 +3865,175 @@ pub struct UpdateProjectInput {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl UpdateProjectInput {
+    /// Returns a builder for `UpdateProjectInput`, checking its required members when built.
+    pub fn builder() -> UpdateProjectInputBuilder {
+        UpdateProjectInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateProjectInput`](struct.UpdateProjectInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateProjectInputBuilder {
+    artifacts: Option<ProjectArtifacts>,
+    badge_enabled: Option<bool>,
+    cache: Option<ProjectCache>,
+    description: Option<String>,
+    encryption_key: Option<String>,
+    environment: Option<ProjectEnvironment>,
+    logs_config: Option<LogsConfig>,
+    name: Option<String>,
+    queued_timeout_in_minutes: Option<i64>,
+    secondary_artifacts: Option<Vec<ProjectArtifacts>>,
+    secondary_source_versions: Option<Vec<ProjectSourceVersion>>,
+    secondary_sources: Option<Vec<ProjectSource>>,
+    service_role: Option<String>,
+    source: Option<ProjectSource>,
+    source_version: Option<String>,
+    tags: Option<Vec<Tag>>,
+    timeout_in_minutes: Option<i64>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl UpdateProjectInputBuilder {
+    /// Sets the [`artifacts`](struct.UpdateProjectInput.html#structfield.artifacts) member.
+    pub fn artifacts<T: Into<ProjectArtifacts>>(mut self, value: T) -> Self {
+        self.artifacts = Some(value.into());
+        self
+    }
+
+    /// Sets the [`badge_enabled`](struct.UpdateProjectInput.html#structfield.badge_enabled) member.
+    pub fn badge_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.badge_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache`](struct.UpdateProjectInput.html#structfield.cache) member.
+    pub fn cache<T: Into<ProjectCache>>(mut self, value: T) -> Self {
+        self.cache = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateProjectInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`encryption_key`](struct.UpdateProjectInput.html#structfield.encryption_key) member.
+    pub fn encryption_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.encryption_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment`](struct.UpdateProjectInput.html#structfield.environment) member.
+    pub fn environment<T: Into<ProjectEnvironment>>(mut self, value: T) -> Self {
+        self.environment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logs_config`](struct.UpdateProjectInput.html#structfield.logs_config) member.
+    pub fn logs_config<T: Into<LogsConfig>>(mut self, value: T) -> Self {
+        self.logs_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateProjectInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queued_timeout_in_minutes`](struct.UpdateProjectInput.html#structfield.queued_timeout_in_minutes) member.
+    pub fn queued_timeout_in_minutes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.queued_timeout_in_minutes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secondary_artifacts`](struct.UpdateProjectInput.html#structfield.secondary_artifacts) member.
+    pub fn secondary_artifacts<T: Into<Vec<ProjectArtifacts>>>(mut self, value: T) -> Self {
+        self.secondary_artifacts = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secondary_source_versions`](struct.UpdateProjectInput.html#structfield.secondary_source_versions) member.
+    pub fn secondary_source_versions<T: Into<Vec<ProjectSourceVersion>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.secondary_source_versions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secondary_sources`](struct.UpdateProjectInput.html#structfield.secondary_sources) member.
+    pub fn secondary_sources<T: Into<Vec<ProjectSource>>>(mut self, value: T) -> Self {
+        self.secondary_sources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role`](struct.UpdateProjectInput.html#structfield.service_role) member.
+    pub fn service_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.UpdateProjectInput.html#structfield.source) member.
+    pub fn source<T: Into<ProjectSource>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_version`](struct.UpdateProjectInput.html#structfield.source_version) member.
+    pub fn source_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.UpdateProjectInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout_in_minutes`](struct.UpdateProjectInput.html#structfield.timeout_in_minutes) member.
+    pub fn timeout_in_minutes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout_in_minutes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.UpdateProjectInput.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateProjectInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateProjectInput, ::rusoto_core::BuildError> {
+        Ok(UpdateProjectInput {
+            artifacts: self.artifacts,
+            badge_enabled: self.badge_enabled,
+            cache: self.cache,
+            description: self.description,
+            encryption_key: self.encryption_key,
+            environment: self.environment,
+            logs_config: self.logs_config,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            queued_timeout_in_minutes: self.queued_timeout_in_minutes,
+            secondary_artifacts: self.secondary_artifacts,
+            secondary_source_versions: self.secondary_source_versions,
+            secondary_sources: self.secondary_sources,
+            service_role: self.service_role,
+            source: self.source,
+            source_version: self.source_version,
+            tags: self.tags,
+            timeout_in_minutes: self.timeout_in_minutes,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateProjectOutput {
@@ -1274,6 +4062,60 @@ pub struct UpdateWebhookInput {
     pub rotate_secret: Option<bool>,
 }
 
+impl UpdateWebhookInput {
+    /// Returns a builder for `UpdateWebhookInput`, checking its required members when built.
+    pub fn builder() -> UpdateWebhookInputBuilder {
+        UpdateWebhookInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateWebhookInput`](struct.UpdateWebhookInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateWebhookInputBuilder {
+    branch_filter: Option<String>,
+    filter_groups: Option<Vec<Vec<WebhookFilter>>>,
+    project_name: Option<String>,
+    rotate_secret: Option<bool>,
+}
+
+impl UpdateWebhookInputBuilder {
+    /// Sets the [`branch_filter`](struct.UpdateWebhookInput.html#structfield.branch_filter) member.
+    pub fn branch_filter<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_groups`](struct.UpdateWebhookInput.html#structfield.filter_groups) member.
+    pub fn filter_groups<T: Into<Vec<Vec<WebhookFilter>>>>(mut self, value: T) -> Self {
+        self.filter_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.UpdateWebhookInput.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rotate_secret`](struct.UpdateWebhookInput.html#structfield.rotate_secret) member.
+    pub fn rotate_secret<T: Into<bool>>(mut self, value: T) -> Self {
+        self.rotate_secret = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateWebhookInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateWebhookInput, ::rusoto_core::BuildError> {
+        Ok(UpdateWebhookInput {
+            branch_filter: self.branch_filter,
+            filter_groups: self.filter_groups,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+            rotate_secret: self.rotate_secret,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateWebhookOutput {
@@ -1314,8 +4156,9 @@ pub struct Webhook {
     pub filter_groups: Option<Vec<Vec<WebhookFilter>>>,
     /// <p> A timestamp that indicates the last time a repository's secret token was modified. </p>
     #[serde(rename = "lastModifiedSecret")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_secret: Option<f64>,
+    pub last_modified_secret: Option<EpochTimestamp>,
     /// <p> The AWS CodeBuild endpoint where webhook events are sent.</p>
     #[serde(rename = "payloadUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1331,7 +4174,7 @@ pub struct Webhook {
 }
 
 /// <p> A filter used to determine which webhooks trigger a build. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebhookFilter {
     /// <p> Used to indicate that the <code>pattern</code> determines which webhook events do not trigger a build. If true, then a webhook event that does not match the <code>pattern</code> triggers a build. If false, then a webhook event that matches the <code>pattern</code> triggers a build. </p>
     #[serde(rename = "excludeMatchedPattern")]
@@ -1342,7 +4185,95 @@ pub struct WebhookFilter {
     pub pattern: String,
     /// <p><p> The type of webhook filter. There are five webhook filter types: <code>EVENT</code>, <code>ACTOR<em>ACCOUNT</em>ID</code>, <code>HEAD<em>REF</code>, <code>BASE</em>REF</code>, and <code>FILE<em>PATH</code>. </p> <dl> <dt> EVENT </dt> <dd> <p> A webhook event triggers a build when the provided <code>pattern</code> matches one of four event types: <code>PUSH</code>, <code>PULL</em>REQUEST<em>CREATED</code>, <code>PULL</em>REQUEST<em>UPDATED</code>, and <code>PULL</em>REQUEST<em>REOPENED</code>. The <code>EVENT</code> patterns are specified as a comma-separated string. For example, <code>PUSH, PULL</em>REQUEST<em>CREATED, PULL</em>REQUEST<em>UPDATED</code> filters all push, pull request created, and pull request updated events. </p> <note> <p> The <code>PULL</em>REQUEST<em>REOPENED</code> works with GitHub and GitHub Enterprise only. </p> </note> </dd> <dt> ACTOR</em>ACCOUNT<em>ID </dt> <dd> <p> A webhook event triggers a build when a GitHub, GitHub Enterprise, or Bitbucket account ID matches the regular expression <code>pattern</code>. </p> </dd> <dt> HEAD</em>REF </dt> <dd> <p> A webhook event triggers a build when the head reference matches the regular expression <code>pattern</code>. For example, <code>refs/heads/branch-name</code> and <code>refs/tags/tag-name</code>. </p> <p> Works with GitHub and GitHub Enterprise push, GitHub and GitHub Enterprise pull request, Bitbucket push, and Bitbucket pull request events. </p> </dd> <dt> BASE<em>REF </dt> <dd> <p> A webhook event triggers a build when the base reference matches the regular expression <code>pattern</code>. For example, <code>refs/heads/branch-name</code>. </p> <note> <p> Works with pull request events only. </p> </note> </dd> <dt> FILE</em>PATH </dt> <dd> <p> A webhook triggers a build when the path of a changed file matches the regular expression <code>pattern</code>. </p> <note> <p> Works with GitHub and GitHub Enterprise push events only. </p> </note> </dd> </dl></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: WebhookFilterType,
+}
+
+/// <p><p> The type of webhook filter. There are five webhook filter types: <code>EVENT</code>, <code>ACTOR<em>ACCOUNT</em>ID</code>, <code>HEAD<em>REF</code>, <code>BASE</em>REF</code>, and <code>FILE<em>PATH</code>. </p> <dl> <dt> EVENT </dt> <dd> <p> A webhook event triggers a build when the provided <code>pattern</code> matches one of four event types: <code>PUSH</code>, <code>PULL</em>REQUEST<em>CREATED</code>, <code>PULL</em>REQUEST<em>UPDATED</code>, and <code>PULL</em>REQUEST<em>REOPENED</code>. The <code>EVENT</code> patterns are specified as a comma-separated string. For example, <code>PUSH, PULL</em>REQUEST<em>CREATED, PULL</em>REQUEST<em>UPDATED</code> filters all push, pull request created, and pull request updated events. </p> <note> <p> The <code>PULL</em>REQUEST<em>REOPENED</code> works with GitHub and GitHub Enterprise only. </p> </note> </dd> <dt> ACTOR</em>ACCOUNT<em>ID </dt> <dd> <p> A webhook event triggers a build when a GitHub, GitHub Enterprise, or Bitbucket account ID matches the regular expression <code>pattern</code>. </p> </dd> <dt> HEAD</em>REF </dt> <dd> <p> A webhook event triggers a build when the head reference matches the regular expression <code>pattern</code>. For example, <code>refs/heads/branch-name</code> and <code>refs/tags/tag-name</code>. </p> <p> Works with GitHub and GitHub Enterprise push, GitHub and GitHub Enterprise pull request, Bitbucket push, and Bitbucket pull request events. </p> </dd> <dt> BASE<em>REF </dt> <dd> <p> A webhook event triggers a build when the base reference matches the regular expression <code>pattern</code>. For example, <code>refs/heads/branch-name</code>. </p> <note> <p> Works with pull request events only. </p> </note> </dd> <dt> FILE</em>PATH </dt> <dd> <p> A webhook triggers a build when the path of a changed file matches the regular expression <code>pattern</code>. </p> <note> <p> Works with GitHub and GitHub Enterprise push events only. </p> </note> </dd> </dl></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WebhookFilterType {
+    /// <p><code>EVENT</code></p>
+    Event,
+    /// <p><code>BASE_REF</code></p>
+    BaseRef,
+    /// <p><code>HEAD_REF</code></p>
+    HeadRef,
+    /// <p><code>ACTOR_ACCOUNT_ID</code></p>
+    ActorAccountId,
+    /// <p><code>FILE_PATH</code></p>
+    FilePath,
+    /// <p><code>COMMIT_MESSAGE</code></p>
+    CommitMessage,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WebhookFilterType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WebhookFilterType::Event => "EVENT",
+            WebhookFilterType::BaseRef => "BASE_REF",
+            WebhookFilterType::HeadRef => "HEAD_REF",
+            WebhookFilterType::ActorAccountId => "ACTOR_ACCOUNT_ID",
+            WebhookFilterType::FilePath => "FILE_PATH",
+            WebhookFilterType::CommitMessage => "COMMIT_MESSAGE",
+            WebhookFilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WebhookFilterType {
+    fn from(value: &'a str) -> WebhookFilterType {
+        match value {
+            "EVENT" => WebhookFilterType::Event,
+            "BASE_REF" => WebhookFilterType::BaseRef,
+            "HEAD_REF" => WebhookFilterType::HeadRef,
+            "ACTOR_ACCOUNT_ID" => WebhookFilterType::ActorAccountId,
+            "FILE_PATH" => WebhookFilterType::FilePath,
+            "COMMIT_MESSAGE" => WebhookFilterType::CommitMessage,
+            _ => WebhookFilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WebhookFilterType {
+    fn from(value: String) -> WebhookFilterType {
+        WebhookFilterType::from(value.as_str())
+    }
+}
+
+impl From<WebhookFilterType> for String {
+    fn from(value: WebhookFilterType) -> String {
+        match value {
+            WebhookFilterType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WebhookFilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WebhookFilterType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WebhookFilterType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WebhookFilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WebhookFilterType::from(value))
+    }
 }
 
 /// Errors returned by BatchDeleteBuilds
@@ -2191,6 +5122,124 @@ pub trait CodeBuild {
         input: UpdateWebhookInput,
     ) -> RusotoFuture<UpdateWebhookOutput, UpdateWebhookError>;
 }
+impl<T: ?Sized + CodeBuild> CodeBuild for ::std::sync::Arc<T> {
+    fn batch_delete_builds(
+        &self,
+        input: BatchDeleteBuildsInput,
+    ) -> RusotoFuture<BatchDeleteBuildsOutput, BatchDeleteBuildsError> {
+        (**self).batch_delete_builds(input)
+    }
+    fn batch_get_builds(
+        &self,
+        input: BatchGetBuildsInput,
+    ) -> RusotoFuture<BatchGetBuildsOutput, BatchGetBuildsError> {
+        (**self).batch_get_builds(input)
+    }
+    fn batch_get_projects(
+        &self,
+        input: BatchGetProjectsInput,
+    ) -> RusotoFuture<BatchGetProjectsOutput, BatchGetProjectsError> {
+        (**self).batch_get_projects(input)
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectInput,
+    ) -> RusotoFuture<CreateProjectOutput, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn create_webhook(
+        &self,
+        input: CreateWebhookInput,
+    ) -> RusotoFuture<CreateWebhookOutput, CreateWebhookError> {
+        (**self).create_webhook(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectInput,
+    ) -> RusotoFuture<DeleteProjectOutput, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn delete_source_credentials(
+        &self,
+        input: DeleteSourceCredentialsInput,
+    ) -> RusotoFuture<DeleteSourceCredentialsOutput, DeleteSourceCredentialsError> {
+        (**self).delete_source_credentials(input)
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> RusotoFuture<DeleteWebhookOutput, DeleteWebhookError> {
+        (**self).delete_webhook(input)
+    }
+    fn import_source_credentials(
+        &self,
+        input: ImportSourceCredentialsInput,
+    ) -> RusotoFuture<ImportSourceCredentialsOutput, ImportSourceCredentialsError> {
+        (**self).import_source_credentials(input)
+    }
+    fn invalidate_project_cache(
+        &self,
+        input: InvalidateProjectCacheInput,
+    ) -> RusotoFuture<InvalidateProjectCacheOutput, InvalidateProjectCacheError> {
+        (**self).invalidate_project_cache(input)
+    }
+    fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> RusotoFuture<ListBuildsOutput, ListBuildsError> {
+        (**self).list_builds(input)
+    }
+    fn list_builds_for_project(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> RusotoFuture<ListBuildsForProjectOutput, ListBuildsForProjectError> {
+        (**self).list_builds_for_project(input)
+    }
+    fn list_curated_environment_images(
+        &self,
+    ) -> RusotoFuture<ListCuratedEnvironmentImagesOutput, ListCuratedEnvironmentImagesError> {
+        (**self).list_curated_environment_images()
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsInput,
+    ) -> RusotoFuture<ListProjectsOutput, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_source_credentials(
+        &self,
+    ) -> RusotoFuture<ListSourceCredentialsOutput, ListSourceCredentialsError> {
+        (**self).list_source_credentials()
+    }
+    fn start_build(
+        &self,
+        input: StartBuildInput,
+    ) -> RusotoFuture<StartBuildOutput, StartBuildError> {
+        (**self).start_build(input)
+    }
+    fn stop_build(&self, input: StopBuildInput) -> RusotoFuture<StopBuildOutput, StopBuildError> {
+        (**self).stop_build(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectInput,
+    ) -> RusotoFuture<UpdateProjectOutput, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+    fn update_webhook(
+        &self,
+        input: UpdateWebhookInput,
+    ) -> RusotoFuture<UpdateWebhookOutput, UpdateWebhookError> {
+        (**self).update_webhook(input)
+    }
+}
+/// The error codes AWS CodeBuild retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS CodeBuild API.
 #[derive(Clone)]
 pub struct CodeBuildClient {
@@ -2206,6 +5255,27 @@ impl CodeBuildClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CODEBUILD`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CodeBuildClient {
+        Self::new(region::Region::from_env_for_service("CODEBUILD"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `codebuild`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CodeBuildClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("codebuild", "CODEBUILD"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2224,7 +5294,54 @@ impl CodeBuildClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodeBuildClient {
-        CodeBuildClient { client, region }
+        CodeBuildClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "codebuild")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "codebuild",
+            "codebuild",
+            "CODEBUILD",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CodeBuild` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CodeBuild + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CodeBuildClient {
+    fn service_name(&self) -> &'static str {
+        "AWS CodeBuild"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "codebuild"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CodeBuildClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS CodeCommit clients.
+//!
+//! Makes a single read-only `ListRepositories` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_codecommit::{CodeCommit, CodeCommitClient, ListRepositoriesInput};
+
+fn main() {
+    let client = CodeCommitClient::from_env();
+    match client
+        .list_repositories(ListRepositoriesInput::default())
+        .sync()
+    {
+        Ok(_) => println!("ListRepositories succeeded"),
+        Err(error) => {
+            eprintln!("ListRepositories failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
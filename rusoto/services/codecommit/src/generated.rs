@@ -22,7 +22,84 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>Whether the change type of the difference is an addition (A), deletion (D), or modification (M).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeTypeEnum {
+    /// <p><code>A</code></p>
+    A,
+    /// <p><code>M</code></p>
+    M,
+    /// <p><code>D</code></p>
+    D,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeTypeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeTypeEnum::A => "A",
+            ChangeTypeEnum::M => "M",
+            ChangeTypeEnum::D => "D",
+            ChangeTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeTypeEnum {
+    fn from(value: &'a str) -> ChangeTypeEnum {
+        match value {
+            "A" => ChangeTypeEnum::A,
+            "M" => ChangeTypeEnum::M,
+            "D" => ChangeTypeEnum::D,
+            _ => ChangeTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeTypeEnum {
+    fn from(value: String) -> ChangeTypeEnum {
+        ChangeTypeEnum::from(value.as_str())
+    }
+}
+
+impl From<ChangeTypeEnum> for String {
+    fn from(value: ChangeTypeEnum) -> String {
+        match value {
+            ChangeTypeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ChangeTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeTypeEnum {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChangeTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChangeTypeEnum::from(value))
+    }
+}
+
 /// <p>Information about errors in a BatchDescribeMergeConflicts operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -38,16 +115,16 @@ pub struct CodeCommitBatchDescribeMergeConflictsError {
     pub message: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct BatchDescribeMergeConflictsInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation will be successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit. For example, a branch name or a full commit ID.</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -65,7 +142,7 @@ pub struct BatchDescribeMergeConflictsInput {
     pub max_merge_hunks: Option<i64>,
     /// <p>The merge option or strategy you want to use to merge the code.</p>
     #[serde(rename = "mergeOption")]
-    pub merge_option: String,
+    pub merge_option: MergeOptionTypeEnum,
     /// <p>An enumeration token that when provided in a request, returns the next batch of the results.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -78,6 +155,119 @@ pub struct BatchDescribeMergeConflictsInput {
     pub source_commit_specifier: String,
 }
 
+impl BatchDescribeMergeConflictsInput {
+    /// Returns a builder for `BatchDescribeMergeConflictsInput`, checking its required members when built.
+    pub fn builder() -> BatchDescribeMergeConflictsInputBuilder {
+        BatchDescribeMergeConflictsInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDescribeMergeConflictsInput`](struct.BatchDescribeMergeConflictsInput.html).
+#[derive(Default, Debug)]
+pub struct BatchDescribeMergeConflictsInputBuilder {
+    conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
+    conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
+    destination_commit_specifier: Option<String>,
+    file_paths: Option<Vec<String>>,
+    max_conflict_files: Option<i64>,
+    max_merge_hunks: Option<i64>,
+    merge_option: Option<MergeOptionTypeEnum>,
+    next_token: Option<String>,
+    repository_name: Option<String>,
+    source_commit_specifier: Option<String>,
+}
+
+impl BatchDescribeMergeConflictsInputBuilder {
+    /// Sets the [`conflict_detail_level`](struct.BatchDescribeMergeConflictsInput.html#structfield.conflict_detail_level) member.
+    pub fn conflict_detail_level<T: Into<ConflictDetailLevelTypeEnum>>(mut self, value: T) -> Self {
+        self.conflict_detail_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution_strategy`](struct.BatchDescribeMergeConflictsInput.html#structfield.conflict_resolution_strategy) member.
+    pub fn conflict_resolution_strategy<T: Into<ConflictResolutionStrategyTypeEnum>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.conflict_resolution_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_commit_specifier`](struct.BatchDescribeMergeConflictsInput.html#structfield.destination_commit_specifier) member.
+    pub fn destination_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_paths`](struct.BatchDescribeMergeConflictsInput.html#structfield.file_paths) member.
+    pub fn file_paths<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.file_paths = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_conflict_files`](struct.BatchDescribeMergeConflictsInput.html#structfield.max_conflict_files) member.
+    pub fn max_conflict_files<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_conflict_files = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_merge_hunks`](struct.BatchDescribeMergeConflictsInput.html#structfield.max_merge_hunks) member.
+    pub fn max_merge_hunks<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_merge_hunks = Some(value.into());
+        self
+    }
+
+    /// Sets the [`merge_option`](struct.BatchDescribeMergeConflictsInput.html#structfield.merge_option) member.
+    pub fn merge_option<T: Into<MergeOptionTypeEnum>>(mut self, value: T) -> Self {
+        self.merge_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.BatchDescribeMergeConflictsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.BatchDescribeMergeConflictsInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_commit_specifier`](struct.BatchDescribeMergeConflictsInput.html#structfield.source_commit_specifier) member.
+    pub fn source_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDescribeMergeConflictsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDescribeMergeConflictsInput, ::rusoto_core::BuildError> {
+        Ok(BatchDescribeMergeConflictsInput {
+            conflict_detail_level: self.conflict_detail_level,
+            conflict_resolution_strategy: self.conflict_resolution_strategy,
+            destination_commit_specifier: self.destination_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("destination_commit_specifier"),
+            )?,
+            file_paths: self.file_paths,
+            max_conflict_files: self.max_conflict_files,
+            max_merge_hunks: self.max_merge_hunks,
+            merge_option: self
+                .merge_option
+                .ok_or(::rusoto_core::BuildError::missing("merge_option"))?,
+            next_token: self.next_token,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            source_commit_specifier: self.source_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_commit_specifier"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDescribeMergeConflictsOutput {
@@ -132,6 +322,46 @@ pub struct BatchGetCommitsInput {
     pub repository_name: String,
 }
 
+impl BatchGetCommitsInput {
+    /// Returns a builder for `BatchGetCommitsInput`, checking its required members when built.
+    pub fn builder() -> BatchGetCommitsInputBuilder {
+        BatchGetCommitsInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetCommitsInput`](struct.BatchGetCommitsInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetCommitsInputBuilder {
+    commit_ids: Option<Vec<String>>,
+    repository_name: Option<String>,
+}
+
+impl BatchGetCommitsInputBuilder {
+    /// Sets the [`commit_ids`](struct.BatchGetCommitsInput.html#structfield.commit_ids) member.
+    pub fn commit_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.commit_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.BatchGetCommitsInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetCommitsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchGetCommitsInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetCommitsInput {
+            commit_ids: self
+                .commit_ids
+                .ok_or(::rusoto_core::BuildError::missing("commit_ids"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetCommitsOutput {
@@ -153,6 +383,38 @@ pub struct BatchGetRepositoriesInput {
     pub repository_names: Vec<String>,
 }
 
+impl BatchGetRepositoriesInput {
+    /// Returns a builder for `BatchGetRepositoriesInput`, checking its required members when built.
+    pub fn builder() -> BatchGetRepositoriesInputBuilder {
+        BatchGetRepositoriesInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetRepositoriesInput`](struct.BatchGetRepositoriesInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetRepositoriesInputBuilder {
+    repository_names: Option<Vec<String>>,
+}
+
+impl BatchGetRepositoriesInputBuilder {
+    /// Sets the [`repository_names`](struct.BatchGetRepositoriesInput.html#structfield.repository_names) member.
+    pub fn repository_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.repository_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetRepositoriesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetRepositoriesInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetRepositoriesInput {
+            repository_names: self
+                .repository_names
+                .ok_or(::rusoto_core::BuildError::missing("repository_names"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a batch get repositories operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -221,8 +483,9 @@ pub struct Comment {
     pub content: Option<String>,
     /// <p>The date and time the comment was created, in timestamp format.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>A Boolean value indicating whether the comment has been deleted.</p>
     #[serde(rename = "deleted")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -233,8 +496,9 @@ pub struct Comment {
     pub in_reply_to: Option<String>,
     /// <p>The date and time the comment was most recently modified, in timestamp format.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
 }
 
 /// <p>Returns information about comments on the comparison between two commits.</p>
@@ -357,6 +621,80 @@ pub struct Conflict {
     pub merge_hunks: Option<Vec<MergeHunk>>,
 }
 
+/// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConflictDetailLevelTypeEnum {
+    /// <p><code>FILE_LEVEL</code></p>
+    FileLevel,
+    /// <p><code>LINE_LEVEL</code></p>
+    LineLevel,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConflictDetailLevelTypeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConflictDetailLevelTypeEnum::FileLevel => "FILE_LEVEL",
+            ConflictDetailLevelTypeEnum::LineLevel => "LINE_LEVEL",
+            ConflictDetailLevelTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConflictDetailLevelTypeEnum {
+    fn from(value: &'a str) -> ConflictDetailLevelTypeEnum {
+        match value {
+            "FILE_LEVEL" => ConflictDetailLevelTypeEnum::FileLevel,
+            "LINE_LEVEL" => ConflictDetailLevelTypeEnum::LineLevel,
+            _ => ConflictDetailLevelTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConflictDetailLevelTypeEnum {
+    fn from(value: String) -> ConflictDetailLevelTypeEnum {
+        ConflictDetailLevelTypeEnum::from(value.as_str())
+    }
+}
+
+impl From<ConflictDetailLevelTypeEnum> for String {
+    fn from(value: ConflictDetailLevelTypeEnum) -> String {
+        match value {
+            ConflictDetailLevelTypeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConflictDetailLevelTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConflictDetailLevelTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConflictDetailLevelTypeEnum {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ConflictDetailLevelTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConflictDetailLevelTypeEnum::from(value))
+    }
+}
+
 /// <p>Information about the metadata for a conflict in a merge operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -420,6 +758,88 @@ pub struct ConflictResolution {
     pub set_file_modes: Option<Vec<SetFileModeEntry>>,
 }
 
+/// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation will be successful.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConflictResolutionStrategyTypeEnum {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>ACCEPT_SOURCE</code></p>
+    AcceptSource,
+    /// <p><code>ACCEPT_DESTINATION</code></p>
+    AcceptDestination,
+    /// <p><code>AUTOMERGE</code></p>
+    Automerge,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConflictResolutionStrategyTypeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConflictResolutionStrategyTypeEnum::None => "NONE",
+            ConflictResolutionStrategyTypeEnum::AcceptSource => "ACCEPT_SOURCE",
+            ConflictResolutionStrategyTypeEnum::AcceptDestination => "ACCEPT_DESTINATION",
+            ConflictResolutionStrategyTypeEnum::Automerge => "AUTOMERGE",
+            ConflictResolutionStrategyTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConflictResolutionStrategyTypeEnum {
+    fn from(value: &'a str) -> ConflictResolutionStrategyTypeEnum {
+        match value {
+            "NONE" => ConflictResolutionStrategyTypeEnum::None,
+            "ACCEPT_SOURCE" => ConflictResolutionStrategyTypeEnum::AcceptSource,
+            "ACCEPT_DESTINATION" => ConflictResolutionStrategyTypeEnum::AcceptDestination,
+            "AUTOMERGE" => ConflictResolutionStrategyTypeEnum::Automerge,
+            _ => ConflictResolutionStrategyTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConflictResolutionStrategyTypeEnum {
+    fn from(value: String) -> ConflictResolutionStrategyTypeEnum {
+        ConflictResolutionStrategyTypeEnum::from(value.as_str())
+    }
+}
+
+impl From<ConflictResolutionStrategyTypeEnum> for String {
+    fn from(value: ConflictResolutionStrategyTypeEnum) -> String {
+        match value {
+            ConflictResolutionStrategyTypeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConflictResolutionStrategyTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConflictResolutionStrategyTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConflictResolutionStrategyTypeEnum {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ConflictResolutionStrategyTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConflictResolutionStrategyTypeEnum::from(value))
+    }
+}
+
 /// <p>Represents the input of a create branch operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateBranchInput {
@@ -434,6 +854,56 @@ pub struct CreateBranchInput {
     pub repository_name: String,
 }
 
+impl CreateBranchInput {
+    /// Returns a builder for `CreateBranchInput`, checking its required members when built.
+    pub fn builder() -> CreateBranchInputBuilder {
+        CreateBranchInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBranchInput`](struct.CreateBranchInput.html).
+#[derive(Default, Debug)]
+pub struct CreateBranchInputBuilder {
+    branch_name: Option<String>,
+    commit_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl CreateBranchInputBuilder {
+    /// Sets the [`branch_name`](struct.CreateBranchInput.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_id`](struct.CreateBranchInput.html#structfield.commit_id) member.
+    pub fn commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.CreateBranchInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBranchInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateBranchInput, ::rusoto_core::BuildError> {
+        Ok(CreateBranchInput {
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            commit_id: self
+                .commit_id
+                .ok_or(::rusoto_core::BuildError::missing("commit_id"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateCommitInput {
     /// <p>The name of the author who created the commit. This information will be used as both the author and committer for the commit.</p>
@@ -476,6 +946,110 @@ pub struct CreateCommitInput {
     pub set_file_modes: Option<Vec<SetFileModeEntry>>,
 }
 
+impl CreateCommitInput {
+    /// Returns a builder for `CreateCommitInput`, checking its required members when built.
+    pub fn builder() -> CreateCommitInputBuilder {
+        CreateCommitInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCommitInput`](struct.CreateCommitInput.html).
+#[derive(Default, Debug)]
+pub struct CreateCommitInputBuilder {
+    author_name: Option<String>,
+    branch_name: Option<String>,
+    commit_message: Option<String>,
+    delete_files: Option<Vec<DeleteFileEntry>>,
+    email: Option<String>,
+    keep_empty_folders: Option<bool>,
+    parent_commit_id: Option<String>,
+    put_files: Option<Vec<PutFileEntry>>,
+    repository_name: Option<String>,
+    set_file_modes: Option<Vec<SetFileModeEntry>>,
+}
+
+impl CreateCommitInputBuilder {
+    /// Sets the [`author_name`](struct.CreateCommitInput.html#structfield.author_name) member.
+    pub fn author_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.author_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`branch_name`](struct.CreateCommitInput.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_message`](struct.CreateCommitInput.html#structfield.commit_message) member.
+    pub fn commit_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delete_files`](struct.CreateCommitInput.html#structfield.delete_files) member.
+    pub fn delete_files<T: Into<Vec<DeleteFileEntry>>>(mut self, value: T) -> Self {
+        self.delete_files = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email`](struct.CreateCommitInput.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`keep_empty_folders`](struct.CreateCommitInput.html#structfield.keep_empty_folders) member.
+    pub fn keep_empty_folders<T: Into<bool>>(mut self, value: T) -> Self {
+        self.keep_empty_folders = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_commit_id`](struct.CreateCommitInput.html#structfield.parent_commit_id) member.
+    pub fn parent_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`put_files`](struct.CreateCommitInput.html#structfield.put_files) member.
+    pub fn put_files<T: Into<Vec<PutFileEntry>>>(mut self, value: T) -> Self {
+        self.put_files = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.CreateCommitInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`set_file_modes`](struct.CreateCommitInput.html#structfield.set_file_modes) member.
+    pub fn set_file_modes<T: Into<Vec<SetFileModeEntry>>>(mut self, value: T) -> Self {
+        self.set_file_modes = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCommitInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateCommitInput, ::rusoto_core::BuildError> {
+        Ok(CreateCommitInput {
+            author_name: self.author_name,
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            commit_message: self.commit_message,
+            delete_files: self.delete_files,
+            email: self.email,
+            keep_empty_folders: self.keep_empty_folders,
+            parent_commit_id: self.parent_commit_id,
+            put_files: self.put_files,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            set_file_modes: self.set_file_modes,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCommitOutput {
@@ -519,6 +1093,62 @@ pub struct CreatePullRequestInput {
     pub title: String,
 }
 
+impl CreatePullRequestInput {
+    /// Returns a builder for `CreatePullRequestInput`, checking its required members when built.
+    pub fn builder() -> CreatePullRequestInputBuilder {
+        CreatePullRequestInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePullRequestInput`](struct.CreatePullRequestInput.html).
+#[derive(Default, Debug)]
+pub struct CreatePullRequestInputBuilder {
+    client_request_token: Option<String>,
+    description: Option<String>,
+    targets: Option<Vec<Target>>,
+    title: Option<String>,
+}
+
+impl CreatePullRequestInputBuilder {
+    /// Sets the [`client_request_token`](struct.CreatePullRequestInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreatePullRequestInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`targets`](struct.CreatePullRequestInput.html#structfield.targets) member.
+    pub fn targets<T: Into<Vec<Target>>>(mut self, value: T) -> Self {
+        self.targets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`title`](struct.CreatePullRequestInput.html#structfield.title) member.
+    pub fn title<T: Into<String>>(mut self, value: T) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePullRequestInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePullRequestInput, ::rusoto_core::BuildError> {
+        Ok(CreatePullRequestInput {
+            client_request_token: self.client_request_token,
+            description: self.description,
+            targets: self
+                .targets
+                .ok_or(::rusoto_core::BuildError::missing("targets"))?,
+            title: self
+                .title
+                .ok_or(::rusoto_core::BuildError::missing("title"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreatePullRequestOutput {
@@ -543,6 +1173,52 @@ pub struct CreateRepositoryInput {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateRepositoryInput {
+    /// Returns a builder for `CreateRepositoryInput`, checking its required members when built.
+    pub fn builder() -> CreateRepositoryInputBuilder {
+        CreateRepositoryInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRepositoryInput`](struct.CreateRepositoryInput.html).
+#[derive(Default, Debug)]
+pub struct CreateRepositoryInputBuilder {
+    repository_description: Option<String>,
+    repository_name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateRepositoryInputBuilder {
+    /// Sets the [`repository_description`](struct.CreateRepositoryInput.html#structfield.repository_description) member.
+    pub fn repository_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.CreateRepositoryInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateRepositoryInput.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRepositoryInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateRepositoryInput, ::rusoto_core::BuildError> {
+        Ok(CreateRepositoryInput {
+            repository_description: self.repository_description,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p>Represents the output of a create repository operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -553,7 +1229,7 @@ pub struct CreateRepositoryOutput {
     pub repository_metadata: Option<RepositoryMetadata>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateUnreferencedMergeCommitInput {
     /// <p>The name of the author who created the unreferenced commit. This information will be used as both the author and committer for the commit.</p>
     #[serde(rename = "authorName")]
@@ -566,7 +1242,7 @@ pub struct CreateUnreferencedMergeCommitInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>A list of inputs to use when resolving conflicts during a merge if AUTOMERGE is chosen as the conflict resolution strategy.</p>
     #[serde(rename = "conflictResolution")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -574,7 +1250,7 @@ pub struct CreateUnreferencedMergeCommitInput {
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation will be successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit. For example, a branch name or a full commit ID.</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -588,7 +1264,7 @@ pub struct CreateUnreferencedMergeCommitInput {
     pub keep_empty_folders: Option<bool>,
     /// <p>The merge option or strategy you want to use to merge the code.</p>
     #[serde(rename = "mergeOption")]
-    pub merge_option: String,
+    pub merge_option: MergeOptionTypeEnum,
     /// <p>The name of the repository where you want to create the unreferenced merge commit.</p>
     #[serde(rename = "repositoryName")]
     pub repository_name: String,
@@ -597,6 +1273,127 @@ pub struct CreateUnreferencedMergeCommitInput {
     pub source_commit_specifier: String,
 }
 
+impl CreateUnreferencedMergeCommitInput {
+    /// Returns a builder for `CreateUnreferencedMergeCommitInput`, checking its required members when built.
+    pub fn builder() -> CreateUnreferencedMergeCommitInputBuilder {
+        CreateUnreferencedMergeCommitInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUnreferencedMergeCommitInput`](struct.CreateUnreferencedMergeCommitInput.html).
+#[derive(Default, Debug)]
+pub struct CreateUnreferencedMergeCommitInputBuilder {
+    author_name: Option<String>,
+    commit_message: Option<String>,
+    conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
+    conflict_resolution: Option<ConflictResolution>,
+    conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
+    destination_commit_specifier: Option<String>,
+    email: Option<String>,
+    keep_empty_folders: Option<bool>,
+    merge_option: Option<MergeOptionTypeEnum>,
+    repository_name: Option<String>,
+    source_commit_specifier: Option<String>,
+}
+
+impl CreateUnreferencedMergeCommitInputBuilder {
+    /// Sets the [`author_name`](struct.CreateUnreferencedMergeCommitInput.html#structfield.author_name) member.
+    pub fn author_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.author_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_message`](struct.CreateUnreferencedMergeCommitInput.html#structfield.commit_message) member.
+    pub fn commit_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_detail_level`](struct.CreateUnreferencedMergeCommitInput.html#structfield.conflict_detail_level) member.
+    pub fn conflict_detail_level<T: Into<ConflictDetailLevelTypeEnum>>(mut self, value: T) -> Self {
+        self.conflict_detail_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution`](struct.CreateUnreferencedMergeCommitInput.html#structfield.conflict_resolution) member.
+    pub fn conflict_resolution<T: Into<ConflictResolution>>(mut self, value: T) -> Self {
+        self.conflict_resolution = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution_strategy`](struct.CreateUnreferencedMergeCommitInput.html#structfield.conflict_resolution_strategy) member.
+    pub fn conflict_resolution_strategy<T: Into<ConflictResolutionStrategyTypeEnum>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.conflict_resolution_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_commit_specifier`](struct.CreateUnreferencedMergeCommitInput.html#structfield.destination_commit_specifier) member.
+    pub fn destination_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email`](struct.CreateUnreferencedMergeCommitInput.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`keep_empty_folders`](struct.CreateUnreferencedMergeCommitInput.html#structfield.keep_empty_folders) member.
+    pub fn keep_empty_folders<T: Into<bool>>(mut self, value: T) -> Self {
+        self.keep_empty_folders = Some(value.into());
+        self
+    }
+
+    /// Sets the [`merge_option`](struct.CreateUnreferencedMergeCommitInput.html#structfield.merge_option) member.
+    pub fn merge_option<T: Into<MergeOptionTypeEnum>>(mut self, value: T) -> Self {
+        self.merge_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.CreateUnreferencedMergeCommitInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_commit_specifier`](struct.CreateUnreferencedMergeCommitInput.html#structfield.source_commit_specifier) member.
+    pub fn source_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUnreferencedMergeCommitInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateUnreferencedMergeCommitInput, ::rusoto_core::BuildError> {
+        Ok(CreateUnreferencedMergeCommitInput {
+            author_name: self.author_name,
+            commit_message: self.commit_message,
+            conflict_detail_level: self.conflict_detail_level,
+            conflict_resolution: self.conflict_resolution,
+            conflict_resolution_strategy: self.conflict_resolution_strategy,
+            destination_commit_specifier: self.destination_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("destination_commit_specifier"),
+            )?,
+            email: self.email,
+            keep_empty_folders: self.keep_empty_folders,
+            merge_option: self
+                .merge_option
+                .ok_or(::rusoto_core::BuildError::missing("merge_option"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            source_commit_specifier: self.source_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_commit_specifier"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateUnreferencedMergeCommitOutput {
@@ -621,14 +1418,54 @@ pub struct DeleteBranchInput {
     pub repository_name: String,
 }
 
-/// <p>Represents the output of a delete branch operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DeleteBranchOutput {
-    /// <p>Information about the branch deleted by the operation, including the branch name and the commit ID that was the tip of the branch.</p>
-    #[serde(rename = "deletedBranch")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub deleted_branch: Option<BranchInfo>,
+impl DeleteBranchInput {
+    /// Returns a builder for `DeleteBranchInput`, checking its required members when built.
+    pub fn builder() -> DeleteBranchInputBuilder {
+        DeleteBranchInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBranchInput`](struct.DeleteBranchInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteBranchInputBuilder {
+    branch_name: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl DeleteBranchInputBuilder {
+    /// Sets the [`branch_name`](struct.DeleteBranchInput.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.DeleteBranchInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBranchInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteBranchInput, ::rusoto_core::BuildError> {
+        Ok(DeleteBranchInput {
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
+/// <p>Represents the output of a delete branch operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteBranchOutput {
+    /// <p>Information about the branch deleted by the operation, including the branch name and the commit ID that was the tip of the branch.</p>
+    #[serde(rename = "deletedBranch")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_branch: Option<BranchInfo>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -638,6 +1475,38 @@ pub struct DeleteCommentContentInput {
     pub comment_id: String,
 }
 
+impl DeleteCommentContentInput {
+    /// Returns a builder for `DeleteCommentContentInput`, checking its required members when built.
+    pub fn builder() -> DeleteCommentContentInputBuilder {
+        DeleteCommentContentInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCommentContentInput`](struct.DeleteCommentContentInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteCommentContentInputBuilder {
+    comment_id: Option<String>,
+}
+
+impl DeleteCommentContentInputBuilder {
+    /// Sets the [`comment_id`](struct.DeleteCommentContentInput.html#structfield.comment_id) member.
+    pub fn comment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCommentContentInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCommentContentInput, ::rusoto_core::BuildError> {
+        Ok(DeleteCommentContentInput {
+            comment_id: self
+                .comment_id
+                .ok_or(::rusoto_core::BuildError::missing("comment_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteCommentContentOutput {
@@ -687,6 +1556,98 @@ pub struct DeleteFileInput {
     pub repository_name: String,
 }
 
+impl DeleteFileInput {
+    /// Returns a builder for `DeleteFileInput`, checking its required members when built.
+    pub fn builder() -> DeleteFileInputBuilder {
+        DeleteFileInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFileInput`](struct.DeleteFileInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteFileInputBuilder {
+    branch_name: Option<String>,
+    commit_message: Option<String>,
+    email: Option<String>,
+    file_path: Option<String>,
+    keep_empty_folders: Option<bool>,
+    name: Option<String>,
+    parent_commit_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl DeleteFileInputBuilder {
+    /// Sets the [`branch_name`](struct.DeleteFileInput.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_message`](struct.DeleteFileInput.html#structfield.commit_message) member.
+    pub fn commit_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email`](struct.DeleteFileInput.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_path`](struct.DeleteFileInput.html#structfield.file_path) member.
+    pub fn file_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`keep_empty_folders`](struct.DeleteFileInput.html#structfield.keep_empty_folders) member.
+    pub fn keep_empty_folders<T: Into<bool>>(mut self, value: T) -> Self {
+        self.keep_empty_folders = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DeleteFileInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_commit_id`](struct.DeleteFileInput.html#structfield.parent_commit_id) member.
+    pub fn parent_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.DeleteFileInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFileInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteFileInput, ::rusoto_core::BuildError> {
+        Ok(DeleteFileInput {
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            commit_message: self.commit_message,
+            email: self.email,
+            file_path: self
+                .file_path
+                .ok_or(::rusoto_core::BuildError::missing("file_path"))?,
+            keep_empty_folders: self.keep_empty_folders,
+            name: self.name,
+            parent_commit_id: self
+                .parent_commit_id
+                .ok_or(::rusoto_core::BuildError::missing("parent_commit_id"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteFileOutput {
@@ -712,6 +1673,36 @@ pub struct DeleteRepositoryInput {
     pub repository_name: String,
 }
 
+impl DeleteRepositoryInput {
+    /// Returns a builder for `DeleteRepositoryInput`, checking its required members when built.
+    pub fn builder() -> DeleteRepositoryInputBuilder {
+        DeleteRepositoryInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRepositoryInput`](struct.DeleteRepositoryInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteRepositoryInputBuilder {
+    repository_name: Option<String>,
+}
+
+impl DeleteRepositoryInputBuilder {
+    /// Sets the [`repository_name`](struct.DeleteRepositoryInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRepositoryInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteRepositoryInput, ::rusoto_core::BuildError> {
+        Ok(DeleteRepositoryInput {
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a delete repository operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -722,16 +1713,16 @@ pub struct DeleteRepositoryOutput {
     pub repository_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeMergeConflictsInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation will be successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit. For example, a branch name or a full commit ID.</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -744,7 +1735,7 @@ pub struct DescribeMergeConflictsInput {
     pub max_merge_hunks: Option<i64>,
     /// <p>The merge option or strategy you want to use to merge the code.</p>
     #[serde(rename = "mergeOption")]
-    pub merge_option: String,
+    pub merge_option: MergeOptionTypeEnum,
     /// <p>An enumeration token that when provided in a request, returns the next batch of the results.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -757,6 +1748,113 @@ pub struct DescribeMergeConflictsInput {
     pub source_commit_specifier: String,
 }
 
+impl DescribeMergeConflictsInput {
+    /// Returns a builder for `DescribeMergeConflictsInput`, checking its required members when built.
+    pub fn builder() -> DescribeMergeConflictsInputBuilder {
+        DescribeMergeConflictsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeMergeConflictsInput`](struct.DescribeMergeConflictsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeMergeConflictsInputBuilder {
+    conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
+    conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
+    destination_commit_specifier: Option<String>,
+    file_path: Option<String>,
+    max_merge_hunks: Option<i64>,
+    merge_option: Option<MergeOptionTypeEnum>,
+    next_token: Option<String>,
+    repository_name: Option<String>,
+    source_commit_specifier: Option<String>,
+}
+
+impl DescribeMergeConflictsInputBuilder {
+    /// Sets the [`conflict_detail_level`](struct.DescribeMergeConflictsInput.html#structfield.conflict_detail_level) member.
+    pub fn conflict_detail_level<T: Into<ConflictDetailLevelTypeEnum>>(mut self, value: T) -> Self {
+        self.conflict_detail_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution_strategy`](struct.DescribeMergeConflictsInput.html#structfield.conflict_resolution_strategy) member.
+    pub fn conflict_resolution_strategy<T: Into<ConflictResolutionStrategyTypeEnum>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.conflict_resolution_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_commit_specifier`](struct.DescribeMergeConflictsInput.html#structfield.destination_commit_specifier) member.
+    pub fn destination_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_path`](struct.DescribeMergeConflictsInput.html#structfield.file_path) member.
+    pub fn file_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_merge_hunks`](struct.DescribeMergeConflictsInput.html#structfield.max_merge_hunks) member.
+    pub fn max_merge_hunks<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_merge_hunks = Some(value.into());
+        self
+    }
+
+    /// Sets the [`merge_option`](struct.DescribeMergeConflictsInput.html#structfield.merge_option) member.
+    pub fn merge_option<T: Into<MergeOptionTypeEnum>>(mut self, value: T) -> Self {
+        self.merge_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeMergeConflictsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.DescribeMergeConflictsInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_commit_specifier`](struct.DescribeMergeConflictsInput.html#structfield.source_commit_specifier) member.
+    pub fn source_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeMergeConflictsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeMergeConflictsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeMergeConflictsInput {
+            conflict_detail_level: self.conflict_detail_level,
+            conflict_resolution_strategy: self.conflict_resolution_strategy,
+            destination_commit_specifier: self.destination_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("destination_commit_specifier"),
+            )?,
+            file_path: self
+                .file_path
+                .ok_or(::rusoto_core::BuildError::missing("file_path"))?,
+            max_merge_hunks: self.max_merge_hunks,
+            merge_option: self
+                .merge_option
+                .ok_or(::rusoto_core::BuildError::missing("merge_option"))?,
+            next_token: self.next_token,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            source_commit_specifier: self.source_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_commit_specifier"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeMergeConflictsOutput {
@@ -799,12 +1897,76 @@ pub struct DescribePullRequestEventsInput {
     /// <p>Optional. The pull request event type about which you want to return information.</p>
     #[serde(rename = "pullRequestEventType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_request_event_type: Option<String>,
+    pub pull_request_event_type: Option<PullRequestEventType>,
     /// <p>The system-generated ID of the pull request. To get this ID, use <a>ListPullRequests</a>.</p>
     #[serde(rename = "pullRequestId")]
     pub pull_request_id: String,
 }
 
+impl DescribePullRequestEventsInput {
+    /// Returns a builder for `DescribePullRequestEventsInput`, checking its required members when built.
+    pub fn builder() -> DescribePullRequestEventsInputBuilder {
+        DescribePullRequestEventsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePullRequestEventsInput`](struct.DescribePullRequestEventsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribePullRequestEventsInputBuilder {
+    actor_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    pull_request_event_type: Option<PullRequestEventType>,
+    pull_request_id: Option<String>,
+}
+
+impl DescribePullRequestEventsInputBuilder {
+    /// Sets the [`actor_arn`](struct.DescribePullRequestEventsInput.html#structfield.actor_arn) member.
+    pub fn actor_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.actor_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribePullRequestEventsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribePullRequestEventsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pull_request_event_type`](struct.DescribePullRequestEventsInput.html#structfield.pull_request_event_type) member.
+    pub fn pull_request_event_type<T: Into<PullRequestEventType>>(mut self, value: T) -> Self {
+        self.pull_request_event_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pull_request_id`](struct.DescribePullRequestEventsInput.html#structfield.pull_request_id) member.
+    pub fn pull_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pull_request_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePullRequestEventsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePullRequestEventsInput, ::rusoto_core::BuildError> {
+        Ok(DescribePullRequestEventsInput {
+            actor_arn: self.actor_arn,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            pull_request_event_type: self.pull_request_event_type,
+            pull_request_id: self
+                .pull_request_id
+                .ok_or(::rusoto_core::BuildError::missing("pull_request_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribePullRequestEventsOutput {
@@ -832,7 +1994,7 @@ pub struct Difference {
     /// <p>Whether the change type of the difference is an addition (A), deletion (D), or modification (M).</p>
     #[serde(rename = "changeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub change_type: Option<String>,
+    pub change_type: Option<ChangeTypeEnum>,
 }
 
 /// <p>Returns information about a file in a repository.</p>
@@ -850,7 +2012,7 @@ pub struct File {
     /// <p>The extrapolated file mode permissions for the file. Valid values include EXECUTABLE and NORMAL.</p>
     #[serde(rename = "fileMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_mode: Option<String>,
+    pub file_mode: Option<FileModeTypeEnum>,
     /// <p>The relative path of the file from the folder where the query originated.</p>
     #[serde(rename = "relativePath")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -872,7 +2034,83 @@ pub struct FileMetadata {
     /// <p>The extrapolated file mode permissions for the file. Valid values include EXECUTABLE and NORMAL.</p>
     #[serde(rename = "fileMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_mode: Option<String>,
+    pub file_mode: Option<FileModeTypeEnum>,
+}
+
+/// <p>The extrapolated file mode permissions for the file. Valid values include EXECUTABLE and NORMAL.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FileModeTypeEnum {
+    /// <p><code>EXECUTABLE</code></p>
+    Executable,
+    /// <p><code>NORMAL</code></p>
+    Normal,
+    /// <p><code>SYMLINK</code></p>
+    Symlink,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FileModeTypeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileModeTypeEnum::Executable => "EXECUTABLE",
+            FileModeTypeEnum::Normal => "NORMAL",
+            FileModeTypeEnum::Symlink => "SYMLINK",
+            FileModeTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FileModeTypeEnum {
+    fn from(value: &'a str) -> FileModeTypeEnum {
+        match value {
+            "EXECUTABLE" => FileModeTypeEnum::Executable,
+            "NORMAL" => FileModeTypeEnum::Normal,
+            "SYMLINK" => FileModeTypeEnum::Symlink,
+            _ => FileModeTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileModeTypeEnum {
+    fn from(value: String) -> FileModeTypeEnum {
+        FileModeTypeEnum::from(value.as_str())
+    }
+}
+
+impl From<FileModeTypeEnum> for String {
+    fn from(value: FileModeTypeEnum) -> String {
+        match value {
+            FileModeTypeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FileModeTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FileModeTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileModeTypeEnum {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FileModeTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FileModeTypeEnum::from(value))
+    }
 }
 
 /// <p>Information about file modes in a merge or pull request.</p>
@@ -882,15 +2120,15 @@ pub struct FileModes {
     /// <p>The file mode of a file in the base of a merge or pull request.</p>
     #[serde(rename = "base")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub base: Option<String>,
+    pub base: Option<FileModeTypeEnum>,
     /// <p>The file mode of a file in the destination of a merge or pull request.</p>
     #[serde(rename = "destination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination: Option<String>,
+    pub destination: Option<FileModeTypeEnum>,
     /// <p>The file mode of a file in the source of a merge or pull request.</p>
     #[serde(rename = "source")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<String>,
+    pub source: Option<FileModeTypeEnum>,
 }
 
 /// <p>Information about the size of files in a merge or pull request.</p>
@@ -940,6 +2178,46 @@ pub struct GetBlobInput {
     pub repository_name: String,
 }
 
+impl GetBlobInput {
+    /// Returns a builder for `GetBlobInput`, checking its required members when built.
+    pub fn builder() -> GetBlobInputBuilder {
+        GetBlobInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetBlobInput`](struct.GetBlobInput.html).
+#[derive(Default, Debug)]
+pub struct GetBlobInputBuilder {
+    blob_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl GetBlobInputBuilder {
+    /// Sets the [`blob_id`](struct.GetBlobInput.html#structfield.blob_id) member.
+    pub fn blob_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.blob_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetBlobInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBlobInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetBlobInput, ::rusoto_core::BuildError> {
+        Ok(GetBlobInput {
+            blob_id: self
+                .blob_id
+                .ok_or(::rusoto_core::BuildError::missing("blob_id"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a get blob operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -967,6 +2245,42 @@ pub struct GetBranchInput {
     pub repository_name: Option<String>,
 }
 
+impl GetBranchInput {
+    /// Returns a builder for `GetBranchInput`, checking its required members when built.
+    pub fn builder() -> GetBranchInputBuilder {
+        GetBranchInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetBranchInput`](struct.GetBranchInput.html).
+#[derive(Default, Debug)]
+pub struct GetBranchInputBuilder {
+    branch_name: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl GetBranchInputBuilder {
+    /// Sets the [`branch_name`](struct.GetBranchInput.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetBranchInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBranchInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetBranchInput, ::rusoto_core::BuildError> {
+        Ok(GetBranchInput {
+            branch_name: self.branch_name,
+            repository_name: self.repository_name,
+        })
+    }
+}
+
 /// <p>Represents the output of a get branch operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -984,6 +2298,36 @@ pub struct GetCommentInput {
     pub comment_id: String,
 }
 
+impl GetCommentInput {
+    /// Returns a builder for `GetCommentInput`, checking its required members when built.
+    pub fn builder() -> GetCommentInputBuilder {
+        GetCommentInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetCommentInput`](struct.GetCommentInput.html).
+#[derive(Default, Debug)]
+pub struct GetCommentInputBuilder {
+    comment_id: Option<String>,
+}
+
+impl GetCommentInputBuilder {
+    /// Sets the [`comment_id`](struct.GetCommentInput.html#structfield.comment_id) member.
+    pub fn comment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCommentInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetCommentInput, ::rusoto_core::BuildError> {
+        Ok(GetCommentInput {
+            comment_id: self
+                .comment_id
+                .ok_or(::rusoto_core::BuildError::missing("comment_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCommentOutput {
@@ -1015,6 +2359,72 @@ pub struct GetCommentsForComparedCommitInput {
     pub repository_name: String,
 }
 
+impl GetCommentsForComparedCommitInput {
+    /// Returns a builder for `GetCommentsForComparedCommitInput`, checking its required members when built.
+    pub fn builder() -> GetCommentsForComparedCommitInputBuilder {
+        GetCommentsForComparedCommitInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetCommentsForComparedCommitInput`](struct.GetCommentsForComparedCommitInput.html).
+#[derive(Default, Debug)]
+pub struct GetCommentsForComparedCommitInputBuilder {
+    after_commit_id: Option<String>,
+    before_commit_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl GetCommentsForComparedCommitInputBuilder {
+    /// Sets the [`after_commit_id`](struct.GetCommentsForComparedCommitInput.html#structfield.after_commit_id) member.
+    pub fn after_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.after_commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`before_commit_id`](struct.GetCommentsForComparedCommitInput.html#structfield.before_commit_id) member.
+    pub fn before_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.before_commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetCommentsForComparedCommitInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetCommentsForComparedCommitInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetCommentsForComparedCommitInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCommentsForComparedCommitInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCommentsForComparedCommitInput, ::rusoto_core::BuildError> {
+        Ok(GetCommentsForComparedCommitInput {
+            after_commit_id: self
+                .after_commit_id
+                .ok_or(::rusoto_core::BuildError::missing("after_commit_id"))?,
+            before_commit_id: self.before_commit_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCommentsForComparedCommitOutput {
@@ -1055,6 +2465,78 @@ pub struct GetCommentsForPullRequestInput {
     pub repository_name: Option<String>,
 }
 
+impl GetCommentsForPullRequestInput {
+    /// Returns a builder for `GetCommentsForPullRequestInput`, checking its required members when built.
+    pub fn builder() -> GetCommentsForPullRequestInputBuilder {
+        GetCommentsForPullRequestInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetCommentsForPullRequestInput`](struct.GetCommentsForPullRequestInput.html).
+#[derive(Default, Debug)]
+pub struct GetCommentsForPullRequestInputBuilder {
+    after_commit_id: Option<String>,
+    before_commit_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    pull_request_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl GetCommentsForPullRequestInputBuilder {
+    /// Sets the [`after_commit_id`](struct.GetCommentsForPullRequestInput.html#structfield.after_commit_id) member.
+    pub fn after_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.after_commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`before_commit_id`](struct.GetCommentsForPullRequestInput.html#structfield.before_commit_id) member.
+    pub fn before_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.before_commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetCommentsForPullRequestInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetCommentsForPullRequestInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pull_request_id`](struct.GetCommentsForPullRequestInput.html#structfield.pull_request_id) member.
+    pub fn pull_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pull_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetCommentsForPullRequestInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCommentsForPullRequestInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCommentsForPullRequestInput, ::rusoto_core::BuildError> {
+        Ok(GetCommentsForPullRequestInput {
+            after_commit_id: self.after_commit_id,
+            before_commit_id: self.before_commit_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            pull_request_id: self
+                .pull_request_id
+                .ok_or(::rusoto_core::BuildError::missing("pull_request_id"))?,
+            repository_name: self.repository_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCommentsForPullRequestOutput {
@@ -1079,6 +2561,46 @@ pub struct GetCommitInput {
     pub repository_name: String,
 }
 
+impl GetCommitInput {
+    /// Returns a builder for `GetCommitInput`, checking its required members when built.
+    pub fn builder() -> GetCommitInputBuilder {
+        GetCommitInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetCommitInput`](struct.GetCommitInput.html).
+#[derive(Default, Debug)]
+pub struct GetCommitInputBuilder {
+    commit_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl GetCommitInputBuilder {
+    /// Sets the [`commit_id`](struct.GetCommitInput.html#structfield.commit_id) member.
+    pub fn commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetCommitInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCommitInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetCommitInput, ::rusoto_core::BuildError> {
+        Ok(GetCommitInput {
+            commit_id: self
+                .commit_id
+                .ok_or(::rusoto_core::BuildError::missing("commit_id"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a get commit operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1118,6 +2640,86 @@ pub struct GetDifferencesInput {
     pub repository_name: String,
 }
 
+impl GetDifferencesInput {
+    /// Returns a builder for `GetDifferencesInput`, checking its required members when built.
+    pub fn builder() -> GetDifferencesInputBuilder {
+        GetDifferencesInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetDifferencesInput`](struct.GetDifferencesInput.html).
+#[derive(Default, Debug)]
+pub struct GetDifferencesInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    after_commit_specifier: Option<String>,
+    after_path: Option<String>,
+    before_commit_specifier: Option<String>,
+    before_path: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl GetDifferencesInputBuilder {
+    /// Sets the [`max_results`](struct.GetDifferencesInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetDifferencesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`after_commit_specifier`](struct.GetDifferencesInput.html#structfield.after_commit_specifier) member.
+    pub fn after_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.after_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`after_path`](struct.GetDifferencesInput.html#structfield.after_path) member.
+    pub fn after_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.after_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`before_commit_specifier`](struct.GetDifferencesInput.html#structfield.before_commit_specifier) member.
+    pub fn before_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.before_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`before_path`](struct.GetDifferencesInput.html#structfield.before_path) member.
+    pub fn before_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.before_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetDifferencesInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDifferencesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDifferencesInput, ::rusoto_core::BuildError> {
+        Ok(GetDifferencesInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            after_commit_specifier: self
+                .after_commit_specifier
+                .ok_or(::rusoto_core::BuildError::missing("after_commit_specifier"))?,
+            after_path: self.after_path,
+            before_commit_specifier: self.before_commit_specifier,
+            before_path: self.before_path,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDifferencesOutput {
@@ -1145,7 +2747,55 @@ pub struct GetFileInput {
     pub repository_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl GetFileInput {
+    /// Returns a builder for `GetFileInput`, checking its required members when built.
+    pub fn builder() -> GetFileInputBuilder {
+        GetFileInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetFileInput`](struct.GetFileInput.html).
+#[derive(Default, Debug)]
+pub struct GetFileInputBuilder {
+    commit_specifier: Option<String>,
+    file_path: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl GetFileInputBuilder {
+    /// Sets the [`commit_specifier`](struct.GetFileInput.html#structfield.commit_specifier) member.
+    pub fn commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_path`](struct.GetFileInput.html#structfield.file_path) member.
+    pub fn file_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetFileInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFileInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetFileInput, ::rusoto_core::BuildError> {
+        Ok(GetFileInput {
+            commit_specifier: self.commit_specifier,
+            file_path: self
+                .file_path
+                .ok_or(::rusoto_core::BuildError::missing("file_path"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFileOutput {
     /// <p>The blob ID of the object that represents the file content.</p>
@@ -1164,7 +2814,7 @@ pub struct GetFileOutput {
     pub file_content: bytes::Bytes,
     /// <p><p>The extrapolated file mode permissions of the blob. Valid values include strings such as EXECUTABLE and not numeric values.</p> <note> <p>The file mode permissions returned by this API are not the standard file mode permission values, such as 100644, but rather extrapolated values. See below for a full list of supported return values.</p> </note></p>
     #[serde(rename = "fileMode")]
-    pub file_mode: String,
+    pub file_mode: FileModeTypeEnum,
     /// <p>The fully qualified path to the specified file. This returns the name and extension of the file.</p>
     #[serde(rename = "filePath")]
     pub file_path: String,
@@ -1187,6 +2837,54 @@ pub struct GetFolderInput {
     pub repository_name: String,
 }
 
+impl GetFolderInput {
+    /// Returns a builder for `GetFolderInput`, checking its required members when built.
+    pub fn builder() -> GetFolderInputBuilder {
+        GetFolderInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetFolderInput`](struct.GetFolderInput.html).
+#[derive(Default, Debug)]
+pub struct GetFolderInputBuilder {
+    commit_specifier: Option<String>,
+    folder_path: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl GetFolderInputBuilder {
+    /// Sets the [`commit_specifier`](struct.GetFolderInput.html#structfield.commit_specifier) member.
+    pub fn commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`folder_path`](struct.GetFolderInput.html#structfield.folder_path) member.
+    pub fn folder_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.folder_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetFolderInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFolderInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetFolderInput, ::rusoto_core::BuildError> {
+        Ok(GetFolderInput {
+            commit_specifier: self.commit_specifier,
+            folder_path: self
+                .folder_path
+                .ok_or(::rusoto_core::BuildError::missing("folder_path"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFolderOutput {
@@ -1223,11 +2921,11 @@ pub struct GetMergeCommitInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation will be successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit. For example, a branch name or a full commit ID.</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -1239,6 +2937,75 @@ pub struct GetMergeCommitInput {
     pub source_commit_specifier: String,
 }
 
+impl GetMergeCommitInput {
+    /// Returns a builder for `GetMergeCommitInput`, checking its required members when built.
+    pub fn builder() -> GetMergeCommitInputBuilder {
+        GetMergeCommitInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetMergeCommitInput`](struct.GetMergeCommitInput.html).
+#[derive(Default, Debug)]
+pub struct GetMergeCommitInputBuilder {
+    conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
+    conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
+    destination_commit_specifier: Option<String>,
+    repository_name: Option<String>,
+    source_commit_specifier: Option<String>,
+}
+
+impl GetMergeCommitInputBuilder {
+    /// Sets the [`conflict_detail_level`](struct.GetMergeCommitInput.html#structfield.conflict_detail_level) member.
+    pub fn conflict_detail_level<T: Into<ConflictDetailLevelTypeEnum>>(mut self, value: T) -> Self {
+        self.conflict_detail_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution_strategy`](struct.GetMergeCommitInput.html#structfield.conflict_resolution_strategy) member.
+    pub fn conflict_resolution_strategy<T: Into<ConflictResolutionStrategyTypeEnum>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.conflict_resolution_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_commit_specifier`](struct.GetMergeCommitInput.html#structfield.destination_commit_specifier) member.
+    pub fn destination_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetMergeCommitInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_commit_specifier`](struct.GetMergeCommitInput.html#structfield.source_commit_specifier) member.
+    pub fn source_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMergeCommitInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMergeCommitInput, ::rusoto_core::BuildError> {
+        Ok(GetMergeCommitInput {
+            conflict_detail_level: self.conflict_detail_level,
+            conflict_resolution_strategy: self.conflict_resolution_strategy,
+            destination_commit_specifier: self.destination_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("destination_commit_specifier"),
+            )?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            source_commit_specifier: self.source_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_commit_specifier"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetMergeCommitOutput {
@@ -1260,16 +3027,16 @@ pub struct GetMergeCommitOutput {
     pub source_commit_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetMergeConflictsInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation will be successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit. For example, a branch name or a full commit ID.</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -1279,7 +3046,7 @@ pub struct GetMergeConflictsInput {
     pub max_conflict_files: Option<i64>,
     /// <p>The merge option or strategy you want to use to merge the code. </p>
     #[serde(rename = "mergeOption")]
-    pub merge_option: String,
+    pub merge_option: MergeOptionTypeEnum,
     /// <p>An enumeration token that when provided in a request, returns the next batch of the results.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1292,6 +3059,101 @@ pub struct GetMergeConflictsInput {
     pub source_commit_specifier: String,
 }
 
+impl GetMergeConflictsInput {
+    /// Returns a builder for `GetMergeConflictsInput`, checking its required members when built.
+    pub fn builder() -> GetMergeConflictsInputBuilder {
+        GetMergeConflictsInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetMergeConflictsInput`](struct.GetMergeConflictsInput.html).
+#[derive(Default, Debug)]
+pub struct GetMergeConflictsInputBuilder {
+    conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
+    conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
+    destination_commit_specifier: Option<String>,
+    max_conflict_files: Option<i64>,
+    merge_option: Option<MergeOptionTypeEnum>,
+    next_token: Option<String>,
+    repository_name: Option<String>,
+    source_commit_specifier: Option<String>,
+}
+
+impl GetMergeConflictsInputBuilder {
+    /// Sets the [`conflict_detail_level`](struct.GetMergeConflictsInput.html#structfield.conflict_detail_level) member.
+    pub fn conflict_detail_level<T: Into<ConflictDetailLevelTypeEnum>>(mut self, value: T) -> Self {
+        self.conflict_detail_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution_strategy`](struct.GetMergeConflictsInput.html#structfield.conflict_resolution_strategy) member.
+    pub fn conflict_resolution_strategy<T: Into<ConflictResolutionStrategyTypeEnum>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.conflict_resolution_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_commit_specifier`](struct.GetMergeConflictsInput.html#structfield.destination_commit_specifier) member.
+    pub fn destination_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_conflict_files`](struct.GetMergeConflictsInput.html#structfield.max_conflict_files) member.
+    pub fn max_conflict_files<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_conflict_files = Some(value.into());
+        self
+    }
+
+    /// Sets the [`merge_option`](struct.GetMergeConflictsInput.html#structfield.merge_option) member.
+    pub fn merge_option<T: Into<MergeOptionTypeEnum>>(mut self, value: T) -> Self {
+        self.merge_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetMergeConflictsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetMergeConflictsInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_commit_specifier`](struct.GetMergeConflictsInput.html#structfield.source_commit_specifier) member.
+    pub fn source_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMergeConflictsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMergeConflictsInput, ::rusoto_core::BuildError> {
+        Ok(GetMergeConflictsInput {
+            conflict_detail_level: self.conflict_detail_level,
+            conflict_resolution_strategy: self.conflict_resolution_strategy,
+            destination_commit_specifier: self.destination_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("destination_commit_specifier"),
+            )?,
+            max_conflict_files: self.max_conflict_files,
+            merge_option: self
+                .merge_option
+                .ok_or(::rusoto_core::BuildError::missing("merge_option"))?,
+            next_token: self.next_token,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            source_commit_specifier: self.source_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_commit_specifier"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetMergeConflictsOutput {
@@ -1322,11 +3184,11 @@ pub struct GetMergeOptionsInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation will be successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit. For example, a branch name or a full commit ID.</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -1338,6 +3200,75 @@ pub struct GetMergeOptionsInput {
     pub source_commit_specifier: String,
 }
 
+impl GetMergeOptionsInput {
+    /// Returns a builder for `GetMergeOptionsInput`, checking its required members when built.
+    pub fn builder() -> GetMergeOptionsInputBuilder {
+        GetMergeOptionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetMergeOptionsInput`](struct.GetMergeOptionsInput.html).
+#[derive(Default, Debug)]
+pub struct GetMergeOptionsInputBuilder {
+    conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
+    conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
+    destination_commit_specifier: Option<String>,
+    repository_name: Option<String>,
+    source_commit_specifier: Option<String>,
+}
+
+impl GetMergeOptionsInputBuilder {
+    /// Sets the [`conflict_detail_level`](struct.GetMergeOptionsInput.html#structfield.conflict_detail_level) member.
+    pub fn conflict_detail_level<T: Into<ConflictDetailLevelTypeEnum>>(mut self, value: T) -> Self {
+        self.conflict_detail_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution_strategy`](struct.GetMergeOptionsInput.html#structfield.conflict_resolution_strategy) member.
+    pub fn conflict_resolution_strategy<T: Into<ConflictResolutionStrategyTypeEnum>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.conflict_resolution_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_commit_specifier`](struct.GetMergeOptionsInput.html#structfield.destination_commit_specifier) member.
+    pub fn destination_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetMergeOptionsInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_commit_specifier`](struct.GetMergeOptionsInput.html#structfield.source_commit_specifier) member.
+    pub fn source_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMergeOptionsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMergeOptionsInput, ::rusoto_core::BuildError> {
+        Ok(GetMergeOptionsInput {
+            conflict_detail_level: self.conflict_detail_level,
+            conflict_resolution_strategy: self.conflict_resolution_strategy,
+            destination_commit_specifier: self.destination_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("destination_commit_specifier"),
+            )?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            source_commit_specifier: self.source_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_commit_specifier"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetMergeOptionsOutput {
@@ -1349,7 +3280,7 @@ pub struct GetMergeOptionsOutput {
     pub destination_commit_id: String,
     /// <p>The merge option or strategy used to merge the code.</p>
     #[serde(rename = "mergeOptions")]
-    pub merge_options: Vec<String>,
+    pub merge_options: Vec<MergeOptionTypeEnum>,
     /// <p>The commit ID of the source commit specifier that was used in the merge evaluation.</p>
     #[serde(rename = "sourceCommitId")]
     pub source_commit_id: String,
@@ -1362,6 +3293,36 @@ pub struct GetPullRequestInput {
     pub pull_request_id: String,
 }
 
+impl GetPullRequestInput {
+    /// Returns a builder for `GetPullRequestInput`, checking its required members when built.
+    pub fn builder() -> GetPullRequestInputBuilder {
+        GetPullRequestInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetPullRequestInput`](struct.GetPullRequestInput.html).
+#[derive(Default, Debug)]
+pub struct GetPullRequestInputBuilder {
+    pull_request_id: Option<String>,
+}
+
+impl GetPullRequestInputBuilder {
+    /// Sets the [`pull_request_id`](struct.GetPullRequestInput.html#structfield.pull_request_id) member.
+    pub fn pull_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pull_request_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPullRequestInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetPullRequestInput, ::rusoto_core::BuildError> {
+        Ok(GetPullRequestInput {
+            pull_request_id: self
+                .pull_request_id
+                .ok_or(::rusoto_core::BuildError::missing("pull_request_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPullRequestOutput {
@@ -1378,6 +3339,36 @@ pub struct GetRepositoryInput {
     pub repository_name: String,
 }
 
+impl GetRepositoryInput {
+    /// Returns a builder for `GetRepositoryInput`, checking its required members when built.
+    pub fn builder() -> GetRepositoryInputBuilder {
+        GetRepositoryInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetRepositoryInput`](struct.GetRepositoryInput.html).
+#[derive(Default, Debug)]
+pub struct GetRepositoryInputBuilder {
+    repository_name: Option<String>,
+}
+
+impl GetRepositoryInputBuilder {
+    /// Sets the [`repository_name`](struct.GetRepositoryInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRepositoryInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRepositoryInput, ::rusoto_core::BuildError> {
+        Ok(GetRepositoryInput {
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a get repository operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1396,6 +3387,38 @@ pub struct GetRepositoryTriggersInput {
     pub repository_name: String,
 }
 
+impl GetRepositoryTriggersInput {
+    /// Returns a builder for `GetRepositoryTriggersInput`, checking its required members when built.
+    pub fn builder() -> GetRepositoryTriggersInputBuilder {
+        GetRepositoryTriggersInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetRepositoryTriggersInput`](struct.GetRepositoryTriggersInput.html).
+#[derive(Default, Debug)]
+pub struct GetRepositoryTriggersInputBuilder {
+    repository_name: Option<String>,
+}
+
+impl GetRepositoryTriggersInputBuilder {
+    /// Sets the [`repository_name`](struct.GetRepositoryTriggersInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRepositoryTriggersInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRepositoryTriggersInput, ::rusoto_core::BuildError> {
+        Ok(GetRepositoryTriggersInput {
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a get repository triggers operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1440,6 +3463,44 @@ pub struct ListBranchesInput {
     pub repository_name: String,
 }
 
+impl ListBranchesInput {
+    /// Returns a builder for `ListBranchesInput`, checking its required members when built.
+    pub fn builder() -> ListBranchesInputBuilder {
+        ListBranchesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListBranchesInput`](struct.ListBranchesInput.html).
+#[derive(Default, Debug)]
+pub struct ListBranchesInputBuilder {
+    next_token: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl ListBranchesInputBuilder {
+    /// Sets the [`next_token`](struct.ListBranchesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.ListBranchesInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBranchesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListBranchesInput, ::rusoto_core::BuildError> {
+        Ok(ListBranchesInput {
+            next_token: self.next_token,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a list branches operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1471,12 +3532,74 @@ pub struct ListPullRequestsInput {
     /// <p>Optional. The status of the pull request. If used, this refines the results to the pull requests that match the specified status.</p>
     #[serde(rename = "pullRequestStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_request_status: Option<String>,
+    pub pull_request_status: Option<PullRequestStatusEnum>,
     /// <p>The name of the repository for which you want to list pull requests.</p>
     #[serde(rename = "repositoryName")]
     pub repository_name: String,
 }
 
+impl ListPullRequestsInput {
+    /// Returns a builder for `ListPullRequestsInput`, checking its required members when built.
+    pub fn builder() -> ListPullRequestsInputBuilder {
+        ListPullRequestsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListPullRequestsInput`](struct.ListPullRequestsInput.html).
+#[derive(Default, Debug)]
+pub struct ListPullRequestsInputBuilder {
+    author_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    pull_request_status: Option<PullRequestStatusEnum>,
+    repository_name: Option<String>,
+}
+
+impl ListPullRequestsInputBuilder {
+    /// Sets the [`author_arn`](struct.ListPullRequestsInput.html#structfield.author_arn) member.
+    pub fn author_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.author_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListPullRequestsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPullRequestsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pull_request_status`](struct.ListPullRequestsInput.html#structfield.pull_request_status) member.
+    pub fn pull_request_status<T: Into<PullRequestStatusEnum>>(mut self, value: T) -> Self {
+        self.pull_request_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.ListPullRequestsInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPullRequestsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPullRequestsInput, ::rusoto_core::BuildError> {
+        Ok(ListPullRequestsInput {
+            author_arn: self.author_arn,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            pull_request_status: self.pull_request_status,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPullRequestsOutput {
@@ -1499,11 +3622,55 @@ pub struct ListRepositoriesInput {
     /// <p>The order in which to sort the results of a list repositories operation.</p>
     #[serde(rename = "order")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    pub order: Option<OrderEnum>,
     /// <p>The criteria used to sort the results of a list repositories operation.</p>
     #[serde(rename = "sortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<SortByEnum>,
+}
+
+impl ListRepositoriesInput {
+    /// Returns a builder for `ListRepositoriesInput`, checking its required members when built.
+    pub fn builder() -> ListRepositoriesInputBuilder {
+        ListRepositoriesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListRepositoriesInput`](struct.ListRepositoriesInput.html).
+#[derive(Default, Debug)]
+pub struct ListRepositoriesInputBuilder {
+    next_token: Option<String>,
+    order: Option<OrderEnum>,
+    sort_by: Option<SortByEnum>,
+}
+
+impl ListRepositoriesInputBuilder {
+    /// Sets the [`next_token`](struct.ListRepositoriesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`order`](struct.ListRepositoriesInput.html#structfield.order) member.
+    pub fn order<T: Into<OrderEnum>>(mut self, value: T) -> Self {
+        self.order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_by`](struct.ListRepositoriesInput.html#structfield.sort_by) member.
+    pub fn sort_by<T: Into<SortByEnum>>(mut self, value: T) -> Self {
+        self.sort_by = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRepositoriesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListRepositoriesInput, ::rusoto_core::BuildError> {
+        Ok(ListRepositoriesInput {
+            next_token: self.next_token,
+            order: self.order,
+            sort_by: self.sort_by,
+        })
+    }
 }
 
 /// <p>Represents the output of a list repositories operation.</p>
@@ -1531,6 +3698,46 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceInput {
+    /// Returns a builder for `ListTagsForResourceInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceInputBuilder {
+        ListTagsForResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceInput`](struct.ListTagsForResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceInputBuilder {
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceInputBuilder {
+    /// Sets the [`next_token`](struct.ListTagsForResourceInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceInput {
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceOutput {
@@ -1558,7 +3765,7 @@ pub struct Location {
     /// <p>In a comparison of commits or a pull request, whether the change is in the 'before' or 'after' of that comparison.</p>
     #[serde(rename = "relativeFileVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub relative_file_version: Option<String>,
+    pub relative_file_version: Option<RelativeFileVersionEnum>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1578,6 +3785,66 @@ pub struct MergeBranchesByFastForwardInput {
     pub target_branch: Option<String>,
 }
 
+impl MergeBranchesByFastForwardInput {
+    /// Returns a builder for `MergeBranchesByFastForwardInput`, checking its required members when built.
+    pub fn builder() -> MergeBranchesByFastForwardInputBuilder {
+        MergeBranchesByFastForwardInputBuilder::default()
+    }
+}
+
+/// Builds a [`MergeBranchesByFastForwardInput`](struct.MergeBranchesByFastForwardInput.html).
+#[derive(Default, Debug)]
+pub struct MergeBranchesByFastForwardInputBuilder {
+    destination_commit_specifier: Option<String>,
+    repository_name: Option<String>,
+    source_commit_specifier: Option<String>,
+    target_branch: Option<String>,
+}
+
+impl MergeBranchesByFastForwardInputBuilder {
+    /// Sets the [`destination_commit_specifier`](struct.MergeBranchesByFastForwardInput.html#structfield.destination_commit_specifier) member.
+    pub fn destination_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.MergeBranchesByFastForwardInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_commit_specifier`](struct.MergeBranchesByFastForwardInput.html#structfield.source_commit_specifier) member.
+    pub fn source_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_branch`](struct.MergeBranchesByFastForwardInput.html#structfield.target_branch) member.
+    pub fn target_branch<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_branch = Some(value.into());
+        self
+    }
+
+    /// Builds the `MergeBranchesByFastForwardInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<MergeBranchesByFastForwardInput, ::rusoto_core::BuildError> {
+        Ok(MergeBranchesByFastForwardInput {
+            destination_commit_specifier: self.destination_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("destination_commit_specifier"),
+            )?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            source_commit_specifier: self.source_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_commit_specifier"),
+            )?,
+            target_branch: self.target_branch,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct MergeBranchesByFastForwardOutput {
@@ -1604,7 +3871,7 @@ pub struct MergeBranchesBySquashInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>A list of inputs to use when resolving conflicts during a merge if AUTOMERGE is chosen as the conflict resolution strategy.</p>
     #[serde(rename = "conflictResolution")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1612,7 +3879,7 @@ pub struct MergeBranchesBySquashInput {
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation will be successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit. For example, a branch name or a full commit ID.</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -1636,6 +3903,125 @@ pub struct MergeBranchesBySquashInput {
     pub target_branch: Option<String>,
 }
 
+impl MergeBranchesBySquashInput {
+    /// Returns a builder for `MergeBranchesBySquashInput`, checking its required members when built.
+    pub fn builder() -> MergeBranchesBySquashInputBuilder {
+        MergeBranchesBySquashInputBuilder::default()
+    }
+}
+
+/// Builds a [`MergeBranchesBySquashInput`](struct.MergeBranchesBySquashInput.html).
+#[derive(Default, Debug)]
+pub struct MergeBranchesBySquashInputBuilder {
+    author_name: Option<String>,
+    commit_message: Option<String>,
+    conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
+    conflict_resolution: Option<ConflictResolution>,
+    conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
+    destination_commit_specifier: Option<String>,
+    email: Option<String>,
+    keep_empty_folders: Option<bool>,
+    repository_name: Option<String>,
+    source_commit_specifier: Option<String>,
+    target_branch: Option<String>,
+}
+
+impl MergeBranchesBySquashInputBuilder {
+    /// Sets the [`author_name`](struct.MergeBranchesBySquashInput.html#structfield.author_name) member.
+    pub fn author_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.author_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_message`](struct.MergeBranchesBySquashInput.html#structfield.commit_message) member.
+    pub fn commit_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_detail_level`](struct.MergeBranchesBySquashInput.html#structfield.conflict_detail_level) member.
+    pub fn conflict_detail_level<T: Into<ConflictDetailLevelTypeEnum>>(mut self, value: T) -> Self {
+        self.conflict_detail_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution`](struct.MergeBranchesBySquashInput.html#structfield.conflict_resolution) member.
+    pub fn conflict_resolution<T: Into<ConflictResolution>>(mut self, value: T) -> Self {
+        self.conflict_resolution = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution_strategy`](struct.MergeBranchesBySquashInput.html#structfield.conflict_resolution_strategy) member.
+    pub fn conflict_resolution_strategy<T: Into<ConflictResolutionStrategyTypeEnum>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.conflict_resolution_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_commit_specifier`](struct.MergeBranchesBySquashInput.html#structfield.destination_commit_specifier) member.
+    pub fn destination_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email`](struct.MergeBranchesBySquashInput.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`keep_empty_folders`](struct.MergeBranchesBySquashInput.html#structfield.keep_empty_folders) member.
+    pub fn keep_empty_folders<T: Into<bool>>(mut self, value: T) -> Self {
+        self.keep_empty_folders = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.MergeBranchesBySquashInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_commit_specifier`](struct.MergeBranchesBySquashInput.html#structfield.source_commit_specifier) member.
+    pub fn source_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_branch`](struct.MergeBranchesBySquashInput.html#structfield.target_branch) member.
+    pub fn target_branch<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_branch = Some(value.into());
+        self
+    }
+
+    /// Builds the `MergeBranchesBySquashInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<MergeBranchesBySquashInput, ::rusoto_core::BuildError> {
+        Ok(MergeBranchesBySquashInput {
+            author_name: self.author_name,
+            commit_message: self.commit_message,
+            conflict_detail_level: self.conflict_detail_level,
+            conflict_resolution: self.conflict_resolution,
+            conflict_resolution_strategy: self.conflict_resolution_strategy,
+            destination_commit_specifier: self.destination_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("destination_commit_specifier"),
+            )?,
+            email: self.email,
+            keep_empty_folders: self.keep_empty_folders,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            source_commit_specifier: self.source_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_commit_specifier"),
+            )?,
+            target_branch: self.target_branch,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct MergeBranchesBySquashOutput {
@@ -1662,7 +4048,7 @@ pub struct MergeBranchesByThreeWayInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>A list of inputs to use when resolving conflicts during a merge if AUTOMERGE is chosen as the conflict resolution strategy.</p>
     #[serde(rename = "conflictResolution")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1670,7 +4056,7 @@ pub struct MergeBranchesByThreeWayInput {
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation will be successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The branch, tag, HEAD, or other fully qualified reference used to identify a commit. For example, a branch name or a full commit ID.</p>
     #[serde(rename = "destinationCommitSpecifier")]
     pub destination_commit_specifier: String,
@@ -1694,31 +4080,150 @@ pub struct MergeBranchesByThreeWayInput {
     pub target_branch: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct MergeBranchesByThreeWayOutput {
-    /// <p>The commit ID of the merge in the destination or target branch.</p>
-    #[serde(rename = "commitId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub commit_id: Option<String>,
-    /// <p>The tree ID of the merge in the destination or target branch.</p>
-    #[serde(rename = "treeId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tree_id: Option<String>,
+impl MergeBranchesByThreeWayInput {
+    /// Returns a builder for `MergeBranchesByThreeWayInput`, checking its required members when built.
+    pub fn builder() -> MergeBranchesByThreeWayInputBuilder {
+        MergeBranchesByThreeWayInputBuilder::default()
+    }
 }
 
-/// <p>Information about merge hunks in a merge or pull request operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct MergeHunk {
-    /// <p>Information about the merge hunk in the base of a merge or pull request.</p>
-    #[serde(rename = "base")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub base: Option<MergeHunkDetail>,
-    /// <p>Information about the merge hunk in the destination of a merge or pull request.</p>
-    #[serde(rename = "destination")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination: Option<MergeHunkDetail>,
+/// Builds a [`MergeBranchesByThreeWayInput`](struct.MergeBranchesByThreeWayInput.html).
+#[derive(Default, Debug)]
+pub struct MergeBranchesByThreeWayInputBuilder {
+    author_name: Option<String>,
+    commit_message: Option<String>,
+    conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
+    conflict_resolution: Option<ConflictResolution>,
+    conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
+    destination_commit_specifier: Option<String>,
+    email: Option<String>,
+    keep_empty_folders: Option<bool>,
+    repository_name: Option<String>,
+    source_commit_specifier: Option<String>,
+    target_branch: Option<String>,
+}
+
+impl MergeBranchesByThreeWayInputBuilder {
+    /// Sets the [`author_name`](struct.MergeBranchesByThreeWayInput.html#structfield.author_name) member.
+    pub fn author_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.author_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_message`](struct.MergeBranchesByThreeWayInput.html#structfield.commit_message) member.
+    pub fn commit_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_detail_level`](struct.MergeBranchesByThreeWayInput.html#structfield.conflict_detail_level) member.
+    pub fn conflict_detail_level<T: Into<ConflictDetailLevelTypeEnum>>(mut self, value: T) -> Self {
+        self.conflict_detail_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution`](struct.MergeBranchesByThreeWayInput.html#structfield.conflict_resolution) member.
+    pub fn conflict_resolution<T: Into<ConflictResolution>>(mut self, value: T) -> Self {
+        self.conflict_resolution = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution_strategy`](struct.MergeBranchesByThreeWayInput.html#structfield.conflict_resolution_strategy) member.
+    pub fn conflict_resolution_strategy<T: Into<ConflictResolutionStrategyTypeEnum>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.conflict_resolution_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_commit_specifier`](struct.MergeBranchesByThreeWayInput.html#structfield.destination_commit_specifier) member.
+    pub fn destination_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email`](struct.MergeBranchesByThreeWayInput.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`keep_empty_folders`](struct.MergeBranchesByThreeWayInput.html#structfield.keep_empty_folders) member.
+    pub fn keep_empty_folders<T: Into<bool>>(mut self, value: T) -> Self {
+        self.keep_empty_folders = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.MergeBranchesByThreeWayInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_commit_specifier`](struct.MergeBranchesByThreeWayInput.html#structfield.source_commit_specifier) member.
+    pub fn source_commit_specifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_commit_specifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_branch`](struct.MergeBranchesByThreeWayInput.html#structfield.target_branch) member.
+    pub fn target_branch<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_branch = Some(value.into());
+        self
+    }
+
+    /// Builds the `MergeBranchesByThreeWayInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<MergeBranchesByThreeWayInput, ::rusoto_core::BuildError> {
+        Ok(MergeBranchesByThreeWayInput {
+            author_name: self.author_name,
+            commit_message: self.commit_message,
+            conflict_detail_level: self.conflict_detail_level,
+            conflict_resolution: self.conflict_resolution,
+            conflict_resolution_strategy: self.conflict_resolution_strategy,
+            destination_commit_specifier: self.destination_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("destination_commit_specifier"),
+            )?,
+            email: self.email,
+            keep_empty_folders: self.keep_empty_folders,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            source_commit_specifier: self.source_commit_specifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_commit_specifier"),
+            )?,
+            target_branch: self.target_branch,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct MergeBranchesByThreeWayOutput {
+    /// <p>The commit ID of the merge in the destination or target branch.</p>
+    #[serde(rename = "commitId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_id: Option<String>,
+    /// <p>The tree ID of the merge in the destination or target branch.</p>
+    #[serde(rename = "treeId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tree_id: Option<String>,
+}
+
+/// <p>Information about merge hunks in a merge or pull request operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct MergeHunk {
+    /// <p>Information about the merge hunk in the base of a merge or pull request.</p>
+    #[serde(rename = "base")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<MergeHunkDetail>,
+    /// <p>Information about the merge hunk in the destination of a merge or pull request.</p>
+    #[serde(rename = "destination")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<MergeHunkDetail>,
     /// <p>A Boolean value indicating whether a combination of hunks contains a conflict. Conflicts occur when the same file or the same lines in a file were modified in both the source and destination of a merge or pull request. Valid values include true, false, and null. This will be true when the hunk represents a conflict and one or more files contains a line conflict. File mode conflicts in a merge will not set this to be true.</p>
     #[serde(rename = "isConflict")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1762,7 +4267,7 @@ pub struct MergeMetadata {
     /// <p>The merge strategy used in the merge.</p>
     #[serde(rename = "mergeOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub merge_option: Option<String>,
+    pub merge_option: Option<MergeOptionTypeEnum>,
     /// <p>The Amazon Resource Name (ARN) of the user who merged the branches.</p>
     #[serde(rename = "mergedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1776,11 +4281,87 @@ pub struct MergeOperations {
     /// <p>The operation on a file in the destination of a merge or pull request.</p>
     #[serde(rename = "destination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination: Option<String>,
+    pub destination: Option<ChangeTypeEnum>,
     /// <p>The operation on a file (add, modify, or delete) of a file in the source of a merge or pull request.</p>
     #[serde(rename = "source")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<String>,
+    pub source: Option<ChangeTypeEnum>,
+}
+
+/// <p>The merge option or strategy you want to use to merge the code.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MergeOptionTypeEnum {
+    /// <p><code>FAST_FORWARD_MERGE</code></p>
+    FastForwardMerge,
+    /// <p><code>SQUASH_MERGE</code></p>
+    SquashMerge,
+    /// <p><code>THREE_WAY_MERGE</code></p>
+    ThreeWayMerge,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MergeOptionTypeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MergeOptionTypeEnum::FastForwardMerge => "FAST_FORWARD_MERGE",
+            MergeOptionTypeEnum::SquashMerge => "SQUASH_MERGE",
+            MergeOptionTypeEnum::ThreeWayMerge => "THREE_WAY_MERGE",
+            MergeOptionTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MergeOptionTypeEnum {
+    fn from(value: &'a str) -> MergeOptionTypeEnum {
+        match value {
+            "FAST_FORWARD_MERGE" => MergeOptionTypeEnum::FastForwardMerge,
+            "SQUASH_MERGE" => MergeOptionTypeEnum::SquashMerge,
+            "THREE_WAY_MERGE" => MergeOptionTypeEnum::ThreeWayMerge,
+            _ => MergeOptionTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MergeOptionTypeEnum {
+    fn from(value: String) -> MergeOptionTypeEnum {
+        MergeOptionTypeEnum::from(value.as_str())
+    }
+}
+
+impl From<MergeOptionTypeEnum> for String {
+    fn from(value: MergeOptionTypeEnum) -> String {
+        match value {
+            MergeOptionTypeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MergeOptionTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MergeOptionTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MergeOptionTypeEnum {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MergeOptionTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MergeOptionTypeEnum::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1797,6 +4378,56 @@ pub struct MergePullRequestByFastForwardInput {
     pub source_commit_id: Option<String>,
 }
 
+impl MergePullRequestByFastForwardInput {
+    /// Returns a builder for `MergePullRequestByFastForwardInput`, checking its required members when built.
+    pub fn builder() -> MergePullRequestByFastForwardInputBuilder {
+        MergePullRequestByFastForwardInputBuilder::default()
+    }
+}
+
+/// Builds a [`MergePullRequestByFastForwardInput`](struct.MergePullRequestByFastForwardInput.html).
+#[derive(Default, Debug)]
+pub struct MergePullRequestByFastForwardInputBuilder {
+    pull_request_id: Option<String>,
+    repository_name: Option<String>,
+    source_commit_id: Option<String>,
+}
+
+impl MergePullRequestByFastForwardInputBuilder {
+    /// Sets the [`pull_request_id`](struct.MergePullRequestByFastForwardInput.html#structfield.pull_request_id) member.
+    pub fn pull_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pull_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.MergePullRequestByFastForwardInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_commit_id`](struct.MergePullRequestByFastForwardInput.html#structfield.source_commit_id) member.
+    pub fn source_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_commit_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `MergePullRequestByFastForwardInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<MergePullRequestByFastForwardInput, ::rusoto_core::BuildError> {
+        Ok(MergePullRequestByFastForwardInput {
+            pull_request_id: self
+                .pull_request_id
+                .ok_or(::rusoto_core::BuildError::missing("pull_request_id"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            source_commit_id: self.source_commit_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct MergePullRequestByFastForwardOutput {
@@ -1819,7 +4450,7 @@ pub struct MergePullRequestBySquashInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>A list of inputs to use when resolving conflicts during a merge if AUTOMERGE is chosen as the conflict resolution strategy.</p>
     #[serde(rename = "conflictResolution")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1827,7 +4458,7 @@ pub struct MergePullRequestBySquashInput {
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation will be successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The email address of the person merging the branches. This information will be used in the commit information for the merge.</p>
     #[serde(rename = "email")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1848,6 +4479,115 @@ pub struct MergePullRequestBySquashInput {
     pub source_commit_id: Option<String>,
 }
 
+impl MergePullRequestBySquashInput {
+    /// Returns a builder for `MergePullRequestBySquashInput`, checking its required members when built.
+    pub fn builder() -> MergePullRequestBySquashInputBuilder {
+        MergePullRequestBySquashInputBuilder::default()
+    }
+}
+
+/// Builds a [`MergePullRequestBySquashInput`](struct.MergePullRequestBySquashInput.html).
+#[derive(Default, Debug)]
+pub struct MergePullRequestBySquashInputBuilder {
+    author_name: Option<String>,
+    commit_message: Option<String>,
+    conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
+    conflict_resolution: Option<ConflictResolution>,
+    conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
+    email: Option<String>,
+    keep_empty_folders: Option<bool>,
+    pull_request_id: Option<String>,
+    repository_name: Option<String>,
+    source_commit_id: Option<String>,
+}
+
+impl MergePullRequestBySquashInputBuilder {
+    /// Sets the [`author_name`](struct.MergePullRequestBySquashInput.html#structfield.author_name) member.
+    pub fn author_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.author_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_message`](struct.MergePullRequestBySquashInput.html#structfield.commit_message) member.
+    pub fn commit_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_detail_level`](struct.MergePullRequestBySquashInput.html#structfield.conflict_detail_level) member.
+    pub fn conflict_detail_level<T: Into<ConflictDetailLevelTypeEnum>>(mut self, value: T) -> Self {
+        self.conflict_detail_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution`](struct.MergePullRequestBySquashInput.html#structfield.conflict_resolution) member.
+    pub fn conflict_resolution<T: Into<ConflictResolution>>(mut self, value: T) -> Self {
+        self.conflict_resolution = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution_strategy`](struct.MergePullRequestBySquashInput.html#structfield.conflict_resolution_strategy) member.
+    pub fn conflict_resolution_strategy<T: Into<ConflictResolutionStrategyTypeEnum>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.conflict_resolution_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email`](struct.MergePullRequestBySquashInput.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`keep_empty_folders`](struct.MergePullRequestBySquashInput.html#structfield.keep_empty_folders) member.
+    pub fn keep_empty_folders<T: Into<bool>>(mut self, value: T) -> Self {
+        self.keep_empty_folders = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pull_request_id`](struct.MergePullRequestBySquashInput.html#structfield.pull_request_id) member.
+    pub fn pull_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pull_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.MergePullRequestBySquashInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_commit_id`](struct.MergePullRequestBySquashInput.html#structfield.source_commit_id) member.
+    pub fn source_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_commit_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `MergePullRequestBySquashInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<MergePullRequestBySquashInput, ::rusoto_core::BuildError> {
+        Ok(MergePullRequestBySquashInput {
+            author_name: self.author_name,
+            commit_message: self.commit_message,
+            conflict_detail_level: self.conflict_detail_level,
+            conflict_resolution: self.conflict_resolution,
+            conflict_resolution_strategy: self.conflict_resolution_strategy,
+            email: self.email,
+            keep_empty_folders: self.keep_empty_folders,
+            pull_request_id: self
+                .pull_request_id
+                .ok_or(::rusoto_core::BuildError::missing("pull_request_id"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            source_commit_id: self.source_commit_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct MergePullRequestBySquashOutput {
@@ -1869,7 +4609,7 @@ pub struct MergePullRequestByThreeWayInput {
     /// <p>The level of conflict detail to use. If unspecified, the default FILE_LEVEL is used, which will return a not mergeable result if the same file has differences in both branches. If LINE_LEVEL is specified, a conflict will be considered not mergeable if the same file in both branches has differences on the same line.</p>
     #[serde(rename = "conflictDetailLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_detail_level: Option<String>,
+    pub conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
     /// <p>A list of inputs to use when resolving conflicts during a merge if AUTOMERGE is chosen as the conflict resolution strategy.</p>
     #[serde(rename = "conflictResolution")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1877,7 +4617,7 @@ pub struct MergePullRequestByThreeWayInput {
     /// <p>Specifies which branch to use when resolving conflicts, or whether to attempt automatically merging two versions of a file. The default is NONE, which requires any conflicts to be resolved manually before the merge operation will be successful.</p>
     #[serde(rename = "conflictResolutionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conflict_resolution_strategy: Option<String>,
+    pub conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
     /// <p>The email address of the person merging the branches. This information will be used in the commit information for the merge.</p>
     #[serde(rename = "email")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1898,6 +4638,115 @@ pub struct MergePullRequestByThreeWayInput {
     pub source_commit_id: Option<String>,
 }
 
+impl MergePullRequestByThreeWayInput {
+    /// Returns a builder for `MergePullRequestByThreeWayInput`, checking its required members when built.
+    pub fn builder() -> MergePullRequestByThreeWayInputBuilder {
+        MergePullRequestByThreeWayInputBuilder::default()
+    }
+}
+
+/// Builds a [`MergePullRequestByThreeWayInput`](struct.MergePullRequestByThreeWayInput.html).
+#[derive(Default, Debug)]
+pub struct MergePullRequestByThreeWayInputBuilder {
+    author_name: Option<String>,
+    commit_message: Option<String>,
+    conflict_detail_level: Option<ConflictDetailLevelTypeEnum>,
+    conflict_resolution: Option<ConflictResolution>,
+    conflict_resolution_strategy: Option<ConflictResolutionStrategyTypeEnum>,
+    email: Option<String>,
+    keep_empty_folders: Option<bool>,
+    pull_request_id: Option<String>,
+    repository_name: Option<String>,
+    source_commit_id: Option<String>,
+}
+
+impl MergePullRequestByThreeWayInputBuilder {
+    /// Sets the [`author_name`](struct.MergePullRequestByThreeWayInput.html#structfield.author_name) member.
+    pub fn author_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.author_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_message`](struct.MergePullRequestByThreeWayInput.html#structfield.commit_message) member.
+    pub fn commit_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_detail_level`](struct.MergePullRequestByThreeWayInput.html#structfield.conflict_detail_level) member.
+    pub fn conflict_detail_level<T: Into<ConflictDetailLevelTypeEnum>>(mut self, value: T) -> Self {
+        self.conflict_detail_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution`](struct.MergePullRequestByThreeWayInput.html#structfield.conflict_resolution) member.
+    pub fn conflict_resolution<T: Into<ConflictResolution>>(mut self, value: T) -> Self {
+        self.conflict_resolution = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conflict_resolution_strategy`](struct.MergePullRequestByThreeWayInput.html#structfield.conflict_resolution_strategy) member.
+    pub fn conflict_resolution_strategy<T: Into<ConflictResolutionStrategyTypeEnum>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.conflict_resolution_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email`](struct.MergePullRequestByThreeWayInput.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`keep_empty_folders`](struct.MergePullRequestByThreeWayInput.html#structfield.keep_empty_folders) member.
+    pub fn keep_empty_folders<T: Into<bool>>(mut self, value: T) -> Self {
+        self.keep_empty_folders = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pull_request_id`](struct.MergePullRequestByThreeWayInput.html#structfield.pull_request_id) member.
+    pub fn pull_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pull_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.MergePullRequestByThreeWayInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_commit_id`](struct.MergePullRequestByThreeWayInput.html#structfield.source_commit_id) member.
+    pub fn source_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_commit_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `MergePullRequestByThreeWayInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<MergePullRequestByThreeWayInput, ::rusoto_core::BuildError> {
+        Ok(MergePullRequestByThreeWayInput {
+            author_name: self.author_name,
+            commit_message: self.commit_message,
+            conflict_detail_level: self.conflict_detail_level,
+            conflict_resolution: self.conflict_resolution,
+            conflict_resolution_strategy: self.conflict_resolution_strategy,
+            email: self.email,
+            keep_empty_folders: self.keep_empty_folders,
+            pull_request_id: self
+                .pull_request_id
+                .ok_or(::rusoto_core::BuildError::missing("pull_request_id"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            source_commit_id: self.source_commit_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct MergePullRequestByThreeWayOutput {
@@ -1906,6 +4755,86 @@ pub struct MergePullRequestByThreeWayOutput {
     pub pull_request: Option<PullRequest>,
 }
 
+/// <p>The type of the object in the base commit of the merge.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ObjectTypeEnum {
+    /// <p><code>FILE</code></p>
+    File,
+    /// <p><code>DIRECTORY</code></p>
+    Directory,
+    /// <p><code>GIT_LINK</code></p>
+    GitLink,
+    /// <p><code>SYMBOLIC_LINK</code></p>
+    SymbolicLink,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ObjectTypeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ObjectTypeEnum::File => "FILE",
+            ObjectTypeEnum::Directory => "DIRECTORY",
+            ObjectTypeEnum::GitLink => "GIT_LINK",
+            ObjectTypeEnum::SymbolicLink => "SYMBOLIC_LINK",
+            ObjectTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ObjectTypeEnum {
+    fn from(value: &'a str) -> ObjectTypeEnum {
+        match value {
+            "FILE" => ObjectTypeEnum::File,
+            "DIRECTORY" => ObjectTypeEnum::Directory,
+            "GIT_LINK" => ObjectTypeEnum::GitLink,
+            "SYMBOLIC_LINK" => ObjectTypeEnum::SymbolicLink,
+            _ => ObjectTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ObjectTypeEnum {
+    fn from(value: String) -> ObjectTypeEnum {
+        ObjectTypeEnum::from(value.as_str())
+    }
+}
+
+impl From<ObjectTypeEnum> for String {
+    fn from(value: ObjectTypeEnum) -> String {
+        match value {
+            ObjectTypeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ObjectTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ObjectTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ObjectTypeEnum {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ObjectTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ObjectTypeEnum::from(value))
+    }
+}
+
 /// <p>Information about the type of an object in a merge operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1913,15 +4842,87 @@ pub struct ObjectTypes {
     /// <p>The type of the object in the base commit of the merge.</p>
     #[serde(rename = "base")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub base: Option<String>,
+    pub base: Option<ObjectTypeEnum>,
     /// <p>The type of the object in the destination branch.</p>
     #[serde(rename = "destination")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination: Option<String>,
+    pub destination: Option<ObjectTypeEnum>,
     /// <p>The type of the object in the source branch.</p>
     #[serde(rename = "source")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<String>,
+    pub source: Option<ObjectTypeEnum>,
+}
+
+/// <p>The order in which to sort the results of a list repositories operation.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrderEnum {
+    /// <p><code>ascending</code></p>
+    Ascending,
+    /// <p><code>descending</code></p>
+    Descending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OrderEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderEnum::Ascending => "ascending",
+            OrderEnum::Descending => "descending",
+            OrderEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OrderEnum {
+    fn from(value: &'a str) -> OrderEnum {
+        match value {
+            "ascending" => OrderEnum::Ascending,
+            "descending" => OrderEnum::Descending,
+            _ => OrderEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderEnum {
+    fn from(value: String) -> OrderEnum {
+        OrderEnum::from(value.as_str())
+    }
+}
+
+impl From<OrderEnum> for String {
+    fn from(value: OrderEnum) -> String {
+        match value {
+            OrderEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OrderEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OrderEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderEnum {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OrderEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrderEnum::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1949,6 +4950,82 @@ pub struct PostCommentForComparedCommitInput {
     pub repository_name: String,
 }
 
+impl PostCommentForComparedCommitInput {
+    /// Returns a builder for `PostCommentForComparedCommitInput`, checking its required members when built.
+    pub fn builder() -> PostCommentForComparedCommitInputBuilder {
+        PostCommentForComparedCommitInputBuilder::default()
+    }
+}
+
+/// Builds a [`PostCommentForComparedCommitInput`](struct.PostCommentForComparedCommitInput.html).
+#[derive(Default, Debug)]
+pub struct PostCommentForComparedCommitInputBuilder {
+    after_commit_id: Option<String>,
+    before_commit_id: Option<String>,
+    client_request_token: Option<String>,
+    content: Option<String>,
+    location: Option<Location>,
+    repository_name: Option<String>,
+}
+
+impl PostCommentForComparedCommitInputBuilder {
+    /// Sets the [`after_commit_id`](struct.PostCommentForComparedCommitInput.html#structfield.after_commit_id) member.
+    pub fn after_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.after_commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`before_commit_id`](struct.PostCommentForComparedCommitInput.html#structfield.before_commit_id) member.
+    pub fn before_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.before_commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_request_token`](struct.PostCommentForComparedCommitInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content`](struct.PostCommentForComparedCommitInput.html#structfield.content) member.
+    pub fn content<T: Into<String>>(mut self, value: T) -> Self {
+        self.content = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location`](struct.PostCommentForComparedCommitInput.html#structfield.location) member.
+    pub fn location<T: Into<Location>>(mut self, value: T) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.PostCommentForComparedCommitInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PostCommentForComparedCommitInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PostCommentForComparedCommitInput, ::rusoto_core::BuildError> {
+        Ok(PostCommentForComparedCommitInput {
+            after_commit_id: self
+                .after_commit_id
+                .ok_or(::rusoto_core::BuildError::missing("after_commit_id"))?,
+            before_commit_id: self.before_commit_id,
+            client_request_token: self.client_request_token,
+            content: self
+                .content
+                .ok_or(::rusoto_core::BuildError::missing("content"))?,
+            location: self.location,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PostCommentForComparedCommitOutput {
@@ -2009,27 +5086,115 @@ pub struct PostCommentForPullRequestInput {
     pub repository_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct PostCommentForPullRequestOutput {
-    /// <p>In the directionality of the pull request, the blob ID of the 'after' blob.</p>
-    #[serde(rename = "afterBlobId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub after_blob_id: Option<String>,
-    /// <p>The full commit ID of the commit in the destination branch where the pull request will be merged.</p>
-    #[serde(rename = "afterCommitId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub after_commit_id: Option<String>,
-    /// <p>In the directionality of the pull request, the blob ID of the 'before' blob.</p>
-    #[serde(rename = "beforeBlobId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub before_blob_id: Option<String>,
-    /// <p>The full commit ID of the commit in the source branch used to create the pull request, or in the case of an updated pull request, the full commit ID of the commit used to update the pull request.</p>
-    #[serde(rename = "beforeCommitId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub before_commit_id: Option<String>,
-    /// <p>The content of the comment you posted.</p>
-    #[serde(rename = "comment")]
+impl PostCommentForPullRequestInput {
+    /// Returns a builder for `PostCommentForPullRequestInput`, checking its required members when built.
+    pub fn builder() -> PostCommentForPullRequestInputBuilder {
+        PostCommentForPullRequestInputBuilder::default()
+    }
+}
+
+/// Builds a [`PostCommentForPullRequestInput`](struct.PostCommentForPullRequestInput.html).
+#[derive(Default, Debug)]
+pub struct PostCommentForPullRequestInputBuilder {
+    after_commit_id: Option<String>,
+    before_commit_id: Option<String>,
+    client_request_token: Option<String>,
+    content: Option<String>,
+    location: Option<Location>,
+    pull_request_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl PostCommentForPullRequestInputBuilder {
+    /// Sets the [`after_commit_id`](struct.PostCommentForPullRequestInput.html#structfield.after_commit_id) member.
+    pub fn after_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.after_commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`before_commit_id`](struct.PostCommentForPullRequestInput.html#structfield.before_commit_id) member.
+    pub fn before_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.before_commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_request_token`](struct.PostCommentForPullRequestInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content`](struct.PostCommentForPullRequestInput.html#structfield.content) member.
+    pub fn content<T: Into<String>>(mut self, value: T) -> Self {
+        self.content = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location`](struct.PostCommentForPullRequestInput.html#structfield.location) member.
+    pub fn location<T: Into<Location>>(mut self, value: T) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pull_request_id`](struct.PostCommentForPullRequestInput.html#structfield.pull_request_id) member.
+    pub fn pull_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pull_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.PostCommentForPullRequestInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PostCommentForPullRequestInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PostCommentForPullRequestInput, ::rusoto_core::BuildError> {
+        Ok(PostCommentForPullRequestInput {
+            after_commit_id: self
+                .after_commit_id
+                .ok_or(::rusoto_core::BuildError::missing("after_commit_id"))?,
+            before_commit_id: self
+                .before_commit_id
+                .ok_or(::rusoto_core::BuildError::missing("before_commit_id"))?,
+            client_request_token: self.client_request_token,
+            content: self
+                .content
+                .ok_or(::rusoto_core::BuildError::missing("content"))?,
+            location: self.location,
+            pull_request_id: self
+                .pull_request_id
+                .ok_or(::rusoto_core::BuildError::missing("pull_request_id"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct PostCommentForPullRequestOutput {
+    /// <p>In the directionality of the pull request, the blob ID of the 'after' blob.</p>
+    #[serde(rename = "afterBlobId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_blob_id: Option<String>,
+    /// <p>The full commit ID of the commit in the destination branch where the pull request will be merged.</p>
+    #[serde(rename = "afterCommitId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_commit_id: Option<String>,
+    /// <p>In the directionality of the pull request, the blob ID of the 'before' blob.</p>
+    #[serde(rename = "beforeBlobId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_blob_id: Option<String>,
+    /// <p>The full commit ID of the commit in the source branch used to create the pull request, or in the case of an updated pull request, the full commit ID of the commit used to update the pull request.</p>
+    #[serde(rename = "beforeCommitId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_commit_id: Option<String>,
+    /// <p>The content of the comment you posted.</p>
+    #[serde(rename = "comment")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<Comment>,
     /// <p>The location of the change where you posted your comment.</p>
@@ -2060,6 +5225,54 @@ pub struct PostCommentReplyInput {
     pub in_reply_to: String,
 }
 
+impl PostCommentReplyInput {
+    /// Returns a builder for `PostCommentReplyInput`, checking its required members when built.
+    pub fn builder() -> PostCommentReplyInputBuilder {
+        PostCommentReplyInputBuilder::default()
+    }
+}
+
+/// Builds a [`PostCommentReplyInput`](struct.PostCommentReplyInput.html).
+#[derive(Default, Debug)]
+pub struct PostCommentReplyInputBuilder {
+    client_request_token: Option<String>,
+    content: Option<String>,
+    in_reply_to: Option<String>,
+}
+
+impl PostCommentReplyInputBuilder {
+    /// Sets the [`client_request_token`](struct.PostCommentReplyInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content`](struct.PostCommentReplyInput.html#structfield.content) member.
+    pub fn content<T: Into<String>>(mut self, value: T) -> Self {
+        self.content = Some(value.into());
+        self
+    }
+
+    /// Sets the [`in_reply_to`](struct.PostCommentReplyInput.html#structfield.in_reply_to) member.
+    pub fn in_reply_to<T: Into<String>>(mut self, value: T) -> Self {
+        self.in_reply_to = Some(value.into());
+        self
+    }
+
+    /// Builds the `PostCommentReplyInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PostCommentReplyInput, ::rusoto_core::BuildError> {
+        Ok(PostCommentReplyInput {
+            client_request_token: self.client_request_token,
+            content: self
+                .content
+                .ok_or(::rusoto_core::BuildError::missing("content"))?,
+            in_reply_to: self
+                .in_reply_to
+                .ok_or(::rusoto_core::BuildError::missing("in_reply_to"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PostCommentReplyOutput {
@@ -2083,16 +5296,18 @@ pub struct PullRequest {
     pub client_request_token: Option<String>,
     /// <p>The date and time the pull request was originally created, in timestamp format.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The user-defined description of the pull request. This description can be used to clarify what should be reviewed and other details of the request.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The day and time of the last user or system activity on the pull request, in timestamp format.</p>
     #[serde(rename = "lastActivityDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_activity_date: Option<f64>,
+    pub last_activity_date: Option<EpochTimestamp>,
     /// <p>The system-generated ID of the pull request. </p>
     #[serde(rename = "pullRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2100,7 +5315,7 @@ pub struct PullRequest {
     /// <p>The status of the pull request. Pull request status can only change from <code>OPEN</code> to <code>CLOSED</code>.</p>
     #[serde(rename = "pullRequestStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_request_status: Option<String>,
+    pub pull_request_status: Option<PullRequestStatusEnum>,
     /// <p>The targets of the pull request, including the source branch and destination branch for the pull request.</p>
     #[serde(rename = "pullRequestTargets")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2143,8 +5358,9 @@ pub struct PullRequestEvent {
     pub actor_arn: Option<String>,
     /// <p>The day and time of the pull request event, in timestamp format.</p>
     #[serde(rename = "eventDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_date: Option<f64>,
+    pub event_date: Option<EpochTimestamp>,
     /// <p>Information about the source and destination branches for the pull request.</p>
     #[serde(rename = "pullRequestCreatedEventMetadata")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2152,7 +5368,7 @@ pub struct PullRequestEvent {
     /// <p>The type of the pull request event, for example a status change event (PULL_REQUEST_STATUS_CHANGED) or update event (PULL_REQUEST_SOURCE_REFERENCE_UPDATED).</p>
     #[serde(rename = "pullRequestEventType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_request_event_type: Option<String>,
+    pub pull_request_event_type: Option<PullRequestEventType>,
     /// <p>The system-generated ID of the pull request.</p>
     #[serde(rename = "pullRequestId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2173,6 +5389,134 @@ pub struct PullRequestEvent {
     pub pull_request_status_changed_event_metadata: Option<PullRequestStatusChangedEventMetadata>,
 }
 
+/// <p>Optional. The pull request event type about which you want to return information.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PullRequestEventType {
+    /// <p><code>PULL_REQUEST_CREATED</code></p>
+    PullRequestCreated,
+    /// <p><code>PULL_REQUEST_STATUS_CHANGED</code></p>
+    PullRequestStatusChanged,
+    /// <p><code>PULL_REQUEST_SOURCE_REFERENCE_UPDATED</code></p>
+    PullRequestSourceReferenceUpdated,
+    /// <p><code>PULL_REQUEST_MERGE_STATE_CHANGED</code></p>
+    PullRequestMergeStateChanged,
+    /// <p><code>PULL_REQUEST_APPROVAL_RULE_CREATED</code></p>
+    PullRequestApprovalRuleCreated,
+    /// <p><code>PULL_REQUEST_APPROVAL_RULE_UPDATED</code></p>
+    PullRequestApprovalRuleUpdated,
+    /// <p><code>PULL_REQUEST_APPROVAL_RULE_DELETED</code></p>
+    PullRequestApprovalRuleDeleted,
+    /// <p><code>PULL_REQUEST_APPROVAL_RULE_OVERRIDDEN</code></p>
+    PullRequestApprovalRuleOverridden,
+    /// <p><code>PULL_REQUEST_APPROVAL_STATE_CHANGED</code></p>
+    PullRequestApprovalStateChanged,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PullRequestEventType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PullRequestEventType::PullRequestCreated => "PULL_REQUEST_CREATED",
+            PullRequestEventType::PullRequestStatusChanged => "PULL_REQUEST_STATUS_CHANGED",
+            PullRequestEventType::PullRequestSourceReferenceUpdated => {
+                "PULL_REQUEST_SOURCE_REFERENCE_UPDATED"
+            }
+            PullRequestEventType::PullRequestMergeStateChanged => {
+                "PULL_REQUEST_MERGE_STATE_CHANGED"
+            }
+            PullRequestEventType::PullRequestApprovalRuleCreated => {
+                "PULL_REQUEST_APPROVAL_RULE_CREATED"
+            }
+            PullRequestEventType::PullRequestApprovalRuleUpdated => {
+                "PULL_REQUEST_APPROVAL_RULE_UPDATED"
+            }
+            PullRequestEventType::PullRequestApprovalRuleDeleted => {
+                "PULL_REQUEST_APPROVAL_RULE_DELETED"
+            }
+            PullRequestEventType::PullRequestApprovalRuleOverridden => {
+                "PULL_REQUEST_APPROVAL_RULE_OVERRIDDEN"
+            }
+            PullRequestEventType::PullRequestApprovalStateChanged => {
+                "PULL_REQUEST_APPROVAL_STATE_CHANGED"
+            }
+            PullRequestEventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PullRequestEventType {
+    fn from(value: &'a str) -> PullRequestEventType {
+        match value {
+            "PULL_REQUEST_CREATED" => PullRequestEventType::PullRequestCreated,
+            "PULL_REQUEST_STATUS_CHANGED" => PullRequestEventType::PullRequestStatusChanged,
+            "PULL_REQUEST_SOURCE_REFERENCE_UPDATED" => {
+                PullRequestEventType::PullRequestSourceReferenceUpdated
+            }
+            "PULL_REQUEST_MERGE_STATE_CHANGED" => {
+                PullRequestEventType::PullRequestMergeStateChanged
+            }
+            "PULL_REQUEST_APPROVAL_RULE_CREATED" => {
+                PullRequestEventType::PullRequestApprovalRuleCreated
+            }
+            "PULL_REQUEST_APPROVAL_RULE_UPDATED" => {
+                PullRequestEventType::PullRequestApprovalRuleUpdated
+            }
+            "PULL_REQUEST_APPROVAL_RULE_DELETED" => {
+                PullRequestEventType::PullRequestApprovalRuleDeleted
+            }
+            "PULL_REQUEST_APPROVAL_RULE_OVERRIDDEN" => {
+                PullRequestEventType::PullRequestApprovalRuleOverridden
+            }
+            "PULL_REQUEST_APPROVAL_STATE_CHANGED" => {
+                PullRequestEventType::PullRequestApprovalStateChanged
+            }
+            _ => PullRequestEventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PullRequestEventType {
+    fn from(value: String) -> PullRequestEventType {
+        PullRequestEventType::from(value.as_str())
+    }
+}
+
+impl From<PullRequestEventType> for String {
+    fn from(value: PullRequestEventType) -> String {
+        match value {
+            PullRequestEventType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PullRequestEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PullRequestEventType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PullRequestEventType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PullRequestEventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PullRequestEventType::from(value))
+    }
+}
+
 /// <p>Returns information about the change in the merge state for a pull request event. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2220,7 +5564,79 @@ pub struct PullRequestStatusChangedEventMetadata {
     /// <p>The changed status of the pull request.</p>
     #[serde(rename = "pullRequestStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_request_status: Option<String>,
+    pub pull_request_status: Option<PullRequestStatusEnum>,
+}
+
+/// <p>Optional. The status of the pull request. If used, this refines the results to the pull requests that match the specified status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PullRequestStatusEnum {
+    /// <p><code>OPEN</code></p>
+    Open,
+    /// <p><code>CLOSED</code></p>
+    Closed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PullRequestStatusEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PullRequestStatusEnum::Open => "OPEN",
+            PullRequestStatusEnum::Closed => "CLOSED",
+            PullRequestStatusEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PullRequestStatusEnum {
+    fn from(value: &'a str) -> PullRequestStatusEnum {
+        match value {
+            "OPEN" => PullRequestStatusEnum::Open,
+            "CLOSED" => PullRequestStatusEnum::Closed,
+            _ => PullRequestStatusEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PullRequestStatusEnum {
+    fn from(value: String) -> PullRequestStatusEnum {
+        PullRequestStatusEnum::from(value.as_str())
+    }
+}
+
+impl From<PullRequestStatusEnum> for String {
+    fn from(value: PullRequestStatusEnum) -> String {
+        match value {
+            PullRequestStatusEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PullRequestStatusEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PullRequestStatusEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PullRequestStatusEnum {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PullRequestStatusEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PullRequestStatusEnum::from(value))
+    }
 }
 
 /// <p>Returns information about a pull request target.</p>
@@ -2272,7 +5688,7 @@ pub struct PutFileEntry {
     /// <p>The extrapolated file mode permissions for the file. Valid values include EXECUTABLE and NORMAL.</p>
     #[serde(rename = "fileMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_mode: Option<String>,
+    pub file_mode: Option<FileModeTypeEnum>,
     /// <p>The full path to the file in the repository, including the name of the file.</p>
     #[serde(rename = "filePath")]
     pub file_path: String,
@@ -2306,7 +5722,7 @@ pub struct PutFileInput {
     /// <p>The file mode permissions of the blob. Valid file mode permissions are listed below.</p>
     #[serde(rename = "fileMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_mode: Option<String>,
+    pub file_mode: Option<FileModeTypeEnum>,
     /// <p><p>The name of the file you want to add or update, including the relative path to the file in the repository.</p> <note> <p>If the path does not currently exist in the repository, the path will be created as part of adding the file.</p> </note></p>
     #[serde(rename = "filePath")]
     pub file_path: String,
@@ -2323,6 +5739,106 @@ pub struct PutFileInput {
     pub repository_name: String,
 }
 
+impl PutFileInput {
+    /// Returns a builder for `PutFileInput`, checking its required members when built.
+    pub fn builder() -> PutFileInputBuilder {
+        PutFileInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutFileInput`](struct.PutFileInput.html).
+#[derive(Default, Debug)]
+pub struct PutFileInputBuilder {
+    branch_name: Option<String>,
+    commit_message: Option<String>,
+    email: Option<String>,
+    file_content: Option<bytes::Bytes>,
+    file_mode: Option<FileModeTypeEnum>,
+    file_path: Option<String>,
+    name: Option<String>,
+    parent_commit_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl PutFileInputBuilder {
+    /// Sets the [`branch_name`](struct.PutFileInput.html#structfield.branch_name) member.
+    pub fn branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_message`](struct.PutFileInput.html#structfield.commit_message) member.
+    pub fn commit_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.commit_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email`](struct.PutFileInput.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_content`](struct.PutFileInput.html#structfield.file_content) member.
+    pub fn file_content<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.file_content = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_mode`](struct.PutFileInput.html#structfield.file_mode) member.
+    pub fn file_mode<T: Into<FileModeTypeEnum>>(mut self, value: T) -> Self {
+        self.file_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_path`](struct.PutFileInput.html#structfield.file_path) member.
+    pub fn file_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.PutFileInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_commit_id`](struct.PutFileInput.html#structfield.parent_commit_id) member.
+    pub fn parent_commit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_commit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.PutFileInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutFileInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutFileInput, ::rusoto_core::BuildError> {
+        Ok(PutFileInput {
+            branch_name: self
+                .branch_name
+                .ok_or(::rusoto_core::BuildError::missing("branch_name"))?,
+            commit_message: self.commit_message,
+            email: self.email,
+            file_content: self
+                .file_content
+                .ok_or(::rusoto_core::BuildError::missing("file_content"))?,
+            file_mode: self.file_mode,
+            file_path: self
+                .file_path
+                .ok_or(::rusoto_core::BuildError::missing("file_path"))?,
+            name: self.name,
+            parent_commit_id: self.parent_commit_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutFileOutput {
@@ -2348,6 +5864,48 @@ pub struct PutRepositoryTriggersInput {
     pub triggers: Vec<RepositoryTrigger>,
 }
 
+impl PutRepositoryTriggersInput {
+    /// Returns a builder for `PutRepositoryTriggersInput`, checking its required members when built.
+    pub fn builder() -> PutRepositoryTriggersInputBuilder {
+        PutRepositoryTriggersInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutRepositoryTriggersInput`](struct.PutRepositoryTriggersInput.html).
+#[derive(Default, Debug)]
+pub struct PutRepositoryTriggersInputBuilder {
+    repository_name: Option<String>,
+    triggers: Option<Vec<RepositoryTrigger>>,
+}
+
+impl PutRepositoryTriggersInputBuilder {
+    /// Sets the [`repository_name`](struct.PutRepositoryTriggersInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`triggers`](struct.PutRepositoryTriggersInput.html#structfield.triggers) member.
+    pub fn triggers<T: Into<Vec<RepositoryTrigger>>>(mut self, value: T) -> Self {
+        self.triggers = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRepositoryTriggersInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutRepositoryTriggersInput, ::rusoto_core::BuildError> {
+        Ok(PutRepositoryTriggersInput {
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            triggers: self
+                .triggers
+                .ok_or(::rusoto_core::BuildError::missing("triggers"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a put repository triggers operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2358,8 +5916,80 @@ pub struct PutRepositoryTriggersOutput {
     pub configuration_id: Option<String>,
 }
 
+/// <p>In a comparison of commits or a pull request, whether the change is in the 'before' or 'after' of that comparison.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RelativeFileVersionEnum {
+    /// <p><code>BEFORE</code></p>
+    Before,
+    /// <p><code>AFTER</code></p>
+    After,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RelativeFileVersionEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelativeFileVersionEnum::Before => "BEFORE",
+            RelativeFileVersionEnum::After => "AFTER",
+            RelativeFileVersionEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RelativeFileVersionEnum {
+    fn from(value: &'a str) -> RelativeFileVersionEnum {
+        match value {
+            "BEFORE" => RelativeFileVersionEnum::Before,
+            "AFTER" => RelativeFileVersionEnum::After,
+            _ => RelativeFileVersionEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelativeFileVersionEnum {
+    fn from(value: String) -> RelativeFileVersionEnum {
+        RelativeFileVersionEnum::from(value.as_str())
+    }
+}
+
+impl From<RelativeFileVersionEnum> for String {
+    fn from(value: RelativeFileVersionEnum) -> String {
+        match value {
+            RelativeFileVersionEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RelativeFileVersionEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RelativeFileVersionEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RelativeFileVersionEnum {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RelativeFileVersionEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RelativeFileVersionEnum::from(value))
+    }
+}
+
 /// <p>Information about a replacement content entry in the conflict of a merge or pull request operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ReplaceContentEntry {
     /// <p>The base-64 encoded content to use when the replacement type is USE_NEW_CONTENT.</p>
     #[serde(rename = "content")]
@@ -2373,13 +6003,93 @@ pub struct ReplaceContentEntry {
     /// <p>The file mode to apply during conflict resoltion.</p>
     #[serde(rename = "fileMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_mode: Option<String>,
+    pub file_mode: Option<FileModeTypeEnum>,
     /// <p>The path of the conflicting file.</p>
     #[serde(rename = "filePath")]
     pub file_path: String,
     /// <p>The replacement type to use when determining how to resolve the conflict.</p>
     #[serde(rename = "replacementType")]
-    pub replacement_type: String,
+    pub replacement_type: ReplacementTypeEnum,
+}
+
+/// <p>The replacement type to use when determining how to resolve the conflict.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReplacementTypeEnum {
+    /// <p><code>KEEP_BASE</code></p>
+    KeepBase,
+    /// <p><code>KEEP_SOURCE</code></p>
+    KeepSource,
+    /// <p><code>KEEP_DESTINATION</code></p>
+    KeepDestination,
+    /// <p><code>USE_NEW_CONTENT</code></p>
+    UseNewContent,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReplacementTypeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplacementTypeEnum::KeepBase => "KEEP_BASE",
+            ReplacementTypeEnum::KeepSource => "KEEP_SOURCE",
+            ReplacementTypeEnum::KeepDestination => "KEEP_DESTINATION",
+            ReplacementTypeEnum::UseNewContent => "USE_NEW_CONTENT",
+            ReplacementTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReplacementTypeEnum {
+    fn from(value: &'a str) -> ReplacementTypeEnum {
+        match value {
+            "KEEP_BASE" => ReplacementTypeEnum::KeepBase,
+            "KEEP_SOURCE" => ReplacementTypeEnum::KeepSource,
+            "KEEP_DESTINATION" => ReplacementTypeEnum::KeepDestination,
+            "USE_NEW_CONTENT" => ReplacementTypeEnum::UseNewContent,
+            _ => ReplacementTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplacementTypeEnum {
+    fn from(value: String) -> ReplacementTypeEnum {
+        ReplacementTypeEnum::from(value.as_str())
+    }
+}
+
+impl From<ReplacementTypeEnum> for String {
+    fn from(value: ReplacementTypeEnum) -> String {
+        match value {
+            ReplacementTypeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReplacementTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReplacementTypeEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplacementTypeEnum {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReplacementTypeEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReplacementTypeEnum::from(value))
+    }
 }
 
 /// <p>Information about a repository.</p>
@@ -2404,16 +6114,18 @@ pub struct RepositoryMetadata {
     pub clone_url_ssh: Option<String>,
     /// <p>The date and time the repository was created, in timestamp format.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The repository's default branch name.</p>
     #[serde(rename = "defaultBranch")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_branch: Option<String>,
     /// <p>The date and time the repository was last modified, in timestamp format.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>A comment or description about the repository.</p>
     #[serde(rename = "repositoryDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2458,37 +6170,191 @@ pub struct RepositoryTrigger {
     pub destination_arn: String,
     /// <p><p>The repository events that will cause the trigger to run actions in another service, such as sending a notification through Amazon SNS. </p> <note> <p>The valid value &quot;all&quot; cannot be used with any other values.</p> </note></p>
     #[serde(rename = "events")]
-    pub events: Vec<String>,
+    pub events: Vec<RepositoryTriggerEventEnum>,
     /// <p>The name of the trigger.</p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
-/// <p>A trigger failed to run.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct RepositoryTriggerExecutionFailure {
-    /// <p>Additional message information about the trigger that did not run.</p>
-    #[serde(rename = "failureMessage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_message: Option<String>,
-    /// <p>The name of the trigger that did not run.</p>
-    #[serde(rename = "trigger")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub trigger: Option<String>,
+/// <p><p>The repository events that will cause the trigger to run actions in another service, such as sending a notification through Amazon SNS. </p> <note> <p>The valid value &quot;all&quot; cannot be used with any other values.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RepositoryTriggerEventEnum {
+    /// <p><code>all</code></p>
+    All,
+    /// <p><code>updateReference</code></p>
+    UpdateReference,
+    /// <p><code>createReference</code></p>
+    CreateReference,
+    /// <p><code>deleteReference</code></p>
+    DeleteReference,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RepositoryTriggerEventEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RepositoryTriggerEventEnum::All => "all",
+            RepositoryTriggerEventEnum::UpdateReference => "updateReference",
+            RepositoryTriggerEventEnum::CreateReference => "createReference",
+            RepositoryTriggerEventEnum::DeleteReference => "deleteReference",
+            RepositoryTriggerEventEnum::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Information about the file mode changes.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct SetFileModeEntry {
-    /// <p>The file mode for the file.</p>
-    #[serde(rename = "fileMode")]
-    pub file_mode: String,
-    /// <p>The full path to the file, including the name of the file.</p>
-    #[serde(rename = "filePath")]
+impl<'a> From<&'a str> for RepositoryTriggerEventEnum {
+    fn from(value: &'a str) -> RepositoryTriggerEventEnum {
+        match value {
+            "all" => RepositoryTriggerEventEnum::All,
+            "updateReference" => RepositoryTriggerEventEnum::UpdateReference,
+            "createReference" => RepositoryTriggerEventEnum::CreateReference,
+            "deleteReference" => RepositoryTriggerEventEnum::DeleteReference,
+            _ => RepositoryTriggerEventEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RepositoryTriggerEventEnum {
+    fn from(value: String) -> RepositoryTriggerEventEnum {
+        RepositoryTriggerEventEnum::from(value.as_str())
+    }
+}
+
+impl From<RepositoryTriggerEventEnum> for String {
+    fn from(value: RepositoryTriggerEventEnum) -> String {
+        match value {
+            RepositoryTriggerEventEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RepositoryTriggerEventEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RepositoryTriggerEventEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RepositoryTriggerEventEnum {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<RepositoryTriggerEventEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RepositoryTriggerEventEnum::from(value))
+    }
+}
+
+/// <p>A trigger failed to run.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct RepositoryTriggerExecutionFailure {
+    /// <p>Additional message information about the trigger that did not run.</p>
+    #[serde(rename = "failureMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_message: Option<String>,
+    /// <p>The name of the trigger that did not run.</p>
+    #[serde(rename = "trigger")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<String>,
+}
+
+/// <p>Information about the file mode changes.</p>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SetFileModeEntry {
+    /// <p>The file mode for the file.</p>
+    #[serde(rename = "fileMode")]
+    pub file_mode: FileModeTypeEnum,
+    /// <p>The full path to the file, including the name of the file.</p>
+    #[serde(rename = "filePath")]
     pub file_path: String,
 }
 
+/// <p>The criteria used to sort the results of a list repositories operation.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortByEnum {
+    /// <p><code>repositoryName</code></p>
+    RepositoryName,
+    /// <p><code>lastModifiedDate</code></p>
+    LastModifiedDate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SortByEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortByEnum::RepositoryName => "repositoryName",
+            SortByEnum::LastModifiedDate => "lastModifiedDate",
+            SortByEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SortByEnum {
+    fn from(value: &'a str) -> SortByEnum {
+        match value {
+            "repositoryName" => SortByEnum::RepositoryName,
+            "lastModifiedDate" => SortByEnum::LastModifiedDate,
+            _ => SortByEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortByEnum {
+    fn from(value: String) -> SortByEnum {
+        SortByEnum::from(value.as_str())
+    }
+}
+
+impl From<SortByEnum> for String {
+    fn from(value: SortByEnum) -> String {
+        match value {
+            SortByEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SortByEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SortByEnum {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortByEnum {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SortByEnum, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SortByEnum::from(value))
+    }
+}
+
 /// <p>Information about a source file that is part of changes made in a commit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SourceFileSpecifier {
@@ -2534,7 +6400,7 @@ pub struct SymbolicLink {
     /// <p>The file mode permissions of the blob that cotains information about the symbolic link.</p>
     #[serde(rename = "fileMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_mode: Option<String>,
+    pub file_mode: Option<FileModeTypeEnum>,
     /// <p>The relative path of the symbolic link from the folder where the query originated.</p>
     #[serde(rename = "relativePath")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2551,6 +6417,46 @@ pub struct TagResourceInput {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceInput {
+    /// Returns a builder for `TagResourceInput`, checking its required members when built.
+    pub fn builder() -> TagResourceInputBuilder {
+        TagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceInput`](struct.TagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct TagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceInput, ::rusoto_core::BuildError> {
+        Ok(TagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>Returns information about a target for a pull request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct Target {
@@ -2577,6 +6483,48 @@ pub struct TestRepositoryTriggersInput {
     pub triggers: Vec<RepositoryTrigger>,
 }
 
+impl TestRepositoryTriggersInput {
+    /// Returns a builder for `TestRepositoryTriggersInput`, checking its required members when built.
+    pub fn builder() -> TestRepositoryTriggersInputBuilder {
+        TestRepositoryTriggersInputBuilder::default()
+    }
+}
+
+/// Builds a [`TestRepositoryTriggersInput`](struct.TestRepositoryTriggersInput.html).
+#[derive(Default, Debug)]
+pub struct TestRepositoryTriggersInputBuilder {
+    repository_name: Option<String>,
+    triggers: Option<Vec<RepositoryTrigger>>,
+}
+
+impl TestRepositoryTriggersInputBuilder {
+    /// Sets the [`repository_name`](struct.TestRepositoryTriggersInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`triggers`](struct.TestRepositoryTriggersInput.html#structfield.triggers) member.
+    pub fn triggers<T: Into<Vec<RepositoryTrigger>>>(mut self, value: T) -> Self {
+        self.triggers = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestRepositoryTriggersInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TestRepositoryTriggersInput, ::rusoto_core::BuildError> {
+        Ok(TestRepositoryTriggersInput {
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            triggers: self
+                .triggers
+                .ok_or(::rusoto_core::BuildError::missing("triggers"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a test repository triggers operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2601,6 +6549,46 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceInput {
+    /// Returns a builder for `UntagResourceInput`, checking its required members when built.
+    pub fn builder() -> UntagResourceInputBuilder {
+        UntagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceInput`](struct.UntagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceInput, ::rusoto_core::BuildError> {
+        Ok(UntagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateCommentInput {
     /// <p>The system-generated ID of the comment you want to update. To get this ID, use <a>GetCommentsForComparedCommit</a> or <a>GetCommentsForPullRequest</a>.</p>
@@ -2611,6 +6599,46 @@ pub struct UpdateCommentInput {
     pub content: String,
 }
 
+impl UpdateCommentInput {
+    /// Returns a builder for `UpdateCommentInput`, checking its required members when built.
+    pub fn builder() -> UpdateCommentInputBuilder {
+        UpdateCommentInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateCommentInput`](struct.UpdateCommentInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateCommentInputBuilder {
+    comment_id: Option<String>,
+    content: Option<String>,
+}
+
+impl UpdateCommentInputBuilder {
+    /// Sets the [`comment_id`](struct.UpdateCommentInput.html#structfield.comment_id) member.
+    pub fn comment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content`](struct.UpdateCommentInput.html#structfield.content) member.
+    pub fn content<T: Into<String>>(mut self, value: T) -> Self {
+        self.content = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateCommentInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateCommentInput, ::rusoto_core::BuildError> {
+        Ok(UpdateCommentInput {
+            comment_id: self
+                .comment_id
+                .ok_or(::rusoto_core::BuildError::missing("comment_id"))?,
+            content: self
+                .content
+                .ok_or(::rusoto_core::BuildError::missing("content"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateCommentOutput {
@@ -2631,6 +6659,48 @@ pub struct UpdateDefaultBranchInput {
     pub repository_name: String,
 }
 
+impl UpdateDefaultBranchInput {
+    /// Returns a builder for `UpdateDefaultBranchInput`, checking its required members when built.
+    pub fn builder() -> UpdateDefaultBranchInputBuilder {
+        UpdateDefaultBranchInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDefaultBranchInput`](struct.UpdateDefaultBranchInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateDefaultBranchInputBuilder {
+    default_branch_name: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl UpdateDefaultBranchInputBuilder {
+    /// Sets the [`default_branch_name`](struct.UpdateDefaultBranchInput.html#structfield.default_branch_name) member.
+    pub fn default_branch_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_branch_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.UpdateDefaultBranchInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDefaultBranchInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDefaultBranchInput, ::rusoto_core::BuildError> {
+        Ok(UpdateDefaultBranchInput {
+            default_branch_name: self
+                .default_branch_name
+                .ok_or(::rusoto_core::BuildError::missing("default_branch_name"))?,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdatePullRequestDescriptionInput {
     /// <p>The updated content of the description for the pull request. This content will replace the existing description.</p>
@@ -2641,6 +6711,48 @@ pub struct UpdatePullRequestDescriptionInput {
     pub pull_request_id: String,
 }
 
+impl UpdatePullRequestDescriptionInput {
+    /// Returns a builder for `UpdatePullRequestDescriptionInput`, checking its required members when built.
+    pub fn builder() -> UpdatePullRequestDescriptionInputBuilder {
+        UpdatePullRequestDescriptionInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePullRequestDescriptionInput`](struct.UpdatePullRequestDescriptionInput.html).
+#[derive(Default, Debug)]
+pub struct UpdatePullRequestDescriptionInputBuilder {
+    description: Option<String>,
+    pull_request_id: Option<String>,
+}
+
+impl UpdatePullRequestDescriptionInputBuilder {
+    /// Sets the [`description`](struct.UpdatePullRequestDescriptionInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pull_request_id`](struct.UpdatePullRequestDescriptionInput.html#structfield.pull_request_id) member.
+    pub fn pull_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pull_request_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePullRequestDescriptionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdatePullRequestDescriptionInput, ::rusoto_core::BuildError> {
+        Ok(UpdatePullRequestDescriptionInput {
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            pull_request_id: self
+                .pull_request_id
+                .ok_or(::rusoto_core::BuildError::missing("pull_request_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdatePullRequestDescriptionOutput {
@@ -2649,14 +6761,56 @@ pub struct UpdatePullRequestDescriptionOutput {
     pub pull_request: PullRequest,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdatePullRequestStatusInput {
     /// <p>The system-generated ID of the pull request. To get this ID, use <a>ListPullRequests</a>.</p>
     #[serde(rename = "pullRequestId")]
     pub pull_request_id: String,
     /// <p>The status of the pull request. The only valid operations are to update the status from <code>OPEN</code> to <code>OPEN</code>, <code>OPEN</code> to <code>CLOSED</code> or from from <code>CLOSED</code> to <code>CLOSED</code>.</p>
     #[serde(rename = "pullRequestStatus")]
-    pub pull_request_status: String,
+    pub pull_request_status: PullRequestStatusEnum,
+}
+
+impl UpdatePullRequestStatusInput {
+    /// Returns a builder for `UpdatePullRequestStatusInput`, checking its required members when built.
+    pub fn builder() -> UpdatePullRequestStatusInputBuilder {
+        UpdatePullRequestStatusInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePullRequestStatusInput`](struct.UpdatePullRequestStatusInput.html).
+#[derive(Default, Debug)]
+pub struct UpdatePullRequestStatusInputBuilder {
+    pull_request_id: Option<String>,
+    pull_request_status: Option<PullRequestStatusEnum>,
+}
+
+impl UpdatePullRequestStatusInputBuilder {
+    /// Sets the [`pull_request_id`](struct.UpdatePullRequestStatusInput.html#structfield.pull_request_id) member.
+    pub fn pull_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pull_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pull_request_status`](struct.UpdatePullRequestStatusInput.html#structfield.pull_request_status) member.
+    pub fn pull_request_status<T: Into<PullRequestStatusEnum>>(mut self, value: T) -> Self {
+        self.pull_request_status = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePullRequestStatusInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdatePullRequestStatusInput, ::rusoto_core::BuildError> {
+        Ok(UpdatePullRequestStatusInput {
+            pull_request_id: self
+                .pull_request_id
+                .ok_or(::rusoto_core::BuildError::missing("pull_request_id"))?,
+            pull_request_status: self
+                .pull_request_status
+                .ok_or(::rusoto_core::BuildError::missing("pull_request_status"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2677,6 +6831,48 @@ pub struct UpdatePullRequestTitleInput {
     pub title: String,
 }
 
+impl UpdatePullRequestTitleInput {
+    /// Returns a builder for `UpdatePullRequestTitleInput`, checking its required members when built.
+    pub fn builder() -> UpdatePullRequestTitleInputBuilder {
+        UpdatePullRequestTitleInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePullRequestTitleInput`](struct.UpdatePullRequestTitleInput.html).
+#[derive(Default, Debug)]
+pub struct UpdatePullRequestTitleInputBuilder {
+    pull_request_id: Option<String>,
+    title: Option<String>,
+}
+
+impl UpdatePullRequestTitleInputBuilder {
+    /// Sets the [`pull_request_id`](struct.UpdatePullRequestTitleInput.html#structfield.pull_request_id) member.
+    pub fn pull_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pull_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`title`](struct.UpdatePullRequestTitleInput.html#structfield.title) member.
+    pub fn title<T: Into<String>>(mut self, value: T) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePullRequestTitleInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdatePullRequestTitleInput, ::rusoto_core::BuildError> {
+        Ok(UpdatePullRequestTitleInput {
+            pull_request_id: self
+                .pull_request_id
+                .ok_or(::rusoto_core::BuildError::missing("pull_request_id"))?,
+            title: self
+                .title
+                .ok_or(::rusoto_core::BuildError::missing("title"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdatePullRequestTitleOutput {
@@ -2697,6 +6893,46 @@ pub struct UpdateRepositoryDescriptionInput {
     pub repository_name: String,
 }
 
+impl UpdateRepositoryDescriptionInput {
+    /// Returns a builder for `UpdateRepositoryDescriptionInput`, checking its required members when built.
+    pub fn builder() -> UpdateRepositoryDescriptionInputBuilder {
+        UpdateRepositoryDescriptionInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRepositoryDescriptionInput`](struct.UpdateRepositoryDescriptionInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateRepositoryDescriptionInputBuilder {
+    repository_description: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl UpdateRepositoryDescriptionInputBuilder {
+    /// Sets the [`repository_description`](struct.UpdateRepositoryDescriptionInput.html#structfield.repository_description) member.
+    pub fn repository_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.UpdateRepositoryDescriptionInput.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRepositoryDescriptionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateRepositoryDescriptionInput, ::rusoto_core::BuildError> {
+        Ok(UpdateRepositoryDescriptionInput {
+            repository_description: self.repository_description,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the input of an update repository description operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateRepositoryNameInput {
@@ -2708,6 +6944,48 @@ pub struct UpdateRepositoryNameInput {
     pub old_name: String,
 }
 
+impl UpdateRepositoryNameInput {
+    /// Returns a builder for `UpdateRepositoryNameInput`, checking its required members when built.
+    pub fn builder() -> UpdateRepositoryNameInputBuilder {
+        UpdateRepositoryNameInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRepositoryNameInput`](struct.UpdateRepositoryNameInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateRepositoryNameInputBuilder {
+    new_name: Option<String>,
+    old_name: Option<String>,
+}
+
+impl UpdateRepositoryNameInputBuilder {
+    /// Sets the [`new_name`](struct.UpdateRepositoryNameInput.html#structfield.new_name) member.
+    pub fn new_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`old_name`](struct.UpdateRepositoryNameInput.html#structfield.old_name) member.
+    pub fn old_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.old_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRepositoryNameInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateRepositoryNameInput, ::rusoto_core::BuildError> {
+        Ok(UpdateRepositoryNameInput {
+            new_name: self
+                .new_name
+                .ok_or(::rusoto_core::BuildError::missing("new_name"))?,
+            old_name: self
+                .old_name
+                .ok_or(::rusoto_core::BuildError::missing("old_name"))?,
+        })
+    }
+}
+
 /// <p>Information about the user who made a specified commit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -10959,6 +15237,312 @@ pub trait CodeCommit {
         input: UpdateRepositoryNameInput,
     ) -> RusotoFuture<(), UpdateRepositoryNameError>;
 }
+impl<T: ?Sized + CodeCommit> CodeCommit for ::std::sync::Arc<T> {
+    fn batch_describe_merge_conflicts(
+        &self,
+        input: BatchDescribeMergeConflictsInput,
+    ) -> RusotoFuture<BatchDescribeMergeConflictsOutput, BatchDescribeMergeConflictsError> {
+        (**self).batch_describe_merge_conflicts(input)
+    }
+    fn batch_get_commits(
+        &self,
+        input: BatchGetCommitsInput,
+    ) -> RusotoFuture<BatchGetCommitsOutput, BatchGetCommitsError> {
+        (**self).batch_get_commits(input)
+    }
+    fn batch_get_repositories(
+        &self,
+        input: BatchGetRepositoriesInput,
+    ) -> RusotoFuture<BatchGetRepositoriesOutput, BatchGetRepositoriesError> {
+        (**self).batch_get_repositories(input)
+    }
+    fn create_branch(&self, input: CreateBranchInput) -> RusotoFuture<(), CreateBranchError> {
+        (**self).create_branch(input)
+    }
+    fn create_commit(
+        &self,
+        input: CreateCommitInput,
+    ) -> RusotoFuture<CreateCommitOutput, CreateCommitError> {
+        (**self).create_commit(input)
+    }
+    fn create_pull_request(
+        &self,
+        input: CreatePullRequestInput,
+    ) -> RusotoFuture<CreatePullRequestOutput, CreatePullRequestError> {
+        (**self).create_pull_request(input)
+    }
+    fn create_repository(
+        &self,
+        input: CreateRepositoryInput,
+    ) -> RusotoFuture<CreateRepositoryOutput, CreateRepositoryError> {
+        (**self).create_repository(input)
+    }
+    fn create_unreferenced_merge_commit(
+        &self,
+        input: CreateUnreferencedMergeCommitInput,
+    ) -> RusotoFuture<CreateUnreferencedMergeCommitOutput, CreateUnreferencedMergeCommitError> {
+        (**self).create_unreferenced_merge_commit(input)
+    }
+    fn delete_branch(
+        &self,
+        input: DeleteBranchInput,
+    ) -> RusotoFuture<DeleteBranchOutput, DeleteBranchError> {
+        (**self).delete_branch(input)
+    }
+    fn delete_comment_content(
+        &self,
+        input: DeleteCommentContentInput,
+    ) -> RusotoFuture<DeleteCommentContentOutput, DeleteCommentContentError> {
+        (**self).delete_comment_content(input)
+    }
+    fn delete_file(
+        &self,
+        input: DeleteFileInput,
+    ) -> RusotoFuture<DeleteFileOutput, DeleteFileError> {
+        (**self).delete_file(input)
+    }
+    fn delete_repository(
+        &self,
+        input: DeleteRepositoryInput,
+    ) -> RusotoFuture<DeleteRepositoryOutput, DeleteRepositoryError> {
+        (**self).delete_repository(input)
+    }
+    fn describe_merge_conflicts(
+        &self,
+        input: DescribeMergeConflictsInput,
+    ) -> RusotoFuture<DescribeMergeConflictsOutput, DescribeMergeConflictsError> {
+        (**self).describe_merge_conflicts(input)
+    }
+    fn describe_pull_request_events(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> RusotoFuture<DescribePullRequestEventsOutput, DescribePullRequestEventsError> {
+        (**self).describe_pull_request_events(input)
+    }
+    fn get_blob(&self, input: GetBlobInput) -> RusotoFuture<GetBlobOutput, GetBlobError> {
+        (**self).get_blob(input)
+    }
+    fn get_branch(&self, input: GetBranchInput) -> RusotoFuture<GetBranchOutput, GetBranchError> {
+        (**self).get_branch(input)
+    }
+    fn get_comment(
+        &self,
+        input: GetCommentInput,
+    ) -> RusotoFuture<GetCommentOutput, GetCommentError> {
+        (**self).get_comment(input)
+    }
+    fn get_comments_for_compared_commit(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> RusotoFuture<GetCommentsForComparedCommitOutput, GetCommentsForComparedCommitError> {
+        (**self).get_comments_for_compared_commit(input)
+    }
+    fn get_comments_for_pull_request(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> RusotoFuture<GetCommentsForPullRequestOutput, GetCommentsForPullRequestError> {
+        (**self).get_comments_for_pull_request(input)
+    }
+    fn get_commit(&self, input: GetCommitInput) -> RusotoFuture<GetCommitOutput, GetCommitError> {
+        (**self).get_commit(input)
+    }
+    fn get_differences(
+        &self,
+        input: GetDifferencesInput,
+    ) -> RusotoFuture<GetDifferencesOutput, GetDifferencesError> {
+        (**self).get_differences(input)
+    }
+    fn get_file(&self, input: GetFileInput) -> RusotoFuture<GetFileOutput, GetFileError> {
+        (**self).get_file(input)
+    }
+    fn get_folder(&self, input: GetFolderInput) -> RusotoFuture<GetFolderOutput, GetFolderError> {
+        (**self).get_folder(input)
+    }
+    fn get_merge_commit(
+        &self,
+        input: GetMergeCommitInput,
+    ) -> RusotoFuture<GetMergeCommitOutput, GetMergeCommitError> {
+        (**self).get_merge_commit(input)
+    }
+    fn get_merge_conflicts(
+        &self,
+        input: GetMergeConflictsInput,
+    ) -> RusotoFuture<GetMergeConflictsOutput, GetMergeConflictsError> {
+        (**self).get_merge_conflicts(input)
+    }
+    fn get_merge_options(
+        &self,
+        input: GetMergeOptionsInput,
+    ) -> RusotoFuture<GetMergeOptionsOutput, GetMergeOptionsError> {
+        (**self).get_merge_options(input)
+    }
+    fn get_pull_request(
+        &self,
+        input: GetPullRequestInput,
+    ) -> RusotoFuture<GetPullRequestOutput, GetPullRequestError> {
+        (**self).get_pull_request(input)
+    }
+    fn get_repository(
+        &self,
+        input: GetRepositoryInput,
+    ) -> RusotoFuture<GetRepositoryOutput, GetRepositoryError> {
+        (**self).get_repository(input)
+    }
+    fn get_repository_triggers(
+        &self,
+        input: GetRepositoryTriggersInput,
+    ) -> RusotoFuture<GetRepositoryTriggersOutput, GetRepositoryTriggersError> {
+        (**self).get_repository_triggers(input)
+    }
+    fn list_branches(
+        &self,
+        input: ListBranchesInput,
+    ) -> RusotoFuture<ListBranchesOutput, ListBranchesError> {
+        (**self).list_branches(input)
+    }
+    fn list_pull_requests(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> RusotoFuture<ListPullRequestsOutput, ListPullRequestsError> {
+        (**self).list_pull_requests(input)
+    }
+    fn list_repositories(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> RusotoFuture<ListRepositoriesOutput, ListRepositoriesError> {
+        (**self).list_repositories(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn merge_branches_by_fast_forward(
+        &self,
+        input: MergeBranchesByFastForwardInput,
+    ) -> RusotoFuture<MergeBranchesByFastForwardOutput, MergeBranchesByFastForwardError> {
+        (**self).merge_branches_by_fast_forward(input)
+    }
+    fn merge_branches_by_squash(
+        &self,
+        input: MergeBranchesBySquashInput,
+    ) -> RusotoFuture<MergeBranchesBySquashOutput, MergeBranchesBySquashError> {
+        (**self).merge_branches_by_squash(input)
+    }
+    fn merge_branches_by_three_way(
+        &self,
+        input: MergeBranchesByThreeWayInput,
+    ) -> RusotoFuture<MergeBranchesByThreeWayOutput, MergeBranchesByThreeWayError> {
+        (**self).merge_branches_by_three_way(input)
+    }
+    fn merge_pull_request_by_fast_forward(
+        &self,
+        input: MergePullRequestByFastForwardInput,
+    ) -> RusotoFuture<MergePullRequestByFastForwardOutput, MergePullRequestByFastForwardError> {
+        (**self).merge_pull_request_by_fast_forward(input)
+    }
+    fn merge_pull_request_by_squash(
+        &self,
+        input: MergePullRequestBySquashInput,
+    ) -> RusotoFuture<MergePullRequestBySquashOutput, MergePullRequestBySquashError> {
+        (**self).merge_pull_request_by_squash(input)
+    }
+    fn merge_pull_request_by_three_way(
+        &self,
+        input: MergePullRequestByThreeWayInput,
+    ) -> RusotoFuture<MergePullRequestByThreeWayOutput, MergePullRequestByThreeWayError> {
+        (**self).merge_pull_request_by_three_way(input)
+    }
+    fn post_comment_for_compared_commit(
+        &self,
+        input: PostCommentForComparedCommitInput,
+    ) -> RusotoFuture<PostCommentForComparedCommitOutput, PostCommentForComparedCommitError> {
+        (**self).post_comment_for_compared_commit(input)
+    }
+    fn post_comment_for_pull_request(
+        &self,
+        input: PostCommentForPullRequestInput,
+    ) -> RusotoFuture<PostCommentForPullRequestOutput, PostCommentForPullRequestError> {
+        (**self).post_comment_for_pull_request(input)
+    }
+    fn post_comment_reply(
+        &self,
+        input: PostCommentReplyInput,
+    ) -> RusotoFuture<PostCommentReplyOutput, PostCommentReplyError> {
+        (**self).post_comment_reply(input)
+    }
+    fn put_file(&self, input: PutFileInput) -> RusotoFuture<PutFileOutput, PutFileError> {
+        (**self).put_file(input)
+    }
+    fn put_repository_triggers(
+        &self,
+        input: PutRepositoryTriggersInput,
+    ) -> RusotoFuture<PutRepositoryTriggersOutput, PutRepositoryTriggersError> {
+        (**self).put_repository_triggers(input)
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_repository_triggers(
+        &self,
+        input: TestRepositoryTriggersInput,
+    ) -> RusotoFuture<TestRepositoryTriggersOutput, TestRepositoryTriggersError> {
+        (**self).test_repository_triggers(input)
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_comment(
+        &self,
+        input: UpdateCommentInput,
+    ) -> RusotoFuture<UpdateCommentOutput, UpdateCommentError> {
+        (**self).update_comment(input)
+    }
+    fn update_default_branch(
+        &self,
+        input: UpdateDefaultBranchInput,
+    ) -> RusotoFuture<(), UpdateDefaultBranchError> {
+        (**self).update_default_branch(input)
+    }
+    fn update_pull_request_description(
+        &self,
+        input: UpdatePullRequestDescriptionInput,
+    ) -> RusotoFuture<UpdatePullRequestDescriptionOutput, UpdatePullRequestDescriptionError> {
+        (**self).update_pull_request_description(input)
+    }
+    fn update_pull_request_status(
+        &self,
+        input: UpdatePullRequestStatusInput,
+    ) -> RusotoFuture<UpdatePullRequestStatusOutput, UpdatePullRequestStatusError> {
+        (**self).update_pull_request_status(input)
+    }
+    fn update_pull_request_title(
+        &self,
+        input: UpdatePullRequestTitleInput,
+    ) -> RusotoFuture<UpdatePullRequestTitleOutput, UpdatePullRequestTitleError> {
+        (**self).update_pull_request_title(input)
+    }
+    fn update_repository_description(
+        &self,
+        input: UpdateRepositoryDescriptionInput,
+    ) -> RusotoFuture<(), UpdateRepositoryDescriptionError> {
+        (**self).update_repository_description(input)
+    }
+    fn update_repository_name(
+        &self,
+        input: UpdateRepositoryNameInput,
+    ) -> RusotoFuture<(), UpdateRepositoryNameError> {
+        (**self).update_repository_name(input)
+    }
+}
+/// The error codes CodeCommit retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the CodeCommit API.
 #[derive(Clone)]
 pub struct CodeCommitClient {
@@ -10974,6 +15558,27 @@ impl CodeCommitClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CODECOMMIT`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CodeCommitClient {
+        Self::new(region::Region::from_env_for_service("CODECOMMIT"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `codecommit`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CodeCommitClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("codecommit", "CODECOMMIT"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -10992,7 +15597,54 @@ impl CodeCommitClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodeCommitClient {
-        CodeCommitClient { client, region }
+        CodeCommitClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "codecommit")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "codecommit",
+            "codecommit",
+            "CODECOMMIT",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CodeCommit` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CodeCommit + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CodeCommitClient {
+    fn service_name(&self) -> &'static str {
+        "CodeCommit"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "codecommit"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CodeCommitClient {
+        Self::new_with_client(client, region)
     }
 }
 
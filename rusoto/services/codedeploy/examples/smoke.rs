@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS CodeDeploy clients.
+//!
+//! Makes a single read-only `ListApplications` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_codedeploy::{CodeDeploy, CodeDeployClient, ListApplicationsInput};
+
+fn main() {
+    let client = CodeDeployClient::from_env();
+    match client
+        .list_applications(ListApplicationsInput::default())
+        .sync()
+    {
+        Ok(_) => println!("ListApplications succeeded"),
+        Err(error) => {
+            eprintln!("ListApplications failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
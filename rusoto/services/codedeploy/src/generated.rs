@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Represents the input of, and adds tags to, an on-premises instance operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -34,6 +35,48 @@ pub struct AddTagsToOnPremisesInstancesInput {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsToOnPremisesInstancesInput {
+    /// Returns a builder for `AddTagsToOnPremisesInstancesInput`, checking its required members when built.
+    pub fn builder() -> AddTagsToOnPremisesInstancesInputBuilder {
+        AddTagsToOnPremisesInstancesInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsToOnPremisesInstancesInput`](struct.AddTagsToOnPremisesInstancesInput.html).
+#[derive(Default, Debug)]
+pub struct AddTagsToOnPremisesInstancesInputBuilder {
+    instance_names: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsToOnPremisesInstancesInputBuilder {
+    /// Sets the [`instance_names`](struct.AddTagsToOnPremisesInstancesInput.html#structfield.instance_names) member.
+    pub fn instance_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsToOnPremisesInstancesInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsToOnPremisesInstancesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddTagsToOnPremisesInstancesInput, ::rusoto_core::BuildError> {
+        Ok(AddTagsToOnPremisesInstancesInput {
+            instance_names: self
+                .instance_names
+                .ok_or(::rusoto_core::BuildError::missing("instance_names"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>Information about an alarm.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Alarm {
@@ -88,11 +131,12 @@ pub struct ApplicationInfo {
     /// <p>The destination platform type for deployment of the application (<code>Lambda</code> or <code>Server</code>).</p>
     #[serde(rename = "computePlatform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_platform: Option<String>,
+    pub compute_platform: Option<ComputePlatform>,
     /// <p>The time at which the application was created.</p>
     #[serde(rename = "createTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<EpochTimestamp>,
     /// <p>The name for a connection to a GitHub account.</p>
     #[serde(rename = "gitHubAccountName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -103,6 +147,82 @@ pub struct ApplicationInfo {
     pub linked_to_git_hub: Option<bool>,
 }
 
+/// <p>The column name to use to sort the list results:</p> <ul> <li> <p>registerTime: Sort by the time the revisions were registered with AWS CodeDeploy.</p> </li> <li> <p>firstUsedTime: Sort by the time the revisions were first used in a deployment.</p> </li> <li> <p>lastUsedTime: Sort by the time the revisions were last used in a deployment.</p> </li> </ul> <p> If not specified or set to null, the results are returned in an arbitrary order. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApplicationRevisionSortBy {
+    /// <p><code>registerTime</code></p>
+    RegisterTime,
+    /// <p><code>firstUsedTime</code></p>
+    FirstUsedTime,
+    /// <p><code>lastUsedTime</code></p>
+    LastUsedTime,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ApplicationRevisionSortBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplicationRevisionSortBy::RegisterTime => "registerTime",
+            ApplicationRevisionSortBy::FirstUsedTime => "firstUsedTime",
+            ApplicationRevisionSortBy::LastUsedTime => "lastUsedTime",
+            ApplicationRevisionSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ApplicationRevisionSortBy {
+    fn from(value: &'a str) -> ApplicationRevisionSortBy {
+        match value {
+            "registerTime" => ApplicationRevisionSortBy::RegisterTime,
+            "firstUsedTime" => ApplicationRevisionSortBy::FirstUsedTime,
+            "lastUsedTime" => ApplicationRevisionSortBy::LastUsedTime,
+            _ => ApplicationRevisionSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplicationRevisionSortBy {
+    fn from(value: String) -> ApplicationRevisionSortBy {
+        ApplicationRevisionSortBy::from(value.as_str())
+    }
+}
+
+impl From<ApplicationRevisionSortBy> for String {
+    fn from(value: ApplicationRevisionSortBy) -> String {
+        match value {
+            ApplicationRevisionSortBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ApplicationRevisionSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ApplicationRevisionSortBy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApplicationRevisionSortBy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ApplicationRevisionSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ApplicationRevisionSortBy::from(value))
+    }
+}
+
 /// <p>Information about a configuration for automatically rolling back to a previous version of an application revision when a deployment is not completed successfully.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AutoRollbackConfiguration {
@@ -113,7 +233,83 @@ pub struct AutoRollbackConfiguration {
     /// <p>The event type or types that trigger a rollback.</p>
     #[serde(rename = "events")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub events: Option<Vec<String>>,
+    pub events: Option<Vec<AutoRollbackEvent>>,
+}
+
+/// <p>The event type or types that trigger a rollback.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AutoRollbackEvent {
+    /// <p><code>DEPLOYMENT_FAILURE</code></p>
+    DeploymentFailure,
+    /// <p><code>DEPLOYMENT_STOP_ON_ALARM</code></p>
+    DeploymentStopOnAlarm,
+    /// <p><code>DEPLOYMENT_STOP_ON_REQUEST</code></p>
+    DeploymentStopOnRequest,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AutoRollbackEvent {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoRollbackEvent::DeploymentFailure => "DEPLOYMENT_FAILURE",
+            AutoRollbackEvent::DeploymentStopOnAlarm => "DEPLOYMENT_STOP_ON_ALARM",
+            AutoRollbackEvent::DeploymentStopOnRequest => "DEPLOYMENT_STOP_ON_REQUEST",
+            AutoRollbackEvent::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AutoRollbackEvent {
+    fn from(value: &'a str) -> AutoRollbackEvent {
+        match value {
+            "DEPLOYMENT_FAILURE" => AutoRollbackEvent::DeploymentFailure,
+            "DEPLOYMENT_STOP_ON_ALARM" => AutoRollbackEvent::DeploymentStopOnAlarm,
+            "DEPLOYMENT_STOP_ON_REQUEST" => AutoRollbackEvent::DeploymentStopOnRequest,
+            _ => AutoRollbackEvent::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoRollbackEvent {
+    fn from(value: String) -> AutoRollbackEvent {
+        AutoRollbackEvent::from(value.as_str())
+    }
+}
+
+impl From<AutoRollbackEvent> for String {
+    fn from(value: AutoRollbackEvent) -> String {
+        match value {
+            AutoRollbackEvent::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AutoRollbackEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AutoRollbackEvent {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutoRollbackEvent {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AutoRollbackEvent, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AutoRollbackEvent::from(value))
+    }
 }
 
 /// <p>Information about an Auto Scaling group.</p>
@@ -141,6 +337,48 @@ pub struct BatchGetApplicationRevisionsInput {
     pub revisions: Vec<RevisionLocation>,
 }
 
+impl BatchGetApplicationRevisionsInput {
+    /// Returns a builder for `BatchGetApplicationRevisionsInput`, checking its required members when built.
+    pub fn builder() -> BatchGetApplicationRevisionsInputBuilder {
+        BatchGetApplicationRevisionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetApplicationRevisionsInput`](struct.BatchGetApplicationRevisionsInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetApplicationRevisionsInputBuilder {
+    application_name: Option<String>,
+    revisions: Option<Vec<RevisionLocation>>,
+}
+
+impl BatchGetApplicationRevisionsInputBuilder {
+    /// Sets the [`application_name`](struct.BatchGetApplicationRevisionsInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revisions`](struct.BatchGetApplicationRevisionsInput.html#structfield.revisions) member.
+    pub fn revisions<T: Into<Vec<RevisionLocation>>>(mut self, value: T) -> Self {
+        self.revisions = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetApplicationRevisionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetApplicationRevisionsInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetApplicationRevisionsInput {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            revisions: self
+                .revisions
+                .ok_or(::rusoto_core::BuildError::missing("revisions"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a BatchGetApplicationRevisions operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -167,6 +405,38 @@ pub struct BatchGetApplicationsInput {
     pub application_names: Vec<String>,
 }
 
+impl BatchGetApplicationsInput {
+    /// Returns a builder for `BatchGetApplicationsInput`, checking its required members when built.
+    pub fn builder() -> BatchGetApplicationsInputBuilder {
+        BatchGetApplicationsInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetApplicationsInput`](struct.BatchGetApplicationsInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetApplicationsInputBuilder {
+    application_names: Option<Vec<String>>,
+}
+
+impl BatchGetApplicationsInputBuilder {
+    /// Sets the [`application_names`](struct.BatchGetApplicationsInput.html#structfield.application_names) member.
+    pub fn application_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.application_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetApplicationsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetApplicationsInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetApplicationsInput {
+            application_names: self
+                .application_names
+                .ok_or(::rusoto_core::BuildError::missing("application_names"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a BatchGetApplications operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -188,6 +458,48 @@ pub struct BatchGetDeploymentGroupsInput {
     pub deployment_group_names: Vec<String>,
 }
 
+impl BatchGetDeploymentGroupsInput {
+    /// Returns a builder for `BatchGetDeploymentGroupsInput`, checking its required members when built.
+    pub fn builder() -> BatchGetDeploymentGroupsInputBuilder {
+        BatchGetDeploymentGroupsInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetDeploymentGroupsInput`](struct.BatchGetDeploymentGroupsInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetDeploymentGroupsInputBuilder {
+    application_name: Option<String>,
+    deployment_group_names: Option<Vec<String>>,
+}
+
+impl BatchGetDeploymentGroupsInputBuilder {
+    /// Sets the [`application_name`](struct.BatchGetDeploymentGroupsInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_group_names`](struct.BatchGetDeploymentGroupsInput.html#structfield.deployment_group_names) member.
+    pub fn deployment_group_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.deployment_group_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetDeploymentGroupsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetDeploymentGroupsInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetDeploymentGroupsInput {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            deployment_group_names: self
+                .deployment_group_names
+                .ok_or(::rusoto_core::BuildError::missing("deployment_group_names"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a BatchGetDeploymentGroups operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -213,6 +525,48 @@ pub struct BatchGetDeploymentInstancesInput {
     pub instance_ids: Vec<String>,
 }
 
+impl BatchGetDeploymentInstancesInput {
+    /// Returns a builder for `BatchGetDeploymentInstancesInput`, checking its required members when built.
+    pub fn builder() -> BatchGetDeploymentInstancesInputBuilder {
+        BatchGetDeploymentInstancesInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetDeploymentInstancesInput`](struct.BatchGetDeploymentInstancesInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetDeploymentInstancesInputBuilder {
+    deployment_id: Option<String>,
+    instance_ids: Option<Vec<String>>,
+}
+
+impl BatchGetDeploymentInstancesInputBuilder {
+    /// Sets the [`deployment_id`](struct.BatchGetDeploymentInstancesInput.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_ids`](struct.BatchGetDeploymentInstancesInput.html#structfield.instance_ids) member.
+    pub fn instance_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetDeploymentInstancesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetDeploymentInstancesInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetDeploymentInstancesInput {
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+            instance_ids: self
+                .instance_ids
+                .ok_or(::rusoto_core::BuildError::missing("instance_ids"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a BatchGetDeploymentInstances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -239,6 +593,44 @@ pub struct BatchGetDeploymentTargetsInput {
     pub target_ids: Option<Vec<String>>,
 }
 
+impl BatchGetDeploymentTargetsInput {
+    /// Returns a builder for `BatchGetDeploymentTargetsInput`, checking its required members when built.
+    pub fn builder() -> BatchGetDeploymentTargetsInputBuilder {
+        BatchGetDeploymentTargetsInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetDeploymentTargetsInput`](struct.BatchGetDeploymentTargetsInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetDeploymentTargetsInputBuilder {
+    deployment_id: Option<String>,
+    target_ids: Option<Vec<String>>,
+}
+
+impl BatchGetDeploymentTargetsInputBuilder {
+    /// Sets the [`deployment_id`](struct.BatchGetDeploymentTargetsInput.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_ids`](struct.BatchGetDeploymentTargetsInput.html#structfield.target_ids) member.
+    pub fn target_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.target_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetDeploymentTargetsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetDeploymentTargetsInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetDeploymentTargetsInput {
+            deployment_id: self.deployment_id,
+            target_ids: self.target_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetDeploymentTargetsOutput {
@@ -256,6 +648,38 @@ pub struct BatchGetDeploymentsInput {
     pub deployment_ids: Vec<String>,
 }
 
+impl BatchGetDeploymentsInput {
+    /// Returns a builder for `BatchGetDeploymentsInput`, checking its required members when built.
+    pub fn builder() -> BatchGetDeploymentsInputBuilder {
+        BatchGetDeploymentsInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetDeploymentsInput`](struct.BatchGetDeploymentsInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetDeploymentsInputBuilder {
+    deployment_ids: Option<Vec<String>>,
+}
+
+impl BatchGetDeploymentsInputBuilder {
+    /// Sets the [`deployment_ids`](struct.BatchGetDeploymentsInput.html#structfield.deployment_ids) member.
+    pub fn deployment_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.deployment_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetDeploymentsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetDeploymentsInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetDeploymentsInput {
+            deployment_ids: self
+                .deployment_ids
+                .ok_or(::rusoto_core::BuildError::missing("deployment_ids"))?,
+        })
+    }
+}
+
 /// <p> Represents the output of a BatchGetDeployments operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -274,6 +698,38 @@ pub struct BatchGetOnPremisesInstancesInput {
     pub instance_names: Vec<String>,
 }
 
+impl BatchGetOnPremisesInstancesInput {
+    /// Returns a builder for `BatchGetOnPremisesInstancesInput`, checking its required members when built.
+    pub fn builder() -> BatchGetOnPremisesInstancesInputBuilder {
+        BatchGetOnPremisesInstancesInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetOnPremisesInstancesInput`](struct.BatchGetOnPremisesInstancesInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetOnPremisesInstancesInputBuilder {
+    instance_names: Option<Vec<String>>,
+}
+
+impl BatchGetOnPremisesInstancesInputBuilder {
+    /// Sets the [`instance_names`](struct.BatchGetOnPremisesInstancesInput.html#structfield.instance_names) member.
+    pub fn instance_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetOnPremisesInstancesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetOnPremisesInstancesInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetOnPremisesInstancesInput {
+            instance_names: self
+                .instance_names
+                .ok_or(::rusoto_core::BuildError::missing("instance_names"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a BatchGetOnPremisesInstances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -307,13 +763,173 @@ pub struct BlueInstanceTerminationOption {
     /// <p><p>The action to take on instances in the original environment after a successful blue/green deployment.</p> <ul> <li> <p>TERMINATE: Instances are terminated after a specified wait time.</p> </li> <li> <p>KEEP_ALIVE: Instances are left running after they are deregistered from the load balancer and removed from the deployment group.</p> </li> </ul></p>
     #[serde(rename = "action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<InstanceAction>,
     /// <p>For an Amazon EC2 deployment, the number of minutes to wait after a successful blue/green deployment before terminating instances from the original environment.</p> <p> For an Amazon ECS deployment, the number of minutes before deleting the original (blue) task set. During an Amazon ECS deployment, CodeDeploy shifts traffic from the original (blue) task set to a replacement (green) task set. </p> <p> The maximum setting is 2880 minutes (2 days). </p>
     #[serde(rename = "terminationWaitTimeInMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub termination_wait_time_in_minutes: Option<i64>,
 }
 
+/// <p><p>The file type of the application revision. Must be one of the following:</p> <ul> <li> <p>tar: A tar archive file.</p> </li> <li> <p>tgz: A compressed tar archive file.</p> </li> <li> <p>zip: A zip archive file.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BundleType {
+    /// <p><code>tar</code></p>
+    Tar,
+    /// <p><code>tgz</code></p>
+    Tgz,
+    /// <p><code>zip</code></p>
+    Zip,
+    /// <p><code>YAML</code></p>
+    Yaml,
+    /// <p><code>JSON</code></p>
+    Json,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BundleType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BundleType::Tar => "tar",
+            BundleType::Tgz => "tgz",
+            BundleType::Zip => "zip",
+            BundleType::Yaml => "YAML",
+            BundleType::Json => "JSON",
+            BundleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BundleType {
+    fn from(value: &'a str) -> BundleType {
+        match value {
+            "tar" => BundleType::Tar,
+            "tgz" => BundleType::Tgz,
+            "zip" => BundleType::Zip,
+            "YAML" => BundleType::Yaml,
+            "JSON" => BundleType::Json,
+            _ => BundleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BundleType {
+    fn from(value: String) -> BundleType {
+        BundleType::from(value.as_str())
+    }
+}
+
+impl From<BundleType> for String {
+    fn from(value: BundleType) -> String {
+        match value {
+            BundleType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BundleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BundleType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BundleType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BundleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BundleType::from(value))
+    }
+}
+
+/// <p>The destination platform type for deployment of the application (<code>Lambda</code> or <code>Server</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComputePlatform {
+    /// <p><code>Server</code></p>
+    Server,
+    /// <p><code>Lambda</code></p>
+    Lambda,
+    /// <p><code>ECS</code></p>
+    Ecs,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComputePlatform {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComputePlatform::Server => "Server",
+            ComputePlatform::Lambda => "Lambda",
+            ComputePlatform::Ecs => "ECS",
+            ComputePlatform::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComputePlatform {
+    fn from(value: &'a str) -> ComputePlatform {
+        match value {
+            "Server" => ComputePlatform::Server,
+            "Lambda" => ComputePlatform::Lambda,
+            "ECS" => ComputePlatform::Ecs,
+            _ => ComputePlatform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComputePlatform {
+    fn from(value: String) -> ComputePlatform {
+        ComputePlatform::from(value.as_str())
+    }
+}
+
+impl From<ComputePlatform> for String {
+    fn from(value: ComputePlatform) -> String {
+        match value {
+            ComputePlatform::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComputePlatform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ComputePlatform {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComputePlatform {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ComputePlatform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ComputePlatform::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ContinueDeploymentInput {
     /// <p> The unique ID of a blue/green deployment for which you want to start rerouting traffic to the replacement environment. </p>
@@ -323,7 +939,45 @@ pub struct ContinueDeploymentInput {
     /// <p> The status of the deployment's waiting period. READY_WAIT indicates the deployment is ready to start shifting traffic. TERMINATION_WAIT indicates the traffic is shifted, but the original target is not terminated. </p>
     #[serde(rename = "deploymentWaitType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_wait_type: Option<String>,
+    pub deployment_wait_type: Option<DeploymentWaitType>,
+}
+
+impl ContinueDeploymentInput {
+    /// Returns a builder for `ContinueDeploymentInput`, checking its required members when built.
+    pub fn builder() -> ContinueDeploymentInputBuilder {
+        ContinueDeploymentInputBuilder::default()
+    }
+}
+
+/// Builds a [`ContinueDeploymentInput`](struct.ContinueDeploymentInput.html).
+#[derive(Default, Debug)]
+pub struct ContinueDeploymentInputBuilder {
+    deployment_id: Option<String>,
+    deployment_wait_type: Option<DeploymentWaitType>,
+}
+
+impl ContinueDeploymentInputBuilder {
+    /// Sets the [`deployment_id`](struct.ContinueDeploymentInput.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_wait_type`](struct.ContinueDeploymentInput.html#structfield.deployment_wait_type) member.
+    pub fn deployment_wait_type<T: Into<DeploymentWaitType>>(mut self, value: T) -> Self {
+        self.deployment_wait_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ContinueDeploymentInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ContinueDeploymentInput, ::rusoto_core::BuildError> {
+        Ok(ContinueDeploymentInput {
+            deployment_id: self.deployment_id,
+            deployment_wait_type: self.deployment_wait_type,
+        })
+    }
 }
 
 /// <p>Represents the input of a CreateApplication operation.</p>
@@ -335,13 +989,59 @@ pub struct CreateApplicationInput {
     /// <p> The destination platform type for the deployment (<code>Lambda</code>, <code>Server</code>, or <code>ECS</code>).</p>
     #[serde(rename = "computePlatform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_platform: Option<String>,
+    pub compute_platform: Option<ComputePlatform>,
     /// <p> The metadata that you apply to CodeDeploy applications to help you organize and categorize them. Each tag consists of a key and an optional value, both of which you define. </p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateApplicationInput {
+    /// Returns a builder for `CreateApplicationInput`, checking its required members when built.
+    pub fn builder() -> CreateApplicationInputBuilder {
+        CreateApplicationInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApplicationInput`](struct.CreateApplicationInput.html).
+#[derive(Default, Debug)]
+pub struct CreateApplicationInputBuilder {
+    application_name: Option<String>,
+    compute_platform: Option<ComputePlatform>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateApplicationInputBuilder {
+    /// Sets the [`application_name`](struct.CreateApplicationInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`compute_platform`](struct.CreateApplicationInput.html#structfield.compute_platform) member.
+    pub fn compute_platform<T: Into<ComputePlatform>>(mut self, value: T) -> Self {
+        self.compute_platform = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateApplicationInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApplicationInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateApplicationInput, ::rusoto_core::BuildError> {
+        Ok(CreateApplicationInput {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            compute_platform: self.compute_platform,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p>Represents the output of a CreateApplication operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -358,7 +1058,7 @@ pub struct CreateDeploymentConfigInput {
     /// <p>The destination platform type for the deployment (<code>Lambda</code>, <code>Server</code>, or <code>ECS</code>).</p>
     #[serde(rename = "computePlatform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_platform: Option<String>,
+    pub compute_platform: Option<ComputePlatform>,
     /// <p>The name of the deployment configuration to create.</p>
     #[serde(rename = "deploymentConfigName")]
     pub deployment_config_name: String,
@@ -372,10 +1072,66 @@ pub struct CreateDeploymentConfigInput {
     pub traffic_routing_config: Option<TrafficRoutingConfig>,
 }
 
-/// <p>Represents the output of a CreateDeploymentConfig operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CreateDeploymentConfigOutput {
+impl CreateDeploymentConfigInput {
+    /// Returns a builder for `CreateDeploymentConfigInput`, checking its required members when built.
+    pub fn builder() -> CreateDeploymentConfigInputBuilder {
+        CreateDeploymentConfigInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDeploymentConfigInput`](struct.CreateDeploymentConfigInput.html).
+#[derive(Default, Debug)]
+pub struct CreateDeploymentConfigInputBuilder {
+    compute_platform: Option<ComputePlatform>,
+    deployment_config_name: Option<String>,
+    minimum_healthy_hosts: Option<MinimumHealthyHosts>,
+    traffic_routing_config: Option<TrafficRoutingConfig>,
+}
+
+impl CreateDeploymentConfigInputBuilder {
+    /// Sets the [`compute_platform`](struct.CreateDeploymentConfigInput.html#structfield.compute_platform) member.
+    pub fn compute_platform<T: Into<ComputePlatform>>(mut self, value: T) -> Self {
+        self.compute_platform = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_config_name`](struct.CreateDeploymentConfigInput.html#structfield.deployment_config_name) member.
+    pub fn deployment_config_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_config_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`minimum_healthy_hosts`](struct.CreateDeploymentConfigInput.html#structfield.minimum_healthy_hosts) member.
+    pub fn minimum_healthy_hosts<T: Into<MinimumHealthyHosts>>(mut self, value: T) -> Self {
+        self.minimum_healthy_hosts = Some(value.into());
+        self
+    }
+
+    /// Sets the [`traffic_routing_config`](struct.CreateDeploymentConfigInput.html#structfield.traffic_routing_config) member.
+    pub fn traffic_routing_config<T: Into<TrafficRoutingConfig>>(mut self, value: T) -> Self {
+        self.traffic_routing_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDeploymentConfigInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDeploymentConfigInput, ::rusoto_core::BuildError> {
+        Ok(CreateDeploymentConfigInput {
+            compute_platform: self.compute_platform,
+            deployment_config_name: self
+                .deployment_config_name
+                .ok_or(::rusoto_core::BuildError::missing("deployment_config_name"))?,
+            minimum_healthy_hosts: self.minimum_healthy_hosts,
+            traffic_routing_config: self.traffic_routing_config,
+        })
+    }
+}
+
+/// <p>Represents the output of a CreateDeploymentConfig operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateDeploymentConfigOutput {
     /// <p>A unique deployment configuration ID.</p>
     #[serde(rename = "deploymentConfigId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -452,6 +1208,176 @@ pub struct CreateDeploymentGroupInput {
     pub trigger_configurations: Option<Vec<TriggerConfig>>,
 }
 
+impl CreateDeploymentGroupInput {
+    /// Returns a builder for `CreateDeploymentGroupInput`, checking its required members when built.
+    pub fn builder() -> CreateDeploymentGroupInputBuilder {
+        CreateDeploymentGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDeploymentGroupInput`](struct.CreateDeploymentGroupInput.html).
+#[derive(Default, Debug)]
+pub struct CreateDeploymentGroupInputBuilder {
+    alarm_configuration: Option<AlarmConfiguration>,
+    application_name: Option<String>,
+    auto_rollback_configuration: Option<AutoRollbackConfiguration>,
+    auto_scaling_groups: Option<Vec<String>>,
+    blue_green_deployment_configuration: Option<BlueGreenDeploymentConfiguration>,
+    deployment_config_name: Option<String>,
+    deployment_group_name: Option<String>,
+    deployment_style: Option<DeploymentStyle>,
+    ec_2_tag_filters: Option<Vec<EC2TagFilter>>,
+    ec_2_tag_set: Option<EC2TagSet>,
+    ecs_services: Option<Vec<ECSService>>,
+    load_balancer_info: Option<LoadBalancerInfo>,
+    on_premises_instance_tag_filters: Option<Vec<TagFilter>>,
+    on_premises_tag_set: Option<OnPremisesTagSet>,
+    service_role_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+    trigger_configurations: Option<Vec<TriggerConfig>>,
+}
+
+impl CreateDeploymentGroupInputBuilder {
+    /// Sets the [`alarm_configuration`](struct.CreateDeploymentGroupInput.html#structfield.alarm_configuration) member.
+    pub fn alarm_configuration<T: Into<AlarmConfiguration>>(mut self, value: T) -> Self {
+        self.alarm_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`application_name`](struct.CreateDeploymentGroupInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_rollback_configuration`](struct.CreateDeploymentGroupInput.html#structfield.auto_rollback_configuration) member.
+    pub fn auto_rollback_configuration<T: Into<AutoRollbackConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.auto_rollback_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_scaling_groups`](struct.CreateDeploymentGroupInput.html#structfield.auto_scaling_groups) member.
+    pub fn auto_scaling_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.auto_scaling_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`blue_green_deployment_configuration`](struct.CreateDeploymentGroupInput.html#structfield.blue_green_deployment_configuration) member.
+    pub fn blue_green_deployment_configuration<T: Into<BlueGreenDeploymentConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.blue_green_deployment_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_config_name`](struct.CreateDeploymentGroupInput.html#structfield.deployment_config_name) member.
+    pub fn deployment_config_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_config_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_group_name`](struct.CreateDeploymentGroupInput.html#structfield.deployment_group_name) member.
+    pub fn deployment_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_style`](struct.CreateDeploymentGroupInput.html#structfield.deployment_style) member.
+    pub fn deployment_style<T: Into<DeploymentStyle>>(mut self, value: T) -> Self {
+        self.deployment_style = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec_2_tag_filters`](struct.CreateDeploymentGroupInput.html#structfield.ec_2_tag_filters) member.
+    pub fn ec_2_tag_filters<T: Into<Vec<EC2TagFilter>>>(mut self, value: T) -> Self {
+        self.ec_2_tag_filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec_2_tag_set`](struct.CreateDeploymentGroupInput.html#structfield.ec_2_tag_set) member.
+    pub fn ec_2_tag_set<T: Into<EC2TagSet>>(mut self, value: T) -> Self {
+        self.ec_2_tag_set = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ecs_services`](struct.CreateDeploymentGroupInput.html#structfield.ecs_services) member.
+    pub fn ecs_services<T: Into<Vec<ECSService>>>(mut self, value: T) -> Self {
+        self.ecs_services = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_info`](struct.CreateDeploymentGroupInput.html#structfield.load_balancer_info) member.
+    pub fn load_balancer_info<T: Into<LoadBalancerInfo>>(mut self, value: T) -> Self {
+        self.load_balancer_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`on_premises_instance_tag_filters`](struct.CreateDeploymentGroupInput.html#structfield.on_premises_instance_tag_filters) member.
+    pub fn on_premises_instance_tag_filters<T: Into<Vec<TagFilter>>>(mut self, value: T) -> Self {
+        self.on_premises_instance_tag_filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`on_premises_tag_set`](struct.CreateDeploymentGroupInput.html#structfield.on_premises_tag_set) member.
+    pub fn on_premises_tag_set<T: Into<OnPremisesTagSet>>(mut self, value: T) -> Self {
+        self.on_premises_tag_set = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role_arn`](struct.CreateDeploymentGroupInput.html#structfield.service_role_arn) member.
+    pub fn service_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDeploymentGroupInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trigger_configurations`](struct.CreateDeploymentGroupInput.html#structfield.trigger_configurations) member.
+    pub fn trigger_configurations<T: Into<Vec<TriggerConfig>>>(mut self, value: T) -> Self {
+        self.trigger_configurations = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDeploymentGroupInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDeploymentGroupInput, ::rusoto_core::BuildError> {
+        Ok(CreateDeploymentGroupInput {
+            alarm_configuration: self.alarm_configuration,
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            auto_rollback_configuration: self.auto_rollback_configuration,
+            auto_scaling_groups: self.auto_scaling_groups,
+            blue_green_deployment_configuration: self.blue_green_deployment_configuration,
+            deployment_config_name: self.deployment_config_name,
+            deployment_group_name: self
+                .deployment_group_name
+                .ok_or(::rusoto_core::BuildError::missing("deployment_group_name"))?,
+            deployment_style: self.deployment_style,
+            ec_2_tag_filters: self.ec_2_tag_filters,
+            ec_2_tag_set: self.ec_2_tag_set,
+            ecs_services: self.ecs_services,
+            load_balancer_info: self.load_balancer_info,
+            on_premises_instance_tag_filters: self.on_premises_instance_tag_filters,
+            on_premises_tag_set: self.on_premises_tag_set,
+            service_role_arn: self
+                .service_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("service_role_arn"))?,
+            tags: self.tags,
+            trigger_configurations: self.trigger_configurations,
+        })
+    }
+}
+
 /// <p>Represents the output of a CreateDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -487,7 +1413,7 @@ pub struct CreateDeploymentInput {
     /// <p><p>Information about how AWS CodeDeploy handles files that already exist in a deployment target location but weren&#39;t part of the previous successful deployment.</p> <p>The fileExistsBehavior parameter takes any of the following values:</p> <ul> <li> <p>DISALLOW: The deployment fails. This is also the default behavior if no option is specified.</p> </li> <li> <p>OVERWRITE: The version of the file from the application revision currently being deployed replaces the version already on the instance.</p> </li> <li> <p>RETAIN: The version of the file already on the instance is kept and used as part of the new deployment.</p> </li> </ul></p>
     #[serde(rename = "fileExistsBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_exists_behavior: Option<String>,
+    pub file_exists_behavior: Option<FileExistsBehavior>,
     /// <p> If true, then if an ApplicationStop, BeforeBlockTraffic, or AfterBlockTraffic deployment lifecycle event to an instance fails, then the deployment continues to the next deployment lifecycle event. For example, if ApplicationStop fails, the deployment continues with DownloadBundle. If BeforeBlockTraffic fails, the deployment continues with BlockTraffic. If AfterBlockTraffic fails, the deployment continues with ApplicationStop. </p> <p> If false or not specified, then if a lifecycle event fails during a deployment to an instance, that deployment fails. If deployment to that instance is part of an overall deployment and the number of healthy hosts is not less than the minimum number of healthy hosts, then a deployment to the next instance is attempted. </p> <p> During a deployment, the AWS CodeDeploy agent runs the scripts specified for ApplicationStop, BeforeBlockTraffic, and AfterBlockTraffic in the AppSpec file from the previous successful deployment. (All other scripts are run from the AppSpec file in the current deployment.) If one of these scripts contains an error and does not run successfully, the deployment can fail. </p> <p> If the cause of the failure is a script from the last successful deployment that will never run successfully, create a new deployment and use <code>ignoreApplicationStopFailures</code> to specify that the ApplicationStop, BeforeBlockTraffic, and AfterBlockTraffic failures should be ignored. </p>
     #[serde(rename = "ignoreApplicationStopFailures")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -506,6 +1432,111 @@ pub struct CreateDeploymentInput {
     pub update_outdated_instances_only: Option<bool>,
 }
 
+impl CreateDeploymentInput {
+    /// Returns a builder for `CreateDeploymentInput`, checking its required members when built.
+    pub fn builder() -> CreateDeploymentInputBuilder {
+        CreateDeploymentInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDeploymentInput`](struct.CreateDeploymentInput.html).
+#[derive(Default, Debug)]
+pub struct CreateDeploymentInputBuilder {
+    application_name: Option<String>,
+    auto_rollback_configuration: Option<AutoRollbackConfiguration>,
+    deployment_config_name: Option<String>,
+    deployment_group_name: Option<String>,
+    description: Option<String>,
+    file_exists_behavior: Option<FileExistsBehavior>,
+    ignore_application_stop_failures: Option<bool>,
+    revision: Option<RevisionLocation>,
+    target_instances: Option<TargetInstances>,
+    update_outdated_instances_only: Option<bool>,
+}
+
+impl CreateDeploymentInputBuilder {
+    /// Sets the [`application_name`](struct.CreateDeploymentInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_rollback_configuration`](struct.CreateDeploymentInput.html#structfield.auto_rollback_configuration) member.
+    pub fn auto_rollback_configuration<T: Into<AutoRollbackConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.auto_rollback_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_config_name`](struct.CreateDeploymentInput.html#structfield.deployment_config_name) member.
+    pub fn deployment_config_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_config_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_group_name`](struct.CreateDeploymentInput.html#structfield.deployment_group_name) member.
+    pub fn deployment_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateDeploymentInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_exists_behavior`](struct.CreateDeploymentInput.html#structfield.file_exists_behavior) member.
+    pub fn file_exists_behavior<T: Into<FileExistsBehavior>>(mut self, value: T) -> Self {
+        self.file_exists_behavior = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ignore_application_stop_failures`](struct.CreateDeploymentInput.html#structfield.ignore_application_stop_failures) member.
+    pub fn ignore_application_stop_failures<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ignore_application_stop_failures = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision`](struct.CreateDeploymentInput.html#structfield.revision) member.
+    pub fn revision<T: Into<RevisionLocation>>(mut self, value: T) -> Self {
+        self.revision = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_instances`](struct.CreateDeploymentInput.html#structfield.target_instances) member.
+    pub fn target_instances<T: Into<TargetInstances>>(mut self, value: T) -> Self {
+        self.target_instances = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_outdated_instances_only`](struct.CreateDeploymentInput.html#structfield.update_outdated_instances_only) member.
+    pub fn update_outdated_instances_only<T: Into<bool>>(mut self, value: T) -> Self {
+        self.update_outdated_instances_only = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDeploymentInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDeploymentInput, ::rusoto_core::BuildError> {
+        Ok(CreateDeploymentInput {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            auto_rollback_configuration: self.auto_rollback_configuration,
+            deployment_config_name: self.deployment_config_name,
+            deployment_group_name: self.deployment_group_name,
+            description: self.description,
+            file_exists_behavior: self.file_exists_behavior,
+            ignore_application_stop_failures: self.ignore_application_stop_failures,
+            revision: self.revision,
+            target_instances: self.target_instances,
+            update_outdated_instances_only: self.update_outdated_instances_only,
+        })
+    }
+}
+
 /// <p> Represents the output of a CreateDeployment operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -524,6 +1555,36 @@ pub struct DeleteApplicationInput {
     pub application_name: String,
 }
 
+impl DeleteApplicationInput {
+    /// Returns a builder for `DeleteApplicationInput`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationInputBuilder {
+        DeleteApplicationInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationInput`](struct.DeleteApplicationInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationInputBuilder {
+    application_name: Option<String>,
+}
+
+impl DeleteApplicationInputBuilder {
+    /// Sets the [`application_name`](struct.DeleteApplicationInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteApplicationInput, ::rusoto_core::BuildError> {
+        Ok(DeleteApplicationInput {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the input of a DeleteDeploymentConfig operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDeploymentConfigInput {
@@ -532,6 +1593,38 @@ pub struct DeleteDeploymentConfigInput {
     pub deployment_config_name: String,
 }
 
+impl DeleteDeploymentConfigInput {
+    /// Returns a builder for `DeleteDeploymentConfigInput`, checking its required members when built.
+    pub fn builder() -> DeleteDeploymentConfigInputBuilder {
+        DeleteDeploymentConfigInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDeploymentConfigInput`](struct.DeleteDeploymentConfigInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteDeploymentConfigInputBuilder {
+    deployment_config_name: Option<String>,
+}
+
+impl DeleteDeploymentConfigInputBuilder {
+    /// Sets the [`deployment_config_name`](struct.DeleteDeploymentConfigInput.html#structfield.deployment_config_name) member.
+    pub fn deployment_config_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_config_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDeploymentConfigInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDeploymentConfigInput, ::rusoto_core::BuildError> {
+        Ok(DeleteDeploymentConfigInput {
+            deployment_config_name: self
+                .deployment_config_name
+                .ok_or(::rusoto_core::BuildError::missing("deployment_config_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the input of a DeleteDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDeploymentGroupInput {
@@ -543,6 +1636,48 @@ pub struct DeleteDeploymentGroupInput {
     pub deployment_group_name: String,
 }
 
+impl DeleteDeploymentGroupInput {
+    /// Returns a builder for `DeleteDeploymentGroupInput`, checking its required members when built.
+    pub fn builder() -> DeleteDeploymentGroupInputBuilder {
+        DeleteDeploymentGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDeploymentGroupInput`](struct.DeleteDeploymentGroupInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteDeploymentGroupInputBuilder {
+    application_name: Option<String>,
+    deployment_group_name: Option<String>,
+}
+
+impl DeleteDeploymentGroupInputBuilder {
+    /// Sets the [`application_name`](struct.DeleteDeploymentGroupInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_group_name`](struct.DeleteDeploymentGroupInput.html#structfield.deployment_group_name) member.
+    pub fn deployment_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDeploymentGroupInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDeploymentGroupInput, ::rusoto_core::BuildError> {
+        Ok(DeleteDeploymentGroupInput {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            deployment_group_name: self
+                .deployment_group_name
+                .ok_or(::rusoto_core::BuildError::missing("deployment_group_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a DeleteDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -562,6 +1697,36 @@ pub struct DeleteGitHubAccountTokenInput {
     pub token_name: Option<String>,
 }
 
+impl DeleteGitHubAccountTokenInput {
+    /// Returns a builder for `DeleteGitHubAccountTokenInput`, checking its required members when built.
+    pub fn builder() -> DeleteGitHubAccountTokenInputBuilder {
+        DeleteGitHubAccountTokenInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGitHubAccountTokenInput`](struct.DeleteGitHubAccountTokenInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteGitHubAccountTokenInputBuilder {
+    token_name: Option<String>,
+}
+
+impl DeleteGitHubAccountTokenInputBuilder {
+    /// Sets the [`token_name`](struct.DeleteGitHubAccountTokenInput.html#structfield.token_name) member.
+    pub fn token_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.token_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGitHubAccountTokenInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteGitHubAccountTokenInput, ::rusoto_core::BuildError> {
+        Ok(DeleteGitHubAccountTokenInput {
+            token_name: self.token_name,
+        })
+    }
+}
+
 /// <p>Represents the output of a DeleteGitHubAccountToken operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -579,11 +1744,12 @@ pub struct DeploymentConfigInfo {
     /// <p>The destination platform type for the deployment (<code>Lambda</code>, <code>Server</code>, or <code>ECS</code>).</p>
     #[serde(rename = "computePlatform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_platform: Option<String>,
+    pub compute_platform: Option<ComputePlatform>,
     /// <p>The time at which the deployment configuration was created.</p>
     #[serde(rename = "createTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<EpochTimestamp>,
     /// <p>The deployment configuration ID.</p>
     #[serde(rename = "deploymentConfigId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -602,6 +1768,102 @@ pub struct DeploymentConfigInfo {
     pub traffic_routing_config: Option<TrafficRoutingConfig>,
 }
 
+/// <p><p>The means by which the deployment was created:</p> <ul> <li> <p>user: A user created the deployment.</p> </li> <li> <p>autoscaling: Amazon EC2 Auto Scaling created the deployment.</p> </li> <li> <p>codeDeployRollback: A rollback process created the deployment.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentCreator {
+    /// <p><code>user</code></p>
+    User,
+    /// <p><code>autoscaling</code></p>
+    Autoscaling,
+    /// <p><code>codeDeployRollback</code></p>
+    CodeDeployRollback,
+    /// <p><code>CodeDeploy</code></p>
+    CodeDeploy,
+    /// <p><code>CodeDeployAutoUpdate</code></p>
+    CodeDeployAutoUpdate,
+    /// <p><code>CloudFormation</code></p>
+    CloudFormation,
+    /// <p><code>CloudFormationRollback</code></p>
+    CloudFormationRollback,
+    /// <p><code>autoscalingTermination</code></p>
+    AutoscalingTermination,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeploymentCreator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentCreator::User => "user",
+            DeploymentCreator::Autoscaling => "autoscaling",
+            DeploymentCreator::CodeDeployRollback => "codeDeployRollback",
+            DeploymentCreator::CodeDeploy => "CodeDeploy",
+            DeploymentCreator::CodeDeployAutoUpdate => "CodeDeployAutoUpdate",
+            DeploymentCreator::CloudFormation => "CloudFormation",
+            DeploymentCreator::CloudFormationRollback => "CloudFormationRollback",
+            DeploymentCreator::AutoscalingTermination => "autoscalingTermination",
+            DeploymentCreator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentCreator {
+    fn from(value: &'a str) -> DeploymentCreator {
+        match value {
+            "user" => DeploymentCreator::User,
+            "autoscaling" => DeploymentCreator::Autoscaling,
+            "codeDeployRollback" => DeploymentCreator::CodeDeployRollback,
+            "CodeDeploy" => DeploymentCreator::CodeDeploy,
+            "CodeDeployAutoUpdate" => DeploymentCreator::CodeDeployAutoUpdate,
+            "CloudFormation" => DeploymentCreator::CloudFormation,
+            "CloudFormationRollback" => DeploymentCreator::CloudFormationRollback,
+            "autoscalingTermination" => DeploymentCreator::AutoscalingTermination,
+            _ => DeploymentCreator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentCreator {
+    fn from(value: String) -> DeploymentCreator {
+        DeploymentCreator::from(value.as_str())
+    }
+}
+
+impl From<DeploymentCreator> for String {
+    fn from(value: DeploymentCreator) -> String {
+        match value {
+            DeploymentCreator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentCreator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeploymentCreator {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentCreator {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeploymentCreator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeploymentCreator::from(value))
+    }
+}
+
 /// <p>Information about a deployment group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -629,7 +1891,7 @@ pub struct DeploymentGroupInfo {
     /// <p>The destination platform type for the deployment (<code>Lambda</code>, <code>Server</code>, or <code>ECS</code>).</p>
     #[serde(rename = "computePlatform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_platform: Option<String>,
+    pub compute_platform: Option<ComputePlatform>,
     /// <p>The deployment configuration name.</p>
     #[serde(rename = "deploymentConfigName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -714,20 +1976,22 @@ pub struct DeploymentInfo {
     pub blue_green_deployment_configuration: Option<BlueGreenDeploymentConfiguration>,
     /// <p>A timestamp that indicates when the deployment was complete.</p>
     #[serde(rename = "completeTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub complete_time: Option<f64>,
+    pub complete_time: Option<EpochTimestamp>,
     /// <p>The destination platform type for the deployment (<code>Lambda</code>, <code>Server</code>, or <code>ECS</code>).</p>
     #[serde(rename = "computePlatform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compute_platform: Option<String>,
+    pub compute_platform: Option<ComputePlatform>,
     /// <p>A timestamp that indicates when the deployment was created.</p>
     #[serde(rename = "createTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<EpochTimestamp>,
     /// <p><p>The means by which the deployment was created:</p> <ul> <li> <p>user: A user created the deployment.</p> </li> <li> <p>autoscaling: Amazon EC2 Auto Scaling created the deployment.</p> </li> <li> <p>codeDeployRollback: A rollback process created the deployment.</p> </li> </ul></p>
     #[serde(rename = "creator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creator: Option<String>,
+    pub creator: Option<DeploymentCreator>,
     /// <p> The deployment configuration name. </p>
     #[serde(rename = "deploymentConfigName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -763,7 +2027,7 @@ pub struct DeploymentInfo {
     /// <p><p>Information about how AWS CodeDeploy handles files that already exist in a deployment target location but weren&#39;t part of the previous successful deployment.</p> <ul> <li> <p>DISALLOW: The deployment fails. This is also the default behavior if no option is specified.</p> </li> <li> <p>OVERWRITE: The version of the file from the application revision currently being deployed replaces the version already on the instance.</p> </li> <li> <p>RETAIN: The version of the file already on the instance is kept and used as part of the new deployment.</p> </li> </ul></p>
     #[serde(rename = "fileExistsBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_exists_behavior: Option<String>,
+    pub file_exists_behavior: Option<FileExistsBehavior>,
     /// <p> If true, then if an <code>ApplicationStop</code>, <code>BeforeBlockTraffic</code>, or <code>AfterBlockTraffic</code> deployment lifecycle event to an instance fails, then the deployment continues to the next deployment lifecycle event. For example, if <code>ApplicationStop</code> fails, the deployment continues with DownloadBundle. If <code>BeforeBlockTraffic</code> fails, the deployment continues with <code>BlockTraffic</code>. If <code>AfterBlockTraffic</code> fails, the deployment continues with <code>ApplicationStop</code>. </p> <p> If false or not specified, then if a lifecycle event fails during a deployment to an instance, that deployment fails. If deployment to that instance is part of an overall deployment and the number of healthy hosts is not less than the minimum number of healthy hosts, then a deployment to the next instance is attempted. </p> <p> During a deployment, the AWS CodeDeploy agent runs the scripts specified for <code>ApplicationStop</code>, <code>BeforeBlockTraffic</code>, and <code>AfterBlockTraffic</code> in the AppSpec file from the previous successful deployment. (All other scripts are run from the AppSpec file in the current deployment.) If one of these scripts contains an error and does not run successfully, the deployment can fail. </p> <p> If the cause of the failure is a script from the last successful deployment that will never run successfully, create a new deployment and use <code>ignoreApplicationStopFailures</code> to specify that the <code>ApplicationStop</code>, <code>BeforeBlockTraffic</code>, and <code>AfterBlockTraffic</code> failures should be ignored. </p>
     #[serde(rename = "ignoreApplicationStopFailures")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -790,12 +2054,13 @@ pub struct DeploymentInfo {
     pub rollback_info: Option<RollbackInfo>,
     /// <p>A timestamp that indicates when the deployment was deployed to the deployment group.</p> <p>In some cases, the reported value of the start time might be later than the complete time. This is due to differences in the clock settings of backend servers that participate in the deployment process.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>The current state of the deployment as a whole.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeploymentStatus>,
     /// <p>Information about the instances that belong to the replacement environment in a blue/green deployment.</p>
     #[serde(rename = "targetInstances")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -806,6 +2071,78 @@ pub struct DeploymentInfo {
     pub update_outdated_instances_only: Option<bool>,
 }
 
+/// <p>Indicates whether to route deployment traffic behind a load balancer.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentOption {
+    /// <p><code>WITH_TRAFFIC_CONTROL</code></p>
+    WithTrafficControl,
+    /// <p><code>WITHOUT_TRAFFIC_CONTROL</code></p>
+    WithoutTrafficControl,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeploymentOption {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentOption::WithTrafficControl => "WITH_TRAFFIC_CONTROL",
+            DeploymentOption::WithoutTrafficControl => "WITHOUT_TRAFFIC_CONTROL",
+            DeploymentOption::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentOption {
+    fn from(value: &'a str) -> DeploymentOption {
+        match value {
+            "WITH_TRAFFIC_CONTROL" => DeploymentOption::WithTrafficControl,
+            "WITHOUT_TRAFFIC_CONTROL" => DeploymentOption::WithoutTrafficControl,
+            _ => DeploymentOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentOption {
+    fn from(value: String) -> DeploymentOption {
+        DeploymentOption::from(value.as_str())
+    }
+}
+
+impl From<DeploymentOption> for String {
+    fn from(value: DeploymentOption) -> String {
+        match value {
+            DeploymentOption::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeploymentOption {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentOption {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeploymentOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeploymentOption::from(value))
+    }
+}
+
 /// <p>Information about the deployment status of the instances in the deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -836,30 +2173,198 @@ pub struct DeploymentOverview {
     pub succeeded: Option<i64>,
 }
 
+/// <p><p>Information about when to reroute traffic from an original environment to a replacement environment in a blue/green deployment.</p> <ul> <li> <p>CONTINUE<em>DEPLOYMENT: Register new instances with the load balancer immediately after the new application revision is installed on the instances in the replacement environment.</p> </li> <li> <p>STOP</em>DEPLOYMENT: Do not register new instances with a load balancer unless traffic rerouting is started using <a>ContinueDeployment</a>. If traffic rerouting is not started before the end of the specified wait period, the deployment status is changed to Stopped.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentReadyAction {
+    /// <p><code>CONTINUE_DEPLOYMENT</code></p>
+    ContinueDeployment,
+    /// <p><code>STOP_DEPLOYMENT</code></p>
+    StopDeployment,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeploymentReadyAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentReadyAction::ContinueDeployment => "CONTINUE_DEPLOYMENT",
+            DeploymentReadyAction::StopDeployment => "STOP_DEPLOYMENT",
+            DeploymentReadyAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentReadyAction {
+    fn from(value: &'a str) -> DeploymentReadyAction {
+        match value {
+            "CONTINUE_DEPLOYMENT" => DeploymentReadyAction::ContinueDeployment,
+            "STOP_DEPLOYMENT" => DeploymentReadyAction::StopDeployment,
+            _ => DeploymentReadyAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentReadyAction {
+    fn from(value: String) -> DeploymentReadyAction {
+        DeploymentReadyAction::from(value.as_str())
+    }
+}
+
+impl From<DeploymentReadyAction> for String {
+    fn from(value: DeploymentReadyAction) -> String {
+        match value {
+            DeploymentReadyAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentReadyAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeploymentReadyAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentReadyAction {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeploymentReadyAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeploymentReadyAction::from(value))
+    }
+}
+
 /// <p>Information about how traffic is rerouted to instances in a replacement environment in a blue/green deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeploymentReadyOption {
     /// <p><p>Information about when to reroute traffic from an original environment to a replacement environment in a blue/green deployment.</p> <ul> <li> <p>CONTINUE<em>DEPLOYMENT: Register new instances with the load balancer immediately after the new application revision is installed on the instances in the replacement environment.</p> </li> <li> <p>STOP</em>DEPLOYMENT: Do not register new instances with a load balancer unless traffic rerouting is started using <a>ContinueDeployment</a>. If traffic rerouting is not started before the end of the specified wait period, the deployment status is changed to Stopped.</p> </li> </ul></p>
     #[serde(rename = "actionOnTimeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_on_timeout: Option<String>,
+    pub action_on_timeout: Option<DeploymentReadyAction>,
     /// <p>The number of minutes to wait before the status of a blue/green deployment is changed to Stopped if rerouting is not started manually. Applies only to the STOP_DEPLOYMENT option for actionOnTimeout</p>
     #[serde(rename = "waitTimeInMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub wait_time_in_minutes: Option<i64>,
 }
 
+/// <p>The current state of the deployment as a whole.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentStatus {
+    /// <p><code>Created</code></p>
+    Created,
+    /// <p><code>Queued</code></p>
+    Queued,
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>Baking</code></p>
+    Baking,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Stopped</code></p>
+    Stopped,
+    /// <p><code>Ready</code></p>
+    Ready,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeploymentStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentStatus::Created => "Created",
+            DeploymentStatus::Queued => "Queued",
+            DeploymentStatus::InProgress => "InProgress",
+            DeploymentStatus::Baking => "Baking",
+            DeploymentStatus::Succeeded => "Succeeded",
+            DeploymentStatus::Failed => "Failed",
+            DeploymentStatus::Stopped => "Stopped",
+            DeploymentStatus::Ready => "Ready",
+            DeploymentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentStatus {
+    fn from(value: &'a str) -> DeploymentStatus {
+        match value {
+            "Created" => DeploymentStatus::Created,
+            "Queued" => DeploymentStatus::Queued,
+            "InProgress" => DeploymentStatus::InProgress,
+            "Baking" => DeploymentStatus::Baking,
+            "Succeeded" => DeploymentStatus::Succeeded,
+            "Failed" => DeploymentStatus::Failed,
+            "Stopped" => DeploymentStatus::Stopped,
+            "Ready" => DeploymentStatus::Ready,
+            _ => DeploymentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentStatus {
+    fn from(value: String) -> DeploymentStatus {
+        DeploymentStatus::from(value.as_str())
+    }
+}
+
+impl From<DeploymentStatus> for String {
+    fn from(value: DeploymentStatus) -> String {
+        match value {
+            DeploymentStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeploymentStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeploymentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeploymentStatus::from(value))
+    }
+}
+
 /// <p>Information about the type of deployment, either in-place or blue/green, you want to run and whether to route deployment traffic behind a load balancer.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeploymentStyle {
     /// <p>Indicates whether to route deployment traffic behind a load balancer.</p>
     #[serde(rename = "deploymentOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_option: Option<String>,
+    pub deployment_option: Option<DeploymentOption>,
     /// <p>Indicates whether to run an in-place deployment or a blue/green deployment.</p>
     #[serde(rename = "deploymentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_type: Option<String>,
+    pub deployment_type: Option<DeploymentType>,
 }
 
 /// <p> Information about the deployment target. </p>
@@ -869,7 +2374,7 @@ pub struct DeploymentTarget {
     /// <p> The deployment type that is specific to the deployment's compute platform. </p>
     #[serde(rename = "deploymentTargetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_target_type: Option<String>,
+    pub deployment_target_type: Option<DeploymentTargetType>,
     /// <p> Information about the target for a deployment that uses the Amazon ECS compute platform. </p>
     #[serde(rename = "ecsTarget")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -884,22 +2389,278 @@ pub struct DeploymentTarget {
     pub lambda_target: Option<LambdaTarget>,
 }
 
-/// <p>Represents the input of a DeregisterOnPremisesInstance operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DeregisterOnPremisesInstanceInput {
-    /// <p>The name of the on-premises instance to deregister.</p>
-    #[serde(rename = "instanceName")]
-    pub instance_name: String,
+/// <p> The deployment type that is specific to the deployment's compute platform. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentTargetType {
+    /// <p><code>InstanceTarget</code></p>
+    InstanceTarget,
+    /// <p><code>LambdaTarget</code></p>
+    LambdaTarget,
+    /// <p><code>ECSTarget</code></p>
+    ECSTarget,
+    /// <p><code>CloudFormationTarget</code></p>
+    CloudFormationTarget,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeploymentTargetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentTargetType::InstanceTarget => "InstanceTarget",
+            DeploymentTargetType::LambdaTarget => "LambdaTarget",
+            DeploymentTargetType::ECSTarget => "ECSTarget",
+            DeploymentTargetType::CloudFormationTarget => "CloudFormationTarget",
+            DeploymentTargetType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Diagnostic information about executable scripts that are part of a deployment.</p>
+impl<'a> From<&'a str> for DeploymentTargetType {
+    fn from(value: &'a str) -> DeploymentTargetType {
+        match value {
+            "InstanceTarget" => DeploymentTargetType::InstanceTarget,
+            "LambdaTarget" => DeploymentTargetType::LambdaTarget,
+            "ECSTarget" => DeploymentTargetType::ECSTarget,
+            "CloudFormationTarget" => DeploymentTargetType::CloudFormationTarget,
+            _ => DeploymentTargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentTargetType {
+    fn from(value: String) -> DeploymentTargetType {
+        DeploymentTargetType::from(value.as_str())
+    }
+}
+
+impl From<DeploymentTargetType> for String {
+    fn from(value: DeploymentTargetType) -> String {
+        match value {
+            DeploymentTargetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeploymentTargetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentTargetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeploymentTargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeploymentTargetType::from(value))
+    }
+}
+
+/// <p>Indicates whether to run an in-place deployment or a blue/green deployment.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentType {
+    /// <p><code>IN_PLACE</code></p>
+    InPlace,
+    /// <p><code>BLUE_GREEN</code></p>
+    BlueGreen,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeploymentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentType::InPlace => "IN_PLACE",
+            DeploymentType::BlueGreen => "BLUE_GREEN",
+            DeploymentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentType {
+    fn from(value: &'a str) -> DeploymentType {
+        match value {
+            "IN_PLACE" => DeploymentType::InPlace,
+            "BLUE_GREEN" => DeploymentType::BlueGreen,
+            _ => DeploymentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentType {
+    fn from(value: String) -> DeploymentType {
+        DeploymentType::from(value.as_str())
+    }
+}
+
+impl From<DeploymentType> for String {
+    fn from(value: DeploymentType) -> String {
+        match value {
+            DeploymentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeploymentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeploymentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeploymentType::from(value))
+    }
+}
+
+/// <p> The status of the deployment's waiting period. READY_WAIT indicates the deployment is ready to start shifting traffic. TERMINATION_WAIT indicates the traffic is shifted, but the original target is not terminated. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentWaitType {
+    /// <p><code>READY_WAIT</code></p>
+    ReadyWait,
+    /// <p><code>TERMINATION_WAIT</code></p>
+    TerminationWait,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeploymentWaitType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentWaitType::ReadyWait => "READY_WAIT",
+            DeploymentWaitType::TerminationWait => "TERMINATION_WAIT",
+            DeploymentWaitType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentWaitType {
+    fn from(value: &'a str) -> DeploymentWaitType {
+        match value {
+            "READY_WAIT" => DeploymentWaitType::ReadyWait,
+            "TERMINATION_WAIT" => DeploymentWaitType::TerminationWait,
+            _ => DeploymentWaitType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentWaitType {
+    fn from(value: String) -> DeploymentWaitType {
+        DeploymentWaitType::from(value.as_str())
+    }
+}
+
+impl From<DeploymentWaitType> for String {
+    fn from(value: DeploymentWaitType) -> String {
+        match value {
+            DeploymentWaitType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentWaitType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeploymentWaitType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentWaitType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeploymentWaitType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeploymentWaitType::from(value))
+    }
+}
+
+/// <p>Represents the input of a DeregisterOnPremisesInstance operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeregisterOnPremisesInstanceInput {
+    /// <p>The name of the on-premises instance to deregister.</p>
+    #[serde(rename = "instanceName")]
+    pub instance_name: String,
+}
+
+impl DeregisterOnPremisesInstanceInput {
+    /// Returns a builder for `DeregisterOnPremisesInstanceInput`, checking its required members when built.
+    pub fn builder() -> DeregisterOnPremisesInstanceInputBuilder {
+        DeregisterOnPremisesInstanceInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterOnPremisesInstanceInput`](struct.DeregisterOnPremisesInstanceInput.html).
+#[derive(Default, Debug)]
+pub struct DeregisterOnPremisesInstanceInputBuilder {
+    instance_name: Option<String>,
+}
+
+impl DeregisterOnPremisesInstanceInputBuilder {
+    /// Sets the [`instance_name`](struct.DeregisterOnPremisesInstanceInput.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterOnPremisesInstanceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterOnPremisesInstanceInput, ::rusoto_core::BuildError> {
+        Ok(DeregisterOnPremisesInstanceInput {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+        })
+    }
+}
+
+/// <p>Diagnostic information about executable scripts that are part of a deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Diagnostics {
     /// <p><p>The associated error code:</p> <ul> <li> <p>Success: The specified script ran.</p> </li> <li> <p>ScriptMissing: The specified script was not found in the specified location.</p> </li> <li> <p>ScriptNotExecutable: The specified script is not a recognized executable file type.</p> </li> <li> <p>ScriptTimedOut: The specified script did not finish running in the specified time period.</p> </li> <li> <p>ScriptFailed: The specified script failed to run as expected.</p> </li> <li> <p>UnknownError: The specified script did not run for an unknown reason.</p> </li> </ul></p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<LifecycleErrorCode>,
     /// <p>The last portion of the diagnostic log.</p> <p>If available, AWS CodeDeploy returns up to the last 4 KB of the diagnostic log.</p>
     #[serde(rename = "logTail")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -924,13 +2685,89 @@ pub struct EC2TagFilter {
     /// <p><p>The tag filter type:</p> <ul> <li> <p>KEY<em>ONLY: Key only.</p> </li> <li> <p>VALUE</em>ONLY: Value only.</p> </li> <li> <p>KEY<em>AND</em>VALUE: Key and value.</p> </li> </ul></p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EC2TagFilterType>,
     /// <p>The tag filter value.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+/// <p><p>The tag filter type:</p> <ul> <li> <p>KEY<em>ONLY: Key only.</p> </li> <li> <p>VALUE</em>ONLY: Value only.</p> </li> <li> <p>KEY<em>AND</em>VALUE: Key and value.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EC2TagFilterType {
+    /// <p><code>KEY_ONLY</code></p>
+    KeyOnly,
+    /// <p><code>VALUE_ONLY</code></p>
+    ValueOnly,
+    /// <p><code>KEY_AND_VALUE</code></p>
+    KeyAndValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EC2TagFilterType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EC2TagFilterType::KeyOnly => "KEY_ONLY",
+            EC2TagFilterType::ValueOnly => "VALUE_ONLY",
+            EC2TagFilterType::KeyAndValue => "KEY_AND_VALUE",
+            EC2TagFilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EC2TagFilterType {
+    fn from(value: &'a str) -> EC2TagFilterType {
+        match value {
+            "KEY_ONLY" => EC2TagFilterType::KeyOnly,
+            "VALUE_ONLY" => EC2TagFilterType::ValueOnly,
+            "KEY_AND_VALUE" => EC2TagFilterType::KeyAndValue,
+            _ => EC2TagFilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EC2TagFilterType {
+    fn from(value: String) -> EC2TagFilterType {
+        EC2TagFilterType::from(value.as_str())
+    }
+}
+
+impl From<EC2TagFilterType> for String {
+    fn from(value: EC2TagFilterType) -> String {
+        match value {
+            EC2TagFilterType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EC2TagFilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EC2TagFilterType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EC2TagFilterType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EC2TagFilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EC2TagFilterType::from(value))
+    }
+}
+
 /// <p>Information about groups of EC2 instance tags.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EC2TagSet {
@@ -963,8 +2800,9 @@ pub struct ECSTarget {
     pub deployment_id: Option<String>,
     /// <p> The date and time when the target Amazon ECS application was updated by a deployment. </p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p> The lifecycle events of the deployment to this target Amazon ECS application. </p>
     #[serde(rename = "lifecycleEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -972,7 +2810,7 @@ pub struct ECSTarget {
     /// <p> The status an Amazon ECS deployment's target ECS application. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TargetStatus>,
     /// <p> The ARN of the target. </p>
     #[serde(rename = "targetArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1018,7 +2856,7 @@ pub struct ECSTaskSet {
     /// <p> A label that identifies whether the ECS task set is an original target (<code>BLUE</code>) or a replacement target (<code>GREEN</code>). </p>
     #[serde(rename = "taskSetLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_set_label: Option<String>,
+    pub task_set_label: Option<TargetLabel>,
     /// <p> The percentage of traffic served by this task set. </p>
     #[serde(rename = "trafficWeight")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1034,6 +2872,210 @@ pub struct ELBInfo {
     pub name: Option<String>,
 }
 
+/// <p><p>For more information, see <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide/error-codes.html">Error Codes for AWS CodeDeploy</a> in the <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide">AWS CodeDeploy User Guide</a>.</p> <p>The error code:</p> <ul> <li> <p>APPLICATION<em>MISSING: The application was missing. This error code is most likely raised if the application is deleted after the deployment is created, but before it is started.</p> </li> <li> <p>DEPLOYMENT</em>GROUP<em>MISSING: The deployment group was missing. This error code is most likely raised if the deployment group is deleted after the deployment is created, but before it is started.</p> </li> <li> <p>HEALTH</em>CONSTRAINTS: The deployment failed on too many instances to be successfully deployed within the instance health constraints specified.</p> </li> <li> <p>HEALTH<em>CONSTRAINTS</em>INVALID: The revision cannot be successfully deployed within the instance health constraints specified.</p> </li> <li> <p>IAM<em>ROLE</em>MISSING: The service role cannot be accessed.</p> </li> <li> <p>IAM<em>ROLE</em>PERMISSIONS: The service role does not have the correct permissions.</p> </li> <li> <p>INTERNAL<em>ERROR: There was an internal error.</p> </li> <li> <p>NO</em>EC2<em>SUBSCRIPTION: The calling account is not subscribed to Amazon EC2.</p> </li> <li> <p>NO</em>INSTANCES: No instances were specified, or no instances can be found.</p> </li> <li> <p>OVER<em>MAX</em>INSTANCES: The maximum number of instances was exceeded.</p> </li> <li> <p>THROTTLED: The operation was throttled because the calling account exceeded the throttling limits of one or more AWS services.</p> </li> <li> <p>TIMEOUT: The deployment has timed out.</p> </li> <li> <p>REVISION_MISSING: The revision ID was missing. This error code is most likely raised if the revision is deleted after the deployment is created, but before it is started.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    /// <p><code>AGENT_ISSUE</code></p>
+    AgentIssue,
+    /// <p><code>ALARM_ACTIVE</code></p>
+    AlarmActive,
+    /// <p><code>APPLICATION_MISSING</code></p>
+    ApplicationMissing,
+    /// <p><code>AUTOSCALING_VALIDATION_ERROR</code></p>
+    AutoscalingValidationError,
+    /// <p><code>AUTO_SCALING_CONFIGURATION</code></p>
+    AutoScalingConfiguration,
+    /// <p><code>AUTO_SCALING_IAM_ROLE_PERMISSIONS</code></p>
+    AutoScalingIamRolePermissions,
+    /// <p><code>CODEDEPLOY_RESOURCE_CANNOT_BE_FOUND</code></p>
+    CodedeployResourceCannotBeFound,
+    /// <p><code>CUSTOMER_APPLICATION_UNHEALTHY</code></p>
+    CustomerApplicationUnhealthy,
+    /// <p><code>DEPLOYMENT_GROUP_MISSING</code></p>
+    DeploymentGroupMissing,
+    /// <p><code>ECS_UPDATE_ERROR</code></p>
+    EcsUpdateError,
+    /// <p><code>ELASTIC_LOAD_BALANCING_INVALID</code></p>
+    ElasticLoadBalancingInvalid,
+    /// <p><code>ELB_INVALID_INSTANCE</code></p>
+    ElbInvalidInstance,
+    /// <p><code>HEALTH_CONSTRAINTS</code></p>
+    HealthConstraints,
+    /// <p><code>HEALTH_CONSTRAINTS_INVALID</code></p>
+    HealthConstraintsInvalid,
+    /// <p><code>HOOK_EXECUTION_FAILURE</code></p>
+    HookExecutionFailure,
+    /// <p><code>IAM_ROLE_MISSING</code></p>
+    IamRoleMissing,
+    /// <p><code>IAM_ROLE_PERMISSIONS</code></p>
+    IamRolePermissions,
+    /// <p><code>INTERNAL_ERROR</code></p>
+    InternalError,
+    /// <p><code>INVALID_ECS_SERVICE</code></p>
+    InvalidEcsService,
+    /// <p><code>INVALID_LAMBDA_CONFIGURATION</code></p>
+    InvalidLambdaConfiguration,
+    /// <p><code>INVALID_LAMBDA_FUNCTION</code></p>
+    InvalidLambdaFunction,
+    /// <p><code>INVALID_REVISION</code></p>
+    InvalidRevision,
+    /// <p><code>MANUAL_STOP</code></p>
+    ManualStop,
+    /// <p><code>MISSING_BLUE_GREEN_DEPLOYMENT_CONFIGURATION</code></p>
+    MissingBlueGreenDeploymentConfiguration,
+    /// <p><code>MISSING_ELB_INFORMATION</code></p>
+    MissingElbInformation,
+    /// <p><code>MISSING_GITHUB_TOKEN</code></p>
+    MissingGithubToken,
+    /// <p><code>NO_EC2_SUBSCRIPTION</code></p>
+    NoEc2Subscription,
+    /// <p><code>NO_INSTANCES</code></p>
+    NoInstances,
+    /// <p><code>OVER_MAX_INSTANCES</code></p>
+    OverMaxInstances,
+    /// <p><code>RESOURCE_LIMIT_EXCEEDED</code></p>
+    ResourceLimitExceeded,
+    /// <p><code>REVISION_MISSING</code></p>
+    RevisionMissing,
+    /// <p><code>THROTTLED</code></p>
+    Throttled,
+    /// <p><code>TIMEOUT</code></p>
+    Timeout,
+    /// <p><code>CLOUDFORMATION_STACK_FAILURE</code></p>
+    CloudformationStackFailure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::AgentIssue => "AGENT_ISSUE",
+            ErrorCode::AlarmActive => "ALARM_ACTIVE",
+            ErrorCode::ApplicationMissing => "APPLICATION_MISSING",
+            ErrorCode::AutoscalingValidationError => "AUTOSCALING_VALIDATION_ERROR",
+            ErrorCode::AutoScalingConfiguration => "AUTO_SCALING_CONFIGURATION",
+            ErrorCode::AutoScalingIamRolePermissions => "AUTO_SCALING_IAM_ROLE_PERMISSIONS",
+            ErrorCode::CodedeployResourceCannotBeFound => "CODEDEPLOY_RESOURCE_CANNOT_BE_FOUND",
+            ErrorCode::CustomerApplicationUnhealthy => "CUSTOMER_APPLICATION_UNHEALTHY",
+            ErrorCode::DeploymentGroupMissing => "DEPLOYMENT_GROUP_MISSING",
+            ErrorCode::EcsUpdateError => "ECS_UPDATE_ERROR",
+            ErrorCode::ElasticLoadBalancingInvalid => "ELASTIC_LOAD_BALANCING_INVALID",
+            ErrorCode::ElbInvalidInstance => "ELB_INVALID_INSTANCE",
+            ErrorCode::HealthConstraints => "HEALTH_CONSTRAINTS",
+            ErrorCode::HealthConstraintsInvalid => "HEALTH_CONSTRAINTS_INVALID",
+            ErrorCode::HookExecutionFailure => "HOOK_EXECUTION_FAILURE",
+            ErrorCode::IamRoleMissing => "IAM_ROLE_MISSING",
+            ErrorCode::IamRolePermissions => "IAM_ROLE_PERMISSIONS",
+            ErrorCode::InternalError => "INTERNAL_ERROR",
+            ErrorCode::InvalidEcsService => "INVALID_ECS_SERVICE",
+            ErrorCode::InvalidLambdaConfiguration => "INVALID_LAMBDA_CONFIGURATION",
+            ErrorCode::InvalidLambdaFunction => "INVALID_LAMBDA_FUNCTION",
+            ErrorCode::InvalidRevision => "INVALID_REVISION",
+            ErrorCode::ManualStop => "MANUAL_STOP",
+            ErrorCode::MissingBlueGreenDeploymentConfiguration => {
+                "MISSING_BLUE_GREEN_DEPLOYMENT_CONFIGURATION"
+            }
+            ErrorCode::MissingElbInformation => "MISSING_ELB_INFORMATION",
+            ErrorCode::MissingGithubToken => "MISSING_GITHUB_TOKEN",
+            ErrorCode::NoEc2Subscription => "NO_EC2_SUBSCRIPTION",
+            ErrorCode::NoInstances => "NO_INSTANCES",
+            ErrorCode::OverMaxInstances => "OVER_MAX_INSTANCES",
+            ErrorCode::ResourceLimitExceeded => "RESOURCE_LIMIT_EXCEEDED",
+            ErrorCode::RevisionMissing => "REVISION_MISSING",
+            ErrorCode::Throttled => "THROTTLED",
+            ErrorCode::Timeout => "TIMEOUT",
+            ErrorCode::CloudformationStackFailure => "CLOUDFORMATION_STACK_FAILURE",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "AGENT_ISSUE" => ErrorCode::AgentIssue,
+            "ALARM_ACTIVE" => ErrorCode::AlarmActive,
+            "APPLICATION_MISSING" => ErrorCode::ApplicationMissing,
+            "AUTOSCALING_VALIDATION_ERROR" => ErrorCode::AutoscalingValidationError,
+            "AUTO_SCALING_CONFIGURATION" => ErrorCode::AutoScalingConfiguration,
+            "AUTO_SCALING_IAM_ROLE_PERMISSIONS" => ErrorCode::AutoScalingIamRolePermissions,
+            "CODEDEPLOY_RESOURCE_CANNOT_BE_FOUND" => ErrorCode::CodedeployResourceCannotBeFound,
+            "CUSTOMER_APPLICATION_UNHEALTHY" => ErrorCode::CustomerApplicationUnhealthy,
+            "DEPLOYMENT_GROUP_MISSING" => ErrorCode::DeploymentGroupMissing,
+            "ECS_UPDATE_ERROR" => ErrorCode::EcsUpdateError,
+            "ELASTIC_LOAD_BALANCING_INVALID" => ErrorCode::ElasticLoadBalancingInvalid,
+            "ELB_INVALID_INSTANCE" => ErrorCode::ElbInvalidInstance,
+            "HEALTH_CONSTRAINTS" => ErrorCode::HealthConstraints,
+            "HEALTH_CONSTRAINTS_INVALID" => ErrorCode::HealthConstraintsInvalid,
+            "HOOK_EXECUTION_FAILURE" => ErrorCode::HookExecutionFailure,
+            "IAM_ROLE_MISSING" => ErrorCode::IamRoleMissing,
+            "IAM_ROLE_PERMISSIONS" => ErrorCode::IamRolePermissions,
+            "INTERNAL_ERROR" => ErrorCode::InternalError,
+            "INVALID_ECS_SERVICE" => ErrorCode::InvalidEcsService,
+            "INVALID_LAMBDA_CONFIGURATION" => ErrorCode::InvalidLambdaConfiguration,
+            "INVALID_LAMBDA_FUNCTION" => ErrorCode::InvalidLambdaFunction,
+            "INVALID_REVISION" => ErrorCode::InvalidRevision,
+            "MANUAL_STOP" => ErrorCode::ManualStop,
+            "MISSING_BLUE_GREEN_DEPLOYMENT_CONFIGURATION" => {
+                ErrorCode::MissingBlueGreenDeploymentConfiguration
+            }
+            "MISSING_ELB_INFORMATION" => ErrorCode::MissingElbInformation,
+            "MISSING_GITHUB_TOKEN" => ErrorCode::MissingGithubToken,
+            "NO_EC2_SUBSCRIPTION" => ErrorCode::NoEc2Subscription,
+            "NO_INSTANCES" => ErrorCode::NoInstances,
+            "OVER_MAX_INSTANCES" => ErrorCode::OverMaxInstances,
+            "RESOURCE_LIMIT_EXCEEDED" => ErrorCode::ResourceLimitExceeded,
+            "REVISION_MISSING" => ErrorCode::RevisionMissing,
+            "THROTTLED" => ErrorCode::Throttled,
+            "TIMEOUT" => ErrorCode::Timeout,
+            "CLOUDFORMATION_STACK_FAILURE" => ErrorCode::CloudformationStackFailure,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        ErrorCode::from(value.as_str())
+    }
+}
+
+impl From<ErrorCode> for String {
+    fn from(value: ErrorCode) -> String {
+        match value {
+            ErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ErrorCode::from(value))
+    }
+}
+
 /// <p>Information about a deployment error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1041,13 +3083,89 @@ pub struct ErrorInformation {
     /// <p><p>For more information, see <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide/error-codes.html">Error Codes for AWS CodeDeploy</a> in the <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide">AWS CodeDeploy User Guide</a>.</p> <p>The error code:</p> <ul> <li> <p>APPLICATION<em>MISSING: The application was missing. This error code is most likely raised if the application is deleted after the deployment is created, but before it is started.</p> </li> <li> <p>DEPLOYMENT</em>GROUP<em>MISSING: The deployment group was missing. This error code is most likely raised if the deployment group is deleted after the deployment is created, but before it is started.</p> </li> <li> <p>HEALTH</em>CONSTRAINTS: The deployment failed on too many instances to be successfully deployed within the instance health constraints specified.</p> </li> <li> <p>HEALTH<em>CONSTRAINTS</em>INVALID: The revision cannot be successfully deployed within the instance health constraints specified.</p> </li> <li> <p>IAM<em>ROLE</em>MISSING: The service role cannot be accessed.</p> </li> <li> <p>IAM<em>ROLE</em>PERMISSIONS: The service role does not have the correct permissions.</p> </li> <li> <p>INTERNAL<em>ERROR: There was an internal error.</p> </li> <li> <p>NO</em>EC2<em>SUBSCRIPTION: The calling account is not subscribed to Amazon EC2.</p> </li> <li> <p>NO</em>INSTANCES: No instances were specified, or no instances can be found.</p> </li> <li> <p>OVER<em>MAX</em>INSTANCES: The maximum number of instances was exceeded.</p> </li> <li> <p>THROTTLED: The operation was throttled because the calling account exceeded the throttling limits of one or more AWS services.</p> </li> <li> <p>TIMEOUT: The deployment has timed out.</p> </li> <li> <p>REVISION_MISSING: The revision ID was missing. This error code is most likely raised if the revision is deleted after the deployment is created, but before it is started.</p> </li> </ul></p>
     #[serde(rename = "code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<ErrorCode>,
     /// <p>An accompanying error message.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+/// <p><p>Information about how AWS CodeDeploy handles files that already exist in a deployment target location but weren&#39;t part of the previous successful deployment.</p> <p>The fileExistsBehavior parameter takes any of the following values:</p> <ul> <li> <p>DISALLOW: The deployment fails. This is also the default behavior if no option is specified.</p> </li> <li> <p>OVERWRITE: The version of the file from the application revision currently being deployed replaces the version already on the instance.</p> </li> <li> <p>RETAIN: The version of the file already on the instance is kept and used as part of the new deployment.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FileExistsBehavior {
+    /// <p><code>DISALLOW</code></p>
+    Disallow,
+    /// <p><code>OVERWRITE</code></p>
+    Overwrite,
+    /// <p><code>RETAIN</code></p>
+    Retain,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FileExistsBehavior {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileExistsBehavior::Disallow => "DISALLOW",
+            FileExistsBehavior::Overwrite => "OVERWRITE",
+            FileExistsBehavior::Retain => "RETAIN",
+            FileExistsBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FileExistsBehavior {
+    fn from(value: &'a str) -> FileExistsBehavior {
+        match value {
+            "DISALLOW" => FileExistsBehavior::Disallow,
+            "OVERWRITE" => FileExistsBehavior::Overwrite,
+            "RETAIN" => FileExistsBehavior::Retain,
+            _ => FileExistsBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileExistsBehavior {
+    fn from(value: String) -> FileExistsBehavior {
+        FileExistsBehavior::from(value.as_str())
+    }
+}
+
+impl From<FileExistsBehavior> for String {
+    fn from(value: FileExistsBehavior) -> String {
+        match value {
+            FileExistsBehavior::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FileExistsBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FileExistsBehavior {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileExistsBehavior {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FileExistsBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FileExistsBehavior::from(value))
+    }
+}
+
 /// <p>Information about an application revision.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1062,16 +3180,19 @@ pub struct GenericRevisionInfo {
     pub description: Option<String>,
     /// <p>When the revision was first used by AWS CodeDeploy.</p>
     #[serde(rename = "firstUsedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_used_time: Option<f64>,
+    pub first_used_time: Option<EpochTimestamp>,
     /// <p>When the revision was last used by AWS CodeDeploy.</p>
     #[serde(rename = "lastUsedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_used_time: Option<f64>,
+    pub last_used_time: Option<EpochTimestamp>,
     /// <p>When the revision was registered with AWS CodeDeploy.</p>
     #[serde(rename = "registerTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub register_time: Option<f64>,
+    pub register_time: Option<EpochTimestamp>,
 }
 
 /// <p>Represents the input of a GetApplication operation.</p>
@@ -1082,6 +3203,36 @@ pub struct GetApplicationInput {
     pub application_name: String,
 }
 
+impl GetApplicationInput {
+    /// Returns a builder for `GetApplicationInput`, checking its required members when built.
+    pub fn builder() -> GetApplicationInputBuilder {
+        GetApplicationInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetApplicationInput`](struct.GetApplicationInput.html).
+#[derive(Default, Debug)]
+pub struct GetApplicationInputBuilder {
+    application_name: Option<String>,
+}
+
+impl GetApplicationInputBuilder {
+    /// Sets the [`application_name`](struct.GetApplicationInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApplicationInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetApplicationInput, ::rusoto_core::BuildError> {
+        Ok(GetApplicationInput {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a GetApplication operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1103,6 +3254,48 @@ pub struct GetApplicationRevisionInput {
     pub revision: RevisionLocation,
 }
 
+impl GetApplicationRevisionInput {
+    /// Returns a builder for `GetApplicationRevisionInput`, checking its required members when built.
+    pub fn builder() -> GetApplicationRevisionInputBuilder {
+        GetApplicationRevisionInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetApplicationRevisionInput`](struct.GetApplicationRevisionInput.html).
+#[derive(Default, Debug)]
+pub struct GetApplicationRevisionInputBuilder {
+    application_name: Option<String>,
+    revision: Option<RevisionLocation>,
+}
+
+impl GetApplicationRevisionInputBuilder {
+    /// Sets the [`application_name`](struct.GetApplicationRevisionInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision`](struct.GetApplicationRevisionInput.html#structfield.revision) member.
+    pub fn revision<T: Into<RevisionLocation>>(mut self, value: T) -> Self {
+        self.revision = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetApplicationRevisionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetApplicationRevisionInput, ::rusoto_core::BuildError> {
+        Ok(GetApplicationRevisionInput {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            revision: self
+                .revision
+                .ok_or(::rusoto_core::BuildError::missing("revision"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a GetApplicationRevision operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1129,6 +3322,38 @@ pub struct GetDeploymentConfigInput {
     pub deployment_config_name: String,
 }
 
+impl GetDeploymentConfigInput {
+    /// Returns a builder for `GetDeploymentConfigInput`, checking its required members when built.
+    pub fn builder() -> GetDeploymentConfigInputBuilder {
+        GetDeploymentConfigInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeploymentConfigInput`](struct.GetDeploymentConfigInput.html).
+#[derive(Default, Debug)]
+pub struct GetDeploymentConfigInputBuilder {
+    deployment_config_name: Option<String>,
+}
+
+impl GetDeploymentConfigInputBuilder {
+    /// Sets the [`deployment_config_name`](struct.GetDeploymentConfigInput.html#structfield.deployment_config_name) member.
+    pub fn deployment_config_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_config_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeploymentConfigInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDeploymentConfigInput, ::rusoto_core::BuildError> {
+        Ok(GetDeploymentConfigInput {
+            deployment_config_name: self
+                .deployment_config_name
+                .ok_or(::rusoto_core::BuildError::missing("deployment_config_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a GetDeploymentConfig operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1150,6 +3375,48 @@ pub struct GetDeploymentGroupInput {
     pub deployment_group_name: String,
 }
 
+impl GetDeploymentGroupInput {
+    /// Returns a builder for `GetDeploymentGroupInput`, checking its required members when built.
+    pub fn builder() -> GetDeploymentGroupInputBuilder {
+        GetDeploymentGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeploymentGroupInput`](struct.GetDeploymentGroupInput.html).
+#[derive(Default, Debug)]
+pub struct GetDeploymentGroupInputBuilder {
+    application_name: Option<String>,
+    deployment_group_name: Option<String>,
+}
+
+impl GetDeploymentGroupInputBuilder {
+    /// Sets the [`application_name`](struct.GetDeploymentGroupInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_group_name`](struct.GetDeploymentGroupInput.html#structfield.deployment_group_name) member.
+    pub fn deployment_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeploymentGroupInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDeploymentGroupInput, ::rusoto_core::BuildError> {
+        Ok(GetDeploymentGroupInput {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            deployment_group_name: self
+                .deployment_group_name
+                .ok_or(::rusoto_core::BuildError::missing("deployment_group_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a GetDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1168,8 +3435,38 @@ pub struct GetDeploymentInput {
     pub deployment_id: String,
 }
 
-/// <p> Represents the input of a GetDeploymentInstance operation. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl GetDeploymentInput {
+    /// Returns a builder for `GetDeploymentInput`, checking its required members when built.
+    pub fn builder() -> GetDeploymentInputBuilder {
+        GetDeploymentInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeploymentInput`](struct.GetDeploymentInput.html).
+#[derive(Default, Debug)]
+pub struct GetDeploymentInputBuilder {
+    deployment_id: Option<String>,
+}
+
+impl GetDeploymentInputBuilder {
+    /// Sets the [`deployment_id`](struct.GetDeploymentInput.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeploymentInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDeploymentInput, ::rusoto_core::BuildError> {
+        Ok(GetDeploymentInput {
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+        })
+    }
+}
+
+/// <p> Represents the input of a GetDeploymentInstance operation. </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetDeploymentInstanceInput {
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
@@ -1179,6 +3476,48 @@ pub struct GetDeploymentInstanceInput {
     pub instance_id: String,
 }
 
+impl GetDeploymentInstanceInput {
+    /// Returns a builder for `GetDeploymentInstanceInput`, checking its required members when built.
+    pub fn builder() -> GetDeploymentInstanceInputBuilder {
+        GetDeploymentInstanceInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeploymentInstanceInput`](struct.GetDeploymentInstanceInput.html).
+#[derive(Default, Debug)]
+pub struct GetDeploymentInstanceInputBuilder {
+    deployment_id: Option<String>,
+    instance_id: Option<String>,
+}
+
+impl GetDeploymentInstanceInputBuilder {
+    /// Sets the [`deployment_id`](struct.GetDeploymentInstanceInput.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.GetDeploymentInstanceInput.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeploymentInstanceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDeploymentInstanceInput, ::rusoto_core::BuildError> {
+        Ok(GetDeploymentInstanceInput {
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
 /// <p> Represents the output of a GetDeploymentInstance operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1211,6 +3550,44 @@ pub struct GetDeploymentTargetInput {
     pub target_id: Option<String>,
 }
 
+impl GetDeploymentTargetInput {
+    /// Returns a builder for `GetDeploymentTargetInput`, checking its required members when built.
+    pub fn builder() -> GetDeploymentTargetInputBuilder {
+        GetDeploymentTargetInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeploymentTargetInput`](struct.GetDeploymentTargetInput.html).
+#[derive(Default, Debug)]
+pub struct GetDeploymentTargetInputBuilder {
+    deployment_id: Option<String>,
+    target_id: Option<String>,
+}
+
+impl GetDeploymentTargetInputBuilder {
+    /// Sets the [`deployment_id`](struct.GetDeploymentTargetInput.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_id`](struct.GetDeploymentTargetInput.html#structfield.target_id) member.
+    pub fn target_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeploymentTargetInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDeploymentTargetInput, ::rusoto_core::BuildError> {
+        Ok(GetDeploymentTargetInput {
+            deployment_id: self.deployment_id,
+            target_id: self.target_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDeploymentTargetOutput {
@@ -1228,6 +3605,38 @@ pub struct GetOnPremisesInstanceInput {
     pub instance_name: String,
 }
 
+impl GetOnPremisesInstanceInput {
+    /// Returns a builder for `GetOnPremisesInstanceInput`, checking its required members when built.
+    pub fn builder() -> GetOnPremisesInstanceInputBuilder {
+        GetOnPremisesInstanceInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetOnPremisesInstanceInput`](struct.GetOnPremisesInstanceInput.html).
+#[derive(Default, Debug)]
+pub struct GetOnPremisesInstanceInputBuilder {
+    instance_name: Option<String>,
+}
+
+impl GetOnPremisesInstanceInputBuilder {
+    /// Sets the [`instance_name`](struct.GetOnPremisesInstanceInput.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOnPremisesInstanceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetOnPremisesInstanceInput, ::rusoto_core::BuildError> {
+        Ok(GetOnPremisesInstanceInput {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+        })
+    }
+}
+
 /// <p> Represents the output of a GetOnPremisesInstance operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1251,13 +3660,159 @@ pub struct GitHubLocation {
     pub repository: Option<String>,
 }
 
+/// <p><p>The method used to add instances to a replacement environment.</p> <ul> <li> <p>DISCOVER<em>EXISTING: Use instances that already exist or will be created manually.</p> </li> <li> <p>COPY</em>AUTO<em>SCALING</em>GROUP: Use settings from a specified Auto Scaling group to define and create instances in a new Auto Scaling group.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GreenFleetProvisioningAction {
+    /// <p><code>DISCOVER_EXISTING</code></p>
+    DiscoverExisting,
+    /// <p><code>COPY_AUTO_SCALING_GROUP</code></p>
+    CopyAutoScalingGroup,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl GreenFleetProvisioningAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GreenFleetProvisioningAction::DiscoverExisting => "DISCOVER_EXISTING",
+            GreenFleetProvisioningAction::CopyAutoScalingGroup => "COPY_AUTO_SCALING_GROUP",
+            GreenFleetProvisioningAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GreenFleetProvisioningAction {
+    fn from(value: &'a str) -> GreenFleetProvisioningAction {
+        match value {
+            "DISCOVER_EXISTING" => GreenFleetProvisioningAction::DiscoverExisting,
+            "COPY_AUTO_SCALING_GROUP" => GreenFleetProvisioningAction::CopyAutoScalingGroup,
+            _ => GreenFleetProvisioningAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GreenFleetProvisioningAction {
+    fn from(value: String) -> GreenFleetProvisioningAction {
+        GreenFleetProvisioningAction::from(value.as_str())
+    }
+}
+
+impl From<GreenFleetProvisioningAction> for String {
+    fn from(value: GreenFleetProvisioningAction) -> String {
+        match value {
+            GreenFleetProvisioningAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GreenFleetProvisioningAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for GreenFleetProvisioningAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GreenFleetProvisioningAction {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<GreenFleetProvisioningAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(GreenFleetProvisioningAction::from(value))
+    }
+}
+
 /// <p>Information about the instances that belong to the replacement environment in a blue/green deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GreenFleetProvisioningOption {
     /// <p><p>The method used to add instances to a replacement environment.</p> <ul> <li> <p>DISCOVER<em>EXISTING: Use instances that already exist or will be created manually.</p> </li> <li> <p>COPY</em>AUTO<em>SCALING</em>GROUP: Use settings from a specified Auto Scaling group to define and create instances in a new Auto Scaling group.</p> </li> </ul></p>
     #[serde(rename = "action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<GreenFleetProvisioningAction>,
+}
+
+/// <p><p>The action to take on instances in the original environment after a successful blue/green deployment.</p> <ul> <li> <p>TERMINATE: Instances are terminated after a specified wait time.</p> </li> <li> <p>KEEP_ALIVE: Instances are left running after they are deregistered from the load balancer and removed from the deployment group.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceAction {
+    /// <p><code>TERMINATE</code></p>
+    Terminate,
+    /// <p><code>KEEP_ALIVE</code></p>
+    KeepAlive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceAction::Terminate => "TERMINATE",
+            InstanceAction::KeepAlive => "KEEP_ALIVE",
+            InstanceAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceAction {
+    fn from(value: &'a str) -> InstanceAction {
+        match value {
+            "TERMINATE" => InstanceAction::Terminate,
+            "KEEP_ALIVE" => InstanceAction::KeepAlive,
+            _ => InstanceAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceAction {
+    fn from(value: String) -> InstanceAction {
+        InstanceAction::from(value.as_str())
+    }
+}
+
+impl From<InstanceAction> for String {
+    fn from(value: InstanceAction) -> String {
+        match value {
+            InstanceAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceAction {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceAction::from(value))
+    }
 }
 
 /// <p>Information about an on-premises instance.</p>
@@ -1266,8 +3821,9 @@ pub struct GreenFleetProvisioningOption {
 pub struct InstanceInfo {
     /// <p>If the on-premises instance was deregistered, the time at which the on-premises instance was deregistered.</p>
     #[serde(rename = "deregisterTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deregister_time: Option<f64>,
+    pub deregister_time: Option<EpochTimestamp>,
     /// <p>The ARN of the IAM session associated with the on-premises instance.</p>
     #[serde(rename = "iamSessionArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1286,14 +3842,107 @@ pub struct InstanceInfo {
     pub instance_name: Option<String>,
     /// <p>The time at which the on-premises instance was registered.</p>
     #[serde(rename = "registerTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub register_time: Option<f64>,
+    pub register_time: Option<EpochTimestamp>,
     /// <p>The tags currently associated with the on-premises instance.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
 }
 
+/// <p><p>The deployment status for this instance:</p> <ul> <li> <p>Pending: The deployment is pending for this instance.</p> </li> <li> <p>In Progress: The deployment is in progress for this instance.</p> </li> <li> <p>Succeeded: The deployment has succeeded for this instance.</p> </li> <li> <p>Failed: The deployment has failed for this instance.</p> </li> <li> <p>Skipped: The deployment has been skipped for this instance.</p> </li> <li> <p>Unknown: The deployment status is unknown for this instance.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceStatus {
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Skipped</code></p>
+    Skipped,
+    /// <p><code>Unknown</code></p>
+    UnknownValue,
+    /// <p><code>Ready</code></p>
+    Ready,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceStatus::Pending => "Pending",
+            InstanceStatus::InProgress => "InProgress",
+            InstanceStatus::Succeeded => "Succeeded",
+            InstanceStatus::Failed => "Failed",
+            InstanceStatus::Skipped => "Skipped",
+            InstanceStatus::UnknownValue => "Unknown",
+            InstanceStatus::Ready => "Ready",
+            InstanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceStatus {
+    fn from(value: &'a str) -> InstanceStatus {
+        match value {
+            "Pending" => InstanceStatus::Pending,
+            "InProgress" => InstanceStatus::InProgress,
+            "Succeeded" => InstanceStatus::Succeeded,
+            "Failed" => InstanceStatus::Failed,
+            "Skipped" => InstanceStatus::Skipped,
+            "Unknown" => InstanceStatus::UnknownValue,
+            "Ready" => InstanceStatus::Ready,
+            _ => InstanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceStatus {
+    fn from(value: String) -> InstanceStatus {
+        InstanceStatus::from(value.as_str())
+    }
+}
+
+impl From<InstanceStatus> for String {
+    fn from(value: InstanceStatus) -> String {
+        match value {
+            InstanceStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceStatus::from(value))
+    }
+}
+
 /// <p>Information about an instance in a deployment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1309,11 +3958,12 @@ pub struct InstanceSummary {
     /// <p><p>Information about which environment an instance belongs to in a blue/green deployment.</p> <ul> <li> <p>BLUE: The instance is part of the original environment.</p> </li> <li> <p>GREEN: The instance is part of the replacement environment.</p> </li> </ul></p>
     #[serde(rename = "instanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_type: Option<String>,
+    pub instance_type: Option<InstanceType>,
     /// <p>A timestamp that indicaties when the instance information was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>A list of lifecycle events for this instance.</p>
     #[serde(rename = "lifecycleEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1321,7 +3971,7 @@ pub struct InstanceSummary {
     /// <p><p>The deployment status for this instance:</p> <ul> <li> <p>Pending: The deployment is pending for this instance.</p> </li> <li> <p>In Progress: The deployment is in progress for this instance.</p> </li> <li> <p>Succeeded: The deployment has succeeded for this instance.</p> </li> <li> <p>Failed: The deployment has failed for this instance.</p> </li> <li> <p>Skipped: The deployment has been skipped for this instance.</p> </li> <li> <p>Unknown: The deployment status is unknown for this instance.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<InstanceStatus>,
 }
 
 /// <p> A target Amazon EC2 or on-premises instance during a deployment that uses the EC2/On-premises compute platform. </p>
@@ -1335,11 +3985,12 @@ pub struct InstanceTarget {
     /// <p> A label that identifies whether the instance is an original target (<code>BLUE</code>) or a replacement target (<code>GREEN</code>). </p>
     #[serde(rename = "instanceLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_label: Option<String>,
+    pub instance_label: Option<TargetLabel>,
     /// <p> The date and time when the target instance was updated by a deployment. </p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p> The lifecycle events of the deployment to this target instance. </p>
     #[serde(rename = "lifecycleEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1347,7 +3998,7 @@ pub struct InstanceTarget {
     /// <p> The status an EC2/On-premises deployment's target instance. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TargetStatus>,
     /// <p> The ARN of the target. </p>
     #[serde(rename = "targetArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1358,6 +4009,78 @@ pub struct InstanceTarget {
     pub target_id: Option<String>,
 }
 
+/// <p><p>Information about which environment an instance belongs to in a blue/green deployment.</p> <ul> <li> <p>BLUE: The instance is part of the original environment.</p> </li> <li> <p>GREEN: The instance is part of the replacement environment.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceType {
+    /// <p><code>Blue</code></p>
+    Blue,
+    /// <p><code>Green</code></p>
+    Green,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceType::Blue => "Blue",
+            InstanceType::Green => "Green",
+            InstanceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceType {
+    fn from(value: &'a str) -> InstanceType {
+        match value {
+            "Blue" => InstanceType::Blue,
+            "Green" => InstanceType::Green,
+            _ => InstanceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceType {
+    fn from(value: String) -> InstanceType {
+        InstanceType::from(value.as_str())
+    }
+}
+
+impl From<InstanceType> for String {
+    fn from(value: InstanceType) -> String {
+        match value {
+            InstanceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceType::from(value))
+    }
+}
+
 /// <p> Information about a Lambda function specified in a deployment. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1398,8 +4121,9 @@ pub struct LambdaTarget {
     pub lambda_function_info: Option<LambdaFunctionInfo>,
     /// <p> The date and time when the target Lambda function was updated by a deployment. </p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p> The lifecycle events of the deployment to this target Lambda function. </p>
     #[serde(rename = "lifecycleEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1407,7 +4131,7 @@ pub struct LambdaTarget {
     /// <p> The status an AWS Lambda deployment's target Lambda function. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TargetStatus>,
     /// <p> The ARN of the target. </p>
     #[serde(rename = "targetArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1424,20 +4148,110 @@ pub struct LambdaTarget {
 pub struct LastDeploymentInfo {
     /// <p>A timestamp that indicates when the most recent deployment to the deployment group started.</p>
     #[serde(rename = "createTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<EpochTimestamp>,
     /// <p> The unique ID of a deployment. </p>
     #[serde(rename = "deploymentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deployment_id: Option<String>,
     /// <p>A timestamp that indicates when the most recent deployment to the deployment group was complete.</p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The status of the most recent deployment.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeploymentStatus>,
+}
+
+/// <p><p>The associated error code:</p> <ul> <li> <p>Success: The specified script ran.</p> </li> <li> <p>ScriptMissing: The specified script was not found in the specified location.</p> </li> <li> <p>ScriptNotExecutable: The specified script is not a recognized executable file type.</p> </li> <li> <p>ScriptTimedOut: The specified script did not finish running in the specified time period.</p> </li> <li> <p>ScriptFailed: The specified script failed to run as expected.</p> </li> <li> <p>UnknownError: The specified script did not run for an unknown reason.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LifecycleErrorCode {
+    /// <p><code>Success</code></p>
+    Success,
+    /// <p><code>ScriptMissing</code></p>
+    ScriptMissing,
+    /// <p><code>ScriptNotExecutable</code></p>
+    ScriptNotExecutable,
+    /// <p><code>ScriptTimedOut</code></p>
+    ScriptTimedOut,
+    /// <p><code>ScriptFailed</code></p>
+    ScriptFailed,
+    /// <p><code>UnknownError</code></p>
+    UnknownError,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LifecycleErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LifecycleErrorCode::Success => "Success",
+            LifecycleErrorCode::ScriptMissing => "ScriptMissing",
+            LifecycleErrorCode::ScriptNotExecutable => "ScriptNotExecutable",
+            LifecycleErrorCode::ScriptTimedOut => "ScriptTimedOut",
+            LifecycleErrorCode::ScriptFailed => "ScriptFailed",
+            LifecycleErrorCode::UnknownError => "UnknownError",
+            LifecycleErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LifecycleErrorCode {
+    fn from(value: &'a str) -> LifecycleErrorCode {
+        match value {
+            "Success" => LifecycleErrorCode::Success,
+            "ScriptMissing" => LifecycleErrorCode::ScriptMissing,
+            "ScriptNotExecutable" => LifecycleErrorCode::ScriptNotExecutable,
+            "ScriptTimedOut" => LifecycleErrorCode::ScriptTimedOut,
+            "ScriptFailed" => LifecycleErrorCode::ScriptFailed,
+            "UnknownError" => LifecycleErrorCode::UnknownError,
+            _ => LifecycleErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LifecycleErrorCode {
+    fn from(value: String) -> LifecycleErrorCode {
+        LifecycleErrorCode::from(value.as_str())
+    }
+}
+
+impl From<LifecycleErrorCode> for String {
+    fn from(value: LifecycleErrorCode) -> String {
+        match value {
+            LifecycleErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LifecycleErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LifecycleErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LifecycleErrorCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LifecycleErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LifecycleErrorCode::from(value))
+    }
 }
 
 /// <p>Information about a deployment lifecycle event.</p>
@@ -1450,20 +4264,110 @@ pub struct LifecycleEvent {
     pub diagnostics: Option<Diagnostics>,
     /// <p>A timestamp that indicates when the deployment lifecycle event ended.</p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The deployment lifecycle event name, such as ApplicationStop, BeforeInstall, AfterInstall, ApplicationStart, or ValidateService.</p>
     #[serde(rename = "lifecycleEventName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lifecycle_event_name: Option<String>,
     /// <p>A timestamp that indicates when the deployment lifecycle event started.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p><p>The deployment lifecycle event status:</p> <ul> <li> <p>Pending: The deployment lifecycle event is pending.</p> </li> <li> <p>InProgress: The deployment lifecycle event is in progress.</p> </li> <li> <p>Succeeded: The deployment lifecycle event ran successfully.</p> </li> <li> <p>Failed: The deployment lifecycle event has failed.</p> </li> <li> <p>Skipped: The deployment lifecycle event has been skipped.</p> </li> <li> <p>Unknown: The deployment lifecycle event is unknown.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<LifecycleEventStatus>,
+}
+
+/// <p><p>The deployment lifecycle event status:</p> <ul> <li> <p>Pending: The deployment lifecycle event is pending.</p> </li> <li> <p>InProgress: The deployment lifecycle event is in progress.</p> </li> <li> <p>Succeeded: The deployment lifecycle event ran successfully.</p> </li> <li> <p>Failed: The deployment lifecycle event has failed.</p> </li> <li> <p>Skipped: The deployment lifecycle event has been skipped.</p> </li> <li> <p>Unknown: The deployment lifecycle event is unknown.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LifecycleEventStatus {
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Skipped</code></p>
+    Skipped,
+    /// <p><code>Unknown</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LifecycleEventStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LifecycleEventStatus::Pending => "Pending",
+            LifecycleEventStatus::InProgress => "InProgress",
+            LifecycleEventStatus::Succeeded => "Succeeded",
+            LifecycleEventStatus::Failed => "Failed",
+            LifecycleEventStatus::Skipped => "Skipped",
+            LifecycleEventStatus::UnknownValue => "Unknown",
+            LifecycleEventStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LifecycleEventStatus {
+    fn from(value: &'a str) -> LifecycleEventStatus {
+        match value {
+            "Pending" => LifecycleEventStatus::Pending,
+            "InProgress" => LifecycleEventStatus::InProgress,
+            "Succeeded" => LifecycleEventStatus::Succeeded,
+            "Failed" => LifecycleEventStatus::Failed,
+            "Skipped" => LifecycleEventStatus::Skipped,
+            "Unknown" => LifecycleEventStatus::UnknownValue,
+            _ => LifecycleEventStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LifecycleEventStatus {
+    fn from(value: String) -> LifecycleEventStatus {
+        LifecycleEventStatus::from(value.as_str())
+    }
+}
+
+impl From<LifecycleEventStatus> for String {
+    fn from(value: LifecycleEventStatus) -> String {
+        match value {
+            LifecycleEventStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LifecycleEventStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LifecycleEventStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LifecycleEventStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LifecycleEventStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LifecycleEventStatus::from(value))
+    }
 }
 
 /// <p> Represents the input of a ListApplicationRevisions operation. </p>
@@ -1475,7 +4379,7 @@ pub struct ListApplicationRevisionsInput {
     /// <p><p> Whether to list revisions based on whether the revision is the target revision of an deployment group: </p> <ul> <li> <p>include: List revisions that are target revisions of a deployment group.</p> </li> <li> <p>exclude: Do not list revisions that are target revisions of a deployment group.</p> </li> <li> <p>ignore: List all revisions.</p> </li> </ul></p>
     #[serde(rename = "deployed")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployed: Option<String>,
+    pub deployed: Option<ListStateFilterAction>,
     /// <p>An identifier returned from the previous <code>ListApplicationRevisions</code> call. It can be used to return the next set of applications in the list.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1491,36 +4395,144 @@ pub struct ListApplicationRevisionsInput {
     /// <p>The column name to use to sort the list results:</p> <ul> <li> <p>registerTime: Sort by the time the revisions were registered with AWS CodeDeploy.</p> </li> <li> <p>firstUsedTime: Sort by the time the revisions were first used in a deployment.</p> </li> <li> <p>lastUsedTime: Sort by the time the revisions were last used in a deployment.</p> </li> </ul> <p> If not specified or set to null, the results are returned in an arbitrary order. </p>
     #[serde(rename = "sortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<ApplicationRevisionSortBy>,
     /// <p> The order in which to sort the list results: </p> <ul> <li> <p>ascending: ascending order.</p> </li> <li> <p>descending: descending order.</p> </li> </ul> <p>If not specified, the results are sorted in ascending order.</p> <p>If set to null, the results are sorted in an arbitrary order.</p>
     #[serde(rename = "sortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
 }
 
-/// <p>Represents the output of a ListApplicationRevisions operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListApplicationRevisionsOutput {
-    /// <p>If a large amount of information is returned, an identifier is also returned. It can be used in a subsequent list application revisions call to return the next set of application revisions in the list.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>A list of locations that contain the matching revisions.</p>
-    #[serde(rename = "revisions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub revisions: Option<Vec<RevisionLocation>>,
+impl ListApplicationRevisionsInput {
+    /// Returns a builder for `ListApplicationRevisionsInput`, checking its required members when built.
+    pub fn builder() -> ListApplicationRevisionsInputBuilder {
+        ListApplicationRevisionsInputBuilder::default()
+    }
 }
 
-/// <p>Represents the input of a ListApplications operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ListApplicationsInput {
-    /// <p>An identifier returned from the previous list applications call. It can be used to return the next set of applications in the list.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+/// Builds a [`ListApplicationRevisionsInput`](struct.ListApplicationRevisionsInput.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationRevisionsInputBuilder {
+    application_name: Option<String>,
+    deployed: Option<ListStateFilterAction>,
+    next_token: Option<String>,
+    s_3_bucket: Option<String>,
+    s_3_key_prefix: Option<String>,
+    sort_by: Option<ApplicationRevisionSortBy>,
+    sort_order: Option<SortOrder>,
+}
+
+impl ListApplicationRevisionsInputBuilder {
+    /// Sets the [`application_name`](struct.ListApplicationRevisionsInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployed`](struct.ListApplicationRevisionsInput.html#structfield.deployed) member.
+    pub fn deployed<T: Into<ListStateFilterAction>>(mut self, value: T) -> Self {
+        self.deployed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListApplicationRevisionsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s_3_bucket`](struct.ListApplicationRevisionsInput.html#structfield.s_3_bucket) member.
+    pub fn s_3_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.s_3_bucket = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s_3_key_prefix`](struct.ListApplicationRevisionsInput.html#structfield.s_3_key_prefix) member.
+    pub fn s_3_key_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.s_3_key_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_by`](struct.ListApplicationRevisionsInput.html#structfield.sort_by) member.
+    pub fn sort_by<T: Into<ApplicationRevisionSortBy>>(mut self, value: T) -> Self {
+        self.sort_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_order`](struct.ListApplicationRevisionsInput.html#structfield.sort_order) member.
+    pub fn sort_order<T: Into<SortOrder>>(mut self, value: T) -> Self {
+        self.sort_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationRevisionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListApplicationRevisionsInput, ::rusoto_core::BuildError> {
+        Ok(ListApplicationRevisionsInput {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            deployed: self.deployed,
+            next_token: self.next_token,
+            s_3_bucket: self.s_3_bucket,
+            s_3_key_prefix: self.s_3_key_prefix,
+            sort_by: self.sort_by,
+            sort_order: self.sort_order,
+        })
+    }
+}
+
+/// <p>Represents the output of a ListApplicationRevisions operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListApplicationRevisionsOutput {
+    /// <p>If a large amount of information is returned, an identifier is also returned. It can be used in a subsequent list application revisions call to return the next set of application revisions in the list.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>A list of locations that contain the matching revisions.</p>
+    #[serde(rename = "revisions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revisions: Option<Vec<RevisionLocation>>,
+}
+
+/// <p>Represents the input of a ListApplications operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListApplicationsInput {
+    /// <p>An identifier returned from the previous list applications call. It can be used to return the next set of applications in the list.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub next_token: Option<String>,
 }
 
+impl ListApplicationsInput {
+    /// Returns a builder for `ListApplicationsInput`, checking its required members when built.
+    pub fn builder() -> ListApplicationsInputBuilder {
+        ListApplicationsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListApplicationsInput`](struct.ListApplicationsInput.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationsInputBuilder {
+    next_token: Option<String>,
+}
+
+impl ListApplicationsInputBuilder {
+    /// Sets the [`next_token`](struct.ListApplicationsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListApplicationsInput, ::rusoto_core::BuildError> {
+        Ok(ListApplicationsInput {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the output of a ListApplications operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1544,6 +4556,36 @@ pub struct ListDeploymentConfigsInput {
     pub next_token: Option<String>,
 }
 
+impl ListDeploymentConfigsInput {
+    /// Returns a builder for `ListDeploymentConfigsInput`, checking its required members when built.
+    pub fn builder() -> ListDeploymentConfigsInputBuilder {
+        ListDeploymentConfigsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeploymentConfigsInput`](struct.ListDeploymentConfigsInput.html).
+#[derive(Default, Debug)]
+pub struct ListDeploymentConfigsInputBuilder {
+    next_token: Option<String>,
+}
+
+impl ListDeploymentConfigsInputBuilder {
+    /// Sets the [`next_token`](struct.ListDeploymentConfigsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeploymentConfigsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDeploymentConfigsInput, ::rusoto_core::BuildError> {
+        Ok(ListDeploymentConfigsInput {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the output of a ListDeploymentConfigs operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1570,6 +4612,46 @@ pub struct ListDeploymentGroupsInput {
     pub next_token: Option<String>,
 }
 
+impl ListDeploymentGroupsInput {
+    /// Returns a builder for `ListDeploymentGroupsInput`, checking its required members when built.
+    pub fn builder() -> ListDeploymentGroupsInputBuilder {
+        ListDeploymentGroupsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeploymentGroupsInput`](struct.ListDeploymentGroupsInput.html).
+#[derive(Default, Debug)]
+pub struct ListDeploymentGroupsInputBuilder {
+    application_name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListDeploymentGroupsInputBuilder {
+    /// Sets the [`application_name`](struct.ListDeploymentGroupsInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDeploymentGroupsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeploymentGroupsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDeploymentGroupsInput, ::rusoto_core::BuildError> {
+        Ok(ListDeploymentGroupsInput {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the output of a ListDeploymentGroups operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1597,17 +4679,73 @@ pub struct ListDeploymentInstancesInput {
     /// <p><p>A subset of instances to list by status:</p> <ul> <li> <p>Pending: Include those instances with pending deployments.</p> </li> <li> <p>InProgress: Include those instances where deployments are still in progress.</p> </li> <li> <p>Succeeded: Include those instances with successful deployments.</p> </li> <li> <p>Failed: Include those instances with failed deployments.</p> </li> <li> <p>Skipped: Include those instances with skipped deployments.</p> </li> <li> <p>Unknown: Include those instances with deployments in an unknown state.</p> </li> </ul></p>
     #[serde(rename = "instanceStatusFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_status_filter: Option<Vec<String>>,
+    pub instance_status_filter: Option<Vec<InstanceStatus>>,
     /// <p>The set of instances in a blue/green deployment, either those in the original environment ("BLUE") or those in the replacement environment ("GREEN"), for which you want to view instance information.</p>
     #[serde(rename = "instanceTypeFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_type_filter: Option<Vec<String>>,
+    pub instance_type_filter: Option<Vec<InstanceType>>,
     /// <p>An identifier returned from the previous list deployment instances call. It can be used to return the next set of deployment instances in the list.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_token: Option<String>,
 }
 
+impl ListDeploymentInstancesInput {
+    /// Returns a builder for `ListDeploymentInstancesInput`, checking its required members when built.
+    pub fn builder() -> ListDeploymentInstancesInputBuilder {
+        ListDeploymentInstancesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeploymentInstancesInput`](struct.ListDeploymentInstancesInput.html).
+#[derive(Default, Debug)]
+pub struct ListDeploymentInstancesInputBuilder {
+    deployment_id: Option<String>,
+    instance_status_filter: Option<Vec<InstanceStatus>>,
+    instance_type_filter: Option<Vec<InstanceType>>,
+    next_token: Option<String>,
+}
+
+impl ListDeploymentInstancesInputBuilder {
+    /// Sets the [`deployment_id`](struct.ListDeploymentInstancesInput.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_status_filter`](struct.ListDeploymentInstancesInput.html#structfield.instance_status_filter) member.
+    pub fn instance_status_filter<T: Into<Vec<InstanceStatus>>>(mut self, value: T) -> Self {
+        self.instance_status_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_type_filter`](struct.ListDeploymentInstancesInput.html#structfield.instance_type_filter) member.
+    pub fn instance_type_filter<T: Into<Vec<InstanceType>>>(mut self, value: T) -> Self {
+        self.instance_type_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDeploymentInstancesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeploymentInstancesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDeploymentInstancesInput, ::rusoto_core::BuildError> {
+        Ok(ListDeploymentInstancesInput {
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+            instance_status_filter: self.instance_status_filter,
+            instance_type_filter: self.instance_type_filter,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the output of a ListDeploymentInstances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1635,7 +4773,56 @@ pub struct ListDeploymentTargetsInput {
     /// <p><p> A key used to filter the returned targets. The two valid values are:</p> <ul> <li> <p> <code>TargetStatus</code> - A <code>TargetStatus</code> filter string can be <code>Failed</code>, <code>InProgress</code>, <code>Pending</code>, <code>Ready</code>, <code>Skipped</code>, <code>Succeeded</code>, or <code>Unknown</code>. </p> </li> <li> <p> <code>ServerInstanceLabel</code> - A <code>ServerInstanceLabel</code> filter string can be <code>Blue</code> or <code>Green</code>. </p> </li> </ul></p>
     #[serde(rename = "targetFilters")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_filters: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub target_filters: Option<::std::collections::HashMap<TargetFilterName, Vec<String>>>,
+}
+
+impl ListDeploymentTargetsInput {
+    /// Returns a builder for `ListDeploymentTargetsInput`, checking its required members when built.
+    pub fn builder() -> ListDeploymentTargetsInputBuilder {
+        ListDeploymentTargetsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeploymentTargetsInput`](struct.ListDeploymentTargetsInput.html).
+#[derive(Default, Debug)]
+pub struct ListDeploymentTargetsInputBuilder {
+    deployment_id: Option<String>,
+    next_token: Option<String>,
+    target_filters: Option<::std::collections::HashMap<TargetFilterName, Vec<String>>>,
+}
+
+impl ListDeploymentTargetsInputBuilder {
+    /// Sets the [`deployment_id`](struct.ListDeploymentTargetsInput.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDeploymentTargetsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_filters`](struct.ListDeploymentTargetsInput.html#structfield.target_filters) member.
+    pub fn target_filters<T: Into<::std::collections::HashMap<TargetFilterName, Vec<String>>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.target_filters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeploymentTargetsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDeploymentTargetsInput, ::rusoto_core::BuildError> {
+        Ok(ListDeploymentTargetsInput {
+            deployment_id: self.deployment_id,
+            next_token: self.next_token,
+            target_filters: self.target_filters,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1669,13 +4856,73 @@ pub struct ListDeploymentsInput {
     /// <p><p>A subset of deployments to list by status:</p> <ul> <li> <p>Created: Include created deployments in the resulting list.</p> </li> <li> <p>Queued: Include queued deployments in the resulting list.</p> </li> <li> <p>In Progress: Include in-progress deployments in the resulting list.</p> </li> <li> <p>Succeeded: Include successful deployments in the resulting list.</p> </li> <li> <p>Failed: Include failed deployments in the resulting list.</p> </li> <li> <p>Stopped: Include stopped deployments in the resulting list.</p> </li> </ul></p>
     #[serde(rename = "includeOnlyStatuses")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include_only_statuses: Option<Vec<String>>,
+    pub include_only_statuses: Option<Vec<DeploymentStatus>>,
     /// <p>An identifier returned from the previous list deployments call. It can be used to return the next set of deployments in the list.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_token: Option<String>,
 }
 
+impl ListDeploymentsInput {
+    /// Returns a builder for `ListDeploymentsInput`, checking its required members when built.
+    pub fn builder() -> ListDeploymentsInputBuilder {
+        ListDeploymentsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeploymentsInput`](struct.ListDeploymentsInput.html).
+#[derive(Default, Debug)]
+pub struct ListDeploymentsInputBuilder {
+    application_name: Option<String>,
+    create_time_range: Option<TimeRange>,
+    deployment_group_name: Option<String>,
+    include_only_statuses: Option<Vec<DeploymentStatus>>,
+    next_token: Option<String>,
+}
+
+impl ListDeploymentsInputBuilder {
+    /// Sets the [`application_name`](struct.ListDeploymentsInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`create_time_range`](struct.ListDeploymentsInput.html#structfield.create_time_range) member.
+    pub fn create_time_range<T: Into<TimeRange>>(mut self, value: T) -> Self {
+        self.create_time_range = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_group_name`](struct.ListDeploymentsInput.html#structfield.deployment_group_name) member.
+    pub fn deployment_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_only_statuses`](struct.ListDeploymentsInput.html#structfield.include_only_statuses) member.
+    pub fn include_only_statuses<T: Into<Vec<DeploymentStatus>>>(mut self, value: T) -> Self {
+        self.include_only_statuses = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDeploymentsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeploymentsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDeploymentsInput, ::rusoto_core::BuildError> {
+        Ok(ListDeploymentsInput {
+            application_name: self.application_name,
+            create_time_range: self.create_time_range,
+            deployment_group_name: self.deployment_group_name,
+            include_only_statuses: self.include_only_statuses,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the output of a ListDeployments operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1699,6 +4946,36 @@ pub struct ListGitHubAccountTokenNamesInput {
     pub next_token: Option<String>,
 }
 
+impl ListGitHubAccountTokenNamesInput {
+    /// Returns a builder for `ListGitHubAccountTokenNamesInput`, checking its required members when built.
+    pub fn builder() -> ListGitHubAccountTokenNamesInputBuilder {
+        ListGitHubAccountTokenNamesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListGitHubAccountTokenNamesInput`](struct.ListGitHubAccountTokenNamesInput.html).
+#[derive(Default, Debug)]
+pub struct ListGitHubAccountTokenNamesInputBuilder {
+    next_token: Option<String>,
+}
+
+impl ListGitHubAccountTokenNamesInputBuilder {
+    /// Sets the [`next_token`](struct.ListGitHubAccountTokenNamesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGitHubAccountTokenNamesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListGitHubAccountTokenNamesInput, ::rusoto_core::BuildError> {
+        Ok(ListGitHubAccountTokenNamesInput {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the output of a ListGitHubAccountTokenNames operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1723,13 +5000,59 @@ pub struct ListOnPremisesInstancesInput {
     /// <p><p>The registration status of the on-premises instances:</p> <ul> <li> <p>Deregistered: Include deregistered on-premises instances in the resulting list.</p> </li> <li> <p>Registered: Include registered on-premises instances in the resulting list.</p> </li> </ul></p>
     #[serde(rename = "registrationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub registration_status: Option<String>,
+    pub registration_status: Option<RegistrationStatus>,
     /// <p>The on-premises instance tags that are used to restrict the on-premises instance names returned.</p>
     #[serde(rename = "tagFilters")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag_filters: Option<Vec<TagFilter>>,
 }
 
+impl ListOnPremisesInstancesInput {
+    /// Returns a builder for `ListOnPremisesInstancesInput`, checking its required members when built.
+    pub fn builder() -> ListOnPremisesInstancesInputBuilder {
+        ListOnPremisesInstancesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListOnPremisesInstancesInput`](struct.ListOnPremisesInstancesInput.html).
+#[derive(Default, Debug)]
+pub struct ListOnPremisesInstancesInputBuilder {
+    next_token: Option<String>,
+    registration_status: Option<RegistrationStatus>,
+    tag_filters: Option<Vec<TagFilter>>,
+}
+
+impl ListOnPremisesInstancesInputBuilder {
+    /// Sets the [`next_token`](struct.ListOnPremisesInstancesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registration_status`](struct.ListOnPremisesInstancesInput.html#structfield.registration_status) member.
+    pub fn registration_status<T: Into<RegistrationStatus>>(mut self, value: T) -> Self {
+        self.registration_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_filters`](struct.ListOnPremisesInstancesInput.html#structfield.tag_filters) member.
+    pub fn tag_filters<T: Into<Vec<TagFilter>>>(mut self, value: T) -> Self {
+        self.tag_filters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOnPremisesInstancesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListOnPremisesInstancesInput, ::rusoto_core::BuildError> {
+        Ok(ListOnPremisesInstancesInput {
+            next_token: self.next_token,
+            registration_status: self.registration_status,
+            tag_filters: self.tag_filters,
+        })
+    }
+}
+
 /// <p>Represents the output of the list on-premises instances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1744,6 +5067,82 @@ pub struct ListOnPremisesInstancesOutput {
     pub next_token: Option<String>,
 }
 
+/// <p><p> Whether to list revisions based on whether the revision is the target revision of an deployment group: </p> <ul> <li> <p>include: List revisions that are target revisions of a deployment group.</p> </li> <li> <p>exclude: Do not list revisions that are target revisions of a deployment group.</p> </li> <li> <p>ignore: List all revisions.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ListStateFilterAction {
+    /// <p><code>include</code></p>
+    Include,
+    /// <p><code>exclude</code></p>
+    Exclude,
+    /// <p><code>ignore</code></p>
+    Ignore,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ListStateFilterAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ListStateFilterAction::Include => "include",
+            ListStateFilterAction::Exclude => "exclude",
+            ListStateFilterAction::Ignore => "ignore",
+            ListStateFilterAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ListStateFilterAction {
+    fn from(value: &'a str) -> ListStateFilterAction {
+        match value {
+            "include" => ListStateFilterAction::Include,
+            "exclude" => ListStateFilterAction::Exclude,
+            "ignore" => ListStateFilterAction::Ignore,
+            _ => ListStateFilterAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ListStateFilterAction {
+    fn from(value: String) -> ListStateFilterAction {
+        ListStateFilterAction::from(value.as_str())
+    }
+}
+
+impl From<ListStateFilterAction> for String {
+    fn from(value: ListStateFilterAction) -> String {
+        match value {
+            ListStateFilterAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ListStateFilterAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ListStateFilterAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ListStateFilterAction {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ListStateFilterAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ListStateFilterAction::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListTagsForResourceInput {
     /// <p>An identifier returned from the previous <code>ListTagsForResource</code> call. It can be used to return the next set of applications in the list.</p>
@@ -1755,6 +5154,46 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceInput {
+    /// Returns a builder for `ListTagsForResourceInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceInputBuilder {
+        ListTagsForResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceInput`](struct.ListTagsForResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceInputBuilder {
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceInputBuilder {
+    /// Sets the [`next_token`](struct.ListTagsForResourceInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceInput {
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceOutput {
@@ -1791,13 +5230,85 @@ pub struct MinimumHealthyHosts {
     /// <p>The minimum healthy instance type:</p> <ul> <li> <p>HOST_COUNT: The minimum number of healthy instance as an absolute value.</p> </li> <li> <p>FLEET_PERCENT: The minimum number of healthy instance as a percentage of the total number of instance in the deployment.</p> </li> </ul> <p>In an example of nine instance, if a HOST_COUNT of six is specified, deploy to up to three instances at a time. The deployment is successful if six or more instances are deployed to successfully. Otherwise, the deployment fails. If a FLEET_PERCENT of 40 is specified, deploy to up to five instance at a time. The deployment is successful if four or more instance are deployed to successfully. Otherwise, the deployment fails.</p> <note> <p>In a call to the <code>GetDeploymentConfig</code>, CodeDeployDefault.OneAtATime returns a minimum healthy instance type of MOST_CONCURRENCY and a value of 1. This means a deployment to only one instance at a time. (You cannot set the type to MOST_CONCURRENCY, only to HOST_COUNT or FLEET_PERCENT.) In addition, with CodeDeployDefault.OneAtATime, AWS CodeDeploy attempts to ensure that all instances but one are kept in a healthy state during the deployment. Although this allows one instance at a time to be taken offline for a new deployment, it also means that if the deployment to the last instance fails, the overall deployment is still successful.</p> </note> <p>For more information, see <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide/instances-health.html">AWS CodeDeploy Instance Health</a> in the <i>AWS CodeDeploy User Guide</i>.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<MinimumHealthyHostsType>,
     /// <p>The minimum healthy instance value.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<i64>,
 }
 
+/// <p>The minimum healthy instance type:</p> <ul> <li> <p>HOST_COUNT: The minimum number of healthy instance as an absolute value.</p> </li> <li> <p>FLEET_PERCENT: The minimum number of healthy instance as a percentage of the total number of instance in the deployment.</p> </li> </ul> <p>In an example of nine instance, if a HOST_COUNT of six is specified, deploy to up to three instances at a time. The deployment is successful if six or more instances are deployed to successfully. Otherwise, the deployment fails. If a FLEET_PERCENT of 40 is specified, deploy to up to five instance at a time. The deployment is successful if four or more instance are deployed to successfully. Otherwise, the deployment fails.</p> <note> <p>In a call to the <code>GetDeploymentConfig</code>, CodeDeployDefault.OneAtATime returns a minimum healthy instance type of MOST_CONCURRENCY and a value of 1. This means a deployment to only one instance at a time. (You cannot set the type to MOST_CONCURRENCY, only to HOST_COUNT or FLEET_PERCENT.) In addition, with CodeDeployDefault.OneAtATime, AWS CodeDeploy attempts to ensure that all instances but one are kept in a healthy state during the deployment. Although this allows one instance at a time to be taken offline for a new deployment, it also means that if the deployment to the last instance fails, the overall deployment is still successful.</p> </note> <p>For more information, see <a href="https://docs.aws.amazon.com/codedeploy/latest/userguide/instances-health.html">AWS CodeDeploy Instance Health</a> in the <i>AWS CodeDeploy User Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MinimumHealthyHostsType {
+    /// <p><code>HOST_COUNT</code></p>
+    HostCount,
+    /// <p><code>FLEET_PERCENT</code></p>
+    FleetPercent,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MinimumHealthyHostsType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MinimumHealthyHostsType::HostCount => "HOST_COUNT",
+            MinimumHealthyHostsType::FleetPercent => "FLEET_PERCENT",
+            MinimumHealthyHostsType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MinimumHealthyHostsType {
+    fn from(value: &'a str) -> MinimumHealthyHostsType {
+        match value {
+            "HOST_COUNT" => MinimumHealthyHostsType::HostCount,
+            "FLEET_PERCENT" => MinimumHealthyHostsType::FleetPercent,
+            _ => MinimumHealthyHostsType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MinimumHealthyHostsType {
+    fn from(value: String) -> MinimumHealthyHostsType {
+        MinimumHealthyHostsType::from(value.as_str())
+    }
+}
+
+impl From<MinimumHealthyHostsType> for String {
+    fn from(value: MinimumHealthyHostsType) -> String {
+        match value {
+            MinimumHealthyHostsType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MinimumHealthyHostsType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MinimumHealthyHostsType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MinimumHealthyHostsType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MinimumHealthyHostsType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MinimumHealthyHostsType::from(value))
+    }
+}
+
 /// <p>Information about groups of on-premises instance tags.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OnPremisesTagSet {
@@ -1820,7 +5331,54 @@ pub struct PutLifecycleEventHookExecutionStatusInput {
     /// <p>The result of a Lambda function that validates a deployment lifecycle event (<code>Succeeded</code> or <code>Failed</code>).</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<LifecycleEventStatus>,
+}
+
+impl PutLifecycleEventHookExecutionStatusInput {
+    /// Returns a builder for `PutLifecycleEventHookExecutionStatusInput`, checking its required members when built.
+    pub fn builder() -> PutLifecycleEventHookExecutionStatusInputBuilder {
+        PutLifecycleEventHookExecutionStatusInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutLifecycleEventHookExecutionStatusInput`](struct.PutLifecycleEventHookExecutionStatusInput.html).
+#[derive(Default, Debug)]
+pub struct PutLifecycleEventHookExecutionStatusInputBuilder {
+    deployment_id: Option<String>,
+    lifecycle_event_hook_execution_id: Option<String>,
+    status: Option<LifecycleEventStatus>,
+}
+
+impl PutLifecycleEventHookExecutionStatusInputBuilder {
+    /// Sets the [`deployment_id`](struct.PutLifecycleEventHookExecutionStatusInput.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_event_hook_execution_id`](struct.PutLifecycleEventHookExecutionStatusInput.html#structfield.lifecycle_event_hook_execution_id) member.
+    pub fn lifecycle_event_hook_execution_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.lifecycle_event_hook_execution_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.PutLifecycleEventHookExecutionStatusInput.html#structfield.status) member.
+    pub fn status<T: Into<LifecycleEventStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutLifecycleEventHookExecutionStatusInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutLifecycleEventHookExecutionStatusInput, ::rusoto_core::BuildError>
+    {
+        Ok(PutLifecycleEventHookExecutionStatusInput {
+            deployment_id: self.deployment_id,
+            lifecycle_event_hook_execution_id: self.lifecycle_event_hook_execution_id,
+            status: self.status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1860,22 +5418,192 @@ pub struct RegisterApplicationRevisionInput {
     pub revision: RevisionLocation,
 }
 
-/// <p>Represents the input of the register on-premises instance operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct RegisterOnPremisesInstanceInput {
-    /// <p>The ARN of the IAM session to associate with the on-premises instance.</p>
-    #[serde(rename = "iamSessionArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub iam_session_arn: Option<String>,
-    /// <p>The ARN of the IAM user to associate with the on-premises instance.</p>
-    #[serde(rename = "iamUserArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl RegisterApplicationRevisionInput {
+    /// Returns a builder for `RegisterApplicationRevisionInput`, checking its required members when built.
+    pub fn builder() -> RegisterApplicationRevisionInputBuilder {
+        RegisterApplicationRevisionInputBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterApplicationRevisionInput`](struct.RegisterApplicationRevisionInput.html).
+#[derive(Default, Debug)]
+pub struct RegisterApplicationRevisionInputBuilder {
+    application_name: Option<String>,
+    description: Option<String>,
+    revision: Option<RevisionLocation>,
+}
+
+impl RegisterApplicationRevisionInputBuilder {
+    /// Sets the [`application_name`](struct.RegisterApplicationRevisionInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.RegisterApplicationRevisionInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision`](struct.RegisterApplicationRevisionInput.html#structfield.revision) member.
+    pub fn revision<T: Into<RevisionLocation>>(mut self, value: T) -> Self {
+        self.revision = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterApplicationRevisionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterApplicationRevisionInput, ::rusoto_core::BuildError> {
+        Ok(RegisterApplicationRevisionInput {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            description: self.description,
+            revision: self
+                .revision
+                .ok_or(::rusoto_core::BuildError::missing("revision"))?,
+        })
+    }
+}
+
+/// <p>Represents the input of the register on-premises instance operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct RegisterOnPremisesInstanceInput {
+    /// <p>The ARN of the IAM session to associate with the on-premises instance.</p>
+    #[serde(rename = "iamSessionArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iam_session_arn: Option<String>,
+    /// <p>The ARN of the IAM user to associate with the on-premises instance.</p>
+    #[serde(rename = "iamUserArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub iam_user_arn: Option<String>,
     /// <p>The name of the on-premises instance to register.</p>
     #[serde(rename = "instanceName")]
     pub instance_name: String,
 }
 
+impl RegisterOnPremisesInstanceInput {
+    /// Returns a builder for `RegisterOnPremisesInstanceInput`, checking its required members when built.
+    pub fn builder() -> RegisterOnPremisesInstanceInputBuilder {
+        RegisterOnPremisesInstanceInputBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterOnPremisesInstanceInput`](struct.RegisterOnPremisesInstanceInput.html).
+#[derive(Default, Debug)]
+pub struct RegisterOnPremisesInstanceInputBuilder {
+    iam_session_arn: Option<String>,
+    iam_user_arn: Option<String>,
+    instance_name: Option<String>,
+}
+
+impl RegisterOnPremisesInstanceInputBuilder {
+    /// Sets the [`iam_session_arn`](struct.RegisterOnPremisesInstanceInput.html#structfield.iam_session_arn) member.
+    pub fn iam_session_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_session_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_user_arn`](struct.RegisterOnPremisesInstanceInput.html#structfield.iam_user_arn) member.
+    pub fn iam_user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_user_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_name`](struct.RegisterOnPremisesInstanceInput.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterOnPremisesInstanceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterOnPremisesInstanceInput, ::rusoto_core::BuildError> {
+        Ok(RegisterOnPremisesInstanceInput {
+            iam_session_arn: self.iam_session_arn,
+            iam_user_arn: self.iam_user_arn,
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+        })
+    }
+}
+
+/// <p><p>The registration status of the on-premises instances:</p> <ul> <li> <p>Deregistered: Include deregistered on-premises instances in the resulting list.</p> </li> <li> <p>Registered: Include registered on-premises instances in the resulting list.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RegistrationStatus {
+    /// <p><code>Registered</code></p>
+    Registered,
+    /// <p><code>Deregistered</code></p>
+    Deregistered,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RegistrationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RegistrationStatus::Registered => "Registered",
+            RegistrationStatus::Deregistered => "Deregistered",
+            RegistrationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RegistrationStatus {
+    fn from(value: &'a str) -> RegistrationStatus {
+        match value {
+            "Registered" => RegistrationStatus::Registered,
+            "Deregistered" => RegistrationStatus::Deregistered,
+            _ => RegistrationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RegistrationStatus {
+    fn from(value: String) -> RegistrationStatus {
+        RegistrationStatus::from(value.as_str())
+    }
+}
+
+impl From<RegistrationStatus> for String {
+    fn from(value: RegistrationStatus) -> String {
+        match value {
+            RegistrationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RegistrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RegistrationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RegistrationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RegistrationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RegistrationStatus::from(value))
+    }
+}
+
 /// <p>Represents the input of a RemoveTagsFromOnPremisesInstances operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RemoveTagsFromOnPremisesInstancesInput {
@@ -1887,6 +5615,49 @@ pub struct RemoveTagsFromOnPremisesInstancesInput {
     pub tags: Vec<Tag>,
 }
 
+impl RemoveTagsFromOnPremisesInstancesInput {
+    /// Returns a builder for `RemoveTagsFromOnPremisesInstancesInput`, checking its required members when built.
+    pub fn builder() -> RemoveTagsFromOnPremisesInstancesInputBuilder {
+        RemoveTagsFromOnPremisesInstancesInputBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsFromOnPremisesInstancesInput`](struct.RemoveTagsFromOnPremisesInstancesInput.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsFromOnPremisesInstancesInputBuilder {
+    instance_names: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl RemoveTagsFromOnPremisesInstancesInputBuilder {
+    /// Sets the [`instance_names`](struct.RemoveTagsFromOnPremisesInstancesInput.html#structfield.instance_names) member.
+    pub fn instance_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RemoveTagsFromOnPremisesInstancesInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsFromOnPremisesInstancesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveTagsFromOnPremisesInstancesInput, ::rusoto_core::BuildError>
+    {
+        Ok(RemoveTagsFromOnPremisesInstancesInput {
+            instance_names: self
+                .instance_names
+                .ok_or(::rusoto_core::BuildError::missing("instance_names"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>Information about an application revision.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1915,7 +5686,7 @@ pub struct RevisionLocation {
     /// <p><p>The type of application revision:</p> <ul> <li> <p>S3: An application revision stored in Amazon S3.</p> </li> <li> <p>GitHub: An application revision stored in GitHub (EC2/On-premises deployments only).</p> </li> <li> <p>String: A YAML-formatted or JSON-formatted string (AWS Lambda deployments only).</p> </li> </ul></p>
     #[serde(rename = "revisionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub revision_type: Option<String>,
+    pub revision_type: Option<RevisionLocationType>,
     /// <p>Information about the location of a revision stored in Amazon S3. </p>
     #[serde(rename = "s3Location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1926,6 +5697,86 @@ pub struct RevisionLocation {
     pub string: Option<RawString>,
 }
 
+/// <p><p>The type of application revision:</p> <ul> <li> <p>S3: An application revision stored in Amazon S3.</p> </li> <li> <p>GitHub: An application revision stored in GitHub (EC2/On-premises deployments only).</p> </li> <li> <p>String: A YAML-formatted or JSON-formatted string (AWS Lambda deployments only).</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RevisionLocationType {
+    /// <p><code>S3</code></p>
+    S3,
+    /// <p><code>GitHub</code></p>
+    GitHub,
+    /// <p><code>String</code></p>
+    String,
+    /// <p><code>AppSpecContent</code></p>
+    AppSpecContent,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RevisionLocationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RevisionLocationType::S3 => "S3",
+            RevisionLocationType::GitHub => "GitHub",
+            RevisionLocationType::String => "String",
+            RevisionLocationType::AppSpecContent => "AppSpecContent",
+            RevisionLocationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RevisionLocationType {
+    fn from(value: &'a str) -> RevisionLocationType {
+        match value {
+            "S3" => RevisionLocationType::S3,
+            "GitHub" => RevisionLocationType::GitHub,
+            "String" => RevisionLocationType::String,
+            "AppSpecContent" => RevisionLocationType::AppSpecContent,
+            _ => RevisionLocationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RevisionLocationType {
+    fn from(value: String) -> RevisionLocationType {
+        RevisionLocationType::from(value.as_str())
+    }
+}
+
+impl From<RevisionLocationType> for String {
+    fn from(value: RevisionLocationType) -> String {
+        match value {
+            RevisionLocationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RevisionLocationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RevisionLocationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RevisionLocationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RevisionLocationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RevisionLocationType::from(value))
+    }
+}
+
 /// <p>Information about a deployment rollback.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1954,7 +5805,7 @@ pub struct S3Location {
     /// <p><p>The file type of the application revision. Must be one of the following:</p> <ul> <li> <p>tar: A tar archive file.</p> </li> <li> <p>tgz: A compressed tar archive file.</p> </li> <li> <p>zip: A zip archive file.</p> </li> </ul></p>
     #[serde(rename = "bundleType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bundle_type: Option<String>,
+    pub bundle_type: Option<BundleType>,
     /// <p>The ETag of the Amazon S3 object that represents the bundled artifacts for the application revision.</p> <p>If the ETag is not specified as an input parameter, ETag validation of the object is skipped.</p>
     #[serde(rename = "eTag")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1977,6 +5828,109 @@ pub struct SkipWaitTimeForInstanceTerminationInput {
     pub deployment_id: Option<String>,
 }
 
+impl SkipWaitTimeForInstanceTerminationInput {
+    /// Returns a builder for `SkipWaitTimeForInstanceTerminationInput`, checking its required members when built.
+    pub fn builder() -> SkipWaitTimeForInstanceTerminationInputBuilder {
+        SkipWaitTimeForInstanceTerminationInputBuilder::default()
+    }
+}
+
+/// Builds a [`SkipWaitTimeForInstanceTerminationInput`](struct.SkipWaitTimeForInstanceTerminationInput.html).
+#[derive(Default, Debug)]
+pub struct SkipWaitTimeForInstanceTerminationInputBuilder {
+    deployment_id: Option<String>,
+}
+
+impl SkipWaitTimeForInstanceTerminationInputBuilder {
+    /// Sets the [`deployment_id`](struct.SkipWaitTimeForInstanceTerminationInput.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SkipWaitTimeForInstanceTerminationInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SkipWaitTimeForInstanceTerminationInput, ::rusoto_core::BuildError>
+    {
+        Ok(SkipWaitTimeForInstanceTerminationInput {
+            deployment_id: self.deployment_id,
+        })
+    }
+}
+
+/// <p> The order in which to sort the list results: </p> <ul> <li> <p>ascending: ascending order.</p> </li> <li> <p>descending: descending order.</p> </li> </ul> <p>If not specified, the results are sorted in ascending order.</p> <p>If set to null, the results are sorted in an arbitrary order.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// <p><code>ascending</code></p>
+    Ascending,
+    /// <p><code>descending</code></p>
+    Descending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SortOrder {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrder::Ascending => "ascending",
+            SortOrder::Descending => "descending",
+            SortOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SortOrder {
+    fn from(value: &'a str) -> SortOrder {
+        match value {
+            "ascending" => SortOrder::Ascending,
+            "descending" => SortOrder::Descending,
+            _ => SortOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrder {
+    fn from(value: String) -> SortOrder {
+        SortOrder::from(value.as_str())
+    }
+}
+
+impl From<SortOrder> for String {
+    fn from(value: SortOrder) -> String {
+        match value {
+            SortOrder::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SortOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SortOrder::from(value))
+    }
+}
+
 /// <p> Represents the input of a StopDeployment operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StopDeploymentInput {
@@ -1989,6 +5943,44 @@ pub struct StopDeploymentInput {
     pub deployment_id: String,
 }
 
+impl StopDeploymentInput {
+    /// Returns a builder for `StopDeploymentInput`, checking its required members when built.
+    pub fn builder() -> StopDeploymentInputBuilder {
+        StopDeploymentInputBuilder::default()
+    }
+}
+
+/// Builds a [`StopDeploymentInput`](struct.StopDeploymentInput.html).
+#[derive(Default, Debug)]
+pub struct StopDeploymentInputBuilder {
+    auto_rollback_enabled: Option<bool>,
+    deployment_id: Option<String>,
+}
+
+impl StopDeploymentInputBuilder {
+    /// Sets the [`auto_rollback_enabled`](struct.StopDeploymentInput.html#structfield.auto_rollback_enabled) member.
+    pub fn auto_rollback_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_rollback_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_id`](struct.StopDeploymentInput.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopDeploymentInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopDeploymentInput, ::rusoto_core::BuildError> {
+        Ok(StopDeploymentInput {
+            auto_rollback_enabled: self.auto_rollback_enabled,
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+        })
+    }
+}
+
 /// <p> Represents the output of a StopDeployment operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1996,13 +5988,85 @@ pub struct StopDeploymentOutput {
     /// <p><p>The status of the stop deployment operation:</p> <ul> <li> <p>Pending: The stop operation is pending.</p> </li> <li> <p>Succeeded: The stop operation was successful.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<StopStatus>,
     /// <p>An accompanying status message.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_message: Option<String>,
 }
 
+/// <p><p>The status of the stop deployment operation:</p> <ul> <li> <p>Pending: The stop operation is pending.</p> </li> <li> <p>Succeeded: The stop operation was successful.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StopStatus {
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StopStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StopStatus::Pending => "Pending",
+            StopStatus::Succeeded => "Succeeded",
+            StopStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StopStatus {
+    fn from(value: &'a str) -> StopStatus {
+        match value {
+            "Pending" => StopStatus::Pending,
+            "Succeeded" => StopStatus::Succeeded,
+            _ => StopStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StopStatus {
+    fn from(value: String) -> StopStatus {
+        StopStatus::from(value.as_str())
+    }
+}
+
+impl From<StopStatus> for String {
+    fn from(value: StopStatus) -> String {
+        match value {
+            StopStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StopStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StopStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StopStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StopStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StopStatus::from(value))
+    }
+}
+
 /// <p>Information about a tag.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -2026,13 +6090,89 @@ pub struct TagFilter {
     /// <p><p>The on-premises instance tag filter type:</p> <ul> <li> <p>KEY<em>ONLY: Key only.</p> </li> <li> <p>VALUE</em>ONLY: Value only.</p> </li> <li> <p>KEY<em>AND</em>VALUE: Key and value.</p> </li> </ul></p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TagFilterType>,
     /// <p>The on-premises instance tag filter value.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+/// <p><p>The on-premises instance tag filter type:</p> <ul> <li> <p>KEY<em>ONLY: Key only.</p> </li> <li> <p>VALUE</em>ONLY: Value only.</p> </li> <li> <p>KEY<em>AND</em>VALUE: Key and value.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TagFilterType {
+    /// <p><code>KEY_ONLY</code></p>
+    KeyOnly,
+    /// <p><code>VALUE_ONLY</code></p>
+    ValueOnly,
+    /// <p><code>KEY_AND_VALUE</code></p>
+    KeyAndValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TagFilterType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TagFilterType::KeyOnly => "KEY_ONLY",
+            TagFilterType::ValueOnly => "VALUE_ONLY",
+            TagFilterType::KeyAndValue => "KEY_AND_VALUE",
+            TagFilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TagFilterType {
+    fn from(value: &'a str) -> TagFilterType {
+        match value {
+            "KEY_ONLY" => TagFilterType::KeyOnly,
+            "VALUE_ONLY" => TagFilterType::ValueOnly,
+            "KEY_AND_VALUE" => TagFilterType::KeyAndValue,
+            _ => TagFilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TagFilterType {
+    fn from(value: String) -> TagFilterType {
+        TagFilterType::from(value.as_str())
+    }
+}
+
+impl From<TagFilterType> for String {
+    fn from(value: TagFilterType) -> String {
+        match value {
+            TagFilterType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TagFilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TagFilterType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TagFilterType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TagFilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TagFilterType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TagResourceInput {
     /// <p> The ARN of a resource, such as a CodeDeploy application or deployment group. </p>
@@ -2043,15 +6183,127 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceInput {
+    /// Returns a builder for `TagResourceInput`, checking its required members when built.
+    pub fn builder() -> TagResourceInputBuilder {
+        TagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceInput`](struct.TagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct TagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceInput, ::rusoto_core::BuildError> {
+        Ok(TagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceOutput {}
 
-/// <p>Information about a target group in Elastic Load Balancing to use in a deployment. Instances are registered as targets in a target group, and traffic is routed to the target group.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct TargetGroupInfo {
-    /// <p>For blue/green deployments, the name of the target group that instances in the original environment are deregistered from, and instances in the replacement environment are registered with. For in-place deployments, the name of the target group that instances are deregistered from, so they are not serving traffic during a deployment, and then re-registered with after the deployment is complete. </p>
-    #[serde(rename = "name")]
+/// <p><p> A key used to filter the returned targets. The two valid values are:</p> <ul> <li> <p> <code>TargetStatus</code> - A <code>TargetStatus</code> filter string can be <code>Failed</code>, <code>InProgress</code>, <code>Pending</code>, <code>Ready</code>, <code>Skipped</code>, <code>Succeeded</code>, or <code>Unknown</code>. </p> </li> <li> <p> <code>ServerInstanceLabel</code> - A <code>ServerInstanceLabel</code> filter string can be <code>Blue</code> or <code>Green</code>. </p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TargetFilterName {
+    /// <p><code>TargetStatus</code> - A <code>TargetStatus</code> filter string can be <code>Failed</code>, <code>InProgress</code>, <code>Pending</code>, <code>Ready</code>, <code>Skipped</code>, <code>Succeeded</code>, or <code>Unknown</code>.</p>
+    TargetStatus,
+    /// <p><code>ServerInstanceLabel</code> - A <code>ServerInstanceLabel</code> filter string can be <code>Blue</code> or <code>Green</code>.</p>
+    ServerInstanceLabel,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TargetFilterName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetFilterName::TargetStatus => "TargetStatus",
+            TargetFilterName::ServerInstanceLabel => "ServerInstanceLabel",
+            TargetFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TargetFilterName {
+    fn from(value: &'a str) -> TargetFilterName {
+        match value {
+            "TargetStatus" => TargetFilterName::TargetStatus,
+            "ServerInstanceLabel" => TargetFilterName::ServerInstanceLabel,
+            _ => TargetFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetFilterName {
+    fn from(value: String) -> TargetFilterName {
+        TargetFilterName::from(value.as_str())
+    }
+}
+
+impl From<TargetFilterName> for String {
+    fn from(value: TargetFilterName) -> String {
+        match value {
+            TargetFilterName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TargetFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TargetFilterName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetFilterName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TargetFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TargetFilterName::from(value))
+    }
+}
+
+/// <p>Information about a target group in Elastic Load Balancing to use in a deployment. Instances are registered as targets in a target group, and traffic is routed to the target group.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TargetGroupInfo {
+    /// <p>For blue/green deployments, the name of the target group that instances in the original environment are deregistered from, and instances in the replacement environment are registered with. For in-place deployments, the name of the target group that instances are deregistered from, so they are not serving traffic during a deployment, and then re-registered with after the deployment is complete. </p>
+    #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
@@ -2090,6 +6342,170 @@ pub struct TargetInstances {
     pub tag_filters: Option<Vec<EC2TagFilter>>,
 }
 
+/// <p> A label that identifies whether the ECS task set is an original target (<code>BLUE</code>) or a replacement target (<code>GREEN</code>). </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TargetLabel {
+    /// <p><code>Blue</code></p>
+    Blue,
+    /// <p><code>Green</code></p>
+    Green,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TargetLabel {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetLabel::Blue => "Blue",
+            TargetLabel::Green => "Green",
+            TargetLabel::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TargetLabel {
+    fn from(value: &'a str) -> TargetLabel {
+        match value {
+            "Blue" => TargetLabel::Blue,
+            "Green" => TargetLabel::Green,
+            _ => TargetLabel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetLabel {
+    fn from(value: String) -> TargetLabel {
+        TargetLabel::from(value.as_str())
+    }
+}
+
+impl From<TargetLabel> for String {
+    fn from(value: TargetLabel) -> String {
+        match value {
+            TargetLabel::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TargetLabel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TargetLabel {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetLabel {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TargetLabel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TargetLabel::from(value))
+    }
+}
+
+/// <p> The status an Amazon ECS deployment's target ECS application. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TargetStatus {
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Skipped</code></p>
+    Skipped,
+    /// <p><code>Unknown</code></p>
+    UnknownValue,
+    /// <p><code>Ready</code></p>
+    Ready,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TargetStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetStatus::Pending => "Pending",
+            TargetStatus::InProgress => "InProgress",
+            TargetStatus::Succeeded => "Succeeded",
+            TargetStatus::Failed => "Failed",
+            TargetStatus::Skipped => "Skipped",
+            TargetStatus::UnknownValue => "Unknown",
+            TargetStatus::Ready => "Ready",
+            TargetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TargetStatus {
+    fn from(value: &'a str) -> TargetStatus {
+        match value {
+            "Pending" => TargetStatus::Pending,
+            "InProgress" => TargetStatus::InProgress,
+            "Succeeded" => TargetStatus::Succeeded,
+            "Failed" => TargetStatus::Failed,
+            "Skipped" => TargetStatus::Skipped,
+            "Unknown" => TargetStatus::UnknownValue,
+            "Ready" => TargetStatus::Ready,
+            _ => TargetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetStatus {
+    fn from(value: String) -> TargetStatus {
+        TargetStatus::from(value.as_str())
+    }
+}
+
+impl From<TargetStatus> for String {
+    fn from(value: TargetStatus) -> String {
+        match value {
+            TargetStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TargetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TargetStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TargetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TargetStatus::from(value))
+    }
+}
+
 /// <p>A configuration that shifts traffic from one version of a Lambda function to another in two increments. The original and target Lambda function versions are specified in the deployment's AppSpec file.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TimeBasedCanary {
@@ -2121,12 +6537,14 @@ pub struct TimeBasedLinear {
 pub struct TimeRange {
     /// <p><p>The end time of the time range.</p> <note> <p>Specify null to leave the end time open-ended.</p> </note></p>
     #[serde(rename = "end")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end: Option<f64>,
+    pub end: Option<EpochTimestamp>,
     /// <p><p>The start time of the time range.</p> <note> <p>Specify null to leave the start time open-ended.</p> </note></p>
     #[serde(rename = "start")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start: Option<f64>,
+    pub start: Option<EpochTimestamp>,
 }
 
 /// <p> Information about a listener. The listener contains the path used to route traffic that is received from the load balancer to a target group. </p>
@@ -2152,7 +6570,83 @@ pub struct TrafficRoutingConfig {
     /// <p>The type of traffic shifting (<code>TimeBasedCanary</code> or <code>TimeBasedLinear</code>) used by a deployment configuration .</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TrafficRoutingType>,
+}
+
+/// <p>The type of traffic shifting (<code>TimeBasedCanary</code> or <code>TimeBasedLinear</code>) used by a deployment configuration .</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TrafficRoutingType {
+    /// <p><code>TimeBasedCanary</code></p>
+    TimeBasedCanary,
+    /// <p><code>TimeBasedLinear</code></p>
+    TimeBasedLinear,
+    /// <p><code>AllAtOnce</code></p>
+    AllAtOnce,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TrafficRoutingType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TrafficRoutingType::TimeBasedCanary => "TimeBasedCanary",
+            TrafficRoutingType::TimeBasedLinear => "TimeBasedLinear",
+            TrafficRoutingType::AllAtOnce => "AllAtOnce",
+            TrafficRoutingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TrafficRoutingType {
+    fn from(value: &'a str) -> TrafficRoutingType {
+        match value {
+            "TimeBasedCanary" => TrafficRoutingType::TimeBasedCanary,
+            "TimeBasedLinear" => TrafficRoutingType::TimeBasedLinear,
+            "AllAtOnce" => TrafficRoutingType::AllAtOnce,
+            _ => TrafficRoutingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TrafficRoutingType {
+    fn from(value: String) -> TrafficRoutingType {
+        TrafficRoutingType::from(value.as_str())
+    }
+}
+
+impl From<TrafficRoutingType> for String {
+    fn from(value: TrafficRoutingType) -> String {
+        match value {
+            TrafficRoutingType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TrafficRoutingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TrafficRoutingType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TrafficRoutingType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TrafficRoutingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TrafficRoutingType::from(value))
+    }
 }
 
 /// <p>Information about notification triggers for the deployment group.</p>
@@ -2161,7 +6655,7 @@ pub struct TriggerConfig {
     /// <p>The event type or types for which notifications are triggered.</p>
     #[serde(rename = "triggerEvents")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trigger_events: Option<Vec<String>>,
+    pub trigger_events: Option<Vec<TriggerEventType>>,
     /// <p>The name of the notification trigger.</p>
     #[serde(rename = "triggerName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2172,6 +6666,110 @@ pub struct TriggerConfig {
     pub trigger_target_arn: Option<String>,
 }
 
+/// <p>The event type or types for which notifications are triggered.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TriggerEventType {
+    /// <p><code>DeploymentStart</code></p>
+    DeploymentStart,
+    /// <p><code>DeploymentSuccess</code></p>
+    DeploymentSuccess,
+    /// <p><code>DeploymentFailure</code></p>
+    DeploymentFailure,
+    /// <p><code>DeploymentStop</code></p>
+    DeploymentStop,
+    /// <p><code>DeploymentRollback</code></p>
+    DeploymentRollback,
+    /// <p><code>DeploymentReady</code></p>
+    DeploymentReady,
+    /// <p><code>InstanceStart</code></p>
+    InstanceStart,
+    /// <p><code>InstanceSuccess</code></p>
+    InstanceSuccess,
+    /// <p><code>InstanceFailure</code></p>
+    InstanceFailure,
+    /// <p><code>InstanceReady</code></p>
+    InstanceReady,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TriggerEventType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TriggerEventType::DeploymentStart => "DeploymentStart",
+            TriggerEventType::DeploymentSuccess => "DeploymentSuccess",
+            TriggerEventType::DeploymentFailure => "DeploymentFailure",
+            TriggerEventType::DeploymentStop => "DeploymentStop",
+            TriggerEventType::DeploymentRollback => "DeploymentRollback",
+            TriggerEventType::DeploymentReady => "DeploymentReady",
+            TriggerEventType::InstanceStart => "InstanceStart",
+            TriggerEventType::InstanceSuccess => "InstanceSuccess",
+            TriggerEventType::InstanceFailure => "InstanceFailure",
+            TriggerEventType::InstanceReady => "InstanceReady",
+            TriggerEventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TriggerEventType {
+    fn from(value: &'a str) -> TriggerEventType {
+        match value {
+            "DeploymentStart" => TriggerEventType::DeploymentStart,
+            "DeploymentSuccess" => TriggerEventType::DeploymentSuccess,
+            "DeploymentFailure" => TriggerEventType::DeploymentFailure,
+            "DeploymentStop" => TriggerEventType::DeploymentStop,
+            "DeploymentRollback" => TriggerEventType::DeploymentRollback,
+            "DeploymentReady" => TriggerEventType::DeploymentReady,
+            "InstanceStart" => TriggerEventType::InstanceStart,
+            "InstanceSuccess" => TriggerEventType::InstanceSuccess,
+            "InstanceFailure" => TriggerEventType::InstanceFailure,
+            "InstanceReady" => TriggerEventType::InstanceReady,
+            _ => TriggerEventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TriggerEventType {
+    fn from(value: String) -> TriggerEventType {
+        TriggerEventType::from(value.as_str())
+    }
+}
+
+impl From<TriggerEventType> for String {
+    fn from(value: TriggerEventType) -> String {
+        match value {
+            TriggerEventType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TriggerEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TriggerEventType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TriggerEventType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TriggerEventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TriggerEventType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceInput {
     /// <p> The ARN that specifies from which resource to disassociate the tags with the keys in the <code>TagKeys</code> input paramter. </p>
@@ -2182,6 +6780,46 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceInput {
+    /// Returns a builder for `UntagResourceInput`, checking its required members when built.
+    pub fn builder() -> UntagResourceInputBuilder {
+        UntagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceInput`](struct.UntagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceInput, ::rusoto_core::BuildError> {
+        Ok(UntagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceOutput {}
@@ -2199,6 +6837,42 @@ pub struct UpdateApplicationInput {
     pub new_application_name: Option<String>,
 }
 
+impl UpdateApplicationInput {
+    /// Returns a builder for `UpdateApplicationInput`, checking its required members when built.
+    pub fn builder() -> UpdateApplicationInputBuilder {
+        UpdateApplicationInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateApplicationInput`](struct.UpdateApplicationInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateApplicationInputBuilder {
+    application_name: Option<String>,
+    new_application_name: Option<String>,
+}
+
+impl UpdateApplicationInputBuilder {
+    /// Sets the [`application_name`](struct.UpdateApplicationInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_application_name`](struct.UpdateApplicationInput.html#structfield.new_application_name) member.
+    pub fn new_application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_application_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateApplicationInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateApplicationInput, ::rusoto_core::BuildError> {
+        Ok(UpdateApplicationInput {
+            application_name: self.application_name,
+            new_application_name: self.new_application_name,
+        })
+    }
+}
+
 /// <p>Represents the input of an UpdateDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateDeploymentGroupInput {
@@ -2270,6 +6944,174 @@ pub struct UpdateDeploymentGroupInput {
     pub trigger_configurations: Option<Vec<TriggerConfig>>,
 }
 
+impl UpdateDeploymentGroupInput {
+    /// Returns a builder for `UpdateDeploymentGroupInput`, checking its required members when built.
+    pub fn builder() -> UpdateDeploymentGroupInputBuilder {
+        UpdateDeploymentGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDeploymentGroupInput`](struct.UpdateDeploymentGroupInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateDeploymentGroupInputBuilder {
+    alarm_configuration: Option<AlarmConfiguration>,
+    application_name: Option<String>,
+    auto_rollback_configuration: Option<AutoRollbackConfiguration>,
+    auto_scaling_groups: Option<Vec<String>>,
+    blue_green_deployment_configuration: Option<BlueGreenDeploymentConfiguration>,
+    current_deployment_group_name: Option<String>,
+    deployment_config_name: Option<String>,
+    deployment_style: Option<DeploymentStyle>,
+    ec_2_tag_filters: Option<Vec<EC2TagFilter>>,
+    ec_2_tag_set: Option<EC2TagSet>,
+    ecs_services: Option<Vec<ECSService>>,
+    load_balancer_info: Option<LoadBalancerInfo>,
+    new_deployment_group_name: Option<String>,
+    on_premises_instance_tag_filters: Option<Vec<TagFilter>>,
+    on_premises_tag_set: Option<OnPremisesTagSet>,
+    service_role_arn: Option<String>,
+    trigger_configurations: Option<Vec<TriggerConfig>>,
+}
+
+impl UpdateDeploymentGroupInputBuilder {
+    /// Sets the [`alarm_configuration`](struct.UpdateDeploymentGroupInput.html#structfield.alarm_configuration) member.
+    pub fn alarm_configuration<T: Into<AlarmConfiguration>>(mut self, value: T) -> Self {
+        self.alarm_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`application_name`](struct.UpdateDeploymentGroupInput.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_rollback_configuration`](struct.UpdateDeploymentGroupInput.html#structfield.auto_rollback_configuration) member.
+    pub fn auto_rollback_configuration<T: Into<AutoRollbackConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.auto_rollback_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_scaling_groups`](struct.UpdateDeploymentGroupInput.html#structfield.auto_scaling_groups) member.
+    pub fn auto_scaling_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.auto_scaling_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`blue_green_deployment_configuration`](struct.UpdateDeploymentGroupInput.html#structfield.blue_green_deployment_configuration) member.
+    pub fn blue_green_deployment_configuration<T: Into<BlueGreenDeploymentConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.blue_green_deployment_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_deployment_group_name`](struct.UpdateDeploymentGroupInput.html#structfield.current_deployment_group_name) member.
+    pub fn current_deployment_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.current_deployment_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_config_name`](struct.UpdateDeploymentGroupInput.html#structfield.deployment_config_name) member.
+    pub fn deployment_config_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_config_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_style`](struct.UpdateDeploymentGroupInput.html#structfield.deployment_style) member.
+    pub fn deployment_style<T: Into<DeploymentStyle>>(mut self, value: T) -> Self {
+        self.deployment_style = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec_2_tag_filters`](struct.UpdateDeploymentGroupInput.html#structfield.ec_2_tag_filters) member.
+    pub fn ec_2_tag_filters<T: Into<Vec<EC2TagFilter>>>(mut self, value: T) -> Self {
+        self.ec_2_tag_filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec_2_tag_set`](struct.UpdateDeploymentGroupInput.html#structfield.ec_2_tag_set) member.
+    pub fn ec_2_tag_set<T: Into<EC2TagSet>>(mut self, value: T) -> Self {
+        self.ec_2_tag_set = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ecs_services`](struct.UpdateDeploymentGroupInput.html#structfield.ecs_services) member.
+    pub fn ecs_services<T: Into<Vec<ECSService>>>(mut self, value: T) -> Self {
+        self.ecs_services = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_info`](struct.UpdateDeploymentGroupInput.html#structfield.load_balancer_info) member.
+    pub fn load_balancer_info<T: Into<LoadBalancerInfo>>(mut self, value: T) -> Self {
+        self.load_balancer_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_deployment_group_name`](struct.UpdateDeploymentGroupInput.html#structfield.new_deployment_group_name) member.
+    pub fn new_deployment_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_deployment_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`on_premises_instance_tag_filters`](struct.UpdateDeploymentGroupInput.html#structfield.on_premises_instance_tag_filters) member.
+    pub fn on_premises_instance_tag_filters<T: Into<Vec<TagFilter>>>(mut self, value: T) -> Self {
+        self.on_premises_instance_tag_filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`on_premises_tag_set`](struct.UpdateDeploymentGroupInput.html#structfield.on_premises_tag_set) member.
+    pub fn on_premises_tag_set<T: Into<OnPremisesTagSet>>(mut self, value: T) -> Self {
+        self.on_premises_tag_set = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role_arn`](struct.UpdateDeploymentGroupInput.html#structfield.service_role_arn) member.
+    pub fn service_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trigger_configurations`](struct.UpdateDeploymentGroupInput.html#structfield.trigger_configurations) member.
+    pub fn trigger_configurations<T: Into<Vec<TriggerConfig>>>(mut self, value: T) -> Self {
+        self.trigger_configurations = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDeploymentGroupInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDeploymentGroupInput, ::rusoto_core::BuildError> {
+        Ok(UpdateDeploymentGroupInput {
+            alarm_configuration: self.alarm_configuration,
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            auto_rollback_configuration: self.auto_rollback_configuration,
+            auto_scaling_groups: self.auto_scaling_groups,
+            blue_green_deployment_configuration: self.blue_green_deployment_configuration,
+            current_deployment_group_name: self.current_deployment_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("current_deployment_group_name"),
+            )?,
+            deployment_config_name: self.deployment_config_name,
+            deployment_style: self.deployment_style,
+            ec_2_tag_filters: self.ec_2_tag_filters,
+            ec_2_tag_set: self.ec_2_tag_set,
+            ecs_services: self.ecs_services,
+            load_balancer_info: self.load_balancer_info,
+            new_deployment_group_name: self.new_deployment_group_name,
+            on_premises_instance_tag_filters: self.on_premises_instance_tag_filters,
+            on_premises_tag_set: self.on_premises_tag_set,
+            service_role_arn: self.service_role_arn,
+            trigger_configurations: self.trigger_configurations,
+        })
+    }
+}
+
 /// <p>Represents the output of an UpdateDeploymentGroup operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -6329,6 +11171,294 @@ pub trait CodeDeploy {
         input: UpdateDeploymentGroupInput,
     ) -> RusotoFuture<UpdateDeploymentGroupOutput, UpdateDeploymentGroupError>;
 }
+impl<T: ?Sized + CodeDeploy> CodeDeploy for ::std::sync::Arc<T> {
+    fn add_tags_to_on_premises_instances(
+        &self,
+        input: AddTagsToOnPremisesInstancesInput,
+    ) -> RusotoFuture<(), AddTagsToOnPremisesInstancesError> {
+        (**self).add_tags_to_on_premises_instances(input)
+    }
+    fn batch_get_application_revisions(
+        &self,
+        input: BatchGetApplicationRevisionsInput,
+    ) -> RusotoFuture<BatchGetApplicationRevisionsOutput, BatchGetApplicationRevisionsError> {
+        (**self).batch_get_application_revisions(input)
+    }
+    fn batch_get_applications(
+        &self,
+        input: BatchGetApplicationsInput,
+    ) -> RusotoFuture<BatchGetApplicationsOutput, BatchGetApplicationsError> {
+        (**self).batch_get_applications(input)
+    }
+    fn batch_get_deployment_groups(
+        &self,
+        input: BatchGetDeploymentGroupsInput,
+    ) -> RusotoFuture<BatchGetDeploymentGroupsOutput, BatchGetDeploymentGroupsError> {
+        (**self).batch_get_deployment_groups(input)
+    }
+    fn batch_get_deployment_instances(
+        &self,
+        input: BatchGetDeploymentInstancesInput,
+    ) -> RusotoFuture<BatchGetDeploymentInstancesOutput, BatchGetDeploymentInstancesError> {
+        (**self).batch_get_deployment_instances(input)
+    }
+    fn batch_get_deployment_targets(
+        &self,
+        input: BatchGetDeploymentTargetsInput,
+    ) -> RusotoFuture<BatchGetDeploymentTargetsOutput, BatchGetDeploymentTargetsError> {
+        (**self).batch_get_deployment_targets(input)
+    }
+    fn batch_get_deployments(
+        &self,
+        input: BatchGetDeploymentsInput,
+    ) -> RusotoFuture<BatchGetDeploymentsOutput, BatchGetDeploymentsError> {
+        (**self).batch_get_deployments(input)
+    }
+    fn batch_get_on_premises_instances(
+        &self,
+        input: BatchGetOnPremisesInstancesInput,
+    ) -> RusotoFuture<BatchGetOnPremisesInstancesOutput, BatchGetOnPremisesInstancesError> {
+        (**self).batch_get_on_premises_instances(input)
+    }
+    fn continue_deployment(
+        &self,
+        input: ContinueDeploymentInput,
+    ) -> RusotoFuture<(), ContinueDeploymentError> {
+        (**self).continue_deployment(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationInput,
+    ) -> RusotoFuture<CreateApplicationOutput, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentInput,
+    ) -> RusotoFuture<CreateDeploymentOutput, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_deployment_config(
+        &self,
+        input: CreateDeploymentConfigInput,
+    ) -> RusotoFuture<CreateDeploymentConfigOutput, CreateDeploymentConfigError> {
+        (**self).create_deployment_config(input)
+    }
+    fn create_deployment_group(
+        &self,
+        input: CreateDeploymentGroupInput,
+    ) -> RusotoFuture<CreateDeploymentGroupOutput, CreateDeploymentGroupError> {
+        (**self).create_deployment_group(input)
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationInput,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn delete_deployment_config(
+        &self,
+        input: DeleteDeploymentConfigInput,
+    ) -> RusotoFuture<(), DeleteDeploymentConfigError> {
+        (**self).delete_deployment_config(input)
+    }
+    fn delete_deployment_group(
+        &self,
+        input: DeleteDeploymentGroupInput,
+    ) -> RusotoFuture<DeleteDeploymentGroupOutput, DeleteDeploymentGroupError> {
+        (**self).delete_deployment_group(input)
+    }
+    fn delete_git_hub_account_token(
+        &self,
+        input: DeleteGitHubAccountTokenInput,
+    ) -> RusotoFuture<DeleteGitHubAccountTokenOutput, DeleteGitHubAccountTokenError> {
+        (**self).delete_git_hub_account_token(input)
+    }
+    fn deregister_on_premises_instance(
+        &self,
+        input: DeregisterOnPremisesInstanceInput,
+    ) -> RusotoFuture<(), DeregisterOnPremisesInstanceError> {
+        (**self).deregister_on_premises_instance(input)
+    }
+    fn get_application(
+        &self,
+        input: GetApplicationInput,
+    ) -> RusotoFuture<GetApplicationOutput, GetApplicationError> {
+        (**self).get_application(input)
+    }
+    fn get_application_revision(
+        &self,
+        input: GetApplicationRevisionInput,
+    ) -> RusotoFuture<GetApplicationRevisionOutput, GetApplicationRevisionError> {
+        (**self).get_application_revision(input)
+    }
+    fn get_deployment(
+        &self,
+        input: GetDeploymentInput,
+    ) -> RusotoFuture<GetDeploymentOutput, GetDeploymentError> {
+        (**self).get_deployment(input)
+    }
+    fn get_deployment_config(
+        &self,
+        input: GetDeploymentConfigInput,
+    ) -> RusotoFuture<GetDeploymentConfigOutput, GetDeploymentConfigError> {
+        (**self).get_deployment_config(input)
+    }
+    fn get_deployment_group(
+        &self,
+        input: GetDeploymentGroupInput,
+    ) -> RusotoFuture<GetDeploymentGroupOutput, GetDeploymentGroupError> {
+        (**self).get_deployment_group(input)
+    }
+    fn get_deployment_instance(
+        &self,
+        input: GetDeploymentInstanceInput,
+    ) -> RusotoFuture<GetDeploymentInstanceOutput, GetDeploymentInstanceError> {
+        (**self).get_deployment_instance(input)
+    }
+    fn get_deployment_target(
+        &self,
+        input: GetDeploymentTargetInput,
+    ) -> RusotoFuture<GetDeploymentTargetOutput, GetDeploymentTargetError> {
+        (**self).get_deployment_target(input)
+    }
+    fn get_on_premises_instance(
+        &self,
+        input: GetOnPremisesInstanceInput,
+    ) -> RusotoFuture<GetOnPremisesInstanceOutput, GetOnPremisesInstanceError> {
+        (**self).get_on_premises_instance(input)
+    }
+    fn list_application_revisions(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> RusotoFuture<ListApplicationRevisionsOutput, ListApplicationRevisionsError> {
+        (**self).list_application_revisions(input)
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsInput,
+    ) -> RusotoFuture<ListApplicationsOutput, ListApplicationsError> {
+        (**self).list_applications(input)
+    }
+    fn list_deployment_configs(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> RusotoFuture<ListDeploymentConfigsOutput, ListDeploymentConfigsError> {
+        (**self).list_deployment_configs(input)
+    }
+    fn list_deployment_groups(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> RusotoFuture<ListDeploymentGroupsOutput, ListDeploymentGroupsError> {
+        (**self).list_deployment_groups(input)
+    }
+    fn list_deployment_instances(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> RusotoFuture<ListDeploymentInstancesOutput, ListDeploymentInstancesError> {
+        (**self).list_deployment_instances(input)
+    }
+    fn list_deployment_targets(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> RusotoFuture<ListDeploymentTargetsOutput, ListDeploymentTargetsError> {
+        (**self).list_deployment_targets(input)
+    }
+    fn list_deployments(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> RusotoFuture<ListDeploymentsOutput, ListDeploymentsError> {
+        (**self).list_deployments(input)
+    }
+    fn list_git_hub_account_token_names(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> RusotoFuture<ListGitHubAccountTokenNamesOutput, ListGitHubAccountTokenNamesError> {
+        (**self).list_git_hub_account_token_names(input)
+    }
+    fn list_on_premises_instances(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> RusotoFuture<ListOnPremisesInstancesOutput, ListOnPremisesInstancesError> {
+        (**self).list_on_premises_instances(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_lifecycle_event_hook_execution_status(
+        &self,
+        input: PutLifecycleEventHookExecutionStatusInput,
+    ) -> RusotoFuture<
+        PutLifecycleEventHookExecutionStatusOutput,
+        PutLifecycleEventHookExecutionStatusError,
+    > {
+        (**self).put_lifecycle_event_hook_execution_status(input)
+    }
+    fn register_application_revision(
+        &self,
+        input: RegisterApplicationRevisionInput,
+    ) -> RusotoFuture<(), RegisterApplicationRevisionError> {
+        (**self).register_application_revision(input)
+    }
+    fn register_on_premises_instance(
+        &self,
+        input: RegisterOnPremisesInstanceInput,
+    ) -> RusotoFuture<(), RegisterOnPremisesInstanceError> {
+        (**self).register_on_premises_instance(input)
+    }
+    fn remove_tags_from_on_premises_instances(
+        &self,
+        input: RemoveTagsFromOnPremisesInstancesInput,
+    ) -> RusotoFuture<(), RemoveTagsFromOnPremisesInstancesError> {
+        (**self).remove_tags_from_on_premises_instances(input)
+    }
+    fn skip_wait_time_for_instance_termination(
+        &self,
+        input: SkipWaitTimeForInstanceTerminationInput,
+    ) -> RusotoFuture<(), SkipWaitTimeForInstanceTerminationError> {
+        (**self).skip_wait_time_for_instance_termination(input)
+    }
+    fn stop_deployment(
+        &self,
+        input: StopDeploymentInput,
+    ) -> RusotoFuture<StopDeploymentOutput, StopDeploymentError> {
+        (**self).stop_deployment(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationInput,
+    ) -> RusotoFuture<(), UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+    fn update_deployment_group(
+        &self,
+        input: UpdateDeploymentGroupInput,
+    ) -> RusotoFuture<UpdateDeploymentGroupOutput, UpdateDeploymentGroupError> {
+        (**self).update_deployment_group(input)
+    }
+}
+/// The error codes CodeDeploy retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the CodeDeploy API.
 #[derive(Clone)]
 pub struct CodeDeployClient {
@@ -6344,6 +11474,27 @@ impl CodeDeployClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CODEDEPLOY`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CodeDeployClient {
+        Self::new(region::Region::from_env_for_service("CODEDEPLOY"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `codedeploy`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CodeDeployClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("codedeploy", "CODEDEPLOY"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6362,7 +11513,54 @@ impl CodeDeployClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodeDeployClient {
-        CodeDeployClient { client, region }
+        CodeDeployClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "codedeploy")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "codedeploy",
+            "codedeploy",
+            "CODEDEPLOY",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CodeDeploy` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CodeDeploy + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CodeDeployClient {
+    fn service_name(&self) -> &'static str {
+        "CodeDeploy"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "codedeploy"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CodeDeployClient {
+        Self::new_with_client(client, region)
     }
 }
 
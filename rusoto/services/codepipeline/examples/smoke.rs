@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS CodePipeline clients.
+//!
+//! Makes a single read-only `ListActionTypes` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_codepipeline::{CodePipeline, CodePipelineClient, ListActionTypesInput};
+
+fn main() {
+    let client = CodePipelineClient::from_env();
+    match client
+        .list_action_types(ListActionTypesInput::default())
+        .sync()
+    {
+        Ok(_) => println!("ListActionTypes succeeded"),
+        Err(error) => {
+            eprintln!("ListActionTypes failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Represents an AWS session credentials object. These credentials are temporary credentials that are issued by AWS Secure Token Service (STS). They can be used to access input and output artifacts in the Amazon S3 bucket used to store artifact for the pipeline in AWS CodePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -49,6 +50,46 @@ pub struct AcknowledgeJobInput {
     pub nonce: String,
 }
 
+impl AcknowledgeJobInput {
+    /// Returns a builder for `AcknowledgeJobInput`, checking its required members when built.
+    pub fn builder() -> AcknowledgeJobInputBuilder {
+        AcknowledgeJobInputBuilder::default()
+    }
+}
+
+/// Builds a [`AcknowledgeJobInput`](struct.AcknowledgeJobInput.html).
+#[derive(Default, Debug)]
+pub struct AcknowledgeJobInputBuilder {
+    job_id: Option<String>,
+    nonce: Option<String>,
+}
+
+impl AcknowledgeJobInputBuilder {
+    /// Sets the [`job_id`](struct.AcknowledgeJobInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`nonce`](struct.AcknowledgeJobInput.html#structfield.nonce) member.
+    pub fn nonce<T: Into<String>>(mut self, value: T) -> Self {
+        self.nonce = Some(value.into());
+        self
+    }
+
+    /// Builds the `AcknowledgeJobInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AcknowledgeJobInput, ::rusoto_core::BuildError> {
+        Ok(AcknowledgeJobInput {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            nonce: self
+                .nonce
+                .ok_or(::rusoto_core::BuildError::missing("nonce"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of an AcknowledgeJob action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -56,7 +97,7 @@ pub struct AcknowledgeJobOutput {
     /// <p>Whether the job worker has received the specified job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobStatus>,
 }
 
 /// <p>Represents the input of an AcknowledgeThirdPartyJob action.</p>
@@ -73,6 +114,58 @@ pub struct AcknowledgeThirdPartyJobInput {
     pub nonce: String,
 }
 
+impl AcknowledgeThirdPartyJobInput {
+    /// Returns a builder for `AcknowledgeThirdPartyJobInput`, checking its required members when built.
+    pub fn builder() -> AcknowledgeThirdPartyJobInputBuilder {
+        AcknowledgeThirdPartyJobInputBuilder::default()
+    }
+}
+
+/// Builds a [`AcknowledgeThirdPartyJobInput`](struct.AcknowledgeThirdPartyJobInput.html).
+#[derive(Default, Debug)]
+pub struct AcknowledgeThirdPartyJobInputBuilder {
+    client_token: Option<String>,
+    job_id: Option<String>,
+    nonce: Option<String>,
+}
+
+impl AcknowledgeThirdPartyJobInputBuilder {
+    /// Sets the [`client_token`](struct.AcknowledgeThirdPartyJobInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.AcknowledgeThirdPartyJobInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`nonce`](struct.AcknowledgeThirdPartyJobInput.html#structfield.nonce) member.
+    pub fn nonce<T: Into<String>>(mut self, value: T) -> Self {
+        self.nonce = Some(value.into());
+        self
+    }
+
+    /// Builds the `AcknowledgeThirdPartyJobInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AcknowledgeThirdPartyJobInput, ::rusoto_core::BuildError> {
+        Ok(AcknowledgeThirdPartyJobInput {
+            client_token: self
+                .client_token
+                .ok_or(::rusoto_core::BuildError::missing("client_token"))?,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            nonce: self
+                .nonce
+                .ok_or(::rusoto_core::BuildError::missing("nonce"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of an AcknowledgeThirdPartyJob action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -80,7 +173,95 @@ pub struct AcknowledgeThirdPartyJobOutput {
     /// <p>The status information for the third party job, if any.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobStatus>,
+}
+
+/// <p>A category defines what kind of action can be taken in the stage, and constrains the provider type for the action. Valid categories are limited to one of the values below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionCategory {
+    /// <p><code>Source</code></p>
+    Source,
+    /// <p><code>Build</code></p>
+    Build,
+    /// <p><code>Deploy</code></p>
+    Deploy,
+    /// <p><code>Test</code></p>
+    Test,
+    /// <p><code>Invoke</code></p>
+    Invoke,
+    /// <p><code>Approval</code></p>
+    Approval,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionCategory {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionCategory::Source => "Source",
+            ActionCategory::Build => "Build",
+            ActionCategory::Deploy => "Deploy",
+            ActionCategory::Test => "Test",
+            ActionCategory::Invoke => "Invoke",
+            ActionCategory::Approval => "Approval",
+            ActionCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionCategory {
+    fn from(value: &'a str) -> ActionCategory {
+        match value {
+            "Source" => ActionCategory::Source,
+            "Build" => ActionCategory::Build,
+            "Deploy" => ActionCategory::Deploy,
+            "Test" => ActionCategory::Test,
+            "Invoke" => ActionCategory::Invoke,
+            "Approval" => ActionCategory::Approval,
+            _ => ActionCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionCategory {
+    fn from(value: String) -> ActionCategory {
+        ActionCategory::from(value.as_str())
+    }
+}
+
+impl From<ActionCategory> for String {
+    fn from(value: ActionCategory) -> String {
+        match value {
+            ActionCategory::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ActionCategory {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionCategory {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ActionCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ActionCategory::from(value))
+    }
 }
 
 /// <p>Represents information about an action configuration.</p>
@@ -119,7 +300,85 @@ pub struct ActionConfigurationProperty {
     /// <p>The type of the configuration property.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ActionConfigurationPropertyType>,
+}
+
+/// <p>The type of the configuration property.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionConfigurationPropertyType {
+    /// <p><code>String</code></p>
+    String,
+    /// <p><code>Number</code></p>
+    Number,
+    /// <p><code>Boolean</code></p>
+    Boolean,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionConfigurationPropertyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionConfigurationPropertyType::String => "String",
+            ActionConfigurationPropertyType::Number => "Number",
+            ActionConfigurationPropertyType::Boolean => "Boolean",
+            ActionConfigurationPropertyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionConfigurationPropertyType {
+    fn from(value: &'a str) -> ActionConfigurationPropertyType {
+        match value {
+            "String" => ActionConfigurationPropertyType::String,
+            "Number" => ActionConfigurationPropertyType::Number,
+            "Boolean" => ActionConfigurationPropertyType::Boolean,
+            _ => ActionConfigurationPropertyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionConfigurationPropertyType {
+    fn from(value: String) -> ActionConfigurationPropertyType {
+        ActionConfigurationPropertyType::from(value.as_str())
+    }
+}
+
+impl From<ActionConfigurationPropertyType> for String {
+    fn from(value: ActionConfigurationPropertyType) -> String {
+        match value {
+            ActionConfigurationPropertyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionConfigurationPropertyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ActionConfigurationPropertyType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionConfigurationPropertyType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ActionConfigurationPropertyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ActionConfigurationPropertyType::from(value))
+    }
 }
 
 /// <p>Represents the context of an action within the stage of a pipeline to a job worker.</p>
@@ -137,7 +396,7 @@ pub struct ActionContext {
 }
 
 /// <p>Represents information about an action declaration.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActionDeclaration {
     /// <p>Specifies the action type and the provider of the action.</p>
     #[serde(rename = "actionTypeId")]
@@ -189,8 +448,9 @@ pub struct ActionExecution {
     pub external_execution_url: Option<String>,
     /// <p>The last status change of the action.</p>
     #[serde(rename = "lastStatusChange")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status_change: Option<f64>,
+    pub last_status_change: Option<EpochTimestamp>,
     /// <p>The ARN of the user who last changed the pipeline.</p>
     #[serde(rename = "lastUpdatedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -202,7 +462,7 @@ pub struct ActionExecution {
     /// <p>The status of the action, or for a completed action, the last status of the action.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ActionExecutionStatus>,
     /// <p>A summary of the run of the action.</p>
     #[serde(rename = "summary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -231,8 +491,9 @@ pub struct ActionExecutionDetail {
     pub input: Option<ActionExecutionInput>,
     /// <p>The last update time of the action execution.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>Output details for the action execution, such as the action execution result.</p>
     #[serde(rename = "output")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -251,12 +512,13 @@ pub struct ActionExecutionDetail {
     pub stage_name: Option<String>,
     /// <p>The start time of the action execution.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p> The status of the action execution. Status categories are <code>InProgress</code>, <code>Succeeded</code>, and <code>Failed</code>.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ActionExecutionStatus>,
 }
 
 /// <p>Filter values for the action execution.</p>
@@ -325,12 +587,169 @@ pub struct ActionExecutionResult {
     pub external_execution_url: Option<String>,
 }
 
+/// <p>The status of the action, or for a completed action, the last status of the action.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionExecutionStatus {
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>Abandoned</code></p>
+    Abandoned,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionExecutionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionExecutionStatus::InProgress => "InProgress",
+            ActionExecutionStatus::Abandoned => "Abandoned",
+            ActionExecutionStatus::Succeeded => "Succeeded",
+            ActionExecutionStatus::Failed => "Failed",
+            ActionExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionExecutionStatus {
+    fn from(value: &'a str) -> ActionExecutionStatus {
+        match value {
+            "InProgress" => ActionExecutionStatus::InProgress,
+            "Abandoned" => ActionExecutionStatus::Abandoned,
+            "Succeeded" => ActionExecutionStatus::Succeeded,
+            "Failed" => ActionExecutionStatus::Failed,
+            _ => ActionExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionExecutionStatus {
+    fn from(value: String) -> ActionExecutionStatus {
+        ActionExecutionStatus::from(value.as_str())
+    }
+}
+
+impl From<ActionExecutionStatus> for String {
+    fn from(value: ActionExecutionStatus) -> String {
+        match value {
+            ActionExecutionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ActionExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ActionExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ActionExecutionStatus::from(value))
+    }
+}
+
+/// <p>The creator of the action being called.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionOwner {
+    /// <p><code>AWS</code></p>
+    Aws,
+    /// <p><code>ThirdParty</code></p>
+    ThirdParty,
+    /// <p><code>Custom</code></p>
+    Custom,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionOwner {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionOwner::Aws => "AWS",
+            ActionOwner::ThirdParty => "ThirdParty",
+            ActionOwner::Custom => "Custom",
+            ActionOwner::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionOwner {
+    fn from(value: &'a str) -> ActionOwner {
+        match value {
+            "AWS" => ActionOwner::Aws,
+            "ThirdParty" => ActionOwner::ThirdParty,
+            "Custom" => ActionOwner::Custom,
+            _ => ActionOwner::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionOwner {
+    fn from(value: String) -> ActionOwner {
+        ActionOwner::from(value.as_str())
+    }
+}
+
+impl From<ActionOwner> for String {
+    fn from(value: ActionOwner) -> String {
+        match value {
+            ActionOwner::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionOwner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ActionOwner {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionOwner {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ActionOwner, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ActionOwner::from(value))
+    }
+}
+
 /// <p>Represents information about the version (or revision) of an action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActionRevision {
     /// <p>The date and time when the most recent version of the action was created, in timestamp format.</p>
     #[serde(rename = "created")]
-    pub created: Option<f64>,
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    pub created: Option<EpochTimestamp>,
     /// <p>The unique identifier of the change that set the state to this revision, for example a deployment ID or timestamp.</p>
     #[serde(rename = "revisionChangeId")]
     pub revision_change_id: Option<String>,
@@ -366,7 +785,7 @@ pub struct ActionState {
 }
 
 /// <p>Returns information about the details of an action type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ActionType {
     /// <p>The configuration properties for the action type.</p>
@@ -389,14 +808,14 @@ pub struct ActionType {
 }
 
 /// <p>Represents information about an action type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActionTypeId {
     /// <p>A category defines what kind of action can be taken in the stage, and constrains the provider type for the action. Valid categories are limited to one of the values below.</p>
     #[serde(rename = "category")]
-    pub category: String,
+    pub category: ActionCategory,
     /// <p>The creator of the action being called.</p>
     #[serde(rename = "owner")]
-    pub owner: String,
+    pub owner: ActionOwner,
     /// <p>The provider of the service being called by the action. Valid providers are determined by the action category. For example, an action in the Deploy category type might have a provider of AWS CodeDeploy, which would be specified as CodeDeploy. To reference a list of action providers by action type, see <a href="https://docs.aws.amazon.com/codepipeline/latest/userguide/reference-pipeline-structure.html#actions-valid-providers">Valid Action Types and Providers in CodePipeline</a>.</p>
     #[serde(rename = "provider")]
     pub provider: String,
@@ -427,16 +846,88 @@ pub struct ActionTypeSettings {
 }
 
 /// <p>Represents information about the result of an approval request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ApprovalResult {
     /// <p>The response submitted by a reviewer assigned to an approval action request.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: ApprovalStatus,
     /// <p>The summary of the current status of the approval request.</p>
     #[serde(rename = "summary")]
     pub summary: String,
 }
 
+/// <p>The response submitted by a reviewer assigned to an approval action request.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApprovalStatus {
+    /// <p><code>Approved</code></p>
+    Approved,
+    /// <p><code>Rejected</code></p>
+    Rejected,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ApprovalStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApprovalStatus::Approved => "Approved",
+            ApprovalStatus::Rejected => "Rejected",
+            ApprovalStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ApprovalStatus {
+    fn from(value: &'a str) -> ApprovalStatus {
+        match value {
+            "Approved" => ApprovalStatus::Approved,
+            "Rejected" => ApprovalStatus::Rejected,
+            _ => ApprovalStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApprovalStatus {
+    fn from(value: String) -> ApprovalStatus {
+        ApprovalStatus::from(value.as_str())
+    }
+}
+
+impl From<ApprovalStatus> for String {
+    fn from(value: ApprovalStatus) -> String {
+        match value {
+            ApprovalStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ApprovalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ApprovalStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApprovalStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ApprovalStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ApprovalStatus::from(value))
+    }
+}
+
 /// <p>Represents information about an artifact that will be worked upon by actions in the pipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -491,7 +982,75 @@ pub struct ArtifactLocation {
     /// <p>The type of artifact in the location.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ArtifactLocationType>,
+}
+
+/// <p>The type of artifact in the location.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ArtifactLocationType {
+    /// <p><code>S3</code></p>
+    S3,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ArtifactLocationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactLocationType::S3 => "S3",
+            ArtifactLocationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactLocationType {
+    fn from(value: &'a str) -> ArtifactLocationType {
+        match value {
+            "S3" => ArtifactLocationType::S3,
+            _ => ArtifactLocationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactLocationType {
+    fn from(value: String) -> ArtifactLocationType {
+        ArtifactLocationType::from(value.as_str())
+    }
+}
+
+impl From<ArtifactLocationType> for String {
+    fn from(value: ArtifactLocationType) -> String {
+        match value {
+            ArtifactLocationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ArtifactLocationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ArtifactLocationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactLocationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ArtifactLocationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ArtifactLocationType::from(value))
+    }
 }
 
 /// <p>Represents revision details of an artifact. </p>
@@ -500,8 +1059,9 @@ pub struct ArtifactLocation {
 pub struct ArtifactRevision {
     /// <p>The date and time when the most recent revision of the artifact was created, in timestamp format.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>The name of an artifact. This name might be system-generated, such as "MyApp", or might be defined by the user when an action is created.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -525,7 +1085,7 @@ pub struct ArtifactRevision {
 }
 
 /// <p><p>The Amazon S3 bucket where artifacts are stored for the pipeline.</p> <note> <p>You must include either <code>artifactStore</code> or <code>artifactStores</code> in your pipeline, but you cannot use both. If you create a cross-region action in your pipeline, you must use <code>artifactStores</code>.</p> </note></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ArtifactStore {
     /// <p>The encryption key used to encrypt the data in the artifact store, such as an AWS Key Management Service (AWS KMS) key. If this is undefined, the default key for Amazon S3 is used.</p>
     #[serde(rename = "encryptionKey")]
@@ -536,26 +1096,162 @@ pub struct ArtifactStore {
     pub location: String,
     /// <p>The type of the artifact store, such as S3.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ArtifactStoreType,
+}
+
+/// <p>The type of the artifact store, such as S3.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ArtifactStoreType {
+    /// <p><code>S3</code></p>
+    S3,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ArtifactStoreType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactStoreType::S3 => "S3",
+            ArtifactStoreType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactStoreType {
+    fn from(value: &'a str) -> ArtifactStoreType {
+        match value {
+            "S3" => ArtifactStoreType::S3,
+            _ => ArtifactStoreType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactStoreType {
+    fn from(value: String) -> ArtifactStoreType {
+        ArtifactStoreType::from(value.as_str())
+    }
+}
+
+impl From<ArtifactStoreType> for String {
+    fn from(value: ArtifactStoreType) -> String {
+        match value {
+            ArtifactStoreType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ArtifactStoreType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ArtifactStoreType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactStoreType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ArtifactStoreType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ArtifactStoreType::from(value))
+    }
 }
 
 /// <p>Reserved for future use.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlockerDeclaration {
     /// <p>Reserved for future use.</p>
     #[serde(rename = "name")]
     pub name: String,
     /// <p>Reserved for future use.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: BlockerType,
 }
 
-/// <p>Represents the input of a CreateCustomActionType operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct CreateCustomActionTypeInput {
-    /// <p><p>The category of the custom action, such as a build action or a test action.</p> <note> <p>Although <code>Source</code> and <code>Approval</code> are listed as valid values, they are not currently functional. These values are reserved for future use.</p> </note></p>
+/// <p>Reserved for future use.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BlockerType {
+    /// <p><code>Schedule</code></p>
+    Schedule,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BlockerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BlockerType::Schedule => "Schedule",
+            BlockerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BlockerType {
+    fn from(value: &'a str) -> BlockerType {
+        match value {
+            "Schedule" => BlockerType::Schedule,
+            _ => BlockerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BlockerType {
+    fn from(value: String) -> BlockerType {
+        BlockerType::from(value.as_str())
+    }
+}
+
+impl From<BlockerType> for String {
+    fn from(value: BlockerType) -> String {
+        match value {
+            BlockerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BlockerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BlockerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BlockerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BlockerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BlockerType::from(value))
+    }
+}
+
+/// <p>Represents the input of a CreateCustomActionType operation.</p>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateCustomActionTypeInput {
+    /// <p><p>The category of the custom action, such as a build action or a test action.</p> <note> <p>Although <code>Source</code> and <code>Approval</code> are listed as valid values, they are not currently functional. These values are reserved for future use.</p> </note></p>
     #[serde(rename = "category")]
-    pub category: String,
+    pub category: ActionCategory,
     /// <p><p>The configuration properties for the custom action.</p> <note> <p>You can refer to a name in the configuration properties of the custom action within the URL templates by following the format of {Config:name}, as long as the configuration property is both required and not secret. For more information, see <a href="https://docs.aws.amazon.com/codepipeline/latest/userguide/how-to-create-custom-action.html">Create a Custom Action for a Pipeline</a>.</p> </note></p>
     #[serde(rename = "configurationProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -582,8 +1278,107 @@ pub struct CreateCustomActionTypeInput {
     pub version: String,
 }
 
+impl CreateCustomActionTypeInput {
+    /// Returns a builder for `CreateCustomActionTypeInput`, checking its required members when built.
+    pub fn builder() -> CreateCustomActionTypeInputBuilder {
+        CreateCustomActionTypeInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCustomActionTypeInput`](struct.CreateCustomActionTypeInput.html).
+#[derive(Default, Debug)]
+pub struct CreateCustomActionTypeInputBuilder {
+    category: Option<ActionCategory>,
+    configuration_properties: Option<Vec<ActionConfigurationProperty>>,
+    input_artifact_details: Option<ArtifactDetails>,
+    output_artifact_details: Option<ArtifactDetails>,
+    provider: Option<String>,
+    settings: Option<ActionTypeSettings>,
+    tags: Option<Vec<Tag>>,
+    version: Option<String>,
+}
+
+impl CreateCustomActionTypeInputBuilder {
+    /// Sets the [`category`](struct.CreateCustomActionTypeInput.html#structfield.category) member.
+    pub fn category<T: Into<ActionCategory>>(mut self, value: T) -> Self {
+        self.category = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration_properties`](struct.CreateCustomActionTypeInput.html#structfield.configuration_properties) member.
+    pub fn configuration_properties<T: Into<Vec<ActionConfigurationProperty>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.configuration_properties = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_artifact_details`](struct.CreateCustomActionTypeInput.html#structfield.input_artifact_details) member.
+    pub fn input_artifact_details<T: Into<ArtifactDetails>>(mut self, value: T) -> Self {
+        self.input_artifact_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_artifact_details`](struct.CreateCustomActionTypeInput.html#structfield.output_artifact_details) member.
+    pub fn output_artifact_details<T: Into<ArtifactDetails>>(mut self, value: T) -> Self {
+        self.output_artifact_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider`](struct.CreateCustomActionTypeInput.html#structfield.provider) member.
+    pub fn provider<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider = Some(value.into());
+        self
+    }
+
+    /// Sets the [`settings`](struct.CreateCustomActionTypeInput.html#structfield.settings) member.
+    pub fn settings<T: Into<ActionTypeSettings>>(mut self, value: T) -> Self {
+        self.settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateCustomActionTypeInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.CreateCustomActionTypeInput.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCustomActionTypeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCustomActionTypeInput, ::rusoto_core::BuildError> {
+        Ok(CreateCustomActionTypeInput {
+            category: self
+                .category
+                .ok_or(::rusoto_core::BuildError::missing("category"))?,
+            configuration_properties: self.configuration_properties,
+            input_artifact_details: self
+                .input_artifact_details
+                .ok_or(::rusoto_core::BuildError::missing("input_artifact_details"))?,
+            output_artifact_details: self.output_artifact_details.ok_or(
+                ::rusoto_core::BuildError::missing("output_artifact_details"),
+            )?,
+            provider: self
+                .provider
+                .ok_or(::rusoto_core::BuildError::missing("provider"))?,
+            settings: self.settings,
+            tags: self.tags,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>CreateCustomActionType</code> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCustomActionTypeOutput {
     /// <p>Returns information about the details of an action type.</p>
@@ -607,6 +1402,44 @@ pub struct CreatePipelineInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreatePipelineInput {
+    /// Returns a builder for `CreatePipelineInput`, checking its required members when built.
+    pub fn builder() -> CreatePipelineInputBuilder {
+        CreatePipelineInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePipelineInput`](struct.CreatePipelineInput.html).
+#[derive(Default, Debug)]
+pub struct CreatePipelineInputBuilder {
+    pipeline: Option<PipelineDeclaration>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreatePipelineInputBuilder {
+    /// Sets the [`pipeline`](struct.CreatePipelineInput.html#structfield.pipeline) member.
+    pub fn pipeline<T: Into<PipelineDeclaration>>(mut self, value: T) -> Self {
+        self.pipeline = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreatePipelineInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePipelineInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePipelineInput, ::rusoto_core::BuildError> {
+        Ok(CreatePipelineInput {
+            pipeline: self
+                .pipeline
+                .ok_or(::rusoto_core::BuildError::missing("pipeline"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>CreatePipeline</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -629,8 +1462,9 @@ pub struct CurrentRevision {
     pub change_identifier: String,
     /// <p>The date and time when the most recent revision of the artifact was created, in timestamp format.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>The revision ID of the current version of an artifact.</p>
     #[serde(rename = "revision")]
     pub revision: String,
@@ -641,11 +1475,11 @@ pub struct CurrentRevision {
 }
 
 /// <p>Represents the input of a <code>DeleteCustomActionType</code> operation. The custom action will be marked as deleted.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteCustomActionTypeInput {
     /// <p>The category of the custom action that you want to delete, such as source or deploy.</p>
     #[serde(rename = "category")]
-    pub category: String,
+    pub category: ActionCategory,
     /// <p>The provider of the service used in the custom action, such as AWS CodeDeploy.</p>
     #[serde(rename = "provider")]
     pub provider: String,
@@ -654,6 +1488,58 @@ pub struct DeleteCustomActionTypeInput {
     pub version: String,
 }
 
+impl DeleteCustomActionTypeInput {
+    /// Returns a builder for `DeleteCustomActionTypeInput`, checking its required members when built.
+    pub fn builder() -> DeleteCustomActionTypeInputBuilder {
+        DeleteCustomActionTypeInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCustomActionTypeInput`](struct.DeleteCustomActionTypeInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteCustomActionTypeInputBuilder {
+    category: Option<ActionCategory>,
+    provider: Option<String>,
+    version: Option<String>,
+}
+
+impl DeleteCustomActionTypeInputBuilder {
+    /// Sets the [`category`](struct.DeleteCustomActionTypeInput.html#structfield.category) member.
+    pub fn category<T: Into<ActionCategory>>(mut self, value: T) -> Self {
+        self.category = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider`](struct.DeleteCustomActionTypeInput.html#structfield.provider) member.
+    pub fn provider<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.DeleteCustomActionTypeInput.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCustomActionTypeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCustomActionTypeInput, ::rusoto_core::BuildError> {
+        Ok(DeleteCustomActionTypeInput {
+            category: self
+                .category
+                .ok_or(::rusoto_core::BuildError::missing("category"))?,
+            provider: self
+                .provider
+                .ok_or(::rusoto_core::BuildError::missing("provider"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 /// <p>Represents the input of a <code>DeletePipeline</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeletePipelineInput {
@@ -662,6 +1548,36 @@ pub struct DeletePipelineInput {
     pub name: String,
 }
 
+impl DeletePipelineInput {
+    /// Returns a builder for `DeletePipelineInput`, checking its required members when built.
+    pub fn builder() -> DeletePipelineInputBuilder {
+        DeletePipelineInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePipelineInput`](struct.DeletePipelineInput.html).
+#[derive(Default, Debug)]
+pub struct DeletePipelineInputBuilder {
+    name: Option<String>,
+}
+
+impl DeletePipelineInputBuilder {
+    /// Sets the [`name`](struct.DeletePipelineInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePipelineInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePipelineInput, ::rusoto_core::BuildError> {
+        Ok(DeletePipelineInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteWebhookInput {
     /// <p>The name of the webhook you want to delete.</p>
@@ -669,6 +1585,36 @@ pub struct DeleteWebhookInput {
     pub name: String,
 }
 
+impl DeleteWebhookInput {
+    /// Returns a builder for `DeleteWebhookInput`, checking its required members when built.
+    pub fn builder() -> DeleteWebhookInputBuilder {
+        DeleteWebhookInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteWebhookInput`](struct.DeleteWebhookInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteWebhookInputBuilder {
+    name: Option<String>,
+}
+
+impl DeleteWebhookInputBuilder {
+    /// Sets the [`name`](struct.DeleteWebhookInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteWebhookInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteWebhookInput, ::rusoto_core::BuildError> {
+        Ok(DeleteWebhookInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteWebhookOutput {}
@@ -681,12 +1627,43 @@ pub struct DeregisterWebhookWithThirdPartyInput {
     pub webhook_name: Option<String>,
 }
 
+impl DeregisterWebhookWithThirdPartyInput {
+    /// Returns a builder for `DeregisterWebhookWithThirdPartyInput`, checking its required members when built.
+    pub fn builder() -> DeregisterWebhookWithThirdPartyInputBuilder {
+        DeregisterWebhookWithThirdPartyInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterWebhookWithThirdPartyInput`](struct.DeregisterWebhookWithThirdPartyInput.html).
+#[derive(Default, Debug)]
+pub struct DeregisterWebhookWithThirdPartyInputBuilder {
+    webhook_name: Option<String>,
+}
+
+impl DeregisterWebhookWithThirdPartyInputBuilder {
+    /// Sets the [`webhook_name`](struct.DeregisterWebhookWithThirdPartyInput.html#structfield.webhook_name) member.
+    pub fn webhook_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.webhook_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterWebhookWithThirdPartyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterWebhookWithThirdPartyInput, ::rusoto_core::BuildError>
+    {
+        Ok(DeregisterWebhookWithThirdPartyInput {
+            webhook_name: self.webhook_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeregisterWebhookWithThirdPartyOutput {}
 
 /// <p>Represents the input of a <code>DisableStageTransition</code> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DisableStageTransitionInput {
     /// <p>The name of the pipeline in which you want to disable the flow of artifacts from one stage to another.</p>
     #[serde(rename = "pipelineName")]
@@ -699,11 +1676,73 @@ pub struct DisableStageTransitionInput {
     pub stage_name: String,
     /// <p>Specifies whether artifacts will be prevented from transitioning into the stage and being processed by the actions in that stage (inbound), or prevented from transitioning from the stage after they have been processed by the actions in that stage (outbound).</p>
     #[serde(rename = "transitionType")]
-    pub transition_type: String,
+    pub transition_type: StageTransitionType,
+}
+
+impl DisableStageTransitionInput {
+    /// Returns a builder for `DisableStageTransitionInput`, checking its required members when built.
+    pub fn builder() -> DisableStageTransitionInputBuilder {
+        DisableStageTransitionInputBuilder::default()
+    }
+}
+
+/// Builds a [`DisableStageTransitionInput`](struct.DisableStageTransitionInput.html).
+#[derive(Default, Debug)]
+pub struct DisableStageTransitionInputBuilder {
+    pipeline_name: Option<String>,
+    reason: Option<String>,
+    stage_name: Option<String>,
+    transition_type: Option<StageTransitionType>,
+}
+
+impl DisableStageTransitionInputBuilder {
+    /// Sets the [`pipeline_name`](struct.DisableStageTransitionInput.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reason`](struct.DisableStageTransitionInput.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.DisableStageTransitionInput.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transition_type`](struct.DisableStageTransitionInput.html#structfield.transition_type) member.
+    pub fn transition_type<T: Into<StageTransitionType>>(mut self, value: T) -> Self {
+        self.transition_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableStageTransitionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisableStageTransitionInput, ::rusoto_core::BuildError> {
+        Ok(DisableStageTransitionInput {
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+            reason: self
+                .reason
+                .ok_or(::rusoto_core::BuildError::missing("reason"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+            transition_type: self
+                .transition_type
+                .ok_or(::rusoto_core::BuildError::missing("transition_type"))?,
+        })
+    }
 }
 
 /// <p>Represents the input of an <code>EnableStageTransition</code> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct EnableStageTransitionInput {
     /// <p>The name of the pipeline in which you want to enable the flow of artifacts from one stage to another.</p>
     #[serde(rename = "pipelineName")]
@@ -713,18 +1752,138 @@ pub struct EnableStageTransitionInput {
     pub stage_name: String,
     /// <p>Specifies whether artifacts will be allowed to enter the stage and be processed by the actions in that stage (inbound) or whether already-processed artifacts will be allowed to transition to the next stage (outbound).</p>
     #[serde(rename = "transitionType")]
-    pub transition_type: String,
+    pub transition_type: StageTransitionType,
+}
+
+impl EnableStageTransitionInput {
+    /// Returns a builder for `EnableStageTransitionInput`, checking its required members when built.
+    pub fn builder() -> EnableStageTransitionInputBuilder {
+        EnableStageTransitionInputBuilder::default()
+    }
+}
+
+/// Builds a [`EnableStageTransitionInput`](struct.EnableStageTransitionInput.html).
+#[derive(Default, Debug)]
+pub struct EnableStageTransitionInputBuilder {
+    pipeline_name: Option<String>,
+    stage_name: Option<String>,
+    transition_type: Option<StageTransitionType>,
+}
+
+impl EnableStageTransitionInputBuilder {
+    /// Sets the [`pipeline_name`](struct.EnableStageTransitionInput.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.EnableStageTransitionInput.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transition_type`](struct.EnableStageTransitionInput.html#structfield.transition_type) member.
+    pub fn transition_type<T: Into<StageTransitionType>>(mut self, value: T) -> Self {
+        self.transition_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableStageTransitionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EnableStageTransitionInput, ::rusoto_core::BuildError> {
+        Ok(EnableStageTransitionInput {
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+            transition_type: self
+                .transition_type
+                .ok_or(::rusoto_core::BuildError::missing("transition_type"))?,
+        })
+    }
 }
 
 /// <p>Represents information about the key used to encrypt data in the artifact store, such as an AWS Key Management Service (AWS KMS) key.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptionKey {
     /// <p><p>The ID used to identify the key. For an AWS KMS key, you can use the key ID, the key ARN, or the alias ARN.</p> <note> <p>Aliases are recognized only in the account that created the customer master key (CMK). For cross-account actions, you can only use the key ID or key ARN to identify the key.</p> </note></p>
     #[serde(rename = "id")]
     pub id: String,
     /// <p>The type of encryption key, such as an AWS Key Management Service (AWS KMS) key. When creating or updating a pipeline, the value must be set to 'KMS'.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: EncryptionKeyType,
+}
+
+/// <p>The type of encryption key, such as an AWS Key Management Service (AWS KMS) key. When creating or updating a pipeline, the value must be set to 'KMS'.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EncryptionKeyType {
+    /// <p><code>KMS</code></p>
+    Kms,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EncryptionKeyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionKeyType::Kms => "KMS",
+            EncryptionKeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionKeyType {
+    fn from(value: &'a str) -> EncryptionKeyType {
+        match value {
+            "KMS" => EncryptionKeyType::Kms,
+            _ => EncryptionKeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionKeyType {
+    fn from(value: String) -> EncryptionKeyType {
+        EncryptionKeyType::from(value.as_str())
+    }
+}
+
+impl From<EncryptionKeyType> for String {
+    fn from(value: EncryptionKeyType) -> String {
+        match value {
+            EncryptionKeyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncryptionKeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EncryptionKeyType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionKeyType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EncryptionKeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EncryptionKeyType::from(value))
+    }
 }
 
 /// <p>Represents information about an error in AWS CodePipeline.</p>
@@ -769,11 +1928,11 @@ pub struct ExecutionTrigger {
     /// <p>The type of change-detection method, command, or user interaction that started a pipeline execution.</p>
     #[serde(rename = "triggerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trigger_type: Option<String>,
+    pub trigger_type: Option<TriggerType>,
 }
 
 /// <p>Represents information about failure details.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct FailureDetails {
     /// <p>The external ID of the run of the action that failed.</p>
     #[serde(rename = "externalExecutionId")]
@@ -784,7 +1943,95 @@ pub struct FailureDetails {
     pub message: String,
     /// <p>The type of the failure.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: FailureType,
+}
+
+/// <p>The type of the failure.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FailureType {
+    /// <p><code>JobFailed</code></p>
+    JobFailed,
+    /// <p><code>ConfigurationError</code></p>
+    ConfigurationError,
+    /// <p><code>PermissionError</code></p>
+    PermissionError,
+    /// <p><code>RevisionOutOfSync</code></p>
+    RevisionOutOfSync,
+    /// <p><code>RevisionUnavailable</code></p>
+    RevisionUnavailable,
+    /// <p><code>SystemUnavailable</code></p>
+    SystemUnavailable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FailureType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailureType::JobFailed => "JobFailed",
+            FailureType::ConfigurationError => "ConfigurationError",
+            FailureType::PermissionError => "PermissionError",
+            FailureType::RevisionOutOfSync => "RevisionOutOfSync",
+            FailureType::RevisionUnavailable => "RevisionUnavailable",
+            FailureType::SystemUnavailable => "SystemUnavailable",
+            FailureType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FailureType {
+    fn from(value: &'a str) -> FailureType {
+        match value {
+            "JobFailed" => FailureType::JobFailed,
+            "ConfigurationError" => FailureType::ConfigurationError,
+            "PermissionError" => FailureType::PermissionError,
+            "RevisionOutOfSync" => FailureType::RevisionOutOfSync,
+            "RevisionUnavailable" => FailureType::RevisionUnavailable,
+            "SystemUnavailable" => FailureType::SystemUnavailable,
+            _ => FailureType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailureType {
+    fn from(value: String) -> FailureType {
+        FailureType::from(value.as_str())
+    }
+}
+
+impl From<FailureType> for String {
+    fn from(value: FailureType) -> String {
+        match value {
+            FailureType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FailureType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FailureType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FailureType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FailureType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FailureType::from(value))
+    }
 }
 
 /// <p>Represents the input of a <code>GetJobDetails</code> action.</p>
@@ -795,6 +2042,36 @@ pub struct GetJobDetailsInput {
     pub job_id: String,
 }
 
+impl GetJobDetailsInput {
+    /// Returns a builder for `GetJobDetailsInput`, checking its required members when built.
+    pub fn builder() -> GetJobDetailsInputBuilder {
+        GetJobDetailsInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetJobDetailsInput`](struct.GetJobDetailsInput.html).
+#[derive(Default, Debug)]
+pub struct GetJobDetailsInputBuilder {
+    job_id: Option<String>,
+}
+
+impl GetJobDetailsInputBuilder {
+    /// Sets the [`job_id`](struct.GetJobDetailsInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetJobDetailsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetJobDetailsInput, ::rusoto_core::BuildError> {
+        Ok(GetJobDetailsInput {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>GetJobDetails</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -816,6 +2093,48 @@ pub struct GetPipelineExecutionInput {
     pub pipeline_name: String,
 }
 
+impl GetPipelineExecutionInput {
+    /// Returns a builder for `GetPipelineExecutionInput`, checking its required members when built.
+    pub fn builder() -> GetPipelineExecutionInputBuilder {
+        GetPipelineExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetPipelineExecutionInput`](struct.GetPipelineExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct GetPipelineExecutionInputBuilder {
+    pipeline_execution_id: Option<String>,
+    pipeline_name: Option<String>,
+}
+
+impl GetPipelineExecutionInputBuilder {
+    /// Sets the [`pipeline_execution_id`](struct.GetPipelineExecutionInput.html#structfield.pipeline_execution_id) member.
+    pub fn pipeline_execution_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_execution_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_name`](struct.GetPipelineExecutionInput.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPipelineExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetPipelineExecutionInput, ::rusoto_core::BuildError> {
+        Ok(GetPipelineExecutionInput {
+            pipeline_execution_id: self
+                .pipeline_execution_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_execution_id"))?,
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>GetPipelineExecution</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -838,6 +2157,44 @@ pub struct GetPipelineInput {
     pub version: Option<i64>,
 }
 
+impl GetPipelineInput {
+    /// Returns a builder for `GetPipelineInput`, checking its required members when built.
+    pub fn builder() -> GetPipelineInputBuilder {
+        GetPipelineInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetPipelineInput`](struct.GetPipelineInput.html).
+#[derive(Default, Debug)]
+pub struct GetPipelineInputBuilder {
+    name: Option<String>,
+    version: Option<i64>,
+}
+
+impl GetPipelineInputBuilder {
+    /// Sets the [`name`](struct.GetPipelineInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.GetPipelineInput.html#structfield.version) member.
+    pub fn version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPipelineInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetPipelineInput, ::rusoto_core::BuildError> {
+        Ok(GetPipelineInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            version: self.version,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>GetPipeline</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -860,14 +2217,45 @@ pub struct GetPipelineStateInput {
     pub name: String,
 }
 
+impl GetPipelineStateInput {
+    /// Returns a builder for `GetPipelineStateInput`, checking its required members when built.
+    pub fn builder() -> GetPipelineStateInputBuilder {
+        GetPipelineStateInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetPipelineStateInput`](struct.GetPipelineStateInput.html).
+#[derive(Default, Debug)]
+pub struct GetPipelineStateInputBuilder {
+    name: Option<String>,
+}
+
+impl GetPipelineStateInputBuilder {
+    /// Sets the [`name`](struct.GetPipelineStateInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPipelineStateInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetPipelineStateInput, ::rusoto_core::BuildError> {
+        Ok(GetPipelineStateInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>GetPipelineState</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPipelineStateOutput {
     /// <p>The date and time the pipeline was created, in timestamp format.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>The name of the pipeline for which you want to get the state.</p>
     #[serde(rename = "pipelineName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -882,8 +2270,9 @@ pub struct GetPipelineStateOutput {
     pub stage_states: Option<Vec<StageState>>,
     /// <p>The date and time the pipeline was last updated, in timestamp format.</p>
     #[serde(rename = "updated")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated: Option<f64>,
+    pub updated: Option<EpochTimestamp>,
 }
 
 /// <p>Represents the input of a <code>GetThirdPartyJobDetails</code> action.</p>
@@ -897,6 +2286,48 @@ pub struct GetThirdPartyJobDetailsInput {
     pub job_id: String,
 }
 
+impl GetThirdPartyJobDetailsInput {
+    /// Returns a builder for `GetThirdPartyJobDetailsInput`, checking its required members when built.
+    pub fn builder() -> GetThirdPartyJobDetailsInputBuilder {
+        GetThirdPartyJobDetailsInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetThirdPartyJobDetailsInput`](struct.GetThirdPartyJobDetailsInput.html).
+#[derive(Default, Debug)]
+pub struct GetThirdPartyJobDetailsInputBuilder {
+    client_token: Option<String>,
+    job_id: Option<String>,
+}
+
+impl GetThirdPartyJobDetailsInputBuilder {
+    /// Sets the [`client_token`](struct.GetThirdPartyJobDetailsInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.GetThirdPartyJobDetailsInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetThirdPartyJobDetailsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetThirdPartyJobDetailsInput, ::rusoto_core::BuildError> {
+        Ok(GetThirdPartyJobDetailsInput {
+            client_token: self
+                .client_token
+                .ok_or(::rusoto_core::BuildError::missing("client_token"))?,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>GetThirdPartyJobDetails</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -993,6 +2424,98 @@ pub struct JobDetails {
     pub id: Option<String>,
 }
 
+/// <p>Whether the job worker has received the specified job.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobStatus {
+    /// <p><code>Created</code></p>
+    Created,
+    /// <p><code>Queued</code></p>
+    Queued,
+    /// <p><code>Dispatched</code></p>
+    Dispatched,
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>TimedOut</code></p>
+    TimedOut,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::Created => "Created",
+            JobStatus::Queued => "Queued",
+            JobStatus::Dispatched => "Dispatched",
+            JobStatus::InProgress => "InProgress",
+            JobStatus::TimedOut => "TimedOut",
+            JobStatus::Succeeded => "Succeeded",
+            JobStatus::Failed => "Failed",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "Created" => JobStatus::Created,
+            "Queued" => JobStatus::Queued,
+            "Dispatched" => JobStatus::Dispatched,
+            "InProgress" => JobStatus::InProgress,
+            "TimedOut" => JobStatus::TimedOut,
+            "Succeeded" => JobStatus::Succeeded,
+            "Failed" => JobStatus::Failed,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        JobStatus::from(value.as_str())
+    }
+}
+
+impl From<JobStatus> for String {
+    fn from(value: JobStatus) -> String {
+        match value {
+            JobStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListActionExecutionsInput {
     /// <p>Input information used to filter action execution history.</p>
@@ -1012,6 +2535,62 @@ pub struct ListActionExecutionsInput {
     pub pipeline_name: String,
 }
 
+impl ListActionExecutionsInput {
+    /// Returns a builder for `ListActionExecutionsInput`, checking its required members when built.
+    pub fn builder() -> ListActionExecutionsInputBuilder {
+        ListActionExecutionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListActionExecutionsInput`](struct.ListActionExecutionsInput.html).
+#[derive(Default, Debug)]
+pub struct ListActionExecutionsInputBuilder {
+    filter: Option<ActionExecutionFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    pipeline_name: Option<String>,
+}
+
+impl ListActionExecutionsInputBuilder {
+    /// Sets the [`filter`](struct.ListActionExecutionsInput.html#structfield.filter) member.
+    pub fn filter<T: Into<ActionExecutionFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListActionExecutionsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListActionExecutionsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_name`](struct.ListActionExecutionsInput.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListActionExecutionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListActionExecutionsInput, ::rusoto_core::BuildError> {
+        Ok(ListActionExecutionsInput {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListActionExecutionsOutput {
@@ -1031,13 +2610,49 @@ pub struct ListActionTypesInput {
     /// <p>Filters the list of action types to those created by a specified entity.</p>
     #[serde(rename = "actionOwnerFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_owner_filter: Option<String>,
+    pub action_owner_filter: Option<ActionOwner>,
     /// <p>An identifier that was returned from the previous list action types call, which can be used to return the next set of action types in the list.</p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_token: Option<String>,
 }
 
+impl ListActionTypesInput {
+    /// Returns a builder for `ListActionTypesInput`, checking its required members when built.
+    pub fn builder() -> ListActionTypesInputBuilder {
+        ListActionTypesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListActionTypesInput`](struct.ListActionTypesInput.html).
+#[derive(Default, Debug)]
+pub struct ListActionTypesInputBuilder {
+    action_owner_filter: Option<ActionOwner>,
+    next_token: Option<String>,
+}
+
+impl ListActionTypesInputBuilder {
+    /// Sets the [`action_owner_filter`](struct.ListActionTypesInput.html#structfield.action_owner_filter) member.
+    pub fn action_owner_filter<T: Into<ActionOwner>>(mut self, value: T) -> Self {
+        self.action_owner_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListActionTypesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListActionTypesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListActionTypesInput, ::rusoto_core::BuildError> {
+        Ok(ListActionTypesInput {
+            action_owner_filter: self.action_owner_filter,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>ListActionTypes</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1067,6 +2682,54 @@ pub struct ListPipelineExecutionsInput {
     pub pipeline_name: String,
 }
 
+impl ListPipelineExecutionsInput {
+    /// Returns a builder for `ListPipelineExecutionsInput`, checking its required members when built.
+    pub fn builder() -> ListPipelineExecutionsInputBuilder {
+        ListPipelineExecutionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListPipelineExecutionsInput`](struct.ListPipelineExecutionsInput.html).
+#[derive(Default, Debug)]
+pub struct ListPipelineExecutionsInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    pipeline_name: Option<String>,
+}
+
+impl ListPipelineExecutionsInputBuilder {
+    /// Sets the [`max_results`](struct.ListPipelineExecutionsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPipelineExecutionsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_name`](struct.ListPipelineExecutionsInput.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPipelineExecutionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPipelineExecutionsInput, ::rusoto_core::BuildError> {
+        Ok(ListPipelineExecutionsInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>ListPipelineExecutions</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1090,6 +2753,34 @@ pub struct ListPipelinesInput {
     pub next_token: Option<String>,
 }
 
+impl ListPipelinesInput {
+    /// Returns a builder for `ListPipelinesInput`, checking its required members when built.
+    pub fn builder() -> ListPipelinesInputBuilder {
+        ListPipelinesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListPipelinesInput`](struct.ListPipelinesInput.html).
+#[derive(Default, Debug)]
+pub struct ListPipelinesInputBuilder {
+    next_token: Option<String>,
+}
+
+impl ListPipelinesInputBuilder {
+    /// Sets the [`next_token`](struct.ListPipelinesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPipelinesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPipelinesInput, ::rusoto_core::BuildError> {
+        Ok(ListPipelinesInput {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>ListPipelines</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1119,6 +2810,54 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceInput {
+    /// Returns a builder for `ListTagsForResourceInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceInputBuilder {
+        ListTagsForResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceInput`](struct.ListTagsForResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceInputBuilder {
+    /// Sets the [`max_results`](struct.ListTagsForResourceInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsForResourceInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceOutput {
@@ -1133,7 +2872,7 @@ pub struct ListTagsForResourceOutput {
 }
 
 /// <p>The detail returned for each webhook after listing webhooks, such as the webhook URL, the webhook name, and the webhook ARN.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListWebhookItem {
     /// <p>The Amazon Resource Name (ARN) of the webhook.</p>
@@ -1153,8 +2892,9 @@ pub struct ListWebhookItem {
     pub error_message: Option<String>,
     /// <p>The date and time a webhook was last successfully triggered, in timestamp format.</p>
     #[serde(rename = "lastTriggered")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_triggered: Option<f64>,
+    pub last_triggered: Option<EpochTimestamp>,
     /// <p>Specifies the tags applied to the webhook.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1176,6 +2916,42 @@ pub struct ListWebhooksInput {
     pub next_token: Option<String>,
 }
 
+impl ListWebhooksInput {
+    /// Returns a builder for `ListWebhooksInput`, checking its required members when built.
+    pub fn builder() -> ListWebhooksInputBuilder {
+        ListWebhooksInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListWebhooksInput`](struct.ListWebhooksInput.html).
+#[derive(Default, Debug)]
+pub struct ListWebhooksInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListWebhooksInputBuilder {
+    /// Sets the [`max_results`](struct.ListWebhooksInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListWebhooksInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListWebhooksInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListWebhooksInput, ::rusoto_core::BuildError> {
+        Ok(ListWebhooksInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListWebhooksOutput {
@@ -1272,7 +3048,99 @@ pub struct PipelineExecution {
     /// <p><p>The status of the pipeline execution.</p> <ul> <li> <p>InProgress: The pipeline execution is currently running.</p> </li> <li> <p>Succeeded: The pipeline execution was completed successfully. </p> </li> <li> <p>Superseded: While this pipeline execution was waiting for the next stage to be completed, a newer pipeline execution advanced and continued through the pipeline instead. </p> </li> <li> <p>Failed: The pipeline execution was not completed successfully.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PipelineExecutionStatus>,
+}
+
+/// <p><p>The status of the pipeline execution.</p> <ul> <li> <p>InProgress: The pipeline execution is currently running.</p> </li> <li> <p>Succeeded: The pipeline execution was completed successfully. </p> </li> <li> <p>Superseded: While this pipeline execution was waiting for the next stage to be completed, a newer pipeline execution advanced and continued through the pipeline instead. </p> </li> <li> <p>Failed: The pipeline execution was not completed successfully.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PipelineExecutionStatus {
+    /// <p><code>Cancelled</code></p>
+    Cancelled,
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>Stopped</code></p>
+    Stopped,
+    /// <p><code>Stopping</code></p>
+    Stopping,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// <p><code>Superseded</code></p>
+    Superseded,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PipelineExecutionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PipelineExecutionStatus::Cancelled => "Cancelled",
+            PipelineExecutionStatus::InProgress => "InProgress",
+            PipelineExecutionStatus::Stopped => "Stopped",
+            PipelineExecutionStatus::Stopping => "Stopping",
+            PipelineExecutionStatus::Succeeded => "Succeeded",
+            PipelineExecutionStatus::Superseded => "Superseded",
+            PipelineExecutionStatus::Failed => "Failed",
+            PipelineExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PipelineExecutionStatus {
+    fn from(value: &'a str) -> PipelineExecutionStatus {
+        match value {
+            "Cancelled" => PipelineExecutionStatus::Cancelled,
+            "InProgress" => PipelineExecutionStatus::InProgress,
+            "Stopped" => PipelineExecutionStatus::Stopped,
+            "Stopping" => PipelineExecutionStatus::Stopping,
+            "Succeeded" => PipelineExecutionStatus::Succeeded,
+            "Superseded" => PipelineExecutionStatus::Superseded,
+            "Failed" => PipelineExecutionStatus::Failed,
+            _ => PipelineExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PipelineExecutionStatus {
+    fn from(value: String) -> PipelineExecutionStatus {
+        PipelineExecutionStatus::from(value.as_str())
+    }
+}
+
+impl From<PipelineExecutionStatus> for String {
+    fn from(value: PipelineExecutionStatus) -> String {
+        match value {
+            PipelineExecutionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PipelineExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PipelineExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PipelineExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PipelineExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PipelineExecutionStatus::from(value))
+    }
 }
 
 /// <p>Summary information about a pipeline execution.</p>
@@ -1281,8 +3149,9 @@ pub struct PipelineExecution {
 pub struct PipelineExecutionSummary {
     /// <p>The date and time of the last change to the pipeline execution, in timestamp format.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The ID of the pipeline execution.</p>
     #[serde(rename = "pipelineExecutionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1293,12 +3162,13 @@ pub struct PipelineExecutionSummary {
     pub source_revisions: Option<Vec<SourceRevision>>,
     /// <p>The date and time when the pipeline execution began, in timestamp format.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p><p>The status of the pipeline execution.</p> <ul> <li> <p>InProgress: The pipeline execution is currently running.</p> </li> <li> <p>Succeeded: The pipeline execution was completed successfully. </p> </li> <li> <p>Superseded: While this pipeline execution was waiting for the next stage to be completed, a newer pipeline execution advanced and continued through the pipeline instead. </p> </li> <li> <p>Failed: The pipeline execution was not completed successfully.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PipelineExecutionStatus>,
     /// <p>The interaction or event that started a pipeline execution, such as automated change detection or a <code>StartPipelineExecution</code> API call.</p>
     #[serde(rename = "trigger")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1311,16 +3181,18 @@ pub struct PipelineExecutionSummary {
 pub struct PipelineMetadata {
     /// <p>The date and time the pipeline was created, in timestamp format.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>The Amazon Resource Name (ARN) of the pipeline.</p>
     #[serde(rename = "pipelineArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pipeline_arn: Option<String>,
     /// <p>The date and time the pipeline was last updated, in timestamp format.</p>
     #[serde(rename = "updated")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated: Option<f64>,
+    pub updated: Option<EpochTimestamp>,
 }
 
 /// <p>Returns a summary of a pipeline.</p>
@@ -1329,16 +3201,18 @@ pub struct PipelineMetadata {
 pub struct PipelineSummary {
     /// <p>The date and time the pipeline was created, in timestamp format.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>The name of the pipeline.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// <p>The date and time of the last update to the pipeline, in timestamp format.</p>
     #[serde(rename = "updated")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated: Option<f64>,
+    pub updated: Option<EpochTimestamp>,
     /// <p>The version number of the pipeline.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1346,7 +3220,7 @@ pub struct PipelineSummary {
 }
 
 /// <p>Represents the input of a <code>PollForJobs</code> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PollForJobsInput {
     /// <p>Represents information about an action type.</p>
     #[serde(rename = "actionTypeId")]
@@ -1361,6 +3235,55 @@ pub struct PollForJobsInput {
     pub query_param: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl PollForJobsInput {
+    /// Returns a builder for `PollForJobsInput`, checking its required members when built.
+    pub fn builder() -> PollForJobsInputBuilder {
+        PollForJobsInputBuilder::default()
+    }
+}
+
+/// Builds a [`PollForJobsInput`](struct.PollForJobsInput.html).
+#[derive(Default, Debug)]
+pub struct PollForJobsInputBuilder {
+    action_type_id: Option<ActionTypeId>,
+    max_batch_size: Option<i64>,
+    query_param: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl PollForJobsInputBuilder {
+    /// Sets the [`action_type_id`](struct.PollForJobsInput.html#structfield.action_type_id) member.
+    pub fn action_type_id<T: Into<ActionTypeId>>(mut self, value: T) -> Self {
+        self.action_type_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_batch_size`](struct.PollForJobsInput.html#structfield.max_batch_size) member.
+    pub fn max_batch_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_batch_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_param`](struct.PollForJobsInput.html#structfield.query_param) member.
+    pub fn query_param<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.query_param = Some(value.into());
+        self
+    }
+
+    /// Builds the `PollForJobsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PollForJobsInput, ::rusoto_core::BuildError> {
+        Ok(PollForJobsInput {
+            action_type_id: self
+                .action_type_id
+                .ok_or(::rusoto_core::BuildError::missing("action_type_id"))?,
+            max_batch_size: self.max_batch_size,
+            query_param: self.query_param,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>PollForJobs</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1372,7 +3295,7 @@ pub struct PollForJobsOutput {
 }
 
 /// <p>Represents the input of a <code>PollForThirdPartyJobs</code> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PollForThirdPartyJobsInput {
     /// <p>Represents information about an action type.</p>
     #[serde(rename = "actionTypeId")]
@@ -1383,6 +3306,46 @@ pub struct PollForThirdPartyJobsInput {
     pub max_batch_size: Option<i64>,
 }
 
+impl PollForThirdPartyJobsInput {
+    /// Returns a builder for `PollForThirdPartyJobsInput`, checking its required members when built.
+    pub fn builder() -> PollForThirdPartyJobsInputBuilder {
+        PollForThirdPartyJobsInputBuilder::default()
+    }
+}
+
+/// Builds a [`PollForThirdPartyJobsInput`](struct.PollForThirdPartyJobsInput.html).
+#[derive(Default, Debug)]
+pub struct PollForThirdPartyJobsInputBuilder {
+    action_type_id: Option<ActionTypeId>,
+    max_batch_size: Option<i64>,
+}
+
+impl PollForThirdPartyJobsInputBuilder {
+    /// Sets the [`action_type_id`](struct.PollForThirdPartyJobsInput.html#structfield.action_type_id) member.
+    pub fn action_type_id<T: Into<ActionTypeId>>(mut self, value: T) -> Self {
+        self.action_type_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_batch_size`](struct.PollForThirdPartyJobsInput.html#structfield.max_batch_size) member.
+    pub fn max_batch_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_batch_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `PollForThirdPartyJobsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PollForThirdPartyJobsInput, ::rusoto_core::BuildError> {
+        Ok(PollForThirdPartyJobsInput {
+            action_type_id: self
+                .action_type_id
+                .ok_or(::rusoto_core::BuildError::missing("action_type_id"))?,
+            max_batch_size: self.max_batch_size,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>PollForThirdPartyJobs</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1410,6 +3373,66 @@ pub struct PutActionRevisionInput {
     pub stage_name: String,
 }
 
+impl PutActionRevisionInput {
+    /// Returns a builder for `PutActionRevisionInput`, checking its required members when built.
+    pub fn builder() -> PutActionRevisionInputBuilder {
+        PutActionRevisionInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutActionRevisionInput`](struct.PutActionRevisionInput.html).
+#[derive(Default, Debug)]
+pub struct PutActionRevisionInputBuilder {
+    action_name: Option<String>,
+    action_revision: Option<ActionRevision>,
+    pipeline_name: Option<String>,
+    stage_name: Option<String>,
+}
+
+impl PutActionRevisionInputBuilder {
+    /// Sets the [`action_name`](struct.PutActionRevisionInput.html#structfield.action_name) member.
+    pub fn action_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.action_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`action_revision`](struct.PutActionRevisionInput.html#structfield.action_revision) member.
+    pub fn action_revision<T: Into<ActionRevision>>(mut self, value: T) -> Self {
+        self.action_revision = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_name`](struct.PutActionRevisionInput.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.PutActionRevisionInput.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutActionRevisionInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutActionRevisionInput, ::rusoto_core::BuildError> {
+        Ok(PutActionRevisionInput {
+            action_name: self
+                .action_name
+                .ok_or(::rusoto_core::BuildError::missing("action_name"))?,
+            action_revision: self
+                .action_revision
+                .ok_or(::rusoto_core::BuildError::missing("action_revision"))?,
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>PutActionRevision</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1425,7 +3448,7 @@ pub struct PutActionRevisionOutput {
 }
 
 /// <p>Represents the input of a <code>PutApprovalResult</code> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutApprovalResultInput {
     /// <p>The name of the action for which approval is requested.</p>
     #[serde(rename = "actionName")]
@@ -1444,18 +3467,89 @@ pub struct PutApprovalResultInput {
     pub token: String,
 }
 
+impl PutApprovalResultInput {
+    /// Returns a builder for `PutApprovalResultInput`, checking its required members when built.
+    pub fn builder() -> PutApprovalResultInputBuilder {
+        PutApprovalResultInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutApprovalResultInput`](struct.PutApprovalResultInput.html).
+#[derive(Default, Debug)]
+pub struct PutApprovalResultInputBuilder {
+    action_name: Option<String>,
+    pipeline_name: Option<String>,
+    result: Option<ApprovalResult>,
+    stage_name: Option<String>,
+    token: Option<String>,
+}
+
+impl PutApprovalResultInputBuilder {
+    /// Sets the [`action_name`](struct.PutApprovalResultInput.html#structfield.action_name) member.
+    pub fn action_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.action_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_name`](struct.PutApprovalResultInput.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`result`](struct.PutApprovalResultInput.html#structfield.result) member.
+    pub fn result<T: Into<ApprovalResult>>(mut self, value: T) -> Self {
+        self.result = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.PutApprovalResultInput.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token`](struct.PutApprovalResultInput.html#structfield.token) member.
+    pub fn token<T: Into<String>>(mut self, value: T) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutApprovalResultInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutApprovalResultInput, ::rusoto_core::BuildError> {
+        Ok(PutApprovalResultInput {
+            action_name: self
+                .action_name
+                .ok_or(::rusoto_core::BuildError::missing("action_name"))?,
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+            result: self
+                .result
+                .ok_or(::rusoto_core::BuildError::missing("result"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+            token: self
+                .token
+                .ok_or(::rusoto_core::BuildError::missing("token"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>PutApprovalResult</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutApprovalResultOutput {
     /// <p>The timestamp showing when the approval or rejection was submitted.</p>
     #[serde(rename = "approvedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approved_at: Option<f64>,
+    pub approved_at: Option<EpochTimestamp>,
 }
 
 /// <p>Represents the input of a <code>PutJobFailureResult</code> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutJobFailureResultInput {
     /// <p>The details about the failure of a job.</p>
     #[serde(rename = "failureDetails")]
@@ -1465,6 +3559,48 @@ pub struct PutJobFailureResultInput {
     pub job_id: String,
 }
 
+impl PutJobFailureResultInput {
+    /// Returns a builder for `PutJobFailureResultInput`, checking its required members when built.
+    pub fn builder() -> PutJobFailureResultInputBuilder {
+        PutJobFailureResultInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutJobFailureResultInput`](struct.PutJobFailureResultInput.html).
+#[derive(Default, Debug)]
+pub struct PutJobFailureResultInputBuilder {
+    failure_details: Option<FailureDetails>,
+    job_id: Option<String>,
+}
+
+impl PutJobFailureResultInputBuilder {
+    /// Sets the [`failure_details`](struct.PutJobFailureResultInput.html#structfield.failure_details) member.
+    pub fn failure_details<T: Into<FailureDetails>>(mut self, value: T) -> Self {
+        self.failure_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.PutJobFailureResultInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutJobFailureResultInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutJobFailureResultInput, ::rusoto_core::BuildError> {
+        Ok(PutJobFailureResultInput {
+            failure_details: self
+                .failure_details
+                .ok_or(::rusoto_core::BuildError::missing("failure_details"))?,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the input of a <code>PutJobSuccessResult</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutJobSuccessResultInput {
@@ -1485,8 +3621,64 @@ pub struct PutJobSuccessResultInput {
     pub job_id: String,
 }
 
+impl PutJobSuccessResultInput {
+    /// Returns a builder for `PutJobSuccessResultInput`, checking its required members when built.
+    pub fn builder() -> PutJobSuccessResultInputBuilder {
+        PutJobSuccessResultInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutJobSuccessResultInput`](struct.PutJobSuccessResultInput.html).
+#[derive(Default, Debug)]
+pub struct PutJobSuccessResultInputBuilder {
+    continuation_token: Option<String>,
+    current_revision: Option<CurrentRevision>,
+    execution_details: Option<ExecutionDetails>,
+    job_id: Option<String>,
+}
+
+impl PutJobSuccessResultInputBuilder {
+    /// Sets the [`continuation_token`](struct.PutJobSuccessResultInput.html#structfield.continuation_token) member.
+    pub fn continuation_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.continuation_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_revision`](struct.PutJobSuccessResultInput.html#structfield.current_revision) member.
+    pub fn current_revision<T: Into<CurrentRevision>>(mut self, value: T) -> Self {
+        self.current_revision = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_details`](struct.PutJobSuccessResultInput.html#structfield.execution_details) member.
+    pub fn execution_details<T: Into<ExecutionDetails>>(mut self, value: T) -> Self {
+        self.execution_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.PutJobSuccessResultInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutJobSuccessResultInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutJobSuccessResultInput, ::rusoto_core::BuildError> {
+        Ok(PutJobSuccessResultInput {
+            continuation_token: self.continuation_token,
+            current_revision: self.current_revision,
+            execution_details: self.execution_details,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the input of a <code>PutThirdPartyJobFailureResult</code> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutThirdPartyJobFailureResultInput {
     /// <p>The clientToken portion of the clientId and clientToken pair used to verify that the calling entity is allowed access to the job and its details.</p>
     #[serde(rename = "clientToken")]
@@ -1499,6 +3691,58 @@ pub struct PutThirdPartyJobFailureResultInput {
     pub job_id: String,
 }
 
+impl PutThirdPartyJobFailureResultInput {
+    /// Returns a builder for `PutThirdPartyJobFailureResultInput`, checking its required members when built.
+    pub fn builder() -> PutThirdPartyJobFailureResultInputBuilder {
+        PutThirdPartyJobFailureResultInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutThirdPartyJobFailureResultInput`](struct.PutThirdPartyJobFailureResultInput.html).
+#[derive(Default, Debug)]
+pub struct PutThirdPartyJobFailureResultInputBuilder {
+    client_token: Option<String>,
+    failure_details: Option<FailureDetails>,
+    job_id: Option<String>,
+}
+
+impl PutThirdPartyJobFailureResultInputBuilder {
+    /// Sets the [`client_token`](struct.PutThirdPartyJobFailureResultInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`failure_details`](struct.PutThirdPartyJobFailureResultInput.html#structfield.failure_details) member.
+    pub fn failure_details<T: Into<FailureDetails>>(mut self, value: T) -> Self {
+        self.failure_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.PutThirdPartyJobFailureResultInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutThirdPartyJobFailureResultInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutThirdPartyJobFailureResultInput, ::rusoto_core::BuildError> {
+        Ok(PutThirdPartyJobFailureResultInput {
+            client_token: self
+                .client_token
+                .ok_or(::rusoto_core::BuildError::missing("client_token"))?,
+            failure_details: self
+                .failure_details
+                .ok_or(::rusoto_core::BuildError::missing("failure_details"))?,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the input of a <code>PutThirdPartyJobSuccessResult</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutThirdPartyJobSuccessResultInput {
@@ -1522,7 +3766,73 @@ pub struct PutThirdPartyJobSuccessResultInput {
     pub job_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl PutThirdPartyJobSuccessResultInput {
+    /// Returns a builder for `PutThirdPartyJobSuccessResultInput`, checking its required members when built.
+    pub fn builder() -> PutThirdPartyJobSuccessResultInputBuilder {
+        PutThirdPartyJobSuccessResultInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutThirdPartyJobSuccessResultInput`](struct.PutThirdPartyJobSuccessResultInput.html).
+#[derive(Default, Debug)]
+pub struct PutThirdPartyJobSuccessResultInputBuilder {
+    client_token: Option<String>,
+    continuation_token: Option<String>,
+    current_revision: Option<CurrentRevision>,
+    execution_details: Option<ExecutionDetails>,
+    job_id: Option<String>,
+}
+
+impl PutThirdPartyJobSuccessResultInputBuilder {
+    /// Sets the [`client_token`](struct.PutThirdPartyJobSuccessResultInput.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`continuation_token`](struct.PutThirdPartyJobSuccessResultInput.html#structfield.continuation_token) member.
+    pub fn continuation_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.continuation_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_revision`](struct.PutThirdPartyJobSuccessResultInput.html#structfield.current_revision) member.
+    pub fn current_revision<T: Into<CurrentRevision>>(mut self, value: T) -> Self {
+        self.current_revision = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_details`](struct.PutThirdPartyJobSuccessResultInput.html#structfield.execution_details) member.
+    pub fn execution_details<T: Into<ExecutionDetails>>(mut self, value: T) -> Self {
+        self.execution_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.PutThirdPartyJobSuccessResultInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutThirdPartyJobSuccessResultInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutThirdPartyJobSuccessResultInput, ::rusoto_core::BuildError> {
+        Ok(PutThirdPartyJobSuccessResultInput {
+            client_token: self
+                .client_token
+                .ok_or(::rusoto_core::BuildError::missing("client_token"))?,
+            continuation_token: self.continuation_token,
+            current_revision: self.current_revision,
+            execution_details: self.execution_details,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutWebhookInput {
     /// <p>The tags for the webhook.</p>
     #[serde(rename = "tags")]
@@ -1533,6 +3843,44 @@ pub struct PutWebhookInput {
     pub webhook: WebhookDefinition,
 }
 
+impl PutWebhookInput {
+    /// Returns a builder for `PutWebhookInput`, checking its required members when built.
+    pub fn builder() -> PutWebhookInputBuilder {
+        PutWebhookInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutWebhookInput`](struct.PutWebhookInput.html).
+#[derive(Default, Debug)]
+pub struct PutWebhookInputBuilder {
+    tags: Option<Vec<Tag>>,
+    webhook: Option<WebhookDefinition>,
+}
+
+impl PutWebhookInputBuilder {
+    /// Sets the [`tags`](struct.PutWebhookInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`webhook`](struct.PutWebhookInput.html#structfield.webhook) member.
+    pub fn webhook<T: Into<WebhookDefinition>>(mut self, value: T) -> Self {
+        self.webhook = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutWebhookInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutWebhookInput, ::rusoto_core::BuildError> {
+        Ok(PutWebhookInput {
+            tags: self.tags,
+            webhook: self
+                .webhook
+                .ok_or(::rusoto_core::BuildError::missing("webhook"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutWebhookOutput {
@@ -1550,12 +3898,42 @@ pub struct RegisterWebhookWithThirdPartyInput {
     pub webhook_name: Option<String>,
 }
 
+impl RegisterWebhookWithThirdPartyInput {
+    /// Returns a builder for `RegisterWebhookWithThirdPartyInput`, checking its required members when built.
+    pub fn builder() -> RegisterWebhookWithThirdPartyInputBuilder {
+        RegisterWebhookWithThirdPartyInputBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterWebhookWithThirdPartyInput`](struct.RegisterWebhookWithThirdPartyInput.html).
+#[derive(Default, Debug)]
+pub struct RegisterWebhookWithThirdPartyInputBuilder {
+    webhook_name: Option<String>,
+}
+
+impl RegisterWebhookWithThirdPartyInputBuilder {
+    /// Sets the [`webhook_name`](struct.RegisterWebhookWithThirdPartyInput.html#structfield.webhook_name) member.
+    pub fn webhook_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.webhook_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterWebhookWithThirdPartyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterWebhookWithThirdPartyInput, ::rusoto_core::BuildError> {
+        Ok(RegisterWebhookWithThirdPartyInput {
+            webhook_name: self.webhook_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RegisterWebhookWithThirdPartyOutput {}
 
 /// <p>Represents the input of a <code>RetryStageExecution</code> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RetryStageExecutionInput {
     /// <p>The ID of the pipeline execution in the failed stage to be retried. Use the <a>GetPipelineState</a> action to retrieve the current pipelineExecutionId of the failed stage</p>
     #[serde(rename = "pipelineExecutionId")]
@@ -1565,12 +3943,74 @@ pub struct RetryStageExecutionInput {
     pub pipeline_name: String,
     /// <p>The scope of the retry attempt. Currently, the only supported value is FAILED_ACTIONS.</p>
     #[serde(rename = "retryMode")]
-    pub retry_mode: String,
+    pub retry_mode: StageRetryMode,
     /// <p>The name of the failed stage to be retried.</p>
     #[serde(rename = "stageName")]
     pub stage_name: String,
 }
 
+impl RetryStageExecutionInput {
+    /// Returns a builder for `RetryStageExecutionInput`, checking its required members when built.
+    pub fn builder() -> RetryStageExecutionInputBuilder {
+        RetryStageExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`RetryStageExecutionInput`](struct.RetryStageExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct RetryStageExecutionInputBuilder {
+    pipeline_execution_id: Option<String>,
+    pipeline_name: Option<String>,
+    retry_mode: Option<StageRetryMode>,
+    stage_name: Option<String>,
+}
+
+impl RetryStageExecutionInputBuilder {
+    /// Sets the [`pipeline_execution_id`](struct.RetryStageExecutionInput.html#structfield.pipeline_execution_id) member.
+    pub fn pipeline_execution_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_execution_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_name`](struct.RetryStageExecutionInput.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retry_mode`](struct.RetryStageExecutionInput.html#structfield.retry_mode) member.
+    pub fn retry_mode<T: Into<StageRetryMode>>(mut self, value: T) -> Self {
+        self.retry_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stage_name`](struct.RetryStageExecutionInput.html#structfield.stage_name) member.
+    pub fn stage_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stage_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RetryStageExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RetryStageExecutionInput, ::rusoto_core::BuildError> {
+        Ok(RetryStageExecutionInput {
+            pipeline_execution_id: self
+                .pipeline_execution_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_execution_id"))?,
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+            retry_mode: self
+                .retry_mode
+                .ok_or(::rusoto_core::BuildError::missing("retry_mode"))?,
+            stage_name: self
+                .stage_name
+                .ok_or(::rusoto_core::BuildError::missing("stage_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>RetryStageExecution</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1654,7 +4094,7 @@ pub struct StageDeclaration {
 }
 
 /// <p>Represents information about the run of a stage.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StageExecution {
     /// <p>The ID of the pipeline execution associated with the stage.</p>
@@ -1662,7 +4102,167 @@ pub struct StageExecution {
     pub pipeline_execution_id: String,
     /// <p>The status of the stage, or for a completed stage, the last status of the stage.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: StageExecutionStatus,
+}
+
+/// <p>The status of the stage, or for a completed stage, the last status of the stage.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StageExecutionStatus {
+    /// <p><code>Cancelled</code></p>
+    Cancelled,
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Stopped</code></p>
+    Stopped,
+    /// <p><code>Stopping</code></p>
+    Stopping,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StageExecutionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StageExecutionStatus::Cancelled => "Cancelled",
+            StageExecutionStatus::InProgress => "InProgress",
+            StageExecutionStatus::Failed => "Failed",
+            StageExecutionStatus::Stopped => "Stopped",
+            StageExecutionStatus::Stopping => "Stopping",
+            StageExecutionStatus::Succeeded => "Succeeded",
+            StageExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StageExecutionStatus {
+    fn from(value: &'a str) -> StageExecutionStatus {
+        match value {
+            "Cancelled" => StageExecutionStatus::Cancelled,
+            "InProgress" => StageExecutionStatus::InProgress,
+            "Failed" => StageExecutionStatus::Failed,
+            "Stopped" => StageExecutionStatus::Stopped,
+            "Stopping" => StageExecutionStatus::Stopping,
+            "Succeeded" => StageExecutionStatus::Succeeded,
+            _ => StageExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StageExecutionStatus {
+    fn from(value: String) -> StageExecutionStatus {
+        StageExecutionStatus::from(value.as_str())
+    }
+}
+
+impl From<StageExecutionStatus> for String {
+    fn from(value: StageExecutionStatus) -> String {
+        match value {
+            StageExecutionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StageExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StageExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StageExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StageExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StageExecutionStatus::from(value))
+    }
+}
+
+/// <p>The scope of the retry attempt. Currently, the only supported value is FAILED_ACTIONS.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StageRetryMode {
+    /// <p><code>FAILED_ACTIONS</code></p>
+    FailedActions,
+    /// <p><code>ALL_ACTIONS</code></p>
+    AllActions,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StageRetryMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StageRetryMode::FailedActions => "FAILED_ACTIONS",
+            StageRetryMode::AllActions => "ALL_ACTIONS",
+            StageRetryMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StageRetryMode {
+    fn from(value: &'a str) -> StageRetryMode {
+        match value {
+            "FAILED_ACTIONS" => StageRetryMode::FailedActions,
+            "ALL_ACTIONS" => StageRetryMode::AllActions,
+            _ => StageRetryMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StageRetryMode {
+    fn from(value: String) -> StageRetryMode {
+        StageRetryMode::from(value.as_str())
+    }
+}
+
+impl From<StageRetryMode> for String {
+    fn from(value: StageRetryMode) -> String {
+        match value {
+            StageRetryMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StageRetryMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StageRetryMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StageRetryMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StageRetryMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StageRetryMode::from(value))
+    }
 }
 
 /// <p>Represents information about the state of the stage.</p>
@@ -1687,6 +4287,78 @@ pub struct StageState {
     pub stage_name: Option<String>,
 }
 
+/// <p>Specifies whether artifacts will be prevented from transitioning into the stage and being processed by the actions in that stage (inbound), or prevented from transitioning from the stage after they have been processed by the actions in that stage (outbound).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StageTransitionType {
+    /// <p><code>Inbound</code></p>
+    Inbound,
+    /// <p><code>Outbound</code></p>
+    Outbound,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StageTransitionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StageTransitionType::Inbound => "Inbound",
+            StageTransitionType::Outbound => "Outbound",
+            StageTransitionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StageTransitionType {
+    fn from(value: &'a str) -> StageTransitionType {
+        match value {
+            "Inbound" => StageTransitionType::Inbound,
+            "Outbound" => StageTransitionType::Outbound,
+            _ => StageTransitionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StageTransitionType {
+    fn from(value: String) -> StageTransitionType {
+        StageTransitionType::from(value.as_str())
+    }
+}
+
+impl From<StageTransitionType> for String {
+    fn from(value: StageTransitionType) -> String {
+        match value {
+            StageTransitionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StageTransitionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StageTransitionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StageTransitionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StageTransitionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StageTransitionType::from(value))
+    }
+}
+
 /// <p>Represents the input of a <code>StartPipelineExecution</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartPipelineExecutionInput {
@@ -1699,6 +4371,46 @@ pub struct StartPipelineExecutionInput {
     pub name: String,
 }
 
+impl StartPipelineExecutionInput {
+    /// Returns a builder for `StartPipelineExecutionInput`, checking its required members when built.
+    pub fn builder() -> StartPipelineExecutionInputBuilder {
+        StartPipelineExecutionInputBuilder::default()
+    }
+}
+
+/// Builds a [`StartPipelineExecutionInput`](struct.StartPipelineExecutionInput.html).
+#[derive(Default, Debug)]
+pub struct StartPipelineExecutionInputBuilder {
+    client_request_token: Option<String>,
+    name: Option<String>,
+}
+
+impl StartPipelineExecutionInputBuilder {
+    /// Sets the [`client_request_token`](struct.StartPipelineExecutionInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.StartPipelineExecutionInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartPipelineExecutionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartPipelineExecutionInput, ::rusoto_core::BuildError> {
+        Ok(StartPipelineExecutionInput {
+            client_request_token: self.client_request_token,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>StartPipelineExecution</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1730,6 +4442,46 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceInput {
+    /// Returns a builder for `TagResourceInput`, checking its required members when built.
+    pub fn builder() -> TagResourceInputBuilder {
+        TagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceInput`](struct.TagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct TagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceInput, ::rusoto_core::BuildError> {
+        Ok(TagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceOutput {}
@@ -1818,14 +4570,107 @@ pub struct TransitionState {
     pub enabled: Option<bool>,
     /// <p>The timestamp when the transition state was last changed.</p>
     #[serde(rename = "lastChangedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_changed_at: Option<f64>,
+    pub last_changed_at: Option<EpochTimestamp>,
     /// <p>The ID of the user who last changed the transition state.</p>
     #[serde(rename = "lastChangedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_changed_by: Option<String>,
 }
 
+/// <p>The type of change-detection method, command, or user interaction that started a pipeline execution.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TriggerType {
+    /// <p><code>CreatePipeline</code></p>
+    CreatePipeline,
+    /// <p><code>StartPipelineExecution</code></p>
+    StartPipelineExecution,
+    /// <p><code>PollForSourceChanges</code></p>
+    PollForSourceChanges,
+    /// <p><code>Webhook</code></p>
+    Webhook,
+    /// <p><code>CloudWatchEvent</code></p>
+    CloudWatchEvent,
+    /// <p><code>PutActionRevision</code></p>
+    PutActionRevision,
+    /// <p><code>WebhookV2</code></p>
+    WebhookV2,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TriggerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TriggerType::CreatePipeline => "CreatePipeline",
+            TriggerType::StartPipelineExecution => "StartPipelineExecution",
+            TriggerType::PollForSourceChanges => "PollForSourceChanges",
+            TriggerType::Webhook => "Webhook",
+            TriggerType::CloudWatchEvent => "CloudWatchEvent",
+            TriggerType::PutActionRevision => "PutActionRevision",
+            TriggerType::WebhookV2 => "WebhookV2",
+            TriggerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TriggerType {
+    fn from(value: &'a str) -> TriggerType {
+        match value {
+            "CreatePipeline" => TriggerType::CreatePipeline,
+            "StartPipelineExecution" => TriggerType::StartPipelineExecution,
+            "PollForSourceChanges" => TriggerType::PollForSourceChanges,
+            "Webhook" => TriggerType::Webhook,
+            "CloudWatchEvent" => TriggerType::CloudWatchEvent,
+            "PutActionRevision" => TriggerType::PutActionRevision,
+            "WebhookV2" => TriggerType::WebhookV2,
+            _ => TriggerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TriggerType {
+    fn from(value: String) -> TriggerType {
+        TriggerType::from(value.as_str())
+    }
+}
+
+impl From<TriggerType> for String {
+    fn from(value: TriggerType) -> String {
+        match value {
+            TriggerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TriggerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TriggerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TriggerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TriggerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TriggerType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceInput {
     /// <p> The Amazon Resource Name (ARN) of the resource to remove tags from.</p>
@@ -1836,6 +4681,46 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceInput {
+    /// Returns a builder for `UntagResourceInput`, checking its required members when built.
+    pub fn builder() -> UntagResourceInputBuilder {
+        UntagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceInput`](struct.UntagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceInput, ::rusoto_core::BuildError> {
+        Ok(UntagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceOutput {}
@@ -1848,6 +4733,36 @@ pub struct UpdatePipelineInput {
     pub pipeline: PipelineDeclaration,
 }
 
+impl UpdatePipelineInput {
+    /// Returns a builder for `UpdatePipelineInput`, checking its required members when built.
+    pub fn builder() -> UpdatePipelineInputBuilder {
+        UpdatePipelineInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePipelineInput`](struct.UpdatePipelineInput.html).
+#[derive(Default, Debug)]
+pub struct UpdatePipelineInputBuilder {
+    pipeline: Option<PipelineDeclaration>,
+}
+
+impl UpdatePipelineInputBuilder {
+    /// Sets the [`pipeline`](struct.UpdatePipelineInput.html#structfield.pipeline) member.
+    pub fn pipeline<T: Into<PipelineDeclaration>>(mut self, value: T) -> Self {
+        self.pipeline = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePipelineInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdatePipelineInput, ::rusoto_core::BuildError> {
+        Ok(UpdatePipelineInput {
+            pipeline: self
+                .pipeline
+                .ok_or(::rusoto_core::BuildError::missing("pipeline"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of an <code>UpdatePipeline</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1871,12 +4786,88 @@ pub struct WebhookAuthConfiguration {
     pub secret_token: Option<String>,
 }
 
+/// <p><p>Supported options are GITHUB<em>HMAC, IP and UNAUTHENTICATED.</p> <ul> <li> <p>For information about the authentication scheme implemented by GITHUB</em>HMAC, see <a href="https://developer.github.com/webhooks/securing/">Securing your webhooks</a> on the GitHub Developer website.</p> </li> <li> <p> IP will reject webhooks trigger requests unless they originate from an IP within the IP range whitelisted in the authentication configuration.</p> </li> <li> <p> UNAUTHENTICATED will accept all webhook trigger requests regardless of origin.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WebhookAuthenticationType {
+    /// <p><code>GITHUB_HMAC</code></p>
+    GithubHmac,
+    /// <p><code>IP</code></p>
+    Ip,
+    /// <p><code>UNAUTHENTICATED</code></p>
+    Unauthenticated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WebhookAuthenticationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WebhookAuthenticationType::GithubHmac => "GITHUB_HMAC",
+            WebhookAuthenticationType::Ip => "IP",
+            WebhookAuthenticationType::Unauthenticated => "UNAUTHENTICATED",
+            WebhookAuthenticationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WebhookAuthenticationType {
+    fn from(value: &'a str) -> WebhookAuthenticationType {
+        match value {
+            "GITHUB_HMAC" => WebhookAuthenticationType::GithubHmac,
+            "IP" => WebhookAuthenticationType::Ip,
+            "UNAUTHENTICATED" => WebhookAuthenticationType::Unauthenticated,
+            _ => WebhookAuthenticationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WebhookAuthenticationType {
+    fn from(value: String) -> WebhookAuthenticationType {
+        WebhookAuthenticationType::from(value.as_str())
+    }
+}
+
+impl From<WebhookAuthenticationType> for String {
+    fn from(value: WebhookAuthenticationType) -> String {
+        match value {
+            WebhookAuthenticationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WebhookAuthenticationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WebhookAuthenticationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WebhookAuthenticationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WebhookAuthenticationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WebhookAuthenticationType::from(value))
+    }
+}
+
 /// <p>Represents information about a webhook and its definition.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WebhookDefinition {
     /// <p><p>Supported options are GITHUB<em>HMAC, IP and UNAUTHENTICATED.</p> <ul> <li> <p>For information about the authentication scheme implemented by GITHUB</em>HMAC, see <a href="https://developer.github.com/webhooks/securing/">Securing your webhooks</a> on the GitHub Developer website.</p> </li> <li> <p> IP will reject webhooks trigger requests unless they originate from an IP within the IP range whitelisted in the authentication configuration.</p> </li> <li> <p> UNAUTHENTICATED will accept all webhook trigger requests regardless of origin.</p> </li> </ul></p>
     #[serde(rename = "authentication")]
-    pub authentication: String,
+    pub authentication: WebhookAuthenticationType,
     /// <p>Properties that configure the authentication applied to incoming webhook trigger requests. The required properties depend on the authentication type. For GITHUB_HMAC, only the <code>SecretToken </code>property must be set. For IP, only the <code>AllowedIPRange </code>property must be set to a valid CIDR range. For UNAUTHENTICATED, no properties can be set.</p>
     #[serde(rename = "authenticationConfiguration")]
     pub authentication_configuration: WebhookAuthConfiguration,
@@ -3751,6 +6742,229 @@ pub trait CodePipeline {
         input: UpdatePipelineInput,
     ) -> RusotoFuture<UpdatePipelineOutput, UpdatePipelineError>;
 }
+impl<T: ?Sized + CodePipeline> CodePipeline for ::std::sync::Arc<T> {
+    fn acknowledge_job(
+        &self,
+        input: AcknowledgeJobInput,
+    ) -> RusotoFuture<AcknowledgeJobOutput, AcknowledgeJobError> {
+        (**self).acknowledge_job(input)
+    }
+    fn acknowledge_third_party_job(
+        &self,
+        input: AcknowledgeThirdPartyJobInput,
+    ) -> RusotoFuture<AcknowledgeThirdPartyJobOutput, AcknowledgeThirdPartyJobError> {
+        (**self).acknowledge_third_party_job(input)
+    }
+    fn create_custom_action_type(
+        &self,
+        input: CreateCustomActionTypeInput,
+    ) -> RusotoFuture<CreateCustomActionTypeOutput, CreateCustomActionTypeError> {
+        (**self).create_custom_action_type(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> RusotoFuture<CreatePipelineOutput, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn delete_custom_action_type(
+        &self,
+        input: DeleteCustomActionTypeInput,
+    ) -> RusotoFuture<(), DeleteCustomActionTypeError> {
+        (**self).delete_custom_action_type(input)
+    }
+    fn delete_pipeline(&self, input: DeletePipelineInput) -> RusotoFuture<(), DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> RusotoFuture<DeleteWebhookOutput, DeleteWebhookError> {
+        (**self).delete_webhook(input)
+    }
+    fn deregister_webhook_with_third_party(
+        &self,
+        input: DeregisterWebhookWithThirdPartyInput,
+    ) -> RusotoFuture<DeregisterWebhookWithThirdPartyOutput, DeregisterWebhookWithThirdPartyError>
+    {
+        (**self).deregister_webhook_with_third_party(input)
+    }
+    fn disable_stage_transition(
+        &self,
+        input: DisableStageTransitionInput,
+    ) -> RusotoFuture<(), DisableStageTransitionError> {
+        (**self).disable_stage_transition(input)
+    }
+    fn enable_stage_transition(
+        &self,
+        input: EnableStageTransitionInput,
+    ) -> RusotoFuture<(), EnableStageTransitionError> {
+        (**self).enable_stage_transition(input)
+    }
+    fn get_job_details(
+        &self,
+        input: GetJobDetailsInput,
+    ) -> RusotoFuture<GetJobDetailsOutput, GetJobDetailsError> {
+        (**self).get_job_details(input)
+    }
+    fn get_pipeline(
+        &self,
+        input: GetPipelineInput,
+    ) -> RusotoFuture<GetPipelineOutput, GetPipelineError> {
+        (**self).get_pipeline(input)
+    }
+    fn get_pipeline_execution(
+        &self,
+        input: GetPipelineExecutionInput,
+    ) -> RusotoFuture<GetPipelineExecutionOutput, GetPipelineExecutionError> {
+        (**self).get_pipeline_execution(input)
+    }
+    fn get_pipeline_state(
+        &self,
+        input: GetPipelineStateInput,
+    ) -> RusotoFuture<GetPipelineStateOutput, GetPipelineStateError> {
+        (**self).get_pipeline_state(input)
+    }
+    fn get_third_party_job_details(
+        &self,
+        input: GetThirdPartyJobDetailsInput,
+    ) -> RusotoFuture<GetThirdPartyJobDetailsOutput, GetThirdPartyJobDetailsError> {
+        (**self).get_third_party_job_details(input)
+    }
+    fn list_action_executions(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> RusotoFuture<ListActionExecutionsOutput, ListActionExecutionsError> {
+        (**self).list_action_executions(input)
+    }
+    fn list_action_types(
+        &self,
+        input: ListActionTypesInput,
+    ) -> RusotoFuture<ListActionTypesOutput, ListActionTypesError> {
+        (**self).list_action_types(input)
+    }
+    fn list_pipeline_executions(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> RusotoFuture<ListPipelineExecutionsOutput, ListPipelineExecutionsError> {
+        (**self).list_pipeline_executions(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> RusotoFuture<ListPipelinesOutput, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_webhooks(
+        &self,
+        input: ListWebhooksInput,
+    ) -> RusotoFuture<ListWebhooksOutput, ListWebhooksError> {
+        (**self).list_webhooks(input)
+    }
+    fn poll_for_jobs(
+        &self,
+        input: PollForJobsInput,
+    ) -> RusotoFuture<PollForJobsOutput, PollForJobsError> {
+        (**self).poll_for_jobs(input)
+    }
+    fn poll_for_third_party_jobs(
+        &self,
+        input: PollForThirdPartyJobsInput,
+    ) -> RusotoFuture<PollForThirdPartyJobsOutput, PollForThirdPartyJobsError> {
+        (**self).poll_for_third_party_jobs(input)
+    }
+    fn put_action_revision(
+        &self,
+        input: PutActionRevisionInput,
+    ) -> RusotoFuture<PutActionRevisionOutput, PutActionRevisionError> {
+        (**self).put_action_revision(input)
+    }
+    fn put_approval_result(
+        &self,
+        input: PutApprovalResultInput,
+    ) -> RusotoFuture<PutApprovalResultOutput, PutApprovalResultError> {
+        (**self).put_approval_result(input)
+    }
+    fn put_job_failure_result(
+        &self,
+        input: PutJobFailureResultInput,
+    ) -> RusotoFuture<(), PutJobFailureResultError> {
+        (**self).put_job_failure_result(input)
+    }
+    fn put_job_success_result(
+        &self,
+        input: PutJobSuccessResultInput,
+    ) -> RusotoFuture<(), PutJobSuccessResultError> {
+        (**self).put_job_success_result(input)
+    }
+    fn put_third_party_job_failure_result(
+        &self,
+        input: PutThirdPartyJobFailureResultInput,
+    ) -> RusotoFuture<(), PutThirdPartyJobFailureResultError> {
+        (**self).put_third_party_job_failure_result(input)
+    }
+    fn put_third_party_job_success_result(
+        &self,
+        input: PutThirdPartyJobSuccessResultInput,
+    ) -> RusotoFuture<(), PutThirdPartyJobSuccessResultError> {
+        (**self).put_third_party_job_success_result(input)
+    }
+    fn put_webhook(
+        &self,
+        input: PutWebhookInput,
+    ) -> RusotoFuture<PutWebhookOutput, PutWebhookError> {
+        (**self).put_webhook(input)
+    }
+    fn register_webhook_with_third_party(
+        &self,
+        input: RegisterWebhookWithThirdPartyInput,
+    ) -> RusotoFuture<RegisterWebhookWithThirdPartyOutput, RegisterWebhookWithThirdPartyError> {
+        (**self).register_webhook_with_third_party(input)
+    }
+    fn retry_stage_execution(
+        &self,
+        input: RetryStageExecutionInput,
+    ) -> RusotoFuture<RetryStageExecutionOutput, RetryStageExecutionError> {
+        (**self).retry_stage_execution(input)
+    }
+    fn start_pipeline_execution(
+        &self,
+        input: StartPipelineExecutionInput,
+    ) -> RusotoFuture<StartPipelineExecutionOutput, StartPipelineExecutionError> {
+        (**self).start_pipeline_execution(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineInput,
+    ) -> RusotoFuture<UpdatePipelineOutput, UpdatePipelineError> {
+        (**self).update_pipeline(input)
+    }
+}
+/// The error codes CodePipeline retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the CodePipeline API.
 #[derive(Clone)]
 pub struct CodePipelineClient {
@@ -3766,6 +6980,27 @@ impl CodePipelineClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CODEPIPELINE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CodePipelineClient {
+        Self::new(region::Region::from_env_for_service("CODEPIPELINE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `codepipeline`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CodePipelineClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("codepipeline", "CODEPIPELINE"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3784,7 +7019,54 @@ impl CodePipelineClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodePipelineClient {
-        CodePipelineClient { client, region }
+        CodePipelineClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "codepipeline")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "codepipeline",
+            "codepipeline",
+            "CODEPIPELINE",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CodePipeline` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CodePipeline + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CodePipelineClient {
+    fn service_name(&self) -> &'static str {
+        "CodePipeline"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "codepipeline"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CodePipelineClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateTeamMemberRequest {
@@ -44,6 +45,74 @@ pub struct AssociateTeamMemberRequest {
     pub user_arn: String,
 }
 
+impl AssociateTeamMemberRequest {
+    /// Returns a builder for `AssociateTeamMemberRequest`, checking its required members when built.
+    pub fn builder() -> AssociateTeamMemberRequestBuilder {
+        AssociateTeamMemberRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateTeamMemberRequest`](struct.AssociateTeamMemberRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateTeamMemberRequestBuilder {
+    client_request_token: Option<String>,
+    project_id: Option<String>,
+    project_role: Option<String>,
+    remote_access_allowed: Option<bool>,
+    user_arn: Option<String>,
+}
+
+impl AssociateTeamMemberRequestBuilder {
+    /// Sets the [`client_request_token`](struct.AssociateTeamMemberRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_id`](struct.AssociateTeamMemberRequest.html#structfield.project_id) member.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_role`](struct.AssociateTeamMemberRequest.html#structfield.project_role) member.
+    pub fn project_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remote_access_allowed`](struct.AssociateTeamMemberRequest.html#structfield.remote_access_allowed) member.
+    pub fn remote_access_allowed<T: Into<bool>>(mut self, value: T) -> Self {
+        self.remote_access_allowed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_arn`](struct.AssociateTeamMemberRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateTeamMemberRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateTeamMemberRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateTeamMemberRequest {
+            client_request_token: self.client_request_token,
+            project_id: self
+                .project_id
+                .ok_or(::rusoto_core::BuildError::missing("project_id"))?,
+            project_role: self
+                .project_role
+                .ok_or(::rusoto_core::BuildError::missing("project_role"))?,
+            remote_access_allowed: self.remote_access_allowed,
+            user_arn: self
+                .user_arn
+                .ok_or(::rusoto_core::BuildError::missing("user_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateTeamMemberResult {
@@ -123,6 +192,84 @@ pub struct CreateProjectRequest {
     pub toolchain: Option<Toolchain>,
 }
 
+impl CreateProjectRequest {
+    /// Returns a builder for `CreateProjectRequest`, checking its required members when built.
+    pub fn builder() -> CreateProjectRequestBuilder {
+        CreateProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateProjectRequest`](struct.CreateProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateProjectRequestBuilder {
+    client_request_token: Option<String>,
+    description: Option<String>,
+    id: Option<String>,
+    name: Option<String>,
+    source_code: Option<Vec<Code>>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    toolchain: Option<Toolchain>,
+}
+
+impl CreateProjectRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateProjectRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateProjectRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.CreateProjectRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateProjectRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_code`](struct.CreateProjectRequest.html#structfield.source_code) member.
+    pub fn source_code<T: Into<Vec<Code>>>(mut self, value: T) -> Self {
+        self.source_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateProjectRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`toolchain`](struct.CreateProjectRequest.html#structfield.toolchain) member.
+    pub fn toolchain<T: Into<Toolchain>>(mut self, value: T) -> Self {
+        self.toolchain = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateProjectRequest, ::rusoto_core::BuildError> {
+        Ok(CreateProjectRequest {
+            client_request_token: self.client_request_token,
+            description: self.description,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            source_code: self.source_code,
+            tags: self.tags,
+            toolchain: self.toolchain,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateProjectResult {
@@ -159,13 +306,74 @@ pub struct CreateUserProfileRequest {
     pub user_arn: String,
 }
 
+impl CreateUserProfileRequest {
+    /// Returns a builder for `CreateUserProfileRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserProfileRequestBuilder {
+        CreateUserProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserProfileRequest`](struct.CreateUserProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserProfileRequestBuilder {
+    display_name: Option<String>,
+    email_address: Option<String>,
+    ssh_public_key: Option<String>,
+    user_arn: Option<String>,
+}
+
+impl CreateUserProfileRequestBuilder {
+    /// Sets the [`display_name`](struct.CreateUserProfileRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email_address`](struct.CreateUserProfileRequest.html#structfield.email_address) member.
+    pub fn email_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.email_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_public_key`](struct.CreateUserProfileRequest.html#structfield.ssh_public_key) member.
+    pub fn ssh_public_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_arn`](struct.CreateUserProfileRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateUserProfileRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserProfileRequest {
+            display_name: self
+                .display_name
+                .ok_or(::rusoto_core::BuildError::missing("display_name"))?,
+            email_address: self
+                .email_address
+                .ok_or(::rusoto_core::BuildError::missing("email_address"))?,
+            ssh_public_key: self.ssh_public_key,
+            user_arn: self
+                .user_arn
+                .ok_or(::rusoto_core::BuildError::missing("user_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateUserProfileResult {
     /// <p>The date the user profile was created, in timestamp format.</p>
     #[serde(rename = "createdTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The name that is displayed as the friendly name for the user in AWS CodeStar.</p>
     #[serde(rename = "displayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -176,8 +384,9 @@ pub struct CreateUserProfileResult {
     pub email_address: Option<String>,
     /// <p>The date the user profile was last modified, in timestamp format.</p>
     #[serde(rename = "lastModifiedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_timestamp: Option<f64>,
+    pub last_modified_timestamp: Option<EpochTimestamp>,
     /// <p>The SSH public key associated with the user in AWS CodeStar. This is the public portion of the public/private keypair the user can use to access project resources if a project owner allows the user remote access to those resources.</p>
     #[serde(rename = "sshPublicKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -202,6 +411,50 @@ pub struct DeleteProjectRequest {
     pub id: String,
 }
 
+impl DeleteProjectRequest {
+    /// Returns a builder for `DeleteProjectRequest`, checking its required members when built.
+    pub fn builder() -> DeleteProjectRequestBuilder {
+        DeleteProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteProjectRequest`](struct.DeleteProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteProjectRequestBuilder {
+    client_request_token: Option<String>,
+    delete_stack: Option<bool>,
+    id: Option<String>,
+}
+
+impl DeleteProjectRequestBuilder {
+    /// Sets the [`client_request_token`](struct.DeleteProjectRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delete_stack`](struct.DeleteProjectRequest.html#structfield.delete_stack) member.
+    pub fn delete_stack<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_stack = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.DeleteProjectRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteProjectRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteProjectRequest {
+            client_request_token: self.client_request_token,
+            delete_stack: self.delete_stack,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteProjectResult {
@@ -222,6 +475,38 @@ pub struct DeleteUserProfileRequest {
     pub user_arn: String,
 }
 
+impl DeleteUserProfileRequest {
+    /// Returns a builder for `DeleteUserProfileRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserProfileRequestBuilder {
+        DeleteUserProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserProfileRequest`](struct.DeleteUserProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserProfileRequestBuilder {
+    user_arn: Option<String>,
+}
+
+impl DeleteUserProfileRequestBuilder {
+    /// Sets the [`user_arn`](struct.DeleteUserProfileRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteUserProfileRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserProfileRequest {
+            user_arn: self
+                .user_arn
+                .ok_or(::rusoto_core::BuildError::missing("user_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteUserProfileResult {
@@ -237,6 +522,34 @@ pub struct DescribeProjectRequest {
     pub id: String,
 }
 
+impl DescribeProjectRequest {
+    /// Returns a builder for `DescribeProjectRequest`, checking its required members when built.
+    pub fn builder() -> DescribeProjectRequestBuilder {
+        DescribeProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeProjectRequest`](struct.DescribeProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeProjectRequestBuilder {
+    id: Option<String>,
+}
+
+impl DescribeProjectRequestBuilder {
+    /// Sets the [`id`](struct.DescribeProjectRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeProjectRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeProjectRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeProjectResult {
@@ -250,8 +563,9 @@ pub struct DescribeProjectResult {
     pub client_request_token: Option<String>,
     /// <p>The date and time the project was created, in timestamp format.</p>
     #[serde(rename = "createdTimeStamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time_stamp: Option<f64>,
+    pub created_time_stamp: Option<EpochTimestamp>,
     /// <p>The description of the project, if any.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -285,12 +599,45 @@ pub struct DescribeUserProfileRequest {
     pub user_arn: String,
 }
 
+impl DescribeUserProfileRequest {
+    /// Returns a builder for `DescribeUserProfileRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserProfileRequestBuilder {
+        DescribeUserProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserProfileRequest`](struct.DescribeUserProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserProfileRequestBuilder {
+    user_arn: Option<String>,
+}
+
+impl DescribeUserProfileRequestBuilder {
+    /// Sets the [`user_arn`](struct.DescribeUserProfileRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeUserProfileRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUserProfileRequest {
+            user_arn: self
+                .user_arn
+                .ok_or(::rusoto_core::BuildError::missing("user_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUserProfileResult {
     /// <p>The date and time when the user profile was created in AWS CodeStar, in timestamp format.</p>
     #[serde(rename = "createdTimestamp")]
-    pub created_timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub created_timestamp: EpochTimestamp,
     /// <p>The display name shown for the user in AWS CodeStar projects. For example, this could be set to both first and last name ("Mary Major") or a single name ("Mary"). The display name is also used to generate the initial icon associated with the user in AWS CodeStar projects. If spaces are included in the display name, the first character that appears after the space will be used as the second character in the user initial icon. The initial icon displays a maximum of two characters, so a display name with more than one space (for example "Mary Jane Major") would generate an initial icon using the first character and the first character after the space ("MJ", not "MM").</p>
     #[serde(rename = "displayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -301,7 +648,8 @@ pub struct DescribeUserProfileResult {
     pub email_address: Option<String>,
     /// <p>The date and time when the user profile was last modified, in timestamp format.</p>
     #[serde(rename = "lastModifiedTimestamp")]
-    pub last_modified_timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub last_modified_timestamp: EpochTimestamp,
     /// <p>The SSH public key associated with the user. This SSH public key is associated with the user profile, and can be used in conjunction with the associated private key for access to project resources, such as Amazon EC2 instances, if a project owner grants remote access to those resources.</p>
     #[serde(rename = "sshPublicKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -321,6 +669,48 @@ pub struct DisassociateTeamMemberRequest {
     pub user_arn: String,
 }
 
+impl DisassociateTeamMemberRequest {
+    /// Returns a builder for `DisassociateTeamMemberRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateTeamMemberRequestBuilder {
+        DisassociateTeamMemberRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateTeamMemberRequest`](struct.DisassociateTeamMemberRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateTeamMemberRequestBuilder {
+    project_id: Option<String>,
+    user_arn: Option<String>,
+}
+
+impl DisassociateTeamMemberRequestBuilder {
+    /// Sets the [`project_id`](struct.DisassociateTeamMemberRequest.html#structfield.project_id) member.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_arn`](struct.DisassociateTeamMemberRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateTeamMemberRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateTeamMemberRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateTeamMemberRequest {
+            project_id: self
+                .project_id
+                .ok_or(::rusoto_core::BuildError::missing("project_id"))?,
+            user_arn: self
+                .user_arn
+                .ok_or(::rusoto_core::BuildError::missing("user_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateTeamMemberResult {}
@@ -364,6 +754,42 @@ pub struct ListProjectsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListProjectsRequest {
+    /// Returns a builder for `ListProjectsRequest`, checking its required members when built.
+    pub fn builder() -> ListProjectsRequestBuilder {
+        ListProjectsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListProjectsRequest`](struct.ListProjectsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListProjectsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListProjectsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListProjectsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListProjectsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListProjectsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListProjectsRequest, ::rusoto_core::BuildError> {
+        Ok(ListProjectsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListProjectsResult {
@@ -391,6 +817,52 @@ pub struct ListResourcesRequest {
     pub project_id: String,
 }
 
+impl ListResourcesRequest {
+    /// Returns a builder for `ListResourcesRequest`, checking its required members when built.
+    pub fn builder() -> ListResourcesRequestBuilder {
+        ListResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResourcesRequest`](struct.ListResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResourcesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    project_id: Option<String>,
+}
+
+impl ListResourcesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListResourcesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListResourcesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_id`](struct.ListResourcesRequest.html#structfield.project_id) member.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(ListResourcesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            project_id: self
+                .project_id
+                .ok_or(::rusoto_core::BuildError::missing("project_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResourcesResult {
@@ -419,6 +891,52 @@ pub struct ListTagsForProjectRequest {
     pub next_token: Option<String>,
 }
 
+impl ListTagsForProjectRequest {
+    /// Returns a builder for `ListTagsForProjectRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForProjectRequestBuilder {
+        ListTagsForProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForProjectRequest`](struct.ListTagsForProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForProjectRequestBuilder {
+    id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListTagsForProjectRequestBuilder {
+    /// Sets the [`id`](struct.ListTagsForProjectRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListTagsForProjectRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsForProjectRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForProjectRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForProjectRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForProjectResult {
@@ -447,6 +965,52 @@ pub struct ListTeamMembersRequest {
     pub project_id: String,
 }
 
+impl ListTeamMembersRequest {
+    /// Returns a builder for `ListTeamMembersRequest`, checking its required members when built.
+    pub fn builder() -> ListTeamMembersRequestBuilder {
+        ListTeamMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTeamMembersRequest`](struct.ListTeamMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTeamMembersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    project_id: Option<String>,
+}
+
+impl ListTeamMembersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListTeamMembersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTeamMembersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_id`](struct.ListTeamMembersRequest.html#structfield.project_id) member.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTeamMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTeamMembersRequest, ::rusoto_core::BuildError> {
+        Ok(ListTeamMembersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            project_id: self
+                .project_id
+                .ok_or(::rusoto_core::BuildError::missing("project_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTeamMembersResult {
@@ -471,6 +1035,44 @@ pub struct ListUserProfilesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListUserProfilesRequest {
+    /// Returns a builder for `ListUserProfilesRequest`, checking its required members when built.
+    pub fn builder() -> ListUserProfilesRequestBuilder {
+        ListUserProfilesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUserProfilesRequest`](struct.ListUserProfilesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUserProfilesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListUserProfilesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListUserProfilesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUserProfilesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUserProfilesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListUserProfilesRequest, ::rusoto_core::BuildError> {
+        Ok(ListUserProfilesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListUserProfilesResult {
@@ -542,6 +1144,44 @@ pub struct TagProjectRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagProjectRequest {
+    /// Returns a builder for `TagProjectRequest`, checking its required members when built.
+    pub fn builder() -> TagProjectRequestBuilder {
+        TagProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagProjectRequest`](struct.TagProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct TagProjectRequestBuilder {
+    id: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagProjectRequestBuilder {
+    /// Sets the [`id`](struct.TagProjectRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagProjectRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagProjectRequest, ::rusoto_core::BuildError> {
+        Ok(TagProjectRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagProjectResult {
@@ -601,6 +1241,44 @@ pub struct UntagProjectRequest {
     pub tags: Vec<String>,
 }
 
+impl UntagProjectRequest {
+    /// Returns a builder for `UntagProjectRequest`, checking its required members when built.
+    pub fn builder() -> UntagProjectRequestBuilder {
+        UntagProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagProjectRequest`](struct.UntagProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagProjectRequestBuilder {
+    id: Option<String>,
+    tags: Option<Vec<String>>,
+}
+
+impl UntagProjectRequestBuilder {
+    /// Sets the [`id`](struct.UntagProjectRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.UntagProjectRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagProjectRequest, ::rusoto_core::BuildError> {
+        Ok(UntagProjectRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagProjectResult {}
@@ -620,6 +1298,50 @@ pub struct UpdateProjectRequest {
     pub name: Option<String>,
 }
 
+impl UpdateProjectRequest {
+    /// Returns a builder for `UpdateProjectRequest`, checking its required members when built.
+    pub fn builder() -> UpdateProjectRequestBuilder {
+        UpdateProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateProjectRequest`](struct.UpdateProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateProjectRequestBuilder {
+    description: Option<String>,
+    id: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateProjectRequestBuilder {
+    /// Sets the [`description`](struct.UpdateProjectRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateProjectRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateProjectRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateProjectRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateProjectRequest {
+            description: self.description,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateProjectResult {}
@@ -642,6 +1364,64 @@ pub struct UpdateTeamMemberRequest {
     pub user_arn: String,
 }
 
+impl UpdateTeamMemberRequest {
+    /// Returns a builder for `UpdateTeamMemberRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTeamMemberRequestBuilder {
+        UpdateTeamMemberRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTeamMemberRequest`](struct.UpdateTeamMemberRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTeamMemberRequestBuilder {
+    project_id: Option<String>,
+    project_role: Option<String>,
+    remote_access_allowed: Option<bool>,
+    user_arn: Option<String>,
+}
+
+impl UpdateTeamMemberRequestBuilder {
+    /// Sets the [`project_id`](struct.UpdateTeamMemberRequest.html#structfield.project_id) member.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_role`](struct.UpdateTeamMemberRequest.html#structfield.project_role) member.
+    pub fn project_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remote_access_allowed`](struct.UpdateTeamMemberRequest.html#structfield.remote_access_allowed) member.
+    pub fn remote_access_allowed<T: Into<bool>>(mut self, value: T) -> Self {
+        self.remote_access_allowed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_arn`](struct.UpdateTeamMemberRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTeamMemberRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateTeamMemberRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTeamMemberRequest {
+            project_id: self
+                .project_id
+                .ok_or(::rusoto_core::BuildError::missing("project_id"))?,
+            project_role: self.project_role,
+            remote_access_allowed: self.remote_access_allowed,
+            user_arn: self
+                .user_arn
+                .ok_or(::rusoto_core::BuildError::missing("user_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateTeamMemberResult {
@@ -678,13 +1458,70 @@ pub struct UpdateUserProfileRequest {
     pub user_arn: String,
 }
 
+impl UpdateUserProfileRequest {
+    /// Returns a builder for `UpdateUserProfileRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserProfileRequestBuilder {
+        UpdateUserProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserProfileRequest`](struct.UpdateUserProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserProfileRequestBuilder {
+    display_name: Option<String>,
+    email_address: Option<String>,
+    ssh_public_key: Option<String>,
+    user_arn: Option<String>,
+}
+
+impl UpdateUserProfileRequestBuilder {
+    /// Sets the [`display_name`](struct.UpdateUserProfileRequest.html#structfield.display_name) member.
+    pub fn display_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.display_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email_address`](struct.UpdateUserProfileRequest.html#structfield.email_address) member.
+    pub fn email_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.email_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_public_key`](struct.UpdateUserProfileRequest.html#structfield.ssh_public_key) member.
+    pub fn ssh_public_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_arn`](struct.UpdateUserProfileRequest.html#structfield.user_arn) member.
+    pub fn user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateUserProfileRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserProfileRequest {
+            display_name: self.display_name,
+            email_address: self.email_address,
+            ssh_public_key: self.ssh_public_key,
+            user_arn: self
+                .user_arn
+                .ok_or(::rusoto_core::BuildError::missing("user_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateUserProfileResult {
     /// <p>The date the user profile was created, in timestamp format.</p>
     #[serde(rename = "createdTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The name that is displayed as the friendly name for the user in AWS CodeStar.</p>
     #[serde(rename = "displayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -695,8 +1532,9 @@ pub struct UpdateUserProfileResult {
     pub email_address: Option<String>,
     /// <p>The date the user profile was last modified, in timestamp format.</p>
     #[serde(rename = "lastModifiedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_timestamp: Option<f64>,
+    pub last_modified_timestamp: Option<EpochTimestamp>,
     /// <p>The SSH public key associated with the user in AWS CodeStar. This is the public portion of the public/private keypair the user can use to access project resources if a project owner allows the user remote access to those resources.</p>
     #[serde(rename = "sshPublicKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1624,6 +2462,123 @@ pub trait CodeStar {
         input: UpdateUserProfileRequest,
     ) -> RusotoFuture<UpdateUserProfileResult, UpdateUserProfileError>;
 }
+impl<T: ?Sized + CodeStar> CodeStar for ::std::sync::Arc<T> {
+    fn associate_team_member(
+        &self,
+        input: AssociateTeamMemberRequest,
+    ) -> RusotoFuture<AssociateTeamMemberResult, AssociateTeamMemberError> {
+        (**self).associate_team_member(input)
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> RusotoFuture<CreateUserProfileResult, CreateUserProfileError> {
+        (**self).create_user_profile(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> RusotoFuture<DeleteUserProfileResult, DeleteUserProfileError> {
+        (**self).delete_user_profile(input)
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResult, DescribeProjectError> {
+        (**self).describe_project(input)
+    }
+    fn describe_user_profile(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> RusotoFuture<DescribeUserProfileResult, DescribeUserProfileError> {
+        (**self).describe_user_profile(input)
+    }
+    fn disassociate_team_member(
+        &self,
+        input: DisassociateTeamMemberRequest,
+    ) -> RusotoFuture<DisassociateTeamMemberResult, DisassociateTeamMemberError> {
+        (**self).disassociate_team_member(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResult, ListResourcesError> {
+        (**self).list_resources(input)
+    }
+    fn list_tags_for_project(
+        &self,
+        input: ListTagsForProjectRequest,
+    ) -> RusotoFuture<ListTagsForProjectResult, ListTagsForProjectError> {
+        (**self).list_tags_for_project(input)
+    }
+    fn list_team_members(
+        &self,
+        input: ListTeamMembersRequest,
+    ) -> RusotoFuture<ListTeamMembersResult, ListTeamMembersError> {
+        (**self).list_team_members(input)
+    }
+    fn list_user_profiles(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> RusotoFuture<ListUserProfilesResult, ListUserProfilesError> {
+        (**self).list_user_profiles(input)
+    }
+    fn tag_project(
+        &self,
+        input: TagProjectRequest,
+    ) -> RusotoFuture<TagProjectResult, TagProjectError> {
+        (**self).tag_project(input)
+    }
+    fn untag_project(
+        &self,
+        input: UntagProjectRequest,
+    ) -> RusotoFuture<UntagProjectResult, UntagProjectError> {
+        (**self).untag_project(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+    fn update_team_member(
+        &self,
+        input: UpdateTeamMemberRequest,
+    ) -> RusotoFuture<UpdateTeamMemberResult, UpdateTeamMemberError> {
+        (**self).update_team_member(input)
+    }
+    fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> RusotoFuture<UpdateUserProfileResult, UpdateUserProfileError> {
+        (**self).update_user_profile(input)
+    }
+}
+/// The error codes CodeStar retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the CodeStar API.
 #[derive(Clone)]
 pub struct CodeStarClient {
@@ -1639,6 +2594,27 @@ impl CodeStarClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CODESTAR`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CodeStarClient {
+        Self::new(region::Region::from_env_for_service("CODESTAR"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `codestar`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CodeStarClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("codestar", "CODESTAR"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1657,7 +2633,54 @@ impl CodeStarClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodeStarClient {
-        CodeStarClient { client, region }
+        CodeStarClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "codestar")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "codestar",
+            "codestar",
+            "CODESTAR",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CodeStar` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CodeStar + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CodeStarClient {
+    fn service_name(&self) -> &'static str {
+        "CodeStar"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "codestar"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CodeStarClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -22,7 +22,82 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>If you specify Token or Rules as the <code>Type</code>, <code>AmbiguousRoleResolution</code> is required.</p> <p>Specifies the action to be taken if either no rules match the claim value for the <code>Rules</code> type, or there is no <code>cognito:preferred_role</code> claim and there are multiple <code>cognito:roles</code> matches for the <code>Token</code> type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AmbiguousRoleResolutionType {
+    /// <p><code>AuthenticatedRole</code></p>
+    AuthenticatedRole,
+    /// <p><code>Deny</code></p>
+    Deny,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AmbiguousRoleResolutionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AmbiguousRoleResolutionType::AuthenticatedRole => "AuthenticatedRole",
+            AmbiguousRoleResolutionType::Deny => "Deny",
+            AmbiguousRoleResolutionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AmbiguousRoleResolutionType {
+    fn from(value: &'a str) -> AmbiguousRoleResolutionType {
+        match value {
+            "AuthenticatedRole" => AmbiguousRoleResolutionType::AuthenticatedRole,
+            "Deny" => AmbiguousRoleResolutionType::Deny,
+            _ => AmbiguousRoleResolutionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AmbiguousRoleResolutionType {
+    fn from(value: String) -> AmbiguousRoleResolutionType {
+        AmbiguousRoleResolutionType::from(value.as_str())
+    }
+}
+
+impl From<AmbiguousRoleResolutionType> for String {
+    fn from(value: AmbiguousRoleResolutionType) -> String {
+        match value {
+            AmbiguousRoleResolutionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AmbiguousRoleResolutionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AmbiguousRoleResolutionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AmbiguousRoleResolutionType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<AmbiguousRoleResolutionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AmbiguousRoleResolutionType::from(value))
+    }
+}
+
 /// <p>A provider representing an Amazon Cognito user pool and its client ID.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CognitoIdentityProvider {
@@ -75,6 +150,105 @@ pub struct CreateIdentityPoolInput {
     pub supported_login_providers: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateIdentityPoolInput {
+    /// Returns a builder for `CreateIdentityPoolInput`, checking its required members when built.
+    pub fn builder() -> CreateIdentityPoolInputBuilder {
+        CreateIdentityPoolInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateIdentityPoolInput`](struct.CreateIdentityPoolInput.html).
+#[derive(Default, Debug)]
+pub struct CreateIdentityPoolInputBuilder {
+    allow_unauthenticated_identities: Option<bool>,
+    cognito_identity_providers: Option<Vec<CognitoIdentityProvider>>,
+    developer_provider_name: Option<String>,
+    identity_pool_name: Option<String>,
+    identity_pool_tags: Option<::std::collections::HashMap<String, String>>,
+    open_id_connect_provider_ar_ns: Option<Vec<String>>,
+    saml_provider_ar_ns: Option<Vec<String>>,
+    supported_login_providers: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateIdentityPoolInputBuilder {
+    /// Sets the [`allow_unauthenticated_identities`](struct.CreateIdentityPoolInput.html#structfield.allow_unauthenticated_identities) member.
+    pub fn allow_unauthenticated_identities<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_unauthenticated_identities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cognito_identity_providers`](struct.CreateIdentityPoolInput.html#structfield.cognito_identity_providers) member.
+    pub fn cognito_identity_providers<T: Into<Vec<CognitoIdentityProvider>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cognito_identity_providers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`developer_provider_name`](struct.CreateIdentityPoolInput.html#structfield.developer_provider_name) member.
+    pub fn developer_provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.developer_provider_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_name`](struct.CreateIdentityPoolInput.html#structfield.identity_pool_name) member.
+    pub fn identity_pool_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_tags`](struct.CreateIdentityPoolInput.html#structfield.identity_pool_tags) member.
+    pub fn identity_pool_tags<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.identity_pool_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`open_id_connect_provider_ar_ns`](struct.CreateIdentityPoolInput.html#structfield.open_id_connect_provider_ar_ns) member.
+    pub fn open_id_connect_provider_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.open_id_connect_provider_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`saml_provider_ar_ns`](struct.CreateIdentityPoolInput.html#structfield.saml_provider_ar_ns) member.
+    pub fn saml_provider_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.saml_provider_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`supported_login_providers`](struct.CreateIdentityPoolInput.html#structfield.supported_login_providers) member.
+    pub fn supported_login_providers<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.supported_login_providers = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateIdentityPoolInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateIdentityPoolInput, ::rusoto_core::BuildError> {
+        Ok(CreateIdentityPoolInput {
+            allow_unauthenticated_identities: self.allow_unauthenticated_identities.ok_or(
+                ::rusoto_core::BuildError::missing("allow_unauthenticated_identities"),
+            )?,
+            cognito_identity_providers: self.cognito_identity_providers,
+            developer_provider_name: self.developer_provider_name,
+            identity_pool_name: self
+                .identity_pool_name
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_name"))?,
+            identity_pool_tags: self.identity_pool_tags,
+            open_id_connect_provider_ar_ns: self.open_id_connect_provider_ar_ns,
+            saml_provider_ar_ns: self.saml_provider_ar_ns,
+            supported_login_providers: self.supported_login_providers,
+        })
+    }
+}
+
 /// <p>Credentials for the provided identity ID.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -85,8 +259,9 @@ pub struct Credentials {
     pub access_key_id: Option<String>,
     /// <p>The date at which these credentials will expire.</p>
     #[serde(rename = "Expiration")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration: Option<f64>,
+    pub expiration: Option<EpochTimestamp>,
     /// <p>The Secret Access Key portion of the credentials</p>
     #[serde(rename = "SecretKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -105,6 +280,36 @@ pub struct DeleteIdentitiesInput {
     pub identity_ids_to_delete: Vec<String>,
 }
 
+impl DeleteIdentitiesInput {
+    /// Returns a builder for `DeleteIdentitiesInput`, checking its required members when built.
+    pub fn builder() -> DeleteIdentitiesInputBuilder {
+        DeleteIdentitiesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIdentitiesInput`](struct.DeleteIdentitiesInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteIdentitiesInputBuilder {
+    identity_ids_to_delete: Option<Vec<String>>,
+}
+
+impl DeleteIdentitiesInputBuilder {
+    /// Sets the [`identity_ids_to_delete`](struct.DeleteIdentitiesInput.html#structfield.identity_ids_to_delete) member.
+    pub fn identity_ids_to_delete<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.identity_ids_to_delete = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIdentitiesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteIdentitiesInput, ::rusoto_core::BuildError> {
+        Ok(DeleteIdentitiesInput {
+            identity_ids_to_delete: self
+                .identity_ids_to_delete
+                .ok_or(::rusoto_core::BuildError::missing("identity_ids_to_delete"))?,
+        })
+    }
+}
+
 /// <p>Returned in response to a successful <code>DeleteIdentities</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -123,6 +328,38 @@ pub struct DeleteIdentityPoolInput {
     pub identity_pool_id: String,
 }
 
+impl DeleteIdentityPoolInput {
+    /// Returns a builder for `DeleteIdentityPoolInput`, checking its required members when built.
+    pub fn builder() -> DeleteIdentityPoolInputBuilder {
+        DeleteIdentityPoolInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIdentityPoolInput`](struct.DeleteIdentityPoolInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteIdentityPoolInputBuilder {
+    identity_pool_id: Option<String>,
+}
+
+impl DeleteIdentityPoolInputBuilder {
+    /// Sets the [`identity_pool_id`](struct.DeleteIdentityPoolInput.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIdentityPoolInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteIdentityPoolInput, ::rusoto_core::BuildError> {
+        Ok(DeleteIdentityPoolInput {
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Input to the <code>DescribeIdentity</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeIdentityInput {
@@ -131,6 +368,36 @@ pub struct DescribeIdentityInput {
     pub identity_id: String,
 }
 
+impl DescribeIdentityInput {
+    /// Returns a builder for `DescribeIdentityInput`, checking its required members when built.
+    pub fn builder() -> DescribeIdentityInputBuilder {
+        DescribeIdentityInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeIdentityInput`](struct.DescribeIdentityInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeIdentityInputBuilder {
+    identity_id: Option<String>,
+}
+
+impl DescribeIdentityInputBuilder {
+    /// Sets the [`identity_id`](struct.DescribeIdentityInput.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeIdentityInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeIdentityInput, ::rusoto_core::BuildError> {
+        Ok(DescribeIdentityInput {
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+        })
+    }
+}
+
 /// <p>Input to the DescribeIdentityPool action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeIdentityPoolInput {
@@ -139,6 +406,110 @@ pub struct DescribeIdentityPoolInput {
     pub identity_pool_id: String,
 }
 
+impl DescribeIdentityPoolInput {
+    /// Returns a builder for `DescribeIdentityPoolInput`, checking its required members when built.
+    pub fn builder() -> DescribeIdentityPoolInputBuilder {
+        DescribeIdentityPoolInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeIdentityPoolInput`](struct.DescribeIdentityPoolInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeIdentityPoolInputBuilder {
+    identity_pool_id: Option<String>,
+}
+
+impl DescribeIdentityPoolInputBuilder {
+    /// Sets the [`identity_pool_id`](struct.DescribeIdentityPoolInput.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeIdentityPoolInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeIdentityPoolInput, ::rusoto_core::BuildError> {
+        Ok(DescribeIdentityPoolInput {
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
+/// <p>The error code indicating the type of error that occurred.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    /// <p><code>AccessDenied</code></p>
+    AccessDenied,
+    /// <p><code>InternalServerError</code></p>
+    InternalServerError,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::AccessDenied => "AccessDenied",
+            ErrorCode::InternalServerError => "InternalServerError",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "AccessDenied" => ErrorCode::AccessDenied,
+            "InternalServerError" => ErrorCode::InternalServerError,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        ErrorCode::from(value.as_str())
+    }
+}
+
+impl From<ErrorCode> for String {
+    fn from(value: ErrorCode) -> String {
+        match value {
+            ErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ErrorCode::from(value))
+    }
+}
+
 /// <p>Input to the <code>GetCredentialsForIdentity</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetCredentialsForIdentityInput {
@@ -155,6 +526,57 @@ pub struct GetCredentialsForIdentityInput {
     pub logins: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl GetCredentialsForIdentityInput {
+    /// Returns a builder for `GetCredentialsForIdentityInput`, checking its required members when built.
+    pub fn builder() -> GetCredentialsForIdentityInputBuilder {
+        GetCredentialsForIdentityInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetCredentialsForIdentityInput`](struct.GetCredentialsForIdentityInput.html).
+#[derive(Default, Debug)]
+pub struct GetCredentialsForIdentityInputBuilder {
+    custom_role_arn: Option<String>,
+    identity_id: Option<String>,
+    logins: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl GetCredentialsForIdentityInputBuilder {
+    /// Sets the [`custom_role_arn`](struct.GetCredentialsForIdentityInput.html#structfield.custom_role_arn) member.
+    pub fn custom_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_id`](struct.GetCredentialsForIdentityInput.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logins`](struct.GetCredentialsForIdentityInput.html#structfield.logins) member.
+    pub fn logins<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.logins = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCredentialsForIdentityInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCredentialsForIdentityInput, ::rusoto_core::BuildError> {
+        Ok(GetCredentialsForIdentityInput {
+            custom_role_arn: self.custom_role_arn,
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            logins: self.logins,
+        })
+    }
+}
+
 /// <p>Returned in response to a successful <code>GetCredentialsForIdentity</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -185,6 +607,55 @@ pub struct GetIdInput {
     pub logins: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl GetIdInput {
+    /// Returns a builder for `GetIdInput`, checking its required members when built.
+    pub fn builder() -> GetIdInputBuilder {
+        GetIdInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetIdInput`](struct.GetIdInput.html).
+#[derive(Default, Debug)]
+pub struct GetIdInputBuilder {
+    account_id: Option<String>,
+    identity_pool_id: Option<String>,
+    logins: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl GetIdInputBuilder {
+    /// Sets the [`account_id`](struct.GetIdInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.GetIdInput.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logins`](struct.GetIdInput.html#structfield.logins) member.
+    pub fn logins<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.logins = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIdInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetIdInput, ::rusoto_core::BuildError> {
+        Ok(GetIdInput {
+            account_id: self.account_id,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+            logins: self.logins,
+        })
+    }
+}
+
 /// <p>Returned in response to a GetId request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -203,6 +674,38 @@ pub struct GetIdentityPoolRolesInput {
     pub identity_pool_id: String,
 }
 
+impl GetIdentityPoolRolesInput {
+    /// Returns a builder for `GetIdentityPoolRolesInput`, checking its required members when built.
+    pub fn builder() -> GetIdentityPoolRolesInputBuilder {
+        GetIdentityPoolRolesInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetIdentityPoolRolesInput`](struct.GetIdentityPoolRolesInput.html).
+#[derive(Default, Debug)]
+pub struct GetIdentityPoolRolesInputBuilder {
+    identity_pool_id: Option<String>,
+}
+
+impl GetIdentityPoolRolesInputBuilder {
+    /// Sets the [`identity_pool_id`](struct.GetIdentityPoolRolesInput.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIdentityPoolRolesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIdentityPoolRolesInput, ::rusoto_core::BuildError> {
+        Ok(GetIdentityPoolRolesInput {
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Returned in response to a successful <code>GetIdentityPoolRoles</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -240,6 +743,68 @@ pub struct GetOpenIdTokenForDeveloperIdentityInput {
     pub token_duration: Option<i64>,
 }
 
+impl GetOpenIdTokenForDeveloperIdentityInput {
+    /// Returns a builder for `GetOpenIdTokenForDeveloperIdentityInput`, checking its required members when built.
+    pub fn builder() -> GetOpenIdTokenForDeveloperIdentityInputBuilder {
+        GetOpenIdTokenForDeveloperIdentityInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetOpenIdTokenForDeveloperIdentityInput`](struct.GetOpenIdTokenForDeveloperIdentityInput.html).
+#[derive(Default, Debug)]
+pub struct GetOpenIdTokenForDeveloperIdentityInputBuilder {
+    identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+    logins: Option<::std::collections::HashMap<String, String>>,
+    token_duration: Option<i64>,
+}
+
+impl GetOpenIdTokenForDeveloperIdentityInputBuilder {
+    /// Sets the [`identity_id`](struct.GetOpenIdTokenForDeveloperIdentityInput.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.GetOpenIdTokenForDeveloperIdentityInput.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logins`](struct.GetOpenIdTokenForDeveloperIdentityInput.html#structfield.logins) member.
+    pub fn logins<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.logins = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token_duration`](struct.GetOpenIdTokenForDeveloperIdentityInput.html#structfield.token_duration) member.
+    pub fn token_duration<T: Into<i64>>(mut self, value: T) -> Self {
+        self.token_duration = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOpenIdTokenForDeveloperIdentityInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetOpenIdTokenForDeveloperIdentityInput, ::rusoto_core::BuildError>
+    {
+        Ok(GetOpenIdTokenForDeveloperIdentityInput {
+            identity_id: self.identity_id,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+            logins: self
+                .logins
+                .ok_or(::rusoto_core::BuildError::missing("logins"))?,
+            token_duration: self.token_duration,
+        })
+    }
+}
+
 /// <p>Returned in response to a successful <code>GetOpenIdTokenForDeveloperIdentity</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -266,6 +831,47 @@ pub struct GetOpenIdTokenInput {
     pub logins: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl GetOpenIdTokenInput {
+    /// Returns a builder for `GetOpenIdTokenInput`, checking its required members when built.
+    pub fn builder() -> GetOpenIdTokenInputBuilder {
+        GetOpenIdTokenInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetOpenIdTokenInput`](struct.GetOpenIdTokenInput.html).
+#[derive(Default, Debug)]
+pub struct GetOpenIdTokenInputBuilder {
+    identity_id: Option<String>,
+    logins: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl GetOpenIdTokenInputBuilder {
+    /// Sets the [`identity_id`](struct.GetOpenIdTokenInput.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logins`](struct.GetOpenIdTokenInput.html#structfield.logins) member.
+    pub fn logins<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.logins = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOpenIdTokenInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetOpenIdTokenInput, ::rusoto_core::BuildError> {
+        Ok(GetOpenIdTokenInput {
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            logins: self.logins,
+        })
+    }
+}
+
 /// <p>Returned in response to a successful GetOpenIdToken request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -286,16 +892,18 @@ pub struct GetOpenIdTokenResponse {
 pub struct IdentityDescription {
     /// <p>Date on which the identity was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>A unique identifier in the format REGION:GUID.</p>
     #[serde(rename = "IdentityId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub identity_id: Option<String>,
     /// <p>Date on which the identity was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The provider names.</p>
     #[serde(rename = "Logins")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -340,6 +948,113 @@ pub struct IdentityPool {
     pub supported_login_providers: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl IdentityPool {
+    /// Returns a builder for `IdentityPool`, checking its required members when built.
+    pub fn builder() -> IdentityPoolBuilder {
+        IdentityPoolBuilder::default()
+    }
+}
+
+/// Builds a [`IdentityPool`](struct.IdentityPool.html).
+#[derive(Default, Debug)]
+pub struct IdentityPoolBuilder {
+    allow_unauthenticated_identities: Option<bool>,
+    cognito_identity_providers: Option<Vec<CognitoIdentityProvider>>,
+    developer_provider_name: Option<String>,
+    identity_pool_id: Option<String>,
+    identity_pool_name: Option<String>,
+    identity_pool_tags: Option<::std::collections::HashMap<String, String>>,
+    open_id_connect_provider_ar_ns: Option<Vec<String>>,
+    saml_provider_ar_ns: Option<Vec<String>>,
+    supported_login_providers: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl IdentityPoolBuilder {
+    /// Sets the [`allow_unauthenticated_identities`](struct.IdentityPool.html#structfield.allow_unauthenticated_identities) member.
+    pub fn allow_unauthenticated_identities<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_unauthenticated_identities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cognito_identity_providers`](struct.IdentityPool.html#structfield.cognito_identity_providers) member.
+    pub fn cognito_identity_providers<T: Into<Vec<CognitoIdentityProvider>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cognito_identity_providers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`developer_provider_name`](struct.IdentityPool.html#structfield.developer_provider_name) member.
+    pub fn developer_provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.developer_provider_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.IdentityPool.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_name`](struct.IdentityPool.html#structfield.identity_pool_name) member.
+    pub fn identity_pool_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_tags`](struct.IdentityPool.html#structfield.identity_pool_tags) member.
+    pub fn identity_pool_tags<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.identity_pool_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`open_id_connect_provider_ar_ns`](struct.IdentityPool.html#structfield.open_id_connect_provider_ar_ns) member.
+    pub fn open_id_connect_provider_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.open_id_connect_provider_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`saml_provider_ar_ns`](struct.IdentityPool.html#structfield.saml_provider_ar_ns) member.
+    pub fn saml_provider_ar_ns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.saml_provider_ar_ns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`supported_login_providers`](struct.IdentityPool.html#structfield.supported_login_providers) member.
+    pub fn supported_login_providers<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.supported_login_providers = Some(value.into());
+        self
+    }
+
+    /// Builds the `IdentityPool`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<IdentityPool, ::rusoto_core::BuildError> {
+        Ok(IdentityPool {
+            allow_unauthenticated_identities: self.allow_unauthenticated_identities.ok_or(
+                ::rusoto_core::BuildError::missing("allow_unauthenticated_identities"),
+            )?,
+            cognito_identity_providers: self.cognito_identity_providers,
+            developer_provider_name: self.developer_provider_name,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+            identity_pool_name: self
+                .identity_pool_name
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_name"))?,
+            identity_pool_tags: self.identity_pool_tags,
+            open_id_connect_provider_ar_ns: self.open_id_connect_provider_ar_ns,
+            saml_provider_ar_ns: self.saml_provider_ar_ns,
+            supported_login_providers: self.supported_login_providers,
+        })
+    }
+}
+
 /// <p>A description of the identity pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -373,6 +1088,62 @@ pub struct ListIdentitiesInput {
     pub next_token: Option<String>,
 }
 
+impl ListIdentitiesInput {
+    /// Returns a builder for `ListIdentitiesInput`, checking its required members when built.
+    pub fn builder() -> ListIdentitiesInputBuilder {
+        ListIdentitiesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListIdentitiesInput`](struct.ListIdentitiesInput.html).
+#[derive(Default, Debug)]
+pub struct ListIdentitiesInputBuilder {
+    hide_disabled: Option<bool>,
+    identity_pool_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListIdentitiesInputBuilder {
+    /// Sets the [`hide_disabled`](struct.ListIdentitiesInput.html#structfield.hide_disabled) member.
+    pub fn hide_disabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.hide_disabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.ListIdentitiesInput.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListIdentitiesInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListIdentitiesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListIdentitiesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListIdentitiesInput, ::rusoto_core::BuildError> {
+        Ok(ListIdentitiesInput {
+            hide_disabled: self.hide_disabled,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+            max_results: self
+                .max_results
+                .ok_or(::rusoto_core::BuildError::missing("max_results"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>The response to a ListIdentities request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -403,6 +1174,44 @@ pub struct ListIdentityPoolsInput {
     pub next_token: Option<String>,
 }
 
+impl ListIdentityPoolsInput {
+    /// Returns a builder for `ListIdentityPoolsInput`, checking its required members when built.
+    pub fn builder() -> ListIdentityPoolsInputBuilder {
+        ListIdentityPoolsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListIdentityPoolsInput`](struct.ListIdentityPoolsInput.html).
+#[derive(Default, Debug)]
+pub struct ListIdentityPoolsInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListIdentityPoolsInputBuilder {
+    /// Sets the [`max_results`](struct.ListIdentityPoolsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListIdentityPoolsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListIdentityPoolsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListIdentityPoolsInput, ::rusoto_core::BuildError> {
+        Ok(ListIdentityPoolsInput {
+            max_results: self
+                .max_results
+                .ok_or(::rusoto_core::BuildError::missing("max_results"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>The result of a successful ListIdentityPools action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -424,6 +1233,38 @@ pub struct ListTagsForResourceInput {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceInput {
+    /// Returns a builder for `ListTagsForResourceInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceInputBuilder {
+        ListTagsForResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceInput`](struct.ListTagsForResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceInputBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -457,6 +1298,70 @@ pub struct LookupDeveloperIdentityInput {
     pub next_token: Option<String>,
 }
 
+impl LookupDeveloperIdentityInput {
+    /// Returns a builder for `LookupDeveloperIdentityInput`, checking its required members when built.
+    pub fn builder() -> LookupDeveloperIdentityInputBuilder {
+        LookupDeveloperIdentityInputBuilder::default()
+    }
+}
+
+/// Builds a [`LookupDeveloperIdentityInput`](struct.LookupDeveloperIdentityInput.html).
+#[derive(Default, Debug)]
+pub struct LookupDeveloperIdentityInputBuilder {
+    developer_user_identifier: Option<String>,
+    identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl LookupDeveloperIdentityInputBuilder {
+    /// Sets the [`developer_user_identifier`](struct.LookupDeveloperIdentityInput.html#structfield.developer_user_identifier) member.
+    pub fn developer_user_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.developer_user_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_id`](struct.LookupDeveloperIdentityInput.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.LookupDeveloperIdentityInput.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.LookupDeveloperIdentityInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.LookupDeveloperIdentityInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `LookupDeveloperIdentityInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<LookupDeveloperIdentityInput, ::rusoto_core::BuildError> {
+        Ok(LookupDeveloperIdentityInput {
+            developer_user_identifier: self.developer_user_identifier,
+            identity_id: self.identity_id,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Returned in response to a successful <code>LookupDeveloperIdentity</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -476,14 +1381,14 @@ pub struct LookupDeveloperIdentityResponse {
 }
 
 /// <p>A rule that maps a claim name, a claim value, and a match type to a role ARN.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MappingRule {
     /// <p>The claim name that must be present in the token, for example, "isAdmin" or "paid".</p>
     #[serde(rename = "Claim")]
     pub claim: String,
     /// <p>The match condition that specifies how closely the claim value in the IdP token must match <code>Value</code>.</p>
     #[serde(rename = "MatchType")]
-    pub match_type: String,
+    pub match_type: MappingRuleMatchType,
     /// <p>The role ARN.</p>
     #[serde(rename = "RoleARN")]
     pub role_arn: String,
@@ -492,6 +1397,86 @@ pub struct MappingRule {
     pub value: String,
 }
 
+/// <p>The match condition that specifies how closely the claim value in the IdP token must match <code>Value</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MappingRuleMatchType {
+    /// <p><code>Equals</code></p>
+    Equals,
+    /// <p><code>Contains</code></p>
+    Contains,
+    /// <p><code>StartsWith</code></p>
+    StartsWith,
+    /// <p><code>NotEqual</code></p>
+    NotEqual,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MappingRuleMatchType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MappingRuleMatchType::Equals => "Equals",
+            MappingRuleMatchType::Contains => "Contains",
+            MappingRuleMatchType::StartsWith => "StartsWith",
+            MappingRuleMatchType::NotEqual => "NotEqual",
+            MappingRuleMatchType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MappingRuleMatchType {
+    fn from(value: &'a str) -> MappingRuleMatchType {
+        match value {
+            "Equals" => MappingRuleMatchType::Equals,
+            "Contains" => MappingRuleMatchType::Contains,
+            "StartsWith" => MappingRuleMatchType::StartsWith,
+            "NotEqual" => MappingRuleMatchType::NotEqual,
+            _ => MappingRuleMatchType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MappingRuleMatchType {
+    fn from(value: String) -> MappingRuleMatchType {
+        MappingRuleMatchType::from(value.as_str())
+    }
+}
+
+impl From<MappingRuleMatchType> for String {
+    fn from(value: MappingRuleMatchType) -> String {
+        match value {
+            MappingRuleMatchType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MappingRuleMatchType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MappingRuleMatchType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MappingRuleMatchType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MappingRuleMatchType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MappingRuleMatchType::from(value))
+    }
+}
+
 /// <p>Input to the <code>MergeDeveloperIdentities</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct MergeDeveloperIdentitiesInput {
@@ -509,6 +1494,68 @@ pub struct MergeDeveloperIdentitiesInput {
     pub source_user_identifier: String,
 }
 
+impl MergeDeveloperIdentitiesInput {
+    /// Returns a builder for `MergeDeveloperIdentitiesInput`, checking its required members when built.
+    pub fn builder() -> MergeDeveloperIdentitiesInputBuilder {
+        MergeDeveloperIdentitiesInputBuilder::default()
+    }
+}
+
+/// Builds a [`MergeDeveloperIdentitiesInput`](struct.MergeDeveloperIdentitiesInput.html).
+#[derive(Default, Debug)]
+pub struct MergeDeveloperIdentitiesInputBuilder {
+    destination_user_identifier: Option<String>,
+    developer_provider_name: Option<String>,
+    identity_pool_id: Option<String>,
+    source_user_identifier: Option<String>,
+}
+
+impl MergeDeveloperIdentitiesInputBuilder {
+    /// Sets the [`destination_user_identifier`](struct.MergeDeveloperIdentitiesInput.html#structfield.destination_user_identifier) member.
+    pub fn destination_user_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_user_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`developer_provider_name`](struct.MergeDeveloperIdentitiesInput.html#structfield.developer_provider_name) member.
+    pub fn developer_provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.developer_provider_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.MergeDeveloperIdentitiesInput.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_user_identifier`](struct.MergeDeveloperIdentitiesInput.html#structfield.source_user_identifier) member.
+    pub fn source_user_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_user_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `MergeDeveloperIdentitiesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<MergeDeveloperIdentitiesInput, ::rusoto_core::BuildError> {
+        Ok(MergeDeveloperIdentitiesInput {
+            destination_user_identifier: self.destination_user_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("destination_user_identifier"),
+            )?,
+            developer_provider_name: self.developer_provider_name.ok_or(
+                ::rusoto_core::BuildError::missing("developer_provider_name"),
+            )?,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+            source_user_identifier: self
+                .source_user_identifier
+                .ok_or(::rusoto_core::BuildError::missing("source_user_identifier"))?,
+        })
+    }
+}
+
 /// <p>Returned in response to a successful <code>MergeDeveloperIdentities</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -520,19 +1567,91 @@ pub struct MergeDeveloperIdentitiesResponse {
 }
 
 /// <p>A role mapping.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RoleMapping {
     /// <p>If you specify Token or Rules as the <code>Type</code>, <code>AmbiguousRoleResolution</code> is required.</p> <p>Specifies the action to be taken if either no rules match the claim value for the <code>Rules</code> type, or there is no <code>cognito:preferred_role</code> claim and there are multiple <code>cognito:roles</code> matches for the <code>Token</code> type.</p>
     #[serde(rename = "AmbiguousRoleResolution")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ambiguous_role_resolution: Option<String>,
+    pub ambiguous_role_resolution: Option<AmbiguousRoleResolutionType>,
     /// <p>The rules to be used for mapping users to roles.</p> <p>If you specify Rules as the role mapping type, <code>RulesConfiguration</code> is required.</p>
     #[serde(rename = "RulesConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rules_configuration: Option<RulesConfigurationType>,
     /// <p>The role mapping type. Token will use <code>cognito:roles</code> and <code>cognito:preferred_role</code> claims from the Cognito identity provider token to map groups to roles. Rules will attempt to match claims from the token to map to a role.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: RoleMappingType,
+}
+
+/// <p>The role mapping type. Token will use <code>cognito:roles</code> and <code>cognito:preferred_role</code> claims from the Cognito identity provider token to map groups to roles. Rules will attempt to match claims from the token to map to a role.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoleMappingType {
+    /// <p><code>Token</code></p>
+    Token,
+    /// <p><code>Rules</code></p>
+    Rules,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RoleMappingType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RoleMappingType::Token => "Token",
+            RoleMappingType::Rules => "Rules",
+            RoleMappingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RoleMappingType {
+    fn from(value: &'a str) -> RoleMappingType {
+        match value {
+            "Token" => RoleMappingType::Token,
+            "Rules" => RoleMappingType::Rules,
+            _ => RoleMappingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RoleMappingType {
+    fn from(value: String) -> RoleMappingType {
+        RoleMappingType::from(value.as_str())
+    }
+}
+
+impl From<RoleMappingType> for String {
+    fn from(value: RoleMappingType) -> String {
+        match value {
+            RoleMappingType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RoleMappingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RoleMappingType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RoleMappingType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RoleMappingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RoleMappingType::from(value))
+    }
 }
 
 /// <p>A container for rules.</p>
@@ -558,6 +1677,59 @@ pub struct SetIdentityPoolRolesInput {
     pub roles: ::std::collections::HashMap<String, String>,
 }
 
+impl SetIdentityPoolRolesInput {
+    /// Returns a builder for `SetIdentityPoolRolesInput`, checking its required members when built.
+    pub fn builder() -> SetIdentityPoolRolesInputBuilder {
+        SetIdentityPoolRolesInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetIdentityPoolRolesInput`](struct.SetIdentityPoolRolesInput.html).
+#[derive(Default, Debug)]
+pub struct SetIdentityPoolRolesInputBuilder {
+    identity_pool_id: Option<String>,
+    role_mappings: Option<::std::collections::HashMap<String, RoleMapping>>,
+    roles: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl SetIdentityPoolRolesInputBuilder {
+    /// Sets the [`identity_pool_id`](struct.SetIdentityPoolRolesInput.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_mappings`](struct.SetIdentityPoolRolesInput.html#structfield.role_mappings) member.
+    pub fn role_mappings<T: Into<::std::collections::HashMap<String, RoleMapping>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.role_mappings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`roles`](struct.SetIdentityPoolRolesInput.html#structfield.roles) member.
+    pub fn roles<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.roles = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetIdentityPoolRolesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetIdentityPoolRolesInput, ::rusoto_core::BuildError> {
+        Ok(SetIdentityPoolRolesInput {
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+            role_mappings: self.role_mappings,
+            roles: self
+                .roles
+                .ok_or(::rusoto_core::BuildError::missing("roles"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TagResourceInput {
     /// <p>The Amazon Resource Name (ARN) of the identity pool to assign the tags to.</p>
@@ -569,6 +1741,44 @@ pub struct TagResourceInput {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl TagResourceInput {
+    /// Returns a builder for `TagResourceInput`, checking its required members when built.
+    pub fn builder() -> TagResourceInputBuilder {
+        TagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceInput`](struct.TagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct TagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceInput, ::rusoto_core::BuildError> {
+        Ok(TagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -590,6 +1800,68 @@ pub struct UnlinkDeveloperIdentityInput {
     pub identity_pool_id: String,
 }
 
+impl UnlinkDeveloperIdentityInput {
+    /// Returns a builder for `UnlinkDeveloperIdentityInput`, checking its required members when built.
+    pub fn builder() -> UnlinkDeveloperIdentityInputBuilder {
+        UnlinkDeveloperIdentityInputBuilder::default()
+    }
+}
+
+/// Builds a [`UnlinkDeveloperIdentityInput`](struct.UnlinkDeveloperIdentityInput.html).
+#[derive(Default, Debug)]
+pub struct UnlinkDeveloperIdentityInputBuilder {
+    developer_provider_name: Option<String>,
+    developer_user_identifier: Option<String>,
+    identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+}
+
+impl UnlinkDeveloperIdentityInputBuilder {
+    /// Sets the [`developer_provider_name`](struct.UnlinkDeveloperIdentityInput.html#structfield.developer_provider_name) member.
+    pub fn developer_provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.developer_provider_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`developer_user_identifier`](struct.UnlinkDeveloperIdentityInput.html#structfield.developer_user_identifier) member.
+    pub fn developer_user_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.developer_user_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_id`](struct.UnlinkDeveloperIdentityInput.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.UnlinkDeveloperIdentityInput.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UnlinkDeveloperIdentityInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UnlinkDeveloperIdentityInput, ::rusoto_core::BuildError> {
+        Ok(UnlinkDeveloperIdentityInput {
+            developer_provider_name: self.developer_provider_name.ok_or(
+                ::rusoto_core::BuildError::missing("developer_provider_name"),
+            )?,
+            developer_user_identifier: self.developer_user_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("developer_user_identifier"),
+            )?,
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Input to the UnlinkIdentity action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UnlinkIdentityInput {
@@ -604,6 +1876,59 @@ pub struct UnlinkIdentityInput {
     pub logins_to_remove: Vec<String>,
 }
 
+impl UnlinkIdentityInput {
+    /// Returns a builder for `UnlinkIdentityInput`, checking its required members when built.
+    pub fn builder() -> UnlinkIdentityInputBuilder {
+        UnlinkIdentityInputBuilder::default()
+    }
+}
+
+/// Builds a [`UnlinkIdentityInput`](struct.UnlinkIdentityInput.html).
+#[derive(Default, Debug)]
+pub struct UnlinkIdentityInputBuilder {
+    identity_id: Option<String>,
+    logins: Option<::std::collections::HashMap<String, String>>,
+    logins_to_remove: Option<Vec<String>>,
+}
+
+impl UnlinkIdentityInputBuilder {
+    /// Sets the [`identity_id`](struct.UnlinkIdentityInput.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logins`](struct.UnlinkIdentityInput.html#structfield.logins) member.
+    pub fn logins<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.logins = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logins_to_remove`](struct.UnlinkIdentityInput.html#structfield.logins_to_remove) member.
+    pub fn logins_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.logins_to_remove = Some(value.into());
+        self
+    }
+
+    /// Builds the `UnlinkIdentityInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UnlinkIdentityInput, ::rusoto_core::BuildError> {
+        Ok(UnlinkIdentityInput {
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            logins: self
+                .logins
+                .ok_or(::rusoto_core::BuildError::missing("logins"))?,
+            logins_to_remove: self
+                .logins_to_remove
+                .ok_or(::rusoto_core::BuildError::missing("logins_to_remove"))?,
+        })
+    }
+}
+
 /// <p>An array of UnprocessedIdentityId objects, each of which contains an ErrorCode and IdentityId.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -611,7 +1936,7 @@ pub struct UnprocessedIdentityId {
     /// <p>The error code indicating the type of error that occurred.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>A unique identifier in the format REGION:GUID.</p>
     #[serde(rename = "IdentityId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -629,6 +1954,44 @@ pub struct UntagResourceInput {
     pub tag_keys: Option<Vec<String>>,
 }
 
+impl UntagResourceInput {
+    /// Returns a builder for `UntagResourceInput`, checking its required members when built.
+    pub fn builder() -> UntagResourceInputBuilder {
+        UntagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceInput`](struct.UntagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceInput, ::rusoto_core::BuildError> {
+        Ok(UntagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self.tag_keys,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -2177,6 +3540,138 @@ pub trait CognitoIdentity {
         input: IdentityPool,
     ) -> RusotoFuture<IdentityPool, UpdateIdentityPoolError>;
 }
+impl<T: ?Sized + CognitoIdentity> CognitoIdentity for ::std::sync::Arc<T> {
+    fn create_identity_pool(
+        &self,
+        input: CreateIdentityPoolInput,
+    ) -> RusotoFuture<IdentityPool, CreateIdentityPoolError> {
+        (**self).create_identity_pool(input)
+    }
+    fn delete_identities(
+        &self,
+        input: DeleteIdentitiesInput,
+    ) -> RusotoFuture<DeleteIdentitiesResponse, DeleteIdentitiesError> {
+        (**self).delete_identities(input)
+    }
+    fn delete_identity_pool(
+        &self,
+        input: DeleteIdentityPoolInput,
+    ) -> RusotoFuture<(), DeleteIdentityPoolError> {
+        (**self).delete_identity_pool(input)
+    }
+    fn describe_identity(
+        &self,
+        input: DescribeIdentityInput,
+    ) -> RusotoFuture<IdentityDescription, DescribeIdentityError> {
+        (**self).describe_identity(input)
+    }
+    fn describe_identity_pool(
+        &self,
+        input: DescribeIdentityPoolInput,
+    ) -> RusotoFuture<IdentityPool, DescribeIdentityPoolError> {
+        (**self).describe_identity_pool(input)
+    }
+    fn get_credentials_for_identity(
+        &self,
+        input: GetCredentialsForIdentityInput,
+    ) -> RusotoFuture<GetCredentialsForIdentityResponse, GetCredentialsForIdentityError> {
+        (**self).get_credentials_for_identity(input)
+    }
+    fn get_id(&self, input: GetIdInput) -> RusotoFuture<GetIdResponse, GetIdError> {
+        (**self).get_id(input)
+    }
+    fn get_identity_pool_roles(
+        &self,
+        input: GetIdentityPoolRolesInput,
+    ) -> RusotoFuture<GetIdentityPoolRolesResponse, GetIdentityPoolRolesError> {
+        (**self).get_identity_pool_roles(input)
+    }
+    fn get_open_id_token(
+        &self,
+        input: GetOpenIdTokenInput,
+    ) -> RusotoFuture<GetOpenIdTokenResponse, GetOpenIdTokenError> {
+        (**self).get_open_id_token(input)
+    }
+    fn get_open_id_token_for_developer_identity(
+        &self,
+        input: GetOpenIdTokenForDeveloperIdentityInput,
+    ) -> RusotoFuture<
+        GetOpenIdTokenForDeveloperIdentityResponse,
+        GetOpenIdTokenForDeveloperIdentityError,
+    > {
+        (**self).get_open_id_token_for_developer_identity(input)
+    }
+    fn list_identities(
+        &self,
+        input: ListIdentitiesInput,
+    ) -> RusotoFuture<ListIdentitiesResponse, ListIdentitiesError> {
+        (**self).list_identities(input)
+    }
+    fn list_identity_pools(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> RusotoFuture<ListIdentityPoolsResponse, ListIdentityPoolsError> {
+        (**self).list_identity_pools(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn lookup_developer_identity(
+        &self,
+        input: LookupDeveloperIdentityInput,
+    ) -> RusotoFuture<LookupDeveloperIdentityResponse, LookupDeveloperIdentityError> {
+        (**self).lookup_developer_identity(input)
+    }
+    fn merge_developer_identities(
+        &self,
+        input: MergeDeveloperIdentitiesInput,
+    ) -> RusotoFuture<MergeDeveloperIdentitiesResponse, MergeDeveloperIdentitiesError> {
+        (**self).merge_developer_identities(input)
+    }
+    fn set_identity_pool_roles(
+        &self,
+        input: SetIdentityPoolRolesInput,
+    ) -> RusotoFuture<(), SetIdentityPoolRolesError> {
+        (**self).set_identity_pool_roles(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unlink_developer_identity(
+        &self,
+        input: UnlinkDeveloperIdentityInput,
+    ) -> RusotoFuture<(), UnlinkDeveloperIdentityError> {
+        (**self).unlink_developer_identity(input)
+    }
+    fn unlink_identity(&self, input: UnlinkIdentityInput) -> RusotoFuture<(), UnlinkIdentityError> {
+        (**self).unlink_identity(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_identity_pool(
+        &self,
+        input: IdentityPool,
+    ) -> RusotoFuture<IdentityPool, UpdateIdentityPoolError> {
+        (**self).update_identity_pool(input)
+    }
+}
+/// The error codes Amazon Cognito Identity retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the Amazon Cognito Identity API.
 #[derive(Clone)]
 pub struct CognitoIdentityClient {
@@ -2192,6 +3687,27 @@ impl CognitoIdentityClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_COGNITO_IDENTITY`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CognitoIdentityClient {
+        Self::new(region::Region::from_env_for_service("COGNITO_IDENTITY"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `cognito-identity`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CognitoIdentityClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("cognito-identity", "COGNITO_IDENTITY"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2210,7 +3726,54 @@ impl CognitoIdentityClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CognitoIdentityClient {
-        CognitoIdentityClient { client, region }
+        CognitoIdentityClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "cognito-identity")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "cognito-identity",
+            "cognito-identity",
+            "COGNITO_IDENTITY",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CognitoIdentity` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CognitoIdentity + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CognitoIdentityClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Cognito Identity"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "cognito-identity"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CognitoIdentityClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -22,13 +22,14 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Account takeover action type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AccountTakeoverActionType {
     /// <p><p>The event action.</p> <ul> <li> <p> <code>BLOCK</code> Choosing this action will block the request.</p> </li> <li> <p> <code>MFA<em>IF</em>CONFIGURED</code> Throw MFA challenge if user has configured it, else allow the request.</p> </li> <li> <p> <code>MFA<em>REQUIRED</code> Throw MFA challenge if user has configured it, else block the request.</p> </li> <li> <p> <code>NO</em>ACTION</code> Allow the user sign-in.</p> </li> </ul></p>
     #[serde(rename = "EventAction")]
-    pub event_action: String,
+    pub event_action: AccountTakeoverEventActionType,
     /// <p>Flag specifying whether to send a notification.</p>
     #[serde(rename = "Notify")]
     pub notify: bool,
@@ -51,6 +52,88 @@ pub struct AccountTakeoverActionsType {
     pub medium_action: Option<AccountTakeoverActionType>,
 }
 
+/// <p><p>The event action.</p> <ul> <li> <p> <code>BLOCK</code> Choosing this action will block the request.</p> </li> <li> <p> <code>MFA<em>IF</em>CONFIGURED</code> Throw MFA challenge if user has configured it, else allow the request.</p> </li> <li> <p> <code>MFA<em>REQUIRED</code> Throw MFA challenge if user has configured it, else block the request.</p> </li> <li> <p> <code>NO</em>ACTION</code> Allow the user sign-in.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccountTakeoverEventActionType {
+    /// <p><code>BLOCK</code></p>
+    Block,
+    /// <p><code>MFA_IF_CONFIGURED</code></p>
+    MfaIfConfigured,
+    /// <p><code>MFA_REQUIRED</code></p>
+    MfaRequired,
+    /// <p><code>NO_ACTION</code></p>
+    NoAction,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AccountTakeoverEventActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountTakeoverEventActionType::Block => "BLOCK",
+            AccountTakeoverEventActionType::MfaIfConfigured => "MFA_IF_CONFIGURED",
+            AccountTakeoverEventActionType::MfaRequired => "MFA_REQUIRED",
+            AccountTakeoverEventActionType::NoAction => "NO_ACTION",
+            AccountTakeoverEventActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AccountTakeoverEventActionType {
+    fn from(value: &'a str) -> AccountTakeoverEventActionType {
+        match value {
+            "BLOCK" => AccountTakeoverEventActionType::Block,
+            "MFA_IF_CONFIGURED" => AccountTakeoverEventActionType::MfaIfConfigured,
+            "MFA_REQUIRED" => AccountTakeoverEventActionType::MfaRequired,
+            "NO_ACTION" => AccountTakeoverEventActionType::NoAction,
+            _ => AccountTakeoverEventActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountTakeoverEventActionType {
+    fn from(value: String) -> AccountTakeoverEventActionType {
+        AccountTakeoverEventActionType::from(value.as_str())
+    }
+}
+
+impl From<AccountTakeoverEventActionType> for String {
+    fn from(value: AccountTakeoverEventActionType) -> String {
+        match value {
+            AccountTakeoverEventActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AccountTakeoverEventActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AccountTakeoverEventActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccountTakeoverEventActionType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<AccountTakeoverEventActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AccountTakeoverEventActionType::from(value))
+    }
+}
+
 /// <p>Configuration for mitigation actions and notification for different levels of risk detected for a potential account takeover.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AccountTakeoverRiskConfigurationType {
@@ -74,6 +157,48 @@ pub struct AddCustomAttributesRequest {
     pub user_pool_id: String,
 }
 
+impl AddCustomAttributesRequest {
+    /// Returns a builder for `AddCustomAttributesRequest`, checking its required members when built.
+    pub fn builder() -> AddCustomAttributesRequestBuilder {
+        AddCustomAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddCustomAttributesRequest`](struct.AddCustomAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct AddCustomAttributesRequestBuilder {
+    custom_attributes: Option<Vec<SchemaAttributeType>>,
+    user_pool_id: Option<String>,
+}
+
+impl AddCustomAttributesRequestBuilder {
+    /// Sets the [`custom_attributes`](struct.AddCustomAttributesRequest.html#structfield.custom_attributes) member.
+    pub fn custom_attributes<T: Into<Vec<SchemaAttributeType>>>(mut self, value: T) -> Self {
+        self.custom_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AddCustomAttributesRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddCustomAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddCustomAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(AddCustomAttributesRequest {
+            custom_attributes: self
+                .custom_attributes
+                .ok_or(::rusoto_core::BuildError::missing("custom_attributes"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server for the request to add custom attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -92,6 +217,58 @@ pub struct AdminAddUserToGroupRequest {
     pub username: String,
 }
 
+impl AdminAddUserToGroupRequest {
+    /// Returns a builder for `AdminAddUserToGroupRequest`, checking its required members when built.
+    pub fn builder() -> AdminAddUserToGroupRequestBuilder {
+        AdminAddUserToGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminAddUserToGroupRequest`](struct.AdminAddUserToGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminAddUserToGroupRequestBuilder {
+    group_name: Option<String>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminAddUserToGroupRequestBuilder {
+    /// Sets the [`group_name`](struct.AdminAddUserToGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminAddUserToGroupRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminAddUserToGroupRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminAddUserToGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminAddUserToGroupRequest, ::rusoto_core::BuildError> {
+        Ok(AdminAddUserToGroupRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Represents the request to confirm user registration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AdminConfirmSignUpRequest {
@@ -103,6 +280,48 @@ pub struct AdminConfirmSignUpRequest {
     pub username: String,
 }
 
+impl AdminConfirmSignUpRequest {
+    /// Returns a builder for `AdminConfirmSignUpRequest`, checking its required members when built.
+    pub fn builder() -> AdminConfirmSignUpRequestBuilder {
+        AdminConfirmSignUpRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminConfirmSignUpRequest`](struct.AdminConfirmSignUpRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminConfirmSignUpRequestBuilder {
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminConfirmSignUpRequestBuilder {
+    /// Sets the [`user_pool_id`](struct.AdminConfirmSignUpRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminConfirmSignUpRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminConfirmSignUpRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminConfirmSignUpRequest, ::rusoto_core::BuildError> {
+        Ok(AdminConfirmSignUpRequest {
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server for the request to confirm registration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -131,7 +350,7 @@ pub struct AdminCreateUserRequest {
     /// <p>Specify <code>"EMAIL"</code> if email will be used to send the welcome message. Specify <code>"SMS"</code> if the phone number will be used. The default value is <code>"SMS"</code>. More than one value can be specified.</p>
     #[serde(rename = "DesiredDeliveryMediums")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub desired_delivery_mediums: Option<Vec<String>>,
+    pub desired_delivery_mediums: Option<Vec<DeliveryMediumType>>,
     /// <p>This parameter is only used if the <code>phone_number_verified</code> or <code>email_verified</code> attribute is set to <code>True</code>. Otherwise, it is ignored.</p> <p>If this parameter is set to <code>True</code> and the phone number or email address specified in the UserAttributes parameter already exists as an alias with a different user, the API call will migrate the alias from the previous user to the newly created user. The previous user will no longer be able to log in using that alias.</p> <p>If this parameter is set to <code>False</code>, the API throws an <code>AliasExistsException</code> error if the alias already exists. The default value is <code>False</code>.</p>
     #[serde(rename = "ForceAliasCreation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -139,7 +358,7 @@ pub struct AdminCreateUserRequest {
     /// <p>Set to <code>"RESEND"</code> to resend the invitation message to a user that already exists and reset the expiration limit on the user's account. Set to <code>"SUPPRESS"</code> to suppress sending the message. Only one value can be specified.</p>
     #[serde(rename = "MessageAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_action: Option<String>,
+    pub message_action: Option<MessageActionType>,
     /// <p>The user's temporary password. This password must conform to the password policy that you specified when you created the user pool.</p> <p>The temporary password is valid only once. To complete the Admin Create User flow, the user must enter the temporary password in the sign-in page along with a new password to be used in all future sign-ins.</p> <p>This parameter is not required. If you do not specify a value, Amazon Cognito generates one for you.</p> <p>The temporary password can only be used until the user account expiration limit that you specified when you created the user pool. To reset the account after that time limit, you must call <code>AdminCreateUser</code> again, specifying <code>"RESEND"</code> for the <code>MessageAction</code> parameter.</p>
     #[serde(rename = "TemporaryPassword")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -160,6 +379,94 @@ pub struct AdminCreateUserRequest {
     pub validation_data: Option<Vec<AttributeType>>,
 }
 
+impl AdminCreateUserRequest {
+    /// Returns a builder for `AdminCreateUserRequest`, checking its required members when built.
+    pub fn builder() -> AdminCreateUserRequestBuilder {
+        AdminCreateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminCreateUserRequest`](struct.AdminCreateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminCreateUserRequestBuilder {
+    desired_delivery_mediums: Option<Vec<DeliveryMediumType>>,
+    force_alias_creation: Option<bool>,
+    message_action: Option<MessageActionType>,
+    temporary_password: Option<String>,
+    user_attributes: Option<Vec<AttributeType>>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+    validation_data: Option<Vec<AttributeType>>,
+}
+
+impl AdminCreateUserRequestBuilder {
+    /// Sets the [`desired_delivery_mediums`](struct.AdminCreateUserRequest.html#structfield.desired_delivery_mediums) member.
+    pub fn desired_delivery_mediums<T: Into<Vec<DeliveryMediumType>>>(mut self, value: T) -> Self {
+        self.desired_delivery_mediums = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_alias_creation`](struct.AdminCreateUserRequest.html#structfield.force_alias_creation) member.
+    pub fn force_alias_creation<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_alias_creation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message_action`](struct.AdminCreateUserRequest.html#structfield.message_action) member.
+    pub fn message_action<T: Into<MessageActionType>>(mut self, value: T) -> Self {
+        self.message_action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`temporary_password`](struct.AdminCreateUserRequest.html#structfield.temporary_password) member.
+    pub fn temporary_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.temporary_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_attributes`](struct.AdminCreateUserRequest.html#structfield.user_attributes) member.
+    pub fn user_attributes<T: Into<Vec<AttributeType>>>(mut self, value: T) -> Self {
+        self.user_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminCreateUserRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminCreateUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Sets the [`validation_data`](struct.AdminCreateUserRequest.html#structfield.validation_data) member.
+    pub fn validation_data<T: Into<Vec<AttributeType>>>(mut self, value: T) -> Self {
+        self.validation_data = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminCreateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AdminCreateUserRequest, ::rusoto_core::BuildError> {
+        Ok(AdminCreateUserRequest {
+            desired_delivery_mediums: self.desired_delivery_mediums,
+            force_alias_creation: self.force_alias_creation,
+            message_action: self.message_action,
+            temporary_password: self.temporary_password,
+            user_attributes: self.user_attributes,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+            validation_data: self.validation_data,
+        })
+    }
+}
+
 /// <p>Represents the response from the server to the request to create the user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -184,6 +491,58 @@ pub struct AdminDeleteUserAttributesRequest {
     pub username: String,
 }
 
+impl AdminDeleteUserAttributesRequest {
+    /// Returns a builder for `AdminDeleteUserAttributesRequest`, checking its required members when built.
+    pub fn builder() -> AdminDeleteUserAttributesRequestBuilder {
+        AdminDeleteUserAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminDeleteUserAttributesRequest`](struct.AdminDeleteUserAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminDeleteUserAttributesRequestBuilder {
+    user_attribute_names: Option<Vec<String>>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminDeleteUserAttributesRequestBuilder {
+    /// Sets the [`user_attribute_names`](struct.AdminDeleteUserAttributesRequest.html#structfield.user_attribute_names) member.
+    pub fn user_attribute_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.user_attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminDeleteUserAttributesRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminDeleteUserAttributesRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminDeleteUserAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminDeleteUserAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(AdminDeleteUserAttributesRequest {
+            user_attribute_names: self
+                .user_attribute_names
+                .ok_or(::rusoto_core::BuildError::missing("user_attribute_names"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Represents the response received from the server for a request to delete user attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -200,6 +559,46 @@ pub struct AdminDeleteUserRequest {
     pub username: String,
 }
 
+impl AdminDeleteUserRequest {
+    /// Returns a builder for `AdminDeleteUserRequest`, checking its required members when built.
+    pub fn builder() -> AdminDeleteUserRequestBuilder {
+        AdminDeleteUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminDeleteUserRequest`](struct.AdminDeleteUserRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminDeleteUserRequestBuilder {
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminDeleteUserRequestBuilder {
+    /// Sets the [`user_pool_id`](struct.AdminDeleteUserRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminDeleteUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminDeleteUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AdminDeleteUserRequest, ::rusoto_core::BuildError> {
+        Ok(AdminDeleteUserRequest {
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AdminDisableProviderForUserRequest {
     /// <p>The user to be disabled.</p>
@@ -210,6 +609,48 @@ pub struct AdminDisableProviderForUserRequest {
     pub user_pool_id: String,
 }
 
+impl AdminDisableProviderForUserRequest {
+    /// Returns a builder for `AdminDisableProviderForUserRequest`, checking its required members when built.
+    pub fn builder() -> AdminDisableProviderForUserRequestBuilder {
+        AdminDisableProviderForUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminDisableProviderForUserRequest`](struct.AdminDisableProviderForUserRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminDisableProviderForUserRequestBuilder {
+    user: Option<ProviderUserIdentifierType>,
+    user_pool_id: Option<String>,
+}
+
+impl AdminDisableProviderForUserRequestBuilder {
+    /// Sets the [`user`](struct.AdminDisableProviderForUserRequest.html#structfield.user) member.
+    pub fn user<T: Into<ProviderUserIdentifierType>>(mut self, value: T) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminDisableProviderForUserRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminDisableProviderForUserRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminDisableProviderForUserRequest, ::rusoto_core::BuildError> {
+        Ok(AdminDisableProviderForUserRequest {
+            user: self
+                .user
+                .ok_or(::rusoto_core::BuildError::missing("user"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AdminDisableProviderForUserResponse {}
@@ -225,6 +666,48 @@ pub struct AdminDisableUserRequest {
     pub username: String,
 }
 
+impl AdminDisableUserRequest {
+    /// Returns a builder for `AdminDisableUserRequest`, checking its required members when built.
+    pub fn builder() -> AdminDisableUserRequestBuilder {
+        AdminDisableUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminDisableUserRequest`](struct.AdminDisableUserRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminDisableUserRequestBuilder {
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminDisableUserRequestBuilder {
+    /// Sets the [`user_pool_id`](struct.AdminDisableUserRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminDisableUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminDisableUserRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminDisableUserRequest, ::rusoto_core::BuildError> {
+        Ok(AdminDisableUserRequest {
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Represents the response received from the server to disable the user as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -241,6 +724,46 @@ pub struct AdminEnableUserRequest {
     pub username: String,
 }
 
+impl AdminEnableUserRequest {
+    /// Returns a builder for `AdminEnableUserRequest`, checking its required members when built.
+    pub fn builder() -> AdminEnableUserRequestBuilder {
+        AdminEnableUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminEnableUserRequest`](struct.AdminEnableUserRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminEnableUserRequestBuilder {
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminEnableUserRequestBuilder {
+    /// Sets the [`user_pool_id`](struct.AdminEnableUserRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminEnableUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminEnableUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AdminEnableUserRequest, ::rusoto_core::BuildError> {
+        Ok(AdminEnableUserRequest {
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server for the request to enable a user as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -260,6 +783,58 @@ pub struct AdminForgetDeviceRequest {
     pub username: String,
 }
 
+impl AdminForgetDeviceRequest {
+    /// Returns a builder for `AdminForgetDeviceRequest`, checking its required members when built.
+    pub fn builder() -> AdminForgetDeviceRequestBuilder {
+        AdminForgetDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminForgetDeviceRequest`](struct.AdminForgetDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminForgetDeviceRequestBuilder {
+    device_key: Option<String>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminForgetDeviceRequestBuilder {
+    /// Sets the [`device_key`](struct.AdminForgetDeviceRequest.html#structfield.device_key) member.
+    pub fn device_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminForgetDeviceRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminForgetDeviceRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminForgetDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminForgetDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(AdminForgetDeviceRequest {
+            device_key: self
+                .device_key
+                .ok_or(::rusoto_core::BuildError::missing("device_key"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Represents the request to get the device, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AdminGetDeviceRequest {
@@ -274,6 +849,56 @@ pub struct AdminGetDeviceRequest {
     pub username: String,
 }
 
+impl AdminGetDeviceRequest {
+    /// Returns a builder for `AdminGetDeviceRequest`, checking its required members when built.
+    pub fn builder() -> AdminGetDeviceRequestBuilder {
+        AdminGetDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminGetDeviceRequest`](struct.AdminGetDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminGetDeviceRequestBuilder {
+    device_key: Option<String>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminGetDeviceRequestBuilder {
+    /// Sets the [`device_key`](struct.AdminGetDeviceRequest.html#structfield.device_key) member.
+    pub fn device_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminGetDeviceRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminGetDeviceRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminGetDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AdminGetDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(AdminGetDeviceRequest {
+            device_key: self
+                .device_key
+                .ok_or(::rusoto_core::BuildError::missing("device_key"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Gets the device response, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -294,6 +919,46 @@ pub struct AdminGetUserRequest {
     pub username: String,
 }
 
+impl AdminGetUserRequest {
+    /// Returns a builder for `AdminGetUserRequest`, checking its required members when built.
+    pub fn builder() -> AdminGetUserRequestBuilder {
+        AdminGetUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminGetUserRequest`](struct.AdminGetUserRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminGetUserRequestBuilder {
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminGetUserRequestBuilder {
+    /// Sets the [`user_pool_id`](struct.AdminGetUserRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminGetUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminGetUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AdminGetUserRequest, ::rusoto_core::BuildError> {
+        Ok(AdminGetUserRequest {
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server from the request to get the specified user as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -316,12 +981,14 @@ pub struct AdminGetUserResponse {
     pub user_attributes: Option<Vec<AttributeType>>,
     /// <p>The date the user was created.</p>
     #[serde(rename = "UserCreateDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_create_date: Option<f64>,
+    pub user_create_date: Option<EpochTimestamp>,
     /// <p>The date the user was last modified.</p>
     #[serde(rename = "UserLastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_last_modified_date: Option<f64>,
+    pub user_last_modified_date: Option<EpochTimestamp>,
     /// <p>The list of the user's MFA settings.</p>
     #[serde(rename = "UserMFASettingList")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -329,14 +996,14 @@ pub struct AdminGetUserResponse {
     /// <p><p>The user status. Can be one of the following:</p> <ul> <li> <p>UNCONFIRMED - User has been created but not confirmed.</p> </li> <li> <p>CONFIRMED - User has been confirmed.</p> </li> <li> <p>ARCHIVED - User is no longer active.</p> </li> <li> <p>COMPROMISED - User is disabled due to a potential security threat.</p> </li> <li> <p>UNKNOWN - User status is not known.</p> </li> <li> <p>RESET<em>REQUIRED - User is confirmed, but the user must request a code and reset his or her password before he or she can sign in.</p> </li> <li> <p>FORCE</em>CHANGE_PASSWORD - The user is confirmed and the user can sign in using a temporary password, but on first sign-in, the user must change his or her password to a new value before doing anything else. </p> </li> </ul></p>
     #[serde(rename = "UserStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_status: Option<String>,
+    pub user_status: Option<UserStatusType>,
     /// <p>The user name of the user about whom you are receiving information.</p>
     #[serde(rename = "Username")]
     pub username: String,
 }
 
 /// <p>Initiates the authorization request, as an administrator.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AdminInitiateAuthRequest {
     /// <p>The analytics metadata for collecting Amazon Pinpoint metrics for <code>AdminInitiateAuth</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -344,7 +1011,7 @@ pub struct AdminInitiateAuthRequest {
     pub analytics_metadata: Option<AnalyticsMetadataType>,
     /// <p><p>The authentication flow for this call to execute. The API action will depend on this value. For example:</p> <ul> <li> <p> <code>REFRESH<em>TOKEN</em>AUTH</code> will take in a valid refresh token and return new tokens.</p> </li> <li> <p> <code>USER<em>SRP</em>AUTH</code> will take in <code>USERNAME</code> and <code>SRP<em>A</code> and return the SRP variables to be used for next challenge execution.</p> </li> <li> <p> <code>USER</em>PASSWORD<em>AUTH</code> will take in <code>USERNAME</code> and <code>PASSWORD</code> and return the next challenge or tokens.</p> </li> </ul> <p>Valid values include:</p> <ul> <li> <p> <code>USER</em>SRP<em>AUTH</code>: Authentication flow for the Secure Remote Password (SRP) protocol.</p> </li> <li> <p> <code>REFRESH</em>TOKEN<em>AUTH</code>/<code>REFRESH</em>TOKEN</code>: Authentication flow for refreshing the access token and ID token by supplying a valid refresh token.</p> </li> <li> <p> <code>CUSTOM<em>AUTH</code>: Custom authentication flow.</p> </li> <li> <p> <code>ADMIN</em>NO<em>SRP</em>AUTH</code>: Non-SRP authentication flow; you can pass in the USERNAME and PASSWORD directly if the flow is enabled for calling the app client.</p> </li> <li> <p> <code>USER<em>PASSWORD</em>AUTH</code>: Non-SRP authentication flow; USERNAME and PASSWORD are passed directly. If a user migration Lambda trigger is set, this flow will invoke the user migration Lambda if the USERNAME is not found in the user pool. </p> </li> </ul></p>
     #[serde(rename = "AuthFlow")]
-    pub auth_flow: String,
+    pub auth_flow: AuthFlowType,
     /// <p><p>The authentication parameters. These are inputs corresponding to the <code>AuthFlow</code> that you are invoking. The required values depend on the value of <code>AuthFlow</code>:</p> <ul> <li> <p>For <code>USER<em>SRP</em>AUTH</code>: <code>USERNAME</code> (required), <code>SRP<em>A</code> (required), <code>SECRET</em>HASH</code> (required if the app client is configured with a client secret), <code>DEVICE<em>KEY</code> </p> </li> <li> <p>For <code>REFRESH</em>TOKEN<em>AUTH/REFRESH</em>TOKEN</code>: <code>REFRESH<em>TOKEN</code> (required), <code>SECRET</em>HASH</code> (required if the app client is configured with a client secret), <code>DEVICE<em>KEY</code> </p> </li> <li> <p>For <code>ADMIN</em>NO<em>SRP</em>AUTH</code>: <code>USERNAME</code> (required), <code>SECRET<em>HASH</code> (if app client is configured with client secret), <code>PASSWORD</code> (required), <code>DEVICE</em>KEY</code> </p> </li> <li> <p>For <code>CUSTOM<em>AUTH</code>: <code>USERNAME</code> (required), <code>SECRET</em>HASH</code> (if app client is configured with client secret), <code>DEVICE_KEY</code> </p> </li> </ul></p>
     #[serde(rename = "AuthParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -365,6 +1032,96 @@ pub struct AdminInitiateAuthRequest {
     pub user_pool_id: String,
 }
 
+impl AdminInitiateAuthRequest {
+    /// Returns a builder for `AdminInitiateAuthRequest`, checking its required members when built.
+    pub fn builder() -> AdminInitiateAuthRequestBuilder {
+        AdminInitiateAuthRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminInitiateAuthRequest`](struct.AdminInitiateAuthRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminInitiateAuthRequestBuilder {
+    analytics_metadata: Option<AnalyticsMetadataType>,
+    auth_flow: Option<AuthFlowType>,
+    auth_parameters: Option<::std::collections::HashMap<String, String>>,
+    client_id: Option<String>,
+    client_metadata: Option<::std::collections::HashMap<String, String>>,
+    context_data: Option<ContextDataType>,
+    user_pool_id: Option<String>,
+}
+
+impl AdminInitiateAuthRequestBuilder {
+    /// Sets the [`analytics_metadata`](struct.AdminInitiateAuthRequest.html#structfield.analytics_metadata) member.
+    pub fn analytics_metadata<T: Into<AnalyticsMetadataType>>(mut self, value: T) -> Self {
+        self.analytics_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auth_flow`](struct.AdminInitiateAuthRequest.html#structfield.auth_flow) member.
+    pub fn auth_flow<T: Into<AuthFlowType>>(mut self, value: T) -> Self {
+        self.auth_flow = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auth_parameters`](struct.AdminInitiateAuthRequest.html#structfield.auth_parameters) member.
+    pub fn auth_parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.auth_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.AdminInitiateAuthRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_metadata`](struct.AdminInitiateAuthRequest.html#structfield.client_metadata) member.
+    pub fn client_metadata<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.client_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`context_data`](struct.AdminInitiateAuthRequest.html#structfield.context_data) member.
+    pub fn context_data<T: Into<ContextDataType>>(mut self, value: T) -> Self {
+        self.context_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminInitiateAuthRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminInitiateAuthRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminInitiateAuthRequest, ::rusoto_core::BuildError> {
+        Ok(AdminInitiateAuthRequest {
+            analytics_metadata: self.analytics_metadata,
+            auth_flow: self
+                .auth_flow
+                .ok_or(::rusoto_core::BuildError::missing("auth_flow"))?,
+            auth_parameters: self.auth_parameters,
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            client_metadata: self.client_metadata,
+            context_data: self.context_data,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Initiates the authentication response, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -376,7 +1133,7 @@ pub struct AdminInitiateAuthResponse {
     /// <p><p>The name of the challenge which you are responding to with this call. This is returned to you in the <code>AdminInitiateAuth</code> response if you need to pass another challenge.</p> <ul> <li> <p> <code>MFA<em>SETUP</code>: If MFA is required, users who do not have at least one of the MFA methods set up are presented with an <code>MFA</em>SETUP</code> challenge. The user must set up at least one MFA type to continue to authenticate.</p> </li> <li> <p> <code>SELECT<em>MFA</em>TYPE</code>: Selects the MFA type. Valid MFA options are <code>SMS<em>MFA</code> for text SMS MFA, and <code>SOFTWARE</em>TOKEN<em>MFA</code> for TOTP software token MFA.</p> </li> <li> <p> <code>SMS</em>MFA</code>: Next challenge is to supply an <code>SMS<em>MFA</em>CODE</code>, delivered via SMS.</p> </li> <li> <p> <code>PASSWORD<em>VERIFIER</code>: Next challenge is to supply <code>PASSWORD</em>CLAIM<em>SIGNATURE</code>, <code>PASSWORD</em>CLAIM<em>SECRET</em>BLOCK</code>, and <code>TIMESTAMP</code> after the client-side SRP calculations.</p> </li> <li> <p> <code>CUSTOM<em>CHALLENGE</code>: This is returned if your custom authentication flow determines that the user should pass another challenge before tokens are issued.</p> </li> <li> <p> <code>DEVICE</em>SRP<em>AUTH</code>: If device tracking was enabled on your user pool and the previous challenges were passed, this challenge is returned so that Amazon Cognito can start tracking this device.</p> </li> <li> <p> <code>DEVICE</em>PASSWORD<em>VERIFIER</code>: Similar to <code>PASSWORD</em>VERIFIER</code>, but for devices only.</p> </li> <li> <p> <code>ADMIN<em>NO</em>SRP<em>AUTH</code>: This is returned if you need to authenticate with <code>USERNAME</code> and <code>PASSWORD</code> directly. An app client must be enabled to use this flow.</p> </li> <li> <p> <code>NEW</em>PASSWORD<em>REQUIRED</code>: For users which are required to change their passwords after successful first login. This challenge should be passed with <code>NEW</em>PASSWORD</code> and any other required attributes.</p> </li> </ul></p>
     #[serde(rename = "ChallengeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_name: Option<String>,
+    pub challenge_name: Option<ChallengeNameType>,
     /// <p>The challenge parameters. These are returned to you in the <code>AdminInitiateAuth</code> response if you need to pass another challenge. The responses in this parameter should be used to compute inputs to the next call (<code>AdminRespondToAuthChallenge</code>).</p> <p>All challenges require <code>USERNAME</code> and <code>SECRET_HASH</code> (if applicable).</p> <p>The value of the <code>USER_ID_FOR_SRP</code> attribute will be the user's actual username, not an alias (such as email address or phone number), even if you specified an alias in your call to <code>AdminInitiateAuth</code>. This is because, in the <code>AdminRespondToAuthChallenge</code> API <code>ChallengeResponses</code>, the <code>USERNAME</code> attribute cannot be an alias.</p>
     #[serde(rename = "ChallengeParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -400,6 +1157,58 @@ pub struct AdminLinkProviderForUserRequest {
     pub user_pool_id: String,
 }
 
+impl AdminLinkProviderForUserRequest {
+    /// Returns a builder for `AdminLinkProviderForUserRequest`, checking its required members when built.
+    pub fn builder() -> AdminLinkProviderForUserRequestBuilder {
+        AdminLinkProviderForUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminLinkProviderForUserRequest`](struct.AdminLinkProviderForUserRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminLinkProviderForUserRequestBuilder {
+    destination_user: Option<ProviderUserIdentifierType>,
+    source_user: Option<ProviderUserIdentifierType>,
+    user_pool_id: Option<String>,
+}
+
+impl AdminLinkProviderForUserRequestBuilder {
+    /// Sets the [`destination_user`](struct.AdminLinkProviderForUserRequest.html#structfield.destination_user) member.
+    pub fn destination_user<T: Into<ProviderUserIdentifierType>>(mut self, value: T) -> Self {
+        self.destination_user = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_user`](struct.AdminLinkProviderForUserRequest.html#structfield.source_user) member.
+    pub fn source_user<T: Into<ProviderUserIdentifierType>>(mut self, value: T) -> Self {
+        self.source_user = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminLinkProviderForUserRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminLinkProviderForUserRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminLinkProviderForUserRequest, ::rusoto_core::BuildError> {
+        Ok(AdminLinkProviderForUserRequest {
+            destination_user: self
+                .destination_user
+                .ok_or(::rusoto_core::BuildError::missing("destination_user"))?,
+            source_user: self
+                .source_user
+                .ok_or(::rusoto_core::BuildError::missing("source_user"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AdminLinkProviderForUserResponse {}
@@ -423,6 +1232,64 @@ pub struct AdminListDevicesRequest {
     pub username: String,
 }
 
+impl AdminListDevicesRequest {
+    /// Returns a builder for `AdminListDevicesRequest`, checking its required members when built.
+    pub fn builder() -> AdminListDevicesRequestBuilder {
+        AdminListDevicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminListDevicesRequest`](struct.AdminListDevicesRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminListDevicesRequestBuilder {
+    limit: Option<i64>,
+    pagination_token: Option<String>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminListDevicesRequestBuilder {
+    /// Sets the [`limit`](struct.AdminListDevicesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pagination_token`](struct.AdminListDevicesRequest.html#structfield.pagination_token) member.
+    pub fn pagination_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.pagination_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminListDevicesRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminListDevicesRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminListDevicesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminListDevicesRequest, ::rusoto_core::BuildError> {
+        Ok(AdminListDevicesRequest {
+            limit: self.limit,
+            pagination_token: self.pagination_token,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Lists the device's response, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -455,17 +1322,75 @@ pub struct AdminListGroupsForUserRequest {
     pub username: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct AdminListGroupsForUserResponse {
-    /// <p>The groups that the user belongs to.</p>
-    #[serde(rename = "Groups")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub groups: Option<Vec<GroupType>>,
-    /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl AdminListGroupsForUserRequest {
+    /// Returns a builder for `AdminListGroupsForUserRequest`, checking its required members when built.
+    pub fn builder() -> AdminListGroupsForUserRequestBuilder {
+        AdminListGroupsForUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminListGroupsForUserRequest`](struct.AdminListGroupsForUserRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminListGroupsForUserRequestBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminListGroupsForUserRequestBuilder {
+    /// Sets the [`limit`](struct.AdminListGroupsForUserRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.AdminListGroupsForUserRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminListGroupsForUserRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminListGroupsForUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminListGroupsForUserRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminListGroupsForUserRequest, ::rusoto_core::BuildError> {
+        Ok(AdminListGroupsForUserRequest {
+            limit: self.limit,
+            next_token: self.next_token,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AdminListGroupsForUserResponse {
+    /// <p>The groups that the user belongs to.</p>
+    #[serde(rename = "Groups")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<GroupType>>,
+    /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -486,6 +1411,64 @@ pub struct AdminListUserAuthEventsRequest {
     pub username: String,
 }
 
+impl AdminListUserAuthEventsRequest {
+    /// Returns a builder for `AdminListUserAuthEventsRequest`, checking its required members when built.
+    pub fn builder() -> AdminListUserAuthEventsRequestBuilder {
+        AdminListUserAuthEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminListUserAuthEventsRequest`](struct.AdminListUserAuthEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminListUserAuthEventsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminListUserAuthEventsRequestBuilder {
+    /// Sets the [`max_results`](struct.AdminListUserAuthEventsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.AdminListUserAuthEventsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminListUserAuthEventsRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminListUserAuthEventsRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminListUserAuthEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminListUserAuthEventsRequest, ::rusoto_core::BuildError> {
+        Ok(AdminListUserAuthEventsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AdminListUserAuthEventsResponse {
@@ -512,6 +1495,58 @@ pub struct AdminRemoveUserFromGroupRequest {
     pub username: String,
 }
 
+impl AdminRemoveUserFromGroupRequest {
+    /// Returns a builder for `AdminRemoveUserFromGroupRequest`, checking its required members when built.
+    pub fn builder() -> AdminRemoveUserFromGroupRequestBuilder {
+        AdminRemoveUserFromGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminRemoveUserFromGroupRequest`](struct.AdminRemoveUserFromGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminRemoveUserFromGroupRequestBuilder {
+    group_name: Option<String>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminRemoveUserFromGroupRequestBuilder {
+    /// Sets the [`group_name`](struct.AdminRemoveUserFromGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminRemoveUserFromGroupRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminRemoveUserFromGroupRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminRemoveUserFromGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminRemoveUserFromGroupRequest, ::rusoto_core::BuildError> {
+        Ok(AdminRemoveUserFromGroupRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Represents the request to reset a user's password as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AdminResetUserPasswordRequest {
@@ -523,13 +1558,55 @@ pub struct AdminResetUserPasswordRequest {
     pub username: String,
 }
 
+impl AdminResetUserPasswordRequest {
+    /// Returns a builder for `AdminResetUserPasswordRequest`, checking its required members when built.
+    pub fn builder() -> AdminResetUserPasswordRequestBuilder {
+        AdminResetUserPasswordRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminResetUserPasswordRequest`](struct.AdminResetUserPasswordRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminResetUserPasswordRequestBuilder {
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminResetUserPasswordRequestBuilder {
+    /// Sets the [`user_pool_id`](struct.AdminResetUserPasswordRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminResetUserPasswordRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminResetUserPasswordRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminResetUserPasswordRequest, ::rusoto_core::BuildError> {
+        Ok(AdminResetUserPasswordRequest {
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server to reset a user password as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AdminResetUserPasswordResponse {}
 
 /// <p>The request to respond to the authentication challenge, as an administrator.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AdminRespondToAuthChallengeRequest {
     /// <p>The analytics metadata for collecting Amazon Pinpoint metrics for <code>AdminRespondToAuthChallenge</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -537,7 +1614,7 @@ pub struct AdminRespondToAuthChallengeRequest {
     pub analytics_metadata: Option<AnalyticsMetadataType>,
     /// <p>The challenge name. For more information, see .</p>
     #[serde(rename = "ChallengeName")]
-    pub challenge_name: String,
+    pub challenge_name: ChallengeNameType,
     /// <p>The challenge responses. These are inputs corresponding to the value of <code>ChallengeName</code>, for example:</p> <ul> <li> <p> <code>SMS_MFA</code>: <code>SMS_MFA_CODE</code>, <code>USERNAME</code>, <code>SECRET_HASH</code> (if app client is configured with client secret).</p> </li> <li> <p> <code>PASSWORD_VERIFIER</code>: <code>PASSWORD_CLAIM_SIGNATURE</code>, <code>PASSWORD_CLAIM_SECRET_BLOCK</code>, <code>TIMESTAMP</code>, <code>USERNAME</code>, <code>SECRET_HASH</code> (if app client is configured with client secret).</p> </li> <li> <p> <code>ADMIN_NO_SRP_AUTH</code>: <code>PASSWORD</code>, <code>USERNAME</code>, <code>SECRET_HASH</code> (if app client is configured with client secret). </p> </li> <li> <p> <code>NEW_PASSWORD_REQUIRED</code>: <code>NEW_PASSWORD</code>, any other required attributes, <code>USERNAME</code>, <code>SECRET_HASH</code> (if app client is configured with client secret). </p> </li> </ul> <p>The value of the <code>USERNAME</code> attribute must be the user's actual username, not an alias (such as email address or phone number). To make this easier, the <code>AdminInitiateAuth</code> response includes the actual username value in the <code>USERNAMEUSER_ID_FOR_SRP</code> attribute, even if you specified an alias in your call to <code>AdminInitiateAuth</code>.</p>
     #[serde(rename = "ChallengeResponses")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -558,6 +1635,93 @@ pub struct AdminRespondToAuthChallengeRequest {
     pub user_pool_id: String,
 }
 
+impl AdminRespondToAuthChallengeRequest {
+    /// Returns a builder for `AdminRespondToAuthChallengeRequest`, checking its required members when built.
+    pub fn builder() -> AdminRespondToAuthChallengeRequestBuilder {
+        AdminRespondToAuthChallengeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminRespondToAuthChallengeRequest`](struct.AdminRespondToAuthChallengeRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminRespondToAuthChallengeRequestBuilder {
+    analytics_metadata: Option<AnalyticsMetadataType>,
+    challenge_name: Option<ChallengeNameType>,
+    challenge_responses: Option<::std::collections::HashMap<String, String>>,
+    client_id: Option<String>,
+    context_data: Option<ContextDataType>,
+    session: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl AdminRespondToAuthChallengeRequestBuilder {
+    /// Sets the [`analytics_metadata`](struct.AdminRespondToAuthChallengeRequest.html#structfield.analytics_metadata) member.
+    pub fn analytics_metadata<T: Into<AnalyticsMetadataType>>(mut self, value: T) -> Self {
+        self.analytics_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`challenge_name`](struct.AdminRespondToAuthChallengeRequest.html#structfield.challenge_name) member.
+    pub fn challenge_name<T: Into<ChallengeNameType>>(mut self, value: T) -> Self {
+        self.challenge_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`challenge_responses`](struct.AdminRespondToAuthChallengeRequest.html#structfield.challenge_responses) member.
+    pub fn challenge_responses<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.challenge_responses = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.AdminRespondToAuthChallengeRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`context_data`](struct.AdminRespondToAuthChallengeRequest.html#structfield.context_data) member.
+    pub fn context_data<T: Into<ContextDataType>>(mut self, value: T) -> Self {
+        self.context_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`session`](struct.AdminRespondToAuthChallengeRequest.html#structfield.session) member.
+    pub fn session<T: Into<String>>(mut self, value: T) -> Self {
+        self.session = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminRespondToAuthChallengeRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminRespondToAuthChallengeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminRespondToAuthChallengeRequest, ::rusoto_core::BuildError> {
+        Ok(AdminRespondToAuthChallengeRequest {
+            analytics_metadata: self.analytics_metadata,
+            challenge_name: self
+                .challenge_name
+                .ok_or(::rusoto_core::BuildError::missing("challenge_name"))?,
+            challenge_responses: self.challenge_responses,
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            context_data: self.context_data,
+            session: self.session,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Responds to the authentication challenge, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -569,7 +1733,7 @@ pub struct AdminRespondToAuthChallengeResponse {
     /// <p>The name of the challenge. For more information, see .</p>
     #[serde(rename = "ChallengeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_name: Option<String>,
+    pub challenge_name: Option<ChallengeNameType>,
     /// <p>The challenge parameters. For more information, see .</p>
     #[serde(rename = "ChallengeParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -598,6 +1762,67 @@ pub struct AdminSetUserMFAPreferenceRequest {
     pub username: String,
 }
 
+impl AdminSetUserMFAPreferenceRequest {
+    /// Returns a builder for `AdminSetUserMFAPreferenceRequest`, checking its required members when built.
+    pub fn builder() -> AdminSetUserMFAPreferenceRequestBuilder {
+        AdminSetUserMFAPreferenceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminSetUserMFAPreferenceRequest`](struct.AdminSetUserMFAPreferenceRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminSetUserMFAPreferenceRequestBuilder {
+    sms_mfa_settings: Option<SMSMfaSettingsType>,
+    software_token_mfa_settings: Option<SoftwareTokenMfaSettingsType>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminSetUserMFAPreferenceRequestBuilder {
+    /// Sets the [`sms_mfa_settings`](struct.AdminSetUserMFAPreferenceRequest.html#structfield.sms_mfa_settings) member.
+    pub fn sms_mfa_settings<T: Into<SMSMfaSettingsType>>(mut self, value: T) -> Self {
+        self.sms_mfa_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`software_token_mfa_settings`](struct.AdminSetUserMFAPreferenceRequest.html#structfield.software_token_mfa_settings) member.
+    pub fn software_token_mfa_settings<T: Into<SoftwareTokenMfaSettingsType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.software_token_mfa_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminSetUserMFAPreferenceRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminSetUserMFAPreferenceRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminSetUserMFAPreferenceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminSetUserMFAPreferenceRequest, ::rusoto_core::BuildError> {
+        Ok(AdminSetUserMFAPreferenceRequest {
+            sms_mfa_settings: self.sms_mfa_settings,
+            software_token_mfa_settings: self.software_token_mfa_settings,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AdminSetUserMFAPreferenceResponse {}
@@ -615,6 +1840,66 @@ pub struct AdminSetUserPasswordRequest {
     pub username: String,
 }
 
+impl AdminSetUserPasswordRequest {
+    /// Returns a builder for `AdminSetUserPasswordRequest`, checking its required members when built.
+    pub fn builder() -> AdminSetUserPasswordRequestBuilder {
+        AdminSetUserPasswordRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminSetUserPasswordRequest`](struct.AdminSetUserPasswordRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminSetUserPasswordRequestBuilder {
+    password: Option<String>,
+    permanent: Option<bool>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminSetUserPasswordRequestBuilder {
+    /// Sets the [`password`](struct.AdminSetUserPasswordRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permanent`](struct.AdminSetUserPasswordRequest.html#structfield.permanent) member.
+    pub fn permanent<T: Into<bool>>(mut self, value: T) -> Self {
+        self.permanent = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminSetUserPasswordRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminSetUserPasswordRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminSetUserPasswordRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminSetUserPasswordRequest, ::rusoto_core::BuildError> {
+        Ok(AdminSetUserPasswordRequest {
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+            permanent: self.permanent,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AdminSetUserPasswordResponse {}
@@ -633,19 +1918,71 @@ pub struct AdminSetUserSettingsRequest {
     pub username: String,
 }
 
+impl AdminSetUserSettingsRequest {
+    /// Returns a builder for `AdminSetUserSettingsRequest`, checking its required members when built.
+    pub fn builder() -> AdminSetUserSettingsRequestBuilder {
+        AdminSetUserSettingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminSetUserSettingsRequest`](struct.AdminSetUserSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminSetUserSettingsRequestBuilder {
+    mfa_options: Option<Vec<MFAOptionType>>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminSetUserSettingsRequestBuilder {
+    /// Sets the [`mfa_options`](struct.AdminSetUserSettingsRequest.html#structfield.mfa_options) member.
+    pub fn mfa_options<T: Into<Vec<MFAOptionType>>>(mut self, value: T) -> Self {
+        self.mfa_options = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminSetUserSettingsRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminSetUserSettingsRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminSetUserSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminSetUserSettingsRequest, ::rusoto_core::BuildError> {
+        Ok(AdminSetUserSettingsRequest {
+            mfa_options: self
+                .mfa_options
+                .ok_or(::rusoto_core::BuildError::missing("mfa_options"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server to set user settings as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AdminSetUserSettingsResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AdminUpdateAuthEventFeedbackRequest {
     /// <p>The authentication event ID.</p>
     #[serde(rename = "EventId")]
     pub event_id: String,
     /// <p>The authentication event feedback value.</p>
     #[serde(rename = "FeedbackValue")]
-    pub feedback_value: String,
+    pub feedback_value: FeedbackValueType,
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
@@ -654,6 +1991,68 @@ pub struct AdminUpdateAuthEventFeedbackRequest {
     pub username: String,
 }
 
+impl AdminUpdateAuthEventFeedbackRequest {
+    /// Returns a builder for `AdminUpdateAuthEventFeedbackRequest`, checking its required members when built.
+    pub fn builder() -> AdminUpdateAuthEventFeedbackRequestBuilder {
+        AdminUpdateAuthEventFeedbackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminUpdateAuthEventFeedbackRequest`](struct.AdminUpdateAuthEventFeedbackRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminUpdateAuthEventFeedbackRequestBuilder {
+    event_id: Option<String>,
+    feedback_value: Option<FeedbackValueType>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminUpdateAuthEventFeedbackRequestBuilder {
+    /// Sets the [`event_id`](struct.AdminUpdateAuthEventFeedbackRequest.html#structfield.event_id) member.
+    pub fn event_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`feedback_value`](struct.AdminUpdateAuthEventFeedbackRequest.html#structfield.feedback_value) member.
+    pub fn feedback_value<T: Into<FeedbackValueType>>(mut self, value: T) -> Self {
+        self.feedback_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminUpdateAuthEventFeedbackRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminUpdateAuthEventFeedbackRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminUpdateAuthEventFeedbackRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminUpdateAuthEventFeedbackRequest, ::rusoto_core::BuildError> {
+        Ok(AdminUpdateAuthEventFeedbackRequest {
+            event_id: self
+                .event_id
+                .ok_or(::rusoto_core::BuildError::missing("event_id"))?,
+            feedback_value: self
+                .feedback_value
+                .ok_or(::rusoto_core::BuildError::missing("feedback_value"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AdminUpdateAuthEventFeedbackResponse {}
@@ -667,7 +2066,7 @@ pub struct AdminUpdateDeviceStatusRequest {
     /// <p>The status indicating whether a device has been remembered or not.</p>
     #[serde(rename = "DeviceRememberedStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_remembered_status: Option<String>,
+    pub device_remembered_status: Option<DeviceRememberedStatusType>,
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
@@ -676,6 +2075,69 @@ pub struct AdminUpdateDeviceStatusRequest {
     pub username: String,
 }
 
+impl AdminUpdateDeviceStatusRequest {
+    /// Returns a builder for `AdminUpdateDeviceStatusRequest`, checking its required members when built.
+    pub fn builder() -> AdminUpdateDeviceStatusRequestBuilder {
+        AdminUpdateDeviceStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminUpdateDeviceStatusRequest`](struct.AdminUpdateDeviceStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminUpdateDeviceStatusRequestBuilder {
+    device_key: Option<String>,
+    device_remembered_status: Option<DeviceRememberedStatusType>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminUpdateDeviceStatusRequestBuilder {
+    /// Sets the [`device_key`](struct.AdminUpdateDeviceStatusRequest.html#structfield.device_key) member.
+    pub fn device_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_remembered_status`](struct.AdminUpdateDeviceStatusRequest.html#structfield.device_remembered_status) member.
+    pub fn device_remembered_status<T: Into<DeviceRememberedStatusType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.device_remembered_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminUpdateDeviceStatusRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminUpdateDeviceStatusRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminUpdateDeviceStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminUpdateDeviceStatusRequest, ::rusoto_core::BuildError> {
+        Ok(AdminUpdateDeviceStatusRequest {
+            device_key: self
+                .device_key
+                .ok_or(::rusoto_core::BuildError::missing("device_key"))?,
+            device_remembered_status: self.device_remembered_status,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>The status response from the request to update the device, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -695,6 +2157,58 @@ pub struct AdminUpdateUserAttributesRequest {
     pub username: String,
 }
 
+impl AdminUpdateUserAttributesRequest {
+    /// Returns a builder for `AdminUpdateUserAttributesRequest`, checking its required members when built.
+    pub fn builder() -> AdminUpdateUserAttributesRequestBuilder {
+        AdminUpdateUserAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminUpdateUserAttributesRequest`](struct.AdminUpdateUserAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminUpdateUserAttributesRequestBuilder {
+    user_attributes: Option<Vec<AttributeType>>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminUpdateUserAttributesRequestBuilder {
+    /// Sets the [`user_attributes`](struct.AdminUpdateUserAttributesRequest.html#structfield.user_attributes) member.
+    pub fn user_attributes<T: Into<Vec<AttributeType>>>(mut self, value: T) -> Self {
+        self.user_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.AdminUpdateUserAttributesRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminUpdateUserAttributesRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminUpdateUserAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminUpdateUserAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(AdminUpdateUserAttributesRequest {
+            user_attributes: self
+                .user_attributes
+                .ok_or(::rusoto_core::BuildError::missing("user_attributes"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server for the request to update user attributes as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -711,63 +2225,375 @@ pub struct AdminUserGlobalSignOutRequest {
     pub username: String,
 }
 
+impl AdminUserGlobalSignOutRequest {
+    /// Returns a builder for `AdminUserGlobalSignOutRequest`, checking its required members when built.
+    pub fn builder() -> AdminUserGlobalSignOutRequestBuilder {
+        AdminUserGlobalSignOutRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AdminUserGlobalSignOutRequest`](struct.AdminUserGlobalSignOutRequest.html).
+#[derive(Default, Debug)]
+pub struct AdminUserGlobalSignOutRequestBuilder {
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl AdminUserGlobalSignOutRequestBuilder {
+    /// Sets the [`user_pool_id`](struct.AdminUserGlobalSignOutRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.AdminUserGlobalSignOutRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `AdminUserGlobalSignOutRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AdminUserGlobalSignOutRequest, ::rusoto_core::BuildError> {
+        Ok(AdminUserGlobalSignOutRequest {
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>The global sign-out response, as an administrator.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AdminUserGlobalSignOutResponse {}
 
-/// <p>The Amazon Pinpoint analytics configuration for collecting metrics for a user pool.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct AnalyticsConfigurationType {
-    /// <p>The application ID for an Amazon Pinpoint application.</p>
-    #[serde(rename = "ApplicationId")]
-    pub application_id: String,
-    /// <p>The external ID.</p>
-    #[serde(rename = "ExternalId")]
-    pub external_id: String,
-    /// <p>The ARN of an IAM role that authorizes Amazon Cognito to publish events to Amazon Pinpoint analytics.</p>
-    #[serde(rename = "RoleArn")]
-    pub role_arn: String,
-    /// <p>If <code>UserDataShared</code> is <code>true</code>, Amazon Cognito will include user data in the events it publishes to Amazon Pinpoint analytics.</p>
-    #[serde(rename = "UserDataShared")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_data_shared: Option<bool>,
+/// <p>The advanced security mode.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AdvancedSecurityModeType {
+    /// <p><code>OFF</code></p>
+    Off,
+    /// <p><code>AUDIT</code></p>
+    Audit,
+    /// <p><code>ENFORCED</code></p>
+    Enforced,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AdvancedSecurityModeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdvancedSecurityModeType::Off => "OFF",
+            AdvancedSecurityModeType::Audit => "AUDIT",
+            AdvancedSecurityModeType::Enforced => "ENFORCED",
+            AdvancedSecurityModeType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>An Amazon Pinpoint analytics endpoint.</p> <p>An endpoint uniquely identifies a mobile device, email address, or phone number that can receive messages from Amazon Pinpoint analytics.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct AnalyticsMetadataType {
-    /// <p>The endpoint ID.</p>
-    #[serde(rename = "AnalyticsEndpointId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub analytics_endpoint_id: Option<String>,
+impl<'a> From<&'a str> for AdvancedSecurityModeType {
+    fn from(value: &'a str) -> AdvancedSecurityModeType {
+        match value {
+            "OFF" => AdvancedSecurityModeType::Off,
+            "AUDIT" => AdvancedSecurityModeType::Audit,
+            "ENFORCED" => AdvancedSecurityModeType::Enforced,
+            _ => AdvancedSecurityModeType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct AssociateSoftwareTokenRequest {
-    /// <p>The access token.</p>
-    #[serde(rename = "AccessToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_token: Option<String>,
-    /// <p>The session which should be passed both ways in challenge-response calls to the service. This allows authentication of the user as part of the MFA setup process.</p>
-    #[serde(rename = "Session")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub session: Option<String>,
+impl From<String> for AdvancedSecurityModeType {
+    fn from(value: String) -> AdvancedSecurityModeType {
+        AdvancedSecurityModeType::from(value.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct AssociateSoftwareTokenResponse {
-    /// <p>A unique generated shared secret code that is used in the TOTP algorithm to generate a one time code.</p>
-    #[serde(rename = "SecretCode")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub secret_code: Option<String>,
-    /// <p>The session which should be passed both ways in challenge-response calls to the service. This allows authentication of the user as part of the MFA setup process.</p>
-    #[serde(rename = "Session")]
+impl From<AdvancedSecurityModeType> for String {
+    fn from(value: AdvancedSecurityModeType) -> String {
+        match value {
+            AdvancedSecurityModeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AdvancedSecurityModeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AdvancedSecurityModeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdvancedSecurityModeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AdvancedSecurityModeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AdvancedSecurityModeType::from(value))
+    }
+}
+
+/// <p>Attributes supported as an alias for this user pool. Possible values: <b>phone_number</b>, <b>email</b>, or <b>preferred_username</b>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AliasAttributeType {
+    /// <p><code>phone_number</code></p>
+    PhoneNumber,
+    /// <p><code>email</code></p>
+    Email,
+    /// <p><code>preferred_username</code></p>
+    PreferredUsername,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AliasAttributeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AliasAttributeType::PhoneNumber => "phone_number",
+            AliasAttributeType::Email => "email",
+            AliasAttributeType::PreferredUsername => "preferred_username",
+            AliasAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AliasAttributeType {
+    fn from(value: &'a str) -> AliasAttributeType {
+        match value {
+            "phone_number" => AliasAttributeType::PhoneNumber,
+            "email" => AliasAttributeType::Email,
+            "preferred_username" => AliasAttributeType::PreferredUsername,
+            _ => AliasAttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AliasAttributeType {
+    fn from(value: String) -> AliasAttributeType {
+        AliasAttributeType::from(value.as_str())
+    }
+}
+
+impl From<AliasAttributeType> for String {
+    fn from(value: AliasAttributeType) -> String {
+        match value {
+            AliasAttributeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AliasAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AliasAttributeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AliasAttributeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AliasAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AliasAttributeType::from(value))
+    }
+}
+
+/// <p>The Amazon Pinpoint analytics configuration for collecting metrics for a user pool.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnalyticsConfigurationType {
+    /// <p>The application ID for an Amazon Pinpoint application.</p>
+    #[serde(rename = "ApplicationId")]
+    pub application_id: String,
+    /// <p>The external ID.</p>
+    #[serde(rename = "ExternalId")]
+    pub external_id: String,
+    /// <p>The ARN of an IAM role that authorizes Amazon Cognito to publish events to Amazon Pinpoint analytics.</p>
+    #[serde(rename = "RoleArn")]
+    pub role_arn: String,
+    /// <p>If <code>UserDataShared</code> is <code>true</code>, Amazon Cognito will include user data in the events it publishes to Amazon Pinpoint analytics.</p>
+    #[serde(rename = "UserDataShared")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_data_shared: Option<bool>,
+}
+
+/// <p>An Amazon Pinpoint analytics endpoint.</p> <p>An endpoint uniquely identifies a mobile device, email address, or phone number that can receive messages from Amazon Pinpoint analytics.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct AnalyticsMetadataType {
+    /// <p>The endpoint ID.</p>
+    #[serde(rename = "AnalyticsEndpointId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analytics_endpoint_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct AssociateSoftwareTokenRequest {
+    /// <p>The access token.</p>
+    #[serde(rename = "AccessToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token: Option<String>,
+    /// <p>The session which should be passed both ways in challenge-response calls to the service. This allows authentication of the user as part of the MFA setup process.</p>
+    #[serde(rename = "Session")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+}
+
+impl AssociateSoftwareTokenRequest {
+    /// Returns a builder for `AssociateSoftwareTokenRequest`, checking its required members when built.
+    pub fn builder() -> AssociateSoftwareTokenRequestBuilder {
+        AssociateSoftwareTokenRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateSoftwareTokenRequest`](struct.AssociateSoftwareTokenRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateSoftwareTokenRequestBuilder {
+    access_token: Option<String>,
+    session: Option<String>,
+}
+
+impl AssociateSoftwareTokenRequestBuilder {
+    /// Sets the [`access_token`](struct.AssociateSoftwareTokenRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`session`](struct.AssociateSoftwareTokenRequest.html#structfield.session) member.
+    pub fn session<T: Into<String>>(mut self, value: T) -> Self {
+        self.session = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateSoftwareTokenRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateSoftwareTokenRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateSoftwareTokenRequest {
+            access_token: self.access_token,
+            session: self.session,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AssociateSoftwareTokenResponse {
+    /// <p>A unique generated shared secret code that is used in the TOTP algorithm to generate a one time code.</p>
+    #[serde(rename = "SecretCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_code: Option<String>,
+    /// <p>The session which should be passed both ways in challenge-response calls to the service. This allows authentication of the user as part of the MFA setup process.</p>
+    #[serde(rename = "Session")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub session: Option<String>,
 }
 
+/// <p>The attribute data type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AttributeDataType {
+    /// <p><code>String</code></p>
+    String,
+    /// <p><code>Number</code></p>
+    Number,
+    /// <p><code>DateTime</code></p>
+    DateTime,
+    /// <p><code>Boolean</code></p>
+    Boolean,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AttributeDataType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttributeDataType::String => "String",
+            AttributeDataType::Number => "Number",
+            AttributeDataType::DateTime => "DateTime",
+            AttributeDataType::Boolean => "Boolean",
+            AttributeDataType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AttributeDataType {
+    fn from(value: &'a str) -> AttributeDataType {
+        match value {
+            "String" => AttributeDataType::String,
+            "Number" => AttributeDataType::Number,
+            "DateTime" => AttributeDataType::DateTime,
+            "Boolean" => AttributeDataType::Boolean,
+            _ => AttributeDataType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttributeDataType {
+    fn from(value: String) -> AttributeDataType {
+        AttributeDataType::from(value.as_str())
+    }
+}
+
+impl From<AttributeDataType> for String {
+    fn from(value: AttributeDataType) -> String {
+        match value {
+            AttributeDataType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AttributeDataType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AttributeDataType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AttributeDataType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AttributeDataType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AttributeDataType::from(value))
+    }
+}
+
 /// <p>Specifies whether the attribute is standard or custom.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttributeType {
@@ -790,8 +2616,9 @@ pub struct AuthEventType {
     pub challenge_responses: Option<Vec<ChallengeResponseType>>,
     /// <p>The creation date</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The user context data captured at the time of an event request. It provides additional information about the client from which event the request is received.</p>
     #[serde(rename = "EventContextData")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -807,7 +2634,7 @@ pub struct AuthEventType {
     /// <p>The event response.</p>
     #[serde(rename = "EventResponse")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_response: Option<String>,
+    pub event_response: Option<EventResponseType>,
     /// <p>The event risk.</p>
     #[serde(rename = "EventRisk")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -815,7 +2642,99 @@ pub struct AuthEventType {
     /// <p>The event type.</p>
     #[serde(rename = "EventType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type: Option<String>,
+    pub event_type: Option<EventType>,
+}
+
+/// <p><p>The authentication flow for this call to execute. The API action will depend on this value. For example:</p> <ul> <li> <p> <code>REFRESH<em>TOKEN</em>AUTH</code> will take in a valid refresh token and return new tokens.</p> </li> <li> <p> <code>USER<em>SRP</em>AUTH</code> will take in <code>USERNAME</code> and <code>SRP<em>A</code> and return the SRP variables to be used for next challenge execution.</p> </li> <li> <p> <code>USER</em>PASSWORD<em>AUTH</code> will take in <code>USERNAME</code> and <code>PASSWORD</code> and return the next challenge or tokens.</p> </li> </ul> <p>Valid values include:</p> <ul> <li> <p> <code>USER</em>SRP<em>AUTH</code>: Authentication flow for the Secure Remote Password (SRP) protocol.</p> </li> <li> <p> <code>REFRESH</em>TOKEN<em>AUTH</code>/<code>REFRESH</em>TOKEN</code>: Authentication flow for refreshing the access token and ID token by supplying a valid refresh token.</p> </li> <li> <p> <code>CUSTOM<em>AUTH</code>: Custom authentication flow.</p> </li> <li> <p> <code>ADMIN</em>NO<em>SRP</em>AUTH</code>: Non-SRP authentication flow; you can pass in the USERNAME and PASSWORD directly if the flow is enabled for calling the app client.</p> </li> <li> <p> <code>USER<em>PASSWORD</em>AUTH</code>: Non-SRP authentication flow; USERNAME and PASSWORD are passed directly. If a user migration Lambda trigger is set, this flow will invoke the user migration Lambda if the USERNAME is not found in the user pool. </p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthFlowType {
+    /// <p><code>USER_SRP_AUTH</code> - Authentication flow for the Secure Remote Password (SRP) protocol.</p>
+    UserSrpAuth,
+    /// <p><code>REFRESH_TOKEN_AUTH</code></p>
+    RefreshTokenAuth,
+    /// <p><code>REFRESH_TOKEN</code> - Authentication flow for refreshing the access token and ID token by supplying a valid refresh token.</p>
+    RefreshToken,
+    /// <p><code>CUSTOM_AUTH</code> - Custom authentication flow.</p>
+    CustomAuth,
+    /// <p><code>ADMIN_NO_SRP_AUTH</code></p>
+    AdminNoSrpAuth,
+    /// <p><code>USER_PASSWORD_AUTH</code> - Non-SRP authentication flow; USERNAME and PASSWORD are passed directly. If a user migration Lambda trigger is set, this flow will invoke the user migration Lambda if the USERNAME is not found in the user pool.</p>
+    UserPasswordAuth,
+    /// <p><code>ADMIN_USER_PASSWORD_AUTH</code></p>
+    AdminUserPasswordAuth,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthFlowType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthFlowType::UserSrpAuth => "USER_SRP_AUTH",
+            AuthFlowType::RefreshTokenAuth => "REFRESH_TOKEN_AUTH",
+            AuthFlowType::RefreshToken => "REFRESH_TOKEN",
+            AuthFlowType::CustomAuth => "CUSTOM_AUTH",
+            AuthFlowType::AdminNoSrpAuth => "ADMIN_NO_SRP_AUTH",
+            AuthFlowType::UserPasswordAuth => "USER_PASSWORD_AUTH",
+            AuthFlowType::AdminUserPasswordAuth => "ADMIN_USER_PASSWORD_AUTH",
+            AuthFlowType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthFlowType {
+    fn from(value: &'a str) -> AuthFlowType {
+        match value {
+            "USER_SRP_AUTH" => AuthFlowType::UserSrpAuth,
+            "REFRESH_TOKEN_AUTH" => AuthFlowType::RefreshTokenAuth,
+            "REFRESH_TOKEN" => AuthFlowType::RefreshToken,
+            "CUSTOM_AUTH" => AuthFlowType::CustomAuth,
+            "ADMIN_NO_SRP_AUTH" => AuthFlowType::AdminNoSrpAuth,
+            "USER_PASSWORD_AUTH" => AuthFlowType::UserPasswordAuth,
+            "ADMIN_USER_PASSWORD_AUTH" => AuthFlowType::AdminUserPasswordAuth,
+            _ => AuthFlowType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthFlowType {
+    fn from(value: String) -> AuthFlowType {
+        AuthFlowType::from(value.as_str())
+    }
+}
+
+impl From<AuthFlowType> for String {
+    fn from(value: AuthFlowType) -> String {
+        match value {
+            AuthFlowType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthFlowType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AuthFlowType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthFlowType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthFlowType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthFlowType::from(value))
+    }
 }
 
 /// <p>The authentication result.</p>
@@ -848,97 +2767,528 @@ pub struct AuthenticationResultType {
     pub token_type: Option<String>,
 }
 
-/// <p>The challenge response type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ChallengeResponseType {
-    /// <p>The challenge name</p>
-    #[serde(rename = "ChallengeName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_name: Option<String>,
-    /// <p>The challenge response.</p>
-    #[serde(rename = "ChallengeResponse")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_response: Option<String>,
+/// <p>The challenge name</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChallengeName {
+    /// <p><code>Password</code></p>
+    Password,
+    /// <p><code>Mfa</code></p>
+    Mfa,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Represents the request to change a user password.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ChangePasswordRequest {
-    /// <p>The access token.</p>
-    #[serde(rename = "AccessToken")]
-    pub access_token: String,
-    /// <p>The old password.</p>
-    #[serde(rename = "PreviousPassword")]
-    pub previous_password: String,
-    /// <p>The new password.</p>
-    #[serde(rename = "ProposedPassword")]
-    pub proposed_password: String,
+impl ChallengeName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChallengeName::Password => "Password",
+            ChallengeName::Mfa => "Mfa",
+            ChallengeName::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>The response from the server to the change password request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ChangePasswordResponse {}
+impl<'a> From<&'a str> for ChallengeName {
+    fn from(value: &'a str) -> ChallengeName {
+        match value {
+            "Password" => ChallengeName::Password,
+            "Mfa" => ChallengeName::Mfa,
+            _ => ChallengeName::Unknown(value.to_owned()),
+        }
+    }
+}
 
-/// <p>The code delivery details being returned from the server.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CodeDeliveryDetailsType {
-    /// <p>The attribute name.</p>
-    #[serde(rename = "AttributeName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute_name: Option<String>,
-    /// <p>The delivery medium (email message or phone number).</p>
-    #[serde(rename = "DeliveryMedium")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_medium: Option<String>,
-    /// <p>The destination for the code delivery details.</p>
-    #[serde(rename = "Destination")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination: Option<String>,
+impl From<String> for ChallengeName {
+    fn from(value: String) -> ChallengeName {
+        ChallengeName::from(value.as_str())
+    }
 }
 
-/// <p>The compromised credentials actions type</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CompromisedCredentialsActionsType {
-    /// <p>The event action.</p>
-    #[serde(rename = "EventAction")]
-    pub event_action: String,
+impl From<ChallengeName> for String {
+    fn from(value: ChallengeName) -> String {
+        match value {
+            ChallengeName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-/// <p>The compromised credentials risk configuration type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CompromisedCredentialsRiskConfigurationType {
-    /// <p>The compromised credentials risk configuration actions.</p>
-    #[serde(rename = "Actions")]
-    pub actions: CompromisedCredentialsActionsType,
-    /// <p>Perform the action for these events. The default is to perform all events if no event filter is specified.</p>
-    #[serde(rename = "EventFilter")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_filter: Option<Vec<String>>,
+impl fmt::Display for ChallengeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
-/// <p>Confirms the device request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ConfirmDeviceRequest {
-    /// <p>The access token.</p>
-    #[serde(rename = "AccessToken")]
-    pub access_token: String,
-    /// <p>The device key.</p>
-    #[serde(rename = "DeviceKey")]
-    pub device_key: String,
-    /// <p>The device name.</p>
-    #[serde(rename = "DeviceName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_name: Option<String>,
-    /// <p>The configuration of the device secret verifier.</p>
-    #[serde(rename = "DeviceSecretVerifierConfig")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_secret_verifier_config: Option<DeviceSecretVerifierConfigType>,
+impl ::serde::Serialize for ChallengeName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-/// <p>Confirms the device response.</p>
+impl<'de> ::serde::Deserialize<'de> for ChallengeName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChallengeName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChallengeName::from(value))
+    }
+}
+
+/// <p><p>The name of the challenge which you are responding to with this call. This is returned to you in the <code>AdminInitiateAuth</code> response if you need to pass another challenge.</p> <ul> <li> <p> <code>MFA<em>SETUP</code>: If MFA is required, users who do not have at least one of the MFA methods set up are presented with an <code>MFA</em>SETUP</code> challenge. The user must set up at least one MFA type to continue to authenticate.</p> </li> <li> <p> <code>SELECT<em>MFA</em>TYPE</code>: Selects the MFA type. Valid MFA options are <code>SMS<em>MFA</code> for text SMS MFA, and <code>SOFTWARE</em>TOKEN<em>MFA</code> for TOTP software token MFA.</p> </li> <li> <p> <code>SMS</em>MFA</code>: Next challenge is to supply an <code>SMS<em>MFA</em>CODE</code>, delivered via SMS.</p> </li> <li> <p> <code>PASSWORD<em>VERIFIER</code>: Next challenge is to supply <code>PASSWORD</em>CLAIM<em>SIGNATURE</code>, <code>PASSWORD</em>CLAIM<em>SECRET</em>BLOCK</code>, and <code>TIMESTAMP</code> after the client-side SRP calculations.</p> </li> <li> <p> <code>CUSTOM<em>CHALLENGE</code>: This is returned if your custom authentication flow determines that the user should pass another challenge before tokens are issued.</p> </li> <li> <p> <code>DEVICE</em>SRP<em>AUTH</code>: If device tracking was enabled on your user pool and the previous challenges were passed, this challenge is returned so that Amazon Cognito can start tracking this device.</p> </li> <li> <p> <code>DEVICE</em>PASSWORD<em>VERIFIER</code>: Similar to <code>PASSWORD</em>VERIFIER</code>, but for devices only.</p> </li> <li> <p> <code>ADMIN<em>NO</em>SRP<em>AUTH</code>: This is returned if you need to authenticate with <code>USERNAME</code> and <code>PASSWORD</code> directly. An app client must be enabled to use this flow.</p> </li> <li> <p> <code>NEW</em>PASSWORD<em>REQUIRED</code>: For users which are required to change their passwords after successful first login. This challenge should be passed with <code>NEW</em>PASSWORD</code> and any other required attributes.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChallengeNameType {
+    /// <p><code>SMS_MFA</code></p>
+    SmsMfa,
+    /// <p><code>SOFTWARE_TOKEN_MFA</code></p>
+    SoftwareTokenMfa,
+    /// <p><code>SELECT_MFA_TYPE</code></p>
+    SelectMfaType,
+    /// <p><code>MFA_SETUP</code></p>
+    MfaSetup,
+    /// <p><code>PASSWORD_VERIFIER</code></p>
+    PasswordVerifier,
+    /// <p><code>CUSTOM_CHALLENGE</code></p>
+    CustomChallenge,
+    /// <p><code>DEVICE_SRP_AUTH</code></p>
+    DeviceSrpAuth,
+    /// <p><code>DEVICE_PASSWORD_VERIFIER</code></p>
+    DevicePasswordVerifier,
+    /// <p><code>ADMIN_NO_SRP_AUTH</code></p>
+    AdminNoSrpAuth,
+    /// <p><code>NEW_PASSWORD_REQUIRED</code></p>
+    NewPasswordRequired,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChallengeNameType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChallengeNameType::SmsMfa => "SMS_MFA",
+            ChallengeNameType::SoftwareTokenMfa => "SOFTWARE_TOKEN_MFA",
+            ChallengeNameType::SelectMfaType => "SELECT_MFA_TYPE",
+            ChallengeNameType::MfaSetup => "MFA_SETUP",
+            ChallengeNameType::PasswordVerifier => "PASSWORD_VERIFIER",
+            ChallengeNameType::CustomChallenge => "CUSTOM_CHALLENGE",
+            ChallengeNameType::DeviceSrpAuth => "DEVICE_SRP_AUTH",
+            ChallengeNameType::DevicePasswordVerifier => "DEVICE_PASSWORD_VERIFIER",
+            ChallengeNameType::AdminNoSrpAuth => "ADMIN_NO_SRP_AUTH",
+            ChallengeNameType::NewPasswordRequired => "NEW_PASSWORD_REQUIRED",
+            ChallengeNameType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChallengeNameType {
+    fn from(value: &'a str) -> ChallengeNameType {
+        match value {
+            "SMS_MFA" => ChallengeNameType::SmsMfa,
+            "SOFTWARE_TOKEN_MFA" => ChallengeNameType::SoftwareTokenMfa,
+            "SELECT_MFA_TYPE" => ChallengeNameType::SelectMfaType,
+            "MFA_SETUP" => ChallengeNameType::MfaSetup,
+            "PASSWORD_VERIFIER" => ChallengeNameType::PasswordVerifier,
+            "CUSTOM_CHALLENGE" => ChallengeNameType::CustomChallenge,
+            "DEVICE_SRP_AUTH" => ChallengeNameType::DeviceSrpAuth,
+            "DEVICE_PASSWORD_VERIFIER" => ChallengeNameType::DevicePasswordVerifier,
+            "ADMIN_NO_SRP_AUTH" => ChallengeNameType::AdminNoSrpAuth,
+            "NEW_PASSWORD_REQUIRED" => ChallengeNameType::NewPasswordRequired,
+            _ => ChallengeNameType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChallengeNameType {
+    fn from(value: String) -> ChallengeNameType {
+        ChallengeNameType::from(value.as_str())
+    }
+}
+
+impl From<ChallengeNameType> for String {
+    fn from(value: ChallengeNameType) -> String {
+        match value {
+            ChallengeNameType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChallengeNameType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ChallengeNameType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChallengeNameType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChallengeNameType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChallengeNameType::from(value))
+    }
+}
+
+/// <p>The challenge response.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChallengeResponse {
+    /// <p><code>Success</code></p>
+    Success,
+    /// <p><code>Failure</code></p>
+    Failure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChallengeResponse {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChallengeResponse::Success => "Success",
+            ChallengeResponse::Failure => "Failure",
+            ChallengeResponse::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChallengeResponse {
+    fn from(value: &'a str) -> ChallengeResponse {
+        match value {
+            "Success" => ChallengeResponse::Success,
+            "Failure" => ChallengeResponse::Failure,
+            _ => ChallengeResponse::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChallengeResponse {
+    fn from(value: String) -> ChallengeResponse {
+        ChallengeResponse::from(value.as_str())
+    }
+}
+
+impl From<ChallengeResponse> for String {
+    fn from(value: ChallengeResponse) -> String {
+        match value {
+            ChallengeResponse::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChallengeResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ChallengeResponse {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChallengeResponse {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChallengeResponse, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChallengeResponse::from(value))
+    }
+}
+
+/// <p>The challenge response type.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ChallengeResponseType {
+    /// <p>The challenge name</p>
+    #[serde(rename = "ChallengeName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge_name: Option<ChallengeName>,
+    /// <p>The challenge response.</p>
+    #[serde(rename = "ChallengeResponse")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge_response: Option<ChallengeResponse>,
+}
+
+/// <p>Represents the request to change a user password.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ChangePasswordRequest {
+    /// <p>The access token.</p>
+    #[serde(rename = "AccessToken")]
+    pub access_token: String,
+    /// <p>The old password.</p>
+    #[serde(rename = "PreviousPassword")]
+    pub previous_password: String,
+    /// <p>The new password.</p>
+    #[serde(rename = "ProposedPassword")]
+    pub proposed_password: String,
+}
+
+impl ChangePasswordRequest {
+    /// Returns a builder for `ChangePasswordRequest`, checking its required members when built.
+    pub fn builder() -> ChangePasswordRequestBuilder {
+        ChangePasswordRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ChangePasswordRequest`](struct.ChangePasswordRequest.html).
+#[derive(Default, Debug)]
+pub struct ChangePasswordRequestBuilder {
+    access_token: Option<String>,
+    previous_password: Option<String>,
+    proposed_password: Option<String>,
+}
+
+impl ChangePasswordRequestBuilder {
+    /// Sets the [`access_token`](struct.ChangePasswordRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`previous_password`](struct.ChangePasswordRequest.html#structfield.previous_password) member.
+    pub fn previous_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.previous_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`proposed_password`](struct.ChangePasswordRequest.html#structfield.proposed_password) member.
+    pub fn proposed_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.proposed_password = Some(value.into());
+        self
+    }
+
+    /// Builds the `ChangePasswordRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ChangePasswordRequest, ::rusoto_core::BuildError> {
+        Ok(ChangePasswordRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+            previous_password: self
+                .previous_password
+                .ok_or(::rusoto_core::BuildError::missing("previous_password"))?,
+            proposed_password: self
+                .proposed_password
+                .ok_or(::rusoto_core::BuildError::missing("proposed_password"))?,
+        })
+    }
+}
+
+/// <p>The response from the server to the change password request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ChangePasswordResponse {}
+
+/// <p>The code delivery details being returned from the server.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CodeDeliveryDetailsType {
+    /// <p>The attribute name.</p>
+    #[serde(rename = "AttributeName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribute_name: Option<String>,
+    /// <p>The delivery medium (email message or phone number).</p>
+    #[serde(rename = "DeliveryMedium")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_medium: Option<DeliveryMediumType>,
+    /// <p>The destination for the code delivery details.</p>
+    #[serde(rename = "Destination")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+}
+
+/// <p>The compromised credentials actions type</p>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompromisedCredentialsActionsType {
+    /// <p>The event action.</p>
+    #[serde(rename = "EventAction")]
+    pub event_action: CompromisedCredentialsEventActionType,
+}
+
+/// <p>The event action.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CompromisedCredentialsEventActionType {
+    /// <p><code>BLOCK</code></p>
+    Block,
+    /// <p><code>NO_ACTION</code></p>
+    NoAction,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CompromisedCredentialsEventActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CompromisedCredentialsEventActionType::Block => "BLOCK",
+            CompromisedCredentialsEventActionType::NoAction => "NO_ACTION",
+            CompromisedCredentialsEventActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CompromisedCredentialsEventActionType {
+    fn from(value: &'a str) -> CompromisedCredentialsEventActionType {
+        match value {
+            "BLOCK" => CompromisedCredentialsEventActionType::Block,
+            "NO_ACTION" => CompromisedCredentialsEventActionType::NoAction,
+            _ => CompromisedCredentialsEventActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CompromisedCredentialsEventActionType {
+    fn from(value: String) -> CompromisedCredentialsEventActionType {
+        CompromisedCredentialsEventActionType::from(value.as_str())
+    }
+}
+
+impl From<CompromisedCredentialsEventActionType> for String {
+    fn from(value: CompromisedCredentialsEventActionType) -> String {
+        match value {
+            CompromisedCredentialsEventActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CompromisedCredentialsEventActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CompromisedCredentialsEventActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CompromisedCredentialsEventActionType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<CompromisedCredentialsEventActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CompromisedCredentialsEventActionType::from(value))
+    }
+}
+
+/// <p>The compromised credentials risk configuration type.</p>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompromisedCredentialsRiskConfigurationType {
+    /// <p>The compromised credentials risk configuration actions.</p>
+    #[serde(rename = "Actions")]
+    pub actions: CompromisedCredentialsActionsType,
+    /// <p>Perform the action for these events. The default is to perform all events if no event filter is specified.</p>
+    #[serde(rename = "EventFilter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_filter: Option<Vec<EventFilterType>>,
+}
+
+/// <p>Confirms the device request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ConfirmDeviceRequest {
+    /// <p>The access token.</p>
+    #[serde(rename = "AccessToken")]
+    pub access_token: String,
+    /// <p>The device key.</p>
+    #[serde(rename = "DeviceKey")]
+    pub device_key: String,
+    /// <p>The device name.</p>
+    #[serde(rename = "DeviceName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_name: Option<String>,
+    /// <p>The configuration of the device secret verifier.</p>
+    #[serde(rename = "DeviceSecretVerifierConfig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_secret_verifier_config: Option<DeviceSecretVerifierConfigType>,
+}
+
+impl ConfirmDeviceRequest {
+    /// Returns a builder for `ConfirmDeviceRequest`, checking its required members when built.
+    pub fn builder() -> ConfirmDeviceRequestBuilder {
+        ConfirmDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ConfirmDeviceRequest`](struct.ConfirmDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct ConfirmDeviceRequestBuilder {
+    access_token: Option<String>,
+    device_key: Option<String>,
+    device_name: Option<String>,
+    device_secret_verifier_config: Option<DeviceSecretVerifierConfigType>,
+}
+
+impl ConfirmDeviceRequestBuilder {
+    /// Sets the [`access_token`](struct.ConfirmDeviceRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_key`](struct.ConfirmDeviceRequest.html#structfield.device_key) member.
+    pub fn device_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_name`](struct.ConfirmDeviceRequest.html#structfield.device_name) member.
+    pub fn device_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_secret_verifier_config`](struct.ConfirmDeviceRequest.html#structfield.device_secret_verifier_config) member.
+    pub fn device_secret_verifier_config<T: Into<DeviceSecretVerifierConfigType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.device_secret_verifier_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfirmDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ConfirmDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(ConfirmDeviceRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+            device_key: self
+                .device_key
+                .ok_or(::rusoto_core::BuildError::missing("device_key"))?,
+            device_name: self.device_name,
+            device_secret_verifier_config: self.device_secret_verifier_config,
+        })
+    }
+}
+
+/// <p>Confirms the device response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ConfirmDeviceResponse {
@@ -977,6 +3327,92 @@ pub struct ConfirmForgotPasswordRequest {
     pub username: String,
 }
 
+impl ConfirmForgotPasswordRequest {
+    /// Returns a builder for `ConfirmForgotPasswordRequest`, checking its required members when built.
+    pub fn builder() -> ConfirmForgotPasswordRequestBuilder {
+        ConfirmForgotPasswordRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ConfirmForgotPasswordRequest`](struct.ConfirmForgotPasswordRequest.html).
+#[derive(Default, Debug)]
+pub struct ConfirmForgotPasswordRequestBuilder {
+    analytics_metadata: Option<AnalyticsMetadataType>,
+    client_id: Option<String>,
+    confirmation_code: Option<String>,
+    password: Option<String>,
+    secret_hash: Option<String>,
+    user_context_data: Option<UserContextDataType>,
+    username: Option<String>,
+}
+
+impl ConfirmForgotPasswordRequestBuilder {
+    /// Sets the [`analytics_metadata`](struct.ConfirmForgotPasswordRequest.html#structfield.analytics_metadata) member.
+    pub fn analytics_metadata<T: Into<AnalyticsMetadataType>>(mut self, value: T) -> Self {
+        self.analytics_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.ConfirmForgotPasswordRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`confirmation_code`](struct.ConfirmForgotPasswordRequest.html#structfield.confirmation_code) member.
+    pub fn confirmation_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.confirmation_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.ConfirmForgotPasswordRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_hash`](struct.ConfirmForgotPasswordRequest.html#structfield.secret_hash) member.
+    pub fn secret_hash<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_hash = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_context_data`](struct.ConfirmForgotPasswordRequest.html#structfield.user_context_data) member.
+    pub fn user_context_data<T: Into<UserContextDataType>>(mut self, value: T) -> Self {
+        self.user_context_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.ConfirmForgotPasswordRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfirmForgotPasswordRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ConfirmForgotPasswordRequest, ::rusoto_core::BuildError> {
+        Ok(ConfirmForgotPasswordRequest {
+            analytics_metadata: self.analytics_metadata,
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            confirmation_code: self
+                .confirmation_code
+                .ok_or(::rusoto_core::BuildError::missing("confirmation_code"))?,
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+            secret_hash: self.secret_hash,
+            user_context_data: self.user_context_data,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>The response from the server that results from a user's request to retrieve a forgotten password.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1012,26 +3448,108 @@ pub struct ConfirmSignUpRequest {
     pub username: String,
 }
 
-/// <p>Represents the response from the server for the registration confirmation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ConfirmSignUpResponse {}
+impl ConfirmSignUpRequest {
+    /// Returns a builder for `ConfirmSignUpRequest`, checking its required members when built.
+    pub fn builder() -> ConfirmSignUpRequestBuilder {
+        ConfirmSignUpRequestBuilder::default()
+    }
+}
 
-/// <p>Contextual user data type used for evaluating the risk of an unexpected event by Amazon Cognito advanced security.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ContextDataType {
-    /// <p>Encoded data containing device fingerprinting details, collected using the Amazon Cognito context data collection library.</p>
-    #[serde(rename = "EncodedData")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub encoded_data: Option<String>,
-    /// <p>HttpHeaders received on your server in same order.</p>
-    #[serde(rename = "HttpHeaders")]
-    pub http_headers: Vec<HttpHeader>,
-    /// <p>Source IP address of your user.</p>
-    #[serde(rename = "IpAddress")]
-    pub ip_address: String,
-    /// <p>Your server endpoint where this API is invoked.</p>
-    #[serde(rename = "ServerName")]
+/// Builds a [`ConfirmSignUpRequest`](struct.ConfirmSignUpRequest.html).
+#[derive(Default, Debug)]
+pub struct ConfirmSignUpRequestBuilder {
+    analytics_metadata: Option<AnalyticsMetadataType>,
+    client_id: Option<String>,
+    confirmation_code: Option<String>,
+    force_alias_creation: Option<bool>,
+    secret_hash: Option<String>,
+    user_context_data: Option<UserContextDataType>,
+    username: Option<String>,
+}
+
+impl ConfirmSignUpRequestBuilder {
+    /// Sets the [`analytics_metadata`](struct.ConfirmSignUpRequest.html#structfield.analytics_metadata) member.
+    pub fn analytics_metadata<T: Into<AnalyticsMetadataType>>(mut self, value: T) -> Self {
+        self.analytics_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.ConfirmSignUpRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`confirmation_code`](struct.ConfirmSignUpRequest.html#structfield.confirmation_code) member.
+    pub fn confirmation_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.confirmation_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_alias_creation`](struct.ConfirmSignUpRequest.html#structfield.force_alias_creation) member.
+    pub fn force_alias_creation<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_alias_creation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_hash`](struct.ConfirmSignUpRequest.html#structfield.secret_hash) member.
+    pub fn secret_hash<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_hash = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_context_data`](struct.ConfirmSignUpRequest.html#structfield.user_context_data) member.
+    pub fn user_context_data<T: Into<UserContextDataType>>(mut self, value: T) -> Self {
+        self.user_context_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.ConfirmSignUpRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfirmSignUpRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ConfirmSignUpRequest, ::rusoto_core::BuildError> {
+        Ok(ConfirmSignUpRequest {
+            analytics_metadata: self.analytics_metadata,
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            confirmation_code: self
+                .confirmation_code
+                .ok_or(::rusoto_core::BuildError::missing("confirmation_code"))?,
+            force_alias_creation: self.force_alias_creation,
+            secret_hash: self.secret_hash,
+            user_context_data: self.user_context_data,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
+/// <p>Represents the response from the server for the registration confirmation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ConfirmSignUpResponse {}
+
+/// <p>Contextual user data type used for evaluating the risk of an unexpected event by Amazon Cognito advanced security.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ContextDataType {
+    /// <p>Encoded data containing device fingerprinting details, collected using the Amazon Cognito context data collection library.</p>
+    #[serde(rename = "EncodedData")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoded_data: Option<String>,
+    /// <p>HttpHeaders received on your server in same order.</p>
+    #[serde(rename = "HttpHeaders")]
+    pub http_headers: Vec<HttpHeader>,
+    /// <p>Source IP address of your user.</p>
+    #[serde(rename = "IpAddress")]
+    pub ip_address: String,
+    /// <p>Your server endpoint where this API is invoked.</p>
+    #[serde(rename = "ServerName")]
     pub server_name: String,
     /// <p>Your server path where this API is invoked. </p>
     #[serde(rename = "ServerPath")]
@@ -1060,6 +3578,70 @@ pub struct CreateGroupRequest {
     pub user_pool_id: String,
 }
 
+impl CreateGroupRequest {
+    /// Returns a builder for `CreateGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateGroupRequestBuilder {
+        CreateGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGroupRequest`](struct.CreateGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateGroupRequestBuilder {
+    description: Option<String>,
+    group_name: Option<String>,
+    precedence: Option<i64>,
+    role_arn: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl CreateGroupRequestBuilder {
+    /// Sets the [`description`](struct.CreateGroupRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.CreateGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`precedence`](struct.CreateGroupRequest.html#structfield.precedence) member.
+    pub fn precedence<T: Into<i64>>(mut self, value: T) -> Self {
+        self.precedence = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateGroupRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.CreateGroupRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateGroupRequest {
+            description: self.description,
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            precedence: self.precedence,
+            role_arn: self.role_arn,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateGroupResponse {
@@ -1069,7 +3651,7 @@ pub struct CreateGroupResponse {
     pub group: Option<GroupType>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateIdentityProviderRequest {
     /// <p>A mapping of identity provider attributes to standard and custom user pool attributes.</p>
     #[serde(rename = "AttributeMapping")]
@@ -1087,12 +3669,96 @@ pub struct CreateIdentityProviderRequest {
     pub provider_name: String,
     /// <p>The identity provider type.</p>
     #[serde(rename = "ProviderType")]
-    pub provider_type: String,
+    pub provider_type: IdentityProviderTypeType,
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
 }
 
+impl CreateIdentityProviderRequest {
+    /// Returns a builder for `CreateIdentityProviderRequest`, checking its required members when built.
+    pub fn builder() -> CreateIdentityProviderRequestBuilder {
+        CreateIdentityProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateIdentityProviderRequest`](struct.CreateIdentityProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateIdentityProviderRequestBuilder {
+    attribute_mapping: Option<::std::collections::HashMap<String, String>>,
+    idp_identifiers: Option<Vec<String>>,
+    provider_details: Option<::std::collections::HashMap<String, String>>,
+    provider_name: Option<String>,
+    provider_type: Option<IdentityProviderTypeType>,
+    user_pool_id: Option<String>,
+}
+
+impl CreateIdentityProviderRequestBuilder {
+    /// Sets the [`attribute_mapping`](struct.CreateIdentityProviderRequest.html#structfield.attribute_mapping) member.
+    pub fn attribute_mapping<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attribute_mapping = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idp_identifiers`](struct.CreateIdentityProviderRequest.html#structfield.idp_identifiers) member.
+    pub fn idp_identifiers<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.idp_identifiers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_details`](struct.CreateIdentityProviderRequest.html#structfield.provider_details) member.
+    pub fn provider_details<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.provider_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_name`](struct.CreateIdentityProviderRequest.html#structfield.provider_name) member.
+    pub fn provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_type`](struct.CreateIdentityProviderRequest.html#structfield.provider_type) member.
+    pub fn provider_type<T: Into<IdentityProviderTypeType>>(mut self, value: T) -> Self {
+        self.provider_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.CreateIdentityProviderRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateIdentityProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateIdentityProviderRequest, ::rusoto_core::BuildError> {
+        Ok(CreateIdentityProviderRequest {
+            attribute_mapping: self.attribute_mapping,
+            idp_identifiers: self.idp_identifiers,
+            provider_details: self
+                .provider_details
+                .ok_or(::rusoto_core::BuildError::missing("provider_details"))?,
+            provider_name: self
+                .provider_name
+                .ok_or(::rusoto_core::BuildError::missing("provider_name"))?,
+            provider_type: self
+                .provider_type
+                .ok_or(::rusoto_core::BuildError::missing("provider_type"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateIdentityProviderResponse {
@@ -1118,6 +3784,66 @@ pub struct CreateResourceServerRequest {
     pub user_pool_id: String,
 }
 
+impl CreateResourceServerRequest {
+    /// Returns a builder for `CreateResourceServerRequest`, checking its required members when built.
+    pub fn builder() -> CreateResourceServerRequestBuilder {
+        CreateResourceServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateResourceServerRequest`](struct.CreateResourceServerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateResourceServerRequestBuilder {
+    identifier: Option<String>,
+    name: Option<String>,
+    scopes: Option<Vec<ResourceServerScopeType>>,
+    user_pool_id: Option<String>,
+}
+
+impl CreateResourceServerRequestBuilder {
+    /// Sets the [`identifier`](struct.CreateResourceServerRequest.html#structfield.identifier) member.
+    pub fn identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateResourceServerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scopes`](struct.CreateResourceServerRequest.html#structfield.scopes) member.
+    pub fn scopes<T: Into<Vec<ResourceServerScopeType>>>(mut self, value: T) -> Self {
+        self.scopes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.CreateResourceServerRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateResourceServerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateResourceServerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateResourceServerRequest {
+            identifier: self
+                .identifier
+                .ok_or(::rusoto_core::BuildError::missing("identifier"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            scopes: self.scopes,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateResourceServerResponse {
@@ -1140,6 +3866,58 @@ pub struct CreateUserImportJobRequest {
     pub user_pool_id: String,
 }
 
+impl CreateUserImportJobRequest {
+    /// Returns a builder for `CreateUserImportJobRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserImportJobRequestBuilder {
+        CreateUserImportJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserImportJobRequest`](struct.CreateUserImportJobRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserImportJobRequestBuilder {
+    cloud_watch_logs_role_arn: Option<String>,
+    job_name: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl CreateUserImportJobRequestBuilder {
+    /// Sets the [`cloud_watch_logs_role_arn`](struct.CreateUserImportJobRequest.html#structfield.cloud_watch_logs_role_arn) member.
+    pub fn cloud_watch_logs_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cloud_watch_logs_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_name`](struct.CreateUserImportJobRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.CreateUserImportJobRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserImportJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateUserImportJobRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserImportJobRequest {
+            cloud_watch_logs_role_arn: self.cloud_watch_logs_role_arn.ok_or(
+                ::rusoto_core::BuildError::missing("cloud_watch_logs_role_arn"),
+            )?,
+            job_name: self
+                .job_name
+                .ok_or(::rusoto_core::BuildError::missing("job_name"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server to the request to create the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1156,7 +3934,7 @@ pub struct CreateUserPoolClientRequest {
     /// <p>Set to <code>code</code> to initiate a code grant flow, which provides an authorization code as the response. This code can be exchanged for access tokens with the token endpoint.</p> <p>Set to <code>token</code> to specify that the client should get the access token (and, optionally, ID token, based on scopes) directly.</p>
     #[serde(rename = "AllowedOAuthFlows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_o_auth_flows: Option<Vec<String>>,
+    pub allowed_o_auth_flows: Option<Vec<OAuthFlowType>>,
     /// <p>Set to <code>True</code> if the client is allowed to follow the OAuth protocol when interacting with Cognito user pools.</p>
     #[serde(rename = "AllowedOAuthFlowsUserPoolClient")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1183,7 +3961,7 @@ pub struct CreateUserPoolClientRequest {
     /// <p>The explicit authentication flows.</p>
     #[serde(rename = "ExplicitAuthFlows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub explicit_auth_flows: Option<Vec<String>>,
+    pub explicit_auth_flows: Option<Vec<ExplicitAuthFlowsType>>,
     /// <p>Boolean to specify whether you want to generate a secret for the user pool client being created.</p>
     #[serde(rename = "GenerateSecret")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1213,6 +3991,155 @@ pub struct CreateUserPoolClientRequest {
     pub write_attributes: Option<Vec<String>>,
 }
 
+impl CreateUserPoolClientRequest {
+    /// Returns a builder for `CreateUserPoolClientRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserPoolClientRequestBuilder {
+        CreateUserPoolClientRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserPoolClientRequest`](struct.CreateUserPoolClientRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserPoolClientRequestBuilder {
+    allowed_o_auth_flows: Option<Vec<OAuthFlowType>>,
+    allowed_o_auth_flows_user_pool_client: Option<bool>,
+    allowed_o_auth_scopes: Option<Vec<String>>,
+    analytics_configuration: Option<AnalyticsConfigurationType>,
+    callback_ur_ls: Option<Vec<String>>,
+    client_name: Option<String>,
+    default_redirect_uri: Option<String>,
+    explicit_auth_flows: Option<Vec<ExplicitAuthFlowsType>>,
+    generate_secret: Option<bool>,
+    logout_ur_ls: Option<Vec<String>>,
+    read_attributes: Option<Vec<String>>,
+    refresh_token_validity: Option<i64>,
+    supported_identity_providers: Option<Vec<String>>,
+    user_pool_id: Option<String>,
+    write_attributes: Option<Vec<String>>,
+}
+
+impl CreateUserPoolClientRequestBuilder {
+    /// Sets the [`allowed_o_auth_flows`](struct.CreateUserPoolClientRequest.html#structfield.allowed_o_auth_flows) member.
+    pub fn allowed_o_auth_flows<T: Into<Vec<OAuthFlowType>>>(mut self, value: T) -> Self {
+        self.allowed_o_auth_flows = Some(value.into());
+        self
+    }
+
+    /// Sets the [`allowed_o_auth_flows_user_pool_client`](struct.CreateUserPoolClientRequest.html#structfield.allowed_o_auth_flows_user_pool_client) member.
+    pub fn allowed_o_auth_flows_user_pool_client<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allowed_o_auth_flows_user_pool_client = Some(value.into());
+        self
+    }
+
+    /// Sets the [`allowed_o_auth_scopes`](struct.CreateUserPoolClientRequest.html#structfield.allowed_o_auth_scopes) member.
+    pub fn allowed_o_auth_scopes<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.allowed_o_auth_scopes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`analytics_configuration`](struct.CreateUserPoolClientRequest.html#structfield.analytics_configuration) member.
+    pub fn analytics_configuration<T: Into<AnalyticsConfigurationType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.analytics_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`callback_ur_ls`](struct.CreateUserPoolClientRequest.html#structfield.callback_ur_ls) member.
+    pub fn callback_ur_ls<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.callback_ur_ls = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_name`](struct.CreateUserPoolClientRequest.html#structfield.client_name) member.
+    pub fn client_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_redirect_uri`](struct.CreateUserPoolClientRequest.html#structfield.default_redirect_uri) member.
+    pub fn default_redirect_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_redirect_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`explicit_auth_flows`](struct.CreateUserPoolClientRequest.html#structfield.explicit_auth_flows) member.
+    pub fn explicit_auth_flows<T: Into<Vec<ExplicitAuthFlowsType>>>(mut self, value: T) -> Self {
+        self.explicit_auth_flows = Some(value.into());
+        self
+    }
+
+    /// Sets the [`generate_secret`](struct.CreateUserPoolClientRequest.html#structfield.generate_secret) member.
+    pub fn generate_secret<T: Into<bool>>(mut self, value: T) -> Self {
+        self.generate_secret = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logout_ur_ls`](struct.CreateUserPoolClientRequest.html#structfield.logout_ur_ls) member.
+    pub fn logout_ur_ls<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.logout_ur_ls = Some(value.into());
+        self
+    }
+
+    /// Sets the [`read_attributes`](struct.CreateUserPoolClientRequest.html#structfield.read_attributes) member.
+    pub fn read_attributes<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.read_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`refresh_token_validity`](struct.CreateUserPoolClientRequest.html#structfield.refresh_token_validity) member.
+    pub fn refresh_token_validity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.refresh_token_validity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`supported_identity_providers`](struct.CreateUserPoolClientRequest.html#structfield.supported_identity_providers) member.
+    pub fn supported_identity_providers<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.supported_identity_providers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.CreateUserPoolClientRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`write_attributes`](struct.CreateUserPoolClientRequest.html#structfield.write_attributes) member.
+    pub fn write_attributes<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.write_attributes = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserPoolClientRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateUserPoolClientRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserPoolClientRequest {
+            allowed_o_auth_flows: self.allowed_o_auth_flows,
+            allowed_o_auth_flows_user_pool_client: self.allowed_o_auth_flows_user_pool_client,
+            allowed_o_auth_scopes: self.allowed_o_auth_scopes,
+            analytics_configuration: self.analytics_configuration,
+            callback_ur_ls: self.callback_ur_ls,
+            client_name: self
+                .client_name
+                .ok_or(::rusoto_core::BuildError::missing("client_name"))?,
+            default_redirect_uri: self.default_redirect_uri,
+            explicit_auth_flows: self.explicit_auth_flows,
+            generate_secret: self.generate_secret,
+            logout_ur_ls: self.logout_ur_ls,
+            read_attributes: self.read_attributes,
+            refresh_token_validity: self.refresh_token_validity,
+            supported_identity_providers: self.supported_identity_providers,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            write_attributes: self.write_attributes,
+        })
+    }
+}
+
 /// <p>Represents the response from the server to create a user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1237,6 +4164,56 @@ pub struct CreateUserPoolDomainRequest {
     pub user_pool_id: String,
 }
 
+impl CreateUserPoolDomainRequest {
+    /// Returns a builder for `CreateUserPoolDomainRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserPoolDomainRequestBuilder {
+        CreateUserPoolDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserPoolDomainRequest`](struct.CreateUserPoolDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserPoolDomainRequestBuilder {
+    custom_domain_config: Option<CustomDomainConfigType>,
+    domain: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl CreateUserPoolDomainRequestBuilder {
+    /// Sets the [`custom_domain_config`](struct.CreateUserPoolDomainRequest.html#structfield.custom_domain_config) member.
+    pub fn custom_domain_config<T: Into<CustomDomainConfigType>>(mut self, value: T) -> Self {
+        self.custom_domain_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.CreateUserPoolDomainRequest.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.CreateUserPoolDomainRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserPoolDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateUserPoolDomainRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserPoolDomainRequest {
+            custom_domain_config: self.custom_domain_config,
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateUserPoolDomainResponse {
@@ -1256,11 +4233,11 @@ pub struct CreateUserPoolRequest {
     /// <p>Attributes supported as an alias for this user pool. Possible values: <b>phone_number</b>, <b>email</b>, or <b>preferred_username</b>.</p>
     #[serde(rename = "AliasAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alias_attributes: Option<Vec<String>>,
+    pub alias_attributes: Option<Vec<AliasAttributeType>>,
     /// <p>The attributes to be auto-verified. Possible values: <b>email</b>, <b>phone_number</b>.</p>
     #[serde(rename = "AutoVerifiedAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_verified_attributes: Option<Vec<String>>,
+    pub auto_verified_attributes: Option<Vec<VerifiedAttributeType>>,
     /// <p>The device configuration.</p>
     #[serde(rename = "DeviceConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1284,7 +4261,7 @@ pub struct CreateUserPoolRequest {
     /// <p>Specifies MFA configuration details.</p>
     #[serde(rename = "MfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mfa_configuration: Option<String>,
+    pub mfa_configuration: Option<UserPoolMfaType>,
     /// <p>The policies associated with the new user pool.</p>
     #[serde(rename = "Policies")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1319,13 +4296,199 @@ pub struct CreateUserPoolRequest {
     /// <p>Specifies whether email addresses or phone numbers can be specified as usernames when a user signs up.</p>
     #[serde(rename = "UsernameAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub username_attributes: Option<Vec<String>>,
+    pub username_attributes: Option<Vec<UsernameAttributeType>>,
     /// <p>The template for the verification message that the user sees when the app requests permission to access the user's information.</p>
     #[serde(rename = "VerificationMessageTemplate")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verification_message_template: Option<VerificationMessageTemplateType>,
 }
 
+impl CreateUserPoolRequest {
+    /// Returns a builder for `CreateUserPoolRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserPoolRequestBuilder {
+        CreateUserPoolRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserPoolRequest`](struct.CreateUserPoolRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserPoolRequestBuilder {
+    admin_create_user_config: Option<AdminCreateUserConfigType>,
+    alias_attributes: Option<Vec<AliasAttributeType>>,
+    auto_verified_attributes: Option<Vec<VerifiedAttributeType>>,
+    device_configuration: Option<DeviceConfigurationType>,
+    email_configuration: Option<EmailConfigurationType>,
+    email_verification_message: Option<String>,
+    email_verification_subject: Option<String>,
+    lambda_config: Option<LambdaConfigType>,
+    mfa_configuration: Option<UserPoolMfaType>,
+    policies: Option<UserPoolPolicyType>,
+    pool_name: Option<String>,
+    schema: Option<Vec<SchemaAttributeType>>,
+    sms_authentication_message: Option<String>,
+    sms_configuration: Option<SmsConfigurationType>,
+    sms_verification_message: Option<String>,
+    user_pool_add_ons: Option<UserPoolAddOnsType>,
+    user_pool_tags: Option<::std::collections::HashMap<String, String>>,
+    username_attributes: Option<Vec<UsernameAttributeType>>,
+    verification_message_template: Option<VerificationMessageTemplateType>,
+}
+
+impl CreateUserPoolRequestBuilder {
+    /// Sets the [`admin_create_user_config`](struct.CreateUserPoolRequest.html#structfield.admin_create_user_config) member.
+    pub fn admin_create_user_config<T: Into<AdminCreateUserConfigType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.admin_create_user_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`alias_attributes`](struct.CreateUserPoolRequest.html#structfield.alias_attributes) member.
+    pub fn alias_attributes<T: Into<Vec<AliasAttributeType>>>(mut self, value: T) -> Self {
+        self.alias_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_verified_attributes`](struct.CreateUserPoolRequest.html#structfield.auto_verified_attributes) member.
+    pub fn auto_verified_attributes<T: Into<Vec<VerifiedAttributeType>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.auto_verified_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_configuration`](struct.CreateUserPoolRequest.html#structfield.device_configuration) member.
+    pub fn device_configuration<T: Into<DeviceConfigurationType>>(mut self, value: T) -> Self {
+        self.device_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email_configuration`](struct.CreateUserPoolRequest.html#structfield.email_configuration) member.
+    pub fn email_configuration<T: Into<EmailConfigurationType>>(mut self, value: T) -> Self {
+        self.email_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email_verification_message`](struct.CreateUserPoolRequest.html#structfield.email_verification_message) member.
+    pub fn email_verification_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.email_verification_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email_verification_subject`](struct.CreateUserPoolRequest.html#structfield.email_verification_subject) member.
+    pub fn email_verification_subject<T: Into<String>>(mut self, value: T) -> Self {
+        self.email_verification_subject = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lambda_config`](struct.CreateUserPoolRequest.html#structfield.lambda_config) member.
+    pub fn lambda_config<T: Into<LambdaConfigType>>(mut self, value: T) -> Self {
+        self.lambda_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mfa_configuration`](struct.CreateUserPoolRequest.html#structfield.mfa_configuration) member.
+    pub fn mfa_configuration<T: Into<UserPoolMfaType>>(mut self, value: T) -> Self {
+        self.mfa_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policies`](struct.CreateUserPoolRequest.html#structfield.policies) member.
+    pub fn policies<T: Into<UserPoolPolicyType>>(mut self, value: T) -> Self {
+        self.policies = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pool_name`](struct.CreateUserPoolRequest.html#structfield.pool_name) member.
+    pub fn pool_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pool_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema`](struct.CreateUserPoolRequest.html#structfield.schema) member.
+    pub fn schema<T: Into<Vec<SchemaAttributeType>>>(mut self, value: T) -> Self {
+        self.schema = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sms_authentication_message`](struct.CreateUserPoolRequest.html#structfield.sms_authentication_message) member.
+    pub fn sms_authentication_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.sms_authentication_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sms_configuration`](struct.CreateUserPoolRequest.html#structfield.sms_configuration) member.
+    pub fn sms_configuration<T: Into<SmsConfigurationType>>(mut self, value: T) -> Self {
+        self.sms_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sms_verification_message`](struct.CreateUserPoolRequest.html#structfield.sms_verification_message) member.
+    pub fn sms_verification_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.sms_verification_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_add_ons`](struct.CreateUserPoolRequest.html#structfield.user_pool_add_ons) member.
+    pub fn user_pool_add_ons<T: Into<UserPoolAddOnsType>>(mut self, value: T) -> Self {
+        self.user_pool_add_ons = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_tags`](struct.CreateUserPoolRequest.html#structfield.user_pool_tags) member.
+    pub fn user_pool_tags<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.user_pool_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username_attributes`](struct.CreateUserPoolRequest.html#structfield.username_attributes) member.
+    pub fn username_attributes<T: Into<Vec<UsernameAttributeType>>>(mut self, value: T) -> Self {
+        self.username_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`verification_message_template`](struct.CreateUserPoolRequest.html#structfield.verification_message_template) member.
+    pub fn verification_message_template<T: Into<VerificationMessageTemplateType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.verification_message_template = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserPoolRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateUserPoolRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserPoolRequest {
+            admin_create_user_config: self.admin_create_user_config,
+            alias_attributes: self.alias_attributes,
+            auto_verified_attributes: self.auto_verified_attributes,
+            device_configuration: self.device_configuration,
+            email_configuration: self.email_configuration,
+            email_verification_message: self.email_verification_message,
+            email_verification_subject: self.email_verification_subject,
+            lambda_config: self.lambda_config,
+            mfa_configuration: self.mfa_configuration,
+            policies: self.policies,
+            pool_name: self
+                .pool_name
+                .ok_or(::rusoto_core::BuildError::missing("pool_name"))?,
+            schema: self.schema,
+            sms_authentication_message: self.sms_authentication_message,
+            sms_configuration: self.sms_configuration,
+            sms_verification_message: self.sms_verification_message,
+            user_pool_add_ons: self.user_pool_add_ons,
+            user_pool_tags: self.user_pool_tags,
+            username_attributes: self.username_attributes,
+            verification_message_template: self.verification_message_template,
+        })
+    }
+}
+
 /// <p>Represents the response from the server for the request to create a user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1344,6 +4507,78 @@ pub struct CustomDomainConfigType {
     pub certificate_arn: String,
 }
 
+/// <p>The default email option.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DefaultEmailOptionType {
+    /// <p><code>CONFIRM_WITH_LINK</code></p>
+    ConfirmWithLink,
+    /// <p><code>CONFIRM_WITH_CODE</code></p>
+    ConfirmWithCode,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DefaultEmailOptionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DefaultEmailOptionType::ConfirmWithLink => "CONFIRM_WITH_LINK",
+            DefaultEmailOptionType::ConfirmWithCode => "CONFIRM_WITH_CODE",
+            DefaultEmailOptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DefaultEmailOptionType {
+    fn from(value: &'a str) -> DefaultEmailOptionType {
+        match value {
+            "CONFIRM_WITH_LINK" => DefaultEmailOptionType::ConfirmWithLink,
+            "CONFIRM_WITH_CODE" => DefaultEmailOptionType::ConfirmWithCode,
+            _ => DefaultEmailOptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DefaultEmailOptionType {
+    fn from(value: String) -> DefaultEmailOptionType {
+        DefaultEmailOptionType::from(value.as_str())
+    }
+}
+
+impl From<DefaultEmailOptionType> for String {
+    fn from(value: DefaultEmailOptionType) -> String {
+        match value {
+            DefaultEmailOptionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DefaultEmailOptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DefaultEmailOptionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DefaultEmailOptionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DefaultEmailOptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DefaultEmailOptionType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteGroupRequest {
     /// <p>The name of the group.</p>
@@ -1354,6 +4589,46 @@ pub struct DeleteGroupRequest {
     pub user_pool_id: String,
 }
 
+impl DeleteGroupRequest {
+    /// Returns a builder for `DeleteGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteGroupRequestBuilder {
+        DeleteGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGroupRequest`](struct.DeleteGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteGroupRequestBuilder {
+    group_name: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl DeleteGroupRequestBuilder {
+    /// Sets the [`group_name`](struct.DeleteGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.DeleteGroupRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteGroupRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteIdentityProviderRequest {
     /// <p>The identity provider name.</p>
@@ -1364,6 +4639,48 @@ pub struct DeleteIdentityProviderRequest {
     pub user_pool_id: String,
 }
 
+impl DeleteIdentityProviderRequest {
+    /// Returns a builder for `DeleteIdentityProviderRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIdentityProviderRequestBuilder {
+        DeleteIdentityProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIdentityProviderRequest`](struct.DeleteIdentityProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIdentityProviderRequestBuilder {
+    provider_name: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl DeleteIdentityProviderRequestBuilder {
+    /// Sets the [`provider_name`](struct.DeleteIdentityProviderRequest.html#structfield.provider_name) member.
+    pub fn provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.DeleteIdentityProviderRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIdentityProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteIdentityProviderRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIdentityProviderRequest {
+            provider_name: self
+                .provider_name
+                .ok_or(::rusoto_core::BuildError::missing("provider_name"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteResourceServerRequest {
     /// <p>The identifier for the resource server.</p>
@@ -1374,6 +4691,48 @@ pub struct DeleteResourceServerRequest {
     pub user_pool_id: String,
 }
 
+impl DeleteResourceServerRequest {
+    /// Returns a builder for `DeleteResourceServerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteResourceServerRequestBuilder {
+        DeleteResourceServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteResourceServerRequest`](struct.DeleteResourceServerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteResourceServerRequestBuilder {
+    identifier: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl DeleteResourceServerRequestBuilder {
+    /// Sets the [`identifier`](struct.DeleteResourceServerRequest.html#structfield.identifier) member.
+    pub fn identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.DeleteResourceServerRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteResourceServerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteResourceServerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteResourceServerRequest {
+            identifier: self
+                .identifier
+                .ok_or(::rusoto_core::BuildError::missing("identifier"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the request to delete user attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteUserAttributesRequest {
@@ -1385,10 +4744,52 @@ pub struct DeleteUserAttributesRequest {
     pub user_attribute_names: Vec<String>,
 }
 
-/// <p>Represents the response from the server to delete user attributes.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DeleteUserAttributesResponse {}
+impl DeleteUserAttributesRequest {
+    /// Returns a builder for `DeleteUserAttributesRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserAttributesRequestBuilder {
+        DeleteUserAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserAttributesRequest`](struct.DeleteUserAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserAttributesRequestBuilder {
+    access_token: Option<String>,
+    user_attribute_names: Option<Vec<String>>,
+}
+
+impl DeleteUserAttributesRequestBuilder {
+    /// Sets the [`access_token`](struct.DeleteUserAttributesRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_attribute_names`](struct.DeleteUserAttributesRequest.html#structfield.user_attribute_names) member.
+    pub fn user_attribute_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.user_attribute_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteUserAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserAttributesRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+            user_attribute_names: self
+                .user_attribute_names
+                .ok_or(::rusoto_core::BuildError::missing("user_attribute_names"))?,
+        })
+    }
+}
+
+/// <p>Represents the response from the server to delete user attributes.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteUserAttributesResponse {}
 
 /// <p>Represents the request to delete a user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1401,6 +4802,48 @@ pub struct DeleteUserPoolClientRequest {
     pub user_pool_id: String,
 }
 
+impl DeleteUserPoolClientRequest {
+    /// Returns a builder for `DeleteUserPoolClientRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserPoolClientRequestBuilder {
+        DeleteUserPoolClientRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserPoolClientRequest`](struct.DeleteUserPoolClientRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserPoolClientRequestBuilder {
+    client_id: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl DeleteUserPoolClientRequestBuilder {
+    /// Sets the [`client_id`](struct.DeleteUserPoolClientRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.DeleteUserPoolClientRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserPoolClientRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteUserPoolClientRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserPoolClientRequest {
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteUserPoolDomainRequest {
     /// <p>The domain string.</p>
@@ -1411,6 +4854,48 @@ pub struct DeleteUserPoolDomainRequest {
     pub user_pool_id: String,
 }
 
+impl DeleteUserPoolDomainRequest {
+    /// Returns a builder for `DeleteUserPoolDomainRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserPoolDomainRequestBuilder {
+        DeleteUserPoolDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserPoolDomainRequest`](struct.DeleteUserPoolDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserPoolDomainRequestBuilder {
+    domain: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl DeleteUserPoolDomainRequestBuilder {
+    /// Sets the [`domain`](struct.DeleteUserPoolDomainRequest.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.DeleteUserPoolDomainRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserPoolDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteUserPoolDomainRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserPoolDomainRequest {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteUserPoolDomainResponse {}
@@ -1423,6 +4908,36 @@ pub struct DeleteUserPoolRequest {
     pub user_pool_id: String,
 }
 
+impl DeleteUserPoolRequest {
+    /// Returns a builder for `DeleteUserPoolRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserPoolRequestBuilder {
+        DeleteUserPoolRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserPoolRequest`](struct.DeleteUserPoolRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserPoolRequestBuilder {
+    user_pool_id: Option<String>,
+}
+
+impl DeleteUserPoolRequestBuilder {
+    /// Sets the [`user_pool_id`](struct.DeleteUserPoolRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserPoolRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteUserPoolRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserPoolRequest {
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the request to delete a user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteUserRequest {
@@ -1431,6 +4946,108 @@ pub struct DeleteUserRequest {
     pub access_token: String,
 }
 
+impl DeleteUserRequest {
+    /// Returns a builder for `DeleteUserRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserRequestBuilder {
+        DeleteUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserRequest`](struct.DeleteUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserRequestBuilder {
+    access_token: Option<String>,
+}
+
+impl DeleteUserRequestBuilder {
+    /// Sets the [`access_token`](struct.DeleteUserRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteUserRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+        })
+    }
+}
+
+/// <p>Specify <code>"EMAIL"</code> if email will be used to send the welcome message. Specify <code>"SMS"</code> if the phone number will be used. The default value is <code>"SMS"</code>. More than one value can be specified.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeliveryMediumType {
+    /// <p><code>SMS</code></p>
+    Sms,
+    /// <p><code>EMAIL</code></p>
+    Email,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeliveryMediumType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliveryMediumType::Sms => "SMS",
+            DeliveryMediumType::Email => "EMAIL",
+            DeliveryMediumType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeliveryMediumType {
+    fn from(value: &'a str) -> DeliveryMediumType {
+        match value {
+            "SMS" => DeliveryMediumType::Sms,
+            "EMAIL" => DeliveryMediumType::Email,
+            _ => DeliveryMediumType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliveryMediumType {
+    fn from(value: String) -> DeliveryMediumType {
+        DeliveryMediumType::from(value.as_str())
+    }
+}
+
+impl From<DeliveryMediumType> for String {
+    fn from(value: DeliveryMediumType) -> String {
+        match value {
+            DeliveryMediumType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeliveryMediumType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeliveryMediumType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliveryMediumType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeliveryMediumType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeliveryMediumType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeIdentityProviderRequest {
     /// <p>The identity provider name.</p>
@@ -1441,6 +5058,48 @@ pub struct DescribeIdentityProviderRequest {
     pub user_pool_id: String,
 }
 
+impl DescribeIdentityProviderRequest {
+    /// Returns a builder for `DescribeIdentityProviderRequest`, checking its required members when built.
+    pub fn builder() -> DescribeIdentityProviderRequestBuilder {
+        DescribeIdentityProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeIdentityProviderRequest`](struct.DescribeIdentityProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeIdentityProviderRequestBuilder {
+    provider_name: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl DescribeIdentityProviderRequestBuilder {
+    /// Sets the [`provider_name`](struct.DescribeIdentityProviderRequest.html#structfield.provider_name) member.
+    pub fn provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.DescribeIdentityProviderRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeIdentityProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeIdentityProviderRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeIdentityProviderRequest {
+            provider_name: self
+                .provider_name
+                .ok_or(::rusoto_core::BuildError::missing("provider_name"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeIdentityProviderResponse {
@@ -1459,6 +5118,48 @@ pub struct DescribeResourceServerRequest {
     pub user_pool_id: String,
 }
 
+impl DescribeResourceServerRequest {
+    /// Returns a builder for `DescribeResourceServerRequest`, checking its required members when built.
+    pub fn builder() -> DescribeResourceServerRequestBuilder {
+        DescribeResourceServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeResourceServerRequest`](struct.DescribeResourceServerRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeResourceServerRequestBuilder {
+    identifier: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl DescribeResourceServerRequestBuilder {
+    /// Sets the [`identifier`](struct.DescribeResourceServerRequest.html#structfield.identifier) member.
+    pub fn identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.DescribeResourceServerRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeResourceServerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeResourceServerRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeResourceServerRequest {
+            identifier: self
+                .identifier
+                .ok_or(::rusoto_core::BuildError::missing("identifier"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeResourceServerResponse {
@@ -1478,6 +5179,46 @@ pub struct DescribeRiskConfigurationRequest {
     pub user_pool_id: String,
 }
 
+impl DescribeRiskConfigurationRequest {
+    /// Returns a builder for `DescribeRiskConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeRiskConfigurationRequestBuilder {
+        DescribeRiskConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRiskConfigurationRequest`](struct.DescribeRiskConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeRiskConfigurationRequestBuilder {
+    client_id: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl DescribeRiskConfigurationRequestBuilder {
+    /// Sets the [`client_id`](struct.DescribeRiskConfigurationRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.DescribeRiskConfigurationRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRiskConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeRiskConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeRiskConfigurationRequest {
+            client_id: self.client_id,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeRiskConfigurationResponse {
@@ -1497,6 +5238,48 @@ pub struct DescribeUserImportJobRequest {
     pub user_pool_id: String,
 }
 
+impl DescribeUserImportJobRequest {
+    /// Returns a builder for `DescribeUserImportJobRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserImportJobRequestBuilder {
+        DescribeUserImportJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserImportJobRequest`](struct.DescribeUserImportJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserImportJobRequestBuilder {
+    job_id: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl DescribeUserImportJobRequestBuilder {
+    /// Sets the [`job_id`](struct.DescribeUserImportJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.DescribeUserImportJobRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserImportJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeUserImportJobRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUserImportJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server to the request to describe the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1518,6 +5301,48 @@ pub struct DescribeUserPoolClientRequest {
     pub user_pool_id: String,
 }
 
+impl DescribeUserPoolClientRequest {
+    /// Returns a builder for `DescribeUserPoolClientRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserPoolClientRequestBuilder {
+        DescribeUserPoolClientRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserPoolClientRequest`](struct.DescribeUserPoolClientRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserPoolClientRequestBuilder {
+    client_id: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl DescribeUserPoolClientRequestBuilder {
+    /// Sets the [`client_id`](struct.DescribeUserPoolClientRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.DescribeUserPoolClientRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserPoolClientRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeUserPoolClientRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUserPoolClientRequest {
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server from a request to describe the user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1535,6 +5360,38 @@ pub struct DescribeUserPoolDomainRequest {
     pub domain: String,
 }
 
+impl DescribeUserPoolDomainRequest {
+    /// Returns a builder for `DescribeUserPoolDomainRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserPoolDomainRequestBuilder {
+        DescribeUserPoolDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserPoolDomainRequest`](struct.DescribeUserPoolDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserPoolDomainRequestBuilder {
+    domain: Option<String>,
+}
+
+impl DescribeUserPoolDomainRequestBuilder {
+    /// Sets the [`domain`](struct.DescribeUserPoolDomainRequest.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserPoolDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeUserPoolDomainRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUserPoolDomainRequest {
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUserPoolDomainResponse {
@@ -1552,6 +5409,38 @@ pub struct DescribeUserPoolRequest {
     pub user_pool_id: String,
 }
 
+impl DescribeUserPoolRequest {
+    /// Returns a builder for `DescribeUserPoolRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserPoolRequestBuilder {
+        DescribeUserPoolRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserPoolRequest`](struct.DescribeUserPoolRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserPoolRequestBuilder {
+    user_pool_id: Option<String>,
+}
+
+impl DescribeUserPoolRequestBuilder {
+    /// Sets the [`user_pool_id`](struct.DescribeUserPoolRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserPoolRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeUserPoolRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUserPoolRequest {
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the response to describe the user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1575,6 +5464,80 @@ pub struct DeviceConfigurationType {
     pub device_only_remembered_on_user_prompt: Option<bool>,
 }
 
+/// <p>The status indicating whether a device has been remembered or not.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceRememberedStatusType {
+    /// <p><code>remembered</code></p>
+    Remembered,
+    /// <p><code>not_remembered</code></p>
+    NotRemembered,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceRememberedStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceRememberedStatusType::Remembered => "remembered",
+            DeviceRememberedStatusType::NotRemembered => "not_remembered",
+            DeviceRememberedStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeviceRememberedStatusType {
+    fn from(value: &'a str) -> DeviceRememberedStatusType {
+        match value {
+            "remembered" => DeviceRememberedStatusType::Remembered,
+            "not_remembered" => DeviceRememberedStatusType::NotRemembered,
+            _ => DeviceRememberedStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceRememberedStatusType {
+    fn from(value: String) -> DeviceRememberedStatusType {
+        DeviceRememberedStatusType::from(value.as_str())
+    }
+}
+
+impl From<DeviceRememberedStatusType> for String {
+    fn from(value: DeviceRememberedStatusType) -> String {
+        match value {
+            DeviceRememberedStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceRememberedStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeviceRememberedStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceRememberedStatusType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<DeviceRememberedStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceRememberedStatusType::from(value))
+    }
+}
+
 /// <p>The device verifier against which it will be authenticated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeviceSecretVerifierConfigType {
@@ -1598,20 +5561,23 @@ pub struct DeviceType {
     pub device_attributes: Option<Vec<AttributeType>>,
     /// <p>The creation date of the device.</p>
     #[serde(rename = "DeviceCreateDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_create_date: Option<f64>,
+    pub device_create_date: Option<EpochTimestamp>,
     /// <p>The device key.</p>
     #[serde(rename = "DeviceKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub device_key: Option<String>,
     /// <p>The date in which the device was last authenticated.</p>
     #[serde(rename = "DeviceLastAuthenticatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_last_authenticated_date: Option<f64>,
+    pub device_last_authenticated_date: Option<EpochTimestamp>,
     /// <p>The last modified date of the device.</p>
     #[serde(rename = "DeviceLastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_last_modified_date: Option<f64>,
+    pub device_last_modified_date: Option<EpochTimestamp>,
 }
 
 /// <p>A container for information about a domain.</p>
@@ -1641,7 +5607,7 @@ pub struct DomainDescriptionType {
     /// <p>The domain status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DomainStatusType>,
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1652,13 +5618,97 @@ pub struct DomainDescriptionType {
     pub version: Option<String>,
 }
 
+/// <p>The domain status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DomainStatusType {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DomainStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainStatusType::Creating => "CREATING",
+            DomainStatusType::Deleting => "DELETING",
+            DomainStatusType::Updating => "UPDATING",
+            DomainStatusType::Active => "ACTIVE",
+            DomainStatusType::Failed => "FAILED",
+            DomainStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DomainStatusType {
+    fn from(value: &'a str) -> DomainStatusType {
+        match value {
+            "CREATING" => DomainStatusType::Creating,
+            "DELETING" => DomainStatusType::Deleting,
+            "UPDATING" => DomainStatusType::Updating,
+            "ACTIVE" => DomainStatusType::Active,
+            "FAILED" => DomainStatusType::Failed,
+            _ => DomainStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainStatusType {
+    fn from(value: String) -> DomainStatusType {
+        DomainStatusType::from(value.as_str())
+    }
+}
+
+impl From<DomainStatusType> for String {
+    fn from(value: DomainStatusType) -> String {
+        match value {
+            DomainStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DomainStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DomainStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainStatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DomainStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DomainStatusType::from(value))
+    }
+}
+
 /// <p>The email configuration type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EmailConfigurationType {
     /// <p><p>Specifies whether Amazon Cognito emails your users by using its built-in email functionality or your Amazon SES email configuration. Specify one of the following values:</p> <dl> <dt>COGNITO_DEFAULT</dt> <dd> <p>When Amazon Cognito emails your users, it uses its built-in email functionality. When you use the default option, Amazon Cognito allows only a limited number of emails each day for your user pool. For typical production environments, the default email limit is below the required delivery volume. To achieve a higher delivery volume, specify DEVELOPER to use your Amazon SES email configuration.</p> <p>To look up the email delivery limit for the default option, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/limits.html">Limits in Amazon Cognito</a> in the <i>Amazon Cognito Developer Guide</i>.</p> <p>The default FROM address is no-reply@verificationemail.com. To customize the FROM address, provide the ARN of an Amazon SES verified email address for the <code>SourceArn</code> parameter.</p> </dd> <dt>DEVELOPER</dt> <dd> <p>When Amazon Cognito emails your users, it uses your Amazon SES configuration. Amazon Cognito calls Amazon SES on your behalf to send email from your verified email address. When you use this option, the email delivery limits are the same limits that apply to your Amazon SES verified email address in your AWS account.</p> <p>If you use this option, you must provide the ARN of an Amazon SES verified email address for the <code>SourceArn</code> parameter.</p> <p>Before Amazon Cognito can email your users, it requires additional permissions to call Amazon SES on your behalf. When you update your user pool with this option, Amazon Cognito creates a <i>service-linked role</i>, which is a type of IAM role, in your AWS account. This role contains the permissions that allow Amazon Cognito to access Amazon SES and send email messages with your address. For more information about the service-linked role that Amazon Cognito creates, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/using-service-linked-roles.html">Using Service-Linked Roles for Amazon Cognito</a> in the <i>Amazon Cognito Developer Guide</i>.</p> </dd> </dl></p>
     #[serde(rename = "EmailSendingAccount")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub email_sending_account: Option<String>,
+    pub email_sending_account: Option<EmailSendingAccountType>,
     /// <p>The destination to which the receiver of the email should reply to.</p>
     #[serde(rename = "ReplyToEmailAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1669,16 +5719,88 @@ pub struct EmailConfigurationType {
     pub source_arn: Option<String>,
 }
 
-/// <p>Specifies the user context data captured at the time of an event request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct EventContextDataType {
-    /// <p>The user's city.</p>
-    #[serde(rename = "City")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub city: Option<String>,
-    /// <p>The user's country.</p>
-    #[serde(rename = "Country")]
+/// <p><p>Specifies whether Amazon Cognito emails your users by using its built-in email functionality or your Amazon SES email configuration. Specify one of the following values:</p> <dl> <dt>COGNITO_DEFAULT</dt> <dd> <p>When Amazon Cognito emails your users, it uses its built-in email functionality. When you use the default option, Amazon Cognito allows only a limited number of emails each day for your user pool. For typical production environments, the default email limit is below the required delivery volume. To achieve a higher delivery volume, specify DEVELOPER to use your Amazon SES email configuration.</p> <p>To look up the email delivery limit for the default option, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/limits.html">Limits in Amazon Cognito</a> in the <i>Amazon Cognito Developer Guide</i>.</p> <p>The default FROM address is no-reply@verificationemail.com. To customize the FROM address, provide the ARN of an Amazon SES verified email address for the <code>SourceArn</code> parameter.</p> </dd> <dt>DEVELOPER</dt> <dd> <p>When Amazon Cognito emails your users, it uses your Amazon SES configuration. Amazon Cognito calls Amazon SES on your behalf to send email from your verified email address. When you use this option, the email delivery limits are the same limits that apply to your Amazon SES verified email address in your AWS account.</p> <p>If you use this option, you must provide the ARN of an Amazon SES verified email address for the <code>SourceArn</code> parameter.</p> <p>Before Amazon Cognito can email your users, it requires additional permissions to call Amazon SES on your behalf. When you update your user pool with this option, Amazon Cognito creates a <i>service-linked role</i>, which is a type of IAM role, in your AWS account. This role contains the permissions that allow Amazon Cognito to access Amazon SES and send email messages with your address. For more information about the service-linked role that Amazon Cognito creates, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/using-service-linked-roles.html">Using Service-Linked Roles for Amazon Cognito</a> in the <i>Amazon Cognito Developer Guide</i>.</p> </dd> </dl></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EmailSendingAccountType {
+    /// <p><code>COGNITO_DEFAULT</code></p>
+    CognitoDefault,
+    /// <p><code>DEVELOPER</code></p>
+    Developer,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EmailSendingAccountType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EmailSendingAccountType::CognitoDefault => "COGNITO_DEFAULT",
+            EmailSendingAccountType::Developer => "DEVELOPER",
+            EmailSendingAccountType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EmailSendingAccountType {
+    fn from(value: &'a str) -> EmailSendingAccountType {
+        match value {
+            "COGNITO_DEFAULT" => EmailSendingAccountType::CognitoDefault,
+            "DEVELOPER" => EmailSendingAccountType::Developer,
+            _ => EmailSendingAccountType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EmailSendingAccountType {
+    fn from(value: String) -> EmailSendingAccountType {
+        EmailSendingAccountType::from(value.as_str())
+    }
+}
+
+impl From<EmailSendingAccountType> for String {
+    fn from(value: EmailSendingAccountType) -> String {
+        match value {
+            EmailSendingAccountType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EmailSendingAccountType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EmailSendingAccountType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EmailSendingAccountType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EmailSendingAccountType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EmailSendingAccountType::from(value))
+    }
+}
+
+/// <p>Specifies the user context data captured at the time of an event request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct EventContextDataType {
+    /// <p>The user's city.</p>
+    #[serde(rename = "City")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// <p>The user's country.</p>
+    #[serde(rename = "Country")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<String>,
     /// <p>The user's device name.</p>
@@ -1696,21 +5818,174 @@ pub struct EventContextDataType {
 }
 
 /// <p>Specifies the event feedback type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct EventFeedbackType {
     /// <p>The event feedback date.</p>
     #[serde(rename = "FeedbackDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub feedback_date: Option<f64>,
+    pub feedback_date: Option<EpochTimestamp>,
     /// <p>The event feedback value.</p>
     #[serde(rename = "FeedbackValue")]
-    pub feedback_value: String,
+    pub feedback_value: FeedbackValueType,
     /// <p>The provider.</p>
     #[serde(rename = "Provider")]
     pub provider: String,
 }
 
+/// <p>Perform the action for these events. The default is to perform all events if no event filter is specified.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventFilterType {
+    /// <p><code>SIGN_IN</code></p>
+    SignIn,
+    /// <p><code>PASSWORD_CHANGE</code></p>
+    PasswordChange,
+    /// <p><code>SIGN_UP</code></p>
+    SignUp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventFilterType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventFilterType::SignIn => "SIGN_IN",
+            EventFilterType::PasswordChange => "PASSWORD_CHANGE",
+            EventFilterType::SignUp => "SIGN_UP",
+            EventFilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventFilterType {
+    fn from(value: &'a str) -> EventFilterType {
+        match value {
+            "SIGN_IN" => EventFilterType::SignIn,
+            "PASSWORD_CHANGE" => EventFilterType::PasswordChange,
+            "SIGN_UP" => EventFilterType::SignUp,
+            _ => EventFilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventFilterType {
+    fn from(value: String) -> EventFilterType {
+        EventFilterType::from(value.as_str())
+    }
+}
+
+impl From<EventFilterType> for String {
+    fn from(value: EventFilterType) -> String {
+        match value {
+            EventFilterType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventFilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EventFilterType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventFilterType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EventFilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EventFilterType::from(value))
+    }
+}
+
+/// <p>The event response.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventResponseType {
+    /// <p><code>Pass</code></p>
+    Pass,
+    /// <p><code>Fail</code></p>
+    Fail,
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventResponseType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventResponseType::Pass => "Pass",
+            EventResponseType::Fail => "Fail",
+            EventResponseType::InProgress => "InProgress",
+            EventResponseType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventResponseType {
+    fn from(value: &'a str) -> EventResponseType {
+        match value {
+            "Pass" => EventResponseType::Pass,
+            "Fail" => EventResponseType::Fail,
+            "InProgress" => EventResponseType::InProgress,
+            _ => EventResponseType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventResponseType {
+    fn from(value: String) -> EventResponseType {
+        EventResponseType::from(value.as_str())
+    }
+}
+
+impl From<EventResponseType> for String {
+    fn from(value: EventResponseType) -> String {
+        match value {
+            EventResponseType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventResponseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EventResponseType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventResponseType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EventResponseType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EventResponseType::from(value))
+    }
+}
+
 /// <p>The event risk type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1718,11 +5993,263 @@ pub struct EventRiskType {
     /// <p>The risk decision.</p>
     #[serde(rename = "RiskDecision")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub risk_decision: Option<String>,
+    pub risk_decision: Option<RiskDecisionType>,
     /// <p>The risk level.</p>
     #[serde(rename = "RiskLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub risk_level: Option<String>,
+    pub risk_level: Option<RiskLevelType>,
+}
+
+/// <p>The event type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventType {
+    /// <p><code>SignIn</code></p>
+    SignIn,
+    /// <p><code>SignUp</code></p>
+    SignUp,
+    /// <p><code>ForgotPassword</code></p>
+    ForgotPassword,
+    /// <p><code>PasswordChange</code></p>
+    PasswordChange,
+    /// <p><code>ResendCode</code></p>
+    ResendCode,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::SignIn => "SignIn",
+            EventType::SignUp => "SignUp",
+            EventType::ForgotPassword => "ForgotPassword",
+            EventType::PasswordChange => "PasswordChange",
+            EventType::ResendCode => "ResendCode",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "SignIn" => EventType::SignIn,
+            "SignUp" => EventType::SignUp,
+            "ForgotPassword" => EventType::ForgotPassword,
+            "PasswordChange" => EventType::PasswordChange,
+            "ResendCode" => EventType::ResendCode,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        EventType::from(value.as_str())
+    }
+}
+
+impl From<EventType> for String {
+    fn from(value: EventType) -> String {
+        match value {
+            EventType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EventType::from(value))
+    }
+}
+
+/// <p>The explicit authentication flows.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExplicitAuthFlowsType {
+    /// <p><code>ADMIN_NO_SRP_AUTH</code></p>
+    AdminNoSrpAuth,
+    /// <p><code>CUSTOM_AUTH_FLOW_ONLY</code></p>
+    CustomAuthFlowOnly,
+    /// <p><code>USER_PASSWORD_AUTH</code></p>
+    UserPasswordAuth,
+    /// <p><code>ALLOW_ADMIN_USER_PASSWORD_AUTH</code></p>
+    AllowAdminUserPasswordAuth,
+    /// <p><code>ALLOW_CUSTOM_AUTH</code></p>
+    AllowCustomAuth,
+    /// <p><code>ALLOW_USER_PASSWORD_AUTH</code></p>
+    AllowUserPasswordAuth,
+    /// <p><code>ALLOW_USER_SRP_AUTH</code></p>
+    AllowUserSrpAuth,
+    /// <p><code>ALLOW_REFRESH_TOKEN_AUTH</code></p>
+    AllowRefreshTokenAuth,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExplicitAuthFlowsType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExplicitAuthFlowsType::AdminNoSrpAuth => "ADMIN_NO_SRP_AUTH",
+            ExplicitAuthFlowsType::CustomAuthFlowOnly => "CUSTOM_AUTH_FLOW_ONLY",
+            ExplicitAuthFlowsType::UserPasswordAuth => "USER_PASSWORD_AUTH",
+            ExplicitAuthFlowsType::AllowAdminUserPasswordAuth => "ALLOW_ADMIN_USER_PASSWORD_AUTH",
+            ExplicitAuthFlowsType::AllowCustomAuth => "ALLOW_CUSTOM_AUTH",
+            ExplicitAuthFlowsType::AllowUserPasswordAuth => "ALLOW_USER_PASSWORD_AUTH",
+            ExplicitAuthFlowsType::AllowUserSrpAuth => "ALLOW_USER_SRP_AUTH",
+            ExplicitAuthFlowsType::AllowRefreshTokenAuth => "ALLOW_REFRESH_TOKEN_AUTH",
+            ExplicitAuthFlowsType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExplicitAuthFlowsType {
+    fn from(value: &'a str) -> ExplicitAuthFlowsType {
+        match value {
+            "ADMIN_NO_SRP_AUTH" => ExplicitAuthFlowsType::AdminNoSrpAuth,
+            "CUSTOM_AUTH_FLOW_ONLY" => ExplicitAuthFlowsType::CustomAuthFlowOnly,
+            "USER_PASSWORD_AUTH" => ExplicitAuthFlowsType::UserPasswordAuth,
+            "ALLOW_ADMIN_USER_PASSWORD_AUTH" => ExplicitAuthFlowsType::AllowAdminUserPasswordAuth,
+            "ALLOW_CUSTOM_AUTH" => ExplicitAuthFlowsType::AllowCustomAuth,
+            "ALLOW_USER_PASSWORD_AUTH" => ExplicitAuthFlowsType::AllowUserPasswordAuth,
+            "ALLOW_USER_SRP_AUTH" => ExplicitAuthFlowsType::AllowUserSrpAuth,
+            "ALLOW_REFRESH_TOKEN_AUTH" => ExplicitAuthFlowsType::AllowRefreshTokenAuth,
+            _ => ExplicitAuthFlowsType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExplicitAuthFlowsType {
+    fn from(value: String) -> ExplicitAuthFlowsType {
+        ExplicitAuthFlowsType::from(value.as_str())
+    }
+}
+
+impl From<ExplicitAuthFlowsType> for String {
+    fn from(value: ExplicitAuthFlowsType) -> String {
+        match value {
+            ExplicitAuthFlowsType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExplicitAuthFlowsType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExplicitAuthFlowsType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExplicitAuthFlowsType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExplicitAuthFlowsType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExplicitAuthFlowsType::from(value))
+    }
+}
+
+/// <p>The authentication event feedback value.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FeedbackValueType {
+    /// <p><code>Valid</code></p>
+    Valid,
+    /// <p><code>Invalid</code></p>
+    Invalid,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FeedbackValueType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FeedbackValueType::Valid => "Valid",
+            FeedbackValueType::Invalid => "Invalid",
+            FeedbackValueType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FeedbackValueType {
+    fn from(value: &'a str) -> FeedbackValueType {
+        match value {
+            "Valid" => FeedbackValueType::Valid,
+            "Invalid" => FeedbackValueType::Invalid,
+            _ => FeedbackValueType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FeedbackValueType {
+    fn from(value: String) -> FeedbackValueType {
+        FeedbackValueType::from(value.as_str())
+    }
+}
+
+impl From<FeedbackValueType> for String {
+    fn from(value: FeedbackValueType) -> String {
+        match value {
+            FeedbackValueType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FeedbackValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FeedbackValueType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FeedbackValueType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FeedbackValueType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FeedbackValueType::from(value))
+    }
 }
 
 /// <p>Represents the request to forget the device.</p>
@@ -1737,6 +6264,44 @@ pub struct ForgetDeviceRequest {
     pub device_key: String,
 }
 
+impl ForgetDeviceRequest {
+    /// Returns a builder for `ForgetDeviceRequest`, checking its required members when built.
+    pub fn builder() -> ForgetDeviceRequestBuilder {
+        ForgetDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ForgetDeviceRequest`](struct.ForgetDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct ForgetDeviceRequestBuilder {
+    access_token: Option<String>,
+    device_key: Option<String>,
+}
+
+impl ForgetDeviceRequestBuilder {
+    /// Sets the [`access_token`](struct.ForgetDeviceRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_key`](struct.ForgetDeviceRequest.html#structfield.device_key) member.
+    pub fn device_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_key = Some(value.into());
+        self
+    }
+
+    /// Builds the `ForgetDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ForgetDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(ForgetDeviceRequest {
+            access_token: self.access_token,
+            device_key: self
+                .device_key
+                .ok_or(::rusoto_core::BuildError::missing("device_key"))?,
+        })
+    }
+}
+
 /// <p>Represents the request to reset a user's password.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ForgotPasswordRequest {
@@ -1760,6 +6325,70 @@ pub struct ForgotPasswordRequest {
     pub username: String,
 }
 
+impl ForgotPasswordRequest {
+    /// Returns a builder for `ForgotPasswordRequest`, checking its required members when built.
+    pub fn builder() -> ForgotPasswordRequestBuilder {
+        ForgotPasswordRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ForgotPasswordRequest`](struct.ForgotPasswordRequest.html).
+#[derive(Default, Debug)]
+pub struct ForgotPasswordRequestBuilder {
+    analytics_metadata: Option<AnalyticsMetadataType>,
+    client_id: Option<String>,
+    secret_hash: Option<String>,
+    user_context_data: Option<UserContextDataType>,
+    username: Option<String>,
+}
+
+impl ForgotPasswordRequestBuilder {
+    /// Sets the [`analytics_metadata`](struct.ForgotPasswordRequest.html#structfield.analytics_metadata) member.
+    pub fn analytics_metadata<T: Into<AnalyticsMetadataType>>(mut self, value: T) -> Self {
+        self.analytics_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.ForgotPasswordRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_hash`](struct.ForgotPasswordRequest.html#structfield.secret_hash) member.
+    pub fn secret_hash<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_hash = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_context_data`](struct.ForgotPasswordRequest.html#structfield.user_context_data) member.
+    pub fn user_context_data<T: Into<UserContextDataType>>(mut self, value: T) -> Self {
+        self.user_context_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.ForgotPasswordRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `ForgotPasswordRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ForgotPasswordRequest, ::rusoto_core::BuildError> {
+        Ok(ForgotPasswordRequest {
+            analytics_metadata: self.analytics_metadata,
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            secret_hash: self.secret_hash,
+            user_context_data: self.user_context_data,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>Respresents the response from the server regarding the request to reset a password.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1778,6 +6407,36 @@ pub struct GetCSVHeaderRequest {
     pub user_pool_id: String,
 }
 
+impl GetCSVHeaderRequest {
+    /// Returns a builder for `GetCSVHeaderRequest`, checking its required members when built.
+    pub fn builder() -> GetCSVHeaderRequestBuilder {
+        GetCSVHeaderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCSVHeaderRequest`](struct.GetCSVHeaderRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCSVHeaderRequestBuilder {
+    user_pool_id: Option<String>,
+}
+
+impl GetCSVHeaderRequestBuilder {
+    /// Sets the [`user_pool_id`](struct.GetCSVHeaderRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCSVHeaderRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetCSVHeaderRequest, ::rusoto_core::BuildError> {
+        Ok(GetCSVHeaderRequest {
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server to the request to get the header information for the .csv file for the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1804,6 +6463,44 @@ pub struct GetDeviceRequest {
     pub device_key: String,
 }
 
+impl GetDeviceRequest {
+    /// Returns a builder for `GetDeviceRequest`, checking its required members when built.
+    pub fn builder() -> GetDeviceRequestBuilder {
+        GetDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeviceRequest`](struct.GetDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDeviceRequestBuilder {
+    access_token: Option<String>,
+    device_key: Option<String>,
+}
+
+impl GetDeviceRequestBuilder {
+    /// Sets the [`access_token`](struct.GetDeviceRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_key`](struct.GetDeviceRequest.html#structfield.device_key) member.
+    pub fn device_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_key = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(GetDeviceRequest {
+            access_token: self.access_token,
+            device_key: self
+                .device_key
+                .ok_or(::rusoto_core::BuildError::missing("device_key"))?,
+        })
+    }
+}
+
 /// <p>Gets the device response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1823,12 +6520,52 @@ pub struct GetGroupRequest {
     pub user_pool_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetGroupResponse {
-    /// <p>The group object for the group.</p>
-    #[serde(rename = "Group")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl GetGroupRequest {
+    /// Returns a builder for `GetGroupRequest`, checking its required members when built.
+    pub fn builder() -> GetGroupRequestBuilder {
+        GetGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGroupRequest`](struct.GetGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGroupRequestBuilder {
+    group_name: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl GetGroupRequestBuilder {
+    /// Sets the [`group_name`](struct.GetGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.GetGroupRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGroupRequest, ::rusoto_core::BuildError> {
+        Ok(GetGroupRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetGroupResponse {
+    /// <p>The group object for the group.</p>
+    #[serde(rename = "Group")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub group: Option<GroupType>,
 }
 
@@ -1842,6 +6579,49 @@ pub struct GetIdentityProviderByIdentifierRequest {
     pub user_pool_id: String,
 }
 
+impl GetIdentityProviderByIdentifierRequest {
+    /// Returns a builder for `GetIdentityProviderByIdentifierRequest`, checking its required members when built.
+    pub fn builder() -> GetIdentityProviderByIdentifierRequestBuilder {
+        GetIdentityProviderByIdentifierRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIdentityProviderByIdentifierRequest`](struct.GetIdentityProviderByIdentifierRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIdentityProviderByIdentifierRequestBuilder {
+    idp_identifier: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl GetIdentityProviderByIdentifierRequestBuilder {
+    /// Sets the [`idp_identifier`](struct.GetIdentityProviderByIdentifierRequest.html#structfield.idp_identifier) member.
+    pub fn idp_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.idp_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.GetIdentityProviderByIdentifierRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIdentityProviderByIdentifierRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIdentityProviderByIdentifierRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetIdentityProviderByIdentifierRequest {
+            idp_identifier: self
+                .idp_identifier
+                .ok_or(::rusoto_core::BuildError::missing("idp_identifier"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetIdentityProviderByIdentifierResponse {
@@ -1858,6 +6638,38 @@ pub struct GetSigningCertificateRequest {
     pub user_pool_id: String,
 }
 
+impl GetSigningCertificateRequest {
+    /// Returns a builder for `GetSigningCertificateRequest`, checking its required members when built.
+    pub fn builder() -> GetSigningCertificateRequestBuilder {
+        GetSigningCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSigningCertificateRequest`](struct.GetSigningCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSigningCertificateRequestBuilder {
+    user_pool_id: Option<String>,
+}
+
+impl GetSigningCertificateRequestBuilder {
+    /// Sets the [`user_pool_id`](struct.GetSigningCertificateRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSigningCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSigningCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(GetSigningCertificateRequest {
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Response from Cognito for a signing certificate request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1879,6 +6691,46 @@ pub struct GetUICustomizationRequest {
     pub user_pool_id: String,
 }
 
+impl GetUICustomizationRequest {
+    /// Returns a builder for `GetUICustomizationRequest`, checking its required members when built.
+    pub fn builder() -> GetUICustomizationRequestBuilder {
+        GetUICustomizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUICustomizationRequest`](struct.GetUICustomizationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUICustomizationRequestBuilder {
+    client_id: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl GetUICustomizationRequestBuilder {
+    /// Sets the [`client_id`](struct.GetUICustomizationRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.GetUICustomizationRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUICustomizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetUICustomizationRequest, ::rusoto_core::BuildError> {
+        Ok(GetUICustomizationRequest {
+            client_id: self.client_id,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetUICustomizationResponse {
@@ -1898,6 +6750,49 @@ pub struct GetUserAttributeVerificationCodeRequest {
     pub attribute_name: String,
 }
 
+impl GetUserAttributeVerificationCodeRequest {
+    /// Returns a builder for `GetUserAttributeVerificationCodeRequest`, checking its required members when built.
+    pub fn builder() -> GetUserAttributeVerificationCodeRequestBuilder {
+        GetUserAttributeVerificationCodeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUserAttributeVerificationCodeRequest`](struct.GetUserAttributeVerificationCodeRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUserAttributeVerificationCodeRequestBuilder {
+    access_token: Option<String>,
+    attribute_name: Option<String>,
+}
+
+impl GetUserAttributeVerificationCodeRequestBuilder {
+    /// Sets the [`access_token`](struct.GetUserAttributeVerificationCodeRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attribute_name`](struct.GetUserAttributeVerificationCodeRequest.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUserAttributeVerificationCodeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetUserAttributeVerificationCodeRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetUserAttributeVerificationCodeRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+            attribute_name: self
+                .attribute_name
+                .ok_or(::rusoto_core::BuildError::missing("attribute_name"))?,
+        })
+    }
+}
+
 /// <p>The verification code response returned by the server response to get the user attribute verification code.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1915,13 +6810,45 @@ pub struct GetUserPoolMfaConfigRequest {
     pub user_pool_id: String,
 }
 
+impl GetUserPoolMfaConfigRequest {
+    /// Returns a builder for `GetUserPoolMfaConfigRequest`, checking its required members when built.
+    pub fn builder() -> GetUserPoolMfaConfigRequestBuilder {
+        GetUserPoolMfaConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUserPoolMfaConfigRequest`](struct.GetUserPoolMfaConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUserPoolMfaConfigRequestBuilder {
+    user_pool_id: Option<String>,
+}
+
+impl GetUserPoolMfaConfigRequestBuilder {
+    /// Sets the [`user_pool_id`](struct.GetUserPoolMfaConfigRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUserPoolMfaConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetUserPoolMfaConfigRequest, ::rusoto_core::BuildError> {
+        Ok(GetUserPoolMfaConfigRequest {
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetUserPoolMfaConfigResponse {
     /// <p>The multi-factor (MFA) configuration.</p>
     #[serde(rename = "MfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mfa_configuration: Option<String>,
+    pub mfa_configuration: Option<UserPoolMfaType>,
     /// <p>The SMS text message multi-factor (MFA) configuration.</p>
     #[serde(rename = "SmsMfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1940,6 +6867,36 @@ pub struct GetUserRequest {
     pub access_token: String,
 }
 
+impl GetUserRequest {
+    /// Returns a builder for `GetUserRequest`, checking its required members when built.
+    pub fn builder() -> GetUserRequestBuilder {
+        GetUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUserRequest`](struct.GetUserRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUserRequestBuilder {
+    access_token: Option<String>,
+}
+
+impl GetUserRequestBuilder {
+    /// Sets the [`access_token`](struct.GetUserRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetUserRequest, ::rusoto_core::BuildError> {
+        Ok(GetUserRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server from the request to get information about the user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1972,6 +6929,36 @@ pub struct GlobalSignOutRequest {
     pub access_token: String,
 }
 
+impl GlobalSignOutRequest {
+    /// Returns a builder for `GlobalSignOutRequest`, checking its required members when built.
+    pub fn builder() -> GlobalSignOutRequestBuilder {
+        GlobalSignOutRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GlobalSignOutRequest`](struct.GlobalSignOutRequest.html).
+#[derive(Default, Debug)]
+pub struct GlobalSignOutRequestBuilder {
+    access_token: Option<String>,
+}
+
+impl GlobalSignOutRequestBuilder {
+    /// Sets the [`access_token`](struct.GlobalSignOutRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GlobalSignOutRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GlobalSignOutRequest, ::rusoto_core::BuildError> {
+        Ok(GlobalSignOutRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+        })
+    }
+}
+
 /// <p>The response to the request to sign out all devices.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1983,8 +6970,9 @@ pub struct GlobalSignOutResponse {}
 pub struct GroupType {
     /// <p>The date the group was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>A string containing the description of the group.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1995,8 +6983,9 @@ pub struct GroupType {
     pub group_name: Option<String>,
     /// <p>The date the group was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>A nonnegative integer value that specifies the precedence of this group relative to the other groups that a user can belong to in the user pool. If a user belongs to two or more groups, it is the group with the highest precedence whose role ARN will be used in the <code>cognito:roles</code> and <code>cognito:preferred_role</code> claims in the user's tokens. Groups with higher <code>Precedence</code> values take precedence over groups with lower <code>Precedence</code> values or with null <code>Precedence</code> values.</p> <p>Two groups can have the same <code>Precedence</code> value. If this happens, neither group takes precedence over the other. If two groups with the same <code>Precedence</code> have the same role ARN, that role is used in the <code>cognito:preferred_role</code> claim in tokens for users in each group. If the two groups have different role ARNs, the <code>cognito:preferred_role</code> claim is not set in users' tokens.</p> <p>The default <code>Precedence</code> value is null.</p>
     #[serde(rename = "Precedence")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2034,16 +7023,18 @@ pub struct IdentityProviderType {
     pub attribute_mapping: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date the identity provider was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>A list of identity provider identifiers.</p>
     #[serde(rename = "IdpIdentifiers")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub idp_identifiers: Option<Vec<String>>,
     /// <p>The date the identity provider was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The identity provider details, such as <code>MetadataURL</code> and <code>MetadataFile</code>.</p>
     #[serde(rename = "ProviderDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2055,15 +7046,103 @@ pub struct IdentityProviderType {
     /// <p>The identity provider type.</p>
     #[serde(rename = "ProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_type: Option<String>,
+    pub provider_type: Option<IdentityProviderTypeType>,
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_pool_id: Option<String>,
 }
 
+/// <p>The identity provider type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IdentityProviderTypeType {
+    /// <p><code>SAML</code></p>
+    Saml,
+    /// <p><code>Facebook</code></p>
+    Facebook,
+    /// <p><code>Google</code></p>
+    Google,
+    /// <p><code>LoginWithAmazon</code></p>
+    LoginWithAmazon,
+    /// <p><code>SignInWithApple</code></p>
+    SignInWithApple,
+    /// <p><code>OIDC</code></p>
+    Oidc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IdentityProviderTypeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IdentityProviderTypeType::Saml => "SAML",
+            IdentityProviderTypeType::Facebook => "Facebook",
+            IdentityProviderTypeType::Google => "Google",
+            IdentityProviderTypeType::LoginWithAmazon => "LoginWithAmazon",
+            IdentityProviderTypeType::SignInWithApple => "SignInWithApple",
+            IdentityProviderTypeType::Oidc => "OIDC",
+            IdentityProviderTypeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IdentityProviderTypeType {
+    fn from(value: &'a str) -> IdentityProviderTypeType {
+        match value {
+            "SAML" => IdentityProviderTypeType::Saml,
+            "Facebook" => IdentityProviderTypeType::Facebook,
+            "Google" => IdentityProviderTypeType::Google,
+            "LoginWithAmazon" => IdentityProviderTypeType::LoginWithAmazon,
+            "SignInWithApple" => IdentityProviderTypeType::SignInWithApple,
+            "OIDC" => IdentityProviderTypeType::Oidc,
+            _ => IdentityProviderTypeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IdentityProviderTypeType {
+    fn from(value: String) -> IdentityProviderTypeType {
+        IdentityProviderTypeType::from(value.as_str())
+    }
+}
+
+impl From<IdentityProviderTypeType> for String {
+    fn from(value: IdentityProviderTypeType) -> String {
+        match value {
+            IdentityProviderTypeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IdentityProviderTypeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for IdentityProviderTypeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IdentityProviderTypeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IdentityProviderTypeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IdentityProviderTypeType::from(value))
+    }
+}
+
 /// <p>Initiates the authentication request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InitiateAuthRequest {
     /// <p>The Amazon Pinpoint analytics metadata for collecting metrics for <code>InitiateAuth</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -2071,7 +7150,7 @@ pub struct InitiateAuthRequest {
     pub analytics_metadata: Option<AnalyticsMetadataType>,
     /// <p>The authentication flow for this call to execute. The API action will depend on this value. For example: </p> <ul> <li> <p> <code>REFRESH_TOKEN_AUTH</code> will take in a valid refresh token and return new tokens.</p> </li> <li> <p> <code>USER_SRP_AUTH</code> will take in <code>USERNAME</code> and <code>SRP_A</code> and return the SRP variables to be used for next challenge execution.</p> </li> <li> <p> <code>USER_PASSWORD_AUTH</code> will take in <code>USERNAME</code> and <code>PASSWORD</code> and return the next challenge or tokens.</p> </li> </ul> <p>Valid values include:</p> <ul> <li> <p> <code>USER_SRP_AUTH</code>: Authentication flow for the Secure Remote Password (SRP) protocol.</p> </li> <li> <p> <code>REFRESH_TOKEN_AUTH</code>/<code>REFRESH_TOKEN</code>: Authentication flow for refreshing the access token and ID token by supplying a valid refresh token.</p> </li> <li> <p> <code>CUSTOM_AUTH</code>: Custom authentication flow.</p> </li> <li> <p> <code>USER_PASSWORD_AUTH</code>: Non-SRP authentication flow; USERNAME and PASSWORD are passed directly. If a user migration Lambda trigger is set, this flow will invoke the user migration Lambda if the USERNAME is not found in the user pool. </p> </li> </ul> <p> <code>ADMIN_NO_SRP_AUTH</code> is not a valid value.</p>
     #[serde(rename = "AuthFlow")]
-    pub auth_flow: String,
+    pub auth_flow: AuthFlowType,
     /// <p><p>The authentication parameters. These are inputs corresponding to the <code>AuthFlow</code> that you are invoking. The required values depend on the value of <code>AuthFlow</code>:</p> <ul> <li> <p>For <code>USER<em>SRP</em>AUTH</code>: <code>USERNAME</code> (required), <code>SRP<em>A</code> (required), <code>SECRET</em>HASH</code> (required if the app client is configured with a client secret), <code>DEVICE<em>KEY</code> </p> </li> <li> <p>For <code>REFRESH</em>TOKEN<em>AUTH/REFRESH</em>TOKEN</code>: <code>REFRESH<em>TOKEN</code> (required), <code>SECRET</em>HASH</code> (required if the app client is configured with a client secret), <code>DEVICE<em>KEY</code> </p> </li> <li> <p>For <code>CUSTOM</em>AUTH</code>: <code>USERNAME</code> (required), <code>SECRET<em>HASH</code> (if app client is configured with client secret), <code>DEVICE</em>KEY</code> </p> </li> </ul></p>
     #[serde(rename = "AuthParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2089,6 +7168,84 @@ pub struct InitiateAuthRequest {
     pub user_context_data: Option<UserContextDataType>,
 }
 
+impl InitiateAuthRequest {
+    /// Returns a builder for `InitiateAuthRequest`, checking its required members when built.
+    pub fn builder() -> InitiateAuthRequestBuilder {
+        InitiateAuthRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InitiateAuthRequest`](struct.InitiateAuthRequest.html).
+#[derive(Default, Debug)]
+pub struct InitiateAuthRequestBuilder {
+    analytics_metadata: Option<AnalyticsMetadataType>,
+    auth_flow: Option<AuthFlowType>,
+    auth_parameters: Option<::std::collections::HashMap<String, String>>,
+    client_id: Option<String>,
+    client_metadata: Option<::std::collections::HashMap<String, String>>,
+    user_context_data: Option<UserContextDataType>,
+}
+
+impl InitiateAuthRequestBuilder {
+    /// Sets the [`analytics_metadata`](struct.InitiateAuthRequest.html#structfield.analytics_metadata) member.
+    pub fn analytics_metadata<T: Into<AnalyticsMetadataType>>(mut self, value: T) -> Self {
+        self.analytics_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auth_flow`](struct.InitiateAuthRequest.html#structfield.auth_flow) member.
+    pub fn auth_flow<T: Into<AuthFlowType>>(mut self, value: T) -> Self {
+        self.auth_flow = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auth_parameters`](struct.InitiateAuthRequest.html#structfield.auth_parameters) member.
+    pub fn auth_parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.auth_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.InitiateAuthRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_metadata`](struct.InitiateAuthRequest.html#structfield.client_metadata) member.
+    pub fn client_metadata<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.client_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_context_data`](struct.InitiateAuthRequest.html#structfield.user_context_data) member.
+    pub fn user_context_data<T: Into<UserContextDataType>>(mut self, value: T) -> Self {
+        self.user_context_data = Some(value.into());
+        self
+    }
+
+    /// Builds the `InitiateAuthRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<InitiateAuthRequest, ::rusoto_core::BuildError> {
+        Ok(InitiateAuthRequest {
+            analytics_metadata: self.analytics_metadata,
+            auth_flow: self
+                .auth_flow
+                .ok_or(::rusoto_core::BuildError::missing("auth_flow"))?,
+            auth_parameters: self.auth_parameters,
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            client_metadata: self.client_metadata,
+            user_context_data: self.user_context_data,
+        })
+    }
+}
+
 /// <p>Initiates the authentication response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2100,7 +7257,7 @@ pub struct InitiateAuthResponse {
     /// <p><p>The name of the challenge which you are responding to with this call. This is returned to you in the <code>AdminInitiateAuth</code> response if you need to pass another challenge.</p> <p>Valid values include the following. Note that all of these challenges require <code>USERNAME</code> and <code>SECRET<em>HASH</code> (if applicable) in the parameters.</p> <ul> <li> <p> <code>SMS</em>MFA</code>: Next challenge is to supply an <code>SMS<em>MFA</em>CODE</code>, delivered via SMS.</p> </li> <li> <p> <code>PASSWORD<em>VERIFIER</code>: Next challenge is to supply <code>PASSWORD</em>CLAIM<em>SIGNATURE</code>, <code>PASSWORD</em>CLAIM<em>SECRET</em>BLOCK</code>, and <code>TIMESTAMP</code> after the client-side SRP calculations.</p> </li> <li> <p> <code>CUSTOM<em>CHALLENGE</code>: This is returned if your custom authentication flow determines that the user should pass another challenge before tokens are issued.</p> </li> <li> <p> <code>DEVICE</em>SRP<em>AUTH</code>: If device tracking was enabled on your user pool and the previous challenges were passed, this challenge is returned so that Amazon Cognito can start tracking this device.</p> </li> <li> <p> <code>DEVICE</em>PASSWORD<em>VERIFIER</code>: Similar to <code>PASSWORD</em>VERIFIER</code>, but for devices only.</p> </li> <li> <p> <code>NEW<em>PASSWORD</em>REQUIRED</code>: For users which are required to change their passwords after successful first login. This challenge should be passed with <code>NEW_PASSWORD</code> and any other required attributes.</p> </li> </ul></p>
     #[serde(rename = "ChallengeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_name: Option<String>,
+    pub challenge_name: Option<ChallengeNameType>,
     /// <p>The challenge parameters. These are returned to you in the <code>InitiateAuth</code> response if you need to pass another challenge. The responses in this parameter should be used to compute inputs to the next call (<code>RespondToAuthChallenge</code>). </p> <p>All challenges require <code>USERNAME</code> and <code>SECRET_HASH</code> (if applicable).</p>
     #[serde(rename = "ChallengeParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2172,6 +7329,52 @@ pub struct ListDevicesRequest {
     pub pagination_token: Option<String>,
 }
 
+impl ListDevicesRequest {
+    /// Returns a builder for `ListDevicesRequest`, checking its required members when built.
+    pub fn builder() -> ListDevicesRequestBuilder {
+        ListDevicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDevicesRequest`](struct.ListDevicesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDevicesRequestBuilder {
+    access_token: Option<String>,
+    limit: Option<i64>,
+    pagination_token: Option<String>,
+}
+
+impl ListDevicesRequestBuilder {
+    /// Sets the [`access_token`](struct.ListDevicesRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListDevicesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pagination_token`](struct.ListDevicesRequest.html#structfield.pagination_token) member.
+    pub fn pagination_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.pagination_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDevicesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDevicesRequest, ::rusoto_core::BuildError> {
+        Ok(ListDevicesRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+            limit: self.limit,
+            pagination_token: self.pagination_token,
+        })
+    }
+}
+
 /// <p>Represents the response to list devices.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2201,6 +7404,52 @@ pub struct ListGroupsRequest {
     pub user_pool_id: String,
 }
 
+impl ListGroupsRequest {
+    /// Returns a builder for `ListGroupsRequest`, checking its required members when built.
+    pub fn builder() -> ListGroupsRequestBuilder {
+        ListGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGroupsRequest`](struct.ListGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGroupsRequestBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl ListGroupsRequestBuilder {
+    /// Sets the [`limit`](struct.ListGroupsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.ListGroupsRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(ListGroupsRequest {
+            limit: self.limit,
+            next_token: self.next_token,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGroupsResponse {
@@ -2229,6 +7478,54 @@ pub struct ListIdentityProvidersRequest {
     pub user_pool_id: String,
 }
 
+impl ListIdentityProvidersRequest {
+    /// Returns a builder for `ListIdentityProvidersRequest`, checking its required members when built.
+    pub fn builder() -> ListIdentityProvidersRequestBuilder {
+        ListIdentityProvidersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListIdentityProvidersRequest`](struct.ListIdentityProvidersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListIdentityProvidersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl ListIdentityProvidersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListIdentityProvidersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListIdentityProvidersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.ListIdentityProvidersRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListIdentityProvidersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListIdentityProvidersRequest, ::rusoto_core::BuildError> {
+        Ok(ListIdentityProvidersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListIdentityProvidersResponse {
@@ -2256,6 +7553,54 @@ pub struct ListResourceServersRequest {
     pub user_pool_id: String,
 }
 
+impl ListResourceServersRequest {
+    /// Returns a builder for `ListResourceServersRequest`, checking its required members when built.
+    pub fn builder() -> ListResourceServersRequestBuilder {
+        ListResourceServersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResourceServersRequest`](struct.ListResourceServersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResourceServersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl ListResourceServersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListResourceServersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListResourceServersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.ListResourceServersRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResourceServersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListResourceServersRequest, ::rusoto_core::BuildError> {
+        Ok(ListResourceServersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResourceServersResponse {
@@ -2275,6 +7620,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -2299,6 +7676,56 @@ pub struct ListUserImportJobsRequest {
     pub user_pool_id: String,
 }
 
+impl ListUserImportJobsRequest {
+    /// Returns a builder for `ListUserImportJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListUserImportJobsRequestBuilder {
+        ListUserImportJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUserImportJobsRequest`](struct.ListUserImportJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUserImportJobsRequestBuilder {
+    max_results: Option<i64>,
+    pagination_token: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl ListUserImportJobsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListUserImportJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pagination_token`](struct.ListUserImportJobsRequest.html#structfield.pagination_token) member.
+    pub fn pagination_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.pagination_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.ListUserImportJobsRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUserImportJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListUserImportJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListUserImportJobsRequest {
+            max_results: self
+                .max_results
+                .ok_or(::rusoto_core::BuildError::missing("max_results"))?,
+            pagination_token: self.pagination_token,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server to the request to list the user import jobs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2329,21 +7756,69 @@ pub struct ListUserPoolClientsRequest {
     pub user_pool_id: String,
 }
 
-/// <p>Represents the response from the server that lists user pool clients.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListUserPoolClientsResponse {
-    /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>The user pool clients in the response that lists user pool clients.</p>
-    #[serde(rename = "UserPoolClients")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_pool_clients: Option<Vec<UserPoolClientDescription>>,
+impl ListUserPoolClientsRequest {
+    /// Returns a builder for `ListUserPoolClientsRequest`, checking its required members when built.
+    pub fn builder() -> ListUserPoolClientsRequestBuilder {
+        ListUserPoolClientsRequestBuilder::default()
+    }
 }
 
-/// <p>Represents the request to list user pools.</p>
+/// Builds a [`ListUserPoolClientsRequest`](struct.ListUserPoolClientsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUserPoolClientsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl ListUserPoolClientsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListUserPoolClientsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUserPoolClientsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.ListUserPoolClientsRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUserPoolClientsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListUserPoolClientsRequest, ::rusoto_core::BuildError> {
+        Ok(ListUserPoolClientsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
+/// <p>Represents the response from the server that lists user pool clients.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListUserPoolClientsResponse {
+    /// <p>An identifier that was returned from the previous call to this operation, which can be used to return the next set of items in the list.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>The user pool clients in the response that lists user pool clients.</p>
+    #[serde(rename = "UserPoolClients")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_pool_clients: Option<Vec<UserPoolClientDescription>>,
+}
+
+/// <p>Represents the request to list user pools.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListUserPoolsRequest {
     /// <p>The maximum number of results you want the request to return when listing the user pools.</p>
@@ -2355,6 +7830,44 @@ pub struct ListUserPoolsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListUserPoolsRequest {
+    /// Returns a builder for `ListUserPoolsRequest`, checking its required members when built.
+    pub fn builder() -> ListUserPoolsRequestBuilder {
+        ListUserPoolsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUserPoolsRequest`](struct.ListUserPoolsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUserPoolsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListUserPoolsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListUserPoolsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUserPoolsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUserPoolsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListUserPoolsRequest, ::rusoto_core::BuildError> {
+        Ok(ListUserPoolsRequest {
+            max_results: self
+                .max_results
+                .ok_or(::rusoto_core::BuildError::missing("max_results"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the response to list user pools.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2387,6 +7900,64 @@ pub struct ListUsersInGroupRequest {
     pub user_pool_id: String,
 }
 
+impl ListUsersInGroupRequest {
+    /// Returns a builder for `ListUsersInGroupRequest`, checking its required members when built.
+    pub fn builder() -> ListUsersInGroupRequestBuilder {
+        ListUsersInGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUsersInGroupRequest`](struct.ListUsersInGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUsersInGroupRequestBuilder {
+    group_name: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl ListUsersInGroupRequestBuilder {
+    /// Sets the [`group_name`](struct.ListUsersInGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListUsersInGroupRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUsersInGroupRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.ListUsersInGroupRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUsersInGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListUsersInGroupRequest, ::rusoto_core::BuildError> {
+        Ok(ListUsersInGroupRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            limit: self.limit,
+            next_token: self.next_token,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListUsersInGroupResponse {
@@ -2424,6 +7995,68 @@ pub struct ListUsersRequest {
     pub user_pool_id: String,
 }
 
+impl ListUsersRequest {
+    /// Returns a builder for `ListUsersRequest`, checking its required members when built.
+    pub fn builder() -> ListUsersRequestBuilder {
+        ListUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUsersRequest`](struct.ListUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUsersRequestBuilder {
+    attributes_to_get: Option<Vec<String>>,
+    filter: Option<String>,
+    limit: Option<i64>,
+    pagination_token: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl ListUsersRequestBuilder {
+    /// Sets the [`attributes_to_get`](struct.ListUsersRequest.html#structfield.attributes_to_get) member.
+    pub fn attributes_to_get<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.attributes_to_get = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter`](struct.ListUsersRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListUsersRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pagination_token`](struct.ListUsersRequest.html#structfield.pagination_token) member.
+    pub fn pagination_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.pagination_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.ListUsersRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListUsersRequest, ::rusoto_core::BuildError> {
+        Ok(ListUsersRequest {
+            attributes_to_get: self.attributes_to_get,
+            filter: self.filter,
+            limit: self.limit,
+            pagination_token: self.pagination_token,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>The response from the request to list users.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2448,7 +8081,79 @@ pub struct MFAOptionType {
     /// <p>The delivery medium (email message or SMS message) to send the MFA code.</p>
     #[serde(rename = "DeliveryMedium")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_medium: Option<String>,
+    pub delivery_medium: Option<DeliveryMediumType>,
+}
+
+/// <p>Set to <code>"RESEND"</code> to resend the invitation message to a user that already exists and reset the expiration limit on the user's account. Set to <code>"SUPPRESS"</code> to suppress sending the message. Only one value can be specified.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MessageActionType {
+    /// <p><code>RESEND</code></p>
+    Resend,
+    /// <p><code>SUPPRESS</code></p>
+    Suppress,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MessageActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageActionType::Resend => "RESEND",
+            MessageActionType::Suppress => "SUPPRESS",
+            MessageActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MessageActionType {
+    fn from(value: &'a str) -> MessageActionType {
+        match value {
+            "RESEND" => MessageActionType::Resend,
+            "SUPPRESS" => MessageActionType::Suppress,
+            _ => MessageActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageActionType {
+    fn from(value: String) -> MessageActionType {
+        MessageActionType::from(value.as_str())
+    }
+}
+
+impl From<MessageActionType> for String {
+    fn from(value: MessageActionType) -> String {
+        match value {
+            MessageActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MessageActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MessageActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MessageActionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MessageActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MessageActionType::from(value))
+    }
 }
 
 /// <p>The message template structure.</p>
@@ -2539,6 +8244,82 @@ pub struct NumberAttributeConstraintsType {
     pub min_value: Option<String>,
 }
 
+/// <p>Set to <code>code</code> to initiate a code grant flow, which provides an authorization code as the response. This code can be exchanged for access tokens with the token endpoint.</p> <p>Set to <code>token</code> to specify that the client should get the access token (and, optionally, ID token, based on scopes) directly.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OAuthFlowType {
+    /// <p><code>code</code></p>
+    Code,
+    /// <p><code>implicit</code></p>
+    Implicit,
+    /// <p><code>client_credentials</code></p>
+    ClientCredentials,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OAuthFlowType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OAuthFlowType::Code => "code",
+            OAuthFlowType::Implicit => "implicit",
+            OAuthFlowType::ClientCredentials => "client_credentials",
+            OAuthFlowType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OAuthFlowType {
+    fn from(value: &'a str) -> OAuthFlowType {
+        match value {
+            "code" => OAuthFlowType::Code,
+            "implicit" => OAuthFlowType::Implicit,
+            "client_credentials" => OAuthFlowType::ClientCredentials,
+            _ => OAuthFlowType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OAuthFlowType {
+    fn from(value: String) -> OAuthFlowType {
+        OAuthFlowType::from(value.as_str())
+    }
+}
+
+impl From<OAuthFlowType> for String {
+    fn from(value: OAuthFlowType) -> String {
+        match value {
+            OAuthFlowType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OAuthFlowType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OAuthFlowType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OAuthFlowType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OAuthFlowType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OAuthFlowType::from(value))
+    }
+}
+
 /// <p>The password policy type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PasswordPolicyType {
@@ -2573,12 +8354,14 @@ pub struct PasswordPolicyType {
 pub struct ProviderDescription {
     /// <p>The date the provider was added to the user pool.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The date the provider was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The identity provider name.</p>
     #[serde(rename = "ProviderName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2586,7 +8369,7 @@ pub struct ProviderDescription {
     /// <p>The identity provider type.</p>
     #[serde(rename = "ProviderType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provider_type: Option<String>,
+    pub provider_type: Option<IdentityProviderTypeType>,
 }
 
 /// <p>A container for information about an identity provider for a user pool.</p>
@@ -2629,6 +8412,72 @@ pub struct ResendConfirmationCodeRequest {
     pub username: String,
 }
 
+impl ResendConfirmationCodeRequest {
+    /// Returns a builder for `ResendConfirmationCodeRequest`, checking its required members when built.
+    pub fn builder() -> ResendConfirmationCodeRequestBuilder {
+        ResendConfirmationCodeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ResendConfirmationCodeRequest`](struct.ResendConfirmationCodeRequest.html).
+#[derive(Default, Debug)]
+pub struct ResendConfirmationCodeRequestBuilder {
+    analytics_metadata: Option<AnalyticsMetadataType>,
+    client_id: Option<String>,
+    secret_hash: Option<String>,
+    user_context_data: Option<UserContextDataType>,
+    username: Option<String>,
+}
+
+impl ResendConfirmationCodeRequestBuilder {
+    /// Sets the [`analytics_metadata`](struct.ResendConfirmationCodeRequest.html#structfield.analytics_metadata) member.
+    pub fn analytics_metadata<T: Into<AnalyticsMetadataType>>(mut self, value: T) -> Self {
+        self.analytics_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.ResendConfirmationCodeRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_hash`](struct.ResendConfirmationCodeRequest.html#structfield.secret_hash) member.
+    pub fn secret_hash<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_hash = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_context_data`](struct.ResendConfirmationCodeRequest.html#structfield.user_context_data) member.
+    pub fn user_context_data<T: Into<UserContextDataType>>(mut self, value: T) -> Self {
+        self.user_context_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.ResendConfirmationCodeRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResendConfirmationCodeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResendConfirmationCodeRequest, ::rusoto_core::BuildError> {
+        Ok(ResendConfirmationCodeRequest {
+            analytics_metadata: self.analytics_metadata,
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            secret_hash: self.secret_hash,
+            user_context_data: self.user_context_data,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 /// <p>The response from the server when the Amazon Cognito Your User Pools service makes the request to resend a confirmation code.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2673,7 +8522,7 @@ pub struct ResourceServerType {
 }
 
 /// <p>The request to respond to an authentication challenge.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RespondToAuthChallengeRequest {
     /// <p>The Amazon Pinpoint analytics metadata for collecting metrics for <code>RespondToAuthChallenge</code> calls.</p>
     #[serde(rename = "AnalyticsMetadata")]
@@ -2681,7 +8530,7 @@ pub struct RespondToAuthChallengeRequest {
     pub analytics_metadata: Option<AnalyticsMetadataType>,
     /// <p>The challenge name. For more information, see .</p> <p> <code>ADMIN_NO_SRP_AUTH</code> is not a valid value.</p>
     #[serde(rename = "ChallengeName")]
-    pub challenge_name: String,
+    pub challenge_name: ChallengeNameType,
     /// <p><p>The challenge responses. These are inputs corresponding to the value of <code>ChallengeName</code>, for example:</p> <ul> <li> <p> <code>SMS<em>MFA</code>: <code>SMS</em>MFA<em>CODE</code>, <code>USERNAME</code>, <code>SECRET</em>HASH</code> (if app client is configured with client secret).</p> </li> <li> <p> <code>PASSWORD<em>VERIFIER</code>: <code>PASSWORD</em>CLAIM<em>SIGNATURE</code>, <code>PASSWORD</em>CLAIM<em>SECRET</em>BLOCK</code>, <code>TIMESTAMP</code>, <code>USERNAME</code>, <code>SECRET<em>HASH</code> (if app client is configured with client secret).</p> </li> <li> <p> <code>NEW</em>PASSWORD<em>REQUIRED</code>: <code>NEW</em>PASSWORD</code>, any other required attributes, <code>USERNAME</code>, <code>SECRET_HASH</code> (if app client is configured with client secret). </p> </li> </ul></p>
     #[serde(rename = "ChallengeResponses")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2699,6 +8548,83 @@ pub struct RespondToAuthChallengeRequest {
     pub user_context_data: Option<UserContextDataType>,
 }
 
+impl RespondToAuthChallengeRequest {
+    /// Returns a builder for `RespondToAuthChallengeRequest`, checking its required members when built.
+    pub fn builder() -> RespondToAuthChallengeRequestBuilder {
+        RespondToAuthChallengeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RespondToAuthChallengeRequest`](struct.RespondToAuthChallengeRequest.html).
+#[derive(Default, Debug)]
+pub struct RespondToAuthChallengeRequestBuilder {
+    analytics_metadata: Option<AnalyticsMetadataType>,
+    challenge_name: Option<ChallengeNameType>,
+    challenge_responses: Option<::std::collections::HashMap<String, String>>,
+    client_id: Option<String>,
+    session: Option<String>,
+    user_context_data: Option<UserContextDataType>,
+}
+
+impl RespondToAuthChallengeRequestBuilder {
+    /// Sets the [`analytics_metadata`](struct.RespondToAuthChallengeRequest.html#structfield.analytics_metadata) member.
+    pub fn analytics_metadata<T: Into<AnalyticsMetadataType>>(mut self, value: T) -> Self {
+        self.analytics_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`challenge_name`](struct.RespondToAuthChallengeRequest.html#structfield.challenge_name) member.
+    pub fn challenge_name<T: Into<ChallengeNameType>>(mut self, value: T) -> Self {
+        self.challenge_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`challenge_responses`](struct.RespondToAuthChallengeRequest.html#structfield.challenge_responses) member.
+    pub fn challenge_responses<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.challenge_responses = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.RespondToAuthChallengeRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`session`](struct.RespondToAuthChallengeRequest.html#structfield.session) member.
+    pub fn session<T: Into<String>>(mut self, value: T) -> Self {
+        self.session = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_context_data`](struct.RespondToAuthChallengeRequest.html#structfield.user_context_data) member.
+    pub fn user_context_data<T: Into<UserContextDataType>>(mut self, value: T) -> Self {
+        self.user_context_data = Some(value.into());
+        self
+    }
+
+    /// Builds the `RespondToAuthChallengeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RespondToAuthChallengeRequest, ::rusoto_core::BuildError> {
+        Ok(RespondToAuthChallengeRequest {
+            analytics_metadata: self.analytics_metadata,
+            challenge_name: self
+                .challenge_name
+                .ok_or(::rusoto_core::BuildError::missing("challenge_name"))?,
+            challenge_responses: self.challenge_responses,
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            session: self.session,
+            user_context_data: self.user_context_data,
+        })
+    }
+}
+
 /// <p>The response to respond to the authentication challenge.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2710,7 +8636,7 @@ pub struct RespondToAuthChallengeResponse {
     /// <p>The challenge name. For more information, see .</p>
     #[serde(rename = "ChallengeName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub challenge_name: Option<String>,
+    pub challenge_name: Option<ChallengeNameType>,
     /// <p>The challenge parameters. For more information, see .</p>
     #[serde(rename = "ChallengeParameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2740,8 +8666,9 @@ pub struct RiskConfigurationType {
         Option<CompromisedCredentialsRiskConfigurationType>,
     /// <p>The last modified date.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The configuration to override the risk decision.</p>
     #[serde(rename = "RiskExceptionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2752,6 +8679,82 @@ pub struct RiskConfigurationType {
     pub user_pool_id: Option<String>,
 }
 
+/// <p>The risk decision.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RiskDecisionType {
+    /// <p><code>NoRisk</code></p>
+    NoRisk,
+    /// <p><code>AccountTakeover</code></p>
+    AccountTakeover,
+    /// <p><code>Block</code></p>
+    Block,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RiskDecisionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RiskDecisionType::NoRisk => "NoRisk",
+            RiskDecisionType::AccountTakeover => "AccountTakeover",
+            RiskDecisionType::Block => "Block",
+            RiskDecisionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RiskDecisionType {
+    fn from(value: &'a str) -> RiskDecisionType {
+        match value {
+            "NoRisk" => RiskDecisionType::NoRisk,
+            "AccountTakeover" => RiskDecisionType::AccountTakeover,
+            "Block" => RiskDecisionType::Block,
+            _ => RiskDecisionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RiskDecisionType {
+    fn from(value: String) -> RiskDecisionType {
+        RiskDecisionType::from(value.as_str())
+    }
+}
+
+impl From<RiskDecisionType> for String {
+    fn from(value: RiskDecisionType) -> String {
+        match value {
+            RiskDecisionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RiskDecisionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RiskDecisionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RiskDecisionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RiskDecisionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RiskDecisionType::from(value))
+    }
+}
+
 /// <p>The type of the configuration to override the risk decision.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RiskExceptionConfigurationType {
@@ -2765,6 +8768,82 @@ pub struct RiskExceptionConfigurationType {
     pub skipped_ip_range_list: Option<Vec<String>>,
 }
 
+/// <p>The risk level.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RiskLevelType {
+    /// <p><code>Low</code></p>
+    Low,
+    /// <p><code>Medium</code></p>
+    Medium,
+    /// <p><code>High</code></p>
+    High,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RiskLevelType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RiskLevelType::Low => "Low",
+            RiskLevelType::Medium => "Medium",
+            RiskLevelType::High => "High",
+            RiskLevelType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RiskLevelType {
+    fn from(value: &'a str) -> RiskLevelType {
+        match value {
+            "Low" => RiskLevelType::Low,
+            "Medium" => RiskLevelType::Medium,
+            "High" => RiskLevelType::High,
+            _ => RiskLevelType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RiskLevelType {
+    fn from(value: String) -> RiskLevelType {
+        RiskLevelType::from(value.as_str())
+    }
+}
+
+impl From<RiskLevelType> for String {
+    fn from(value: RiskLevelType) -> String {
+        match value {
+            RiskLevelType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RiskLevelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RiskLevelType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RiskLevelType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RiskLevelType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RiskLevelType::from(value))
+    }
+}
+
 /// <p>The SMS multi-factor authentication (MFA) settings type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SMSMfaSettingsType {
@@ -2784,7 +8863,7 @@ pub struct SchemaAttributeType {
     /// <p>The attribute data type.</p>
     #[serde(rename = "AttributeDataType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute_data_type: Option<String>,
+    pub attribute_data_type: Option<AttributeDataType>,
     /// <p>Specifies whether the attribute type is developer only.</p>
     #[serde(rename = "DeveloperOnlyAttribute")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2835,6 +8914,82 @@ pub struct SetRiskConfigurationRequest {
     pub user_pool_id: String,
 }
 
+impl SetRiskConfigurationRequest {
+    /// Returns a builder for `SetRiskConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> SetRiskConfigurationRequestBuilder {
+        SetRiskConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetRiskConfigurationRequest`](struct.SetRiskConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct SetRiskConfigurationRequestBuilder {
+    account_takeover_risk_configuration: Option<AccountTakeoverRiskConfigurationType>,
+    client_id: Option<String>,
+    compromised_credentials_risk_configuration: Option<CompromisedCredentialsRiskConfigurationType>,
+    risk_exception_configuration: Option<RiskExceptionConfigurationType>,
+    user_pool_id: Option<String>,
+}
+
+impl SetRiskConfigurationRequestBuilder {
+    /// Sets the [`account_takeover_risk_configuration`](struct.SetRiskConfigurationRequest.html#structfield.account_takeover_risk_configuration) member.
+    pub fn account_takeover_risk_configuration<T: Into<AccountTakeoverRiskConfigurationType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.account_takeover_risk_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.SetRiskConfigurationRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`compromised_credentials_risk_configuration`](struct.SetRiskConfigurationRequest.html#structfield.compromised_credentials_risk_configuration) member.
+    pub fn compromised_credentials_risk_configuration<
+        T: Into<CompromisedCredentialsRiskConfigurationType>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.compromised_credentials_risk_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`risk_exception_configuration`](struct.SetRiskConfigurationRequest.html#structfield.risk_exception_configuration) member.
+    pub fn risk_exception_configuration<T: Into<RiskExceptionConfigurationType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.risk_exception_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.SetRiskConfigurationRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetRiskConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetRiskConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(SetRiskConfigurationRequest {
+            account_takeover_risk_configuration: self.account_takeover_risk_configuration,
+            client_id: self.client_id,
+            compromised_credentials_risk_configuration: self
+                .compromised_credentials_risk_configuration,
+            risk_exception_configuration: self.risk_exception_configuration,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SetRiskConfigurationResponse {
@@ -2867,12 +9022,68 @@ pub struct SetUICustomizationRequest {
     pub user_pool_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct SetUICustomizationResponse {
-    /// <p>The UI customization information.</p>
-    #[serde(rename = "UICustomization")]
-    pub ui_customization: UICustomizationType,
+impl SetUICustomizationRequest {
+    /// Returns a builder for `SetUICustomizationRequest`, checking its required members when built.
+    pub fn builder() -> SetUICustomizationRequestBuilder {
+        SetUICustomizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetUICustomizationRequest`](struct.SetUICustomizationRequest.html).
+#[derive(Default, Debug)]
+pub struct SetUICustomizationRequestBuilder {
+    css: Option<String>,
+    client_id: Option<String>,
+    image_file: Option<bytes::Bytes>,
+    user_pool_id: Option<String>,
+}
+
+impl SetUICustomizationRequestBuilder {
+    /// Sets the [`css`](struct.SetUICustomizationRequest.html#structfield.css) member.
+    pub fn css<T: Into<String>>(mut self, value: T) -> Self {
+        self.css = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.SetUICustomizationRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_file`](struct.SetUICustomizationRequest.html#structfield.image_file) member.
+    pub fn image_file<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.image_file = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.SetUICustomizationRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetUICustomizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetUICustomizationRequest, ::rusoto_core::BuildError> {
+        Ok(SetUICustomizationRequest {
+            css: self.css,
+            client_id: self.client_id,
+            image_file: self.image_file,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct SetUICustomizationResponse {
+    /// <p>The UI customization information.</p>
+    #[serde(rename = "UICustomization")]
+    pub ui_customization: UICustomizationType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2890,6 +9101,57 @@ pub struct SetUserMFAPreferenceRequest {
     pub software_token_mfa_settings: Option<SoftwareTokenMfaSettingsType>,
 }
 
+impl SetUserMFAPreferenceRequest {
+    /// Returns a builder for `SetUserMFAPreferenceRequest`, checking its required members when built.
+    pub fn builder() -> SetUserMFAPreferenceRequestBuilder {
+        SetUserMFAPreferenceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetUserMFAPreferenceRequest`](struct.SetUserMFAPreferenceRequest.html).
+#[derive(Default, Debug)]
+pub struct SetUserMFAPreferenceRequestBuilder {
+    access_token: Option<String>,
+    sms_mfa_settings: Option<SMSMfaSettingsType>,
+    software_token_mfa_settings: Option<SoftwareTokenMfaSettingsType>,
+}
+
+impl SetUserMFAPreferenceRequestBuilder {
+    /// Sets the [`access_token`](struct.SetUserMFAPreferenceRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sms_mfa_settings`](struct.SetUserMFAPreferenceRequest.html#structfield.sms_mfa_settings) member.
+    pub fn sms_mfa_settings<T: Into<SMSMfaSettingsType>>(mut self, value: T) -> Self {
+        self.sms_mfa_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`software_token_mfa_settings`](struct.SetUserMFAPreferenceRequest.html#structfield.software_token_mfa_settings) member.
+    pub fn software_token_mfa_settings<T: Into<SoftwareTokenMfaSettingsType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.software_token_mfa_settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetUserMFAPreferenceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetUserMFAPreferenceRequest, ::rusoto_core::BuildError> {
+        Ok(SetUserMFAPreferenceRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+            sms_mfa_settings: self.sms_mfa_settings,
+            software_token_mfa_settings: self.software_token_mfa_settings,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SetUserMFAPreferenceResponse {}
@@ -2899,7 +9161,7 @@ pub struct SetUserPoolMfaConfigRequest {
     /// <p>The MFA configuration.</p>
     #[serde(rename = "MfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mfa_configuration: Option<String>,
+    pub mfa_configuration: Option<UserPoolMfaType>,
     /// <p>The SMS text message MFA configuration.</p>
     #[serde(rename = "SmsMfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2913,13 +9175,72 @@ pub struct SetUserPoolMfaConfigRequest {
     pub user_pool_id: String,
 }
 
+impl SetUserPoolMfaConfigRequest {
+    /// Returns a builder for `SetUserPoolMfaConfigRequest`, checking its required members when built.
+    pub fn builder() -> SetUserPoolMfaConfigRequestBuilder {
+        SetUserPoolMfaConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetUserPoolMfaConfigRequest`](struct.SetUserPoolMfaConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct SetUserPoolMfaConfigRequestBuilder {
+    mfa_configuration: Option<UserPoolMfaType>,
+    sms_mfa_configuration: Option<SmsMfaConfigType>,
+    software_token_mfa_configuration: Option<SoftwareTokenMfaConfigType>,
+    user_pool_id: Option<String>,
+}
+
+impl SetUserPoolMfaConfigRequestBuilder {
+    /// Sets the [`mfa_configuration`](struct.SetUserPoolMfaConfigRequest.html#structfield.mfa_configuration) member.
+    pub fn mfa_configuration<T: Into<UserPoolMfaType>>(mut self, value: T) -> Self {
+        self.mfa_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sms_mfa_configuration`](struct.SetUserPoolMfaConfigRequest.html#structfield.sms_mfa_configuration) member.
+    pub fn sms_mfa_configuration<T: Into<SmsMfaConfigType>>(mut self, value: T) -> Self {
+        self.sms_mfa_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`software_token_mfa_configuration`](struct.SetUserPoolMfaConfigRequest.html#structfield.software_token_mfa_configuration) member.
+    pub fn software_token_mfa_configuration<T: Into<SoftwareTokenMfaConfigType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.software_token_mfa_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.SetUserPoolMfaConfigRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetUserPoolMfaConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetUserPoolMfaConfigRequest, ::rusoto_core::BuildError> {
+        Ok(SetUserPoolMfaConfigRequest {
+            mfa_configuration: self.mfa_configuration,
+            sms_mfa_configuration: self.sms_mfa_configuration,
+            software_token_mfa_configuration: self.software_token_mfa_configuration,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SetUserPoolMfaConfigResponse {
     /// <p>The MFA configuration.</p>
     #[serde(rename = "MfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mfa_configuration: Option<String>,
+    pub mfa_configuration: Option<UserPoolMfaType>,
     /// <p>The SMS text message MFA configuration.</p>
     #[serde(rename = "SmsMfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2941,6 +9262,46 @@ pub struct SetUserSettingsRequest {
     pub mfa_options: Vec<MFAOptionType>,
 }
 
+impl SetUserSettingsRequest {
+    /// Returns a builder for `SetUserSettingsRequest`, checking its required members when built.
+    pub fn builder() -> SetUserSettingsRequestBuilder {
+        SetUserSettingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetUserSettingsRequest`](struct.SetUserSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct SetUserSettingsRequestBuilder {
+    access_token: Option<String>,
+    mfa_options: Option<Vec<MFAOptionType>>,
+}
+
+impl SetUserSettingsRequestBuilder {
+    /// Sets the [`access_token`](struct.SetUserSettingsRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mfa_options`](struct.SetUserSettingsRequest.html#structfield.mfa_options) member.
+    pub fn mfa_options<T: Into<Vec<MFAOptionType>>>(mut self, value: T) -> Self {
+        self.mfa_options = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetUserSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetUserSettingsRequest, ::rusoto_core::BuildError> {
+        Ok(SetUserSettingsRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+            mfa_options: self
+                .mfa_options
+                .ok_or(::rusoto_core::BuildError::missing("mfa_options"))?,
+        })
+    }
+}
+
 /// <p>The response from the server for a set user settings request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2980,6 +9341,96 @@ pub struct SignUpRequest {
     pub validation_data: Option<Vec<AttributeType>>,
 }
 
+impl SignUpRequest {
+    /// Returns a builder for `SignUpRequest`, checking its required members when built.
+    pub fn builder() -> SignUpRequestBuilder {
+        SignUpRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SignUpRequest`](struct.SignUpRequest.html).
+#[derive(Default, Debug)]
+pub struct SignUpRequestBuilder {
+    analytics_metadata: Option<AnalyticsMetadataType>,
+    client_id: Option<String>,
+    password: Option<String>,
+    secret_hash: Option<String>,
+    user_attributes: Option<Vec<AttributeType>>,
+    user_context_data: Option<UserContextDataType>,
+    username: Option<String>,
+    validation_data: Option<Vec<AttributeType>>,
+}
+
+impl SignUpRequestBuilder {
+    /// Sets the [`analytics_metadata`](struct.SignUpRequest.html#structfield.analytics_metadata) member.
+    pub fn analytics_metadata<T: Into<AnalyticsMetadataType>>(mut self, value: T) -> Self {
+        self.analytics_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.SignUpRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.SignUpRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_hash`](struct.SignUpRequest.html#structfield.secret_hash) member.
+    pub fn secret_hash<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_hash = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_attributes`](struct.SignUpRequest.html#structfield.user_attributes) member.
+    pub fn user_attributes<T: Into<Vec<AttributeType>>>(mut self, value: T) -> Self {
+        self.user_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_context_data`](struct.SignUpRequest.html#structfield.user_context_data) member.
+    pub fn user_context_data<T: Into<UserContextDataType>>(mut self, value: T) -> Self {
+        self.user_context_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.SignUpRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Sets the [`validation_data`](struct.SignUpRequest.html#structfield.validation_data) member.
+    pub fn validation_data<T: Into<Vec<AttributeType>>>(mut self, value: T) -> Self {
+        self.validation_data = Some(value.into());
+        self
+    }
+
+    /// Builds the `SignUpRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SignUpRequest, ::rusoto_core::BuildError> {
+        Ok(SignUpRequest {
+            analytics_metadata: self.analytics_metadata,
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+            secret_hash: self.secret_hash,
+            user_attributes: self.user_attributes,
+            user_context_data: self.user_context_data,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+            validation_data: self.validation_data,
+        })
+    }
+}
+
 /// <p>The response from the server for a registration request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3054,6 +9505,48 @@ pub struct StartUserImportJobRequest {
     pub user_pool_id: String,
 }
 
+impl StartUserImportJobRequest {
+    /// Returns a builder for `StartUserImportJobRequest`, checking its required members when built.
+    pub fn builder() -> StartUserImportJobRequestBuilder {
+        StartUserImportJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartUserImportJobRequest`](struct.StartUserImportJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StartUserImportJobRequestBuilder {
+    job_id: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl StartUserImportJobRequestBuilder {
+    /// Sets the [`job_id`](struct.StartUserImportJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.StartUserImportJobRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartUserImportJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartUserImportJobRequest, ::rusoto_core::BuildError> {
+        Ok(StartUserImportJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server to the request to start the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3064,6 +9557,78 @@ pub struct StartUserImportJobResponse {
     pub user_import_job: Option<UserImportJobType>,
 }
 
+/// <p>The user pool status in a user pool description.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StatusType {
+    /// <p><code>Enabled</code></p>
+    Enabled,
+    /// <p><code>Disabled</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusType::Enabled => "Enabled",
+            StatusType::Disabled => "Disabled",
+            StatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StatusType {
+    fn from(value: &'a str) -> StatusType {
+        match value {
+            "Enabled" => StatusType::Enabled,
+            "Disabled" => StatusType::Disabled,
+            _ => StatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusType {
+    fn from(value: String) -> StatusType {
+        StatusType::from(value.as_str())
+    }
+}
+
+impl From<StatusType> for String {
+    fn from(value: StatusType) -> String {
+        match value {
+            StatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StatusType::from(value))
+    }
+}
+
 /// <p>Represents the request to stop the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StopUserImportJobRequest {
@@ -3075,6 +9640,48 @@ pub struct StopUserImportJobRequest {
     pub user_pool_id: String,
 }
 
+impl StopUserImportJobRequest {
+    /// Returns a builder for `StopUserImportJobRequest`, checking its required members when built.
+    pub fn builder() -> StopUserImportJobRequestBuilder {
+        StopUserImportJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopUserImportJobRequest`](struct.StopUserImportJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StopUserImportJobRequestBuilder {
+    job_id: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl StopUserImportJobRequestBuilder {
+    /// Sets the [`job_id`](struct.StopUserImportJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.StopUserImportJobRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopUserImportJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopUserImportJobRequest, ::rusoto_core::BuildError> {
+        Ok(StopUserImportJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server to the request to stop the user import job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3109,6 +9716,44 @@ pub struct TagResourceRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -3131,16 +9776,18 @@ pub struct UICustomizationType {
     pub client_id: Option<String>,
     /// <p>The creation date for the UI customization.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The logo image for the UI customization.</p>
     #[serde(rename = "ImageUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_url: Option<String>,
     /// <p>The last-modified date for the UI customization.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The user pool ID for the user pool.</p>
     #[serde(rename = "UserPoolId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3158,11 +9805,49 @@ pub struct UntagResourceRequest {
     pub tag_keys: Option<Vec<String>>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self.tag_keys,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateAuthEventFeedbackRequest {
     /// <p>The event ID.</p>
     #[serde(rename = "EventId")]
@@ -3172,7 +9857,7 @@ pub struct UpdateAuthEventFeedbackRequest {
     pub feedback_token: String,
     /// <p>The authentication event feedback value.</p>
     #[serde(rename = "FeedbackValue")]
-    pub feedback_value: String,
+    pub feedback_value: FeedbackValueType,
     /// <p>The user pool ID.</p>
     #[serde(rename = "UserPoolId")]
     pub user_pool_id: String,
@@ -3181,6 +9866,78 @@ pub struct UpdateAuthEventFeedbackRequest {
     pub username: String,
 }
 
+impl UpdateAuthEventFeedbackRequest {
+    /// Returns a builder for `UpdateAuthEventFeedbackRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAuthEventFeedbackRequestBuilder {
+        UpdateAuthEventFeedbackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAuthEventFeedbackRequest`](struct.UpdateAuthEventFeedbackRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAuthEventFeedbackRequestBuilder {
+    event_id: Option<String>,
+    feedback_token: Option<String>,
+    feedback_value: Option<FeedbackValueType>,
+    user_pool_id: Option<String>,
+    username: Option<String>,
+}
+
+impl UpdateAuthEventFeedbackRequestBuilder {
+    /// Sets the [`event_id`](struct.UpdateAuthEventFeedbackRequest.html#structfield.event_id) member.
+    pub fn event_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`feedback_token`](struct.UpdateAuthEventFeedbackRequest.html#structfield.feedback_token) member.
+    pub fn feedback_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.feedback_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`feedback_value`](struct.UpdateAuthEventFeedbackRequest.html#structfield.feedback_value) member.
+    pub fn feedback_value<T: Into<FeedbackValueType>>(mut self, value: T) -> Self {
+        self.feedback_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.UpdateAuthEventFeedbackRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.UpdateAuthEventFeedbackRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAuthEventFeedbackRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAuthEventFeedbackRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAuthEventFeedbackRequest {
+            event_id: self
+                .event_id
+                .ok_or(::rusoto_core::BuildError::missing("event_id"))?,
+            feedback_token: self
+                .feedback_token
+                .ok_or(::rusoto_core::BuildError::missing("feedback_token"))?,
+            feedback_value: self
+                .feedback_value
+                .ok_or(::rusoto_core::BuildError::missing("feedback_value"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateAuthEventFeedbackResponse {}
@@ -3197,7 +9954,60 @@ pub struct UpdateDeviceStatusRequest {
     /// <p>The status of whether a device is remembered.</p>
     #[serde(rename = "DeviceRememberedStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_remembered_status: Option<String>,
+    pub device_remembered_status: Option<DeviceRememberedStatusType>,
+}
+
+impl UpdateDeviceStatusRequest {
+    /// Returns a builder for `UpdateDeviceStatusRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDeviceStatusRequestBuilder {
+        UpdateDeviceStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDeviceStatusRequest`](struct.UpdateDeviceStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDeviceStatusRequestBuilder {
+    access_token: Option<String>,
+    device_key: Option<String>,
+    device_remembered_status: Option<DeviceRememberedStatusType>,
+}
+
+impl UpdateDeviceStatusRequestBuilder {
+    /// Sets the [`access_token`](struct.UpdateDeviceStatusRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_key`](struct.UpdateDeviceStatusRequest.html#structfield.device_key) member.
+    pub fn device_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_remembered_status`](struct.UpdateDeviceStatusRequest.html#structfield.device_remembered_status) member.
+    pub fn device_remembered_status<T: Into<DeviceRememberedStatusType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.device_remembered_status = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDeviceStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDeviceStatusRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDeviceStatusRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+            device_key: self
+                .device_key
+                .ok_or(::rusoto_core::BuildError::missing("device_key"))?,
+            device_remembered_status: self.device_remembered_status,
+        })
+    }
 }
 
 /// <p>The response to the request to update the device status.</p>
@@ -3227,6 +10037,70 @@ pub struct UpdateGroupRequest {
     pub user_pool_id: String,
 }
 
+impl UpdateGroupRequest {
+    /// Returns a builder for `UpdateGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateGroupRequestBuilder {
+        UpdateGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGroupRequest`](struct.UpdateGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateGroupRequestBuilder {
+    description: Option<String>,
+    group_name: Option<String>,
+    precedence: Option<i64>,
+    role_arn: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl UpdateGroupRequestBuilder {
+    /// Sets the [`description`](struct.UpdateGroupRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.UpdateGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`precedence`](struct.UpdateGroupRequest.html#structfield.precedence) member.
+    pub fn precedence<T: Into<i64>>(mut self, value: T) -> Self {
+        self.precedence = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.UpdateGroupRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.UpdateGroupRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateGroupRequest {
+            description: self.description,
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            precedence: self.precedence,
+            role_arn: self.role_arn,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateGroupResponse {
@@ -3258,6 +10132,78 @@ pub struct UpdateIdentityProviderRequest {
     pub user_pool_id: String,
 }
 
+impl UpdateIdentityProviderRequest {
+    /// Returns a builder for `UpdateIdentityProviderRequest`, checking its required members when built.
+    pub fn builder() -> UpdateIdentityProviderRequestBuilder {
+        UpdateIdentityProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateIdentityProviderRequest`](struct.UpdateIdentityProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateIdentityProviderRequestBuilder {
+    attribute_mapping: Option<::std::collections::HashMap<String, String>>,
+    idp_identifiers: Option<Vec<String>>,
+    provider_details: Option<::std::collections::HashMap<String, String>>,
+    provider_name: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl UpdateIdentityProviderRequestBuilder {
+    /// Sets the [`attribute_mapping`](struct.UpdateIdentityProviderRequest.html#structfield.attribute_mapping) member.
+    pub fn attribute_mapping<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attribute_mapping = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idp_identifiers`](struct.UpdateIdentityProviderRequest.html#structfield.idp_identifiers) member.
+    pub fn idp_identifiers<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.idp_identifiers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_details`](struct.UpdateIdentityProviderRequest.html#structfield.provider_details) member.
+    pub fn provider_details<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.provider_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_name`](struct.UpdateIdentityProviderRequest.html#structfield.provider_name) member.
+    pub fn provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.UpdateIdentityProviderRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateIdentityProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateIdentityProviderRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateIdentityProviderRequest {
+            attribute_mapping: self.attribute_mapping,
+            idp_identifiers: self.idp_identifiers,
+            provider_details: self.provider_details,
+            provider_name: self
+                .provider_name
+                .ok_or(::rusoto_core::BuildError::missing("provider_name"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateIdentityProviderResponse {
@@ -3283,12 +10229,72 @@ pub struct UpdateResourceServerRequest {
     pub user_pool_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateResourceServerResponse {
-    /// <p>The resource server.</p>
-    #[serde(rename = "ResourceServer")]
-    pub resource_server: ResourceServerType,
+impl UpdateResourceServerRequest {
+    /// Returns a builder for `UpdateResourceServerRequest`, checking its required members when built.
+    pub fn builder() -> UpdateResourceServerRequestBuilder {
+        UpdateResourceServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateResourceServerRequest`](struct.UpdateResourceServerRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateResourceServerRequestBuilder {
+    identifier: Option<String>,
+    name: Option<String>,
+    scopes: Option<Vec<ResourceServerScopeType>>,
+    user_pool_id: Option<String>,
+}
+
+impl UpdateResourceServerRequestBuilder {
+    /// Sets the [`identifier`](struct.UpdateResourceServerRequest.html#structfield.identifier) member.
+    pub fn identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateResourceServerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scopes`](struct.UpdateResourceServerRequest.html#structfield.scopes) member.
+    pub fn scopes<T: Into<Vec<ResourceServerScopeType>>>(mut self, value: T) -> Self {
+        self.scopes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.UpdateResourceServerRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateResourceServerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateResourceServerRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateResourceServerRequest {
+            identifier: self
+                .identifier
+                .ok_or(::rusoto_core::BuildError::missing("identifier"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            scopes: self.scopes,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateResourceServerResponse {
+    /// <p>The resource server.</p>
+    #[serde(rename = "ResourceServer")]
+    pub resource_server: ResourceServerType,
 }
 
 /// <p>Represents the request to update user attributes.</p>
@@ -3302,6 +10308,48 @@ pub struct UpdateUserAttributesRequest {
     pub user_attributes: Vec<AttributeType>,
 }
 
+impl UpdateUserAttributesRequest {
+    /// Returns a builder for `UpdateUserAttributesRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserAttributesRequestBuilder {
+        UpdateUserAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserAttributesRequest`](struct.UpdateUserAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserAttributesRequestBuilder {
+    access_token: Option<String>,
+    user_attributes: Option<Vec<AttributeType>>,
+}
+
+impl UpdateUserAttributesRequestBuilder {
+    /// Sets the [`access_token`](struct.UpdateUserAttributesRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_attributes`](struct.UpdateUserAttributesRequest.html#structfield.user_attributes) member.
+    pub fn user_attributes<T: Into<Vec<AttributeType>>>(mut self, value: T) -> Self {
+        self.user_attributes = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateUserAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserAttributesRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+            user_attributes: self
+                .user_attributes
+                .ok_or(::rusoto_core::BuildError::missing("user_attributes"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server for the request to update user attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3318,7 +10366,7 @@ pub struct UpdateUserPoolClientRequest {
     /// <p>Set to <code>code</code> to initiate a code grant flow, which provides an authorization code as the response. This code can be exchanged for access tokens with the token endpoint.</p>
     #[serde(rename = "AllowedOAuthFlows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_o_auth_flows: Option<Vec<String>>,
+    pub allowed_o_auth_flows: Option<Vec<OAuthFlowType>>,
     /// <p>Set to TRUE if the client is allowed to follow the OAuth protocol when interacting with Cognito user pools.</p>
     #[serde(rename = "AllowedOAuthFlowsUserPoolClient")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3349,7 +10397,7 @@ pub struct UpdateUserPoolClientRequest {
     /// <p>Explicit authentication flows.</p>
     #[serde(rename = "ExplicitAuthFlows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub explicit_auth_flows: Option<Vec<String>>,
+    pub explicit_auth_flows: Option<Vec<ExplicitAuthFlowsType>>,
     /// <p>A list of allowed logout URLs for the identity providers.</p>
     #[serde(rename = "LogoutURLs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3375,6 +10423,155 @@ pub struct UpdateUserPoolClientRequest {
     pub write_attributes: Option<Vec<String>>,
 }
 
+impl UpdateUserPoolClientRequest {
+    /// Returns a builder for `UpdateUserPoolClientRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserPoolClientRequestBuilder {
+        UpdateUserPoolClientRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserPoolClientRequest`](struct.UpdateUserPoolClientRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserPoolClientRequestBuilder {
+    allowed_o_auth_flows: Option<Vec<OAuthFlowType>>,
+    allowed_o_auth_flows_user_pool_client: Option<bool>,
+    allowed_o_auth_scopes: Option<Vec<String>>,
+    analytics_configuration: Option<AnalyticsConfigurationType>,
+    callback_ur_ls: Option<Vec<String>>,
+    client_id: Option<String>,
+    client_name: Option<String>,
+    default_redirect_uri: Option<String>,
+    explicit_auth_flows: Option<Vec<ExplicitAuthFlowsType>>,
+    logout_ur_ls: Option<Vec<String>>,
+    read_attributes: Option<Vec<String>>,
+    refresh_token_validity: Option<i64>,
+    supported_identity_providers: Option<Vec<String>>,
+    user_pool_id: Option<String>,
+    write_attributes: Option<Vec<String>>,
+}
+
+impl UpdateUserPoolClientRequestBuilder {
+    /// Sets the [`allowed_o_auth_flows`](struct.UpdateUserPoolClientRequest.html#structfield.allowed_o_auth_flows) member.
+    pub fn allowed_o_auth_flows<T: Into<Vec<OAuthFlowType>>>(mut self, value: T) -> Self {
+        self.allowed_o_auth_flows = Some(value.into());
+        self
+    }
+
+    /// Sets the [`allowed_o_auth_flows_user_pool_client`](struct.UpdateUserPoolClientRequest.html#structfield.allowed_o_auth_flows_user_pool_client) member.
+    pub fn allowed_o_auth_flows_user_pool_client<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allowed_o_auth_flows_user_pool_client = Some(value.into());
+        self
+    }
+
+    /// Sets the [`allowed_o_auth_scopes`](struct.UpdateUserPoolClientRequest.html#structfield.allowed_o_auth_scopes) member.
+    pub fn allowed_o_auth_scopes<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.allowed_o_auth_scopes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`analytics_configuration`](struct.UpdateUserPoolClientRequest.html#structfield.analytics_configuration) member.
+    pub fn analytics_configuration<T: Into<AnalyticsConfigurationType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.analytics_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`callback_ur_ls`](struct.UpdateUserPoolClientRequest.html#structfield.callback_ur_ls) member.
+    pub fn callback_ur_ls<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.callback_ur_ls = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.UpdateUserPoolClientRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_name`](struct.UpdateUserPoolClientRequest.html#structfield.client_name) member.
+    pub fn client_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_redirect_uri`](struct.UpdateUserPoolClientRequest.html#structfield.default_redirect_uri) member.
+    pub fn default_redirect_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_redirect_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`explicit_auth_flows`](struct.UpdateUserPoolClientRequest.html#structfield.explicit_auth_flows) member.
+    pub fn explicit_auth_flows<T: Into<Vec<ExplicitAuthFlowsType>>>(mut self, value: T) -> Self {
+        self.explicit_auth_flows = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logout_ur_ls`](struct.UpdateUserPoolClientRequest.html#structfield.logout_ur_ls) member.
+    pub fn logout_ur_ls<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.logout_ur_ls = Some(value.into());
+        self
+    }
+
+    /// Sets the [`read_attributes`](struct.UpdateUserPoolClientRequest.html#structfield.read_attributes) member.
+    pub fn read_attributes<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.read_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`refresh_token_validity`](struct.UpdateUserPoolClientRequest.html#structfield.refresh_token_validity) member.
+    pub fn refresh_token_validity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.refresh_token_validity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`supported_identity_providers`](struct.UpdateUserPoolClientRequest.html#structfield.supported_identity_providers) member.
+    pub fn supported_identity_providers<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.supported_identity_providers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.UpdateUserPoolClientRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`write_attributes`](struct.UpdateUserPoolClientRequest.html#structfield.write_attributes) member.
+    pub fn write_attributes<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.write_attributes = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserPoolClientRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateUserPoolClientRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserPoolClientRequest {
+            allowed_o_auth_flows: self.allowed_o_auth_flows,
+            allowed_o_auth_flows_user_pool_client: self.allowed_o_auth_flows_user_pool_client,
+            allowed_o_auth_scopes: self.allowed_o_auth_scopes,
+            analytics_configuration: self.analytics_configuration,
+            callback_ur_ls: self.callback_ur_ls,
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            client_name: self.client_name,
+            default_redirect_uri: self.default_redirect_uri,
+            explicit_auth_flows: self.explicit_auth_flows,
+            logout_ur_ls: self.logout_ur_ls,
+            read_attributes: self.read_attributes,
+            refresh_token_validity: self.refresh_token_validity,
+            supported_identity_providers: self.supported_identity_providers,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            write_attributes: self.write_attributes,
+        })
+    }
+}
+
 /// <p>Represents the response from the server to the request to update the user pool client.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3399,6 +10596,58 @@ pub struct UpdateUserPoolDomainRequest {
     pub user_pool_id: String,
 }
 
+impl UpdateUserPoolDomainRequest {
+    /// Returns a builder for `UpdateUserPoolDomainRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserPoolDomainRequestBuilder {
+        UpdateUserPoolDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserPoolDomainRequest`](struct.UpdateUserPoolDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserPoolDomainRequestBuilder {
+    custom_domain_config: Option<CustomDomainConfigType>,
+    domain: Option<String>,
+    user_pool_id: Option<String>,
+}
+
+impl UpdateUserPoolDomainRequestBuilder {
+    /// Sets the [`custom_domain_config`](struct.UpdateUserPoolDomainRequest.html#structfield.custom_domain_config) member.
+    pub fn custom_domain_config<T: Into<CustomDomainConfigType>>(mut self, value: T) -> Self {
+        self.custom_domain_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.UpdateUserPoolDomainRequest.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.UpdateUserPoolDomainRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserPoolDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateUserPoolDomainRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserPoolDomainRequest {
+            custom_domain_config: self
+                .custom_domain_config
+                .ok_or(::rusoto_core::BuildError::missing("custom_domain_config"))?,
+            domain: self
+                .domain
+                .ok_or(::rusoto_core::BuildError::missing("domain"))?,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+        })
+    }
+}
+
 /// <p>The UpdateUserPoolDomain response output.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3419,7 +10668,7 @@ pub struct UpdateUserPoolRequest {
     /// <p>The attributes that are automatically verified when the Amazon Cognito service makes a request to update user pools.</p>
     #[serde(rename = "AutoVerifiedAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_verified_attributes: Option<Vec<String>>,
+    pub auto_verified_attributes: Option<Vec<VerifiedAttributeType>>,
     /// <p>Device configuration.</p>
     #[serde(rename = "DeviceConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3443,7 +10692,7 @@ pub struct UpdateUserPoolRequest {
     /// <p><p>Can be one of the following values:</p> <ul> <li> <p> <code>OFF</code> - MFA tokens are not required and cannot be specified during user registration.</p> </li> <li> <p> <code>ON</code> - MFA tokens are required for all user registrations. You can only specify required when you are initially creating a user pool.</p> </li> <li> <p> <code>OPTIONAL</code> - Users have the option when registering to create an MFA token.</p> </li> </ul></p>
     #[serde(rename = "MfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mfa_configuration: Option<String>,
+    pub mfa_configuration: Option<UserPoolMfaType>,
     /// <p>A container with the policies you wish to update in a user pool.</p>
     #[serde(rename = "Policies")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3477,6 +10726,168 @@ pub struct UpdateUserPoolRequest {
     pub verification_message_template: Option<VerificationMessageTemplateType>,
 }
 
+impl UpdateUserPoolRequest {
+    /// Returns a builder for `UpdateUserPoolRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserPoolRequestBuilder {
+        UpdateUserPoolRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserPoolRequest`](struct.UpdateUserPoolRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserPoolRequestBuilder {
+    admin_create_user_config: Option<AdminCreateUserConfigType>,
+    auto_verified_attributes: Option<Vec<VerifiedAttributeType>>,
+    device_configuration: Option<DeviceConfigurationType>,
+    email_configuration: Option<EmailConfigurationType>,
+    email_verification_message: Option<String>,
+    email_verification_subject: Option<String>,
+    lambda_config: Option<LambdaConfigType>,
+    mfa_configuration: Option<UserPoolMfaType>,
+    policies: Option<UserPoolPolicyType>,
+    sms_authentication_message: Option<String>,
+    sms_configuration: Option<SmsConfigurationType>,
+    sms_verification_message: Option<String>,
+    user_pool_add_ons: Option<UserPoolAddOnsType>,
+    user_pool_id: Option<String>,
+    user_pool_tags: Option<::std::collections::HashMap<String, String>>,
+    verification_message_template: Option<VerificationMessageTemplateType>,
+}
+
+impl UpdateUserPoolRequestBuilder {
+    /// Sets the [`admin_create_user_config`](struct.UpdateUserPoolRequest.html#structfield.admin_create_user_config) member.
+    pub fn admin_create_user_config<T: Into<AdminCreateUserConfigType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.admin_create_user_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_verified_attributes`](struct.UpdateUserPoolRequest.html#structfield.auto_verified_attributes) member.
+    pub fn auto_verified_attributes<T: Into<Vec<VerifiedAttributeType>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.auto_verified_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_configuration`](struct.UpdateUserPoolRequest.html#structfield.device_configuration) member.
+    pub fn device_configuration<T: Into<DeviceConfigurationType>>(mut self, value: T) -> Self {
+        self.device_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email_configuration`](struct.UpdateUserPoolRequest.html#structfield.email_configuration) member.
+    pub fn email_configuration<T: Into<EmailConfigurationType>>(mut self, value: T) -> Self {
+        self.email_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email_verification_message`](struct.UpdateUserPoolRequest.html#structfield.email_verification_message) member.
+    pub fn email_verification_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.email_verification_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email_verification_subject`](struct.UpdateUserPoolRequest.html#structfield.email_verification_subject) member.
+    pub fn email_verification_subject<T: Into<String>>(mut self, value: T) -> Self {
+        self.email_verification_subject = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lambda_config`](struct.UpdateUserPoolRequest.html#structfield.lambda_config) member.
+    pub fn lambda_config<T: Into<LambdaConfigType>>(mut self, value: T) -> Self {
+        self.lambda_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mfa_configuration`](struct.UpdateUserPoolRequest.html#structfield.mfa_configuration) member.
+    pub fn mfa_configuration<T: Into<UserPoolMfaType>>(mut self, value: T) -> Self {
+        self.mfa_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policies`](struct.UpdateUserPoolRequest.html#structfield.policies) member.
+    pub fn policies<T: Into<UserPoolPolicyType>>(mut self, value: T) -> Self {
+        self.policies = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sms_authentication_message`](struct.UpdateUserPoolRequest.html#structfield.sms_authentication_message) member.
+    pub fn sms_authentication_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.sms_authentication_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sms_configuration`](struct.UpdateUserPoolRequest.html#structfield.sms_configuration) member.
+    pub fn sms_configuration<T: Into<SmsConfigurationType>>(mut self, value: T) -> Self {
+        self.sms_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sms_verification_message`](struct.UpdateUserPoolRequest.html#structfield.sms_verification_message) member.
+    pub fn sms_verification_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.sms_verification_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_add_ons`](struct.UpdateUserPoolRequest.html#structfield.user_pool_add_ons) member.
+    pub fn user_pool_add_ons<T: Into<UserPoolAddOnsType>>(mut self, value: T) -> Self {
+        self.user_pool_add_ons = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_id`](struct.UpdateUserPoolRequest.html#structfield.user_pool_id) member.
+    pub fn user_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_pool_tags`](struct.UpdateUserPoolRequest.html#structfield.user_pool_tags) member.
+    pub fn user_pool_tags<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.user_pool_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`verification_message_template`](struct.UpdateUserPoolRequest.html#structfield.verification_message_template) member.
+    pub fn verification_message_template<T: Into<VerificationMessageTemplateType>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.verification_message_template = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserPoolRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateUserPoolRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserPoolRequest {
+            admin_create_user_config: self.admin_create_user_config,
+            auto_verified_attributes: self.auto_verified_attributes,
+            device_configuration: self.device_configuration,
+            email_configuration: self.email_configuration,
+            email_verification_message: self.email_verification_message,
+            email_verification_subject: self.email_verification_subject,
+            lambda_config: self.lambda_config,
+            mfa_configuration: self.mfa_configuration,
+            policies: self.policies,
+            sms_authentication_message: self.sms_authentication_message,
+            sms_configuration: self.sms_configuration,
+            sms_verification_message: self.sms_verification_message,
+            user_pool_add_ons: self.user_pool_add_ons,
+            user_pool_id: self
+                .user_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("user_pool_id"))?,
+            user_pool_tags: self.user_pool_tags,
+            verification_message_template: self.verification_message_template,
+        })
+    }
+}
+
 /// <p>Represents the response from the server when you make a request to update the user pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3491,6 +10902,102 @@ pub struct UserContextDataType {
     pub encoded_data: Option<String>,
 }
 
+/// <p><p>The status of the user import job. One of the following:</p> <ul> <li> <p> <code>Created</code> - The job was created but not started.</p> </li> <li> <p> <code>Pending</code> - A transition state. You have started the job, but it has not begun importing users yet.</p> </li> <li> <p> <code>InProgress</code> - The job has started, and users are being imported.</p> </li> <li> <p> <code>Stopping</code> - You have stopped the job, but the job has not stopped importing users yet.</p> </li> <li> <p> <code>Stopped</code> - You have stopped the job, and the job has stopped importing users.</p> </li> <li> <p> <code>Succeeded</code> - The job has completed successfully.</p> </li> <li> <p> <code>Failed</code> - The job has stopped due to an error.</p> </li> <li> <p> <code>Expired</code> - You created a job, but did not start the job within 24-48 hours. All data associated with the job was deleted, and the job cannot be started.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UserImportJobStatusType {
+    /// <p><code>Created</code> - The job was created but not started.</p>
+    Created,
+    /// <p><code>Pending</code> - A transition state. You have started the job, but it has not begun importing users yet.</p>
+    Pending,
+    /// <p><code>InProgress</code> - The job has started, and users are being imported.</p>
+    InProgress,
+    /// <p><code>Stopping</code> - You have stopped the job, but the job has not stopped importing users yet.</p>
+    Stopping,
+    /// <p><code>Expired</code> - You created a job, but did not start the job within 24-48 hours. All data associated with the job was deleted, and the job cannot be started.</p>
+    Expired,
+    /// <p><code>Stopped</code> - You have stopped the job, and the job has stopped importing users.</p>
+    Stopped,
+    /// <p><code>Failed</code> - The job has stopped due to an error.</p>
+    Failed,
+    /// <p><code>Succeeded</code> - The job has completed successfully.</p>
+    Succeeded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UserImportJobStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserImportJobStatusType::Created => "Created",
+            UserImportJobStatusType::Pending => "Pending",
+            UserImportJobStatusType::InProgress => "InProgress",
+            UserImportJobStatusType::Stopping => "Stopping",
+            UserImportJobStatusType::Expired => "Expired",
+            UserImportJobStatusType::Stopped => "Stopped",
+            UserImportJobStatusType::Failed => "Failed",
+            UserImportJobStatusType::Succeeded => "Succeeded",
+            UserImportJobStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UserImportJobStatusType {
+    fn from(value: &'a str) -> UserImportJobStatusType {
+        match value {
+            "Created" => UserImportJobStatusType::Created,
+            "Pending" => UserImportJobStatusType::Pending,
+            "InProgress" => UserImportJobStatusType::InProgress,
+            "Stopping" => UserImportJobStatusType::Stopping,
+            "Expired" => UserImportJobStatusType::Expired,
+            "Stopped" => UserImportJobStatusType::Stopped,
+            "Failed" => UserImportJobStatusType::Failed,
+            "Succeeded" => UserImportJobStatusType::Succeeded,
+            _ => UserImportJobStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserImportJobStatusType {
+    fn from(value: String) -> UserImportJobStatusType {
+        UserImportJobStatusType::from(value.as_str())
+    }
+}
+
+impl From<UserImportJobStatusType> for String {
+    fn from(value: UserImportJobStatusType) -> String {
+        match value {
+            UserImportJobStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UserImportJobStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UserImportJobStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserImportJobStatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UserImportJobStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UserImportJobStatusType::from(value))
+    }
+}
+
 /// <p>The user import job type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3501,16 +11008,18 @@ pub struct UserImportJobType {
     pub cloud_watch_logs_role_arn: Option<String>,
     /// <p>The date when the user import job was completed.</p>
     #[serde(rename = "CompletionDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completion_date: Option<f64>,
+    pub completion_date: Option<EpochTimestamp>,
     /// <p>The message returned when the user import job is completed.</p>
     #[serde(rename = "CompletionMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub completion_message: Option<String>,
     /// <p>The date the user import job was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The number of users that could not be imported.</p>
     #[serde(rename = "FailedUsers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3537,12 +11046,13 @@ pub struct UserImportJobType {
     pub skipped_users: Option<i64>,
     /// <p>The date when the user import job was started.</p>
     #[serde(rename = "StartDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date: Option<f64>,
+    pub start_date: Option<EpochTimestamp>,
     /// <p><p>The status of the user import job. One of the following:</p> <ul> <li> <p> <code>Created</code> - The job was created but not started.</p> </li> <li> <p> <code>Pending</code> - A transition state. You have started the job, but it has not begun importing users yet.</p> </li> <li> <p> <code>InProgress</code> - The job has started, and users are being imported.</p> </li> <li> <p> <code>Stopping</code> - You have stopped the job, but the job has not stopped importing users yet.</p> </li> <li> <p> <code>Stopped</code> - You have stopped the job, and the job has stopped importing users.</p> </li> <li> <p> <code>Succeeded</code> - The job has completed successfully.</p> </li> <li> <p> <code>Failed</code> - The job has stopped due to an error.</p> </li> <li> <p> <code>Expired</code> - You created a job, but did not start the job within 24-48 hours. All data associated with the job was deleted, and the job cannot be started.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<UserImportJobStatusType>,
     /// <p>The user pool ID for the user pool that the users are being imported into.</p>
     #[serde(rename = "UserPoolId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3550,11 +11060,11 @@ pub struct UserImportJobType {
 }
 
 /// <p>The user pool add-ons type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserPoolAddOnsType {
     /// <p>The advanced security mode.</p>
     #[serde(rename = "AdvancedSecurityMode")]
-    pub advanced_security_mode: String,
+    pub advanced_security_mode: AdvancedSecurityModeType,
 }
 
 /// <p>The description of the user pool client.</p>
@@ -3582,7 +11092,7 @@ pub struct UserPoolClientType {
     /// <p>Set to <code>code</code> to initiate a code grant flow, which provides an authorization code as the response. This code can be exchanged for access tokens with the token endpoint.</p> <p>Set to <code>token</code> to specify that the client should get the access token (and, optionally, ID token, based on scopes) directly.</p>
     #[serde(rename = "AllowedOAuthFlows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_o_auth_flows: Option<Vec<String>>,
+    pub allowed_o_auth_flows: Option<Vec<OAuthFlowType>>,
     /// <p>Set to TRUE if the client is allowed to follow the OAuth protocol when interacting with Cognito user pools.</p>
     #[serde(rename = "AllowedOAuthFlowsUserPoolClient")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3613,8 +11123,9 @@ pub struct UserPoolClientType {
     pub client_secret: Option<String>,
     /// <p>The date the user pool client was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The default redirect URI. Must be in the <code>CallbackURLs</code> list.</p> <p>A redirect URI must:</p> <ul> <li> <p>Be an absolute URI.</p> </li> <li> <p>Be registered with the authorization server.</p> </li> <li> <p>Not include a fragment component.</p> </li> </ul> <p>See <a href="https://tools.ietf.org/html/rfc6749#section-3.1.2">OAuth 2.0 - Redirection Endpoint</a>.</p> <p>Amazon Cognito requires HTTPS over HTTP except for http://localhost for testing purposes only.</p> <p>App callback URLs such as myapp://example are also supported.</p>
     #[serde(rename = "DefaultRedirectURI")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3622,11 +11133,12 @@ pub struct UserPoolClientType {
     /// <p>The explicit authentication flows.</p>
     #[serde(rename = "ExplicitAuthFlows")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub explicit_auth_flows: Option<Vec<String>>,
+    pub explicit_auth_flows: Option<Vec<ExplicitAuthFlowsType>>,
     /// <p>The date the user pool client was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>A list of allowed logout URLs for the identity providers.</p>
     #[serde(rename = "LogoutURLs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3659,8 +11171,9 @@ pub struct UserPoolClientType {
 pub struct UserPoolDescriptionType {
     /// <p>The date the user pool description was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The ID in a user pool description.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3671,8 +11184,9 @@ pub struct UserPoolDescriptionType {
     pub lambda_config: Option<LambdaConfigType>,
     /// <p>The date the user pool description was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The name in a user pool description.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3680,7 +11194,83 @@ pub struct UserPoolDescriptionType {
     /// <p>The user pool status in a user pool description.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<StatusType>,
+}
+
+/// <p>Specifies MFA configuration details.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UserPoolMfaType {
+    /// <p><code>OFF</code> - MFA tokens are not required and cannot be specified during user registration.</p>
+    Off,
+    /// <p><code>ON</code> - MFA tokens are required for all user registrations. You can only specify required when you are initially creating a user pool.</p>
+    On,
+    /// <p><code>OPTIONAL</code> - Users have the option when registering to create an MFA token.</p>
+    Optional,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UserPoolMfaType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserPoolMfaType::Off => "OFF",
+            UserPoolMfaType::On => "ON",
+            UserPoolMfaType::Optional => "OPTIONAL",
+            UserPoolMfaType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UserPoolMfaType {
+    fn from(value: &'a str) -> UserPoolMfaType {
+        match value {
+            "OFF" => UserPoolMfaType::Off,
+            "ON" => UserPoolMfaType::On,
+            "OPTIONAL" => UserPoolMfaType::Optional,
+            _ => UserPoolMfaType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserPoolMfaType {
+    fn from(value: String) -> UserPoolMfaType {
+        UserPoolMfaType::from(value.as_str())
+    }
+}
+
+impl From<UserPoolMfaType> for String {
+    fn from(value: UserPoolMfaType) -> String {
+        match value {
+            UserPoolMfaType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UserPoolMfaType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UserPoolMfaType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserPoolMfaType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UserPoolMfaType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UserPoolMfaType::from(value))
+    }
 }
 
 /// <p>The policy associated with a user pool.</p>
@@ -3703,7 +11293,7 @@ pub struct UserPoolType {
     /// <p>Specifies the attributes that are aliased in a user pool.</p>
     #[serde(rename = "AliasAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alias_attributes: Option<Vec<String>>,
+    pub alias_attributes: Option<Vec<AliasAttributeType>>,
     /// <p>The Amazon Resource Name (ARN) for the user pool.</p>
     #[serde(rename = "Arn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3711,11 +11301,12 @@ pub struct UserPoolType {
     /// <p>Specifies the attributes that are auto-verified in a user pool.</p>
     #[serde(rename = "AutoVerifiedAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_verified_attributes: Option<Vec<String>>,
+    pub auto_verified_attributes: Option<Vec<VerifiedAttributeType>>,
     /// <p>The date the user pool was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>A custom domain name that you provide to Amazon Cognito. This parameter applies only if you use a custom domain to host the sign-up and sign-in pages for your application. For example: <code>auth.example.com</code>.</p> <p>For more information about adding a custom domain to your user pool, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/cognito-user-pools-add-custom-domain.html">Using Your Own Domain for the Hosted UI</a>.</p>
     #[serde(rename = "CustomDomain")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3758,12 +11349,13 @@ pub struct UserPoolType {
     pub lambda_config: Option<LambdaConfigType>,
     /// <p>The date the user pool was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p><p>Can be one of the following values:</p> <ul> <li> <p> <code>OFF</code> - MFA tokens are not required and cannot be specified during user registration.</p> </li> <li> <p> <code>ON</code> - MFA tokens are required for all user registrations. You can only specify required when you are initially creating a user pool.</p> </li> <li> <p> <code>OPTIONAL</code> - Users have the option when registering to create an MFA token.</p> </li> </ul></p>
     #[serde(rename = "MfaConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mfa_configuration: Option<String>,
+    pub mfa_configuration: Option<UserPoolMfaType>,
     /// <p>The name of the user pool.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3795,7 +11387,7 @@ pub struct UserPoolType {
     /// <p>The status of a user pool.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<StatusType>,
     /// <p>The user pool add-ons.</p>
     #[serde(rename = "UserPoolAddOns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3807,13 +11399,105 @@ pub struct UserPoolType {
     /// <p>Specifies whether email addresses or phone numbers can be specified as usernames when a user signs up.</p>
     #[serde(rename = "UsernameAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub username_attributes: Option<Vec<String>>,
+    pub username_attributes: Option<Vec<UsernameAttributeType>>,
     /// <p>The template for verification messages.</p>
     #[serde(rename = "VerificationMessageTemplate")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verification_message_template: Option<VerificationMessageTemplateType>,
 }
 
+/// <p><p>The user status. Can be one of the following:</p> <ul> <li> <p>UNCONFIRMED - User has been created but not confirmed.</p> </li> <li> <p>CONFIRMED - User has been confirmed.</p> </li> <li> <p>ARCHIVED - User is no longer active.</p> </li> <li> <p>COMPROMISED - User is disabled due to a potential security threat.</p> </li> <li> <p>UNKNOWN - User status is not known.</p> </li> <li> <p>RESET<em>REQUIRED - User is confirmed, but the user must request a code and reset his or her password before he or she can sign in.</p> </li> <li> <p>FORCE</em>CHANGE_PASSWORD - The user is confirmed and the user can sign in using a temporary password, but on first sign-in, the user must change his or her password to a new value before doing anything else. </p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UserStatusType {
+    /// <p><code>UNCONFIRMED</code></p>
+    Unconfirmed,
+    /// <p><code>CONFIRMED</code></p>
+    Confirmed,
+    /// <p><code>ARCHIVED</code></p>
+    Archived,
+    /// <p><code>COMPROMISED</code></p>
+    Compromised,
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// <p><code>RESET_REQUIRED</code></p>
+    ResetRequired,
+    /// <p><code>FORCE_CHANGE_PASSWORD</code></p>
+    ForceChangePassword,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UserStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UserStatusType::Unconfirmed => "UNCONFIRMED",
+            UserStatusType::Confirmed => "CONFIRMED",
+            UserStatusType::Archived => "ARCHIVED",
+            UserStatusType::Compromised => "COMPROMISED",
+            UserStatusType::UnknownValue => "UNKNOWN",
+            UserStatusType::ResetRequired => "RESET_REQUIRED",
+            UserStatusType::ForceChangePassword => "FORCE_CHANGE_PASSWORD",
+            UserStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UserStatusType {
+    fn from(value: &'a str) -> UserStatusType {
+        match value {
+            "UNCONFIRMED" => UserStatusType::Unconfirmed,
+            "CONFIRMED" => UserStatusType::Confirmed,
+            "ARCHIVED" => UserStatusType::Archived,
+            "COMPROMISED" => UserStatusType::Compromised,
+            "UNKNOWN" => UserStatusType::UnknownValue,
+            "RESET_REQUIRED" => UserStatusType::ResetRequired,
+            "FORCE_CHANGE_PASSWORD" => UserStatusType::ForceChangePassword,
+            _ => UserStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UserStatusType {
+    fn from(value: String) -> UserStatusType {
+        UserStatusType::from(value.as_str())
+    }
+}
+
+impl From<UserStatusType> for String {
+    fn from(value: UserStatusType) -> String {
+        match value {
+            UserStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UserStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UserStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UserStatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UserStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UserStatusType::from(value))
+    }
+}
+
 /// <p>The user type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3832,29 +11516,103 @@ pub struct UserType {
     pub mfa_options: Option<Vec<MFAOptionType>>,
     /// <p>The creation date of the user.</p>
     #[serde(rename = "UserCreateDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_create_date: Option<f64>,
+    pub user_create_date: Option<EpochTimestamp>,
     /// <p>The last modified date of the user.</p>
     #[serde(rename = "UserLastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_last_modified_date: Option<f64>,
+    pub user_last_modified_date: Option<EpochTimestamp>,
     /// <p><p>The user status. Can be one of the following:</p> <ul> <li> <p>UNCONFIRMED - User has been created but not confirmed.</p> </li> <li> <p>CONFIRMED - User has been confirmed.</p> </li> <li> <p>ARCHIVED - User is no longer active.</p> </li> <li> <p>COMPROMISED - User is disabled due to a potential security threat.</p> </li> <li> <p>UNKNOWN - User status is not known.</p> </li> <li> <p>RESET<em>REQUIRED - User is confirmed, but the user must request a code and reset his or her password before he or she can sign in.</p> </li> <li> <p>FORCE</em>CHANGE_PASSWORD - The user is confirmed and the user can sign in using a temporary password, but on first sign-in, the user must change his or her password to a new value before doing anything else. </p> </li> </ul></p>
     #[serde(rename = "UserStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_status: Option<String>,
+    pub user_status: Option<UserStatusType>,
     /// <p>The user name of the user you wish to describe.</p>
     #[serde(rename = "Username")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
 
+/// <p>Specifies whether email addresses or phone numbers can be specified as usernames when a user signs up.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UsernameAttributeType {
+    /// <p><code>phone_number</code></p>
+    PhoneNumber,
+    /// <p><code>email</code></p>
+    Email,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UsernameAttributeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UsernameAttributeType::PhoneNumber => "phone_number",
+            UsernameAttributeType::Email => "email",
+            UsernameAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UsernameAttributeType {
+    fn from(value: &'a str) -> UsernameAttributeType {
+        match value {
+            "phone_number" => UsernameAttributeType::PhoneNumber,
+            "email" => UsernameAttributeType::Email,
+            _ => UsernameAttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UsernameAttributeType {
+    fn from(value: String) -> UsernameAttributeType {
+        UsernameAttributeType::from(value.as_str())
+    }
+}
+
+impl From<UsernameAttributeType> for String {
+    fn from(value: UsernameAttributeType) -> String {
+        match value {
+            UsernameAttributeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UsernameAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UsernameAttributeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UsernameAttributeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UsernameAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UsernameAttributeType::from(value))
+    }
+}
+
 /// <p>The template for verification messages.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VerificationMessageTemplateType {
     /// <p>The default email option.</p>
     #[serde(rename = "DefaultEmailOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_email_option: Option<String>,
+    pub default_email_option: Option<DefaultEmailOptionType>,
     /// <p>The email message template.</p>
     #[serde(rename = "EmailMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3877,36 +11635,238 @@ pub struct VerificationMessageTemplateType {
     pub sms_message: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct VerifySoftwareTokenRequest {
-    /// <p>The access token.</p>
-    #[serde(rename = "AccessToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_token: Option<String>,
-    /// <p>The friendly device name.</p>
-    #[serde(rename = "FriendlyDeviceName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub friendly_device_name: Option<String>,
-    /// <p>The session which should be passed both ways in challenge-response calls to the service.</p>
-    #[serde(rename = "Session")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub session: Option<String>,
-    /// <p>The one time password computed using the secret code returned by </p>
-    #[serde(rename = "UserCode")]
-    pub user_code: String,
+/// <p>The attributes to be auto-verified. Possible values: <b>email</b>, <b>phone_number</b>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VerifiedAttributeType {
+    /// <p><code>phone_number</code></p>
+    PhoneNumber,
+    /// <p><code>email</code></p>
+    Email,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct VerifySoftwareTokenResponse {
-    /// <p>The session which should be passed both ways in challenge-response calls to the service.</p>
-    #[serde(rename = "Session")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub session: Option<String>,
-    /// <p>The status of the verify software token.</p>
-    #[serde(rename = "Status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+impl VerifiedAttributeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VerifiedAttributeType::PhoneNumber => "phone_number",
+            VerifiedAttributeType::Email => "email",
+            VerifiedAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VerifiedAttributeType {
+    fn from(value: &'a str) -> VerifiedAttributeType {
+        match value {
+            "phone_number" => VerifiedAttributeType::PhoneNumber,
+            "email" => VerifiedAttributeType::Email,
+            _ => VerifiedAttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VerifiedAttributeType {
+    fn from(value: String) -> VerifiedAttributeType {
+        VerifiedAttributeType::from(value.as_str())
+    }
+}
+
+impl From<VerifiedAttributeType> for String {
+    fn from(value: VerifiedAttributeType) -> String {
+        match value {
+            VerifiedAttributeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VerifiedAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for VerifiedAttributeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VerifiedAttributeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VerifiedAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VerifiedAttributeType::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct VerifySoftwareTokenRequest {
+    /// <p>The access token.</p>
+    #[serde(rename = "AccessToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token: Option<String>,
+    /// <p>The friendly device name.</p>
+    #[serde(rename = "FriendlyDeviceName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_device_name: Option<String>,
+    /// <p>The session which should be passed both ways in challenge-response calls to the service.</p>
+    #[serde(rename = "Session")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+    /// <p>The one time password computed using the secret code returned by </p>
+    #[serde(rename = "UserCode")]
+    pub user_code: String,
+}
+
+impl VerifySoftwareTokenRequest {
+    /// Returns a builder for `VerifySoftwareTokenRequest`, checking its required members when built.
+    pub fn builder() -> VerifySoftwareTokenRequestBuilder {
+        VerifySoftwareTokenRequestBuilder::default()
+    }
+}
+
+/// Builds a [`VerifySoftwareTokenRequest`](struct.VerifySoftwareTokenRequest.html).
+#[derive(Default, Debug)]
+pub struct VerifySoftwareTokenRequestBuilder {
+    access_token: Option<String>,
+    friendly_device_name: Option<String>,
+    session: Option<String>,
+    user_code: Option<String>,
+}
+
+impl VerifySoftwareTokenRequestBuilder {
+    /// Sets the [`access_token`](struct.VerifySoftwareTokenRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`friendly_device_name`](struct.VerifySoftwareTokenRequest.html#structfield.friendly_device_name) member.
+    pub fn friendly_device_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.friendly_device_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`session`](struct.VerifySoftwareTokenRequest.html#structfield.session) member.
+    pub fn session<T: Into<String>>(mut self, value: T) -> Self {
+        self.session = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_code`](struct.VerifySoftwareTokenRequest.html#structfield.user_code) member.
+    pub fn user_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `VerifySoftwareTokenRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<VerifySoftwareTokenRequest, ::rusoto_core::BuildError> {
+        Ok(VerifySoftwareTokenRequest {
+            access_token: self.access_token,
+            friendly_device_name: self.friendly_device_name,
+            session: self.session,
+            user_code: self
+                .user_code
+                .ok_or(::rusoto_core::BuildError::missing("user_code"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct VerifySoftwareTokenResponse {
+    /// <p>The session which should be passed both ways in challenge-response calls to the service.</p>
+    #[serde(rename = "Session")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+    /// <p>The status of the verify software token.</p>
+    #[serde(rename = "Status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<VerifySoftwareTokenResponseType>,
+}
+
+/// <p>The status of the verify software token.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VerifySoftwareTokenResponseType {
+    /// <p><code>SUCCESS</code></p>
+    Success,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VerifySoftwareTokenResponseType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VerifySoftwareTokenResponseType::Success => "SUCCESS",
+            VerifySoftwareTokenResponseType::Error => "ERROR",
+            VerifySoftwareTokenResponseType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VerifySoftwareTokenResponseType {
+    fn from(value: &'a str) -> VerifySoftwareTokenResponseType {
+        match value {
+            "SUCCESS" => VerifySoftwareTokenResponseType::Success,
+            "ERROR" => VerifySoftwareTokenResponseType::Error,
+            _ => VerifySoftwareTokenResponseType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VerifySoftwareTokenResponseType {
+    fn from(value: String) -> VerifySoftwareTokenResponseType {
+        VerifySoftwareTokenResponseType::from(value.as_str())
+    }
+}
+
+impl From<VerifySoftwareTokenResponseType> for String {
+    fn from(value: VerifySoftwareTokenResponseType) -> String {
+        match value {
+            VerifySoftwareTokenResponseType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VerifySoftwareTokenResponseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for VerifySoftwareTokenResponseType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VerifySoftwareTokenResponseType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<VerifySoftwareTokenResponseType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VerifySoftwareTokenResponseType::from(value))
+    }
 }
 
 /// <p>Represents the request to verify user attributes.</p>
@@ -3923,6 +11883,58 @@ pub struct VerifyUserAttributeRequest {
     pub code: String,
 }
 
+impl VerifyUserAttributeRequest {
+    /// Returns a builder for `VerifyUserAttributeRequest`, checking its required members when built.
+    pub fn builder() -> VerifyUserAttributeRequestBuilder {
+        VerifyUserAttributeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`VerifyUserAttributeRequest`](struct.VerifyUserAttributeRequest.html).
+#[derive(Default, Debug)]
+pub struct VerifyUserAttributeRequestBuilder {
+    access_token: Option<String>,
+    attribute_name: Option<String>,
+    code: Option<String>,
+}
+
+impl VerifyUserAttributeRequestBuilder {
+    /// Sets the [`access_token`](struct.VerifyUserAttributeRequest.html#structfield.access_token) member.
+    pub fn access_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attribute_name`](struct.VerifyUserAttributeRequest.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`code`](struct.VerifyUserAttributeRequest.html#structfield.code) member.
+    pub fn code<T: Into<String>>(mut self, value: T) -> Self {
+        self.code = Some(value.into());
+        self
+    }
+
+    /// Builds the `VerifyUserAttributeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<VerifyUserAttributeRequest, ::rusoto_core::BuildError> {
+        Ok(VerifyUserAttributeRequest {
+            access_token: self
+                .access_token
+                .ok_or(::rusoto_core::BuildError::missing("access_token"))?,
+            attribute_name: self
+                .attribute_name
+                .ok_or(::rusoto_core::BuildError::missing("attribute_name"))?,
+            code: self
+                .code
+                .ok_or(::rusoto_core::BuildError::missing("code"))?,
+        })
+    }
+}
+
 /// <p>A container representing the response from the server from the request to verify user attributes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -12004,559 +20016,1152 @@ pub trait CognitoIdentityProvider {
     fn admin_delete_user(
         &self,
         input: AdminDeleteUserRequest,
-    ) -> RusotoFuture<(), AdminDeleteUserError>;
-
-    /// <p>Deletes the user attributes in a user pool as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<(), AdminDeleteUserError>;
+
+    /// <p>Deletes the user attributes in a user pool as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    fn admin_delete_user_attributes(
+        &self,
+        input: AdminDeleteUserAttributesRequest,
+    ) -> RusotoFuture<AdminDeleteUserAttributesResponse, AdminDeleteUserAttributesError>;
+
+    /// <p>Disables the user from signing in with the specified external (SAML or social) identity provider. If the user to disable is a Cognito User Pools native username + password user, they are not permitted to use their password to sign-in. If the user to disable is a linked external IdP user, any link between that user and an existing user is removed. The next time the external user (no longer attached to the previously linked <code>DestinationUser</code>) signs in, they must create a new user account. See .</p> <p>This action is enabled only for admin access and requires developer credentials.</p> <p>The <code>ProviderName</code> must match the value specified when creating an IdP for the pool. </p> <p>To disable a native username + password user, the <code>ProviderName</code> value must be <code>Cognito</code> and the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code>, with the <code>ProviderAttributeValue</code> being the name that is used in the user pool for the user.</p> <p>The <code>ProviderAttributeName</code> must always be <code>Cognito_Subject</code> for social identity providers. The <code>ProviderAttributeValue</code> must always be the exact subject that was used when the user was originally linked as a source user.</p> <p>For de-linking a SAML identity, there are two scenarios. If the linked identity has not yet been used to sign-in, the <code>ProviderAttributeName</code> and <code>ProviderAttributeValue</code> must be the same values that were used for the <code>SourceUser</code> when the identities were originally linked in the call. (If the linking was done with <code>ProviderAttributeName</code> set to <code>Cognito_Subject</code>, the same applies here). However, if the user has already signed in, the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code> and <code>ProviderAttributeValue</code> must be the subject of the SAML assertion.</p>
+    fn admin_disable_provider_for_user(
+        &self,
+        input: AdminDisableProviderForUserRequest,
+    ) -> RusotoFuture<AdminDisableProviderForUserResponse, AdminDisableProviderForUserError>;
+
+    /// <p>Disables the specified user as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    fn admin_disable_user(
+        &self,
+        input: AdminDisableUserRequest,
+    ) -> RusotoFuture<AdminDisableUserResponse, AdminDisableUserError>;
+
+    /// <p>Enables the specified user as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    fn admin_enable_user(
+        &self,
+        input: AdminEnableUserRequest,
+    ) -> RusotoFuture<AdminEnableUserResponse, AdminEnableUserError>;
+
+    /// <p>Forgets the device, as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_forget_device(
+        &self,
+        input: AdminForgetDeviceRequest,
+    ) -> RusotoFuture<(), AdminForgetDeviceError>;
+
+    /// <p>Gets the device, as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_get_device(
+        &self,
+        input: AdminGetDeviceRequest,
+    ) -> RusotoFuture<AdminGetDeviceResponse, AdminGetDeviceError>;
+
+    /// <p>Gets the specified user by user name in a user pool as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    fn admin_get_user(
+        &self,
+        input: AdminGetUserRequest,
+    ) -> RusotoFuture<AdminGetUserResponse, AdminGetUserError>;
+
+    /// <p>Initiates the authentication flow, as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_initiate_auth(
+        &self,
+        input: AdminInitiateAuthRequest,
+    ) -> RusotoFuture<AdminInitiateAuthResponse, AdminInitiateAuthError>;
+
+    /// <p>Links an existing user account in a user pool (<code>DestinationUser</code>) to an identity from an external identity provider (<code>SourceUser</code>) based on a specified attribute name and value from the external identity provider. This allows you to create a link from the existing user account to an external federated user identity that has not yet been used to sign in, so that the federated user identity can be used to sign in as the existing user account. </p> <p> For example, if there is an existing user with a username and password, this API links that user to a federated user identity, so that when the federated user identity is used, the user signs in as the existing user account. </p> <important> <p>Because this API allows a user with an external federated identity to sign in as an existing user in the user pool, it is critical that it only be used with external identity providers and provider attributes that have been trusted by the application owner.</p> </important> <p>See also .</p> <p>This action is enabled only for admin access and requires developer credentials.</p>
+    fn admin_link_provider_for_user(
+        &self,
+        input: AdminLinkProviderForUserRequest,
+    ) -> RusotoFuture<AdminLinkProviderForUserResponse, AdminLinkProviderForUserError>;
+
+    /// <p>Lists devices, as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_list_devices(
+        &self,
+        input: AdminListDevicesRequest,
+    ) -> RusotoFuture<AdminListDevicesResponse, AdminListDevicesError>;
+
+    /// <p>Lists the groups that the user belongs to.</p> <p>Requires developer credentials.</p>
+    fn admin_list_groups_for_user(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> RusotoFuture<AdminListGroupsForUserResponse, AdminListGroupsForUserError>;
+
+    /// <p>Lists a history of user activity and any risks detected as part of Amazon Cognito advanced security.</p>
+    fn admin_list_user_auth_events(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> RusotoFuture<AdminListUserAuthEventsResponse, AdminListUserAuthEventsError>;
+
+    /// <p>Removes the specified user from the specified group.</p> <p>Requires developer credentials.</p>
+    fn admin_remove_user_from_group(
+        &self,
+        input: AdminRemoveUserFromGroupRequest,
+    ) -> RusotoFuture<(), AdminRemoveUserFromGroupError>;
+
+    /// <p>Resets the specified user's password in a user pool as an administrator. Works on any user.</p> <p>When a developer calls this API, the current password is invalidated, so it must be changed. If a user tries to sign in after the API is called, the app will get a PasswordResetRequiredException exception back and should direct the user down the flow to reset the password, which is the same as the forgot password flow. In addition, if the user pool has phone verification selected and a verified phone number exists for the user, or if email verification is selected and a verified email exists for the user, calling this API will also result in sending a message to the end user with the code to change their password.</p> <p>Requires developer credentials.</p>
+    fn admin_reset_user_password(
+        &self,
+        input: AdminResetUserPasswordRequest,
+    ) -> RusotoFuture<AdminResetUserPasswordResponse, AdminResetUserPasswordError>;
+
+    /// <p>Responds to an authentication challenge, as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_respond_to_auth_challenge(
+        &self,
+        input: AdminRespondToAuthChallengeRequest,
+    ) -> RusotoFuture<AdminRespondToAuthChallengeResponse, AdminRespondToAuthChallengeError>;
+
+    /// <p>Sets the user's multi-factor authentication (MFA) preference.</p>
+    fn admin_set_user_mfa_preference(
+        &self,
+        input: AdminSetUserMFAPreferenceRequest,
+    ) -> RusotoFuture<AdminSetUserMFAPreferenceResponse, AdminSetUserMFAPreferenceError>;
+
+    fn admin_set_user_password(
+        &self,
+        input: AdminSetUserPasswordRequest,
+    ) -> RusotoFuture<AdminSetUserPasswordResponse, AdminSetUserPasswordError>;
+
+    /// <p>Sets all the user settings for a specified user name. Works on any user.</p> <p>Requires developer credentials.</p>
+    fn admin_set_user_settings(
+        &self,
+        input: AdminSetUserSettingsRequest,
+    ) -> RusotoFuture<AdminSetUserSettingsResponse, AdminSetUserSettingsError>;
+
+    /// <p>Provides feedback for an authentication event as to whether it was from a valid user. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
+    fn admin_update_auth_event_feedback(
+        &self,
+        input: AdminUpdateAuthEventFeedbackRequest,
+    ) -> RusotoFuture<AdminUpdateAuthEventFeedbackResponse, AdminUpdateAuthEventFeedbackError>;
+
+    /// <p>Updates the device status as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_update_device_status(
+        &self,
+        input: AdminUpdateDeviceStatusRequest,
+    ) -> RusotoFuture<AdminUpdateDeviceStatusResponse, AdminUpdateDeviceStatusError>;
+
+    /// <p>Updates the specified user's attributes, including developer attributes, as an administrator. Works on any user.</p> <p>For custom attributes, you must prepend the <code>custom:</code> prefix to the attribute name.</p> <p>In addition to updating user attributes, this API can also be used to mark phone and email as verified.</p> <p>Requires developer credentials.</p>
+    fn admin_update_user_attributes(
+        &self,
+        input: AdminUpdateUserAttributesRequest,
+    ) -> RusotoFuture<AdminUpdateUserAttributesResponse, AdminUpdateUserAttributesError>;
+
+    /// <p>Signs out users from all devices, as an administrator.</p> <p>Requires developer credentials.</p>
+    fn admin_user_global_sign_out(
+        &self,
+        input: AdminUserGlobalSignOutRequest,
+    ) -> RusotoFuture<AdminUserGlobalSignOutResponse, AdminUserGlobalSignOutError>;
+
+    /// <p>Returns a unique generated shared secret key code for the user account. The request takes an access token or a session string, but not both.</p>
+    fn associate_software_token(
+        &self,
+        input: AssociateSoftwareTokenRequest,
+    ) -> RusotoFuture<AssociateSoftwareTokenResponse, AssociateSoftwareTokenError>;
+
+    /// <p>Changes the password for a specified user in a user pool.</p>
+    fn change_password(
+        &self,
+        input: ChangePasswordRequest,
+    ) -> RusotoFuture<ChangePasswordResponse, ChangePasswordError>;
+
+    /// <p>Confirms tracking of the device. This API call is the call that begins device tracking.</p>
+    fn confirm_device(
+        &self,
+        input: ConfirmDeviceRequest,
+    ) -> RusotoFuture<ConfirmDeviceResponse, ConfirmDeviceError>;
+
+    /// <p>Allows a user to enter a confirmation code to reset a forgotten password.</p>
+    fn confirm_forgot_password(
+        &self,
+        input: ConfirmForgotPasswordRequest,
+    ) -> RusotoFuture<ConfirmForgotPasswordResponse, ConfirmForgotPasswordError>;
+
+    /// <p>Confirms registration of a user and handles the existing alias from a previous user.</p>
+    fn confirm_sign_up(
+        &self,
+        input: ConfirmSignUpRequest,
+    ) -> RusotoFuture<ConfirmSignUpResponse, ConfirmSignUpError>;
+
+    /// <p>Creates a new group in the specified user pool.</p> <p>Requires developer credentials.</p>
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError>;
+
+    /// <p>Creates an identity provider for a user pool.</p>
+    fn create_identity_provider(
+        &self,
+        input: CreateIdentityProviderRequest,
+    ) -> RusotoFuture<CreateIdentityProviderResponse, CreateIdentityProviderError>;
+
+    /// <p>Creates a new OAuth2.0 resource server and defines custom scopes in it.</p>
+    fn create_resource_server(
+        &self,
+        input: CreateResourceServerRequest,
+    ) -> RusotoFuture<CreateResourceServerResponse, CreateResourceServerError>;
+
+    /// <p>Creates the user import job.</p>
+    fn create_user_import_job(
+        &self,
+        input: CreateUserImportJobRequest,
+    ) -> RusotoFuture<CreateUserImportJobResponse, CreateUserImportJobError>;
+
+    /// <p>Creates a new Amazon Cognito user pool and sets the password policy for the pool.</p>
+    fn create_user_pool(
+        &self,
+        input: CreateUserPoolRequest,
+    ) -> RusotoFuture<CreateUserPoolResponse, CreateUserPoolError>;
+
+    /// <p>Creates the user pool client.</p>
+    fn create_user_pool_client(
+        &self,
+        input: CreateUserPoolClientRequest,
+    ) -> RusotoFuture<CreateUserPoolClientResponse, CreateUserPoolClientError>;
+
+    /// <p>Creates a new domain for a user pool.</p>
+    fn create_user_pool_domain(
+        &self,
+        input: CreateUserPoolDomainRequest,
+    ) -> RusotoFuture<CreateUserPoolDomainResponse, CreateUserPoolDomainError>;
+
+    /// <p>Deletes a group. Currently only groups with no members can be deleted.</p> <p>Requires developer credentials.</p>
+    fn delete_group(&self, input: DeleteGroupRequest) -> RusotoFuture<(), DeleteGroupError>;
+
+    /// <p>Deletes an identity provider for a user pool.</p>
+    fn delete_identity_provider(
+        &self,
+        input: DeleteIdentityProviderRequest,
+    ) -> RusotoFuture<(), DeleteIdentityProviderError>;
+
+    /// <p>Deletes a resource server.</p>
+    fn delete_resource_server(
+        &self,
+        input: DeleteResourceServerRequest,
+    ) -> RusotoFuture<(), DeleteResourceServerError>;
+
+    /// <p>Allows a user to delete himself or herself.</p>
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError>;
+
+    /// <p>Deletes the attributes for a user.</p>
+    fn delete_user_attributes(
+        &self,
+        input: DeleteUserAttributesRequest,
+    ) -> RusotoFuture<DeleteUserAttributesResponse, DeleteUserAttributesError>;
+
+    /// <p>Deletes the specified Amazon Cognito user pool.</p>
+    fn delete_user_pool(
+        &self,
+        input: DeleteUserPoolRequest,
+    ) -> RusotoFuture<(), DeleteUserPoolError>;
+
+    /// <p>Allows the developer to delete the user pool client.</p>
+    fn delete_user_pool_client(
+        &self,
+        input: DeleteUserPoolClientRequest,
+    ) -> RusotoFuture<(), DeleteUserPoolClientError>;
+
+    /// <p>Deletes a domain for a user pool.</p>
+    fn delete_user_pool_domain(
+        &self,
+        input: DeleteUserPoolDomainRequest,
+    ) -> RusotoFuture<DeleteUserPoolDomainResponse, DeleteUserPoolDomainError>;
+
+    /// <p>Gets information about a specific identity provider.</p>
+    fn describe_identity_provider(
+        &self,
+        input: DescribeIdentityProviderRequest,
+    ) -> RusotoFuture<DescribeIdentityProviderResponse, DescribeIdentityProviderError>;
+
+    /// <p>Describes a resource server.</p>
+    fn describe_resource_server(
+        &self,
+        input: DescribeResourceServerRequest,
+    ) -> RusotoFuture<DescribeResourceServerResponse, DescribeResourceServerError>;
+
+    /// <p>Describes the risk configuration.</p>
+    fn describe_risk_configuration(
+        &self,
+        input: DescribeRiskConfigurationRequest,
+    ) -> RusotoFuture<DescribeRiskConfigurationResponse, DescribeRiskConfigurationError>;
+
+    /// <p>Describes the user import job.</p>
+    fn describe_user_import_job(
+        &self,
+        input: DescribeUserImportJobRequest,
+    ) -> RusotoFuture<DescribeUserImportJobResponse, DescribeUserImportJobError>;
+
+    /// <p>Returns the configuration information and metadata of the specified user pool.</p>
+    fn describe_user_pool(
+        &self,
+        input: DescribeUserPoolRequest,
+    ) -> RusotoFuture<DescribeUserPoolResponse, DescribeUserPoolError>;
+
+    /// <p>Client method for returning the configuration information and metadata of the specified user pool app client.</p>
+    fn describe_user_pool_client(
+        &self,
+        input: DescribeUserPoolClientRequest,
+    ) -> RusotoFuture<DescribeUserPoolClientResponse, DescribeUserPoolClientError>;
+
+    /// <p>Gets information about a domain.</p>
+    fn describe_user_pool_domain(
+        &self,
+        input: DescribeUserPoolDomainRequest,
+    ) -> RusotoFuture<DescribeUserPoolDomainResponse, DescribeUserPoolDomainError>;
+
+    /// <p>Forgets the specified device.</p>
+    fn forget_device(&self, input: ForgetDeviceRequest) -> RusotoFuture<(), ForgetDeviceError>;
+
+    /// <p>Calling this API causes a message to be sent to the end user with a confirmation code that is required to change the user's password. For the <code>Username</code> parameter, you can use the username or user alias. If a verified phone number exists for the user, the confirmation code is sent to the phone number. Otherwise, if a verified email exists, the confirmation code is sent to the email. If neither a verified phone number nor a verified email exists, <code>InvalidParameterException</code> is thrown. To use the confirmation code for resetting the password, call .</p>
+    fn forgot_password(
+        &self,
+        input: ForgotPasswordRequest,
+    ) -> RusotoFuture<ForgotPasswordResponse, ForgotPasswordError>;
+
+    /// <p>Gets the header information for the .csv file to be used as input for the user import job.</p>
+    fn get_csv_header(
+        &self,
+        input: GetCSVHeaderRequest,
+    ) -> RusotoFuture<GetCSVHeaderResponse, GetCSVHeaderError>;
+
+    /// <p>Gets the device.</p>
+    fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError>;
+
+    /// <p>Gets a group.</p> <p>Requires developer credentials.</p>
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError>;
+
+    /// <p>Gets the specified identity provider.</p>
+    fn get_identity_provider_by_identifier(
+        &self,
+        input: GetIdentityProviderByIdentifierRequest,
+    ) -> RusotoFuture<GetIdentityProviderByIdentifierResponse, GetIdentityProviderByIdentifierError>;
+
+    /// <p>This method takes a user pool ID, and returns the signing certificate.</p>
+    fn get_signing_certificate(
+        &self,
+        input: GetSigningCertificateRequest,
+    ) -> RusotoFuture<GetSigningCertificateResponse, GetSigningCertificateError>;
+
+    /// <p>Gets the UI Customization information for a particular app client's app UI, if there is something set. If nothing is set for the particular client, but there is an existing pool level customization (app <code>clientId</code> will be <code>ALL</code>), then that is returned. If nothing is present, then an empty shape is returned.</p>
+    fn get_ui_customization(
+        &self,
+        input: GetUICustomizationRequest,
+    ) -> RusotoFuture<GetUICustomizationResponse, GetUICustomizationError>;
+
+    /// <p>Gets the user attributes and metadata for a user.</p>
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError>;
+
+    /// <p>Gets the user attribute verification code for the specified attribute name.</p>
+    fn get_user_attribute_verification_code(
+        &self,
+        input: GetUserAttributeVerificationCodeRequest,
+    ) -> RusotoFuture<GetUserAttributeVerificationCodeResponse, GetUserAttributeVerificationCodeError>;
+
+    /// <p>Gets the user pool multi-factor authentication (MFA) configuration.</p>
+    fn get_user_pool_mfa_config(
+        &self,
+        input: GetUserPoolMfaConfigRequest,
+    ) -> RusotoFuture<GetUserPoolMfaConfigResponse, GetUserPoolMfaConfigError>;
+
+    /// <p>Signs out users from all devices.</p>
+    fn global_sign_out(
+        &self,
+        input: GlobalSignOutRequest,
+    ) -> RusotoFuture<GlobalSignOutResponse, GlobalSignOutError>;
+
+    /// <p>Initiates the authentication flow.</p>
+    fn initiate_auth(
+        &self,
+        input: InitiateAuthRequest,
+    ) -> RusotoFuture<InitiateAuthResponse, InitiateAuthError>;
+
+    /// <p>Lists the devices.</p>
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError>;
+
+    /// <p>Lists the groups associated with a user pool.</p> <p>Requires developer credentials.</p>
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError>;
+
+    /// <p>Lists information about all identity providers for a user pool.</p>
+    fn list_identity_providers(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> RusotoFuture<ListIdentityProvidersResponse, ListIdentityProvidersError>;
+
+    /// <p>Lists the resource servers for a user pool.</p>
+    fn list_resource_servers(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> RusotoFuture<ListResourceServersResponse, ListResourceServersError>;
+
+    /// <p>Lists the tags that are assigned to an Amazon Cognito user pool.</p> <p>A tag is a label that you can apply to user pools to categorize and manage them in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>You can use this action up to 10 times per second, per account.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
+
+    /// <p>Lists the user import jobs.</p>
+    fn list_user_import_jobs(
+        &self,
+        input: ListUserImportJobsRequest,
+    ) -> RusotoFuture<ListUserImportJobsResponse, ListUserImportJobsError>;
+
+    /// <p>Lists the clients that have been created for the specified user pool.</p>
+    fn list_user_pool_clients(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> RusotoFuture<ListUserPoolClientsResponse, ListUserPoolClientsError>;
+
+    /// <p>Lists the user pools associated with an AWS account.</p>
+    fn list_user_pools(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> RusotoFuture<ListUserPoolsResponse, ListUserPoolsError>;
+
+    /// <p>Lists the users in the Amazon Cognito user pool.</p>
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError>;
+
+    /// <p>Lists the users in the specified group.</p> <p>Requires developer credentials.</p>
+    fn list_users_in_group(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> RusotoFuture<ListUsersInGroupResponse, ListUsersInGroupError>;
+
+    /// <p>Resends the confirmation (for confirmation of registration) to a specific user in the user pool.</p>
+    fn resend_confirmation_code(
+        &self,
+        input: ResendConfirmationCodeRequest,
+    ) -> RusotoFuture<ResendConfirmationCodeResponse, ResendConfirmationCodeError>;
+
+    /// <p>Responds to the authentication challenge.</p>
+    fn respond_to_auth_challenge(
+        &self,
+        input: RespondToAuthChallengeRequest,
+    ) -> RusotoFuture<RespondToAuthChallengeResponse, RespondToAuthChallengeError>;
+
+    /// <p>Configures actions on detected risks. To delete the risk configuration for <code>UserPoolId</code> or <code>ClientId</code>, pass null values for all four configuration types.</p> <p>To enable Amazon Cognito advanced security features, update the user pool to include the <code>UserPoolAddOns</code> key<code>AdvancedSecurityMode</code>.</p> <p>See .</p>
+    fn set_risk_configuration(
+        &self,
+        input: SetRiskConfigurationRequest,
+    ) -> RusotoFuture<SetRiskConfigurationResponse, SetRiskConfigurationError>;
+
+    /// <p><p>Sets the UI customization information for a user pool&#39;s built-in app UI.</p> <p>You can specify app UI customization settings for a single client (with a specific <code>clientId</code>) or for all clients (by setting the <code>clientId</code> to <code>ALL</code>). If you specify <code>ALL</code>, the default configuration will be used for every client that has no UI customization set previously. If you specify UI customization settings for a particular client, it will no longer fall back to the <code>ALL</code> configuration. </p> <note> <p>To use this API, your user pool must have a domain associated with it. Otherwise, there is no place to host the app&#39;s pages, and the service will throw an error.</p> </note></p>
+    fn set_ui_customization(
+        &self,
+        input: SetUICustomizationRequest,
+    ) -> RusotoFuture<SetUICustomizationResponse, SetUICustomizationError>;
+
+    /// <p>Set the user's multi-factor authentication (MFA) method preference.</p>
+    fn set_user_mfa_preference(
+        &self,
+        input: SetUserMFAPreferenceRequest,
+    ) -> RusotoFuture<SetUserMFAPreferenceResponse, SetUserMFAPreferenceError>;
+
+    /// <p>Set the user pool MFA configuration.</p>
+    fn set_user_pool_mfa_config(
+        &self,
+        input: SetUserPoolMfaConfigRequest,
+    ) -> RusotoFuture<SetUserPoolMfaConfigResponse, SetUserPoolMfaConfigError>;
+
+    /// <p>Sets the user settings like multi-factor authentication (MFA). If MFA is to be removed for a particular attribute pass the attribute with code delivery as null. If null list is passed, all MFA options are removed.</p>
+    fn set_user_settings(
+        &self,
+        input: SetUserSettingsRequest,
+    ) -> RusotoFuture<SetUserSettingsResponse, SetUserSettingsError>;
+
+    /// <p>Registers the user in the specified user pool and creates a user name, password, and user attributes.</p>
+    fn sign_up(&self, input: SignUpRequest) -> RusotoFuture<SignUpResponse, SignUpError>;
+
+    /// <p>Starts the user import.</p>
+    fn start_user_import_job(
+        &self,
+        input: StartUserImportJobRequest,
+    ) -> RusotoFuture<StartUserImportJobResponse, StartUserImportJobError>;
+
+    /// <p>Stops the user import job.</p>
+    fn stop_user_import_job(
+        &self,
+        input: StopUserImportJobRequest,
+    ) -> RusotoFuture<StopUserImportJobResponse, StopUserImportJobError>;
+
+    /// <p>Assigns a set of tags to an Amazon Cognito user pool. A tag is a label that you can use to categorize and manage user pools in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>Each tag consists of a key and value, both of which you define. A key is a general category for more specific values. For example, if you have two versions of a user pool, one for testing and another for production, you might assign an <code>Environment</code> tag key to both user pools. The value of this key might be <code>Test</code> for one user pool and <code>Production</code> for the other.</p> <p>Tags are useful for cost tracking and access control. You can activate your tags so that they appear on the Billing and Cost Management console, where you can track the costs associated with your user pools. In an IAM policy, you can constrain permissions for user pools based on specific tags or tag values.</p> <p>You can use this action up to 5 times per second, per account. A user pool can have as many as 50 tags.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError>;
+
+    /// <p>Removes the specified tags from an Amazon Cognito user pool. You can use this action up to 5 times per second, per account</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
+
+    /// <p>Provides the feedback for an authentication event whether it was from a valid user or not. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
+    fn update_auth_event_feedback(
+        &self,
+        input: UpdateAuthEventFeedbackRequest,
+    ) -> RusotoFuture<UpdateAuthEventFeedbackResponse, UpdateAuthEventFeedbackError>;
+
+    /// <p>Updates the device status.</p>
+    fn update_device_status(
+        &self,
+        input: UpdateDeviceStatusRequest,
+    ) -> RusotoFuture<UpdateDeviceStatusResponse, UpdateDeviceStatusError>;
+
+    /// <p>Updates the specified group with the specified attributes.</p> <p>Requires developer credentials.</p>
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError>;
+
+    /// <p>Updates identity provider information for a user pool.</p>
+    fn update_identity_provider(
+        &self,
+        input: UpdateIdentityProviderRequest,
+    ) -> RusotoFuture<UpdateIdentityProviderResponse, UpdateIdentityProviderError>;
+
+    /// <p>Updates the name and scopes of resource server. All other fields are read-only.</p>
+    fn update_resource_server(
+        &self,
+        input: UpdateResourceServerRequest,
+    ) -> RusotoFuture<UpdateResourceServerResponse, UpdateResourceServerError>;
+
+    /// <p>Allows a user to update a specific attribute (one at a time).</p>
+    fn update_user_attributes(
+        &self,
+        input: UpdateUserAttributesRequest,
+    ) -> RusotoFuture<UpdateUserAttributesResponse, UpdateUserAttributesError>;
+
+    /// <p>Updates the specified user pool with the specified attributes. If you don't provide a value for an attribute, it will be set to the default value. You can get a list of the current user pool settings with .</p>
+    fn update_user_pool(
+        &self,
+        input: UpdateUserPoolRequest,
+    ) -> RusotoFuture<UpdateUserPoolResponse, UpdateUserPoolError>;
+
+    /// <p>Updates the specified user pool app client with the specified attributes. If you don't provide a value for an attribute, it will be set to the default value. You can get a list of the current user pool app client settings with .</p>
+    fn update_user_pool_client(
+        &self,
+        input: UpdateUserPoolClientRequest,
+    ) -> RusotoFuture<UpdateUserPoolClientResponse, UpdateUserPoolClientError>;
+
+    /// <p>Updates the Secure Sockets Layer (SSL) certificate for the custom domain for your user pool.</p> <p>You can use this operation to provide the Amazon Resource Name (ARN) of a new certificate to Amazon Cognito. You cannot use it to change the domain for a user pool.</p> <p>A custom domain is used to host the Amazon Cognito hosted UI, which provides sign-up and sign-in pages for your application. When you set up a custom domain, you provide a certificate that you manage with AWS Certificate Manager (ACM). When necessary, you can use this operation to change the certificate that you applied to your custom domain.</p> <p>Usually, this is unnecessary following routine certificate renewal with ACM. When you renew your existing certificate in ACM, the ARN for your certificate remains the same, and your custom domain uses the new certificate automatically.</p> <p>However, if you replace your existing certificate with a new one, ACM gives the new certificate a new ARN. To apply the new certificate to your custom domain, you must provide this ARN to Amazon Cognito.</p> <p>When you add your new certificate in ACM, you must choose US East (N. Virginia) as the AWS Region.</p> <p>After you submit your request, Amazon Cognito requires up to 1 hour to distribute your new certificate to your custom domain.</p> <p>For more information about adding a custom domain to your user pool, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/cognito-user-pools-add-custom-domain.html">Using Your Own Domain for the Hosted UI</a>.</p>
+    fn update_user_pool_domain(
+        &self,
+        input: UpdateUserPoolDomainRequest,
+    ) -> RusotoFuture<UpdateUserPoolDomainResponse, UpdateUserPoolDomainError>;
+
+    /// <p>Use this API to register a user's entered TOTP code and mark the user's software token MFA status as "verified" if successful. The request takes an access token or a session string, but not both.</p>
+    fn verify_software_token(
+        &self,
+        input: VerifySoftwareTokenRequest,
+    ) -> RusotoFuture<VerifySoftwareTokenResponse, VerifySoftwareTokenError>;
+
+    /// <p>Verifies the specified user attributes in the user pool.</p>
+    fn verify_user_attribute(
+        &self,
+        input: VerifyUserAttributeRequest,
+    ) -> RusotoFuture<VerifyUserAttributeResponse, VerifyUserAttributeError>;
+}
+impl<T: ?Sized + CognitoIdentityProvider> CognitoIdentityProvider for ::std::sync::Arc<T> {
+    fn add_custom_attributes(
+        &self,
+        input: AddCustomAttributesRequest,
+    ) -> RusotoFuture<AddCustomAttributesResponse, AddCustomAttributesError> {
+        (**self).add_custom_attributes(input)
+    }
+    fn admin_add_user_to_group(
+        &self,
+        input: AdminAddUserToGroupRequest,
+    ) -> RusotoFuture<(), AdminAddUserToGroupError> {
+        (**self).admin_add_user_to_group(input)
+    }
+    fn admin_confirm_sign_up(
+        &self,
+        input: AdminConfirmSignUpRequest,
+    ) -> RusotoFuture<AdminConfirmSignUpResponse, AdminConfirmSignUpError> {
+        (**self).admin_confirm_sign_up(input)
+    }
+    fn admin_create_user(
+        &self,
+        input: AdminCreateUserRequest,
+    ) -> RusotoFuture<AdminCreateUserResponse, AdminCreateUserError> {
+        (**self).admin_create_user(input)
+    }
+    fn admin_delete_user(
+        &self,
+        input: AdminDeleteUserRequest,
+    ) -> RusotoFuture<(), AdminDeleteUserError> {
+        (**self).admin_delete_user(input)
+    }
     fn admin_delete_user_attributes(
         &self,
         input: AdminDeleteUserAttributesRequest,
-    ) -> RusotoFuture<AdminDeleteUserAttributesResponse, AdminDeleteUserAttributesError>;
-
-    /// <p>Disables the user from signing in with the specified external (SAML or social) identity provider. If the user to disable is a Cognito User Pools native username + password user, they are not permitted to use their password to sign-in. If the user to disable is a linked external IdP user, any link between that user and an existing user is removed. The next time the external user (no longer attached to the previously linked <code>DestinationUser</code>) signs in, they must create a new user account. See .</p> <p>This action is enabled only for admin access and requires developer credentials.</p> <p>The <code>ProviderName</code> must match the value specified when creating an IdP for the pool. </p> <p>To disable a native username + password user, the <code>ProviderName</code> value must be <code>Cognito</code> and the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code>, with the <code>ProviderAttributeValue</code> being the name that is used in the user pool for the user.</p> <p>The <code>ProviderAttributeName</code> must always be <code>Cognito_Subject</code> for social identity providers. The <code>ProviderAttributeValue</code> must always be the exact subject that was used when the user was originally linked as a source user.</p> <p>For de-linking a SAML identity, there are two scenarios. If the linked identity has not yet been used to sign-in, the <code>ProviderAttributeName</code> and <code>ProviderAttributeValue</code> must be the same values that were used for the <code>SourceUser</code> when the identities were originally linked in the call. (If the linking was done with <code>ProviderAttributeName</code> set to <code>Cognito_Subject</code>, the same applies here). However, if the user has already signed in, the <code>ProviderAttributeName</code> must be <code>Cognito_Subject</code> and <code>ProviderAttributeValue</code> must be the subject of the SAML assertion.</p>
+    ) -> RusotoFuture<AdminDeleteUserAttributesResponse, AdminDeleteUserAttributesError> {
+        (**self).admin_delete_user_attributes(input)
+    }
     fn admin_disable_provider_for_user(
         &self,
         input: AdminDisableProviderForUserRequest,
-    ) -> RusotoFuture<AdminDisableProviderForUserResponse, AdminDisableProviderForUserError>;
-
-    /// <p>Disables the specified user as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminDisableProviderForUserResponse, AdminDisableProviderForUserError> {
+        (**self).admin_disable_provider_for_user(input)
+    }
     fn admin_disable_user(
         &self,
         input: AdminDisableUserRequest,
-    ) -> RusotoFuture<AdminDisableUserResponse, AdminDisableUserError>;
-
-    /// <p>Enables the specified user as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminDisableUserResponse, AdminDisableUserError> {
+        (**self).admin_disable_user(input)
+    }
     fn admin_enable_user(
         &self,
         input: AdminEnableUserRequest,
-    ) -> RusotoFuture<AdminEnableUserResponse, AdminEnableUserError>;
-
-    /// <p>Forgets the device, as an administrator.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminEnableUserResponse, AdminEnableUserError> {
+        (**self).admin_enable_user(input)
+    }
     fn admin_forget_device(
         &self,
         input: AdminForgetDeviceRequest,
-    ) -> RusotoFuture<(), AdminForgetDeviceError>;
-
-    /// <p>Gets the device, as an administrator.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<(), AdminForgetDeviceError> {
+        (**self).admin_forget_device(input)
+    }
     fn admin_get_device(
         &self,
         input: AdminGetDeviceRequest,
-    ) -> RusotoFuture<AdminGetDeviceResponse, AdminGetDeviceError>;
-
-    /// <p>Gets the specified user by user name in a user pool as an administrator. Works on any user.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminGetDeviceResponse, AdminGetDeviceError> {
+        (**self).admin_get_device(input)
+    }
     fn admin_get_user(
         &self,
         input: AdminGetUserRequest,
-    ) -> RusotoFuture<AdminGetUserResponse, AdminGetUserError>;
-
-    /// <p>Initiates the authentication flow, as an administrator.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminGetUserResponse, AdminGetUserError> {
+        (**self).admin_get_user(input)
+    }
     fn admin_initiate_auth(
         &self,
         input: AdminInitiateAuthRequest,
-    ) -> RusotoFuture<AdminInitiateAuthResponse, AdminInitiateAuthError>;
-
-    /// <p>Links an existing user account in a user pool (<code>DestinationUser</code>) to an identity from an external identity provider (<code>SourceUser</code>) based on a specified attribute name and value from the external identity provider. This allows you to create a link from the existing user account to an external federated user identity that has not yet been used to sign in, so that the federated user identity can be used to sign in as the existing user account. </p> <p> For example, if there is an existing user with a username and password, this API links that user to a federated user identity, so that when the federated user identity is used, the user signs in as the existing user account. </p> <important> <p>Because this API allows a user with an external federated identity to sign in as an existing user in the user pool, it is critical that it only be used with external identity providers and provider attributes that have been trusted by the application owner.</p> </important> <p>See also .</p> <p>This action is enabled only for admin access and requires developer credentials.</p>
+    ) -> RusotoFuture<AdminInitiateAuthResponse, AdminInitiateAuthError> {
+        (**self).admin_initiate_auth(input)
+    }
     fn admin_link_provider_for_user(
         &self,
         input: AdminLinkProviderForUserRequest,
-    ) -> RusotoFuture<AdminLinkProviderForUserResponse, AdminLinkProviderForUserError>;
-
-    /// <p>Lists devices, as an administrator.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminLinkProviderForUserResponse, AdminLinkProviderForUserError> {
+        (**self).admin_link_provider_for_user(input)
+    }
     fn admin_list_devices(
         &self,
         input: AdminListDevicesRequest,
-    ) -> RusotoFuture<AdminListDevicesResponse, AdminListDevicesError>;
-
-    /// <p>Lists the groups that the user belongs to.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminListDevicesResponse, AdminListDevicesError> {
+        (**self).admin_list_devices(input)
+    }
     fn admin_list_groups_for_user(
         &self,
         input: AdminListGroupsForUserRequest,
-    ) -> RusotoFuture<AdminListGroupsForUserResponse, AdminListGroupsForUserError>;
-
-    /// <p>Lists a history of user activity and any risks detected as part of Amazon Cognito advanced security.</p>
+    ) -> RusotoFuture<AdminListGroupsForUserResponse, AdminListGroupsForUserError> {
+        (**self).admin_list_groups_for_user(input)
+    }
     fn admin_list_user_auth_events(
         &self,
         input: AdminListUserAuthEventsRequest,
-    ) -> RusotoFuture<AdminListUserAuthEventsResponse, AdminListUserAuthEventsError>;
-
-    /// <p>Removes the specified user from the specified group.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminListUserAuthEventsResponse, AdminListUserAuthEventsError> {
+        (**self).admin_list_user_auth_events(input)
+    }
     fn admin_remove_user_from_group(
         &self,
         input: AdminRemoveUserFromGroupRequest,
-    ) -> RusotoFuture<(), AdminRemoveUserFromGroupError>;
-
-    /// <p>Resets the specified user's password in a user pool as an administrator. Works on any user.</p> <p>When a developer calls this API, the current password is invalidated, so it must be changed. If a user tries to sign in after the API is called, the app will get a PasswordResetRequiredException exception back and should direct the user down the flow to reset the password, which is the same as the forgot password flow. In addition, if the user pool has phone verification selected and a verified phone number exists for the user, or if email verification is selected and a verified email exists for the user, calling this API will also result in sending a message to the end user with the code to change their password.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<(), AdminRemoveUserFromGroupError> {
+        (**self).admin_remove_user_from_group(input)
+    }
     fn admin_reset_user_password(
         &self,
         input: AdminResetUserPasswordRequest,
-    ) -> RusotoFuture<AdminResetUserPasswordResponse, AdminResetUserPasswordError>;
-
-    /// <p>Responds to an authentication challenge, as an administrator.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminResetUserPasswordResponse, AdminResetUserPasswordError> {
+        (**self).admin_reset_user_password(input)
+    }
     fn admin_respond_to_auth_challenge(
         &self,
         input: AdminRespondToAuthChallengeRequest,
-    ) -> RusotoFuture<AdminRespondToAuthChallengeResponse, AdminRespondToAuthChallengeError>;
-
-    /// <p>Sets the user's multi-factor authentication (MFA) preference.</p>
+    ) -> RusotoFuture<AdminRespondToAuthChallengeResponse, AdminRespondToAuthChallengeError> {
+        (**self).admin_respond_to_auth_challenge(input)
+    }
     fn admin_set_user_mfa_preference(
         &self,
         input: AdminSetUserMFAPreferenceRequest,
-    ) -> RusotoFuture<AdminSetUserMFAPreferenceResponse, AdminSetUserMFAPreferenceError>;
-
+    ) -> RusotoFuture<AdminSetUserMFAPreferenceResponse, AdminSetUserMFAPreferenceError> {
+        (**self).admin_set_user_mfa_preference(input)
+    }
     fn admin_set_user_password(
         &self,
         input: AdminSetUserPasswordRequest,
-    ) -> RusotoFuture<AdminSetUserPasswordResponse, AdminSetUserPasswordError>;
-
-    /// <p>Sets all the user settings for a specified user name. Works on any user.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminSetUserPasswordResponse, AdminSetUserPasswordError> {
+        (**self).admin_set_user_password(input)
+    }
     fn admin_set_user_settings(
         &self,
         input: AdminSetUserSettingsRequest,
-    ) -> RusotoFuture<AdminSetUserSettingsResponse, AdminSetUserSettingsError>;
-
-    /// <p>Provides feedback for an authentication event as to whether it was from a valid user. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
+    ) -> RusotoFuture<AdminSetUserSettingsResponse, AdminSetUserSettingsError> {
+        (**self).admin_set_user_settings(input)
+    }
     fn admin_update_auth_event_feedback(
         &self,
         input: AdminUpdateAuthEventFeedbackRequest,
-    ) -> RusotoFuture<AdminUpdateAuthEventFeedbackResponse, AdminUpdateAuthEventFeedbackError>;
-
-    /// <p>Updates the device status as an administrator.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminUpdateAuthEventFeedbackResponse, AdminUpdateAuthEventFeedbackError> {
+        (**self).admin_update_auth_event_feedback(input)
+    }
     fn admin_update_device_status(
         &self,
         input: AdminUpdateDeviceStatusRequest,
-    ) -> RusotoFuture<AdminUpdateDeviceStatusResponse, AdminUpdateDeviceStatusError>;
-
-    /// <p>Updates the specified user's attributes, including developer attributes, as an administrator. Works on any user.</p> <p>For custom attributes, you must prepend the <code>custom:</code> prefix to the attribute name.</p> <p>In addition to updating user attributes, this API can also be used to mark phone and email as verified.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminUpdateDeviceStatusResponse, AdminUpdateDeviceStatusError> {
+        (**self).admin_update_device_status(input)
+    }
     fn admin_update_user_attributes(
         &self,
         input: AdminUpdateUserAttributesRequest,
-    ) -> RusotoFuture<AdminUpdateUserAttributesResponse, AdminUpdateUserAttributesError>;
-
-    /// <p>Signs out users from all devices, as an administrator.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<AdminUpdateUserAttributesResponse, AdminUpdateUserAttributesError> {
+        (**self).admin_update_user_attributes(input)
+    }
     fn admin_user_global_sign_out(
         &self,
         input: AdminUserGlobalSignOutRequest,
-    ) -> RusotoFuture<AdminUserGlobalSignOutResponse, AdminUserGlobalSignOutError>;
-
-    /// <p>Returns a unique generated shared secret key code for the user account. The request takes an access token or a session string, but not both.</p>
+    ) -> RusotoFuture<AdminUserGlobalSignOutResponse, AdminUserGlobalSignOutError> {
+        (**self).admin_user_global_sign_out(input)
+    }
     fn associate_software_token(
         &self,
         input: AssociateSoftwareTokenRequest,
-    ) -> RusotoFuture<AssociateSoftwareTokenResponse, AssociateSoftwareTokenError>;
-
-    /// <p>Changes the password for a specified user in a user pool.</p>
+    ) -> RusotoFuture<AssociateSoftwareTokenResponse, AssociateSoftwareTokenError> {
+        (**self).associate_software_token(input)
+    }
     fn change_password(
         &self,
         input: ChangePasswordRequest,
-    ) -> RusotoFuture<ChangePasswordResponse, ChangePasswordError>;
-
-    /// <p>Confirms tracking of the device. This API call is the call that begins device tracking.</p>
+    ) -> RusotoFuture<ChangePasswordResponse, ChangePasswordError> {
+        (**self).change_password(input)
+    }
     fn confirm_device(
         &self,
         input: ConfirmDeviceRequest,
-    ) -> RusotoFuture<ConfirmDeviceResponse, ConfirmDeviceError>;
-
-    /// <p>Allows a user to enter a confirmation code to reset a forgotten password.</p>
+    ) -> RusotoFuture<ConfirmDeviceResponse, ConfirmDeviceError> {
+        (**self).confirm_device(input)
+    }
     fn confirm_forgot_password(
         &self,
         input: ConfirmForgotPasswordRequest,
-    ) -> RusotoFuture<ConfirmForgotPasswordResponse, ConfirmForgotPasswordError>;
-
-    /// <p>Confirms registration of a user and handles the existing alias from a previous user.</p>
+    ) -> RusotoFuture<ConfirmForgotPasswordResponse, ConfirmForgotPasswordError> {
+        (**self).confirm_forgot_password(input)
+    }
     fn confirm_sign_up(
         &self,
         input: ConfirmSignUpRequest,
-    ) -> RusotoFuture<ConfirmSignUpResponse, ConfirmSignUpError>;
-
-    /// <p>Creates a new group in the specified user pool.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<ConfirmSignUpResponse, ConfirmSignUpError> {
+        (**self).confirm_sign_up(input)
+    }
     fn create_group(
         &self,
         input: CreateGroupRequest,
-    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError>;
-
-    /// <p>Creates an identity provider for a user pool.</p>
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        (**self).create_group(input)
+    }
     fn create_identity_provider(
         &self,
         input: CreateIdentityProviderRequest,
-    ) -> RusotoFuture<CreateIdentityProviderResponse, CreateIdentityProviderError>;
-
-    /// <p>Creates a new OAuth2.0 resource server and defines custom scopes in it.</p>
+    ) -> RusotoFuture<CreateIdentityProviderResponse, CreateIdentityProviderError> {
+        (**self).create_identity_provider(input)
+    }
     fn create_resource_server(
         &self,
         input: CreateResourceServerRequest,
-    ) -> RusotoFuture<CreateResourceServerResponse, CreateResourceServerError>;
-
-    /// <p>Creates the user import job.</p>
+    ) -> RusotoFuture<CreateResourceServerResponse, CreateResourceServerError> {
+        (**self).create_resource_server(input)
+    }
     fn create_user_import_job(
         &self,
         input: CreateUserImportJobRequest,
-    ) -> RusotoFuture<CreateUserImportJobResponse, CreateUserImportJobError>;
-
-    /// <p>Creates a new Amazon Cognito user pool and sets the password policy for the pool.</p>
+    ) -> RusotoFuture<CreateUserImportJobResponse, CreateUserImportJobError> {
+        (**self).create_user_import_job(input)
+    }
     fn create_user_pool(
         &self,
         input: CreateUserPoolRequest,
-    ) -> RusotoFuture<CreateUserPoolResponse, CreateUserPoolError>;
-
-    /// <p>Creates the user pool client.</p>
+    ) -> RusotoFuture<CreateUserPoolResponse, CreateUserPoolError> {
+        (**self).create_user_pool(input)
+    }
     fn create_user_pool_client(
         &self,
         input: CreateUserPoolClientRequest,
-    ) -> RusotoFuture<CreateUserPoolClientResponse, CreateUserPoolClientError>;
-
-    /// <p>Creates a new domain for a user pool.</p>
+    ) -> RusotoFuture<CreateUserPoolClientResponse, CreateUserPoolClientError> {
+        (**self).create_user_pool_client(input)
+    }
     fn create_user_pool_domain(
         &self,
         input: CreateUserPoolDomainRequest,
-    ) -> RusotoFuture<CreateUserPoolDomainResponse, CreateUserPoolDomainError>;
-
-    /// <p>Deletes a group. Currently only groups with no members can be deleted.</p> <p>Requires developer credentials.</p>
-    fn delete_group(&self, input: DeleteGroupRequest) -> RusotoFuture<(), DeleteGroupError>;
-
-    /// <p>Deletes an identity provider for a user pool.</p>
+    ) -> RusotoFuture<CreateUserPoolDomainResponse, CreateUserPoolDomainError> {
+        (**self).create_user_pool_domain(input)
+    }
+    fn delete_group(&self, input: DeleteGroupRequest) -> RusotoFuture<(), DeleteGroupError> {
+        (**self).delete_group(input)
+    }
     fn delete_identity_provider(
         &self,
         input: DeleteIdentityProviderRequest,
-    ) -> RusotoFuture<(), DeleteIdentityProviderError>;
-
-    /// <p>Deletes a resource server.</p>
+    ) -> RusotoFuture<(), DeleteIdentityProviderError> {
+        (**self).delete_identity_provider(input)
+    }
     fn delete_resource_server(
         &self,
         input: DeleteResourceServerRequest,
-    ) -> RusotoFuture<(), DeleteResourceServerError>;
-
-    /// <p>Allows a user to delete himself or herself.</p>
-    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError>;
-
-    /// <p>Deletes the attributes for a user.</p>
+    ) -> RusotoFuture<(), DeleteResourceServerError> {
+        (**self).delete_resource_server(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
     fn delete_user_attributes(
         &self,
         input: DeleteUserAttributesRequest,
-    ) -> RusotoFuture<DeleteUserAttributesResponse, DeleteUserAttributesError>;
-
-    /// <p>Deletes the specified Amazon Cognito user pool.</p>
+    ) -> RusotoFuture<DeleteUserAttributesResponse, DeleteUserAttributesError> {
+        (**self).delete_user_attributes(input)
+    }
     fn delete_user_pool(
         &self,
         input: DeleteUserPoolRequest,
-    ) -> RusotoFuture<(), DeleteUserPoolError>;
-
-    /// <p>Allows the developer to delete the user pool client.</p>
+    ) -> RusotoFuture<(), DeleteUserPoolError> {
+        (**self).delete_user_pool(input)
+    }
     fn delete_user_pool_client(
         &self,
         input: DeleteUserPoolClientRequest,
-    ) -> RusotoFuture<(), DeleteUserPoolClientError>;
-
-    /// <p>Deletes a domain for a user pool.</p>
+    ) -> RusotoFuture<(), DeleteUserPoolClientError> {
+        (**self).delete_user_pool_client(input)
+    }
     fn delete_user_pool_domain(
         &self,
         input: DeleteUserPoolDomainRequest,
-    ) -> RusotoFuture<DeleteUserPoolDomainResponse, DeleteUserPoolDomainError>;
-
-    /// <p>Gets information about a specific identity provider.</p>
+    ) -> RusotoFuture<DeleteUserPoolDomainResponse, DeleteUserPoolDomainError> {
+        (**self).delete_user_pool_domain(input)
+    }
     fn describe_identity_provider(
         &self,
         input: DescribeIdentityProviderRequest,
-    ) -> RusotoFuture<DescribeIdentityProviderResponse, DescribeIdentityProviderError>;
-
-    /// <p>Describes a resource server.</p>
+    ) -> RusotoFuture<DescribeIdentityProviderResponse, DescribeIdentityProviderError> {
+        (**self).describe_identity_provider(input)
+    }
     fn describe_resource_server(
         &self,
         input: DescribeResourceServerRequest,
-    ) -> RusotoFuture<DescribeResourceServerResponse, DescribeResourceServerError>;
-
-    /// <p>Describes the risk configuration.</p>
+    ) -> RusotoFuture<DescribeResourceServerResponse, DescribeResourceServerError> {
+        (**self).describe_resource_server(input)
+    }
     fn describe_risk_configuration(
         &self,
         input: DescribeRiskConfigurationRequest,
-    ) -> RusotoFuture<DescribeRiskConfigurationResponse, DescribeRiskConfigurationError>;
-
-    /// <p>Describes the user import job.</p>
+    ) -> RusotoFuture<DescribeRiskConfigurationResponse, DescribeRiskConfigurationError> {
+        (**self).describe_risk_configuration(input)
+    }
     fn describe_user_import_job(
         &self,
         input: DescribeUserImportJobRequest,
-    ) -> RusotoFuture<DescribeUserImportJobResponse, DescribeUserImportJobError>;
-
-    /// <p>Returns the configuration information and metadata of the specified user pool.</p>
+    ) -> RusotoFuture<DescribeUserImportJobResponse, DescribeUserImportJobError> {
+        (**self).describe_user_import_job(input)
+    }
     fn describe_user_pool(
         &self,
         input: DescribeUserPoolRequest,
-    ) -> RusotoFuture<DescribeUserPoolResponse, DescribeUserPoolError>;
-
-    /// <p>Client method for returning the configuration information and metadata of the specified user pool app client.</p>
+    ) -> RusotoFuture<DescribeUserPoolResponse, DescribeUserPoolError> {
+        (**self).describe_user_pool(input)
+    }
     fn describe_user_pool_client(
         &self,
         input: DescribeUserPoolClientRequest,
-    ) -> RusotoFuture<DescribeUserPoolClientResponse, DescribeUserPoolClientError>;
-
-    /// <p>Gets information about a domain.</p>
+    ) -> RusotoFuture<DescribeUserPoolClientResponse, DescribeUserPoolClientError> {
+        (**self).describe_user_pool_client(input)
+    }
     fn describe_user_pool_domain(
         &self,
         input: DescribeUserPoolDomainRequest,
-    ) -> RusotoFuture<DescribeUserPoolDomainResponse, DescribeUserPoolDomainError>;
-
-    /// <p>Forgets the specified device.</p>
-    fn forget_device(&self, input: ForgetDeviceRequest) -> RusotoFuture<(), ForgetDeviceError>;
-
-    /// <p>Calling this API causes a message to be sent to the end user with a confirmation code that is required to change the user's password. For the <code>Username</code> parameter, you can use the username or user alias. If a verified phone number exists for the user, the confirmation code is sent to the phone number. Otherwise, if a verified email exists, the confirmation code is sent to the email. If neither a verified phone number nor a verified email exists, <code>InvalidParameterException</code> is thrown. To use the confirmation code for resetting the password, call .</p>
+    ) -> RusotoFuture<DescribeUserPoolDomainResponse, DescribeUserPoolDomainError> {
+        (**self).describe_user_pool_domain(input)
+    }
+    fn forget_device(&self, input: ForgetDeviceRequest) -> RusotoFuture<(), ForgetDeviceError> {
+        (**self).forget_device(input)
+    }
     fn forgot_password(
         &self,
         input: ForgotPasswordRequest,
-    ) -> RusotoFuture<ForgotPasswordResponse, ForgotPasswordError>;
-
-    /// <p>Gets the header information for the .csv file to be used as input for the user import job.</p>
+    ) -> RusotoFuture<ForgotPasswordResponse, ForgotPasswordError> {
+        (**self).forgot_password(input)
+    }
     fn get_csv_header(
         &self,
         input: GetCSVHeaderRequest,
-    ) -> RusotoFuture<GetCSVHeaderResponse, GetCSVHeaderError>;
-
-    /// <p>Gets the device.</p>
+    ) -> RusotoFuture<GetCSVHeaderResponse, GetCSVHeaderError> {
+        (**self).get_csv_header(input)
+    }
     fn get_device(
         &self,
         input: GetDeviceRequest,
-    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError>;
-
-    /// <p>Gets a group.</p> <p>Requires developer credentials.</p>
-    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError>;
-
-    /// <p>Gets the specified identity provider.</p>
+    ) -> RusotoFuture<GetDeviceResponse, GetDeviceError> {
+        (**self).get_device(input)
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
+        (**self).get_group(input)
+    }
     fn get_identity_provider_by_identifier(
         &self,
         input: GetIdentityProviderByIdentifierRequest,
-    ) -> RusotoFuture<GetIdentityProviderByIdentifierResponse, GetIdentityProviderByIdentifierError>;
-
-    /// <p>This method takes a user pool ID, and returns the signing certificate.</p>
+    ) -> RusotoFuture<GetIdentityProviderByIdentifierResponse, GetIdentityProviderByIdentifierError>
+    {
+        (**self).get_identity_provider_by_identifier(input)
+    }
     fn get_signing_certificate(
         &self,
         input: GetSigningCertificateRequest,
-    ) -> RusotoFuture<GetSigningCertificateResponse, GetSigningCertificateError>;
-
-    /// <p>Gets the UI Customization information for a particular app client's app UI, if there is something set. If nothing is set for the particular client, but there is an existing pool level customization (app <code>clientId</code> will be <code>ALL</code>), then that is returned. If nothing is present, then an empty shape is returned.</p>
+    ) -> RusotoFuture<GetSigningCertificateResponse, GetSigningCertificateError> {
+        (**self).get_signing_certificate(input)
+    }
     fn get_ui_customization(
         &self,
         input: GetUICustomizationRequest,
-    ) -> RusotoFuture<GetUICustomizationResponse, GetUICustomizationError>;
-
-    /// <p>Gets the user attributes and metadata for a user.</p>
-    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError>;
-
-    /// <p>Gets the user attribute verification code for the specified attribute name.</p>
+    ) -> RusotoFuture<GetUICustomizationResponse, GetUICustomizationError> {
+        (**self).get_ui_customization(input)
+    }
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
+        (**self).get_user(input)
+    }
     fn get_user_attribute_verification_code(
         &self,
         input: GetUserAttributeVerificationCodeRequest,
-    ) -> RusotoFuture<GetUserAttributeVerificationCodeResponse, GetUserAttributeVerificationCodeError>;
-
-    /// <p>Gets the user pool multi-factor authentication (MFA) configuration.</p>
+    ) -> RusotoFuture<GetUserAttributeVerificationCodeResponse, GetUserAttributeVerificationCodeError>
+    {
+        (**self).get_user_attribute_verification_code(input)
+    }
     fn get_user_pool_mfa_config(
         &self,
         input: GetUserPoolMfaConfigRequest,
-    ) -> RusotoFuture<GetUserPoolMfaConfigResponse, GetUserPoolMfaConfigError>;
-
-    /// <p>Signs out users from all devices.</p>
+    ) -> RusotoFuture<GetUserPoolMfaConfigResponse, GetUserPoolMfaConfigError> {
+        (**self).get_user_pool_mfa_config(input)
+    }
     fn global_sign_out(
         &self,
         input: GlobalSignOutRequest,
-    ) -> RusotoFuture<GlobalSignOutResponse, GlobalSignOutError>;
-
-    /// <p>Initiates the authentication flow.</p>
+    ) -> RusotoFuture<GlobalSignOutResponse, GlobalSignOutError> {
+        (**self).global_sign_out(input)
+    }
     fn initiate_auth(
         &self,
         input: InitiateAuthRequest,
-    ) -> RusotoFuture<InitiateAuthResponse, InitiateAuthError>;
-
-    /// <p>Lists the devices.</p>
+    ) -> RusotoFuture<InitiateAuthResponse, InitiateAuthError> {
+        (**self).initiate_auth(input)
+    }
     fn list_devices(
         &self,
         input: ListDevicesRequest,
-    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError>;
-
-    /// <p>Lists the groups associated with a user pool.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        (**self).list_devices(input)
+    }
     fn list_groups(
         &self,
         input: ListGroupsRequest,
-    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError>;
-
-    /// <p>Lists information about all identity providers for a user pool.</p>
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        (**self).list_groups(input)
+    }
     fn list_identity_providers(
         &self,
         input: ListIdentityProvidersRequest,
-    ) -> RusotoFuture<ListIdentityProvidersResponse, ListIdentityProvidersError>;
-
-    /// <p>Lists the resource servers for a user pool.</p>
+    ) -> RusotoFuture<ListIdentityProvidersResponse, ListIdentityProvidersError> {
+        (**self).list_identity_providers(input)
+    }
     fn list_resource_servers(
         &self,
         input: ListResourceServersRequest,
-    ) -> RusotoFuture<ListResourceServersResponse, ListResourceServersError>;
-
-    /// <p>Lists the tags that are assigned to an Amazon Cognito user pool.</p> <p>A tag is a label that you can apply to user pools to categorize and manage them in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>You can use this action up to 10 times per second, per account.</p>
+    ) -> RusotoFuture<ListResourceServersResponse, ListResourceServersError> {
+        (**self).list_resource_servers(input)
+    }
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
-    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
-
-    /// <p>Lists the user import jobs.</p>
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
     fn list_user_import_jobs(
         &self,
         input: ListUserImportJobsRequest,
-    ) -> RusotoFuture<ListUserImportJobsResponse, ListUserImportJobsError>;
-
-    /// <p>Lists the clients that have been created for the specified user pool.</p>
+    ) -> RusotoFuture<ListUserImportJobsResponse, ListUserImportJobsError> {
+        (**self).list_user_import_jobs(input)
+    }
     fn list_user_pool_clients(
         &self,
         input: ListUserPoolClientsRequest,
-    ) -> RusotoFuture<ListUserPoolClientsResponse, ListUserPoolClientsError>;
-
-    /// <p>Lists the user pools associated with an AWS account.</p>
+    ) -> RusotoFuture<ListUserPoolClientsResponse, ListUserPoolClientsError> {
+        (**self).list_user_pool_clients(input)
+    }
     fn list_user_pools(
         &self,
         input: ListUserPoolsRequest,
-    ) -> RusotoFuture<ListUserPoolsResponse, ListUserPoolsError>;
-
-    /// <p>Lists the users in the Amazon Cognito user pool.</p>
+    ) -> RusotoFuture<ListUserPoolsResponse, ListUserPoolsError> {
+        (**self).list_user_pools(input)
+    }
     fn list_users(
         &self,
         input: ListUsersRequest,
-    ) -> RusotoFuture<ListUsersResponse, ListUsersError>;
-
-    /// <p>Lists the users in the specified group.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
     fn list_users_in_group(
         &self,
         input: ListUsersInGroupRequest,
-    ) -> RusotoFuture<ListUsersInGroupResponse, ListUsersInGroupError>;
-
-    /// <p>Resends the confirmation (for confirmation of registration) to a specific user in the user pool.</p>
+    ) -> RusotoFuture<ListUsersInGroupResponse, ListUsersInGroupError> {
+        (**self).list_users_in_group(input)
+    }
     fn resend_confirmation_code(
         &self,
         input: ResendConfirmationCodeRequest,
-    ) -> RusotoFuture<ResendConfirmationCodeResponse, ResendConfirmationCodeError>;
-
-    /// <p>Responds to the authentication challenge.</p>
+    ) -> RusotoFuture<ResendConfirmationCodeResponse, ResendConfirmationCodeError> {
+        (**self).resend_confirmation_code(input)
+    }
     fn respond_to_auth_challenge(
         &self,
         input: RespondToAuthChallengeRequest,
-    ) -> RusotoFuture<RespondToAuthChallengeResponse, RespondToAuthChallengeError>;
-
-    /// <p>Configures actions on detected risks. To delete the risk configuration for <code>UserPoolId</code> or <code>ClientId</code>, pass null values for all four configuration types.</p> <p>To enable Amazon Cognito advanced security features, update the user pool to include the <code>UserPoolAddOns</code> key<code>AdvancedSecurityMode</code>.</p> <p>See .</p>
+    ) -> RusotoFuture<RespondToAuthChallengeResponse, RespondToAuthChallengeError> {
+        (**self).respond_to_auth_challenge(input)
+    }
     fn set_risk_configuration(
         &self,
         input: SetRiskConfigurationRequest,
-    ) -> RusotoFuture<SetRiskConfigurationResponse, SetRiskConfigurationError>;
-
-    /// <p><p>Sets the UI customization information for a user pool&#39;s built-in app UI.</p> <p>You can specify app UI customization settings for a single client (with a specific <code>clientId</code>) or for all clients (by setting the <code>clientId</code> to <code>ALL</code>). If you specify <code>ALL</code>, the default configuration will be used for every client that has no UI customization set previously. If you specify UI customization settings for a particular client, it will no longer fall back to the <code>ALL</code> configuration. </p> <note> <p>To use this API, your user pool must have a domain associated with it. Otherwise, there is no place to host the app&#39;s pages, and the service will throw an error.</p> </note></p>
+    ) -> RusotoFuture<SetRiskConfigurationResponse, SetRiskConfigurationError> {
+        (**self).set_risk_configuration(input)
+    }
     fn set_ui_customization(
         &self,
         input: SetUICustomizationRequest,
-    ) -> RusotoFuture<SetUICustomizationResponse, SetUICustomizationError>;
-
-    /// <p>Set the user's multi-factor authentication (MFA) method preference.</p>
+    ) -> RusotoFuture<SetUICustomizationResponse, SetUICustomizationError> {
+        (**self).set_ui_customization(input)
+    }
     fn set_user_mfa_preference(
         &self,
         input: SetUserMFAPreferenceRequest,
-    ) -> RusotoFuture<SetUserMFAPreferenceResponse, SetUserMFAPreferenceError>;
-
-    /// <p>Set the user pool MFA configuration.</p>
+    ) -> RusotoFuture<SetUserMFAPreferenceResponse, SetUserMFAPreferenceError> {
+        (**self).set_user_mfa_preference(input)
+    }
     fn set_user_pool_mfa_config(
         &self,
         input: SetUserPoolMfaConfigRequest,
-    ) -> RusotoFuture<SetUserPoolMfaConfigResponse, SetUserPoolMfaConfigError>;
-
-    /// <p>Sets the user settings like multi-factor authentication (MFA). If MFA is to be removed for a particular attribute pass the attribute with code delivery as null. If null list is passed, all MFA options are removed.</p>
+    ) -> RusotoFuture<SetUserPoolMfaConfigResponse, SetUserPoolMfaConfigError> {
+        (**self).set_user_pool_mfa_config(input)
+    }
     fn set_user_settings(
         &self,
         input: SetUserSettingsRequest,
-    ) -> RusotoFuture<SetUserSettingsResponse, SetUserSettingsError>;
-
-    /// <p>Registers the user in the specified user pool and creates a user name, password, and user attributes.</p>
-    fn sign_up(&self, input: SignUpRequest) -> RusotoFuture<SignUpResponse, SignUpError>;
-
-    /// <p>Starts the user import.</p>
+    ) -> RusotoFuture<SetUserSettingsResponse, SetUserSettingsError> {
+        (**self).set_user_settings(input)
+    }
+    fn sign_up(&self, input: SignUpRequest) -> RusotoFuture<SignUpResponse, SignUpError> {
+        (**self).sign_up(input)
+    }
     fn start_user_import_job(
         &self,
         input: StartUserImportJobRequest,
-    ) -> RusotoFuture<StartUserImportJobResponse, StartUserImportJobError>;
-
-    /// <p>Stops the user import job.</p>
+    ) -> RusotoFuture<StartUserImportJobResponse, StartUserImportJobError> {
+        (**self).start_user_import_job(input)
+    }
     fn stop_user_import_job(
         &self,
         input: StopUserImportJobRequest,
-    ) -> RusotoFuture<StopUserImportJobResponse, StopUserImportJobError>;
-
-    /// <p>Assigns a set of tags to an Amazon Cognito user pool. A tag is a label that you can use to categorize and manage user pools in different ways, such as by purpose, owner, environment, or other criteria.</p> <p>Each tag consists of a key and value, both of which you define. A key is a general category for more specific values. For example, if you have two versions of a user pool, one for testing and another for production, you might assign an <code>Environment</code> tag key to both user pools. The value of this key might be <code>Test</code> for one user pool and <code>Production</code> for the other.</p> <p>Tags are useful for cost tracking and access control. You can activate your tags so that they appear on the Billing and Cost Management console, where you can track the costs associated with your user pools. In an IAM policy, you can constrain permissions for user pools based on specific tags or tag values.</p> <p>You can use this action up to 5 times per second, per account. A user pool can have as many as 50 tags.</p>
+    ) -> RusotoFuture<StopUserImportJobResponse, StopUserImportJobError> {
+        (**self).stop_user_import_job(input)
+    }
     fn tag_resource(
         &self,
         input: TagResourceRequest,
-    ) -> RusotoFuture<TagResourceResponse, TagResourceError>;
-
-    /// <p>Removes the specified tags from an Amazon Cognito user pool. You can use this action up to 5 times per second, per account</p>
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
-    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
-
-    /// <p>Provides the feedback for an authentication event whether it was from a valid user or not. This feedback is used for improving the risk evaluation decision for the user pool as part of Amazon Cognito advanced security.</p>
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
     fn update_auth_event_feedback(
         &self,
         input: UpdateAuthEventFeedbackRequest,
-    ) -> RusotoFuture<UpdateAuthEventFeedbackResponse, UpdateAuthEventFeedbackError>;
-
-    /// <p>Updates the device status.</p>
+    ) -> RusotoFuture<UpdateAuthEventFeedbackResponse, UpdateAuthEventFeedbackError> {
+        (**self).update_auth_event_feedback(input)
+    }
     fn update_device_status(
         &self,
         input: UpdateDeviceStatusRequest,
-    ) -> RusotoFuture<UpdateDeviceStatusResponse, UpdateDeviceStatusError>;
-
-    /// <p>Updates the specified group with the specified attributes.</p> <p>Requires developer credentials.</p>
+    ) -> RusotoFuture<UpdateDeviceStatusResponse, UpdateDeviceStatusError> {
+        (**self).update_device_status(input)
+    }
     fn update_group(
         &self,
         input: UpdateGroupRequest,
-    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError>;
-
-    /// <p>Updates identity provider information for a user pool.</p>
+    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError> {
+        (**self).update_group(input)
+    }
     fn update_identity_provider(
         &self,
         input: UpdateIdentityProviderRequest,
-    ) -> RusotoFuture<UpdateIdentityProviderResponse, UpdateIdentityProviderError>;
-
-    /// <p>Updates the name and scopes of resource server. All other fields are read-only.</p>
+    ) -> RusotoFuture<UpdateIdentityProviderResponse, UpdateIdentityProviderError> {
+        (**self).update_identity_provider(input)
+    }
     fn update_resource_server(
         &self,
         input: UpdateResourceServerRequest,
-    ) -> RusotoFuture<UpdateResourceServerResponse, UpdateResourceServerError>;
-
-    /// <p>Allows a user to update a specific attribute (one at a time).</p>
+    ) -> RusotoFuture<UpdateResourceServerResponse, UpdateResourceServerError> {
+        (**self).update_resource_server(input)
+    }
     fn update_user_attributes(
         &self,
         input: UpdateUserAttributesRequest,
-    ) -> RusotoFuture<UpdateUserAttributesResponse, UpdateUserAttributesError>;
-
-    /// <p>Updates the specified user pool with the specified attributes. If you don't provide a value for an attribute, it will be set to the default value. You can get a list of the current user pool settings with .</p>
+    ) -> RusotoFuture<UpdateUserAttributesResponse, UpdateUserAttributesError> {
+        (**self).update_user_attributes(input)
+    }
     fn update_user_pool(
         &self,
         input: UpdateUserPoolRequest,
-    ) -> RusotoFuture<UpdateUserPoolResponse, UpdateUserPoolError>;
-
-    /// <p>Updates the specified user pool app client with the specified attributes. If you don't provide a value for an attribute, it will be set to the default value. You can get a list of the current user pool app client settings with .</p>
+    ) -> RusotoFuture<UpdateUserPoolResponse, UpdateUserPoolError> {
+        (**self).update_user_pool(input)
+    }
     fn update_user_pool_client(
         &self,
         input: UpdateUserPoolClientRequest,
-    ) -> RusotoFuture<UpdateUserPoolClientResponse, UpdateUserPoolClientError>;
-
-    /// <p>Updates the Secure Sockets Layer (SSL) certificate for the custom domain for your user pool.</p> <p>You can use this operation to provide the Amazon Resource Name (ARN) of a new certificate to Amazon Cognito. You cannot use it to change the domain for a user pool.</p> <p>A custom domain is used to host the Amazon Cognito hosted UI, which provides sign-up and sign-in pages for your application. When you set up a custom domain, you provide a certificate that you manage with AWS Certificate Manager (ACM). When necessary, you can use this operation to change the certificate that you applied to your custom domain.</p> <p>Usually, this is unnecessary following routine certificate renewal with ACM. When you renew your existing certificate in ACM, the ARN for your certificate remains the same, and your custom domain uses the new certificate automatically.</p> <p>However, if you replace your existing certificate with a new one, ACM gives the new certificate a new ARN. To apply the new certificate to your custom domain, you must provide this ARN to Amazon Cognito.</p> <p>When you add your new certificate in ACM, you must choose US East (N. Virginia) as the AWS Region.</p> <p>After you submit your request, Amazon Cognito requires up to 1 hour to distribute your new certificate to your custom domain.</p> <p>For more information about adding a custom domain to your user pool, see <a href="https://docs.aws.amazon.com/cognito/latest/developerguide/cognito-user-pools-add-custom-domain.html">Using Your Own Domain for the Hosted UI</a>.</p>
+    ) -> RusotoFuture<UpdateUserPoolClientResponse, UpdateUserPoolClientError> {
+        (**self).update_user_pool_client(input)
+    }
     fn update_user_pool_domain(
         &self,
         input: UpdateUserPoolDomainRequest,
-    ) -> RusotoFuture<UpdateUserPoolDomainResponse, UpdateUserPoolDomainError>;
-
-    /// <p>Use this API to register a user's entered TOTP code and mark the user's software token MFA status as "verified" if successful. The request takes an access token or a session string, but not both.</p>
+    ) -> RusotoFuture<UpdateUserPoolDomainResponse, UpdateUserPoolDomainError> {
+        (**self).update_user_pool_domain(input)
+    }
     fn verify_software_token(
         &self,
         input: VerifySoftwareTokenRequest,
-    ) -> RusotoFuture<VerifySoftwareTokenResponse, VerifySoftwareTokenError>;
-
-    /// <p>Verifies the specified user attributes in the user pool.</p>
+    ) -> RusotoFuture<VerifySoftwareTokenResponse, VerifySoftwareTokenError> {
+        (**self).verify_software_token(input)
+    }
     fn verify_user_attribute(
         &self,
         input: VerifyUserAttributeRequest,
-    ) -> RusotoFuture<VerifyUserAttributeResponse, VerifyUserAttributeError>;
+    ) -> RusotoFuture<VerifyUserAttributeResponse, VerifyUserAttributeError> {
+        (**self).verify_user_attribute(input)
+    }
 }
+/// The error codes Amazon Cognito Identity Provider retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the Amazon Cognito Identity Provider API.
 #[derive(Clone)]
 pub struct CognitoIdentityProviderClient {
@@ -12572,6 +21177,27 @@ impl CognitoIdentityProviderClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_COGNITO_IDP`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CognitoIdentityProviderClient {
+        Self::new(region::Region::from_env_for_service("COGNITO_IDP"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `cognito-idp`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CognitoIdentityProviderClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("cognito-idp", "COGNITO_IDP"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -12593,7 +21219,54 @@ impl CognitoIdentityProviderClient {
         client: Client,
         region: region::Region,
     ) -> CognitoIdentityProviderClient {
-        CognitoIdentityProviderClient { client, region }
+        CognitoIdentityProviderClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "cognito-idp")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "cognito-idp",
+            "cognito-idp",
+            "COGNITO_IDP",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CognitoIdentityProvider` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CognitoIdentityProvider + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CognitoIdentityProviderClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Cognito Identity Provider"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "cognito-idp"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CognitoIdentityProviderClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Cognito Sync clients.
+//!
+//! Makes a single read-only `ListIdentityPoolUsage` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_cognito_sync::{CognitoSync, CognitoSyncClient, ListIdentityPoolUsageRequest};
+
+fn main() {
+    let client = CognitoSyncClient::from_env();
+    match client
+        .list_identity_pool_usage(ListIdentityPoolUsageRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListIdentityPoolUsage succeeded"),
+        Err(error) => {
+            eprintln!("ListIdentityPoolUsage failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
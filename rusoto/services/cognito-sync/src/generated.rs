@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>The input for the BulkPublish operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -32,6 +33,36 @@ pub struct BulkPublishRequest {
     pub identity_pool_id: String,
 }
 
+impl BulkPublishRequest {
+    /// Returns a builder for `BulkPublishRequest`, checking its required members when built.
+    pub fn builder() -> BulkPublishRequestBuilder {
+        BulkPublishRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BulkPublishRequest`](struct.BulkPublishRequest.html).
+#[derive(Default, Debug)]
+pub struct BulkPublishRequestBuilder {
+    identity_pool_id: Option<String>,
+}
+
+impl BulkPublishRequestBuilder {
+    /// Sets the [`identity_pool_id`](struct.BulkPublishRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `BulkPublishRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BulkPublishRequest, ::rusoto_core::BuildError> {
+        Ok(BulkPublishRequest {
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>The output for the BulkPublish operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -42,6 +73,92 @@ pub struct BulkPublishResponse {
     pub identity_pool_id: Option<String>,
 }
 
+/// <p>Status of the last bulk publish operation, valid values are: <p>NOT<em>STARTED - No bulk publish has been requested for this identity pool</p> <p>IN</em>PROGRESS - Data is being published to the configured stream</p> <p>SUCCEEDED - All data for the identity pool has been published to the configured stream</p> <p>FAILED - Some portion of the data has failed to publish, check FailureMessage for the cause.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BulkPublishStatus {
+    /// <p><code>NOT_STARTED</code></p>
+    NotStarted,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BulkPublishStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BulkPublishStatus::NotStarted => "NOT_STARTED",
+            BulkPublishStatus::InProgress => "IN_PROGRESS",
+            BulkPublishStatus::Failed => "FAILED",
+            BulkPublishStatus::Succeeded => "SUCCEEDED",
+            BulkPublishStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BulkPublishStatus {
+    fn from(value: &'a str) -> BulkPublishStatus {
+        match value {
+            "NOT_STARTED" => BulkPublishStatus::NotStarted,
+            "IN_PROGRESS" => BulkPublishStatus::InProgress,
+            "FAILED" => BulkPublishStatus::Failed,
+            "SUCCEEDED" => BulkPublishStatus::Succeeded,
+            _ => BulkPublishStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BulkPublishStatus {
+    fn from(value: String) -> BulkPublishStatus {
+        BulkPublishStatus::from(value.as_str())
+    }
+}
+
+impl From<BulkPublishStatus> for String {
+    fn from(value: BulkPublishStatus) -> String {
+        match value {
+            BulkPublishStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BulkPublishStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BulkPublishStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BulkPublishStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BulkPublishStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BulkPublishStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BulkPublishStatus::from(value))
+    }
+}
+
 /// <p>Configuration options for configure Cognito streams.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CognitoStreams {
@@ -56,7 +173,7 @@ pub struct CognitoStreams {
     /// <p>Status of the Cognito streams. Valid values are: <p>ENABLED - Streaming of updates to identity pool is enabled.</p> <p>DISABLED - Streaming of updates to identity pool is disabled. Bulk publish will also fail if StreamingStatus is DISABLED.</p></p>
     #[serde(rename = "StreamingStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub streaming_status: Option<String>,
+    pub streaming_status: Option<StreamingStatus>,
 }
 
 /// <p>A collection of data for an identity pool. An identity pool can have multiple datasets. A dataset is per identity and can be general or associated with a particular entity in an application (like a saved game). Datasets are automatically created if they don&#39;t exist. Data is synced by dataset, and a dataset can hold up to 1MB of key-value pairs.</p>
@@ -65,8 +182,9 @@ pub struct CognitoStreams {
 pub struct Dataset {
     /// <p>Date on which the dataset was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>Total size in bytes of the records in this dataset.</p>
     #[serde(rename = "DataStorage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -85,8 +203,9 @@ pub struct Dataset {
     pub last_modified_by: Option<String>,
     /// <p>Date when the dataset was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>Number of records in this dataset.</p>
     #[serde(rename = "NumRecords")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -107,6 +226,56 @@ pub struct DeleteDatasetRequest {
     pub identity_pool_id: String,
 }
 
+impl DeleteDatasetRequest {
+    /// Returns a builder for `DeleteDatasetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDatasetRequestBuilder {
+        DeleteDatasetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDatasetRequest`](struct.DeleteDatasetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDatasetRequestBuilder {
+    dataset_name: Option<String>,
+    identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+}
+
+impl DeleteDatasetRequestBuilder {
+    /// Sets the [`dataset_name`](struct.DeleteDatasetRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_id`](struct.DeleteDatasetRequest.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.DeleteDatasetRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDatasetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDatasetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDatasetRequest {
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Response to a successful DeleteDataset request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -131,6 +300,56 @@ pub struct DescribeDatasetRequest {
     pub identity_pool_id: String,
 }
 
+impl DescribeDatasetRequest {
+    /// Returns a builder for `DescribeDatasetRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDatasetRequestBuilder {
+        DescribeDatasetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDatasetRequest`](struct.DescribeDatasetRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDatasetRequestBuilder {
+    dataset_name: Option<String>,
+    identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+}
+
+impl DescribeDatasetRequestBuilder {
+    /// Sets the [`dataset_name`](struct.DescribeDatasetRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_id`](struct.DescribeDatasetRequest.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.DescribeDatasetRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDatasetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeDatasetRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDatasetRequest {
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Response to a successful DescribeDataset request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -149,6 +368,38 @@ pub struct DescribeIdentityPoolUsageRequest {
     pub identity_pool_id: String,
 }
 
+impl DescribeIdentityPoolUsageRequest {
+    /// Returns a builder for `DescribeIdentityPoolUsageRequest`, checking its required members when built.
+    pub fn builder() -> DescribeIdentityPoolUsageRequestBuilder {
+        DescribeIdentityPoolUsageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeIdentityPoolUsageRequest`](struct.DescribeIdentityPoolUsageRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeIdentityPoolUsageRequestBuilder {
+    identity_pool_id: Option<String>,
+}
+
+impl DescribeIdentityPoolUsageRequestBuilder {
+    /// Sets the [`identity_pool_id`](struct.DescribeIdentityPoolUsageRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeIdentityPoolUsageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeIdentityPoolUsageRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeIdentityPoolUsageRequest {
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Response to a successful DescribeIdentityPoolUsage request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -170,6 +421,48 @@ pub struct DescribeIdentityUsageRequest {
     pub identity_pool_id: String,
 }
 
+impl DescribeIdentityUsageRequest {
+    /// Returns a builder for `DescribeIdentityUsageRequest`, checking its required members when built.
+    pub fn builder() -> DescribeIdentityUsageRequestBuilder {
+        DescribeIdentityUsageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeIdentityUsageRequest`](struct.DescribeIdentityUsageRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeIdentityUsageRequestBuilder {
+    identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+}
+
+impl DescribeIdentityUsageRequestBuilder {
+    /// Sets the [`identity_id`](struct.DescribeIdentityUsageRequest.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.DescribeIdentityUsageRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeIdentityUsageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeIdentityUsageRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeIdentityUsageRequest {
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>The response to a successful DescribeIdentityUsage request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -188,22 +481,56 @@ pub struct GetBulkPublishDetailsRequest {
     pub identity_pool_id: String,
 }
 
+impl GetBulkPublishDetailsRequest {
+    /// Returns a builder for `GetBulkPublishDetailsRequest`, checking its required members when built.
+    pub fn builder() -> GetBulkPublishDetailsRequestBuilder {
+        GetBulkPublishDetailsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBulkPublishDetailsRequest`](struct.GetBulkPublishDetailsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBulkPublishDetailsRequestBuilder {
+    identity_pool_id: Option<String>,
+}
+
+impl GetBulkPublishDetailsRequestBuilder {
+    /// Sets the [`identity_pool_id`](struct.GetBulkPublishDetailsRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBulkPublishDetailsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetBulkPublishDetailsRequest, ::rusoto_core::BuildError> {
+        Ok(GetBulkPublishDetailsRequest {
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>The output for the GetBulkPublishDetails operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBulkPublishDetailsResponse {
     /// <p>If BulkPublishStatus is SUCCEEDED, the time the last bulk publish operation completed.</p>
     #[serde(rename = "BulkPublishCompleteTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bulk_publish_complete_time: Option<f64>,
+    pub bulk_publish_complete_time: Option<EpochTimestamp>,
     /// <p>The date/time at which the last bulk publish was initiated.</p>
     #[serde(rename = "BulkPublishStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bulk_publish_start_time: Option<f64>,
+    pub bulk_publish_start_time: Option<EpochTimestamp>,
     /// <p>Status of the last bulk publish operation, valid values are: <p>NOT<em>STARTED - No bulk publish has been requested for this identity pool</p> <p>IN</em>PROGRESS - Data is being published to the configured stream</p> <p>SUCCEEDED - All data for the identity pool has been published to the configured stream</p> <p>FAILED - Some portion of the data has failed to publish, check FailureMessage for the cause.</p></p>
     #[serde(rename = "BulkPublishStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bulk_publish_status: Option<String>,
+    pub bulk_publish_status: Option<BulkPublishStatus>,
     /// <p>If BulkPublishStatus is FAILED this field will contain the error message that caused the bulk publish to fail.</p>
     #[serde(rename = "FailureMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -222,6 +549,38 @@ pub struct GetCognitoEventsRequest {
     pub identity_pool_id: String,
 }
 
+impl GetCognitoEventsRequest {
+    /// Returns a builder for `GetCognitoEventsRequest`, checking its required members when built.
+    pub fn builder() -> GetCognitoEventsRequestBuilder {
+        GetCognitoEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCognitoEventsRequest`](struct.GetCognitoEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCognitoEventsRequestBuilder {
+    identity_pool_id: Option<String>,
+}
+
+impl GetCognitoEventsRequestBuilder {
+    /// Sets the [`identity_pool_id`](struct.GetCognitoEventsRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCognitoEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCognitoEventsRequest, ::rusoto_core::BuildError> {
+        Ok(GetCognitoEventsRequest {
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>The response from the GetCognitoEvents request</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -240,6 +599,38 @@ pub struct GetIdentityPoolConfigurationRequest {
     pub identity_pool_id: String,
 }
 
+impl GetIdentityPoolConfigurationRequest {
+    /// Returns a builder for `GetIdentityPoolConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> GetIdentityPoolConfigurationRequestBuilder {
+        GetIdentityPoolConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIdentityPoolConfigurationRequest`](struct.GetIdentityPoolConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIdentityPoolConfigurationRequestBuilder {
+    identity_pool_id: Option<String>,
+}
+
+impl GetIdentityPoolConfigurationRequestBuilder {
+    /// Sets the [`identity_pool_id`](struct.GetIdentityPoolConfigurationRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIdentityPoolConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIdentityPoolConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(GetIdentityPoolConfigurationRequest {
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>The output for the GetIdentityPoolConfiguration operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -272,8 +663,9 @@ pub struct IdentityPoolUsage {
     pub identity_pool_id: Option<String>,
     /// <p>Date on which the identity pool was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>Number of sync sessions for the identity pool.</p>
     #[serde(rename = "SyncSessionsCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -302,8 +694,9 @@ pub struct IdentityUsage {
     pub identity_pool_id: Option<String>,
     /// <p>Date on which the identity was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
 }
 
 /// <p>Request for a list of datasets for an identity.</p>
@@ -325,6 +718,62 @@ pub struct ListDatasetsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDatasetsRequest {
+    /// Returns a builder for `ListDatasetsRequest`, checking its required members when built.
+    pub fn builder() -> ListDatasetsRequestBuilder {
+        ListDatasetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDatasetsRequest`](struct.ListDatasetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDatasetsRequestBuilder {
+    identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDatasetsRequestBuilder {
+    /// Sets the [`identity_id`](struct.ListDatasetsRequest.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.ListDatasetsRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDatasetsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDatasetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDatasetsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDatasetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDatasetsRequest {
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Returned for a successful ListDatasets request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -356,6 +805,44 @@ pub struct ListIdentityPoolUsageRequest {
     pub next_token: Option<String>,
 }
 
+impl ListIdentityPoolUsageRequest {
+    /// Returns a builder for `ListIdentityPoolUsageRequest`, checking its required members when built.
+    pub fn builder() -> ListIdentityPoolUsageRequestBuilder {
+        ListIdentityPoolUsageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListIdentityPoolUsageRequest`](struct.ListIdentityPoolUsageRequest.html).
+#[derive(Default, Debug)]
+pub struct ListIdentityPoolUsageRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListIdentityPoolUsageRequestBuilder {
+    /// Sets the [`max_results`](struct.ListIdentityPoolUsageRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListIdentityPoolUsageRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListIdentityPoolUsageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListIdentityPoolUsageRequest, ::rusoto_core::BuildError> {
+        Ok(ListIdentityPoolUsageRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Returned for a successful ListIdentityPoolUsage request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -408,6 +895,88 @@ pub struct ListRecordsRequest {
     pub sync_session_token: Option<String>,
 }
 
+impl ListRecordsRequest {
+    /// Returns a builder for `ListRecordsRequest`, checking its required members when built.
+    pub fn builder() -> ListRecordsRequestBuilder {
+        ListRecordsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRecordsRequest`](struct.ListRecordsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRecordsRequestBuilder {
+    dataset_name: Option<String>,
+    identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+    last_sync_count: Option<i64>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sync_session_token: Option<String>,
+}
+
+impl ListRecordsRequestBuilder {
+    /// Sets the [`dataset_name`](struct.ListRecordsRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_id`](struct.ListRecordsRequest.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.ListRecordsRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`last_sync_count`](struct.ListRecordsRequest.html#structfield.last_sync_count) member.
+    pub fn last_sync_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.last_sync_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListRecordsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListRecordsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sync_session_token`](struct.ListRecordsRequest.html#structfield.sync_session_token) member.
+    pub fn sync_session_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.sync_session_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRecordsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListRecordsRequest, ::rusoto_core::BuildError> {
+        Ok(ListRecordsRequest {
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+            last_sync_count: self.last_sync_count,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sync_session_token: self.sync_session_token,
+        })
+    }
+}
+
 /// <p>Returned for a successful ListRecordsRequest.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -450,6 +1019,170 @@ pub struct ListRecordsResponse {
     pub sync_session_token: Option<String>,
 }
 
+/// <p>An operation, either replace or remove.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Operation {
+    /// <p><code>replace</code></p>
+    Replace,
+    /// <p><code>remove</code></p>
+    Remove,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Operation {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Operation::Replace => "replace",
+            Operation::Remove => "remove",
+            Operation::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Operation {
+    fn from(value: &'a str) -> Operation {
+        match value {
+            "replace" => Operation::Replace,
+            "remove" => Operation::Remove,
+            _ => Operation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Operation {
+    fn from(value: String) -> Operation {
+        Operation::from(value.as_str())
+    }
+}
+
+impl From<Operation> for String {
+    fn from(value: Operation) -> String {
+        match value {
+            Operation::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Operation {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Operation {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Operation {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Operation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Operation::from(value))
+    }
+}
+
+/// <p>The SNS platform type (e.g. GCM, SDM, APNS, APNS_SANDBOX).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Platform {
+    /// <p><code>APNS</code></p>
+    Apns,
+    /// <p><code>APNS_SANDBOX</code></p>
+    ApnsSandbox,
+    /// <p><code>GCM</code></p>
+    Gcm,
+    /// <p><code>ADM</code></p>
+    Adm,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Platform {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Platform::Apns => "APNS",
+            Platform::ApnsSandbox => "APNS_SANDBOX",
+            Platform::Gcm => "GCM",
+            Platform::Adm => "ADM",
+            Platform::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Platform {
+    fn from(value: &'a str) -> Platform {
+        match value {
+            "APNS" => Platform::Apns,
+            "APNS_SANDBOX" => Platform::ApnsSandbox,
+            "GCM" => Platform::Gcm,
+            "ADM" => Platform::Adm,
+            _ => Platform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Platform {
+    fn from(value: String) -> Platform {
+        Platform::from(value.as_str())
+    }
+}
+
+impl From<Platform> for String {
+    fn from(value: Platform) -> String {
+        match value {
+            Platform::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Platform {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Platform {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Platform {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Platform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Platform::from(value))
+    }
+}
+
 /// <p>Configuration options to be applied to the identity pool.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PushSync {
@@ -469,8 +1202,9 @@ pub struct PushSync {
 pub struct Record {
     /// <p>The last modified date of the client device.</p>
     #[serde(rename = "DeviceLastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_last_modified_date: Option<f64>,
+    pub device_last_modified_date: Option<EpochTimestamp>,
     /// <p>The key for the record.</p>
     #[serde(rename = "Key")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -481,8 +1215,9 @@ pub struct Record {
     pub last_modified_by: Option<String>,
     /// <p>The date on which the record was last modified.</p>
     #[serde(rename = "LastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The server sync count for this record.</p>
     #[serde(rename = "SyncCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -494,18 +1229,19 @@ pub struct Record {
 }
 
 /// <p>An update operation for a record.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RecordPatch {
     /// <p>The last modified date of the client device.</p>
     #[serde(rename = "DeviceLastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_last_modified_date: Option<f64>,
+    pub device_last_modified_date: Option<EpochTimestamp>,
     /// <p>The key associated with the record patch.</p>
     #[serde(rename = "Key")]
     pub key: String,
     /// <p>An operation, either replace or remove.</p>
     #[serde(rename = "Op")]
-    pub op: String,
+    pub op: Operation,
     /// <p>Last known server sync count for this record. Set to 0 if unknown.</p>
     #[serde(rename = "SyncCount")]
     pub sync_count: i64,
@@ -516,7 +1252,7 @@ pub struct RecordPatch {
 }
 
 /// <p>A request to RegisterDevice.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RegisterDeviceRequest {
     /// <p>The unique ID for this identity.</p>
     #[serde(rename = "IdentityId")]
@@ -526,12 +1262,72 @@ pub struct RegisterDeviceRequest {
     pub identity_pool_id: String,
     /// <p>The SNS platform type (e.g. GCM, SDM, APNS, APNS_SANDBOX).</p>
     #[serde(rename = "Platform")]
-    pub platform: String,
+    pub platform: Platform,
     /// <p>The push token.</p>
     #[serde(rename = "Token")]
     pub token: String,
 }
 
+impl RegisterDeviceRequest {
+    /// Returns a builder for `RegisterDeviceRequest`, checking its required members when built.
+    pub fn builder() -> RegisterDeviceRequestBuilder {
+        RegisterDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterDeviceRequest`](struct.RegisterDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterDeviceRequestBuilder {
+    identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+    platform: Option<Platform>,
+    token: Option<String>,
+}
+
+impl RegisterDeviceRequestBuilder {
+    /// Sets the [`identity_id`](struct.RegisterDeviceRequest.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.RegisterDeviceRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform`](struct.RegisterDeviceRequest.html#structfield.platform) member.
+    pub fn platform<T: Into<Platform>>(mut self, value: T) -> Self {
+        self.platform = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token`](struct.RegisterDeviceRequest.html#structfield.token) member.
+    pub fn token<T: Into<String>>(mut self, value: T) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RegisterDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterDeviceRequest {
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+            platform: self
+                .platform
+                .ok_or(::rusoto_core::BuildError::missing("platform"))?,
+            token: self
+                .token
+                .ok_or(::rusoto_core::BuildError::missing("token"))?,
+        })
+    }
+}
+
 /// <p>Response to a RegisterDevice request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -553,6 +1349,51 @@ pub struct SetCognitoEventsRequest {
     pub identity_pool_id: String,
 }
 
+impl SetCognitoEventsRequest {
+    /// Returns a builder for `SetCognitoEventsRequest`, checking its required members when built.
+    pub fn builder() -> SetCognitoEventsRequestBuilder {
+        SetCognitoEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetCognitoEventsRequest`](struct.SetCognitoEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct SetCognitoEventsRequestBuilder {
+    events: Option<::std::collections::HashMap<String, String>>,
+    identity_pool_id: Option<String>,
+}
+
+impl SetCognitoEventsRequestBuilder {
+    /// Sets the [`events`](struct.SetCognitoEventsRequest.html#structfield.events) member.
+    pub fn events<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.events = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.SetCognitoEventsRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetCognitoEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetCognitoEventsRequest, ::rusoto_core::BuildError> {
+        Ok(SetCognitoEventsRequest {
+            events: self
+                .events
+                .ok_or(::rusoto_core::BuildError::missing("events"))?,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>The input for the SetIdentityPoolConfiguration operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SetIdentityPoolConfigurationRequest {
@@ -569,6 +1410,54 @@ pub struct SetIdentityPoolConfigurationRequest {
     pub push_sync: Option<PushSync>,
 }
 
+impl SetIdentityPoolConfigurationRequest {
+    /// Returns a builder for `SetIdentityPoolConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> SetIdentityPoolConfigurationRequestBuilder {
+        SetIdentityPoolConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetIdentityPoolConfigurationRequest`](struct.SetIdentityPoolConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct SetIdentityPoolConfigurationRequestBuilder {
+    cognito_streams: Option<CognitoStreams>,
+    identity_pool_id: Option<String>,
+    push_sync: Option<PushSync>,
+}
+
+impl SetIdentityPoolConfigurationRequestBuilder {
+    /// Sets the [`cognito_streams`](struct.SetIdentityPoolConfigurationRequest.html#structfield.cognito_streams) member.
+    pub fn cognito_streams<T: Into<CognitoStreams>>(mut self, value: T) -> Self {
+        self.cognito_streams = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.SetIdentityPoolConfigurationRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`push_sync`](struct.SetIdentityPoolConfigurationRequest.html#structfield.push_sync) member.
+    pub fn push_sync<T: Into<PushSync>>(mut self, value: T) -> Self {
+        self.push_sync = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetIdentityPoolConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetIdentityPoolConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(SetIdentityPoolConfigurationRequest {
+            cognito_streams: self.cognito_streams,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+            push_sync: self.push_sync,
+        })
+    }
+}
+
 /// <p>The output for the SetIdentityPoolConfiguration operation</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -587,6 +1476,84 @@ pub struct SetIdentityPoolConfigurationResponse {
     pub push_sync: Option<PushSync>,
 }
 
+/// <p>Status of the Cognito streams. Valid values are: <p>ENABLED - Streaming of updates to identity pool is enabled.</p> <p>DISABLED - Streaming of updates to identity pool is disabled. Bulk publish will also fail if StreamingStatus is DISABLED.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StreamingStatus {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StreamingStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamingStatus::Enabled => "ENABLED",
+            StreamingStatus::Disabled => "DISABLED",
+            StreamingStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StreamingStatus {
+    fn from(value: &'a str) -> StreamingStatus {
+        match value {
+            "ENABLED" => StreamingStatus::Enabled,
+            "DISABLED" => StreamingStatus::Disabled,
+            _ => StreamingStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamingStatus {
+    fn from(value: String) -> StreamingStatus {
+        StreamingStatus::from(value.as_str())
+    }
+}
+
+impl From<StreamingStatus> for String {
+    fn from(value: StreamingStatus) -> String {
+        match value {
+            StreamingStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StreamingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StreamingStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StreamingStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamingStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StreamingStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StreamingStatus::from(value))
+    }
+}
+
 /// <p>A request to SubscribeToDatasetRequest.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SubscribeToDatasetRequest {
@@ -604,6 +1571,68 @@ pub struct SubscribeToDatasetRequest {
     pub identity_pool_id: String,
 }
 
+impl SubscribeToDatasetRequest {
+    /// Returns a builder for `SubscribeToDatasetRequest`, checking its required members when built.
+    pub fn builder() -> SubscribeToDatasetRequestBuilder {
+        SubscribeToDatasetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SubscribeToDatasetRequest`](struct.SubscribeToDatasetRequest.html).
+#[derive(Default, Debug)]
+pub struct SubscribeToDatasetRequestBuilder {
+    dataset_name: Option<String>,
+    device_id: Option<String>,
+    identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+}
+
+impl SubscribeToDatasetRequestBuilder {
+    /// Sets the [`dataset_name`](struct.SubscribeToDatasetRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_id`](struct.SubscribeToDatasetRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_id`](struct.SubscribeToDatasetRequest.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.SubscribeToDatasetRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SubscribeToDatasetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SubscribeToDatasetRequest, ::rusoto_core::BuildError> {
+        Ok(SubscribeToDatasetRequest {
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+            device_id: self
+                .device_id
+                .ok_or(::rusoto_core::BuildError::missing("device_id"))?,
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Response to a SubscribeToDataset request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -626,6 +1655,68 @@ pub struct UnsubscribeFromDatasetRequest {
     pub identity_pool_id: String,
 }
 
+impl UnsubscribeFromDatasetRequest {
+    /// Returns a builder for `UnsubscribeFromDatasetRequest`, checking its required members when built.
+    pub fn builder() -> UnsubscribeFromDatasetRequestBuilder {
+        UnsubscribeFromDatasetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UnsubscribeFromDatasetRequest`](struct.UnsubscribeFromDatasetRequest.html).
+#[derive(Default, Debug)]
+pub struct UnsubscribeFromDatasetRequestBuilder {
+    dataset_name: Option<String>,
+    device_id: Option<String>,
+    identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+}
+
+impl UnsubscribeFromDatasetRequestBuilder {
+    /// Sets the [`dataset_name`](struct.UnsubscribeFromDatasetRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_id`](struct.UnsubscribeFromDatasetRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_id`](struct.UnsubscribeFromDatasetRequest.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.UnsubscribeFromDatasetRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UnsubscribeFromDatasetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UnsubscribeFromDatasetRequest, ::rusoto_core::BuildError> {
+        Ok(UnsubscribeFromDatasetRequest {
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+            device_id: self
+                .device_id
+                .ok_or(::rusoto_core::BuildError::missing("device_id"))?,
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+        })
+    }
+}
+
 /// <p>Response to an UnsubscribeFromDataset request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -660,6 +1751,90 @@ pub struct UpdateRecordsRequest {
     pub sync_session_token: String,
 }
 
+impl UpdateRecordsRequest {
+    /// Returns a builder for `UpdateRecordsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRecordsRequestBuilder {
+        UpdateRecordsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRecordsRequest`](struct.UpdateRecordsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRecordsRequestBuilder {
+    client_context: Option<String>,
+    dataset_name: Option<String>,
+    device_id: Option<String>,
+    identity_id: Option<String>,
+    identity_pool_id: Option<String>,
+    record_patches: Option<Vec<RecordPatch>>,
+    sync_session_token: Option<String>,
+}
+
+impl UpdateRecordsRequestBuilder {
+    /// Sets the [`client_context`](struct.UpdateRecordsRequest.html#structfield.client_context) member.
+    pub fn client_context<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_context = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dataset_name`](struct.UpdateRecordsRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_id`](struct.UpdateRecordsRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_id`](struct.UpdateRecordsRequest.html#structfield.identity_id) member.
+    pub fn identity_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_pool_id`](struct.UpdateRecordsRequest.html#structfield.identity_pool_id) member.
+    pub fn identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`record_patches`](struct.UpdateRecordsRequest.html#structfield.record_patches) member.
+    pub fn record_patches<T: Into<Vec<RecordPatch>>>(mut self, value: T) -> Self {
+        self.record_patches = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sync_session_token`](struct.UpdateRecordsRequest.html#structfield.sync_session_token) member.
+    pub fn sync_session_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.sync_session_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRecordsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateRecordsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRecordsRequest {
+            client_context: self.client_context,
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+            device_id: self.device_id,
+            identity_id: self
+                .identity_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_id"))?,
+            identity_pool_id: self
+                .identity_pool_id
+                .ok_or(::rusoto_core::BuildError::missing("identity_pool_id"))?,
+            record_patches: self.record_patches,
+            sync_session_token: self
+                .sync_session_token
+                .ok_or(::rusoto_core::BuildError::missing("sync_session_token"))?,
+        })
+    }
+}
+
 /// <p>Returned for a successful UpdateRecordsRequest.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1845,6 +3020,117 @@ pub trait CognitoSync {
         input: UpdateRecordsRequest,
     ) -> RusotoFuture<UpdateRecordsResponse, UpdateRecordsError>;
 }
+impl<T: ?Sized + CognitoSync> CognitoSync for ::std::sync::Arc<T> {
+    fn bulk_publish(
+        &self,
+        input: BulkPublishRequest,
+    ) -> RusotoFuture<BulkPublishResponse, BulkPublishError> {
+        (**self).bulk_publish(input)
+    }
+    fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> RusotoFuture<DeleteDatasetResponse, DeleteDatasetError> {
+        (**self).delete_dataset(input)
+    }
+    fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> RusotoFuture<DescribeDatasetResponse, DescribeDatasetError> {
+        (**self).describe_dataset(input)
+    }
+    fn describe_identity_pool_usage(
+        &self,
+        input: DescribeIdentityPoolUsageRequest,
+    ) -> RusotoFuture<DescribeIdentityPoolUsageResponse, DescribeIdentityPoolUsageError> {
+        (**self).describe_identity_pool_usage(input)
+    }
+    fn describe_identity_usage(
+        &self,
+        input: DescribeIdentityUsageRequest,
+    ) -> RusotoFuture<DescribeIdentityUsageResponse, DescribeIdentityUsageError> {
+        (**self).describe_identity_usage(input)
+    }
+    fn get_bulk_publish_details(
+        &self,
+        input: GetBulkPublishDetailsRequest,
+    ) -> RusotoFuture<GetBulkPublishDetailsResponse, GetBulkPublishDetailsError> {
+        (**self).get_bulk_publish_details(input)
+    }
+    fn get_cognito_events(
+        &self,
+        input: GetCognitoEventsRequest,
+    ) -> RusotoFuture<GetCognitoEventsResponse, GetCognitoEventsError> {
+        (**self).get_cognito_events(input)
+    }
+    fn get_identity_pool_configuration(
+        &self,
+        input: GetIdentityPoolConfigurationRequest,
+    ) -> RusotoFuture<GetIdentityPoolConfigurationResponse, GetIdentityPoolConfigurationError> {
+        (**self).get_identity_pool_configuration(input)
+    }
+    fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> RusotoFuture<ListDatasetsResponse, ListDatasetsError> {
+        (**self).list_datasets(input)
+    }
+    fn list_identity_pool_usage(
+        &self,
+        input: ListIdentityPoolUsageRequest,
+    ) -> RusotoFuture<ListIdentityPoolUsageResponse, ListIdentityPoolUsageError> {
+        (**self).list_identity_pool_usage(input)
+    }
+    fn list_records(
+        &self,
+        input: ListRecordsRequest,
+    ) -> RusotoFuture<ListRecordsResponse, ListRecordsError> {
+        (**self).list_records(input)
+    }
+    fn register_device(
+        &self,
+        input: RegisterDeviceRequest,
+    ) -> RusotoFuture<RegisterDeviceResponse, RegisterDeviceError> {
+        (**self).register_device(input)
+    }
+    fn set_cognito_events(
+        &self,
+        input: SetCognitoEventsRequest,
+    ) -> RusotoFuture<(), SetCognitoEventsError> {
+        (**self).set_cognito_events(input)
+    }
+    fn set_identity_pool_configuration(
+        &self,
+        input: SetIdentityPoolConfigurationRequest,
+    ) -> RusotoFuture<SetIdentityPoolConfigurationResponse, SetIdentityPoolConfigurationError> {
+        (**self).set_identity_pool_configuration(input)
+    }
+    fn subscribe_to_dataset(
+        &self,
+        input: SubscribeToDatasetRequest,
+    ) -> RusotoFuture<SubscribeToDatasetResponse, SubscribeToDatasetError> {
+        (**self).subscribe_to_dataset(input)
+    }
+    fn unsubscribe_from_dataset(
+        &self,
+        input: UnsubscribeFromDatasetRequest,
+    ) -> RusotoFuture<UnsubscribeFromDatasetResponse, UnsubscribeFromDatasetError> {
+        (**self).unsubscribe_from_dataset(input)
+    }
+    fn update_records(
+        &self,
+        input: UpdateRecordsRequest,
+    ) -> RusotoFuture<UpdateRecordsResponse, UpdateRecordsError> {
+        (**self).update_records(input)
+    }
+}
+/// The error codes Amazon Cognito Sync retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the Amazon Cognito Sync API.
 #[derive(Clone)]
 pub struct CognitoSyncClient {
@@ -1860,6 +3146,27 @@ impl CognitoSyncClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_COGNITO_SYNC`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CognitoSyncClient {
+        Self::new(region::Region::from_env_for_service("COGNITO_SYNC"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `cognito-sync`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CognitoSyncClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("cognito-sync", "COGNITO_SYNC"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1878,7 +3185,54 @@ impl CognitoSyncClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CognitoSyncClient {
-        CognitoSyncClient { client, region }
+        CognitoSyncClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "cognito-sync")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "cognito-sync",
+            "cognito-sync",
+            "COGNITO_SYNC",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CognitoSync` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CognitoSync + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CognitoSyncClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Cognito Sync"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "cognito-sync"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CognitoSyncClient {
+        Self::new_with_client(client, region)
     }
 }
 
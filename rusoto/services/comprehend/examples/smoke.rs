@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Comprehend clients.
+//!
+//! Makes a single read-only `ListDocumentClassificationJobs` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_comprehend::{Comprehend, ComprehendClient, ListDocumentClassificationJobsRequest};
+
+fn main() {
+    let client = ComprehendClient::from_env();
+    match client
+        .list_document_classification_jobs(ListDocumentClassificationJobsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListDocumentClassificationJobs succeeded"),
+        Err(error) => {
+            eprintln!("ListDocumentClassificationJobs failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>The result of calling the operation. The operation returns one object for each document that is successfully processed by the operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -44,6 +45,38 @@ pub struct BatchDetectDominantLanguageRequest {
     pub text_list: Vec<String>,
 }
 
+impl BatchDetectDominantLanguageRequest {
+    /// Returns a builder for `BatchDetectDominantLanguageRequest`, checking its required members when built.
+    pub fn builder() -> BatchDetectDominantLanguageRequestBuilder {
+        BatchDetectDominantLanguageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDetectDominantLanguageRequest`](struct.BatchDetectDominantLanguageRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDetectDominantLanguageRequestBuilder {
+    text_list: Option<Vec<String>>,
+}
+
+impl BatchDetectDominantLanguageRequestBuilder {
+    /// Sets the [`text_list`](struct.BatchDetectDominantLanguageRequest.html#structfield.text_list) member.
+    pub fn text_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.text_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDetectDominantLanguageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDetectDominantLanguageRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDetectDominantLanguageRequest {
+            text_list: self
+                .text_list
+                .ok_or(::rusoto_core::BuildError::missing("text_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDetectDominantLanguageResponse {
@@ -69,16 +102,58 @@ pub struct BatchDetectEntitiesItemResult {
     pub index: Option<i64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct BatchDetectEntitiesRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>A list containing the text of the input documents. The list can contain a maximum of 25 documents. Each document must contain fewer than 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "TextList")]
     pub text_list: Vec<String>,
 }
 
+impl BatchDetectEntitiesRequest {
+    /// Returns a builder for `BatchDetectEntitiesRequest`, checking its required members when built.
+    pub fn builder() -> BatchDetectEntitiesRequestBuilder {
+        BatchDetectEntitiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDetectEntitiesRequest`](struct.BatchDetectEntitiesRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDetectEntitiesRequestBuilder {
+    language_code: Option<LanguageCode>,
+    text_list: Option<Vec<String>>,
+}
+
+impl BatchDetectEntitiesRequestBuilder {
+    /// Sets the [`language_code`](struct.BatchDetectEntitiesRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text_list`](struct.BatchDetectEntitiesRequest.html#structfield.text_list) member.
+    pub fn text_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.text_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDetectEntitiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDetectEntitiesRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDetectEntitiesRequest {
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            text_list: self
+                .text_list
+                .ok_or(::rusoto_core::BuildError::missing("text_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDetectEntitiesResponse {
@@ -104,16 +179,58 @@ pub struct BatchDetectKeyPhrasesItemResult {
     pub key_phrases: Option<Vec<KeyPhrase>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct BatchDetectKeyPhrasesRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>A list containing the text of the input documents. The list can contain a maximum of 25 documents. Each document must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "TextList")]
     pub text_list: Vec<String>,
 }
 
+impl BatchDetectKeyPhrasesRequest {
+    /// Returns a builder for `BatchDetectKeyPhrasesRequest`, checking its required members when built.
+    pub fn builder() -> BatchDetectKeyPhrasesRequestBuilder {
+        BatchDetectKeyPhrasesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDetectKeyPhrasesRequest`](struct.BatchDetectKeyPhrasesRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDetectKeyPhrasesRequestBuilder {
+    language_code: Option<LanguageCode>,
+    text_list: Option<Vec<String>>,
+}
+
+impl BatchDetectKeyPhrasesRequestBuilder {
+    /// Sets the [`language_code`](struct.BatchDetectKeyPhrasesRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text_list`](struct.BatchDetectKeyPhrasesRequest.html#structfield.text_list) member.
+    pub fn text_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.text_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDetectKeyPhrasesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDetectKeyPhrasesRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDetectKeyPhrasesRequest {
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            text_list: self
+                .text_list
+                .ok_or(::rusoto_core::BuildError::missing("text_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDetectKeyPhrasesResponse {
@@ -136,23 +253,65 @@ pub struct BatchDetectSentimentItemResult {
     /// <p>The sentiment detected in the document.</p>
     #[serde(rename = "Sentiment")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sentiment: Option<String>,
+    pub sentiment: Option<SentimentType>,
     /// <p>The level of confidence that Amazon Comprehend has in the accuracy of its sentiment detection.</p>
     #[serde(rename = "SentimentScore")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sentiment_score: Option<SentimentScore>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct BatchDetectSentimentRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>A list containing the text of the input documents. The list can contain a maximum of 25 documents. Each document must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "TextList")]
     pub text_list: Vec<String>,
 }
 
+impl BatchDetectSentimentRequest {
+    /// Returns a builder for `BatchDetectSentimentRequest`, checking its required members when built.
+    pub fn builder() -> BatchDetectSentimentRequestBuilder {
+        BatchDetectSentimentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDetectSentimentRequest`](struct.BatchDetectSentimentRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDetectSentimentRequestBuilder {
+    language_code: Option<LanguageCode>,
+    text_list: Option<Vec<String>>,
+}
+
+impl BatchDetectSentimentRequestBuilder {
+    /// Sets the [`language_code`](struct.BatchDetectSentimentRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text_list`](struct.BatchDetectSentimentRequest.html#structfield.text_list) member.
+    pub fn text_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.text_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDetectSentimentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDetectSentimentRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDetectSentimentRequest {
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            text_list: self
+                .text_list
+                .ok_or(::rusoto_core::BuildError::missing("text_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDetectSentimentResponse {
@@ -178,16 +337,58 @@ pub struct BatchDetectSyntaxItemResult {
     pub syntax_tokens: Option<Vec<SyntaxToken>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct BatchDetectSyntaxRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: SyntaxLanguageCode,
     /// <p>A list containing the text of the input documents. The list can contain a maximum of 25 documents. Each document must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "TextList")]
     pub text_list: Vec<String>,
 }
 
+impl BatchDetectSyntaxRequest {
+    /// Returns a builder for `BatchDetectSyntaxRequest`, checking its required members when built.
+    pub fn builder() -> BatchDetectSyntaxRequestBuilder {
+        BatchDetectSyntaxRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDetectSyntaxRequest`](struct.BatchDetectSyntaxRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDetectSyntaxRequestBuilder {
+    language_code: Option<SyntaxLanguageCode>,
+    text_list: Option<Vec<String>>,
+}
+
+impl BatchDetectSyntaxRequestBuilder {
+    /// Sets the [`language_code`](struct.BatchDetectSyntaxRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<SyntaxLanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text_list`](struct.BatchDetectSyntaxRequest.html#structfield.text_list) member.
+    pub fn text_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.text_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDetectSyntaxRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDetectSyntaxRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDetectSyntaxRequest {
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            text_list: self
+                .text_list
+                .ok_or(::rusoto_core::BuildError::missing("text_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDetectSyntaxResponse {
@@ -261,7 +462,7 @@ pub struct ClassifierMetadata {
     pub number_of_trained_documents: Option<i64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateDocumentClassifierRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -278,7 +479,7 @@ pub struct CreateDocumentClassifierRequest {
     pub input_data_config: DocumentClassifierInputDataConfig,
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>Enables the addition of output results configuration parameters for custom classifier jobs.</p>
     #[serde(rename = "OutputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -297,6 +498,114 @@ pub struct CreateDocumentClassifierRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl CreateDocumentClassifierRequest {
+    /// Returns a builder for `CreateDocumentClassifierRequest`, checking its required members when built.
+    pub fn builder() -> CreateDocumentClassifierRequestBuilder {
+        CreateDocumentClassifierRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDocumentClassifierRequest`](struct.CreateDocumentClassifierRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDocumentClassifierRequestBuilder {
+    client_request_token: Option<String>,
+    data_access_role_arn: Option<String>,
+    document_classifier_name: Option<String>,
+    input_data_config: Option<DocumentClassifierInputDataConfig>,
+    language_code: Option<LanguageCode>,
+    output_data_config: Option<DocumentClassifierOutputDataConfig>,
+    tags: Option<Vec<Tag>>,
+    volume_kms_key_id: Option<String>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl CreateDocumentClassifierRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateDocumentClassifierRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_access_role_arn`](struct.CreateDocumentClassifierRequest.html#structfield.data_access_role_arn) member.
+    pub fn data_access_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_access_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_classifier_name`](struct.CreateDocumentClassifierRequest.html#structfield.document_classifier_name) member.
+    pub fn document_classifier_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_classifier_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_data_config`](struct.CreateDocumentClassifierRequest.html#structfield.input_data_config) member.
+    pub fn input_data_config<T: Into<DocumentClassifierInputDataConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.input_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`language_code`](struct.CreateDocumentClassifierRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_data_config`](struct.CreateDocumentClassifierRequest.html#structfield.output_data_config) member.
+    pub fn output_data_config<T: Into<DocumentClassifierOutputDataConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.output_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDocumentClassifierRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_kms_key_id`](struct.CreateDocumentClassifierRequest.html#structfield.volume_kms_key_id) member.
+    pub fn volume_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.CreateDocumentClassifierRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDocumentClassifierRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDocumentClassifierRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDocumentClassifierRequest {
+            client_request_token: self.client_request_token,
+            data_access_role_arn: self
+                .data_access_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("data_access_role_arn"))?,
+            document_classifier_name: self.document_classifier_name.ok_or(
+                ::rusoto_core::BuildError::missing("document_classifier_name"),
+            )?,
+            input_data_config: self
+                .input_data_config
+                .ok_or(::rusoto_core::BuildError::missing("input_data_config"))?,
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            output_data_config: self.output_data_config,
+            tags: self.tags,
+            volume_kms_key_id: self.volume_kms_key_id,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDocumentClassifierResponse {
@@ -306,7 +615,7 @@ pub struct CreateDocumentClassifierResponse {
     pub document_classifier_arn: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateEntityRecognizerRequest {
     /// <p> A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -320,7 +629,7 @@ pub struct CreateEntityRecognizerRequest {
     pub input_data_config: EntityRecognizerInputDataConfig,
     /// <p> The language of the input documents. All documents must be in the same language. Only English ("en") is currently supported. </p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>The name given to the newly created recognizer. Recognizer names can be a maximum of 256 characters. Alphanumeric characters, hyphens (-) and underscores (_) are allowed. The name must be unique in the account/region.</p>
     #[serde(rename = "RecognizerName")]
     pub recognizer_name: String,
@@ -338,6 +647,100 @@ pub struct CreateEntityRecognizerRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl CreateEntityRecognizerRequest {
+    /// Returns a builder for `CreateEntityRecognizerRequest`, checking its required members when built.
+    pub fn builder() -> CreateEntityRecognizerRequestBuilder {
+        CreateEntityRecognizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateEntityRecognizerRequest`](struct.CreateEntityRecognizerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateEntityRecognizerRequestBuilder {
+    client_request_token: Option<String>,
+    data_access_role_arn: Option<String>,
+    input_data_config: Option<EntityRecognizerInputDataConfig>,
+    language_code: Option<LanguageCode>,
+    recognizer_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    volume_kms_key_id: Option<String>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl CreateEntityRecognizerRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateEntityRecognizerRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_access_role_arn`](struct.CreateEntityRecognizerRequest.html#structfield.data_access_role_arn) member.
+    pub fn data_access_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_access_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_data_config`](struct.CreateEntityRecognizerRequest.html#structfield.input_data_config) member.
+    pub fn input_data_config<T: Into<EntityRecognizerInputDataConfig>>(mut self, value: T) -> Self {
+        self.input_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`language_code`](struct.CreateEntityRecognizerRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recognizer_name`](struct.CreateEntityRecognizerRequest.html#structfield.recognizer_name) member.
+    pub fn recognizer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.recognizer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateEntityRecognizerRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_kms_key_id`](struct.CreateEntityRecognizerRequest.html#structfield.volume_kms_key_id) member.
+    pub fn volume_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.CreateEntityRecognizerRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateEntityRecognizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateEntityRecognizerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateEntityRecognizerRequest {
+            client_request_token: self.client_request_token,
+            data_access_role_arn: self
+                .data_access_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("data_access_role_arn"))?,
+            input_data_config: self
+                .input_data_config
+                .ok_or(::rusoto_core::BuildError::missing("input_data_config"))?,
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            recognizer_name: self
+                .recognizer_name
+                .ok_or(::rusoto_core::BuildError::missing("recognizer_name"))?,
+            tags: self.tags,
+            volume_kms_key_id: self.volume_kms_key_id,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateEntityRecognizerResponse {
@@ -354,6 +757,38 @@ pub struct DeleteDocumentClassifierRequest {
     pub document_classifier_arn: String,
 }
 
+impl DeleteDocumentClassifierRequest {
+    /// Returns a builder for `DeleteDocumentClassifierRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDocumentClassifierRequestBuilder {
+        DeleteDocumentClassifierRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDocumentClassifierRequest`](struct.DeleteDocumentClassifierRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDocumentClassifierRequestBuilder {
+    document_classifier_arn: Option<String>,
+}
+
+impl DeleteDocumentClassifierRequestBuilder {
+    /// Sets the [`document_classifier_arn`](struct.DeleteDocumentClassifierRequest.html#structfield.document_classifier_arn) member.
+    pub fn document_classifier_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_classifier_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDocumentClassifierRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDocumentClassifierRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDocumentClassifierRequest {
+            document_classifier_arn: self.document_classifier_arn.ok_or(
+                ::rusoto_core::BuildError::missing("document_classifier_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDocumentClassifierResponse {}
@@ -365,6 +800,38 @@ pub struct DeleteEntityRecognizerRequest {
     pub entity_recognizer_arn: String,
 }
 
+impl DeleteEntityRecognizerRequest {
+    /// Returns a builder for `DeleteEntityRecognizerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteEntityRecognizerRequestBuilder {
+        DeleteEntityRecognizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEntityRecognizerRequest`](struct.DeleteEntityRecognizerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteEntityRecognizerRequestBuilder {
+    entity_recognizer_arn: Option<String>,
+}
+
+impl DeleteEntityRecognizerRequestBuilder {
+    /// Sets the [`entity_recognizer_arn`](struct.DeleteEntityRecognizerRequest.html#structfield.entity_recognizer_arn) member.
+    pub fn entity_recognizer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_recognizer_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEntityRecognizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteEntityRecognizerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteEntityRecognizerRequest {
+            entity_recognizer_arn: self
+                .entity_recognizer_arn
+                .ok_or(::rusoto_core::BuildError::missing("entity_recognizer_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteEntityRecognizerResponse {}
@@ -376,6 +843,39 @@ pub struct DescribeDocumentClassificationJobRequest {
     pub job_id: String,
 }
 
+impl DescribeDocumentClassificationJobRequest {
+    /// Returns a builder for `DescribeDocumentClassificationJobRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDocumentClassificationJobRequestBuilder {
+        DescribeDocumentClassificationJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDocumentClassificationJobRequest`](struct.DescribeDocumentClassificationJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDocumentClassificationJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl DescribeDocumentClassificationJobRequestBuilder {
+    /// Sets the [`job_id`](struct.DescribeDocumentClassificationJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDocumentClassificationJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDocumentClassificationJobRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDocumentClassificationJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDocumentClassificationJobResponse {
@@ -392,6 +892,38 @@ pub struct DescribeDocumentClassifierRequest {
     pub document_classifier_arn: String,
 }
 
+impl DescribeDocumentClassifierRequest {
+    /// Returns a builder for `DescribeDocumentClassifierRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDocumentClassifierRequestBuilder {
+        DescribeDocumentClassifierRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDocumentClassifierRequest`](struct.DescribeDocumentClassifierRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDocumentClassifierRequestBuilder {
+    document_classifier_arn: Option<String>,
+}
+
+impl DescribeDocumentClassifierRequestBuilder {
+    /// Sets the [`document_classifier_arn`](struct.DescribeDocumentClassifierRequest.html#structfield.document_classifier_arn) member.
+    pub fn document_classifier_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_classifier_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDocumentClassifierRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDocumentClassifierRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDocumentClassifierRequest {
+            document_classifier_arn: self.document_classifier_arn.ok_or(
+                ::rusoto_core::BuildError::missing("document_classifier_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDocumentClassifierResponse {
@@ -408,6 +940,39 @@ pub struct DescribeDominantLanguageDetectionJobRequest {
     pub job_id: String,
 }
 
+impl DescribeDominantLanguageDetectionJobRequest {
+    /// Returns a builder for `DescribeDominantLanguageDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDominantLanguageDetectionJobRequestBuilder {
+        DescribeDominantLanguageDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDominantLanguageDetectionJobRequest`](struct.DescribeDominantLanguageDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDominantLanguageDetectionJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl DescribeDominantLanguageDetectionJobRequestBuilder {
+    /// Sets the [`job_id`](struct.DescribeDominantLanguageDetectionJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDominantLanguageDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDominantLanguageDetectionJobRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDominantLanguageDetectionJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDominantLanguageDetectionJobResponse {
@@ -424,6 +989,38 @@ pub struct DescribeEntitiesDetectionJobRequest {
     pub job_id: String,
 }
 
+impl DescribeEntitiesDetectionJobRequest {
+    /// Returns a builder for `DescribeEntitiesDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEntitiesDetectionJobRequestBuilder {
+        DescribeEntitiesDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEntitiesDetectionJobRequest`](struct.DescribeEntitiesDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEntitiesDetectionJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl DescribeEntitiesDetectionJobRequestBuilder {
+    /// Sets the [`job_id`](struct.DescribeEntitiesDetectionJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEntitiesDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEntitiesDetectionJobRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEntitiesDetectionJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEntitiesDetectionJobResponse {
@@ -440,6 +1037,38 @@ pub struct DescribeEntityRecognizerRequest {
     pub entity_recognizer_arn: String,
 }
 
+impl DescribeEntityRecognizerRequest {
+    /// Returns a builder for `DescribeEntityRecognizerRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEntityRecognizerRequestBuilder {
+        DescribeEntityRecognizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEntityRecognizerRequest`](struct.DescribeEntityRecognizerRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEntityRecognizerRequestBuilder {
+    entity_recognizer_arn: Option<String>,
+}
+
+impl DescribeEntityRecognizerRequestBuilder {
+    /// Sets the [`entity_recognizer_arn`](struct.DescribeEntityRecognizerRequest.html#structfield.entity_recognizer_arn) member.
+    pub fn entity_recognizer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_recognizer_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEntityRecognizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEntityRecognizerRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEntityRecognizerRequest {
+            entity_recognizer_arn: self
+                .entity_recognizer_arn
+                .ok_or(::rusoto_core::BuildError::missing("entity_recognizer_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEntityRecognizerResponse {
@@ -456,6 +1085,39 @@ pub struct DescribeKeyPhrasesDetectionJobRequest {
     pub job_id: String,
 }
 
+impl DescribeKeyPhrasesDetectionJobRequest {
+    /// Returns a builder for `DescribeKeyPhrasesDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> DescribeKeyPhrasesDetectionJobRequestBuilder {
+        DescribeKeyPhrasesDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeKeyPhrasesDetectionJobRequest`](struct.DescribeKeyPhrasesDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeKeyPhrasesDetectionJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl DescribeKeyPhrasesDetectionJobRequestBuilder {
+    /// Sets the [`job_id`](struct.DescribeKeyPhrasesDetectionJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeKeyPhrasesDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeKeyPhrasesDetectionJobRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeKeyPhrasesDetectionJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeKeyPhrasesDetectionJobResponse {
@@ -472,6 +1134,39 @@ pub struct DescribeSentimentDetectionJobRequest {
     pub job_id: String,
 }
 
+impl DescribeSentimentDetectionJobRequest {
+    /// Returns a builder for `DescribeSentimentDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> DescribeSentimentDetectionJobRequestBuilder {
+        DescribeSentimentDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSentimentDetectionJobRequest`](struct.DescribeSentimentDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeSentimentDetectionJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl DescribeSentimentDetectionJobRequestBuilder {
+    /// Sets the [`job_id`](struct.DescribeSentimentDetectionJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSentimentDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSentimentDetectionJobRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeSentimentDetectionJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeSentimentDetectionJobResponse {
@@ -488,6 +1183,38 @@ pub struct DescribeTopicsDetectionJobRequest {
     pub job_id: String,
 }
 
+impl DescribeTopicsDetectionJobRequest {
+    /// Returns a builder for `DescribeTopicsDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTopicsDetectionJobRequestBuilder {
+        DescribeTopicsDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTopicsDetectionJobRequest`](struct.DescribeTopicsDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTopicsDetectionJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl DescribeTopicsDetectionJobRequestBuilder {
+    /// Sets the [`job_id`](struct.DescribeTopicsDetectionJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTopicsDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTopicsDetectionJobRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTopicsDetectionJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeTopicsDetectionJobResponse {
@@ -504,6 +1231,38 @@ pub struct DetectDominantLanguageRequest {
     pub text: String,
 }
 
+impl DetectDominantLanguageRequest {
+    /// Returns a builder for `DetectDominantLanguageRequest`, checking its required members when built.
+    pub fn builder() -> DetectDominantLanguageRequestBuilder {
+        DetectDominantLanguageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetectDominantLanguageRequest`](struct.DetectDominantLanguageRequest.html).
+#[derive(Default, Debug)]
+pub struct DetectDominantLanguageRequestBuilder {
+    text: Option<String>,
+}
+
+impl DetectDominantLanguageRequestBuilder {
+    /// Sets the [`text`](struct.DetectDominantLanguageRequest.html#structfield.text) member.
+    pub fn text<T: Into<String>>(mut self, value: T) -> Self {
+        self.text = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectDominantLanguageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetectDominantLanguageRequest, ::rusoto_core::BuildError> {
+        Ok(DetectDominantLanguageRequest {
+            text: self
+                .text
+                .ok_or(::rusoto_core::BuildError::missing("text"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetectDominantLanguageResponse {
@@ -513,16 +1272,56 @@ pub struct DetectDominantLanguageResponse {
     pub languages: Option<Vec<DominantLanguage>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DetectEntitiesRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>A UTF-8 text string. Each string must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "Text")]
     pub text: String,
 }
 
+impl DetectEntitiesRequest {
+    /// Returns a builder for `DetectEntitiesRequest`, checking its required members when built.
+    pub fn builder() -> DetectEntitiesRequestBuilder {
+        DetectEntitiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetectEntitiesRequest`](struct.DetectEntitiesRequest.html).
+#[derive(Default, Debug)]
+pub struct DetectEntitiesRequestBuilder {
+    language_code: Option<LanguageCode>,
+    text: Option<String>,
+}
+
+impl DetectEntitiesRequestBuilder {
+    /// Sets the [`language_code`](struct.DetectEntitiesRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text`](struct.DetectEntitiesRequest.html#structfield.text) member.
+    pub fn text<T: Into<String>>(mut self, value: T) -> Self {
+        self.text = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectEntitiesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetectEntitiesRequest, ::rusoto_core::BuildError> {
+        Ok(DetectEntitiesRequest {
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            text: self
+                .text
+                .ok_or(::rusoto_core::BuildError::missing("text"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetectEntitiesResponse {
@@ -532,16 +1331,58 @@ pub struct DetectEntitiesResponse {
     pub entities: Option<Vec<Entity>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DetectKeyPhrasesRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>A UTF-8 text string. Each string must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "Text")]
     pub text: String,
 }
 
+impl DetectKeyPhrasesRequest {
+    /// Returns a builder for `DetectKeyPhrasesRequest`, checking its required members when built.
+    pub fn builder() -> DetectKeyPhrasesRequestBuilder {
+        DetectKeyPhrasesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetectKeyPhrasesRequest`](struct.DetectKeyPhrasesRequest.html).
+#[derive(Default, Debug)]
+pub struct DetectKeyPhrasesRequestBuilder {
+    language_code: Option<LanguageCode>,
+    text: Option<String>,
+}
+
+impl DetectKeyPhrasesRequestBuilder {
+    /// Sets the [`language_code`](struct.DetectKeyPhrasesRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text`](struct.DetectKeyPhrasesRequest.html#structfield.text) member.
+    pub fn text<T: Into<String>>(mut self, value: T) -> Self {
+        self.text = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectKeyPhrasesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetectKeyPhrasesRequest, ::rusoto_core::BuildError> {
+        Ok(DetectKeyPhrasesRequest {
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            text: self
+                .text
+                .ok_or(::rusoto_core::BuildError::missing("text"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetectKeyPhrasesResponse {
@@ -551,39 +1392,119 @@ pub struct DetectKeyPhrasesResponse {
     pub key_phrases: Option<Vec<KeyPhrase>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DetectSentimentRequest {
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>A UTF-8 text string. Each string must contain fewer that 5,000 bytes of UTF-8 encoded characters.</p>
     #[serde(rename = "Text")]
     pub text: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DetectSentimentResponse {
-    /// <p>The inferred sentiment that Amazon Comprehend has the highest level of confidence in.</p>
+impl DetectSentimentRequest {
+    /// Returns a builder for `DetectSentimentRequest`, checking its required members when built.
+    pub fn builder() -> DetectSentimentRequestBuilder {
+        DetectSentimentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetectSentimentRequest`](struct.DetectSentimentRequest.html).
+#[derive(Default, Debug)]
+pub struct DetectSentimentRequestBuilder {
+    language_code: Option<LanguageCode>,
+    text: Option<String>,
+}
+
+impl DetectSentimentRequestBuilder {
+    /// Sets the [`language_code`](struct.DetectSentimentRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text`](struct.DetectSentimentRequest.html#structfield.text) member.
+    pub fn text<T: Into<String>>(mut self, value: T) -> Self {
+        self.text = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectSentimentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetectSentimentRequest, ::rusoto_core::BuildError> {
+        Ok(DetectSentimentRequest {
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            text: self
+                .text
+                .ok_or(::rusoto_core::BuildError::missing("text"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DetectSentimentResponse {
+    /// <p>The inferred sentiment that Amazon Comprehend has the highest level of confidence in.</p>
     #[serde(rename = "Sentiment")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sentiment: Option<String>,
+    pub sentiment: Option<SentimentType>,
     /// <p>An object that lists the sentiments, and their corresponding confidence levels.</p>
     #[serde(rename = "SentimentScore")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sentiment_score: Option<SentimentScore>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DetectSyntaxRequest {
     /// <p>The language code of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt").</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: SyntaxLanguageCode,
     /// <p>A UTF-8 string. Each string must contain fewer that 5,000 bytes of UTF encoded characters.</p>
     #[serde(rename = "Text")]
     pub text: String,
 }
 
+impl DetectSyntaxRequest {
+    /// Returns a builder for `DetectSyntaxRequest`, checking its required members when built.
+    pub fn builder() -> DetectSyntaxRequestBuilder {
+        DetectSyntaxRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetectSyntaxRequest`](struct.DetectSyntaxRequest.html).
+#[derive(Default, Debug)]
+pub struct DetectSyntaxRequestBuilder {
+    language_code: Option<SyntaxLanguageCode>,
+    text: Option<String>,
+}
+
+impl DetectSyntaxRequestBuilder {
+    /// Sets the [`language_code`](struct.DetectSyntaxRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<SyntaxLanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text`](struct.DetectSyntaxRequest.html#structfield.text) member.
+    pub fn text<T: Into<String>>(mut self, value: T) -> Self {
+        self.text = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectSyntaxRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetectSyntaxRequest, ::rusoto_core::BuildError> {
+        Ok(DetectSyntaxRequest {
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            text: self
+                .text
+                .ok_or(::rusoto_core::BuildError::missing("text"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetectSyntaxResponse {
@@ -603,15 +1524,17 @@ pub struct DocumentClassificationJobFilter {
     /// <p>Filters the list based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<EpochTimestamp>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<EpochTimestamp>,
 }
 
 /// <p>Provides information about a document classification job.</p>
@@ -628,8 +1551,9 @@ pub struct DocumentClassificationJobProperties {
     pub document_classifier_arn: Option<String>,
     /// <p>The time that the document classification job completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The input data configuration that you supplied when you created the document classification job.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -645,7 +1569,7 @@ pub struct DocumentClassificationJobProperties {
     /// <p>The current status of the document classification job. If the status is <code>FAILED</code>, the <code>Message</code> field shows the reason for the failure.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>A description of the status of the job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -656,8 +1580,9 @@ pub struct DocumentClassificationJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the document classification job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<EpochTimestamp>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -674,15 +1599,17 @@ pub struct DocumentClassifierFilter {
     /// <p>Filters the list of classifiers based on status. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ModelStatus>,
     /// <p>Filters the list of classifiers based on the time that the classifier was submitted for processing. Returns only classifiers submitted after the specified time. Classifiers are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<EpochTimestamp>,
     /// <p>Filters the list of classifiers based on the time that the classifier was submitted for processing. Returns only classifiers submitted before the specified time. Classifiers are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<EpochTimestamp>,
 }
 
 /// <p>The input properties for training a document classifier. </p> <p>For more information on how the input file is formatted, see <a>how-document-classification-training-data</a>. </p>
@@ -724,8 +1651,9 @@ pub struct DocumentClassifierProperties {
     pub document_classifier_arn: Option<String>,
     /// <p>The time that training the document classifier completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The input data configuration that you supplied when you created the document classifier for training.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -733,7 +1661,7 @@ pub struct DocumentClassifierProperties {
     /// <p>The language code for the language of the documents that the classifier was trained on.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>Additional information about the status of the classifier.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -745,19 +1673,22 @@ pub struct DocumentClassifierProperties {
     /// <p>The status of the document classifier. If the status is <code>TRAINED</code> the classifier is ready to use. If the status is <code>FAILED</code> you can see additional information about why the classifier wasn't trained in the <code>Message</code> field.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ModelStatus>,
     /// <p>The time that the document classifier was submitted for training.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<EpochTimestamp>,
     /// <p>The time that training of the document classifier was completed. Indicates the time when the training completes on documentation classifiers. You are billed for the time interval between this time and the value of TrainingStartTime.</p>
     #[serde(rename = "TrainingEndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub training_end_time: Option<f64>,
+    pub training_end_time: Option<EpochTimestamp>,
     /// <p>Indicates the time when the training starts on documentation classifiers. You are billed for the time interval between this time and the value of TrainingEndTime. </p>
     #[serde(rename = "TrainingStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub training_start_time: Option<f64>,
+    pub training_start_time: Option<EpochTimestamp>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -792,15 +1723,17 @@ pub struct DominantLanguageDetectionJobFilter {
     /// <p>Filters the list of jobs based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<EpochTimestamp>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<EpochTimestamp>,
 }
 
 /// <p>Provides information about a dominant language detection job.</p>
@@ -813,8 +1746,9 @@ pub struct DominantLanguageDetectionJobProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the dominant language detection job completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The input data configuration that you supplied when you created the dominant language detection job.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -830,7 +1764,7 @@ pub struct DominantLanguageDetectionJobProperties {
     /// <p>The current status of the dominant language detection job. If the status is <code>FAILED</code>, the <code>Message</code> field shows the reason for the failure.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>A description for the status of a job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -841,8 +1775,9 @@ pub struct DominantLanguageDetectionJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the dominant language detection job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<EpochTimestamp>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -863,15 +1798,17 @@ pub struct EntitiesDetectionJobFilter {
     /// <p>Filters the list of jobs based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<EpochTimestamp>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<EpochTimestamp>,
 }
 
 /// <p>Provides information about an entities detection job.</p>
@@ -884,8 +1821,9 @@ pub struct EntitiesDetectionJobProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the entities detection job completed</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The Amazon Resource Name (ARN) that identifies the entity recognizer.</p>
     #[serde(rename = "EntityRecognizerArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -905,11 +1843,11 @@ pub struct EntitiesDetectionJobProperties {
     /// <p>The current status of the entities detection job. If the status is <code>FAILED</code>, the <code>Message</code> field shows the reason for the failure.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>The language code of the input documents.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>A description of the status of a job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -920,8 +1858,9 @@ pub struct EntitiesDetectionJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the entities detection job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<EpochTimestamp>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -955,7 +1894,7 @@ pub struct Entity {
     /// <p>The entity's type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EntityType>,
 }
 
 /// <p>Describes the annotations associated with a entity recognizer.</p>
@@ -1006,15 +1945,17 @@ pub struct EntityRecognizerFilter {
     /// <p>The status of an entity recognizer.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ModelStatus>,
     /// <p>Filters the list of entities based on the time that the list was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<EpochTimestamp>,
     /// <p>Filters the list of entities based on the time that the list was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<EpochTimestamp>,
 }
 
 /// <p>Specifies the format and location of the input data.</p>
@@ -1086,8 +2027,9 @@ pub struct EntityRecognizerProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the recognizer creation completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The Amazon Resource Name (ARN) that identifies the entity recognizer.</p>
     #[serde(rename = "EntityRecognizerArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1099,7 +2041,7 @@ pub struct EntityRecognizerProperties {
     /// <p> The language of the input documents. All documents must be in the same language. Only English ("en") is currently supported.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p> A description of the status of the recognizer.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1111,19 +2053,22 @@ pub struct EntityRecognizerProperties {
     /// <p>Provides the status of the entity recognizer.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ModelStatus>,
     /// <p>The time that the recognizer was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<EpochTimestamp>,
     /// <p>The time that training of the entity recognizer was completed.</p>
     #[serde(rename = "TrainingEndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub training_end_time: Option<f64>,
+    pub training_end_time: Option<EpochTimestamp>,
     /// <p>The time that training of the entity recognizer started.</p>
     #[serde(rename = "TrainingStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub training_start_time: Option<f64>,
+    pub training_start_time: Option<EpochTimestamp>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1134,6 +2079,106 @@ pub struct EntityRecognizerProperties {
     pub vpc_config: Option<VpcConfig>,
 }
 
+/// <p>The entity's type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EntityType {
+    /// <p><code>PERSON</code></p>
+    Person,
+    /// <p><code>LOCATION</code></p>
+    Location,
+    /// <p><code>ORGANIZATION</code></p>
+    Organization,
+    /// <p><code>COMMERCIAL_ITEM</code></p>
+    CommercialItem,
+    /// <p><code>EVENT</code></p>
+    Event,
+    /// <p><code>DATE</code></p>
+    Date,
+    /// <p><code>QUANTITY</code></p>
+    Quantity,
+    /// <p><code>TITLE</code></p>
+    Title,
+    /// <p><code>OTHER</code></p>
+    Other,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EntityType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityType::Person => "PERSON",
+            EntityType::Location => "LOCATION",
+            EntityType::Organization => "ORGANIZATION",
+            EntityType::CommercialItem => "COMMERCIAL_ITEM",
+            EntityType::Event => "EVENT",
+            EntityType::Date => "DATE",
+            EntityType::Quantity => "QUANTITY",
+            EntityType::Title => "TITLE",
+            EntityType::Other => "OTHER",
+            EntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EntityType {
+    fn from(value: &'a str) -> EntityType {
+        match value {
+            "PERSON" => EntityType::Person,
+            "LOCATION" => EntityType::Location,
+            "ORGANIZATION" => EntityType::Organization,
+            "COMMERCIAL_ITEM" => EntityType::CommercialItem,
+            "EVENT" => EntityType::Event,
+            "DATE" => EntityType::Date,
+            "QUANTITY" => EntityType::Quantity,
+            "TITLE" => EntityType::Title,
+            "OTHER" => EntityType::Other,
+            _ => EntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityType {
+    fn from(value: String) -> EntityType {
+        EntityType::from(value.as_str())
+    }
+}
+
+impl From<EntityType> for String {
+    fn from(value: EntityType) -> String {
+        match value {
+            EntityType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EntityType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EntityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EntityType::from(value))
+    }
+}
+
 /// <p>Detailed information about the accuracy of an entity recognizer for a specific entity type. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1166,12 +2211,172 @@ pub struct InputDataConfig {
     /// <p><p>Specifies how the text in an input file should be processed:</p> <ul> <li> <p> <code>ONE<em>DOC</em>PER<em>FILE</code> - Each file is considered a separate document. Use this option when you are processing large documents, such as newspaper articles or scientific papers.</p> </li> <li> <p> <code>ONE</em>DOC<em>PER</em>LINE</code> - Each line in a file is considered a separate document. Use this option when you are processing many short documents, such as text messages.</p> </li> </ul></p>
     #[serde(rename = "InputFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub input_format: Option<String>,
+    pub input_format: Option<InputFormat>,
     /// <p>The Amazon S3 URI for the input data. The URI must be in same region as the API endpoint that you are calling. The URI can point to a single input file or it can provide the prefix for a collection of data files. </p> <p>For example, if you use the URI <code>S3://bucketName/prefix</code>, if the prefix is a single file, Amazon Comprehend uses that file as input. If more than one file begins with the prefix, Amazon Comprehend uses all of them as input.</p>
     #[serde(rename = "S3Uri")]
     pub s3_uri: String,
 }
 
+/// <p><p>Specifies how the text in an input file should be processed:</p> <ul> <li> <p> <code>ONE<em>DOC</em>PER<em>FILE</code> - Each file is considered a separate document. Use this option when you are processing large documents, such as newspaper articles or scientific papers.</p> </li> <li> <p> <code>ONE</em>DOC<em>PER</em>LINE</code> - Each line in a file is considered a separate document. Use this option when you are processing many short documents, such as text messages.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InputFormat {
+    /// <p><code>ONE_DOC_PER_FILE</code></p>
+    OneDocPerFile,
+    /// <p><code>ONE_DOC_PER_LINE</code></p>
+    OneDocPerLine,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InputFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InputFormat::OneDocPerFile => "ONE_DOC_PER_FILE",
+            InputFormat::OneDocPerLine => "ONE_DOC_PER_LINE",
+            InputFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InputFormat {
+    fn from(value: &'a str) -> InputFormat {
+        match value {
+            "ONE_DOC_PER_FILE" => InputFormat::OneDocPerFile,
+            "ONE_DOC_PER_LINE" => InputFormat::OneDocPerLine,
+            _ => InputFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InputFormat {
+    fn from(value: String) -> InputFormat {
+        InputFormat::from(value.as_str())
+    }
+}
+
+impl From<InputFormat> for String {
+    fn from(value: InputFormat) -> String {
+        match value {
+            InputFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InputFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InputFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InputFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InputFormat::from(value))
+    }
+}
+
+/// <p>Filters the list based on job status. Returns only jobs with the specified status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobStatus {
+    /// <p><code>SUBMITTED</code></p>
+    Submitted,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>STOP_REQUESTED</code></p>
+    StopRequested,
+    /// <p><code>STOPPED</code></p>
+    Stopped,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::Submitted => "SUBMITTED",
+            JobStatus::InProgress => "IN_PROGRESS",
+            JobStatus::Completed => "COMPLETED",
+            JobStatus::Failed => "FAILED",
+            JobStatus::StopRequested => "STOP_REQUESTED",
+            JobStatus::Stopped => "STOPPED",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "SUBMITTED" => JobStatus::Submitted,
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "COMPLETED" => JobStatus::Completed,
+            "FAILED" => JobStatus::Failed,
+            "STOP_REQUESTED" => JobStatus::StopRequested,
+            "STOPPED" => JobStatus::Stopped,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        JobStatus::from(value.as_str())
+    }
+}
+
+impl From<JobStatus> for String {
+    fn from(value: JobStatus) -> String {
+        match value {
+            JobStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobStatus::from(value))
+    }
+}
+
 /// <p>Describes a key noun phrase.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1204,15 +2409,17 @@ pub struct KeyPhrasesDetectionJobFilter {
     /// <p>Filters the list of jobs based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<EpochTimestamp>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<EpochTimestamp>,
 }
 
 /// <p>Provides information about a key phrases detection job.</p>
@@ -1225,8 +2432,9 @@ pub struct KeyPhrasesDetectionJobProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the key phrases detection job completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The input data configuration that you supplied when you created the key phrases detection job.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1242,11 +2450,11 @@ pub struct KeyPhrasesDetectionJobProperties {
     /// <p>The current status of the key phrases detection job. If the status is <code>FAILED</code>, the <code>Message</code> field shows the reason for the failure.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>The language code of the input documents.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>A description of the status of a job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1257,8 +2465,9 @@ pub struct KeyPhrasesDetectionJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the key phrases detection job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<EpochTimestamp>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1269,6 +2478,118 @@ pub struct KeyPhrasesDetectionJobProperties {
     pub vpc_config: Option<VpcConfig>,
 }
 
+/// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LanguageCode {
+    /// <p><code>en</code></p>
+    En,
+    /// <p><code>es</code></p>
+    Es,
+    /// <p><code>fr</code></p>
+    Fr,
+    /// <p><code>de</code></p>
+    De,
+    /// <p><code>it</code></p>
+    It,
+    /// <p><code>pt</code></p>
+    Pt,
+    /// <p><code>ar</code></p>
+    Ar,
+    /// <p><code>hi</code></p>
+    Hi,
+    /// <p><code>ja</code></p>
+    Ja,
+    /// <p><code>ko</code></p>
+    Ko,
+    /// <p><code>zh</code></p>
+    Zh,
+    /// <p><code>zh-TW</code></p>
+    ZhTw,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LanguageCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LanguageCode::En => "en",
+            LanguageCode::Es => "es",
+            LanguageCode::Fr => "fr",
+            LanguageCode::De => "de",
+            LanguageCode::It => "it",
+            LanguageCode::Pt => "pt",
+            LanguageCode::Ar => "ar",
+            LanguageCode::Hi => "hi",
+            LanguageCode::Ja => "ja",
+            LanguageCode::Ko => "ko",
+            LanguageCode::Zh => "zh",
+            LanguageCode::ZhTw => "zh-TW",
+            LanguageCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LanguageCode {
+    fn from(value: &'a str) -> LanguageCode {
+        match value {
+            "en" => LanguageCode::En,
+            "es" => LanguageCode::Es,
+            "fr" => LanguageCode::Fr,
+            "de" => LanguageCode::De,
+            "it" => LanguageCode::It,
+            "pt" => LanguageCode::Pt,
+            "ar" => LanguageCode::Ar,
+            "hi" => LanguageCode::Hi,
+            "ja" => LanguageCode::Ja,
+            "ko" => LanguageCode::Ko,
+            "zh" => LanguageCode::Zh,
+            "zh-TW" => LanguageCode::ZhTw,
+            _ => LanguageCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LanguageCode {
+    fn from(value: String) -> LanguageCode {
+        LanguageCode::from(value.as_str())
+    }
+}
+
+impl From<LanguageCode> for String {
+    fn from(value: LanguageCode) -> String {
+        match value {
+            LanguageCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LanguageCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LanguageCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LanguageCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LanguageCode::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListDocumentClassificationJobsRequest {
     /// <p>Filters the jobs that are returned. You can filter jobs on their names, status, or the date and time that they were submitted. You can only set one filter at a time.</p>
@@ -1285,6 +2606,53 @@ pub struct ListDocumentClassificationJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDocumentClassificationJobsRequest {
+    /// Returns a builder for `ListDocumentClassificationJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListDocumentClassificationJobsRequestBuilder {
+        ListDocumentClassificationJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDocumentClassificationJobsRequest`](struct.ListDocumentClassificationJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDocumentClassificationJobsRequestBuilder {
+    filter: Option<DocumentClassificationJobFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDocumentClassificationJobsRequestBuilder {
+    /// Sets the [`filter`](struct.ListDocumentClassificationJobsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<DocumentClassificationJobFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDocumentClassificationJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDocumentClassificationJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDocumentClassificationJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDocumentClassificationJobsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListDocumentClassificationJobsRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDocumentClassificationJobsResponse {
@@ -1315,6 +2683,52 @@ pub struct ListDocumentClassifiersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDocumentClassifiersRequest {
+    /// Returns a builder for `ListDocumentClassifiersRequest`, checking its required members when built.
+    pub fn builder() -> ListDocumentClassifiersRequestBuilder {
+        ListDocumentClassifiersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDocumentClassifiersRequest`](struct.ListDocumentClassifiersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDocumentClassifiersRequestBuilder {
+    filter: Option<DocumentClassifierFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDocumentClassifiersRequestBuilder {
+    /// Sets the [`filter`](struct.ListDocumentClassifiersRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<DocumentClassifierFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDocumentClassifiersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDocumentClassifiersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDocumentClassifiersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDocumentClassifiersRequest, ::rusoto_core::BuildError> {
+        Ok(ListDocumentClassifiersRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDocumentClassifiersResponse {
@@ -1344,6 +2758,53 @@ pub struct ListDominantLanguageDetectionJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDominantLanguageDetectionJobsRequest {
+    /// Returns a builder for `ListDominantLanguageDetectionJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListDominantLanguageDetectionJobsRequestBuilder {
+        ListDominantLanguageDetectionJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDominantLanguageDetectionJobsRequest`](struct.ListDominantLanguageDetectionJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDominantLanguageDetectionJobsRequestBuilder {
+    filter: Option<DominantLanguageDetectionJobFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDominantLanguageDetectionJobsRequestBuilder {
+    /// Sets the [`filter`](struct.ListDominantLanguageDetectionJobsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<DominantLanguageDetectionJobFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDominantLanguageDetectionJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDominantLanguageDetectionJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDominantLanguageDetectionJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDominantLanguageDetectionJobsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListDominantLanguageDetectionJobsRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDominantLanguageDetectionJobsResponse {
@@ -1374,6 +2835,52 @@ pub struct ListEntitiesDetectionJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListEntitiesDetectionJobsRequest {
+    /// Returns a builder for `ListEntitiesDetectionJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListEntitiesDetectionJobsRequestBuilder {
+        ListEntitiesDetectionJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListEntitiesDetectionJobsRequest`](struct.ListEntitiesDetectionJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListEntitiesDetectionJobsRequestBuilder {
+    filter: Option<EntitiesDetectionJobFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListEntitiesDetectionJobsRequestBuilder {
+    /// Sets the [`filter`](struct.ListEntitiesDetectionJobsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<EntitiesDetectionJobFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListEntitiesDetectionJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListEntitiesDetectionJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListEntitiesDetectionJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListEntitiesDetectionJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListEntitiesDetectionJobsRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListEntitiesDetectionJobsResponse {
@@ -1403,6 +2910,52 @@ pub struct ListEntityRecognizersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListEntityRecognizersRequest {
+    /// Returns a builder for `ListEntityRecognizersRequest`, checking its required members when built.
+    pub fn builder() -> ListEntityRecognizersRequestBuilder {
+        ListEntityRecognizersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListEntityRecognizersRequest`](struct.ListEntityRecognizersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListEntityRecognizersRequestBuilder {
+    filter: Option<EntityRecognizerFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListEntityRecognizersRequestBuilder {
+    /// Sets the [`filter`](struct.ListEntityRecognizersRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<EntityRecognizerFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListEntityRecognizersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListEntityRecognizersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListEntityRecognizersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListEntityRecognizersRequest, ::rusoto_core::BuildError> {
+        Ok(ListEntityRecognizersRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListEntityRecognizersResponse {
@@ -1432,6 +2985,52 @@ pub struct ListKeyPhrasesDetectionJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListKeyPhrasesDetectionJobsRequest {
+    /// Returns a builder for `ListKeyPhrasesDetectionJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListKeyPhrasesDetectionJobsRequestBuilder {
+        ListKeyPhrasesDetectionJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListKeyPhrasesDetectionJobsRequest`](struct.ListKeyPhrasesDetectionJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListKeyPhrasesDetectionJobsRequestBuilder {
+    filter: Option<KeyPhrasesDetectionJobFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListKeyPhrasesDetectionJobsRequestBuilder {
+    /// Sets the [`filter`](struct.ListKeyPhrasesDetectionJobsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<KeyPhrasesDetectionJobFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListKeyPhrasesDetectionJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListKeyPhrasesDetectionJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListKeyPhrasesDetectionJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListKeyPhrasesDetectionJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListKeyPhrasesDetectionJobsRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListKeyPhrasesDetectionJobsResponse {
@@ -1461,15 +3060,61 @@ pub struct ListSentimentDetectionJobsRequest {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListSentimentDetectionJobsResponse {
-    /// <p>Identifies the next page of results to return.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>A list containing the properties of each job that is returned.</p>
-    #[serde(rename = "SentimentDetectionJobPropertiesList")]
+impl ListSentimentDetectionJobsRequest {
+    /// Returns a builder for `ListSentimentDetectionJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListSentimentDetectionJobsRequestBuilder {
+        ListSentimentDetectionJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSentimentDetectionJobsRequest`](struct.ListSentimentDetectionJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSentimentDetectionJobsRequestBuilder {
+    filter: Option<SentimentDetectionJobFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListSentimentDetectionJobsRequestBuilder {
+    /// Sets the [`filter`](struct.ListSentimentDetectionJobsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<SentimentDetectionJobFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListSentimentDetectionJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSentimentDetectionJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSentimentDetectionJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSentimentDetectionJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListSentimentDetectionJobsRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListSentimentDetectionJobsResponse {
+    /// <p>Identifies the next page of results to return.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>A list containing the properties of each job that is returned.</p>
+    #[serde(rename = "SentimentDetectionJobPropertiesList")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sentiment_detection_job_properties_list: Option<Vec<SentimentDetectionJobProperties>>,
 }
@@ -1481,6 +3126,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -1510,6 +3187,52 @@ pub struct ListTopicsDetectionJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListTopicsDetectionJobsRequest {
+    /// Returns a builder for `ListTopicsDetectionJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListTopicsDetectionJobsRequestBuilder {
+        ListTopicsDetectionJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTopicsDetectionJobsRequest`](struct.ListTopicsDetectionJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTopicsDetectionJobsRequestBuilder {
+    filter: Option<TopicsDetectionJobFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListTopicsDetectionJobsRequestBuilder {
+    /// Sets the [`filter`](struct.ListTopicsDetectionJobsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<TopicsDetectionJobFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListTopicsDetectionJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTopicsDetectionJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTopicsDetectionJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTopicsDetectionJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTopicsDetectionJobsRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTopicsDetectionJobsResponse {
@@ -1523,6 +3246,102 @@ pub struct ListTopicsDetectionJobsResponse {
     pub topics_detection_job_properties_list: Option<Vec<TopicsDetectionJobProperties>>,
 }
 
+/// <p>Filters the list of classifiers based on status. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ModelStatus {
+    /// <p><code>SUBMITTED</code></p>
+    Submitted,
+    /// <p><code>TRAINING</code></p>
+    Training,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>STOP_REQUESTED</code></p>
+    StopRequested,
+    /// <p><code>STOPPED</code></p>
+    Stopped,
+    /// <p><code>IN_ERROR</code></p>
+    InError,
+    /// <p><code>TRAINED</code></p>
+    Trained,
+    /// <p><code>TRAINED_WITH_WARNING</code></p>
+    TrainedWithWarning,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ModelStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ModelStatus::Submitted => "SUBMITTED",
+            ModelStatus::Training => "TRAINING",
+            ModelStatus::Deleting => "DELETING",
+            ModelStatus::StopRequested => "STOP_REQUESTED",
+            ModelStatus::Stopped => "STOPPED",
+            ModelStatus::InError => "IN_ERROR",
+            ModelStatus::Trained => "TRAINED",
+            ModelStatus::TrainedWithWarning => "TRAINED_WITH_WARNING",
+            ModelStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ModelStatus {
+    fn from(value: &'a str) -> ModelStatus {
+        match value {
+            "SUBMITTED" => ModelStatus::Submitted,
+            "TRAINING" => ModelStatus::Training,
+            "DELETING" => ModelStatus::Deleting,
+            "STOP_REQUESTED" => ModelStatus::StopRequested,
+            "STOPPED" => ModelStatus::Stopped,
+            "IN_ERROR" => ModelStatus::InError,
+            "TRAINED" => ModelStatus::Trained,
+            "TRAINED_WITH_WARNING" => ModelStatus::TrainedWithWarning,
+            _ => ModelStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ModelStatus {
+    fn from(value: String) -> ModelStatus {
+        ModelStatus::from(value.as_str())
+    }
+}
+
+impl From<ModelStatus> for String {
+    fn from(value: ModelStatus) -> String {
+        match value {
+            ModelStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ModelStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ModelStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ModelStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ModelStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ModelStatus::from(value))
+    }
+}
+
 /// <p><p>Provides configuration parameters for the output of topic detection jobs.</p> <p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OutputDataConfig {
@@ -1546,7 +3365,143 @@ pub struct PartOfSpeechTag {
     /// <p>Identifies the part of speech that the token represents.</p>
     #[serde(rename = "Tag")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag: Option<String>,
+    pub tag: Option<PartOfSpeechTagType>,
+}
+
+/// <p>Identifies the part of speech that the token represents.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PartOfSpeechTagType {
+    /// <p><code>ADJ</code></p>
+    Adj,
+    /// <p><code>ADP</code></p>
+    Adp,
+    /// <p><code>ADV</code></p>
+    Adv,
+    /// <p><code>AUX</code></p>
+    Aux,
+    /// <p><code>CONJ</code></p>
+    Conj,
+    /// <p><code>CCONJ</code></p>
+    Cconj,
+    /// <p><code>DET</code></p>
+    Det,
+    /// <p><code>INTJ</code></p>
+    Intj,
+    /// <p><code>NOUN</code></p>
+    Noun,
+    /// <p><code>NUM</code></p>
+    Num,
+    /// <p><code>O</code></p>
+    O,
+    /// <p><code>PART</code></p>
+    Part,
+    /// <p><code>PRON</code></p>
+    Pron,
+    /// <p><code>PROPN</code></p>
+    Propn,
+    /// <p><code>PUNCT</code></p>
+    Punct,
+    /// <p><code>SCONJ</code></p>
+    Sconj,
+    /// <p><code>SYM</code></p>
+    Sym,
+    /// <p><code>VERB</code></p>
+    Verb,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PartOfSpeechTagType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PartOfSpeechTagType::Adj => "ADJ",
+            PartOfSpeechTagType::Adp => "ADP",
+            PartOfSpeechTagType::Adv => "ADV",
+            PartOfSpeechTagType::Aux => "AUX",
+            PartOfSpeechTagType::Conj => "CONJ",
+            PartOfSpeechTagType::Cconj => "CCONJ",
+            PartOfSpeechTagType::Det => "DET",
+            PartOfSpeechTagType::Intj => "INTJ",
+            PartOfSpeechTagType::Noun => "NOUN",
+            PartOfSpeechTagType::Num => "NUM",
+            PartOfSpeechTagType::O => "O",
+            PartOfSpeechTagType::Part => "PART",
+            PartOfSpeechTagType::Pron => "PRON",
+            PartOfSpeechTagType::Propn => "PROPN",
+            PartOfSpeechTagType::Punct => "PUNCT",
+            PartOfSpeechTagType::Sconj => "SCONJ",
+            PartOfSpeechTagType::Sym => "SYM",
+            PartOfSpeechTagType::Verb => "VERB",
+            PartOfSpeechTagType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PartOfSpeechTagType {
+    fn from(value: &'a str) -> PartOfSpeechTagType {
+        match value {
+            "ADJ" => PartOfSpeechTagType::Adj,
+            "ADP" => PartOfSpeechTagType::Adp,
+            "ADV" => PartOfSpeechTagType::Adv,
+            "AUX" => PartOfSpeechTagType::Aux,
+            "CONJ" => PartOfSpeechTagType::Conj,
+            "CCONJ" => PartOfSpeechTagType::Cconj,
+            "DET" => PartOfSpeechTagType::Det,
+            "INTJ" => PartOfSpeechTagType::Intj,
+            "NOUN" => PartOfSpeechTagType::Noun,
+            "NUM" => PartOfSpeechTagType::Num,
+            "O" => PartOfSpeechTagType::O,
+            "PART" => PartOfSpeechTagType::Part,
+            "PRON" => PartOfSpeechTagType::Pron,
+            "PROPN" => PartOfSpeechTagType::Propn,
+            "PUNCT" => PartOfSpeechTagType::Punct,
+            "SCONJ" => PartOfSpeechTagType::Sconj,
+            "SYM" => PartOfSpeechTagType::Sym,
+            "VERB" => PartOfSpeechTagType::Verb,
+            _ => PartOfSpeechTagType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PartOfSpeechTagType {
+    fn from(value: String) -> PartOfSpeechTagType {
+        PartOfSpeechTagType::from(value.as_str())
+    }
+}
+
+impl From<PartOfSpeechTagType> for String {
+    fn from(value: PartOfSpeechTagType) -> String {
+        match value {
+            PartOfSpeechTagType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PartOfSpeechTagType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PartOfSpeechTagType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PartOfSpeechTagType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PartOfSpeechTagType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PartOfSpeechTagType::from(value))
+    }
 }
 
 /// <p>Provides information for filtering a list of dominant language detection jobs. For more information, see the operation.</p>
@@ -1559,15 +3514,17 @@ pub struct SentimentDetectionJobFilter {
     /// <p>Filters the list of jobs based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted after the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<EpochTimestamp>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Returns only jobs submitted before the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<EpochTimestamp>,
 }
 
 /// <p>Provides information about a sentiment detection job.</p>
@@ -1580,8 +3537,9 @@ pub struct SentimentDetectionJobProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the sentiment detection job ended.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The input data configuration that you supplied when you created the sentiment detection job.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1597,11 +3555,11 @@ pub struct SentimentDetectionJobProperties {
     /// <p>The current status of the sentiment detection job. If the status is <code>FAILED</code>, the <code>Messages</code> field shows the reason for the failure.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>The language code of the input documents.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>A description of the status of a job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1612,8 +3570,9 @@ pub struct SentimentDetectionJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the sentiment detection job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<EpochTimestamp>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1646,6 +3605,86 @@ pub struct SentimentScore {
     pub positive: Option<f32>,
 }
 
+/// <p>The sentiment detected in the document.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SentimentType {
+    /// <p><code>POSITIVE</code></p>
+    Positive,
+    /// <p><code>NEGATIVE</code></p>
+    Negative,
+    /// <p><code>NEUTRAL</code></p>
+    Neutral,
+    /// <p><code>MIXED</code></p>
+    Mixed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SentimentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SentimentType::Positive => "POSITIVE",
+            SentimentType::Negative => "NEGATIVE",
+            SentimentType::Neutral => "NEUTRAL",
+            SentimentType::Mixed => "MIXED",
+            SentimentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SentimentType {
+    fn from(value: &'a str) -> SentimentType {
+        match value {
+            "POSITIVE" => SentimentType::Positive,
+            "NEGATIVE" => SentimentType::Negative,
+            "NEUTRAL" => SentimentType::Neutral,
+            "MIXED" => SentimentType::Mixed,
+            _ => SentimentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SentimentType {
+    fn from(value: String) -> SentimentType {
+        SentimentType::from(value.as_str())
+    }
+}
+
+impl From<SentimentType> for String {
+    fn from(value: SentimentType) -> String {
+        match value {
+            SentimentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SentimentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SentimentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SentimentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SentimentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SentimentType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartDocumentClassificationJobRequest {
     /// <p>A unique identifier for the request. If you do not set the client request token, Amazon Comprehend generates one.</p>
@@ -1678,6 +3717,101 @@ pub struct StartDocumentClassificationJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl StartDocumentClassificationJobRequest {
+    /// Returns a builder for `StartDocumentClassificationJobRequest`, checking its required members when built.
+    pub fn builder() -> StartDocumentClassificationJobRequestBuilder {
+        StartDocumentClassificationJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartDocumentClassificationJobRequest`](struct.StartDocumentClassificationJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StartDocumentClassificationJobRequestBuilder {
+    client_request_token: Option<String>,
+    data_access_role_arn: Option<String>,
+    document_classifier_arn: Option<String>,
+    input_data_config: Option<InputDataConfig>,
+    job_name: Option<String>,
+    output_data_config: Option<OutputDataConfig>,
+    volume_kms_key_id: Option<String>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl StartDocumentClassificationJobRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartDocumentClassificationJobRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_access_role_arn`](struct.StartDocumentClassificationJobRequest.html#structfield.data_access_role_arn) member.
+    pub fn data_access_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_access_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_classifier_arn`](struct.StartDocumentClassificationJobRequest.html#structfield.document_classifier_arn) member.
+    pub fn document_classifier_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_classifier_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_data_config`](struct.StartDocumentClassificationJobRequest.html#structfield.input_data_config) member.
+    pub fn input_data_config<T: Into<InputDataConfig>>(mut self, value: T) -> Self {
+        self.input_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_name`](struct.StartDocumentClassificationJobRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_data_config`](struct.StartDocumentClassificationJobRequest.html#structfield.output_data_config) member.
+    pub fn output_data_config<T: Into<OutputDataConfig>>(mut self, value: T) -> Self {
+        self.output_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_kms_key_id`](struct.StartDocumentClassificationJobRequest.html#structfield.volume_kms_key_id) member.
+    pub fn volume_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.StartDocumentClassificationJobRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartDocumentClassificationJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartDocumentClassificationJobRequest, ::rusoto_core::BuildError>
+    {
+        Ok(StartDocumentClassificationJobRequest {
+            client_request_token: self.client_request_token,
+            data_access_role_arn: self
+                .data_access_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("data_access_role_arn"))?,
+            document_classifier_arn: self.document_classifier_arn.ok_or(
+                ::rusoto_core::BuildError::missing("document_classifier_arn"),
+            )?,
+            input_data_config: self
+                .input_data_config
+                .ok_or(::rusoto_core::BuildError::missing("input_data_config"))?,
+            job_name: self.job_name,
+            output_data_config: self
+                .output_data_config
+                .ok_or(::rusoto_core::BuildError::missing("output_data_config"))?,
+            volume_kms_key_id: self.volume_kms_key_id,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartDocumentClassificationJobResponse {
@@ -1688,7 +3822,7 @@ pub struct StartDocumentClassificationJobResponse {
     /// <p><p>The status of the job:</p> <ul> <li> <p>SUBMITTED - The job has been received and queued for processing.</p> </li> <li> <p>IN<em>PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. For details, use the operation.</p> </li> <li> <p>STOP</em>REQUESTED - Amazon Comprehend has received a stop request for the job and is processing the request.</p> </li> <li> <p>STOPPED - The job was successfully stopped without completing.</p> </li> </ul></p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1720,6 +3854,91 @@ pub struct StartDominantLanguageDetectionJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl StartDominantLanguageDetectionJobRequest {
+    /// Returns a builder for `StartDominantLanguageDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> StartDominantLanguageDetectionJobRequestBuilder {
+        StartDominantLanguageDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartDominantLanguageDetectionJobRequest`](struct.StartDominantLanguageDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StartDominantLanguageDetectionJobRequestBuilder {
+    client_request_token: Option<String>,
+    data_access_role_arn: Option<String>,
+    input_data_config: Option<InputDataConfig>,
+    job_name: Option<String>,
+    output_data_config: Option<OutputDataConfig>,
+    volume_kms_key_id: Option<String>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl StartDominantLanguageDetectionJobRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartDominantLanguageDetectionJobRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_access_role_arn`](struct.StartDominantLanguageDetectionJobRequest.html#structfield.data_access_role_arn) member.
+    pub fn data_access_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_access_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_data_config`](struct.StartDominantLanguageDetectionJobRequest.html#structfield.input_data_config) member.
+    pub fn input_data_config<T: Into<InputDataConfig>>(mut self, value: T) -> Self {
+        self.input_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_name`](struct.StartDominantLanguageDetectionJobRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_data_config`](struct.StartDominantLanguageDetectionJobRequest.html#structfield.output_data_config) member.
+    pub fn output_data_config<T: Into<OutputDataConfig>>(mut self, value: T) -> Self {
+        self.output_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_kms_key_id`](struct.StartDominantLanguageDetectionJobRequest.html#structfield.volume_kms_key_id) member.
+    pub fn volume_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.StartDominantLanguageDetectionJobRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartDominantLanguageDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartDominantLanguageDetectionJobRequest, ::rusoto_core::BuildError>
+    {
+        Ok(StartDominantLanguageDetectionJobRequest {
+            client_request_token: self.client_request_token,
+            data_access_role_arn: self
+                .data_access_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("data_access_role_arn"))?,
+            input_data_config: self
+                .input_data_config
+                .ok_or(::rusoto_core::BuildError::missing("input_data_config"))?,
+            job_name: self.job_name,
+            output_data_config: self
+                .output_data_config
+                .ok_or(::rusoto_core::BuildError::missing("output_data_config"))?,
+            volume_kms_key_id: self.volume_kms_key_id,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartDominantLanguageDetectionJobResponse {
@@ -1730,10 +3949,10 @@ pub struct StartDominantLanguageDetectionJobResponse {
     /// <p><p>The status of the job. </p> <ul> <li> <p>SUBMITTED - The job has been received and is queued for processing.</p> </li> <li> <p>IN_PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. To get details, use the operation.</p> </li> </ul></p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct StartEntitiesDetectionJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -1755,7 +3974,7 @@ pub struct StartEntitiesDetectionJobRequest {
     pub job_name: Option<String>,
     /// <p>The language of the input documents. All documents must be in the same language. You can specify any of the languages supported by Amazon Comprehend: English ("en"), Spanish ("es"), French ("fr"), German ("de"), Italian ("it"), or Portuguese ("pt"). If custom entities recognition is used, this parameter is ignored and the language used for training the model is used instead.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>Specifies where to send the output files.</p>
     #[serde(rename = "OutputDataConfig")]
     pub output_data_config: OutputDataConfig,
@@ -1769,6 +3988,108 @@ pub struct StartEntitiesDetectionJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl StartEntitiesDetectionJobRequest {
+    /// Returns a builder for `StartEntitiesDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> StartEntitiesDetectionJobRequestBuilder {
+        StartEntitiesDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartEntitiesDetectionJobRequest`](struct.StartEntitiesDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StartEntitiesDetectionJobRequestBuilder {
+    client_request_token: Option<String>,
+    data_access_role_arn: Option<String>,
+    entity_recognizer_arn: Option<String>,
+    input_data_config: Option<InputDataConfig>,
+    job_name: Option<String>,
+    language_code: Option<LanguageCode>,
+    output_data_config: Option<OutputDataConfig>,
+    volume_kms_key_id: Option<String>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl StartEntitiesDetectionJobRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartEntitiesDetectionJobRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_access_role_arn`](struct.StartEntitiesDetectionJobRequest.html#structfield.data_access_role_arn) member.
+    pub fn data_access_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_access_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`entity_recognizer_arn`](struct.StartEntitiesDetectionJobRequest.html#structfield.entity_recognizer_arn) member.
+    pub fn entity_recognizer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_recognizer_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_data_config`](struct.StartEntitiesDetectionJobRequest.html#structfield.input_data_config) member.
+    pub fn input_data_config<T: Into<InputDataConfig>>(mut self, value: T) -> Self {
+        self.input_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_name`](struct.StartEntitiesDetectionJobRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`language_code`](struct.StartEntitiesDetectionJobRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_data_config`](struct.StartEntitiesDetectionJobRequest.html#structfield.output_data_config) member.
+    pub fn output_data_config<T: Into<OutputDataConfig>>(mut self, value: T) -> Self {
+        self.output_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_kms_key_id`](struct.StartEntitiesDetectionJobRequest.html#structfield.volume_kms_key_id) member.
+    pub fn volume_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.StartEntitiesDetectionJobRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartEntitiesDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartEntitiesDetectionJobRequest, ::rusoto_core::BuildError> {
+        Ok(StartEntitiesDetectionJobRequest {
+            client_request_token: self.client_request_token,
+            data_access_role_arn: self
+                .data_access_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("data_access_role_arn"))?,
+            entity_recognizer_arn: self.entity_recognizer_arn,
+            input_data_config: self
+                .input_data_config
+                .ok_or(::rusoto_core::BuildError::missing("input_data_config"))?,
+            job_name: self.job_name,
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            output_data_config: self
+                .output_data_config
+                .ok_or(::rusoto_core::BuildError::missing("output_data_config"))?,
+            volume_kms_key_id: self.volume_kms_key_id,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartEntitiesDetectionJobResponse {
@@ -1779,10 +4100,10 @@ pub struct StartEntitiesDetectionJobResponse {
     /// <p><p>The status of the job. </p> <ul> <li> <p>SUBMITTED - The job has been received and is queued for processing.</p> </li> <li> <p>IN<em>PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. To get details, use the operation.</p> </li> <li> <p>STOP</em>REQUESTED - Amazon Comprehend has received a stop request for the job and is processing the request.</p> </li> <li> <p>STOPPED - The job was successfully stopped without completing.</p> </li> </ul></p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct StartKeyPhrasesDetectionJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -1800,7 +4121,7 @@ pub struct StartKeyPhrasesDetectionJobRequest {
     pub job_name: Option<String>,
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>Specifies where to send the output files.</p>
     #[serde(rename = "OutputDataConfig")]
     pub output_data_config: OutputDataConfig,
@@ -1814,6 +4135,100 @@ pub struct StartKeyPhrasesDetectionJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl StartKeyPhrasesDetectionJobRequest {
+    /// Returns a builder for `StartKeyPhrasesDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> StartKeyPhrasesDetectionJobRequestBuilder {
+        StartKeyPhrasesDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartKeyPhrasesDetectionJobRequest`](struct.StartKeyPhrasesDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StartKeyPhrasesDetectionJobRequestBuilder {
+    client_request_token: Option<String>,
+    data_access_role_arn: Option<String>,
+    input_data_config: Option<InputDataConfig>,
+    job_name: Option<String>,
+    language_code: Option<LanguageCode>,
+    output_data_config: Option<OutputDataConfig>,
+    volume_kms_key_id: Option<String>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl StartKeyPhrasesDetectionJobRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartKeyPhrasesDetectionJobRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_access_role_arn`](struct.StartKeyPhrasesDetectionJobRequest.html#structfield.data_access_role_arn) member.
+    pub fn data_access_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_access_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_data_config`](struct.StartKeyPhrasesDetectionJobRequest.html#structfield.input_data_config) member.
+    pub fn input_data_config<T: Into<InputDataConfig>>(mut self, value: T) -> Self {
+        self.input_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_name`](struct.StartKeyPhrasesDetectionJobRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`language_code`](struct.StartKeyPhrasesDetectionJobRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_data_config`](struct.StartKeyPhrasesDetectionJobRequest.html#structfield.output_data_config) member.
+    pub fn output_data_config<T: Into<OutputDataConfig>>(mut self, value: T) -> Self {
+        self.output_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_kms_key_id`](struct.StartKeyPhrasesDetectionJobRequest.html#structfield.volume_kms_key_id) member.
+    pub fn volume_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.StartKeyPhrasesDetectionJobRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartKeyPhrasesDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartKeyPhrasesDetectionJobRequest, ::rusoto_core::BuildError> {
+        Ok(StartKeyPhrasesDetectionJobRequest {
+            client_request_token: self.client_request_token,
+            data_access_role_arn: self
+                .data_access_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("data_access_role_arn"))?,
+            input_data_config: self
+                .input_data_config
+                .ok_or(::rusoto_core::BuildError::missing("input_data_config"))?,
+            job_name: self.job_name,
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            output_data_config: self
+                .output_data_config
+                .ok_or(::rusoto_core::BuildError::missing("output_data_config"))?,
+            volume_kms_key_id: self.volume_kms_key_id,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartKeyPhrasesDetectionJobResponse {
@@ -1824,10 +4239,10 @@ pub struct StartKeyPhrasesDetectionJobResponse {
     /// <p><p>The status of the job. </p> <ul> <li> <p>SUBMITTED - The job has been received and is queued for processing.</p> </li> <li> <p>IN_PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. To get details, use the operation.</p> </li> </ul></p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct StartSentimentDetectionJobRequest {
     /// <p>A unique identifier for the request. If you don't set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -1845,7 +4260,7 @@ pub struct StartSentimentDetectionJobRequest {
     pub job_name: Option<String>,
     /// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
     #[serde(rename = "LanguageCode")]
-    pub language_code: String,
+    pub language_code: LanguageCode,
     /// <p>Specifies where to send the output files. </p>
     #[serde(rename = "OutputDataConfig")]
     pub output_data_config: OutputDataConfig,
@@ -1859,20 +4274,114 @@ pub struct StartSentimentDetectionJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct StartSentimentDetectionJobResponse {
-    /// <p>The identifier generated for the job. To get the status of a job, use this identifier with the operation.</p>
-    #[serde(rename = "JobId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_id: Option<String>,
-    /// <p><p>The status of the job. </p> <ul> <li> <p>SUBMITTED - The job has been received and is queued for processing.</p> </li> <li> <p>IN_PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. To get details, use the operation.</p> </li> </ul></p>
-    #[serde(rename = "JobStatus")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+impl StartSentimentDetectionJobRequest {
+    /// Returns a builder for `StartSentimentDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> StartSentimentDetectionJobRequestBuilder {
+        StartSentimentDetectionJobRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// Builds a [`StartSentimentDetectionJobRequest`](struct.StartSentimentDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StartSentimentDetectionJobRequestBuilder {
+    client_request_token: Option<String>,
+    data_access_role_arn: Option<String>,
+    input_data_config: Option<InputDataConfig>,
+    job_name: Option<String>,
+    language_code: Option<LanguageCode>,
+    output_data_config: Option<OutputDataConfig>,
+    volume_kms_key_id: Option<String>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl StartSentimentDetectionJobRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartSentimentDetectionJobRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_access_role_arn`](struct.StartSentimentDetectionJobRequest.html#structfield.data_access_role_arn) member.
+    pub fn data_access_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_access_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_data_config`](struct.StartSentimentDetectionJobRequest.html#structfield.input_data_config) member.
+    pub fn input_data_config<T: Into<InputDataConfig>>(mut self, value: T) -> Self {
+        self.input_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_name`](struct.StartSentimentDetectionJobRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`language_code`](struct.StartSentimentDetectionJobRequest.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_data_config`](struct.StartSentimentDetectionJobRequest.html#structfield.output_data_config) member.
+    pub fn output_data_config<T: Into<OutputDataConfig>>(mut self, value: T) -> Self {
+        self.output_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_kms_key_id`](struct.StartSentimentDetectionJobRequest.html#structfield.volume_kms_key_id) member.
+    pub fn volume_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.StartSentimentDetectionJobRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartSentimentDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartSentimentDetectionJobRequest, ::rusoto_core::BuildError> {
+        Ok(StartSentimentDetectionJobRequest {
+            client_request_token: self.client_request_token,
+            data_access_role_arn: self
+                .data_access_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("data_access_role_arn"))?,
+            input_data_config: self
+                .input_data_config
+                .ok_or(::rusoto_core::BuildError::missing("input_data_config"))?,
+            job_name: self.job_name,
+            language_code: self
+                .language_code
+                .ok_or(::rusoto_core::BuildError::missing("language_code"))?,
+            output_data_config: self
+                .output_data_config
+                .ok_or(::rusoto_core::BuildError::missing("output_data_config"))?,
+            volume_kms_key_id: self.volume_kms_key_id,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct StartSentimentDetectionJobResponse {
+    /// <p>The identifier generated for the job. To get the status of a job, use this identifier with the operation.</p>
+    #[serde(rename = "JobId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    /// <p><p>The status of the job. </p> <ul> <li> <p>SUBMITTED - The job has been received and is queued for processing.</p> </li> <li> <p>IN_PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. To get details, use the operation.</p> </li> </ul></p>
+    #[serde(rename = "JobStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_status: Option<JobStatus>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartTopicsDetectionJobRequest {
     /// <p>A unique identifier for the request. If you do not set the client request token, Amazon Comprehend generates one.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -1905,6 +4414,98 @@ pub struct StartTopicsDetectionJobRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl StartTopicsDetectionJobRequest {
+    /// Returns a builder for `StartTopicsDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> StartTopicsDetectionJobRequestBuilder {
+        StartTopicsDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartTopicsDetectionJobRequest`](struct.StartTopicsDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StartTopicsDetectionJobRequestBuilder {
+    client_request_token: Option<String>,
+    data_access_role_arn: Option<String>,
+    input_data_config: Option<InputDataConfig>,
+    job_name: Option<String>,
+    number_of_topics: Option<i64>,
+    output_data_config: Option<OutputDataConfig>,
+    volume_kms_key_id: Option<String>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl StartTopicsDetectionJobRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartTopicsDetectionJobRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_access_role_arn`](struct.StartTopicsDetectionJobRequest.html#structfield.data_access_role_arn) member.
+    pub fn data_access_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_access_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_data_config`](struct.StartTopicsDetectionJobRequest.html#structfield.input_data_config) member.
+    pub fn input_data_config<T: Into<InputDataConfig>>(mut self, value: T) -> Self {
+        self.input_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_name`](struct.StartTopicsDetectionJobRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_topics`](struct.StartTopicsDetectionJobRequest.html#structfield.number_of_topics) member.
+    pub fn number_of_topics<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_topics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_data_config`](struct.StartTopicsDetectionJobRequest.html#structfield.output_data_config) member.
+    pub fn output_data_config<T: Into<OutputDataConfig>>(mut self, value: T) -> Self {
+        self.output_data_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_kms_key_id`](struct.StartTopicsDetectionJobRequest.html#structfield.volume_kms_key_id) member.
+    pub fn volume_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.StartTopicsDetectionJobRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartTopicsDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartTopicsDetectionJobRequest, ::rusoto_core::BuildError> {
+        Ok(StartTopicsDetectionJobRequest {
+            client_request_token: self.client_request_token,
+            data_access_role_arn: self
+                .data_access_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("data_access_role_arn"))?,
+            input_data_config: self
+                .input_data_config
+                .ok_or(::rusoto_core::BuildError::missing("input_data_config"))?,
+            job_name: self.job_name,
+            number_of_topics: self.number_of_topics,
+            output_data_config: self
+                .output_data_config
+                .ok_or(::rusoto_core::BuildError::missing("output_data_config"))?,
+            volume_kms_key_id: self.volume_kms_key_id,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartTopicsDetectionJobResponse {
@@ -1915,7 +4516,7 @@ pub struct StartTopicsDetectionJobResponse {
     /// <p><p>The status of the job: </p> <ul> <li> <p>SUBMITTED - The job has been received and is queued for processing.</p> </li> <li> <p>IN_PROGRESS - Amazon Comprehend is processing the job.</p> </li> <li> <p>COMPLETED - The job was successfully completed and the output is available.</p> </li> <li> <p>FAILED - The job did not complete. To get details, use the <code>DescribeTopicDetectionJob</code> operation.</p> </li> </ul></p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1925,6 +4526,39 @@ pub struct StopDominantLanguageDetectionJobRequest {
     pub job_id: String,
 }
 
+impl StopDominantLanguageDetectionJobRequest {
+    /// Returns a builder for `StopDominantLanguageDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> StopDominantLanguageDetectionJobRequestBuilder {
+        StopDominantLanguageDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopDominantLanguageDetectionJobRequest`](struct.StopDominantLanguageDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StopDominantLanguageDetectionJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl StopDominantLanguageDetectionJobRequestBuilder {
+    /// Sets the [`job_id`](struct.StopDominantLanguageDetectionJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopDominantLanguageDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopDominantLanguageDetectionJobRequest, ::rusoto_core::BuildError>
+    {
+        Ok(StopDominantLanguageDetectionJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopDominantLanguageDetectionJobResponse {
@@ -1935,7 +4569,7 @@ pub struct StopDominantLanguageDetectionJobResponse {
     /// <p>Either <code>STOP_REQUESTED</code> if the job is currently running, or <code>STOPPED</code> if the job was previously stopped with the <code>StopDominantLanguageDetectionJob</code> operation.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1945,6 +4579,38 @@ pub struct StopEntitiesDetectionJobRequest {
     pub job_id: String,
 }
 
+impl StopEntitiesDetectionJobRequest {
+    /// Returns a builder for `StopEntitiesDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> StopEntitiesDetectionJobRequestBuilder {
+        StopEntitiesDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopEntitiesDetectionJobRequest`](struct.StopEntitiesDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StopEntitiesDetectionJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl StopEntitiesDetectionJobRequestBuilder {
+    /// Sets the [`job_id`](struct.StopEntitiesDetectionJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopEntitiesDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopEntitiesDetectionJobRequest, ::rusoto_core::BuildError> {
+        Ok(StopEntitiesDetectionJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopEntitiesDetectionJobResponse {
@@ -1955,7 +4621,7 @@ pub struct StopEntitiesDetectionJobResponse {
     /// <p>Either <code>STOP_REQUESTED</code> if the job is currently running, or <code>STOPPED</code> if the job was previously stopped with the <code>StopEntitiesDetectionJob</code> operation.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1965,6 +4631,38 @@ pub struct StopKeyPhrasesDetectionJobRequest {
     pub job_id: String,
 }
 
+impl StopKeyPhrasesDetectionJobRequest {
+    /// Returns a builder for `StopKeyPhrasesDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> StopKeyPhrasesDetectionJobRequestBuilder {
+        StopKeyPhrasesDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopKeyPhrasesDetectionJobRequest`](struct.StopKeyPhrasesDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StopKeyPhrasesDetectionJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl StopKeyPhrasesDetectionJobRequestBuilder {
+    /// Sets the [`job_id`](struct.StopKeyPhrasesDetectionJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopKeyPhrasesDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopKeyPhrasesDetectionJobRequest, ::rusoto_core::BuildError> {
+        Ok(StopKeyPhrasesDetectionJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopKeyPhrasesDetectionJobResponse {
@@ -1975,7 +4673,7 @@ pub struct StopKeyPhrasesDetectionJobResponse {
     /// <p>Either <code>STOP_REQUESTED</code> if the job is currently running, or <code>STOPPED</code> if the job was previously stopped with the <code>StopKeyPhrasesDetectionJob</code> operation.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1985,6 +4683,38 @@ pub struct StopSentimentDetectionJobRequest {
     pub job_id: String,
 }
 
+impl StopSentimentDetectionJobRequest {
+    /// Returns a builder for `StopSentimentDetectionJobRequest`, checking its required members when built.
+    pub fn builder() -> StopSentimentDetectionJobRequestBuilder {
+        StopSentimentDetectionJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopSentimentDetectionJobRequest`](struct.StopSentimentDetectionJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StopSentimentDetectionJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl StopSentimentDetectionJobRequestBuilder {
+    /// Sets the [`job_id`](struct.StopSentimentDetectionJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopSentimentDetectionJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopSentimentDetectionJobRequest, ::rusoto_core::BuildError> {
+        Ok(StopSentimentDetectionJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopSentimentDetectionJobResponse {
@@ -1995,7 +4725,7 @@ pub struct StopSentimentDetectionJobResponse {
     /// <p>Either <code>STOP_REQUESTED</code> if the job is currently running, or <code>STOPPED</code> if the job was previously stopped with the <code>StopSentimentDetectionJob</code> operation.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2005,6 +4735,39 @@ pub struct StopTrainingDocumentClassifierRequest {
     pub document_classifier_arn: String,
 }
 
+impl StopTrainingDocumentClassifierRequest {
+    /// Returns a builder for `StopTrainingDocumentClassifierRequest`, checking its required members when built.
+    pub fn builder() -> StopTrainingDocumentClassifierRequestBuilder {
+        StopTrainingDocumentClassifierRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopTrainingDocumentClassifierRequest`](struct.StopTrainingDocumentClassifierRequest.html).
+#[derive(Default, Debug)]
+pub struct StopTrainingDocumentClassifierRequestBuilder {
+    document_classifier_arn: Option<String>,
+}
+
+impl StopTrainingDocumentClassifierRequestBuilder {
+    /// Sets the [`document_classifier_arn`](struct.StopTrainingDocumentClassifierRequest.html#structfield.document_classifier_arn) member.
+    pub fn document_classifier_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_classifier_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopTrainingDocumentClassifierRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopTrainingDocumentClassifierRequest, ::rusoto_core::BuildError>
+    {
+        Ok(StopTrainingDocumentClassifierRequest {
+            document_classifier_arn: self.document_classifier_arn.ok_or(
+                ::rusoto_core::BuildError::missing("document_classifier_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopTrainingDocumentClassifierResponse {}
@@ -2016,10 +4779,130 @@ pub struct StopTrainingEntityRecognizerRequest {
     pub entity_recognizer_arn: String,
 }
 
+impl StopTrainingEntityRecognizerRequest {
+    /// Returns a builder for `StopTrainingEntityRecognizerRequest`, checking its required members when built.
+    pub fn builder() -> StopTrainingEntityRecognizerRequestBuilder {
+        StopTrainingEntityRecognizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopTrainingEntityRecognizerRequest`](struct.StopTrainingEntityRecognizerRequest.html).
+#[derive(Default, Debug)]
+pub struct StopTrainingEntityRecognizerRequestBuilder {
+    entity_recognizer_arn: Option<String>,
+}
+
+impl StopTrainingEntityRecognizerRequestBuilder {
+    /// Sets the [`entity_recognizer_arn`](struct.StopTrainingEntityRecognizerRequest.html#structfield.entity_recognizer_arn) member.
+    pub fn entity_recognizer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_recognizer_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopTrainingEntityRecognizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopTrainingEntityRecognizerRequest, ::rusoto_core::BuildError> {
+        Ok(StopTrainingEntityRecognizerRequest {
+            entity_recognizer_arn: self
+                .entity_recognizer_arn
+                .ok_or(::rusoto_core::BuildError::missing("entity_recognizer_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopTrainingEntityRecognizerResponse {}
 
+/// <p>The language of the input documents. You can specify any of the primary languages supported by Amazon Comprehend: German ("de"), English ("en"), Spanish ("es"), French ("fr"), Italian ("it"), or Portuguese ("pt"). All documents must be in the same language.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SyntaxLanguageCode {
+    /// <p><code>en</code></p>
+    En,
+    /// <p><code>es</code></p>
+    Es,
+    /// <p><code>fr</code></p>
+    Fr,
+    /// <p><code>de</code></p>
+    De,
+    /// <p><code>it</code></p>
+    It,
+    /// <p><code>pt</code></p>
+    Pt,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SyntaxLanguageCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SyntaxLanguageCode::En => "en",
+            SyntaxLanguageCode::Es => "es",
+            SyntaxLanguageCode::Fr => "fr",
+            SyntaxLanguageCode::De => "de",
+            SyntaxLanguageCode::It => "it",
+            SyntaxLanguageCode::Pt => "pt",
+            SyntaxLanguageCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SyntaxLanguageCode {
+    fn from(value: &'a str) -> SyntaxLanguageCode {
+        match value {
+            "en" => SyntaxLanguageCode::En,
+            "es" => SyntaxLanguageCode::Es,
+            "fr" => SyntaxLanguageCode::Fr,
+            "de" => SyntaxLanguageCode::De,
+            "it" => SyntaxLanguageCode::It,
+            "pt" => SyntaxLanguageCode::Pt,
+            _ => SyntaxLanguageCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SyntaxLanguageCode {
+    fn from(value: String) -> SyntaxLanguageCode {
+        SyntaxLanguageCode::from(value.as_str())
+    }
+}
+
+impl From<SyntaxLanguageCode> for String {
+    fn from(value: SyntaxLanguageCode) -> String {
+        match value {
+            SyntaxLanguageCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SyntaxLanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SyntaxLanguageCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SyntaxLanguageCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SyntaxLanguageCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SyntaxLanguageCode::from(value))
+    }
+}
+
 /// <p>Represents a work in the input text that was recognized and assigned a part of speech. There is one syntax token record for each word in the source text.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2068,6 +4951,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -2082,15 +5005,17 @@ pub struct TopicsDetectionJobFilter {
     /// <p>Filters the list of topic detection jobs based on job status. Returns only jobs with the specified status.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Only returns jobs submitted after the specified time. Jobs are returned in ascending order, oldest to newest.</p>
     #[serde(rename = "SubmitTimeAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_after: Option<f64>,
+    pub submit_time_after: Option<EpochTimestamp>,
     /// <p>Filters the list of jobs based on the time that the job was submitted for processing. Only returns jobs submitted before the specified time. Jobs are returned in descending order, newest to oldest.</p>
     #[serde(rename = "SubmitTimeBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time_before: Option<f64>,
+    pub submit_time_before: Option<EpochTimestamp>,
 }
 
 /// <p>Provides information about a topic detection job.</p>
@@ -2103,8 +5028,9 @@ pub struct TopicsDetectionJobProperties {
     pub data_access_role_arn: Option<String>,
     /// <p>The time that the topic detection job was completed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The input data configuration supplied when you created the topic detection job.</p>
     #[serde(rename = "InputDataConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2120,7 +5046,7 @@ pub struct TopicsDetectionJobProperties {
     /// <p>The current status of the topic detection job. If the status is <code>Failed</code>, the reason for the failure is shown in the <code>Message</code> field.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<JobStatus>,
     /// <p>A description for the status of a job.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2135,8 +5061,9 @@ pub struct TopicsDetectionJobProperties {
     pub output_data_config: Option<OutputDataConfig>,
     /// <p>The time that the topic detection job was submitted for processing.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<EpochTimestamp>,
     /// <p><p>ID for the AWS Key Management Service (KMS) key that Amazon Comprehend uses to encrypt data on the storage volume attached to the ML compute instance(s) that process the analysis job. The VolumeKmsKeyId can be either of the following formats:</p> <ul> <li> <p>KMS Key ID: <code>&quot;1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> <li> <p>Amazon Resource Name (ARN) of a KMS Key: <code>&quot;arn:aws:kms:us-west-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab&quot;</code> </p> </li> </ul></p>
     #[serde(rename = "VolumeKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2157,6 +5084,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -5217,6 +8184,303 @@ pub trait Comprehend {
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
 }
+impl<T: ?Sized + Comprehend> Comprehend for ::std::sync::Arc<T> {
+    fn batch_detect_dominant_language(
+        &self,
+        input: BatchDetectDominantLanguageRequest,
+    ) -> RusotoFuture<BatchDetectDominantLanguageResponse, BatchDetectDominantLanguageError> {
+        (**self).batch_detect_dominant_language(input)
+    }
+    fn batch_detect_entities(
+        &self,
+        input: BatchDetectEntitiesRequest,
+    ) -> RusotoFuture<BatchDetectEntitiesResponse, BatchDetectEntitiesError> {
+        (**self).batch_detect_entities(input)
+    }
+    fn batch_detect_key_phrases(
+        &self,
+        input: BatchDetectKeyPhrasesRequest,
+    ) -> RusotoFuture<BatchDetectKeyPhrasesResponse, BatchDetectKeyPhrasesError> {
+        (**self).batch_detect_key_phrases(input)
+    }
+    fn batch_detect_sentiment(
+        &self,
+        input: BatchDetectSentimentRequest,
+    ) -> RusotoFuture<BatchDetectSentimentResponse, BatchDetectSentimentError> {
+        (**self).batch_detect_sentiment(input)
+    }
+    fn batch_detect_syntax(
+        &self,
+        input: BatchDetectSyntaxRequest,
+    ) -> RusotoFuture<BatchDetectSyntaxResponse, BatchDetectSyntaxError> {
+        (**self).batch_detect_syntax(input)
+    }
+    fn create_document_classifier(
+        &self,
+        input: CreateDocumentClassifierRequest,
+    ) -> RusotoFuture<CreateDocumentClassifierResponse, CreateDocumentClassifierError> {
+        (**self).create_document_classifier(input)
+    }
+    fn create_entity_recognizer(
+        &self,
+        input: CreateEntityRecognizerRequest,
+    ) -> RusotoFuture<CreateEntityRecognizerResponse, CreateEntityRecognizerError> {
+        (**self).create_entity_recognizer(input)
+    }
+    fn delete_document_classifier(
+        &self,
+        input: DeleteDocumentClassifierRequest,
+    ) -> RusotoFuture<DeleteDocumentClassifierResponse, DeleteDocumentClassifierError> {
+        (**self).delete_document_classifier(input)
+    }
+    fn delete_entity_recognizer(
+        &self,
+        input: DeleteEntityRecognizerRequest,
+    ) -> RusotoFuture<DeleteEntityRecognizerResponse, DeleteEntityRecognizerError> {
+        (**self).delete_entity_recognizer(input)
+    }
+    fn describe_document_classification_job(
+        &self,
+        input: DescribeDocumentClassificationJobRequest,
+    ) -> RusotoFuture<
+        DescribeDocumentClassificationJobResponse,
+        DescribeDocumentClassificationJobError,
+    > {
+        (**self).describe_document_classification_job(input)
+    }
+    fn describe_document_classifier(
+        &self,
+        input: DescribeDocumentClassifierRequest,
+    ) -> RusotoFuture<DescribeDocumentClassifierResponse, DescribeDocumentClassifierError> {
+        (**self).describe_document_classifier(input)
+    }
+    fn describe_dominant_language_detection_job(
+        &self,
+        input: DescribeDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<
+        DescribeDominantLanguageDetectionJobResponse,
+        DescribeDominantLanguageDetectionJobError,
+    > {
+        (**self).describe_dominant_language_detection_job(input)
+    }
+    fn describe_entities_detection_job(
+        &self,
+        input: DescribeEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<DescribeEntitiesDetectionJobResponse, DescribeEntitiesDetectionJobError> {
+        (**self).describe_entities_detection_job(input)
+    }
+    fn describe_entity_recognizer(
+        &self,
+        input: DescribeEntityRecognizerRequest,
+    ) -> RusotoFuture<DescribeEntityRecognizerResponse, DescribeEntityRecognizerError> {
+        (**self).describe_entity_recognizer(input)
+    }
+    fn describe_key_phrases_detection_job(
+        &self,
+        input: DescribeKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<DescribeKeyPhrasesDetectionJobResponse, DescribeKeyPhrasesDetectionJobError>
+    {
+        (**self).describe_key_phrases_detection_job(input)
+    }
+    fn describe_sentiment_detection_job(
+        &self,
+        input: DescribeSentimentDetectionJobRequest,
+    ) -> RusotoFuture<DescribeSentimentDetectionJobResponse, DescribeSentimentDetectionJobError>
+    {
+        (**self).describe_sentiment_detection_job(input)
+    }
+    fn describe_topics_detection_job(
+        &self,
+        input: DescribeTopicsDetectionJobRequest,
+    ) -> RusotoFuture<DescribeTopicsDetectionJobResponse, DescribeTopicsDetectionJobError> {
+        (**self).describe_topics_detection_job(input)
+    }
+    fn detect_dominant_language(
+        &self,
+        input: DetectDominantLanguageRequest,
+    ) -> RusotoFuture<DetectDominantLanguageResponse, DetectDominantLanguageError> {
+        (**self).detect_dominant_language(input)
+    }
+    fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> RusotoFuture<DetectEntitiesResponse, DetectEntitiesError> {
+        (**self).detect_entities(input)
+    }
+    fn detect_key_phrases(
+        &self,
+        input: DetectKeyPhrasesRequest,
+    ) -> RusotoFuture<DetectKeyPhrasesResponse, DetectKeyPhrasesError> {
+        (**self).detect_key_phrases(input)
+    }
+    fn detect_sentiment(
+        &self,
+        input: DetectSentimentRequest,
+    ) -> RusotoFuture<DetectSentimentResponse, DetectSentimentError> {
+        (**self).detect_sentiment(input)
+    }
+    fn detect_syntax(
+        &self,
+        input: DetectSyntaxRequest,
+    ) -> RusotoFuture<DetectSyntaxResponse, DetectSyntaxError> {
+        (**self).detect_syntax(input)
+    }
+    fn list_document_classification_jobs(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> RusotoFuture<ListDocumentClassificationJobsResponse, ListDocumentClassificationJobsError>
+    {
+        (**self).list_document_classification_jobs(input)
+    }
+    fn list_document_classifiers(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> RusotoFuture<ListDocumentClassifiersResponse, ListDocumentClassifiersError> {
+        (**self).list_document_classifiers(input)
+    }
+    fn list_dominant_language_detection_jobs(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> RusotoFuture<
+        ListDominantLanguageDetectionJobsResponse,
+        ListDominantLanguageDetectionJobsError,
+    > {
+        (**self).list_dominant_language_detection_jobs(input)
+    }
+    fn list_entities_detection_jobs(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> RusotoFuture<ListEntitiesDetectionJobsResponse, ListEntitiesDetectionJobsError> {
+        (**self).list_entities_detection_jobs(input)
+    }
+    fn list_entity_recognizers(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> RusotoFuture<ListEntityRecognizersResponse, ListEntityRecognizersError> {
+        (**self).list_entity_recognizers(input)
+    }
+    fn list_key_phrases_detection_jobs(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> RusotoFuture<ListKeyPhrasesDetectionJobsResponse, ListKeyPhrasesDetectionJobsError> {
+        (**self).list_key_phrases_detection_jobs(input)
+    }
+    fn list_sentiment_detection_jobs(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> RusotoFuture<ListSentimentDetectionJobsResponse, ListSentimentDetectionJobsError> {
+        (**self).list_sentiment_detection_jobs(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_topics_detection_jobs(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> RusotoFuture<ListTopicsDetectionJobsResponse, ListTopicsDetectionJobsError> {
+        (**self).list_topics_detection_jobs(input)
+    }
+    fn start_document_classification_job(
+        &self,
+        input: StartDocumentClassificationJobRequest,
+    ) -> RusotoFuture<StartDocumentClassificationJobResponse, StartDocumentClassificationJobError>
+    {
+        (**self).start_document_classification_job(input)
+    }
+    fn start_dominant_language_detection_job(
+        &self,
+        input: StartDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<
+        StartDominantLanguageDetectionJobResponse,
+        StartDominantLanguageDetectionJobError,
+    > {
+        (**self).start_dominant_language_detection_job(input)
+    }
+    fn start_entities_detection_job(
+        &self,
+        input: StartEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<StartEntitiesDetectionJobResponse, StartEntitiesDetectionJobError> {
+        (**self).start_entities_detection_job(input)
+    }
+    fn start_key_phrases_detection_job(
+        &self,
+        input: StartKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<StartKeyPhrasesDetectionJobResponse, StartKeyPhrasesDetectionJobError> {
+        (**self).start_key_phrases_detection_job(input)
+    }
+    fn start_sentiment_detection_job(
+        &self,
+        input: StartSentimentDetectionJobRequest,
+    ) -> RusotoFuture<StartSentimentDetectionJobResponse, StartSentimentDetectionJobError> {
+        (**self).start_sentiment_detection_job(input)
+    }
+    fn start_topics_detection_job(
+        &self,
+        input: StartTopicsDetectionJobRequest,
+    ) -> RusotoFuture<StartTopicsDetectionJobResponse, StartTopicsDetectionJobError> {
+        (**self).start_topics_detection_job(input)
+    }
+    fn stop_dominant_language_detection_job(
+        &self,
+        input: StopDominantLanguageDetectionJobRequest,
+    ) -> RusotoFuture<StopDominantLanguageDetectionJobResponse, StopDominantLanguageDetectionJobError>
+    {
+        (**self).stop_dominant_language_detection_job(input)
+    }
+    fn stop_entities_detection_job(
+        &self,
+        input: StopEntitiesDetectionJobRequest,
+    ) -> RusotoFuture<StopEntitiesDetectionJobResponse, StopEntitiesDetectionJobError> {
+        (**self).stop_entities_detection_job(input)
+    }
+    fn stop_key_phrases_detection_job(
+        &self,
+        input: StopKeyPhrasesDetectionJobRequest,
+    ) -> RusotoFuture<StopKeyPhrasesDetectionJobResponse, StopKeyPhrasesDetectionJobError> {
+        (**self).stop_key_phrases_detection_job(input)
+    }
+    fn stop_sentiment_detection_job(
+        &self,
+        input: StopSentimentDetectionJobRequest,
+    ) -> RusotoFuture<StopSentimentDetectionJobResponse, StopSentimentDetectionJobError> {
+        (**self).stop_sentiment_detection_job(input)
+    }
+    fn stop_training_document_classifier(
+        &self,
+        input: StopTrainingDocumentClassifierRequest,
+    ) -> RusotoFuture<StopTrainingDocumentClassifierResponse, StopTrainingDocumentClassifierError>
+    {
+        (**self).stop_training_document_classifier(input)
+    }
+    fn stop_training_entity_recognizer(
+        &self,
+        input: StopTrainingEntityRecognizerRequest,
+    ) -> RusotoFuture<StopTrainingEntityRecognizerResponse, StopTrainingEntityRecognizerError> {
+        (**self).stop_training_entity_recognizer(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// The error codes Amazon Comprehend retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the Amazon Comprehend API.
 #[derive(Clone)]
 pub struct ComprehendClient {
@@ -5232,6 +8496,27 @@ impl ComprehendClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_COMPREHEND`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ComprehendClient {
+        Self::new(region::Region::from_env_for_service("COMPREHEND"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `comprehend`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ComprehendClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("comprehend", "COMPREHEND"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5250,7 +8535,54 @@ impl ComprehendClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ComprehendClient {
-        ComprehendClient { client, region }
+        ComprehendClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "comprehend")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "comprehend",
+            "comprehend",
+            "COMPREHEND",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Comprehend` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Comprehend + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ComprehendClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Comprehend"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "comprehend"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ComprehendClient {
+        Self::new_with_client(client, region)
     }
 }
 
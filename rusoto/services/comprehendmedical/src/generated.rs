@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p> An extracted segment of the text that is an attribute of an entity, or otherwise related to an entity, such as the dosage of a medication taken. It contains information about the attribute such as id, begin and end offset within the input text, and the segment of the input text. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -58,7 +59,107 @@ pub struct Attribute {
     /// <p> The type of attribute. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EntitySubType>,
+}
+
+/// <p> Provides a name or contextual description about the trait. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AttributeName {
+    /// <p><code>SIGN</code></p>
+    Sign,
+    /// <p><code>SYMPTOM</code></p>
+    Symptom,
+    /// <p><code>DIAGNOSIS</code></p>
+    Diagnosis,
+    /// <p><code>NEGATION</code></p>
+    Negation,
+    /// <p><code>PERTAINS_TO_FAMILY</code></p>
+    PertainsToFamily,
+    /// <p><code>HYPOTHETICAL</code></p>
+    Hypothetical,
+    /// <p><code>LOW_CONFIDENCE</code></p>
+    LowConfidence,
+    /// <p><code>PAST_HISTORY</code></p>
+    PastHistory,
+    /// <p><code>FUTURE</code></p>
+    Future,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AttributeName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttributeName::Sign => "SIGN",
+            AttributeName::Symptom => "SYMPTOM",
+            AttributeName::Diagnosis => "DIAGNOSIS",
+            AttributeName::Negation => "NEGATION",
+            AttributeName::PertainsToFamily => "PERTAINS_TO_FAMILY",
+            AttributeName::Hypothetical => "HYPOTHETICAL",
+            AttributeName::LowConfidence => "LOW_CONFIDENCE",
+            AttributeName::PastHistory => "PAST_HISTORY",
+            AttributeName::Future => "FUTURE",
+            AttributeName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AttributeName {
+    fn from(value: &'a str) -> AttributeName {
+        match value {
+            "SIGN" => AttributeName::Sign,
+            "SYMPTOM" => AttributeName::Symptom,
+            "DIAGNOSIS" => AttributeName::Diagnosis,
+            "NEGATION" => AttributeName::Negation,
+            "PERTAINS_TO_FAMILY" => AttributeName::PertainsToFamily,
+            "HYPOTHETICAL" => AttributeName::Hypothetical,
+            "LOW_CONFIDENCE" => AttributeName::LowConfidence,
+            "PAST_HISTORY" => AttributeName::PastHistory,
+            "FUTURE" => AttributeName::Future,
+            _ => AttributeName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttributeName {
+    fn from(value: String) -> AttributeName {
+        AttributeName::from(value.as_str())
+    }
+}
+
+impl From<AttributeName> for String {
+    fn from(value: AttributeName) -> String {
+        match value {
+            AttributeName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AttributeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AttributeName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AttributeName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AttributeName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AttributeName::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -68,6 +169,36 @@ pub struct DetectEntitiesRequest {
     pub text: String,
 }
 
+impl DetectEntitiesRequest {
+    /// Returns a builder for `DetectEntitiesRequest`, checking its required members when built.
+    pub fn builder() -> DetectEntitiesRequestBuilder {
+        DetectEntitiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetectEntitiesRequest`](struct.DetectEntitiesRequest.html).
+#[derive(Default, Debug)]
+pub struct DetectEntitiesRequestBuilder {
+    text: Option<String>,
+}
+
+impl DetectEntitiesRequestBuilder {
+    /// Sets the [`text`](struct.DetectEntitiesRequest.html#structfield.text) member.
+    pub fn text<T: Into<String>>(mut self, value: T) -> Self {
+        self.text = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectEntitiesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetectEntitiesRequest, ::rusoto_core::BuildError> {
+        Ok(DetectEntitiesRequest {
+            text: self
+                .text
+                .ok_or(::rusoto_core::BuildError::missing("text"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetectEntitiesResponse {
@@ -91,6 +222,36 @@ pub struct DetectPHIRequest {
     pub text: String,
 }
 
+impl DetectPHIRequest {
+    /// Returns a builder for `DetectPHIRequest`, checking its required members when built.
+    pub fn builder() -> DetectPHIRequestBuilder {
+        DetectPHIRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetectPHIRequest`](struct.DetectPHIRequest.html).
+#[derive(Default, Debug)]
+pub struct DetectPHIRequestBuilder {
+    text: Option<String>,
+}
+
+impl DetectPHIRequestBuilder {
+    /// Sets the [`text`](struct.DetectPHIRequest.html#structfield.text) member.
+    pub fn text<T: Into<String>>(mut self, value: T) -> Self {
+        self.text = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectPHIRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetectPHIRequest, ::rusoto_core::BuildError> {
+        Ok(DetectPHIRequest {
+            text: self
+                .text
+                .ok_or(::rusoto_core::BuildError::missing("text"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetectPHIResponse {
@@ -118,7 +279,7 @@ pub struct Entity {
     /// <p> The category of the entity.</p>
     #[serde(rename = "Category")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
+    pub category: Option<EntityType>,
     /// <p> The 0-based character offset in the input text that shows where the entity ends. The offset returns the UTF-8 code point in the string. </p>
     #[serde(rename = "EndOffset")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -142,7 +303,343 @@ pub struct Entity {
     /// <p> Describes the specific type of entity with category of entities. </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EntitySubType>,
+}
+
+/// <p> The type of attribute. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EntitySubType {
+    /// <p><code>NAME</code></p>
+    Name,
+    /// <p><code>DX_NAME</code></p>
+    DxName,
+    /// <p><code>DOSAGE</code></p>
+    Dosage,
+    /// <p><code>ROUTE_OR_MODE</code></p>
+    RouteOrMode,
+    /// <p><code>FORM</code></p>
+    Form,
+    /// <p><code>FREQUENCY</code></p>
+    Frequency,
+    /// <p><code>DURATION</code></p>
+    Duration,
+    /// <p><code>GENERIC_NAME</code></p>
+    GenericName,
+    /// <p><code>BRAND_NAME</code></p>
+    BrandName,
+    /// <p><code>STRENGTH</code></p>
+    Strength,
+    /// <p><code>RATE</code></p>
+    Rate,
+    /// <p><code>ACUITY</code></p>
+    Acuity,
+    /// <p><code>TEST_NAME</code></p>
+    TestName,
+    /// <p><code>TEST_VALUE</code></p>
+    TestValue,
+    /// <p><code>TEST_UNITS</code></p>
+    TestUnits,
+    /// <p><code>TEST_UNIT</code></p>
+    TestUnit,
+    /// <p><code>PROCEDURE_NAME</code></p>
+    ProcedureName,
+    /// <p><code>TREATMENT_NAME</code></p>
+    TreatmentName,
+    /// <p><code>DATE</code></p>
+    Date,
+    /// <p><code>AGE</code></p>
+    Age,
+    /// <p><code>CONTACT_POINT</code></p>
+    ContactPoint,
+    /// <p><code>PHONE_OR_FAX</code></p>
+    PhoneOrFax,
+    /// <p><code>EMAIL</code></p>
+    Email,
+    /// <p><code>IDENTIFIER</code></p>
+    Identifier,
+    /// <p><code>ID</code></p>
+    Id,
+    /// <p><code>URL</code></p>
+    Url,
+    /// <p><code>ADDRESS</code></p>
+    Address,
+    /// <p><code>PROFESSION</code></p>
+    Profession,
+    /// <p><code>SYSTEM_ORGAN_SITE</code></p>
+    SystemOrganSite,
+    /// <p><code>DIRECTION</code></p>
+    Direction,
+    /// <p><code>QUALITY</code></p>
+    Quality,
+    /// <p><code>QUANTITY</code></p>
+    Quantity,
+    /// <p><code>TIME_EXPRESSION</code></p>
+    TimeExpression,
+    /// <p><code>TIME_TO_MEDICATION_NAME</code></p>
+    TimeToMedicationName,
+    /// <p><code>TIME_TO_DX_NAME</code></p>
+    TimeToDxName,
+    /// <p><code>TIME_TO_TEST_NAME</code></p>
+    TimeToTestName,
+    /// <p><code>TIME_TO_PROCEDURE_NAME</code></p>
+    TimeToProcedureName,
+    /// <p><code>TIME_TO_TREATMENT_NAME</code></p>
+    TimeToTreatmentName,
+    /// <p><code>AMOUNT</code></p>
+    Amount,
+    /// <p><code>GENDER</code></p>
+    Gender,
+    /// <p><code>RACE_ETHNICITY</code></p>
+    RaceEthnicity,
+    /// <p><code>ALLERGIES</code></p>
+    Allergies,
+    /// <p><code>TOBACCO_USE</code></p>
+    TobaccoUse,
+    /// <p><code>ALCOHOL_CONSUMPTION</code></p>
+    AlcoholConsumption,
+    /// <p><code>REC_DRUG_USE</code></p>
+    RecDrugUse,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EntitySubType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntitySubType::Name => "NAME",
+            EntitySubType::DxName => "DX_NAME",
+            EntitySubType::Dosage => "DOSAGE",
+            EntitySubType::RouteOrMode => "ROUTE_OR_MODE",
+            EntitySubType::Form => "FORM",
+            EntitySubType::Frequency => "FREQUENCY",
+            EntitySubType::Duration => "DURATION",
+            EntitySubType::GenericName => "GENERIC_NAME",
+            EntitySubType::BrandName => "BRAND_NAME",
+            EntitySubType::Strength => "STRENGTH",
+            EntitySubType::Rate => "RATE",
+            EntitySubType::Acuity => "ACUITY",
+            EntitySubType::TestName => "TEST_NAME",
+            EntitySubType::TestValue => "TEST_VALUE",
+            EntitySubType::TestUnits => "TEST_UNITS",
+            EntitySubType::TestUnit => "TEST_UNIT",
+            EntitySubType::ProcedureName => "PROCEDURE_NAME",
+            EntitySubType::TreatmentName => "TREATMENT_NAME",
+            EntitySubType::Date => "DATE",
+            EntitySubType::Age => "AGE",
+            EntitySubType::ContactPoint => "CONTACT_POINT",
+            EntitySubType::PhoneOrFax => "PHONE_OR_FAX",
+            EntitySubType::Email => "EMAIL",
+            EntitySubType::Identifier => "IDENTIFIER",
+            EntitySubType::Id => "ID",
+            EntitySubType::Url => "URL",
+            EntitySubType::Address => "ADDRESS",
+            EntitySubType::Profession => "PROFESSION",
+            EntitySubType::SystemOrganSite => "SYSTEM_ORGAN_SITE",
+            EntitySubType::Direction => "DIRECTION",
+            EntitySubType::Quality => "QUALITY",
+            EntitySubType::Quantity => "QUANTITY",
+            EntitySubType::TimeExpression => "TIME_EXPRESSION",
+            EntitySubType::TimeToMedicationName => "TIME_TO_MEDICATION_NAME",
+            EntitySubType::TimeToDxName => "TIME_TO_DX_NAME",
+            EntitySubType::TimeToTestName => "TIME_TO_TEST_NAME",
+            EntitySubType::TimeToProcedureName => "TIME_TO_PROCEDURE_NAME",
+            EntitySubType::TimeToTreatmentName => "TIME_TO_TREATMENT_NAME",
+            EntitySubType::Amount => "AMOUNT",
+            EntitySubType::Gender => "GENDER",
+            EntitySubType::RaceEthnicity => "RACE_ETHNICITY",
+            EntitySubType::Allergies => "ALLERGIES",
+            EntitySubType::TobaccoUse => "TOBACCO_USE",
+            EntitySubType::AlcoholConsumption => "ALCOHOL_CONSUMPTION",
+            EntitySubType::RecDrugUse => "REC_DRUG_USE",
+            EntitySubType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EntitySubType {
+    fn from(value: &'a str) -> EntitySubType {
+        match value {
+            "NAME" => EntitySubType::Name,
+            "DX_NAME" => EntitySubType::DxName,
+            "DOSAGE" => EntitySubType::Dosage,
+            "ROUTE_OR_MODE" => EntitySubType::RouteOrMode,
+            "FORM" => EntitySubType::Form,
+            "FREQUENCY" => EntitySubType::Frequency,
+            "DURATION" => EntitySubType::Duration,
+            "GENERIC_NAME" => EntitySubType::GenericName,
+            "BRAND_NAME" => EntitySubType::BrandName,
+            "STRENGTH" => EntitySubType::Strength,
+            "RATE" => EntitySubType::Rate,
+            "ACUITY" => EntitySubType::Acuity,
+            "TEST_NAME" => EntitySubType::TestName,
+            "TEST_VALUE" => EntitySubType::TestValue,
+            "TEST_UNITS" => EntitySubType::TestUnits,
+            "TEST_UNIT" => EntitySubType::TestUnit,
+            "PROCEDURE_NAME" => EntitySubType::ProcedureName,
+            "TREATMENT_NAME" => EntitySubType::TreatmentName,
+            "DATE" => EntitySubType::Date,
+            "AGE" => EntitySubType::Age,
+            "CONTACT_POINT" => EntitySubType::ContactPoint,
+            "PHONE_OR_FAX" => EntitySubType::PhoneOrFax,
+            "EMAIL" => EntitySubType::Email,
+            "IDENTIFIER" => EntitySubType::Identifier,
+            "ID" => EntitySubType::Id,
+            "URL" => EntitySubType::Url,
+            "ADDRESS" => EntitySubType::Address,
+            "PROFESSION" => EntitySubType::Profession,
+            "SYSTEM_ORGAN_SITE" => EntitySubType::SystemOrganSite,
+            "DIRECTION" => EntitySubType::Direction,
+            "QUALITY" => EntitySubType::Quality,
+            "QUANTITY" => EntitySubType::Quantity,
+            "TIME_EXPRESSION" => EntitySubType::TimeExpression,
+            "TIME_TO_MEDICATION_NAME" => EntitySubType::TimeToMedicationName,
+            "TIME_TO_DX_NAME" => EntitySubType::TimeToDxName,
+            "TIME_TO_TEST_NAME" => EntitySubType::TimeToTestName,
+            "TIME_TO_PROCEDURE_NAME" => EntitySubType::TimeToProcedureName,
+            "TIME_TO_TREATMENT_NAME" => EntitySubType::TimeToTreatmentName,
+            "AMOUNT" => EntitySubType::Amount,
+            "GENDER" => EntitySubType::Gender,
+            "RACE_ETHNICITY" => EntitySubType::RaceEthnicity,
+            "ALLERGIES" => EntitySubType::Allergies,
+            "TOBACCO_USE" => EntitySubType::TobaccoUse,
+            "ALCOHOL_CONSUMPTION" => EntitySubType::AlcoholConsumption,
+            "REC_DRUG_USE" => EntitySubType::RecDrugUse,
+            _ => EntitySubType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntitySubType {
+    fn from(value: String) -> EntitySubType {
+        EntitySubType::from(value.as_str())
+    }
+}
+
+impl From<EntitySubType> for String {
+    fn from(value: EntitySubType) -> String {
+        match value {
+            EntitySubType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EntitySubType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EntitySubType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntitySubType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EntitySubType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EntitySubType::from(value))
+    }
+}
+
+/// <p> The category of the entity.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EntityType {
+    /// <p><code>MEDICATION</code></p>
+    Medication,
+    /// <p><code>MEDICAL_CONDITION</code></p>
+    MedicalCondition,
+    /// <p><code>PROTECTED_HEALTH_INFORMATION</code></p>
+    ProtectedHealthInformation,
+    /// <p><code>TEST_TREATMENT_PROCEDURE</code></p>
+    TestTreatmentProcedure,
+    /// <p><code>ANATOMY</code></p>
+    Anatomy,
+    /// <p><code>TIME_EXPRESSION</code></p>
+    TimeExpression,
+    /// <p><code>BEHAVIORAL_ENVIRONMENTAL_SOCIAL</code></p>
+    BehavioralEnvironmentalSocial,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EntityType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityType::Medication => "MEDICATION",
+            EntityType::MedicalCondition => "MEDICAL_CONDITION",
+            EntityType::ProtectedHealthInformation => "PROTECTED_HEALTH_INFORMATION",
+            EntityType::TestTreatmentProcedure => "TEST_TREATMENT_PROCEDURE",
+            EntityType::Anatomy => "ANATOMY",
+            EntityType::TimeExpression => "TIME_EXPRESSION",
+            EntityType::BehavioralEnvironmentalSocial => "BEHAVIORAL_ENVIRONMENTAL_SOCIAL",
+            EntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EntityType {
+    fn from(value: &'a str) -> EntityType {
+        match value {
+            "MEDICATION" => EntityType::Medication,
+            "MEDICAL_CONDITION" => EntityType::MedicalCondition,
+            "PROTECTED_HEALTH_INFORMATION" => EntityType::ProtectedHealthInformation,
+            "TEST_TREATMENT_PROCEDURE" => EntityType::TestTreatmentProcedure,
+            "ANATOMY" => EntityType::Anatomy,
+            "TIME_EXPRESSION" => EntityType::TimeExpression,
+            "BEHAVIORAL_ENVIRONMENTAL_SOCIAL" => EntityType::BehavioralEnvironmentalSocial,
+            _ => EntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityType {
+    fn from(value: String) -> EntityType {
+        EntityType::from(value.as_str())
+    }
+}
+
+impl From<EntityType> for String {
+    fn from(value: EntityType) -> String {
+        match value {
+            EntityType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EntityType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EntityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EntityType::from(value))
+    }
 }
 
 /// <p> Provides contextual information about the extracted entity. </p>
@@ -152,7 +649,7 @@ pub struct Trait {
     /// <p> Provides a name or contextual description about the trait. </p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<AttributeName>,
     /// <p> The level of confidence that Comprehend Medical has in the accuracy of this trait.</p>
     #[serde(rename = "Score")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -170,7 +667,7 @@ pub struct UnmappedAttribute {
     /// <p> The type of the attribute, could be one of the following values: "MEDICATION", "MEDICAL_CONDITION", "ANATOMY", "TEST_AND_TREATMENT_PROCEDURE" or "PERSONAL_HEALTH_INFORMATION". </p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EntityType>,
 }
 
 /// Errors returned by DetectEntities
@@ -315,6 +812,27 @@ pub trait ComprehendMedical {
         input: DetectPHIRequest,
     ) -> RusotoFuture<DetectPHIResponse, DetectPHIError>;
 }
+impl<T: ?Sized + ComprehendMedical> ComprehendMedical for ::std::sync::Arc<T> {
+    fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> RusotoFuture<DetectEntitiesResponse, DetectEntitiesError> {
+        (**self).detect_entities(input)
+    }
+    fn detect_phi(
+        &self,
+        input: DetectPHIRequest,
+    ) -> RusotoFuture<DetectPHIResponse, DetectPHIError> {
+        (**self).detect_phi(input)
+    }
+}
+/// The error codes ComprehendMedical retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the ComprehendMedical API.
 #[derive(Clone)]
 pub struct ComprehendMedicalClient {
@@ -330,6 +848,27 @@ impl ComprehendMedicalClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_COMPREHENDMEDICAL`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ComprehendMedicalClient {
+        Self::new(region::Region::from_env_for_service("COMPREHENDMEDICAL"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `comprehendmedical`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ComprehendMedicalClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("comprehendmedical", "COMPREHENDMEDICAL"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -348,7 +887,54 @@ impl ComprehendMedicalClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ComprehendMedicalClient {
-        ComprehendMedicalClient { client, region }
+        ComprehendMedicalClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "comprehendmedical")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "comprehendmedical",
+            "comprehendmedical",
+            "COMPREHENDMEDICAL",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ComprehendMedical` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ComprehendMedical + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ComprehendMedicalClient {
+    fn service_name(&self) -> &'static str {
+        "ComprehendMedical"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "comprehendmedical"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ComprehendMedicalClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Config clients.
+//!
+//! Makes a single read-only `DescribeAggregationAuthorizations` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_config::{ConfigService, ConfigServiceClient, DescribeAggregationAuthorizationsRequest};
+
+fn main() {
+    let client = ConfigServiceClient::from_env();
+    match client
+        .describe_aggregation_authorizations(DescribeAggregationAuthorizationsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeAggregationAuthorizations succeeded"),
+        Err(error) => {
+            eprintln!("DescribeAggregationAuthorizations failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
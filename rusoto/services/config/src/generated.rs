@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>A collection of accounts and regions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -94,23 +95,25 @@ pub struct AggregateEvaluationResult {
     /// <p>The resource compliance status.</p> <p>For the <code>AggregationEvaluationResult</code> data type, AWS Config supports only the <code>COMPLIANT</code> and <code>NON_COMPLIANT</code>. AWS Config does not support the <code>NOT_APPLICABLE</code> and <code>INSUFFICIENT_DATA</code> value.</p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ComplianceType>,
     /// <p>The time when the AWS Config rule evaluated the AWS resource.</p>
     #[serde(rename = "ConfigRuleInvokedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub config_rule_invoked_time: Option<f64>,
+    pub config_rule_invoked_time: Option<EpochTimestamp>,
     /// <p>Uniquely identifies the evaluation result.</p>
     #[serde(rename = "EvaluationResultIdentifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub evaluation_result_identifier: Option<EvaluationResultIdentifier>,
     /// <p>The time when AWS Config recorded the aggregate evaluation result.</p>
     #[serde(rename = "ResultRecordedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result_recorded_time: Option<f64>,
+    pub result_recorded_time: Option<EpochTimestamp>,
 }
 
 /// <p>The details that identify a resource that is collected by AWS Config aggregator, including the resource type, ID, (if available) the custom resource name, the source account, and source region.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AggregateResourceIdentifier {
     /// <p>The ID of the AWS resource.</p>
     #[serde(rename = "ResourceId")]
@@ -121,7 +124,7 @@ pub struct AggregateResourceIdentifier {
     pub resource_name: Option<String>,
     /// <p>The type of the AWS resource.</p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
     /// <p>The 12-digit account ID of the source account.</p>
     #[serde(rename = "SourceAccountId")]
     pub source_account_id: String,
@@ -149,11 +152,12 @@ pub struct AggregatedSourceStatus {
     /// <p><p>Filters the last updated status type.</p> <ul> <li> <p>Valid value FAILED indicates errors while moving data.</p> </li> <li> <p>Valid value SUCCEEDED indicates the data was successfully moved.</p> </li> <li> <p>Valid value OUTDATED indicates the data is not the most recent.</p> </li> </ul></p>
     #[serde(rename = "LastUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_status: Option<String>,
+    pub last_update_status: Option<AggregatedSourceStatusType>,
     /// <p>The time of the last update.</p>
     #[serde(rename = "LastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The source account ID or an organization.</p>
     #[serde(rename = "SourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -161,7 +165,157 @@ pub struct AggregatedSourceStatus {
     /// <p>The source account or an organization.</p>
     #[serde(rename = "SourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<String>,
+    pub source_type: Option<AggregatedSourceType>,
+}
+
+/// <p><p>Filters the last updated status type.</p> <ul> <li> <p>Valid value FAILED indicates errors while moving data.</p> </li> <li> <p>Valid value SUCCEEDED indicates the data was successfully moved.</p> </li> <li> <p>Valid value OUTDATED indicates the data is not the most recent.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AggregatedSourceStatusType {
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>OUTDATED</code></p>
+    Outdated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AggregatedSourceStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AggregatedSourceStatusType::Failed => "FAILED",
+            AggregatedSourceStatusType::Succeeded => "SUCCEEDED",
+            AggregatedSourceStatusType::Outdated => "OUTDATED",
+            AggregatedSourceStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AggregatedSourceStatusType {
+    fn from(value: &'a str) -> AggregatedSourceStatusType {
+        match value {
+            "FAILED" => AggregatedSourceStatusType::Failed,
+            "SUCCEEDED" => AggregatedSourceStatusType::Succeeded,
+            "OUTDATED" => AggregatedSourceStatusType::Outdated,
+            _ => AggregatedSourceStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AggregatedSourceStatusType {
+    fn from(value: String) -> AggregatedSourceStatusType {
+        AggregatedSourceStatusType::from(value.as_str())
+    }
+}
+
+impl From<AggregatedSourceStatusType> for String {
+    fn from(value: AggregatedSourceStatusType) -> String {
+        match value {
+            AggregatedSourceStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AggregatedSourceStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AggregatedSourceStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AggregatedSourceStatusType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<AggregatedSourceStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AggregatedSourceStatusType::from(value))
+    }
+}
+
+/// <p>The source account or an organization.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AggregatedSourceType {
+    /// <p><code>ACCOUNT</code></p>
+    Account,
+    /// <p><code>ORGANIZATION</code></p>
+    Organization,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AggregatedSourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AggregatedSourceType::Account => "ACCOUNT",
+            AggregatedSourceType::Organization => "ORGANIZATION",
+            AggregatedSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AggregatedSourceType {
+    fn from(value: &'a str) -> AggregatedSourceType {
+        match value {
+            "ACCOUNT" => AggregatedSourceType::Account,
+            "ORGANIZATION" => AggregatedSourceType::Organization,
+            _ => AggregatedSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AggregatedSourceType {
+    fn from(value: String) -> AggregatedSourceType {
+        AggregatedSourceType::from(value.as_str())
+    }
+}
+
+impl From<AggregatedSourceType> for String {
+    fn from(value: AggregatedSourceType) -> String {
+        match value {
+            AggregatedSourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AggregatedSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AggregatedSourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AggregatedSourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AggregatedSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AggregatedSourceType::from(value))
+    }
 }
 
 /// <p>An object that represents the authorizations granted to aggregator accounts and regions.</p>
@@ -182,8 +336,9 @@ pub struct AggregationAuthorization {
     pub authorized_aws_region: Option<String>,
     /// <p>The time stamp when the aggregation authorization was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
 }
 
 /// <p>The detailed configuration of a specified resource.</p>
@@ -212,20 +367,22 @@ pub struct BaseConfigurationItem {
     pub configuration: Option<String>,
     /// <p>The time when the configuration recording was initiated.</p>
     #[serde(rename = "configurationItemCaptureTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_item_capture_time: Option<f64>,
+    pub configuration_item_capture_time: Option<EpochTimestamp>,
     /// <p>The configuration item status.</p>
     #[serde(rename = "configurationItemStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_item_status: Option<String>,
+    pub configuration_item_status: Option<ConfigurationItemStatus>,
     /// <p>An identifier that indicates the ordering of the configuration items of a resource.</p>
     #[serde(rename = "configurationStateId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub configuration_state_id: Option<String>,
     /// <p>The time stamp when the resource was created.</p>
     #[serde(rename = "resourceCreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_creation_time: Option<f64>,
+    pub resource_creation_time: Option<EpochTimestamp>,
     /// <p>The ID of the resource (for example., sg-xxxxxx).</p>
     #[serde(rename = "resourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -237,7 +394,7 @@ pub struct BaseConfigurationItem {
     /// <p>The type of AWS resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>Configuration attributes that AWS Config returns for certain resource types to supplement the information returned for the configuration parameter.</p>
     #[serde(rename = "supplementaryConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -258,6 +415,52 @@ pub struct BatchGetAggregateResourceConfigRequest {
     pub resource_identifiers: Vec<AggregateResourceIdentifier>,
 }
 
+impl BatchGetAggregateResourceConfigRequest {
+    /// Returns a builder for `BatchGetAggregateResourceConfigRequest`, checking its required members when built.
+    pub fn builder() -> BatchGetAggregateResourceConfigRequestBuilder {
+        BatchGetAggregateResourceConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetAggregateResourceConfigRequest`](struct.BatchGetAggregateResourceConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchGetAggregateResourceConfigRequestBuilder {
+    configuration_aggregator_name: Option<String>,
+    resource_identifiers: Option<Vec<AggregateResourceIdentifier>>,
+}
+
+impl BatchGetAggregateResourceConfigRequestBuilder {
+    /// Sets the [`configuration_aggregator_name`](struct.BatchGetAggregateResourceConfigRequest.html#structfield.configuration_aggregator_name) member.
+    pub fn configuration_aggregator_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_aggregator_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_identifiers`](struct.BatchGetAggregateResourceConfigRequest.html#structfield.resource_identifiers) member.
+    pub fn resource_identifiers<T: Into<Vec<AggregateResourceIdentifier>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.resource_identifiers = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetAggregateResourceConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetAggregateResourceConfigRequest, ::rusoto_core::BuildError>
+    {
+        Ok(BatchGetAggregateResourceConfigRequest {
+            configuration_aggregator_name: self.configuration_aggregator_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_aggregator_name"),
+            )?,
+            resource_identifiers: self
+                .resource_identifiers
+                .ok_or(::rusoto_core::BuildError::missing("resource_identifiers"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetAggregateResourceConfigResponse {
@@ -278,6 +481,38 @@ pub struct BatchGetResourceConfigRequest {
     pub resource_keys: Vec<ResourceKey>,
 }
 
+impl BatchGetResourceConfigRequest {
+    /// Returns a builder for `BatchGetResourceConfigRequest`, checking its required members when built.
+    pub fn builder() -> BatchGetResourceConfigRequestBuilder {
+        BatchGetResourceConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetResourceConfigRequest`](struct.BatchGetResourceConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchGetResourceConfigRequestBuilder {
+    resource_keys: Option<Vec<ResourceKey>>,
+}
+
+impl BatchGetResourceConfigRequestBuilder {
+    /// Sets the [`resource_keys`](struct.BatchGetResourceConfigRequest.html#structfield.resource_keys) member.
+    pub fn resource_keys<T: Into<Vec<ResourceKey>>>(mut self, value: T) -> Self {
+        self.resource_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetResourceConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetResourceConfigRequest, ::rusoto_core::BuildError> {
+        Ok(BatchGetResourceConfigRequest {
+            resource_keys: self
+                .resource_keys
+                .ok_or(::rusoto_core::BuildError::missing("resource_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetResourceConfigResponse {
@@ -291,6 +526,78 @@ pub struct BatchGetResourceConfigResponse {
     pub unprocessed_resource_keys: Option<Vec<ResourceKey>>,
 }
 
+/// <p>The chronological order for configuration items listed. By default, the results are listed in reverse chronological order.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChronologicalOrder {
+    /// <p><code>Reverse</code></p>
+    Reverse,
+    /// <p><code>Forward</code></p>
+    Forward,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChronologicalOrder {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChronologicalOrder::Reverse => "Reverse",
+            ChronologicalOrder::Forward => "Forward",
+            ChronologicalOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChronologicalOrder {
+    fn from(value: &'a str) -> ChronologicalOrder {
+        match value {
+            "Reverse" => ChronologicalOrder::Reverse,
+            "Forward" => ChronologicalOrder::Forward,
+            _ => ChronologicalOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChronologicalOrder {
+    fn from(value: String) -> ChronologicalOrder {
+        ChronologicalOrder::from(value.as_str())
+    }
+}
+
+impl From<ChronologicalOrder> for String {
+    fn from(value: ChronologicalOrder) -> String {
+        match value {
+            ChronologicalOrder::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChronologicalOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ChronologicalOrder {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChronologicalOrder {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChronologicalOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChronologicalOrder::from(value))
+    }
+}
+
 /// <p>Indicates whether an AWS resource or AWS Config rule is compliant and provides the number of contributors that affect the compliance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -302,7 +609,7 @@ pub struct Compliance {
     /// <p>Indicates whether an AWS resource or AWS Config rule is compliant.</p> <p>A resource is compliant if it complies with all of the AWS Config rules that evaluate it. A resource is noncompliant if it does not comply with one or more of these rules.</p> <p>A rule is compliant if all of the resources that the rule evaluates comply with it. A rule is noncompliant if any of these resources do not comply.</p> <p>AWS Config returns the <code>INSUFFICIENT_DATA</code> value when no evaluation results are available for the AWS resource or AWS Config rule.</p> <p>For the <code>Compliance</code> data type, AWS Config supports only <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>INSUFFICIENT_DATA</code> values. AWS Config does not support the <code>NOT_APPLICABLE</code> value for the <code>Compliance</code> data type.</p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ComplianceType>,
 }
 
 /// <p>Indicates whether an AWS Config rule is compliant. A rule is compliant if all of the resources that the rule evaluated comply with it. A rule is noncompliant if any of these resources do not comply.</p>
@@ -357,8 +664,9 @@ pub struct ComplianceContributorCount {
 pub struct ComplianceSummary {
     /// <p>The time that AWS Config created the compliance summary.</p>
     #[serde(rename = "ComplianceSummaryTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_summary_timestamp: Option<f64>,
+    pub compliance_summary_timestamp: Option<EpochTimestamp>,
     /// <p>The number of AWS Config rules or AWS resources that are compliant, up to a maximum of 25 for rules and 100 for resources.</p>
     #[serde(rename = "CompliantResourceCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -383,14 +691,95 @@ pub struct ComplianceSummaryByResourceType {
     pub resource_type: Option<String>,
 }
 
+/// <p>The resource compliance status.</p> <p>For the <code>AggregationEvaluationResult</code> data type, AWS Config supports only the <code>COMPLIANT</code> and <code>NON_COMPLIANT</code>. AWS Config does not support the <code>NOT_APPLICABLE</code> and <code>INSUFFICIENT_DATA</code> value.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComplianceType {
+    /// <p><code>COMPLIANT</code></p>
+    Compliant,
+    /// <p><code>NON_COMPLIANT</code></p>
+    NonCompliant,
+    /// <p><code>NOT_APPLICABLE</code></p>
+    NotApplicable,
+    /// <p><code>INSUFFICIENT_DATA</code></p>
+    InsufficientData,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComplianceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComplianceType::Compliant => "COMPLIANT",
+            ComplianceType::NonCompliant => "NON_COMPLIANT",
+            ComplianceType::NotApplicable => "NOT_APPLICABLE",
+            ComplianceType::InsufficientData => "INSUFFICIENT_DATA",
+            ComplianceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComplianceType {
+    fn from(value: &'a str) -> ComplianceType {
+        match value {
+            "COMPLIANT" => ComplianceType::Compliant,
+            "NON_COMPLIANT" => ComplianceType::NonCompliant,
+            "NOT_APPLICABLE" => ComplianceType::NotApplicable,
+            "INSUFFICIENT_DATA" => ComplianceType::InsufficientData,
+            _ => ComplianceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComplianceType {
+    fn from(value: String) -> ComplianceType {
+        ComplianceType::from(value.as_str())
+    }
+}
+
+impl From<ComplianceType> for String {
+    fn from(value: ComplianceType) -> String {
+        match value {
+            ComplianceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComplianceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ComplianceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComplianceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ComplianceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ComplianceType::from(value))
+    }
+}
+
 /// <p>Provides status of the delivery of the snapshot or the configuration history to the specified Amazon S3 bucket. Also provides the status of notifications about the Amazon S3 delivery to the specified Amazon SNS topic.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ConfigExportDeliveryInfo {
     /// <p>The time of the last attempted delivery.</p>
     #[serde(rename = "lastAttemptTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_attempt_time: Option<f64>,
+    pub last_attempt_time: Option<EpochTimestamp>,
     /// <p>The error code from the last attempted delivery.</p>
     #[serde(rename = "lastErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -402,19 +791,21 @@ pub struct ConfigExportDeliveryInfo {
     /// <p>Status of the last attempted delivery.</p>
     #[serde(rename = "lastStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status: Option<String>,
+    pub last_status: Option<DeliveryStatus>,
     /// <p>The time of the last successful delivery.</p>
     #[serde(rename = "lastSuccessfulTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_successful_time: Option<f64>,
+    pub last_successful_time: Option<EpochTimestamp>,
     /// <p>The time that the next delivery occurs.</p>
     #[serde(rename = "nextDeliveryTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_delivery_time: Option<f64>,
+    pub next_delivery_time: Option<EpochTimestamp>,
 }
 
 /// <p>An AWS Config rule represents an AWS Lambda function that you create for a custom rule or a predefined function for an AWS managed rule. The function evaluates configuration items to assess whether your AWS resources comply with your desired configurations. This function can run when AWS Config detects a configuration change to an AWS resource and at a periodic frequency that you choose (for example, every 24 hours).</p> <note> <p>You can use the AWS CLI and AWS SDKs if you want to create a rule that triggers evaluations for your resources when AWS Config delivers the configuration snapshot. For more information, see <a>ConfigSnapshotDeliveryProperties</a>.</p> </note> <p>For more information about developing and using AWS Config rules, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config.html">Evaluating AWS Resource Configurations with AWS Config</a> in the <i>AWS Config Developer Guide</i>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConfigRule {
     /// <p>The Amazon Resource Name (ARN) of the AWS Config rule.</p>
     #[serde(rename = "ConfigRuleArn")]
@@ -431,7 +822,7 @@ pub struct ConfigRule {
     /// <p>Indicates whether the AWS Config rule is active or is currently being deleted by AWS Config. It can also indicate the evaluation status for the AWS Config rule.</p> <p>AWS Config sets the state of the rule to <code>EVALUATING</code> temporarily after you use the <code>StartConfigRulesEvaluation</code> request to evaluate your resources against the AWS Config rule.</p> <p>AWS Config sets the state of the rule to <code>DELETING_RESULTS</code> temporarily after you use the <code>DeleteEvaluationResults</code> request to delete the current evaluation results for the AWS Config rule.</p> <p>AWS Config temporarily sets the state of a rule to <code>DELETING</code> after you use the <code>DeleteConfigRule</code> request to delete the rule. After AWS Config deletes the rule, the rule and all of its evaluations are erased and are no longer available.</p>
     #[serde(rename = "ConfigRuleState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub config_rule_state: Option<String>,
+    pub config_rule_state: Option<ConfigRuleState>,
     /// <p><p>Service principal name of the service that created the rule.</p> <note> <p>The field is populated only if the service linked rule is created by a service. The field is empty if you create your own rule.</p> </note></p>
     #[serde(rename = "CreatedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -447,7 +838,7 @@ pub struct ConfigRule {
     /// <p><p>The maximum frequency with which AWS Config runs evaluations for a rule. You can specify a value for <code>MaximumExecutionFrequency</code> when:</p> <ul> <li> <p>You are using an AWS managed rule that is triggered at a periodic frequency.</p> </li> <li> <p>Your custom rule is triggered when AWS Config delivers the configuration snapshot. For more information, see <a>ConfigSnapshotDeliveryProperties</a>.</p> </li> </ul> <note> <p>By default, rules with a periodic trigger are evaluated every 24 hours. To change the frequency, specify a valid value for the <code>MaximumExecutionFrequency</code> parameter.</p> </note></p>
     #[serde(rename = "MaximumExecutionFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maximum_execution_frequency: Option<String>,
+    pub maximum_execution_frequency: Option<MaximumExecutionFrequency>,
     /// <p>Defines which resources can trigger an evaluation for the rule. The scope can include one or more resource types, a combination of one resource type and one resource ID, or a combination of a tag key and value. Specify a scope to constrain the resources that can trigger an evaluation for the rule. If you do not specify a scope, evaluations are triggered when any resource in the recording group changes.</p>
     #[serde(rename = "Scope")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -471,7 +862,7 @@ pub struct ConfigRuleComplianceFilters {
     /// <p>The rule compliance status.</p> <p>For the <code>ConfigRuleComplianceFilters</code> data type, AWS Config supports only <code>COMPLIANT</code> and <code>NON_COMPLIANT</code>. AWS Config does not support the <code>NOT_APPLICABLE</code> and the <code>INSUFFICIENT_DATA</code> values.</p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ComplianceType>,
     /// <p>The name of the AWS Config rule.</p>
     #[serde(rename = "ConfigRuleName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -491,6 +882,80 @@ pub struct ConfigRuleComplianceSummaryFilters {
     pub aws_region: Option<String>,
 }
 
+/// <p>Groups the result based on ACCOUNT_ID or AWS_REGION.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConfigRuleComplianceSummaryGroupKey {
+    /// <p><code>ACCOUNT_ID</code></p>
+    AccountId,
+    /// <p><code>AWS_REGION</code></p>
+    AwsRegion,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConfigRuleComplianceSummaryGroupKey {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigRuleComplianceSummaryGroupKey::AccountId => "ACCOUNT_ID",
+            ConfigRuleComplianceSummaryGroupKey::AwsRegion => "AWS_REGION",
+            ConfigRuleComplianceSummaryGroupKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConfigRuleComplianceSummaryGroupKey {
+    fn from(value: &'a str) -> ConfigRuleComplianceSummaryGroupKey {
+        match value {
+            "ACCOUNT_ID" => ConfigRuleComplianceSummaryGroupKey::AccountId,
+            "AWS_REGION" => ConfigRuleComplianceSummaryGroupKey::AwsRegion,
+            _ => ConfigRuleComplianceSummaryGroupKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigRuleComplianceSummaryGroupKey {
+    fn from(value: String) -> ConfigRuleComplianceSummaryGroupKey {
+        ConfigRuleComplianceSummaryGroupKey::from(value.as_str())
+    }
+}
+
+impl From<ConfigRuleComplianceSummaryGroupKey> for String {
+    fn from(value: ConfigRuleComplianceSummaryGroupKey) -> String {
+        match value {
+            ConfigRuleComplianceSummaryGroupKey::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigRuleComplianceSummaryGroupKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConfigRuleComplianceSummaryGroupKey {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfigRuleComplianceSummaryGroupKey {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ConfigRuleComplianceSummaryGroupKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConfigRuleComplianceSummaryGroupKey::from(value))
+    }
+}
+
 /// <p>Status information for your AWS managed Config rules. The status includes information such as the last time the rule ran, the last time it failed, and the related error for the last failure.</p> <p>This action does not return status information about custom AWS Config rules.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -509,8 +974,9 @@ pub struct ConfigRuleEvaluationStatus {
     pub config_rule_name: Option<String>,
     /// <p>The time that you first activated the AWS Config rule.</p>
     #[serde(rename = "FirstActivatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_activated_time: Option<f64>,
+    pub first_activated_time: Option<EpochTimestamp>,
     /// <p><p>Indicates whether AWS Config has evaluated your resources against the rule at least once.</p> <ul> <li> <p> <code>true</code> - AWS Config has evaluated your AWS resources against the rule at least once.</p> </li> <li> <p> <code>false</code> - AWS Config has not once finished evaluating your AWS resources against the rule.</p> </li> </ul></p>
     #[serde(rename = "FirstEvaluationStarted")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -525,20 +991,104 @@ pub struct ConfigRuleEvaluationStatus {
     pub last_error_message: Option<String>,
     /// <p>The time that AWS Config last failed to evaluate your AWS resources against the rule.</p>
     #[serde(rename = "LastFailedEvaluationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_failed_evaluation_time: Option<f64>,
+    pub last_failed_evaluation_time: Option<EpochTimestamp>,
     /// <p>The time that AWS Config last failed to invoke the AWS Config rule to evaluate your AWS resources.</p>
     #[serde(rename = "LastFailedInvocationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_failed_invocation_time: Option<f64>,
+    pub last_failed_invocation_time: Option<EpochTimestamp>,
     /// <p>The time that AWS Config last successfully evaluated your AWS resources against the rule.</p>
     #[serde(rename = "LastSuccessfulEvaluationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_successful_evaluation_time: Option<f64>,
+    pub last_successful_evaluation_time: Option<EpochTimestamp>,
     /// <p>The time that AWS Config last successfully invoked the AWS Config rule to evaluate your AWS resources.</p>
     #[serde(rename = "LastSuccessfulInvocationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_successful_invocation_time: Option<f64>,
+    pub last_successful_invocation_time: Option<EpochTimestamp>,
+}
+
+/// <p>Indicates whether the AWS Config rule is active or is currently being deleted by AWS Config. It can also indicate the evaluation status for the AWS Config rule.</p> <p>AWS Config sets the state of the rule to <code>EVALUATING</code> temporarily after you use the <code>StartConfigRulesEvaluation</code> request to evaluate your resources against the AWS Config rule.</p> <p>AWS Config sets the state of the rule to <code>DELETING_RESULTS</code> temporarily after you use the <code>DeleteEvaluationResults</code> request to delete the current evaluation results for the AWS Config rule.</p> <p>AWS Config temporarily sets the state of a rule to <code>DELETING</code> after you use the <code>DeleteConfigRule</code> request to delete the rule. After AWS Config deletes the rule, the rule and all of its evaluations are erased and are no longer available.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConfigRuleState {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>DELETING_RESULTS</code></p>
+    DeletingResults,
+    /// <p><code>EVALUATING</code></p>
+    Evaluating,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConfigRuleState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigRuleState::Active => "ACTIVE",
+            ConfigRuleState::Deleting => "DELETING",
+            ConfigRuleState::DeletingResults => "DELETING_RESULTS",
+            ConfigRuleState::Evaluating => "EVALUATING",
+            ConfigRuleState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConfigRuleState {
+    fn from(value: &'a str) -> ConfigRuleState {
+        match value {
+            "ACTIVE" => ConfigRuleState::Active,
+            "DELETING" => ConfigRuleState::Deleting,
+            "DELETING_RESULTS" => ConfigRuleState::DeletingResults,
+            "EVALUATING" => ConfigRuleState::Evaluating,
+            _ => ConfigRuleState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigRuleState {
+    fn from(value: String) -> ConfigRuleState {
+        ConfigRuleState::from(value.as_str())
+    }
+}
+
+impl From<ConfigRuleState> for String {
+    fn from(value: ConfigRuleState) -> String {
+        match value {
+            ConfigRuleState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigRuleState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConfigRuleState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfigRuleState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConfigRuleState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConfigRuleState::from(value))
+    }
 }
 
 /// <p>Provides options for how often AWS Config delivers configuration snapshots to the Amazon S3 bucket in your delivery channel.</p> <p>The frequency for a rule that triggers evaluations for your resources when AWS Config delivers the configuration snapshot is set by one of two values, depending on which is less frequent:</p> <ul> <li> <p>The value for the <code>deliveryFrequency</code> parameter within the delivery channel configuration, which sets how often AWS Config delivers configuration snapshots. This value also sets how often AWS Config invokes evaluations for AWS Config rules.</p> </li> <li> <p>The value for the <code>MaximumExecutionFrequency</code> parameter, which sets the maximum frequency with which AWS Config invokes evaluations for the rule. For more information, see <a>ConfigRule</a>.</p> </li> </ul> <p>If the <code>deliveryFrequency</code> value is less frequent than the <code>MaximumExecutionFrequency</code> value for a rule, AWS Config invokes the rule only as often as the <code>deliveryFrequency</code> value.</p> <ol> <li> <p>For example, you want your rule to run evaluations when AWS Config delivers the configuration snapshot.</p> </li> <li> <p>You specify the <code>MaximumExecutionFrequency</code> value for <code>Six_Hours</code>. </p> </li> <li> <p>You then specify the delivery channel <code>deliveryFrequency</code> value for <code>TwentyFour_Hours</code>.</p> </li> <li> <p>Because the value for <code>deliveryFrequency</code> is less frequent than <code>MaximumExecutionFrequency</code>, AWS Config invokes evaluations for the rule every 24 hours. </p> </li> </ol> <p>You should set the <code>MaximumExecutionFrequency</code> value to be at least as frequent as the <code>deliveryFrequency</code> value. You can view the <code>deliveryFrequency</code> value by using the <code>DescribeDeliveryChannnels</code> action.</p> <p>To update the <code>deliveryFrequency</code> with which AWS Config delivers your configuration snapshots, use the <code>PutDeliveryChannel</code> action.</p>
@@ -547,7 +1097,7 @@ pub struct ConfigSnapshotDeliveryProperties {
     /// <p>The frequency with which AWS Config delivers configuration snapshots.</p>
     #[serde(rename = "deliveryFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_frequency: Option<String>,
+    pub delivery_frequency: Option<MaximumExecutionFrequency>,
 }
 
 /// <p>A list that contains the status of the delivery of the configuration stream notification to the Amazon SNS topic.</p>
@@ -565,11 +1115,12 @@ pub struct ConfigStreamDeliveryInfo {
     /// <p>Status of the last attempted delivery.</p> <p> <b>Note</b> Providing an SNS topic on a <a href="https://docs.aws.amazon.com/config/latest/APIReference/API_DeliveryChannel.html">DeliveryChannel</a> for AWS Config is optional. If the SNS delivery is turned off, the last status will be <b>Not_Applicable</b>.</p>
     #[serde(rename = "lastStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status: Option<String>,
+    pub last_status: Option<DeliveryStatus>,
     /// <p>The time from the last status change.</p>
     #[serde(rename = "lastStatusChangeTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status_change_time: Option<f64>,
+    pub last_status_change_time: Option<EpochTimestamp>,
 }
 
 /// <p>The details about the configuration aggregator, including information about source accounts, regions, and metadata of the aggregator. </p>
@@ -590,12 +1141,14 @@ pub struct ConfigurationAggregator {
     pub configuration_aggregator_name: Option<String>,
     /// <p>The time stamp when the configuration aggregator was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The time of the last update.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<EpochTimestamp>,
     /// <p>Provides an organization and list of regions to be aggregated.</p>
     #[serde(rename = "OrganizationAggregationSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -628,8 +1181,9 @@ pub struct ConfigurationItem {
     pub configuration: Option<String>,
     /// <p>The time when the configuration recording was initiated.</p>
     #[serde(rename = "configurationItemCaptureTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_item_capture_time: Option<f64>,
+    pub configuration_item_capture_time: Option<EpochTimestamp>,
     /// <p>Unique MD5 hash that represents the configuration item's state.</p> <p>You can use MD5 hash to compare the states of two or more configuration items that are associated with the same resource.</p>
     #[serde(rename = "configurationItemMD5Hash")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -637,7 +1191,7 @@ pub struct ConfigurationItem {
     /// <p>The configuration item status.</p>
     #[serde(rename = "configurationItemStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_item_status: Option<String>,
+    pub configuration_item_status: Option<ConfigurationItemStatus>,
     /// <p>An identifier that indicates the ordering of the configuration items of a resource.</p>
     #[serde(rename = "configurationStateId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -652,8 +1206,9 @@ pub struct ConfigurationItem {
     pub relationships: Option<Vec<Relationship>>,
     /// <p>The time stamp when the resource was created.</p>
     #[serde(rename = "resourceCreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_creation_time: Option<f64>,
+    pub resource_creation_time: Option<EpochTimestamp>,
     /// <p>The ID of the resource (for example, <code>sg-xxxxxx</code>).</p>
     #[serde(rename = "resourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -665,7 +1220,7 @@ pub struct ConfigurationItem {
     /// <p>The type of AWS resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>Configuration attributes that AWS Config returns for certain resource types to supplement the information returned for the <code>configuration</code> parameter.</p>
     #[serde(rename = "supplementaryConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -680,6 +1235,90 @@ pub struct ConfigurationItem {
     pub version: Option<String>,
 }
 
+/// <p>The configuration item status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConfigurationItemStatus {
+    /// <p><code>OK</code></p>
+    Ok,
+    /// <p><code>ResourceDiscovered</code></p>
+    ResourceDiscovered,
+    /// <p><code>ResourceNotRecorded</code></p>
+    ResourceNotRecorded,
+    /// <p><code>ResourceDeleted</code></p>
+    ResourceDeleted,
+    /// <p><code>ResourceDeletedNotRecorded</code></p>
+    ResourceDeletedNotRecorded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConfigurationItemStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationItemStatus::Ok => "OK",
+            ConfigurationItemStatus::ResourceDiscovered => "ResourceDiscovered",
+            ConfigurationItemStatus::ResourceNotRecorded => "ResourceNotRecorded",
+            ConfigurationItemStatus::ResourceDeleted => "ResourceDeleted",
+            ConfigurationItemStatus::ResourceDeletedNotRecorded => "ResourceDeletedNotRecorded",
+            ConfigurationItemStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationItemStatus {
+    fn from(value: &'a str) -> ConfigurationItemStatus {
+        match value {
+            "OK" => ConfigurationItemStatus::Ok,
+            "ResourceDiscovered" => ConfigurationItemStatus::ResourceDiscovered,
+            "ResourceNotRecorded" => ConfigurationItemStatus::ResourceNotRecorded,
+            "ResourceDeleted" => ConfigurationItemStatus::ResourceDeleted,
+            "ResourceDeletedNotRecorded" => ConfigurationItemStatus::ResourceDeletedNotRecorded,
+            _ => ConfigurationItemStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationItemStatus {
+    fn from(value: String) -> ConfigurationItemStatus {
+        ConfigurationItemStatus::from(value.as_str())
+    }
+}
+
+impl From<ConfigurationItemStatus> for String {
+    fn from(value: ConfigurationItemStatus) -> String {
+        match value {
+            ConfigurationItemStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigurationItemStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConfigurationItemStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfigurationItemStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConfigurationItemStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConfigurationItemStatus::from(value))
+    }
+}
+
 /// <p>An object that represents the recording of configuration changes of an AWS resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConfigurationRecorder {
@@ -711,20 +1350,23 @@ pub struct ConfigurationRecorderStatus {
     pub last_error_message: Option<String>,
     /// <p>The time the recorder was last started.</p>
     #[serde(rename = "lastStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_start_time: Option<f64>,
+    pub last_start_time: Option<EpochTimestamp>,
     /// <p>The last (previous) status of the recorder.</p>
     #[serde(rename = "lastStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status: Option<String>,
+    pub last_status: Option<RecorderStatus>,
     /// <p>The time when the status was last changed.</p>
     #[serde(rename = "lastStatusChangeTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_status_change_time: Option<f64>,
+    pub last_status_change_time: Option<EpochTimestamp>,
     /// <p>The time the recorder was last stopped.</p>
     #[serde(rename = "lastStopTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_stop_time: Option<f64>,
+    pub last_stop_time: Option<EpochTimestamp>,
     /// <p>The name of the configuration recorder.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -745,12 +1387,87 @@ pub struct DeleteAggregationAuthorizationRequest {
     pub authorized_aws_region: String,
 }
 
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DeleteConfigRuleRequest {
-    /// <p>The name of the AWS Config rule that you want to delete.</p>
-    #[serde(rename = "ConfigRuleName")]
-    pub config_rule_name: String,
+impl DeleteAggregationAuthorizationRequest {
+    /// Returns a builder for `DeleteAggregationAuthorizationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAggregationAuthorizationRequestBuilder {
+        DeleteAggregationAuthorizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAggregationAuthorizationRequest`](struct.DeleteAggregationAuthorizationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAggregationAuthorizationRequestBuilder {
+    authorized_account_id: Option<String>,
+    authorized_aws_region: Option<String>,
+}
+
+impl DeleteAggregationAuthorizationRequestBuilder {
+    /// Sets the [`authorized_account_id`](struct.DeleteAggregationAuthorizationRequest.html#structfield.authorized_account_id) member.
+    pub fn authorized_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorized_account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorized_aws_region`](struct.DeleteAggregationAuthorizationRequest.html#structfield.authorized_aws_region) member.
+    pub fn authorized_aws_region<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorized_aws_region = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAggregationAuthorizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAggregationAuthorizationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteAggregationAuthorizationRequest {
+            authorized_account_id: self
+                .authorized_account_id
+                .ok_or(::rusoto_core::BuildError::missing("authorized_account_id"))?,
+            authorized_aws_region: self
+                .authorized_aws_region
+                .ok_or(::rusoto_core::BuildError::missing("authorized_aws_region"))?,
+        })
+    }
+}
+
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteConfigRuleRequest {
+    /// <p>The name of the AWS Config rule that you want to delete.</p>
+    #[serde(rename = "ConfigRuleName")]
+    pub config_rule_name: String,
+}
+
+impl DeleteConfigRuleRequest {
+    /// Returns a builder for `DeleteConfigRuleRequest`, checking its required members when built.
+    pub fn builder() -> DeleteConfigRuleRequestBuilder {
+        DeleteConfigRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConfigRuleRequest`](struct.DeleteConfigRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteConfigRuleRequestBuilder {
+    config_rule_name: Option<String>,
+}
+
+impl DeleteConfigRuleRequestBuilder {
+    /// Sets the [`config_rule_name`](struct.DeleteConfigRuleRequest.html#structfield.config_rule_name) member.
+    pub fn config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConfigRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteConfigRuleRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteConfigRuleRequest {
+            config_rule_name: self
+                .config_rule_name
+                .ok_or(::rusoto_core::BuildError::missing("config_rule_name"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -760,6 +1477,39 @@ pub struct DeleteConfigurationAggregatorRequest {
     pub configuration_aggregator_name: String,
 }
 
+impl DeleteConfigurationAggregatorRequest {
+    /// Returns a builder for `DeleteConfigurationAggregatorRequest`, checking its required members when built.
+    pub fn builder() -> DeleteConfigurationAggregatorRequestBuilder {
+        DeleteConfigurationAggregatorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConfigurationAggregatorRequest`](struct.DeleteConfigurationAggregatorRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteConfigurationAggregatorRequestBuilder {
+    configuration_aggregator_name: Option<String>,
+}
+
+impl DeleteConfigurationAggregatorRequestBuilder {
+    /// Sets the [`configuration_aggregator_name`](struct.DeleteConfigurationAggregatorRequest.html#structfield.configuration_aggregator_name) member.
+    pub fn configuration_aggregator_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_aggregator_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConfigurationAggregatorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteConfigurationAggregatorRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteConfigurationAggregatorRequest {
+            configuration_aggregator_name: self.configuration_aggregator_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_aggregator_name"),
+            )?,
+        })
+    }
+}
+
 /// <p>The request object for the <code>DeleteConfigurationRecorder</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteConfigurationRecorderRequest {
@@ -768,6 +1518,38 @@ pub struct DeleteConfigurationRecorderRequest {
     pub configuration_recorder_name: String,
 }
 
+impl DeleteConfigurationRecorderRequest {
+    /// Returns a builder for `DeleteConfigurationRecorderRequest`, checking its required members when built.
+    pub fn builder() -> DeleteConfigurationRecorderRequestBuilder {
+        DeleteConfigurationRecorderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConfigurationRecorderRequest`](struct.DeleteConfigurationRecorderRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteConfigurationRecorderRequestBuilder {
+    configuration_recorder_name: Option<String>,
+}
+
+impl DeleteConfigurationRecorderRequestBuilder {
+    /// Sets the [`configuration_recorder_name`](struct.DeleteConfigurationRecorderRequest.html#structfield.configuration_recorder_name) member.
+    pub fn configuration_recorder_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_recorder_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConfigurationRecorderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteConfigurationRecorderRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteConfigurationRecorderRequest {
+            configuration_recorder_name: self.configuration_recorder_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_recorder_name"),
+            )?,
+        })
+    }
+}
+
 /// <p>The input for the <a>DeleteDeliveryChannel</a> action. The action accepts the following data, in JSON format. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDeliveryChannelRequest {
@@ -776,6 +1558,38 @@ pub struct DeleteDeliveryChannelRequest {
     pub delivery_channel_name: String,
 }
 
+impl DeleteDeliveryChannelRequest {
+    /// Returns a builder for `DeleteDeliveryChannelRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDeliveryChannelRequestBuilder {
+        DeleteDeliveryChannelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDeliveryChannelRequest`](struct.DeleteDeliveryChannelRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDeliveryChannelRequestBuilder {
+    delivery_channel_name: Option<String>,
+}
+
+impl DeleteDeliveryChannelRequestBuilder {
+    /// Sets the [`delivery_channel_name`](struct.DeleteDeliveryChannelRequest.html#structfield.delivery_channel_name) member.
+    pub fn delivery_channel_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_channel_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDeliveryChannelRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDeliveryChannelRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDeliveryChannelRequest {
+            delivery_channel_name: self
+                .delivery_channel_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_channel_name"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteEvaluationResultsRequest {
@@ -784,6 +1598,38 @@ pub struct DeleteEvaluationResultsRequest {
     pub config_rule_name: String,
 }
 
+impl DeleteEvaluationResultsRequest {
+    /// Returns a builder for `DeleteEvaluationResultsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteEvaluationResultsRequestBuilder {
+        DeleteEvaluationResultsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEvaluationResultsRequest`](struct.DeleteEvaluationResultsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteEvaluationResultsRequestBuilder {
+    config_rule_name: Option<String>,
+}
+
+impl DeleteEvaluationResultsRequestBuilder {
+    /// Sets the [`config_rule_name`](struct.DeleteEvaluationResultsRequest.html#structfield.config_rule_name) member.
+    pub fn config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEvaluationResultsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteEvaluationResultsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteEvaluationResultsRequest {
+            config_rule_name: self
+                .config_rule_name
+                .ok_or(::rusoto_core::BuildError::missing("config_rule_name"))?,
+        })
+    }
+}
+
 /// <p>The output when you delete the evaluation results for the specified AWS Config rule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -796,6 +1642,38 @@ pub struct DeleteOrganizationConfigRuleRequest {
     pub organization_config_rule_name: String,
 }
 
+impl DeleteOrganizationConfigRuleRequest {
+    /// Returns a builder for `DeleteOrganizationConfigRuleRequest`, checking its required members when built.
+    pub fn builder() -> DeleteOrganizationConfigRuleRequestBuilder {
+        DeleteOrganizationConfigRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteOrganizationConfigRuleRequest`](struct.DeleteOrganizationConfigRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteOrganizationConfigRuleRequestBuilder {
+    organization_config_rule_name: Option<String>,
+}
+
+impl DeleteOrganizationConfigRuleRequestBuilder {
+    /// Sets the [`organization_config_rule_name`](struct.DeleteOrganizationConfigRuleRequest.html#structfield.organization_config_rule_name) member.
+    pub fn organization_config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteOrganizationConfigRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteOrganizationConfigRuleRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteOrganizationConfigRuleRequest {
+            organization_config_rule_name: self.organization_config_rule_name.ok_or(
+                ::rusoto_core::BuildError::missing("organization_config_rule_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeletePendingAggregationRequestRequest {
     /// <p>The 12-digit account ID of the account requesting to aggregate data.</p>
@@ -806,6 +1684,49 @@ pub struct DeletePendingAggregationRequestRequest {
     pub requester_aws_region: String,
 }
 
+impl DeletePendingAggregationRequestRequest {
+    /// Returns a builder for `DeletePendingAggregationRequestRequest`, checking its required members when built.
+    pub fn builder() -> DeletePendingAggregationRequestRequestBuilder {
+        DeletePendingAggregationRequestRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePendingAggregationRequestRequest`](struct.DeletePendingAggregationRequestRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePendingAggregationRequestRequestBuilder {
+    requester_account_id: Option<String>,
+    requester_aws_region: Option<String>,
+}
+
+impl DeletePendingAggregationRequestRequestBuilder {
+    /// Sets the [`requester_account_id`](struct.DeletePendingAggregationRequestRequest.html#structfield.requester_account_id) member.
+    pub fn requester_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.requester_account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`requester_aws_region`](struct.DeletePendingAggregationRequestRequest.html#structfield.requester_aws_region) member.
+    pub fn requester_aws_region<T: Into<String>>(mut self, value: T) -> Self {
+        self.requester_aws_region = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePendingAggregationRequestRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeletePendingAggregationRequestRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeletePendingAggregationRequestRequest {
+            requester_account_id: self
+                .requester_account_id
+                .ok_or(::rusoto_core::BuildError::missing("requester_account_id"))?,
+            requester_aws_region: self
+                .requester_aws_region
+                .ok_or(::rusoto_core::BuildError::missing("requester_aws_region"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteRemediationConfigurationRequest {
     /// <p>The name of the AWS Config rule for which you want to delete remediation configuration.</p>
@@ -817,6 +1738,47 @@ pub struct DeleteRemediationConfigurationRequest {
     pub resource_type: Option<String>,
 }
 
+impl DeleteRemediationConfigurationRequest {
+    /// Returns a builder for `DeleteRemediationConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRemediationConfigurationRequestBuilder {
+        DeleteRemediationConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRemediationConfigurationRequest`](struct.DeleteRemediationConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRemediationConfigurationRequestBuilder {
+    config_rule_name: Option<String>,
+    resource_type: Option<String>,
+}
+
+impl DeleteRemediationConfigurationRequestBuilder {
+    /// Sets the [`config_rule_name`](struct.DeleteRemediationConfigurationRequest.html#structfield.config_rule_name) member.
+    pub fn config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.DeleteRemediationConfigurationRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRemediationConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRemediationConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteRemediationConfigurationRequest {
+            config_rule_name: self
+                .config_rule_name
+                .ok_or(::rusoto_core::BuildError::missing("config_rule_name"))?,
+            resource_type: self.resource_type,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRemediationConfigurationResponse {}
@@ -831,6 +1793,51 @@ pub struct DeleteRemediationExceptionsRequest {
     pub resource_keys: Vec<RemediationExceptionResourceKey>,
 }
 
+impl DeleteRemediationExceptionsRequest {
+    /// Returns a builder for `DeleteRemediationExceptionsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRemediationExceptionsRequestBuilder {
+        DeleteRemediationExceptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRemediationExceptionsRequest`](struct.DeleteRemediationExceptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRemediationExceptionsRequestBuilder {
+    config_rule_name: Option<String>,
+    resource_keys: Option<Vec<RemediationExceptionResourceKey>>,
+}
+
+impl DeleteRemediationExceptionsRequestBuilder {
+    /// Sets the [`config_rule_name`](struct.DeleteRemediationExceptionsRequest.html#structfield.config_rule_name) member.
+    pub fn config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_keys`](struct.DeleteRemediationExceptionsRequest.html#structfield.resource_keys) member.
+    pub fn resource_keys<T: Into<Vec<RemediationExceptionResourceKey>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.resource_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRemediationExceptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRemediationExceptionsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRemediationExceptionsRequest {
+            config_rule_name: self
+                .config_rule_name
+                .ok_or(::rusoto_core::BuildError::missing("config_rule_name"))?,
+            resource_keys: self
+                .resource_keys
+                .ok_or(::rusoto_core::BuildError::missing("resource_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRemediationExceptionsResponse {
@@ -847,6 +1854,38 @@ pub struct DeleteRetentionConfigurationRequest {
     pub retention_configuration_name: String,
 }
 
+impl DeleteRetentionConfigurationRequest {
+    /// Returns a builder for `DeleteRetentionConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRetentionConfigurationRequestBuilder {
+        DeleteRetentionConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRetentionConfigurationRequest`](struct.DeleteRetentionConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRetentionConfigurationRequestBuilder {
+    retention_configuration_name: Option<String>,
+}
+
+impl DeleteRetentionConfigurationRequestBuilder {
+    /// Sets the [`retention_configuration_name`](struct.DeleteRetentionConfigurationRequest.html#structfield.retention_configuration_name) member.
+    pub fn retention_configuration_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.retention_configuration_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRetentionConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRetentionConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRetentionConfigurationRequest {
+            retention_configuration_name: self.retention_configuration_name.ok_or(
+                ::rusoto_core::BuildError::missing("retention_configuration_name"),
+            )?,
+        })
+    }
+}
+
 /// <p>The input for the <a>DeliverConfigSnapshot</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeliverConfigSnapshotRequest {
@@ -855,6 +1894,38 @@ pub struct DeliverConfigSnapshotRequest {
     pub delivery_channel_name: String,
 }
 
+impl DeliverConfigSnapshotRequest {
+    /// Returns a builder for `DeliverConfigSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> DeliverConfigSnapshotRequestBuilder {
+        DeliverConfigSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeliverConfigSnapshotRequest`](struct.DeliverConfigSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct DeliverConfigSnapshotRequestBuilder {
+    delivery_channel_name: Option<String>,
+}
+
+impl DeliverConfigSnapshotRequestBuilder {
+    /// Sets the [`delivery_channel_name`](struct.DeliverConfigSnapshotRequest.html#structfield.delivery_channel_name) member.
+    pub fn delivery_channel_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_channel_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeliverConfigSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeliverConfigSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(DeliverConfigSnapshotRequest {
+            delivery_channel_name: self
+                .delivery_channel_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_channel_name"))?,
+        })
+    }
+}
+
 /// <p>The output for the <a>DeliverConfigSnapshot</a> action, in JSON format.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -912,6 +1983,82 @@ pub struct DeliveryChannelStatus {
     pub name: Option<String>,
 }
 
+/// <p>Status of the last attempted delivery.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeliveryStatus {
+    /// <p><code>Success</code></p>
+    Success,
+    /// <p><code>Failure</code></p>
+    Failure,
+    /// <p><code>Not_Applicable</code></p>
+    NotApplicable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeliveryStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliveryStatus::Success => "Success",
+            DeliveryStatus::Failure => "Failure",
+            DeliveryStatus::NotApplicable => "Not_Applicable",
+            DeliveryStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeliveryStatus {
+    fn from(value: &'a str) -> DeliveryStatus {
+        match value {
+            "Success" => DeliveryStatus::Success,
+            "Failure" => DeliveryStatus::Failure,
+            "Not_Applicable" => DeliveryStatus::NotApplicable,
+            _ => DeliveryStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliveryStatus {
+    fn from(value: String) -> DeliveryStatus {
+        DeliveryStatus::from(value.as_str())
+    }
+}
+
+impl From<DeliveryStatus> for String {
+    fn from(value: DeliveryStatus) -> String {
+        match value {
+            DeliveryStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeliveryStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliveryStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeliveryStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeliveryStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeAggregateComplianceByConfigRulesRequest {
     /// <p>The name of the configuration aggregator.</p>
@@ -931,6 +2078,65 @@ pub struct DescribeAggregateComplianceByConfigRulesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeAggregateComplianceByConfigRulesRequest {
+    /// Returns a builder for `DescribeAggregateComplianceByConfigRulesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAggregateComplianceByConfigRulesRequestBuilder {
+        DescribeAggregateComplianceByConfigRulesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAggregateComplianceByConfigRulesRequest`](struct.DescribeAggregateComplianceByConfigRulesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAggregateComplianceByConfigRulesRequestBuilder {
+    configuration_aggregator_name: Option<String>,
+    filters: Option<ConfigRuleComplianceFilters>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeAggregateComplianceByConfigRulesRequestBuilder {
+    /// Sets the [`configuration_aggregator_name`](struct.DescribeAggregateComplianceByConfigRulesRequest.html#structfield.configuration_aggregator_name) member.
+    pub fn configuration_aggregator_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_aggregator_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeAggregateComplianceByConfigRulesRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<ConfigRuleComplianceFilters>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeAggregateComplianceByConfigRulesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeAggregateComplianceByConfigRulesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAggregateComplianceByConfigRulesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeAggregateComplianceByConfigRulesRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeAggregateComplianceByConfigRulesRequest {
+            configuration_aggregator_name: self.configuration_aggregator_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_aggregator_name"),
+            )?,
+            filters: self.filters,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAggregateComplianceByConfigRulesResponse {
@@ -956,6 +2162,45 @@ pub struct DescribeAggregationAuthorizationsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeAggregationAuthorizationsRequest {
+    /// Returns a builder for `DescribeAggregationAuthorizationsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAggregationAuthorizationsRequestBuilder {
+        DescribeAggregationAuthorizationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAggregationAuthorizationsRequest`](struct.DescribeAggregationAuthorizationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAggregationAuthorizationsRequestBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeAggregationAuthorizationsRequestBuilder {
+    /// Sets the [`limit`](struct.DescribeAggregationAuthorizationsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeAggregationAuthorizationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAggregationAuthorizationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAggregationAuthorizationsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeAggregationAuthorizationsRequest {
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAggregationAuthorizationsResponse {
@@ -975,7 +2220,7 @@ pub struct DescribeComplianceByConfigRuleRequest {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code> and <code>NON_COMPLIANT</code>.</p>
     #[serde(rename = "ComplianceTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_types: Option<Vec<String>>,
+    pub compliance_types: Option<Vec<ComplianceType>>,
     /// <p>Specify one or more AWS Config rule names to filter the results by rule.</p>
     #[serde(rename = "ConfigRuleNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -986,6 +2231,53 @@ pub struct DescribeComplianceByConfigRuleRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeComplianceByConfigRuleRequest {
+    /// Returns a builder for `DescribeComplianceByConfigRuleRequest`, checking its required members when built.
+    pub fn builder() -> DescribeComplianceByConfigRuleRequestBuilder {
+        DescribeComplianceByConfigRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeComplianceByConfigRuleRequest`](struct.DescribeComplianceByConfigRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeComplianceByConfigRuleRequestBuilder {
+    compliance_types: Option<Vec<ComplianceType>>,
+    config_rule_names: Option<Vec<String>>,
+    next_token: Option<String>,
+}
+
+impl DescribeComplianceByConfigRuleRequestBuilder {
+    /// Sets the [`compliance_types`](struct.DescribeComplianceByConfigRuleRequest.html#structfield.compliance_types) member.
+    pub fn compliance_types<T: Into<Vec<ComplianceType>>>(mut self, value: T) -> Self {
+        self.compliance_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`config_rule_names`](struct.DescribeComplianceByConfigRuleRequest.html#structfield.config_rule_names) member.
+    pub fn config_rule_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.config_rule_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeComplianceByConfigRuleRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeComplianceByConfigRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeComplianceByConfigRuleRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeComplianceByConfigRuleRequest {
+            compliance_types: self.compliance_types,
+            config_rule_names: self.config_rule_names,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1006,7 +2298,7 @@ pub struct DescribeComplianceByResourceRequest {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>INSUFFICIENT_DATA</code>.</p>
     #[serde(rename = "ComplianceTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_types: Option<Vec<String>>,
+    pub compliance_types: Option<Vec<ComplianceType>>,
     /// <p>The maximum number of evaluation results returned on each page. The default is 10. You cannot specify a number greater than 100. If you specify 0, AWS Config uses the default.</p>
     #[serde(rename = "Limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1025,6 +2317,68 @@ pub struct DescribeComplianceByResourceRequest {
     pub resource_type: Option<String>,
 }
 
+impl DescribeComplianceByResourceRequest {
+    /// Returns a builder for `DescribeComplianceByResourceRequest`, checking its required members when built.
+    pub fn builder() -> DescribeComplianceByResourceRequestBuilder {
+        DescribeComplianceByResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeComplianceByResourceRequest`](struct.DescribeComplianceByResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeComplianceByResourceRequestBuilder {
+    compliance_types: Option<Vec<ComplianceType>>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    resource_id: Option<String>,
+    resource_type: Option<String>,
+}
+
+impl DescribeComplianceByResourceRequestBuilder {
+    /// Sets the [`compliance_types`](struct.DescribeComplianceByResourceRequest.html#structfield.compliance_types) member.
+    pub fn compliance_types<T: Into<Vec<ComplianceType>>>(mut self, value: T) -> Self {
+        self.compliance_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeComplianceByResourceRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeComplianceByResourceRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.DescribeComplianceByResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.DescribeComplianceByResourceRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeComplianceByResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeComplianceByResourceRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeComplianceByResourceRequest {
+            compliance_types: self.compliance_types,
+            limit: self.limit,
+            next_token: self.next_token,
+            resource_id: self.resource_id,
+            resource_type: self.resource_type,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1056,13 +2410,60 @@ pub struct DescribeConfigRuleEvaluationStatusRequest {
     pub next_token: Option<String>,
 }
 
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DescribeConfigRuleEvaluationStatusResponse {
-    /// <p>Status information about your AWS managed Config rules.</p>
-    #[serde(rename = "ConfigRulesEvaluationStatus")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl DescribeConfigRuleEvaluationStatusRequest {
+    /// Returns a builder for `DescribeConfigRuleEvaluationStatusRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConfigRuleEvaluationStatusRequestBuilder {
+        DescribeConfigRuleEvaluationStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigRuleEvaluationStatusRequest`](struct.DescribeConfigRuleEvaluationStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigRuleEvaluationStatusRequestBuilder {
+    config_rule_names: Option<Vec<String>>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeConfigRuleEvaluationStatusRequestBuilder {
+    /// Sets the [`config_rule_names`](struct.DescribeConfigRuleEvaluationStatusRequest.html#structfield.config_rule_names) member.
+    pub fn config_rule_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.config_rule_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeConfigRuleEvaluationStatusRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeConfigRuleEvaluationStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigRuleEvaluationStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigRuleEvaluationStatusRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeConfigRuleEvaluationStatusRequest {
+            config_rule_names: self.config_rule_names,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeConfigRuleEvaluationStatusResponse {
+    /// <p>Status information about your AWS managed Config rules.</p>
+    #[serde(rename = "ConfigRulesEvaluationStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub config_rules_evaluation_status: Option<Vec<ConfigRuleEvaluationStatus>>,
     /// <p>The string that you use in a subsequent request to get the next page of results in a paginated response.</p>
     #[serde(rename = "NextToken")]
@@ -1083,6 +2484,44 @@ pub struct DescribeConfigRulesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeConfigRulesRequest {
+    /// Returns a builder for `DescribeConfigRulesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConfigRulesRequestBuilder {
+        DescribeConfigRulesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigRulesRequest`](struct.DescribeConfigRulesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigRulesRequestBuilder {
+    config_rule_names: Option<Vec<String>>,
+    next_token: Option<String>,
+}
+
+impl DescribeConfigRulesRequestBuilder {
+    /// Sets the [`config_rule_names`](struct.DescribeConfigRulesRequest.html#structfield.config_rule_names) member.
+    pub fn config_rule_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.config_rule_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeConfigRulesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigRulesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigRulesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeConfigRulesRequest {
+            config_rule_names: self.config_rule_names,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1113,7 +2552,66 @@ pub struct DescribeConfigurationAggregatorSourcesStatusRequest {
     /// <p><p>Filters the status type.</p> <ul> <li> <p>Valid value FAILED indicates errors while moving data.</p> </li> <li> <p>Valid value SUCCEEDED indicates the data was successfully moved.</p> </li> <li> <p>Valid value OUTDATED indicates the data is not the most recent.</p> </li> </ul></p>
     #[serde(rename = "UpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_status: Option<Vec<String>>,
+    pub update_status: Option<Vec<AggregatedSourceStatusType>>,
+}
+
+impl DescribeConfigurationAggregatorSourcesStatusRequest {
+    /// Returns a builder for `DescribeConfigurationAggregatorSourcesStatusRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConfigurationAggregatorSourcesStatusRequestBuilder {
+        DescribeConfigurationAggregatorSourcesStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigurationAggregatorSourcesStatusRequest`](struct.DescribeConfigurationAggregatorSourcesStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigurationAggregatorSourcesStatusRequestBuilder {
+    configuration_aggregator_name: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    update_status: Option<Vec<AggregatedSourceStatusType>>,
+}
+
+impl DescribeConfigurationAggregatorSourcesStatusRequestBuilder {
+    /// Sets the [`configuration_aggregator_name`](struct.DescribeConfigurationAggregatorSourcesStatusRequest.html#structfield.configuration_aggregator_name) member.
+    pub fn configuration_aggregator_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_aggregator_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeConfigurationAggregatorSourcesStatusRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeConfigurationAggregatorSourcesStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_status`](struct.DescribeConfigurationAggregatorSourcesStatusRequest.html#structfield.update_status) member.
+    pub fn update_status<T: Into<Vec<AggregatedSourceStatusType>>>(mut self, value: T) -> Self {
+        self.update_status = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigurationAggregatorSourcesStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeConfigurationAggregatorSourcesStatusRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeConfigurationAggregatorSourcesStatusRequest {
+            configuration_aggregator_name: self.configuration_aggregator_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_aggregator_name"),
+            )?,
+            limit: self.limit,
+            next_token: self.next_token,
+            update_status: self.update_status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1145,6 +2643,53 @@ pub struct DescribeConfigurationAggregatorsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeConfigurationAggregatorsRequest {
+    /// Returns a builder for `DescribeConfigurationAggregatorsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConfigurationAggregatorsRequestBuilder {
+        DescribeConfigurationAggregatorsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigurationAggregatorsRequest`](struct.DescribeConfigurationAggregatorsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigurationAggregatorsRequestBuilder {
+    configuration_aggregator_names: Option<Vec<String>>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeConfigurationAggregatorsRequestBuilder {
+    /// Sets the [`configuration_aggregator_names`](struct.DescribeConfigurationAggregatorsRequest.html#structfield.configuration_aggregator_names) member.
+    pub fn configuration_aggregator_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.configuration_aggregator_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeConfigurationAggregatorsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeConfigurationAggregatorsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigurationAggregatorsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigurationAggregatorsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeConfigurationAggregatorsRequest {
+            configuration_aggregator_names: self.configuration_aggregator_names,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeConfigurationAggregatorsResponse {
@@ -1167,6 +2712,37 @@ pub struct DescribeConfigurationRecorderStatusRequest {
     pub configuration_recorder_names: Option<Vec<String>>,
 }
 
+impl DescribeConfigurationRecorderStatusRequest {
+    /// Returns a builder for `DescribeConfigurationRecorderStatusRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConfigurationRecorderStatusRequestBuilder {
+        DescribeConfigurationRecorderStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigurationRecorderStatusRequest`](struct.DescribeConfigurationRecorderStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigurationRecorderStatusRequestBuilder {
+    configuration_recorder_names: Option<Vec<String>>,
+}
+
+impl DescribeConfigurationRecorderStatusRequestBuilder {
+    /// Sets the [`configuration_recorder_names`](struct.DescribeConfigurationRecorderStatusRequest.html#structfield.configuration_recorder_names) member.
+    pub fn configuration_recorder_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.configuration_recorder_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigurationRecorderStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigurationRecorderStatusRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeConfigurationRecorderStatusRequest {
+            configuration_recorder_names: self.configuration_recorder_names,
+        })
+    }
+}
+
 /// <p>The output for the <a>DescribeConfigurationRecorderStatus</a> action, in JSON format.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1186,6 +2762,37 @@ pub struct DescribeConfigurationRecordersRequest {
     pub configuration_recorder_names: Option<Vec<String>>,
 }
 
+impl DescribeConfigurationRecordersRequest {
+    /// Returns a builder for `DescribeConfigurationRecordersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConfigurationRecordersRequestBuilder {
+        DescribeConfigurationRecordersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigurationRecordersRequest`](struct.DescribeConfigurationRecordersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigurationRecordersRequestBuilder {
+    configuration_recorder_names: Option<Vec<String>>,
+}
+
+impl DescribeConfigurationRecordersRequestBuilder {
+    /// Sets the [`configuration_recorder_names`](struct.DescribeConfigurationRecordersRequest.html#structfield.configuration_recorder_names) member.
+    pub fn configuration_recorder_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.configuration_recorder_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigurationRecordersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigurationRecordersRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeConfigurationRecordersRequest {
+            configuration_recorder_names: self.configuration_recorder_names,
+        })
+    }
+}
+
 /// <p>The output for the <a>DescribeConfigurationRecorders</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1205,6 +2812,37 @@ pub struct DescribeDeliveryChannelStatusRequest {
     pub delivery_channel_names: Option<Vec<String>>,
 }
 
+impl DescribeDeliveryChannelStatusRequest {
+    /// Returns a builder for `DescribeDeliveryChannelStatusRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDeliveryChannelStatusRequestBuilder {
+        DescribeDeliveryChannelStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDeliveryChannelStatusRequest`](struct.DescribeDeliveryChannelStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDeliveryChannelStatusRequestBuilder {
+    delivery_channel_names: Option<Vec<String>>,
+}
+
+impl DescribeDeliveryChannelStatusRequestBuilder {
+    /// Sets the [`delivery_channel_names`](struct.DescribeDeliveryChannelStatusRequest.html#structfield.delivery_channel_names) member.
+    pub fn delivery_channel_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.delivery_channel_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDeliveryChannelStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDeliveryChannelStatusRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDeliveryChannelStatusRequest {
+            delivery_channel_names: self.delivery_channel_names,
+        })
+    }
+}
+
 /// <p>The output for the <a>DescribeDeliveryChannelStatus</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1224,6 +2862,36 @@ pub struct DescribeDeliveryChannelsRequest {
     pub delivery_channel_names: Option<Vec<String>>,
 }
 
+impl DescribeDeliveryChannelsRequest {
+    /// Returns a builder for `DescribeDeliveryChannelsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDeliveryChannelsRequestBuilder {
+        DescribeDeliveryChannelsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDeliveryChannelsRequest`](struct.DescribeDeliveryChannelsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDeliveryChannelsRequestBuilder {
+    delivery_channel_names: Option<Vec<String>>,
+}
+
+impl DescribeDeliveryChannelsRequestBuilder {
+    /// Sets the [`delivery_channel_names`](struct.DescribeDeliveryChannelsRequest.html#structfield.delivery_channel_names) member.
+    pub fn delivery_channel_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.delivery_channel_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDeliveryChannelsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDeliveryChannelsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDeliveryChannelsRequest {
+            delivery_channel_names: self.delivery_channel_names,
+        })
+    }
+}
+
 /// <p>The output for the <a>DescribeDeliveryChannels</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1250,6 +2918,55 @@ pub struct DescribeOrganizationConfigRuleStatusesRequest {
     pub organization_config_rule_names: Option<Vec<String>>,
 }
 
+impl DescribeOrganizationConfigRuleStatusesRequest {
+    /// Returns a builder for `DescribeOrganizationConfigRuleStatusesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeOrganizationConfigRuleStatusesRequestBuilder {
+        DescribeOrganizationConfigRuleStatusesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeOrganizationConfigRuleStatusesRequest`](struct.DescribeOrganizationConfigRuleStatusesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeOrganizationConfigRuleStatusesRequestBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+    organization_config_rule_names: Option<Vec<String>>,
+}
+
+impl DescribeOrganizationConfigRuleStatusesRequestBuilder {
+    /// Sets the [`limit`](struct.DescribeOrganizationConfigRuleStatusesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeOrganizationConfigRuleStatusesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_config_rule_names`](struct.DescribeOrganizationConfigRuleStatusesRequest.html#structfield.organization_config_rule_names) member.
+    pub fn organization_config_rule_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.organization_config_rule_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeOrganizationConfigRuleStatusesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeOrganizationConfigRuleStatusesRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeOrganizationConfigRuleStatusesRequest {
+            limit: self.limit,
+            next_token: self.next_token,
+            organization_config_rule_names: self.organization_config_rule_names,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeOrganizationConfigRuleStatusesResponse {
@@ -1279,6 +2996,53 @@ pub struct DescribeOrganizationConfigRulesRequest {
     pub organization_config_rule_names: Option<Vec<String>>,
 }
 
+impl DescribeOrganizationConfigRulesRequest {
+    /// Returns a builder for `DescribeOrganizationConfigRulesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeOrganizationConfigRulesRequestBuilder {
+        DescribeOrganizationConfigRulesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeOrganizationConfigRulesRequest`](struct.DescribeOrganizationConfigRulesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeOrganizationConfigRulesRequestBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+    organization_config_rule_names: Option<Vec<String>>,
+}
+
+impl DescribeOrganizationConfigRulesRequestBuilder {
+    /// Sets the [`limit`](struct.DescribeOrganizationConfigRulesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeOrganizationConfigRulesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_config_rule_names`](struct.DescribeOrganizationConfigRulesRequest.html#structfield.organization_config_rule_names) member.
+    pub fn organization_config_rule_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.organization_config_rule_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeOrganizationConfigRulesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeOrganizationConfigRulesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeOrganizationConfigRulesRequest {
+            limit: self.limit,
+            next_token: self.next_token,
+            organization_config_rule_names: self.organization_config_rule_names,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeOrganizationConfigRulesResponse {
@@ -1304,6 +3068,45 @@ pub struct DescribePendingAggregationRequestsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribePendingAggregationRequestsRequest {
+    /// Returns a builder for `DescribePendingAggregationRequestsRequest`, checking its required members when built.
+    pub fn builder() -> DescribePendingAggregationRequestsRequestBuilder {
+        DescribePendingAggregationRequestsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePendingAggregationRequestsRequest`](struct.DescribePendingAggregationRequestsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribePendingAggregationRequestsRequestBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribePendingAggregationRequestsRequestBuilder {
+    /// Sets the [`limit`](struct.DescribePendingAggregationRequestsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribePendingAggregationRequestsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePendingAggregationRequestsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePendingAggregationRequestsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribePendingAggregationRequestsRequest {
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribePendingAggregationRequestsResponse {
@@ -1324,6 +3127,39 @@ pub struct DescribeRemediationConfigurationsRequest {
     pub config_rule_names: Vec<String>,
 }
 
+impl DescribeRemediationConfigurationsRequest {
+    /// Returns a builder for `DescribeRemediationConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeRemediationConfigurationsRequestBuilder {
+        DescribeRemediationConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRemediationConfigurationsRequest`](struct.DescribeRemediationConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeRemediationConfigurationsRequestBuilder {
+    config_rule_names: Option<Vec<String>>,
+}
+
+impl DescribeRemediationConfigurationsRequestBuilder {
+    /// Sets the [`config_rule_names`](struct.DescribeRemediationConfigurationsRequest.html#structfield.config_rule_names) member.
+    pub fn config_rule_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.config_rule_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRemediationConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeRemediationConfigurationsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeRemediationConfigurationsRequest {
+            config_rule_names: self
+                .config_rule_names
+                .ok_or(::rusoto_core::BuildError::missing("config_rule_names"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeRemediationConfigurationsResponse {
@@ -1352,6 +3188,66 @@ pub struct DescribeRemediationExceptionsRequest {
     pub resource_keys: Option<Vec<RemediationExceptionResourceKey>>,
 }
 
+impl DescribeRemediationExceptionsRequest {
+    /// Returns a builder for `DescribeRemediationExceptionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeRemediationExceptionsRequestBuilder {
+        DescribeRemediationExceptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRemediationExceptionsRequest`](struct.DescribeRemediationExceptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeRemediationExceptionsRequestBuilder {
+    config_rule_name: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    resource_keys: Option<Vec<RemediationExceptionResourceKey>>,
+}
+
+impl DescribeRemediationExceptionsRequestBuilder {
+    /// Sets the [`config_rule_name`](struct.DescribeRemediationExceptionsRequest.html#structfield.config_rule_name) member.
+    pub fn config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeRemediationExceptionsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeRemediationExceptionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_keys`](struct.DescribeRemediationExceptionsRequest.html#structfield.resource_keys) member.
+    pub fn resource_keys<T: Into<Vec<RemediationExceptionResourceKey>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.resource_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRemediationExceptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeRemediationExceptionsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeRemediationExceptionsRequest {
+            config_rule_name: self
+                .config_rule_name
+                .ok_or(::rusoto_core::BuildError::missing("config_rule_name"))?,
+            limit: self.limit,
+            next_token: self.next_token,
+            resource_keys: self.resource_keys,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeRemediationExceptionsResponse {
@@ -1384,6 +3280,63 @@ pub struct DescribeRemediationExecutionStatusRequest {
     pub resource_keys: Option<Vec<ResourceKey>>,
 }
 
+impl DescribeRemediationExecutionStatusRequest {
+    /// Returns a builder for `DescribeRemediationExecutionStatusRequest`, checking its required members when built.
+    pub fn builder() -> DescribeRemediationExecutionStatusRequestBuilder {
+        DescribeRemediationExecutionStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRemediationExecutionStatusRequest`](struct.DescribeRemediationExecutionStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeRemediationExecutionStatusRequestBuilder {
+    config_rule_name: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    resource_keys: Option<Vec<ResourceKey>>,
+}
+
+impl DescribeRemediationExecutionStatusRequestBuilder {
+    /// Sets the [`config_rule_name`](struct.DescribeRemediationExecutionStatusRequest.html#structfield.config_rule_name) member.
+    pub fn config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeRemediationExecutionStatusRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeRemediationExecutionStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_keys`](struct.DescribeRemediationExecutionStatusRequest.html#structfield.resource_keys) member.
+    pub fn resource_keys<T: Into<Vec<ResourceKey>>>(mut self, value: T) -> Self {
+        self.resource_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRemediationExecutionStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeRemediationExecutionStatusRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeRemediationExecutionStatusRequest {
+            config_rule_name: self
+                .config_rule_name
+                .ok_or(::rusoto_core::BuildError::missing("config_rule_name"))?,
+            limit: self.limit,
+            next_token: self.next_token,
+            resource_keys: self.resource_keys,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeRemediationExecutionStatusResponse {
@@ -1409,6 +3362,45 @@ pub struct DescribeRetentionConfigurationsRequest {
     pub retention_configuration_names: Option<Vec<String>>,
 }
 
+impl DescribeRetentionConfigurationsRequest {
+    /// Returns a builder for `DescribeRetentionConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeRetentionConfigurationsRequestBuilder {
+        DescribeRetentionConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRetentionConfigurationsRequest`](struct.DescribeRetentionConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeRetentionConfigurationsRequestBuilder {
+    next_token: Option<String>,
+    retention_configuration_names: Option<Vec<String>>,
+}
+
+impl DescribeRetentionConfigurationsRequestBuilder {
+    /// Sets the [`next_token`](struct.DescribeRetentionConfigurationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retention_configuration_names`](struct.DescribeRetentionConfigurationsRequest.html#structfield.retention_configuration_names) member.
+    pub fn retention_configuration_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.retention_configuration_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRetentionConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeRetentionConfigurationsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeRetentionConfigurationsRequest {
+            next_token: self.next_token,
+            retention_configuration_names: self.retention_configuration_names,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeRetentionConfigurationsResponse {
@@ -1423,7 +3415,7 @@ pub struct DescribeRetentionConfigurationsResponse {
 }
 
 /// <p>Identifies an AWS resource and indicates whether it complies with the AWS Config rule that it was evaluated against.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Evaluation {
     /// <p>Supplementary information about how the evaluation determined the compliance.</p>
     #[serde(rename = "Annotation")]
@@ -1437,10 +3429,11 @@ pub struct Evaluation {
     pub compliance_resource_type: String,
     /// <p>Indicates whether the AWS resource complies with the AWS Config rule that it was evaluated against.</p> <p>For the <code>Evaluation</code> data type, AWS Config supports only the <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>NOT_APPLICABLE</code> values. AWS Config does not support the <code>INSUFFICIENT_DATA</code> value for this data type.</p> <p>Similarly, AWS Config does not accept <code>INSUFFICIENT_DATA</code> as the value for <code>ComplianceType</code> from a <code>PutEvaluations</code> request. For example, an AWS Lambda function for a custom AWS Config rule cannot pass an <code>INSUFFICIENT_DATA</code> value to AWS Config.</p>
     #[serde(rename = "ComplianceType")]
-    pub compliance_type: String,
+    pub compliance_type: ComplianceType,
     /// <p>The time of the event in AWS Config that triggered the evaluation. For event-based evaluations, the time indicates when AWS Config created the configuration item that triggered the evaluation. For periodic evaluations, the time indicates when AWS Config triggered the evaluation at the frequency that you specified (for example, every 24 hours).</p>
     #[serde(rename = "OrderingTimestamp")]
-    pub ordering_timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub ordering_timestamp: EpochTimestamp,
 }
 
 /// <p>The details of an AWS Config evaluation. Provides the AWS resource that was evaluated, the compliance of the resource, related time stamps, and supplementary information.</p>
@@ -1454,19 +3447,21 @@ pub struct EvaluationResult {
     /// <p>Indicates whether the AWS resource complies with the AWS Config rule that evaluated it.</p> <p>For the <code>EvaluationResult</code> data type, AWS Config supports only the <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>NOT_APPLICABLE</code> values. AWS Config does not support the <code>INSUFFICIENT_DATA</code> value for the <code>EvaluationResult</code> data type.</p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ComplianceType>,
     /// <p>The time when the AWS Config rule evaluated the AWS resource.</p>
     #[serde(rename = "ConfigRuleInvokedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub config_rule_invoked_time: Option<f64>,
+    pub config_rule_invoked_time: Option<EpochTimestamp>,
     /// <p>Uniquely identifies the evaluation result.</p>
     #[serde(rename = "EvaluationResultIdentifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub evaluation_result_identifier: Option<EvaluationResultIdentifier>,
     /// <p>The time when AWS Config recorded the evaluation result.</p>
     #[serde(rename = "ResultRecordedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result_recorded_time: Option<f64>,
+    pub result_recorded_time: Option<EpochTimestamp>,
     /// <p>An encrypted token that associates an evaluation with an AWS Config rule. The token identifies the rule, the AWS resource being evaluated, and the event that triggered the evaluation.</p>
     #[serde(rename = "ResultToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1483,8 +3478,9 @@ pub struct EvaluationResultIdentifier {
     pub evaluation_result_qualifier: Option<EvaluationResultQualifier>,
     /// <p>The time of the event that triggered the evaluation of your AWS resources. The time can indicate when AWS Config delivered a configuration item change notification, or it can indicate when AWS Config delivered the configuration snapshot, depending on which event triggered the evaluation.</p>
     #[serde(rename = "OrderingTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ordering_timestamp: Option<f64>,
+    pub ordering_timestamp: Option<EpochTimestamp>,
 }
 
 /// <p>Identifies an AWS Config rule that evaluated an AWS resource, and provides the type and ID of the resource that the rule evaluated.</p>
@@ -1505,37 +3501,105 @@ pub struct EvaluationResultQualifier {
     pub resource_type: Option<String>,
 }
 
-/// <p>The controls that AWS Config uses for executing remediations.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ExecutionControls {
-    /// <p>A SsmControls object.</p>
-    #[serde(rename = "SsmControls")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ssm_controls: Option<SsmControls>,
+/// <p>The source of the event, such as an AWS service, that triggers AWS Config to evaluate your AWS resources.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventSource {
+    /// <p><code>aws.config</code></p>
+    AwsConfig,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>List of each of the failed delete remediation exceptions with specific reasons.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct FailedDeleteRemediationExceptionsBatch {
-    /// <p>Returns remediation exception resource key object of the failed items.</p>
-    #[serde(rename = "FailedItems")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub failed_items: Option<Vec<RemediationExceptionResourceKey>>,
-    /// <p>Returns a failure message for delete remediation exception. For example, AWS Config creates an exception due to an internal error.</p>
-    #[serde(rename = "FailureMessage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_message: Option<String>,
+impl EventSource {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventSource::AwsConfig => "aws.config",
+            EventSource::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>List of each of the failed remediations with specific reasons.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct FailedRemediationBatch {
-    /// <p>Returns remediation configurations of the failed items.</p>
-    #[serde(rename = "FailedItems")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub failed_items: Option<Vec<RemediationConfiguration>>,
+impl<'a> From<&'a str> for EventSource {
+    fn from(value: &'a str) -> EventSource {
+        match value {
+            "aws.config" => EventSource::AwsConfig,
+            _ => EventSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventSource {
+    fn from(value: String) -> EventSource {
+        EventSource::from(value.as_str())
+    }
+}
+
+impl From<EventSource> for String {
+    fn from(value: EventSource) -> String {
+        match value {
+            EventSource::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EventSource {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventSource {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EventSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EventSource::from(value))
+    }
+}
+
+/// <p>The controls that AWS Config uses for executing remediations.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionControls {
+    /// <p>A SsmControls object.</p>
+    #[serde(rename = "SsmControls")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssm_controls: Option<SsmControls>,
+}
+
+/// <p>List of each of the failed delete remediation exceptions with specific reasons.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct FailedDeleteRemediationExceptionsBatch {
+    /// <p>Returns remediation exception resource key object of the failed items.</p>
+    #[serde(rename = "FailedItems")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_items: Option<Vec<RemediationExceptionResourceKey>>,
+    /// <p>Returns a failure message for delete remediation exception. For example, AWS Config creates an exception due to an internal error.</p>
+    #[serde(rename = "FailureMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_message: Option<String>,
+}
+
+/// <p>List of each of the failed remediations with specific reasons.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct FailedRemediationBatch {
+    /// <p>Returns remediation configurations of the failed items.</p>
+    #[serde(rename = "FailedItems")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_items: Option<Vec<RemediationConfiguration>>,
     /// <p>Returns a failure message. For example, the resource is already compliant.</p>
     #[serde(rename = "FailureMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1577,7 +3641,7 @@ pub struct GetAggregateComplianceDetailsByConfigRuleRequest {
     /// <p><p>The resource compliance status.</p> <note> <p>For the <code>GetAggregateComplianceDetailsByConfigRuleRequest</code> data type, AWS Config supports only the <code>COMPLIANT</code> and <code>NON<em>COMPLIANT</code>. AWS Config does not support the <code>NOT</em>APPLICABLE</code> and <code>INSUFFICIENT_DATA</code> values.</p> </note></p>
     #[serde(rename = "ComplianceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_type: Option<String>,
+    pub compliance_type: Option<ComplianceType>,
     /// <p>The name of the AWS Config rule for which you want compliance information.</p>
     #[serde(rename = "ConfigRuleName")]
     pub config_rule_name: String,
@@ -1594,6 +3658,95 @@ pub struct GetAggregateComplianceDetailsByConfigRuleRequest {
     pub next_token: Option<String>,
 }
 
+impl GetAggregateComplianceDetailsByConfigRuleRequest {
+    /// Returns a builder for `GetAggregateComplianceDetailsByConfigRuleRequest`, checking its required members when built.
+    pub fn builder() -> GetAggregateComplianceDetailsByConfigRuleRequestBuilder {
+        GetAggregateComplianceDetailsByConfigRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAggregateComplianceDetailsByConfigRuleRequest`](struct.GetAggregateComplianceDetailsByConfigRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAggregateComplianceDetailsByConfigRuleRequestBuilder {
+    account_id: Option<String>,
+    aws_region: Option<String>,
+    compliance_type: Option<ComplianceType>,
+    config_rule_name: Option<String>,
+    configuration_aggregator_name: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetAggregateComplianceDetailsByConfigRuleRequestBuilder {
+    /// Sets the [`account_id`](struct.GetAggregateComplianceDetailsByConfigRuleRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`aws_region`](struct.GetAggregateComplianceDetailsByConfigRuleRequest.html#structfield.aws_region) member.
+    pub fn aws_region<T: Into<String>>(mut self, value: T) -> Self {
+        self.aws_region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`compliance_type`](struct.GetAggregateComplianceDetailsByConfigRuleRequest.html#structfield.compliance_type) member.
+    pub fn compliance_type<T: Into<ComplianceType>>(mut self, value: T) -> Self {
+        self.compliance_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`config_rule_name`](struct.GetAggregateComplianceDetailsByConfigRuleRequest.html#structfield.config_rule_name) member.
+    pub fn config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration_aggregator_name`](struct.GetAggregateComplianceDetailsByConfigRuleRequest.html#structfield.configuration_aggregator_name) member.
+    pub fn configuration_aggregator_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_aggregator_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetAggregateComplianceDetailsByConfigRuleRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetAggregateComplianceDetailsByConfigRuleRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAggregateComplianceDetailsByConfigRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        GetAggregateComplianceDetailsByConfigRuleRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(GetAggregateComplianceDetailsByConfigRuleRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            aws_region: self
+                .aws_region
+                .ok_or(::rusoto_core::BuildError::missing("aws_region"))?,
+            compliance_type: self.compliance_type,
+            config_rule_name: self
+                .config_rule_name
+                .ok_or(::rusoto_core::BuildError::missing("config_rule_name"))?,
+            configuration_aggregator_name: self.configuration_aggregator_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_aggregator_name"),
+            )?,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAggregateComplianceDetailsByConfigRuleResponse {
@@ -1619,7 +3772,7 @@ pub struct GetAggregateConfigRuleComplianceSummaryRequest {
     /// <p>Groups the result based on ACCOUNT_ID or AWS_REGION.</p>
     #[serde(rename = "GroupByKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub group_by_key: Option<String>,
+    pub group_by_key: Option<ConfigRuleComplianceSummaryGroupKey>,
     /// <p>The maximum number of evaluation results returned on each page. The default is 1000. You cannot specify a number greater than 1000. If you specify 0, AWS Config uses the default.</p>
     #[serde(rename = "Limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1630,6 +3783,73 @@ pub struct GetAggregateConfigRuleComplianceSummaryRequest {
     pub next_token: Option<String>,
 }
 
+impl GetAggregateConfigRuleComplianceSummaryRequest {
+    /// Returns a builder for `GetAggregateConfigRuleComplianceSummaryRequest`, checking its required members when built.
+    pub fn builder() -> GetAggregateConfigRuleComplianceSummaryRequestBuilder {
+        GetAggregateConfigRuleComplianceSummaryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAggregateConfigRuleComplianceSummaryRequest`](struct.GetAggregateConfigRuleComplianceSummaryRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAggregateConfigRuleComplianceSummaryRequestBuilder {
+    configuration_aggregator_name: Option<String>,
+    filters: Option<ConfigRuleComplianceSummaryFilters>,
+    group_by_key: Option<ConfigRuleComplianceSummaryGroupKey>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetAggregateConfigRuleComplianceSummaryRequestBuilder {
+    /// Sets the [`configuration_aggregator_name`](struct.GetAggregateConfigRuleComplianceSummaryRequest.html#structfield.configuration_aggregator_name) member.
+    pub fn configuration_aggregator_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_aggregator_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.GetAggregateConfigRuleComplianceSummaryRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<ConfigRuleComplianceSummaryFilters>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_by_key`](struct.GetAggregateConfigRuleComplianceSummaryRequest.html#structfield.group_by_key) member.
+    pub fn group_by_key<T: Into<ConfigRuleComplianceSummaryGroupKey>>(mut self, value: T) -> Self {
+        self.group_by_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetAggregateConfigRuleComplianceSummaryRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetAggregateConfigRuleComplianceSummaryRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAggregateConfigRuleComplianceSummaryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        GetAggregateConfigRuleComplianceSummaryRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(GetAggregateConfigRuleComplianceSummaryRequest {
+            configuration_aggregator_name: self.configuration_aggregator_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_aggregator_name"),
+            )?,
+            filters: self.filters,
+            group_by_key: self.group_by_key,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAggregateConfigRuleComplianceSummaryResponse {
@@ -1659,7 +3879,7 @@ pub struct GetAggregateDiscoveredResourceCountsRequest {
     /// <p>The key to group the resource counts.</p>
     #[serde(rename = "GroupByKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub group_by_key: Option<String>,
+    pub group_by_key: Option<ResourceCountGroupKey>,
     /// <p>The maximum number of <a>GroupedResourceCount</a> objects returned on each page. The default is 1000. You cannot specify a number greater than 1000. If you specify 0, AWS Config uses the default.</p>
     #[serde(rename = "Limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1670,6 +3890,71 @@ pub struct GetAggregateDiscoveredResourceCountsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetAggregateDiscoveredResourceCountsRequest {
+    /// Returns a builder for `GetAggregateDiscoveredResourceCountsRequest`, checking its required members when built.
+    pub fn builder() -> GetAggregateDiscoveredResourceCountsRequestBuilder {
+        GetAggregateDiscoveredResourceCountsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAggregateDiscoveredResourceCountsRequest`](struct.GetAggregateDiscoveredResourceCountsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAggregateDiscoveredResourceCountsRequestBuilder {
+    configuration_aggregator_name: Option<String>,
+    filters: Option<ResourceCountFilters>,
+    group_by_key: Option<ResourceCountGroupKey>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetAggregateDiscoveredResourceCountsRequestBuilder {
+    /// Sets the [`configuration_aggregator_name`](struct.GetAggregateDiscoveredResourceCountsRequest.html#structfield.configuration_aggregator_name) member.
+    pub fn configuration_aggregator_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_aggregator_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.GetAggregateDiscoveredResourceCountsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<ResourceCountFilters>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_by_key`](struct.GetAggregateDiscoveredResourceCountsRequest.html#structfield.group_by_key) member.
+    pub fn group_by_key<T: Into<ResourceCountGroupKey>>(mut self, value: T) -> Self {
+        self.group_by_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetAggregateDiscoveredResourceCountsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetAggregateDiscoveredResourceCountsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAggregateDiscoveredResourceCountsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAggregateDiscoveredResourceCountsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetAggregateDiscoveredResourceCountsRequest {
+            configuration_aggregator_name: self.configuration_aggregator_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_aggregator_name"),
+            )?,
+            filters: self.filters,
+            group_by_key: self.group_by_key,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAggregateDiscoveredResourceCountsResponse {
@@ -1690,7 +3975,7 @@ pub struct GetAggregateDiscoveredResourceCountsResponse {
     pub total_discovered_resources: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetAggregateResourceConfigRequest {
     /// <p>The name of the configuration aggregator.</p>
     #[serde(rename = "ConfigurationAggregatorName")]
@@ -1700,6 +3985,48 @@ pub struct GetAggregateResourceConfigRequest {
     pub resource_identifier: AggregateResourceIdentifier,
 }
 
+impl GetAggregateResourceConfigRequest {
+    /// Returns a builder for `GetAggregateResourceConfigRequest`, checking its required members when built.
+    pub fn builder() -> GetAggregateResourceConfigRequestBuilder {
+        GetAggregateResourceConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAggregateResourceConfigRequest`](struct.GetAggregateResourceConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAggregateResourceConfigRequestBuilder {
+    configuration_aggregator_name: Option<String>,
+    resource_identifier: Option<AggregateResourceIdentifier>,
+}
+
+impl GetAggregateResourceConfigRequestBuilder {
+    /// Sets the [`configuration_aggregator_name`](struct.GetAggregateResourceConfigRequest.html#structfield.configuration_aggregator_name) member.
+    pub fn configuration_aggregator_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_aggregator_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_identifier`](struct.GetAggregateResourceConfigRequest.html#structfield.resource_identifier) member.
+    pub fn resource_identifier<T: Into<AggregateResourceIdentifier>>(mut self, value: T) -> Self {
+        self.resource_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAggregateResourceConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAggregateResourceConfigRequest, ::rusoto_core::BuildError> {
+        Ok(GetAggregateResourceConfigRequest {
+            configuration_aggregator_name: self.configuration_aggregator_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_aggregator_name"),
+            )?,
+            resource_identifier: self
+                .resource_identifier
+                .ok_or(::rusoto_core::BuildError::missing("resource_identifier"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAggregateResourceConfigResponse {
@@ -1715,7 +4042,7 @@ pub struct GetComplianceDetailsByConfigRuleRequest {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>NOT_APPLICABLE</code>.</p>
     #[serde(rename = "ComplianceTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_types: Option<Vec<String>>,
+    pub compliance_types: Option<Vec<ComplianceType>>,
     /// <p>The name of the AWS Config rule for which you want compliance information.</p>
     #[serde(rename = "ConfigRuleName")]
     pub config_rule_name: String,
@@ -1729,6 +4056,63 @@ pub struct GetComplianceDetailsByConfigRuleRequest {
     pub next_token: Option<String>,
 }
 
+impl GetComplianceDetailsByConfigRuleRequest {
+    /// Returns a builder for `GetComplianceDetailsByConfigRuleRequest`, checking its required members when built.
+    pub fn builder() -> GetComplianceDetailsByConfigRuleRequestBuilder {
+        GetComplianceDetailsByConfigRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetComplianceDetailsByConfigRuleRequest`](struct.GetComplianceDetailsByConfigRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct GetComplianceDetailsByConfigRuleRequestBuilder {
+    compliance_types: Option<Vec<ComplianceType>>,
+    config_rule_name: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetComplianceDetailsByConfigRuleRequestBuilder {
+    /// Sets the [`compliance_types`](struct.GetComplianceDetailsByConfigRuleRequest.html#structfield.compliance_types) member.
+    pub fn compliance_types<T: Into<Vec<ComplianceType>>>(mut self, value: T) -> Self {
+        self.compliance_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`config_rule_name`](struct.GetComplianceDetailsByConfigRuleRequest.html#structfield.config_rule_name) member.
+    pub fn config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetComplianceDetailsByConfigRuleRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetComplianceDetailsByConfigRuleRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetComplianceDetailsByConfigRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetComplianceDetailsByConfigRuleRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetComplianceDetailsByConfigRuleRequest {
+            compliance_types: self.compliance_types,
+            config_rule_name: self
+                .config_rule_name
+                .ok_or(::rusoto_core::BuildError::missing("config_rule_name"))?,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1749,7 +4133,7 @@ pub struct GetComplianceDetailsByResourceRequest {
     /// <p>Filters the results by compliance.</p> <p>The allowed values are <code>COMPLIANT</code>, <code>NON_COMPLIANT</code>, and <code>NOT_APPLICABLE</code>.</p>
     #[serde(rename = "ComplianceTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_types: Option<Vec<String>>,
+    pub compliance_types: Option<Vec<ComplianceType>>,
     /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1762,6 +4146,65 @@ pub struct GetComplianceDetailsByResourceRequest {
     pub resource_type: String,
 }
 
+impl GetComplianceDetailsByResourceRequest {
+    /// Returns a builder for `GetComplianceDetailsByResourceRequest`, checking its required members when built.
+    pub fn builder() -> GetComplianceDetailsByResourceRequestBuilder {
+        GetComplianceDetailsByResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetComplianceDetailsByResourceRequest`](struct.GetComplianceDetailsByResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetComplianceDetailsByResourceRequestBuilder {
+    compliance_types: Option<Vec<ComplianceType>>,
+    next_token: Option<String>,
+    resource_id: Option<String>,
+    resource_type: Option<String>,
+}
+
+impl GetComplianceDetailsByResourceRequestBuilder {
+    /// Sets the [`compliance_types`](struct.GetComplianceDetailsByResourceRequest.html#structfield.compliance_types) member.
+    pub fn compliance_types<T: Into<Vec<ComplianceType>>>(mut self, value: T) -> Self {
+        self.compliance_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetComplianceDetailsByResourceRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.GetComplianceDetailsByResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.GetComplianceDetailsByResourceRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetComplianceDetailsByResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetComplianceDetailsByResourceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetComplianceDetailsByResourceRequest {
+            compliance_types: self.compliance_types,
+            next_token: self.next_token,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            resource_type: self
+                .resource_type
+                .ok_or(::rusoto_core::BuildError::missing("resource_type"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1795,6 +4238,37 @@ pub struct GetComplianceSummaryByResourceTypeRequest {
     pub resource_types: Option<Vec<String>>,
 }
 
+impl GetComplianceSummaryByResourceTypeRequest {
+    /// Returns a builder for `GetComplianceSummaryByResourceTypeRequest`, checking its required members when built.
+    pub fn builder() -> GetComplianceSummaryByResourceTypeRequestBuilder {
+        GetComplianceSummaryByResourceTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetComplianceSummaryByResourceTypeRequest`](struct.GetComplianceSummaryByResourceTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct GetComplianceSummaryByResourceTypeRequestBuilder {
+    resource_types: Option<Vec<String>>,
+}
+
+impl GetComplianceSummaryByResourceTypeRequestBuilder {
+    /// Sets the [`resource_types`](struct.GetComplianceSummaryByResourceTypeRequest.html#structfield.resource_types) member.
+    pub fn resource_types<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_types = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetComplianceSummaryByResourceTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetComplianceSummaryByResourceTypeRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetComplianceSummaryByResourceTypeRequest {
+            resource_types: self.resource_types,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1821,6 +4295,52 @@ pub struct GetDiscoveredResourceCountsRequest {
     pub resource_types: Option<Vec<String>>,
 }
 
+impl GetDiscoveredResourceCountsRequest {
+    /// Returns a builder for `GetDiscoveredResourceCountsRequest`, checking its required members when built.
+    pub fn builder() -> GetDiscoveredResourceCountsRequestBuilder {
+        GetDiscoveredResourceCountsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDiscoveredResourceCountsRequest`](struct.GetDiscoveredResourceCountsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDiscoveredResourceCountsRequestBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+    resource_types: Option<Vec<String>>,
+}
+
+impl GetDiscoveredResourceCountsRequestBuilder {
+    /// Sets the [`limit`](struct.GetDiscoveredResourceCountsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetDiscoveredResourceCountsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_types`](struct.GetDiscoveredResourceCountsRequest.html#structfield.resource_types) member.
+    pub fn resource_types<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_types = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDiscoveredResourceCountsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDiscoveredResourceCountsRequest, ::rusoto_core::BuildError> {
+        Ok(GetDiscoveredResourceCountsRequest {
+            limit: self.limit,
+            next_token: self.next_token,
+            resource_types: self.resource_types,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDiscoveredResourceCountsResponse {
@@ -1857,6 +4377,65 @@ pub struct GetOrganizationConfigRuleDetailedStatusRequest {
     pub organization_config_rule_name: String,
 }
 
+impl GetOrganizationConfigRuleDetailedStatusRequest {
+    /// Returns a builder for `GetOrganizationConfigRuleDetailedStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetOrganizationConfigRuleDetailedStatusRequestBuilder {
+        GetOrganizationConfigRuleDetailedStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetOrganizationConfigRuleDetailedStatusRequest`](struct.GetOrganizationConfigRuleDetailedStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetOrganizationConfigRuleDetailedStatusRequestBuilder {
+    filters: Option<StatusDetailFilters>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    organization_config_rule_name: Option<String>,
+}
+
+impl GetOrganizationConfigRuleDetailedStatusRequestBuilder {
+    /// Sets the [`filters`](struct.GetOrganizationConfigRuleDetailedStatusRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<StatusDetailFilters>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetOrganizationConfigRuleDetailedStatusRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetOrganizationConfigRuleDetailedStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_config_rule_name`](struct.GetOrganizationConfigRuleDetailedStatusRequest.html#structfield.organization_config_rule_name) member.
+    pub fn organization_config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOrganizationConfigRuleDetailedStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        GetOrganizationConfigRuleDetailedStatusRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(GetOrganizationConfigRuleDetailedStatusRequest {
+            filters: self.filters,
+            limit: self.limit,
+            next_token: self.next_token,
+            organization_config_rule_name: self.organization_config_rule_name.ok_or(
+                ::rusoto_core::BuildError::missing("organization_config_rule_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetOrganizationConfigRuleDetailedStatusResponse {
@@ -1871,20 +4450,22 @@ pub struct GetOrganizationConfigRuleDetailedStatusResponse {
 }
 
 /// <p>The input for the <a>GetResourceConfigHistory</a> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetResourceConfigHistoryRequest {
     /// <p>The chronological order for configuration items listed. By default, the results are listed in reverse chronological order.</p>
     #[serde(rename = "chronologicalOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chronological_order: Option<String>,
+    pub chronological_order: Option<ChronologicalOrder>,
     /// <p>The time stamp that indicates an earlier time. If not specified, the action returns paginated results that contain configuration items that start when the first configuration item was recorded.</p>
     #[serde(rename = "earlierTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub earlier_time: Option<f64>,
+    pub earlier_time: Option<EpochTimestamp>,
     /// <p>The time stamp that indicates a later time. If not specified, current time is taken.</p>
     #[serde(rename = "laterTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub later_time: Option<f64>,
+    pub later_time: Option<EpochTimestamp>,
     /// <p>The maximum number of configuration items returned on each page. The default is 10. You cannot specify a number greater than 100. If you specify 0, AWS Config uses the default.</p>
     #[serde(rename = "limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1898,7 +4479,89 @@ pub struct GetResourceConfigHistoryRequest {
     pub resource_id: String,
     /// <p>The resource type.</p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
+}
+
+impl GetResourceConfigHistoryRequest {
+    /// Returns a builder for `GetResourceConfigHistoryRequest`, checking its required members when built.
+    pub fn builder() -> GetResourceConfigHistoryRequestBuilder {
+        GetResourceConfigHistoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourceConfigHistoryRequest`](struct.GetResourceConfigHistoryRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResourceConfigHistoryRequestBuilder {
+    chronological_order: Option<ChronologicalOrder>,
+    earlier_time: Option<EpochTimestamp>,
+    later_time: Option<EpochTimestamp>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    resource_id: Option<String>,
+    resource_type: Option<ResourceType>,
+}
+
+impl GetResourceConfigHistoryRequestBuilder {
+    /// Sets the [`chronological_order`](struct.GetResourceConfigHistoryRequest.html#structfield.chronological_order) member.
+    pub fn chronological_order<T: Into<ChronologicalOrder>>(mut self, value: T) -> Self {
+        self.chronological_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`earlier_time`](struct.GetResourceConfigHistoryRequest.html#structfield.earlier_time) member.
+    pub fn earlier_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.earlier_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`later_time`](struct.GetResourceConfigHistoryRequest.html#structfield.later_time) member.
+    pub fn later_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.later_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetResourceConfigHistoryRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetResourceConfigHistoryRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.GetResourceConfigHistoryRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.GetResourceConfigHistoryRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<ResourceType>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourceConfigHistoryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetResourceConfigHistoryRequest, ::rusoto_core::BuildError> {
+        Ok(GetResourceConfigHistoryRequest {
+            chronological_order: self.chronological_order,
+            earlier_time: self.earlier_time,
+            later_time: self.later_time,
+            limit: self.limit,
+            next_token: self.next_token,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            resource_type: self
+                .resource_type
+                .ok_or(::rusoto_core::BuildError::missing("resource_type"))?,
+        })
+    }
 }
 
 /// <p>The output for the <a>GetResourceConfigHistory</a> action.</p>
@@ -1927,7 +4590,7 @@ pub struct GroupedResourceCount {
     pub resource_count: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListAggregateDiscoveredResourcesRequest {
     /// <p>The name of the configuration aggregator. </p>
     #[serde(rename = "ConfigurationAggregatorName")]
@@ -1946,24 +4609,91 @@ pub struct ListAggregateDiscoveredResourcesRequest {
     pub next_token: Option<String>,
     /// <p>The type of resources that you want AWS Config to list in the response.</p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListAggregateDiscoveredResourcesResponse {
-    /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>Returns a list of <code>ResourceIdentifiers</code> objects.</p>
-    #[serde(rename = "ResourceIdentifiers")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_identifiers: Option<Vec<AggregateResourceIdentifier>>,
+impl ListAggregateDiscoveredResourcesRequest {
+    /// Returns a builder for `ListAggregateDiscoveredResourcesRequest`, checking its required members when built.
+    pub fn builder() -> ListAggregateDiscoveredResourcesRequestBuilder {
+        ListAggregateDiscoveredResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAggregateDiscoveredResourcesRequest`](struct.ListAggregateDiscoveredResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAggregateDiscoveredResourcesRequestBuilder {
+    configuration_aggregator_name: Option<String>,
+    filters: Option<ResourceFilters>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    resource_type: Option<ResourceType>,
+}
+
+impl ListAggregateDiscoveredResourcesRequestBuilder {
+    /// Sets the [`configuration_aggregator_name`](struct.ListAggregateDiscoveredResourcesRequest.html#structfield.configuration_aggregator_name) member.
+    pub fn configuration_aggregator_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_aggregator_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.ListAggregateDiscoveredResourcesRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<ResourceFilters>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListAggregateDiscoveredResourcesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAggregateDiscoveredResourcesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.ListAggregateDiscoveredResourcesRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<ResourceType>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAggregateDiscoveredResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAggregateDiscoveredResourcesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListAggregateDiscoveredResourcesRequest {
+            configuration_aggregator_name: self.configuration_aggregator_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_aggregator_name"),
+            )?,
+            filters: self.filters,
+            limit: self.limit,
+            next_token: self.next_token,
+            resource_type: self
+                .resource_type
+                .ok_or(::rusoto_core::BuildError::missing("resource_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListAggregateDiscoveredResourcesResponse {
+    /// <p>The <code>nextToken</code> string returned on a previous page that you use to get the next page of results in a paginated response.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>Returns a list of <code>ResourceIdentifiers</code> objects.</p>
+    #[serde(rename = "ResourceIdentifiers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_identifiers: Option<Vec<AggregateResourceIdentifier>>,
 }
 
 /// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListDiscoveredResourcesRequest {
     /// <p>Specifies whether AWS Config includes deleted resources in the results. By default, deleted resources are not included.</p>
     #[serde(rename = "includeDeletedResources")]
@@ -1987,7 +4717,79 @@ pub struct ListDiscoveredResourcesRequest {
     pub resource_name: Option<String>,
     /// <p>The type of resources that you want AWS Config to list in the response.</p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
+}
+
+impl ListDiscoveredResourcesRequest {
+    /// Returns a builder for `ListDiscoveredResourcesRequest`, checking its required members when built.
+    pub fn builder() -> ListDiscoveredResourcesRequestBuilder {
+        ListDiscoveredResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDiscoveredResourcesRequest`](struct.ListDiscoveredResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDiscoveredResourcesRequestBuilder {
+    include_deleted_resources: Option<bool>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    resource_ids: Option<Vec<String>>,
+    resource_name: Option<String>,
+    resource_type: Option<ResourceType>,
+}
+
+impl ListDiscoveredResourcesRequestBuilder {
+    /// Sets the [`include_deleted_resources`](struct.ListDiscoveredResourcesRequest.html#structfield.include_deleted_resources) member.
+    pub fn include_deleted_resources<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_deleted_resources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListDiscoveredResourcesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDiscoveredResourcesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_ids`](struct.ListDiscoveredResourcesRequest.html#structfield.resource_ids) member.
+    pub fn resource_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_name`](struct.ListDiscoveredResourcesRequest.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.ListDiscoveredResourcesRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<ResourceType>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDiscoveredResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDiscoveredResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(ListDiscoveredResourcesRequest {
+            include_deleted_resources: self.include_deleted_resources,
+            limit: self.limit,
+            next_token: self.next_token,
+            resource_ids: self.resource_ids,
+            resource_name: self.resource_name,
+            resource_type: self
+                .resource_type
+                .ok_or(::rusoto_core::BuildError::missing("resource_type"))?,
+        })
+    }
 }
 
 /// <p><p/></p>
@@ -2019,6 +4821,54 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`limit`](struct.ListTagsForResourceRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsForResourceRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            limit: self.limit,
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -2032,8 +4882,192 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<Vec<Tag>>,
 }
 
+/// <p><p>The maximum frequency with which AWS Config runs evaluations for a rule. You can specify a value for <code>MaximumExecutionFrequency</code> when:</p> <ul> <li> <p>You are using an AWS managed rule that is triggered at a periodic frequency.</p> </li> <li> <p>Your custom rule is triggered when AWS Config delivers the configuration snapshot. For more information, see <a>ConfigSnapshotDeliveryProperties</a>.</p> </li> </ul> <note> <p>By default, rules with a periodic trigger are evaluated every 24 hours. To change the frequency, specify a valid value for the <code>MaximumExecutionFrequency</code> parameter.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MaximumExecutionFrequency {
+    /// <p><code>One_Hour</code></p>
+    OneHour,
+    /// <p><code>Three_Hours</code></p>
+    ThreeHours,
+    /// <p><code>Six_Hours</code></p>
+    SixHours,
+    /// <p><code>Twelve_Hours</code></p>
+    TwelveHours,
+    /// <p><code>TwentyFour_Hours</code></p>
+    TwentyFourHours,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MaximumExecutionFrequency {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MaximumExecutionFrequency::OneHour => "One_Hour",
+            MaximumExecutionFrequency::ThreeHours => "Three_Hours",
+            MaximumExecutionFrequency::SixHours => "Six_Hours",
+            MaximumExecutionFrequency::TwelveHours => "Twelve_Hours",
+            MaximumExecutionFrequency::TwentyFourHours => "TwentyFour_Hours",
+            MaximumExecutionFrequency::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MaximumExecutionFrequency {
+    fn from(value: &'a str) -> MaximumExecutionFrequency {
+        match value {
+            "One_Hour" => MaximumExecutionFrequency::OneHour,
+            "Three_Hours" => MaximumExecutionFrequency::ThreeHours,
+            "Six_Hours" => MaximumExecutionFrequency::SixHours,
+            "Twelve_Hours" => MaximumExecutionFrequency::TwelveHours,
+            "TwentyFour_Hours" => MaximumExecutionFrequency::TwentyFourHours,
+            _ => MaximumExecutionFrequency::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MaximumExecutionFrequency {
+    fn from(value: String) -> MaximumExecutionFrequency {
+        MaximumExecutionFrequency::from(value.as_str())
+    }
+}
+
+impl From<MaximumExecutionFrequency> for String {
+    fn from(value: MaximumExecutionFrequency) -> String {
+        match value {
+            MaximumExecutionFrequency::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MaximumExecutionFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MaximumExecutionFrequency {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MaximumExecutionFrequency {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MaximumExecutionFrequency, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MaximumExecutionFrequency::from(value))
+    }
+}
+
+/// <p><p>Indicates deployment status for config rule in the member account. When master account calls <code>PutOrganizationConfigRule</code> action for the first time, config rule status is created in the member account. When master account calls <code>PutOrganizationConfigRule</code> action for the second time, config rule status is updated in the member account. Config rule status is deleted when the master account deletes <code>OrganizationConfigRule</code> and disables service access for <code>config-multiaccountsetup.amazonaws.com</code>. </p> <p> AWS Config sets the state of the rule to:</p> <ul> <li> <p> <code>CREATE<em>SUCCESSFUL</code> when config rule has been created in the member account. </p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> when config rule is being created in the member account.</p> </li> <li> <p> <code>CREATE</em>FAILED</code> when config rule creation has failed in the member account.</p> </li> <li> <p> <code>DELETE<em>FAILED</code> when config rule deletion has failed in the member account.</p> </li> <li> <p> <code>DELETE</em>IN<em>PROGRESS</code> when config rule is being deleted in the member account.</p> </li> <li> <p> <code>DELETE</em>SUCCESSFUL</code> when config rule has been deleted in the member account. </p> </li> <li> <p> <code>UPDATE<em>SUCCESSFUL</code> when config rule has been updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>IN<em>PROGRESS</code> when config rule is being updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>FAILED</code> when config rule deletion has failed in the member account.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MemberAccountRuleStatus {
+    /// <p><code>CREATE_SUCCESSFUL</code></p>
+    CreateSuccessful,
+    /// <p><code>CREATE_IN_PROGRESS</code></p>
+    CreateInProgress,
+    /// <p><code>CREATE_FAILED</code></p>
+    CreateFailed,
+    /// <p><code>DELETE_SUCCESSFUL</code></p>
+    DeleteSuccessful,
+    /// <p><code>DELETE_FAILED</code></p>
+    DeleteFailed,
+    /// <p><code>DELETE_IN_PROGRESS</code></p>
+    DeleteInProgress,
+    /// <p><code>UPDATE_SUCCESSFUL</code></p>
+    UpdateSuccessful,
+    /// <p><code>UPDATE_IN_PROGRESS</code></p>
+    UpdateInProgress,
+    /// <p><code>UPDATE_FAILED</code></p>
+    UpdateFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MemberAccountRuleStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MemberAccountRuleStatus::CreateSuccessful => "CREATE_SUCCESSFUL",
+            MemberAccountRuleStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            MemberAccountRuleStatus::CreateFailed => "CREATE_FAILED",
+            MemberAccountRuleStatus::DeleteSuccessful => "DELETE_SUCCESSFUL",
+            MemberAccountRuleStatus::DeleteFailed => "DELETE_FAILED",
+            MemberAccountRuleStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            MemberAccountRuleStatus::UpdateSuccessful => "UPDATE_SUCCESSFUL",
+            MemberAccountRuleStatus::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            MemberAccountRuleStatus::UpdateFailed => "UPDATE_FAILED",
+            MemberAccountRuleStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MemberAccountRuleStatus {
+    fn from(value: &'a str) -> MemberAccountRuleStatus {
+        match value {
+            "CREATE_SUCCESSFUL" => MemberAccountRuleStatus::CreateSuccessful,
+            "CREATE_IN_PROGRESS" => MemberAccountRuleStatus::CreateInProgress,
+            "CREATE_FAILED" => MemberAccountRuleStatus::CreateFailed,
+            "DELETE_SUCCESSFUL" => MemberAccountRuleStatus::DeleteSuccessful,
+            "DELETE_FAILED" => MemberAccountRuleStatus::DeleteFailed,
+            "DELETE_IN_PROGRESS" => MemberAccountRuleStatus::DeleteInProgress,
+            "UPDATE_SUCCESSFUL" => MemberAccountRuleStatus::UpdateSuccessful,
+            "UPDATE_IN_PROGRESS" => MemberAccountRuleStatus::UpdateInProgress,
+            "UPDATE_FAILED" => MemberAccountRuleStatus::UpdateFailed,
+            _ => MemberAccountRuleStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MemberAccountRuleStatus {
+    fn from(value: String) -> MemberAccountRuleStatus {
+        MemberAccountRuleStatus::from(value.as_str())
+    }
+}
+
+impl From<MemberAccountRuleStatus> for String {
+    fn from(value: MemberAccountRuleStatus) -> String {
+        match value {
+            MemberAccountRuleStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MemberAccountRuleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MemberAccountRuleStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MemberAccountRuleStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MemberAccountRuleStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MemberAccountRuleStatus::from(value))
+    }
+}
+
 /// <p>Organization config rule creation or deletion status in each member account. This includes the name of the rule, the status, error code and error message when the rule creation or deletion failed.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct MemberAccountStatus {
     /// <p>The 12-digit account ID of a member account.</p>
@@ -2052,11 +5086,104 @@ pub struct MemberAccountStatus {
     pub error_message: Option<String>,
     /// <p>The timestamp of the last status update.</p>
     #[serde(rename = "LastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p><p>Indicates deployment status for config rule in the member account. When master account calls <code>PutOrganizationConfigRule</code> action for the first time, config rule status is created in the member account. When master account calls <code>PutOrganizationConfigRule</code> action for the second time, config rule status is updated in the member account. Config rule status is deleted when the master account deletes <code>OrganizationConfigRule</code> and disables service access for <code>config-multiaccountsetup.amazonaws.com</code>. </p> <p> AWS Config sets the state of the rule to:</p> <ul> <li> <p> <code>CREATE<em>SUCCESSFUL</code> when config rule has been created in the member account. </p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> when config rule is being created in the member account.</p> </li> <li> <p> <code>CREATE</em>FAILED</code> when config rule creation has failed in the member account.</p> </li> <li> <p> <code>DELETE<em>FAILED</code> when config rule deletion has failed in the member account.</p> </li> <li> <p> <code>DELETE</em>IN<em>PROGRESS</code> when config rule is being deleted in the member account.</p> </li> <li> <p> <code>DELETE</em>SUCCESSFUL</code> when config rule has been deleted in the member account. </p> </li> <li> <p> <code>UPDATE<em>SUCCESSFUL</code> when config rule has been updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>IN<em>PROGRESS</code> when config rule is being updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>FAILED</code> when config rule deletion has failed in the member account.</p> </li> </ul></p>
     #[serde(rename = "MemberAccountRuleStatus")]
-    pub member_account_rule_status: String,
+    pub member_account_rule_status: MemberAccountRuleStatus,
+}
+
+/// <p>The type of notification that triggers AWS Config to run an evaluation for a rule. You can specify the following notification types:</p> <ul> <li> <p> <code>ConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers a configuration item as a result of a resource change.</p> </li> <li> <p> <code>OversizedConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers an oversized configuration item. AWS Config may generate this notification type when a resource changes and the notification exceeds the maximum size allowed by Amazon SNS.</p> </li> <li> <p> <code>ScheduledNotification</code> - Triggers a periodic evaluation at the frequency specified for <code>MaximumExecutionFrequency</code>.</p> </li> <li> <p> <code>ConfigurationSnapshotDeliveryCompleted</code> - Triggers a periodic evaluation when AWS Config delivers a configuration snapshot.</p> </li> </ul> <p>If you want your custom rule to be triggered by configuration changes, specify two SourceDetail objects, one for <code>ConfigurationItemChangeNotification</code> and one for <code>OversizedConfigurationItemChangeNotification</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MessageType {
+    /// <p><code>ConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers a configuration item as a result of a resource change.</p>
+    ConfigurationItemChangeNotification,
+    /// <p><code>ConfigurationSnapshotDeliveryCompleted</code> - Triggers a periodic evaluation when AWS Config delivers a configuration snapshot.</p>
+    ConfigurationSnapshotDeliveryCompleted,
+    /// <p><code>ScheduledNotification</code> - Triggers a periodic evaluation at the frequency specified for <code>MaximumExecutionFrequency</code>.</p>
+    ScheduledNotification,
+    /// <p><code>OversizedConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers an oversized configuration item. AWS Config may generate this notification type when a resource changes and the notification exceeds the maximum size allowed by Amazon SNS.</p>
+    OversizedConfigurationItemChangeNotification,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MessageType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageType::ConfigurationItemChangeNotification => {
+                "ConfigurationItemChangeNotification"
+            }
+            MessageType::ConfigurationSnapshotDeliveryCompleted => {
+                "ConfigurationSnapshotDeliveryCompleted"
+            }
+            MessageType::ScheduledNotification => "ScheduledNotification",
+            MessageType::OversizedConfigurationItemChangeNotification => {
+                "OversizedConfigurationItemChangeNotification"
+            }
+            MessageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MessageType {
+    fn from(value: &'a str) -> MessageType {
+        match value {
+            "ConfigurationItemChangeNotification" => {
+                MessageType::ConfigurationItemChangeNotification
+            }
+            "ConfigurationSnapshotDeliveryCompleted" => {
+                MessageType::ConfigurationSnapshotDeliveryCompleted
+            }
+            "ScheduledNotification" => MessageType::ScheduledNotification,
+            "OversizedConfigurationItemChangeNotification" => {
+                MessageType::OversizedConfigurationItemChangeNotification
+            }
+            _ => MessageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageType {
+    fn from(value: String) -> MessageType {
+        MessageType::from(value.as_str())
+    }
+}
+
+impl From<MessageType> for String {
+    fn from(value: MessageType) -> String {
+        match value {
+            MessageType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MessageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MessageType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MessageType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MessageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MessageType::from(value))
+    }
 }
 
 /// <p>This object contains regions to set up the aggregator and an IAM role to retrieve organization details.</p>
@@ -2085,8 +5212,9 @@ pub struct OrganizationConfigRule {
     pub excluded_accounts: Option<Vec<String>>,
     /// <p>The timestamp of the last update.</p>
     #[serde(rename = "LastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The Amazon Resource Name (ARN) of organization config rule.</p>
     #[serde(rename = "OrganizationConfigRuleArn")]
     pub organization_config_rule_arn: String,
@@ -2104,7 +5232,7 @@ pub struct OrganizationConfigRule {
 }
 
 /// <p>Returns the status for an organization config rule in an organization.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct OrganizationConfigRuleStatus {
     /// <p>An error code that is returned when organization config rule creation or deletion has failed.</p>
@@ -2117,14 +5245,101 @@ pub struct OrganizationConfigRuleStatus {
     pub error_message: Option<String>,
     /// <p>The timestamp of the last update.</p>
     #[serde(rename = "LastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The name that you assign to organization config rule.</p>
     #[serde(rename = "OrganizationConfigRuleName")]
     pub organization_config_rule_name: String,
     /// <p><p>Indicates deployment status of an organization config rule. When master account calls PutOrganizationConfigRule action for the first time, config rule status is created in all the member accounts. When master account calls PutOrganizationConfigRule action for the second time, config rule status is updated in all the member accounts. Additionally, config rule status is updated when one or more member accounts join or leave an organization. Config rule status is deleted when the master account deletes OrganizationConfigRule in all the member accounts and disables service access for <code>config-multiaccountsetup.amazonaws.com</code>.</p> <p>AWS Config sets the state of the rule to:</p> <ul> <li> <p> <code>CREATE<em>SUCCESSFUL</code> when an organization config rule has been successfully created in all the member accounts. </p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> when an organization config rule creation is in progress.</p> </li> <li> <p> <code>CREATE</em>FAILED</code> when an organization config rule creation failed in one or more member accounts within that organization.</p> </li> <li> <p> <code>DELETE<em>FAILED</code> when an organization config rule deletion failed in one or more member accounts within that organization.</p> </li> <li> <p> <code>DELETE</em>IN<em>PROGRESS</code> when an organization config rule deletion is in progress.</p> </li> <li> <p> <code>DELETE</em>SUCCESSFUL</code> when an organization config rule has been successfully deleted from all the member accounts.</p> </li> <li> <p> <code>UPDATE<em>SUCCESSFUL</code> when an organization config rule has been successfully updated in all the member accounts.</p> </li> <li> <p> <code>UPDATE</em>IN<em>PROGRESS</code> when an organization config rule update is in progress.</p> </li> <li> <p> <code>UPDATE</em>FAILED</code> when an organization config rule update failed in one or more member accounts within that organization.</p> </li> </ul></p>
     #[serde(rename = "OrganizationRuleStatus")]
-    pub organization_rule_status: String,
+    pub organization_rule_status: OrganizationRuleStatus,
+}
+
+/// <p><p>The type of notification that triggers AWS Config to run an evaluation for a rule. You can specify the following notification types:</p> <ul> <li> <p> <code>ConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers a configuration item as a result of a resource change.</p> </li> <li> <p> <code>OversizedConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers an oversized configuration item. AWS Config may generate this notification type when a resource changes and the notification exceeds the maximum size allowed by Amazon SNS.</p> </li> <li> <p> <code>ScheduledNotification</code> - Triggers a periodic evaluation at the frequency specified for <code>MaximumExecutionFrequency</code>.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrganizationConfigRuleTriggerType {
+    /// <p><code>ConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers a configuration item as a result of a resource change.</p>
+    ConfigurationItemChangeNotification,
+    /// <p><code>OversizedConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers an oversized configuration item. AWS Config may generate this notification type when a resource changes and the notification exceeds the maximum size allowed by Amazon SNS.</p>
+    OversizedConfigurationItemChangeNotification,
+    /// <p><code>ScheduledNotification</code> - Triggers a periodic evaluation at the frequency specified for <code>MaximumExecutionFrequency</code>.</p>
+    ScheduledNotification,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OrganizationConfigRuleTriggerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrganizationConfigRuleTriggerType::ConfigurationItemChangeNotification => {
+                "ConfigurationItemChangeNotification"
+            }
+            OrganizationConfigRuleTriggerType::OversizedConfigurationItemChangeNotification => {
+                "OversizedConfigurationItemChangeNotification"
+            }
+            OrganizationConfigRuleTriggerType::ScheduledNotification => "ScheduledNotification",
+            OrganizationConfigRuleTriggerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OrganizationConfigRuleTriggerType {
+    fn from(value: &'a str) -> OrganizationConfigRuleTriggerType {
+        match value {
+            "ConfigurationItemChangeNotification" => {
+                OrganizationConfigRuleTriggerType::ConfigurationItemChangeNotification
+            }
+            "OversizedConfigurationItemChangeNotification" => {
+                OrganizationConfigRuleTriggerType::OversizedConfigurationItemChangeNotification
+            }
+            "ScheduledNotification" => OrganizationConfigRuleTriggerType::ScheduledNotification,
+            _ => OrganizationConfigRuleTriggerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrganizationConfigRuleTriggerType {
+    fn from(value: String) -> OrganizationConfigRuleTriggerType {
+        OrganizationConfigRuleTriggerType::from(value.as_str())
+    }
+}
+
+impl From<OrganizationConfigRuleTriggerType> for String {
+    fn from(value: OrganizationConfigRuleTriggerType) -> String {
+        match value {
+            OrganizationConfigRuleTriggerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OrganizationConfigRuleTriggerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OrganizationConfigRuleTriggerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrganizationConfigRuleTriggerType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<OrganizationConfigRuleTriggerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrganizationConfigRuleTriggerType::from(value))
+    }
 }
 
 /// <p>An object that specifies organization custom rule metadata such as resource type, resource ID of AWS resource, Lamdba function ARN, and organization trigger types that trigger AWS Config to evaluate your AWS resources against a rule. It also provides the frequency with which you want AWS Config to run evaluations for the rule if the trigger type is periodic.</p>
@@ -2144,10 +5359,10 @@ pub struct OrganizationCustomRuleMetadata {
     /// <p><p>The maximum frequency with which AWS Config runs evaluations for a rule. Your custom rule is triggered when AWS Config delivers the configuration snapshot. For more information, see <a>ConfigSnapshotDeliveryProperties</a>.</p> <note> <p>By default, rules with a periodic trigger are evaluated every 24 hours. To change the frequency, specify a valid value for the <code>MaximumExecutionFrequency</code> parameter.</p> </note></p>
     #[serde(rename = "MaximumExecutionFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maximum_execution_frequency: Option<String>,
+    pub maximum_execution_frequency: Option<MaximumExecutionFrequency>,
     /// <p><p>The type of notification that triggers AWS Config to run an evaluation for a rule. You can specify the following notification types:</p> <ul> <li> <p> <code>ConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers a configuration item as a result of a resource change.</p> </li> <li> <p> <code>OversizedConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers an oversized configuration item. AWS Config may generate this notification type when a resource changes and the notification exceeds the maximum size allowed by Amazon SNS.</p> </li> <li> <p> <code>ScheduledNotification</code> - Triggers a periodic evaluation at the frequency specified for <code>MaximumExecutionFrequency</code>.</p> </li> </ul></p>
     #[serde(rename = "OrganizationConfigRuleTriggerTypes")]
-    pub organization_config_rule_trigger_types: Vec<String>,
+    pub organization_config_rule_trigger_types: Vec<OrganizationConfigRuleTriggerType>,
     /// <p>The ID of the AWS resource that was evaluated.</p>
     #[serde(rename = "ResourceIdScope")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2180,7 +5395,7 @@ pub struct OrganizationManagedRuleMetadata {
     /// <p><p>The maximum frequency with which AWS Config runs evaluations for a rule. You are using an AWS managed rule that is triggered at a periodic frequency.</p> <note> <p>By default, rules with a periodic trigger are evaluated every 24 hours. To change the frequency, specify a valid value for the <code>MaximumExecutionFrequency</code> parameter.</p> </note></p>
     #[serde(rename = "MaximumExecutionFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maximum_execution_frequency: Option<String>,
+    pub maximum_execution_frequency: Option<MaximumExecutionFrequency>,
     /// <p>The ID of the AWS resource that was evaluated.</p>
     #[serde(rename = "ResourceIdScope")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2202,65 +5417,329 @@ pub struct OrganizationManagedRuleMetadata {
     pub tag_value_scope: Option<String>,
 }
 
-/// <p>An object that represents the account ID and region of an aggregator account that is requesting authorization but is not yet authorized.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct PendingAggregationRequest {
-    /// <p>The 12-digit account ID of the account requesting to aggregate data.</p>
-    #[serde(rename = "RequesterAccountId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub requester_account_id: Option<String>,
-    /// <p>The region requesting to aggregate data. </p>
-    #[serde(rename = "RequesterAwsRegion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub requester_aws_region: Option<String>,
+/// <p><p>Indicates deployment status of an organization config rule. When master account calls PutOrganizationConfigRule action for the first time, config rule status is created in all the member accounts. When master account calls PutOrganizationConfigRule action for the second time, config rule status is updated in all the member accounts. Additionally, config rule status is updated when one or more member accounts join or leave an organization. Config rule status is deleted when the master account deletes OrganizationConfigRule in all the member accounts and disables service access for <code>config-multiaccountsetup.amazonaws.com</code>.</p> <p>AWS Config sets the state of the rule to:</p> <ul> <li> <p> <code>CREATE<em>SUCCESSFUL</code> when an organization config rule has been successfully created in all the member accounts. </p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> when an organization config rule creation is in progress.</p> </li> <li> <p> <code>CREATE</em>FAILED</code> when an organization config rule creation failed in one or more member accounts within that organization.</p> </li> <li> <p> <code>DELETE<em>FAILED</code> when an organization config rule deletion failed in one or more member accounts within that organization.</p> </li> <li> <p> <code>DELETE</em>IN<em>PROGRESS</code> when an organization config rule deletion is in progress.</p> </li> <li> <p> <code>DELETE</em>SUCCESSFUL</code> when an organization config rule has been successfully deleted from all the member accounts.</p> </li> <li> <p> <code>UPDATE<em>SUCCESSFUL</code> when an organization config rule has been successfully updated in all the member accounts.</p> </li> <li> <p> <code>UPDATE</em>IN<em>PROGRESS</code> when an organization config rule update is in progress.</p> </li> <li> <p> <code>UPDATE</em>FAILED</code> when an organization config rule update failed in one or more member accounts within that organization.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrganizationRuleStatus {
+    /// <p><code>CREATE_SUCCESSFUL</code></p>
+    CreateSuccessful,
+    /// <p><code>CREATE_IN_PROGRESS</code></p>
+    CreateInProgress,
+    /// <p><code>CREATE_FAILED</code></p>
+    CreateFailed,
+    /// <p><code>DELETE_SUCCESSFUL</code></p>
+    DeleteSuccessful,
+    /// <p><code>DELETE_FAILED</code></p>
+    DeleteFailed,
+    /// <p><code>DELETE_IN_PROGRESS</code></p>
+    DeleteInProgress,
+    /// <p><code>UPDATE_SUCCESSFUL</code></p>
+    UpdateSuccessful,
+    /// <p><code>UPDATE_IN_PROGRESS</code></p>
+    UpdateInProgress,
+    /// <p><code>UPDATE_FAILED</code></p>
+    UpdateFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OrganizationRuleStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrganizationRuleStatus::CreateSuccessful => "CREATE_SUCCESSFUL",
+            OrganizationRuleStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            OrganizationRuleStatus::CreateFailed => "CREATE_FAILED",
+            OrganizationRuleStatus::DeleteSuccessful => "DELETE_SUCCESSFUL",
+            OrganizationRuleStatus::DeleteFailed => "DELETE_FAILED",
+            OrganizationRuleStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            OrganizationRuleStatus::UpdateSuccessful => "UPDATE_SUCCESSFUL",
+            OrganizationRuleStatus::UpdateInProgress => "UPDATE_IN_PROGRESS",
+            OrganizationRuleStatus::UpdateFailed => "UPDATE_FAILED",
+            OrganizationRuleStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct PutAggregationAuthorizationRequest {
-    /// <p>The 12-digit account ID of the account authorized to aggregate data.</p>
-    #[serde(rename = "AuthorizedAccountId")]
-    pub authorized_account_id: String,
-    /// <p>The region authorized to collect aggregated data.</p>
-    #[serde(rename = "AuthorizedAwsRegion")]
-    pub authorized_aws_region: String,
-    /// <p>An array of tag object.</p>
-    #[serde(rename = "Tags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<Vec<Tag>>,
+impl<'a> From<&'a str> for OrganizationRuleStatus {
+    fn from(value: &'a str) -> OrganizationRuleStatus {
+        match value {
+            "CREATE_SUCCESSFUL" => OrganizationRuleStatus::CreateSuccessful,
+            "CREATE_IN_PROGRESS" => OrganizationRuleStatus::CreateInProgress,
+            "CREATE_FAILED" => OrganizationRuleStatus::CreateFailed,
+            "DELETE_SUCCESSFUL" => OrganizationRuleStatus::DeleteSuccessful,
+            "DELETE_FAILED" => OrganizationRuleStatus::DeleteFailed,
+            "DELETE_IN_PROGRESS" => OrganizationRuleStatus::DeleteInProgress,
+            "UPDATE_SUCCESSFUL" => OrganizationRuleStatus::UpdateSuccessful,
+            "UPDATE_IN_PROGRESS" => OrganizationRuleStatus::UpdateInProgress,
+            "UPDATE_FAILED" => OrganizationRuleStatus::UpdateFailed,
+            _ => OrganizationRuleStatus::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct PutAggregationAuthorizationResponse {
-    /// <p>Returns an AggregationAuthorization object. </p>
-    #[serde(rename = "AggregationAuthorization")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub aggregation_authorization: Option<AggregationAuthorization>,
+impl From<String> for OrganizationRuleStatus {
+    fn from(value: String) -> OrganizationRuleStatus {
+        OrganizationRuleStatus::from(value.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct PutConfigRuleRequest {
-    /// <p>The rule that you want to add to your account.</p>
-    #[serde(rename = "ConfigRule")]
-    pub config_rule: ConfigRule,
-    /// <p>An array of tag object.</p>
-    #[serde(rename = "Tags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<Vec<Tag>>,
+impl From<OrganizationRuleStatus> for String {
+    fn from(value: OrganizationRuleStatus) -> String {
+        match value {
+            OrganizationRuleStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct PutConfigurationAggregatorRequest {
-    /// <p>A list of AccountAggregationSource object. </p>
-    #[serde(rename = "AccountAggregationSources")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_aggregation_sources: Option<Vec<AccountAggregationSource>>,
-    /// <p>The name of the configuration aggregator.</p>
-    #[serde(rename = "ConfigurationAggregatorName")]
-    pub configuration_aggregator_name: String,
-    /// <p>An OrganizationAggregationSource object.</p>
-    #[serde(rename = "OrganizationAggregationSource")]
+impl fmt::Display for OrganizationRuleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OrganizationRuleStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrganizationRuleStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OrganizationRuleStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrganizationRuleStatus::from(value))
+    }
+}
+
+/// <p>Indicates whether AWS or the customer owns and manages the AWS Config rule.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Owner {
+    /// <p><code>CUSTOM_LAMBDA</code></p>
+    CustomLambda,
+    /// <p><code>AWS</code></p>
+    Aws,
+    /// <p><code>CUSTOM_POLICY</code></p>
+    CustomPolicy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Owner {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Owner::CustomLambda => "CUSTOM_LAMBDA",
+            Owner::Aws => "AWS",
+            Owner::CustomPolicy => "CUSTOM_POLICY",
+            Owner::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Owner {
+    fn from(value: &'a str) -> Owner {
+        match value {
+            "CUSTOM_LAMBDA" => Owner::CustomLambda,
+            "AWS" => Owner::Aws,
+            "CUSTOM_POLICY" => Owner::CustomPolicy,
+            _ => Owner::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Owner {
+    fn from(value: String) -> Owner {
+        Owner::from(value.as_str())
+    }
+}
+
+impl From<Owner> for String {
+    fn from(value: Owner) -> String {
+        match value {
+            Owner::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Owner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Owner {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Owner {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Owner, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Owner::from(value))
+    }
+}
+
+/// <p>An object that represents the account ID and region of an aggregator account that is requesting authorization but is not yet authorized.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct PendingAggregationRequest {
+    /// <p>The 12-digit account ID of the account requesting to aggregate data.</p>
+    #[serde(rename = "RequesterAccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requester_account_id: Option<String>,
+    /// <p>The region requesting to aggregate data. </p>
+    #[serde(rename = "RequesterAwsRegion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requester_aws_region: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct PutAggregationAuthorizationRequest {
+    /// <p>The 12-digit account ID of the account authorized to aggregate data.</p>
+    #[serde(rename = "AuthorizedAccountId")]
+    pub authorized_account_id: String,
+    /// <p>The region authorized to collect aggregated data.</p>
+    #[serde(rename = "AuthorizedAwsRegion")]
+    pub authorized_aws_region: String,
+    /// <p>An array of tag object.</p>
+    #[serde(rename = "Tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+impl PutAggregationAuthorizationRequest {
+    /// Returns a builder for `PutAggregationAuthorizationRequest`, checking its required members when built.
+    pub fn builder() -> PutAggregationAuthorizationRequestBuilder {
+        PutAggregationAuthorizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutAggregationAuthorizationRequest`](struct.PutAggregationAuthorizationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutAggregationAuthorizationRequestBuilder {
+    authorized_account_id: Option<String>,
+    authorized_aws_region: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl PutAggregationAuthorizationRequestBuilder {
+    /// Sets the [`authorized_account_id`](struct.PutAggregationAuthorizationRequest.html#structfield.authorized_account_id) member.
+    pub fn authorized_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorized_account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorized_aws_region`](struct.PutAggregationAuthorizationRequest.html#structfield.authorized_aws_region) member.
+    pub fn authorized_aws_region<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorized_aws_region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.PutAggregationAuthorizationRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutAggregationAuthorizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutAggregationAuthorizationRequest, ::rusoto_core::BuildError> {
+        Ok(PutAggregationAuthorizationRequest {
+            authorized_account_id: self
+                .authorized_account_id
+                .ok_or(::rusoto_core::BuildError::missing("authorized_account_id"))?,
+            authorized_aws_region: self
+                .authorized_aws_region
+                .ok_or(::rusoto_core::BuildError::missing("authorized_aws_region"))?,
+            tags: self.tags,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct PutAggregationAuthorizationResponse {
+    /// <p>Returns an AggregationAuthorization object. </p>
+    #[serde(rename = "AggregationAuthorization")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregation_authorization: Option<AggregationAuthorization>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PutConfigRuleRequest {
+    /// <p>The rule that you want to add to your account.</p>
+    #[serde(rename = "ConfigRule")]
+    pub config_rule: ConfigRule,
+    /// <p>An array of tag object.</p>
+    #[serde(rename = "Tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+impl PutConfigRuleRequest {
+    /// Returns a builder for `PutConfigRuleRequest`, checking its required members when built.
+    pub fn builder() -> PutConfigRuleRequestBuilder {
+        PutConfigRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutConfigRuleRequest`](struct.PutConfigRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct PutConfigRuleRequestBuilder {
+    config_rule: Option<ConfigRule>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl PutConfigRuleRequestBuilder {
+    /// Sets the [`config_rule`](struct.PutConfigRuleRequest.html#structfield.config_rule) member.
+    pub fn config_rule<T: Into<ConfigRule>>(mut self, value: T) -> Self {
+        self.config_rule = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.PutConfigRuleRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutConfigRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutConfigRuleRequest, ::rusoto_core::BuildError> {
+        Ok(PutConfigRuleRequest {
+            config_rule: self
+                .config_rule
+                .ok_or(::rusoto_core::BuildError::missing("config_rule"))?,
+            tags: self.tags,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct PutConfigurationAggregatorRequest {
+    /// <p>A list of AccountAggregationSource object. </p>
+    #[serde(rename = "AccountAggregationSources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_aggregation_sources: Option<Vec<AccountAggregationSource>>,
+    /// <p>The name of the configuration aggregator.</p>
+    #[serde(rename = "ConfigurationAggregatorName")]
+    pub configuration_aggregator_name: String,
+    /// <p>An OrganizationAggregationSource object.</p>
+    #[serde(rename = "OrganizationAggregationSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub organization_aggregation_source: Option<OrganizationAggregationSource>,
     /// <p>An array of tag object.</p>
@@ -2269,6 +5748,68 @@ pub struct PutConfigurationAggregatorRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl PutConfigurationAggregatorRequest {
+    /// Returns a builder for `PutConfigurationAggregatorRequest`, checking its required members when built.
+    pub fn builder() -> PutConfigurationAggregatorRequestBuilder {
+        PutConfigurationAggregatorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutConfigurationAggregatorRequest`](struct.PutConfigurationAggregatorRequest.html).
+#[derive(Default, Debug)]
+pub struct PutConfigurationAggregatorRequestBuilder {
+    account_aggregation_sources: Option<Vec<AccountAggregationSource>>,
+    configuration_aggregator_name: Option<String>,
+    organization_aggregation_source: Option<OrganizationAggregationSource>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl PutConfigurationAggregatorRequestBuilder {
+    /// Sets the [`account_aggregation_sources`](struct.PutConfigurationAggregatorRequest.html#structfield.account_aggregation_sources) member.
+    pub fn account_aggregation_sources<T: Into<Vec<AccountAggregationSource>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.account_aggregation_sources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration_aggregator_name`](struct.PutConfigurationAggregatorRequest.html#structfield.configuration_aggregator_name) member.
+    pub fn configuration_aggregator_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_aggregator_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_aggregation_source`](struct.PutConfigurationAggregatorRequest.html#structfield.organization_aggregation_source) member.
+    pub fn organization_aggregation_source<T: Into<OrganizationAggregationSource>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.organization_aggregation_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.PutConfigurationAggregatorRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutConfigurationAggregatorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutConfigurationAggregatorRequest, ::rusoto_core::BuildError> {
+        Ok(PutConfigurationAggregatorRequest {
+            account_aggregation_sources: self.account_aggregation_sources,
+            configuration_aggregator_name: self.configuration_aggregator_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_aggregator_name"),
+            )?,
+            organization_aggregation_source: self.organization_aggregation_source,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutConfigurationAggregatorResponse {
@@ -2286,6 +5827,38 @@ pub struct PutConfigurationRecorderRequest {
     pub configuration_recorder: ConfigurationRecorder,
 }
 
+impl PutConfigurationRecorderRequest {
+    /// Returns a builder for `PutConfigurationRecorderRequest`, checking its required members when built.
+    pub fn builder() -> PutConfigurationRecorderRequestBuilder {
+        PutConfigurationRecorderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutConfigurationRecorderRequest`](struct.PutConfigurationRecorderRequest.html).
+#[derive(Default, Debug)]
+pub struct PutConfigurationRecorderRequestBuilder {
+    configuration_recorder: Option<ConfigurationRecorder>,
+}
+
+impl PutConfigurationRecorderRequestBuilder {
+    /// Sets the [`configuration_recorder`](struct.PutConfigurationRecorderRequest.html#structfield.configuration_recorder) member.
+    pub fn configuration_recorder<T: Into<ConfigurationRecorder>>(mut self, value: T) -> Self {
+        self.configuration_recorder = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutConfigurationRecorderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutConfigurationRecorderRequest, ::rusoto_core::BuildError> {
+        Ok(PutConfigurationRecorderRequest {
+            configuration_recorder: self
+                .configuration_recorder
+                .ok_or(::rusoto_core::BuildError::missing("configuration_recorder"))?,
+        })
+    }
+}
+
 /// <p>The input for the <a>PutDeliveryChannel</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutDeliveryChannelRequest {
@@ -2294,6 +5867,38 @@ pub struct PutDeliveryChannelRequest {
     pub delivery_channel: DeliveryChannel,
 }
 
+impl PutDeliveryChannelRequest {
+    /// Returns a builder for `PutDeliveryChannelRequest`, checking its required members when built.
+    pub fn builder() -> PutDeliveryChannelRequestBuilder {
+        PutDeliveryChannelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutDeliveryChannelRequest`](struct.PutDeliveryChannelRequest.html).
+#[derive(Default, Debug)]
+pub struct PutDeliveryChannelRequestBuilder {
+    delivery_channel: Option<DeliveryChannel>,
+}
+
+impl PutDeliveryChannelRequestBuilder {
+    /// Sets the [`delivery_channel`](struct.PutDeliveryChannelRequest.html#structfield.delivery_channel) member.
+    pub fn delivery_channel<T: Into<DeliveryChannel>>(mut self, value: T) -> Self {
+        self.delivery_channel = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutDeliveryChannelRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutDeliveryChannelRequest, ::rusoto_core::BuildError> {
+        Ok(PutDeliveryChannelRequest {
+            delivery_channel: self
+                .delivery_channel
+                .ok_or(::rusoto_core::BuildError::missing("delivery_channel"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutEvaluationsRequest {
@@ -2310,6 +5915,52 @@ pub struct PutEvaluationsRequest {
     pub test_mode: Option<bool>,
 }
 
+impl PutEvaluationsRequest {
+    /// Returns a builder for `PutEvaluationsRequest`, checking its required members when built.
+    pub fn builder() -> PutEvaluationsRequestBuilder {
+        PutEvaluationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutEvaluationsRequest`](struct.PutEvaluationsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutEvaluationsRequestBuilder {
+    evaluations: Option<Vec<Evaluation>>,
+    result_token: Option<String>,
+    test_mode: Option<bool>,
+}
+
+impl PutEvaluationsRequestBuilder {
+    /// Sets the [`evaluations`](struct.PutEvaluationsRequest.html#structfield.evaluations) member.
+    pub fn evaluations<T: Into<Vec<Evaluation>>>(mut self, value: T) -> Self {
+        self.evaluations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`result_token`](struct.PutEvaluationsRequest.html#structfield.result_token) member.
+    pub fn result_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.result_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`test_mode`](struct.PutEvaluationsRequest.html#structfield.test_mode) member.
+    pub fn test_mode<T: Into<bool>>(mut self, value: T) -> Self {
+        self.test_mode = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutEvaluationsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutEvaluationsRequest, ::rusoto_core::BuildError> {
+        Ok(PutEvaluationsRequest {
+            evaluations: self.evaluations,
+            result_token: self
+                .result_token
+                .ok_or(::rusoto_core::BuildError::missing("result_token"))?,
+            test_mode: self.test_mode,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2339,6 +5990,68 @@ pub struct PutOrganizationConfigRuleRequest {
     pub organization_managed_rule_metadata: Option<OrganizationManagedRuleMetadata>,
 }
 
+impl PutOrganizationConfigRuleRequest {
+    /// Returns a builder for `PutOrganizationConfigRuleRequest`, checking its required members when built.
+    pub fn builder() -> PutOrganizationConfigRuleRequestBuilder {
+        PutOrganizationConfigRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutOrganizationConfigRuleRequest`](struct.PutOrganizationConfigRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct PutOrganizationConfigRuleRequestBuilder {
+    excluded_accounts: Option<Vec<String>>,
+    organization_config_rule_name: Option<String>,
+    organization_custom_rule_metadata: Option<OrganizationCustomRuleMetadata>,
+    organization_managed_rule_metadata: Option<OrganizationManagedRuleMetadata>,
+}
+
+impl PutOrganizationConfigRuleRequestBuilder {
+    /// Sets the [`excluded_accounts`](struct.PutOrganizationConfigRuleRequest.html#structfield.excluded_accounts) member.
+    pub fn excluded_accounts<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.excluded_accounts = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_config_rule_name`](struct.PutOrganizationConfigRuleRequest.html#structfield.organization_config_rule_name) member.
+    pub fn organization_config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_custom_rule_metadata`](struct.PutOrganizationConfigRuleRequest.html#structfield.organization_custom_rule_metadata) member.
+    pub fn organization_custom_rule_metadata<T: Into<OrganizationCustomRuleMetadata>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.organization_custom_rule_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_managed_rule_metadata`](struct.PutOrganizationConfigRuleRequest.html#structfield.organization_managed_rule_metadata) member.
+    pub fn organization_managed_rule_metadata<T: Into<OrganizationManagedRuleMetadata>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.organization_managed_rule_metadata = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutOrganizationConfigRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutOrganizationConfigRuleRequest, ::rusoto_core::BuildError> {
+        Ok(PutOrganizationConfigRuleRequest {
+            excluded_accounts: self.excluded_accounts,
+            organization_config_rule_name: self.organization_config_rule_name.ok_or(
+                ::rusoto_core::BuildError::missing("organization_config_rule_name"),
+            )?,
+            organization_custom_rule_metadata: self.organization_custom_rule_metadata,
+            organization_managed_rule_metadata: self.organization_managed_rule_metadata,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutOrganizationConfigRuleResponse {
@@ -2355,6 +6068,41 @@ pub struct PutRemediationConfigurationsRequest {
     pub remediation_configurations: Vec<RemediationConfiguration>,
 }
 
+impl PutRemediationConfigurationsRequest {
+    /// Returns a builder for `PutRemediationConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> PutRemediationConfigurationsRequestBuilder {
+        PutRemediationConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutRemediationConfigurationsRequest`](struct.PutRemediationConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutRemediationConfigurationsRequestBuilder {
+    remediation_configurations: Option<Vec<RemediationConfiguration>>,
+}
+
+impl PutRemediationConfigurationsRequestBuilder {
+    /// Sets the [`remediation_configurations`](struct.PutRemediationConfigurationsRequest.html#structfield.remediation_configurations) member.
+    pub fn remediation_configurations<T: Into<Vec<RemediationConfiguration>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.remediation_configurations = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRemediationConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutRemediationConfigurationsRequest, ::rusoto_core::BuildError> {
+        Ok(PutRemediationConfigurationsRequest {
+            remediation_configurations: self.remediation_configurations.ok_or(
+                ::rusoto_core::BuildError::missing("remediation_configurations"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutRemediationConfigurationsResponse {
@@ -2371,8 +6119,9 @@ pub struct PutRemediationExceptionsRequest {
     pub config_rule_name: String,
     /// <p>The exception is automatically deleted after the expiration date.</p>
     #[serde(rename = "ExpirationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<f64>,
+    pub expiration_time: Option<EpochTimestamp>,
     /// <p>The message contains an explanation of the exception.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2382,6 +6131,67 @@ pub struct PutRemediationExceptionsRequest {
     pub resource_keys: Vec<RemediationExceptionResourceKey>,
 }
 
+impl PutRemediationExceptionsRequest {
+    /// Returns a builder for `PutRemediationExceptionsRequest`, checking its required members when built.
+    pub fn builder() -> PutRemediationExceptionsRequestBuilder {
+        PutRemediationExceptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutRemediationExceptionsRequest`](struct.PutRemediationExceptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutRemediationExceptionsRequestBuilder {
+    config_rule_name: Option<String>,
+    expiration_time: Option<EpochTimestamp>,
+    message: Option<String>,
+    resource_keys: Option<Vec<RemediationExceptionResourceKey>>,
+}
+
+impl PutRemediationExceptionsRequestBuilder {
+    /// Sets the [`config_rule_name`](struct.PutRemediationExceptionsRequest.html#structfield.config_rule_name) member.
+    pub fn config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expiration_time`](struct.PutRemediationExceptionsRequest.html#structfield.expiration_time) member.
+    pub fn expiration_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.expiration_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message`](struct.PutRemediationExceptionsRequest.html#structfield.message) member.
+    pub fn message<T: Into<String>>(mut self, value: T) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_keys`](struct.PutRemediationExceptionsRequest.html#structfield.resource_keys) member.
+    pub fn resource_keys<T: Into<Vec<RemediationExceptionResourceKey>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.resource_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRemediationExceptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutRemediationExceptionsRequest, ::rusoto_core::BuildError> {
+        Ok(PutRemediationExceptionsRequest {
+            config_rule_name: self
+                .config_rule_name
+                .ok_or(::rusoto_core::BuildError::missing("config_rule_name"))?,
+            expiration_time: self.expiration_time,
+            message: self.message,
+            resource_keys: self
+                .resource_keys
+                .ok_or(::rusoto_core::BuildError::missing("resource_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutRemediationExceptionsResponse {
@@ -2398,6 +6208,38 @@ pub struct PutRetentionConfigurationRequest {
     pub retention_period_in_days: i64,
 }
 
+impl PutRetentionConfigurationRequest {
+    /// Returns a builder for `PutRetentionConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> PutRetentionConfigurationRequestBuilder {
+        PutRetentionConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutRetentionConfigurationRequest`](struct.PutRetentionConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutRetentionConfigurationRequestBuilder {
+    retention_period_in_days: Option<i64>,
+}
+
+impl PutRetentionConfigurationRequestBuilder {
+    /// Sets the [`retention_period_in_days`](struct.PutRetentionConfigurationRequest.html#structfield.retention_period_in_days) member.
+    pub fn retention_period_in_days<T: Into<i64>>(mut self, value: T) -> Self {
+        self.retention_period_in_days = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRetentionConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutRetentionConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(PutRetentionConfigurationRequest {
+            retention_period_in_days: self.retention_period_in_days.ok_or(
+                ::rusoto_core::BuildError::missing("retention_period_in_days"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutRetentionConfigurationResponse {
@@ -2417,21 +6259,97 @@ pub struct QueryInfo {
     pub select_fields: Option<Vec<FieldInfo>>,
 }
 
-/// <p>Specifies the types of AWS resource for which AWS Config records configuration changes.</p> <p>In the recording group, you specify whether all supported types or specific types of resources are recorded.</p> <p>By default, AWS Config records configuration changes for all supported types of regional resources that AWS Config discovers in the region in which it is running. Regional resources are tied to a region and can be used only in that region. Examples of regional resources are EC2 instances and EBS volumes.</p> <p>You can also have AWS Config record configuration changes for supported types of global resources (for example, IAM resources). Global resources are not tied to an individual region and can be used in all regions.</p> <important> <p>The configuration details for any global resource are the same in all regions. If you customize AWS Config in multiple regions to record global resources, it will create multiple configuration items each time a global resource changes: one configuration item for each region. These configuration items will contain identical data. To prevent duplicate configuration items, you should consider customizing AWS Config in only one region to record global resources, unless you want the configuration items to be available in multiple regions.</p> </important> <p>If you don't want AWS Config to record all resources, you can specify which types of resources it will record with the <code>resourceTypes</code> parameter.</p> <p>For a list of supported resource types, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/resource-config-reference.html#supported-resources">Supported Resource Types</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/select-resources.html">Selecting Which Resources AWS Config Records</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct RecordingGroup {
-    /// <p>Specifies whether AWS Config records configuration changes for every supported type of regional resource.</p> <p>If you set this option to <code>true</code>, when AWS Config adds support for a new type of regional resource, it starts recording resources of that type automatically.</p> <p>If you set this option to <code>true</code>, you cannot enumerate a list of <code>resourceTypes</code>.</p>
-    #[serde(rename = "allSupported")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub all_supported: Option<bool>,
-    /// <p>Specifies whether AWS Config includes all supported types of global resources (for example, IAM resources) with the resources that it records.</p> <p>Before you can set this option to <code>true</code>, you must set the <code>allSupported</code> option to <code>true</code>.</p> <p>If you set this option to <code>true</code>, when AWS Config adds support for a new type of global resource, it starts recording resources of that type automatically.</p> <p>The configuration details for any global resource are the same in all regions. To prevent duplicate configuration items, you should consider customizing AWS Config in only one region to record global resources.</p>
-    #[serde(rename = "includeGlobalResourceTypes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub include_global_resource_types: Option<bool>,
-    /// <p>A comma-separated list that specifies the types of AWS resources for which AWS Config records configuration changes (for example, <code>AWS::EC2::Instance</code> or <code>AWS::CloudTrail::Trail</code>).</p> <p>Before you can set this option to <code>true</code>, you must set the <code>allSupported</code> option to <code>false</code>.</p> <p>If you set this option to <code>true</code>, when AWS Config adds support for a new type of resource, it will not record resources of that type unless you manually add that type to your recording group.</p> <p>For a list of valid <code>resourceTypes</code> values, see the <b>resourceType Value</b> column in <a href="https://docs.aws.amazon.com/config/latest/developerguide/resource-config-reference.html#supported-resources">Supported AWS Resource Types</a>.</p>
-    #[serde(rename = "resourceTypes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_types: Option<Vec<String>>,
+/// <p>The last (previous) status of the recorder.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecorderStatus {
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>Success</code></p>
+    Success,
+    /// <p><code>Failure</code></p>
+    Failure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RecorderStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecorderStatus::Pending => "Pending",
+            RecorderStatus::Success => "Success",
+            RecorderStatus::Failure => "Failure",
+            RecorderStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RecorderStatus {
+    fn from(value: &'a str) -> RecorderStatus {
+        match value {
+            "Pending" => RecorderStatus::Pending,
+            "Success" => RecorderStatus::Success,
+            "Failure" => RecorderStatus::Failure,
+            _ => RecorderStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecorderStatus {
+    fn from(value: String) -> RecorderStatus {
+        RecorderStatus::from(value.as_str())
+    }
+}
+
+impl From<RecorderStatus> for String {
+    fn from(value: RecorderStatus) -> String {
+        match value {
+            RecorderStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RecorderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RecorderStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecorderStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RecorderStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RecorderStatus::from(value))
+    }
+}
+
+/// <p>Specifies the types of AWS resource for which AWS Config records configuration changes.</p> <p>In the recording group, you specify whether all supported types or specific types of resources are recorded.</p> <p>By default, AWS Config records configuration changes for all supported types of regional resources that AWS Config discovers in the region in which it is running. Regional resources are tied to a region and can be used only in that region. Examples of regional resources are EC2 instances and EBS volumes.</p> <p>You can also have AWS Config record configuration changes for supported types of global resources (for example, IAM resources). Global resources are not tied to an individual region and can be used in all regions.</p> <important> <p>The configuration details for any global resource are the same in all regions. If you customize AWS Config in multiple regions to record global resources, it will create multiple configuration items each time a global resource changes: one configuration item for each region. These configuration items will contain identical data. To prevent duplicate configuration items, you should consider customizing AWS Config in only one region to record global resources, unless you want the configuration items to be available in multiple regions.</p> </important> <p>If you don't want AWS Config to record all resources, you can specify which types of resources it will record with the <code>resourceTypes</code> parameter.</p> <p>For a list of supported resource types, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/resource-config-reference.html#supported-resources">Supported Resource Types</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/select-resources.html">Selecting Which Resources AWS Config Records</a>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordingGroup {
+    /// <p>Specifies whether AWS Config records configuration changes for every supported type of regional resource.</p> <p>If you set this option to <code>true</code>, when AWS Config adds support for a new type of regional resource, it starts recording resources of that type automatically.</p> <p>If you set this option to <code>true</code>, you cannot enumerate a list of <code>resourceTypes</code>.</p>
+    #[serde(rename = "allSupported")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_supported: Option<bool>,
+    /// <p>Specifies whether AWS Config includes all supported types of global resources (for example, IAM resources) with the resources that it records.</p> <p>Before you can set this option to <code>true</code>, you must set the <code>allSupported</code> option to <code>true</code>.</p> <p>If you set this option to <code>true</code>, when AWS Config adds support for a new type of global resource, it starts recording resources of that type automatically.</p> <p>The configuration details for any global resource are the same in all regions. To prevent duplicate configuration items, you should consider customizing AWS Config in only one region to record global resources.</p>
+    #[serde(rename = "includeGlobalResourceTypes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_global_resource_types: Option<bool>,
+    /// <p>A comma-separated list that specifies the types of AWS resources for which AWS Config records configuration changes (for example, <code>AWS::EC2::Instance</code> or <code>AWS::CloudTrail::Trail</code>).</p> <p>Before you can set this option to <code>true</code>, you must set the <code>allSupported</code> option to <code>false</code>.</p> <p>If you set this option to <code>true</code>, when AWS Config adds support for a new type of resource, it will not record resources of that type unless you manually add that type to your recording group.</p> <p>For a list of valid <code>resourceTypes</code> values, see the <b>resourceType Value</b> column in <a href="https://docs.aws.amazon.com/config/latest/developerguide/resource-config-reference.html#supported-resources">Supported AWS Resource Types</a>.</p>
+    #[serde(rename = "resourceTypes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_types: Option<Vec<ResourceType>>,
 }
 
 /// <p>The relationship of the related resource to the main resource.</p>
@@ -2453,11 +6371,11 @@ pub struct Relationship {
     /// <p>The resource type of the related resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 /// <p>An object that represents the details about the remediation configuration that includes the remediation action, parameters, and data to execute the action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RemediationConfiguration {
     /// <p>Amazon Resource Name (ARN) of remediation configuration.</p>
     #[serde(rename = "Arn")]
@@ -2499,7 +6417,7 @@ pub struct RemediationConfiguration {
     pub target_id: String,
     /// <p>The type of the target. Target executes remediation. For example, SSM document.</p>
     #[serde(rename = "TargetType")]
-    pub target_type: String,
+    pub target_type: RemediationTargetType,
     /// <p>Version of the target. For example, version of the SSM document.</p>
     #[serde(rename = "TargetVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2515,8 +6433,9 @@ pub struct RemediationException {
     pub config_rule_name: String,
     /// <p>The time when the remediation exception will be deleted.</p>
     #[serde(rename = "ExpirationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<f64>,
+    pub expiration_time: Option<EpochTimestamp>,
     /// <p>An explanation of an remediation exception.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2542,25 +6461,107 @@ pub struct RemediationExceptionResourceKey {
     pub resource_type: Option<String>,
 }
 
+/// <p>ENUM of the values.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RemediationExecutionState {
+    /// <p><code>QUEUED</code></p>
+    Queued,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RemediationExecutionState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RemediationExecutionState::Queued => "QUEUED",
+            RemediationExecutionState::InProgress => "IN_PROGRESS",
+            RemediationExecutionState::Succeeded => "SUCCEEDED",
+            RemediationExecutionState::Failed => "FAILED",
+            RemediationExecutionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RemediationExecutionState {
+    fn from(value: &'a str) -> RemediationExecutionState {
+        match value {
+            "QUEUED" => RemediationExecutionState::Queued,
+            "IN_PROGRESS" => RemediationExecutionState::InProgress,
+            "SUCCEEDED" => RemediationExecutionState::Succeeded,
+            "FAILED" => RemediationExecutionState::Failed,
+            _ => RemediationExecutionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RemediationExecutionState {
+    fn from(value: String) -> RemediationExecutionState {
+        RemediationExecutionState::from(value.as_str())
+    }
+}
+
+impl From<RemediationExecutionState> for String {
+    fn from(value: RemediationExecutionState) -> String {
+        match value {
+            RemediationExecutionState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RemediationExecutionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RemediationExecutionState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RemediationExecutionState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RemediationExecutionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RemediationExecutionState::from(value))
+    }
+}
+
 /// <p>Provides details of the current status of the invoked remediation action for that resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RemediationExecutionStatus {
     /// <p>Start time when the remediation was executed.</p>
     #[serde(rename = "InvocationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invocation_time: Option<f64>,
+    pub invocation_time: Option<EpochTimestamp>,
     /// <p>The time when the remediation execution was last updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<EpochTimestamp>,
     #[serde(rename = "ResourceKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_key: Option<ResourceKey>,
     /// <p>ENUM of the values.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RemediationExecutionState>,
     /// <p>Details of every step.</p>
     #[serde(rename = "StepDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2581,16 +6582,96 @@ pub struct RemediationExecutionStep {
     pub name: Option<String>,
     /// <p>The time when the step started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>The valid status of the step.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RemediationExecutionStepState>,
     /// <p>The time when the step stopped.</p>
     #[serde(rename = "StopTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_time: Option<f64>,
+    pub stop_time: Option<EpochTimestamp>,
+}
+
+/// <p>The valid status of the step.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RemediationExecutionStepState {
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RemediationExecutionStepState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RemediationExecutionStepState::Succeeded => "SUCCEEDED",
+            RemediationExecutionStepState::Pending => "PENDING",
+            RemediationExecutionStepState::Failed => "FAILED",
+            RemediationExecutionStepState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RemediationExecutionStepState {
+    fn from(value: &'a str) -> RemediationExecutionStepState {
+        match value {
+            "SUCCEEDED" => RemediationExecutionStepState::Succeeded,
+            "PENDING" => RemediationExecutionStepState::Pending,
+            "FAILED" => RemediationExecutionStepState::Failed,
+            _ => RemediationExecutionStepState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RemediationExecutionStepState {
+    fn from(value: String) -> RemediationExecutionStepState {
+        RemediationExecutionStepState::from(value.as_str())
+    }
+}
+
+impl From<RemediationExecutionStepState> for String {
+    fn from(value: RemediationExecutionStepState) -> String {
+        match value {
+            RemediationExecutionStepState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RemediationExecutionStepState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RemediationExecutionStepState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RemediationExecutionStepState {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<RemediationExecutionStepState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RemediationExecutionStepState::from(value))
+    }
 }
 
 /// <p>The value is either a dynamic (resource) value or a static value. You must select either a dynamic value or a static value.</p>
@@ -2606,6 +6687,74 @@ pub struct RemediationParameterValue {
     pub static_value: Option<StaticValue>,
 }
 
+/// <p>The type of the target. Target executes remediation. For example, SSM document.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RemediationTargetType {
+    /// <p><code>SSM_DOCUMENT</code></p>
+    SsmDocument,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RemediationTargetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RemediationTargetType::SsmDocument => "SSM_DOCUMENT",
+            RemediationTargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RemediationTargetType {
+    fn from(value: &'a str) -> RemediationTargetType {
+        match value {
+            "SSM_DOCUMENT" => RemediationTargetType::SsmDocument,
+            _ => RemediationTargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RemediationTargetType {
+    fn from(value: String) -> RemediationTargetType {
+        RemediationTargetType::from(value.as_str())
+    }
+}
+
+impl From<RemediationTargetType> for String {
+    fn from(value: RemediationTargetType) -> String {
+        match value {
+            RemediationTargetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RemediationTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RemediationTargetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RemediationTargetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RemediationTargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RemediationTargetType::from(value))
+    }
+}
+
 /// <p>An object that contains the resource type and the number of resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2617,7 +6766,7 @@ pub struct ResourceCount {
     /// <p>The resource type (for example, <code>"AWS::EC2::Instance"</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 /// <p>Filters the resource count based on account ID, region, and resource type.</p>
@@ -2634,7 +6783,83 @@ pub struct ResourceCountFilters {
     /// <p>The type of the AWS resource.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
+}
+
+/// <p>The key to group the resource counts.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceCountGroupKey {
+    /// <p><code>RESOURCE_TYPE</code></p>
+    ResourceType,
+    /// <p><code>ACCOUNT_ID</code></p>
+    AccountId,
+    /// <p><code>AWS_REGION</code></p>
+    AwsRegion,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceCountGroupKey {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceCountGroupKey::ResourceType => "RESOURCE_TYPE",
+            ResourceCountGroupKey::AccountId => "ACCOUNT_ID",
+            ResourceCountGroupKey::AwsRegion => "AWS_REGION",
+            ResourceCountGroupKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceCountGroupKey {
+    fn from(value: &'a str) -> ResourceCountGroupKey {
+        match value {
+            "RESOURCE_TYPE" => ResourceCountGroupKey::ResourceType,
+            "ACCOUNT_ID" => ResourceCountGroupKey::AccountId,
+            "AWS_REGION" => ResourceCountGroupKey::AwsRegion,
+            _ => ResourceCountGroupKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceCountGroupKey {
+    fn from(value: String) -> ResourceCountGroupKey {
+        ResourceCountGroupKey::from(value.as_str())
+    }
+}
+
+impl From<ResourceCountGroupKey> for String {
+    fn from(value: ResourceCountGroupKey) -> String {
+        match value {
+            ResourceCountGroupKey::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceCountGroupKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ResourceCountGroupKey {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceCountGroupKey {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceCountGroupKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceCountGroupKey::from(value))
+    }
 }
 
 /// <p>Filters the results by resource account ID, region, resource ID, and resource name.</p>
@@ -2664,8 +6889,9 @@ pub struct ResourceFilters {
 pub struct ResourceIdentifier {
     /// <p>The time that the resource was deleted.</p>
     #[serde(rename = "resourceDeletionTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_deletion_time: Option<f64>,
+    pub resource_deletion_time: Option<EpochTimestamp>,
     /// <p>The ID of the resource (for example, <code>sg-xxxxxx</code>).</p>
     #[serde(rename = "resourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2677,26 +6903,1998 @@ pub struct ResourceIdentifier {
     /// <p>The type of resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 /// <p>The details that identify a resource within AWS Config, including the resource type and resource ID.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceKey {
     /// <p>The ID of the resource (for example., sg-xxxxxx). </p>
     #[serde(rename = "resourceId")]
     pub resource_id: String,
     /// <p>The resource type.</p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
+}
+
+/// <p>The type of the AWS resource.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// <p><code>AWS::EC2::CustomerGateway</code></p>
+    AwsEc2CustomerGateway,
+    /// <p><code>AWS::EC2::EIP</code></p>
+    AwsEc2Eip,
+    /// <p><code>AWS::EC2::Host</code></p>
+    AwsEc2Host,
+    /// <p><code>AWS::EC2::Instance</code></p>
+    AwsEc2Instance,
+    /// <p><code>AWS::EC2::InternetGateway</code></p>
+    AwsEc2InternetGateway,
+    /// <p><code>AWS::EC2::NetworkAcl</code></p>
+    AwsEc2NetworkAcl,
+    /// <p><code>AWS::EC2::NetworkInterface</code></p>
+    AwsEc2NetworkInterface,
+    /// <p><code>AWS::EC2::RouteTable</code></p>
+    AwsEc2RouteTable,
+    /// <p><code>AWS::EC2::SecurityGroup</code></p>
+    AwsEc2SecurityGroup,
+    /// <p><code>AWS::EC2::Subnet</code></p>
+    AwsEc2Subnet,
+    /// <p><code>AWS::CloudTrail::Trail</code></p>
+    AwsCloudTrailTrail,
+    /// <p><code>AWS::EC2::Volume</code></p>
+    AwsEc2Volume,
+    /// <p><code>AWS::EC2::VPC</code></p>
+    AwsEc2Vpc,
+    /// <p><code>AWS::EC2::VPNConnection</code></p>
+    AwsEc2VPNConnection,
+    /// <p><code>AWS::EC2::VPNGateway</code></p>
+    AwsEc2VPNGateway,
+    /// <p><code>AWS::EC2::RegisteredHAInstance</code></p>
+    AwsEc2RegisteredHAInstance,
+    /// <p><code>AWS::EC2::NatGateway</code></p>
+    AwsEc2NatGateway,
+    /// <p><code>AWS::EC2::EgressOnlyInternetGateway</code></p>
+    AwsEc2EgressOnlyInternetGateway,
+    /// <p><code>AWS::EC2::VPCEndpoint</code></p>
+    AwsEc2VPCEndpoint,
+    /// <p><code>AWS::EC2::VPCEndpointService</code></p>
+    AwsEc2VPCEndpointService,
+    /// <p><code>AWS::EC2::FlowLog</code></p>
+    AwsEc2FlowLog,
+    /// <p><code>AWS::EC2::VPCPeeringConnection</code></p>
+    AwsEc2VPCPeeringConnection,
+    /// <p><code>AWS::Elasticsearch::Domain</code></p>
+    AwsElasticsearchDomain,
+    /// <p><code>AWS::IAM::Group</code></p>
+    AwsIamGroup,
+    /// <p><code>AWS::IAM::Policy</code></p>
+    AwsIamPolicy,
+    /// <p><code>AWS::IAM::Role</code></p>
+    AwsIamRole,
+    /// <p><code>AWS::IAM::User</code></p>
+    AwsIamUser,
+    /// <p><code>AWS::ElasticLoadBalancingV2::LoadBalancer</code></p>
+    AwsElasticLoadBalancingV2LoadBalancer,
+    /// <p><code>AWS::ACM::Certificate</code></p>
+    AwsAcmCertificate,
+    /// <p><code>AWS::RDS::DBInstance</code></p>
+    AwsRdsDBInstance,
+    /// <p><code>AWS::RDS::DBSubnetGroup</code></p>
+    AwsRdsDBSubnetGroup,
+    /// <p><code>AWS::RDS::DBSecurityGroup</code></p>
+    AwsRdsDBSecurityGroup,
+    /// <p><code>AWS::RDS::DBSnapshot</code></p>
+    AwsRdsDBSnapshot,
+    /// <p><code>AWS::RDS::DBCluster</code></p>
+    AwsRdsDBCluster,
+    /// <p><code>AWS::RDS::DBClusterSnapshot</code></p>
+    AwsRdsDBClusterSnapshot,
+    /// <p><code>AWS::RDS::EventSubscription</code></p>
+    AwsRdsEventSubscription,
+    /// <p><code>AWS::S3::Bucket</code></p>
+    AwsS3Bucket,
+    /// <p><code>AWS::S3::AccountPublicAccessBlock</code></p>
+    AwsS3AccountPublicAccessBlock,
+    /// <p><code>AWS::Redshift::Cluster</code></p>
+    AwsRedshiftCluster,
+    /// <p><code>AWS::Redshift::ClusterSnapshot</code></p>
+    AwsRedshiftClusterSnapshot,
+    /// <p><code>AWS::Redshift::ClusterParameterGroup</code></p>
+    AwsRedshiftClusterParameterGroup,
+    /// <p><code>AWS::Redshift::ClusterSecurityGroup</code></p>
+    AwsRedshiftClusterSecurityGroup,
+    /// <p><code>AWS::Redshift::ClusterSubnetGroup</code></p>
+    AwsRedshiftClusterSubnetGroup,
+    /// <p><code>AWS::Redshift::EventSubscription</code></p>
+    AwsRedshiftEventSubscription,
+    /// <p><code>AWS::SSM::ManagedInstanceInventory</code></p>
+    AwsSsmManagedInstanceInventory,
+    /// <p><code>AWS::CloudWatch::Alarm</code></p>
+    AwsCloudWatchAlarm,
+    /// <p><code>AWS::CloudFormation::Stack</code></p>
+    AwsCloudFormationStack,
+    /// <p><code>AWS::ElasticLoadBalancing::LoadBalancer</code></p>
+    AwsElasticLoadBalancingLoadBalancer,
+    /// <p><code>AWS::AutoScaling::AutoScalingGroup</code></p>
+    AwsAutoScalingAutoScalingGroup,
+    /// <p><code>AWS::AutoScaling::LaunchConfiguration</code></p>
+    AwsAutoScalingLaunchConfiguration,
+    /// <p><code>AWS::AutoScaling::ScalingPolicy</code></p>
+    AwsAutoScalingScalingPolicy,
+    /// <p><code>AWS::AutoScaling::ScheduledAction</code></p>
+    AwsAutoScalingScheduledAction,
+    /// <p><code>AWS::DynamoDB::Table</code></p>
+    AwsDynamoDBTable,
+    /// <p><code>AWS::CodeBuild::Project</code></p>
+    AwsCodeBuildProject,
+    /// <p><code>AWS::WAF::RateBasedRule</code></p>
+    AwsWafRateBasedRule,
+    /// <p><code>AWS::WAF::Rule</code></p>
+    AwsWafRule,
+    /// <p><code>AWS::WAF::RuleGroup</code></p>
+    AwsWafRuleGroup,
+    /// <p><code>AWS::WAF::WebACL</code></p>
+    AwsWafWebACL,
+    /// <p><code>AWS::WAFRegional::RateBasedRule</code></p>
+    AwsWAFRegionalRateBasedRule,
+    /// <p><code>AWS::WAFRegional::Rule</code></p>
+    AwsWAFRegionalRule,
+    /// <p><code>AWS::WAFRegional::RuleGroup</code></p>
+    AwsWAFRegionalRuleGroup,
+    /// <p><code>AWS::WAFRegional::WebACL</code></p>
+    AwsWAFRegionalWebACL,
+    /// <p><code>AWS::CloudFront::Distribution</code></p>
+    AwsCloudFrontDistribution,
+    /// <p><code>AWS::CloudFront::StreamingDistribution</code></p>
+    AwsCloudFrontStreamingDistribution,
+    /// <p><code>AWS::Lambda::Function</code></p>
+    AwsLambdaFunction,
+    /// <p><code>AWS::NetworkFirewall::Firewall</code></p>
+    AwsNetworkFirewallFirewall,
+    /// <p><code>AWS::NetworkFirewall::FirewallPolicy</code></p>
+    AwsNetworkFirewallFirewallPolicy,
+    /// <p><code>AWS::NetworkFirewall::RuleGroup</code></p>
+    AwsNetworkFirewallRuleGroup,
+    /// <p><code>AWS::ElasticBeanstalk::Application</code></p>
+    AwsElasticBeanstalkApplication,
+    /// <p><code>AWS::ElasticBeanstalk::ApplicationVersion</code></p>
+    AwsElasticBeanstalkApplicationVersion,
+    /// <p><code>AWS::ElasticBeanstalk::Environment</code></p>
+    AwsElasticBeanstalkEnvironment,
+    /// <p><code>AWS::WAFv2::WebACL</code></p>
+    AwsWAFv2WebACL,
+    /// <p><code>AWS::WAFv2::RuleGroup</code></p>
+    AwsWAFv2RuleGroup,
+    /// <p><code>AWS::WAFv2::IPSet</code></p>
+    AwsWAFv2IPSet,
+    /// <p><code>AWS::WAFv2::RegexPatternSet</code></p>
+    AwsWAFv2RegexPatternSet,
+    /// <p><code>AWS::WAFv2::ManagedRuleSet</code></p>
+    AwsWAFv2ManagedRuleSet,
+    /// <p><code>AWS::XRay::EncryptionConfig</code></p>
+    AwsXRayEncryptionConfig,
+    /// <p><code>AWS::SSM::AssociationCompliance</code></p>
+    AwsSsmAssociationCompliance,
+    /// <p><code>AWS::SSM::PatchCompliance</code></p>
+    AwsSsmPatchCompliance,
+    /// <p><code>AWS::Shield::Protection</code></p>
+    AwsShieldProtection,
+    /// <p><code>AWS::ShieldRegional::Protection</code></p>
+    AwsShieldRegionalProtection,
+    /// <p><code>AWS::Config::ConformancePackCompliance</code></p>
+    AwsConfigConformancePackCompliance,
+    /// <p><code>AWS::Config::ResourceCompliance</code></p>
+    AwsConfigResourceCompliance,
+    /// <p><code>AWS::ApiGateway::Stage</code></p>
+    AwsApiGatewayStage,
+    /// <p><code>AWS::ApiGateway::RestApi</code></p>
+    AwsApiGatewayRestApi,
+    /// <p><code>AWS::ApiGatewayV2::Stage</code></p>
+    AwsApiGatewayV2Stage,
+    /// <p><code>AWS::ApiGatewayV2::Api</code></p>
+    AwsApiGatewayV2Api,
+    /// <p><code>AWS::CodePipeline::Pipeline</code></p>
+    AwsCodePipelinePipeline,
+    /// <p><code>AWS::ServiceCatalog::CloudFormationProvisionedProduct</code></p>
+    AwsServiceCatalogCloudFormationProvisionedProduct,
+    /// <p><code>AWS::ServiceCatalog::CloudFormationProduct</code></p>
+    AwsServiceCatalogCloudFormationProduct,
+    /// <p><code>AWS::ServiceCatalog::Portfolio</code></p>
+    AwsServiceCatalogPortfolio,
+    /// <p><code>AWS::SQS::Queue</code></p>
+    AwsSqsQueue,
+    /// <p><code>AWS::KMS::Key</code></p>
+    AwsKmsKey,
+    /// <p><code>AWS::QLDB::Ledger</code></p>
+    AwsQldbLedger,
+    /// <p><code>AWS::SecretsManager::Secret</code></p>
+    AwsSecretsManagerSecret,
+    /// <p><code>AWS::SNS::Topic</code></p>
+    AwsSnsTopic,
+    /// <p><code>AWS::SSM::FileData</code></p>
+    AwsSsmFileData,
+    /// <p><code>AWS::Backup::BackupPlan</code></p>
+    AwsBackupBackupPlan,
+    /// <p><code>AWS::Backup::BackupSelection</code></p>
+    AwsBackupBackupSelection,
+    /// <p><code>AWS::Backup::BackupVault</code></p>
+    AwsBackupBackupVault,
+    /// <p><code>AWS::Backup::RecoveryPoint</code></p>
+    AwsBackupRecoveryPoint,
+    /// <p><code>AWS::ECR::Repository</code></p>
+    AwsEcrRepository,
+    /// <p><code>AWS::ECS::Cluster</code></p>
+    AwsEcsCluster,
+    /// <p><code>AWS::ECS::Service</code></p>
+    AwsEcsService,
+    /// <p><code>AWS::ECS::TaskDefinition</code></p>
+    AwsEcsTaskDefinition,
+    /// <p><code>AWS::EFS::AccessPoint</code></p>
+    AwsEfsAccessPoint,
+    /// <p><code>AWS::EFS::FileSystem</code></p>
+    AwsEfsFileSystem,
+    /// <p><code>AWS::EKS::Cluster</code></p>
+    AwsEksCluster,
+    /// <p><code>AWS::OpenSearch::Domain</code></p>
+    AwsOpenSearchDomain,
+    /// <p><code>AWS::EC2::TransitGateway</code></p>
+    AwsEc2TransitGateway,
+    /// <p><code>AWS::Kinesis::Stream</code></p>
+    AwsKinesisStream,
+    /// <p><code>AWS::Kinesis::StreamConsumer</code></p>
+    AwsKinesisStreamConsumer,
+    /// <p><code>AWS::CodeDeploy::Application</code></p>
+    AwsCodeDeployApplication,
+    /// <p><code>AWS::CodeDeploy::DeploymentConfig</code></p>
+    AwsCodeDeployDeploymentConfig,
+    /// <p><code>AWS::CodeDeploy::DeploymentGroup</code></p>
+    AwsCodeDeployDeploymentGroup,
+    /// <p><code>AWS::EC2::LaunchTemplate</code></p>
+    AwsEc2LaunchTemplate,
+    /// <p><code>AWS::ECR::PublicRepository</code></p>
+    AwsEcrPublicRepository,
+    /// <p><code>AWS::GuardDuty::Detector</code></p>
+    AwsGuardDutyDetector,
+    /// <p><code>AWS::EMR::SecurityConfiguration</code></p>
+    AwsEmrSecurityConfiguration,
+    /// <p><code>AWS::SageMaker::CodeRepository</code></p>
+    AwsSageMakerCodeRepository,
+    /// <p><code>AWS::Route53Resolver::ResolverEndpoint</code></p>
+    AwsRoute53ResolverResolverEndpoint,
+    /// <p><code>AWS::Route53Resolver::ResolverRule</code></p>
+    AwsRoute53ResolverResolverRule,
+    /// <p><code>AWS::Route53Resolver::ResolverRuleAssociation</code></p>
+    AwsRoute53ResolverResolverRuleAssociation,
+    /// <p><code>AWS::DMS::ReplicationSubnetGroup</code></p>
+    AwsDmsReplicationSubnetGroup,
+    /// <p><code>AWS::DMS::EventSubscription</code></p>
+    AwsDmsEventSubscription,
+    /// <p><code>AWS::MSK::Cluster</code></p>
+    AwsMskCluster,
+    /// <p><code>AWS::StepFunctions::Activity</code></p>
+    AwsStepFunctionsActivity,
+    /// <p><code>AWS::WorkSpaces::Workspace</code></p>
+    AwsWorkSpacesWorkspace,
+    /// <p><code>AWS::WorkSpaces::ConnectionAlias</code></p>
+    AwsWorkSpacesConnectionAlias,
+    /// <p><code>AWS::SageMaker::Model</code></p>
+    AwsSageMakerModel,
+    /// <p><code>AWS::ElasticLoadBalancingV2::Listener</code></p>
+    AwsElasticLoadBalancingV2Listener,
+    /// <p><code>AWS::StepFunctions::StateMachine</code></p>
+    AwsStepFunctionsStateMachine,
+    /// <p><code>AWS::Batch::JobQueue</code></p>
+    AwsBatchJobQueue,
+    /// <p><code>AWS::Batch::ComputeEnvironment</code></p>
+    AwsBatchComputeEnvironment,
+    /// <p><code>AWS::AccessAnalyzer::Analyzer</code></p>
+    AwsAccessAnalyzerAnalyzer,
+    /// <p><code>AWS::Athena::WorkGroup</code></p>
+    AwsAthenaWorkGroup,
+    /// <p><code>AWS::Athena::DataCatalog</code></p>
+    AwsAthenaDataCatalog,
+    /// <p><code>AWS::Detective::Graph</code></p>
+    AwsDetectiveGraph,
+    /// <p><code>AWS::GlobalAccelerator::Accelerator</code></p>
+    AwsGlobalAcceleratorAccelerator,
+    /// <p><code>AWS::GlobalAccelerator::EndpointGroup</code></p>
+    AwsGlobalAcceleratorEndpointGroup,
+    /// <p><code>AWS::GlobalAccelerator::Listener</code></p>
+    AwsGlobalAcceleratorListener,
+    /// <p><code>AWS::EC2::TransitGatewayAttachment</code></p>
+    AwsEc2TransitGatewayAttachment,
+    /// <p><code>AWS::EC2::TransitGatewayRouteTable</code></p>
+    AwsEc2TransitGatewayRouteTable,
+    /// <p><code>AWS::DMS::Certificate</code></p>
+    AwsDmsCertificate,
+    /// <p><code>AWS::AppConfig::Application</code></p>
+    AwsAppConfigApplication,
+    /// <p><code>AWS::AppSync::GraphQLApi</code></p>
+    AwsAppSyncGraphQLApi,
+    /// <p><code>AWS::DataSync::LocationSMB</code></p>
+    AwsDataSyncLocationSMB,
+    /// <p><code>AWS::DataSync::LocationFSxLustre</code></p>
+    AwsDataSyncLocationFSxLustre,
+    /// <p><code>AWS::DataSync::LocationS3</code></p>
+    AwsDataSyncLocationS3,
+    /// <p><code>AWS::DataSync::LocationEFS</code></p>
+    AwsDataSyncLocationEFS,
+    /// <p><code>AWS::DataSync::Task</code></p>
+    AwsDataSyncTask,
+    /// <p><code>AWS::DataSync::LocationNFS</code></p>
+    AwsDataSyncLocationNFS,
+    /// <p><code>AWS::EC2::NetworkInsightsAccessScopeAnalysis</code></p>
+    AwsEc2NetworkInsightsAccessScopeAnalysis,
+    /// <p><code>AWS::EKS::FargateProfile</code></p>
+    AwsEksFargateProfile,
+    /// <p><code>AWS::Glue::Job</code></p>
+    AwsGlueJob,
+    /// <p><code>AWS::GuardDuty::ThreatIntelSet</code></p>
+    AwsGuardDutyThreatIntelSet,
+    /// <p><code>AWS::GuardDuty::IPSet</code></p>
+    AwsGuardDutyIPSet,
+    /// <p><code>AWS::SageMaker::Workteam</code></p>
+    AwsSageMakerWorkteam,
+    /// <p><code>AWS::SageMaker::NotebookInstanceLifecycleConfig</code></p>
+    AwsSageMakerNotebookInstanceLifecycleConfig,
+    /// <p><code>AWS::ServiceDiscovery::Service</code></p>
+    AwsServiceDiscoveryService,
+    /// <p><code>AWS::ServiceDiscovery::PublicDnsNamespace</code></p>
+    AwsServiceDiscoveryPublicDnsNamespace,
+    /// <p><code>AWS::SES::ContactList</code></p>
+    AwsSesContactList,
+    /// <p><code>AWS::SES::ConfigurationSet</code></p>
+    AwsSesConfigurationSet,
+    /// <p><code>AWS::Route53::HostedZone</code></p>
+    AwsRoute53HostedZone,
+    /// <p><code>AWS::IoTEvents::Input</code></p>
+    AwsIoTEventsInput,
+    /// <p><code>AWS::IoTEvents::DetectorModel</code></p>
+    AwsIoTEventsDetectorModel,
+    /// <p><code>AWS::IoTEvents::AlarmModel</code></p>
+    AwsIoTEventsAlarmModel,
+    /// <p><code>AWS::ServiceDiscovery::HttpNamespace</code></p>
+    AwsServiceDiscoveryHttpNamespace,
+    /// <p><code>AWS::Events::EventBus</code></p>
+    AwsEventsEventBus,
+    /// <p><code>AWS::ImageBuilder::ContainerRecipe</code></p>
+    AwsImageBuilderContainerRecipe,
+    /// <p><code>AWS::ImageBuilder::DistributionConfiguration</code></p>
+    AwsImageBuilderDistributionConfiguration,
+    /// <p><code>AWS::ImageBuilder::InfrastructureConfiguration</code></p>
+    AwsImageBuilderInfrastructureConfiguration,
+    /// <p><code>AWS::DataSync::LocationObjectStorage</code></p>
+    AwsDataSyncLocationObjectStorage,
+    /// <p><code>AWS::DataSync::LocationHDFS</code></p>
+    AwsDataSyncLocationHDFS,
+    /// <p><code>AWS::Glue::Classifier</code></p>
+    AwsGlueClassifier,
+    /// <p><code>AWS::Route53RecoveryReadiness::Cell</code></p>
+    AwsRoute53RecoveryReadinessCell,
+    /// <p><code>AWS::Route53RecoveryReadiness::ReadinessCheck</code></p>
+    AwsRoute53RecoveryReadinessReadinessCheck,
+    /// <p><code>AWS::ECR::RegistryPolicy</code></p>
+    AwsEcrRegistryPolicy,
+    /// <p><code>AWS::Backup::ReportPlan</code></p>
+    AwsBackupReportPlan,
+    /// <p><code>AWS::Lightsail::Certificate</code></p>
+    AwsLightsailCertificate,
+    /// <p><code>AWS::RUM::AppMonitor</code></p>
+    AwsRumAppMonitor,
+    /// <p><code>AWS::Events::Endpoint</code></p>
+    AwsEventsEndpoint,
+    /// <p><code>AWS::SES::ReceiptRuleSet</code></p>
+    AwsSesReceiptRuleSet,
+    /// <p><code>AWS::Events::Archive</code></p>
+    AwsEventsArchive,
+    /// <p><code>AWS::Events::ApiDestination</code></p>
+    AwsEventsApiDestination,
+    /// <p><code>AWS::Lightsail::Disk</code></p>
+    AwsLightsailDisk,
+    /// <p><code>AWS::FIS::ExperimentTemplate</code></p>
+    AwsFisExperimentTemplate,
+    /// <p><code>AWS::DataSync::LocationFSxWindows</code></p>
+    AwsDataSyncLocationFSxWindows,
+    /// <p><code>AWS::SES::ReceiptFilter</code></p>
+    AwsSesReceiptFilter,
+    /// <p><code>AWS::GuardDuty::Filter</code></p>
+    AwsGuardDutyFilter,
+    /// <p><code>AWS::SES::Template</code></p>
+    AwsSesTemplate,
+    /// <p><code>AWS::AmazonMQ::Broker</code></p>
+    AwsAmazonMQBroker,
+    /// <p><code>AWS::AppConfig::Environment</code></p>
+    AwsAppConfigEnvironment,
+    /// <p><code>AWS::AppConfig::ConfigurationProfile</code></p>
+    AwsAppConfigConfigurationProfile,
+    /// <p><code>AWS::Cloud9::EnvironmentEC2</code></p>
+    AwsCloud9EnvironmentEC2,
+    /// <p><code>AWS::EventSchemas::Registry</code></p>
+    AwsEventSchemasRegistry,
+    /// <p><code>AWS::EventSchemas::RegistryPolicy</code></p>
+    AwsEventSchemasRegistryPolicy,
+    /// <p><code>AWS::EventSchemas::Discoverer</code></p>
+    AwsEventSchemasDiscoverer,
+    /// <p><code>AWS::FraudDetector::Label</code></p>
+    AwsFraudDetectorLabel,
+    /// <p><code>AWS::FraudDetector::EntityType</code></p>
+    AwsFraudDetectorEntityType,
+    /// <p><code>AWS::FraudDetector::Variable</code></p>
+    AwsFraudDetectorVariable,
+    /// <p><code>AWS::FraudDetector::Outcome</code></p>
+    AwsFraudDetectorOutcome,
+    /// <p><code>AWS::IoT::Authorizer</code></p>
+    AwsIoTAuthorizer,
+    /// <p><code>AWS::IoT::SecurityProfile</code></p>
+    AwsIoTSecurityProfile,
+    /// <p><code>AWS::IoT::RoleAlias</code></p>
+    AwsIoTRoleAlias,
+    /// <p><code>AWS::IoT::Dimension</code></p>
+    AwsIoTDimension,
+    /// <p><code>AWS::IoTAnalytics::Datastore</code></p>
+    AwsIoTAnalyticsDatastore,
+    /// <p><code>AWS::Lightsail::Bucket</code></p>
+    AwsLightsailBucket,
+    /// <p><code>AWS::Lightsail::StaticIp</code></p>
+    AwsLightsailStaticIp,
+    /// <p><code>AWS::MediaPackage::PackagingGroup</code></p>
+    AwsMediaPackagePackagingGroup,
+    /// <p><code>AWS::Route53RecoveryReadiness::RecoveryGroup</code></p>
+    AwsRoute53RecoveryReadinessRecoveryGroup,
+    /// <p><code>AWS::ResilienceHub::ResiliencyPolicy</code></p>
+    AwsResilienceHubResiliencyPolicy,
+    /// <p><code>AWS::Transfer::Workflow</code></p>
+    AwsTransferWorkflow,
+    /// <p><code>AWS::EKS::IdentityProviderConfig</code></p>
+    AwsEksIdentityProviderConfig,
+    /// <p><code>AWS::EKS::Addon</code></p>
+    AwsEksAddon,
+    /// <p><code>AWS::Glue::MLTransform</code></p>
+    AwsGlueMLTransform,
+    /// <p><code>AWS::IoT::Policy</code></p>
+    AwsIoTPolicy,
+    /// <p><code>AWS::IoT::MitigationAction</code></p>
+    AwsIoTMitigationAction,
+    /// <p><code>AWS::IoTTwinMaker::Workspace</code></p>
+    AwsIoTTwinMakerWorkspace,
+    /// <p><code>AWS::IoTTwinMaker::Entity</code></p>
+    AwsIoTTwinMakerEntity,
+    /// <p><code>AWS::IoTAnalytics::Dataset</code></p>
+    AwsIoTAnalyticsDataset,
+    /// <p><code>AWS::IoTAnalytics::Pipeline</code></p>
+    AwsIoTAnalyticsPipeline,
+    /// <p><code>AWS::IoTAnalytics::Channel</code></p>
+    AwsIoTAnalyticsChannel,
+    /// <p><code>AWS::IoTSiteWise::Dashboard</code></p>
+    AwsIoTSiteWiseDashboard,
+    /// <p><code>AWS::IoTSiteWise::Project</code></p>
+    AwsIoTSiteWiseProject,
+    /// <p><code>AWS::IoTSiteWise::Portal</code></p>
+    AwsIoTSiteWisePortal,
+    /// <p><code>AWS::IoTSiteWise::AssetModel</code></p>
+    AwsIoTSiteWiseAssetModel,
+    /// <p><code>AWS::IVS::Channel</code></p>
+    AwsIvsChannel,
+    /// <p><code>AWS::IVS::RecordingConfiguration</code></p>
+    AwsIvsRecordingConfiguration,
+    /// <p><code>AWS::IVS::PlaybackKeyPair</code></p>
+    AwsIvsPlaybackKeyPair,
+    /// <p><code>AWS::KinesisAnalyticsV2::Application</code></p>
+    AwsKinesisAnalyticsV2Application,
+    /// <p><code>AWS::RDS::GlobalCluster</code></p>
+    AwsRdsGlobalCluster,
+    /// <p><code>AWS::S3::MultiRegionAccessPoint</code></p>
+    AwsS3MultiRegionAccessPoint,
+    /// <p><code>AWS::DeviceFarm::TestGridProject</code></p>
+    AwsDeviceFarmTestGridProject,
+    /// <p><code>AWS::Budgets::BudgetsAction</code></p>
+    AwsBudgetsBudgetsAction,
+    /// <p><code>AWS::Lex::Bot</code></p>
+    AwsLexBot,
+    /// <p><code>AWS::CodeGuruReviewer::RepositoryAssociation</code></p>
+    AwsCodeGuruReviewerRepositoryAssociation,
+    /// <p><code>AWS::IoT::CustomMetric</code></p>
+    AwsIoTCustomMetric,
+    /// <p><code>AWS::Route53Resolver::FirewallDomainList</code></p>
+    AwsRoute53ResolverFirewallDomainList,
+    /// <p><code>AWS::RoboMaker::RobotApplicationVersion</code></p>
+    AwsRoboMakerRobotApplicationVersion,
+    /// <p><code>AWS::EC2::TrafficMirrorSession</code></p>
+    AwsEc2TrafficMirrorSession,
+    /// <p><code>AWS::IoTSiteWise::Gateway</code></p>
+    AwsIoTSiteWiseGateway,
+    /// <p><code>AWS::Lex::BotAlias</code></p>
+    AwsLexBotAlias,
+    /// <p><code>AWS::LookoutMetrics::Alert</code></p>
+    AwsLookoutMetricsAlert,
+    /// <p><code>AWS::IoT::AccountAuditConfiguration</code></p>
+    AwsIoTAccountAuditConfiguration,
+    /// <p><code>AWS::EC2::TrafficMirrorTarget</code></p>
+    AwsEc2TrafficMirrorTarget,
+    /// <p><code>AWS::S3::StorageLens</code></p>
+    AwsS3StorageLens,
+    /// <p><code>AWS::IoT::ScheduledAudit</code></p>
+    AwsIoTScheduledAudit,
+    /// <p><code>AWS::Events::Connection</code></p>
+    AwsEventsConnection,
+    /// <p><code>AWS::EventSchemas::Schema</code></p>
+    AwsEventSchemasSchema,
+    /// <p><code>AWS::MediaPackage::PackagingConfiguration</code></p>
+    AwsMediaPackagePackagingConfiguration,
+    /// <p><code>AWS::KinesisVideo::SignalingChannel</code></p>
+    AwsKinesisVideoSignalingChannel,
+    /// <p><code>AWS::AppStream::DirectoryConfig</code></p>
+    AwsAppStreamDirectoryConfig,
+    /// <p><code>AWS::LookoutVision::Project</code></p>
+    AwsLookoutVisionProject,
+    /// <p><code>AWS::Route53RecoveryControl::Cluster</code></p>
+    AwsRoute53RecoveryControlCluster,
+    /// <p><code>AWS::Route53RecoveryControl::SafetyRule</code></p>
+    AwsRoute53RecoveryControlSafetyRule,
+    /// <p><code>AWS::Route53RecoveryControl::ControlPanel</code></p>
+    AwsRoute53RecoveryControlControlPanel,
+    /// <p><code>AWS::Route53RecoveryControl::RoutingControl</code></p>
+    AwsRoute53RecoveryControlRoutingControl,
+    /// <p><code>AWS::Route53RecoveryReadiness::ResourceSet</code></p>
+    AwsRoute53RecoveryReadinessResourceSet,
+    /// <p><code>AWS::RoboMaker::SimulationApplication</code></p>
+    AwsRoboMakerSimulationApplication,
+    /// <p><code>AWS::RoboMaker::RobotApplication</code></p>
+    AwsRoboMakerRobotApplication,
+    /// <p><code>AWS::HealthLake::FHIRDatastore</code></p>
+    AwsHealthLakeFHIRDatastore,
+    /// <p><code>AWS::Pinpoint::Segment</code></p>
+    AwsPinpointSegment,
+    /// <p><code>AWS::Pinpoint::ApplicationSettings</code></p>
+    AwsPinpointApplicationSettings,
+    /// <p><code>AWS::Events::Rule</code></p>
+    AwsEventsRule,
+    /// <p><code>AWS::EC2::DHCPOptions</code></p>
+    AwsEc2DHCPOptions,
+    /// <p><code>AWS::EC2::NetworkInsightsPath</code></p>
+    AwsEc2NetworkInsightsPath,
+    /// <p><code>AWS::EC2::TrafficMirrorFilter</code></p>
+    AwsEc2TrafficMirrorFilter,
+    /// <p><code>AWS::EC2::IPAM</code></p>
+    AwsEc2Ipam,
+    /// <p><code>AWS::IoTTwinMaker::Scene</code></p>
+    AwsIoTTwinMakerScene,
+    /// <p><code>AWS::NetworkManager::TransitGatewayRegistration</code></p>
+    AwsNetworkManagerTransitGatewayRegistration,
+    /// <p><code>AWS::CustomerProfiles::Domain</code></p>
+    AwsCustomerProfilesDomain,
+    /// <p><code>AWS::AutoScaling::WarmPool</code></p>
+    AwsAutoScalingWarmPool,
+    /// <p><code>AWS::Connect::PhoneNumber</code></p>
+    AwsConnectPhoneNumber,
+    /// <p><code>AWS::AppConfig::DeploymentStrategy</code></p>
+    AwsAppConfigDeploymentStrategy,
+    /// <p><code>AWS::AppFlow::Flow</code></p>
+    AwsAppFlowFlow,
+    /// <p><code>AWS::AuditManager::Assessment</code></p>
+    AwsAuditManagerAssessment,
+    /// <p><code>AWS::CloudWatch::MetricStream</code></p>
+    AwsCloudWatchMetricStream,
+    /// <p><code>AWS::DeviceFarm::InstanceProfile</code></p>
+    AwsDeviceFarmInstanceProfile,
+    /// <p><code>AWS::DeviceFarm::Project</code></p>
+    AwsDeviceFarmProject,
+    /// <p><code>AWS::EC2::EC2Fleet</code></p>
+    AwsEc2EC2Fleet,
+    /// <p><code>AWS::EC2::SubnetRouteTableAssociation</code></p>
+    AwsEc2SubnetRouteTableAssociation,
+    /// <p><code>AWS::ECR::PullThroughCacheRule</code></p>
+    AwsEcrPullThroughCacheRule,
+    /// <p><code>AWS::GroundStation::Config</code></p>
+    AwsGroundStationConfig,
+    /// <p><code>AWS::ImageBuilder::ImagePipeline</code></p>
+    AwsImageBuilderImagePipeline,
+    /// <p><code>AWS::IoT::FleetMetric</code></p>
+    AwsIoTFleetMetric,
+    /// <p><code>AWS::IoTWireless::ServiceProfile</code></p>
+    AwsIoTWirelessServiceProfile,
+    /// <p><code>AWS::NetworkManager::Device</code></p>
+    AwsNetworkManagerDevice,
+    /// <p><code>AWS::NetworkManager::GlobalNetwork</code></p>
+    AwsNetworkManagerGlobalNetwork,
+    /// <p><code>AWS::NetworkManager::Link</code></p>
+    AwsNetworkManagerLink,
+    /// <p><code>AWS::NetworkManager::Site</code></p>
+    AwsNetworkManagerSite,
+    /// <p><code>AWS::Panorama::Package</code></p>
+    AwsPanoramaPackage,
+    /// <p><code>AWS::Pinpoint::App</code></p>
+    AwsPinpointApp,
+    /// <p><code>AWS::Redshift::ScheduledAction</code></p>
+    AwsRedshiftScheduledAction,
+    /// <p><code>AWS::Route53Resolver::FirewallRuleGroupAssociation</code></p>
+    AwsRoute53ResolverFirewallRuleGroupAssociation,
+    /// <p><code>AWS::SageMaker::AppImageConfig</code></p>
+    AwsSageMakerAppImageConfig,
+    /// <p><code>AWS::SageMaker::Image</code></p>
+    AwsSageMakerImage,
+    /// <p><code>AWS::ECS::TaskSet</code></p>
+    AwsEcsTaskSet,
+    /// <p><code>AWS::Cassandra::Keyspace</code></p>
+    AwsCassandraKeyspace,
+    /// <p><code>AWS::Signer::SigningProfile</code></p>
+    AwsSignerSigningProfile,
+    /// <p><code>AWS::Amplify::App</code></p>
+    AwsAmplifyApp,
+    /// <p><code>AWS::AppMesh::VirtualNode</code></p>
+    AwsAppMeshVirtualNode,
+    /// <p><code>AWS::AppMesh::VirtualService</code></p>
+    AwsAppMeshVirtualService,
+    /// <p><code>AWS::AppRunner::VpcConnector</code></p>
+    AwsAppRunnerVpcConnector,
+    /// <p><code>AWS::AppStream::Application</code></p>
+    AwsAppStreamApplication,
+    /// <p><code>AWS::CodeArtifact::Repository</code></p>
+    AwsCodeArtifactRepository,
+    /// <p><code>AWS::EC2::PrefixList</code></p>
+    AwsEc2PrefixList,
+    /// <p><code>AWS::EC2::SpotFleet</code></p>
+    AwsEc2SpotFleet,
+    /// <p><code>AWS::Evidently::Project</code></p>
+    AwsEvidentlyProject,
+    /// <p><code>AWS::Forecast::Dataset</code></p>
+    AwsForecastDataset,
+    /// <p><code>AWS::IAM::SAMLProvider</code></p>
+    AwsIamSAMLProvider,
+    /// <p><code>AWS::IAM::ServerCertificate</code></p>
+    AwsIamServerCertificate,
+    /// <p><code>AWS::Pinpoint::Campaign</code></p>
+    AwsPinpointCampaign,
+    /// <p><code>AWS::Pinpoint::InAppTemplate</code></p>
+    AwsPinpointInAppTemplate,
+    /// <p><code>AWS::SageMaker::Domain</code></p>
+    AwsSageMakerDomain,
+    /// <p><code>AWS::Transfer::Agreement</code></p>
+    AwsTransferAgreement,
+    /// <p><code>AWS::Transfer::Connector</code></p>
+    AwsTransferConnector,
+    /// <p><code>AWS::KinesisFirehose::DeliveryStream</code></p>
+    AwsKinesisFirehoseDeliveryStream,
+    /// <p><code>AWS::Amplify::Branch</code></p>
+    AwsAmplifyBranch,
+    /// <p><code>AWS::AppIntegrations::EventIntegration</code></p>
+    AwsAppIntegrationsEventIntegration,
+    /// <p><code>AWS::AppMesh::Route</code></p>
+    AwsAppMeshRoute,
+    /// <p><code>AWS::Athena::PreparedStatement</code></p>
+    AwsAthenaPreparedStatement,
+    /// <p><code>AWS::EC2::IPAMScope</code></p>
+    AwsEc2IPAMScope,
+    /// <p><code>AWS::Evidently::Launch</code></p>
+    AwsEvidentlyLaunch,
+    /// <p><code>AWS::Forecast::DatasetGroup</code></p>
+    AwsForecastDatasetGroup,
+    /// <p><code>AWS::GreengrassV2::ComponentVersion</code></p>
+    AwsGreengrassV2ComponentVersion,
+    /// <p><code>AWS::GroundStation::MissionProfile</code></p>
+    AwsGroundStationMissionProfile,
+    /// <p><code>AWS::MediaConnect::FlowEntitlement</code></p>
+    AwsMediaConnectFlowEntitlement,
+    /// <p><code>AWS::MediaConnect::FlowVpcInterface</code></p>
+    AwsMediaConnectFlowVpcInterface,
+    /// <p><code>AWS::MediaTailor::PlaybackConfiguration</code></p>
+    AwsMediaTailorPlaybackConfiguration,
+    /// <p><code>AWS::MSK::Configuration</code></p>
+    AwsMskConfiguration,
+    /// <p><code>AWS::Personalize::Dataset</code></p>
+    AwsPersonalizeDataset,
+    /// <p><code>AWS::Personalize::Schema</code></p>
+    AwsPersonalizeSchema,
+    /// <p><code>AWS::Personalize::Solution</code></p>
+    AwsPersonalizeSolution,
+    /// <p><code>AWS::Pinpoint::EmailTemplate</code></p>
+    AwsPinpointEmailTemplate,
+    /// <p><code>AWS::Pinpoint::EventStream</code></p>
+    AwsPinpointEventStream,
+    /// <p><code>AWS::ResilienceHub::App</code></p>
+    AwsResilienceHubApp,
+    /// <p><code>AWS::ACMPCA::CertificateAuthority</code></p>
+    AwsAcmpcaCertificateAuthority,
+    /// <p><code>AWS::AppConfig::HostedConfigurationVersion</code></p>
+    AwsAppConfigHostedConfigurationVersion,
+    /// <p><code>AWS::AppMesh::VirtualGateway</code></p>
+    AwsAppMeshVirtualGateway,
+    /// <p><code>AWS::AppMesh::VirtualRouter</code></p>
+    AwsAppMeshVirtualRouter,
+    /// <p><code>AWS::AppRunner::Service</code></p>
+    AwsAppRunnerService,
+    /// <p><code>AWS::CustomerProfiles::ObjectType</code></p>
+    AwsCustomerProfilesObjectType,
+    /// <p><code>AWS::DMS::Endpoint</code></p>
+    AwsDmsEndpoint,
+    /// <p><code>AWS::EC2::CapacityReservation</code></p>
+    AwsEc2CapacityReservation,
+    /// <p><code>AWS::EC2::ClientVpnEndpoint</code></p>
+    AwsEc2ClientVpnEndpoint,
+    /// <p><code>AWS::Kendra::Index</code></p>
+    AwsKendraIndex,
+    /// <p><code>AWS::KinesisVideo::Stream</code></p>
+    AwsKinesisVideoStream,
+    /// <p><code>AWS::Logs::Destination</code></p>
+    AwsLogsDestination,
+    /// <p><code>AWS::Pinpoint::EmailChannel</code></p>
+    AwsPinpointEmailChannel,
+    /// <p><code>AWS::S3::AccessPoint</code></p>
+    AwsS3AccessPoint,
+    /// <p><code>AWS::NetworkManager::CustomerGatewayAssociation</code></p>
+    AwsNetworkManagerCustomerGatewayAssociation,
+    /// <p><code>AWS::NetworkManager::LinkAssociation</code></p>
+    AwsNetworkManagerLinkAssociation,
+    /// <p><code>AWS::IoTWireless::MulticastGroup</code></p>
+    AwsIoTWirelessMulticastGroup,
+    /// <p><code>AWS::Personalize::DatasetGroup</code></p>
+    AwsPersonalizeDatasetGroup,
+    /// <p><code>AWS::IoTTwinMaker::ComponentType</code></p>
+    AwsIoTTwinMakerComponentType,
+    /// <p><code>AWS::CodeBuild::ReportGroup</code></p>
+    AwsCodeBuildReportGroup,
+    /// <p><code>AWS::SageMaker::FeatureGroup</code></p>
+    AwsSageMakerFeatureGroup,
+    /// <p><code>AWS::MSK::BatchScramSecret</code></p>
+    AwsMskBatchScramSecret,
+    /// <p><code>AWS::AppStream::Stack</code></p>
+    AwsAppStreamStack,
+    /// <p><code>AWS::IoT::JobTemplate</code></p>
+    AwsIoTJobTemplate,
+    /// <p><code>AWS::IoTWireless::FuotaTask</code></p>
+    AwsIoTWirelessFuotaTask,
+    /// <p><code>AWS::IoT::ProvisioningTemplate</code></p>
+    AwsIoTProvisioningTemplate,
+    /// <p><code>AWS::InspectorV2::Filter</code></p>
+    AwsInspectorV2Filter,
+    /// <p><code>AWS::Route53Resolver::ResolverQueryLoggingConfigAssociation</code></p>
+    AwsRoute53ResolverResolverQueryLoggingConfigAssociation,
+    /// <p><code>AWS::ServiceDiscovery::Instance</code></p>
+    AwsServiceDiscoveryInstance,
+    /// <p><code>AWS::Transfer::Certificate</code></p>
+    AwsTransferCertificate,
+    /// <p><code>AWS::MediaConnect::FlowSource</code></p>
+    AwsMediaConnectFlowSource,
+    /// <p><code>AWS::APS::RuleGroupsNamespace</code></p>
+    AwsApsRuleGroupsNamespace,
+    /// <p><code>AWS::CodeGuruProfiler::ProfilingGroup</code></p>
+    AwsCodeGuruProfilerProfilingGroup,
+    /// <p><code>AWS::Route53Resolver::ResolverQueryLoggingConfig</code></p>
+    AwsRoute53ResolverResolverQueryLoggingConfig,
+    /// <p><code>AWS::Batch::SchedulingPolicy</code></p>
+    AwsBatchSchedulingPolicy,
+    /// <p><code>AWS::ACMPCA::CertificateAuthorityActivation</code></p>
+    AwsAcmpcaCertificateAuthorityActivation,
+    /// <p><code>AWS::AppMesh::GatewayRoute</code></p>
+    AwsAppMeshGatewayRoute,
+    /// <p><code>AWS::AppMesh::Mesh</code></p>
+    AwsAppMeshMesh,
+    /// <p><code>AWS::Connect::Instance</code></p>
+    AwsConnectInstance,
+    /// <p><code>AWS::Connect::QuickConnect</code></p>
+    AwsConnectQuickConnect,
+    /// <p><code>AWS::EC2::CarrierGateway</code></p>
+    AwsEc2CarrierGateway,
+    /// <p><code>AWS::EC2::IPAMPool</code></p>
+    AwsEc2IPAMPool,
+    /// <p><code>AWS::EC2::TransitGatewayConnect</code></p>
+    AwsEc2TransitGatewayConnect,
+    /// <p><code>AWS::EC2::TransitGatewayMulticastDomain</code></p>
+    AwsEc2TransitGatewayMulticastDomain,
+    /// <p><code>AWS::ECS::CapacityProvider</code></p>
+    AwsEcsCapacityProvider,
+    /// <p><code>AWS::IAM::InstanceProfile</code></p>
+    AwsIamInstanceProfile,
+    /// <p><code>AWS::IoT::CACertificate</code></p>
+    AwsIoTCACertificate,
+    /// <p><code>AWS::IoTTwinMaker::SyncJob</code></p>
+    AwsIoTTwinMakerSyncJob,
+    /// <p><code>AWS::KafkaConnect::Connector</code></p>
+    AwsKafkaConnectConnector,
+    /// <p><code>AWS::Lambda::CodeSigningConfig</code></p>
+    AwsLambdaCodeSigningConfig,
+    /// <p><code>AWS::NetworkManager::ConnectPeer</code></p>
+    AwsNetworkManagerConnectPeer,
+    /// <p><code>AWS::ResourceExplorer2::Index</code></p>
+    AwsResourceExplorer2Index,
+    /// <p><code>AWS::AppStream::Fleet</code></p>
+    AwsAppStreamFleet,
+    /// <p><code>AWS::Cognito::UserPool</code></p>
+    AwsCognitoUserPool,
+    /// <p><code>AWS::Cognito::UserPoolClient</code></p>
+    AwsCognitoUserPoolClient,
+    /// <p><code>AWS::Cognito::UserPoolGroup</code></p>
+    AwsCognitoUserPoolGroup,
+    /// <p><code>AWS::EC2::NetworkInsightsAccessScope</code></p>
+    AwsEc2NetworkInsightsAccessScope,
+    /// <p><code>AWS::EC2::NetworkInsightsAnalysis</code></p>
+    AwsEc2NetworkInsightsAnalysis,
+    /// <p><code>AWS::Grafana::Workspace</code></p>
+    AwsGrafanaWorkspace,
+    /// <p><code>AWS::GroundStation::DataflowEndpointGroup</code></p>
+    AwsGroundStationDataflowEndpointGroup,
+    /// <p><code>AWS::ImageBuilder::ImageRecipe</code></p>
+    AwsImageBuilderImageRecipe,
+    /// <p><code>AWS::KMS::Alias</code></p>
+    AwsKmsAlias,
+    /// <p><code>AWS::M2::Environment</code></p>
+    AwsM2Environment,
+    /// <p><code>AWS::QuickSight::DataSource</code></p>
+    AwsQuickSightDataSource,
+    /// <p><code>AWS::QuickSight::Template</code></p>
+    AwsQuickSightTemplate,
+    /// <p><code>AWS::QuickSight::Theme</code></p>
+    AwsQuickSightTheme,
+    /// <p><code>AWS::RDS::OptionGroup</code></p>
+    AwsRdsOptionGroup,
+    /// <p><code>AWS::Redshift::EndpointAccess</code></p>
+    AwsRedshiftEndpointAccess,
+    /// <p><code>AWS::Route53Resolver::FirewallRuleGroup</code></p>
+    AwsRoute53ResolverFirewallRuleGroup,
+    /// <p><code>AWS::SSM::Document</code></p>
+    AwsSsmDocument,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::AwsEc2CustomerGateway => "AWS::EC2::CustomerGateway",
+            ResourceType::AwsEc2Eip => "AWS::EC2::EIP",
+            ResourceType::AwsEc2Host => "AWS::EC2::Host",
+            ResourceType::AwsEc2Instance => "AWS::EC2::Instance",
+            ResourceType::AwsEc2InternetGateway => "AWS::EC2::InternetGateway",
+            ResourceType::AwsEc2NetworkAcl => "AWS::EC2::NetworkAcl",
+            ResourceType::AwsEc2NetworkInterface => "AWS::EC2::NetworkInterface",
+            ResourceType::AwsEc2RouteTable => "AWS::EC2::RouteTable",
+            ResourceType::AwsEc2SecurityGroup => "AWS::EC2::SecurityGroup",
+            ResourceType::AwsEc2Subnet => "AWS::EC2::Subnet",
+            ResourceType::AwsCloudTrailTrail => "AWS::CloudTrail::Trail",
+            ResourceType::AwsEc2Volume => "AWS::EC2::Volume",
+            ResourceType::AwsEc2Vpc => "AWS::EC2::VPC",
+            ResourceType::AwsEc2VPNConnection => "AWS::EC2::VPNConnection",
+            ResourceType::AwsEc2VPNGateway => "AWS::EC2::VPNGateway",
+            ResourceType::AwsEc2RegisteredHAInstance => "AWS::EC2::RegisteredHAInstance",
+            ResourceType::AwsEc2NatGateway => "AWS::EC2::NatGateway",
+            ResourceType::AwsEc2EgressOnlyInternetGateway => "AWS::EC2::EgressOnlyInternetGateway",
+            ResourceType::AwsEc2VPCEndpoint => "AWS::EC2::VPCEndpoint",
+            ResourceType::AwsEc2VPCEndpointService => "AWS::EC2::VPCEndpointService",
+            ResourceType::AwsEc2FlowLog => "AWS::EC2::FlowLog",
+            ResourceType::AwsEc2VPCPeeringConnection => "AWS::EC2::VPCPeeringConnection",
+            ResourceType::AwsElasticsearchDomain => "AWS::Elasticsearch::Domain",
+            ResourceType::AwsIamGroup => "AWS::IAM::Group",
+            ResourceType::AwsIamPolicy => "AWS::IAM::Policy",
+            ResourceType::AwsIamRole => "AWS::IAM::Role",
+            ResourceType::AwsIamUser => "AWS::IAM::User",
+            ResourceType::AwsElasticLoadBalancingV2LoadBalancer => {
+                "AWS::ElasticLoadBalancingV2::LoadBalancer"
+            }
+            ResourceType::AwsAcmCertificate => "AWS::ACM::Certificate",
+            ResourceType::AwsRdsDBInstance => "AWS::RDS::DBInstance",
+            ResourceType::AwsRdsDBSubnetGroup => "AWS::RDS::DBSubnetGroup",
+            ResourceType::AwsRdsDBSecurityGroup => "AWS::RDS::DBSecurityGroup",
+            ResourceType::AwsRdsDBSnapshot => "AWS::RDS::DBSnapshot",
+            ResourceType::AwsRdsDBCluster => "AWS::RDS::DBCluster",
+            ResourceType::AwsRdsDBClusterSnapshot => "AWS::RDS::DBClusterSnapshot",
+            ResourceType::AwsRdsEventSubscription => "AWS::RDS::EventSubscription",
+            ResourceType::AwsS3Bucket => "AWS::S3::Bucket",
+            ResourceType::AwsS3AccountPublicAccessBlock => "AWS::S3::AccountPublicAccessBlock",
+            ResourceType::AwsRedshiftCluster => "AWS::Redshift::Cluster",
+            ResourceType::AwsRedshiftClusterSnapshot => "AWS::Redshift::ClusterSnapshot",
+            ResourceType::AwsRedshiftClusterParameterGroup => {
+                "AWS::Redshift::ClusterParameterGroup"
+            }
+            ResourceType::AwsRedshiftClusterSecurityGroup => "AWS::Redshift::ClusterSecurityGroup",
+            ResourceType::AwsRedshiftClusterSubnetGroup => "AWS::Redshift::ClusterSubnetGroup",
+            ResourceType::AwsRedshiftEventSubscription => "AWS::Redshift::EventSubscription",
+            ResourceType::AwsSsmManagedInstanceInventory => "AWS::SSM::ManagedInstanceInventory",
+            ResourceType::AwsCloudWatchAlarm => "AWS::CloudWatch::Alarm",
+            ResourceType::AwsCloudFormationStack => "AWS::CloudFormation::Stack",
+            ResourceType::AwsElasticLoadBalancingLoadBalancer => {
+                "AWS::ElasticLoadBalancing::LoadBalancer"
+            }
+            ResourceType::AwsAutoScalingAutoScalingGroup => "AWS::AutoScaling::AutoScalingGroup",
+            ResourceType::AwsAutoScalingLaunchConfiguration => {
+                "AWS::AutoScaling::LaunchConfiguration"
+            }
+            ResourceType::AwsAutoScalingScalingPolicy => "AWS::AutoScaling::ScalingPolicy",
+            ResourceType::AwsAutoScalingScheduledAction => "AWS::AutoScaling::ScheduledAction",
+            ResourceType::AwsDynamoDBTable => "AWS::DynamoDB::Table",
+            ResourceType::AwsCodeBuildProject => "AWS::CodeBuild::Project",
+            ResourceType::AwsWafRateBasedRule => "AWS::WAF::RateBasedRule",
+            ResourceType::AwsWafRule => "AWS::WAF::Rule",
+            ResourceType::AwsWafRuleGroup => "AWS::WAF::RuleGroup",
+            ResourceType::AwsWafWebACL => "AWS::WAF::WebACL",
+            ResourceType::AwsWAFRegionalRateBasedRule => "AWS::WAFRegional::RateBasedRule",
+            ResourceType::AwsWAFRegionalRule => "AWS::WAFRegional::Rule",
+            ResourceType::AwsWAFRegionalRuleGroup => "AWS::WAFRegional::RuleGroup",
+            ResourceType::AwsWAFRegionalWebACL => "AWS::WAFRegional::WebACL",
+            ResourceType::AwsCloudFrontDistribution => "AWS::CloudFront::Distribution",
+            ResourceType::AwsCloudFrontStreamingDistribution => {
+                "AWS::CloudFront::StreamingDistribution"
+            }
+            ResourceType::AwsLambdaFunction => "AWS::Lambda::Function",
+            ResourceType::AwsNetworkFirewallFirewall => "AWS::NetworkFirewall::Firewall",
+            ResourceType::AwsNetworkFirewallFirewallPolicy => {
+                "AWS::NetworkFirewall::FirewallPolicy"
+            }
+            ResourceType::AwsNetworkFirewallRuleGroup => "AWS::NetworkFirewall::RuleGroup",
+            ResourceType::AwsElasticBeanstalkApplication => "AWS::ElasticBeanstalk::Application",
+            ResourceType::AwsElasticBeanstalkApplicationVersion => {
+                "AWS::ElasticBeanstalk::ApplicationVersion"
+            }
+            ResourceType::AwsElasticBeanstalkEnvironment => "AWS::ElasticBeanstalk::Environment",
+            ResourceType::AwsWAFv2WebACL => "AWS::WAFv2::WebACL",
+            ResourceType::AwsWAFv2RuleGroup => "AWS::WAFv2::RuleGroup",
+            ResourceType::AwsWAFv2IPSet => "AWS::WAFv2::IPSet",
+            ResourceType::AwsWAFv2RegexPatternSet => "AWS::WAFv2::RegexPatternSet",
+            ResourceType::AwsWAFv2ManagedRuleSet => "AWS::WAFv2::ManagedRuleSet",
+            ResourceType::AwsXRayEncryptionConfig => "AWS::XRay::EncryptionConfig",
+            ResourceType::AwsSsmAssociationCompliance => "AWS::SSM::AssociationCompliance",
+            ResourceType::AwsSsmPatchCompliance => "AWS::SSM::PatchCompliance",
+            ResourceType::AwsShieldProtection => "AWS::Shield::Protection",
+            ResourceType::AwsShieldRegionalProtection => "AWS::ShieldRegional::Protection",
+            ResourceType::AwsConfigConformancePackCompliance => {
+                "AWS::Config::ConformancePackCompliance"
+            }
+            ResourceType::AwsConfigResourceCompliance => "AWS::Config::ResourceCompliance",
+            ResourceType::AwsApiGatewayStage => "AWS::ApiGateway::Stage",
+            ResourceType::AwsApiGatewayRestApi => "AWS::ApiGateway::RestApi",
+            ResourceType::AwsApiGatewayV2Stage => "AWS::ApiGatewayV2::Stage",
+            ResourceType::AwsApiGatewayV2Api => "AWS::ApiGatewayV2::Api",
+            ResourceType::AwsCodePipelinePipeline => "AWS::CodePipeline::Pipeline",
+            ResourceType::AwsServiceCatalogCloudFormationProvisionedProduct => {
+                "AWS::ServiceCatalog::CloudFormationProvisionedProduct"
+            }
+            ResourceType::AwsServiceCatalogCloudFormationProduct => {
+                "AWS::ServiceCatalog::CloudFormationProduct"
+            }
+            ResourceType::AwsServiceCatalogPortfolio => "AWS::ServiceCatalog::Portfolio",
+            ResourceType::AwsSqsQueue => "AWS::SQS::Queue",
+            ResourceType::AwsKmsKey => "AWS::KMS::Key",
+            ResourceType::AwsQldbLedger => "AWS::QLDB::Ledger",
+            ResourceType::AwsSecretsManagerSecret => "AWS::SecretsManager::Secret",
+            ResourceType::AwsSnsTopic => "AWS::SNS::Topic",
+            ResourceType::AwsSsmFileData => "AWS::SSM::FileData",
+            ResourceType::AwsBackupBackupPlan => "AWS::Backup::BackupPlan",
+            ResourceType::AwsBackupBackupSelection => "AWS::Backup::BackupSelection",
+            ResourceType::AwsBackupBackupVault => "AWS::Backup::BackupVault",
+            ResourceType::AwsBackupRecoveryPoint => "AWS::Backup::RecoveryPoint",
+            ResourceType::AwsEcrRepository => "AWS::ECR::Repository",
+            ResourceType::AwsEcsCluster => "AWS::ECS::Cluster",
+            ResourceType::AwsEcsService => "AWS::ECS::Service",
+            ResourceType::AwsEcsTaskDefinition => "AWS::ECS::TaskDefinition",
+            ResourceType::AwsEfsAccessPoint => "AWS::EFS::AccessPoint",
+            ResourceType::AwsEfsFileSystem => "AWS::EFS::FileSystem",
+            ResourceType::AwsEksCluster => "AWS::EKS::Cluster",
+            ResourceType::AwsOpenSearchDomain => "AWS::OpenSearch::Domain",
+            ResourceType::AwsEc2TransitGateway => "AWS::EC2::TransitGateway",
+            ResourceType::AwsKinesisStream => "AWS::Kinesis::Stream",
+            ResourceType::AwsKinesisStreamConsumer => "AWS::Kinesis::StreamConsumer",
+            ResourceType::AwsCodeDeployApplication => "AWS::CodeDeploy::Application",
+            ResourceType::AwsCodeDeployDeploymentConfig => "AWS::CodeDeploy::DeploymentConfig",
+            ResourceType::AwsCodeDeployDeploymentGroup => "AWS::CodeDeploy::DeploymentGroup",
+            ResourceType::AwsEc2LaunchTemplate => "AWS::EC2::LaunchTemplate",
+            ResourceType::AwsEcrPublicRepository => "AWS::ECR::PublicRepository",
+            ResourceType::AwsGuardDutyDetector => "AWS::GuardDuty::Detector",
+            ResourceType::AwsEmrSecurityConfiguration => "AWS::EMR::SecurityConfiguration",
+            ResourceType::AwsSageMakerCodeRepository => "AWS::SageMaker::CodeRepository",
+            ResourceType::AwsRoute53ResolverResolverEndpoint => {
+                "AWS::Route53Resolver::ResolverEndpoint"
+            }
+            ResourceType::AwsRoute53ResolverResolverRule => "AWS::Route53Resolver::ResolverRule",
+            ResourceType::AwsRoute53ResolverResolverRuleAssociation => {
+                "AWS::Route53Resolver::ResolverRuleAssociation"
+            }
+            ResourceType::AwsDmsReplicationSubnetGroup => "AWS::DMS::ReplicationSubnetGroup",
+            ResourceType::AwsDmsEventSubscription => "AWS::DMS::EventSubscription",
+            ResourceType::AwsMskCluster => "AWS::MSK::Cluster",
+            ResourceType::AwsStepFunctionsActivity => "AWS::StepFunctions::Activity",
+            ResourceType::AwsWorkSpacesWorkspace => "AWS::WorkSpaces::Workspace",
+            ResourceType::AwsWorkSpacesConnectionAlias => "AWS::WorkSpaces::ConnectionAlias",
+            ResourceType::AwsSageMakerModel => "AWS::SageMaker::Model",
+            ResourceType::AwsElasticLoadBalancingV2Listener => {
+                "AWS::ElasticLoadBalancingV2::Listener"
+            }
+            ResourceType::AwsStepFunctionsStateMachine => "AWS::StepFunctions::StateMachine",
+            ResourceType::AwsBatchJobQueue => "AWS::Batch::JobQueue",
+            ResourceType::AwsBatchComputeEnvironment => "AWS::Batch::ComputeEnvironment",
+            ResourceType::AwsAccessAnalyzerAnalyzer => "AWS::AccessAnalyzer::Analyzer",
+            ResourceType::AwsAthenaWorkGroup => "AWS::Athena::WorkGroup",
+            ResourceType::AwsAthenaDataCatalog => "AWS::Athena::DataCatalog",
+            ResourceType::AwsDetectiveGraph => "AWS::Detective::Graph",
+            ResourceType::AwsGlobalAcceleratorAccelerator => "AWS::GlobalAccelerator::Accelerator",
+            ResourceType::AwsGlobalAcceleratorEndpointGroup => {
+                "AWS::GlobalAccelerator::EndpointGroup"
+            }
+            ResourceType::AwsGlobalAcceleratorListener => "AWS::GlobalAccelerator::Listener",
+            ResourceType::AwsEc2TransitGatewayAttachment => "AWS::EC2::TransitGatewayAttachment",
+            ResourceType::AwsEc2TransitGatewayRouteTable => "AWS::EC2::TransitGatewayRouteTable",
+            ResourceType::AwsDmsCertificate => "AWS::DMS::Certificate",
+            ResourceType::AwsAppConfigApplication => "AWS::AppConfig::Application",
+            ResourceType::AwsAppSyncGraphQLApi => "AWS::AppSync::GraphQLApi",
+            ResourceType::AwsDataSyncLocationSMB => "AWS::DataSync::LocationSMB",
+            ResourceType::AwsDataSyncLocationFSxLustre => "AWS::DataSync::LocationFSxLustre",
+            ResourceType::AwsDataSyncLocationS3 => "AWS::DataSync::LocationS3",
+            ResourceType::AwsDataSyncLocationEFS => "AWS::DataSync::LocationEFS",
+            ResourceType::AwsDataSyncTask => "AWS::DataSync::Task",
+            ResourceType::AwsDataSyncLocationNFS => "AWS::DataSync::LocationNFS",
+            ResourceType::AwsEc2NetworkInsightsAccessScopeAnalysis => {
+                "AWS::EC2::NetworkInsightsAccessScopeAnalysis"
+            }
+            ResourceType::AwsEksFargateProfile => "AWS::EKS::FargateProfile",
+            ResourceType::AwsGlueJob => "AWS::Glue::Job",
+            ResourceType::AwsGuardDutyThreatIntelSet => "AWS::GuardDuty::ThreatIntelSet",
+            ResourceType::AwsGuardDutyIPSet => "AWS::GuardDuty::IPSet",
+            ResourceType::AwsSageMakerWorkteam => "AWS::SageMaker::Workteam",
+            ResourceType::AwsSageMakerNotebookInstanceLifecycleConfig => {
+                "AWS::SageMaker::NotebookInstanceLifecycleConfig"
+            }
+            ResourceType::AwsServiceDiscoveryService => "AWS::ServiceDiscovery::Service",
+            ResourceType::AwsServiceDiscoveryPublicDnsNamespace => {
+                "AWS::ServiceDiscovery::PublicDnsNamespace"
+            }
+            ResourceType::AwsSesContactList => "AWS::SES::ContactList",
+            ResourceType::AwsSesConfigurationSet => "AWS::SES::ConfigurationSet",
+            ResourceType::AwsRoute53HostedZone => "AWS::Route53::HostedZone",
+            ResourceType::AwsIoTEventsInput => "AWS::IoTEvents::Input",
+            ResourceType::AwsIoTEventsDetectorModel => "AWS::IoTEvents::DetectorModel",
+            ResourceType::AwsIoTEventsAlarmModel => "AWS::IoTEvents::AlarmModel",
+            ResourceType::AwsServiceDiscoveryHttpNamespace => {
+                "AWS::ServiceDiscovery::HttpNamespace"
+            }
+            ResourceType::AwsEventsEventBus => "AWS::Events::EventBus",
+            ResourceType::AwsImageBuilderContainerRecipe => "AWS::ImageBuilder::ContainerRecipe",
+            ResourceType::AwsImageBuilderDistributionConfiguration => {
+                "AWS::ImageBuilder::DistributionConfiguration"
+            }
+            ResourceType::AwsImageBuilderInfrastructureConfiguration => {
+                "AWS::ImageBuilder::InfrastructureConfiguration"
+            }
+            ResourceType::AwsDataSyncLocationObjectStorage => {
+                "AWS::DataSync::LocationObjectStorage"
+            }
+            ResourceType::AwsDataSyncLocationHDFS => "AWS::DataSync::LocationHDFS",
+            ResourceType::AwsGlueClassifier => "AWS::Glue::Classifier",
+            ResourceType::AwsRoute53RecoveryReadinessCell => "AWS::Route53RecoveryReadiness::Cell",
+            ResourceType::AwsRoute53RecoveryReadinessReadinessCheck => {
+                "AWS::Route53RecoveryReadiness::ReadinessCheck"
+            }
+            ResourceType::AwsEcrRegistryPolicy => "AWS::ECR::RegistryPolicy",
+            ResourceType::AwsBackupReportPlan => "AWS::Backup::ReportPlan",
+            ResourceType::AwsLightsailCertificate => "AWS::Lightsail::Certificate",
+            ResourceType::AwsRumAppMonitor => "AWS::RUM::AppMonitor",
+            ResourceType::AwsEventsEndpoint => "AWS::Events::Endpoint",
+            ResourceType::AwsSesReceiptRuleSet => "AWS::SES::ReceiptRuleSet",
+            ResourceType::AwsEventsArchive => "AWS::Events::Archive",
+            ResourceType::AwsEventsApiDestination => "AWS::Events::ApiDestination",
+            ResourceType::AwsLightsailDisk => "AWS::Lightsail::Disk",
+            ResourceType::AwsFisExperimentTemplate => "AWS::FIS::ExperimentTemplate",
+            ResourceType::AwsDataSyncLocationFSxWindows => "AWS::DataSync::LocationFSxWindows",
+            ResourceType::AwsSesReceiptFilter => "AWS::SES::ReceiptFilter",
+            ResourceType::AwsGuardDutyFilter => "AWS::GuardDuty::Filter",
+            ResourceType::AwsSesTemplate => "AWS::SES::Template",
+            ResourceType::AwsAmazonMQBroker => "AWS::AmazonMQ::Broker",
+            ResourceType::AwsAppConfigEnvironment => "AWS::AppConfig::Environment",
+            ResourceType::AwsAppConfigConfigurationProfile => {
+                "AWS::AppConfig::ConfigurationProfile"
+            }
+            ResourceType::AwsCloud9EnvironmentEC2 => "AWS::Cloud9::EnvironmentEC2",
+            ResourceType::AwsEventSchemasRegistry => "AWS::EventSchemas::Registry",
+            ResourceType::AwsEventSchemasRegistryPolicy => "AWS::EventSchemas::RegistryPolicy",
+            ResourceType::AwsEventSchemasDiscoverer => "AWS::EventSchemas::Discoverer",
+            ResourceType::AwsFraudDetectorLabel => "AWS::FraudDetector::Label",
+            ResourceType::AwsFraudDetectorEntityType => "AWS::FraudDetector::EntityType",
+            ResourceType::AwsFraudDetectorVariable => "AWS::FraudDetector::Variable",
+            ResourceType::AwsFraudDetectorOutcome => "AWS::FraudDetector::Outcome",
+            ResourceType::AwsIoTAuthorizer => "AWS::IoT::Authorizer",
+            ResourceType::AwsIoTSecurityProfile => "AWS::IoT::SecurityProfile",
+            ResourceType::AwsIoTRoleAlias => "AWS::IoT::RoleAlias",
+            ResourceType::AwsIoTDimension => "AWS::IoT::Dimension",
+            ResourceType::AwsIoTAnalyticsDatastore => "AWS::IoTAnalytics::Datastore",
+            ResourceType::AwsLightsailBucket => "AWS::Lightsail::Bucket",
+            ResourceType::AwsLightsailStaticIp => "AWS::Lightsail::StaticIp",
+            ResourceType::AwsMediaPackagePackagingGroup => "AWS::MediaPackage::PackagingGroup",
+            ResourceType::AwsRoute53RecoveryReadinessRecoveryGroup => {
+                "AWS::Route53RecoveryReadiness::RecoveryGroup"
+            }
+            ResourceType::AwsResilienceHubResiliencyPolicy => {
+                "AWS::ResilienceHub::ResiliencyPolicy"
+            }
+            ResourceType::AwsTransferWorkflow => "AWS::Transfer::Workflow",
+            ResourceType::AwsEksIdentityProviderConfig => "AWS::EKS::IdentityProviderConfig",
+            ResourceType::AwsEksAddon => "AWS::EKS::Addon",
+            ResourceType::AwsGlueMLTransform => "AWS::Glue::MLTransform",
+            ResourceType::AwsIoTPolicy => "AWS::IoT::Policy",
+            ResourceType::AwsIoTMitigationAction => "AWS::IoT::MitigationAction",
+            ResourceType::AwsIoTTwinMakerWorkspace => "AWS::IoTTwinMaker::Workspace",
+            ResourceType::AwsIoTTwinMakerEntity => "AWS::IoTTwinMaker::Entity",
+            ResourceType::AwsIoTAnalyticsDataset => "AWS::IoTAnalytics::Dataset",
+            ResourceType::AwsIoTAnalyticsPipeline => "AWS::IoTAnalytics::Pipeline",
+            ResourceType::AwsIoTAnalyticsChannel => "AWS::IoTAnalytics::Channel",
+            ResourceType::AwsIoTSiteWiseDashboard => "AWS::IoTSiteWise::Dashboard",
+            ResourceType::AwsIoTSiteWiseProject => "AWS::IoTSiteWise::Project",
+            ResourceType::AwsIoTSiteWisePortal => "AWS::IoTSiteWise::Portal",
+            ResourceType::AwsIoTSiteWiseAssetModel => "AWS::IoTSiteWise::AssetModel",
+            ResourceType::AwsIvsChannel => "AWS::IVS::Channel",
+            ResourceType::AwsIvsRecordingConfiguration => "AWS::IVS::RecordingConfiguration",
+            ResourceType::AwsIvsPlaybackKeyPair => "AWS::IVS::PlaybackKeyPair",
+            ResourceType::AwsKinesisAnalyticsV2Application => {
+                "AWS::KinesisAnalyticsV2::Application"
+            }
+            ResourceType::AwsRdsGlobalCluster => "AWS::RDS::GlobalCluster",
+            ResourceType::AwsS3MultiRegionAccessPoint => "AWS::S3::MultiRegionAccessPoint",
+            ResourceType::AwsDeviceFarmTestGridProject => "AWS::DeviceFarm::TestGridProject",
+            ResourceType::AwsBudgetsBudgetsAction => "AWS::Budgets::BudgetsAction",
+            ResourceType::AwsLexBot => "AWS::Lex::Bot",
+            ResourceType::AwsCodeGuruReviewerRepositoryAssociation => {
+                "AWS::CodeGuruReviewer::RepositoryAssociation"
+            }
+            ResourceType::AwsIoTCustomMetric => "AWS::IoT::CustomMetric",
+            ResourceType::AwsRoute53ResolverFirewallDomainList => {
+                "AWS::Route53Resolver::FirewallDomainList"
+            }
+            ResourceType::AwsRoboMakerRobotApplicationVersion => {
+                "AWS::RoboMaker::RobotApplicationVersion"
+            }
+            ResourceType::AwsEc2TrafficMirrorSession => "AWS::EC2::TrafficMirrorSession",
+            ResourceType::AwsIoTSiteWiseGateway => "AWS::IoTSiteWise::Gateway",
+            ResourceType::AwsLexBotAlias => "AWS::Lex::BotAlias",
+            ResourceType::AwsLookoutMetricsAlert => "AWS::LookoutMetrics::Alert",
+            ResourceType::AwsIoTAccountAuditConfiguration => "AWS::IoT::AccountAuditConfiguration",
+            ResourceType::AwsEc2TrafficMirrorTarget => "AWS::EC2::TrafficMirrorTarget",
+            ResourceType::AwsS3StorageLens => "AWS::S3::StorageLens",
+            ResourceType::AwsIoTScheduledAudit => "AWS::IoT::ScheduledAudit",
+            ResourceType::AwsEventsConnection => "AWS::Events::Connection",
+            ResourceType::AwsEventSchemasSchema => "AWS::EventSchemas::Schema",
+            ResourceType::AwsMediaPackagePackagingConfiguration => {
+                "AWS::MediaPackage::PackagingConfiguration"
+            }
+            ResourceType::AwsKinesisVideoSignalingChannel => "AWS::KinesisVideo::SignalingChannel",
+            ResourceType::AwsAppStreamDirectoryConfig => "AWS::AppStream::DirectoryConfig",
+            ResourceType::AwsLookoutVisionProject => "AWS::LookoutVision::Project",
+            ResourceType::AwsRoute53RecoveryControlCluster => {
+                "AWS::Route53RecoveryControl::Cluster"
+            }
+            ResourceType::AwsRoute53RecoveryControlSafetyRule => {
+                "AWS::Route53RecoveryControl::SafetyRule"
+            }
+            ResourceType::AwsRoute53RecoveryControlControlPanel => {
+                "AWS::Route53RecoveryControl::ControlPanel"
+            }
+            ResourceType::AwsRoute53RecoveryControlRoutingControl => {
+                "AWS::Route53RecoveryControl::RoutingControl"
+            }
+            ResourceType::AwsRoute53RecoveryReadinessResourceSet => {
+                "AWS::Route53RecoveryReadiness::ResourceSet"
+            }
+            ResourceType::AwsRoboMakerSimulationApplication => {
+                "AWS::RoboMaker::SimulationApplication"
+            }
+            ResourceType::AwsRoboMakerRobotApplication => "AWS::RoboMaker::RobotApplication",
+            ResourceType::AwsHealthLakeFHIRDatastore => "AWS::HealthLake::FHIRDatastore",
+            ResourceType::AwsPinpointSegment => "AWS::Pinpoint::Segment",
+            ResourceType::AwsPinpointApplicationSettings => "AWS::Pinpoint::ApplicationSettings",
+            ResourceType::AwsEventsRule => "AWS::Events::Rule",
+            ResourceType::AwsEc2DHCPOptions => "AWS::EC2::DHCPOptions",
+            ResourceType::AwsEc2NetworkInsightsPath => "AWS::EC2::NetworkInsightsPath",
+            ResourceType::AwsEc2TrafficMirrorFilter => "AWS::EC2::TrafficMirrorFilter",
+            ResourceType::AwsEc2Ipam => "AWS::EC2::IPAM",
+            ResourceType::AwsIoTTwinMakerScene => "AWS::IoTTwinMaker::Scene",
+            ResourceType::AwsNetworkManagerTransitGatewayRegistration => {
+                "AWS::NetworkManager::TransitGatewayRegistration"
+            }
+            ResourceType::AwsCustomerProfilesDomain => "AWS::CustomerProfiles::Domain",
+            ResourceType::AwsAutoScalingWarmPool => "AWS::AutoScaling::WarmPool",
+            ResourceType::AwsConnectPhoneNumber => "AWS::Connect::PhoneNumber",
+            ResourceType::AwsAppConfigDeploymentStrategy => "AWS::AppConfig::DeploymentStrategy",
+            ResourceType::AwsAppFlowFlow => "AWS::AppFlow::Flow",
+            ResourceType::AwsAuditManagerAssessment => "AWS::AuditManager::Assessment",
+            ResourceType::AwsCloudWatchMetricStream => "AWS::CloudWatch::MetricStream",
+            ResourceType::AwsDeviceFarmInstanceProfile => "AWS::DeviceFarm::InstanceProfile",
+            ResourceType::AwsDeviceFarmProject => "AWS::DeviceFarm::Project",
+            ResourceType::AwsEc2EC2Fleet => "AWS::EC2::EC2Fleet",
+            ResourceType::AwsEc2SubnetRouteTableAssociation => {
+                "AWS::EC2::SubnetRouteTableAssociation"
+            }
+            ResourceType::AwsEcrPullThroughCacheRule => "AWS::ECR::PullThroughCacheRule",
+            ResourceType::AwsGroundStationConfig => "AWS::GroundStation::Config",
+            ResourceType::AwsImageBuilderImagePipeline => "AWS::ImageBuilder::ImagePipeline",
+            ResourceType::AwsIoTFleetMetric => "AWS::IoT::FleetMetric",
+            ResourceType::AwsIoTWirelessServiceProfile => "AWS::IoTWireless::ServiceProfile",
+            ResourceType::AwsNetworkManagerDevice => "AWS::NetworkManager::Device",
+            ResourceType::AwsNetworkManagerGlobalNetwork => "AWS::NetworkManager::GlobalNetwork",
+            ResourceType::AwsNetworkManagerLink => "AWS::NetworkManager::Link",
+            ResourceType::AwsNetworkManagerSite => "AWS::NetworkManager::Site",
+            ResourceType::AwsPanoramaPackage => "AWS::Panorama::Package",
+            ResourceType::AwsPinpointApp => "AWS::Pinpoint::App",
+            ResourceType::AwsRedshiftScheduledAction => "AWS::Redshift::ScheduledAction",
+            ResourceType::AwsRoute53ResolverFirewallRuleGroupAssociation => {
+                "AWS::Route53Resolver::FirewallRuleGroupAssociation"
+            }
+            ResourceType::AwsSageMakerAppImageConfig => "AWS::SageMaker::AppImageConfig",
+            ResourceType::AwsSageMakerImage => "AWS::SageMaker::Image",
+            ResourceType::AwsEcsTaskSet => "AWS::ECS::TaskSet",
+            ResourceType::AwsCassandraKeyspace => "AWS::Cassandra::Keyspace",
+            ResourceType::AwsSignerSigningProfile => "AWS::Signer::SigningProfile",
+            ResourceType::AwsAmplifyApp => "AWS::Amplify::App",
+            ResourceType::AwsAppMeshVirtualNode => "AWS::AppMesh::VirtualNode",
+            ResourceType::AwsAppMeshVirtualService => "AWS::AppMesh::VirtualService",
+            ResourceType::AwsAppRunnerVpcConnector => "AWS::AppRunner::VpcConnector",
+            ResourceType::AwsAppStreamApplication => "AWS::AppStream::Application",
+            ResourceType::AwsCodeArtifactRepository => "AWS::CodeArtifact::Repository",
+            ResourceType::AwsEc2PrefixList => "AWS::EC2::PrefixList",
+            ResourceType::AwsEc2SpotFleet => "AWS::EC2::SpotFleet",
+            ResourceType::AwsEvidentlyProject => "AWS::Evidently::Project",
+            ResourceType::AwsForecastDataset => "AWS::Forecast::Dataset",
+            ResourceType::AwsIamSAMLProvider => "AWS::IAM::SAMLProvider",
+            ResourceType::AwsIamServerCertificate => "AWS::IAM::ServerCertificate",
+            ResourceType::AwsPinpointCampaign => "AWS::Pinpoint::Campaign",
+            ResourceType::AwsPinpointInAppTemplate => "AWS::Pinpoint::InAppTemplate",
+            ResourceType::AwsSageMakerDomain => "AWS::SageMaker::Domain",
+            ResourceType::AwsTransferAgreement => "AWS::Transfer::Agreement",
+            ResourceType::AwsTransferConnector => "AWS::Transfer::Connector",
+            ResourceType::AwsKinesisFirehoseDeliveryStream => {
+                "AWS::KinesisFirehose::DeliveryStream"
+            }
+            ResourceType::AwsAmplifyBranch => "AWS::Amplify::Branch",
+            ResourceType::AwsAppIntegrationsEventIntegration => {
+                "AWS::AppIntegrations::EventIntegration"
+            }
+            ResourceType::AwsAppMeshRoute => "AWS::AppMesh::Route",
+            ResourceType::AwsAthenaPreparedStatement => "AWS::Athena::PreparedStatement",
+            ResourceType::AwsEc2IPAMScope => "AWS::EC2::IPAMScope",
+            ResourceType::AwsEvidentlyLaunch => "AWS::Evidently::Launch",
+            ResourceType::AwsForecastDatasetGroup => "AWS::Forecast::DatasetGroup",
+            ResourceType::AwsGreengrassV2ComponentVersion => "AWS::GreengrassV2::ComponentVersion",
+            ResourceType::AwsGroundStationMissionProfile => "AWS::GroundStation::MissionProfile",
+            ResourceType::AwsMediaConnectFlowEntitlement => "AWS::MediaConnect::FlowEntitlement",
+            ResourceType::AwsMediaConnectFlowVpcInterface => "AWS::MediaConnect::FlowVpcInterface",
+            ResourceType::AwsMediaTailorPlaybackConfiguration => {
+                "AWS::MediaTailor::PlaybackConfiguration"
+            }
+            ResourceType::AwsMskConfiguration => "AWS::MSK::Configuration",
+            ResourceType::AwsPersonalizeDataset => "AWS::Personalize::Dataset",
+            ResourceType::AwsPersonalizeSchema => "AWS::Personalize::Schema",
+            ResourceType::AwsPersonalizeSolution => "AWS::Personalize::Solution",
+            ResourceType::AwsPinpointEmailTemplate => "AWS::Pinpoint::EmailTemplate",
+            ResourceType::AwsPinpointEventStream => "AWS::Pinpoint::EventStream",
+            ResourceType::AwsResilienceHubApp => "AWS::ResilienceHub::App",
+            ResourceType::AwsAcmpcaCertificateAuthority => "AWS::ACMPCA::CertificateAuthority",
+            ResourceType::AwsAppConfigHostedConfigurationVersion => {
+                "AWS::AppConfig::HostedConfigurationVersion"
+            }
+            ResourceType::AwsAppMeshVirtualGateway => "AWS::AppMesh::VirtualGateway",
+            ResourceType::AwsAppMeshVirtualRouter => "AWS::AppMesh::VirtualRouter",
+            ResourceType::AwsAppRunnerService => "AWS::AppRunner::Service",
+            ResourceType::AwsCustomerProfilesObjectType => "AWS::CustomerProfiles::ObjectType",
+            ResourceType::AwsDmsEndpoint => "AWS::DMS::Endpoint",
+            ResourceType::AwsEc2CapacityReservation => "AWS::EC2::CapacityReservation",
+            ResourceType::AwsEc2ClientVpnEndpoint => "AWS::EC2::ClientVpnEndpoint",
+            ResourceType::AwsKendraIndex => "AWS::Kendra::Index",
+            ResourceType::AwsKinesisVideoStream => "AWS::KinesisVideo::Stream",
+            ResourceType::AwsLogsDestination => "AWS::Logs::Destination",
+            ResourceType::AwsPinpointEmailChannel => "AWS::Pinpoint::EmailChannel",
+            ResourceType::AwsS3AccessPoint => "AWS::S3::AccessPoint",
+            ResourceType::AwsNetworkManagerCustomerGatewayAssociation => {
+                "AWS::NetworkManager::CustomerGatewayAssociation"
+            }
+            ResourceType::AwsNetworkManagerLinkAssociation => {
+                "AWS::NetworkManager::LinkAssociation"
+            }
+            ResourceType::AwsIoTWirelessMulticastGroup => "AWS::IoTWireless::MulticastGroup",
+            ResourceType::AwsPersonalizeDatasetGroup => "AWS::Personalize::DatasetGroup",
+            ResourceType::AwsIoTTwinMakerComponentType => "AWS::IoTTwinMaker::ComponentType",
+            ResourceType::AwsCodeBuildReportGroup => "AWS::CodeBuild::ReportGroup",
+            ResourceType::AwsSageMakerFeatureGroup => "AWS::SageMaker::FeatureGroup",
+            ResourceType::AwsMskBatchScramSecret => "AWS::MSK::BatchScramSecret",
+            ResourceType::AwsAppStreamStack => "AWS::AppStream::Stack",
+            ResourceType::AwsIoTJobTemplate => "AWS::IoT::JobTemplate",
+            ResourceType::AwsIoTWirelessFuotaTask => "AWS::IoTWireless::FuotaTask",
+            ResourceType::AwsIoTProvisioningTemplate => "AWS::IoT::ProvisioningTemplate",
+            ResourceType::AwsInspectorV2Filter => "AWS::InspectorV2::Filter",
+            ResourceType::AwsRoute53ResolverResolverQueryLoggingConfigAssociation => {
+                "AWS::Route53Resolver::ResolverQueryLoggingConfigAssociation"
+            }
+            ResourceType::AwsServiceDiscoveryInstance => "AWS::ServiceDiscovery::Instance",
+            ResourceType::AwsTransferCertificate => "AWS::Transfer::Certificate",
+            ResourceType::AwsMediaConnectFlowSource => "AWS::MediaConnect::FlowSource",
+            ResourceType::AwsApsRuleGroupsNamespace => "AWS::APS::RuleGroupsNamespace",
+            ResourceType::AwsCodeGuruProfilerProfilingGroup => {
+                "AWS::CodeGuruProfiler::ProfilingGroup"
+            }
+            ResourceType::AwsRoute53ResolverResolverQueryLoggingConfig => {
+                "AWS::Route53Resolver::ResolverQueryLoggingConfig"
+            }
+            ResourceType::AwsBatchSchedulingPolicy => "AWS::Batch::SchedulingPolicy",
+            ResourceType::AwsAcmpcaCertificateAuthorityActivation => {
+                "AWS::ACMPCA::CertificateAuthorityActivation"
+            }
+            ResourceType::AwsAppMeshGatewayRoute => "AWS::AppMesh::GatewayRoute",
+            ResourceType::AwsAppMeshMesh => "AWS::AppMesh::Mesh",
+            ResourceType::AwsConnectInstance => "AWS::Connect::Instance",
+            ResourceType::AwsConnectQuickConnect => "AWS::Connect::QuickConnect",
+            ResourceType::AwsEc2CarrierGateway => "AWS::EC2::CarrierGateway",
+            ResourceType::AwsEc2IPAMPool => "AWS::EC2::IPAMPool",
+            ResourceType::AwsEc2TransitGatewayConnect => "AWS::EC2::TransitGatewayConnect",
+            ResourceType::AwsEc2TransitGatewayMulticastDomain => {
+                "AWS::EC2::TransitGatewayMulticastDomain"
+            }
+            ResourceType::AwsEcsCapacityProvider => "AWS::ECS::CapacityProvider",
+            ResourceType::AwsIamInstanceProfile => "AWS::IAM::InstanceProfile",
+            ResourceType::AwsIoTCACertificate => "AWS::IoT::CACertificate",
+            ResourceType::AwsIoTTwinMakerSyncJob => "AWS::IoTTwinMaker::SyncJob",
+            ResourceType::AwsKafkaConnectConnector => "AWS::KafkaConnect::Connector",
+            ResourceType::AwsLambdaCodeSigningConfig => "AWS::Lambda::CodeSigningConfig",
+            ResourceType::AwsNetworkManagerConnectPeer => "AWS::NetworkManager::ConnectPeer",
+            ResourceType::AwsResourceExplorer2Index => "AWS::ResourceExplorer2::Index",
+            ResourceType::AwsAppStreamFleet => "AWS::AppStream::Fleet",
+            ResourceType::AwsCognitoUserPool => "AWS::Cognito::UserPool",
+            ResourceType::AwsCognitoUserPoolClient => "AWS::Cognito::UserPoolClient",
+            ResourceType::AwsCognitoUserPoolGroup => "AWS::Cognito::UserPoolGroup",
+            ResourceType::AwsEc2NetworkInsightsAccessScope => {
+                "AWS::EC2::NetworkInsightsAccessScope"
+            }
+            ResourceType::AwsEc2NetworkInsightsAnalysis => "AWS::EC2::NetworkInsightsAnalysis",
+            ResourceType::AwsGrafanaWorkspace => "AWS::Grafana::Workspace",
+            ResourceType::AwsGroundStationDataflowEndpointGroup => {
+                "AWS::GroundStation::DataflowEndpointGroup"
+            }
+            ResourceType::AwsImageBuilderImageRecipe => "AWS::ImageBuilder::ImageRecipe",
+            ResourceType::AwsKmsAlias => "AWS::KMS::Alias",
+            ResourceType::AwsM2Environment => "AWS::M2::Environment",
+            ResourceType::AwsQuickSightDataSource => "AWS::QuickSight::DataSource",
+            ResourceType::AwsQuickSightTemplate => "AWS::QuickSight::Template",
+            ResourceType::AwsQuickSightTheme => "AWS::QuickSight::Theme",
+            ResourceType::AwsRdsOptionGroup => "AWS::RDS::OptionGroup",
+            ResourceType::AwsRedshiftEndpointAccess => "AWS::Redshift::EndpointAccess",
+            ResourceType::AwsRoute53ResolverFirewallRuleGroup => {
+                "AWS::Route53Resolver::FirewallRuleGroup"
+            }
+            ResourceType::AwsSsmDocument => "AWS::SSM::Document",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "AWS::EC2::CustomerGateway" => ResourceType::AwsEc2CustomerGateway,
+            "AWS::EC2::EIP" => ResourceType::AwsEc2Eip,
+            "AWS::EC2::Host" => ResourceType::AwsEc2Host,
+            "AWS::EC2::Instance" => ResourceType::AwsEc2Instance,
+            "AWS::EC2::InternetGateway" => ResourceType::AwsEc2InternetGateway,
+            "AWS::EC2::NetworkAcl" => ResourceType::AwsEc2NetworkAcl,
+            "AWS::EC2::NetworkInterface" => ResourceType::AwsEc2NetworkInterface,
+            "AWS::EC2::RouteTable" => ResourceType::AwsEc2RouteTable,
+            "AWS::EC2::SecurityGroup" => ResourceType::AwsEc2SecurityGroup,
+            "AWS::EC2::Subnet" => ResourceType::AwsEc2Subnet,
+            "AWS::CloudTrail::Trail" => ResourceType::AwsCloudTrailTrail,
+            "AWS::EC2::Volume" => ResourceType::AwsEc2Volume,
+            "AWS::EC2::VPC" => ResourceType::AwsEc2Vpc,
+            "AWS::EC2::VPNConnection" => ResourceType::AwsEc2VPNConnection,
+            "AWS::EC2::VPNGateway" => ResourceType::AwsEc2VPNGateway,
+            "AWS::EC2::RegisteredHAInstance" => ResourceType::AwsEc2RegisteredHAInstance,
+            "AWS::EC2::NatGateway" => ResourceType::AwsEc2NatGateway,
+            "AWS::EC2::EgressOnlyInternetGateway" => ResourceType::AwsEc2EgressOnlyInternetGateway,
+            "AWS::EC2::VPCEndpoint" => ResourceType::AwsEc2VPCEndpoint,
+            "AWS::EC2::VPCEndpointService" => ResourceType::AwsEc2VPCEndpointService,
+            "AWS::EC2::FlowLog" => ResourceType::AwsEc2FlowLog,
+            "AWS::EC2::VPCPeeringConnection" => ResourceType::AwsEc2VPCPeeringConnection,
+            "AWS::Elasticsearch::Domain" => ResourceType::AwsElasticsearchDomain,
+            "AWS::IAM::Group" => ResourceType::AwsIamGroup,
+            "AWS::IAM::Policy" => ResourceType::AwsIamPolicy,
+            "AWS::IAM::Role" => ResourceType::AwsIamRole,
+            "AWS::IAM::User" => ResourceType::AwsIamUser,
+            "AWS::ElasticLoadBalancingV2::LoadBalancer" => {
+                ResourceType::AwsElasticLoadBalancingV2LoadBalancer
+            }
+            "AWS::ACM::Certificate" => ResourceType::AwsAcmCertificate,
+            "AWS::RDS::DBInstance" => ResourceType::AwsRdsDBInstance,
+            "AWS::RDS::DBSubnetGroup" => ResourceType::AwsRdsDBSubnetGroup,
+            "AWS::RDS::DBSecurityGroup" => ResourceType::AwsRdsDBSecurityGroup,
+            "AWS::RDS::DBSnapshot" => ResourceType::AwsRdsDBSnapshot,
+            "AWS::RDS::DBCluster" => ResourceType::AwsRdsDBCluster,
+            "AWS::RDS::DBClusterSnapshot" => ResourceType::AwsRdsDBClusterSnapshot,
+            "AWS::RDS::EventSubscription" => ResourceType::AwsRdsEventSubscription,
+            "AWS::S3::Bucket" => ResourceType::AwsS3Bucket,
+            "AWS::S3::AccountPublicAccessBlock" => ResourceType::AwsS3AccountPublicAccessBlock,
+            "AWS::Redshift::Cluster" => ResourceType::AwsRedshiftCluster,
+            "AWS::Redshift::ClusterSnapshot" => ResourceType::AwsRedshiftClusterSnapshot,
+            "AWS::Redshift::ClusterParameterGroup" => {
+                ResourceType::AwsRedshiftClusterParameterGroup
+            }
+            "AWS::Redshift::ClusterSecurityGroup" => ResourceType::AwsRedshiftClusterSecurityGroup,
+            "AWS::Redshift::ClusterSubnetGroup" => ResourceType::AwsRedshiftClusterSubnetGroup,
+            "AWS::Redshift::EventSubscription" => ResourceType::AwsRedshiftEventSubscription,
+            "AWS::SSM::ManagedInstanceInventory" => ResourceType::AwsSsmManagedInstanceInventory,
+            "AWS::CloudWatch::Alarm" => ResourceType::AwsCloudWatchAlarm,
+            "AWS::CloudFormation::Stack" => ResourceType::AwsCloudFormationStack,
+            "AWS::ElasticLoadBalancing::LoadBalancer" => {
+                ResourceType::AwsElasticLoadBalancingLoadBalancer
+            }
+            "AWS::AutoScaling::AutoScalingGroup" => ResourceType::AwsAutoScalingAutoScalingGroup,
+            "AWS::AutoScaling::LaunchConfiguration" => {
+                ResourceType::AwsAutoScalingLaunchConfiguration
+            }
+            "AWS::AutoScaling::ScalingPolicy" => ResourceType::AwsAutoScalingScalingPolicy,
+            "AWS::AutoScaling::ScheduledAction" => ResourceType::AwsAutoScalingScheduledAction,
+            "AWS::DynamoDB::Table" => ResourceType::AwsDynamoDBTable,
+            "AWS::CodeBuild::Project" => ResourceType::AwsCodeBuildProject,
+            "AWS::WAF::RateBasedRule" => ResourceType::AwsWafRateBasedRule,
+            "AWS::WAF::Rule" => ResourceType::AwsWafRule,
+            "AWS::WAF::RuleGroup" => ResourceType::AwsWafRuleGroup,
+            "AWS::WAF::WebACL" => ResourceType::AwsWafWebACL,
+            "AWS::WAFRegional::RateBasedRule" => ResourceType::AwsWAFRegionalRateBasedRule,
+            "AWS::WAFRegional::Rule" => ResourceType::AwsWAFRegionalRule,
+            "AWS::WAFRegional::RuleGroup" => ResourceType::AwsWAFRegionalRuleGroup,
+            "AWS::WAFRegional::WebACL" => ResourceType::AwsWAFRegionalWebACL,
+            "AWS::CloudFront::Distribution" => ResourceType::AwsCloudFrontDistribution,
+            "AWS::CloudFront::StreamingDistribution" => {
+                ResourceType::AwsCloudFrontStreamingDistribution
+            }
+            "AWS::Lambda::Function" => ResourceType::AwsLambdaFunction,
+            "AWS::NetworkFirewall::Firewall" => ResourceType::AwsNetworkFirewallFirewall,
+            "AWS::NetworkFirewall::FirewallPolicy" => {
+                ResourceType::AwsNetworkFirewallFirewallPolicy
+            }
+            "AWS::NetworkFirewall::RuleGroup" => ResourceType::AwsNetworkFirewallRuleGroup,
+            "AWS::ElasticBeanstalk::Application" => ResourceType::AwsElasticBeanstalkApplication,
+            "AWS::ElasticBeanstalk::ApplicationVersion" => {
+                ResourceType::AwsElasticBeanstalkApplicationVersion
+            }
+            "AWS::ElasticBeanstalk::Environment" => ResourceType::AwsElasticBeanstalkEnvironment,
+            "AWS::WAFv2::WebACL" => ResourceType::AwsWAFv2WebACL,
+            "AWS::WAFv2::RuleGroup" => ResourceType::AwsWAFv2RuleGroup,
+            "AWS::WAFv2::IPSet" => ResourceType::AwsWAFv2IPSet,
+            "AWS::WAFv2::RegexPatternSet" => ResourceType::AwsWAFv2RegexPatternSet,
+            "AWS::WAFv2::ManagedRuleSet" => ResourceType::AwsWAFv2ManagedRuleSet,
+            "AWS::XRay::EncryptionConfig" => ResourceType::AwsXRayEncryptionConfig,
+            "AWS::SSM::AssociationCompliance" => ResourceType::AwsSsmAssociationCompliance,
+            "AWS::SSM::PatchCompliance" => ResourceType::AwsSsmPatchCompliance,
+            "AWS::Shield::Protection" => ResourceType::AwsShieldProtection,
+            "AWS::ShieldRegional::Protection" => ResourceType::AwsShieldRegionalProtection,
+            "AWS::Config::ConformancePackCompliance" => {
+                ResourceType::AwsConfigConformancePackCompliance
+            }
+            "AWS::Config::ResourceCompliance" => ResourceType::AwsConfigResourceCompliance,
+            "AWS::ApiGateway::Stage" => ResourceType::AwsApiGatewayStage,
+            "AWS::ApiGateway::RestApi" => ResourceType::AwsApiGatewayRestApi,
+            "AWS::ApiGatewayV2::Stage" => ResourceType::AwsApiGatewayV2Stage,
+            "AWS::ApiGatewayV2::Api" => ResourceType::AwsApiGatewayV2Api,
+            "AWS::CodePipeline::Pipeline" => ResourceType::AwsCodePipelinePipeline,
+            "AWS::ServiceCatalog::CloudFormationProvisionedProduct" => {
+                ResourceType::AwsServiceCatalogCloudFormationProvisionedProduct
+            }
+            "AWS::ServiceCatalog::CloudFormationProduct" => {
+                ResourceType::AwsServiceCatalogCloudFormationProduct
+            }
+            "AWS::ServiceCatalog::Portfolio" => ResourceType::AwsServiceCatalogPortfolio,
+            "AWS::SQS::Queue" => ResourceType::AwsSqsQueue,
+            "AWS::KMS::Key" => ResourceType::AwsKmsKey,
+            "AWS::QLDB::Ledger" => ResourceType::AwsQldbLedger,
+            "AWS::SecretsManager::Secret" => ResourceType::AwsSecretsManagerSecret,
+            "AWS::SNS::Topic" => ResourceType::AwsSnsTopic,
+            "AWS::SSM::FileData" => ResourceType::AwsSsmFileData,
+            "AWS::Backup::BackupPlan" => ResourceType::AwsBackupBackupPlan,
+            "AWS::Backup::BackupSelection" => ResourceType::AwsBackupBackupSelection,
+            "AWS::Backup::BackupVault" => ResourceType::AwsBackupBackupVault,
+            "AWS::Backup::RecoveryPoint" => ResourceType::AwsBackupRecoveryPoint,
+            "AWS::ECR::Repository" => ResourceType::AwsEcrRepository,
+            "AWS::ECS::Cluster" => ResourceType::AwsEcsCluster,
+            "AWS::ECS::Service" => ResourceType::AwsEcsService,
+            "AWS::ECS::TaskDefinition" => ResourceType::AwsEcsTaskDefinition,
+            "AWS::EFS::AccessPoint" => ResourceType::AwsEfsAccessPoint,
+            "AWS::EFS::FileSystem" => ResourceType::AwsEfsFileSystem,
+            "AWS::EKS::Cluster" => ResourceType::AwsEksCluster,
+            "AWS::OpenSearch::Domain" => ResourceType::AwsOpenSearchDomain,
+            "AWS::EC2::TransitGateway" => ResourceType::AwsEc2TransitGateway,
+            "AWS::Kinesis::Stream" => ResourceType::AwsKinesisStream,
+            "AWS::Kinesis::StreamConsumer" => ResourceType::AwsKinesisStreamConsumer,
+            "AWS::CodeDeploy::Application" => ResourceType::AwsCodeDeployApplication,
+            "AWS::CodeDeploy::DeploymentConfig" => ResourceType::AwsCodeDeployDeploymentConfig,
+            "AWS::CodeDeploy::DeploymentGroup" => ResourceType::AwsCodeDeployDeploymentGroup,
+            "AWS::EC2::LaunchTemplate" => ResourceType::AwsEc2LaunchTemplate,
+            "AWS::ECR::PublicRepository" => ResourceType::AwsEcrPublicRepository,
+            "AWS::GuardDuty::Detector" => ResourceType::AwsGuardDutyDetector,
+            "AWS::EMR::SecurityConfiguration" => ResourceType::AwsEmrSecurityConfiguration,
+            "AWS::SageMaker::CodeRepository" => ResourceType::AwsSageMakerCodeRepository,
+            "AWS::Route53Resolver::ResolverEndpoint" => {
+                ResourceType::AwsRoute53ResolverResolverEndpoint
+            }
+            "AWS::Route53Resolver::ResolverRule" => ResourceType::AwsRoute53ResolverResolverRule,
+            "AWS::Route53Resolver::ResolverRuleAssociation" => {
+                ResourceType::AwsRoute53ResolverResolverRuleAssociation
+            }
+            "AWS::DMS::ReplicationSubnetGroup" => ResourceType::AwsDmsReplicationSubnetGroup,
+            "AWS::DMS::EventSubscription" => ResourceType::AwsDmsEventSubscription,
+            "AWS::MSK::Cluster" => ResourceType::AwsMskCluster,
+            "AWS::StepFunctions::Activity" => ResourceType::AwsStepFunctionsActivity,
+            "AWS::WorkSpaces::Workspace" => ResourceType::AwsWorkSpacesWorkspace,
+            "AWS::WorkSpaces::ConnectionAlias" => ResourceType::AwsWorkSpacesConnectionAlias,
+            "AWS::SageMaker::Model" => ResourceType::AwsSageMakerModel,
+            "AWS::ElasticLoadBalancingV2::Listener" => {
+                ResourceType::AwsElasticLoadBalancingV2Listener
+            }
+            "AWS::StepFunctions::StateMachine" => ResourceType::AwsStepFunctionsStateMachine,
+            "AWS::Batch::JobQueue" => ResourceType::AwsBatchJobQueue,
+            "AWS::Batch::ComputeEnvironment" => ResourceType::AwsBatchComputeEnvironment,
+            "AWS::AccessAnalyzer::Analyzer" => ResourceType::AwsAccessAnalyzerAnalyzer,
+            "AWS::Athena::WorkGroup" => ResourceType::AwsAthenaWorkGroup,
+            "AWS::Athena::DataCatalog" => ResourceType::AwsAthenaDataCatalog,
+            "AWS::Detective::Graph" => ResourceType::AwsDetectiveGraph,
+            "AWS::GlobalAccelerator::Accelerator" => ResourceType::AwsGlobalAcceleratorAccelerator,
+            "AWS::GlobalAccelerator::EndpointGroup" => {
+                ResourceType::AwsGlobalAcceleratorEndpointGroup
+            }
+            "AWS::GlobalAccelerator::Listener" => ResourceType::AwsGlobalAcceleratorListener,
+            "AWS::EC2::TransitGatewayAttachment" => ResourceType::AwsEc2TransitGatewayAttachment,
+            "AWS::EC2::TransitGatewayRouteTable" => ResourceType::AwsEc2TransitGatewayRouteTable,
+            "AWS::DMS::Certificate" => ResourceType::AwsDmsCertificate,
+            "AWS::AppConfig::Application" => ResourceType::AwsAppConfigApplication,
+            "AWS::AppSync::GraphQLApi" => ResourceType::AwsAppSyncGraphQLApi,
+            "AWS::DataSync::LocationSMB" => ResourceType::AwsDataSyncLocationSMB,
+            "AWS::DataSync::LocationFSxLustre" => ResourceType::AwsDataSyncLocationFSxLustre,
+            "AWS::DataSync::LocationS3" => ResourceType::AwsDataSyncLocationS3,
+            "AWS::DataSync::LocationEFS" => ResourceType::AwsDataSyncLocationEFS,
+            "AWS::DataSync::Task" => ResourceType::AwsDataSyncTask,
+            "AWS::DataSync::LocationNFS" => ResourceType::AwsDataSyncLocationNFS,
+            "AWS::EC2::NetworkInsightsAccessScopeAnalysis" => {
+                ResourceType::AwsEc2NetworkInsightsAccessScopeAnalysis
+            }
+            "AWS::EKS::FargateProfile" => ResourceType::AwsEksFargateProfile,
+            "AWS::Glue::Job" => ResourceType::AwsGlueJob,
+            "AWS::GuardDuty::ThreatIntelSet" => ResourceType::AwsGuardDutyThreatIntelSet,
+            "AWS::GuardDuty::IPSet" => ResourceType::AwsGuardDutyIPSet,
+            "AWS::SageMaker::Workteam" => ResourceType::AwsSageMakerWorkteam,
+            "AWS::SageMaker::NotebookInstanceLifecycleConfig" => {
+                ResourceType::AwsSageMakerNotebookInstanceLifecycleConfig
+            }
+            "AWS::ServiceDiscovery::Service" => ResourceType::AwsServiceDiscoveryService,
+            "AWS::ServiceDiscovery::PublicDnsNamespace" => {
+                ResourceType::AwsServiceDiscoveryPublicDnsNamespace
+            }
+            "AWS::SES::ContactList" => ResourceType::AwsSesContactList,
+            "AWS::SES::ConfigurationSet" => ResourceType::AwsSesConfigurationSet,
+            "AWS::Route53::HostedZone" => ResourceType::AwsRoute53HostedZone,
+            "AWS::IoTEvents::Input" => ResourceType::AwsIoTEventsInput,
+            "AWS::IoTEvents::DetectorModel" => ResourceType::AwsIoTEventsDetectorModel,
+            "AWS::IoTEvents::AlarmModel" => ResourceType::AwsIoTEventsAlarmModel,
+            "AWS::ServiceDiscovery::HttpNamespace" => {
+                ResourceType::AwsServiceDiscoveryHttpNamespace
+            }
+            "AWS::Events::EventBus" => ResourceType::AwsEventsEventBus,
+            "AWS::ImageBuilder::ContainerRecipe" => ResourceType::AwsImageBuilderContainerRecipe,
+            "AWS::ImageBuilder::DistributionConfiguration" => {
+                ResourceType::AwsImageBuilderDistributionConfiguration
+            }
+            "AWS::ImageBuilder::InfrastructureConfiguration" => {
+                ResourceType::AwsImageBuilderInfrastructureConfiguration
+            }
+            "AWS::DataSync::LocationObjectStorage" => {
+                ResourceType::AwsDataSyncLocationObjectStorage
+            }
+            "AWS::DataSync::LocationHDFS" => ResourceType::AwsDataSyncLocationHDFS,
+            "AWS::Glue::Classifier" => ResourceType::AwsGlueClassifier,
+            "AWS::Route53RecoveryReadiness::Cell" => ResourceType::AwsRoute53RecoveryReadinessCell,
+            "AWS::Route53RecoveryReadiness::ReadinessCheck" => {
+                ResourceType::AwsRoute53RecoveryReadinessReadinessCheck
+            }
+            "AWS::ECR::RegistryPolicy" => ResourceType::AwsEcrRegistryPolicy,
+            "AWS::Backup::ReportPlan" => ResourceType::AwsBackupReportPlan,
+            "AWS::Lightsail::Certificate" => ResourceType::AwsLightsailCertificate,
+            "AWS::RUM::AppMonitor" => ResourceType::AwsRumAppMonitor,
+            "AWS::Events::Endpoint" => ResourceType::AwsEventsEndpoint,
+            "AWS::SES::ReceiptRuleSet" => ResourceType::AwsSesReceiptRuleSet,
+            "AWS::Events::Archive" => ResourceType::AwsEventsArchive,
+            "AWS::Events::ApiDestination" => ResourceType::AwsEventsApiDestination,
+            "AWS::Lightsail::Disk" => ResourceType::AwsLightsailDisk,
+            "AWS::FIS::ExperimentTemplate" => ResourceType::AwsFisExperimentTemplate,
+            "AWS::DataSync::LocationFSxWindows" => ResourceType::AwsDataSyncLocationFSxWindows,
+            "AWS::SES::ReceiptFilter" => ResourceType::AwsSesReceiptFilter,
+            "AWS::GuardDuty::Filter" => ResourceType::AwsGuardDutyFilter,
+            "AWS::SES::Template" => ResourceType::AwsSesTemplate,
+            "AWS::AmazonMQ::Broker" => ResourceType::AwsAmazonMQBroker,
+            "AWS::AppConfig::Environment" => ResourceType::AwsAppConfigEnvironment,
+            "AWS::AppConfig::ConfigurationProfile" => {
+                ResourceType::AwsAppConfigConfigurationProfile
+            }
+            "AWS::Cloud9::EnvironmentEC2" => ResourceType::AwsCloud9EnvironmentEC2,
+            "AWS::EventSchemas::Registry" => ResourceType::AwsEventSchemasRegistry,
+            "AWS::EventSchemas::RegistryPolicy" => ResourceType::AwsEventSchemasRegistryPolicy,
+            "AWS::EventSchemas::Discoverer" => ResourceType::AwsEventSchemasDiscoverer,
+            "AWS::FraudDetector::Label" => ResourceType::AwsFraudDetectorLabel,
+            "AWS::FraudDetector::EntityType" => ResourceType::AwsFraudDetectorEntityType,
+            "AWS::FraudDetector::Variable" => ResourceType::AwsFraudDetectorVariable,
+            "AWS::FraudDetector::Outcome" => ResourceType::AwsFraudDetectorOutcome,
+            "AWS::IoT::Authorizer" => ResourceType::AwsIoTAuthorizer,
+            "AWS::IoT::SecurityProfile" => ResourceType::AwsIoTSecurityProfile,
+            "AWS::IoT::RoleAlias" => ResourceType::AwsIoTRoleAlias,
+            "AWS::IoT::Dimension" => ResourceType::AwsIoTDimension,
+            "AWS::IoTAnalytics::Datastore" => ResourceType::AwsIoTAnalyticsDatastore,
+            "AWS::Lightsail::Bucket" => ResourceType::AwsLightsailBucket,
+            "AWS::Lightsail::StaticIp" => ResourceType::AwsLightsailStaticIp,
+            "AWS::MediaPackage::PackagingGroup" => ResourceType::AwsMediaPackagePackagingGroup,
+            "AWS::Route53RecoveryReadiness::RecoveryGroup" => {
+                ResourceType::AwsRoute53RecoveryReadinessRecoveryGroup
+            }
+            "AWS::ResilienceHub::ResiliencyPolicy" => {
+                ResourceType::AwsResilienceHubResiliencyPolicy
+            }
+            "AWS::Transfer::Workflow" => ResourceType::AwsTransferWorkflow,
+            "AWS::EKS::IdentityProviderConfig" => ResourceType::AwsEksIdentityProviderConfig,
+            "AWS::EKS::Addon" => ResourceType::AwsEksAddon,
+            "AWS::Glue::MLTransform" => ResourceType::AwsGlueMLTransform,
+            "AWS::IoT::Policy" => ResourceType::AwsIoTPolicy,
+            "AWS::IoT::MitigationAction" => ResourceType::AwsIoTMitigationAction,
+            "AWS::IoTTwinMaker::Workspace" => ResourceType::AwsIoTTwinMakerWorkspace,
+            "AWS::IoTTwinMaker::Entity" => ResourceType::AwsIoTTwinMakerEntity,
+            "AWS::IoTAnalytics::Dataset" => ResourceType::AwsIoTAnalyticsDataset,
+            "AWS::IoTAnalytics::Pipeline" => ResourceType::AwsIoTAnalyticsPipeline,
+            "AWS::IoTAnalytics::Channel" => ResourceType::AwsIoTAnalyticsChannel,
+            "AWS::IoTSiteWise::Dashboard" => ResourceType::AwsIoTSiteWiseDashboard,
+            "AWS::IoTSiteWise::Project" => ResourceType::AwsIoTSiteWiseProject,
+            "AWS::IoTSiteWise::Portal" => ResourceType::AwsIoTSiteWisePortal,
+            "AWS::IoTSiteWise::AssetModel" => ResourceType::AwsIoTSiteWiseAssetModel,
+            "AWS::IVS::Channel" => ResourceType::AwsIvsChannel,
+            "AWS::IVS::RecordingConfiguration" => ResourceType::AwsIvsRecordingConfiguration,
+            "AWS::IVS::PlaybackKeyPair" => ResourceType::AwsIvsPlaybackKeyPair,
+            "AWS::KinesisAnalyticsV2::Application" => {
+                ResourceType::AwsKinesisAnalyticsV2Application
+            }
+            "AWS::RDS::GlobalCluster" => ResourceType::AwsRdsGlobalCluster,
+            "AWS::S3::MultiRegionAccessPoint" => ResourceType::AwsS3MultiRegionAccessPoint,
+            "AWS::DeviceFarm::TestGridProject" => ResourceType::AwsDeviceFarmTestGridProject,
+            "AWS::Budgets::BudgetsAction" => ResourceType::AwsBudgetsBudgetsAction,
+            "AWS::Lex::Bot" => ResourceType::AwsLexBot,
+            "AWS::CodeGuruReviewer::RepositoryAssociation" => {
+                ResourceType::AwsCodeGuruReviewerRepositoryAssociation
+            }
+            "AWS::IoT::CustomMetric" => ResourceType::AwsIoTCustomMetric,
+            "AWS::Route53Resolver::FirewallDomainList" => {
+                ResourceType::AwsRoute53ResolverFirewallDomainList
+            }
+            "AWS::RoboMaker::RobotApplicationVersion" => {
+                ResourceType::AwsRoboMakerRobotApplicationVersion
+            }
+            "AWS::EC2::TrafficMirrorSession" => ResourceType::AwsEc2TrafficMirrorSession,
+            "AWS::IoTSiteWise::Gateway" => ResourceType::AwsIoTSiteWiseGateway,
+            "AWS::Lex::BotAlias" => ResourceType::AwsLexBotAlias,
+            "AWS::LookoutMetrics::Alert" => ResourceType::AwsLookoutMetricsAlert,
+            "AWS::IoT::AccountAuditConfiguration" => ResourceType::AwsIoTAccountAuditConfiguration,
+            "AWS::EC2::TrafficMirrorTarget" => ResourceType::AwsEc2TrafficMirrorTarget,
+            "AWS::S3::StorageLens" => ResourceType::AwsS3StorageLens,
+            "AWS::IoT::ScheduledAudit" => ResourceType::AwsIoTScheduledAudit,
+            "AWS::Events::Connection" => ResourceType::AwsEventsConnection,
+            "AWS::EventSchemas::Schema" => ResourceType::AwsEventSchemasSchema,
+            "AWS::MediaPackage::PackagingConfiguration" => {
+                ResourceType::AwsMediaPackagePackagingConfiguration
+            }
+            "AWS::KinesisVideo::SignalingChannel" => ResourceType::AwsKinesisVideoSignalingChannel,
+            "AWS::AppStream::DirectoryConfig" => ResourceType::AwsAppStreamDirectoryConfig,
+            "AWS::LookoutVision::Project" => ResourceType::AwsLookoutVisionProject,
+            "AWS::Route53RecoveryControl::Cluster" => {
+                ResourceType::AwsRoute53RecoveryControlCluster
+            }
+            "AWS::Route53RecoveryControl::SafetyRule" => {
+                ResourceType::AwsRoute53RecoveryControlSafetyRule
+            }
+            "AWS::Route53RecoveryControl::ControlPanel" => {
+                ResourceType::AwsRoute53RecoveryControlControlPanel
+            }
+            "AWS::Route53RecoveryControl::RoutingControl" => {
+                ResourceType::AwsRoute53RecoveryControlRoutingControl
+            }
+            "AWS::Route53RecoveryReadiness::ResourceSet" => {
+                ResourceType::AwsRoute53RecoveryReadinessResourceSet
+            }
+            "AWS::RoboMaker::SimulationApplication" => {
+                ResourceType::AwsRoboMakerSimulationApplication
+            }
+            "AWS::RoboMaker::RobotApplication" => ResourceType::AwsRoboMakerRobotApplication,
+            "AWS::HealthLake::FHIRDatastore" => ResourceType::AwsHealthLakeFHIRDatastore,
+            "AWS::Pinpoint::Segment" => ResourceType::AwsPinpointSegment,
+            "AWS::Pinpoint::ApplicationSettings" => ResourceType::AwsPinpointApplicationSettings,
+            "AWS::Events::Rule" => ResourceType::AwsEventsRule,
+            "AWS::EC2::DHCPOptions" => ResourceType::AwsEc2DHCPOptions,
+            "AWS::EC2::NetworkInsightsPath" => ResourceType::AwsEc2NetworkInsightsPath,
+            "AWS::EC2::TrafficMirrorFilter" => ResourceType::AwsEc2TrafficMirrorFilter,
+            "AWS::EC2::IPAM" => ResourceType::AwsEc2Ipam,
+            "AWS::IoTTwinMaker::Scene" => ResourceType::AwsIoTTwinMakerScene,
+            "AWS::NetworkManager::TransitGatewayRegistration" => {
+                ResourceType::AwsNetworkManagerTransitGatewayRegistration
+            }
+            "AWS::CustomerProfiles::Domain" => ResourceType::AwsCustomerProfilesDomain,
+            "AWS::AutoScaling::WarmPool" => ResourceType::AwsAutoScalingWarmPool,
+            "AWS::Connect::PhoneNumber" => ResourceType::AwsConnectPhoneNumber,
+            "AWS::AppConfig::DeploymentStrategy" => ResourceType::AwsAppConfigDeploymentStrategy,
+            "AWS::AppFlow::Flow" => ResourceType::AwsAppFlowFlow,
+            "AWS::AuditManager::Assessment" => ResourceType::AwsAuditManagerAssessment,
+            "AWS::CloudWatch::MetricStream" => ResourceType::AwsCloudWatchMetricStream,
+            "AWS::DeviceFarm::InstanceProfile" => ResourceType::AwsDeviceFarmInstanceProfile,
+            "AWS::DeviceFarm::Project" => ResourceType::AwsDeviceFarmProject,
+            "AWS::EC2::EC2Fleet" => ResourceType::AwsEc2EC2Fleet,
+            "AWS::EC2::SubnetRouteTableAssociation" => {
+                ResourceType::AwsEc2SubnetRouteTableAssociation
+            }
+            "AWS::ECR::PullThroughCacheRule" => ResourceType::AwsEcrPullThroughCacheRule,
+            "AWS::GroundStation::Config" => ResourceType::AwsGroundStationConfig,
+            "AWS::ImageBuilder::ImagePipeline" => ResourceType::AwsImageBuilderImagePipeline,
+            "AWS::IoT::FleetMetric" => ResourceType::AwsIoTFleetMetric,
+            "AWS::IoTWireless::ServiceProfile" => ResourceType::AwsIoTWirelessServiceProfile,
+            "AWS::NetworkManager::Device" => ResourceType::AwsNetworkManagerDevice,
+            "AWS::NetworkManager::GlobalNetwork" => ResourceType::AwsNetworkManagerGlobalNetwork,
+            "AWS::NetworkManager::Link" => ResourceType::AwsNetworkManagerLink,
+            "AWS::NetworkManager::Site" => ResourceType::AwsNetworkManagerSite,
+            "AWS::Panorama::Package" => ResourceType::AwsPanoramaPackage,
+            "AWS::Pinpoint::App" => ResourceType::AwsPinpointApp,
+            "AWS::Redshift::ScheduledAction" => ResourceType::AwsRedshiftScheduledAction,
+            "AWS::Route53Resolver::FirewallRuleGroupAssociation" => {
+                ResourceType::AwsRoute53ResolverFirewallRuleGroupAssociation
+            }
+            "AWS::SageMaker::AppImageConfig" => ResourceType::AwsSageMakerAppImageConfig,
+            "AWS::SageMaker::Image" => ResourceType::AwsSageMakerImage,
+            "AWS::ECS::TaskSet" => ResourceType::AwsEcsTaskSet,
+            "AWS::Cassandra::Keyspace" => ResourceType::AwsCassandraKeyspace,
+            "AWS::Signer::SigningProfile" => ResourceType::AwsSignerSigningProfile,
+            "AWS::Amplify::App" => ResourceType::AwsAmplifyApp,
+            "AWS::AppMesh::VirtualNode" => ResourceType::AwsAppMeshVirtualNode,
+            "AWS::AppMesh::VirtualService" => ResourceType::AwsAppMeshVirtualService,
+            "AWS::AppRunner::VpcConnector" => ResourceType::AwsAppRunnerVpcConnector,
+            "AWS::AppStream::Application" => ResourceType::AwsAppStreamApplication,
+            "AWS::CodeArtifact::Repository" => ResourceType::AwsCodeArtifactRepository,
+            "AWS::EC2::PrefixList" => ResourceType::AwsEc2PrefixList,
+            "AWS::EC2::SpotFleet" => ResourceType::AwsEc2SpotFleet,
+            "AWS::Evidently::Project" => ResourceType::AwsEvidentlyProject,
+            "AWS::Forecast::Dataset" => ResourceType::AwsForecastDataset,
+            "AWS::IAM::SAMLProvider" => ResourceType::AwsIamSAMLProvider,
+            "AWS::IAM::ServerCertificate" => ResourceType::AwsIamServerCertificate,
+            "AWS::Pinpoint::Campaign" => ResourceType::AwsPinpointCampaign,
+            "AWS::Pinpoint::InAppTemplate" => ResourceType::AwsPinpointInAppTemplate,
+            "AWS::SageMaker::Domain" => ResourceType::AwsSageMakerDomain,
+            "AWS::Transfer::Agreement" => ResourceType::AwsTransferAgreement,
+            "AWS::Transfer::Connector" => ResourceType::AwsTransferConnector,
+            "AWS::KinesisFirehose::DeliveryStream" => {
+                ResourceType::AwsKinesisFirehoseDeliveryStream
+            }
+            "AWS::Amplify::Branch" => ResourceType::AwsAmplifyBranch,
+            "AWS::AppIntegrations::EventIntegration" => {
+                ResourceType::AwsAppIntegrationsEventIntegration
+            }
+            "AWS::AppMesh::Route" => ResourceType::AwsAppMeshRoute,
+            "AWS::Athena::PreparedStatement" => ResourceType::AwsAthenaPreparedStatement,
+            "AWS::EC2::IPAMScope" => ResourceType::AwsEc2IPAMScope,
+            "AWS::Evidently::Launch" => ResourceType::AwsEvidentlyLaunch,
+            "AWS::Forecast::DatasetGroup" => ResourceType::AwsForecastDatasetGroup,
+            "AWS::GreengrassV2::ComponentVersion" => ResourceType::AwsGreengrassV2ComponentVersion,
+            "AWS::GroundStation::MissionProfile" => ResourceType::AwsGroundStationMissionProfile,
+            "AWS::MediaConnect::FlowEntitlement" => ResourceType::AwsMediaConnectFlowEntitlement,
+            "AWS::MediaConnect::FlowVpcInterface" => ResourceType::AwsMediaConnectFlowVpcInterface,
+            "AWS::MediaTailor::PlaybackConfiguration" => {
+                ResourceType::AwsMediaTailorPlaybackConfiguration
+            }
+            "AWS::MSK::Configuration" => ResourceType::AwsMskConfiguration,
+            "AWS::Personalize::Dataset" => ResourceType::AwsPersonalizeDataset,
+            "AWS::Personalize::Schema" => ResourceType::AwsPersonalizeSchema,
+            "AWS::Personalize::Solution" => ResourceType::AwsPersonalizeSolution,
+            "AWS::Pinpoint::EmailTemplate" => ResourceType::AwsPinpointEmailTemplate,
+            "AWS::Pinpoint::EventStream" => ResourceType::AwsPinpointEventStream,
+            "AWS::ResilienceHub::App" => ResourceType::AwsResilienceHubApp,
+            "AWS::ACMPCA::CertificateAuthority" => ResourceType::AwsAcmpcaCertificateAuthority,
+            "AWS::AppConfig::HostedConfigurationVersion" => {
+                ResourceType::AwsAppConfigHostedConfigurationVersion
+            }
+            "AWS::AppMesh::VirtualGateway" => ResourceType::AwsAppMeshVirtualGateway,
+            "AWS::AppMesh::VirtualRouter" => ResourceType::AwsAppMeshVirtualRouter,
+            "AWS::AppRunner::Service" => ResourceType::AwsAppRunnerService,
+            "AWS::CustomerProfiles::ObjectType" => ResourceType::AwsCustomerProfilesObjectType,
+            "AWS::DMS::Endpoint" => ResourceType::AwsDmsEndpoint,
+            "AWS::EC2::CapacityReservation" => ResourceType::AwsEc2CapacityReservation,
+            "AWS::EC2::ClientVpnEndpoint" => ResourceType::AwsEc2ClientVpnEndpoint,
+            "AWS::Kendra::Index" => ResourceType::AwsKendraIndex,
+            "AWS::KinesisVideo::Stream" => ResourceType::AwsKinesisVideoStream,
+            "AWS::Logs::Destination" => ResourceType::AwsLogsDestination,
+            "AWS::Pinpoint::EmailChannel" => ResourceType::AwsPinpointEmailChannel,
+            "AWS::S3::AccessPoint" => ResourceType::AwsS3AccessPoint,
+            "AWS::NetworkManager::CustomerGatewayAssociation" => {
+                ResourceType::AwsNetworkManagerCustomerGatewayAssociation
+            }
+            "AWS::NetworkManager::LinkAssociation" => {
+                ResourceType::AwsNetworkManagerLinkAssociation
+            }
+            "AWS::IoTWireless::MulticastGroup" => ResourceType::AwsIoTWirelessMulticastGroup,
+            "AWS::Personalize::DatasetGroup" => ResourceType::AwsPersonalizeDatasetGroup,
+            "AWS::IoTTwinMaker::ComponentType" => ResourceType::AwsIoTTwinMakerComponentType,
+            "AWS::CodeBuild::ReportGroup" => ResourceType::AwsCodeBuildReportGroup,
+            "AWS::SageMaker::FeatureGroup" => ResourceType::AwsSageMakerFeatureGroup,
+            "AWS::MSK::BatchScramSecret" => ResourceType::AwsMskBatchScramSecret,
+            "AWS::AppStream::Stack" => ResourceType::AwsAppStreamStack,
+            "AWS::IoT::JobTemplate" => ResourceType::AwsIoTJobTemplate,
+            "AWS::IoTWireless::FuotaTask" => ResourceType::AwsIoTWirelessFuotaTask,
+            "AWS::IoT::ProvisioningTemplate" => ResourceType::AwsIoTProvisioningTemplate,
+            "AWS::InspectorV2::Filter" => ResourceType::AwsInspectorV2Filter,
+            "AWS::Route53Resolver::ResolverQueryLoggingConfigAssociation" => {
+                ResourceType::AwsRoute53ResolverResolverQueryLoggingConfigAssociation
+            }
+            "AWS::ServiceDiscovery::Instance" => ResourceType::AwsServiceDiscoveryInstance,
+            "AWS::Transfer::Certificate" => ResourceType::AwsTransferCertificate,
+            "AWS::MediaConnect::FlowSource" => ResourceType::AwsMediaConnectFlowSource,
+            "AWS::APS::RuleGroupsNamespace" => ResourceType::AwsApsRuleGroupsNamespace,
+            "AWS::CodeGuruProfiler::ProfilingGroup" => {
+                ResourceType::AwsCodeGuruProfilerProfilingGroup
+            }
+            "AWS::Route53Resolver::ResolverQueryLoggingConfig" => {
+                ResourceType::AwsRoute53ResolverResolverQueryLoggingConfig
+            }
+            "AWS::Batch::SchedulingPolicy" => ResourceType::AwsBatchSchedulingPolicy,
+            "AWS::ACMPCA::CertificateAuthorityActivation" => {
+                ResourceType::AwsAcmpcaCertificateAuthorityActivation
+            }
+            "AWS::AppMesh::GatewayRoute" => ResourceType::AwsAppMeshGatewayRoute,
+            "AWS::AppMesh::Mesh" => ResourceType::AwsAppMeshMesh,
+            "AWS::Connect::Instance" => ResourceType::AwsConnectInstance,
+            "AWS::Connect::QuickConnect" => ResourceType::AwsConnectQuickConnect,
+            "AWS::EC2::CarrierGateway" => ResourceType::AwsEc2CarrierGateway,
+            "AWS::EC2::IPAMPool" => ResourceType::AwsEc2IPAMPool,
+            "AWS::EC2::TransitGatewayConnect" => ResourceType::AwsEc2TransitGatewayConnect,
+            "AWS::EC2::TransitGatewayMulticastDomain" => {
+                ResourceType::AwsEc2TransitGatewayMulticastDomain
+            }
+            "AWS::ECS::CapacityProvider" => ResourceType::AwsEcsCapacityProvider,
+            "AWS::IAM::InstanceProfile" => ResourceType::AwsIamInstanceProfile,
+            "AWS::IoT::CACertificate" => ResourceType::AwsIoTCACertificate,
+            "AWS::IoTTwinMaker::SyncJob" => ResourceType::AwsIoTTwinMakerSyncJob,
+            "AWS::KafkaConnect::Connector" => ResourceType::AwsKafkaConnectConnector,
+            "AWS::Lambda::CodeSigningConfig" => ResourceType::AwsLambdaCodeSigningConfig,
+            "AWS::NetworkManager::ConnectPeer" => ResourceType::AwsNetworkManagerConnectPeer,
+            "AWS::ResourceExplorer2::Index" => ResourceType::AwsResourceExplorer2Index,
+            "AWS::AppStream::Fleet" => ResourceType::AwsAppStreamFleet,
+            "AWS::Cognito::UserPool" => ResourceType::AwsCognitoUserPool,
+            "AWS::Cognito::UserPoolClient" => ResourceType::AwsCognitoUserPoolClient,
+            "AWS::Cognito::UserPoolGroup" => ResourceType::AwsCognitoUserPoolGroup,
+            "AWS::EC2::NetworkInsightsAccessScope" => {
+                ResourceType::AwsEc2NetworkInsightsAccessScope
+            }
+            "AWS::EC2::NetworkInsightsAnalysis" => ResourceType::AwsEc2NetworkInsightsAnalysis,
+            "AWS::Grafana::Workspace" => ResourceType::AwsGrafanaWorkspace,
+            "AWS::GroundStation::DataflowEndpointGroup" => {
+                ResourceType::AwsGroundStationDataflowEndpointGroup
+            }
+            "AWS::ImageBuilder::ImageRecipe" => ResourceType::AwsImageBuilderImageRecipe,
+            "AWS::KMS::Alias" => ResourceType::AwsKmsAlias,
+            "AWS::M2::Environment" => ResourceType::AwsM2Environment,
+            "AWS::QuickSight::DataSource" => ResourceType::AwsQuickSightDataSource,
+            "AWS::QuickSight::Template" => ResourceType::AwsQuickSightTemplate,
+            "AWS::QuickSight::Theme" => ResourceType::AwsQuickSightTheme,
+            "AWS::RDS::OptionGroup" => ResourceType::AwsRdsOptionGroup,
+            "AWS::Redshift::EndpointAccess" => ResourceType::AwsRedshiftEndpointAccess,
+            "AWS::Route53Resolver::FirewallRuleGroup" => {
+                ResourceType::AwsRoute53ResolverFirewallRuleGroup
+            }
+            "AWS::SSM::Document" => ResourceType::AwsSsmDocument,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        ResourceType::from(value.as_str())
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(value: ResourceType) -> String {
+        match value {
+            ResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceType::from(value))
+    }
 }
 
 /// <p>The dynamic value of the resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceValue {
     /// <p>The value is a resource ID.</p>
     #[serde(rename = "Value")]
-    pub value: String,
+    pub value: ResourceValueType,
+}
+
+/// <p>The value is a resource ID.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceValueType {
+    /// <p><code>RESOURCE_ID</code></p>
+    ResourceId,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceValueType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceValueType::ResourceId => "RESOURCE_ID",
+            ResourceValueType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceValueType {
+    fn from(value: &'a str) -> ResourceValueType {
+        match value {
+            "RESOURCE_ID" => ResourceValueType::ResourceId,
+            _ => ResourceValueType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceValueType {
+    fn from(value: String) -> ResourceValueType {
+        ResourceValueType::from(value.as_str())
+    }
+}
+
+impl From<ResourceValueType> for String {
+    fn from(value: ResourceValueType) -> String {
+        match value {
+            ResourceValueType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ResourceValueType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceValueType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceValueType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceValueType::from(value))
+    }
 }
 
 /// <p>An object with the name of the retention configuration and the retention period in days. The object stores the configuration for data retention in AWS Config.</p>
@@ -2747,6 +8945,54 @@ pub struct SelectResourceConfigRequest {
     pub next_token: Option<String>,
 }
 
+impl SelectResourceConfigRequest {
+    /// Returns a builder for `SelectResourceConfigRequest`, checking its required members when built.
+    pub fn builder() -> SelectResourceConfigRequestBuilder {
+        SelectResourceConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SelectResourceConfigRequest`](struct.SelectResourceConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct SelectResourceConfigRequestBuilder {
+    expression: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl SelectResourceConfigRequestBuilder {
+    /// Sets the [`expression`](struct.SelectResourceConfigRequest.html#structfield.expression) member.
+    pub fn expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.SelectResourceConfigRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SelectResourceConfigRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `SelectResourceConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SelectResourceConfigRequest, ::rusoto_core::BuildError> {
+        Ok(SelectResourceConfigRequest {
+            expression: self
+                .expression
+                .ok_or(::rusoto_core::BuildError::missing("expression"))?,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SelectResourceConfigResponse {
@@ -2765,11 +9011,11 @@ pub struct SelectResourceConfigResponse {
 }
 
 /// <p>Provides the AWS Config rule owner (AWS or customer), the rule identifier, and the events that trigger the evaluation of your AWS resources.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Source {
     /// <p>Indicates whether AWS or the customer owns and manages the AWS Config rule.</p>
     #[serde(rename = "Owner")]
-    pub owner: String,
+    pub owner: Owner,
     /// <p>Provides the source and type of the event that causes AWS Config to evaluate your AWS resources.</p>
     #[serde(rename = "SourceDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2785,15 +9031,15 @@ pub struct SourceDetail {
     /// <p>The source of the event, such as an AWS service, that triggers AWS Config to evaluate your AWS resources.</p>
     #[serde(rename = "EventSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_source: Option<String>,
+    pub event_source: Option<EventSource>,
     /// <p><p>The frequency at which you want AWS Config to run evaluations for a custom rule with a periodic trigger. If you specify a value for <code>MaximumExecutionFrequency</code>, then <code>MessageType</code> must use the <code>ScheduledNotification</code> value.</p> <note> <p>By default, rules with a periodic trigger are evaluated every 24 hours. To change the frequency, specify a valid value for the <code>MaximumExecutionFrequency</code> parameter.</p> <p>Based on the valid value you choose, AWS Config runs evaluations once for each valid value. For example, if you choose <code>Three<em>Hours</code>, AWS Config runs evaluations once every three hours. In this case, <code>Three</em>Hours</code> is the frequency of this rule. </p> </note></p>
     #[serde(rename = "MaximumExecutionFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maximum_execution_frequency: Option<String>,
+    pub maximum_execution_frequency: Option<MaximumExecutionFrequency>,
     /// <p>The type of notification that triggers AWS Config to run an evaluation for a rule. You can specify the following notification types:</p> <ul> <li> <p> <code>ConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers a configuration item as a result of a resource change.</p> </li> <li> <p> <code>OversizedConfigurationItemChangeNotification</code> - Triggers an evaluation when AWS Config delivers an oversized configuration item. AWS Config may generate this notification type when a resource changes and the notification exceeds the maximum size allowed by Amazon SNS.</p> </li> <li> <p> <code>ScheduledNotification</code> - Triggers a periodic evaluation at the frequency specified for <code>MaximumExecutionFrequency</code>.</p> </li> <li> <p> <code>ConfigurationSnapshotDeliveryCompleted</code> - Triggers a periodic evaluation when AWS Config delivers a configuration snapshot.</p> </li> </ul> <p>If you want your custom rule to be triggered by configuration changes, specify two SourceDetail objects, one for <code>ConfigurationItemChangeNotification</code> and one for <code>OversizedConfigurationItemChangeNotification</code>.</p>
     #[serde(rename = "MessageType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_type: Option<String>,
+    pub message_type: Option<MessageType>,
 }
 
 /// <p>AWS Systems Manager (SSM) specific remediation controls.</p>
@@ -2818,6 +9064,36 @@ pub struct StartConfigRulesEvaluationRequest {
     pub config_rule_names: Option<Vec<String>>,
 }
 
+impl StartConfigRulesEvaluationRequest {
+    /// Returns a builder for `StartConfigRulesEvaluationRequest`, checking its required members when built.
+    pub fn builder() -> StartConfigRulesEvaluationRequestBuilder {
+        StartConfigRulesEvaluationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartConfigRulesEvaluationRequest`](struct.StartConfigRulesEvaluationRequest.html).
+#[derive(Default, Debug)]
+pub struct StartConfigRulesEvaluationRequestBuilder {
+    config_rule_names: Option<Vec<String>>,
+}
+
+impl StartConfigRulesEvaluationRequestBuilder {
+    /// Sets the [`config_rule_names`](struct.StartConfigRulesEvaluationRequest.html#structfield.config_rule_names) member.
+    pub fn config_rule_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.config_rule_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartConfigRulesEvaluationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartConfigRulesEvaluationRequest, ::rusoto_core::BuildError> {
+        Ok(StartConfigRulesEvaluationRequest {
+            config_rule_names: self.config_rule_names,
+        })
+    }
+}
+
 /// <p>The output when you start the evaluation for the specified AWS Config rule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2831,6 +9107,38 @@ pub struct StartConfigurationRecorderRequest {
     pub configuration_recorder_name: String,
 }
 
+impl StartConfigurationRecorderRequest {
+    /// Returns a builder for `StartConfigurationRecorderRequest`, checking its required members when built.
+    pub fn builder() -> StartConfigurationRecorderRequestBuilder {
+        StartConfigurationRecorderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartConfigurationRecorderRequest`](struct.StartConfigurationRecorderRequest.html).
+#[derive(Default, Debug)]
+pub struct StartConfigurationRecorderRequestBuilder {
+    configuration_recorder_name: Option<String>,
+}
+
+impl StartConfigurationRecorderRequestBuilder {
+    /// Sets the [`configuration_recorder_name`](struct.StartConfigurationRecorderRequest.html#structfield.configuration_recorder_name) member.
+    pub fn configuration_recorder_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_recorder_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartConfigurationRecorderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartConfigurationRecorderRequest, ::rusoto_core::BuildError> {
+        Ok(StartConfigurationRecorderRequest {
+            configuration_recorder_name: self.configuration_recorder_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_recorder_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartRemediationExecutionRequest {
     /// <p>The list of names of AWS Config rules that you want to run remediation execution for.</p>
@@ -2841,6 +9149,48 @@ pub struct StartRemediationExecutionRequest {
     pub resource_keys: Vec<ResourceKey>,
 }
 
+impl StartRemediationExecutionRequest {
+    /// Returns a builder for `StartRemediationExecutionRequest`, checking its required members when built.
+    pub fn builder() -> StartRemediationExecutionRequestBuilder {
+        StartRemediationExecutionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartRemediationExecutionRequest`](struct.StartRemediationExecutionRequest.html).
+#[derive(Default, Debug)]
+pub struct StartRemediationExecutionRequestBuilder {
+    config_rule_name: Option<String>,
+    resource_keys: Option<Vec<ResourceKey>>,
+}
+
+impl StartRemediationExecutionRequestBuilder {
+    /// Sets the [`config_rule_name`](struct.StartRemediationExecutionRequest.html#structfield.config_rule_name) member.
+    pub fn config_rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.config_rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_keys`](struct.StartRemediationExecutionRequest.html#structfield.resource_keys) member.
+    pub fn resource_keys<T: Into<Vec<ResourceKey>>>(mut self, value: T) -> Self {
+        self.resource_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartRemediationExecutionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartRemediationExecutionRequest, ::rusoto_core::BuildError> {
+        Ok(StartRemediationExecutionRequest {
+            config_rule_name: self
+                .config_rule_name
+                .ok_or(::rusoto_core::BuildError::missing("config_rule_name"))?,
+            resource_keys: self
+                .resource_keys
+                .ok_or(::rusoto_core::BuildError::missing("resource_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartRemediationExecutionResponse {
@@ -2872,7 +9222,7 @@ pub struct StatusDetailFilters {
     /// <p><p>Indicates deployment status for config rule in the member account. When master account calls <code>PutOrganizationConfigRule</code> action for the first time, config rule status is created in the member account. When master account calls <code>PutOrganizationConfigRule</code> action for the second time, config rule status is updated in the member account. Config rule status is deleted when the master account deletes <code>OrganizationConfigRule</code> and disables service access for <code>config-multiaccountsetup.amazonaws.com</code>. </p> <p>AWS Config sets the state of the rule to:</p> <ul> <li> <p> <code>CREATE<em>SUCCESSFUL</code> when config rule has been created in the member account.</p> </li> <li> <p> <code>CREATE</em>IN<em>PROGRESS</code> when config rule is being created in the member account.</p> </li> <li> <p> <code>CREATE</em>FAILED</code> when config rule creation has failed in the member account.</p> </li> <li> <p> <code>DELETE<em>FAILED</code> when config rule deletion has failed in the member account.</p> </li> <li> <p> <code>DELETE</em>IN<em>PROGRESS</code> when config rule is being deleted in the member account.</p> </li> <li> <p> <code>DELETE</em>SUCCESSFUL</code> when config rule has been deleted in the member account.</p> </li> <li> <p> <code>UPDATE<em>SUCCESSFUL</code> when config rule has been updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>IN<em>PROGRESS</code> when config rule is being updated in the member account.</p> </li> <li> <p> <code>UPDATE</em>FAILED</code> when config rule deletion has failed in the member account.</p> </li> </ul></p>
     #[serde(rename = "MemberAccountRuleStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub member_account_rule_status: Option<String>,
+    pub member_account_rule_status: Option<MemberAccountRuleStatus>,
 }
 
 /// <p>The input for the <a>StopConfigurationRecorder</a> action.</p>
@@ -2883,6 +9233,38 @@ pub struct StopConfigurationRecorderRequest {
     pub configuration_recorder_name: String,
 }
 
+impl StopConfigurationRecorderRequest {
+    /// Returns a builder for `StopConfigurationRecorderRequest`, checking its required members when built.
+    pub fn builder() -> StopConfigurationRecorderRequestBuilder {
+        StopConfigurationRecorderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopConfigurationRecorderRequest`](struct.StopConfigurationRecorderRequest.html).
+#[derive(Default, Debug)]
+pub struct StopConfigurationRecorderRequestBuilder {
+    configuration_recorder_name: Option<String>,
+}
+
+impl StopConfigurationRecorderRequestBuilder {
+    /// Sets the [`configuration_recorder_name`](struct.StopConfigurationRecorderRequest.html#structfield.configuration_recorder_name) member.
+    pub fn configuration_recorder_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_recorder_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopConfigurationRecorderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopConfigurationRecorderRequest, ::rusoto_core::BuildError> {
+        Ok(StopConfigurationRecorderRequest {
+            configuration_recorder_name: self.configuration_recorder_name.ok_or(
+                ::rusoto_core::BuildError::missing("configuration_recorder_name"),
+            )?,
+        })
+    }
+}
+
 /// <p>The tags for the resource. The metadata that you apply to a resource to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define. Tag keys can have a maximum character length of 128 characters, and tag values can have a maximum length of 256 characters.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -2906,6 +9288,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) that identifies the resource for which to list the tags. Currently, the supported resources are <code>ConfigRule</code>, <code>ConfigurationAggregator</code> and <code>AggregatorAuthorization</code>.</p>
@@ -2916,6 +9338,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// Errors returned by BatchGetAggregateResourceConfig
 #[derive(Debug, PartialEq)]
 pub enum BatchGetAggregateResourceConfigError {
@@ -6166,286 +12628,724 @@ pub trait ConfigService {
     ) -> RusotoFuture<
         DescribeConfigurationAggregatorSourcesStatusResponse,
         DescribeConfigurationAggregatorSourcesStatusError,
-    >;
-
-    /// <p>Returns the details of one or more configuration aggregators. If the configuration aggregator is not specified, this action returns the details for all the configuration aggregators associated with the account. </p>
+    >;
+
+    /// <p>Returns the details of one or more configuration aggregators. If the configuration aggregator is not specified, this action returns the details for all the configuration aggregators associated with the account. </p>
+    fn describe_configuration_aggregators(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> RusotoFuture<DescribeConfigurationAggregatorsResponse, DescribeConfigurationAggregatorsError>;
+
+    /// <p><p>Returns the current status of the specified configuration recorder. If a configuration recorder is not specified, this action returns the status of all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
+    fn describe_configuration_recorder_status(
+        &self,
+        input: DescribeConfigurationRecorderStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigurationRecorderStatusResponse,
+        DescribeConfigurationRecorderStatusError,
+    >;
+
+    /// <p><p>Returns the details for the specified configuration recorders. If the configuration recorder is not specified, this action returns the details for all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
+    fn describe_configuration_recorders(
+        &self,
+        input: DescribeConfigurationRecordersRequest,
+    ) -> RusotoFuture<DescribeConfigurationRecordersResponse, DescribeConfigurationRecordersError>;
+
+    /// <p><p>Returns the current status of the specified delivery channel. If a delivery channel is not specified, this action returns the current status of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
+    fn describe_delivery_channel_status(
+        &self,
+        input: DescribeDeliveryChannelStatusRequest,
+    ) -> RusotoFuture<DescribeDeliveryChannelStatusResponse, DescribeDeliveryChannelStatusError>;
+
+    /// <p><p>Returns details about the specified delivery channel. If a delivery channel is not specified, this action returns the details of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
+    fn describe_delivery_channels(
+        &self,
+        input: DescribeDeliveryChannelsRequest,
+    ) -> RusotoFuture<DescribeDeliveryChannelsResponse, DescribeDeliveryChannelsError>;
+
+    /// <p><p>Provides organization config rule deployment status for an organization.</p> <note> <p>The status is not considered successful until organization config rule is successfully deployed in all the member accounts with an exception of excluded accounts.</p> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
+    fn describe_organization_config_rule_statuses(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> RusotoFuture<
+        DescribeOrganizationConfigRuleStatusesResponse,
+        DescribeOrganizationConfigRuleStatusesError,
+    >;
+
+    /// <p><p>Returns a list of organization config rules.</p> <note> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
+    fn describe_organization_config_rules(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> RusotoFuture<DescribeOrganizationConfigRulesResponse, DescribeOrganizationConfigRulesError>;
+
+    /// <p>Returns a list of all pending aggregation requests.</p>
+    fn describe_pending_aggregation_requests(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> RusotoFuture<
+        DescribePendingAggregationRequestsResponse,
+        DescribePendingAggregationRequestsError,
+    >;
+
+    /// <p>Returns the details of one or more remediation configurations.</p>
+    fn describe_remediation_configurations(
+        &self,
+        input: DescribeRemediationConfigurationsRequest,
+    ) -> RusotoFuture<
+        DescribeRemediationConfigurationsResponse,
+        DescribeRemediationConfigurationsError,
+    >;
+
+    /// <p><p>Returns the details of one or more remediation exceptions. A detailed view of a remediation exception for a set of resources that includes an explanation of an exception and the time when the exception will be deleted. When you specify the limit and the next token, you receive a paginated response. </p> <note> <p>When you specify the limit and the next token, you receive a paginated response. </p> <p>Limit and next token are not applicable if you request resources in batch. It is only applicable, when you request all resources.</p> </note></p>
+    fn describe_remediation_exceptions(
+        &self,
+        input: DescribeRemediationExceptionsRequest,
+    ) -> RusotoFuture<DescribeRemediationExceptionsResponse, DescribeRemediationExceptionsError>;
+
+    /// <p>Provides a detailed view of a Remediation Execution for a set of resources including state, timestamps for when steps for the remediation execution occur, and any error messages for steps that have failed. When you specify the limit and the next token, you receive a paginated response.</p>
+    fn describe_remediation_execution_status(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> RusotoFuture<
+        DescribeRemediationExecutionStatusResponse,
+        DescribeRemediationExecutionStatusError,
+    >;
+
+    /// <p><p>Returns the details of one or more retention configurations. If the retention configuration name is not specified, this action returns the details for all the retention configurations for that account.</p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
+    fn describe_retention_configurations(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> RusotoFuture<DescribeRetentionConfigurationsResponse, DescribeRetentionConfigurationsError>;
+
+    /// <p><p>Returns the evaluation results for the specified AWS Config rule for a specific resource in a rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule. </p> <note> <p>The results can return an empty result page. But if you have a <code>nextToken</code>, the results are displayed on the next page.</p> </note></p>
+    fn get_aggregate_compliance_details_by_config_rule(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> RusotoFuture<
+        GetAggregateComplianceDetailsByConfigRuleResponse,
+        GetAggregateComplianceDetailsByConfigRuleError,
+    >;
+
+    /// <p><p>Returns the number of compliant and noncompliant rules for one or more accounts and regions in an aggregator.</p> <note> <p>The results can return an empty result page, but if you have a nextToken, the results are displayed on the next page.</p> </note></p>
+    fn get_aggregate_config_rule_compliance_summary(
+        &self,
+        input: GetAggregateConfigRuleComplianceSummaryRequest,
+    ) -> RusotoFuture<
+        GetAggregateConfigRuleComplianceSummaryResponse,
+        GetAggregateConfigRuleComplianceSummaryError,
+    >;
+
+    /// <p>Returns the resource counts across accounts and regions that are present in your AWS Config aggregator. You can request the resource counts by providing filters and GroupByKey.</p> <p>For example, if the input contains accountID 12345678910 and region us-east-1 in filters, the API returns the count of resources in account ID 12345678910 and region us-east-1. If the input contains ACCOUNT_ID as a GroupByKey, the API returns resource counts for all source accounts that are present in your aggregator.</p>
+    fn get_aggregate_discovered_resource_counts(
+        &self,
+        input: GetAggregateDiscoveredResourceCountsRequest,
+    ) -> RusotoFuture<
+        GetAggregateDiscoveredResourceCountsResponse,
+        GetAggregateDiscoveredResourceCountsError,
+    >;
+
+    /// <p>Returns configuration item that is aggregated for your specific resource in a specific source account and region.</p>
+    fn get_aggregate_resource_config(
+        &self,
+        input: GetAggregateResourceConfigRequest,
+    ) -> RusotoFuture<GetAggregateResourceConfigResponse, GetAggregateResourceConfigError>;
+
+    /// <p>Returns the evaluation results for the specified AWS Config rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule.</p>
+    fn get_compliance_details_by_config_rule(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> RusotoFuture<GetComplianceDetailsByConfigRuleResponse, GetComplianceDetailsByConfigRuleError>;
+
+    /// <p>Returns the evaluation results for the specified AWS resource. The results indicate which AWS Config rules were used to evaluate the resource, when each rule was last used, and whether the resource complies with each rule.</p>
+    fn get_compliance_details_by_resource(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> RusotoFuture<GetComplianceDetailsByResourceResponse, GetComplianceDetailsByResourceError>;
+
+    /// <p>Returns the number of AWS Config rules that are compliant and noncompliant, up to a maximum of 25 for each.</p>
+    fn get_compliance_summary_by_config_rule(
+        &self,
+    ) -> RusotoFuture<GetComplianceSummaryByConfigRuleResponse, GetComplianceSummaryByConfigRuleError>;
+
+    /// <p>Returns the number of resources that are compliant and the number that are noncompliant. You can specify one or more resource types to get these numbers for each resource type. The maximum number returned is 100.</p>
+    fn get_compliance_summary_by_resource_type(
+        &self,
+        input: GetComplianceSummaryByResourceTypeRequest,
+    ) -> RusotoFuture<
+        GetComplianceSummaryByResourceTypeResponse,
+        GetComplianceSummaryByResourceTypeError,
+    >;
+
+    /// <p><p>Returns the resource types, the number of each resource type, and the total number of resources that AWS Config is recording in this region for your AWS account. </p> <p class="title"> <b>Example</b> </p> <ol> <li> <p>AWS Config is recording three resource types in the US East (Ohio) Region for your account: 25 EC2 instances, 20 IAM users, and 15 S3 buckets.</p> </li> <li> <p>You make a call to the <code>GetDiscoveredResourceCounts</code> action and specify that you want all resource types. </p> </li> <li> <p>AWS Config returns the following:</p> <ul> <li> <p>The resource types (EC2 instances, IAM users, and S3 buckets).</p> </li> <li> <p>The number of each resource type (25, 20, and 15).</p> </li> <li> <p>The total number of all resources (60).</p> </li> </ul> </li> </ol> <p>The response is paginated. By default, AWS Config lists 100 <a>ResourceCount</a> objects on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>If you make a call to the <a>GetDiscoveredResourceCounts</a> action, you might not immediately receive resource counts in the following situations:</p> <ul> <li> <p>You are a new AWS Config customer.</p> </li> <li> <p>You just enabled resource recording.</p> </li> </ul> <p>It might take a few minutes for AWS Config to record and count your resources. Wait a few minutes and then retry the <a>GetDiscoveredResourceCounts</a> action. </p> </note></p>
+    fn get_discovered_resource_counts(
+        &self,
+        input: GetDiscoveredResourceCountsRequest,
+    ) -> RusotoFuture<GetDiscoveredResourceCountsResponse, GetDiscoveredResourceCountsError>;
+
+    /// <p><p>Returns detailed status for each member account within an organization for a given organization config rule.</p> <note> <p>Only a master account can call this API.</p> </note></p>
+    fn get_organization_config_rule_detailed_status(
+        &self,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> RusotoFuture<
+        GetOrganizationConfigRuleDetailedStatusResponse,
+        GetOrganizationConfigRuleDetailedStatusError,
+    >;
+
+    /// <p><p>Returns a list of configuration items for the specified resource. The list contains details about each state of the resource during the specified time interval. If you specified a retention period to retain your <code>ConfigurationItems</code> between a minimum of 30 days and a maximum of 7 years (2557 days), AWS Config returns the <code>ConfigurationItems</code> for the specified retention period. </p> <p>The response is paginated. By default, AWS Config returns a limit of 10 configuration items per page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>Each call to the API is limited to span a duration of seven days. It is likely that the number of records returned is smaller than the specified <code>limit</code>. In such cases, you can make another call, using the <code>nextToken</code>.</p> </note></p>
+    fn get_resource_config_history(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> RusotoFuture<GetResourceConfigHistoryResponse, GetResourceConfigHistoryError>;
+
+    /// <p>Accepts a resource type and returns a list of resource identifiers that are aggregated for a specific resource type across accounts and regions. A resource identifier includes the resource type, ID, (if available) the custom resource name, source account, and source region. You can narrow the results to include only resources that have specific resource IDs, or a resource name, or source account ID, or source region.</p> <p>For example, if the input consists of accountID 12345678910 and the region is us-east-1 for resource type <code>AWS::EC2::Instance</code> then the API returns all the EC2 instance identifiers of accountID 12345678910 and region us-east-1.</p>
+    fn list_aggregate_discovered_resources(
+        &self,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListAggregateDiscoveredResourcesResponse, ListAggregateDiscoveredResourcesError>;
+
+    /// <p>Accepts a resource type and returns a list of resource identifiers for the resources of that type. A resource identifier includes the resource type, ID, and (if available) the custom resource name. The results consist of resources that AWS Config has discovered, including those that AWS Config is not currently recording. You can narrow the results to include only resources that have specific resource IDs or a resource name.</p> <note> <p>You can specify either resource IDs or a resource name, but not both, in the same request.</p> </note> <p>The response is paginated. By default, AWS Config lists 100 resource identifiers on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p>
+    fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListDiscoveredResourcesResponse, ListDiscoveredResourcesError>;
+
+    /// <p>List the tags for AWS Config resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
+
+    /// <p>Authorizes the aggregator account and region to collect data from the source account and region. </p>
+    fn put_aggregation_authorization(
+        &self,
+        input: PutAggregationAuthorizationRequest,
+    ) -> RusotoFuture<PutAggregationAuthorizationResponse, PutAggregationAuthorizationError>;
+
+    /// <p>Adds or updates an AWS Config rule for evaluating whether your AWS resources comply with your desired configurations.</p> <p>You can use this action for custom AWS Config rules and AWS managed Config rules. A custom AWS Config rule is a rule that you develop and maintain. An AWS managed Config rule is a customizable, predefined rule that AWS Config provides.</p> <p>If you are adding a new custom AWS Config rule, you must first create the AWS Lambda function that the rule invokes to evaluate your resources. When you use the <code>PutConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. Specify the ARN for the <code>SourceIdentifier</code> key. This key is part of the <code>Source</code> object, which is part of the <code>ConfigRule</code> object. </p> <p>If you are adding an AWS managed Config rule, specify the rule's identifier for the <code>SourceIdentifier</code> key. To reference AWS managed Config rule identifiers, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config_use-managed-rules.html">About AWS Managed Config Rules</a>.</p> <p>For any new rule that you add, specify the <code>ConfigRuleName</code> in the <code>ConfigRule</code> object. Do not specify the <code>ConfigRuleArn</code> or the <code>ConfigRuleId</code>. These values are generated by AWS Config for new rules.</p> <p>If you are updating a rule that you added previously, you can specify the rule by <code>ConfigRuleName</code>, <code>ConfigRuleId</code>, or <code>ConfigRuleArn</code> in the <code>ConfigRule</code> data type that you use in this request.</p> <p>The maximum number of rules that AWS Config supports is 150.</p> <p>For information about requesting a rule limit increase, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_config">AWS Config Limits</a> in the <i>AWS General Reference Guide</i>.</p> <p>For more information about developing and using AWS Config rules, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config.html">Evaluating AWS Resource Configurations with AWS Config</a> in the <i>AWS Config Developer Guide</i>.</p>
+    fn put_config_rule(&self, input: PutConfigRuleRequest) -> RusotoFuture<(), PutConfigRuleError>;
+
+    /// <p><p>Creates and updates the configuration aggregator with the selected source accounts and regions. The source account can be individual account(s) or an organization.</p> <note> <p>AWS Config should be enabled in source accounts and regions you want to aggregate.</p> <p>If your source type is an organization, you must be signed in to the master account and all features must be enabled in your organization. AWS Config calls <code>EnableAwsServiceAccess</code> API to enable integration between AWS Config and AWS Organizations. </p> </note></p>
+    fn put_configuration_aggregator(
+        &self,
+        input: PutConfigurationAggregatorRequest,
+    ) -> RusotoFuture<PutConfigurationAggregatorResponse, PutConfigurationAggregatorError>;
+
+    /// <p><p>Creates a new configuration recorder to record the selected resource configurations.</p> <p>You can use this action to change the role <code>roleARN</code> or the <code>recordingGroup</code> of an existing recorder. To change the role, call the action on the existing configuration recorder and specify a role.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> <p>If <code>ConfigurationRecorder</code> does not have the <b>recordingGroup</b> parameter specified, the default is to record all supported resource types.</p> </note></p>
+    fn put_configuration_recorder(
+        &self,
+        input: PutConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), PutConfigurationRecorderError>;
+
+    /// <p><p>Creates a delivery channel object to deliver configuration information to an Amazon S3 bucket and Amazon SNS topic.</p> <p>Before you can create a delivery channel, you must create a configuration recorder.</p> <p>You can use this action to change the Amazon S3 bucket or an Amazon SNS topic of the existing delivery channel. To change the Amazon S3 bucket or an Amazon SNS topic, call this action and specify the changed values for the S3 bucket and the SNS topic. If you specify a different value for either the S3 bucket or the SNS topic, this action will keep the existing value for the parameter that is not changed.</p> <note> <p>You can have only one delivery channel per region in your account.</p> </note></p>
+    fn put_delivery_channel(
+        &self,
+        input: PutDeliveryChannelRequest,
+    ) -> RusotoFuture<(), PutDeliveryChannelError>;
+
+    /// <p>Used by an AWS Lambda function to deliver evaluation results to AWS Config. This action is required in every AWS Lambda function that is invoked by an AWS Config rule.</p>
+    fn put_evaluations(
+        &self,
+        input: PutEvaluationsRequest,
+    ) -> RusotoFuture<PutEvaluationsResponse, PutEvaluationsError>;
+
+    /// <p><p>Adds or updates organization config rule for your entire organization evaluating whether your AWS resources comply with your desired configurations. Only a master account can create or update an organization config rule.</p> <p>This API enables organization service access through the <code>EnableAWSServiceAccess</code> action and creates a service linked role <code>AWSServiceRoleForConfigMultiAccountSetup</code> in the master account of your organization. The service linked role is created only when the role does not exist in the master account. AWS Config verifies the existence of role with <code>GetRole</code> action.</p> <p>You can use this action to create both custom AWS Config rules and AWS managed Config rules. If you are adding a new custom AWS Config rule, you must first create AWS Lambda function in the master account that the rule invokes to evaluate your resources. When you use the <code>PutOrganizationConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. If you are adding an AWS managed Config rule, specify the rule&#39;s identifier for the <code>RuleIdentifier</code> key.</p> <p>The maximum number of organization config rules that AWS Config supports is 150.</p> <note> <p>Specify either <code>OrganizationCustomRuleMetadata</code> or <code>OrganizationManagedRuleMetadata</code>.</p> </note></p>
+    fn put_organization_config_rule(
+        &self,
+        input: PutOrganizationConfigRuleRequest,
+    ) -> RusotoFuture<PutOrganizationConfigRuleResponse, PutOrganizationConfigRuleError>;
+
+    /// <p>Adds or updates the remediation configuration with a specific AWS Config rule with the selected target or action. The API creates the <code>RemediationConfiguration</code> object for the AWS Config rule. The AWS Config rule must already exist for you to add a remediation configuration. The target (SSM document) must exist and have permissions to use the target. </p>
+    fn put_remediation_configurations(
+        &self,
+        input: PutRemediationConfigurationsRequest,
+    ) -> RusotoFuture<PutRemediationConfigurationsResponse, PutRemediationConfigurationsError>;
+
+    /// <p>A remediation exception is when a specific resource is no longer considered for auto-remediation. This API adds a new exception or updates an exisiting exception for a specific resource with a specific AWS Config rule. </p>
+    fn put_remediation_exceptions(
+        &self,
+        input: PutRemediationExceptionsRequest,
+    ) -> RusotoFuture<PutRemediationExceptionsResponse, PutRemediationExceptionsError>;
+
+    /// <p><p>Creates and updates the retention configuration with details about retention period (number of days) that AWS Config stores your historical information. The API creates the <code>RetentionConfiguration</code> object and names the object as <b>default</b>. When you have a <code>RetentionConfiguration</code> object named <b>default</b>, calling the API modifies the default object. </p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
+    fn put_retention_configuration(
+        &self,
+        input: PutRetentionConfigurationRequest,
+    ) -> RusotoFuture<PutRetentionConfigurationResponse, PutRetentionConfigurationError>;
+
+    /// <p>Accepts a structured query language (SQL) <code>SELECT</code> command, performs the corresponding search, and returns resource configurations matching the properties.</p> <p>For more information about query components, see the <a href="https://docs.aws.amazon.com/config/latest/developerguide/query-components.html"> <b>Query Components</b> </a> section in the AWS Config Developer Guide.</p>
+    fn select_resource_config(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> RusotoFuture<SelectResourceConfigResponse, SelectResourceConfigError>;
+
+    /// <p><p>Runs an on-demand evaluation for the specified AWS Config rules against the last known configuration state of the resources. Use <code>StartConfigRulesEvaluation</code> when you want to test that a rule you updated is working as expected. <code>StartConfigRulesEvaluation</code> does not re-record the latest configuration state for your resources. It re-runs an evaluation against the last known state of your resources. </p> <p>You can specify up to 25 AWS Config rules per request. </p> <p>An existing <code>StartConfigRulesEvaluation</code> call for the specified rules must complete before you can call the API again. If you chose to have AWS Config stream to an Amazon SNS topic, you will receive a <code>ConfigRuleEvaluationStarted</code> notification when the evaluation starts.</p> <note> <p>You don&#39;t need to call the <code>StartConfigRulesEvaluation</code> API to run an evaluation for a new rule. When you create a rule, AWS Config evaluates your resources against the rule automatically. </p> </note> <p>The <code>StartConfigRulesEvaluation</code> API is useful if you want to run on-demand evaluations, such as the following example:</p> <ol> <li> <p>You have a custom rule that evaluates your IAM resources every 24 hours.</p> </li> <li> <p>You update your Lambda function to add additional conditions to your rule.</p> </li> <li> <p>Instead of waiting for the next periodic evaluation, you call the <code>StartConfigRulesEvaluation</code> API.</p> </li> <li> <p>AWS Config invokes your Lambda function and evaluates your IAM resources.</p> </li> <li> <p>Your custom rule will still run periodic evaluations every 24 hours.</p> </li> </ol></p>
+    fn start_config_rules_evaluation(
+        &self,
+        input: StartConfigRulesEvaluationRequest,
+    ) -> RusotoFuture<StartConfigRulesEvaluationResponse, StartConfigRulesEvaluationError>;
+
+    /// <p>Starts recording configurations of the AWS resources you have selected to record in your AWS account.</p> <p>You must have created at least one delivery channel to successfully start the configuration recorder.</p>
+    fn start_configuration_recorder(
+        &self,
+        input: StartConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), StartConfigurationRecorderError>;
+
+    /// <p>Runs an on-demand remediation for the specified AWS Config rules against the last known remediation configuration. It runs an execution against the current state of your resources. Remediation execution is asynchronous.</p> <p>You can specify up to 100 resource keys per request. An existing StartRemediationExecution call for the specified resource keys must complete before you can call the API again.</p>
+    fn start_remediation_execution(
+        &self,
+        input: StartRemediationExecutionRequest,
+    ) -> RusotoFuture<StartRemediationExecutionResponse, StartRemediationExecutionError>;
+
+    /// <p>Stops recording configurations of the AWS resources you have selected to record in your AWS account.</p>
+    fn stop_configuration_recorder(
+        &self,
+        input: StopConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), StopConfigurationRecorderError>;
+
+    /// <p>Associates the specified tags to a resource with the specified resourceArn. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError>;
+
+    /// <p>Deletes specified tags from a resource.</p>
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError>;
+}
+impl<T: ?Sized + ConfigService> ConfigService for ::std::sync::Arc<T> {
+    fn batch_get_aggregate_resource_config(
+        &self,
+        input: BatchGetAggregateResourceConfigRequest,
+    ) -> RusotoFuture<BatchGetAggregateResourceConfigResponse, BatchGetAggregateResourceConfigError>
+    {
+        (**self).batch_get_aggregate_resource_config(input)
+    }
+    fn batch_get_resource_config(
+        &self,
+        input: BatchGetResourceConfigRequest,
+    ) -> RusotoFuture<BatchGetResourceConfigResponse, BatchGetResourceConfigError> {
+        (**self).batch_get_resource_config(input)
+    }
+    fn delete_aggregation_authorization(
+        &self,
+        input: DeleteAggregationAuthorizationRequest,
+    ) -> RusotoFuture<(), DeleteAggregationAuthorizationError> {
+        (**self).delete_aggregation_authorization(input)
+    }
+    fn delete_config_rule(
+        &self,
+        input: DeleteConfigRuleRequest,
+    ) -> RusotoFuture<(), DeleteConfigRuleError> {
+        (**self).delete_config_rule(input)
+    }
+    fn delete_configuration_aggregator(
+        &self,
+        input: DeleteConfigurationAggregatorRequest,
+    ) -> RusotoFuture<(), DeleteConfigurationAggregatorError> {
+        (**self).delete_configuration_aggregator(input)
+    }
+    fn delete_configuration_recorder(
+        &self,
+        input: DeleteConfigurationRecorderRequest,
+    ) -> RusotoFuture<(), DeleteConfigurationRecorderError> {
+        (**self).delete_configuration_recorder(input)
+    }
+    fn delete_delivery_channel(
+        &self,
+        input: DeleteDeliveryChannelRequest,
+    ) -> RusotoFuture<(), DeleteDeliveryChannelError> {
+        (**self).delete_delivery_channel(input)
+    }
+    fn delete_evaluation_results(
+        &self,
+        input: DeleteEvaluationResultsRequest,
+    ) -> RusotoFuture<DeleteEvaluationResultsResponse, DeleteEvaluationResultsError> {
+        (**self).delete_evaluation_results(input)
+    }
+    fn delete_organization_config_rule(
+        &self,
+        input: DeleteOrganizationConfigRuleRequest,
+    ) -> RusotoFuture<(), DeleteOrganizationConfigRuleError> {
+        (**self).delete_organization_config_rule(input)
+    }
+    fn delete_pending_aggregation_request(
+        &self,
+        input: DeletePendingAggregationRequestRequest,
+    ) -> RusotoFuture<(), DeletePendingAggregationRequestError> {
+        (**self).delete_pending_aggregation_request(input)
+    }
+    fn delete_remediation_configuration(
+        &self,
+        input: DeleteRemediationConfigurationRequest,
+    ) -> RusotoFuture<DeleteRemediationConfigurationResponse, DeleteRemediationConfigurationError>
+    {
+        (**self).delete_remediation_configuration(input)
+    }
+    fn delete_remediation_exceptions(
+        &self,
+        input: DeleteRemediationExceptionsRequest,
+    ) -> RusotoFuture<DeleteRemediationExceptionsResponse, DeleteRemediationExceptionsError> {
+        (**self).delete_remediation_exceptions(input)
+    }
+    fn delete_retention_configuration(
+        &self,
+        input: DeleteRetentionConfigurationRequest,
+    ) -> RusotoFuture<(), DeleteRetentionConfigurationError> {
+        (**self).delete_retention_configuration(input)
+    }
+    fn deliver_config_snapshot(
+        &self,
+        input: DeliverConfigSnapshotRequest,
+    ) -> RusotoFuture<DeliverConfigSnapshotResponse, DeliverConfigSnapshotError> {
+        (**self).deliver_config_snapshot(input)
+    }
+    fn describe_aggregate_compliance_by_config_rules(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> RusotoFuture<
+        DescribeAggregateComplianceByConfigRulesResponse,
+        DescribeAggregateComplianceByConfigRulesError,
+    > {
+        (**self).describe_aggregate_compliance_by_config_rules(input)
+    }
+    fn describe_aggregation_authorizations(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> RusotoFuture<
+        DescribeAggregationAuthorizationsResponse,
+        DescribeAggregationAuthorizationsError,
+    > {
+        (**self).describe_aggregation_authorizations(input)
+    }
+    fn describe_compliance_by_config_rule(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> RusotoFuture<DescribeComplianceByConfigRuleResponse, DescribeComplianceByConfigRuleError>
+    {
+        (**self).describe_compliance_by_config_rule(input)
+    }
+    fn describe_compliance_by_resource(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> RusotoFuture<DescribeComplianceByResourceResponse, DescribeComplianceByResourceError> {
+        (**self).describe_compliance_by_resource(input)
+    }
+    fn describe_config_rule_evaluation_status(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigRuleEvaluationStatusResponse,
+        DescribeConfigRuleEvaluationStatusError,
+    > {
+        (**self).describe_config_rule_evaluation_status(input)
+    }
+    fn describe_config_rules(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> RusotoFuture<DescribeConfigRulesResponse, DescribeConfigRulesError> {
+        (**self).describe_config_rules(input)
+    }
+    fn describe_configuration_aggregator_sources_status(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> RusotoFuture<
+        DescribeConfigurationAggregatorSourcesStatusResponse,
+        DescribeConfigurationAggregatorSourcesStatusError,
+    > {
+        (**self).describe_configuration_aggregator_sources_status(input)
+    }
     fn describe_configuration_aggregators(
         &self,
         input: DescribeConfigurationAggregatorsRequest,
-    ) -> RusotoFuture<DescribeConfigurationAggregatorsResponse, DescribeConfigurationAggregatorsError>;
-
-    /// <p><p>Returns the current status of the specified configuration recorder. If a configuration recorder is not specified, this action returns the status of all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
+    ) -> RusotoFuture<DescribeConfigurationAggregatorsResponse, DescribeConfigurationAggregatorsError>
+    {
+        (**self).describe_configuration_aggregators(input)
+    }
     fn describe_configuration_recorder_status(
         &self,
         input: DescribeConfigurationRecorderStatusRequest,
     ) -> RusotoFuture<
         DescribeConfigurationRecorderStatusResponse,
         DescribeConfigurationRecorderStatusError,
-    >;
-
-    /// <p><p>Returns the details for the specified configuration recorders. If the configuration recorder is not specified, this action returns the details for all configuration recorders associated with the account.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> </note></p>
+    > {
+        (**self).describe_configuration_recorder_status(input)
+    }
     fn describe_configuration_recorders(
         &self,
         input: DescribeConfigurationRecordersRequest,
-    ) -> RusotoFuture<DescribeConfigurationRecordersResponse, DescribeConfigurationRecordersError>;
-
-    /// <p><p>Returns the current status of the specified delivery channel. If a delivery channel is not specified, this action returns the current status of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
+    ) -> RusotoFuture<DescribeConfigurationRecordersResponse, DescribeConfigurationRecordersError>
+    {
+        (**self).describe_configuration_recorders(input)
+    }
     fn describe_delivery_channel_status(
         &self,
         input: DescribeDeliveryChannelStatusRequest,
-    ) -> RusotoFuture<DescribeDeliveryChannelStatusResponse, DescribeDeliveryChannelStatusError>;
-
-    /// <p><p>Returns details about the specified delivery channel. If a delivery channel is not specified, this action returns the details of all delivery channels associated with the account.</p> <note> <p>Currently, you can specify only one delivery channel per region in your account.</p> </note></p>
+    ) -> RusotoFuture<DescribeDeliveryChannelStatusResponse, DescribeDeliveryChannelStatusError>
+    {
+        (**self).describe_delivery_channel_status(input)
+    }
     fn describe_delivery_channels(
         &self,
         input: DescribeDeliveryChannelsRequest,
-    ) -> RusotoFuture<DescribeDeliveryChannelsResponse, DescribeDeliveryChannelsError>;
-
-    /// <p><p>Provides organization config rule deployment status for an organization.</p> <note> <p>The status is not considered successful until organization config rule is successfully deployed in all the member accounts with an exception of excluded accounts.</p> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
+    ) -> RusotoFuture<DescribeDeliveryChannelsResponse, DescribeDeliveryChannelsError> {
+        (**self).describe_delivery_channels(input)
+    }
     fn describe_organization_config_rule_statuses(
         &self,
         input: DescribeOrganizationConfigRuleStatusesRequest,
     ) -> RusotoFuture<
         DescribeOrganizationConfigRuleStatusesResponse,
         DescribeOrganizationConfigRuleStatusesError,
-    >;
-
-    /// <p><p>Returns a list of organization config rules.</p> <note> <p>When you specify the limit and the next token, you receive a paginated response. Limit and next token are not applicable if you specify organization config rule names. It is only applicable, when you request all the organization config rules.</p> <p>Only a master account can call this API.</p> </note></p>
+    > {
+        (**self).describe_organization_config_rule_statuses(input)
+    }
     fn describe_organization_config_rules(
         &self,
         input: DescribeOrganizationConfigRulesRequest,
-    ) -> RusotoFuture<DescribeOrganizationConfigRulesResponse, DescribeOrganizationConfigRulesError>;
-
-    /// <p>Returns a list of all pending aggregation requests.</p>
+    ) -> RusotoFuture<DescribeOrganizationConfigRulesResponse, DescribeOrganizationConfigRulesError>
+    {
+        (**self).describe_organization_config_rules(input)
+    }
     fn describe_pending_aggregation_requests(
         &self,
         input: DescribePendingAggregationRequestsRequest,
     ) -> RusotoFuture<
         DescribePendingAggregationRequestsResponse,
         DescribePendingAggregationRequestsError,
-    >;
-
-    /// <p>Returns the details of one or more remediation configurations.</p>
+    > {
+        (**self).describe_pending_aggregation_requests(input)
+    }
     fn describe_remediation_configurations(
         &self,
         input: DescribeRemediationConfigurationsRequest,
     ) -> RusotoFuture<
         DescribeRemediationConfigurationsResponse,
         DescribeRemediationConfigurationsError,
-    >;
-
-    /// <p><p>Returns the details of one or more remediation exceptions. A detailed view of a remediation exception for a set of resources that includes an explanation of an exception and the time when the exception will be deleted. When you specify the limit and the next token, you receive a paginated response. </p> <note> <p>When you specify the limit and the next token, you receive a paginated response. </p> <p>Limit and next token are not applicable if you request resources in batch. It is only applicable, when you request all resources.</p> </note></p>
+    > {
+        (**self).describe_remediation_configurations(input)
+    }
     fn describe_remediation_exceptions(
         &self,
         input: DescribeRemediationExceptionsRequest,
-    ) -> RusotoFuture<DescribeRemediationExceptionsResponse, DescribeRemediationExceptionsError>;
-
-    /// <p>Provides a detailed view of a Remediation Execution for a set of resources including state, timestamps for when steps for the remediation execution occur, and any error messages for steps that have failed. When you specify the limit and the next token, you receive a paginated response.</p>
+    ) -> RusotoFuture<DescribeRemediationExceptionsResponse, DescribeRemediationExceptionsError>
+    {
+        (**self).describe_remediation_exceptions(input)
+    }
     fn describe_remediation_execution_status(
         &self,
         input: DescribeRemediationExecutionStatusRequest,
     ) -> RusotoFuture<
         DescribeRemediationExecutionStatusResponse,
         DescribeRemediationExecutionStatusError,
-    >;
-
-    /// <p><p>Returns the details of one or more retention configurations. If the retention configuration name is not specified, this action returns the details for all the retention configurations for that account.</p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
+    > {
+        (**self).describe_remediation_execution_status(input)
+    }
     fn describe_retention_configurations(
         &self,
         input: DescribeRetentionConfigurationsRequest,
-    ) -> RusotoFuture<DescribeRetentionConfigurationsResponse, DescribeRetentionConfigurationsError>;
-
-    /// <p><p>Returns the evaluation results for the specified AWS Config rule for a specific resource in a rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule. </p> <note> <p>The results can return an empty result page. But if you have a <code>nextToken</code>, the results are displayed on the next page.</p> </note></p>
+    ) -> RusotoFuture<DescribeRetentionConfigurationsResponse, DescribeRetentionConfigurationsError>
+    {
+        (**self).describe_retention_configurations(input)
+    }
     fn get_aggregate_compliance_details_by_config_rule(
         &self,
         input: GetAggregateComplianceDetailsByConfigRuleRequest,
     ) -> RusotoFuture<
         GetAggregateComplianceDetailsByConfigRuleResponse,
         GetAggregateComplianceDetailsByConfigRuleError,
-    >;
-
-    /// <p><p>Returns the number of compliant and noncompliant rules for one or more accounts and regions in an aggregator.</p> <note> <p>The results can return an empty result page, but if you have a nextToken, the results are displayed on the next page.</p> </note></p>
+    > {
+        (**self).get_aggregate_compliance_details_by_config_rule(input)
+    }
     fn get_aggregate_config_rule_compliance_summary(
         &self,
         input: GetAggregateConfigRuleComplianceSummaryRequest,
     ) -> RusotoFuture<
         GetAggregateConfigRuleComplianceSummaryResponse,
         GetAggregateConfigRuleComplianceSummaryError,
-    >;
-
-    /// <p>Returns the resource counts across accounts and regions that are present in your AWS Config aggregator. You can request the resource counts by providing filters and GroupByKey.</p> <p>For example, if the input contains accountID 12345678910 and region us-east-1 in filters, the API returns the count of resources in account ID 12345678910 and region us-east-1. If the input contains ACCOUNT_ID as a GroupByKey, the API returns resource counts for all source accounts that are present in your aggregator.</p>
+    > {
+        (**self).get_aggregate_config_rule_compliance_summary(input)
+    }
     fn get_aggregate_discovered_resource_counts(
         &self,
         input: GetAggregateDiscoveredResourceCountsRequest,
     ) -> RusotoFuture<
         GetAggregateDiscoveredResourceCountsResponse,
         GetAggregateDiscoveredResourceCountsError,
-    >;
-
-    /// <p>Returns configuration item that is aggregated for your specific resource in a specific source account and region.</p>
+    > {
+        (**self).get_aggregate_discovered_resource_counts(input)
+    }
     fn get_aggregate_resource_config(
         &self,
         input: GetAggregateResourceConfigRequest,
-    ) -> RusotoFuture<GetAggregateResourceConfigResponse, GetAggregateResourceConfigError>;
-
-    /// <p>Returns the evaluation results for the specified AWS Config rule. The results indicate which AWS resources were evaluated by the rule, when each resource was last evaluated, and whether each resource complies with the rule.</p>
+    ) -> RusotoFuture<GetAggregateResourceConfigResponse, GetAggregateResourceConfigError> {
+        (**self).get_aggregate_resource_config(input)
+    }
     fn get_compliance_details_by_config_rule(
         &self,
         input: GetComplianceDetailsByConfigRuleRequest,
-    ) -> RusotoFuture<GetComplianceDetailsByConfigRuleResponse, GetComplianceDetailsByConfigRuleError>;
-
-    /// <p>Returns the evaluation results for the specified AWS resource. The results indicate which AWS Config rules were used to evaluate the resource, when each rule was last used, and whether the resource complies with each rule.</p>
+    ) -> RusotoFuture<GetComplianceDetailsByConfigRuleResponse, GetComplianceDetailsByConfigRuleError>
+    {
+        (**self).get_compliance_details_by_config_rule(input)
+    }
     fn get_compliance_details_by_resource(
         &self,
         input: GetComplianceDetailsByResourceRequest,
-    ) -> RusotoFuture<GetComplianceDetailsByResourceResponse, GetComplianceDetailsByResourceError>;
-
-    /// <p>Returns the number of AWS Config rules that are compliant and noncompliant, up to a maximum of 25 for each.</p>
+    ) -> RusotoFuture<GetComplianceDetailsByResourceResponse, GetComplianceDetailsByResourceError>
+    {
+        (**self).get_compliance_details_by_resource(input)
+    }
     fn get_compliance_summary_by_config_rule(
         &self,
-    ) -> RusotoFuture<GetComplianceSummaryByConfigRuleResponse, GetComplianceSummaryByConfigRuleError>;
-
-    /// <p>Returns the number of resources that are compliant and the number that are noncompliant. You can specify one or more resource types to get these numbers for each resource type. The maximum number returned is 100.</p>
+    ) -> RusotoFuture<GetComplianceSummaryByConfigRuleResponse, GetComplianceSummaryByConfigRuleError>
+    {
+        (**self).get_compliance_summary_by_config_rule()
+    }
     fn get_compliance_summary_by_resource_type(
         &self,
         input: GetComplianceSummaryByResourceTypeRequest,
     ) -> RusotoFuture<
         GetComplianceSummaryByResourceTypeResponse,
         GetComplianceSummaryByResourceTypeError,
-    >;
-
-    /// <p><p>Returns the resource types, the number of each resource type, and the total number of resources that AWS Config is recording in this region for your AWS account. </p> <p class="title"> <b>Example</b> </p> <ol> <li> <p>AWS Config is recording three resource types in the US East (Ohio) Region for your account: 25 EC2 instances, 20 IAM users, and 15 S3 buckets.</p> </li> <li> <p>You make a call to the <code>GetDiscoveredResourceCounts</code> action and specify that you want all resource types. </p> </li> <li> <p>AWS Config returns the following:</p> <ul> <li> <p>The resource types (EC2 instances, IAM users, and S3 buckets).</p> </li> <li> <p>The number of each resource type (25, 20, and 15).</p> </li> <li> <p>The total number of all resources (60).</p> </li> </ul> </li> </ol> <p>The response is paginated. By default, AWS Config lists 100 <a>ResourceCount</a> objects on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>If you make a call to the <a>GetDiscoveredResourceCounts</a> action, you might not immediately receive resource counts in the following situations:</p> <ul> <li> <p>You are a new AWS Config customer.</p> </li> <li> <p>You just enabled resource recording.</p> </li> </ul> <p>It might take a few minutes for AWS Config to record and count your resources. Wait a few minutes and then retry the <a>GetDiscoveredResourceCounts</a> action. </p> </note></p>
+    > {
+        (**self).get_compliance_summary_by_resource_type(input)
+    }
     fn get_discovered_resource_counts(
         &self,
         input: GetDiscoveredResourceCountsRequest,
-    ) -> RusotoFuture<GetDiscoveredResourceCountsResponse, GetDiscoveredResourceCountsError>;
-
-    /// <p><p>Returns detailed status for each member account within an organization for a given organization config rule.</p> <note> <p>Only a master account can call this API.</p> </note></p>
+    ) -> RusotoFuture<GetDiscoveredResourceCountsResponse, GetDiscoveredResourceCountsError> {
+        (**self).get_discovered_resource_counts(input)
+    }
     fn get_organization_config_rule_detailed_status(
         &self,
         input: GetOrganizationConfigRuleDetailedStatusRequest,
     ) -> RusotoFuture<
         GetOrganizationConfigRuleDetailedStatusResponse,
         GetOrganizationConfigRuleDetailedStatusError,
-    >;
-
-    /// <p><p>Returns a list of configuration items for the specified resource. The list contains details about each state of the resource during the specified time interval. If you specified a retention period to retain your <code>ConfigurationItems</code> between a minimum of 30 days and a maximum of 7 years (2557 days), AWS Config returns the <code>ConfigurationItems</code> for the specified retention period. </p> <p>The response is paginated. By default, AWS Config returns a limit of 10 configuration items per page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p> <note> <p>Each call to the API is limited to span a duration of seven days. It is likely that the number of records returned is smaller than the specified <code>limit</code>. In such cases, you can make another call, using the <code>nextToken</code>.</p> </note></p>
+    > {
+        (**self).get_organization_config_rule_detailed_status(input)
+    }
     fn get_resource_config_history(
         &self,
         input: GetResourceConfigHistoryRequest,
-    ) -> RusotoFuture<GetResourceConfigHistoryResponse, GetResourceConfigHistoryError>;
-
-    /// <p>Accepts a resource type and returns a list of resource identifiers that are aggregated for a specific resource type across accounts and regions. A resource identifier includes the resource type, ID, (if available) the custom resource name, source account, and source region. You can narrow the results to include only resources that have specific resource IDs, or a resource name, or source account ID, or source region.</p> <p>For example, if the input consists of accountID 12345678910 and the region is us-east-1 for resource type <code>AWS::EC2::Instance</code> then the API returns all the EC2 instance identifiers of accountID 12345678910 and region us-east-1.</p>
+    ) -> RusotoFuture<GetResourceConfigHistoryResponse, GetResourceConfigHistoryError> {
+        (**self).get_resource_config_history(input)
+    }
     fn list_aggregate_discovered_resources(
         &self,
         input: ListAggregateDiscoveredResourcesRequest,
-    ) -> RusotoFuture<ListAggregateDiscoveredResourcesResponse, ListAggregateDiscoveredResourcesError>;
-
-    /// <p>Accepts a resource type and returns a list of resource identifiers for the resources of that type. A resource identifier includes the resource type, ID, and (if available) the custom resource name. The results consist of resources that AWS Config has discovered, including those that AWS Config is not currently recording. You can narrow the results to include only resources that have specific resource IDs or a resource name.</p> <note> <p>You can specify either resource IDs or a resource name, but not both, in the same request.</p> </note> <p>The response is paginated. By default, AWS Config lists 100 resource identifiers on each page. You can customize this number with the <code>limit</code> parameter. The response includes a <code>nextToken</code> string. To get the next page of results, run the request again and specify the string for the <code>nextToken</code> parameter.</p>
+    ) -> RusotoFuture<ListAggregateDiscoveredResourcesResponse, ListAggregateDiscoveredResourcesError>
+    {
+        (**self).list_aggregate_discovered_resources(input)
+    }
     fn list_discovered_resources(
         &self,
         input: ListDiscoveredResourcesRequest,
-    ) -> RusotoFuture<ListDiscoveredResourcesResponse, ListDiscoveredResourcesError>;
-
-    /// <p>List the tags for AWS Config resource.</p>
+    ) -> RusotoFuture<ListDiscoveredResourcesResponse, ListDiscoveredResourcesError> {
+        (**self).list_discovered_resources(input)
+    }
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
-    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
-
-    /// <p>Authorizes the aggregator account and region to collect data from the source account and region. </p>
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
     fn put_aggregation_authorization(
         &self,
         input: PutAggregationAuthorizationRequest,
-    ) -> RusotoFuture<PutAggregationAuthorizationResponse, PutAggregationAuthorizationError>;
-
-    /// <p>Adds or updates an AWS Config rule for evaluating whether your AWS resources comply with your desired configurations.</p> <p>You can use this action for custom AWS Config rules and AWS managed Config rules. A custom AWS Config rule is a rule that you develop and maintain. An AWS managed Config rule is a customizable, predefined rule that AWS Config provides.</p> <p>If you are adding a new custom AWS Config rule, you must first create the AWS Lambda function that the rule invokes to evaluate your resources. When you use the <code>PutConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. Specify the ARN for the <code>SourceIdentifier</code> key. This key is part of the <code>Source</code> object, which is part of the <code>ConfigRule</code> object. </p> <p>If you are adding an AWS managed Config rule, specify the rule's identifier for the <code>SourceIdentifier</code> key. To reference AWS managed Config rule identifiers, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config_use-managed-rules.html">About AWS Managed Config Rules</a>.</p> <p>For any new rule that you add, specify the <code>ConfigRuleName</code> in the <code>ConfigRule</code> object. Do not specify the <code>ConfigRuleArn</code> or the <code>ConfigRuleId</code>. These values are generated by AWS Config for new rules.</p> <p>If you are updating a rule that you added previously, you can specify the rule by <code>ConfigRuleName</code>, <code>ConfigRuleId</code>, or <code>ConfigRuleArn</code> in the <code>ConfigRule</code> data type that you use in this request.</p> <p>The maximum number of rules that AWS Config supports is 150.</p> <p>For information about requesting a rule limit increase, see <a href="http://docs.aws.amazon.com/general/latest/gr/aws_service_limits.html#limits_config">AWS Config Limits</a> in the <i>AWS General Reference Guide</i>.</p> <p>For more information about developing and using AWS Config rules, see <a href="https://docs.aws.amazon.com/config/latest/developerguide/evaluate-config.html">Evaluating AWS Resource Configurations with AWS Config</a> in the <i>AWS Config Developer Guide</i>.</p>
-    fn put_config_rule(&self, input: PutConfigRuleRequest) -> RusotoFuture<(), PutConfigRuleError>;
-
-    /// <p><p>Creates and updates the configuration aggregator with the selected source accounts and regions. The source account can be individual account(s) or an organization.</p> <note> <p>AWS Config should be enabled in source accounts and regions you want to aggregate.</p> <p>If your source type is an organization, you must be signed in to the master account and all features must be enabled in your organization. AWS Config calls <code>EnableAwsServiceAccess</code> API to enable integration between AWS Config and AWS Organizations. </p> </note></p>
+    ) -> RusotoFuture<PutAggregationAuthorizationResponse, PutAggregationAuthorizationError> {
+        (**self).put_aggregation_authorization(input)
+    }
+    fn put_config_rule(&self, input: PutConfigRuleRequest) -> RusotoFuture<(), PutConfigRuleError> {
+        (**self).put_config_rule(input)
+    }
     fn put_configuration_aggregator(
         &self,
         input: PutConfigurationAggregatorRequest,
-    ) -> RusotoFuture<PutConfigurationAggregatorResponse, PutConfigurationAggregatorError>;
-
-    /// <p><p>Creates a new configuration recorder to record the selected resource configurations.</p> <p>You can use this action to change the role <code>roleARN</code> or the <code>recordingGroup</code> of an existing recorder. To change the role, call the action on the existing configuration recorder and specify a role.</p> <note> <p>Currently, you can specify only one configuration recorder per region in your account.</p> <p>If <code>ConfigurationRecorder</code> does not have the <b>recordingGroup</b> parameter specified, the default is to record all supported resource types.</p> </note></p>
+    ) -> RusotoFuture<PutConfigurationAggregatorResponse, PutConfigurationAggregatorError> {
+        (**self).put_configuration_aggregator(input)
+    }
     fn put_configuration_recorder(
         &self,
         input: PutConfigurationRecorderRequest,
-    ) -> RusotoFuture<(), PutConfigurationRecorderError>;
-
-    /// <p><p>Creates a delivery channel object to deliver configuration information to an Amazon S3 bucket and Amazon SNS topic.</p> <p>Before you can create a delivery channel, you must create a configuration recorder.</p> <p>You can use this action to change the Amazon S3 bucket or an Amazon SNS topic of the existing delivery channel. To change the Amazon S3 bucket or an Amazon SNS topic, call this action and specify the changed values for the S3 bucket and the SNS topic. If you specify a different value for either the S3 bucket or the SNS topic, this action will keep the existing value for the parameter that is not changed.</p> <note> <p>You can have only one delivery channel per region in your account.</p> </note></p>
+    ) -> RusotoFuture<(), PutConfigurationRecorderError> {
+        (**self).put_configuration_recorder(input)
+    }
     fn put_delivery_channel(
         &self,
         input: PutDeliveryChannelRequest,
-    ) -> RusotoFuture<(), PutDeliveryChannelError>;
-
-    /// <p>Used by an AWS Lambda function to deliver evaluation results to AWS Config. This action is required in every AWS Lambda function that is invoked by an AWS Config rule.</p>
+    ) -> RusotoFuture<(), PutDeliveryChannelError> {
+        (**self).put_delivery_channel(input)
+    }
     fn put_evaluations(
         &self,
         input: PutEvaluationsRequest,
-    ) -> RusotoFuture<PutEvaluationsResponse, PutEvaluationsError>;
-
-    /// <p><p>Adds or updates organization config rule for your entire organization evaluating whether your AWS resources comply with your desired configurations. Only a master account can create or update an organization config rule.</p> <p>This API enables organization service access through the <code>EnableAWSServiceAccess</code> action and creates a service linked role <code>AWSServiceRoleForConfigMultiAccountSetup</code> in the master account of your organization. The service linked role is created only when the role does not exist in the master account. AWS Config verifies the existence of role with <code>GetRole</code> action.</p> <p>You can use this action to create both custom AWS Config rules and AWS managed Config rules. If you are adding a new custom AWS Config rule, you must first create AWS Lambda function in the master account that the rule invokes to evaluate your resources. When you use the <code>PutOrganizationConfigRule</code> action to add the rule to AWS Config, you must specify the Amazon Resource Name (ARN) that AWS Lambda assigns to the function. If you are adding an AWS managed Config rule, specify the rule&#39;s identifier for the <code>RuleIdentifier</code> key.</p> <p>The maximum number of organization config rules that AWS Config supports is 150.</p> <note> <p>Specify either <code>OrganizationCustomRuleMetadata</code> or <code>OrganizationManagedRuleMetadata</code>.</p> </note></p>
+    ) -> RusotoFuture<PutEvaluationsResponse, PutEvaluationsError> {
+        (**self).put_evaluations(input)
+    }
     fn put_organization_config_rule(
         &self,
         input: PutOrganizationConfigRuleRequest,
-    ) -> RusotoFuture<PutOrganizationConfigRuleResponse, PutOrganizationConfigRuleError>;
-
-    /// <p>Adds or updates the remediation configuration with a specific AWS Config rule with the selected target or action. The API creates the <code>RemediationConfiguration</code> object for the AWS Config rule. The AWS Config rule must already exist for you to add a remediation configuration. The target (SSM document) must exist and have permissions to use the target. </p>
+    ) -> RusotoFuture<PutOrganizationConfigRuleResponse, PutOrganizationConfigRuleError> {
+        (**self).put_organization_config_rule(input)
+    }
     fn put_remediation_configurations(
         &self,
         input: PutRemediationConfigurationsRequest,
-    ) -> RusotoFuture<PutRemediationConfigurationsResponse, PutRemediationConfigurationsError>;
-
-    /// <p>A remediation exception is when a specific resource is no longer considered for auto-remediation. This API adds a new exception or updates an exisiting exception for a specific resource with a specific AWS Config rule. </p>
+    ) -> RusotoFuture<PutRemediationConfigurationsResponse, PutRemediationConfigurationsError> {
+        (**self).put_remediation_configurations(input)
+    }
     fn put_remediation_exceptions(
         &self,
         input: PutRemediationExceptionsRequest,
-    ) -> RusotoFuture<PutRemediationExceptionsResponse, PutRemediationExceptionsError>;
-
-    /// <p><p>Creates and updates the retention configuration with details about retention period (number of days) that AWS Config stores your historical information. The API creates the <code>RetentionConfiguration</code> object and names the object as <b>default</b>. When you have a <code>RetentionConfiguration</code> object named <b>default</b>, calling the API modifies the default object. </p> <note> <p>Currently, AWS Config supports only one retention configuration per region in your account.</p> </note></p>
+    ) -> RusotoFuture<PutRemediationExceptionsResponse, PutRemediationExceptionsError> {
+        (**self).put_remediation_exceptions(input)
+    }
     fn put_retention_configuration(
         &self,
         input: PutRetentionConfigurationRequest,
-    ) -> RusotoFuture<PutRetentionConfigurationResponse, PutRetentionConfigurationError>;
-
-    /// <p>Accepts a structured query language (SQL) <code>SELECT</code> command, performs the corresponding search, and returns resource configurations matching the properties.</p> <p>For more information about query components, see the <a href="https://docs.aws.amazon.com/config/latest/developerguide/query-components.html"> <b>Query Components</b> </a> section in the AWS Config Developer Guide.</p>
+    ) -> RusotoFuture<PutRetentionConfigurationResponse, PutRetentionConfigurationError> {
+        (**self).put_retention_configuration(input)
+    }
     fn select_resource_config(
         &self,
         input: SelectResourceConfigRequest,
-    ) -> RusotoFuture<SelectResourceConfigResponse, SelectResourceConfigError>;
-
-    /// <p><p>Runs an on-demand evaluation for the specified AWS Config rules against the last known configuration state of the resources. Use <code>StartConfigRulesEvaluation</code> when you want to test that a rule you updated is working as expected. <code>StartConfigRulesEvaluation</code> does not re-record the latest configuration state for your resources. It re-runs an evaluation against the last known state of your resources. </p> <p>You can specify up to 25 AWS Config rules per request. </p> <p>An existing <code>StartConfigRulesEvaluation</code> call for the specified rules must complete before you can call the API again. If you chose to have AWS Config stream to an Amazon SNS topic, you will receive a <code>ConfigRuleEvaluationStarted</code> notification when the evaluation starts.</p> <note> <p>You don&#39;t need to call the <code>StartConfigRulesEvaluation</code> API to run an evaluation for a new rule. When you create a rule, AWS Config evaluates your resources against the rule automatically. </p> </note> <p>The <code>StartConfigRulesEvaluation</code> API is useful if you want to run on-demand evaluations, such as the following example:</p> <ol> <li> <p>You have a custom rule that evaluates your IAM resources every 24 hours.</p> </li> <li> <p>You update your Lambda function to add additional conditions to your rule.</p> </li> <li> <p>Instead of waiting for the next periodic evaluation, you call the <code>StartConfigRulesEvaluation</code> API.</p> </li> <li> <p>AWS Config invokes your Lambda function and evaluates your IAM resources.</p> </li> <li> <p>Your custom rule will still run periodic evaluations every 24 hours.</p> </li> </ol></p>
+    ) -> RusotoFuture<SelectResourceConfigResponse, SelectResourceConfigError> {
+        (**self).select_resource_config(input)
+    }
     fn start_config_rules_evaluation(
         &self,
         input: StartConfigRulesEvaluationRequest,
-    ) -> RusotoFuture<StartConfigRulesEvaluationResponse, StartConfigRulesEvaluationError>;
-
-    /// <p>Starts recording configurations of the AWS resources you have selected to record in your AWS account.</p> <p>You must have created at least one delivery channel to successfully start the configuration recorder.</p>
+    ) -> RusotoFuture<StartConfigRulesEvaluationResponse, StartConfigRulesEvaluationError> {
+        (**self).start_config_rules_evaluation(input)
+    }
     fn start_configuration_recorder(
         &self,
         input: StartConfigurationRecorderRequest,
-    ) -> RusotoFuture<(), StartConfigurationRecorderError>;
-
-    /// <p>Runs an on-demand remediation for the specified AWS Config rules against the last known remediation configuration. It runs an execution against the current state of your resources. Remediation execution is asynchronous.</p> <p>You can specify up to 100 resource keys per request. An existing StartRemediationExecution call for the specified resource keys must complete before you can call the API again.</p>
+    ) -> RusotoFuture<(), StartConfigurationRecorderError> {
+        (**self).start_configuration_recorder(input)
+    }
     fn start_remediation_execution(
         &self,
         input: StartRemediationExecutionRequest,
-    ) -> RusotoFuture<StartRemediationExecutionResponse, StartRemediationExecutionError>;
-
-    /// <p>Stops recording configurations of the AWS resources you have selected to record in your AWS account.</p>
+    ) -> RusotoFuture<StartRemediationExecutionResponse, StartRemediationExecutionError> {
+        (**self).start_remediation_execution(input)
+    }
     fn stop_configuration_recorder(
         &self,
         input: StopConfigurationRecorderRequest,
-    ) -> RusotoFuture<(), StopConfigurationRecorderError>;
-
-    /// <p>Associates the specified tags to a resource with the specified resourceArn. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
-    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError>;
-
-    /// <p>Deletes specified tags from a resource.</p>
-    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError>;
+    ) -> RusotoFuture<(), StopConfigurationRecorderError> {
+        (**self).stop_configuration_recorder(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
 }
+/// The error codes Config Service retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Config Service API.
 #[derive(Clone)]
 pub struct ConfigServiceClient {
@@ -6461,6 +13361,27 @@ impl ConfigServiceClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CONFIG`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ConfigServiceClient {
+        Self::new(region::Region::from_env_for_service("CONFIG"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `config`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ConfigServiceClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("config", "CONFIG"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6479,7 +13400,49 @@ impl ConfigServiceClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ConfigServiceClient {
-        ConfigServiceClient { client, region }
+        ConfigServiceClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "config")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "config", "config", "CONFIG")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ConfigService` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ConfigService + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ConfigServiceClient {
+    fn service_name(&self) -> &'static str {
+        "Config Service"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "config"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ConfigServiceClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -23,8 +23,165 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p>The channel used for grouping and filters. Only VOICE is supported.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Channel {
+    /// <p><code>VOICE</code></p>
+    Voice,
+    /// <p><code>CHAT</code></p>
+    Chat,
+    /// <p><code>TASK</code></p>
+    Task,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Channel {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Channel::Voice => "VOICE",
+            Channel::Chat => "CHAT",
+            Channel::Task => "TASK",
+            Channel::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Channel {
+    fn from(value: &'a str) -> Channel {
+        match value {
+            "VOICE" => Channel::Voice,
+            "CHAT" => Channel::Chat,
+            "TASK" => Channel::Task,
+            _ => Channel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Channel {
+    fn from(value: String) -> Channel {
+        Channel::from(value.as_str())
+    }
+}
+
+impl From<Channel> for String {
+    fn from(value: Channel) -> String {
+        match value {
+            Channel::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Channel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Channel {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Channel {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Channel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Channel::from(value))
+    }
+}
+
+/// <p>The Threshold to use to compare service level metrics to. Only "Less than" (LT) comparisons are supported.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    /// <p><code>LT</code></p>
+    Lt,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Comparison {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Comparison::Lt => "LT",
+            Comparison::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Comparison {
+    fn from(value: &'a str) -> Comparison {
+        match value {
+            "LT" => Comparison::Lt,
+            _ => Comparison::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Comparison {
+    fn from(value: String) -> Comparison {
+        Comparison::from(value.as_str())
+    }
+}
+
+impl From<Comparison> for String {
+    fn from(value: Comparison) -> String {
+        match value {
+            Comparison::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Comparison {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Comparison {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Comparison {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Comparison, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Comparison::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateUserRequest {
     /// <p>The unique identifier for the user account in the directory service directory used for identity management. If Amazon Connect is unable to access the existing directory, you can use the <code>DirectoryUserId</code> to authenticate users. If you include the parameter, it is assumed that Amazon Connect cannot access the directory. If the parameter is not included, the <code>UserIdentityInfo</code> is used to authenticate users from your existing directory.</p> <p>This parameter is required if you are using an existing directory for identity management in Amazon Connect when Amazon Connect cannot access your directory to authenticate users. If you are using SAML for identity management and include this parameter, an <code>InvalidRequestException</code> is returned.</p>
     #[serde(rename = "DirectoryUserId")]
@@ -59,6 +216,108 @@ pub struct CreateUserRequest {
     pub username: String,
 }
 
+impl CreateUserRequest {
+    /// Returns a builder for `CreateUserRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserRequestBuilder {
+        CreateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserRequest`](struct.CreateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserRequestBuilder {
+    directory_user_id: Option<String>,
+    hierarchy_group_id: Option<String>,
+    identity_info: Option<UserIdentityInfo>,
+    instance_id: Option<String>,
+    password: Option<String>,
+    phone_config: Option<UserPhoneConfig>,
+    routing_profile_id: Option<String>,
+    security_profile_ids: Option<Vec<String>>,
+    username: Option<String>,
+}
+
+impl CreateUserRequestBuilder {
+    /// Sets the [`directory_user_id`](struct.CreateUserRequest.html#structfield.directory_user_id) member.
+    pub fn directory_user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_user_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hierarchy_group_id`](struct.CreateUserRequest.html#structfield.hierarchy_group_id) member.
+    pub fn hierarchy_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hierarchy_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identity_info`](struct.CreateUserRequest.html#structfield.identity_info) member.
+    pub fn identity_info<T: Into<UserIdentityInfo>>(mut self, value: T) -> Self {
+        self.identity_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.CreateUserRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.CreateUserRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`phone_config`](struct.CreateUserRequest.html#structfield.phone_config) member.
+    pub fn phone_config<T: Into<UserPhoneConfig>>(mut self, value: T) -> Self {
+        self.phone_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`routing_profile_id`](struct.CreateUserRequest.html#structfield.routing_profile_id) member.
+    pub fn routing_profile_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.routing_profile_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_ids`](struct.CreateUserRequest.html#structfield.security_profile_ids) member.
+    pub fn security_profile_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_profile_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.CreateUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateUserRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserRequest {
+            directory_user_id: self.directory_user_id,
+            hierarchy_group_id: self.hierarchy_group_id,
+            identity_info: self.identity_info,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            password: self.password,
+            phone_config: self
+                .phone_config
+                .ok_or(::rusoto_core::BuildError::missing("phone_config"))?,
+            routing_profile_id: self
+                .routing_profile_id
+                .ok_or(::rusoto_core::BuildError::missing("routing_profile_id"))?,
+            security_profile_ids: self
+                .security_profile_ids
+                .ok_or(::rusoto_core::BuildError::missing("security_profile_ids"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateUserResponse {
@@ -82,16 +341,18 @@ pub struct Credentials {
     pub access_token: Option<String>,
     /// <p>A token generated with an expiration time for the session a user is logged in to Amazon Connect</p>
     #[serde(rename = "AccessTokenExpiration")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_token_expiration: Option<f64>,
+    pub access_token_expiration: Option<EpochTimestamp>,
     /// <p>Renews a token generated for a user to access the Amazon Connect instance.</p>
     #[serde(rename = "RefreshToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub refresh_token: Option<String>,
     /// <p>Renews the expiration timer for a generated token.</p>
     #[serde(rename = "RefreshTokenExpiration")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub refresh_token_expiration: Option<f64>,
+    pub refresh_token_expiration: Option<EpochTimestamp>,
 }
 
 /// <p>A <code>CurrentMetric</code> object that contains the Name and Unit for the metric.</p>
@@ -100,11 +361,11 @@ pub struct CurrentMetric {
     /// <p>The name of the metric.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<CurrentMetricName>,
     /// <p>The unit for the metric.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<Unit>,
 }
 
 /// <p>A <code>CurrentMetricData</code> object.</p>
@@ -121,6 +382,128 @@ pub struct CurrentMetricData {
     pub value: Option<f64>,
 }
 
+/// <p>The current metric names.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CurrentMetricName {
+    /// <p><code>AGENTS_ONLINE</code></p>
+    AgentsOnline,
+    /// <p><code>AGENTS_AVAILABLE</code></p>
+    AgentsAvailable,
+    /// <p><code>AGENTS_ON_CALL</code></p>
+    AgentsOnCall,
+    /// <p><code>AGENTS_NON_PRODUCTIVE</code></p>
+    AgentsNonProductive,
+    /// <p><code>AGENTS_AFTER_CONTACT_WORK</code></p>
+    AgentsAfterContactWork,
+    /// <p><code>AGENTS_ERROR</code></p>
+    AgentsError,
+    /// <p><code>AGENTS_STAFFED</code></p>
+    AgentsStaffed,
+    /// <p><code>CONTACTS_IN_QUEUE</code></p>
+    ContactsInQueue,
+    /// <p><code>OLDEST_CONTACT_AGE</code></p>
+    OldestContactAge,
+    /// <p><code>CONTACTS_SCHEDULED</code></p>
+    ContactsScheduled,
+    /// <p><code>AGENTS_ON_CONTACT</code></p>
+    AgentsOnContact,
+    /// <p><code>SLOTS_ACTIVE</code></p>
+    SlotsActive,
+    /// <p><code>SLOTS_AVAILABLE</code></p>
+    SlotsAvailable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CurrentMetricName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CurrentMetricName::AgentsOnline => "AGENTS_ONLINE",
+            CurrentMetricName::AgentsAvailable => "AGENTS_AVAILABLE",
+            CurrentMetricName::AgentsOnCall => "AGENTS_ON_CALL",
+            CurrentMetricName::AgentsNonProductive => "AGENTS_NON_PRODUCTIVE",
+            CurrentMetricName::AgentsAfterContactWork => "AGENTS_AFTER_CONTACT_WORK",
+            CurrentMetricName::AgentsError => "AGENTS_ERROR",
+            CurrentMetricName::AgentsStaffed => "AGENTS_STAFFED",
+            CurrentMetricName::ContactsInQueue => "CONTACTS_IN_QUEUE",
+            CurrentMetricName::OldestContactAge => "OLDEST_CONTACT_AGE",
+            CurrentMetricName::ContactsScheduled => "CONTACTS_SCHEDULED",
+            CurrentMetricName::AgentsOnContact => "AGENTS_ON_CONTACT",
+            CurrentMetricName::SlotsActive => "SLOTS_ACTIVE",
+            CurrentMetricName::SlotsAvailable => "SLOTS_AVAILABLE",
+            CurrentMetricName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CurrentMetricName {
+    fn from(value: &'a str) -> CurrentMetricName {
+        match value {
+            "AGENTS_ONLINE" => CurrentMetricName::AgentsOnline,
+            "AGENTS_AVAILABLE" => CurrentMetricName::AgentsAvailable,
+            "AGENTS_ON_CALL" => CurrentMetricName::AgentsOnCall,
+            "AGENTS_NON_PRODUCTIVE" => CurrentMetricName::AgentsNonProductive,
+            "AGENTS_AFTER_CONTACT_WORK" => CurrentMetricName::AgentsAfterContactWork,
+            "AGENTS_ERROR" => CurrentMetricName::AgentsError,
+            "AGENTS_STAFFED" => CurrentMetricName::AgentsStaffed,
+            "CONTACTS_IN_QUEUE" => CurrentMetricName::ContactsInQueue,
+            "OLDEST_CONTACT_AGE" => CurrentMetricName::OldestContactAge,
+            "CONTACTS_SCHEDULED" => CurrentMetricName::ContactsScheduled,
+            "AGENTS_ON_CONTACT" => CurrentMetricName::AgentsOnContact,
+            "SLOTS_ACTIVE" => CurrentMetricName::SlotsActive,
+            "SLOTS_AVAILABLE" => CurrentMetricName::SlotsAvailable,
+            _ => CurrentMetricName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CurrentMetricName {
+    fn from(value: String) -> CurrentMetricName {
+        CurrentMetricName::from(value.as_str())
+    }
+}
+
+impl From<CurrentMetricName> for String {
+    fn from(value: CurrentMetricName) -> String {
+        match value {
+            CurrentMetricName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CurrentMetricName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CurrentMetricName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CurrentMetricName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CurrentMetricName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CurrentMetricName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CurrentMetricName::from(value))
+    }
+}
+
 /// <p>A <code>CurrentMetricResult</code> object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -145,6 +528,46 @@ pub struct DeleteUserRequest {
     pub user_id: String,
 }
 
+impl DeleteUserRequest {
+    /// Returns a builder for `DeleteUserRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserRequestBuilder {
+        DeleteUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserRequest`](struct.DeleteUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserRequestBuilder {
+    instance_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl DeleteUserRequestBuilder {
+    /// Sets the [`instance_id`](struct.DeleteUserRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.DeleteUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteUserRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeUserHierarchyGroupRequest {
     /// <p>The identifier for the hierarchy group to return.</p>
@@ -155,6 +578,48 @@ pub struct DescribeUserHierarchyGroupRequest {
     pub instance_id: String,
 }
 
+impl DescribeUserHierarchyGroupRequest {
+    /// Returns a builder for `DescribeUserHierarchyGroupRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserHierarchyGroupRequestBuilder {
+        DescribeUserHierarchyGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserHierarchyGroupRequest`](struct.DescribeUserHierarchyGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserHierarchyGroupRequestBuilder {
+    hierarchy_group_id: Option<String>,
+    instance_id: Option<String>,
+}
+
+impl DescribeUserHierarchyGroupRequestBuilder {
+    /// Sets the [`hierarchy_group_id`](struct.DescribeUserHierarchyGroupRequest.html#structfield.hierarchy_group_id) member.
+    pub fn hierarchy_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hierarchy_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.DescribeUserHierarchyGroupRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserHierarchyGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeUserHierarchyGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUserHierarchyGroupRequest {
+            hierarchy_group_id: self
+                .hierarchy_group_id
+                .ok_or(::rusoto_core::BuildError::missing("hierarchy_group_id"))?,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUserHierarchyGroupResponse {
@@ -171,6 +636,39 @@ pub struct DescribeUserHierarchyStructureRequest {
     pub instance_id: String,
 }
 
+impl DescribeUserHierarchyStructureRequest {
+    /// Returns a builder for `DescribeUserHierarchyStructureRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserHierarchyStructureRequestBuilder {
+        DescribeUserHierarchyStructureRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserHierarchyStructureRequest`](struct.DescribeUserHierarchyStructureRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserHierarchyStructureRequestBuilder {
+    instance_id: Option<String>,
+}
+
+impl DescribeUserHierarchyStructureRequestBuilder {
+    /// Sets the [`instance_id`](struct.DescribeUserHierarchyStructureRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserHierarchyStructureRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeUserHierarchyStructureRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeUserHierarchyStructureRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUserHierarchyStructureResponse {
@@ -190,6 +688,46 @@ pub struct DescribeUserRequest {
     pub user_id: String,
 }
 
+impl DescribeUserRequest {
+    /// Returns a builder for `DescribeUserRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserRequestBuilder {
+        DescribeUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserRequest`](struct.DescribeUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserRequestBuilder {
+    instance_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl DescribeUserRequestBuilder {
+    /// Sets the [`instance_id`](struct.DescribeUserRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.DescribeUserRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeUserRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUserRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUserResponse {
@@ -206,7 +744,7 @@ pub struct Dimensions {
     /// <p>The channel used for grouping and filters. Only VOICE is supported.</p>
     #[serde(rename = "Channel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub channel: Option<String>,
+    pub channel: Option<Channel>,
     /// <p>A <code>QueueReference</code> object used as one part of dimension for the metrics results.</p>
     #[serde(rename = "Queue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -219,7 +757,7 @@ pub struct Filters {
     /// <p>The Channel to use as a filter for the metrics returned. Only VOICE is supported.</p>
     #[serde(rename = "Channels")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub channels: Option<Vec<String>>,
+    pub channels: Option<Vec<Channel>>,
     /// <p>A list of up to 100 queue IDs or queue ARNs to use to filter the metrics retrieved. You can include both IDs and ARNs in a request.</p>
     #[serde(rename = "Queues")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -236,6 +774,48 @@ pub struct GetContactAttributesRequest {
     pub instance_id: String,
 }
 
+impl GetContactAttributesRequest {
+    /// Returns a builder for `GetContactAttributesRequest`, checking its required members when built.
+    pub fn builder() -> GetContactAttributesRequestBuilder {
+        GetContactAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetContactAttributesRequest`](struct.GetContactAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetContactAttributesRequestBuilder {
+    initial_contact_id: Option<String>,
+    instance_id: Option<String>,
+}
+
+impl GetContactAttributesRequestBuilder {
+    /// Sets the [`initial_contact_id`](struct.GetContactAttributesRequest.html#structfield.initial_contact_id) member.
+    pub fn initial_contact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.initial_contact_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.GetContactAttributesRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetContactAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetContactAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(GetContactAttributesRequest {
+            initial_contact_id: self
+                .initial_contact_id
+                .ok_or(::rusoto_core::BuildError::missing("initial_contact_id"))?,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetContactAttributesResponse {
@@ -256,7 +836,7 @@ pub struct GetCurrentMetricDataRequest {
     /// <p>The grouping applied to the metrics returned. For example, when grouped by QUEUE, the metrics returned apply to each queue rather than aggregated for all queues. If you group by CHANNEL, you should include a Channels filter. The only supported channel is VOICE.</p> <p>If no <code>Grouping</code> is included in the request, a summary of <code>CurrentMetrics</code> is returned.</p>
     #[serde(rename = "Groupings")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub groupings: Option<Vec<String>>,
+    pub groupings: Option<Vec<Grouping>>,
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
     pub instance_id: String,
@@ -270,13 +850,90 @@ pub struct GetCurrentMetricDataRequest {
     pub next_token: Option<String>,
 }
 
+impl GetCurrentMetricDataRequest {
+    /// Returns a builder for `GetCurrentMetricDataRequest`, checking its required members when built.
+    pub fn builder() -> GetCurrentMetricDataRequestBuilder {
+        GetCurrentMetricDataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCurrentMetricDataRequest`](struct.GetCurrentMetricDataRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCurrentMetricDataRequestBuilder {
+    current_metrics: Option<Vec<CurrentMetric>>,
+    filters: Option<Filters>,
+    groupings: Option<Vec<Grouping>>,
+    instance_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetCurrentMetricDataRequestBuilder {
+    /// Sets the [`current_metrics`](struct.GetCurrentMetricDataRequest.html#structfield.current_metrics) member.
+    pub fn current_metrics<T: Into<Vec<CurrentMetric>>>(mut self, value: T) -> Self {
+        self.current_metrics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.GetCurrentMetricDataRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Filters>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`groupings`](struct.GetCurrentMetricDataRequest.html#structfield.groupings) member.
+    pub fn groupings<T: Into<Vec<Grouping>>>(mut self, value: T) -> Self {
+        self.groupings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.GetCurrentMetricDataRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetCurrentMetricDataRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetCurrentMetricDataRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCurrentMetricDataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCurrentMetricDataRequest, ::rusoto_core::BuildError> {
+        Ok(GetCurrentMetricDataRequest {
+            current_metrics: self
+                .current_metrics
+                .ok_or(::rusoto_core::BuildError::missing("current_metrics"))?,
+            filters: self
+                .filters
+                .ok_or(::rusoto_core::BuildError::missing("filters"))?,
+            groupings: self.groupings,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCurrentMetricDataResponse {
     /// <p>The time at which <code>CurrentMetricData</code> was retrieved and cached for pagination.</p>
     #[serde(rename = "DataSnapshotTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_snapshot_time: Option<f64>,
+    pub data_snapshot_time: Option<EpochTimestamp>,
     /// <p>A list of <code>CurrentMetricResult</code> objects organized by <code>Dimensions</code> combining with <code>CurrentMetricDataCollections</code>.</p> <p> <code>Dimensions</code> is the resourceId specified in the <code>Filters</code> of the request. </p> <p> <code>Collections</code> is a list of <code>CurrentMetricData</code> objects with corresponding values to the <code>CurrentMetrics</code> specified in the request.</p> <p>If no <code>Grouping</code> is specified in the request, <code>Collections</code> is a summary for the <code>CurrentMetric</code> returned.</p>
     #[serde(rename = "MetricResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -294,6 +951,38 @@ pub struct GetFederationTokenRequest {
     pub instance_id: String,
 }
 
+impl GetFederationTokenRequest {
+    /// Returns a builder for `GetFederationTokenRequest`, checking its required members when built.
+    pub fn builder() -> GetFederationTokenRequestBuilder {
+        GetFederationTokenRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFederationTokenRequest`](struct.GetFederationTokenRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFederationTokenRequestBuilder {
+    instance_id: Option<String>,
+}
+
+impl GetFederationTokenRequestBuilder {
+    /// Sets the [`instance_id`](struct.GetFederationTokenRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFederationTokenRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetFederationTokenRequest, ::rusoto_core::BuildError> {
+        Ok(GetFederationTokenRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFederationTokenResponse {
@@ -307,14 +996,15 @@ pub struct GetFederationTokenResponse {
 pub struct GetMetricDataRequest {
     /// <p>The timestamp, in UNIX Epoch time format, at which to end the reporting interval for the retrieval of historical metrics data. The time must be specified using an interval of 5 minutes, such as 11:00, 11:05, 11:10, and must be later than the <code>StartTime</code> timestamp.</p> <p>The time range between <code>StartTime</code> and <code>EndTime</code> must be less than 24 hours.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p>A <code>Filters</code> object that contains a list of queue IDs or queue ARNs, up to 100, or a list of Channels to use to filter the metrics returned in the response. Metric data is retrieved only for the resources associated with the IDs, ARNs, or Channels included in the filter. You can use both IDs and ARNs together in a request. Only VOICE is supported for Channel.</p> <p>To find the ARN for a queue, open the queue you want to use in the Amazon Connect Queue editor. The ARN for the queue is displayed in the address bar as part of the URL. For example, the queue ARN is the set of characters at the end of the URL, after 'id=' such as <code>arn:aws:connect:us-east-1:270923740243:instance/78fb859d-1b7d-44b1-8aa3-12f0835c5855/queue/1d1a4575-9618-40ab-bbeb-81e45795fe61</code>. The queue ID is also included in the URL, and is the string after 'queue/'.</p>
     #[serde(rename = "Filters")]
     pub filters: Filters,
     /// <p>The grouping applied to the metrics returned. For example, when results are grouped by queueId, the metrics returned are grouped by queue. The values returned apply to the metrics for each queue rather than aggregated for all queues.</p> <p>The current version supports grouping by Queue</p> <p>If no <code>Grouping</code> is included in the request, a summary of <code>HistoricalMetrics</code> for all queues is returned.</p>
     #[serde(rename = "Groupings")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub groupings: Option<Vec<String>>,
+    pub groupings: Option<Vec<Grouping>>,
     /// <p><p>A list of <code>HistoricalMetric</code> objects that contain the metrics to retrieve with the request.</p> <p>A <code>HistoricalMetric</code> object contains: <code>HistoricalMetricName</code>, <code>Statistic</code>, <code>Threshold</code>, and <code>Unit</code>.</p> <p>You must list each metric to retrieve data for in the request. For each historical metric you include in the request, you must include a <code>Unit</code> and a <code>Statistic</code>. </p> <p>The following historical metrics are available:</p> <dl> <dt>CONTACTS<em>QUEUED</dt> <dd> <p>Unit: COUNT</p> <p>Statistic: SUM</p> </dd> <dt>CONTACTS</em>HANDLED</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CONTACTS<em>ABANDONED</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CONTACTS</em>CONSULTED</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CONTACTS<em>AGENT</em>HUNG<em>UP</em>FIRST</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CONTACTS<em>HANDLED</em>INCOMING</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CONTACTS<em>HANDLED</em>OUTBOUND</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CONTACTS<em>HOLD</em>ABANDONS</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CONTACTS<em>TRANSFERRED</em>IN</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CONTACTS<em>TRANSFERRED</em>OUT</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CONTACTS<em>TRANSFERRED</em>IN<em>FROM</em>QUEUE</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CONTACTS<em>TRANSFERRED</em>OUT<em>FROM</em>QUEUE</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CALLBACK<em>CONTACTS</em>HANDLED</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CALLBACK<em>CONTACTS</em>HANDLED</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>API<em>CONTACTS</em>HANDLED</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>CONTACTS<em>MISSED</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>OCCUPANCY</dt> <dd> <p>Unit: PERCENT</p> <p>Statistics: AVG</p> </dd> <dt>HANDLE</em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistics: AVG</p> </dd> <dt>AFTER<em>CONTACT</em>WORK<em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistics: AVG</p> </dd> <dt>QUEUED</em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistics: MAX</p> </dd> <dt>ABANDON<em>TIME</dt> <dd> <p>Unit: COUNT</p> <p>Statistics: SUM</p> </dd> <dt>QUEUE</em>ANSWER<em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistics: AVG</p> </dd> <dt>HOLD</em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistics: AVG</p> </dd> <dt>INTERACTION<em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistics: AVG</p> </dd> <dt>INTERACTION</em>AND<em>HOLD</em>TIME</dt> <dd> <p>Unit: SECONDS</p> <p>Statistics: AVG</p> </dd> <dt>SERVICE_LEVEL</dt> <dd> <p>Unit: PERCENT</p> <p>Statistics: AVG</p> <p>Threshold: Only &quot;Less than&quot; comparisons are supported, with the following service level thresholds: 15, 20, 25, 30, 45, 60, 90, 120, 180, 240, 300, 600</p> </dd> </dl></p>
     #[serde(rename = "HistoricalMetrics")]
     pub historical_metrics: Vec<HistoricalMetric>,
@@ -331,7 +1021,102 @@ pub struct GetMetricDataRequest {
     pub next_token: Option<String>,
     /// <p>The timestamp, in UNIX Epoch time format, at which to start the reporting interval for the retrieval of historical metrics data. The time must be specified using a multiple of 5 minutes, such as 10:05, 10:10, 10:15.</p> <p> <code>StartTime</code> cannot be earlier than 24 hours before the time of the request. Historical metrics are available in Amazon Connect only for 24 hours.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
+}
+
+impl GetMetricDataRequest {
+    /// Returns a builder for `GetMetricDataRequest`, checking its required members when built.
+    pub fn builder() -> GetMetricDataRequestBuilder {
+        GetMetricDataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetMetricDataRequest`](struct.GetMetricDataRequest.html).
+#[derive(Default, Debug)]
+pub struct GetMetricDataRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    filters: Option<Filters>,
+    groupings: Option<Vec<Grouping>>,
+    historical_metrics: Option<Vec<HistoricalMetric>>,
+    instance_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl GetMetricDataRequestBuilder {
+    /// Sets the [`end_time`](struct.GetMetricDataRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.GetMetricDataRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Filters>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`groupings`](struct.GetMetricDataRequest.html#structfield.groupings) member.
+    pub fn groupings<T: Into<Vec<Grouping>>>(mut self, value: T) -> Self {
+        self.groupings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`historical_metrics`](struct.GetMetricDataRequest.html#structfield.historical_metrics) member.
+    pub fn historical_metrics<T: Into<Vec<HistoricalMetric>>>(mut self, value: T) -> Self {
+        self.historical_metrics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.GetMetricDataRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetMetricDataRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetMetricDataRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetMetricDataRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMetricDataRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMetricDataRequest, ::rusoto_core::BuildError> {
+        Ok(GetMetricDataRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            filters: self
+                .filters
+                .ok_or(::rusoto_core::BuildError::missing("filters"))?,
+            groupings: self.groupings,
+            historical_metrics: self
+                .historical_metrics
+                .ok_or(::rusoto_core::BuildError::missing("historical_metrics"))?,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -347,6 +1132,92 @@ pub struct GetMetricDataResponse {
     pub next_token: Option<String>,
 }
 
+/// <p>The grouping applied to the metrics returned. For example, when grouped by QUEUE, the metrics returned apply to each queue rather than aggregated for all queues. If you group by CHANNEL, you should include a Channels filter. The only supported channel is VOICE.</p> <p>If no <code>Grouping</code> is included in the request, a summary of <code>CurrentMetrics</code> is returned.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Grouping {
+    /// <p><code>QUEUE</code></p>
+    Queue,
+    /// <p><code>CHANNEL</code></p>
+    Channel,
+    /// <p><code>ROUTING_PROFILE</code></p>
+    RoutingProfile,
+    /// <p><code>ROUTING_STEP_EXPRESSION</code></p>
+    RoutingStepExpression,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Grouping {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Grouping::Queue => "QUEUE",
+            Grouping::Channel => "CHANNEL",
+            Grouping::RoutingProfile => "ROUTING_PROFILE",
+            Grouping::RoutingStepExpression => "ROUTING_STEP_EXPRESSION",
+            Grouping::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Grouping {
+    fn from(value: &'a str) -> Grouping {
+        match value {
+            "QUEUE" => Grouping::Queue,
+            "CHANNEL" => Grouping::Channel,
+            "ROUTING_PROFILE" => Grouping::RoutingProfile,
+            "ROUTING_STEP_EXPRESSION" => Grouping::RoutingStepExpression,
+            _ => Grouping::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Grouping {
+    fn from(value: String) -> Grouping {
+        Grouping::from(value.as_str())
+    }
+}
+
+impl From<Grouping> for String {
+    fn from(value: Grouping) -> String {
+        match value {
+            Grouping::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Grouping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Grouping {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Grouping {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Grouping {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Grouping, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Grouping::from(value))
+    }
+}
+
 /// <p>A <code>HierarchyGroup</code> object that contains information about a hierarchy group in your Amazon Connect instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -467,11 +1338,11 @@ pub struct HistoricalMetric {
     /// <p>The name of the historical metric.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<HistoricalMetricName>,
     /// <p>The statistic for the metric.</p>
     #[serde(rename = "Statistic")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub statistic: Option<String>,
+    pub statistic: Option<Statistic>,
     /// <p>The threshold for the metric, used with service level metrics.</p>
     #[serde(rename = "Threshold")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -479,7 +1350,7 @@ pub struct HistoricalMetric {
     /// <p>The unit for the metric.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<Unit>,
 }
 
 /// <p>A <code>HistoricalMetricData</code> object than contains a <code>Metric</code> and a <code>Value</code>.</p>
@@ -496,6 +1367,184 @@ pub struct HistoricalMetricData {
     pub value: Option<f64>,
 }
 
+/// <p>The historical metric names.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HistoricalMetricName {
+    /// <p><code>CONTACTS_QUEUED</code></p>
+    ContactsQueued,
+    /// <p><code>CONTACTS_HANDLED</code></p>
+    ContactsHandled,
+    /// <p><code>CONTACTS_ABANDONED</code></p>
+    ContactsAbandoned,
+    /// <p><code>CONTACTS_CONSULTED</code></p>
+    ContactsConsulted,
+    /// <p><code>CONTACTS_AGENT_HUNG_UP_FIRST</code></p>
+    ContactsAgentHungUpFirst,
+    /// <p><code>CONTACTS_HANDLED_INCOMING</code></p>
+    ContactsHandledIncoming,
+    /// <p><code>CONTACTS_HANDLED_OUTBOUND</code></p>
+    ContactsHandledOutbound,
+    /// <p><code>CONTACTS_HOLD_ABANDONS</code></p>
+    ContactsHoldAbandons,
+    /// <p><code>CONTACTS_TRANSFERRED_IN</code></p>
+    ContactsTransferredIn,
+    /// <p><code>CONTACTS_TRANSFERRED_OUT</code></p>
+    ContactsTransferredOut,
+    /// <p><code>CONTACTS_TRANSFERRED_IN_FROM_QUEUE</code></p>
+    ContactsTransferredInFromQueue,
+    /// <p><code>CONTACTS_TRANSFERRED_OUT_FROM_QUEUE</code></p>
+    ContactsTransferredOutFromQueue,
+    /// <p><code>CONTACTS_MISSED</code></p>
+    ContactsMissed,
+    /// <p><code>CALLBACK_CONTACTS_HANDLED</code></p>
+    CallbackContactsHandled,
+    /// <p><code>API_CONTACTS_HANDLED</code></p>
+    ApiContactsHandled,
+    /// <p><code>OCCUPANCY</code></p>
+    Occupancy,
+    /// <p><code>HANDLE_TIME</code></p>
+    HandleTime,
+    /// <p><code>AFTER_CONTACT_WORK_TIME</code></p>
+    AfterContactWorkTime,
+    /// <p><code>QUEUED_TIME</code></p>
+    QueuedTime,
+    /// <p><code>ABANDON_TIME</code></p>
+    AbandonTime,
+    /// <p><code>QUEUE_ANSWER_TIME</code></p>
+    QueueAnswerTime,
+    /// <p><code>HOLD_TIME</code></p>
+    HoldTime,
+    /// <p><code>INTERACTION_TIME</code></p>
+    InteractionTime,
+    /// <p><code>INTERACTION_AND_HOLD_TIME</code></p>
+    InteractionAndHoldTime,
+    /// <p><code>SERVICE_LEVEL</code></p>
+    ServiceLevel,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HistoricalMetricName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HistoricalMetricName::ContactsQueued => "CONTACTS_QUEUED",
+            HistoricalMetricName::ContactsHandled => "CONTACTS_HANDLED",
+            HistoricalMetricName::ContactsAbandoned => "CONTACTS_ABANDONED",
+            HistoricalMetricName::ContactsConsulted => "CONTACTS_CONSULTED",
+            HistoricalMetricName::ContactsAgentHungUpFirst => "CONTACTS_AGENT_HUNG_UP_FIRST",
+            HistoricalMetricName::ContactsHandledIncoming => "CONTACTS_HANDLED_INCOMING",
+            HistoricalMetricName::ContactsHandledOutbound => "CONTACTS_HANDLED_OUTBOUND",
+            HistoricalMetricName::ContactsHoldAbandons => "CONTACTS_HOLD_ABANDONS",
+            HistoricalMetricName::ContactsTransferredIn => "CONTACTS_TRANSFERRED_IN",
+            HistoricalMetricName::ContactsTransferredOut => "CONTACTS_TRANSFERRED_OUT",
+            HistoricalMetricName::ContactsTransferredInFromQueue => {
+                "CONTACTS_TRANSFERRED_IN_FROM_QUEUE"
+            }
+            HistoricalMetricName::ContactsTransferredOutFromQueue => {
+                "CONTACTS_TRANSFERRED_OUT_FROM_QUEUE"
+            }
+            HistoricalMetricName::ContactsMissed => "CONTACTS_MISSED",
+            HistoricalMetricName::CallbackContactsHandled => "CALLBACK_CONTACTS_HANDLED",
+            HistoricalMetricName::ApiContactsHandled => "API_CONTACTS_HANDLED",
+            HistoricalMetricName::Occupancy => "OCCUPANCY",
+            HistoricalMetricName::HandleTime => "HANDLE_TIME",
+            HistoricalMetricName::AfterContactWorkTime => "AFTER_CONTACT_WORK_TIME",
+            HistoricalMetricName::QueuedTime => "QUEUED_TIME",
+            HistoricalMetricName::AbandonTime => "ABANDON_TIME",
+            HistoricalMetricName::QueueAnswerTime => "QUEUE_ANSWER_TIME",
+            HistoricalMetricName::HoldTime => "HOLD_TIME",
+            HistoricalMetricName::InteractionTime => "INTERACTION_TIME",
+            HistoricalMetricName::InteractionAndHoldTime => "INTERACTION_AND_HOLD_TIME",
+            HistoricalMetricName::ServiceLevel => "SERVICE_LEVEL",
+            HistoricalMetricName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HistoricalMetricName {
+    fn from(value: &'a str) -> HistoricalMetricName {
+        match value {
+            "CONTACTS_QUEUED" => HistoricalMetricName::ContactsQueued,
+            "CONTACTS_HANDLED" => HistoricalMetricName::ContactsHandled,
+            "CONTACTS_ABANDONED" => HistoricalMetricName::ContactsAbandoned,
+            "CONTACTS_CONSULTED" => HistoricalMetricName::ContactsConsulted,
+            "CONTACTS_AGENT_HUNG_UP_FIRST" => HistoricalMetricName::ContactsAgentHungUpFirst,
+            "CONTACTS_HANDLED_INCOMING" => HistoricalMetricName::ContactsHandledIncoming,
+            "CONTACTS_HANDLED_OUTBOUND" => HistoricalMetricName::ContactsHandledOutbound,
+            "CONTACTS_HOLD_ABANDONS" => HistoricalMetricName::ContactsHoldAbandons,
+            "CONTACTS_TRANSFERRED_IN" => HistoricalMetricName::ContactsTransferredIn,
+            "CONTACTS_TRANSFERRED_OUT" => HistoricalMetricName::ContactsTransferredOut,
+            "CONTACTS_TRANSFERRED_IN_FROM_QUEUE" => {
+                HistoricalMetricName::ContactsTransferredInFromQueue
+            }
+            "CONTACTS_TRANSFERRED_OUT_FROM_QUEUE" => {
+                HistoricalMetricName::ContactsTransferredOutFromQueue
+            }
+            "CONTACTS_MISSED" => HistoricalMetricName::ContactsMissed,
+            "CALLBACK_CONTACTS_HANDLED" => HistoricalMetricName::CallbackContactsHandled,
+            "API_CONTACTS_HANDLED" => HistoricalMetricName::ApiContactsHandled,
+            "OCCUPANCY" => HistoricalMetricName::Occupancy,
+            "HANDLE_TIME" => HistoricalMetricName::HandleTime,
+            "AFTER_CONTACT_WORK_TIME" => HistoricalMetricName::AfterContactWorkTime,
+            "QUEUED_TIME" => HistoricalMetricName::QueuedTime,
+            "ABANDON_TIME" => HistoricalMetricName::AbandonTime,
+            "QUEUE_ANSWER_TIME" => HistoricalMetricName::QueueAnswerTime,
+            "HOLD_TIME" => HistoricalMetricName::HoldTime,
+            "INTERACTION_TIME" => HistoricalMetricName::InteractionTime,
+            "INTERACTION_AND_HOLD_TIME" => HistoricalMetricName::InteractionAndHoldTime,
+            "SERVICE_LEVEL" => HistoricalMetricName::ServiceLevel,
+            _ => HistoricalMetricName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HistoricalMetricName {
+    fn from(value: String) -> HistoricalMetricName {
+        HistoricalMetricName::from(value.as_str())
+    }
+}
+
+impl From<HistoricalMetricName> for String {
+    fn from(value: HistoricalMetricName) -> String {
+        match value {
+            HistoricalMetricName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HistoricalMetricName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HistoricalMetricName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HistoricalMetricName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HistoricalMetricName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HistoricalMetricName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HistoricalMetricName::from(value))
+    }
+}
+
 /// <p>The metrics data returned from a <code>GetMetricData</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -525,6 +1574,54 @@ pub struct ListRoutingProfilesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListRoutingProfilesRequest {
+    /// Returns a builder for `ListRoutingProfilesRequest`, checking its required members when built.
+    pub fn builder() -> ListRoutingProfilesRequestBuilder {
+        ListRoutingProfilesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRoutingProfilesRequest`](struct.ListRoutingProfilesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRoutingProfilesRequestBuilder {
+    instance_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListRoutingProfilesRequestBuilder {
+    /// Sets the [`instance_id`](struct.ListRoutingProfilesRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListRoutingProfilesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListRoutingProfilesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRoutingProfilesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListRoutingProfilesRequest, ::rusoto_core::BuildError> {
+        Ok(ListRoutingProfilesRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRoutingProfilesResponse {
@@ -553,6 +1650,54 @@ pub struct ListSecurityProfilesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListSecurityProfilesRequest {
+    /// Returns a builder for `ListSecurityProfilesRequest`, checking its required members when built.
+    pub fn builder() -> ListSecurityProfilesRequestBuilder {
+        ListSecurityProfilesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSecurityProfilesRequest`](struct.ListSecurityProfilesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSecurityProfilesRequestBuilder {
+    instance_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListSecurityProfilesRequestBuilder {
+    /// Sets the [`instance_id`](struct.ListSecurityProfilesRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListSecurityProfilesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSecurityProfilesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSecurityProfilesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSecurityProfilesRequest, ::rusoto_core::BuildError> {
+        Ok(ListSecurityProfilesRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSecurityProfilesResponse {
@@ -581,6 +1726,54 @@ pub struct ListUserHierarchyGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListUserHierarchyGroupsRequest {
+    /// Returns a builder for `ListUserHierarchyGroupsRequest`, checking its required members when built.
+    pub fn builder() -> ListUserHierarchyGroupsRequestBuilder {
+        ListUserHierarchyGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUserHierarchyGroupsRequest`](struct.ListUserHierarchyGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUserHierarchyGroupsRequestBuilder {
+    instance_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListUserHierarchyGroupsRequestBuilder {
+    /// Sets the [`instance_id`](struct.ListUserHierarchyGroupsRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListUserHierarchyGroupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUserHierarchyGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUserHierarchyGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListUserHierarchyGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(ListUserHierarchyGroupsRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListUserHierarchyGroupsResponse {
@@ -609,6 +1802,52 @@ pub struct ListUsersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListUsersRequest {
+    /// Returns a builder for `ListUsersRequest`, checking its required members when built.
+    pub fn builder() -> ListUsersRequestBuilder {
+        ListUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUsersRequest`](struct.ListUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUsersRequestBuilder {
+    instance_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListUsersRequestBuilder {
+    /// Sets the [`instance_id`](struct.ListUsersRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListUsersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUsersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListUsersRequest, ::rusoto_core::BuildError> {
+        Ok(ListUsersRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListUsersResponse {
@@ -622,6 +1861,84 @@ pub struct ListUsersResponse {
     pub user_summary_list: Option<Vec<UserSummary>>,
 }
 
+/// <p>The phone type selected for the user, either Soft phone or Desk phone.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PhoneType {
+    /// <p><code>SOFT_PHONE</code></p>
+    SoftPhone,
+    /// <p><code>DESK_PHONE</code></p>
+    DeskPhone,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PhoneType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PhoneType::SoftPhone => "SOFT_PHONE",
+            PhoneType::DeskPhone => "DESK_PHONE",
+            PhoneType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PhoneType {
+    fn from(value: &'a str) -> PhoneType {
+        match value {
+            "SOFT_PHONE" => PhoneType::SoftPhone,
+            "DESK_PHONE" => PhoneType::DeskPhone,
+            _ => PhoneType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PhoneType {
+    fn from(value: String) -> PhoneType {
+        PhoneType::from(value.as_str())
+    }
+}
+
+impl From<PhoneType> for String {
+    fn from(value: PhoneType) -> String {
+        match value {
+            PhoneType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PhoneType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PhoneType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PhoneType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PhoneType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PhoneType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PhoneType::from(value))
+    }
+}
+
 /// <p>A QueueReference object that contains the the QueueId and ARN for the queue resource for which metrics are returned.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -701,6 +2018,93 @@ pub struct StartOutboundVoiceContactRequest {
     pub source_phone_number: Option<String>,
 }
 
+impl StartOutboundVoiceContactRequest {
+    /// Returns a builder for `StartOutboundVoiceContactRequest`, checking its required members when built.
+    pub fn builder() -> StartOutboundVoiceContactRequestBuilder {
+        StartOutboundVoiceContactRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartOutboundVoiceContactRequest`](struct.StartOutboundVoiceContactRequest.html).
+#[derive(Default, Debug)]
+pub struct StartOutboundVoiceContactRequestBuilder {
+    attributes: Option<::std::collections::HashMap<String, String>>,
+    client_token: Option<String>,
+    contact_flow_id: Option<String>,
+    destination_phone_number: Option<String>,
+    instance_id: Option<String>,
+    queue_id: Option<String>,
+    source_phone_number: Option<String>,
+}
+
+impl StartOutboundVoiceContactRequestBuilder {
+    /// Sets the [`attributes`](struct.StartOutboundVoiceContactRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_token`](struct.StartOutboundVoiceContactRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`contact_flow_id`](struct.StartOutboundVoiceContactRequest.html#structfield.contact_flow_id) member.
+    pub fn contact_flow_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.contact_flow_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_phone_number`](struct.StartOutboundVoiceContactRequest.html#structfield.destination_phone_number) member.
+    pub fn destination_phone_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_phone_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.StartOutboundVoiceContactRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`queue_id`](struct.StartOutboundVoiceContactRequest.html#structfield.queue_id) member.
+    pub fn queue_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.queue_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_phone_number`](struct.StartOutboundVoiceContactRequest.html#structfield.source_phone_number) member.
+    pub fn source_phone_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_phone_number = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartOutboundVoiceContactRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartOutboundVoiceContactRequest, ::rusoto_core::BuildError> {
+        Ok(StartOutboundVoiceContactRequest {
+            attributes: self.attributes,
+            client_token: self.client_token,
+            contact_flow_id: self
+                .contact_flow_id
+                .ok_or(::rusoto_core::BuildError::missing("contact_flow_id"))?,
+            destination_phone_number: self.destination_phone_number.ok_or(
+                ::rusoto_core::BuildError::missing("destination_phone_number"),
+            )?,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            queue_id: self.queue_id,
+            source_phone_number: self.source_phone_number,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartOutboundVoiceContactResponse {
@@ -710,31 +2114,235 @@ pub struct StartOutboundVoiceContactResponse {
     pub contact_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct StopContactRequest {
-    /// <p>The unique identifier of the contact to end.</p>
-    #[serde(rename = "ContactId")]
-    pub contact_id: String,
-    /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
-    #[serde(rename = "InstanceId")]
-    pub instance_id: String,
+/// <p>The statistic for the metric.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Statistic {
+    /// <p><code>SUM</code></p>
+    Sum,
+    /// <p><code>MAX</code></p>
+    Max,
+    /// <p><code>AVG</code></p>
+    Avg,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Statistic {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Statistic::Sum => "SUM",
+            Statistic::Max => "MAX",
+            Statistic::Avg => "AVG",
+            Statistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Statistic {
+    fn from(value: &'a str) -> Statistic {
+        match value {
+            "SUM" => Statistic::Sum,
+            "MAX" => Statistic::Max,
+            "AVG" => Statistic::Avg,
+            _ => Statistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Statistic {
+    fn from(value: String) -> Statistic {
+        Statistic::from(value.as_str())
+    }
+}
+
+impl From<Statistic> for String {
+    fn from(value: Statistic) -> String {
+        match value {
+            Statistic::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Statistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Statistic {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Statistic {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Statistic {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Statistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Statistic::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct StopContactRequest {
+    /// <p>The unique identifier of the contact to end.</p>
+    #[serde(rename = "ContactId")]
+    pub contact_id: String,
+    /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
+    #[serde(rename = "InstanceId")]
+    pub instance_id: String,
+}
+
+impl StopContactRequest {
+    /// Returns a builder for `StopContactRequest`, checking its required members when built.
+    pub fn builder() -> StopContactRequestBuilder {
+        StopContactRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopContactRequest`](struct.StopContactRequest.html).
+#[derive(Default, Debug)]
+pub struct StopContactRequestBuilder {
+    contact_id: Option<String>,
+    instance_id: Option<String>,
+}
+
+impl StopContactRequestBuilder {
+    /// Sets the [`contact_id`](struct.StopContactRequest.html#structfield.contact_id) member.
+    pub fn contact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.contact_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.StopContactRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopContactRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopContactRequest, ::rusoto_core::BuildError> {
+        Ok(StopContactRequest {
+            contact_id: self
+                .contact_id
+                .ok_or(::rusoto_core::BuildError::missing("contact_id"))?,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct StopContactResponse {}
+
+/// <p>A <code>Threshold</code> object that includes a comparison and <code>ThresholdValue</code> to compare to. Used with service level metrics.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Threshold {
+    /// <p>The Threshold to use to compare service level metrics to. Only "Less than" (LT) comparisons are supported.</p>
+    #[serde(rename = "Comparison")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comparison: Option<Comparison>,
+    /// <p>The value of the threshold to compare the metric to. Only "Less than" (LT) comparisons are supported.</p>
+    #[serde(rename = "ThresholdValue")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold_value: Option<f64>,
+}
+
+/// <p>The unit for the metric.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// <p><code>SECONDS</code></p>
+    Seconds,
+    /// <p><code>COUNT</code></p>
+    Count,
+    /// <p><code>PERCENT</code></p>
+    Percent,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Unit {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Unit::Seconds => "SECONDS",
+            Unit::Count => "COUNT",
+            Unit::Percent => "PERCENT",
+            Unit::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Unit {
+    fn from(value: &'a str) -> Unit {
+        match value {
+            "SECONDS" => Unit::Seconds,
+            "COUNT" => Unit::Count,
+            "PERCENT" => Unit::Percent,
+            _ => Unit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Unit {
+    fn from(value: String) -> Unit {
+        Unit::from(value.as_str())
+    }
+}
+
+impl From<Unit> for String {
+    fn from(value: Unit) -> String {
+        match value {
+            Unit::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Unit {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct StopContactResponse {}
+impl ::serde::Serialize for Unit {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
 
-/// <p>A <code>Threshold</code> object that includes a comparison and <code>ThresholdValue</code> to compare to. Used with service level metrics.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Threshold {
-    /// <p>The Threshold to use to compare service level metrics to. Only "Less than" (LT) comparisons are supported.</p>
-    #[serde(rename = "Comparison")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison: Option<String>,
-    /// <p>The value of the threshold to compare the metric to. Only "Less than" (LT) comparisons are supported.</p>
-    #[serde(rename = "ThresholdValue")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub threshold_value: Option<f64>,
+impl<'de> ::serde::Deserialize<'de> for Unit {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Unit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Unit::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -750,6 +2358,61 @@ pub struct UpdateContactAttributesRequest {
     pub instance_id: String,
 }
 
+impl UpdateContactAttributesRequest {
+    /// Returns a builder for `UpdateContactAttributesRequest`, checking its required members when built.
+    pub fn builder() -> UpdateContactAttributesRequestBuilder {
+        UpdateContactAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateContactAttributesRequest`](struct.UpdateContactAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateContactAttributesRequestBuilder {
+    attributes: Option<::std::collections::HashMap<String, String>>,
+    initial_contact_id: Option<String>,
+    instance_id: Option<String>,
+}
+
+impl UpdateContactAttributesRequestBuilder {
+    /// Sets the [`attributes`](struct.UpdateContactAttributesRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`initial_contact_id`](struct.UpdateContactAttributesRequest.html#structfield.initial_contact_id) member.
+    pub fn initial_contact_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.initial_contact_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.UpdateContactAttributesRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateContactAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateContactAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateContactAttributesRequest {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            initial_contact_id: self
+                .initial_contact_id
+                .ok_or(::rusoto_core::BuildError::missing("initial_contact_id"))?,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateContactAttributesResponse {}
@@ -768,6 +2431,56 @@ pub struct UpdateUserHierarchyRequest {
     pub user_id: String,
 }
 
+impl UpdateUserHierarchyRequest {
+    /// Returns a builder for `UpdateUserHierarchyRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserHierarchyRequestBuilder {
+        UpdateUserHierarchyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserHierarchyRequest`](struct.UpdateUserHierarchyRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserHierarchyRequestBuilder {
+    hierarchy_group_id: Option<String>,
+    instance_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl UpdateUserHierarchyRequestBuilder {
+    /// Sets the [`hierarchy_group_id`](struct.UpdateUserHierarchyRequest.html#structfield.hierarchy_group_id) member.
+    pub fn hierarchy_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hierarchy_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.UpdateUserHierarchyRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.UpdateUserHierarchyRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserHierarchyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateUserHierarchyRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserHierarchyRequest {
+            hierarchy_group_id: self.hierarchy_group_id,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateUserIdentityInfoRequest {
     /// <p>A <code>UserIdentityInfo</code> object.</p>
@@ -781,7 +2494,59 @@ pub struct UpdateUserIdentityInfoRequest {
     pub user_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl UpdateUserIdentityInfoRequest {
+    /// Returns a builder for `UpdateUserIdentityInfoRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserIdentityInfoRequestBuilder {
+        UpdateUserIdentityInfoRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserIdentityInfoRequest`](struct.UpdateUserIdentityInfoRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserIdentityInfoRequestBuilder {
+    identity_info: Option<UserIdentityInfo>,
+    instance_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl UpdateUserIdentityInfoRequestBuilder {
+    /// Sets the [`identity_info`](struct.UpdateUserIdentityInfoRequest.html#structfield.identity_info) member.
+    pub fn identity_info<T: Into<UserIdentityInfo>>(mut self, value: T) -> Self {
+        self.identity_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.UpdateUserIdentityInfoRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.UpdateUserIdentityInfoRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserIdentityInfoRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateUserIdentityInfoRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserIdentityInfoRequest {
+            identity_info: self
+                .identity_info
+                .ok_or(::rusoto_core::BuildError::missing("identity_info"))?,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateUserPhoneConfigRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
     #[serde(rename = "InstanceId")]
@@ -794,6 +2559,58 @@ pub struct UpdateUserPhoneConfigRequest {
     pub user_id: String,
 }
 
+impl UpdateUserPhoneConfigRequest {
+    /// Returns a builder for `UpdateUserPhoneConfigRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserPhoneConfigRequestBuilder {
+        UpdateUserPhoneConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserPhoneConfigRequest`](struct.UpdateUserPhoneConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserPhoneConfigRequestBuilder {
+    instance_id: Option<String>,
+    phone_config: Option<UserPhoneConfig>,
+    user_id: Option<String>,
+}
+
+impl UpdateUserPhoneConfigRequestBuilder {
+    /// Sets the [`instance_id`](struct.UpdateUserPhoneConfigRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`phone_config`](struct.UpdateUserPhoneConfigRequest.html#structfield.phone_config) member.
+    pub fn phone_config<T: Into<UserPhoneConfig>>(mut self, value: T) -> Self {
+        self.phone_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.UpdateUserPhoneConfigRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserPhoneConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateUserPhoneConfigRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserPhoneConfigRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            phone_config: self
+                .phone_config
+                .ok_or(::rusoto_core::BuildError::missing("phone_config"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateUserRoutingProfileRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
@@ -807,6 +2624,58 @@ pub struct UpdateUserRoutingProfileRequest {
     pub user_id: String,
 }
 
+impl UpdateUserRoutingProfileRequest {
+    /// Returns a builder for `UpdateUserRoutingProfileRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserRoutingProfileRequestBuilder {
+        UpdateUserRoutingProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserRoutingProfileRequest`](struct.UpdateUserRoutingProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserRoutingProfileRequestBuilder {
+    instance_id: Option<String>,
+    routing_profile_id: Option<String>,
+    user_id: Option<String>,
+}
+
+impl UpdateUserRoutingProfileRequestBuilder {
+    /// Sets the [`instance_id`](struct.UpdateUserRoutingProfileRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`routing_profile_id`](struct.UpdateUserRoutingProfileRequest.html#structfield.routing_profile_id) member.
+    pub fn routing_profile_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.routing_profile_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.UpdateUserRoutingProfileRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserRoutingProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateUserRoutingProfileRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserRoutingProfileRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            routing_profile_id: self
+                .routing_profile_id
+                .ok_or(::rusoto_core::BuildError::missing("routing_profile_id"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateUserSecurityProfilesRequest {
     /// <p>The identifier for your Amazon Connect instance. To find the ID of your instance, open the AWS console and select Amazon Connect. Select the alias of the instance in the Instance alias column. The instance ID is displayed in the Overview section of your instance settings. For example, the instance ID is the set of characters at the end of the instance ARN, after instance/, such as 10a4c4eb-f57e-4d4c-b602-bf39176ced07.</p>
@@ -820,6 +2689,58 @@ pub struct UpdateUserSecurityProfilesRequest {
     pub user_id: String,
 }
 
+impl UpdateUserSecurityProfilesRequest {
+    /// Returns a builder for `UpdateUserSecurityProfilesRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserSecurityProfilesRequestBuilder {
+        UpdateUserSecurityProfilesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserSecurityProfilesRequest`](struct.UpdateUserSecurityProfilesRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserSecurityProfilesRequestBuilder {
+    instance_id: Option<String>,
+    security_profile_ids: Option<Vec<String>>,
+    user_id: Option<String>,
+}
+
+impl UpdateUserSecurityProfilesRequestBuilder {
+    /// Sets the [`instance_id`](struct.UpdateUserSecurityProfilesRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_ids`](struct.UpdateUserSecurityProfilesRequest.html#structfield.security_profile_ids) member.
+    pub fn security_profile_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_profile_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.UpdateUserSecurityProfilesRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserSecurityProfilesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateUserSecurityProfilesRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserSecurityProfilesRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            security_profile_ids: self
+                .security_profile_ids
+                .ok_or(::rusoto_core::BuildError::missing("security_profile_ids"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 /// <p>A <code>User</code> object that contains information about a user account in your Amazon Connect instance, including configuration settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -880,7 +2801,7 @@ pub struct UserIdentityInfo {
 }
 
 /// <p>A <code>UserPhoneConfig</code> object that contains information about the user phone configuration settings.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserPhoneConfig {
     /// <p>The After Call Work (ACW) timeout setting, in seconds, for the user.</p>
     #[serde(rename = "AfterContactWorkTimeLimit")]
@@ -896,7 +2817,7 @@ pub struct UserPhoneConfig {
     pub desk_phone_number: Option<String>,
     /// <p>The phone type selected for the user, either Soft phone or Desk phone.</p>
     #[serde(rename = "PhoneType")]
-    pub phone_type: String,
+    pub phone_type: PhoneType,
 }
 
 /// <p>A <code>UserSummary</code> object that contains Information about a user, including ARN, Id, and user name.</p>
@@ -2367,6 +4288,139 @@ pub trait Connect {
         input: UpdateUserSecurityProfilesRequest,
     ) -> RusotoFuture<(), UpdateUserSecurityProfilesError>;
 }
+impl<T: ?Sized + Connect> Connect for ::std::sync::Arc<T> {
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn describe_user_hierarchy_group(
+        &self,
+        input: DescribeUserHierarchyGroupRequest,
+    ) -> RusotoFuture<DescribeUserHierarchyGroupResponse, DescribeUserHierarchyGroupError> {
+        (**self).describe_user_hierarchy_group(input)
+    }
+    fn describe_user_hierarchy_structure(
+        &self,
+        input: DescribeUserHierarchyStructureRequest,
+    ) -> RusotoFuture<DescribeUserHierarchyStructureResponse, DescribeUserHierarchyStructureError>
+    {
+        (**self).describe_user_hierarchy_structure(input)
+    }
+    fn get_contact_attributes(
+        &self,
+        input: GetContactAttributesRequest,
+    ) -> RusotoFuture<GetContactAttributesResponse, GetContactAttributesError> {
+        (**self).get_contact_attributes(input)
+    }
+    fn get_current_metric_data(
+        &self,
+        input: GetCurrentMetricDataRequest,
+    ) -> RusotoFuture<GetCurrentMetricDataResponse, GetCurrentMetricDataError> {
+        (**self).get_current_metric_data(input)
+    }
+    fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> RusotoFuture<GetFederationTokenResponse, GetFederationTokenError> {
+        (**self).get_federation_token(input)
+    }
+    fn get_metric_data(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> RusotoFuture<GetMetricDataResponse, GetMetricDataError> {
+        (**self).get_metric_data(input)
+    }
+    fn list_routing_profiles(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> RusotoFuture<ListRoutingProfilesResponse, ListRoutingProfilesError> {
+        (**self).list_routing_profiles(input)
+    }
+    fn list_security_profiles(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> RusotoFuture<ListSecurityProfilesResponse, ListSecurityProfilesError> {
+        (**self).list_security_profiles(input)
+    }
+    fn list_user_hierarchy_groups(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> RusotoFuture<ListUserHierarchyGroupsResponse, ListUserHierarchyGroupsError> {
+        (**self).list_user_hierarchy_groups(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn start_outbound_voice_contact(
+        &self,
+        input: StartOutboundVoiceContactRequest,
+    ) -> RusotoFuture<StartOutboundVoiceContactResponse, StartOutboundVoiceContactError> {
+        (**self).start_outbound_voice_contact(input)
+    }
+    fn stop_contact(
+        &self,
+        input: StopContactRequest,
+    ) -> RusotoFuture<StopContactResponse, StopContactError> {
+        (**self).stop_contact(input)
+    }
+    fn update_contact_attributes(
+        &self,
+        input: UpdateContactAttributesRequest,
+    ) -> RusotoFuture<UpdateContactAttributesResponse, UpdateContactAttributesError> {
+        (**self).update_contact_attributes(input)
+    }
+    fn update_user_hierarchy(
+        &self,
+        input: UpdateUserHierarchyRequest,
+    ) -> RusotoFuture<(), UpdateUserHierarchyError> {
+        (**self).update_user_hierarchy(input)
+    }
+    fn update_user_identity_info(
+        &self,
+        input: UpdateUserIdentityInfoRequest,
+    ) -> RusotoFuture<(), UpdateUserIdentityInfoError> {
+        (**self).update_user_identity_info(input)
+    }
+    fn update_user_phone_config(
+        &self,
+        input: UpdateUserPhoneConfigRequest,
+    ) -> RusotoFuture<(), UpdateUserPhoneConfigError> {
+        (**self).update_user_phone_config(input)
+    }
+    fn update_user_routing_profile(
+        &self,
+        input: UpdateUserRoutingProfileRequest,
+    ) -> RusotoFuture<(), UpdateUserRoutingProfileError> {
+        (**self).update_user_routing_profile(input)
+    }
+    fn update_user_security_profiles(
+        &self,
+        input: UpdateUserSecurityProfilesRequest,
+    ) -> RusotoFuture<(), UpdateUserSecurityProfilesError> {
+        (**self).update_user_security_profiles(input)
+    }
+}
+/// The error codes Amazon Connect retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Connect API.
 #[derive(Clone)]
 pub struct ConnectClient {
@@ -2382,6 +4436,27 @@ impl ConnectClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CONNECT`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ConnectClient {
+        Self::new(region::Region::from_env_for_service("CONNECT"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `connect`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ConnectClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("connect", "CONNECT"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2400,7 +4475,49 @@ impl ConnectClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ConnectClient {
-        ConnectClient { client, region }
+        ConnectClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "connect")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "connect", "connect", "CONNECT")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Connect` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Connect + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ConnectClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Connect"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "connect"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ConnectClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Cost and Usage Report Service clients.
+//!
+//! Makes a single read-only `DescribeReportDefinitions` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_cur::{CostAndUsageReport, CostAndUsageReportClient, DescribeReportDefinitionsRequest};
+
+fn main() {
+    let client = CostAndUsageReportClient::from_env();
+    match client
+        .describe_report_definitions(DescribeReportDefinitionsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeReportDefinitions succeeded"),
+        Err(error) => {
+            eprintln!("DescribeReportDefinitions failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -1 +1,3 @@
-
+/// Reading Cost and Usage Reports delivered to S3
+#[cfg(feature = "reader")]
+pub mod reader;
@@ -0,0 +1,526 @@
+//! Reading Cost and Usage Reports from S3.
+//!
+//! AWS delivers a report as gzip'd CSV files in an S3 bucket, listed by a JSON manifest written
+//! next to them for each billing period. `ReportReader::manifest` fetches a manifest, and
+//! `ReportReader::line_items` streams the line items of the files it lists, decompressing and
+//! parsing each file as it is downloaded, so reports much larger than memory can be read.
+//!
+//! The most used columns of a line item are typed fields of `LineItem`. Resource tags, which have
+//! a column per tag key, are collected in `LineItem::resource_tags`, and every other column is
+//! kept in `LineItem::columns` by its CSV header, such as `product/region`.
+//!
+//! This module is only available with the `reader` feature enabled. Only reports compressed
+//! with GZIP can be read.
+
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::io::Write;
+use std::mem;
+
+use flate2::write::GzDecoder;
+use futures::stream::{self, Stream};
+use futures::{Async, Future, Poll};
+use rusoto_core::request::HttpDispatchError;
+use rusoto_core::{ByteStream, RusotoError, RusotoFuture};
+use rusoto_s3::{GetObjectError, GetObjectRequest, S3};
+
+use crate::generated::ReportDefinition;
+
+/// A stream of line items.
+pub type LineItems = Box<dyn Stream<Item = LineItem, Error = RusotoError<CurReadError>> + Send>;
+
+/// The manifest of a report for a billing period.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportManifest {
+    /// The ID of this version of the report. Each update of a report gets a new one.
+    pub assembly_id: String,
+    /// The account the report is for.
+    #[serde(default)]
+    pub account: Option<String>,
+    /// The columns of the report.
+    #[serde(default)]
+    pub columns: Vec<ReportColumn>,
+    /// The compression of the report files, e.g. `GZIP`.
+    #[serde(default)]
+    pub compression: Option<String>,
+    /// The content type of the report files, e.g. `text/csv`.
+    #[serde(default)]
+    pub content_type: Option<String>,
+    /// The name of the report.
+    pub report_name: String,
+    /// The billing period of the report.
+    pub billing_period: BillingPeriod,
+    /// The bucket of the report files.
+    pub bucket: String,
+    /// The keys of the report files.
+    pub report_keys: Vec<String>,
+}
+
+/// A column of a report.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct ReportColumn {
+    /// The category of the column, e.g. `lineItem` or `resourceTags`.
+    pub category: String,
+    /// The name of the column in its category, e.g. `UnblendedCost`.
+    pub name: String,
+}
+
+/// The billing period of a report.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct BillingPeriod {
+    /// The start of the period, e.g. `20191001T000000.000Z`.
+    pub start: String,
+    /// The end of the period, excluded.
+    pub end: String,
+}
+
+/// A line item of a report.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LineItem {
+    /// `identity/LineItemId`
+    pub line_item_id: String,
+    /// `identity/TimeInterval`, e.g. `2019-10-01T00:00:00Z/2019-10-01T01:00:00Z`.
+    pub time_interval: String,
+    /// `bill/PayerAccountId`
+    pub payer_account_id: String,
+    /// `lineItem/UsageAccountId`
+    pub usage_account_id: String,
+    /// `lineItem/LineItemType`, e.g. `Usage`, `Tax` or `Credit`.
+    pub line_item_type: String,
+    /// `lineItem/UsageStartDate`
+    pub usage_start_date: String,
+    /// `lineItem/UsageEndDate`
+    pub usage_end_date: String,
+    /// `lineItem/ProductCode`, e.g. `AmazonEC2`.
+    pub product_code: String,
+    /// `lineItem/UsageType`
+    pub usage_type: String,
+    /// `lineItem/Operation`
+    pub operation: String,
+    /// `lineItem/ResourceId`, when the report includes resource IDs.
+    pub resource_id: Option<String>,
+    /// `lineItem/UsageAmount`
+    pub usage_amount: Option<f64>,
+    /// `lineItem/CurrencyCode`
+    pub currency_code: String,
+    /// `lineItem/UnblendedCost`
+    pub unblended_cost: Option<f64>,
+    /// `lineItem/BlendedCost`
+    pub blended_cost: Option<f64>,
+    /// `lineItem/LineItemDescription`
+    pub description: String,
+    /// The resource tags of the line item by key, e.g. `user:team`. Tags without a value for
+    /// this line item are left out.
+    pub resource_tags: HashMap<String, String>,
+    /// The other columns by CSV header, e.g. `product/region`.
+    pub columns: HashMap<String, String>,
+}
+
+impl LineItem {
+    fn from_record(
+        header: &[String],
+        record: &csv::StringRecord,
+    ) -> Result<LineItem, CurReadError> {
+        let mut item = LineItem::default();
+        for (column, value) in header.iter().zip(record.iter()) {
+            match column.as_str() {
+                "identity/LineItemId" => item.line_item_id = value.to_owned(),
+                "identity/TimeInterval" => item.time_interval = value.to_owned(),
+                "bill/PayerAccountId" => item.payer_account_id = value.to_owned(),
+                "lineItem/UsageAccountId" => item.usage_account_id = value.to_owned(),
+                "lineItem/LineItemType" => item.line_item_type = value.to_owned(),
+                "lineItem/UsageStartDate" => item.usage_start_date = value.to_owned(),
+                "lineItem/UsageEndDate" => item.usage_end_date = value.to_owned(),
+                "lineItem/ProductCode" => item.product_code = value.to_owned(),
+                "lineItem/UsageType" => item.usage_type = value.to_owned(),
+                "lineItem/Operation" => item.operation = value.to_owned(),
+                "lineItem/ResourceId" if !value.is_empty() => {
+                    item.resource_id = Some(value.to_owned())
+                }
+                "lineItem/ResourceId" => {}
+                "lineItem/UsageAmount" => item.usage_amount = parse_amount(column, value)?,
+                "lineItem/CurrencyCode" => item.currency_code = value.to_owned(),
+                "lineItem/UnblendedCost" => item.unblended_cost = parse_amount(column, value)?,
+                "lineItem/BlendedCost" => item.blended_cost = parse_amount(column, value)?,
+                "lineItem/LineItemDescription" => item.description = value.to_owned(),
+                _ if column.starts_with("resourceTags/") => {
+                    if !value.is_empty() {
+                        let key = &column["resourceTags/".len()..];
+                        item.resource_tags.insert(key.to_owned(), value.to_owned());
+                    }
+                }
+                _ => {
+                    item.columns.insert(column.clone(), value.to_owned());
+                }
+            }
+        }
+        Ok(item)
+    }
+}
+
+/// Errors returned while reading a report.
+#[derive(Debug, PartialEq)]
+pub enum CurReadError {
+    /// Downloading the manifest or a report file failed.
+    GetObject(GetObjectError),
+    /// The manifest isn't valid JSON.
+    InvalidManifest(String),
+    /// The report isn't compressed with GZIP.
+    UnsupportedCompression(String),
+    /// A report file isn't valid gzip data.
+    Decompress(String),
+    /// A report file isn't valid CSV.
+    Csv(String),
+    /// A line item has an invalid value.
+    InvalidLineItem(String),
+}
+
+impl fmt::Display for CurReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for CurReadError {
+    fn description(&self) -> &str {
+        match *self {
+            CurReadError::GetObject(ref cause) => cause.description(),
+            CurReadError::InvalidManifest(ref cause) => cause,
+            CurReadError::UnsupportedCompression(ref cause) => cause,
+            CurReadError::Decompress(ref cause) => cause,
+            CurReadError::Csv(ref cause) => cause,
+            CurReadError::InvalidLineItem(ref cause) => cause,
+        }
+    }
+}
+
+/// The key of the manifest of a report for a billing period, such as `20191001-20191101`.
+pub fn manifest_key(definition: &ReportDefinition, billing_period: &str) -> String {
+    let prefix = definition.s3_prefix.trim_matches('/');
+    let key = format!(
+        "{name}/{period}/{name}-Manifest.json",
+        name = definition.report_name,
+        period = billing_period
+    );
+    if prefix.is_empty() {
+        key
+    } else {
+        format!("{}/{}", prefix, key)
+    }
+}
+
+/// Reads reports from S3.
+#[derive(Clone)]
+pub struct ReportReader<S> {
+    s3: S,
+}
+
+impl<S: S3 + Clone + Send + 'static> ReportReader<S> {
+    /// Creates a reader downloading reports with `s3`, a client for the region of the bucket
+    /// the reports are delivered to.
+    pub fn new(s3: S) -> ReportReader<S> {
+        ReportReader { s3 }
+    }
+
+    /// Fetches the manifest at `key` in `bucket`.
+    pub fn manifest(&self, bucket: &str, key: &str) -> RusotoFuture<ReportManifest, CurReadError> {
+        let future = get_object(&self.s3, bucket.to_owned(), key.to_owned())
+            .and_then(|body| {
+                body.concat2()
+                    .map_err(|e| RusotoError::from(HttpDispatchError::new(e.to_string())))
+            })
+            .and_then(|body| {
+                serde_json::from_slice(&body)
+                    .map_err(|e| RusotoError::Service(CurReadError::InvalidManifest(e.to_string())))
+            });
+        RusotoFuture::from_future(future)
+    }
+
+    /// Streams the line items of the report files listed by `manifest`, one file after the
+    /// other.
+    pub fn line_items(&self, manifest: &ReportManifest) -> LineItems {
+        if let Some(ref compression) = manifest.compression {
+            if !compression.eq_ignore_ascii_case("GZIP") {
+                return Box::new(stream::once(Err(RusotoError::Service(
+                    CurReadError::UnsupportedCompression(format!(
+                        "Report {} is compressed with {}",
+                        manifest.report_name, compression
+                    )),
+                ))));
+            }
+        }
+        let s3 = self.s3.clone();
+        let bucket = manifest.bucket.clone();
+        let files = stream::iter_ok::<_, RusotoError<CurReadError>>(manifest.report_keys.clone())
+            .map(move |key| {
+                get_object(&s3, bucket.clone(), key)
+                    .map(ReportFileStream::new)
+                    .flatten_stream()
+            });
+        Box::new(files.flatten())
+    }
+
+    /// Fetches the manifest at `key` in `bucket` and streams the line items of its report.
+    pub fn report_line_items(&self, bucket: &str, key: &str) -> LineItems {
+        let reader = self.clone();
+        Box::new(
+            self.manifest(bucket, key)
+                .map(move |manifest| reader.line_items(&manifest))
+                .flatten_stream(),
+        )
+    }
+}
+
+/// Downloads an object, returning its body.
+fn get_object<S: S3>(
+    s3: &S,
+    bucket: String,
+    key: String,
+) -> impl Future<Item = ByteStream, Error = RusotoError<CurReadError>> {
+    s3.get_object(GetObjectRequest {
+        bucket,
+        key,
+        ..Default::default()
+    })
+    .map_err(|e| e.map_service(CurReadError::GetObject))
+    .map(|output| output.body.unwrap_or_else(|| ByteStream::from(Vec::new())))
+}
+
+/// The line items of a report file, parsed as its body is received.
+struct ReportFileStream {
+    body: ByteStream,
+    parser: ReportParser,
+    items: VecDeque<LineItem>,
+    finished: bool,
+}
+
+impl ReportFileStream {
+    fn new(body: ByteStream) -> ReportFileStream {
+        ReportFileStream {
+            body,
+            parser: ReportParser::new(),
+            items: VecDeque::new(),
+            finished: false,
+        }
+    }
+}
+
+impl Stream for ReportFileStream {
+    type Item = LineItem;
+    type Error = RusotoError<CurReadError>;
+
+    fn poll(&mut self) -> Poll<Option<LineItem>, Self::Error> {
+        loop {
+            if let Some(item) = self.items.pop_front() {
+                return Ok(Async::Ready(Some(item)));
+            }
+            if self.finished {
+                return Ok(Async::Ready(None));
+            }
+            let items = match self.body.poll() {
+                Ok(Async::Ready(Some(chunk))) => self.parser.push(&chunk),
+                Ok(Async::Ready(None)) => {
+                    self.finished = true;
+                    self.parser.finish()
+                }
+                Ok(Async::NotReady) => return Ok(Async::NotReady),
+                // an interrupted transfer fails like a failed request
+                Err(e) => return Err(RusotoError::from(HttpDispatchError::new(e.to_string()))),
+            };
+            self.items.extend(items.map_err(RusotoError::Service)?);
+        }
+    }
+}
+
+/// Parses the line items of a gzip'd CSV report file from its chunks.
+struct ReportParser {
+    decoder: GzDecoder<Vec<u8>>,
+    /// Decompressed data not parsed yet, starting with a record
+    pending: Vec<u8>,
+    /// The CSV header, once parsed
+    header: Option<Vec<String>>,
+}
+
+impl ReportParser {
+    fn new() -> ReportParser {
+        ReportParser {
+            decoder: GzDecoder::new(Vec::new()),
+            pending: Vec::new(),
+            header: None,
+        }
+    }
+
+    /// Parses the records completed by `chunk`.
+    fn push(&mut self, chunk: &[u8]) -> Result<Vec<LineItem>, CurReadError> {
+        self.decoder
+            .write_all(chunk)
+            .and_then(|_| self.decoder.flush())
+            .map_err(|e| CurReadError::Decompress(e.to_string()))?;
+        self.take_decompressed();
+        match last_record_end(&self.pending) {
+            Some(end) => {
+                let records = self.pending.drain(..end).collect::<Vec<_>>();
+                self.parse(&records)
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Parses the remaining records, once the whole file was pushed.
+    fn finish(&mut self) -> Result<Vec<LineItem>, CurReadError> {
+        self.decoder
+            .try_finish()
+            .map_err(|e| CurReadError::Decompress(e.to_string()))?;
+        self.take_decompressed();
+        let records = mem::replace(&mut self.pending, Vec::new());
+        self.parse(&records)
+    }
+
+    fn take_decompressed(&mut self) {
+        let decompressed = mem::replace(self.decoder.get_mut(), Vec::new());
+        self.pending.extend_from_slice(&decompressed);
+    }
+
+    fn parse(&mut self, records: &[u8]) -> Result<Vec<LineItem>, CurReadError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(records);
+        let mut items = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(|e| CurReadError::Csv(e.to_string()))?;
+            match self.header {
+                Some(ref header) => items.push(LineItem::from_record(header, &record)?),
+                None => self.header = Some(record.iter().map(str::to_owned).collect()),
+            }
+        }
+        Ok(items)
+    }
+}
+
+/// The end of the last complete CSV record in `data`, just after its newline.
+///
+/// Quoted values may contain newlines; quotes in them are doubled, so a newline ends a record
+/// when an even number of quotes precede it.
+fn last_record_end(data: &[u8]) -> Option<usize> {
+    let mut quoted = false;
+    let mut end = None;
+    for (i, byte) in data.iter().enumerate() {
+        match *byte {
+            b'"' => quoted = !quoted,
+            b'\n' if !quoted => end = Some(i + 1),
+            _ => {}
+        }
+    }
+    end
+}
+
+fn parse_amount(column: &str, value: &str) -> Result<Option<f64>, CurReadError> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    value
+        .parse()
+        .map(Some)
+        .map_err(|_| CurReadError::InvalidLineItem(format!("Invalid {}: {:?}", column, value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    use crate::generated::{AWSRegion, CompressionFormat, ReportFormat, TimeUnit};
+
+    const REPORT: &str = "identity/LineItemId,lineItem/LineItemType,lineItem/ProductCode,\
+lineItem/UsageAmount,lineItem/UnblendedCost,lineItem/LineItemDescription,\
+lineItem/ResourceId,product/region,resourceTags/user:team,resourceTags/user:env
+abc,Usage,AmazonEC2,24.0,2.5,\"$0.104 per On Demand Linux t3.large, per hour\",i-123,us-east-1,billing,
+def,Tax,AmazonS3,,0.01,\"Tax for product code \"\"AmazonS3\"\"\nspanning lines\",,,,prod
+";
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn parse_in_chunks(report: &[u8], chunk_size: usize) -> Vec<LineItem> {
+        let mut parser = ReportParser::new();
+        let mut items = Vec::new();
+        for chunk in gzip(report).chunks(chunk_size) {
+            items.extend(parser.push(chunk).unwrap());
+        }
+        items.extend(parser.finish().unwrap());
+        items
+    }
+
+    #[test]
+    fn parses_line_items_in_any_chunks() {
+        let items = parse_in_chunks(REPORT.as_bytes(), 7);
+        assert_eq!(items, parse_in_chunks(REPORT.as_bytes(), 4096));
+        assert_eq!(items.len(), 2);
+
+        let usage = &items[0];
+        assert_eq!(usage.line_item_id, "abc");
+        assert_eq!(usage.product_code, "AmazonEC2");
+        assert_eq!(usage.usage_amount, Some(24.0));
+        assert_eq!(usage.unblended_cost, Some(2.5));
+        assert_eq!(
+            usage.description,
+            "$0.104 per On Demand Linux t3.large, per hour"
+        );
+        assert_eq!(usage.resource_id, Some("i-123".to_owned()));
+        assert_eq!(usage.columns["product/region"], "us-east-1");
+        assert_eq!(usage.resource_tags.len(), 1);
+        assert_eq!(usage.resource_tags["user:team"], "billing");
+
+        let tax = &items[1];
+        assert_eq!(tax.usage_amount, None);
+        assert_eq!(tax.resource_id, None);
+        assert_eq!(
+            tax.description,
+            "Tax for product code \"AmazonS3\"\nspanning lines"
+        );
+        assert_eq!(tax.resource_tags["user:env"], "prod");
+    }
+
+    #[test]
+    fn rejects_invalid_amounts() {
+        let report = "identity/LineItemId,lineItem/UnblendedCost\nabc,lots\n";
+        let mut parser = ReportParser::new();
+        assert!(parser.push(&gzip(report.as_bytes())).is_err());
+    }
+
+    #[test]
+    fn finds_the_end_of_complete_records() {
+        assert_eq!(last_record_end(b"a,b\nc,d"), Some(4));
+        assert_eq!(last_record_end(b"a,\"b\nc"), None);
+        assert_eq!(last_record_end(b"a,\"b\"\"\n\"\nc"), Some(9));
+    }
+
+    #[test]
+    fn builds_manifest_keys() {
+        let definition = ReportDefinition {
+            additional_artifacts: None,
+            additional_schema_elements: Vec::new(),
+            compression: CompressionFormat::Gzip,
+            format: ReportFormat::TextORcsv,
+            refresh_closed_reports: None,
+            report_name: "hourly".to_owned(),
+            report_versioning: None,
+            s3_bucket: "my-bucket".to_owned(),
+            s3_prefix: "cur/".to_owned(),
+            s3_region: AWSRegion::UsEast1,
+            time_unit: TimeUnit::Hourly,
+        };
+        assert_eq!(
+            manifest_key(&definition, "20191001-20191101"),
+            "cur/hourly/20191001-20191101/hourly-Manifest.json"
+        );
+    }
+}
@@ -22,7 +22,336 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The region of the S3 bucket that Amazon Web Services delivers the report into.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AWSRegion {
+    /// <p><code>af-south-1</code></p>
+    AfSouth1,
+    /// <p><code>ap-east-1</code></p>
+    ApEast1,
+    /// <p><code>ap-south-1</code></p>
+    ApSouth1,
+    /// <p><code>ap-south-2</code></p>
+    ApSouth2,
+    /// <p><code>ap-southeast-1</code></p>
+    ApSoutheast1,
+    /// <p><code>ap-southeast-2</code></p>
+    ApSoutheast2,
+    /// <p><code>ap-southeast-3</code></p>
+    ApSoutheast3,
+    /// <p><code>ap-northeast-1</code></p>
+    ApNortheast1,
+    /// <p><code>ap-northeast-2</code></p>
+    ApNortheast2,
+    /// <p><code>ap-northeast-3</code></p>
+    ApNortheast3,
+    /// <p><code>ca-central-1</code></p>
+    CaCentral1,
+    /// <p><code>eu-central-1</code></p>
+    EuCentral1,
+    /// <p><code>eu-central-2</code></p>
+    EuCentral2,
+    /// <p><code>eu-west-1</code></p>
+    EuWest1,
+    /// <p><code>eu-west-2</code></p>
+    EuWest2,
+    /// <p><code>eu-west-3</code></p>
+    EuWest3,
+    /// <p><code>eu-north-1</code></p>
+    EuNorth1,
+    /// <p><code>eu-south-1</code></p>
+    EuSouth1,
+    /// <p><code>eu-south-2</code></p>
+    EuSouth2,
+    /// <p><code>me-central-1</code></p>
+    MeCentral1,
+    /// <p><code>me-south-1</code></p>
+    MeSouth1,
+    /// <p><code>sa-east-1</code></p>
+    SaEast1,
+    /// <p><code>us-east-1</code></p>
+    UsEast1,
+    /// <p><code>us-east-2</code></p>
+    UsEast2,
+    /// <p><code>us-west-1</code></p>
+    UsWest1,
+    /// <p><code>us-west-2</code></p>
+    UsWest2,
+    /// <p><code>cn-north-1</code></p>
+    CnNorth1,
+    /// <p><code>cn-northwest-1</code></p>
+    CnNorthwest1,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AWSRegion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AWSRegion::AfSouth1 => "af-south-1",
+            AWSRegion::ApEast1 => "ap-east-1",
+            AWSRegion::ApSouth1 => "ap-south-1",
+            AWSRegion::ApSouth2 => "ap-south-2",
+            AWSRegion::ApSoutheast1 => "ap-southeast-1",
+            AWSRegion::ApSoutheast2 => "ap-southeast-2",
+            AWSRegion::ApSoutheast3 => "ap-southeast-3",
+            AWSRegion::ApNortheast1 => "ap-northeast-1",
+            AWSRegion::ApNortheast2 => "ap-northeast-2",
+            AWSRegion::ApNortheast3 => "ap-northeast-3",
+            AWSRegion::CaCentral1 => "ca-central-1",
+            AWSRegion::EuCentral1 => "eu-central-1",
+            AWSRegion::EuCentral2 => "eu-central-2",
+            AWSRegion::EuWest1 => "eu-west-1",
+            AWSRegion::EuWest2 => "eu-west-2",
+            AWSRegion::EuWest3 => "eu-west-3",
+            AWSRegion::EuNorth1 => "eu-north-1",
+            AWSRegion::EuSouth1 => "eu-south-1",
+            AWSRegion::EuSouth2 => "eu-south-2",
+            AWSRegion::MeCentral1 => "me-central-1",
+            AWSRegion::MeSouth1 => "me-south-1",
+            AWSRegion::SaEast1 => "sa-east-1",
+            AWSRegion::UsEast1 => "us-east-1",
+            AWSRegion::UsEast2 => "us-east-2",
+            AWSRegion::UsWest1 => "us-west-1",
+            AWSRegion::UsWest2 => "us-west-2",
+            AWSRegion::CnNorth1 => "cn-north-1",
+            AWSRegion::CnNorthwest1 => "cn-northwest-1",
+            AWSRegion::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AWSRegion {
+    fn from(value: &'a str) -> AWSRegion {
+        match value {
+            "af-south-1" => AWSRegion::AfSouth1,
+            "ap-east-1" => AWSRegion::ApEast1,
+            "ap-south-1" => AWSRegion::ApSouth1,
+            "ap-south-2" => AWSRegion::ApSouth2,
+            "ap-southeast-1" => AWSRegion::ApSoutheast1,
+            "ap-southeast-2" => AWSRegion::ApSoutheast2,
+            "ap-southeast-3" => AWSRegion::ApSoutheast3,
+            "ap-northeast-1" => AWSRegion::ApNortheast1,
+            "ap-northeast-2" => AWSRegion::ApNortheast2,
+            "ap-northeast-3" => AWSRegion::ApNortheast3,
+            "ca-central-1" => AWSRegion::CaCentral1,
+            "eu-central-1" => AWSRegion::EuCentral1,
+            "eu-central-2" => AWSRegion::EuCentral2,
+            "eu-west-1" => AWSRegion::EuWest1,
+            "eu-west-2" => AWSRegion::EuWest2,
+            "eu-west-3" => AWSRegion::EuWest3,
+            "eu-north-1" => AWSRegion::EuNorth1,
+            "eu-south-1" => AWSRegion::EuSouth1,
+            "eu-south-2" => AWSRegion::EuSouth2,
+            "me-central-1" => AWSRegion::MeCentral1,
+            "me-south-1" => AWSRegion::MeSouth1,
+            "sa-east-1" => AWSRegion::SaEast1,
+            "us-east-1" => AWSRegion::UsEast1,
+            "us-east-2" => AWSRegion::UsEast2,
+            "us-west-1" => AWSRegion::UsWest1,
+            "us-west-2" => AWSRegion::UsWest2,
+            "cn-north-1" => AWSRegion::CnNorth1,
+            "cn-northwest-1" => AWSRegion::CnNorthwest1,
+            _ => AWSRegion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AWSRegion {
+    fn from(value: String) -> AWSRegion {
+        AWSRegion::from(value.as_str())
+    }
+}
+
+impl From<AWSRegion> for String {
+    fn from(value: AWSRegion) -> String {
+        match value {
+            AWSRegion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AWSRegion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AWSRegion {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AWSRegion {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AWSRegion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AWSRegion::from(value))
+    }
+}
+
+/// <p>The types of manifest that you want Amazon Web Services to create for this report.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AdditionalArtifact {
+    /// <p><code>REDSHIFT</code></p>
+    Redshift,
+    /// <p><code>QUICKSIGHT</code></p>
+    Quicksight,
+    /// <p><code>ATHENA</code></p>
+    Athena,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AdditionalArtifact {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdditionalArtifact::Redshift => "REDSHIFT",
+            AdditionalArtifact::Quicksight => "QUICKSIGHT",
+            AdditionalArtifact::Athena => "ATHENA",
+            AdditionalArtifact::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AdditionalArtifact {
+    fn from(value: &'a str) -> AdditionalArtifact {
+        match value {
+            "REDSHIFT" => AdditionalArtifact::Redshift,
+            "QUICKSIGHT" => AdditionalArtifact::Quicksight,
+            "ATHENA" => AdditionalArtifact::Athena,
+            _ => AdditionalArtifact::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdditionalArtifact {
+    fn from(value: String) -> AdditionalArtifact {
+        AdditionalArtifact::from(value.as_str())
+    }
+}
+
+impl From<AdditionalArtifact> for String {
+    fn from(value: AdditionalArtifact) -> String {
+        match value {
+            AdditionalArtifact::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AdditionalArtifact {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AdditionalArtifact {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdditionalArtifact {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AdditionalArtifact, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AdditionalArtifact::from(value))
+    }
+}
+
+/// <p>The compression format that Amazon Web Services uses for the report.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CompressionFormat {
+    /// <p><code>ZIP</code></p>
+    Zip,
+    /// <p><code>GZIP</code></p>
+    Gzip,
+    /// <p><code>Parquet</code></p>
+    Parquet,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CompressionFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CompressionFormat::Zip => "ZIP",
+            CompressionFormat::Gzip => "GZIP",
+            CompressionFormat::Parquet => "Parquet",
+            CompressionFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CompressionFormat {
+    fn from(value: &'a str) -> CompressionFormat {
+        match value {
+            "ZIP" => CompressionFormat::Zip,
+            "GZIP" => CompressionFormat::Gzip,
+            "Parquet" => CompressionFormat::Parquet,
+            _ => CompressionFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CompressionFormat {
+    fn from(value: String) -> CompressionFormat {
+        CompressionFormat::from(value.as_str())
+    }
+}
+
+impl From<CompressionFormat> for String {
+    fn from(value: CompressionFormat) -> String {
+        match value {
+            CompressionFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CompressionFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CompressionFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CompressionFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CompressionFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CompressionFormat::from(value))
+    }
+}
+
 /// <p>Deletes the specified report.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteReportDefinitionRequest {
@@ -31,6 +360,36 @@ pub struct DeleteReportDefinitionRequest {
     pub report_name: Option<String>,
 }
 
+impl DeleteReportDefinitionRequest {
+    /// Returns a builder for `DeleteReportDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteReportDefinitionRequestBuilder {
+        DeleteReportDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteReportDefinitionRequest`](struct.DeleteReportDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteReportDefinitionRequestBuilder {
+    report_name: Option<String>,
+}
+
+impl DeleteReportDefinitionRequestBuilder {
+    /// Sets the [`report_name`](struct.DeleteReportDefinitionRequest.html#structfield.report_name) member.
+    pub fn report_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.report_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteReportDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteReportDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteReportDefinitionRequest {
+            report_name: self.report_name,
+        })
+    }
+}
+
 /// <p>If the action is successful, the service sends back an HTTP 200 response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -51,6 +410,44 @@ pub struct DescribeReportDefinitionsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeReportDefinitionsRequest {
+    /// Returns a builder for `DescribeReportDefinitionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeReportDefinitionsRequestBuilder {
+        DescribeReportDefinitionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReportDefinitionsRequest`](struct.DescribeReportDefinitionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeReportDefinitionsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeReportDefinitionsRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeReportDefinitionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeReportDefinitionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReportDefinitionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReportDefinitionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeReportDefinitionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>If the action is successful, the service sends back an HTTP 200 response.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -64,7 +461,7 @@ pub struct DescribeReportDefinitionsResponse {
     pub report_definitions: Option<Vec<ReportDefinition>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ModifyReportDefinitionRequest {
     #[serde(rename = "ReportDefinition")]
     pub report_definition: ReportDefinition,
@@ -72,37 +469,111 @@ pub struct ModifyReportDefinitionRequest {
     pub report_name: String,
 }
 
+impl ModifyReportDefinitionRequest {
+    /// Returns a builder for `ModifyReportDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> ModifyReportDefinitionRequestBuilder {
+        ModifyReportDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyReportDefinitionRequest`](struct.ModifyReportDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct ModifyReportDefinitionRequestBuilder {
+    report_definition: Option<ReportDefinition>,
+    report_name: Option<String>,
+}
+
+impl ModifyReportDefinitionRequestBuilder {
+    /// Sets the [`report_definition`](struct.ModifyReportDefinitionRequest.html#structfield.report_definition) member.
+    pub fn report_definition<T: Into<ReportDefinition>>(mut self, value: T) -> Self {
+        self.report_definition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`report_name`](struct.ModifyReportDefinitionRequest.html#structfield.report_name) member.
+    pub fn report_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.report_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyReportDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyReportDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(ModifyReportDefinitionRequest {
+            report_definition: self
+                .report_definition
+                .ok_or(::rusoto_core::BuildError::missing("report_definition"))?,
+            report_name: self
+                .report_name
+                .ok_or(::rusoto_core::BuildError::missing("report_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ModifyReportDefinitionResponse {}
 
 /// <p>Creates a Cost and Usage Report.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutReportDefinitionRequest {
     /// <p>Represents the output of the PutReportDefinition operation. The content consists of the detailed metadata and data file information. </p>
     #[serde(rename = "ReportDefinition")]
     pub report_definition: ReportDefinition,
 }
 
+impl PutReportDefinitionRequest {
+    /// Returns a builder for `PutReportDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> PutReportDefinitionRequestBuilder {
+        PutReportDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutReportDefinitionRequest`](struct.PutReportDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct PutReportDefinitionRequestBuilder {
+    report_definition: Option<ReportDefinition>,
+}
+
+impl PutReportDefinitionRequestBuilder {
+    /// Sets the [`report_definition`](struct.PutReportDefinitionRequest.html#structfield.report_definition) member.
+    pub fn report_definition<T: Into<ReportDefinition>>(mut self, value: T) -> Self {
+        self.report_definition = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutReportDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutReportDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(PutReportDefinitionRequest {
+            report_definition: self
+                .report_definition
+                .ok_or(::rusoto_core::BuildError::missing("report_definition"))?,
+        })
+    }
+}
+
 /// <p>If the action is successful, the service sends back an HTTP 200 response with an empty HTTP body.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutReportDefinitionResponse {}
 
 /// <p>The definition of AWS Cost and Usage Report. You can specify the report name, time unit, report format, compression format, S3 bucket, additional artifacts, and schema elements in the definition. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReportDefinition {
     /// <p>A list of manifests that you want Amazon Web Services to create for this report.</p>
     #[serde(rename = "AdditionalArtifacts")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_artifacts: Option<Vec<String>>,
+    pub additional_artifacts: Option<Vec<AdditionalArtifact>>,
     /// <p>A list of strings that indicate additional content that Amazon Web Services includes in the report, such as individual resource IDs. </p>
     #[serde(rename = "AdditionalSchemaElements")]
-    pub additional_schema_elements: Vec<String>,
+    pub additional_schema_elements: Vec<SchemaElement>,
     #[serde(rename = "Compression")]
-    pub compression: String,
+    pub compression: CompressionFormat,
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: ReportFormat,
     /// <p>Whether you want Amazon Web Services to update your reports after they have been finalized if Amazon Web Services detects charges related to previous months. These charges can include refunds, credits, or support fees.</p>
     #[serde(rename = "RefreshClosedReports")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -112,15 +583,311 @@ pub struct ReportDefinition {
     /// <p>Whether you want Amazon Web Services to overwrite the previous version of each report or to deliver the report in addition to the previous versions.</p>
     #[serde(rename = "ReportVersioning")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub report_versioning: Option<String>,
+    pub report_versioning: Option<ReportVersioning>,
     #[serde(rename = "S3Bucket")]
     pub s3_bucket: String,
     #[serde(rename = "S3Prefix")]
     pub s3_prefix: String,
     #[serde(rename = "S3Region")]
-    pub s3_region: String,
+    pub s3_region: AWSRegion,
     #[serde(rename = "TimeUnit")]
-    pub time_unit: String,
+    pub time_unit: TimeUnit,
+}
+
+/// <p>The format that Amazon Web Services saves the report in.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReportFormat {
+    /// <p><code>textORcsv</code></p>
+    TextORcsv,
+    /// <p><code>Parquet</code></p>
+    Parquet,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReportFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportFormat::TextORcsv => "textORcsv",
+            ReportFormat::Parquet => "Parquet",
+            ReportFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReportFormat {
+    fn from(value: &'a str) -> ReportFormat {
+        match value {
+            "textORcsv" => ReportFormat::TextORcsv,
+            "Parquet" => ReportFormat::Parquet,
+            _ => ReportFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportFormat {
+    fn from(value: String) -> ReportFormat {
+        ReportFormat::from(value.as_str())
+    }
+}
+
+impl From<ReportFormat> for String {
+    fn from(value: ReportFormat) -> String {
+        match value {
+            ReportFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReportFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReportFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReportFormat::from(value))
+    }
+}
+
+/// <p>Whether you want Amazon Web Services to overwrite the previous version of each report or to deliver the report in addition to the previous versions.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReportVersioning {
+    /// <p><code>CREATE_NEW_REPORT</code></p>
+    CreateNewReport,
+    /// <p><code>OVERWRITE_REPORT</code></p>
+    OverwriteReport,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReportVersioning {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportVersioning::CreateNewReport => "CREATE_NEW_REPORT",
+            ReportVersioning::OverwriteReport => "OVERWRITE_REPORT",
+            ReportVersioning::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReportVersioning {
+    fn from(value: &'a str) -> ReportVersioning {
+        match value {
+            "CREATE_NEW_REPORT" => ReportVersioning::CreateNewReport,
+            "OVERWRITE_REPORT" => ReportVersioning::OverwriteReport,
+            _ => ReportVersioning::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportVersioning {
+    fn from(value: String) -> ReportVersioning {
+        ReportVersioning::from(value.as_str())
+    }
+}
+
+impl From<ReportVersioning> for String {
+    fn from(value: ReportVersioning) -> String {
+        match value {
+            ReportVersioning::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReportVersioning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReportVersioning {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportVersioning {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReportVersioning, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReportVersioning::from(value))
+    }
+}
+
+/// <p>Whether or not Amazon Web Services includes resource IDs in the report. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SchemaElement {
+    /// <p><code>RESOURCES</code></p>
+    Resources,
+    /// <p><code>SPLIT_COST_ALLOCATION_DATA</code></p>
+    SplitCostAllocationData,
+    /// <p><code>MANUAL_DISCOUNT_COMPATIBILITY</code></p>
+    ManualDiscountCompatibility,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SchemaElement {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SchemaElement::Resources => "RESOURCES",
+            SchemaElement::SplitCostAllocationData => "SPLIT_COST_ALLOCATION_DATA",
+            SchemaElement::ManualDiscountCompatibility => "MANUAL_DISCOUNT_COMPATIBILITY",
+            SchemaElement::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SchemaElement {
+    fn from(value: &'a str) -> SchemaElement {
+        match value {
+            "RESOURCES" => SchemaElement::Resources,
+            "SPLIT_COST_ALLOCATION_DATA" => SchemaElement::SplitCostAllocationData,
+            "MANUAL_DISCOUNT_COMPATIBILITY" => SchemaElement::ManualDiscountCompatibility,
+            _ => SchemaElement::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SchemaElement {
+    fn from(value: String) -> SchemaElement {
+        SchemaElement::from(value.as_str())
+    }
+}
+
+impl From<SchemaElement> for String {
+    fn from(value: SchemaElement) -> String {
+        match value {
+            SchemaElement::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SchemaElement {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SchemaElement {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SchemaElement, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SchemaElement::from(value))
+    }
+}
+
+/// <p>The length of time covered by the report. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TimeUnit {
+    /// <p><code>HOURLY</code></p>
+    Hourly,
+    /// <p><code>DAILY</code></p>
+    Daily,
+    /// <p><code>MONTHLY</code></p>
+    Monthly,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TimeUnit {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TimeUnit::Hourly => "HOURLY",
+            TimeUnit::Daily => "DAILY",
+            TimeUnit::Monthly => "MONTHLY",
+            TimeUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TimeUnit {
+    fn from(value: &'a str) -> TimeUnit {
+        match value {
+            "HOURLY" => TimeUnit::Hourly,
+            "DAILY" => TimeUnit::Daily,
+            "MONTHLY" => TimeUnit::Monthly,
+            _ => TimeUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TimeUnit {
+    fn from(value: String) -> TimeUnit {
+        TimeUnit::from(value.as_str())
+    }
+}
+
+impl From<TimeUnit> for String {
+    fn from(value: TimeUnit) -> String {
+        match value {
+            TimeUnit::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TimeUnit {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TimeUnit {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TimeUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TimeUnit::from(value))
+    }
 }
 
 /// Errors returned by DeleteReportDefinition
@@ -303,6 +1070,39 @@ pub trait CostAndUsageReport {
         input: PutReportDefinitionRequest,
     ) -> RusotoFuture<PutReportDefinitionResponse, PutReportDefinitionError>;
 }
+impl<T: ?Sized + CostAndUsageReport> CostAndUsageReport for ::std::sync::Arc<T> {
+    fn delete_report_definition(
+        &self,
+        input: DeleteReportDefinitionRequest,
+    ) -> RusotoFuture<DeleteReportDefinitionResponse, DeleteReportDefinitionError> {
+        (**self).delete_report_definition(input)
+    }
+    fn describe_report_definitions(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> RusotoFuture<DescribeReportDefinitionsResponse, DescribeReportDefinitionsError> {
+        (**self).describe_report_definitions(input)
+    }
+    fn modify_report_definition(
+        &self,
+        input: ModifyReportDefinitionRequest,
+    ) -> RusotoFuture<ModifyReportDefinitionResponse, ModifyReportDefinitionError> {
+        (**self).modify_report_definition(input)
+    }
+    fn put_report_definition(
+        &self,
+        input: PutReportDefinitionRequest,
+    ) -> RusotoFuture<PutReportDefinitionResponse, PutReportDefinitionError> {
+        (**self).put_report_definition(input)
+    }
+}
+/// The error codes AWS Cost and Usage Report Service retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Cost and Usage Report Service API.
 #[derive(Clone)]
 pub struct CostAndUsageReportClient {
@@ -318,6 +1118,24 @@ impl CostAndUsageReportClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_CUR`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CostAndUsageReportClient {
+        Self::new(region::Region::from_env_for_service("CUR"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `cur`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CostAndUsageReportClient {
+        Self::new_with_client(config.client().clone(), config.region_for("cur", "CUR"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -336,7 +1154,49 @@ impl CostAndUsageReportClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CostAndUsageReportClient {
-        CostAndUsageReportClient { client, region }
+        CostAndUsageReportClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "cur")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "cur", "cur", "CUR")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CostAndUsageReport` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CostAndUsageReport + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CostAndUsageReportClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Cost and Usage Report Service"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "cur"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CostAndUsageReportClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Data Pipeline clients.
+//!
+//! Makes a single read-only `ListPipelines` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_datapipeline::{DataPipeline, DataPipelineClient, ListPipelinesInput};
+
+fn main() {
+    let client = DataPipelineClient::from_env();
+    match client.list_pipelines(ListPipelinesInput::default()).sync() {
+        Ok(_) => println!("ListPipelines succeeded"),
+        Err(error) => {
+            eprintln!("ListPipelines failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
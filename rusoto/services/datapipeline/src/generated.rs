@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Contains the parameters for ActivatePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -35,8 +36,55 @@ pub struct ActivatePipelineInput {
     pub pipeline_id: String,
     /// <p>The date and time to resume the pipeline. By default, the pipeline resumes from the last completed execution.</p>
     #[serde(rename = "startTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_timestamp: Option<f64>,
+    pub start_timestamp: Option<EpochTimestamp>,
+}
+
+impl ActivatePipelineInput {
+    /// Returns a builder for `ActivatePipelineInput`, checking its required members when built.
+    pub fn builder() -> ActivatePipelineInputBuilder {
+        ActivatePipelineInputBuilder::default()
+    }
+}
+
+/// Builds a [`ActivatePipelineInput`](struct.ActivatePipelineInput.html).
+#[derive(Default, Debug)]
+pub struct ActivatePipelineInputBuilder {
+    parameter_values: Option<Vec<ParameterValue>>,
+    pipeline_id: Option<String>,
+    start_timestamp: Option<EpochTimestamp>,
+}
+
+impl ActivatePipelineInputBuilder {
+    /// Sets the [`parameter_values`](struct.ActivatePipelineInput.html#structfield.parameter_values) member.
+    pub fn parameter_values<T: Into<Vec<ParameterValue>>>(mut self, value: T) -> Self {
+        self.parameter_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_id`](struct.ActivatePipelineInput.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_timestamp`](struct.ActivatePipelineInput.html#structfield.start_timestamp) member.
+    pub fn start_timestamp<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_timestamp = Some(value.into());
+        self
+    }
+
+    /// Builds the `ActivatePipelineInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ActivatePipelineInput, ::rusoto_core::BuildError> {
+        Ok(ActivatePipelineInput {
+            parameter_values: self.parameter_values,
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+            start_timestamp: self.start_timestamp,
+        })
+    }
 }
 
 /// <p>Contains the output of ActivatePipeline.</p>
@@ -55,6 +103,46 @@ pub struct AddTagsInput {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsInput {
+    /// Returns a builder for `AddTagsInput`, checking its required members when built.
+    pub fn builder() -> AddTagsInputBuilder {
+        AddTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsInput`](struct.AddTagsInput.html).
+#[derive(Default, Debug)]
+pub struct AddTagsInputBuilder {
+    pipeline_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsInputBuilder {
+    /// Sets the [`pipeline_id`](struct.AddTagsInput.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddTagsInput, ::rusoto_core::BuildError> {
+        Ok(AddTagsInput {
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of AddTags.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -79,6 +167,62 @@ pub struct CreatePipelineInput {
     pub unique_id: String,
 }
 
+impl CreatePipelineInput {
+    /// Returns a builder for `CreatePipelineInput`, checking its required members when built.
+    pub fn builder() -> CreatePipelineInputBuilder {
+        CreatePipelineInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePipelineInput`](struct.CreatePipelineInput.html).
+#[derive(Default, Debug)]
+pub struct CreatePipelineInputBuilder {
+    description: Option<String>,
+    name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    unique_id: Option<String>,
+}
+
+impl CreatePipelineInputBuilder {
+    /// Sets the [`description`](struct.CreatePipelineInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreatePipelineInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreatePipelineInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unique_id`](struct.CreatePipelineInput.html#structfield.unique_id) member.
+    pub fn unique_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.unique_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePipelineInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePipelineInput, ::rusoto_core::BuildError> {
+        Ok(CreatePipelineInput {
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+            unique_id: self
+                .unique_id
+                .ok_or(::rusoto_core::BuildError::missing("unique_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of CreatePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -100,6 +244,46 @@ pub struct DeactivatePipelineInput {
     pub pipeline_id: String,
 }
 
+impl DeactivatePipelineInput {
+    /// Returns a builder for `DeactivatePipelineInput`, checking its required members when built.
+    pub fn builder() -> DeactivatePipelineInputBuilder {
+        DeactivatePipelineInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeactivatePipelineInput`](struct.DeactivatePipelineInput.html).
+#[derive(Default, Debug)]
+pub struct DeactivatePipelineInputBuilder {
+    cancel_active: Option<bool>,
+    pipeline_id: Option<String>,
+}
+
+impl DeactivatePipelineInputBuilder {
+    /// Sets the [`cancel_active`](struct.DeactivatePipelineInput.html#structfield.cancel_active) member.
+    pub fn cancel_active<T: Into<bool>>(mut self, value: T) -> Self {
+        self.cancel_active = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_id`](struct.DeactivatePipelineInput.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeactivatePipelineInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeactivatePipelineInput, ::rusoto_core::BuildError> {
+        Ok(DeactivatePipelineInput {
+            cancel_active: self.cancel_active,
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of DeactivatePipeline.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -113,6 +297,36 @@ pub struct DeletePipelineInput {
     pub pipeline_id: String,
 }
 
+impl DeletePipelineInput {
+    /// Returns a builder for `DeletePipelineInput`, checking its required members when built.
+    pub fn builder() -> DeletePipelineInputBuilder {
+        DeletePipelineInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePipelineInput`](struct.DeletePipelineInput.html).
+#[derive(Default, Debug)]
+pub struct DeletePipelineInputBuilder {
+    pipeline_id: Option<String>,
+}
+
+impl DeletePipelineInputBuilder {
+    /// Sets the [`pipeline_id`](struct.DeletePipelineInput.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePipelineInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePipelineInput, ::rusoto_core::BuildError> {
+        Ok(DeletePipelineInput {
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the parameters for DescribeObjects.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeObjectsInput {
@@ -132,6 +346,62 @@ pub struct DescribeObjectsInput {
     pub pipeline_id: String,
 }
 
+impl DescribeObjectsInput {
+    /// Returns a builder for `DescribeObjectsInput`, checking its required members when built.
+    pub fn builder() -> DescribeObjectsInputBuilder {
+        DescribeObjectsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeObjectsInput`](struct.DescribeObjectsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeObjectsInputBuilder {
+    evaluate_expressions: Option<bool>,
+    marker: Option<String>,
+    object_ids: Option<Vec<String>>,
+    pipeline_id: Option<String>,
+}
+
+impl DescribeObjectsInputBuilder {
+    /// Sets the [`evaluate_expressions`](struct.DescribeObjectsInput.html#structfield.evaluate_expressions) member.
+    pub fn evaluate_expressions<T: Into<bool>>(mut self, value: T) -> Self {
+        self.evaluate_expressions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeObjectsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_ids`](struct.DescribeObjectsInput.html#structfield.object_ids) member.
+    pub fn object_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.object_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_id`](struct.DescribeObjectsInput.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeObjectsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeObjectsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeObjectsInput {
+            evaluate_expressions: self.evaluate_expressions,
+            marker: self.marker,
+            object_ids: self
+                .object_ids
+                .ok_or(::rusoto_core::BuildError::missing("object_ids"))?,
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of DescribeObjects.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -157,6 +427,36 @@ pub struct DescribePipelinesInput {
     pub pipeline_ids: Vec<String>,
 }
 
+impl DescribePipelinesInput {
+    /// Returns a builder for `DescribePipelinesInput`, checking its required members when built.
+    pub fn builder() -> DescribePipelinesInputBuilder {
+        DescribePipelinesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePipelinesInput`](struct.DescribePipelinesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribePipelinesInputBuilder {
+    pipeline_ids: Option<Vec<String>>,
+}
+
+impl DescribePipelinesInputBuilder {
+    /// Sets the [`pipeline_ids`](struct.DescribePipelinesInput.html#structfield.pipeline_ids) member.
+    pub fn pipeline_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.pipeline_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePipelinesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribePipelinesInput, ::rusoto_core::BuildError> {
+        Ok(DescribePipelinesInput {
+            pipeline_ids: self
+                .pipeline_ids
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_ids"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of DescribePipelines.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -180,6 +480,58 @@ pub struct EvaluateExpressionInput {
     pub pipeline_id: String,
 }
 
+impl EvaluateExpressionInput {
+    /// Returns a builder for `EvaluateExpressionInput`, checking its required members when built.
+    pub fn builder() -> EvaluateExpressionInputBuilder {
+        EvaluateExpressionInputBuilder::default()
+    }
+}
+
+/// Builds a [`EvaluateExpressionInput`](struct.EvaluateExpressionInput.html).
+#[derive(Default, Debug)]
+pub struct EvaluateExpressionInputBuilder {
+    expression: Option<String>,
+    object_id: Option<String>,
+    pipeline_id: Option<String>,
+}
+
+impl EvaluateExpressionInputBuilder {
+    /// Sets the [`expression`](struct.EvaluateExpressionInput.html#structfield.expression) member.
+    pub fn expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`object_id`](struct.EvaluateExpressionInput.html#structfield.object_id) member.
+    pub fn object_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.object_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_id`](struct.EvaluateExpressionInput.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `EvaluateExpressionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EvaluateExpressionInput, ::rusoto_core::BuildError> {
+        Ok(EvaluateExpressionInput {
+            expression: self
+                .expression
+                .ok_or(::rusoto_core::BuildError::missing("expression"))?,
+            object_id: self
+                .object_id
+                .ok_or(::rusoto_core::BuildError::missing("object_id"))?,
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of EvaluateExpression.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -217,6 +569,46 @@ pub struct GetPipelineDefinitionInput {
     pub version: Option<String>,
 }
 
+impl GetPipelineDefinitionInput {
+    /// Returns a builder for `GetPipelineDefinitionInput`, checking its required members when built.
+    pub fn builder() -> GetPipelineDefinitionInputBuilder {
+        GetPipelineDefinitionInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetPipelineDefinitionInput`](struct.GetPipelineDefinitionInput.html).
+#[derive(Default, Debug)]
+pub struct GetPipelineDefinitionInputBuilder {
+    pipeline_id: Option<String>,
+    version: Option<String>,
+}
+
+impl GetPipelineDefinitionInputBuilder {
+    /// Sets the [`pipeline_id`](struct.GetPipelineDefinitionInput.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.GetPipelineDefinitionInput.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPipelineDefinitionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetPipelineDefinitionInput, ::rusoto_core::BuildError> {
+        Ok(GetPipelineDefinitionInput {
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+            version: self.version,
+        })
+    }
+}
+
 /// <p>Contains the output of GetPipelineDefinition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -257,6 +649,34 @@ pub struct ListPipelinesInput {
     pub marker: Option<String>,
 }
 
+impl ListPipelinesInput {
+    /// Returns a builder for `ListPipelinesInput`, checking its required members when built.
+    pub fn builder() -> ListPipelinesInputBuilder {
+        ListPipelinesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListPipelinesInput`](struct.ListPipelinesInput.html).
+#[derive(Default, Debug)]
+pub struct ListPipelinesInputBuilder {
+    marker: Option<String>,
+}
+
+impl ListPipelinesInputBuilder {
+    /// Sets the [`marker`](struct.ListPipelinesInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPipelinesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPipelinesInput, ::rusoto_core::BuildError> {
+        Ok(ListPipelinesInput {
+            marker: self.marker,
+        })
+    }
+}
+
 /// <p>Contains the output of ListPipelines.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -280,13 +700,97 @@ pub struct Operator {
     /// <p> The logical operation to be performed: equal (<code>EQ</code>), equal reference (<code>REF_EQ</code>), less than or equal (<code>LE</code>), greater than or equal (<code>GE</code>), or between (<code>BETWEEN</code>). Equal reference (<code>REF_EQ</code>) can be used only with reference fields. The other comparison types can be used only with String fields. The comparison types you can use apply only to certain object fields, as detailed below. </p> <p> The comparison operators EQ and REF_EQ act on the following fields: </p> <ul> <li>name</li> <li>@sphere</li> <li>parent</li> <li>@componentParent</li> <li>@instanceParent</li> <li>@status</li> <li>@scheduledStartTime</li> <li>@scheduledEndTime</li> <li>@actualStartTime</li> <li>@actualEndTime</li> </ul> <p> The comparison operators <code>GE</code>, <code>LE</code>, and <code>BETWEEN</code> act on the following fields: </p> <ul> <li>@scheduledStartTime</li> <li>@scheduledEndTime</li> <li>@actualStartTime</li> <li>@actualEndTime</li> </ul> <p>Note that fields beginning with the at sign (@) are read-only and set by the web service. When you name fields, you should choose names containing only alpha-numeric values, as symbols may be reserved by AWS Data Pipeline. User-defined fields that you add to a pipeline should prefix their name with the string "my".</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OperatorType>,
     /// <p>The value that the actual field value will be compared with.</p>
     #[serde(rename = "values")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
 }
 
+/// <p> The logical operation to be performed: equal (<code>EQ</code>), equal reference (<code>REF_EQ</code>), less than or equal (<code>LE</code>), greater than or equal (<code>GE</code>), or between (<code>BETWEEN</code>). Equal reference (<code>REF_EQ</code>) can be used only with reference fields. The other comparison types can be used only with String fields. The comparison types you can use apply only to certain object fields, as detailed below. </p> <p> The comparison operators EQ and REF_EQ act on the following fields: </p> <ul> <li>name</li> <li>@sphere</li> <li>parent</li> <li>@componentParent</li> <li>@instanceParent</li> <li>@status</li> <li>@scheduledStartTime</li> <li>@scheduledEndTime</li> <li>@actualStartTime</li> <li>@actualEndTime</li> </ul> <p> The comparison operators <code>GE</code>, <code>LE</code>, and <code>BETWEEN</code> act on the following fields: </p> <ul> <li>@scheduledStartTime</li> <li>@scheduledEndTime</li> <li>@actualStartTime</li> <li>@actualEndTime</li> </ul> <p>Note that fields beginning with the at sign (@) are read-only and set by the web service. When you name fields, you should choose names containing only alpha-numeric values, as symbols may be reserved by AWS Data Pipeline. User-defined fields that you add to a pipeline should prefix their name with the string "my".</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperatorType {
+    /// <p><code>EQ</code></p>
+    Eq,
+    /// <p><code>REF_EQ</code></p>
+    RefEq,
+    /// <p><code>LE</code></p>
+    Le,
+    /// <p><code>GE</code></p>
+    Ge,
+    /// <p><code>BETWEEN</code></p>
+    Between,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OperatorType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperatorType::Eq => "EQ",
+            OperatorType::RefEq => "REF_EQ",
+            OperatorType::Le => "LE",
+            OperatorType::Ge => "GE",
+            OperatorType::Between => "BETWEEN",
+            OperatorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperatorType {
+    fn from(value: &'a str) -> OperatorType {
+        match value {
+            "EQ" => OperatorType::Eq,
+            "REF_EQ" => OperatorType::RefEq,
+            "LE" => OperatorType::Le,
+            "GE" => OperatorType::Ge,
+            "BETWEEN" => OperatorType::Between,
+            _ => OperatorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperatorType {
+    fn from(value: String) -> OperatorType {
+        OperatorType::from(value.as_str())
+    }
+}
+
+impl From<OperatorType> for String {
+    fn from(value: OperatorType) -> String {
+        match value {
+            OperatorType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OperatorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OperatorType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperatorType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OperatorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OperatorType::from(value))
+    }
+}
+
 /// <p>The attributes allowed or specified with a parameter object.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParameterAttribute {
@@ -387,6 +891,52 @@ pub struct PollForTaskInput {
     pub worker_group: String,
 }
 
+impl PollForTaskInput {
+    /// Returns a builder for `PollForTaskInput`, checking its required members when built.
+    pub fn builder() -> PollForTaskInputBuilder {
+        PollForTaskInputBuilder::default()
+    }
+}
+
+/// Builds a [`PollForTaskInput`](struct.PollForTaskInput.html).
+#[derive(Default, Debug)]
+pub struct PollForTaskInputBuilder {
+    hostname: Option<String>,
+    instance_identity: Option<InstanceIdentity>,
+    worker_group: Option<String>,
+}
+
+impl PollForTaskInputBuilder {
+    /// Sets the [`hostname`](struct.PollForTaskInput.html#structfield.hostname) member.
+    pub fn hostname<T: Into<String>>(mut self, value: T) -> Self {
+        self.hostname = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_identity`](struct.PollForTaskInput.html#structfield.instance_identity) member.
+    pub fn instance_identity<T: Into<InstanceIdentity>>(mut self, value: T) -> Self {
+        self.instance_identity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_group`](struct.PollForTaskInput.html#structfield.worker_group) member.
+    pub fn worker_group<T: Into<String>>(mut self, value: T) -> Self {
+        self.worker_group = Some(value.into());
+        self
+    }
+
+    /// Builds the `PollForTaskInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PollForTaskInput, ::rusoto_core::BuildError> {
+        Ok(PollForTaskInput {
+            hostname: self.hostname,
+            instance_identity: self.instance_identity,
+            worker_group: self
+                .worker_group
+                .ok_or(::rusoto_core::BuildError::missing("worker_group"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of PollForTask.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -416,6 +966,64 @@ pub struct PutPipelineDefinitionInput {
     pub pipeline_objects: Vec<PipelineObject>,
 }
 
+impl PutPipelineDefinitionInput {
+    /// Returns a builder for `PutPipelineDefinitionInput`, checking its required members when built.
+    pub fn builder() -> PutPipelineDefinitionInputBuilder {
+        PutPipelineDefinitionInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutPipelineDefinitionInput`](struct.PutPipelineDefinitionInput.html).
+#[derive(Default, Debug)]
+pub struct PutPipelineDefinitionInputBuilder {
+    parameter_objects: Option<Vec<ParameterObject>>,
+    parameter_values: Option<Vec<ParameterValue>>,
+    pipeline_id: Option<String>,
+    pipeline_objects: Option<Vec<PipelineObject>>,
+}
+
+impl PutPipelineDefinitionInputBuilder {
+    /// Sets the [`parameter_objects`](struct.PutPipelineDefinitionInput.html#structfield.parameter_objects) member.
+    pub fn parameter_objects<T: Into<Vec<ParameterObject>>>(mut self, value: T) -> Self {
+        self.parameter_objects = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_values`](struct.PutPipelineDefinitionInput.html#structfield.parameter_values) member.
+    pub fn parameter_values<T: Into<Vec<ParameterValue>>>(mut self, value: T) -> Self {
+        self.parameter_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_id`](struct.PutPipelineDefinitionInput.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_objects`](struct.PutPipelineDefinitionInput.html#structfield.pipeline_objects) member.
+    pub fn pipeline_objects<T: Into<Vec<PipelineObject>>>(mut self, value: T) -> Self {
+        self.pipeline_objects = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutPipelineDefinitionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutPipelineDefinitionInput, ::rusoto_core::BuildError> {
+        Ok(PutPipelineDefinitionInput {
+            parameter_objects: self.parameter_objects,
+            parameter_values: self.parameter_values,
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+            pipeline_objects: self
+                .pipeline_objects
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_objects"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of PutPipelineDefinition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -465,6 +1073,70 @@ pub struct QueryObjectsInput {
     pub sphere: String,
 }
 
+impl QueryObjectsInput {
+    /// Returns a builder for `QueryObjectsInput`, checking its required members when built.
+    pub fn builder() -> QueryObjectsInputBuilder {
+        QueryObjectsInputBuilder::default()
+    }
+}
+
+/// Builds a [`QueryObjectsInput`](struct.QueryObjectsInput.html).
+#[derive(Default, Debug)]
+pub struct QueryObjectsInputBuilder {
+    limit: Option<i64>,
+    marker: Option<String>,
+    pipeline_id: Option<String>,
+    query: Option<Query>,
+    sphere: Option<String>,
+}
+
+impl QueryObjectsInputBuilder {
+    /// Sets the [`limit`](struct.QueryObjectsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.QueryObjectsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_id`](struct.QueryObjectsInput.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query`](struct.QueryObjectsInput.html#structfield.query) member.
+    pub fn query<T: Into<Query>>(mut self, value: T) -> Self {
+        self.query = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sphere`](struct.QueryObjectsInput.html#structfield.sphere) member.
+    pub fn sphere<T: Into<String>>(mut self, value: T) -> Self {
+        self.sphere = Some(value.into());
+        self
+    }
+
+    /// Builds the `QueryObjectsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<QueryObjectsInput, ::rusoto_core::BuildError> {
+        Ok(QueryObjectsInput {
+            limit: self.limit,
+            marker: self.marker,
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+            query: self.query,
+            sphere: self
+                .sphere
+                .ok_or(::rusoto_core::BuildError::missing("sphere"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of QueryObjects.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -494,6 +1166,46 @@ pub struct RemoveTagsInput {
     pub tag_keys: Vec<String>,
 }
 
+impl RemoveTagsInput {
+    /// Returns a builder for `RemoveTagsInput`, checking its required members when built.
+    pub fn builder() -> RemoveTagsInputBuilder {
+        RemoveTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsInput`](struct.RemoveTagsInput.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsInputBuilder {
+    pipeline_id: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl RemoveTagsInputBuilder {
+    /// Sets the [`pipeline_id`](struct.RemoveTagsInput.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.RemoveTagsInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RemoveTagsInput, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsInput {
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of RemoveTags.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -511,6 +1223,46 @@ pub struct ReportTaskProgressInput {
     pub task_id: String,
 }
 
+impl ReportTaskProgressInput {
+    /// Returns a builder for `ReportTaskProgressInput`, checking its required members when built.
+    pub fn builder() -> ReportTaskProgressInputBuilder {
+        ReportTaskProgressInputBuilder::default()
+    }
+}
+
+/// Builds a [`ReportTaskProgressInput`](struct.ReportTaskProgressInput.html).
+#[derive(Default, Debug)]
+pub struct ReportTaskProgressInputBuilder {
+    fields: Option<Vec<Field>>,
+    task_id: Option<String>,
+}
+
+impl ReportTaskProgressInputBuilder {
+    /// Sets the [`fields`](struct.ReportTaskProgressInput.html#structfield.fields) member.
+    pub fn fields<T: Into<Vec<Field>>>(mut self, value: T) -> Self {
+        self.fields = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_id`](struct.ReportTaskProgressInput.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReportTaskProgressInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ReportTaskProgressInput, ::rusoto_core::BuildError> {
+        Ok(ReportTaskProgressInput {
+            fields: self.fields,
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of ReportTaskProgress.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -536,6 +1288,54 @@ pub struct ReportTaskRunnerHeartbeatInput {
     pub worker_group: Option<String>,
 }
 
+impl ReportTaskRunnerHeartbeatInput {
+    /// Returns a builder for `ReportTaskRunnerHeartbeatInput`, checking its required members when built.
+    pub fn builder() -> ReportTaskRunnerHeartbeatInputBuilder {
+        ReportTaskRunnerHeartbeatInputBuilder::default()
+    }
+}
+
+/// Builds a [`ReportTaskRunnerHeartbeatInput`](struct.ReportTaskRunnerHeartbeatInput.html).
+#[derive(Default, Debug)]
+pub struct ReportTaskRunnerHeartbeatInputBuilder {
+    hostname: Option<String>,
+    taskrunner_id: Option<String>,
+    worker_group: Option<String>,
+}
+
+impl ReportTaskRunnerHeartbeatInputBuilder {
+    /// Sets the [`hostname`](struct.ReportTaskRunnerHeartbeatInput.html#structfield.hostname) member.
+    pub fn hostname<T: Into<String>>(mut self, value: T) -> Self {
+        self.hostname = Some(value.into());
+        self
+    }
+
+    /// Sets the [`taskrunner_id`](struct.ReportTaskRunnerHeartbeatInput.html#structfield.taskrunner_id) member.
+    pub fn taskrunner_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.taskrunner_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_group`](struct.ReportTaskRunnerHeartbeatInput.html#structfield.worker_group) member.
+    pub fn worker_group<T: Into<String>>(mut self, value: T) -> Self {
+        self.worker_group = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReportTaskRunnerHeartbeatInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ReportTaskRunnerHeartbeatInput, ::rusoto_core::BuildError> {
+        Ok(ReportTaskRunnerHeartbeatInput {
+            hostname: self.hostname,
+            taskrunner_id: self
+                .taskrunner_id
+                .ok_or(::rusoto_core::BuildError::missing("taskrunner_id"))?,
+            worker_group: self.worker_group,
+        })
+    }
+}
+
 /// <p>Contains the output of ReportTaskRunnerHeartbeat.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -571,8 +1371,58 @@ pub struct SetStatusInput {
     pub status: String,
 }
 
+impl SetStatusInput {
+    /// Returns a builder for `SetStatusInput`, checking its required members when built.
+    pub fn builder() -> SetStatusInputBuilder {
+        SetStatusInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetStatusInput`](struct.SetStatusInput.html).
+#[derive(Default, Debug)]
+pub struct SetStatusInputBuilder {
+    object_ids: Option<Vec<String>>,
+    pipeline_id: Option<String>,
+    status: Option<String>,
+}
+
+impl SetStatusInputBuilder {
+    /// Sets the [`object_ids`](struct.SetStatusInput.html#structfield.object_ids) member.
+    pub fn object_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.object_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_id`](struct.SetStatusInput.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.SetStatusInput.html#structfield.status) member.
+    pub fn status<T: Into<String>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetStatusInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetStatusInput, ::rusoto_core::BuildError> {
+        Ok(SetStatusInput {
+            object_ids: self
+                .object_ids
+                .ok_or(::rusoto_core::BuildError::missing("object_ids"))?,
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+            status: self
+                .status
+                .ok_or(::rusoto_core::BuildError::missing("status"))?,
+        })
+    }
+}
+
 /// <p>Contains the parameters for SetTaskStatus.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SetTaskStatusInput {
     /// <p>If an error occurred during the task, this value specifies the error code. This value is set on the physical attempt object. It is used to display error information to the user. It should not start with string "Service_" which is reserved by the system.</p>
     #[serde(rename = "errorId")]
@@ -591,7 +1441,71 @@ pub struct SetTaskStatusInput {
     pub task_id: String,
     /// <p>If <code>FINISHED</code>, the task successfully completed. If <code>FAILED</code>, the task ended unsuccessfully. Preconditions use false.</p>
     #[serde(rename = "taskStatus")]
-    pub task_status: String,
+    pub task_status: TaskStatus,
+}
+
+impl SetTaskStatusInput {
+    /// Returns a builder for `SetTaskStatusInput`, checking its required members when built.
+    pub fn builder() -> SetTaskStatusInputBuilder {
+        SetTaskStatusInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetTaskStatusInput`](struct.SetTaskStatusInput.html).
+#[derive(Default, Debug)]
+pub struct SetTaskStatusInputBuilder {
+    error_id: Option<String>,
+    error_message: Option<String>,
+    error_stack_trace: Option<String>,
+    task_id: Option<String>,
+    task_status: Option<TaskStatus>,
+}
+
+impl SetTaskStatusInputBuilder {
+    /// Sets the [`error_id`](struct.SetTaskStatusInput.html#structfield.error_id) member.
+    pub fn error_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.error_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`error_message`](struct.SetTaskStatusInput.html#structfield.error_message) member.
+    pub fn error_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.error_message = Some(value.into());
+        self
+    }
+
+    /// Sets the [`error_stack_trace`](struct.SetTaskStatusInput.html#structfield.error_stack_trace) member.
+    pub fn error_stack_trace<T: Into<String>>(mut self, value: T) -> Self {
+        self.error_stack_trace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_id`](struct.SetTaskStatusInput.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_status`](struct.SetTaskStatusInput.html#structfield.task_status) member.
+    pub fn task_status<T: Into<TaskStatus>>(mut self, value: T) -> Self {
+        self.task_status = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetTaskStatusInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetTaskStatusInput, ::rusoto_core::BuildError> {
+        Ok(SetTaskStatusInput {
+            error_id: self.error_id,
+            error_message: self.error_message,
+            error_stack_trace: self.error_stack_trace,
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+            task_status: self
+                .task_status
+                .ok_or(::rusoto_core::BuildError::missing("task_status"))?,
+        })
+    }
 }
 
 /// <p>Contains the output of SetTaskStatus.</p>
@@ -632,6 +1546,82 @@ pub struct TaskObject {
     pub task_id: Option<String>,
 }
 
+/// <p>If <code>FINISHED</code>, the task successfully completed. If <code>FAILED</code>, the task ended unsuccessfully. Preconditions use false.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaskStatus {
+    /// <p><code>FINISHED</code></p>
+    Finished,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>FALSE</code></p>
+    False,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TaskStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskStatus::Finished => "FINISHED",
+            TaskStatus::Failed => "FAILED",
+            TaskStatus::False => "FALSE",
+            TaskStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaskStatus {
+    fn from(value: &'a str) -> TaskStatus {
+        match value {
+            "FINISHED" => TaskStatus::Finished,
+            "FAILED" => TaskStatus::Failed,
+            "FALSE" => TaskStatus::False,
+            _ => TaskStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskStatus {
+    fn from(value: String) -> TaskStatus {
+        TaskStatus::from(value.as_str())
+    }
+}
+
+impl From<TaskStatus> for String {
+    fn from(value: TaskStatus) -> String {
+        match value {
+            TaskStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TaskStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TaskStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TaskStatus::from(value))
+    }
+}
+
 /// <p>Contains the parameters for ValidatePipelineDefinition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ValidatePipelineDefinitionInput {
@@ -651,6 +1641,64 @@ pub struct ValidatePipelineDefinitionInput {
     pub pipeline_objects: Vec<PipelineObject>,
 }
 
+impl ValidatePipelineDefinitionInput {
+    /// Returns a builder for `ValidatePipelineDefinitionInput`, checking its required members when built.
+    pub fn builder() -> ValidatePipelineDefinitionInputBuilder {
+        ValidatePipelineDefinitionInputBuilder::default()
+    }
+}
+
+/// Builds a [`ValidatePipelineDefinitionInput`](struct.ValidatePipelineDefinitionInput.html).
+#[derive(Default, Debug)]
+pub struct ValidatePipelineDefinitionInputBuilder {
+    parameter_objects: Option<Vec<ParameterObject>>,
+    parameter_values: Option<Vec<ParameterValue>>,
+    pipeline_id: Option<String>,
+    pipeline_objects: Option<Vec<PipelineObject>>,
+}
+
+impl ValidatePipelineDefinitionInputBuilder {
+    /// Sets the [`parameter_objects`](struct.ValidatePipelineDefinitionInput.html#structfield.parameter_objects) member.
+    pub fn parameter_objects<T: Into<Vec<ParameterObject>>>(mut self, value: T) -> Self {
+        self.parameter_objects = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_values`](struct.ValidatePipelineDefinitionInput.html#structfield.parameter_values) member.
+    pub fn parameter_values<T: Into<Vec<ParameterValue>>>(mut self, value: T) -> Self {
+        self.parameter_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_id`](struct.ValidatePipelineDefinitionInput.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_objects`](struct.ValidatePipelineDefinitionInput.html#structfield.pipeline_objects) member.
+    pub fn pipeline_objects<T: Into<Vec<PipelineObject>>>(mut self, value: T) -> Self {
+        self.pipeline_objects = Some(value.into());
+        self
+    }
+
+    /// Builds the `ValidatePipelineDefinitionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ValidatePipelineDefinitionInput, ::rusoto_core::BuildError> {
+        Ok(ValidatePipelineDefinitionInput {
+            parameter_objects: self.parameter_objects,
+            parameter_values: self.parameter_values,
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+            pipeline_objects: self
+                .pipeline_objects
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_objects"))?,
+        })
+    }
+}
+
 /// <p>Contains the output of ValidatePipelineDefinition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1784,6 +2832,120 @@ pub trait DataPipeline {
         input: ValidatePipelineDefinitionInput,
     ) -> RusotoFuture<ValidatePipelineDefinitionOutput, ValidatePipelineDefinitionError>;
 }
+impl<T: ?Sized + DataPipeline> DataPipeline for ::std::sync::Arc<T> {
+    fn activate_pipeline(
+        &self,
+        input: ActivatePipelineInput,
+    ) -> RusotoFuture<ActivatePipelineOutput, ActivatePipelineError> {
+        (**self).activate_pipeline(input)
+    }
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> RusotoFuture<CreatePipelineOutput, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn deactivate_pipeline(
+        &self,
+        input: DeactivatePipelineInput,
+    ) -> RusotoFuture<DeactivatePipelineOutput, DeactivatePipelineError> {
+        (**self).deactivate_pipeline(input)
+    }
+    fn delete_pipeline(&self, input: DeletePipelineInput) -> RusotoFuture<(), DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn describe_objects(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> RusotoFuture<DescribeObjectsOutput, DescribeObjectsError> {
+        (**self).describe_objects(input)
+    }
+    fn describe_pipelines(
+        &self,
+        input: DescribePipelinesInput,
+    ) -> RusotoFuture<DescribePipelinesOutput, DescribePipelinesError> {
+        (**self).describe_pipelines(input)
+    }
+    fn evaluate_expression(
+        &self,
+        input: EvaluateExpressionInput,
+    ) -> RusotoFuture<EvaluateExpressionOutput, EvaluateExpressionError> {
+        (**self).evaluate_expression(input)
+    }
+    fn get_pipeline_definition(
+        &self,
+        input: GetPipelineDefinitionInput,
+    ) -> RusotoFuture<GetPipelineDefinitionOutput, GetPipelineDefinitionError> {
+        (**self).get_pipeline_definition(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> RusotoFuture<ListPipelinesOutput, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn poll_for_task(
+        &self,
+        input: PollForTaskInput,
+    ) -> RusotoFuture<PollForTaskOutput, PollForTaskError> {
+        (**self).poll_for_task(input)
+    }
+    fn put_pipeline_definition(
+        &self,
+        input: PutPipelineDefinitionInput,
+    ) -> RusotoFuture<PutPipelineDefinitionOutput, PutPipelineDefinitionError> {
+        (**self).put_pipeline_definition(input)
+    }
+    fn query_objects(
+        &self,
+        input: QueryObjectsInput,
+    ) -> RusotoFuture<QueryObjectsOutput, QueryObjectsError> {
+        (**self).query_objects(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn report_task_progress(
+        &self,
+        input: ReportTaskProgressInput,
+    ) -> RusotoFuture<ReportTaskProgressOutput, ReportTaskProgressError> {
+        (**self).report_task_progress(input)
+    }
+    fn report_task_runner_heartbeat(
+        &self,
+        input: ReportTaskRunnerHeartbeatInput,
+    ) -> RusotoFuture<ReportTaskRunnerHeartbeatOutput, ReportTaskRunnerHeartbeatError> {
+        (**self).report_task_runner_heartbeat(input)
+    }
+    fn set_status(&self, input: SetStatusInput) -> RusotoFuture<(), SetStatusError> {
+        (**self).set_status(input)
+    }
+    fn set_task_status(
+        &self,
+        input: SetTaskStatusInput,
+    ) -> RusotoFuture<SetTaskStatusOutput, SetTaskStatusError> {
+        (**self).set_task_status(input)
+    }
+    fn validate_pipeline_definition(
+        &self,
+        input: ValidatePipelineDefinitionInput,
+    ) -> RusotoFuture<ValidatePipelineDefinitionOutput, ValidatePipelineDefinitionError> {
+        (**self).validate_pipeline_definition(input)
+    }
+}
+/// The error codes AWS Data Pipeline retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Data Pipeline API.
 #[derive(Clone)]
 pub struct DataPipelineClient {
@@ -1799,6 +2961,27 @@ impl DataPipelineClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_DATAPIPELINE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> DataPipelineClient {
+        Self::new(region::Region::from_env_for_service("DATAPIPELINE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `datapipeline`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> DataPipelineClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("datapipeline", "DATAPIPELINE"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1817,7 +3000,54 @@ impl DataPipelineClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DataPipelineClient {
-        DataPipelineClient { client, region }
+        DataPipelineClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "datapipeline")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "datapipeline",
+            "datapipeline",
+            "DATAPIPELINE",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `DataPipeline` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn DataPipeline + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for DataPipelineClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Data Pipeline"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "datapipeline"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> DataPipelineClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon DynamoDB Accelerator (DAX) clients.
+//!
+//! Makes a single read-only `DescribeClusters` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_dax::{DescribeClustersRequest, DynamodbAccelerator, DynamodbAcceleratorClient};
+
+fn main() {
+    let client = DynamodbAcceleratorClient::from_env();
+    match client
+        .describe_clusters(DescribeClustersRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeClusters succeeded"),
+        Err(error) => {
+            eprintln!("DescribeClusters failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,7 +22,80 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The conditions under which changes to this parameter can be applied. For example, <code>requires-reboot</code> indicates that a new value for this parameter will only take effect if a node is rebooted.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeType {
+    /// <p><code>IMMEDIATE</code></p>
+    Immediate,
+    /// <p><code>REQUIRES_REBOOT</code></p>
+    RequiresReboot,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeType::Immediate => "IMMEDIATE",
+            ChangeType::RequiresReboot => "REQUIRES_REBOOT",
+            ChangeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeType {
+    fn from(value: &'a str) -> ChangeType {
+        match value {
+            "IMMEDIATE" => ChangeType::Immediate,
+            "REQUIRES_REBOOT" => ChangeType::RequiresReboot,
+            _ => ChangeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeType {
+    fn from(value: String) -> ChangeType {
+        ChangeType::from(value.as_str())
+    }
+}
+
+impl From<ChangeType> for String {
+    fn from(value: ChangeType) -> String {
+        match value {
+            ChangeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ChangeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChangeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChangeType::from(value))
+    }
+}
+
 /// <p>Contains all of the attributes of a specific DAX cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -149,6 +222,138 @@ pub struct CreateClusterRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateClusterRequest {
+    /// Returns a builder for `CreateClusterRequest`, checking its required members when built.
+    pub fn builder() -> CreateClusterRequestBuilder {
+        CreateClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateClusterRequest`](struct.CreateClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateClusterRequestBuilder {
+    availability_zones: Option<Vec<String>>,
+    cluster_name: Option<String>,
+    description: Option<String>,
+    iam_role_arn: Option<String>,
+    node_type: Option<String>,
+    notification_topic_arn: Option<String>,
+    parameter_group_name: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    replication_factor: Option<i64>,
+    sse_specification: Option<SSESpecification>,
+    security_group_ids: Option<Vec<String>>,
+    subnet_group_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateClusterRequestBuilder {
+    /// Sets the [`availability_zones`](struct.CreateClusterRequest.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_name`](struct.CreateClusterRequest.html#structfield.cluster_name) member.
+    pub fn cluster_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateClusterRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_role_arn`](struct.CreateClusterRequest.html#structfield.iam_role_arn) member.
+    pub fn iam_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_type`](struct.CreateClusterRequest.html#structfield.node_type) member.
+    pub fn node_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.node_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_topic_arn`](struct.CreateClusterRequest.html#structfield.notification_topic_arn) member.
+    pub fn notification_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_group_name`](struct.CreateClusterRequest.html#structfield.parameter_group_name) member.
+    pub fn parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateClusterRequest.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_factor`](struct.CreateClusterRequest.html#structfield.replication_factor) member.
+    pub fn replication_factor<T: Into<i64>>(mut self, value: T) -> Self {
+        self.replication_factor = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sse_specification`](struct.CreateClusterRequest.html#structfield.sse_specification) member.
+    pub fn sse_specification<T: Into<SSESpecification>>(mut self, value: T) -> Self {
+        self.sse_specification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_group_ids`](struct.CreateClusterRequest.html#structfield.security_group_ids) member.
+    pub fn security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_group_name`](struct.CreateClusterRequest.html#structfield.subnet_group_name) member.
+    pub fn subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateClusterRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateClusterRequest, ::rusoto_core::BuildError> {
+        Ok(CreateClusterRequest {
+            availability_zones: self.availability_zones,
+            cluster_name: self
+                .cluster_name
+                .ok_or(::rusoto_core::BuildError::missing("cluster_name"))?,
+            description: self.description,
+            iam_role_arn: self
+                .iam_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("iam_role_arn"))?,
+            node_type: self
+                .node_type
+                .ok_or(::rusoto_core::BuildError::missing("node_type"))?,
+            notification_topic_arn: self.notification_topic_arn,
+            parameter_group_name: self.parameter_group_name,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            replication_factor: self
+                .replication_factor
+                .ok_or(::rusoto_core::BuildError::missing("replication_factor"))?,
+            sse_specification: self.sse_specification,
+            security_group_ids: self.security_group_ids,
+            subnet_group_name: self.subnet_group_name,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateClusterResponse {
@@ -169,6 +374,46 @@ pub struct CreateParameterGroupRequest {
     pub parameter_group_name: String,
 }
 
+impl CreateParameterGroupRequest {
+    /// Returns a builder for `CreateParameterGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateParameterGroupRequestBuilder {
+        CreateParameterGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateParameterGroupRequest`](struct.CreateParameterGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateParameterGroupRequestBuilder {
+    description: Option<String>,
+    parameter_group_name: Option<String>,
+}
+
+impl CreateParameterGroupRequestBuilder {
+    /// Sets the [`description`](struct.CreateParameterGroupRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_group_name`](struct.CreateParameterGroupRequest.html#structfield.parameter_group_name) member.
+    pub fn parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateParameterGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateParameterGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateParameterGroupRequest {
+            description: self.description,
+            parameter_group_name: self
+                .parameter_group_name
+                .ok_or(::rusoto_core::BuildError::missing("parameter_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateParameterGroupResponse {
@@ -192,6 +437,56 @@ pub struct CreateSubnetGroupRequest {
     pub subnet_ids: Vec<String>,
 }
 
+impl CreateSubnetGroupRequest {
+    /// Returns a builder for `CreateSubnetGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateSubnetGroupRequestBuilder {
+        CreateSubnetGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSubnetGroupRequest`](struct.CreateSubnetGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSubnetGroupRequestBuilder {
+    description: Option<String>,
+    subnet_group_name: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+}
+
+impl CreateSubnetGroupRequestBuilder {
+    /// Sets the [`description`](struct.CreateSubnetGroupRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_group_name`](struct.CreateSubnetGroupRequest.html#structfield.subnet_group_name) member.
+    pub fn subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.CreateSubnetGroupRequest.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSubnetGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSubnetGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSubnetGroupRequest {
+            description: self.description,
+            subnet_group_name: self
+                .subnet_group_name
+                .ok_or(::rusoto_core::BuildError::missing("subnet_group_name"))?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateSubnetGroupResponse {
@@ -219,6 +514,64 @@ pub struct DecreaseReplicationFactorRequest {
     pub node_ids_to_remove: Option<Vec<String>>,
 }
 
+impl DecreaseReplicationFactorRequest {
+    /// Returns a builder for `DecreaseReplicationFactorRequest`, checking its required members when built.
+    pub fn builder() -> DecreaseReplicationFactorRequestBuilder {
+        DecreaseReplicationFactorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DecreaseReplicationFactorRequest`](struct.DecreaseReplicationFactorRequest.html).
+#[derive(Default, Debug)]
+pub struct DecreaseReplicationFactorRequestBuilder {
+    availability_zones: Option<Vec<String>>,
+    cluster_name: Option<String>,
+    new_replication_factor: Option<i64>,
+    node_ids_to_remove: Option<Vec<String>>,
+}
+
+impl DecreaseReplicationFactorRequestBuilder {
+    /// Sets the [`availability_zones`](struct.DecreaseReplicationFactorRequest.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_name`](struct.DecreaseReplicationFactorRequest.html#structfield.cluster_name) member.
+    pub fn cluster_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_replication_factor`](struct.DecreaseReplicationFactorRequest.html#structfield.new_replication_factor) member.
+    pub fn new_replication_factor<T: Into<i64>>(mut self, value: T) -> Self {
+        self.new_replication_factor = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_ids_to_remove`](struct.DecreaseReplicationFactorRequest.html#structfield.node_ids_to_remove) member.
+    pub fn node_ids_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.node_ids_to_remove = Some(value.into());
+        self
+    }
+
+    /// Builds the `DecreaseReplicationFactorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DecreaseReplicationFactorRequest, ::rusoto_core::BuildError> {
+        Ok(DecreaseReplicationFactorRequest {
+            availability_zones: self.availability_zones,
+            cluster_name: self
+                .cluster_name
+                .ok_or(::rusoto_core::BuildError::missing("cluster_name"))?,
+            new_replication_factor: self
+                .new_replication_factor
+                .ok_or(::rusoto_core::BuildError::missing("new_replication_factor"))?,
+            node_ids_to_remove: self.node_ids_to_remove,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DecreaseReplicationFactorResponse {
@@ -235,6 +588,36 @@ pub struct DeleteClusterRequest {
     pub cluster_name: String,
 }
 
+impl DeleteClusterRequest {
+    /// Returns a builder for `DeleteClusterRequest`, checking its required members when built.
+    pub fn builder() -> DeleteClusterRequestBuilder {
+        DeleteClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteClusterRequest`](struct.DeleteClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteClusterRequestBuilder {
+    cluster_name: Option<String>,
+}
+
+impl DeleteClusterRequestBuilder {
+    /// Sets the [`cluster_name`](struct.DeleteClusterRequest.html#structfield.cluster_name) member.
+    pub fn cluster_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteClusterRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteClusterRequest {
+            cluster_name: self
+                .cluster_name
+                .ok_or(::rusoto_core::BuildError::missing("cluster_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteClusterResponse {
@@ -251,6 +634,38 @@ pub struct DeleteParameterGroupRequest {
     pub parameter_group_name: String,
 }
 
+impl DeleteParameterGroupRequest {
+    /// Returns a builder for `DeleteParameterGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteParameterGroupRequestBuilder {
+        DeleteParameterGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteParameterGroupRequest`](struct.DeleteParameterGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteParameterGroupRequestBuilder {
+    parameter_group_name: Option<String>,
+}
+
+impl DeleteParameterGroupRequestBuilder {
+    /// Sets the [`parameter_group_name`](struct.DeleteParameterGroupRequest.html#structfield.parameter_group_name) member.
+    pub fn parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteParameterGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteParameterGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteParameterGroupRequest {
+            parameter_group_name: self
+                .parameter_group_name
+                .ok_or(::rusoto_core::BuildError::missing("parameter_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteParameterGroupResponse {
@@ -267,6 +682,38 @@ pub struct DeleteSubnetGroupRequest {
     pub subnet_group_name: String,
 }
 
+impl DeleteSubnetGroupRequest {
+    /// Returns a builder for `DeleteSubnetGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSubnetGroupRequestBuilder {
+        DeleteSubnetGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSubnetGroupRequest`](struct.DeleteSubnetGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSubnetGroupRequestBuilder {
+    subnet_group_name: Option<String>,
+}
+
+impl DeleteSubnetGroupRequestBuilder {
+    /// Sets the [`subnet_group_name`](struct.DeleteSubnetGroupRequest.html#structfield.subnet_group_name) member.
+    pub fn subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSubnetGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSubnetGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSubnetGroupRequest {
+            subnet_group_name: self
+                .subnet_group_name
+                .ok_or(::rusoto_core::BuildError::missing("subnet_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSubnetGroupResponse {
@@ -292,6 +739,52 @@ pub struct DescribeClustersRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeClustersRequest {
+    /// Returns a builder for `DescribeClustersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeClustersRequestBuilder {
+        DescribeClustersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClustersRequest`](struct.DescribeClustersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeClustersRequestBuilder {
+    cluster_names: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeClustersRequestBuilder {
+    /// Sets the [`cluster_names`](struct.DescribeClustersRequest.html#structfield.cluster_names) member.
+    pub fn cluster_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cluster_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeClustersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeClustersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClustersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClustersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeClustersRequest {
+            cluster_names: self.cluster_names,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeClustersResponse {
@@ -317,6 +810,44 @@ pub struct DescribeDefaultParametersRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeDefaultParametersRequest {
+    /// Returns a builder for `DescribeDefaultParametersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDefaultParametersRequestBuilder {
+        DescribeDefaultParametersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDefaultParametersRequest`](struct.DescribeDefaultParametersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDefaultParametersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeDefaultParametersRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeDefaultParametersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeDefaultParametersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDefaultParametersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDefaultParametersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDefaultParametersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDefaultParametersResponse {
@@ -338,8 +869,9 @@ pub struct DescribeEventsRequest {
     pub duration: Option<i64>,
     /// <p>The end of the time interval for which to retrieve events, specified in ISO 8601 format.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The maximum number of results to include in the response. If more results exist than the specified <code>MaxResults</code> value, a token is included in the response so that the remaining results can be retrieved.</p> <p>The value for <code>MaxResults</code> must be between 20 and 100.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -355,11 +887,88 @@ pub struct DescribeEventsRequest {
     /// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
     #[serde(rename = "SourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p>The beginning of the time interval to retrieve events for, specified in ISO 8601 format.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
+}
+
+impl DescribeEventsRequest {
+    /// Returns a builder for `DescribeEventsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEventsRequestBuilder {
+        DescribeEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventsRequest`](struct.DescribeEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventsRequestBuilder {
+    duration: Option<i64>,
+    end_time: Option<EpochTimestamp>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    source_name: Option<String>,
+    source_type: Option<SourceType>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl DescribeEventsRequestBuilder {
+    /// Sets the [`duration`](struct.DescribeEventsRequest.html#structfield.duration) member.
+    pub fn duration<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.DescribeEventsRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeEventsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeEventsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_name`](struct.DescribeEventsRequest.html#structfield.source_name) member.
+    pub fn source_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.DescribeEventsRequest.html#structfield.source_type) member.
+    pub fn source_type<T: Into<SourceType>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.DescribeEventsRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeEventsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEventsRequest {
+            duration: self.duration,
+            end_time: self.end_time,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            source_name: self.source_name,
+            source_type: self.source_type,
+            start_time: self.start_time,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -391,6 +1000,52 @@ pub struct DescribeParameterGroupsRequest {
     pub parameter_group_names: Option<Vec<String>>,
 }
 
+impl DescribeParameterGroupsRequest {
+    /// Returns a builder for `DescribeParameterGroupsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeParameterGroupsRequestBuilder {
+        DescribeParameterGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeParameterGroupsRequest`](struct.DescribeParameterGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeParameterGroupsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    parameter_group_names: Option<Vec<String>>,
+}
+
+impl DescribeParameterGroupsRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeParameterGroupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeParameterGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_group_names`](struct.DescribeParameterGroupsRequest.html#structfield.parameter_group_names) member.
+    pub fn parameter_group_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.parameter_group_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeParameterGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeParameterGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeParameterGroupsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            parameter_group_names: self.parameter_group_names,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeParameterGroupsResponse {
@@ -423,6 +1078,62 @@ pub struct DescribeParametersRequest {
     pub source: Option<String>,
 }
 
+impl DescribeParametersRequest {
+    /// Returns a builder for `DescribeParametersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeParametersRequestBuilder {
+        DescribeParametersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeParametersRequest`](struct.DescribeParametersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeParametersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    parameter_group_name: Option<String>,
+    source: Option<String>,
+}
+
+impl DescribeParametersRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeParametersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeParametersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_group_name`](struct.DescribeParametersRequest.html#structfield.parameter_group_name) member.
+    pub fn parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.DescribeParametersRequest.html#structfield.source) member.
+    pub fn source<T: Into<String>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeParametersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeParametersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeParametersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            parameter_group_name: self
+                .parameter_group_name
+                .ok_or(::rusoto_core::BuildError::missing("parameter_group_name"))?,
+            source: self.source,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeParametersResponse {
@@ -452,6 +1163,52 @@ pub struct DescribeSubnetGroupsRequest {
     pub subnet_group_names: Option<Vec<String>>,
 }
 
+impl DescribeSubnetGroupsRequest {
+    /// Returns a builder for `DescribeSubnetGroupsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeSubnetGroupsRequestBuilder {
+        DescribeSubnetGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSubnetGroupsRequest`](struct.DescribeSubnetGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeSubnetGroupsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    subnet_group_names: Option<Vec<String>>,
+}
+
+impl DescribeSubnetGroupsRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeSubnetGroupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeSubnetGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_group_names`](struct.DescribeSubnetGroupsRequest.html#structfield.subnet_group_names) member.
+    pub fn subnet_group_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_group_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSubnetGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSubnetGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeSubnetGroupsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            subnet_group_names: self.subnet_group_names,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeSubnetGroupsResponse {
@@ -485,8 +1242,9 @@ pub struct Endpoint {
 pub struct Event {
     /// <p>The date and time when the event occurred.</p>
     #[serde(rename = "Date")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date: Option<f64>,
+    pub date: Option<EpochTimestamp>,
     /// <p>A user-defined message associated with the event.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -498,7 +1256,7 @@ pub struct Event {
     /// <p>Specifies the origin of this event - a cluster, a parameter group, a node ID, etc.</p>
     #[serde(rename = "SourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -515,6 +1273,56 @@ pub struct IncreaseReplicationFactorRequest {
     pub new_replication_factor: i64,
 }
 
+impl IncreaseReplicationFactorRequest {
+    /// Returns a builder for `IncreaseReplicationFactorRequest`, checking its required members when built.
+    pub fn builder() -> IncreaseReplicationFactorRequestBuilder {
+        IncreaseReplicationFactorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`IncreaseReplicationFactorRequest`](struct.IncreaseReplicationFactorRequest.html).
+#[derive(Default, Debug)]
+pub struct IncreaseReplicationFactorRequestBuilder {
+    availability_zones: Option<Vec<String>>,
+    cluster_name: Option<String>,
+    new_replication_factor: Option<i64>,
+}
+
+impl IncreaseReplicationFactorRequestBuilder {
+    /// Sets the [`availability_zones`](struct.IncreaseReplicationFactorRequest.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_name`](struct.IncreaseReplicationFactorRequest.html#structfield.cluster_name) member.
+    pub fn cluster_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_replication_factor`](struct.IncreaseReplicationFactorRequest.html#structfield.new_replication_factor) member.
+    pub fn new_replication_factor<T: Into<i64>>(mut self, value: T) -> Self {
+        self.new_replication_factor = Some(value.into());
+        self
+    }
+
+    /// Builds the `IncreaseReplicationFactorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<IncreaseReplicationFactorRequest, ::rusoto_core::BuildError> {
+        Ok(IncreaseReplicationFactorRequest {
+            availability_zones: self.availability_zones,
+            cluster_name: self
+                .cluster_name
+                .ok_or(::rusoto_core::BuildError::missing("cluster_name"))?,
+            new_replication_factor: self
+                .new_replication_factor
+                .ok_or(::rusoto_core::BuildError::missing("new_replication_factor"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct IncreaseReplicationFactorResponse {
@@ -524,6 +1332,82 @@ pub struct IncreaseReplicationFactorResponse {
     pub cluster: Option<Cluster>,
 }
 
+/// <p>Whether the customer is allowed to modify the parameter.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IsModifiable {
+    /// <p><code>TRUE</code></p>
+    True,
+    /// <p><code>FALSE</code></p>
+    False,
+    /// <p><code>CONDITIONAL</code></p>
+    Conditional,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IsModifiable {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IsModifiable::True => "TRUE",
+            IsModifiable::False => "FALSE",
+            IsModifiable::Conditional => "CONDITIONAL",
+            IsModifiable::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IsModifiable {
+    fn from(value: &'a str) -> IsModifiable {
+        match value {
+            "TRUE" => IsModifiable::True,
+            "FALSE" => IsModifiable::False,
+            "CONDITIONAL" => IsModifiable::Conditional,
+            _ => IsModifiable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IsModifiable {
+    fn from(value: String) -> IsModifiable {
+        IsModifiable::from(value.as_str())
+    }
+}
+
+impl From<IsModifiable> for String {
+    fn from(value: IsModifiable) -> String {
+        match value {
+            IsModifiable::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IsModifiable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for IsModifiable {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IsModifiable {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IsModifiable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IsModifiable::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListTagsRequest {
     /// <p>An optional token returned from a prior request. Use this token for pagination of results from this action. If this parameter is specified, the response includes only results beyond the token.</p>
@@ -535,6 +1419,44 @@ pub struct ListTagsRequest {
     pub resource_name: String,
 }
 
+impl ListTagsRequest {
+    /// Returns a builder for `ListTagsRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsRequestBuilder {
+        ListTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsRequest`](struct.ListTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsRequestBuilder {
+    next_token: Option<String>,
+    resource_name: Option<String>,
+}
+
+impl ListTagsRequestBuilder {
+    /// Sets the [`next_token`](struct.ListTagsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_name`](struct.ListTagsRequest.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTagsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsRequest {
+            next_token: self.next_token,
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsResponse {
@@ -562,8 +1484,9 @@ pub struct Node {
     pub endpoint: Option<Endpoint>,
     /// <p>The date and time (in UNIX epoch format) when the node was launched.</p>
     #[serde(rename = "NodeCreateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_create_time: Option<f64>,
+    pub node_create_time: Option<EpochTimestamp>,
     /// <p>A system-generated identifier for the node.</p>
     #[serde(rename = "NodeId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -617,7 +1540,7 @@ pub struct Parameter {
     /// <p>The conditions under which changes to this parameter can be applied. For example, <code>requires-reboot</code> indicates that a new value for this parameter will only take effect if a node is rebooted.</p>
     #[serde(rename = "ChangeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub change_type: Option<String>,
+    pub change_type: Option<ChangeType>,
     /// <p>The data type of the parameter. For example, <code>integer</code>:</p>
     #[serde(rename = "DataType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -629,7 +1552,7 @@ pub struct Parameter {
     /// <p>Whether the customer is allowed to modify the parameter.</p>
     #[serde(rename = "IsModifiable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_modifiable: Option<String>,
+    pub is_modifiable: Option<IsModifiable>,
     /// <p>A list of node types, and specific parameter values for each node.</p>
     #[serde(rename = "NodeTypeSpecificValues")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -641,7 +1564,7 @@ pub struct Parameter {
     /// <p>Determines whether the parameter can be applied to any nodes, or only nodes of a particular type.</p>
     #[serde(rename = "ParameterType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameter_type: Option<String>,
+    pub parameter_type: Option<ParameterType>,
     /// <p>The value for the parameter.</p>
     #[serde(rename = "ParameterValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -697,6 +1620,78 @@ pub struct ParameterNameValue {
     pub parameter_value: Option<String>,
 }
 
+/// <p>Determines whether the parameter can be applied to any nodes, or only nodes of a particular type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParameterType {
+    /// <p><code>DEFAULT</code></p>
+    Default,
+    /// <p><code>NODE_TYPE_SPECIFIC</code></p>
+    NodeTypeSpecific,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ParameterType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParameterType::Default => "DEFAULT",
+            ParameterType::NodeTypeSpecific => "NODE_TYPE_SPECIFIC",
+            ParameterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ParameterType {
+    fn from(value: &'a str) -> ParameterType {
+        match value {
+            "DEFAULT" => ParameterType::Default,
+            "NODE_TYPE_SPECIFIC" => ParameterType::NodeTypeSpecific,
+            _ => ParameterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParameterType {
+    fn from(value: String) -> ParameterType {
+        ParameterType::from(value.as_str())
+    }
+}
+
+impl From<ParameterType> for String {
+    fn from(value: ParameterType) -> String {
+        match value {
+            ParameterType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParameterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ParameterType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParameterType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ParameterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ParameterType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RebootNodeRequest {
     /// <p>The name of the DAX cluster containing the node to be rebooted.</p>
@@ -707,6 +1702,46 @@ pub struct RebootNodeRequest {
     pub node_id: String,
 }
 
+impl RebootNodeRequest {
+    /// Returns a builder for `RebootNodeRequest`, checking its required members when built.
+    pub fn builder() -> RebootNodeRequestBuilder {
+        RebootNodeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RebootNodeRequest`](struct.RebootNodeRequest.html).
+#[derive(Default, Debug)]
+pub struct RebootNodeRequestBuilder {
+    cluster_name: Option<String>,
+    node_id: Option<String>,
+}
+
+impl RebootNodeRequestBuilder {
+    /// Sets the [`cluster_name`](struct.RebootNodeRequest.html#structfield.cluster_name) member.
+    pub fn cluster_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_id`](struct.RebootNodeRequest.html#structfield.node_id) member.
+    pub fn node_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.node_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebootNodeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RebootNodeRequest, ::rusoto_core::BuildError> {
+        Ok(RebootNodeRequest {
+            cluster_name: self
+                .cluster_name
+                .ok_or(::rusoto_core::BuildError::missing("cluster_name"))?,
+            node_id: self
+                .node_id
+                .ok_or(::rusoto_core::BuildError::missing("node_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RebootNodeResponse {
@@ -723,15 +1758,95 @@ pub struct SSEDescription {
     /// <p><p>The current state of server-side encryption:</p> <ul> <li> <p> <code>ENABLING</code> - Server-side encryption is being enabled.</p> </li> <li> <p> <code>ENABLED</code> - Server-side encryption is enabled.</p> </li> <li> <p> <code>DISABLING</code> - Server-side encryption is being disabled.</p> </li> <li> <p> <code>DISABLED</code> - Server-side encryption is disabled.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SSEStatus>,
+}
+
+/// <p>Represents the settings used to enable server-side encryption.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct SSESpecification {
+    /// <p>Indicates whether server-side encryption is enabled (true) or disabled (false) on the cluster.</p>
+    #[serde(rename = "Enabled")]
+    pub enabled: bool,
+}
+
+/// <p><p>The current state of server-side encryption:</p> <ul> <li> <p> <code>ENABLING</code> - Server-side encryption is being enabled.</p> </li> <li> <p> <code>ENABLED</code> - Server-side encryption is enabled.</p> </li> <li> <p> <code>DISABLING</code> - Server-side encryption is being disabled.</p> </li> <li> <p> <code>DISABLED</code> - Server-side encryption is disabled.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SSEStatus {
+    /// <p><code>ENABLING</code> - Server-side encryption is being enabled.</p>
+    Enabling,
+    /// <p><code>ENABLED</code> - Server-side encryption is enabled.</p>
+    Enabled,
+    /// <p><code>DISABLING</code> - Server-side encryption is being disabled.</p>
+    Disabling,
+    /// <p><code>DISABLED</code> - Server-side encryption is disabled.</p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SSEStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SSEStatus::Enabling => "ENABLING",
+            SSEStatus::Enabled => "ENABLED",
+            SSEStatus::Disabling => "DISABLING",
+            SSEStatus::Disabled => "DISABLED",
+            SSEStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SSEStatus {
+    fn from(value: &'a str) -> SSEStatus {
+        match value {
+            "ENABLING" => SSEStatus::Enabling,
+            "ENABLED" => SSEStatus::Enabled,
+            "DISABLING" => SSEStatus::Disabling,
+            "DISABLED" => SSEStatus::Disabled,
+            _ => SSEStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SSEStatus {
+    fn from(value: String) -> SSEStatus {
+        SSEStatus::from(value.as_str())
+    }
+}
+
+impl From<SSEStatus> for String {
+    fn from(value: SSEStatus) -> String {
+        match value {
+            SSEStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-/// <p>Represents the settings used to enable server-side encryption.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct SSESpecification {
-    /// <p>Indicates whether server-side encryption is enabled (true) or disabled (false) on the cluster.</p>
-    #[serde(rename = "Enabled")]
-    pub enabled: bool,
+impl fmt::Display for SSEStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SSEStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SSEStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SSEStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SSEStatus::from(value))
+    }
 }
 
 /// <p>An individual VPC security group and its status.</p>
@@ -748,6 +1863,82 @@ pub struct SecurityGroupMembership {
     pub status: Option<String>,
 }
 
+/// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceType {
+    /// <p><code>CLUSTER</code></p>
+    Cluster,
+    /// <p><code>PARAMETER_GROUP</code></p>
+    ParameterGroup,
+    /// <p><code>SUBNET_GROUP</code></p>
+    SubnetGroup,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::Cluster => "CLUSTER",
+            SourceType::ParameterGroup => "PARAMETER_GROUP",
+            SourceType::SubnetGroup => "SUBNET_GROUP",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "CLUSTER" => SourceType::Cluster,
+            "PARAMETER_GROUP" => SourceType::ParameterGroup,
+            "SUBNET_GROUP" => SourceType::SubnetGroup,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        SourceType::from(value.as_str())
+    }
+}
+
+impl From<SourceType> for String {
+    fn from(value: SourceType) -> String {
+        match value {
+            SourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SourceType::from(value))
+    }
+}
+
 /// <p>Represents the subnet associated with a DAX cluster. This parameter refers to subnets defined in Amazon Virtual Private Cloud (Amazon VPC) and used with DAX.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -807,6 +1998,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_name`](struct.TagResourceRequest.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {
@@ -826,6 +2057,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_name`](struct.UntagResourceRequest.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {
@@ -866,6 +2137,84 @@ pub struct UpdateClusterRequest {
     pub security_group_ids: Option<Vec<String>>,
 }
 
+impl UpdateClusterRequest {
+    /// Returns a builder for `UpdateClusterRequest`, checking its required members when built.
+    pub fn builder() -> UpdateClusterRequestBuilder {
+        UpdateClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateClusterRequest`](struct.UpdateClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateClusterRequestBuilder {
+    cluster_name: Option<String>,
+    description: Option<String>,
+    notification_topic_arn: Option<String>,
+    notification_topic_status: Option<String>,
+    parameter_group_name: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    security_group_ids: Option<Vec<String>>,
+}
+
+impl UpdateClusterRequestBuilder {
+    /// Sets the [`cluster_name`](struct.UpdateClusterRequest.html#structfield.cluster_name) member.
+    pub fn cluster_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateClusterRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_topic_arn`](struct.UpdateClusterRequest.html#structfield.notification_topic_arn) member.
+    pub fn notification_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_topic_status`](struct.UpdateClusterRequest.html#structfield.notification_topic_status) member.
+    pub fn notification_topic_status<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification_topic_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_group_name`](struct.UpdateClusterRequest.html#structfield.parameter_group_name) member.
+    pub fn parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.UpdateClusterRequest.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_group_ids`](struct.UpdateClusterRequest.html#structfield.security_group_ids) member.
+    pub fn security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateClusterRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateClusterRequest {
+            cluster_name: self
+                .cluster_name
+                .ok_or(::rusoto_core::BuildError::missing("cluster_name"))?,
+            description: self.description,
+            notification_topic_arn: self.notification_topic_arn,
+            notification_topic_status: self.notification_topic_status,
+            parameter_group_name: self.parameter_group_name,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            security_group_ids: self.security_group_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateClusterResponse {
@@ -885,6 +2234,48 @@ pub struct UpdateParameterGroupRequest {
     pub parameter_name_values: Vec<ParameterNameValue>,
 }
 
+impl UpdateParameterGroupRequest {
+    /// Returns a builder for `UpdateParameterGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateParameterGroupRequestBuilder {
+        UpdateParameterGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateParameterGroupRequest`](struct.UpdateParameterGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateParameterGroupRequestBuilder {
+    parameter_group_name: Option<String>,
+    parameter_name_values: Option<Vec<ParameterNameValue>>,
+}
+
+impl UpdateParameterGroupRequestBuilder {
+    /// Sets the [`parameter_group_name`](struct.UpdateParameterGroupRequest.html#structfield.parameter_group_name) member.
+    pub fn parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_name_values`](struct.UpdateParameterGroupRequest.html#structfield.parameter_name_values) member.
+    pub fn parameter_name_values<T: Into<Vec<ParameterNameValue>>>(mut self, value: T) -> Self {
+        self.parameter_name_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateParameterGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateParameterGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateParameterGroupRequest {
+            parameter_group_name: self
+                .parameter_group_name
+                .ok_or(::rusoto_core::BuildError::missing("parameter_group_name"))?,
+            parameter_name_values: self
+                .parameter_name_values
+                .ok_or(::rusoto_core::BuildError::missing("parameter_name_values"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateParameterGroupResponse {
@@ -909,6 +2300,54 @@ pub struct UpdateSubnetGroupRequest {
     pub subnet_ids: Option<Vec<String>>,
 }
 
+impl UpdateSubnetGroupRequest {
+    /// Returns a builder for `UpdateSubnetGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSubnetGroupRequestBuilder {
+        UpdateSubnetGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSubnetGroupRequest`](struct.UpdateSubnetGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSubnetGroupRequestBuilder {
+    description: Option<String>,
+    subnet_group_name: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+}
+
+impl UpdateSubnetGroupRequestBuilder {
+    /// Sets the [`description`](struct.UpdateSubnetGroupRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_group_name`](struct.UpdateSubnetGroupRequest.html#structfield.subnet_group_name) member.
+    pub fn subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.UpdateSubnetGroupRequest.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSubnetGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSubnetGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSubnetGroupRequest {
+            description: self.description,
+            subnet_group_name: self
+                .subnet_group_name
+                .ok_or(::rusoto_core::BuildError::missing("subnet_group_name"))?,
+            subnet_ids: self.subnet_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateSubnetGroupResponse {
@@ -2490,6 +3929,138 @@ pub trait DynamodbAccelerator {
         input: UpdateSubnetGroupRequest,
     ) -> RusotoFuture<UpdateSubnetGroupResponse, UpdateSubnetGroupError>;
 }
+impl<T: ?Sized + DynamodbAccelerator> DynamodbAccelerator for ::std::sync::Arc<T> {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_parameter_group(
+        &self,
+        input: CreateParameterGroupRequest,
+    ) -> RusotoFuture<CreateParameterGroupResponse, CreateParameterGroupError> {
+        (**self).create_parameter_group(input)
+    }
+    fn create_subnet_group(
+        &self,
+        input: CreateSubnetGroupRequest,
+    ) -> RusotoFuture<CreateSubnetGroupResponse, CreateSubnetGroupError> {
+        (**self).create_subnet_group(input)
+    }
+    fn decrease_replication_factor(
+        &self,
+        input: DecreaseReplicationFactorRequest,
+    ) -> RusotoFuture<DecreaseReplicationFactorResponse, DecreaseReplicationFactorError> {
+        (**self).decrease_replication_factor(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_parameter_group(
+        &self,
+        input: DeleteParameterGroupRequest,
+    ) -> RusotoFuture<DeleteParameterGroupResponse, DeleteParameterGroupError> {
+        (**self).delete_parameter_group(input)
+    }
+    fn delete_subnet_group(
+        &self,
+        input: DeleteSubnetGroupRequest,
+    ) -> RusotoFuture<DeleteSubnetGroupResponse, DeleteSubnetGroupError> {
+        (**self).delete_subnet_group(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn describe_default_parameters(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> RusotoFuture<DescribeDefaultParametersResponse, DescribeDefaultParametersError> {
+        (**self).describe_default_parameters(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_parameter_groups(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> RusotoFuture<DescribeParameterGroupsResponse, DescribeParameterGroupsError> {
+        (**self).describe_parameter_groups(input)
+    }
+    fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> RusotoFuture<DescribeParametersResponse, DescribeParametersError> {
+        (**self).describe_parameters(input)
+    }
+    fn describe_subnet_groups(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> RusotoFuture<DescribeSubnetGroupsResponse, DescribeSubnetGroupsError> {
+        (**self).describe_subnet_groups(input)
+    }
+    fn increase_replication_factor(
+        &self,
+        input: IncreaseReplicationFactorRequest,
+    ) -> RusotoFuture<IncreaseReplicationFactorResponse, IncreaseReplicationFactorError> {
+        (**self).increase_replication_factor(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn reboot_node(
+        &self,
+        input: RebootNodeRequest,
+    ) -> RusotoFuture<RebootNodeResponse, RebootNodeError> {
+        (**self).reboot_node(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> RusotoFuture<UpdateClusterResponse, UpdateClusterError> {
+        (**self).update_cluster(input)
+    }
+    fn update_parameter_group(
+        &self,
+        input: UpdateParameterGroupRequest,
+    ) -> RusotoFuture<UpdateParameterGroupResponse, UpdateParameterGroupError> {
+        (**self).update_parameter_group(input)
+    }
+    fn update_subnet_group(
+        &self,
+        input: UpdateSubnetGroupRequest,
+    ) -> RusotoFuture<UpdateSubnetGroupResponse, UpdateSubnetGroupError> {
+        (**self).update_subnet_group(input)
+    }
+}
+/// The error codes Amazon DAX retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon DAX API.
 #[derive(Clone)]
 pub struct DynamodbAcceleratorClient {
@@ -2505,6 +4076,24 @@ impl DynamodbAcceleratorClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_DAX`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> DynamodbAcceleratorClient {
+        Self::new(region::Region::from_env_for_service("DAX"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `dax`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> DynamodbAcceleratorClient {
+        Self::new_with_client(config.client().clone(), config.region_for("dax", "DAX"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2523,7 +4112,49 @@ impl DynamodbAcceleratorClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DynamodbAcceleratorClient {
-        DynamodbAcceleratorClient { client, region }
+        DynamodbAcceleratorClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "dax")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "dax", "dax", "DAX")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `DynamodbAccelerator` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn DynamodbAccelerator + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for DynamodbAcceleratorClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon DAX"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "dax"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> DynamodbAcceleratorClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Device Farm clients.
+//!
+//! Makes a single read-only `ListDeviceInstances` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_devicefarm::{DeviceFarm, DeviceFarmClient, ListDeviceInstancesRequest};
+
+fn main() {
+    let client = DeviceFarmClient::from_env();
+    match client
+        .list_device_instances(ListDeviceInstancesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListDeviceInstances succeeded"),
+        Err(error) => {
+            eprintln!("ListDeviceInstances failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
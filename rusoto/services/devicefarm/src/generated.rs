@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>A container for account-level settings within AWS Device Farm.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -54,11 +55,11 @@ pub struct AccountSettings {
     /// <p>Returns the unmetered devices you have purchased or want to purchase.</p>
     #[serde(rename = "unmeteredDevices")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unmetered_devices: Option<::std::collections::HashMap<String, i64>>,
+    pub unmetered_devices: Option<::std::collections::HashMap<DevicePlatform, i64>>,
     /// <p>Returns the unmetered remote access devices you have purchased or want to purchase.</p>
     #[serde(rename = "unmeteredRemoteAccessDevices")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unmetered_remote_access_devices: Option<::std::collections::HashMap<String, i64>>,
+    pub unmetered_remote_access_devices: Option<::std::collections::HashMap<DevicePlatform, i64>>,
 }
 
 /// <p>Represents the output of a test. Examples of artifacts include logs and screenshots.</p>
@@ -80,13 +81,337 @@ pub struct Artifact {
     /// <p><p>The artifact&#39;s type.</p> <p>Allowed values include the following:</p> <ul> <li> <p>UNKNOWN: An unknown type.</p> </li> <li> <p>SCREENSHOT: The screenshot type.</p> </li> <li> <p>DEVICE<em>LOG: The device log type.</p> </li> <li> <p>MESSAGE</em>LOG: The message log type.</p> </li> <li> <p>VIDEO<em>LOG: The video log type.</p> </li> <li> <p>RESULT</em>LOG: The result log type.</p> </li> <li> <p>SERVICE<em>LOG: The service log type.</p> </li> <li> <p>WEBKIT</em>LOG: The web kit log type.</p> </li> <li> <p>INSTRUMENTATION<em>OUTPUT: The instrumentation type.</p> </li> <li> <p>EXERCISER</em>MONKEY<em>OUTPUT: For Android, the artifact (log) generated by an Android fuzz test.</p> </li> <li> <p>CALABASH</em>JSON<em>OUTPUT: The Calabash JSON output type.</p> </li> <li> <p>CALABASH</em>PRETTY<em>OUTPUT: The Calabash pretty output type.</p> </li> <li> <p>CALABASH</em>STANDARD<em>OUTPUT: The Calabash standard output type.</p> </li> <li> <p>CALABASH</em>JAVA<em>XML</em>OUTPUT: The Calabash Java XML output type.</p> </li> <li> <p>AUTOMATION<em>OUTPUT: The automation output type.</p> </li> <li> <p>APPIUM</em>SERVER<em>OUTPUT: The Appium server output type.</p> </li> <li> <p>APPIUM</em>JAVA<em>OUTPUT: The Appium Java output type.</p> </li> <li> <p>APPIUM</em>JAVA<em>XML</em>OUTPUT: The Appium Java XML output type.</p> </li> <li> <p>APPIUM<em>PYTHON</em>OUTPUT: The Appium Python output type.</p> </li> <li> <p>APPIUM<em>PYTHON</em>XML<em>OUTPUT: The Appium Python XML output type.</p> </li> <li> <p>EXPLORER</em>EVENT<em>LOG: The Explorer event log output type.</p> </li> <li> <p>EXPLORER</em>SUMMARY<em>LOG: The Explorer summary log output type.</p> </li> <li> <p>APPLICATION</em>CRASH<em>REPORT: The application crash report output type.</p> </li> <li> <p>XCTEST</em>LOG: The Xcode test output type.</p> </li> <li> <p>VIDEO: The Video output type.</p> </li> <li> <p>CUSTOMER<em>ARTIFACT:The Customer Artifact output type.</p> </li> <li> <p>CUSTOMER</em>ARTIFACT<em>LOG: The Customer Artifact Log output type.</p> </li> <li> <p>TESTSPEC</em>OUTPUT: The Test Spec Output type.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ArtifactType>,
     /// <p>The pre-signed Amazon S3 URL that can be used with a corresponding GET request to download the artifact's file.</p>
     #[serde(rename = "url")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
 
+/// <p><p>The artifacts&#39; type.</p> <p>Allowed values include:</p> <ul> <li> <p>FILE: The artifacts are files.</p> </li> <li> <p>LOG: The artifacts are logs.</p> </li> <li> <p>SCREENSHOT: The artifacts are screenshots.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ArtifactCategory {
+    /// <p><code>SCREENSHOT</code></p>
+    Screenshot,
+    /// <p><code>FILE</code></p>
+    File,
+    /// <p><code>LOG</code></p>
+    Log,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ArtifactCategory {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactCategory::Screenshot => "SCREENSHOT",
+            ArtifactCategory::File => "FILE",
+            ArtifactCategory::Log => "LOG",
+            ArtifactCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactCategory {
+    fn from(value: &'a str) -> ArtifactCategory {
+        match value {
+            "SCREENSHOT" => ArtifactCategory::Screenshot,
+            "FILE" => ArtifactCategory::File,
+            "LOG" => ArtifactCategory::Log,
+            _ => ArtifactCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactCategory {
+    fn from(value: String) -> ArtifactCategory {
+        ArtifactCategory::from(value.as_str())
+    }
+}
+
+impl From<ArtifactCategory> for String {
+    fn from(value: ArtifactCategory) -> String {
+        match value {
+            ArtifactCategory::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ArtifactCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ArtifactCategory {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactCategory {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ArtifactCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ArtifactCategory::from(value))
+    }
+}
+
+/// <p><p>The artifact&#39;s type.</p> <p>Allowed values include the following:</p> <ul> <li> <p>UNKNOWN: An unknown type.</p> </li> <li> <p>SCREENSHOT: The screenshot type.</p> </li> <li> <p>DEVICE<em>LOG: The device log type.</p> </li> <li> <p>MESSAGE</em>LOG: The message log type.</p> </li> <li> <p>VIDEO<em>LOG: The video log type.</p> </li> <li> <p>RESULT</em>LOG: The result log type.</p> </li> <li> <p>SERVICE<em>LOG: The service log type.</p> </li> <li> <p>WEBKIT</em>LOG: The web kit log type.</p> </li> <li> <p>INSTRUMENTATION<em>OUTPUT: The instrumentation type.</p> </li> <li> <p>EXERCISER</em>MONKEY<em>OUTPUT: For Android, the artifact (log) generated by an Android fuzz test.</p> </li> <li> <p>CALABASH</em>JSON<em>OUTPUT: The Calabash JSON output type.</p> </li> <li> <p>CALABASH</em>PRETTY<em>OUTPUT: The Calabash pretty output type.</p> </li> <li> <p>CALABASH</em>STANDARD<em>OUTPUT: The Calabash standard output type.</p> </li> <li> <p>CALABASH</em>JAVA<em>XML</em>OUTPUT: The Calabash Java XML output type.</p> </li> <li> <p>AUTOMATION<em>OUTPUT: The automation output type.</p> </li> <li> <p>APPIUM</em>SERVER<em>OUTPUT: The Appium server output type.</p> </li> <li> <p>APPIUM</em>JAVA<em>OUTPUT: The Appium Java output type.</p> </li> <li> <p>APPIUM</em>JAVA<em>XML</em>OUTPUT: The Appium Java XML output type.</p> </li> <li> <p>APPIUM<em>PYTHON</em>OUTPUT: The Appium Python output type.</p> </li> <li> <p>APPIUM<em>PYTHON</em>XML<em>OUTPUT: The Appium Python XML output type.</p> </li> <li> <p>EXPLORER</em>EVENT<em>LOG: The Explorer event log output type.</p> </li> <li> <p>EXPLORER</em>SUMMARY<em>LOG: The Explorer summary log output type.</p> </li> <li> <p>APPLICATION</em>CRASH<em>REPORT: The application crash report output type.</p> </li> <li> <p>XCTEST</em>LOG: The Xcode test output type.</p> </li> <li> <p>VIDEO: The Video output type.</p> </li> <li> <p>CUSTOMER<em>ARTIFACT:The Customer Artifact output type.</p> </li> <li> <p>CUSTOMER</em>ARTIFACT<em>LOG: The Customer Artifact Log output type.</p> </li> <li> <p>TESTSPEC</em>OUTPUT: The Test Spec Output type.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ArtifactType {
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// <p><code>SCREENSHOT</code></p>
+    Screenshot,
+    /// <p><code>DEVICE_LOG</code></p>
+    DeviceLog,
+    /// <p><code>MESSAGE_LOG</code></p>
+    MessageLog,
+    /// <p><code>VIDEO_LOG</code></p>
+    VideoLog,
+    /// <p><code>RESULT_LOG</code></p>
+    ResultLog,
+    /// <p><code>SERVICE_LOG</code></p>
+    ServiceLog,
+    /// <p><code>WEBKIT_LOG</code></p>
+    WebkitLog,
+    /// <p><code>INSTRUMENTATION_OUTPUT</code></p>
+    InstrumentationOutput,
+    /// <p><code>EXERCISER_MONKEY_OUTPUT</code></p>
+    ExerciserMonkeyOutput,
+    /// <p><code>CALABASH_JSON_OUTPUT</code></p>
+    CalabashJsonOutput,
+    /// <p><code>CALABASH_PRETTY_OUTPUT</code></p>
+    CalabashPrettyOutput,
+    /// <p><code>CALABASH_STANDARD_OUTPUT</code></p>
+    CalabashStandardOutput,
+    /// <p><code>CALABASH_JAVA_XML_OUTPUT</code></p>
+    CalabashJavaXmlOutput,
+    /// <p><code>AUTOMATION_OUTPUT</code></p>
+    AutomationOutput,
+    /// <p><code>APPIUM_SERVER_OUTPUT</code></p>
+    AppiumServerOutput,
+    /// <p><code>APPIUM_JAVA_OUTPUT</code></p>
+    AppiumJavaOutput,
+    /// <p><code>APPIUM_JAVA_XML_OUTPUT</code></p>
+    AppiumJavaXmlOutput,
+    /// <p><code>APPIUM_PYTHON_OUTPUT</code></p>
+    AppiumPythonOutput,
+    /// <p><code>APPIUM_PYTHON_XML_OUTPUT</code></p>
+    AppiumPythonXmlOutput,
+    /// <p><code>EXPLORER_EVENT_LOG</code></p>
+    ExplorerEventLog,
+    /// <p><code>EXPLORER_SUMMARY_LOG</code></p>
+    ExplorerSummaryLog,
+    /// <p><code>APPLICATION_CRASH_REPORT</code></p>
+    ApplicationCrashReport,
+    /// <p><code>XCTEST_LOG</code></p>
+    XctestLog,
+    /// <p><code>VIDEO</code></p>
+    Video,
+    /// <p><code>CUSTOMER_ARTIFACT</code></p>
+    CustomerArtifact,
+    /// <p><code>CUSTOMER_ARTIFACT_LOG</code></p>
+    CustomerArtifactLog,
+    /// <p><code>TESTSPEC_OUTPUT</code></p>
+    TestspecOutput,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ArtifactType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ArtifactType::UnknownValue => "UNKNOWN",
+            ArtifactType::Screenshot => "SCREENSHOT",
+            ArtifactType::DeviceLog => "DEVICE_LOG",
+            ArtifactType::MessageLog => "MESSAGE_LOG",
+            ArtifactType::VideoLog => "VIDEO_LOG",
+            ArtifactType::ResultLog => "RESULT_LOG",
+            ArtifactType::ServiceLog => "SERVICE_LOG",
+            ArtifactType::WebkitLog => "WEBKIT_LOG",
+            ArtifactType::InstrumentationOutput => "INSTRUMENTATION_OUTPUT",
+            ArtifactType::ExerciserMonkeyOutput => "EXERCISER_MONKEY_OUTPUT",
+            ArtifactType::CalabashJsonOutput => "CALABASH_JSON_OUTPUT",
+            ArtifactType::CalabashPrettyOutput => "CALABASH_PRETTY_OUTPUT",
+            ArtifactType::CalabashStandardOutput => "CALABASH_STANDARD_OUTPUT",
+            ArtifactType::CalabashJavaXmlOutput => "CALABASH_JAVA_XML_OUTPUT",
+            ArtifactType::AutomationOutput => "AUTOMATION_OUTPUT",
+            ArtifactType::AppiumServerOutput => "APPIUM_SERVER_OUTPUT",
+            ArtifactType::AppiumJavaOutput => "APPIUM_JAVA_OUTPUT",
+            ArtifactType::AppiumJavaXmlOutput => "APPIUM_JAVA_XML_OUTPUT",
+            ArtifactType::AppiumPythonOutput => "APPIUM_PYTHON_OUTPUT",
+            ArtifactType::AppiumPythonXmlOutput => "APPIUM_PYTHON_XML_OUTPUT",
+            ArtifactType::ExplorerEventLog => "EXPLORER_EVENT_LOG",
+            ArtifactType::ExplorerSummaryLog => "EXPLORER_SUMMARY_LOG",
+            ArtifactType::ApplicationCrashReport => "APPLICATION_CRASH_REPORT",
+            ArtifactType::XctestLog => "XCTEST_LOG",
+            ArtifactType::Video => "VIDEO",
+            ArtifactType::CustomerArtifact => "CUSTOMER_ARTIFACT",
+            ArtifactType::CustomerArtifactLog => "CUSTOMER_ARTIFACT_LOG",
+            ArtifactType::TestspecOutput => "TESTSPEC_OUTPUT",
+            ArtifactType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ArtifactType {
+    fn from(value: &'a str) -> ArtifactType {
+        match value {
+            "UNKNOWN" => ArtifactType::UnknownValue,
+            "SCREENSHOT" => ArtifactType::Screenshot,
+            "DEVICE_LOG" => ArtifactType::DeviceLog,
+            "MESSAGE_LOG" => ArtifactType::MessageLog,
+            "VIDEO_LOG" => ArtifactType::VideoLog,
+            "RESULT_LOG" => ArtifactType::ResultLog,
+            "SERVICE_LOG" => ArtifactType::ServiceLog,
+            "WEBKIT_LOG" => ArtifactType::WebkitLog,
+            "INSTRUMENTATION_OUTPUT" => ArtifactType::InstrumentationOutput,
+            "EXERCISER_MONKEY_OUTPUT" => ArtifactType::ExerciserMonkeyOutput,
+            "CALABASH_JSON_OUTPUT" => ArtifactType::CalabashJsonOutput,
+            "CALABASH_PRETTY_OUTPUT" => ArtifactType::CalabashPrettyOutput,
+            "CALABASH_STANDARD_OUTPUT" => ArtifactType::CalabashStandardOutput,
+            "CALABASH_JAVA_XML_OUTPUT" => ArtifactType::CalabashJavaXmlOutput,
+            "AUTOMATION_OUTPUT" => ArtifactType::AutomationOutput,
+            "APPIUM_SERVER_OUTPUT" => ArtifactType::AppiumServerOutput,
+            "APPIUM_JAVA_OUTPUT" => ArtifactType::AppiumJavaOutput,
+            "APPIUM_JAVA_XML_OUTPUT" => ArtifactType::AppiumJavaXmlOutput,
+            "APPIUM_PYTHON_OUTPUT" => ArtifactType::AppiumPythonOutput,
+            "APPIUM_PYTHON_XML_OUTPUT" => ArtifactType::AppiumPythonXmlOutput,
+            "EXPLORER_EVENT_LOG" => ArtifactType::ExplorerEventLog,
+            "EXPLORER_SUMMARY_LOG" => ArtifactType::ExplorerSummaryLog,
+            "APPLICATION_CRASH_REPORT" => ArtifactType::ApplicationCrashReport,
+            "XCTEST_LOG" => ArtifactType::XctestLog,
+            "VIDEO" => ArtifactType::Video,
+            "CUSTOMER_ARTIFACT" => ArtifactType::CustomerArtifact,
+            "CUSTOMER_ARTIFACT_LOG" => ArtifactType::CustomerArtifactLog,
+            "TESTSPEC_OUTPUT" => ArtifactType::TestspecOutput,
+            _ => ArtifactType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ArtifactType {
+    fn from(value: String) -> ArtifactType {
+        ArtifactType::from(value.as_str())
+    }
+}
+
+impl From<ArtifactType> for String {
+    fn from(value: ArtifactType) -> String {
+        match value {
+            ArtifactType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ArtifactType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ArtifactType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ArtifactType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ArtifactType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ArtifactType::from(value))
+    }
+}
+
+/// <p>The billing method for the remote access session.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BillingMethod {
+    /// <p><code>METERED</code></p>
+    Metered,
+    /// <p><code>UNMETERED</code></p>
+    Unmetered,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BillingMethod {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BillingMethod::Metered => "METERED",
+            BillingMethod::Unmetered => "UNMETERED",
+            BillingMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BillingMethod {
+    fn from(value: &'a str) -> BillingMethod {
+        match value {
+            "METERED" => BillingMethod::Metered,
+            "UNMETERED" => BillingMethod::Unmetered,
+            _ => BillingMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BillingMethod {
+    fn from(value: String) -> BillingMethod {
+        BillingMethod::from(value.as_str())
+    }
+}
+
+impl From<BillingMethod> for String {
+    fn from(value: BillingMethod) -> String {
+        match value {
+            BillingMethod::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BillingMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BillingMethod {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BillingMethod {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BillingMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BillingMethod::from(value))
+    }
+}
+
 /// <p>Represents the amount of CPU that an app is using on a physical device.</p> <p>Note that this does not represent system-wide CPU usage.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -161,6 +486,74 @@ pub struct CreateDevicePoolRequest {
     pub rules: Vec<Rule>,
 }
 
+impl CreateDevicePoolRequest {
+    /// Returns a builder for `CreateDevicePoolRequest`, checking its required members when built.
+    pub fn builder() -> CreateDevicePoolRequestBuilder {
+        CreateDevicePoolRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDevicePoolRequest`](struct.CreateDevicePoolRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDevicePoolRequestBuilder {
+    description: Option<String>,
+    max_devices: Option<i64>,
+    name: Option<String>,
+    project_arn: Option<String>,
+    rules: Option<Vec<Rule>>,
+}
+
+impl CreateDevicePoolRequestBuilder {
+    /// Sets the [`description`](struct.CreateDevicePoolRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_devices`](struct.CreateDevicePoolRequest.html#structfield.max_devices) member.
+    pub fn max_devices<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_devices = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateDevicePoolRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_arn`](struct.CreateDevicePoolRequest.html#structfield.project_arn) member.
+    pub fn project_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rules`](struct.CreateDevicePoolRequest.html#structfield.rules) member.
+    pub fn rules<T: Into<Vec<Rule>>>(mut self, value: T) -> Self {
+        self.rules = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDevicePoolRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDevicePoolRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDevicePoolRequest {
+            description: self.description,
+            max_devices: self.max_devices,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            project_arn: self
+                .project_arn
+                .ok_or(::rusoto_core::BuildError::missing("project_arn"))?,
+            rules: self
+                .rules
+                .ok_or(::rusoto_core::BuildError::missing("rules"))?,
+        })
+    }
+}
+
 /// <p>Represents the result of a create device pool request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -194,6 +587,70 @@ pub struct CreateInstanceProfileRequest {
     pub reboot_after_use: Option<bool>,
 }
 
+impl CreateInstanceProfileRequest {
+    /// Returns a builder for `CreateInstanceProfileRequest`, checking its required members when built.
+    pub fn builder() -> CreateInstanceProfileRequestBuilder {
+        CreateInstanceProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateInstanceProfileRequest`](struct.CreateInstanceProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateInstanceProfileRequestBuilder {
+    description: Option<String>,
+    exclude_app_packages_from_cleanup: Option<Vec<String>>,
+    name: Option<String>,
+    package_cleanup: Option<bool>,
+    reboot_after_use: Option<bool>,
+}
+
+impl CreateInstanceProfileRequestBuilder {
+    /// Sets the [`description`](struct.CreateInstanceProfileRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exclude_app_packages_from_cleanup`](struct.CreateInstanceProfileRequest.html#structfield.exclude_app_packages_from_cleanup) member.
+    pub fn exclude_app_packages_from_cleanup<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.exclude_app_packages_from_cleanup = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateInstanceProfileRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`package_cleanup`](struct.CreateInstanceProfileRequest.html#structfield.package_cleanup) member.
+    pub fn package_cleanup<T: Into<bool>>(mut self, value: T) -> Self {
+        self.package_cleanup = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reboot_after_use`](struct.CreateInstanceProfileRequest.html#structfield.reboot_after_use) member.
+    pub fn reboot_after_use<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reboot_after_use = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateInstanceProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateInstanceProfileRequest, ::rusoto_core::BuildError> {
+        Ok(CreateInstanceProfileRequest {
+            description: self.description,
+            exclude_app_packages_from_cleanup: self.exclude_app_packages_from_cleanup,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            package_cleanup: self.package_cleanup,
+            reboot_after_use: self.reboot_after_use,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateInstanceProfileResult {
@@ -234,7 +691,7 @@ pub struct CreateNetworkProfileRequest {
     /// <p>The type of network profile you wish to create. Valid values are listed below.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NetworkProfileType>,
     /// <p>The data throughput rate in bits per second, as an integer from 0 to 104857600.</p>
     #[serde(rename = "uplinkBandwidthBits")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -253,6 +710,128 @@ pub struct CreateNetworkProfileRequest {
     pub uplink_loss_percent: Option<i64>,
 }
 
+impl CreateNetworkProfileRequest {
+    /// Returns a builder for `CreateNetworkProfileRequest`, checking its required members when built.
+    pub fn builder() -> CreateNetworkProfileRequestBuilder {
+        CreateNetworkProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateNetworkProfileRequest`](struct.CreateNetworkProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateNetworkProfileRequestBuilder {
+    description: Option<String>,
+    downlink_bandwidth_bits: Option<i64>,
+    downlink_delay_ms: Option<i64>,
+    downlink_jitter_ms: Option<i64>,
+    downlink_loss_percent: Option<i64>,
+    name: Option<String>,
+    project_arn: Option<String>,
+    type_: Option<NetworkProfileType>,
+    uplink_bandwidth_bits: Option<i64>,
+    uplink_delay_ms: Option<i64>,
+    uplink_jitter_ms: Option<i64>,
+    uplink_loss_percent: Option<i64>,
+}
+
+impl CreateNetworkProfileRequestBuilder {
+    /// Sets the [`description`](struct.CreateNetworkProfileRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`downlink_bandwidth_bits`](struct.CreateNetworkProfileRequest.html#structfield.downlink_bandwidth_bits) member.
+    pub fn downlink_bandwidth_bits<T: Into<i64>>(mut self, value: T) -> Self {
+        self.downlink_bandwidth_bits = Some(value.into());
+        self
+    }
+
+    /// Sets the [`downlink_delay_ms`](struct.CreateNetworkProfileRequest.html#structfield.downlink_delay_ms) member.
+    pub fn downlink_delay_ms<T: Into<i64>>(mut self, value: T) -> Self {
+        self.downlink_delay_ms = Some(value.into());
+        self
+    }
+
+    /// Sets the [`downlink_jitter_ms`](struct.CreateNetworkProfileRequest.html#structfield.downlink_jitter_ms) member.
+    pub fn downlink_jitter_ms<T: Into<i64>>(mut self, value: T) -> Self {
+        self.downlink_jitter_ms = Some(value.into());
+        self
+    }
+
+    /// Sets the [`downlink_loss_percent`](struct.CreateNetworkProfileRequest.html#structfield.downlink_loss_percent) member.
+    pub fn downlink_loss_percent<T: Into<i64>>(mut self, value: T) -> Self {
+        self.downlink_loss_percent = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateNetworkProfileRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_arn`](struct.CreateNetworkProfileRequest.html#structfield.project_arn) member.
+    pub fn project_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.CreateNetworkProfileRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<NetworkProfileType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets the [`uplink_bandwidth_bits`](struct.CreateNetworkProfileRequest.html#structfield.uplink_bandwidth_bits) member.
+    pub fn uplink_bandwidth_bits<T: Into<i64>>(mut self, value: T) -> Self {
+        self.uplink_bandwidth_bits = Some(value.into());
+        self
+    }
+
+    /// Sets the [`uplink_delay_ms`](struct.CreateNetworkProfileRequest.html#structfield.uplink_delay_ms) member.
+    pub fn uplink_delay_ms<T: Into<i64>>(mut self, value: T) -> Self {
+        self.uplink_delay_ms = Some(value.into());
+        self
+    }
+
+    /// Sets the [`uplink_jitter_ms`](struct.CreateNetworkProfileRequest.html#structfield.uplink_jitter_ms) member.
+    pub fn uplink_jitter_ms<T: Into<i64>>(mut self, value: T) -> Self {
+        self.uplink_jitter_ms = Some(value.into());
+        self
+    }
+
+    /// Sets the [`uplink_loss_percent`](struct.CreateNetworkProfileRequest.html#structfield.uplink_loss_percent) member.
+    pub fn uplink_loss_percent<T: Into<i64>>(mut self, value: T) -> Self {
+        self.uplink_loss_percent = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateNetworkProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateNetworkProfileRequest, ::rusoto_core::BuildError> {
+        Ok(CreateNetworkProfileRequest {
+            description: self.description,
+            downlink_bandwidth_bits: self.downlink_bandwidth_bits,
+            downlink_delay_ms: self.downlink_delay_ms,
+            downlink_jitter_ms: self.downlink_jitter_ms,
+            downlink_loss_percent: self.downlink_loss_percent,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            project_arn: self
+                .project_arn
+                .ok_or(::rusoto_core::BuildError::missing("project_arn"))?,
+            type_: self.type_,
+            uplink_bandwidth_bits: self.uplink_bandwidth_bits,
+            uplink_delay_ms: self.uplink_delay_ms,
+            uplink_jitter_ms: self.uplink_jitter_ms,
+            uplink_loss_percent: self.uplink_loss_percent,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateNetworkProfileResult {
@@ -274,6 +853,44 @@ pub struct CreateProjectRequest {
     pub name: String,
 }
 
+impl CreateProjectRequest {
+    /// Returns a builder for `CreateProjectRequest`, checking its required members when built.
+    pub fn builder() -> CreateProjectRequestBuilder {
+        CreateProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateProjectRequest`](struct.CreateProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateProjectRequestBuilder {
+    default_job_timeout_minutes: Option<i64>,
+    name: Option<String>,
+}
+
+impl CreateProjectRequestBuilder {
+    /// Sets the [`default_job_timeout_minutes`](struct.CreateProjectRequest.html#structfield.default_job_timeout_minutes) member.
+    pub fn default_job_timeout_minutes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.default_job_timeout_minutes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateProjectRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateProjectRequest, ::rusoto_core::BuildError> {
+        Ok(CreateProjectRequest {
+            default_job_timeout_minutes: self.default_job_timeout_minutes,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 /// <p>Represents the result of a create project request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -290,7 +907,7 @@ pub struct CreateRemoteAccessSessionConfiguration {
     /// <p>The billing method for the remote access session.</p>
     #[serde(rename = "billingMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_method: Option<String>,
+    pub billing_method: Option<BillingMethod>,
     /// <p>An array of Amazon Resource Names (ARNs) included in the VPC endpoint configuration.</p>
     #[serde(rename = "vpceConfigurationArns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -318,7 +935,7 @@ pub struct CreateRemoteAccessSessionRequest {
     /// <p><p>The interaction mode of the remote access session. Valid values are:</p> <ul> <li> <p>INTERACTIVE: You can interact with the iOS device by viewing, touching, and rotating the screen. You <b>cannot</b> run XCUITest framework-based tests in this mode.</p> </li> <li> <p>NO<em>VIDEO: You are connected to the device but cannot interact with it or view the screen. This mode has the fastest test execution speed. You <b>can</b> run XCUITest framework-based tests in this mode.</p> </li> <li> <p>VIDEO</em>ONLY: You can view the screen but cannot touch or rotate it. You <b>can</b> run XCUITest framework-based tests and watch the screen in this mode.</p> </li> </ul></p>
     #[serde(rename = "interactionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interaction_mode: Option<String>,
+    pub interaction_mode: Option<InteractionMode>,
     /// <p>The name of the remote access session that you wish to create.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -348,6 +965,131 @@ pub struct CreateRemoteAccessSessionRequest {
     pub ssh_public_key: Option<String>,
 }
 
+impl CreateRemoteAccessSessionRequest {
+    /// Returns a builder for `CreateRemoteAccessSessionRequest`, checking its required members when built.
+    pub fn builder() -> CreateRemoteAccessSessionRequestBuilder {
+        CreateRemoteAccessSessionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRemoteAccessSessionRequest`](struct.CreateRemoteAccessSessionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRemoteAccessSessionRequestBuilder {
+    client_id: Option<String>,
+    configuration: Option<CreateRemoteAccessSessionConfiguration>,
+    device_arn: Option<String>,
+    instance_arn: Option<String>,
+    interaction_mode: Option<InteractionMode>,
+    name: Option<String>,
+    project_arn: Option<String>,
+    remote_debug_enabled: Option<bool>,
+    remote_record_app_arn: Option<String>,
+    remote_record_enabled: Option<bool>,
+    skip_app_resign: Option<bool>,
+    ssh_public_key: Option<String>,
+}
+
+impl CreateRemoteAccessSessionRequestBuilder {
+    /// Sets the [`client_id`](struct.CreateRemoteAccessSessionRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration`](struct.CreateRemoteAccessSessionRequest.html#structfield.configuration) member.
+    pub fn configuration<T: Into<CreateRemoteAccessSessionConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_arn`](struct.CreateRemoteAccessSessionRequest.html#structfield.device_arn) member.
+    pub fn device_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_arn`](struct.CreateRemoteAccessSessionRequest.html#structfield.instance_arn) member.
+    pub fn instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`interaction_mode`](struct.CreateRemoteAccessSessionRequest.html#structfield.interaction_mode) member.
+    pub fn interaction_mode<T: Into<InteractionMode>>(mut self, value: T) -> Self {
+        self.interaction_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateRemoteAccessSessionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_arn`](struct.CreateRemoteAccessSessionRequest.html#structfield.project_arn) member.
+    pub fn project_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remote_debug_enabled`](struct.CreateRemoteAccessSessionRequest.html#structfield.remote_debug_enabled) member.
+    pub fn remote_debug_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.remote_debug_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remote_record_app_arn`](struct.CreateRemoteAccessSessionRequest.html#structfield.remote_record_app_arn) member.
+    pub fn remote_record_app_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.remote_record_app_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remote_record_enabled`](struct.CreateRemoteAccessSessionRequest.html#structfield.remote_record_enabled) member.
+    pub fn remote_record_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.remote_record_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skip_app_resign`](struct.CreateRemoteAccessSessionRequest.html#structfield.skip_app_resign) member.
+    pub fn skip_app_resign<T: Into<bool>>(mut self, value: T) -> Self {
+        self.skip_app_resign = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_public_key`](struct.CreateRemoteAccessSessionRequest.html#structfield.ssh_public_key) member.
+    pub fn ssh_public_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRemoteAccessSessionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateRemoteAccessSessionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRemoteAccessSessionRequest {
+            client_id: self.client_id,
+            configuration: self.configuration,
+            device_arn: self
+                .device_arn
+                .ok_or(::rusoto_core::BuildError::missing("device_arn"))?,
+            instance_arn: self.instance_arn,
+            interaction_mode: self.interaction_mode,
+            name: self.name,
+            project_arn: self
+                .project_arn
+                .ok_or(::rusoto_core::BuildError::missing("project_arn"))?,
+            remote_debug_enabled: self.remote_debug_enabled,
+            remote_record_app_arn: self.remote_record_app_arn,
+            remote_record_enabled: self.remote_record_enabled,
+            skip_app_resign: self.skip_app_resign,
+            ssh_public_key: self.ssh_public_key,
+        })
+    }
+}
+
 /// <p>Represents the server response from a request to create a remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -359,7 +1101,7 @@ pub struct CreateRemoteAccessSessionResult {
 }
 
 /// <p>Represents a request to the create upload operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateUploadRequest {
     /// <p>The upload's content type (for example, "application/octet-stream").</p>
     #[serde(rename = "contentType")]
@@ -373,7 +1115,65 @@ pub struct CreateUploadRequest {
     pub project_arn: String,
     /// <p>The upload's upload type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>ANDROID_APP: An Android upload.</p> </li> <li> <p>IOS_APP: An iOS upload.</p> </li> <li> <p>WEB_APP: A web application upload.</p> </li> <li> <p>EXTERNAL_DATA: An external data upload.</p> </li> <li> <p>APPIUM_JAVA_JUNIT_TEST_PACKAGE: An Appium Java JUnit test package upload.</p> </li> <li> <p>APPIUM_JAVA_TESTNG_TEST_PACKAGE: An Appium Java TestNG test package upload.</p> </li> <li> <p>APPIUM_PYTHON_TEST_PACKAGE: An Appium Python test package upload.</p> </li> <li> <p>APPIUM_NODE_TEST_PACKAGE: An Appium Node.js test package upload.</p> </li> <li> <p>APPIUM_RUBY_TEST_PACKAGE: An Appium Ruby test package upload.</p> </li> <li> <p>APPIUM_WEB_JAVA_JUNIT_TEST_PACKAGE: An Appium Java JUnit test package upload for a web app.</p> </li> <li> <p>APPIUM_WEB_JAVA_TESTNG_TEST_PACKAGE: An Appium Java TestNG test package upload for a web app.</p> </li> <li> <p>APPIUM_WEB_PYTHON_TEST_PACKAGE: An Appium Python test package upload for a web app.</p> </li> <li> <p>APPIUM_WEB_NODE_TEST_PACKAGE: An Appium Node.js test package upload for a web app.</p> </li> <li> <p>APPIUM_WEB_RUBY_TEST_PACKAGE: An Appium Ruby test package upload for a web app.</p> </li> <li> <p>CALABASH_TEST_PACKAGE: A Calabash test package upload.</p> </li> <li> <p>INSTRUMENTATION_TEST_PACKAGE: An instrumentation upload.</p> </li> <li> <p>UIAUTOMATION_TEST_PACKAGE: A uiautomation test package upload.</p> </li> <li> <p>UIAUTOMATOR_TEST_PACKAGE: A uiautomator test package upload.</p> </li> <li> <p>XCTEST_TEST_PACKAGE: An Xcode test package upload.</p> </li> <li> <p>XCTEST_UI_TEST_PACKAGE: An Xcode UI test package upload.</p> </li> <li> <p>APPIUM_JAVA_JUNIT_TEST_SPEC: An Appium Java JUnit test spec upload.</p> </li> <li> <p>APPIUM_JAVA_TESTNG_TEST_SPEC: An Appium Java TestNG test spec upload.</p> </li> <li> <p>APPIUM_PYTHON_TEST_SPEC: An Appium Python test spec upload.</p> </li> <li> <p>APPIUM_NODE_TEST_SPEC: An Appium Node.js test spec upload.</p> </li> <li> <p>APPIUM_RUBY_TEST_SPEC: An Appium Ruby test spec upload.</p> </li> <li> <p>APPIUM_WEB_JAVA_JUNIT_TEST_SPEC: An Appium Java JUnit test spec upload for a web app.</p> </li> <li> <p>APPIUM_WEB_JAVA_TESTNG_TEST_SPEC: An Appium Java TestNG test spec upload for a web app.</p> </li> <li> <p>APPIUM_WEB_PYTHON_TEST_SPEC: An Appium Python test spec upload for a web app.</p> </li> <li> <p>APPIUM_WEB_NODE_TEST_SPEC: An Appium Node.js test spec upload for a web app.</p> </li> <li> <p>APPIUM_WEB_RUBY_TEST_SPEC: An Appium Ruby test spec upload for a web app.</p> </li> <li> <p>INSTRUMENTATION_TEST_SPEC: An instrumentation test spec upload.</p> </li> <li> <p>XCTEST_UI_TEST_SPEC: An Xcode UI test spec upload.</p> </li> </ul> <p> <b>Note</b> If you call <code>CreateUpload</code> with <code>WEB_APP</code> specified, AWS Device Farm throws an <code>ArgumentException</code> error.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: UploadType,
+}
+
+impl CreateUploadRequest {
+    /// Returns a builder for `CreateUploadRequest`, checking its required members when built.
+    pub fn builder() -> CreateUploadRequestBuilder {
+        CreateUploadRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUploadRequest`](struct.CreateUploadRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUploadRequestBuilder {
+    content_type: Option<String>,
+    name: Option<String>,
+    project_arn: Option<String>,
+    type_: Option<UploadType>,
+}
+
+impl CreateUploadRequestBuilder {
+    /// Sets the [`content_type`](struct.CreateUploadRequest.html#structfield.content_type) member.
+    pub fn content_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateUploadRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_arn`](struct.CreateUploadRequest.html#structfield.project_arn) member.
+    pub fn project_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.CreateUploadRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<UploadType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUploadRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateUploadRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUploadRequest {
+            content_type: self.content_type,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            project_arn: self
+                .project_arn
+                .ok_or(::rusoto_core::BuildError::missing("project_arn"))?,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 /// <p>Represents the result of a create upload request.</p>
@@ -403,6 +1203,66 @@ pub struct CreateVPCEConfigurationRequest {
     pub vpce_service_name: String,
 }
 
+impl CreateVPCEConfigurationRequest {
+    /// Returns a builder for `CreateVPCEConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> CreateVPCEConfigurationRequestBuilder {
+        CreateVPCEConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateVPCEConfigurationRequest`](struct.CreateVPCEConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateVPCEConfigurationRequestBuilder {
+    service_dns_name: Option<String>,
+    vpce_configuration_description: Option<String>,
+    vpce_configuration_name: Option<String>,
+    vpce_service_name: Option<String>,
+}
+
+impl CreateVPCEConfigurationRequestBuilder {
+    /// Sets the [`service_dns_name`](struct.CreateVPCEConfigurationRequest.html#structfield.service_dns_name) member.
+    pub fn service_dns_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_dns_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpce_configuration_description`](struct.CreateVPCEConfigurationRequest.html#structfield.vpce_configuration_description) member.
+    pub fn vpce_configuration_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpce_configuration_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpce_configuration_name`](struct.CreateVPCEConfigurationRequest.html#structfield.vpce_configuration_name) member.
+    pub fn vpce_configuration_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpce_configuration_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpce_service_name`](struct.CreateVPCEConfigurationRequest.html#structfield.vpce_service_name) member.
+    pub fn vpce_service_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpce_service_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateVPCEConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateVPCEConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateVPCEConfigurationRequest {
+            service_dns_name: self
+                .service_dns_name
+                .ok_or(::rusoto_core::BuildError::missing("service_dns_name"))?,
+            vpce_configuration_description: self.vpce_configuration_description,
+            vpce_configuration_name: self.vpce_configuration_name.ok_or(
+                ::rusoto_core::BuildError::missing("vpce_configuration_name"),
+            )?,
+            vpce_service_name: self
+                .vpce_service_name
+                .ok_or(::rusoto_core::BuildError::missing("vpce_service_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateVPCEConfigurationResult {
@@ -412,35 +1272,133 @@ pub struct CreateVPCEConfigurationResult {
     pub vpce_configuration: Option<VPCEConfiguration>,
 }
 
-/// <p>A JSON object specifying the paths where the artifacts generated by the customer's tests, on the device or in the test environment, will be pulled from.</p> <p>Specify <code>deviceHostPaths</code> and optionally specify either <code>iosPaths</code> or <code>androidPaths</code>.</p> <p>For web app tests, you can specify both <code>iosPaths</code> and <code>androidPaths</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CustomerArtifactPaths {
-    /// <p>Comma-separated list of paths on the Android device where the artifacts generated by the customer's tests will be pulled from.</p>
-    #[serde(rename = "androidPaths")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub android_paths: Option<Vec<String>>,
-    /// <p>Comma-separated list of paths in the test execution environment where the artifacts generated by the customer's tests will be pulled from.</p>
-    #[serde(rename = "deviceHostPaths")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_host_paths: Option<Vec<String>>,
-    /// <p>Comma-separated list of paths on the iOS device where the artifacts generated by the customer's tests will be pulled from.</p>
-    #[serde(rename = "iosPaths")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ios_paths: Option<Vec<String>>,
+/// <p>The currency code of a monetary amount. For example, <code>USD</code> means "U.S. dollars."</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CurrencyCode {
+    /// <p><code>USD</code></p>
+    Usd,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Represents a request to the delete device pool operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DeleteDevicePoolRequest {
-    /// <p>Represents the Amazon Resource Name (ARN) of the Device Farm device pool you wish to delete.</p>
-    #[serde(rename = "arn")]
-    pub arn: String,
+impl CurrencyCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CurrencyCode::Usd => "USD",
+            CurrencyCode::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Represents the result of a delete device pool request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DeleteDevicePoolResult {}
+impl<'a> From<&'a str> for CurrencyCode {
+    fn from(value: &'a str) -> CurrencyCode {
+        match value {
+            "USD" => CurrencyCode::Usd,
+            _ => CurrencyCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CurrencyCode {
+    fn from(value: String) -> CurrencyCode {
+        CurrencyCode::from(value.as_str())
+    }
+}
+
+impl From<CurrencyCode> for String {
+    fn from(value: CurrencyCode) -> String {
+        match value {
+            CurrencyCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CurrencyCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CurrencyCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CurrencyCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CurrencyCode::from(value))
+    }
+}
+
+/// <p>A JSON object specifying the paths where the artifacts generated by the customer's tests, on the device or in the test environment, will be pulled from.</p> <p>Specify <code>deviceHostPaths</code> and optionally specify either <code>iosPaths</code> or <code>androidPaths</code>.</p> <p>For web app tests, you can specify both <code>iosPaths</code> and <code>androidPaths</code>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomerArtifactPaths {
+    /// <p>Comma-separated list of paths on the Android device where the artifacts generated by the customer's tests will be pulled from.</p>
+    #[serde(rename = "androidPaths")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub android_paths: Option<Vec<String>>,
+    /// <p>Comma-separated list of paths in the test execution environment where the artifacts generated by the customer's tests will be pulled from.</p>
+    #[serde(rename = "deviceHostPaths")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_host_paths: Option<Vec<String>>,
+    /// <p>Comma-separated list of paths on the iOS device where the artifacts generated by the customer's tests will be pulled from.</p>
+    #[serde(rename = "iosPaths")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ios_paths: Option<Vec<String>>,
+}
+
+/// <p>Represents a request to the delete device pool operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteDevicePoolRequest {
+    /// <p>Represents the Amazon Resource Name (ARN) of the Device Farm device pool you wish to delete.</p>
+    #[serde(rename = "arn")]
+    pub arn: String,
+}
+
+impl DeleteDevicePoolRequest {
+    /// Returns a builder for `DeleteDevicePoolRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDevicePoolRequestBuilder {
+        DeleteDevicePoolRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDevicePoolRequest`](struct.DeleteDevicePoolRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDevicePoolRequestBuilder {
+    arn: Option<String>,
+}
+
+impl DeleteDevicePoolRequestBuilder {
+    /// Sets the [`arn`](struct.DeleteDevicePoolRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDevicePoolRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDevicePoolRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDevicePoolRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
+/// <p>Represents the result of a delete device pool request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteDevicePoolResult {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteInstanceProfileRequest {
@@ -449,6 +1407,36 @@ pub struct DeleteInstanceProfileRequest {
     pub arn: String,
 }
 
+impl DeleteInstanceProfileRequest {
+    /// Returns a builder for `DeleteInstanceProfileRequest`, checking its required members when built.
+    pub fn builder() -> DeleteInstanceProfileRequestBuilder {
+        DeleteInstanceProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteInstanceProfileRequest`](struct.DeleteInstanceProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteInstanceProfileRequestBuilder {
+    arn: Option<String>,
+}
+
+impl DeleteInstanceProfileRequestBuilder {
+    /// Sets the [`arn`](struct.DeleteInstanceProfileRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteInstanceProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteInstanceProfileRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteInstanceProfileRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteInstanceProfileResult {}
@@ -460,6 +1448,36 @@ pub struct DeleteNetworkProfileRequest {
     pub arn: String,
 }
 
+impl DeleteNetworkProfileRequest {
+    /// Returns a builder for `DeleteNetworkProfileRequest`, checking its required members when built.
+    pub fn builder() -> DeleteNetworkProfileRequestBuilder {
+        DeleteNetworkProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteNetworkProfileRequest`](struct.DeleteNetworkProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteNetworkProfileRequestBuilder {
+    arn: Option<String>,
+}
+
+impl DeleteNetworkProfileRequestBuilder {
+    /// Sets the [`arn`](struct.DeleteNetworkProfileRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteNetworkProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteNetworkProfileRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteNetworkProfileRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteNetworkProfileResult {}
@@ -472,6 +1490,34 @@ pub struct DeleteProjectRequest {
     pub arn: String,
 }
 
+impl DeleteProjectRequest {
+    /// Returns a builder for `DeleteProjectRequest`, checking its required members when built.
+    pub fn builder() -> DeleteProjectRequestBuilder {
+        DeleteProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteProjectRequest`](struct.DeleteProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteProjectRequestBuilder {
+    arn: Option<String>,
+}
+
+impl DeleteProjectRequestBuilder {
+    /// Sets the [`arn`](struct.DeleteProjectRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteProjectRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteProjectRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the result of a delete project request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -485,6 +1531,36 @@ pub struct DeleteRemoteAccessSessionRequest {
     pub arn: String,
 }
 
+impl DeleteRemoteAccessSessionRequest {
+    /// Returns a builder for `DeleteRemoteAccessSessionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRemoteAccessSessionRequestBuilder {
+        DeleteRemoteAccessSessionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRemoteAccessSessionRequest`](struct.DeleteRemoteAccessSessionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRemoteAccessSessionRequestBuilder {
+    arn: Option<String>,
+}
+
+impl DeleteRemoteAccessSessionRequestBuilder {
+    /// Sets the [`arn`](struct.DeleteRemoteAccessSessionRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRemoteAccessSessionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRemoteAccessSessionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRemoteAccessSessionRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>The response from the server when a request is made to delete the remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -498,6 +1574,34 @@ pub struct DeleteRunRequest {
     pub arn: String,
 }
 
+impl DeleteRunRequest {
+    /// Returns a builder for `DeleteRunRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRunRequestBuilder {
+        DeleteRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRunRequest`](struct.DeleteRunRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRunRequestBuilder {
+    arn: Option<String>,
+}
+
+impl DeleteRunRequestBuilder {
+    /// Sets the [`arn`](struct.DeleteRunRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRunRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteRunRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRunRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the result of a delete run request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -511,6 +1615,34 @@ pub struct DeleteUploadRequest {
     pub arn: String,
 }
 
+impl DeleteUploadRequest {
+    /// Returns a builder for `DeleteUploadRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUploadRequestBuilder {
+        DeleteUploadRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUploadRequest`](struct.DeleteUploadRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUploadRequestBuilder {
+    arn: Option<String>,
+}
+
+impl DeleteUploadRequestBuilder {
+    /// Sets the [`arn`](struct.DeleteUploadRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUploadRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteUploadRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUploadRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the result of a delete upload request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -523,6 +1655,36 @@ pub struct DeleteVPCEConfigurationRequest {
     pub arn: String,
 }
 
+impl DeleteVPCEConfigurationRequest {
+    /// Returns a builder for `DeleteVPCEConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteVPCEConfigurationRequestBuilder {
+        DeleteVPCEConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVPCEConfigurationRequest`](struct.DeleteVPCEConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteVPCEConfigurationRequestBuilder {
+    arn: Option<String>,
+}
+
+impl DeleteVPCEConfigurationRequestBuilder {
+    /// Sets the [`arn`](struct.DeleteVPCEConfigurationRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVPCEConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVPCEConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteVPCEConfigurationRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteVPCEConfigurationResult {}
@@ -538,7 +1700,7 @@ pub struct Device {
     /// <p>Reflects how likely a device will be available for a test run. It is currently available in the ListDevices and GetDevice API methods.</p>
     #[serde(rename = "availability")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub availability: Option<String>,
+    pub availability: Option<DeviceAvailability>,
     /// <p>The device's carrier.</p>
     #[serde(rename = "carrier")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -558,7 +1720,7 @@ pub struct Device {
     /// <p><p>The device&#39;s form factor.</p> <p>Allowed values include:</p> <ul> <li> <p>PHONE: The phone form factor.</p> </li> <li> <p>TABLET: The tablet form factor.</p> </li> </ul></p>
     #[serde(rename = "formFactor")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub form_factor: Option<String>,
+    pub form_factor: Option<DeviceFormFactor>,
     /// <p>The device's heap size, expressed in bytes.</p>
     #[serde(rename = "heapSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -598,7 +1760,7 @@ pub struct Device {
     /// <p><p>The device&#39;s platform.</p> <p>Allowed values include:</p> <ul> <li> <p>ANDROID: The Android platform.</p> </li> <li> <p>IOS: The iOS platform.</p> </li> </ul></p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<DevicePlatform>,
     /// <p>The device's radio.</p>
     #[serde(rename = "radio")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -617,75 +1779,527 @@ pub struct Device {
     pub resolution: Option<Resolution>,
 }
 
+/// <p><p>The type of incompatibility.</p> <p>Allowed values include:</p> <ul> <li> <p>ARN: The ARN.</p> </li> <li> <p>FORM<em>FACTOR: The form factor (for example, phone or tablet).</p> </li> <li> <p>MANUFACTURER: The manufacturer.</p> </li> <li> <p>PLATFORM: The platform (for example, Android or iOS).</p> </li> <li> <p>REMOTE</em>ACCESS<em>ENABLED: Whether the device is enabled for remote access.</p> </li> <li> <p>APPIUM</em>VERSION: The Appium version for the test.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceAttribute {
+    /// <p><code>ARN</code></p>
+    Arn,
+    /// <p><code>PLATFORM</code></p>
+    Platform,
+    /// <p><code>FORM_FACTOR</code></p>
+    FormFactor,
+    /// <p><code>MANUFACTURER</code></p>
+    Manufacturer,
+    /// <p><code>REMOTE_ACCESS_ENABLED</code></p>
+    RemoteAccessEnabled,
+    /// <p><code>REMOTE_DEBUG_ENABLED</code></p>
+    RemoteDebugEnabled,
+    /// <p><code>APPIUM_VERSION</code></p>
+    AppiumVersion,
+    /// <p><code>INSTANCE_ARN</code></p>
+    InstanceArn,
+    /// <p><code>INSTANCE_LABELS</code></p>
+    InstanceLabels,
+    /// <p><code>FLEET_TYPE</code></p>
+    FleetType,
+    /// <p><code>OS_VERSION</code></p>
+    OsVersion,
+    /// <p><code>MODEL</code></p>
+    Model,
+    /// <p><code>AVAILABILITY</code></p>
+    Availability,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceAttribute {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceAttribute::Arn => "ARN",
+            DeviceAttribute::Platform => "PLATFORM",
+            DeviceAttribute::FormFactor => "FORM_FACTOR",
+            DeviceAttribute::Manufacturer => "MANUFACTURER",
+            DeviceAttribute::RemoteAccessEnabled => "REMOTE_ACCESS_ENABLED",
+            DeviceAttribute::RemoteDebugEnabled => "REMOTE_DEBUG_ENABLED",
+            DeviceAttribute::AppiumVersion => "APPIUM_VERSION",
+            DeviceAttribute::InstanceArn => "INSTANCE_ARN",
+            DeviceAttribute::InstanceLabels => "INSTANCE_LABELS",
+            DeviceAttribute::FleetType => "FLEET_TYPE",
+            DeviceAttribute::OsVersion => "OS_VERSION",
+            DeviceAttribute::Model => "MODEL",
+            DeviceAttribute::Availability => "AVAILABILITY",
+            DeviceAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeviceAttribute {
+    fn from(value: &'a str) -> DeviceAttribute {
+        match value {
+            "ARN" => DeviceAttribute::Arn,
+            "PLATFORM" => DeviceAttribute::Platform,
+            "FORM_FACTOR" => DeviceAttribute::FormFactor,
+            "MANUFACTURER" => DeviceAttribute::Manufacturer,
+            "REMOTE_ACCESS_ENABLED" => DeviceAttribute::RemoteAccessEnabled,
+            "REMOTE_DEBUG_ENABLED" => DeviceAttribute::RemoteDebugEnabled,
+            "APPIUM_VERSION" => DeviceAttribute::AppiumVersion,
+            "INSTANCE_ARN" => DeviceAttribute::InstanceArn,
+            "INSTANCE_LABELS" => DeviceAttribute::InstanceLabels,
+            "FLEET_TYPE" => DeviceAttribute::FleetType,
+            "OS_VERSION" => DeviceAttribute::OsVersion,
+            "MODEL" => DeviceAttribute::Model,
+            "AVAILABILITY" => DeviceAttribute::Availability,
+            _ => DeviceAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceAttribute {
+    fn from(value: String) -> DeviceAttribute {
+        DeviceAttribute::from(value.as_str())
+    }
+}
+
+impl From<DeviceAttribute> for String {
+    fn from(value: DeviceAttribute) -> String {
+        match value {
+            DeviceAttribute::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeviceAttribute {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceAttribute {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeviceAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceAttribute::from(value))
+    }
+}
+
+/// <p>Reflects how likely a device will be available for a test run. It is currently available in the ListDevices and GetDevice API methods.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceAvailability {
+    /// <p><code>TEMPORARY_NOT_AVAILABLE</code></p>
+    TemporaryNotAvailable,
+    /// <p><code>BUSY</code></p>
+    Busy,
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>HIGHLY_AVAILABLE</code></p>
+    HighlyAvailable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceAvailability {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceAvailability::TemporaryNotAvailable => "TEMPORARY_NOT_AVAILABLE",
+            DeviceAvailability::Busy => "BUSY",
+            DeviceAvailability::Available => "AVAILABLE",
+            DeviceAvailability::HighlyAvailable => "HIGHLY_AVAILABLE",
+            DeviceAvailability::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeviceAvailability {
+    fn from(value: &'a str) -> DeviceAvailability {
+        match value {
+            "TEMPORARY_NOT_AVAILABLE" => DeviceAvailability::TemporaryNotAvailable,
+            "BUSY" => DeviceAvailability::Busy,
+            "AVAILABLE" => DeviceAvailability::Available,
+            "HIGHLY_AVAILABLE" => DeviceAvailability::HighlyAvailable,
+            _ => DeviceAvailability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceAvailability {
+    fn from(value: String) -> DeviceAvailability {
+        DeviceAvailability::from(value.as_str())
+    }
+}
+
+impl From<DeviceAvailability> for String {
+    fn from(value: DeviceAvailability) -> String {
+        match value {
+            DeviceAvailability::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceAvailability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeviceAvailability {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceAvailability {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeviceAvailability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceAvailability::from(value))
+    }
+}
+
 /// <p>Represents a device filter used to select a set of devices to be included in a test run. This data structure is passed in as the <code>deviceSelectionConfiguration</code> parameter to ScheduleRun. For an example of the JSON request syntax, see <a>ScheduleRun</a>.</p> <p>It is also passed in as the <code>filters</code> parameter to ListDevices. For an example of the JSON request syntax, see <a>ListDevices</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeviceFilter {
     /// <p><p>The aspect of a device such as platform or model used as the selection criteria in a device filter.</p> <p>The supported operators for each attribute are provided in the following list.</p> <dl> <dt>ARN</dt> <dd> <p>The Amazon Resource Name (ARN) of the device. For example, &quot;arn:aws:devicefarm:us-west-2::device:12345Example&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>IN</code>, <code>NOT<em>IN</code> </p> </dd> <dt>PLATFORM</dt> <dd> <p>The device platform. Valid values are &quot;ANDROID&quot; or &quot;IOS&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>OS</em>VERSION</dt> <dd> <p>The operating system version. For example, &quot;10.3.2&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>GREATER<em>THAN</code>, <code>GREATER</em>THAN<em>OR</em>EQUALS</code>, <code>IN</code>, <code>LESS<em>THAN</code>, <code>LESS</em>THAN<em>OR</em>EQUALS</code>, <code>NOT<em>IN</code> </p> </dd> <dt>MODEL</dt> <dd> <p>The device model. For example, &quot;iPad 5th Gen&quot;.</p> <p> <i>Supported operators</i>: <code>CONTAINS</code>, <code>EQUALS</code>, <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>AVAILABILITY</dt> <dd> <p>The current availability of the device. Valid values are &quot;AVAILABLE&quot;, &quot;HIGHLY<em>AVAILABLE&quot;, &quot;BUSY&quot;, or &quot;TEMPORARY</em>NOT<em>AVAILABLE&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>FORM</em>FACTOR</dt> <dd> <p>The device form factor. Valid values are &quot;PHONE&quot; or &quot;TABLET&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>MANUFACTURER</dt> <dd> <p>The device manufacturer. For example, &quot;Apple&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>IN</code>, <code>NOT<em>IN</code> </p> </dd> <dt>REMOTE</em>ACCESS<em>ENABLED</dt> <dd> <p>Whether the device is enabled for remote access. Valid values are &quot;TRUE&quot; or &quot;FALSE&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>REMOTE</em>DEBUG<em>ENABLED</dt> <dd> <p>Whether the device is enabled for remote debugging. Valid values are &quot;TRUE&quot; or &quot;FALSE&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>INSTANCE</em>ARN</dt> <dd> <p>The Amazon Resource Name (ARN) of the device instance.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>IN</code>, <code>NOT<em>IN</code> </p> </dd> <dt>INSTANCE</em>LABELS</dt> <dd> <p>The label of the device instance.</p> <p> <i>Supported operators</i>: <code>CONTAINS</code> </p> </dd> <dt>FLEET_TYPE</dt> <dd> <p>The fleet type. Valid values are &quot;PUBLIC&quot; or &quot;PRIVATE&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> </dl></p>
     #[serde(rename = "attribute")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute: Option<String>,
+    pub attribute: Option<DeviceFilterAttribute>,
     /// <p>Specifies how Device Farm compares the filter's attribute to the value. For the operators that are supported by each attribute, see the attribute descriptions.</p>
     #[serde(rename = "operator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operator: Option<String>,
+    pub operator: Option<RuleOperator>,
     /// <p><p>An array of one or more filter values used in a device filter.</p> <p class="title"> <b>Operator Values</b> </p> <ul> <li> <p>The IN and NOT<em>IN operators can take a values array that has more than one element.</p> </li> <li> <p>The other operators require an array with a single element.</p> </li> </ul> <p class="title"> <b>Attribute Values</b> </p> <ul> <li> <p>The PLATFORM attribute can be set to &quot;ANDROID&quot; or &quot;IOS&quot;.</p> </li> <li> <p>The AVAILABILITY attribute can be set to &quot;AVAILABLE&quot;, &quot;HIGHLY</em>AVAILABLE&quot;, &quot;BUSY&quot;, or &quot;TEMPORARY<em>NOT</em>AVAILABLE&quot;.</p> </li> <li> <p>The FORM<em>FACTOR attribute can be set to &quot;PHONE&quot; or &quot;TABLET&quot;.</p> </li> <li> <p>The FLEET</em>TYPE attribute can be set to &quot;PUBLIC&quot; or &quot;PRIVATE&quot;.</p> </li> </ul></p>
     #[serde(rename = "values")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
 }
 
-/// <p>Represents the device instance.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DeviceInstance {
-    /// <p>The Amazon Resource Name (ARN) of the device instance.</p>
-    #[serde(rename = "arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The Amazon Resource Name (ARN) of the device.</p>
-    #[serde(rename = "deviceArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_arn: Option<String>,
-    /// <p>A object containing information about the instance profile.</p>
-    #[serde(rename = "instanceProfile")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_profile: Option<InstanceProfile>,
-    /// <p>An array of strings describing the device instance.</p>
-    #[serde(rename = "labels")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<Vec<String>>,
-    /// <p>The status of the device instance. Valid values are listed below.</p>
-    #[serde(rename = "status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    /// <p>Unique device identifier for the device instance.</p>
-    #[serde(rename = "udid")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub udid: Option<String>,
+/// <p><p>The aspect of a device such as platform or model used as the selection criteria in a device filter.</p> <p>The supported operators for each attribute are provided in the following list.</p> <dl> <dt>ARN</dt> <dd> <p>The Amazon Resource Name (ARN) of the device. For example, &quot;arn:aws:devicefarm:us-west-2::device:12345Example&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>IN</code>, <code>NOT<em>IN</code> </p> </dd> <dt>PLATFORM</dt> <dd> <p>The device platform. Valid values are &quot;ANDROID&quot; or &quot;IOS&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>OS</em>VERSION</dt> <dd> <p>The operating system version. For example, &quot;10.3.2&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>GREATER<em>THAN</code>, <code>GREATER</em>THAN<em>OR</em>EQUALS</code>, <code>IN</code>, <code>LESS<em>THAN</code>, <code>LESS</em>THAN<em>OR</em>EQUALS</code>, <code>NOT<em>IN</code> </p> </dd> <dt>MODEL</dt> <dd> <p>The device model. For example, &quot;iPad 5th Gen&quot;.</p> <p> <i>Supported operators</i>: <code>CONTAINS</code>, <code>EQUALS</code>, <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>AVAILABILITY</dt> <dd> <p>The current availability of the device. Valid values are &quot;AVAILABLE&quot;, &quot;HIGHLY<em>AVAILABLE&quot;, &quot;BUSY&quot;, or &quot;TEMPORARY</em>NOT<em>AVAILABLE&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>FORM</em>FACTOR</dt> <dd> <p>The device form factor. Valid values are &quot;PHONE&quot; or &quot;TABLET&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>MANUFACTURER</dt> <dd> <p>The device manufacturer. For example, &quot;Apple&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>IN</code>, <code>NOT<em>IN</code> </p> </dd> <dt>REMOTE</em>ACCESS<em>ENABLED</dt> <dd> <p>Whether the device is enabled for remote access. Valid values are &quot;TRUE&quot; or &quot;FALSE&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>REMOTE</em>DEBUG<em>ENABLED</dt> <dd> <p>Whether the device is enabled for remote debugging. Valid values are &quot;TRUE&quot; or &quot;FALSE&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>INSTANCE</em>ARN</dt> <dd> <p>The Amazon Resource Name (ARN) of the device instance.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>IN</code>, <code>NOT<em>IN</code> </p> </dd> <dt>INSTANCE</em>LABELS</dt> <dd> <p>The label of the device instance.</p> <p> <i>Supported operators</i>: <code>CONTAINS</code> </p> </dd> <dt>FLEET_TYPE</dt> <dd> <p>The fleet type. Valid values are &quot;PUBLIC&quot; or &quot;PRIVATE&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> </dl></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceFilterAttribute {
+    /// <p><code>ARN</code></p>
+    Arn,
+    /// <p><code>PLATFORM</code></p>
+    Platform,
+    /// <p><code>OS_VERSION</code></p>
+    OsVersion,
+    /// <p><code>MODEL</code></p>
+    Model,
+    /// <p><code>AVAILABILITY</code></p>
+    Availability,
+    /// <p><code>FORM_FACTOR</code></p>
+    FormFactor,
+    /// <p><code>MANUFACTURER</code></p>
+    Manufacturer,
+    /// <p><code>REMOTE_ACCESS_ENABLED</code></p>
+    RemoteAccessEnabled,
+    /// <p><code>REMOTE_DEBUG_ENABLED</code></p>
+    RemoteDebugEnabled,
+    /// <p><code>INSTANCE_ARN</code></p>
+    InstanceArn,
+    /// <p><code>INSTANCE_LABELS</code></p>
+    InstanceLabels,
+    /// <p><code>FLEET_TYPE</code></p>
+    FleetType,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceFilterAttribute {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceFilterAttribute::Arn => "ARN",
+            DeviceFilterAttribute::Platform => "PLATFORM",
+            DeviceFilterAttribute::OsVersion => "OS_VERSION",
+            DeviceFilterAttribute::Model => "MODEL",
+            DeviceFilterAttribute::Availability => "AVAILABILITY",
+            DeviceFilterAttribute::FormFactor => "FORM_FACTOR",
+            DeviceFilterAttribute::Manufacturer => "MANUFACTURER",
+            DeviceFilterAttribute::RemoteAccessEnabled => "REMOTE_ACCESS_ENABLED",
+            DeviceFilterAttribute::RemoteDebugEnabled => "REMOTE_DEBUG_ENABLED",
+            DeviceFilterAttribute::InstanceArn => "INSTANCE_ARN",
+            DeviceFilterAttribute::InstanceLabels => "INSTANCE_LABELS",
+            DeviceFilterAttribute::FleetType => "FLEET_TYPE",
+            DeviceFilterAttribute::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Represents the total (metered or unmetered) minutes used by the resource to run tests. Contains the sum of minutes consumed by all children.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DeviceMinutes {
-    /// <p>When specified, represents only the sum of metered minutes used by the resource to run tests.</p>
-    #[serde(rename = "metered")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub metered: Option<f64>,
-    /// <p>When specified, represents the total minutes used by the resource to run tests.</p>
-    #[serde(rename = "total")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub total: Option<f64>,
-    /// <p>When specified, represents only the sum of unmetered minutes used by the resource to run tests.</p>
-    #[serde(rename = "unmetered")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub unmetered: Option<f64>,
+impl<'a> From<&'a str> for DeviceFilterAttribute {
+    fn from(value: &'a str) -> DeviceFilterAttribute {
+        match value {
+            "ARN" => DeviceFilterAttribute::Arn,
+            "PLATFORM" => DeviceFilterAttribute::Platform,
+            "OS_VERSION" => DeviceFilterAttribute::OsVersion,
+            "MODEL" => DeviceFilterAttribute::Model,
+            "AVAILABILITY" => DeviceFilterAttribute::Availability,
+            "FORM_FACTOR" => DeviceFilterAttribute::FormFactor,
+            "MANUFACTURER" => DeviceFilterAttribute::Manufacturer,
+            "REMOTE_ACCESS_ENABLED" => DeviceFilterAttribute::RemoteAccessEnabled,
+            "REMOTE_DEBUG_ENABLED" => DeviceFilterAttribute::RemoteDebugEnabled,
+            "INSTANCE_ARN" => DeviceFilterAttribute::InstanceArn,
+            "INSTANCE_LABELS" => DeviceFilterAttribute::InstanceLabels,
+            "FLEET_TYPE" => DeviceFilterAttribute::FleetType,
+            _ => DeviceFilterAttribute::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>Represents a collection of device types.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DevicePool {
+impl From<String> for DeviceFilterAttribute {
+    fn from(value: String) -> DeviceFilterAttribute {
+        DeviceFilterAttribute::from(value.as_str())
+    }
+}
+
+impl From<DeviceFilterAttribute> for String {
+    fn from(value: DeviceFilterAttribute) -> String {
+        match value {
+            DeviceFilterAttribute::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceFilterAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeviceFilterAttribute {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceFilterAttribute {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeviceFilterAttribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceFilterAttribute::from(value))
+    }
+}
+
+/// <p><p>The device&#39;s form factor.</p> <p>Allowed values include:</p> <ul> <li> <p>PHONE: The phone form factor.</p> </li> <li> <p>TABLET: The tablet form factor.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceFormFactor {
+    /// <p><code>PHONE</code></p>
+    Phone,
+    /// <p><code>TABLET</code></p>
+    Tablet,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceFormFactor {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceFormFactor::Phone => "PHONE",
+            DeviceFormFactor::Tablet => "TABLET",
+            DeviceFormFactor::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeviceFormFactor {
+    fn from(value: &'a str) -> DeviceFormFactor {
+        match value {
+            "PHONE" => DeviceFormFactor::Phone,
+            "TABLET" => DeviceFormFactor::Tablet,
+            _ => DeviceFormFactor::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceFormFactor {
+    fn from(value: String) -> DeviceFormFactor {
+        DeviceFormFactor::from(value.as_str())
+    }
+}
+
+impl From<DeviceFormFactor> for String {
+    fn from(value: DeviceFormFactor) -> String {
+        match value {
+            DeviceFormFactor::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceFormFactor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeviceFormFactor {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceFormFactor {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeviceFormFactor, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceFormFactor::from(value))
+    }
+}
+
+/// <p>Represents the device instance.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeviceInstance {
+    /// <p>The Amazon Resource Name (ARN) of the device instance.</p>
+    #[serde(rename = "arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The Amazon Resource Name (ARN) of the device.</p>
+    #[serde(rename = "deviceArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_arn: Option<String>,
+    /// <p>A object containing information about the instance profile.</p>
+    #[serde(rename = "instanceProfile")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_profile: Option<InstanceProfile>,
+    /// <p>An array of strings describing the device instance.</p>
+    #[serde(rename = "labels")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+    /// <p>The status of the device instance. Valid values are listed below.</p>
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<InstanceStatus>,
+    /// <p>Unique device identifier for the device instance.</p>
+    #[serde(rename = "udid")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub udid: Option<String>,
+}
+
+/// <p>Represents the total (metered or unmetered) minutes used by the resource to run tests. Contains the sum of minutes consumed by all children.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeviceMinutes {
+    /// <p>When specified, represents only the sum of metered minutes used by the resource to run tests.</p>
+    #[serde(rename = "metered")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metered: Option<f64>,
+    /// <p>When specified, represents the total minutes used by the resource to run tests.</p>
+    #[serde(rename = "total")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<f64>,
+    /// <p>When specified, represents only the sum of unmetered minutes used by the resource to run tests.</p>
+    #[serde(rename = "unmetered")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmetered: Option<f64>,
+}
+
+/// <p>Returns the unmetered devices you have purchased or want to purchase.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DevicePlatform {
+    /// <p><code>ANDROID</code></p>
+    Android,
+    /// <p><code>IOS</code></p>
+    Ios,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DevicePlatform {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DevicePlatform::Android => "ANDROID",
+            DevicePlatform::Ios => "IOS",
+            DevicePlatform::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DevicePlatform {
+    fn from(value: &'a str) -> DevicePlatform {
+        match value {
+            "ANDROID" => DevicePlatform::Android,
+            "IOS" => DevicePlatform::Ios,
+            _ => DevicePlatform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DevicePlatform {
+    fn from(value: String) -> DevicePlatform {
+        DevicePlatform::from(value.as_str())
+    }
+}
+
+impl From<DevicePlatform> for String {
+    fn from(value: DevicePlatform) -> String {
+        match value {
+            DevicePlatform::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DevicePlatform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DevicePlatform {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DevicePlatform {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DevicePlatform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DevicePlatform::from(value))
+    }
+}
+
+/// <p>Represents a collection of device types.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DevicePool {
     /// <p>The device pool's ARN.</p>
     #[serde(rename = "arn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -709,7 +2323,7 @@ pub struct DevicePool {
     /// <p><p>The device pool&#39;s type.</p> <p>Allowed values include:</p> <ul> <li> <p>CURATED: A device pool that is created and managed by AWS Device Farm.</p> </li> <li> <p>PRIVATE: A device pool that is created and managed by the device pool developer.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DevicePoolType>,
 }
 
 /// <p>Represents a device pool compatibility result.</p>
@@ -730,6 +2344,78 @@ pub struct DevicePoolCompatibilityResult {
     pub incompatibility_messages: Option<Vec<IncompatibilityMessage>>,
 }
 
+/// <p><p>The device pool&#39;s type.</p> <p>Allowed values include:</p> <ul> <li> <p>CURATED: A device pool that is created and managed by AWS Device Farm.</p> </li> <li> <p>PRIVATE: A device pool that is created and managed by the device pool developer.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DevicePoolType {
+    /// <p><code>CURATED</code></p>
+    Curated,
+    /// <p><code>PRIVATE</code></p>
+    Private,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DevicePoolType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DevicePoolType::Curated => "CURATED",
+            DevicePoolType::Private => "PRIVATE",
+            DevicePoolType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DevicePoolType {
+    fn from(value: &'a str) -> DevicePoolType {
+        match value {
+            "CURATED" => DevicePoolType::Curated,
+            "PRIVATE" => DevicePoolType::Private,
+            _ => DevicePoolType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DevicePoolType {
+    fn from(value: String) -> DevicePoolType {
+        DevicePoolType::from(value.as_str())
+    }
+}
+
+impl From<DevicePoolType> for String {
+    fn from(value: DevicePoolType) -> String {
+        match value {
+            DevicePoolType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DevicePoolType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DevicePoolType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DevicePoolType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DevicePoolType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DevicePoolType::from(value))
+    }
+}
+
 /// <p>Represents the device filters used in a test run as well as the maximum number of devices to be included in the run. It is passed in as the <code>deviceSelectionConfiguration</code> request parameter in <a>ScheduleRun</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeviceSelectionConfiguration {
@@ -784,93 +2470,479 @@ pub struct ExecutionConfiguration {
     pub video_capture: Option<bool>,
 }
 
-/// <p>Represents the request sent to retrieve the account settings.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetAccountSettingsRequest {}
+/// <p><p>The job&#39;s result.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending condition.</p> </li> <li> <p>PASSED: A passing condition.</p> </li> <li> <p>WARNED: A warning condition.</p> </li> <li> <p>FAILED: A failed condition.</p> </li> <li> <p>SKIPPED: A skipped condition.</p> </li> <li> <p>ERRORED: An error condition.</p> </li> <li> <p>STOPPED: A stopped condition.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExecutionResult {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>PASSED</code></p>
+    Passed,
+    /// <p><code>WARNED</code></p>
+    Warned,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>SKIPPED</code></p>
+    Skipped,
+    /// <p><code>ERRORED</code></p>
+    Errored,
+    /// <p><code>STOPPED</code></p>
+    Stopped,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExecutionResult {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionResult::Pending => "PENDING",
+            ExecutionResult::Passed => "PASSED",
+            ExecutionResult::Warned => "WARNED",
+            ExecutionResult::Failed => "FAILED",
+            ExecutionResult::Skipped => "SKIPPED",
+            ExecutionResult::Errored => "ERRORED",
+            ExecutionResult::Stopped => "STOPPED",
+            ExecutionResult::Unknown(ref value) => value,
+        }
+    }
+}
 
-/// <p>Represents the account settings return values from the <code>GetAccountSettings</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetAccountSettingsResult {
-    /// <p>The account settings.</p>
-    #[serde(rename = "accountSettings")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_settings: Option<AccountSettings>,
+impl<'a> From<&'a str> for ExecutionResult {
+    fn from(value: &'a str) -> ExecutionResult {
+        match value {
+            "PENDING" => ExecutionResult::Pending,
+            "PASSED" => ExecutionResult::Passed,
+            "WARNED" => ExecutionResult::Warned,
+            "FAILED" => ExecutionResult::Failed,
+            "SKIPPED" => ExecutionResult::Skipped,
+            "ERRORED" => ExecutionResult::Errored,
+            "STOPPED" => ExecutionResult::Stopped,
+            _ => ExecutionResult::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetDeviceInstanceRequest {
-    /// <p>The Amazon Resource Name (ARN) of the instance you're requesting information about.</p>
-    #[serde(rename = "arn")]
-    pub arn: String,
+impl From<String> for ExecutionResult {
+    fn from(value: String) -> ExecutionResult {
+        ExecutionResult::from(value.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetDeviceInstanceResult {
-    /// <p>An object containing information about your device instance.</p>
-    #[serde(rename = "deviceInstance")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_instance: Option<DeviceInstance>,
+impl From<ExecutionResult> for String {
+    fn from(value: ExecutionResult) -> String {
+        match value {
+            ExecutionResult::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-/// <p>Represents a request to the get device pool compatibility operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetDevicePoolCompatibilityRequest {
-    /// <p>The ARN of the app that is associated with the specified device pool.</p>
-    #[serde(rename = "appArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub app_arn: Option<String>,
-    /// <p>An object containing information about the settings for a run.</p>
-    #[serde(rename = "configuration")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration: Option<ScheduleRunConfiguration>,
-    /// <p>The device pool's ARN.</p>
-    #[serde(rename = "devicePoolArn")]
-    pub device_pool_arn: String,
-    /// <p>Information about the uploaded test to be run against the device pool.</p>
-    #[serde(rename = "test")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub test: Option<ScheduleRunTest>,
-    /// <p><p>The test type for the specified device pool.</p> <p>Allowed values include the following:</p> <ul> <li> <p>BUILTIN<em>FUZZ: The built-in fuzz type.</p> </li> <li> <p>BUILTIN</em>EXPLORER: For Android, an app explorer that will traverse an Android app, interacting with it and capturing screenshots at the same time.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT: The Appium Java JUnit type.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG: The Appium Java TestNG type.</p> </li> <li> <p>APPIUM<em>PYTHON: The Appium Python type.</p> </li> <li> <p>APPIUM</em>NODE: The Appium Node.js type.</p> </li> <li> <p>APPIUM<em>RUBY: The Appium Ruby type.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT: The Appium Java JUnit type for web apps.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG: The Appium Java TestNG type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON: The Appium Python type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE: The Appium Node.js type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY: The Appium Ruby type for web apps.</p> </li> <li> <p>CALABASH: The Calabash type.</p> </li> <li> <p>INSTRUMENTATION: The Instrumentation type.</p> </li> <li> <p>UIAUTOMATION: The uiautomation type.</p> </li> <li> <p>UIAUTOMATOR: The uiautomator type.</p> </li> <li> <p>XCTEST: The Xcode test type.</p> </li> <li> <p>XCTEST</em>UI: The Xcode UI test type.</p> </li> </ul></p>
-    #[serde(rename = "testType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub test_type: Option<String>,
+impl fmt::Display for ExecutionResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
-/// <p>Represents the result of describe device pool compatibility request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetDevicePoolCompatibilityResult {
-    /// <p>Information about compatible devices.</p>
-    #[serde(rename = "compatibleDevices")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_devices: Option<Vec<DevicePoolCompatibilityResult>>,
-    /// <p>Information about incompatible devices.</p>
-    #[serde(rename = "incompatibleDevices")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub incompatible_devices: Option<Vec<DevicePoolCompatibilityResult>>,
+impl ::serde::Serialize for ExecutionResult {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-/// <p>Represents a request to the get device pool operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetDevicePoolRequest {
-    /// <p>The device pool's ARN.</p>
-    #[serde(rename = "arn")]
-    pub arn: String,
+impl<'de> ::serde::Deserialize<'de> for ExecutionResult {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExecutionResult, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExecutionResult::from(value))
+    }
 }
 
-/// <p>Represents the result of a get device pool request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetDevicePoolResult {
-    /// <p>An object containing information about the requested device pool.</p>
-    #[serde(rename = "devicePool")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_pool: Option<DevicePool>,
+/// <p>Supporting field for the result field. Set only if <code>result</code> is <code>SKIPPED</code>. <code>PARSING_FAILED</code> if the result is skipped because of test package parsing failure.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExecutionResultCode {
+    /// <p><code>PARSING_FAILED</code></p>
+    ParsingFailed,
+    /// <p><code>VPC_ENDPOINT_SETUP_FAILED</code></p>
+    VpcEndpointSetupFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Represents a request to the get device request.</p>
+impl ExecutionResultCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionResultCode::ParsingFailed => "PARSING_FAILED",
+            ExecutionResultCode::VpcEndpointSetupFailed => "VPC_ENDPOINT_SETUP_FAILED",
+            ExecutionResultCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExecutionResultCode {
+    fn from(value: &'a str) -> ExecutionResultCode {
+        match value {
+            "PARSING_FAILED" => ExecutionResultCode::ParsingFailed,
+            "VPC_ENDPOINT_SETUP_FAILED" => ExecutionResultCode::VpcEndpointSetupFailed,
+            _ => ExecutionResultCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExecutionResultCode {
+    fn from(value: String) -> ExecutionResultCode {
+        ExecutionResultCode::from(value.as_str())
+    }
+}
+
+impl From<ExecutionResultCode> for String {
+    fn from(value: ExecutionResultCode) -> String {
+        match value {
+            ExecutionResultCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExecutionResultCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExecutionResultCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExecutionResultCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExecutionResultCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExecutionResultCode::from(value))
+    }
+}
+
+/// <p><p>The job&#39;s status.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending status.</p> </li> <li> <p>PENDING<em>CONCURRENCY: A pending concurrency status.</p> </li> <li> <p>PENDING</em>DEVICE: A pending device status.</p> </li> <li> <p>PROCESSING: A processing status.</p> </li> <li> <p>SCHEDULING: A scheduling status.</p> </li> <li> <p>PREPARING: A preparing status.</p> </li> <li> <p>RUNNING: A running status.</p> </li> <li> <p>COMPLETED: A completed status.</p> </li> <li> <p>STOPPING: A stopping status.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExecutionStatus {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>PENDING_CONCURRENCY</code></p>
+    PendingConcurrency,
+    /// <p><code>PENDING_DEVICE</code></p>
+    PendingDevice,
+    /// <p><code>PROCESSING</code></p>
+    Processing,
+    /// <p><code>SCHEDULING</code></p>
+    Scheduling,
+    /// <p><code>PREPARING</code></p>
+    Preparing,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>STOPPING</code></p>
+    Stopping,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExecutionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExecutionStatus::Pending => "PENDING",
+            ExecutionStatus::PendingConcurrency => "PENDING_CONCURRENCY",
+            ExecutionStatus::PendingDevice => "PENDING_DEVICE",
+            ExecutionStatus::Processing => "PROCESSING",
+            ExecutionStatus::Scheduling => "SCHEDULING",
+            ExecutionStatus::Preparing => "PREPARING",
+            ExecutionStatus::Running => "RUNNING",
+            ExecutionStatus::Completed => "COMPLETED",
+            ExecutionStatus::Stopping => "STOPPING",
+            ExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExecutionStatus {
+    fn from(value: &'a str) -> ExecutionStatus {
+        match value {
+            "PENDING" => ExecutionStatus::Pending,
+            "PENDING_CONCURRENCY" => ExecutionStatus::PendingConcurrency,
+            "PENDING_DEVICE" => ExecutionStatus::PendingDevice,
+            "PROCESSING" => ExecutionStatus::Processing,
+            "SCHEDULING" => ExecutionStatus::Scheduling,
+            "PREPARING" => ExecutionStatus::Preparing,
+            "RUNNING" => ExecutionStatus::Running,
+            "COMPLETED" => ExecutionStatus::Completed,
+            "STOPPING" => ExecutionStatus::Stopping,
+            _ => ExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExecutionStatus {
+    fn from(value: String) -> ExecutionStatus {
+        ExecutionStatus::from(value.as_str())
+    }
+}
+
+impl From<ExecutionStatus> for String {
+    fn from(value: ExecutionStatus) -> String {
+        match value {
+            ExecutionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExecutionStatus::from(value))
+    }
+}
+
+/// <p>Represents the request sent to retrieve the account settings.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetAccountSettingsRequest {}
+
+/// <p>Represents the account settings return values from the <code>GetAccountSettings</code> request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetAccountSettingsResult {
+    /// <p>The account settings.</p>
+    #[serde(rename = "accountSettings")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_settings: Option<AccountSettings>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetDeviceInstanceRequest {
+    /// <p>The Amazon Resource Name (ARN) of the instance you're requesting information about.</p>
+    #[serde(rename = "arn")]
+    pub arn: String,
+}
+
+impl GetDeviceInstanceRequest {
+    /// Returns a builder for `GetDeviceInstanceRequest`, checking its required members when built.
+    pub fn builder() -> GetDeviceInstanceRequestBuilder {
+        GetDeviceInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeviceInstanceRequest`](struct.GetDeviceInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDeviceInstanceRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetDeviceInstanceRequestBuilder {
+    /// Sets the [`arn`](struct.GetDeviceInstanceRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeviceInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDeviceInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(GetDeviceInstanceRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetDeviceInstanceResult {
+    /// <p>An object containing information about your device instance.</p>
+    #[serde(rename = "deviceInstance")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_instance: Option<DeviceInstance>,
+}
+
+/// <p>Represents a request to the get device pool compatibility operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetDevicePoolCompatibilityRequest {
+    /// <p>The ARN of the app that is associated with the specified device pool.</p>
+    #[serde(rename = "appArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_arn: Option<String>,
+    /// <p>An object containing information about the settings for a run.</p>
+    #[serde(rename = "configuration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<ScheduleRunConfiguration>,
+    /// <p>The device pool's ARN.</p>
+    #[serde(rename = "devicePoolArn")]
+    pub device_pool_arn: String,
+    /// <p>Information about the uploaded test to be run against the device pool.</p>
+    #[serde(rename = "test")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test: Option<ScheduleRunTest>,
+    /// <p><p>The test type for the specified device pool.</p> <p>Allowed values include the following:</p> <ul> <li> <p>BUILTIN<em>FUZZ: The built-in fuzz type.</p> </li> <li> <p>BUILTIN</em>EXPLORER: For Android, an app explorer that will traverse an Android app, interacting with it and capturing screenshots at the same time.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT: The Appium Java JUnit type.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG: The Appium Java TestNG type.</p> </li> <li> <p>APPIUM<em>PYTHON: The Appium Python type.</p> </li> <li> <p>APPIUM</em>NODE: The Appium Node.js type.</p> </li> <li> <p>APPIUM<em>RUBY: The Appium Ruby type.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT: The Appium Java JUnit type for web apps.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG: The Appium Java TestNG type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON: The Appium Python type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE: The Appium Node.js type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY: The Appium Ruby type for web apps.</p> </li> <li> <p>CALABASH: The Calabash type.</p> </li> <li> <p>INSTRUMENTATION: The Instrumentation type.</p> </li> <li> <p>UIAUTOMATION: The uiautomation type.</p> </li> <li> <p>UIAUTOMATOR: The uiautomator type.</p> </li> <li> <p>XCTEST: The Xcode test type.</p> </li> <li> <p>XCTEST</em>UI: The Xcode UI test type.</p> </li> </ul></p>
+    #[serde(rename = "testType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_type: Option<TestType>,
+}
+
+impl GetDevicePoolCompatibilityRequest {
+    /// Returns a builder for `GetDevicePoolCompatibilityRequest`, checking its required members when built.
+    pub fn builder() -> GetDevicePoolCompatibilityRequestBuilder {
+        GetDevicePoolCompatibilityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDevicePoolCompatibilityRequest`](struct.GetDevicePoolCompatibilityRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDevicePoolCompatibilityRequestBuilder {
+    app_arn: Option<String>,
+    configuration: Option<ScheduleRunConfiguration>,
+    device_pool_arn: Option<String>,
+    test: Option<ScheduleRunTest>,
+    test_type: Option<TestType>,
+}
+
+impl GetDevicePoolCompatibilityRequestBuilder {
+    /// Sets the [`app_arn`](struct.GetDevicePoolCompatibilityRequest.html#structfield.app_arn) member.
+    pub fn app_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration`](struct.GetDevicePoolCompatibilityRequest.html#structfield.configuration) member.
+    pub fn configuration<T: Into<ScheduleRunConfiguration>>(mut self, value: T) -> Self {
+        self.configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_pool_arn`](struct.GetDevicePoolCompatibilityRequest.html#structfield.device_pool_arn) member.
+    pub fn device_pool_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_pool_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`test`](struct.GetDevicePoolCompatibilityRequest.html#structfield.test) member.
+    pub fn test<T: Into<ScheduleRunTest>>(mut self, value: T) -> Self {
+        self.test = Some(value.into());
+        self
+    }
+
+    /// Sets the [`test_type`](struct.GetDevicePoolCompatibilityRequest.html#structfield.test_type) member.
+    pub fn test_type<T: Into<TestType>>(mut self, value: T) -> Self {
+        self.test_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDevicePoolCompatibilityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDevicePoolCompatibilityRequest, ::rusoto_core::BuildError> {
+        Ok(GetDevicePoolCompatibilityRequest {
+            app_arn: self.app_arn,
+            configuration: self.configuration,
+            device_pool_arn: self
+                .device_pool_arn
+                .ok_or(::rusoto_core::BuildError::missing("device_pool_arn"))?,
+            test: self.test,
+            test_type: self.test_type,
+        })
+    }
+}
+
+/// <p>Represents the result of describe device pool compatibility request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetDevicePoolCompatibilityResult {
+    /// <p>Information about compatible devices.</p>
+    #[serde(rename = "compatibleDevices")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compatible_devices: Option<Vec<DevicePoolCompatibilityResult>>,
+    /// <p>Information about incompatible devices.</p>
+    #[serde(rename = "incompatibleDevices")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub incompatible_devices: Option<Vec<DevicePoolCompatibilityResult>>,
+}
+
+/// <p>Represents a request to the get device pool operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetDevicePoolRequest {
+    /// <p>The device pool's ARN.</p>
+    #[serde(rename = "arn")]
+    pub arn: String,
+}
+
+impl GetDevicePoolRequest {
+    /// Returns a builder for `GetDevicePoolRequest`, checking its required members when built.
+    pub fn builder() -> GetDevicePoolRequestBuilder {
+        GetDevicePoolRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDevicePoolRequest`](struct.GetDevicePoolRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDevicePoolRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetDevicePoolRequestBuilder {
+    /// Sets the [`arn`](struct.GetDevicePoolRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDevicePoolRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDevicePoolRequest, ::rusoto_core::BuildError> {
+        Ok(GetDevicePoolRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
+/// <p>Represents the result of a get device pool request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetDevicePoolResult {
+    /// <p>An object containing information about the requested device pool.</p>
+    #[serde(rename = "devicePool")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_pool: Option<DevicePool>,
+}
+
+/// <p>Represents a request to the get device request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetDeviceRequest {
     /// <p>The device type's ARN.</p>
@@ -878,6 +2950,34 @@ pub struct GetDeviceRequest {
     pub arn: String,
 }
 
+impl GetDeviceRequest {
+    /// Returns a builder for `GetDeviceRequest`, checking its required members when built.
+    pub fn builder() -> GetDeviceRequestBuilder {
+        GetDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeviceRequest`](struct.GetDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDeviceRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetDeviceRequestBuilder {
+    /// Sets the [`arn`](struct.GetDeviceRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(GetDeviceRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the result of a get device request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -895,6 +2995,36 @@ pub struct GetInstanceProfileRequest {
     pub arn: String,
 }
 
+impl GetInstanceProfileRequest {
+    /// Returns a builder for `GetInstanceProfileRequest`, checking its required members when built.
+    pub fn builder() -> GetInstanceProfileRequestBuilder {
+        GetInstanceProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInstanceProfileRequest`](struct.GetInstanceProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInstanceProfileRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetInstanceProfileRequestBuilder {
+    /// Sets the [`arn`](struct.GetInstanceProfileRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInstanceProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetInstanceProfileRequest, ::rusoto_core::BuildError> {
+        Ok(GetInstanceProfileRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetInstanceProfileResult {
@@ -912,6 +3042,34 @@ pub struct GetJobRequest {
     pub arn: String,
 }
 
+impl GetJobRequest {
+    /// Returns a builder for `GetJobRequest`, checking its required members when built.
+    pub fn builder() -> GetJobRequestBuilder {
+        GetJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetJobRequest`](struct.GetJobRequest.html).
+#[derive(Default, Debug)]
+pub struct GetJobRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetJobRequestBuilder {
+    /// Sets the [`arn`](struct.GetJobRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetJobRequest, ::rusoto_core::BuildError> {
+        Ok(GetJobRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the result of a get job request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -929,6 +3087,36 @@ pub struct GetNetworkProfileRequest {
     pub arn: String,
 }
 
+impl GetNetworkProfileRequest {
+    /// Returns a builder for `GetNetworkProfileRequest`, checking its required members when built.
+    pub fn builder() -> GetNetworkProfileRequestBuilder {
+        GetNetworkProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetNetworkProfileRequest`](struct.GetNetworkProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct GetNetworkProfileRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetNetworkProfileRequestBuilder {
+    /// Sets the [`arn`](struct.GetNetworkProfileRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetNetworkProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetNetworkProfileRequest, ::rusoto_core::BuildError> {
+        Ok(GetNetworkProfileRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetNetworkProfileResult {
@@ -947,6 +3135,36 @@ pub struct GetOfferingStatusRequest {
     pub next_token: Option<String>,
 }
 
+impl GetOfferingStatusRequest {
+    /// Returns a builder for `GetOfferingStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetOfferingStatusRequestBuilder {
+        GetOfferingStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetOfferingStatusRequest`](struct.GetOfferingStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetOfferingStatusRequestBuilder {
+    next_token: Option<String>,
+}
+
+impl GetOfferingStatusRequestBuilder {
+    /// Sets the [`next_token`](struct.GetOfferingStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOfferingStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetOfferingStatusRequest, ::rusoto_core::BuildError> {
+        Ok(GetOfferingStatusRequest {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Returns the status result for a device offering.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -973,6 +3191,34 @@ pub struct GetProjectRequest {
     pub arn: String,
 }
 
+impl GetProjectRequest {
+    /// Returns a builder for `GetProjectRequest`, checking its required members when built.
+    pub fn builder() -> GetProjectRequestBuilder {
+        GetProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetProjectRequest`](struct.GetProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct GetProjectRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetProjectRequestBuilder {
+    /// Sets the [`arn`](struct.GetProjectRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetProjectRequest, ::rusoto_core::BuildError> {
+        Ok(GetProjectRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the result of a get project request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -991,12 +3237,42 @@ pub struct GetRemoteAccessSessionRequest {
     pub arn: String,
 }
 
-/// <p>Represents the response from the server that lists detailed information about the remote access session.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetRemoteAccessSessionResult {
-    /// <p>A container that lists detailed information about the remote access session.</p>
-    #[serde(rename = "remoteAccessSession")]
+impl GetRemoteAccessSessionRequest {
+    /// Returns a builder for `GetRemoteAccessSessionRequest`, checking its required members when built.
+    pub fn builder() -> GetRemoteAccessSessionRequestBuilder {
+        GetRemoteAccessSessionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRemoteAccessSessionRequest`](struct.GetRemoteAccessSessionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRemoteAccessSessionRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetRemoteAccessSessionRequestBuilder {
+    /// Sets the [`arn`](struct.GetRemoteAccessSessionRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRemoteAccessSessionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRemoteAccessSessionRequest, ::rusoto_core::BuildError> {
+        Ok(GetRemoteAccessSessionRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
+/// <p>Represents the response from the server that lists detailed information about the remote access session.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetRemoteAccessSessionResult {
+    /// <p>A container that lists detailed information about the remote access session.</p>
+    #[serde(rename = "remoteAccessSession")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remote_access_session: Option<RemoteAccessSession>,
 }
@@ -1009,6 +3285,34 @@ pub struct GetRunRequest {
     pub arn: String,
 }
 
+impl GetRunRequest {
+    /// Returns a builder for `GetRunRequest`, checking its required members when built.
+    pub fn builder() -> GetRunRequestBuilder {
+        GetRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRunRequest`](struct.GetRunRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRunRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetRunRequestBuilder {
+    /// Sets the [`arn`](struct.GetRunRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRunRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRunRequest, ::rusoto_core::BuildError> {
+        Ok(GetRunRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the result of a get run request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1027,6 +3331,34 @@ pub struct GetSuiteRequest {
     pub arn: String,
 }
 
+impl GetSuiteRequest {
+    /// Returns a builder for `GetSuiteRequest`, checking its required members when built.
+    pub fn builder() -> GetSuiteRequestBuilder {
+        GetSuiteRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSuiteRequest`](struct.GetSuiteRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSuiteRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetSuiteRequestBuilder {
+    /// Sets the [`arn`](struct.GetSuiteRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSuiteRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSuiteRequest, ::rusoto_core::BuildError> {
+        Ok(GetSuiteRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the result of a get suite request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1045,6 +3377,34 @@ pub struct GetTestRequest {
     pub arn: String,
 }
 
+impl GetTestRequest {
+    /// Returns a builder for `GetTestRequest`, checking its required members when built.
+    pub fn builder() -> GetTestRequestBuilder {
+        GetTestRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTestRequest`](struct.GetTestRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTestRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetTestRequestBuilder {
+    /// Sets the [`arn`](struct.GetTestRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTestRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTestRequest, ::rusoto_core::BuildError> {
+        Ok(GetTestRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the result of a get test request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1063,6 +3423,34 @@ pub struct GetUploadRequest {
     pub arn: String,
 }
 
+impl GetUploadRequest {
+    /// Returns a builder for `GetUploadRequest`, checking its required members when built.
+    pub fn builder() -> GetUploadRequestBuilder {
+        GetUploadRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUploadRequest`](struct.GetUploadRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUploadRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetUploadRequestBuilder {
+    /// Sets the [`arn`](struct.GetUploadRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUploadRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetUploadRequest, ::rusoto_core::BuildError> {
+        Ok(GetUploadRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the result of a get upload request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1080,6 +3468,36 @@ pub struct GetVPCEConfigurationRequest {
     pub arn: String,
 }
 
+impl GetVPCEConfigurationRequest {
+    /// Returns a builder for `GetVPCEConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> GetVPCEConfigurationRequestBuilder {
+        GetVPCEConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetVPCEConfigurationRequest`](struct.GetVPCEConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetVPCEConfigurationRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetVPCEConfigurationRequestBuilder {
+    /// Sets the [`arn`](struct.GetVPCEConfigurationRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetVPCEConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetVPCEConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(GetVPCEConfigurationRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetVPCEConfigurationResult {
@@ -1100,7 +3518,7 @@ pub struct IncompatibilityMessage {
     /// <p><p>The type of incompatibility.</p> <p>Allowed values include:</p> <ul> <li> <p>ARN: The ARN.</p> </li> <li> <p>FORM<em>FACTOR: The form factor (for example, phone or tablet).</p> </li> <li> <p>MANUFACTURER: The manufacturer.</p> </li> <li> <p>PLATFORM: The platform (for example, Android or iOS).</p> </li> <li> <p>REMOTE</em>ACCESS<em>ENABLED: Whether the device is enabled for remote access.</p> </li> <li> <p>APPIUM</em>VERSION: The Appium version for the test.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DeviceAttribute>,
 }
 
 /// <p>Represents the request to install an Android application (in .apk format) or an iOS application (in .ipa format) as part of a remote access session.</p>
@@ -1114,6 +3532,48 @@ pub struct InstallToRemoteAccessSessionRequest {
     pub remote_access_session_arn: String,
 }
 
+impl InstallToRemoteAccessSessionRequest {
+    /// Returns a builder for `InstallToRemoteAccessSessionRequest`, checking its required members when built.
+    pub fn builder() -> InstallToRemoteAccessSessionRequestBuilder {
+        InstallToRemoteAccessSessionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InstallToRemoteAccessSessionRequest`](struct.InstallToRemoteAccessSessionRequest.html).
+#[derive(Default, Debug)]
+pub struct InstallToRemoteAccessSessionRequestBuilder {
+    app_arn: Option<String>,
+    remote_access_session_arn: Option<String>,
+}
+
+impl InstallToRemoteAccessSessionRequestBuilder {
+    /// Sets the [`app_arn`](struct.InstallToRemoteAccessSessionRequest.html#structfield.app_arn) member.
+    pub fn app_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remote_access_session_arn`](struct.InstallToRemoteAccessSessionRequest.html#structfield.remote_access_session_arn) member.
+    pub fn remote_access_session_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.remote_access_session_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `InstallToRemoteAccessSessionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<InstallToRemoteAccessSessionRequest, ::rusoto_core::BuildError> {
+        Ok(InstallToRemoteAccessSessionRequest {
+            app_arn: self
+                .app_arn
+                .ok_or(::rusoto_core::BuildError::missing("app_arn"))?,
+            remote_access_session_arn: self.remote_access_session_arn.ok_or(
+                ::rusoto_core::BuildError::missing("remote_access_session_arn"),
+            )?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server after AWS Device Farm makes a request to install to a remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1154,6 +3614,162 @@ pub struct InstanceProfile {
     pub reboot_after_use: Option<bool>,
 }
 
+/// <p>The status of the device instance. Valid values are listed below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceStatus {
+    /// <p><code>IN_USE</code></p>
+    InUse,
+    /// <p><code>PREPARING</code></p>
+    Preparing,
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>NOT_AVAILABLE</code></p>
+    NotAvailable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceStatus::InUse => "IN_USE",
+            InstanceStatus::Preparing => "PREPARING",
+            InstanceStatus::Available => "AVAILABLE",
+            InstanceStatus::NotAvailable => "NOT_AVAILABLE",
+            InstanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceStatus {
+    fn from(value: &'a str) -> InstanceStatus {
+        match value {
+            "IN_USE" => InstanceStatus::InUse,
+            "PREPARING" => InstanceStatus::Preparing,
+            "AVAILABLE" => InstanceStatus::Available,
+            "NOT_AVAILABLE" => InstanceStatus::NotAvailable,
+            _ => InstanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceStatus {
+    fn from(value: String) -> InstanceStatus {
+        InstanceStatus::from(value.as_str())
+    }
+}
+
+impl From<InstanceStatus> for String {
+    fn from(value: InstanceStatus) -> String {
+        match value {
+            InstanceStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceStatus::from(value))
+    }
+}
+
+/// <p><p>The interaction mode of the remote access session. Valid values are:</p> <ul> <li> <p>INTERACTIVE: You can interact with the iOS device by viewing, touching, and rotating the screen. You <b>cannot</b> run XCUITest framework-based tests in this mode.</p> </li> <li> <p>NO<em>VIDEO: You are connected to the device but cannot interact with it or view the screen. This mode has the fastest test execution speed. You <b>can</b> run XCUITest framework-based tests in this mode.</p> </li> <li> <p>VIDEO</em>ONLY: You can view the screen but cannot touch or rotate it. You <b>can</b> run XCUITest framework-based tests and watch the screen in this mode.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InteractionMode {
+    /// <p><code>INTERACTIVE</code></p>
+    Interactive,
+    /// <p><code>NO_VIDEO</code></p>
+    NoVideo,
+    /// <p><code>VIDEO_ONLY</code></p>
+    VideoOnly,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InteractionMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InteractionMode::Interactive => "INTERACTIVE",
+            InteractionMode::NoVideo => "NO_VIDEO",
+            InteractionMode::VideoOnly => "VIDEO_ONLY",
+            InteractionMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InteractionMode {
+    fn from(value: &'a str) -> InteractionMode {
+        match value {
+            "INTERACTIVE" => InteractionMode::Interactive,
+            "NO_VIDEO" => InteractionMode::NoVideo,
+            "VIDEO_ONLY" => InteractionMode::VideoOnly,
+            _ => InteractionMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InteractionMode {
+    fn from(value: String) -> InteractionMode {
+        InteractionMode::from(value.as_str())
+    }
+}
+
+impl From<InteractionMode> for String {
+    fn from(value: InteractionMode) -> String {
+        match value {
+            InteractionMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InteractionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InteractionMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InteractionMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InteractionMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InteractionMode::from(value))
+    }
+}
+
 /// <p>Represents a device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1168,8 +3784,9 @@ pub struct Job {
     pub counters: Option<Counters>,
     /// <p>When the job was created.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>The device (phone or tablet).</p>
     #[serde(rename = "device")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1193,23 +3810,25 @@ pub struct Job {
     /// <p><p>The job&#39;s result.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending condition.</p> </li> <li> <p>PASSED: A passing condition.</p> </li> <li> <p>WARNED: A warning condition.</p> </li> <li> <p>FAILED: A failed condition.</p> </li> <li> <p>SKIPPED: A skipped condition.</p> </li> <li> <p>ERRORED: An error condition.</p> </li> <li> <p>STOPPED: A stopped condition.</p> </li> </ul></p>
     #[serde(rename = "result")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
+    pub result: Option<ExecutionResult>,
     /// <p>The job's start time.</p>
     #[serde(rename = "started")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started: Option<f64>,
+    pub started: Option<EpochTimestamp>,
     /// <p><p>The job&#39;s status.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending status.</p> </li> <li> <p>PENDING<em>CONCURRENCY: A pending concurrency status.</p> </li> <li> <p>PENDING</em>DEVICE: A pending device status.</p> </li> <li> <p>PROCESSING: A processing status.</p> </li> <li> <p>SCHEDULING: A scheduling status.</p> </li> <li> <p>PREPARING: A preparing status.</p> </li> <li> <p>RUNNING: A running status.</p> </li> <li> <p>COMPLETED: A completed status.</p> </li> <li> <p>STOPPING: A stopping status.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ExecutionStatus>,
     /// <p>The job's stop time.</p>
     #[serde(rename = "stopped")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped: Option<f64>,
+    pub stopped: Option<EpochTimestamp>,
     /// <p><p>The job&#39;s type.</p> <p>Allowed values include the following:</p> <ul> <li> <p>BUILTIN<em>FUZZ: The built-in fuzz type.</p> </li> <li> <p>BUILTIN</em>EXPLORER: For Android, an app explorer that will traverse an Android app, interacting with it and capturing screenshots at the same time.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT: The Appium Java JUnit type.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG: The Appium Java TestNG type.</p> </li> <li> <p>APPIUM<em>PYTHON: The Appium Python type.</p> </li> <li> <p>APPIUM</em>NODE: The Appium Node.js type.</p> </li> <li> <p>APPIUM<em>RUBY: The Appium Ruby type.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT: The Appium Java JUnit type for web apps.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG: The Appium Java TestNG type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON: The Appium Python type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE: The Appium Node.js type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY: The Appium Ruby test type for web apps.</p> </li> <li> <p>CALABASH: The Calabash type.</p> </li> <li> <p>INSTRUMENTATION: The Instrumentation type.</p> </li> <li> <p>UIAUTOMATION: The uiautomation type.</p> </li> <li> <p>UIAUTOMATOR: The uiautomator type.</p> </li> <li> <p>XCTEST: The Xcode test type.</p> </li> <li> <p>XCTEST</em>UI: The Xcode UI test type.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TestType>,
     /// <p>This value is set to true if video capture is enabled; otherwise, it is set to false.</p>
     #[serde(rename = "videoCapture")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1221,7 +3840,7 @@ pub struct Job {
 }
 
 /// <p>Represents a request to the list artifacts operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListArtifactsRequest {
     /// <p>The Run, Job, Suite, or Test ARN.</p>
     #[serde(rename = "arn")]
@@ -1232,7 +3851,53 @@ pub struct ListArtifactsRequest {
     pub next_token: Option<String>,
     /// <p><p>The artifacts&#39; type.</p> <p>Allowed values include:</p> <ul> <li> <p>FILE: The artifacts are files.</p> </li> <li> <p>LOG: The artifacts are logs.</p> </li> <li> <p>SCREENSHOT: The artifacts are screenshots.</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ArtifactCategory,
+}
+
+impl ListArtifactsRequest {
+    /// Returns a builder for `ListArtifactsRequest`, checking its required members when built.
+    pub fn builder() -> ListArtifactsRequestBuilder {
+        ListArtifactsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListArtifactsRequest`](struct.ListArtifactsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListArtifactsRequestBuilder {
+    arn: Option<String>,
+    next_token: Option<String>,
+    type_: Option<ArtifactCategory>,
+}
+
+impl ListArtifactsRequestBuilder {
+    /// Sets the [`arn`](struct.ListArtifactsRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListArtifactsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.ListArtifactsRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<ArtifactCategory>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListArtifactsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListArtifactsRequest, ::rusoto_core::BuildError> {
+        Ok(ListArtifactsRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            next_token: self.next_token,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 /// <p>Represents the result of a list artifacts operation.</p>
@@ -1261,6 +3926,44 @@ pub struct ListDeviceInstancesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDeviceInstancesRequest {
+    /// Returns a builder for `ListDeviceInstancesRequest`, checking its required members when built.
+    pub fn builder() -> ListDeviceInstancesRequestBuilder {
+        ListDeviceInstancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeviceInstancesRequest`](struct.ListDeviceInstancesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDeviceInstancesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDeviceInstancesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListDeviceInstancesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDeviceInstancesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeviceInstancesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDeviceInstancesRequest, ::rusoto_core::BuildError> {
+        Ok(ListDeviceInstancesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDeviceInstancesResult {
@@ -1287,7 +3990,51 @@ pub struct ListDevicePoolsRequest {
     /// <p><p>The device pools&#39; type.</p> <p>Allowed values include:</p> <ul> <li> <p>CURATED: A device pool that is created and managed by AWS Device Farm.</p> </li> <li> <p>PRIVATE: A device pool that is created and managed by the device pool developer.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DevicePoolType>,
+}
+
+impl ListDevicePoolsRequest {
+    /// Returns a builder for `ListDevicePoolsRequest`, checking its required members when built.
+    pub fn builder() -> ListDevicePoolsRequestBuilder {
+        ListDevicePoolsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDevicePoolsRequest`](struct.ListDevicePoolsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDevicePoolsRequestBuilder {
+    arn: Option<String>,
+    next_token: Option<String>,
+    type_: Option<DevicePoolType>,
+}
+
+impl ListDevicePoolsRequestBuilder {
+    /// Sets the [`arn`](struct.ListDevicePoolsRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDevicePoolsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.ListDevicePoolsRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<DevicePoolType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDevicePoolsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDevicePoolsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDevicePoolsRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            next_token: self.next_token,
+            type_: self.type_,
+        })
+    }
 }
 
 /// <p>Represents the result of a list device pools request.</p>
@@ -1321,6 +4068,50 @@ pub struct ListDevicesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDevicesRequest {
+    /// Returns a builder for `ListDevicesRequest`, checking its required members when built.
+    pub fn builder() -> ListDevicesRequestBuilder {
+        ListDevicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDevicesRequest`](struct.ListDevicesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDevicesRequestBuilder {
+    arn: Option<String>,
+    filters: Option<Vec<DeviceFilter>>,
+    next_token: Option<String>,
+}
+
+impl ListDevicesRequestBuilder {
+    /// Sets the [`arn`](struct.ListDevicesRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.ListDevicesRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<DeviceFilter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDevicesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDevicesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDevicesRequest, ::rusoto_core::BuildError> {
+        Ok(ListDevicesRequest {
+            arn: self.arn,
+            filters: self.filters,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the result of a list devices operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1347,6 +4138,44 @@ pub struct ListInstanceProfilesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListInstanceProfilesRequest {
+    /// Returns a builder for `ListInstanceProfilesRequest`, checking its required members when built.
+    pub fn builder() -> ListInstanceProfilesRequestBuilder {
+        ListInstanceProfilesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListInstanceProfilesRequest`](struct.ListInstanceProfilesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListInstanceProfilesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListInstanceProfilesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListInstanceProfilesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListInstanceProfilesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListInstanceProfilesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListInstanceProfilesRequest, ::rusoto_core::BuildError> {
+        Ok(ListInstanceProfilesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListInstanceProfilesResult {
@@ -1372,6 +4201,42 @@ pub struct ListJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListJobsRequest {
+    /// Returns a builder for `ListJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListJobsRequestBuilder {
+        ListJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListJobsRequest`](struct.ListJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListJobsRequestBuilder {
+    arn: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListJobsRequestBuilder {
+    /// Sets the [`arn`](struct.ListJobsRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListJobsRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the result of a list jobs request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1398,7 +4263,53 @@ pub struct ListNetworkProfilesRequest {
     /// <p>The type of network profile you wish to return information about. Valid values are listed below.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NetworkProfileType>,
+}
+
+impl ListNetworkProfilesRequest {
+    /// Returns a builder for `ListNetworkProfilesRequest`, checking its required members when built.
+    pub fn builder() -> ListNetworkProfilesRequestBuilder {
+        ListNetworkProfilesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListNetworkProfilesRequest`](struct.ListNetworkProfilesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListNetworkProfilesRequestBuilder {
+    arn: Option<String>,
+    next_token: Option<String>,
+    type_: Option<NetworkProfileType>,
+}
+
+impl ListNetworkProfilesRequestBuilder {
+    /// Sets the [`arn`](struct.ListNetworkProfilesRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListNetworkProfilesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.ListNetworkProfilesRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<NetworkProfileType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListNetworkProfilesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListNetworkProfilesRequest, ::rusoto_core::BuildError> {
+        Ok(ListNetworkProfilesRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            next_token: self.next_token,
+            type_: self.type_,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1422,15 +4333,45 @@ pub struct ListOfferingPromotionsRequest {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListOfferingPromotionsResult {
-    /// <p>An identifier to be used in the next call to this operation, to return the next set of items in the list.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>Information about the offering promotions.</p>
-    #[serde(rename = "offeringPromotions")]
+impl ListOfferingPromotionsRequest {
+    /// Returns a builder for `ListOfferingPromotionsRequest`, checking its required members when built.
+    pub fn builder() -> ListOfferingPromotionsRequestBuilder {
+        ListOfferingPromotionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListOfferingPromotionsRequest`](struct.ListOfferingPromotionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListOfferingPromotionsRequestBuilder {
+    next_token: Option<String>,
+}
+
+impl ListOfferingPromotionsRequestBuilder {
+    /// Sets the [`next_token`](struct.ListOfferingPromotionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOfferingPromotionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListOfferingPromotionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListOfferingPromotionsRequest {
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListOfferingPromotionsResult {
+    /// <p>An identifier to be used in the next call to this operation, to return the next set of items in the list.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>Information about the offering promotions.</p>
+    #[serde(rename = "offeringPromotions")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offering_promotions: Option<Vec<OfferingPromotion>>,
 }
@@ -1444,6 +4385,36 @@ pub struct ListOfferingTransactionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListOfferingTransactionsRequest {
+    /// Returns a builder for `ListOfferingTransactionsRequest`, checking its required members when built.
+    pub fn builder() -> ListOfferingTransactionsRequestBuilder {
+        ListOfferingTransactionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListOfferingTransactionsRequest`](struct.ListOfferingTransactionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListOfferingTransactionsRequestBuilder {
+    next_token: Option<String>,
+}
+
+impl ListOfferingTransactionsRequestBuilder {
+    /// Sets the [`next_token`](struct.ListOfferingTransactionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOfferingTransactionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListOfferingTransactionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListOfferingTransactionsRequest {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Returns the transaction log of the specified offerings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1467,6 +4438,34 @@ pub struct ListOfferingsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListOfferingsRequest {
+    /// Returns a builder for `ListOfferingsRequest`, checking its required members when built.
+    pub fn builder() -> ListOfferingsRequestBuilder {
+        ListOfferingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListOfferingsRequest`](struct.ListOfferingsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListOfferingsRequestBuilder {
+    next_token: Option<String>,
+}
+
+impl ListOfferingsRequestBuilder {
+    /// Sets the [`next_token`](struct.ListOfferingsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOfferingsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListOfferingsRequest, ::rusoto_core::BuildError> {
+        Ok(ListOfferingsRequest {
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the return values of the list of offerings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1494,6 +4493,42 @@ pub struct ListProjectsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListProjectsRequest {
+    /// Returns a builder for `ListProjectsRequest`, checking its required members when built.
+    pub fn builder() -> ListProjectsRequestBuilder {
+        ListProjectsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListProjectsRequest`](struct.ListProjectsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListProjectsRequestBuilder {
+    arn: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListProjectsRequestBuilder {
+    /// Sets the [`arn`](struct.ListProjectsRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListProjectsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListProjectsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListProjectsRequest, ::rusoto_core::BuildError> {
+        Ok(ListProjectsRequest {
+            arn: self.arn,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the result of a list projects request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1520,6 +4555,44 @@ pub struct ListRemoteAccessSessionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListRemoteAccessSessionsRequest {
+    /// Returns a builder for `ListRemoteAccessSessionsRequest`, checking its required members when built.
+    pub fn builder() -> ListRemoteAccessSessionsRequestBuilder {
+        ListRemoteAccessSessionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRemoteAccessSessionsRequest`](struct.ListRemoteAccessSessionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRemoteAccessSessionsRequestBuilder {
+    arn: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListRemoteAccessSessionsRequestBuilder {
+    /// Sets the [`arn`](struct.ListRemoteAccessSessionsRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListRemoteAccessSessionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRemoteAccessSessionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListRemoteAccessSessionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListRemoteAccessSessionsRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the response from the server after AWS Device Farm makes a request to return information about the remote access session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1546,6 +4619,42 @@ pub struct ListRunsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListRunsRequest {
+    /// Returns a builder for `ListRunsRequest`, checking its required members when built.
+    pub fn builder() -> ListRunsRequestBuilder {
+        ListRunsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRunsRequest`](struct.ListRunsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRunsRequestBuilder {
+    arn: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListRunsRequestBuilder {
+    /// Sets the [`arn`](struct.ListRunsRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListRunsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRunsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListRunsRequest, ::rusoto_core::BuildError> {
+        Ok(ListRunsRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the result of a list runs request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1572,6 +4681,42 @@ pub struct ListSamplesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListSamplesRequest {
+    /// Returns a builder for `ListSamplesRequest`, checking its required members when built.
+    pub fn builder() -> ListSamplesRequestBuilder {
+        ListSamplesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSamplesRequest`](struct.ListSamplesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSamplesRequestBuilder {
+    arn: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListSamplesRequestBuilder {
+    /// Sets the [`arn`](struct.ListSamplesRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSamplesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSamplesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListSamplesRequest, ::rusoto_core::BuildError> {
+        Ok(ListSamplesRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the result of a list samples request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1598,6 +4743,42 @@ pub struct ListSuitesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListSuitesRequest {
+    /// Returns a builder for `ListSuitesRequest`, checking its required members when built.
+    pub fn builder() -> ListSuitesRequestBuilder {
+        ListSuitesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSuitesRequest`](struct.ListSuitesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSuitesRequestBuilder {
+    arn: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListSuitesRequestBuilder {
+    /// Sets the [`arn`](struct.ListSuitesRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSuitesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSuitesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListSuitesRequest, ::rusoto_core::BuildError> {
+        Ok(ListSuitesRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the result of a list suites request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1619,6 +4800,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -1640,6 +4853,42 @@ pub struct ListTestsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListTestsRequest {
+    /// Returns a builder for `ListTestsRequest`, checking its required members when built.
+    pub fn builder() -> ListTestsRequestBuilder {
+        ListTestsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTestsRequest`](struct.ListTestsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTestsRequestBuilder {
+    arn: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListTestsRequestBuilder {
+    /// Sets the [`arn`](struct.ListTestsRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTestsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTestsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTestsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTestsRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the result of a list tests request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1666,6 +4915,44 @@ pub struct ListUniqueProblemsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListUniqueProblemsRequest {
+    /// Returns a builder for `ListUniqueProblemsRequest`, checking its required members when built.
+    pub fn builder() -> ListUniqueProblemsRequestBuilder {
+        ListUniqueProblemsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUniqueProblemsRequest`](struct.ListUniqueProblemsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUniqueProblemsRequestBuilder {
+    arn: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListUniqueProblemsRequestBuilder {
+    /// Sets the [`arn`](struct.ListUniqueProblemsRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUniqueProblemsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUniqueProblemsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListUniqueProblemsRequest, ::rusoto_core::BuildError> {
+        Ok(ListUniqueProblemsRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Represents the result of a list unique problems request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1677,7 +4964,7 @@ pub struct ListUniqueProblemsResult {
     /// <p><p>Information about the unique problems.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending condition.</p> </li> <li> <p>PASSED: A passing condition.</p> </li> <li> <p>WARNED: A warning condition.</p> </li> <li> <p>FAILED: A failed condition.</p> </li> <li> <p>SKIPPED: A skipped condition.</p> </li> <li> <p>ERRORED: An error condition.</p> </li> <li> <p>STOPPED: A stopped condition.</p> </li> </ul></p>
     #[serde(rename = "uniqueProblems")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unique_problems: Option<::std::collections::HashMap<String, Vec<UniqueProblem>>>,
+    pub unique_problems: Option<::std::collections::HashMap<ExecutionResult, Vec<UniqueProblem>>>,
 }
 
 /// <p>Represents a request to the list uploads operation.</p>
@@ -1693,7 +4980,51 @@ pub struct ListUploadsRequest {
     /// <p><p>The type of upload.</p> <p>Must be one of the following values:</p> <ul> <li> <p>ANDROID<em>APP: An Android upload.</p> </li> <li> <p>IOS</em>APP: An iOS upload.</p> </li> <li> <p>WEB<em>APP: A web application upload.</p> </li> <li> <p>EXTERNAL</em>DATA: An external data upload.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT<em>TEST</em>PACKAGE: An Appium Java JUnit test package upload.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG<em>TEST</em>PACKAGE: An Appium Java TestNG test package upload.</p> </li> <li> <p>APPIUM<em>PYTHON</em>TEST<em>PACKAGE: An Appium Python test package upload.</p> </li> <li> <p>APPIUM</em>NODE<em>TEST</em>PACKAGE: An Appium Node.js test package upload.</p> </li> <li> <p>APPIUM<em>RUBY</em>TEST<em>PACKAGE: An Appium Ruby test package upload.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT<em>TEST</em>PACKAGE: An Appium Java JUnit test package upload for a web app.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</em>TEST<em>PACKAGE: An Appium Java TestNG test package upload for a web app.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</em>TEST<em>PACKAGE: An Appium Python test package upload for a web app.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</em>TEST<em>PACKAGE: An Appium Node.js test package upload for a web app.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</em>TEST<em>PACKAGE: An Appium Ruby test package upload for a web app.</p> </li> <li> <p>CALABASH</em>TEST<em>PACKAGE: A Calabash test package upload.</p> </li> <li> <p>INSTRUMENTATION</em>TEST<em>PACKAGE: An instrumentation upload.</p> </li> <li> <p>UIAUTOMATION</em>TEST<em>PACKAGE: A uiautomation test package upload.</p> </li> <li> <p>UIAUTOMATOR</em>TEST<em>PACKAGE: A uiautomator test package upload.</p> </li> <li> <p>XCTEST</em>TEST<em>PACKAGE: An Xcode test package upload.</p> </li> <li> <p>XCTEST</em>UI<em>TEST</em>PACKAGE: An Xcode UI test package upload.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT<em>TEST</em>SPEC: An Appium Java JUnit test spec upload.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG<em>TEST</em>SPEC: An Appium Java TestNG test spec upload.</p> </li> <li> <p>APPIUM<em>PYTHON</em>TEST<em>SPEC: An Appium Python test spec upload.</p> </li> <li> <p>APPIUM</em>NODE<em>TEST</em>SPEC: An Appium Node.js test spec upload.</p> </li> <li> <p> APPIUM<em>RUBY</em>TEST<em>SPEC: An Appium Ruby test spec upload.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT<em>TEST</em>SPEC: An Appium Java JUnit test spec upload for a web app.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</em>TEST<em>SPEC: An Appium Java TestNG test spec upload for a web app.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</em>TEST<em>SPEC: An Appium Python test spec upload for a web app.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</em>TEST<em>SPEC: An Appium Node.js test spec upload for a web app.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</em>TEST<em>SPEC: An Appium Ruby test spec upload for a web app.</p> </li> <li> <p>INSTRUMENTATION</em>TEST<em>SPEC: An instrumentation test spec upload.</p> </li> <li> <p>XCTEST</em>UI<em>TEST</em>SPEC: An Xcode UI test spec upload.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<UploadType>,
+}
+
+impl ListUploadsRequest {
+    /// Returns a builder for `ListUploadsRequest`, checking its required members when built.
+    pub fn builder() -> ListUploadsRequestBuilder {
+        ListUploadsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUploadsRequest`](struct.ListUploadsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUploadsRequestBuilder {
+    arn: Option<String>,
+    next_token: Option<String>,
+    type_: Option<UploadType>,
+}
+
+impl ListUploadsRequestBuilder {
+    /// Sets the [`arn`](struct.ListUploadsRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUploadsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.ListUploadsRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<UploadType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUploadsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListUploadsRequest, ::rusoto_core::BuildError> {
+        Ok(ListUploadsRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            next_token: self.next_token,
+            type_: self.type_,
+        })
+    }
 }
 
 /// <p>Represents the result of a list uploads request.</p>
@@ -1722,6 +5053,44 @@ pub struct ListVPCEConfigurationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListVPCEConfigurationsRequest {
+    /// Returns a builder for `ListVPCEConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> ListVPCEConfigurationsRequestBuilder {
+        ListVPCEConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListVPCEConfigurationsRequest`](struct.ListVPCEConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListVPCEConfigurationsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListVPCEConfigurationsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListVPCEConfigurationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListVPCEConfigurationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVPCEConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListVPCEConfigurationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListVPCEConfigurationsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListVPCEConfigurationsResult {
@@ -1757,7 +5126,7 @@ pub struct MonetaryAmount {
     /// <p>The currency code of a monetary amount. For example, <code>USD</code> means "U.S. dollars."</p>
     #[serde(rename = "currencyCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency_code: Option<String>,
+    pub currency_code: Option<CurrencyCode>,
 }
 
 /// <p>An array of settings that describes characteristics of a network profile.</p>
@@ -1795,7 +5164,7 @@ pub struct NetworkProfile {
     /// <p>The type of network profile. Valid values are listed below.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NetworkProfileType>,
     /// <p>The data throughput rate in bits per second, as an integer from 0 to 104857600.</p>
     #[serde(rename = "uplinkBandwidthBits")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1814,6 +5183,78 @@ pub struct NetworkProfile {
     pub uplink_loss_percent: Option<i64>,
 }
 
+/// <p>The type of network profile you wish to create. Valid values are listed below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NetworkProfileType {
+    /// <p><code>CURATED</code></p>
+    Curated,
+    /// <p><code>PRIVATE</code></p>
+    Private,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NetworkProfileType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkProfileType::Curated => "CURATED",
+            NetworkProfileType::Private => "PRIVATE",
+            NetworkProfileType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NetworkProfileType {
+    fn from(value: &'a str) -> NetworkProfileType {
+        match value {
+            "CURATED" => NetworkProfileType::Curated,
+            "PRIVATE" => NetworkProfileType::Private,
+            _ => NetworkProfileType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkProfileType {
+    fn from(value: String) -> NetworkProfileType {
+        NetworkProfileType::from(value.as_str())
+    }
+}
+
+impl From<NetworkProfileType> for String {
+    fn from(value: NetworkProfileType) -> String {
+        match value {
+            NetworkProfileType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NetworkProfileType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NetworkProfileType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NetworkProfileType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NetworkProfileType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NetworkProfileType::from(value))
+    }
+}
+
 /// <p>Represents the metadata of a device offering.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1829,7 +5270,7 @@ pub struct Offering {
     /// <p>The platform of the device (e.g., ANDROID or IOS).</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<DevicePlatform>,
     /// <p>Specifies whether there are recurring charges for the offering.</p>
     #[serde(rename = "recurringCharges")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1837,7 +5278,7 @@ pub struct Offering {
     /// <p>The type of offering (e.g., "RECURRING") for a device.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OfferingType>,
 }
 
 /// <p>Represents information about an offering promotion.</p>
@@ -1860,8 +5301,9 @@ pub struct OfferingPromotion {
 pub struct OfferingStatus {
     /// <p>The date on which the offering is effective.</p>
     #[serde(rename = "effectiveOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub effective_on: Option<f64>,
+    pub effective_on: Option<EpochTimestamp>,
     /// <p>Represents the metadata of an offering status.</p>
     #[serde(rename = "offering")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1873,7 +5315,7 @@ pub struct OfferingStatus {
     /// <p>The type specified for the offering status.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<OfferingTransactionType>,
 }
 
 /// <p>Represents the metadata of an offering transaction.</p>
@@ -1886,8 +5328,9 @@ pub struct OfferingTransaction {
     pub cost: Option<MonetaryAmount>,
     /// <p>The date on which an offering transaction was created.</p>
     #[serde(rename = "createdOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_on: Option<f64>,
+    pub created_on: Option<EpochTimestamp>,
     /// <p>The ID that corresponds to a device offering promotion.</p>
     #[serde(rename = "offeringPromotionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1902,66 +5345,211 @@ pub struct OfferingTransaction {
     pub transaction_id: Option<String>,
 }
 
-/// <p>Represents a specific warning or failure.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Problem {
-    /// <p>Information about the associated device.</p>
-    #[serde(rename = "device")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub device: Option<Device>,
-    /// <p>Information about the associated job.</p>
-    #[serde(rename = "job")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub job: Option<ProblemDetail>,
-    /// <p>A message about the problem's result.</p>
-    #[serde(rename = "message")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
-    /// <p><p>The problem&#39;s result.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending condition.</p> </li> <li> <p>PASSED: A passing condition.</p> </li> <li> <p>WARNED: A warning condition.</p> </li> <li> <p>FAILED: A failed condition.</p> </li> <li> <p>SKIPPED: A skipped condition.</p> </li> <li> <p>ERRORED: An error condition.</p> </li> <li> <p>STOPPED: A stopped condition.</p> </li> </ul></p>
-    #[serde(rename = "result")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
-    /// <p>Information about the associated run.</p>
-    #[serde(rename = "run")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub run: Option<ProblemDetail>,
-    /// <p>Information about the associated suite.</p>
-    #[serde(rename = "suite")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub suite: Option<ProblemDetail>,
-    /// <p>Information about the associated test.</p>
-    #[serde(rename = "test")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub test: Option<ProblemDetail>,
+/// <p>The type specified for the offering status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OfferingTransactionType {
+    /// <p><code>PURCHASE</code></p>
+    Purchase,
+    /// <p><code>RENEW</code></p>
+    Renew,
+    /// <p><code>SYSTEM</code></p>
+    System,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Information about a problem detail.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ProblemDetail {
-    /// <p>The problem detail's ARN.</p>
-    #[serde(rename = "arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The problem detail's name.</p>
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+impl OfferingTransactionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OfferingTransactionType::Purchase => "PURCHASE",
+            OfferingTransactionType::Renew => "RENEW",
+            OfferingTransactionType::System => "SYSTEM",
+            OfferingTransactionType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Represents an operating-system neutral workspace for running and managing tests.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Project {
-    /// <p>The project's ARN.</p>
-    #[serde(rename = "arn")]
+impl<'a> From<&'a str> for OfferingTransactionType {
+    fn from(value: &'a str) -> OfferingTransactionType {
+        match value {
+            "PURCHASE" => OfferingTransactionType::Purchase,
+            "RENEW" => OfferingTransactionType::Renew,
+            "SYSTEM" => OfferingTransactionType::System,
+            _ => OfferingTransactionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OfferingTransactionType {
+    fn from(value: String) -> OfferingTransactionType {
+        OfferingTransactionType::from(value.as_str())
+    }
+}
+
+impl From<OfferingTransactionType> for String {
+    fn from(value: OfferingTransactionType) -> String {
+        match value {
+            OfferingTransactionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OfferingTransactionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OfferingTransactionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OfferingTransactionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OfferingTransactionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OfferingTransactionType::from(value))
+    }
+}
+
+/// <p>The type of offering (e.g., "RECURRING") for a device.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OfferingType {
+    /// <p><code>RECURRING</code></p>
+    Recurring,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OfferingType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OfferingType::Recurring => "RECURRING",
+            OfferingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OfferingType {
+    fn from(value: &'a str) -> OfferingType {
+        match value {
+            "RECURRING" => OfferingType::Recurring,
+            _ => OfferingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OfferingType {
+    fn from(value: String) -> OfferingType {
+        OfferingType::from(value.as_str())
+    }
+}
+
+impl From<OfferingType> for String {
+    fn from(value: OfferingType) -> String {
+        match value {
+            OfferingType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OfferingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OfferingType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OfferingType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OfferingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OfferingType::from(value))
+    }
+}
+
+/// <p>Represents a specific warning or failure.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Problem {
+    /// <p>Information about the associated device.</p>
+    #[serde(rename = "device")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<Device>,
+    /// <p>Information about the associated job.</p>
+    #[serde(rename = "job")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job: Option<ProblemDetail>,
+    /// <p>A message about the problem's result.</p>
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// <p><p>The problem&#39;s result.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending condition.</p> </li> <li> <p>PASSED: A passing condition.</p> </li> <li> <p>WARNED: A warning condition.</p> </li> <li> <p>FAILED: A failed condition.</p> </li> <li> <p>SKIPPED: A skipped condition.</p> </li> <li> <p>ERRORED: An error condition.</p> </li> <li> <p>STOPPED: A stopped condition.</p> </li> </ul></p>
+    #[serde(rename = "result")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<ExecutionResult>,
+    /// <p>Information about the associated run.</p>
+    #[serde(rename = "run")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run: Option<ProblemDetail>,
+    /// <p>Information about the associated suite.</p>
+    #[serde(rename = "suite")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suite: Option<ProblemDetail>,
+    /// <p>Information about the associated test.</p>
+    #[serde(rename = "test")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test: Option<ProblemDetail>,
+}
+
+/// <p>Information about a problem detail.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ProblemDetail {
+    /// <p>The problem detail's ARN.</p>
+    #[serde(rename = "arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The problem detail's name.</p>
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// <p>Represents an operating-system neutral workspace for running and managing tests.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Project {
+    /// <p>The project's ARN.</p>
+    #[serde(rename = "arn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arn: Option<String>,
     /// <p>When the project was created.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>The default number of minutes (at the project level) a test run will execute before it times out. The default value is 150 minutes.</p>
     #[serde(rename = "defaultJobTimeoutMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1989,6 +5577,52 @@ pub struct PurchaseOfferingRequest {
     pub quantity: Option<i64>,
 }
 
+impl PurchaseOfferingRequest {
+    /// Returns a builder for `PurchaseOfferingRequest`, checking its required members when built.
+    pub fn builder() -> PurchaseOfferingRequestBuilder {
+        PurchaseOfferingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PurchaseOfferingRequest`](struct.PurchaseOfferingRequest.html).
+#[derive(Default, Debug)]
+pub struct PurchaseOfferingRequestBuilder {
+    offering_id: Option<String>,
+    offering_promotion_id: Option<String>,
+    quantity: Option<i64>,
+}
+
+impl PurchaseOfferingRequestBuilder {
+    /// Sets the [`offering_id`](struct.PurchaseOfferingRequest.html#structfield.offering_id) member.
+    pub fn offering_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.offering_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`offering_promotion_id`](struct.PurchaseOfferingRequest.html#structfield.offering_promotion_id) member.
+    pub fn offering_promotion_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.offering_promotion_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`quantity`](struct.PurchaseOfferingRequest.html#structfield.quantity) member.
+    pub fn quantity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.quantity = Some(value.into());
+        self
+    }
+
+    /// Builds the `PurchaseOfferingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PurchaseOfferingRequest, ::rusoto_core::BuildError> {
+        Ok(PurchaseOfferingRequest {
+            offering_id: self.offering_id,
+            offering_promotion_id: self.offering_promotion_id,
+            quantity: self.quantity,
+        })
+    }
+}
+
 /// <p>The result of the purchase offering (e.g., success or failure).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2031,7 +5665,75 @@ pub struct RecurringCharge {
     /// <p>The frequency in which charges will recur.</p>
     #[serde(rename = "frequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<String>,
+    pub frequency: Option<RecurringChargeFrequency>,
+}
+
+/// <p>The frequency in which charges will recur.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecurringChargeFrequency {
+    /// <p><code>MONTHLY</code></p>
+    Monthly,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RecurringChargeFrequency {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecurringChargeFrequency::Monthly => "MONTHLY",
+            RecurringChargeFrequency::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RecurringChargeFrequency {
+    fn from(value: &'a str) -> RecurringChargeFrequency {
+        match value {
+            "MONTHLY" => RecurringChargeFrequency::Monthly,
+            _ => RecurringChargeFrequency::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecurringChargeFrequency {
+    fn from(value: String) -> RecurringChargeFrequency {
+        RecurringChargeFrequency::from(value.as_str())
+    }
+}
+
+impl From<RecurringChargeFrequency> for String {
+    fn from(value: RecurringChargeFrequency) -> String {
+        match value {
+            RecurringChargeFrequency::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RecurringChargeFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RecurringChargeFrequency {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecurringChargeFrequency {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RecurringChargeFrequency, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RecurringChargeFrequency::from(value))
+    }
 }
 
 /// <p>Represents information about the remote access session.</p>
@@ -2045,15 +5747,16 @@ pub struct RemoteAccessSession {
     /// <p>The billing method of the remote access session. Possible values include <code>METERED</code> or <code>UNMETERED</code>. For more information about metered devices, see <a href="https://docs.aws.amazon.com/devicefarm/latest/developerguide/welcome.html#welcome-terminology">AWS Device Farm terminology</a>."</p>
     #[serde(rename = "billingMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_method: Option<String>,
+    pub billing_method: Option<BillingMethod>,
     /// <p>Unique identifier of your client for the remote access session. Only returned if remote debugging is enabled for the remote access session.</p>
     #[serde(rename = "clientId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_id: Option<String>,
     /// <p>The date and time the remote access session was created.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>The device (phone or tablet) used in the remote access session.</p>
     #[serde(rename = "device")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2081,7 +5784,7 @@ pub struct RemoteAccessSession {
     /// <p><p>The interaction mode of the remote access session. Valid values are:</p> <ul> <li> <p>INTERACTIVE: You can interact with the iOS device by viewing, touching, and rotating the screen. You <b>cannot</b> run XCUITest framework-based tests in this mode.</p> </li> <li> <p>NO<em>VIDEO: You are connected to the device but cannot interact with it or view the screen. This mode has the fastest test execution speed. You <b>can</b> run XCUITest framework-based tests in this mode.</p> </li> <li> <p>VIDEO</em>ONLY: You can view the screen but cannot touch or rotate it. You <b>can</b> run XCUITest framework-based tests and watch the screen in this mode.</p> </li> </ul></p>
     #[serde(rename = "interactionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interaction_mode: Option<String>,
+    pub interaction_mode: Option<InteractionMode>,
     /// <p>A message about the remote access session.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2105,23 +5808,25 @@ pub struct RemoteAccessSession {
     /// <p><p>The result of the remote access session. Can be any of the following:</p> <ul> <li> <p>PENDING: A pending condition.</p> </li> <li> <p>PASSED: A passing condition.</p> </li> <li> <p>WARNED: A warning condition.</p> </li> <li> <p>FAILED: A failed condition.</p> </li> <li> <p>SKIPPED: A skipped condition.</p> </li> <li> <p>ERRORED: An error condition.</p> </li> <li> <p>STOPPED: A stopped condition.</p> </li> </ul></p>
     #[serde(rename = "result")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
+    pub result: Option<ExecutionResult>,
     /// <p>When set to <code>true</code>, for private devices, Device Farm will not sign your app again. For public devices, Device Farm always signs your apps again and this parameter has no effect.</p> <p>For more information about how Device Farm re-signs your app(s), see <a href="https://aws.amazon.com/device-farm/faq/">Do you modify my app?</a> in the <i>AWS Device Farm FAQs</i>.</p>
     #[serde(rename = "skipAppResign")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub skip_app_resign: Option<bool>,
     /// <p>The date and time the remote access session was started.</p>
     #[serde(rename = "started")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started: Option<f64>,
+    pub started: Option<EpochTimestamp>,
     /// <p><p>The status of the remote access session. Can be any of the following:</p> <ul> <li> <p>PENDING: A pending status.</p> </li> <li> <p>PENDING<em>CONCURRENCY: A pending concurrency status.</p> </li> <li> <p>PENDING</em>DEVICE: A pending device status.</p> </li> <li> <p>PROCESSING: A processing status.</p> </li> <li> <p>SCHEDULING: A scheduling status.</p> </li> <li> <p>PREPARING: A preparing status.</p> </li> <li> <p>RUNNING: A running status.</p> </li> <li> <p>COMPLETED: A completed status.</p> </li> <li> <p>STOPPING: A stopping status.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ExecutionStatus>,
     /// <p>The date and time the remote access session was stopped.</p>
     #[serde(rename = "stopped")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped: Option<f64>,
+    pub stopped: Option<EpochTimestamp>,
 }
 
 /// <p>A request representing an offering renewal.</p>
@@ -2137,6 +5842,42 @@ pub struct RenewOfferingRequest {
     pub quantity: Option<i64>,
 }
 
+impl RenewOfferingRequest {
+    /// Returns a builder for `RenewOfferingRequest`, checking its required members when built.
+    pub fn builder() -> RenewOfferingRequestBuilder {
+        RenewOfferingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RenewOfferingRequest`](struct.RenewOfferingRequest.html).
+#[derive(Default, Debug)]
+pub struct RenewOfferingRequestBuilder {
+    offering_id: Option<String>,
+    quantity: Option<i64>,
+}
+
+impl RenewOfferingRequestBuilder {
+    /// Sets the [`offering_id`](struct.RenewOfferingRequest.html#structfield.offering_id) member.
+    pub fn offering_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.offering_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`quantity`](struct.RenewOfferingRequest.html#structfield.quantity) member.
+    pub fn quantity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.quantity = Some(value.into());
+        self
+    }
+
+    /// Builds the `RenewOfferingRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RenewOfferingRequest, ::rusoto_core::BuildError> {
+        Ok(RenewOfferingRequest {
+            offering_id: self.offering_id,
+            quantity: self.quantity,
+        })
+    }
+}
+
 /// <p>The result of a renewal offering.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2167,17 +5908,113 @@ pub struct Rule {
     /// <p><p>The rule&#39;s stringified attribute. For example, specify the value as <code>&quot;&quot;abc&quot;&quot;</code>.</p> <p>The supported operators for each attribute are provided in the following list.</p> <dl> <dt>APPIUM<em>VERSION</dt> <dd> <p>The Appium version for the test.</p> <p> <i>Supported operators</i>: <code>CONTAINS</code> </p> </dd> <dt>ARN</dt> <dd> <p>The Amazon Resource Name (ARN) of the device. For example, &quot;arn:aws:devicefarm:us-west-2::device:12345Example&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>AVAILABILITY</dt> <dd> <p>The current availability of the device. Valid values are &quot;AVAILABLE&quot;, &quot;HIGHLY<em>AVAILABLE&quot;, &quot;BUSY&quot;, or &quot;TEMPORARY</em>NOT<em>AVAILABLE&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>FLEET</em>TYPE</dt> <dd> <p>The fleet type. Valid values are &quot;PUBLIC&quot; or &quot;PRIVATE&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>FORM<em>FACTOR</dt> <dd> <p>The device form factor. Valid values are &quot;PHONE&quot; or &quot;TABLET&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>INSTANCE<em>ARN</dt> <dd> <p>The Amazon Resource Name (ARN) of the device instance.</p> <p> <i>Supported operators</i>: <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>INSTANCE<em>LABELS</dt> <dd> <p>The label of the device instance.</p> <p> <i>Supported operators</i>: <code>CONTAINS</code> </p> </dd> <dt>MANUFACTURER</dt> <dd> <p>The device manufacturer. For example, &quot;Apple&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>MODEL</dt> <dd> <p>The device model, such as &quot;Apple iPad Air 2&quot; or &quot;Google Pixel&quot;.</p> <p> <i>Supported operators</i>: <code>CONTAINS</code>, <code>EQUALS</code>, <code>IN</code>, <code>NOT<em>IN</code> </p> </dd> <dt>OS</em>VERSION</dt> <dd> <p>The operating system version. For example, &quot;10.3.2&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>GREATER<em>THAN</code>, <code>GREATER</em>THAN<em>OR</em>EQUALS</code>, <code>IN</code>, <code>LESS<em>THAN</code>, <code>LESS</em>THAN<em>OR</em>EQUALS</code>, <code>NOT<em>IN</code> </p> </dd> <dt>PLATFORM</dt> <dd> <p>The device platform. Valid values are &quot;ANDROID&quot; or &quot;IOS&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code>, <code>IN</code>, <code>NOT</em>IN</code> </p> </dd> <dt>REMOTE<em>ACCESS</em>ENABLED</dt> <dd> <p>Whether the device is enabled for remote access. Valid values are &quot;TRUE&quot; or &quot;FALSE&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> <dt>REMOTE<em>DEBUG</em>ENABLED</dt> <dd> <p>Whether the device is enabled for remote debugging. Valid values are &quot;TRUE&quot; or &quot;FALSE&quot;.</p> <p> <i>Supported operators</i>: <code>EQUALS</code> </p> </dd> </dl></p>
     #[serde(rename = "attribute")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attribute: Option<String>,
+    pub attribute: Option<DeviceAttribute>,
     /// <p>Specifies how Device Farm compares the rule's attribute to the value. For the operators that are supported by each attribute, see the attribute descriptions.</p>
     #[serde(rename = "operator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operator: Option<String>,
+    pub operator: Option<RuleOperator>,
     /// <p>The rule's value.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+/// <p>Specifies how Device Farm compares the filter's attribute to the value. For the operators that are supported by each attribute, see the attribute descriptions.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RuleOperator {
+    /// <p><code>EQUALS</code></p>
+    Equals,
+    /// <p><code>LESS_THAN</code></p>
+    LessThan,
+    /// <p><code>LESS_THAN_OR_EQUALS</code></p>
+    LessThanOrEquals,
+    /// <p><code>GREATER_THAN</code></p>
+    GreaterThan,
+    /// <p><code>GREATER_THAN_OR_EQUALS</code></p>
+    GreaterThanOrEquals,
+    /// <p><code>IN</code></p>
+    In,
+    /// <p><code>NOT_IN</code></p>
+    NotIn,
+    /// <p><code>CONTAINS</code></p>
+    Contains,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RuleOperator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RuleOperator::Equals => "EQUALS",
+            RuleOperator::LessThan => "LESS_THAN",
+            RuleOperator::LessThanOrEquals => "LESS_THAN_OR_EQUALS",
+            RuleOperator::GreaterThan => "GREATER_THAN",
+            RuleOperator::GreaterThanOrEquals => "GREATER_THAN_OR_EQUALS",
+            RuleOperator::In => "IN",
+            RuleOperator::NotIn => "NOT_IN",
+            RuleOperator::Contains => "CONTAINS",
+            RuleOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RuleOperator {
+    fn from(value: &'a str) -> RuleOperator {
+        match value {
+            "EQUALS" => RuleOperator::Equals,
+            "LESS_THAN" => RuleOperator::LessThan,
+            "LESS_THAN_OR_EQUALS" => RuleOperator::LessThanOrEquals,
+            "GREATER_THAN" => RuleOperator::GreaterThan,
+            "GREATER_THAN_OR_EQUALS" => RuleOperator::GreaterThanOrEquals,
+            "IN" => RuleOperator::In,
+            "NOT_IN" => RuleOperator::NotIn,
+            "CONTAINS" => RuleOperator::Contains,
+            _ => RuleOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RuleOperator {
+    fn from(value: String) -> RuleOperator {
+        RuleOperator::from(value.as_str())
+    }
+}
+
+impl From<RuleOperator> for String {
+    fn from(value: RuleOperator) -> String {
+        match value {
+            RuleOperator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RuleOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RuleOperator {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RuleOperator {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RuleOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RuleOperator::from(value))
+    }
+}
+
 /// <p>Represents a test run on a set of devices with a given app package, test parameters, etc.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2193,7 +6030,7 @@ pub struct Run {
     /// <p>Specifies the billing method for a test run: <code>metered</code> or <code>unmetered</code>. If the parameter is not specified, the default value is <code>metered</code>.</p>
     #[serde(rename = "billingMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_method: Option<String>,
+    pub billing_method: Option<BillingMethod>,
     /// <p>The total number of completed jobs.</p>
     #[serde(rename = "completedJobs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2204,8 +6041,9 @@ pub struct Run {
     pub counters: Option<Counters>,
     /// <p>When the run was created.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>Output <code>CustomerArtifactPaths</code> object for the test run.</p>
     #[serde(rename = "customerArtifactPaths")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2257,7 +6095,7 @@ pub struct Run {
     /// <p><p>The run&#39;s platform.</p> <p>Allowed values include:</p> <ul> <li> <p>ANDROID: The Android platform.</p> </li> <li> <p>IOS: The iOS platform.</p> </li> </ul></p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<DevicePlatform>,
     /// <p>Information about the radio states for the run.</p>
     #[serde(rename = "radios")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2265,11 +6103,11 @@ pub struct Run {
     /// <p><p>The run&#39;s result.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending condition.</p> </li> <li> <p>PASSED: A passing condition.</p> </li> <li> <p>WARNED: A warning condition.</p> </li> <li> <p>FAILED: A failed condition.</p> </li> <li> <p>SKIPPED: A skipped condition.</p> </li> <li> <p>ERRORED: An error condition.</p> </li> <li> <p>STOPPED: A stopped condition.</p> </li> </ul></p>
     #[serde(rename = "result")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
+    pub result: Option<ExecutionResult>,
     /// <p>Supporting field for the result field. Set only if <code>result</code> is <code>SKIPPED</code>. <code>PARSING_FAILED</code> if the result is skipped because of test package parsing failure.</p>
     #[serde(rename = "resultCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result_code: Option<String>,
+    pub result_code: Option<ExecutionResultCode>,
     /// <p>For fuzz tests, this is a seed to use for randomizing the UI fuzz test. Using the same seed value between tests ensures identical event sequences.</p>
     #[serde(rename = "seed")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2280,16 +6118,18 @@ pub struct Run {
     pub skip_app_resign: Option<bool>,
     /// <p>The run's start time.</p>
     #[serde(rename = "started")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started: Option<f64>,
+    pub started: Option<EpochTimestamp>,
     /// <p><p>The run&#39;s status.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending status.</p> </li> <li> <p>PENDING<em>CONCURRENCY: A pending concurrency status.</p> </li> <li> <p>PENDING</em>DEVICE: A pending device status.</p> </li> <li> <p>PROCESSING: A processing status.</p> </li> <li> <p>SCHEDULING: A scheduling status.</p> </li> <li> <p>PREPARING: A preparing status.</p> </li> <li> <p>RUNNING: A running status.</p> </li> <li> <p>COMPLETED: A completed status.</p> </li> <li> <p>STOPPING: A stopping status.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ExecutionStatus>,
     /// <p>The run's stop time.</p>
     #[serde(rename = "stopped")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped: Option<f64>,
+    pub stopped: Option<EpochTimestamp>,
     /// <p>The ARN of the YAML-formatted test specification for the run.</p>
     #[serde(rename = "testSpecArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2301,7 +6141,7 @@ pub struct Run {
     /// <p><p>The run&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>BUILTIN<em>FUZZ: The built-in fuzz type.</p> </li> <li> <p>BUILTIN</em>EXPLORER: For Android, an app explorer that will traverse an Android app, interacting with it and capturing screenshots at the same time.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT: The Appium Java JUnit type.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG: The Appium Java TestNG type.</p> </li> <li> <p>APPIUM<em>PYTHON: The Appium Python type.</p> </li> <li> <p>APPIUM</em>NODE: The Appium Node.js type.</p> </li> <li> <p>APPIUM<em>RUBY: The Appium Ruby type.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT: The Appium Java JUnit type for web apps.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG: The Appium Java TestNG type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON: The Appium Python type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE: The Appium Node.js type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY: The Appium Ruby type for web apps.</p> </li> <li> <p>CALABASH: The Calabash type.</p> </li> <li> <p>INSTRUMENTATION: The Instrumentation type.</p> </li> <li> <p>UIAUTOMATION: The uiautomation type.</p> </li> <li> <p>UIAUTOMATOR: The uiautomator type.</p> </li> <li> <p>XCTEST: The Xcode test type.</p> </li> <li> <p>XCTEST</em>UI: The Xcode UI test type.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TestType>,
     /// <p>The Device Farm console URL for the recording of the run.</p>
     #[serde(rename = "webUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2319,13 +6159,145 @@ pub struct Sample {
     /// <p><p>The sample&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>CPU: A CPU sample type. This is expressed as the app processing CPU time (including child processes) as reported by process, as a percentage.</p> </li> <li> <p>MEMORY: A memory usage sample type. This is expressed as the total proportional set size of an app process, in kilobytes.</p> </li> <li> <p>NATIVE<em>AVG</em>DRAWTIME</p> </li> <li> <p>NATIVE<em>FPS</p> </li> <li> <p>NATIVE</em>FRAMES</p> </li> <li> <p>NATIVE<em>MAX</em>DRAWTIME</p> </li> <li> <p>NATIVE<em>MIN</em>DRAWTIME</p> </li> <li> <p>OPENGL<em>AVG</em>DRAWTIME</p> </li> <li> <p>OPENGL<em>FPS</p> </li> <li> <p>OPENGL</em>FRAMES</p> </li> <li> <p>OPENGL<em>MAX</em>DRAWTIME</p> </li> <li> <p>OPENGL<em>MIN</em>DRAWTIME</p> </li> <li> <p>RX</p> </li> <li> <p>RX<em>RATE: The total number of bytes per second (TCP and UDP) that are sent, by app process.</p> </li> <li> <p>THREADS: A threads sample type. This is expressed as the total number of threads per app process.</p> </li> <li> <p>TX</p> </li> <li> <p>TX</em>RATE: The total number of bytes per second (TCP and UDP) that are received, by app process.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<SampleType>,
     /// <p>The pre-signed Amazon S3 URL that can be used with a corresponding GET request to download the sample's file.</p>
     #[serde(rename = "url")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
 
+/// <p><p>The sample&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>CPU: A CPU sample type. This is expressed as the app processing CPU time (including child processes) as reported by process, as a percentage.</p> </li> <li> <p>MEMORY: A memory usage sample type. This is expressed as the total proportional set size of an app process, in kilobytes.</p> </li> <li> <p>NATIVE<em>AVG</em>DRAWTIME</p> </li> <li> <p>NATIVE<em>FPS</p> </li> <li> <p>NATIVE</em>FRAMES</p> </li> <li> <p>NATIVE<em>MAX</em>DRAWTIME</p> </li> <li> <p>NATIVE<em>MIN</em>DRAWTIME</p> </li> <li> <p>OPENGL<em>AVG</em>DRAWTIME</p> </li> <li> <p>OPENGL<em>FPS</p> </li> <li> <p>OPENGL</em>FRAMES</p> </li> <li> <p>OPENGL<em>MAX</em>DRAWTIME</p> </li> <li> <p>OPENGL<em>MIN</em>DRAWTIME</p> </li> <li> <p>RX</p> </li> <li> <p>RX<em>RATE: The total number of bytes per second (TCP and UDP) that are sent, by app process.</p> </li> <li> <p>THREADS: A threads sample type. This is expressed as the total number of threads per app process.</p> </li> <li> <p>TX</p> </li> <li> <p>TX</em>RATE: The total number of bytes per second (TCP and UDP) that are received, by app process.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SampleType {
+    /// <p><code>CPU</code></p>
+    Cpu,
+    /// <p><code>MEMORY</code></p>
+    Memory,
+    /// <p><code>THREADS</code></p>
+    Threads,
+    /// <p><code>RX_RATE</code></p>
+    RxRate,
+    /// <p><code>TX_RATE</code></p>
+    TxRate,
+    /// <p><code>RX</code></p>
+    Rx,
+    /// <p><code>TX</code></p>
+    Tx,
+    /// <p><code>NATIVE_FRAMES</code></p>
+    NativeFrames,
+    /// <p><code>NATIVE_FPS</code></p>
+    NativeFps,
+    /// <p><code>NATIVE_MIN_DRAWTIME</code></p>
+    NativeMinDrawtime,
+    /// <p><code>NATIVE_AVG_DRAWTIME</code></p>
+    NativeAvgDrawtime,
+    /// <p><code>NATIVE_MAX_DRAWTIME</code></p>
+    NativeMaxDrawtime,
+    /// <p><code>OPENGL_FRAMES</code></p>
+    OpenglFrames,
+    /// <p><code>OPENGL_FPS</code></p>
+    OpenglFps,
+    /// <p><code>OPENGL_MIN_DRAWTIME</code></p>
+    OpenglMinDrawtime,
+    /// <p><code>OPENGL_AVG_DRAWTIME</code></p>
+    OpenglAvgDrawtime,
+    /// <p><code>OPENGL_MAX_DRAWTIME</code></p>
+    OpenglMaxDrawtime,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SampleType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SampleType::Cpu => "CPU",
+            SampleType::Memory => "MEMORY",
+            SampleType::Threads => "THREADS",
+            SampleType::RxRate => "RX_RATE",
+            SampleType::TxRate => "TX_RATE",
+            SampleType::Rx => "RX",
+            SampleType::Tx => "TX",
+            SampleType::NativeFrames => "NATIVE_FRAMES",
+            SampleType::NativeFps => "NATIVE_FPS",
+            SampleType::NativeMinDrawtime => "NATIVE_MIN_DRAWTIME",
+            SampleType::NativeAvgDrawtime => "NATIVE_AVG_DRAWTIME",
+            SampleType::NativeMaxDrawtime => "NATIVE_MAX_DRAWTIME",
+            SampleType::OpenglFrames => "OPENGL_FRAMES",
+            SampleType::OpenglFps => "OPENGL_FPS",
+            SampleType::OpenglMinDrawtime => "OPENGL_MIN_DRAWTIME",
+            SampleType::OpenglAvgDrawtime => "OPENGL_AVG_DRAWTIME",
+            SampleType::OpenglMaxDrawtime => "OPENGL_MAX_DRAWTIME",
+            SampleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SampleType {
+    fn from(value: &'a str) -> SampleType {
+        match value {
+            "CPU" => SampleType::Cpu,
+            "MEMORY" => SampleType::Memory,
+            "THREADS" => SampleType::Threads,
+            "RX_RATE" => SampleType::RxRate,
+            "TX_RATE" => SampleType::TxRate,
+            "RX" => SampleType::Rx,
+            "TX" => SampleType::Tx,
+            "NATIVE_FRAMES" => SampleType::NativeFrames,
+            "NATIVE_FPS" => SampleType::NativeFps,
+            "NATIVE_MIN_DRAWTIME" => SampleType::NativeMinDrawtime,
+            "NATIVE_AVG_DRAWTIME" => SampleType::NativeAvgDrawtime,
+            "NATIVE_MAX_DRAWTIME" => SampleType::NativeMaxDrawtime,
+            "OPENGL_FRAMES" => SampleType::OpenglFrames,
+            "OPENGL_FPS" => SampleType::OpenglFps,
+            "OPENGL_MIN_DRAWTIME" => SampleType::OpenglMinDrawtime,
+            "OPENGL_AVG_DRAWTIME" => SampleType::OpenglAvgDrawtime,
+            "OPENGL_MAX_DRAWTIME" => SampleType::OpenglMaxDrawtime,
+            _ => SampleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SampleType {
+    fn from(value: String) -> SampleType {
+        SampleType::from(value.as_str())
+    }
+}
+
+impl From<SampleType> for String {
+    fn from(value: SampleType) -> String {
+        match value {
+            SampleType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SampleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SampleType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SampleType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SampleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SampleType::from(value))
+    }
+}
+
 /// <p>Represents the settings for a run. Includes things like location, radio states, auxiliary apps, and network profiles.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ScheduleRunConfiguration {
@@ -2336,7 +6308,7 @@ pub struct ScheduleRunConfiguration {
     /// <p>Specifies the billing method for a test run: <code>metered</code> or <code>unmetered</code>. If the parameter is not specified, the default value is <code>metered</code>.</p>
     #[serde(rename = "billingMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_method: Option<String>,
+    pub billing_method: Option<BillingMethod>,
     /// <p>Input <code>CustomerArtifactPaths</code> object for the scheduled run configuration.</p>
     #[serde(rename = "customerArtifactPaths")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2368,7 +6340,7 @@ pub struct ScheduleRunConfiguration {
 }
 
 /// <p>Represents a request to the schedule run operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ScheduleRunRequest {
     /// <p>The ARN of the app to schedule a run.</p>
     #[serde(rename = "appArn")]
@@ -2402,38 +6374,129 @@ pub struct ScheduleRunRequest {
     pub test: ScheduleRunTest,
 }
 
-/// <p>Represents the result of a schedule run request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ScheduleRunResult {
-    /// <p>Information about the scheduled run.</p>
-    #[serde(rename = "run")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub run: Option<Run>,
+impl ScheduleRunRequest {
+    /// Returns a builder for `ScheduleRunRequest`, checking its required members when built.
+    pub fn builder() -> ScheduleRunRequestBuilder {
+        ScheduleRunRequestBuilder::default()
+    }
 }
 
-/// <p>Represents test settings. This data structure is passed in as the "test" parameter to ScheduleRun. For an example of the JSON request syntax, see <a>ScheduleRun</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ScheduleRunTest {
-    /// <p>The test's filter.</p>
-    #[serde(rename = "filter")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter: Option<String>,
-    /// <p><p>The test&#39;s parameters, such as test framework parameters and fixture settings. Parameters are represented by name-value pairs of strings.</p> <p>For all tests:</p> <ul> <li> <p>app<em>performance</em>monitoring: Performance monitoring is enabled by default. Set this parameter to &quot;false&quot; to disable it.</p> </li> </ul> <p>For Calabash tests:</p> <ul> <li> <p>profile: A cucumber profile, for example, &quot;my<em>profile</em>name&quot;.</p> </li> <li> <p>tags: You can limit execution to features or scenarios that have (or don&#39;t have) certain tags, for example, &quot;@smoke&quot; or &quot;@smoke,~@wip&quot;.</p> </li> </ul> <p>For Appium tests (all types):</p> <ul> <li> <p>appium<em>version: The Appium version. Currently supported values are &quot;1.6.5&quot; (and higher), &quot;latest&quot;, and &quot;default&quot;.</p> <ul> <li> <p>“latest” will run the latest Appium version supported by Device Farm (1.9.1).</p> </li> <li> <p>For “default”, Device Farm will choose a compatible version of Appium for the device. The current behavior is to run 1.7.2 on Android devices and iOS 9 and earlier, 1.7.2 for iOS 10 and later.</p> </li> <li> <p>This behavior is subject to change.</p> </li> </ul> </li> </ul> <p>For Fuzz tests (Android only):</p> <ul> <li> <p>event</em>count: The number of events, between 1 and 10000, that the UI fuzz test should perform.</p> </li> <li> <p>throttle: The time, in ms, between 0 and 1000, that the UI fuzz test should wait between events.</p> </li> <li> <p>seed: A seed to use for randomizing the UI fuzz test. Using the same seed value between tests ensures identical event sequences.</p> </li> </ul> <p>For Explorer tests:</p> <ul> <li> <p>username: A username to use if the Explorer encounters a login form. If not supplied, no username will be inserted.</p> </li> <li> <p>password: A password to use if the Explorer encounters a login form. If not supplied, no password will be inserted.</p> </li> </ul> <p>For Instrumentation:</p> <ul> <li> <p>filter: A test filter string. Examples:</p> <ul> <li> <p>Running a single test case: &quot;com.android.abc.Test1&quot;</p> </li> <li> <p>Running a single test: &quot;com.android.abc.Test1#smoke&quot;</p> </li> <li> <p>Running multiple tests: &quot;com.android.abc.Test1,com.android.abc.Test2&quot;</p> </li> </ul> </li> </ul> <p>For XCTest and XCTestUI:</p> <ul> <li> <p>filter: A test filter string. Examples:</p> <ul> <li> <p>Running a single test class: &quot;LoginTests&quot;</p> </li> <li> <p>Running a multiple test classes: &quot;LoginTests,SmokeTests&quot;</p> </li> <li> <p>Running a single test: &quot;LoginTests/testValid&quot;</p> </li> <li> <p>Running multiple tests: &quot;LoginTests/testValid,LoginTests/testInvalid&quot;</p> </li> </ul> </li> </ul> <p>For UIAutomator:</p> <ul> <li> <p>filter: A test filter string. Examples:</p> <ul> <li> <p>Running a single test case: &quot;com.android.abc.Test1&quot;</p> </li> <li> <p>Running a single test: &quot;com.android.abc.Test1#smoke&quot;</p> </li> <li> <p>Running multiple tests: &quot;com.android.abc.Test1,com.android.abc.Test2&quot;</p> </li> </ul> </li> </ul></p>
-    #[serde(rename = "parameters")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<::std::collections::HashMap<String, String>>,
-    /// <p>The ARN of the uploaded test that will be run.</p>
-    #[serde(rename = "testPackageArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub test_package_arn: Option<String>,
+/// Builds a [`ScheduleRunRequest`](struct.ScheduleRunRequest.html).
+#[derive(Default, Debug)]
+pub struct ScheduleRunRequestBuilder {
+    app_arn: Option<String>,
+    configuration: Option<ScheduleRunConfiguration>,
+    device_pool_arn: Option<String>,
+    device_selection_configuration: Option<DeviceSelectionConfiguration>,
+    execution_configuration: Option<ExecutionConfiguration>,
+    name: Option<String>,
+    project_arn: Option<String>,
+    test: Option<ScheduleRunTest>,
+}
+
+impl ScheduleRunRequestBuilder {
+    /// Sets the [`app_arn`](struct.ScheduleRunRequest.html#structfield.app_arn) member.
+    pub fn app_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration`](struct.ScheduleRunRequest.html#structfield.configuration) member.
+    pub fn configuration<T: Into<ScheduleRunConfiguration>>(mut self, value: T) -> Self {
+        self.configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_pool_arn`](struct.ScheduleRunRequest.html#structfield.device_pool_arn) member.
+    pub fn device_pool_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_pool_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_selection_configuration`](struct.ScheduleRunRequest.html#structfield.device_selection_configuration) member.
+    pub fn device_selection_configuration<T: Into<DeviceSelectionConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.device_selection_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_configuration`](struct.ScheduleRunRequest.html#structfield.execution_configuration) member.
+    pub fn execution_configuration<T: Into<ExecutionConfiguration>>(mut self, value: T) -> Self {
+        self.execution_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.ScheduleRunRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_arn`](struct.ScheduleRunRequest.html#structfield.project_arn) member.
+    pub fn project_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`test`](struct.ScheduleRunRequest.html#structfield.test) member.
+    pub fn test<T: Into<ScheduleRunTest>>(mut self, value: T) -> Self {
+        self.test = Some(value.into());
+        self
+    }
+
+    /// Builds the `ScheduleRunRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ScheduleRunRequest, ::rusoto_core::BuildError> {
+        Ok(ScheduleRunRequest {
+            app_arn: self.app_arn,
+            configuration: self.configuration,
+            device_pool_arn: self.device_pool_arn,
+            device_selection_configuration: self.device_selection_configuration,
+            execution_configuration: self.execution_configuration,
+            name: self.name,
+            project_arn: self
+                .project_arn
+                .ok_or(::rusoto_core::BuildError::missing("project_arn"))?,
+            test: self
+                .test
+                .ok_or(::rusoto_core::BuildError::missing("test"))?,
+        })
+    }
+}
+
+/// <p>Represents the result of a schedule run request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ScheduleRunResult {
+    /// <p>Information about the scheduled run.</p>
+    #[serde(rename = "run")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run: Option<Run>,
+}
+
+/// <p>Represents test settings. This data structure is passed in as the "test" parameter to ScheduleRun. For an example of the JSON request syntax, see <a>ScheduleRun</a>.</p>
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScheduleRunTest {
+    /// <p>The test's filter.</p>
+    #[serde(rename = "filter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// <p><p>The test&#39;s parameters, such as test framework parameters and fixture settings. Parameters are represented by name-value pairs of strings.</p> <p>For all tests:</p> <ul> <li> <p>app<em>performance</em>monitoring: Performance monitoring is enabled by default. Set this parameter to &quot;false&quot; to disable it.</p> </li> </ul> <p>For Calabash tests:</p> <ul> <li> <p>profile: A cucumber profile, for example, &quot;my<em>profile</em>name&quot;.</p> </li> <li> <p>tags: You can limit execution to features or scenarios that have (or don&#39;t have) certain tags, for example, &quot;@smoke&quot; or &quot;@smoke,~@wip&quot;.</p> </li> </ul> <p>For Appium tests (all types):</p> <ul> <li> <p>appium<em>version: The Appium version. Currently supported values are &quot;1.6.5&quot; (and higher), &quot;latest&quot;, and &quot;default&quot;.</p> <ul> <li> <p>“latest” will run the latest Appium version supported by Device Farm (1.9.1).</p> </li> <li> <p>For “default”, Device Farm will choose a compatible version of Appium for the device. The current behavior is to run 1.7.2 on Android devices and iOS 9 and earlier, 1.7.2 for iOS 10 and later.</p> </li> <li> <p>This behavior is subject to change.</p> </li> </ul> </li> </ul> <p>For Fuzz tests (Android only):</p> <ul> <li> <p>event</em>count: The number of events, between 1 and 10000, that the UI fuzz test should perform.</p> </li> <li> <p>throttle: The time, in ms, between 0 and 1000, that the UI fuzz test should wait between events.</p> </li> <li> <p>seed: A seed to use for randomizing the UI fuzz test. Using the same seed value between tests ensures identical event sequences.</p> </li> </ul> <p>For Explorer tests:</p> <ul> <li> <p>username: A username to use if the Explorer encounters a login form. If not supplied, no username will be inserted.</p> </li> <li> <p>password: A password to use if the Explorer encounters a login form. If not supplied, no password will be inserted.</p> </li> </ul> <p>For Instrumentation:</p> <ul> <li> <p>filter: A test filter string. Examples:</p> <ul> <li> <p>Running a single test case: &quot;com.android.abc.Test1&quot;</p> </li> <li> <p>Running a single test: &quot;com.android.abc.Test1#smoke&quot;</p> </li> <li> <p>Running multiple tests: &quot;com.android.abc.Test1,com.android.abc.Test2&quot;</p> </li> </ul> </li> </ul> <p>For XCTest and XCTestUI:</p> <ul> <li> <p>filter: A test filter string. Examples:</p> <ul> <li> <p>Running a single test class: &quot;LoginTests&quot;</p> </li> <li> <p>Running a multiple test classes: &quot;LoginTests,SmokeTests&quot;</p> </li> <li> <p>Running a single test: &quot;LoginTests/testValid&quot;</p> </li> <li> <p>Running multiple tests: &quot;LoginTests/testValid,LoginTests/testInvalid&quot;</p> </li> </ul> </li> </ul> <p>For UIAutomator:</p> <ul> <li> <p>filter: A test filter string. Examples:</p> <ul> <li> <p>Running a single test case: &quot;com.android.abc.Test1&quot;</p> </li> <li> <p>Running a single test: &quot;com.android.abc.Test1#smoke&quot;</p> </li> <li> <p>Running multiple tests: &quot;com.android.abc.Test1,com.android.abc.Test2&quot;</p> </li> </ul> </li> </ul></p>
+    #[serde(rename = "parameters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<::std::collections::HashMap<String, String>>,
+    /// <p>The ARN of the uploaded test that will be run.</p>
+    #[serde(rename = "testPackageArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_package_arn: Option<String>,
     /// <p>The ARN of the YAML-formatted test specification.</p>
     #[serde(rename = "testSpecArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub test_spec_arn: Option<String>,
     /// <p><p>The test&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>BUILTIN<em>FUZZ: The built-in fuzz type.</p> </li> <li> <p>BUILTIN</em>EXPLORER: For Android, an app explorer that will traverse an Android app, interacting with it and capturing screenshots at the same time.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT: The Appium Java JUnit type.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG: The Appium Java TestNG type.</p> </li> <li> <p>APPIUM<em>PYTHON: The Appium Python type.</p> </li> <li> <p>APPIUM</em>NODE: The Appium Node.js type.</p> </li> <li> <p>APPIUM<em>RUBY: The Appium Ruby type.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT: The Appium Java JUnit type for web apps.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG: The Appium Java TestNG type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON: The Appium Python type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE: The Appium Node.js type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY: The Appium Ruby type for web apps.</p> </li> <li> <p>CALABASH: The Calabash type.</p> </li> <li> <p>INSTRUMENTATION: The Instrumentation type.</p> </li> <li> <p>UIAUTOMATION: The uiautomation type.</p> </li> <li> <p>UIAUTOMATOR: The uiautomator type.</p> </li> <li> <p>XCTEST: The Xcode test type.</p> </li> <li> <p>XCTEST</em>UI: The Xcode UI test type.</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: TestType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2443,6 +6506,34 @@ pub struct StopJobRequest {
     pub arn: String,
 }
 
+impl StopJobRequest {
+    /// Returns a builder for `StopJobRequest`, checking its required members when built.
+    pub fn builder() -> StopJobRequestBuilder {
+        StopJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopJobRequest`](struct.StopJobRequest.html).
+#[derive(Default, Debug)]
+pub struct StopJobRequestBuilder {
+    arn: Option<String>,
+}
+
+impl StopJobRequestBuilder {
+    /// Sets the [`arn`](struct.StopJobRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopJobRequest, ::rusoto_core::BuildError> {
+        Ok(StopJobRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopJobResult {
@@ -2460,6 +6551,36 @@ pub struct StopRemoteAccessSessionRequest {
     pub arn: String,
 }
 
+impl StopRemoteAccessSessionRequest {
+    /// Returns a builder for `StopRemoteAccessSessionRequest`, checking its required members when built.
+    pub fn builder() -> StopRemoteAccessSessionRequestBuilder {
+        StopRemoteAccessSessionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopRemoteAccessSessionRequest`](struct.StopRemoteAccessSessionRequest.html).
+#[derive(Default, Debug)]
+pub struct StopRemoteAccessSessionRequestBuilder {
+    arn: Option<String>,
+}
+
+impl StopRemoteAccessSessionRequestBuilder {
+    /// Sets the [`arn`](struct.StopRemoteAccessSessionRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopRemoteAccessSessionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopRemoteAccessSessionRequest, ::rusoto_core::BuildError> {
+        Ok(StopRemoteAccessSessionRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the response from the server that describes the remote access session when AWS Device Farm stops the session.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2478,6 +6599,34 @@ pub struct StopRunRequest {
     pub arn: String,
 }
 
+impl StopRunRequest {
+    /// Returns a builder for `StopRunRequest`, checking its required members when built.
+    pub fn builder() -> StopRunRequestBuilder {
+        StopRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopRunRequest`](struct.StopRunRequest.html).
+#[derive(Default, Debug)]
+pub struct StopRunRequestBuilder {
+    arn: Option<String>,
+}
+
+impl StopRunRequestBuilder {
+    /// Sets the [`arn`](struct.StopRunRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopRunRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopRunRequest, ::rusoto_core::BuildError> {
+        Ok(StopRunRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the results of your stop run attempt.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2502,8 +6651,9 @@ pub struct Suite {
     pub counters: Option<Counters>,
     /// <p>When the suite was created.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>Represents the total (metered or unmetered) minutes used by the test suite.</p>
     #[serde(rename = "deviceMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2519,23 +6669,25 @@ pub struct Suite {
     /// <p><p>The suite&#39;s result.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending condition.</p> </li> <li> <p>PASSED: A passing condition.</p> </li> <li> <p>WARNED: A warning condition.</p> </li> <li> <p>FAILED: A failed condition.</p> </li> <li> <p>SKIPPED: A skipped condition.</p> </li> <li> <p>ERRORED: An error condition.</p> </li> <li> <p>STOPPED: A stopped condition.</p> </li> </ul></p>
     #[serde(rename = "result")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
+    pub result: Option<ExecutionResult>,
     /// <p>The suite's start time.</p>
     #[serde(rename = "started")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started: Option<f64>,
+    pub started: Option<EpochTimestamp>,
     /// <p><p>The suite&#39;s status.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending status.</p> </li> <li> <p>PENDING<em>CONCURRENCY: A pending concurrency status.</p> </li> <li> <p>PENDING</em>DEVICE: A pending device status.</p> </li> <li> <p>PROCESSING: A processing status.</p> </li> <li> <p>SCHEDULING: A scheduling status.</p> </li> <li> <p>PREPARING: A preparing status.</p> </li> <li> <p>RUNNING: A running status.</p> </li> <li> <p>COMPLETED: A completed status.</p> </li> <li> <p>STOPPING: A stopping status.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ExecutionStatus>,
     /// <p>The suite's stop time.</p>
     #[serde(rename = "stopped")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped: Option<f64>,
+    pub stopped: Option<EpochTimestamp>,
     /// <p><p>The suite&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>BUILTIN<em>FUZZ: The built-in fuzz type.</p> </li> <li> <p>BUILTIN</em>EXPLORER: For Android, an app explorer that will traverse an Android app, interacting with it and capturing screenshots at the same time.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT: The Appium Java JUnit type.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG: The Appium Java TestNG type.</p> </li> <li> <p>APPIUM<em>PYTHON: The Appium Python type.</p> </li> <li> <p>APPIUM</em>NODE: The Appium Node.js type.</p> </li> <li> <p>APPIUM<em>RUBY: The Appium Ruby type.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT: The Appium Java JUnit type for web apps.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG: The Appium Java TestNG type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON: The Appium Python type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE: The Appium Node.js type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY: The Appium Ruby type for web apps.</p> </li> <li> <p>CALABASH: The Calabash type.</p> </li> <li> <p>INSTRUMENTATION: The Instrumentation type.</p> </li> <li> <p>UIAUTOMATION: The uiautomation type.</p> </li> <li> <p>UIAUTOMATOR: The uiautomator type.</p> </li> <li> <p>XCTEST: The Xcode test type.</p> </li> <li> <p>XCTEST</em>UI: The Xcode UI test type.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TestType>,
 }
 
 /// <p>The metadata that you apply to a resource to help you categorize and organize it. Each tag consists of a key and an optional value, both of which you define. Tag keys can have a maximum character length of 128 characters, and tag values can have a maximum length of 256 characters. </p>
@@ -2559,6 +6711,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -2577,8 +6769,9 @@ pub struct Test {
     pub counters: Option<Counters>,
     /// <p>When the test was created.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>Represents the total (metered or unmetered) minutes used by the test.</p>
     #[serde(rename = "deviceMinutes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2594,23 +6787,173 @@ pub struct Test {
     /// <p><p>The test&#39;s result.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending condition.</p> </li> <li> <p>PASSED: A passing condition.</p> </li> <li> <p>WARNED: A warning condition.</p> </li> <li> <p>FAILED: A failed condition.</p> </li> <li> <p>SKIPPED: A skipped condition.</p> </li> <li> <p>ERRORED: An error condition.</p> </li> <li> <p>STOPPED: A stopped condition.</p> </li> </ul></p>
     #[serde(rename = "result")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<String>,
+    pub result: Option<ExecutionResult>,
     /// <p>The test's start time.</p>
     #[serde(rename = "started")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started: Option<f64>,
+    pub started: Option<EpochTimestamp>,
     /// <p><p>The test&#39;s status.</p> <p>Allowed values include:</p> <ul> <li> <p>PENDING: A pending status.</p> </li> <li> <p>PENDING<em>CONCURRENCY: A pending concurrency status.</p> </li> <li> <p>PENDING</em>DEVICE: A pending device status.</p> </li> <li> <p>PROCESSING: A processing status.</p> </li> <li> <p>SCHEDULING: A scheduling status.</p> </li> <li> <p>PREPARING: A preparing status.</p> </li> <li> <p>RUNNING: A running status.</p> </li> <li> <p>COMPLETED: A completed status.</p> </li> <li> <p>STOPPING: A stopping status.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ExecutionStatus>,
     /// <p>The test's stop time.</p>
     #[serde(rename = "stopped")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped: Option<f64>,
+    pub stopped: Option<EpochTimestamp>,
     /// <p><p>The test&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>BUILTIN<em>FUZZ: The built-in fuzz type.</p> </li> <li> <p>BUILTIN</em>EXPLORER: For Android, an app explorer that will traverse an Android app, interacting with it and capturing screenshots at the same time.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT: The Appium Java JUnit type.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG: The Appium Java TestNG type.</p> </li> <li> <p>APPIUM<em>PYTHON: The Appium Python type.</p> </li> <li> <p>APPIUM</em>NODE: The Appium Node.js type.</p> </li> <li> <p>APPIUM<em>RUBY: The Appium Ruby type.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT: The Appium Java JUnit type for web apps.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG: The Appium Java TestNG type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON: The Appium Python type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE: The Appium Node.js type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY: The Appium Ruby type for web apps.</p> </li> <li> <p>CALABASH: The Calabash type.</p> </li> <li> <p>INSTRUMENTATION: The Instrumentation type.</p> </li> <li> <p>UIAUTOMATION: The uiautomation type.</p> </li> <li> <p>UIAUTOMATOR: The uiautomator type.</p> </li> <li> <p>XCTEST: The Xcode test type.</p> </li> <li> <p>XCTEST</em>UI: The Xcode UI test type.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TestType>,
+}
+
+/// <p><p>The test type for the specified device pool.</p> <p>Allowed values include the following:</p> <ul> <li> <p>BUILTIN<em>FUZZ: The built-in fuzz type.</p> </li> <li> <p>BUILTIN</em>EXPLORER: For Android, an app explorer that will traverse an Android app, interacting with it and capturing screenshots at the same time.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT: The Appium Java JUnit type.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG: The Appium Java TestNG type.</p> </li> <li> <p>APPIUM<em>PYTHON: The Appium Python type.</p> </li> <li> <p>APPIUM</em>NODE: The Appium Node.js type.</p> </li> <li> <p>APPIUM<em>RUBY: The Appium Ruby type.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT: The Appium Java JUnit type for web apps.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG: The Appium Java TestNG type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON: The Appium Python type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE: The Appium Node.js type for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY: The Appium Ruby type for web apps.</p> </li> <li> <p>CALABASH: The Calabash type.</p> </li> <li> <p>INSTRUMENTATION: The Instrumentation type.</p> </li> <li> <p>UIAUTOMATION: The uiautomation type.</p> </li> <li> <p>UIAUTOMATOR: The uiautomator type.</p> </li> <li> <p>XCTEST: The Xcode test type.</p> </li> <li> <p>XCTEST</em>UI: The Xcode UI test type.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TestType {
+    /// <p><code>BUILTIN_FUZZ</code></p>
+    BuiltinFuzz,
+    /// <p><code>BUILTIN_EXPLORER</code></p>
+    BuiltinExplorer,
+    /// <p><code>WEB_PERFORMANCE_PROFILE</code></p>
+    WebPerformanceProfile,
+    /// <p><code>APPIUM_JAVA_JUNIT</code></p>
+    AppiumJavaJunit,
+    /// <p><code>APPIUM_JAVA_TESTNG</code></p>
+    AppiumJavaTestng,
+    /// <p><code>APPIUM_PYTHON</code></p>
+    AppiumPython,
+    /// <p><code>APPIUM_NODE</code></p>
+    AppiumNode,
+    /// <p><code>APPIUM_RUBY</code></p>
+    AppiumRuby,
+    /// <p><code>APPIUM_WEB_JAVA_JUNIT</code></p>
+    AppiumWebJavaJunit,
+    /// <p><code>APPIUM_WEB_JAVA_TESTNG</code></p>
+    AppiumWebJavaTestng,
+    /// <p><code>APPIUM_WEB_PYTHON</code></p>
+    AppiumWebPython,
+    /// <p><code>APPIUM_WEB_NODE</code></p>
+    AppiumWebNode,
+    /// <p><code>APPIUM_WEB_RUBY</code></p>
+    AppiumWebRuby,
+    /// <p><code>CALABASH</code></p>
+    Calabash,
+    /// <p><code>INSTRUMENTATION</code></p>
+    Instrumentation,
+    /// <p><code>UIAUTOMATION</code></p>
+    Uiautomation,
+    /// <p><code>UIAUTOMATOR</code></p>
+    Uiautomator,
+    /// <p><code>XCTEST</code></p>
+    Xctest,
+    /// <p><code>XCTEST_UI</code></p>
+    XctestUi,
+    /// <p><code>REMOTE_ACCESS_RECORD</code></p>
+    RemoteAccessRecord,
+    /// <p><code>REMOTE_ACCESS_REPLAY</code></p>
+    RemoteAccessReplay,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TestType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TestType::BuiltinFuzz => "BUILTIN_FUZZ",
+            TestType::BuiltinExplorer => "BUILTIN_EXPLORER",
+            TestType::WebPerformanceProfile => "WEB_PERFORMANCE_PROFILE",
+            TestType::AppiumJavaJunit => "APPIUM_JAVA_JUNIT",
+            TestType::AppiumJavaTestng => "APPIUM_JAVA_TESTNG",
+            TestType::AppiumPython => "APPIUM_PYTHON",
+            TestType::AppiumNode => "APPIUM_NODE",
+            TestType::AppiumRuby => "APPIUM_RUBY",
+            TestType::AppiumWebJavaJunit => "APPIUM_WEB_JAVA_JUNIT",
+            TestType::AppiumWebJavaTestng => "APPIUM_WEB_JAVA_TESTNG",
+            TestType::AppiumWebPython => "APPIUM_WEB_PYTHON",
+            TestType::AppiumWebNode => "APPIUM_WEB_NODE",
+            TestType::AppiumWebRuby => "APPIUM_WEB_RUBY",
+            TestType::Calabash => "CALABASH",
+            TestType::Instrumentation => "INSTRUMENTATION",
+            TestType::Uiautomation => "UIAUTOMATION",
+            TestType::Uiautomator => "UIAUTOMATOR",
+            TestType::Xctest => "XCTEST",
+            TestType::XctestUi => "XCTEST_UI",
+            TestType::RemoteAccessRecord => "REMOTE_ACCESS_RECORD",
+            TestType::RemoteAccessReplay => "REMOTE_ACCESS_REPLAY",
+            TestType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TestType {
+    fn from(value: &'a str) -> TestType {
+        match value {
+            "BUILTIN_FUZZ" => TestType::BuiltinFuzz,
+            "BUILTIN_EXPLORER" => TestType::BuiltinExplorer,
+            "WEB_PERFORMANCE_PROFILE" => TestType::WebPerformanceProfile,
+            "APPIUM_JAVA_JUNIT" => TestType::AppiumJavaJunit,
+            "APPIUM_JAVA_TESTNG" => TestType::AppiumJavaTestng,
+            "APPIUM_PYTHON" => TestType::AppiumPython,
+            "APPIUM_NODE" => TestType::AppiumNode,
+            "APPIUM_RUBY" => TestType::AppiumRuby,
+            "APPIUM_WEB_JAVA_JUNIT" => TestType::AppiumWebJavaJunit,
+            "APPIUM_WEB_JAVA_TESTNG" => TestType::AppiumWebJavaTestng,
+            "APPIUM_WEB_PYTHON" => TestType::AppiumWebPython,
+            "APPIUM_WEB_NODE" => TestType::AppiumWebNode,
+            "APPIUM_WEB_RUBY" => TestType::AppiumWebRuby,
+            "CALABASH" => TestType::Calabash,
+            "INSTRUMENTATION" => TestType::Instrumentation,
+            "UIAUTOMATION" => TestType::Uiautomation,
+            "UIAUTOMATOR" => TestType::Uiautomator,
+            "XCTEST" => TestType::Xctest,
+            "XCTEST_UI" => TestType::XctestUi,
+            "REMOTE_ACCESS_RECORD" => TestType::RemoteAccessRecord,
+            "REMOTE_ACCESS_REPLAY" => TestType::RemoteAccessReplay,
+            _ => TestType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TestType {
+    fn from(value: String) -> TestType {
+        TestType::from(value.as_str())
+    }
+}
+
+impl From<TestType> for String {
+    fn from(value: TestType) -> String {
+        match value {
+            TestType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TestType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TestType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TestType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TestType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TestType::from(value))
+    }
 }
 
 /// <p>Represents information about free trial device minutes for an AWS account.</p>
@@ -2651,6 +6994,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -2670,6 +7053,52 @@ pub struct UpdateDeviceInstanceRequest {
     pub profile_arn: Option<String>,
 }
 
+impl UpdateDeviceInstanceRequest {
+    /// Returns a builder for `UpdateDeviceInstanceRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDeviceInstanceRequestBuilder {
+        UpdateDeviceInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDeviceInstanceRequest`](struct.UpdateDeviceInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDeviceInstanceRequestBuilder {
+    arn: Option<String>,
+    labels: Option<Vec<String>>,
+    profile_arn: Option<String>,
+}
+
+impl UpdateDeviceInstanceRequestBuilder {
+    /// Sets the [`arn`](struct.UpdateDeviceInstanceRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`labels`](struct.UpdateDeviceInstanceRequest.html#structfield.labels) member.
+    pub fn labels<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.labels = Some(value.into());
+        self
+    }
+
+    /// Sets the [`profile_arn`](struct.UpdateDeviceInstanceRequest.html#structfield.profile_arn) member.
+    pub fn profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.profile_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDeviceInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDeviceInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDeviceInstanceRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            labels: self.labels,
+            profile_arn: self.profile_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDeviceInstanceResult {
@@ -2707,6 +7136,76 @@ pub struct UpdateDevicePoolRequest {
     pub rules: Option<Vec<Rule>>,
 }
 
+impl UpdateDevicePoolRequest {
+    /// Returns a builder for `UpdateDevicePoolRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDevicePoolRequestBuilder {
+        UpdateDevicePoolRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDevicePoolRequest`](struct.UpdateDevicePoolRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDevicePoolRequestBuilder {
+    arn: Option<String>,
+    clear_max_devices: Option<bool>,
+    description: Option<String>,
+    max_devices: Option<i64>,
+    name: Option<String>,
+    rules: Option<Vec<Rule>>,
+}
+
+impl UpdateDevicePoolRequestBuilder {
+    /// Sets the [`arn`](struct.UpdateDevicePoolRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`clear_max_devices`](struct.UpdateDevicePoolRequest.html#structfield.clear_max_devices) member.
+    pub fn clear_max_devices<T: Into<bool>>(mut self, value: T) -> Self {
+        self.clear_max_devices = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateDevicePoolRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_devices`](struct.UpdateDevicePoolRequest.html#structfield.max_devices) member.
+    pub fn max_devices<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_devices = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateDevicePoolRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rules`](struct.UpdateDevicePoolRequest.html#structfield.rules) member.
+    pub fn rules<T: Into<Vec<Rule>>>(mut self, value: T) -> Self {
+        self.rules = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDevicePoolRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDevicePoolRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDevicePoolRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            clear_max_devices: self.clear_max_devices,
+            description: self.description,
+            max_devices: self.max_devices,
+            name: self.name,
+            rules: self.rules,
+        })
+    }
+}
+
 /// <p>Represents the result of an update device pool request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2744,6 +7243,76 @@ pub struct UpdateInstanceProfileRequest {
     pub reboot_after_use: Option<bool>,
 }
 
+impl UpdateInstanceProfileRequest {
+    /// Returns a builder for `UpdateInstanceProfileRequest`, checking its required members when built.
+    pub fn builder() -> UpdateInstanceProfileRequestBuilder {
+        UpdateInstanceProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateInstanceProfileRequest`](struct.UpdateInstanceProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateInstanceProfileRequestBuilder {
+    arn: Option<String>,
+    description: Option<String>,
+    exclude_app_packages_from_cleanup: Option<Vec<String>>,
+    name: Option<String>,
+    package_cleanup: Option<bool>,
+    reboot_after_use: Option<bool>,
+}
+
+impl UpdateInstanceProfileRequestBuilder {
+    /// Sets the [`arn`](struct.UpdateInstanceProfileRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateInstanceProfileRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exclude_app_packages_from_cleanup`](struct.UpdateInstanceProfileRequest.html#structfield.exclude_app_packages_from_cleanup) member.
+    pub fn exclude_app_packages_from_cleanup<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.exclude_app_packages_from_cleanup = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateInstanceProfileRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`package_cleanup`](struct.UpdateInstanceProfileRequest.html#structfield.package_cleanup) member.
+    pub fn package_cleanup<T: Into<bool>>(mut self, value: T) -> Self {
+        self.package_cleanup = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reboot_after_use`](struct.UpdateInstanceProfileRequest.html#structfield.reboot_after_use) member.
+    pub fn reboot_after_use<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reboot_after_use = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateInstanceProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateInstanceProfileRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateInstanceProfileRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            description: self.description,
+            exclude_app_packages_from_cleanup: self.exclude_app_packages_from_cleanup,
+            name: self.name,
+            package_cleanup: self.package_cleanup,
+            reboot_after_use: self.reboot_after_use,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateInstanceProfileResult {
@@ -2785,7 +7354,7 @@ pub struct UpdateNetworkProfileRequest {
     /// <p>The type of network profile you wish to return information about. Valid values are listed below.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NetworkProfileType>,
     /// <p>The data throughput rate in bits per second, as an integer from 0 to 104857600.</p>
     #[serde(rename = "uplinkBandwidthBits")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2804,6 +7373,124 @@ pub struct UpdateNetworkProfileRequest {
     pub uplink_loss_percent: Option<i64>,
 }
 
+impl UpdateNetworkProfileRequest {
+    /// Returns a builder for `UpdateNetworkProfileRequest`, checking its required members when built.
+    pub fn builder() -> UpdateNetworkProfileRequestBuilder {
+        UpdateNetworkProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateNetworkProfileRequest`](struct.UpdateNetworkProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateNetworkProfileRequestBuilder {
+    arn: Option<String>,
+    description: Option<String>,
+    downlink_bandwidth_bits: Option<i64>,
+    downlink_delay_ms: Option<i64>,
+    downlink_jitter_ms: Option<i64>,
+    downlink_loss_percent: Option<i64>,
+    name: Option<String>,
+    type_: Option<NetworkProfileType>,
+    uplink_bandwidth_bits: Option<i64>,
+    uplink_delay_ms: Option<i64>,
+    uplink_jitter_ms: Option<i64>,
+    uplink_loss_percent: Option<i64>,
+}
+
+impl UpdateNetworkProfileRequestBuilder {
+    /// Sets the [`arn`](struct.UpdateNetworkProfileRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateNetworkProfileRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`downlink_bandwidth_bits`](struct.UpdateNetworkProfileRequest.html#structfield.downlink_bandwidth_bits) member.
+    pub fn downlink_bandwidth_bits<T: Into<i64>>(mut self, value: T) -> Self {
+        self.downlink_bandwidth_bits = Some(value.into());
+        self
+    }
+
+    /// Sets the [`downlink_delay_ms`](struct.UpdateNetworkProfileRequest.html#structfield.downlink_delay_ms) member.
+    pub fn downlink_delay_ms<T: Into<i64>>(mut self, value: T) -> Self {
+        self.downlink_delay_ms = Some(value.into());
+        self
+    }
+
+    /// Sets the [`downlink_jitter_ms`](struct.UpdateNetworkProfileRequest.html#structfield.downlink_jitter_ms) member.
+    pub fn downlink_jitter_ms<T: Into<i64>>(mut self, value: T) -> Self {
+        self.downlink_jitter_ms = Some(value.into());
+        self
+    }
+
+    /// Sets the [`downlink_loss_percent`](struct.UpdateNetworkProfileRequest.html#structfield.downlink_loss_percent) member.
+    pub fn downlink_loss_percent<T: Into<i64>>(mut self, value: T) -> Self {
+        self.downlink_loss_percent = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateNetworkProfileRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.UpdateNetworkProfileRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<NetworkProfileType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets the [`uplink_bandwidth_bits`](struct.UpdateNetworkProfileRequest.html#structfield.uplink_bandwidth_bits) member.
+    pub fn uplink_bandwidth_bits<T: Into<i64>>(mut self, value: T) -> Self {
+        self.uplink_bandwidth_bits = Some(value.into());
+        self
+    }
+
+    /// Sets the [`uplink_delay_ms`](struct.UpdateNetworkProfileRequest.html#structfield.uplink_delay_ms) member.
+    pub fn uplink_delay_ms<T: Into<i64>>(mut self, value: T) -> Self {
+        self.uplink_delay_ms = Some(value.into());
+        self
+    }
+
+    /// Sets the [`uplink_jitter_ms`](struct.UpdateNetworkProfileRequest.html#structfield.uplink_jitter_ms) member.
+    pub fn uplink_jitter_ms<T: Into<i64>>(mut self, value: T) -> Self {
+        self.uplink_jitter_ms = Some(value.into());
+        self
+    }
+
+    /// Sets the [`uplink_loss_percent`](struct.UpdateNetworkProfileRequest.html#structfield.uplink_loss_percent) member.
+    pub fn uplink_loss_percent<T: Into<i64>>(mut self, value: T) -> Self {
+        self.uplink_loss_percent = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateNetworkProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateNetworkProfileRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateNetworkProfileRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            description: self.description,
+            downlink_bandwidth_bits: self.downlink_bandwidth_bits,
+            downlink_delay_ms: self.downlink_delay_ms,
+            downlink_jitter_ms: self.downlink_jitter_ms,
+            downlink_loss_percent: self.downlink_loss_percent,
+            name: self.name,
+            type_: self.type_,
+            uplink_bandwidth_bits: self.uplink_bandwidth_bits,
+            uplink_delay_ms: self.uplink_delay_ms,
+            uplink_jitter_ms: self.uplink_jitter_ms,
+            uplink_loss_percent: self.uplink_loss_percent,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateNetworkProfileResult {
@@ -2829,6 +7516,50 @@ pub struct UpdateProjectRequest {
     pub name: Option<String>,
 }
 
+impl UpdateProjectRequest {
+    /// Returns a builder for `UpdateProjectRequest`, checking its required members when built.
+    pub fn builder() -> UpdateProjectRequestBuilder {
+        UpdateProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateProjectRequest`](struct.UpdateProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateProjectRequestBuilder {
+    arn: Option<String>,
+    default_job_timeout_minutes: Option<i64>,
+    name: Option<String>,
+}
+
+impl UpdateProjectRequestBuilder {
+    /// Sets the [`arn`](struct.UpdateProjectRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_job_timeout_minutes`](struct.UpdateProjectRequest.html#structfield.default_job_timeout_minutes) member.
+    pub fn default_job_timeout_minutes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.default_job_timeout_minutes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateProjectRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateProjectRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateProjectRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            default_job_timeout_minutes: self.default_job_timeout_minutes,
+            name: self.name,
+        })
+    }
+}
+
 /// <p>Represents the result of an update project request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2858,6 +7589,58 @@ pub struct UpdateUploadRequest {
     pub name: Option<String>,
 }
 
+impl UpdateUploadRequest {
+    /// Returns a builder for `UpdateUploadRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUploadRequestBuilder {
+        UpdateUploadRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUploadRequest`](struct.UpdateUploadRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUploadRequestBuilder {
+    arn: Option<String>,
+    content_type: Option<String>,
+    edit_content: Option<bool>,
+    name: Option<String>,
+}
+
+impl UpdateUploadRequestBuilder {
+    /// Sets the [`arn`](struct.UpdateUploadRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_type`](struct.UpdateUploadRequest.html#structfield.content_type) member.
+    pub fn content_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`edit_content`](struct.UpdateUploadRequest.html#structfield.edit_content) member.
+    pub fn edit_content<T: Into<bool>>(mut self, value: T) -> Self {
+        self.edit_content = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateUploadRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUploadRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateUploadRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUploadRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            content_type: self.content_type,
+            edit_content: self.edit_content,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateUploadResult {
@@ -2890,35 +7673,98 @@ pub struct UpdateVPCEConfigurationRequest {
     pub vpce_service_name: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateVPCEConfigurationResult {
-    /// <p>An object containing information about your VPC endpoint configuration.</p>
-    #[serde(rename = "vpceConfiguration")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub vpce_configuration: Option<VPCEConfiguration>,
+impl UpdateVPCEConfigurationRequest {
+    /// Returns a builder for `UpdateVPCEConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateVPCEConfigurationRequestBuilder {
+        UpdateVPCEConfigurationRequestBuilder::default()
+    }
 }
 
-/// <p>An app or a set of one or more tests to upload or that have been uploaded.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Upload {
-    /// <p>The upload's ARN.</p>
-    #[serde(rename = "arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
+/// Builds a [`UpdateVPCEConfigurationRequest`](struct.UpdateVPCEConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateVPCEConfigurationRequestBuilder {
+    arn: Option<String>,
+    service_dns_name: Option<String>,
+    vpce_configuration_description: Option<String>,
+    vpce_configuration_name: Option<String>,
+    vpce_service_name: Option<String>,
+}
+
+impl UpdateVPCEConfigurationRequestBuilder {
+    /// Sets the [`arn`](struct.UpdateVPCEConfigurationRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_dns_name`](struct.UpdateVPCEConfigurationRequest.html#structfield.service_dns_name) member.
+    pub fn service_dns_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_dns_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpce_configuration_description`](struct.UpdateVPCEConfigurationRequest.html#structfield.vpce_configuration_description) member.
+    pub fn vpce_configuration_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpce_configuration_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpce_configuration_name`](struct.UpdateVPCEConfigurationRequest.html#structfield.vpce_configuration_name) member.
+    pub fn vpce_configuration_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpce_configuration_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpce_service_name`](struct.UpdateVPCEConfigurationRequest.html#structfield.vpce_service_name) member.
+    pub fn vpce_service_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpce_service_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateVPCEConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateVPCEConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateVPCEConfigurationRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            service_dns_name: self.service_dns_name,
+            vpce_configuration_description: self.vpce_configuration_description,
+            vpce_configuration_name: self.vpce_configuration_name,
+            vpce_service_name: self.vpce_service_name,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateVPCEConfigurationResult {
+    /// <p>An object containing information about your VPC endpoint configuration.</p>
+    #[serde(rename = "vpceConfiguration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vpce_configuration: Option<VPCEConfiguration>,
+}
+
+/// <p>An app or a set of one or more tests to upload or that have been uploaded.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Upload {
+    /// <p>The upload's ARN.</p>
+    #[serde(rename = "arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
     /// <p><p>The upload&#39;s category. Allowed values include:</p> <ul> <li> <p>CURATED: An upload managed by AWS Device Farm.</p> </li> <li> <p>PRIVATE: An upload managed by the AWS Device Farm customer.</p> </li> </ul></p>
     #[serde(rename = "category")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub category: Option<String>,
+    pub category: Option<UploadCategory>,
     /// <p>The upload's content type (for example, "application/octet-stream").</p>
     #[serde(rename = "contentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
     /// <p>When the upload was created.</p>
     #[serde(rename = "created")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>A message about the upload's result.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2934,17 +7780,361 @@ pub struct Upload {
     /// <p><p>The upload&#39;s status.</p> <p>Must be one of the following values:</p> <ul> <li> <p>FAILED: A failed status.</p> </li> <li> <p>INITIALIZED: An initialized status.</p> </li> <li> <p>PROCESSING: A processing status.</p> </li> <li> <p>SUCCEEDED: A succeeded status.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<UploadStatus>,
     /// <p><p>The upload&#39;s type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>ANDROID<em>APP: An Android upload.</p> </li> <li> <p>IOS</em>APP: An iOS upload.</p> </li> <li> <p>WEB<em>APP: A web application upload.</p> </li> <li> <p>EXTERNAL</em>DATA: An external data upload.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT<em>TEST</em>PACKAGE: An Appium Java JUnit test package upload.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG<em>TEST</em>PACKAGE: An Appium Java TestNG test package upload.</p> </li> <li> <p>APPIUM<em>PYTHON</em>TEST<em>PACKAGE: An Appium Python test package upload.</p> </li> <li> <p>APPIUM</em>NODE<em>TEST</em>PACKAGE: An Appium Node.js test package upload.</p> </li> <li> <p>APPIUM<em>RUBY</em>TEST<em>PACKAGE: An Appium Ruby test package upload.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT<em>TEST</em>PACKAGE: An Appium Java JUnit test package upload for web apps.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</em>TEST<em>PACKAGE: An Appium Java TestNG test package upload for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</em>TEST<em>PACKAGE: An Appium Python test package upload for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</em>TEST<em>PACKAGE: An Appium Node.js test package upload for web apps.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</em>TEST<em>PACKAGE: An Appium Ruby test package upload for web apps.</p> </li> <li> <p>CALABASH</em>TEST<em>PACKAGE: A Calabash test package upload.</p> </li> <li> <p>INSTRUMENTATION</em>TEST<em>PACKAGE: An instrumentation upload.</p> </li> <li> <p>UIAUTOMATION</em>TEST<em>PACKAGE: A uiautomation test package upload.</p> </li> <li> <p>UIAUTOMATOR</em>TEST<em>PACKAGE: A uiautomator test package upload.</p> </li> <li> <p>XCTEST</em>TEST<em>PACKAGE: An Xcode test package upload.</p> </li> <li> <p>XCTEST</em>UI<em>TEST</em>PACKAGE: An Xcode UI test package upload.</p> </li> <li> <p>APPIUM<em>JAVA</em>JUNIT<em>TEST</em>SPEC: An Appium Java JUnit test spec upload.</p> </li> <li> <p>APPIUM<em>JAVA</em>TESTNG<em>TEST</em>SPEC: An Appium Java TestNG test spec upload.</p> </li> <li> <p>APPIUM<em>PYTHON</em>TEST<em>SPEC: An Appium Python test spec upload.</p> </li> <li> <p>APPIUM</em>NODE<em>TEST</em>SPEC: An Appium Node.js test spec upload.</p> </li> <li> <p>APPIUM<em>RUBY</em>TEST<em>SPEC: An Appium Ruby test spec upload.</p> </li> <li> <p>APPIUM</em>WEB<em>JAVA</em>JUNIT<em>TEST</em>SPEC: An Appium Java JUnit test spec upload for a web app.</p> </li> <li> <p>APPIUM<em>WEB</em>JAVA<em>TESTNG</em>TEST<em>SPEC: An Appium Java TestNG test spec upload for a web app.</p> </li> <li> <p>APPIUM</em>WEB<em>PYTHON</em>TEST<em>SPEC: An Appium Python test spec upload for a web app.</p> </li> <li> <p>APPIUM</em>WEB<em>NODE</em>TEST<em>SPEC: An Appium Node.js test spec upload for a web app.</p> </li> <li> <p>APPIUM</em>WEB<em>RUBY</em>TEST<em>SPEC: An Appium Ruby test spec upload for a web app.</p> </li> <li> <p>INSTRUMENTATION</em>TEST<em>SPEC: An instrumentation test spec upload.</p> </li> <li> <p>XCTEST</em>UI<em>TEST</em>SPEC: An Xcode UI test spec upload.</p> </li> </ul></p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<UploadType>,
     /// <p>The pre-signed Amazon S3 URL that was used to store a file through a corresponding PUT request.</p>
     #[serde(rename = "url")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
 
+/// <p><p>The upload&#39;s category. Allowed values include:</p> <ul> <li> <p>CURATED: An upload managed by AWS Device Farm.</p> </li> <li> <p>PRIVATE: An upload managed by the AWS Device Farm customer.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UploadCategory {
+    /// <p><code>CURATED</code></p>
+    Curated,
+    /// <p><code>PRIVATE</code></p>
+    Private,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UploadCategory {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UploadCategory::Curated => "CURATED",
+            UploadCategory::Private => "PRIVATE",
+            UploadCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UploadCategory {
+    fn from(value: &'a str) -> UploadCategory {
+        match value {
+            "CURATED" => UploadCategory::Curated,
+            "PRIVATE" => UploadCategory::Private,
+            _ => UploadCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UploadCategory {
+    fn from(value: String) -> UploadCategory {
+        UploadCategory::from(value.as_str())
+    }
+}
+
+impl From<UploadCategory> for String {
+    fn from(value: UploadCategory) -> String {
+        match value {
+            UploadCategory::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UploadCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UploadCategory {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UploadCategory {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UploadCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UploadCategory::from(value))
+    }
+}
+
+/// <p><p>The upload&#39;s status.</p> <p>Must be one of the following values:</p> <ul> <li> <p>FAILED: A failed status.</p> </li> <li> <p>INITIALIZED: An initialized status.</p> </li> <li> <p>PROCESSING: A processing status.</p> </li> <li> <p>SUCCEEDED: A succeeded status.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UploadStatus {
+    /// <p><code>INITIALIZED</code></p>
+    Initialized,
+    /// <p><code>PROCESSING</code></p>
+    Processing,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UploadStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UploadStatus::Initialized => "INITIALIZED",
+            UploadStatus::Processing => "PROCESSING",
+            UploadStatus::Succeeded => "SUCCEEDED",
+            UploadStatus::Failed => "FAILED",
+            UploadStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UploadStatus {
+    fn from(value: &'a str) -> UploadStatus {
+        match value {
+            "INITIALIZED" => UploadStatus::Initialized,
+            "PROCESSING" => UploadStatus::Processing,
+            "SUCCEEDED" => UploadStatus::Succeeded,
+            "FAILED" => UploadStatus::Failed,
+            _ => UploadStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UploadStatus {
+    fn from(value: String) -> UploadStatus {
+        UploadStatus::from(value.as_str())
+    }
+}
+
+impl From<UploadStatus> for String {
+    fn from(value: UploadStatus) -> String {
+        match value {
+            UploadStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UploadStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UploadStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UploadStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UploadStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UploadStatus::from(value))
+    }
+}
+
+/// <p>The upload's upload type.</p> <p>Must be one of the following values:</p> <ul> <li> <p>ANDROID_APP: An Android upload.</p> </li> <li> <p>IOS_APP: An iOS upload.</p> </li> <li> <p>WEB_APP: A web application upload.</p> </li> <li> <p>EXTERNAL_DATA: An external data upload.</p> </li> <li> <p>APPIUM_JAVA_JUNIT_TEST_PACKAGE: An Appium Java JUnit test package upload.</p> </li> <li> <p>APPIUM_JAVA_TESTNG_TEST_PACKAGE: An Appium Java TestNG test package upload.</p> </li> <li> <p>APPIUM_PYTHON_TEST_PACKAGE: An Appium Python test package upload.</p> </li> <li> <p>APPIUM_NODE_TEST_PACKAGE: An Appium Node.js test package upload.</p> </li> <li> <p>APPIUM_RUBY_TEST_PACKAGE: An Appium Ruby test package upload.</p> </li> <li> <p>APPIUM_WEB_JAVA_JUNIT_TEST_PACKAGE: An Appium Java JUnit test package upload for a web app.</p> </li> <li> <p>APPIUM_WEB_JAVA_TESTNG_TEST_PACKAGE: An Appium Java TestNG test package upload for a web app.</p> </li> <li> <p>APPIUM_WEB_PYTHON_TEST_PACKAGE: An Appium Python test package upload for a web app.</p> </li> <li> <p>APPIUM_WEB_NODE_TEST_PACKAGE: An Appium Node.js test package upload for a web app.</p> </li> <li> <p>APPIUM_WEB_RUBY_TEST_PACKAGE: An Appium Ruby test package upload for a web app.</p> </li> <li> <p>CALABASH_TEST_PACKAGE: A Calabash test package upload.</p> </li> <li> <p>INSTRUMENTATION_TEST_PACKAGE: An instrumentation upload.</p> </li> <li> <p>UIAUTOMATION_TEST_PACKAGE: A uiautomation test package upload.</p> </li> <li> <p>UIAUTOMATOR_TEST_PACKAGE: A uiautomator test package upload.</p> </li> <li> <p>XCTEST_TEST_PACKAGE: An Xcode test package upload.</p> </li> <li> <p>XCTEST_UI_TEST_PACKAGE: An Xcode UI test package upload.</p> </li> <li> <p>APPIUM_JAVA_JUNIT_TEST_SPEC: An Appium Java JUnit test spec upload.</p> </li> <li> <p>APPIUM_JAVA_TESTNG_TEST_SPEC: An Appium Java TestNG test spec upload.</p> </li> <li> <p>APPIUM_PYTHON_TEST_SPEC: An Appium Python test spec upload.</p> </li> <li> <p>APPIUM_NODE_TEST_SPEC: An Appium Node.js test spec upload.</p> </li> <li> <p>APPIUM_RUBY_TEST_SPEC: An Appium Ruby test spec upload.</p> </li> <li> <p>APPIUM_WEB_JAVA_JUNIT_TEST_SPEC: An Appium Java JUnit test spec upload for a web app.</p> </li> <li> <p>APPIUM_WEB_JAVA_TESTNG_TEST_SPEC: An Appium Java TestNG test spec upload for a web app.</p> </li> <li> <p>APPIUM_WEB_PYTHON_TEST_SPEC: An Appium Python test spec upload for a web app.</p> </li> <li> <p>APPIUM_WEB_NODE_TEST_SPEC: An Appium Node.js test spec upload for a web app.</p> </li> <li> <p>APPIUM_WEB_RUBY_TEST_SPEC: An Appium Ruby test spec upload for a web app.</p> </li> <li> <p>INSTRUMENTATION_TEST_SPEC: An instrumentation test spec upload.</p> </li> <li> <p>XCTEST_UI_TEST_SPEC: An Xcode UI test spec upload.</p> </li> </ul> <p> <b>Note</b> If you call <code>CreateUpload</code> with <code>WEB_APP</code> specified, AWS Device Farm throws an <code>ArgumentException</code> error.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UploadType {
+    /// <p><code>ANDROID_APP</code></p>
+    AndroidApp,
+    /// <p><code>IOS_APP</code></p>
+    IosApp,
+    /// <p><code>WEB_APP</code></p>
+    WebApp,
+    /// <p><code>EXTERNAL_DATA</code></p>
+    ExternalData,
+    /// <p><code>APPIUM_JAVA_JUNIT_TEST_PACKAGE</code></p>
+    AppiumJavaJunitTestPackage,
+    /// <p><code>APPIUM_JAVA_TESTNG_TEST_PACKAGE</code></p>
+    AppiumJavaTestngTestPackage,
+    /// <p><code>APPIUM_PYTHON_TEST_PACKAGE</code></p>
+    AppiumPythonTestPackage,
+    /// <p><code>APPIUM_NODE_TEST_PACKAGE</code></p>
+    AppiumNodeTestPackage,
+    /// <p><code>APPIUM_RUBY_TEST_PACKAGE</code></p>
+    AppiumRubyTestPackage,
+    /// <p><code>APPIUM_WEB_JAVA_JUNIT_TEST_PACKAGE</code></p>
+    AppiumWebJavaJunitTestPackage,
+    /// <p><code>APPIUM_WEB_JAVA_TESTNG_TEST_PACKAGE</code></p>
+    AppiumWebJavaTestngTestPackage,
+    /// <p><code>APPIUM_WEB_PYTHON_TEST_PACKAGE</code></p>
+    AppiumWebPythonTestPackage,
+    /// <p><code>APPIUM_WEB_NODE_TEST_PACKAGE</code></p>
+    AppiumWebNodeTestPackage,
+    /// <p><code>APPIUM_WEB_RUBY_TEST_PACKAGE</code></p>
+    AppiumWebRubyTestPackage,
+    /// <p><code>CALABASH_TEST_PACKAGE</code></p>
+    CalabashTestPackage,
+    /// <p><code>INSTRUMENTATION_TEST_PACKAGE</code></p>
+    InstrumentationTestPackage,
+    /// <p><code>UIAUTOMATION_TEST_PACKAGE</code></p>
+    UiautomationTestPackage,
+    /// <p><code>UIAUTOMATOR_TEST_PACKAGE</code></p>
+    UiautomatorTestPackage,
+    /// <p><code>XCTEST_TEST_PACKAGE</code></p>
+    XctestTestPackage,
+    /// <p><code>XCTEST_UI_TEST_PACKAGE</code></p>
+    XctestUiTestPackage,
+    /// <p><code>APPIUM_JAVA_JUNIT_TEST_SPEC</code></p>
+    AppiumJavaJunitTestSpec,
+    /// <p><code>APPIUM_JAVA_TESTNG_TEST_SPEC</code></p>
+    AppiumJavaTestngTestSpec,
+    /// <p><code>APPIUM_PYTHON_TEST_SPEC</code></p>
+    AppiumPythonTestSpec,
+    /// <p><code>APPIUM_NODE_TEST_SPEC</code></p>
+    AppiumNodeTestSpec,
+    /// <p><code>APPIUM_RUBY_TEST_SPEC</code></p>
+    AppiumRubyTestSpec,
+    /// <p><code>APPIUM_WEB_JAVA_JUNIT_TEST_SPEC</code></p>
+    AppiumWebJavaJunitTestSpec,
+    /// <p><code>APPIUM_WEB_JAVA_TESTNG_TEST_SPEC</code></p>
+    AppiumWebJavaTestngTestSpec,
+    /// <p><code>APPIUM_WEB_PYTHON_TEST_SPEC</code></p>
+    AppiumWebPythonTestSpec,
+    /// <p><code>APPIUM_WEB_NODE_TEST_SPEC</code></p>
+    AppiumWebNodeTestSpec,
+    /// <p><code>APPIUM_WEB_RUBY_TEST_SPEC</code></p>
+    AppiumWebRubyTestSpec,
+    /// <p><code>INSTRUMENTATION_TEST_SPEC</code></p>
+    InstrumentationTestSpec,
+    /// <p><code>XCTEST_UI_TEST_SPEC</code></p>
+    XctestUiTestSpec,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UploadType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UploadType::AndroidApp => "ANDROID_APP",
+            UploadType::IosApp => "IOS_APP",
+            UploadType::WebApp => "WEB_APP",
+            UploadType::ExternalData => "EXTERNAL_DATA",
+            UploadType::AppiumJavaJunitTestPackage => "APPIUM_JAVA_JUNIT_TEST_PACKAGE",
+            UploadType::AppiumJavaTestngTestPackage => "APPIUM_JAVA_TESTNG_TEST_PACKAGE",
+            UploadType::AppiumPythonTestPackage => "APPIUM_PYTHON_TEST_PACKAGE",
+            UploadType::AppiumNodeTestPackage => "APPIUM_NODE_TEST_PACKAGE",
+            UploadType::AppiumRubyTestPackage => "APPIUM_RUBY_TEST_PACKAGE",
+            UploadType::AppiumWebJavaJunitTestPackage => "APPIUM_WEB_JAVA_JUNIT_TEST_PACKAGE",
+            UploadType::AppiumWebJavaTestngTestPackage => "APPIUM_WEB_JAVA_TESTNG_TEST_PACKAGE",
+            UploadType::AppiumWebPythonTestPackage => "APPIUM_WEB_PYTHON_TEST_PACKAGE",
+            UploadType::AppiumWebNodeTestPackage => "APPIUM_WEB_NODE_TEST_PACKAGE",
+            UploadType::AppiumWebRubyTestPackage => "APPIUM_WEB_RUBY_TEST_PACKAGE",
+            UploadType::CalabashTestPackage => "CALABASH_TEST_PACKAGE",
+            UploadType::InstrumentationTestPackage => "INSTRUMENTATION_TEST_PACKAGE",
+            UploadType::UiautomationTestPackage => "UIAUTOMATION_TEST_PACKAGE",
+            UploadType::UiautomatorTestPackage => "UIAUTOMATOR_TEST_PACKAGE",
+            UploadType::XctestTestPackage => "XCTEST_TEST_PACKAGE",
+            UploadType::XctestUiTestPackage => "XCTEST_UI_TEST_PACKAGE",
+            UploadType::AppiumJavaJunitTestSpec => "APPIUM_JAVA_JUNIT_TEST_SPEC",
+            UploadType::AppiumJavaTestngTestSpec => "APPIUM_JAVA_TESTNG_TEST_SPEC",
+            UploadType::AppiumPythonTestSpec => "APPIUM_PYTHON_TEST_SPEC",
+            UploadType::AppiumNodeTestSpec => "APPIUM_NODE_TEST_SPEC",
+            UploadType::AppiumRubyTestSpec => "APPIUM_RUBY_TEST_SPEC",
+            UploadType::AppiumWebJavaJunitTestSpec => "APPIUM_WEB_JAVA_JUNIT_TEST_SPEC",
+            UploadType::AppiumWebJavaTestngTestSpec => "APPIUM_WEB_JAVA_TESTNG_TEST_SPEC",
+            UploadType::AppiumWebPythonTestSpec => "APPIUM_WEB_PYTHON_TEST_SPEC",
+            UploadType::AppiumWebNodeTestSpec => "APPIUM_WEB_NODE_TEST_SPEC",
+            UploadType::AppiumWebRubyTestSpec => "APPIUM_WEB_RUBY_TEST_SPEC",
+            UploadType::InstrumentationTestSpec => "INSTRUMENTATION_TEST_SPEC",
+            UploadType::XctestUiTestSpec => "XCTEST_UI_TEST_SPEC",
+            UploadType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UploadType {
+    fn from(value: &'a str) -> UploadType {
+        match value {
+            "ANDROID_APP" => UploadType::AndroidApp,
+            "IOS_APP" => UploadType::IosApp,
+            "WEB_APP" => UploadType::WebApp,
+            "EXTERNAL_DATA" => UploadType::ExternalData,
+            "APPIUM_JAVA_JUNIT_TEST_PACKAGE" => UploadType::AppiumJavaJunitTestPackage,
+            "APPIUM_JAVA_TESTNG_TEST_PACKAGE" => UploadType::AppiumJavaTestngTestPackage,
+            "APPIUM_PYTHON_TEST_PACKAGE" => UploadType::AppiumPythonTestPackage,
+            "APPIUM_NODE_TEST_PACKAGE" => UploadType::AppiumNodeTestPackage,
+            "APPIUM_RUBY_TEST_PACKAGE" => UploadType::AppiumRubyTestPackage,
+            "APPIUM_WEB_JAVA_JUNIT_TEST_PACKAGE" => UploadType::AppiumWebJavaJunitTestPackage,
+            "APPIUM_WEB_JAVA_TESTNG_TEST_PACKAGE" => UploadType::AppiumWebJavaTestngTestPackage,
+            "APPIUM_WEB_PYTHON_TEST_PACKAGE" => UploadType::AppiumWebPythonTestPackage,
+            "APPIUM_WEB_NODE_TEST_PACKAGE" => UploadType::AppiumWebNodeTestPackage,
+            "APPIUM_WEB_RUBY_TEST_PACKAGE" => UploadType::AppiumWebRubyTestPackage,
+            "CALABASH_TEST_PACKAGE" => UploadType::CalabashTestPackage,
+            "INSTRUMENTATION_TEST_PACKAGE" => UploadType::InstrumentationTestPackage,
+            "UIAUTOMATION_TEST_PACKAGE" => UploadType::UiautomationTestPackage,
+            "UIAUTOMATOR_TEST_PACKAGE" => UploadType::UiautomatorTestPackage,
+            "XCTEST_TEST_PACKAGE" => UploadType::XctestTestPackage,
+            "XCTEST_UI_TEST_PACKAGE" => UploadType::XctestUiTestPackage,
+            "APPIUM_JAVA_JUNIT_TEST_SPEC" => UploadType::AppiumJavaJunitTestSpec,
+            "APPIUM_JAVA_TESTNG_TEST_SPEC" => UploadType::AppiumJavaTestngTestSpec,
+            "APPIUM_PYTHON_TEST_SPEC" => UploadType::AppiumPythonTestSpec,
+            "APPIUM_NODE_TEST_SPEC" => UploadType::AppiumNodeTestSpec,
+            "APPIUM_RUBY_TEST_SPEC" => UploadType::AppiumRubyTestSpec,
+            "APPIUM_WEB_JAVA_JUNIT_TEST_SPEC" => UploadType::AppiumWebJavaJunitTestSpec,
+            "APPIUM_WEB_JAVA_TESTNG_TEST_SPEC" => UploadType::AppiumWebJavaTestngTestSpec,
+            "APPIUM_WEB_PYTHON_TEST_SPEC" => UploadType::AppiumWebPythonTestSpec,
+            "APPIUM_WEB_NODE_TEST_SPEC" => UploadType::AppiumWebNodeTestSpec,
+            "APPIUM_WEB_RUBY_TEST_SPEC" => UploadType::AppiumWebRubyTestSpec,
+            "INSTRUMENTATION_TEST_SPEC" => UploadType::InstrumentationTestSpec,
+            "XCTEST_UI_TEST_SPEC" => UploadType::XctestUiTestSpec,
+            _ => UploadType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UploadType {
+    fn from(value: String) -> UploadType {
+        UploadType::from(value.as_str())
+    }
+}
+
+impl From<UploadType> for String {
+    fn from(value: UploadType) -> String {
+        match value {
+            UploadType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UploadType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UploadType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UploadType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UploadType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UploadType::from(value))
+    }
+}
+
 /// <p>Represents an Amazon Virtual Private Cloud (VPC) endpoint configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -6574,245 +11764,619 @@ pub trait DeviceFarm {
     fn get_network_profile(
         &self,
         input: GetNetworkProfileRequest,
-    ) -> RusotoFuture<GetNetworkProfileResult, GetNetworkProfileError>;
-
-    /// <p>Gets the current status and future status of all offerings purchased by an AWS account. The response indicates how many offerings are currently available and the offerings that will be available in the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    ) -> RusotoFuture<GetNetworkProfileResult, GetNetworkProfileError>;
+
+    /// <p>Gets the current status and future status of all offerings purchased by an AWS account. The response indicates how many offerings are currently available and the offerings that will be available in the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    fn get_offering_status(
+        &self,
+        input: GetOfferingStatusRequest,
+    ) -> RusotoFuture<GetOfferingStatusResult, GetOfferingStatusError>;
+
+    /// <p>Gets information about a project.</p>
+    fn get_project(
+        &self,
+        input: GetProjectRequest,
+    ) -> RusotoFuture<GetProjectResult, GetProjectError>;
+
+    /// <p>Returns a link to a currently running remote access session.</p>
+    fn get_remote_access_session(
+        &self,
+        input: GetRemoteAccessSessionRequest,
+    ) -> RusotoFuture<GetRemoteAccessSessionResult, GetRemoteAccessSessionError>;
+
+    /// <p>Gets information about a run.</p>
+    fn get_run(&self, input: GetRunRequest) -> RusotoFuture<GetRunResult, GetRunError>;
+
+    /// <p>Gets information about a suite.</p>
+    fn get_suite(&self, input: GetSuiteRequest) -> RusotoFuture<GetSuiteResult, GetSuiteError>;
+
+    /// <p>Gets information about a test.</p>
+    fn get_test(&self, input: GetTestRequest) -> RusotoFuture<GetTestResult, GetTestError>;
+
+    /// <p>Gets information about an upload.</p>
+    fn get_upload(&self, input: GetUploadRequest) -> RusotoFuture<GetUploadResult, GetUploadError>;
+
+    /// <p>Returns information about the configuration settings for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
+    fn get_vpce_configuration(
+        &self,
+        input: GetVPCEConfigurationRequest,
+    ) -> RusotoFuture<GetVPCEConfigurationResult, GetVPCEConfigurationError>;
+
+    /// <p>Installs an application to the device in a remote access session. For Android applications, the file must be in .apk format. For iOS applications, the file must be in .ipa format.</p>
+    fn install_to_remote_access_session(
+        &self,
+        input: InstallToRemoteAccessSessionRequest,
+    ) -> RusotoFuture<InstallToRemoteAccessSessionResult, InstallToRemoteAccessSessionError>;
+
+    /// <p>Gets information about artifacts.</p>
+    fn list_artifacts(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> RusotoFuture<ListArtifactsResult, ListArtifactsError>;
+
+    /// <p>Returns information about the private device instances associated with one or more AWS accounts.</p>
+    fn list_device_instances(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> RusotoFuture<ListDeviceInstancesResult, ListDeviceInstancesError>;
+
+    /// <p>Gets information about device pools.</p>
+    fn list_device_pools(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> RusotoFuture<ListDevicePoolsResult, ListDevicePoolsError>;
+
+    /// <p>Gets information about unique device types.</p>
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResult, ListDevicesError>;
+
+    /// <p>Returns information about all the instance profiles in an AWS account.</p>
+    fn list_instance_profiles(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> RusotoFuture<ListInstanceProfilesResult, ListInstanceProfilesError>;
+
+    /// <p>Gets information about jobs for a given test run.</p>
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError>;
+
+    /// <p>Returns the list of available network profiles.</p>
+    fn list_network_profiles(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> RusotoFuture<ListNetworkProfilesResult, ListNetworkProfilesError>;
+
+    /// <p>Returns a list of offering promotions. Each offering promotion record contains the ID and description of the promotion. The API returns a <code>NotEligible</code> error if the caller is not permitted to invoke the operation. Contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    fn list_offering_promotions(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> RusotoFuture<ListOfferingPromotionsResult, ListOfferingPromotionsError>;
+
+    /// <p>Returns a list of all historical purchases, renewals, and system renewal transactions for an AWS account. The list is paginated and ordered by a descending timestamp (most recent transactions are first). The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    fn list_offering_transactions(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> RusotoFuture<ListOfferingTransactionsResult, ListOfferingTransactionsError>;
+
+    /// <p>Returns a list of products or offerings that the user can manage through the API. Each offering record indicates the recurring price per unit and the frequency for that offering. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> RusotoFuture<ListOfferingsResult, ListOfferingsError>;
+
+    /// <p>Gets information about projects.</p>
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError>;
+
+    /// <p>Returns a list of all currently running remote access sessions.</p>
+    fn list_remote_access_sessions(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> RusotoFuture<ListRemoteAccessSessionsResult, ListRemoteAccessSessionsError>;
+
+    /// <p>Gets information about runs, given an AWS Device Farm project ARN.</p>
+    fn list_runs(&self, input: ListRunsRequest) -> RusotoFuture<ListRunsResult, ListRunsError>;
+
+    /// <p>Gets information about samples, given an AWS Device Farm job ARN.</p>
+    fn list_samples(
+        &self,
+        input: ListSamplesRequest,
+    ) -> RusotoFuture<ListSamplesResult, ListSamplesError>;
+
+    /// <p>Gets information about test suites for a given job.</p>
+    fn list_suites(
+        &self,
+        input: ListSuitesRequest,
+    ) -> RusotoFuture<ListSuitesResult, ListSuitesError>;
+
+    /// <p>List the tags for an AWS Device Farm resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
+
+    /// <p>Gets information about tests in a given test suite.</p>
+    fn list_tests(&self, input: ListTestsRequest) -> RusotoFuture<ListTestsResult, ListTestsError>;
+
+    /// <p>Gets information about unique problems.</p>
+    fn list_unique_problems(
+        &self,
+        input: ListUniqueProblemsRequest,
+    ) -> RusotoFuture<ListUniqueProblemsResult, ListUniqueProblemsError>;
+
+    /// <p>Gets information about uploads, given an AWS Device Farm project ARN.</p>
+    fn list_uploads(
+        &self,
+        input: ListUploadsRequest,
+    ) -> RusotoFuture<ListUploadsResult, ListUploadsError>;
+
+    /// <p>Returns information about all Amazon Virtual Private Cloud (VPC) endpoint configurations in the AWS account.</p>
+    fn list_vpce_configurations(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> RusotoFuture<ListVPCEConfigurationsResult, ListVPCEConfigurationsError>;
+
+    /// <p>Immediately purchases offerings for an AWS account. Offerings renew with the latest total purchased quantity for an offering, unless the renewal was overridden. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> RusotoFuture<PurchaseOfferingResult, PurchaseOfferingError>;
+
+    /// <p>Explicitly sets the quantity of devices to renew for an offering, starting from the <code>effectiveDate</code> of the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    fn renew_offering(
+        &self,
+        input: RenewOfferingRequest,
+    ) -> RusotoFuture<RenewOfferingResult, RenewOfferingError>;
+
+    /// <p>Schedules a run.</p>
+    fn schedule_run(
+        &self,
+        input: ScheduleRunRequest,
+    ) -> RusotoFuture<ScheduleRunResult, ScheduleRunError>;
+
+    /// <p>Initiates a stop request for the current job. AWS Device Farm will immediately stop the job on the device where tests have not started executing, and you will not be billed for this device. On the device where tests have started executing, Setup Suite and Teardown Suite tests will run to completion before stopping execution on the device. You will be billed for Setup, Teardown, and any tests that were in progress or already completed.</p>
+    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError>;
+
+    /// <p>Ends a specified remote access session.</p>
+    fn stop_remote_access_session(
+        &self,
+        input: StopRemoteAccessSessionRequest,
+    ) -> RusotoFuture<StopRemoteAccessSessionResult, StopRemoteAccessSessionError>;
+
+    /// <p>Initiates a stop request for the current test run. AWS Device Farm will immediately stop the run on devices where tests have not started executing, and you will not be billed for these devices. On devices where tests have started executing, Setup Suite and Teardown Suite tests will run to completion before stopping execution on those devices. You will be billed for Setup, Teardown, and any tests that were in progress or already completed.</p>
+    fn stop_run(&self, input: StopRunRequest) -> RusotoFuture<StopRunResult, StopRunError>;
+
+    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError>;
+
+    /// <p>Deletes the specified tags from a resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
+
+    /// <p>Updates information about an existing private device instance.</p>
+    fn update_device_instance(
+        &self,
+        input: UpdateDeviceInstanceRequest,
+    ) -> RusotoFuture<UpdateDeviceInstanceResult, UpdateDeviceInstanceError>;
+
+    /// <p>Modifies the name, description, and rules in a device pool given the attributes and the pool ARN. Rule updates are all-or-nothing, meaning they can only be updated as a whole (or not at all).</p>
+    fn update_device_pool(
+        &self,
+        input: UpdateDevicePoolRequest,
+    ) -> RusotoFuture<UpdateDevicePoolResult, UpdateDevicePoolError>;
+
+    /// <p>Updates information about an existing private device instance profile.</p>
+    fn update_instance_profile(
+        &self,
+        input: UpdateInstanceProfileRequest,
+    ) -> RusotoFuture<UpdateInstanceProfileResult, UpdateInstanceProfileError>;
+
+    /// <p>Updates the network profile with specific settings.</p>
+    fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> RusotoFuture<UpdateNetworkProfileResult, UpdateNetworkProfileError>;
+
+    /// <p>Modifies the specified project name, given the project ARN and a new name.</p>
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError>;
+
+    /// <p>Update an uploaded test specification (test spec).</p>
+    fn update_upload(
+        &self,
+        input: UpdateUploadRequest,
+    ) -> RusotoFuture<UpdateUploadResult, UpdateUploadError>;
+
+    /// <p>Updates information about an existing Amazon Virtual Private Cloud (VPC) endpoint configuration.</p>
+    fn update_vpce_configuration(
+        &self,
+        input: UpdateVPCEConfigurationRequest,
+    ) -> RusotoFuture<UpdateVPCEConfigurationResult, UpdateVPCEConfigurationError>;
+}
+impl<T: ?Sized + DeviceFarm> DeviceFarm for ::std::sync::Arc<T> {
+    fn create_device_pool(
+        &self,
+        input: CreateDevicePoolRequest,
+    ) -> RusotoFuture<CreateDevicePoolResult, CreateDevicePoolError> {
+        (**self).create_device_pool(input)
+    }
+    fn create_instance_profile(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> RusotoFuture<CreateInstanceProfileResult, CreateInstanceProfileError> {
+        (**self).create_instance_profile(input)
+    }
+    fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> RusotoFuture<CreateNetworkProfileResult, CreateNetworkProfileError> {
+        (**self).create_network_profile(input)
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn create_remote_access_session(
+        &self,
+        input: CreateRemoteAccessSessionRequest,
+    ) -> RusotoFuture<CreateRemoteAccessSessionResult, CreateRemoteAccessSessionError> {
+        (**self).create_remote_access_session(input)
+    }
+    fn create_upload(
+        &self,
+        input: CreateUploadRequest,
+    ) -> RusotoFuture<CreateUploadResult, CreateUploadError> {
+        (**self).create_upload(input)
+    }
+    fn create_vpce_configuration(
+        &self,
+        input: CreateVPCEConfigurationRequest,
+    ) -> RusotoFuture<CreateVPCEConfigurationResult, CreateVPCEConfigurationError> {
+        (**self).create_vpce_configuration(input)
+    }
+    fn delete_device_pool(
+        &self,
+        input: DeleteDevicePoolRequest,
+    ) -> RusotoFuture<DeleteDevicePoolResult, DeleteDevicePoolError> {
+        (**self).delete_device_pool(input)
+    }
+    fn delete_instance_profile(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> RusotoFuture<DeleteInstanceProfileResult, DeleteInstanceProfileError> {
+        (**self).delete_instance_profile(input)
+    }
+    fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> RusotoFuture<DeleteNetworkProfileResult, DeleteNetworkProfileError> {
+        (**self).delete_network_profile(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn delete_remote_access_session(
+        &self,
+        input: DeleteRemoteAccessSessionRequest,
+    ) -> RusotoFuture<DeleteRemoteAccessSessionResult, DeleteRemoteAccessSessionError> {
+        (**self).delete_remote_access_session(input)
+    }
+    fn delete_run(&self, input: DeleteRunRequest) -> RusotoFuture<DeleteRunResult, DeleteRunError> {
+        (**self).delete_run(input)
+    }
+    fn delete_upload(
+        &self,
+        input: DeleteUploadRequest,
+    ) -> RusotoFuture<DeleteUploadResult, DeleteUploadError> {
+        (**self).delete_upload(input)
+    }
+    fn delete_vpce_configuration(
+        &self,
+        input: DeleteVPCEConfigurationRequest,
+    ) -> RusotoFuture<DeleteVPCEConfigurationResult, DeleteVPCEConfigurationError> {
+        (**self).delete_vpce_configuration(input)
+    }
+    fn get_account_settings(
+        &self,
+    ) -> RusotoFuture<GetAccountSettingsResult, GetAccountSettingsError> {
+        (**self).get_account_settings()
+    }
+    fn get_device(&self, input: GetDeviceRequest) -> RusotoFuture<GetDeviceResult, GetDeviceError> {
+        (**self).get_device(input)
+    }
+    fn get_device_instance(
+        &self,
+        input: GetDeviceInstanceRequest,
+    ) -> RusotoFuture<GetDeviceInstanceResult, GetDeviceInstanceError> {
+        (**self).get_device_instance(input)
+    }
+    fn get_device_pool(
+        &self,
+        input: GetDevicePoolRequest,
+    ) -> RusotoFuture<GetDevicePoolResult, GetDevicePoolError> {
+        (**self).get_device_pool(input)
+    }
+    fn get_device_pool_compatibility(
+        &self,
+        input: GetDevicePoolCompatibilityRequest,
+    ) -> RusotoFuture<GetDevicePoolCompatibilityResult, GetDevicePoolCompatibilityError> {
+        (**self).get_device_pool_compatibility(input)
+    }
+    fn get_instance_profile(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> RusotoFuture<GetInstanceProfileResult, GetInstanceProfileError> {
+        (**self).get_instance_profile(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResult, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> RusotoFuture<GetNetworkProfileResult, GetNetworkProfileError> {
+        (**self).get_network_profile(input)
+    }
     fn get_offering_status(
         &self,
         input: GetOfferingStatusRequest,
-    ) -> RusotoFuture<GetOfferingStatusResult, GetOfferingStatusError>;
-
-    /// <p>Gets information about a project.</p>
+    ) -> RusotoFuture<GetOfferingStatusResult, GetOfferingStatusError> {
+        (**self).get_offering_status(input)
+    }
     fn get_project(
         &self,
         input: GetProjectRequest,
-    ) -> RusotoFuture<GetProjectResult, GetProjectError>;
-
-    /// <p>Returns a link to a currently running remote access session.</p>
+    ) -> RusotoFuture<GetProjectResult, GetProjectError> {
+        (**self).get_project(input)
+    }
     fn get_remote_access_session(
         &self,
         input: GetRemoteAccessSessionRequest,
-    ) -> RusotoFuture<GetRemoteAccessSessionResult, GetRemoteAccessSessionError>;
-
-    /// <p>Gets information about a run.</p>
-    fn get_run(&self, input: GetRunRequest) -> RusotoFuture<GetRunResult, GetRunError>;
-
-    /// <p>Gets information about a suite.</p>
-    fn get_suite(&self, input: GetSuiteRequest) -> RusotoFuture<GetSuiteResult, GetSuiteError>;
-
-    /// <p>Gets information about a test.</p>
-    fn get_test(&self, input: GetTestRequest) -> RusotoFuture<GetTestResult, GetTestError>;
-
-    /// <p>Gets information about an upload.</p>
-    fn get_upload(&self, input: GetUploadRequest) -> RusotoFuture<GetUploadResult, GetUploadError>;
-
-    /// <p>Returns information about the configuration settings for your Amazon Virtual Private Cloud (VPC) endpoint.</p>
+    ) -> RusotoFuture<GetRemoteAccessSessionResult, GetRemoteAccessSessionError> {
+        (**self).get_remote_access_session(input)
+    }
+    fn get_run(&self, input: GetRunRequest) -> RusotoFuture<GetRunResult, GetRunError> {
+        (**self).get_run(input)
+    }
+    fn get_suite(&self, input: GetSuiteRequest) -> RusotoFuture<GetSuiteResult, GetSuiteError> {
+        (**self).get_suite(input)
+    }
+    fn get_test(&self, input: GetTestRequest) -> RusotoFuture<GetTestResult, GetTestError> {
+        (**self).get_test(input)
+    }
+    fn get_upload(&self, input: GetUploadRequest) -> RusotoFuture<GetUploadResult, GetUploadError> {
+        (**self).get_upload(input)
+    }
     fn get_vpce_configuration(
         &self,
         input: GetVPCEConfigurationRequest,
-    ) -> RusotoFuture<GetVPCEConfigurationResult, GetVPCEConfigurationError>;
-
-    /// <p>Installs an application to the device in a remote access session. For Android applications, the file must be in .apk format. For iOS applications, the file must be in .ipa format.</p>
+    ) -> RusotoFuture<GetVPCEConfigurationResult, GetVPCEConfigurationError> {
+        (**self).get_vpce_configuration(input)
+    }
     fn install_to_remote_access_session(
         &self,
         input: InstallToRemoteAccessSessionRequest,
-    ) -> RusotoFuture<InstallToRemoteAccessSessionResult, InstallToRemoteAccessSessionError>;
-
-    /// <p>Gets information about artifacts.</p>
+    ) -> RusotoFuture<InstallToRemoteAccessSessionResult, InstallToRemoteAccessSessionError> {
+        (**self).install_to_remote_access_session(input)
+    }
     fn list_artifacts(
         &self,
         input: ListArtifactsRequest,
-    ) -> RusotoFuture<ListArtifactsResult, ListArtifactsError>;
-
-    /// <p>Returns information about the private device instances associated with one or more AWS accounts.</p>
+    ) -> RusotoFuture<ListArtifactsResult, ListArtifactsError> {
+        (**self).list_artifacts(input)
+    }
     fn list_device_instances(
         &self,
         input: ListDeviceInstancesRequest,
-    ) -> RusotoFuture<ListDeviceInstancesResult, ListDeviceInstancesError>;
-
-    /// <p>Gets information about device pools.</p>
+    ) -> RusotoFuture<ListDeviceInstancesResult, ListDeviceInstancesError> {
+        (**self).list_device_instances(input)
+    }
     fn list_device_pools(
         &self,
         input: ListDevicePoolsRequest,
-    ) -> RusotoFuture<ListDevicePoolsResult, ListDevicePoolsError>;
-
-    /// <p>Gets information about unique device types.</p>
+    ) -> RusotoFuture<ListDevicePoolsResult, ListDevicePoolsError> {
+        (**self).list_device_pools(input)
+    }
     fn list_devices(
         &self,
         input: ListDevicesRequest,
-    ) -> RusotoFuture<ListDevicesResult, ListDevicesError>;
-
-    /// <p>Returns information about all the instance profiles in an AWS account.</p>
+    ) -> RusotoFuture<ListDevicesResult, ListDevicesError> {
+        (**self).list_devices(input)
+    }
     fn list_instance_profiles(
         &self,
         input: ListInstanceProfilesRequest,
-    ) -> RusotoFuture<ListInstanceProfilesResult, ListInstanceProfilesError>;
-
-    /// <p>Gets information about jobs for a given test run.</p>
-    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError>;
-
-    /// <p>Returns the list of available network profiles.</p>
+    ) -> RusotoFuture<ListInstanceProfilesResult, ListInstanceProfilesError> {
+        (**self).list_instance_profiles(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResult, ListJobsError> {
+        (**self).list_jobs(input)
+    }
     fn list_network_profiles(
         &self,
         input: ListNetworkProfilesRequest,
-    ) -> RusotoFuture<ListNetworkProfilesResult, ListNetworkProfilesError>;
-
-    /// <p>Returns a list of offering promotions. Each offering promotion record contains the ID and description of the promotion. The API returns a <code>NotEligible</code> error if the caller is not permitted to invoke the operation. Contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    ) -> RusotoFuture<ListNetworkProfilesResult, ListNetworkProfilesError> {
+        (**self).list_network_profiles(input)
+    }
     fn list_offering_promotions(
         &self,
         input: ListOfferingPromotionsRequest,
-    ) -> RusotoFuture<ListOfferingPromotionsResult, ListOfferingPromotionsError>;
-
-    /// <p>Returns a list of all historical purchases, renewals, and system renewal transactions for an AWS account. The list is paginated and ordered by a descending timestamp (most recent transactions are first). The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    ) -> RusotoFuture<ListOfferingPromotionsResult, ListOfferingPromotionsError> {
+        (**self).list_offering_promotions(input)
+    }
     fn list_offering_transactions(
         &self,
         input: ListOfferingTransactionsRequest,
-    ) -> RusotoFuture<ListOfferingTransactionsResult, ListOfferingTransactionsError>;
-
-    /// <p>Returns a list of products or offerings that the user can manage through the API. Each offering record indicates the recurring price per unit and the frequency for that offering. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    ) -> RusotoFuture<ListOfferingTransactionsResult, ListOfferingTransactionsError> {
+        (**self).list_offering_transactions(input)
+    }
     fn list_offerings(
         &self,
         input: ListOfferingsRequest,
-    ) -> RusotoFuture<ListOfferingsResult, ListOfferingsError>;
-
-    /// <p>Gets information about projects.</p>
+    ) -> RusotoFuture<ListOfferingsResult, ListOfferingsError> {
+        (**self).list_offerings(input)
+    }
     fn list_projects(
         &self,
         input: ListProjectsRequest,
-    ) -> RusotoFuture<ListProjectsResult, ListProjectsError>;
-
-    /// <p>Returns a list of all currently running remote access sessions.</p>
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        (**self).list_projects(input)
+    }
     fn list_remote_access_sessions(
         &self,
         input: ListRemoteAccessSessionsRequest,
-    ) -> RusotoFuture<ListRemoteAccessSessionsResult, ListRemoteAccessSessionsError>;
-
-    /// <p>Gets information about runs, given an AWS Device Farm project ARN.</p>
-    fn list_runs(&self, input: ListRunsRequest) -> RusotoFuture<ListRunsResult, ListRunsError>;
-
-    /// <p>Gets information about samples, given an AWS Device Farm job ARN.</p>
+    ) -> RusotoFuture<ListRemoteAccessSessionsResult, ListRemoteAccessSessionsError> {
+        (**self).list_remote_access_sessions(input)
+    }
+    fn list_runs(&self, input: ListRunsRequest) -> RusotoFuture<ListRunsResult, ListRunsError> {
+        (**self).list_runs(input)
+    }
     fn list_samples(
         &self,
         input: ListSamplesRequest,
-    ) -> RusotoFuture<ListSamplesResult, ListSamplesError>;
-
-    /// <p>Gets information about test suites for a given job.</p>
+    ) -> RusotoFuture<ListSamplesResult, ListSamplesError> {
+        (**self).list_samples(input)
+    }
     fn list_suites(
         &self,
         input: ListSuitesRequest,
-    ) -> RusotoFuture<ListSuitesResult, ListSuitesError>;
-
-    /// <p>List the tags for an AWS Device Farm resource.</p>
+    ) -> RusotoFuture<ListSuitesResult, ListSuitesError> {
+        (**self).list_suites(input)
+    }
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
-    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
-
-    /// <p>Gets information about tests in a given test suite.</p>
-    fn list_tests(&self, input: ListTestsRequest) -> RusotoFuture<ListTestsResult, ListTestsError>;
-
-    /// <p>Gets information about unique problems.</p>
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_tests(&self, input: ListTestsRequest) -> RusotoFuture<ListTestsResult, ListTestsError> {
+        (**self).list_tests(input)
+    }
     fn list_unique_problems(
         &self,
         input: ListUniqueProblemsRequest,
-    ) -> RusotoFuture<ListUniqueProblemsResult, ListUniqueProblemsError>;
-
-    /// <p>Gets information about uploads, given an AWS Device Farm project ARN.</p>
+    ) -> RusotoFuture<ListUniqueProblemsResult, ListUniqueProblemsError> {
+        (**self).list_unique_problems(input)
+    }
     fn list_uploads(
         &self,
         input: ListUploadsRequest,
-    ) -> RusotoFuture<ListUploadsResult, ListUploadsError>;
-
-    /// <p>Returns information about all Amazon Virtual Private Cloud (VPC) endpoint configurations in the AWS account.</p>
+    ) -> RusotoFuture<ListUploadsResult, ListUploadsError> {
+        (**self).list_uploads(input)
+    }
     fn list_vpce_configurations(
         &self,
         input: ListVPCEConfigurationsRequest,
-    ) -> RusotoFuture<ListVPCEConfigurationsResult, ListVPCEConfigurationsError>;
-
-    /// <p>Immediately purchases offerings for an AWS account. Offerings renew with the latest total purchased quantity for an offering, unless the renewal was overridden. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    ) -> RusotoFuture<ListVPCEConfigurationsResult, ListVPCEConfigurationsError> {
+        (**self).list_vpce_configurations(input)
+    }
     fn purchase_offering(
         &self,
         input: PurchaseOfferingRequest,
-    ) -> RusotoFuture<PurchaseOfferingResult, PurchaseOfferingError>;
-
-    /// <p>Explicitly sets the quantity of devices to renew for an offering, starting from the <code>effectiveDate</code> of the next period. The API returns a <code>NotEligible</code> error if the user is not permitted to invoke the operation. Please contact <a href="mailto:aws-devicefarm-support@amazon.com">aws-devicefarm-support@amazon.com</a> if you believe that you should be able to invoke this operation.</p>
+    ) -> RusotoFuture<PurchaseOfferingResult, PurchaseOfferingError> {
+        (**self).purchase_offering(input)
+    }
     fn renew_offering(
         &self,
         input: RenewOfferingRequest,
-    ) -> RusotoFuture<RenewOfferingResult, RenewOfferingError>;
-
-    /// <p>Schedules a run.</p>
+    ) -> RusotoFuture<RenewOfferingResult, RenewOfferingError> {
+        (**self).renew_offering(input)
+    }
     fn schedule_run(
         &self,
         input: ScheduleRunRequest,
-    ) -> RusotoFuture<ScheduleRunResult, ScheduleRunError>;
-
-    /// <p>Initiates a stop request for the current job. AWS Device Farm will immediately stop the job on the device where tests have not started executing, and you will not be billed for this device. On the device where tests have started executing, Setup Suite and Teardown Suite tests will run to completion before stopping execution on the device. You will be billed for Setup, Teardown, and any tests that were in progress or already completed.</p>
-    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError>;
-
-    /// <p>Ends a specified remote access session.</p>
+    ) -> RusotoFuture<ScheduleRunResult, ScheduleRunError> {
+        (**self).schedule_run(input)
+    }
+    fn stop_job(&self, input: StopJobRequest) -> RusotoFuture<StopJobResult, StopJobError> {
+        (**self).stop_job(input)
+    }
     fn stop_remote_access_session(
         &self,
         input: StopRemoteAccessSessionRequest,
-    ) -> RusotoFuture<StopRemoteAccessSessionResult, StopRemoteAccessSessionError>;
-
-    /// <p>Initiates a stop request for the current test run. AWS Device Farm will immediately stop the run on devices where tests have not started executing, and you will not be billed for these devices. On devices where tests have started executing, Setup Suite and Teardown Suite tests will run to completion before stopping execution on those devices. You will be billed for Setup, Teardown, and any tests that were in progress or already completed.</p>
-    fn stop_run(&self, input: StopRunRequest) -> RusotoFuture<StopRunResult, StopRunError>;
-
-    /// <p>Associates the specified tags to a resource with the specified <code>resourceArn</code>. If existing tags on a resource are not specified in the request parameters, they are not changed. When a resource is deleted, the tags associated with that resource are deleted as well.</p>
+    ) -> RusotoFuture<StopRemoteAccessSessionResult, StopRemoteAccessSessionError> {
+        (**self).stop_remote_access_session(input)
+    }
+    fn stop_run(&self, input: StopRunRequest) -> RusotoFuture<StopRunResult, StopRunError> {
+        (**self).stop_run(input)
+    }
     fn tag_resource(
         &self,
         input: TagResourceRequest,
-    ) -> RusotoFuture<TagResourceResponse, TagResourceError>;
-
-    /// <p>Deletes the specified tags from a resource.</p>
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
-    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
-
-    /// <p>Updates information about an existing private device instance.</p>
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
     fn update_device_instance(
         &self,
         input: UpdateDeviceInstanceRequest,
-    ) -> RusotoFuture<UpdateDeviceInstanceResult, UpdateDeviceInstanceError>;
-
-    /// <p>Modifies the name, description, and rules in a device pool given the attributes and the pool ARN. Rule updates are all-or-nothing, meaning they can only be updated as a whole (or not at all).</p>
+    ) -> RusotoFuture<UpdateDeviceInstanceResult, UpdateDeviceInstanceError> {
+        (**self).update_device_instance(input)
+    }
     fn update_device_pool(
         &self,
         input: UpdateDevicePoolRequest,
-    ) -> RusotoFuture<UpdateDevicePoolResult, UpdateDevicePoolError>;
-
-    /// <p>Updates information about an existing private device instance profile.</p>
+    ) -> RusotoFuture<UpdateDevicePoolResult, UpdateDevicePoolError> {
+        (**self).update_device_pool(input)
+    }
     fn update_instance_profile(
         &self,
         input: UpdateInstanceProfileRequest,
-    ) -> RusotoFuture<UpdateInstanceProfileResult, UpdateInstanceProfileError>;
-
-    /// <p>Updates the network profile with specific settings.</p>
+    ) -> RusotoFuture<UpdateInstanceProfileResult, UpdateInstanceProfileError> {
+        (**self).update_instance_profile(input)
+    }
     fn update_network_profile(
         &self,
         input: UpdateNetworkProfileRequest,
-    ) -> RusotoFuture<UpdateNetworkProfileResult, UpdateNetworkProfileError>;
-
-    /// <p>Modifies the specified project name, given the project ARN and a new name.</p>
+    ) -> RusotoFuture<UpdateNetworkProfileResult, UpdateNetworkProfileError> {
+        (**self).update_network_profile(input)
+    }
     fn update_project(
         &self,
         input: UpdateProjectRequest,
-    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError>;
-
-    /// <p>Update an uploaded test specification (test spec).</p>
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        (**self).update_project(input)
+    }
     fn update_upload(
         &self,
         input: UpdateUploadRequest,
-    ) -> RusotoFuture<UpdateUploadResult, UpdateUploadError>;
-
-    /// <p>Updates information about an existing Amazon Virtual Private Cloud (VPC) endpoint configuration.</p>
+    ) -> RusotoFuture<UpdateUploadResult, UpdateUploadError> {
+        (**self).update_upload(input)
+    }
     fn update_vpce_configuration(
         &self,
         input: UpdateVPCEConfigurationRequest,
-    ) -> RusotoFuture<UpdateVPCEConfigurationResult, UpdateVPCEConfigurationError>;
+    ) -> RusotoFuture<UpdateVPCEConfigurationResult, UpdateVPCEConfigurationError> {
+        (**self).update_vpce_configuration(input)
+    }
 }
+/// The error codes AWS Device Farm retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Device Farm API.
 #[derive(Clone)]
 pub struct DeviceFarmClient {
@@ -6828,6 +12392,27 @@ impl DeviceFarmClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_DEVICEFARM`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> DeviceFarmClient {
+        Self::new(region::Region::from_env_for_service("DEVICEFARM"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `devicefarm`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> DeviceFarmClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("devicefarm", "DEVICEFARM"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6846,7 +12431,54 @@ impl DeviceFarmClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DeviceFarmClient {
-        DeviceFarmClient { client, region }
+        DeviceFarmClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "devicefarm")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "devicefarm",
+            "devicefarm",
+            "DEVICEFARM",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `DeviceFarm` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn DeviceFarm + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for DeviceFarmClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Device Farm"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "devicefarm"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> DeviceFarmClient {
+        Self::new_with_client(client, region)
     }
 }
 
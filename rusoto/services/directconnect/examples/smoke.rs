@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Direct Connect clients.
+//!
+//! Makes a single read-only `DescribeConnections` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_directconnect::{DescribeConnectionsRequest, DirectConnect, DirectConnectClient};
+
+fn main() {
+    let client = DirectConnectClient::from_env();
+    match client
+        .describe_connections(DescribeConnectionsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeConnections succeeded"),
+        Err(error) => {
+            eprintln!("DescribeConnections failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -1 +1,2 @@
-
+/// Waiting for connections, virtual interfaces and BGP sessions to come up
+pub mod waiters;
@@ -0,0 +1,519 @@
+//! Waiting for connections, virtual interfaces and BGP sessions to come up.
+//!
+//! Connections and virtual interfaces are provisioned asynchronously, and BGP sessions are only
+//! established once the customer router is configured. The methods of `ConnectivityWaiterExt`
+//! poll `DescribeConnections` and `DescribeVirtualInterfaces` until a resource reaches a state,
+//! or every BGP peer of a virtual interface is up, and `bgp_transitions` streams the changes of
+//! the BGP status of a virtual interface's peers for monitoring.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use futures::{future, stream, Future, Stream};
+use rusoto_core::waiter::{poll_until, sleep, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    AddressFamily, BGPPeer, BGPPeerState, BGPStatus, Connection, ConnectionState,
+    DescribeConnectionsError, DescribeConnectionsRequest, DescribeVirtualInterfacesError,
+    DescribeVirtualInterfacesRequest, DirectConnect, VirtualInterface, VirtualInterfaceState,
+};
+
+/// The changes of the BGP status of a virtual interface's peers.
+pub type BgpTransitionStream =
+    Box<dyn Stream<Item = BgpTransition, Error = RusotoError<ConnectivityWaitError>> + Send>;
+
+/// Errors returned while waiting for connectivity.
+#[derive(Debug, PartialEq)]
+pub enum ConnectivityWaitError {
+    /// Describing the connection failed.
+    DescribeConnections(DescribeConnectionsError),
+    /// Describing the virtual interface failed.
+    DescribeVirtualInterfaces(DescribeVirtualInterfacesError),
+    /// The connection or virtual interface doesn't exist.
+    NotFound(String),
+    /// The connection or virtual interface reached a state it can't leave for the desired one,
+    /// e.g. `rejected` or `deleted`.
+    UnreachableState(String),
+    /// The desired state wasn't reached within the configured number of attempts.
+    TimedOut(String),
+}
+
+impl fmt::Display for ConnectivityWaitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for ConnectivityWaitError {
+    fn description(&self) -> &str {
+        match *self {
+            ConnectivityWaitError::DescribeConnections(ref cause) => cause.description(),
+            ConnectivityWaitError::DescribeVirtualInterfaces(ref cause) => cause.description(),
+            ConnectivityWaitError::NotFound(ref cause) => cause,
+            ConnectivityWaitError::UnreachableState(ref cause) => cause,
+            ConnectivityWaitError::TimedOut(ref cause) => cause,
+        }
+    }
+}
+
+/// A change of the BGP status of a peer of a virtual interface.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BgpTransition {
+    /// The ID of the virtual interface
+    pub virtual_interface_id: String,
+    /// The ID of the BGP peer
+    pub bgp_peer_id: String,
+    /// `ipv4` or `ipv6`
+    pub address_family: Option<AddressFamily>,
+    /// The previous status, or `None` when the peer is first seen
+    pub from: Option<BGPStatus>,
+    /// The new status
+    pub to: BGPStatus,
+}
+
+impl BgpTransition {
+    /// Whether the session was established.
+    pub fn is_up(&self) -> bool {
+        self.to == BGPStatus::Up
+    }
+
+    /// Whether the session went down, after having been up.
+    pub fn went_down(&self) -> bool {
+        self.from == Some(BGPStatus::Up) && self.to != BGPStatus::Up
+    }
+}
+
+/// Whether a connection in state `current` can still reach state `target`.
+pub fn connection_state_reachable(current: &ConnectionState, target: &ConnectionState) -> bool {
+    match *current {
+        _ if current == target => true,
+        ConnectionState::Deleted | ConnectionState::Rejected => false,
+        ConnectionState::Deleting => *target == ConnectionState::Deleted,
+        _ => true,
+    }
+}
+
+/// Whether a virtual interface in state `current` can still reach state `target`.
+pub fn virtual_interface_state_reachable(
+    current: &VirtualInterfaceState,
+    target: &VirtualInterfaceState,
+) -> bool {
+    match *current {
+        _ if current == target => true,
+        VirtualInterfaceState::Deleted | VirtualInterfaceState::Rejected => false,
+        VirtualInterfaceState::Deleting => *target == VirtualInterfaceState::Deleted,
+        _ => true,
+    }
+}
+
+/// The peers of a virtual interface which aren't being deleted.
+fn active_peers(virtual_interface: &VirtualInterface) -> impl Iterator<Item = &BGPPeer> {
+    virtual_interface
+        .bgp_peers
+        .iter()
+        .flatten()
+        .filter(|peer| match peer.bgp_peer_state {
+            Some(BGPPeerState::Deleting) | Some(BGPPeerState::Deleted) => false,
+            _ => true,
+        })
+}
+
+/// Whether the virtual interface has BGP peers, and every one which isn't being deleted is up.
+pub fn is_bgp_up(virtual_interface: &VirtualInterface) -> bool {
+    let mut peers = active_peers(virtual_interface).peekable();
+    peers.peek().is_some() && peers.all(|peer| peer.bgp_status == Some(BGPStatus::Up))
+}
+
+/// Compares the BGP status of the peers of `virtual_interface` with `previous`, their status when
+/// last polled, returning the changes and their status now.
+fn bgp_transitions_since(
+    previous: &HashMap<String, BGPStatus>,
+    virtual_interface: &VirtualInterface,
+) -> (Vec<BgpTransition>, HashMap<String, BGPStatus>) {
+    let virtual_interface_id = virtual_interface
+        .virtual_interface_id
+        .clone()
+        .unwrap_or_default();
+    let mut transitions = Vec::new();
+    let mut current = HashMap::new();
+    for peer in active_peers(virtual_interface) {
+        let bgp_peer_id = match peer.bgp_peer_id {
+            Some(ref bgp_peer_id) => bgp_peer_id.clone(),
+            None => continue,
+        };
+        let status = peer
+            .bgp_status
+            .clone()
+            .unwrap_or_else(|| BGPStatus::Unknown(String::new()));
+        let from = previous.get(&bgp_peer_id).cloned();
+        if from.as_ref() != Some(&status) {
+            transitions.push(BgpTransition {
+                virtual_interface_id: virtual_interface_id.clone(),
+                bgp_peer_id: bgp_peer_id.clone(),
+                address_family: peer.address_family.clone(),
+                from,
+                to: status.clone(),
+            });
+        }
+        current.insert(bgp_peer_id, status);
+    }
+    (transitions, current)
+}
+
+/// Extension methods which wait for Direct Connect connectivity.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::waiter::WaiterConfig;
+/// use rusoto_core::Region;
+/// use rusoto_directconnect::waiters::ConnectivityWaiterExt;
+/// use rusoto_directconnect::{DirectConnectClient, VirtualInterfaceState};
+///
+/// let client = DirectConnectClient::new(Region::UsEast1);
+/// client
+///     .wait_for_virtual_interface_state(
+///         "dxvif-ffhhk74f",
+///         VirtualInterfaceState::Available,
+///         WaiterConfig::default(),
+///     )
+///     .sync()
+///     .unwrap();
+/// client
+///     .wait_for_bgp_up("dxvif-ffhhk74f", WaiterConfig::default())
+///     .sync()
+///     .unwrap();
+/// ```
+pub trait ConnectivityWaiterExt: DirectConnect + Clone + Send + Sized + 'static {
+    /// Polls `DescribeConnections` until the connection is in state `target`, and returns it.
+    ///
+    /// Fails with `ConnectivityWaitError::UnreachableState` as soon as the connection is in a
+    /// state it can't leave for `target`, e.g. `rejected` while waiting for `available`.
+    fn wait_for_connection_state(
+        &self,
+        connection_id: &str,
+        target: ConnectionState,
+        config: WaiterConfig,
+    ) -> RusotoFuture<Connection, ConnectivityWaitError> {
+        let client = self.clone();
+        let connection_id = connection_id.to_owned();
+        let timeout = RusotoError::Service(ConnectivityWaitError::TimedOut(format!(
+            "Connection {} did not become {}",
+            connection_id, target
+        )));
+
+        let future = poll_until(
+            config,
+            move || {
+                let target = target.clone();
+                describe_connection(&client, &connection_id).and_then(move |connection| {
+                    let state = connection
+                        .connection_state
+                        .clone()
+                        .unwrap_or_else(|| ConnectionState::Unknown(String::new()));
+                    if state == target {
+                        Ok(Some(connection))
+                    } else if connection_state_reachable(&state, &target) {
+                        Ok(None)
+                    } else {
+                        Err(RusotoError::Service(
+                            ConnectivityWaitError::UnreachableState(format!(
+                                "Connection {} is {}, and can't become {}",
+                                connection.connection_id.unwrap_or_default(),
+                                state,
+                                target
+                            )),
+                        ))
+                    }
+                })
+            },
+            timeout,
+        );
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Polls `DescribeVirtualInterfaces` until the virtual interface is in state `target`, and
+    /// returns it.
+    ///
+    /// Fails with `ConnectivityWaitError::UnreachableState` as soon as the virtual interface is
+    /// in a state it can't leave for `target`, e.g. `rejected` while waiting for `available`.
+    fn wait_for_virtual_interface_state(
+        &self,
+        virtual_interface_id: &str,
+        target: VirtualInterfaceState,
+        config: WaiterConfig,
+    ) -> RusotoFuture<VirtualInterface, ConnectivityWaitError> {
+        let client = self.clone();
+        let virtual_interface_id = virtual_interface_id.to_owned();
+        let timeout = RusotoError::Service(ConnectivityWaitError::TimedOut(format!(
+            "Virtual interface {} did not become {}",
+            virtual_interface_id, target
+        )));
+
+        let future = poll_until(
+            config,
+            move || {
+                let target = target.clone();
+                describe_virtual_interface(&client, &virtual_interface_id).and_then(
+                    move |virtual_interface| {
+                        let state = virtual_interface_state(&virtual_interface);
+                        if state == target {
+                            Ok(Some(virtual_interface))
+                        } else if virtual_interface_state_reachable(&state, &target) {
+                            Ok(None)
+                        } else {
+                            Err(unreachable(&virtual_interface, &state, "become", &target))
+                        }
+                    },
+                )
+            },
+            timeout,
+        );
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Polls `DescribeVirtualInterfaces` until the virtual interface has BGP peers and every one
+    /// of them which isn't being deleted is up, and returns it.
+    ///
+    /// Fails with `ConnectivityWaitError::UnreachableState` as soon as the virtual interface is
+    /// `rejected` or being deleted.
+    fn wait_for_bgp_up(
+        &self,
+        virtual_interface_id: &str,
+        config: WaiterConfig,
+    ) -> RusotoFuture<VirtualInterface, ConnectivityWaitError> {
+        let client = self.clone();
+        let virtual_interface_id = virtual_interface_id.to_owned();
+        let timeout = RusotoError::Service(ConnectivityWaitError::TimedOut(format!(
+            "BGP peers of virtual interface {} did not come up",
+            virtual_interface_id
+        )));
+
+        let future = poll_until(
+            config,
+            move || {
+                describe_virtual_interface(&client, &virtual_interface_id).and_then(
+                    |virtual_interface| {
+                        let state = virtual_interface_state(&virtual_interface);
+                        let available = VirtualInterfaceState::Available;
+                        if !virtual_interface_state_reachable(&state, &available) {
+                            Err(unreachable(&virtual_interface, &state, "bring up", &"BGP"))
+                        } else if is_bgp_up(&virtual_interface) {
+                            Ok(Some(virtual_interface))
+                        } else {
+                            Ok(None)
+                        }
+                    },
+                )
+            },
+            timeout,
+        );
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Polls `DescribeVirtualInterfaces` every `interval`, streaming every change of the BGP
+    /// status of the virtual interface's peers, starting with their status when first polled.
+    ///
+    /// The stream ends once the virtual interface is deleted or rejected.
+    fn bgp_transitions(
+        &self,
+        virtual_interface_id: &str,
+        interval: Duration,
+    ) -> BgpTransitionStream {
+        let client = self.clone();
+        let virtual_interface_id = virtual_interface_id.to_owned();
+        let initial = Some((HashMap::new(), true));
+        let batches = stream::unfold(initial, move |state| {
+            let (previous, first) = state?;
+            let delay: Box<
+                dyn Future<Item = (), Error = RusotoError<ConnectivityWaitError>> + Send,
+            > = if first {
+                Box::new(future::ok(()))
+            } else {
+                Box::new(sleep(interval))
+            };
+            let client = client.clone();
+            let virtual_interface_id = virtual_interface_id.clone();
+            Some(delay.and_then(move |_| {
+                describe_virtual_interface(&client, &virtual_interface_id).map(
+                    move |virtual_interface| {
+                        let (transitions, current) =
+                            bgp_transitions_since(&previous, &virtual_interface);
+                        let next = match virtual_interface_state(&virtual_interface) {
+                            VirtualInterfaceState::Deleted | VirtualInterfaceState::Rejected => {
+                                None
+                            }
+                            _ => Some((current, false)),
+                        };
+                        (transitions, next)
+                    },
+                )
+            }))
+        });
+        Box::new(batches.map(stream::iter_ok).flatten())
+    }
+}
+
+impl<T: DirectConnect + Clone + Send + 'static> ConnectivityWaiterExt for T {}
+
+fn virtual_interface_state(virtual_interface: &VirtualInterface) -> VirtualInterfaceState {
+    virtual_interface
+        .virtual_interface_state
+        .clone()
+        .unwrap_or_else(|| VirtualInterfaceState::Unknown(String::new()))
+}
+
+fn unreachable(
+    virtual_interface: &VirtualInterface,
+    state: &VirtualInterfaceState,
+    action: &str,
+    target: &dyn fmt::Display,
+) -> RusotoError<ConnectivityWaitError> {
+    RusotoError::Service(ConnectivityWaitError::UnreachableState(format!(
+        "Virtual interface {} is {}, and can't {} {}",
+        virtual_interface
+            .virtual_interface_id
+            .clone()
+            .unwrap_or_default(),
+        state,
+        action,
+        target
+    )))
+}
+
+fn describe_connection<C: DirectConnect>(
+    client: &C,
+    connection_id: &str,
+) -> impl Future<Item = Connection, Error = RusotoError<ConnectivityWaitError>> {
+    let not_found = format!("Connection {} not found", connection_id);
+    client
+        .describe_connections(DescribeConnectionsRequest {
+            connection_id: Some(connection_id.to_owned()),
+        })
+        .map_err(|e| e.map_service(ConnectivityWaitError::DescribeConnections))
+        .and_then(move |output| {
+            output
+                .connections
+                .and_then(|connections| connections.into_iter().next())
+                .ok_or_else(|| RusotoError::Service(ConnectivityWaitError::NotFound(not_found)))
+        })
+}
+
+fn describe_virtual_interface<C: DirectConnect>(
+    client: &C,
+    virtual_interface_id: &str,
+) -> impl Future<Item = VirtualInterface, Error = RusotoError<ConnectivityWaitError>> {
+    let not_found = format!("Virtual interface {} not found", virtual_interface_id);
+    client
+        .describe_virtual_interfaces(DescribeVirtualInterfacesRequest {
+            virtual_interface_id: Some(virtual_interface_id.to_owned()),
+            ..Default::default()
+        })
+        .map_err(|e| e.map_service(ConnectivityWaitError::DescribeVirtualInterfaces))
+        .and_then(move |output| {
+            output
+                .virtual_interfaces
+                .and_then(|virtual_interfaces| virtual_interfaces.into_iter().next())
+                .ok_or_else(|| RusotoError::Service(ConnectivityWaitError::NotFound(not_found)))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn peer(id: &str, state: &str, status: &str) -> BGPPeer {
+        BGPPeer {
+            bgp_peer_id: Some(id.to_owned()),
+            bgp_peer_state: Some(state.into()),
+            bgp_status: Some(status.into()),
+            address_family: Some("ipv4".into()),
+            ..Default::default()
+        }
+    }
+
+    fn virtual_interface(peers: Vec<BGPPeer>) -> VirtualInterface {
+        VirtualInterface {
+            virtual_interface_id: Some("dxvif-ffhhk74f".to_owned()),
+            virtual_interface_state: Some(VirtualInterfaceState::Available),
+            bgp_peers: Some(peers),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn terminal_states_are_unreachable() {
+        use self::ConnectionState::*;
+
+        assert!(connection_state_reachable(&Pending, &Available));
+        assert!(connection_state_reachable(&Down, &Available));
+        assert!(!connection_state_reachable(&Rejected, &Available));
+        assert!(!connection_state_reachable(&Deleting, &Available));
+        assert!(connection_state_reachable(&Deleting, &Deleted));
+        assert!(connection_state_reachable(&Deleted, &Deleted));
+        assert!(!virtual_interface_state_reachable(
+            &VirtualInterfaceState::Deleted,
+            &VirtualInterfaceState::Available
+        ));
+        assert!(virtual_interface_state_reachable(
+            &VirtualInterfaceState::Confirming,
+            &VirtualInterfaceState::Available
+        ));
+    }
+
+    #[test]
+    fn bgp_is_up_once_every_active_peer_is() {
+        assert!(!is_bgp_up(&virtual_interface(vec![])));
+        assert!(!is_bgp_up(&virtual_interface(vec![
+            peer("dxpeer-1", "available", "up"),
+            peer("dxpeer-2", "available", "down"),
+        ])));
+        assert!(is_bgp_up(&virtual_interface(vec![
+            peer("dxpeer-1", "available", "up"),
+            peer("dxpeer-2", "deleting", "down"),
+        ])));
+    }
+
+    #[test]
+    fn streams_bgp_transitions() {
+        let (transitions, current) = bgp_transitions_since(
+            &HashMap::new(),
+            &virtual_interface(vec![peer("dxpeer-1", "pending", "down")]),
+        );
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(transitions[0].from, None);
+        assert_eq!(transitions[0].to, BGPStatus::Down);
+
+        let (transitions, current) = bgp_transitions_since(
+            &current,
+            &virtual_interface(vec![
+                peer("dxpeer-1", "available", "up"),
+                peer("dxpeer-2", "pending", "down"),
+            ]),
+        );
+        assert_eq!(
+            transitions
+                .iter()
+                .map(|transition| (transition.bgp_peer_id.as_str(), transition.is_up()))
+                .collect::<Vec<_>>(),
+            vec![("dxpeer-1", true), ("dxpeer-2", false)]
+        );
+
+        let (transitions, _) = bgp_transitions_since(
+            &current,
+            &virtual_interface(vec![
+                peer("dxpeer-1", "available", "down"),
+                peer("dxpeer-2", "pending", "down"),
+            ]),
+        );
+        assert_eq!(transitions.len(), 1);
+        assert!(transitions[0].went_down());
+        assert_eq!(transitions[0].virtual_interface_id, "dxvif-ffhhk74f");
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AcceptDirectConnectGatewayAssociationProposalRequest {
@@ -40,6 +41,73 @@ pub struct AcceptDirectConnectGatewayAssociationProposalRequest {
     pub proposal_id: String,
 }
 
+impl AcceptDirectConnectGatewayAssociationProposalRequest {
+    /// Returns a builder for `AcceptDirectConnectGatewayAssociationProposalRequest`, checking its required members when built.
+    pub fn builder() -> AcceptDirectConnectGatewayAssociationProposalRequestBuilder {
+        AcceptDirectConnectGatewayAssociationProposalRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AcceptDirectConnectGatewayAssociationProposalRequest`](struct.AcceptDirectConnectGatewayAssociationProposalRequest.html).
+#[derive(Default, Debug)]
+pub struct AcceptDirectConnectGatewayAssociationProposalRequestBuilder {
+    associated_gateway_owner_account: Option<String>,
+    direct_connect_gateway_id: Option<String>,
+    override_allowed_prefixes_to_direct_connect_gateway: Option<Vec<RouteFilterPrefix>>,
+    proposal_id: Option<String>,
+}
+
+impl AcceptDirectConnectGatewayAssociationProposalRequestBuilder {
+    /// Sets the [`associated_gateway_owner_account`](struct.AcceptDirectConnectGatewayAssociationProposalRequest.html#structfield.associated_gateway_owner_account) member.
+    pub fn associated_gateway_owner_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.associated_gateway_owner_account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`direct_connect_gateway_id`](struct.AcceptDirectConnectGatewayAssociationProposalRequest.html#structfield.direct_connect_gateway_id) member.
+    pub fn direct_connect_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`override_allowed_prefixes_to_direct_connect_gateway`](struct.AcceptDirectConnectGatewayAssociationProposalRequest.html#structfield.override_allowed_prefixes_to_direct_connect_gateway) member.
+    pub fn override_allowed_prefixes_to_direct_connect_gateway<T: Into<Vec<RouteFilterPrefix>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.override_allowed_prefixes_to_direct_connect_gateway = Some(value.into());
+        self
+    }
+
+    /// Sets the [`proposal_id`](struct.AcceptDirectConnectGatewayAssociationProposalRequest.html#structfield.proposal_id) member.
+    pub fn proposal_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.proposal_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AcceptDirectConnectGatewayAssociationProposalRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        AcceptDirectConnectGatewayAssociationProposalRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(AcceptDirectConnectGatewayAssociationProposalRequest {
+            associated_gateway_owner_account: self.associated_gateway_owner_account.ok_or(
+                ::rusoto_core::BuildError::missing("associated_gateway_owner_account"),
+            )?,
+            direct_connect_gateway_id: self.direct_connect_gateway_id.ok_or(
+                ::rusoto_core::BuildError::missing("direct_connect_gateway_id"),
+            )?,
+            override_allowed_prefixes_to_direct_connect_gateway: self
+                .override_allowed_prefixes_to_direct_connect_gateway,
+            proposal_id: self
+                .proposal_id
+                .ok_or(::rusoto_core::BuildError::missing("proposal_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AcceptDirectConnectGatewayAssociationProposalResult {
@@ -48,6 +116,78 @@ pub struct AcceptDirectConnectGatewayAssociationProposalResult {
     pub direct_connect_gateway_association: Option<DirectConnectGatewayAssociation>,
 }
 
+/// <p>The address family for the BGP peer.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AddressFamily {
+    /// <p><code>ipv4</code></p>
+    Ipv4,
+    /// <p><code>ipv6</code></p>
+    Ipv6,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AddressFamily {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AddressFamily::Ipv4 => "ipv4",
+            AddressFamily::Ipv6 => "ipv6",
+            AddressFamily::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AddressFamily {
+    fn from(value: &'a str) -> AddressFamily {
+        match value {
+            "ipv4" => AddressFamily::Ipv4,
+            "ipv6" => AddressFamily::Ipv6,
+            _ => AddressFamily::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AddressFamily {
+    fn from(value: String) -> AddressFamily {
+        AddressFamily::from(value.as_str())
+    }
+}
+
+impl From<AddressFamily> for String {
+    fn from(value: AddressFamily) -> String {
+        match value {
+            AddressFamily::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AddressFamily {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AddressFamily {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AddressFamily {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AddressFamily, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AddressFamily::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AllocateConnectionOnInterconnectRequest {
     /// <p>The bandwidth of the connection. The possible values are 50Mbps, 100Mbps, 200Mbps, 300Mbps, 400Mbps, 500Mbps, 1Gbps, 2Gbps, 5Gbps, and 10Gbps. Note that only those AWS Direct Connect Partners who have met specific requirements are allowed to create a 1Gbps, 2Gbps, 5Gbps or 10Gbps hosted connection.</p>
@@ -67,6 +207,79 @@ pub struct AllocateConnectionOnInterconnectRequest {
     pub vlan: i64,
 }
 
+impl AllocateConnectionOnInterconnectRequest {
+    /// Returns a builder for `AllocateConnectionOnInterconnectRequest`, checking its required members when built.
+    pub fn builder() -> AllocateConnectionOnInterconnectRequestBuilder {
+        AllocateConnectionOnInterconnectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AllocateConnectionOnInterconnectRequest`](struct.AllocateConnectionOnInterconnectRequest.html).
+#[derive(Default, Debug)]
+pub struct AllocateConnectionOnInterconnectRequestBuilder {
+    bandwidth: Option<String>,
+    connection_name: Option<String>,
+    interconnect_id: Option<String>,
+    owner_account: Option<String>,
+    vlan: Option<i64>,
+}
+
+impl AllocateConnectionOnInterconnectRequestBuilder {
+    /// Sets the [`bandwidth`](struct.AllocateConnectionOnInterconnectRequest.html#structfield.bandwidth) member.
+    pub fn bandwidth<T: Into<String>>(mut self, value: T) -> Self {
+        self.bandwidth = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_name`](struct.AllocateConnectionOnInterconnectRequest.html#structfield.connection_name) member.
+    pub fn connection_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`interconnect_id`](struct.AllocateConnectionOnInterconnectRequest.html#structfield.interconnect_id) member.
+    pub fn interconnect_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.interconnect_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`owner_account`](struct.AllocateConnectionOnInterconnectRequest.html#structfield.owner_account) member.
+    pub fn owner_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.owner_account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vlan`](struct.AllocateConnectionOnInterconnectRequest.html#structfield.vlan) member.
+    pub fn vlan<T: Into<i64>>(mut self, value: T) -> Self {
+        self.vlan = Some(value.into());
+        self
+    }
+
+    /// Builds the `AllocateConnectionOnInterconnectRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AllocateConnectionOnInterconnectRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AllocateConnectionOnInterconnectRequest {
+            bandwidth: self
+                .bandwidth
+                .ok_or(::rusoto_core::BuildError::missing("bandwidth"))?,
+            connection_name: self
+                .connection_name
+                .ok_or(::rusoto_core::BuildError::missing("connection_name"))?,
+            interconnect_id: self
+                .interconnect_id
+                .ok_or(::rusoto_core::BuildError::missing("interconnect_id"))?,
+            owner_account: self
+                .owner_account
+                .ok_or(::rusoto_core::BuildError::missing("owner_account"))?,
+            vlan: self
+                .vlan
+                .ok_or(::rusoto_core::BuildError::missing("vlan"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AllocateHostedConnectionRequest {
     /// <p>The bandwidth of the connection. The possible values are 50Mbps, 100Mbps, 200Mbps, 300Mbps, 400Mbps, 500Mbps, 1Gbps, 2Gbps, 5Gbps, and 10Gbps. Note that only those AWS Direct Connect Partners who have met specific requirements are allowed to create a 1Gbps, 2Gbps, 5Gbps or 10Gbps hosted connection. </p>
@@ -90,6 +303,86 @@ pub struct AllocateHostedConnectionRequest {
     pub vlan: i64,
 }
 
+impl AllocateHostedConnectionRequest {
+    /// Returns a builder for `AllocateHostedConnectionRequest`, checking its required members when built.
+    pub fn builder() -> AllocateHostedConnectionRequestBuilder {
+        AllocateHostedConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AllocateHostedConnectionRequest`](struct.AllocateHostedConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct AllocateHostedConnectionRequestBuilder {
+    bandwidth: Option<String>,
+    connection_id: Option<String>,
+    connection_name: Option<String>,
+    owner_account: Option<String>,
+    tags: Option<Vec<Tag>>,
+    vlan: Option<i64>,
+}
+
+impl AllocateHostedConnectionRequestBuilder {
+    /// Sets the [`bandwidth`](struct.AllocateHostedConnectionRequest.html#structfield.bandwidth) member.
+    pub fn bandwidth<T: Into<String>>(mut self, value: T) -> Self {
+        self.bandwidth = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_id`](struct.AllocateHostedConnectionRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_name`](struct.AllocateHostedConnectionRequest.html#structfield.connection_name) member.
+    pub fn connection_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`owner_account`](struct.AllocateHostedConnectionRequest.html#structfield.owner_account) member.
+    pub fn owner_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.owner_account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AllocateHostedConnectionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vlan`](struct.AllocateHostedConnectionRequest.html#structfield.vlan) member.
+    pub fn vlan<T: Into<i64>>(mut self, value: T) -> Self {
+        self.vlan = Some(value.into());
+        self
+    }
+
+    /// Builds the `AllocateHostedConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AllocateHostedConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(AllocateHostedConnectionRequest {
+            bandwidth: self
+                .bandwidth
+                .ok_or(::rusoto_core::BuildError::missing("bandwidth"))?,
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            connection_name: self
+                .connection_name
+                .ok_or(::rusoto_core::BuildError::missing("connection_name"))?,
+            owner_account: self
+                .owner_account
+                .ok_or(::rusoto_core::BuildError::missing("owner_account"))?,
+            tags: self.tags,
+            vlan: self
+                .vlan
+                .ok_or(::rusoto_core::BuildError::missing("vlan"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AllocatePrivateVirtualInterfaceRequest {
     /// <p>The ID of the connection on which the private virtual interface is provisioned.</p>
@@ -103,6 +396,66 @@ pub struct AllocatePrivateVirtualInterfaceRequest {
     pub owner_account: String,
 }
 
+impl AllocatePrivateVirtualInterfaceRequest {
+    /// Returns a builder for `AllocatePrivateVirtualInterfaceRequest`, checking its required members when built.
+    pub fn builder() -> AllocatePrivateVirtualInterfaceRequestBuilder {
+        AllocatePrivateVirtualInterfaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AllocatePrivateVirtualInterfaceRequest`](struct.AllocatePrivateVirtualInterfaceRequest.html).
+#[derive(Default, Debug)]
+pub struct AllocatePrivateVirtualInterfaceRequestBuilder {
+    connection_id: Option<String>,
+    new_private_virtual_interface_allocation: Option<NewPrivateVirtualInterfaceAllocation>,
+    owner_account: Option<String>,
+}
+
+impl AllocatePrivateVirtualInterfaceRequestBuilder {
+    /// Sets the [`connection_id`](struct.AllocatePrivateVirtualInterfaceRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_private_virtual_interface_allocation`](struct.AllocatePrivateVirtualInterfaceRequest.html#structfield.new_private_virtual_interface_allocation) member.
+    pub fn new_private_virtual_interface_allocation<
+        T: Into<NewPrivateVirtualInterfaceAllocation>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.new_private_virtual_interface_allocation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`owner_account`](struct.AllocatePrivateVirtualInterfaceRequest.html#structfield.owner_account) member.
+    pub fn owner_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.owner_account = Some(value.into());
+        self
+    }
+
+    /// Builds the `AllocatePrivateVirtualInterfaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AllocatePrivateVirtualInterfaceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AllocatePrivateVirtualInterfaceRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            new_private_virtual_interface_allocation: self
+                .new_private_virtual_interface_allocation
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "new_private_virtual_interface_allocation",
+                ))?,
+            owner_account: self
+                .owner_account
+                .ok_or(::rusoto_core::BuildError::missing("owner_account"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AllocatePublicVirtualInterfaceRequest {
     /// <p>The ID of the connection on which the public virtual interface is provisioned.</p>
@@ -116,6 +469,64 @@ pub struct AllocatePublicVirtualInterfaceRequest {
     pub owner_account: String,
 }
 
+impl AllocatePublicVirtualInterfaceRequest {
+    /// Returns a builder for `AllocatePublicVirtualInterfaceRequest`, checking its required members when built.
+    pub fn builder() -> AllocatePublicVirtualInterfaceRequestBuilder {
+        AllocatePublicVirtualInterfaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AllocatePublicVirtualInterfaceRequest`](struct.AllocatePublicVirtualInterfaceRequest.html).
+#[derive(Default, Debug)]
+pub struct AllocatePublicVirtualInterfaceRequestBuilder {
+    connection_id: Option<String>,
+    new_public_virtual_interface_allocation: Option<NewPublicVirtualInterfaceAllocation>,
+    owner_account: Option<String>,
+}
+
+impl AllocatePublicVirtualInterfaceRequestBuilder {
+    /// Sets the [`connection_id`](struct.AllocatePublicVirtualInterfaceRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_public_virtual_interface_allocation`](struct.AllocatePublicVirtualInterfaceRequest.html#structfield.new_public_virtual_interface_allocation) member.
+    pub fn new_public_virtual_interface_allocation<T: Into<NewPublicVirtualInterfaceAllocation>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.new_public_virtual_interface_allocation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`owner_account`](struct.AllocatePublicVirtualInterfaceRequest.html#structfield.owner_account) member.
+    pub fn owner_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.owner_account = Some(value.into());
+        self
+    }
+
+    /// Builds the `AllocatePublicVirtualInterfaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AllocatePublicVirtualInterfaceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AllocatePublicVirtualInterfaceRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            new_public_virtual_interface_allocation: self
+                .new_public_virtual_interface_allocation
+                .ok_or(::rusoto_core::BuildError::missing(
+                "new_public_virtual_interface_allocation",
+            ))?,
+            owner_account: self
+                .owner_account
+                .ok_or(::rusoto_core::BuildError::missing("owner_account"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AllocateTransitVirtualInterfaceRequest {
     /// <p>The ID of the connection on which the transit virtual interface is provisioned.</p>
@@ -129,6 +540,66 @@ pub struct AllocateTransitVirtualInterfaceRequest {
     pub owner_account: String,
 }
 
+impl AllocateTransitVirtualInterfaceRequest {
+    /// Returns a builder for `AllocateTransitVirtualInterfaceRequest`, checking its required members when built.
+    pub fn builder() -> AllocateTransitVirtualInterfaceRequestBuilder {
+        AllocateTransitVirtualInterfaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AllocateTransitVirtualInterfaceRequest`](struct.AllocateTransitVirtualInterfaceRequest.html).
+#[derive(Default, Debug)]
+pub struct AllocateTransitVirtualInterfaceRequestBuilder {
+    connection_id: Option<String>,
+    new_transit_virtual_interface_allocation: Option<NewTransitVirtualInterfaceAllocation>,
+    owner_account: Option<String>,
+}
+
+impl AllocateTransitVirtualInterfaceRequestBuilder {
+    /// Sets the [`connection_id`](struct.AllocateTransitVirtualInterfaceRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_transit_virtual_interface_allocation`](struct.AllocateTransitVirtualInterfaceRequest.html#structfield.new_transit_virtual_interface_allocation) member.
+    pub fn new_transit_virtual_interface_allocation<
+        T: Into<NewTransitVirtualInterfaceAllocation>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.new_transit_virtual_interface_allocation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`owner_account`](struct.AllocateTransitVirtualInterfaceRequest.html#structfield.owner_account) member.
+    pub fn owner_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.owner_account = Some(value.into());
+        self
+    }
+
+    /// Builds the `AllocateTransitVirtualInterfaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AllocateTransitVirtualInterfaceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AllocateTransitVirtualInterfaceRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            new_transit_virtual_interface_allocation: self
+                .new_transit_virtual_interface_allocation
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "new_transit_virtual_interface_allocation",
+                ))?,
+            owner_account: self
+                .owner_account
+                .ok_or(::rusoto_core::BuildError::missing("owner_account"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AllocateTransitVirtualInterfaceResult {
@@ -147,6 +618,48 @@ pub struct AssociateConnectionWithLagRequest {
     pub lag_id: String,
 }
 
+impl AssociateConnectionWithLagRequest {
+    /// Returns a builder for `AssociateConnectionWithLagRequest`, checking its required members when built.
+    pub fn builder() -> AssociateConnectionWithLagRequestBuilder {
+        AssociateConnectionWithLagRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateConnectionWithLagRequest`](struct.AssociateConnectionWithLagRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateConnectionWithLagRequestBuilder {
+    connection_id: Option<String>,
+    lag_id: Option<String>,
+}
+
+impl AssociateConnectionWithLagRequestBuilder {
+    /// Sets the [`connection_id`](struct.AssociateConnectionWithLagRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lag_id`](struct.AssociateConnectionWithLagRequest.html#structfield.lag_id) member.
+    pub fn lag_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.lag_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateConnectionWithLagRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateConnectionWithLagRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateConnectionWithLagRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            lag_id: self
+                .lag_id
+                .ok_or(::rusoto_core::BuildError::missing("lag_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateHostedConnectionRequest {
     /// <p>The ID of the hosted connection.</p>
@@ -157,6 +670,48 @@ pub struct AssociateHostedConnectionRequest {
     pub parent_connection_id: String,
 }
 
+impl AssociateHostedConnectionRequest {
+    /// Returns a builder for `AssociateHostedConnectionRequest`, checking its required members when built.
+    pub fn builder() -> AssociateHostedConnectionRequestBuilder {
+        AssociateHostedConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateHostedConnectionRequest`](struct.AssociateHostedConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateHostedConnectionRequestBuilder {
+    connection_id: Option<String>,
+    parent_connection_id: Option<String>,
+}
+
+impl AssociateHostedConnectionRequestBuilder {
+    /// Sets the [`connection_id`](struct.AssociateHostedConnectionRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_connection_id`](struct.AssociateHostedConnectionRequest.html#structfield.parent_connection_id) member.
+    pub fn parent_connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_connection_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateHostedConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateHostedConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateHostedConnectionRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            parent_connection_id: self
+                .parent_connection_id
+                .ok_or(::rusoto_core::BuildError::missing("parent_connection_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateVirtualInterfaceRequest {
     /// <p>The ID of the LAG or connection.</p>
@@ -167,6 +722,48 @@ pub struct AssociateVirtualInterfaceRequest {
     pub virtual_interface_id: String,
 }
 
+impl AssociateVirtualInterfaceRequest {
+    /// Returns a builder for `AssociateVirtualInterfaceRequest`, checking its required members when built.
+    pub fn builder() -> AssociateVirtualInterfaceRequestBuilder {
+        AssociateVirtualInterfaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateVirtualInterfaceRequest`](struct.AssociateVirtualInterfaceRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateVirtualInterfaceRequestBuilder {
+    connection_id: Option<String>,
+    virtual_interface_id: Option<String>,
+}
+
+impl AssociateVirtualInterfaceRequestBuilder {
+    /// Sets the [`connection_id`](struct.AssociateVirtualInterfaceRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_interface_id`](struct.AssociateVirtualInterfaceRequest.html#structfield.virtual_interface_id) member.
+    pub fn virtual_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_interface_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateVirtualInterfaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateVirtualInterfaceRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateVirtualInterfaceRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            virtual_interface_id: self
+                .virtual_interface_id
+                .ok_or(::rusoto_core::BuildError::missing("virtual_interface_id"))?,
+        })
+    }
+}
+
 /// <p>Information about the associated gateway.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -186,7 +783,7 @@ pub struct AssociatedGateway {
     /// <p>The type of associated gateway.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<GatewayType>,
 }
 
 /// <p>Information about a BGP peer.</p>
@@ -196,7 +793,7 @@ pub struct BGPPeer {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -220,17 +817,177 @@ pub struct BGPPeer {
     /// <p><p>The state of the BGP peer. The following are the possible values:</p> <ul> <li> <p> <code>verifying</code>: The BGP peering addresses or ASN require validation before the BGP peer can be created. This state applies only to public virtual interfaces.</p> </li> <li> <p> <code>pending</code>: The BGP peer is created, and remains in this state until it is ready to be established.</p> </li> <li> <p> <code>available</code>: The BGP peer is ready to be established.</p> </li> <li> <p> <code>deleting</code>: The BGP peer is being deleted.</p> </li> <li> <p> <code>deleted</code>: The BGP peer is deleted and cannot be established.</p> </li> </ul></p>
     #[serde(rename = "bgpPeerState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bgp_peer_state: Option<String>,
+    pub bgp_peer_state: Option<BGPPeerState>,
     /// <p><p>The status of the BGP peer. The following are the possible values:</p> <ul> <li> <p> <code>up</code>: The BGP peer is established. This state does not indicate the state of the routing function. Ensure that you are receiving routes over the BGP session.</p> </li> <li> <p> <code>down</code>: The BGP peer is down.</p> </li> <li> <p> <code>unknown</code>: The BGP peer status is not available.</p> </li> </ul></p>
     #[serde(rename = "bgpStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bgp_status: Option<String>,
+    pub bgp_status: Option<BGPStatus>,
     /// <p>The IP address assigned to the customer interface.</p>
     #[serde(rename = "customerAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub customer_address: Option<String>,
 }
 
+/// <p><p>The state of the BGP peer. The following are the possible values:</p> <ul> <li> <p> <code>verifying</code>: The BGP peering addresses or ASN require validation before the BGP peer can be created. This state applies only to public virtual interfaces.</p> </li> <li> <p> <code>pending</code>: The BGP peer is created, and remains in this state until it is ready to be established.</p> </li> <li> <p> <code>available</code>: The BGP peer is ready to be established.</p> </li> <li> <p> <code>deleting</code>: The BGP peer is being deleted.</p> </li> <li> <p> <code>deleted</code>: The BGP peer is deleted and cannot be established.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BGPPeerState {
+    /// <p><code>verifying</code> - The BGP peering addresses or ASN require validation before the BGP peer can be created. This state applies only to public virtual interfaces.</p>
+    Verifying,
+    /// <p><code>pending</code> - The BGP peer is created, and remains in this state until it is ready to be established.</p>
+    Pending,
+    /// <p><code>available</code> - The BGP peer is ready to be established.</p>
+    Available,
+    /// <p><code>deleting</code> - The BGP peer is being deleted.</p>
+    Deleting,
+    /// <p><code>deleted</code> - The BGP peer is deleted and cannot be established.</p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BGPPeerState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BGPPeerState::Verifying => "verifying",
+            BGPPeerState::Pending => "pending",
+            BGPPeerState::Available => "available",
+            BGPPeerState::Deleting => "deleting",
+            BGPPeerState::Deleted => "deleted",
+            BGPPeerState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BGPPeerState {
+    fn from(value: &'a str) -> BGPPeerState {
+        match value {
+            "verifying" => BGPPeerState::Verifying,
+            "pending" => BGPPeerState::Pending,
+            "available" => BGPPeerState::Available,
+            "deleting" => BGPPeerState::Deleting,
+            "deleted" => BGPPeerState::Deleted,
+            _ => BGPPeerState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BGPPeerState {
+    fn from(value: String) -> BGPPeerState {
+        BGPPeerState::from(value.as_str())
+    }
+}
+
+impl From<BGPPeerState> for String {
+    fn from(value: BGPPeerState) -> String {
+        match value {
+            BGPPeerState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BGPPeerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BGPPeerState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BGPPeerState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BGPPeerState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BGPPeerState::from(value))
+    }
+}
+
+/// <p><p>The status of the BGP peer. The following are the possible values:</p> <ul> <li> <p> <code>up</code>: The BGP peer is established. This state does not indicate the state of the routing function. Ensure that you are receiving routes over the BGP session.</p> </li> <li> <p> <code>down</code>: The BGP peer is down.</p> </li> <li> <p> <code>unknown</code>: The BGP peer status is not available.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BGPStatus {
+    /// <p><code>up</code> - The BGP peer is established. This state does not indicate the state of the routing function. Ensure that you are receiving routes over the BGP session.</p>
+    Up,
+    /// <p><code>down</code> - The BGP peer is down.</p>
+    Down,
+    /// <p><code>unknown</code> - The BGP peer status is not available.</p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BGPStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BGPStatus::Up => "up",
+            BGPStatus::Down => "down",
+            BGPStatus::UnknownValue => "unknown",
+            BGPStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BGPStatus {
+    fn from(value: &'a str) -> BGPStatus {
+        match value {
+            "up" => BGPStatus::Up,
+            "down" => BGPStatus::Down,
+            "unknown" => BGPStatus::UnknownValue,
+            _ => BGPStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BGPStatus {
+    fn from(value: String) -> BGPStatus {
+        BGPStatus::from(value.as_str())
+    }
+}
+
+impl From<BGPStatus> for String {
+    fn from(value: BGPStatus) -> String {
+        match value {
+            BGPStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BGPStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BGPStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BGPStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BGPStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BGPStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ConfirmConnectionRequest {
     /// <p>The ID of the hosted connection.</p>
@@ -238,13 +995,45 @@ pub struct ConfirmConnectionRequest {
     pub connection_id: String,
 }
 
+impl ConfirmConnectionRequest {
+    /// Returns a builder for `ConfirmConnectionRequest`, checking its required members when built.
+    pub fn builder() -> ConfirmConnectionRequestBuilder {
+        ConfirmConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ConfirmConnectionRequest`](struct.ConfirmConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct ConfirmConnectionRequestBuilder {
+    connection_id: Option<String>,
+}
+
+impl ConfirmConnectionRequestBuilder {
+    /// Sets the [`connection_id`](struct.ConfirmConnectionRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfirmConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ConfirmConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(ConfirmConnectionRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ConfirmConnectionResponse {
     /// <p><p>The state of the connection. The following are the possible values:</p> <ul> <li> <p> <code>ordering</code>: The initial state of a hosted connection provisioned on an interconnect. The connection stays in the ordering state until the owner of the hosted connection confirms or declines the connection order.</p> </li> <li> <p> <code>requested</code>: The initial state of a standard connection. The connection stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The connection has been approved and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up and the connection is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The connection is being deleted.</p> </li> <li> <p> <code>deleted</code>: The connection has been deleted.</p> </li> <li> <p> <code>rejected</code>: A hosted connection in the <code>ordering</code> state enters the <code>rejected</code> state if it is deleted by the customer.</p> </li> <li> <p> <code>unknown</code>: The state of the connection is not available.</p> </li> </ul></p>
     #[serde(rename = "connectionState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_state: Option<String>,
+    pub connection_state: Option<ConnectionState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -262,13 +1051,62 @@ pub struct ConfirmPrivateVirtualInterfaceRequest {
     pub virtual_interface_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ConfirmPrivateVirtualInterfaceResponse {
-    /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
-    #[serde(rename = "virtualInterfaceState")]
+impl ConfirmPrivateVirtualInterfaceRequest {
+    /// Returns a builder for `ConfirmPrivateVirtualInterfaceRequest`, checking its required members when built.
+    pub fn builder() -> ConfirmPrivateVirtualInterfaceRequestBuilder {
+        ConfirmPrivateVirtualInterfaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ConfirmPrivateVirtualInterfaceRequest`](struct.ConfirmPrivateVirtualInterfaceRequest.html).
+#[derive(Default, Debug)]
+pub struct ConfirmPrivateVirtualInterfaceRequestBuilder {
+    direct_connect_gateway_id: Option<String>,
+    virtual_gateway_id: Option<String>,
+    virtual_interface_id: Option<String>,
+}
+
+impl ConfirmPrivateVirtualInterfaceRequestBuilder {
+    /// Sets the [`direct_connect_gateway_id`](struct.ConfirmPrivateVirtualInterfaceRequest.html#structfield.direct_connect_gateway_id) member.
+    pub fn direct_connect_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_gateway_id`](struct.ConfirmPrivateVirtualInterfaceRequest.html#structfield.virtual_gateway_id) member.
+    pub fn virtual_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_interface_id`](struct.ConfirmPrivateVirtualInterfaceRequest.html#structfield.virtual_interface_id) member.
+    pub fn virtual_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_interface_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfirmPrivateVirtualInterfaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ConfirmPrivateVirtualInterfaceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ConfirmPrivateVirtualInterfaceRequest {
+            direct_connect_gateway_id: self.direct_connect_gateway_id,
+            virtual_gateway_id: self.virtual_gateway_id,
+            virtual_interface_id: self
+                .virtual_interface_id
+                .ok_or(::rusoto_core::BuildError::missing("virtual_interface_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ConfirmPrivateVirtualInterfaceResponse {
+    /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
+    #[serde(rename = "virtualInterfaceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_state: Option<String>,
+    pub virtual_interface_state: Option<VirtualInterfaceState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -278,13 +1116,46 @@ pub struct ConfirmPublicVirtualInterfaceRequest {
     pub virtual_interface_id: String,
 }
 
+impl ConfirmPublicVirtualInterfaceRequest {
+    /// Returns a builder for `ConfirmPublicVirtualInterfaceRequest`, checking its required members when built.
+    pub fn builder() -> ConfirmPublicVirtualInterfaceRequestBuilder {
+        ConfirmPublicVirtualInterfaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ConfirmPublicVirtualInterfaceRequest`](struct.ConfirmPublicVirtualInterfaceRequest.html).
+#[derive(Default, Debug)]
+pub struct ConfirmPublicVirtualInterfaceRequestBuilder {
+    virtual_interface_id: Option<String>,
+}
+
+impl ConfirmPublicVirtualInterfaceRequestBuilder {
+    /// Sets the [`virtual_interface_id`](struct.ConfirmPublicVirtualInterfaceRequest.html#structfield.virtual_interface_id) member.
+    pub fn virtual_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_interface_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfirmPublicVirtualInterfaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ConfirmPublicVirtualInterfaceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ConfirmPublicVirtualInterfaceRequest {
+            virtual_interface_id: self
+                .virtual_interface_id
+                .ok_or(::rusoto_core::BuildError::missing("virtual_interface_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ConfirmPublicVirtualInterfaceResponse {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_state: Option<String>,
+    pub virtual_interface_state: Option<VirtualInterfaceState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -297,13 +1168,56 @@ pub struct ConfirmTransitVirtualInterfaceRequest {
     pub virtual_interface_id: String,
 }
 
+impl ConfirmTransitVirtualInterfaceRequest {
+    /// Returns a builder for `ConfirmTransitVirtualInterfaceRequest`, checking its required members when built.
+    pub fn builder() -> ConfirmTransitVirtualInterfaceRequestBuilder {
+        ConfirmTransitVirtualInterfaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ConfirmTransitVirtualInterfaceRequest`](struct.ConfirmTransitVirtualInterfaceRequest.html).
+#[derive(Default, Debug)]
+pub struct ConfirmTransitVirtualInterfaceRequestBuilder {
+    direct_connect_gateway_id: Option<String>,
+    virtual_interface_id: Option<String>,
+}
+
+impl ConfirmTransitVirtualInterfaceRequestBuilder {
+    /// Sets the [`direct_connect_gateway_id`](struct.ConfirmTransitVirtualInterfaceRequest.html#structfield.direct_connect_gateway_id) member.
+    pub fn direct_connect_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_interface_id`](struct.ConfirmTransitVirtualInterfaceRequest.html#structfield.virtual_interface_id) member.
+    pub fn virtual_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_interface_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfirmTransitVirtualInterfaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ConfirmTransitVirtualInterfaceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ConfirmTransitVirtualInterfaceRequest {
+            direct_connect_gateway_id: self.direct_connect_gateway_id.ok_or(
+                ::rusoto_core::BuildError::missing("direct_connect_gateway_id"),
+            )?,
+            virtual_interface_id: self
+                .virtual_interface_id
+                .ok_or(::rusoto_core::BuildError::missing("virtual_interface_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ConfirmTransitVirtualInterfaceResponse {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_state: Option<String>,
+    pub virtual_interface_state: Option<VirtualInterfaceState>,
 }
 
 /// <p>Information about an AWS Direct Connect connection.</p>
@@ -333,11 +1247,11 @@ pub struct Connection {
     /// <p><p>The state of the connection. The following are the possible values:</p> <ul> <li> <p> <code>ordering</code>: The initial state of a hosted connection provisioned on an interconnect. The connection stays in the ordering state until the owner of the hosted connection confirms or declines the connection order.</p> </li> <li> <p> <code>requested</code>: The initial state of a standard connection. The connection stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The connection has been approved and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up and the connection is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The connection is being deleted.</p> </li> <li> <p> <code>deleted</code>: The connection has been deleted.</p> </li> <li> <p> <code>rejected</code>: A hosted connection in the <code>ordering</code> state enters the <code>rejected</code> state if it is deleted by the customer.</p> </li> <li> <p> <code>unknown</code>: The state of the connection is not available.</p> </li> </ul></p>
     #[serde(rename = "connectionState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_state: Option<String>,
+    pub connection_state: Option<ConnectionState>,
     /// <p>Indicates whether the connection supports a secondary BGP peer in the same address family (IPv4/IPv6).</p>
     #[serde(rename = "hasLogicalRedundancy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_logical_redundancy: Option<String>,
+    pub has_logical_redundancy: Option<HasLogicalRedundancy>,
     /// <p>Indicates whether jumbo frames (9001 MTU) are supported.</p>
     #[serde(rename = "jumboFrameCapable")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -348,8 +1262,9 @@ pub struct Connection {
     pub lag_id: Option<String>,
     /// <p>The time of the most recent call to <a>DescribeLoa</a> for this connection.</p>
     #[serde(rename = "loaIssueTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loa_issue_time: Option<f64>,
+    pub loa_issue_time: Option<EpochTimestamp>,
     /// <p>The location of the connection.</p>
     #[serde(rename = "location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -376,6 +1291,106 @@ pub struct Connection {
     pub vlan: Option<i64>,
 }
 
+/// <p><p>The state of the connection. The following are the possible values:</p> <ul> <li> <p> <code>ordering</code>: The initial state of a hosted connection provisioned on an interconnect. The connection stays in the ordering state until the owner of the hosted connection confirms or declines the connection order.</p> </li> <li> <p> <code>requested</code>: The initial state of a standard connection. The connection stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The connection has been approved and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up and the connection is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The connection is being deleted.</p> </li> <li> <p> <code>deleted</code>: The connection has been deleted.</p> </li> <li> <p> <code>rejected</code>: A hosted connection in the <code>ordering</code> state enters the <code>rejected</code> state if it is deleted by the customer.</p> </li> <li> <p> <code>unknown</code>: The state of the connection is not available.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionState {
+    /// <p><code>ordering</code> - The initial state of a hosted connection provisioned on an interconnect. The connection stays in the ordering state until the owner of the hosted connection confirms or declines the connection order.</p>
+    Ordering,
+    /// <p><code>requested</code> - The initial state of a standard connection. The connection stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p>
+    Requested,
+    /// <p><code>pending</code> - The connection has been approved and is being initialized.</p>
+    Pending,
+    /// <p><code>available</code> - The network link is up and the connection is ready for use.</p>
+    Available,
+    /// <p><code>down</code> - The network link is down.</p>
+    Down,
+    /// <p><code>deleting</code> - The connection is being deleted.</p>
+    Deleting,
+    /// <p><code>deleted</code> - The connection has been deleted.</p>
+    Deleted,
+    /// <p><code>rejected</code> - A hosted connection in the <code>ordering</code> state enters the <code>rejected</code> state if it is deleted by the customer.</p>
+    Rejected,
+    /// <p><code>unknown</code> - The state of the connection is not available.</p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConnectionState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionState::Ordering => "ordering",
+            ConnectionState::Requested => "requested",
+            ConnectionState::Pending => "pending",
+            ConnectionState::Available => "available",
+            ConnectionState::Down => "down",
+            ConnectionState::Deleting => "deleting",
+            ConnectionState::Deleted => "deleted",
+            ConnectionState::Rejected => "rejected",
+            ConnectionState::UnknownValue => "unknown",
+            ConnectionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionState {
+    fn from(value: &'a str) -> ConnectionState {
+        match value {
+            "ordering" => ConnectionState::Ordering,
+            "requested" => ConnectionState::Requested,
+            "pending" => ConnectionState::Pending,
+            "available" => ConnectionState::Available,
+            "down" => ConnectionState::Down,
+            "deleting" => ConnectionState::Deleting,
+            "deleted" => ConnectionState::Deleted,
+            "rejected" => ConnectionState::Rejected,
+            "unknown" => ConnectionState::UnknownValue,
+            _ => ConnectionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionState {
+    fn from(value: String) -> ConnectionState {
+        ConnectionState::from(value.as_str())
+    }
+}
+
+impl From<ConnectionState> for String {
+    fn from(value: ConnectionState) -> String {
+        match value {
+            ConnectionState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConnectionState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConnectionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConnectionState::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Connections {
@@ -397,6 +1412,42 @@ pub struct CreateBGPPeerRequest {
     pub virtual_interface_id: Option<String>,
 }
 
+impl CreateBGPPeerRequest {
+    /// Returns a builder for `CreateBGPPeerRequest`, checking its required members when built.
+    pub fn builder() -> CreateBGPPeerRequestBuilder {
+        CreateBGPPeerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBGPPeerRequest`](struct.CreateBGPPeerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateBGPPeerRequestBuilder {
+    new_bgp_peer: Option<NewBGPPeer>,
+    virtual_interface_id: Option<String>,
+}
+
+impl CreateBGPPeerRequestBuilder {
+    /// Sets the [`new_bgp_peer`](struct.CreateBGPPeerRequest.html#structfield.new_bgp_peer) member.
+    pub fn new_bgp_peer<T: Into<NewBGPPeer>>(mut self, value: T) -> Self {
+        self.new_bgp_peer = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_interface_id`](struct.CreateBGPPeerRequest.html#structfield.virtual_interface_id) member.
+    pub fn virtual_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_interface_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBGPPeerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateBGPPeerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateBGPPeerRequest {
+            new_bgp_peer: self.new_bgp_peer,
+            virtual_interface_id: self.virtual_interface_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateBGPPeerResponse {
@@ -427,6 +1478,74 @@ pub struct CreateConnectionRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateConnectionRequest {
+    /// Returns a builder for `CreateConnectionRequest`, checking its required members when built.
+    pub fn builder() -> CreateConnectionRequestBuilder {
+        CreateConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConnectionRequest`](struct.CreateConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateConnectionRequestBuilder {
+    bandwidth: Option<String>,
+    connection_name: Option<String>,
+    lag_id: Option<String>,
+    location: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateConnectionRequestBuilder {
+    /// Sets the [`bandwidth`](struct.CreateConnectionRequest.html#structfield.bandwidth) member.
+    pub fn bandwidth<T: Into<String>>(mut self, value: T) -> Self {
+        self.bandwidth = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_name`](struct.CreateConnectionRequest.html#structfield.connection_name) member.
+    pub fn connection_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lag_id`](struct.CreateConnectionRequest.html#structfield.lag_id) member.
+    pub fn lag_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.lag_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location`](struct.CreateConnectionRequest.html#structfield.location) member.
+    pub fn location<T: Into<String>>(mut self, value: T) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateConnectionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateConnectionRequest {
+            bandwidth: self
+                .bandwidth
+                .ok_or(::rusoto_core::BuildError::missing("bandwidth"))?,
+            connection_name: self
+                .connection_name
+                .ok_or(::rusoto_core::BuildError::missing("connection_name"))?,
+            lag_id: self.lag_id,
+            location: self
+                .location
+                .ok_or(::rusoto_core::BuildError::missing("location"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateDirectConnectGatewayAssociationProposalRequest {
     /// <p>The Amazon VPC prefixes to advertise to the Direct Connect gateway.</p>
@@ -448,6 +1567,85 @@ pub struct CreateDirectConnectGatewayAssociationProposalRequest {
     pub remove_allowed_prefixes_to_direct_connect_gateway: Option<Vec<RouteFilterPrefix>>,
 }
 
+impl CreateDirectConnectGatewayAssociationProposalRequest {
+    /// Returns a builder for `CreateDirectConnectGatewayAssociationProposalRequest`, checking its required members when built.
+    pub fn builder() -> CreateDirectConnectGatewayAssociationProposalRequestBuilder {
+        CreateDirectConnectGatewayAssociationProposalRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDirectConnectGatewayAssociationProposalRequest`](struct.CreateDirectConnectGatewayAssociationProposalRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDirectConnectGatewayAssociationProposalRequestBuilder {
+    add_allowed_prefixes_to_direct_connect_gateway: Option<Vec<RouteFilterPrefix>>,
+    direct_connect_gateway_id: Option<String>,
+    direct_connect_gateway_owner_account: Option<String>,
+    gateway_id: Option<String>,
+    remove_allowed_prefixes_to_direct_connect_gateway: Option<Vec<RouteFilterPrefix>>,
+}
+
+impl CreateDirectConnectGatewayAssociationProposalRequestBuilder {
+    /// Sets the [`add_allowed_prefixes_to_direct_connect_gateway`](struct.CreateDirectConnectGatewayAssociationProposalRequest.html#structfield.add_allowed_prefixes_to_direct_connect_gateway) member.
+    pub fn add_allowed_prefixes_to_direct_connect_gateway<T: Into<Vec<RouteFilterPrefix>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.add_allowed_prefixes_to_direct_connect_gateway = Some(value.into());
+        self
+    }
+
+    /// Sets the [`direct_connect_gateway_id`](struct.CreateDirectConnectGatewayAssociationProposalRequest.html#structfield.direct_connect_gateway_id) member.
+    pub fn direct_connect_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`direct_connect_gateway_owner_account`](struct.CreateDirectConnectGatewayAssociationProposalRequest.html#structfield.direct_connect_gateway_owner_account) member.
+    pub fn direct_connect_gateway_owner_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_owner_account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_id`](struct.CreateDirectConnectGatewayAssociationProposalRequest.html#structfield.gateway_id) member.
+    pub fn gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remove_allowed_prefixes_to_direct_connect_gateway`](struct.CreateDirectConnectGatewayAssociationProposalRequest.html#structfield.remove_allowed_prefixes_to_direct_connect_gateway) member.
+    pub fn remove_allowed_prefixes_to_direct_connect_gateway<T: Into<Vec<RouteFilterPrefix>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.remove_allowed_prefixes_to_direct_connect_gateway = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDirectConnectGatewayAssociationProposalRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        CreateDirectConnectGatewayAssociationProposalRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(CreateDirectConnectGatewayAssociationProposalRequest {
+            add_allowed_prefixes_to_direct_connect_gateway: self
+                .add_allowed_prefixes_to_direct_connect_gateway,
+            direct_connect_gateway_id: self.direct_connect_gateway_id.ok_or(
+                ::rusoto_core::BuildError::missing("direct_connect_gateway_id"),
+            )?,
+            direct_connect_gateway_owner_account: self.direct_connect_gateway_owner_account.ok_or(
+                ::rusoto_core::BuildError::missing("direct_connect_gateway_owner_account"),
+            )?,
+            gateway_id: self
+                .gateway_id
+                .ok_or(::rusoto_core::BuildError::missing("gateway_id"))?,
+            remove_allowed_prefixes_to_direct_connect_gateway: self
+                .remove_allowed_prefixes_to_direct_connect_gateway,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDirectConnectGatewayAssociationProposalResult {
@@ -477,6 +1675,69 @@ pub struct CreateDirectConnectGatewayAssociationRequest {
     pub virtual_gateway_id: Option<String>,
 }
 
+impl CreateDirectConnectGatewayAssociationRequest {
+    /// Returns a builder for `CreateDirectConnectGatewayAssociationRequest`, checking its required members when built.
+    pub fn builder() -> CreateDirectConnectGatewayAssociationRequestBuilder {
+        CreateDirectConnectGatewayAssociationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDirectConnectGatewayAssociationRequest`](struct.CreateDirectConnectGatewayAssociationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDirectConnectGatewayAssociationRequestBuilder {
+    add_allowed_prefixes_to_direct_connect_gateway: Option<Vec<RouteFilterPrefix>>,
+    direct_connect_gateway_id: Option<String>,
+    gateway_id: Option<String>,
+    virtual_gateway_id: Option<String>,
+}
+
+impl CreateDirectConnectGatewayAssociationRequestBuilder {
+    /// Sets the [`add_allowed_prefixes_to_direct_connect_gateway`](struct.CreateDirectConnectGatewayAssociationRequest.html#structfield.add_allowed_prefixes_to_direct_connect_gateway) member.
+    pub fn add_allowed_prefixes_to_direct_connect_gateway<T: Into<Vec<RouteFilterPrefix>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.add_allowed_prefixes_to_direct_connect_gateway = Some(value.into());
+        self
+    }
+
+    /// Sets the [`direct_connect_gateway_id`](struct.CreateDirectConnectGatewayAssociationRequest.html#structfield.direct_connect_gateway_id) member.
+    pub fn direct_connect_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gateway_id`](struct.CreateDirectConnectGatewayAssociationRequest.html#structfield.gateway_id) member.
+    pub fn gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_gateway_id`](struct.CreateDirectConnectGatewayAssociationRequest.html#structfield.virtual_gateway_id) member.
+    pub fn virtual_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDirectConnectGatewayAssociationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        CreateDirectConnectGatewayAssociationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(CreateDirectConnectGatewayAssociationRequest {
+            add_allowed_prefixes_to_direct_connect_gateway: self
+                .add_allowed_prefixes_to_direct_connect_gateway,
+            direct_connect_gateway_id: self.direct_connect_gateway_id.ok_or(
+                ::rusoto_core::BuildError::missing("direct_connect_gateway_id"),
+            )?,
+            gateway_id: self.gateway_id,
+            virtual_gateway_id: self.virtual_gateway_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDirectConnectGatewayAssociationResult {
@@ -497,6 +1758,46 @@ pub struct CreateDirectConnectGatewayRequest {
     pub direct_connect_gateway_name: String,
 }
 
+impl CreateDirectConnectGatewayRequest {
+    /// Returns a builder for `CreateDirectConnectGatewayRequest`, checking its required members when built.
+    pub fn builder() -> CreateDirectConnectGatewayRequestBuilder {
+        CreateDirectConnectGatewayRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDirectConnectGatewayRequest`](struct.CreateDirectConnectGatewayRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDirectConnectGatewayRequestBuilder {
+    amazon_side_asn: Option<i64>,
+    direct_connect_gateway_name: Option<String>,
+}
+
+impl CreateDirectConnectGatewayRequestBuilder {
+    /// Sets the [`amazon_side_asn`](struct.CreateDirectConnectGatewayRequest.html#structfield.amazon_side_asn) member.
+    pub fn amazon_side_asn<T: Into<i64>>(mut self, value: T) -> Self {
+        self.amazon_side_asn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`direct_connect_gateway_name`](struct.CreateDirectConnectGatewayRequest.html#structfield.direct_connect_gateway_name) member.
+    pub fn direct_connect_gateway_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDirectConnectGatewayRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDirectConnectGatewayRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDirectConnectGatewayRequest {
+            amazon_side_asn: self.amazon_side_asn,
+            direct_connect_gateway_name: self.direct_connect_gateway_name.ok_or(
+                ::rusoto_core::BuildError::missing("direct_connect_gateway_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDirectConnectGatewayResult {
@@ -527,6 +1828,74 @@ pub struct CreateInterconnectRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateInterconnectRequest {
+    /// Returns a builder for `CreateInterconnectRequest`, checking its required members when built.
+    pub fn builder() -> CreateInterconnectRequestBuilder {
+        CreateInterconnectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateInterconnectRequest`](struct.CreateInterconnectRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateInterconnectRequestBuilder {
+    bandwidth: Option<String>,
+    interconnect_name: Option<String>,
+    lag_id: Option<String>,
+    location: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateInterconnectRequestBuilder {
+    /// Sets the [`bandwidth`](struct.CreateInterconnectRequest.html#structfield.bandwidth) member.
+    pub fn bandwidth<T: Into<String>>(mut self, value: T) -> Self {
+        self.bandwidth = Some(value.into());
+        self
+    }
+
+    /// Sets the [`interconnect_name`](struct.CreateInterconnectRequest.html#structfield.interconnect_name) member.
+    pub fn interconnect_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.interconnect_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lag_id`](struct.CreateInterconnectRequest.html#structfield.lag_id) member.
+    pub fn lag_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.lag_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location`](struct.CreateInterconnectRequest.html#structfield.location) member.
+    pub fn location<T: Into<String>>(mut self, value: T) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateInterconnectRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateInterconnectRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateInterconnectRequest, ::rusoto_core::BuildError> {
+        Ok(CreateInterconnectRequest {
+            bandwidth: self
+                .bandwidth
+                .ok_or(::rusoto_core::BuildError::missing("bandwidth"))?,
+            interconnect_name: self
+                .interconnect_name
+                .ok_or(::rusoto_core::BuildError::missing("interconnect_name"))?,
+            lag_id: self.lag_id,
+            location: self
+                .location
+                .ok_or(::rusoto_core::BuildError::missing("location"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateLagRequest {
     /// <p>The tags to assign to the child connections of the LAG. Only newly created child connections as the result of creating a LAG connection are assigned the provided tags. The tags are not assigned to an existing connection that is provided via the “connectionId” parameter that will be migrated to the LAG.</p>
@@ -555,6 +1924,90 @@ pub struct CreateLagRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateLagRequest {
+    /// Returns a builder for `CreateLagRequest`, checking its required members when built.
+    pub fn builder() -> CreateLagRequestBuilder {
+        CreateLagRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLagRequest`](struct.CreateLagRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLagRequestBuilder {
+    child_connection_tags: Option<Vec<Tag>>,
+    connection_id: Option<String>,
+    connections_bandwidth: Option<String>,
+    lag_name: Option<String>,
+    location: Option<String>,
+    number_of_connections: Option<i64>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateLagRequestBuilder {
+    /// Sets the [`child_connection_tags`](struct.CreateLagRequest.html#structfield.child_connection_tags) member.
+    pub fn child_connection_tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.child_connection_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_id`](struct.CreateLagRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connections_bandwidth`](struct.CreateLagRequest.html#structfield.connections_bandwidth) member.
+    pub fn connections_bandwidth<T: Into<String>>(mut self, value: T) -> Self {
+        self.connections_bandwidth = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lag_name`](struct.CreateLagRequest.html#structfield.lag_name) member.
+    pub fn lag_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.lag_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location`](struct.CreateLagRequest.html#structfield.location) member.
+    pub fn location<T: Into<String>>(mut self, value: T) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_connections`](struct.CreateLagRequest.html#structfield.number_of_connections) member.
+    pub fn number_of_connections<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_connections = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateLagRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLagRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateLagRequest, ::rusoto_core::BuildError> {
+        Ok(CreateLagRequest {
+            child_connection_tags: self.child_connection_tags,
+            connection_id: self.connection_id,
+            connections_bandwidth: self
+                .connections_bandwidth
+                .ok_or(::rusoto_core::BuildError::missing("connections_bandwidth"))?,
+            lag_name: self
+                .lag_name
+                .ok_or(::rusoto_core::BuildError::missing("lag_name"))?,
+            location: self
+                .location
+                .ok_or(::rusoto_core::BuildError::missing("location"))?,
+            number_of_connections: self
+                .number_of_connections
+                .ok_or(::rusoto_core::BuildError::missing("number_of_connections"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreatePrivateVirtualInterfaceRequest {
     /// <p>The ID of the connection.</p>
@@ -565,6 +2018,52 @@ pub struct CreatePrivateVirtualInterfaceRequest {
     pub new_private_virtual_interface: NewPrivateVirtualInterface,
 }
 
+impl CreatePrivateVirtualInterfaceRequest {
+    /// Returns a builder for `CreatePrivateVirtualInterfaceRequest`, checking its required members when built.
+    pub fn builder() -> CreatePrivateVirtualInterfaceRequestBuilder {
+        CreatePrivateVirtualInterfaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePrivateVirtualInterfaceRequest`](struct.CreatePrivateVirtualInterfaceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePrivateVirtualInterfaceRequestBuilder {
+    connection_id: Option<String>,
+    new_private_virtual_interface: Option<NewPrivateVirtualInterface>,
+}
+
+impl CreatePrivateVirtualInterfaceRequestBuilder {
+    /// Sets the [`connection_id`](struct.CreatePrivateVirtualInterfaceRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_private_virtual_interface`](struct.CreatePrivateVirtualInterfaceRequest.html#structfield.new_private_virtual_interface) member.
+    pub fn new_private_virtual_interface<T: Into<NewPrivateVirtualInterface>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.new_private_virtual_interface = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePrivateVirtualInterfaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePrivateVirtualInterfaceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreatePrivateVirtualInterfaceRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            new_private_virtual_interface: self.new_private_virtual_interface.ok_or(
+                ::rusoto_core::BuildError::missing("new_private_virtual_interface"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreatePublicVirtualInterfaceRequest {
     /// <p>The ID of the connection.</p>
@@ -575,6 +2074,51 @@ pub struct CreatePublicVirtualInterfaceRequest {
     pub new_public_virtual_interface: NewPublicVirtualInterface,
 }
 
+impl CreatePublicVirtualInterfaceRequest {
+    /// Returns a builder for `CreatePublicVirtualInterfaceRequest`, checking its required members when built.
+    pub fn builder() -> CreatePublicVirtualInterfaceRequestBuilder {
+        CreatePublicVirtualInterfaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePublicVirtualInterfaceRequest`](struct.CreatePublicVirtualInterfaceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePublicVirtualInterfaceRequestBuilder {
+    connection_id: Option<String>,
+    new_public_virtual_interface: Option<NewPublicVirtualInterface>,
+}
+
+impl CreatePublicVirtualInterfaceRequestBuilder {
+    /// Sets the [`connection_id`](struct.CreatePublicVirtualInterfaceRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_public_virtual_interface`](struct.CreatePublicVirtualInterfaceRequest.html#structfield.new_public_virtual_interface) member.
+    pub fn new_public_virtual_interface<T: Into<NewPublicVirtualInterface>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.new_public_virtual_interface = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePublicVirtualInterfaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePublicVirtualInterfaceRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePublicVirtualInterfaceRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            new_public_virtual_interface: self.new_public_virtual_interface.ok_or(
+                ::rusoto_core::BuildError::missing("new_public_virtual_interface"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateTransitVirtualInterfaceRequest {
     /// <p>The ID of the connection.</p>
@@ -585,6 +2129,52 @@ pub struct CreateTransitVirtualInterfaceRequest {
     pub new_transit_virtual_interface: NewTransitVirtualInterface,
 }
 
+impl CreateTransitVirtualInterfaceRequest {
+    /// Returns a builder for `CreateTransitVirtualInterfaceRequest`, checking its required members when built.
+    pub fn builder() -> CreateTransitVirtualInterfaceRequestBuilder {
+        CreateTransitVirtualInterfaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTransitVirtualInterfaceRequest`](struct.CreateTransitVirtualInterfaceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTransitVirtualInterfaceRequestBuilder {
+    connection_id: Option<String>,
+    new_transit_virtual_interface: Option<NewTransitVirtualInterface>,
+}
+
+impl CreateTransitVirtualInterfaceRequestBuilder {
+    /// Sets the [`connection_id`](struct.CreateTransitVirtualInterfaceRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_transit_virtual_interface`](struct.CreateTransitVirtualInterfaceRequest.html#structfield.new_transit_virtual_interface) member.
+    pub fn new_transit_virtual_interface<T: Into<NewTransitVirtualInterface>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.new_transit_virtual_interface = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTransitVirtualInterfaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateTransitVirtualInterfaceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateTransitVirtualInterfaceRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            new_transit_virtual_interface: self.new_transit_virtual_interface.ok_or(
+                ::rusoto_core::BuildError::missing("new_transit_virtual_interface"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateTransitVirtualInterfaceResult {
@@ -613,20 +2203,104 @@ pub struct DeleteBGPPeerRequest {
     pub virtual_interface_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DeleteBGPPeerResponse {
-    /// <p>The virtual interface.</p>
-    #[serde(rename = "virtualInterface")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface: Option<VirtualInterface>,
+impl DeleteBGPPeerRequest {
+    /// Returns a builder for `DeleteBGPPeerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBGPPeerRequestBuilder {
+        DeleteBGPPeerRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DeleteConnectionRequest {
-    /// <p>The ID of the connection.</p>
-    #[serde(rename = "connectionId")]
-    pub connection_id: String,
+/// Builds a [`DeleteBGPPeerRequest`](struct.DeleteBGPPeerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBGPPeerRequestBuilder {
+    asn: Option<i64>,
+    bgp_peer_id: Option<String>,
+    customer_address: Option<String>,
+    virtual_interface_id: Option<String>,
+}
+
+impl DeleteBGPPeerRequestBuilder {
+    /// Sets the [`asn`](struct.DeleteBGPPeerRequest.html#structfield.asn) member.
+    pub fn asn<T: Into<i64>>(mut self, value: T) -> Self {
+        self.asn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bgp_peer_id`](struct.DeleteBGPPeerRequest.html#structfield.bgp_peer_id) member.
+    pub fn bgp_peer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bgp_peer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`customer_address`](struct.DeleteBGPPeerRequest.html#structfield.customer_address) member.
+    pub fn customer_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.customer_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_interface_id`](struct.DeleteBGPPeerRequest.html#structfield.virtual_interface_id) member.
+    pub fn virtual_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_interface_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBGPPeerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteBGPPeerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBGPPeerRequest {
+            asn: self.asn,
+            bgp_peer_id: self.bgp_peer_id,
+            customer_address: self.customer_address,
+            virtual_interface_id: self.virtual_interface_id,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteBGPPeerResponse {
+    /// <p>The virtual interface.</p>
+    #[serde(rename = "virtualInterface")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub virtual_interface: Option<VirtualInterface>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteConnectionRequest {
+    /// <p>The ID of the connection.</p>
+    #[serde(rename = "connectionId")]
+    pub connection_id: String,
+}
+
+impl DeleteConnectionRequest {
+    /// Returns a builder for `DeleteConnectionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteConnectionRequestBuilder {
+        DeleteConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConnectionRequest`](struct.DeleteConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteConnectionRequestBuilder {
+    connection_id: Option<String>,
+}
+
+impl DeleteConnectionRequestBuilder {
+    /// Sets the [`connection_id`](struct.DeleteConnectionRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteConnectionRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -636,6 +2310,41 @@ pub struct DeleteDirectConnectGatewayAssociationProposalRequest {
     pub proposal_id: String,
 }
 
+impl DeleteDirectConnectGatewayAssociationProposalRequest {
+    /// Returns a builder for `DeleteDirectConnectGatewayAssociationProposalRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDirectConnectGatewayAssociationProposalRequestBuilder {
+        DeleteDirectConnectGatewayAssociationProposalRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDirectConnectGatewayAssociationProposalRequest`](struct.DeleteDirectConnectGatewayAssociationProposalRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDirectConnectGatewayAssociationProposalRequestBuilder {
+    proposal_id: Option<String>,
+}
+
+impl DeleteDirectConnectGatewayAssociationProposalRequestBuilder {
+    /// Sets the [`proposal_id`](struct.DeleteDirectConnectGatewayAssociationProposalRequest.html#structfield.proposal_id) member.
+    pub fn proposal_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.proposal_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDirectConnectGatewayAssociationProposalRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DeleteDirectConnectGatewayAssociationProposalRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DeleteDirectConnectGatewayAssociationProposalRequest {
+            proposal_id: self
+                .proposal_id
+                .ok_or(::rusoto_core::BuildError::missing("proposal_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDirectConnectGatewayAssociationProposalResult {
@@ -662,6 +2371,55 @@ pub struct DeleteDirectConnectGatewayAssociationRequest {
     pub virtual_gateway_id: Option<String>,
 }
 
+impl DeleteDirectConnectGatewayAssociationRequest {
+    /// Returns a builder for `DeleteDirectConnectGatewayAssociationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDirectConnectGatewayAssociationRequestBuilder {
+        DeleteDirectConnectGatewayAssociationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDirectConnectGatewayAssociationRequest`](struct.DeleteDirectConnectGatewayAssociationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDirectConnectGatewayAssociationRequestBuilder {
+    association_id: Option<String>,
+    direct_connect_gateway_id: Option<String>,
+    virtual_gateway_id: Option<String>,
+}
+
+impl DeleteDirectConnectGatewayAssociationRequestBuilder {
+    /// Sets the [`association_id`](struct.DeleteDirectConnectGatewayAssociationRequest.html#structfield.association_id) member.
+    pub fn association_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.association_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`direct_connect_gateway_id`](struct.DeleteDirectConnectGatewayAssociationRequest.html#structfield.direct_connect_gateway_id) member.
+    pub fn direct_connect_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_gateway_id`](struct.DeleteDirectConnectGatewayAssociationRequest.html#structfield.virtual_gateway_id) member.
+    pub fn virtual_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDirectConnectGatewayAssociationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DeleteDirectConnectGatewayAssociationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DeleteDirectConnectGatewayAssociationRequest {
+            association_id: self.association_id,
+            direct_connect_gateway_id: self.direct_connect_gateway_id,
+            virtual_gateway_id: self.virtual_gateway_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDirectConnectGatewayAssociationResult {
@@ -678,6 +2436,38 @@ pub struct DeleteDirectConnectGatewayRequest {
     pub direct_connect_gateway_id: String,
 }
 
+impl DeleteDirectConnectGatewayRequest {
+    /// Returns a builder for `DeleteDirectConnectGatewayRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDirectConnectGatewayRequestBuilder {
+        DeleteDirectConnectGatewayRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDirectConnectGatewayRequest`](struct.DeleteDirectConnectGatewayRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDirectConnectGatewayRequestBuilder {
+    direct_connect_gateway_id: Option<String>,
+}
+
+impl DeleteDirectConnectGatewayRequestBuilder {
+    /// Sets the [`direct_connect_gateway_id`](struct.DeleteDirectConnectGatewayRequest.html#structfield.direct_connect_gateway_id) member.
+    pub fn direct_connect_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDirectConnectGatewayRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDirectConnectGatewayRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDirectConnectGatewayRequest {
+            direct_connect_gateway_id: self.direct_connect_gateway_id.ok_or(
+                ::rusoto_core::BuildError::missing("direct_connect_gateway_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDirectConnectGatewayResult {
@@ -694,13 +2484,45 @@ pub struct DeleteInterconnectRequest {
     pub interconnect_id: String,
 }
 
+impl DeleteInterconnectRequest {
+    /// Returns a builder for `DeleteInterconnectRequest`, checking its required members when built.
+    pub fn builder() -> DeleteInterconnectRequestBuilder {
+        DeleteInterconnectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteInterconnectRequest`](struct.DeleteInterconnectRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteInterconnectRequestBuilder {
+    interconnect_id: Option<String>,
+}
+
+impl DeleteInterconnectRequestBuilder {
+    /// Sets the [`interconnect_id`](struct.DeleteInterconnectRequest.html#structfield.interconnect_id) member.
+    pub fn interconnect_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.interconnect_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteInterconnectRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteInterconnectRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteInterconnectRequest {
+            interconnect_id: self
+                .interconnect_id
+                .ok_or(::rusoto_core::BuildError::missing("interconnect_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteInterconnectResponse {
     /// <p><p>The state of the interconnect. The following are the possible values:</p> <ul> <li> <p> <code>requested</code>: The initial state of an interconnect. The interconnect stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The interconnect is approved, and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up, and the interconnect is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The interconnect is being deleted.</p> </li> <li> <p> <code>deleted</code>: The interconnect is deleted.</p> </li> <li> <p> <code>unknown</code>: The state of the interconnect is not available.</p> </li> </ul></p>
     #[serde(rename = "interconnectState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interconnect_state: Option<String>,
+    pub interconnect_state: Option<InterconnectState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -710,6 +2532,36 @@ pub struct DeleteLagRequest {
     pub lag_id: String,
 }
 
+impl DeleteLagRequest {
+    /// Returns a builder for `DeleteLagRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLagRequestBuilder {
+        DeleteLagRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLagRequest`](struct.DeleteLagRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLagRequestBuilder {
+    lag_id: Option<String>,
+}
+
+impl DeleteLagRequestBuilder {
+    /// Sets the [`lag_id`](struct.DeleteLagRequest.html#structfield.lag_id) member.
+    pub fn lag_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.lag_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLagRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteLagRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLagRequest {
+            lag_id: self
+                .lag_id
+                .ok_or(::rusoto_core::BuildError::missing("lag_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteVirtualInterfaceRequest {
     /// <p>The ID of the virtual interface.</p>
@@ -717,13 +2569,45 @@ pub struct DeleteVirtualInterfaceRequest {
     pub virtual_interface_id: String,
 }
 
+impl DeleteVirtualInterfaceRequest {
+    /// Returns a builder for `DeleteVirtualInterfaceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteVirtualInterfaceRequestBuilder {
+        DeleteVirtualInterfaceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVirtualInterfaceRequest`](struct.DeleteVirtualInterfaceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteVirtualInterfaceRequestBuilder {
+    virtual_interface_id: Option<String>,
+}
+
+impl DeleteVirtualInterfaceRequestBuilder {
+    /// Sets the [`virtual_interface_id`](struct.DeleteVirtualInterfaceRequest.html#structfield.virtual_interface_id) member.
+    pub fn virtual_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_interface_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVirtualInterfaceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVirtualInterfaceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteVirtualInterfaceRequest {
+            virtual_interface_id: self
+                .virtual_interface_id
+                .ok_or(::rusoto_core::BuildError::missing("virtual_interface_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteVirtualInterfaceResponse {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_state: Option<String>,
+    pub virtual_interface_state: Option<VirtualInterfaceState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -734,13 +2618,61 @@ pub struct DescribeConnectionLoaRequest {
     /// <p>The standard media type for the LOA-CFA document. The only supported value is application/pdf.</p>
     #[serde(rename = "loaContentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loa_content_type: Option<String>,
+    pub loa_content_type: Option<LoaContentType>,
     /// <p>The name of the APN partner or service provider who establishes connectivity on your behalf. If you specify this parameter, the LOA-CFA lists the provider name alongside your company name as the requester of the cross connect.</p>
     #[serde(rename = "providerName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub provider_name: Option<String>,
 }
 
+impl DescribeConnectionLoaRequest {
+    /// Returns a builder for `DescribeConnectionLoaRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConnectionLoaRequestBuilder {
+        DescribeConnectionLoaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConnectionLoaRequest`](struct.DescribeConnectionLoaRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConnectionLoaRequestBuilder {
+    connection_id: Option<String>,
+    loa_content_type: Option<LoaContentType>,
+    provider_name: Option<String>,
+}
+
+impl DescribeConnectionLoaRequestBuilder {
+    /// Sets the [`connection_id`](struct.DescribeConnectionLoaRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`loa_content_type`](struct.DescribeConnectionLoaRequest.html#structfield.loa_content_type) member.
+    pub fn loa_content_type<T: Into<LoaContentType>>(mut self, value: T) -> Self {
+        self.loa_content_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_name`](struct.DescribeConnectionLoaRequest.html#structfield.provider_name) member.
+    pub fn provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConnectionLoaRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConnectionLoaRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeConnectionLoaRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            loa_content_type: self.loa_content_type,
+            provider_name: self.provider_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeConnectionLoaResponse {
@@ -757,6 +2689,39 @@ pub struct DescribeConnectionsOnInterconnectRequest {
     pub interconnect_id: String,
 }
 
+impl DescribeConnectionsOnInterconnectRequest {
+    /// Returns a builder for `DescribeConnectionsOnInterconnectRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConnectionsOnInterconnectRequestBuilder {
+        DescribeConnectionsOnInterconnectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConnectionsOnInterconnectRequest`](struct.DescribeConnectionsOnInterconnectRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConnectionsOnInterconnectRequestBuilder {
+    interconnect_id: Option<String>,
+}
+
+impl DescribeConnectionsOnInterconnectRequestBuilder {
+    /// Sets the [`interconnect_id`](struct.DescribeConnectionsOnInterconnectRequest.html#structfield.interconnect_id) member.
+    pub fn interconnect_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.interconnect_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConnectionsOnInterconnectRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConnectionsOnInterconnectRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeConnectionsOnInterconnectRequest {
+            interconnect_id: self
+                .interconnect_id
+                .ok_or(::rusoto_core::BuildError::missing("interconnect_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeConnectionsRequest {
     /// <p>The ID of the connection.</p>
@@ -765,6 +2730,36 @@ pub struct DescribeConnectionsRequest {
     pub connection_id: Option<String>,
 }
 
+impl DescribeConnectionsRequest {
+    /// Returns a builder for `DescribeConnectionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConnectionsRequestBuilder {
+        DescribeConnectionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConnectionsRequest`](struct.DescribeConnectionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConnectionsRequestBuilder {
+    connection_id: Option<String>,
+}
+
+impl DescribeConnectionsRequestBuilder {
+    /// Sets the [`connection_id`](struct.DescribeConnectionsRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConnectionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConnectionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeConnectionsRequest {
+            connection_id: self.connection_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeDirectConnectGatewayAssociationProposalsRequest {
     /// <p>The ID of the associated gateway.</p>
@@ -789,6 +2784,71 @@ pub struct DescribeDirectConnectGatewayAssociationProposalsRequest {
     pub proposal_id: Option<String>,
 }
 
+impl DescribeDirectConnectGatewayAssociationProposalsRequest {
+    /// Returns a builder for `DescribeDirectConnectGatewayAssociationProposalsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDirectConnectGatewayAssociationProposalsRequestBuilder {
+        DescribeDirectConnectGatewayAssociationProposalsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDirectConnectGatewayAssociationProposalsRequest`](struct.DescribeDirectConnectGatewayAssociationProposalsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDirectConnectGatewayAssociationProposalsRequestBuilder {
+    associated_gateway_id: Option<String>,
+    direct_connect_gateway_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    proposal_id: Option<String>,
+}
+
+impl DescribeDirectConnectGatewayAssociationProposalsRequestBuilder {
+    /// Sets the [`associated_gateway_id`](struct.DescribeDirectConnectGatewayAssociationProposalsRequest.html#structfield.associated_gateway_id) member.
+    pub fn associated_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.associated_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`direct_connect_gateway_id`](struct.DescribeDirectConnectGatewayAssociationProposalsRequest.html#structfield.direct_connect_gateway_id) member.
+    pub fn direct_connect_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeDirectConnectGatewayAssociationProposalsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeDirectConnectGatewayAssociationProposalsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`proposal_id`](struct.DescribeDirectConnectGatewayAssociationProposalsRequest.html#structfield.proposal_id) member.
+    pub fn proposal_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.proposal_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDirectConnectGatewayAssociationProposalsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeDirectConnectGatewayAssociationProposalsRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeDirectConnectGatewayAssociationProposalsRequest {
+            associated_gateway_id: self.associated_gateway_id,
+            direct_connect_gateway_id: self.direct_connect_gateway_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            proposal_id: self.proposal_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDirectConnectGatewayAssociationProposalsResult {
@@ -831,6 +2891,79 @@ pub struct DescribeDirectConnectGatewayAssociationsRequest {
     pub virtual_gateway_id: Option<String>,
 }
 
+impl DescribeDirectConnectGatewayAssociationsRequest {
+    /// Returns a builder for `DescribeDirectConnectGatewayAssociationsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDirectConnectGatewayAssociationsRequestBuilder {
+        DescribeDirectConnectGatewayAssociationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDirectConnectGatewayAssociationsRequest`](struct.DescribeDirectConnectGatewayAssociationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDirectConnectGatewayAssociationsRequestBuilder {
+    associated_gateway_id: Option<String>,
+    association_id: Option<String>,
+    direct_connect_gateway_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    virtual_gateway_id: Option<String>,
+}
+
+impl DescribeDirectConnectGatewayAssociationsRequestBuilder {
+    /// Sets the [`associated_gateway_id`](struct.DescribeDirectConnectGatewayAssociationsRequest.html#structfield.associated_gateway_id) member.
+    pub fn associated_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.associated_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`association_id`](struct.DescribeDirectConnectGatewayAssociationsRequest.html#structfield.association_id) member.
+    pub fn association_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.association_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`direct_connect_gateway_id`](struct.DescribeDirectConnectGatewayAssociationsRequest.html#structfield.direct_connect_gateway_id) member.
+    pub fn direct_connect_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeDirectConnectGatewayAssociationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeDirectConnectGatewayAssociationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_gateway_id`](struct.DescribeDirectConnectGatewayAssociationsRequest.html#structfield.virtual_gateway_id) member.
+    pub fn virtual_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDirectConnectGatewayAssociationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeDirectConnectGatewayAssociationsRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeDirectConnectGatewayAssociationsRequest {
+            associated_gateway_id: self.associated_gateway_id,
+            association_id: self.association_id,
+            direct_connect_gateway_id: self.direct_connect_gateway_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            virtual_gateway_id: self.virtual_gateway_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDirectConnectGatewayAssociationsResult {
@@ -864,6 +2997,63 @@ pub struct DescribeDirectConnectGatewayAttachmentsRequest {
     pub virtual_interface_id: Option<String>,
 }
 
+impl DescribeDirectConnectGatewayAttachmentsRequest {
+    /// Returns a builder for `DescribeDirectConnectGatewayAttachmentsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDirectConnectGatewayAttachmentsRequestBuilder {
+        DescribeDirectConnectGatewayAttachmentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDirectConnectGatewayAttachmentsRequest`](struct.DescribeDirectConnectGatewayAttachmentsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDirectConnectGatewayAttachmentsRequestBuilder {
+    direct_connect_gateway_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    virtual_interface_id: Option<String>,
+}
+
+impl DescribeDirectConnectGatewayAttachmentsRequestBuilder {
+    /// Sets the [`direct_connect_gateway_id`](struct.DescribeDirectConnectGatewayAttachmentsRequest.html#structfield.direct_connect_gateway_id) member.
+    pub fn direct_connect_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeDirectConnectGatewayAttachmentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeDirectConnectGatewayAttachmentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_interface_id`](struct.DescribeDirectConnectGatewayAttachmentsRequest.html#structfield.virtual_interface_id) member.
+    pub fn virtual_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_interface_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDirectConnectGatewayAttachmentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeDirectConnectGatewayAttachmentsRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeDirectConnectGatewayAttachmentsRequest {
+            direct_connect_gateway_id: self.direct_connect_gateway_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            virtual_interface_id: self.virtual_interface_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDirectConnectGatewayAttachmentsResult {
@@ -893,6 +3083,53 @@ pub struct DescribeDirectConnectGatewaysRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeDirectConnectGatewaysRequest {
+    /// Returns a builder for `DescribeDirectConnectGatewaysRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDirectConnectGatewaysRequestBuilder {
+        DescribeDirectConnectGatewaysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDirectConnectGatewaysRequest`](struct.DescribeDirectConnectGatewaysRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDirectConnectGatewaysRequestBuilder {
+    direct_connect_gateway_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeDirectConnectGatewaysRequestBuilder {
+    /// Sets the [`direct_connect_gateway_id`](struct.DescribeDirectConnectGatewaysRequest.html#structfield.direct_connect_gateway_id) member.
+    pub fn direct_connect_gateway_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.direct_connect_gateway_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeDirectConnectGatewaysRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeDirectConnectGatewaysRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDirectConnectGatewaysRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDirectConnectGatewaysRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDirectConnectGatewaysRequest {
+            direct_connect_gateway_id: self.direct_connect_gateway_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDirectConnectGatewaysResult {
@@ -913,6 +3150,38 @@ pub struct DescribeHostedConnectionsRequest {
     pub connection_id: String,
 }
 
+impl DescribeHostedConnectionsRequest {
+    /// Returns a builder for `DescribeHostedConnectionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeHostedConnectionsRequestBuilder {
+        DescribeHostedConnectionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeHostedConnectionsRequest`](struct.DescribeHostedConnectionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeHostedConnectionsRequestBuilder {
+    connection_id: Option<String>,
+}
+
+impl DescribeHostedConnectionsRequestBuilder {
+    /// Sets the [`connection_id`](struct.DescribeHostedConnectionsRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeHostedConnectionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeHostedConnectionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeHostedConnectionsRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeInterconnectLoaRequest {
     /// <p>The ID of the interconnect.</p>
@@ -921,13 +3190,61 @@ pub struct DescribeInterconnectLoaRequest {
     /// <p>The standard media type for the LOA-CFA document. The only supported value is application/pdf.</p>
     #[serde(rename = "loaContentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loa_content_type: Option<String>,
+    pub loa_content_type: Option<LoaContentType>,
     /// <p>The name of the service provider who establishes connectivity on your behalf. If you supply this parameter, the LOA-CFA lists the provider name alongside your company name as the requester of the cross connect.</p>
     #[serde(rename = "providerName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub provider_name: Option<String>,
 }
 
+impl DescribeInterconnectLoaRequest {
+    /// Returns a builder for `DescribeInterconnectLoaRequest`, checking its required members when built.
+    pub fn builder() -> DescribeInterconnectLoaRequestBuilder {
+        DescribeInterconnectLoaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeInterconnectLoaRequest`](struct.DescribeInterconnectLoaRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeInterconnectLoaRequestBuilder {
+    interconnect_id: Option<String>,
+    loa_content_type: Option<LoaContentType>,
+    provider_name: Option<String>,
+}
+
+impl DescribeInterconnectLoaRequestBuilder {
+    /// Sets the [`interconnect_id`](struct.DescribeInterconnectLoaRequest.html#structfield.interconnect_id) member.
+    pub fn interconnect_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.interconnect_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`loa_content_type`](struct.DescribeInterconnectLoaRequest.html#structfield.loa_content_type) member.
+    pub fn loa_content_type<T: Into<LoaContentType>>(mut self, value: T) -> Self {
+        self.loa_content_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_name`](struct.DescribeInterconnectLoaRequest.html#structfield.provider_name) member.
+    pub fn provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeInterconnectLoaRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeInterconnectLoaRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeInterconnectLoaRequest {
+            interconnect_id: self
+                .interconnect_id
+                .ok_or(::rusoto_core::BuildError::missing("interconnect_id"))?,
+            loa_content_type: self.loa_content_type,
+            provider_name: self.provider_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeInterconnectLoaResponse {
@@ -945,14 +3262,72 @@ pub struct DescribeInterconnectsRequest {
     pub interconnect_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DescribeLagsRequest {
-    /// <p>The ID of the LAG.</p>
-    #[serde(rename = "lagId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl DescribeInterconnectsRequest {
+    /// Returns a builder for `DescribeInterconnectsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeInterconnectsRequestBuilder {
+        DescribeInterconnectsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeInterconnectsRequest`](struct.DescribeInterconnectsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeInterconnectsRequestBuilder {
+    interconnect_id: Option<String>,
+}
+
+impl DescribeInterconnectsRequestBuilder {
+    /// Sets the [`interconnect_id`](struct.DescribeInterconnectsRequest.html#structfield.interconnect_id) member.
+    pub fn interconnect_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.interconnect_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeInterconnectsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeInterconnectsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeInterconnectsRequest {
+            interconnect_id: self.interconnect_id,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DescribeLagsRequest {
+    /// <p>The ID of the LAG.</p>
+    #[serde(rename = "lagId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lag_id: Option<String>,
 }
 
+impl DescribeLagsRequest {
+    /// Returns a builder for `DescribeLagsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeLagsRequestBuilder {
+        DescribeLagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLagsRequest`](struct.DescribeLagsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeLagsRequestBuilder {
+    lag_id: Option<String>,
+}
+
+impl DescribeLagsRequestBuilder {
+    /// Sets the [`lag_id`](struct.DescribeLagsRequest.html#structfield.lag_id) member.
+    pub fn lag_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.lag_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeLagsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeLagsRequest {
+            lag_id: self.lag_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeLoaRequest {
     /// <p>The ID of a connection, LAG, or interconnect.</p>
@@ -961,13 +3336,59 @@ pub struct DescribeLoaRequest {
     /// <p>The standard media type for the LOA-CFA document. The only supported value is application/pdf.</p>
     #[serde(rename = "loaContentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loa_content_type: Option<String>,
+    pub loa_content_type: Option<LoaContentType>,
     /// <p>The name of the service provider who establishes connectivity on your behalf. If you specify this parameter, the LOA-CFA lists the provider name alongside your company name as the requester of the cross connect.</p>
     #[serde(rename = "providerName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub provider_name: Option<String>,
 }
 
+impl DescribeLoaRequest {
+    /// Returns a builder for `DescribeLoaRequest`, checking its required members when built.
+    pub fn builder() -> DescribeLoaRequestBuilder {
+        DescribeLoaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLoaRequest`](struct.DescribeLoaRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeLoaRequestBuilder {
+    connection_id: Option<String>,
+    loa_content_type: Option<LoaContentType>,
+    provider_name: Option<String>,
+}
+
+impl DescribeLoaRequestBuilder {
+    /// Sets the [`connection_id`](struct.DescribeLoaRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`loa_content_type`](struct.DescribeLoaRequest.html#structfield.loa_content_type) member.
+    pub fn loa_content_type<T: Into<LoaContentType>>(mut self, value: T) -> Self {
+        self.loa_content_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provider_name`](struct.DescribeLoaRequest.html#structfield.provider_name) member.
+    pub fn provider_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.provider_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLoaRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeLoaRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeLoaRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            loa_content_type: self.loa_content_type,
+            provider_name: self.provider_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeTagsRequest {
     /// <p>The Amazon Resource Names (ARNs) of the resources.</p>
@@ -975,6 +3396,36 @@ pub struct DescribeTagsRequest {
     pub resource_arns: Vec<String>,
 }
 
+impl DescribeTagsRequest {
+    /// Returns a builder for `DescribeTagsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTagsRequestBuilder {
+        DescribeTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTagsRequest`](struct.DescribeTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTagsRequestBuilder {
+    resource_arns: Option<Vec<String>>,
+}
+
+impl DescribeTagsRequestBuilder {
+    /// Sets the [`resource_arns`](struct.DescribeTagsRequest.html#structfield.resource_arns) member.
+    pub fn resource_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTagsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTagsRequest {
+            resource_arns: self
+                .resource_arns
+                .ok_or(::rusoto_core::BuildError::missing("resource_arns"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeTagsResponse {
@@ -996,6 +3447,44 @@ pub struct DescribeVirtualInterfacesRequest {
     pub virtual_interface_id: Option<String>,
 }
 
+impl DescribeVirtualInterfacesRequest {
+    /// Returns a builder for `DescribeVirtualInterfacesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeVirtualInterfacesRequestBuilder {
+        DescribeVirtualInterfacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeVirtualInterfacesRequest`](struct.DescribeVirtualInterfacesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeVirtualInterfacesRequestBuilder {
+    connection_id: Option<String>,
+    virtual_interface_id: Option<String>,
+}
+
+impl DescribeVirtualInterfacesRequestBuilder {
+    /// Sets the [`connection_id`](struct.DescribeVirtualInterfacesRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_interface_id`](struct.DescribeVirtualInterfacesRequest.html#structfield.virtual_interface_id) member.
+    pub fn virtual_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_interface_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeVirtualInterfacesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeVirtualInterfacesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeVirtualInterfacesRequest {
+            connection_id: self.connection_id,
+            virtual_interface_id: self.virtual_interface_id,
+        })
+    }
+}
+
 /// <p>Information about a Direct Connect gateway, which enables you to connect virtual interfaces and virtual private gateway or transit gateways.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1015,7 +3504,7 @@ pub struct DirectConnectGateway {
     /// <p><p>The state of the Direct Connect gateway. The following are the possible values:</p> <ul> <li> <p> <code>pending</code>: The initial state after calling <a>CreateDirectConnectGateway</a>.</p> </li> <li> <p> <code>available</code>: The Direct Connect gateway is ready for use.</p> </li> <li> <p> <code>deleting</code>: The initial state after calling <a>DeleteDirectConnectGateway</a>.</p> </li> <li> <p> <code>deleted</code>: The Direct Connect gateway is deleted and cannot pass traffic.</p> </li> </ul></p>
     #[serde(rename = "directConnectGatewayState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub direct_connect_gateway_state: Option<String>,
+    pub direct_connect_gateway_state: Option<DirectConnectGatewayState>,
     /// <p>The ID of the AWS account that owns the Direct Connect gateway.</p>
     #[serde(rename = "ownerAccount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1045,7 +3534,7 @@ pub struct DirectConnectGatewayAssociation {
     /// <p><p>The state of the association. The following are the possible values:</p> <ul> <li> <p> <code>associating</code>: The initial state after calling <a>CreateDirectConnectGatewayAssociation</a>.</p> </li> <li> <p> <code>associated</code>: The Direct Connect gateway and virtual private gateway or transit gateway are successfully associated and ready to pass traffic.</p> </li> <li> <p> <code>disassociating</code>: The initial state after calling <a>DeleteDirectConnectGatewayAssociation</a>.</p> </li> <li> <p> <code>disassociated</code>: The virtual private gateway or transit gateway is disassociated from the Direct Connect gateway. Traffic flow between the Direct Connect gateway and virtual private gateway or transit gateway is stopped.</p> </li> </ul></p>
     #[serde(rename = "associationState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_state: Option<String>,
+    pub association_state: Option<DirectConnectGatewayAssociationState>,
     /// <p>The ID of the Direct Connect gateway.</p>
     #[serde(rename = "directConnectGatewayId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1099,13 +3588,177 @@ pub struct DirectConnectGatewayAssociationProposal {
     /// <p><p>The state of the proposal. The following are possible values:</p> <ul> <li> <p> <code>accepted</code>: The proposal has been accepted. The Direct Connect gateway association is available to use in this state.</p> </li> <li> <p> <code>deleted</code>: The proposal has been deleted by the owner that made the proposal. The Direct Connect gateway association cannot be used in this state.</p> </li> <li> <p> <code>requested</code>: The proposal has been requested. The Direct Connect gateway association cannot be used in this state.</p> </li> </ul></p>
     #[serde(rename = "proposalState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub proposal_state: Option<String>,
+    pub proposal_state: Option<DirectConnectGatewayAssociationProposalState>,
     /// <p>The Amazon VPC prefixes to advertise to the Direct Connect gateway.</p>
     #[serde(rename = "requestedAllowedPrefixesToDirectConnectGateway")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub requested_allowed_prefixes_to_direct_connect_gateway: Option<Vec<RouteFilterPrefix>>,
 }
 
+/// <p><p>The state of the proposal. The following are possible values:</p> <ul> <li> <p> <code>accepted</code>: The proposal has been accepted. The Direct Connect gateway association is available to use in this state.</p> </li> <li> <p> <code>deleted</code>: The proposal has been deleted by the owner that made the proposal. The Direct Connect gateway association cannot be used in this state.</p> </li> <li> <p> <code>requested</code>: The proposal has been requested. The Direct Connect gateway association cannot be used in this state.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DirectConnectGatewayAssociationProposalState {
+    /// <p><code>requested</code> - The proposal has been requested. The Direct Connect gateway association cannot be used in this state.</p>
+    Requested,
+    /// <p><code>accepted</code> - The proposal has been accepted. The Direct Connect gateway association is available to use in this state.</p>
+    Accepted,
+    /// <p><code>deleted</code> - The proposal has been deleted by the owner that made the proposal. The Direct Connect gateway association cannot be used in this state.</p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DirectConnectGatewayAssociationProposalState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectConnectGatewayAssociationProposalState::Requested => "requested",
+            DirectConnectGatewayAssociationProposalState::Accepted => "accepted",
+            DirectConnectGatewayAssociationProposalState::Deleted => "deleted",
+            DirectConnectGatewayAssociationProposalState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DirectConnectGatewayAssociationProposalState {
+    fn from(value: &'a str) -> DirectConnectGatewayAssociationProposalState {
+        match value {
+            "requested" => DirectConnectGatewayAssociationProposalState::Requested,
+            "accepted" => DirectConnectGatewayAssociationProposalState::Accepted,
+            "deleted" => DirectConnectGatewayAssociationProposalState::Deleted,
+            _ => DirectConnectGatewayAssociationProposalState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectConnectGatewayAssociationProposalState {
+    fn from(value: String) -> DirectConnectGatewayAssociationProposalState {
+        DirectConnectGatewayAssociationProposalState::from(value.as_str())
+    }
+}
+
+impl From<DirectConnectGatewayAssociationProposalState> for String {
+    fn from(value: DirectConnectGatewayAssociationProposalState) -> String {
+        match value {
+            DirectConnectGatewayAssociationProposalState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DirectConnectGatewayAssociationProposalState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DirectConnectGatewayAssociationProposalState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectConnectGatewayAssociationProposalState {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<DirectConnectGatewayAssociationProposalState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DirectConnectGatewayAssociationProposalState::from(value))
+    }
+}
+
+/// <p><p>The state of the association. The following are the possible values:</p> <ul> <li> <p> <code>associating</code>: The initial state after calling <a>CreateDirectConnectGatewayAssociation</a>.</p> </li> <li> <p> <code>associated</code>: The Direct Connect gateway and virtual private gateway or transit gateway are successfully associated and ready to pass traffic.</p> </li> <li> <p> <code>disassociating</code>: The initial state after calling <a>DeleteDirectConnectGatewayAssociation</a>.</p> </li> <li> <p> <code>disassociated</code>: The virtual private gateway or transit gateway is disassociated from the Direct Connect gateway. Traffic flow between the Direct Connect gateway and virtual private gateway or transit gateway is stopped.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DirectConnectGatewayAssociationState {
+    /// <p><code>associating</code> - The initial state after calling <a>CreateDirectConnectGatewayAssociation</a>.</p>
+    Associating,
+    /// <p><code>associated</code> - The Direct Connect gateway and virtual private gateway or transit gateway are successfully associated and ready to pass traffic.</p>
+    Associated,
+    /// <p><code>disassociating</code> - The initial state after calling <a>DeleteDirectConnectGatewayAssociation</a>.</p>
+    Disassociating,
+    /// <p><code>disassociated</code> - The virtual private gateway or transit gateway is disassociated from the Direct Connect gateway. Traffic flow between the Direct Connect gateway and virtual private gateway or transit gateway is stopped.</p>
+    Disassociated,
+    /// <p><code>updating</code></p>
+    Updating,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DirectConnectGatewayAssociationState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectConnectGatewayAssociationState::Associating => "associating",
+            DirectConnectGatewayAssociationState::Associated => "associated",
+            DirectConnectGatewayAssociationState::Disassociating => "disassociating",
+            DirectConnectGatewayAssociationState::Disassociated => "disassociated",
+            DirectConnectGatewayAssociationState::Updating => "updating",
+            DirectConnectGatewayAssociationState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DirectConnectGatewayAssociationState {
+    fn from(value: &'a str) -> DirectConnectGatewayAssociationState {
+        match value {
+            "associating" => DirectConnectGatewayAssociationState::Associating,
+            "associated" => DirectConnectGatewayAssociationState::Associated,
+            "disassociating" => DirectConnectGatewayAssociationState::Disassociating,
+            "disassociated" => DirectConnectGatewayAssociationState::Disassociated,
+            "updating" => DirectConnectGatewayAssociationState::Updating,
+            _ => DirectConnectGatewayAssociationState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectConnectGatewayAssociationState {
+    fn from(value: String) -> DirectConnectGatewayAssociationState {
+        DirectConnectGatewayAssociationState::from(value.as_str())
+    }
+}
+
+impl From<DirectConnectGatewayAssociationState> for String {
+    fn from(value: DirectConnectGatewayAssociationState) -> String {
+        match value {
+            DirectConnectGatewayAssociationState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DirectConnectGatewayAssociationState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DirectConnectGatewayAssociationState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectConnectGatewayAssociationState {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<DirectConnectGatewayAssociationState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DirectConnectGatewayAssociationState::from(value))
+    }
+}
+
 /// <p>Information about an attachment between a Direct Connect gateway and a virtual interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1113,11 +3766,11 @@ pub struct DirectConnectGatewayAttachment {
     /// <p><p>The state of the attachment. The following are the possible values:</p> <ul> <li> <p> <code>attaching</code>: The initial state after a virtual interface is created using the Direct Connect gateway.</p> </li> <li> <p> <code>attached</code>: The Direct Connect gateway and virtual interface are attached and ready to pass traffic.</p> </li> <li> <p> <code>detaching</code>: The initial state after calling <a>DeleteVirtualInterface</a>.</p> </li> <li> <p> <code>detached</code>: The virtual interface is detached from the Direct Connect gateway. Traffic flow between the Direct Connect gateway and virtual interface is stopped.</p> </li> </ul></p>
     #[serde(rename = "attachmentState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attachment_state: Option<String>,
+    pub attachment_state: Option<DirectConnectGatewayAttachmentState>,
     /// <p>The interface type.</p>
     #[serde(rename = "attachmentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attachment_type: Option<String>,
+    pub attachment_type: Option<DirectConnectGatewayAttachmentType>,
     /// <p>The ID of the Direct Connect gateway.</p>
     #[serde(rename = "directConnectGatewayId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1140,6 +3793,250 @@ pub struct DirectConnectGatewayAttachment {
     pub virtual_interface_region: Option<String>,
 }
 
+/// <p><p>The state of the attachment. The following are the possible values:</p> <ul> <li> <p> <code>attaching</code>: The initial state after a virtual interface is created using the Direct Connect gateway.</p> </li> <li> <p> <code>attached</code>: The Direct Connect gateway and virtual interface are attached and ready to pass traffic.</p> </li> <li> <p> <code>detaching</code>: The initial state after calling <a>DeleteVirtualInterface</a>.</p> </li> <li> <p> <code>detached</code>: The virtual interface is detached from the Direct Connect gateway. Traffic flow between the Direct Connect gateway and virtual interface is stopped.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DirectConnectGatewayAttachmentState {
+    /// <p><code>attaching</code> - The initial state after a virtual interface is created using the Direct Connect gateway.</p>
+    Attaching,
+    /// <p><code>attached</code> - The Direct Connect gateway and virtual interface are attached and ready to pass traffic.</p>
+    Attached,
+    /// <p><code>detaching</code> - The initial state after calling <a>DeleteVirtualInterface</a>.</p>
+    Detaching,
+    /// <p><code>detached</code> - The virtual interface is detached from the Direct Connect gateway. Traffic flow between the Direct Connect gateway and virtual interface is stopped.</p>
+    Detached,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DirectConnectGatewayAttachmentState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectConnectGatewayAttachmentState::Attaching => "attaching",
+            DirectConnectGatewayAttachmentState::Attached => "attached",
+            DirectConnectGatewayAttachmentState::Detaching => "detaching",
+            DirectConnectGatewayAttachmentState::Detached => "detached",
+            DirectConnectGatewayAttachmentState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DirectConnectGatewayAttachmentState {
+    fn from(value: &'a str) -> DirectConnectGatewayAttachmentState {
+        match value {
+            "attaching" => DirectConnectGatewayAttachmentState::Attaching,
+            "attached" => DirectConnectGatewayAttachmentState::Attached,
+            "detaching" => DirectConnectGatewayAttachmentState::Detaching,
+            "detached" => DirectConnectGatewayAttachmentState::Detached,
+            _ => DirectConnectGatewayAttachmentState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectConnectGatewayAttachmentState {
+    fn from(value: String) -> DirectConnectGatewayAttachmentState {
+        DirectConnectGatewayAttachmentState::from(value.as_str())
+    }
+}
+
+impl From<DirectConnectGatewayAttachmentState> for String {
+    fn from(value: DirectConnectGatewayAttachmentState) -> String {
+        match value {
+            DirectConnectGatewayAttachmentState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DirectConnectGatewayAttachmentState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DirectConnectGatewayAttachmentState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectConnectGatewayAttachmentState {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<DirectConnectGatewayAttachmentState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DirectConnectGatewayAttachmentState::from(value))
+    }
+}
+
+/// <p>The interface type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DirectConnectGatewayAttachmentType {
+    /// <p><code>TransitVirtualInterface</code></p>
+    TransitVirtualInterface,
+    /// <p><code>PrivateVirtualInterface</code></p>
+    PrivateVirtualInterface,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DirectConnectGatewayAttachmentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectConnectGatewayAttachmentType::TransitVirtualInterface => {
+                "TransitVirtualInterface"
+            }
+            DirectConnectGatewayAttachmentType::PrivateVirtualInterface => {
+                "PrivateVirtualInterface"
+            }
+            DirectConnectGatewayAttachmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DirectConnectGatewayAttachmentType {
+    fn from(value: &'a str) -> DirectConnectGatewayAttachmentType {
+        match value {
+            "TransitVirtualInterface" => {
+                DirectConnectGatewayAttachmentType::TransitVirtualInterface
+            }
+            "PrivateVirtualInterface" => {
+                DirectConnectGatewayAttachmentType::PrivateVirtualInterface
+            }
+            _ => DirectConnectGatewayAttachmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectConnectGatewayAttachmentType {
+    fn from(value: String) -> DirectConnectGatewayAttachmentType {
+        DirectConnectGatewayAttachmentType::from(value.as_str())
+    }
+}
+
+impl From<DirectConnectGatewayAttachmentType> for String {
+    fn from(value: DirectConnectGatewayAttachmentType) -> String {
+        match value {
+            DirectConnectGatewayAttachmentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DirectConnectGatewayAttachmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DirectConnectGatewayAttachmentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectConnectGatewayAttachmentType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<DirectConnectGatewayAttachmentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DirectConnectGatewayAttachmentType::from(value))
+    }
+}
+
+/// <p><p>The state of the Direct Connect gateway. The following are the possible values:</p> <ul> <li> <p> <code>pending</code>: The initial state after calling <a>CreateDirectConnectGateway</a>.</p> </li> <li> <p> <code>available</code>: The Direct Connect gateway is ready for use.</p> </li> <li> <p> <code>deleting</code>: The initial state after calling <a>DeleteDirectConnectGateway</a>.</p> </li> <li> <p> <code>deleted</code>: The Direct Connect gateway is deleted and cannot pass traffic.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DirectConnectGatewayState {
+    /// <p><code>pending</code> - The initial state after calling <a>CreateDirectConnectGateway</a>.</p>
+    Pending,
+    /// <p><code>available</code> - The Direct Connect gateway is ready for use.</p>
+    Available,
+    /// <p><code>deleting</code> - The initial state after calling <a>DeleteDirectConnectGateway</a>.</p>
+    Deleting,
+    /// <p><code>deleted</code> - The Direct Connect gateway is deleted and cannot pass traffic.</p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DirectConnectGatewayState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectConnectGatewayState::Pending => "pending",
+            DirectConnectGatewayState::Available => "available",
+            DirectConnectGatewayState::Deleting => "deleting",
+            DirectConnectGatewayState::Deleted => "deleted",
+            DirectConnectGatewayState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DirectConnectGatewayState {
+    fn from(value: &'a str) -> DirectConnectGatewayState {
+        match value {
+            "pending" => DirectConnectGatewayState::Pending,
+            "available" => DirectConnectGatewayState::Available,
+            "deleting" => DirectConnectGatewayState::Deleting,
+            "deleted" => DirectConnectGatewayState::Deleted,
+            _ => DirectConnectGatewayState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectConnectGatewayState {
+    fn from(value: String) -> DirectConnectGatewayState {
+        DirectConnectGatewayState::from(value.as_str())
+    }
+}
+
+impl From<DirectConnectGatewayState> for String {
+    fn from(value: DirectConnectGatewayState) -> String {
+        match value {
+            DirectConnectGatewayState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DirectConnectGatewayState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DirectConnectGatewayState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectConnectGatewayState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DirectConnectGatewayState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DirectConnectGatewayState::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DisassociateConnectionFromLagRequest {
     /// <p>The ID of the connection.</p>
@@ -1150,41 +4047,232 @@ pub struct DisassociateConnectionFromLagRequest {
     pub lag_id: String,
 }
 
-/// <p>Information about an interconnect.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Interconnect {
-    /// <p>The Direct Connect endpoint on which the physical connection terminates.</p>
-    #[serde(rename = "awsDevice")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub aws_device: Option<String>,
-    /// <p>The Direct Connect endpoint on which the physical connection terminates.</p>
-    #[serde(rename = "awsDeviceV2")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub aws_device_v2: Option<String>,
-    /// <p>The bandwidth of the connection.</p>
-    #[serde(rename = "bandwidth")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bandwidth: Option<String>,
-    /// <p>Indicates whether the interconnect supports a secondary BGP in the same address family (IPv4/IPv6).</p>
-    #[serde(rename = "hasLogicalRedundancy")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_logical_redundancy: Option<String>,
-    /// <p>The ID of the interconnect.</p>
-    #[serde(rename = "interconnectId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub interconnect_id: Option<String>,
-    /// <p>The name of the interconnect.</p>
-    #[serde(rename = "interconnectName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub interconnect_name: Option<String>,
-    /// <p><p>The state of the interconnect. The following are the possible values:</p> <ul> <li> <p> <code>requested</code>: The initial state of an interconnect. The interconnect stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The interconnect is approved, and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up, and the interconnect is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The interconnect is being deleted.</p> </li> <li> <p> <code>deleted</code>: The interconnect is deleted.</p> </li> <li> <p> <code>unknown</code>: The state of the interconnect is not available.</p> </li> </ul></p>
-    #[serde(rename = "interconnectState")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub interconnect_state: Option<String>,
-    /// <p>Indicates whether jumbo frames (9001 MTU) are supported.</p>
-    #[serde(rename = "jumboFrameCapable")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl DisassociateConnectionFromLagRequest {
+    /// Returns a builder for `DisassociateConnectionFromLagRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateConnectionFromLagRequestBuilder {
+        DisassociateConnectionFromLagRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateConnectionFromLagRequest`](struct.DisassociateConnectionFromLagRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateConnectionFromLagRequestBuilder {
+    connection_id: Option<String>,
+    lag_id: Option<String>,
+}
+
+impl DisassociateConnectionFromLagRequestBuilder {
+    /// Sets the [`connection_id`](struct.DisassociateConnectionFromLagRequest.html#structfield.connection_id) member.
+    pub fn connection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lag_id`](struct.DisassociateConnectionFromLagRequest.html#structfield.lag_id) member.
+    pub fn lag_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.lag_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateConnectionFromLagRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateConnectionFromLagRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociateConnectionFromLagRequest {
+            connection_id: self
+                .connection_id
+                .ok_or(::rusoto_core::BuildError::missing("connection_id"))?,
+            lag_id: self
+                .lag_id
+                .ok_or(::rusoto_core::BuildError::missing("lag_id"))?,
+        })
+    }
+}
+
+/// <p>The type of associated gateway.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GatewayType {
+    /// <p><code>virtualPrivateGateway</code></p>
+    VirtualPrivateGateway,
+    /// <p><code>transitGateway</code></p>
+    TransitGateway,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl GatewayType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GatewayType::VirtualPrivateGateway => "virtualPrivateGateway",
+            GatewayType::TransitGateway => "transitGateway",
+            GatewayType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GatewayType {
+    fn from(value: &'a str) -> GatewayType {
+        match value {
+            "virtualPrivateGateway" => GatewayType::VirtualPrivateGateway,
+            "transitGateway" => GatewayType::TransitGateway,
+            _ => GatewayType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GatewayType {
+    fn from(value: String) -> GatewayType {
+        GatewayType::from(value.as_str())
+    }
+}
+
+impl From<GatewayType> for String {
+    fn from(value: GatewayType) -> String {
+        match value {
+            GatewayType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GatewayType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for GatewayType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GatewayType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<GatewayType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(GatewayType::from(value))
+    }
+}
+
+/// <p>Indicates whether the connection supports a secondary BGP peer in the same address family (IPv4/IPv6).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HasLogicalRedundancy {
+    /// <p><code>unknown</code></p>
+    UnknownValue,
+    /// <p><code>yes</code></p>
+    Yes,
+    /// <p><code>no</code></p>
+    No,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HasLogicalRedundancy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HasLogicalRedundancy::UnknownValue => "unknown",
+            HasLogicalRedundancy::Yes => "yes",
+            HasLogicalRedundancy::No => "no",
+            HasLogicalRedundancy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HasLogicalRedundancy {
+    fn from(value: &'a str) -> HasLogicalRedundancy {
+        match value {
+            "unknown" => HasLogicalRedundancy::UnknownValue,
+            "yes" => HasLogicalRedundancy::Yes,
+            "no" => HasLogicalRedundancy::No,
+            _ => HasLogicalRedundancy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HasLogicalRedundancy {
+    fn from(value: String) -> HasLogicalRedundancy {
+        HasLogicalRedundancy::from(value.as_str())
+    }
+}
+
+impl From<HasLogicalRedundancy> for String {
+    fn from(value: HasLogicalRedundancy) -> String {
+        match value {
+            HasLogicalRedundancy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HasLogicalRedundancy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HasLogicalRedundancy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HasLogicalRedundancy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HasLogicalRedundancy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HasLogicalRedundancy::from(value))
+    }
+}
+
+/// <p>Information about an interconnect.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Interconnect {
+    /// <p>The Direct Connect endpoint on which the physical connection terminates.</p>
+    #[serde(rename = "awsDevice")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_device: Option<String>,
+    /// <p>The Direct Connect endpoint on which the physical connection terminates.</p>
+    #[serde(rename = "awsDeviceV2")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_device_v2: Option<String>,
+    /// <p>The bandwidth of the connection.</p>
+    #[serde(rename = "bandwidth")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bandwidth: Option<String>,
+    /// <p>Indicates whether the interconnect supports a secondary BGP in the same address family (IPv4/IPv6).</p>
+    #[serde(rename = "hasLogicalRedundancy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_logical_redundancy: Option<HasLogicalRedundancy>,
+    /// <p>The ID of the interconnect.</p>
+    #[serde(rename = "interconnectId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interconnect_id: Option<String>,
+    /// <p>The name of the interconnect.</p>
+    #[serde(rename = "interconnectName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interconnect_name: Option<String>,
+    /// <p><p>The state of the interconnect. The following are the possible values:</p> <ul> <li> <p> <code>requested</code>: The initial state of an interconnect. The interconnect stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The interconnect is approved, and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up, and the interconnect is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The interconnect is being deleted.</p> </li> <li> <p> <code>deleted</code>: The interconnect is deleted.</p> </li> <li> <p> <code>unknown</code>: The state of the interconnect is not available.</p> </li> </ul></p>
+    #[serde(rename = "interconnectState")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interconnect_state: Option<InterconnectState>,
+    /// <p>Indicates whether jumbo frames (9001 MTU) are supported.</p>
+    #[serde(rename = "jumboFrameCapable")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub jumbo_frame_capable: Option<bool>,
     /// <p>The ID of the LAG.</p>
     #[serde(rename = "lagId")]
@@ -1192,8 +4280,9 @@ pub struct Interconnect {
     pub lag_id: Option<String>,
     /// <p>The time of the most recent call to <a>DescribeLoa</a> for this connection.</p>
     #[serde(rename = "loaIssueTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loa_issue_time: Option<f64>,
+    pub loa_issue_time: Option<EpochTimestamp>,
     /// <p>The location of the connection.</p>
     #[serde(rename = "location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1208,6 +4297,98 @@ pub struct Interconnect {
     pub tags: Option<Vec<Tag>>,
 }
 
+/// <p><p>The state of the interconnect. The following are the possible values:</p> <ul> <li> <p> <code>requested</code>: The initial state of an interconnect. The interconnect stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p> </li> <li> <p> <code>pending</code>: The interconnect is approved, and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is up, and the interconnect is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The interconnect is being deleted.</p> </li> <li> <p> <code>deleted</code>: The interconnect is deleted.</p> </li> <li> <p> <code>unknown</code>: The state of the interconnect is not available.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InterconnectState {
+    /// <p><code>requested</code> - The initial state of an interconnect. The interconnect stays in the requested state until the Letter of Authorization (LOA) is sent to the customer.</p>
+    Requested,
+    /// <p><code>pending</code> - The interconnect is approved, and is being initialized.</p>
+    Pending,
+    /// <p><code>available</code> - The network link is up, and the interconnect is ready for use.</p>
+    Available,
+    /// <p><code>down</code> - The network link is down.</p>
+    Down,
+    /// <p><code>deleting</code> - The interconnect is being deleted.</p>
+    Deleting,
+    /// <p><code>deleted</code> - The interconnect is deleted.</p>
+    Deleted,
+    /// <p><code>unknown</code> - The state of the interconnect is not available.</p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InterconnectState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InterconnectState::Requested => "requested",
+            InterconnectState::Pending => "pending",
+            InterconnectState::Available => "available",
+            InterconnectState::Down => "down",
+            InterconnectState::Deleting => "deleting",
+            InterconnectState::Deleted => "deleted",
+            InterconnectState::UnknownValue => "unknown",
+            InterconnectState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InterconnectState {
+    fn from(value: &'a str) -> InterconnectState {
+        match value {
+            "requested" => InterconnectState::Requested,
+            "pending" => InterconnectState::Pending,
+            "available" => InterconnectState::Available,
+            "down" => InterconnectState::Down,
+            "deleting" => InterconnectState::Deleting,
+            "deleted" => InterconnectState::Deleted,
+            "unknown" => InterconnectState::UnknownValue,
+            _ => InterconnectState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InterconnectState {
+    fn from(value: String) -> InterconnectState {
+        InterconnectState::from(value.as_str())
+    }
+}
+
+impl From<InterconnectState> for String {
+    fn from(value: InterconnectState) -> String {
+        match value {
+            InterconnectState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InterconnectState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InterconnectState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InterconnectState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InterconnectState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InterconnectState::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Interconnects {
@@ -1244,7 +4425,7 @@ pub struct Lag {
     /// <p>Indicates whether the LAG supports a secondary BGP peer in the same address family (IPv4/IPv6).</p>
     #[serde(rename = "hasLogicalRedundancy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub has_logical_redundancy: Option<String>,
+    pub has_logical_redundancy: Option<HasLogicalRedundancy>,
     /// <p>Indicates whether jumbo frames (9001 MTU) are supported.</p>
     #[serde(rename = "jumboFrameCapable")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1260,7 +4441,7 @@ pub struct Lag {
     /// <p><p>The state of the LAG. The following are the possible values:</p> <ul> <li> <p> <code>requested</code>: The initial state of a LAG. The LAG stays in the requested state until the Letter of Authorization (LOA) is available.</p> </li> <li> <p> <code>pending</code>: The LAG has been approved and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is established and the LAG is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The LAG is being deleted.</p> </li> <li> <p> <code>deleted</code>: The LAG is deleted.</p> </li> <li> <p> <code>unknown</code>: The state of the LAG is not available.</p> </li> </ul></p>
     #[serde(rename = "lagState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lag_state: Option<String>,
+    pub lag_state: Option<LagState>,
     /// <p>The location of the LAG.</p>
     #[serde(rename = "location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1287,6 +4468,98 @@ pub struct Lag {
     pub tags: Option<Vec<Tag>>,
 }
 
+/// <p><p>The state of the LAG. The following are the possible values:</p> <ul> <li> <p> <code>requested</code>: The initial state of a LAG. The LAG stays in the requested state until the Letter of Authorization (LOA) is available.</p> </li> <li> <p> <code>pending</code>: The LAG has been approved and is being initialized.</p> </li> <li> <p> <code>available</code>: The network link is established and the LAG is ready for use.</p> </li> <li> <p> <code>down</code>: The network link is down.</p> </li> <li> <p> <code>deleting</code>: The LAG is being deleted.</p> </li> <li> <p> <code>deleted</code>: The LAG is deleted.</p> </li> <li> <p> <code>unknown</code>: The state of the LAG is not available.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LagState {
+    /// <p><code>requested</code> - The initial state of a LAG. The LAG stays in the requested state until the Letter of Authorization (LOA) is available.</p>
+    Requested,
+    /// <p><code>pending</code> - The LAG has been approved and is being initialized.</p>
+    Pending,
+    /// <p><code>available</code> - The network link is established and the LAG is ready for use.</p>
+    Available,
+    /// <p><code>down</code> - The network link is down.</p>
+    Down,
+    /// <p><code>deleting</code> - The LAG is being deleted.</p>
+    Deleting,
+    /// <p><code>deleted</code> - The LAG is deleted.</p>
+    Deleted,
+    /// <p><code>unknown</code> - The state of the LAG is not available.</p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LagState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LagState::Requested => "requested",
+            LagState::Pending => "pending",
+            LagState::Available => "available",
+            LagState::Down => "down",
+            LagState::Deleting => "deleting",
+            LagState::Deleted => "deleted",
+            LagState::UnknownValue => "unknown",
+            LagState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LagState {
+    fn from(value: &'a str) -> LagState {
+        match value {
+            "requested" => LagState::Requested,
+            "pending" => LagState::Pending,
+            "available" => LagState::Available,
+            "down" => LagState::Down,
+            "deleting" => LagState::Deleting,
+            "deleted" => LagState::Deleted,
+            "unknown" => LagState::UnknownValue,
+            _ => LagState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LagState {
+    fn from(value: String) -> LagState {
+        LagState::from(value.as_str())
+    }
+}
+
+impl From<LagState> for String {
+    fn from(value: LagState) -> String {
+        match value {
+            LagState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LagState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LagState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LagState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LagState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LagState::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Lags {
@@ -1312,7 +4585,75 @@ pub struct Loa {
     /// <p>The standard media type for the LOA-CFA document. The only supported value is application/pdf.</p>
     #[serde(rename = "loaContentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub loa_content_type: Option<String>,
+    pub loa_content_type: Option<LoaContentType>,
+}
+
+/// <p>The standard media type for the LOA-CFA document. The only supported value is application/pdf.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoaContentType {
+    /// <p><code>application/pdf</code></p>
+    ApplicationPdf,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoaContentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoaContentType::ApplicationPdf => "application/pdf",
+            LoaContentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoaContentType {
+    fn from(value: &'a str) -> LoaContentType {
+        match value {
+            "application/pdf" => LoaContentType::ApplicationPdf,
+            _ => LoaContentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoaContentType {
+    fn from(value: String) -> LoaContentType {
+        LoaContentType::from(value.as_str())
+    }
+}
+
+impl From<LoaContentType> for String {
+    fn from(value: LoaContentType) -> String {
+        match value {
+            LoaContentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoaContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LoaContentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoaContentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LoaContentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoaContentType::from(value))
+    }
 }
 
 /// <p>Information about an AWS Direct Connect location.</p>
@@ -1352,7 +4693,7 @@ pub struct NewBGPPeer {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1377,7 +4718,7 @@ pub struct NewPrivateVirtualInterface {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1423,7 +4764,7 @@ pub struct NewPrivateVirtualInterfaceAllocation {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1461,7 +4802,7 @@ pub struct NewPublicVirtualInterface {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1499,7 +4840,7 @@ pub struct NewPublicVirtualInterfaceAllocation {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1537,7 +4878,7 @@ pub struct NewTransitVirtualInterface {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1582,7 +4923,7 @@ pub struct NewTransitVirtualInterfaceAllocation {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1662,6 +5003,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -1676,6 +5057,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -1696,6 +5117,63 @@ pub struct UpdateDirectConnectGatewayAssociationRequest {
     pub remove_allowed_prefixes_to_direct_connect_gateway: Option<Vec<RouteFilterPrefix>>,
 }
 
+impl UpdateDirectConnectGatewayAssociationRequest {
+    /// Returns a builder for `UpdateDirectConnectGatewayAssociationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDirectConnectGatewayAssociationRequestBuilder {
+        UpdateDirectConnectGatewayAssociationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDirectConnectGatewayAssociationRequest`](struct.UpdateDirectConnectGatewayAssociationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDirectConnectGatewayAssociationRequestBuilder {
+    add_allowed_prefixes_to_direct_connect_gateway: Option<Vec<RouteFilterPrefix>>,
+    association_id: Option<String>,
+    remove_allowed_prefixes_to_direct_connect_gateway: Option<Vec<RouteFilterPrefix>>,
+}
+
+impl UpdateDirectConnectGatewayAssociationRequestBuilder {
+    /// Sets the [`add_allowed_prefixes_to_direct_connect_gateway`](struct.UpdateDirectConnectGatewayAssociationRequest.html#structfield.add_allowed_prefixes_to_direct_connect_gateway) member.
+    pub fn add_allowed_prefixes_to_direct_connect_gateway<T: Into<Vec<RouteFilterPrefix>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.add_allowed_prefixes_to_direct_connect_gateway = Some(value.into());
+        self
+    }
+
+    /// Sets the [`association_id`](struct.UpdateDirectConnectGatewayAssociationRequest.html#structfield.association_id) member.
+    pub fn association_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.association_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remove_allowed_prefixes_to_direct_connect_gateway`](struct.UpdateDirectConnectGatewayAssociationRequest.html#structfield.remove_allowed_prefixes_to_direct_connect_gateway) member.
+    pub fn remove_allowed_prefixes_to_direct_connect_gateway<T: Into<Vec<RouteFilterPrefix>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.remove_allowed_prefixes_to_direct_connect_gateway = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDirectConnectGatewayAssociationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        UpdateDirectConnectGatewayAssociationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(UpdateDirectConnectGatewayAssociationRequest {
+            add_allowed_prefixes_to_direct_connect_gateway: self
+                .add_allowed_prefixes_to_direct_connect_gateway,
+            association_id: self.association_id,
+            remove_allowed_prefixes_to_direct_connect_gateway: self
+                .remove_allowed_prefixes_to_direct_connect_gateway,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDirectConnectGatewayAssociationResult {
@@ -1719,18 +5197,105 @@ pub struct UpdateLagRequest {
     pub minimum_links: Option<i64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateVirtualInterfaceAttributesRequest {
-    /// <p>The maximum transmission unit (MTU), in bytes. The supported values are 1500 and 9001. The default value is 1500.</p>
-    #[serde(rename = "mtu")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub mtu: Option<i64>,
-    /// <p>The ID of the virtual private interface.</p>
-    #[serde(rename = "virtualInterfaceId")]
-    pub virtual_interface_id: String,
+impl UpdateLagRequest {
+    /// Returns a builder for `UpdateLagRequest`, checking its required members when built.
+    pub fn builder() -> UpdateLagRequestBuilder {
+        UpdateLagRequestBuilder::default()
+    }
 }
 
-/// <p>Information about a virtual private gateway for a private virtual interface.</p>
+/// Builds a [`UpdateLagRequest`](struct.UpdateLagRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateLagRequestBuilder {
+    lag_id: Option<String>,
+    lag_name: Option<String>,
+    minimum_links: Option<i64>,
+}
+
+impl UpdateLagRequestBuilder {
+    /// Sets the [`lag_id`](struct.UpdateLagRequest.html#structfield.lag_id) member.
+    pub fn lag_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.lag_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lag_name`](struct.UpdateLagRequest.html#structfield.lag_name) member.
+    pub fn lag_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.lag_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`minimum_links`](struct.UpdateLagRequest.html#structfield.minimum_links) member.
+    pub fn minimum_links<T: Into<i64>>(mut self, value: T) -> Self {
+        self.minimum_links = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateLagRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateLagRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateLagRequest {
+            lag_id: self
+                .lag_id
+                .ok_or(::rusoto_core::BuildError::missing("lag_id"))?,
+            lag_name: self.lag_name,
+            minimum_links: self.minimum_links,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateVirtualInterfaceAttributesRequest {
+    /// <p>The maximum transmission unit (MTU), in bytes. The supported values are 1500 and 9001. The default value is 1500.</p>
+    #[serde(rename = "mtu")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<i64>,
+    /// <p>The ID of the virtual private interface.</p>
+    #[serde(rename = "virtualInterfaceId")]
+    pub virtual_interface_id: String,
+}
+
+impl UpdateVirtualInterfaceAttributesRequest {
+    /// Returns a builder for `UpdateVirtualInterfaceAttributesRequest`, checking its required members when built.
+    pub fn builder() -> UpdateVirtualInterfaceAttributesRequestBuilder {
+        UpdateVirtualInterfaceAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateVirtualInterfaceAttributesRequest`](struct.UpdateVirtualInterfaceAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateVirtualInterfaceAttributesRequestBuilder {
+    mtu: Option<i64>,
+    virtual_interface_id: Option<String>,
+}
+
+impl UpdateVirtualInterfaceAttributesRequestBuilder {
+    /// Sets the [`mtu`](struct.UpdateVirtualInterfaceAttributesRequest.html#structfield.mtu) member.
+    pub fn mtu<T: Into<i64>>(mut self, value: T) -> Self {
+        self.mtu = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_interface_id`](struct.UpdateVirtualInterfaceAttributesRequest.html#structfield.virtual_interface_id) member.
+    pub fn virtual_interface_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_interface_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateVirtualInterfaceAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateVirtualInterfaceAttributesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateVirtualInterfaceAttributesRequest {
+            mtu: self.mtu,
+            virtual_interface_id: self
+                .virtual_interface_id
+                .ok_or(::rusoto_core::BuildError::missing("virtual_interface_id"))?,
+        })
+    }
+}
+
+/// <p>Information about a virtual private gateway for a private virtual interface.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct VirtualGateway {
@@ -1760,7 +5325,7 @@ pub struct VirtualInterface {
     /// <p>The address family for the BGP peer.</p>
     #[serde(rename = "addressFamily")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub address_family: Option<String>,
+    pub address_family: Option<AddressFamily>,
     /// <p>The IP address assigned to the Amazon interface.</p>
     #[serde(rename = "amazonAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1844,7 +5409,7 @@ pub struct VirtualInterface {
     /// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
     #[serde(rename = "virtualInterfaceState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtual_interface_state: Option<String>,
+    pub virtual_interface_state: Option<VirtualInterfaceState>,
     /// <p>The type of virtual interface. The possible values are <code>private</code> and <code>public</code>.</p>
     #[serde(rename = "virtualInterfaceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1855,6 +5420,106 @@ pub struct VirtualInterface {
     pub vlan: Option<i64>,
 }
 
+/// <p><p>The state of the virtual interface. The following are the possible values:</p> <ul> <li> <p> <code>confirming</code>: The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p> </li> <li> <p> <code>verifying</code>: This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p> </li> <li> <p> <code>pending</code>: A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p> </li> <li> <p> <code>available</code>: A virtual interface that is able to forward traffic.</p> </li> <li> <p> <code>down</code>: A virtual interface that is BGP down.</p> </li> <li> <p> <code>deleting</code>: A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p> </li> <li> <p> <code>deleted</code>: A virtual interface that cannot forward traffic.</p> </li> <li> <p> <code>rejected</code>: The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p> </li> <li> <p> <code>unknown</code>: The state of the virtual interface is not available.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VirtualInterfaceState {
+    /// <p><code>confirming</code> - The creation of the virtual interface is pending confirmation from the virtual interface owner. If the owner of the virtual interface is different from the owner of the connection on which it is provisioned, then the virtual interface will remain in this state until it is confirmed by the virtual interface owner.</p>
+    Confirming,
+    /// <p><code>verifying</code> - This state only applies to public virtual interfaces. Each public virtual interface needs validation before the virtual interface can be created.</p>
+    Verifying,
+    /// <p><code>pending</code> - A virtual interface is in this state from the time that it is created until the virtual interface is ready to forward traffic.</p>
+    Pending,
+    /// <p><code>available</code> - A virtual interface that is able to forward traffic.</p>
+    Available,
+    /// <p><code>down</code> - A virtual interface that is BGP down.</p>
+    Down,
+    /// <p><code>deleting</code> - A virtual interface is in this state immediately after calling <a>DeleteVirtualInterface</a> until it can no longer forward traffic.</p>
+    Deleting,
+    /// <p><code>deleted</code> - A virtual interface that cannot forward traffic.</p>
+    Deleted,
+    /// <p><code>rejected</code> - The virtual interface owner has declined creation of the virtual interface. If a virtual interface in the <code>Confirming</code> state is deleted by the virtual interface owner, the virtual interface enters the <code>Rejected</code> state.</p>
+    Rejected,
+    /// <p><code>unknown</code> - The state of the virtual interface is not available.</p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VirtualInterfaceState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VirtualInterfaceState::Confirming => "confirming",
+            VirtualInterfaceState::Verifying => "verifying",
+            VirtualInterfaceState::Pending => "pending",
+            VirtualInterfaceState::Available => "available",
+            VirtualInterfaceState::Down => "down",
+            VirtualInterfaceState::Deleting => "deleting",
+            VirtualInterfaceState::Deleted => "deleted",
+            VirtualInterfaceState::Rejected => "rejected",
+            VirtualInterfaceState::UnknownValue => "unknown",
+            VirtualInterfaceState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VirtualInterfaceState {
+    fn from(value: &'a str) -> VirtualInterfaceState {
+        match value {
+            "confirming" => VirtualInterfaceState::Confirming,
+            "verifying" => VirtualInterfaceState::Verifying,
+            "pending" => VirtualInterfaceState::Pending,
+            "available" => VirtualInterfaceState::Available,
+            "down" => VirtualInterfaceState::Down,
+            "deleting" => VirtualInterfaceState::Deleting,
+            "deleted" => VirtualInterfaceState::Deleted,
+            "rejected" => VirtualInterfaceState::Rejected,
+            "unknown" => VirtualInterfaceState::UnknownValue,
+            _ => VirtualInterfaceState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VirtualInterfaceState {
+    fn from(value: String) -> VirtualInterfaceState {
+        VirtualInterfaceState::from(value.as_str())
+    }
+}
+
+impl From<VirtualInterfaceState> for String {
+    fn from(value: VirtualInterfaceState) -> String {
+        match value {
+            VirtualInterfaceState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VirtualInterfaceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for VirtualInterfaceState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VirtualInterfaceState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VirtualInterfaceState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VirtualInterfaceState::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct VirtualInterfaces {
@@ -4675,6 +8340,345 @@ pub trait DirectConnect {
         input: UpdateVirtualInterfaceAttributesRequest,
     ) -> RusotoFuture<VirtualInterface, UpdateVirtualInterfaceAttributesError>;
 }
+impl<T: ?Sized + DirectConnect> DirectConnect for ::std::sync::Arc<T> {
+    fn accept_direct_connect_gateway_association_proposal(
+        &self,
+        input: AcceptDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        AcceptDirectConnectGatewayAssociationProposalResult,
+        AcceptDirectConnectGatewayAssociationProposalError,
+    > {
+        (**self).accept_direct_connect_gateway_association_proposal(input)
+    }
+    fn allocate_connection_on_interconnect(
+        &self,
+        input: AllocateConnectionOnInterconnectRequest,
+    ) -> RusotoFuture<Connection, AllocateConnectionOnInterconnectError> {
+        (**self).allocate_connection_on_interconnect(input)
+    }
+    fn allocate_hosted_connection(
+        &self,
+        input: AllocateHostedConnectionRequest,
+    ) -> RusotoFuture<Connection, AllocateHostedConnectionError> {
+        (**self).allocate_hosted_connection(input)
+    }
+    fn allocate_private_virtual_interface(
+        &self,
+        input: AllocatePrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AllocatePrivateVirtualInterfaceError> {
+        (**self).allocate_private_virtual_interface(input)
+    }
+    fn allocate_public_virtual_interface(
+        &self,
+        input: AllocatePublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AllocatePublicVirtualInterfaceError> {
+        (**self).allocate_public_virtual_interface(input)
+    }
+    fn allocate_transit_virtual_interface(
+        &self,
+        input: AllocateTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<AllocateTransitVirtualInterfaceResult, AllocateTransitVirtualInterfaceError>
+    {
+        (**self).allocate_transit_virtual_interface(input)
+    }
+    fn associate_connection_with_lag(
+        &self,
+        input: AssociateConnectionWithLagRequest,
+    ) -> RusotoFuture<Connection, AssociateConnectionWithLagError> {
+        (**self).associate_connection_with_lag(input)
+    }
+    fn associate_hosted_connection(
+        &self,
+        input: AssociateHostedConnectionRequest,
+    ) -> RusotoFuture<Connection, AssociateHostedConnectionError> {
+        (**self).associate_hosted_connection(input)
+    }
+    fn associate_virtual_interface(
+        &self,
+        input: AssociateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, AssociateVirtualInterfaceError> {
+        (**self).associate_virtual_interface(input)
+    }
+    fn confirm_connection(
+        &self,
+        input: ConfirmConnectionRequest,
+    ) -> RusotoFuture<ConfirmConnectionResponse, ConfirmConnectionError> {
+        (**self).confirm_connection(input)
+    }
+    fn confirm_private_virtual_interface(
+        &self,
+        input: ConfirmPrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmPrivateVirtualInterfaceResponse, ConfirmPrivateVirtualInterfaceError>
+    {
+        (**self).confirm_private_virtual_interface(input)
+    }
+    fn confirm_public_virtual_interface(
+        &self,
+        input: ConfirmPublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmPublicVirtualInterfaceResponse, ConfirmPublicVirtualInterfaceError>
+    {
+        (**self).confirm_public_virtual_interface(input)
+    }
+    fn confirm_transit_virtual_interface(
+        &self,
+        input: ConfirmTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<ConfirmTransitVirtualInterfaceResponse, ConfirmTransitVirtualInterfaceError>
+    {
+        (**self).confirm_transit_virtual_interface(input)
+    }
+    fn create_bgp_peer(
+        &self,
+        input: CreateBGPPeerRequest,
+    ) -> RusotoFuture<CreateBGPPeerResponse, CreateBGPPeerError> {
+        (**self).create_bgp_peer(input)
+    }
+    fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> RusotoFuture<Connection, CreateConnectionError> {
+        (**self).create_connection(input)
+    }
+    fn create_direct_connect_gateway(
+        &self,
+        input: CreateDirectConnectGatewayRequest,
+    ) -> RusotoFuture<CreateDirectConnectGatewayResult, CreateDirectConnectGatewayError> {
+        (**self).create_direct_connect_gateway(input)
+    }
+    fn create_direct_connect_gateway_association(
+        &self,
+        input: CreateDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        CreateDirectConnectGatewayAssociationResult,
+        CreateDirectConnectGatewayAssociationError,
+    > {
+        (**self).create_direct_connect_gateway_association(input)
+    }
+    fn create_direct_connect_gateway_association_proposal(
+        &self,
+        input: CreateDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        CreateDirectConnectGatewayAssociationProposalResult,
+        CreateDirectConnectGatewayAssociationProposalError,
+    > {
+        (**self).create_direct_connect_gateway_association_proposal(input)
+    }
+    fn create_interconnect(
+        &self,
+        input: CreateInterconnectRequest,
+    ) -> RusotoFuture<Interconnect, CreateInterconnectError> {
+        (**self).create_interconnect(input)
+    }
+    fn create_lag(&self, input: CreateLagRequest) -> RusotoFuture<Lag, CreateLagError> {
+        (**self).create_lag(input)
+    }
+    fn create_private_virtual_interface(
+        &self,
+        input: CreatePrivateVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, CreatePrivateVirtualInterfaceError> {
+        (**self).create_private_virtual_interface(input)
+    }
+    fn create_public_virtual_interface(
+        &self,
+        input: CreatePublicVirtualInterfaceRequest,
+    ) -> RusotoFuture<VirtualInterface, CreatePublicVirtualInterfaceError> {
+        (**self).create_public_virtual_interface(input)
+    }
+    fn create_transit_virtual_interface(
+        &self,
+        input: CreateTransitVirtualInterfaceRequest,
+    ) -> RusotoFuture<CreateTransitVirtualInterfaceResult, CreateTransitVirtualInterfaceError> {
+        (**self).create_transit_virtual_interface(input)
+    }
+    fn delete_bgp_peer(
+        &self,
+        input: DeleteBGPPeerRequest,
+    ) -> RusotoFuture<DeleteBGPPeerResponse, DeleteBGPPeerError> {
+        (**self).delete_bgp_peer(input)
+    }
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<Connection, DeleteConnectionError> {
+        (**self).delete_connection(input)
+    }
+    fn delete_direct_connect_gateway(
+        &self,
+        input: DeleteDirectConnectGatewayRequest,
+    ) -> RusotoFuture<DeleteDirectConnectGatewayResult, DeleteDirectConnectGatewayError> {
+        (**self).delete_direct_connect_gateway(input)
+    }
+    fn delete_direct_connect_gateway_association(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        DeleteDirectConnectGatewayAssociationResult,
+        DeleteDirectConnectGatewayAssociationError,
+    > {
+        (**self).delete_direct_connect_gateway_association(input)
+    }
+    fn delete_direct_connect_gateway_association_proposal(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationProposalRequest,
+    ) -> RusotoFuture<
+        DeleteDirectConnectGatewayAssociationProposalResult,
+        DeleteDirectConnectGatewayAssociationProposalError,
+    > {
+        (**self).delete_direct_connect_gateway_association_proposal(input)
+    }
+    fn delete_interconnect(
+        &self,
+        input: DeleteInterconnectRequest,
+    ) -> RusotoFuture<DeleteInterconnectResponse, DeleteInterconnectError> {
+        (**self).delete_interconnect(input)
+    }
+    fn delete_lag(&self, input: DeleteLagRequest) -> RusotoFuture<Lag, DeleteLagError> {
+        (**self).delete_lag(input)
+    }
+    fn delete_virtual_interface(
+        &self,
+        input: DeleteVirtualInterfaceRequest,
+    ) -> RusotoFuture<DeleteVirtualInterfaceResponse, DeleteVirtualInterfaceError> {
+        (**self).delete_virtual_interface(input)
+    }
+    fn describe_connection_loa(
+        &self,
+        input: DescribeConnectionLoaRequest,
+    ) -> RusotoFuture<DescribeConnectionLoaResponse, DescribeConnectionLoaError> {
+        (**self).describe_connection_loa(input)
+    }
+    fn describe_connections(
+        &self,
+        input: DescribeConnectionsRequest,
+    ) -> RusotoFuture<Connections, DescribeConnectionsError> {
+        (**self).describe_connections(input)
+    }
+    fn describe_connections_on_interconnect(
+        &self,
+        input: DescribeConnectionsOnInterconnectRequest,
+    ) -> RusotoFuture<Connections, DescribeConnectionsOnInterconnectError> {
+        (**self).describe_connections_on_interconnect(input)
+    }
+    fn describe_direct_connect_gateway_association_proposals(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationProposalsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAssociationProposalsResult,
+        DescribeDirectConnectGatewayAssociationProposalsError,
+    > {
+        (**self).describe_direct_connect_gateway_association_proposals(input)
+    }
+    fn describe_direct_connect_gateway_associations(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAssociationsResult,
+        DescribeDirectConnectGatewayAssociationsError,
+    > {
+        (**self).describe_direct_connect_gateway_associations(input)
+    }
+    fn describe_direct_connect_gateway_attachments(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> RusotoFuture<
+        DescribeDirectConnectGatewayAttachmentsResult,
+        DescribeDirectConnectGatewayAttachmentsError,
+    > {
+        (**self).describe_direct_connect_gateway_attachments(input)
+    }
+    fn describe_direct_connect_gateways(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> RusotoFuture<DescribeDirectConnectGatewaysResult, DescribeDirectConnectGatewaysError> {
+        (**self).describe_direct_connect_gateways(input)
+    }
+    fn describe_hosted_connections(
+        &self,
+        input: DescribeHostedConnectionsRequest,
+    ) -> RusotoFuture<Connections, DescribeHostedConnectionsError> {
+        (**self).describe_hosted_connections(input)
+    }
+    fn describe_interconnect_loa(
+        &self,
+        input: DescribeInterconnectLoaRequest,
+    ) -> RusotoFuture<DescribeInterconnectLoaResponse, DescribeInterconnectLoaError> {
+        (**self).describe_interconnect_loa(input)
+    }
+    fn describe_interconnects(
+        &self,
+        input: DescribeInterconnectsRequest,
+    ) -> RusotoFuture<Interconnects, DescribeInterconnectsError> {
+        (**self).describe_interconnects(input)
+    }
+    fn describe_lags(&self, input: DescribeLagsRequest) -> RusotoFuture<Lags, DescribeLagsError> {
+        (**self).describe_lags(input)
+    }
+    fn describe_loa(&self, input: DescribeLoaRequest) -> RusotoFuture<Loa, DescribeLoaError> {
+        (**self).describe_loa(input)
+    }
+    fn describe_locations(&self) -> RusotoFuture<Locations, DescribeLocationsError> {
+        (**self).describe_locations()
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_virtual_gateways(
+        &self,
+    ) -> RusotoFuture<VirtualGateways, DescribeVirtualGatewaysError> {
+        (**self).describe_virtual_gateways()
+    }
+    fn describe_virtual_interfaces(
+        &self,
+        input: DescribeVirtualInterfacesRequest,
+    ) -> RusotoFuture<VirtualInterfaces, DescribeVirtualInterfacesError> {
+        (**self).describe_virtual_interfaces(input)
+    }
+    fn disassociate_connection_from_lag(
+        &self,
+        input: DisassociateConnectionFromLagRequest,
+    ) -> RusotoFuture<Connection, DisassociateConnectionFromLagError> {
+        (**self).disassociate_connection_from_lag(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_direct_connect_gateway_association(
+        &self,
+        input: UpdateDirectConnectGatewayAssociationRequest,
+    ) -> RusotoFuture<
+        UpdateDirectConnectGatewayAssociationResult,
+        UpdateDirectConnectGatewayAssociationError,
+    > {
+        (**self).update_direct_connect_gateway_association(input)
+    }
+    fn update_lag(&self, input: UpdateLagRequest) -> RusotoFuture<Lag, UpdateLagError> {
+        (**self).update_lag(input)
+    }
+    fn update_virtual_interface_attributes(
+        &self,
+        input: UpdateVirtualInterfaceAttributesRequest,
+    ) -> RusotoFuture<VirtualInterface, UpdateVirtualInterfaceAttributesError> {
+        (**self).update_virtual_interface_attributes(input)
+    }
+}
+/// The error codes AWS Direct Connect retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Direct Connect API.
 #[derive(Clone)]
 pub struct DirectConnectClient {
@@ -4690,6 +8694,27 @@ impl DirectConnectClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_DIRECTCONNECT`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> DirectConnectClient {
+        Self::new(region::Region::from_env_for_service("DIRECTCONNECT"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `directconnect`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> DirectConnectClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("directconnect", "DIRECTCONNECT"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4708,7 +8733,54 @@ impl DirectConnectClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DirectConnectClient {
-        DirectConnectClient { client, region }
+        DirectConnectClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "directconnect")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "directconnect",
+            "directconnect",
+            "DIRECTCONNECT",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `DirectConnect` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn DirectConnect + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for DirectConnectClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Direct Connect"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "directconnect"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> DirectConnectClient {
+        Self::new_with_client(client, region)
     }
 }
 
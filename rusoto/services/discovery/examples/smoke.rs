@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Application Discovery Service clients.
+//!
+//! Makes a single read-only `DescribeAgents` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_discovery::{DescribeAgentsRequest, Discovery, DiscoveryClient};
+
+fn main() {
+    let client = DiscoveryClient::from_env();
+    match client
+        .describe_agents(DescribeAgentsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeAgents succeeded"),
+        Err(error) => {
+            eprintln!("DescribeAgents failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Information about agents or connectors that were instructed to start collecting data. Information includes the agent/connector ID, a description of the operation, and whether the agent/connector configuration was updated.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -68,7 +69,7 @@ pub struct AgentInfo {
     /// <p>The health of the agent or connector.</p>
     #[serde(rename = "health")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health: Option<String>,
+    pub health: Option<AgentStatus>,
     /// <p>The name of the host where the agent or connector resides. The host can be a server or virtual machine.</p>
     #[serde(rename = "hostName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -101,6 +102,94 @@ pub struct AgentNetworkInfo {
     pub mac_address: Option<String>,
 }
 
+/// <p>The health of the agent or connector.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AgentStatus {
+    /// <p><code>HEALTHY</code></p>
+    Healthy,
+    /// <p><code>UNHEALTHY</code></p>
+    Unhealthy,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// <p><code>BLACKLISTED</code></p>
+    Blacklisted,
+    /// <p><code>SHUTDOWN</code></p>
+    Shutdown,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AgentStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AgentStatus::Healthy => "HEALTHY",
+            AgentStatus::Unhealthy => "UNHEALTHY",
+            AgentStatus::Running => "RUNNING",
+            AgentStatus::UnknownValue => "UNKNOWN",
+            AgentStatus::Blacklisted => "BLACKLISTED",
+            AgentStatus::Shutdown => "SHUTDOWN",
+            AgentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AgentStatus {
+    fn from(value: &'a str) -> AgentStatus {
+        match value {
+            "HEALTHY" => AgentStatus::Healthy,
+            "UNHEALTHY" => AgentStatus::Unhealthy,
+            "RUNNING" => AgentStatus::Running,
+            "UNKNOWN" => AgentStatus::UnknownValue,
+            "BLACKLISTED" => AgentStatus::Blacklisted,
+            "SHUTDOWN" => AgentStatus::Shutdown,
+            _ => AgentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AgentStatus {
+    fn from(value: String) -> AgentStatus {
+        AgentStatus::from(value.as_str())
+    }
+}
+
+impl From<AgentStatus> for String {
+    fn from(value: AgentStatus) -> String {
+        match value {
+            AgentStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AgentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AgentStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AgentStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AgentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AgentStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateConfigurationItemsToApplicationRequest {
     /// <p>The configuration ID of an application with which items are to be associated.</p>
@@ -111,10 +200,373 @@ pub struct AssociateConfigurationItemsToApplicationRequest {
     pub configuration_ids: Vec<String>,
 }
 
+impl AssociateConfigurationItemsToApplicationRequest {
+    /// Returns a builder for `AssociateConfigurationItemsToApplicationRequest`, checking its required members when built.
+    pub fn builder() -> AssociateConfigurationItemsToApplicationRequestBuilder {
+        AssociateConfigurationItemsToApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateConfigurationItemsToApplicationRequest`](struct.AssociateConfigurationItemsToApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateConfigurationItemsToApplicationRequestBuilder {
+    application_configuration_id: Option<String>,
+    configuration_ids: Option<Vec<String>>,
+}
+
+impl AssociateConfigurationItemsToApplicationRequestBuilder {
+    /// Sets the [`application_configuration_id`](struct.AssociateConfigurationItemsToApplicationRequest.html#structfield.application_configuration_id) member.
+    pub fn application_configuration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_configuration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration_ids`](struct.AssociateConfigurationItemsToApplicationRequest.html#structfield.configuration_ids) member.
+    pub fn configuration_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.configuration_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateConfigurationItemsToApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        AssociateConfigurationItemsToApplicationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(AssociateConfigurationItemsToApplicationRequest {
+            application_configuration_id: self.application_configuration_id.ok_or(
+                ::rusoto_core::BuildError::missing("application_configuration_id"),
+            )?,
+            configuration_ids: self
+                .configuration_ids
+                .ok_or(::rusoto_core::BuildError::missing("configuration_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateConfigurationItemsToApplicationResponse {}
 
+/// <p>The type of error that occurred for a specific import task.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BatchDeleteImportDataErrorCode {
+    /// <p><code>NOT_FOUND</code></p>
+    NotFound,
+    /// <p><code>INTERNAL_SERVER_ERROR</code></p>
+    InternalServerError,
+    /// <p><code>OVER_LIMIT</code></p>
+    OverLimit,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BatchDeleteImportDataErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BatchDeleteImportDataErrorCode::NotFound => "NOT_FOUND",
+            BatchDeleteImportDataErrorCode::InternalServerError => "INTERNAL_SERVER_ERROR",
+            BatchDeleteImportDataErrorCode::OverLimit => "OVER_LIMIT",
+            BatchDeleteImportDataErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BatchDeleteImportDataErrorCode {
+    fn from(value: &'a str) -> BatchDeleteImportDataErrorCode {
+        match value {
+            "NOT_FOUND" => BatchDeleteImportDataErrorCode::NotFound,
+            "INTERNAL_SERVER_ERROR" => BatchDeleteImportDataErrorCode::InternalServerError,
+            "OVER_LIMIT" => BatchDeleteImportDataErrorCode::OverLimit,
+            _ => BatchDeleteImportDataErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BatchDeleteImportDataErrorCode {
+    fn from(value: String) -> BatchDeleteImportDataErrorCode {
+        BatchDeleteImportDataErrorCode::from(value.as_str())
+    }
+}
+
+impl From<BatchDeleteImportDataErrorCode> for String {
+    fn from(value: BatchDeleteImportDataErrorCode) -> String {
+        match value {
+            BatchDeleteImportDataErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BatchDeleteImportDataErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BatchDeleteImportDataErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BatchDeleteImportDataErrorCode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<BatchDeleteImportDataErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BatchDeleteImportDataErrorCode::from(value))
+    }
+}
+
+/// <p>A type of IT asset to tag.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConfigurationItemType {
+    /// <p><code>SERVER</code></p>
+    Server,
+    /// <p><code>PROCESS</code></p>
+    Process,
+    /// <p><code>CONNECTION</code></p>
+    Connection,
+    /// <p><code>APPLICATION</code></p>
+    Application,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConfigurationItemType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationItemType::Server => "SERVER",
+            ConfigurationItemType::Process => "PROCESS",
+            ConfigurationItemType::Connection => "CONNECTION",
+            ConfigurationItemType::Application => "APPLICATION",
+            ConfigurationItemType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationItemType {
+    fn from(value: &'a str) -> ConfigurationItemType {
+        match value {
+            "SERVER" => ConfigurationItemType::Server,
+            "PROCESS" => ConfigurationItemType::Process,
+            "CONNECTION" => ConfigurationItemType::Connection,
+            "APPLICATION" => ConfigurationItemType::Application,
+            _ => ConfigurationItemType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationItemType {
+    fn from(value: String) -> ConfigurationItemType {
+        ConfigurationItemType::from(value.as_str())
+    }
+}
+
+impl From<ConfigurationItemType> for String {
+    fn from(value: ConfigurationItemType) -> String {
+        match value {
+            ConfigurationItemType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigurationItemType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConfigurationItemType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfigurationItemType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConfigurationItemType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConfigurationItemType::from(value))
+    }
+}
+
+/// <p><p>Describes the status of the export. Can be one of the following values:</p> <ul> <li> <p>START<em>IN</em>PROGRESS - setting up resources to start continuous export.</p> </li> <li> <p>START<em>FAILED - an error occurred setting up continuous export. To recover, call start-continuous-export again.</p> </li> <li> <p>ACTIVE - data is being exported to the customer bucket.</p> </li> <li> <p>ERROR - an error occurred during export. To fix the issue, call stop-continuous-export and start-continuous-export.</p> </li> <li> <p>STOP</em>IN<em>PROGRESS - stopping the export.</p> </li> <li> <p>STOP</em>FAILED - an error occurred stopping the export. To recover, call stop-continuous-export again.</p> </li> <li> <p>INACTIVE - the continuous export has been stopped. Data is no longer being exported to the customer bucket.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContinuousExportStatus {
+    /// <p><code>START_IN_PROGRESS</code></p>
+    StartInProgress,
+    /// <p><code>START_FAILED</code></p>
+    StartFailed,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// <p><code>STOP_IN_PROGRESS</code></p>
+    StopInProgress,
+    /// <p><code>STOP_FAILED</code></p>
+    StopFailed,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContinuousExportStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContinuousExportStatus::StartInProgress => "START_IN_PROGRESS",
+            ContinuousExportStatus::StartFailed => "START_FAILED",
+            ContinuousExportStatus::Active => "ACTIVE",
+            ContinuousExportStatus::Error => "ERROR",
+            ContinuousExportStatus::StopInProgress => "STOP_IN_PROGRESS",
+            ContinuousExportStatus::StopFailed => "STOP_FAILED",
+            ContinuousExportStatus::Inactive => "INACTIVE",
+            ContinuousExportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContinuousExportStatus {
+    fn from(value: &'a str) -> ContinuousExportStatus {
+        match value {
+            "START_IN_PROGRESS" => ContinuousExportStatus::StartInProgress,
+            "START_FAILED" => ContinuousExportStatus::StartFailed,
+            "ACTIVE" => ContinuousExportStatus::Active,
+            "ERROR" => ContinuousExportStatus::Error,
+            "STOP_IN_PROGRESS" => ContinuousExportStatus::StopInProgress,
+            "STOP_FAILED" => ContinuousExportStatus::StopFailed,
+            "INACTIVE" => ContinuousExportStatus::Inactive,
+            _ => ContinuousExportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContinuousExportStatus {
+    fn from(value: String) -> ContinuousExportStatus {
+        ContinuousExportStatus::from(value.as_str())
+    }
+}
+
+impl From<ContinuousExportStatus> for String {
+    fn from(value: ContinuousExportStatus) -> String {
+        match value {
+            ContinuousExportStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContinuousExportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ContinuousExportStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContinuousExportStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContinuousExportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContinuousExportStatus::from(value))
+    }
+}
+
+/// <p>The type of data collector used to gather this data (currently only offered for AGENT).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DataSource {
+    /// <p><code>AGENT</code></p>
+    Agent,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DataSource {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSource::Agent => "AGENT",
+            DataSource::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DataSource {
+    fn from(value: &'a str) -> DataSource {
+        match value {
+            "AGENT" => DataSource::Agent,
+            _ => DataSource::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSource {
+    fn from(value: String) -> DataSource {
+        DataSource::from(value.as_str())
+    }
+}
+
+impl From<DataSource> for String {
+    fn from(value: DataSource) -> String {
+        match value {
+            DataSource::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DataSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DataSource {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSource {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DataSource, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DataSource::from(value))
+    }
+}
+
 /// <p>Error messages returned for each import task that you deleted as a response for this command.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -122,7 +574,7 @@ pub struct DiscoveryBatchDeleteImportDataError {
     /// <p>The type of error that occurred for a specific import task.</p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<BatchDeleteImportDataErrorCode>,
     /// <p>The description of the error that occurred for a specific import task.</p>
     #[serde(rename = "errorDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -140,6 +592,38 @@ pub struct BatchDeleteImportDataRequest {
     pub import_task_ids: Vec<String>,
 }
 
+impl BatchDeleteImportDataRequest {
+    /// Returns a builder for `BatchDeleteImportDataRequest`, checking its required members when built.
+    pub fn builder() -> BatchDeleteImportDataRequestBuilder {
+        BatchDeleteImportDataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDeleteImportDataRequest`](struct.BatchDeleteImportDataRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDeleteImportDataRequestBuilder {
+    import_task_ids: Option<Vec<String>>,
+}
+
+impl BatchDeleteImportDataRequestBuilder {
+    /// Sets the [`import_task_ids`](struct.BatchDeleteImportDataRequest.html#structfield.import_task_ids) member.
+    pub fn import_task_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.import_task_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDeleteImportDataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDeleteImportDataRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDeleteImportDataRequest {
+            import_task_ids: self
+                .import_task_ids
+                .ok_or(::rusoto_core::BuildError::missing("import_task_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDeleteImportDataResponse {
@@ -160,15 +644,16 @@ pub struct ConfigurationTag {
     /// <p>A type of IT asset to tag.</p>
     #[serde(rename = "configurationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_type: Option<String>,
+    pub configuration_type: Option<ConfigurationItemType>,
     /// <p>A type of tag on which to filter. For example, <i>serverType</i>.</p>
     #[serde(rename = "key")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<String>,
     /// <p>The time the configuration tag was created in Coordinated Universal Time (UTC).</p>
     #[serde(rename = "timeOfCreation")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_of_creation: Option<f64>,
+    pub time_of_creation: Option<EpochTimestamp>,
     /// <p>A value on which to filter. For example <i>key = serverType</i> and <i>value = web server</i>.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -182,7 +667,7 @@ pub struct ContinuousExportDescription {
     /// <p>The type of data collector used to gather this data (currently only offered for AGENT).</p>
     #[serde(rename = "dataSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_source: Option<String>,
+    pub data_source: Option<DataSource>,
     /// <p>The unique ID assigned to this export.</p>
     #[serde(rename = "exportId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -197,20 +682,22 @@ pub struct ContinuousExportDescription {
     pub schema_storage_config: Option<::std::collections::HashMap<String, String>>,
     /// <p>The timestamp representing when the continuous export was started.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p><p>Describes the status of the export. Can be one of the following values:</p> <ul> <li> <p>START<em>IN</em>PROGRESS - setting up resources to start continuous export.</p> </li> <li> <p>START<em>FAILED - an error occurred setting up continuous export. To recover, call start-continuous-export again.</p> </li> <li> <p>ACTIVE - data is being exported to the customer bucket.</p> </li> <li> <p>ERROR - an error occurred during export. To fix the issue, call stop-continuous-export and start-continuous-export.</p> </li> <li> <p>STOP</em>IN<em>PROGRESS - stopping the export.</p> </li> <li> <p>STOP</em>FAILED - an error occurred stopping the export. To recover, call stop-continuous-export again.</p> </li> <li> <p>INACTIVE - the continuous export has been stopped. Data is no longer being exported to the customer bucket.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ContinuousExportStatus>,
     /// <p><p>Contains information about any errors that have occurred. This data type can have the following values:</p> <ul> <li> <p>ACCESS<em>DENIED - You don’t have permission to start Data Exploration in Amazon Athena. Contact your AWS administrator for help. For more information, see <a href="http://docs.aws.amazon.com/application-discovery/latest/userguide/setting-up.html">Setting Up AWS Application Discovery Service</a> in the Application Discovery Service User Guide.</p> </li> <li> <p>DELIVERY</em>STREAM<em>LIMIT</em>FAILURE - You reached the limit for Amazon Kinesis Data Firehose delivery streams. Reduce the number of streams or request a limit increase and try again. For more information, see <a href="http://docs.aws.amazon.com/streams/latest/dev/service-sizes-and-limits.html">Kinesis Data Streams Limits</a> in the Amazon Kinesis Data Streams Developer Guide.</p> </li> <li> <p>FIREHOSE<em>ROLE</em>MISSING - The Data Exploration feature is in an error state because your IAM User is missing the AWSApplicationDiscoveryServiceFirehose role. Turn on Data Exploration in Amazon Athena and try again. For more information, see <a href="http://docs.aws.amazon.com/application-discovery/latest/userguide/setting-up.html#setting-up-user-policy">Step 3: Provide Application Discovery Service Access to Non-Administrator Users by Attaching Policies</a> in the Application Discovery Service User Guide.</p> </li> <li> <p>FIREHOSE<em>STREAM</em>DOES<em>NOT</em>EXIST - The Data Exploration feature is in an error state because your IAM User is missing one or more of the Kinesis data delivery streams.</p> </li> <li> <p>INTERNAL<em>FAILURE - The Data Exploration feature is in an error state because of an internal failure. Try again later. If this problem persists, contact AWS Support.</p> </li> <li> <p>S3</em>BUCKET<em>LIMIT</em>FAILURE - You reached the limit for Amazon S3 buckets. Reduce the number of Amazon S3 buckets or request a limit increase and try again. For more information, see <a href="http://docs.aws.amazon.com/AmazonS3/latest/dev/BucketRestrictions.html">Bucket Restrictions and Limitations</a> in the Amazon Simple Storage Service Developer Guide.</p> </li> <li> <p>S3<em>NOT</em>SIGNED_UP - Your account is not signed up for the Amazon S3 service. You must sign up before you can use Amazon S3. You can sign up at the following URL: <a href="https://aws.amazon.com/s3">https://aws.amazon.com/s3</a>.</p> </li> </ul></p>
     #[serde(rename = "statusDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_detail: Option<String>,
     /// <p>The timestamp that represents when this continuous export was stopped.</p>
     #[serde(rename = "stopTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_time: Option<f64>,
+    pub stop_time: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -224,6 +711,46 @@ pub struct CreateApplicationRequest {
     pub name: String,
 }
 
+impl CreateApplicationRequest {
+    /// Returns a builder for `CreateApplicationRequest`, checking its required members when built.
+    pub fn builder() -> CreateApplicationRequestBuilder {
+        CreateApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApplicationRequest`](struct.CreateApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateApplicationRequestBuilder {
+    description: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateApplicationRequestBuilder {
+    /// Sets the [`description`](struct.CreateApplicationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateApplicationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateApplicationRequest {
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateApplicationResponse {
@@ -243,6 +770,46 @@ pub struct CreateTagsRequest {
     pub tags: Vec<Tag>,
 }
 
+impl CreateTagsRequest {
+    /// Returns a builder for `CreateTagsRequest`, checking its required members when built.
+    pub fn builder() -> CreateTagsRequestBuilder {
+        CreateTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTagsRequest`](struct.CreateTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTagsRequestBuilder {
+    configuration_ids: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateTagsRequestBuilder {
+    /// Sets the [`configuration_ids`](struct.CreateTagsRequest.html#structfield.configuration_ids) member.
+    pub fn configuration_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.configuration_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateTagsRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTagsRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTagsRequest {
+            configuration_ids: self
+                .configuration_ids
+                .ok_or(::rusoto_core::BuildError::missing("configuration_ids"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateTagsResponse {}
@@ -308,6 +875,38 @@ pub struct DeleteApplicationsRequest {
     pub configuration_ids: Vec<String>,
 }
 
+impl DeleteApplicationsRequest {
+    /// Returns a builder for `DeleteApplicationsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationsRequestBuilder {
+        DeleteApplicationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationsRequest`](struct.DeleteApplicationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationsRequestBuilder {
+    configuration_ids: Option<Vec<String>>,
+}
+
+impl DeleteApplicationsRequestBuilder {
+    /// Sets the [`configuration_ids`](struct.DeleteApplicationsRequest.html#structfield.configuration_ids) member.
+    pub fn configuration_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.configuration_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteApplicationsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteApplicationsRequest {
+            configuration_ids: self
+                .configuration_ids
+                .ok_or(::rusoto_core::BuildError::missing("configuration_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteApplicationsResponse {}
@@ -323,6 +922,44 @@ pub struct DeleteTagsRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl DeleteTagsRequest {
+    /// Returns a builder for `DeleteTagsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTagsRequestBuilder {
+        DeleteTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTagsRequest`](struct.DeleteTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTagsRequestBuilder {
+    configuration_ids: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl DeleteTagsRequestBuilder {
+    /// Sets the [`configuration_ids`](struct.DeleteTagsRequest.html#structfield.configuration_ids) member.
+    pub fn configuration_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.configuration_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.DeleteTagsRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTagsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTagsRequest {
+            configuration_ids: self
+                .configuration_ids
+                .ok_or(::rusoto_core::BuildError::missing("configuration_ids"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteTagsResponse {}
@@ -347,6 +984,58 @@ pub struct DescribeAgentsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeAgentsRequest {
+    /// Returns a builder for `DescribeAgentsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAgentsRequestBuilder {
+        DescribeAgentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAgentsRequest`](struct.DescribeAgentsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAgentsRequestBuilder {
+    agent_ids: Option<Vec<String>>,
+    filters: Option<Vec<Filter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeAgentsRequestBuilder {
+    /// Sets the [`agent_ids`](struct.DescribeAgentsRequest.html#structfield.agent_ids) member.
+    pub fn agent_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.agent_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeAgentsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeAgentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeAgentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAgentsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeAgentsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAgentsRequest {
+            agent_ids: self.agent_ids,
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAgentsResponse {
@@ -367,6 +1056,38 @@ pub struct DescribeConfigurationsRequest {
     pub configuration_ids: Vec<String>,
 }
 
+impl DescribeConfigurationsRequest {
+    /// Returns a builder for `DescribeConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConfigurationsRequestBuilder {
+        DescribeConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigurationsRequest`](struct.DescribeConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigurationsRequestBuilder {
+    configuration_ids: Option<Vec<String>>,
+}
+
+impl DescribeConfigurationsRequestBuilder {
+    /// Sets the [`configuration_ids`](struct.DescribeConfigurationsRequest.html#structfield.configuration_ids) member.
+    pub fn configuration_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.configuration_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigurationsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeConfigurationsRequest {
+            configuration_ids: self
+                .configuration_ids
+                .ok_or(::rusoto_core::BuildError::missing("configuration_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeConfigurationsResponse {
@@ -392,6 +1113,52 @@ pub struct DescribeContinuousExportsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeContinuousExportsRequest {
+    /// Returns a builder for `DescribeContinuousExportsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeContinuousExportsRequestBuilder {
+        DescribeContinuousExportsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeContinuousExportsRequest`](struct.DescribeContinuousExportsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeContinuousExportsRequestBuilder {
+    export_ids: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeContinuousExportsRequestBuilder {
+    /// Sets the [`export_ids`](struct.DescribeContinuousExportsRequest.html#structfield.export_ids) member.
+    pub fn export_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.export_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeContinuousExportsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeContinuousExportsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeContinuousExportsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeContinuousExportsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeContinuousExportsRequest {
+            export_ids: self.export_ids,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeContinuousExportsResponse {
@@ -421,6 +1188,52 @@ pub struct DescribeExportConfigurationsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeExportConfigurationsRequest {
+    /// Returns a builder for `DescribeExportConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeExportConfigurationsRequestBuilder {
+        DescribeExportConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeExportConfigurationsRequest`](struct.DescribeExportConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeExportConfigurationsRequestBuilder {
+    export_ids: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeExportConfigurationsRequestBuilder {
+    /// Sets the [`export_ids`](struct.DescribeExportConfigurationsRequest.html#structfield.export_ids) member.
+    pub fn export_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.export_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeExportConfigurationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeExportConfigurationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeExportConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeExportConfigurationsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeExportConfigurationsRequest {
+            export_ids: self.export_ids,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeExportConfigurationsResponse {
@@ -454,6 +1267,60 @@ pub struct DescribeExportTasksRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeExportTasksRequest {
+    /// Returns a builder for `DescribeExportTasksRequest`, checking its required members when built.
+    pub fn builder() -> DescribeExportTasksRequestBuilder {
+        DescribeExportTasksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeExportTasksRequest`](struct.DescribeExportTasksRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeExportTasksRequestBuilder {
+    export_ids: Option<Vec<String>>,
+    filters: Option<Vec<ExportFilter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeExportTasksRequestBuilder {
+    /// Sets the [`export_ids`](struct.DescribeExportTasksRequest.html#structfield.export_ids) member.
+    pub fn export_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.export_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeExportTasksRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<ExportFilter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeExportTasksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeExportTasksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeExportTasksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeExportTasksRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeExportTasksRequest {
+            export_ids: self.export_ids,
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeExportTasksResponse {
@@ -483,6 +1350,52 @@ pub struct DescribeImportTasksRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeImportTasksRequest {
+    /// Returns a builder for `DescribeImportTasksRequest`, checking its required members when built.
+    pub fn builder() -> DescribeImportTasksRequestBuilder {
+        DescribeImportTasksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeImportTasksRequest`](struct.DescribeImportTasksRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeImportTasksRequestBuilder {
+    filters: Option<Vec<ImportTaskFilter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeImportTasksRequestBuilder {
+    /// Sets the [`filters`](struct.DescribeImportTasksRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<ImportTaskFilter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeImportTasksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeImportTasksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeImportTasksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeImportTasksRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeImportTasksRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeImportTasksResponse {
@@ -512,6 +1425,50 @@ pub struct DescribeTagsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeTagsRequest {
+    /// Returns a builder for `DescribeTagsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTagsRequestBuilder {
+        DescribeTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTagsRequest`](struct.DescribeTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTagsRequestBuilder {
+    filters: Option<Vec<TagFilter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeTagsRequestBuilder {
+    /// Sets the [`filters`](struct.DescribeTagsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<TagFilter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeTagsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeTagsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTagsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTagsRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeTagsResponse {
@@ -535,6 +1492,51 @@ pub struct DisassociateConfigurationItemsFromApplicationRequest {
     pub configuration_ids: Vec<String>,
 }
 
+impl DisassociateConfigurationItemsFromApplicationRequest {
+    /// Returns a builder for `DisassociateConfigurationItemsFromApplicationRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateConfigurationItemsFromApplicationRequestBuilder {
+        DisassociateConfigurationItemsFromApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateConfigurationItemsFromApplicationRequest`](struct.DisassociateConfigurationItemsFromApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateConfigurationItemsFromApplicationRequestBuilder {
+    application_configuration_id: Option<String>,
+    configuration_ids: Option<Vec<String>>,
+}
+
+impl DisassociateConfigurationItemsFromApplicationRequestBuilder {
+    /// Sets the [`application_configuration_id`](struct.DisassociateConfigurationItemsFromApplicationRequest.html#structfield.application_configuration_id) member.
+    pub fn application_configuration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_configuration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration_ids`](struct.DisassociateConfigurationItemsFromApplicationRequest.html#structfield.configuration_ids) member.
+    pub fn configuration_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.configuration_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateConfigurationItemsFromApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DisassociateConfigurationItemsFromApplicationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DisassociateConfigurationItemsFromApplicationRequest {
+            application_configuration_id: self.application_configuration_id.ok_or(
+                ::rusoto_core::BuildError::missing("application_configuration_id"),
+            )?,
+            configuration_ids: self
+                .configuration_ids
+                .ok_or(::rusoto_core::BuildError::missing("configuration_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateConfigurationItemsFromApplicationResponse {}
@@ -548,6 +1550,74 @@ pub struct ExportConfigurationsResponse {
     pub export_id: Option<String>,
 }
 
+/// <p>The file format for the returned export data. Default value is <code>CSV</code>. <b>Note:</b> <i>The</i> <code>GRAPHML</code> <i>option has been deprecated.</i> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExportDataFormat {
+    /// <p><code>CSV</code></p>
+    Csv,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExportDataFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportDataFormat::Csv => "CSV",
+            ExportDataFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExportDataFormat {
+    fn from(value: &'a str) -> ExportDataFormat {
+        match value {
+            "CSV" => ExportDataFormat::Csv,
+            _ => ExportDataFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportDataFormat {
+    fn from(value: String) -> ExportDataFormat {
+        ExportDataFormat::from(value.as_str())
+    }
+}
+
+impl From<ExportDataFormat> for String {
+    fn from(value: ExportDataFormat) -> String {
+        match value {
+            ExportDataFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExportDataFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExportDataFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportDataFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExportDataFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExportDataFormat::from(value))
+    }
+}
+
 /// <p>Used to select which agent's data is to be exported. A single agent ID may be selected for export using the <a href="http://docs.aws.amazon.com/application-discovery/latest/APIReference/API_StartExportTask.html">StartExportTask</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ExportFilter {
@@ -563,7 +1633,7 @@ pub struct ExportFilter {
 }
 
 /// <p>Information regarding the export status of discovered data. The value is an array of objects.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ExportInfo {
     /// <p>A URL for an Amazon S3 bucket where you can review the exported data. The URL is displayed only if the export succeeded.</p>
@@ -575,27 +1645,106 @@ pub struct ExportInfo {
     pub export_id: String,
     /// <p>The time that the data export was initiated.</p>
     #[serde(rename = "exportRequestTime")]
-    pub export_request_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub export_request_time: EpochTimestamp,
     /// <p>The status of the data export job.</p>
     #[serde(rename = "exportStatus")]
-    pub export_status: String,
+    pub export_status: ExportStatus,
     /// <p>If true, the export of agent information exceeded the size limit for a single export and the exported data is incomplete for the requested time range. To address this, select a smaller time range for the export by using <code>startDate</code> and <code>endDate</code>.</p>
     #[serde(rename = "isTruncated")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_truncated: Option<bool>,
     /// <p>The <code>endTime</code> used in the <code>StartExportTask</code> request. If no <code>endTime</code> was requested, this result does not appear in <code>ExportInfo</code>.</p>
     #[serde(rename = "requestedEndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requested_end_time: Option<f64>,
+    pub requested_end_time: Option<EpochTimestamp>,
     /// <p>The value of <code>startTime</code> parameter in the <code>StartExportTask</code> request. If no <code>startTime</code> was requested, this result does not appear in <code>ExportInfo</code>.</p>
     #[serde(rename = "requestedStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requested_start_time: Option<f64>,
+    pub requested_start_time: Option<EpochTimestamp>,
     /// <p>A status message provided for API callers.</p>
     #[serde(rename = "statusMessage")]
     pub status_message: String,
 }
 
+/// <p>The status of the data export job.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExportStatus {
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExportStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportStatus::Failed => "FAILED",
+            ExportStatus::Succeeded => "SUCCEEDED",
+            ExportStatus::InProgress => "IN_PROGRESS",
+            ExportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExportStatus {
+    fn from(value: &'a str) -> ExportStatus {
+        match value {
+            "FAILED" => ExportStatus::Failed,
+            "SUCCEEDED" => ExportStatus::Succeeded,
+            "IN_PROGRESS" => ExportStatus::InProgress,
+            _ => ExportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportStatus {
+    fn from(value: String) -> ExportStatus {
+        ExportStatus::from(value.as_str())
+    }
+}
+
+impl From<ExportStatus> for String {
+    fn from(value: ExportStatus) -> String {
+        match value {
+            ExportStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExportStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExportStatus::from(value))
+    }
+}
+
 /// <p>A filter that can use conditional operators.</p> <p>For more information about filters, see <a href="http://docs.aws.amazon.com/application-discovery/latest/APIReference/discovery-api-queries.html">Querying Discovered Configuration Items</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct Filter {
@@ -642,6 +1791,114 @@ pub struct GetDiscoverySummaryResponse {
     pub servers_mappedto_tags: Option<i64>,
 }
 
+/// <p>The status of the import task. An import can have the status of <code>IMPORT_COMPLETE</code> and still have some records fail to import from the overall request. More information can be found in the downloadable archive defined in the <code>errorsAndFailedEntriesZip</code> field, or in the Migration Hub management console.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImportStatus {
+    /// <p><code>IMPORT_IN_PROGRESS</code></p>
+    ImportInProgress,
+    /// <p><code>IMPORT_COMPLETE</code></p>
+    ImportComplete,
+    /// <p><code>IMPORT_COMPLETE_WITH_ERRORS</code></p>
+    ImportCompleteWithErrors,
+    /// <p><code>IMPORT_FAILED</code></p>
+    ImportFailed,
+    /// <p><code>IMPORT_FAILED_SERVER_LIMIT_EXCEEDED</code></p>
+    ImportFailedServerLimitExceeded,
+    /// <p><code>IMPORT_FAILED_RECORD_LIMIT_EXCEEDED</code></p>
+    ImportFailedRecordLimitExceeded,
+    /// <p><code>DELETE_IN_PROGRESS</code></p>
+    DeleteInProgress,
+    /// <p><code>DELETE_COMPLETE</code></p>
+    DeleteComplete,
+    /// <p><code>DELETE_FAILED</code></p>
+    DeleteFailed,
+    /// <p><code>DELETE_FAILED_LIMIT_EXCEEDED</code></p>
+    DeleteFailedLimitExceeded,
+    /// <p><code>INTERNAL_ERROR</code></p>
+    InternalError,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ImportStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImportStatus::ImportInProgress => "IMPORT_IN_PROGRESS",
+            ImportStatus::ImportComplete => "IMPORT_COMPLETE",
+            ImportStatus::ImportCompleteWithErrors => "IMPORT_COMPLETE_WITH_ERRORS",
+            ImportStatus::ImportFailed => "IMPORT_FAILED",
+            ImportStatus::ImportFailedServerLimitExceeded => "IMPORT_FAILED_SERVER_LIMIT_EXCEEDED",
+            ImportStatus::ImportFailedRecordLimitExceeded => "IMPORT_FAILED_RECORD_LIMIT_EXCEEDED",
+            ImportStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            ImportStatus::DeleteComplete => "DELETE_COMPLETE",
+            ImportStatus::DeleteFailed => "DELETE_FAILED",
+            ImportStatus::DeleteFailedLimitExceeded => "DELETE_FAILED_LIMIT_EXCEEDED",
+            ImportStatus::InternalError => "INTERNAL_ERROR",
+            ImportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ImportStatus {
+    fn from(value: &'a str) -> ImportStatus {
+        match value {
+            "IMPORT_IN_PROGRESS" => ImportStatus::ImportInProgress,
+            "IMPORT_COMPLETE" => ImportStatus::ImportComplete,
+            "IMPORT_COMPLETE_WITH_ERRORS" => ImportStatus::ImportCompleteWithErrors,
+            "IMPORT_FAILED" => ImportStatus::ImportFailed,
+            "IMPORT_FAILED_SERVER_LIMIT_EXCEEDED" => ImportStatus::ImportFailedServerLimitExceeded,
+            "IMPORT_FAILED_RECORD_LIMIT_EXCEEDED" => ImportStatus::ImportFailedRecordLimitExceeded,
+            "DELETE_IN_PROGRESS" => ImportStatus::DeleteInProgress,
+            "DELETE_COMPLETE" => ImportStatus::DeleteComplete,
+            "DELETE_FAILED" => ImportStatus::DeleteFailed,
+            "DELETE_FAILED_LIMIT_EXCEEDED" => ImportStatus::DeleteFailedLimitExceeded,
+            "INTERNAL_ERROR" => ImportStatus::InternalError,
+            _ => ImportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImportStatus {
+    fn from(value: String) -> ImportStatus {
+        ImportStatus::from(value.as_str())
+    }
+}
+
+impl From<ImportStatus> for String {
+    fn from(value: ImportStatus) -> String {
+        match value {
+            ImportStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ImportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ImportStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImportStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ImportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ImportStatus::from(value))
+    }
+}
+
 /// <p>An array of information related to the import task request that includes status information, times, IDs, the Amazon S3 Object URL for the import file, and more.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -664,16 +1921,19 @@ pub struct ImportTask {
     pub errors_and_failed_entries_zip: Option<String>,
     /// <p>The time that the import task request finished, presented in the Unix time stamp format.</p>
     #[serde(rename = "importCompletionTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_completion_time: Option<f64>,
+    pub import_completion_time: Option<EpochTimestamp>,
     /// <p>The time that the import task request was deleted, presented in the Unix time stamp format.</p>
     #[serde(rename = "importDeletedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_deleted_time: Option<f64>,
+    pub import_deleted_time: Option<EpochTimestamp>,
     /// <p>The time that the import task request was made, presented in the Unix time stamp format.</p>
     #[serde(rename = "importRequestTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_request_time: Option<f64>,
+    pub import_request_time: Option<EpochTimestamp>,
     /// <p>The unique ID for a specific import task. These IDs aren't globally unique, but they are unique within an AWS account.</p>
     #[serde(rename = "importTaskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -697,7 +1957,7 @@ pub struct ImportTask {
     /// <p>The status of the import task. An import can have the status of <code>IMPORT_COMPLETE</code> and still have some records fail to import from the overall request. More information can be found in the downloadable archive defined in the <code>errorsAndFailedEntriesZip</code> field, or in the Migration Hub management console.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ImportStatus>,
 }
 
 /// <p><p>A name-values pair of elements you can use to filter the results when querying your import tasks. Currently, wildcards are not supported for filters.</p> <note> <p>When filtering by import status, all other filter values are ignored.</p> </note></p>
@@ -706,18 +1966,94 @@ pub struct ImportTaskFilter {
     /// <p>The name, status, or import task ID for a specific import task.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<ImportTaskFilterName>,
     /// <p>An array of strings that you can provide to match against a specific name, status, or import task ID to filter the results for your import task queries.</p>
     #[serde(rename = "values")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p>The name, status, or import task ID for a specific import task.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImportTaskFilterName {
+    /// <p><code>IMPORT_TASK_ID</code></p>
+    ImportTaskId,
+    /// <p><code>STATUS</code></p>
+    Status,
+    /// <p><code>NAME</code></p>
+    Name,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ImportTaskFilterName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImportTaskFilterName::ImportTaskId => "IMPORT_TASK_ID",
+            ImportTaskFilterName::Status => "STATUS",
+            ImportTaskFilterName::Name => "NAME",
+            ImportTaskFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ImportTaskFilterName {
+    fn from(value: &'a str) -> ImportTaskFilterName {
+        match value {
+            "IMPORT_TASK_ID" => ImportTaskFilterName::ImportTaskId,
+            "STATUS" => ImportTaskFilterName::Status,
+            "NAME" => ImportTaskFilterName::Name,
+            _ => ImportTaskFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImportTaskFilterName {
+    fn from(value: String) -> ImportTaskFilterName {
+        ImportTaskFilterName::from(value.as_str())
+    }
+}
+
+impl From<ImportTaskFilterName> for String {
+    fn from(value: ImportTaskFilterName) -> String {
+        match value {
+            ImportTaskFilterName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ImportTaskFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ImportTaskFilterName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImportTaskFilterName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ImportTaskFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ImportTaskFilterName::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListConfigurationsRequest {
     /// <p>A valid configuration identified by Application Discovery Service. </p>
     #[serde(rename = "configurationType")]
-    pub configuration_type: String,
+    pub configuration_type: ConfigurationItemType,
     /// <p>You can filter the request using various logical operators and a <i>key</i>-<i>value</i> format. For example: </p> <p> <code>{"key": "serverType", "value": "webServer"}</code> </p> <p>For a complete list of filter options and guidance about using them with this action, see <a href="http://docs.aws.amazon.com/application-discovery/latest/APIReference/discovery-api-queries.html#ListConfigurations">Querying Discovered Configuration Items</a>. </p>
     #[serde(rename = "filters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -736,6 +2072,70 @@ pub struct ListConfigurationsRequest {
     pub order_by: Option<Vec<OrderByElement>>,
 }
 
+impl ListConfigurationsRequest {
+    /// Returns a builder for `ListConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> ListConfigurationsRequestBuilder {
+        ListConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListConfigurationsRequest`](struct.ListConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListConfigurationsRequestBuilder {
+    configuration_type: Option<ConfigurationItemType>,
+    filters: Option<Vec<Filter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    order_by: Option<Vec<OrderByElement>>,
+}
+
+impl ListConfigurationsRequestBuilder {
+    /// Sets the [`configuration_type`](struct.ListConfigurationsRequest.html#structfield.configuration_type) member.
+    pub fn configuration_type<T: Into<ConfigurationItemType>>(mut self, value: T) -> Self {
+        self.configuration_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.ListConfigurationsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListConfigurationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListConfigurationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`order_by`](struct.ListConfigurationsRequest.html#structfield.order_by) member.
+    pub fn order_by<T: Into<Vec<OrderByElement>>>(mut self, value: T) -> Self {
+        self.order_by = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListConfigurationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListConfigurationsRequest {
+            configuration_type: self
+                .configuration_type
+                .ok_or(::rusoto_core::BuildError::missing("configuration_type"))?,
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            order_by: self.order_by,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListConfigurationsResponse {
@@ -772,6 +2172,70 @@ pub struct ListServerNeighborsRequest {
     pub port_information_needed: Option<bool>,
 }
 
+impl ListServerNeighborsRequest {
+    /// Returns a builder for `ListServerNeighborsRequest`, checking its required members when built.
+    pub fn builder() -> ListServerNeighborsRequestBuilder {
+        ListServerNeighborsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListServerNeighborsRequest`](struct.ListServerNeighborsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListServerNeighborsRequestBuilder {
+    configuration_id: Option<String>,
+    max_results: Option<i64>,
+    neighbor_configuration_ids: Option<Vec<String>>,
+    next_token: Option<String>,
+    port_information_needed: Option<bool>,
+}
+
+impl ListServerNeighborsRequestBuilder {
+    /// Sets the [`configuration_id`](struct.ListServerNeighborsRequest.html#structfield.configuration_id) member.
+    pub fn configuration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListServerNeighborsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`neighbor_configuration_ids`](struct.ListServerNeighborsRequest.html#structfield.neighbor_configuration_ids) member.
+    pub fn neighbor_configuration_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.neighbor_configuration_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListServerNeighborsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port_information_needed`](struct.ListServerNeighborsRequest.html#structfield.port_information_needed) member.
+    pub fn port_information_needed<T: Into<bool>>(mut self, value: T) -> Self {
+        self.port_information_needed = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListServerNeighborsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListServerNeighborsRequest, ::rusoto_core::BuildError> {
+        Ok(ListServerNeighborsRequest {
+            configuration_id: self
+                .configuration_id
+                .ok_or(::rusoto_core::BuildError::missing("configuration_id"))?,
+            max_results: self.max_results,
+            neighbor_configuration_ids: self.neighbor_configuration_ids,
+            next_token: self.next_token,
+            port_information_needed: self.port_information_needed,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListServerNeighborsResponse {
@@ -811,16 +2275,88 @@ pub struct NeighborConnectionDetail {
     pub transport_protocol: Option<String>,
 }
 
-/// <p>A field and direction for ordered output.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct OrderByElement {
-    /// <p>The field on which to order.</p>
-    #[serde(rename = "fieldName")]
-    pub field_name: String,
-    /// <p>Ordering direction.</p>
-    #[serde(rename = "sortOrder")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+/// <p>A field and direction for ordered output.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct OrderByElement {
+    /// <p>The field on which to order.</p>
+    #[serde(rename = "fieldName")]
+    pub field_name: String,
+    /// <p>Ordering direction.</p>
+    #[serde(rename = "sortOrder")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<OrderString>,
+}
+
+/// <p>Ordering direction.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrderString {
+    /// <p><code>ASC</code></p>
+    Asc,
+    /// <p><code>DESC</code></p>
+    Desc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OrderString {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderString::Asc => "ASC",
+            OrderString::Desc => "DESC",
+            OrderString::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OrderString {
+    fn from(value: &'a str) -> OrderString {
+        match value {
+            "ASC" => OrderString::Asc,
+            "DESC" => OrderString::Desc,
+            _ => OrderString::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderString {
+    fn from(value: String) -> OrderString {
+        OrderString::from(value.as_str())
+    }
+}
+
+impl From<OrderString> for String {
+    fn from(value: OrderString) -> String {
+        match value {
+            OrderString::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OrderString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OrderString {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderString {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OrderString, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrderString::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -832,7 +2368,7 @@ pub struct StartContinuousExportResponse {
     /// <p>The type of data collector used to gather this data (currently only offered for AGENT).</p>
     #[serde(rename = "dataSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_source: Option<String>,
+    pub data_source: Option<DataSource>,
     /// <p>The unique ID assigned to this export.</p>
     #[serde(rename = "exportId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -847,8 +2383,9 @@ pub struct StartContinuousExportResponse {
     pub schema_storage_config: Option<::std::collections::HashMap<String, String>>,
     /// <p>The timestamp representing when the continuous export was started.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -858,6 +2395,39 @@ pub struct StartDataCollectionByAgentIdsRequest {
     pub agent_ids: Vec<String>,
 }
 
+impl StartDataCollectionByAgentIdsRequest {
+    /// Returns a builder for `StartDataCollectionByAgentIdsRequest`, checking its required members when built.
+    pub fn builder() -> StartDataCollectionByAgentIdsRequestBuilder {
+        StartDataCollectionByAgentIdsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartDataCollectionByAgentIdsRequest`](struct.StartDataCollectionByAgentIdsRequest.html).
+#[derive(Default, Debug)]
+pub struct StartDataCollectionByAgentIdsRequestBuilder {
+    agent_ids: Option<Vec<String>>,
+}
+
+impl StartDataCollectionByAgentIdsRequestBuilder {
+    /// Sets the [`agent_ids`](struct.StartDataCollectionByAgentIdsRequest.html#structfield.agent_ids) member.
+    pub fn agent_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.agent_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartDataCollectionByAgentIdsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartDataCollectionByAgentIdsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(StartDataCollectionByAgentIdsRequest {
+            agent_ids: self
+                .agent_ids
+                .ok_or(::rusoto_core::BuildError::missing("agent_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartDataCollectionByAgentIdsResponse {
@@ -871,20 +2441,74 @@ pub struct StartDataCollectionByAgentIdsResponse {
 pub struct StartExportTaskRequest {
     /// <p>The end timestamp for exported data from the single Application Discovery Agent selected in the filters. If no value is specified, exported data includes the most recent data collected by the agent.</p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The file format for the returned export data. Default value is <code>CSV</code>. <b>Note:</b> <i>The</i> <code>GRAPHML</code> <i>option has been deprecated.</i> </p>
     #[serde(rename = "exportDataFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub export_data_format: Option<Vec<String>>,
+    pub export_data_format: Option<Vec<ExportDataFormat>>,
     /// <p>If a filter is present, it selects the single <code>agentId</code> of the Application Discovery Agent for which data is exported. The <code>agentId</code> can be found in the results of the <code>DescribeAgents</code> API or CLI. If no filter is present, <code>startTime</code> and <code>endTime</code> are ignored and exported data includes both Agentless Discovery Connector data and summary data from Application Discovery agents. </p>
     #[serde(rename = "filters")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filters: Option<Vec<ExportFilter>>,
     /// <p>The start timestamp for exported data from the single Application Discovery Agent selected in the filters. If no value is specified, data is exported starting from the first data collected by the agent.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
+}
+
+impl StartExportTaskRequest {
+    /// Returns a builder for `StartExportTaskRequest`, checking its required members when built.
+    pub fn builder() -> StartExportTaskRequestBuilder {
+        StartExportTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartExportTaskRequest`](struct.StartExportTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct StartExportTaskRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    export_data_format: Option<Vec<ExportDataFormat>>,
+    filters: Option<Vec<ExportFilter>>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl StartExportTaskRequestBuilder {
+    /// Sets the [`end_time`](struct.StartExportTaskRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`export_data_format`](struct.StartExportTaskRequest.html#structfield.export_data_format) member.
+    pub fn export_data_format<T: Into<Vec<ExportDataFormat>>>(mut self, value: T) -> Self {
+        self.export_data_format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.StartExportTaskRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<ExportFilter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.StartExportTaskRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartExportTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartExportTaskRequest, ::rusoto_core::BuildError> {
+        Ok(StartExportTaskRequest {
+            end_time: self.end_time,
+            export_data_format: self.export_data_format,
+            filters: self.filters,
+            start_time: self.start_time,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -910,6 +2534,54 @@ pub struct StartImportTaskRequest {
     pub name: String,
 }
 
+impl StartImportTaskRequest {
+    /// Returns a builder for `StartImportTaskRequest`, checking its required members when built.
+    pub fn builder() -> StartImportTaskRequestBuilder {
+        StartImportTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartImportTaskRequest`](struct.StartImportTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct StartImportTaskRequestBuilder {
+    client_request_token: Option<String>,
+    import_url: Option<String>,
+    name: Option<String>,
+}
+
+impl StartImportTaskRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartImportTaskRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`import_url`](struct.StartImportTaskRequest.html#structfield.import_url) member.
+    pub fn import_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.import_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.StartImportTaskRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartImportTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartImportTaskRequest, ::rusoto_core::BuildError> {
+        Ok(StartImportTaskRequest {
+            client_request_token: self.client_request_token,
+            import_url: self
+                .import_url
+                .ok_or(::rusoto_core::BuildError::missing("import_url"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartImportTaskResponse {
@@ -926,17 +2598,51 @@ pub struct StopContinuousExportRequest {
     pub export_id: String,
 }
 
+impl StopContinuousExportRequest {
+    /// Returns a builder for `StopContinuousExportRequest`, checking its required members when built.
+    pub fn builder() -> StopContinuousExportRequestBuilder {
+        StopContinuousExportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopContinuousExportRequest`](struct.StopContinuousExportRequest.html).
+#[derive(Default, Debug)]
+pub struct StopContinuousExportRequestBuilder {
+    export_id: Option<String>,
+}
+
+impl StopContinuousExportRequestBuilder {
+    /// Sets the [`export_id`](struct.StopContinuousExportRequest.html#structfield.export_id) member.
+    pub fn export_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.export_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopContinuousExportRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopContinuousExportRequest, ::rusoto_core::BuildError> {
+        Ok(StopContinuousExportRequest {
+            export_id: self
+                .export_id
+                .ok_or(::rusoto_core::BuildError::missing("export_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopContinuousExportResponse {
     /// <p>Timestamp that represents when this continuous export started collecting data.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>Timestamp that represents when this continuous export was stopped.</p>
     #[serde(rename = "stopTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_time: Option<f64>,
+    pub stop_time: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -946,6 +2652,38 @@ pub struct StopDataCollectionByAgentIdsRequest {
     pub agent_ids: Vec<String>,
 }
 
+impl StopDataCollectionByAgentIdsRequest {
+    /// Returns a builder for `StopDataCollectionByAgentIdsRequest`, checking its required members when built.
+    pub fn builder() -> StopDataCollectionByAgentIdsRequestBuilder {
+        StopDataCollectionByAgentIdsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopDataCollectionByAgentIdsRequest`](struct.StopDataCollectionByAgentIdsRequest.html).
+#[derive(Default, Debug)]
+pub struct StopDataCollectionByAgentIdsRequestBuilder {
+    agent_ids: Option<Vec<String>>,
+}
+
+impl StopDataCollectionByAgentIdsRequestBuilder {
+    /// Sets the [`agent_ids`](struct.StopDataCollectionByAgentIdsRequest.html#structfield.agent_ids) member.
+    pub fn agent_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.agent_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopDataCollectionByAgentIdsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopDataCollectionByAgentIdsRequest, ::rusoto_core::BuildError> {
+        Ok(StopDataCollectionByAgentIdsRequest {
+            agent_ids: self
+                .agent_ids
+                .ok_or(::rusoto_core::BuildError::missing("agent_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopDataCollectionByAgentIdsResponse {
@@ -992,6 +2730,54 @@ pub struct UpdateApplicationRequest {
     pub name: Option<String>,
 }
 
+impl UpdateApplicationRequest {
+    /// Returns a builder for `UpdateApplicationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateApplicationRequestBuilder {
+        UpdateApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateApplicationRequest`](struct.UpdateApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateApplicationRequestBuilder {
+    configuration_id: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateApplicationRequestBuilder {
+    /// Sets the [`configuration_id`](struct.UpdateApplicationRequest.html#structfield.configuration_id) member.
+    pub fn configuration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateApplicationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateApplicationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateApplicationRequest {
+            configuration_id: self
+                .configuration_id
+                .ok_or(::rusoto_core::BuildError::missing("configuration_id"))?,
+            description: self.description,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateApplicationResponse {}
@@ -2696,6 +4482,169 @@ pub trait Discovery {
         input: UpdateApplicationRequest,
     ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError>;
 }
+impl<T: ?Sized + Discovery> Discovery for ::std::sync::Arc<T> {
+    fn associate_configuration_items_to_application(
+        &self,
+        input: AssociateConfigurationItemsToApplicationRequest,
+    ) -> RusotoFuture<
+        AssociateConfigurationItemsToApplicationResponse,
+        AssociateConfigurationItemsToApplicationError,
+    > {
+        (**self).associate_configuration_items_to_application(input)
+    }
+    fn batch_delete_import_data(
+        &self,
+        input: BatchDeleteImportDataRequest,
+    ) -> RusotoFuture<BatchDeleteImportDataResponse, BatchDeleteImportDataError> {
+        (**self).batch_delete_import_data(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> RusotoFuture<CreateTagsResponse, CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_applications(
+        &self,
+        input: DeleteApplicationsRequest,
+    ) -> RusotoFuture<DeleteApplicationsResponse, DeleteApplicationsError> {
+        (**self).delete_applications(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> RusotoFuture<DeleteTagsResponse, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_agents(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> RusotoFuture<DescribeAgentsResponse, DescribeAgentsError> {
+        (**self).describe_agents(input)
+    }
+    fn describe_configurations(
+        &self,
+        input: DescribeConfigurationsRequest,
+    ) -> RusotoFuture<DescribeConfigurationsResponse, DescribeConfigurationsError> {
+        (**self).describe_configurations(input)
+    }
+    fn describe_continuous_exports(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> RusotoFuture<DescribeContinuousExportsResponse, DescribeContinuousExportsError> {
+        (**self).describe_continuous_exports(input)
+    }
+    fn describe_export_configurations(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> RusotoFuture<DescribeExportConfigurationsResponse, DescribeExportConfigurationsError> {
+        (**self).describe_export_configurations(input)
+    }
+    fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> RusotoFuture<DescribeExportTasksResponse, DescribeExportTasksError> {
+        (**self).describe_export_tasks(input)
+    }
+    fn describe_import_tasks(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> RusotoFuture<DescribeImportTasksResponse, DescribeImportTasksError> {
+        (**self).describe_import_tasks(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn disassociate_configuration_items_from_application(
+        &self,
+        input: DisassociateConfigurationItemsFromApplicationRequest,
+    ) -> RusotoFuture<
+        DisassociateConfigurationItemsFromApplicationResponse,
+        DisassociateConfigurationItemsFromApplicationError,
+    > {
+        (**self).disassociate_configuration_items_from_application(input)
+    }
+    fn export_configurations(
+        &self,
+    ) -> RusotoFuture<ExportConfigurationsResponse, ExportConfigurationsError> {
+        (**self).export_configurations()
+    }
+    fn get_discovery_summary(
+        &self,
+    ) -> RusotoFuture<GetDiscoverySummaryResponse, GetDiscoverySummaryError> {
+        (**self).get_discovery_summary()
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        (**self).list_configurations(input)
+    }
+    fn list_server_neighbors(
+        &self,
+        input: ListServerNeighborsRequest,
+    ) -> RusotoFuture<ListServerNeighborsResponse, ListServerNeighborsError> {
+        (**self).list_server_neighbors(input)
+    }
+    fn start_continuous_export(
+        &self,
+    ) -> RusotoFuture<StartContinuousExportResponse, StartContinuousExportError> {
+        (**self).start_continuous_export()
+    }
+    fn start_data_collection_by_agent_ids(
+        &self,
+        input: StartDataCollectionByAgentIdsRequest,
+    ) -> RusotoFuture<StartDataCollectionByAgentIdsResponse, StartDataCollectionByAgentIdsError>
+    {
+        (**self).start_data_collection_by_agent_ids(input)
+    }
+    fn start_export_task(
+        &self,
+        input: StartExportTaskRequest,
+    ) -> RusotoFuture<StartExportTaskResponse, StartExportTaskError> {
+        (**self).start_export_task(input)
+    }
+    fn start_import_task(
+        &self,
+        input: StartImportTaskRequest,
+    ) -> RusotoFuture<StartImportTaskResponse, StartImportTaskError> {
+        (**self).start_import_task(input)
+    }
+    fn stop_continuous_export(
+        &self,
+        input: StopContinuousExportRequest,
+    ) -> RusotoFuture<StopContinuousExportResponse, StopContinuousExportError> {
+        (**self).stop_continuous_export(input)
+    }
+    fn stop_data_collection_by_agent_ids(
+        &self,
+        input: StopDataCollectionByAgentIdsRequest,
+    ) -> RusotoFuture<StopDataCollectionByAgentIdsResponse, StopDataCollectionByAgentIdsError> {
+        (**self).stop_data_collection_by_agent_ids(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+}
+/// The error codes AWS Application Discovery Service retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Application Discovery Service API.
 #[derive(Clone)]
 pub struct DiscoveryClient {
@@ -2711,6 +4660,27 @@ impl DiscoveryClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_DISCOVERY`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> DiscoveryClient {
+        Self::new(region::Region::from_env_for_service("DISCOVERY"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `discovery`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> DiscoveryClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("discovery", "DISCOVERY"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2729,7 +4699,54 @@ impl DiscoveryClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DiscoveryClient {
-        DiscoveryClient { client, region }
+        DiscoveryClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "discovery")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "discovery",
+            "discovery",
+            "DISCOVERY",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Discovery` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Discovery + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for DiscoveryClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Application Discovery Service"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "discovery"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> DiscoveryClient {
+        Self::new_with_client(client, region)
     }
 }
 
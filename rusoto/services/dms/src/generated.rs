@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Describes a quota for an AWS account, for example, the number of replication instances allowed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -52,6 +53,48 @@ pub struct AddTagsToResourceMessage {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsToResourceMessage {
+    /// Returns a builder for `AddTagsToResourceMessage`, checking its required members when built.
+    pub fn builder() -> AddTagsToResourceMessageBuilder {
+        AddTagsToResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsToResourceMessage`](struct.AddTagsToResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct AddTagsToResourceMessageBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsToResourceMessageBuilder {
+    /// Sets the [`resource_arn`](struct.AddTagsToResourceMessage.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsToResourceMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsToResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddTagsToResourceMessage, ::rusoto_core::BuildError> {
+        Ok(AddTagsToResourceMessage {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -71,6 +114,59 @@ pub struct ApplyPendingMaintenanceActionMessage {
     pub replication_instance_arn: String,
 }
 
+impl ApplyPendingMaintenanceActionMessage {
+    /// Returns a builder for `ApplyPendingMaintenanceActionMessage`, checking its required members when built.
+    pub fn builder() -> ApplyPendingMaintenanceActionMessageBuilder {
+        ApplyPendingMaintenanceActionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ApplyPendingMaintenanceActionMessage`](struct.ApplyPendingMaintenanceActionMessage.html).
+#[derive(Default, Debug)]
+pub struct ApplyPendingMaintenanceActionMessageBuilder {
+    apply_action: Option<String>,
+    opt_in_type: Option<String>,
+    replication_instance_arn: Option<String>,
+}
+
+impl ApplyPendingMaintenanceActionMessageBuilder {
+    /// Sets the [`apply_action`](struct.ApplyPendingMaintenanceActionMessage.html#structfield.apply_action) member.
+    pub fn apply_action<T: Into<String>>(mut self, value: T) -> Self {
+        self.apply_action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`opt_in_type`](struct.ApplyPendingMaintenanceActionMessage.html#structfield.opt_in_type) member.
+    pub fn opt_in_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.opt_in_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_instance_arn`](struct.ApplyPendingMaintenanceActionMessage.html#structfield.replication_instance_arn) member.
+    pub fn replication_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ApplyPendingMaintenanceActionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ApplyPendingMaintenanceActionMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ApplyPendingMaintenanceActionMessage {
+            apply_action: self
+                .apply_action
+                .ok_or(::rusoto_core::BuildError::missing("apply_action"))?,
+            opt_in_type: self
+                .opt_in_type
+                .ok_or(::rusoto_core::BuildError::missing("opt_in_type"))?,
+            replication_instance_arn: self.replication_instance_arn.ok_or(
+                ::rusoto_core::BuildError::missing("replication_instance_arn"),
+            )?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -81,6 +177,154 @@ pub struct ApplyPendingMaintenanceActionResponse {
     pub resource_pending_maintenance_actions: Option<ResourcePendingMaintenanceActions>,
 }
 
+/// <p> The authentication mechanism you use to access the MongoDB source endpoint.</p> <p>Valid values: DEFAULT, MONGODB_CR, SCRAM_SHA_1 </p> <p>DEFAULT – For MongoDB version 2.x, use MONGODB_CR. For MongoDB version 3.x, use SCRAM_SHA_1. This attribute is not used when authType=No.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthMechanismValue {
+    /// <p><code>default</code></p>
+    Default,
+    /// <p><code>mongodb_cr</code></p>
+    MongodbCr,
+    /// <p><code>scram_sha_1</code></p>
+    ScramSha1,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthMechanismValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthMechanismValue::Default => "default",
+            AuthMechanismValue::MongodbCr => "mongodb_cr",
+            AuthMechanismValue::ScramSha1 => "scram_sha_1",
+            AuthMechanismValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthMechanismValue {
+    fn from(value: &'a str) -> AuthMechanismValue {
+        match value {
+            "default" => AuthMechanismValue::Default,
+            "mongodb_cr" => AuthMechanismValue::MongodbCr,
+            "scram_sha_1" => AuthMechanismValue::ScramSha1,
+            _ => AuthMechanismValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthMechanismValue {
+    fn from(value: String) -> AuthMechanismValue {
+        AuthMechanismValue::from(value.as_str())
+    }
+}
+
+impl From<AuthMechanismValue> for String {
+    fn from(value: AuthMechanismValue) -> String {
+        match value {
+            AuthMechanismValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthMechanismValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AuthMechanismValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthMechanismValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthMechanismValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthMechanismValue::from(value))
+    }
+}
+
+/// <p> The authentication type you use to access the MongoDB source endpoint.</p> <p>Valid values: NO, PASSWORD </p> <p>When NO is selected, user name and password parameters are not used and can be empty. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthTypeValue {
+    /// <p><code>no</code></p>
+    No,
+    /// <p><code>password</code></p>
+    Password,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthTypeValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthTypeValue::No => "no",
+            AuthTypeValue::Password => "password",
+            AuthTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthTypeValue {
+    fn from(value: &'a str) -> AuthTypeValue {
+        match value {
+            "no" => AuthTypeValue::No,
+            "password" => AuthTypeValue::Password,
+            _ => AuthTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthTypeValue {
+    fn from(value: String) -> AuthTypeValue {
+        AuthTypeValue::from(value.as_str())
+    }
+}
+
+impl From<AuthTypeValue> for String {
+    fn from(value: AuthTypeValue) -> String {
+        match value {
+            AuthTypeValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AuthTypeValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthTypeValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthTypeValue::from(value))
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -101,8 +345,9 @@ pub struct Certificate {
     pub certificate_arn: Option<String>,
     /// <p>The date that the certificate was created.</p>
     #[serde(rename = "CertificateCreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub certificate_creation_date: Option<f64>,
+    pub certificate_creation_date: Option<EpochTimestamp>,
     /// <p>A customer-assigned name for the certificate. Identifiers must begin with a letter; must contain only ASCII letters, digits, and hyphens; and must not end with a hyphen or contain two consecutive hyphens.</p>
     #[serde(rename = "CertificateIdentifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -134,12 +379,86 @@ pub struct Certificate {
     pub signing_algorithm: Option<String>,
     /// <p>The beginning date that the certificate is valid.</p>
     #[serde(rename = "ValidFromDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valid_from_date: Option<f64>,
+    pub valid_from_date: Option<EpochTimestamp>,
     /// <p>The final date that the certificate is valid.</p>
     #[serde(rename = "ValidToDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valid_to_date: Option<f64>,
+    pub valid_to_date: Option<EpochTimestamp>,
+}
+
+/// <p> An optional parameter to use GZIP to compress the target files. Set to GZIP to compress the target files. Set to NONE (the default) or do not use to leave the files uncompressed. Applies to both .csv and .parquet file formats. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CompressionTypeValue {
+    /// <p><code>none</code></p>
+    None,
+    /// <p><code>gzip</code></p>
+    Gzip,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CompressionTypeValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CompressionTypeValue::None => "none",
+            CompressionTypeValue::Gzip => "gzip",
+            CompressionTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CompressionTypeValue {
+    fn from(value: &'a str) -> CompressionTypeValue {
+        match value {
+            "none" => CompressionTypeValue::None,
+            "gzip" => CompressionTypeValue::Gzip,
+            _ => CompressionTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CompressionTypeValue {
+    fn from(value: String) -> CompressionTypeValue {
+        CompressionTypeValue::from(value.as_str())
+    }
+}
+
+impl From<CompressionTypeValue> for String {
+    fn from(value: CompressionTypeValue) -> String {
+        match value {
+            CompressionTypeValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CompressionTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CompressionTypeValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CompressionTypeValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CompressionTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CompressionTypeValue::from(value))
+    }
 }
 
 /// <p><p/></p>
@@ -173,7 +492,7 @@ pub struct Connection {
 }
 
 /// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateEndpointMessage {
     /// <p>The Amazon Resource Name (ARN) for the certificate.</p>
     #[serde(rename = "CertificateArn")]
@@ -200,7 +519,7 @@ pub struct CreateEndpointMessage {
     pub endpoint_identifier: String,
     /// <p>The type of endpoint. Valid values are <code>source</code> and <code>target</code>.</p>
     #[serde(rename = "EndpointType")]
-    pub endpoint_type: String,
+    pub endpoint_type: ReplicationEndpointTypeValue,
     /// <p>The type of engine for the endpoint. Valid values, depending on the <code>EndpointType</code> value, include <code>mysql</code>, <code>oracle</code>, <code>postgres</code>, <code>mariadb</code>, <code>aurora</code>, <code>aurora-postgresql</code>, <code>redshift</code>, <code>s3</code>, <code>db2</code>, <code>azuredb</code>, <code>sybase</code>, <code>dynamodb</code>, <code>mongodb</code>, and <code>sqlserver</code>.</p>
     #[serde(rename = "EngineName")]
     pub engine_name: String,
@@ -250,7 +569,7 @@ pub struct CreateEndpointMessage {
     /// <p>The Secure Sockets Layer (SSL) mode to use for the SSL connection. The default is <code>none</code> </p>
     #[serde(rename = "SslMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ssl_mode: Option<String>,
+    pub ssl_mode: Option<DmsSslModeValue>,
     /// <p>One or more tags to be assigned to the endpoint.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -261,6 +580,208 @@ pub struct CreateEndpointMessage {
     pub username: Option<String>,
 }
 
+impl CreateEndpointMessage {
+    /// Returns a builder for `CreateEndpointMessage`, checking its required members when built.
+    pub fn builder() -> CreateEndpointMessageBuilder {
+        CreateEndpointMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateEndpointMessage`](struct.CreateEndpointMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateEndpointMessageBuilder {
+    certificate_arn: Option<String>,
+    database_name: Option<String>,
+    dms_transfer_settings: Option<DmsTransferSettings>,
+    dynamo_db_settings: Option<DynamoDbSettings>,
+    elasticsearch_settings: Option<ElasticsearchSettings>,
+    endpoint_identifier: Option<String>,
+    endpoint_type: Option<ReplicationEndpointTypeValue>,
+    engine_name: Option<String>,
+    external_table_definition: Option<String>,
+    extra_connection_attributes: Option<String>,
+    kinesis_settings: Option<KinesisSettings>,
+    kms_key_id: Option<String>,
+    mongo_db_settings: Option<MongoDbSettings>,
+    password: Option<String>,
+    port: Option<i64>,
+    redshift_settings: Option<RedshiftSettings>,
+    s3_settings: Option<S3Settings>,
+    server_name: Option<String>,
+    service_access_role_arn: Option<String>,
+    ssl_mode: Option<DmsSslModeValue>,
+    tags: Option<Vec<Tag>>,
+    username: Option<String>,
+}
+
+impl CreateEndpointMessageBuilder {
+    /// Sets the [`certificate_arn`](struct.CreateEndpointMessage.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.CreateEndpointMessage.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dms_transfer_settings`](struct.CreateEndpointMessage.html#structfield.dms_transfer_settings) member.
+    pub fn dms_transfer_settings<T: Into<DmsTransferSettings>>(mut self, value: T) -> Self {
+        self.dms_transfer_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dynamo_db_settings`](struct.CreateEndpointMessage.html#structfield.dynamo_db_settings) member.
+    pub fn dynamo_db_settings<T: Into<DynamoDbSettings>>(mut self, value: T) -> Self {
+        self.dynamo_db_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`elasticsearch_settings`](struct.CreateEndpointMessage.html#structfield.elasticsearch_settings) member.
+    pub fn elasticsearch_settings<T: Into<ElasticsearchSettings>>(mut self, value: T) -> Self {
+        self.elasticsearch_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_identifier`](struct.CreateEndpointMessage.html#structfield.endpoint_identifier) member.
+    pub fn endpoint_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_type`](struct.CreateEndpointMessage.html#structfield.endpoint_type) member.
+    pub fn endpoint_type<T: Into<ReplicationEndpointTypeValue>>(mut self, value: T) -> Self {
+        self.endpoint_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_name`](struct.CreateEndpointMessage.html#structfield.engine_name) member.
+    pub fn engine_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`external_table_definition`](struct.CreateEndpointMessage.html#structfield.external_table_definition) member.
+    pub fn external_table_definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.external_table_definition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`extra_connection_attributes`](struct.CreateEndpointMessage.html#structfield.extra_connection_attributes) member.
+    pub fn extra_connection_attributes<T: Into<String>>(mut self, value: T) -> Self {
+        self.extra_connection_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kinesis_settings`](struct.CreateEndpointMessage.html#structfield.kinesis_settings) member.
+    pub fn kinesis_settings<T: Into<KinesisSettings>>(mut self, value: T) -> Self {
+        self.kinesis_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateEndpointMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mongo_db_settings`](struct.CreateEndpointMessage.html#structfield.mongo_db_settings) member.
+    pub fn mongo_db_settings<T: Into<MongoDbSettings>>(mut self, value: T) -> Self {
+        self.mongo_db_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.CreateEndpointMessage.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.CreateEndpointMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`redshift_settings`](struct.CreateEndpointMessage.html#structfield.redshift_settings) member.
+    pub fn redshift_settings<T: Into<RedshiftSettings>>(mut self, value: T) -> Self {
+        self.redshift_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_settings`](struct.CreateEndpointMessage.html#structfield.s3_settings) member.
+    pub fn s3_settings<T: Into<S3Settings>>(mut self, value: T) -> Self {
+        self.s3_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.CreateEndpointMessage.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_access_role_arn`](struct.CreateEndpointMessage.html#structfield.service_access_role_arn) member.
+    pub fn service_access_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_access_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssl_mode`](struct.CreateEndpointMessage.html#structfield.ssl_mode) member.
+    pub fn ssl_mode<T: Into<DmsSslModeValue>>(mut self, value: T) -> Self {
+        self.ssl_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateEndpointMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.CreateEndpointMessage.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateEndpointMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateEndpointMessage, ::rusoto_core::BuildError> {
+        Ok(CreateEndpointMessage {
+            certificate_arn: self.certificate_arn,
+            database_name: self.database_name,
+            dms_transfer_settings: self.dms_transfer_settings,
+            dynamo_db_settings: self.dynamo_db_settings,
+            elasticsearch_settings: self.elasticsearch_settings,
+            endpoint_identifier: self
+                .endpoint_identifier
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_identifier"))?,
+            endpoint_type: self
+                .endpoint_type
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_type"))?,
+            engine_name: self
+                .engine_name
+                .ok_or(::rusoto_core::BuildError::missing("engine_name"))?,
+            external_table_definition: self.external_table_definition,
+            extra_connection_attributes: self.extra_connection_attributes,
+            kinesis_settings: self.kinesis_settings,
+            kms_key_id: self.kms_key_id,
+            mongo_db_settings: self.mongo_db_settings,
+            password: self.password,
+            port: self.port,
+            redshift_settings: self.redshift_settings,
+            s3_settings: self.s3_settings,
+            server_name: self.server_name,
+            service_access_role_arn: self.service_access_role_arn,
+            ssl_mode: self.ssl_mode,
+            tags: self.tags,
+            username: self.username,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -302,6 +823,88 @@ pub struct CreateEventSubscriptionMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateEventSubscriptionMessage {
+    /// Returns a builder for `CreateEventSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> CreateEventSubscriptionMessageBuilder {
+        CreateEventSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateEventSubscriptionMessage`](struct.CreateEventSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateEventSubscriptionMessageBuilder {
+    enabled: Option<bool>,
+    event_categories: Option<Vec<String>>,
+    sns_topic_arn: Option<String>,
+    source_ids: Option<Vec<String>>,
+    source_type: Option<String>,
+    subscription_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateEventSubscriptionMessageBuilder {
+    /// Sets the [`enabled`](struct.CreateEventSubscriptionMessage.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_categories`](struct.CreateEventSubscriptionMessage.html#structfield.event_categories) member.
+    pub fn event_categories<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_categories = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.CreateEventSubscriptionMessage.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_ids`](struct.CreateEventSubscriptionMessage.html#structfield.source_ids) member.
+    pub fn source_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.source_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.CreateEventSubscriptionMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.CreateEventSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateEventSubscriptionMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateEventSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateEventSubscriptionMessage, ::rusoto_core::BuildError> {
+        Ok(CreateEventSubscriptionMessage {
+            enabled: self.enabled,
+            event_categories: self.event_categories,
+            sns_topic_arn: self
+                .sns_topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("sns_topic_arn"))?,
+            source_ids: self.source_ids,
+            source_type: self.source_type,
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -371,6 +974,144 @@ pub struct CreateReplicationInstanceMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl CreateReplicationInstanceMessage {
+    /// Returns a builder for `CreateReplicationInstanceMessage`, checking its required members when built.
+    pub fn builder() -> CreateReplicationInstanceMessageBuilder {
+        CreateReplicationInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateReplicationInstanceMessage`](struct.CreateReplicationInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateReplicationInstanceMessageBuilder {
+    allocated_storage: Option<i64>,
+    auto_minor_version_upgrade: Option<bool>,
+    availability_zone: Option<String>,
+    dns_name_servers: Option<String>,
+    engine_version: Option<String>,
+    kms_key_id: Option<String>,
+    multi_az: Option<bool>,
+    preferred_maintenance_window: Option<String>,
+    publicly_accessible: Option<bool>,
+    replication_instance_class: Option<String>,
+    replication_instance_identifier: Option<String>,
+    replication_subnet_group_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl CreateReplicationInstanceMessageBuilder {
+    /// Sets the [`allocated_storage`](struct.CreateReplicationInstanceMessage.html#structfield.allocated_storage) member.
+    pub fn allocated_storage<T: Into<i64>>(mut self, value: T) -> Self {
+        self.allocated_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_minor_version_upgrade`](struct.CreateReplicationInstanceMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zone`](struct.CreateReplicationInstanceMessage.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dns_name_servers`](struct.CreateReplicationInstanceMessage.html#structfield.dns_name_servers) member.
+    pub fn dns_name_servers<T: Into<String>>(mut self, value: T) -> Self {
+        self.dns_name_servers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.CreateReplicationInstanceMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateReplicationInstanceMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.CreateReplicationInstanceMessage.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateReplicationInstanceMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.CreateReplicationInstanceMessage.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_instance_class`](struct.CreateReplicationInstanceMessage.html#structfield.replication_instance_class) member.
+    pub fn replication_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_instance_identifier`](struct.CreateReplicationInstanceMessage.html#structfield.replication_instance_identifier) member.
+    pub fn replication_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_subnet_group_identifier`](struct.CreateReplicationInstanceMessage.html#structfield.replication_subnet_group_identifier) member.
+    pub fn replication_subnet_group_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_subnet_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateReplicationInstanceMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.CreateReplicationInstanceMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateReplicationInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateReplicationInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(CreateReplicationInstanceMessage {
+            allocated_storage: self.allocated_storage,
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            availability_zone: self.availability_zone,
+            dns_name_servers: self.dns_name_servers,
+            engine_version: self.engine_version,
+            kms_key_id: self.kms_key_id,
+            multi_az: self.multi_az,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            publicly_accessible: self.publicly_accessible,
+            replication_instance_class: self.replication_instance_class.ok_or(
+                ::rusoto_core::BuildError::missing("replication_instance_class"),
+            )?,
+            replication_instance_identifier: self.replication_instance_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("replication_instance_identifier"),
+            )?,
+            replication_subnet_group_identifier: self.replication_subnet_group_identifier,
+            tags: self.tags,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -399,6 +1140,66 @@ pub struct CreateReplicationSubnetGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateReplicationSubnetGroupMessage {
+    /// Returns a builder for `CreateReplicationSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateReplicationSubnetGroupMessageBuilder {
+        CreateReplicationSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateReplicationSubnetGroupMessage`](struct.CreateReplicationSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateReplicationSubnetGroupMessageBuilder {
+    replication_subnet_group_description: Option<String>,
+    replication_subnet_group_identifier: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateReplicationSubnetGroupMessageBuilder {
+    /// Sets the [`replication_subnet_group_description`](struct.CreateReplicationSubnetGroupMessage.html#structfield.replication_subnet_group_description) member.
+    pub fn replication_subnet_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_subnet_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_subnet_group_identifier`](struct.CreateReplicationSubnetGroupMessage.html#structfield.replication_subnet_group_identifier) member.
+    pub fn replication_subnet_group_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_subnet_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.CreateReplicationSubnetGroupMessage.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateReplicationSubnetGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateReplicationSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateReplicationSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateReplicationSubnetGroupMessage {
+            replication_subnet_group_description: self.replication_subnet_group_description.ok_or(
+                ::rusoto_core::BuildError::missing("replication_subnet_group_description"),
+            )?,
+            replication_subnet_group_identifier: self.replication_subnet_group_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("replication_subnet_group_identifier"),
+            )?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -410,7 +1211,7 @@ pub struct CreateReplicationSubnetGroupResponse {
 }
 
 /// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateReplicationTaskMessage {
     /// <p>Indicates when you want a change data capture (CDC) operation to start. Use either CdcStartPosition or CdcStartTime to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p> The value can be in date, checkpoint, or LSN/SCN format.</p> <p>Date Example: --cdc-start-position “2018-03-08T12:12:12”</p> <p>Checkpoint Example: --cdc-start-position "checkpoint:V1#27#mysql-bin-changelog.157832:1975:-1:2002:677883278264080:mysql-bin-changelog.157832:1876#0#0#*#0#93"</p> <p>LSN Example: --cdc-start-position “mysql-bin-changelog.000024:373”</p>
     #[serde(rename = "CdcStartPosition")]
@@ -418,15 +1219,16 @@ pub struct CreateReplicationTaskMessage {
     pub cdc_start_position: Option<String>,
     /// <p>Indicates the start time for a change data capture (CDC) operation. Use either CdcStartTime or CdcStartPosition to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p>Timestamp Example: --cdc-start-time “2018-03-08T12:12:12”</p>
     #[serde(rename = "CdcStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cdc_start_time: Option<f64>,
+    pub cdc_start_time: Option<EpochTimestamp>,
     /// <p>Indicates when you want a change data capture (CDC) operation to stop. The value can be either server time or commit time.</p> <p>Server time example: --cdc-stop-position “server_time:3018-02-09T12:12:12”</p> <p>Commit time example: --cdc-stop-position “commit_time: 3018-02-09T12:12:12 “</p>
     #[serde(rename = "CdcStopPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cdc_stop_position: Option<String>,
     /// <p>The migration type. Valid values: <code>full-load</code> | <code>cdc</code> | <code>full-load-and-cdc</code> </p>
     #[serde(rename = "MigrationType")]
-    pub migration_type: String,
+    pub migration_type: MigrationTypeValue,
     /// <p>The Amazon Resource Name (ARN) of a replication instance.</p>
     #[serde(rename = "ReplicationInstanceArn")]
     pub replication_instance_arn: String,
@@ -452,8 +1254,130 @@ pub struct CreateReplicationTaskMessage {
     pub target_endpoint_arn: String,
 }
 
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl CreateReplicationTaskMessage {
+    /// Returns a builder for `CreateReplicationTaskMessage`, checking its required members when built.
+    pub fn builder() -> CreateReplicationTaskMessageBuilder {
+        CreateReplicationTaskMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateReplicationTaskMessage`](struct.CreateReplicationTaskMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateReplicationTaskMessageBuilder {
+    cdc_start_position: Option<String>,
+    cdc_start_time: Option<EpochTimestamp>,
+    cdc_stop_position: Option<String>,
+    migration_type: Option<MigrationTypeValue>,
+    replication_instance_arn: Option<String>,
+    replication_task_identifier: Option<String>,
+    replication_task_settings: Option<String>,
+    source_endpoint_arn: Option<String>,
+    table_mappings: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_endpoint_arn: Option<String>,
+}
+
+impl CreateReplicationTaskMessageBuilder {
+    /// Sets the [`cdc_start_position`](struct.CreateReplicationTaskMessage.html#structfield.cdc_start_position) member.
+    pub fn cdc_start_position<T: Into<String>>(mut self, value: T) -> Self {
+        self.cdc_start_position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cdc_start_time`](struct.CreateReplicationTaskMessage.html#structfield.cdc_start_time) member.
+    pub fn cdc_start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.cdc_start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cdc_stop_position`](struct.CreateReplicationTaskMessage.html#structfield.cdc_stop_position) member.
+    pub fn cdc_stop_position<T: Into<String>>(mut self, value: T) -> Self {
+        self.cdc_stop_position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`migration_type`](struct.CreateReplicationTaskMessage.html#structfield.migration_type) member.
+    pub fn migration_type<T: Into<MigrationTypeValue>>(mut self, value: T) -> Self {
+        self.migration_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_instance_arn`](struct.CreateReplicationTaskMessage.html#structfield.replication_instance_arn) member.
+    pub fn replication_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_task_identifier`](struct.CreateReplicationTaskMessage.html#structfield.replication_task_identifier) member.
+    pub fn replication_task_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_task_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_task_settings`](struct.CreateReplicationTaskMessage.html#structfield.replication_task_settings) member.
+    pub fn replication_task_settings<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_task_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_endpoint_arn`](struct.CreateReplicationTaskMessage.html#structfield.source_endpoint_arn) member.
+    pub fn source_endpoint_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_endpoint_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_mappings`](struct.CreateReplicationTaskMessage.html#structfield.table_mappings) member.
+    pub fn table_mappings<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_mappings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateReplicationTaskMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_endpoint_arn`](struct.CreateReplicationTaskMessage.html#structfield.target_endpoint_arn) member.
+    pub fn target_endpoint_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_endpoint_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateReplicationTaskMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateReplicationTaskMessage, ::rusoto_core::BuildError> {
+        Ok(CreateReplicationTaskMessage {
+            cdc_start_position: self.cdc_start_position,
+            cdc_start_time: self.cdc_start_time,
+            cdc_stop_position: self.cdc_stop_position,
+            migration_type: self
+                .migration_type
+                .ok_or(::rusoto_core::BuildError::missing("migration_type"))?,
+            replication_instance_arn: self.replication_instance_arn.ok_or(
+                ::rusoto_core::BuildError::missing("replication_instance_arn"),
+            )?,
+            replication_task_identifier: self.replication_task_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("replication_task_identifier"),
+            )?,
+            replication_task_settings: self.replication_task_settings,
+            source_endpoint_arn: self
+                .source_endpoint_arn
+                .ok_or(::rusoto_core::BuildError::missing("source_endpoint_arn"))?,
+            table_mappings: self
+                .table_mappings
+                .ok_or(::rusoto_core::BuildError::missing("table_mappings"))?,
+            tags: self.tags,
+            target_endpoint_arn: self
+                .target_endpoint_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_endpoint_arn"))?,
+        })
+    }
+}
+
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateReplicationTaskResponse {
     /// <p>The replication task that was created.</p>
@@ -462,6 +1386,78 @@ pub struct CreateReplicationTaskResponse {
     pub replication_task: Option<ReplicationTask>,
 }
 
+/// <p><p>The format of the data that you want to use for output. You can choose one of the following: </p> <ul> <li> <p> <code>csv</code> : This is a row-based file format with comma-separated values (.csv). </p> </li> <li> <p> <code>parquet</code> : Apache Parquet (.parquet) is a columnar storage file format that features efficient compression and provides faster query response. </p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DataFormatValue {
+    /// <p><code>csv</code> - This is a row-based file format with comma-separated values (.csv).</p>
+    Csv,
+    /// <p><code>parquet</code> - Apache Parquet (.parquet) is a columnar storage file format that features efficient compression and provides faster query response.</p>
+    Parquet,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DataFormatValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataFormatValue::Csv => "csv",
+            DataFormatValue::Parquet => "parquet",
+            DataFormatValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DataFormatValue {
+    fn from(value: &'a str) -> DataFormatValue {
+        match value {
+            "csv" => DataFormatValue::Csv,
+            "parquet" => DataFormatValue::Parquet,
+            _ => DataFormatValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataFormatValue {
+    fn from(value: String) -> DataFormatValue {
+        DataFormatValue::from(value.as_str())
+    }
+}
+
+impl From<DataFormatValue> for String {
+    fn from(value: DataFormatValue) -> String {
+        match value {
+            DataFormatValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DataFormatValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DataFormatValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataFormatValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DataFormatValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DataFormatValue::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteCertificateMessage {
     /// <p>The Amazon Resource Name (ARN) of the deleted certificate.</p>
@@ -469,6 +1465,38 @@ pub struct DeleteCertificateMessage {
     pub certificate_arn: String,
 }
 
+impl DeleteCertificateMessage {
+    /// Returns a builder for `DeleteCertificateMessage`, checking its required members when built.
+    pub fn builder() -> DeleteCertificateMessageBuilder {
+        DeleteCertificateMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCertificateMessage`](struct.DeleteCertificateMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteCertificateMessageBuilder {
+    certificate_arn: Option<String>,
+}
+
+impl DeleteCertificateMessageBuilder {
+    /// Sets the [`certificate_arn`](struct.DeleteCertificateMessage.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCertificateMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCertificateMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteCertificateMessage {
+            certificate_arn: self
+                .certificate_arn
+                .ok_or(::rusoto_core::BuildError::missing("certificate_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteCertificateResponse {
@@ -486,6 +1514,36 @@ pub struct DeleteEndpointMessage {
     pub endpoint_arn: String,
 }
 
+impl DeleteEndpointMessage {
+    /// Returns a builder for `DeleteEndpointMessage`, checking its required members when built.
+    pub fn builder() -> DeleteEndpointMessageBuilder {
+        DeleteEndpointMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEndpointMessage`](struct.DeleteEndpointMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteEndpointMessageBuilder {
+    endpoint_arn: Option<String>,
+}
+
+impl DeleteEndpointMessageBuilder {
+    /// Sets the [`endpoint_arn`](struct.DeleteEndpointMessage.html#structfield.endpoint_arn) member.
+    pub fn endpoint_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEndpointMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteEndpointMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteEndpointMessage {
+            endpoint_arn: self
+                .endpoint_arn
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_arn"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -504,6 +1562,38 @@ pub struct DeleteEventSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl DeleteEventSubscriptionMessage {
+    /// Returns a builder for `DeleteEventSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> DeleteEventSubscriptionMessageBuilder {
+        DeleteEventSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEventSubscriptionMessage`](struct.DeleteEventSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteEventSubscriptionMessageBuilder {
+    subscription_name: Option<String>,
+}
+
+impl DeleteEventSubscriptionMessageBuilder {
+    /// Sets the [`subscription_name`](struct.DeleteEventSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEventSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteEventSubscriptionMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteEventSubscriptionMessage {
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -522,6 +1612,38 @@ pub struct DeleteReplicationInstanceMessage {
     pub replication_instance_arn: String,
 }
 
+impl DeleteReplicationInstanceMessage {
+    /// Returns a builder for `DeleteReplicationInstanceMessage`, checking its required members when built.
+    pub fn builder() -> DeleteReplicationInstanceMessageBuilder {
+        DeleteReplicationInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteReplicationInstanceMessage`](struct.DeleteReplicationInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteReplicationInstanceMessageBuilder {
+    replication_instance_arn: Option<String>,
+}
+
+impl DeleteReplicationInstanceMessageBuilder {
+    /// Sets the [`replication_instance_arn`](struct.DeleteReplicationInstanceMessage.html#structfield.replication_instance_arn) member.
+    pub fn replication_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteReplicationInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteReplicationInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteReplicationInstanceMessage {
+            replication_instance_arn: self.replication_instance_arn.ok_or(
+                ::rusoto_core::BuildError::missing("replication_instance_arn"),
+            )?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -540,6 +1662,38 @@ pub struct DeleteReplicationSubnetGroupMessage {
     pub replication_subnet_group_identifier: String,
 }
 
+impl DeleteReplicationSubnetGroupMessage {
+    /// Returns a builder for `DeleteReplicationSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteReplicationSubnetGroupMessageBuilder {
+        DeleteReplicationSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteReplicationSubnetGroupMessage`](struct.DeleteReplicationSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteReplicationSubnetGroupMessageBuilder {
+    replication_subnet_group_identifier: Option<String>,
+}
+
+impl DeleteReplicationSubnetGroupMessageBuilder {
+    /// Sets the [`replication_subnet_group_identifier`](struct.DeleteReplicationSubnetGroupMessage.html#structfield.replication_subnet_group_identifier) member.
+    pub fn replication_subnet_group_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_subnet_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteReplicationSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteReplicationSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteReplicationSubnetGroupMessage {
+            replication_subnet_group_identifier: self.replication_subnet_group_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("replication_subnet_group_identifier"),
+            )?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -553,6 +1707,38 @@ pub struct DeleteReplicationTaskMessage {
     pub replication_task_arn: String,
 }
 
+impl DeleteReplicationTaskMessage {
+    /// Returns a builder for `DeleteReplicationTaskMessage`, checking its required members when built.
+    pub fn builder() -> DeleteReplicationTaskMessageBuilder {
+        DeleteReplicationTaskMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteReplicationTaskMessage`](struct.DeleteReplicationTaskMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteReplicationTaskMessageBuilder {
+    replication_task_arn: Option<String>,
+}
+
+impl DeleteReplicationTaskMessageBuilder {
+    /// Sets the [`replication_task_arn`](struct.DeleteReplicationTaskMessage.html#structfield.replication_task_arn) member.
+    pub fn replication_task_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_task_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteReplicationTaskMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteReplicationTaskMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteReplicationTaskMessage {
+            replication_task_arn: self
+                .replication_task_arn
+                .ok_or(::rusoto_core::BuildError::missing("replication_task_arn"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -597,6 +1783,52 @@ pub struct DescribeCertificatesMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeCertificatesMessage {
+    /// Returns a builder for `DescribeCertificatesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeCertificatesMessageBuilder {
+        DescribeCertificatesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCertificatesMessage`](struct.DescribeCertificatesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeCertificatesMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeCertificatesMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeCertificatesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeCertificatesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeCertificatesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCertificatesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCertificatesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeCertificatesMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeCertificatesResponse {
@@ -627,6 +1859,52 @@ pub struct DescribeConnectionsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeConnectionsMessage {
+    /// Returns a builder for `DescribeConnectionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeConnectionsMessageBuilder {
+        DescribeConnectionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConnectionsMessage`](struct.DescribeConnectionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeConnectionsMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeConnectionsMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeConnectionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeConnectionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeConnectionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConnectionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConnectionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeConnectionsMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -658,6 +1936,52 @@ pub struct DescribeEndpointTypesMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeEndpointTypesMessage {
+    /// Returns a builder for `DescribeEndpointTypesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEndpointTypesMessageBuilder {
+        DescribeEndpointTypesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEndpointTypesMessage`](struct.DescribeEndpointTypesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEndpointTypesMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeEndpointTypesMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeEndpointTypesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEndpointTypesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEndpointTypesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEndpointTypesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEndpointTypesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEndpointTypesMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -689,6 +2013,52 @@ pub struct DescribeEndpointsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeEndpointsMessage {
+    /// Returns a builder for `DescribeEndpointsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEndpointsMessageBuilder {
+        DescribeEndpointsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEndpointsMessage`](struct.DescribeEndpointsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEndpointsMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeEndpointsMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeEndpointsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEndpointsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEndpointsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEndpointsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEndpointsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEndpointsMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -716,6 +2086,44 @@ pub struct DescribeEventCategoriesMessage {
     pub source_type: Option<String>,
 }
 
+impl DescribeEventCategoriesMessage {
+    /// Returns a builder for `DescribeEventCategoriesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventCategoriesMessageBuilder {
+        DescribeEventCategoriesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventCategoriesMessage`](struct.DescribeEventCategoriesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventCategoriesMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    source_type: Option<String>,
+}
+
+impl DescribeEventCategoriesMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeEventCategoriesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.DescribeEventCategoriesMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventCategoriesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventCategoriesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventCategoriesMessage {
+            filters: self.filters,
+            source_type: self.source_type,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -747,6 +2155,60 @@ pub struct DescribeEventSubscriptionsMessage {
     pub subscription_name: Option<String>,
 }
 
+impl DescribeEventSubscriptionsMessage {
+    /// Returns a builder for `DescribeEventSubscriptionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventSubscriptionsMessageBuilder {
+        DescribeEventSubscriptionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventSubscriptionsMessage`](struct.DescribeEventSubscriptionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventSubscriptionsMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    subscription_name: Option<String>,
+}
+
+impl DescribeEventSubscriptionsMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeEventSubscriptionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEventSubscriptionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEventSubscriptionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.DescribeEventSubscriptionsMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventSubscriptionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventSubscriptionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventSubscriptionsMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            subscription_name: self.subscription_name,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -770,8 +2232,9 @@ pub struct DescribeEventsMessage {
     pub duration: Option<i64>,
     /// <p>The end time for the events to be listed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>A list of event categories for the source type that you've chosen.</p>
     #[serde(rename = "EventCategories")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -795,11 +2258,104 @@ pub struct DescribeEventsMessage {
     /// <p>The type of AWS DMS resource that generates events.</p> <p>Valid values: replication-instance | replication-task</p>
     #[serde(rename = "SourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p>The start time for the events to be listed.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
+}
+
+impl DescribeEventsMessage {
+    /// Returns a builder for `DescribeEventsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventsMessageBuilder {
+        DescribeEventsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventsMessage`](struct.DescribeEventsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventsMessageBuilder {
+    duration: Option<i64>,
+    end_time: Option<EpochTimestamp>,
+    event_categories: Option<Vec<String>>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    source_identifier: Option<String>,
+    source_type: Option<SourceType>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl DescribeEventsMessageBuilder {
+    /// Sets the [`duration`](struct.DescribeEventsMessage.html#structfield.duration) member.
+    pub fn duration<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.DescribeEventsMessage.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_categories`](struct.DescribeEventsMessage.html#structfield.event_categories) member.
+    pub fn event_categories<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_categories = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeEventsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEventsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEventsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_identifier`](struct.DescribeEventsMessage.html#structfield.source_identifier) member.
+    pub fn source_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.DescribeEventsMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<SourceType>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.DescribeEventsMessage.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventsMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeEventsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventsMessage {
+            duration: self.duration,
+            end_time: self.end_time,
+            event_categories: self.event_categories,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            source_identifier: self.source_identifier,
+            source_type: self.source_type,
+            start_time: self.start_time,
+        })
+    }
 }
 
 /// <p><p/></p>
@@ -829,6 +2385,47 @@ pub struct DescribeOrderableReplicationInstancesMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeOrderableReplicationInstancesMessage {
+    /// Returns a builder for `DescribeOrderableReplicationInstancesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeOrderableReplicationInstancesMessageBuilder {
+        DescribeOrderableReplicationInstancesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeOrderableReplicationInstancesMessage`](struct.DescribeOrderableReplicationInstancesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeOrderableReplicationInstancesMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeOrderableReplicationInstancesMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeOrderableReplicationInstancesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeOrderableReplicationInstancesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeOrderableReplicationInstancesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeOrderableReplicationInstancesMessage,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeOrderableReplicationInstancesMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -864,28 +2461,115 @@ pub struct DescribePendingMaintenanceActionsMessage {
     pub replication_instance_arn: Option<String>,
 }
 
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DescribePendingMaintenanceActionsResponse {
-    /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>. </p>
-    #[serde(rename = "Marker")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub marker: Option<String>,
-    /// <p>The pending maintenance action.</p>
-    #[serde(rename = "PendingMaintenanceActions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub pending_maintenance_actions: Option<Vec<ResourcePendingMaintenanceActions>>,
+impl DescribePendingMaintenanceActionsMessage {
+    /// Returns a builder for `DescribePendingMaintenanceActionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribePendingMaintenanceActionsMessageBuilder {
+        DescribePendingMaintenanceActionsMessageBuilder::default()
+    }
 }
 
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DescribeRefreshSchemasStatusMessage {
-    /// <p>The Amazon Resource Name (ARN) string that uniquely identifies the endpoint.</p>
+/// Builds a [`DescribePendingMaintenanceActionsMessage`](struct.DescribePendingMaintenanceActionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribePendingMaintenanceActionsMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    replication_instance_arn: Option<String>,
+}
+
+impl DescribePendingMaintenanceActionsMessageBuilder {
+    /// Sets the [`filters`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_instance_arn`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.replication_instance_arn) member.
+    pub fn replication_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePendingMaintenanceActionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePendingMaintenanceActionsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribePendingMaintenanceActionsMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            replication_instance_arn: self.replication_instance_arn,
+        })
+    }
+}
+
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribePendingMaintenanceActionsResponse {
+    /// <p> An optional pagination token provided by a previous request. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>. </p>
+    #[serde(rename = "Marker")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<String>,
+    /// <p>The pending maintenance action.</p>
+    #[serde(rename = "PendingMaintenanceActions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_maintenance_actions: Option<Vec<ResourcePendingMaintenanceActions>>,
+}
+
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DescribeRefreshSchemasStatusMessage {
+    /// <p>The Amazon Resource Name (ARN) string that uniquely identifies the endpoint.</p>
     #[serde(rename = "EndpointArn")]
     pub endpoint_arn: String,
 }
 
+impl DescribeRefreshSchemasStatusMessage {
+    /// Returns a builder for `DescribeRefreshSchemasStatusMessage`, checking its required members when built.
+    pub fn builder() -> DescribeRefreshSchemasStatusMessageBuilder {
+        DescribeRefreshSchemasStatusMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRefreshSchemasStatusMessage`](struct.DescribeRefreshSchemasStatusMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeRefreshSchemasStatusMessageBuilder {
+    endpoint_arn: Option<String>,
+}
+
+impl DescribeRefreshSchemasStatusMessageBuilder {
+    /// Sets the [`endpoint_arn`](struct.DescribeRefreshSchemasStatusMessage.html#structfield.endpoint_arn) member.
+    pub fn endpoint_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRefreshSchemasStatusMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeRefreshSchemasStatusMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeRefreshSchemasStatusMessage {
+            endpoint_arn: self
+                .endpoint_arn
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_arn"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -911,6 +2595,55 @@ pub struct DescribeReplicationInstanceTaskLogsMessage {
     pub replication_instance_arn: String,
 }
 
+impl DescribeReplicationInstanceTaskLogsMessage {
+    /// Returns a builder for `DescribeReplicationInstanceTaskLogsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeReplicationInstanceTaskLogsMessageBuilder {
+        DescribeReplicationInstanceTaskLogsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReplicationInstanceTaskLogsMessage`](struct.DescribeReplicationInstanceTaskLogsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeReplicationInstanceTaskLogsMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    replication_instance_arn: Option<String>,
+}
+
+impl DescribeReplicationInstanceTaskLogsMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeReplicationInstanceTaskLogsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeReplicationInstanceTaskLogsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_instance_arn`](struct.DescribeReplicationInstanceTaskLogsMessage.html#structfield.replication_instance_arn) member.
+    pub fn replication_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReplicationInstanceTaskLogsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReplicationInstanceTaskLogsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeReplicationInstanceTaskLogsMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            replication_instance_arn: self.replication_instance_arn.ok_or(
+                ::rusoto_core::BuildError::missing("replication_instance_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeReplicationInstanceTaskLogsResponse {
@@ -945,6 +2678,52 @@ pub struct DescribeReplicationInstancesMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeReplicationInstancesMessage {
+    /// Returns a builder for `DescribeReplicationInstancesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeReplicationInstancesMessageBuilder {
+        DescribeReplicationInstancesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReplicationInstancesMessage`](struct.DescribeReplicationInstancesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeReplicationInstancesMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeReplicationInstancesMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeReplicationInstancesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeReplicationInstancesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeReplicationInstancesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReplicationInstancesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReplicationInstancesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeReplicationInstancesMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -976,6 +2755,53 @@ pub struct DescribeReplicationSubnetGroupsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeReplicationSubnetGroupsMessage {
+    /// Returns a builder for `DescribeReplicationSubnetGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeReplicationSubnetGroupsMessageBuilder {
+        DescribeReplicationSubnetGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReplicationSubnetGroupsMessage`](struct.DescribeReplicationSubnetGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeReplicationSubnetGroupsMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeReplicationSubnetGroupsMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeReplicationSubnetGroupsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeReplicationSubnetGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeReplicationSubnetGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReplicationSubnetGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReplicationSubnetGroupsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeReplicationSubnetGroupsMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1007,6 +2833,55 @@ pub struct DescribeReplicationTaskAssessmentResultsMessage {
     pub replication_task_arn: Option<String>,
 }
 
+impl DescribeReplicationTaskAssessmentResultsMessage {
+    /// Returns a builder for `DescribeReplicationTaskAssessmentResultsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeReplicationTaskAssessmentResultsMessageBuilder {
+        DescribeReplicationTaskAssessmentResultsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReplicationTaskAssessmentResultsMessage`](struct.DescribeReplicationTaskAssessmentResultsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeReplicationTaskAssessmentResultsMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    replication_task_arn: Option<String>,
+}
+
+impl DescribeReplicationTaskAssessmentResultsMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeReplicationTaskAssessmentResultsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeReplicationTaskAssessmentResultsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_task_arn`](struct.DescribeReplicationTaskAssessmentResultsMessage.html#structfield.replication_task_arn) member.
+    pub fn replication_task_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_task_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReplicationTaskAssessmentResultsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeReplicationTaskAssessmentResultsMessage,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeReplicationTaskAssessmentResultsMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            replication_task_arn: self.replication_task_arn,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1046,6 +2921,60 @@ pub struct DescribeReplicationTasksMessage {
     pub without_settings: Option<bool>,
 }
 
+impl DescribeReplicationTasksMessage {
+    /// Returns a builder for `DescribeReplicationTasksMessage`, checking its required members when built.
+    pub fn builder() -> DescribeReplicationTasksMessageBuilder {
+        DescribeReplicationTasksMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReplicationTasksMessage`](struct.DescribeReplicationTasksMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeReplicationTasksMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    without_settings: Option<bool>,
+}
+
+impl DescribeReplicationTasksMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeReplicationTasksMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeReplicationTasksMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeReplicationTasksMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`without_settings`](struct.DescribeReplicationTasksMessage.html#structfield.without_settings) member.
+    pub fn without_settings<T: Into<bool>>(mut self, value: T) -> Self {
+        self.without_settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReplicationTasksMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReplicationTasksMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeReplicationTasksMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            without_settings: self.without_settings,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1076,6 +3005,52 @@ pub struct DescribeSchemasMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeSchemasMessage {
+    /// Returns a builder for `DescribeSchemasMessage`, checking its required members when built.
+    pub fn builder() -> DescribeSchemasMessageBuilder {
+        DescribeSchemasMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSchemasMessage`](struct.DescribeSchemasMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeSchemasMessageBuilder {
+    endpoint_arn: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeSchemasMessageBuilder {
+    /// Sets the [`endpoint_arn`](struct.DescribeSchemasMessage.html#structfield.endpoint_arn) member.
+    pub fn endpoint_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeSchemasMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeSchemasMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSchemasMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeSchemasMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeSchemasMessage {
+            endpoint_arn: self
+                .endpoint_arn
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_arn"))?,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1110,6 +3085,62 @@ pub struct DescribeTableStatisticsMessage {
     pub replication_task_arn: String,
 }
 
+impl DescribeTableStatisticsMessage {
+    /// Returns a builder for `DescribeTableStatisticsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeTableStatisticsMessageBuilder {
+        DescribeTableStatisticsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTableStatisticsMessage`](struct.DescribeTableStatisticsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeTableStatisticsMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    replication_task_arn: Option<String>,
+}
+
+impl DescribeTableStatisticsMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeTableStatisticsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeTableStatisticsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeTableStatisticsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_task_arn`](struct.DescribeTableStatisticsMessage.html#structfield.replication_task_arn) member.
+    pub fn replication_task_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_task_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTableStatisticsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTableStatisticsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeTableStatisticsMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            replication_task_arn: self
+                .replication_task_arn
+                .ok_or(::rusoto_core::BuildError::missing("replication_task_arn"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1128,6 +3159,86 @@ pub struct DescribeTableStatisticsResponse {
     pub table_statistics: Option<Vec<TableStatistics>>,
 }
 
+/// <p>The Secure Sockets Layer (SSL) mode to use for the SSL connection. The default is <code>none</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DmsSslModeValue {
+    /// <p><code>none</code></p>
+    None,
+    /// <p><code>require</code></p>
+    Require,
+    /// <p><code>verify-ca</code></p>
+    VerifyCa,
+    /// <p><code>verify-full</code></p>
+    VerifyFull,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DmsSslModeValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DmsSslModeValue::None => "none",
+            DmsSslModeValue::Require => "require",
+            DmsSslModeValue::VerifyCa => "verify-ca",
+            DmsSslModeValue::VerifyFull => "verify-full",
+            DmsSslModeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DmsSslModeValue {
+    fn from(value: &'a str) -> DmsSslModeValue {
+        match value {
+            "none" => DmsSslModeValue::None,
+            "require" => DmsSslModeValue::Require,
+            "verify-ca" => DmsSslModeValue::VerifyCa,
+            "verify-full" => DmsSslModeValue::VerifyFull,
+            _ => DmsSslModeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DmsSslModeValue {
+    fn from(value: String) -> DmsSslModeValue {
+        DmsSslModeValue::from(value.as_str())
+    }
+}
+
+impl From<DmsSslModeValue> for String {
+    fn from(value: DmsSslModeValue) -> String {
+        match value {
+            DmsSslModeValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DmsSslModeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DmsSslModeValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DmsSslModeValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DmsSslModeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DmsSslModeValue::from(value))
+    }
+}
+
 /// <p> The settings in JSON format for the DMS Transfer type source endpoint. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DmsTransferSettings {
@@ -1168,6 +3279,154 @@ pub struct ElasticsearchSettings {
     pub service_access_role_arn: String,
 }
 
+/// <p><p>The type of encoding you are using: </p> <ul> <li> <p> <code>RLE<em>DICTIONARY</code> uses a combination of bit-packing and run-length encoding to store repeated values more efficiently. This is the default.</p> </li> <li> <p> <code>PLAIN</code> doesn&#39;t use encoding at all. Values are stored as they are.</p> </li> <li> <p> <code>PLAIN</em>DICTIONARY</code> builds a dictionary of the values encountered in a given column. The dictionary is stored in a dictionary page for each column chunk.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EncodingTypeValue {
+    /// <p><code>plain</code></p>
+    Plain,
+    /// <p><code>plain-dictionary</code></p>
+    PlainDictionary,
+    /// <p><code>rle-dictionary</code></p>
+    RleDictionary,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EncodingTypeValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncodingTypeValue::Plain => "plain",
+            EncodingTypeValue::PlainDictionary => "plain-dictionary",
+            EncodingTypeValue::RleDictionary => "rle-dictionary",
+            EncodingTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EncodingTypeValue {
+    fn from(value: &'a str) -> EncodingTypeValue {
+        match value {
+            "plain" => EncodingTypeValue::Plain,
+            "plain-dictionary" => EncodingTypeValue::PlainDictionary,
+            "rle-dictionary" => EncodingTypeValue::RleDictionary,
+            _ => EncodingTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncodingTypeValue {
+    fn from(value: String) -> EncodingTypeValue {
+        EncodingTypeValue::from(value.as_str())
+    }
+}
+
+impl From<EncodingTypeValue> for String {
+    fn from(value: EncodingTypeValue) -> String {
+        match value {
+            EncodingTypeValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncodingTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EncodingTypeValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncodingTypeValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EncodingTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EncodingTypeValue::from(value))
+    }
+}
+
+/// <p>The type of server-side encryption that you want to use for your data. This encryption type is part of the endpoint settings or the extra connections attributes for Amazon S3. You can choose either <code>SSE_S3</code> (the default) or <code>SSE_KMS</code>. To use <code>SSE_S3</code>, create an AWS Identity and Access Management (IAM) role with a policy that allows <code>"arn:aws:s3:::*"</code> to use the following actions: <code>"s3:PutObject", "s3:ListBucket"</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EncryptionModeValue {
+    /// <p><code>sse-s3</code></p>
+    SseS3,
+    /// <p><code>sse-kms</code></p>
+    SseKms,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EncryptionModeValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionModeValue::SseS3 => "sse-s3",
+            EncryptionModeValue::SseKms => "sse-kms",
+            EncryptionModeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionModeValue {
+    fn from(value: &'a str) -> EncryptionModeValue {
+        match value {
+            "sse-s3" => EncryptionModeValue::SseS3,
+            "sse-kms" => EncryptionModeValue::SseKms,
+            _ => EncryptionModeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionModeValue {
+    fn from(value: String) -> EncryptionModeValue {
+        EncryptionModeValue::from(value.as_str())
+    }
+}
+
+impl From<EncryptionModeValue> for String {
+    fn from(value: EncryptionModeValue) -> String {
+        match value {
+            EncryptionModeValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncryptionModeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EncryptionModeValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionModeValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EncryptionModeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EncryptionModeValue::from(value))
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1203,7 +3462,7 @@ pub struct Endpoint {
     /// <p>The type of endpoint. Valid values are <code>source</code> and <code>target</code>.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<ReplicationEndpointTypeValue>,
     /// <p>The expanded name for the engine name. For example, if the <code>EngineName</code> parameter is "aurora," this value would be "Amazon Aurora MySQL."</p>
     #[serde(rename = "EngineDisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1259,7 +3518,7 @@ pub struct Endpoint {
     /// <p>The SSL mode used to connect to the endpoint. The default value is <code>none</code>.</p>
     #[serde(rename = "SslMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ssl_mode: Option<String>,
+    pub ssl_mode: Option<DmsSslModeValue>,
     /// <p>The status of the endpoint.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1276,8 +3535,9 @@ pub struct Endpoint {
 pub struct Event {
     /// <p>The date of the event.</p>
     #[serde(rename = "Date")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date: Option<f64>,
+    pub date: Option<EpochTimestamp>,
     /// <p>The event categories available for the specified source type.</p>
     #[serde(rename = "EventCategories")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1293,7 +3553,7 @@ pub struct Event {
     /// <p> The type of AWS DMS resource that generates events. </p> <p>Valid values: replication-instance | endpoint | replication-task</p>
     #[serde(rename = "SourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
 /// <p><p/></p>
@@ -1387,11 +3647,67 @@ pub struct ImportCertificateMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ImportCertificateResponse {
-    /// <p>The certificate to be uploaded.</p>
-    #[serde(rename = "Certificate")]
+impl ImportCertificateMessage {
+    /// Returns a builder for `ImportCertificateMessage`, checking its required members when built.
+    pub fn builder() -> ImportCertificateMessageBuilder {
+        ImportCertificateMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ImportCertificateMessage`](struct.ImportCertificateMessage.html).
+#[derive(Default, Debug)]
+pub struct ImportCertificateMessageBuilder {
+    certificate_identifier: Option<String>,
+    certificate_pem: Option<String>,
+    certificate_wallet: Option<bytes::Bytes>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl ImportCertificateMessageBuilder {
+    /// Sets the [`certificate_identifier`](struct.ImportCertificateMessage.html#structfield.certificate_identifier) member.
+    pub fn certificate_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_pem`](struct.ImportCertificateMessage.html#structfield.certificate_pem) member.
+    pub fn certificate_pem<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_pem = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_wallet`](struct.ImportCertificateMessage.html#structfield.certificate_wallet) member.
+    pub fn certificate_wallet<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.certificate_wallet = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.ImportCertificateMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportCertificateMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ImportCertificateMessage, ::rusoto_core::BuildError> {
+        Ok(ImportCertificateMessage {
+            certificate_identifier: self
+                .certificate_identifier
+                .ok_or(::rusoto_core::BuildError::missing("certificate_identifier"))?,
+            certificate_pem: self.certificate_pem,
+            certificate_wallet: self.certificate_wallet,
+            tags: self.tags,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ImportCertificateResponse {
+    /// <p>The certificate to be uploaded.</p>
+    #[serde(rename = "Certificate")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub certificate: Option<Certificate>,
 }
@@ -1402,7 +3718,7 @@ pub struct KinesisSettings {
     /// <p>The output format for the records created on the endpoint. The message format is <code>JSON</code>.</p>
     #[serde(rename = "MessageFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_format: Option<String>,
+    pub message_format: Option<MessageFormatValue>,
     /// <p>The Amazon Resource Name (ARN) for the IAM role that DMS uses to write to the Amazon Kinesis data stream.</p>
     #[serde(rename = "ServiceAccessRoleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1421,6 +3737,38 @@ pub struct ListTagsForResourceMessage {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceMessage {
+    /// Returns a builder for `ListTagsForResourceMessage`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceMessageBuilder {
+        ListTagsForResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceMessage`](struct.ListTagsForResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceMessageBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceMessageBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceMessage.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceMessage, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceMessage {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1431,6 +3779,154 @@ pub struct ListTagsForResourceResponse {
     pub tag_list: Option<Vec<Tag>>,
 }
 
+/// <p>The output format for the records created on the endpoint. The message format is <code>JSON</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MessageFormatValue {
+    /// <p><code>json</code></p>
+    Json,
+    /// <p><code>json-unformatted</code></p>
+    JsonUnformatted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MessageFormatValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageFormatValue::Json => "json",
+            MessageFormatValue::JsonUnformatted => "json-unformatted",
+            MessageFormatValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MessageFormatValue {
+    fn from(value: &'a str) -> MessageFormatValue {
+        match value {
+            "json" => MessageFormatValue::Json,
+            "json-unformatted" => MessageFormatValue::JsonUnformatted,
+            _ => MessageFormatValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageFormatValue {
+    fn from(value: String) -> MessageFormatValue {
+        MessageFormatValue::from(value.as_str())
+    }
+}
+
+impl From<MessageFormatValue> for String {
+    fn from(value: MessageFormatValue) -> String {
+        match value {
+            MessageFormatValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MessageFormatValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MessageFormatValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MessageFormatValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MessageFormatValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MessageFormatValue::from(value))
+    }
+}
+
+/// <p>The migration type. Valid values: <code>full-load</code> | <code>cdc</code> | <code>full-load-and-cdc</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MigrationTypeValue {
+    /// <p><code>full-load</code></p>
+    FullLoad,
+    /// <p><code>cdc</code></p>
+    Cdc,
+    /// <p><code>full-load-and-cdc</code></p>
+    FullLoadAndCdc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MigrationTypeValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MigrationTypeValue::FullLoad => "full-load",
+            MigrationTypeValue::Cdc => "cdc",
+            MigrationTypeValue::FullLoadAndCdc => "full-load-and-cdc",
+            MigrationTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MigrationTypeValue {
+    fn from(value: &'a str) -> MigrationTypeValue {
+        match value {
+            "full-load" => MigrationTypeValue::FullLoad,
+            "cdc" => MigrationTypeValue::Cdc,
+            "full-load-and-cdc" => MigrationTypeValue::FullLoadAndCdc,
+            _ => MigrationTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MigrationTypeValue {
+    fn from(value: String) -> MigrationTypeValue {
+        MigrationTypeValue::from(value.as_str())
+    }
+}
+
+impl From<MigrationTypeValue> for String {
+    fn from(value: MigrationTypeValue) -> String {
+        match value {
+            MigrationTypeValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MigrationTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MigrationTypeValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MigrationTypeValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MigrationTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MigrationTypeValue::from(value))
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ModifyEndpointMessage {
@@ -1464,7 +3960,7 @@ pub struct ModifyEndpointMessage {
     /// <p>The type of endpoint. Valid values are <code>source</code> and <code>target</code>.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<ReplicationEndpointTypeValue>,
     /// <p>The type of engine for the endpoint. Valid values, depending on the EndpointType, include mysql, oracle, postgres, mariadb, aurora, aurora-postgresql, redshift, s3, db2, azuredb, sybase, dynamodb, mongodb, and sqlserver.</p>
     #[serde(rename = "EngineName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1511,13 +4007,203 @@ pub struct ModifyEndpointMessage {
     /// <p>The SSL mode used to connect to the endpoint. The default value is <code>none</code>.</p>
     #[serde(rename = "SslMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ssl_mode: Option<String>,
+    pub ssl_mode: Option<DmsSslModeValue>,
     /// <p>The user name to be used to login to the endpoint database.</p>
     #[serde(rename = "Username")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
 
+impl ModifyEndpointMessage {
+    /// Returns a builder for `ModifyEndpointMessage`, checking its required members when built.
+    pub fn builder() -> ModifyEndpointMessageBuilder {
+        ModifyEndpointMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyEndpointMessage`](struct.ModifyEndpointMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyEndpointMessageBuilder {
+    certificate_arn: Option<String>,
+    database_name: Option<String>,
+    dms_transfer_settings: Option<DmsTransferSettings>,
+    dynamo_db_settings: Option<DynamoDbSettings>,
+    elasticsearch_settings: Option<ElasticsearchSettings>,
+    endpoint_arn: Option<String>,
+    endpoint_identifier: Option<String>,
+    endpoint_type: Option<ReplicationEndpointTypeValue>,
+    engine_name: Option<String>,
+    external_table_definition: Option<String>,
+    extra_connection_attributes: Option<String>,
+    kinesis_settings: Option<KinesisSettings>,
+    mongo_db_settings: Option<MongoDbSettings>,
+    password: Option<String>,
+    port: Option<i64>,
+    redshift_settings: Option<RedshiftSettings>,
+    s3_settings: Option<S3Settings>,
+    server_name: Option<String>,
+    service_access_role_arn: Option<String>,
+    ssl_mode: Option<DmsSslModeValue>,
+    username: Option<String>,
+}
+
+impl ModifyEndpointMessageBuilder {
+    /// Sets the [`certificate_arn`](struct.ModifyEndpointMessage.html#structfield.certificate_arn) member.
+    pub fn certificate_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.ModifyEndpointMessage.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dms_transfer_settings`](struct.ModifyEndpointMessage.html#structfield.dms_transfer_settings) member.
+    pub fn dms_transfer_settings<T: Into<DmsTransferSettings>>(mut self, value: T) -> Self {
+        self.dms_transfer_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dynamo_db_settings`](struct.ModifyEndpointMessage.html#structfield.dynamo_db_settings) member.
+    pub fn dynamo_db_settings<T: Into<DynamoDbSettings>>(mut self, value: T) -> Self {
+        self.dynamo_db_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`elasticsearch_settings`](struct.ModifyEndpointMessage.html#structfield.elasticsearch_settings) member.
+    pub fn elasticsearch_settings<T: Into<ElasticsearchSettings>>(mut self, value: T) -> Self {
+        self.elasticsearch_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_arn`](struct.ModifyEndpointMessage.html#structfield.endpoint_arn) member.
+    pub fn endpoint_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_identifier`](struct.ModifyEndpointMessage.html#structfield.endpoint_identifier) member.
+    pub fn endpoint_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_type`](struct.ModifyEndpointMessage.html#structfield.endpoint_type) member.
+    pub fn endpoint_type<T: Into<ReplicationEndpointTypeValue>>(mut self, value: T) -> Self {
+        self.endpoint_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_name`](struct.ModifyEndpointMessage.html#structfield.engine_name) member.
+    pub fn engine_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`external_table_definition`](struct.ModifyEndpointMessage.html#structfield.external_table_definition) member.
+    pub fn external_table_definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.external_table_definition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`extra_connection_attributes`](struct.ModifyEndpointMessage.html#structfield.extra_connection_attributes) member.
+    pub fn extra_connection_attributes<T: Into<String>>(mut self, value: T) -> Self {
+        self.extra_connection_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kinesis_settings`](struct.ModifyEndpointMessage.html#structfield.kinesis_settings) member.
+    pub fn kinesis_settings<T: Into<KinesisSettings>>(mut self, value: T) -> Self {
+        self.kinesis_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mongo_db_settings`](struct.ModifyEndpointMessage.html#structfield.mongo_db_settings) member.
+    pub fn mongo_db_settings<T: Into<MongoDbSettings>>(mut self, value: T) -> Self {
+        self.mongo_db_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.ModifyEndpointMessage.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.ModifyEndpointMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`redshift_settings`](struct.ModifyEndpointMessage.html#structfield.redshift_settings) member.
+    pub fn redshift_settings<T: Into<RedshiftSettings>>(mut self, value: T) -> Self {
+        self.redshift_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_settings`](struct.ModifyEndpointMessage.html#structfield.s3_settings) member.
+    pub fn s3_settings<T: Into<S3Settings>>(mut self, value: T) -> Self {
+        self.s3_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.ModifyEndpointMessage.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_access_role_arn`](struct.ModifyEndpointMessage.html#structfield.service_access_role_arn) member.
+    pub fn service_access_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_access_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssl_mode`](struct.ModifyEndpointMessage.html#structfield.ssl_mode) member.
+    pub fn ssl_mode<T: Into<DmsSslModeValue>>(mut self, value: T) -> Self {
+        self.ssl_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.ModifyEndpointMessage.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyEndpointMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ModifyEndpointMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyEndpointMessage {
+            certificate_arn: self.certificate_arn,
+            database_name: self.database_name,
+            dms_transfer_settings: self.dms_transfer_settings,
+            dynamo_db_settings: self.dynamo_db_settings,
+            elasticsearch_settings: self.elasticsearch_settings,
+            endpoint_arn: self
+                .endpoint_arn
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_arn"))?,
+            endpoint_identifier: self.endpoint_identifier,
+            endpoint_type: self.endpoint_type,
+            engine_name: self.engine_name,
+            external_table_definition: self.external_table_definition,
+            extra_connection_attributes: self.extra_connection_attributes,
+            kinesis_settings: self.kinesis_settings,
+            mongo_db_settings: self.mongo_db_settings,
+            password: self.password,
+            port: self.port,
+            redshift_settings: self.redshift_settings,
+            s3_settings: self.s3_settings,
+            server_name: self.server_name,
+            service_access_role_arn: self.service_access_role_arn,
+            ssl_mode: self.ssl_mode,
+            username: self.username,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1552,6 +4238,70 @@ pub struct ModifyEventSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl ModifyEventSubscriptionMessage {
+    /// Returns a builder for `ModifyEventSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> ModifyEventSubscriptionMessageBuilder {
+        ModifyEventSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyEventSubscriptionMessage`](struct.ModifyEventSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyEventSubscriptionMessageBuilder {
+    enabled: Option<bool>,
+    event_categories: Option<Vec<String>>,
+    sns_topic_arn: Option<String>,
+    source_type: Option<String>,
+    subscription_name: Option<String>,
+}
+
+impl ModifyEventSubscriptionMessageBuilder {
+    /// Sets the [`enabled`](struct.ModifyEventSubscriptionMessage.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_categories`](struct.ModifyEventSubscriptionMessage.html#structfield.event_categories) member.
+    pub fn event_categories<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_categories = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.ModifyEventSubscriptionMessage.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.ModifyEventSubscriptionMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.ModifyEventSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyEventSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyEventSubscriptionMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyEventSubscriptionMessage {
+            enabled: self.enabled,
+            event_categories: self.event_categories,
+            sns_topic_arn: self.sns_topic_arn,
+            source_type: self.source_type,
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1610,6 +4360,118 @@ pub struct ModifyReplicationInstanceMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl ModifyReplicationInstanceMessage {
+    /// Returns a builder for `ModifyReplicationInstanceMessage`, checking its required members when built.
+    pub fn builder() -> ModifyReplicationInstanceMessageBuilder {
+        ModifyReplicationInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyReplicationInstanceMessage`](struct.ModifyReplicationInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyReplicationInstanceMessageBuilder {
+    allocated_storage: Option<i64>,
+    allow_major_version_upgrade: Option<bool>,
+    apply_immediately: Option<bool>,
+    auto_minor_version_upgrade: Option<bool>,
+    engine_version: Option<String>,
+    multi_az: Option<bool>,
+    preferred_maintenance_window: Option<String>,
+    replication_instance_arn: Option<String>,
+    replication_instance_class: Option<String>,
+    replication_instance_identifier: Option<String>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl ModifyReplicationInstanceMessageBuilder {
+    /// Sets the [`allocated_storage`](struct.ModifyReplicationInstanceMessage.html#structfield.allocated_storage) member.
+    pub fn allocated_storage<T: Into<i64>>(mut self, value: T) -> Self {
+        self.allocated_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`allow_major_version_upgrade`](struct.ModifyReplicationInstanceMessage.html#structfield.allow_major_version_upgrade) member.
+    pub fn allow_major_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_major_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`apply_immediately`](struct.ModifyReplicationInstanceMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_minor_version_upgrade`](struct.ModifyReplicationInstanceMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.ModifyReplicationInstanceMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.ModifyReplicationInstanceMessage.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.ModifyReplicationInstanceMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_instance_arn`](struct.ModifyReplicationInstanceMessage.html#structfield.replication_instance_arn) member.
+    pub fn replication_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_instance_class`](struct.ModifyReplicationInstanceMessage.html#structfield.replication_instance_class) member.
+    pub fn replication_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_instance_identifier`](struct.ModifyReplicationInstanceMessage.html#structfield.replication_instance_identifier) member.
+    pub fn replication_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.ModifyReplicationInstanceMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyReplicationInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyReplicationInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyReplicationInstanceMessage {
+            allocated_storage: self.allocated_storage,
+            allow_major_version_upgrade: self.allow_major_version_upgrade,
+            apply_immediately: self.apply_immediately,
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            engine_version: self.engine_version,
+            multi_az: self.multi_az,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            replication_instance_arn: self.replication_instance_arn.ok_or(
+                ::rusoto_core::BuildError::missing("replication_instance_arn"),
+            )?,
+            replication_instance_class: self.replication_instance_class,
+            replication_instance_identifier: self.replication_instance_identifier,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1635,6 +4497,56 @@ pub struct ModifyReplicationSubnetGroupMessage {
     pub subnet_ids: Vec<String>,
 }
 
+impl ModifyReplicationSubnetGroupMessage {
+    /// Returns a builder for `ModifyReplicationSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyReplicationSubnetGroupMessageBuilder {
+        ModifyReplicationSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyReplicationSubnetGroupMessage`](struct.ModifyReplicationSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyReplicationSubnetGroupMessageBuilder {
+    replication_subnet_group_description: Option<String>,
+    replication_subnet_group_identifier: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+}
+
+impl ModifyReplicationSubnetGroupMessageBuilder {
+    /// Sets the [`replication_subnet_group_description`](struct.ModifyReplicationSubnetGroupMessage.html#structfield.replication_subnet_group_description) member.
+    pub fn replication_subnet_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_subnet_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_subnet_group_identifier`](struct.ModifyReplicationSubnetGroupMessage.html#structfield.replication_subnet_group_identifier) member.
+    pub fn replication_subnet_group_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_subnet_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.ModifyReplicationSubnetGroupMessage.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyReplicationSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyReplicationSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyReplicationSubnetGroupMessage {
+            replication_subnet_group_description: self.replication_subnet_group_description,
+            replication_subnet_group_identifier: self.replication_subnet_group_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("replication_subnet_group_identifier"),
+            )?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1654,8 +4566,9 @@ pub struct ModifyReplicationTaskMessage {
     pub cdc_start_position: Option<String>,
     /// <p>Indicates the start time for a change data capture (CDC) operation. Use either CdcStartTime or CdcStartPosition to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p>Timestamp Example: --cdc-start-time “2018-03-08T12:12:12”</p>
     #[serde(rename = "CdcStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cdc_start_time: Option<f64>,
+    pub cdc_start_time: Option<EpochTimestamp>,
     /// <p>Indicates when you want a change data capture (CDC) operation to stop. The value can be either server time or commit time.</p> <p>Server time example: --cdc-stop-position “server_time:3018-02-09T12:12:12”</p> <p>Commit time example: --cdc-stop-position “commit_time: 3018-02-09T12:12:12 “</p>
     #[serde(rename = "CdcStopPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1663,7 +4576,7 @@ pub struct ModifyReplicationTaskMessage {
     /// <p>The migration type. Valid values: <code>full-load</code> | <code>cdc</code> | <code>full-load-and-cdc</code> </p>
     #[serde(rename = "MigrationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub migration_type: Option<String>,
+    pub migration_type: Option<MigrationTypeValue>,
     /// <p>The Amazon Resource Name (ARN) of the replication task.</p>
     #[serde(rename = "ReplicationTaskArn")]
     pub replication_task_arn: String,
@@ -1681,6 +4594,94 @@ pub struct ModifyReplicationTaskMessage {
     pub table_mappings: Option<String>,
 }
 
+impl ModifyReplicationTaskMessage {
+    /// Returns a builder for `ModifyReplicationTaskMessage`, checking its required members when built.
+    pub fn builder() -> ModifyReplicationTaskMessageBuilder {
+        ModifyReplicationTaskMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyReplicationTaskMessage`](struct.ModifyReplicationTaskMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyReplicationTaskMessageBuilder {
+    cdc_start_position: Option<String>,
+    cdc_start_time: Option<EpochTimestamp>,
+    cdc_stop_position: Option<String>,
+    migration_type: Option<MigrationTypeValue>,
+    replication_task_arn: Option<String>,
+    replication_task_identifier: Option<String>,
+    replication_task_settings: Option<String>,
+    table_mappings: Option<String>,
+}
+
+impl ModifyReplicationTaskMessageBuilder {
+    /// Sets the [`cdc_start_position`](struct.ModifyReplicationTaskMessage.html#structfield.cdc_start_position) member.
+    pub fn cdc_start_position<T: Into<String>>(mut self, value: T) -> Self {
+        self.cdc_start_position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cdc_start_time`](struct.ModifyReplicationTaskMessage.html#structfield.cdc_start_time) member.
+    pub fn cdc_start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.cdc_start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cdc_stop_position`](struct.ModifyReplicationTaskMessage.html#structfield.cdc_stop_position) member.
+    pub fn cdc_stop_position<T: Into<String>>(mut self, value: T) -> Self {
+        self.cdc_stop_position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`migration_type`](struct.ModifyReplicationTaskMessage.html#structfield.migration_type) member.
+    pub fn migration_type<T: Into<MigrationTypeValue>>(mut self, value: T) -> Self {
+        self.migration_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_task_arn`](struct.ModifyReplicationTaskMessage.html#structfield.replication_task_arn) member.
+    pub fn replication_task_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_task_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_task_identifier`](struct.ModifyReplicationTaskMessage.html#structfield.replication_task_identifier) member.
+    pub fn replication_task_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_task_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_task_settings`](struct.ModifyReplicationTaskMessage.html#structfield.replication_task_settings) member.
+    pub fn replication_task_settings<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_task_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_mappings`](struct.ModifyReplicationTaskMessage.html#structfield.table_mappings) member.
+    pub fn table_mappings<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_mappings = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyReplicationTaskMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyReplicationTaskMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyReplicationTaskMessage {
+            cdc_start_position: self.cdc_start_position,
+            cdc_start_time: self.cdc_start_time,
+            cdc_stop_position: self.cdc_stop_position,
+            migration_type: self.migration_type,
+            replication_task_arn: self
+                .replication_task_arn
+                .ok_or(::rusoto_core::BuildError::missing("replication_task_arn"))?,
+            replication_task_identifier: self.replication_task_identifier,
+            replication_task_settings: self.replication_task_settings,
+            table_mappings: self.table_mappings,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1697,7 +4698,7 @@ pub struct MongoDbSettings {
     /// <p> The authentication mechanism you use to access the MongoDB source endpoint.</p> <p>Valid values: DEFAULT, MONGODB_CR, SCRAM_SHA_1 </p> <p>DEFAULT – For MongoDB version 2.x, use MONGODB_CR. For MongoDB version 3.x, use SCRAM_SHA_1. This attribute is not used when authType=No.</p>
     #[serde(rename = "AuthMechanism")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth_mechanism: Option<String>,
+    pub auth_mechanism: Option<AuthMechanismValue>,
     /// <p> The MongoDB database name. This attribute is not used when <code>authType=NO</code>. </p> <p>The default is admin.</p>
     #[serde(rename = "AuthSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1705,7 +4706,7 @@ pub struct MongoDbSettings {
     /// <p> The authentication type you use to access the MongoDB source endpoint.</p> <p>Valid values: NO, PASSWORD </p> <p>When NO is selected, user name and password parameters are not used and can be empty. </p>
     #[serde(rename = "AuthType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth_type: Option<String>,
+    pub auth_type: Option<AuthTypeValue>,
     /// <p> The database name on the MongoDB source endpoint. </p>
     #[serde(rename = "DatabaseName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1725,7 +4726,7 @@ pub struct MongoDbSettings {
     /// <p> Specifies either document or table mode. </p> <p>Valid values: NONE, ONE</p> <p>Default value is NONE. Specify NONE to use document mode. Specify ONE to use table mode.</p>
     #[serde(rename = "NestingLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub nesting_level: Option<String>,
+    pub nesting_level: Option<NestingLevelValue>,
     /// <p> The password for the user account you use to access the MongoDB source endpoint. </p>
     #[serde(rename = "Password")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1744,24 +4745,96 @@ pub struct MongoDbSettings {
     pub username: Option<String>,
 }
 
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct OrderableReplicationInstance {
-    /// <p>List of Availability Zones for this replication instance.</p>
-    #[serde(rename = "AvailabilityZones")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub availability_zones: Option<Vec<String>>,
-    /// <p>The default amount of storage (in gigabytes) that is allocated for the replication instance.</p>
-    #[serde(rename = "DefaultAllocatedStorage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_allocated_storage: Option<i64>,
-    /// <p>The version of the replication engine.</p>
-    #[serde(rename = "EngineVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_version: Option<String>,
-    /// <p>The amount of storage (in gigabytes) that is allocated for the replication instance.</p>
-    #[serde(rename = "IncludedAllocatedStorage")]
+/// <p> Specifies either document or table mode. </p> <p>Valid values: NONE, ONE</p> <p>Default value is NONE. Specify NONE to use document mode. Specify ONE to use table mode.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NestingLevelValue {
+    /// <p><code>none</code></p>
+    None,
+    /// <p><code>one</code></p>
+    One,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NestingLevelValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NestingLevelValue::None => "none",
+            NestingLevelValue::One => "one",
+            NestingLevelValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NestingLevelValue {
+    fn from(value: &'a str) -> NestingLevelValue {
+        match value {
+            "none" => NestingLevelValue::None,
+            "one" => NestingLevelValue::One,
+            _ => NestingLevelValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NestingLevelValue {
+    fn from(value: String) -> NestingLevelValue {
+        NestingLevelValue::from(value.as_str())
+    }
+}
+
+impl From<NestingLevelValue> for String {
+    fn from(value: NestingLevelValue) -> String {
+        match value {
+            NestingLevelValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NestingLevelValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NestingLevelValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NestingLevelValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NestingLevelValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NestingLevelValue::from(value))
+    }
+}
+
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct OrderableReplicationInstance {
+    /// <p>List of Availability Zones for this replication instance.</p>
+    #[serde(rename = "AvailabilityZones")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub availability_zones: Option<Vec<String>>,
+    /// <p>The default amount of storage (in gigabytes) that is allocated for the replication instance.</p>
+    #[serde(rename = "DefaultAllocatedStorage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_allocated_storage: Option<i64>,
+    /// <p>The version of the replication engine.</p>
+    #[serde(rename = "EngineVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub engine_version: Option<String>,
+    /// <p>The amount of storage (in gigabytes) that is allocated for the replication instance.</p>
+    #[serde(rename = "IncludedAllocatedStorage")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub included_allocated_storage: Option<i64>,
     /// <p>The minimum amount of storage (in gigabytes) that can be allocated for the replication instance.</p>
@@ -1775,7 +4848,7 @@ pub struct OrderableReplicationInstance {
     /// <p><p>The value returned when the specified <code>EngineVersion</code> of the replication instance is in Beta or test mode. This indicates some features might not work as expected.</p> <note> <p>AWS DMS supports <code>ReleaseStatus</code> in versions 3.1.4 and later.</p> </note></p>
     #[serde(rename = "ReleaseStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub release_status: Option<String>,
+    pub release_status: Option<ReleaseStatusValues>,
     /// <p>The compute and memory capacity of the replication instance.</p> <p> Valid Values: <code>dms.t2.micro | dms.t2.small | dms.t2.medium | dms.t2.large | dms.c4.large | dms.c4.xlarge | dms.c4.2xlarge | dms.c4.4xlarge </code> </p>
     #[serde(rename = "ReplicationInstanceClass")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1786,6 +4859,78 @@ pub struct OrderableReplicationInstance {
     pub storage_type: Option<String>,
 }
 
+/// <p>The version of the Apache Parquet format that you want to use: <code>parquet_1_0</code> (the default) or <code>parquet_2_0</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParquetVersionValue {
+    /// <p><code>parquet-1-0</code></p>
+    Parquet10,
+    /// <p><code>parquet-2-0</code></p>
+    Parquet20,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ParquetVersionValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParquetVersionValue::Parquet10 => "parquet-1-0",
+            ParquetVersionValue::Parquet20 => "parquet-2-0",
+            ParquetVersionValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ParquetVersionValue {
+    fn from(value: &'a str) -> ParquetVersionValue {
+        match value {
+            "parquet-1-0" => ParquetVersionValue::Parquet10,
+            "parquet-2-0" => ParquetVersionValue::Parquet20,
+            _ => ParquetVersionValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParquetVersionValue {
+    fn from(value: String) -> ParquetVersionValue {
+        ParquetVersionValue::from(value.as_str())
+    }
+}
+
+impl From<ParquetVersionValue> for String {
+    fn from(value: ParquetVersionValue) -> String {
+        match value {
+            ParquetVersionValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParquetVersionValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ParquetVersionValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParquetVersionValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ParquetVersionValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ParquetVersionValue::from(value))
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1796,20 +4941,23 @@ pub struct PendingMaintenanceAction {
     pub action: Option<String>,
     /// <p>The date of the maintenance window when the action will be applied. The maintenance action will be applied to the resource during its first maintenance window after this date. If this date is specified, any <code>next-maintenance</code> opt-in requests are ignored.</p>
     #[serde(rename = "AutoAppliedAfterDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_applied_after_date: Option<f64>,
+    pub auto_applied_after_date: Option<EpochTimestamp>,
     /// <p>The effective date when the pending maintenance action will be applied to the resource. This date takes into account opt-in requests received from the <code>ApplyPendingMaintenanceAction</code> API, the <code>AutoAppliedAfterDate</code>, and the <code>ForcedApplyDate</code>. This value is blank if an opt-in request has not been received and nothing has been specified as <code>AutoAppliedAfterDate</code> or <code>ForcedApplyDate</code>.</p>
     #[serde(rename = "CurrentApplyDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub current_apply_date: Option<f64>,
+    pub current_apply_date: Option<EpochTimestamp>,
     /// <p>A description providing more detail about the maintenance action.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date when the maintenance action will be automatically applied. The maintenance action will be applied to the resource on this date regardless of the maintenance window for the resource. If this date is specified, any <code>immediate</code> opt-in requests are ignored.</p>
     #[serde(rename = "ForcedApplyDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub forced_apply_date: Option<f64>,
+    pub forced_apply_date: Option<EpochTimestamp>,
     /// <p>Indicates the type of opt-in request that has been received for the resource.</p>
     #[serde(rename = "OptInStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1827,6 +4975,46 @@ pub struct RebootReplicationInstanceMessage {
     pub replication_instance_arn: String,
 }
 
+impl RebootReplicationInstanceMessage {
+    /// Returns a builder for `RebootReplicationInstanceMessage`, checking its required members when built.
+    pub fn builder() -> RebootReplicationInstanceMessageBuilder {
+        RebootReplicationInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RebootReplicationInstanceMessage`](struct.RebootReplicationInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct RebootReplicationInstanceMessageBuilder {
+    force_failover: Option<bool>,
+    replication_instance_arn: Option<String>,
+}
+
+impl RebootReplicationInstanceMessageBuilder {
+    /// Sets the [`force_failover`](struct.RebootReplicationInstanceMessage.html#structfield.force_failover) member.
+    pub fn force_failover<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_failover = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_instance_arn`](struct.RebootReplicationInstanceMessage.html#structfield.replication_instance_arn) member.
+    pub fn replication_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebootReplicationInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RebootReplicationInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(RebootReplicationInstanceMessage {
+            force_failover: self.force_failover,
+            replication_instance_arn: self.replication_instance_arn.ok_or(
+                ::rusoto_core::BuildError::missing("replication_instance_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RebootReplicationInstanceResponse {
@@ -1874,7 +5062,7 @@ pub struct RedshiftSettings {
     /// <p>The type of server-side encryption that you want to use for your data. This encryption type is part of the endpoint settings or the extra connections attributes for Amazon S3. You can choose either <code>SSE_S3</code> (the default) or <code>SSE_KMS</code>. To use <code>SSE_S3</code>, create an AWS Identity and Access Management (IAM) role with a policy that allows <code>"arn:aws:s3:::*"</code> to use the following actions: <code>"s3:PutObject", "s3:ListBucket"</code> </p>
     #[serde(rename = "EncryptionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_mode: Option<String>,
+    pub encryption_mode: Option<EncryptionModeValue>,
     /// <p>The number of threads used to upload a single file. This parameter accepts a value from 1 through 64. It defaults to 10.</p>
     #[serde(rename = "FileTransferUploadStreams")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1952,6 +5140,46 @@ pub struct RefreshSchemasMessage {
     pub replication_instance_arn: String,
 }
 
+impl RefreshSchemasMessage {
+    /// Returns a builder for `RefreshSchemasMessage`, checking its required members when built.
+    pub fn builder() -> RefreshSchemasMessageBuilder {
+        RefreshSchemasMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RefreshSchemasMessage`](struct.RefreshSchemasMessage.html).
+#[derive(Default, Debug)]
+pub struct RefreshSchemasMessageBuilder {
+    endpoint_arn: Option<String>,
+    replication_instance_arn: Option<String>,
+}
+
+impl RefreshSchemasMessageBuilder {
+    /// Sets the [`endpoint_arn`](struct.RefreshSchemasMessage.html#structfield.endpoint_arn) member.
+    pub fn endpoint_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_instance_arn`](struct.RefreshSchemasMessage.html#structfield.replication_instance_arn) member.
+    pub fn replication_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RefreshSchemasMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RefreshSchemasMessage, ::rusoto_core::BuildError> {
+        Ok(RefreshSchemasMessage {
+            endpoint_arn: self
+                .endpoint_arn
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_arn"))?,
+            replication_instance_arn: self.replication_instance_arn.ok_or(
+                ::rusoto_core::BuildError::missing("replication_instance_arn"),
+            )?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1976,8 +5204,9 @@ pub struct RefreshSchemasStatus {
     pub last_failure_message: Option<String>,
     /// <p>The date the schema was last refreshed.</p>
     #[serde(rename = "LastRefreshDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_refresh_date: Option<f64>,
+    pub last_refresh_date: Option<EpochTimestamp>,
     /// <p>The Amazon Resource Name (ARN) of the replication instance.</p>
     #[serde(rename = "ReplicationInstanceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1985,7 +5214,229 @@ pub struct RefreshSchemasStatus {
     /// <p>The status of the schema.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<RefreshSchemasStatusTypeValue>,
+}
+
+/// <p>The status of the schema.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RefreshSchemasStatusTypeValue {
+    /// <p><code>successful</code></p>
+    Successful,
+    /// <p><code>failed</code></p>
+    Failed,
+    /// <p><code>refreshing</code></p>
+    Refreshing,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RefreshSchemasStatusTypeValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RefreshSchemasStatusTypeValue::Successful => "successful",
+            RefreshSchemasStatusTypeValue::Failed => "failed",
+            RefreshSchemasStatusTypeValue::Refreshing => "refreshing",
+            RefreshSchemasStatusTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RefreshSchemasStatusTypeValue {
+    fn from(value: &'a str) -> RefreshSchemasStatusTypeValue {
+        match value {
+            "successful" => RefreshSchemasStatusTypeValue::Successful,
+            "failed" => RefreshSchemasStatusTypeValue::Failed,
+            "refreshing" => RefreshSchemasStatusTypeValue::Refreshing,
+            _ => RefreshSchemasStatusTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RefreshSchemasStatusTypeValue {
+    fn from(value: String) -> RefreshSchemasStatusTypeValue {
+        RefreshSchemasStatusTypeValue::from(value.as_str())
+    }
+}
+
+impl From<RefreshSchemasStatusTypeValue> for String {
+    fn from(value: RefreshSchemasStatusTypeValue) -> String {
+        match value {
+            RefreshSchemasStatusTypeValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RefreshSchemasStatusTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RefreshSchemasStatusTypeValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RefreshSchemasStatusTypeValue {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<RefreshSchemasStatusTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RefreshSchemasStatusTypeValue::from(value))
+    }
+}
+
+/// <p><p>The value returned when the specified <code>EngineVersion</code> of the replication instance is in Beta or test mode. This indicates some features might not work as expected.</p> <note> <p>AWS DMS supports <code>ReleaseStatus</code> in versions 3.1.4 and later.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReleaseStatusValues {
+    /// <p><code>beta</code></p>
+    Beta,
+    /// <p><code>prod</code></p>
+    Prod,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReleaseStatusValues {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReleaseStatusValues::Beta => "beta",
+            ReleaseStatusValues::Prod => "prod",
+            ReleaseStatusValues::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReleaseStatusValues {
+    fn from(value: &'a str) -> ReleaseStatusValues {
+        match value {
+            "beta" => ReleaseStatusValues::Beta,
+            "prod" => ReleaseStatusValues::Prod,
+            _ => ReleaseStatusValues::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReleaseStatusValues {
+    fn from(value: String) -> ReleaseStatusValues {
+        ReleaseStatusValues::from(value.as_str())
+    }
+}
+
+impl From<ReleaseStatusValues> for String {
+    fn from(value: ReleaseStatusValues) -> String {
+        match value {
+            ReleaseStatusValues::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReleaseStatusValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReleaseStatusValues {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReleaseStatusValues {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReleaseStatusValues, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReleaseStatusValues::from(value))
+    }
+}
+
+/// <p>Options for reload. Specify <code>data-reload</code> to reload the data and re-validate it if validation is enabled. Specify <code>validate-only</code> to re-validate the table. This option applies only when validation is enabled for the task. </p> <p>Valid values: data-reload, validate-only</p> <p>Default value is data-reload.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReloadOptionValue {
+    /// <p><code>data-reload</code></p>
+    DataReload,
+    /// <p><code>validate-only</code></p>
+    ValidateOnly,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReloadOptionValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReloadOptionValue::DataReload => "data-reload",
+            ReloadOptionValue::ValidateOnly => "validate-only",
+            ReloadOptionValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReloadOptionValue {
+    fn from(value: &'a str) -> ReloadOptionValue {
+        match value {
+            "data-reload" => ReloadOptionValue::DataReload,
+            "validate-only" => ReloadOptionValue::ValidateOnly,
+            _ => ReloadOptionValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReloadOptionValue {
+    fn from(value: String) -> ReloadOptionValue {
+        ReloadOptionValue::from(value.as_str())
+    }
+}
+
+impl From<ReloadOptionValue> for String {
+    fn from(value: ReloadOptionValue) -> String {
+        match value {
+            ReloadOptionValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReloadOptionValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReloadOptionValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReloadOptionValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReloadOptionValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReloadOptionValue::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1993,7 +5444,7 @@ pub struct ReloadTablesMessage {
     /// <p>Options for reload. Specify <code>data-reload</code> to reload the data and re-validate it if validation is enabled. Specify <code>validate-only</code> to re-validate the table. This option applies only when validation is enabled for the task. </p> <p>Valid values: data-reload, validate-only</p> <p>Default value is data-reload.</p>
     #[serde(rename = "ReloadOption")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reload_option: Option<String>,
+    pub reload_option: Option<ReloadOptionValue>,
     /// <p>The Amazon Resource Name (ARN) of the replication task. </p>
     #[serde(rename = "ReplicationTaskArn")]
     pub replication_task_arn: String,
@@ -2002,6 +5453,54 @@ pub struct ReloadTablesMessage {
     pub tables_to_reload: Vec<TableToReload>,
 }
 
+impl ReloadTablesMessage {
+    /// Returns a builder for `ReloadTablesMessage`, checking its required members when built.
+    pub fn builder() -> ReloadTablesMessageBuilder {
+        ReloadTablesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ReloadTablesMessage`](struct.ReloadTablesMessage.html).
+#[derive(Default, Debug)]
+pub struct ReloadTablesMessageBuilder {
+    reload_option: Option<ReloadOptionValue>,
+    replication_task_arn: Option<String>,
+    tables_to_reload: Option<Vec<TableToReload>>,
+}
+
+impl ReloadTablesMessageBuilder {
+    /// Sets the [`reload_option`](struct.ReloadTablesMessage.html#structfield.reload_option) member.
+    pub fn reload_option<T: Into<ReloadOptionValue>>(mut self, value: T) -> Self {
+        self.reload_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_task_arn`](struct.ReloadTablesMessage.html#structfield.replication_task_arn) member.
+    pub fn replication_task_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_task_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tables_to_reload`](struct.ReloadTablesMessage.html#structfield.tables_to_reload) member.
+    pub fn tables_to_reload<T: Into<Vec<TableToReload>>>(mut self, value: T) -> Self {
+        self.tables_to_reload = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReloadTablesMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ReloadTablesMessage, ::rusoto_core::BuildError> {
+        Ok(ReloadTablesMessage {
+            reload_option: self.reload_option,
+            replication_task_arn: self
+                .replication_task_arn
+                .ok_or(::rusoto_core::BuildError::missing("replication_task_arn"))?,
+            tables_to_reload: self
+                .tables_to_reload
+                .ok_or(::rusoto_core::BuildError::missing("tables_to_reload"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ReloadTablesResponse {
@@ -2022,43 +5521,161 @@ pub struct RemoveTagsFromResourceMessage {
     pub tag_keys: Vec<String>,
 }
 
+impl RemoveTagsFromResourceMessage {
+    /// Returns a builder for `RemoveTagsFromResourceMessage`, checking its required members when built.
+    pub fn builder() -> RemoveTagsFromResourceMessageBuilder {
+        RemoveTagsFromResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsFromResourceMessage`](struct.RemoveTagsFromResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsFromResourceMessageBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl RemoveTagsFromResourceMessageBuilder {
+    /// Sets the [`resource_arn`](struct.RemoveTagsFromResourceMessage.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.RemoveTagsFromResourceMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsFromResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveTagsFromResourceMessage, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsFromResourceMessage {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RemoveTagsFromResourceResponse {}
 
-/// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ReplicationInstance {
-    /// <p>The amount of storage (in gigabytes) that is allocated for the replication instance.</p>
-    #[serde(rename = "AllocatedStorage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub allocated_storage: Option<i64>,
-    /// <p>Boolean value indicating if minor version upgrades will be automatically applied to the instance.</p>
-    #[serde(rename = "AutoMinorVersionUpgrade")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_minor_version_upgrade: Option<bool>,
-    /// <p>The Availability Zone for the instance.</p>
-    #[serde(rename = "AvailabilityZone")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub availability_zone: Option<String>,
-    /// <p>The DNS name servers for the replication instance.</p>
-    #[serde(rename = "DnsNameServers")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub dns_name_servers: Option<String>,
-    /// <p>The engine version number of the replication instance.</p>
-    #[serde(rename = "EngineVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+/// <p>The type of endpoint. Valid values are <code>source</code> and <code>target</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReplicationEndpointTypeValue {
+    /// <p><code>source</code></p>
+    Source,
+    /// <p><code>target</code></p>
+    Target,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReplicationEndpointTypeValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationEndpointTypeValue::Source => "source",
+            ReplicationEndpointTypeValue::Target => "target",
+            ReplicationEndpointTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationEndpointTypeValue {
+    fn from(value: &'a str) -> ReplicationEndpointTypeValue {
+        match value {
+            "source" => ReplicationEndpointTypeValue::Source,
+            "target" => ReplicationEndpointTypeValue::Target,
+            _ => ReplicationEndpointTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationEndpointTypeValue {
+    fn from(value: String) -> ReplicationEndpointTypeValue {
+        ReplicationEndpointTypeValue::from(value.as_str())
+    }
+}
+
+impl From<ReplicationEndpointTypeValue> for String {
+    fn from(value: ReplicationEndpointTypeValue) -> String {
+        match value {
+            ReplicationEndpointTypeValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReplicationEndpointTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReplicationEndpointTypeValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicationEndpointTypeValue {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ReplicationEndpointTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReplicationEndpointTypeValue::from(value))
+    }
+}
+
+/// <p><p/></p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ReplicationInstance {
+    /// <p>The amount of storage (in gigabytes) that is allocated for the replication instance.</p>
+    #[serde(rename = "AllocatedStorage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocated_storage: Option<i64>,
+    /// <p>Boolean value indicating if minor version upgrades will be automatically applied to the instance.</p>
+    #[serde(rename = "AutoMinorVersionUpgrade")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_minor_version_upgrade: Option<bool>,
+    /// <p>The Availability Zone for the instance.</p>
+    #[serde(rename = "AvailabilityZone")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub availability_zone: Option<String>,
+    /// <p>The DNS name servers for the replication instance.</p>
+    #[serde(rename = "DnsNameServers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dns_name_servers: Option<String>,
+    /// <p>The engine version number of the replication instance.</p>
+    #[serde(rename = "EngineVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub engine_version: Option<String>,
     /// <p> The expiration date of the free replication instance that is part of the Free DMS program. </p>
     #[serde(rename = "FreeUntil")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub free_until: Option<f64>,
+    pub free_until: Option<EpochTimestamp>,
     /// <p>The time the replication instance was created.</p>
     #[serde(rename = "InstanceCreateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_create_time: Option<f64>,
+    pub instance_create_time: Option<EpochTimestamp>,
     /// <p>An AWS KMS key identifier that is used to encrypt the data on the replication instance.</p> <p>If you don't specify a value for the <code>KmsKeyId</code> parameter, then AWS DMS uses your default encryption key.</p> <p>AWS KMS creates the default encryption key for your AWS account. Your AWS account has a different default encryption key for each AWS Region.</p>
     #[serde(rename = "KmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2202,7 +5819,7 @@ pub struct ReplicationTask {
     /// <p>The type of migration.</p>
     #[serde(rename = "MigrationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub migration_type: Option<String>,
+    pub migration_type: Option<MigrationTypeValue>,
     /// <p>Indicates the last checkpoint that occurred during a change data capture (CDC) operation. You can provide this value to the <code>CdcStartPosition</code> parameter to start a CDC operation that begins at that checkpoint.</p>
     #[serde(rename = "RecoveryCheckpoint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2217,8 +5834,9 @@ pub struct ReplicationTask {
     pub replication_task_arn: Option<String>,
     /// <p>The date the replication task was created.</p>
     #[serde(rename = "ReplicationTaskCreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replication_task_creation_date: Option<f64>,
+    pub replication_task_creation_date: Option<EpochTimestamp>,
     /// <p><p>The user-assigned replication task identifier or name.</p> <p>Constraints:</p> <ul> <li> <p>Must contain from 1 to 255 alphanumeric characters or hyphens.</p> </li> <li> <p>First character must be a letter.</p> </li> <li> <p>Cannot end with a hyphen or contain two consecutive hyphens.</p> </li> </ul></p>
     #[serde(rename = "ReplicationTaskIdentifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2229,8 +5847,9 @@ pub struct ReplicationTask {
     pub replication_task_settings: Option<String>,
     /// <p>The date the replication task is scheduled to start.</p>
     #[serde(rename = "ReplicationTaskStartDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replication_task_start_date: Option<f64>,
+    pub replication_task_start_date: Option<EpochTimestamp>,
     /// <p>The statistics for the task, including elapsed time, tables loaded, and table errors.</p>
     #[serde(rename = "ReplicationTaskStats")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2283,8 +5902,9 @@ pub struct ReplicationTaskAssessmentResult {
     pub replication_task_identifier: Option<String>,
     /// <p>The date the task assessment was completed. </p>
     #[serde(rename = "ReplicationTaskLastAssessmentDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replication_task_last_assessment_date: Option<f64>,
+    pub replication_task_last_assessment_date: Option<EpochTimestamp>,
     /// <p> The URL of the S3 object containing the task assessment results. </p>
     #[serde(rename = "S3ObjectUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2353,7 +5973,7 @@ pub struct S3Settings {
     /// <p> An optional parameter to use GZIP to compress the target files. Set to GZIP to compress the target files. Set to NONE (the default) or do not use to leave the files uncompressed. Applies to both .csv and .parquet file formats. </p>
     #[serde(rename = "CompressionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression_type: Option<String>,
+    pub compression_type: Option<CompressionTypeValue>,
     /// <p> The delimiter used to separate columns in the source files. The default is a comma. </p>
     #[serde(rename = "CsvDelimiter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2365,7 +5985,7 @@ pub struct S3Settings {
     /// <p><p>The format of the data that you want to use for output. You can choose one of the following: </p> <ul> <li> <p> <code>csv</code> : This is a row-based file format with comma-separated values (.csv). </p> </li> <li> <p> <code>parquet</code> : Apache Parquet (.parquet) is a columnar storage file format that features efficient compression and provides faster query response. </p> </li> </ul></p>
     #[serde(rename = "DataFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_format: Option<String>,
+    pub data_format: Option<DataFormatValue>,
     /// <p>The size of one data page in bytes. This parameter defaults to 1024 * 1024 bytes (1 MiB). This number is used for .parquet file format only. </p>
     #[serde(rename = "DataPageSize")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2381,11 +6001,11 @@ pub struct S3Settings {
     /// <p><p>The type of encoding you are using: </p> <ul> <li> <p> <code>RLE<em>DICTIONARY</code> uses a combination of bit-packing and run-length encoding to store repeated values more efficiently. This is the default.</p> </li> <li> <p> <code>PLAIN</code> doesn&#39;t use encoding at all. Values are stored as they are.</p> </li> <li> <p> <code>PLAIN</em>DICTIONARY</code> builds a dictionary of the values encountered in a given column. The dictionary is stored in a dictionary page for each column chunk.</p> </li> </ul></p>
     #[serde(rename = "EncodingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encoding_type: Option<String>,
+    pub encoding_type: Option<EncodingTypeValue>,
     /// <p><p>The type of server-side encryption that you want to use for your data. This encryption type is part of the endpoint settings or the extra connections attributes for Amazon S3. You can choose either <code>SSE<em>S3</code> (the default) or <code>SSE</em>KMS</code>. To use <code>SSE_S3</code>, you need an AWS Identity and Access Management (IAM) role with permission to allow <code>&quot;arn:aws:s3:::dms-*&quot;</code> to use the following actions:</p> <ul> <li> <p> <code>s3:CreateBucket</code> </p> </li> <li> <p> <code>s3:ListBucket</code> </p> </li> <li> <p> <code>s3:DeleteBucket</code> </p> </li> <li> <p> <code>s3:GetBucketLocation</code> </p> </li> <li> <p> <code>s3:GetObject</code> </p> </li> <li> <p> <code>s3:PutObject</code> </p> </li> <li> <p> <code>s3:DeleteObject</code> </p> </li> <li> <p> <code>s3:GetObjectVersion</code> </p> </li> <li> <p> <code>s3:GetBucketPolicy</code> </p> </li> <li> <p> <code>s3:PutBucketPolicy</code> </p> </li> <li> <p> <code>s3:DeleteBucketPolicy</code> </p> </li> </ul></p>
     #[serde(rename = "EncryptionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_mode: Option<String>,
+    pub encryption_mode: Option<EncryptionModeValue>,
     /// <p> The external table definition. </p>
     #[serde(rename = "ExternalTableDefinition")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2397,7 +6017,7 @@ pub struct S3Settings {
     /// <p>The version of the Apache Parquet format that you want to use: <code>parquet_1_0</code> (the default) or <code>parquet_2_0</code>.</p>
     #[serde(rename = "ParquetVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parquet_version: Option<String>,
+    pub parquet_version: Option<ParquetVersionValue>,
     /// <p>The number of rows in a row group. A smaller row group size provides faster reads. But as the number of row groups grows, the slower writes become. This parameter defaults to 10,000 rows. This number is used for .parquet file format only. </p> <p>If you choose a value larger than the maximum, <code>RowGroupLength</code> is set to the max row group length in bytes (64 * 1024 * 1024). </p>
     #[serde(rename = "RowGroupLength")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2416,6 +6036,74 @@ pub struct S3Settings {
     pub timestamp_column_name: Option<String>,
 }
 
+/// <p>The type of AWS DMS resource that generates events.</p> <p>Valid values: replication-instance | replication-task</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceType {
+    /// <p><code>replication-instance</code></p>
+    ReplicationInstance,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::ReplicationInstance => "replication-instance",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "replication-instance" => SourceType::ReplicationInstance,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        SourceType::from(value.as_str())
+    }
+}
+
+impl From<SourceType> for String {
+    fn from(value: SourceType) -> String {
+        match value {
+            SourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SourceType::from(value))
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartReplicationTaskAssessmentMessage {
@@ -2424,6 +6112,39 @@ pub struct StartReplicationTaskAssessmentMessage {
     pub replication_task_arn: String,
 }
 
+impl StartReplicationTaskAssessmentMessage {
+    /// Returns a builder for `StartReplicationTaskAssessmentMessage`, checking its required members when built.
+    pub fn builder() -> StartReplicationTaskAssessmentMessageBuilder {
+        StartReplicationTaskAssessmentMessageBuilder::default()
+    }
+}
+
+/// Builds a [`StartReplicationTaskAssessmentMessage`](struct.StartReplicationTaskAssessmentMessage.html).
+#[derive(Default, Debug)]
+pub struct StartReplicationTaskAssessmentMessageBuilder {
+    replication_task_arn: Option<String>,
+}
+
+impl StartReplicationTaskAssessmentMessageBuilder {
+    /// Sets the [`replication_task_arn`](struct.StartReplicationTaskAssessmentMessage.html#structfield.replication_task_arn) member.
+    pub fn replication_task_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_task_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartReplicationTaskAssessmentMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartReplicationTaskAssessmentMessage, ::rusoto_core::BuildError>
+    {
+        Ok(StartReplicationTaskAssessmentMessage {
+            replication_task_arn: self
+                .replication_task_arn
+                .ok_or(::rusoto_core::BuildError::missing("replication_task_arn"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2435,7 +6156,7 @@ pub struct StartReplicationTaskAssessmentResponse {
 }
 
 /// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct StartReplicationTaskMessage {
     /// <p>Indicates when you want a change data capture (CDC) operation to start. Use either CdcStartPosition or CdcStartTime to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p> The value can be in date, checkpoint, or LSN/SCN format.</p> <p>Date Example: --cdc-start-position “2018-03-08T12:12:12”</p> <p>Checkpoint Example: --cdc-start-position "checkpoint:V1#27#mysql-bin-changelog.157832:1975:-1:2002:677883278264080:mysql-bin-changelog.157832:1876#0#0#*#0#93"</p> <p>LSN Example: --cdc-start-position “mysql-bin-changelog.000024:373”</p>
     #[serde(rename = "CdcStartPosition")]
@@ -2443,8 +6164,9 @@ pub struct StartReplicationTaskMessage {
     pub cdc_start_position: Option<String>,
     /// <p>Indicates the start time for a change data capture (CDC) operation. Use either CdcStartTime or CdcStartPosition to specify when you want a CDC operation to start. Specifying both values results in an error.</p> <p>Timestamp Example: --cdc-start-time “2018-03-08T12:12:12”</p>
     #[serde(rename = "CdcStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cdc_start_time: Option<f64>,
+    pub cdc_start_time: Option<EpochTimestamp>,
     /// <p>Indicates when you want a change data capture (CDC) operation to stop. The value can be either server time or commit time.</p> <p>Server time example: --cdc-stop-position “server_time:3018-02-09T12:12:12”</p> <p>Commit time example: --cdc-stop-position “commit_time: 3018-02-09T12:12:12 “</p>
     #[serde(rename = "CdcStopPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2454,7 +6176,76 @@ pub struct StartReplicationTaskMessage {
     pub replication_task_arn: String,
     /// <p>The type of replication task.</p>
     #[serde(rename = "StartReplicationTaskType")]
-    pub start_replication_task_type: String,
+    pub start_replication_task_type: StartReplicationTaskTypeValue,
+}
+
+impl StartReplicationTaskMessage {
+    /// Returns a builder for `StartReplicationTaskMessage`, checking its required members when built.
+    pub fn builder() -> StartReplicationTaskMessageBuilder {
+        StartReplicationTaskMessageBuilder::default()
+    }
+}
+
+/// Builds a [`StartReplicationTaskMessage`](struct.StartReplicationTaskMessage.html).
+#[derive(Default, Debug)]
+pub struct StartReplicationTaskMessageBuilder {
+    cdc_start_position: Option<String>,
+    cdc_start_time: Option<EpochTimestamp>,
+    cdc_stop_position: Option<String>,
+    replication_task_arn: Option<String>,
+    start_replication_task_type: Option<StartReplicationTaskTypeValue>,
+}
+
+impl StartReplicationTaskMessageBuilder {
+    /// Sets the [`cdc_start_position`](struct.StartReplicationTaskMessage.html#structfield.cdc_start_position) member.
+    pub fn cdc_start_position<T: Into<String>>(mut self, value: T) -> Self {
+        self.cdc_start_position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cdc_start_time`](struct.StartReplicationTaskMessage.html#structfield.cdc_start_time) member.
+    pub fn cdc_start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.cdc_start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cdc_stop_position`](struct.StartReplicationTaskMessage.html#structfield.cdc_stop_position) member.
+    pub fn cdc_stop_position<T: Into<String>>(mut self, value: T) -> Self {
+        self.cdc_stop_position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_task_arn`](struct.StartReplicationTaskMessage.html#structfield.replication_task_arn) member.
+    pub fn replication_task_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_task_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_replication_task_type`](struct.StartReplicationTaskMessage.html#structfield.start_replication_task_type) member.
+    pub fn start_replication_task_type<T: Into<StartReplicationTaskTypeValue>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.start_replication_task_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartReplicationTaskMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartReplicationTaskMessage, ::rusoto_core::BuildError> {
+        Ok(StartReplicationTaskMessage {
+            cdc_start_position: self.cdc_start_position,
+            cdc_start_time: self.cdc_start_time,
+            cdc_stop_position: self.cdc_stop_position,
+            replication_task_arn: self
+                .replication_task_arn
+                .ok_or(::rusoto_core::BuildError::missing("replication_task_arn"))?,
+            start_replication_task_type: self.start_replication_task_type.ok_or(
+                ::rusoto_core::BuildError::missing("start_replication_task_type"),
+            )?,
+        })
+    }
 }
 
 /// <p><p/></p>
@@ -2467,6 +6258,84 @@ pub struct StartReplicationTaskResponse {
     pub replication_task: Option<ReplicationTask>,
 }
 
+/// <p>The type of replication task.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StartReplicationTaskTypeValue {
+    /// <p><code>start-replication</code></p>
+    StartReplication,
+    /// <p><code>resume-processing</code></p>
+    ResumeProcessing,
+    /// <p><code>reload-target</code></p>
+    ReloadTarget,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StartReplicationTaskTypeValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StartReplicationTaskTypeValue::StartReplication => "start-replication",
+            StartReplicationTaskTypeValue::ResumeProcessing => "resume-processing",
+            StartReplicationTaskTypeValue::ReloadTarget => "reload-target",
+            StartReplicationTaskTypeValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StartReplicationTaskTypeValue {
+    fn from(value: &'a str) -> StartReplicationTaskTypeValue {
+        match value {
+            "start-replication" => StartReplicationTaskTypeValue::StartReplication,
+            "resume-processing" => StartReplicationTaskTypeValue::ResumeProcessing,
+            "reload-target" => StartReplicationTaskTypeValue::ReloadTarget,
+            _ => StartReplicationTaskTypeValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StartReplicationTaskTypeValue {
+    fn from(value: String) -> StartReplicationTaskTypeValue {
+        StartReplicationTaskTypeValue::from(value.as_str())
+    }
+}
+
+impl From<StartReplicationTaskTypeValue> for String {
+    fn from(value: StartReplicationTaskTypeValue) -> String {
+        match value {
+            StartReplicationTaskTypeValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StartReplicationTaskTypeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StartReplicationTaskTypeValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StartReplicationTaskTypeValue {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<StartReplicationTaskTypeValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StartReplicationTaskTypeValue::from(value))
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StopReplicationTaskMessage {
@@ -2475,6 +6344,38 @@ pub struct StopReplicationTaskMessage {
     pub replication_task_arn: String,
 }
 
+impl StopReplicationTaskMessage {
+    /// Returns a builder for `StopReplicationTaskMessage`, checking its required members when built.
+    pub fn builder() -> StopReplicationTaskMessageBuilder {
+        StopReplicationTaskMessageBuilder::default()
+    }
+}
+
+/// Builds a [`StopReplicationTaskMessage`](struct.StopReplicationTaskMessage.html).
+#[derive(Default, Debug)]
+pub struct StopReplicationTaskMessageBuilder {
+    replication_task_arn: Option<String>,
+}
+
+impl StopReplicationTaskMessageBuilder {
+    /// Sets the [`replication_task_arn`](struct.StopReplicationTaskMessage.html#structfield.replication_task_arn) member.
+    pub fn replication_task_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_task_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopReplicationTaskMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopReplicationTaskMessage, ::rusoto_core::BuildError> {
+        Ok(StopReplicationTaskMessage {
+            replication_task_arn: self
+                .replication_task_arn
+                .ok_or(::rusoto_core::BuildError::missing("replication_task_arn"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2510,7 +6411,7 @@ pub struct SupportedEndpointType {
     /// <p>The type of endpoint. Valid values are <code>source</code> and <code>target</code>.</p>
     #[serde(rename = "EndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_type: Option<String>,
+    pub endpoint_type: Option<ReplicationEndpointTypeValue>,
     /// <p>The expanded name for the engine name. For example, if the <code>EngineName</code> parameter is "aurora," this value would be "Amazon Aurora MySQL."</p>
     #[serde(rename = "EngineDisplayName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2555,8 +6456,9 @@ pub struct TableStatistics {
     pub inserts: Option<i64>,
     /// <p>The last time the table was updated.</p>
     #[serde(rename = "LastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The schema name.</p>
     #[serde(rename = "SchemaName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2632,6 +6534,46 @@ pub struct TestConnectionMessage {
     pub replication_instance_arn: String,
 }
 
+impl TestConnectionMessage {
+    /// Returns a builder for `TestConnectionMessage`, checking its required members when built.
+    pub fn builder() -> TestConnectionMessageBuilder {
+        TestConnectionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`TestConnectionMessage`](struct.TestConnectionMessage.html).
+#[derive(Default, Debug)]
+pub struct TestConnectionMessageBuilder {
+    endpoint_arn: Option<String>,
+    replication_instance_arn: Option<String>,
+}
+
+impl TestConnectionMessageBuilder {
+    /// Sets the [`endpoint_arn`](struct.TestConnectionMessage.html#structfield.endpoint_arn) member.
+    pub fn endpoint_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_instance_arn`](struct.TestConnectionMessage.html#structfield.replication_instance_arn) member.
+    pub fn replication_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestConnectionMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TestConnectionMessage, ::rusoto_core::BuildError> {
+        Ok(TestConnectionMessage {
+            endpoint_arn: self
+                .endpoint_arn
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_arn"))?,
+            replication_instance_arn: self.replication_instance_arn.ok_or(
+                ::rusoto_core::BuildError::missing("replication_instance_arn"),
+            )?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -5203,6 +9145,305 @@ pub trait DatabaseMigrationService {
         input: TestConnectionMessage,
     ) -> RusotoFuture<TestConnectionResponse, TestConnectionError>;
 }
+impl<T: ?Sized + DatabaseMigrationService> DatabaseMigrationService for ::std::sync::Arc<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<AddTagsToResourceResponse, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> RusotoFuture<ApplyPendingMaintenanceActionResponse, ApplyPendingMaintenanceActionError>
+    {
+        (**self).apply_pending_maintenance_action(input)
+    }
+    fn create_endpoint(
+        &self,
+        input: CreateEndpointMessage,
+    ) -> RusotoFuture<CreateEndpointResponse, CreateEndpointError> {
+        (**self).create_endpoint(input)
+    }
+    fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> RusotoFuture<CreateEventSubscriptionResponse, CreateEventSubscriptionError> {
+        (**self).create_event_subscription(input)
+    }
+    fn create_replication_instance(
+        &self,
+        input: CreateReplicationInstanceMessage,
+    ) -> RusotoFuture<CreateReplicationInstanceResponse, CreateReplicationInstanceError> {
+        (**self).create_replication_instance(input)
+    }
+    fn create_replication_subnet_group(
+        &self,
+        input: CreateReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<CreateReplicationSubnetGroupResponse, CreateReplicationSubnetGroupError> {
+        (**self).create_replication_subnet_group(input)
+    }
+    fn create_replication_task(
+        &self,
+        input: CreateReplicationTaskMessage,
+    ) -> RusotoFuture<CreateReplicationTaskResponse, CreateReplicationTaskError> {
+        (**self).create_replication_task(input)
+    }
+    fn delete_certificate(
+        &self,
+        input: DeleteCertificateMessage,
+    ) -> RusotoFuture<DeleteCertificateResponse, DeleteCertificateError> {
+        (**self).delete_certificate(input)
+    }
+    fn delete_endpoint(
+        &self,
+        input: DeleteEndpointMessage,
+    ) -> RusotoFuture<DeleteEndpointResponse, DeleteEndpointError> {
+        (**self).delete_endpoint(input)
+    }
+    fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> RusotoFuture<DeleteEventSubscriptionResponse, DeleteEventSubscriptionError> {
+        (**self).delete_event_subscription(input)
+    }
+    fn delete_replication_instance(
+        &self,
+        input: DeleteReplicationInstanceMessage,
+    ) -> RusotoFuture<DeleteReplicationInstanceResponse, DeleteReplicationInstanceError> {
+        (**self).delete_replication_instance(input)
+    }
+    fn delete_replication_subnet_group(
+        &self,
+        input: DeleteReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<DeleteReplicationSubnetGroupResponse, DeleteReplicationSubnetGroupError> {
+        (**self).delete_replication_subnet_group(input)
+    }
+    fn delete_replication_task(
+        &self,
+        input: DeleteReplicationTaskMessage,
+    ) -> RusotoFuture<DeleteReplicationTaskResponse, DeleteReplicationTaskError> {
+        (**self).delete_replication_task(input)
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResponse, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes()
+    }
+    fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> RusotoFuture<DescribeCertificatesResponse, DescribeCertificatesError> {
+        (**self).describe_certificates(input)
+    }
+    fn describe_connections(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> RusotoFuture<DescribeConnectionsResponse, DescribeConnectionsError> {
+        (**self).describe_connections(input)
+    }
+    fn describe_endpoint_types(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> RusotoFuture<DescribeEndpointTypesResponse, DescribeEndpointTypesError> {
+        (**self).describe_endpoint_types(input)
+    }
+    fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        (**self).describe_endpoints(input)
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<DescribeEventCategoriesResponse, DescribeEventCategoriesError> {
+        (**self).describe_event_categories(input)
+    }
+    fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> RusotoFuture<DescribeEventSubscriptionsResponse, DescribeEventSubscriptionsError> {
+        (**self).describe_event_subscriptions(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_orderable_replication_instances(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> RusotoFuture<
+        DescribeOrderableReplicationInstancesResponse,
+        DescribeOrderableReplicationInstancesError,
+    > {
+        (**self).describe_orderable_replication_instances(input)
+    }
+    fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> RusotoFuture<
+        DescribePendingMaintenanceActionsResponse,
+        DescribePendingMaintenanceActionsError,
+    > {
+        (**self).describe_pending_maintenance_actions(input)
+    }
+    fn describe_refresh_schemas_status(
+        &self,
+        input: DescribeRefreshSchemasStatusMessage,
+    ) -> RusotoFuture<DescribeRefreshSchemasStatusResponse, DescribeRefreshSchemasStatusError> {
+        (**self).describe_refresh_schemas_status(input)
+    }
+    fn describe_replication_instance_task_logs(
+        &self,
+        input: DescribeReplicationInstanceTaskLogsMessage,
+    ) -> RusotoFuture<
+        DescribeReplicationInstanceTaskLogsResponse,
+        DescribeReplicationInstanceTaskLogsError,
+    > {
+        (**self).describe_replication_instance_task_logs(input)
+    }
+    fn describe_replication_instances(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> RusotoFuture<DescribeReplicationInstancesResponse, DescribeReplicationInstancesError> {
+        (**self).describe_replication_instances(input)
+    }
+    fn describe_replication_subnet_groups(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> RusotoFuture<DescribeReplicationSubnetGroupsResponse, DescribeReplicationSubnetGroupsError>
+    {
+        (**self).describe_replication_subnet_groups(input)
+    }
+    fn describe_replication_task_assessment_results(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> RusotoFuture<
+        DescribeReplicationTaskAssessmentResultsResponse,
+        DescribeReplicationTaskAssessmentResultsError,
+    > {
+        (**self).describe_replication_task_assessment_results(input)
+    }
+    fn describe_replication_tasks(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> RusotoFuture<DescribeReplicationTasksResponse, DescribeReplicationTasksError> {
+        (**self).describe_replication_tasks(input)
+    }
+    fn describe_schemas(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> RusotoFuture<DescribeSchemasResponse, DescribeSchemasError> {
+        (**self).describe_schemas(input)
+    }
+    fn describe_table_statistics(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> RusotoFuture<DescribeTableStatisticsResponse, DescribeTableStatisticsError> {
+        (**self).describe_table_statistics(input)
+    }
+    fn import_certificate(
+        &self,
+        input: ImportCertificateMessage,
+    ) -> RusotoFuture<ImportCertificateResponse, ImportCertificateError> {
+        (**self).import_certificate(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_endpoint(
+        &self,
+        input: ModifyEndpointMessage,
+    ) -> RusotoFuture<ModifyEndpointResponse, ModifyEndpointError> {
+        (**self).modify_endpoint(input)
+    }
+    fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> RusotoFuture<ModifyEventSubscriptionResponse, ModifyEventSubscriptionError> {
+        (**self).modify_event_subscription(input)
+    }
+    fn modify_replication_instance(
+        &self,
+        input: ModifyReplicationInstanceMessage,
+    ) -> RusotoFuture<ModifyReplicationInstanceResponse, ModifyReplicationInstanceError> {
+        (**self).modify_replication_instance(input)
+    }
+    fn modify_replication_subnet_group(
+        &self,
+        input: ModifyReplicationSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyReplicationSubnetGroupResponse, ModifyReplicationSubnetGroupError> {
+        (**self).modify_replication_subnet_group(input)
+    }
+    fn modify_replication_task(
+        &self,
+        input: ModifyReplicationTaskMessage,
+    ) -> RusotoFuture<ModifyReplicationTaskResponse, ModifyReplicationTaskError> {
+        (**self).modify_replication_task(input)
+    }
+    fn reboot_replication_instance(
+        &self,
+        input: RebootReplicationInstanceMessage,
+    ) -> RusotoFuture<RebootReplicationInstanceResponse, RebootReplicationInstanceError> {
+        (**self).reboot_replication_instance(input)
+    }
+    fn refresh_schemas(
+        &self,
+        input: RefreshSchemasMessage,
+    ) -> RusotoFuture<RefreshSchemasResponse, RefreshSchemasError> {
+        (**self).refresh_schemas(input)
+    }
+    fn reload_tables(
+        &self,
+        input: ReloadTablesMessage,
+    ) -> RusotoFuture<ReloadTablesResponse, ReloadTablesError> {
+        (**self).reload_tables(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<RemoveTagsFromResourceResponse, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn start_replication_task(
+        &self,
+        input: StartReplicationTaskMessage,
+    ) -> RusotoFuture<StartReplicationTaskResponse, StartReplicationTaskError> {
+        (**self).start_replication_task(input)
+    }
+    fn start_replication_task_assessment(
+        &self,
+        input: StartReplicationTaskAssessmentMessage,
+    ) -> RusotoFuture<StartReplicationTaskAssessmentResponse, StartReplicationTaskAssessmentError>
+    {
+        (**self).start_replication_task_assessment(input)
+    }
+    fn stop_replication_task(
+        &self,
+        input: StopReplicationTaskMessage,
+    ) -> RusotoFuture<StopReplicationTaskResponse, StopReplicationTaskError> {
+        (**self).stop_replication_task(input)
+    }
+    fn test_connection(
+        &self,
+        input: TestConnectionMessage,
+    ) -> RusotoFuture<TestConnectionResponse, TestConnectionError> {
+        (**self).test_connection(input)
+    }
+}
+/// The error codes AWS Database Migration Service retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Database Migration Service API.
 #[derive(Clone)]
 pub struct DatabaseMigrationServiceClient {
@@ -5218,6 +9459,24 @@ impl DatabaseMigrationServiceClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_DMS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> DatabaseMigrationServiceClient {
+        Self::new(region::Region::from_env_for_service("DMS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `dms`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> DatabaseMigrationServiceClient {
+        Self::new_with_client(config.client().clone(), config.region_for("dms", "DMS"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5239,7 +9498,49 @@ impl DatabaseMigrationServiceClient {
         client: Client,
         region: region::Region,
     ) -> DatabaseMigrationServiceClient {
-        DatabaseMigrationServiceClient { client, region }
+        DatabaseMigrationServiceClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "dms")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "dms", "dms", "DMS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `DatabaseMigrationService` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn DatabaseMigrationService + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for DatabaseMigrationServiceClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Database Migration Service"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "dms"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> DatabaseMigrationServiceClient {
+        Self::new_with_client(client, region)
     }
 }
 
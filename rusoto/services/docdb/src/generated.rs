@@ -28,6 +28,7 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -42,6 +43,48 @@ pub struct AddTagsToResourceMessage {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsToResourceMessage {
+    /// Returns a builder for `AddTagsToResourceMessage`, checking its required members when built.
+    pub fn builder() -> AddTagsToResourceMessageBuilder {
+        AddTagsToResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsToResourceMessage`](struct.AddTagsToResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct AddTagsToResourceMessageBuilder {
+    resource_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsToResourceMessageBuilder {
+    /// Sets the [`resource_name`](struct.AddTagsToResourceMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsToResourceMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsToResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddTagsToResourceMessage, ::rusoto_core::BuildError> {
+        Ok(AddTagsToResourceMessage {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `AddTagsToResourceMessage` contents to a `SignedRequest`.
 struct AddTagsToResourceMessageSerializer;
 impl AddTagsToResourceMessageSerializer {
@@ -56,17 +99,79 @@ impl AddTagsToResourceMessageSerializer {
     }
 }
 
-struct ApplyMethodDeserializer;
+pub(crate) struct ApplyMethodDeserializer;
 impl ApplyMethodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ApplyMethod, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>Indicates when to apply parameter updates.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApplyMethod {
+    /// <p><code>immediate</code></p>
+    Immediate,
+    /// <p><code>pending-reboot</code></p>
+    PendingReboot,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ApplyMethod {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplyMethod::Immediate => "immediate",
+            ApplyMethod::PendingReboot => "pending-reboot",
+            ApplyMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ApplyMethod {
+    fn from(value: &'a str) -> ApplyMethod {
+        match value {
+            "immediate" => ApplyMethod::Immediate,
+            "pending-reboot" => ApplyMethod::PendingReboot,
+            _ => ApplyMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplyMethod {
+    fn from(value: String) -> ApplyMethod {
+        ApplyMethod::from(value.as_str())
+    }
+}
+
+impl From<ApplyMethod> for String {
+    fn from(value: ApplyMethod) -> String {
+        match value {
+            ApplyMethod::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ApplyMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplyMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Represents the input to <a>ApplyPendingMaintenanceAction</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ApplyPendingMaintenanceActionMessage {
@@ -78,6 +183,59 @@ pub struct ApplyPendingMaintenanceActionMessage {
     pub resource_identifier: String,
 }
 
+impl ApplyPendingMaintenanceActionMessage {
+    /// Returns a builder for `ApplyPendingMaintenanceActionMessage`, checking its required members when built.
+    pub fn builder() -> ApplyPendingMaintenanceActionMessageBuilder {
+        ApplyPendingMaintenanceActionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ApplyPendingMaintenanceActionMessage`](struct.ApplyPendingMaintenanceActionMessage.html).
+#[derive(Default, Debug)]
+pub struct ApplyPendingMaintenanceActionMessageBuilder {
+    apply_action: Option<String>,
+    opt_in_type: Option<String>,
+    resource_identifier: Option<String>,
+}
+
+impl ApplyPendingMaintenanceActionMessageBuilder {
+    /// Sets the [`apply_action`](struct.ApplyPendingMaintenanceActionMessage.html#structfield.apply_action) member.
+    pub fn apply_action<T: Into<String>>(mut self, value: T) -> Self {
+        self.apply_action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`opt_in_type`](struct.ApplyPendingMaintenanceActionMessage.html#structfield.opt_in_type) member.
+    pub fn opt_in_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.opt_in_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_identifier`](struct.ApplyPendingMaintenanceActionMessage.html#structfield.resource_identifier) member.
+    pub fn resource_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `ApplyPendingMaintenanceActionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ApplyPendingMaintenanceActionMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ApplyPendingMaintenanceActionMessage {
+            apply_action: self
+                .apply_action
+                .ok_or(::rusoto_core::BuildError::missing("apply_action"))?,
+            opt_in_type: self
+                .opt_in_type
+                .ok_or(::rusoto_core::BuildError::missing("opt_in_type"))?,
+            resource_identifier: self
+                .resource_identifier
+                .ok_or(::rusoto_core::BuildError::missing("resource_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `ApplyPendingMaintenanceActionMessage` contents to a `SignedRequest`.
 struct ApplyPendingMaintenanceActionMessageSerializer;
 impl ApplyPendingMaintenanceActionMessageSerializer {
@@ -101,10 +259,10 @@ pub struct ApplyPendingMaintenanceActionResult {
     pub resource_pending_maintenance_actions: Option<ResourcePendingMaintenanceActions>,
 }
 
-struct ApplyPendingMaintenanceActionResultDeserializer;
+pub(crate) struct ApplyPendingMaintenanceActionResultDeserializer;
 impl ApplyPendingMaintenanceActionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplyPendingMaintenanceActionResult, XmlParseError> {
@@ -127,10 +285,10 @@ impl ApplyPendingMaintenanceActionResultDeserializer {
         )
     }
 }
-struct AttributeValueListDeserializer;
+pub(crate) struct AttributeValueListDeserializer;
 impl AttributeValueListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -163,10 +321,10 @@ pub struct AvailabilityZone {
     pub name: Option<String>,
 }
 
-struct AvailabilityZoneDeserializer;
+pub(crate) struct AvailabilityZoneDeserializer;
 impl AvailabilityZoneDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AvailabilityZone, XmlParseError> {
@@ -181,10 +339,10 @@ impl AvailabilityZoneDeserializer {
         })
     }
 }
-struct AvailabilityZoneListDeserializer;
+pub(crate) struct AvailabilityZoneListDeserializer;
 impl AvailabilityZoneListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AvailabilityZone>, XmlParseError> {
@@ -201,10 +359,10 @@ impl AvailabilityZoneListDeserializer {
         })
     }
 }
-struct AvailabilityZonesDeserializer;
+pub(crate) struct AvailabilityZonesDeserializer;
 impl AvailabilityZonesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -230,10 +388,13 @@ impl AvailabilityZonesSerializer {
     }
 }
 
-struct BooleanDeserializer;
+pub(crate) struct BooleanDeserializer;
 impl BooleanDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -241,10 +402,13 @@ impl BooleanDeserializer {
         Ok(obj)
     }
 }
-struct BooleanOptionalDeserializer;
+pub(crate) struct BooleanOptionalDeserializer;
 impl BooleanOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -300,6 +464,81 @@ pub struct CopyDBClusterParameterGroupMessage {
     pub target_db_cluster_parameter_group_identifier: String,
 }
 
+impl CopyDBClusterParameterGroupMessage {
+    /// Returns a builder for `CopyDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> CopyDBClusterParameterGroupMessageBuilder {
+        CopyDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CopyDBClusterParameterGroupMessage`](struct.CopyDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CopyDBClusterParameterGroupMessageBuilder {
+    source_db_cluster_parameter_group_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_db_cluster_parameter_group_description: Option<String>,
+    target_db_cluster_parameter_group_identifier: Option<String>,
+}
+
+impl CopyDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`source_db_cluster_parameter_group_identifier`](struct.CopyDBClusterParameterGroupMessage.html#structfield.source_db_cluster_parameter_group_identifier) member.
+    pub fn source_db_cluster_parameter_group_identifier<T: Into<String>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.source_db_cluster_parameter_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CopyDBClusterParameterGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_cluster_parameter_group_description`](struct.CopyDBClusterParameterGroupMessage.html#structfield.target_db_cluster_parameter_group_description) member.
+    pub fn target_db_cluster_parameter_group_description<T: Into<String>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.target_db_cluster_parameter_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_cluster_parameter_group_identifier`](struct.CopyDBClusterParameterGroupMessage.html#structfield.target_db_cluster_parameter_group_identifier) member.
+    pub fn target_db_cluster_parameter_group_identifier<T: Into<String>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.target_db_cluster_parameter_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CopyDBClusterParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CopyDBClusterParameterGroupMessage {
+            source_db_cluster_parameter_group_identifier: self
+                .source_db_cluster_parameter_group_identifier
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "source_db_cluster_parameter_group_identifier",
+                ))?,
+            tags: self.tags,
+            target_db_cluster_parameter_group_description: self
+                .target_db_cluster_parameter_group_description
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "target_db_cluster_parameter_group_description",
+                ))?,
+            target_db_cluster_parameter_group_identifier: self
+                .target_db_cluster_parameter_group_identifier
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "target_db_cluster_parameter_group_identifier",
+                ))?,
+        })
+    }
+}
+
 /// Serialize `CopyDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct CopyDBClusterParameterGroupMessageSerializer;
 impl CopyDBClusterParameterGroupMessageSerializer {
@@ -332,10 +571,10 @@ pub struct CopyDBClusterParameterGroupResult {
     pub db_cluster_parameter_group: Option<DBClusterParameterGroup>,
 }
 
-struct CopyDBClusterParameterGroupResultDeserializer;
+pub(crate) struct CopyDBClusterParameterGroupResultDeserializer;
 impl CopyDBClusterParameterGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CopyDBClusterParameterGroupResult, XmlParseError> {
@@ -375,6 +614,84 @@ pub struct CopyDBClusterSnapshotMessage {
     pub target_db_cluster_snapshot_identifier: String,
 }
 
+impl CopyDBClusterSnapshotMessage {
+    /// Returns a builder for `CopyDBClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> CopyDBClusterSnapshotMessageBuilder {
+        CopyDBClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CopyDBClusterSnapshotMessage`](struct.CopyDBClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct CopyDBClusterSnapshotMessageBuilder {
+    copy_tags: Option<bool>,
+    kms_key_id: Option<String>,
+    pre_signed_url: Option<String>,
+    source_db_cluster_snapshot_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_db_cluster_snapshot_identifier: Option<String>,
+}
+
+impl CopyDBClusterSnapshotMessageBuilder {
+    /// Sets the [`copy_tags`](struct.CopyDBClusterSnapshotMessage.html#structfield.copy_tags) member.
+    pub fn copy_tags<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CopyDBClusterSnapshotMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pre_signed_url`](struct.CopyDBClusterSnapshotMessage.html#structfield.pre_signed_url) member.
+    pub fn pre_signed_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.pre_signed_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_db_cluster_snapshot_identifier`](struct.CopyDBClusterSnapshotMessage.html#structfield.source_db_cluster_snapshot_identifier) member.
+    pub fn source_db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CopyDBClusterSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_cluster_snapshot_identifier`](struct.CopyDBClusterSnapshotMessage.html#structfield.target_db_cluster_snapshot_identifier) member.
+    pub fn target_db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyDBClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CopyDBClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(CopyDBClusterSnapshotMessage {
+            copy_tags: self.copy_tags,
+            kms_key_id: self.kms_key_id,
+            pre_signed_url: self.pre_signed_url,
+            source_db_cluster_snapshot_identifier: self
+                .source_db_cluster_snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "source_db_cluster_snapshot_identifier",
+                ))?,
+            tags: self.tags,
+            target_db_cluster_snapshot_identifier: self
+                .target_db_cluster_snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "target_db_cluster_snapshot_identifier",
+                ))?,
+        })
+    }
+}
+
 /// Serialize `CopyDBClusterSnapshotMessage` contents to a `SignedRequest`.
 struct CopyDBClusterSnapshotMessageSerializer;
 impl CopyDBClusterSnapshotMessageSerializer {
@@ -412,10 +729,10 @@ pub struct CopyDBClusterSnapshotResult {
     pub db_cluster_snapshot: Option<DBClusterSnapshot>,
 }
 
-struct CopyDBClusterSnapshotResultDeserializer;
+pub(crate) struct CopyDBClusterSnapshotResultDeserializer;
 impl CopyDBClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CopyDBClusterSnapshotResult, XmlParseError> {
@@ -478,6 +795,178 @@ pub struct CreateDBClusterMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl CreateDBClusterMessage {
+    /// Returns a builder for `CreateDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBClusterMessageBuilder {
+        CreateDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBClusterMessage`](struct.CreateDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBClusterMessageBuilder {
+    availability_zones: Option<Vec<String>>,
+    backup_retention_period: Option<i64>,
+    db_cluster_identifier: Option<String>,
+    db_cluster_parameter_group_name: Option<String>,
+    db_subnet_group_name: Option<String>,
+    deletion_protection: Option<bool>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    kms_key_id: Option<String>,
+    master_user_password: Option<String>,
+    master_username: Option<String>,
+    port: Option<i64>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    storage_encrypted: Option<bool>,
+    tags: Option<Vec<Tag>>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl CreateDBClusterMessageBuilder {
+    /// Sets the [`availability_zones`](struct.CreateDBClusterMessage.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_period`](struct.CreateDBClusterMessage.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.CreateDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_parameter_group_name`](struct.CreateDBClusterMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.CreateDBClusterMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.CreateDBClusterMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.CreateDBClusterMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.CreateDBClusterMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.CreateDBClusterMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateDBClusterMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.CreateDBClusterMessage.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_username`](struct.CreateDBClusterMessage.html#structfield.master_username) member.
+    pub fn master_username<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_username = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.CreateDBClusterMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.CreateDBClusterMessage.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateDBClusterMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_encrypted`](struct.CreateDBClusterMessage.html#structfield.storage_encrypted) member.
+    pub fn storage_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.storage_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBClusterMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.CreateDBClusterMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBClusterMessage {
+            availability_zones: self.availability_zones,
+            backup_retention_period: self.backup_retention_period,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            db_subnet_group_name: self.db_subnet_group_name,
+            deletion_protection: self.deletion_protection,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_version: self.engine_version,
+            kms_key_id: self.kms_key_id,
+            master_user_password: self
+                .master_user_password
+                .ok_or(::rusoto_core::BuildError::missing("master_user_password"))?,
+            master_username: self
+                .master_username
+                .ok_or(::rusoto_core::BuildError::missing("master_username"))?,
+            port: self.port,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            storage_encrypted: self.storage_encrypted,
+            tags: self.tags,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `CreateDBClusterMessage` contents to a `SignedRequest`.
 struct CreateDBClusterMessageSerializer;
 impl CreateDBClusterMessageSerializer {
@@ -582,6 +1071,67 @@ pub struct CreateDBClusterParameterGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBClusterParameterGroupMessage {
+    /// Returns a builder for `CreateDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBClusterParameterGroupMessageBuilder {
+        CreateDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBClusterParameterGroupMessage`](struct.CreateDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBClusterParameterGroupMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    db_parameter_group_family: Option<String>,
+    description: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.CreateDBClusterParameterGroupMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_family`](struct.CreateDBClusterParameterGroupMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateDBClusterParameterGroupMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBClusterParameterGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBClusterParameterGroupMessage, ::rusoto_core::BuildError>
+    {
+        Ok(CreateDBClusterParameterGroupMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+            db_parameter_group_family: self.db_parameter_group_family.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_family"),
+            )?,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct CreateDBClusterParameterGroupMessageSerializer;
 impl CreateDBClusterParameterGroupMessageSerializer {
@@ -611,10 +1161,10 @@ pub struct CreateDBClusterParameterGroupResult {
     pub db_cluster_parameter_group: Option<DBClusterParameterGroup>,
 }
 
-struct CreateDBClusterParameterGroupResultDeserializer;
+pub(crate) struct CreateDBClusterParameterGroupResultDeserializer;
 impl CreateDBClusterParameterGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBClusterParameterGroupResult, XmlParseError> {
@@ -642,10 +1192,10 @@ pub struct CreateDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct CreateDBClusterResultDeserializer;
+pub(crate) struct CreateDBClusterResultDeserializer;
 impl CreateDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBClusterResult, XmlParseError> {
@@ -671,6 +1221,56 @@ pub struct CreateDBClusterSnapshotMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBClusterSnapshotMessage {
+    /// Returns a builder for `CreateDBClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBClusterSnapshotMessageBuilder {
+        CreateDBClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBClusterSnapshotMessage`](struct.CreateDBClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBClusterSnapshotMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    db_cluster_snapshot_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBClusterSnapshotMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.CreateDBClusterSnapshotMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.CreateDBClusterSnapshotMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBClusterSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBClusterSnapshotMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_snapshot_identifier"),
+            )?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBClusterSnapshotMessage` contents to a `SignedRequest`.
 struct CreateDBClusterSnapshotMessageSerializer;
 impl CreateDBClusterSnapshotMessageSerializer {
@@ -699,10 +1299,10 @@ pub struct CreateDBClusterSnapshotResult {
     pub db_cluster_snapshot: Option<DBClusterSnapshot>,
 }
 
-struct CreateDBClusterSnapshotResultDeserializer;
+pub(crate) struct CreateDBClusterSnapshotResultDeserializer;
 impl CreateDBClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBClusterSnapshotResult, XmlParseError> {
@@ -747,6 +1347,108 @@ pub struct CreateDBInstanceMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBInstanceMessage {
+    /// Returns a builder for `CreateDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBInstanceMessageBuilder {
+        CreateDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBInstanceMessage`](struct.CreateDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBInstanceMessageBuilder {
+    auto_minor_version_upgrade: Option<bool>,
+    availability_zone: Option<String>,
+    db_cluster_identifier: Option<String>,
+    db_instance_class: Option<String>,
+    db_instance_identifier: Option<String>,
+    engine: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    promotion_tier: Option<i64>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBInstanceMessageBuilder {
+    /// Sets the [`auto_minor_version_upgrade`](struct.CreateDBInstanceMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zone`](struct.CreateDBInstanceMessage.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.CreateDBInstanceMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_class`](struct.CreateDBInstanceMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_identifier`](struct.CreateDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.CreateDBInstanceMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateDBInstanceMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`promotion_tier`](struct.CreateDBInstanceMessage.html#structfield.promotion_tier) member.
+    pub fn promotion_tier<T: Into<i64>>(mut self, value: T) -> Self {
+        self.promotion_tier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBInstanceMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBInstanceMessage {
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            availability_zone: self.availability_zone,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_instance_class: self
+                .db_instance_class
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_class"))?,
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            promotion_tier: self.promotion_tier,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBInstanceMessage` contents to a `SignedRequest`.
 struct CreateDBInstanceMessageSerializer;
 impl CreateDBInstanceMessageSerializer {
@@ -798,10 +1500,10 @@ pub struct CreateDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct CreateDBInstanceResultDeserializer;
+pub(crate) struct CreateDBInstanceResultDeserializer;
 impl CreateDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBInstanceResult, XmlParseError> {
@@ -830,6 +1532,66 @@ pub struct CreateDBSubnetGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBSubnetGroupMessage {
+    /// Returns a builder for `CreateDBSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBSubnetGroupMessageBuilder {
+        CreateDBSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBSubnetGroupMessage`](struct.CreateDBSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBSubnetGroupMessageBuilder {
+    db_subnet_group_description: Option<String>,
+    db_subnet_group_name: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBSubnetGroupMessageBuilder {
+    /// Sets the [`db_subnet_group_description`](struct.CreateDBSubnetGroupMessage.html#structfield.db_subnet_group_description) member.
+    pub fn db_subnet_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.CreateDBSubnetGroupMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.CreateDBSubnetGroupMessage.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBSubnetGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBSubnetGroupMessage {
+            db_subnet_group_description: self.db_subnet_group_description.ok_or(
+                ::rusoto_core::BuildError::missing("db_subnet_group_description"),
+            )?,
+            db_subnet_group_name: self
+                .db_subnet_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_subnet_group_name"))?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBSubnetGroupMessage` contents to a `SignedRequest`.
 struct CreateDBSubnetGroupMessageSerializer;
 impl CreateDBSubnetGroupMessageSerializer {
@@ -863,10 +1625,10 @@ pub struct CreateDBSubnetGroupResult {
     pub db_subnet_group: Option<DBSubnetGroup>,
 }
 
-struct CreateDBSubnetGroupResultDeserializer;
+pub(crate) struct CreateDBSubnetGroupResultDeserializer;
 impl CreateDBSubnetGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBSubnetGroupResult, XmlParseError> {
@@ -898,7 +1660,7 @@ pub struct DBCluster {
     /// <p>Specifies the number of days for which automatic DB snapshots are retained.</p>
     pub backup_retention_period: Option<i64>,
     /// <p>Specifies the time when the DB cluster was created, in Universal Coordinated Time (UTC).</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<IsoTimestamp>,
     /// <p>The Amazon Resource Name (ARN) for the DB cluster.</p>
     pub db_cluster_arn: Option<String>,
     /// <p>Contains a user-supplied DB cluster identifier. This identifier is the unique key that identifies a DB cluster.</p>
@@ -914,7 +1676,7 @@ pub struct DBCluster {
     /// <p>Specifies whether this cluster can be deleted. If <code>DeletionProtection</code> is enabled, the cluster cannot be deleted unless it is modified and <code>DeletionProtection</code> is disabled. <code>DeletionProtection</code> protects clusters from being accidentally deleted.</p>
     pub deletion_protection: Option<bool>,
     /// <p>The earliest time to which a database can be restored with point-in-time restore.</p>
-    pub earliest_restorable_time: Option<String>,
+    pub earliest_restorable_time: Option<IsoTimestamp>,
     /// <p>A list of log types that this DB cluster is configured to export to Amazon CloudWatch Logs.</p>
     pub enabled_cloudwatch_logs_exports: Option<Vec<String>>,
     /// <p>Specifies the connection endpoint for the primary instance of the DB cluster.</p>
@@ -928,7 +1690,7 @@ pub struct DBCluster {
     /// <p>If <code>StorageEncrypted</code> is <code>true</code>, the AWS KMS key identifier for the encrypted DB cluster.</p>
     pub kms_key_id: Option<String>,
     /// <p>Specifies the latest time to which a database can be restored with point-in-time restore.</p>
-    pub latest_restorable_time: Option<String>,
+    pub latest_restorable_time: Option<IsoTimestamp>,
     /// <p>Contains the master user name for the DB cluster.</p>
     pub master_username: Option<String>,
     /// <p>Specifies whether the DB cluster has instances in multiple Availability Zones.</p>
@@ -951,10 +1713,10 @@ pub struct DBCluster {
     pub vpc_security_groups: Option<Vec<VpcSecurityGroupMembership>>,
 }
 
-struct DBClusterDeserializer;
+pub(crate) struct DBClusterDeserializer;
 impl DBClusterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBCluster, XmlParseError> {
@@ -1105,10 +1867,10 @@ impl DBClusterDeserializer {
         })
     }
 }
-struct DBClusterListDeserializer;
+pub(crate) struct DBClusterListDeserializer;
 impl DBClusterListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBCluster>, XmlParseError> {
@@ -1135,10 +1897,10 @@ pub struct DBClusterMember {
     pub promotion_tier: Option<i64>,
 }
 
-struct DBClusterMemberDeserializer;
+pub(crate) struct DBClusterMemberDeserializer;
 impl DBClusterMemberDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterMember, XmlParseError> {
@@ -1172,10 +1934,10 @@ impl DBClusterMemberDeserializer {
         })
     }
 }
-struct DBClusterMemberListDeserializer;
+pub(crate) struct DBClusterMemberListDeserializer;
 impl DBClusterMemberListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterMember>, XmlParseError> {
@@ -1201,10 +1963,10 @@ pub struct DBClusterMessage {
     pub marker: Option<String>,
 }
 
-struct DBClusterMessageDeserializer;
+pub(crate) struct DBClusterMessageDeserializer;
 impl DBClusterMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterMessage, XmlParseError> {
@@ -1237,10 +1999,10 @@ pub struct DBClusterParameterGroup {
     pub description: Option<String>,
 }
 
-struct DBClusterParameterGroupDeserializer;
+pub(crate) struct DBClusterParameterGroupDeserializer;
 impl DBClusterParameterGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterParameterGroup, XmlParseError> {
@@ -1286,10 +2048,10 @@ pub struct DBClusterParameterGroupDetails {
     pub parameters: Option<Vec<Parameter>>,
 }
 
-struct DBClusterParameterGroupDetailsDeserializer;
+pub(crate) struct DBClusterParameterGroupDetailsDeserializer;
 impl DBClusterParameterGroupDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterParameterGroupDetails, XmlParseError> {
@@ -1313,10 +2075,10 @@ impl DBClusterParameterGroupDetailsDeserializer {
         )
     }
 }
-struct DBClusterParameterGroupListDeserializer;
+pub(crate) struct DBClusterParameterGroupListDeserializer;
 impl DBClusterParameterGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterParameterGroup>, XmlParseError> {
@@ -1340,10 +2102,10 @@ pub struct DBClusterParameterGroupNameMessage {
     pub db_cluster_parameter_group_name: Option<String>,
 }
 
-struct DBClusterParameterGroupNameMessageDeserializer;
+pub(crate) struct DBClusterParameterGroupNameMessageDeserializer;
 impl DBClusterParameterGroupNameMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterParameterGroupNameMessage, XmlParseError> {
@@ -1373,10 +2135,10 @@ pub struct DBClusterParameterGroupsMessage {
     pub marker: Option<String>,
 }
 
-struct DBClusterParameterGroupsMessageDeserializer;
+pub(crate) struct DBClusterParameterGroupsMessageDeserializer;
 impl DBClusterParameterGroupsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterParameterGroupsMessage, XmlParseError> {
@@ -1412,10 +2174,10 @@ pub struct DBClusterRole {
     pub status: Option<String>,
 }
 
-struct DBClusterRoleDeserializer;
+pub(crate) struct DBClusterRoleDeserializer;
 impl DBClusterRoleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterRole, XmlParseError> {
@@ -1433,10 +2195,10 @@ impl DBClusterRoleDeserializer {
         })
     }
 }
-struct DBClusterRolesDeserializer;
+pub(crate) struct DBClusterRolesDeserializer;
 impl DBClusterRolesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterRole>, XmlParseError> {
@@ -1459,7 +2221,7 @@ pub struct DBClusterSnapshot {
     /// <p>Provides the list of Amazon EC2 Availability Zones that instances in the DB cluster snapshot can be restored in.</p>
     pub availability_zones: Option<Vec<String>>,
     /// <p>Specifies the time when the DB cluster was created, in Universal Coordinated Time (UTC).</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<IsoTimestamp>,
     /// <p>Specifies the DB cluster identifier of the DB cluster that this DB cluster snapshot was created from.</p>
     pub db_cluster_identifier: Option<String>,
     /// <p>The Amazon Resource Name (ARN) for the DB cluster snapshot.</p>
@@ -1479,7 +2241,7 @@ pub struct DBClusterSnapshot {
     /// <p>Specifies the port that the DB cluster was listening on at the time of the snapshot.</p>
     pub port: Option<i64>,
     /// <p>Provides the time when the snapshot was taken, in UTC.</p>
-    pub snapshot_create_time: Option<String>,
+    pub snapshot_create_time: Option<IsoTimestamp>,
     /// <p>Provides the type of the DB cluster snapshot.</p>
     pub snapshot_type: Option<String>,
     /// <p>If the DB cluster snapshot was copied from a source DB cluster snapshot, the ARN for the source DB cluster snapshot; otherwise, a null value.</p>
@@ -1492,10 +2254,10 @@ pub struct DBClusterSnapshot {
     pub vpc_id: Option<String>,
 }
 
-struct DBClusterSnapshotDeserializer;
+pub(crate) struct DBClusterSnapshotDeserializer;
 impl DBClusterSnapshotDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterSnapshot, XmlParseError> {
@@ -1590,10 +2352,10 @@ pub struct DBClusterSnapshotAttribute {
     pub attribute_values: Option<Vec<String>>,
 }
 
-struct DBClusterSnapshotAttributeDeserializer;
+pub(crate) struct DBClusterSnapshotAttributeDeserializer;
 impl DBClusterSnapshotAttributeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterSnapshotAttribute, XmlParseError> {
@@ -1618,10 +2380,10 @@ impl DBClusterSnapshotAttributeDeserializer {
         )
     }
 }
-struct DBClusterSnapshotAttributeListDeserializer;
+pub(crate) struct DBClusterSnapshotAttributeListDeserializer;
 impl DBClusterSnapshotAttributeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterSnapshotAttribute>, XmlParseError> {
@@ -1647,10 +2409,10 @@ pub struct DBClusterSnapshotAttributesResult {
     pub db_cluster_snapshot_identifier: Option<String>,
 }
 
-struct DBClusterSnapshotAttributesResultDeserializer;
+pub(crate) struct DBClusterSnapshotAttributesResultDeserializer;
 impl DBClusterSnapshotAttributesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterSnapshotAttributesResult, XmlParseError> {
@@ -1680,10 +2442,10 @@ impl DBClusterSnapshotAttributesResultDeserializer {
         )
     }
 }
-struct DBClusterSnapshotListDeserializer;
+pub(crate) struct DBClusterSnapshotListDeserializer;
 impl DBClusterSnapshotListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterSnapshot>, XmlParseError> {
@@ -1709,10 +2471,10 @@ pub struct DBClusterSnapshotMessage {
     pub marker: Option<String>,
 }
 
-struct DBClusterSnapshotMessageDeserializer;
+pub(crate) struct DBClusterSnapshotMessageDeserializer;
 impl DBClusterSnapshotMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterSnapshotMessage, XmlParseError> {
@@ -1760,10 +2522,10 @@ pub struct DBEngineVersion {
     pub valid_upgrade_target: Option<Vec<UpgradeTarget>>,
 }
 
-struct DBEngineVersionDeserializer;
+pub(crate) struct DBEngineVersionDeserializer;
 impl DBEngineVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBEngineVersion, XmlParseError> {
@@ -1820,10 +2582,10 @@ impl DBEngineVersionDeserializer {
         })
     }
 }
-struct DBEngineVersionListDeserializer;
+pub(crate) struct DBEngineVersionListDeserializer;
 impl DBEngineVersionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBEngineVersion>, XmlParseError> {
@@ -1849,10 +2611,10 @@ pub struct DBEngineVersionMessage {
     pub marker: Option<String>,
 }
 
-struct DBEngineVersionMessageDeserializer;
+pub(crate) struct DBEngineVersionMessageDeserializer;
 impl DBEngineVersionMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBEngineVersionMessage, XmlParseError> {
@@ -1904,11 +2666,11 @@ pub struct DBInstance {
     /// <p>Indicates the database engine version.</p>
     pub engine_version: Option<String>,
     /// <p>Provides the date and time that the DB instance was created.</p>
-    pub instance_create_time: Option<String>,
+    pub instance_create_time: Option<IsoTimestamp>,
     /// <p> If <code>StorageEncrypted</code> is <code>true</code>, the AWS KMS key identifier for the encrypted DB instance. </p>
     pub kms_key_id: Option<String>,
     /// <p>Specifies the latest time to which a database can be restored with point-in-time restore.</p>
-    pub latest_restorable_time: Option<String>,
+    pub latest_restorable_time: Option<IsoTimestamp>,
     /// <p>Specifies that changes to the DB instance are pending. This element is included only when changes are pending. Specific changes are identified by subelements.</p>
     pub pending_modified_values: Option<PendingModifiedValues>,
     /// <p> Specifies the daily time range during which automated backups are created if automated backups are enabled, as determined by the <code>BackupRetentionPeriod</code>. </p>
@@ -1927,10 +2689,10 @@ pub struct DBInstance {
     pub vpc_security_groups: Option<Vec<VpcSecurityGroupMembership>>,
 }
 
-struct DBInstanceDeserializer;
+pub(crate) struct DBInstanceDeserializer;
 impl DBInstanceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBInstance, XmlParseError> {
@@ -2073,10 +2835,10 @@ impl DBInstanceDeserializer {
         })
     }
 }
-struct DBInstanceListDeserializer;
+pub(crate) struct DBInstanceListDeserializer;
 impl DBInstanceListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBInstance>, XmlParseError> {
@@ -2099,10 +2861,10 @@ pub struct DBInstanceMessage {
     pub marker: Option<String>,
 }
 
-struct DBInstanceMessageDeserializer;
+pub(crate) struct DBInstanceMessageDeserializer;
 impl DBInstanceMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBInstanceMessage, XmlParseError> {
@@ -2135,10 +2897,10 @@ pub struct DBInstanceStatusInfo {
     pub status_type: Option<String>,
 }
 
-struct DBInstanceStatusInfoDeserializer;
+pub(crate) struct DBInstanceStatusInfoDeserializer;
 impl DBInstanceStatusInfoDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBInstanceStatusInfo, XmlParseError> {
@@ -2162,10 +2924,10 @@ impl DBInstanceStatusInfoDeserializer {
         })
     }
 }
-struct DBInstanceStatusInfoListDeserializer;
+pub(crate) struct DBInstanceStatusInfoListDeserializer;
 impl DBInstanceStatusInfoListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBInstanceStatusInfo>, XmlParseError> {
@@ -2199,10 +2961,10 @@ pub struct DBSubnetGroup {
     pub vpc_id: Option<String>,
 }
 
-struct DBSubnetGroupDeserializer;
+pub(crate) struct DBSubnetGroupDeserializer;
 impl DBSubnetGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSubnetGroup, XmlParseError> {
@@ -2249,10 +3011,10 @@ pub struct DBSubnetGroupMessage {
     pub marker: Option<String>,
 }
 
-struct DBSubnetGroupMessageDeserializer;
+pub(crate) struct DBSubnetGroupMessageDeserializer;
 impl DBSubnetGroupMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSubnetGroupMessage, XmlParseError> {
@@ -2272,10 +3034,10 @@ impl DBSubnetGroupMessageDeserializer {
         })
     }
 }
-struct DBSubnetGroupsDeserializer;
+pub(crate) struct DBSubnetGroupsDeserializer;
 impl DBSubnetGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBSubnetGroup>, XmlParseError> {
@@ -2303,6 +3065,52 @@ pub struct DeleteDBClusterMessage {
     pub skip_final_snapshot: Option<bool>,
 }
 
+impl DeleteDBClusterMessage {
+    /// Returns a builder for `DeleteDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBClusterMessageBuilder {
+        DeleteDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBClusterMessage`](struct.DeleteDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    final_db_snapshot_identifier: Option<String>,
+    skip_final_snapshot: Option<bool>,
+}
+
+impl DeleteDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.DeleteDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`final_db_snapshot_identifier`](struct.DeleteDBClusterMessage.html#structfield.final_db_snapshot_identifier) member.
+    pub fn final_db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.final_db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skip_final_snapshot`](struct.DeleteDBClusterMessage.html#structfield.skip_final_snapshot) member.
+    pub fn skip_final_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.skip_final_snapshot = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            final_db_snapshot_identifier: self.final_db_snapshot_identifier,
+            skip_final_snapshot: self.skip_final_snapshot,
+        })
+    }
+}
+
 /// Serialize `DeleteDBClusterMessage` contents to a `SignedRequest`.
 struct DeleteDBClusterMessageSerializer;
 impl DeleteDBClusterMessageSerializer {
@@ -2335,6 +3143,39 @@ pub struct DeleteDBClusterParameterGroupMessage {
     pub db_cluster_parameter_group_name: String,
 }
 
+impl DeleteDBClusterParameterGroupMessage {
+    /// Returns a builder for `DeleteDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBClusterParameterGroupMessageBuilder {
+        DeleteDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBClusterParameterGroupMessage`](struct.DeleteDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBClusterParameterGroupMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+}
+
+impl DeleteDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.DeleteDBClusterParameterGroupMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBClusterParameterGroupMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteDBClusterParameterGroupMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct DeleteDBClusterParameterGroupMessageSerializer;
 impl DeleteDBClusterParameterGroupMessageSerializer {
@@ -2356,10 +3197,10 @@ pub struct DeleteDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct DeleteDBClusterResultDeserializer;
+pub(crate) struct DeleteDBClusterResultDeserializer;
 impl DeleteDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDBClusterResult, XmlParseError> {
@@ -2381,6 +3222,38 @@ pub struct DeleteDBClusterSnapshotMessage {
     pub db_cluster_snapshot_identifier: String,
 }
 
+impl DeleteDBClusterSnapshotMessage {
+    /// Returns a builder for `DeleteDBClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBClusterSnapshotMessageBuilder {
+        DeleteDBClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBClusterSnapshotMessage`](struct.DeleteDBClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBClusterSnapshotMessageBuilder {
+    db_cluster_snapshot_identifier: Option<String>,
+}
+
+impl DeleteDBClusterSnapshotMessageBuilder {
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.DeleteDBClusterSnapshotMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBClusterSnapshotMessage {
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_snapshot_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBClusterSnapshotMessage` contents to a `SignedRequest`.
 struct DeleteDBClusterSnapshotMessageSerializer;
 impl DeleteDBClusterSnapshotMessageSerializer {
@@ -2402,10 +3275,10 @@ pub struct DeleteDBClusterSnapshotResult {
     pub db_cluster_snapshot: Option<DBClusterSnapshot>,
 }
 
-struct DeleteDBClusterSnapshotResultDeserializer;
+pub(crate) struct DeleteDBClusterSnapshotResultDeserializer;
 impl DeleteDBClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDBClusterSnapshotResult, XmlParseError> {
@@ -2434,6 +3307,38 @@ pub struct DeleteDBInstanceMessage {
     pub db_instance_identifier: String,
 }
 
+impl DeleteDBInstanceMessage {
+    /// Returns a builder for `DeleteDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBInstanceMessageBuilder {
+        DeleteDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBInstanceMessage`](struct.DeleteDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBInstanceMessageBuilder {
+    db_instance_identifier: Option<String>,
+}
+
+impl DeleteDBInstanceMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.DeleteDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBInstanceMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBInstanceMessage` contents to a `SignedRequest`.
 struct DeleteDBInstanceMessageSerializer;
 impl DeleteDBInstanceMessageSerializer {
@@ -2455,10 +3360,10 @@ pub struct DeleteDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct DeleteDBInstanceResultDeserializer;
+pub(crate) struct DeleteDBInstanceResultDeserializer;
 impl DeleteDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDBInstanceResult, XmlParseError> {
@@ -2481,7 +3386,39 @@ pub struct DeleteDBSubnetGroupMessage {
     pub db_subnet_group_name: String,
 }
 
-/// Serialize `DeleteDBSubnetGroupMessage` contents to a `SignedRequest`.
+impl DeleteDBSubnetGroupMessage {
+    /// Returns a builder for `DeleteDBSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBSubnetGroupMessageBuilder {
+        DeleteDBSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBSubnetGroupMessage`](struct.DeleteDBSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBSubnetGroupMessageBuilder {
+    db_subnet_group_name: Option<String>,
+}
+
+impl DeleteDBSubnetGroupMessageBuilder {
+    /// Sets the [`db_subnet_group_name`](struct.DeleteDBSubnetGroupMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBSubnetGroupMessage {
+            db_subnet_group_name: self
+                .db_subnet_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_subnet_group_name"))?,
+        })
+    }
+}
+
+/// Serialize `DeleteDBSubnetGroupMessage` contents to a `SignedRequest`.
 struct DeleteDBSubnetGroupMessageSerializer;
 impl DeleteDBSubnetGroupMessageSerializer {
     fn serialize(params: &mut Params, name: &str, obj: &DeleteDBSubnetGroupMessage) {
@@ -2510,6 +3447,61 @@ pub struct DescribeDBClusterParameterGroupsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBClusterParameterGroupsMessage {
+    /// Returns a builder for `DescribeDBClusterParameterGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterParameterGroupsMessageBuilder {
+        DescribeDBClusterParameterGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterParameterGroupsMessage`](struct.DescribeDBClusterParameterGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterParameterGroupsMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBClusterParameterGroupsMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.DescribeDBClusterParameterGroupsMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClusterParameterGroupsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClusterParameterGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClusterParameterGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterParameterGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterParameterGroupsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDBClusterParameterGroupsMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterParameterGroupsMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterParameterGroupsMessageSerializer;
 impl DescribeDBClusterParameterGroupsMessageSerializer {
@@ -2556,6 +3548,70 @@ pub struct DescribeDBClusterParametersMessage {
     pub source: Option<String>,
 }
 
+impl DescribeDBClusterParametersMessage {
+    /// Returns a builder for `DescribeDBClusterParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterParametersMessageBuilder {
+        DescribeDBClusterParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterParametersMessage`](struct.DescribeDBClusterParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterParametersMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    source: Option<String>,
+}
+
+impl DescribeDBClusterParametersMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.DescribeDBClusterParametersMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClusterParametersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClusterParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClusterParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.DescribeDBClusterParametersMessage.html#structfield.source) member.
+    pub fn source<T: Into<String>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterParametersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBClusterParametersMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            source: self.source,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterParametersMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterParametersMessageSerializer;
 impl DescribeDBClusterParametersMessageSerializer {
@@ -2595,6 +3651,39 @@ pub struct DescribeDBClusterSnapshotAttributesMessage {
     pub db_cluster_snapshot_identifier: String,
 }
 
+impl DescribeDBClusterSnapshotAttributesMessage {
+    /// Returns a builder for `DescribeDBClusterSnapshotAttributesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterSnapshotAttributesMessageBuilder {
+        DescribeDBClusterSnapshotAttributesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterSnapshotAttributesMessage`](struct.DescribeDBClusterSnapshotAttributesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterSnapshotAttributesMessageBuilder {
+    db_cluster_snapshot_identifier: Option<String>,
+}
+
+impl DescribeDBClusterSnapshotAttributesMessageBuilder {
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.DescribeDBClusterSnapshotAttributesMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterSnapshotAttributesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterSnapshotAttributesMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDBClusterSnapshotAttributesMessage {
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_snapshot_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterSnapshotAttributesMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterSnapshotAttributesMessageSerializer;
 impl DescribeDBClusterSnapshotAttributesMessageSerializer {
@@ -2620,10 +3709,10 @@ pub struct DescribeDBClusterSnapshotAttributesResult {
     pub db_cluster_snapshot_attributes_result: Option<DBClusterSnapshotAttributesResult>,
 }
 
-struct DescribeDBClusterSnapshotAttributesResultDeserializer;
+pub(crate) struct DescribeDBClusterSnapshotAttributesResultDeserializer;
 impl DescribeDBClusterSnapshotAttributesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeDBClusterSnapshotAttributesResult, XmlParseError> {
@@ -2667,6 +3756,92 @@ pub struct DescribeDBClusterSnapshotsMessage {
     pub snapshot_type: Option<String>,
 }
 
+impl DescribeDBClusterSnapshotsMessage {
+    /// Returns a builder for `DescribeDBClusterSnapshotsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterSnapshotsMessageBuilder {
+        DescribeDBClusterSnapshotsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterSnapshotsMessage`](struct.DescribeDBClusterSnapshotsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterSnapshotsMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    db_cluster_snapshot_identifier: Option<String>,
+    filters: Option<Vec<Filter>>,
+    include_public: Option<bool>,
+    include_shared: Option<bool>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    snapshot_type: Option<String>,
+}
+
+impl DescribeDBClusterSnapshotsMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_public`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.include_public) member.
+    pub fn include_public<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_public = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_shared`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.include_shared) member.
+    pub fn include_shared<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_shared = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_type`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.snapshot_type) member.
+    pub fn snapshot_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterSnapshotsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterSnapshotsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBClusterSnapshotsMessage {
+            db_cluster_identifier: self.db_cluster_identifier,
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier,
+            filters: self.filters,
+            include_public: self.include_public,
+            include_shared: self.include_shared,
+            marker: self.marker,
+            max_records: self.max_records,
+            snapshot_type: self.snapshot_type,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterSnapshotsMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterSnapshotsMessageSerializer;
 impl DescribeDBClusterSnapshotsMessageSerializer {
@@ -2726,6 +3901,60 @@ pub struct DescribeDBClustersMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBClustersMessage {
+    /// Returns a builder for `DescribeDBClustersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClustersMessageBuilder {
+        DescribeDBClustersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClustersMessage`](struct.DescribeDBClustersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClustersMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBClustersMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.DescribeDBClustersMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClustersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClustersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClustersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClustersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClustersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBClustersMessage {
+            db_cluster_identifier: self.db_cluster_identifier,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClustersMessage` contents to a `SignedRequest`.
 struct DescribeDBClustersMessageSerializer;
 impl DescribeDBClustersMessageSerializer {
@@ -2780,6 +4009,100 @@ pub struct DescribeDBEngineVersionsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBEngineVersionsMessage {
+    /// Returns a builder for `DescribeDBEngineVersionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBEngineVersionsMessageBuilder {
+        DescribeDBEngineVersionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBEngineVersionsMessage`](struct.DescribeDBEngineVersionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBEngineVersionsMessageBuilder {
+    db_parameter_group_family: Option<String>,
+    default_only: Option<bool>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    filters: Option<Vec<Filter>>,
+    list_supported_character_sets: Option<bool>,
+    list_supported_timezones: Option<bool>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBEngineVersionsMessageBuilder {
+    /// Sets the [`db_parameter_group_family`](struct.DescribeDBEngineVersionsMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_only`](struct.DescribeDBEngineVersionsMessage.html#structfield.default_only) member.
+    pub fn default_only<T: Into<bool>>(mut self, value: T) -> Self {
+        self.default_only = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.DescribeDBEngineVersionsMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.DescribeDBEngineVersionsMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBEngineVersionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`list_supported_character_sets`](struct.DescribeDBEngineVersionsMessage.html#structfield.list_supported_character_sets) member.
+    pub fn list_supported_character_sets<T: Into<bool>>(mut self, value: T) -> Self {
+        self.list_supported_character_sets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`list_supported_timezones`](struct.DescribeDBEngineVersionsMessage.html#structfield.list_supported_timezones) member.
+    pub fn list_supported_timezones<T: Into<bool>>(mut self, value: T) -> Self {
+        self.list_supported_timezones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBEngineVersionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBEngineVersionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBEngineVersionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBEngineVersionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBEngineVersionsMessage {
+            db_parameter_group_family: self.db_parameter_group_family,
+            default_only: self.default_only,
+            engine: self.engine,
+            engine_version: self.engine_version,
+            filters: self.filters,
+            list_supported_character_sets: self.list_supported_character_sets,
+            list_supported_timezones: self.list_supported_timezones,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBEngineVersionsMessage` contents to a `SignedRequest`.
 struct DescribeDBEngineVersionsMessageSerializer;
 impl DescribeDBEngineVersionsMessageSerializer {
@@ -2845,6 +4168,60 @@ pub struct DescribeDBInstancesMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBInstancesMessage {
+    /// Returns a builder for `DescribeDBInstancesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBInstancesMessageBuilder {
+        DescribeDBInstancesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBInstancesMessage`](struct.DescribeDBInstancesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBInstancesMessageBuilder {
+    db_instance_identifier: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBInstancesMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.DescribeDBInstancesMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBInstancesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBInstancesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBInstancesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBInstancesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBInstancesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBInstancesMessage {
+            db_instance_identifier: self.db_instance_identifier,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBInstancesMessage` contents to a `SignedRequest`.
 struct DescribeDBInstancesMessageSerializer;
 impl DescribeDBInstancesMessageSerializer {
@@ -2889,6 +4266,60 @@ pub struct DescribeDBSubnetGroupsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBSubnetGroupsMessage {
+    /// Returns a builder for `DescribeDBSubnetGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBSubnetGroupsMessageBuilder {
+        DescribeDBSubnetGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBSubnetGroupsMessage`](struct.DescribeDBSubnetGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBSubnetGroupsMessageBuilder {
+    db_subnet_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBSubnetGroupsMessageBuilder {
+    /// Sets the [`db_subnet_group_name`](struct.DescribeDBSubnetGroupsMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBSubnetGroupsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBSubnetGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBSubnetGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBSubnetGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBSubnetGroupsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBSubnetGroupsMessage {
+            db_subnet_group_name: self.db_subnet_group_name,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBSubnetGroupsMessage` contents to a `SignedRequest`.
 struct DescribeDBSubnetGroupsMessageSerializer;
 impl DescribeDBSubnetGroupsMessageSerializer {
@@ -2930,6 +4361,65 @@ pub struct DescribeEngineDefaultClusterParametersMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeEngineDefaultClusterParametersMessage {
+    /// Returns a builder for `DescribeEngineDefaultClusterParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEngineDefaultClusterParametersMessageBuilder {
+        DescribeEngineDefaultClusterParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEngineDefaultClusterParametersMessage`](struct.DescribeEngineDefaultClusterParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEngineDefaultClusterParametersMessageBuilder {
+    db_parameter_group_family: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeEngineDefaultClusterParametersMessageBuilder {
+    /// Sets the [`db_parameter_group_family`](struct.DescribeEngineDefaultClusterParametersMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeEngineDefaultClusterParametersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEngineDefaultClusterParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEngineDefaultClusterParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEngineDefaultClusterParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeEngineDefaultClusterParametersMessage,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeEngineDefaultClusterParametersMessage {
+            db_parameter_group_family: self.db_parameter_group_family.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_family"),
+            )?,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeEngineDefaultClusterParametersMessage` contents to a `SignedRequest`.
 struct DescribeEngineDefaultClusterParametersMessageSerializer;
 impl DescribeEngineDefaultClusterParametersMessageSerializer {
@@ -2968,10 +4458,10 @@ pub struct DescribeEngineDefaultClusterParametersResult {
     pub engine_defaults: Option<EngineDefaults>,
 }
 
-struct DescribeEngineDefaultClusterParametersResultDeserializer;
+pub(crate) struct DescribeEngineDefaultClusterParametersResultDeserializer;
 impl DescribeEngineDefaultClusterParametersResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeEngineDefaultClusterParametersResult, XmlParseError> {
@@ -3002,6 +4492,44 @@ pub struct DescribeEventCategoriesMessage {
     pub source_type: Option<String>,
 }
 
+impl DescribeEventCategoriesMessage {
+    /// Returns a builder for `DescribeEventCategoriesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventCategoriesMessageBuilder {
+        DescribeEventCategoriesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventCategoriesMessage`](struct.DescribeEventCategoriesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventCategoriesMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    source_type: Option<String>,
+}
+
+impl DescribeEventCategoriesMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeEventCategoriesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.DescribeEventCategoriesMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventCategoriesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventCategoriesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventCategoriesMessage {
+            filters: self.filters,
+            source_type: self.source_type,
+        })
+    }
+}
+
 /// Serialize `DescribeEventCategoriesMessage` contents to a `SignedRequest`.
 struct DescribeEventCategoriesMessageSerializer;
 impl DescribeEventCategoriesMessageSerializer {
@@ -3030,7 +4558,7 @@ pub struct DescribeEventsMessage {
     /// <p>The number of minutes to retrieve events for.</p> <p>Default: 60</p>
     pub duration: Option<i64>,
     /// <p> The end of the time interval for which to retrieve events, specified in ISO 8601 format. </p> <p>Example: 2009-07-08T18:00Z</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>A list of event categories that trigger notifications for an event notification subscription.</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>This parameter is not currently supported.</p>
@@ -3042,9 +4570,101 @@ pub struct DescribeEventsMessage {
     /// <p><p>The identifier of the event source for which events are returned. If not specified, then all sources are included in the response.</p> <p>Constraints:</p> <ul> <li> <p>If <code>SourceIdentifier</code> is provided, <code>SourceType</code> must also be provided.</p> </li> <li> <p>If the source type is <code>DBInstance</code>, a <code>DBInstanceIdentifier</code> must be provided.</p> </li> <li> <p>If the source type is <code>DBSecurityGroup</code>, a <code>DBSecurityGroupName</code> must be provided.</p> </li> <li> <p>If the source type is <code>DBParameterGroup</code>, a <code>DBParameterGroupName</code> must be provided.</p> </li> <li> <p>If the source type is <code>DBSnapshot</code>, a <code>DBSnapshotIdentifier</code> must be provided.</p> </li> <li> <p>Cannot end with a hyphen or contain two consecutive hyphens.</p> </li> </ul></p>
     pub source_identifier: Option<String>,
     /// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p> The beginning of the time interval to retrieve events for, specified in ISO 8601 format. </p> <p>Example: 2009-07-08T18:00Z</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
+}
+
+impl DescribeEventsMessage {
+    /// Returns a builder for `DescribeEventsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventsMessageBuilder {
+        DescribeEventsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventsMessage`](struct.DescribeEventsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventsMessageBuilder {
+    duration: Option<i64>,
+    end_time: Option<IsoTimestamp>,
+    event_categories: Option<Vec<String>>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    source_identifier: Option<String>,
+    source_type: Option<SourceType>,
+    start_time: Option<IsoTimestamp>,
+}
+
+impl DescribeEventsMessageBuilder {
+    /// Sets the [`duration`](struct.DescribeEventsMessage.html#structfield.duration) member.
+    pub fn duration<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.DescribeEventsMessage.html#structfield.end_time) member.
+    pub fn end_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_categories`](struct.DescribeEventsMessage.html#structfield.event_categories) member.
+    pub fn event_categories<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_categories = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeEventsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEventsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEventsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_identifier`](struct.DescribeEventsMessage.html#structfield.source_identifier) member.
+    pub fn source_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.DescribeEventsMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<SourceType>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.DescribeEventsMessage.html#structfield.start_time) member.
+    pub fn start_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventsMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeEventsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventsMessage {
+            duration: self.duration,
+            end_time: self.end_time,
+            event_categories: self.event_categories,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            source_identifier: self.source_identifier,
+            source_type: self.source_type,
+            start_time: self.start_time,
+        })
+    }
 }
 
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
@@ -3115,6 +4735,95 @@ pub struct DescribeOrderableDBInstanceOptionsMessage {
     pub vpc: Option<bool>,
 }
 
+impl DescribeOrderableDBInstanceOptionsMessage {
+    /// Returns a builder for `DescribeOrderableDBInstanceOptionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeOrderableDBInstanceOptionsMessageBuilder {
+        DescribeOrderableDBInstanceOptionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeOrderableDBInstanceOptionsMessage`](struct.DescribeOrderableDBInstanceOptionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeOrderableDBInstanceOptionsMessageBuilder {
+    db_instance_class: Option<String>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    filters: Option<Vec<Filter>>,
+    license_model: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    vpc: Option<bool>,
+}
+
+impl DescribeOrderableDBInstanceOptionsMessageBuilder {
+    /// Sets the [`db_instance_class`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_model`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.license_model) member.
+    pub fn license_model<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_model = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.vpc) member.
+    pub fn vpc<T: Into<bool>>(mut self, value: T) -> Self {
+        self.vpc = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeOrderableDBInstanceOptionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeOrderableDBInstanceOptionsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeOrderableDBInstanceOptionsMessage {
+            db_instance_class: self.db_instance_class,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_version: self.engine_version,
+            filters: self.filters,
+            license_model: self.license_model,
+            marker: self.marker,
+            max_records: self.max_records,
+            vpc: self.vpc,
+        })
+    }
+}
+
 /// Serialize `DescribeOrderableDBInstanceOptionsMessage` contents to a `SignedRequest`.
 struct DescribeOrderableDBInstanceOptionsMessageSerializer;
 impl DescribeOrderableDBInstanceOptionsMessageSerializer {
@@ -3166,6 +4875,61 @@ pub struct DescribePendingMaintenanceActionsMessage {
     pub resource_identifier: Option<String>,
 }
 
+impl DescribePendingMaintenanceActionsMessage {
+    /// Returns a builder for `DescribePendingMaintenanceActionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribePendingMaintenanceActionsMessageBuilder {
+        DescribePendingMaintenanceActionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePendingMaintenanceActionsMessage`](struct.DescribePendingMaintenanceActionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribePendingMaintenanceActionsMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    resource_identifier: Option<String>,
+}
+
+impl DescribePendingMaintenanceActionsMessageBuilder {
+    /// Sets the [`filters`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_identifier`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.resource_identifier) member.
+    pub fn resource_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePendingMaintenanceActionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePendingMaintenanceActionsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribePendingMaintenanceActionsMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            resource_identifier: self.resource_identifier,
+        })
+    }
+}
+
 /// Serialize `DescribePendingMaintenanceActionsMessage` contents to a `SignedRequest`.
 struct DescribePendingMaintenanceActionsMessageSerializer;
 impl DescribePendingMaintenanceActionsMessageSerializer {
@@ -3205,10 +4969,10 @@ pub struct Endpoint {
     pub port: Option<i64>,
 }
 
-struct EndpointDeserializer;
+pub(crate) struct EndpointDeserializer;
 impl EndpointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Endpoint, XmlParseError> {
@@ -3241,10 +5005,10 @@ pub struct EngineDefaults {
     pub parameters: Option<Vec<Parameter>>,
 }
 
-struct EngineDefaultsDeserializer;
+pub(crate) struct EngineDefaultsDeserializer;
 impl EngineDefaultsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EngineDefaults, XmlParseError> {
@@ -3274,7 +5038,7 @@ impl EngineDefaultsDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Event {
     /// <p>Specifies the date and time of the event.</p>
-    pub date: Option<String>,
+    pub date: Option<IsoTimestamp>,
     /// <p>Specifies the category for the event.</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>Provides the text of this event.</p>
@@ -3284,13 +5048,16 @@ pub struct Event {
     /// <p>Provides the identifier for the source of the event.</p>
     pub source_identifier: Option<String>,
     /// <p>Specifies the source type for this event.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
-struct EventDeserializer;
+pub(crate) struct EventDeserializer;
 impl EventDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Event, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Event, XmlParseError> {
         deserialize_elements::<_, Event, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Date" => {
@@ -3321,10 +5088,10 @@ impl EventDeserializer {
         })
     }
 }
-struct EventCategoriesListDeserializer;
+pub(crate) struct EventCategoriesListDeserializer;
 impl EventCategoriesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -3359,10 +5126,10 @@ pub struct EventCategoriesMap {
     pub source_type: Option<String>,
 }
 
-struct EventCategoriesMapDeserializer;
+pub(crate) struct EventCategoriesMapDeserializer;
 impl EventCategoriesMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventCategoriesMap, XmlParseError> {
@@ -3382,10 +5149,10 @@ impl EventCategoriesMapDeserializer {
         })
     }
 }
-struct EventCategoriesMapListDeserializer;
+pub(crate) struct EventCategoriesMapListDeserializer;
 impl EventCategoriesMapListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EventCategoriesMap>, XmlParseError> {
@@ -3409,10 +5176,10 @@ pub struct EventCategoriesMessage {
     pub event_categories_map_list: Option<Vec<EventCategoriesMap>>,
 }
 
-struct EventCategoriesMessageDeserializer;
+pub(crate) struct EventCategoriesMessageDeserializer;
 impl EventCategoriesMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventCategoriesMessage, XmlParseError> {
@@ -3432,10 +5199,10 @@ impl EventCategoriesMessageDeserializer {
         })
     }
 }
-struct EventListDeserializer;
+pub(crate) struct EventListDeserializer;
 impl EventListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Event>, XmlParseError> {
@@ -3458,10 +5225,10 @@ pub struct EventsMessage {
     pub marker: Option<String>,
 }
 
-struct EventsMessageDeserializer;
+pub(crate) struct EventsMessageDeserializer;
 impl EventsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventsMessage, XmlParseError> {
@@ -3490,6 +5257,44 @@ pub struct FailoverDBClusterMessage {
     pub target_db_instance_identifier: Option<String>,
 }
 
+impl FailoverDBClusterMessage {
+    /// Returns a builder for `FailoverDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> FailoverDBClusterMessageBuilder {
+        FailoverDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`FailoverDBClusterMessage`](struct.FailoverDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct FailoverDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    target_db_instance_identifier: Option<String>,
+}
+
+impl FailoverDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.FailoverDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_instance_identifier`](struct.FailoverDBClusterMessage.html#structfield.target_db_instance_identifier) member.
+    pub fn target_db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `FailoverDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<FailoverDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(FailoverDBClusterMessage {
+            db_cluster_identifier: self.db_cluster_identifier,
+            target_db_instance_identifier: self.target_db_instance_identifier,
+        })
+    }
+}
+
 /// Serialize `FailoverDBClusterMessage` contents to a `SignedRequest`.
 struct FailoverDBClusterMessageSerializer;
 impl FailoverDBClusterMessageSerializer {
@@ -3519,10 +5324,10 @@ pub struct FailoverDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct FailoverDBClusterResultDeserializer;
+pub(crate) struct FailoverDBClusterResultDeserializer;
 impl FailoverDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FailoverDBClusterResult, XmlParseError> {
@@ -3591,10 +5396,13 @@ impl FilterValueListSerializer {
     }
 }
 
-struct IntegerDeserializer;
+pub(crate) struct IntegerDeserializer;
 impl IntegerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3602,10 +5410,13 @@ impl IntegerDeserializer {
         Ok(obj)
     }
 }
-struct IntegerOptionalDeserializer;
+pub(crate) struct IntegerOptionalDeserializer;
 impl IntegerOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3634,6 +5445,46 @@ pub struct ListTagsForResourceMessage {
     pub resource_name: String,
 }
 
+impl ListTagsForResourceMessage {
+    /// Returns a builder for `ListTagsForResourceMessage`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceMessageBuilder {
+        ListTagsForResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceMessage`](struct.ListTagsForResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    resource_name: Option<String>,
+}
+
+impl ListTagsForResourceMessageBuilder {
+    /// Sets the [`filters`](struct.ListTagsForResourceMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_name`](struct.ListTagsForResourceMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceMessage, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceMessage {
+            filters: self.filters,
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListTagsForResourceMessage` contents to a `SignedRequest`.
 struct ListTagsForResourceMessageSerializer;
 impl ListTagsForResourceMessageSerializer {
@@ -3654,10 +5505,10 @@ impl ListTagsForResourceMessageSerializer {
     }
 }
 
-struct LogTypeListDeserializer;
+pub(crate) struct LogTypeListDeserializer;
 impl LogTypeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -3714,6 +5565,135 @@ pub struct ModifyDBClusterMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl ModifyDBClusterMessage {
+    /// Returns a builder for `ModifyDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBClusterMessageBuilder {
+        ModifyDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBClusterMessage`](struct.ModifyDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBClusterMessageBuilder {
+    apply_immediately: Option<bool>,
+    backup_retention_period: Option<i64>,
+    cloudwatch_logs_export_configuration: Option<CloudwatchLogsExportConfiguration>,
+    db_cluster_identifier: Option<String>,
+    db_cluster_parameter_group_name: Option<String>,
+    deletion_protection: Option<bool>,
+    engine_version: Option<String>,
+    master_user_password: Option<String>,
+    new_db_cluster_identifier: Option<String>,
+    port: Option<i64>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl ModifyDBClusterMessageBuilder {
+    /// Sets the [`apply_immediately`](struct.ModifyDBClusterMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_period`](struct.ModifyDBClusterMessage.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cloudwatch_logs_export_configuration`](struct.ModifyDBClusterMessage.html#structfield.cloudwatch_logs_export_configuration) member.
+    pub fn cloudwatch_logs_export_configuration<T: Into<CloudwatchLogsExportConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cloudwatch_logs_export_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.ModifyDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_parameter_group_name`](struct.ModifyDBClusterMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.ModifyDBClusterMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.ModifyDBClusterMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.ModifyDBClusterMessage.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_db_cluster_identifier`](struct.ModifyDBClusterMessage.html#structfield.new_db_cluster_identifier) member.
+    pub fn new_db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.ModifyDBClusterMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.ModifyDBClusterMessage.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.ModifyDBClusterMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.ModifyDBClusterMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ModifyDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBClusterMessage {
+            apply_immediately: self.apply_immediately,
+            backup_retention_period: self.backup_retention_period,
+            cloudwatch_logs_export_configuration: self.cloudwatch_logs_export_configuration,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            deletion_protection: self.deletion_protection,
+            engine_version: self.engine_version,
+            master_user_password: self.master_user_password,
+            new_db_cluster_identifier: self.new_db_cluster_identifier,
+            port: self.port,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `ModifyDBClusterMessage` contents to a `SignedRequest`.
 struct ModifyDBClusterMessageSerializer;
 impl ModifyDBClusterMessageSerializer {
@@ -3798,6 +5778,49 @@ pub struct ModifyDBClusterParameterGroupMessage {
     pub parameters: Vec<Parameter>,
 }
 
+impl ModifyDBClusterParameterGroupMessage {
+    /// Returns a builder for `ModifyDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBClusterParameterGroupMessageBuilder {
+        ModifyDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBClusterParameterGroupMessage`](struct.ModifyDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBClusterParameterGroupMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+}
+
+impl ModifyDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.ModifyDBClusterParameterGroupMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ModifyDBClusterParameterGroupMessage.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBClusterParameterGroupMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ModifyDBClusterParameterGroupMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+            parameters: self
+                .parameters
+                .ok_or(::rusoto_core::BuildError::missing("parameters"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct ModifyDBClusterParameterGroupMessageSerializer;
 impl ModifyDBClusterParameterGroupMessageSerializer {
@@ -3824,10 +5847,10 @@ pub struct ModifyDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct ModifyDBClusterResultDeserializer;
+pub(crate) struct ModifyDBClusterResultDeserializer;
 impl ModifyDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBClusterResult, XmlParseError> {
@@ -3855,6 +5878,65 @@ pub struct ModifyDBClusterSnapshotAttributeMessage {
     pub values_to_remove: Option<Vec<String>>,
 }
 
+impl ModifyDBClusterSnapshotAttributeMessage {
+    /// Returns a builder for `ModifyDBClusterSnapshotAttributeMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBClusterSnapshotAttributeMessageBuilder {
+        ModifyDBClusterSnapshotAttributeMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBClusterSnapshotAttributeMessage`](struct.ModifyDBClusterSnapshotAttributeMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBClusterSnapshotAttributeMessageBuilder {
+    attribute_name: Option<String>,
+    db_cluster_snapshot_identifier: Option<String>,
+    values_to_add: Option<Vec<String>>,
+    values_to_remove: Option<Vec<String>>,
+}
+
+impl ModifyDBClusterSnapshotAttributeMessageBuilder {
+    /// Sets the [`attribute_name`](struct.ModifyDBClusterSnapshotAttributeMessage.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.ModifyDBClusterSnapshotAttributeMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`values_to_add`](struct.ModifyDBClusterSnapshotAttributeMessage.html#structfield.values_to_add) member.
+    pub fn values_to_add<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.values_to_add = Some(value.into());
+        self
+    }
+
+    /// Sets the [`values_to_remove`](struct.ModifyDBClusterSnapshotAttributeMessage.html#structfield.values_to_remove) member.
+    pub fn values_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.values_to_remove = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBClusterSnapshotAttributeMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBClusterSnapshotAttributeMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ModifyDBClusterSnapshotAttributeMessage {
+            attribute_name: self
+                .attribute_name
+                .ok_or(::rusoto_core::BuildError::missing("attribute_name"))?,
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_snapshot_identifier"),
+            )?,
+            values_to_add: self.values_to_add,
+            values_to_remove: self.values_to_remove,
+        })
+    }
+}
+
 /// Serialize `ModifyDBClusterSnapshotAttributeMessage` contents to a `SignedRequest`.
 struct ModifyDBClusterSnapshotAttributeMessageSerializer;
 impl ModifyDBClusterSnapshotAttributeMessageSerializer {
@@ -3894,10 +5976,10 @@ pub struct ModifyDBClusterSnapshotAttributeResult {
     pub db_cluster_snapshot_attributes_result: Option<DBClusterSnapshotAttributesResult>,
 }
 
-struct ModifyDBClusterSnapshotAttributeResultDeserializer;
+pub(crate) struct ModifyDBClusterSnapshotAttributeResultDeserializer;
 impl ModifyDBClusterSnapshotAttributeResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBClusterSnapshotAttributeResult, XmlParseError> {
@@ -3939,6 +6021,86 @@ pub struct ModifyDBInstanceMessage {
     pub promotion_tier: Option<i64>,
 }
 
+impl ModifyDBInstanceMessage {
+    /// Returns a builder for `ModifyDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBInstanceMessageBuilder {
+        ModifyDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBInstanceMessage`](struct.ModifyDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBInstanceMessageBuilder {
+    apply_immediately: Option<bool>,
+    auto_minor_version_upgrade: Option<bool>,
+    db_instance_class: Option<String>,
+    db_instance_identifier: Option<String>,
+    new_db_instance_identifier: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    promotion_tier: Option<i64>,
+}
+
+impl ModifyDBInstanceMessageBuilder {
+    /// Sets the [`apply_immediately`](struct.ModifyDBInstanceMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_minor_version_upgrade`](struct.ModifyDBInstanceMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_class`](struct.ModifyDBInstanceMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_identifier`](struct.ModifyDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_db_instance_identifier`](struct.ModifyDBInstanceMessage.html#structfield.new_db_instance_identifier) member.
+    pub fn new_db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.ModifyDBInstanceMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`promotion_tier`](struct.ModifyDBInstanceMessage.html#structfield.promotion_tier) member.
+    pub fn promotion_tier<T: Into<i64>>(mut self, value: T) -> Self {
+        self.promotion_tier = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBInstanceMessage {
+            apply_immediately: self.apply_immediately,
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            db_instance_class: self.db_instance_class,
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            new_db_instance_identifier: self.new_db_instance_identifier,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            promotion_tier: self.promotion_tier,
+        })
+    }
+}
+
 /// Serialize `ModifyDBInstanceMessage` contents to a `SignedRequest`.
 struct ModifyDBInstanceMessageSerializer;
 impl ModifyDBInstanceMessageSerializer {
@@ -3987,10 +6149,10 @@ pub struct ModifyDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct ModifyDBInstanceResultDeserializer;
+pub(crate) struct ModifyDBInstanceResultDeserializer;
 impl ModifyDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBInstanceResult, XmlParseError> {
@@ -4017,6 +6179,56 @@ pub struct ModifyDBSubnetGroupMessage {
     pub subnet_ids: Vec<String>,
 }
 
+impl ModifyDBSubnetGroupMessage {
+    /// Returns a builder for `ModifyDBSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBSubnetGroupMessageBuilder {
+        ModifyDBSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBSubnetGroupMessage`](struct.ModifyDBSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBSubnetGroupMessageBuilder {
+    db_subnet_group_description: Option<String>,
+    db_subnet_group_name: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+}
+
+impl ModifyDBSubnetGroupMessageBuilder {
+    /// Sets the [`db_subnet_group_description`](struct.ModifyDBSubnetGroupMessage.html#structfield.db_subnet_group_description) member.
+    pub fn db_subnet_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.ModifyDBSubnetGroupMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.ModifyDBSubnetGroupMessage.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBSubnetGroupMessage {
+            db_subnet_group_description: self.db_subnet_group_description,
+            db_subnet_group_name: self
+                .db_subnet_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_subnet_group_name"))?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyDBSubnetGroupMessage` contents to a `SignedRequest`.
 struct ModifyDBSubnetGroupMessageSerializer;
 impl ModifyDBSubnetGroupMessageSerializer {
@@ -4049,10 +6261,10 @@ pub struct ModifyDBSubnetGroupResult {
     pub db_subnet_group: Option<DBSubnetGroup>,
 }
 
-struct ModifyDBSubnetGroupResultDeserializer;
+pub(crate) struct ModifyDBSubnetGroupResultDeserializer;
 impl ModifyDBSubnetGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBSubnetGroupResult, XmlParseError> {
@@ -4091,10 +6303,10 @@ pub struct OrderableDBInstanceOption {
     pub vpc: Option<bool>,
 }
 
-struct OrderableDBInstanceOptionDeserializer;
+pub(crate) struct OrderableDBInstanceOptionDeserializer;
 impl OrderableDBInstanceOptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OrderableDBInstanceOption, XmlParseError> {
@@ -4136,10 +6348,10 @@ impl OrderableDBInstanceOptionDeserializer {
         )
     }
 }
-struct OrderableDBInstanceOptionsListDeserializer;
+pub(crate) struct OrderableDBInstanceOptionsListDeserializer;
 impl OrderableDBInstanceOptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OrderableDBInstanceOption>, XmlParseError> {
@@ -4165,10 +6377,10 @@ pub struct OrderableDBInstanceOptionsMessage {
     pub orderable_db_instance_options: Option<Vec<OrderableDBInstanceOption>>,
 }
 
-struct OrderableDBInstanceOptionsMessageDeserializer;
+pub(crate) struct OrderableDBInstanceOptionsMessageDeserializer;
 impl OrderableDBInstanceOptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OrderableDBInstanceOptionsMessage, XmlParseError> {
@@ -4201,7 +6413,7 @@ pub struct Parameter {
     /// <p>Specifies the valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
     /// <p>Indicates when to apply parameter updates.</p>
-    pub apply_method: Option<String>,
+    pub apply_method: Option<ApplyMethod>,
     /// <p>Specifies the engine-specific parameters type.</p>
     pub apply_type: Option<String>,
     /// <p>Specifies the valid data type for the parameter.</p>
@@ -4220,10 +6432,10 @@ pub struct Parameter {
     pub source: Option<String>,
 }
 
-struct ParameterDeserializer;
+pub(crate) struct ParameterDeserializer;
 impl ParameterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Parameter, XmlParseError> {
@@ -4319,10 +6531,10 @@ impl ParameterSerializer {
     }
 }
 
-struct ParametersListDeserializer;
+pub(crate) struct ParametersListDeserializer;
 impl ParametersListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Parameter>, XmlParseError> {
@@ -4357,10 +6569,10 @@ pub struct PendingCloudwatchLogsExports {
     pub log_types_to_enable: Option<Vec<String>>,
 }
 
-struct PendingCloudwatchLogsExportsDeserializer;
+pub(crate) struct PendingCloudwatchLogsExportsDeserializer;
 impl PendingCloudwatchLogsExportsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingCloudwatchLogsExports, XmlParseError> {
@@ -4392,21 +6604,21 @@ pub struct PendingMaintenanceAction {
     /// <p>The type of pending maintenance action that is available for the resource.</p>
     pub action: Option<String>,
     /// <p>The date of the maintenance window when the action is applied. The maintenance action is applied to the resource during its first maintenance window after this date. If this date is specified, any <code>next-maintenance</code> opt-in requests are ignored.</p>
-    pub auto_applied_after_date: Option<String>,
+    pub auto_applied_after_date: Option<IsoTimestamp>,
     /// <p>The effective date when the pending maintenance action is applied to the resource.</p>
-    pub current_apply_date: Option<String>,
+    pub current_apply_date: Option<IsoTimestamp>,
     /// <p>A description providing more detail about the maintenance action.</p>
     pub description: Option<String>,
     /// <p>The date when the maintenance action is automatically applied. The maintenance action is applied to the resource on this date regardless of the maintenance window for the resource. If this date is specified, any <code>immediate</code> opt-in requests are ignored.</p>
-    pub forced_apply_date: Option<String>,
+    pub forced_apply_date: Option<IsoTimestamp>,
     /// <p>Indicates the type of opt-in request that has been received for the resource.</p>
     pub opt_in_status: Option<String>,
 }
 
-struct PendingMaintenanceActionDeserializer;
+pub(crate) struct PendingMaintenanceActionDeserializer;
 impl PendingMaintenanceActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingMaintenanceAction, XmlParseError> {
@@ -4447,10 +6659,10 @@ impl PendingMaintenanceActionDeserializer {
         )
     }
 }
-struct PendingMaintenanceActionDetailsDeserializer;
+pub(crate) struct PendingMaintenanceActionDetailsDeserializer;
 impl PendingMaintenanceActionDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PendingMaintenanceAction>, XmlParseError> {
@@ -4467,10 +6679,10 @@ impl PendingMaintenanceActionDetailsDeserializer {
         })
     }
 }
-struct PendingMaintenanceActionsDeserializer;
+pub(crate) struct PendingMaintenanceActionsDeserializer;
 impl PendingMaintenanceActionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ResourcePendingMaintenanceActions>, XmlParseError> {
@@ -4496,10 +6708,10 @@ pub struct PendingMaintenanceActionsMessage {
     pub pending_maintenance_actions: Option<Vec<ResourcePendingMaintenanceActions>>,
 }
 
-struct PendingMaintenanceActionsMessageDeserializer;
+pub(crate) struct PendingMaintenanceActionsMessageDeserializer;
 impl PendingMaintenanceActionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingMaintenanceActionsMessage, XmlParseError> {
@@ -4559,10 +6771,10 @@ pub struct PendingModifiedValues {
     pub storage_type: Option<String>,
 }
 
-struct PendingModifiedValuesDeserializer;
+pub(crate) struct PendingModifiedValuesDeserializer;
 impl PendingModifiedValuesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingModifiedValues, XmlParseError> {
@@ -4649,6 +6861,46 @@ pub struct RebootDBInstanceMessage {
     pub force_failover: Option<bool>,
 }
 
+impl RebootDBInstanceMessage {
+    /// Returns a builder for `RebootDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> RebootDBInstanceMessageBuilder {
+        RebootDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RebootDBInstanceMessage`](struct.RebootDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct RebootDBInstanceMessageBuilder {
+    db_instance_identifier: Option<String>,
+    force_failover: Option<bool>,
+}
+
+impl RebootDBInstanceMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.RebootDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_failover`](struct.RebootDBInstanceMessage.html#structfield.force_failover) member.
+    pub fn force_failover<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_failover = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebootDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RebootDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(RebootDBInstanceMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            force_failover: self.force_failover,
+        })
+    }
+}
+
 /// Serialize `RebootDBInstanceMessage` contents to a `SignedRequest`.
 struct RebootDBInstanceMessageSerializer;
 impl RebootDBInstanceMessageSerializer {
@@ -4673,10 +6925,10 @@ pub struct RebootDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct RebootDBInstanceResultDeserializer;
+pub(crate) struct RebootDBInstanceResultDeserializer;
 impl RebootDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RebootDBInstanceResult, XmlParseError> {
@@ -4701,6 +6953,48 @@ pub struct RemoveTagsFromResourceMessage {
     pub tag_keys: Vec<String>,
 }
 
+impl RemoveTagsFromResourceMessage {
+    /// Returns a builder for `RemoveTagsFromResourceMessage`, checking its required members when built.
+    pub fn builder() -> RemoveTagsFromResourceMessageBuilder {
+        RemoveTagsFromResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsFromResourceMessage`](struct.RemoveTagsFromResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsFromResourceMessageBuilder {
+    resource_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl RemoveTagsFromResourceMessageBuilder {
+    /// Sets the [`resource_name`](struct.RemoveTagsFromResourceMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.RemoveTagsFromResourceMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsFromResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveTagsFromResourceMessage, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsFromResourceMessage {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveTagsFromResourceMessage` contents to a `SignedRequest`.
 struct RemoveTagsFromResourceMessageSerializer;
 impl RemoveTagsFromResourceMessageSerializer {
@@ -4726,6 +7020,54 @@ pub struct ResetDBClusterParameterGroupMessage {
     pub reset_all_parameters: Option<bool>,
 }
 
+impl ResetDBClusterParameterGroupMessage {
+    /// Returns a builder for `ResetDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ResetDBClusterParameterGroupMessageBuilder {
+        ResetDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ResetDBClusterParameterGroupMessage`](struct.ResetDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ResetDBClusterParameterGroupMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+    reset_all_parameters: Option<bool>,
+}
+
+impl ResetDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.ResetDBClusterParameterGroupMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ResetDBClusterParameterGroupMessage.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reset_all_parameters`](struct.ResetDBClusterParameterGroupMessage.html#structfield.reset_all_parameters) member.
+    pub fn reset_all_parameters<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reset_all_parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResetDBClusterParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ResetDBClusterParameterGroupMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+            parameters: self.parameters,
+            reset_all_parameters: self.reset_all_parameters,
+        })
+    }
+}
+
 /// Serialize `ResetDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct ResetDBClusterParameterGroupMessageSerializer;
 impl ResetDBClusterParameterGroupMessageSerializer {
@@ -4761,10 +7103,10 @@ pub struct ResourcePendingMaintenanceActions {
     pub resource_identifier: Option<String>,
 }
 
-struct ResourcePendingMaintenanceActionsDeserializer;
+pub(crate) struct ResourcePendingMaintenanceActionsDeserializer;
 impl ResourcePendingMaintenanceActionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourcePendingMaintenanceActions, XmlParseError> {
@@ -4823,6 +7165,130 @@ pub struct RestoreDBClusterFromSnapshotMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl RestoreDBClusterFromSnapshotMessage {
+    /// Returns a builder for `RestoreDBClusterFromSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> RestoreDBClusterFromSnapshotMessageBuilder {
+        RestoreDBClusterFromSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreDBClusterFromSnapshotMessage`](struct.RestoreDBClusterFromSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct RestoreDBClusterFromSnapshotMessageBuilder {
+    availability_zones: Option<Vec<String>>,
+    db_cluster_identifier: Option<String>,
+    db_subnet_group_name: Option<String>,
+    deletion_protection: Option<bool>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    kms_key_id: Option<String>,
+    port: Option<i64>,
+    snapshot_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl RestoreDBClusterFromSnapshotMessageBuilder {
+    /// Sets the [`availability_zones`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_identifier`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.snapshot_identifier) member.
+    pub fn snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreDBClusterFromSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreDBClusterFromSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(RestoreDBClusterFromSnapshotMessage {
+            availability_zones: self.availability_zones,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_subnet_group_name: self.db_subnet_group_name,
+            deletion_protection: self.deletion_protection,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_version: self.engine_version,
+            kms_key_id: self.kms_key_id,
+            port: self.port,
+            snapshot_identifier: self
+                .snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_identifier"))?,
+            tags: self.tags,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `RestoreDBClusterFromSnapshotMessage` contents to a `SignedRequest`.
 struct RestoreDBClusterFromSnapshotMessageSerializer;
 impl RestoreDBClusterFromSnapshotMessageSerializer {
@@ -4888,10 +7354,10 @@ pub struct RestoreDBClusterFromSnapshotResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct RestoreDBClusterFromSnapshotResultDeserializer;
+pub(crate) struct RestoreDBClusterFromSnapshotResultDeserializer;
 impl RestoreDBClusterFromSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreDBClusterFromSnapshotResult, XmlParseError> {
@@ -4927,7 +7393,7 @@ pub struct RestoreDBClusterToPointInTimeMessage {
     /// <p>The port number on which the new DB cluster accepts connections.</p> <p>Constraints: Must be a value from <code>1150</code> to <code>65535</code>. </p> <p>Default: The default port for the engine.</p>
     pub port: Option<i64>,
     /// <p>The date and time to restore the DB cluster to.</p> <p>Valid values: A time in Universal Coordinated Time (UTC) format.</p> <p>Constraints:</p> <ul> <li> <p>Must be before the latest restorable time for the DB instance.</p> </li> <li> <p>Must be specified if the <code>UseLatestRestorableTime</code> parameter is not provided.</p> </li> <li> <p>Cannot be specified if the <code>UseLatestRestorableTime</code> parameter is <code>true</code>.</p> </li> <li> <p>Cannot be specified if the <code>RestoreType</code> parameter is <code>copy-on-write</code>.</p> </li> </ul> <p>Example: <code>2015-03-07T23:45:00Z</code> </p>
-    pub restore_to_time: Option<String>,
+    pub restore_to_time: Option<IsoTimestamp>,
     /// <p><p>The identifier of the source DB cluster from which to restore.</p> <p>Constraints:</p> <ul> <li> <p>Must match the identifier of an existing <code>DBCluster</code>.</p> </li> </ul></p>
     pub source_db_cluster_identifier: String,
     /// <p>The tags to be assigned to the restored DB cluster.</p>
@@ -4938,6 +7404,121 @@ pub struct RestoreDBClusterToPointInTimeMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl RestoreDBClusterToPointInTimeMessage {
+    /// Returns a builder for `RestoreDBClusterToPointInTimeMessage`, checking its required members when built.
+    pub fn builder() -> RestoreDBClusterToPointInTimeMessageBuilder {
+        RestoreDBClusterToPointInTimeMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreDBClusterToPointInTimeMessage`](struct.RestoreDBClusterToPointInTimeMessage.html).
+#[derive(Default, Debug)]
+pub struct RestoreDBClusterToPointInTimeMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    db_subnet_group_name: Option<String>,
+    deletion_protection: Option<bool>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    kms_key_id: Option<String>,
+    port: Option<i64>,
+    restore_to_time: Option<IsoTimestamp>,
+    source_db_cluster_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    use_latest_restorable_time: Option<bool>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl RestoreDBClusterToPointInTimeMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`restore_to_time`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.restore_to_time) member.
+    pub fn restore_to_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.restore_to_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_db_cluster_identifier`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.source_db_cluster_identifier) member.
+    pub fn source_db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_latest_restorable_time`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.use_latest_restorable_time) member.
+    pub fn use_latest_restorable_time<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_latest_restorable_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreDBClusterToPointInTimeMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreDBClusterToPointInTimeMessage, ::rusoto_core::BuildError>
+    {
+        Ok(RestoreDBClusterToPointInTimeMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_subnet_group_name: self.db_subnet_group_name,
+            deletion_protection: self.deletion_protection,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            kms_key_id: self.kms_key_id,
+            port: self.port,
+            restore_to_time: self.restore_to_time,
+            source_db_cluster_identifier: self.source_db_cluster_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_db_cluster_identifier"),
+            )?,
+            tags: self.tags,
+            use_latest_restorable_time: self.use_latest_restorable_time,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `RestoreDBClusterToPointInTimeMessage` contents to a `SignedRequest`.
 struct RestoreDBClusterToPointInTimeMessageSerializer;
 impl RestoreDBClusterToPointInTimeMessageSerializer {
@@ -5001,10 +7582,10 @@ pub struct RestoreDBClusterToPointInTimeResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct RestoreDBClusterToPointInTimeResultDeserializer;
+pub(crate) struct RestoreDBClusterToPointInTimeResultDeserializer;
 impl RestoreDBClusterToPointInTimeResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreDBClusterToPointInTimeResult, XmlParseError> {
@@ -5024,23 +7605,131 @@ impl RestoreDBClusterToPointInTimeResultDeserializer {
         )
     }
 }
-struct SourceTypeDeserializer;
+pub(crate) struct SourceTypeDeserializer;
 impl SourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceType {
+    /// <p><code>db-instance</code></p>
+    DbInstance,
+    /// <p><code>db-parameter-group</code></p>
+    DbParameterGroup,
+    /// <p><code>db-security-group</code></p>
+    DbSecurityGroup,
+    /// <p><code>db-snapshot</code></p>
+    DbSnapshot,
+    /// <p><code>db-cluster</code></p>
+    DbCluster,
+    /// <p><code>db-cluster-snapshot</code></p>
+    DbClusterSnapshot,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::DbInstance => "db-instance",
+            SourceType::DbParameterGroup => "db-parameter-group",
+            SourceType::DbSecurityGroup => "db-security-group",
+            SourceType::DbSnapshot => "db-snapshot",
+            SourceType::DbCluster => "db-cluster",
+            SourceType::DbClusterSnapshot => "db-cluster-snapshot",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "db-instance" => SourceType::DbInstance,
+            "db-parameter-group" => SourceType::DbParameterGroup,
+            "db-security-group" => SourceType::DbSecurityGroup,
+            "db-snapshot" => SourceType::DbSnapshot,
+            "db-cluster" => SourceType::DbCluster,
+            "db-cluster-snapshot" => SourceType::DbClusterSnapshot,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        SourceType::from(value.as_str())
+    }
+}
+
+impl From<SourceType> for String {
+    fn from(value: SourceType) -> String {
+        match value {
+            SourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct StartDBClusterMessage {
     /// <p>The identifier of the cluster to restart. Example: <code>docdb-2019-05-28-15-24-52</code> </p>
     pub db_cluster_identifier: String,
 }
 
+impl StartDBClusterMessage {
+    /// Returns a builder for `StartDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> StartDBClusterMessageBuilder {
+        StartDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`StartDBClusterMessage`](struct.StartDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct StartDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+}
+
+impl StartDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.StartDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(StartDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `StartDBClusterMessage` contents to a `SignedRequest`.
 struct StartDBClusterMessageSerializer;
 impl StartDBClusterMessageSerializer {
@@ -5062,10 +7751,10 @@ pub struct StartDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct StartDBClusterResultDeserializer;
+pub(crate) struct StartDBClusterResultDeserializer;
 impl StartDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StartDBClusterResult, XmlParseError> {
@@ -5080,11 +7769,41 @@ impl StartDBClusterResultDeserializer {
         })
     }
 }
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct StopDBClusterMessage {
-    /// <p>The identifier of the cluster to stop. Example: <code>docdb-2019-05-28-15-24-52</code> </p>
-    pub db_cluster_identifier: String,
-}
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct StopDBClusterMessage {
+    /// <p>The identifier of the cluster to stop. Example: <code>docdb-2019-05-28-15-24-52</code> </p>
+    pub db_cluster_identifier: String,
+}
+
+impl StopDBClusterMessage {
+    /// Returns a builder for `StopDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> StopDBClusterMessageBuilder {
+        StopDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`StopDBClusterMessage`](struct.StopDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct StopDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+}
+
+impl StopDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.StopDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(StopDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+        })
+    }
+}
 
 /// Serialize `StopDBClusterMessage` contents to a `SignedRequest`.
 struct StopDBClusterMessageSerializer;
@@ -5107,10 +7826,10 @@ pub struct StopDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct StopDBClusterResultDeserializer;
+pub(crate) struct StopDBClusterResultDeserializer;
 impl StopDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StopDBClusterResult, XmlParseError> {
@@ -5125,10 +7844,13 @@ impl StopDBClusterResultDeserializer {
         })
     }
 }
-struct StringDeserializer;
+pub(crate) struct StringDeserializer;
 impl StringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5147,10 +7869,13 @@ pub struct Subnet {
     pub subnet_status: Option<String>,
 }
 
-struct SubnetDeserializer;
+pub(crate) struct SubnetDeserializer;
 impl SubnetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Subnet, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Subnet, XmlParseError> {
         deserialize_elements::<_, Subnet, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "SubnetAvailabilityZone" => {
@@ -5185,10 +7910,10 @@ impl SubnetIdentifierListSerializer {
     }
 }
 
-struct SubnetListDeserializer;
+pub(crate) struct SubnetListDeserializer;
 impl SubnetListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Subnet>, XmlParseError> {
@@ -5202,12 +7927,15 @@ impl SubnetListDeserializer {
         })
     }
 }
-struct TStampDeserializer;
+pub(crate) struct TStampDeserializer;
 impl TStampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -5222,10 +7950,13 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -5259,10 +7990,10 @@ impl TagSerializer {
     }
 }
 
-struct TagListDeserializer;
+pub(crate) struct TagListDeserializer;
 impl TagListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -5295,10 +8026,10 @@ pub struct TagListMessage {
     pub tag_list: Option<Vec<Tag>>,
 }
 
-struct TagListMessageDeserializer;
+pub(crate) struct TagListMessageDeserializer;
 impl TagListMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TagListMessage, XmlParseError> {
@@ -5330,10 +8061,10 @@ pub struct UpgradeTarget {
     pub is_major_version_upgrade: Option<bool>,
 }
 
-struct UpgradeTargetDeserializer;
+pub(crate) struct UpgradeTargetDeserializer;
 impl UpgradeTargetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpgradeTarget, XmlParseError> {
@@ -5365,10 +8096,10 @@ impl UpgradeTargetDeserializer {
         })
     }
 }
-struct ValidUpgradeTargetListDeserializer;
+pub(crate) struct ValidUpgradeTargetListDeserializer;
 impl ValidUpgradeTargetListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<UpgradeTarget>, XmlParseError> {
@@ -5406,10 +8137,10 @@ pub struct VpcSecurityGroupMembership {
     pub vpc_security_group_id: Option<String>,
 }
 
-struct VpcSecurityGroupMembershipDeserializer;
+pub(crate) struct VpcSecurityGroupMembershipDeserializer;
 impl VpcSecurityGroupMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<VpcSecurityGroupMembership, XmlParseError> {
@@ -5434,10 +8165,10 @@ impl VpcSecurityGroupMembershipDeserializer {
         )
     }
 }
-struct VpcSecurityGroupMembershipListDeserializer;
+pub(crate) struct VpcSecurityGroupMembershipListDeserializer;
 impl VpcSecurityGroupMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<VpcSecurityGroupMembership>, XmlParseError> {
@@ -8881,6 +11612,270 @@ pub trait Docdb {
         input: StopDBClusterMessage,
     ) -> RusotoFuture<StopDBClusterResult, StopDBClusterError>;
 }
+impl<T: ?Sized + Docdb> Docdb for ::std::sync::Arc<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<(), AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> RusotoFuture<ApplyPendingMaintenanceActionResult, ApplyPendingMaintenanceActionError> {
+        (**self).apply_pending_maintenance_action(input)
+    }
+    fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CopyDBClusterParameterGroupResult, CopyDBClusterParameterGroupError> {
+        (**self).copy_db_cluster_parameter_group(input)
+    }
+    fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CopyDBClusterSnapshotResult, CopyDBClusterSnapshotError> {
+        (**self).copy_db_cluster_snapshot(input)
+    }
+    fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> RusotoFuture<CreateDBClusterResult, CreateDBClusterError> {
+        (**self).create_db_cluster(input)
+    }
+    fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CreateDBClusterParameterGroupResult, CreateDBClusterParameterGroupError> {
+        (**self).create_db_cluster_parameter_group(input)
+    }
+    fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CreateDBClusterSnapshotResult, CreateDBClusterSnapshotError> {
+        (**self).create_db_cluster_snapshot(input)
+    }
+    fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> RusotoFuture<CreateDBInstanceResult, CreateDBInstanceError> {
+        (**self).create_db_instance(input)
+    }
+    fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> RusotoFuture<CreateDBSubnetGroupResult, CreateDBSubnetGroupError> {
+        (**self).create_db_subnet_group(input)
+    }
+    fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> RusotoFuture<DeleteDBClusterResult, DeleteDBClusterError> {
+        (**self).delete_db_cluster(input)
+    }
+    fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBClusterParameterGroupError> {
+        (**self).delete_db_cluster_parameter_group(input)
+    }
+    fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> RusotoFuture<DeleteDBClusterSnapshotResult, DeleteDBClusterSnapshotError> {
+        (**self).delete_db_cluster_snapshot(input)
+    }
+    fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> RusotoFuture<DeleteDBInstanceResult, DeleteDBInstanceError> {
+        (**self).delete_db_instance(input)
+    }
+    fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBSubnetGroupError> {
+        (**self).delete_db_subnet_group(input)
+    }
+    fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupsMessage, DescribeDBClusterParameterGroupsError> {
+        (**self).describe_db_cluster_parameter_groups(input)
+    }
+    fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupDetails, DescribeDBClusterParametersError> {
+        (**self).describe_db_cluster_parameters(input)
+    }
+    fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> RusotoFuture<
+        DescribeDBClusterSnapshotAttributesResult,
+        DescribeDBClusterSnapshotAttributesError,
+    > {
+        (**self).describe_db_cluster_snapshot_attributes(input)
+    }
+    fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> RusotoFuture<DBClusterSnapshotMessage, DescribeDBClusterSnapshotsError> {
+        (**self).describe_db_cluster_snapshots(input)
+    }
+    fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> RusotoFuture<DBClusterMessage, DescribeDBClustersError> {
+        (**self).describe_db_clusters(input)
+    }
+    fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> RusotoFuture<DBEngineVersionMessage, DescribeDBEngineVersionsError> {
+        (**self).describe_db_engine_versions(input)
+    }
+    fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> RusotoFuture<DBInstanceMessage, DescribeDBInstancesError> {
+        (**self).describe_db_instances(input)
+    }
+    fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> RusotoFuture<DBSubnetGroupMessage, DescribeDBSubnetGroupsError> {
+        (**self).describe_db_subnet_groups(input)
+    }
+    fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> RusotoFuture<
+        DescribeEngineDefaultClusterParametersResult,
+        DescribeEngineDefaultClusterParametersError,
+    > {
+        (**self).describe_engine_default_cluster_parameters(input)
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
+        (**self).describe_event_categories(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> RusotoFuture<OrderableDBInstanceOptionsMessage, DescribeOrderableDBInstanceOptionsError>
+    {
+        (**self).describe_orderable_db_instance_options(input)
+    }
+    fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> RusotoFuture<PendingMaintenanceActionsMessage, DescribePendingMaintenanceActionsError>
+    {
+        (**self).describe_pending_maintenance_actions(input)
+    }
+    fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> RusotoFuture<FailoverDBClusterResult, FailoverDBClusterError> {
+        (**self).failover_db_cluster(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> RusotoFuture<ModifyDBClusterResult, ModifyDBClusterError> {
+        (**self).modify_db_cluster(input)
+    }
+    fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ModifyDBClusterParameterGroupError> {
+        (**self).modify_db_cluster_parameter_group(input)
+    }
+    fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> RusotoFuture<ModifyDBClusterSnapshotAttributeResult, ModifyDBClusterSnapshotAttributeError>
+    {
+        (**self).modify_db_cluster_snapshot_attribute(input)
+    }
+    fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> RusotoFuture<ModifyDBInstanceResult, ModifyDBInstanceError> {
+        (**self).modify_db_instance(input)
+    }
+    fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyDBSubnetGroupResult, ModifyDBSubnetGroupError> {
+        (**self).modify_db_subnet_group(input)
+    }
+    fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> RusotoFuture<RebootDBInstanceResult, RebootDBInstanceError> {
+        (**self).reboot_db_instance(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<(), RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ResetDBClusterParameterGroupError> {
+        (**self).reset_db_cluster_parameter_group(input)
+    }
+    fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> RusotoFuture<RestoreDBClusterFromSnapshotResult, RestoreDBClusterFromSnapshotError> {
+        (**self).restore_db_cluster_from_snapshot(input)
+    }
+    fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> RusotoFuture<RestoreDBClusterToPointInTimeResult, RestoreDBClusterToPointInTimeError> {
+        (**self).restore_db_cluster_to_point_in_time(input)
+    }
+    fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> RusotoFuture<StartDBClusterResult, StartDBClusterError> {
+        (**self).start_db_cluster(input)
+    }
+    fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> RusotoFuture<StopDBClusterResult, StopDBClusterError> {
+        (**self).stop_db_cluster(input)
+    }
+}
+/// The error codes Amazon DocDB retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon DocDB API.
 #[derive(Clone)]
 pub struct DocdbClient {
@@ -8896,6 +11891,24 @@ impl DocdbClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_DOCDB`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> DocdbClient {
+        Self::new(region::Region::from_env_for_service("DOCDB"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `rds`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> DocdbClient {
+        Self::new_with_client(config.client().clone(), config.region_for("rds", "DOCDB"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -8914,7 +11927,49 @@ impl DocdbClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DocdbClient {
-        DocdbClient { client, region }
+        DocdbClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "rds")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "rds", "rds", "DOCDB")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Docdb` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Docdb + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for DocdbClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon DocDB"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "rds"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> DocdbClient {
+        Self::new_with_client(client, region)
     }
 }
 
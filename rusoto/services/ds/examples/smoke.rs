@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Directory Service clients.
+//!
+//! Makes a single read-only `ListLogSubscriptions` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_ds::{DirectoryService, DirectoryServiceClient, ListLogSubscriptionsRequest};
+
+fn main() {
+    let client = DirectoryServiceClient::from_env();
+    match client
+        .list_log_subscriptions(ListLogSubscriptionsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListLogSubscriptions succeeded"),
+        Err(error) => {
+            eprintln!("ListLogSubscriptions failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AcceptSharedDirectoryRequest {
@@ -30,6 +31,38 @@ pub struct AcceptSharedDirectoryRequest {
     pub shared_directory_id: String,
 }
 
+impl AcceptSharedDirectoryRequest {
+    /// Returns a builder for `AcceptSharedDirectoryRequest`, checking its required members when built.
+    pub fn builder() -> AcceptSharedDirectoryRequestBuilder {
+        AcceptSharedDirectoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AcceptSharedDirectoryRequest`](struct.AcceptSharedDirectoryRequest.html).
+#[derive(Default, Debug)]
+pub struct AcceptSharedDirectoryRequestBuilder {
+    shared_directory_id: Option<String>,
+}
+
+impl AcceptSharedDirectoryRequestBuilder {
+    /// Sets the [`shared_directory_id`](struct.AcceptSharedDirectoryRequest.html#structfield.shared_directory_id) member.
+    pub fn shared_directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.shared_directory_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AcceptSharedDirectoryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AcceptSharedDirectoryRequest, ::rusoto_core::BuildError> {
+        Ok(AcceptSharedDirectoryRequest {
+            shared_directory_id: self
+                .shared_directory_id
+                .ok_or(::rusoto_core::BuildError::missing("shared_directory_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AcceptSharedDirectoryResult {
@@ -53,6 +86,58 @@ pub struct AddIpRoutesRequest {
     pub update_security_group_for_directory_controllers: Option<bool>,
 }
 
+impl AddIpRoutesRequest {
+    /// Returns a builder for `AddIpRoutesRequest`, checking its required members when built.
+    pub fn builder() -> AddIpRoutesRequestBuilder {
+        AddIpRoutesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddIpRoutesRequest`](struct.AddIpRoutesRequest.html).
+#[derive(Default, Debug)]
+pub struct AddIpRoutesRequestBuilder {
+    directory_id: Option<String>,
+    ip_routes: Option<Vec<IpRoute>>,
+    update_security_group_for_directory_controllers: Option<bool>,
+}
+
+impl AddIpRoutesRequestBuilder {
+    /// Sets the [`directory_id`](struct.AddIpRoutesRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ip_routes`](struct.AddIpRoutesRequest.html#structfield.ip_routes) member.
+    pub fn ip_routes<T: Into<Vec<IpRoute>>>(mut self, value: T) -> Self {
+        self.ip_routes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_security_group_for_directory_controllers`](struct.AddIpRoutesRequest.html#structfield.update_security_group_for_directory_controllers) member.
+    pub fn update_security_group_for_directory_controllers<T: Into<bool>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.update_security_group_for_directory_controllers = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddIpRoutesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddIpRoutesRequest, ::rusoto_core::BuildError> {
+        Ok(AddIpRoutesRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            ip_routes: self
+                .ip_routes
+                .ok_or(::rusoto_core::BuildError::missing("ip_routes"))?,
+            update_security_group_for_directory_controllers: self
+                .update_security_group_for_directory_controllers,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddIpRoutesResult {}
@@ -67,6 +152,48 @@ pub struct AddTagsToResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsToResourceRequest {
+    /// Returns a builder for `AddTagsToResourceRequest`, checking its required members when built.
+    pub fn builder() -> AddTagsToResourceRequestBuilder {
+        AddTagsToResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsToResourceRequest`](struct.AddTagsToResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct AddTagsToResourceRequestBuilder {
+    resource_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsToResourceRequestBuilder {
+    /// Sets the [`resource_id`](struct.AddTagsToResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsToResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsToResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddTagsToResourceRequest, ::rusoto_core::BuildError> {
+        Ok(AddTagsToResourceRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddTagsToResourceResult {}
@@ -94,6 +221,48 @@ pub struct CancelSchemaExtensionRequest {
     pub schema_extension_id: String,
 }
 
+impl CancelSchemaExtensionRequest {
+    /// Returns a builder for `CancelSchemaExtensionRequest`, checking its required members when built.
+    pub fn builder() -> CancelSchemaExtensionRequestBuilder {
+        CancelSchemaExtensionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelSchemaExtensionRequest`](struct.CancelSchemaExtensionRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelSchemaExtensionRequestBuilder {
+    directory_id: Option<String>,
+    schema_extension_id: Option<String>,
+}
+
+impl CancelSchemaExtensionRequestBuilder {
+    /// Sets the [`directory_id`](struct.CancelSchemaExtensionRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_extension_id`](struct.CancelSchemaExtensionRequest.html#structfield.schema_extension_id) member.
+    pub fn schema_extension_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema_extension_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelSchemaExtensionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CancelSchemaExtensionRequest, ::rusoto_core::BuildError> {
+        Ok(CancelSchemaExtensionRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            schema_extension_id: self
+                .schema_extension_id
+                .ok_or(::rusoto_core::BuildError::missing("schema_extension_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelSchemaExtensionResult {}
@@ -131,11 +300,11 @@ pub struct ConditionalForwarder {
     /// <p>The replication scope of the conditional forwarder. The only allowed value is <code>Domain</code>, which will replicate the conditional forwarder to all of the domain controllers for your AWS directory.</p>
     #[serde(rename = "ReplicationScope")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replication_scope: Option<String>,
+    pub replication_scope: Option<ReplicationScope>,
 }
 
 /// <p>Contains the inputs for the <a>ConnectDirectory</a> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ConnectDirectoryRequest {
     /// <p>A <a>DirectoryConnectSettings</a> object that contains additional information for the operation.</p>
     #[serde(rename = "ConnectSettings")]
@@ -156,13 +325,99 @@ pub struct ConnectDirectoryRequest {
     pub short_name: Option<String>,
     /// <p>The size of the directory.</p>
     #[serde(rename = "Size")]
-    pub size: String,
+    pub size: DirectorySize,
     /// <p>The tags to be assigned to AD Connector.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
 }
 
+impl ConnectDirectoryRequest {
+    /// Returns a builder for `ConnectDirectoryRequest`, checking its required members when built.
+    pub fn builder() -> ConnectDirectoryRequestBuilder {
+        ConnectDirectoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ConnectDirectoryRequest`](struct.ConnectDirectoryRequest.html).
+#[derive(Default, Debug)]
+pub struct ConnectDirectoryRequestBuilder {
+    connect_settings: Option<DirectoryConnectSettings>,
+    description: Option<String>,
+    name: Option<String>,
+    password: Option<String>,
+    short_name: Option<String>,
+    size: Option<DirectorySize>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl ConnectDirectoryRequestBuilder {
+    /// Sets the [`connect_settings`](struct.ConnectDirectoryRequest.html#structfield.connect_settings) member.
+    pub fn connect_settings<T: Into<DirectoryConnectSettings>>(mut self, value: T) -> Self {
+        self.connect_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.ConnectDirectoryRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.ConnectDirectoryRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.ConnectDirectoryRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`short_name`](struct.ConnectDirectoryRequest.html#structfield.short_name) member.
+    pub fn short_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.short_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`size`](struct.ConnectDirectoryRequest.html#structfield.size) member.
+    pub fn size<T: Into<DirectorySize>>(mut self, value: T) -> Self {
+        self.size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.ConnectDirectoryRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConnectDirectoryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ConnectDirectoryRequest, ::rusoto_core::BuildError> {
+        Ok(ConnectDirectoryRequest {
+            connect_settings: self
+                .connect_settings
+                .ok_or(::rusoto_core::BuildError::missing("connect_settings"))?,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+            short_name: self.short_name,
+            size: self
+                .size
+                .ok_or(::rusoto_core::BuildError::missing("size"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p>Contains the results of the <a>ConnectDirectory</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -184,6 +439,46 @@ pub struct CreateAliasRequest {
     pub directory_id: String,
 }
 
+impl CreateAliasRequest {
+    /// Returns a builder for `CreateAliasRequest`, checking its required members when built.
+    pub fn builder() -> CreateAliasRequestBuilder {
+        CreateAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAliasRequest`](struct.CreateAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAliasRequestBuilder {
+    alias: Option<String>,
+    directory_id: Option<String>,
+}
+
+impl CreateAliasRequestBuilder {
+    /// Sets the [`alias`](struct.CreateAliasRequest.html#structfield.alias) member.
+    pub fn alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_id`](struct.CreateAliasRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateAliasRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAliasRequest {
+            alias: self
+                .alias
+                .ok_or(::rusoto_core::BuildError::missing("alias"))?,
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the results of the <a>CreateAlias</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -220,6 +515,72 @@ pub struct CreateComputerRequest {
     pub password: String,
 }
 
+impl CreateComputerRequest {
+    /// Returns a builder for `CreateComputerRequest`, checking its required members when built.
+    pub fn builder() -> CreateComputerRequestBuilder {
+        CreateComputerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateComputerRequest`](struct.CreateComputerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateComputerRequestBuilder {
+    computer_attributes: Option<Vec<Attribute>>,
+    computer_name: Option<String>,
+    directory_id: Option<String>,
+    organizational_unit_distinguished_name: Option<String>,
+    password: Option<String>,
+}
+
+impl CreateComputerRequestBuilder {
+    /// Sets the [`computer_attributes`](struct.CreateComputerRequest.html#structfield.computer_attributes) member.
+    pub fn computer_attributes<T: Into<Vec<Attribute>>>(mut self, value: T) -> Self {
+        self.computer_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`computer_name`](struct.CreateComputerRequest.html#structfield.computer_name) member.
+    pub fn computer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.computer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_id`](struct.CreateComputerRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organizational_unit_distinguished_name`](struct.CreateComputerRequest.html#structfield.organizational_unit_distinguished_name) member.
+    pub fn organizational_unit_distinguished_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.organizational_unit_distinguished_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.CreateComputerRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateComputerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateComputerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateComputerRequest {
+            computer_attributes: self.computer_attributes,
+            computer_name: self
+                .computer_name
+                .ok_or(::rusoto_core::BuildError::missing("computer_name"))?,
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            organizational_unit_distinguished_name: self.organizational_unit_distinguished_name,
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+        })
+    }
+}
+
 /// <p>Contains the results for the <a>CreateComputer</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -244,13 +605,65 @@ pub struct CreateConditionalForwarderRequest {
     pub remote_domain_name: String,
 }
 
+impl CreateConditionalForwarderRequest {
+    /// Returns a builder for `CreateConditionalForwarderRequest`, checking its required members when built.
+    pub fn builder() -> CreateConditionalForwarderRequestBuilder {
+        CreateConditionalForwarderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConditionalForwarderRequest`](struct.CreateConditionalForwarderRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateConditionalForwarderRequestBuilder {
+    directory_id: Option<String>,
+    dns_ip_addrs: Option<Vec<String>>,
+    remote_domain_name: Option<String>,
+}
+
+impl CreateConditionalForwarderRequestBuilder {
+    /// Sets the [`directory_id`](struct.CreateConditionalForwarderRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dns_ip_addrs`](struct.CreateConditionalForwarderRequest.html#structfield.dns_ip_addrs) member.
+    pub fn dns_ip_addrs<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.dns_ip_addrs = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remote_domain_name`](struct.CreateConditionalForwarderRequest.html#structfield.remote_domain_name) member.
+    pub fn remote_domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.remote_domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConditionalForwarderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateConditionalForwarderRequest, ::rusoto_core::BuildError> {
+        Ok(CreateConditionalForwarderRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            dns_ip_addrs: self
+                .dns_ip_addrs
+                .ok_or(::rusoto_core::BuildError::missing("dns_ip_addrs"))?,
+            remote_domain_name: self
+                .remote_domain_name
+                .ok_or(::rusoto_core::BuildError::missing("remote_domain_name"))?,
+        })
+    }
+}
+
 /// <p>The result of a CreateConditinalForwarder request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateConditionalForwarderResult {}
 
 /// <p>Contains the inputs for the <a>CreateDirectory</a> operation. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateDirectoryRequest {
     /// <p>A textual description for the directory.</p>
     #[serde(rename = "Description")]
@@ -268,7 +681,7 @@ pub struct CreateDirectoryRequest {
     pub short_name: Option<String>,
     /// <p>The size of the directory.</p>
     #[serde(rename = "Size")]
-    pub size: String,
+    pub size: DirectorySize,
     /// <p>The tags to be assigned to the Simple AD directory.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -279,6 +692,88 @@ pub struct CreateDirectoryRequest {
     pub vpc_settings: Option<DirectoryVpcSettings>,
 }
 
+impl CreateDirectoryRequest {
+    /// Returns a builder for `CreateDirectoryRequest`, checking its required members when built.
+    pub fn builder() -> CreateDirectoryRequestBuilder {
+        CreateDirectoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDirectoryRequest`](struct.CreateDirectoryRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDirectoryRequestBuilder {
+    description: Option<String>,
+    name: Option<String>,
+    password: Option<String>,
+    short_name: Option<String>,
+    size: Option<DirectorySize>,
+    tags: Option<Vec<Tag>>,
+    vpc_settings: Option<DirectoryVpcSettings>,
+}
+
+impl CreateDirectoryRequestBuilder {
+    /// Sets the [`description`](struct.CreateDirectoryRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateDirectoryRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.CreateDirectoryRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`short_name`](struct.CreateDirectoryRequest.html#structfield.short_name) member.
+    pub fn short_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.short_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`size`](struct.CreateDirectoryRequest.html#structfield.size) member.
+    pub fn size<T: Into<DirectorySize>>(mut self, value: T) -> Self {
+        self.size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDirectoryRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_settings`](struct.CreateDirectoryRequest.html#structfield.vpc_settings) member.
+    pub fn vpc_settings<T: Into<DirectoryVpcSettings>>(mut self, value: T) -> Self {
+        self.vpc_settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDirectoryRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDirectoryRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDirectoryRequest {
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+            short_name: self.short_name,
+            size: self
+                .size
+                .ok_or(::rusoto_core::BuildError::missing("size"))?,
+            tags: self.tags,
+            vpc_settings: self.vpc_settings,
+        })
+    }
+}
+
 /// <p>Contains the results of the <a>CreateDirectory</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -299,6 +794,48 @@ pub struct CreateLogSubscriptionRequest {
     pub log_group_name: String,
 }
 
+impl CreateLogSubscriptionRequest {
+    /// Returns a builder for `CreateLogSubscriptionRequest`, checking its required members when built.
+    pub fn builder() -> CreateLogSubscriptionRequestBuilder {
+        CreateLogSubscriptionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLogSubscriptionRequest`](struct.CreateLogSubscriptionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLogSubscriptionRequestBuilder {
+    directory_id: Option<String>,
+    log_group_name: Option<String>,
+}
+
+impl CreateLogSubscriptionRequestBuilder {
+    /// Sets the [`directory_id`](struct.CreateLogSubscriptionRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.CreateLogSubscriptionRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLogSubscriptionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLogSubscriptionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateLogSubscriptionRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateLogSubscriptionResult {}
@@ -313,7 +850,7 @@ pub struct CreateMicrosoftADRequest {
     /// <p>AWS Managed Microsoft AD is available in two editions: Standard and Enterprise. Enterprise is the default.</p>
     #[serde(rename = "Edition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub edition: Option<String>,
+    pub edition: Option<DirectoryEdition>,
     /// <p>The fully qualified domain name for the directory, such as <code>corp.example.com</code>. This name will resolve inside your VPC only. It does not need to be publicly resolvable.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -333,6 +870,90 @@ pub struct CreateMicrosoftADRequest {
     pub vpc_settings: DirectoryVpcSettings,
 }
 
+impl CreateMicrosoftADRequest {
+    /// Returns a builder for `CreateMicrosoftADRequest`, checking its required members when built.
+    pub fn builder() -> CreateMicrosoftADRequestBuilder {
+        CreateMicrosoftADRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateMicrosoftADRequest`](struct.CreateMicrosoftADRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateMicrosoftADRequestBuilder {
+    description: Option<String>,
+    edition: Option<DirectoryEdition>,
+    name: Option<String>,
+    password: Option<String>,
+    short_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    vpc_settings: Option<DirectoryVpcSettings>,
+}
+
+impl CreateMicrosoftADRequestBuilder {
+    /// Sets the [`description`](struct.CreateMicrosoftADRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`edition`](struct.CreateMicrosoftADRequest.html#structfield.edition) member.
+    pub fn edition<T: Into<DirectoryEdition>>(mut self, value: T) -> Self {
+        self.edition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateMicrosoftADRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.CreateMicrosoftADRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`short_name`](struct.CreateMicrosoftADRequest.html#structfield.short_name) member.
+    pub fn short_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.short_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateMicrosoftADRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_settings`](struct.CreateMicrosoftADRequest.html#structfield.vpc_settings) member.
+    pub fn vpc_settings<T: Into<DirectoryVpcSettings>>(mut self, value: T) -> Self {
+        self.vpc_settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateMicrosoftADRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateMicrosoftADRequest, ::rusoto_core::BuildError> {
+        Ok(CreateMicrosoftADRequest {
+            description: self.description,
+            edition: self.edition,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+            short_name: self.short_name,
+            tags: self.tags,
+            vpc_settings: self
+                .vpc_settings
+                .ok_or(::rusoto_core::BuildError::missing("vpc_settings"))?,
+        })
+    }
+}
+
 /// <p>Result of a CreateMicrosoftAD request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -355,6 +976,44 @@ pub struct CreateSnapshotRequest {
     pub name: Option<String>,
 }
 
+impl CreateSnapshotRequest {
+    /// Returns a builder for `CreateSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> CreateSnapshotRequestBuilder {
+        CreateSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSnapshotRequest`](struct.CreateSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSnapshotRequestBuilder {
+    directory_id: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateSnapshotRequestBuilder {
+    /// Sets the [`directory_id`](struct.CreateSnapshotRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateSnapshotRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSnapshotRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            name: self.name,
+        })
+    }
+}
+
 /// <p>Contains the results of the <a>CreateSnapshot</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -366,7 +1025,7 @@ pub struct CreateSnapshotResult {
 }
 
 /// <p>AWS Directory Service for Microsoft Active Directory allows you to configure trust relationships. For example, you can establish a trust between your AWS Managed Microsoft AD directory, and your existing on-premises Microsoft Active Directory. This would allow you to provide users and groups access to resources in either domain, with a single set of credentials.</p> <p>This action initiates the creation of the AWS side of a trust relationship between an AWS Managed Microsoft AD directory and an external domain.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateTrustRequest {
     /// <p>The IP addresses of the remote DNS server associated with RemoteDomainName.</p>
     #[serde(rename = "ConditionalForwarderIpAddrs")]
@@ -381,17 +1040,101 @@ pub struct CreateTrustRequest {
     /// <p>Optional parameter to enable selective authentication for the trust.</p>
     #[serde(rename = "SelectiveAuth")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub selective_auth: Option<String>,
+    pub selective_auth: Option<SelectiveAuth>,
     /// <p>The direction of the trust relationship.</p>
     #[serde(rename = "TrustDirection")]
-    pub trust_direction: String,
+    pub trust_direction: TrustDirection,
     /// <p>The trust password. The must be the same password that was used when creating the trust relationship on the external domain.</p>
     #[serde(rename = "TrustPassword")]
     pub trust_password: String,
     /// <p>The trust relationship type. <code>Forest</code> is the default.</p>
     #[serde(rename = "TrustType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trust_type: Option<String>,
+    pub trust_type: Option<TrustType>,
+}
+
+impl CreateTrustRequest {
+    /// Returns a builder for `CreateTrustRequest`, checking its required members when built.
+    pub fn builder() -> CreateTrustRequestBuilder {
+        CreateTrustRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTrustRequest`](struct.CreateTrustRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTrustRequestBuilder {
+    conditional_forwarder_ip_addrs: Option<Vec<String>>,
+    directory_id: Option<String>,
+    remote_domain_name: Option<String>,
+    selective_auth: Option<SelectiveAuth>,
+    trust_direction: Option<TrustDirection>,
+    trust_password: Option<String>,
+    trust_type: Option<TrustType>,
+}
+
+impl CreateTrustRequestBuilder {
+    /// Sets the [`conditional_forwarder_ip_addrs`](struct.CreateTrustRequest.html#structfield.conditional_forwarder_ip_addrs) member.
+    pub fn conditional_forwarder_ip_addrs<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.conditional_forwarder_ip_addrs = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_id`](struct.CreateTrustRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remote_domain_name`](struct.CreateTrustRequest.html#structfield.remote_domain_name) member.
+    pub fn remote_domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.remote_domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`selective_auth`](struct.CreateTrustRequest.html#structfield.selective_auth) member.
+    pub fn selective_auth<T: Into<SelectiveAuth>>(mut self, value: T) -> Self {
+        self.selective_auth = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trust_direction`](struct.CreateTrustRequest.html#structfield.trust_direction) member.
+    pub fn trust_direction<T: Into<TrustDirection>>(mut self, value: T) -> Self {
+        self.trust_direction = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trust_password`](struct.CreateTrustRequest.html#structfield.trust_password) member.
+    pub fn trust_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.trust_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trust_type`](struct.CreateTrustRequest.html#structfield.trust_type) member.
+    pub fn trust_type<T: Into<TrustType>>(mut self, value: T) -> Self {
+        self.trust_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTrustRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTrustRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTrustRequest {
+            conditional_forwarder_ip_addrs: self.conditional_forwarder_ip_addrs,
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            remote_domain_name: self
+                .remote_domain_name
+                .ok_or(::rusoto_core::BuildError::missing("remote_domain_name"))?,
+            selective_auth: self.selective_auth,
+            trust_direction: self
+                .trust_direction
+                .ok_or(::rusoto_core::BuildError::missing("trust_direction"))?,
+            trust_password: self
+                .trust_password
+                .ok_or(::rusoto_core::BuildError::missing("trust_password"))?,
+            trust_type: self.trust_type,
+        })
+    }
 }
 
 /// <p>The result of a CreateTrust request.</p>
@@ -415,6 +1158,48 @@ pub struct DeleteConditionalForwarderRequest {
     pub remote_domain_name: String,
 }
 
+impl DeleteConditionalForwarderRequest {
+    /// Returns a builder for `DeleteConditionalForwarderRequest`, checking its required members when built.
+    pub fn builder() -> DeleteConditionalForwarderRequestBuilder {
+        DeleteConditionalForwarderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConditionalForwarderRequest`](struct.DeleteConditionalForwarderRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteConditionalForwarderRequestBuilder {
+    directory_id: Option<String>,
+    remote_domain_name: Option<String>,
+}
+
+impl DeleteConditionalForwarderRequestBuilder {
+    /// Sets the [`directory_id`](struct.DeleteConditionalForwarderRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remote_domain_name`](struct.DeleteConditionalForwarderRequest.html#structfield.remote_domain_name) member.
+    pub fn remote_domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.remote_domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConditionalForwarderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteConditionalForwarderRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteConditionalForwarderRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            remote_domain_name: self
+                .remote_domain_name
+                .ok_or(::rusoto_core::BuildError::missing("remote_domain_name"))?,
+        })
+    }
+}
+
 /// <p>The result of a DeleteConditionalForwarder request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -428,6 +1213,36 @@ pub struct DeleteDirectoryRequest {
     pub directory_id: String,
 }
 
+impl DeleteDirectoryRequest {
+    /// Returns a builder for `DeleteDirectoryRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDirectoryRequestBuilder {
+        DeleteDirectoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDirectoryRequest`](struct.DeleteDirectoryRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDirectoryRequestBuilder {
+    directory_id: Option<String>,
+}
+
+impl DeleteDirectoryRequestBuilder {
+    /// Sets the [`directory_id`](struct.DeleteDirectoryRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDirectoryRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDirectoryRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDirectoryRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the results of the <a>DeleteDirectory</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -445,6 +1260,38 @@ pub struct DeleteLogSubscriptionRequest {
     pub directory_id: String,
 }
 
+impl DeleteLogSubscriptionRequest {
+    /// Returns a builder for `DeleteLogSubscriptionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLogSubscriptionRequestBuilder {
+        DeleteLogSubscriptionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLogSubscriptionRequest`](struct.DeleteLogSubscriptionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLogSubscriptionRequestBuilder {
+    directory_id: Option<String>,
+}
+
+impl DeleteLogSubscriptionRequestBuilder {
+    /// Sets the [`directory_id`](struct.DeleteLogSubscriptionRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLogSubscriptionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLogSubscriptionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLogSubscriptionRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteLogSubscriptionResult {}
@@ -457,7 +1304,37 @@ pub struct DeleteSnapshotRequest {
     pub snapshot_id: String,
 }
 
-/// <p>Contains the results of the <a>DeleteSnapshot</a> operation.</p>
+impl DeleteSnapshotRequest {
+    /// Returns a builder for `DeleteSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSnapshotRequestBuilder {
+        DeleteSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSnapshotRequest`](struct.DeleteSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSnapshotRequestBuilder {
+    snapshot_id: Option<String>,
+}
+
+impl DeleteSnapshotRequestBuilder {
+    /// Sets the [`snapshot_id`](struct.DeleteSnapshotRequest.html#structfield.snapshot_id) member.
+    pub fn snapshot_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSnapshotRequest {
+            snapshot_id: self
+                .snapshot_id
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_id"))?,
+        })
+    }
+}
+
+/// <p>Contains the results of the <a>DeleteSnapshot</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSnapshotResult {
@@ -479,6 +1356,44 @@ pub struct DeleteTrustRequest {
     pub trust_id: String,
 }
 
+impl DeleteTrustRequest {
+    /// Returns a builder for `DeleteTrustRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTrustRequestBuilder {
+        DeleteTrustRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTrustRequest`](struct.DeleteTrustRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTrustRequestBuilder {
+    delete_associated_conditional_forwarder: Option<bool>,
+    trust_id: Option<String>,
+}
+
+impl DeleteTrustRequestBuilder {
+    /// Sets the [`delete_associated_conditional_forwarder`](struct.DeleteTrustRequest.html#structfield.delete_associated_conditional_forwarder) member.
+    pub fn delete_associated_conditional_forwarder<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_associated_conditional_forwarder = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trust_id`](struct.DeleteTrustRequest.html#structfield.trust_id) member.
+    pub fn trust_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.trust_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTrustRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTrustRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTrustRequest {
+            delete_associated_conditional_forwarder: self.delete_associated_conditional_forwarder,
+            trust_id: self
+                .trust_id
+                .ok_or(::rusoto_core::BuildError::missing("trust_id"))?,
+        })
+    }
+}
+
 /// <p>The result of a DeleteTrust request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -500,6 +1415,48 @@ pub struct DeregisterEventTopicRequest {
     pub topic_name: String,
 }
 
+impl DeregisterEventTopicRequest {
+    /// Returns a builder for `DeregisterEventTopicRequest`, checking its required members when built.
+    pub fn builder() -> DeregisterEventTopicRequestBuilder {
+        DeregisterEventTopicRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterEventTopicRequest`](struct.DeregisterEventTopicRequest.html).
+#[derive(Default, Debug)]
+pub struct DeregisterEventTopicRequestBuilder {
+    directory_id: Option<String>,
+    topic_name: Option<String>,
+}
+
+impl DeregisterEventTopicRequestBuilder {
+    /// Sets the [`directory_id`](struct.DeregisterEventTopicRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_name`](struct.DeregisterEventTopicRequest.html#structfield.topic_name) member.
+    pub fn topic_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterEventTopicRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterEventTopicRequest, ::rusoto_core::BuildError> {
+        Ok(DeregisterEventTopicRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            topic_name: self
+                .topic_name
+                .ok_or(::rusoto_core::BuildError::missing("topic_name"))?,
+        })
+    }
+}
+
 /// <p>The result of a DeregisterEventTopic request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -517,6 +1474,47 @@ pub struct DescribeConditionalForwardersRequest {
     pub remote_domain_names: Option<Vec<String>>,
 }
 
+impl DescribeConditionalForwardersRequest {
+    /// Returns a builder for `DescribeConditionalForwardersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConditionalForwardersRequestBuilder {
+        DescribeConditionalForwardersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConditionalForwardersRequest`](struct.DescribeConditionalForwardersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConditionalForwardersRequestBuilder {
+    directory_id: Option<String>,
+    remote_domain_names: Option<Vec<String>>,
+}
+
+impl DescribeConditionalForwardersRequestBuilder {
+    /// Sets the [`directory_id`](struct.DescribeConditionalForwardersRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remote_domain_names`](struct.DescribeConditionalForwardersRequest.html#structfield.remote_domain_names) member.
+    pub fn remote_domain_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.remote_domain_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConditionalForwardersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConditionalForwardersRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeConditionalForwardersRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            remote_domain_names: self.remote_domain_names,
+        })
+    }
+}
+
 /// <p>The result of a DescribeConditionalForwarder request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -544,6 +1542,52 @@ pub struct DescribeDirectoriesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeDirectoriesRequest {
+    /// Returns a builder for `DescribeDirectoriesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDirectoriesRequestBuilder {
+        DescribeDirectoriesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDirectoriesRequest`](struct.DescribeDirectoriesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDirectoriesRequestBuilder {
+    directory_ids: Option<Vec<String>>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeDirectoriesRequestBuilder {
+    /// Sets the [`directory_ids`](struct.DescribeDirectoriesRequest.html#structfield.directory_ids) member.
+    pub fn directory_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.directory_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeDirectoriesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeDirectoriesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDirectoriesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDirectoriesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDirectoriesRequest {
+            directory_ids: self.directory_ids,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Contains the results of the <a>DescribeDirectories</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -577,6 +1621,62 @@ pub struct DescribeDomainControllersRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeDomainControllersRequest {
+    /// Returns a builder for `DescribeDomainControllersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDomainControllersRequestBuilder {
+        DescribeDomainControllersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDomainControllersRequest`](struct.DescribeDomainControllersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDomainControllersRequestBuilder {
+    directory_id: Option<String>,
+    domain_controller_ids: Option<Vec<String>>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeDomainControllersRequestBuilder {
+    /// Sets the [`directory_id`](struct.DescribeDomainControllersRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_controller_ids`](struct.DescribeDomainControllersRequest.html#structfield.domain_controller_ids) member.
+    pub fn domain_controller_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.domain_controller_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeDomainControllersRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeDomainControllersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDomainControllersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDomainControllersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDomainControllersRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            domain_controller_ids: self.domain_controller_ids,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDomainControllersResult {
@@ -603,6 +1703,44 @@ pub struct DescribeEventTopicsRequest {
     pub topic_names: Option<Vec<String>>,
 }
 
+impl DescribeEventTopicsRequest {
+    /// Returns a builder for `DescribeEventTopicsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEventTopicsRequestBuilder {
+        DescribeEventTopicsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventTopicsRequest`](struct.DescribeEventTopicsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventTopicsRequestBuilder {
+    directory_id: Option<String>,
+    topic_names: Option<Vec<String>>,
+}
+
+impl DescribeEventTopicsRequestBuilder {
+    /// Sets the [`directory_id`](struct.DescribeEventTopicsRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_names`](struct.DescribeEventTopicsRequest.html#structfield.topic_names) member.
+    pub fn topic_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.topic_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventTopicsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventTopicsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEventTopicsRequest {
+            directory_id: self.directory_id,
+            topic_names: self.topic_names,
+        })
+    }
+}
+
 /// <p>The result of a DescribeEventTopic request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -632,6 +1770,62 @@ pub struct DescribeSharedDirectoriesRequest {
     pub shared_directory_ids: Option<Vec<String>>,
 }
 
+impl DescribeSharedDirectoriesRequest {
+    /// Returns a builder for `DescribeSharedDirectoriesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeSharedDirectoriesRequestBuilder {
+        DescribeSharedDirectoriesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSharedDirectoriesRequest`](struct.DescribeSharedDirectoriesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeSharedDirectoriesRequestBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+    owner_directory_id: Option<String>,
+    shared_directory_ids: Option<Vec<String>>,
+}
+
+impl DescribeSharedDirectoriesRequestBuilder {
+    /// Sets the [`limit`](struct.DescribeSharedDirectoriesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeSharedDirectoriesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`owner_directory_id`](struct.DescribeSharedDirectoriesRequest.html#structfield.owner_directory_id) member.
+    pub fn owner_directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.owner_directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shared_directory_ids`](struct.DescribeSharedDirectoriesRequest.html#structfield.shared_directory_ids) member.
+    pub fn shared_directory_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.shared_directory_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSharedDirectoriesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSharedDirectoriesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeSharedDirectoriesRequest {
+            limit: self.limit,
+            next_token: self.next_token,
+            owner_directory_id: self
+                .owner_directory_id
+                .ok_or(::rusoto_core::BuildError::missing("owner_directory_id"))?,
+            shared_directory_ids: self.shared_directory_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeSharedDirectoriesResult {
@@ -666,6 +1860,60 @@ pub struct DescribeSnapshotsRequest {
     pub snapshot_ids: Option<Vec<String>>,
 }
 
+impl DescribeSnapshotsRequest {
+    /// Returns a builder for `DescribeSnapshotsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeSnapshotsRequestBuilder {
+        DescribeSnapshotsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSnapshotsRequest`](struct.DescribeSnapshotsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeSnapshotsRequestBuilder {
+    directory_id: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    snapshot_ids: Option<Vec<String>>,
+}
+
+impl DescribeSnapshotsRequestBuilder {
+    /// Sets the [`directory_id`](struct.DescribeSnapshotsRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeSnapshotsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeSnapshotsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_ids`](struct.DescribeSnapshotsRequest.html#structfield.snapshot_ids) member.
+    pub fn snapshot_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.snapshot_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSnapshotsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSnapshotsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeSnapshotsRequest {
+            directory_id: self.directory_id,
+            limit: self.limit,
+            next_token: self.next_token,
+            snapshot_ids: self.snapshot_ids,
+        })
+    }
+}
+
 /// <p>Contains the results of the <a>DescribeSnapshots</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -701,6 +1949,58 @@ pub struct DescribeTrustsRequest {
     pub trust_ids: Option<Vec<String>>,
 }
 
+impl DescribeTrustsRequest {
+    /// Returns a builder for `DescribeTrustsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTrustsRequestBuilder {
+        DescribeTrustsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTrustsRequest`](struct.DescribeTrustsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTrustsRequestBuilder {
+    directory_id: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    trust_ids: Option<Vec<String>>,
+}
+
+impl DescribeTrustsRequestBuilder {
+    /// Sets the [`directory_id`](struct.DescribeTrustsRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeTrustsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeTrustsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trust_ids`](struct.DescribeTrustsRequest.html#structfield.trust_ids) member.
+    pub fn trust_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.trust_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTrustsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTrustsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTrustsRequest {
+            directory_id: self.directory_id,
+            limit: self.limit,
+            next_token: self.next_token,
+            trust_ids: self.trust_ids,
+        })
+    }
+}
+
 /// <p>The result of a DescribeTrust request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -797,11 +2097,12 @@ pub struct DirectoryDescription {
     /// <p>The edition associated with this directory.</p>
     #[serde(rename = "Edition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub edition: Option<String>,
+    pub edition: Option<DirectoryEdition>,
     /// <p>Specifies when the directory was created.</p>
     #[serde(rename = "LaunchTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_time: Option<f64>,
+    pub launch_time: Option<EpochTimestamp>,
     /// <p>The fully qualified name of the directory.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -817,11 +2118,11 @@ pub struct DirectoryDescription {
     /// <p>The status of the RADIUS MFA server connection.</p>
     #[serde(rename = "RadiusStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub radius_status: Option<String>,
+    pub radius_status: Option<RadiusStatus>,
     /// <p>The method used when sharing a directory to determine whether the directory should be shared within your AWS organization (<code>ORGANIZATIONS</code>) or with any AWS account by sending a shared directory request (<code>HANDSHAKE</code>).</p>
     #[serde(rename = "ShareMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub share_method: Option<String>,
+    pub share_method: Option<ShareMethod>,
     /// <p>A directory share request that is sent by the directory owner to the directory consumer. The request includes a typed message to help the directory consumer administrator determine whether to approve or reject the share invitation.</p>
     #[serde(rename = "ShareNotes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -829,7 +2130,7 @@ pub struct DirectoryDescription {
     /// <p>Current directory status of the shared AWS Managed Microsoft AD directory.</p>
     #[serde(rename = "ShareStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub share_status: Option<String>,
+    pub share_status: Option<ShareStatus>,
     /// <p>The short name of the directory.</p>
     #[serde(rename = "ShortName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -837,7 +2138,7 @@ pub struct DirectoryDescription {
     /// <p>The directory size.</p>
     #[serde(rename = "Size")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub size: Option<String>,
+    pub size: Option<DirectorySize>,
     /// <p>Indicates if single sign-on is enabled for the directory. For more information, see <a>EnableSso</a> and <a>DisableSso</a>.</p>
     #[serde(rename = "SsoEnabled")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -845,11 +2146,12 @@ pub struct DirectoryDescription {
     /// <p>The current stage of the directory.</p>
     #[serde(rename = "Stage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stage: Option<String>,
+    pub stage: Option<DirectoryStage>,
     /// <p>The date and time that the stage was last updated.</p>
     #[serde(rename = "StageLastUpdatedDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stage_last_updated_date_time: Option<f64>,
+    pub stage_last_updated_date_time: Option<EpochTimestamp>,
     /// <p>Additional information about the directory stage.</p>
     #[serde(rename = "StageReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -857,13 +2159,85 @@ pub struct DirectoryDescription {
     /// <p>The directory size.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<DirectoryType>,
     /// <p>A <a>DirectoryVpcSettingsDescription</a> object that contains additional information about a directory. This member is only present if the directory is a Simple AD or Managed AD directory.</p>
     #[serde(rename = "VpcSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vpc_settings: Option<DirectoryVpcSettingsDescription>,
 }
 
+/// <p>AWS Managed Microsoft AD is available in two editions: Standard and Enterprise. Enterprise is the default.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DirectoryEdition {
+    /// <p><code>Enterprise</code></p>
+    Enterprise,
+    /// <p><code>Standard</code></p>
+    Standard,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DirectoryEdition {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectoryEdition::Enterprise => "Enterprise",
+            DirectoryEdition::Standard => "Standard",
+            DirectoryEdition::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DirectoryEdition {
+    fn from(value: &'a str) -> DirectoryEdition {
+        match value {
+            "Enterprise" => DirectoryEdition::Enterprise,
+            "Standard" => DirectoryEdition::Standard,
+            _ => DirectoryEdition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectoryEdition {
+    fn from(value: String) -> DirectoryEdition {
+        DirectoryEdition::from(value.as_str())
+    }
+}
+
+impl From<DirectoryEdition> for String {
+    fn from(value: DirectoryEdition) -> String {
+        match value {
+            DirectoryEdition::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DirectoryEdition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DirectoryEdition {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectoryEdition {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DirectoryEdition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DirectoryEdition::from(value))
+    }
+}
+
 /// <p>Contains directory limit information for a region.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -906,80 +2280,416 @@ pub struct DirectoryLimits {
     pub connected_directories_limit_reached: Option<bool>,
 }
 
-/// <p>Contains VPC information for the <a>CreateDirectory</a> or <a>CreateMicrosoftAD</a> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DirectoryVpcSettings {
-    /// <p>The identifiers of the subnets for the directory servers. The two subnets must be in different Availability Zones. AWS Directory Service creates a directory server and a DNS server in each of these subnets.</p>
-    #[serde(rename = "SubnetIds")]
-    pub subnet_ids: Vec<String>,
-    /// <p>The identifier of the VPC in which to create the directory.</p>
-    #[serde(rename = "VpcId")]
-    pub vpc_id: String,
+/// <p>The size of the directory.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DirectorySize {
+    /// <p><code>Small</code></p>
+    Small,
+    /// <p><code>Large</code></p>
+    Large,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Contains information about the directory.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DirectoryVpcSettingsDescription {
-    /// <p>The list of Availability Zones that the directory is in.</p>
-    #[serde(rename = "AvailabilityZones")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub availability_zones: Option<Vec<String>>,
-    /// <p>The domain controller security group identifier for the directory.</p>
-    #[serde(rename = "SecurityGroupId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_group_id: Option<String>,
-    /// <p>The identifiers of the subnets for the directory servers.</p>
-    #[serde(rename = "SubnetIds")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub subnet_ids: Option<Vec<String>>,
-    /// <p>The identifier of the VPC that the directory is in.</p>
-    #[serde(rename = "VpcId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub vpc_id: Option<String>,
+impl DirectorySize {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectorySize::Small => "Small",
+            DirectorySize::Large => "Large",
+            DirectorySize::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Contains the inputs for the <a>DisableRadius</a> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DisableRadiusRequest {
-    /// <p>The identifier of the directory for which to disable MFA.</p>
-    #[serde(rename = "DirectoryId")]
-    pub directory_id: String,
+impl<'a> From<&'a str> for DirectorySize {
+    fn from(value: &'a str) -> DirectorySize {
+        match value {
+            "Small" => DirectorySize::Small,
+            "Large" => DirectorySize::Large,
+            _ => DirectorySize::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>Contains the results of the <a>DisableRadius</a> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DisableRadiusResult {}
+impl From<String> for DirectorySize {
+    fn from(value: String) -> DirectorySize {
+        DirectorySize::from(value.as_str())
+    }
+}
 
-/// <p>Contains the inputs for the <a>DisableSso</a> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DisableSsoRequest {
-    /// <p>The identifier of the directory for which to disable single-sign on.</p>
-    #[serde(rename = "DirectoryId")]
-    pub directory_id: String,
-    /// <p>The password of an alternate account to use to disable single-sign on. This is only used for AD Connector directories. For more information, see the <i>UserName</i> parameter.</p>
-    #[serde(rename = "Password")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub password: Option<String>,
-    /// <p>The username of an alternate account to use to disable single-sign on. This is only used for AD Connector directories. This account must have privileges to remove a service principal name.</p> <p>If the AD Connector service account does not have privileges to remove a service principal name, you can specify an alternate account with the <i>UserName</i> and <i>Password</i> parameters. These credentials are only used to disable single sign-on and are not stored by the service. The AD Connector service account is not changed.</p>
-    #[serde(rename = "UserName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub user_name: Option<String>,
+impl From<DirectorySize> for String {
+    fn from(value: DirectorySize) -> String {
+        match value {
+            DirectorySize::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-/// <p>Contains the results of the <a>DisableSso</a> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DisableSsoResult {}
+impl fmt::Display for DirectorySize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
 
-/// <p>Contains information about the domain controllers for a specified directory.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DomainController {
-    /// <p>The Availability Zone where the domain controller is located.</p>
-    #[serde(rename = "AvailabilityZone")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl ::serde::Serialize for DirectorySize {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectorySize {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DirectorySize, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DirectorySize::from(value))
+    }
+}
+
+/// <p>The current stage of the directory.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DirectoryStage {
+    /// <p><code>Requested</code></p>
+    Requested,
+    /// <p><code>Creating</code></p>
+    Creating,
+    /// <p><code>Created</code></p>
+    Created,
+    /// <p><code>Active</code></p>
+    Active,
+    /// <p><code>Inoperable</code></p>
+    Inoperable,
+    /// <p><code>Impaired</code></p>
+    Impaired,
+    /// <p><code>Restoring</code></p>
+    Restoring,
+    /// <p><code>RestoreFailed</code></p>
+    RestoreFailed,
+    /// <p><code>Deleting</code></p>
+    Deleting,
+    /// <p><code>Deleted</code></p>
+    Deleted,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DirectoryStage {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectoryStage::Requested => "Requested",
+            DirectoryStage::Creating => "Creating",
+            DirectoryStage::Created => "Created",
+            DirectoryStage::Active => "Active",
+            DirectoryStage::Inoperable => "Inoperable",
+            DirectoryStage::Impaired => "Impaired",
+            DirectoryStage::Restoring => "Restoring",
+            DirectoryStage::RestoreFailed => "RestoreFailed",
+            DirectoryStage::Deleting => "Deleting",
+            DirectoryStage::Deleted => "Deleted",
+            DirectoryStage::Failed => "Failed",
+            DirectoryStage::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DirectoryStage {
+    fn from(value: &'a str) -> DirectoryStage {
+        match value {
+            "Requested" => DirectoryStage::Requested,
+            "Creating" => DirectoryStage::Creating,
+            "Created" => DirectoryStage::Created,
+            "Active" => DirectoryStage::Active,
+            "Inoperable" => DirectoryStage::Inoperable,
+            "Impaired" => DirectoryStage::Impaired,
+            "Restoring" => DirectoryStage::Restoring,
+            "RestoreFailed" => DirectoryStage::RestoreFailed,
+            "Deleting" => DirectoryStage::Deleting,
+            "Deleted" => DirectoryStage::Deleted,
+            "Failed" => DirectoryStage::Failed,
+            _ => DirectoryStage::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectoryStage {
+    fn from(value: String) -> DirectoryStage {
+        DirectoryStage::from(value.as_str())
+    }
+}
+
+impl From<DirectoryStage> for String {
+    fn from(value: DirectoryStage) -> String {
+        match value {
+            DirectoryStage::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DirectoryStage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DirectoryStage {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectoryStage {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DirectoryStage, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DirectoryStage::from(value))
+    }
+}
+
+/// <p>The directory size.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DirectoryType {
+    /// <p><code>SimpleAD</code></p>
+    SimpleAD,
+    /// <p><code>ADConnector</code></p>
+    ADConnector,
+    /// <p><code>MicrosoftAD</code></p>
+    MicrosoftAD,
+    /// <p><code>SharedMicrosoftAD</code></p>
+    SharedMicrosoftAD,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DirectoryType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DirectoryType::SimpleAD => "SimpleAD",
+            DirectoryType::ADConnector => "ADConnector",
+            DirectoryType::MicrosoftAD => "MicrosoftAD",
+            DirectoryType::SharedMicrosoftAD => "SharedMicrosoftAD",
+            DirectoryType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DirectoryType {
+    fn from(value: &'a str) -> DirectoryType {
+        match value {
+            "SimpleAD" => DirectoryType::SimpleAD,
+            "ADConnector" => DirectoryType::ADConnector,
+            "MicrosoftAD" => DirectoryType::MicrosoftAD,
+            "SharedMicrosoftAD" => DirectoryType::SharedMicrosoftAD,
+            _ => DirectoryType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DirectoryType {
+    fn from(value: String) -> DirectoryType {
+        DirectoryType::from(value.as_str())
+    }
+}
+
+impl From<DirectoryType> for String {
+    fn from(value: DirectoryType) -> String {
+        match value {
+            DirectoryType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DirectoryType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DirectoryType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DirectoryType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DirectoryType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DirectoryType::from(value))
+    }
+}
+
+/// <p>Contains VPC information for the <a>CreateDirectory</a> or <a>CreateMicrosoftAD</a> operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DirectoryVpcSettings {
+    /// <p>The identifiers of the subnets for the directory servers. The two subnets must be in different Availability Zones. AWS Directory Service creates a directory server and a DNS server in each of these subnets.</p>
+    #[serde(rename = "SubnetIds")]
+    pub subnet_ids: Vec<String>,
+    /// <p>The identifier of the VPC in which to create the directory.</p>
+    #[serde(rename = "VpcId")]
+    pub vpc_id: String,
+}
+
+/// <p>Contains information about the directory.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DirectoryVpcSettingsDescription {
+    /// <p>The list of Availability Zones that the directory is in.</p>
+    #[serde(rename = "AvailabilityZones")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub availability_zones: Option<Vec<String>>,
+    /// <p>The domain controller security group identifier for the directory.</p>
+    #[serde(rename = "SecurityGroupId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security_group_id: Option<String>,
+    /// <p>The identifiers of the subnets for the directory servers.</p>
+    #[serde(rename = "SubnetIds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subnet_ids: Option<Vec<String>>,
+    /// <p>The identifier of the VPC that the directory is in.</p>
+    #[serde(rename = "VpcId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vpc_id: Option<String>,
+}
+
+/// <p>Contains the inputs for the <a>DisableRadius</a> operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DisableRadiusRequest {
+    /// <p>The identifier of the directory for which to disable MFA.</p>
+    #[serde(rename = "DirectoryId")]
+    pub directory_id: String,
+}
+
+impl DisableRadiusRequest {
+    /// Returns a builder for `DisableRadiusRequest`, checking its required members when built.
+    pub fn builder() -> DisableRadiusRequestBuilder {
+        DisableRadiusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisableRadiusRequest`](struct.DisableRadiusRequest.html).
+#[derive(Default, Debug)]
+pub struct DisableRadiusRequestBuilder {
+    directory_id: Option<String>,
+}
+
+impl DisableRadiusRequestBuilder {
+    /// Sets the [`directory_id`](struct.DisableRadiusRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableRadiusRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DisableRadiusRequest, ::rusoto_core::BuildError> {
+        Ok(DisableRadiusRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+        })
+    }
+}
+
+/// <p>Contains the results of the <a>DisableRadius</a> operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DisableRadiusResult {}
+
+/// <p>Contains the inputs for the <a>DisableSso</a> operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DisableSsoRequest {
+    /// <p>The identifier of the directory for which to disable single-sign on.</p>
+    #[serde(rename = "DirectoryId")]
+    pub directory_id: String,
+    /// <p>The password of an alternate account to use to disable single-sign on. This is only used for AD Connector directories. For more information, see the <i>UserName</i> parameter.</p>
+    #[serde(rename = "Password")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// <p>The username of an alternate account to use to disable single-sign on. This is only used for AD Connector directories. This account must have privileges to remove a service principal name.</p> <p>If the AD Connector service account does not have privileges to remove a service principal name, you can specify an alternate account with the <i>UserName</i> and <i>Password</i> parameters. These credentials are only used to disable single sign-on and are not stored by the service. The AD Connector service account is not changed.</p>
+    #[serde(rename = "UserName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+}
+
+impl DisableSsoRequest {
+    /// Returns a builder for `DisableSsoRequest`, checking its required members when built.
+    pub fn builder() -> DisableSsoRequestBuilder {
+        DisableSsoRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisableSsoRequest`](struct.DisableSsoRequest.html).
+#[derive(Default, Debug)]
+pub struct DisableSsoRequestBuilder {
+    directory_id: Option<String>,
+    password: Option<String>,
+    user_name: Option<String>,
+}
+
+impl DisableSsoRequestBuilder {
+    /// Sets the [`directory_id`](struct.DisableSsoRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.DisableSsoRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DisableSsoRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableSsoRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DisableSsoRequest, ::rusoto_core::BuildError> {
+        Ok(DisableSsoRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            password: self.password,
+            user_name: self.user_name,
+        })
+    }
+}
+
+/// <p>Contains the results of the <a>DisableSso</a> operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DisableSsoResult {}
+
+/// <p>Contains information about the domain controllers for a specified directory.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DomainController {
+    /// <p>The Availability Zone where the domain controller is located.</p>
+    #[serde(rename = "AvailabilityZone")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub availability_zone: Option<String>,
     /// <p>Identifier of the directory where the domain controller resides.</p>
     #[serde(rename = "DirectoryId")]
@@ -995,16 +2705,18 @@ pub struct DomainController {
     pub domain_controller_id: Option<String>,
     /// <p>Specifies when the domain controller was created.</p>
     #[serde(rename = "LaunchTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_time: Option<f64>,
+    pub launch_time: Option<EpochTimestamp>,
     /// <p>The status of the domain controller.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DomainControllerStatus>,
     /// <p>The date and time that the status was last updated.</p>
     #[serde(rename = "StatusLastUpdatedDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_last_updated_date_time: Option<f64>,
+    pub status_last_updated_date_time: Option<EpochTimestamp>,
     /// <p>A description of the domain controller state.</p>
     #[serde(rename = "StatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1019,6 +2731,98 @@ pub struct DomainController {
     pub vpc_id: Option<String>,
 }
 
+/// <p>The status of the domain controller.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DomainControllerStatus {
+    /// <p><code>Creating</code></p>
+    Creating,
+    /// <p><code>Active</code></p>
+    Active,
+    /// <p><code>Impaired</code></p>
+    Impaired,
+    /// <p><code>Restoring</code></p>
+    Restoring,
+    /// <p><code>Deleting</code></p>
+    Deleting,
+    /// <p><code>Deleted</code></p>
+    Deleted,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DomainControllerStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DomainControllerStatus::Creating => "Creating",
+            DomainControllerStatus::Active => "Active",
+            DomainControllerStatus::Impaired => "Impaired",
+            DomainControllerStatus::Restoring => "Restoring",
+            DomainControllerStatus::Deleting => "Deleting",
+            DomainControllerStatus::Deleted => "Deleted",
+            DomainControllerStatus::Failed => "Failed",
+            DomainControllerStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DomainControllerStatus {
+    fn from(value: &'a str) -> DomainControllerStatus {
+        match value {
+            "Creating" => DomainControllerStatus::Creating,
+            "Active" => DomainControllerStatus::Active,
+            "Impaired" => DomainControllerStatus::Impaired,
+            "Restoring" => DomainControllerStatus::Restoring,
+            "Deleting" => DomainControllerStatus::Deleting,
+            "Deleted" => DomainControllerStatus::Deleted,
+            "Failed" => DomainControllerStatus::Failed,
+            _ => DomainControllerStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DomainControllerStatus {
+    fn from(value: String) -> DomainControllerStatus {
+        DomainControllerStatus::from(value.as_str())
+    }
+}
+
+impl From<DomainControllerStatus> for String {
+    fn from(value: DomainControllerStatus) -> String {
+        match value {
+            DomainControllerStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DomainControllerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DomainControllerStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DomainControllerStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DomainControllerStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DomainControllerStatus::from(value))
+    }
+}
+
 /// <p>Contains the inputs for the <a>EnableRadius</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct EnableRadiusRequest {
@@ -1030,6 +2834,46 @@ pub struct EnableRadiusRequest {
     pub radius_settings: RadiusSettings,
 }
 
+impl EnableRadiusRequest {
+    /// Returns a builder for `EnableRadiusRequest`, checking its required members when built.
+    pub fn builder() -> EnableRadiusRequestBuilder {
+        EnableRadiusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableRadiusRequest`](struct.EnableRadiusRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableRadiusRequestBuilder {
+    directory_id: Option<String>,
+    radius_settings: Option<RadiusSettings>,
+}
+
+impl EnableRadiusRequestBuilder {
+    /// Sets the [`directory_id`](struct.EnableRadiusRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`radius_settings`](struct.EnableRadiusRequest.html#structfield.radius_settings) member.
+    pub fn radius_settings<T: Into<RadiusSettings>>(mut self, value: T) -> Self {
+        self.radius_settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableRadiusRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<EnableRadiusRequest, ::rusoto_core::BuildError> {
+        Ok(EnableRadiusRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            radius_settings: self
+                .radius_settings
+                .ok_or(::rusoto_core::BuildError::missing("radius_settings"))?,
+        })
+    }
+}
+
 /// <p>Contains the results of the <a>EnableRadius</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1051,6 +2895,52 @@ pub struct EnableSsoRequest {
     pub user_name: Option<String>,
 }
 
+impl EnableSsoRequest {
+    /// Returns a builder for `EnableSsoRequest`, checking its required members when built.
+    pub fn builder() -> EnableSsoRequestBuilder {
+        EnableSsoRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableSsoRequest`](struct.EnableSsoRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableSsoRequestBuilder {
+    directory_id: Option<String>,
+    password: Option<String>,
+    user_name: Option<String>,
+}
+
+impl EnableSsoRequestBuilder {
+    /// Sets the [`directory_id`](struct.EnableSsoRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.EnableSsoRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.EnableSsoRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableSsoRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<EnableSsoRequest, ::rusoto_core::BuildError> {
+        Ok(EnableSsoRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            password: self.password,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// <p>Contains the results of the <a>EnableSso</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1062,8 +2952,9 @@ pub struct EnableSsoResult {}
 pub struct EventTopic {
     /// <p>The date and time of when you associated your directory with the SNS topic.</p>
     #[serde(rename = "CreatedDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date_time: Option<f64>,
+    pub created_date_time: Option<EpochTimestamp>,
     /// <p>The Directory ID of an AWS Directory Service directory that will publish status messages to an SNS topic.</p>
     #[serde(rename = "DirectoryId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1071,7 +2962,7 @@ pub struct EventTopic {
     /// <p>The topic registration status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TopicStatus>,
     /// <p>The SNS topic ARN (Amazon Resource Name).</p>
     #[serde(rename = "TopicArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1104,6 +2995,38 @@ pub struct GetSnapshotLimitsRequest {
     pub directory_id: String,
 }
 
+impl GetSnapshotLimitsRequest {
+    /// Returns a builder for `GetSnapshotLimitsRequest`, checking its required members when built.
+    pub fn builder() -> GetSnapshotLimitsRequestBuilder {
+        GetSnapshotLimitsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSnapshotLimitsRequest`](struct.GetSnapshotLimitsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSnapshotLimitsRequestBuilder {
+    directory_id: Option<String>,
+}
+
+impl GetSnapshotLimitsRequestBuilder {
+    /// Sets the [`directory_id`](struct.GetSnapshotLimitsRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSnapshotLimitsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSnapshotLimitsRequest, ::rusoto_core::BuildError> {
+        Ok(GetSnapshotLimitsRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the results of the <a>GetSnapshotLimits</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1133,8 +3056,9 @@ pub struct IpRoute {
 pub struct IpRouteInfo {
     /// <p>The date and time the address block was added to the directory.</p>
     #[serde(rename = "AddedDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub added_date_time: Option<f64>,
+    pub added_date_time: Option<EpochTimestamp>,
     /// <p>IP address block in the <a>IpRoute</a>.</p>
     #[serde(rename = "CidrIp")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1150,30 +3074,164 @@ pub struct IpRouteInfo {
     /// <p>The status of the IP address block.</p>
     #[serde(rename = "IpRouteStatusMsg")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ip_route_status_msg: Option<String>,
+    pub ip_route_status_msg: Option<IpRouteStatusMsg>,
     /// <p>The reason for the IpRouteStatusMsg.</p>
     #[serde(rename = "IpRouteStatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ip_route_status_reason: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ListIpRoutesRequest {
-    /// <p>Identifier (ID) of the directory for which you want to retrieve the IP addresses.</p>
-    #[serde(rename = "DirectoryId")]
-    pub directory_id: String,
-    /// <p>Maximum number of items to return. If this value is zero, the maximum number of items is specified by the limitations of the operation.</p>
-    #[serde(rename = "Limit")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    /// <p>The <i>ListIpRoutes.NextToken</i> value from a previous call to <a>ListIpRoutes</a>. Pass null if this is the first call.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+/// <p>The status of the IP address block.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IpRouteStatusMsg {
+    /// <p><code>Adding</code></p>
+    Adding,
+    /// <p><code>Added</code></p>
+    Added,
+    /// <p><code>Removing</code></p>
+    Removing,
+    /// <p><code>Removed</code></p>
+    Removed,
+    /// <p><code>AddFailed</code></p>
+    AddFailed,
+    /// <p><code>RemoveFailed</code></p>
+    RemoveFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IpRouteStatusMsg {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpRouteStatusMsg::Adding => "Adding",
+            IpRouteStatusMsg::Added => "Added",
+            IpRouteStatusMsg::Removing => "Removing",
+            IpRouteStatusMsg::Removed => "Removed",
+            IpRouteStatusMsg::AddFailed => "AddFailed",
+            IpRouteStatusMsg::RemoveFailed => "RemoveFailed",
+            IpRouteStatusMsg::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl<'a> From<&'a str> for IpRouteStatusMsg {
+    fn from(value: &'a str) -> IpRouteStatusMsg {
+        match value {
+            "Adding" => IpRouteStatusMsg::Adding,
+            "Added" => IpRouteStatusMsg::Added,
+            "Removing" => IpRouteStatusMsg::Removing,
+            "Removed" => IpRouteStatusMsg::Removed,
+            "AddFailed" => IpRouteStatusMsg::AddFailed,
+            "RemoveFailed" => IpRouteStatusMsg::RemoveFailed,
+            _ => IpRouteStatusMsg::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpRouteStatusMsg {
+    fn from(value: String) -> IpRouteStatusMsg {
+        IpRouteStatusMsg::from(value.as_str())
+    }
+}
+
+impl From<IpRouteStatusMsg> for String {
+    fn from(value: IpRouteStatusMsg) -> String {
+        match value {
+            IpRouteStatusMsg::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IpRouteStatusMsg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for IpRouteStatusMsg {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IpRouteStatusMsg {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IpRouteStatusMsg, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IpRouteStatusMsg::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListIpRoutesRequest {
+    /// <p>Identifier (ID) of the directory for which you want to retrieve the IP addresses.</p>
+    #[serde(rename = "DirectoryId")]
+    pub directory_id: String,
+    /// <p>Maximum number of items to return. If this value is zero, the maximum number of items is specified by the limitations of the operation.</p>
+    #[serde(rename = "Limit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// <p>The <i>ListIpRoutes.NextToken</i> value from a previous call to <a>ListIpRoutes</a>. Pass null if this is the first call.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+impl ListIpRoutesRequest {
+    /// Returns a builder for `ListIpRoutesRequest`, checking its required members when built.
+    pub fn builder() -> ListIpRoutesRequestBuilder {
+        ListIpRoutesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListIpRoutesRequest`](struct.ListIpRoutesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListIpRoutesRequestBuilder {
+    directory_id: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListIpRoutesRequestBuilder {
+    /// Sets the [`directory_id`](struct.ListIpRoutesRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListIpRoutesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListIpRoutesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListIpRoutesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListIpRoutesRequest, ::rusoto_core::BuildError> {
+        Ok(ListIpRoutesRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
 pub struct ListIpRoutesResult {
     /// <p>A list of <a>IpRoute</a>s.</p>
     #[serde(rename = "IpRoutesInfo")]
@@ -1201,6 +3259,52 @@ pub struct ListLogSubscriptionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListLogSubscriptionsRequest {
+    /// Returns a builder for `ListLogSubscriptionsRequest`, checking its required members when built.
+    pub fn builder() -> ListLogSubscriptionsRequestBuilder {
+        ListLogSubscriptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListLogSubscriptionsRequest`](struct.ListLogSubscriptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListLogSubscriptionsRequestBuilder {
+    directory_id: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListLogSubscriptionsRequestBuilder {
+    /// Sets the [`directory_id`](struct.ListLogSubscriptionsRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListLogSubscriptionsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListLogSubscriptionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLogSubscriptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListLogSubscriptionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListLogSubscriptionsRequest {
+            directory_id: self.directory_id,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLogSubscriptionsResult {
@@ -1229,6 +3333,54 @@ pub struct ListSchemaExtensionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListSchemaExtensionsRequest {
+    /// Returns a builder for `ListSchemaExtensionsRequest`, checking its required members when built.
+    pub fn builder() -> ListSchemaExtensionsRequestBuilder {
+        ListSchemaExtensionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSchemaExtensionsRequest`](struct.ListSchemaExtensionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSchemaExtensionsRequestBuilder {
+    directory_id: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListSchemaExtensionsRequestBuilder {
+    /// Sets the [`directory_id`](struct.ListSchemaExtensionsRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListSchemaExtensionsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSchemaExtensionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSchemaExtensionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSchemaExtensionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListSchemaExtensionsRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSchemaExtensionsResult {
@@ -1257,6 +3409,54 @@ pub struct ListTagsForResourceRequest {
     pub resource_id: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`limit`](struct.ListTagsForResourceRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsForResourceRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.ListTagsForResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            limit: self.limit,
+            next_token: self.next_token,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResult {
@@ -1284,8 +3484,9 @@ pub struct LogSubscription {
     pub log_group_name: Option<String>,
     /// <p>The date and time that the log subscription was created.</p>
     #[serde(rename = "SubscriptionCreatedDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription_created_date_time: Option<f64>,
+    pub subscription_created_date_time: Option<EpochTimestamp>,
 }
 
 /// <p>Describes the directory owner account details that have been shared to the directory consumer account.</p>
@@ -1311,20 +3512,102 @@ pub struct OwnerDirectoryDescription {
     /// <p>Information about the status of the RADIUS server.</p>
     #[serde(rename = "RadiusStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub radius_status: Option<String>,
+    pub radius_status: Option<RadiusStatus>,
     /// <p>Information about the VPC settings for the directory.</p>
     #[serde(rename = "VpcSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vpc_settings: Option<DirectoryVpcSettingsDescription>,
 }
 
+/// <p>The protocol specified for your RADIUS endpoints.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RadiusAuthenticationProtocol {
+    /// <p><code>PAP</code></p>
+    Pap,
+    /// <p><code>CHAP</code></p>
+    Chap,
+    /// <p><code>MS-CHAPv1</code></p>
+    MsCHAPv1,
+    /// <p><code>MS-CHAPv2</code></p>
+    MsCHAPv2,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RadiusAuthenticationProtocol {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RadiusAuthenticationProtocol::Pap => "PAP",
+            RadiusAuthenticationProtocol::Chap => "CHAP",
+            RadiusAuthenticationProtocol::MsCHAPv1 => "MS-CHAPv1",
+            RadiusAuthenticationProtocol::MsCHAPv2 => "MS-CHAPv2",
+            RadiusAuthenticationProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RadiusAuthenticationProtocol {
+    fn from(value: &'a str) -> RadiusAuthenticationProtocol {
+        match value {
+            "PAP" => RadiusAuthenticationProtocol::Pap,
+            "CHAP" => RadiusAuthenticationProtocol::Chap,
+            "MS-CHAPv1" => RadiusAuthenticationProtocol::MsCHAPv1,
+            "MS-CHAPv2" => RadiusAuthenticationProtocol::MsCHAPv2,
+            _ => RadiusAuthenticationProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RadiusAuthenticationProtocol {
+    fn from(value: String) -> RadiusAuthenticationProtocol {
+        RadiusAuthenticationProtocol::from(value.as_str())
+    }
+}
+
+impl From<RadiusAuthenticationProtocol> for String {
+    fn from(value: RadiusAuthenticationProtocol) -> String {
+        match value {
+            RadiusAuthenticationProtocol::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RadiusAuthenticationProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RadiusAuthenticationProtocol {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RadiusAuthenticationProtocol {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<RadiusAuthenticationProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RadiusAuthenticationProtocol::from(value))
+    }
+}
+
 /// <p>Contains information about a Remote Authentication Dial In User Service (RADIUS) server.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RadiusSettings {
     /// <p>The protocol specified for your RADIUS endpoints.</p>
     #[serde(rename = "AuthenticationProtocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub authentication_protocol: Option<String>,
+    pub authentication_protocol: Option<RadiusAuthenticationProtocol>,
     /// <p>Not currently used.</p>
     #[serde(rename = "DisplayLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1355,6 +3638,82 @@ pub struct RadiusSettings {
     pub use_same_username: Option<bool>,
 }
 
+/// <p>The status of the RADIUS MFA server connection.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RadiusStatus {
+    /// <p><code>Creating</code></p>
+    Creating,
+    /// <p><code>Completed</code></p>
+    Completed,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RadiusStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RadiusStatus::Creating => "Creating",
+            RadiusStatus::Completed => "Completed",
+            RadiusStatus::Failed => "Failed",
+            RadiusStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RadiusStatus {
+    fn from(value: &'a str) -> RadiusStatus {
+        match value {
+            "Creating" => RadiusStatus::Creating,
+            "Completed" => RadiusStatus::Completed,
+            "Failed" => RadiusStatus::Failed,
+            _ => RadiusStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RadiusStatus {
+    fn from(value: String) -> RadiusStatus {
+        RadiusStatus::from(value.as_str())
+    }
+}
+
+impl From<RadiusStatus> for String {
+    fn from(value: RadiusStatus) -> String {
+        match value {
+            RadiusStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RadiusStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RadiusStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RadiusStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RadiusStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RadiusStatus::from(value))
+    }
+}
+
 /// <p>Registers a new event topic.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RegisterEventTopicRequest {
@@ -1366,6 +3725,48 @@ pub struct RegisterEventTopicRequest {
     pub topic_name: String,
 }
 
+impl RegisterEventTopicRequest {
+    /// Returns a builder for `RegisterEventTopicRequest`, checking its required members when built.
+    pub fn builder() -> RegisterEventTopicRequestBuilder {
+        RegisterEventTopicRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterEventTopicRequest`](struct.RegisterEventTopicRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterEventTopicRequestBuilder {
+    directory_id: Option<String>,
+    topic_name: Option<String>,
+}
+
+impl RegisterEventTopicRequestBuilder {
+    /// Sets the [`directory_id`](struct.RegisterEventTopicRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_name`](struct.RegisterEventTopicRequest.html#structfield.topic_name) member.
+    pub fn topic_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterEventTopicRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterEventTopicRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterEventTopicRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            topic_name: self
+                .topic_name
+                .ok_or(::rusoto_core::BuildError::missing("topic_name"))?,
+        })
+    }
+}
+
 /// <p>The result of a RegisterEventTopic request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1378,6 +3779,38 @@ pub struct RejectSharedDirectoryRequest {
     pub shared_directory_id: String,
 }
 
+impl RejectSharedDirectoryRequest {
+    /// Returns a builder for `RejectSharedDirectoryRequest`, checking its required members when built.
+    pub fn builder() -> RejectSharedDirectoryRequestBuilder {
+        RejectSharedDirectoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RejectSharedDirectoryRequest`](struct.RejectSharedDirectoryRequest.html).
+#[derive(Default, Debug)]
+pub struct RejectSharedDirectoryRequestBuilder {
+    shared_directory_id: Option<String>,
+}
+
+impl RejectSharedDirectoryRequestBuilder {
+    /// Sets the [`shared_directory_id`](struct.RejectSharedDirectoryRequest.html#structfield.shared_directory_id) member.
+    pub fn shared_directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.shared_directory_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RejectSharedDirectoryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RejectSharedDirectoryRequest, ::rusoto_core::BuildError> {
+        Ok(RejectSharedDirectoryRequest {
+            shared_directory_id: self
+                .shared_directory_id
+                .ok_or(::rusoto_core::BuildError::missing("shared_directory_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RejectSharedDirectoryResult {
@@ -1397,6 +3830,46 @@ pub struct RemoveIpRoutesRequest {
     pub directory_id: String,
 }
 
+impl RemoveIpRoutesRequest {
+    /// Returns a builder for `RemoveIpRoutesRequest`, checking its required members when built.
+    pub fn builder() -> RemoveIpRoutesRequestBuilder {
+        RemoveIpRoutesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveIpRoutesRequest`](struct.RemoveIpRoutesRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveIpRoutesRequestBuilder {
+    cidr_ips: Option<Vec<String>>,
+    directory_id: Option<String>,
+}
+
+impl RemoveIpRoutesRequestBuilder {
+    /// Sets the [`cidr_ips`](struct.RemoveIpRoutesRequest.html#structfield.cidr_ips) member.
+    pub fn cidr_ips<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cidr_ips = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_id`](struct.RemoveIpRoutesRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveIpRoutesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RemoveIpRoutesRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveIpRoutesRequest {
+            cidr_ips: self
+                .cidr_ips
+                .ok_or(::rusoto_core::BuildError::missing("cidr_ips"))?,
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RemoveIpRoutesResult {}
@@ -1411,10 +3884,120 @@ pub struct RemoveTagsFromResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl RemoveTagsFromResourceRequest {
+    /// Returns a builder for `RemoveTagsFromResourceRequest`, checking its required members when built.
+    pub fn builder() -> RemoveTagsFromResourceRequestBuilder {
+        RemoveTagsFromResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsFromResourceRequest`](struct.RemoveTagsFromResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsFromResourceRequestBuilder {
+    resource_id: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl RemoveTagsFromResourceRequestBuilder {
+    /// Sets the [`resource_id`](struct.RemoveTagsFromResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.RemoveTagsFromResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsFromResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveTagsFromResourceRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsFromResourceRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RemoveTagsFromResourceResult {}
 
+/// <p>The replication scope of the conditional forwarder. The only allowed value is <code>Domain</code>, which will replicate the conditional forwarder to all of the domain controllers for your AWS directory.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReplicationScope {
+    /// <p><code>Domain</code></p>
+    Domain,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReplicationScope {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicationScope::Domain => "Domain",
+            ReplicationScope::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReplicationScope {
+    fn from(value: &'a str) -> ReplicationScope {
+        match value {
+            "Domain" => ReplicationScope::Domain,
+            _ => ReplicationScope::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicationScope {
+    fn from(value: String) -> ReplicationScope {
+        ReplicationScope::from(value.as_str())
+    }
+}
+
+impl From<ReplicationScope> for String {
+    fn from(value: ReplicationScope) -> String {
+        match value {
+            ReplicationScope::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReplicationScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReplicationScope {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicationScope {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReplicationScope, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReplicationScope::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ResetUserPasswordRequest {
     /// <p>Identifier of the AWS Managed Microsoft AD or Simple AD directory in which the user resides.</p>
@@ -1428,18 +4011,102 @@ pub struct ResetUserPasswordRequest {
     pub user_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ResetUserPasswordResult {}
+impl ResetUserPasswordRequest {
+    /// Returns a builder for `ResetUserPasswordRequest`, checking its required members when built.
+    pub fn builder() -> ResetUserPasswordRequestBuilder {
+        ResetUserPasswordRequestBuilder::default()
+    }
+}
 
-/// <p>An object representing the inputs for the <a>RestoreFromSnapshot</a> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct RestoreFromSnapshotRequest {
-    /// <p>The identifier of the snapshot to restore from.</p>
-    #[serde(rename = "SnapshotId")]
+/// Builds a [`ResetUserPasswordRequest`](struct.ResetUserPasswordRequest.html).
+#[derive(Default, Debug)]
+pub struct ResetUserPasswordRequestBuilder {
+    directory_id: Option<String>,
+    new_password: Option<String>,
+    user_name: Option<String>,
+}
+
+impl ResetUserPasswordRequestBuilder {
+    /// Sets the [`directory_id`](struct.ResetUserPasswordRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_password`](struct.ResetUserPasswordRequest.html#structfield.new_password) member.
+    pub fn new_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ResetUserPasswordRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetUserPasswordRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResetUserPasswordRequest, ::rusoto_core::BuildError> {
+        Ok(ResetUserPasswordRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            new_password: self
+                .new_password
+                .ok_or(::rusoto_core::BuildError::missing("new_password"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ResetUserPasswordResult {}
+
+/// <p>An object representing the inputs for the <a>RestoreFromSnapshot</a> operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct RestoreFromSnapshotRequest {
+    /// <p>The identifier of the snapshot to restore from.</p>
+    #[serde(rename = "SnapshotId")]
     pub snapshot_id: String,
 }
 
+impl RestoreFromSnapshotRequest {
+    /// Returns a builder for `RestoreFromSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> RestoreFromSnapshotRequestBuilder {
+        RestoreFromSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreFromSnapshotRequest`](struct.RestoreFromSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct RestoreFromSnapshotRequestBuilder {
+    snapshot_id: Option<String>,
+}
+
+impl RestoreFromSnapshotRequestBuilder {
+    /// Sets the [`snapshot_id`](struct.RestoreFromSnapshotRequest.html#structfield.snapshot_id) member.
+    pub fn snapshot_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreFromSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreFromSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(RestoreFromSnapshotRequest {
+            snapshot_id: self
+                .snapshot_id
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the results of the <a>RestoreFromSnapshot</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1459,8 +4126,9 @@ pub struct SchemaExtensionInfo {
     pub directory_id: Option<String>,
     /// <p>The date and time that the schema extension was completed.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<EpochTimestamp>,
     /// <p>The identifier of the schema extension.</p>
     #[serde(rename = "SchemaExtensionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1468,25 +4136,198 @@ pub struct SchemaExtensionInfo {
     /// <p>The current status of the schema extension.</p>
     #[serde(rename = "SchemaExtensionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub schema_extension_status: Option<String>,
+    pub schema_extension_status: Option<SchemaExtensionStatus>,
     /// <p>The reason for the <code>SchemaExtensionStatus</code>.</p>
     #[serde(rename = "SchemaExtensionStatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schema_extension_status_reason: Option<String>,
     /// <p>The date and time that the schema extension started being applied to the directory.</p>
     #[serde(rename = "StartDateTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date_time: Option<f64>,
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date_time: Option<EpochTimestamp>,
+}
+
+/// <p>The current status of the schema extension.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SchemaExtensionStatus {
+    /// <p><code>Initializing</code></p>
+    Initializing,
+    /// <p><code>CreatingSnapshot</code></p>
+    CreatingSnapshot,
+    /// <p><code>UpdatingSchema</code></p>
+    UpdatingSchema,
+    /// <p><code>Replicating</code></p>
+    Replicating,
+    /// <p><code>CancelInProgress</code></p>
+    CancelInProgress,
+    /// <p><code>RollbackInProgress</code></p>
+    RollbackInProgress,
+    /// <p><code>Cancelled</code></p>
+    Cancelled,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Completed</code></p>
+    Completed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SchemaExtensionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SchemaExtensionStatus::Initializing => "Initializing",
+            SchemaExtensionStatus::CreatingSnapshot => "CreatingSnapshot",
+            SchemaExtensionStatus::UpdatingSchema => "UpdatingSchema",
+            SchemaExtensionStatus::Replicating => "Replicating",
+            SchemaExtensionStatus::CancelInProgress => "CancelInProgress",
+            SchemaExtensionStatus::RollbackInProgress => "RollbackInProgress",
+            SchemaExtensionStatus::Cancelled => "Cancelled",
+            SchemaExtensionStatus::Failed => "Failed",
+            SchemaExtensionStatus::Completed => "Completed",
+            SchemaExtensionStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl<'a> From<&'a str> for SchemaExtensionStatus {
+    fn from(value: &'a str) -> SchemaExtensionStatus {
+        match value {
+            "Initializing" => SchemaExtensionStatus::Initializing,
+            "CreatingSnapshot" => SchemaExtensionStatus::CreatingSnapshot,
+            "UpdatingSchema" => SchemaExtensionStatus::UpdatingSchema,
+            "Replicating" => SchemaExtensionStatus::Replicating,
+            "CancelInProgress" => SchemaExtensionStatus::CancelInProgress,
+            "RollbackInProgress" => SchemaExtensionStatus::RollbackInProgress,
+            "Cancelled" => SchemaExtensionStatus::Cancelled,
+            "Failed" => SchemaExtensionStatus::Failed,
+            "Completed" => SchemaExtensionStatus::Completed,
+            _ => SchemaExtensionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SchemaExtensionStatus {
+    fn from(value: String) -> SchemaExtensionStatus {
+        SchemaExtensionStatus::from(value.as_str())
+    }
+}
+
+impl From<SchemaExtensionStatus> for String {
+    fn from(value: SchemaExtensionStatus) -> String {
+        match value {
+            SchemaExtensionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaExtensionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SchemaExtensionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SchemaExtensionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SchemaExtensionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SchemaExtensionStatus::from(value))
+    }
+}
+
+/// <p>Optional parameter to enable selective authentication for the trust.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SelectiveAuth {
+    /// <p><code>Enabled</code></p>
+    Enabled,
+    /// <p><code>Disabled</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SelectiveAuth {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SelectiveAuth::Enabled => "Enabled",
+            SelectiveAuth::Disabled => "Disabled",
+            SelectiveAuth::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SelectiveAuth {
+    fn from(value: &'a str) -> SelectiveAuth {
+        match value {
+            "Enabled" => SelectiveAuth::Enabled,
+            "Disabled" => SelectiveAuth::Disabled,
+            _ => SelectiveAuth::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SelectiveAuth {
+    fn from(value: String) -> SelectiveAuth {
+        SelectiveAuth::from(value.as_str())
+    }
+}
+
+impl From<SelectiveAuth> for String {
+    fn from(value: SelectiveAuth) -> String {
+        match value {
+            SelectiveAuth::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SelectiveAuth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SelectiveAuth {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SelectiveAuth {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SelectiveAuth, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SelectiveAuth::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ShareDirectoryRequest {
     /// <p>Identifier of the AWS Managed Microsoft AD directory that you want to share with other AWS accounts.</p>
     #[serde(rename = "DirectoryId")]
     pub directory_id: String,
     /// <p>The method used when sharing a directory to determine whether the directory should be shared within your AWS organization (<code>ORGANIZATIONS</code>) or with any AWS account by sending a directory sharing request (<code>HANDSHAKE</code>).</p>
     #[serde(rename = "ShareMethod")]
-    pub share_method: String,
+    pub share_method: ShareMethod,
     /// <p>A directory share request that is sent by the directory owner to the directory consumer. The request includes a typed message to help the directory consumer administrator determine whether to approve or reject the share invitation.</p>
     #[serde(rename = "ShareNotes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1496,6 +4337,64 @@ pub struct ShareDirectoryRequest {
     pub share_target: ShareTarget,
 }
 
+impl ShareDirectoryRequest {
+    /// Returns a builder for `ShareDirectoryRequest`, checking its required members when built.
+    pub fn builder() -> ShareDirectoryRequestBuilder {
+        ShareDirectoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ShareDirectoryRequest`](struct.ShareDirectoryRequest.html).
+#[derive(Default, Debug)]
+pub struct ShareDirectoryRequestBuilder {
+    directory_id: Option<String>,
+    share_method: Option<ShareMethod>,
+    share_notes: Option<String>,
+    share_target: Option<ShareTarget>,
+}
+
+impl ShareDirectoryRequestBuilder {
+    /// Sets the [`directory_id`](struct.ShareDirectoryRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`share_method`](struct.ShareDirectoryRequest.html#structfield.share_method) member.
+    pub fn share_method<T: Into<ShareMethod>>(mut self, value: T) -> Self {
+        self.share_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`share_notes`](struct.ShareDirectoryRequest.html#structfield.share_notes) member.
+    pub fn share_notes<T: Into<String>>(mut self, value: T) -> Self {
+        self.share_notes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`share_target`](struct.ShareDirectoryRequest.html#structfield.share_target) member.
+    pub fn share_target<T: Into<ShareTarget>>(mut self, value: T) -> Self {
+        self.share_target = Some(value.into());
+        self
+    }
+
+    /// Builds the `ShareDirectoryRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ShareDirectoryRequest, ::rusoto_core::BuildError> {
+        Ok(ShareDirectoryRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            share_method: self
+                .share_method
+                .ok_or(::rusoto_core::BuildError::missing("share_method"))?,
+            share_notes: self.share_notes,
+            share_target: self
+                .share_target
+                .ok_or(::rusoto_core::BuildError::missing("share_target"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ShareDirectoryResult {
@@ -1505,15 +4404,187 @@ pub struct ShareDirectoryResult {
     pub shared_directory_id: Option<String>,
 }
 
+/// <p>The method used when sharing a directory to determine whether the directory should be shared within your AWS organization (<code>ORGANIZATIONS</code>) or with any AWS account by sending a shared directory request (<code>HANDSHAKE</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ShareMethod {
+    /// <p><code>ORGANIZATIONS</code></p>
+    Organizations,
+    /// <p><code>HANDSHAKE</code></p>
+    Handshake,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ShareMethod {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShareMethod::Organizations => "ORGANIZATIONS",
+            ShareMethod::Handshake => "HANDSHAKE",
+            ShareMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ShareMethod {
+    fn from(value: &'a str) -> ShareMethod {
+        match value {
+            "ORGANIZATIONS" => ShareMethod::Organizations,
+            "HANDSHAKE" => ShareMethod::Handshake,
+            _ => ShareMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShareMethod {
+    fn from(value: String) -> ShareMethod {
+        ShareMethod::from(value.as_str())
+    }
+}
+
+impl From<ShareMethod> for String {
+    fn from(value: ShareMethod) -> String {
+        match value {
+            ShareMethod::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ShareMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ShareMethod {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShareMethod {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ShareMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ShareMethod::from(value))
+    }
+}
+
+/// <p>Current directory status of the shared AWS Managed Microsoft AD directory.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ShareStatus {
+    /// <p><code>Shared</code></p>
+    Shared,
+    /// <p><code>PendingAcceptance</code></p>
+    PendingAcceptance,
+    /// <p><code>Rejected</code></p>
+    Rejected,
+    /// <p><code>Rejecting</code></p>
+    Rejecting,
+    /// <p><code>RejectFailed</code></p>
+    RejectFailed,
+    /// <p><code>Sharing</code></p>
+    Sharing,
+    /// <p><code>ShareFailed</code></p>
+    ShareFailed,
+    /// <p><code>Deleted</code></p>
+    Deleted,
+    /// <p><code>Deleting</code></p>
+    Deleting,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ShareStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShareStatus::Shared => "Shared",
+            ShareStatus::PendingAcceptance => "PendingAcceptance",
+            ShareStatus::Rejected => "Rejected",
+            ShareStatus::Rejecting => "Rejecting",
+            ShareStatus::RejectFailed => "RejectFailed",
+            ShareStatus::Sharing => "Sharing",
+            ShareStatus::ShareFailed => "ShareFailed",
+            ShareStatus::Deleted => "Deleted",
+            ShareStatus::Deleting => "Deleting",
+            ShareStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ShareStatus {
+    fn from(value: &'a str) -> ShareStatus {
+        match value {
+            "Shared" => ShareStatus::Shared,
+            "PendingAcceptance" => ShareStatus::PendingAcceptance,
+            "Rejected" => ShareStatus::Rejected,
+            "Rejecting" => ShareStatus::Rejecting,
+            "RejectFailed" => ShareStatus::RejectFailed,
+            "Sharing" => ShareStatus::Sharing,
+            "ShareFailed" => ShareStatus::ShareFailed,
+            "Deleted" => ShareStatus::Deleted,
+            "Deleting" => ShareStatus::Deleting,
+            _ => ShareStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShareStatus {
+    fn from(value: String) -> ShareStatus {
+        ShareStatus::from(value.as_str())
+    }
+}
+
+impl From<ShareStatus> for String {
+    fn from(value: ShareStatus) -> String {
+        match value {
+            ShareStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ShareStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ShareStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShareStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ShareStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ShareStatus::from(value))
+    }
+}
+
 /// <p>Identifier that contains details about the directory consumer account.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ShareTarget {
     /// <p>Identifier of the directory consumer account.</p>
     #[serde(rename = "Id")]
     pub id: String,
     /// <p>Type of identifier to be used in the <code>Id</code> field.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: TargetType,
 }
 
 /// <p>Details about the shared directory in the directory owner account for which the share request in the directory consumer account has been accepted.</p>
@@ -1522,12 +4593,14 @@ pub struct ShareTarget {
 pub struct SharedDirectory {
     /// <p>The date and time that the shared directory was created.</p>
     #[serde(rename = "CreatedDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date_time: Option<f64>,
+    pub created_date_time: Option<EpochTimestamp>,
     /// <p>The date and time that the shared directory was last updated.</p>
     #[serde(rename = "LastUpdatedDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<EpochTimestamp>,
     /// <p>Identifier of the directory owner account, which contains the directory that has been shared to the consumer account.</p>
     #[serde(rename = "OwnerAccountId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1539,7 +4612,7 @@ pub struct SharedDirectory {
     /// <p>The method used when sharing a directory to determine whether the directory should be shared within your AWS organization (<code>ORGANIZATIONS</code>) or with any AWS account by sending a shared directory request (<code>HANDSHAKE</code>).</p>
     #[serde(rename = "ShareMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub share_method: Option<String>,
+    pub share_method: Option<ShareMethod>,
     /// <p>A directory share request that is sent by the directory owner to the directory consumer. The request includes a typed message to help the directory consumer administrator determine whether to approve or reject the share invitation.</p>
     #[serde(rename = "ShareNotes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1547,7 +4620,7 @@ pub struct SharedDirectory {
     /// <p>Current directory status of the shared AWS Managed Microsoft AD directory.</p>
     #[serde(rename = "ShareStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub share_status: Option<String>,
+    pub share_status: Option<ShareStatus>,
     /// <p>Identifier of the directory consumer account that has access to the shared directory (<code>OwnerDirectoryId</code>) in the directory owner account.</p>
     #[serde(rename = "SharedAccountId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1576,16 +4649,17 @@ pub struct Snapshot {
     pub snapshot_id: Option<String>,
     /// <p>The date and time that the snapshot was taken.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>The snapshot status.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SnapshotStatus>,
     /// <p>The snapshot type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<SnapshotType>,
 }
 
 /// <p>Contains manual snapshot limit information for a directory.</p>
@@ -1606,6 +4680,154 @@ pub struct SnapshotLimits {
     pub manual_snapshots_limit_reached: Option<bool>,
 }
 
+/// <p>The snapshot status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SnapshotStatus {
+    /// <p><code>Creating</code></p>
+    Creating,
+    /// <p><code>Completed</code></p>
+    Completed,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SnapshotStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SnapshotStatus::Creating => "Creating",
+            SnapshotStatus::Completed => "Completed",
+            SnapshotStatus::Failed => "Failed",
+            SnapshotStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SnapshotStatus {
+    fn from(value: &'a str) -> SnapshotStatus {
+        match value {
+            "Creating" => SnapshotStatus::Creating,
+            "Completed" => SnapshotStatus::Completed,
+            "Failed" => SnapshotStatus::Failed,
+            _ => SnapshotStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SnapshotStatus {
+    fn from(value: String) -> SnapshotStatus {
+        SnapshotStatus::from(value.as_str())
+    }
+}
+
+impl From<SnapshotStatus> for String {
+    fn from(value: SnapshotStatus) -> String {
+        match value {
+            SnapshotStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SnapshotStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SnapshotStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SnapshotStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SnapshotStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SnapshotStatus::from(value))
+    }
+}
+
+/// <p>The snapshot type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SnapshotType {
+    /// <p><code>Auto</code></p>
+    Auto,
+    /// <p><code>Manual</code></p>
+    Manual,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SnapshotType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SnapshotType::Auto => "Auto",
+            SnapshotType::Manual => "Manual",
+            SnapshotType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SnapshotType {
+    fn from(value: &'a str) -> SnapshotType {
+        match value {
+            "Auto" => SnapshotType::Auto,
+            "Manual" => SnapshotType::Manual,
+            _ => SnapshotType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SnapshotType {
+    fn from(value: String) -> SnapshotType {
+        SnapshotType::from(value.as_str())
+    }
+}
+
+impl From<SnapshotType> for String {
+    fn from(value: SnapshotType) -> String {
+        match value {
+            SnapshotType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SnapshotType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SnapshotType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SnapshotType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SnapshotType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SnapshotType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartSchemaExtensionRequest {
     /// <p>If true, creates a snapshot of the directory before applying the schema extension.</p>
@@ -1622,6 +4844,70 @@ pub struct StartSchemaExtensionRequest {
     pub ldif_content: String,
 }
 
+impl StartSchemaExtensionRequest {
+    /// Returns a builder for `StartSchemaExtensionRequest`, checking its required members when built.
+    pub fn builder() -> StartSchemaExtensionRequestBuilder {
+        StartSchemaExtensionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartSchemaExtensionRequest`](struct.StartSchemaExtensionRequest.html).
+#[derive(Default, Debug)]
+pub struct StartSchemaExtensionRequestBuilder {
+    create_snapshot_before_schema_extension: Option<bool>,
+    description: Option<String>,
+    directory_id: Option<String>,
+    ldif_content: Option<String>,
+}
+
+impl StartSchemaExtensionRequestBuilder {
+    /// Sets the [`create_snapshot_before_schema_extension`](struct.StartSchemaExtensionRequest.html#structfield.create_snapshot_before_schema_extension) member.
+    pub fn create_snapshot_before_schema_extension<T: Into<bool>>(mut self, value: T) -> Self {
+        self.create_snapshot_before_schema_extension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.StartSchemaExtensionRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_id`](struct.StartSchemaExtensionRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ldif_content`](struct.StartSchemaExtensionRequest.html#structfield.ldif_content) member.
+    pub fn ldif_content<T: Into<String>>(mut self, value: T) -> Self {
+        self.ldif_content = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartSchemaExtensionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartSchemaExtensionRequest, ::rusoto_core::BuildError> {
+        Ok(StartSchemaExtensionRequest {
+            create_snapshot_before_schema_extension: self
+                .create_snapshot_before_schema_extension
+                .ok_or(::rusoto_core::BuildError::missing(
+                "create_snapshot_before_schema_extension",
+            ))?,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            ldif_content: self
+                .ldif_content
+                .ok_or(::rusoto_core::BuildError::missing("ldif_content"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartSchemaExtensionResult {
@@ -1642,22 +4928,172 @@ pub struct Tag {
     pub value: String,
 }
 
-/// <p>Describes a trust relationship between an AWS Managed Microsoft AD directory and an external domain.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Trust {
+/// <p>Type of identifier to be used in the <code>Id</code> field.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TargetType {
+    /// <p><code>ACCOUNT</code></p>
+    Account,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TargetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetType::Account => "ACCOUNT",
+            TargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TargetType {
+    fn from(value: &'a str) -> TargetType {
+        match value {
+            "ACCOUNT" => TargetType::Account,
+            _ => TargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetType {
+    fn from(value: String) -> TargetType {
+        TargetType::from(value.as_str())
+    }
+}
+
+impl From<TargetType> for String {
+    fn from(value: TargetType) -> String {
+        match value {
+            TargetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TargetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TargetType::from(value))
+    }
+}
+
+/// <p>The topic registration status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TopicStatus {
+    /// <p><code>Registered</code></p>
+    Registered,
+    /// <p><code>Topic not found</code></p>
+    TopicNotFound,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Deleted</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TopicStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TopicStatus::Registered => "Registered",
+            TopicStatus::TopicNotFound => "Topic not found",
+            TopicStatus::Failed => "Failed",
+            TopicStatus::Deleted => "Deleted",
+            TopicStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TopicStatus {
+    fn from(value: &'a str) -> TopicStatus {
+        match value {
+            "Registered" => TopicStatus::Registered,
+            "Topic not found" => TopicStatus::TopicNotFound,
+            "Failed" => TopicStatus::Failed,
+            "Deleted" => TopicStatus::Deleted,
+            _ => TopicStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TopicStatus {
+    fn from(value: String) -> TopicStatus {
+        TopicStatus::from(value.as_str())
+    }
+}
+
+impl From<TopicStatus> for String {
+    fn from(value: TopicStatus) -> String {
+        match value {
+            TopicStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TopicStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TopicStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TopicStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TopicStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TopicStatus::from(value))
+    }
+}
+
+/// <p>Describes a trust relationship between an AWS Managed Microsoft AD directory and an external domain.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Trust {
     /// <p>The date and time that the trust relationship was created.</p>
     #[serde(rename = "CreatedDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date_time: Option<f64>,
+    pub created_date_time: Option<EpochTimestamp>,
     /// <p>The Directory ID of the AWS directory involved in the trust relationship.</p>
     #[serde(rename = "DirectoryId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub directory_id: Option<String>,
     /// <p>The date and time that the trust relationship was last updated.</p>
     #[serde(rename = "LastUpdatedDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date_time: Option<f64>,
+    pub last_updated_date_time: Option<EpochTimestamp>,
     /// <p>The Fully Qualified Domain Name (FQDN) of the external domain involved in the trust relationship.</p>
     #[serde(rename = "RemoteDomainName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1665,15 +5101,16 @@ pub struct Trust {
     /// <p>Current state of selective authentication for the trust.</p>
     #[serde(rename = "SelectiveAuth")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub selective_auth: Option<String>,
+    pub selective_auth: Option<SelectiveAuth>,
     /// <p>The date and time that the TrustState was last updated.</p>
     #[serde(rename = "StateLastUpdatedDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state_last_updated_date_time: Option<f64>,
+    pub state_last_updated_date_time: Option<EpochTimestamp>,
     /// <p>The trust relationship direction.</p>
     #[serde(rename = "TrustDirection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trust_direction: Option<String>,
+    pub trust_direction: Option<TrustDirection>,
     /// <p>The unique ID of the trust relationship.</p>
     #[serde(rename = "TrustId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1681,7 +5118,7 @@ pub struct Trust {
     /// <p>The trust relationship state.</p>
     #[serde(rename = "TrustState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trust_state: Option<String>,
+    pub trust_state: Option<TrustState>,
     /// <p>The reason for the TrustState.</p>
     #[serde(rename = "TrustStateReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1689,10 +5126,266 @@ pub struct Trust {
     /// <p>The trust relationship type. <code>Forest</code> is the default.</p>
     #[serde(rename = "TrustType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub trust_type: Option<String>,
+    pub trust_type: Option<TrustType>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p>The direction of the trust relationship.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TrustDirection {
+    /// <p><code>One-Way: Outgoing</code></p>
+    OneWayOutgoing,
+    /// <p><code>One-Way: Incoming</code></p>
+    OneWayIncoming,
+    /// <p><code>Two-Way</code></p>
+    TwoWay,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TrustDirection {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TrustDirection::OneWayOutgoing => "One-Way: Outgoing",
+            TrustDirection::OneWayIncoming => "One-Way: Incoming",
+            TrustDirection::TwoWay => "Two-Way",
+            TrustDirection::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TrustDirection {
+    fn from(value: &'a str) -> TrustDirection {
+        match value {
+            "One-Way: Outgoing" => TrustDirection::OneWayOutgoing,
+            "One-Way: Incoming" => TrustDirection::OneWayIncoming,
+            "Two-Way" => TrustDirection::TwoWay,
+            _ => TrustDirection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TrustDirection {
+    fn from(value: String) -> TrustDirection {
+        TrustDirection::from(value.as_str())
+    }
+}
+
+impl From<TrustDirection> for String {
+    fn from(value: TrustDirection) -> String {
+        match value {
+            TrustDirection::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TrustDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TrustDirection {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TrustDirection {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TrustDirection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TrustDirection::from(value))
+    }
+}
+
+/// <p>The trust relationship state.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TrustState {
+    /// <p><code>Creating</code></p>
+    Creating,
+    /// <p><code>Created</code></p>
+    Created,
+    /// <p><code>Verifying</code></p>
+    Verifying,
+    /// <p><code>VerifyFailed</code></p>
+    VerifyFailed,
+    /// <p><code>Verified</code></p>
+    Verified,
+    /// <p><code>Updating</code></p>
+    Updating,
+    /// <p><code>UpdateFailed</code></p>
+    UpdateFailed,
+    /// <p><code>Updated</code></p>
+    Updated,
+    /// <p><code>Deleting</code></p>
+    Deleting,
+    /// <p><code>Deleted</code></p>
+    Deleted,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TrustState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TrustState::Creating => "Creating",
+            TrustState::Created => "Created",
+            TrustState::Verifying => "Verifying",
+            TrustState::VerifyFailed => "VerifyFailed",
+            TrustState::Verified => "Verified",
+            TrustState::Updating => "Updating",
+            TrustState::UpdateFailed => "UpdateFailed",
+            TrustState::Updated => "Updated",
+            TrustState::Deleting => "Deleting",
+            TrustState::Deleted => "Deleted",
+            TrustState::Failed => "Failed",
+            TrustState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TrustState {
+    fn from(value: &'a str) -> TrustState {
+        match value {
+            "Creating" => TrustState::Creating,
+            "Created" => TrustState::Created,
+            "Verifying" => TrustState::Verifying,
+            "VerifyFailed" => TrustState::VerifyFailed,
+            "Verified" => TrustState::Verified,
+            "Updating" => TrustState::Updating,
+            "UpdateFailed" => TrustState::UpdateFailed,
+            "Updated" => TrustState::Updated,
+            "Deleting" => TrustState::Deleting,
+            "Deleted" => TrustState::Deleted,
+            "Failed" => TrustState::Failed,
+            _ => TrustState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TrustState {
+    fn from(value: String) -> TrustState {
+        TrustState::from(value.as_str())
+    }
+}
+
+impl From<TrustState> for String {
+    fn from(value: TrustState) -> String {
+        match value {
+            TrustState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TrustState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TrustState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TrustState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TrustState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TrustState::from(value))
+    }
+}
+
+/// <p>The trust relationship type. <code>Forest</code> is the default.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TrustType {
+    /// <p><code>Forest</code></p>
+    Forest,
+    /// <p><code>External</code></p>
+    External,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TrustType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TrustType::Forest => "Forest",
+            TrustType::External => "External",
+            TrustType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TrustType {
+    fn from(value: &'a str) -> TrustType {
+        match value {
+            "Forest" => TrustType::Forest,
+            "External" => TrustType::External,
+            _ => TrustType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TrustType {
+    fn from(value: String) -> TrustType {
+        TrustType::from(value.as_str())
+    }
+}
+
+impl From<TrustType> for String {
+    fn from(value: TrustType) -> String {
+        match value {
+            TrustType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TrustType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TrustType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TrustType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TrustType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TrustType::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UnshareDirectoryRequest {
     /// <p>The identifier of the AWS Managed Microsoft AD directory that you want to stop sharing.</p>
     #[serde(rename = "DirectoryId")]
@@ -1702,6 +5395,48 @@ pub struct UnshareDirectoryRequest {
     pub unshare_target: UnshareTarget,
 }
 
+impl UnshareDirectoryRequest {
+    /// Returns a builder for `UnshareDirectoryRequest`, checking its required members when built.
+    pub fn builder() -> UnshareDirectoryRequestBuilder {
+        UnshareDirectoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UnshareDirectoryRequest`](struct.UnshareDirectoryRequest.html).
+#[derive(Default, Debug)]
+pub struct UnshareDirectoryRequestBuilder {
+    directory_id: Option<String>,
+    unshare_target: Option<UnshareTarget>,
+}
+
+impl UnshareDirectoryRequestBuilder {
+    /// Sets the [`directory_id`](struct.UnshareDirectoryRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unshare_target`](struct.UnshareDirectoryRequest.html#structfield.unshare_target) member.
+    pub fn unshare_target<T: Into<UnshareTarget>>(mut self, value: T) -> Self {
+        self.unshare_target = Some(value.into());
+        self
+    }
+
+    /// Builds the `UnshareDirectoryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UnshareDirectoryRequest, ::rusoto_core::BuildError> {
+        Ok(UnshareDirectoryRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            unshare_target: self
+                .unshare_target
+                .ok_or(::rusoto_core::BuildError::missing("unshare_target"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UnshareDirectoryResult {
@@ -1712,14 +5447,14 @@ pub struct UnshareDirectoryResult {
 }
 
 /// <p>Identifier that contains details about the directory consumer account with whom the directory is being unshared.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UnshareTarget {
     /// <p>Identifier of the directory consumer account.</p>
     #[serde(rename = "Id")]
     pub id: String,
     /// <p>Type of identifier to be used in the <i>Id</i> field.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: TargetType,
 }
 
 /// <p>Updates a conditional forwarder.</p>
@@ -1736,6 +5471,58 @@ pub struct UpdateConditionalForwarderRequest {
     pub remote_domain_name: String,
 }
 
+impl UpdateConditionalForwarderRequest {
+    /// Returns a builder for `UpdateConditionalForwarderRequest`, checking its required members when built.
+    pub fn builder() -> UpdateConditionalForwarderRequestBuilder {
+        UpdateConditionalForwarderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateConditionalForwarderRequest`](struct.UpdateConditionalForwarderRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateConditionalForwarderRequestBuilder {
+    directory_id: Option<String>,
+    dns_ip_addrs: Option<Vec<String>>,
+    remote_domain_name: Option<String>,
+}
+
+impl UpdateConditionalForwarderRequestBuilder {
+    /// Sets the [`directory_id`](struct.UpdateConditionalForwarderRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dns_ip_addrs`](struct.UpdateConditionalForwarderRequest.html#structfield.dns_ip_addrs) member.
+    pub fn dns_ip_addrs<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.dns_ip_addrs = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remote_domain_name`](struct.UpdateConditionalForwarderRequest.html#structfield.remote_domain_name) member.
+    pub fn remote_domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.remote_domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateConditionalForwarderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateConditionalForwarderRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateConditionalForwarderRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            dns_ip_addrs: self
+                .dns_ip_addrs
+                .ok_or(::rusoto_core::BuildError::missing("dns_ip_addrs"))?,
+            remote_domain_name: self
+                .remote_domain_name
+                .ok_or(::rusoto_core::BuildError::missing("remote_domain_name"))?,
+        })
+    }
+}
+
 /// <p>The result of an UpdateConditionalForwarder request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1751,6 +5538,49 @@ pub struct UpdateNumberOfDomainControllersRequest {
     pub directory_id: String,
 }
 
+impl UpdateNumberOfDomainControllersRequest {
+    /// Returns a builder for `UpdateNumberOfDomainControllersRequest`, checking its required members when built.
+    pub fn builder() -> UpdateNumberOfDomainControllersRequestBuilder {
+        UpdateNumberOfDomainControllersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateNumberOfDomainControllersRequest`](struct.UpdateNumberOfDomainControllersRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateNumberOfDomainControllersRequestBuilder {
+    desired_number: Option<i64>,
+    directory_id: Option<String>,
+}
+
+impl UpdateNumberOfDomainControllersRequestBuilder {
+    /// Sets the [`desired_number`](struct.UpdateNumberOfDomainControllersRequest.html#structfield.desired_number) member.
+    pub fn desired_number<T: Into<i64>>(mut self, value: T) -> Self {
+        self.desired_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`directory_id`](struct.UpdateNumberOfDomainControllersRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateNumberOfDomainControllersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateNumberOfDomainControllersRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateNumberOfDomainControllersRequest {
+            desired_number: self
+                .desired_number
+                .ok_or(::rusoto_core::BuildError::missing("desired_number"))?,
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateNumberOfDomainControllersResult {}
@@ -1766,6 +5596,46 @@ pub struct UpdateRadiusRequest {
     pub radius_settings: RadiusSettings,
 }
 
+impl UpdateRadiusRequest {
+    /// Returns a builder for `UpdateRadiusRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRadiusRequestBuilder {
+        UpdateRadiusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRadiusRequest`](struct.UpdateRadiusRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRadiusRequestBuilder {
+    directory_id: Option<String>,
+    radius_settings: Option<RadiusSettings>,
+}
+
+impl UpdateRadiusRequestBuilder {
+    /// Sets the [`directory_id`](struct.UpdateRadiusRequest.html#structfield.directory_id) member.
+    pub fn directory_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.directory_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`radius_settings`](struct.UpdateRadiusRequest.html#structfield.radius_settings) member.
+    pub fn radius_settings<T: Into<RadiusSettings>>(mut self, value: T) -> Self {
+        self.radius_settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRadiusRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateRadiusRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRadiusRequest {
+            directory_id: self
+                .directory_id
+                .ok_or(::rusoto_core::BuildError::missing("directory_id"))?,
+            radius_settings: self
+                .radius_settings
+                .ok_or(::rusoto_core::BuildError::missing("radius_settings"))?,
+        })
+    }
+}
+
 /// <p>Contains the results of the <a>UpdateRadius</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1776,12 +5646,50 @@ pub struct UpdateTrustRequest {
     /// <p>Updates selective authentication for the trust.</p>
     #[serde(rename = "SelectiveAuth")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub selective_auth: Option<String>,
+    pub selective_auth: Option<SelectiveAuth>,
     /// <p>Identifier of the trust relationship.</p>
     #[serde(rename = "TrustId")]
     pub trust_id: String,
 }
 
+impl UpdateTrustRequest {
+    /// Returns a builder for `UpdateTrustRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTrustRequestBuilder {
+        UpdateTrustRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTrustRequest`](struct.UpdateTrustRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTrustRequestBuilder {
+    selective_auth: Option<SelectiveAuth>,
+    trust_id: Option<String>,
+}
+
+impl UpdateTrustRequestBuilder {
+    /// Sets the [`selective_auth`](struct.UpdateTrustRequest.html#structfield.selective_auth) member.
+    pub fn selective_auth<T: Into<SelectiveAuth>>(mut self, value: T) -> Self {
+        self.selective_auth = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trust_id`](struct.UpdateTrustRequest.html#structfield.trust_id) member.
+    pub fn trust_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.trust_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTrustRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateTrustRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTrustRequest {
+            selective_auth: self.selective_auth,
+            trust_id: self
+                .trust_id
+                .ok_or(::rusoto_core::BuildError::missing("trust_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateTrustResult {
@@ -1802,6 +5710,36 @@ pub struct VerifyTrustRequest {
     pub trust_id: String,
 }
 
+impl VerifyTrustRequest {
+    /// Returns a builder for `VerifyTrustRequest`, checking its required members when built.
+    pub fn builder() -> VerifyTrustRequestBuilder {
+        VerifyTrustRequestBuilder::default()
+    }
+}
+
+/// Builds a [`VerifyTrustRequest`](struct.VerifyTrustRequest.html).
+#[derive(Default, Debug)]
+pub struct VerifyTrustRequestBuilder {
+    trust_id: Option<String>,
+}
+
+impl VerifyTrustRequestBuilder {
+    /// Sets the [`trust_id`](struct.VerifyTrustRequest.html#structfield.trust_id) member.
+    pub fn trust_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.trust_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `VerifyTrustRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<VerifyTrustRequest, ::rusoto_core::BuildError> {
+        Ok(VerifyTrustRequest {
+            trust_id: self
+                .trust_id
+                .ok_or(::rusoto_core::BuildError::missing("trust_id"))?,
+        })
+    }
+}
+
 /// <p>Result of a VerifyTrust request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -5146,22 +9084,346 @@ pub trait DirectoryService {
         input: VerifyTrustRequest,
     ) -> RusotoFuture<VerifyTrustResult, VerifyTrustError>;
 }
-/// A client for the Directory Service API.
-#[derive(Clone)]
-pub struct DirectoryServiceClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl DirectoryServiceClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> DirectoryServiceClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
-    pub fn new_with<P, D>(
+impl<T: ?Sized + DirectoryService> DirectoryService for ::std::sync::Arc<T> {
+    fn accept_shared_directory(
+        &self,
+        input: AcceptSharedDirectoryRequest,
+    ) -> RusotoFuture<AcceptSharedDirectoryResult, AcceptSharedDirectoryError> {
+        (**self).accept_shared_directory(input)
+    }
+    fn add_ip_routes(
+        &self,
+        input: AddIpRoutesRequest,
+    ) -> RusotoFuture<AddIpRoutesResult, AddIpRoutesError> {
+        (**self).add_ip_routes(input)
+    }
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> RusotoFuture<AddTagsToResourceResult, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn cancel_schema_extension(
+        &self,
+        input: CancelSchemaExtensionRequest,
+    ) -> RusotoFuture<CancelSchemaExtensionResult, CancelSchemaExtensionError> {
+        (**self).cancel_schema_extension(input)
+    }
+    fn connect_directory(
+        &self,
+        input: ConnectDirectoryRequest,
+    ) -> RusotoFuture<ConnectDirectoryResult, ConnectDirectoryError> {
+        (**self).connect_directory(input)
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<CreateAliasResult, CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_computer(
+        &self,
+        input: CreateComputerRequest,
+    ) -> RusotoFuture<CreateComputerResult, CreateComputerError> {
+        (**self).create_computer(input)
+    }
+    fn create_conditional_forwarder(
+        &self,
+        input: CreateConditionalForwarderRequest,
+    ) -> RusotoFuture<CreateConditionalForwarderResult, CreateConditionalForwarderError> {
+        (**self).create_conditional_forwarder(input)
+    }
+    fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> RusotoFuture<CreateDirectoryResult, CreateDirectoryError> {
+        (**self).create_directory(input)
+    }
+    fn create_log_subscription(
+        &self,
+        input: CreateLogSubscriptionRequest,
+    ) -> RusotoFuture<CreateLogSubscriptionResult, CreateLogSubscriptionError> {
+        (**self).create_log_subscription(input)
+    }
+    fn create_microsoft_ad(
+        &self,
+        input: CreateMicrosoftADRequest,
+    ) -> RusotoFuture<CreateMicrosoftADResult, CreateMicrosoftADError> {
+        (**self).create_microsoft_ad(input)
+    }
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotRequest,
+    ) -> RusotoFuture<CreateSnapshotResult, CreateSnapshotError> {
+        (**self).create_snapshot(input)
+    }
+    fn create_trust(
+        &self,
+        input: CreateTrustRequest,
+    ) -> RusotoFuture<CreateTrustResult, CreateTrustError> {
+        (**self).create_trust(input)
+    }
+    fn delete_conditional_forwarder(
+        &self,
+        input: DeleteConditionalForwarderRequest,
+    ) -> RusotoFuture<DeleteConditionalForwarderResult, DeleteConditionalForwarderError> {
+        (**self).delete_conditional_forwarder(input)
+    }
+    fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> RusotoFuture<DeleteDirectoryResult, DeleteDirectoryError> {
+        (**self).delete_directory(input)
+    }
+    fn delete_log_subscription(
+        &self,
+        input: DeleteLogSubscriptionRequest,
+    ) -> RusotoFuture<DeleteLogSubscriptionResult, DeleteLogSubscriptionError> {
+        (**self).delete_log_subscription(input)
+    }
+    fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotRequest,
+    ) -> RusotoFuture<DeleteSnapshotResult, DeleteSnapshotError> {
+        (**self).delete_snapshot(input)
+    }
+    fn delete_trust(
+        &self,
+        input: DeleteTrustRequest,
+    ) -> RusotoFuture<DeleteTrustResult, DeleteTrustError> {
+        (**self).delete_trust(input)
+    }
+    fn deregister_event_topic(
+        &self,
+        input: DeregisterEventTopicRequest,
+    ) -> RusotoFuture<DeregisterEventTopicResult, DeregisterEventTopicError> {
+        (**self).deregister_event_topic(input)
+    }
+    fn describe_conditional_forwarders(
+        &self,
+        input: DescribeConditionalForwardersRequest,
+    ) -> RusotoFuture<DescribeConditionalForwardersResult, DescribeConditionalForwardersError> {
+        (**self).describe_conditional_forwarders(input)
+    }
+    fn describe_directories(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> RusotoFuture<DescribeDirectoriesResult, DescribeDirectoriesError> {
+        (**self).describe_directories(input)
+    }
+    fn describe_domain_controllers(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> RusotoFuture<DescribeDomainControllersResult, DescribeDomainControllersError> {
+        (**self).describe_domain_controllers(input)
+    }
+    fn describe_event_topics(
+        &self,
+        input: DescribeEventTopicsRequest,
+    ) -> RusotoFuture<DescribeEventTopicsResult, DescribeEventTopicsError> {
+        (**self).describe_event_topics(input)
+    }
+    fn describe_shared_directories(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> RusotoFuture<DescribeSharedDirectoriesResult, DescribeSharedDirectoriesError> {
+        (**self).describe_shared_directories(input)
+    }
+    fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> RusotoFuture<DescribeSnapshotsResult, DescribeSnapshotsError> {
+        (**self).describe_snapshots(input)
+    }
+    fn describe_trusts(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> RusotoFuture<DescribeTrustsResult, DescribeTrustsError> {
+        (**self).describe_trusts(input)
+    }
+    fn disable_radius(
+        &self,
+        input: DisableRadiusRequest,
+    ) -> RusotoFuture<DisableRadiusResult, DisableRadiusError> {
+        (**self).disable_radius(input)
+    }
+    fn disable_sso(
+        &self,
+        input: DisableSsoRequest,
+    ) -> RusotoFuture<DisableSsoResult, DisableSsoError> {
+        (**self).disable_sso(input)
+    }
+    fn enable_radius(
+        &self,
+        input: EnableRadiusRequest,
+    ) -> RusotoFuture<EnableRadiusResult, EnableRadiusError> {
+        (**self).enable_radius(input)
+    }
+    fn enable_sso(&self, input: EnableSsoRequest) -> RusotoFuture<EnableSsoResult, EnableSsoError> {
+        (**self).enable_sso(input)
+    }
+    fn get_directory_limits(
+        &self,
+    ) -> RusotoFuture<GetDirectoryLimitsResult, GetDirectoryLimitsError> {
+        (**self).get_directory_limits()
+    }
+    fn get_snapshot_limits(
+        &self,
+        input: GetSnapshotLimitsRequest,
+    ) -> RusotoFuture<GetSnapshotLimitsResult, GetSnapshotLimitsError> {
+        (**self).get_snapshot_limits(input)
+    }
+    fn list_ip_routes(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> RusotoFuture<ListIpRoutesResult, ListIpRoutesError> {
+        (**self).list_ip_routes(input)
+    }
+    fn list_log_subscriptions(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> RusotoFuture<ListLogSubscriptionsResult, ListLogSubscriptionsError> {
+        (**self).list_log_subscriptions(input)
+    }
+    fn list_schema_extensions(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> RusotoFuture<ListSchemaExtensionsResult, ListSchemaExtensionsError> {
+        (**self).list_schema_extensions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResult, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn register_event_topic(
+        &self,
+        input: RegisterEventTopicRequest,
+    ) -> RusotoFuture<RegisterEventTopicResult, RegisterEventTopicError> {
+        (**self).register_event_topic(input)
+    }
+    fn reject_shared_directory(
+        &self,
+        input: RejectSharedDirectoryRequest,
+    ) -> RusotoFuture<RejectSharedDirectoryResult, RejectSharedDirectoryError> {
+        (**self).reject_shared_directory(input)
+    }
+    fn remove_ip_routes(
+        &self,
+        input: RemoveIpRoutesRequest,
+    ) -> RusotoFuture<RemoveIpRoutesResult, RemoveIpRoutesError> {
+        (**self).remove_ip_routes(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> RusotoFuture<RemoveTagsFromResourceResult, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_user_password(
+        &self,
+        input: ResetUserPasswordRequest,
+    ) -> RusotoFuture<ResetUserPasswordResult, ResetUserPasswordError> {
+        (**self).reset_user_password(input)
+    }
+    fn restore_from_snapshot(
+        &self,
+        input: RestoreFromSnapshotRequest,
+    ) -> RusotoFuture<RestoreFromSnapshotResult, RestoreFromSnapshotError> {
+        (**self).restore_from_snapshot(input)
+    }
+    fn share_directory(
+        &self,
+        input: ShareDirectoryRequest,
+    ) -> RusotoFuture<ShareDirectoryResult, ShareDirectoryError> {
+        (**self).share_directory(input)
+    }
+    fn start_schema_extension(
+        &self,
+        input: StartSchemaExtensionRequest,
+    ) -> RusotoFuture<StartSchemaExtensionResult, StartSchemaExtensionError> {
+        (**self).start_schema_extension(input)
+    }
+    fn unshare_directory(
+        &self,
+        input: UnshareDirectoryRequest,
+    ) -> RusotoFuture<UnshareDirectoryResult, UnshareDirectoryError> {
+        (**self).unshare_directory(input)
+    }
+    fn update_conditional_forwarder(
+        &self,
+        input: UpdateConditionalForwarderRequest,
+    ) -> RusotoFuture<UpdateConditionalForwarderResult, UpdateConditionalForwarderError> {
+        (**self).update_conditional_forwarder(input)
+    }
+    fn update_number_of_domain_controllers(
+        &self,
+        input: UpdateNumberOfDomainControllersRequest,
+    ) -> RusotoFuture<UpdateNumberOfDomainControllersResult, UpdateNumberOfDomainControllersError>
+    {
+        (**self).update_number_of_domain_controllers(input)
+    }
+    fn update_radius(
+        &self,
+        input: UpdateRadiusRequest,
+    ) -> RusotoFuture<UpdateRadiusResult, UpdateRadiusError> {
+        (**self).update_radius(input)
+    }
+    fn update_trust(
+        &self,
+        input: UpdateTrustRequest,
+    ) -> RusotoFuture<UpdateTrustResult, UpdateTrustError> {
+        (**self).update_trust(input)
+    }
+    fn verify_trust(
+        &self,
+        input: VerifyTrustRequest,
+    ) -> RusotoFuture<VerifyTrustResult, VerifyTrustError> {
+        (**self).verify_trust(input)
+    }
+}
+/// The error codes Directory Service retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
+/// A client for the Directory Service API.
+#[derive(Clone)]
+pub struct DirectoryServiceClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl DirectoryServiceClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> DirectoryServiceClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_DS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> DirectoryServiceClient {
+        Self::new(region::Region::from_env_for_service("DS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `ds`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> DirectoryServiceClient {
+        Self::new_with_client(config.client().clone(), config.region_for("ds", "DS"))
+    }
+
+    pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
         region: region::Region,
@@ -5179,7 +9441,49 @@ impl DirectoryServiceClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DirectoryServiceClient {
-        DirectoryServiceClient { client, region }
+        DirectoryServiceClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "ds")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "ds", "ds", "DS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `DirectoryService` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn DirectoryService + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for DirectoryServiceClient {
+    fn service_name(&self) -> &'static str {
+        "Directory Service"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "ds"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> DirectoryServiceClient {
+        Self::new_with_client(client, region)
     }
 }
 
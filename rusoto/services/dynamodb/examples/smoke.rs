@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon DynamoDB clients.
+//!
+//! Makes a single read-only `ListBackups` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_dynamodb::{DynamoDb, DynamoDbClient, ListBackupsInput};
+
+fn main() {
+    let client = DynamoDbClient::from_env();
+    match client.list_backups(ListBackupsInput::default()).sync() {
+        Ok(_) => println!("ListBackups succeeded"),
+        Err(error) => {
+            eprintln!("ListBackups failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
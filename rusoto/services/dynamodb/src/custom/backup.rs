@@ -0,0 +1,915 @@
+//! Helpers for point-in-time recovery, on-demand backups and restores.
+//!
+//! Restoring a backup, or a table to a point in time, creates a new table with the key schema,
+//! indexes and items of the source table, but without its stream, time to live, point-in-time
+//! recovery, tags or auto scaling. `BackupExt::restore_table_and_wait` restores a table, waits
+//! for it to become active and reapplies the settings of the source table, reporting the ones it
+//! had to reapply. With the `autoscaling` feature, `copy_autoscaling` copies the scalable
+//! targets and scaling policies of the source table and its indexes.
+
+use std::error::Error;
+use std::fmt;
+
+use futures::future::{self, Loop};
+use futures::{stream, Future, Stream};
+use rusoto_core::timestamp::EpochTimestamp;
+use rusoto_core::waiter::{poll_until, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::custom::waiters::{TableWaitError, TableWaiterExt};
+use crate::generated::{
+    BackupDescription, BackupDetails, BackupStatus, CreateBackupError, CreateBackupInput,
+    DescribeBackupError, DescribeBackupInput, DescribeContinuousBackupsError,
+    DescribeContinuousBackupsInput, DescribeTableError, DescribeTableInput,
+    DescribeTimeToLiveError, DescribeTimeToLiveInput, DynamoDb, ListTagsOfResourceError,
+    ListTagsOfResourceInput, PointInTimeRecoveryDescription, PointInTimeRecoverySpecification,
+    PointInTimeRecoveryStatus, RestoreTableFromBackupError, RestoreTableFromBackupInput,
+    RestoreTableToPointInTimeError, RestoreTableToPointInTimeInput, StreamSpecification,
+    StreamViewType, TableDescription, Tag, TagResourceError, TagResourceInput,
+    TimeToLiveDescription, TimeToLiveSpecification, TimeToLiveStatus, UpdateContinuousBackupsError,
+    UpdateContinuousBackupsInput, UpdateTableInput, UpdateTimeToLiveError, UpdateTimeToLiveInput,
+};
+
+#[cfg(feature = "autoscaling")]
+use rusoto_application_autoscaling::{
+    ApplicationAutoScaling, DescribeScalableTargetsError, DescribeScalableTargetsRequest,
+    DescribeScalingPoliciesError, DescribeScalingPoliciesRequest, PutScalingPolicyError,
+    PutScalingPolicyRequest, RegisterScalableTargetError, RegisterScalableTargetRequest,
+    ScalableDimension, ServiceNamespace,
+};
+
+/// Errors returned while backing up, restoring, or reapplying the settings of a table.
+#[derive(Debug, PartialEq)]
+pub enum BackupError {
+    /// Creating the backup failed.
+    CreateBackup(CreateBackupError),
+    /// Describing the backup failed.
+    DescribeBackup(DescribeBackupError),
+    /// Describing the continuous backups of a table failed.
+    DescribeContinuousBackups(DescribeContinuousBackupsError),
+    /// Enabling point-in-time recovery failed.
+    UpdateContinuousBackups(UpdateContinuousBackupsError),
+    /// Restoring the backup failed.
+    RestoreTableFromBackup(RestoreTableFromBackupError),
+    /// Restoring the table to a point in time failed.
+    RestoreTableToPointInTime(RestoreTableToPointInTimeError),
+    /// Describing the source table failed.
+    DescribeTable(DescribeTableError),
+    /// Describing the time to live of the source table failed.
+    DescribeTimeToLive(DescribeTimeToLiveError),
+    /// Enabling time to live on the restored table failed.
+    UpdateTimeToLive(UpdateTimeToLiveError),
+    /// Listing the tags of the source table failed.
+    ListTagsOfResource(ListTagsOfResourceError),
+    /// Tagging the restored table failed.
+    TagResource(TagResourceError),
+    /// Waiting for the restored table, or enabling its stream, failed.
+    Table(TableWaitError),
+    /// Describing the scalable targets of the source table failed.
+    #[cfg(feature = "autoscaling")]
+    DescribeScalableTargets(DescribeScalableTargetsError),
+    /// Registering a scalable target of the restored table failed.
+    #[cfg(feature = "autoscaling")]
+    RegisterScalableTarget(RegisterScalableTargetError),
+    /// Describing the scaling policies of the source table failed.
+    #[cfg(feature = "autoscaling")]
+    DescribeScalingPolicies(DescribeScalingPoliciesError),
+    /// Putting a scaling policy of the restored table failed.
+    #[cfg(feature = "autoscaling")]
+    PutScalingPolicy(PutScalingPolicyError),
+    /// The backup was deleted before it became available.
+    BackupDeleted(String),
+    /// The backup or point-in-time recovery did not become available within the configured
+    /// number of attempts.
+    TimedOut(String),
+}
+
+impl fmt::Display for BackupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for BackupError {
+    fn description(&self) -> &str {
+        match *self {
+            BackupError::CreateBackup(ref cause) => cause.description(),
+            BackupError::DescribeBackup(ref cause) => cause.description(),
+            BackupError::DescribeContinuousBackups(ref cause) => cause.description(),
+            BackupError::UpdateContinuousBackups(ref cause) => cause.description(),
+            BackupError::RestoreTableFromBackup(ref cause) => cause.description(),
+            BackupError::RestoreTableToPointInTime(ref cause) => cause.description(),
+            BackupError::DescribeTable(ref cause) => cause.description(),
+            BackupError::DescribeTimeToLive(ref cause) => cause.description(),
+            BackupError::UpdateTimeToLive(ref cause) => cause.description(),
+            BackupError::ListTagsOfResource(ref cause) => cause.description(),
+            BackupError::TagResource(ref cause) => cause.description(),
+            BackupError::Table(ref cause) => cause.description(),
+            #[cfg(feature = "autoscaling")]
+            BackupError::DescribeScalableTargets(ref cause) => cause.description(),
+            #[cfg(feature = "autoscaling")]
+            BackupError::RegisterScalableTarget(ref cause) => cause.description(),
+            #[cfg(feature = "autoscaling")]
+            BackupError::DescribeScalingPolicies(ref cause) => cause.description(),
+            #[cfg(feature = "autoscaling")]
+            BackupError::PutScalingPolicy(ref cause) => cause.description(),
+            BackupError::BackupDeleted(ref cause) => cause,
+            BackupError::TimedOut(ref cause) => cause,
+        }
+    }
+}
+
+/// What `restore_table_and_wait` restores a table from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RestoreSource {
+    /// An on-demand backup, by its ARN.
+    Backup(String),
+    /// A table with point-in-time recovery enabled, as it was at `restore_date_time`, or at the
+    /// latest restorable time if `None`.
+    PointInTime {
+        /// The name of the table.
+        source_table_name: String,
+        /// The time to restore the table to.
+        restore_date_time: Option<EpochTimestamp>,
+    },
+}
+
+/// The settings of a table which restores don't carry over.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableSettings {
+    /// The view type of the table's stream, if streams are enabled.
+    pub stream_view_type: Option<StreamViewType>,
+    /// The attribute holding expiry times, if time to live is enabled.
+    pub time_to_live_attribute: Option<String>,
+    /// Whether point-in-time recovery is enabled.
+    pub point_in_time_recovery: bool,
+    /// The tags of the table.
+    pub tags: Vec<Tag>,
+}
+
+impl TableSettings {
+    /// The settings recorded in a backup: the stream and time to live of its source table when
+    /// the backup was made.
+    pub fn from_backup(backup: &BackupDescription) -> TableSettings {
+        let features = backup.source_table_feature_details.as_ref();
+        TableSettings {
+            stream_view_type: features
+                .and_then(|features| features.stream_description.as_ref())
+                .and_then(stream_view_type),
+            time_to_live_attribute: features
+                .and_then(|features| features.time_to_live_description.as_ref())
+                .and_then(time_to_live_attribute),
+            ..Default::default()
+        }
+    }
+}
+
+/// A setting of the source table which was applied to the restored table.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReappliedSetting {
+    /// The stream was enabled with this view type.
+    Stream(StreamViewType),
+    /// Time to live was enabled on this attribute.
+    TimeToLive(String),
+    /// Point-in-time recovery was enabled.
+    PointInTimeRecovery,
+    /// The table was tagged with these tags.
+    Tags(Vec<Tag>),
+    /// The table, or one of its indexes, was registered as a scalable target.
+    #[cfg(feature = "autoscaling")]
+    ScalableTarget {
+        /// The resource ID of the table or index, such as `table/my-table/index/by-date`.
+        resource_id: String,
+        /// The scalable dimension, such as `ScalableDimension::DynamodbTableReadCapacityUnits`.
+        scalable_dimension: ScalableDimension,
+    },
+    /// A scaling policy was put on the table or one of its indexes.
+    #[cfg(feature = "autoscaling")]
+    ScalingPolicy {
+        /// The resource ID of the table or index.
+        resource_id: String,
+        /// The name of the policy.
+        policy_name: String,
+    },
+}
+
+/// The outcome of `restore_table_and_wait`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestoreReport {
+    /// The restored table, once active with all settings reapplied.
+    pub table: TableDescription,
+    /// The settings of the source table.
+    pub source_settings: TableSettings,
+    /// The settings which were missing from the restored table, and were reapplied to it.
+    pub reapplied: Vec<ReappliedSetting>,
+}
+
+/// Returns true once point-in-time recovery is enabled.
+pub fn is_point_in_time_recovery_enabled(description: &PointInTimeRecoveryDescription) -> bool {
+    description.point_in_time_recovery_status == Some(PointInTimeRecoveryStatus::Enabled)
+}
+
+/// The settings of the source table missing from the restored table `restored`.
+pub fn settings_to_reapply(
+    settings: &TableSettings,
+    restored: &TableDescription,
+) -> Vec<ReappliedSetting> {
+    let mut reapply = Vec::new();
+    if let Some(ref view_type) = settings.stream_view_type {
+        // a stream can't be enabled on a table which already has one
+        if restored
+            .stream_specification
+            .as_ref()
+            .and_then(stream_view_type)
+            .is_none()
+        {
+            reapply.push(ReappliedSetting::Stream(view_type.clone()));
+        }
+    }
+    if let Some(ref attribute_name) = settings.time_to_live_attribute {
+        reapply.push(ReappliedSetting::TimeToLive(attribute_name.clone()));
+    }
+    if settings.point_in_time_recovery {
+        reapply.push(ReappliedSetting::PointInTimeRecovery);
+    }
+    if !settings.tags.is_empty() {
+        reapply.push(ReappliedSetting::Tags(settings.tags.clone()));
+    }
+    reapply
+}
+
+fn stream_view_type(stream: &StreamSpecification) -> Option<StreamViewType> {
+    if stream.stream_enabled == Some(true) {
+        stream.stream_view_type.clone()
+    } else {
+        None
+    }
+}
+
+fn time_to_live_attribute(time_to_live: &TimeToLiveDescription) -> Option<String> {
+    match time_to_live.time_to_live_status {
+        Some(TimeToLiveStatus::Enabled) | Some(TimeToLiveStatus::Enabling) => {
+            time_to_live.attribute_name.clone()
+        }
+        _ => None,
+    }
+}
+
+type BackupFuture<T> = Box<dyn Future<Item = T, Error = RusotoError<BackupError>> + Send>;
+
+/// Fetches every page with `fetch`, which is given the token of the page and returns its items
+/// and the token of the next page.
+fn collect_pages<T, F, R>(mut fetch: F) -> BackupFuture<Vec<T>>
+where
+    T: Send + 'static,
+    F: FnMut(Option<String>) -> R + Send + 'static,
+    R: Future<Item = (Vec<T>, Option<String>), Error = RusotoError<BackupError>> + Send + 'static,
+{
+    Box::new(future::loop_fn(
+        (Vec::new(), None),
+        move |(mut items, token): (Vec<T>, Option<String>)| {
+            fetch(token).map(move |(page, next_token)| {
+                items.extend(page);
+                match next_token {
+                    Some(token) => Loop::Continue((items, Some(token))),
+                    None => Loop::Break(items),
+                }
+            })
+        },
+    ))
+}
+
+/// Extension methods for point-in-time recovery, backups and restores.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::waiter::WaiterConfig;
+/// use rusoto_core::Region;
+/// use rusoto_dynamodb::backup::{BackupExt, RestoreSource};
+/// use rusoto_dynamodb::DynamoDbClient;
+///
+/// let client = DynamoDbClient::new(Region::UsEast1);
+/// let backup = client
+///     .create_backup_and_wait("orders", "orders-nightly", WaiterConfig::default())
+///     .sync()
+///     .unwrap();
+/// let report = client
+///     .restore_table_and_wait(
+///         RestoreSource::Backup(backup.backup_arn),
+///         "orders-restored",
+///         WaiterConfig::default(),
+///     )
+///     .sync()
+///     .unwrap();
+/// for setting in report.reapplied {
+///     println!("reapplied {:?}", setting);
+/// }
+/// ```
+pub trait BackupExt: DynamoDb + Clone + Send + Sized + 'static {
+    /// Polls `DescribeContinuousBackups` until point-in-time recovery of the table is enabled.
+    fn wait_for_point_in_time_recovery(
+        &self,
+        table_name: &str,
+        config: WaiterConfig,
+    ) -> RusotoFuture<PointInTimeRecoveryDescription, BackupError> {
+        let client = self.clone();
+        let table_name = table_name.to_owned();
+        let timeout = RusotoError::Service(BackupError::TimedOut(format!(
+            "Point-in-time recovery of table {} was not enabled",
+            table_name
+        )));
+
+        let future = poll_until(
+            config,
+            move || {
+                client
+                    .describe_continuous_backups(DescribeContinuousBackupsInput {
+                        table_name: table_name.clone(),
+                    })
+                    .map(|output| {
+                        output
+                            .continuous_backups_description
+                            .and_then(|backups| backups.point_in_time_recovery_description)
+                            .filter(is_point_in_time_recovery_enabled)
+                    })
+                    .map_err(|e| e.map_service(BackupError::DescribeContinuousBackups))
+            },
+            timeout,
+        );
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Enables point-in-time recovery of a table, then waits until it is enabled.
+    ///
+    /// Right after a table is created, enabling it fails with `ContinuousBackupsUnavailable`
+    /// for a while.
+    fn enable_point_in_time_recovery_and_wait(
+        &self,
+        table_name: &str,
+        config: WaiterConfig,
+    ) -> RusotoFuture<PointInTimeRecoveryDescription, BackupError> {
+        let client = self.clone();
+        let table_name = table_name.to_owned();
+
+        let future = self
+            .update_continuous_backups(UpdateContinuousBackupsInput {
+                point_in_time_recovery_specification: PointInTimeRecoverySpecification {
+                    point_in_time_recovery_enabled: true,
+                },
+                table_name: table_name.clone(),
+            })
+            .map_err(|e| e.map_service(BackupError::UpdateContinuousBackups))
+            .and_then(move |_| client.wait_for_point_in_time_recovery(&table_name, config));
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Creates an on-demand backup of a table, then waits until it is available.
+    fn create_backup_and_wait(
+        &self,
+        table_name: &str,
+        backup_name: &str,
+        config: WaiterConfig,
+    ) -> RusotoFuture<BackupDetails, BackupError> {
+        let client = self.clone();
+
+        let future = self
+            .create_backup(CreateBackupInput {
+                backup_name: backup_name.to_owned(),
+                table_name: table_name.to_owned(),
+            })
+            .map_err(|e| e.map_service(BackupError::CreateBackup))
+            .and_then(|output| {
+                output.backup_details.ok_or_else(|| {
+                    RusotoError::ParseError("CreateBackup returned no backup details".to_owned())
+                })
+            })
+            .and_then(move |details| {
+                let backup_arn = details.backup_arn;
+                let timeout = RusotoError::Service(BackupError::TimedOut(format!(
+                    "Backup {} did not become available",
+                    backup_arn
+                )));
+                poll_until(
+                    config,
+                    move || {
+                        let backup_arn = backup_arn.clone();
+                        client
+                            .describe_backup(DescribeBackupInput {
+                                backup_arn: backup_arn.clone(),
+                            })
+                            .map_err(|e| e.map_service(BackupError::DescribeBackup))
+                            .and_then(move |output| {
+                                let details = output
+                                    .backup_description
+                                    .and_then(|backup| backup.backup_details);
+                                match details {
+                                    Some(ref details)
+                                        if details.backup_status == BackupStatus::Deleted =>
+                                    {
+                                        Err(RusotoError::Service(BackupError::BackupDeleted(
+                                            format!("Backup {} was deleted", backup_arn),
+                                        )))
+                                    }
+                                    Some(details) => Ok(Some(details).filter(|details| {
+                                        details.backup_status == BackupStatus::Available
+                                    })),
+                                    None => Ok(None),
+                                }
+                            })
+                    },
+                    timeout,
+                )
+            });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Reads the settings of a table which restores don't carry over: its stream, time to live,
+    /// point-in-time recovery and tags.
+    fn table_settings(&self, table_name: &str) -> RusotoFuture<TableSettings, BackupError> {
+        let client = self.clone();
+        let table_name = table_name.to_owned();
+
+        let future = self
+            .describe_table(DescribeTableInput {
+                table_name: table_name.clone(),
+            })
+            .map_err(|e| e.map_service(BackupError::DescribeTable))
+            .and_then(move |output| {
+                let table = output.table.unwrap_or_default();
+                let stream_view_type = table
+                    .stream_specification
+                    .as_ref()
+                    .and_then(stream_view_type);
+
+                let time_to_live = client
+                    .describe_time_to_live(DescribeTimeToLiveInput {
+                        table_name: table_name.clone(),
+                    })
+                    .map(|output| {
+                        output
+                            .time_to_live_description
+                            .as_ref()
+                            .and_then(time_to_live_attribute)
+                    })
+                    .map_err(|e| e.map_service(BackupError::DescribeTimeToLive));
+                let point_in_time_recovery = client
+                    .describe_continuous_backups(DescribeContinuousBackupsInput {
+                        table_name: table_name.clone(),
+                    })
+                    .map(|output| {
+                        output
+                            .continuous_backups_description
+                            .and_then(|backups| backups.point_in_time_recovery_description)
+                            .map_or(false, |description| {
+                                is_point_in_time_recovery_enabled(&description)
+                            })
+                    })
+                    .map_err(|e| e.map_service(BackupError::DescribeContinuousBackups));
+                let table_arn = table.table_arn.unwrap_or_default();
+                let tags = collect_pages(move |next_token| {
+                    client
+                        .list_tags_of_resource(ListTagsOfResourceInput {
+                            next_token,
+                            resource_arn: table_arn.clone(),
+                        })
+                        .map(|output| (output.tags.unwrap_or_default(), output.next_token))
+                        .map_err(|e| e.map_service(BackupError::ListTagsOfResource))
+                });
+
+                time_to_live.join3(point_in_time_recovery, tags).map(
+                    move |(time_to_live_attribute, point_in_time_recovery, tags)| TableSettings {
+                        stream_view_type,
+                        time_to_live_attribute,
+                        point_in_time_recovery,
+                        tags,
+                    },
+                )
+            });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Restores a table from a backup or to a point in time, waits until it is active, then
+    /// reapplies the settings of the source table which the restore dropped.
+    ///
+    /// The settings are read from the source table as it is now. When restoring a backup of a
+    /// table which has since been deleted, only the stream and time to live recorded in the
+    /// backup are reapplied.
+    fn restore_table_and_wait(
+        &self,
+        source: RestoreSource,
+        target_table_name: &str,
+        config: WaiterConfig,
+    ) -> RusotoFuture<RestoreReport, BackupError> {
+        let client = self.clone();
+        let target_table_name = target_table_name.to_owned();
+
+        let settings: BackupFuture<TableSettings> = match source {
+            RestoreSource::Backup(ref backup_arn) => {
+                let client = self.clone();
+                Box::new(
+                    self.describe_backup(DescribeBackupInput {
+                        backup_arn: backup_arn.clone(),
+                    })
+                    .map_err(|e| e.map_service(BackupError::DescribeBackup))
+                    .and_then(move |output| {
+                        let backup = output.backup_description.unwrap_or_default();
+                        let source_table_name = backup
+                            .source_table_details
+                            .as_ref()
+                            .map(|table| table.table_name.clone())
+                            .unwrap_or_default();
+                        client
+                            .table_settings(&source_table_name)
+                            .then(move |result| match result {
+                                Err(RusotoError::Service(BackupError::DescribeTable(
+                                    DescribeTableError::ResourceNotFound(_),
+                                ))) => Ok(TableSettings::from_backup(&backup)),
+                                result => result,
+                            })
+                    }),
+                )
+            }
+            RestoreSource::PointInTime {
+                ref source_table_name,
+                ..
+            } => Box::new(self.table_settings(source_table_name)),
+        };
+
+        let future = settings
+            .and_then(move |settings| {
+                let restore: BackupFuture<()> = match source {
+                    RestoreSource::Backup(backup_arn) => Box::new(
+                        client
+                            .restore_table_from_backup(RestoreTableFromBackupInput {
+                                backup_arn,
+                                target_table_name: target_table_name.clone(),
+                            })
+                            .map(|_| ())
+                            .map_err(|e| e.map_service(BackupError::RestoreTableFromBackup)),
+                    ),
+                    RestoreSource::PointInTime {
+                        source_table_name,
+                        restore_date_time,
+                    } => Box::new(
+                        client
+                            .restore_table_to_point_in_time(RestoreTableToPointInTimeInput {
+                                use_latest_restorable_time: Some(restore_date_time.is_none()),
+                                restore_date_time,
+                                source_table_name,
+                                target_table_name: target_table_name.clone(),
+                            })
+                            .map(|_| ())
+                            .map_err(|e| e.map_service(BackupError::RestoreTableToPointInTime)),
+                    ),
+                };
+                restore
+                    .and_then(move |()| {
+                        client
+                            .wait_for_table_active(&target_table_name, config.clone())
+                            .map_err(|e| e.map_service(BackupError::Table))
+                            .map(move |table| (client, target_table_name, config, table))
+                    })
+                    .map(move |state| (settings, state))
+            })
+            .and_then(|(settings, (client, table_name, config, table))| {
+                let reapply = settings_to_reapply(&settings, &table);
+                let table_arn = table.table_arn.clone().unwrap_or_default();
+                let reapply_client = client.clone();
+                let reapply_config = config.clone();
+                let reapply_table_name = table_name.clone();
+
+                stream::iter_ok(reapply)
+                    .and_then(move |setting| {
+                        reapply_setting(
+                            &reapply_client,
+                            &reapply_table_name,
+                            &table_arn,
+                            setting,
+                            reapply_config.clone(),
+                        )
+                    })
+                    .collect()
+                    .and_then(move |reapplied| {
+                        client
+                            .wait_for_table_active(&table_name, config)
+                            .map_err(|e| e.map_service(BackupError::Table))
+                            .map(move |table| RestoreReport {
+                                table,
+                                source_settings: settings,
+                                reapplied,
+                            })
+                    })
+            });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: DynamoDb + Clone + Send + 'static> BackupExt for T {}
+
+/// Applies `setting` to the table `table_name`.
+fn reapply_setting<C>(
+    client: &C,
+    table_name: &str,
+    table_arn: &str,
+    setting: ReappliedSetting,
+    config: WaiterConfig,
+) -> BackupFuture<ReappliedSetting>
+where
+    C: DynamoDb + Clone + Send + 'static,
+{
+    match setting {
+        ReappliedSetting::Stream(ref view_type) => {
+            let wait_client = client.clone();
+            let table_name = table_name.to_owned();
+            Box::new(
+                client
+                    .update_table(UpdateTableInput {
+                        table_name: table_name.clone(),
+                        stream_specification: Some(StreamSpecification {
+                            stream_enabled: Some(true),
+                            stream_view_type: Some(view_type.clone()),
+                        }),
+                        ..Default::default()
+                    })
+                    .map_err(|e| {
+                        e.map_service(|e| BackupError::Table(TableWaitError::UpdateTable(e)))
+                    })
+                    .and_then(move |_| {
+                        wait_client
+                            .wait_for_table_active(&table_name, config)
+                            .map_err(|e| e.map_service(BackupError::Table))
+                    })
+                    .map(|_| setting),
+            )
+        }
+        ReappliedSetting::TimeToLive(ref attribute_name) => Box::new(
+            client
+                .update_time_to_live(UpdateTimeToLiveInput {
+                    table_name: table_name.to_owned(),
+                    time_to_live_specification: TimeToLiveSpecification {
+                        attribute_name: attribute_name.clone(),
+                        enabled: true,
+                    },
+                })
+                .map_err(|e| e.map_service(BackupError::UpdateTimeToLive))
+                .map(|_| setting),
+        ),
+        ReappliedSetting::PointInTimeRecovery => Box::new(
+            client
+                .enable_point_in_time_recovery_and_wait(table_name, config)
+                .map(|_| setting),
+        ),
+        ReappliedSetting::Tags(ref tags) => Box::new(
+            client
+                .tag_resource(TagResourceInput {
+                    resource_arn: table_arn.to_owned(),
+                    tags: tags.clone(),
+                })
+                .map_err(|e| e.map_service(BackupError::TagResource))
+                .map(|()| setting),
+        ),
+        // only `copy_autoscaling` applies auto scaling
+        #[cfg(feature = "autoscaling")]
+        ReappliedSetting::ScalableTarget { .. } | ReappliedSetting::ScalingPolicy { .. } => {
+            Box::new(future::ok(setting))
+        }
+    }
+}
+
+/// Moves the resource ID of the table `source_table_name`, or of one of its indexes, to the
+/// table `target_table_name`.
+#[cfg(any(feature = "autoscaling", test))]
+fn retarget_resource_id(
+    resource_id: &str,
+    source_table_name: &str,
+    target_table_name: &str,
+) -> Option<String> {
+    let source = format!("table/{}", source_table_name);
+    if resource_id == source {
+        Some(format!("table/{}", target_table_name))
+    } else if resource_id.starts_with(&format!("{}/index/", source)) {
+        Some(format!(
+            "table/{}{}",
+            target_table_name,
+            &resource_id[source.len()..]
+        ))
+    } else {
+        None
+    }
+}
+
+/// Copies the scalable targets and scaling policies of the table `source_table_name` and its
+/// indexes to the table `target_table_name`, such as a table restored by
+/// `restore_table_and_wait`.
+///
+/// Scalable targets are registered with the same capacities and role, and the policies keep
+/// their names.
+#[cfg(feature = "autoscaling")]
+pub fn copy_autoscaling<C>(
+    client: C,
+    source_table_name: &str,
+    target_table_name: &str,
+) -> RusotoFuture<Vec<ReappliedSetting>, BackupError>
+where
+    C: ApplicationAutoScaling + Clone + Send + 'static,
+{
+    let source = source_table_name.to_owned();
+    let target = target_table_name.to_owned();
+
+    let targets_client = client.clone();
+    let scalable_targets = collect_pages(move |next_token| {
+        targets_client
+            .describe_scalable_targets(DescribeScalableTargetsRequest {
+                max_results: None,
+                next_token,
+                resource_ids: None,
+                scalable_dimension: None,
+                service_namespace: ServiceNamespace::Dynamodb,
+            })
+            .map(|output| {
+                (
+                    output.scalable_targets.unwrap_or_default(),
+                    output.next_token,
+                )
+            })
+            .map_err(|e| e.map_service(BackupError::DescribeScalableTargets))
+    });
+    let policies_client = client.clone();
+    let scaling_policies = collect_pages(move |next_token| {
+        policies_client
+            .describe_scaling_policies(DescribeScalingPoliciesRequest {
+                max_results: None,
+                next_token,
+                policy_names: None,
+                resource_id: None,
+                scalable_dimension: None,
+                service_namespace: ServiceNamespace::Dynamodb,
+            })
+            .map(|output| {
+                (
+                    output.scaling_policies.unwrap_or_default(),
+                    output.next_token,
+                )
+            })
+            .map_err(|e| e.map_service(BackupError::DescribeScalingPolicies))
+    });
+
+    let future = scalable_targets
+        .join(scaling_policies)
+        .and_then(move |(targets, policies)| {
+            let retarget = |resource_id: &str| retarget_resource_id(resource_id, &source, &target);
+            let targets = targets
+                .into_iter()
+                .filter_map(|scalable_target| {
+                    retarget(&scalable_target.resource_id)
+                        .map(|resource_id| (resource_id, scalable_target))
+                })
+                .collect::<Vec<_>>();
+            let policies = policies
+                .into_iter()
+                .filter_map(|policy| {
+                    retarget(&policy.resource_id).map(|resource_id| (resource_id, policy))
+                })
+                .collect::<Vec<_>>();
+
+            // policies can only be put on registered targets
+            let register_client = client.clone();
+            let registered =
+                stream::iter_ok(targets).and_then(move |(resource_id, scalable_target)| {
+                    let scalable_dimension = scalable_target.scalable_dimension.clone();
+                    register_client
+                        .register_scalable_target(RegisterScalableTargetRequest {
+                            max_capacity: Some(scalable_target.max_capacity),
+                            min_capacity: Some(scalable_target.min_capacity),
+                            resource_id: resource_id.clone(),
+                            role_arn: Some(scalable_target.role_arn),
+                            scalable_dimension: scalable_target.scalable_dimension,
+                            service_namespace: scalable_target.service_namespace,
+                            suspended_state: scalable_target.suspended_state,
+                        })
+                        .map_err(|e| e.map_service(BackupError::RegisterScalableTarget))
+                        .map(move |_| ReappliedSetting::ScalableTarget {
+                            resource_id,
+                            scalable_dimension,
+                        })
+                });
+            let put = stream::iter_ok(policies).and_then(move |(resource_id, policy)| {
+                let policy_name = policy.policy_name.clone();
+                client
+                    .put_scaling_policy(PutScalingPolicyRequest {
+                        policy_name: policy.policy_name,
+                        policy_type: Some(policy.policy_type),
+                        resource_id: resource_id.clone(),
+                        scalable_dimension: policy.scalable_dimension,
+                        service_namespace: policy.service_namespace,
+                        step_scaling_policy_configuration: policy.step_scaling_policy_configuration,
+                        target_tracking_scaling_policy_configuration: policy
+                            .target_tracking_scaling_policy_configuration,
+                    })
+                    .map_err(|e| e.map_service(BackupError::PutScalingPolicy))
+                    .map(move |_| ReappliedSetting::ScalingPolicy {
+                        resource_id,
+                        policy_name,
+                    })
+            });
+            registered.chain(put).collect()
+        });
+
+    RusotoFuture::from_future(future)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::SourceTableFeatureDetails;
+
+    fn tag(key: &str, value: &str) -> Tag {
+        Tag {
+            key: key.to_owned(),
+            value: value.to_owned(),
+        }
+    }
+
+    #[test]
+    fn reapplies_settings_missing_from_the_restored_table() {
+        let settings = TableSettings {
+            stream_view_type: Some(StreamViewType::NewImage),
+            time_to_live_attribute: Some("expires_at".to_owned()),
+            point_in_time_recovery: true,
+            tags: vec![tag("team", "orders")],
+        };
+        assert_eq!(
+            settings_to_reapply(&settings, &TableDescription::default()),
+            vec![
+                ReappliedSetting::Stream(StreamViewType::NewImage),
+                ReappliedSetting::TimeToLive("expires_at".to_owned()),
+                ReappliedSetting::PointInTimeRecovery,
+                ReappliedSetting::Tags(vec![tag("team", "orders")]),
+            ]
+        );
+
+        let restored = TableDescription {
+            stream_specification: Some(StreamSpecification {
+                stream_enabled: Some(true),
+                stream_view_type: Some(StreamViewType::NewImage),
+            }),
+            ..Default::default()
+        };
+        let settings = TableSettings {
+            stream_view_type: Some(StreamViewType::NewImage),
+            ..Default::default()
+        };
+        assert_eq!(settings_to_reapply(&settings, &restored), vec![]);
+        assert_eq!(
+            settings_to_reapply(&TableSettings::default(), &TableDescription::default()),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn reads_settings_recorded_in_backups() {
+        let backup = BackupDescription {
+            source_table_feature_details: Some(SourceTableFeatureDetails {
+                stream_description: Some(StreamSpecification {
+                    stream_enabled: Some(true),
+                    stream_view_type: Some(StreamViewType::KeysOnly),
+                }),
+                time_to_live_description: Some(TimeToLiveDescription {
+                    attribute_name: Some("expires_at".to_owned()),
+                    time_to_live_status: Some(TimeToLiveStatus::Disabling),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            TableSettings::from_backup(&backup),
+            TableSettings {
+                stream_view_type: Some(StreamViewType::KeysOnly),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn retargets_table_and_index_resource_ids() {
+        assert_eq!(
+            retarget_resource_id("table/orders", "orders", "restored"),
+            Some("table/restored".to_owned())
+        );
+        assert_eq!(
+            retarget_resource_id("table/orders/index/by-date", "orders", "restored"),
+            Some("table/restored/index/by-date".to_owned())
+        );
+        assert_eq!(
+            retarget_resource_id("table/orders-archive", "orders", "restored"),
+            None
+        );
+    }
+}
@@ -0,0 +1,279 @@
+//! Writing any number of items with `BatchWriteItem`.
+//!
+//! A single `BatchWriteItem` call accepts at most 25 write requests, and may leave some of them
+//! unprocessed when the table is throttled. `batch_write_all` splits the requests into batches,
+//! retries unprocessed items with exponential backoff and reports the requests which could not
+//! be written.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+use futures::future::{self, Loop};
+use futures::Future;
+use rusoto_core::waiter::sleep;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{BatchWriteItemError, BatchWriteItemInput, DynamoDb, WriteRequest};
+
+/// The maximum number of write requests accepted by a single `BatchWriteItem` call.
+pub const MAX_BATCH_WRITE_ITEMS: usize = 25;
+
+/// Controls how `batch_write_all` retries unprocessed items.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchWriteConfig {
+    /// Number of attempts made for each batch before its remaining items are reported as failed
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for every subsequent retry
+    pub base_delay: Duration,
+    /// Upper bound for the delay between two retries
+    pub max_delay: Duration,
+}
+
+impl BatchWriteConfig {
+    /// The delay to wait before the given retry, starting at 1.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(u32::max_value());
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+impl Default for BatchWriteConfig {
+    fn default() -> BatchWriteConfig {
+        BatchWriteConfig {
+            max_attempts: 8,
+            base_delay: Duration::from_millis(50),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Why a write request could not be written.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BatchWriteFailureReason {
+    /// The request was still returned in `UnprocessedItems` after the last attempt.
+    Unprocessed,
+    /// The `BatchWriteItem` call containing the request failed with this error.
+    Error(String),
+}
+
+/// A write request which could not be written.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchWriteFailure {
+    /// The table the request targets
+    pub table_name: String,
+    /// The request itself
+    pub request: WriteRequest,
+    /// Why the request failed
+    pub reason: BatchWriteFailureReason,
+}
+
+/// The result of `batch_write_all`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BatchWriteOutcome {
+    /// Number of write requests which were processed
+    pub written: usize,
+    /// Write requests which could not be written
+    pub failures: Vec<BatchWriteFailure>,
+}
+
+impl BatchWriteOutcome {
+    /// Returns true if every write request was processed.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+type TableRequest = (String, WriteRequest);
+
+struct BatchWriteState {
+    pending: VecDeque<TableRequest>,
+    batch: Vec<TableRequest>,
+    attempt: u32,
+    outcome: BatchWriteOutcome,
+}
+
+impl BatchWriteState {
+    fn fail_batch(&mut self, reason: BatchWriteFailureReason) {
+        let failures = self
+            .batch
+            .drain(..)
+            .map(|(table_name, request)| BatchWriteFailure {
+                table_name,
+                request,
+                reason: reason.clone(),
+            });
+        self.outcome.failures.extend(failures);
+        self.attempt = 0;
+    }
+}
+
+fn group_by_table(batch: &[TableRequest]) -> HashMap<String, Vec<WriteRequest>> {
+    let mut request_items: HashMap<String, Vec<WriteRequest>> = HashMap::new();
+    for (table_name, request) in batch {
+        request_items
+            .entry(table_name.clone())
+            .or_default()
+            .push(request.clone());
+    }
+    request_items
+}
+
+fn flatten(request_items: HashMap<String, Vec<WriteRequest>>) -> Vec<TableRequest> {
+    request_items
+        .into_iter()
+        .flat_map(|(table_name, requests)| {
+            requests
+                .into_iter()
+                .map(move |request| (table_name.clone(), request))
+        })
+        .collect()
+}
+
+fn is_retryable(error: &RusotoError<BatchWriteItemError>) -> bool {
+    match *error {
+        RusotoError::Service(BatchWriteItemError::InternalServerError(_))
+        | RusotoError::Service(BatchWriteItemError::ProvisionedThroughputExceeded(_))
+        | RusotoError::Service(BatchWriteItemError::RequestLimitExceeded(_))
+        | RusotoError::HttpDispatch(_) => true,
+        _ => false,
+    }
+}
+
+/// Extension methods for writing an arbitrary number of items.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::collections::HashMap;
+///
+/// use rusoto_core::Region;
+/// use rusoto_dynamodb::batch::{BatchWriteConfig, BatchWriteExt};
+/// use rusoto_dynamodb::{AttributeValue, DynamoDbClient, PutRequest, WriteRequest};
+///
+/// let client = DynamoDbClient::new(Region::UsEast1);
+/// let requests = (0..100)
+///     .map(|id| {
+///         let mut item = HashMap::new();
+///         item.insert(
+///             "id".to_owned(),
+///             AttributeValue {
+///                 n: Some(id.to_string()),
+///                 ..Default::default()
+///             },
+///         );
+///         WriteRequest {
+///             put_request: Some(PutRequest { item }),
+///             ..Default::default()
+///         }
+///     })
+///     .collect();
+///
+/// let mut request_items = HashMap::new();
+/// request_items.insert("my-table".to_owned(), requests);
+///
+/// let outcome = client
+///     .batch_write_all(request_items, BatchWriteConfig::default())
+///     .sync()
+///     .unwrap();
+/// assert!(outcome.is_complete());
+/// ```
+pub trait BatchWriteExt: DynamoDb + Clone + Send + Sized + 'static {
+    /// Writes all `request_items`, keyed by table name, in batches of at most 25 requests.
+    ///
+    /// Unprocessed items and throttled batches are retried with exponential backoff, up to
+    /// `config.max_attempts` times per batch. Requests which still can't be written, or which
+    /// were part of a batch failing with a non retryable error, are reported in the returned
+    /// `BatchWriteOutcome` rather than failing the whole future. The future must be run on a
+    /// Tokio runtime, which `sync()` provides.
+    fn batch_write_all(
+        &self,
+        request_items: HashMap<String, Vec<WriteRequest>>,
+        config: BatchWriteConfig,
+    ) -> RusotoFuture<BatchWriteOutcome, BatchWriteItemError> {
+        let client = self.clone();
+        let state = BatchWriteState {
+            pending: flatten(request_items).into(),
+            batch: Vec::new(),
+            attempt: 0,
+            outcome: BatchWriteOutcome::default(),
+        };
+
+        let future = future::loop_fn(state, move |mut state| {
+            if state.batch.is_empty() {
+                let size = state.pending.len().min(MAX_BATCH_WRITE_ITEMS);
+                state.batch = state.pending.drain(..size).collect();
+                if state.batch.is_empty() {
+                    return future::Either::A(future::ok(Loop::Break(state.outcome)));
+                }
+            }
+
+            let config = config.clone();
+            state.attempt += 1;
+            let input = BatchWriteItemInput {
+                request_items: group_by_table(&state.batch),
+                ..Default::default()
+            };
+
+            future::Either::B(client.batch_write_item(input).then(move |result| {
+                let mut retry_error = None;
+                match result {
+                    Ok(output) => {
+                        let unprocessed = flatten(output.unprocessed_items.unwrap_or_default());
+                        state.outcome.written += state.batch.len() - unprocessed.len();
+                        state.batch = unprocessed;
+                        if state.batch.is_empty() {
+                            state.attempt = 0;
+                        }
+                    }
+                    Err(ref error) if is_retryable(error) => retry_error = Some(error.to_string()),
+                    Err(error) => {
+                        state.fail_batch(BatchWriteFailureReason::Error(error.to_string()))
+                    }
+                }
+
+                if state.batch.is_empty() {
+                    return future::Either::A(future::ok(Loop::Continue(state)));
+                }
+                if state.attempt >= config.max_attempts {
+                    state.fail_batch(
+                        retry_error.map_or(BatchWriteFailureReason::Unprocessed, |error| {
+                            BatchWriteFailureReason::Error(error)
+                        }),
+                    );
+                    return future::Either::A(future::ok(Loop::Continue(state)));
+                }
+
+                let delay = config.backoff(state.attempt);
+                future::Either::B(sleep(delay).map(move |_| Loop::Continue(state)))
+            }))
+        });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: DynamoDb + Clone + Send + 'static> BatchWriteExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_max_delay() {
+        let config = BatchWriteConfig {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+        };
+
+        assert_eq!(config.backoff(1), Duration::from_millis(100));
+        assert_eq!(config.backoff(2), Duration::from_millis(200));
+        assert_eq!(config.backoff(4), Duration::from_millis(800));
+        assert_eq!(config.backoff(5), Duration::from_secs(1));
+        assert_eq!(config.backoff(64), Duration::from_secs(1));
+    }
+}
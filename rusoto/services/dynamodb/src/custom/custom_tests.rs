@@ -1,4 +1,25 @@
-use crate::generated::AttributeValue;
+extern crate rusoto_mock;
+
+use crate::custom::backup::{BackupError, BackupExt};
+use crate::custom::batch::{BatchWriteConfig, BatchWriteExt, BatchWriteFailureReason};
+use crate::custom::single_table::{
+    number_value, string_value, take_number, take_string, Entity, EntityError, IndexKeys,
+    SingleTable, SingleTableExt,
+};
+use crate::custom::stream::{Item, ItemStreamExt};
+use crate::custom::waiters::{TableWaitError, TableWaiterExt};
+use crate::generated::{
+    AttributeValue, BackupStatus, CreateTableInput, DeleteRequest, DynamoDbClient, ScanInput,
+    TableStatus, WriteRequest,
+};
+
+use self::rusoto_mock::*;
+use futures::{Future, Stream};
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::waiter::WaiterConfig;
+use rusoto_core::{Region, RusotoError};
+use std::collections::HashMap;
+use std::time::Duration;
 
 #[test]
 fn attribute_value_default_is_empty() {
@@ -46,3 +67,453 @@ fn attribute_value_with_binary_set() {
     let serialized = serde_json::to_string(&all_default).unwrap();
     assert_eq!(&serialized, r#"{"BS":["Zm9v","YmFy","YmF6"]}"#);
 }
+
+const TWO_ITEM_SCAN_PAGE: &str = r#"{
+    "Items": [{"id": {"S": "a"}}, {"id": {"S": "b"}}],
+    "Count": 2,
+    "ScannedCount": 2
+}"#;
+
+#[test]
+fn scan_stream_yields_all_items_of_last_page() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(TWO_ITEM_SCAN_PAGE);
+    let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let items = client
+        .scan_stream(ScanInput {
+            table_name: "table".to_owned(),
+            ..Default::default()
+        })
+        .collect()
+        .wait()
+        .unwrap();
+
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["id"].s, Some("a".to_owned()));
+    assert_eq!(items[1]["id"].s, Some("b".to_owned()));
+}
+
+#[test]
+fn parallel_scan_stream_scans_every_segment() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(TWO_ITEM_SCAN_PAGE)
+        .with_request_checker(|request: &SignedRequest| {
+            if let Some(SignedRequestPayload::Buffer(ref buffer)) = request.payload {
+                let body: serde_json::Value = serde_json::from_slice(buffer).unwrap();
+                assert_eq!(body["TotalSegments"], 3);
+                assert!(body["Segment"].as_i64().unwrap() < 3);
+            } else {
+                panic!("request payload is not a buffer");
+            }
+        });
+    let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let items = client
+        .parallel_scan_stream(
+            ScanInput {
+                table_name: "table".to_owned(),
+                ..Default::default()
+            },
+            3,
+        )
+        .collect()
+        .wait()
+        .unwrap();
+
+    assert_eq!(items.len(), 6);
+}
+
+const ACTIVE_TABLE: &str = r#"{
+    "Table": {
+        "TableName": "table",
+        "TableStatus": "ACTIVE",
+        "GlobalSecondaryIndexes": [{"IndexName": "by-owner", "IndexStatus": "ACTIVE"}]
+    },
+    "TableDescription": {"TableName": "table", "TableStatus": "CREATING"}
+}"#;
+
+#[test]
+fn create_table_and_wait_returns_active_table() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(ACTIVE_TABLE);
+    let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let table = client
+        .create_table_and_wait(
+            CreateTableInput {
+                table_name: "table".to_owned(),
+                ..Default::default()
+            },
+            WaiterConfig::new(Duration::from_millis(1), 3),
+        )
+        .sync()
+        .unwrap();
+
+    assert_eq!(table.table_status, Some(TableStatus::Active));
+}
+
+#[test]
+fn wait_for_table_active_times_out_while_backfilling() {
+    let body = r#"{
+        "Table": {
+            "TableName": "table",
+            "TableStatus": "ACTIVE",
+            "GlobalSecondaryIndexes": [
+                {"IndexName": "by-owner", "IndexStatus": "CREATING", "Backfilling": true}
+            ]
+        }
+    }"#;
+    let mock = MockRequestDispatcher::with_status(200).with_body(body);
+    let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let result = client
+        .wait_for_table_active("table", WaiterConfig::new(Duration::from_millis(1), 2))
+        .sync();
+
+    match result {
+        Err(RusotoError::Service(TableWaitError::TimedOut(_))) => {}
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+}
+
+fn backup_body(status: &str) -> String {
+    let details = format!(
+        r#"{{
+            "BackupArn": "arn:aws:dynamodb:us-east-1:123456789012:table/table/backup/01",
+            "BackupName": "nightly",
+            "BackupStatus": "{}",
+            "BackupType": "USER",
+            "BackupCreationDateTime": 1570000000.0
+        }}"#,
+        status
+    );
+    format!(
+        r#"{{"BackupDetails": {}, "BackupDescription": {{"BackupDetails": {}}}}}"#,
+        details, details
+    )
+}
+
+#[test]
+fn create_backup_and_wait_returns_available_backup() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(&backup_body("AVAILABLE"));
+    let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let backup = client
+        .create_backup_and_wait(
+            "table",
+            "nightly",
+            WaiterConfig::new(Duration::from_millis(1), 3),
+        )
+        .sync()
+        .unwrap();
+
+    assert_eq!(backup.backup_name, "nightly");
+    assert_eq!(backup.backup_status, BackupStatus::Available);
+}
+
+#[test]
+fn create_backup_and_wait_fails_once_the_backup_is_deleted() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(&backup_body("DELETED"));
+    let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let result = client
+        .create_backup_and_wait(
+            "table",
+            "nightly",
+            WaiterConfig::new(Duration::from_millis(1), 3),
+        )
+        .sync();
+
+    match result {
+        Err(RusotoError::Service(BackupError::BackupDeleted(_))) => {}
+        other => panic!("expected a deleted backup, got {:?}", other),
+    }
+}
+
+fn delete_requests(count: usize) -> HashMap<String, Vec<WriteRequest>> {
+    let requests = (0..count)
+        .map(|id| {
+            let mut key = HashMap::new();
+            key.insert(
+                "id".to_owned(),
+                AttributeValue {
+                    n: Some(id.to_string()),
+                    ..Default::default()
+                },
+            );
+            WriteRequest {
+                delete_request: Some(DeleteRequest { key }),
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    let mut request_items = HashMap::new();
+    request_items.insert("table".to_owned(), requests);
+    request_items
+}
+
+#[test]
+fn batch_write_all_splits_requests_into_batches() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(r#"{"UnprocessedItems": {}}"#)
+        .with_request_checker(|request: &SignedRequest| {
+            let body = match request.payload {
+                Some(SignedRequestPayload::Buffer(ref body)) => body,
+                _ => panic!("expected a buffered payload"),
+            };
+            let input: serde_json::Value = serde_json::from_slice(body).unwrap();
+            assert!(input["RequestItems"]["table"].as_array().unwrap().len() <= 25);
+        });
+    let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let outcome = client
+        .batch_write_all(delete_requests(60), BatchWriteConfig::default())
+        .sync()
+        .unwrap();
+
+    assert_eq!(outcome.written, 60);
+    assert!(outcome.is_complete());
+}
+
+#[test]
+fn batch_write_all_reports_items_left_unprocessed() {
+    let body = r#"{
+        "UnprocessedItems": {
+            "table": [{"DeleteRequest": {"Key": {"id": {"N": "0"}}}}]
+        }
+    }"#;
+    let mock = MockRequestDispatcher::with_status(200).with_body(body);
+    let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let config = BatchWriteConfig {
+        max_attempts: 3,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(1),
+    };
+
+    let outcome = client
+        .batch_write_all(delete_requests(2), config)
+        .sync()
+        .unwrap();
+
+    assert_eq!(outcome.written, 1);
+    assert_eq!(outcome.failures.len(), 1);
+    assert_eq!(outcome.failures[0].table_name, "table");
+    assert_eq!(
+        outcome.failures[0].reason,
+        BatchWriteFailureReason::Unprocessed
+    );
+}
+
+#[derive(Debug, PartialEq)]
+struct Order {
+    customer_id: String,
+    order_id: String,
+    total: i64,
+}
+
+impl Entity for Order {
+    const TYPE: &'static str = "Order";
+    const PARTITION_KEY: &'static str = "CUSTOMER#{customer_id}";
+    const SORT_KEY: &'static str = "ORDER#{order_id}";
+    const INDEXES: &'static [IndexKeys] = &[IndexKeys {
+        index_name: "GSI1",
+        partition_key: "ORDER#{order_id}",
+        sort_key: "TOTAL#{total}",
+    }];
+
+    fn to_attributes(&self) -> Item {
+        let mut item = Item::new();
+        item.insert("customer_id".to_owned(), string_value(&self.customer_id));
+        item.insert("order_id".to_owned(), string_value(&self.order_id));
+        item.insert("total".to_owned(), number_value(self.total));
+        item
+    }
+
+    fn from_attributes(mut item: Item) -> Result<Order, EntityError> {
+        Ok(Order {
+            customer_id: take_string(&mut item, "customer_id")?,
+            order_id: take_string(&mut item, "order_id")?,
+            total: take_number(&mut item, "total")?,
+        })
+    }
+}
+
+fn order() -> Order {
+    Order {
+        customer_id: "42".to_owned(),
+        order_id: "7".to_owned(),
+        total: 1250,
+    }
+}
+
+fn shop_table() -> SingleTable {
+    SingleTable::new("shop").with_index("GSI1", "GSI1PK", "GSI1SK")
+}
+
+#[test]
+fn single_table_items_carry_keys_and_type() {
+    let table = shop_table();
+    let item = table.to_item(&order()).unwrap();
+
+    assert_eq!(item["PK"].s, Some("CUSTOMER#42".to_owned()));
+    assert_eq!(item["SK"].s, Some("ORDER#7".to_owned()));
+    assert_eq!(item["GSI1PK"].s, Some("ORDER#7".to_owned()));
+    assert_eq!(item["GSI1SK"].s, Some("TOTAL#1250".to_owned()));
+    assert_eq!(item["Type"].s, Some("Order".to_owned()));
+    assert_eq!(table.entity::<Order>(item), Ok(order()));
+}
+
+#[test]
+fn single_table_rejects_items_of_other_types() {
+    let table = shop_table();
+    let mut item = table.to_item(&order()).unwrap();
+    item.insert("Type".to_owned(), string_value("Customer"));
+
+    assert_eq!(
+        table.entity::<Order>(item),
+        Err(EntityError::WrongType {
+            expected: "Order".to_owned(),
+            found: Some("Customer".to_owned()),
+        })
+    );
+    assert_eq!(
+        SingleTable::new("shop").to_item(&order()),
+        Err(EntityError::UnknownIndex("GSI1".to_owned()))
+    );
+}
+
+#[test]
+fn single_table_queries_are_scoped_to_the_entity_type() {
+    let table = shop_table();
+    let input = table
+        .query_input::<Order>(&[("customer_id", "42")])
+        .unwrap();
+
+    assert_eq!(
+        input.key_condition_expression,
+        Some("#pk = :pk AND begins_with(#sk, :sk)".to_owned())
+    );
+    assert_eq!(input.filter_expression, Some("#type = :type".to_owned()));
+    let values = input.expression_attribute_values.unwrap();
+    assert_eq!(values[":pk"].s, Some("CUSTOMER#42".to_owned()));
+    assert_eq!(values[":sk"].s, Some("ORDER#".to_owned()));
+    assert_eq!(values[":type"].s, Some("Order".to_owned()));
+
+    let input = table
+        .index_query_input::<Order>("GSI1", &[("order_id", "7")])
+        .unwrap();
+    assert_eq!(input.index_name, Some("GSI1".to_owned()));
+    assert_eq!(input.expression_attribute_names.unwrap()["#pk"], "GSI1PK");
+
+    assert_eq!(
+        table.query_input::<Order>(&[]).err(),
+        Some(EntityError::MissingKeyValue("customer_id".to_owned()))
+    );
+}
+
+#[test]
+fn query_entities_skips_key_attributes() {
+    let body = r#"{
+        "Items": [{
+            "PK": {"S": "CUSTOMER#42"},
+            "SK": {"S": "ORDER#7"},
+            "GSI1PK": {"S": "ORDER#7"},
+            "GSI1SK": {"S": "TOTAL#1250"},
+            "Type": {"S": "Order"},
+            "customer_id": {"S": "42"},
+            "order_id": {"S": "7"},
+            "total": {"N": "1250"}
+        }],
+        "Count": 1
+    }"#;
+    let mock = MockRequestDispatcher::with_status(200).with_body(body);
+    let client = DynamoDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let orders = client
+        .query_entities::<Order>(&shop_table(), &[("customer_id", "42")])
+        .collect()
+        .wait()
+        .unwrap();
+
+    assert_eq!(orders, vec![order()]);
+}
+
+#[cfg(feature = "fake")]
+#[test]
+fn fake_dynamodb_stores_and_scans_items() {
+    use crate::fake::FakeDynamoDb;
+    use crate::generated::{
+        DynamoDb, GetItemInput, KeySchemaElement, KeyType, PutItemInput, ScanError,
+    };
+
+    let dynamodb = FakeDynamoDb::new();
+    dynamodb
+        .create_table(CreateTableInput {
+            table_name: "people".to_owned(),
+            key_schema: vec![KeySchemaElement {
+                attribute_name: "id".to_owned(),
+                key_type: KeyType::Hash,
+            }],
+            ..Default::default()
+        })
+        .sync()
+        .unwrap();
+    for id in &["1", "2", "3"] {
+        let mut item = HashMap::new();
+        item.insert("id".to_owned(), string_value(*id));
+        item.insert("name".to_owned(), string_value(format!("person {}", id)));
+        dynamodb
+            .put_item(PutItemInput {
+                table_name: "people".to_owned(),
+                item,
+                ..Default::default()
+            })
+            .sync()
+            .unwrap();
+    }
+
+    let mut key = HashMap::new();
+    key.insert("id".to_owned(), string_value("2"));
+    let item = dynamodb
+        .get_item(GetItemInput {
+            table_name: "people".to_owned(),
+            key,
+            ..Default::default()
+        })
+        .sync()
+        .unwrap()
+        .item
+        .unwrap();
+    assert_eq!(item["name"], string_value("person 2"));
+
+    let first_page = dynamodb
+        .scan(ScanInput {
+            table_name: "people".to_owned(),
+            limit: Some(2),
+            ..Default::default()
+        })
+        .sync()
+        .unwrap();
+    assert_eq!(first_page.count, Some(2));
+    let second_page = dynamodb
+        .scan(ScanInput {
+            table_name: "people".to_owned(),
+            exclusive_start_key: first_page.last_evaluated_key,
+            ..Default::default()
+        })
+        .sync()
+        .unwrap();
+    assert_eq!(second_page.count, Some(1));
+    assert_eq!(second_page.last_evaluated_key, None);
+
+    match dynamodb
+        .scan(ScanInput {
+            table_name: "missing".to_owned(),
+            ..Default::default()
+        })
+        .sync()
+    {
+        Err(RusotoError::Service(ScanError::ResourceNotFound(_))) => {}
+        other => panic!("expected ResourceNotFound, got {:?}", other),
+    }
+}
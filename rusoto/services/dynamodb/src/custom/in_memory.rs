@@ -0,0 +1,298 @@
+//! A DynamoDB backend keeping tables and items in memory.
+//!
+//! `FakeDynamoDb::new()` creates a fake DynamoDB backed by an empty `InMemoryDynamoDb`, which can
+//! stand in for a `DynamoDbClient` in unit tests. Tables can be created, described, listed and
+//! deleted, and items put, read, deleted and scanned. Tables become `ACTIVE` as soon as they are
+//! created.
+//!
+//! Expressions aren't evaluated: condition expressions, filters and projections are ignored. The
+//! other operations fail with `RusotoError::Validation`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+
+use rusoto_core::RusotoError;
+
+use crate::fake::{DynamoDbBackend, FakeDynamoDb};
+use crate::generated::{
+    AttributeValue, CreateTableError, CreateTableInput, CreateTableOutput, DeleteItemError,
+    DeleteItemInput, DeleteItemOutput, DeleteTableError, DeleteTableInput, DeleteTableOutput,
+    DescribeTableError, DescribeTableInput, DescribeTableOutput, GetItemError, GetItemInput,
+    GetItemOutput, KeySchemaElement, ListTablesError, ListTablesInput, ListTablesOutput,
+    PutItemError, PutItemInput, PutItemOutput, ReturnValue, ScanError, ScanInput, ScanOutput,
+    TableDescription, TableStatus,
+};
+
+type Item = HashMap<String, AttributeValue>;
+
+/// `ListTables` returns up to 100 table names at a time.
+const LIST_TABLES_LIMIT: usize = 100;
+
+#[derive(Debug)]
+struct Table {
+    description: TableDescription,
+    key_schema: Vec<KeySchemaElement>,
+    // items by their serialized key, so they're scanned in a stable order
+    items: BTreeMap<String, Item>,
+}
+
+impl Table {
+    /// The serialized key attributes of `item`, in key schema order.
+    fn key_of<E>(&self, item: &Item) -> Result<String, RusotoError<E>> {
+        let key = self
+            .key_schema
+            .iter()
+            .map(|element| {
+                item.get(&element.attribute_name).ok_or_else(|| {
+                    RusotoError::Validation(format!(
+                        "The provided key element does not match the schema: missing {}",
+                        element.attribute_name
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(serde_json::to_string(&key).expect("attribute values are serializable"))
+    }
+
+    fn key_attributes(&self, item: &Item) -> Item {
+        self.key_schema
+            .iter()
+            .filter_map(|element| {
+                item.get(&element.attribute_name)
+                    .map(|value| (element.attribute_name.clone(), value.clone()))
+            })
+            .collect()
+    }
+
+    fn description(&self) -> TableDescription {
+        TableDescription {
+            item_count: Some(self.items.len() as i64),
+            ..self.description.clone()
+        }
+    }
+}
+
+/// Tables and their items, kept in memory.
+#[derive(Debug, Default)]
+pub struct InMemoryDynamoDb {
+    tables: Mutex<BTreeMap<String, Table>>,
+}
+
+impl InMemoryDynamoDb {
+    /// The items of a table, in a stable order.
+    pub fn items(&self, table_name: &str) -> Vec<HashMap<String, AttributeValue>> {
+        self.tables()
+            .get(table_name)
+            .map(|table| table.items.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn tables(&self) -> MutexGuard<'_, BTreeMap<String, Table>> {
+        self.tables.lock().expect("InMemoryDynamoDb lock poisoned")
+    }
+}
+
+impl FakeDynamoDb<InMemoryDynamoDb> {
+    /// Creates a fake DynamoDB without any tables.
+    pub fn new() -> FakeDynamoDb<InMemoryDynamoDb> {
+        FakeDynamoDb::with_backend(InMemoryDynamoDb::default())
+    }
+}
+
+impl Default for FakeDynamoDb<InMemoryDynamoDb> {
+    fn default() -> FakeDynamoDb<InMemoryDynamoDb> {
+        FakeDynamoDb::new()
+    }
+}
+
+fn table_not_found(table_name: &str) -> String {
+    format!(
+        "Requested resource not found: Table: {} not found",
+        table_name
+    )
+}
+
+/// The previous item, if `ALL_OLD` values were asked for.
+fn old_values(return_values: Option<&ReturnValue>, old: Option<Item>) -> Option<Item> {
+    match return_values {
+        Some(ReturnValue::AllOld) => old,
+        _ => None,
+    }
+}
+
+impl DynamoDbBackend for InMemoryDynamoDb {
+    fn create_table(
+        &self,
+        input: CreateTableInput,
+    ) -> Result<CreateTableOutput, RusotoError<CreateTableError>> {
+        let mut tables = self.tables();
+        if tables.contains_key(&input.table_name) {
+            return Err(RusotoError::Service(CreateTableError::ResourceInUse(
+                format!("Table already exists: {}", input.table_name),
+            )));
+        }
+        let description = TableDescription {
+            attribute_definitions: Some(input.attribute_definitions),
+            item_count: Some(0),
+            key_schema: Some(input.key_schema.clone()),
+            stream_specification: input.stream_specification,
+            table_arn: Some(format!(
+                "arn:aws:dynamodb:us-east-1:123456789012:table/{}",
+                input.table_name
+            )),
+            table_name: Some(input.table_name.clone()),
+            table_size_bytes: Some(0),
+            table_status: Some(TableStatus::Active),
+            ..Default::default()
+        };
+        tables.insert(
+            input.table_name,
+            Table {
+                description: description.clone(),
+                key_schema: input.key_schema,
+                items: BTreeMap::new(),
+            },
+        );
+        Ok(CreateTableOutput {
+            table_description: Some(description),
+        })
+    }
+
+    fn delete_item(
+        &self,
+        input: DeleteItemInput,
+    ) -> Result<DeleteItemOutput, RusotoError<DeleteItemError>> {
+        let mut tables = self.tables();
+        let table = tables.get_mut(&input.table_name).ok_or_else(|| {
+            RusotoError::Service(DeleteItemError::ResourceNotFound(table_not_found(
+                &input.table_name,
+            )))
+        })?;
+        let key = table.key_of(&input.key)?;
+        let old = table.items.remove(&key);
+        Ok(DeleteItemOutput {
+            attributes: old_values(input.return_values.as_ref(), old),
+            ..Default::default()
+        })
+    }
+
+    fn delete_table(
+        &self,
+        input: DeleteTableInput,
+    ) -> Result<DeleteTableOutput, RusotoError<DeleteTableError>> {
+        let table = self.tables().remove(&input.table_name).ok_or_else(|| {
+            RusotoError::Service(DeleteTableError::ResourceNotFound(table_not_found(
+                &input.table_name,
+            )))
+        })?;
+        Ok(DeleteTableOutput {
+            table_description: Some(TableDescription {
+                table_status: Some(TableStatus::Deleting),
+                ..table.description()
+            }),
+        })
+    }
+
+    fn describe_table(
+        &self,
+        input: DescribeTableInput,
+    ) -> Result<DescribeTableOutput, RusotoError<DescribeTableError>> {
+        let tables = self.tables();
+        let table = tables.get(&input.table_name).ok_or_else(|| {
+            RusotoError::Service(DescribeTableError::ResourceNotFound(table_not_found(
+                &input.table_name,
+            )))
+        })?;
+        Ok(DescribeTableOutput {
+            table: Some(table.description()),
+        })
+    }
+
+    fn get_item(&self, input: GetItemInput) -> Result<GetItemOutput, RusotoError<GetItemError>> {
+        let tables = self.tables();
+        let table = tables.get(&input.table_name).ok_or_else(|| {
+            RusotoError::Service(GetItemError::ResourceNotFound(table_not_found(
+                &input.table_name,
+            )))
+        })?;
+        let key = table.key_of(&input.key)?;
+        Ok(GetItemOutput {
+            item: table.items.get(&key).cloned(),
+            ..Default::default()
+        })
+    }
+
+    fn list_tables(
+        &self,
+        input: ListTablesInput,
+    ) -> Result<ListTablesOutput, RusotoError<ListTablesError>> {
+        let limit = input
+            .limit
+            .map_or(LIST_TABLES_LIMIT, |limit| limit.max(1) as usize)
+            .min(LIST_TABLES_LIMIT);
+        let tables = self.tables();
+        let mut names = tables
+            .keys()
+            .filter(|name| Some(*name) > input.exclusive_start_table_name.as_ref())
+            .cloned();
+        let table_names: Vec<String> = names.by_ref().take(limit).collect();
+        let last_evaluated_table_name = if names.next().is_some() {
+            table_names.last().cloned()
+        } else {
+            None
+        };
+        Ok(ListTablesOutput {
+            last_evaluated_table_name,
+            table_names: Some(table_names),
+        })
+    }
+
+    fn put_item(&self, input: PutItemInput) -> Result<PutItemOutput, RusotoError<PutItemError>> {
+        let mut tables = self.tables();
+        let table = tables.get_mut(&input.table_name).ok_or_else(|| {
+            RusotoError::Service(PutItemError::ResourceNotFound(table_not_found(
+                &input.table_name,
+            )))
+        })?;
+        let key = table.key_of(&input.item)?;
+        let old = table.items.insert(key, input.item);
+        Ok(PutItemOutput {
+            attributes: old_values(input.return_values.as_ref(), old),
+            ..Default::default()
+        })
+    }
+
+    fn scan(&self, input: ScanInput) -> Result<ScanOutput, RusotoError<ScanError>> {
+        let tables = self.tables();
+        let table = tables.get(&input.table_name).ok_or_else(|| {
+            RusotoError::Service(ScanError::ResourceNotFound(table_not_found(
+                &input.table_name,
+            )))
+        })?;
+        let start = match input.exclusive_start_key {
+            Some(ref start) => Some(table.key_of(start)?),
+            None => None,
+        };
+        let limit = input
+            .limit
+            .map_or(usize::max_value(), |limit| limit.max(1) as usize);
+
+        let mut remaining = table
+            .items
+            .iter()
+            .filter(|&(key, _)| Some(key) > start.as_ref())
+            .map(|(_, item)| item);
+        let items: Vec<Item> = remaining.by_ref().take(limit).cloned().collect();
+        let last_evaluated_key = match (remaining.next(), items.last()) {
+            (Some(_), Some(last)) => Some(table.key_attributes(last)),
+            _ => None,
+        };
+        Ok(ScanOutput {
+            count: Some(items.len() as i64),
+            scanned_count: Some(items.len() as i64),
+            items: Some(items),
+            last_evaluated_key,
+            ..Default::default()
+        })
+    }
+}
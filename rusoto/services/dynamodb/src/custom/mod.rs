@@ -1,2 +1,16 @@
+/// Point-in-time recovery, on-demand backups and restores which keep the table's settings
+pub mod backup;
+/// Writing any number of items with batched requests
+pub mod batch;
+/// An in-memory backend for `FakeDynamoDb`
+#[cfg(feature = "fake")]
+pub mod in_memory;
+/// Mapping entity types to the items of a single-table design
+pub mod single_table;
+/// Streams over the items of paginated scans and queries
+pub mod stream;
+/// Waiting for table changes, such as creation or new indexes, to complete
+pub mod waiters;
+
 #[cfg(test)]
 mod custom_tests;
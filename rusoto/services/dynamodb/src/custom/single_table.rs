@@ -0,0 +1,625 @@
+//! Storing several entity types in a single table.
+//!
+//! In a single-table design, every item has generic key attributes (`PK` and `SK` by default)
+//! whose values are built from the entity's own attributes, such as `USER#42`, and an attribute
+//! naming its entity type. `Entity` describes how an entity type maps to items, `SingleTable`
+//! describes the table and builds the requests for an entity type, and `SingleTableExt` sends
+//! them.
+//!
+//! Key templates contain placeholders like `{id}`, which are replaced with the string or number
+//! attribute of that name. Entities can also define the keys of global secondary indexes with
+//! `IndexKeys`; the attributes holding them are configured with `SingleTable::with_index`.
+//!
+//! ```rust,no_run
+//! use futures::{Future, Stream};
+//! use rusoto_core::Region;
+//! use rusoto_dynamodb::single_table::{
+//!     number_value, string_value, take_number, take_string, Entity, EntityError, IndexKeys,
+//!     SingleTable, SingleTableExt,
+//! };
+//! use rusoto_dynamodb::stream::Item;
+//! use rusoto_dynamodb::DynamoDbClient;
+//!
+//! struct Order {
+//!     customer_id: String,
+//!     order_id: String,
+//!     total: i64,
+//! }
+//!
+//! impl Entity for Order {
+//!     const TYPE: &'static str = "Order";
+//!     const PARTITION_KEY: &'static str = "CUSTOMER#{customer_id}";
+//!     const SORT_KEY: &'static str = "ORDER#{order_id}";
+//!     const INDEXES: &'static [IndexKeys] = &[IndexKeys {
+//!         index_name: "GSI1",
+//!         partition_key: "ORDER#{order_id}",
+//!         sort_key: "ORDER#{order_id}",
+//!     }];
+//!
+//!     fn to_attributes(&self) -> Item {
+//!         let mut item = Item::new();
+//!         item.insert("customer_id".to_owned(), string_value(&self.customer_id));
+//!         item.insert("order_id".to_owned(), string_value(&self.order_id));
+//!         item.insert("total".to_owned(), number_value(self.total));
+//!         item
+//!     }
+//!
+//!     fn from_attributes(mut item: Item) -> Result<Order, EntityError> {
+//!         Ok(Order {
+//!             customer_id: take_string(&mut item, "customer_id")?,
+//!             order_id: take_string(&mut item, "order_id")?,
+//!             total: take_number(&mut item, "total")?,
+//!         })
+//!     }
+//! }
+//!
+//! let client = DynamoDbClient::new(Region::UsEast1);
+//! let table = SingleTable::new("shop").with_index("GSI1", "GSI1PK", "GSI1SK");
+//!
+//! // every order of a customer
+//! let orders: Vec<Order> = client
+//!     .query_entities(&table, &[("customer_id", "42")])
+//!     .collect()
+//!     .wait()
+//!     .unwrap();
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use futures::{Future, Stream};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::custom::stream::{Item, ItemStream};
+use crate::generated::{
+    AttributeValue, DeleteItemError, DeleteItemInput, DynamoDb, GetItemError, GetItemInput,
+    PutItemError, PutItemInput, QueryError, QueryInput,
+};
+
+/// The keys of an entity in a global secondary index.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IndexKeys {
+    /// The name of the index, as passed to `SingleTable::with_index`
+    pub index_name: &'static str,
+    /// The template of the index partition key
+    pub partition_key: &'static str,
+    /// The template of the index sort key
+    pub sort_key: &'static str,
+}
+
+/// An entity type stored in a single table.
+pub trait Entity: Sized {
+    /// The name of the entity type, stored in the type attribute of its items.
+    const TYPE: &'static str;
+
+    /// The template of the partition key, such as `USER#{id}`.
+    const PARTITION_KEY: &'static str;
+
+    /// The template of the sort key, such as `PROFILE#{id}`.
+    const SORT_KEY: &'static str;
+
+    /// The keys of the entity in global secondary indexes.
+    const INDEXES: &'static [IndexKeys] = &[];
+
+    /// Returns the attributes of the entity, without the key and type attributes.
+    fn to_attributes(&self) -> Item;
+
+    /// Creates an entity from the attributes returned by `to_attributes`.
+    fn from_attributes(item: Item) -> Result<Self, EntityError>;
+}
+
+/// Why an entity could not be converted from or to an item.
+#[derive(Debug, PartialEq)]
+pub enum EntityError {
+    /// A key template names an attribute which is missing, or not a string or number.
+    MissingKeyValue(String),
+    /// The item is of another entity type.
+    WrongType {
+        /// The type of the entity the item was converted to
+        expected: String,
+        /// The type of the item, if it has one
+        found: Option<String>,
+    },
+    /// The entity has no keys for the index, or the index was not added to the table.
+    UnknownIndex(String),
+    /// The item doesn't have the attributes of the entity.
+    InvalidItem(String),
+}
+
+impl fmt::Display for EntityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EntityError::MissingKeyValue(ref name) => {
+                write!(f, "No string or number value for key attribute {}", name)
+            }
+            EntityError::WrongType {
+                ref expected,
+                ref found,
+            } => write!(
+                f,
+                "Expected an item of type {}, found {}",
+                expected,
+                found.as_ref().map_or("no type", String::as_str)
+            ),
+            EntityError::UnknownIndex(ref name) => write!(f, "Unknown index {}", name),
+            EntityError::InvalidItem(ref cause) => write!(f, "{}", cause),
+        }
+    }
+}
+
+impl Error for EntityError {
+    fn description(&self) -> &str {
+        match *self {
+            EntityError::MissingKeyValue(_) => "missing key value",
+            EntityError::WrongType { .. } => "wrong entity type",
+            EntityError::UnknownIndex(_) => "unknown index",
+            EntityError::InvalidItem(ref cause) => cause,
+        }
+    }
+}
+
+/// A global secondary index of a single table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableIndex {
+    /// The name of the index
+    pub name: String,
+    /// The attribute holding the partition key of the index
+    pub partition_key_attribute: String,
+    /// The attribute holding the sort key of the index
+    pub sort_key_attribute: String,
+}
+
+/// A table storing several entity types.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SingleTable {
+    /// The name of the table
+    pub table_name: String,
+    /// The partition key attribute, `PK` by default
+    pub partition_key_attribute: String,
+    /// The sort key attribute, `SK` by default
+    pub sort_key_attribute: String,
+    /// The attribute holding the entity type, `Type` by default
+    pub type_attribute: String,
+    /// The global secondary indexes entities can define keys for
+    pub indexes: Vec<TableIndex>,
+}
+
+impl SingleTable {
+    /// Describes the table `table_name` with the default attribute names and no indexes.
+    pub fn new(table_name: &str) -> SingleTable {
+        SingleTable {
+            table_name: table_name.to_owned(),
+            partition_key_attribute: "PK".to_owned(),
+            sort_key_attribute: "SK".to_owned(),
+            type_attribute: "Type".to_owned(),
+            indexes: Vec::new(),
+        }
+    }
+
+    /// Adds a global secondary index, whose keys are stored in the given attributes.
+    pub fn with_index(
+        mut self,
+        name: &str,
+        partition_key_attribute: &str,
+        sort_key_attribute: &str,
+    ) -> SingleTable {
+        self.indexes.push(TableIndex {
+            name: name.to_owned(),
+            partition_key_attribute: partition_key_attribute.to_owned(),
+            sort_key_attribute: sort_key_attribute.to_owned(),
+        });
+        self
+    }
+
+    /// Converts an entity to an item, adding its keys, index keys and type.
+    pub fn to_item<E: Entity>(&self, entity: &E) -> Result<Item, EntityError> {
+        let mut item = entity.to_attributes();
+        let lookup = |name: &str| item.get(name).and_then(key_value);
+        let mut keys = vec![
+            (
+                self.partition_key_attribute.clone(),
+                render(E::PARTITION_KEY, &lookup)?,
+            ),
+            (
+                self.sort_key_attribute.clone(),
+                render(E::SORT_KEY, &lookup)?,
+            ),
+        ];
+        for index_keys in E::INDEXES {
+            let index = self.index(index_keys.index_name)?;
+            keys.push((
+                index.partition_key_attribute.clone(),
+                render(index_keys.partition_key, &lookup)?,
+            ));
+            keys.push((
+                index.sort_key_attribute.clone(),
+                render(index_keys.sort_key, &lookup)?,
+            ));
+        }
+
+        for (name, value) in keys {
+            item.insert(name, string_value(value));
+        }
+        item.insert(self.type_attribute.clone(), string_value(E::TYPE));
+        Ok(item)
+    }
+
+    /// Converts an item to an entity, checking its type.
+    pub fn entity<E: Entity>(&self, mut item: Item) -> Result<E, EntityError> {
+        let found = item.remove(&self.type_attribute).and_then(|value| value.s);
+        if found.as_ref().map(String::as_str) != Some(E::TYPE) {
+            return Err(EntityError::WrongType {
+                expected: E::TYPE.to_owned(),
+                found,
+            });
+        }
+        item.remove(&self.partition_key_attribute);
+        item.remove(&self.sort_key_attribute);
+        for index in &self.indexes {
+            item.remove(&index.partition_key_attribute);
+            item.remove(&index.sort_key_attribute);
+        }
+        E::from_attributes(item)
+    }
+
+    /// Returns the primary key of the entity with the given key attribute values.
+    pub fn key<E: Entity>(&self, values: &[(&str, &str)]) -> Result<Item, EntityError> {
+        let lookup = lookup_values(values);
+        let mut key = Item::new();
+        key.insert(
+            self.partition_key_attribute.clone(),
+            string_value(render(E::PARTITION_KEY, &lookup)?),
+        );
+        key.insert(
+            self.sort_key_attribute.clone(),
+            string_value(render(E::SORT_KEY, &lookup)?),
+        );
+        Ok(key)
+    }
+
+    /// Creates a `PutItem` request writing `entity`.
+    pub fn put_item_input<E: Entity>(&self, entity: &E) -> Result<PutItemInput, EntityError> {
+        Ok(PutItemInput {
+            table_name: self.table_name.clone(),
+            item: self.to_item(entity)?,
+            ..Default::default()
+        })
+    }
+
+    /// Creates a `GetItem` request reading the entity with the given key attribute values.
+    pub fn get_item_input<E: Entity>(
+        &self,
+        values: &[(&str, &str)],
+    ) -> Result<GetItemInput, EntityError> {
+        Ok(GetItemInput {
+            table_name: self.table_name.clone(),
+            key: self.key::<E>(values)?,
+            ..Default::default()
+        })
+    }
+
+    /// Creates a `DeleteItem` request deleting the entity with the given key attribute values.
+    pub fn delete_item_input<E: Entity>(
+        &self,
+        values: &[(&str, &str)],
+    ) -> Result<DeleteItemInput, EntityError> {
+        Ok(DeleteItemInput {
+            table_name: self.table_name.clone(),
+            key: self.key::<E>(values)?,
+            ..Default::default()
+        })
+    }
+
+    /// Creates a `Query` request for the entities of type `E` in a partition.
+    ///
+    /// The values must fill in the partition key template. The sort key template is filled in
+    /// as far as the values allow and matched with `begins_with`, so an entity type whose sort
+    /// keys start with a constant prefix is matched without a filter. Items of other types are
+    /// filtered out with the type attribute.
+    pub fn query_input<E: Entity>(
+        &self,
+        values: &[(&str, &str)],
+    ) -> Result<QueryInput, EntityError> {
+        self.build_query::<E>(
+            None,
+            &self.partition_key_attribute,
+            E::PARTITION_KEY,
+            &self.sort_key_attribute,
+            E::SORT_KEY,
+            values,
+        )
+    }
+
+    /// Creates a `Query` request for the entities of type `E` in a partition of an index,
+    /// like `query_input`.
+    pub fn index_query_input<E: Entity>(
+        &self,
+        index_name: &str,
+        values: &[(&str, &str)],
+    ) -> Result<QueryInput, EntityError> {
+        let index = self.index(index_name)?;
+        let keys = E::INDEXES
+            .iter()
+            .find(|keys| keys.index_name == index_name)
+            .ok_or_else(|| EntityError::UnknownIndex(index_name.to_owned()))?;
+        self.build_query::<E>(
+            Some(index_name),
+            &index.partition_key_attribute,
+            keys.partition_key,
+            &index.sort_key_attribute,
+            keys.sort_key,
+            values,
+        )
+    }
+
+    fn build_query<E: Entity>(
+        &self,
+        index_name: Option<&str>,
+        partition_key_attribute: &str,
+        partition_key: &str,
+        sort_key_attribute: &str,
+        sort_key: &str,
+        values: &[(&str, &str)],
+    ) -> Result<QueryInput, EntityError> {
+        let lookup = lookup_values(values);
+        let partition_key = render(partition_key, &lookup)?;
+        let (sort_key_prefix, _) = render_prefix(sort_key, &lookup);
+
+        let mut names = HashMap::new();
+        names.insert("#pk".to_owned(), partition_key_attribute.to_owned());
+        names.insert("#type".to_owned(), self.type_attribute.clone());
+        let mut expression_values = HashMap::new();
+        expression_values.insert(":pk".to_owned(), string_value(partition_key));
+        expression_values.insert(":type".to_owned(), string_value(E::TYPE));
+        let mut key_condition = "#pk = :pk".to_owned();
+        if !sort_key_prefix.is_empty() {
+            names.insert("#sk".to_owned(), sort_key_attribute.to_owned());
+            expression_values.insert(":sk".to_owned(), string_value(sort_key_prefix));
+            key_condition.push_str(" AND begins_with(#sk, :sk)");
+        }
+
+        Ok(QueryInput {
+            table_name: self.table_name.clone(),
+            index_name: index_name.map(ToOwned::to_owned),
+            key_condition_expression: Some(key_condition),
+            filter_expression: Some("#type = :type".to_owned()),
+            expression_attribute_names: Some(names),
+            expression_attribute_values: Some(expression_values),
+            ..Default::default()
+        })
+    }
+
+    fn index(&self, name: &str) -> Result<&TableIndex, EntityError> {
+        self.indexes
+            .iter()
+            .find(|index| index.name == name)
+            .ok_or_else(|| EntityError::UnknownIndex(name.to_owned()))
+    }
+}
+
+/// Errors returned while reading or writing entities.
+#[derive(Debug, PartialEq)]
+pub enum SingleTableError {
+    /// Converting an entity from or to an item failed.
+    Entity(EntityError),
+    /// Writing the item failed.
+    PutItem(PutItemError),
+    /// Reading the item failed.
+    GetItem(GetItemError),
+    /// Deleting the item failed.
+    DeleteItem(DeleteItemError),
+    /// Querying the items failed.
+    Query(QueryError),
+}
+
+impl fmt::Display for SingleTableError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SingleTableError::Entity(ref cause) => write!(f, "{}", cause),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for SingleTableError {
+    fn description(&self) -> &str {
+        match *self {
+            SingleTableError::Entity(ref cause) => cause.description(),
+            SingleTableError::PutItem(ref cause) => cause.description(),
+            SingleTableError::GetItem(ref cause) => cause.description(),
+            SingleTableError::DeleteItem(ref cause) => cause.description(),
+            SingleTableError::Query(ref cause) => cause.description(),
+        }
+    }
+}
+
+fn entity_error(error: EntityError) -> RusotoError<SingleTableError> {
+    RusotoError::Service(SingleTableError::Entity(error))
+}
+
+/// Extension methods reading and writing the entities of a `SingleTable`.
+pub trait SingleTableExt: DynamoDb + Clone + Send + Sized + 'static {
+    /// Writes `entity`, replacing the item with the same key.
+    fn put_entity<E: Entity>(
+        &self,
+        table: &SingleTable,
+        entity: &E,
+    ) -> RusotoFuture<(), SingleTableError> {
+        let input = match table.put_item_input(entity) {
+            Ok(input) => input,
+            Err(e) => return RusotoFuture::from_future(futures::future::err(entity_error(e))),
+        };
+        let future = self
+            .put_item(input)
+            .map(|_| ())
+            .map_err(|e| e.map_service(SingleTableError::PutItem));
+        RusotoFuture::from_future(future)
+    }
+
+    /// Reads the entity with the given key attribute values, if it exists.
+    fn get_entity<E: Entity + Send + 'static>(
+        &self,
+        table: &SingleTable,
+        values: &[(&str, &str)],
+    ) -> RusotoFuture<Option<E>, SingleTableError> {
+        let input = match table.get_item_input::<E>(values) {
+            Ok(input) => input,
+            Err(e) => return RusotoFuture::from_future(futures::future::err(entity_error(e))),
+        };
+        let table = table.clone();
+        let future = self
+            .get_item(input)
+            .map_err(|e| e.map_service(SingleTableError::GetItem))
+            .and_then(move |output| match output.item {
+                Some(item) => table.entity(item).map(Some).map_err(entity_error),
+                None => Ok(None),
+            });
+        RusotoFuture::from_future(future)
+    }
+
+    /// Deletes the entity with the given key attribute values.
+    fn delete_entity<E: Entity>(
+        &self,
+        table: &SingleTable,
+        values: &[(&str, &str)],
+    ) -> RusotoFuture<(), SingleTableError> {
+        let input = match table.delete_item_input::<E>(values) {
+            Ok(input) => input,
+            Err(e) => return RusotoFuture::from_future(futures::future::err(entity_error(e))),
+        };
+        let future = self
+            .delete_item(input)
+            .map(|_| ())
+            .map_err(|e| e.map_service(SingleTableError::DeleteItem));
+        RusotoFuture::from_future(future)
+    }
+
+    /// Streams the entities of type `E` in a partition, as selected by
+    /// `SingleTable::query_input`.
+    fn query_entities<E: Entity + Send + 'static>(
+        &self,
+        table: &SingleTable,
+        values: &[(&str, &str)],
+    ) -> Box<dyn Stream<Item = E, Error = RusotoError<SingleTableError>> + Send> {
+        match table.query_input::<E>(values) {
+            Ok(input) => self.query_entities_with(table, input),
+            Err(e) => Box::new(futures::stream::once(Err(entity_error(e)))),
+        }
+    }
+
+    /// Streams the entities of type `E` in a partition of an index, as selected by
+    /// `SingleTable::index_query_input`.
+    fn query_index_entities<E: Entity + Send + 'static>(
+        &self,
+        table: &SingleTable,
+        index_name: &str,
+        values: &[(&str, &str)],
+    ) -> Box<dyn Stream<Item = E, Error = RusotoError<SingleTableError>> + Send> {
+        match table.index_query_input::<E>(index_name, values) {
+            Ok(input) => self.query_entities_with(table, input),
+            Err(e) => Box::new(futures::stream::once(Err(entity_error(e)))),
+        }
+    }
+
+    /// Streams the entities of type `E` returned by `input`, which may be a request created by
+    /// `SingleTable` and then refined, e.g. with a `Limit` or an additional filter.
+    fn query_entities_with<E: Entity + Send + 'static>(
+        &self,
+        table: &SingleTable,
+        input: QueryInput,
+    ) -> Box<dyn Stream<Item = E, Error = RusotoError<SingleTableError>> + Send> {
+        let table = table.clone();
+        Box::new(
+            ItemStream::new(self.clone(), input)
+                .map_err(|e| e.map_service(SingleTableError::Query))
+                .and_then(move |item| table.entity(item).map_err(entity_error)),
+        )
+    }
+}
+
+impl<T: DynamoDb + Clone + Send + 'static> SingleTableExt for T {}
+
+/// Returns a string attribute value.
+pub fn string_value<S: Into<String>>(value: S) -> AttributeValue {
+    AttributeValue {
+        s: Some(value.into()),
+        ..Default::default()
+    }
+}
+
+/// Returns a number attribute value.
+pub fn number_value<N: ToString>(value: N) -> AttributeValue {
+    AttributeValue {
+        n: Some(value.to_string()),
+        ..Default::default()
+    }
+}
+
+/// Removes the string attribute `name` from `item`, for implementing
+/// `Entity::from_attributes`.
+pub fn take_string(item: &mut Item, name: &str) -> Result<String, EntityError> {
+    item.remove(name)
+        .and_then(|value| value.s)
+        .ok_or_else(|| EntityError::InvalidItem(format!("Missing string attribute {}", name)))
+}
+
+/// Removes the number attribute `name` from `item` and parses it, for implementing
+/// `Entity::from_attributes`.
+pub fn take_number<N: FromStr>(item: &mut Item, name: &str) -> Result<N, EntityError> {
+    item.remove(name)
+        .and_then(|value| value.n)
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| EntityError::InvalidItem(format!("Missing number attribute {}", name)))
+}
+
+/// The string a key template placeholder is replaced with.
+fn key_value(value: &AttributeValue) -> Option<String> {
+    value.s.clone().or_else(|| value.n.clone())
+}
+
+fn lookup_values<'a>(values: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+    move |name| {
+        values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| (*value).to_owned())
+    }
+}
+
+/// Replaces every placeholder of `template`, failing if a value is missing.
+fn render<F: Fn(&str) -> Option<String>>(
+    template: &str,
+    lookup: &F,
+) -> Result<String, EntityError> {
+    match render_prefix(template, lookup) {
+        (rendered, None) => Ok(rendered),
+        (_, Some(missing)) => Err(EntityError::MissingKeyValue(missing.to_owned())),
+    }
+}
+
+/// Replaces the placeholders of `template` up to the first one without a value, returning the
+/// result up to there and the name of that placeholder.
+fn render_prefix<'t, F: Fn(&str) -> Option<String>>(
+    template: &'t str,
+    lookup: &F,
+) -> (String, Option<&'t str>) {
+    let mut rendered = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let end = rest[start..]
+            .find('}')
+            .map(|end| start + end)
+            .unwrap_or_else(|| rest.len());
+        let name = &rest[start + 1..end];
+        rendered.push_str(&rest[..start]);
+        match lookup(name) {
+            Some(value) => rendered.push_str(&value),
+            None => return (rendered, Some(name)),
+        }
+        rest = &rest[(end + 1).min(rest.len())..];
+    }
+    rendered.push_str(rest);
+    (rendered, None)
+}
@@ -0,0 +1,241 @@
+//! Streams over every item matched by a `Scan` or `Query`.
+//!
+//! `ItemStream` follows `LastEvaluatedKey` across pages, only requesting the next page once the
+//! items of the current one have been consumed. `ParallelScanStream` splits a scan into
+//! segments which are fetched concurrently.
+
+use std::collections::{HashMap, VecDeque};
+
+use futures::{Async, Future, Poll, Stream};
+use rusoto_core::RusotoError;
+
+use crate::generated::{AttributeValue, DynamoDb, QueryError, QueryInput, ScanError, ScanInput};
+
+/// A single DynamoDB item, keyed by attribute name.
+pub type Item = HashMap<String, AttributeValue>;
+
+type PageFuture<E> =
+    Box<dyn Future<Item = (Vec<Item>, Option<Item>), Error = RusotoError<E>> + Send>;
+
+/// A paginated request which can be driven by an `ItemStream`.
+pub trait PaginatedItems: Clone + Send + 'static {
+    /// The service error returned by the request.
+    type Error: Send + 'static;
+
+    /// Fetches a single page, returning its items and the key to continue from, if any.
+    fn fetch_page<C: DynamoDb>(&self, client: &C) -> PageFuture<Self::Error>;
+
+    /// Sets the key the next page starts from.
+    fn set_exclusive_start_key(&mut self, key: Item);
+}
+
+impl PaginatedItems for ScanInput {
+    type Error = ScanError;
+
+    fn fetch_page<C: DynamoDb>(&self, client: &C) -> PageFuture<ScanError> {
+        Box::new(
+            client
+                .scan(self.clone())
+                .map(|output| (output.items.unwrap_or_default(), output.last_evaluated_key)),
+        )
+    }
+
+    fn set_exclusive_start_key(&mut self, key: Item) {
+        self.exclusive_start_key = Some(key);
+    }
+}
+
+impl PaginatedItems for QueryInput {
+    type Error = QueryError;
+
+    fn fetch_page<C: DynamoDb>(&self, client: &C) -> PageFuture<QueryError> {
+        Box::new(
+            client
+                .query(self.clone())
+                .map(|output| (output.items.unwrap_or_default(), output.last_evaluated_key)),
+        )
+    }
+
+    fn set_exclusive_start_key(&mut self, key: Item) {
+        self.exclusive_start_key = Some(key);
+    }
+}
+
+/// A stream of all items returned by a paginated `Scan` or `Query`.
+///
+/// Pages are fetched lazily: the next page is only requested once every item of the previous
+/// one has been yielded.
+pub struct ItemStream<C, R: PaginatedItems> {
+    client: C,
+    request: Option<R>,
+    page: Option<PageFuture<R::Error>>,
+    items: VecDeque<Item>,
+}
+
+impl<C, R> ItemStream<C, R>
+where
+    C: DynamoDb,
+    R: PaginatedItems,
+{
+    /// Creates a stream starting from `request`, which may already carry an
+    /// `exclusive_start_key` to resume from.
+    pub fn new(client: C, request: R) -> ItemStream<C, R> {
+        ItemStream {
+            client,
+            request: Some(request),
+            page: None,
+            items: VecDeque::new(),
+        }
+    }
+}
+
+impl<C, R> Stream for ItemStream<C, R>
+where
+    C: DynamoDb,
+    R: PaginatedItems,
+{
+    type Item = Item;
+    type Error = RusotoError<R::Error>;
+
+    fn poll(&mut self) -> Poll<Option<Item>, RusotoError<R::Error>> {
+        loop {
+            if let Some(item) = self.items.pop_front() {
+                return Ok(Async::Ready(Some(item)));
+            }
+
+            if let Some(mut page) = self.page.take() {
+                match page.poll()? {
+                    Async::NotReady => {
+                        self.page = Some(page);
+                        return Ok(Async::NotReady);
+                    }
+                    Async::Ready((items, last_evaluated_key)) => {
+                        self.items.extend(items);
+                        match last_evaluated_key {
+                            Some(key) => {
+                                if let Some(ref mut request) = self.request {
+                                    request.set_exclusive_start_key(key);
+                                }
+                            }
+                            None => self.request = None,
+                        }
+                        continue;
+                    }
+                }
+            }
+
+            match self.request {
+                Some(ref request) => self.page = Some(request.fetch_page(&self.client)),
+                None => return Ok(Async::Ready(None)),
+            }
+        }
+    }
+}
+
+/// A stream of all items returned by a scan split into several segments.
+///
+/// Each segment is paginated independently and all segments are fetched concurrently. Items
+/// are yielded in the order they arrive, so there is no ordering guarantee between segments.
+pub struct ParallelScanStream<C> {
+    segments: Vec<ItemStream<C, ScanInput>>,
+    next: usize,
+}
+
+impl<C> ParallelScanStream<C>
+where
+    C: DynamoDb + Clone,
+{
+    /// Creates a stream scanning `request` in `total_segments` concurrent segments.
+    pub fn new(client: C, request: ScanInput, total_segments: i64) -> ParallelScanStream<C> {
+        let segments = (0..total_segments)
+            .map(|segment| {
+                let request = ScanInput {
+                    segment: Some(segment),
+                    total_segments: Some(total_segments),
+                    ..request.clone()
+                };
+                ItemStream::new(client.clone(), request)
+            })
+            .collect();
+        ParallelScanStream { segments, next: 0 }
+    }
+}
+
+impl<C> Stream for ParallelScanStream<C>
+where
+    C: DynamoDb,
+{
+    type Item = Item;
+    type Error = RusotoError<ScanError>;
+
+    fn poll(&mut self) -> Poll<Option<Item>, RusotoError<ScanError>> {
+        // poll segments round robin, starting after the one that last produced an item, so
+        // that a fast segment can't starve the others
+        let mut polled = 0;
+        while polled < self.segments.len() {
+            let index = (self.next + polled) % self.segments.len();
+            match self.segments[index].poll()? {
+                Async::Ready(Some(item)) => {
+                    self.next = index + 1;
+                    return Ok(Async::Ready(Some(item)));
+                }
+                Async::Ready(None) => {
+                    self.segments.remove(index);
+                }
+                Async::NotReady => polled += 1,
+            }
+        }
+
+        if self.segments.is_empty() {
+            Ok(Async::Ready(None))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
+
+/// Extension methods for streaming the results of scans and queries.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures::{Future, Stream};
+/// use rusoto_core::Region;
+/// use rusoto_dynamodb::stream::ItemStreamExt;
+/// use rusoto_dynamodb::{DynamoDbClient, ScanInput};
+///
+/// let client = DynamoDbClient::new(Region::UsEast1);
+/// let items = client
+///     .parallel_scan_stream(
+///         ScanInput {
+///             table_name: "my-table".to_owned(),
+///             ..Default::default()
+///         },
+///         4,
+///     )
+///     .collect()
+///     .wait()
+///     .unwrap();
+/// ```
+pub trait ItemStreamExt: DynamoDb + Clone + Sized {
+    /// Streams every item matched by `input`, following `LastEvaluatedKey` across pages.
+    fn scan_stream(&self, input: ScanInput) -> ItemStream<Self, ScanInput> {
+        ItemStream::new(self.clone(), input)
+    }
+
+    /// Streams every item matched by `input`, following `LastEvaluatedKey` across pages.
+    fn query_stream(&self, input: QueryInput) -> ItemStream<Self, QueryInput> {
+        ItemStream::new(self.clone(), input)
+    }
+
+    /// Streams every item matched by `input`, scanning `total_segments` segments concurrently.
+    fn parallel_scan_stream(
+        &self,
+        input: ScanInput,
+        total_segments: i64,
+    ) -> ParallelScanStream<Self> {
+        ParallelScanStream::new(self.clone(), input, total_segments)
+    }
+}
+
+impl<T: DynamoDb + Clone> ItemStreamExt for T {}
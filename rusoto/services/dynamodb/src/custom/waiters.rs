@@ -0,0 +1,230 @@
+//! Helpers for table operations which complete asynchronously.
+//!
+//! Creating a table, switching its billing mode or adding a global secondary index all return
+//! as soon as the change is accepted, while the table keeps `CREATING` or `UPDATING` (and a new
+//! index keeps backfilling) for a while after. The methods of `TableWaiterExt` issue the request
+//! and then poll `DescribeTable` until the table and all of its indexes are usable.
+
+use std::error::Error;
+use std::fmt;
+
+use futures::Future;
+use rusoto_core::waiter::{poll_until, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    AttributeDefinition, CreateGlobalSecondaryIndexAction, CreateTableError, CreateTableInput,
+    DescribeTableError, DescribeTableInput, DynamoDb, GlobalSecondaryIndexUpdate, IndexStatus,
+    ProvisionedThroughput, TableDescription, TableStatus, UpdateGlobalSecondaryIndexAction,
+    UpdateTableError, UpdateTableInput,
+};
+
+/// Errors returned while changing a table and waiting for the change to complete.
+#[derive(Debug, PartialEq)]
+pub enum TableWaitError {
+    /// Creating the table failed.
+    CreateTable(CreateTableError),
+    /// Updating the table failed.
+    UpdateTable(UpdateTableError),
+    /// Describing the table while waiting failed.
+    DescribeTable(DescribeTableError),
+    /// The table did not become active within the configured number of attempts.
+    TimedOut(String),
+}
+
+impl fmt::Display for TableWaitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for TableWaitError {
+    fn description(&self) -> &str {
+        match *self {
+            TableWaitError::CreateTable(ref cause) => cause.description(),
+            TableWaitError::UpdateTable(ref cause) => cause.description(),
+            TableWaitError::DescribeTable(ref cause) => cause.description(),
+            TableWaitError::TimedOut(ref cause) => cause,
+        }
+    }
+}
+
+/// The billing mode a table is switched to by `set_billing_mode_and_wait`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BillingMode {
+    /// On-demand capacity, billed per request.
+    PayPerRequest,
+    /// Provisioned capacity. The throughput is applied to the table and to each of its global
+    /// secondary indexes.
+    Provisioned(ProvisionedThroughput),
+}
+
+/// Returns true once the table is `ACTIVE` and all of its global secondary indexes are `ACTIVE`
+/// and done backfilling.
+pub fn is_table_active(table: &TableDescription) -> bool {
+    table.table_status == Some(TableStatus::Active)
+        && table
+            .global_secondary_indexes
+            .iter()
+            .flatten()
+            .all(|index| {
+                index.index_status == Some(IndexStatus::Active)
+                    && index.backfilling != Some(true)
+            })
+}
+
+/// Extension methods which wait for table changes to complete.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::waiter::WaiterConfig;
+/// use rusoto_core::Region;
+/// use rusoto_dynamodb::waiters::{BillingMode, TableWaiterExt};
+/// use rusoto_dynamodb::DynamoDbClient;
+///
+/// let client = DynamoDbClient::new(Region::UsEast1);
+/// let table = client
+///     .set_billing_mode_and_wait("my-table", BillingMode::PayPerRequest, WaiterConfig::default())
+///     .sync()
+///     .unwrap();
+/// ```
+pub trait TableWaiterExt: DynamoDb + Clone + Send + Sized + 'static {
+    /// Polls `DescribeTable` until the table and all of its global secondary indexes are active.
+    fn wait_for_table_active(
+        &self,
+        table_name: &str,
+        config: WaiterConfig,
+    ) -> RusotoFuture<TableDescription, TableWaitError> {
+        let client = self.clone();
+        let table_name = table_name.to_owned();
+        let timeout = RusotoError::Service(TableWaitError::TimedOut(format!(
+            "Table {} did not become active",
+            table_name
+        )));
+
+        let future = poll_until(
+            config,
+            move || {
+                client
+                    .describe_table(DescribeTableInput {
+                        table_name: table_name.clone(),
+                    })
+                    .map(|output| output.table.filter(is_table_active))
+                    .map_err(|e| e.map_service(TableWaitError::DescribeTable))
+            },
+            timeout,
+        );
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Creates a table, then waits until it and all of its global secondary indexes are active.
+    fn create_table_and_wait(
+        &self,
+        input: CreateTableInput,
+        config: WaiterConfig,
+    ) -> RusotoFuture<TableDescription, TableWaitError> {
+        let client = self.clone();
+        let table_name = input.table_name.clone();
+
+        let future = self
+            .create_table(input)
+            .map_err(|e| e.map_service(TableWaitError::CreateTable))
+            .and_then(move |_| client.wait_for_table_active(&table_name, config));
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Switches the billing mode of a table, then waits until the table is active again.
+    ///
+    /// When switching to provisioned capacity, the same throughput is applied to every existing
+    /// global secondary index, as required by `UpdateTable`.
+    fn set_billing_mode_and_wait(
+        &self,
+        table_name: &str,
+        billing_mode: BillingMode,
+        config: WaiterConfig,
+    ) -> RusotoFuture<TableDescription, TableWaitError> {
+        let update_client = self.clone();
+        let wait_client = self.clone();
+        let table_name = table_name.to_owned();
+
+        let future = self
+            .describe_table(DescribeTableInput {
+                table_name: table_name.clone(),
+            })
+            .map_err(|e| e.map_service(TableWaitError::DescribeTable))
+            .and_then(move |output| {
+                let mut input = UpdateTableInput {
+                    table_name: table_name.clone(),
+                    ..Default::default()
+                };
+                match billing_mode {
+                    BillingMode::PayPerRequest => {
+                        input.billing_mode = Some(crate::generated::BillingMode::PayPerRequest);
+                    }
+                    BillingMode::Provisioned(throughput) => {
+                        let index_updates: Vec<GlobalSecondaryIndexUpdate> = output
+                            .table
+                            .and_then(|table| table.global_secondary_indexes)
+                            .unwrap_or_default()
+                            .into_iter()
+                            .filter_map(|index| index.index_name)
+                            .map(|index_name| GlobalSecondaryIndexUpdate {
+                                update: Some(UpdateGlobalSecondaryIndexAction {
+                                    index_name,
+                                    provisioned_throughput: throughput.clone(),
+                                }),
+                                ..Default::default()
+                            })
+                            .collect();
+                        input.billing_mode = Some(crate::generated::BillingMode::Provisioned);
+                        input.provisioned_throughput = Some(throughput);
+                        if !index_updates.is_empty() {
+                            input.global_secondary_index_updates = Some(index_updates);
+                        }
+                    }
+                }
+                update_client
+                    .update_table(input)
+                    .map_err(|e| e.map_service(TableWaitError::UpdateTable))
+                    .and_then(move |_| wait_client.wait_for_table_active(&table_name, config))
+            });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Adds a global secondary index to a table, then waits until the index has finished
+    /// backfilling and is active.
+    ///
+    /// `attribute_definitions` must describe every attribute used by the key schema of the new
+    /// index.
+    fn add_global_secondary_index_and_wait(
+        &self,
+        table_name: &str,
+        attribute_definitions: Vec<AttributeDefinition>,
+        index: CreateGlobalSecondaryIndexAction,
+        config: WaiterConfig,
+    ) -> RusotoFuture<TableDescription, TableWaitError> {
+        let client = self.clone();
+        let table_name = table_name.to_owned();
+
+        let future = self
+            .update_table(UpdateTableInput {
+                table_name: table_name.clone(),
+                attribute_definitions: Some(attribute_definitions),
+                global_secondary_index_updates: Some(vec![GlobalSecondaryIndexUpdate {
+                    create: Some(index),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            })
+            .map_err(|e| e.map_service(TableWaitError::UpdateTable))
+            .and_then(move |_| client.wait_for_table_active(&table_name, config));
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: DynamoDb + Clone + Send + 'static> TableWaiterExt for T {}
@@ -0,0 +1,506 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! An in-process fake of the DynamoDB API for unit tests.
+//!
+//! `FakeDynamoDb` implements `DynamoDb` by calling a `DynamoDbBackend`, which holds the
+//! state of the fake. Backend methods fail with `RusotoError::Validation` unless they are
+//! implemented, so a backend only needs to implement the operations a test calls.
+
+use std::sync::Arc;
+
+use futures::future;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::*;
+
+/// The error returned by operations a backend doesn't implement.
+pub fn unsupported_operation<E>(operation: &str) -> RusotoError<E> {
+    RusotoError::Validation(format!("{} is not supported by this fake", operation))
+}
+
+/// The state and behaviour of a `FakeDynamoDb`.
+pub trait DynamoDbBackend: Send + Sync {
+    fn batch_get_item(
+        &self,
+        _input: BatchGetItemInput,
+    ) -> Result<BatchGetItemOutput, RusotoError<BatchGetItemError>> {
+        Err(unsupported_operation("BatchGetItem"))
+    }
+    fn batch_write_item(
+        &self,
+        _input: BatchWriteItemInput,
+    ) -> Result<BatchWriteItemOutput, RusotoError<BatchWriteItemError>> {
+        Err(unsupported_operation("BatchWriteItem"))
+    }
+    fn create_backup(
+        &self,
+        _input: CreateBackupInput,
+    ) -> Result<CreateBackupOutput, RusotoError<CreateBackupError>> {
+        Err(unsupported_operation("CreateBackup"))
+    }
+    fn create_global_table(
+        &self,
+        _input: CreateGlobalTableInput,
+    ) -> Result<CreateGlobalTableOutput, RusotoError<CreateGlobalTableError>> {
+        Err(unsupported_operation("CreateGlobalTable"))
+    }
+    fn create_table(
+        &self,
+        _input: CreateTableInput,
+    ) -> Result<CreateTableOutput, RusotoError<CreateTableError>> {
+        Err(unsupported_operation("CreateTable"))
+    }
+    fn delete_backup(
+        &self,
+        _input: DeleteBackupInput,
+    ) -> Result<DeleteBackupOutput, RusotoError<DeleteBackupError>> {
+        Err(unsupported_operation("DeleteBackup"))
+    }
+    fn delete_item(
+        &self,
+        _input: DeleteItemInput,
+    ) -> Result<DeleteItemOutput, RusotoError<DeleteItemError>> {
+        Err(unsupported_operation("DeleteItem"))
+    }
+    fn delete_table(
+        &self,
+        _input: DeleteTableInput,
+    ) -> Result<DeleteTableOutput, RusotoError<DeleteTableError>> {
+        Err(unsupported_operation("DeleteTable"))
+    }
+    fn describe_backup(
+        &self,
+        _input: DescribeBackupInput,
+    ) -> Result<DescribeBackupOutput, RusotoError<DescribeBackupError>> {
+        Err(unsupported_operation("DescribeBackup"))
+    }
+    fn describe_continuous_backups(
+        &self,
+        _input: DescribeContinuousBackupsInput,
+    ) -> Result<DescribeContinuousBackupsOutput, RusotoError<DescribeContinuousBackupsError>> {
+        Err(unsupported_operation("DescribeContinuousBackups"))
+    }
+    fn describe_endpoints(
+        &self,
+    ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
+        Err(unsupported_operation("DescribeEndpoints"))
+    }
+    fn describe_global_table(
+        &self,
+        _input: DescribeGlobalTableInput,
+    ) -> Result<DescribeGlobalTableOutput, RusotoError<DescribeGlobalTableError>> {
+        Err(unsupported_operation("DescribeGlobalTable"))
+    }
+    fn describe_global_table_settings(
+        &self,
+        _input: DescribeGlobalTableSettingsInput,
+    ) -> Result<DescribeGlobalTableSettingsOutput, RusotoError<DescribeGlobalTableSettingsError>>
+    {
+        Err(unsupported_operation("DescribeGlobalTableSettings"))
+    }
+    fn describe_limits(&self) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
+        Err(unsupported_operation("DescribeLimits"))
+    }
+    fn describe_table(
+        &self,
+        _input: DescribeTableInput,
+    ) -> Result<DescribeTableOutput, RusotoError<DescribeTableError>> {
+        Err(unsupported_operation("DescribeTable"))
+    }
+    fn describe_time_to_live(
+        &self,
+        _input: DescribeTimeToLiveInput,
+    ) -> Result<DescribeTimeToLiveOutput, RusotoError<DescribeTimeToLiveError>> {
+        Err(unsupported_operation("DescribeTimeToLive"))
+    }
+    fn get_item(&self, _input: GetItemInput) -> Result<GetItemOutput, RusotoError<GetItemError>> {
+        Err(unsupported_operation("GetItem"))
+    }
+    fn list_backups(
+        &self,
+        _input: ListBackupsInput,
+    ) -> Result<ListBackupsOutput, RusotoError<ListBackupsError>> {
+        Err(unsupported_operation("ListBackups"))
+    }
+    fn list_global_tables(
+        &self,
+        _input: ListGlobalTablesInput,
+    ) -> Result<ListGlobalTablesOutput, RusotoError<ListGlobalTablesError>> {
+        Err(unsupported_operation("ListGlobalTables"))
+    }
+    fn list_tables(
+        &self,
+        _input: ListTablesInput,
+    ) -> Result<ListTablesOutput, RusotoError<ListTablesError>> {
+        Err(unsupported_operation("ListTables"))
+    }
+    fn list_tags_of_resource(
+        &self,
+        _input: ListTagsOfResourceInput,
+    ) -> Result<ListTagsOfResourceOutput, RusotoError<ListTagsOfResourceError>> {
+        Err(unsupported_operation("ListTagsOfResource"))
+    }
+    fn put_item(&self, _input: PutItemInput) -> Result<PutItemOutput, RusotoError<PutItemError>> {
+        Err(unsupported_operation("PutItem"))
+    }
+    fn query(&self, _input: QueryInput) -> Result<QueryOutput, RusotoError<QueryError>> {
+        Err(unsupported_operation("Query"))
+    }
+    fn restore_table_from_backup(
+        &self,
+        _input: RestoreTableFromBackupInput,
+    ) -> Result<RestoreTableFromBackupOutput, RusotoError<RestoreTableFromBackupError>> {
+        Err(unsupported_operation("RestoreTableFromBackup"))
+    }
+    fn restore_table_to_point_in_time(
+        &self,
+        _input: RestoreTableToPointInTimeInput,
+    ) -> Result<RestoreTableToPointInTimeOutput, RusotoError<RestoreTableToPointInTimeError>> {
+        Err(unsupported_operation("RestoreTableToPointInTime"))
+    }
+    fn scan(&self, _input: ScanInput) -> Result<ScanOutput, RusotoError<ScanError>> {
+        Err(unsupported_operation("Scan"))
+    }
+    fn tag_resource(&self, _input: TagResourceInput) -> Result<(), RusotoError<TagResourceError>> {
+        Err(unsupported_operation("TagResource"))
+    }
+    fn transact_get_items(
+        &self,
+        _input: TransactGetItemsInput,
+    ) -> Result<TransactGetItemsOutput, RusotoError<TransactGetItemsError>> {
+        Err(unsupported_operation("TransactGetItems"))
+    }
+    fn transact_write_items(
+        &self,
+        _input: TransactWriteItemsInput,
+    ) -> Result<TransactWriteItemsOutput, RusotoError<TransactWriteItemsError>> {
+        Err(unsupported_operation("TransactWriteItems"))
+    }
+    fn untag_resource(
+        &self,
+        _input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        Err(unsupported_operation("UntagResource"))
+    }
+    fn update_continuous_backups(
+        &self,
+        _input: UpdateContinuousBackupsInput,
+    ) -> Result<UpdateContinuousBackupsOutput, RusotoError<UpdateContinuousBackupsError>> {
+        Err(unsupported_operation("UpdateContinuousBackups"))
+    }
+    fn update_global_table(
+        &self,
+        _input: UpdateGlobalTableInput,
+    ) -> Result<UpdateGlobalTableOutput, RusotoError<UpdateGlobalTableError>> {
+        Err(unsupported_operation("UpdateGlobalTable"))
+    }
+    fn update_global_table_settings(
+        &self,
+        _input: UpdateGlobalTableSettingsInput,
+    ) -> Result<UpdateGlobalTableSettingsOutput, RusotoError<UpdateGlobalTableSettingsError>> {
+        Err(unsupported_operation("UpdateGlobalTableSettings"))
+    }
+    fn update_item(
+        &self,
+        _input: UpdateItemInput,
+    ) -> Result<UpdateItemOutput, RusotoError<UpdateItemError>> {
+        Err(unsupported_operation("UpdateItem"))
+    }
+    fn update_table(
+        &self,
+        _input: UpdateTableInput,
+    ) -> Result<UpdateTableOutput, RusotoError<UpdateTableError>> {
+        Err(unsupported_operation("UpdateTable"))
+    }
+    fn update_time_to_live(
+        &self,
+        _input: UpdateTimeToLiveInput,
+    ) -> Result<UpdateTimeToLiveOutput, RusotoError<UpdateTimeToLiveError>> {
+        Err(unsupported_operation("UpdateTimeToLive"))
+    }
+}
+
+/// An implementation of `DynamoDb` calling a `DynamoDbBackend` instead of AWS.
+///
+/// Operations are run by the backend when the returned futures are polled. Clones of the fake
+/// share the same backend.
+pub struct FakeDynamoDb<B> {
+    backend: Arc<B>,
+}
+
+impl<B> Clone for FakeDynamoDb<B> {
+    fn clone(&self) -> Self {
+        FakeDynamoDb {
+            backend: self.backend.clone(),
+        }
+    }
+}
+
+impl<B: DynamoDbBackend> FakeDynamoDb<B> {
+    /// Creates a fake calling `backend`.
+    pub fn with_backend(backend: B) -> FakeDynamoDb<B> {
+        FakeDynamoDb {
+            backend: Arc::new(backend),
+        }
+    }
+
+    /// The backend of the fake, to set up or inspect its state.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+}
+
+impl<B: DynamoDbBackend + 'static> DynamoDb for FakeDynamoDb<B> {
+    fn batch_get_item(
+        &self,
+        input: BatchGetItemInput,
+    ) -> RusotoFuture<BatchGetItemOutput, BatchGetItemError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.batch_get_item(input)))
+    }
+    fn batch_write_item(
+        &self,
+        input: BatchWriteItemInput,
+    ) -> RusotoFuture<BatchWriteItemOutput, BatchWriteItemError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.batch_write_item(input)))
+    }
+    fn create_backup(
+        &self,
+        input: CreateBackupInput,
+    ) -> RusotoFuture<CreateBackupOutput, CreateBackupError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.create_backup(input)))
+    }
+    fn create_global_table(
+        &self,
+        input: CreateGlobalTableInput,
+    ) -> RusotoFuture<CreateGlobalTableOutput, CreateGlobalTableError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.create_global_table(input)))
+    }
+    fn create_table(
+        &self,
+        input: CreateTableInput,
+    ) -> RusotoFuture<CreateTableOutput, CreateTableError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.create_table(input)))
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupInput,
+    ) -> RusotoFuture<DeleteBackupOutput, DeleteBackupError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_backup(input)))
+    }
+    fn delete_item(
+        &self,
+        input: DeleteItemInput,
+    ) -> RusotoFuture<DeleteItemOutput, DeleteItemError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_item(input)))
+    }
+    fn delete_table(
+        &self,
+        input: DeleteTableInput,
+    ) -> RusotoFuture<DeleteTableOutput, DeleteTableError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.delete_table(input)))
+    }
+    fn describe_backup(
+        &self,
+        input: DescribeBackupInput,
+    ) -> RusotoFuture<DescribeBackupOutput, DescribeBackupError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.describe_backup(input)))
+    }
+    fn describe_continuous_backups(
+        &self,
+        input: DescribeContinuousBackupsInput,
+    ) -> RusotoFuture<DescribeContinuousBackupsOutput, DescribeContinuousBackupsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.describe_continuous_backups(input)
+        }))
+    }
+    fn describe_endpoints(
+        &self,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.describe_endpoints()))
+    }
+    fn describe_global_table(
+        &self,
+        input: DescribeGlobalTableInput,
+    ) -> RusotoFuture<DescribeGlobalTableOutput, DescribeGlobalTableError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.describe_global_table(input)))
+    }
+    fn describe_global_table_settings(
+        &self,
+        input: DescribeGlobalTableSettingsInput,
+    ) -> RusotoFuture<DescribeGlobalTableSettingsOutput, DescribeGlobalTableSettingsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.describe_global_table_settings(input)
+        }))
+    }
+    fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.describe_limits()))
+    }
+    fn describe_table(
+        &self,
+        input: DescribeTableInput,
+    ) -> RusotoFuture<DescribeTableOutput, DescribeTableError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.describe_table(input)))
+    }
+    fn describe_time_to_live(
+        &self,
+        input: DescribeTimeToLiveInput,
+    ) -> RusotoFuture<DescribeTimeToLiveOutput, DescribeTimeToLiveError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.describe_time_to_live(input)))
+    }
+    fn get_item(&self, input: GetItemInput) -> RusotoFuture<GetItemOutput, GetItemError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.get_item(input)))
+    }
+    fn list_backups(
+        &self,
+        input: ListBackupsInput,
+    ) -> RusotoFuture<ListBackupsOutput, ListBackupsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_backups(input)))
+    }
+    fn list_global_tables(
+        &self,
+        input: ListGlobalTablesInput,
+    ) -> RusotoFuture<ListGlobalTablesOutput, ListGlobalTablesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_global_tables(input)))
+    }
+    fn list_tables(
+        &self,
+        input: ListTablesInput,
+    ) -> RusotoFuture<ListTablesOutput, ListTablesError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_tables(input)))
+    }
+    fn list_tags_of_resource(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> RusotoFuture<ListTagsOfResourceOutput, ListTagsOfResourceError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.list_tags_of_resource(input)))
+    }
+    fn put_item(&self, input: PutItemInput) -> RusotoFuture<PutItemOutput, PutItemError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.put_item(input)))
+    }
+    fn query(&self, input: QueryInput) -> RusotoFuture<QueryOutput, QueryError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.query(input)))
+    }
+    fn restore_table_from_backup(
+        &self,
+        input: RestoreTableFromBackupInput,
+    ) -> RusotoFuture<RestoreTableFromBackupOutput, RestoreTableFromBackupError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.restore_table_from_backup(input)
+        }))
+    }
+    fn restore_table_to_point_in_time(
+        &self,
+        input: RestoreTableToPointInTimeInput,
+    ) -> RusotoFuture<RestoreTableToPointInTimeOutput, RestoreTableToPointInTimeError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.restore_table_to_point_in_time(input)
+        }))
+    }
+    fn scan(&self, input: ScanInput) -> RusotoFuture<ScanOutput, ScanError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.scan(input)))
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.tag_resource(input)))
+    }
+    fn transact_get_items(
+        &self,
+        input: TransactGetItemsInput,
+    ) -> RusotoFuture<TransactGetItemsOutput, TransactGetItemsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.transact_get_items(input)))
+    }
+    fn transact_write_items(
+        &self,
+        input: TransactWriteItemsInput,
+    ) -> RusotoFuture<TransactWriteItemsOutput, TransactWriteItemsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.transact_write_items(input)))
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.untag_resource(input)))
+    }
+    fn update_continuous_backups(
+        &self,
+        input: UpdateContinuousBackupsInput,
+    ) -> RusotoFuture<UpdateContinuousBackupsOutput, UpdateContinuousBackupsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.update_continuous_backups(input)
+        }))
+    }
+    fn update_global_table(
+        &self,
+        input: UpdateGlobalTableInput,
+    ) -> RusotoFuture<UpdateGlobalTableOutput, UpdateGlobalTableError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.update_global_table(input)))
+    }
+    fn update_global_table_settings(
+        &self,
+        input: UpdateGlobalTableSettingsInput,
+    ) -> RusotoFuture<UpdateGlobalTableSettingsOutput, UpdateGlobalTableSettingsError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || {
+            backend.update_global_table_settings(input)
+        }))
+    }
+    fn update_item(
+        &self,
+        input: UpdateItemInput,
+    ) -> RusotoFuture<UpdateItemOutput, UpdateItemError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.update_item(input)))
+    }
+    fn update_table(
+        &self,
+        input: UpdateTableInput,
+    ) -> RusotoFuture<UpdateTableOutput, UpdateTableError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.update_table(input)))
+    }
+    fn update_time_to_live(
+        &self,
+        input: UpdateTimeToLiveInput,
+    ) -> RusotoFuture<UpdateTimeToLiveOutput, UpdateTimeToLiveError> {
+        let backend = self.backend.clone();
+        RusotoFuture::from_future(future::lazy(move || backend.update_time_to_live(input)))
+    }
+}
@@ -22,16 +22,93 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p><p>Specifies how to perform the update. Valid values are <code>PUT</code> (default), <code>DELETE</code>, and <code>ADD</code>. The behavior depends on whether the specified primary key already exists in the table.</p> <p> <b>If an item with the specified <i>Key</i> is found in the table:</b> </p> <ul> <li> <p> <code>PUT</code> - Adds the specified attribute to the item. If the attribute already exists, it is replaced by the new value. </p> </li> <li> <p> <code>DELETE</code> - If no value is specified, the attribute and its value are removed from the item. The data type of the specified value must match the existing value&#39;s data type.</p> <p>If a <i>set</i> of values is specified, then those values are subtracted from the old set. For example, if the attribute value was the set <code>[a,b,c]</code> and the <code>DELETE</code> action specified <code>[a,c]</code>, then the final attribute value would be <code>[b]</code>. Specifying an empty set is an error.</p> </li> <li> <p> <code>ADD</code> - If the attribute does not already exist, then the attribute and its values are added to the item. If the attribute does exist, then the behavior of <code>ADD</code> depends on the data type of the attribute:</p> <ul> <li> <p>If the existing attribute is a number, and if <code>Value</code> is also a number, then the <code>Value</code> is mathematically added to the existing attribute. If <code>Value</code> is a negative number, then it is subtracted from the existing attribute.</p> <note> <p> If you use <code>ADD</code> to increment or decrement a number value for an item that doesn&#39;t exist before the update, DynamoDB uses 0 as the initial value.</p> <p>In addition, if you use <code>ADD</code> to update an existing item, and intend to increment or decrement an attribute value which does not yet exist, DynamoDB uses <code>0</code> as the initial value. For example, suppose that the item you want to update does not yet have an attribute named <i>itemcount</i>, but you decide to <code>ADD</code> the number <code>3</code> to this attribute anyway, even though it currently does not exist. DynamoDB will create the <i>itemcount</i> attribute, set its initial value to <code>0</code>, and finally add <code>3</code> to it. The result will be a new <i>itemcount</i> attribute in the item, with a value of <code>3</code>.</p> </note> </li> <li> <p>If the existing data type is a set, and if the <code>Value</code> is also a set, then the <code>Value</code> is added to the existing set. (This is a <i>set</i> operation, not mathematical addition.) For example, if the attribute value was the set <code>[1,2]</code>, and the <code>ADD</code> action specified <code>[3]</code>, then the final attribute value would be <code>[1,2,3]</code>. An error occurs if an Add action is specified for a set attribute and the attribute type specified does not match the existing set type. </p> <p>Both sets must have the same primitive data type. For example, if the existing data type is a set of strings, the <code>Value</code> must also be a set of strings. The same holds true for number sets and binary sets.</p> </li> </ul> <p>This action is only valid for an existing attribute whose data type is number or is a set. Do not use <code>ADD</code> for any other data types.</p> </li> </ul> <p> <b>If no item with the specified <i>Key</i> is found:</b> </p> <ul> <li> <p> <code>PUT</code> - DynamoDB creates a new item with the specified primary key, and then adds the attribute. </p> </li> <li> <p> <code>DELETE</code> - Nothing happens; there is no attribute to delete.</p> </li> <li> <p> <code>ADD</code> - DynamoDB creates an item with the supplied primary key and number (or set of numbers) for the attribute value. The only data types allowed are number and number set; no other data types can be specified.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AttributeAction {
+    /// <p><code>ADD</code> - If the attribute does not already exist, then the attribute and its values are added to the item. If the attribute does exist, then the behavior of <code>ADD</code> depends on the data type of the attribute:</p>
+    Add,
+    /// <p><code>PUT</code> - Adds the specified attribute to the item. If the attribute already exists, it is replaced by the new value.</p>
+    Put,
+    /// <p><code>DELETE</code> - If no value is specified, the attribute and its value are removed from the item. The data type of the specified value must match the existing value&#39;s data type.</p>
+    Delete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AttributeAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AttributeAction::Add => "ADD",
+            AttributeAction::Put => "PUT",
+            AttributeAction::Delete => "DELETE",
+            AttributeAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AttributeAction {
+    fn from(value: &'a str) -> AttributeAction {
+        match value {
+            "ADD" => AttributeAction::Add,
+            "PUT" => AttributeAction::Put,
+            "DELETE" => AttributeAction::Delete,
+            _ => AttributeAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AttributeAction {
+    fn from(value: String) -> AttributeAction {
+        AttributeAction::from(value.as_str())
+    }
+}
+
+impl From<AttributeAction> for String {
+    fn from(value: AttributeAction) -> String {
+        match value {
+            AttributeAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AttributeAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AttributeAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AttributeAction {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AttributeAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AttributeAction::from(value))
+    }
+}
+
 /// <p>Represents an attribute for describing the key schema for the table and indexes.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AttributeDefinition {
     /// <p>A name for the attribute.</p>
     #[serde(rename = "AttributeName")]
     pub attribute_name: String,
     /// <p><p>The data type for the attribute, where:</p> <ul> <li> <p> <code>S</code> - the attribute is of type String</p> </li> <li> <p> <code>N</code> - the attribute is of type Number</p> </li> <li> <p> <code>B</code> - the attribute is of type Binary</p> </li> </ul></p>
     #[serde(rename = "AttributeType")]
-    pub attribute_type: String,
+    pub attribute_type: ScalarAttributeType,
 }
 
 /// <p>Represents the data for an attribute.</p> <p>Each attribute value is described as a name-value pair. The name is the data type, and the value is the data itself.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.NamingRulesDataTypes.html#HowItWorks.DataTypes">Data Types</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
@@ -95,7 +172,7 @@ pub struct AttributeValueUpdate {
     /// <p><p>Specifies how to perform the update. Valid values are <code>PUT</code> (default), <code>DELETE</code>, and <code>ADD</code>. The behavior depends on whether the specified primary key already exists in the table.</p> <p> <b>If an item with the specified <i>Key</i> is found in the table:</b> </p> <ul> <li> <p> <code>PUT</code> - Adds the specified attribute to the item. If the attribute already exists, it is replaced by the new value. </p> </li> <li> <p> <code>DELETE</code> - If no value is specified, the attribute and its value are removed from the item. The data type of the specified value must match the existing value&#39;s data type.</p> <p>If a <i>set</i> of values is specified, then those values are subtracted from the old set. For example, if the attribute value was the set <code>[a,b,c]</code> and the <code>DELETE</code> action specified <code>[a,c]</code>, then the final attribute value would be <code>[b]</code>. Specifying an empty set is an error.</p> </li> <li> <p> <code>ADD</code> - If the attribute does not already exist, then the attribute and its values are added to the item. If the attribute does exist, then the behavior of <code>ADD</code> depends on the data type of the attribute:</p> <ul> <li> <p>If the existing attribute is a number, and if <code>Value</code> is also a number, then the <code>Value</code> is mathematically added to the existing attribute. If <code>Value</code> is a negative number, then it is subtracted from the existing attribute.</p> <note> <p> If you use <code>ADD</code> to increment or decrement a number value for an item that doesn&#39;t exist before the update, DynamoDB uses 0 as the initial value.</p> <p>In addition, if you use <code>ADD</code> to update an existing item, and intend to increment or decrement an attribute value which does not yet exist, DynamoDB uses <code>0</code> as the initial value. For example, suppose that the item you want to update does not yet have an attribute named <i>itemcount</i>, but you decide to <code>ADD</code> the number <code>3</code> to this attribute anyway, even though it currently does not exist. DynamoDB will create the <i>itemcount</i> attribute, set its initial value to <code>0</code>, and finally add <code>3</code> to it. The result will be a new <i>itemcount</i> attribute in the item, with a value of <code>3</code>.</p> </note> </li> <li> <p>If the existing data type is a set, and if the <code>Value</code> is also a set, then the <code>Value</code> is added to the existing set. (This is a <i>set</i> operation, not mathematical addition.) For example, if the attribute value was the set <code>[1,2]</code>, and the <code>ADD</code> action specified <code>[3]</code>, then the final attribute value would be <code>[1,2,3]</code>. An error occurs if an Add action is specified for a set attribute and the attribute type specified does not match the existing set type. </p> <p>Both sets must have the same primitive data type. For example, if the existing data type is a set of strings, the <code>Value</code> must also be a set of strings. The same holds true for number sets and binary sets.</p> </li> </ul> <p>This action is only valid for an existing attribute whose data type is number or is a set. Do not use <code>ADD</code> for any other data types.</p> </li> </ul> <p> <b>If no item with the specified <i>Key</i> is found:</b> </p> <ul> <li> <p> <code>PUT</code> - DynamoDB creates a new item with the specified primary key, and then adds the attribute. </p> </li> <li> <p> <code>DELETE</code> - Nothing happens; there is no attribute to delete.</p> </li> <li> <p> <code>ADD</code> - DynamoDB creates an item with the supplied primary key and number (or set of numbers) for the attribute value. The only data types allowed are number and number set; no other data types can be specified.</p> </li> </ul></p>
     #[serde(rename = "Action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<AttributeAction>,
     /// <p>Represents the data for an attribute.</p> <p>Each attribute value is described as a name-value pair. The name is the data type, and the value is the data itself.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/HowItWorks.NamingRulesDataTypes.html#HowItWorks.DataTypes">Data Types</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -241,7 +318,7 @@ pub struct BackupDescription {
 }
 
 /// <p>Contains the details of the backup created for the table.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BackupDetails {
     /// <p>ARN associated with the backup.</p>
@@ -249,11 +326,13 @@ pub struct BackupDetails {
     pub backup_arn: String,
     /// <p>Time at which the backup was created. This is the request time of the backup. </p>
     #[serde(rename = "BackupCreationDateTime")]
-    pub backup_creation_date_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub backup_creation_date_time: EpochTimestamp,
     /// <p>Time at which the automatic on-demand backup created by DynamoDB will expire. This <code>SYSTEM</code> on-demand backup expires automatically 35 days after its creation.</p>
     #[serde(rename = "BackupExpiryDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_expiry_date_time: Option<f64>,
+    pub backup_expiry_date_time: Option<EpochTimestamp>,
     /// <p>Name of the requested backup.</p>
     #[serde(rename = "BackupName")]
     pub backup_name: String,
@@ -263,10 +342,86 @@ pub struct BackupDetails {
     pub backup_size_bytes: Option<i64>,
     /// <p>Backup can be in one of the following states: CREATING, ACTIVE, DELETED. </p>
     #[serde(rename = "BackupStatus")]
-    pub backup_status: String,
+    pub backup_status: BackupStatus,
     /// <p><p>BackupType:</p> <ul> <li> <p> <code>USER</code> - You create and manage these using the on-demand backup feature.</p> </li> <li> <p> <code>SYSTEM</code> - If you delete a table with point-in-time recovery enabled, a <code>SYSTEM</code> backup is automatically created and is retained for 35 days (at no additional cost). System backups allow you to restore the deleted table to the state it was in just before the point of deletion. </p> </li> <li> <p> <code>AWS_BACKUP</code> - On-demand backup created by you from AWS Backup service.</p> </li> </ul></p>
     #[serde(rename = "BackupType")]
-    pub backup_type: String,
+    pub backup_type: BackupType,
+}
+
+/// <p>Backup can be in one of the following states: CREATING, ACTIVE, DELETED. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BackupStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BackupStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupStatus::Creating => "CREATING",
+            BackupStatus::Deleted => "DELETED",
+            BackupStatus::Available => "AVAILABLE",
+            BackupStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BackupStatus {
+    fn from(value: &'a str) -> BackupStatus {
+        match value {
+            "CREATING" => BackupStatus::Creating,
+            "DELETED" => BackupStatus::Deleted,
+            "AVAILABLE" => BackupStatus::Available,
+            _ => BackupStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupStatus {
+    fn from(value: String) -> BackupStatus {
+        BackupStatus::from(value.as_str())
+    }
+}
+
+impl From<BackupStatus> for String {
+    fn from(value: BackupStatus) -> String {
+        match value {
+            BackupStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BackupStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BackupStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BackupStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BackupStatus::from(value))
+    }
 }
 
 /// <p>Contains details for the backup.</p>
@@ -279,12 +434,14 @@ pub struct BackupSummary {
     pub backup_arn: Option<String>,
     /// <p>Time at which the backup was created.</p>
     #[serde(rename = "BackupCreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_creation_date_time: Option<f64>,
+    pub backup_creation_date_time: Option<EpochTimestamp>,
     /// <p>Time at which the automatic on-demand backup created by DynamoDB will expire. This <code>SYSTEM</code> on-demand backup expires automatically 35 days after its creation.</p>
     #[serde(rename = "BackupExpiryDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_expiry_date_time: Option<f64>,
+    pub backup_expiry_date_time: Option<EpochTimestamp>,
     /// <p>Name of the specified backup.</p>
     #[serde(rename = "BackupName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -296,11 +453,11 @@ pub struct BackupSummary {
     /// <p>Backup can be in one of the following states: CREATING, ACTIVE, DELETED.</p>
     #[serde(rename = "BackupStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_status: Option<String>,
+    pub backup_status: Option<BackupStatus>,
     /// <p><p>BackupType:</p> <ul> <li> <p> <code>USER</code> - You create and manage these using the on-demand backup feature.</p> </li> <li> <p> <code>SYSTEM</code> - If you delete a table with point-in-time recovery enabled, a <code>SYSTEM</code> backup is automatically created and is retained for 35 days (at no additional cost). System backups allow you to restore the deleted table to the state it was in just before the point of deletion. </p> </li> <li> <p> <code>AWS_BACKUP</code> - On-demand backup created by you from AWS Backup service.</p> </li> </ul></p>
     #[serde(rename = "BackupType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_type: Option<String>,
+    pub backup_type: Option<BackupType>,
     /// <p>ARN associated with the table.</p>
     #[serde(rename = "TableArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -315,6 +472,162 @@ pub struct BackupSummary {
     pub table_name: Option<String>,
 }
 
+/// <p><p>BackupType:</p> <ul> <li> <p> <code>USER</code> - You create and manage these using the on-demand backup feature.</p> </li> <li> <p> <code>SYSTEM</code> - If you delete a table with point-in-time recovery enabled, a <code>SYSTEM</code> backup is automatically created and is retained for 35 days (at no additional cost). System backups allow you to restore the deleted table to the state it was in just before the point of deletion. </p> </li> <li> <p> <code>AWS_BACKUP</code> - On-demand backup created by you from AWS Backup service.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BackupType {
+    /// <p><code>USER</code> - You create and manage these using the on-demand backup feature.</p>
+    User,
+    /// <p><code>SYSTEM</code> - If you delete a table with point-in-time recovery enabled, a <code>SYSTEM</code> backup is automatically created and is retained for 35 days (at no additional cost). System backups allow you to restore the deleted table to the state it was in just before the point of deletion.</p>
+    System,
+    /// <p><code>AWS_BACKUP</code> - On-demand backup created by you from AWS Backup service.</p>
+    AwsBackup,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BackupType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupType::User => "USER",
+            BackupType::System => "SYSTEM",
+            BackupType::AwsBackup => "AWS_BACKUP",
+            BackupType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BackupType {
+    fn from(value: &'a str) -> BackupType {
+        match value {
+            "USER" => BackupType::User,
+            "SYSTEM" => BackupType::System,
+            "AWS_BACKUP" => BackupType::AwsBackup,
+            _ => BackupType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupType {
+    fn from(value: String) -> BackupType {
+        BackupType::from(value.as_str())
+    }
+}
+
+impl From<BackupType> for String {
+    fn from(value: BackupType) -> String {
+        match value {
+            BackupType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BackupType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BackupType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BackupType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BackupType::from(value))
+    }
+}
+
+/// <p><p>The backups from the table specified by <code>BackupType</code> are listed.</p> <p>Where <code>BackupType</code> can be:</p> <ul> <li> <p> <code>USER</code> - On-demand backup created by you.</p> </li> <li> <p> <code>SYSTEM</code> - On-demand backup automatically created by DynamoDB.</p> </li> <li> <p> <code>ALL</code> - All types of on-demand backups (USER and SYSTEM).</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BackupTypeFilter {
+    /// <p><code>USER</code> - On-demand backup created by you.</p>
+    User,
+    /// <p><code>SYSTEM</code> - On-demand backup automatically created by DynamoDB.</p>
+    System,
+    /// <p><code>AWS_BACKUP</code></p>
+    AwsBackup,
+    /// <p><code>ALL</code> - All types of on-demand backups (USER and SYSTEM).</p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BackupTypeFilter {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupTypeFilter::User => "USER",
+            BackupTypeFilter::System => "SYSTEM",
+            BackupTypeFilter::AwsBackup => "AWS_BACKUP",
+            BackupTypeFilter::All => "ALL",
+            BackupTypeFilter::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BackupTypeFilter {
+    fn from(value: &'a str) -> BackupTypeFilter {
+        match value {
+            "USER" => BackupTypeFilter::User,
+            "SYSTEM" => BackupTypeFilter::System,
+            "AWS_BACKUP" => BackupTypeFilter::AwsBackup,
+            "ALL" => BackupTypeFilter::All,
+            _ => BackupTypeFilter::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupTypeFilter {
+    fn from(value: String) -> BackupTypeFilter {
+        BackupTypeFilter::from(value.as_str())
+    }
+}
+
+impl From<BackupTypeFilter> for String {
+    fn from(value: BackupTypeFilter) -> String {
+        match value {
+            BackupTypeFilter::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BackupTypeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BackupTypeFilter {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupTypeFilter {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BackupTypeFilter, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BackupTypeFilter::from(value))
+    }
+}
+
 /// <p>Represents the input of a <code>BatchGetItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct BatchGetItemInput {
@@ -323,7 +636,48 @@ pub struct BatchGetItemInput {
     pub request_items: ::std::collections::HashMap<String, KeysAndAttributes>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
+}
+
+impl BatchGetItemInput {
+    /// Returns a builder for `BatchGetItemInput`, checking its required members when built.
+    pub fn builder() -> BatchGetItemInputBuilder {
+        BatchGetItemInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetItemInput`](struct.BatchGetItemInput.html).
+#[derive(Default, Debug)]
+pub struct BatchGetItemInputBuilder {
+    request_items: Option<::std::collections::HashMap<String, KeysAndAttributes>>,
+    return_consumed_capacity: Option<ReturnConsumedCapacity>,
+}
+
+impl BatchGetItemInputBuilder {
+    /// Sets the [`request_items`](struct.BatchGetItemInput.html#structfield.request_items) member.
+    pub fn request_items<T: Into<::std::collections::HashMap<String, KeysAndAttributes>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_consumed_capacity`](struct.BatchGetItemInput.html#structfield.return_consumed_capacity) member.
+    pub fn return_consumed_capacity<T: Into<ReturnConsumedCapacity>>(mut self, value: T) -> Self {
+        self.return_consumed_capacity = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetItemInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchGetItemInput, ::rusoto_core::BuildError> {
+        Ok(BatchGetItemInput {
+            request_items: self
+                .request_items
+                .ok_or(::rusoto_core::BuildError::missing("request_items"))?,
+            return_consumed_capacity: self.return_consumed_capacity,
+        })
+    }
 }
 
 /// <p>Represents the output of a <code>BatchGetItem</code> operation.</p>
@@ -357,11 +711,63 @@ pub struct BatchWriteItemInput {
     pub request_items: ::std::collections::HashMap<String, Vec<WriteRequest>>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>Determines whether item collection metrics are returned. If set to <code>SIZE</code>, the response includes statistics about item collections, if any, that were modified during the operation are returned in the response. If set to <code>NONE</code> (the default), no statistics are returned.</p>
     #[serde(rename = "ReturnItemCollectionMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_item_collection_metrics: Option<String>,
+    pub return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
+}
+
+impl BatchWriteItemInput {
+    /// Returns a builder for `BatchWriteItemInput`, checking its required members when built.
+    pub fn builder() -> BatchWriteItemInputBuilder {
+        BatchWriteItemInputBuilder::default()
+    }
+}
+
+/// Builds a [`BatchWriteItemInput`](struct.BatchWriteItemInput.html).
+#[derive(Default, Debug)]
+pub struct BatchWriteItemInputBuilder {
+    request_items: Option<::std::collections::HashMap<String, Vec<WriteRequest>>>,
+    return_consumed_capacity: Option<ReturnConsumedCapacity>,
+    return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
+}
+
+impl BatchWriteItemInputBuilder {
+    /// Sets the [`request_items`](struct.BatchWriteItemInput.html#structfield.request_items) member.
+    pub fn request_items<T: Into<::std::collections::HashMap<String, Vec<WriteRequest>>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_consumed_capacity`](struct.BatchWriteItemInput.html#structfield.return_consumed_capacity) member.
+    pub fn return_consumed_capacity<T: Into<ReturnConsumedCapacity>>(mut self, value: T) -> Self {
+        self.return_consumed_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_item_collection_metrics`](struct.BatchWriteItemInput.html#structfield.return_item_collection_metrics) member.
+    pub fn return_item_collection_metrics<T: Into<ReturnItemCollectionMetrics>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.return_item_collection_metrics = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchWriteItemInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchWriteItemInput, ::rusoto_core::BuildError> {
+        Ok(BatchWriteItemInput {
+            request_items: self
+                .request_items
+                .ok_or(::rusoto_core::BuildError::missing("request_items"))?,
+            return_consumed_capacity: self.return_consumed_capacity,
+            return_item_collection_metrics: self.return_item_collection_metrics,
+        })
+    }
 }
 
 /// <p>Represents the output of a <code>BatchWriteItem</code> operation.</p>
@@ -383,6 +789,78 @@ pub struct BatchWriteItemOutput {
     pub unprocessed_items: Option<::std::collections::HashMap<String, Vec<WriteRequest>>>,
 }
 
+/// <p><p>Controls how you are charged for read and write throughput and how you manage capacity. This setting can be changed later.</p> <ul> <li> <p> <code>PROVISIONED</code> - Sets the read/write capacity mode to <code>PROVISIONED</code>. We recommend using <code>PROVISIONED</code> for predictable workloads.</p> </li> <li> <p> <code>PAY<em>PER</em>REQUEST</code> - Sets the read/write capacity mode to <code>PAY<em>PER</em>REQUEST</code>. We recommend using <code>PAY<em>PER</em>REQUEST</code> for unpredictable workloads. </p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BillingMode {
+    /// <p><code>PROVISIONED</code> - Sets the read/write capacity mode to <code>PROVISIONED</code>. We recommend using <code>PROVISIONED</code> for predictable workloads.</p>
+    Provisioned,
+    /// <p><code>PAY_PER_REQUEST</code></p>
+    PayPerRequest,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BillingMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BillingMode::Provisioned => "PROVISIONED",
+            BillingMode::PayPerRequest => "PAY_PER_REQUEST",
+            BillingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BillingMode {
+    fn from(value: &'a str) -> BillingMode {
+        match value {
+            "PROVISIONED" => BillingMode::Provisioned,
+            "PAY_PER_REQUEST" => BillingMode::PayPerRequest,
+            _ => BillingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BillingMode {
+    fn from(value: String) -> BillingMode {
+        BillingMode::from(value.as_str())
+    }
+}
+
+impl From<BillingMode> for String {
+    fn from(value: BillingMode) -> String {
+        match value {
+            BillingMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BillingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BillingMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BillingMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BillingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BillingMode::from(value))
+    }
+}
+
 /// <p>Contains the details for the read/write capacity mode.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -390,11 +868,12 @@ pub struct BillingModeSummary {
     /// <p><p>Controls how you are charged for read and write throughput and how you manage capacity. This setting can be changed later.</p> <ul> <li> <p> <code>PROVISIONED</code> - Sets the read/write capacity mode to <code>PROVISIONED</code>. We recommend using <code>PROVISIONED</code> for predictable workloads.</p> </li> <li> <p> <code>PAY<em>PER</em>REQUEST</code> - Sets the read/write capacity mode to <code>PAY<em>PER</em>REQUEST</code>. We recommend using <code>PAY<em>PER</em>REQUEST</code> for unpredictable workloads. </p> </li> </ul></p>
     #[serde(rename = "BillingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_mode: Option<String>,
+    pub billing_mode: Option<BillingMode>,
     /// <p>Represents the time when <code>PAY_PER_REQUEST</code> was last set as the read/write capacity mode.</p>
     #[serde(rename = "LastUpdateToPayPerRequestDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_to_pay_per_request_date_time: Option<f64>,
+    pub last_update_to_pay_per_request_date_time: Option<EpochTimestamp>,
 }
 
 /// <p>An ordered list of errors for each item in the request which caused the transaction to get cancelled. The values of the list are ordered according to the ordering of the <code>TransactWriteItems</code> request parameter. If no error occurred for the associated item an error with a Null code and Null message will be present. </p>
@@ -426,8 +905,124 @@ pub struct Capacity {
     pub write_capacity_units: Option<f64>,
 }
 
+/// <p>A comparator for evaluating attributes. For example, equals, greater than, less than, etc.</p> <p>The following comparison operators are available:</p> <p> <code>EQ | NE | LE | LT | GE | GT | NOT_NULL | NULL | CONTAINS | NOT_CONTAINS | BEGINS_WITH | IN | BETWEEN</code> </p> <p>The following are descriptions of each comparison operator.</p> <ul> <li> <p> <code>EQ</code> : Equal. <code>EQ</code> is supported for all data types, including lists and maps.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, Binary, String Set, Number Set, or Binary Set. If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not equal <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>NE</code> : Not equal. <code>NE</code> is supported for all data types, including lists and maps.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String, Number, Binary, String Set, Number Set, or Binary Set. If an item contains an <code>AttributeValue</code> of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not equal <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>LE</code> : Less than or equal. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>LT</code> : Less than. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>GE</code> : Greater than or equal. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>GT</code> : Greater than. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>NOT_NULL</code> : The attribute exists. <code>NOT_NULL</code> is supported for all data types, including lists and maps.</p> <note> <p>This operator tests for the existence of an attribute, not its data type. If the data type of attribute "<code>a</code>" is null, and you evaluate it using <code>NOT_NULL</code>, the result is a Boolean <code>true</code>. This result is because the attribute "<code>a</code>" exists; its data type is not relevant to the <code>NOT_NULL</code> comparison operator.</p> </note> </li> <li> <p> <code>NULL</code> : The attribute does not exist. <code>NULL</code> is supported for all data types, including lists and maps.</p> <note> <p>This operator tests for the nonexistence of an attribute, not its data type. If the data type of attribute "<code>a</code>" is null, and you evaluate it using <code>NULL</code>, the result is a Boolean <code>false</code>. This is because the attribute "<code>a</code>" exists; its data type is not relevant to the <code>NULL</code> comparison operator.</p> </note> </li> <li> <p> <code>CONTAINS</code> : Checks for a subsequence, or value in a set.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If the target attribute of the comparison is of type String, then the operator checks for a substring match. If the target attribute of the comparison is of type Binary, then the operator looks for a subsequence of the target that matches the input. If the target attribute of the comparison is a set ("<code>SS</code>", "<code>NS</code>", or "<code>BS</code>"), then the operator evaluates to true if it finds an exact match with any member of the set.</p> <p>CONTAINS is supported for lists: When evaluating "<code>a CONTAINS b</code>", "<code>a</code>" can be a list; however, "<code>b</code>" cannot be a set, a map, or a list.</p> </li> <li> <p> <code>NOT_CONTAINS</code> : Checks for absence of a subsequence, or absence of a value in a set.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If the target attribute of the comparison is a String, then the operator checks for the absence of a substring match. If the target attribute of the comparison is Binary, then the operator checks for the absence of a subsequence of the target that matches the input. If the target attribute of the comparison is a set ("<code>SS</code>", "<code>NS</code>", or "<code>BS</code>"), then the operator evaluates to true if it <i>does not</i> find an exact match with any member of the set.</p> <p>NOT_CONTAINS is supported for lists: When evaluating "<code>a NOT CONTAINS b</code>", "<code>a</code>" can be a list; however, "<code>b</code>" cannot be a set, a map, or a list.</p> </li> <li> <p> <code>BEGINS_WITH</code> : Checks for a prefix. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String or Binary (not a Number or a set type). The target attribute of the comparison must be of type String or Binary (not a Number or a set type).</p> <p/> </li> <li> <p> <code>IN</code> : Checks for matching elements in a list.</p> <p> <code>AttributeValueList</code> can contain one or more <code>AttributeValue</code> elements of type String, Number, or Binary. These attributes are compared against an existing attribute of an item. If any elements of the input are equal to the item attribute, the expression evaluates to true.</p> </li> <li> <p> <code>BETWEEN</code> : Greater than or equal to the first value, and less than or equal to the second value. </p> <p> <code>AttributeValueList</code> must contain two <code>AttributeValue</code> elements of the same type, either String, Number, or Binary (not a set type). A target attribute matches if the target value is greater than, or equal to, the first element and less than, or equal to, the second element. If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not compare to <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code> </p> </li> </ul> <p>For usage examples of <code>AttributeValueList</code> and <code>ComparisonOperator</code>, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.html">Legacy Conditional Parameters</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonOperator {
+    /// <p><code>EQ</code> - Equal. <code>EQ</code> is supported for all data types, including lists and maps.</p>
+    Eq,
+    /// <p><code>NE</code> - Not equal. <code>NE</code> is supported for all data types, including lists and maps.</p>
+    Ne,
+    /// <p><code>IN</code> - Checks for matching elements in a list.</p>
+    In,
+    /// <p><code>LE</code> - Less than or equal.</p>
+    Le,
+    /// <p><code>LT</code> - Less than.</p>
+    Lt,
+    /// <p><code>GE</code> - Greater than or equal.</p>
+    Ge,
+    /// <p><code>GT</code> - Greater than.</p>
+    Gt,
+    /// <p><code>BETWEEN</code> - Greater than or equal to the first value, and less than or equal to the second value.</p>
+    Between,
+    /// <p><code>NOT_NULL</code> - The attribute exists. <code>NOT_NULL</code> is supported for all data types, including lists and maps.</p>
+    NotNull,
+    /// <p><code>NULL</code> - The attribute does not exist. <code>NULL</code> is supported for all data types, including lists and maps.</p>
+    Null,
+    /// <p><code>CONTAINS</code> - Checks for a subsequence, or value in a set.</p>
+    Contains,
+    /// <p><code>NOT_CONTAINS</code> - Checks for absence of a subsequence, or absence of a value in a set.</p>
+    NotContains,
+    /// <p><code>BEGINS_WITH</code> - Checks for a prefix.</p>
+    BeginsWith,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::Eq => "EQ",
+            ComparisonOperator::Ne => "NE",
+            ComparisonOperator::In => "IN",
+            ComparisonOperator::Le => "LE",
+            ComparisonOperator::Lt => "LT",
+            ComparisonOperator::Ge => "GE",
+            ComparisonOperator::Gt => "GT",
+            ComparisonOperator::Between => "BETWEEN",
+            ComparisonOperator::NotNull => "NOT_NULL",
+            ComparisonOperator::Null => "NULL",
+            ComparisonOperator::Contains => "CONTAINS",
+            ComparisonOperator::NotContains => "NOT_CONTAINS",
+            ComparisonOperator::BeginsWith => "BEGINS_WITH",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "EQ" => ComparisonOperator::Eq,
+            "NE" => ComparisonOperator::Ne,
+            "IN" => ComparisonOperator::In,
+            "LE" => ComparisonOperator::Le,
+            "LT" => ComparisonOperator::Lt,
+            "GE" => ComparisonOperator::Ge,
+            "GT" => ComparisonOperator::Gt,
+            "BETWEEN" => ComparisonOperator::Between,
+            "NOT_NULL" => ComparisonOperator::NotNull,
+            "NULL" => ComparisonOperator::Null,
+            "CONTAINS" => ComparisonOperator::Contains,
+            "NOT_CONTAINS" => ComparisonOperator::NotContains,
+            "BEGINS_WITH" => ComparisonOperator::BeginsWith,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        ComparisonOperator::from(value.as_str())
+    }
+}
+
+impl From<ComparisonOperator> for String {
+    fn from(value: ComparisonOperator) -> String {
+        match value {
+            ComparisonOperator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ComparisonOperator::from(value))
+    }
+}
+
 /// <p><p>Represents the selection criteria for a <code>Query</code> or <code>Scan</code> operation:</p> <ul> <li> <p>For a <code>Query</code> operation, <code>Condition</code> is used for specifying the <code>KeyConditions</code> to use when querying a table or an index. For <code>KeyConditions</code>, only the following comparison operators are supported:</p> <p> <code>EQ | LE | LT | GE | GT | BEGINS_WITH | BETWEEN</code> </p> <p> <code>Condition</code> is also used in a <code>QueryFilter</code>, which evaluates the query results and returns only the desired values.</p> </li> <li> <p>For a <code>Scan</code> operation, <code>Condition</code> is used in a <code>ScanFilter</code>, which evaluates the scan results and returns only the desired values.</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Condition {
     /// <p>One or more values to evaluate against the supplied attribute. The number of values in the list depends on the <code>ComparisonOperator</code> being used.</p> <p>For type Number, value comparisons are numeric.</p> <p>String value comparisons for greater than, equals, or less than are based on ASCII character code values. For example, <code>a</code> is greater than <code>A</code>, and <code>a</code> is greater than <code>B</code>. For a list of code values, see <a href="http://en.wikipedia.org/wiki/ASCII#ASCII_printable_characters">http://en.wikipedia.org/wiki/ASCII#ASCII_printable_characters</a>.</p> <p>For Binary, DynamoDB treats each byte of the binary data as unsigned when it compares binary values.</p>
     #[serde(rename = "AttributeValueList")]
@@ -435,7 +1030,7 @@ pub struct Condition {
     pub attribute_value_list: Option<Vec<AttributeValue>>,
     /// <p>A comparator for evaluating attributes. For example, equals, greater than, less than, etc.</p> <p>The following comparison operators are available:</p> <p> <code>EQ | NE | LE | LT | GE | GT | NOT_NULL | NULL | CONTAINS | NOT_CONTAINS | BEGINS_WITH | IN | BETWEEN</code> </p> <p>The following are descriptions of each comparison operator.</p> <ul> <li> <p> <code>EQ</code> : Equal. <code>EQ</code> is supported for all data types, including lists and maps.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, Binary, String Set, Number Set, or Binary Set. If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not equal <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>NE</code> : Not equal. <code>NE</code> is supported for all data types, including lists and maps.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String, Number, Binary, String Set, Number Set, or Binary Set. If an item contains an <code>AttributeValue</code> of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not equal <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>LE</code> : Less than or equal. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>LT</code> : Less than. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>GE</code> : Greater than or equal. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>GT</code> : Greater than. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not equal <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code>.</p> <p/> </li> <li> <p> <code>NOT_NULL</code> : The attribute exists. <code>NOT_NULL</code> is supported for all data types, including lists and maps.</p> <note> <p>This operator tests for the existence of an attribute, not its data type. If the data type of attribute "<code>a</code>" is null, and you evaluate it using <code>NOT_NULL</code>, the result is a Boolean <code>true</code>. This result is because the attribute "<code>a</code>" exists; its data type is not relevant to the <code>NOT_NULL</code> comparison operator.</p> </note> </li> <li> <p> <code>NULL</code> : The attribute does not exist. <code>NULL</code> is supported for all data types, including lists and maps.</p> <note> <p>This operator tests for the nonexistence of an attribute, not its data type. If the data type of attribute "<code>a</code>" is null, and you evaluate it using <code>NULL</code>, the result is a Boolean <code>false</code>. This is because the attribute "<code>a</code>" exists; its data type is not relevant to the <code>NULL</code> comparison operator.</p> </note> </li> <li> <p> <code>CONTAINS</code> : Checks for a subsequence, or value in a set.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If the target attribute of the comparison is of type String, then the operator checks for a substring match. If the target attribute of the comparison is of type Binary, then the operator looks for a subsequence of the target that matches the input. If the target attribute of the comparison is a set ("<code>SS</code>", "<code>NS</code>", or "<code>BS</code>"), then the operator evaluates to true if it finds an exact match with any member of the set.</p> <p>CONTAINS is supported for lists: When evaluating "<code>a CONTAINS b</code>", "<code>a</code>" can be a list; however, "<code>b</code>" cannot be a set, a map, or a list.</p> </li> <li> <p> <code>NOT_CONTAINS</code> : Checks for absence of a subsequence, or absence of a value in a set.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If the target attribute of the comparison is a String, then the operator checks for the absence of a substring match. If the target attribute of the comparison is Binary, then the operator checks for the absence of a subsequence of the target that matches the input. If the target attribute of the comparison is a set ("<code>SS</code>", "<code>NS</code>", or "<code>BS</code>"), then the operator evaluates to true if it <i>does not</i> find an exact match with any member of the set.</p> <p>NOT_CONTAINS is supported for lists: When evaluating "<code>a NOT CONTAINS b</code>", "<code>a</code>" can be a list; however, "<code>b</code>" cannot be a set, a map, or a list.</p> </li> <li> <p> <code>BEGINS_WITH</code> : Checks for a prefix. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String or Binary (not a Number or a set type). The target attribute of the comparison must be of type String or Binary (not a Number or a set type).</p> <p/> </li> <li> <p> <code>IN</code> : Checks for matching elements in a list.</p> <p> <code>AttributeValueList</code> can contain one or more <code>AttributeValue</code> elements of type String, Number, or Binary. These attributes are compared against an existing attribute of an item. If any elements of the input are equal to the item attribute, the expression evaluates to true.</p> </li> <li> <p> <code>BETWEEN</code> : Greater than or equal to the first value, and less than or equal to the second value. </p> <p> <code>AttributeValueList</code> must contain two <code>AttributeValue</code> elements of the same type, either String, Number, or Binary (not a set type). A target attribute matches if the target value is greater than, or equal to, the first element and less than, or equal to, the second element. If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{"S":"6"}</code> does not compare to <code>{"N":"6"}</code>. Also, <code>{"N":"6"}</code> does not compare to <code>{"NS":["6", "2", "1"]}</code> </p> </li> </ul> <p>For usage examples of <code>AttributeValueList</code> and <code>ComparisonOperator</code>, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.html">Legacy Conditional Parameters</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ComparisonOperator")]
-    pub comparison_operator: String,
+    pub comparison_operator: ComparisonOperator,
 }
 
 /// <p>Represents a request to perform a check that an item exists or to check the condition of specific attributes of the item..</p>
@@ -458,12 +1053,84 @@ pub struct ConditionCheck {
     /// <p>Use <code>ReturnValuesOnConditionCheckFailure</code> to get the item attributes if the <code>ConditionCheck</code> condition fails. For <code>ReturnValuesOnConditionCheckFailure</code>, the valid values are: NONE and ALL_OLD.</p>
     #[serde(rename = "ReturnValuesOnConditionCheckFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values_on_condition_check_failure: Option<String>,
+    pub return_values_on_condition_check_failure: Option<ReturnValuesOnConditionCheckFailure>,
     /// <p>Name of the table for the check item request.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
 }
 
+/// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ConditionalOperator.html">ConditionalOperator</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConditionalOperator {
+    /// <p><code>AND</code></p>
+    And,
+    /// <p><code>OR</code></p>
+    Or,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConditionalOperator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConditionalOperator::And => "AND",
+            ConditionalOperator::Or => "OR",
+            ConditionalOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConditionalOperator {
+    fn from(value: &'a str) -> ConditionalOperator {
+        match value {
+            "AND" => ConditionalOperator::And,
+            "OR" => ConditionalOperator::Or,
+            _ => ConditionalOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConditionalOperator {
+    fn from(value: String) -> ConditionalOperator {
+        ConditionalOperator::from(value.as_str())
+    }
+}
+
+impl From<ConditionalOperator> for String {
+    fn from(value: ConditionalOperator) -> String {
+        match value {
+            ConditionalOperator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConditionalOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConditionalOperator {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConditionalOperator {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConditionalOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConditionalOperator::from(value))
+    }
+}
+
 /// <p>The capacity units consumed by an operation. The data returned includes the total provisioned throughput consumed, along with statistics for the table and any indexes involved in the operation. <code>ConsumedCapacity</code> is only returned if the request asked for it. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ProvisionedThroughputIntro.html">Provisioned Throughput</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -499,28 +1166,140 @@ pub struct ConsumedCapacity {
 }
 
 /// <p>Represents the continuous backups and point in time recovery settings on the table.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ContinuousBackupsDescription {
     /// <p> <code>ContinuousBackupsStatus</code> can be one of the following states: ENABLED, DISABLED</p>
     #[serde(rename = "ContinuousBackupsStatus")]
-    pub continuous_backups_status: String,
+    pub continuous_backups_status: ContinuousBackupsStatus,
     /// <p>The description of the point in time recovery settings applied to the table.</p>
     #[serde(rename = "PointInTimeRecoveryDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub point_in_time_recovery_description: Option<PointInTimeRecoveryDescription>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct CreateBackupInput {
-    /// <p>Specified name for the backup.</p>
-    #[serde(rename = "BackupName")]
+/// <p> <code>ContinuousBackupsStatus</code> can be one of the following states: ENABLED, DISABLED</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContinuousBackupsStatus {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContinuousBackupsStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContinuousBackupsStatus::Enabled => "ENABLED",
+            ContinuousBackupsStatus::Disabled => "DISABLED",
+            ContinuousBackupsStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContinuousBackupsStatus {
+    fn from(value: &'a str) -> ContinuousBackupsStatus {
+        match value {
+            "ENABLED" => ContinuousBackupsStatus::Enabled,
+            "DISABLED" => ContinuousBackupsStatus::Disabled,
+            _ => ContinuousBackupsStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContinuousBackupsStatus {
+    fn from(value: String) -> ContinuousBackupsStatus {
+        ContinuousBackupsStatus::from(value.as_str())
+    }
+}
+
+impl From<ContinuousBackupsStatus> for String {
+    fn from(value: ContinuousBackupsStatus) -> String {
+        match value {
+            ContinuousBackupsStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContinuousBackupsStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ContinuousBackupsStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContinuousBackupsStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContinuousBackupsStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContinuousBackupsStatus::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct CreateBackupInput {
+    /// <p>Specified name for the backup.</p>
+    #[serde(rename = "BackupName")]
     pub backup_name: String,
     /// <p>The name of the table.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
 }
 
+impl CreateBackupInput {
+    /// Returns a builder for `CreateBackupInput`, checking its required members when built.
+    pub fn builder() -> CreateBackupInputBuilder {
+        CreateBackupInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBackupInput`](struct.CreateBackupInput.html).
+#[derive(Default, Debug)]
+pub struct CreateBackupInputBuilder {
+    backup_name: Option<String>,
+    table_name: Option<String>,
+}
+
+impl CreateBackupInputBuilder {
+    /// Sets the [`backup_name`](struct.CreateBackupInput.html#structfield.backup_name) member.
+    pub fn backup_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.CreateBackupInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBackupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateBackupInput, ::rusoto_core::BuildError> {
+        Ok(CreateBackupInput {
+            backup_name: self
+                .backup_name
+                .ok_or(::rusoto_core::BuildError::missing("backup_name"))?,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateBackupOutput {
@@ -558,6 +1337,46 @@ pub struct CreateGlobalTableInput {
     pub replication_group: Vec<Replica>,
 }
 
+impl CreateGlobalTableInput {
+    /// Returns a builder for `CreateGlobalTableInput`, checking its required members when built.
+    pub fn builder() -> CreateGlobalTableInputBuilder {
+        CreateGlobalTableInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGlobalTableInput`](struct.CreateGlobalTableInput.html).
+#[derive(Default, Debug)]
+pub struct CreateGlobalTableInputBuilder {
+    global_table_name: Option<String>,
+    replication_group: Option<Vec<Replica>>,
+}
+
+impl CreateGlobalTableInputBuilder {
+    /// Sets the [`global_table_name`](struct.CreateGlobalTableInput.html#structfield.global_table_name) member.
+    pub fn global_table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.global_table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group`](struct.CreateGlobalTableInput.html#structfield.replication_group) member.
+    pub fn replication_group<T: Into<Vec<Replica>>>(mut self, value: T) -> Self {
+        self.replication_group = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGlobalTableInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateGlobalTableInput, ::rusoto_core::BuildError> {
+        Ok(CreateGlobalTableInput {
+            global_table_name: self
+                .global_table_name
+                .ok_or(::rusoto_core::BuildError::missing("global_table_name"))?,
+            replication_group: self
+                .replication_group
+                .ok_or(::rusoto_core::BuildError::missing("replication_group"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateGlobalTableOutput {
@@ -584,7 +1403,7 @@ pub struct CreateTableInput {
     /// <p><p>Controls how you are charged for read and write throughput and how you manage capacity. This setting can be changed later.</p> <ul> <li> <p> <code>PROVISIONED</code> - Sets the billing mode to <code>PROVISIONED</code>. We recommend using <code>PROVISIONED</code> for predictable workloads.</p> </li> <li> <p> <code>PAY<em>PER</em>REQUEST</code> - Sets the billing mode to <code>PAY<em>PER</em>REQUEST</code>. We recommend using <code>PAY<em>PER</em>REQUEST</code> for unpredictable workloads. </p> </li> </ul></p>
     #[serde(rename = "BillingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_mode: Option<String>,
+    pub billing_mode: Option<BillingMode>,
     /// <p><p>One or more global secondary indexes (the maximum is 20) to be created on the table. Each global secondary index in the array includes the following:</p> <ul> <li> <p> <code>IndexName</code> - The name of the global secondary index. Must be unique only for this table.</p> <p/> </li> <li> <p> <code>KeySchema</code> - Specifies the key schema for the global secondary index.</p> </li> <li> <p> <code>Projection</code> - Specifies attributes that are copied (projected) from the table into the index. These are in addition to the primary key attributes and index key attributes, which are automatically projected. Each attribute specification is composed of:</p> <ul> <li> <p> <code>ProjectionType</code> - One of the following:</p> <ul> <li> <p> <code>KEYS_ONLY</code> - Only the index and primary keys are projected into the index.</p> </li> <li> <p> <code>INCLUDE</code> - Only the specified table attributes are projected into the index. The list of projected attributes is in <code>NonKeyAttributes</code>.</p> </li> <li> <p> <code>ALL</code> - All of the table attributes are projected into the index.</p> </li> </ul> </li> <li> <p> <code>NonKeyAttributes</code> - A list of one or more non-key attribute names that are projected into the secondary index. The total count of attributes provided in <code>NonKeyAttributes</code>, summed across all of the secondary indexes, must not exceed 100. If you project the same attribute into two different indexes, this counts as two distinct attributes when determining the total.</p> </li> </ul> </li> <li> <p> <code>ProvisionedThroughput</code> - The provisioned throughput settings for the global secondary index, consisting of read and write capacity units.</p> </li> </ul></p>
     #[serde(rename = "GlobalSecondaryIndexes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -617,6 +1436,115 @@ pub struct CreateTableInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateTableInput {
+    /// Returns a builder for `CreateTableInput`, checking its required members when built.
+    pub fn builder() -> CreateTableInputBuilder {
+        CreateTableInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTableInput`](struct.CreateTableInput.html).
+#[derive(Default, Debug)]
+pub struct CreateTableInputBuilder {
+    attribute_definitions: Option<Vec<AttributeDefinition>>,
+    billing_mode: Option<BillingMode>,
+    global_secondary_indexes: Option<Vec<GlobalSecondaryIndex>>,
+    key_schema: Option<Vec<KeySchemaElement>>,
+    local_secondary_indexes: Option<Vec<LocalSecondaryIndex>>,
+    provisioned_throughput: Option<ProvisionedThroughput>,
+    sse_specification: Option<SSESpecification>,
+    stream_specification: Option<StreamSpecification>,
+    table_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateTableInputBuilder {
+    /// Sets the [`attribute_definitions`](struct.CreateTableInput.html#structfield.attribute_definitions) member.
+    pub fn attribute_definitions<T: Into<Vec<AttributeDefinition>>>(mut self, value: T) -> Self {
+        self.attribute_definitions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`billing_mode`](struct.CreateTableInput.html#structfield.billing_mode) member.
+    pub fn billing_mode<T: Into<BillingMode>>(mut self, value: T) -> Self {
+        self.billing_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`global_secondary_indexes`](struct.CreateTableInput.html#structfield.global_secondary_indexes) member.
+    pub fn global_secondary_indexes<T: Into<Vec<GlobalSecondaryIndex>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.global_secondary_indexes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_schema`](struct.CreateTableInput.html#structfield.key_schema) member.
+    pub fn key_schema<T: Into<Vec<KeySchemaElement>>>(mut self, value: T) -> Self {
+        self.key_schema = Some(value.into());
+        self
+    }
+
+    /// Sets the [`local_secondary_indexes`](struct.CreateTableInput.html#structfield.local_secondary_indexes) member.
+    pub fn local_secondary_indexes<T: Into<Vec<LocalSecondaryIndex>>>(mut self, value: T) -> Self {
+        self.local_secondary_indexes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_throughput`](struct.CreateTableInput.html#structfield.provisioned_throughput) member.
+    pub fn provisioned_throughput<T: Into<ProvisionedThroughput>>(mut self, value: T) -> Self {
+        self.provisioned_throughput = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sse_specification`](struct.CreateTableInput.html#structfield.sse_specification) member.
+    pub fn sse_specification<T: Into<SSESpecification>>(mut self, value: T) -> Self {
+        self.sse_specification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_specification`](struct.CreateTableInput.html#structfield.stream_specification) member.
+    pub fn stream_specification<T: Into<StreamSpecification>>(mut self, value: T) -> Self {
+        self.stream_specification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.CreateTableInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateTableInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTableInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTableInput, ::rusoto_core::BuildError> {
+        Ok(CreateTableInput {
+            attribute_definitions: self
+                .attribute_definitions
+                .ok_or(::rusoto_core::BuildError::missing("attribute_definitions"))?,
+            billing_mode: self.billing_mode,
+            global_secondary_indexes: self.global_secondary_indexes,
+            key_schema: self
+                .key_schema
+                .ok_or(::rusoto_core::BuildError::missing("key_schema"))?,
+            local_secondary_indexes: self.local_secondary_indexes,
+            provisioned_throughput: self.provisioned_throughput,
+            sse_specification: self.sse_specification,
+            stream_specification: self.stream_specification,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>CreateTable</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -648,7 +1576,7 @@ pub struct Delete {
     /// <p>Use <code>ReturnValuesOnConditionCheckFailure</code> to get the item attributes if the <code>Delete</code> condition fails. For <code>ReturnValuesOnConditionCheckFailure</code>, the valid values are: NONE and ALL_OLD.</p>
     #[serde(rename = "ReturnValuesOnConditionCheckFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values_on_condition_check_failure: Option<String>,
+    pub return_values_on_condition_check_failure: Option<ReturnValuesOnConditionCheckFailure>,
     /// <p>Name of the table in which the item to be deleted resides.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -661,6 +1589,36 @@ pub struct DeleteBackupInput {
     pub backup_arn: String,
 }
 
+impl DeleteBackupInput {
+    /// Returns a builder for `DeleteBackupInput`, checking its required members when built.
+    pub fn builder() -> DeleteBackupInputBuilder {
+        DeleteBackupInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBackupInput`](struct.DeleteBackupInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteBackupInputBuilder {
+    backup_arn: Option<String>,
+}
+
+impl DeleteBackupInputBuilder {
+    /// Sets the [`backup_arn`](struct.DeleteBackupInput.html#structfield.backup_arn) member.
+    pub fn backup_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBackupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteBackupInput, ::rusoto_core::BuildError> {
+        Ok(DeleteBackupInput {
+            backup_arn: self
+                .backup_arn
+                .ok_or(::rusoto_core::BuildError::missing("backup_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteBackupOutput {
@@ -688,7 +1646,7 @@ pub struct DeleteItemInput {
     /// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ConditionalOperator.html">ConditionalOperator</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConditionalOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conditional_operator: Option<String>,
+    pub conditional_operator: Option<ConditionalOperator>,
     /// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.Expected.html">Expected</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "Expected")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -706,20 +1664,139 @@ pub struct DeleteItemInput {
     pub key: ::std::collections::HashMap<String, AttributeValue>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>Determines whether item collection metrics are returned. If set to <code>SIZE</code>, the response includes statistics about item collections, if any, that were modified during the operation are returned in the response. If set to <code>NONE</code> (the default), no statistics are returned.</p>
     #[serde(rename = "ReturnItemCollectionMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_item_collection_metrics: Option<String>,
+    pub return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
     /// <p><p>Use <code>ReturnValues</code> if you want to get the item attributes as they appeared before they were deleted. For <code>DeleteItem</code>, the valid values are:</p> <ul> <li> <p> <code>NONE</code> - If <code>ReturnValues</code> is not specified, or if its value is <code>NONE</code>, then nothing is returned. (This setting is the default for <code>ReturnValues</code>.)</p> </li> <li> <p> <code>ALL<em>OLD</code> - The content of the old item is returned.</p> </li> </ul> <note> <p>The <code>ReturnValues</code> parameter is used by several DynamoDB operations; however, <code>DeleteItem</code> does not recognize any values other than <code>NONE</code> or <code>ALL</em>OLD</code>.</p> </note></p>
     #[serde(rename = "ReturnValues")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values: Option<String>,
+    pub return_values: Option<ReturnValue>,
     /// <p>The name of the table from which to delete the item.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
 }
 
+impl DeleteItemInput {
+    /// Returns a builder for `DeleteItemInput`, checking its required members when built.
+    pub fn builder() -> DeleteItemInputBuilder {
+        DeleteItemInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteItemInput`](struct.DeleteItemInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteItemInputBuilder {
+    condition_expression: Option<String>,
+    conditional_operator: Option<ConditionalOperator>,
+    expected: Option<::std::collections::HashMap<String, ExpectedAttributeValue>>,
+    expression_attribute_names: Option<::std::collections::HashMap<String, String>>,
+    expression_attribute_values: Option<::std::collections::HashMap<String, AttributeValue>>,
+    key: Option<::std::collections::HashMap<String, AttributeValue>>,
+    return_consumed_capacity: Option<ReturnConsumedCapacity>,
+    return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
+    return_values: Option<ReturnValue>,
+    table_name: Option<String>,
+}
+
+impl DeleteItemInputBuilder {
+    /// Sets the [`condition_expression`](struct.DeleteItemInput.html#structfield.condition_expression) member.
+    pub fn condition_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.condition_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conditional_operator`](struct.DeleteItemInput.html#structfield.conditional_operator) member.
+    pub fn conditional_operator<T: Into<ConditionalOperator>>(mut self, value: T) -> Self {
+        self.conditional_operator = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expected`](struct.DeleteItemInput.html#structfield.expected) member.
+    pub fn expected<T: Into<::std::collections::HashMap<String, ExpectedAttributeValue>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expected = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_attribute_names`](struct.DeleteItemInput.html#structfield.expression_attribute_names) member.
+    pub fn expression_attribute_names<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expression_attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_attribute_values`](struct.DeleteItemInput.html#structfield.expression_attribute_values) member.
+    pub fn expression_attribute_values<
+        T: Into<::std::collections::HashMap<String, AttributeValue>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expression_attribute_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key`](struct.DeleteItemInput.html#structfield.key) member.
+    pub fn key<T: Into<::std::collections::HashMap<String, AttributeValue>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_consumed_capacity`](struct.DeleteItemInput.html#structfield.return_consumed_capacity) member.
+    pub fn return_consumed_capacity<T: Into<ReturnConsumedCapacity>>(mut self, value: T) -> Self {
+        self.return_consumed_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_item_collection_metrics`](struct.DeleteItemInput.html#structfield.return_item_collection_metrics) member.
+    pub fn return_item_collection_metrics<T: Into<ReturnItemCollectionMetrics>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.return_item_collection_metrics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_values`](struct.DeleteItemInput.html#structfield.return_values) member.
+    pub fn return_values<T: Into<ReturnValue>>(mut self, value: T) -> Self {
+        self.return_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.DeleteItemInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteItemInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteItemInput, ::rusoto_core::BuildError> {
+        Ok(DeleteItemInput {
+            condition_expression: self.condition_expression,
+            conditional_operator: self.conditional_operator,
+            expected: self.expected,
+            expression_attribute_names: self.expression_attribute_names,
+            expression_attribute_values: self.expression_attribute_values,
+            key: self.key.ok_or(::rusoto_core::BuildError::missing("key"))?,
+            return_consumed_capacity: self.return_consumed_capacity,
+            return_item_collection_metrics: self.return_item_collection_metrics,
+            return_values: self.return_values,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>DeleteItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -762,6 +1839,36 @@ pub struct DeleteTableInput {
     pub table_name: String,
 }
 
+impl DeleteTableInput {
+    /// Returns a builder for `DeleteTableInput`, checking its required members when built.
+    pub fn builder() -> DeleteTableInputBuilder {
+        DeleteTableInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTableInput`](struct.DeleteTableInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteTableInputBuilder {
+    table_name: Option<String>,
+}
+
+impl DeleteTableInputBuilder {
+    /// Sets the [`table_name`](struct.DeleteTableInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTableInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTableInput, ::rusoto_core::BuildError> {
+        Ok(DeleteTableInput {
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>DeleteTable</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -779,6 +1886,36 @@ pub struct DescribeBackupInput {
     pub backup_arn: String,
 }
 
+impl DescribeBackupInput {
+    /// Returns a builder for `DescribeBackupInput`, checking its required members when built.
+    pub fn builder() -> DescribeBackupInputBuilder {
+        DescribeBackupInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBackupInput`](struct.DescribeBackupInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeBackupInputBuilder {
+    backup_arn: Option<String>,
+}
+
+impl DescribeBackupInputBuilder {
+    /// Sets the [`backup_arn`](struct.DescribeBackupInput.html#structfield.backup_arn) member.
+    pub fn backup_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBackupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeBackupInput, ::rusoto_core::BuildError> {
+        Ok(DescribeBackupInput {
+            backup_arn: self
+                .backup_arn
+                .ok_or(::rusoto_core::BuildError::missing("backup_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeBackupOutput {
@@ -795,6 +1932,38 @@ pub struct DescribeContinuousBackupsInput {
     pub table_name: String,
 }
 
+impl DescribeContinuousBackupsInput {
+    /// Returns a builder for `DescribeContinuousBackupsInput`, checking its required members when built.
+    pub fn builder() -> DescribeContinuousBackupsInputBuilder {
+        DescribeContinuousBackupsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeContinuousBackupsInput`](struct.DescribeContinuousBackupsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeContinuousBackupsInputBuilder {
+    table_name: Option<String>,
+}
+
+impl DescribeContinuousBackupsInputBuilder {
+    /// Sets the [`table_name`](struct.DescribeContinuousBackupsInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeContinuousBackupsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeContinuousBackupsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeContinuousBackupsInput {
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeContinuousBackupsOutput {
@@ -822,6 +1991,38 @@ pub struct DescribeGlobalTableInput {
     pub global_table_name: String,
 }
 
+impl DescribeGlobalTableInput {
+    /// Returns a builder for `DescribeGlobalTableInput`, checking its required members when built.
+    pub fn builder() -> DescribeGlobalTableInputBuilder {
+        DescribeGlobalTableInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeGlobalTableInput`](struct.DescribeGlobalTableInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeGlobalTableInputBuilder {
+    global_table_name: Option<String>,
+}
+
+impl DescribeGlobalTableInputBuilder {
+    /// Sets the [`global_table_name`](struct.DescribeGlobalTableInput.html#structfield.global_table_name) member.
+    pub fn global_table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.global_table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeGlobalTableInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeGlobalTableInput, ::rusoto_core::BuildError> {
+        Ok(DescribeGlobalTableInput {
+            global_table_name: self
+                .global_table_name
+                .ok_or(::rusoto_core::BuildError::missing("global_table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeGlobalTableOutput {
@@ -838,6 +2039,38 @@ pub struct DescribeGlobalTableSettingsInput {
     pub global_table_name: String,
 }
 
+impl DescribeGlobalTableSettingsInput {
+    /// Returns a builder for `DescribeGlobalTableSettingsInput`, checking its required members when built.
+    pub fn builder() -> DescribeGlobalTableSettingsInputBuilder {
+        DescribeGlobalTableSettingsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeGlobalTableSettingsInput`](struct.DescribeGlobalTableSettingsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeGlobalTableSettingsInputBuilder {
+    global_table_name: Option<String>,
+}
+
+impl DescribeGlobalTableSettingsInputBuilder {
+    /// Sets the [`global_table_name`](struct.DescribeGlobalTableSettingsInput.html#structfield.global_table_name) member.
+    pub fn global_table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.global_table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeGlobalTableSettingsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeGlobalTableSettingsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeGlobalTableSettingsInput {
+            global_table_name: self
+                .global_table_name
+                .ok_or(::rusoto_core::BuildError::missing("global_table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeGlobalTableSettingsOutput {
@@ -885,6 +2118,36 @@ pub struct DescribeTableInput {
     pub table_name: String,
 }
 
+impl DescribeTableInput {
+    /// Returns a builder for `DescribeTableInput`, checking its required members when built.
+    pub fn builder() -> DescribeTableInputBuilder {
+        DescribeTableInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTableInput`](struct.DescribeTableInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeTableInputBuilder {
+    table_name: Option<String>,
+}
+
+impl DescribeTableInputBuilder {
+    /// Sets the [`table_name`](struct.DescribeTableInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTableInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTableInput, ::rusoto_core::BuildError> {
+        Ok(DescribeTableInput {
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>DescribeTable</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -902,6 +2165,38 @@ pub struct DescribeTimeToLiveInput {
     pub table_name: String,
 }
 
+impl DescribeTimeToLiveInput {
+    /// Returns a builder for `DescribeTimeToLiveInput`, checking its required members when built.
+    pub fn builder() -> DescribeTimeToLiveInputBuilder {
+        DescribeTimeToLiveInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTimeToLiveInput`](struct.DescribeTimeToLiveInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeTimeToLiveInputBuilder {
+    table_name: Option<String>,
+}
+
+impl DescribeTimeToLiveInputBuilder {
+    /// Sets the [`table_name`](struct.DescribeTimeToLiveInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTimeToLiveInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTimeToLiveInput, ::rusoto_core::BuildError> {
+        Ok(DescribeTimeToLiveInput {
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeTimeToLiveOutput {
@@ -933,7 +2228,7 @@ pub struct ExpectedAttributeValue {
     /// <p><p>A comparator for evaluating attributes in the <code>AttributeValueList</code>. For example, equals, greater than, less than, etc.</p> <p>The following comparison operators are available:</p> <p> <code>EQ | NE | LE | LT | GE | GT | NOT<em>NULL | NULL | CONTAINS | NOT</em>CONTAINS | BEGINS<em>WITH | IN | BETWEEN</code> </p> <p>The following are descriptions of each comparison operator.</p> <ul> <li> <p> <code>EQ</code> : Equal. <code>EQ</code> is supported for all data types, including lists and maps.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, Binary, String Set, Number Set, or Binary Set. If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code>.</p> <p/> </li> <li> <p> <code>NE</code> : Not equal. <code>NE</code> is supported for all data types, including lists and maps.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String, Number, Binary, String Set, Number Set, or Binary Set. If an item contains an <code>AttributeValue</code> of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code>.</p> <p/> </li> <li> <p> <code>LE</code> : Less than or equal. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not compare to <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code>.</p> <p/> </li> <li> <p> <code>LT</code> : Less than. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not compare to <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code>.</p> <p/> </li> <li> <p> <code>GE</code> : Greater than or equal. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not compare to <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code>.</p> <p/> </li> <li> <p> <code>GT</code> : Greater than. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not equal <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not compare to <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code>.</p> <p/> </li> <li> <p> <code>NOT</em>NULL</code> : The attribute exists. <code>NOT<em>NULL</code> is supported for all data types, including lists and maps.</p> <note> <p>This operator tests for the existence of an attribute, not its data type. If the data type of attribute &quot;<code>a</code>&quot; is null, and you evaluate it using <code>NOT</em>NULL</code>, the result is a Boolean <code>true</code>. This result is because the attribute &quot;<code>a</code>&quot; exists; its data type is not relevant to the <code>NOT<em>NULL</code> comparison operator.</p> </note> </li> <li> <p> <code>NULL</code> : The attribute does not exist. <code>NULL</code> is supported for all data types, including lists and maps.</p> <note> <p>This operator tests for the nonexistence of an attribute, not its data type. If the data type of attribute &quot;<code>a</code>&quot; is null, and you evaluate it using <code>NULL</code>, the result is a Boolean <code>false</code>. This is because the attribute &quot;<code>a</code>&quot; exists; its data type is not relevant to the <code>NULL</code> comparison operator.</p> </note> </li> <li> <p> <code>CONTAINS</code> : Checks for a subsequence, or value in a set.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If the target attribute of the comparison is of type String, then the operator checks for a substring match. If the target attribute of the comparison is of type Binary, then the operator looks for a subsequence of the target that matches the input. If the target attribute of the comparison is a set (&quot;<code>SS</code>&quot;, &quot;<code>NS</code>&quot;, or &quot;<code>BS</code>&quot;), then the operator evaluates to true if it finds an exact match with any member of the set.</p> <p>CONTAINS is supported for lists: When evaluating &quot;<code>a CONTAINS b</code>&quot;, &quot;<code>a</code>&quot; can be a list; however, &quot;<code>b</code>&quot; cannot be a set, a map, or a list.</p> </li> <li> <p> <code>NOT</em>CONTAINS</code> : Checks for absence of a subsequence, or absence of a value in a set.</p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> element of type String, Number, or Binary (not a set type). If the target attribute of the comparison is a String, then the operator checks for the absence of a substring match. If the target attribute of the comparison is Binary, then the operator checks for the absence of a subsequence of the target that matches the input. If the target attribute of the comparison is a set (&quot;<code>SS</code>&quot;, &quot;<code>NS</code>&quot;, or &quot;<code>BS</code>&quot;), then the operator evaluates to true if it <i>does not</i> find an exact match with any member of the set.</p> <p>NOT<em>CONTAINS is supported for lists: When evaluating &quot;<code>a NOT CONTAINS b</code>&quot;, &quot;<code>a</code>&quot; can be a list; however, &quot;<code>b</code>&quot; cannot be a set, a map, or a list.</p> </li> <li> <p> <code>BEGINS</em>WITH</code> : Checks for a prefix. </p> <p> <code>AttributeValueList</code> can contain only one <code>AttributeValue</code> of type String or Binary (not a Number or a set type). The target attribute of the comparison must be of type String or Binary (not a Number or a set type).</p> <p/> </li> <li> <p> <code>IN</code> : Checks for matching elements in a list.</p> <p> <code>AttributeValueList</code> can contain one or more <code>AttributeValue</code> elements of type String, Number, or Binary. These attributes are compared against an existing attribute of an item. If any elements of the input are equal to the item attribute, the expression evaluates to true.</p> </li> <li> <p> <code>BETWEEN</code> : Greater than or equal to the first value, and less than or equal to the second value. </p> <p> <code>AttributeValueList</code> must contain two <code>AttributeValue</code> elements of the same type, either String, Number, or Binary (not a set type). A target attribute matches if the target value is greater than, or equal to, the first element and less than, or equal to, the second element. If an item contains an <code>AttributeValue</code> element of a different type than the one provided in the request, the value does not match. For example, <code>{&quot;S&quot;:&quot;6&quot;}</code> does not compare to <code>{&quot;N&quot;:&quot;6&quot;}</code>. Also, <code>{&quot;N&quot;:&quot;6&quot;}</code> does not compare to <code>{&quot;NS&quot;:[&quot;6&quot;, &quot;2&quot;, &quot;1&quot;]}</code> </p> </li> </ul></p>
     #[serde(rename = "ComparisonOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison_operator: Option<String>,
+    pub comparison_operator: Option<ComparisonOperator>,
     /// <p><p>Causes DynamoDB to evaluate the value before attempting a conditional operation:</p> <ul> <li> <p>If <code>Exists</code> is <code>true</code>, DynamoDB will check to see if that attribute value already exists in the table. If it is found, then the operation succeeds. If it is not found, the operation fails with a <code>ConditionCheckFailedException</code>.</p> </li> <li> <p>If <code>Exists</code> is <code>false</code>, DynamoDB assumes that the attribute value does not exist in the table. If in fact the value does not exist, then the assumption is valid and the operation succeeds. If the value is found, despite the assumption that it does not exist, the operation fails with a <code>ConditionCheckFailedException</code>.</p> </li> </ul> <p>The default setting for <code>Exists</code> is <code>true</code>. If you supply a <code>Value</code> all by itself, DynamoDB assumes the attribute exists: You don&#39;t have to set <code>Exists</code> to <code>true</code>, because it is implied.</p> <p>DynamoDB returns a <code>ValidationException</code> if:</p> <ul> <li> <p> <code>Exists</code> is <code>true</code> but there is no <code>Value</code> to check. (You expect a value to exist, but don&#39;t specify what that value is.)</p> </li> <li> <p> <code>Exists</code> is <code>false</code> but you also provide a <code>Value</code>. (You cannot expect an attribute to have a value, while also expecting it not to exist.)</p> </li> </ul></p>
     #[serde(rename = "Exists")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -987,12 +2282,96 @@ pub struct GetItemInput {
     pub projection_expression: Option<String>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>The name of the table containing the requested item.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
 }
 
+impl GetItemInput {
+    /// Returns a builder for `GetItemInput`, checking its required members when built.
+    pub fn builder() -> GetItemInputBuilder {
+        GetItemInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetItemInput`](struct.GetItemInput.html).
+#[derive(Default, Debug)]
+pub struct GetItemInputBuilder {
+    attributes_to_get: Option<Vec<String>>,
+    consistent_read: Option<bool>,
+    expression_attribute_names: Option<::std::collections::HashMap<String, String>>,
+    key: Option<::std::collections::HashMap<String, AttributeValue>>,
+    projection_expression: Option<String>,
+    return_consumed_capacity: Option<ReturnConsumedCapacity>,
+    table_name: Option<String>,
+}
+
+impl GetItemInputBuilder {
+    /// Sets the [`attributes_to_get`](struct.GetItemInput.html#structfield.attributes_to_get) member.
+    pub fn attributes_to_get<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.attributes_to_get = Some(value.into());
+        self
+    }
+
+    /// Sets the [`consistent_read`](struct.GetItemInput.html#structfield.consistent_read) member.
+    pub fn consistent_read<T: Into<bool>>(mut self, value: T) -> Self {
+        self.consistent_read = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_attribute_names`](struct.GetItemInput.html#structfield.expression_attribute_names) member.
+    pub fn expression_attribute_names<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expression_attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key`](struct.GetItemInput.html#structfield.key) member.
+    pub fn key<T: Into<::std::collections::HashMap<String, AttributeValue>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`projection_expression`](struct.GetItemInput.html#structfield.projection_expression) member.
+    pub fn projection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.projection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_consumed_capacity`](struct.GetItemInput.html#structfield.return_consumed_capacity) member.
+    pub fn return_consumed_capacity<T: Into<ReturnConsumedCapacity>>(mut self, value: T) -> Self {
+        self.return_consumed_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.GetItemInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetItemInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetItemInput, ::rusoto_core::BuildError> {
+        Ok(GetItemInput {
+            attributes_to_get: self.attributes_to_get,
+            consistent_read: self.consistent_read,
+            expression_attribute_names: self.expression_attribute_names,
+            key: self.key.ok_or(::rusoto_core::BuildError::missing("key"))?,
+            projection_expression: self.projection_expression,
+            return_consumed_capacity: self.return_consumed_capacity,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>GetItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1048,7 +2427,7 @@ pub struct GlobalSecondaryIndexDescription {
     /// <p><p>The current state of the global secondary index:</p> <ul> <li> <p> <code>CREATING</code> - The index is being created.</p> </li> <li> <p> <code>UPDATING</code> - The index is being updated.</p> </li> <li> <p> <code>DELETING</code> - The index is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The index is ready for use.</p> </li> </ul></p>
     #[serde(rename = "IndexStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_status: Option<String>,
+    pub index_status: Option<IndexStatus>,
     /// <p>The number of items in the specified index. DynamoDB updates this value approximately every six hours. Recent changes might not be reflected in this value.</p>
     #[serde(rename = "ItemCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1126,8 +2505,9 @@ pub struct GlobalTable {
 pub struct GlobalTableDescription {
     /// <p>The creation time of the global table.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The unique identifier of the global table.</p>
     #[serde(rename = "GlobalTableArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1139,7 +2519,7 @@ pub struct GlobalTableDescription {
     /// <p><p>The current state of the global table:</p> <ul> <li> <p> <code>CREATING</code> - The global table is being created.</p> </li> <li> <p> <code>UPDATING</code> - The global table is being updated.</p> </li> <li> <p> <code>DELETING</code> - The global table is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The global table is ready for use.</p> </li> </ul></p>
     #[serde(rename = "GlobalTableStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub global_table_status: Option<String>,
+    pub global_table_status: Option<GlobalTableStatus>,
     /// <p>The regions where the global table has replicas.</p>
     #[serde(rename = "ReplicationGroup")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1162,54 +2542,286 @@ pub struct GlobalTableGlobalSecondaryIndexSettingsUpdate {
     pub provisioned_write_capacity_units: Option<i64>,
 }
 
-/// <p>Information about item collections, if any, that were affected by the operation. <code>ItemCollectionMetrics</code> is only returned if the request asked for it. If the table does not have any local secondary indexes, this information is not returned in the response.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ItemCollectionMetrics {
-    /// <p>The partition key value of the item collection. This value is the same as the partition key value of the item.</p>
-    #[serde(rename = "ItemCollectionKey")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub item_collection_key: Option<::std::collections::HashMap<String, AttributeValue>>,
-    /// <p>An estimate of item collection size, in gigabytes. This value is a two-element array containing a lower bound and an upper bound for the estimate. The estimate includes the size of all the items in the table, plus the size of all attributes projected into all of the local secondary indexes on that table. Use this estimate to measure whether a local secondary index is approaching its size limit.</p> <p>The estimate is subject to change over time; therefore, do not rely on the precision or accuracy of the estimate.</p>
-    #[serde(rename = "SizeEstimateRangeGB")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub size_estimate_range_gb: Option<Vec<f64>>,
+/// <p><p>The current state of the global table:</p> <ul> <li> <p> <code>CREATING</code> - The global table is being created.</p> </li> <li> <p> <code>UPDATING</code> - The global table is being updated.</p> </li> <li> <p> <code>DELETING</code> - The global table is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The global table is ready for use.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GlobalTableStatus {
+    /// <p><code>CREATING</code> - The global table is being created.</p>
+    Creating,
+    /// <p><code>ACTIVE</code> - The global table is ready for use.</p>
+    Active,
+    /// <p><code>DELETING</code> - The global table is being deleted.</p>
+    Deleting,
+    /// <p><code>UPDATING</code> - The global table is being updated.</p>
+    Updating,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl GlobalTableStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GlobalTableStatus::Creating => "CREATING",
+            GlobalTableStatus::Active => "ACTIVE",
+            GlobalTableStatus::Deleting => "DELETING",
+            GlobalTableStatus::Updating => "UPDATING",
+            GlobalTableStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Details for the requested item.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ItemResponse {
-    /// <p>Map of attribute data consisting of the data type and attribute value.</p>
-    #[serde(rename = "Item")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub item: Option<::std::collections::HashMap<String, AttributeValue>>,
+impl<'a> From<&'a str> for GlobalTableStatus {
+    fn from(value: &'a str) -> GlobalTableStatus {
+        match value {
+            "CREATING" => GlobalTableStatus::Creating,
+            "ACTIVE" => GlobalTableStatus::Active,
+            "DELETING" => GlobalTableStatus::Deleting,
+            "UPDATING" => GlobalTableStatus::Updating,
+            _ => GlobalTableStatus::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>Represents <i>a single element</i> of a key schema. A key schema specifies the attributes that make up the primary key of a table, or the key attributes of an index.</p> <p>A <code>KeySchemaElement</code> represents exactly one attribute of the primary key. For example, a simple primary key would be represented by one <code>KeySchemaElement</code> (for the partition key). A composite primary key would require one <code>KeySchemaElement</code> for the partition key, and another <code>KeySchemaElement</code> for the sort key.</p> <p>A <code>KeySchemaElement</code> must be a scalar, top-level attribute (not a nested attribute). The data type must be one of String, Number, or Binary. The attribute cannot be nested within a List or a Map.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct KeySchemaElement {
-    /// <p>The name of a key attribute.</p>
-    #[serde(rename = "AttributeName")]
-    pub attribute_name: String,
-    /// <p><p>The role that this key attribute will assume:</p> <ul> <li> <p> <code>HASH</code> - partition key</p> </li> <li> <p> <code>RANGE</code> - sort key</p> </li> </ul> <note> <p>The partition key of an item is also known as its <i>hash attribute</i>. The term &quot;hash attribute&quot; derives from DynamoDB&#39; usage of an internal hash function to evenly distribute data items across partitions, based on their partition key values.</p> <p>The sort key of an item is also known as its <i>range attribute</i>. The term &quot;range attribute&quot; derives from the way DynamoDB stores items with the same partition key physically close together, in sorted order by the sort key value.</p> </note></p>
-    #[serde(rename = "KeyType")]
-    pub key_type: String,
+impl From<String> for GlobalTableStatus {
+    fn from(value: String) -> GlobalTableStatus {
+        GlobalTableStatus::from(value.as_str())
+    }
 }
 
-/// <p>Represents a set of primary keys and, for each key, the attributes to retrieve from the table.</p> <p>For each primary key, you must provide <i>all</i> of the key attributes. For example, with a simple primary key, you only need to provide the partition key. For a composite primary key, you must provide <i>both</i> the partition key and the sort key.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct KeysAndAttributes {
-    /// <p>This is a legacy parameter. Use <code>ProjectionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.html">Legacy Conditional Parameters</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    #[serde(rename = "AttributesToGet")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes_to_get: Option<Vec<String>>,
-    /// <p>The consistency of a read operation. If set to <code>true</code>, then a strongly consistent read is used; otherwise, an eventually consistent read is used.</p>
-    #[serde(rename = "ConsistentRead")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistent_read: Option<bool>,
-    /// <p>One or more substitution tokens for attribute names in an expression. The following are some use cases for using <code>ExpressionAttributeNames</code>:</p> <ul> <li> <p>To access an attribute whose name conflicts with a DynamoDB reserved word.</p> </li> <li> <p>To create a placeholder for repeating occurrences of an attribute name in an expression.</p> </li> <li> <p>To prevent special characters in an attribute name from being misinterpreted in an expression.</p> </li> </ul> <p>Use the <b>#</b> character in an expression to dereference an attribute name. For example, consider the following attribute name:</p> <ul> <li> <p> <code>Percentile</code> </p> </li> </ul> <p>The name of this attribute conflicts with a reserved word, so it cannot be used directly in an expression. (For the complete list of reserved words, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ReservedWords.html">Reserved Words</a> in the <i>Amazon DynamoDB Developer Guide</i>). To work around this, you could specify the following for <code>ExpressionAttributeNames</code>:</p> <ul> <li> <p> <code>{"#P":"Percentile"}</code> </p> </li> </ul> <p>You could then use this substitution in an expression, as in this example:</p> <ul> <li> <p> <code>#P = :val</code> </p> </li> </ul> <note> <p>Tokens that begin with the <b>:</b> character are <i>expression attribute values</i>, which are placeholders for the actual value at runtime.</p> </note> <p>For more information on expression attribute names, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.AccessingItemAttributes.html">Accessing Item Attributes</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    #[serde(rename = "ExpressionAttributeNames")]
+impl From<GlobalTableStatus> for String {
+    fn from(value: GlobalTableStatus) -> String {
+        match value {
+            GlobalTableStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GlobalTableStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for GlobalTableStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GlobalTableStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<GlobalTableStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(GlobalTableStatus::from(value))
+    }
+}
+
+/// <p><p>The current state of the global secondary index:</p> <ul> <li> <p> <code>CREATING</code> - The index is being created.</p> </li> <li> <p> <code>UPDATING</code> - The index is being updated.</p> </li> <li> <p> <code>DELETING</code> - The index is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The index is ready for use.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IndexStatus {
+    /// <p><code>CREATING</code> - The index is being created.</p>
+    Creating,
+    /// <p><code>UPDATING</code> - The index is being updated.</p>
+    Updating,
+    /// <p><code>DELETING</code> - The index is being deleted.</p>
+    Deleting,
+    /// <p><code>ACTIVE</code> - The index is ready for use.</p>
+    Active,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IndexStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IndexStatus::Creating => "CREATING",
+            IndexStatus::Updating => "UPDATING",
+            IndexStatus::Deleting => "DELETING",
+            IndexStatus::Active => "ACTIVE",
+            IndexStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IndexStatus {
+    fn from(value: &'a str) -> IndexStatus {
+        match value {
+            "CREATING" => IndexStatus::Creating,
+            "UPDATING" => IndexStatus::Updating,
+            "DELETING" => IndexStatus::Deleting,
+            "ACTIVE" => IndexStatus::Active,
+            _ => IndexStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IndexStatus {
+    fn from(value: String) -> IndexStatus {
+        IndexStatus::from(value.as_str())
+    }
+}
+
+impl From<IndexStatus> for String {
+    fn from(value: IndexStatus) -> String {
+        match value {
+            IndexStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IndexStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for IndexStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IndexStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IndexStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IndexStatus::from(value))
+    }
+}
+
+/// <p>Information about item collections, if any, that were affected by the operation. <code>ItemCollectionMetrics</code> is only returned if the request asked for it. If the table does not have any local secondary indexes, this information is not returned in the response.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ItemCollectionMetrics {
+    /// <p>The partition key value of the item collection. This value is the same as the partition key value of the item.</p>
+    #[serde(rename = "ItemCollectionKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_collection_key: Option<::std::collections::HashMap<String, AttributeValue>>,
+    /// <p>An estimate of item collection size, in gigabytes. This value is a two-element array containing a lower bound and an upper bound for the estimate. The estimate includes the size of all the items in the table, plus the size of all attributes projected into all of the local secondary indexes on that table. Use this estimate to measure whether a local secondary index is approaching its size limit.</p> <p>The estimate is subject to change over time; therefore, do not rely on the precision or accuracy of the estimate.</p>
+    #[serde(rename = "SizeEstimateRangeGB")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_estimate_range_gb: Option<Vec<f64>>,
+}
+
+/// <p>Details for the requested item.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ItemResponse {
+    /// <p>Map of attribute data consisting of the data type and attribute value.</p>
+    #[serde(rename = "Item")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<::std::collections::HashMap<String, AttributeValue>>,
+}
+
+/// <p>Represents <i>a single element</i> of a key schema. A key schema specifies the attributes that make up the primary key of a table, or the key attributes of an index.</p> <p>A <code>KeySchemaElement</code> represents exactly one attribute of the primary key. For example, a simple primary key would be represented by one <code>KeySchemaElement</code> (for the partition key). A composite primary key would require one <code>KeySchemaElement</code> for the partition key, and another <code>KeySchemaElement</code> for the sort key.</p> <p>A <code>KeySchemaElement</code> must be a scalar, top-level attribute (not a nested attribute). The data type must be one of String, Number, or Binary. The attribute cannot be nested within a List or a Map.</p>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeySchemaElement {
+    /// <p>The name of a key attribute.</p>
+    #[serde(rename = "AttributeName")]
+    pub attribute_name: String,
+    /// <p><p>The role that this key attribute will assume:</p> <ul> <li> <p> <code>HASH</code> - partition key</p> </li> <li> <p> <code>RANGE</code> - sort key</p> </li> </ul> <note> <p>The partition key of an item is also known as its <i>hash attribute</i>. The term &quot;hash attribute&quot; derives from DynamoDB&#39; usage of an internal hash function to evenly distribute data items across partitions, based on their partition key values.</p> <p>The sort key of an item is also known as its <i>range attribute</i>. The term &quot;range attribute&quot; derives from the way DynamoDB stores items with the same partition key physically close together, in sorted order by the sort key value.</p> </note></p>
+    #[serde(rename = "KeyType")]
+    pub key_type: KeyType,
+}
+
+/// <p><p>The role that this key attribute will assume:</p> <ul> <li> <p> <code>HASH</code> - partition key</p> </li> <li> <p> <code>RANGE</code> - sort key</p> </li> </ul> <note> <p>The partition key of an item is also known as its <i>hash attribute</i>. The term &quot;hash attribute&quot; derives from DynamoDB&#39; usage of an internal hash function to evenly distribute data items across partitions, based on their partition key values.</p> <p>The sort key of an item is also known as its <i>range attribute</i>. The term &quot;range attribute&quot; derives from the way DynamoDB stores items with the same partition key physically close together, in sorted order by the sort key value.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeyType {
+    /// <p><code>HASH</code> - partition key</p>
+    Hash,
+    /// <p><code>RANGE</code> - sort key</p>
+    Range,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl KeyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyType::Hash => "HASH",
+            KeyType::Range => "RANGE",
+            KeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for KeyType {
+    fn from(value: &'a str) -> KeyType {
+        match value {
+            "HASH" => KeyType::Hash,
+            "RANGE" => KeyType::Range,
+            _ => KeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyType {
+    fn from(value: String) -> KeyType {
+        KeyType::from(value.as_str())
+    }
+}
+
+impl From<KeyType> for String {
+    fn from(value: KeyType) -> String {
+        match value {
+            KeyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for KeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for KeyType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<KeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(KeyType::from(value))
+    }
+}
+
+/// <p>Represents a set of primary keys and, for each key, the attributes to retrieve from the table.</p> <p>For each primary key, you must provide <i>all</i> of the key attributes. For example, with a simple primary key, you only need to provide the partition key. For a composite primary key, you must provide <i>both</i> the partition key and the sort key.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeysAndAttributes {
+    /// <p>This is a legacy parameter. Use <code>ProjectionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.html">Legacy Conditional Parameters</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    #[serde(rename = "AttributesToGet")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes_to_get: Option<Vec<String>>,
+    /// <p>The consistency of a read operation. If set to <code>true</code>, then a strongly consistent read is used; otherwise, an eventually consistent read is used.</p>
+    #[serde(rename = "ConsistentRead")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consistent_read: Option<bool>,
+    /// <p>One or more substitution tokens for attribute names in an expression. The following are some use cases for using <code>ExpressionAttributeNames</code>:</p> <ul> <li> <p>To access an attribute whose name conflicts with a DynamoDB reserved word.</p> </li> <li> <p>To create a placeholder for repeating occurrences of an attribute name in an expression.</p> </li> <li> <p>To prevent special characters in an attribute name from being misinterpreted in an expression.</p> </li> </ul> <p>Use the <b>#</b> character in an expression to dereference an attribute name. For example, consider the following attribute name:</p> <ul> <li> <p> <code>Percentile</code> </p> </li> </ul> <p>The name of this attribute conflicts with a reserved word, so it cannot be used directly in an expression. (For the complete list of reserved words, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ReservedWords.html">Reserved Words</a> in the <i>Amazon DynamoDB Developer Guide</i>). To work around this, you could specify the following for <code>ExpressionAttributeNames</code>:</p> <ul> <li> <p> <code>{"#P":"Percentile"}</code> </p> </li> </ul> <p>You could then use this substitution in an expression, as in this example:</p> <ul> <li> <p> <code>#P = :val</code> </p> </li> </ul> <note> <p>Tokens that begin with the <b>:</b> character are <i>expression attribute values</i>, which are placeholders for the actual value at runtime.</p> </note> <p>For more information on expression attribute names, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.AccessingItemAttributes.html">Accessing Item Attributes</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    #[serde(rename = "ExpressionAttributeNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expression_attribute_names: Option<::std::collections::HashMap<String, String>>,
     /// <p>The primary key attribute values that define the items and the attributes associated with the items.</p>
@@ -1226,7 +2838,7 @@ pub struct ListBackupsInput {
     /// <p><p>The backups from the table specified by <code>BackupType</code> are listed.</p> <p>Where <code>BackupType</code> can be:</p> <ul> <li> <p> <code>USER</code> - On-demand backup created by you.</p> </li> <li> <p> <code>SYSTEM</code> - On-demand backup automatically created by DynamoDB.</p> </li> <li> <p> <code>ALL</code> - All types of on-demand backups (USER and SYSTEM).</p> </li> </ul></p>
     #[serde(rename = "BackupType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_type: Option<String>,
+    pub backup_type: Option<BackupTypeFilter>,
     /// <p> <code>LastEvaluatedBackupArn</code> is the Amazon Resource Name (ARN) of the backup last evaluated when the current page of results was returned, inclusive of the current page of results. This value may be specified as the <code>ExclusiveStartBackupArn</code> of a new <code>ListBackups</code> operation in order to fetch the next page of results. </p>
     #[serde(rename = "ExclusiveStartBackupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1241,12 +2853,82 @@ pub struct ListBackupsInput {
     pub table_name: Option<String>,
     /// <p>Only backups created after this time are listed. <code>TimeRangeLowerBound</code> is inclusive.</p>
     #[serde(rename = "TimeRangeLowerBound")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_range_lower_bound: Option<f64>,
+    pub time_range_lower_bound: Option<EpochTimestamp>,
     /// <p>Only backups created before this time are listed. <code>TimeRangeUpperBound</code> is exclusive. </p>
     #[serde(rename = "TimeRangeUpperBound")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_range_upper_bound: Option<f64>,
+    pub time_range_upper_bound: Option<EpochTimestamp>,
+}
+
+impl ListBackupsInput {
+    /// Returns a builder for `ListBackupsInput`, checking its required members when built.
+    pub fn builder() -> ListBackupsInputBuilder {
+        ListBackupsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListBackupsInput`](struct.ListBackupsInput.html).
+#[derive(Default, Debug)]
+pub struct ListBackupsInputBuilder {
+    backup_type: Option<BackupTypeFilter>,
+    exclusive_start_backup_arn: Option<String>,
+    limit: Option<i64>,
+    table_name: Option<String>,
+    time_range_lower_bound: Option<EpochTimestamp>,
+    time_range_upper_bound: Option<EpochTimestamp>,
+}
+
+impl ListBackupsInputBuilder {
+    /// Sets the [`backup_type`](struct.ListBackupsInput.html#structfield.backup_type) member.
+    pub fn backup_type<T: Into<BackupTypeFilter>>(mut self, value: T) -> Self {
+        self.backup_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exclusive_start_backup_arn`](struct.ListBackupsInput.html#structfield.exclusive_start_backup_arn) member.
+    pub fn exclusive_start_backup_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_backup_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListBackupsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.ListBackupsInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_range_lower_bound`](struct.ListBackupsInput.html#structfield.time_range_lower_bound) member.
+    pub fn time_range_lower_bound<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.time_range_lower_bound = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_range_upper_bound`](struct.ListBackupsInput.html#structfield.time_range_upper_bound) member.
+    pub fn time_range_upper_bound<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.time_range_upper_bound = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBackupsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListBackupsInput, ::rusoto_core::BuildError> {
+        Ok(ListBackupsInput {
+            backup_type: self.backup_type,
+            exclusive_start_backup_arn: self.exclusive_start_backup_arn,
+            limit: self.limit,
+            table_name: self.table_name,
+            time_range_lower_bound: self.time_range_lower_bound,
+            time_range_upper_bound: self.time_range_upper_bound,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1278,6 +2960,50 @@ pub struct ListGlobalTablesInput {
     pub region_name: Option<String>,
 }
 
+impl ListGlobalTablesInput {
+    /// Returns a builder for `ListGlobalTablesInput`, checking its required members when built.
+    pub fn builder() -> ListGlobalTablesInputBuilder {
+        ListGlobalTablesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListGlobalTablesInput`](struct.ListGlobalTablesInput.html).
+#[derive(Default, Debug)]
+pub struct ListGlobalTablesInputBuilder {
+    exclusive_start_global_table_name: Option<String>,
+    limit: Option<i64>,
+    region_name: Option<String>,
+}
+
+impl ListGlobalTablesInputBuilder {
+    /// Sets the [`exclusive_start_global_table_name`](struct.ListGlobalTablesInput.html#structfield.exclusive_start_global_table_name) member.
+    pub fn exclusive_start_global_table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_global_table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListGlobalTablesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`region_name`](struct.ListGlobalTablesInput.html#structfield.region_name) member.
+    pub fn region_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.region_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGlobalTablesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListGlobalTablesInput, ::rusoto_core::BuildError> {
+        Ok(ListGlobalTablesInput {
+            exclusive_start_global_table_name: self.exclusive_start_global_table_name,
+            limit: self.limit,
+            region_name: self.region_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGlobalTablesOutput {
@@ -1304,6 +3030,42 @@ pub struct ListTablesInput {
     pub limit: Option<i64>,
 }
 
+impl ListTablesInput {
+    /// Returns a builder for `ListTablesInput`, checking its required members when built.
+    pub fn builder() -> ListTablesInputBuilder {
+        ListTablesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTablesInput`](struct.ListTablesInput.html).
+#[derive(Default, Debug)]
+pub struct ListTablesInputBuilder {
+    exclusive_start_table_name: Option<String>,
+    limit: Option<i64>,
+}
+
+impl ListTablesInputBuilder {
+    /// Sets the [`exclusive_start_table_name`](struct.ListTablesInput.html#structfield.exclusive_start_table_name) member.
+    pub fn exclusive_start_table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListTablesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTablesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTablesInput, ::rusoto_core::BuildError> {
+        Ok(ListTablesInput {
+            exclusive_start_table_name: self.exclusive_start_table_name,
+            limit: self.limit,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>ListTables</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1329,6 +3091,46 @@ pub struct ListTagsOfResourceInput {
     pub resource_arn: String,
 }
 
+impl ListTagsOfResourceInput {
+    /// Returns a builder for `ListTagsOfResourceInput`, checking its required members when built.
+    pub fn builder() -> ListTagsOfResourceInputBuilder {
+        ListTagsOfResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsOfResourceInput`](struct.ListTagsOfResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsOfResourceInputBuilder {
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsOfResourceInputBuilder {
+    /// Sets the [`next_token`](struct.ListTagsOfResourceInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsOfResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsOfResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsOfResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsOfResourceInput {
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsOfResourceOutput {
@@ -1410,16 +3212,18 @@ pub struct LocalSecondaryIndexInfo {
 pub struct PointInTimeRecoveryDescription {
     /// <p>Specifies the earliest point in time you can restore your table to. It You can restore your table to any point in time during the last 35 days. </p>
     #[serde(rename = "EarliestRestorableDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub earliest_restorable_date_time: Option<f64>,
+    pub earliest_restorable_date_time: Option<EpochTimestamp>,
     /// <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. </p>
     #[serde(rename = "LatestRestorableDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_restorable_date_time: Option<f64>,
+    pub latest_restorable_date_time: Option<EpochTimestamp>,
     /// <p><p>The current state of point in time recovery:</p> <ul> <li> <p> <code>ENABLING</code> - Point in time recovery is being enabled.</p> </li> <li> <p> <code>ENABLED</code> - Point in time recovery is enabled.</p> </li> <li> <p> <code>DISABLED</code> - Point in time recovery is disabled.</p> </li> </ul></p>
     #[serde(rename = "PointInTimeRecoveryStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub point_in_time_recovery_status: Option<String>,
+    pub point_in_time_recovery_status: Option<PointInTimeRecoveryStatus>,
 }
 
 /// <p>Represents the settings used to enable point in time recovery.</p>
@@ -1430,6 +3234,78 @@ pub struct PointInTimeRecoverySpecification {
     pub point_in_time_recovery_enabled: bool,
 }
 
+/// <p><p>The current state of point in time recovery:</p> <ul> <li> <p> <code>ENABLING</code> - Point in time recovery is being enabled.</p> </li> <li> <p> <code>ENABLED</code> - Point in time recovery is enabled.</p> </li> <li> <p> <code>DISABLED</code> - Point in time recovery is disabled.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PointInTimeRecoveryStatus {
+    /// <p><code>ENABLED</code> - Point in time recovery is enabled.</p>
+    Enabled,
+    /// <p><code>DISABLED</code> - Point in time recovery is disabled.</p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PointInTimeRecoveryStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PointInTimeRecoveryStatus::Enabled => "ENABLED",
+            PointInTimeRecoveryStatus::Disabled => "DISABLED",
+            PointInTimeRecoveryStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PointInTimeRecoveryStatus {
+    fn from(value: &'a str) -> PointInTimeRecoveryStatus {
+        match value {
+            "ENABLED" => PointInTimeRecoveryStatus::Enabled,
+            "DISABLED" => PointInTimeRecoveryStatus::Disabled,
+            _ => PointInTimeRecoveryStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PointInTimeRecoveryStatus {
+    fn from(value: String) -> PointInTimeRecoveryStatus {
+        PointInTimeRecoveryStatus::from(value.as_str())
+    }
+}
+
+impl From<PointInTimeRecoveryStatus> for String {
+    fn from(value: PointInTimeRecoveryStatus) -> String {
+        match value {
+            PointInTimeRecoveryStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PointInTimeRecoveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PointInTimeRecoveryStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PointInTimeRecoveryStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PointInTimeRecoveryStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PointInTimeRecoveryStatus::from(value))
+    }
+}
+
 /// <p>Represents attributes that are copied (projected) from the table into an index. These are in addition to the primary key attributes and index key attributes, which are automatically projected.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Projection {
@@ -1440,7 +3316,83 @@ pub struct Projection {
     /// <p><p>The set of attributes that are projected into the index:</p> <ul> <li> <p> <code>KEYS_ONLY</code> - Only the index and primary keys are projected into the index.</p> </li> <li> <p> <code>INCLUDE</code> - Only the specified table attributes are projected into the index. The list of projected attributes are in <code>NonKeyAttributes</code>.</p> </li> <li> <p> <code>ALL</code> - All of the table attributes are projected into the index.</p> </li> </ul></p>
     #[serde(rename = "ProjectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub projection_type: Option<String>,
+    pub projection_type: Option<ProjectionType>,
+}
+
+/// <p><p>The set of attributes that are projected into the index:</p> <ul> <li> <p> <code>KEYS_ONLY</code> - Only the index and primary keys are projected into the index.</p> </li> <li> <p> <code>INCLUDE</code> - Only the specified table attributes are projected into the index. The list of projected attributes are in <code>NonKeyAttributes</code>.</p> </li> <li> <p> <code>ALL</code> - All of the table attributes are projected into the index.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProjectionType {
+    /// <p><code>ALL</code> - All of the table attributes are projected into the index.</p>
+    All,
+    /// <p><code>KEYS_ONLY</code> - Only the index and primary keys are projected into the index.</p>
+    KeysOnly,
+    /// <p><code>INCLUDE</code> - Only the specified table attributes are projected into the index. The list of projected attributes are in <code>NonKeyAttributes</code>.</p>
+    Include,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProjectionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProjectionType::All => "ALL",
+            ProjectionType::KeysOnly => "KEYS_ONLY",
+            ProjectionType::Include => "INCLUDE",
+            ProjectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProjectionType {
+    fn from(value: &'a str) -> ProjectionType {
+        match value {
+            "ALL" => ProjectionType::All,
+            "KEYS_ONLY" => ProjectionType::KeysOnly,
+            "INCLUDE" => ProjectionType::Include,
+            _ => ProjectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProjectionType {
+    fn from(value: String) -> ProjectionType {
+        ProjectionType::from(value.as_str())
+    }
+}
+
+impl From<ProjectionType> for String {
+    fn from(value: ProjectionType) -> String {
+        match value {
+            ProjectionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProjectionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProjectionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProjectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProjectionType::from(value))
+    }
 }
 
 /// <p>Represents the provisioned throughput settings for a specified table or index. The settings can be modified using the <code>UpdateTable</code> operation.</p> <p>For current minimum and maximum provisioned throughput values, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Limits.html">Limits</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
@@ -1460,12 +3412,14 @@ pub struct ProvisionedThroughput {
 pub struct ProvisionedThroughputDescription {
     /// <p>The date and time of the last provisioned throughput decrease for this table.</p>
     #[serde(rename = "LastDecreaseDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_decrease_date_time: Option<f64>,
+    pub last_decrease_date_time: Option<EpochTimestamp>,
     /// <p>The date and time of the last provisioned throughput increase for this table.</p>
     #[serde(rename = "LastIncreaseDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_increase_date_time: Option<f64>,
+    pub last_increase_date_time: Option<EpochTimestamp>,
     /// <p>The number of provisioned throughput decreases for this table during this UTC calendar day. For current maximums on provisioned throughput decreases, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Limits.html">Limits</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "NumberOfDecreasesToday")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1501,7 +3455,7 @@ pub struct Put {
     /// <p>Use <code>ReturnValuesOnConditionCheckFailure</code> to get the item attributes if the <code>Put</code> condition fails. For <code>ReturnValuesOnConditionCheckFailure</code>, the valid values are: NONE and ALL_OLD.</p>
     #[serde(rename = "ReturnValuesOnConditionCheckFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values_on_condition_check_failure: Option<String>,
+    pub return_values_on_condition_check_failure: Option<ReturnValuesOnConditionCheckFailure>,
     /// <p>Name of the table in which to write the item.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -1517,7 +3471,7 @@ pub struct PutItemInput {
     /// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ConditionalOperator.html">ConditionalOperator</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConditionalOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conditional_operator: Option<String>,
+    pub conditional_operator: Option<ConditionalOperator>,
     /// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.Expected.html">Expected</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "Expected")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1535,22 +3489,143 @@ pub struct PutItemInput {
     pub item: ::std::collections::HashMap<String, AttributeValue>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>Determines whether item collection metrics are returned. If set to <code>SIZE</code>, the response includes statistics about item collections, if any, that were modified during the operation are returned in the response. If set to <code>NONE</code> (the default), no statistics are returned.</p>
     #[serde(rename = "ReturnItemCollectionMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_item_collection_metrics: Option<String>,
+    pub return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
     /// <p><p>Use <code>ReturnValues</code> if you want to get the item attributes as they appeared before they were updated with the <code>PutItem</code> request. For <code>PutItem</code>, the valid values are:</p> <ul> <li> <p> <code>NONE</code> - If <code>ReturnValues</code> is not specified, or if its value is <code>NONE</code>, then nothing is returned. (This setting is the default for <code>ReturnValues</code>.)</p> </li> <li> <p> <code>ALL<em>OLD</code> - If <code>PutItem</code> overwrote an attribute name-value pair, then the content of the old item is returned.</p> </li> </ul> <note> <p>The <code>ReturnValues</code> parameter is used by several DynamoDB operations; however, <code>PutItem</code> does not recognize any values other than <code>NONE</code> or <code>ALL</em>OLD</code>.</p> </note></p>
     #[serde(rename = "ReturnValues")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values: Option<String>,
+    pub return_values: Option<ReturnValue>,
     /// <p>The name of the table to contain the item.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
 }
 
-/// <p>Represents the output of a <code>PutItem</code> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl PutItemInput {
+    /// Returns a builder for `PutItemInput`, checking its required members when built.
+    pub fn builder() -> PutItemInputBuilder {
+        PutItemInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutItemInput`](struct.PutItemInput.html).
+#[derive(Default, Debug)]
+pub struct PutItemInputBuilder {
+    condition_expression: Option<String>,
+    conditional_operator: Option<ConditionalOperator>,
+    expected: Option<::std::collections::HashMap<String, ExpectedAttributeValue>>,
+    expression_attribute_names: Option<::std::collections::HashMap<String, String>>,
+    expression_attribute_values: Option<::std::collections::HashMap<String, AttributeValue>>,
+    item: Option<::std::collections::HashMap<String, AttributeValue>>,
+    return_consumed_capacity: Option<ReturnConsumedCapacity>,
+    return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
+    return_values: Option<ReturnValue>,
+    table_name: Option<String>,
+}
+
+impl PutItemInputBuilder {
+    /// Sets the [`condition_expression`](struct.PutItemInput.html#structfield.condition_expression) member.
+    pub fn condition_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.condition_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conditional_operator`](struct.PutItemInput.html#structfield.conditional_operator) member.
+    pub fn conditional_operator<T: Into<ConditionalOperator>>(mut self, value: T) -> Self {
+        self.conditional_operator = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expected`](struct.PutItemInput.html#structfield.expected) member.
+    pub fn expected<T: Into<::std::collections::HashMap<String, ExpectedAttributeValue>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expected = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_attribute_names`](struct.PutItemInput.html#structfield.expression_attribute_names) member.
+    pub fn expression_attribute_names<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expression_attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_attribute_values`](struct.PutItemInput.html#structfield.expression_attribute_values) member.
+    pub fn expression_attribute_values<
+        T: Into<::std::collections::HashMap<String, AttributeValue>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expression_attribute_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`item`](struct.PutItemInput.html#structfield.item) member.
+    pub fn item<T: Into<::std::collections::HashMap<String, AttributeValue>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.item = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_consumed_capacity`](struct.PutItemInput.html#structfield.return_consumed_capacity) member.
+    pub fn return_consumed_capacity<T: Into<ReturnConsumedCapacity>>(mut self, value: T) -> Self {
+        self.return_consumed_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_item_collection_metrics`](struct.PutItemInput.html#structfield.return_item_collection_metrics) member.
+    pub fn return_item_collection_metrics<T: Into<ReturnItemCollectionMetrics>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.return_item_collection_metrics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_values`](struct.PutItemInput.html#structfield.return_values) member.
+    pub fn return_values<T: Into<ReturnValue>>(mut self, value: T) -> Self {
+        self.return_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.PutItemInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutItemInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutItemInput, ::rusoto_core::BuildError> {
+        Ok(PutItemInput {
+            condition_expression: self.condition_expression,
+            conditional_operator: self.conditional_operator,
+            expected: self.expected,
+            expression_attribute_names: self.expression_attribute_names,
+            expression_attribute_values: self.expression_attribute_values,
+            item: self
+                .item
+                .ok_or(::rusoto_core::BuildError::missing("item"))?,
+            return_consumed_capacity: self.return_consumed_capacity,
+            return_item_collection_metrics: self.return_item_collection_metrics,
+            return_values: self.return_values,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
+/// <p>Represents the output of a <code>PutItem</code> operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutItemOutput {
     /// <p>The attribute values as they appeared before the <code>PutItem</code> operation, but only if <code>ReturnValues</code> is specified as <code>ALL_OLD</code> in the request. Each element consists of an attribute name and an attribute value.</p>
@@ -1585,7 +3660,7 @@ pub struct QueryInput {
     /// <p>This is a legacy parameter. Use <code>FilterExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ConditionalOperator.html">ConditionalOperator</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConditionalOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conditional_operator: Option<String>,
+    pub conditional_operator: Option<ConditionalOperator>,
     /// <p>Determines the read consistency model: If set to <code>true</code>, then the operation uses strongly consistent reads; otherwise, the operation uses eventually consistent reads.</p> <p>Strongly consistent reads are not supported on global secondary indexes. If you query a global secondary index with <code>ConsistentRead</code> set to <code>true</code>, you will receive a <code>ValidationException</code>.</p>
     #[serde(rename = "ConsistentRead")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1632,7 +3707,7 @@ pub struct QueryInput {
     pub query_filter: Option<::std::collections::HashMap<String, Condition>>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>Specifies the order for index traversal: If <code>true</code> (default), the traversal is performed in ascending order; if <code>false</code>, the traversal is performed in descending order. </p> <p>Items with the same partition key value are stored in sorted order by sort key. If the sort key data type is Number, the results are stored in numeric order. For type String, the results are stored in order of UTF-8 bytes. For type Binary, DynamoDB treats each byte of the binary data as unsigned.</p> <p>If <code>ScanIndexForward</code> is <code>true</code>, DynamoDB returns the results in the order in which they are stored (by sort key value). This is the default behavior. If <code>ScanIndexForward</code> is <code>false</code>, DynamoDB reads the results in reverse order by sort key value, and then returns the results to the client.</p>
     #[serde(rename = "ScanIndexForward")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1640,12 +3715,187 @@ pub struct QueryInput {
     /// <p><p>The attributes to be returned in the result. You can retrieve all item attributes, specific item attributes, the count of matching items, or in the case of an index, some or all of the attributes projected into the index.</p> <ul> <li> <p> <code>ALL<em>ATTRIBUTES</code> - Returns all of the item attributes from the specified table or index. If you query a local secondary index, then for each matching item in the index, DynamoDB fetches the entire item from the parent table. If the index is configured to project all item attributes, then all of the data can be obtained from the local secondary index, and no fetching is required.</p> </li> <li> <p> <code>ALL</em>PROJECTED<em>ATTRIBUTES</code> - Allowed only when querying an index. Retrieves all attributes that have been projected into the index. If the index is configured to project all attributes, this return value is equivalent to specifying <code>ALL</em>ATTRIBUTES</code>.</p> </li> <li> <p> <code>COUNT</code> - Returns the number of matching items, rather than the matching items themselves.</p> </li> <li> <p> <code>SPECIFIC<em>ATTRIBUTES</code> - Returns only the attributes listed in <code>AttributesToGet</code>. This return value is equivalent to specifying <code>AttributesToGet</code> without specifying any value for <code>Select</code>.</p> <p>If you query or scan a local secondary index and request only attributes that are projected into that index, the operation will read only the index and not the table. If any of the requested attributes are not projected into the local secondary index, DynamoDB fetches each of these attributes from the parent table. This extra fetching incurs additional throughput cost and latency.</p> <p>If you query or scan a global secondary index, you can only request attributes that are projected into the index. Global secondary index queries cannot fetch attributes from the parent table.</p> </li> </ul> <p>If neither <code>Select</code> nor <code>AttributesToGet</code> are specified, DynamoDB defaults to <code>ALL</em>ATTRIBUTES</code> when accessing a table, and <code>ALL<em>PROJECTED</em>ATTRIBUTES</code> when accessing an index. You cannot use both <code>Select</code> and <code>AttributesToGet</code> together in a single request, unless the value for <code>Select</code> is <code>SPECIFIC<em>ATTRIBUTES</code>. (This usage is equivalent to specifying <code>AttributesToGet</code> without any value for <code>Select</code>.)</p> <note> <p>If you use the <code>ProjectionExpression</code> parameter, then the value for <code>Select</code> can only be <code>SPECIFIC</em>ATTRIBUTES</code>. Any other value for <code>Select</code> will return an error.</p> </note></p>
     #[serde(rename = "Select")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub select: Option<String>,
+    pub select: Option<Select>,
     /// <p>The name of the table containing the requested items.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
 }
 
+impl QueryInput {
+    /// Returns a builder for `QueryInput`, checking its required members when built.
+    pub fn builder() -> QueryInputBuilder {
+        QueryInputBuilder::default()
+    }
+}
+
+/// Builds a [`QueryInput`](struct.QueryInput.html).
+#[derive(Default, Debug)]
+pub struct QueryInputBuilder {
+    attributes_to_get: Option<Vec<String>>,
+    conditional_operator: Option<ConditionalOperator>,
+    consistent_read: Option<bool>,
+    exclusive_start_key: Option<::std::collections::HashMap<String, AttributeValue>>,
+    expression_attribute_names: Option<::std::collections::HashMap<String, String>>,
+    expression_attribute_values: Option<::std::collections::HashMap<String, AttributeValue>>,
+    filter_expression: Option<String>,
+    index_name: Option<String>,
+    key_condition_expression: Option<String>,
+    key_conditions: Option<::std::collections::HashMap<String, Condition>>,
+    limit: Option<i64>,
+    projection_expression: Option<String>,
+    query_filter: Option<::std::collections::HashMap<String, Condition>>,
+    return_consumed_capacity: Option<ReturnConsumedCapacity>,
+    scan_index_forward: Option<bool>,
+    select: Option<Select>,
+    table_name: Option<String>,
+}
+
+impl QueryInputBuilder {
+    /// Sets the [`attributes_to_get`](struct.QueryInput.html#structfield.attributes_to_get) member.
+    pub fn attributes_to_get<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.attributes_to_get = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conditional_operator`](struct.QueryInput.html#structfield.conditional_operator) member.
+    pub fn conditional_operator<T: Into<ConditionalOperator>>(mut self, value: T) -> Self {
+        self.conditional_operator = Some(value.into());
+        self
+    }
+
+    /// Sets the [`consistent_read`](struct.QueryInput.html#structfield.consistent_read) member.
+    pub fn consistent_read<T: Into<bool>>(mut self, value: T) -> Self {
+        self.consistent_read = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exclusive_start_key`](struct.QueryInput.html#structfield.exclusive_start_key) member.
+    pub fn exclusive_start_key<T: Into<::std::collections::HashMap<String, AttributeValue>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.exclusive_start_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_attribute_names`](struct.QueryInput.html#structfield.expression_attribute_names) member.
+    pub fn expression_attribute_names<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expression_attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_attribute_values`](struct.QueryInput.html#structfield.expression_attribute_values) member.
+    pub fn expression_attribute_values<
+        T: Into<::std::collections::HashMap<String, AttributeValue>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expression_attribute_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_expression`](struct.QueryInput.html#structfield.filter_expression) member.
+    pub fn filter_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`index_name`](struct.QueryInput.html#structfield.index_name) member.
+    pub fn index_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.index_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_condition_expression`](struct.QueryInput.html#structfield.key_condition_expression) member.
+    pub fn key_condition_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_condition_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_conditions`](struct.QueryInput.html#structfield.key_conditions) member.
+    pub fn key_conditions<T: Into<::std::collections::HashMap<String, Condition>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.key_conditions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.QueryInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`projection_expression`](struct.QueryInput.html#structfield.projection_expression) member.
+    pub fn projection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.projection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_filter`](struct.QueryInput.html#structfield.query_filter) member.
+    pub fn query_filter<T: Into<::std::collections::HashMap<String, Condition>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.query_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_consumed_capacity`](struct.QueryInput.html#structfield.return_consumed_capacity) member.
+    pub fn return_consumed_capacity<T: Into<ReturnConsumedCapacity>>(mut self, value: T) -> Self {
+        self.return_consumed_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scan_index_forward`](struct.QueryInput.html#structfield.scan_index_forward) member.
+    pub fn scan_index_forward<T: Into<bool>>(mut self, value: T) -> Self {
+        self.scan_index_forward = Some(value.into());
+        self
+    }
+
+    /// Sets the [`select`](struct.QueryInput.html#structfield.select) member.
+    pub fn select<T: Into<Select>>(mut self, value: T) -> Self {
+        self.select = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.QueryInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `QueryInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<QueryInput, ::rusoto_core::BuildError> {
+        Ok(QueryInput {
+            attributes_to_get: self.attributes_to_get,
+            conditional_operator: self.conditional_operator,
+            consistent_read: self.consistent_read,
+            exclusive_start_key: self.exclusive_start_key,
+            expression_attribute_names: self.expression_attribute_names,
+            expression_attribute_values: self.expression_attribute_values,
+            filter_expression: self.filter_expression,
+            index_name: self.index_name,
+            key_condition_expression: self.key_condition_expression,
+            key_conditions: self.key_conditions,
+            limit: self.limit,
+            projection_expression: self.projection_expression,
+            query_filter: self.query_filter,
+            return_consumed_capacity: self.return_consumed_capacity,
+            scan_index_forward: self.scan_index_forward,
+            select: self.select,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>Query</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1701,7 +3951,7 @@ pub struct ReplicaGlobalSecondaryIndexSettingsDescription {
     /// <p><p> The current status of the global secondary index:</p> <ul> <li> <p> <code>CREATING</code> - The global secondary index is being created.</p> </li> <li> <p> <code>UPDATING</code> - The global secondary index is being updated.</p> </li> <li> <p> <code>DELETING</code> - The global secondary index is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The global secondary index is ready for use.</p> </li> </ul></p>
     #[serde(rename = "IndexStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_status: Option<String>,
+    pub index_status: Option<IndexStatus>,
     /// <p>Autoscaling settings for a global secondary index replica's read capacity units.</p>
     #[serde(rename = "ProvisionedReadCapacityAutoScalingSettings")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1773,7 +4023,7 @@ pub struct ReplicaSettingsDescription {
     /// <p><p>The current state of the region:</p> <ul> <li> <p> <code>CREATING</code> - The region is being created.</p> </li> <li> <p> <code>UPDATING</code> - The region is being updated.</p> </li> <li> <p> <code>DELETING</code> - The region is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The region is ready for use.</p> </li> </ul></p>
     #[serde(rename = "ReplicaStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub replica_status: Option<String>,
+    pub replica_status: Option<ReplicaStatus>,
 }
 
 /// <p>Represents the settings for a global table in a region that will be modified.</p>
@@ -1798,6 +4048,102 @@ pub struct ReplicaSettingsUpdate {
     pub replica_provisioned_read_capacity_units: Option<i64>,
 }
 
+/// <p><p>The current state of the region:</p> <ul> <li> <p> <code>CREATING</code> - The region is being created.</p> </li> <li> <p> <code>UPDATING</code> - The region is being updated.</p> </li> <li> <p> <code>DELETING</code> - The region is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The region is ready for use.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReplicaStatus {
+    /// <p><code>CREATING</code> - The region is being created.</p>
+    Creating,
+    /// <p><code>CREATION_FAILED</code></p>
+    CreationFailed,
+    /// <p><code>UPDATING</code> - The region is being updated.</p>
+    Updating,
+    /// <p><code>DELETING</code> - The region is being deleted.</p>
+    Deleting,
+    /// <p><code>ACTIVE</code> - The region is ready for use.</p>
+    Active,
+    /// <p><code>REGION_DISABLED</code></p>
+    RegionDisabled,
+    /// <p><code>INACCESSIBLE_ENCRYPTION_CREDENTIALS</code></p>
+    InaccessibleEncryptionCredentials,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReplicaStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReplicaStatus::Creating => "CREATING",
+            ReplicaStatus::CreationFailed => "CREATION_FAILED",
+            ReplicaStatus::Updating => "UPDATING",
+            ReplicaStatus::Deleting => "DELETING",
+            ReplicaStatus::Active => "ACTIVE",
+            ReplicaStatus::RegionDisabled => "REGION_DISABLED",
+            ReplicaStatus::InaccessibleEncryptionCredentials => {
+                "INACCESSIBLE_ENCRYPTION_CREDENTIALS"
+            }
+            ReplicaStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReplicaStatus {
+    fn from(value: &'a str) -> ReplicaStatus {
+        match value {
+            "CREATING" => ReplicaStatus::Creating,
+            "CREATION_FAILED" => ReplicaStatus::CreationFailed,
+            "UPDATING" => ReplicaStatus::Updating,
+            "DELETING" => ReplicaStatus::Deleting,
+            "ACTIVE" => ReplicaStatus::Active,
+            "REGION_DISABLED" => ReplicaStatus::RegionDisabled,
+            "INACCESSIBLE_ENCRYPTION_CREDENTIALS" => {
+                ReplicaStatus::InaccessibleEncryptionCredentials
+            }
+            _ => ReplicaStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReplicaStatus {
+    fn from(value: String) -> ReplicaStatus {
+        ReplicaStatus::from(value.as_str())
+    }
+}
+
+impl From<ReplicaStatus> for String {
+    fn from(value: ReplicaStatus) -> String {
+        match value {
+            ReplicaStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReplicaStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReplicaStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReplicaStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReplicaStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReplicaStatus::from(value))
+    }
+}
+
 /// <p><p>Represents one of the following:</p> <ul> <li> <p>A new replica to be added to an existing global table.</p> </li> <li> <p>New parameters for an existing replica.</p> </li> <li> <p>An existing replica to be removed from an existing global table.</p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ReplicaUpdate {
@@ -1817,7 +4163,8 @@ pub struct ReplicaUpdate {
 pub struct RestoreSummary {
     /// <p>Point in time or source backup time.</p>
     #[serde(rename = "RestoreDateTime")]
-    pub restore_date_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub restore_date_time: EpochTimestamp,
     /// <p>Indicates if a restore is in progress or not.</p>
     #[serde(rename = "RestoreInProgress")]
     pub restore_in_progress: bool,
@@ -1841,6 +4188,48 @@ pub struct RestoreTableFromBackupInput {
     pub target_table_name: String,
 }
 
+impl RestoreTableFromBackupInput {
+    /// Returns a builder for `RestoreTableFromBackupInput`, checking its required members when built.
+    pub fn builder() -> RestoreTableFromBackupInputBuilder {
+        RestoreTableFromBackupInputBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreTableFromBackupInput`](struct.RestoreTableFromBackupInput.html).
+#[derive(Default, Debug)]
+pub struct RestoreTableFromBackupInputBuilder {
+    backup_arn: Option<String>,
+    target_table_name: Option<String>,
+}
+
+impl RestoreTableFromBackupInputBuilder {
+    /// Sets the [`backup_arn`](struct.RestoreTableFromBackupInput.html#structfield.backup_arn) member.
+    pub fn backup_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_table_name`](struct.RestoreTableFromBackupInput.html#structfield.target_table_name) member.
+    pub fn target_table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreTableFromBackupInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreTableFromBackupInput, ::rusoto_core::BuildError> {
+        Ok(RestoreTableFromBackupInput {
+            backup_arn: self
+                .backup_arn
+                .ok_or(::rusoto_core::BuildError::missing("backup_arn"))?,
+            target_table_name: self
+                .target_table_name
+                .ok_or(::rusoto_core::BuildError::missing("target_table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RestoreTableFromBackupOutput {
@@ -1854,8 +4243,9 @@ pub struct RestoreTableFromBackupOutput {
 pub struct RestoreTableToPointInTimeInput {
     /// <p>Time in the past to restore the table to.</p>
     #[serde(rename = "RestoreDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub restore_date_time: Option<f64>,
+    pub restore_date_time: Option<EpochTimestamp>,
     /// <p>Name of the source table that is being restored.</p>
     #[serde(rename = "SourceTableName")]
     pub source_table_name: String,
@@ -1868,6 +4258,64 @@ pub struct RestoreTableToPointInTimeInput {
     pub use_latest_restorable_time: Option<bool>,
 }
 
+impl RestoreTableToPointInTimeInput {
+    /// Returns a builder for `RestoreTableToPointInTimeInput`, checking its required members when built.
+    pub fn builder() -> RestoreTableToPointInTimeInputBuilder {
+        RestoreTableToPointInTimeInputBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreTableToPointInTimeInput`](struct.RestoreTableToPointInTimeInput.html).
+#[derive(Default, Debug)]
+pub struct RestoreTableToPointInTimeInputBuilder {
+    restore_date_time: Option<EpochTimestamp>,
+    source_table_name: Option<String>,
+    target_table_name: Option<String>,
+    use_latest_restorable_time: Option<bool>,
+}
+
+impl RestoreTableToPointInTimeInputBuilder {
+    /// Sets the [`restore_date_time`](struct.RestoreTableToPointInTimeInput.html#structfield.restore_date_time) member.
+    pub fn restore_date_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.restore_date_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_table_name`](struct.RestoreTableToPointInTimeInput.html#structfield.source_table_name) member.
+    pub fn source_table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_table_name`](struct.RestoreTableToPointInTimeInput.html#structfield.target_table_name) member.
+    pub fn target_table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_latest_restorable_time`](struct.RestoreTableToPointInTimeInput.html#structfield.use_latest_restorable_time) member.
+    pub fn use_latest_restorable_time<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_latest_restorable_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreTableToPointInTimeInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreTableToPointInTimeInput, ::rusoto_core::BuildError> {
+        Ok(RestoreTableToPointInTimeInput {
+            restore_date_time: self.restore_date_time,
+            source_table_name: self
+                .source_table_name
+                .ok_or(::rusoto_core::BuildError::missing("source_table_name"))?,
+            target_table_name: self
+                .target_table_name
+                .ok_or(::rusoto_core::BuildError::missing("target_table_name"))?,
+            use_latest_restorable_time: self.use_latest_restorable_time,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RestoreTableToPointInTimeOutput {
@@ -1877,6 +4325,314 @@ pub struct RestoreTableToPointInTimeOutput {
     pub table_description: Option<TableDescription>,
 }
 
+/// <p>Determines the level of detail about either provisioned or on-demand throughput consumption that is returned in the response:</p> <ul> <li> <p> <code>INDEXES</code> - The response includes the aggregate <code>ConsumedCapacity</code> for the operation, together with <code>ConsumedCapacity</code> for each table and secondary index that was accessed.</p> <p>Note that some operations, such as <code>GetItem</code> and <code>BatchGetItem</code>, do not access any indexes at all. In these cases, specifying <code>INDEXES</code> will only return <code>ConsumedCapacity</code> information for table(s).</p> </li> <li> <p> <code>TOTAL</code> - The response includes only the aggregate <code>ConsumedCapacity</code> for the operation.</p> </li> <li> <p> <code>NONE</code> - No <code>ConsumedCapacity</code> details are included in the response.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReturnConsumedCapacity {
+    /// <p><code>INDEXES</code> - The response includes the aggregate <code>ConsumedCapacity</code> for the operation, together with <code>ConsumedCapacity</code> for each table and secondary index that was accessed.</p>
+    Indexes,
+    /// <p><code>TOTAL</code> - The response includes only the aggregate <code>ConsumedCapacity</code> for the operation.</p>
+    Total,
+    /// <p><code>NONE</code> - No <code>ConsumedCapacity</code> details are included in the response.</p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReturnConsumedCapacity {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReturnConsumedCapacity::Indexes => "INDEXES",
+            ReturnConsumedCapacity::Total => "TOTAL",
+            ReturnConsumedCapacity::None => "NONE",
+            ReturnConsumedCapacity::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReturnConsumedCapacity {
+    fn from(value: &'a str) -> ReturnConsumedCapacity {
+        match value {
+            "INDEXES" => ReturnConsumedCapacity::Indexes,
+            "TOTAL" => ReturnConsumedCapacity::Total,
+            "NONE" => ReturnConsumedCapacity::None,
+            _ => ReturnConsumedCapacity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReturnConsumedCapacity {
+    fn from(value: String) -> ReturnConsumedCapacity {
+        ReturnConsumedCapacity::from(value.as_str())
+    }
+}
+
+impl From<ReturnConsumedCapacity> for String {
+    fn from(value: ReturnConsumedCapacity) -> String {
+        match value {
+            ReturnConsumedCapacity::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReturnConsumedCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReturnConsumedCapacity {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReturnConsumedCapacity {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReturnConsumedCapacity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReturnConsumedCapacity::from(value))
+    }
+}
+
+/// <p>Determines whether item collection metrics are returned. If set to <code>SIZE</code>, the response includes statistics about item collections, if any, that were modified during the operation are returned in the response. If set to <code>NONE</code> (the default), no statistics are returned.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReturnItemCollectionMetrics {
+    /// <p><code>SIZE</code></p>
+    Size,
+    /// <p><code>NONE</code></p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReturnItemCollectionMetrics {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReturnItemCollectionMetrics::Size => "SIZE",
+            ReturnItemCollectionMetrics::None => "NONE",
+            ReturnItemCollectionMetrics::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReturnItemCollectionMetrics {
+    fn from(value: &'a str) -> ReturnItemCollectionMetrics {
+        match value {
+            "SIZE" => ReturnItemCollectionMetrics::Size,
+            "NONE" => ReturnItemCollectionMetrics::None,
+            _ => ReturnItemCollectionMetrics::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReturnItemCollectionMetrics {
+    fn from(value: String) -> ReturnItemCollectionMetrics {
+        ReturnItemCollectionMetrics::from(value.as_str())
+    }
+}
+
+impl From<ReturnItemCollectionMetrics> for String {
+    fn from(value: ReturnItemCollectionMetrics) -> String {
+        match value {
+            ReturnItemCollectionMetrics::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReturnItemCollectionMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReturnItemCollectionMetrics {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReturnItemCollectionMetrics {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ReturnItemCollectionMetrics, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReturnItemCollectionMetrics::from(value))
+    }
+}
+
+/// <p><p>Use <code>ReturnValues</code> if you want to get the item attributes as they appeared before they were deleted. For <code>DeleteItem</code>, the valid values are:</p> <ul> <li> <p> <code>NONE</code> - If <code>ReturnValues</code> is not specified, or if its value is <code>NONE</code>, then nothing is returned. (This setting is the default for <code>ReturnValues</code>.)</p> </li> <li> <p> <code>ALL<em>OLD</code> - The content of the old item is returned.</p> </li> </ul> <note> <p>The <code>ReturnValues</code> parameter is used by several DynamoDB operations; however, <code>DeleteItem</code> does not recognize any values other than <code>NONE</code> or <code>ALL</em>OLD</code>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReturnValue {
+    /// <p><code>NONE</code> - If <code>ReturnValues</code> is not specified, or if its value is <code>NONE</code>, then nothing is returned. (This setting is the default for <code>ReturnValues</code>.)</p>
+    None,
+    /// <p><code>ALL_OLD</code> - Returns all of the attributes of the item, as they appeared before the UpdateItem operation.</p>
+    AllOld,
+    /// <p><code>UPDATED_OLD</code> - Returns only the updated attributes, as they appeared before the UpdateItem operation.</p>
+    UpdatedOld,
+    /// <p><code>ALL_NEW</code> - Returns all of the attributes of the item, as they appear after the UpdateItem operation.</p>
+    AllNew,
+    /// <p><code>UPDATED_NEW</code> - Returns only the updated attributes, as they appear after the UpdateItem operation.</p>
+    UpdatedNew,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReturnValue {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReturnValue::None => "NONE",
+            ReturnValue::AllOld => "ALL_OLD",
+            ReturnValue::UpdatedOld => "UPDATED_OLD",
+            ReturnValue::AllNew => "ALL_NEW",
+            ReturnValue::UpdatedNew => "UPDATED_NEW",
+            ReturnValue::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReturnValue {
+    fn from(value: &'a str) -> ReturnValue {
+        match value {
+            "NONE" => ReturnValue::None,
+            "ALL_OLD" => ReturnValue::AllOld,
+            "UPDATED_OLD" => ReturnValue::UpdatedOld,
+            "ALL_NEW" => ReturnValue::AllNew,
+            "UPDATED_NEW" => ReturnValue::UpdatedNew,
+            _ => ReturnValue::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReturnValue {
+    fn from(value: String) -> ReturnValue {
+        ReturnValue::from(value.as_str())
+    }
+}
+
+impl From<ReturnValue> for String {
+    fn from(value: ReturnValue) -> String {
+        match value {
+            ReturnValue::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReturnValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReturnValue {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReturnValue {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReturnValue, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReturnValue::from(value))
+    }
+}
+
+/// <p>Use <code>ReturnValuesOnConditionCheckFailure</code> to get the item attributes if the <code>ConditionCheck</code> condition fails. For <code>ReturnValuesOnConditionCheckFailure</code>, the valid values are: NONE and ALL_OLD.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReturnValuesOnConditionCheckFailure {
+    /// <p><code>ALL_OLD</code></p>
+    AllOld,
+    /// <p><code>NONE</code></p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReturnValuesOnConditionCheckFailure {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReturnValuesOnConditionCheckFailure::AllOld => "ALL_OLD",
+            ReturnValuesOnConditionCheckFailure::None => "NONE",
+            ReturnValuesOnConditionCheckFailure::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReturnValuesOnConditionCheckFailure {
+    fn from(value: &'a str) -> ReturnValuesOnConditionCheckFailure {
+        match value {
+            "ALL_OLD" => ReturnValuesOnConditionCheckFailure::AllOld,
+            "NONE" => ReturnValuesOnConditionCheckFailure::None,
+            _ => ReturnValuesOnConditionCheckFailure::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReturnValuesOnConditionCheckFailure {
+    fn from(value: String) -> ReturnValuesOnConditionCheckFailure {
+        ReturnValuesOnConditionCheckFailure::from(value.as_str())
+    }
+}
+
+impl From<ReturnValuesOnConditionCheckFailure> for String {
+    fn from(value: ReturnValuesOnConditionCheckFailure) -> String {
+        match value {
+            ReturnValuesOnConditionCheckFailure::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReturnValuesOnConditionCheckFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReturnValuesOnConditionCheckFailure {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReturnValuesOnConditionCheckFailure {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ReturnValuesOnConditionCheckFailure, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReturnValuesOnConditionCheckFailure::from(value))
+    }
+}
+
 /// <p>The description of the server-side encryption status on the specified table.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1888,11 +4644,11 @@ pub struct SSEDescription {
     /// <p><p>Server-side encryption type. The only supported value is:</p> <ul> <li> <p> <code>KMS</code> - Server-side encryption which uses AWS Key Management Service. Key is stored in your account and is managed by AWS KMS (KMS charges apply).</p> </li> </ul></p>
     #[serde(rename = "SSEType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sse_type: Option<String>,
+    pub sse_type: Option<SSEType>,
     /// <p><p>Represents the current state of server-side encryption. The only supported values are:</p> <ul> <li> <p> <code>ENABLED</code> - Server-side encryption is enabled.</p> </li> <li> <p> <code>UPDATING</code> - Server-side encryption is being updated.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<SSEStatus>,
 }
 
 /// <p>Represents the settings used to enable server-side encryption.</p>
@@ -1909,55 +4665,287 @@ pub struct SSESpecification {
     /// <p><p>Server-side encryption type. The only supported value is:</p> <ul> <li> <p> <code>KMS</code> - Server-side encryption which uses AWS Key Management Service. Key is stored in your account and is managed by AWS KMS (KMS charges apply).</p> </li> </ul></p>
     #[serde(rename = "SSEType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sse_type: Option<String>,
+    pub sse_type: Option<SSEType>,
+}
+
+/// <p><p>Represents the current state of server-side encryption. The only supported values are:</p> <ul> <li> <p> <code>ENABLED</code> - Server-side encryption is enabled.</p> </li> <li> <p> <code>UPDATING</code> - Server-side encryption is being updated.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SSEStatus {
+    /// <p><code>ENABLING</code></p>
+    Enabling,
+    /// <p><code>ENABLED</code> - Server-side encryption is enabled.</p>
+    Enabled,
+    /// <p><code>DISABLING</code></p>
+    Disabling,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// <p><code>UPDATING</code> - Server-side encryption is being updated.</p>
+    Updating,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SSEStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SSEStatus::Enabling => "ENABLING",
+            SSEStatus::Enabled => "ENABLED",
+            SSEStatus::Disabling => "DISABLING",
+            SSEStatus::Disabled => "DISABLED",
+            SSEStatus::Updating => "UPDATING",
+            SSEStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Represents the input of a <code>Scan</code> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ScanInput {
-    /// <p>This is a legacy parameter. Use <code>ProjectionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.AttributesToGet.html">AttributesToGet</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    #[serde(rename = "AttributesToGet")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes_to_get: Option<Vec<String>>,
-    /// <p>This is a legacy parameter. Use <code>FilterExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ConditionalOperator.html">ConditionalOperator</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    #[serde(rename = "ConditionalOperator")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub conditional_operator: Option<String>,
-    /// <p>A Boolean value that determines the read consistency model during the scan:</p> <ul> <li> <p>If <code>ConsistentRead</code> is <code>false</code>, then the data returned from <code>Scan</code> might not contain the results from other recently completed write operations (<code>PutItem</code>, <code>UpdateItem</code>, or <code>DeleteItem</code>).</p> </li> <li> <p>If <code>ConsistentRead</code> is <code>true</code>, then all of the write operations that completed before the <code>Scan</code> began are guaranteed to be contained in the <code>Scan</code> response.</p> </li> </ul> <p>The default setting for <code>ConsistentRead</code> is <code>false</code>.</p> <p>The <code>ConsistentRead</code> parameter is not supported on global secondary indexes. If you scan a global secondary index with <code>ConsistentRead</code> set to true, you will receive a <code>ValidationException</code>.</p>
-    #[serde(rename = "ConsistentRead")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub consistent_read: Option<bool>,
-    /// <p>The primary key of the first item that this operation will evaluate. Use the value that was returned for <code>LastEvaluatedKey</code> in the previous operation.</p> <p>The data type for <code>ExclusiveStartKey</code> must be String, Number or Binary. No set data types are allowed.</p> <p>In a parallel scan, a <code>Scan</code> request that includes <code>ExclusiveStartKey</code> must specify the same segment whose previous <code>Scan</code> returned the corresponding value of <code>LastEvaluatedKey</code>.</p>
-    #[serde(rename = "ExclusiveStartKey")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub exclusive_start_key: Option<::std::collections::HashMap<String, AttributeValue>>,
-    /// <p>One or more substitution tokens for attribute names in an expression. The following are some use cases for using <code>ExpressionAttributeNames</code>:</p> <ul> <li> <p>To access an attribute whose name conflicts with a DynamoDB reserved word.</p> </li> <li> <p>To create a placeholder for repeating occurrences of an attribute name in an expression.</p> </li> <li> <p>To prevent special characters in an attribute name from being misinterpreted in an expression.</p> </li> </ul> <p>Use the <b>#</b> character in an expression to dereference an attribute name. For example, consider the following attribute name:</p> <ul> <li> <p> <code>Percentile</code> </p> </li> </ul> <p>The name of this attribute conflicts with a reserved word, so it cannot be used directly in an expression. (For the complete list of reserved words, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ReservedWords.html">Reserved Words</a> in the <i>Amazon DynamoDB Developer Guide</i>). To work around this, you could specify the following for <code>ExpressionAttributeNames</code>:</p> <ul> <li> <p> <code>{"#P":"Percentile"}</code> </p> </li> </ul> <p>You could then use this substitution in an expression, as in this example:</p> <ul> <li> <p> <code>#P = :val</code> </p> </li> </ul> <note> <p>Tokens that begin with the <b>:</b> character are <i>expression attribute values</i>, which are placeholders for the actual value at runtime.</p> </note> <p>For more information on expression attribute names, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.AccessingItemAttributes.html">Specifying Item Attributes</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    #[serde(rename = "ExpressionAttributeNames")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expression_attribute_names: Option<::std::collections::HashMap<String, String>>,
-    /// <p>One or more values that can be substituted in an expression.</p> <p>Use the <b>:</b> (colon) character in an expression to dereference an attribute value. For example, suppose that you wanted to check whether the value of the <code>ProductStatus</code> attribute was one of the following: </p> <p> <code>Available | Backordered | Discontinued</code> </p> <p>You would first need to specify <code>ExpressionAttributeValues</code> as follows:</p> <p> <code>{ ":avail":{"S":"Available"}, ":back":{"S":"Backordered"}, ":disc":{"S":"Discontinued"} }</code> </p> <p>You could then use these values in an expression, such as this:</p> <p> <code>ProductStatus IN (:avail, :back, :disc)</code> </p> <p>For more information on expression attribute values, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.SpecifyingConditions.html">Condition Expressions</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    #[serde(rename = "ExpressionAttributeValues")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expression_attribute_values: Option<::std::collections::HashMap<String, AttributeValue>>,
-    /// <p>A string that contains conditions that DynamoDB applies after the <code>Scan</code> operation, but before the data is returned to you. Items that do not satisfy the <code>FilterExpression</code> criteria are not returned.</p> <note> <p>A <code>FilterExpression</code> is applied after the items have already been read; the process of filtering does not consume any additional read capacity units.</p> </note> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/QueryAndScan.html#FilteringResults">Filter Expressions</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    #[serde(rename = "FilterExpression")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_expression: Option<String>,
-    /// <p>The name of a secondary index to scan. This index can be any local secondary index or global secondary index. Note that if you use the <code>IndexName</code> parameter, you must also provide <code>TableName</code>.</p>
-    #[serde(rename = "IndexName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_name: Option<String>,
-    /// <p>The maximum number of items to evaluate (not necessarily the number of matching items). If DynamoDB processes the number of items up to the limit while processing the results, it stops the operation and returns the matching values up to that point, and a key in <code>LastEvaluatedKey</code> to apply in a subsequent operation, so that you can pick up where you left off. Also, if the processed dataset size exceeds 1 MB before DynamoDB reaches this limit, it stops the operation and returns the matching values up to the limit, and a key in <code>LastEvaluatedKey</code> to apply in a subsequent operation to continue the operation. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/QueryAndScan.html">Working with Queries</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    #[serde(rename = "Limit")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub limit: Option<i64>,
-    /// <p>A string that identifies one or more attributes to retrieve from the specified table or index. These attributes can include scalars, sets, or elements of a JSON document. The attributes in the expression must be separated by commas.</p> <p>If no attribute names are specified, then all attributes will be returned. If any of the requested attributes are not found, they will not appear in the result.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.AccessingItemAttributes.html">Specifying Item Attributes</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
-    #[serde(rename = "ProjectionExpression")]
+impl<'a> From<&'a str> for SSEStatus {
+    fn from(value: &'a str) -> SSEStatus {
+        match value {
+            "ENABLING" => SSEStatus::Enabling,
+            "ENABLED" => SSEStatus::Enabled,
+            "DISABLING" => SSEStatus::Disabling,
+            "DISABLED" => SSEStatus::Disabled,
+            "UPDATING" => SSEStatus::Updating,
+            _ => SSEStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SSEStatus {
+    fn from(value: String) -> SSEStatus {
+        SSEStatus::from(value.as_str())
+    }
+}
+
+impl From<SSEStatus> for String {
+    fn from(value: SSEStatus) -> String {
+        match value {
+            SSEStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SSEStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SSEStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SSEStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SSEStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SSEStatus::from(value))
+    }
+}
+
+/// <p><p>Server-side encryption type. The only supported value is:</p> <ul> <li> <p> <code>KMS</code> - Server-side encryption which uses AWS Key Management Service. Key is stored in your account and is managed by AWS KMS (KMS charges apply).</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SSEType {
+    /// <p><code>AES256</code></p>
+    Aes256,
+    /// <p><code>KMS</code> - Server-side encryption which uses AWS Key Management Service. Key is stored in your account and is managed by AWS KMS (KMS charges apply).</p>
+    Kms,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SSEType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SSEType::Aes256 => "AES256",
+            SSEType::Kms => "KMS",
+            SSEType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SSEType {
+    fn from(value: &'a str) -> SSEType {
+        match value {
+            "AES256" => SSEType::Aes256,
+            "KMS" => SSEType::Kms,
+            _ => SSEType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SSEType {
+    fn from(value: String) -> SSEType {
+        SSEType::from(value.as_str())
+    }
+}
+
+impl From<SSEType> for String {
+    fn from(value: SSEType) -> String {
+        match value {
+            SSEType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SSEType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SSEType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SSEType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SSEType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SSEType::from(value))
+    }
+}
+
+/// <p><p>The data type for the attribute, where:</p> <ul> <li> <p> <code>S</code> - the attribute is of type String</p> </li> <li> <p> <code>N</code> - the attribute is of type Number</p> </li> <li> <p> <code>B</code> - the attribute is of type Binary</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScalarAttributeType {
+    /// <p><code>S</code> - the attribute is of type String</p>
+    S,
+    /// <p><code>N</code> - the attribute is of type Number</p>
+    N,
+    /// <p><code>B</code> - the attribute is of type Binary</p>
+    B,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScalarAttributeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalarAttributeType::S => "S",
+            ScalarAttributeType::N => "N",
+            ScalarAttributeType::B => "B",
+            ScalarAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScalarAttributeType {
+    fn from(value: &'a str) -> ScalarAttributeType {
+        match value {
+            "S" => ScalarAttributeType::S,
+            "N" => ScalarAttributeType::N,
+            "B" => ScalarAttributeType::B,
+            _ => ScalarAttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalarAttributeType {
+    fn from(value: String) -> ScalarAttributeType {
+        ScalarAttributeType::from(value.as_str())
+    }
+}
+
+impl From<ScalarAttributeType> for String {
+    fn from(value: ScalarAttributeType) -> String {
+        match value {
+            ScalarAttributeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScalarAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScalarAttributeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalarAttributeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ScalarAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScalarAttributeType::from(value))
+    }
+}
+
+/// <p>Represents the input of a <code>Scan</code> operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ScanInput {
+    /// <p>This is a legacy parameter. Use <code>ProjectionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.AttributesToGet.html">AttributesToGet</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    #[serde(rename = "AttributesToGet")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes_to_get: Option<Vec<String>>,
+    /// <p>This is a legacy parameter. Use <code>FilterExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ConditionalOperator.html">ConditionalOperator</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    #[serde(rename = "ConditionalOperator")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditional_operator: Option<ConditionalOperator>,
+    /// <p>A Boolean value that determines the read consistency model during the scan:</p> <ul> <li> <p>If <code>ConsistentRead</code> is <code>false</code>, then the data returned from <code>Scan</code> might not contain the results from other recently completed write operations (<code>PutItem</code>, <code>UpdateItem</code>, or <code>DeleteItem</code>).</p> </li> <li> <p>If <code>ConsistentRead</code> is <code>true</code>, then all of the write operations that completed before the <code>Scan</code> began are guaranteed to be contained in the <code>Scan</code> response.</p> </li> </ul> <p>The default setting for <code>ConsistentRead</code> is <code>false</code>.</p> <p>The <code>ConsistentRead</code> parameter is not supported on global secondary indexes. If you scan a global secondary index with <code>ConsistentRead</code> set to true, you will receive a <code>ValidationException</code>.</p>
+    #[serde(rename = "ConsistentRead")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consistent_read: Option<bool>,
+    /// <p>The primary key of the first item that this operation will evaluate. Use the value that was returned for <code>LastEvaluatedKey</code> in the previous operation.</p> <p>The data type for <code>ExclusiveStartKey</code> must be String, Number or Binary. No set data types are allowed.</p> <p>In a parallel scan, a <code>Scan</code> request that includes <code>ExclusiveStartKey</code> must specify the same segment whose previous <code>Scan</code> returned the corresponding value of <code>LastEvaluatedKey</code>.</p>
+    #[serde(rename = "ExclusiveStartKey")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclusive_start_key: Option<::std::collections::HashMap<String, AttributeValue>>,
+    /// <p>One or more substitution tokens for attribute names in an expression. The following are some use cases for using <code>ExpressionAttributeNames</code>:</p> <ul> <li> <p>To access an attribute whose name conflicts with a DynamoDB reserved word.</p> </li> <li> <p>To create a placeholder for repeating occurrences of an attribute name in an expression.</p> </li> <li> <p>To prevent special characters in an attribute name from being misinterpreted in an expression.</p> </li> </ul> <p>Use the <b>#</b> character in an expression to dereference an attribute name. For example, consider the following attribute name:</p> <ul> <li> <p> <code>Percentile</code> </p> </li> </ul> <p>The name of this attribute conflicts with a reserved word, so it cannot be used directly in an expression. (For the complete list of reserved words, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ReservedWords.html">Reserved Words</a> in the <i>Amazon DynamoDB Developer Guide</i>). To work around this, you could specify the following for <code>ExpressionAttributeNames</code>:</p> <ul> <li> <p> <code>{"#P":"Percentile"}</code> </p> </li> </ul> <p>You could then use this substitution in an expression, as in this example:</p> <ul> <li> <p> <code>#P = :val</code> </p> </li> </ul> <note> <p>Tokens that begin with the <b>:</b> character are <i>expression attribute values</i>, which are placeholders for the actual value at runtime.</p> </note> <p>For more information on expression attribute names, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.AccessingItemAttributes.html">Specifying Item Attributes</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    #[serde(rename = "ExpressionAttributeNames")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expression_attribute_names: Option<::std::collections::HashMap<String, String>>,
+    /// <p>One or more values that can be substituted in an expression.</p> <p>Use the <b>:</b> (colon) character in an expression to dereference an attribute value. For example, suppose that you wanted to check whether the value of the <code>ProductStatus</code> attribute was one of the following: </p> <p> <code>Available | Backordered | Discontinued</code> </p> <p>You would first need to specify <code>ExpressionAttributeValues</code> as follows:</p> <p> <code>{ ":avail":{"S":"Available"}, ":back":{"S":"Backordered"}, ":disc":{"S":"Discontinued"} }</code> </p> <p>You could then use these values in an expression, such as this:</p> <p> <code>ProductStatus IN (:avail, :back, :disc)</code> </p> <p>For more information on expression attribute values, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.SpecifyingConditions.html">Condition Expressions</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    #[serde(rename = "ExpressionAttributeValues")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expression_attribute_values: Option<::std::collections::HashMap<String, AttributeValue>>,
+    /// <p>A string that contains conditions that DynamoDB applies after the <code>Scan</code> operation, but before the data is returned to you. Items that do not satisfy the <code>FilterExpression</code> criteria are not returned.</p> <note> <p>A <code>FilterExpression</code> is applied after the items have already been read; the process of filtering does not consume any additional read capacity units.</p> </note> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/QueryAndScan.html#FilteringResults">Filter Expressions</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    #[serde(rename = "FilterExpression")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_expression: Option<String>,
+    /// <p>The name of a secondary index to scan. This index can be any local secondary index or global secondary index. Note that if you use the <code>IndexName</code> parameter, you must also provide <code>TableName</code>.</p>
+    #[serde(rename = "IndexName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_name: Option<String>,
+    /// <p>The maximum number of items to evaluate (not necessarily the number of matching items). If DynamoDB processes the number of items up to the limit while processing the results, it stops the operation and returns the matching values up to that point, and a key in <code>LastEvaluatedKey</code> to apply in a subsequent operation, so that you can pick up where you left off. Also, if the processed dataset size exceeds 1 MB before DynamoDB reaches this limit, it stops the operation and returns the matching values up to the limit, and a key in <code>LastEvaluatedKey</code> to apply in a subsequent operation to continue the operation. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/QueryAndScan.html">Working with Queries</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    #[serde(rename = "Limit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// <p>A string that identifies one or more attributes to retrieve from the specified table or index. These attributes can include scalars, sets, or elements of a JSON document. The attributes in the expression must be separated by commas.</p> <p>If no attribute names are specified, then all attributes will be returned. If any of the requested attributes are not found, they will not appear in the result.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Expressions.AccessingItemAttributes.html">Specifying Item Attributes</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    #[serde(rename = "ProjectionExpression")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub projection_expression: Option<String>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>This is a legacy parameter. Use <code>FilterExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ScanFilter.html">ScanFilter</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ScanFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1969,7 +4957,7 @@ pub struct ScanInput {
     /// <p><p>The attributes to be returned in the result. You can retrieve all item attributes, specific item attributes, the count of matching items, or in the case of an index, some or all of the attributes projected into the index.</p> <ul> <li> <p> <code>ALL<em>ATTRIBUTES</code> - Returns all of the item attributes from the specified table or index. If you query a local secondary index, then for each matching item in the index, DynamoDB fetches the entire item from the parent table. If the index is configured to project all item attributes, then all of the data can be obtained from the local secondary index, and no fetching is required.</p> </li> <li> <p> <code>ALL</em>PROJECTED<em>ATTRIBUTES</code> - Allowed only when querying an index. Retrieves all attributes that have been projected into the index. If the index is configured to project all attributes, this return value is equivalent to specifying <code>ALL</em>ATTRIBUTES</code>.</p> </li> <li> <p> <code>COUNT</code> - Returns the number of matching items, rather than the matching items themselves.</p> </li> <li> <p> <code>SPECIFIC<em>ATTRIBUTES</code> - Returns only the attributes listed in <code>AttributesToGet</code>. This return value is equivalent to specifying <code>AttributesToGet</code> without specifying any value for <code>Select</code>.</p> <p>If you query or scan a local secondary index and request only attributes that are projected into that index, the operation reads only the index and not the table. If any of the requested attributes are not projected into the local secondary index, DynamoDB fetches each of these attributes from the parent table. This extra fetching incurs additional throughput cost and latency.</p> <p>If you query or scan a global secondary index, you can only request attributes that are projected into the index. Global secondary index queries cannot fetch attributes from the parent table.</p> </li> </ul> <p>If neither <code>Select</code> nor <code>AttributesToGet</code> are specified, DynamoDB defaults to <code>ALL</em>ATTRIBUTES</code> when accessing a table, and <code>ALL<em>PROJECTED</em>ATTRIBUTES</code> when accessing an index. You cannot use both <code>Select</code> and <code>AttributesToGet</code> together in a single request, unless the value for <code>Select</code> is <code>SPECIFIC<em>ATTRIBUTES</code>. (This usage is equivalent to specifying <code>AttributesToGet</code> without any value for <code>Select</code>.)</p> <note> <p>If you use the <code>ProjectionExpression</code> parameter, then the value for <code>Select</code> can only be <code>SPECIFIC</em>ATTRIBUTES</code>. Any other value for <code>Select</code> will return an error.</p> </note></p>
     #[serde(rename = "Select")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub select: Option<String>,
+    pub select: Option<Select>,
     /// <p>The name of the table containing the requested items; or, if you provide <code>IndexName</code>, the name of the table to which that index belongs.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -1979,6 +4967,170 @@ pub struct ScanInput {
     pub total_segments: Option<i64>,
 }
 
+impl ScanInput {
+    /// Returns a builder for `ScanInput`, checking its required members when built.
+    pub fn builder() -> ScanInputBuilder {
+        ScanInputBuilder::default()
+    }
+}
+
+/// Builds a [`ScanInput`](struct.ScanInput.html).
+#[derive(Default, Debug)]
+pub struct ScanInputBuilder {
+    attributes_to_get: Option<Vec<String>>,
+    conditional_operator: Option<ConditionalOperator>,
+    consistent_read: Option<bool>,
+    exclusive_start_key: Option<::std::collections::HashMap<String, AttributeValue>>,
+    expression_attribute_names: Option<::std::collections::HashMap<String, String>>,
+    expression_attribute_values: Option<::std::collections::HashMap<String, AttributeValue>>,
+    filter_expression: Option<String>,
+    index_name: Option<String>,
+    limit: Option<i64>,
+    projection_expression: Option<String>,
+    return_consumed_capacity: Option<ReturnConsumedCapacity>,
+    scan_filter: Option<::std::collections::HashMap<String, Condition>>,
+    segment: Option<i64>,
+    select: Option<Select>,
+    table_name: Option<String>,
+    total_segments: Option<i64>,
+}
+
+impl ScanInputBuilder {
+    /// Sets the [`attributes_to_get`](struct.ScanInput.html#structfield.attributes_to_get) member.
+    pub fn attributes_to_get<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.attributes_to_get = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conditional_operator`](struct.ScanInput.html#structfield.conditional_operator) member.
+    pub fn conditional_operator<T: Into<ConditionalOperator>>(mut self, value: T) -> Self {
+        self.conditional_operator = Some(value.into());
+        self
+    }
+
+    /// Sets the [`consistent_read`](struct.ScanInput.html#structfield.consistent_read) member.
+    pub fn consistent_read<T: Into<bool>>(mut self, value: T) -> Self {
+        self.consistent_read = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exclusive_start_key`](struct.ScanInput.html#structfield.exclusive_start_key) member.
+    pub fn exclusive_start_key<T: Into<::std::collections::HashMap<String, AttributeValue>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.exclusive_start_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_attribute_names`](struct.ScanInput.html#structfield.expression_attribute_names) member.
+    pub fn expression_attribute_names<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expression_attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_attribute_values`](struct.ScanInput.html#structfield.expression_attribute_values) member.
+    pub fn expression_attribute_values<
+        T: Into<::std::collections::HashMap<String, AttributeValue>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expression_attribute_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_expression`](struct.ScanInput.html#structfield.filter_expression) member.
+    pub fn filter_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`index_name`](struct.ScanInput.html#structfield.index_name) member.
+    pub fn index_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.index_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ScanInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`projection_expression`](struct.ScanInput.html#structfield.projection_expression) member.
+    pub fn projection_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.projection_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_consumed_capacity`](struct.ScanInput.html#structfield.return_consumed_capacity) member.
+    pub fn return_consumed_capacity<T: Into<ReturnConsumedCapacity>>(mut self, value: T) -> Self {
+        self.return_consumed_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scan_filter`](struct.ScanInput.html#structfield.scan_filter) member.
+    pub fn scan_filter<T: Into<::std::collections::HashMap<String, Condition>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.scan_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`segment`](struct.ScanInput.html#structfield.segment) member.
+    pub fn segment<T: Into<i64>>(mut self, value: T) -> Self {
+        self.segment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`select`](struct.ScanInput.html#structfield.select) member.
+    pub fn select<T: Into<Select>>(mut self, value: T) -> Self {
+        self.select = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.ScanInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`total_segments`](struct.ScanInput.html#structfield.total_segments) member.
+    pub fn total_segments<T: Into<i64>>(mut self, value: T) -> Self {
+        self.total_segments = Some(value.into());
+        self
+    }
+
+    /// Builds the `ScanInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ScanInput, ::rusoto_core::BuildError> {
+        Ok(ScanInput {
+            attributes_to_get: self.attributes_to_get,
+            conditional_operator: self.conditional_operator,
+            consistent_read: self.consistent_read,
+            exclusive_start_key: self.exclusive_start_key,
+            expression_attribute_names: self.expression_attribute_names,
+            expression_attribute_values: self.expression_attribute_values,
+            filter_expression: self.filter_expression,
+            index_name: self.index_name,
+            limit: self.limit,
+            projection_expression: self.projection_expression,
+            return_consumed_capacity: self.return_consumed_capacity,
+            scan_filter: self.scan_filter,
+            segment: self.segment,
+            select: self.select,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+            total_segments: self.total_segments,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>Scan</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2005,6 +5157,86 @@ pub struct ScanOutput {
     pub scanned_count: Option<i64>,
 }
 
+/// <p><p>The attributes to be returned in the result. You can retrieve all item attributes, specific item attributes, the count of matching items, or in the case of an index, some or all of the attributes projected into the index.</p> <ul> <li> <p> <code>ALL<em>ATTRIBUTES</code> - Returns all of the item attributes from the specified table or index. If you query a local secondary index, then for each matching item in the index, DynamoDB fetches the entire item from the parent table. If the index is configured to project all item attributes, then all of the data can be obtained from the local secondary index, and no fetching is required.</p> </li> <li> <p> <code>ALL</em>PROJECTED<em>ATTRIBUTES</code> - Allowed only when querying an index. Retrieves all attributes that have been projected into the index. If the index is configured to project all attributes, this return value is equivalent to specifying <code>ALL</em>ATTRIBUTES</code>.</p> </li> <li> <p> <code>COUNT</code> - Returns the number of matching items, rather than the matching items themselves.</p> </li> <li> <p> <code>SPECIFIC<em>ATTRIBUTES</code> - Returns only the attributes listed in <code>AttributesToGet</code>. This return value is equivalent to specifying <code>AttributesToGet</code> without specifying any value for <code>Select</code>.</p> <p>If you query or scan a local secondary index and request only attributes that are projected into that index, the operation will read only the index and not the table. If any of the requested attributes are not projected into the local secondary index, DynamoDB fetches each of these attributes from the parent table. This extra fetching incurs additional throughput cost and latency.</p> <p>If you query or scan a global secondary index, you can only request attributes that are projected into the index. Global secondary index queries cannot fetch attributes from the parent table.</p> </li> </ul> <p>If neither <code>Select</code> nor <code>AttributesToGet</code> are specified, DynamoDB defaults to <code>ALL</em>ATTRIBUTES</code> when accessing a table, and <code>ALL<em>PROJECTED</em>ATTRIBUTES</code> when accessing an index. You cannot use both <code>Select</code> and <code>AttributesToGet</code> together in a single request, unless the value for <code>Select</code> is <code>SPECIFIC<em>ATTRIBUTES</code>. (This usage is equivalent to specifying <code>AttributesToGet</code> without any value for <code>Select</code>.)</p> <note> <p>If you use the <code>ProjectionExpression</code> parameter, then the value for <code>Select</code> can only be <code>SPECIFIC</em>ATTRIBUTES</code>. Any other value for <code>Select</code> will return an error.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Select {
+    /// <p><code>ALL_ATTRIBUTES</code></p>
+    AllAttributes,
+    /// <p><code>ALL_PROJECTED_ATTRIBUTES</code></p>
+    AllProjectedAttributes,
+    /// <p><code>SPECIFIC_ATTRIBUTES</code></p>
+    SpecificAttributes,
+    /// <p><code>COUNT</code> - Returns the number of matching items, rather than the matching items themselves.</p>
+    Count,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Select {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Select::AllAttributes => "ALL_ATTRIBUTES",
+            Select::AllProjectedAttributes => "ALL_PROJECTED_ATTRIBUTES",
+            Select::SpecificAttributes => "SPECIFIC_ATTRIBUTES",
+            Select::Count => "COUNT",
+            Select::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Select {
+    fn from(value: &'a str) -> Select {
+        match value {
+            "ALL_ATTRIBUTES" => Select::AllAttributes,
+            "ALL_PROJECTED_ATTRIBUTES" => Select::AllProjectedAttributes,
+            "SPECIFIC_ATTRIBUTES" => Select::SpecificAttributes,
+            "COUNT" => Select::Count,
+            _ => Select::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Select {
+    fn from(value: String) -> Select {
+        Select::from(value.as_str())
+    }
+}
+
+impl From<Select> for String {
+    fn from(value: Select) -> String {
+        match value {
+            Select::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Select {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Select {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Select {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Select, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Select::from(value))
+    }
+}
+
 /// <p>Contains the details of the table when the backup was created. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2012,7 +5244,7 @@ pub struct SourceTableDetails {
     /// <p><p>Controls how you are charged for read and write throughput and how you manage capacity. This setting can be changed later.</p> <ul> <li> <p> <code>PROVISIONED</code> - Sets the read/write capacity mode to <code>PROVISIONED</code>. We recommend using <code>PROVISIONED</code> for predictable workloads.</p> </li> <li> <p> <code>PAY<em>PER</em>REQUEST</code> - Sets the read/write capacity mode to <code>PAY<em>PER</em>REQUEST</code>. We recommend using <code>PAY<em>PER</em>REQUEST</code> for unpredictable workloads. </p> </li> </ul></p>
     #[serde(rename = "BillingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_mode: Option<String>,
+    pub billing_mode: Option<BillingMode>,
     /// <p>Number of items in the table. Please note this is an approximate value. </p>
     #[serde(rename = "ItemCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2029,7 +5261,8 @@ pub struct SourceTableDetails {
     pub table_arn: Option<String>,
     /// <p>Time when the source table was created. </p>
     #[serde(rename = "TableCreationDateTime")]
-    pub table_creation_date_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub table_creation_date_time: EpochTimestamp,
     /// <p>Unique identifier for the table for which the backup was created. </p>
     #[serde(rename = "TableId")]
     pub table_id: String,
@@ -2078,7 +5311,87 @@ pub struct StreamSpecification {
     /// <p><p> When an item in the table is modified, <code>StreamViewType</code> determines what information is written to the stream for this table. Valid values for <code>StreamViewType</code> are:</p> <ul> <li> <p> <code>KEYS<em>ONLY</code> - Only the key attributes of the modified item are written to the stream.</p> </li> <li> <p> <code>NEW</em>IMAGE</code> - The entire item, as it appears after it was modified, is written to the stream.</p> </li> <li> <p> <code>OLD<em>IMAGE</code> - The entire item, as it appeared before it was modified, is written to the stream.</p> </li> <li> <p> <code>NEW</em>AND<em>OLD</em>IMAGES</code> - Both the new and the old item images of the item are written to the stream.</p> </li> </ul></p>
     #[serde(rename = "StreamViewType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_view_type: Option<String>,
+    pub stream_view_type: Option<StreamViewType>,
+}
+
+/// <p><p> When an item in the table is modified, <code>StreamViewType</code> determines what information is written to the stream for this table. Valid values for <code>StreamViewType</code> are:</p> <ul> <li> <p> <code>KEYS<em>ONLY</code> - Only the key attributes of the modified item are written to the stream.</p> </li> <li> <p> <code>NEW</em>IMAGE</code> - The entire item, as it appears after it was modified, is written to the stream.</p> </li> <li> <p> <code>OLD<em>IMAGE</code> - The entire item, as it appeared before it was modified, is written to the stream.</p> </li> <li> <p> <code>NEW</em>AND<em>OLD</em>IMAGES</code> - Both the new and the old item images of the item are written to the stream.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StreamViewType {
+    /// <p><code>NEW_IMAGE</code></p>
+    NewImage,
+    /// <p><code>OLD_IMAGE</code></p>
+    OldImage,
+    /// <p><code>NEW_AND_OLD_IMAGES</code></p>
+    NewAndOldImages,
+    /// <p><code>KEYS_ONLY</code></p>
+    KeysOnly,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StreamViewType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamViewType::NewImage => "NEW_IMAGE",
+            StreamViewType::OldImage => "OLD_IMAGE",
+            StreamViewType::NewAndOldImages => "NEW_AND_OLD_IMAGES",
+            StreamViewType::KeysOnly => "KEYS_ONLY",
+            StreamViewType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StreamViewType {
+    fn from(value: &'a str) -> StreamViewType {
+        match value {
+            "NEW_IMAGE" => StreamViewType::NewImage,
+            "OLD_IMAGE" => StreamViewType::OldImage,
+            "NEW_AND_OLD_IMAGES" => StreamViewType::NewAndOldImages,
+            "KEYS_ONLY" => StreamViewType::KeysOnly,
+            _ => StreamViewType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamViewType {
+    fn from(value: String) -> StreamViewType {
+        StreamViewType::from(value.as_str())
+    }
+}
+
+impl From<StreamViewType> for String {
+    fn from(value: StreamViewType) -> String {
+        match value {
+            StreamViewType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StreamViewType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StreamViewType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamViewType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StreamViewType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StreamViewType::from(value))
+    }
 }
 
 /// <p>Represents the properties of a table.</p>
@@ -2095,8 +5408,9 @@ pub struct TableDescription {
     pub billing_mode_summary: Option<BillingModeSummary>,
     /// <p>The date and time when the table was created, in <a href="http://www.epochconverter.com/">UNIX epoch time</a> format.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The global secondary indexes, if any, on the table. Each index is scoped to a given partition key value. Each element is composed of:</p> <ul> <li> <p> <code>Backfilling</code> - If true, then the index is currently in the backfilling phase. Backfilling occurs only when a new global secondary index is added to the table; it is the process by which DynamoDB populates the new index with data from the table. (This attribute does not appear for indexes that were created during a <code>CreateTable</code> operation.)</p> </li> <li> <p> <code>IndexName</code> - The name of the global secondary index.</p> </li> <li> <p> <code>IndexSizeBytes</code> - The total size of the global secondary index, in bytes. DynamoDB updates this value approximately every six hours. Recent changes might not be reflected in this value. </p> </li> <li> <p> <code>IndexStatus</code> - The current status of the global secondary index:</p> <ul> <li> <p> <code>CREATING</code> - The index is being created.</p> </li> <li> <p> <code>UPDATING</code> - The index is being updated.</p> </li> <li> <p> <code>DELETING</code> - The index is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The index is ready for use.</p> </li> </ul> </li> <li> <p> <code>ItemCount</code> - The number of items in the global secondary index. DynamoDB updates this value approximately every six hours. Recent changes might not be reflected in this value. </p> </li> <li> <p> <code>KeySchema</code> - Specifies the complete index key schema. The attribute names in the key schema must be between 1 and 255 characters (inclusive). The key schema must begin with the same partition key as the table.</p> </li> <li> <p> <code>Projection</code> - Specifies attributes that are copied (projected) from the table into the index. These are in addition to the primary key attributes and index key attributes, which are automatically projected. Each attribute specification is composed of:</p> <ul> <li> <p> <code>ProjectionType</code> - One of the following:</p> <ul> <li> <p> <code>KEYS_ONLY</code> - Only the index and primary keys are projected into the index.</p> </li> <li> <p> <code>INCLUDE</code> - Only the specified table attributes are projected into the index. The list of projected attributes are in <code>NonKeyAttributes</code>.</p> </li> <li> <p> <code>ALL</code> - All of the table attributes are projected into the index.</p> </li> </ul> </li> <li> <p> <code>NonKeyAttributes</code> - A list of one or more non-key attribute names that are projected into the secondary index. The total count of attributes provided in <code>NonKeyAttributes</code>, summed across all of the secondary indexes, must not exceed 20. If you project the same attribute into two different indexes, this counts as two distinct attributes when determining the total.</p> </li> </ul> </li> <li> <p> <code>ProvisionedThroughput</code> - The provisioned throughput settings for the global secondary index, consisting of read and write capacity units, along with data about increases and decreases. </p> </li> </ul> <p>If the table is in the <code>DELETING</code> state, no information about indexes will be returned.</p>
     #[serde(rename = "GlobalSecondaryIndexes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2156,7 +5470,99 @@ pub struct TableDescription {
     /// <p><p>The current state of the table:</p> <ul> <li> <p> <code>CREATING</code> - The table is being created.</p> </li> <li> <p> <code>UPDATING</code> - The table is being updated.</p> </li> <li> <p> <code>DELETING</code> - The table is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The table is ready for use.</p> </li> </ul></p>
     #[serde(rename = "TableStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub table_status: Option<String>,
+    pub table_status: Option<TableStatus>,
+}
+
+/// <p><p>The current state of the table:</p> <ul> <li> <p> <code>CREATING</code> - The table is being created.</p> </li> <li> <p> <code>UPDATING</code> - The table is being updated.</p> </li> <li> <p> <code>DELETING</code> - The table is being deleted.</p> </li> <li> <p> <code>ACTIVE</code> - The table is ready for use.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TableStatus {
+    /// <p><code>CREATING</code> - The table is being created.</p>
+    Creating,
+    /// <p><code>UPDATING</code> - The table is being updated.</p>
+    Updating,
+    /// <p><code>DELETING</code> - The table is being deleted.</p>
+    Deleting,
+    /// <p><code>ACTIVE</code> - The table is ready for use.</p>
+    Active,
+    /// <p><code>INACCESSIBLE_ENCRYPTION_CREDENTIALS</code></p>
+    InaccessibleEncryptionCredentials,
+    /// <p><code>ARCHIVING</code></p>
+    Archiving,
+    /// <p><code>ARCHIVED</code></p>
+    Archived,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TableStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TableStatus::Creating => "CREATING",
+            TableStatus::Updating => "UPDATING",
+            TableStatus::Deleting => "DELETING",
+            TableStatus::Active => "ACTIVE",
+            TableStatus::InaccessibleEncryptionCredentials => "INACCESSIBLE_ENCRYPTION_CREDENTIALS",
+            TableStatus::Archiving => "ARCHIVING",
+            TableStatus::Archived => "ARCHIVED",
+            TableStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TableStatus {
+    fn from(value: &'a str) -> TableStatus {
+        match value {
+            "CREATING" => TableStatus::Creating,
+            "UPDATING" => TableStatus::Updating,
+            "DELETING" => TableStatus::Deleting,
+            "ACTIVE" => TableStatus::Active,
+            "INACCESSIBLE_ENCRYPTION_CREDENTIALS" => TableStatus::InaccessibleEncryptionCredentials,
+            "ARCHIVING" => TableStatus::Archiving,
+            "ARCHIVED" => TableStatus::Archived,
+            _ => TableStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TableStatus {
+    fn from(value: String) -> TableStatus {
+        TableStatus::from(value.as_str())
+    }
+}
+
+impl From<TableStatus> for String {
+    fn from(value: TableStatus) -> String {
+        match value {
+            TableStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TableStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TableStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TableStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TableStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TableStatus::from(value))
+    }
 }
 
 /// <p>Describes a tag. A tag is a key-value pair. You can add up to 50 tags to a single DynamoDB table. </p> <p> AWS-assigned tag names and values are automatically assigned the <code>aws:</code> prefix, which the user cannot assign. AWS-assigned tag names do not count towards the tag limit of 50. User-assigned tag names have the prefix <code>user:</code> in the Cost Allocation Report. You cannot backdate the application of a tag. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
@@ -2180,6 +5586,46 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceInput {
+    /// Returns a builder for `TagResourceInput`, checking its required members when built.
+    pub fn builder() -> TagResourceInputBuilder {
+        TagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceInput`](struct.TagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct TagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceInput, ::rusoto_core::BuildError> {
+        Ok(TagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>The description of the Time to Live (TTL) status on the specified table. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2191,7 +5637,7 @@ pub struct TimeToLiveDescription {
     /// <p> The TTL status for the table.</p>
     #[serde(rename = "TimeToLiveStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_to_live_status: Option<String>,
+    pub time_to_live_status: Option<TimeToLiveStatus>,
 }
 
 /// <p>Represents the settings used to enable or disable Time to Live (TTL) for the specified table.</p>
@@ -2205,7 +5651,87 @@ pub struct TimeToLiveSpecification {
     pub enabled: bool,
 }
 
-/// <p>Specifies an item to be retrieved as part of the transaction.</p>
+/// <p> The TTL status for the table.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TimeToLiveStatus {
+    /// <p><code>ENABLING</code></p>
+    Enabling,
+    /// <p><code>DISABLING</code></p>
+    Disabling,
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TimeToLiveStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TimeToLiveStatus::Enabling => "ENABLING",
+            TimeToLiveStatus::Disabling => "DISABLING",
+            TimeToLiveStatus::Enabled => "ENABLED",
+            TimeToLiveStatus::Disabled => "DISABLED",
+            TimeToLiveStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TimeToLiveStatus {
+    fn from(value: &'a str) -> TimeToLiveStatus {
+        match value {
+            "ENABLING" => TimeToLiveStatus::Enabling,
+            "DISABLING" => TimeToLiveStatus::Disabling,
+            "ENABLED" => TimeToLiveStatus::Enabled,
+            "DISABLED" => TimeToLiveStatus::Disabled,
+            _ => TimeToLiveStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TimeToLiveStatus {
+    fn from(value: String) -> TimeToLiveStatus {
+        TimeToLiveStatus::from(value.as_str())
+    }
+}
+
+impl From<TimeToLiveStatus> for String {
+    fn from(value: TimeToLiveStatus) -> String {
+        match value {
+            TimeToLiveStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TimeToLiveStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TimeToLiveStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TimeToLiveStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TimeToLiveStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TimeToLiveStatus::from(value))
+    }
+}
+
+/// <p>Specifies an item to be retrieved as part of the transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TransactGetItem {
     /// <p>Contains the primary key that identifies the item to get, together with the name of the table that contains the item, and optionally the specific attributes of the item to retrieve.</p>
@@ -2218,12 +5744,50 @@ pub struct TransactGetItemsInput {
     /// <p>A value of <code>TOTAL</code> causes consumed capacity information to be returned, and a value of <code>NONE</code> prevents that information from being returned. No other value is valid.</p>
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>An ordered array of up to 25 <code>TransactGetItem</code> objects, each of which contains a <code>Get</code> structure.</p>
     #[serde(rename = "TransactItems")]
     pub transact_items: Vec<TransactGetItem>,
 }
 
+impl TransactGetItemsInput {
+    /// Returns a builder for `TransactGetItemsInput`, checking its required members when built.
+    pub fn builder() -> TransactGetItemsInputBuilder {
+        TransactGetItemsInputBuilder::default()
+    }
+}
+
+/// Builds a [`TransactGetItemsInput`](struct.TransactGetItemsInput.html).
+#[derive(Default, Debug)]
+pub struct TransactGetItemsInputBuilder {
+    return_consumed_capacity: Option<ReturnConsumedCapacity>,
+    transact_items: Option<Vec<TransactGetItem>>,
+}
+
+impl TransactGetItemsInputBuilder {
+    /// Sets the [`return_consumed_capacity`](struct.TransactGetItemsInput.html#structfield.return_consumed_capacity) member.
+    pub fn return_consumed_capacity<T: Into<ReturnConsumedCapacity>>(mut self, value: T) -> Self {
+        self.return_consumed_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transact_items`](struct.TransactGetItemsInput.html#structfield.transact_items) member.
+    pub fn transact_items<T: Into<Vec<TransactGetItem>>>(mut self, value: T) -> Self {
+        self.transact_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `TransactGetItemsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TransactGetItemsInput, ::rusoto_core::BuildError> {
+        Ok(TransactGetItemsInput {
+            return_consumed_capacity: self.return_consumed_capacity,
+            transact_items: self
+                .transact_items
+                .ok_or(::rusoto_core::BuildError::missing("transact_items"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TransactGetItemsOutput {
@@ -2266,16 +5830,75 @@ pub struct TransactWriteItemsInput {
     pub client_request_token: Option<String>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>Determines whether item collection metrics are returned. If set to <code>SIZE</code>, the response includes statistics about item collections (if any), that were modified during the operation and are returned in the response. If set to <code>NONE</code> (the default), no statistics are returned. </p>
     #[serde(rename = "ReturnItemCollectionMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_item_collection_metrics: Option<String>,
+    pub return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
     /// <p>An ordered array of up to 25 <code>TransactWriteItem</code> objects, each of which contains a <code>ConditionCheck</code>, <code>Put</code>, <code>Update</code>, or <code>Delete</code> object. These can operate on items in different tables, but the tables must reside in the same AWS account and Region, and no two of them can operate on the same item. </p>
     #[serde(rename = "TransactItems")]
     pub transact_items: Vec<TransactWriteItem>,
 }
 
+impl TransactWriteItemsInput {
+    /// Returns a builder for `TransactWriteItemsInput`, checking its required members when built.
+    pub fn builder() -> TransactWriteItemsInputBuilder {
+        TransactWriteItemsInputBuilder::default()
+    }
+}
+
+/// Builds a [`TransactWriteItemsInput`](struct.TransactWriteItemsInput.html).
+#[derive(Default, Debug)]
+pub struct TransactWriteItemsInputBuilder {
+    client_request_token: Option<String>,
+    return_consumed_capacity: Option<ReturnConsumedCapacity>,
+    return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
+    transact_items: Option<Vec<TransactWriteItem>>,
+}
+
+impl TransactWriteItemsInputBuilder {
+    /// Sets the [`client_request_token`](struct.TransactWriteItemsInput.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_consumed_capacity`](struct.TransactWriteItemsInput.html#structfield.return_consumed_capacity) member.
+    pub fn return_consumed_capacity<T: Into<ReturnConsumedCapacity>>(mut self, value: T) -> Self {
+        self.return_consumed_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_item_collection_metrics`](struct.TransactWriteItemsInput.html#structfield.return_item_collection_metrics) member.
+    pub fn return_item_collection_metrics<T: Into<ReturnItemCollectionMetrics>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.return_item_collection_metrics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transact_items`](struct.TransactWriteItemsInput.html#structfield.transact_items) member.
+    pub fn transact_items<T: Into<Vec<TransactWriteItem>>>(mut self, value: T) -> Self {
+        self.transact_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `TransactWriteItemsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TransactWriteItemsInput, ::rusoto_core::BuildError> {
+        Ok(TransactWriteItemsInput {
+            client_request_token: self.client_request_token,
+            return_consumed_capacity: self.return_consumed_capacity,
+            return_item_collection_metrics: self.return_item_collection_metrics,
+            transact_items: self
+                .transact_items
+                .ok_or(::rusoto_core::BuildError::missing("transact_items"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TransactWriteItemsOutput {
@@ -2300,6 +5923,46 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceInput {
+    /// Returns a builder for `UntagResourceInput`, checking its required members when built.
+    pub fn builder() -> UntagResourceInputBuilder {
+        UntagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceInput`](struct.UntagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceInputBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceInputBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceInput.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceInput, ::rusoto_core::BuildError> {
+        Ok(UntagResourceInput {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p>Represents a request to perform an <code>UpdateItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct Update {
@@ -2321,7 +5984,7 @@ pub struct Update {
     /// <p>Use <code>ReturnValuesOnConditionCheckFailure</code> to get the item attributes if the <code>Update</code> condition fails. For <code>ReturnValuesOnConditionCheckFailure</code>, the valid values are: NONE, ALL_OLD, UPDATED_OLD, ALL_NEW, UPDATED_NEW.</p>
     #[serde(rename = "ReturnValuesOnConditionCheckFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values_on_condition_check_failure: Option<String>,
+    pub return_values_on_condition_check_failure: Option<ReturnValuesOnConditionCheckFailure>,
     /// <p>Name of the table for the <code>UpdateItem</code> request.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -2340,6 +6003,51 @@ pub struct UpdateContinuousBackupsInput {
     pub table_name: String,
 }
 
+impl UpdateContinuousBackupsInput {
+    /// Returns a builder for `UpdateContinuousBackupsInput`, checking its required members when built.
+    pub fn builder() -> UpdateContinuousBackupsInputBuilder {
+        UpdateContinuousBackupsInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateContinuousBackupsInput`](struct.UpdateContinuousBackupsInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateContinuousBackupsInputBuilder {
+    point_in_time_recovery_specification: Option<PointInTimeRecoverySpecification>,
+    table_name: Option<String>,
+}
+
+impl UpdateContinuousBackupsInputBuilder {
+    /// Sets the [`point_in_time_recovery_specification`](struct.UpdateContinuousBackupsInput.html#structfield.point_in_time_recovery_specification) member.
+    pub fn point_in_time_recovery_specification<T: Into<PointInTimeRecoverySpecification>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.point_in_time_recovery_specification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.UpdateContinuousBackupsInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateContinuousBackupsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateContinuousBackupsInput, ::rusoto_core::BuildError> {
+        Ok(UpdateContinuousBackupsInput {
+            point_in_time_recovery_specification: self.point_in_time_recovery_specification.ok_or(
+                ::rusoto_core::BuildError::missing("point_in_time_recovery_specification"),
+            )?,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateContinuousBackupsOutput {
@@ -2370,6 +6078,46 @@ pub struct UpdateGlobalTableInput {
     pub replica_updates: Vec<ReplicaUpdate>,
 }
 
+impl UpdateGlobalTableInput {
+    /// Returns a builder for `UpdateGlobalTableInput`, checking its required members when built.
+    pub fn builder() -> UpdateGlobalTableInputBuilder {
+        UpdateGlobalTableInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGlobalTableInput`](struct.UpdateGlobalTableInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateGlobalTableInputBuilder {
+    global_table_name: Option<String>,
+    replica_updates: Option<Vec<ReplicaUpdate>>,
+}
+
+impl UpdateGlobalTableInputBuilder {
+    /// Sets the [`global_table_name`](struct.UpdateGlobalTableInput.html#structfield.global_table_name) member.
+    pub fn global_table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.global_table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replica_updates`](struct.UpdateGlobalTableInput.html#structfield.replica_updates) member.
+    pub fn replica_updates<T: Into<Vec<ReplicaUpdate>>>(mut self, value: T) -> Self {
+        self.replica_updates = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGlobalTableInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateGlobalTableInput, ::rusoto_core::BuildError> {
+        Ok(UpdateGlobalTableInput {
+            global_table_name: self
+                .global_table_name
+                .ok_or(::rusoto_core::BuildError::missing("global_table_name"))?,
+            replica_updates: self
+                .replica_updates
+                .ok_or(::rusoto_core::BuildError::missing("replica_updates"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateGlobalTableOutput {
@@ -2384,7 +6132,7 @@ pub struct UpdateGlobalTableSettingsInput {
     /// <p>The billing mode of the global table. If <code>GlobalTableBillingMode</code> is not specified, the global table defaults to <code>PROVISIONED</code> capacity billing mode.</p>
     #[serde(rename = "GlobalTableBillingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub global_table_billing_mode: Option<String>,
+    pub global_table_billing_mode: Option<BillingMode>,
     /// <p>Represents the settings of a global secondary index for a global table that will be modified.</p>
     #[serde(rename = "GlobalTableGlobalSecondaryIndexSettingsUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2408,6 +6156,97 @@ pub struct UpdateGlobalTableSettingsInput {
     pub replica_settings_update: Option<Vec<ReplicaSettingsUpdate>>,
 }
 
+impl UpdateGlobalTableSettingsInput {
+    /// Returns a builder for `UpdateGlobalTableSettingsInput`, checking its required members when built.
+    pub fn builder() -> UpdateGlobalTableSettingsInputBuilder {
+        UpdateGlobalTableSettingsInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGlobalTableSettingsInput`](struct.UpdateGlobalTableSettingsInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateGlobalTableSettingsInputBuilder {
+    global_table_billing_mode: Option<BillingMode>,
+    global_table_global_secondary_index_settings_update:
+        Option<Vec<GlobalTableGlobalSecondaryIndexSettingsUpdate>>,
+    global_table_name: Option<String>,
+    global_table_provisioned_write_capacity_auto_scaling_settings_update:
+        Option<AutoScalingSettingsUpdate>,
+    global_table_provisioned_write_capacity_units: Option<i64>,
+    replica_settings_update: Option<Vec<ReplicaSettingsUpdate>>,
+}
+
+impl UpdateGlobalTableSettingsInputBuilder {
+    /// Sets the [`global_table_billing_mode`](struct.UpdateGlobalTableSettingsInput.html#structfield.global_table_billing_mode) member.
+    pub fn global_table_billing_mode<T: Into<BillingMode>>(mut self, value: T) -> Self {
+        self.global_table_billing_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`global_table_global_secondary_index_settings_update`](struct.UpdateGlobalTableSettingsInput.html#structfield.global_table_global_secondary_index_settings_update) member.
+    pub fn global_table_global_secondary_index_settings_update<
+        T: Into<Vec<GlobalTableGlobalSecondaryIndexSettingsUpdate>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.global_table_global_secondary_index_settings_update = Some(value.into());
+        self
+    }
+
+    /// Sets the [`global_table_name`](struct.UpdateGlobalTableSettingsInput.html#structfield.global_table_name) member.
+    pub fn global_table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.global_table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`global_table_provisioned_write_capacity_auto_scaling_settings_update`](struct.UpdateGlobalTableSettingsInput.html#structfield.global_table_provisioned_write_capacity_auto_scaling_settings_update) member.
+    pub fn global_table_provisioned_write_capacity_auto_scaling_settings_update<
+        T: Into<AutoScalingSettingsUpdate>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.global_table_provisioned_write_capacity_auto_scaling_settings_update =
+            Some(value.into());
+        self
+    }
+
+    /// Sets the [`global_table_provisioned_write_capacity_units`](struct.UpdateGlobalTableSettingsInput.html#structfield.global_table_provisioned_write_capacity_units) member.
+    pub fn global_table_provisioned_write_capacity_units<T: Into<i64>>(mut self, value: T) -> Self {
+        self.global_table_provisioned_write_capacity_units = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replica_settings_update`](struct.UpdateGlobalTableSettingsInput.html#structfield.replica_settings_update) member.
+    pub fn replica_settings_update<T: Into<Vec<ReplicaSettingsUpdate>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.replica_settings_update = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGlobalTableSettingsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateGlobalTableSettingsInput, ::rusoto_core::BuildError> {
+        Ok(UpdateGlobalTableSettingsInput {
+            global_table_billing_mode: self.global_table_billing_mode,
+            global_table_global_secondary_index_settings_update: self
+                .global_table_global_secondary_index_settings_update,
+            global_table_name: self
+                .global_table_name
+                .ok_or(::rusoto_core::BuildError::missing("global_table_name"))?,
+            global_table_provisioned_write_capacity_auto_scaling_settings_update: self
+                .global_table_provisioned_write_capacity_auto_scaling_settings_update,
+            global_table_provisioned_write_capacity_units: self
+                .global_table_provisioned_write_capacity_units,
+            replica_settings_update: self.replica_settings_update,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateGlobalTableSettingsOutput {
@@ -2435,7 +6274,7 @@ pub struct UpdateItemInput {
     /// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.ConditionalOperator.html">ConditionalOperator</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "ConditionalOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub conditional_operator: Option<String>,
+    pub conditional_operator: Option<ConditionalOperator>,
     /// <p>This is a legacy parameter. Use <code>ConditionExpression</code> instead. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/LegacyConditionalParameters.Expected.html">Expected</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
     #[serde(rename = "Expected")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2453,15 +6292,15 @@ pub struct UpdateItemInput {
     pub key: ::std::collections::HashMap<String, AttributeValue>,
     #[serde(rename = "ReturnConsumedCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_consumed_capacity: Option<String>,
+    pub return_consumed_capacity: Option<ReturnConsumedCapacity>,
     /// <p>Determines whether item collection metrics are returned. If set to <code>SIZE</code>, the response includes statistics about item collections, if any, that were modified during the operation are returned in the response. If set to <code>NONE</code> (the default), no statistics are returned.</p>
     #[serde(rename = "ReturnItemCollectionMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_item_collection_metrics: Option<String>,
+    pub return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
     /// <p>Use <code>ReturnValues</code> if you want to get the item attributes as they appear before or after they are updated. For <code>UpdateItem</code>, the valid values are:</p> <ul> <li> <p> <code>NONE</code> - If <code>ReturnValues</code> is not specified, or if its value is <code>NONE</code>, then nothing is returned. (This setting is the default for <code>ReturnValues</code>.)</p> </li> <li> <p> <code>ALL_OLD</code> - Returns all of the attributes of the item, as they appeared before the UpdateItem operation.</p> </li> <li> <p> <code>UPDATED_OLD</code> - Returns only the updated attributes, as they appeared before the UpdateItem operation.</p> </li> <li> <p> <code>ALL_NEW</code> - Returns all of the attributes of the item, as they appear after the UpdateItem operation.</p> </li> <li> <p> <code>UPDATED_NEW</code> - Returns only the updated attributes, as they appear after the UpdateItem operation.</p> </li> </ul> <p>There is no additional cost associated with requesting a return value aside from the small network and processing overhead of receiving a larger response. No read capacity units are consumed.</p> <p>The values returned are strongly consistent.</p>
     #[serde(rename = "ReturnValues")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub return_values: Option<String>,
+    pub return_values: Option<ReturnValue>,
     /// <p>The name of the table containing the item to update.</p>
     #[serde(rename = "TableName")]
     pub table_name: String,
@@ -2471,6 +6310,144 @@ pub struct UpdateItemInput {
     pub update_expression: Option<String>,
 }
 
+impl UpdateItemInput {
+    /// Returns a builder for `UpdateItemInput`, checking its required members when built.
+    pub fn builder() -> UpdateItemInputBuilder {
+        UpdateItemInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateItemInput`](struct.UpdateItemInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateItemInputBuilder {
+    attribute_updates: Option<::std::collections::HashMap<String, AttributeValueUpdate>>,
+    condition_expression: Option<String>,
+    conditional_operator: Option<ConditionalOperator>,
+    expected: Option<::std::collections::HashMap<String, ExpectedAttributeValue>>,
+    expression_attribute_names: Option<::std::collections::HashMap<String, String>>,
+    expression_attribute_values: Option<::std::collections::HashMap<String, AttributeValue>>,
+    key: Option<::std::collections::HashMap<String, AttributeValue>>,
+    return_consumed_capacity: Option<ReturnConsumedCapacity>,
+    return_item_collection_metrics: Option<ReturnItemCollectionMetrics>,
+    return_values: Option<ReturnValue>,
+    table_name: Option<String>,
+    update_expression: Option<String>,
+}
+
+impl UpdateItemInputBuilder {
+    /// Sets the [`attribute_updates`](struct.UpdateItemInput.html#structfield.attribute_updates) member.
+    pub fn attribute_updates<T: Into<::std::collections::HashMap<String, AttributeValueUpdate>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attribute_updates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`condition_expression`](struct.UpdateItemInput.html#structfield.condition_expression) member.
+    pub fn condition_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.condition_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conditional_operator`](struct.UpdateItemInput.html#structfield.conditional_operator) member.
+    pub fn conditional_operator<T: Into<ConditionalOperator>>(mut self, value: T) -> Self {
+        self.conditional_operator = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expected`](struct.UpdateItemInput.html#structfield.expected) member.
+    pub fn expected<T: Into<::std::collections::HashMap<String, ExpectedAttributeValue>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expected = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_attribute_names`](struct.UpdateItemInput.html#structfield.expression_attribute_names) member.
+    pub fn expression_attribute_names<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expression_attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression_attribute_values`](struct.UpdateItemInput.html#structfield.expression_attribute_values) member.
+    pub fn expression_attribute_values<
+        T: Into<::std::collections::HashMap<String, AttributeValue>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.expression_attribute_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key`](struct.UpdateItemInput.html#structfield.key) member.
+    pub fn key<T: Into<::std::collections::HashMap<String, AttributeValue>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_consumed_capacity`](struct.UpdateItemInput.html#structfield.return_consumed_capacity) member.
+    pub fn return_consumed_capacity<T: Into<ReturnConsumedCapacity>>(mut self, value: T) -> Self {
+        self.return_consumed_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_item_collection_metrics`](struct.UpdateItemInput.html#structfield.return_item_collection_metrics) member.
+    pub fn return_item_collection_metrics<T: Into<ReturnItemCollectionMetrics>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.return_item_collection_metrics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`return_values`](struct.UpdateItemInput.html#structfield.return_values) member.
+    pub fn return_values<T: Into<ReturnValue>>(mut self, value: T) -> Self {
+        self.return_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.UpdateItemInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_expression`](struct.UpdateItemInput.html#structfield.update_expression) member.
+    pub fn update_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.update_expression = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateItemInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateItemInput, ::rusoto_core::BuildError> {
+        Ok(UpdateItemInput {
+            attribute_updates: self.attribute_updates,
+            condition_expression: self.condition_expression,
+            conditional_operator: self.conditional_operator,
+            expected: self.expected,
+            expression_attribute_names: self.expression_attribute_names,
+            expression_attribute_values: self.expression_attribute_values,
+            key: self.key.ok_or(::rusoto_core::BuildError::missing("key"))?,
+            return_consumed_capacity: self.return_consumed_capacity,
+            return_item_collection_metrics: self.return_item_collection_metrics,
+            return_values: self.return_values,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+            update_expression: self.update_expression,
+        })
+    }
+}
+
 /// <p>Represents the output of an <code>UpdateItem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2499,7 +6476,7 @@ pub struct UpdateTableInput {
     /// <p><p>Controls how you are charged for read and write throughput and how you manage capacity. When switching from pay-per-request to provisioned capacity, initial provisioned capacity values must be set. The initial provisioned capacity values are estimated based on the consumed read and write capacity of your table and global secondary indexes over the past 30 minutes.</p> <ul> <li> <p> <code>PROVISIONED</code> - Sets the billing mode to <code>PROVISIONED</code>. We recommend using <code>PROVISIONED</code> for predictable workloads.</p> </li> <li> <p> <code>PAY<em>PER</em>REQUEST</code> - Sets the billing mode to <code>PAY<em>PER</em>REQUEST</code>. We recommend using <code>PAY<em>PER</em>REQUEST</code> for unpredictable workloads. </p> </li> </ul></p>
     #[serde(rename = "BillingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub billing_mode: Option<String>,
+    pub billing_mode: Option<BillingMode>,
     /// <p>An array of one or more global secondary indexes for the table. For each index in the array, you can request one action:</p> <ul> <li> <p> <code>Create</code> - add a new global secondary index to the table.</p> </li> <li> <p> <code>Update</code> - modify the provisioned throughput settings of an existing global secondary index.</p> </li> <li> <p> <code>Delete</code> - remove a global secondary index from the table.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/GSI.OnlineOps.html">Managing Global Secondary Indexes</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p>
     #[serde(rename = "GlobalSecondaryIndexUpdates")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2521,6 +6498,87 @@ pub struct UpdateTableInput {
     pub table_name: String,
 }
 
+impl UpdateTableInput {
+    /// Returns a builder for `UpdateTableInput`, checking its required members when built.
+    pub fn builder() -> UpdateTableInputBuilder {
+        UpdateTableInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTableInput`](struct.UpdateTableInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateTableInputBuilder {
+    attribute_definitions: Option<Vec<AttributeDefinition>>,
+    billing_mode: Option<BillingMode>,
+    global_secondary_index_updates: Option<Vec<GlobalSecondaryIndexUpdate>>,
+    provisioned_throughput: Option<ProvisionedThroughput>,
+    sse_specification: Option<SSESpecification>,
+    stream_specification: Option<StreamSpecification>,
+    table_name: Option<String>,
+}
+
+impl UpdateTableInputBuilder {
+    /// Sets the [`attribute_definitions`](struct.UpdateTableInput.html#structfield.attribute_definitions) member.
+    pub fn attribute_definitions<T: Into<Vec<AttributeDefinition>>>(mut self, value: T) -> Self {
+        self.attribute_definitions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`billing_mode`](struct.UpdateTableInput.html#structfield.billing_mode) member.
+    pub fn billing_mode<T: Into<BillingMode>>(mut self, value: T) -> Self {
+        self.billing_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`global_secondary_index_updates`](struct.UpdateTableInput.html#structfield.global_secondary_index_updates) member.
+    pub fn global_secondary_index_updates<T: Into<Vec<GlobalSecondaryIndexUpdate>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.global_secondary_index_updates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_throughput`](struct.UpdateTableInput.html#structfield.provisioned_throughput) member.
+    pub fn provisioned_throughput<T: Into<ProvisionedThroughput>>(mut self, value: T) -> Self {
+        self.provisioned_throughput = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sse_specification`](struct.UpdateTableInput.html#structfield.sse_specification) member.
+    pub fn sse_specification<T: Into<SSESpecification>>(mut self, value: T) -> Self {
+        self.sse_specification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_specification`](struct.UpdateTableInput.html#structfield.stream_specification) member.
+    pub fn stream_specification<T: Into<StreamSpecification>>(mut self, value: T) -> Self {
+        self.stream_specification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.UpdateTableInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTableInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateTableInput, ::rusoto_core::BuildError> {
+        Ok(UpdateTableInput {
+            attribute_definitions: self.attribute_definitions,
+            billing_mode: self.billing_mode,
+            global_secondary_index_updates: self.global_secondary_index_updates,
+            provisioned_throughput: self.provisioned_throughput,
+            sse_specification: self.sse_specification,
+            stream_specification: self.stream_specification,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of an <code>UpdateTable</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2542,6 +6600,49 @@ pub struct UpdateTimeToLiveInput {
     pub time_to_live_specification: TimeToLiveSpecification,
 }
 
+impl UpdateTimeToLiveInput {
+    /// Returns a builder for `UpdateTimeToLiveInput`, checking its required members when built.
+    pub fn builder() -> UpdateTimeToLiveInputBuilder {
+        UpdateTimeToLiveInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTimeToLiveInput`](struct.UpdateTimeToLiveInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateTimeToLiveInputBuilder {
+    table_name: Option<String>,
+    time_to_live_specification: Option<TimeToLiveSpecification>,
+}
+
+impl UpdateTimeToLiveInputBuilder {
+    /// Sets the [`table_name`](struct.UpdateTimeToLiveInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_to_live_specification`](struct.UpdateTimeToLiveInput.html#structfield.time_to_live_specification) member.
+    pub fn time_to_live_specification<T: Into<TimeToLiveSpecification>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.time_to_live_specification = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTimeToLiveInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateTimeToLiveInput, ::rusoto_core::BuildError> {
+        Ok(UpdateTimeToLiveInput {
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+            time_to_live_specification: self.time_to_live_specification.ok_or(
+                ::rusoto_core::BuildError::missing("time_to_live_specification"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateTimeToLiveOutput {
@@ -4451,198 +8552,1011 @@ impl Error for UpdateTimeToLiveError {
 /// Trait representing the capabilities of the DynamoDB API. DynamoDB clients implement this trait.
 pub trait DynamoDb {
     /// <p>The <code>BatchGetItem</code> operation returns the attributes of one or more items from one or more tables. You identify requested items by primary key.</p> <p>A single operation can retrieve up to 16 MB of data, which can contain as many as 100 items. <code>BatchGetItem</code> returns a partial result if the response size limit is exceeded, the table's provisioned throughput is exceeded, or an internal processing failure occurs. If a partial result is returned, the operation returns a value for <code>UnprocessedKeys</code>. You can use this value to retry the operation starting with the next item to get.</p> <important> <p>If you request more than 100 items, <code>BatchGetItem</code> returns a <code>ValidationException</code> with the message "Too many items requested for the BatchGetItem call."</p> </important> <p>For example, if you ask to retrieve 100 items, but each individual item is 300 KB in size, the system returns 52 items (so as not to exceed the 16 MB limit). It also returns an appropriate <code>UnprocessedKeys</code> value so you can get the next page of results. If desired, your application can include its own logic to assemble the pages of results into one dataset.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchGetItem</code> returns a <code>ProvisionedThroughputExceededException</code>. If <i>at least one</i> of the items is successfully processed, then <code>BatchGetItem</code> completes successfully, while returning the keys of the unread items in <code>UnprocessedKeys</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>By default, <code>BatchGetItem</code> performs eventually consistent reads on every table in the request. If you want strongly consistent reads instead, you can set <code>ConsistentRead</code> to <code>true</code> for any or all tables.</p> <p>In order to minimize response latency, <code>BatchGetItem</code> retrieves items in parallel.</p> <p>When designing your application, keep in mind that DynamoDB does not return items in any particular order. To help parse the response by item, include the primary key values for the items in your request in the <code>ProjectionExpression</code> parameter.</p> <p>If a requested item does not exist, it is not returned in the result. Requests for nonexistent items consume the minimum read capacity units according to the type of read. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithTables.html#CapacityUnitCalculations">Working with Tables</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{BatchGetItemInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = BatchGetItemInput::builder()
+    ///     .request_items(::std::collections::HashMap::new())
+    ///     .build()
+    ///     .unwrap();
+    /// match client.batch_get_item(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("BatchGetItem failed: {}", error),
+    /// }
+    /// ```
     fn batch_get_item(
         &self,
         input: BatchGetItemInput,
     ) -> RusotoFuture<BatchGetItemOutput, BatchGetItemError>;
 
     /// <p><p>The <code>BatchWriteItem</code> operation puts or deletes multiple items in one or more tables. A single call to <code>BatchWriteItem</code> can write up to 16 MB of data, which can comprise as many as 25 put or delete requests. Individual items to be written can be as large as 400 KB.</p> <note> <p> <code>BatchWriteItem</code> cannot update items. To update items, use the <code>UpdateItem</code> action.</p> </note> <p>The individual <code>PutItem</code> and <code>DeleteItem</code> operations specified in <code>BatchWriteItem</code> are atomic; however <code>BatchWriteItem</code> as a whole is not. If any requested operations fail because the table&#39;s provisioned throughput is exceeded or an internal processing failure occurs, the failed operations are returned in the <code>UnprocessedItems</code> response parameter. You can investigate and optionally resend the requests. Typically, you would call <code>BatchWriteItem</code> in a loop. Each iteration would check for unprocessed items and submit a new <code>BatchWriteItem</code> request with those unprocessed items until all items have been processed.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchWriteItem</code> returns a <code>ProvisionedThroughputExceededException</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#Programming.Errors.BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>With <code>BatchWriteItem</code>, you can efficiently write or delete large amounts of data, such as from Amazon EMR, or copy data from another database into DynamoDB. In order to improve performance with these large-scale operations, <code>BatchWriteItem</code> does not behave in the same way as individual <code>PutItem</code> and <code>DeleteItem</code> calls would. For example, you cannot specify conditions on individual put and delete requests, and <code>BatchWriteItem</code> does not return deleted items in the response.</p> <p>If you use a programming language that supports concurrency, you can use threads to write items in parallel. Your application must include the necessary logic to manage the threads. With languages that don&#39;t support threading, you must update or delete the specified items one at a time. In both situations, <code>BatchWriteItem</code> performs the specified put and delete operations in parallel, giving you the power of the thread pool approach without having to introduce complexity into your application.</p> <p>Parallel processing reduces latency, but each specified put and delete request consumes the same number of write capacity units whether it is processed in parallel or not. Delete operations on nonexistent items consume one write capacity unit.</p> <p>If one or more of the following is true, DynamoDB rejects the entire batch write operation:</p> <ul> <li> <p>One or more tables specified in the <code>BatchWriteItem</code> request does not exist.</p> </li> <li> <p>Primary key attributes specified on an item in the request do not match those in the corresponding table&#39;s primary key schema.</p> </li> <li> <p>You try to perform multiple operations on the same item in the same <code>BatchWriteItem</code> request. For example, you cannot put and delete the same item in the same <code>BatchWriteItem</code> request. </p> </li> <li> <p> Your request contains at least two items with identical hash and range keys (which essentially is two put operations). </p> </li> <li> <p>There are more than 25 requests in the batch.</p> </li> <li> <p>Any individual item in a batch exceeds 400 KB.</p> </li> <li> <p>The total request size exceeds 16 MB.</p> </li> </ul></p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{BatchWriteItemInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = BatchWriteItemInput::builder()
+    ///     .request_items(::std::collections::HashMap::new())
+    ///     .build()
+    ///     .unwrap();
+    /// match client.batch_write_item(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("BatchWriteItem failed: {}", error),
+    /// }
+    /// ```
     fn batch_write_item(
         &self,
         input: BatchWriteItemInput,
     ) -> RusotoFuture<BatchWriteItemOutput, BatchWriteItemError>;
 
     /// <p><p>Creates a backup for an existing table.</p> <p> Each time you create an on-demand backup, the entire table data is backed up. There is no limit to the number of on-demand backups that can be taken. </p> <p> When you create an on-demand backup, a time marker of the request is cataloged, and the backup is created asynchronously, by applying all changes until the time of the request to the last full table snapshot. Backup requests are processed instantaneously and become available for restore within minutes. </p> <p>You can call <code>CreateBackup</code> at a maximum rate of 50 times per second.</p> <p>All backups in DynamoDB work without consuming any provisioned throughput on the table.</p> <p> If you submit a backup request on 2018-12-14 at 14:25:00, the backup is guaranteed to contain all data committed to the table up to 14:24:00, and data committed after 14:26:00 will not be. The backup might contain data modifications made between 14:24:00 and 14:26:00. On-demand backup does not support causal consistency. </p> <p> Along with data, the following are also included on the backups: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Streams</p> </li> <li> <p>Provisioned read and write capacity</p> </li> </ul></p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{CreateBackupInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = CreateBackupInput::builder()
+    ///     .backup_name("backup_name")
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.create_backup(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("CreateBackup failed: {}", error),
+    /// }
+    /// ```
     fn create_backup(
         &self,
         input: CreateBackupInput,
     ) -> RusotoFuture<CreateBackupOutput, CreateBackupError>;
 
     /// <p><p>Creates a global table from an existing table. A global table creates a replication relationship between two or more DynamoDB tables with the same table name in the provided Regions. </p> <p>If you want to add a new replica table to a global table, each of the following conditions must be true:</p> <ul> <li> <p>The table must have the same primary key as all of the other replicas.</p> </li> <li> <p>The table must have the same name as all of the other replicas.</p> </li> <li> <p>The table must have DynamoDB Streams enabled, with the stream containing both the new and the old images of the item.</p> </li> <li> <p>None of the replica tables in the global table can contain any data.</p> </li> </ul> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> </ul> <important> <p> Write capacity settings should be set consistently across your replica tables and secondary indexes. DynamoDB strongly recommends enabling auto scaling to manage the write capacity settings for all of your global tables replicas and indexes. </p> <p> If you prefer to manage write capacity settings manually, you should provision equal replicated write capacity units to your replica tables. You should also provision equal replicated write capacity units to matching secondary indexes across your global table. </p> </important></p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{CreateGlobalTableInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = CreateGlobalTableInput::builder()
+    ///     .global_table_name("global_table_name")
+    ///     .replication_group(Vec::new())
+    ///     .build()
+    ///     .unwrap();
+    /// match client.create_global_table(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("CreateGlobalTable failed: {}", error),
+    /// }
+    /// ```
     fn create_global_table(
         &self,
         input: CreateGlobalTableInput,
     ) -> RusotoFuture<CreateGlobalTableOutput, CreateGlobalTableError>;
 
     /// <p>The <code>CreateTable</code> operation adds a new table to your account. In an AWS account, table names must be unique within each Region. That is, you can have two tables with same name if you create the tables in different Regions.</p> <p> <code>CreateTable</code> is an asynchronous operation. Upon receiving a <code>CreateTable</code> request, DynamoDB immediately returns a response with a <code>TableStatus</code> of <code>CREATING</code>. After the table is created, DynamoDB sets the <code>TableStatus</code> to <code>ACTIVE</code>. You can perform read and write operations only on an <code>ACTIVE</code> table. </p> <p>You can optionally define secondary indexes on the new table, as part of the <code>CreateTable</code> operation. If you want to create multiple tables with secondary indexes on them, you must create the tables sequentially. Only one table with secondary indexes can be in the <code>CREATING</code> state at any given time.</p> <p>You can use the <code>DescribeTable</code> action to check the table status.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{CreateTableInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = CreateTableInput::builder()
+    ///     .attribute_definitions(Vec::new())
+    ///     .key_schema(Vec::new())
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.create_table(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("CreateTable failed: {}", error),
+    /// }
+    /// ```
     fn create_table(
         &self,
         input: CreateTableInput,
     ) -> RusotoFuture<CreateTableOutput, CreateTableError>;
 
     /// <p>Deletes an existing backup of a table.</p> <p>You can call <code>DeleteBackup</code> at a maximum rate of 10 times per second.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DeleteBackupInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = DeleteBackupInput::builder()
+    ///     .backup_arn("backup_arn")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.delete_backup(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("DeleteBackup failed: {}", error),
+    /// }
+    /// ```
     fn delete_backup(
         &self,
         input: DeleteBackupInput,
     ) -> RusotoFuture<DeleteBackupOutput, DeleteBackupError>;
 
     /// <p>Deletes a single item in a table by primary key. You can perform a conditional delete operation that deletes the item if it exists, or if it has an expected attribute value.</p> <p>In addition to deleting an item, you can also return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <p>Unless you specify conditions, the <code>DeleteItem</code> is an idempotent operation; running it multiple times on the same item or attribute does <i>not</i> result in an error response.</p> <p>Conditional deletes are useful for deleting items only if specific conditions are met. If those conditions are met, DynamoDB performs the delete. Otherwise, the item is not deleted.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DeleteItemInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = DeleteItemInput::builder()
+    ///     .key(::std::collections::HashMap::new())
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.delete_item(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("DeleteItem failed: {}", error),
+    /// }
+    /// ```
     fn delete_item(
         &self,
         input: DeleteItemInput,
     ) -> RusotoFuture<DeleteItemOutput, DeleteItemError>;
 
     /// <p>The <code>DeleteTable</code> operation deletes a table and all of its items. After a <code>DeleteTable</code> request, the specified table is in the <code>DELETING</code> state until DynamoDB completes the deletion. If the table is in the <code>ACTIVE</code> state, you can delete it. If a table is in <code>CREATING</code> or <code>UPDATING</code> states, then DynamoDB returns a <code>ResourceInUseException</code>. If the specified table does not exist, DynamoDB returns a <code>ResourceNotFoundException</code>. If table is already in the <code>DELETING</code> state, no error is returned. </p> <note> <p>DynamoDB might continue to accept data read and write operations, such as <code>GetItem</code> and <code>PutItem</code>, on a table in the <code>DELETING</code> state until the table deletion is complete.</p> </note> <p>When you delete a table, any indexes on that table are also deleted.</p> <p>If you have DynamoDB Streams enabled on the table, then the corresponding stream on that table goes into the <code>DISABLED</code> state, and the stream is automatically deleted after 24 hours.</p> <p>Use the <code>DescribeTable</code> action to check the status of the table. </p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DeleteTableInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = DeleteTableInput::builder()
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.delete_table(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("DeleteTable failed: {}", error),
+    /// }
+    /// ```
     fn delete_table(
         &self,
         input: DeleteTableInput,
     ) -> RusotoFuture<DeleteTableOutput, DeleteTableError>;
 
     /// <p>Describes an existing backup of a table.</p> <p>You can call <code>DescribeBackup</code> at a maximum rate of 10 times per second.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DescribeBackupInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = DescribeBackupInput::builder()
+    ///     .backup_arn("backup_arn")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.describe_backup(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("DescribeBackup failed: {}", error),
+    /// }
+    /// ```
     fn describe_backup(
         &self,
         input: DescribeBackupInput,
     ) -> RusotoFuture<DescribeBackupOutput, DescribeBackupError>;
 
     /// <p>Checks the status of continuous backups and point in time recovery on the specified table. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> After continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p> <p>You can call <code>DescribeContinuousBackups</code> at a maximum rate of 10 times per second.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DescribeContinuousBackupsInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = DescribeContinuousBackupsInput::builder()
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.describe_continuous_backups(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("DescribeContinuousBackups failed: {}", error),
+    /// }
+    /// ```
     fn describe_continuous_backups(
         &self,
         input: DescribeContinuousBackupsInput,
     ) -> RusotoFuture<DescribeContinuousBackupsOutput, DescribeContinuousBackupsError>;
 
     /// <p>Returns the regional endpoint information.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// match client.describe_endpoints().sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("DescribeEndpoints failed: {}", error),
+    /// }
+    /// ```
     fn describe_endpoints(&self)
         -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError>;
 
     /// <p>Returns information about the specified global table.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DescribeGlobalTableInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = DescribeGlobalTableInput::builder()
+    ///     .global_table_name("global_table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.describe_global_table(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("DescribeGlobalTable failed: {}", error),
+    /// }
+    /// ```
     fn describe_global_table(
         &self,
         input: DescribeGlobalTableInput,
     ) -> RusotoFuture<DescribeGlobalTableOutput, DescribeGlobalTableError>;
 
     /// <p>Describes Region-specific settings for a global table.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DescribeGlobalTableSettingsInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = DescribeGlobalTableSettingsInput::builder()
+    ///     .global_table_name("global_table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.describe_global_table_settings(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("DescribeGlobalTableSettings failed: {}", error),
+    /// }
+    /// ```
     fn describe_global_table_settings(
         &self,
         input: DescribeGlobalTableSettingsInput,
     ) -> RusotoFuture<DescribeGlobalTableSettingsOutput, DescribeGlobalTableSettingsError>;
 
     /// <p>Returns the current provisioned-capacity limits for your AWS account in a Region, both for the Region as a whole and for any one DynamoDB table that you create there.</p> <p>When you establish an AWS account, the account has initial limits on the maximum read capacity units and write capacity units that you can provision across all of your DynamoDB tables in a given Region. Also, there are per-table limits that apply when you create a table there. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Limits.html">Limits</a> page in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p>Although you can increase these limits by filing a case at <a href="https://console.aws.amazon.com/support/home#/">AWS Support Center</a>, obtaining the increase is not instantaneous. The <code>DescribeLimits</code> action lets you write code to compare the capacity you are currently using to those limits imposed by your account so that you have enough time to apply for an increase before you hit a limit.</p> <p>For example, you could use one of the AWS SDKs to do the following:</p> <ol> <li> <p>Call <code>DescribeLimits</code> for a particular Region to obtain your current account limits on provisioned capacity there.</p> </li> <li> <p>Create a variable to hold the aggregate read capacity units provisioned for all your tables in that Region, and one to hold the aggregate write capacity units. Zero them both.</p> </li> <li> <p>Call <code>ListTables</code> to obtain a list of all your DynamoDB tables.</p> </li> <li> <p>For each table name listed by <code>ListTables</code>, do the following:</p> <ul> <li> <p>Call <code>DescribeTable</code> with the table name.</p> </li> <li> <p>Use the data returned by <code>DescribeTable</code> to add the read capacity units and write capacity units provisioned for the table itself to your variables.</p> </li> <li> <p>If the table has one or more global secondary indexes (GSIs), loop over these GSIs and add their provisioned capacity values to your variables as well.</p> </li> </ul> </li> <li> <p>Report the account limits for that Region returned by <code>DescribeLimits</code>, along with the total current provisioned capacity levels you have calculated.</p> </li> </ol> <p>This will let you see whether you are getting close to your account-level limits.</p> <p>The per-table limits apply only when you are creating a new table. They restrict the sum of the provisioned capacity of the new table itself and all its global secondary indexes.</p> <p>For existing tables and their GSIs, DynamoDB doesn't let you increase provisioned capacity extremely rapidly. But the only upper limit that applies is that the aggregate provisioned capacity over all your tables and GSIs cannot exceed either of the per-account limits.</p> <note> <p> <code>DescribeLimits</code> should only be called periodically. You can expect throttling errors if you call it more than once in a minute.</p> </note> <p>The <code>DescribeLimits</code> Request element has no content.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// match client.describe_limits().sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("DescribeLimits failed: {}", error),
+    /// }
+    /// ```
     fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError>;
 
     /// <p><p>Returns information about the table, including the current status of the table, when it was created, the primary key schema, and any indexes on the table.</p> <note> <p>If you issue a <code>DescribeTable</code> request immediately after a <code>CreateTable</code> request, DynamoDB might return a <code>ResourceNotFoundException</code>. This is because <code>DescribeTable</code> uses an eventually consistent query, and the metadata for your table might not be available at that moment. Wait for a few seconds, and then try the <code>DescribeTable</code> request again.</p> </note></p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DescribeTableInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = DescribeTableInput::builder()
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.describe_table(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("DescribeTable failed: {}", error),
+    /// }
+    /// ```
     fn describe_table(
         &self,
         input: DescribeTableInput,
     ) -> RusotoFuture<DescribeTableOutput, DescribeTableError>;
 
     /// <p>Gives a description of the Time to Live (TTL) status on the specified table. </p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DescribeTimeToLiveInput, DynamoDb, DynamoDbClient};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = DescribeTimeToLiveInput::builder()
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.describe_time_to_live(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("DescribeTimeToLive failed: {}", error),
+    /// }
+    /// ```
     fn describe_time_to_live(
         &self,
         input: DescribeTimeToLiveInput,
     ) -> RusotoFuture<DescribeTimeToLiveOutput, DescribeTimeToLiveError>;
 
     /// <p>The <code>GetItem</code> operation returns a set of attributes for the item with the given primary key. If there is no matching item, <code>GetItem</code> does not return any data and there will be no <code>Item</code> element in the response.</p> <p> <code>GetItem</code> provides an eventually consistent read by default. If your application requires a strongly consistent read, set <code>ConsistentRead</code> to <code>true</code>. Although a strongly consistent read might take more time than an eventually consistent read, it always returns the last updated value.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, GetItemInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = GetItemInput::builder()
+    ///     .key(::std::collections::HashMap::new())
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.get_item(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("GetItem failed: {}", error),
+    /// }
+    /// ```
     fn get_item(&self, input: GetItemInput) -> RusotoFuture<GetItemOutput, GetItemError>;
 
     /// <p>List backups associated with an AWS account. To list backups for a given table, specify <code>TableName</code>. <code>ListBackups</code> returns a paginated list of results with at most 1 MB worth of items in a page. You can also specify a limit for the maximum number of entries to be returned in a page. </p> <p>In the request, start time is inclusive, but end time is exclusive. Note that these limits are for the time at which the original backup was requested.</p> <p>You can call <code>ListBackups</code> a maximum of five times per second.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, ListBackupsInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = ListBackupsInput::default();
+    /// match client.list_backups(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("ListBackups failed: {}", error),
+    /// }
+    /// ```
     fn list_backups(
         &self,
         input: ListBackupsInput,
     ) -> RusotoFuture<ListBackupsOutput, ListBackupsError>;
 
     /// <p>Lists all global tables that have a replica in the specified Region.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, ListGlobalTablesInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = ListGlobalTablesInput::default();
+    /// match client.list_global_tables(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("ListGlobalTables failed: {}", error),
+    /// }
+    /// ```
     fn list_global_tables(
         &self,
         input: ListGlobalTablesInput,
     ) -> RusotoFuture<ListGlobalTablesOutput, ListGlobalTablesError>;
 
     /// <p>Returns an array of table names associated with the current account and endpoint. The output from <code>ListTables</code> is paginated, with each page returning a maximum of 100 table names.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, ListTablesInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = ListTablesInput::default();
+    /// match client.list_tables(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("ListTables failed: {}", error),
+    /// }
+    /// ```
     fn list_tables(
         &self,
         input: ListTablesInput,
     ) -> RusotoFuture<ListTablesOutput, ListTablesError>;
 
     /// <p>List all tags on an Amazon DynamoDB resource. You can call ListTagsOfResource up to 10 times per second, per account.</p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, ListTagsOfResourceInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = ListTagsOfResourceInput::builder()
+    ///     .resource_arn("resource_arn")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.list_tags_of_resource(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("ListTagsOfResource failed: {}", error),
+    /// }
+    /// ```
     fn list_tags_of_resource(
         &self,
         input: ListTagsOfResourceInput,
     ) -> RusotoFuture<ListTagsOfResourceOutput, ListTagsOfResourceError>;
 
     /// <p>Creates a new item, or replaces an old item with a new item. If an item that has the same primary key as the new item already exists in the specified table, the new item completely replaces the existing item. You can perform a conditional put operation (add a new item if one with the specified primary key doesn't exist), or replace an existing item if it has certain attribute values. You can return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <important> <p>This topic provides general information about the <code>PutItem</code> API.</p> <p>For information on how to call the <code>PutItem</code> API using the AWS SDK in specific languages, see the following:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/goto/aws-cli/dynamodb-2012-08-10/PutItem"> PutItem in the AWS Command Line Interface</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/DotNetSDKV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for .NET</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForCpp/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for C++</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForGoV1/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Go</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForJava/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Java</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/AWSJavaScriptSDK/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for JavaScript</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForPHPV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for PHP V3</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/boto3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Python</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForRubyV2/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Ruby V2</a> </p> </li> </ul> </important> <p>When you add an item, the primary key attributes are the only required attributes. Attribute values cannot be null. String and Binary type attributes must have lengths greater than zero. Set type attributes cannot be empty. Requests with empty values will be rejected with a <code>ValidationException</code> exception.</p> <note> <p>To prevent a new item from replacing an existing item, use a conditional expression that contains the <code>attribute_not_exists</code> function with the name of the attribute being used as the partition key for the table. Since every record must contain that attribute, the <code>attribute_not_exists</code> function will only succeed if no matching item exists.</p> </note> <p>For more information about <code>PutItem</code>, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithItems.html">Working with Items</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, PutItemInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = PutItemInput::builder()
+    ///     .item(::std::collections::HashMap::new())
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.put_item(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("PutItem failed: {}", error),
+    /// }
+    /// ```
     fn put_item(&self, input: PutItemInput) -> RusotoFuture<PutItemOutput, PutItemError>;
 
     /// <p>The <code>Query</code> operation finds items based on primary key values. You can query any table or secondary index that has a composite primary key (a partition key and a sort key). </p> <p>Use the <code>KeyConditionExpression</code> parameter to provide a specific value for the partition key. The <code>Query</code> operation will return all of the items from the table or index with that partition key value. You can optionally narrow the scope of the <code>Query</code> operation by specifying a sort key value and a comparison operator in <code>KeyConditionExpression</code>. To further refine the <code>Query</code> results, you can optionally provide a <code>FilterExpression</code>. A <code>FilterExpression</code> determines which items within the results should be returned to you. All of the other results are discarded. </p> <p> A <code>Query</code> operation always returns a result set. If no matching items are found, the result set will be empty. Queries that do not return results consume the minimum number of read capacity units for that type of read operation. </p> <note> <p> DynamoDB calculates the number of read capacity units consumed based on item size, not on the amount of data that is returned to an application. The number of capacity units consumed will be the same whether you request all of the attributes (the default behavior) or just some of them (using a projection expression). The number will also be the same whether or not you use a <code>FilterExpression</code>. </p> </note> <p> <code>Query</code> results are always sorted by the sort key value. If the data type of the sort key is Number, the results are returned in numeric order; otherwise, the results are returned in order of UTF-8 bytes. By default, the sort order is ascending. To reverse the order, set the <code>ScanIndexForward</code> parameter to false. </p> <p> A single <code>Query</code> operation will read up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you will need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Query.html#Query.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>FilterExpression</code> is applied after a <code>Query</code> finishes, but before the results are returned. A <code>FilterExpression</code> cannot contain partition key or sort key attributes. You need to specify those attributes in the <code>KeyConditionExpression</code>. </p> <note> <p> A <code>Query</code> operation can return an empty result set and a <code>LastEvaluatedKey</code> if all the items read for the page of results are filtered out. </p> </note> <p>You can query a table, a local secondary index, or a global secondary index. For a query on a table or on a local secondary index, you can set the <code>ConsistentRead</code> parameter to <code>true</code> and obtain a strongly consistent result. Global secondary indexes support eventually consistent reads only, so do not specify <code>ConsistentRead</code> when querying a global secondary index.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, QueryInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = QueryInput::builder()
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.query(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("Query failed: {}", error),
+    /// }
+    /// ```
     fn query(&self, input: QueryInput) -> RusotoFuture<QueryOutput, QueryError>;
 
     /// <p><p>Creates a new table from an existing backup. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p>You can call <code>RestoreTableFromBackup</code> at a maximum rate of 10 times per second.</p> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> </ul></p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, RestoreTableFromBackupInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = RestoreTableFromBackupInput::builder()
+    ///     .backup_arn("backup_arn")
+    ///     .target_table_name("target_table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.restore_table_from_backup(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("RestoreTableFromBackup failed: {}", error),
+    /// }
+    /// ```
     fn restore_table_from_backup(
         &self,
         input: RestoreTableFromBackupInput,
     ) -> RusotoFuture<RestoreTableFromBackupOutput, RestoreTableFromBackupError>;
 
     /// <p><p>Restores the specified table to the specified point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. You can restore your table to any point in time during the last 35 days. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p> When you restore using point in time recovery, DynamoDB restores your table data to the state based on the selected date and time (day:hour:minute:second) to a new table. </p> <p> Along with data, the following are also included on the new restored table using point in time recovery: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Provisioned read and write capacity</p> </li> <li> <p>Encryption settings</p> <important> <p> All these settings come from the current settings of the source table at the time of restore. </p> </important> </li> </ul> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> <li> <p>Point in time recovery settings</p> </li> </ul></p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, RestoreTableToPointInTimeInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = RestoreTableToPointInTimeInput::builder()
+    ///     .source_table_name("source_table_name")
+    ///     .target_table_name("target_table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.restore_table_to_point_in_time(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("RestoreTableToPointInTime failed: {}", error),
+    /// }
+    /// ```
     fn restore_table_to_point_in_time(
         &self,
         input: RestoreTableToPointInTimeInput,
     ) -> RusotoFuture<RestoreTableToPointInTimeOutput, RestoreTableToPointInTimeError>;
 
     /// <p>The <code>Scan</code> operation returns one or more items and item attributes by accessing every item in a table or a secondary index. To have DynamoDB return fewer items, you can provide a <code>FilterExpression</code> operation.</p> <p>If the total number of scanned items exceeds the maximum dataset size limit of 1 MB, the scan stops and results are returned to the user as a <code>LastEvaluatedKey</code> value to continue the scan in a subsequent operation. The results also include the number of items exceeding the limit. A scan can result in no table data meeting the filter criteria. </p> <p>A single <code>Scan</code> operation reads up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>Scan</code> operations proceed sequentially; however, for faster performance on a large table or secondary index, applications can request a parallel <code>Scan</code> operation by providing the <code>Segment</code> and <code>TotalSegments</code> parameters. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.ParallelScan">Parallel Scan</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p> <code>Scan</code> uses eventually consistent reads when accessing the data in a table; therefore, the result set might not include the changes to data in the table immediately before the operation began. If you need a consistent copy of the data, as of the time that the <code>Scan</code> begins, you can set the <code>ConsistentRead</code> parameter to <code>true</code>.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, ScanInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = ScanInput::builder()
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.scan(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("Scan failed: {}", error),
+    /// }
+    /// ```
     fn scan(&self, input: ScanInput) -> RusotoFuture<ScanOutput, ScanError>;
 
     /// <p>Associate a set of tags with an Amazon DynamoDB resource. You can then activate these user-defined tags so that they appear on the Billing and Cost Management console for cost allocation tracking. You can call TagResource up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, TagResourceInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = TagResourceInput::builder()
+    ///     .resource_arn("resource_arn")
+    ///     .tags(Vec::new())
+    ///     .build()
+    ///     .unwrap();
+    /// match client.tag_resource(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("TagResource failed: {}", error),
+    /// }
+    /// ```
     fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError>;
 
     /// <p><p> <code>TransactGetItems</code> is a synchronous operation that atomically retrieves multiple items from one or more tables (but not from indexes) in a single account and Region. A <code>TransactGetItems</code> call can contain up to 25 <code>TransactGetItem</code> objects, each of which contains a <code>Get</code> structure that specifies an item to retrieve from a table in the account and Region. A call to <code>TransactGetItems</code> cannot retrieve items from tables in more than one AWS account or Region. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <note> <p>All AWS Regions and AWS GovCloud (US) support up to 25 items per transaction with up to 4 MB of data, except the following AWS Regions: </p> <ul> <li> <p>China (Beijing)</p> </li> <li> <p>China (Ningxia)</p> </li> </ul> <p>The China (Beijing) and China (Ningxia) Regions support up to 10 items per transaction with up to 4 MB of data. </p> </note> <p>DynamoDB rejects the entire <code>TransactGetItems</code> request if any of the following is true:</p> <ul> <li> <p>A conflicting operation is in the process of updating an item to be read.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> <li> <p>The aggregate size of the items in the transaction cannot exceed 4 MB.</p> </li> </ul></p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, TransactGetItemsInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = TransactGetItemsInput::builder()
+    ///     .transact_items(Vec::new())
+    ///     .build()
+    ///     .unwrap();
+    /// match client.transact_get_items(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("TransactGetItems failed: {}", error),
+    /// }
+    /// ```
     fn transact_get_items(
         &self,
         input: TransactGetItemsInput,
     ) -> RusotoFuture<TransactGetItemsOutput, TransactGetItemsError>;
 
     /// <p><p> <code>TransactWriteItems</code> is a synchronous write operation that groups up to 25 action requests. These actions can target items in different tables, but not in different AWS accounts or Regions, and no two actions can target the same item. For example, you cannot both <code>ConditionCheck</code> and <code>Update</code> the same item. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <note> <p>All AWS Regions and AWS GovCloud (US) support up to 25 items per transaction with up to 4 MB of data, except the following AWS Regions: </p> <ul> <li> <p>China (Beijing)</p> </li> <li> <p>China (Ningxia)</p> </li> </ul> <p>The China (Beijing) and China (Ningxia) Regions support up to 10 items per transaction with up to 4 MB of data. </p> </note> <p>The actions are completed atomically so that either all of them succeed, or all of them fail. They are defined by the following objects:</p> <ul> <li> <p> <code>Put</code>  &#x97;   Initiates a <code>PutItem</code> operation to write a new item. This structure specifies the primary key of the item to be written, the name of the table to write it in, an optional condition expression that must be satisfied for the write to succeed, a list of the item&#39;s attributes, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Update</code>  &#x97;   Initiates an <code>UpdateItem</code> operation to update an existing item. This structure specifies the primary key of the item to be updated, the name of the table where it resides, an optional condition expression that must be satisfied for the update to succeed, an expression that defines one or more attributes to be updated, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Delete</code>  &#x97;   Initiates a <code>DeleteItem</code> operation to delete an existing item. This structure specifies the primary key of the item to be deleted, the name of the table where it resides, an optional condition expression that must be satisfied for the deletion to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>ConditionCheck</code>  &#x97;   Applies a condition to an item that is not being modified by the transaction. This structure specifies the primary key of the item to be checked, the name of the table where it resides, a condition expression that must be satisfied for the transaction to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> </ul> <p>DynamoDB rejects the entire <code>TransactWriteItems</code> request if any of the following is true:</p> <ul> <li> <p>A condition in one of the condition expressions is not met.</p> </li> <li> <p>An ongoing operation is in the process of updating the same item.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>An item size becomes too large (bigger than 400 KB), a local secondary index (LSI) becomes too large, or a similar validation error occurs because of changes made by the transaction.</p> </li> <li> <p>The aggregate size of the items in the transaction exceeds 4 MB.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> </ul></p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, TransactWriteItemsInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = TransactWriteItemsInput::builder()
+    ///     .transact_items(Vec::new())
+    ///     .build()
+    ///     .unwrap();
+    /// match client.transact_write_items(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("TransactWriteItems failed: {}", error),
+    /// }
+    /// ```
     fn transact_write_items(
         &self,
         input: TransactWriteItemsInput,
     ) -> RusotoFuture<TransactWriteItemsOutput, TransactWriteItemsError>;
 
     /// <p>Removes the association of tags from an Amazon DynamoDB resource. You can call <code>UntagResource</code> up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, UntagResourceInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = UntagResourceInput::builder()
+    ///     .resource_arn("resource_arn")
+    ///     .tag_keys(Vec::new())
+    ///     .build()
+    ///     .unwrap();
+    /// match client.untag_resource(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("UntagResource failed: {}", error),
+    /// }
+    /// ```
     fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError>;
 
     /// <p> <code>UpdateContinuousBackups</code> enables or disables point in time recovery for the specified table. A successful <code>UpdateContinuousBackups</code> call returns the current <code>ContinuousBackupsDescription</code>. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> Once continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, PointInTimeRecoverySpecification, UpdateContinuousBackupsInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = UpdateContinuousBackupsInput::builder()
+    ///     .point_in_time_recovery_specification(PointInTimeRecoverySpecification::default())
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.update_continuous_backups(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("UpdateContinuousBackups failed: {}", error),
+    /// }
+    /// ```
     fn update_continuous_backups(
         &self,
         input: UpdateContinuousBackupsInput,
     ) -> RusotoFuture<UpdateContinuousBackupsOutput, UpdateContinuousBackupsError>;
 
     /// <p><p>Adds or removes replicas in the specified global table. The global table must already exist to be able to use this operation. Any replica to be added must be empty, have the same name as the global table, have the same key schema, have DynamoDB Streams enabled, and have the same provisioned and maximum write capacity units.</p> <note> <p>Although you can use <code>UpdateGlobalTable</code> to add replicas and remove replicas in a single request, for simplicity we recommend that you issue separate requests for adding or removing replicas.</p> </note> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> <li> <p> The global secondary indexes must have the same provisioned and maximum write capacity units. </p> </li> </ul></p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, UpdateGlobalTableInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = UpdateGlobalTableInput::builder()
+    ///     .global_table_name("global_table_name")
+    ///     .replica_updates(Vec::new())
+    ///     .build()
+    ///     .unwrap();
+    /// match client.update_global_table(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("UpdateGlobalTable failed: {}", error),
+    /// }
+    /// ```
     fn update_global_table(
         &self,
         input: UpdateGlobalTableInput,
     ) -> RusotoFuture<UpdateGlobalTableOutput, UpdateGlobalTableError>;
 
     /// <p>Updates settings for a global table.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, UpdateGlobalTableSettingsInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = UpdateGlobalTableSettingsInput::builder()
+    ///     .global_table_name("global_table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.update_global_table_settings(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("UpdateGlobalTableSettings failed: {}", error),
+    /// }
+    /// ```
     fn update_global_table_settings(
         &self,
         input: UpdateGlobalTableSettingsInput,
     ) -> RusotoFuture<UpdateGlobalTableSettingsOutput, UpdateGlobalTableSettingsError>;
 
     /// <p>Edits an existing item's attributes, or adds a new item to the table if it does not already exist. You can put, delete, or add attribute values. You can also perform a conditional update on an existing item (insert a new attribute name-value pair if it doesn't exist, or replace an existing name-value pair if it has certain expected attribute values).</p> <p>You can also return the item's attribute values in the same <code>UpdateItem</code> operation using the <code>ReturnValues</code> parameter.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, UpdateItemInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = UpdateItemInput::builder()
+    ///     .key(::std::collections::HashMap::new())
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.update_item(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("UpdateItem failed: {}", error),
+    /// }
+    /// ```
     fn update_item(
         &self,
         input: UpdateItemInput,
     ) -> RusotoFuture<UpdateItemOutput, UpdateItemError>;
 
     /// <p>Modifies the provisioned throughput settings, global secondary indexes, or DynamoDB Streams settings for a given table.</p> <p>You can only perform one of the following operations at once:</p> <ul> <li> <p>Modify the provisioned throughput settings of the table.</p> </li> <li> <p>Enable or disable DynamoDB Streams on the table.</p> </li> <li> <p>Remove a global secondary index from the table.</p> </li> <li> <p>Create a new global secondary index on the table. After the index begins backfilling, you can use <code>UpdateTable</code> to perform other operations.</p> </li> </ul> <p> <code>UpdateTable</code> is an asynchronous operation; while it is executing, the table status changes from <code>ACTIVE</code> to <code>UPDATING</code>. While it is <code>UPDATING</code>, you cannot issue another <code>UpdateTable</code> request. When the table returns to the <code>ACTIVE</code> state, the <code>UpdateTable</code> operation is complete.</p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, UpdateTableInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = UpdateTableInput::builder()
+    ///     .table_name("table_name")
+    ///     .build()
+    ///     .unwrap();
+    /// match client.update_table(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("UpdateTable failed: {}", error),
+    /// }
+    /// ```
     fn update_table(
         &self,
         input: UpdateTableInput,
     ) -> RusotoFuture<UpdateTableOutput, UpdateTableError>;
 
     /// <p>The <code>UpdateTimeToLive</code> method enables or disables Time to Live (TTL) for the specified table. A successful <code>UpdateTimeToLive</code> call returns the current <code>TimeToLiveSpecification</code>. It can take up to one hour for the change to fully process. Any additional <code>UpdateTimeToLive</code> calls for the same table during this one hour duration result in a <code>ValidationException</code>. </p> <p>TTL compares the current time in epoch time format to the time stored in the TTL attribute of an item. If the epoch time value stored in the attribute is less than the current time, the item is marked as expired and subsequently deleted.</p> <note> <p> The epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC. </p> </note> <p>DynamoDB deletes expired items on a best-effort basis to ensure availability of throughput for other data operations. </p> <important> <p>DynamoDB typically deletes expired items within two days of expiration. The exact duration within which an item gets deleted after expiration is specific to the nature of the workload. Items that have expired and not been deleted will still show up in reads, queries, and scans.</p> </important> <p>As items are deleted, they are removed from any local secondary index and global secondary index immediately in the same eventually consistent way as a standard delete operation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/TTL.html">Time To Live</a> in the Amazon DynamoDB Developer Guide. </p>
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_dynamodb::{DynamoDb, DynamoDbClient, TimeToLiveSpecification, UpdateTimeToLiveInput};
+    ///
+    /// let client = DynamoDbClient::new(Region::UsEast1);
+    /// let request = UpdateTimeToLiveInput::builder()
+    ///     .table_name("table_name")
+    ///     .time_to_live_specification(TimeToLiveSpecification::default())
+    ///     .build()
+    ///     .unwrap();
+    /// match client.update_time_to_live(request).sync() {
+    ///     Ok(output) => println!("{:?}", output),
+    ///     Err(error) => eprintln!("UpdateTimeToLive failed: {}", error),
+    /// }
+    /// ```
     fn update_time_to_live(
         &self,
         input: UpdateTimeToLiveInput,
     ) -> RusotoFuture<UpdateTimeToLiveOutput, UpdateTimeToLiveError>;
 }
+impl<T: ?Sized + DynamoDb> DynamoDb for ::std::sync::Arc<T> {
+    fn batch_get_item(
+        &self,
+        input: BatchGetItemInput,
+    ) -> RusotoFuture<BatchGetItemOutput, BatchGetItemError> {
+        (**self).batch_get_item(input)
+    }
+    fn batch_write_item(
+        &self,
+        input: BatchWriteItemInput,
+    ) -> RusotoFuture<BatchWriteItemOutput, BatchWriteItemError> {
+        (**self).batch_write_item(input)
+    }
+    fn create_backup(
+        &self,
+        input: CreateBackupInput,
+    ) -> RusotoFuture<CreateBackupOutput, CreateBackupError> {
+        (**self).create_backup(input)
+    }
+    fn create_global_table(
+        &self,
+        input: CreateGlobalTableInput,
+    ) -> RusotoFuture<CreateGlobalTableOutput, CreateGlobalTableError> {
+        (**self).create_global_table(input)
+    }
+    fn create_table(
+        &self,
+        input: CreateTableInput,
+    ) -> RusotoFuture<CreateTableOutput, CreateTableError> {
+        (**self).create_table(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupInput,
+    ) -> RusotoFuture<DeleteBackupOutput, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_item(
+        &self,
+        input: DeleteItemInput,
+    ) -> RusotoFuture<DeleteItemOutput, DeleteItemError> {
+        (**self).delete_item(input)
+    }
+    fn delete_table(
+        &self,
+        input: DeleteTableInput,
+    ) -> RusotoFuture<DeleteTableOutput, DeleteTableError> {
+        (**self).delete_table(input)
+    }
+    fn describe_backup(
+        &self,
+        input: DescribeBackupInput,
+    ) -> RusotoFuture<DescribeBackupOutput, DescribeBackupError> {
+        (**self).describe_backup(input)
+    }
+    fn describe_continuous_backups(
+        &self,
+        input: DescribeContinuousBackupsInput,
+    ) -> RusotoFuture<DescribeContinuousBackupsOutput, DescribeContinuousBackupsError> {
+        (**self).describe_continuous_backups(input)
+    }
+    fn describe_endpoints(
+        &self,
+    ) -> RusotoFuture<DescribeEndpointsResponse, DescribeEndpointsError> {
+        (**self).describe_endpoints()
+    }
+    fn describe_global_table(
+        &self,
+        input: DescribeGlobalTableInput,
+    ) -> RusotoFuture<DescribeGlobalTableOutput, DescribeGlobalTableError> {
+        (**self).describe_global_table(input)
+    }
+    fn describe_global_table_settings(
+        &self,
+        input: DescribeGlobalTableSettingsInput,
+    ) -> RusotoFuture<DescribeGlobalTableSettingsOutput, DescribeGlobalTableSettingsError> {
+        (**self).describe_global_table_settings(input)
+    }
+    fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
+        (**self).describe_limits()
+    }
+    fn describe_table(
+        &self,
+        input: DescribeTableInput,
+    ) -> RusotoFuture<DescribeTableOutput, DescribeTableError> {
+        (**self).describe_table(input)
+    }
+    fn describe_time_to_live(
+        &self,
+        input: DescribeTimeToLiveInput,
+    ) -> RusotoFuture<DescribeTimeToLiveOutput, DescribeTimeToLiveError> {
+        (**self).describe_time_to_live(input)
+    }
+    fn get_item(&self, input: GetItemInput) -> RusotoFuture<GetItemOutput, GetItemError> {
+        (**self).get_item(input)
+    }
+    fn list_backups(
+        &self,
+        input: ListBackupsInput,
+    ) -> RusotoFuture<ListBackupsOutput, ListBackupsError> {
+        (**self).list_backups(input)
+    }
+    fn list_global_tables(
+        &self,
+        input: ListGlobalTablesInput,
+    ) -> RusotoFuture<ListGlobalTablesOutput, ListGlobalTablesError> {
+        (**self).list_global_tables(input)
+    }
+    fn list_tables(
+        &self,
+        input: ListTablesInput,
+    ) -> RusotoFuture<ListTablesOutput, ListTablesError> {
+        (**self).list_tables(input)
+    }
+    fn list_tags_of_resource(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> RusotoFuture<ListTagsOfResourceOutput, ListTagsOfResourceError> {
+        (**self).list_tags_of_resource(input)
+    }
+    fn put_item(&self, input: PutItemInput) -> RusotoFuture<PutItemOutput, PutItemError> {
+        (**self).put_item(input)
+    }
+    fn query(&self, input: QueryInput) -> RusotoFuture<QueryOutput, QueryError> {
+        (**self).query(input)
+    }
+    fn restore_table_from_backup(
+        &self,
+        input: RestoreTableFromBackupInput,
+    ) -> RusotoFuture<RestoreTableFromBackupOutput, RestoreTableFromBackupError> {
+        (**self).restore_table_from_backup(input)
+    }
+    fn restore_table_to_point_in_time(
+        &self,
+        input: RestoreTableToPointInTimeInput,
+    ) -> RusotoFuture<RestoreTableToPointInTimeOutput, RestoreTableToPointInTimeError> {
+        (**self).restore_table_to_point_in_time(input)
+    }
+    fn scan(&self, input: ScanInput) -> RusotoFuture<ScanOutput, ScanError> {
+        (**self).scan(input)
+    }
+    fn tag_resource(&self, input: TagResourceInput) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn transact_get_items(
+        &self,
+        input: TransactGetItemsInput,
+    ) -> RusotoFuture<TransactGetItemsOutput, TransactGetItemsError> {
+        (**self).transact_get_items(input)
+    }
+    fn transact_write_items(
+        &self,
+        input: TransactWriteItemsInput,
+    ) -> RusotoFuture<TransactWriteItemsOutput, TransactWriteItemsError> {
+        (**self).transact_write_items(input)
+    }
+    fn untag_resource(&self, input: UntagResourceInput) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_continuous_backups(
+        &self,
+        input: UpdateContinuousBackupsInput,
+    ) -> RusotoFuture<UpdateContinuousBackupsOutput, UpdateContinuousBackupsError> {
+        (**self).update_continuous_backups(input)
+    }
+    fn update_global_table(
+        &self,
+        input: UpdateGlobalTableInput,
+    ) -> RusotoFuture<UpdateGlobalTableOutput, UpdateGlobalTableError> {
+        (**self).update_global_table(input)
+    }
+    fn update_global_table_settings(
+        &self,
+        input: UpdateGlobalTableSettingsInput,
+    ) -> RusotoFuture<UpdateGlobalTableSettingsOutput, UpdateGlobalTableSettingsError> {
+        (**self).update_global_table_settings(input)
+    }
+    fn update_item(
+        &self,
+        input: UpdateItemInput,
+    ) -> RusotoFuture<UpdateItemOutput, UpdateItemError> {
+        (**self).update_item(input)
+    }
+    fn update_table(
+        &self,
+        input: UpdateTableInput,
+    ) -> RusotoFuture<UpdateTableOutput, UpdateTableError> {
+        (**self).update_table(input)
+    }
+    fn update_time_to_live(
+        &self,
+        input: UpdateTimeToLiveInput,
+    ) -> RusotoFuture<UpdateTimeToLiveOutput, UpdateTimeToLiveError> {
+        (**self).update_time_to_live(input)
+    }
+}
+/// The error codes DynamoDB retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["ProvisionedThroughputExceededException"],
+        transient: &[],
+    };
+
 /// A client for the DynamoDB API.
 #[derive(Clone)]
 pub struct DynamoDbClient {
@@ -4658,6 +9572,27 @@ impl DynamoDbClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_DYNAMODB`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> DynamoDbClient {
+        Self::new(region::Region::from_env_for_service("DYNAMODB"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `dynamodb`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> DynamoDbClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("dynamodb", "DYNAMODB"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4675,8 +9610,55 @@ impl DynamoDbClient {
         )
     }
 
-    pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbClient {
-        DynamoDbClient { client, region }
+    pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbClient {
+        DynamoDbClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "dynamodb")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "dynamodb",
+            "dynamodb",
+            "DYNAMODB",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `DynamoDb` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn DynamoDb + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for DynamoDbClient {
+    fn service_name(&self) -> &'static str {
+        "DynamoDB"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "dynamodb"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> DynamoDbClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -4693,21 +9675,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchGetItemOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchGetItemError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<BatchGetItemOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(BatchGetItemError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p><p>The <code>BatchWriteItem</code> operation puts or deletes multiple items in one or more tables. A single call to <code>BatchWriteItem</code> can write up to 16 MB of data, which can comprise as many as 25 put or delete requests. Individual items to be written can be as large as 400 KB.</p> <note> <p> <code>BatchWriteItem</code> cannot update items. To update items, use the <code>UpdateItem</code> action.</p> </note> <p>The individual <code>PutItem</code> and <code>DeleteItem</code> operations specified in <code>BatchWriteItem</code> are atomic; however <code>BatchWriteItem</code> as a whole is not. If any requested operations fail because the table&#39;s provisioned throughput is exceeded or an internal processing failure occurs, the failed operations are returned in the <code>UnprocessedItems</code> response parameter. You can investigate and optionally resend the requests. Typically, you would call <code>BatchWriteItem</code> in a loop. Each iteration would check for unprocessed items and submit a new <code>BatchWriteItem</code> request with those unprocessed items until all items have been processed.</p> <p>If <i>none</i> of the items can be processed due to insufficient provisioned throughput on all of the tables in the request, then <code>BatchWriteItem</code> returns a <code>ProvisionedThroughputExceededException</code>.</p> <important> <p>If DynamoDB returns any unprocessed items, you should retry the batch operation on those items. However, <i>we strongly recommend that you use an exponential backoff algorithm</i>. If you retry the batch operation immediately, the underlying read or write requests can still fail due to throttling on the individual tables. If you delay the batch operation using exponential backoff, the individual requests in the batch are much more likely to succeed.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/ErrorHandling.html#Programming.Errors.BatchOperations">Batch Operations and Error Handling</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> </important> <p>With <code>BatchWriteItem</code>, you can efficiently write or delete large amounts of data, such as from Amazon EMR, or copy data from another database into DynamoDB. In order to improve performance with these large-scale operations, <code>BatchWriteItem</code> does not behave in the same way as individual <code>PutItem</code> and <code>DeleteItem</code> calls would. For example, you cannot specify conditions on individual put and delete requests, and <code>BatchWriteItem</code> does not return deleted items in the response.</p> <p>If you use a programming language that supports concurrency, you can use threads to write items in parallel. Your application must include the necessary logic to manage the threads. With languages that don&#39;t support threading, you must update or delete the specified items one at a time. In both situations, <code>BatchWriteItem</code> performs the specified put and delete operations in parallel, giving you the power of the thread pool approach without having to introduce complexity into your application.</p> <p>Parallel processing reduces latency, but each specified put and delete request consumes the same number of write capacity units whether it is processed in parallel or not. Delete operations on nonexistent items consume one write capacity unit.</p> <p>If one or more of the following is true, DynamoDB rejects the entire batch write operation:</p> <ul> <li> <p>One or more tables specified in the <code>BatchWriteItem</code> request does not exist.</p> </li> <li> <p>Primary key attributes specified on an item in the request do not match those in the corresponding table&#39;s primary key schema.</p> </li> <li> <p>You try to perform multiple operations on the same item in the same <code>BatchWriteItem</code> request. For example, you cannot put and delete the same item in the same <code>BatchWriteItem</code> request. </p> </li> <li> <p> Your request contains at least two items with identical hash and range keys (which essentially is two put operations). </p> </li> <li> <p>There are more than 25 requests in the batch.</p> </li> <li> <p>Any individual item in a batch exceeds 400 KB.</p> </li> <li> <p>The total request size exceeds 16 MB.</p> </li> </ul></p>
@@ -4722,21 +9725,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<BatchWriteItemOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(BatchWriteItemError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<BatchWriteItemOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(BatchWriteItemError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p><p>Creates a backup for an existing table.</p> <p> Each time you create an on-demand backup, the entire table data is backed up. There is no limit to the number of on-demand backups that can be taken. </p> <p> When you create an on-demand backup, a time marker of the request is cataloged, and the backup is created asynchronously, by applying all changes until the time of the request to the last full table snapshot. Backup requests are processed instantaneously and become available for restore within minutes. </p> <p>You can call <code>CreateBackup</code> at a maximum rate of 50 times per second.</p> <p>All backups in DynamoDB work without consuming any provisioned throughput on the table.</p> <p> If you submit a backup request on 2018-12-14 at 14:25:00, the backup is guaranteed to contain all data committed to the table up to 14:24:00, and data committed after 14:26:00 will not be. The backup might contain data modifications made between 14:24:00 and 14:26:00. On-demand backup does not support causal consistency. </p> <p> Along with data, the following are also included on the backups: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Streams</p> </li> <li> <p>Provisioned read and write capacity</p> </li> </ul></p>
@@ -4751,21 +9775,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateBackupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateBackupError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<CreateBackupOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(CreateBackupError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p><p>Creates a global table from an existing table. A global table creates a replication relationship between two or more DynamoDB tables with the same table name in the provided Regions. </p> <p>If you want to add a new replica table to a global table, each of the following conditions must be true:</p> <ul> <li> <p>The table must have the same primary key as all of the other replicas.</p> </li> <li> <p>The table must have the same name as all of the other replicas.</p> </li> <li> <p>The table must have DynamoDB Streams enabled, with the stream containing both the new and the old images of the item.</p> </li> <li> <p>None of the replica tables in the global table can contain any data.</p> </li> </ul> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> </ul> <important> <p> Write capacity settings should be set consistently across your replica tables and secondary indexes. DynamoDB strongly recommends enabling auto scaling to manage the write capacity settings for all of your global tables replicas and indexes. </p> <p> If you prefer to manage write capacity settings manually, you should provision equal replicated write capacity units to your replica tables. You should also provision equal replicated write capacity units to matching secondary indexes across your global table. </p> </important></p>
@@ -4780,21 +9825,41 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateGlobalTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateGlobalTableError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<CreateGlobalTableOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response.buffer().from_err().and_then(|response| {
+                            Err(CreateGlobalTableError::from_response(response))
+                        }),
+                    )
+                }
+            })
     }
 
     /// <p>The <code>CreateTable</code> operation adds a new table to your account. In an AWS account, table names must be unique within each Region. That is, you can have two tables with same name if you create the tables in different Regions.</p> <p> <code>CreateTable</code> is an asynchronous operation. Upon receiving a <code>CreateTable</code> request, DynamoDB immediately returns a response with a <code>TableStatus</code> of <code>CREATING</code>. After the table is created, DynamoDB sets the <code>TableStatus</code> to <code>ACTIVE</code>. You can perform read and write operations only on an <code>ACTIVE</code> table. </p> <p>You can optionally define secondary indexes on the new table, as part of the <code>CreateTable</code> operation. If you want to create multiple tables with secondary indexes on them, you must create the tables sequentially. Only one table with secondary indexes can be in the <code>CREATING</code> state at any given time.</p> <p>You can use the <code>DescribeTable</code> action to check the table status.</p>
@@ -4809,21 +9874,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<CreateTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(CreateTableError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<CreateTableOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(CreateTableError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p>Deletes an existing backup of a table.</p> <p>You can call <code>DeleteBackup</code> at a maximum rate of 10 times per second.</p>
@@ -4838,21 +9924,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteBackupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteBackupError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<DeleteBackupOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(DeleteBackupError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p>Deletes a single item in a table by primary key. You can perform a conditional delete operation that deletes the item if it exists, or if it has an expected attribute value.</p> <p>In addition to deleting an item, you can also return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <p>Unless you specify conditions, the <code>DeleteItem</code> is an idempotent operation; running it multiple times on the same item or attribute does <i>not</i> result in an error response.</p> <p>Conditional deletes are useful for deleting items only if specific conditions are met. If those conditions are met, DynamoDB performs the delete. Otherwise, the item is not deleted.</p>
@@ -4867,21 +9974,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteItemOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteItemError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<DeleteItemOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(DeleteItemError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p>The <code>DeleteTable</code> operation deletes a table and all of its items. After a <code>DeleteTable</code> request, the specified table is in the <code>DELETING</code> state until DynamoDB completes the deletion. If the table is in the <code>ACTIVE</code> state, you can delete it. If a table is in <code>CREATING</code> or <code>UPDATING</code> states, then DynamoDB returns a <code>ResourceInUseException</code>. If the specified table does not exist, DynamoDB returns a <code>ResourceNotFoundException</code>. If table is already in the <code>DELETING</code> state, no error is returned. </p> <note> <p>DynamoDB might continue to accept data read and write operations, such as <code>GetItem</code> and <code>PutItem</code>, on a table in the <code>DELETING</code> state until the table deletion is complete.</p> </note> <p>When you delete a table, any indexes on that table are also deleted.</p> <p>If you have DynamoDB Streams enabled on the table, then the corresponding stream on that table goes into the <code>DISABLED</code> state, and the stream is automatically deleted after 24 hours.</p> <p>Use the <code>DescribeTable</code> action to check the status of the table. </p>
@@ -4896,21 +10024,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DeleteTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DeleteTableError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<DeleteTableOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(DeleteTableError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p>Describes an existing backup of a table.</p> <p>You can call <code>DescribeBackup</code> at a maximum rate of 10 times per second.</p>
@@ -4925,21 +10074,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeBackupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeBackupError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<DescribeBackupOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(DescribeBackupError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p>Checks the status of continuous backups and point in time recovery on the specified table. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> After continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p> <p>You can call <code>DescribeContinuousBackups</code> at a maximum rate of 10 times per second.</p>
@@ -4957,18 +10127,39 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeContinuousBackupsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeContinuousBackupsError::from_response(response))
-                }))
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<DescribeContinuousBackupsOutput, _>()
+                    }))
+                } else {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        Err(DescribeContinuousBackupsError::from_response(response))
+                    }))
+                }
+            })
     }
 
     /// <p>Returns the regional endpoint information.</p>
@@ -5010,20 +10201,39 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeGlobalTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<DescribeGlobalTableOutput, _>()
+                    }))
+                } else {
+                    Box::new(response.buffer().from_err().and_then(|response| {
                         Err(DescribeGlobalTableError::from_response(response))
-                    }),
-                )
-            }
-        })
+                    }))
+                }
+            })
     }
 
     /// <p>Describes Region-specific settings for a global table.</p>
@@ -5041,18 +10251,39 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeGlobalTableSettingsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(DescribeGlobalTableSettingsError::from_response(response))
-                }))
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<DescribeGlobalTableSettingsOutput, _>()
+                    }))
+                } else {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        Err(DescribeGlobalTableSettingsError::from_response(response))
+                    }))
+                }
+            })
     }
 
     /// <p>Returns the current provisioned-capacity limits for your AWS account in a Region, both for the Region as a whole and for any one DynamoDB table that you create there.</p> <p>When you establish an AWS account, the account has initial limits on the maximum read capacity units and write capacity units that you can provision across all of your DynamoDB tables in a given Region. Also, there are per-table limits that apply when you create a table there. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Limits.html">Limits</a> page in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p>Although you can increase these limits by filing a case at <a href="https://console.aws.amazon.com/support/home#/">AWS Support Center</a>, obtaining the increase is not instantaneous. The <code>DescribeLimits</code> action lets you write code to compare the capacity you are currently using to those limits imposed by your account so that you have enough time to apply for an increase before you hit a limit.</p> <p>For example, you could use one of the AWS SDKs to do the following:</p> <ol> <li> <p>Call <code>DescribeLimits</code> for a particular Region to obtain your current account limits on provisioned capacity there.</p> </li> <li> <p>Create a variable to hold the aggregate read capacity units provisioned for all your tables in that Region, and one to hold the aggregate write capacity units. Zero them both.</p> </li> <li> <p>Call <code>ListTables</code> to obtain a list of all your DynamoDB tables.</p> </li> <li> <p>For each table name listed by <code>ListTables</code>, do the following:</p> <ul> <li> <p>Call <code>DescribeTable</code> with the table name.</p> </li> <li> <p>Use the data returned by <code>DescribeTable</code> to add the read capacity units and write capacity units provisioned for the table itself to your variables.</p> </li> <li> <p>If the table has one or more global secondary indexes (GSIs), loop over these GSIs and add their provisioned capacity values to your variables as well.</p> </li> </ul> </li> <li> <p>Report the account limits for that Region returned by <code>DescribeLimits</code>, along with the total current provisioned capacity levels you have calculated.</p> </li> </ol> <p>This will let you see whether you are getting close to your account-level limits.</p> <p>The per-table limits apply only when you are creating a new table. They restrict the sum of the provisioned capacity of the new table itself and all its global secondary indexes.</p> <p>For existing tables and their GSIs, DynamoDB doesn't let you increase provisioned capacity extremely rapidly. But the only upper limit that applies is that the aggregate provisioned capacity over all your tables and GSIs cannot exceed either of the per-account limits.</p> <note> <p> <code>DescribeLimits</code> should only be called periodically. You can expect throttling errors if you call it more than once in a minute.</p> </note> <p>The <code>DescribeLimits</code> Request element has no content.</p>
@@ -5063,21 +10294,42 @@ impl DynamoDb for DynamoDbClient {
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeLimits");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeLimitsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeLimitsError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<DescribeLimitsOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(DescribeLimitsError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p><p>Returns information about the table, including the current status of the table, when it was created, the primary key schema, and any indexes on the table.</p> <note> <p>If you issue a <code>DescribeTable</code> request immediately after a <code>CreateTable</code> request, DynamoDB might return a <code>ResourceNotFoundException</code>. This is because <code>DescribeTable</code> uses an eventually consistent query, and the metadata for your table might not be available at that moment. Wait for a few seconds, and then try the <code>DescribeTable</code> request again.</p> </note></p>
@@ -5092,21 +10344,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTableError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<DescribeTableOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(DescribeTableError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p>Gives a description of the Time to Live (TTL) status on the specified table. </p>
@@ -5121,21 +10394,41 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<DescribeTimeToLiveOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(DescribeTimeToLiveError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<DescribeTimeToLiveOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response.buffer().from_err().and_then(|response| {
+                            Err(DescribeTimeToLiveError::from_response(response))
+                        }),
+                    )
+                }
+            })
     }
 
     /// <p>The <code>GetItem</code> operation returns a set of attributes for the item with the given primary key. If there is no matching item, <code>GetItem</code> does not return any data and there will be no <code>Item</code> element in the response.</p> <p> <code>GetItem</code> provides an eventually consistent read by default. If your application requires a strongly consistent read, set <code>ConsistentRead</code> to <code>true</code>. Although a strongly consistent read might take more time than an eventually consistent read, it always returns the last updated value.</p>
@@ -5147,49 +10440,92 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<GetItemOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(GetItemError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<GetItemOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(GetItemError::from_response(response))),
+                    )
+                }
+            })
     }
 
-    /// <p>List backups associated with an AWS account. To list backups for a given table, specify <code>TableName</code>. <code>ListBackups</code> returns a paginated list of results with at most 1 MB worth of items in a page. You can also specify a limit for the maximum number of entries to be returned in a page. </p> <p>In the request, start time is inclusive, but end time is exclusive. Note that these limits are for the time at which the original backup was requested.</p> <p>You can call <code>ListBackups</code> a maximum of five times per second.</p>
-    fn list_backups(
-        &self,
-        input: ListBackupsInput,
-    ) -> RusotoFuture<ListBackupsOutput, ListBackupsError> {
-        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
-
-        request.set_content_type("application/x-amz-json-1.0".to_owned());
-        request.add_header("x-amz-target", "DynamoDB_20120810.ListBackups");
-        let encoded = serde_json::to_string(&input).unwrap();
-        request.set_payload(Some(encoded));
-
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListBackupsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListBackupsError::from_response(response))),
-                )
-            }
-        })
+    /// <p>List backups associated with an AWS account. To list backups for a given table, specify <code>TableName</code>. <code>ListBackups</code> returns a paginated list of results with at most 1 MB worth of items in a page. You can also specify a limit for the maximum number of entries to be returned in a page. </p> <p>In the request, start time is inclusive, but end time is exclusive. Note that these limits are for the time at which the original backup was requested.</p> <p>You can call <code>ListBackups</code> a maximum of five times per second.</p>
+    fn list_backups(
+        &self,
+        input: ListBackupsInput,
+    ) -> RusotoFuture<ListBackupsOutput, ListBackupsError> {
+        let mut request = SignedRequest::new("POST", "dynamodb", &self.region, "/");
+
+        request.set_content_type("application/x-amz-json-1.0".to_owned());
+        request.add_header("x-amz-target", "DynamoDB_20120810.ListBackups");
+        let encoded = serde_json::to_string(&input).unwrap();
+        request.set_payload(Some(encoded));
+
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<ListBackupsOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(ListBackupsError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p>Lists all global tables that have a replica in the specified Region.</p>
@@ -5204,21 +10540,41 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListGlobalTablesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListGlobalTablesError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<ListGlobalTablesOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response.buffer().from_err().and_then(|response| {
+                            Err(ListGlobalTablesError::from_response(response))
+                        }),
+                    )
+                }
+            })
     }
 
     /// <p>Returns an array of table names associated with the current account and endpoint. The output from <code>ListTables</code> is paginated, with each page returning a maximum of 100 table names.</p>
@@ -5233,21 +10589,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTablesOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTablesError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<ListTablesOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(ListTablesError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p>List all tags on an Amazon DynamoDB resource. You can call ListTagsOfResource up to 10 times per second, per account.</p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
@@ -5262,21 +10639,41 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<ListTagsOfResourceOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ListTagsOfResourceError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<ListTagsOfResourceOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response.buffer().from_err().and_then(|response| {
+                            Err(ListTagsOfResourceError::from_response(response))
+                        }),
+                    )
+                }
+            })
     }
 
     /// <p>Creates a new item, or replaces an old item with a new item. If an item that has the same primary key as the new item already exists in the specified table, the new item completely replaces the existing item. You can perform a conditional put operation (add a new item if one with the specified primary key doesn't exist), or replace an existing item if it has certain attribute values. You can return the item's attribute values in the same operation, using the <code>ReturnValues</code> parameter.</p> <important> <p>This topic provides general information about the <code>PutItem</code> API.</p> <p>For information on how to call the <code>PutItem</code> API using the AWS SDK in specific languages, see the following:</p> <ul> <li> <p> <a href="http://docs.aws.amazon.com/goto/aws-cli/dynamodb-2012-08-10/PutItem"> PutItem in the AWS Command Line Interface</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/DotNetSDKV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for .NET</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForCpp/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for C++</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForGoV1/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Go</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForJava/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Java</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/AWSJavaScriptSDK/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for JavaScript</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForPHPV3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for PHP V3</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/boto3/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Python</a> </p> </li> <li> <p> <a href="http://docs.aws.amazon.com/goto/SdkForRubyV2/dynamodb-2012-08-10/PutItem"> PutItem in the AWS SDK for Ruby V2</a> </p> </li> </ul> </important> <p>When you add an item, the primary key attributes are the only required attributes. Attribute values cannot be null. String and Binary type attributes must have lengths greater than zero. Set type attributes cannot be empty. Requests with empty values will be rejected with a <code>ValidationException</code> exception.</p> <note> <p>To prevent a new item from replacing an existing item, use a conditional expression that contains the <code>attribute_not_exists</code> function with the name of the attribute being used as the partition key for the table. Since every record must contain that attribute, the <code>attribute_not_exists</code> function will only succeed if no matching item exists.</p> </note> <p>For more information about <code>PutItem</code>, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/WorkingWithItems.html">Working with Items</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
@@ -5288,20 +10685,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<PutItemOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(PutItemError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<PutItemOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(PutItemError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p>The <code>Query</code> operation finds items based on primary key values. You can query any table or secondary index that has a composite primary key (a partition key and a sort key). </p> <p>Use the <code>KeyConditionExpression</code> parameter to provide a specific value for the partition key. The <code>Query</code> operation will return all of the items from the table or index with that partition key value. You can optionally narrow the scope of the <code>Query</code> operation by specifying a sort key value and a comparison operator in <code>KeyConditionExpression</code>. To further refine the <code>Query</code> results, you can optionally provide a <code>FilterExpression</code>. A <code>FilterExpression</code> determines which items within the results should be returned to you. All of the other results are discarded. </p> <p> A <code>Query</code> operation always returns a result set. If no matching items are found, the result set will be empty. Queries that do not return results consume the minimum number of read capacity units for that type of read operation. </p> <note> <p> DynamoDB calculates the number of read capacity units consumed based on item size, not on the amount of data that is returned to an application. The number of capacity units consumed will be the same whether you request all of the attributes (the default behavior) or just some of them (using a projection expression). The number will also be the same whether or not you use a <code>FilterExpression</code>. </p> </note> <p> <code>Query</code> results are always sorted by the sort key value. If the data type of the sort key is Number, the results are returned in numeric order; otherwise, the results are returned in order of UTF-8 bytes. By default, the sort order is ascending. To reverse the order, set the <code>ScanIndexForward</code> parameter to false. </p> <p> A single <code>Query</code> operation will read up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you will need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Query.html#Query.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>FilterExpression</code> is applied after a <code>Query</code> finishes, but before the results are returned. A <code>FilterExpression</code> cannot contain partition key or sort key attributes. You need to specify those attributes in the <code>KeyConditionExpression</code>. </p> <note> <p> A <code>Query</code> operation can return an empty result set and a <code>LastEvaluatedKey</code> if all the items read for the page of results are filtered out. </p> </note> <p>You can query a table, a local secondary index, or a global secondary index. For a query on a table or on a local secondary index, you can set the <code>ConsistentRead</code> parameter to <code>true</code> and obtain a strongly consistent result. Global secondary indexes support eventually consistent reads only, so do not specify <code>ConsistentRead</code> when querying a global secondary index.</p>
@@ -5313,20 +10732,41 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<QueryOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(QueryError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response).deserialize::<QueryOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(QueryError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p><p>Creates a new table from an existing backup. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p>You can call <code>RestoreTableFromBackup</code> at a maximum rate of 10 times per second.</p> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> </ul></p>
@@ -5341,20 +10781,39 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RestoreTableFromBackupOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response.buffer().from_err().and_then(|response| {
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<RestoreTableFromBackupOutput, _>()
+                    }))
+                } else {
+                    Box::new(response.buffer().from_err().and_then(|response| {
                         Err(RestoreTableFromBackupError::from_response(response))
-                    }),
-                )
-            }
-        })
+                    }))
+                }
+            })
     }
 
     /// <p><p>Restores the specified table to the specified point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. You can restore your table to any point in time during the last 35 days. Any number of users can execute up to 4 concurrent restores (any type of restore) in a given account. </p> <p> When you restore using point in time recovery, DynamoDB restores your table data to the state based on the selected date and time (day:hour:minute:second) to a new table. </p> <p> Along with data, the following are also included on the new restored table using point in time recovery: </p> <ul> <li> <p>Global secondary indexes (GSIs)</p> </li> <li> <p>Local secondary indexes (LSIs)</p> </li> <li> <p>Provisioned read and write capacity</p> </li> <li> <p>Encryption settings</p> <important> <p> All these settings come from the current settings of the source table at the time of restore. </p> </important> </li> </ul> <p>You must manually set up the following on the restored table:</p> <ul> <li> <p>Auto scaling policies</p> </li> <li> <p>IAM policies</p> </li> <li> <p>Amazon CloudWatch metrics and alarms</p> </li> <li> <p>Tags</p> </li> <li> <p>Stream settings</p> </li> <li> <p>Time to Live (TTL) settings</p> </li> <li> <p>Point in time recovery settings</p> </li> </ul></p>
@@ -5372,18 +10831,39 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<RestoreTableToPointInTimeOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(RestoreTableToPointInTimeError::from_response(response))
-                }))
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<RestoreTableToPointInTimeOutput, _>()
+                    }))
+                } else {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        Err(RestoreTableToPointInTimeError::from_response(response))
+                    }))
+                }
+            })
     }
 
     /// <p>The <code>Scan</code> operation returns one or more items and item attributes by accessing every item in a table or a secondary index. To have DynamoDB return fewer items, you can provide a <code>FilterExpression</code> operation.</p> <p>If the total number of scanned items exceeds the maximum dataset size limit of 1 MB, the scan stops and results are returned to the user as a <code>LastEvaluatedKey</code> value to continue the scan in a subsequent operation. The results also include the number of items exceeding the limit. A scan can result in no table data meeting the filter criteria. </p> <p>A single <code>Scan</code> operation reads up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>Scan</code> operations proceed sequentially; however, for faster performance on a large table or secondary index, applications can request a parallel <code>Scan</code> operation by providing the <code>Segment</code> and <code>TotalSegments</code> parameters. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.ParallelScan">Parallel Scan</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p> <code>Scan</code> uses eventually consistent reads when accessing the data in a table; therefore, the result set might not include the changes to data in the table immediately before the operation began. If you need a consistent copy of the data, as of the time that the <code>Scan</code> begins, you can set the <code>ConsistentRead</code> parameter to <code>true</code>.</p>
@@ -5395,20 +10875,41 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response).deserialize::<ScanOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(ScanError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response).deserialize::<ScanOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(ScanError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p>Associate a set of tags with an Amazon DynamoDB resource. You can then activate these user-defined tags so that they appear on the Billing and Cost Management console for cost allocation tracking. You can call TagResource up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
@@ -5420,18 +10921,39 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TagResourceError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(future::ok(::std::mem::drop(response)))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(TagResourceError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p><p> <code>TransactGetItems</code> is a synchronous operation that atomically retrieves multiple items from one or more tables (but not from indexes) in a single account and Region. A <code>TransactGetItems</code> call can contain up to 25 <code>TransactGetItem</code> objects, each of which contains a <code>Get</code> structure that specifies an item to retrieve from a table in the account and Region. A call to <code>TransactGetItems</code> cannot retrieve items from tables in more than one AWS account or Region. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <note> <p>All AWS Regions and AWS GovCloud (US) support up to 25 items per transaction with up to 4 MB of data, except the following AWS Regions: </p> <ul> <li> <p>China (Beijing)</p> </li> <li> <p>China (Ningxia)</p> </li> </ul> <p>The China (Beijing) and China (Ningxia) Regions support up to 10 items per transaction with up to 4 MB of data. </p> </note> <p>DynamoDB rejects the entire <code>TransactGetItems</code> request if any of the following is true:</p> <ul> <li> <p>A conflicting operation is in the process of updating an item to be read.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> <li> <p>The aggregate size of the items in the transaction cannot exceed 4 MB.</p> </li> </ul></p>
@@ -5446,21 +10968,41 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TransactGetItemsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TransactGetItemsError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<TransactGetItemsOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response.buffer().from_err().and_then(|response| {
+                            Err(TransactGetItemsError::from_response(response))
+                        }),
+                    )
+                }
+            })
     }
 
     /// <p><p> <code>TransactWriteItems</code> is a synchronous write operation that groups up to 25 action requests. These actions can target items in different tables, but not in different AWS accounts or Regions, and no two actions can target the same item. For example, you cannot both <code>ConditionCheck</code> and <code>Update</code> the same item. The aggregate size of the items in the transaction cannot exceed 4 MB.</p> <note> <p>All AWS Regions and AWS GovCloud (US) support up to 25 items per transaction with up to 4 MB of data, except the following AWS Regions: </p> <ul> <li> <p>China (Beijing)</p> </li> <li> <p>China (Ningxia)</p> </li> </ul> <p>The China (Beijing) and China (Ningxia) Regions support up to 10 items per transaction with up to 4 MB of data. </p> </note> <p>The actions are completed atomically so that either all of them succeed, or all of them fail. They are defined by the following objects:</p> <ul> <li> <p> <code>Put</code>  &#x97;   Initiates a <code>PutItem</code> operation to write a new item. This structure specifies the primary key of the item to be written, the name of the table to write it in, an optional condition expression that must be satisfied for the write to succeed, a list of the item&#39;s attributes, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Update</code>  &#x97;   Initiates an <code>UpdateItem</code> operation to update an existing item. This structure specifies the primary key of the item to be updated, the name of the table where it resides, an optional condition expression that must be satisfied for the update to succeed, an expression that defines one or more attributes to be updated, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>Delete</code>  &#x97;   Initiates a <code>DeleteItem</code> operation to delete an existing item. This structure specifies the primary key of the item to be deleted, the name of the table where it resides, an optional condition expression that must be satisfied for the deletion to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> <li> <p> <code>ConditionCheck</code>  &#x97;   Applies a condition to an item that is not being modified by the transaction. This structure specifies the primary key of the item to be checked, the name of the table where it resides, a condition expression that must be satisfied for the transaction to succeed, and a field indicating whether to retrieve the item&#39;s attributes if the condition is not met.</p> </li> </ul> <p>DynamoDB rejects the entire <code>TransactWriteItems</code> request if any of the following is true:</p> <ul> <li> <p>A condition in one of the condition expressions is not met.</p> </li> <li> <p>An ongoing operation is in the process of updating the same item.</p> </li> <li> <p>There is insufficient provisioned capacity for the transaction to be completed.</p> </li> <li> <p>An item size becomes too large (bigger than 400 KB), a local secondary index (LSI) becomes too large, or a similar validation error occurs because of changes made by the transaction.</p> </li> <li> <p>The aggregate size of the items in the transaction exceeds 4 MB.</p> </li> <li> <p>There is a user error, such as an invalid data format.</p> </li> </ul></p>
@@ -5475,21 +11017,41 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<TransactWriteItemsOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(TransactWriteItemsError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<TransactWriteItemsOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response.buffer().from_err().and_then(|response| {
+                            Err(TransactWriteItemsError::from_response(response))
+                        }),
+                    )
+                }
+            })
     }
 
     /// <p>Removes the association of tags from an Amazon DynamoDB resource. You can call <code>UntagResource</code> up to five times per second, per account. </p> <p>For an overview on tagging DynamoDB resources, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Tagging.html">Tagging for DynamoDB</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p>
@@ -5501,18 +11063,39 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(future::ok(::std::mem::drop(response)))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UntagResourceError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(future::ok(::std::mem::drop(response)))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(UntagResourceError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p> <code>UpdateContinuousBackups</code> enables or disables point in time recovery for the specified table. A successful <code>UpdateContinuousBackups</code> call returns the current <code>ContinuousBackupsDescription</code>. Continuous backups are <code>ENABLED</code> on all tables at table creation. If point in time recovery is enabled, <code>PointInTimeRecoveryStatus</code> will be set to ENABLED.</p> <p> Once continuous backups and point in time recovery are enabled, you can restore to any point in time within <code>EarliestRestorableDateTime</code> and <code>LatestRestorableDateTime</code>. </p> <p> <code>LatestRestorableDateTime</code> is typically 5 minutes before the current time. You can restore your table to any point in time during the last 35 days. </p>
@@ -5527,18 +11110,39 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateContinuousBackupsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateContinuousBackupsError::from_response(response))
-                }))
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<UpdateContinuousBackupsOutput, _>()
+                    }))
+                } else {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        Err(UpdateContinuousBackupsError::from_response(response))
+                    }))
+                }
+            })
     }
 
     /// <p><p>Adds or removes replicas in the specified global table. The global table must already exist to be able to use this operation. Any replica to be added must be empty, have the same name as the global table, have the same key schema, have DynamoDB Streams enabled, and have the same provisioned and maximum write capacity units.</p> <note> <p>Although you can use <code>UpdateGlobalTable</code> to add replicas and remove replicas in a single request, for simplicity we recommend that you issue separate requests for adding or removing replicas.</p> </note> <p> If global secondary indexes are specified, then the following conditions must also be met: </p> <ul> <li> <p> The global secondary indexes must have the same name. </p> </li> <li> <p> The global secondary indexes must have the same hash key and sort key (if present). </p> </li> <li> <p> The global secondary indexes must have the same provisioned and maximum write capacity units. </p> </li> </ul></p>
@@ -5553,21 +11157,41 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGlobalTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateGlobalTableError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<UpdateGlobalTableOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response.buffer().from_err().and_then(|response| {
+                            Err(UpdateGlobalTableError::from_response(response))
+                        }),
+                    )
+                }
+            })
     }
 
     /// <p>Updates settings for a global table.</p>
@@ -5585,18 +11209,39 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateGlobalTableSettingsOutput, _>()
-                }))
-            } else {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    Err(UpdateGlobalTableSettingsError::from_response(response))
-                }))
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<UpdateGlobalTableSettingsOutput, _>()
+                    }))
+                } else {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        Err(UpdateGlobalTableSettingsError::from_response(response))
+                    }))
+                }
+            })
     }
 
     /// <p>Edits an existing item's attributes, or adds a new item to the table if it does not already exist. You can put, delete, or add attribute values. You can also perform a conditional update on an existing item (insert a new attribute name-value pair if it doesn't exist, or replace an existing name-value pair if it has certain expected attribute values).</p> <p>You can also return the item's attribute values in the same <code>UpdateItem</code> operation using the <code>ReturnValues</code> parameter.</p>
@@ -5611,21 +11256,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateItemOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateItemError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<UpdateItemOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(UpdateItemError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p>Modifies the provisioned throughput settings, global secondary indexes, or DynamoDB Streams settings for a given table.</p> <p>You can only perform one of the following operations at once:</p> <ul> <li> <p>Modify the provisioned throughput settings of the table.</p> </li> <li> <p>Enable or disable DynamoDB Streams on the table.</p> </li> <li> <p>Remove a global secondary index from the table.</p> </li> <li> <p>Create a new global secondary index on the table. After the index begins backfilling, you can use <code>UpdateTable</code> to perform other operations.</p> </li> </ul> <p> <code>UpdateTable</code> is an asynchronous operation; while it is executing, the table status changes from <code>ACTIVE</code> to <code>UPDATING</code>. While it is <code>UPDATING</code>, you cannot issue another <code>UpdateTable</code> request. When the table returns to the <code>ACTIVE</code> state, the <code>UpdateTable</code> operation is complete.</p>
@@ -5640,21 +11306,42 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTableOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateTableError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<UpdateTableOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response
+                            .buffer()
+                            .from_err()
+                            .and_then(|response| Err(UpdateTableError::from_response(response))),
+                    )
+                }
+            })
     }
 
     /// <p>The <code>UpdateTimeToLive</code> method enables or disables Time to Live (TTL) for the specified table. A successful <code>UpdateTimeToLive</code> call returns the current <code>TimeToLiveSpecification</code>. It can take up to one hour for the change to fully process. Any additional <code>UpdateTimeToLive</code> calls for the same table during this one hour duration result in a <code>ValidationException</code>. </p> <p>TTL compares the current time in epoch time format to the time stored in the TTL attribute of an item. If the epoch time value stored in the attribute is less than the current time, the item is marked as expired and subsequently deleted.</p> <note> <p> The epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC. </p> </note> <p>DynamoDB deletes expired items on a best-effort basis to ensure availability of throughput for other data operations. </p> <important> <p>DynamoDB typically deletes expired items within two days of expiration. The exact duration within which an item gets deleted after expiration is specific to the nature of the workload. Items that have expired and not been deleted will still show up in reads, queries, and scans.</p> </important> <p>As items are deleted, they are removed from any local secondary index and global secondary index immediately in the same eventually consistent way as a standard delete operation.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/TTL.html">Time To Live</a> in the Amazon DynamoDB Developer Guide. </p>
@@ -5669,20 +11356,40 @@ impl DynamoDb for DynamoDbClient {
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
 
-        self.client.sign_and_dispatch(request, |response| {
-            if response.status.is_success() {
-                Box::new(response.buffer().from_err().and_then(|response| {
-                    proto::json::ResponsePayload::new(&response)
-                        .deserialize::<UpdateTimeToLiveOutput, _>()
-                }))
-            } else {
-                Box::new(
-                    response
-                        .buffer()
-                        .from_err()
-                        .and_then(|response| Err(UpdateTimeToLiveError::from_response(response))),
-                )
-            }
-        })
+        let discovery_client = self.clone();
+        let discovery = rusoto_core::endpoint_discovery::EndpointDiscovery::new(false, move || {
+            Box::new(
+                discovery_client
+                    .describe_endpoints()
+                    .map(|response| {
+                        response
+                            .endpoints
+                            .into_iter()
+                            .map(|endpoint| {
+                                rusoto_core::endpoint_discovery::DiscoveredEndpoint::new(
+                                    endpoint.address,
+                                    endpoint.cache_period_in_minutes,
+                                )
+                            })
+                            .collect()
+                    })
+                    .map_err(|err| err.to_string()),
+            )
+        });
+        self.client
+            .sign_and_dispatch_with_discovery(request, discovery, |response| {
+                if response.status.is_success() {
+                    Box::new(response.buffer().from_err().and_then(|response| {
+                        proto::json::ResponsePayload::new(&response)
+                            .deserialize::<UpdateTimeToLiveOutput, _>()
+                    }))
+                } else {
+                    Box::new(
+                        response.buffer().from_err().and_then(|response| {
+                            Err(UpdateTimeToLiveError::from_response(response))
+                        }),
+                    )
+                }
+            })
     }
 }
@@ -62,6 +62,8 @@ extern crate serde_json;
 
 mod generated;
 mod custom;
+#[cfg(feature = "fake")]
+pub mod fake;
 
 pub use crate::generated::*;
 pub use crate::custom::*;
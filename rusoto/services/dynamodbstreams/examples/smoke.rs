@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon DynamoDB Streams clients.
+//!
+//! Makes a single read-only `ListStreams` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_dynamodbstreams::{DynamoDbStreams, DynamoDbStreamsClient, ListStreamsInput};
+
+fn main() {
+    let client = DynamoDbStreamsClient::from_env();
+    match client.list_streams(ListStreamsInput::default()).sync() {
+        Ok(_) => println!("ListStreams succeeded"),
+        Err(error) => {
+            eprintln!("ListStreams failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Stream records delivered to Lambda functions.
+//!
+//! Lambda functions triggered by a DynamoDB stream receive its records as a JSON document
+//! listing them as `Records`. `DynamoDbStreamEvent` is that document, parsed with
+//! `DynamoDbStreamEvent::from_json`. Its records differ slightly from the ones returned by
+//! `GetRecords`: they name the ARN of their stream, and their user identity is in camel case.
+//! They convert into `Record`s, so both can be handled by the same code.
+//!
+//! With the `dynamodb` feature enabled, the keys and images of stream records also convert into
+//! `rusoto_dynamodb` items, to be read as the items returned by `rusoto_dynamodb` are.
+//!
+//! # Examples
+//!
+//! ```
+//! use rusoto_dynamodbstreams::events::DynamoDbStreamEvent;
+//!
+//! let event = DynamoDbStreamEvent::from_json(r#"{"Records": [{
+//!     "eventID": "c4ca4238a0b923820dcc509a6f75849b",
+//!     "eventName": "INSERT",
+//!     "eventVersion": "1.1",
+//!     "eventSource": "aws:dynamodb",
+//!     "awsRegion": "us-east-1",
+//!     "dynamodb": {
+//!         "Keys": {"Id": {"N": "101"}},
+//!         "NewImage": {"Id": {"N": "101"}, "Message": {"S": "New item!"}},
+//!         "SequenceNumber": "111",
+//!         "SizeBytes": 26,
+//!         "StreamViewType": "NEW_IMAGE"
+//!     },
+//!     "eventSourceARN": "arn:aws:dynamodb:us-east-1:123456789012:table/ExampleTable/stream/2015-06-27T00:48:05.899"
+//! }]}"#).unwrap();
+//!
+//! let record = &event.records[0];
+//! let new_image = record.dynamodb.new_image.as_ref().unwrap();
+//! assert_eq!(new_image["Message"].s, Some("New item!".to_owned()));
+//! ```
+
+#[cfg(feature = "dynamodb")]
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer};
+
+#[cfg(feature = "dynamodb")]
+use crate::generated::AttributeValue;
+use crate::generated::{Identity, OperationType, Record, StreamRecord};
+
+/// The records of a DynamoDB stream a Lambda function is invoked with.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub struct DynamoDbStreamEvent {
+    /// The records.
+    #[serde(rename = "Records", default)]
+    pub records: Vec<StreamEventRecord>,
+}
+
+impl DynamoDbStreamEvent {
+    /// Parses an event.
+    pub fn from_json(json: &str) -> Result<DynamoDbStreamEvent, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// A stream record delivered to a Lambda function.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamEventRecord {
+    /// The region of the table.
+    #[serde(default)]
+    pub aws_region: Option<String>,
+    /// The change to the item, with its keys and images.
+    pub dynamodb: StreamRecord,
+    /// The unique identifier of the record.
+    #[serde(rename = "eventID", default)]
+    pub event_id: Option<String>,
+    /// `INSERT`, `MODIFY` or `REMOVE`.
+    #[serde(default)]
+    pub event_name: Option<OperationType>,
+    /// `aws:dynamodb`.
+    #[serde(default)]
+    pub event_source: Option<String>,
+    /// The ARN of the stream.
+    #[serde(rename = "eventSourceARN", default)]
+    pub event_source_arn: Option<String>,
+    /// The version of the record format.
+    #[serde(default)]
+    pub event_version: Option<String>,
+    /// The service removing the item, for items deleted by Time to Live.
+    #[serde(default, deserialize_with = "deserialize_identity")]
+    pub user_identity: Option<Identity>,
+}
+
+impl From<StreamEventRecord> for Record {
+    fn from(record: StreamEventRecord) -> Record {
+        Record {
+            aws_region: record.aws_region,
+            dynamodb: Some(record.dynamodb),
+            event_id: record.event_id,
+            event_name: record.event_name,
+            event_source: record.event_source,
+            event_version: record.event_version,
+            user_identity: record.user_identity,
+        }
+    }
+}
+
+/// Reads the camel cased user identity of records delivered to Lambda.
+fn deserialize_identity<'de, D>(deserializer: D) -> Result<Option<Identity>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct LambdaIdentity {
+        #[serde(default)]
+        principal_id: Option<String>,
+        #[serde(rename = "type", default)]
+        type_: Option<String>,
+    }
+
+    let identity = Option::<LambdaIdentity>::deserialize(deserializer)?;
+    Ok(identity.map(|identity| Identity {
+        principal_id: identity.principal_id,
+        type_: identity.type_,
+    }))
+}
+
+#[cfg(feature = "dynamodb")]
+impl From<AttributeValue> for rusoto_dynamodb::AttributeValue {
+    fn from(value: AttributeValue) -> rusoto_dynamodb::AttributeValue {
+        rusoto_dynamodb::AttributeValue {
+            b: value.b,
+            bool: value.bool,
+            bs: value.bs,
+            l: value
+                .l
+                .map(|list| list.into_iter().map(Into::into).collect()),
+            m: value.m.map(to_item),
+            n: value.n,
+            ns: value.ns,
+            null: value.null,
+            s: value.s,
+            ss: value.ss,
+        }
+    }
+}
+
+/// Converts the keys or an image of a stream record into a `rusoto_dynamodb` item.
+#[cfg(feature = "dynamodb")]
+pub fn to_item(image: HashMap<String, AttributeValue>) -> rusoto_dynamodb::stream::Item {
+    image
+        .into_iter()
+        .map(|(name, value)| (name, value.into()))
+        .collect()
+}
+
+#[cfg(feature = "dynamodb")]
+impl StreamRecord {
+    /// The primary key of the modified item, as a `rusoto_dynamodb` item.
+    pub fn keys_item(&self) -> Option<rusoto_dynamodb::stream::Item> {
+        self.keys.clone().map(to_item)
+    }
+
+    /// The item after it was modified, as a `rusoto_dynamodb` item.
+    pub fn new_item(&self) -> Option<rusoto_dynamodb::stream::Item> {
+        self.new_image.clone().map(to_item)
+    }
+
+    /// The item before it was modified, as a `rusoto_dynamodb` item.
+    pub fn old_item(&self) -> Option<rusoto_dynamodb::stream::Item> {
+        self.old_image.clone().map(to_item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a TTL deletion, as delivered to Lambda
+    const EVENT: &str = r#"{
+        "Records": [
+            {
+                "eventID": "2",
+                "eventName": "REMOVE",
+                "eventVersion": "1.1",
+                "eventSource": "aws:dynamodb",
+                "awsRegion": "us-east-1",
+                "dynamodb": {
+                    "ApproximateCreationDateTime": 1479499740,
+                    "Keys": {"Id": {"N": "101"}},
+                    "OldImage": {
+                        "Id": {"N": "101"},
+                        "Tags": {"L": [{"S": "a"}, {"M": {"Nested": {"BOOL": true}}}]},
+                        "Payload": {"B": "aGVsbG8="}
+                    },
+                    "SequenceNumber": "222",
+                    "SizeBytes": 38,
+                    "StreamViewType": "OLD_IMAGE"
+                },
+                "userIdentity": {"type": "Service", "principalId": "dynamodb.amazonaws.com"},
+                "eventSourceARN": "arn:aws:dynamodb:us-east-1:123456789012:table/ExampleTable/stream/2015-06-27T00:48:05.899"
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn parses_lambda_events() {
+        let event = DynamoDbStreamEvent::from_json(EVENT).unwrap();
+        assert_eq!(event.records.len(), 1);
+
+        let record = &event.records[0];
+        assert_eq!(record.event_name, Some(OperationType::Remove));
+        assert!(record
+            .event_source_arn
+            .as_ref()
+            .unwrap()
+            .ends_with("/stream/2015-06-27T00:48:05.899"));
+        assert_eq!(
+            record.user_identity,
+            Some(Identity {
+                principal_id: Some("dynamodb.amazonaws.com".to_owned()),
+                type_: Some("Service".to_owned()),
+            })
+        );
+        let old_image = record.dynamodb.old_image.as_ref().unwrap();
+        assert_eq!(old_image["Payload"].b, Some(b"hello"[..].into()));
+        assert_eq!(record.dynamodb.new_image, None);
+    }
+
+    #[test]
+    fn converts_into_records() {
+        let event = DynamoDbStreamEvent::from_json(EVENT).unwrap();
+        let stream_record = event.records[0].dynamodb.clone();
+        let record: Record = event.records.into_iter().next().unwrap().into();
+
+        assert_eq!(record.event_id, Some("2".to_owned()));
+        assert_eq!(record.dynamodb, Some(stream_record));
+        assert!(record.user_identity.is_some());
+    }
+
+    #[cfg(feature = "dynamodb")]
+    #[test]
+    fn converts_images_into_dynamodb_items() {
+        let event = DynamoDbStreamEvent::from_json(EVENT).unwrap();
+        let record = &event.records[0].dynamodb;
+
+        let keys = record.keys_item().unwrap();
+        assert_eq!(keys["Id"].n, Some("101".to_owned()));
+        assert!(record.new_item().is_none());
+
+        let old_item = record.old_item().unwrap();
+        let tags = old_item["Tags"].l.as_ref().unwrap();
+        assert_eq!(tags[0].s, Some("a".to_owned()));
+        assert_eq!(tags[1].m.as_ref().unwrap()["Nested"].bool, Some(true));
+        assert_eq!(old_item["Payload"].b, Some(b"hello"[..].into()));
+    }
+}
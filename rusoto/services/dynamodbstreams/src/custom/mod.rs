@@ -1 +1,2 @@
-
+/// Stream records delivered to Lambda functions, and their conversion into `rusoto_dynamodb` items
+pub mod events;
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Represents the data for an attribute. You can set one, and only one, of the elements.</p> <p>Each attribute in an item is a name-value pair. An attribute can be single-valued or multi-valued set. For example, a book item can have title and authors attributes. Each book has one title but can have many authors. The multi-valued attribute is a set; duplicate values are not allowed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -95,6 +96,52 @@ pub struct DescribeStreamInput {
     pub stream_arn: String,
 }
 
+impl DescribeStreamInput {
+    /// Returns a builder for `DescribeStreamInput`, checking its required members when built.
+    pub fn builder() -> DescribeStreamInputBuilder {
+        DescribeStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStreamInput`](struct.DescribeStreamInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStreamInputBuilder {
+    exclusive_start_shard_id: Option<String>,
+    limit: Option<i64>,
+    stream_arn: Option<String>,
+}
+
+impl DescribeStreamInputBuilder {
+    /// Sets the [`exclusive_start_shard_id`](struct.DescribeStreamInput.html#structfield.exclusive_start_shard_id) member.
+    pub fn exclusive_start_shard_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_shard_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeStreamInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.DescribeStreamInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeStreamInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStreamInput {
+            exclusive_start_shard_id: self.exclusive_start_shard_id,
+            limit: self.limit,
+            stream_arn: self
+                .stream_arn
+                .ok_or(::rusoto_core::BuildError::missing("stream_arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>DescribeStream</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -117,6 +164,44 @@ pub struct GetRecordsInput {
     pub shard_iterator: String,
 }
 
+impl GetRecordsInput {
+    /// Returns a builder for `GetRecordsInput`, checking its required members when built.
+    pub fn builder() -> GetRecordsInputBuilder {
+        GetRecordsInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetRecordsInput`](struct.GetRecordsInput.html).
+#[derive(Default, Debug)]
+pub struct GetRecordsInputBuilder {
+    limit: Option<i64>,
+    shard_iterator: Option<String>,
+}
+
+impl GetRecordsInputBuilder {
+    /// Sets the [`limit`](struct.GetRecordsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shard_iterator`](struct.GetRecordsInput.html#structfield.shard_iterator) member.
+    pub fn shard_iterator<T: Into<String>>(mut self, value: T) -> Self {
+        self.shard_iterator = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRecordsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRecordsInput, ::rusoto_core::BuildError> {
+        Ok(GetRecordsInput {
+            limit: self.limit,
+            shard_iterator: self
+                .shard_iterator
+                .ok_or(::rusoto_core::BuildError::missing("shard_iterator"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>GetRecords</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -132,7 +217,7 @@ pub struct GetRecordsOutput {
 }
 
 /// <p>Represents the input of a <code>GetShardIterator</code> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetShardIteratorInput {
     /// <p>The sequence number of a stream record in the shard from which to start reading.</p>
     #[serde(rename = "SequenceNumber")]
@@ -143,12 +228,70 @@ pub struct GetShardIteratorInput {
     pub shard_id: String,
     /// <p><p>Determines how the shard iterator is used to start reading stream records from the shard:</p> <ul> <li> <p> <code>AT<em>SEQUENCE</em>NUMBER</code> - Start reading exactly from the position denoted by a specific sequence number.</p> </li> <li> <p> <code>AFTER<em>SEQUENCE</em>NUMBER</code> - Start reading right after the position denoted by a specific sequence number.</p> </li> <li> <p> <code>TRIM_HORIZON</code> - Start reading at the last (untrimmed) stream record, which is the oldest record in the shard. In DynamoDB Streams, there is a 24 hour limit on data retention. Stream records whose age exceeds this limit are subject to removal (trimming) from the stream.</p> </li> <li> <p> <code>LATEST</code> - Start reading just after the most recent stream record in the shard, so that you always read the most recent data in the shard.</p> </li> </ul></p>
     #[serde(rename = "ShardIteratorType")]
-    pub shard_iterator_type: String,
+    pub shard_iterator_type: ShardIteratorType,
     /// <p>The Amazon Resource Name (ARN) for the stream.</p>
     #[serde(rename = "StreamArn")]
     pub stream_arn: String,
 }
 
+impl GetShardIteratorInput {
+    /// Returns a builder for `GetShardIteratorInput`, checking its required members when built.
+    pub fn builder() -> GetShardIteratorInputBuilder {
+        GetShardIteratorInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetShardIteratorInput`](struct.GetShardIteratorInput.html).
+#[derive(Default, Debug)]
+pub struct GetShardIteratorInputBuilder {
+    sequence_number: Option<String>,
+    shard_id: Option<String>,
+    shard_iterator_type: Option<ShardIteratorType>,
+    stream_arn: Option<String>,
+}
+
+impl GetShardIteratorInputBuilder {
+    /// Sets the [`sequence_number`](struct.GetShardIteratorInput.html#structfield.sequence_number) member.
+    pub fn sequence_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.sequence_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shard_id`](struct.GetShardIteratorInput.html#structfield.shard_id) member.
+    pub fn shard_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.shard_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shard_iterator_type`](struct.GetShardIteratorInput.html#structfield.shard_iterator_type) member.
+    pub fn shard_iterator_type<T: Into<ShardIteratorType>>(mut self, value: T) -> Self {
+        self.shard_iterator_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.GetShardIteratorInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetShardIteratorInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetShardIteratorInput, ::rusoto_core::BuildError> {
+        Ok(GetShardIteratorInput {
+            sequence_number: self.sequence_number,
+            shard_id: self
+                .shard_id
+                .ok_or(::rusoto_core::BuildError::missing("shard_id"))?,
+            shard_iterator_type: self
+                .shard_iterator_type
+                .ok_or(::rusoto_core::BuildError::missing("shard_iterator_type"))?,
+            stream_arn: self
+                .stream_arn
+                .ok_or(::rusoto_core::BuildError::missing("stream_arn"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>GetShardIterator</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -174,7 +317,7 @@ pub struct Identity {
 }
 
 /// <p><p>Represents <i>a single element</i> of a key schema. A key schema specifies the attributes that make up the primary key of a table, or the key attributes of an index.</p> <p>A <code>KeySchemaElement</code> represents exactly one attribute of the primary key. For example, a simple primary key (partition key) would be represented by one <code>KeySchemaElement</code>. A composite primary key (partition key and sort key) would require one <code>KeySchemaElement</code> for the partition key, and another <code>KeySchemaElement</code> for the sort key.</p> <note> <p>The partition key of an item is also known as its <i>hash attribute</i>. The term &quot;hash attribute&quot; derives from DynamoDB&#39;s usage of an internal hash function to evenly distribute data items across partitions, based on their partition key values.</p> <p>The sort key of an item is also known as its <i>range attribute</i>. The term &quot;range attribute&quot; derives from the way DynamoDB stores items with the same partition key physically close together, in sorted order by the sort key value.</p> </note></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct KeySchemaElement {
     /// <p>The name of a key attribute.</p>
@@ -182,7 +325,79 @@ pub struct KeySchemaElement {
     pub attribute_name: String,
     /// <p>The attribute data, consisting of the data type and the attribute value itself.</p>
     #[serde(rename = "KeyType")]
-    pub key_type: String,
+    pub key_type: KeyType,
+}
+
+/// <p>The attribute data, consisting of the data type and the attribute value itself.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeyType {
+    /// <p><code>HASH</code></p>
+    Hash,
+    /// <p><code>RANGE</code></p>
+    Range,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl KeyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyType::Hash => "HASH",
+            KeyType::Range => "RANGE",
+            KeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for KeyType {
+    fn from(value: &'a str) -> KeyType {
+        match value {
+            "HASH" => KeyType::Hash,
+            "RANGE" => KeyType::Range,
+            _ => KeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyType {
+    fn from(value: String) -> KeyType {
+        KeyType::from(value.as_str())
+    }
+}
+
+impl From<KeyType> for String {
+    fn from(value: KeyType) -> String {
+        match value {
+            KeyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for KeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for KeyType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<KeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(KeyType::from(value))
+    }
 }
 
 /// <p>Represents the input of a <code>ListStreams</code> operation.</p>
@@ -202,6 +417,50 @@ pub struct ListStreamsInput {
     pub table_name: Option<String>,
 }
 
+impl ListStreamsInput {
+    /// Returns a builder for `ListStreamsInput`, checking its required members when built.
+    pub fn builder() -> ListStreamsInputBuilder {
+        ListStreamsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStreamsInput`](struct.ListStreamsInput.html).
+#[derive(Default, Debug)]
+pub struct ListStreamsInputBuilder {
+    exclusive_start_stream_arn: Option<String>,
+    limit: Option<i64>,
+    table_name: Option<String>,
+}
+
+impl ListStreamsInputBuilder {
+    /// Sets the [`exclusive_start_stream_arn`](struct.ListStreamsInput.html#structfield.exclusive_start_stream_arn) member.
+    pub fn exclusive_start_stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListStreamsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.ListStreamsInput.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStreamsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListStreamsInput, ::rusoto_core::BuildError> {
+        Ok(ListStreamsInput {
+            exclusive_start_stream_arn: self.exclusive_start_stream_arn,
+            limit: self.limit,
+            table_name: self.table_name,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>ListStreams</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -216,6 +475,82 @@ pub struct ListStreamsOutput {
     pub streams: Option<Vec<Stream>>,
 }
 
+/// <p><p>The type of data modification that was performed on the DynamoDB table:</p> <ul> <li> <p> <code>INSERT</code> - a new item was added to the table.</p> </li> <li> <p> <code>MODIFY</code> - one or more of an existing item&#39;s attributes were modified.</p> </li> <li> <p> <code>REMOVE</code> - the item was deleted from the table</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperationType {
+    /// <p><code>INSERT</code> - a new item was added to the table.</p>
+    Insert,
+    /// <p><code>MODIFY</code> - one or more of an existing item&#39;s attributes were modified.</p>
+    Modify,
+    /// <p><code>REMOVE</code> - the item was deleted from the table</p>
+    Remove,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OperationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationType::Insert => "INSERT",
+            OperationType::Modify => "MODIFY",
+            OperationType::Remove => "REMOVE",
+            OperationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperationType {
+    fn from(value: &'a str) -> OperationType {
+        match value {
+            "INSERT" => OperationType::Insert,
+            "MODIFY" => OperationType::Modify,
+            "REMOVE" => OperationType::Remove,
+            _ => OperationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationType {
+    fn from(value: String) -> OperationType {
+        OperationType::from(value.as_str())
+    }
+}
+
+impl From<OperationType> for String {
+    fn from(value: OperationType) -> String {
+        match value {
+            OperationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OperationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OperationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OperationType::from(value))
+    }
+}
+
 /// <p>A description of a unique event within a stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -235,7 +570,7 @@ pub struct Record {
     /// <p><p>The type of data modification that was performed on the DynamoDB table:</p> <ul> <li> <p> <code>INSERT</code> - a new item was added to the table.</p> </li> <li> <p> <code>MODIFY</code> - one or more of an existing item&#39;s attributes were modified.</p> </li> <li> <p> <code>REMOVE</code> - the item was deleted from the table</p> </li> </ul></p>
     #[serde(rename = "eventName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_name: Option<String>,
+    pub event_name: Option<OperationType>,
     /// <p>The AWS service from which the stream record originated. For DynamoDB Streams, this is <code>aws:dynamodb</code>.</p>
     #[serde(rename = "eventSource")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -282,6 +617,86 @@ pub struct Shard {
     pub shard_id: Option<String>,
 }
 
+/// <p><p>Determines how the shard iterator is used to start reading stream records from the shard:</p> <ul> <li> <p> <code>AT<em>SEQUENCE</em>NUMBER</code> - Start reading exactly from the position denoted by a specific sequence number.</p> </li> <li> <p> <code>AFTER<em>SEQUENCE</em>NUMBER</code> - Start reading right after the position denoted by a specific sequence number.</p> </li> <li> <p> <code>TRIM_HORIZON</code> - Start reading at the last (untrimmed) stream record, which is the oldest record in the shard. In DynamoDB Streams, there is a 24 hour limit on data retention. Stream records whose age exceeds this limit are subject to removal (trimming) from the stream.</p> </li> <li> <p> <code>LATEST</code> - Start reading just after the most recent stream record in the shard, so that you always read the most recent data in the shard.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ShardIteratorType {
+    /// <p><code>TRIM_HORIZON</code> - Start reading at the last (untrimmed) stream record, which is the oldest record in the shard. In DynamoDB Streams, there is a 24 hour limit on data retention. Stream records whose age exceeds this limit are subject to removal (trimming) from the stream.</p>
+    TrimHorizon,
+    /// <p><code>LATEST</code> - Start reading just after the most recent stream record in the shard, so that you always read the most recent data in the shard.</p>
+    Latest,
+    /// <p><code>AT_SEQUENCE_NUMBER</code></p>
+    AtSequenceNumber,
+    /// <p><code>AFTER_SEQUENCE_NUMBER</code></p>
+    AfterSequenceNumber,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ShardIteratorType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShardIteratorType::TrimHorizon => "TRIM_HORIZON",
+            ShardIteratorType::Latest => "LATEST",
+            ShardIteratorType::AtSequenceNumber => "AT_SEQUENCE_NUMBER",
+            ShardIteratorType::AfterSequenceNumber => "AFTER_SEQUENCE_NUMBER",
+            ShardIteratorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ShardIteratorType {
+    fn from(value: &'a str) -> ShardIteratorType {
+        match value {
+            "TRIM_HORIZON" => ShardIteratorType::TrimHorizon,
+            "LATEST" => ShardIteratorType::Latest,
+            "AT_SEQUENCE_NUMBER" => ShardIteratorType::AtSequenceNumber,
+            "AFTER_SEQUENCE_NUMBER" => ShardIteratorType::AfterSequenceNumber,
+            _ => ShardIteratorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShardIteratorType {
+    fn from(value: String) -> ShardIteratorType {
+        ShardIteratorType::from(value.as_str())
+    }
+}
+
+impl From<ShardIteratorType> for String {
+    fn from(value: ShardIteratorType) -> String {
+        match value {
+            ShardIteratorType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ShardIteratorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ShardIteratorType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShardIteratorType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ShardIteratorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ShardIteratorType::from(value))
+    }
+}
+
 /// <p>Represents all of the data describing a particular stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -306,8 +721,9 @@ pub struct Stream {
 pub struct StreamDescription {
     /// <p>The date and time when the request to create this stream was issued.</p>
     #[serde(rename = "CreationRequestDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_request_date_time: Option<f64>,
+    pub creation_request_date_time: Option<EpochTimestamp>,
     /// <p>The key attribute(s) of the stream's DynamoDB table.</p>
     #[serde(rename = "KeySchema")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -331,11 +747,11 @@ pub struct StreamDescription {
     /// <p><p>Indicates the current status of the stream:</p> <ul> <li> <p> <code>ENABLING</code> - Streams is currently being enabled on the DynamoDB table.</p> </li> <li> <p> <code>ENABLED</code> - the stream is enabled.</p> </li> <li> <p> <code>DISABLING</code> - Streams is currently being disabled on the DynamoDB table.</p> </li> <li> <p> <code>DISABLED</code> - the stream is disabled.</p> </li> </ul></p>
     #[serde(rename = "StreamStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_status: Option<String>,
+    pub stream_status: Option<StreamStatus>,
     /// <p><p>Indicates the format of the records within this stream:</p> <ul> <li> <p> <code>KEYS<em>ONLY</code> - only the key attributes of items that were modified in the DynamoDB table.</p> </li> <li> <p> <code>NEW</em>IMAGE</code> - entire items from the table, as they appeared after they were modified.</p> </li> <li> <p> <code>OLD<em>IMAGE</code> - entire items from the table, as they appeared before they were modified.</p> </li> <li> <p> <code>NEW</em>AND<em>OLD</em>IMAGES</code> - both the new and the old images of the items from the table.</p> </li> </ul></p>
     #[serde(rename = "StreamViewType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_view_type: Option<String>,
+    pub stream_view_type: Option<StreamViewType>,
     /// <p>The DynamoDB table with which the stream is associated.</p>
     #[serde(rename = "TableName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -348,8 +764,9 @@ pub struct StreamDescription {
 pub struct StreamRecord {
     /// <p>The approximate date and time when the stream record was created, in <a href="http://www.epochconverter.com/">UNIX epoch time</a> format.</p>
     #[serde(rename = "ApproximateCreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approximate_creation_date_time: Option<f64>,
+    pub approximate_creation_date_time: Option<EpochTimestamp>,
     /// <p>The primary key attribute(s) for the DynamoDB item that was modified.</p>
     #[serde(rename = "Keys")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -373,7 +790,167 @@ pub struct StreamRecord {
     /// <p><p>The type of data from the modified DynamoDB item that was captured in this stream record:</p> <ul> <li> <p> <code>KEYS<em>ONLY</code> - only the key attributes of the modified item.</p> </li> <li> <p> <code>NEW</em>IMAGE</code> - the entire item, as it appeared after it was modified.</p> </li> <li> <p> <code>OLD<em>IMAGE</code> - the entire item, as it appeared before it was modified.</p> </li> <li> <p> <code>NEW</em>AND<em>OLD</em>IMAGES</code> - both the new and the old item images of the item.</p> </li> </ul></p>
     #[serde(rename = "StreamViewType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_view_type: Option<String>,
+    pub stream_view_type: Option<StreamViewType>,
+}
+
+/// <p><p>Indicates the current status of the stream:</p> <ul> <li> <p> <code>ENABLING</code> - Streams is currently being enabled on the DynamoDB table.</p> </li> <li> <p> <code>ENABLED</code> - the stream is enabled.</p> </li> <li> <p> <code>DISABLING</code> - Streams is currently being disabled on the DynamoDB table.</p> </li> <li> <p> <code>DISABLED</code> - the stream is disabled.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StreamStatus {
+    /// <p><code>ENABLING</code> - Streams is currently being enabled on the DynamoDB table.</p>
+    Enabling,
+    /// <p><code>ENABLED</code> - the stream is enabled.</p>
+    Enabled,
+    /// <p><code>DISABLING</code> - Streams is currently being disabled on the DynamoDB table.</p>
+    Disabling,
+    /// <p><code>DISABLED</code> - the stream is disabled.</p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StreamStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamStatus::Enabling => "ENABLING",
+            StreamStatus::Enabled => "ENABLED",
+            StreamStatus::Disabling => "DISABLING",
+            StreamStatus::Disabled => "DISABLED",
+            StreamStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StreamStatus {
+    fn from(value: &'a str) -> StreamStatus {
+        match value {
+            "ENABLING" => StreamStatus::Enabling,
+            "ENABLED" => StreamStatus::Enabled,
+            "DISABLING" => StreamStatus::Disabling,
+            "DISABLED" => StreamStatus::Disabled,
+            _ => StreamStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamStatus {
+    fn from(value: String) -> StreamStatus {
+        StreamStatus::from(value.as_str())
+    }
+}
+
+impl From<StreamStatus> for String {
+    fn from(value: StreamStatus) -> String {
+        match value {
+            StreamStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StreamStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StreamStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StreamStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StreamStatus::from(value))
+    }
+}
+
+/// <p><p>Indicates the format of the records within this stream:</p> <ul> <li> <p> <code>KEYS<em>ONLY</code> - only the key attributes of items that were modified in the DynamoDB table.</p> </li> <li> <p> <code>NEW</em>IMAGE</code> - entire items from the table, as they appeared after they were modified.</p> </li> <li> <p> <code>OLD<em>IMAGE</code> - entire items from the table, as they appeared before they were modified.</p> </li> <li> <p> <code>NEW</em>AND<em>OLD</em>IMAGES</code> - both the new and the old images of the items from the table.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StreamViewType {
+    /// <p><code>NEW_IMAGE</code></p>
+    NewImage,
+    /// <p><code>OLD_IMAGE</code></p>
+    OldImage,
+    /// <p><code>NEW_AND_OLD_IMAGES</code></p>
+    NewAndOldImages,
+    /// <p><code>KEYS_ONLY</code></p>
+    KeysOnly,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StreamViewType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamViewType::NewImage => "NEW_IMAGE",
+            StreamViewType::OldImage => "OLD_IMAGE",
+            StreamViewType::NewAndOldImages => "NEW_AND_OLD_IMAGES",
+            StreamViewType::KeysOnly => "KEYS_ONLY",
+            StreamViewType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StreamViewType {
+    fn from(value: &'a str) -> StreamViewType {
+        match value {
+            "NEW_IMAGE" => StreamViewType::NewImage,
+            "OLD_IMAGE" => StreamViewType::OldImage,
+            "NEW_AND_OLD_IMAGES" => StreamViewType::NewAndOldImages,
+            "KEYS_ONLY" => StreamViewType::KeysOnly,
+            _ => StreamViewType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamViewType {
+    fn from(value: String) -> StreamViewType {
+        StreamViewType::from(value.as_str())
+    }
+}
+
+impl From<StreamViewType> for String {
+    fn from(value: StreamViewType) -> String {
+        match value {
+            StreamViewType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StreamViewType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StreamViewType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamViewType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StreamViewType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StreamViewType::from(value))
+    }
 }
 
 /// Errors returned by DescribeStream
@@ -584,6 +1161,39 @@ pub trait DynamoDbStreams {
         input: ListStreamsInput,
     ) -> RusotoFuture<ListStreamsOutput, ListStreamsError>;
 }
+impl<T: ?Sized + DynamoDbStreams> DynamoDbStreams for ::std::sync::Arc<T> {
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        (**self).describe_stream(input)
+    }
+    fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> RusotoFuture<GetRecordsOutput, GetRecordsError> {
+        (**self).get_records(input)
+    }
+    fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> RusotoFuture<GetShardIteratorOutput, GetShardIteratorError> {
+        (**self).get_shard_iterator(input)
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        (**self).list_streams(input)
+    }
+}
+/// The error codes Amazon DynamoDB Streams retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon DynamoDB Streams API.
 #[derive(Clone)]
 pub struct DynamoDbStreamsClient {
@@ -599,6 +1209,27 @@ impl DynamoDbStreamsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_DYNAMODBSTREAMS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> DynamoDbStreamsClient {
+        Self::new(region::Region::from_env_for_service("DYNAMODBSTREAMS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `streams.dynamodb`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> DynamoDbStreamsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("streams.dynamodb", "DYNAMODBSTREAMS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -617,7 +1248,54 @@ impl DynamoDbStreamsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbStreamsClient {
-        DynamoDbStreamsClient { client, region }
+        DynamoDbStreamsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "streams.dynamodb")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "streams.dynamodb",
+            "dynamodb",
+            "DYNAMODBSTREAMS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `DynamoDbStreams` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn DynamoDbStreams + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for DynamoDbStreamsClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon DynamoDB Streams"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "streams.dynamodb"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> DynamoDbStreamsClient {
+        Self::new_with_client(client, region)
     }
 }
 
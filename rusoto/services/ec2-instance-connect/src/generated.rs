@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SendSSHPublicKeyRequest {
@@ -39,6 +40,68 @@ pub struct SendSSHPublicKeyRequest {
     pub ssh_public_key: String,
 }
 
+impl SendSSHPublicKeyRequest {
+    /// Returns a builder for `SendSSHPublicKeyRequest`, checking its required members when built.
+    pub fn builder() -> SendSSHPublicKeyRequestBuilder {
+        SendSSHPublicKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendSSHPublicKeyRequest`](struct.SendSSHPublicKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct SendSSHPublicKeyRequestBuilder {
+    availability_zone: Option<String>,
+    instance_id: Option<String>,
+    instance_os_user: Option<String>,
+    ssh_public_key: Option<String>,
+}
+
+impl SendSSHPublicKeyRequestBuilder {
+    /// Sets the [`availability_zone`](struct.SendSSHPublicKeyRequest.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.SendSSHPublicKeyRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_os_user`](struct.SendSSHPublicKeyRequest.html#structfield.instance_os_user) member.
+    pub fn instance_os_user<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_os_user = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_public_key`](struct.SendSSHPublicKeyRequest.html#structfield.ssh_public_key) member.
+    pub fn ssh_public_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendSSHPublicKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SendSSHPublicKeyRequest, ::rusoto_core::BuildError> {
+        Ok(SendSSHPublicKeyRequest {
+            availability_zone: self
+                .availability_zone
+                .ok_or(::rusoto_core::BuildError::missing("availability_zone"))?,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            instance_os_user: self
+                .instance_os_user
+                .ok_or(::rusoto_core::BuildError::missing("instance_os_user"))?,
+            ssh_public_key: self
+                .ssh_public_key
+                .ok_or(::rusoto_core::BuildError::missing("ssh_public_key"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SendSSHPublicKeyResponse {
@@ -119,6 +182,21 @@ pub trait Ec2InstanceConnect {
         input: SendSSHPublicKeyRequest,
     ) -> RusotoFuture<SendSSHPublicKeyResponse, SendSSHPublicKeyError>;
 }
+impl<T: ?Sized + Ec2InstanceConnect> Ec2InstanceConnect for ::std::sync::Arc<T> {
+    fn send_ssh_public_key(
+        &self,
+        input: SendSSHPublicKeyRequest,
+    ) -> RusotoFuture<SendSSHPublicKeyResponse, SendSSHPublicKeyError> {
+        (**self).send_ssh_public_key(input)
+    }
+}
+/// The error codes EC2 Instance Connect retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the EC2 Instance Connect API.
 #[derive(Clone)]
 pub struct Ec2InstanceConnectClient {
@@ -134,6 +212,27 @@ impl Ec2InstanceConnectClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_EC2_INSTANCE_CONNECT`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> Ec2InstanceConnectClient {
+        Self::new(region::Region::from_env_for_service("EC2_INSTANCE_CONNECT"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `ec2-instance-connect`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> Ec2InstanceConnectClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("ec2-instance-connect", "EC2_INSTANCE_CONNECT"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -152,7 +251,54 @@ impl Ec2InstanceConnectClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> Ec2InstanceConnectClient {
-        Ec2InstanceConnectClient { client, region }
+        Ec2InstanceConnectClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "ec2-instance-connect")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "ec2-instance-connect",
+            "ec2-instance-connect",
+            "EC2_INSTANCE_CONNECT",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Ec2InstanceConnect` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Ec2InstanceConnect + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for Ec2InstanceConnectClient {
+    fn service_name(&self) -> &'static str {
+        "EC2 Instance Connect"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "ec2-instance-connect"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> Ec2InstanceConnectClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Elastic Compute Cloud clients.
+//!
+//! Makes a single read-only `DescribeAccountAttributes` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_ec2::{DescribeAccountAttributesRequest, Ec2, Ec2Client};
+
+fn main() {
+    let client = Ec2Client::from_env();
+    match client
+        .describe_account_attributes(DescribeAccountAttributesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeAccountAttributes succeeded"),
+        Err(error) => {
+            eprintln!("DescribeAccountAttributes failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
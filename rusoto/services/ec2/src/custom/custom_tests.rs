@@ -0,0 +1,148 @@
+extern crate rusoto_mock;
+
+use std::sync::{Arc, Mutex};
+
+use crate::generated::{CreateNatGatewayRequest, Ec2, Ec2Client};
+use crate::security_groups::{
+    RuleDirection, RuleSource, SecurityGroupRule, SecurityGroupRuleDiff, SecurityGroupRulesExt,
+};
+
+use self::rusoto_mock::*;
+use rusoto_core::param::Params;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::Region;
+
+/// The `Action` parameter of an EC2 request.
+fn action(request: &SignedRequest) -> String {
+    let payload = match request.payload {
+        Some(SignedRequestPayload::Buffer(ref buffer)) => String::from_utf8_lossy(buffer),
+        _ => panic!("request payload is not a buffer"),
+    };
+    payload
+        .split('&')
+        .find(|param| param.starts_with("Action="))
+        .map(|param| param["Action=".len()..].to_owned())
+        .unwrap()
+}
+
+fn client_recording_actions() -> (Ec2Client, Arc<Mutex<Vec<String>>>) {
+    let actions = Arc::new(Mutex::new(Vec::new()));
+    let recorded = actions.clone();
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(&MockResponseReader::read_response(
+            "test_resources/custom",
+            "ec2-describe-security-group.xml",
+        ))
+        .with_request_checker(move |request: &SignedRequest| {
+            recorded.lock().unwrap().push(action(request));
+        });
+    let client = Ec2Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    (client, actions)
+}
+
+fn desired_rules() -> Vec<SecurityGroupRule> {
+    vec![
+        SecurityGroupRule::all_traffic(
+            RuleDirection::Ingress,
+            RuleSource::SecurityGroup("sg-97b1aefb".to_owned()),
+        ),
+        SecurityGroupRule::tcp(
+            RuleDirection::Egress,
+            443,
+            443,
+            RuleSource::Cidr("0.0.0.0/0".to_owned()),
+        ),
+    ]
+}
+
+fn expected_diff() -> SecurityGroupRuleDiff {
+    SecurityGroupRuleDiff {
+        to_authorize: vec![SecurityGroupRule::tcp(
+            RuleDirection::Egress,
+            443,
+            443,
+            RuleSource::Cidr("0.0.0.0/0".to_owned()),
+        )],
+        to_revoke: vec![SecurityGroupRule::all_traffic(
+            RuleDirection::Egress,
+            RuleSource::Cidr("0.0.0.0/0".to_owned()),
+        )],
+    }
+}
+
+#[test]
+fn should_only_describe_security_group_on_dry_run() {
+    let (client, actions) = client_recording_actions();
+    let diff = client
+        .reconcile_security_group_rules("sg-97b1aefb", desired_rules(), true)
+        .sync()
+        .unwrap();
+
+    assert_eq!(diff, expected_diff());
+    assert_eq!(*actions.lock().unwrap(), vec!["DescribeSecurityGroups"]);
+}
+
+#[test]
+fn should_authorize_before_revoking() {
+    let (client, actions) = client_recording_actions();
+    let diff = client
+        .reconcile_security_group_rules("sg-97b1aefb", desired_rules(), false)
+        .sync()
+        .unwrap();
+
+    assert_eq!(diff, expected_diff());
+    assert_eq!(
+        *actions.lock().unwrap(),
+        vec![
+            "DescribeSecurityGroups",
+            "AuthorizeSecurityGroupEgress",
+            "RevokeSecurityGroupEgress"
+        ]
+    );
+}
+
+/// Sends a `CreateNatGateway` request and returns its `ClientToken` parameter.
+fn sent_client_token(client_token: Option<String>) -> Option<String> {
+    let token = Arc::new(Mutex::new(None));
+    let recorded = token.clone();
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body("")
+        .with_request_checker(move |request: &SignedRequest| {
+            let params: Params = match request.payload {
+                Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                    serde_urlencoded::from_bytes(buffer).unwrap()
+                }
+                _ => panic!("request payload is not a buffer"),
+            };
+            *recorded.lock().unwrap() = params.get("ClientToken").cloned().unwrap_or(None);
+        });
+    let client = Ec2Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let _ = client
+        .create_nat_gateway(CreateNatGatewayRequest {
+            allocation_id: "eipalloc-37fc1a52".to_owned(),
+            client_token,
+            subnet_id: "subnet-1a2b3c4d".to_owned(),
+        })
+        .sync();
+
+    let sent = token.lock().unwrap().take();
+    sent
+}
+
+#[test]
+fn generates_missing_idempotency_tokens() {
+    let first = sent_client_token(None).expect("no client token sent");
+    let second = sent_client_token(None).expect("no client token sent");
+
+    assert_eq!(first.len(), 36);
+    assert_ne!(first, second);
+}
+
+#[test]
+fn keeps_given_idempotency_tokens() {
+    assert_eq!(
+        sent_client_token(Some("my-token".to_owned())),
+        Some("my-token".to_owned())
+    );
+}
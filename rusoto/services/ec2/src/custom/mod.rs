@@ -1 +1,7 @@
+/// Reconciling security group rules with a desired rule set
+pub mod security_groups;
+/// Waiting for Site-to-Site VPN connections and their tunnels to come up
+pub mod vpn;
 
+#[cfg(test)]
+mod custom_tests;
@@ -0,0 +1,591 @@
+//! Reconciling the rules of a security group with a desired rule set.
+//!
+//! `reconcile_security_group_rules` describes a security group, compares its rules with the
+//! desired ones and authorizes the missing rules before revoking the extra ones, so that traffic
+//! allowed by both rule sets isn't interrupted. Every rule is authorized or revoked on its own,
+//! and rules which were added or removed concurrently are skipped instead of failing the whole
+//! reconciliation, so it can safely be run repeatedly.
+//!
+//! Rules are compared by direction, protocol, port range and source; changes to descriptions
+//! alone aren't applied. A security group referenced as source is identified by its ID, without
+//! its owner.
+
+use std::error::Error;
+use std::fmt;
+
+use futures::future::{self, Loop};
+use futures::Future;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    AuthorizeSecurityGroupEgressError, AuthorizeSecurityGroupEgressRequest,
+    AuthorizeSecurityGroupIngressError, AuthorizeSecurityGroupIngressRequest,
+    DescribeSecurityGroupsError, DescribeSecurityGroupsRequest, Ec2, IpPermission, IpRange,
+    Ipv6Range, PrefixListId, RevokeSecurityGroupEgressError, RevokeSecurityGroupEgressRequest,
+    RevokeSecurityGroupIngressError, RevokeSecurityGroupIngressRequest, SecurityGroup,
+    UserIdGroupPair,
+};
+
+/// The traffic a rule applies to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RuleDirection {
+    /// Inbound traffic
+    Ingress,
+    /// Outbound traffic
+    Egress,
+}
+
+/// Where inbound traffic comes from, or outbound traffic goes to.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RuleSource {
+    /// An IPv4 CIDR block
+    Cidr(String),
+    /// An IPv6 CIDR block
+    Ipv6Cidr(String),
+    /// The ID of a prefix list
+    PrefixList(String),
+    /// The ID of a security group
+    SecurityGroup(String),
+}
+
+/// A single security group rule.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SecurityGroupRule {
+    /// Inbound or outbound
+    pub direction: RuleDirection,
+    /// `tcp`, `udp`, `icmp`, `icmpv6`, a protocol number, or `-1` for all protocols
+    pub protocol: String,
+    /// The start of the port range, or the ICMP type
+    pub from_port: Option<i64>,
+    /// The end of the port range, or the ICMP code
+    pub to_port: Option<i64>,
+    /// The peer of the traffic
+    pub source: RuleSource,
+    /// A description, only sent when the rule is authorized
+    pub description: Option<String>,
+}
+
+impl SecurityGroupRule {
+    /// A rule for all traffic from or to `source`.
+    pub fn all_traffic(direction: RuleDirection, source: RuleSource) -> SecurityGroupRule {
+        SecurityGroupRule {
+            direction,
+            protocol: "-1".to_owned(),
+            from_port: None,
+            to_port: None,
+            source,
+            description: None,
+        }
+    }
+
+    /// A rule for a TCP port range.
+    pub fn tcp(
+        direction: RuleDirection,
+        from_port: i64,
+        to_port: i64,
+        source: RuleSource,
+    ) -> SecurityGroupRule {
+        SecurityGroupRule {
+            direction,
+            protocol: "tcp".to_owned(),
+            from_port: Some(from_port),
+            to_port: Some(to_port),
+            source,
+            description: None,
+        }
+    }
+
+    /// Sets the description of the rule.
+    pub fn with_description(mut self, description: &str) -> SecurityGroupRule {
+        self.description = Some(description.to_owned());
+        self
+    }
+
+    /// What identifies the rule, in the form EC2 reports it.
+    fn key(&self) -> (RuleDirection, String, Option<i64>, Option<i64>, RuleSource) {
+        let protocol = match self.protocol.to_ascii_lowercase().as_str() {
+            "all" => "-1".to_owned(),
+            "1" => "icmp".to_owned(),
+            "6" => "tcp".to_owned(),
+            "17" => "udp".to_owned(),
+            "58" => "icmpv6".to_owned(),
+            other => other.to_owned(),
+        };
+        // ports don't apply to rules for all protocols
+        let (from_port, to_port) = if protocol == "-1" {
+            (None, None)
+        } else {
+            (self.from_port, self.to_port)
+        };
+        (
+            self.direction,
+            protocol,
+            from_port,
+            to_port,
+            self.source.clone(),
+        )
+    }
+
+    /// The permission to authorize or revoke this rule with.
+    pub fn to_ip_permission(&self) -> IpPermission {
+        let mut permission = IpPermission {
+            ip_protocol: Some(self.protocol.clone()),
+            from_port: self.from_port,
+            to_port: self.to_port,
+            ..Default::default()
+        };
+        let description = self.description.clone();
+        match self.source {
+            RuleSource::Cidr(ref cidr) => {
+                permission.ip_ranges = Some(vec![IpRange {
+                    cidr_ip: Some(cidr.clone()),
+                    description,
+                }])
+            }
+            RuleSource::Ipv6Cidr(ref cidr) => {
+                permission.ipv_6_ranges = Some(vec![Ipv6Range {
+                    cidr_ipv_6: Some(cidr.clone()),
+                    description,
+                }])
+            }
+            RuleSource::PrefixList(ref id) => {
+                permission.prefix_list_ids = Some(vec![PrefixListId {
+                    prefix_list_id: Some(id.clone()),
+                    description,
+                }])
+            }
+            RuleSource::SecurityGroup(ref id) => {
+                permission.user_id_group_pairs = Some(vec![UserIdGroupPair {
+                    group_id: Some(id.clone()),
+                    description,
+                    ..Default::default()
+                }])
+            }
+        }
+        permission
+    }
+}
+
+impl fmt::Display for SecurityGroupRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let direction = match self.direction {
+            RuleDirection::Ingress => "ingress",
+            RuleDirection::Egress => "egress",
+        };
+        let protocol = if self.protocol == "-1" {
+            "all"
+        } else {
+            &self.protocol
+        };
+        write!(f, "{} {}", direction, protocol)?;
+        match (self.from_port, self.to_port) {
+            (Some(from), Some(to)) if from == to => write!(f, " {}", from)?,
+            (Some(from), Some(to)) => write!(f, " {}-{}", from, to)?,
+            _ => {}
+        }
+        let peer = match self.direction {
+            RuleDirection::Ingress => "from",
+            RuleDirection::Egress => "to",
+        };
+        match self.source {
+            RuleSource::Cidr(ref source)
+            | RuleSource::Ipv6Cidr(ref source)
+            | RuleSource::PrefixList(ref source)
+            | RuleSource::SecurityGroup(ref source) => write!(f, " {} {}", peer, source),
+        }
+    }
+}
+
+/// Flattens the permissions of a security group into one rule per source.
+pub fn security_group_rules(group: &SecurityGroup) -> Vec<SecurityGroupRule> {
+    let mut rules = Vec::new();
+    let directions = [
+        (RuleDirection::Ingress, &group.ip_permissions),
+        (RuleDirection::Egress, &group.ip_permissions_egress),
+    ];
+    for &(direction, permissions) in &directions {
+        for permission in permissions.iter().flatten() {
+            let rule = |source, description: &Option<String>| SecurityGroupRule {
+                direction,
+                protocol: permission.ip_protocol.clone().unwrap_or_default(),
+                from_port: permission.from_port,
+                to_port: permission.to_port,
+                source,
+                description: description.clone(),
+            };
+            for range in permission.ip_ranges.iter().flatten() {
+                if let Some(ref cidr) = range.cidr_ip {
+                    rules.push(rule(RuleSource::Cidr(cidr.clone()), &range.description));
+                }
+            }
+            for range in permission.ipv_6_ranges.iter().flatten() {
+                if let Some(ref cidr) = range.cidr_ipv_6 {
+                    rules.push(rule(RuleSource::Ipv6Cidr(cidr.clone()), &range.description));
+                }
+            }
+            for prefix_list in permission.prefix_list_ids.iter().flatten() {
+                if let Some(ref id) = prefix_list.prefix_list_id {
+                    rules.push(rule(
+                        RuleSource::PrefixList(id.clone()),
+                        &prefix_list.description,
+                    ));
+                }
+            }
+            for pair in permission.user_id_group_pairs.iter().flatten() {
+                if let Some(ref id) = pair.group_id {
+                    rules.push(rule(
+                        RuleSource::SecurityGroup(id.clone()),
+                        &pair.description,
+                    ));
+                }
+            }
+        }
+    }
+    rules
+}
+
+/// The changes turning one rule set into another.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SecurityGroupRuleDiff {
+    /// Desired rules missing from the security group
+    pub to_authorize: Vec<SecurityGroupRule>,
+    /// Rules of the security group which aren't desired
+    pub to_revoke: Vec<SecurityGroupRule>,
+}
+
+impl SecurityGroupRuleDiff {
+    /// Compares the `current` rules of a security group with the `desired` ones.
+    pub fn new(
+        current: &[SecurityGroupRule],
+        desired: &[SecurityGroupRule],
+    ) -> SecurityGroupRuleDiff {
+        let missing = |rules: &[SecurityGroupRule], rule: &SecurityGroupRule| {
+            !rules.iter().any(|other| other.key() == rule.key())
+        };
+        let mut diff = SecurityGroupRuleDiff::default();
+        for rule in desired {
+            if missing(current, rule) && missing(&diff.to_authorize, rule) {
+                diff.to_authorize.push(rule.clone());
+            }
+        }
+        for rule in current {
+            if missing(desired, rule) && missing(&diff.to_revoke, rule) {
+                diff.to_revoke.push(rule.clone());
+            }
+        }
+        diff
+    }
+
+    /// Whether the rule sets are the same.
+    pub fn is_empty(&self) -> bool {
+        self.to_authorize.is_empty() && self.to_revoke.is_empty()
+    }
+}
+
+/// Lists the changes, one per line, prefixed with `+` for rules to authorize and `-` for
+/// rules to revoke.
+impl fmt::Display for SecurityGroupRuleDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for rule in &self.to_authorize {
+            writeln!(f, "+ {}", rule)?;
+        }
+        for rule in &self.to_revoke {
+            writeln!(f, "- {}", rule)?;
+        }
+        Ok(())
+    }
+}
+
+/// Errors returned by `reconcile_security_group_rules`.
+#[derive(Debug, PartialEq)]
+pub enum ReconcileError {
+    /// The security group doesn't exist.
+    GroupNotFound(String),
+    /// Describing the security group failed.
+    DescribeSecurityGroups(DescribeSecurityGroupsError),
+    /// Authorizing an inbound rule failed.
+    AuthorizeIngress(AuthorizeSecurityGroupIngressError),
+    /// Authorizing an outbound rule failed.
+    AuthorizeEgress(AuthorizeSecurityGroupEgressError),
+    /// Revoking an inbound rule failed.
+    RevokeIngress(RevokeSecurityGroupIngressError),
+    /// Revoking an outbound rule failed.
+    RevokeEgress(RevokeSecurityGroupEgressError),
+}
+
+impl fmt::Display for ReconcileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ReconcileError::GroupNotFound(ref group_id) => {
+                write!(f, "{}: {}", self.description(), group_id)
+            }
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for ReconcileError {
+    fn description(&self) -> &str {
+        match *self {
+            ReconcileError::GroupNotFound(_) => "Security group not found",
+            ReconcileError::DescribeSecurityGroups(ref cause) => cause.description(),
+            ReconcileError::AuthorizeIngress(ref cause) => cause.description(),
+            ReconcileError::AuthorizeEgress(ref cause) => cause.description(),
+            ReconcileError::RevokeIngress(ref cause) => cause.description(),
+            ReconcileError::RevokeEgress(ref cause) => cause.description(),
+        }
+    }
+}
+
+/// Whether a failed call returned the given EC2 error code.
+fn has_error_code<E>(error: &RusotoError<E>, code: &str) -> bool {
+    match *error {
+        RusotoError::Unknown(ref response) => {
+            String::from_utf8_lossy(&response.body).contains(&format!("<Code>{}</Code>", code))
+        }
+        _ => false,
+    }
+}
+
+/// Ignores the error of a call whose change was already made by someone else.
+fn already_applied<E>(
+    result: Result<(), RusotoError<E>>,
+    code: &str,
+) -> Result<(), RusotoError<E>> {
+    match result {
+        Err(ref error) if has_error_code(error, code) => Ok(()),
+        result => result,
+    }
+}
+
+type ApplyFuture = Box<dyn Future<Item = (), Error = RusotoError<ReconcileError>> + Send>;
+
+/// Authorizes (`authorize` is true) or revokes a single rule.
+fn apply_rule<C: Ec2>(
+    client: &C,
+    group_id: &str,
+    rule: &SecurityGroupRule,
+    authorize: bool,
+) -> ApplyFuture {
+    let ip_permissions = Some(vec![rule.to_ip_permission()]);
+    let group_id = group_id.to_owned();
+    match (rule.direction, authorize) {
+        (RuleDirection::Ingress, true) => Box::new(
+            client
+                .authorize_security_group_ingress(AuthorizeSecurityGroupIngressRequest {
+                    group_id: Some(group_id),
+                    ip_permissions,
+                    ..Default::default()
+                })
+                .then(|result| already_applied(result, "InvalidPermission.Duplicate"))
+                .map_err(|e| e.map_service(ReconcileError::AuthorizeIngress)),
+        ),
+        (RuleDirection::Egress, true) => Box::new(
+            client
+                .authorize_security_group_egress(AuthorizeSecurityGroupEgressRequest {
+                    group_id,
+                    ip_permissions,
+                    ..Default::default()
+                })
+                .then(|result| already_applied(result, "InvalidPermission.Duplicate"))
+                .map_err(|e| e.map_service(ReconcileError::AuthorizeEgress)),
+        ),
+        (RuleDirection::Ingress, false) => Box::new(
+            client
+                .revoke_security_group_ingress(RevokeSecurityGroupIngressRequest {
+                    group_id: Some(group_id),
+                    ip_permissions,
+                    ..Default::default()
+                })
+                .then(|result| already_applied(result, "InvalidPermission.NotFound"))
+                .map_err(|e| e.map_service(ReconcileError::RevokeIngress)),
+        ),
+        (RuleDirection::Egress, false) => Box::new(
+            client
+                .revoke_security_group_egress(RevokeSecurityGroupEgressRequest {
+                    group_id,
+                    ip_permissions,
+                    ..Default::default()
+                })
+                .then(|result| already_applied(result, "InvalidPermission.NotFound"))
+                .map_err(|e| e.map_service(ReconcileError::RevokeEgress)),
+        ),
+    }
+}
+
+/// Extension methods for managing security group rules.
+pub trait SecurityGroupRulesExt: Ec2 + Clone + Send + Sized + 'static {
+    /// Makes the rules of a security group match `desired`, returning the changes.
+    ///
+    /// With `dry_run`, the changes are computed but not applied.
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_ec2::security_groups::{
+    ///     RuleDirection, RuleSource, SecurityGroupRule, SecurityGroupRulesExt,
+    /// };
+    /// use rusoto_ec2::Ec2Client;
+    ///
+    /// let client = Ec2Client::new(Region::UsEast1);
+    /// let desired = vec![
+    ///     SecurityGroupRule::tcp(
+    ///         RuleDirection::Ingress,
+    ///         443,
+    ///         443,
+    ///         RuleSource::Cidr("0.0.0.0/0".to_owned()),
+    ///     ),
+    ///     SecurityGroupRule::all_traffic(
+    ///         RuleDirection::Egress,
+    ///         RuleSource::Cidr("0.0.0.0/0".to_owned()),
+    ///     ),
+    /// ];
+    /// let diff = client
+    ///     .reconcile_security_group_rules("sg-0123456789abcdef0", desired, true)
+    ///     .sync()
+    ///     .unwrap();
+    /// print!("{}", diff);
+    /// ```
+    fn reconcile_security_group_rules(
+        &self,
+        group_id: &str,
+        desired: Vec<SecurityGroupRule>,
+        dry_run: bool,
+    ) -> RusotoFuture<SecurityGroupRuleDiff, ReconcileError> {
+        let client = self.clone();
+        let group_id = group_id.to_owned();
+
+        let future = self
+            .describe_security_groups(DescribeSecurityGroupsRequest {
+                group_ids: Some(vec![group_id.clone()]),
+                ..Default::default()
+            })
+            .map_err(|e| e.map_service(ReconcileError::DescribeSecurityGroups))
+            .and_then(move |result| {
+                let group = result
+                    .security_groups
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|group| group.group_id.as_ref() == Some(&group_id))
+                    .ok_or_else(|| {
+                        RusotoError::Service(ReconcileError::GroupNotFound(group_id.clone()))
+                    })?;
+                let diff = SecurityGroupRuleDiff::new(&security_group_rules(&group), &desired);
+                Ok((group_id, diff))
+            })
+            .and_then(move |(group_id, diff)| {
+                let mut changes = Vec::new();
+                if !dry_run {
+                    // authorize first, so that traffic allowed by both rule sets keeps flowing
+                    changes.extend(diff.to_authorize.iter().map(|rule| (rule.clone(), true)));
+                    changes.extend(diff.to_revoke.iter().map(|rule| (rule.clone(), false)));
+                }
+                future::loop_fn(changes.into_iter(), move |mut changes| {
+                    match changes.next() {
+                        Some((rule, authorize)) => future::Either::A(
+                            apply_rule(&client, &group_id, &rule, authorize)
+                                .map(|()| Loop::Continue(changes)),
+                        ),
+                        None => future::Either::B(future::ok(Loop::Break(()))),
+                    }
+                })
+                .map(move |()| diff)
+            });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: Ec2 + Clone + Send + 'static> SecurityGroupRulesExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn https_from(cidr: &str) -> SecurityGroupRule {
+        SecurityGroupRule::tcp(
+            RuleDirection::Ingress,
+            443,
+            443,
+            RuleSource::Cidr(cidr.to_owned()),
+        )
+    }
+
+    #[test]
+    fn flattens_permissions_into_rules() {
+        let group = SecurityGroup {
+            ip_permissions: Some(vec![IpPermission {
+                ip_protocol: Some("tcp".to_owned()),
+                from_port: Some(443),
+                to_port: Some(443),
+                ip_ranges: Some(vec![
+                    IpRange {
+                        cidr_ip: Some("10.0.0.0/8".to_owned()),
+                        description: None,
+                    },
+                    IpRange {
+                        cidr_ip: Some("192.168.0.0/16".to_owned()),
+                        description: Some("office".to_owned()),
+                    },
+                ]),
+                ..Default::default()
+            }]),
+            ip_permissions_egress: Some(vec![IpPermission {
+                ip_protocol: Some("-1".to_owned()),
+                user_id_group_pairs: Some(vec![UserIdGroupPair {
+                    group_id: Some("sg-1".to_owned()),
+                    user_id: Some("123456789012".to_owned()),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            security_group_rules(&group),
+            vec![
+                https_from("10.0.0.0/8"),
+                https_from("192.168.0.0/16").with_description("office"),
+                SecurityGroupRule::all_traffic(
+                    RuleDirection::Egress,
+                    RuleSource::SecurityGroup("sg-1".to_owned())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn diffs_rules_ignoring_descriptions_and_protocol_spelling() {
+        let current = vec![
+            https_from("10.0.0.0/8").with_description("old"),
+            https_from("192.168.0.0/16"),
+        ];
+        let mut numbered = https_from("172.16.0.0/12");
+        numbered.protocol = "6".to_owned();
+        let desired = vec![
+            https_from("10.0.0.0/8").with_description("new"),
+            numbered.clone(),
+            numbered.clone(),
+        ];
+
+        let diff = SecurityGroupRuleDiff::new(&current, &desired);
+        assert_eq!(diff.to_authorize, vec![numbered]);
+        assert_eq!(diff.to_revoke, vec![https_from("192.168.0.0/16")]);
+        assert!(SecurityGroupRuleDiff::new(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn formats_diffs_for_dry_runs() {
+        let diff = SecurityGroupRuleDiff {
+            to_authorize: vec![https_from("10.0.0.0/8")],
+            to_revoke: vec![SecurityGroupRule::all_traffic(
+                RuleDirection::Egress,
+                RuleSource::Cidr("0.0.0.0/0".to_owned()),
+            )],
+        };
+        assert_eq!(
+            diff.to_string(),
+            "+ ingress tcp 443 from 10.0.0.0/8\n- egress all to 0.0.0.0/0\n"
+        );
+    }
+}
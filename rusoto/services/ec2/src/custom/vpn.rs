@@ -0,0 +1,424 @@
+//! Waiting for Site-to-Site VPN connections and their tunnels to come up.
+//!
+//! A new VPN connection is `pending` for several minutes, and its two tunnels only come up once
+//! the customer gateway has established IPsec (and BGP, for dynamic routing) with them. The
+//! methods of `VpnWaiterExt` poll `DescribeVpnConnections` until a connection reaches a state or
+//! enough of its tunnels are up, and `tunnel_transitions` streams the changes of the status of
+//! its tunnels for monitoring.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use futures::{future, stream, Future, Stream};
+use rusoto_core::waiter::{poll_until, sleep, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    DescribeVpnConnectionsError, DescribeVpnConnectionsRequest, Ec2, TelemetryStatus, VgwTelemetry,
+    VpnConnection, VpnState,
+};
+
+/// The changes of the status of a VPN connection's tunnels.
+pub type TunnelTransitionStream =
+    Box<dyn Stream<Item = TunnelTransition, Error = RusotoError<VpnWaitError>> + Send>;
+
+/// Errors returned while waiting for a VPN connection.
+#[derive(Debug, PartialEq)]
+pub enum VpnWaitError {
+    /// Describing the VPN connection failed.
+    DescribeVpnConnections(DescribeVpnConnectionsError),
+    /// The VPN connection doesn't exist.
+    NotFound(String),
+    /// The VPN connection is being deleted, or was deleted.
+    UnreachableState(String),
+    /// The desired state wasn't reached within the configured number of attempts.
+    TimedOut(String),
+}
+
+impl fmt::Display for VpnWaitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for VpnWaitError {
+    fn description(&self) -> &str {
+        match *self {
+            VpnWaitError::DescribeVpnConnections(ref cause) => cause.description(),
+            VpnWaitError::NotFound(ref cause) => cause,
+            VpnWaitError::UnreachableState(ref cause) => cause,
+            VpnWaitError::TimedOut(ref cause) => cause,
+        }
+    }
+}
+
+/// A change of the status of a tunnel of a VPN connection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TunnelTransition {
+    /// The ID of the VPN connection
+    pub vpn_connection_id: String,
+    /// The outside IP address of the tunnel's AWS endpoint, which identifies the tunnel
+    pub outside_ip_address: String,
+    /// The previous status, or `None` when the tunnel is first seen
+    pub from: Option<TelemetryStatus>,
+    /// The new status
+    pub to: TelemetryStatus,
+    /// Why the tunnel is down, if AWS knows
+    pub status_message: Option<String>,
+}
+
+impl TunnelTransition {
+    /// Whether the tunnel came up.
+    pub fn is_up(&self) -> bool {
+        self.to == TelemetryStatus::Up
+    }
+
+    /// Whether the tunnel went down, after having been up.
+    pub fn went_down(&self) -> bool {
+        self.from == Some(TelemetryStatus::Up) && !self.is_up()
+    }
+}
+
+/// Whether a VPN connection in state `current` can still reach state `target`.
+pub fn vpn_state_reachable(current: &VpnState, target: &VpnState) -> bool {
+    match *current {
+        _ if current == target => true,
+        VpnState::Deleted => false,
+        VpnState::Deleting => *target == VpnState::Deleted,
+        _ => true,
+    }
+}
+
+fn tunnel_status(tunnel: &VgwTelemetry) -> TelemetryStatus {
+    tunnel
+        .status
+        .clone()
+        .unwrap_or_else(|| TelemetryStatus::Unknown(String::new()))
+}
+
+/// The number of tunnels of the VPN connection which are up.
+pub fn tunnels_up(vpn_connection: &VpnConnection) -> usize {
+    vpn_connection
+        .vgw_telemetry
+        .iter()
+        .flatten()
+        .filter(|tunnel| tunnel_status(tunnel) == TelemetryStatus::Up)
+        .count()
+}
+
+/// Compares the status of the tunnels of `vpn_connection` with `previous`, their status when last
+/// polled, returning the changes and their status now.
+fn tunnel_transitions_since(
+    previous: &HashMap<String, TelemetryStatus>,
+    vpn_connection: &VpnConnection,
+) -> (Vec<TunnelTransition>, HashMap<String, TelemetryStatus>) {
+    let vpn_connection_id = vpn_connection.vpn_connection_id.clone().unwrap_or_default();
+    let mut transitions = Vec::new();
+    let mut current = HashMap::new();
+    for tunnel in vpn_connection.vgw_telemetry.iter().flatten() {
+        let outside_ip_address = match tunnel.outside_ip_address {
+            Some(ref outside_ip_address) => outside_ip_address.clone(),
+            None => continue,
+        };
+        let status = tunnel_status(tunnel);
+        let from = previous.get(&outside_ip_address).cloned();
+        if from.as_ref() != Some(&status) {
+            transitions.push(TunnelTransition {
+                vpn_connection_id: vpn_connection_id.clone(),
+                outside_ip_address: outside_ip_address.clone(),
+                from,
+                to: status.clone(),
+                status_message: tunnel
+                    .status_message
+                    .clone()
+                    .filter(|message| !message.is_empty()),
+            });
+        }
+        current.insert(outside_ip_address, status);
+    }
+    (transitions, current)
+}
+
+/// Extension methods which wait for Site-to-Site VPN connections.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::waiter::WaiterConfig;
+/// use rusoto_core::Region;
+/// use rusoto_ec2::vpn::VpnWaiterExt;
+/// use rusoto_ec2::{Ec2Client, VpnState};
+///
+/// let client = Ec2Client::new(Region::UsEast1);
+/// client
+///     .wait_for_vpn_connection_state("vpn-40f41529", VpnState::Available, WaiterConfig::default())
+///     .sync()
+///     .unwrap();
+/// // both tunnels, for redundancy
+/// client
+///     .wait_for_tunnels_up("vpn-40f41529", 2, WaiterConfig::default())
+///     .sync()
+///     .unwrap();
+/// ```
+pub trait VpnWaiterExt: Ec2 + Clone + Send + Sized + 'static {
+    /// Polls `DescribeVpnConnections` until the VPN connection is in state `target`, and returns
+    /// it.
+    ///
+    /// Fails with `VpnWaitError::UnreachableState` as soon as the connection is being deleted,
+    /// unless waiting for `deleted`.
+    fn wait_for_vpn_connection_state(
+        &self,
+        vpn_connection_id: &str,
+        target: VpnState,
+        config: WaiterConfig,
+    ) -> RusotoFuture<VpnConnection, VpnWaitError> {
+        let client = self.clone();
+        let vpn_connection_id = vpn_connection_id.to_owned();
+        let timeout = RusotoError::Service(VpnWaitError::TimedOut(format!(
+            "VPN connection {} did not become {}",
+            vpn_connection_id, target
+        )));
+
+        let future = poll_until(
+            config,
+            move || {
+                let target = target.clone();
+                describe_vpn_connection(&client, &vpn_connection_id).and_then(
+                    move |vpn_connection| {
+                        let state = vpn_state(&vpn_connection);
+                        if state == target {
+                            Ok(Some(vpn_connection))
+                        } else if vpn_state_reachable(&state, &target) {
+                            Ok(None)
+                        } else {
+                            Err(unreachable(&vpn_connection, &state, &target))
+                        }
+                    },
+                )
+            },
+            timeout,
+        );
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Polls `DescribeVpnConnections` until at least `min_tunnels_up` tunnels of the VPN
+    /// connection are up, and returns it.
+    ///
+    /// Fails with `VpnWaitError::UnreachableState` as soon as the connection is being deleted.
+    fn wait_for_tunnels_up(
+        &self,
+        vpn_connection_id: &str,
+        min_tunnels_up: usize,
+        config: WaiterConfig,
+    ) -> RusotoFuture<VpnConnection, VpnWaitError> {
+        let client = self.clone();
+        let vpn_connection_id = vpn_connection_id.to_owned();
+        let timeout = RusotoError::Service(VpnWaitError::TimedOut(format!(
+            "Fewer than {} tunnels of VPN connection {} came up",
+            min_tunnels_up, vpn_connection_id
+        )));
+
+        let future = poll_until(
+            config,
+            move || {
+                describe_vpn_connection(&client, &vpn_connection_id).and_then(
+                    move |vpn_connection| {
+                        let state = vpn_state(&vpn_connection);
+                        if !vpn_state_reachable(&state, &VpnState::Available) {
+                            Err(unreachable(&vpn_connection, &state, &VpnState::Available))
+                        } else if tunnels_up(&vpn_connection) >= min_tunnels_up {
+                            Ok(Some(vpn_connection))
+                        } else {
+                            Ok(None)
+                        }
+                    },
+                )
+            },
+            timeout,
+        );
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Polls `DescribeVpnConnections` every `interval`, streaming every change of the status of
+    /// the VPN connection's tunnels, starting with their status when first polled.
+    ///
+    /// AWS updates the status of tunnels every few minutes, so intervals under a minute don't
+    /// notice changes sooner. The stream ends once the VPN connection is deleted.
+    fn tunnel_transitions(
+        &self,
+        vpn_connection_id: &str,
+        interval: Duration,
+    ) -> TunnelTransitionStream {
+        let client = self.clone();
+        let vpn_connection_id = vpn_connection_id.to_owned();
+        let initial = Some((HashMap::new(), true));
+        let batches = stream::unfold(initial, move |state| {
+            let (previous, first) = state?;
+            let delay: Box<dyn Future<Item = (), Error = RusotoError<VpnWaitError>> + Send> =
+                if first {
+                    Box::new(future::ok(()))
+                } else {
+                    Box::new(sleep(interval))
+                };
+            let client = client.clone();
+            let vpn_connection_id = vpn_connection_id.clone();
+            Some(delay.and_then(move |_| {
+                describe_vpn_connection(&client, &vpn_connection_id).map(move |vpn_connection| {
+                    let (transitions, current) =
+                        tunnel_transitions_since(&previous, &vpn_connection);
+                    let next = match vpn_state(&vpn_connection) {
+                        VpnState::Deleted => None,
+                        _ => Some((current, false)),
+                    };
+                    (transitions, next)
+                })
+            }))
+        });
+        Box::new(batches.map(stream::iter_ok).flatten())
+    }
+}
+
+impl<T: Ec2 + Clone + Send + 'static> VpnWaiterExt for T {}
+
+fn vpn_state(vpn_connection: &VpnConnection) -> VpnState {
+    vpn_connection
+        .state
+        .clone()
+        .unwrap_or_else(|| VpnState::Unknown(String::new()))
+}
+
+fn unreachable(
+    vpn_connection: &VpnConnection,
+    state: &VpnState,
+    target: &VpnState,
+) -> RusotoError<VpnWaitError> {
+    RusotoError::Service(VpnWaitError::UnreachableState(format!(
+        "VPN connection {} is {}, and can't become {}",
+        vpn_connection.vpn_connection_id.clone().unwrap_or_default(),
+        state,
+        target
+    )))
+}
+
+fn describe_vpn_connection<C: Ec2>(
+    client: &C,
+    vpn_connection_id: &str,
+) -> impl Future<Item = VpnConnection, Error = RusotoError<VpnWaitError>> {
+    let not_found = format!("VPN connection {} not found", vpn_connection_id);
+    client
+        .describe_vpn_connections(DescribeVpnConnectionsRequest {
+            vpn_connection_ids: Some(vec![vpn_connection_id.to_owned()]),
+            ..Default::default()
+        })
+        .map_err(|e| e.map_service(VpnWaitError::DescribeVpnConnections))
+        .and_then(move |output| {
+            output
+                .vpn_connections
+                .and_then(|vpn_connections| vpn_connections.into_iter().next())
+                .ok_or_else(|| RusotoError::Service(VpnWaitError::NotFound(not_found)))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tunnel(
+        outside_ip_address: &str,
+        status: TelemetryStatus,
+        status_message: &str,
+    ) -> VgwTelemetry {
+        VgwTelemetry {
+            outside_ip_address: Some(outside_ip_address.to_owned()),
+            status: Some(status),
+            status_message: Some(status_message.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn vpn_connection(tunnels: Vec<VgwTelemetry>) -> VpnConnection {
+        VpnConnection {
+            vpn_connection_id: Some("vpn-40f41529".to_owned()),
+            state: Some(VpnState::Available),
+            vgw_telemetry: Some(tunnels),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn deleted_connections_cant_become_available() {
+        assert!(vpn_state_reachable(
+            &VpnState::Pending,
+            &VpnState::Available
+        ));
+        assert!(!vpn_state_reachable(
+            &VpnState::Deleting,
+            &VpnState::Available
+        ));
+        assert!(vpn_state_reachable(&VpnState::Deleting, &VpnState::Deleted));
+        assert!(!vpn_state_reachable(
+            &VpnState::Deleted,
+            &VpnState::Available
+        ));
+    }
+
+    #[test]
+    fn counts_tunnels_up() {
+        assert_eq!(tunnels_up(&VpnConnection::default()), 0);
+        let connection = vpn_connection(vec![
+            tunnel("52.0.0.1", TelemetryStatus::Up, ""),
+            tunnel("52.0.0.2", TelemetryStatus::Down, "IPSEC IS DOWN"),
+        ]);
+        assert_eq!(tunnels_up(&connection), 1);
+    }
+
+    #[test]
+    fn streams_tunnel_transitions() {
+        let (transitions, current) = tunnel_transitions_since(
+            &HashMap::new(),
+            &vpn_connection(vec![
+                tunnel("52.0.0.1", TelemetryStatus::Down, "IPSEC IS DOWN"),
+                tunnel("52.0.0.2", TelemetryStatus::Down, "IPSEC IS DOWN"),
+            ]),
+        );
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].from, None);
+        assert_eq!(
+            transitions[0].status_message,
+            Some("IPSEC IS DOWN".to_owned())
+        );
+
+        let (transitions, current) = tunnel_transitions_since(
+            &current,
+            &vpn_connection(vec![
+                tunnel("52.0.0.1", TelemetryStatus::Up, ""),
+                tunnel("52.0.0.2", TelemetryStatus::Down, "IPSEC IS DOWN"),
+            ]),
+        );
+        assert_eq!(
+            transitions,
+            vec![TunnelTransition {
+                vpn_connection_id: "vpn-40f41529".to_owned(),
+                outside_ip_address: "52.0.0.1".to_owned(),
+                from: Some(TelemetryStatus::Down),
+                to: TelemetryStatus::Up,
+                status_message: None,
+            }]
+        );
+        assert!(transitions[0].is_up());
+
+        let (transitions, _) = tunnel_transitions_since(
+            &current,
+            &vpn_connection(vec![
+                tunnel("52.0.0.1", TelemetryStatus::Down, "IPSEC IS DOWN"),
+                tunnel("52.0.0.2", TelemetryStatus::Down, "IPSEC IS DOWN"),
+            ]),
+        );
+        assert_eq!(transitions.len(), 1);
+        assert!(transitions[0].went_down());
+    }
+}
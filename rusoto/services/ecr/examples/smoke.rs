@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon EC2 Container Registry clients.
+//!
+//! Makes a single read-only `DescribeRepositories` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_ecr::{DescribeRepositoriesRequest, Ecr, EcrClient};
+
+fn main() {
+    let client = EcrClient::from_env();
+    match client
+        .describe_repositories(DescribeRepositoriesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeRepositories succeeded"),
+        Err(error) => {
+            eprintln!("DescribeRepositories failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
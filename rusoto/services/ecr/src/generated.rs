@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>An object representing authorization data for an Amazon ECR registry.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -33,8 +34,9 @@ pub struct AuthorizationData {
     pub authorization_token: Option<String>,
     /// <p>The Unix time in seconds and milliseconds when the authorization token expires. Authorization tokens are valid for 12 hours.</p>
     #[serde(rename = "expiresAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_at: Option<f64>,
+    pub expires_at: Option<EpochTimestamp>,
     /// <p>The registry URL to use for this authorization token in a <code>docker login</code> command. The Amazon ECR registry URL format is <code>https://aws_account_id.dkr.ecr.region.amazonaws.com</code>. For example, <code>https://012345678910.dkr.ecr.us-east-1.amazonaws.com</code>.. </p>
     #[serde(rename = "proxyEndpoint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -55,6 +57,56 @@ pub struct BatchCheckLayerAvailabilityRequest {
     pub repository_name: String,
 }
 
+impl BatchCheckLayerAvailabilityRequest {
+    /// Returns a builder for `BatchCheckLayerAvailabilityRequest`, checking its required members when built.
+    pub fn builder() -> BatchCheckLayerAvailabilityRequestBuilder {
+        BatchCheckLayerAvailabilityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchCheckLayerAvailabilityRequest`](struct.BatchCheckLayerAvailabilityRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchCheckLayerAvailabilityRequestBuilder {
+    layer_digests: Option<Vec<String>>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl BatchCheckLayerAvailabilityRequestBuilder {
+    /// Sets the [`layer_digests`](struct.BatchCheckLayerAvailabilityRequest.html#structfield.layer_digests) member.
+    pub fn layer_digests<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.layer_digests = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.BatchCheckLayerAvailabilityRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.BatchCheckLayerAvailabilityRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchCheckLayerAvailabilityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchCheckLayerAvailabilityRequest, ::rusoto_core::BuildError> {
+        Ok(BatchCheckLayerAvailabilityRequest {
+            layer_digests: self
+                .layer_digests
+                .ok_or(::rusoto_core::BuildError::missing("layer_digests"))?,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchCheckLayerAvailabilityResponse {
@@ -83,6 +135,56 @@ pub struct BatchDeleteImageRequest {
     pub repository_name: String,
 }
 
+impl BatchDeleteImageRequest {
+    /// Returns a builder for `BatchDeleteImageRequest`, checking its required members when built.
+    pub fn builder() -> BatchDeleteImageRequestBuilder {
+        BatchDeleteImageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDeleteImageRequest`](struct.BatchDeleteImageRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDeleteImageRequestBuilder {
+    image_ids: Option<Vec<ImageIdentifier>>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl BatchDeleteImageRequestBuilder {
+    /// Sets the [`image_ids`](struct.BatchDeleteImageRequest.html#structfield.image_ids) member.
+    pub fn image_ids<T: Into<Vec<ImageIdentifier>>>(mut self, value: T) -> Self {
+        self.image_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.BatchDeleteImageRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.BatchDeleteImageRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDeleteImageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDeleteImageRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDeleteImageRequest {
+            image_ids: self
+                .image_ids
+                .ok_or(::rusoto_core::BuildError::missing("image_ids"))?,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDeleteImageResponse {
@@ -114,6 +216,62 @@ pub struct BatchGetImageRequest {
     pub repository_name: String,
 }
 
+impl BatchGetImageRequest {
+    /// Returns a builder for `BatchGetImageRequest`, checking its required members when built.
+    pub fn builder() -> BatchGetImageRequestBuilder {
+        BatchGetImageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetImageRequest`](struct.BatchGetImageRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchGetImageRequestBuilder {
+    accepted_media_types: Option<Vec<String>>,
+    image_ids: Option<Vec<ImageIdentifier>>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl BatchGetImageRequestBuilder {
+    /// Sets the [`accepted_media_types`](struct.BatchGetImageRequest.html#structfield.accepted_media_types) member.
+    pub fn accepted_media_types<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.accepted_media_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_ids`](struct.BatchGetImageRequest.html#structfield.image_ids) member.
+    pub fn image_ids<T: Into<Vec<ImageIdentifier>>>(mut self, value: T) -> Self {
+        self.image_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.BatchGetImageRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.BatchGetImageRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetImageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchGetImageRequest, ::rusoto_core::BuildError> {
+        Ok(BatchGetImageRequest {
+            accepted_media_types: self.accepted_media_types,
+            image_ids: self
+                .image_ids
+                .ok_or(::rusoto_core::BuildError::missing("image_ids"))?,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetImageResponse {
@@ -144,6 +302,66 @@ pub struct CompleteLayerUploadRequest {
     pub upload_id: String,
 }
 
+impl CompleteLayerUploadRequest {
+    /// Returns a builder for `CompleteLayerUploadRequest`, checking its required members when built.
+    pub fn builder() -> CompleteLayerUploadRequestBuilder {
+        CompleteLayerUploadRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CompleteLayerUploadRequest`](struct.CompleteLayerUploadRequest.html).
+#[derive(Default, Debug)]
+pub struct CompleteLayerUploadRequestBuilder {
+    layer_digests: Option<Vec<String>>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+    upload_id: Option<String>,
+}
+
+impl CompleteLayerUploadRequestBuilder {
+    /// Sets the [`layer_digests`](struct.CompleteLayerUploadRequest.html#structfield.layer_digests) member.
+    pub fn layer_digests<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.layer_digests = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.CompleteLayerUploadRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.CompleteLayerUploadRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`upload_id`](struct.CompleteLayerUploadRequest.html#structfield.upload_id) member.
+    pub fn upload_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.upload_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CompleteLayerUploadRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CompleteLayerUploadRequest, ::rusoto_core::BuildError> {
+        Ok(CompleteLayerUploadRequest {
+            layer_digests: self
+                .layer_digests
+                .ok_or(::rusoto_core::BuildError::missing("layer_digests"))?,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            upload_id: self
+                .upload_id
+                .ok_or(::rusoto_core::BuildError::missing("upload_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CompleteLayerUploadResponse {
@@ -170,7 +388,7 @@ pub struct CreateRepositoryRequest {
     /// <p>The tag mutability setting for the repository. If this parameter is omitted, the default setting of <code>MUTABLE</code> will be used which will allow image tags to be overwritten. If <code>IMMUTABLE</code> is specified, all image tags within the repository will be immutable which will prevent them from being overwritten.</p>
     #[serde(rename = "imageTagMutability")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_tag_mutability: Option<String>,
+    pub image_tag_mutability: Option<ImageTagMutability>,
     /// <p>The name to use for the repository. The repository name may be specified on its own (such as <code>nginx-web-app</code>) or it can be prepended with a namespace to group the repository into a category (such as <code>project-a/nginx-web-app</code>).</p>
     #[serde(rename = "repositoryName")]
     pub repository_name: String,
@@ -180,6 +398,54 @@ pub struct CreateRepositoryRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateRepositoryRequest {
+    /// Returns a builder for `CreateRepositoryRequest`, checking its required members when built.
+    pub fn builder() -> CreateRepositoryRequestBuilder {
+        CreateRepositoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRepositoryRequest`](struct.CreateRepositoryRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRepositoryRequestBuilder {
+    image_tag_mutability: Option<ImageTagMutability>,
+    repository_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateRepositoryRequestBuilder {
+    /// Sets the [`image_tag_mutability`](struct.CreateRepositoryRequest.html#structfield.image_tag_mutability) member.
+    pub fn image_tag_mutability<T: Into<ImageTagMutability>>(mut self, value: T) -> Self {
+        self.image_tag_mutability = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.CreateRepositoryRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateRepositoryRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRepositoryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateRepositoryRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRepositoryRequest {
+            image_tag_mutability: self.image_tag_mutability,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRepositoryResponse {
@@ -200,13 +466,54 @@ pub struct DeleteLifecyclePolicyRequest {
     pub repository_name: String,
 }
 
+impl DeleteLifecyclePolicyRequest {
+    /// Returns a builder for `DeleteLifecyclePolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLifecyclePolicyRequestBuilder {
+        DeleteLifecyclePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLifecyclePolicyRequest`](struct.DeleteLifecyclePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLifecyclePolicyRequestBuilder {
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl DeleteLifecyclePolicyRequestBuilder {
+    /// Sets the [`registry_id`](struct.DeleteLifecyclePolicyRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.DeleteLifecyclePolicyRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLifecyclePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLifecyclePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLifecyclePolicyRequest {
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteLifecyclePolicyResponse {
     /// <p>The time stamp of the last time that the lifecycle policy was run.</p>
     #[serde(rename = "lastEvaluatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_evaluated_at: Option<f64>,
+    pub last_evaluated_at: Option<EpochTimestamp>,
     /// <p>The JSON lifecycle policy text.</p>
     #[serde(rename = "lifecyclePolicyText")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -232,6 +539,46 @@ pub struct DeleteRepositoryPolicyRequest {
     pub repository_name: String,
 }
 
+impl DeleteRepositoryPolicyRequest {
+    /// Returns a builder for `DeleteRepositoryPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRepositoryPolicyRequestBuilder {
+        DeleteRepositoryPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRepositoryPolicyRequest`](struct.DeleteRepositoryPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRepositoryPolicyRequestBuilder {
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl DeleteRepositoryPolicyRequestBuilder {
+    /// Sets the [`registry_id`](struct.DeleteRepositoryPolicyRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.DeleteRepositoryPolicyRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRepositoryPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRepositoryPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRepositoryPolicyRequest {
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRepositoryPolicyResponse {
@@ -264,6 +611,54 @@ pub struct DeleteRepositoryRequest {
     pub repository_name: String,
 }
 
+impl DeleteRepositoryRequest {
+    /// Returns a builder for `DeleteRepositoryRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRepositoryRequestBuilder {
+        DeleteRepositoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRepositoryRequest`](struct.DeleteRepositoryRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRepositoryRequestBuilder {
+    force: Option<bool>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl DeleteRepositoryRequestBuilder {
+    /// Sets the [`force`](struct.DeleteRepositoryRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.DeleteRepositoryRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.DeleteRepositoryRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRepositoryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRepositoryRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRepositoryRequest {
+            force: self.force,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRepositoryResponse {
@@ -279,7 +674,7 @@ pub struct DescribeImagesFilter {
     /// <p>The tag status with which to filter your <a>DescribeImages</a> results. You can filter results based on whether they are <code>TAGGED</code> or <code>UNTAGGED</code>.</p>
     #[serde(rename = "tagStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag_status: Option<String>,
+    pub tag_status: Option<TagStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -309,6 +704,76 @@ pub struct DescribeImagesRequest {
     pub repository_name: String,
 }
 
+impl DescribeImagesRequest {
+    /// Returns a builder for `DescribeImagesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeImagesRequestBuilder {
+        DescribeImagesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeImagesRequest`](struct.DescribeImagesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeImagesRequestBuilder {
+    filter: Option<DescribeImagesFilter>,
+    image_ids: Option<Vec<ImageIdentifier>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl DescribeImagesRequestBuilder {
+    /// Sets the [`filter`](struct.DescribeImagesRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<DescribeImagesFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_ids`](struct.DescribeImagesRequest.html#structfield.image_ids) member.
+    pub fn image_ids<T: Into<Vec<ImageIdentifier>>>(mut self, value: T) -> Self {
+        self.image_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeImagesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeImagesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.DescribeImagesRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.DescribeImagesRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeImagesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeImagesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeImagesRequest {
+            filter: self.filter,
+            image_ids: self.image_ids,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeImagesResponse {
@@ -342,6 +807,60 @@ pub struct DescribeRepositoriesRequest {
     pub repository_names: Option<Vec<String>>,
 }
 
+impl DescribeRepositoriesRequest {
+    /// Returns a builder for `DescribeRepositoriesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeRepositoriesRequestBuilder {
+        DescribeRepositoriesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRepositoriesRequest`](struct.DescribeRepositoriesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeRepositoriesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    registry_id: Option<String>,
+    repository_names: Option<Vec<String>>,
+}
+
+impl DescribeRepositoriesRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeRepositoriesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeRepositoriesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.DescribeRepositoriesRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_names`](struct.DescribeRepositoriesRequest.html#structfield.repository_names) member.
+    pub fn repository_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.repository_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRepositoriesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeRepositoriesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeRepositoriesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            registry_id: self.registry_id,
+            repository_names: self.repository_names,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeRepositoriesResponse {
@@ -363,6 +882,36 @@ pub struct GetAuthorizationTokenRequest {
     pub registry_ids: Option<Vec<String>>,
 }
 
+impl GetAuthorizationTokenRequest {
+    /// Returns a builder for `GetAuthorizationTokenRequest`, checking its required members when built.
+    pub fn builder() -> GetAuthorizationTokenRequestBuilder {
+        GetAuthorizationTokenRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAuthorizationTokenRequest`](struct.GetAuthorizationTokenRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAuthorizationTokenRequestBuilder {
+    registry_ids: Option<Vec<String>>,
+}
+
+impl GetAuthorizationTokenRequestBuilder {
+    /// Sets the [`registry_ids`](struct.GetAuthorizationTokenRequest.html#structfield.registry_ids) member.
+    pub fn registry_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.registry_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAuthorizationTokenRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAuthorizationTokenRequest, ::rusoto_core::BuildError> {
+        Ok(GetAuthorizationTokenRequest {
+            registry_ids: self.registry_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAuthorizationTokenResponse {
@@ -386,6 +935,56 @@ pub struct GetDownloadUrlForLayerRequest {
     pub repository_name: String,
 }
 
+impl GetDownloadUrlForLayerRequest {
+    /// Returns a builder for `GetDownloadUrlForLayerRequest`, checking its required members when built.
+    pub fn builder() -> GetDownloadUrlForLayerRequestBuilder {
+        GetDownloadUrlForLayerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDownloadUrlForLayerRequest`](struct.GetDownloadUrlForLayerRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDownloadUrlForLayerRequestBuilder {
+    layer_digest: Option<String>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl GetDownloadUrlForLayerRequestBuilder {
+    /// Sets the [`layer_digest`](struct.GetDownloadUrlForLayerRequest.html#structfield.layer_digest) member.
+    pub fn layer_digest<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_digest = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.GetDownloadUrlForLayerRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetDownloadUrlForLayerRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDownloadUrlForLayerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDownloadUrlForLayerRequest, ::rusoto_core::BuildError> {
+        Ok(GetDownloadUrlForLayerRequest {
+            layer_digest: self
+                .layer_digest
+                .ok_or(::rusoto_core::BuildError::missing("layer_digest"))?,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDownloadUrlForLayerResponse {
@@ -426,6 +1025,78 @@ pub struct GetLifecyclePolicyPreviewRequest {
     pub repository_name: String,
 }
 
+impl GetLifecyclePolicyPreviewRequest {
+    /// Returns a builder for `GetLifecyclePolicyPreviewRequest`, checking its required members when built.
+    pub fn builder() -> GetLifecyclePolicyPreviewRequestBuilder {
+        GetLifecyclePolicyPreviewRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLifecyclePolicyPreviewRequest`](struct.GetLifecyclePolicyPreviewRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLifecyclePolicyPreviewRequestBuilder {
+    filter: Option<LifecyclePolicyPreviewFilter>,
+    image_ids: Option<Vec<ImageIdentifier>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl GetLifecyclePolicyPreviewRequestBuilder {
+    /// Sets the [`filter`](struct.GetLifecyclePolicyPreviewRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<LifecyclePolicyPreviewFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_ids`](struct.GetLifecyclePolicyPreviewRequest.html#structfield.image_ids) member.
+    pub fn image_ids<T: Into<Vec<ImageIdentifier>>>(mut self, value: T) -> Self {
+        self.image_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetLifecyclePolicyPreviewRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetLifecyclePolicyPreviewRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.GetLifecyclePolicyPreviewRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetLifecyclePolicyPreviewRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLifecyclePolicyPreviewRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLifecyclePolicyPreviewRequest, ::rusoto_core::BuildError> {
+        Ok(GetLifecyclePolicyPreviewRequest {
+            filter: self.filter,
+            image_ids: self.image_ids,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLifecyclePolicyPreviewResponse {
@@ -452,7 +1123,7 @@ pub struct GetLifecyclePolicyPreviewResponse {
     /// <p>The status of the lifecycle policy preview request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<LifecyclePolicyPreviewStatus>,
     /// <p>The list of images that is returned as a result of the action.</p>
     #[serde(rename = "summary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -470,13 +1141,54 @@ pub struct GetLifecyclePolicyRequest {
     pub repository_name: String,
 }
 
+impl GetLifecyclePolicyRequest {
+    /// Returns a builder for `GetLifecyclePolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetLifecyclePolicyRequestBuilder {
+        GetLifecyclePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLifecyclePolicyRequest`](struct.GetLifecyclePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLifecyclePolicyRequestBuilder {
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl GetLifecyclePolicyRequestBuilder {
+    /// Sets the [`registry_id`](struct.GetLifecyclePolicyRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetLifecyclePolicyRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLifecyclePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLifecyclePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetLifecyclePolicyRequest {
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLifecyclePolicyResponse {
     /// <p>The time stamp of the last time that the lifecycle policy was run.</p>
     #[serde(rename = "lastEvaluatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_evaluated_at: Option<f64>,
+    pub last_evaluated_at: Option<EpochTimestamp>,
     /// <p>The JSON lifecycle policy text.</p>
     #[serde(rename = "lifecyclePolicyText")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -502,6 +1214,46 @@ pub struct GetRepositoryPolicyRequest {
     pub repository_name: String,
 }
 
+impl GetRepositoryPolicyRequest {
+    /// Returns a builder for `GetRepositoryPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetRepositoryPolicyRequestBuilder {
+        GetRepositoryPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRepositoryPolicyRequest`](struct.GetRepositoryPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRepositoryPolicyRequestBuilder {
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl GetRepositoryPolicyRequestBuilder {
+    /// Sets the [`registry_id`](struct.GetRepositoryPolicyRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.GetRepositoryPolicyRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRepositoryPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRepositoryPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetRepositoryPolicyRequest {
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRepositoryPolicyResponse {
@@ -541,32 +1293,101 @@ pub struct Image {
     pub repository_name: Option<String>,
 }
 
-/// <p>An object that describes an image returned by a <a>DescribeImages</a> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ImageDetail {
-    /// <p>The <code>sha256</code> digest of the image manifest.</p>
-    #[serde(rename = "imageDigest")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_digest: Option<String>,
-    /// <p>The date and time, expressed in standard JavaScript date format, at which the current image was pushed to the repository. </p>
-    #[serde(rename = "imagePushedAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_pushed_at: Option<f64>,
-    /// <p><p>The size, in bytes, of the image in the repository.</p> <note> <p>Beginning with Docker version 1.9, the Docker client compresses image layers before pushing them to a V2 Docker registry. The output of the <code>docker images</code> command shows the uncompressed image size, so it may return a larger image size than the image sizes returned by <a>DescribeImages</a>.</p> </note></p>
-    #[serde(rename = "imageSizeInBytes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_size_in_bytes: Option<i64>,
-    /// <p>The list of tags associated with this image.</p>
-    #[serde(rename = "imageTags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_tags: Option<Vec<String>>,
-    /// <p>The AWS account ID associated with the registry to which this image belongs.</p>
-    #[serde(rename = "registryId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub registry_id: Option<String>,
-    /// <p>The name of the repository to which this image belongs.</p>
-    #[serde(rename = "repositoryName")]
+/// <p>The type of action to be taken.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImageActionType {
+    /// <p><code>EXPIRE</code></p>
+    Expire,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ImageActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageActionType::Expire => "EXPIRE",
+            ImageActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ImageActionType {
+    fn from(value: &'a str) -> ImageActionType {
+        match value {
+            "EXPIRE" => ImageActionType::Expire,
+            _ => ImageActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageActionType {
+    fn from(value: String) -> ImageActionType {
+        ImageActionType::from(value.as_str())
+    }
+}
+
+impl From<ImageActionType> for String {
+    fn from(value: ImageActionType) -> String {
+        match value {
+            ImageActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ImageActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ImageActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageActionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ImageActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ImageActionType::from(value))
+    }
+}
+
+/// <p>An object that describes an image returned by a <a>DescribeImages</a> operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ImageDetail {
+    /// <p>The <code>sha256</code> digest of the image manifest.</p>
+    #[serde(rename = "imageDigest")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_digest: Option<String>,
+    /// <p>The date and time, expressed in standard JavaScript date format, at which the current image was pushed to the repository. </p>
+    #[serde(rename = "imagePushedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_pushed_at: Option<EpochTimestamp>,
+    /// <p><p>The size, in bytes, of the image in the repository.</p> <note> <p>Beginning with Docker version 1.9, the Docker client compresses image layers before pushing them to a V2 Docker registry. The output of the <code>docker images</code> command shows the uncompressed image size, so it may return a larger image size than the image sizes returned by <a>DescribeImages</a>.</p> </note></p>
+    #[serde(rename = "imageSizeInBytes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_size_in_bytes: Option<i64>,
+    /// <p>The list of tags associated with this image.</p>
+    #[serde(rename = "imageTags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_tags: Option<Vec<String>>,
+    /// <p>The AWS account ID associated with the registry to which this image belongs.</p>
+    #[serde(rename = "registryId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry_id: Option<String>,
+    /// <p>The name of the repository to which this image belongs.</p>
+    #[serde(rename = "repositoryName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub repository_name: Option<String>,
 }
@@ -578,7 +1399,7 @@ pub struct ImageFailure {
     /// <p>The code associated with the failure.</p>
     #[serde(rename = "failureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<ImageFailureCode>,
     /// <p>The reason for the failure.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -589,6 +1410,110 @@ pub struct ImageFailure {
     pub image_id: Option<ImageIdentifier>,
 }
 
+/// <p>The code associated with the failure.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImageFailureCode {
+    /// <p><code>InvalidImageDigest</code></p>
+    InvalidImageDigest,
+    /// <p><code>InvalidImageTag</code></p>
+    InvalidImageTag,
+    /// <p><code>ImageTagDoesNotMatchDigest</code></p>
+    ImageTagDoesNotMatchDigest,
+    /// <p><code>ImageNotFound</code></p>
+    ImageNotFound,
+    /// <p><code>MissingDigestAndTag</code></p>
+    MissingDigestAndTag,
+    /// <p><code>ImageReferencedByManifestList</code></p>
+    ImageReferencedByManifestList,
+    /// <p><code>KmsError</code></p>
+    KmsError,
+    /// <p><code>UpstreamAccessDenied</code></p>
+    UpstreamAccessDenied,
+    /// <p><code>UpstreamTooManyRequests</code></p>
+    UpstreamTooManyRequests,
+    /// <p><code>UpstreamUnavailable</code></p>
+    UpstreamUnavailable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ImageFailureCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageFailureCode::InvalidImageDigest => "InvalidImageDigest",
+            ImageFailureCode::InvalidImageTag => "InvalidImageTag",
+            ImageFailureCode::ImageTagDoesNotMatchDigest => "ImageTagDoesNotMatchDigest",
+            ImageFailureCode::ImageNotFound => "ImageNotFound",
+            ImageFailureCode::MissingDigestAndTag => "MissingDigestAndTag",
+            ImageFailureCode::ImageReferencedByManifestList => "ImageReferencedByManifestList",
+            ImageFailureCode::KmsError => "KmsError",
+            ImageFailureCode::UpstreamAccessDenied => "UpstreamAccessDenied",
+            ImageFailureCode::UpstreamTooManyRequests => "UpstreamTooManyRequests",
+            ImageFailureCode::UpstreamUnavailable => "UpstreamUnavailable",
+            ImageFailureCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ImageFailureCode {
+    fn from(value: &'a str) -> ImageFailureCode {
+        match value {
+            "InvalidImageDigest" => ImageFailureCode::InvalidImageDigest,
+            "InvalidImageTag" => ImageFailureCode::InvalidImageTag,
+            "ImageTagDoesNotMatchDigest" => ImageFailureCode::ImageTagDoesNotMatchDigest,
+            "ImageNotFound" => ImageFailureCode::ImageNotFound,
+            "MissingDigestAndTag" => ImageFailureCode::MissingDigestAndTag,
+            "ImageReferencedByManifestList" => ImageFailureCode::ImageReferencedByManifestList,
+            "KmsError" => ImageFailureCode::KmsError,
+            "UpstreamAccessDenied" => ImageFailureCode::UpstreamAccessDenied,
+            "UpstreamTooManyRequests" => ImageFailureCode::UpstreamTooManyRequests,
+            "UpstreamUnavailable" => ImageFailureCode::UpstreamUnavailable,
+            _ => ImageFailureCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageFailureCode {
+    fn from(value: String) -> ImageFailureCode {
+        ImageFailureCode::from(value.as_str())
+    }
+}
+
+impl From<ImageFailureCode> for String {
+    fn from(value: ImageFailureCode) -> String {
+        match value {
+            ImageFailureCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ImageFailureCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ImageFailureCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageFailureCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ImageFailureCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ImageFailureCode::from(value))
+    }
+}
+
 /// <p>An object with identifying information for an Amazon ECR image.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImageIdentifier {
@@ -602,6 +1527,78 @@ pub struct ImageIdentifier {
     pub image_tag: Option<String>,
 }
 
+/// <p>The tag mutability setting for the repository. If this parameter is omitted, the default setting of <code>MUTABLE</code> will be used which will allow image tags to be overwritten. If <code>IMMUTABLE</code> is specified, all image tags within the repository will be immutable which will prevent them from being overwritten.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImageTagMutability {
+    /// <p><code>MUTABLE</code></p>
+    Mutable,
+    /// <p><code>IMMUTABLE</code></p>
+    Immutable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ImageTagMutability {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImageTagMutability::Mutable => "MUTABLE",
+            ImageTagMutability::Immutable => "IMMUTABLE",
+            ImageTagMutability::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ImageTagMutability {
+    fn from(value: &'a str) -> ImageTagMutability {
+        match value {
+            "MUTABLE" => ImageTagMutability::Mutable,
+            "IMMUTABLE" => ImageTagMutability::Immutable,
+            _ => ImageTagMutability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImageTagMutability {
+    fn from(value: String) -> ImageTagMutability {
+        ImageTagMutability::from(value.as_str())
+    }
+}
+
+impl From<ImageTagMutability> for String {
+    fn from(value: ImageTagMutability) -> String {
+        match value {
+            ImageTagMutability::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ImageTagMutability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ImageTagMutability {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImageTagMutability {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ImageTagMutability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ImageTagMutability::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct InitiateLayerUploadRequest {
     /// <p>The AWS account ID associated with the registry to which you intend to upload layers. If you do not specify a registry, the default registry is assumed.</p>
@@ -613,6 +1610,46 @@ pub struct InitiateLayerUploadRequest {
     pub repository_name: String,
 }
 
+impl InitiateLayerUploadRequest {
+    /// Returns a builder for `InitiateLayerUploadRequest`, checking its required members when built.
+    pub fn builder() -> InitiateLayerUploadRequestBuilder {
+        InitiateLayerUploadRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InitiateLayerUploadRequest`](struct.InitiateLayerUploadRequest.html).
+#[derive(Default, Debug)]
+pub struct InitiateLayerUploadRequestBuilder {
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl InitiateLayerUploadRequestBuilder {
+    /// Sets the [`registry_id`](struct.InitiateLayerUploadRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.InitiateLayerUploadRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `InitiateLayerUploadRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<InitiateLayerUploadRequest, ::rusoto_core::BuildError> {
+        Ok(InitiateLayerUploadRequest {
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct InitiateLayerUploadResponse {
@@ -633,7 +1670,7 @@ pub struct Layer {
     /// <p>The availability status of the image layer.</p>
     #[serde(rename = "layerAvailability")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub layer_availability: Option<String>,
+    pub layer_availability: Option<LayerAvailability>,
     /// <p>The <code>sha256</code> digest of the image layer.</p>
     #[serde(rename = "layerDigest")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -648,6 +1685,78 @@ pub struct Layer {
     pub media_type: Option<String>,
 }
 
+/// <p>The availability status of the image layer.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LayerAvailability {
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>UNAVAILABLE</code></p>
+    Unavailable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LayerAvailability {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LayerAvailability::Available => "AVAILABLE",
+            LayerAvailability::Unavailable => "UNAVAILABLE",
+            LayerAvailability::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LayerAvailability {
+    fn from(value: &'a str) -> LayerAvailability {
+        match value {
+            "AVAILABLE" => LayerAvailability::Available,
+            "UNAVAILABLE" => LayerAvailability::Unavailable,
+            _ => LayerAvailability::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LayerAvailability {
+    fn from(value: String) -> LayerAvailability {
+        LayerAvailability::from(value.as_str())
+    }
+}
+
+impl From<LayerAvailability> for String {
+    fn from(value: LayerAvailability) -> String {
+        match value {
+            LayerAvailability::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LayerAvailability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LayerAvailability {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LayerAvailability {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LayerAvailability, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LayerAvailability::from(value))
+    }
+}
+
 /// <p>An object representing an Amazon ECR image layer failure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -655,7 +1764,7 @@ pub struct LayerFailure {
     /// <p>The failure code associated with the failure.</p>
     #[serde(rename = "failureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_code: Option<String>,
+    pub failure_code: Option<LayerFailureCode>,
     /// <p>The reason for the failure.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -666,13 +1775,85 @@ pub struct LayerFailure {
     pub layer_digest: Option<String>,
 }
 
+/// <p>The failure code associated with the failure.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LayerFailureCode {
+    /// <p><code>InvalidLayerDigest</code></p>
+    InvalidLayerDigest,
+    /// <p><code>MissingLayerDigest</code></p>
+    MissingLayerDigest,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LayerFailureCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LayerFailureCode::InvalidLayerDigest => "InvalidLayerDigest",
+            LayerFailureCode::MissingLayerDigest => "MissingLayerDigest",
+            LayerFailureCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LayerFailureCode {
+    fn from(value: &'a str) -> LayerFailureCode {
+        match value {
+            "InvalidLayerDigest" => LayerFailureCode::InvalidLayerDigest,
+            "MissingLayerDigest" => LayerFailureCode::MissingLayerDigest,
+            _ => LayerFailureCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LayerFailureCode {
+    fn from(value: String) -> LayerFailureCode {
+        LayerFailureCode::from(value.as_str())
+    }
+}
+
+impl From<LayerFailureCode> for String {
+    fn from(value: LayerFailureCode) -> String {
+        match value {
+            LayerFailureCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LayerFailureCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LayerFailureCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LayerFailureCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LayerFailureCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LayerFailureCode::from(value))
+    }
+}
+
 /// <p>The filter for the lifecycle policy preview.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct LifecyclePolicyPreviewFilter {
     /// <p>The tag status of the image.</p>
     #[serde(rename = "tagStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag_status: Option<String>,
+    pub tag_status: Option<TagStatus>,
 }
 
 /// <p>The result of the lifecycle policy preview.</p>
@@ -693,14 +1874,97 @@ pub struct LifecyclePolicyPreviewResult {
     pub image_digest: Option<String>,
     /// <p>The date and time, expressed in standard JavaScript date format, at which the current image was pushed to the repository.</p>
     #[serde(rename = "imagePushedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_pushed_at: Option<f64>,
+    pub image_pushed_at: Option<EpochTimestamp>,
     /// <p>The list of tags associated with this image.</p>
     #[serde(rename = "imageTags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_tags: Option<Vec<String>>,
 }
 
+/// <p>The status of the lifecycle policy preview request.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LifecyclePolicyPreviewStatus {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>COMPLETE</code></p>
+    Complete,
+    /// <p><code>EXPIRED</code></p>
+    Expired,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LifecyclePolicyPreviewStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LifecyclePolicyPreviewStatus::InProgress => "IN_PROGRESS",
+            LifecyclePolicyPreviewStatus::Complete => "COMPLETE",
+            LifecyclePolicyPreviewStatus::Expired => "EXPIRED",
+            LifecyclePolicyPreviewStatus::Failed => "FAILED",
+            LifecyclePolicyPreviewStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LifecyclePolicyPreviewStatus {
+    fn from(value: &'a str) -> LifecyclePolicyPreviewStatus {
+        match value {
+            "IN_PROGRESS" => LifecyclePolicyPreviewStatus::InProgress,
+            "COMPLETE" => LifecyclePolicyPreviewStatus::Complete,
+            "EXPIRED" => LifecyclePolicyPreviewStatus::Expired,
+            "FAILED" => LifecyclePolicyPreviewStatus::Failed,
+            _ => LifecyclePolicyPreviewStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LifecyclePolicyPreviewStatus {
+    fn from(value: String) -> LifecyclePolicyPreviewStatus {
+        LifecyclePolicyPreviewStatus::from(value.as_str())
+    }
+}
+
+impl From<LifecyclePolicyPreviewStatus> for String {
+    fn from(value: LifecyclePolicyPreviewStatus) -> String {
+        match value {
+            LifecyclePolicyPreviewStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LifecyclePolicyPreviewStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LifecyclePolicyPreviewStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LifecyclePolicyPreviewStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<LifecyclePolicyPreviewStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LifecyclePolicyPreviewStatus::from(value))
+    }
+}
+
 /// <p>The summary of the lifecycle policy preview request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -718,7 +1982,7 @@ pub struct LifecyclePolicyRuleAction {
     /// <p>The type of action to be taken.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ImageActionType>,
 }
 
 /// <p>An object representing a filter on a <a>ListImages</a> operation.</p>
@@ -727,7 +1991,7 @@ pub struct ListImagesFilter {
     /// <p>The tag status with which to filter your <a>ListImages</a> results. You can filter results based on whether they are <code>TAGGED</code> or <code>UNTAGGED</code>.</p>
     #[serde(rename = "tagStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tag_status: Option<String>,
+    pub tag_status: Option<TagStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -753,6 +2017,68 @@ pub struct ListImagesRequest {
     pub repository_name: String,
 }
 
+impl ListImagesRequest {
+    /// Returns a builder for `ListImagesRequest`, checking its required members when built.
+    pub fn builder() -> ListImagesRequestBuilder {
+        ListImagesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListImagesRequest`](struct.ListImagesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListImagesRequestBuilder {
+    filter: Option<ListImagesFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl ListImagesRequestBuilder {
+    /// Sets the [`filter`](struct.ListImagesRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<ListImagesFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListImagesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListImagesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.ListImagesRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.ListImagesRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListImagesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListImagesRequest, ::rusoto_core::BuildError> {
+        Ok(ListImagesRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListImagesResponse {
@@ -773,6 +2099,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -800,6 +2158,62 @@ pub struct PutImageRequest {
     pub repository_name: String,
 }
 
+impl PutImageRequest {
+    /// Returns a builder for `PutImageRequest`, checking its required members when built.
+    pub fn builder() -> PutImageRequestBuilder {
+        PutImageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutImageRequest`](struct.PutImageRequest.html).
+#[derive(Default, Debug)]
+pub struct PutImageRequestBuilder {
+    image_manifest: Option<String>,
+    image_tag: Option<String>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl PutImageRequestBuilder {
+    /// Sets the [`image_manifest`](struct.PutImageRequest.html#structfield.image_manifest) member.
+    pub fn image_manifest<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_manifest = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image_tag`](struct.PutImageRequest.html#structfield.image_tag) member.
+    pub fn image_tag<T: Into<String>>(mut self, value: T) -> Self {
+        self.image_tag = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.PutImageRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.PutImageRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutImageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutImageRequest, ::rusoto_core::BuildError> {
+        Ok(PutImageRequest {
+            image_manifest: self
+                .image_manifest
+                .ok_or(::rusoto_core::BuildError::missing("image_manifest"))?,
+            image_tag: self.image_tag,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutImageResponse {
@@ -809,11 +2223,11 @@ pub struct PutImageResponse {
     pub image: Option<Image>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutImageTagMutabilityRequest {
     /// <p>The tag mutability setting for the repository. If <code>MUTABLE</code> is specified, image tags can be overwritten. If <code>IMMUTABLE</code> is specified, all image tags within the repository will be immutable which will prevent them from being overwritten.</p>
     #[serde(rename = "imageTagMutability")]
-    pub image_tag_mutability: String,
+    pub image_tag_mutability: ImageTagMutability,
     /// <p>The AWS account ID associated with the registry that contains the repository in which to update the image tag mutability settings. If you do not specify a registry, the default registry is assumed.</p>
     #[serde(rename = "registryId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -823,13 +2237,63 @@ pub struct PutImageTagMutabilityRequest {
     pub repository_name: String,
 }
 
+impl PutImageTagMutabilityRequest {
+    /// Returns a builder for `PutImageTagMutabilityRequest`, checking its required members when built.
+    pub fn builder() -> PutImageTagMutabilityRequestBuilder {
+        PutImageTagMutabilityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutImageTagMutabilityRequest`](struct.PutImageTagMutabilityRequest.html).
+#[derive(Default, Debug)]
+pub struct PutImageTagMutabilityRequestBuilder {
+    image_tag_mutability: Option<ImageTagMutability>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl PutImageTagMutabilityRequestBuilder {
+    /// Sets the [`image_tag_mutability`](struct.PutImageTagMutabilityRequest.html#structfield.image_tag_mutability) member.
+    pub fn image_tag_mutability<T: Into<ImageTagMutability>>(mut self, value: T) -> Self {
+        self.image_tag_mutability = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.PutImageTagMutabilityRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.PutImageTagMutabilityRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutImageTagMutabilityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutImageTagMutabilityRequest, ::rusoto_core::BuildError> {
+        Ok(PutImageTagMutabilityRequest {
+            image_tag_mutability: self
+                .image_tag_mutability
+                .ok_or(::rusoto_core::BuildError::missing("image_tag_mutability"))?,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutImageTagMutabilityResponse {
     /// <p>The image tag mutability setting for the repository.</p>
     #[serde(rename = "imageTagMutability")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_tag_mutability: Option<String>,
+    pub image_tag_mutability: Option<ImageTagMutability>,
     /// <p>The registry ID associated with the request.</p>
     #[serde(rename = "registryId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -854,6 +2318,56 @@ pub struct PutLifecyclePolicyRequest {
     pub repository_name: String,
 }
 
+impl PutLifecyclePolicyRequest {
+    /// Returns a builder for `PutLifecyclePolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutLifecyclePolicyRequestBuilder {
+        PutLifecyclePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutLifecyclePolicyRequest`](struct.PutLifecyclePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutLifecyclePolicyRequestBuilder {
+    lifecycle_policy_text: Option<String>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl PutLifecyclePolicyRequestBuilder {
+    /// Sets the [`lifecycle_policy_text`](struct.PutLifecyclePolicyRequest.html#structfield.lifecycle_policy_text) member.
+    pub fn lifecycle_policy_text<T: Into<String>>(mut self, value: T) -> Self {
+        self.lifecycle_policy_text = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.PutLifecyclePolicyRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.PutLifecyclePolicyRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutLifecyclePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutLifecyclePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutLifecyclePolicyRequest {
+            lifecycle_policy_text: self
+                .lifecycle_policy_text
+                .ok_or(::rusoto_core::BuildError::missing("lifecycle_policy_text"))?,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutLifecyclePolicyResponse {
@@ -877,12 +2391,13 @@ pub struct PutLifecyclePolicyResponse {
 pub struct Repository {
     /// <p>The date and time, in JavaScript date format, when the repository was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The tag mutability setting for the repository.</p>
     #[serde(rename = "imageTagMutability")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_tag_mutability: Option<String>,
+    pub image_tag_mutability: Option<ImageTagMutability>,
     /// <p>The AWS account ID associated with the registry that contains the repository.</p>
     #[serde(rename = "registryId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -919,6 +2434,64 @@ pub struct SetRepositoryPolicyRequest {
     pub repository_name: String,
 }
 
+impl SetRepositoryPolicyRequest {
+    /// Returns a builder for `SetRepositoryPolicyRequest`, checking its required members when built.
+    pub fn builder() -> SetRepositoryPolicyRequestBuilder {
+        SetRepositoryPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetRepositoryPolicyRequest`](struct.SetRepositoryPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct SetRepositoryPolicyRequestBuilder {
+    force: Option<bool>,
+    policy_text: Option<String>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl SetRepositoryPolicyRequestBuilder {
+    /// Sets the [`force`](struct.SetRepositoryPolicyRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_text`](struct.SetRepositoryPolicyRequest.html#structfield.policy_text) member.
+    pub fn policy_text<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_text = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.SetRepositoryPolicyRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.SetRepositoryPolicyRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetRepositoryPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetRepositoryPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(SetRepositoryPolicyRequest {
+            force: self.force,
+            policy_text: self
+                .policy_text
+                .ok_or(::rusoto_core::BuildError::missing("policy_text"))?,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SetRepositoryPolicyResponse {
@@ -951,6 +2524,54 @@ pub struct StartLifecyclePolicyPreviewRequest {
     pub repository_name: String,
 }
 
+impl StartLifecyclePolicyPreviewRequest {
+    /// Returns a builder for `StartLifecyclePolicyPreviewRequest`, checking its required members when built.
+    pub fn builder() -> StartLifecyclePolicyPreviewRequestBuilder {
+        StartLifecyclePolicyPreviewRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartLifecyclePolicyPreviewRequest`](struct.StartLifecyclePolicyPreviewRequest.html).
+#[derive(Default, Debug)]
+pub struct StartLifecyclePolicyPreviewRequestBuilder {
+    lifecycle_policy_text: Option<String>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+}
+
+impl StartLifecyclePolicyPreviewRequestBuilder {
+    /// Sets the [`lifecycle_policy_text`](struct.StartLifecyclePolicyPreviewRequest.html#structfield.lifecycle_policy_text) member.
+    pub fn lifecycle_policy_text<T: Into<String>>(mut self, value: T) -> Self {
+        self.lifecycle_policy_text = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.StartLifecyclePolicyPreviewRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.StartLifecyclePolicyPreviewRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartLifecyclePolicyPreviewRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartLifecyclePolicyPreviewRequest, ::rusoto_core::BuildError> {
+        Ok(StartLifecyclePolicyPreviewRequest {
+            lifecycle_policy_text: self.lifecycle_policy_text,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartLifecyclePolicyPreviewResponse {
@@ -969,7 +2590,7 @@ pub struct StartLifecyclePolicyPreviewResponse {
     /// <p>The status of the lifecycle policy preview request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<LifecyclePolicyPreviewStatus>,
 }
 
 /// <p>The metadata that you apply to a resource to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define. Tag keys can have a maximum character length of 128 characters, and tag values can have a maximum length of 256 characters.</p>
@@ -995,10 +2616,126 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
 
+/// <p>The tag status with which to filter your <a>DescribeImages</a> results. You can filter results based on whether they are <code>TAGGED</code> or <code>UNTAGGED</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TagStatus {
+    /// <p><code>TAGGED</code></p>
+    Tagged,
+    /// <p><code>UNTAGGED</code></p>
+    Untagged,
+    /// <p><code>ANY</code></p>
+    Any,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TagStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TagStatus::Tagged => "TAGGED",
+            TagStatus::Untagged => "UNTAGGED",
+            TagStatus::Any => "ANY",
+            TagStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TagStatus {
+    fn from(value: &'a str) -> TagStatus {
+        match value {
+            "TAGGED" => TagStatus::Tagged,
+            "UNTAGGED" => TagStatus::Untagged,
+            "ANY" => TagStatus::Any,
+            _ => TagStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TagStatus {
+    fn from(value: String) -> TagStatus {
+        TagStatus::from(value.as_str())
+    }
+}
+
+impl From<TagStatus> for String {
+    fn from(value: TagStatus) -> String {
+        match value {
+            TagStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TagStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TagStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TagStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TagStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TagStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource from which to remove tags. Currently, the only supported resource is an Amazon ECR repository.</p>
@@ -1009,6 +2746,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -1041,6 +2818,84 @@ pub struct UploadLayerPartRequest {
     pub upload_id: String,
 }
 
+impl UploadLayerPartRequest {
+    /// Returns a builder for `UploadLayerPartRequest`, checking its required members when built.
+    pub fn builder() -> UploadLayerPartRequestBuilder {
+        UploadLayerPartRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UploadLayerPartRequest`](struct.UploadLayerPartRequest.html).
+#[derive(Default, Debug)]
+pub struct UploadLayerPartRequestBuilder {
+    layer_part_blob: Option<bytes::Bytes>,
+    part_first_byte: Option<i64>,
+    part_last_byte: Option<i64>,
+    registry_id: Option<String>,
+    repository_name: Option<String>,
+    upload_id: Option<String>,
+}
+
+impl UploadLayerPartRequestBuilder {
+    /// Sets the [`layer_part_blob`](struct.UploadLayerPartRequest.html#structfield.layer_part_blob) member.
+    pub fn layer_part_blob<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.layer_part_blob = Some(value.into());
+        self
+    }
+
+    /// Sets the [`part_first_byte`](struct.UploadLayerPartRequest.html#structfield.part_first_byte) member.
+    pub fn part_first_byte<T: Into<i64>>(mut self, value: T) -> Self {
+        self.part_first_byte = Some(value.into());
+        self
+    }
+
+    /// Sets the [`part_last_byte`](struct.UploadLayerPartRequest.html#structfield.part_last_byte) member.
+    pub fn part_last_byte<T: Into<i64>>(mut self, value: T) -> Self {
+        self.part_last_byte = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registry_id`](struct.UploadLayerPartRequest.html#structfield.registry_id) member.
+    pub fn registry_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.registry_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repository_name`](struct.UploadLayerPartRequest.html#structfield.repository_name) member.
+    pub fn repository_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.repository_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`upload_id`](struct.UploadLayerPartRequest.html#structfield.upload_id) member.
+    pub fn upload_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.upload_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UploadLayerPartRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UploadLayerPartRequest, ::rusoto_core::BuildError> {
+        Ok(UploadLayerPartRequest {
+            layer_part_blob: self
+                .layer_part_blob
+                .ok_or(::rusoto_core::BuildError::missing("layer_part_blob"))?,
+            part_first_byte: self
+                .part_first_byte
+                .ok_or(::rusoto_core::BuildError::missing("part_first_byte"))?,
+            part_last_byte: self
+                .part_last_byte
+                .ok_or(::rusoto_core::BuildError::missing("part_last_byte"))?,
+            registry_id: self.registry_id,
+            repository_name: self
+                .repository_name
+                .ok_or(::rusoto_core::BuildError::missing("repository_name"))?,
+            upload_id: self
+                .upload_id
+                .ok_or(::rusoto_core::BuildError::missing("upload_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UploadLayerPartResponse {
@@ -2645,6 +4500,168 @@ pub trait Ecr {
         input: UploadLayerPartRequest,
     ) -> RusotoFuture<UploadLayerPartResponse, UploadLayerPartError>;
 }
+impl<T: ?Sized + Ecr> Ecr for ::std::sync::Arc<T> {
+    fn batch_check_layer_availability(
+        &self,
+        input: BatchCheckLayerAvailabilityRequest,
+    ) -> RusotoFuture<BatchCheckLayerAvailabilityResponse, BatchCheckLayerAvailabilityError> {
+        (**self).batch_check_layer_availability(input)
+    }
+    fn batch_delete_image(
+        &self,
+        input: BatchDeleteImageRequest,
+    ) -> RusotoFuture<BatchDeleteImageResponse, BatchDeleteImageError> {
+        (**self).batch_delete_image(input)
+    }
+    fn batch_get_image(
+        &self,
+        input: BatchGetImageRequest,
+    ) -> RusotoFuture<BatchGetImageResponse, BatchGetImageError> {
+        (**self).batch_get_image(input)
+    }
+    fn complete_layer_upload(
+        &self,
+        input: CompleteLayerUploadRequest,
+    ) -> RusotoFuture<CompleteLayerUploadResponse, CompleteLayerUploadError> {
+        (**self).complete_layer_upload(input)
+    }
+    fn create_repository(
+        &self,
+        input: CreateRepositoryRequest,
+    ) -> RusotoFuture<CreateRepositoryResponse, CreateRepositoryError> {
+        (**self).create_repository(input)
+    }
+    fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> RusotoFuture<DeleteLifecyclePolicyResponse, DeleteLifecyclePolicyError> {
+        (**self).delete_lifecycle_policy(input)
+    }
+    fn delete_repository(
+        &self,
+        input: DeleteRepositoryRequest,
+    ) -> RusotoFuture<DeleteRepositoryResponse, DeleteRepositoryError> {
+        (**self).delete_repository(input)
+    }
+    fn delete_repository_policy(
+        &self,
+        input: DeleteRepositoryPolicyRequest,
+    ) -> RusotoFuture<DeleteRepositoryPolicyResponse, DeleteRepositoryPolicyError> {
+        (**self).delete_repository_policy(input)
+    }
+    fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> RusotoFuture<DescribeImagesResponse, DescribeImagesError> {
+        (**self).describe_images(input)
+    }
+    fn describe_repositories(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> RusotoFuture<DescribeRepositoriesResponse, DescribeRepositoriesError> {
+        (**self).describe_repositories(input)
+    }
+    fn get_authorization_token(
+        &self,
+        input: GetAuthorizationTokenRequest,
+    ) -> RusotoFuture<GetAuthorizationTokenResponse, GetAuthorizationTokenError> {
+        (**self).get_authorization_token(input)
+    }
+    fn get_download_url_for_layer(
+        &self,
+        input: GetDownloadUrlForLayerRequest,
+    ) -> RusotoFuture<GetDownloadUrlForLayerResponse, GetDownloadUrlForLayerError> {
+        (**self).get_download_url_for_layer(input)
+    }
+    fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> RusotoFuture<GetLifecyclePolicyResponse, GetLifecyclePolicyError> {
+        (**self).get_lifecycle_policy(input)
+    }
+    fn get_lifecycle_policy_preview(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> RusotoFuture<GetLifecyclePolicyPreviewResponse, GetLifecyclePolicyPreviewError> {
+        (**self).get_lifecycle_policy_preview(input)
+    }
+    fn get_repository_policy(
+        &self,
+        input: GetRepositoryPolicyRequest,
+    ) -> RusotoFuture<GetRepositoryPolicyResponse, GetRepositoryPolicyError> {
+        (**self).get_repository_policy(input)
+    }
+    fn initiate_layer_upload(
+        &self,
+        input: InitiateLayerUploadRequest,
+    ) -> RusotoFuture<InitiateLayerUploadResponse, InitiateLayerUploadError> {
+        (**self).initiate_layer_upload(input)
+    }
+    fn list_images(
+        &self,
+        input: ListImagesRequest,
+    ) -> RusotoFuture<ListImagesResponse, ListImagesError> {
+        (**self).list_images(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_image(&self, input: PutImageRequest) -> RusotoFuture<PutImageResponse, PutImageError> {
+        (**self).put_image(input)
+    }
+    fn put_image_tag_mutability(
+        &self,
+        input: PutImageTagMutabilityRequest,
+    ) -> RusotoFuture<PutImageTagMutabilityResponse, PutImageTagMutabilityError> {
+        (**self).put_image_tag_mutability(input)
+    }
+    fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyRequest,
+    ) -> RusotoFuture<PutLifecyclePolicyResponse, PutLifecyclePolicyError> {
+        (**self).put_lifecycle_policy(input)
+    }
+    fn set_repository_policy(
+        &self,
+        input: SetRepositoryPolicyRequest,
+    ) -> RusotoFuture<SetRepositoryPolicyResponse, SetRepositoryPolicyError> {
+        (**self).set_repository_policy(input)
+    }
+    fn start_lifecycle_policy_preview(
+        &self,
+        input: StartLifecyclePolicyPreviewRequest,
+    ) -> RusotoFuture<StartLifecyclePolicyPreviewResponse, StartLifecyclePolicyPreviewError> {
+        (**self).start_lifecycle_policy_preview(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn upload_layer_part(
+        &self,
+        input: UploadLayerPartRequest,
+    ) -> RusotoFuture<UploadLayerPartResponse, UploadLayerPartError> {
+        (**self).upload_layer_part(input)
+    }
+}
+/// The error codes Amazon ECR retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon ECR API.
 #[derive(Clone)]
 pub struct EcrClient {
@@ -2660,6 +4677,24 @@ impl EcrClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ECR`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> EcrClient {
+        Self::new(region::Region::from_env_for_service("ECR"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `api.ecr`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> EcrClient {
+        Self::new_with_client(config.client().clone(), config.region_for("api.ecr", "ECR"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2678,7 +4713,49 @@ impl EcrClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EcrClient {
-        EcrClient { client, region }
+        EcrClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "api.ecr")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "api.ecr", "ecr", "ECR")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Ecr` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Ecr + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for EcrClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon ECR"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "api.ecr"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> EcrClient {
+        Self::new_with_client(client, region)
     }
 }
 
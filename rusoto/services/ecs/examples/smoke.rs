@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon EC2 Container Service clients.
+//!
+//! Makes a single read-only `ListAccountSettings` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_ecs::{Ecs, EcsClient, ListAccountSettingsRequest};
+
+fn main() {
+    let client = EcsClient::from_env();
+    match client
+        .list_account_settings(ListAccountSettingsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListAccountSettings succeeded"),
+        Err(error) => {
+            eprintln!("ListAccountSettings failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
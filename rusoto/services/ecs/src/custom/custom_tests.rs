@@ -0,0 +1,217 @@
+extern crate rusoto_mock;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::custom::deployments::{
+    classify_stopped_task, DeploymentWatchConfig, DeploymentWatchError, DeploymentWatcherExt,
+    StoppedTaskReason,
+};
+use crate::generated::{Container, EcsClient, Task};
+
+use self::rusoto_mock::*;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::waiter::WaiterConfig;
+use rusoto_core::{Region, RusotoError};
+
+fn target(request: &SignedRequest) -> String {
+    let value = &request.headers["x-amz-target"][0];
+    String::from_utf8(value.clone()).unwrap()
+}
+
+fn input(request: &SignedRequest) -> serde_json::Value {
+    match request.payload {
+        Some(SignedRequestPayload::Buffer(ref body)) => serde_json::from_slice(body).unwrap(),
+        _ => panic!("expected a buffered payload"),
+    }
+}
+
+/// A body answering `DescribeServices`, `ListTasks` and `DescribeTasks` at once.
+fn service_body(deployments: &str, stopped_reason: &str, container_reason: &str) -> String {
+    format!(
+        r#"{{
+            "services": [{{
+                "serviceArn": "arn:aws:ecs:us-east-1:123456789012:service/web",
+                "serviceName": "web",
+                "status": "ACTIVE",
+                "desiredCount": 2,
+                "runningCount": 2,
+                "taskDefinition": "arn:aws:ecs:us-east-1:123456789012:task-definition/web:2",
+                "deployments": [{}]
+            }}],
+            "taskArns": ["arn:aws:ecs:us-east-1:123456789012:task/1"],
+            "tasks": [{{
+                "taskArn": "arn:aws:ecs:us-east-1:123456789012:task/1",
+                "taskDefinitionArn": "arn:aws:ecs:us-east-1:123456789012:task-definition/web:2",
+                "startedBy": "ecs-svc/2",
+                "lastStatus": "STOPPED",
+                "stoppedReason": "{}",
+                "containers": [{{ "name": "web", "reason": "{}" }}]
+            }}]
+        }}"#,
+        deployments, stopped_reason, container_reason
+    )
+}
+
+const PRIMARY: &str = r#"{
+    "id": "ecs-svc/2",
+    "status": "PRIMARY",
+    "taskDefinition": "arn:aws:ecs:us-east-1:123456789012:task-definition/web:2",
+    "desiredCount": 2,
+    "runningCount": 2
+}"#;
+
+const ACTIVE: &str = r#"{
+    "id": "ecs-svc/1",
+    "status": "ACTIVE",
+    "taskDefinition": "arn:aws:ecs:us-east-1:123456789012:task-definition/web:1",
+    "desiredCount": 2,
+    "runningCount": 2
+}"#;
+
+fn watch_config(failure_threshold: Option<usize>, rollback: bool) -> DeploymentWatchConfig {
+    DeploymentWatchConfig {
+        waiter: WaiterConfig::new(Duration::from_millis(1), 2),
+        failure_threshold,
+        rollback,
+    }
+}
+
+#[test]
+fn wait_for_service_stable_returns_stable_service() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(&service_body(PRIMARY, "", ""))
+        .with_request_checker(|request: &SignedRequest| {
+            assert_eq!(
+                target(request),
+                "AmazonEC2ContainerServiceV20141113.DescribeServices"
+            );
+            let input = input(request);
+            assert_eq!(input["cluster"], "cluster");
+            assert_eq!(input["services"][0], "web");
+        });
+    let client = EcsClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let service = client
+        .wait_for_service_stable(Some("cluster"), "web", watch_config(Some(1), true))
+        .sync()
+        .unwrap();
+
+    assert_eq!(service.service_name, Some("web".to_owned()));
+}
+
+#[test]
+fn wait_for_service_stable_rolls_back_failed_deployments() {
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let recorded = updates.clone();
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(&service_body(
+            &format!("{}, {}", PRIMARY, ACTIVE),
+            "CannotPullContainerError: pull image manifest has been retried 5 time(s)",
+            "",
+        ))
+        .with_request_checker(
+            move |request: &SignedRequest| match target(request).as_str() {
+                "AmazonEC2ContainerServiceV20141113.ListTasks" => {
+                    let input = input(request);
+                    assert_eq!(input["serviceName"], "web");
+                    assert_eq!(input["desiredStatus"], "STOPPED");
+                }
+                "AmazonEC2ContainerServiceV20141113.UpdateService" => {
+                    recorded.lock().unwrap().push(input(request));
+                }
+                _ => {}
+            },
+        );
+    let client = EcsClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let result = client
+        .wait_for_service_stable(Some("cluster"), "web", watch_config(Some(1), true))
+        .sync();
+
+    match result {
+        Err(RusotoError::Service(DeploymentWatchError::DeploymentFailed {
+            stopped_tasks,
+            rolled_back_to,
+        })) => {
+            assert_eq!(stopped_tasks.len(), 1);
+            match stopped_tasks[0].reason {
+                StoppedTaskReason::ImagePullFailed(_) => {}
+                ref other => panic!("expected an image pull failure, got {:?}", other),
+            }
+            assert_eq!(
+                rolled_back_to,
+                Some("arn:aws:ecs:us-east-1:123456789012:task-definition/web:1".to_owned())
+            );
+        }
+        other => panic!("expected a failed deployment, got {:?}", other),
+    }
+    let updates = updates.lock().unwrap();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(
+        updates[0]["taskDefinition"],
+        "arn:aws:ecs:us-east-1:123456789012:task-definition/web:1"
+    );
+}
+
+#[test]
+fn wait_for_service_stable_times_out_below_failure_threshold() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(&service_body(
+        &format!("{}, {}", PRIMARY, ACTIVE),
+        "Essential container in task exited",
+        "OutOfMemoryError: Container killed due to memory usage",
+    ));
+    let client = EcsClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let result = client
+        .wait_for_service_stable(None, "web", watch_config(Some(2), false))
+        .sync();
+
+    match result {
+        Err(RusotoError::Service(DeploymentWatchError::TimedOut(_))) => {}
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+}
+
+#[test]
+fn classify_stopped_task_recognizes_reasons() {
+    let task = |stopped_reason: &str, container_reason: Option<&str>| Task {
+        stopped_reason: Some(stopped_reason.to_owned()),
+        containers: Some(vec![Container {
+            reason: container_reason.map(str::to_owned),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        classify_stopped_task(&task(
+            "Essential container in task exited",
+            Some("OutOfMemoryError: Container killed due to memory usage")
+        )),
+        StoppedTaskReason::OutOfMemory(
+            "Essential container in task exited \
+             (OutOfMemoryError: Container killed due to memory usage)"
+                .to_owned()
+        )
+    );
+    assert_eq!(
+        classify_stopped_task(&task(
+            "Task failed ELB health checks in (target-group tg)",
+            None
+        )),
+        StoppedTaskReason::HealthCheckFailed(
+            "Task failed ELB health checks in (target-group tg)".to_owned()
+        )
+    );
+    assert_eq!(
+        classify_stopped_task(&task("Essential container in task exited", None)),
+        StoppedTaskReason::EssentialContainerExited(
+            "Essential container in task exited".to_owned()
+        )
+    );
+    assert_eq!(
+        classify_stopped_task(&task("Task stopped by user", None)),
+        StoppedTaskReason::Other("Task stopped by user".to_owned())
+    );
+}
@@ -0,0 +1,489 @@
+//! Watching service deployments until they are stable, or have failed.
+//!
+//! `UpdateService` returns as soon as the new deployment is created. ECS then starts tasks of the
+//! new task definition and drains the old ones, retrying tasks which fail to start or become
+//! unhealthy forever. `wait_for_service_stable` polls `DescribeServices` until only the primary
+//! deployment is left and it runs the desired number of tasks.
+//!
+//! With a failure threshold set, the tasks of the primary deployment which have stopped are
+//! described on every attempt, and the deployment is considered failed once enough of them have
+//! stopped. The reasons they stopped, such as image pull failures, containers running out of
+//! memory or failed health checks, are returned as `StoppedTaskReason`s, and the service can be
+//! rolled back to the task definition it ran before.
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use futures::future::{self, Loop};
+use futures::Future;
+use rusoto_core::waiter::{poll_until, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    Deployment, DescribeServicesError, DescribeServicesRequest, DescribeTasksError,
+    DescribeTasksRequest, DesiredStatus, Ecs, ListTasksError, ListTasksRequest, Service, Task,
+    UpdateServiceError, UpdateServiceRequest,
+};
+
+/// `DescribeTasks` accepts up to 100 tasks per request.
+const DESCRIBE_TASKS_LIMIT: usize = 100;
+
+type WatchFuture<T> = Box<dyn Future<Item = T, Error = RusotoError<DeploymentWatchError>> + Send>;
+
+/// How to watch a deployment, and when to give up on it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeploymentWatchConfig {
+    /// How often, and for how long, to poll the service
+    pub waiter: WaiterConfig,
+    /// Number of stopped tasks of the primary deployment after which it is considered failed.
+    /// When `None`, stopped tasks are not looked at and the deployment can only time out.
+    pub failure_threshold: Option<usize>,
+    /// Whether to update the service to the previous task definition once the deployment failed
+    pub rollback: bool,
+}
+
+impl Default for DeploymentWatchConfig {
+    /// Polls every 15 seconds for up to 10 minutes, without failure detection.
+    fn default() -> DeploymentWatchConfig {
+        DeploymentWatchConfig {
+            waiter: WaiterConfig::new(Duration::from_secs(15), 40),
+            failure_threshold: None,
+            rollback: false,
+        }
+    }
+}
+
+/// Why a task of a deployment stopped.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StoppedTaskReason {
+    /// The image of a container could not be pulled, e.g. because it doesn't exist or the
+    /// task execution role can't access the registry.
+    ImagePullFailed(String),
+    /// A container was killed for exceeding its memory limit.
+    OutOfMemory(String),
+    /// The task failed container or load balancer health checks.
+    HealthCheckFailed(String),
+    /// An essential container exited.
+    EssentialContainerExited(String),
+    /// Any other reason, such as the task being stopped by a user.
+    Other(String),
+}
+
+impl StoppedTaskReason {
+    /// The message ECS gave for stopping the task.
+    pub fn message(&self) -> &str {
+        match *self {
+            StoppedTaskReason::ImagePullFailed(ref message)
+            | StoppedTaskReason::OutOfMemory(ref message)
+            | StoppedTaskReason::HealthCheckFailed(ref message)
+            | StoppedTaskReason::EssentialContainerExited(ref message)
+            | StoppedTaskReason::Other(ref message) => message,
+        }
+    }
+}
+
+/// A task of a deployment which stopped.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StoppedTask {
+    /// The ARN of the task
+    pub task_arn: String,
+    /// The task definition the task ran
+    pub task_definition_arn: Option<String>,
+    /// Why the task stopped
+    pub reason: StoppedTaskReason,
+}
+
+impl fmt::Display for StoppedTask {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.task_arn, self.reason.message())
+    }
+}
+
+/// Errors returned while watching a deployment.
+#[derive(Debug, PartialEq)]
+pub enum DeploymentWatchError {
+    /// Describing the service failed.
+    DescribeServices(DescribeServicesError),
+    /// Listing the stopped tasks of the service failed.
+    ListTasks(ListTasksError),
+    /// Describing the stopped tasks of the service failed.
+    DescribeTasks(DescribeTasksError),
+    /// Updating the service, to deploy or to roll back, failed.
+    UpdateService(UpdateServiceError),
+    /// The service doesn't exist or isn't active.
+    ServiceNotFound(String),
+    /// As many tasks of the primary deployment as the failure threshold have stopped.
+    DeploymentFailed {
+        /// The stopped tasks of the deployment
+        stopped_tasks: Vec<StoppedTask>,
+        /// The task definition the service was rolled back to, if it was
+        rolled_back_to: Option<String>,
+    },
+    /// The service did not become stable within the configured number of attempts.
+    TimedOut(String),
+}
+
+impl fmt::Display for DeploymentWatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DeploymentWatchError::DeploymentFailed {
+                ref stopped_tasks,
+                ref rolled_back_to,
+            } => {
+                write!(f, "{} tasks of the deployment stopped", stopped_tasks.len())?;
+                if let Some(task) = stopped_tasks.first() {
+                    write!(f, ", e.g. {}", task)?;
+                }
+                if let Some(ref task_definition) = *rolled_back_to {
+                    write!(f, "; rolled back to {}", task_definition)?;
+                }
+                Ok(())
+            }
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for DeploymentWatchError {
+    fn description(&self) -> &str {
+        match *self {
+            DeploymentWatchError::DescribeServices(ref cause) => cause.description(),
+            DeploymentWatchError::ListTasks(ref cause) => cause.description(),
+            DeploymentWatchError::DescribeTasks(ref cause) => cause.description(),
+            DeploymentWatchError::UpdateService(ref cause) => cause.description(),
+            DeploymentWatchError::ServiceNotFound(ref cause) => cause,
+            DeploymentWatchError::DeploymentFailed { .. } => "Deployment failed",
+            DeploymentWatchError::TimedOut(ref cause) => cause,
+        }
+    }
+}
+
+/// Works out why a task stopped from its stop reason and the reasons of its containers.
+pub fn classify_stopped_task(task: &Task) -> StoppedTaskReason {
+    let stopped_reason = task.stopped_reason.clone().unwrap_or_default();
+    let container_reasons = task
+        .containers
+        .iter()
+        .flat_map(|containers| containers.iter())
+        .filter_map(|container| container.reason.clone())
+        .collect::<Vec<_>>();
+    let mentions = |needle: &str| {
+        stopped_reason.contains(needle)
+            || container_reasons
+                .iter()
+                .any(|reason| reason.contains(needle))
+    };
+    let message = if container_reasons.is_empty() {
+        stopped_reason.clone()
+    } else {
+        format!("{} ({})", stopped_reason, container_reasons.join("; "))
+    };
+
+    if mentions("CannotPullContainerError") || mentions("CannotPullImageManifestError") {
+        StoppedTaskReason::ImagePullFailed(message)
+    } else if mentions("OutOfMemoryError") {
+        StoppedTaskReason::OutOfMemory(message)
+    } else if stopped_reason.to_lowercase().contains("health check") {
+        StoppedTaskReason::HealthCheckFailed(message)
+    } else if stopped_reason.starts_with("Essential container in task exited") {
+        StoppedTaskReason::EssentialContainerExited(message)
+    } else {
+        StoppedTaskReason::Other(message)
+    }
+}
+
+/// Returns true once the service has a single deployment running the desired number of tasks.
+pub fn is_service_stable(service: &Service) -> bool {
+    match service.deployments {
+        Some(ref deployments) if deployments.len() == 1 => {
+            deployments[0].running_count == service.desired_count
+        }
+        _ => false,
+    }
+}
+
+fn deployment_with_status<'a>(service: &'a Service, status: &str) -> Option<&'a Deployment> {
+    service
+        .deployments
+        .iter()
+        .flat_map(|deployments| deployments.iter())
+        .find(|deployment| deployment.status.as_ref().map(String::as_str) == Some(status))
+}
+
+/// Extension methods which watch service deployments.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_ecs::deployments::{DeploymentWatchConfig, DeploymentWatcherExt};
+/// use rusoto_ecs::{EcsClient, UpdateServiceRequest};
+///
+/// let client = EcsClient::new(Region::UsEast1);
+/// let service = client
+///     .update_service_and_wait(
+///         UpdateServiceRequest {
+///             cluster: Some("cluster".to_owned()),
+///             service: "web".to_owned(),
+///             task_definition: Some("web:42".to_owned()),
+///             ..Default::default()
+///         },
+///         DeploymentWatchConfig {
+///             failure_threshold: Some(3),
+///             rollback: true,
+///             ..Default::default()
+///         },
+///     )
+///     .sync()
+///     .unwrap();
+/// ```
+pub trait DeploymentWatcherExt: Ecs + Clone + Send + Sized + 'static {
+    /// Polls `DescribeServices` until the service is stable, and returns it.
+    ///
+    /// When `config.failure_threshold` is set, fails with `DeploymentWatchError::DeploymentFailed`
+    /// as soon as that many tasks of the primary deployment have stopped. With `config.rollback`,
+    /// the service is first updated to the task definition of its previous, `ACTIVE` deployment;
+    /// the rollback itself is not waited for.
+    fn wait_for_service_stable(
+        &self,
+        cluster: Option<&str>,
+        service: &str,
+        config: DeploymentWatchConfig,
+    ) -> RusotoFuture<Service, DeploymentWatchError> {
+        RusotoFuture::from_future(watch(
+            self.clone(),
+            cluster.map(str::to_owned),
+            service.to_owned(),
+            config,
+            None,
+        ))
+    }
+
+    /// Updates a service, then waits until it is stable like `wait_for_service_stable`.
+    ///
+    /// The task definition the service runs before the update is the one it is rolled back to.
+    fn update_service_and_wait(
+        &self,
+        input: UpdateServiceRequest,
+        config: DeploymentWatchConfig,
+    ) -> RusotoFuture<Service, DeploymentWatchError> {
+        let client = self.clone();
+        let cluster = input.cluster.clone();
+        let service = input.service.clone();
+
+        let future =
+            describe_service(self, cluster.clone(), service.clone()).and_then(move |current| {
+                let previous_task_definition = current.task_definition;
+                client
+                    .update_service(input)
+                    .map_err(|e| e.map_service(DeploymentWatchError::UpdateService))
+                    .and_then(move |_| {
+                        watch(client, cluster, service, config, previous_task_definition)
+                    })
+            });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: Ecs + Clone + Send + 'static> DeploymentWatcherExt for T {}
+
+fn watch<C: Ecs + Clone + Send + 'static>(
+    client: C,
+    cluster: Option<String>,
+    service: String,
+    config: DeploymentWatchConfig,
+    rollback_to: Option<String>,
+) -> WatchFuture<Service> {
+    let timeout = RusotoError::Service(DeploymentWatchError::TimedOut(format!(
+        "Service {} did not become stable",
+        service
+    )));
+    let DeploymentWatchConfig {
+        waiter,
+        failure_threshold,
+        rollback,
+    } = config;
+
+    poll_until(
+        waiter,
+        move || {
+            let client = client.clone();
+            let cluster = cluster.clone();
+            let rollback_to = rollback_to.clone();
+            describe_service(&client, cluster.clone(), service.clone()).and_then(
+                move |service| -> WatchFuture<Option<Service>> {
+                    if is_service_stable(&service) {
+                        return Box::new(future::ok(Some(service)));
+                    }
+                    match failure_threshold {
+                        Some(threshold) => {
+                            let rollback_to = if rollback { rollback_to } else { None };
+                            check_stopped_tasks(client, cluster, service, threshold, rollback_to)
+                        }
+                        None => Box::new(future::ok(None)),
+                    }
+                },
+            )
+        },
+        timeout,
+    )
+}
+
+/// Fails the deployment once `threshold` tasks of the primary deployment have stopped, rolling
+/// back to `rollback_to` or else to the task definition of the `ACTIVE` deployment when given.
+fn check_stopped_tasks<C: Ecs + Clone + Send + 'static>(
+    client: C,
+    cluster: Option<String>,
+    service: Service,
+    threshold: usize,
+    rollback_to: Option<String>,
+) -> WatchFuture<Option<Service>> {
+    let primary = match deployment_with_status(&service, "PRIMARY") {
+        Some(primary) => primary.clone(),
+        None => return Box::new(future::ok(None)),
+    };
+    let rollback_to = rollback_to.or_else(|| {
+        deployment_with_status(&service, "ACTIVE")
+            .and_then(|deployment| deployment.task_definition.clone())
+    });
+    let rollback_to = rollback_to
+        .filter(|task_definition| primary.task_definition.as_ref() != Some(task_definition));
+
+    Box::new(
+        stopped_tasks(&client, cluster.clone(), &service, &primary).and_then(
+            move |stopped_tasks| -> WatchFuture<Option<Service>> {
+                if stopped_tasks.len() < threshold {
+                    return Box::new(future::ok(None));
+                }
+                let failed = |rolled_back_to| {
+                    RusotoError::Service(DeploymentWatchError::DeploymentFailed {
+                        stopped_tasks,
+                        rolled_back_to,
+                    })
+                };
+                match rollback_to {
+                    Some(task_definition) => Box::new(
+                        roll_back(&client, cluster, &service, task_definition).and_then(
+                            move |task_definition| {
+                                Err::<Option<Service>, _>(failed(Some(task_definition)))
+                            },
+                        ),
+                    ),
+                    None => Box::new(future::err(failed(None))),
+                }
+            },
+        ),
+    )
+}
+
+fn describe_service<C: Ecs>(
+    client: &C,
+    cluster: Option<String>,
+    service: String,
+) -> impl Future<Item = Service, Error = RusotoError<DeploymentWatchError>> {
+    client
+        .describe_services(DescribeServicesRequest {
+            cluster,
+            services: vec![service.clone()],
+            ..Default::default()
+        })
+        .map_err(|e| e.map_service(DeploymentWatchError::DescribeServices))
+        .and_then(move |output| {
+            output
+                .services
+                .unwrap_or_default()
+                .into_iter()
+                .find(|found| found.status.as_ref().map(String::as_str) != Some("INACTIVE"))
+                .ok_or_else(|| {
+                    RusotoError::Service(DeploymentWatchError::ServiceNotFound(format!(
+                        "Service {} not found",
+                        service
+                    )))
+                })
+        })
+}
+
+/// Lists and describes the stopped tasks started by a deployment.
+fn stopped_tasks<C: Ecs + Clone + Send + 'static>(
+    client: &C,
+    cluster: Option<String>,
+    service: &Service,
+    deployment: &Deployment,
+) -> WatchFuture<Vec<StoppedTask>> {
+    let describe_client = client.clone();
+    let list_client = client.clone();
+    let deployment_id = deployment.id.clone();
+    let request = ListTasksRequest {
+        cluster: cluster.clone(),
+        service_name: service.service_name.clone(),
+        desired_status: Some(DesiredStatus::Stopped),
+        ..Default::default()
+    };
+
+    let task_arns = future::loop_fn((request, Vec::new()), move |(mut request, mut arns)| {
+        list_client
+            .list_tasks(request.clone())
+            .map_err(|e| e.map_service(DeploymentWatchError::ListTasks))
+            .map(move |output| {
+                arns.extend(output.task_arns.unwrap_or_default());
+                match output.next_token {
+                    Some(token) => {
+                        request.next_token = Some(token);
+                        Loop::Continue((request, arns))
+                    }
+                    None => Loop::Break(arns),
+                }
+            })
+    });
+
+    Box::new(task_arns.and_then(move |task_arns| {
+        let chunks = task_arns
+            .chunks(DESCRIBE_TASKS_LIMIT)
+            .map(|chunk| {
+                describe_client
+                    .describe_tasks(DescribeTasksRequest {
+                        cluster: cluster.clone(),
+                        tasks: chunk.to_vec(),
+                        ..Default::default()
+                    })
+                    .map_err(|e| e.map_service(DeploymentWatchError::DescribeTasks))
+            })
+            .collect::<Vec<_>>();
+
+        future::join_all(chunks).map(move |outputs| {
+            outputs
+                .into_iter()
+                .flat_map(|output| output.tasks.unwrap_or_default())
+                .filter(|task| deployment_id.is_some() && task.started_by == deployment_id)
+                .map(|task| StoppedTask {
+                    reason: classify_stopped_task(&task),
+                    task_arn: task.task_arn.unwrap_or_default(),
+                    task_definition_arn: task.task_definition_arn,
+                })
+                .collect()
+        })
+    }))
+}
+
+fn roll_back<C: Ecs>(
+    client: &C,
+    cluster: Option<String>,
+    service: &Service,
+    task_definition: String,
+) -> impl Future<Item = String, Error = RusotoError<DeploymentWatchError>> {
+    client
+        .update_service(UpdateServiceRequest {
+            cluster,
+            service: service
+                .service_arn
+                .clone()
+                .or_else(|| service.service_name.clone())
+                .unwrap_or_default(),
+            task_definition: Some(task_definition.clone()),
+            ..Default::default()
+        })
+        .map_err(|e| e.map_service(DeploymentWatchError::UpdateService))
+        .map(move |_| task_definition)
+}
@@ -1 +1,5 @@
+/// Watching service deployments until they are stable
+pub mod deployments;
 
+#[cfg(test)]
+mod custom_tests;
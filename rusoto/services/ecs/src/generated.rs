@@ -22,7 +22,168 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The status of the most recent agent update. If an update has never been requested, this value is <code>NULL</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AgentUpdateStatus {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>STAGING</code></p>
+    Staging,
+    /// <p><code>STAGED</code></p>
+    Staged,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>UPDATED</code></p>
+    Updated,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AgentUpdateStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AgentUpdateStatus::Pending => "PENDING",
+            AgentUpdateStatus::Staging => "STAGING",
+            AgentUpdateStatus::Staged => "STAGED",
+            AgentUpdateStatus::Updating => "UPDATING",
+            AgentUpdateStatus::Updated => "UPDATED",
+            AgentUpdateStatus::Failed => "FAILED",
+            AgentUpdateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AgentUpdateStatus {
+    fn from(value: &'a str) -> AgentUpdateStatus {
+        match value {
+            "PENDING" => AgentUpdateStatus::Pending,
+            "STAGING" => AgentUpdateStatus::Staging,
+            "STAGED" => AgentUpdateStatus::Staged,
+            "UPDATING" => AgentUpdateStatus::Updating,
+            "UPDATED" => AgentUpdateStatus::Updated,
+            "FAILED" => AgentUpdateStatus::Failed,
+            _ => AgentUpdateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AgentUpdateStatus {
+    fn from(value: String) -> AgentUpdateStatus {
+        AgentUpdateStatus::from(value.as_str())
+    }
+}
+
+impl From<AgentUpdateStatus> for String {
+    fn from(value: AgentUpdateStatus) -> String {
+        match value {
+            AgentUpdateStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AgentUpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AgentUpdateStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AgentUpdateStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AgentUpdateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AgentUpdateStatus::from(value))
+    }
+}
+
+/// <p>Whether the task's elastic network interface receives a public IP address. The default value is <code>DISABLED</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AssignPublicIp {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AssignPublicIp {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssignPublicIp::Enabled => "ENABLED",
+            AssignPublicIp::Disabled => "DISABLED",
+            AssignPublicIp::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AssignPublicIp {
+    fn from(value: &'a str) -> AssignPublicIp {
+        match value {
+            "ENABLED" => AssignPublicIp::Enabled,
+            "DISABLED" => AssignPublicIp::Disabled,
+            _ => AssignPublicIp::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssignPublicIp {
+    fn from(value: String) -> AssignPublicIp {
+        AssignPublicIp::from(value.as_str())
+    }
+}
+
+impl From<AssignPublicIp> for String {
+    fn from(value: AssignPublicIp) -> String {
+        match value {
+            AssignPublicIp::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AssignPublicIp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AssignPublicIp {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssignPublicIp {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AssignPublicIp, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AssignPublicIp::from(value))
+    }
+}
+
 /// <p>An object representing a container instance or task attachment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -69,7 +230,7 @@ pub struct Attribute {
     /// <p>The type of the target with which to attach the attribute. This parameter is required if you use the short form ID for a resource instead of the full ARN.</p>
     #[serde(rename = "targetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_type: Option<String>,
+    pub target_type: Option<TargetType>,
     /// <p>The value of the attribute. Up to 128 letters (uppercase and lowercase), numbers, hyphens, underscores, periods, at signs (@), forward slashes, colons, and spaces are allowed.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -82,7 +243,7 @@ pub struct AwsVpcConfiguration {
     /// <p>Whether the task's elastic network interface receives a public IP address. The default value is <code>DISABLED</code>.</p>
     #[serde(rename = "assignPublicIp")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assign_public_ip: Option<String>,
+    pub assign_public_ip: Option<AssignPublicIp>,
     /// <p><p>The security groups associated with the task or service. If you do not specify a security group, the default security group for the VPC is used. There is a limit of 5 security groups that can be specified per <code>AwsVpcConfiguration</code>.</p> <note> <p>All specified security groups must be from the same VPC.</p> </note></p>
     #[serde(rename = "securityGroups")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -138,19 +299,319 @@ pub struct Cluster {
     pub tags: Option<Vec<Tag>>,
 }
 
+/// <p><p>Additional information about your clusters to be separated by launch type, including:</p> <ul> <li> <p>runningEC2TasksCount</p> </li> <li> <p>runningFargateTasksCount</p> </li> <li> <p>pendingEC2TasksCount</p> </li> <li> <p>pendingFargateTasksCount</p> </li> <li> <p>activeEC2ServiceCount</p> </li> <li> <p>activeFargateServiceCount</p> </li> <li> <p>drainingEC2ServiceCount</p> </li> <li> <p>drainingFargateServiceCount</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClusterField {
+    /// <p><code>ATTACHMENTS</code></p>
+    Attachments,
+    /// <p><code>CONFIGURATIONS</code></p>
+    Configurations,
+    /// <p><code>SETTINGS</code></p>
+    Settings,
+    /// <p><code>STATISTICS</code></p>
+    Statistics,
+    /// <p><code>TAGS</code></p>
+    Tags,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ClusterField {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterField::Attachments => "ATTACHMENTS",
+            ClusterField::Configurations => "CONFIGURATIONS",
+            ClusterField::Settings => "SETTINGS",
+            ClusterField::Statistics => "STATISTICS",
+            ClusterField::Tags => "TAGS",
+            ClusterField::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ClusterField {
+    fn from(value: &'a str) -> ClusterField {
+        match value {
+            "ATTACHMENTS" => ClusterField::Attachments,
+            "CONFIGURATIONS" => ClusterField::Configurations,
+            "SETTINGS" => ClusterField::Settings,
+            "STATISTICS" => ClusterField::Statistics,
+            "TAGS" => ClusterField::Tags,
+            _ => ClusterField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterField {
+    fn from(value: String) -> ClusterField {
+        ClusterField::from(value.as_str())
+    }
+}
+
+impl From<ClusterField> for String {
+    fn from(value: ClusterField) -> String {
+        match value {
+            ClusterField::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ClusterField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ClusterField {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterField {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ClusterField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ClusterField::from(value))
+    }
+}
+
 /// <p>The settings to use when creating a cluster. This parameter is used to enable CloudWatch Container Insights for a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ClusterSetting {
     /// <p>The name of the cluster setting. The only supported value is <code>containerInsights</code>.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<ClusterSettingName>,
     /// <p>The value to set for the cluster setting. The supported values are <code>enabled</code> and <code>disabled</code>. If <code>enabled</code> is specified, CloudWatch Container Insights will be enabled for the cluster, otherwise it will be disabled unless the <code>containerInsights</code> account setting is enabled. If a cluster value is specified, it will override the <code>containerInsights</code> value set with <a>PutAccountSetting</a> or <a>PutAccountSettingDefault</a>.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+/// <p>The name of the cluster setting. The only supported value is <code>containerInsights</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClusterSettingName {
+    /// <p><code>containerInsights</code></p>
+    ContainerInsights,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ClusterSettingName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterSettingName::ContainerInsights => "containerInsights",
+            ClusterSettingName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ClusterSettingName {
+    fn from(value: &'a str) -> ClusterSettingName {
+        match value {
+            "containerInsights" => ClusterSettingName::ContainerInsights,
+            _ => ClusterSettingName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterSettingName {
+    fn from(value: String) -> ClusterSettingName {
+        ClusterSettingName::from(value.as_str())
+    }
+}
+
+impl From<ClusterSettingName> for String {
+    fn from(value: ClusterSettingName) -> String {
+        match value {
+            ClusterSettingName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ClusterSettingName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ClusterSettingName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterSettingName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ClusterSettingName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ClusterSettingName::from(value))
+    }
+}
+
+/// <p>The launch type required by the task. If no value is specified, it defaults to <code>EC2</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Compatibility {
+    /// <p><code>EC2</code></p>
+    Ec2,
+    /// <p><code>FARGATE</code></p>
+    Fargate,
+    /// <p><code>EXTERNAL</code></p>
+    External,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Compatibility {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Compatibility::Ec2 => "EC2",
+            Compatibility::Fargate => "FARGATE",
+            Compatibility::External => "EXTERNAL",
+            Compatibility::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Compatibility {
+    fn from(value: &'a str) -> Compatibility {
+        match value {
+            "EC2" => Compatibility::Ec2,
+            "FARGATE" => Compatibility::Fargate,
+            "EXTERNAL" => Compatibility::External,
+            _ => Compatibility::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Compatibility {
+    fn from(value: String) -> Compatibility {
+        Compatibility::from(value.as_str())
+    }
+}
+
+impl From<Compatibility> for String {
+    fn from(value: Compatibility) -> String {
+        match value {
+            Compatibility::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Compatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Compatibility {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Compatibility {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Compatibility, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Compatibility::from(value))
+    }
+}
+
+/// <p>The connectivity status of a task.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// <p><code>CONNECTED</code></p>
+    Connected,
+    /// <p><code>DISCONNECTED</code></p>
+    Disconnected,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Connectivity {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Connectivity::Connected => "CONNECTED",
+            Connectivity::Disconnected => "DISCONNECTED",
+            Connectivity::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Connectivity {
+    fn from(value: &'a str) -> Connectivity {
+        match value {
+            "CONNECTED" => Connectivity::Connected,
+            "DISCONNECTED" => Connectivity::Disconnected,
+            _ => Connectivity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Connectivity {
+    fn from(value: String) -> Connectivity {
+        Connectivity::from(value.as_str())
+    }
+}
+
+impl From<Connectivity> for String {
+    fn from(value: Connectivity) -> String {
+        match value {
+            Connectivity::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Connectivity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Connectivity {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Connectivity {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Connectivity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Connectivity::from(value))
+    }
+}
+
 /// <p>A Docker container that is part of a task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -178,7 +639,7 @@ pub struct Container {
     /// <p>The health status of the container. If health checks are not configured for this container in its task definition, then it reports the health status as <code>UNKNOWN</code>.</p>
     #[serde(rename = "healthStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_status: Option<String>,
+    pub health_status: Option<HealthStatus>,
     /// <p>The last known status of the container.</p>
     #[serde(rename = "lastStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -217,6 +678,86 @@ pub struct Container {
     pub task_arn: Option<String>,
 }
 
+/// <p><p>The dependency condition of the container. The following are the available conditions and their behavior:</p> <ul> <li> <p> <code>START</code> - This condition emulates the behavior of links and volumes today. It validates that a dependent container is started before permitting other containers to start.</p> </li> <li> <p> <code>COMPLETE</code> - This condition validates that a dependent container runs to completion (exits) before permitting other containers to start. This can be useful for nonessential containers that run a script and then exit.</p> </li> <li> <p> <code>SUCCESS</code> - This condition is the same as <code>COMPLETE</code>, but it also requires that the container exits with a <code>zero</code> status.</p> </li> <li> <p> <code>HEALTHY</code> - This condition validates that the dependent container passes its Docker health check before permitting other containers to start. This requires that the dependent container has health checks configured. This condition is confirmed only at task startup.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContainerCondition {
+    /// <p><code>START</code> - This condition emulates the behavior of links and volumes today. It validates that a dependent container is started before permitting other containers to start.</p>
+    Start,
+    /// <p><code>COMPLETE</code> - This condition validates that a dependent container runs to completion (exits) before permitting other containers to start. This can be useful for nonessential containers that run a script and then exit.</p>
+    Complete,
+    /// <p><code>SUCCESS</code> - This condition is the same as <code>COMPLETE</code>, but it also requires that the container exits with a <code>zero</code> status.</p>
+    Success,
+    /// <p><code>HEALTHY</code> - This condition validates that the dependent container passes its Docker health check before permitting other containers to start. This requires that the dependent container has health checks configured. This condition is confirmed only at task startup.</p>
+    Healthy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContainerCondition {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContainerCondition::Start => "START",
+            ContainerCondition::Complete => "COMPLETE",
+            ContainerCondition::Success => "SUCCESS",
+            ContainerCondition::Healthy => "HEALTHY",
+            ContainerCondition::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContainerCondition {
+    fn from(value: &'a str) -> ContainerCondition {
+        match value {
+            "START" => ContainerCondition::Start,
+            "COMPLETE" => ContainerCondition::Complete,
+            "SUCCESS" => ContainerCondition::Success,
+            "HEALTHY" => ContainerCondition::Healthy,
+            _ => ContainerCondition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContainerCondition {
+    fn from(value: String) -> ContainerCondition {
+        ContainerCondition::from(value.as_str())
+    }
+}
+
+impl From<ContainerCondition> for String {
+    fn from(value: ContainerCondition) -> String {
+        match value {
+            ContainerCondition::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContainerCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ContainerCondition {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContainerCondition {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContainerCondition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContainerCondition::from(value))
+    }
+}
+
 /// <p>Container definitions are used in task definitions to describe the different containers that are launched as part of a task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContainerDefinition {
@@ -375,11 +916,11 @@ pub struct ContainerDefinition {
 }
 
 /// <p><p>The dependencies defined for container startup and shutdown. A container can contain multiple dependencies. When a dependency is defined for container startup, for container shutdown it is reversed.</p> <p>Your Amazon ECS container instances require at least version 1.26.0 of the container agent to enable container dependencies. However, we recommend using the latest container agent version. For information about checking your agent version and updating to the latest version, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-update.html">Updating the Amazon ECS Container Agent</a> in the <i>Amazon Elastic Container Service Developer Guide</i>. If you are using an Amazon ECS-optimized Linux AMI, your instance needs at least version 1.26.0-1 of the <code>ecs-init</code> package. If your container instances are launched from version <code>20190301</code> or later, then they contain the required versions of the container agent and <code>ecs-init</code>. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-optimized_AMI.html">Amazon ECS-optimized Linux AMI</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>If you are using tasks that use the Fargate launch type, container dependency parameters are not supported.</p> </note></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContainerDependency {
     /// <p><p>The dependency condition of the container. The following are the available conditions and their behavior:</p> <ul> <li> <p> <code>START</code> - This condition emulates the behavior of links and volumes today. It validates that a dependent container is started before permitting other containers to start.</p> </li> <li> <p> <code>COMPLETE</code> - This condition validates that a dependent container runs to completion (exits) before permitting other containers to start. This can be useful for nonessential containers that run a script and then exit.</p> </li> <li> <p> <code>SUCCESS</code> - This condition is the same as <code>COMPLETE</code>, but it also requires that the container exits with a <code>zero</code> status.</p> </li> <li> <p> <code>HEALTHY</code> - This condition validates that the dependent container passes its Docker health check before permitting other containers to start. This requires that the dependent container has health checks configured. This condition is confirmed only at task startup.</p> </li> </ul></p>
     #[serde(rename = "condition")]
-    pub condition: String,
+    pub condition: ContainerCondition,
     /// <p>The name of a container.</p>
     #[serde(rename = "containerName")]
     pub container_name: String,
@@ -396,7 +937,7 @@ pub struct ContainerInstance {
     /// <p>The status of the most recent agent update. If an update has never been requested, this value is <code>NULL</code>.</p>
     #[serde(rename = "agentUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub agent_update_status: Option<String>,
+    pub agent_update_status: Option<AgentUpdateStatus>,
     /// <p>The resources attached to a container instance, such as elastic network interfaces.</p>
     #[serde(rename = "attachments")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -419,8 +960,9 @@ pub struct ContainerInstance {
     pub pending_tasks_count: Option<i64>,
     /// <p>The Unix timestamp for when the container instance was registered.</p>
     #[serde(rename = "registeredAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub registered_at: Option<f64>,
+    pub registered_at: Option<EpochTimestamp>,
     /// <p>For CPU and memory resource types, this parameter describes the amount of each resource that was available on the container instance when the container agent registered it with Amazon ECS. This value represents the total amount of CPU and memory that can be allocated on this container instance to tasks. For port resource types, this parameter describes the ports that were reserved by the Amazon ECS container agent when it registered the container instance with Amazon ECS.</p>
     #[serde(rename = "registeredResources")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -455,44 +997,200 @@ pub struct ContainerInstance {
     pub version_info: Option<VersionInfo>,
 }
 
-/// <p>The overrides that should be sent to a container. An empty container override can be passed in. An example of an empty container override would be <code>{"containerOverrides": [ ] }</code>. If a non-empty container override is specified, the <code>name</code> parameter must be included.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ContainerOverride {
-    /// <p>The command to send to the container that overrides the default command from the Docker image or the task definition. You must also specify a container name.</p>
-    #[serde(rename = "command")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub command: Option<Vec<String>>,
-    /// <p>The number of <code>cpu</code> units reserved for the container, instead of the default value from the task definition. You must also specify a container name.</p>
-    #[serde(rename = "cpu")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cpu: Option<i64>,
-    /// <p>The environment variables to send to the container. You can add new environment variables, which are added to the container at launch, or you can override the existing environment variables from the Docker image or the task definition. You must also specify a container name.</p>
-    #[serde(rename = "environment")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub environment: Option<Vec<KeyValuePair>>,
-    /// <p>The hard limit (in MiB) of memory to present to the container, instead of the default value from the task definition. If your container attempts to exceed the memory specified here, the container is killed. You must also specify a container name.</p>
-    #[serde(rename = "memory")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub memory: Option<i64>,
-    /// <p>The soft limit (in MiB) of memory to reserve for the container, instead of the default value from the task definition. You must also specify a container name.</p>
-    #[serde(rename = "memoryReservation")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub memory_reservation: Option<i64>,
-    /// <p>The name of the container that receives the override. This parameter is required if any override is specified.</p>
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The type and amount of a resource to assign to a container, instead of the default value from the task definition. The only supported resource is a GPU.</p>
-    #[serde(rename = "resourceRequirements")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_requirements: Option<Vec<ResourceRequirement>>,
+/// <p>Specifies whether you want to see the resource tags for the container instance. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContainerInstanceField {
+    /// <p><code>TAGS</code></p>
+    Tags,
+    /// <p><code>CONTAINER_INSTANCE_HEALTH</code></p>
+    ContainerInstanceHealth,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>An object representing a change in state for a container.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ContainerStateChange {
-    /// <p>The name of the container.</p>
-    #[serde(rename = "containerName")]
+impl ContainerInstanceField {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContainerInstanceField::Tags => "TAGS",
+            ContainerInstanceField::ContainerInstanceHealth => "CONTAINER_INSTANCE_HEALTH",
+            ContainerInstanceField::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContainerInstanceField {
+    fn from(value: &'a str) -> ContainerInstanceField {
+        match value {
+            "TAGS" => ContainerInstanceField::Tags,
+            "CONTAINER_INSTANCE_HEALTH" => ContainerInstanceField::ContainerInstanceHealth,
+            _ => ContainerInstanceField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContainerInstanceField {
+    fn from(value: String) -> ContainerInstanceField {
+        ContainerInstanceField::from(value.as_str())
+    }
+}
+
+impl From<ContainerInstanceField> for String {
+    fn from(value: ContainerInstanceField) -> String {
+        match value {
+            ContainerInstanceField::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContainerInstanceField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ContainerInstanceField {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContainerInstanceField {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContainerInstanceField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContainerInstanceField::from(value))
+    }
+}
+
+/// <p>Filters the container instances by status. For example, if you specify the <code>DRAINING</code> status, the results include only container instances that have been set to <code>DRAINING</code> using <a>UpdateContainerInstancesState</a>. If you do not specify this parameter, the default is to include container instances set to all states other than <code>INACTIVE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContainerInstanceStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DRAINING</code></p>
+    Draining,
+    /// <p><code>REGISTERING</code></p>
+    Registering,
+    /// <p><code>DEREGISTERING</code></p>
+    Deregistering,
+    /// <p><code>REGISTRATION_FAILED</code></p>
+    RegistrationFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContainerInstanceStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContainerInstanceStatus::Active => "ACTIVE",
+            ContainerInstanceStatus::Draining => "DRAINING",
+            ContainerInstanceStatus::Registering => "REGISTERING",
+            ContainerInstanceStatus::Deregistering => "DEREGISTERING",
+            ContainerInstanceStatus::RegistrationFailed => "REGISTRATION_FAILED",
+            ContainerInstanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContainerInstanceStatus {
+    fn from(value: &'a str) -> ContainerInstanceStatus {
+        match value {
+            "ACTIVE" => ContainerInstanceStatus::Active,
+            "DRAINING" => ContainerInstanceStatus::Draining,
+            "REGISTERING" => ContainerInstanceStatus::Registering,
+            "DEREGISTERING" => ContainerInstanceStatus::Deregistering,
+            "REGISTRATION_FAILED" => ContainerInstanceStatus::RegistrationFailed,
+            _ => ContainerInstanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContainerInstanceStatus {
+    fn from(value: String) -> ContainerInstanceStatus {
+        ContainerInstanceStatus::from(value.as_str())
+    }
+}
+
+impl From<ContainerInstanceStatus> for String {
+    fn from(value: ContainerInstanceStatus) -> String {
+        match value {
+            ContainerInstanceStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContainerInstanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ContainerInstanceStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContainerInstanceStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContainerInstanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContainerInstanceStatus::from(value))
+    }
+}
+
+/// <p>The overrides that should be sent to a container. An empty container override can be passed in. An example of an empty container override would be <code>{"containerOverrides": [ ] }</code>. If a non-empty container override is specified, the <code>name</code> parameter must be included.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerOverride {
+    /// <p>The command to send to the container that overrides the default command from the Docker image or the task definition. You must also specify a container name.</p>
+    #[serde(rename = "command")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<Vec<String>>,
+    /// <p>The number of <code>cpu</code> units reserved for the container, instead of the default value from the task definition. You must also specify a container name.</p>
+    #[serde(rename = "cpu")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<i64>,
+    /// <p>The environment variables to send to the container. You can add new environment variables, which are added to the container at launch, or you can override the existing environment variables from the Docker image or the task definition. You must also specify a container name.</p>
+    #[serde(rename = "environment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<Vec<KeyValuePair>>,
+    /// <p>The hard limit (in MiB) of memory to present to the container, instead of the default value from the task definition. If your container attempts to exceed the memory specified here, the container is killed. You must also specify a container name.</p>
+    #[serde(rename = "memory")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<i64>,
+    /// <p>The soft limit (in MiB) of memory to reserve for the container, instead of the default value from the task definition. You must also specify a container name.</p>
+    #[serde(rename = "memoryReservation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_reservation: Option<i64>,
+    /// <p>The name of the container that receives the override. This parameter is required if any override is specified.</p>
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The type and amount of a resource to assign to a container, instead of the default value from the task definition. The only supported resource is a GPU.</p>
+    #[serde(rename = "resourceRequirements")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_requirements: Option<Vec<ResourceRequirement>>,
+}
+
+/// <p>An object representing a change in state for a container.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ContainerStateChange {
+    /// <p>The name of the container.</p>
+    #[serde(rename = "containerName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub container_name: Option<String>,
     /// <p>The exit code for the container, if the state change is a result of the container exiting.</p>
@@ -533,6 +1231,50 @@ pub struct CreateClusterRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateClusterRequest {
+    /// Returns a builder for `CreateClusterRequest`, checking its required members when built.
+    pub fn builder() -> CreateClusterRequestBuilder {
+        CreateClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateClusterRequest`](struct.CreateClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateClusterRequestBuilder {
+    cluster_name: Option<String>,
+    settings: Option<Vec<ClusterSetting>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateClusterRequestBuilder {
+    /// Sets the [`cluster_name`](struct.CreateClusterRequest.html#structfield.cluster_name) member.
+    pub fn cluster_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`settings`](struct.CreateClusterRequest.html#structfield.settings) member.
+    pub fn settings<T: Into<Vec<ClusterSetting>>>(mut self, value: T) -> Self {
+        self.settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateClusterRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateClusterRequest, ::rusoto_core::BuildError> {
+        Ok(CreateClusterRequest {
+            cluster_name: self.cluster_name,
+            settings: self.settings,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateClusterResponse {
@@ -575,7 +1317,7 @@ pub struct CreateServiceRequest {
     /// <p>The launch type on which to run your service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>A load balancer object representing the load balancers to use with your service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/service-load-balancing.html">Service Load Balancing</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>If the service is using the rolling update (<code>ECS</code>) deployment controller and using either an Application Load Balancer or Network Load Balancer, you can specify multiple target groups to attach to the service.</p> <p>If the service is using the <code>CODE_DEPLOY</code> deployment controller, the service is required to use either an Application Load Balancer or Network Load Balancer. When creating an AWS CodeDeploy deployment group, you specify two target groups (referred to as a <code>targetGroupPair</code>). During a deployment, AWS CodeDeploy determines which task set in your service has the status <code>PRIMARY</code> and associates one target group with it, and then associates the other target group with the replacement task set. The load balancer can also have up to two listeners: a required listener for production traffic and an optional listener that allows you perform validation tests with Lambda functions before routing production traffic to it.</p> <p>After you create a service using the <code>ECS</code> deployment controller, the load balancer name or target group ARN, container name, and container port specified in the service definition are immutable. If you are using the <code>CODE_DEPLOY</code> deployment controller, these values can be changed when updating the service.</p> <p>For Application Load Balancers and Network Load Balancers, this object must contain the load balancer target group ARN, the container name (as it appears in a container definition), and the container port to access from the load balancer. When a task from this service is placed on a container instance, the container instance and port combination is registered as a target in the target group specified here.</p> <p>For Classic Load Balancers, this object must contain the load balancer name, the container name (as it appears in a container definition), and the container port to access from the load balancer. When a task from this service is placed on a container instance, the container instance is registered with the load balancer specified here.</p> <p>Services with tasks that use the <code>awsvpc</code> network mode (for example, those with the Fargate launch type) only support Application Load Balancers and Network Load Balancers. Classic Load Balancers are not supported. Also, when you create any target groups for these services, you must choose <code>ip</code> as the target type, not <code>instance</code>, because tasks that use the <code>awsvpc</code> network mode are associated with an elastic network interface, not an Amazon EC2 instance.</p>
     #[serde(rename = "loadBalancers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -599,7 +1341,7 @@ pub struct CreateServiceRequest {
     /// <p>Specifies whether to propagate the tags from the task definition or the service to the tasks in the service. If no value is specified, the tags are not propagated. Tags can only be propagated to the tasks within the service during service creation. To add tags to a task after service creation, use the <a>TagResource</a> API action.</p>
     #[serde(rename = "propagateTags")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub propagate_tags: Option<String>,
+    pub propagate_tags: Option<PropagateTags>,
     /// <p>The name or full Amazon Resource Name (ARN) of the IAM role that allows Amazon ECS to make calls to your load balancer on your behalf. This parameter is only permitted if you are using a load balancer with your service and your task definition does not use the <code>awsvpc</code> network mode. If you specify the <code>role</code> parameter, you must also specify a load balancer object with the <code>loadBalancers</code> parameter.</p> <important> <p>If your account has already created the Amazon ECS service-linked role, that role is used by default for your service unless you specify a role here. The service-linked role is required if your task definition uses the <code>awsvpc</code> network mode, in which case you should not specify a role here. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/using-service-linked-roles.html">Using Service-Linked Roles for Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> </important> <p>If your specified role has a path other than <code>/</code>, then you must either specify the full role ARN (this is recommended) or prefix the role name with the path. For example, if a role with the name <code>bar</code> has a path of <code>/foo/</code> then you would specify <code>/foo/bar</code> as the role name. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_identifiers.html#identifiers-friendly-names">Friendly Names and Paths</a> in the <i>IAM User Guide</i>.</p>
     #[serde(rename = "role")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -607,7 +1349,7 @@ pub struct CreateServiceRequest {
     /// <p><p>The scheduling strategy to use for the service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Services</a>.</p> <p>There are two service scheduler strategies available:</p> <ul> <li> <p> <code>REPLICA</code>-The replica scheduling strategy places and maintains the desired number of tasks across your cluster. By default, the service scheduler spreads tasks across Availability Zones. You can use task placement strategies and constraints to customize task placement decisions. This scheduler strategy is required if the service is using the <code>CODE<em>DEPLOY</code> or <code>EXTERNAL</code> deployment controller types.</p> </li> <li> <p> <code>DAEMON</code>-The daemon scheduling strategy deploys exactly one task on each active container instance that meets all of the task placement constraints that you specify in your cluster. When you&#39;re using this strategy, you don&#39;t need to specify a desired number of tasks, a task placement strategy, or use Service Auto Scaling policies.</p> <note> <p>Tasks using the Fargate launch type or the <code>CODE</em>DEPLOY</code> or <code>EXTERNAL</code> deployment controller types don&#39;t support the <code>DAEMON</code> scheduling strategy.</p> </note> </li> </ul></p>
     #[serde(rename = "schedulingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduling_strategy: Option<String>,
+    pub scheduling_strategy: Option<SchedulingStrategy>,
     /// <p>The name of your service. Up to 255 letters (uppercase and lowercase), numbers, and hyphens are allowed. Service names must be unique within a cluster, but you can have similarly named services in multiple clusters within a Region or across multiple Regions.</p>
     #[serde(rename = "serviceName")]
     pub service_name: String,
@@ -625,6 +1367,188 @@ pub struct CreateServiceRequest {
     pub task_definition: Option<String>,
 }
 
+impl CreateServiceRequest {
+    /// Returns a builder for `CreateServiceRequest`, checking its required members when built.
+    pub fn builder() -> CreateServiceRequestBuilder {
+        CreateServiceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateServiceRequest`](struct.CreateServiceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateServiceRequestBuilder {
+    client_token: Option<String>,
+    cluster: Option<String>,
+    deployment_configuration: Option<DeploymentConfiguration>,
+    deployment_controller: Option<DeploymentController>,
+    desired_count: Option<i64>,
+    enable_ecs_managed_tags: Option<bool>,
+    health_check_grace_period_seconds: Option<i64>,
+    launch_type: Option<LaunchType>,
+    load_balancers: Option<Vec<LoadBalancer>>,
+    network_configuration: Option<NetworkConfiguration>,
+    placement_constraints: Option<Vec<PlacementConstraint>>,
+    placement_strategy: Option<Vec<PlacementStrategy>>,
+    platform_version: Option<String>,
+    propagate_tags: Option<PropagateTags>,
+    role: Option<String>,
+    scheduling_strategy: Option<SchedulingStrategy>,
+    service_name: Option<String>,
+    service_registries: Option<Vec<ServiceRegistry>>,
+    tags: Option<Vec<Tag>>,
+    task_definition: Option<String>,
+}
+
+impl CreateServiceRequestBuilder {
+    /// Sets the [`client_token`](struct.CreateServiceRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster`](struct.CreateServiceRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_configuration`](struct.CreateServiceRequest.html#structfield.deployment_configuration) member.
+    pub fn deployment_configuration<T: Into<DeploymentConfiguration>>(mut self, value: T) -> Self {
+        self.deployment_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_controller`](struct.CreateServiceRequest.html#structfield.deployment_controller) member.
+    pub fn deployment_controller<T: Into<DeploymentController>>(mut self, value: T) -> Self {
+        self.deployment_controller = Some(value.into());
+        self
+    }
+
+    /// Sets the [`desired_count`](struct.CreateServiceRequest.html#structfield.desired_count) member.
+    pub fn desired_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.desired_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_ecs_managed_tags`](struct.CreateServiceRequest.html#structfield.enable_ecs_managed_tags) member.
+    pub fn enable_ecs_managed_tags<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_ecs_managed_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_grace_period_seconds`](struct.CreateServiceRequest.html#structfield.health_check_grace_period_seconds) member.
+    pub fn health_check_grace_period_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.health_check_grace_period_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`launch_type`](struct.CreateServiceRequest.html#structfield.launch_type) member.
+    pub fn launch_type<T: Into<LaunchType>>(mut self, value: T) -> Self {
+        self.launch_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancers`](struct.CreateServiceRequest.html#structfield.load_balancers) member.
+    pub fn load_balancers<T: Into<Vec<LoadBalancer>>>(mut self, value: T) -> Self {
+        self.load_balancers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_configuration`](struct.CreateServiceRequest.html#structfield.network_configuration) member.
+    pub fn network_configuration<T: Into<NetworkConfiguration>>(mut self, value: T) -> Self {
+        self.network_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_constraints`](struct.CreateServiceRequest.html#structfield.placement_constraints) member.
+    pub fn placement_constraints<T: Into<Vec<PlacementConstraint>>>(mut self, value: T) -> Self {
+        self.placement_constraints = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_strategy`](struct.CreateServiceRequest.html#structfield.placement_strategy) member.
+    pub fn placement_strategy<T: Into<Vec<PlacementStrategy>>>(mut self, value: T) -> Self {
+        self.placement_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_version`](struct.CreateServiceRequest.html#structfield.platform_version) member.
+    pub fn platform_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`propagate_tags`](struct.CreateServiceRequest.html#structfield.propagate_tags) member.
+    pub fn propagate_tags<T: Into<PropagateTags>>(mut self, value: T) -> Self {
+        self.propagate_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.CreateServiceRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduling_strategy`](struct.CreateServiceRequest.html#structfield.scheduling_strategy) member.
+    pub fn scheduling_strategy<T: Into<SchedulingStrategy>>(mut self, value: T) -> Self {
+        self.scheduling_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_name`](struct.CreateServiceRequest.html#structfield.service_name) member.
+    pub fn service_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_registries`](struct.CreateServiceRequest.html#structfield.service_registries) member.
+    pub fn service_registries<T: Into<Vec<ServiceRegistry>>>(mut self, value: T) -> Self {
+        self.service_registries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateServiceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_definition`](struct.CreateServiceRequest.html#structfield.task_definition) member.
+    pub fn task_definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_definition = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateServiceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateServiceRequest, ::rusoto_core::BuildError> {
+        Ok(CreateServiceRequest {
+            client_token: self.client_token,
+            cluster: self.cluster,
+            deployment_configuration: self.deployment_configuration,
+            deployment_controller: self.deployment_controller,
+            desired_count: self.desired_count,
+            enable_ecs_managed_tags: self.enable_ecs_managed_tags,
+            health_check_grace_period_seconds: self.health_check_grace_period_seconds,
+            launch_type: self.launch_type,
+            load_balancers: self.load_balancers,
+            network_configuration: self.network_configuration,
+            placement_constraints: self.placement_constraints,
+            placement_strategy: self.placement_strategy,
+            platform_version: self.platform_version,
+            propagate_tags: self.propagate_tags,
+            role: self.role,
+            scheduling_strategy: self.scheduling_strategy,
+            service_name: self
+                .service_name
+                .ok_or(::rusoto_core::BuildError::missing("service_name"))?,
+            service_registries: self.service_registries,
+            tags: self.tags,
+            task_definition: self.task_definition,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateServiceResponse {
@@ -650,7 +1574,7 @@ pub struct CreateTaskSetRequest {
     /// <p>The launch type that new tasks in the task set will use. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>A load balancer object representing the load balancer to use with the task set. The supported load balancer types are either an Application Load Balancer or a Network Load Balancer.</p>
     #[serde(rename = "loadBalancers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -677,6 +1601,120 @@ pub struct CreateTaskSetRequest {
     pub task_definition: String,
 }
 
+impl CreateTaskSetRequest {
+    /// Returns a builder for `CreateTaskSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateTaskSetRequestBuilder {
+        CreateTaskSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTaskSetRequest`](struct.CreateTaskSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTaskSetRequestBuilder {
+    client_token: Option<String>,
+    cluster: Option<String>,
+    external_id: Option<String>,
+    launch_type: Option<LaunchType>,
+    load_balancers: Option<Vec<LoadBalancer>>,
+    network_configuration: Option<NetworkConfiguration>,
+    platform_version: Option<String>,
+    scale: Option<Scale>,
+    service: Option<String>,
+    service_registries: Option<Vec<ServiceRegistry>>,
+    task_definition: Option<String>,
+}
+
+impl CreateTaskSetRequestBuilder {
+    /// Sets the [`client_token`](struct.CreateTaskSetRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster`](struct.CreateTaskSetRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`external_id`](struct.CreateTaskSetRequest.html#structfield.external_id) member.
+    pub fn external_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.external_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`launch_type`](struct.CreateTaskSetRequest.html#structfield.launch_type) member.
+    pub fn launch_type<T: Into<LaunchType>>(mut self, value: T) -> Self {
+        self.launch_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancers`](struct.CreateTaskSetRequest.html#structfield.load_balancers) member.
+    pub fn load_balancers<T: Into<Vec<LoadBalancer>>>(mut self, value: T) -> Self {
+        self.load_balancers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_configuration`](struct.CreateTaskSetRequest.html#structfield.network_configuration) member.
+    pub fn network_configuration<T: Into<NetworkConfiguration>>(mut self, value: T) -> Self {
+        self.network_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_version`](struct.CreateTaskSetRequest.html#structfield.platform_version) member.
+    pub fn platform_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scale`](struct.CreateTaskSetRequest.html#structfield.scale) member.
+    pub fn scale<T: Into<Scale>>(mut self, value: T) -> Self {
+        self.scale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service`](struct.CreateTaskSetRequest.html#structfield.service) member.
+    pub fn service<T: Into<String>>(mut self, value: T) -> Self {
+        self.service = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_registries`](struct.CreateTaskSetRequest.html#structfield.service_registries) member.
+    pub fn service_registries<T: Into<Vec<ServiceRegistry>>>(mut self, value: T) -> Self {
+        self.service_registries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_definition`](struct.CreateTaskSetRequest.html#structfield.task_definition) member.
+    pub fn task_definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_definition = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTaskSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTaskSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTaskSetRequest {
+            client_token: self.client_token,
+            cluster: self
+                .cluster
+                .ok_or(::rusoto_core::BuildError::missing("cluster"))?,
+            external_id: self.external_id,
+            launch_type: self.launch_type,
+            load_balancers: self.load_balancers,
+            network_configuration: self.network_configuration,
+            platform_version: self.platform_version,
+            scale: self.scale,
+            service: self
+                .service
+                .ok_or(::rusoto_core::BuildError::missing("service"))?,
+            service_registries: self.service_registries,
+            task_definition: self
+                .task_definition
+                .ok_or(::rusoto_core::BuildError::missing("task_definition"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateTaskSetResponse {
@@ -685,17 +1723,57 @@ pub struct CreateTaskSetResponse {
     pub task_set: Option<TaskSet>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteAccountSettingRequest {
     /// <p>The resource name for which to disable the account setting. If <code>serviceLongArnFormat</code> is specified, the ARN for your Amazon ECS services is affected. If <code>taskLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS tasks is affected. If <code>containerInstanceLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS container instances is affected. If <code>awsvpcTrunking</code> is specified, the ENI limit for your Amazon ECS container instances is affected.</p>
     #[serde(rename = "name")]
-    pub name: String,
+    pub name: SettingName,
     /// <p>The ARN of the principal, which can be an IAM user, IAM role, or the root user. If you specify the root user, it disables the account setting for all IAM users, IAM roles, and the root user of the account unless an IAM user or role explicitly overrides these settings. If this field is omitted, the setting is changed only for the authenticated user.</p>
     #[serde(rename = "principalArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub principal_arn: Option<String>,
 }
 
+impl DeleteAccountSettingRequest {
+    /// Returns a builder for `DeleteAccountSettingRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAccountSettingRequestBuilder {
+        DeleteAccountSettingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAccountSettingRequest`](struct.DeleteAccountSettingRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAccountSettingRequestBuilder {
+    name: Option<SettingName>,
+    principal_arn: Option<String>,
+}
+
+impl DeleteAccountSettingRequestBuilder {
+    /// Sets the [`name`](struct.DeleteAccountSettingRequest.html#structfield.name) member.
+    pub fn name<T: Into<SettingName>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_arn`](struct.DeleteAccountSettingRequest.html#structfield.principal_arn) member.
+    pub fn principal_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAccountSettingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAccountSettingRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAccountSettingRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            principal_arn: self.principal_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteAccountSettingResponse {
@@ -716,6 +1794,46 @@ pub struct DeleteAttributesRequest {
     pub cluster: Option<String>,
 }
 
+impl DeleteAttributesRequest {
+    /// Returns a builder for `DeleteAttributesRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAttributesRequestBuilder {
+        DeleteAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAttributesRequest`](struct.DeleteAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAttributesRequestBuilder {
+    attributes: Option<Vec<Attribute>>,
+    cluster: Option<String>,
+}
+
+impl DeleteAttributesRequestBuilder {
+    /// Sets the [`attributes`](struct.DeleteAttributesRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<Vec<Attribute>>>(mut self, value: T) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster`](struct.DeleteAttributesRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAttributesRequest {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            cluster: self.cluster,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteAttributesResponse {
@@ -732,6 +1850,36 @@ pub struct DeleteClusterRequest {
     pub cluster: String,
 }
 
+impl DeleteClusterRequest {
+    /// Returns a builder for `DeleteClusterRequest`, checking its required members when built.
+    pub fn builder() -> DeleteClusterRequestBuilder {
+        DeleteClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteClusterRequest`](struct.DeleteClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteClusterRequestBuilder {
+    cluster: Option<String>,
+}
+
+impl DeleteClusterRequestBuilder {
+    /// Sets the [`cluster`](struct.DeleteClusterRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteClusterRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteClusterRequest {
+            cluster: self
+                .cluster
+                .ok_or(::rusoto_core::BuildError::missing("cluster"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteClusterResponse {
@@ -756,6 +1904,52 @@ pub struct DeleteServiceRequest {
     pub service: String,
 }
 
+impl DeleteServiceRequest {
+    /// Returns a builder for `DeleteServiceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteServiceRequestBuilder {
+        DeleteServiceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteServiceRequest`](struct.DeleteServiceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteServiceRequestBuilder {
+    cluster: Option<String>,
+    force: Option<bool>,
+    service: Option<String>,
+}
+
+impl DeleteServiceRequestBuilder {
+    /// Sets the [`cluster`](struct.DeleteServiceRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force`](struct.DeleteServiceRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service`](struct.DeleteServiceRequest.html#structfield.service) member.
+    pub fn service<T: Into<String>>(mut self, value: T) -> Self {
+        self.service = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteServiceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteServiceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteServiceRequest {
+            cluster: self.cluster,
+            force: self.force,
+            service: self
+                .service
+                .ok_or(::rusoto_core::BuildError::missing("service"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteServiceResponse {
@@ -782,22 +1976,81 @@ pub struct DeleteTaskSetRequest {
     pub task_set: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DeleteTaskSetResponse {
-    #[serde(rename = "taskSet")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_set: Option<TaskSet>,
+impl DeleteTaskSetRequest {
+    /// Returns a builder for `DeleteTaskSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTaskSetRequestBuilder {
+        DeleteTaskSetRequestBuilder::default()
+    }
 }
 
-/// <p>The details of an Amazon ECS service deployment. This is used only when a service uses the <code>ECS</code> deployment controller type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Deployment {
-    /// <p>The Unix timestamp for when the service deployment was created.</p>
+/// Builds a [`DeleteTaskSetRequest`](struct.DeleteTaskSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTaskSetRequestBuilder {
+    cluster: Option<String>,
+    force: Option<bool>,
+    service: Option<String>,
+    task_set: Option<String>,
+}
+
+impl DeleteTaskSetRequestBuilder {
+    /// Sets the [`cluster`](struct.DeleteTaskSetRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force`](struct.DeleteTaskSetRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service`](struct.DeleteTaskSetRequest.html#structfield.service) member.
+    pub fn service<T: Into<String>>(mut self, value: T) -> Self {
+        self.service = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_set`](struct.DeleteTaskSetRequest.html#structfield.task_set) member.
+    pub fn task_set<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_set = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTaskSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTaskSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTaskSetRequest {
+            cluster: self
+                .cluster
+                .ok_or(::rusoto_core::BuildError::missing("cluster"))?,
+            force: self.force,
+            service: self
+                .service
+                .ok_or(::rusoto_core::BuildError::missing("service"))?,
+            task_set: self
+                .task_set
+                .ok_or(::rusoto_core::BuildError::missing("task_set"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteTaskSetResponse {
+    #[serde(rename = "taskSet")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_set: Option<TaskSet>,
+}
+
+/// <p>The details of an Amazon ECS service deployment. This is used only when a service uses the <code>ECS</code> deployment controller type.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Deployment {
+    /// <p>The Unix timestamp for when the service deployment was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The most recent desired count of tasks that was specified for the service to deploy or maintain.</p>
     #[serde(rename = "desiredCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -809,7 +2062,7 @@ pub struct Deployment {
     /// <p>The launch type the tasks in the service are using. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>The VPC subnet and security group configuration for tasks that receive their own elastic network interface by using the <code>awsvpc</code> networking mode.</p>
     #[serde(rename = "networkConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -836,8 +2089,9 @@ pub struct Deployment {
     pub task_definition: Option<String>,
     /// <p>The Unix timestamp for when the service deployment was last updated.</p>
     #[serde(rename = "updatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    pub updated_at: Option<EpochTimestamp>,
 }
 
 /// <p>Optional deployment parameters that control how many tasks run during a deployment and the ordering of stopping and starting tasks.</p>
@@ -854,11 +2108,87 @@ pub struct DeploymentConfiguration {
 }
 
 /// <p>The deployment controller to use for the service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/deployment-types.html">Amazon ECS Deployment Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeploymentController {
     /// <p><p>The deployment controller type to use.</p> <p>There are three deployment controller types available:</p> <dl> <dt>ECS</dt> <dd> <p>The rolling update (<code>ECS</code>) deployment type involves replacing the current running version of the container with the latest version. The number of containers Amazon ECS adds or removes from the service during a rolling update is controlled by adjusting the minimum and maximum number of healthy tasks allowed during a service deployment, as specified in the <a>DeploymentConfiguration</a>.</p> </dd> <dt>CODE<em>DEPLOY</dt> <dd> <p>The blue/green (<code>CODE</em>DEPLOY</code>) deployment type uses the blue/green deployment model powered by AWS CodeDeploy, which allows you to verify a new deployment of a service before sending production traffic to it.</p> </dd> <dt>EXTERNAL</dt> <dd> <p>The external (<code>EXTERNAL</code>) deployment type enables you to use any third-party deployment controller for full control over the deployment process for an Amazon ECS service.</p> </dd> </dl></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: DeploymentControllerType,
+}
+
+/// <p><p>The deployment controller type to use.</p> <p>There are three deployment controller types available:</p> <dl> <dt>ECS</dt> <dd> <p>The rolling update (<code>ECS</code>) deployment type involves replacing the current running version of the container with the latest version. The number of containers Amazon ECS adds or removes from the service during a rolling update is controlled by adjusting the minimum and maximum number of healthy tasks allowed during a service deployment, as specified in the <a>DeploymentConfiguration</a>.</p> </dd> <dt>CODE<em>DEPLOY</dt> <dd> <p>The blue/green (<code>CODE</em>DEPLOY</code>) deployment type uses the blue/green deployment model powered by AWS CodeDeploy, which allows you to verify a new deployment of a service before sending production traffic to it.</p> </dd> <dt>EXTERNAL</dt> <dd> <p>The external (<code>EXTERNAL</code>) deployment type enables you to use any third-party deployment controller for full control over the deployment process for an Amazon ECS service.</p> </dd> </dl></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentControllerType {
+    /// <p><code>ECS</code></p>
+    Ecs,
+    /// <p><code>CODE_DEPLOY</code></p>
+    CodeDeploy,
+    /// <p><code>EXTERNAL</code></p>
+    External,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeploymentControllerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentControllerType::Ecs => "ECS",
+            DeploymentControllerType::CodeDeploy => "CODE_DEPLOY",
+            DeploymentControllerType::External => "EXTERNAL",
+            DeploymentControllerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentControllerType {
+    fn from(value: &'a str) -> DeploymentControllerType {
+        match value {
+            "ECS" => DeploymentControllerType::Ecs,
+            "CODE_DEPLOY" => DeploymentControllerType::CodeDeploy,
+            "EXTERNAL" => DeploymentControllerType::External,
+            _ => DeploymentControllerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentControllerType {
+    fn from(value: String) -> DeploymentControllerType {
+        DeploymentControllerType::from(value.as_str())
+    }
+}
+
+impl From<DeploymentControllerType> for String {
+    fn from(value: DeploymentControllerType) -> String {
+        match value {
+            DeploymentControllerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentControllerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeploymentControllerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentControllerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeploymentControllerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeploymentControllerType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -876,6 +2206,54 @@ pub struct DeregisterContainerInstanceRequest {
     pub force: Option<bool>,
 }
 
+impl DeregisterContainerInstanceRequest {
+    /// Returns a builder for `DeregisterContainerInstanceRequest`, checking its required members when built.
+    pub fn builder() -> DeregisterContainerInstanceRequestBuilder {
+        DeregisterContainerInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterContainerInstanceRequest`](struct.DeregisterContainerInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeregisterContainerInstanceRequestBuilder {
+    cluster: Option<String>,
+    container_instance: Option<String>,
+    force: Option<bool>,
+}
+
+impl DeregisterContainerInstanceRequestBuilder {
+    /// Sets the [`cluster`](struct.DeregisterContainerInstanceRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`container_instance`](struct.DeregisterContainerInstanceRequest.html#structfield.container_instance) member.
+    pub fn container_instance<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_instance = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force`](struct.DeregisterContainerInstanceRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterContainerInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterContainerInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(DeregisterContainerInstanceRequest {
+            cluster: self.cluster,
+            container_instance: self
+                .container_instance
+                .ok_or(::rusoto_core::BuildError::missing("container_instance"))?,
+            force: self.force,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeregisterContainerInstanceResponse {
@@ -892,6 +2270,38 @@ pub struct DeregisterTaskDefinitionRequest {
     pub task_definition: String,
 }
 
+impl DeregisterTaskDefinitionRequest {
+    /// Returns a builder for `DeregisterTaskDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> DeregisterTaskDefinitionRequestBuilder {
+        DeregisterTaskDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterTaskDefinitionRequest`](struct.DeregisterTaskDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeregisterTaskDefinitionRequestBuilder {
+    task_definition: Option<String>,
+}
+
+impl DeregisterTaskDefinitionRequestBuilder {
+    /// Sets the [`task_definition`](struct.DeregisterTaskDefinitionRequest.html#structfield.task_definition) member.
+    pub fn task_definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_definition = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterTaskDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterTaskDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(DeregisterTaskDefinitionRequest {
+            task_definition: self
+                .task_definition
+                .ok_or(::rusoto_core::BuildError::missing("task_definition"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeregisterTaskDefinitionResponse {
@@ -910,7 +2320,45 @@ pub struct DescribeClustersRequest {
     /// <p><p>Additional information about your clusters to be separated by launch type, including:</p> <ul> <li> <p>runningEC2TasksCount</p> </li> <li> <p>runningFargateTasksCount</p> </li> <li> <p>pendingEC2TasksCount</p> </li> <li> <p>pendingFargateTasksCount</p> </li> <li> <p>activeEC2ServiceCount</p> </li> <li> <p>activeFargateServiceCount</p> </li> <li> <p>drainingEC2ServiceCount</p> </li> <li> <p>drainingFargateServiceCount</p> </li> </ul></p>
     #[serde(rename = "include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<String>>,
+    pub include: Option<Vec<ClusterField>>,
+}
+
+impl DescribeClustersRequest {
+    /// Returns a builder for `DescribeClustersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeClustersRequestBuilder {
+        DescribeClustersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClustersRequest`](struct.DescribeClustersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeClustersRequestBuilder {
+    clusters: Option<Vec<String>>,
+    include: Option<Vec<ClusterField>>,
+}
+
+impl DescribeClustersRequestBuilder {
+    /// Sets the [`clusters`](struct.DescribeClustersRequest.html#structfield.clusters) member.
+    pub fn clusters<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.clusters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include`](struct.DescribeClustersRequest.html#structfield.include) member.
+    pub fn include<T: Into<Vec<ClusterField>>>(mut self, value: T) -> Self {
+        self.include = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClustersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClustersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeClustersRequest {
+            clusters: self.clusters,
+            include: self.include,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -938,7 +2386,55 @@ pub struct DescribeContainerInstancesRequest {
     /// <p>Specifies whether you want to see the resource tags for the container instance. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
     #[serde(rename = "include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<String>>,
+    pub include: Option<Vec<ContainerInstanceField>>,
+}
+
+impl DescribeContainerInstancesRequest {
+    /// Returns a builder for `DescribeContainerInstancesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeContainerInstancesRequestBuilder {
+        DescribeContainerInstancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeContainerInstancesRequest`](struct.DescribeContainerInstancesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeContainerInstancesRequestBuilder {
+    cluster: Option<String>,
+    container_instances: Option<Vec<String>>,
+    include: Option<Vec<ContainerInstanceField>>,
+}
+
+impl DescribeContainerInstancesRequestBuilder {
+    /// Sets the [`cluster`](struct.DescribeContainerInstancesRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`container_instances`](struct.DescribeContainerInstancesRequest.html#structfield.container_instances) member.
+    pub fn container_instances<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.container_instances = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include`](struct.DescribeContainerInstancesRequest.html#structfield.include) member.
+    pub fn include<T: Into<Vec<ContainerInstanceField>>>(mut self, value: T) -> Self {
+        self.include = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeContainerInstancesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeContainerInstancesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeContainerInstancesRequest {
+            cluster: self.cluster,
+            container_instances: self
+                .container_instances
+                .ok_or(::rusoto_core::BuildError::missing("container_instances"))?,
+            include: self.include,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -963,12 +2459,60 @@ pub struct DescribeServicesRequest {
     /// <p>Specifies whether you want to see the resource tags for the service. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
     #[serde(rename = "include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<String>>,
+    pub include: Option<Vec<ServiceField>>,
     /// <p>A list of services to describe. You may specify up to 10 services to describe in a single operation.</p>
     #[serde(rename = "services")]
     pub services: Vec<String>,
 }
 
+impl DescribeServicesRequest {
+    /// Returns a builder for `DescribeServicesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeServicesRequestBuilder {
+        DescribeServicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeServicesRequest`](struct.DescribeServicesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeServicesRequestBuilder {
+    cluster: Option<String>,
+    include: Option<Vec<ServiceField>>,
+    services: Option<Vec<String>>,
+}
+
+impl DescribeServicesRequestBuilder {
+    /// Sets the [`cluster`](struct.DescribeServicesRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include`](struct.DescribeServicesRequest.html#structfield.include) member.
+    pub fn include<T: Into<Vec<ServiceField>>>(mut self, value: T) -> Self {
+        self.include = Some(value.into());
+        self
+    }
+
+    /// Sets the [`services`](struct.DescribeServicesRequest.html#structfield.services) member.
+    pub fn services<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.services = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeServicesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeServicesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeServicesRequest {
+            cluster: self.cluster,
+            include: self.include,
+            services: self
+                .services
+                .ok_or(::rusoto_core::BuildError::missing("services"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeServicesResponse {
@@ -987,12 +2531,52 @@ pub struct DescribeTaskDefinitionRequest {
     /// <p>Specifies whether to see the resource tags for the task definition. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
     #[serde(rename = "include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<String>>,
+    pub include: Option<Vec<TaskDefinitionField>>,
     /// <p>The <code>family</code> for the latest <code>ACTIVE</code> revision, <code>family</code> and <code>revision</code> (<code>family:revision</code>) for a specific revision in the family, or full Amazon Resource Name (ARN) of the task definition to describe.</p>
     #[serde(rename = "taskDefinition")]
     pub task_definition: String,
 }
 
+impl DescribeTaskDefinitionRequest {
+    /// Returns a builder for `DescribeTaskDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTaskDefinitionRequestBuilder {
+        DescribeTaskDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTaskDefinitionRequest`](struct.DescribeTaskDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTaskDefinitionRequestBuilder {
+    include: Option<Vec<TaskDefinitionField>>,
+    task_definition: Option<String>,
+}
+
+impl DescribeTaskDefinitionRequestBuilder {
+    /// Sets the [`include`](struct.DescribeTaskDefinitionRequest.html#structfield.include) member.
+    pub fn include<T: Into<Vec<TaskDefinitionField>>>(mut self, value: T) -> Self {
+        self.include = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_definition`](struct.DescribeTaskDefinitionRequest.html#structfield.task_definition) member.
+    pub fn task_definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_definition = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTaskDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTaskDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTaskDefinitionRequest {
+            include: self.include,
+            task_definition: self
+                .task_definition
+                .ok_or(::rusoto_core::BuildError::missing("task_definition"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeTaskDefinitionResponse {
@@ -1020,6 +2604,56 @@ pub struct DescribeTaskSetsRequest {
     pub task_sets: Option<Vec<String>>,
 }
 
+impl DescribeTaskSetsRequest {
+    /// Returns a builder for `DescribeTaskSetsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTaskSetsRequestBuilder {
+        DescribeTaskSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTaskSetsRequest`](struct.DescribeTaskSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTaskSetsRequestBuilder {
+    cluster: Option<String>,
+    service: Option<String>,
+    task_sets: Option<Vec<String>>,
+}
+
+impl DescribeTaskSetsRequestBuilder {
+    /// Sets the [`cluster`](struct.DescribeTaskSetsRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service`](struct.DescribeTaskSetsRequest.html#structfield.service) member.
+    pub fn service<T: Into<String>>(mut self, value: T) -> Self {
+        self.service = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_sets`](struct.DescribeTaskSetsRequest.html#structfield.task_sets) member.
+    pub fn task_sets<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.task_sets = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTaskSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTaskSetsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTaskSetsRequest {
+            cluster: self
+                .cluster
+                .ok_or(::rusoto_core::BuildError::missing("cluster"))?,
+            service: self
+                .service
+                .ok_or(::rusoto_core::BuildError::missing("service"))?,
+            task_sets: self.task_sets,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeTaskSetsResponse {
@@ -1042,12 +2676,58 @@ pub struct DescribeTasksRequest {
     /// <p>Specifies whether you want to see the resource tags for the task. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
     #[serde(rename = "include")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include: Option<Vec<String>>,
+    pub include: Option<Vec<TaskField>>,
     /// <p>A list of up to 100 task IDs or full ARN entries.</p>
     #[serde(rename = "tasks")]
     pub tasks: Vec<String>,
 }
 
+impl DescribeTasksRequest {
+    /// Returns a builder for `DescribeTasksRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTasksRequestBuilder {
+        DescribeTasksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTasksRequest`](struct.DescribeTasksRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTasksRequestBuilder {
+    cluster: Option<String>,
+    include: Option<Vec<TaskField>>,
+    tasks: Option<Vec<String>>,
+}
+
+impl DescribeTasksRequestBuilder {
+    /// Sets the [`cluster`](struct.DescribeTasksRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include`](struct.DescribeTasksRequest.html#structfield.include) member.
+    pub fn include<T: Into<Vec<TaskField>>>(mut self, value: T) -> Self {
+        self.include = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tasks`](struct.DescribeTasksRequest.html#structfield.tasks) member.
+    pub fn tasks<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tasks = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTasksRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTasksRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTasksRequest {
+            cluster: self.cluster,
+            include: self.include,
+            tasks: self
+                .tasks
+                .ok_or(::rusoto_core::BuildError::missing("tasks"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeTasksResponse {
@@ -1061,6 +2741,82 @@ pub struct DescribeTasksResponse {
     pub tasks: Option<Vec<Task>>,
 }
 
+/// <p><p>The task desired status with which to filter the <code>ListTasks</code> results. Specifying a <code>desiredStatus</code> of <code>STOPPED</code> limits the results to tasks that Amazon ECS has set the desired status to <code>STOPPED</code>. This can be useful for debugging tasks that are not starting properly or have died or finished. The default status filter is <code>RUNNING</code>, which shows tasks that Amazon ECS has set the desired status to <code>RUNNING</code>.</p> <note> <p>Although you can filter results based on a desired status of <code>PENDING</code>, this does not return any results. Amazon ECS never sets the desired status of a task to that value (only a task&#39;s <code>lastStatus</code> may have a value of <code>PENDING</code>).</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DesiredStatus {
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>STOPPED</code></p>
+    Stopped,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DesiredStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DesiredStatus::Running => "RUNNING",
+            DesiredStatus::Pending => "PENDING",
+            DesiredStatus::Stopped => "STOPPED",
+            DesiredStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DesiredStatus {
+    fn from(value: &'a str) -> DesiredStatus {
+        match value {
+            "RUNNING" => DesiredStatus::Running,
+            "PENDING" => DesiredStatus::Pending,
+            "STOPPED" => DesiredStatus::Stopped,
+            _ => DesiredStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DesiredStatus {
+    fn from(value: String) -> DesiredStatus {
+        DesiredStatus::from(value.as_str())
+    }
+}
+
+impl From<DesiredStatus> for String {
+    fn from(value: DesiredStatus) -> String {
+        match value {
+            DesiredStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DesiredStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DesiredStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DesiredStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DesiredStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DesiredStatus::from(value))
+    }
+}
+
 /// <p>An object representing a container instance host device.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Device {
@@ -1074,7 +2830,83 @@ pub struct Device {
     /// <p>The explicit permissions to provide to the container for the device. By default, the container has permissions for <code>read</code>, <code>write</code>, and <code>mknod</code> for the device.</p>
     #[serde(rename = "permissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Vec<String>>,
+    pub permissions: Option<Vec<DeviceCgroupPermission>>,
+}
+
+/// <p>The explicit permissions to provide to the container for the device. By default, the container has permissions for <code>read</code>, <code>write</code>, and <code>mknod</code> for the device.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceCgroupPermission {
+    /// <p><code>read</code></p>
+    Read,
+    /// <p><code>write</code></p>
+    Write,
+    /// <p><code>mknod</code></p>
+    Mknod,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceCgroupPermission {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceCgroupPermission::Read => "read",
+            DeviceCgroupPermission::Write => "write",
+            DeviceCgroupPermission::Mknod => "mknod",
+            DeviceCgroupPermission::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeviceCgroupPermission {
+    fn from(value: &'a str) -> DeviceCgroupPermission {
+        match value {
+            "read" => DeviceCgroupPermission::Read,
+            "write" => DeviceCgroupPermission::Write,
+            "mknod" => DeviceCgroupPermission::Mknod,
+            _ => DeviceCgroupPermission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceCgroupPermission {
+    fn from(value: String) -> DeviceCgroupPermission {
+        DeviceCgroupPermission::from(value.as_str())
+    }
+}
+
+impl From<DeviceCgroupPermission> for String {
+    fn from(value: DeviceCgroupPermission) -> String {
+        match value {
+            DeviceCgroupPermission::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceCgroupPermission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeviceCgroupPermission {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceCgroupPermission {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeviceCgroupPermission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceCgroupPermission::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1089,6 +2921,44 @@ pub struct DiscoverPollEndpointRequest {
     pub container_instance: Option<String>,
 }
 
+impl DiscoverPollEndpointRequest {
+    /// Returns a builder for `DiscoverPollEndpointRequest`, checking its required members when built.
+    pub fn builder() -> DiscoverPollEndpointRequestBuilder {
+        DiscoverPollEndpointRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DiscoverPollEndpointRequest`](struct.DiscoverPollEndpointRequest.html).
+#[derive(Default, Debug)]
+pub struct DiscoverPollEndpointRequestBuilder {
+    cluster: Option<String>,
+    container_instance: Option<String>,
+}
+
+impl DiscoverPollEndpointRequestBuilder {
+    /// Sets the [`cluster`](struct.DiscoverPollEndpointRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`container_instance`](struct.DiscoverPollEndpointRequest.html#structfield.container_instance) member.
+    pub fn container_instance<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_instance = Some(value.into());
+        self
+    }
+
+    /// Builds the `DiscoverPollEndpointRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DiscoverPollEndpointRequest, ::rusoto_core::BuildError> {
+        Ok(DiscoverPollEndpointRequest {
+            cluster: self.cluster,
+            container_instance: self.container_instance,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DiscoverPollEndpointResponse {
@@ -1124,7 +2994,7 @@ pub struct DockerVolumeConfiguration {
     /// <p>The scope for the Docker volume that determines its lifecycle. Docker volumes that are scoped to a <code>task</code> are automatically provisioned when the task starts and destroyed when the task stops. Docker volumes that are scoped as <code>shared</code> persist after the task stops.</p>
     #[serde(rename = "scope")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scope: Option<String>,
+    pub scope: Option<Scope>,
 }
 
 /// <p>A failed resource.</p>
@@ -1142,7 +3012,7 @@ pub struct Failure {
 }
 
 /// <p>The FireLens configuration for the container. This is used to specify and configure a log router for container logs.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FirelensConfiguration {
     /// <p>The options to use when configuring the log router. This field is optional and can be used to add additional metadata, such as the task, task definition, cluster, and container instance details to the log event. If specified, the syntax to use is <code>"options":{"enable-ecs-log-metadata":"true|false"}</code>.</p>
     #[serde(rename = "options")]
@@ -1150,15 +3020,87 @@ pub struct FirelensConfiguration {
     pub options: Option<::std::collections::HashMap<String, String>>,
     /// <p>The log router to use. The valid values are <code>fluentd</code> or <code>fluentbit</code>.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: FirelensConfigurationType,
 }
 
-/// <p><p>An object representing a container health check. Health check parameters that are specified in a container definition override any Docker health checks that exist in the container image (such as those specified in a parent image or from the image&#39;s Dockerfile).</p> <p>The following are notes about container health check support:</p> <ul> <li> <p>Container health checks require version 1.17.0 or greater of the Amazon ECS container agent. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-update.html">Updating the Amazon ECS Container Agent</a>.</p> </li> <li> <p>Container health checks are supported for Fargate tasks if you are using platform version 1.1.0 or greater. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/platform_versions.html">AWS Fargate Platform Versions</a>.</p> </li> <li> <p>Container health checks are not supported for tasks that are part of a service that is configured to use a Classic Load Balancer.</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct HealthCheck {
-    /// <p>A string array representing the command that the container runs to determine if it is healthy. The string array must start with <code>CMD</code> to execute the command arguments directly, or <code>CMD-SHELL</code> to run the command with the container's default shell. For example:</p> <p> <code>[ "CMD-SHELL", "curl -f http://localhost/ || exit 1" ]</code> </p> <p>An exit code of 0 indicates success, and non-zero exit code indicates failure. For more information, see <code>HealthCheck</code> in the <a href="https://docs.docker.com/engine/api/v1.35/#operation/ContainerCreate">Create a container</a> section of the <a href="https://docs.docker.com/engine/api/v1.35/">Docker Remote API</a>.</p>
-    #[serde(rename = "command")]
-    pub command: Vec<String>,
+/// <p>The log router to use. The valid values are <code>fluentd</code> or <code>fluentbit</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FirelensConfigurationType {
+    /// <p><code>fluentd</code></p>
+    Fluentd,
+    /// <p><code>fluentbit</code></p>
+    Fluentbit,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FirelensConfigurationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FirelensConfigurationType::Fluentd => "fluentd",
+            FirelensConfigurationType::Fluentbit => "fluentbit",
+            FirelensConfigurationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FirelensConfigurationType {
+    fn from(value: &'a str) -> FirelensConfigurationType {
+        match value {
+            "fluentd" => FirelensConfigurationType::Fluentd,
+            "fluentbit" => FirelensConfigurationType::Fluentbit,
+            _ => FirelensConfigurationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FirelensConfigurationType {
+    fn from(value: String) -> FirelensConfigurationType {
+        FirelensConfigurationType::from(value.as_str())
+    }
+}
+
+impl From<FirelensConfigurationType> for String {
+    fn from(value: FirelensConfigurationType) -> String {
+        match value {
+            FirelensConfigurationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FirelensConfigurationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FirelensConfigurationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FirelensConfigurationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FirelensConfigurationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FirelensConfigurationType::from(value))
+    }
+}
+
+/// <p><p>An object representing a container health check. Health check parameters that are specified in a container definition override any Docker health checks that exist in the container image (such as those specified in a parent image or from the image&#39;s Dockerfile).</p> <p>The following are notes about container health check support:</p> <ul> <li> <p>Container health checks require version 1.17.0 or greater of the Amazon ECS container agent. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-agent-update.html">Updating the Amazon ECS Container Agent</a>.</p> </li> <li> <p>Container health checks are supported for Fargate tasks if you are using platform version 1.1.0 or greater. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/platform_versions.html">AWS Fargate Platform Versions</a>.</p> </li> <li> <p>Container health checks are not supported for tasks that are part of a service that is configured to use a Classic Load Balancer.</p> </li> </ul></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthCheck {
+    /// <p>A string array representing the command that the container runs to determine if it is healthy. The string array must start with <code>CMD</code> to execute the command arguments directly, or <code>CMD-SHELL</code> to run the command with the container's default shell. For example:</p> <p> <code>[ "CMD-SHELL", "curl -f http://localhost/ || exit 1" ]</code> </p> <p>An exit code of 0 indicates success, and non-zero exit code indicates failure. For more information, see <code>HealthCheck</code> in the <a href="https://docs.docker.com/engine/api/v1.35/#operation/ContainerCreate">Create a container</a> section of the <a href="https://docs.docker.com/engine/api/v1.35/">Docker Remote API</a>.</p>
+    #[serde(rename = "command")]
+    pub command: Vec<String>,
     /// <p>The time period in seconds between each health check execution. You may specify between 5 and 300 seconds. The default value is 30 seconds.</p>
     #[serde(rename = "interval")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1177,6 +3119,82 @@ pub struct HealthCheck {
     pub timeout: Option<i64>,
 }
 
+/// <p>The health status of the container. If health checks are not configured for this container in its task definition, then it reports the health status as <code>UNKNOWN</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HealthStatus {
+    /// <p><code>HEALTHY</code></p>
+    Healthy,
+    /// <p><code>UNHEALTHY</code></p>
+    Unhealthy,
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HealthStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HealthStatus::Healthy => "HEALTHY",
+            HealthStatus::Unhealthy => "UNHEALTHY",
+            HealthStatus::UnknownValue => "UNKNOWN",
+            HealthStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HealthStatus {
+    fn from(value: &'a str) -> HealthStatus {
+        match value {
+            "HEALTHY" => HealthStatus::Healthy,
+            "UNHEALTHY" => HealthStatus::Unhealthy,
+            "UNKNOWN" => HealthStatus::UnknownValue,
+            _ => HealthStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HealthStatus {
+    fn from(value: String) -> HealthStatus {
+        HealthStatus::from(value.as_str())
+    }
+}
+
+impl From<HealthStatus> for String {
+    fn from(value: HealthStatus) -> String {
+        match value {
+            HealthStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HealthStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HealthStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HealthStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HealthStatus::from(value))
+    }
+}
+
 /// <p>Hostnames and IP address entries that are added to the <code>/etc/hosts</code> file of a container via the <code>extraHosts</code> parameter of its <a>ContainerDefinition</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HostEntry {
@@ -1221,6 +3239,82 @@ pub struct InferenceAcceleratorOverride {
     pub device_type: Option<String>,
 }
 
+/// <p><p>The IPC resource namespace to use for the containers in the task. The valid values are <code>host</code>, <code>task</code>, or <code>none</code>. If <code>host</code> is specified, then all containers within the tasks that specified the <code>host</code> IPC mode on the same container instance share the same IPC resources with the host Amazon EC2 instance. If <code>task</code> is specified, all containers within the specified task share the same IPC resources. If <code>none</code> is specified, then IPC resources within the containers of a task are private and not shared with other containers in a task or on the container instance. If no value is specified, then the IPC resource namespace sharing depends on the Docker daemon setting on the container instance. For more information, see <a href="https://docs.docker.com/engine/reference/run/#ipc-settings---ipc">IPC settings</a> in the <i>Docker run reference</i>.</p> <p>If the <code>host</code> IPC mode is used, be aware that there is a heightened risk of undesired IPC namespace expose. For more information, see <a href="https://docs.docker.com/engine/security/security/">Docker security</a>.</p> <p>If you are setting namespaced kernel parameters using <code>systemControls</code> for the containers in the task, the following will apply to your IPC resource namespace. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task_definition_parameters.html">System Controls</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <ul> <li> <p>For tasks that use the <code>host</code> IPC mode, IPC namespace related <code>systemControls</code> are not supported.</p> </li> <li> <p>For tasks that use the <code>task</code> IPC mode, IPC namespace related <code>systemControls</code> will apply to all containers within a task.</p> </li> </ul> <note> <p>This parameter is not supported for Windows containers or tasks using the Fargate launch type.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IpcMode {
+    /// <p><code>host</code></p>
+    Host,
+    /// <p><code>task</code></p>
+    Task,
+    /// <p><code>none</code></p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IpcMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpcMode::Host => "host",
+            IpcMode::Task => "task",
+            IpcMode::None => "none",
+            IpcMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IpcMode {
+    fn from(value: &'a str) -> IpcMode {
+        match value {
+            "host" => IpcMode::Host,
+            "task" => IpcMode::Task,
+            "none" => IpcMode::None,
+            _ => IpcMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpcMode {
+    fn from(value: String) -> IpcMode {
+        IpcMode::from(value.as_str())
+    }
+}
+
+impl From<IpcMode> for String {
+    fn from(value: IpcMode) -> String {
+        match value {
+            IpcMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IpcMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for IpcMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IpcMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IpcMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IpcMode::from(value))
+    }
+}
+
 /// <p>The Linux capabilities for the container that are added to or dropped from the default configuration provided by Docker. For more information on the default capabilities and the non-default available capabilities, see <a href="https://docs.docker.com/engine/reference/run/#runtime-privilege-and-linux-capabilities">Runtime privilege and Linux capabilities</a> in the <i>Docker run reference</i>. For more detailed information on these Linux capabilities, see the <a href="http://man7.org/linux/man-pages/man7/capabilities.7.html">capabilities(7)</a> Linux manual page.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KernelCapabilities {
@@ -1247,6 +3341,82 @@ pub struct KeyValuePair {
     pub value: Option<String>,
 }
 
+/// <p>The launch type on which to run your service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LaunchType {
+    /// <p><code>EC2</code></p>
+    Ec2,
+    /// <p><code>FARGATE</code></p>
+    Fargate,
+    /// <p><code>EXTERNAL</code></p>
+    External,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LaunchType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LaunchType::Ec2 => "EC2",
+            LaunchType::Fargate => "FARGATE",
+            LaunchType::External => "EXTERNAL",
+            LaunchType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LaunchType {
+    fn from(value: &'a str) -> LaunchType {
+        match value {
+            "EC2" => LaunchType::Ec2,
+            "FARGATE" => LaunchType::Fargate,
+            "EXTERNAL" => LaunchType::External,
+            _ => LaunchType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LaunchType {
+    fn from(value: String) -> LaunchType {
+        LaunchType::from(value.as_str())
+    }
+}
+
+impl From<LaunchType> for String {
+    fn from(value: LaunchType) -> String {
+        match value {
+            LaunchType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LaunchType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LaunchType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LaunchType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LaunchType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LaunchType::from(value))
+    }
+}
+
 /// <p>Linux-specific options that are applied to the container, such as Linux <a>KernelCapabilities</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LinuxParameters {
@@ -1293,7 +3463,7 @@ pub struct ListAccountSettingsRequest {
     /// <p>The resource name you want to list the account settings for.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SettingName>,
     /// <p><p>The <code>nextToken</code> value returned from a previous paginated <code>ListAccountSettings</code> request where <code>maxResults</code> was used and the results exceeded the value of that parameter. Pagination continues from the end of the previous results that returned the <code>nextToken</code> value.</p> <note> <p>This token should be treated as an opaque identifier that is only used to retrieve the next items in a list and not for other programmatic purposes.</p> </note></p>
     #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1308,6 +3478,76 @@ pub struct ListAccountSettingsRequest {
     pub value: Option<String>,
 }
 
+impl ListAccountSettingsRequest {
+    /// Returns a builder for `ListAccountSettingsRequest`, checking its required members when built.
+    pub fn builder() -> ListAccountSettingsRequestBuilder {
+        ListAccountSettingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAccountSettingsRequest`](struct.ListAccountSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAccountSettingsRequestBuilder {
+    effective_settings: Option<bool>,
+    max_results: Option<i64>,
+    name: Option<SettingName>,
+    next_token: Option<String>,
+    principal_arn: Option<String>,
+    value: Option<String>,
+}
+
+impl ListAccountSettingsRequestBuilder {
+    /// Sets the [`effective_settings`](struct.ListAccountSettingsRequest.html#structfield.effective_settings) member.
+    pub fn effective_settings<T: Into<bool>>(mut self, value: T) -> Self {
+        self.effective_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAccountSettingsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.ListAccountSettingsRequest.html#structfield.name) member.
+    pub fn name<T: Into<SettingName>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAccountSettingsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_arn`](struct.ListAccountSettingsRequest.html#structfield.principal_arn) member.
+    pub fn principal_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`value`](struct.ListAccountSettingsRequest.html#structfield.value) member.
+    pub fn value<T: Into<String>>(mut self, value: T) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAccountSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAccountSettingsRequest, ::rusoto_core::BuildError> {
+        Ok(ListAccountSettingsRequest {
+            effective_settings: self.effective_settings,
+            max_results: self.max_results,
+            name: self.name,
+            next_token: self.next_token,
+            principal_arn: self.principal_arn,
+            value: self.value,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAccountSettingsResponse {
@@ -1321,7 +3561,7 @@ pub struct ListAccountSettingsResponse {
     pub settings: Option<Vec<Setting>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListAttributesRequest {
     /// <p>The name of the attribute with which to filter the results. </p>
     #[serde(rename = "attributeName")]
@@ -1345,7 +3585,77 @@ pub struct ListAttributesRequest {
     pub next_token: Option<String>,
     /// <p>The type of the target with which to list attributes.</p>
     #[serde(rename = "targetType")]
-    pub target_type: String,
+    pub target_type: TargetType,
+}
+
+impl ListAttributesRequest {
+    /// Returns a builder for `ListAttributesRequest`, checking its required members when built.
+    pub fn builder() -> ListAttributesRequestBuilder {
+        ListAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAttributesRequest`](struct.ListAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAttributesRequestBuilder {
+    attribute_name: Option<String>,
+    attribute_value: Option<String>,
+    cluster: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    target_type: Option<TargetType>,
+}
+
+impl ListAttributesRequestBuilder {
+    /// Sets the [`attribute_name`](struct.ListAttributesRequest.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attribute_value`](struct.ListAttributesRequest.html#structfield.attribute_value) member.
+    pub fn attribute_value<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster`](struct.ListAttributesRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAttributesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAttributesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_type`](struct.ListAttributesRequest.html#structfield.target_type) member.
+    pub fn target_type<T: Into<TargetType>>(mut self, value: T) -> Self {
+        self.target_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(ListAttributesRequest {
+            attribute_name: self.attribute_name,
+            attribute_value: self.attribute_value,
+            cluster: self.cluster,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            target_type: self
+                .target_type
+                .ok_or(::rusoto_core::BuildError::missing("target_type"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1373,6 +3683,42 @@ pub struct ListClustersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListClustersRequest {
+    /// Returns a builder for `ListClustersRequest`, checking its required members when built.
+    pub fn builder() -> ListClustersRequestBuilder {
+        ListClustersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListClustersRequest`](struct.ListClustersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListClustersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListClustersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListClustersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListClustersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListClustersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListClustersRequest, ::rusoto_core::BuildError> {
+        Ok(ListClustersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListClustersResponse {
@@ -1407,7 +3753,69 @@ pub struct ListContainerInstancesRequest {
     /// <p>Filters the container instances by status. For example, if you specify the <code>DRAINING</code> status, the results include only container instances that have been set to <code>DRAINING</code> using <a>UpdateContainerInstancesState</a>. If you do not specify this parameter, the default is to include container instances set to all states other than <code>INACTIVE</code>.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ContainerInstanceStatus>,
+}
+
+impl ListContainerInstancesRequest {
+    /// Returns a builder for `ListContainerInstancesRequest`, checking its required members when built.
+    pub fn builder() -> ListContainerInstancesRequestBuilder {
+        ListContainerInstancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListContainerInstancesRequest`](struct.ListContainerInstancesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListContainerInstancesRequestBuilder {
+    cluster: Option<String>,
+    filter: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    status: Option<ContainerInstanceStatus>,
+}
+
+impl ListContainerInstancesRequestBuilder {
+    /// Sets the [`cluster`](struct.ListContainerInstancesRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter`](struct.ListContainerInstancesRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListContainerInstancesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListContainerInstancesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListContainerInstancesRequest.html#structfield.status) member.
+    pub fn status<T: Into<ContainerInstanceStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListContainerInstancesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListContainerInstancesRequest, ::rusoto_core::BuildError> {
+        Ok(ListContainerInstancesRequest {
+            cluster: self.cluster,
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            status: self.status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1432,7 +3840,7 @@ pub struct ListServicesRequest {
     /// <p>The launch type for the services to list.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>The maximum number of service results returned by <code>ListServices</code> in paginated output. When this parameter is used, <code>ListServices</code> only returns <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. The remaining results of the initial request can be seen by sending another <code>ListServices</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 100. If this parameter is not used, then <code>ListServices</code> returns up to 10 results and a <code>nextToken</code> value if applicable.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1444,7 +3852,67 @@ pub struct ListServicesRequest {
     /// <p>The scheduling strategy for services to list.</p>
     #[serde(rename = "schedulingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduling_strategy: Option<String>,
+    pub scheduling_strategy: Option<SchedulingStrategy>,
+}
+
+impl ListServicesRequest {
+    /// Returns a builder for `ListServicesRequest`, checking its required members when built.
+    pub fn builder() -> ListServicesRequestBuilder {
+        ListServicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListServicesRequest`](struct.ListServicesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListServicesRequestBuilder {
+    cluster: Option<String>,
+    launch_type: Option<LaunchType>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    scheduling_strategy: Option<SchedulingStrategy>,
+}
+
+impl ListServicesRequestBuilder {
+    /// Sets the [`cluster`](struct.ListServicesRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`launch_type`](struct.ListServicesRequest.html#structfield.launch_type) member.
+    pub fn launch_type<T: Into<LaunchType>>(mut self, value: T) -> Self {
+        self.launch_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListServicesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListServicesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduling_strategy`](struct.ListServicesRequest.html#structfield.scheduling_strategy) member.
+    pub fn scheduling_strategy<T: Into<SchedulingStrategy>>(mut self, value: T) -> Self {
+        self.scheduling_strategy = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListServicesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListServicesRequest, ::rusoto_core::BuildError> {
+        Ok(ListServicesRequest {
+            cluster: self.cluster,
+            launch_type: self.launch_type,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            scheduling_strategy: self.scheduling_strategy,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1467,6 +3935,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -1493,24 +3993,78 @@ pub struct ListTaskDefinitionFamiliesRequest {
     /// <p>The task definition family status with which to filter the <code>ListTaskDefinitionFamilies</code> results. By default, both <code>ACTIVE</code> and <code>INACTIVE</code> task definition families are listed. If this parameter is set to <code>ACTIVE</code>, only task definition families that have an <code>ACTIVE</code> task definition revision are returned. If this parameter is set to <code>INACTIVE</code>, only task definition families that do not have any <code>ACTIVE</code> task definition revisions are returned. If you paginate the resulting output, be sure to keep the <code>status</code> value constant in each subsequent request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskDefinitionFamilyStatus>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListTaskDefinitionFamiliesResponse {
-    /// <p>The list of task definition family names that match the <code>ListTaskDefinitionFamilies</code> request.</p>
-    #[serde(rename = "families")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub families: Option<Vec<String>>,
-    /// <p>The <code>nextToken</code> value to include in a future <code>ListTaskDefinitionFamilies</code> request. When the results of a <code>ListTaskDefinitionFamilies</code> request exceed <code>maxResults</code>, this value can be used to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl ListTaskDefinitionFamiliesRequest {
+    /// Returns a builder for `ListTaskDefinitionFamiliesRequest`, checking its required members when built.
+    pub fn builder() -> ListTaskDefinitionFamiliesRequestBuilder {
+        ListTaskDefinitionFamiliesRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ListTaskDefinitionsRequest {
+/// Builds a [`ListTaskDefinitionFamiliesRequest`](struct.ListTaskDefinitionFamiliesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTaskDefinitionFamiliesRequestBuilder {
+    family_prefix: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    status: Option<TaskDefinitionFamilyStatus>,
+}
+
+impl ListTaskDefinitionFamiliesRequestBuilder {
+    /// Sets the [`family_prefix`](struct.ListTaskDefinitionFamiliesRequest.html#structfield.family_prefix) member.
+    pub fn family_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.family_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListTaskDefinitionFamiliesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTaskDefinitionFamiliesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListTaskDefinitionFamiliesRequest.html#structfield.status) member.
+    pub fn status<T: Into<TaskDefinitionFamilyStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTaskDefinitionFamiliesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTaskDefinitionFamiliesRequest, ::rusoto_core::BuildError> {
+        Ok(ListTaskDefinitionFamiliesRequest {
+            family_prefix: self.family_prefix,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            status: self.status,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListTaskDefinitionFamiliesResponse {
+    /// <p>The list of task definition family names that match the <code>ListTaskDefinitionFamilies</code> request.</p>
+    #[serde(rename = "families")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub families: Option<Vec<String>>,
+    /// <p>The <code>nextToken</code> value to include in a future <code>ListTaskDefinitionFamilies</code> request. When the results of a <code>ListTaskDefinitionFamilies</code> request exceed <code>maxResults</code>, this value can be used to retrieve the next page of results. This value is <code>null</code> when there are no more results to return.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListTaskDefinitionsRequest {
     /// <p>The full family name with which to filter the <code>ListTaskDefinitions</code> results. Specifying a <code>familyPrefix</code> limits the listed task definitions to task definition revisions that belong to that family.</p>
     #[serde(rename = "familyPrefix")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1526,11 +4080,73 @@ pub struct ListTaskDefinitionsRequest {
     /// <p>The order in which to sort the results. Valid values are <code>ASC</code> and <code>DESC</code>. By default (<code>ASC</code>), task definitions are listed lexicographically by family name and in ascending numerical order by revision so that the newest task definitions in a family are listed last. Setting this parameter to <code>DESC</code> reverses the sort order on family name and revision so that the newest task definitions in a family are listed first.</p>
     #[serde(rename = "sort")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<SortOrder>,
     /// <p>The task definition status with which to filter the <code>ListTaskDefinitions</code> results. By default, only <code>ACTIVE</code> task definitions are listed. By setting this parameter to <code>INACTIVE</code>, you can view task definitions that are <code>INACTIVE</code> as long as an active task or service still references them. If you paginate the resulting output, be sure to keep the <code>status</code> value constant in each subsequent request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskDefinitionStatus>,
+}
+
+impl ListTaskDefinitionsRequest {
+    /// Returns a builder for `ListTaskDefinitionsRequest`, checking its required members when built.
+    pub fn builder() -> ListTaskDefinitionsRequestBuilder {
+        ListTaskDefinitionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTaskDefinitionsRequest`](struct.ListTaskDefinitionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTaskDefinitionsRequestBuilder {
+    family_prefix: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort: Option<SortOrder>,
+    status: Option<TaskDefinitionStatus>,
+}
+
+impl ListTaskDefinitionsRequestBuilder {
+    /// Sets the [`family_prefix`](struct.ListTaskDefinitionsRequest.html#structfield.family_prefix) member.
+    pub fn family_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.family_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListTaskDefinitionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTaskDefinitionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort`](struct.ListTaskDefinitionsRequest.html#structfield.sort) member.
+    pub fn sort<T: Into<SortOrder>>(mut self, value: T) -> Self {
+        self.sort = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListTaskDefinitionsRequest.html#structfield.status) member.
+    pub fn status<T: Into<TaskDefinitionStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTaskDefinitionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTaskDefinitionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTaskDefinitionsRequest {
+            family_prefix: self.family_prefix,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort: self.sort,
+            status: self.status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1559,7 +4175,7 @@ pub struct ListTasksRequest {
     /// <p><p>The task desired status with which to filter the <code>ListTasks</code> results. Specifying a <code>desiredStatus</code> of <code>STOPPED</code> limits the results to tasks that Amazon ECS has set the desired status to <code>STOPPED</code>. This can be useful for debugging tasks that are not starting properly or have died or finished. The default status filter is <code>RUNNING</code>, which shows tasks that Amazon ECS has set the desired status to <code>RUNNING</code>.</p> <note> <p>Although you can filter results based on a desired status of <code>PENDING</code>, this does not return any results. Amazon ECS never sets the desired status of a task to that value (only a task&#39;s <code>lastStatus</code> may have a value of <code>PENDING</code>).</p> </note></p>
     #[serde(rename = "desiredStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub desired_status: Option<String>,
+    pub desired_status: Option<DesiredStatus>,
     /// <p>The name of the family with which to filter the <code>ListTasks</code> results. Specifying a <code>family</code> limits the results to tasks that belong to that family.</p>
     #[serde(rename = "family")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1567,7 +4183,7 @@ pub struct ListTasksRequest {
     /// <p>The launch type for services to list.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>The maximum number of task results returned by <code>ListTasks</code> in paginated output. When this parameter is used, <code>ListTasks</code> only returns <code>maxResults</code> results in a single page along with a <code>nextToken</code> response element. The remaining results of the initial request can be seen by sending another <code>ListTasks</code> request with the returned <code>nextToken</code> value. This value can be between 1 and 100. If this parameter is not used, then <code>ListTasks</code> returns up to 100 results and a <code>nextToken</code> value if applicable.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1586,6 +4202,98 @@ pub struct ListTasksRequest {
     pub started_by: Option<String>,
 }
 
+impl ListTasksRequest {
+    /// Returns a builder for `ListTasksRequest`, checking its required members when built.
+    pub fn builder() -> ListTasksRequestBuilder {
+        ListTasksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTasksRequest`](struct.ListTasksRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTasksRequestBuilder {
+    cluster: Option<String>,
+    container_instance: Option<String>,
+    desired_status: Option<DesiredStatus>,
+    family: Option<String>,
+    launch_type: Option<LaunchType>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    service_name: Option<String>,
+    started_by: Option<String>,
+}
+
+impl ListTasksRequestBuilder {
+    /// Sets the [`cluster`](struct.ListTasksRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`container_instance`](struct.ListTasksRequest.html#structfield.container_instance) member.
+    pub fn container_instance<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_instance = Some(value.into());
+        self
+    }
+
+    /// Sets the [`desired_status`](struct.ListTasksRequest.html#structfield.desired_status) member.
+    pub fn desired_status<T: Into<DesiredStatus>>(mut self, value: T) -> Self {
+        self.desired_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`family`](struct.ListTasksRequest.html#structfield.family) member.
+    pub fn family<T: Into<String>>(mut self, value: T) -> Self {
+        self.family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`launch_type`](struct.ListTasksRequest.html#structfield.launch_type) member.
+    pub fn launch_type<T: Into<LaunchType>>(mut self, value: T) -> Self {
+        self.launch_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListTasksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTasksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_name`](struct.ListTasksRequest.html#structfield.service_name) member.
+    pub fn service_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`started_by`](struct.ListTasksRequest.html#structfield.started_by) member.
+    pub fn started_by<T: Into<String>>(mut self, value: T) -> Self {
+        self.started_by = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTasksRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTasksRequest, ::rusoto_core::BuildError> {
+        Ok(ListTasksRequest {
+            cluster: self.cluster,
+            container_instance: self.container_instance,
+            desired_status: self.desired_status,
+            family: self.family,
+            launch_type: self.launch_type,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            service_name: self.service_name,
+            started_by: self.started_by,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTasksResponse {
@@ -1621,11 +4329,11 @@ pub struct LoadBalancer {
 }
 
 /// <p>Log configuration options to send to a custom log driver for the container.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogConfiguration {
     /// <p>The log driver to use for the container. The valid values listed for this parameter are log drivers that the Amazon ECS container agent can communicate with by default.</p> <p>For tasks using the Fargate launch type, the supported log drivers are <code>awslogs</code> and <code>splunk</code>.</p> <p>For tasks using the EC2 launch type, the supported log drivers are <code>awslogs</code>, <code>fluentd</code>, <code>gelf</code>, <code>json-file</code>, <code>journald</code>, <code>logentries</code>, <code>syslog</code>, and <code>splunk</code>.</p> <p>For more information about using the <code>awslogs</code> log driver, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/using_awslogs.html">Using the awslogs Log Driver</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>If you have a custom driver that is not listed above that you would like to work with the Amazon ECS container agent, you can fork the Amazon ECS container agent project that is <a href="https://github.com/aws/amazon-ecs-agent">available on GitHub</a> and customize it to work with that driver. We encourage you to submit pull requests for changes that you would like to have included. However, Amazon Web Services does not currently support running modified copies of this software.</p> </note> <p>This parameter requires version 1.18 of the Docker Remote API or greater on your container instance. To check the Docker Remote API version on your container instance, log in to your container instance and run the following command: <code>sudo docker version --format '{{.Server.APIVersion}}'</code> </p>
     #[serde(rename = "logDriver")]
-    pub log_driver: String,
+    pub log_driver: LogDriver,
     /// <p>The configuration options to send to the log driver. This parameter requires version 1.19 of the Docker Remote API or greater on your container instance. To check the Docker Remote API version on your container instance, log in to your container instance and run the following command: <code>sudo docker version --format '{{.Server.APIVersion}}'</code> </p>
     #[serde(rename = "options")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1636,6 +4344,102 @@ pub struct LogConfiguration {
     pub secret_options: Option<Vec<Secret>>,
 }
 
+/// <p>The log driver to use for the container. The valid values listed for this parameter are log drivers that the Amazon ECS container agent can communicate with by default.</p> <p>For tasks using the Fargate launch type, the supported log drivers are <code>awslogs</code> and <code>splunk</code>.</p> <p>For tasks using the EC2 launch type, the supported log drivers are <code>awslogs</code>, <code>fluentd</code>, <code>gelf</code>, <code>json-file</code>, <code>journald</code>, <code>logentries</code>, <code>syslog</code>, and <code>splunk</code>.</p> <p>For more information about using the <code>awslogs</code> log driver, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/using_awslogs.html">Using the awslogs Log Driver</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>If you have a custom driver that is not listed above that you would like to work with the Amazon ECS container agent, you can fork the Amazon ECS container agent project that is <a href="https://github.com/aws/amazon-ecs-agent">available on GitHub</a> and customize it to work with that driver. We encourage you to submit pull requests for changes that you would like to have included. However, Amazon Web Services does not currently support running modified copies of this software.</p> </note> <p>This parameter requires version 1.18 of the Docker Remote API or greater on your container instance. To check the Docker Remote API version on your container instance, log in to your container instance and run the following command: <code>sudo docker version --format '{{.Server.APIVersion}}'</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LogDriver {
+    /// <p><code>json-file</code></p>
+    JsonFile,
+    /// <p><code>syslog</code></p>
+    Syslog,
+    /// <p><code>journald</code></p>
+    Journald,
+    /// <p><code>gelf</code></p>
+    Gelf,
+    /// <p><code>fluentd</code></p>
+    Fluentd,
+    /// <p><code>awslogs</code></p>
+    Awslogs,
+    /// <p><code>splunk</code></p>
+    Splunk,
+    /// <p><code>awsfirelens</code></p>
+    Awsfirelens,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LogDriver {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogDriver::JsonFile => "json-file",
+            LogDriver::Syslog => "syslog",
+            LogDriver::Journald => "journald",
+            LogDriver::Gelf => "gelf",
+            LogDriver::Fluentd => "fluentd",
+            LogDriver::Awslogs => "awslogs",
+            LogDriver::Splunk => "splunk",
+            LogDriver::Awsfirelens => "awsfirelens",
+            LogDriver::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LogDriver {
+    fn from(value: &'a str) -> LogDriver {
+        match value {
+            "json-file" => LogDriver::JsonFile,
+            "syslog" => LogDriver::Syslog,
+            "journald" => LogDriver::Journald,
+            "gelf" => LogDriver::Gelf,
+            "fluentd" => LogDriver::Fluentd,
+            "awslogs" => LogDriver::Awslogs,
+            "splunk" => LogDriver::Splunk,
+            "awsfirelens" => LogDriver::Awsfirelens,
+            _ => LogDriver::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogDriver {
+    fn from(value: String) -> LogDriver {
+        LogDriver::from(value.as_str())
+    }
+}
+
+impl From<LogDriver> for String {
+    fn from(value: LogDriver) -> String {
+        match value {
+            LogDriver::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LogDriver {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LogDriver {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogDriver {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LogDriver, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LogDriver::from(value))
+    }
+}
+
 /// <p>Details on a volume mount point that is used in a container definition.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MountPoint {
@@ -1671,7 +4475,7 @@ pub struct NetworkBinding {
     /// <p>The protocol used for the network binding.</p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<TransportProtocol>,
 }
 
 /// <p>An object representing the network configuration for a task or service.</p>
@@ -1701,6 +4505,158 @@ pub struct NetworkInterface {
     pub private_ipv_4_address: Option<String>,
 }
 
+/// <p>The Docker networking mode to use for the containers in the task. The valid values are <code>none</code>, <code>bridge</code>, <code>awsvpc</code>, and <code>host</code>. The default Docker network mode is <code>bridge</code>. If you are using the Fargate launch type, the <code>awsvpc</code> network mode is required. If you are using the EC2 launch type, any network mode can be used. If the network mode is set to <code>none</code>, you cannot specify port mappings in your container definitions, and the tasks containers do not have external connectivity. The <code>host</code> and <code>awsvpc</code> network modes offer the highest networking performance for containers because they use the EC2 network stack instead of the virtualized network stack provided by the <code>bridge</code> mode.</p> <p>With the <code>host</code> and <code>awsvpc</code> network modes, exposed container ports are mapped directly to the corresponding host port (for the <code>host</code> network mode) or the attached elastic network interface port (for the <code>awsvpc</code> network mode), so you cannot take advantage of dynamic host port mappings. </p> <p>If the network mode is <code>awsvpc</code>, the task is allocated an elastic network interface, and you must specify a <a>NetworkConfiguration</a> value when you create a service or run a task with the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>Currently, only Amazon ECS-optimized AMIs, other Amazon Linux variants with the <code>ecs-init</code> package, or AWS Fargate infrastructure support the <code>awsvpc</code> network mode. </p> </note> <p>If the network mode is <code>host</code>, you cannot run multiple instantiations of the same task on a single container instance when port mappings are used.</p> <p>Docker for Windows uses different network modes than Docker for Linux. When you register a task definition with Windows containers, you must not specify a network mode. If you use the console to register a task definition with Windows containers, you must choose the <code>&lt;default&gt;</code> network mode object. </p> <p>For more information, see <a href="https://docs.docker.com/engine/reference/run/#network-settings">Network settings</a> in the <i>Docker run reference</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NetworkMode {
+    /// <p><code>bridge</code></p>
+    Bridge,
+    /// <p><code>host</code></p>
+    Host,
+    /// <p><code>awsvpc</code></p>
+    Awsvpc,
+    /// <p><code>none</code></p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NetworkMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkMode::Bridge => "bridge",
+            NetworkMode::Host => "host",
+            NetworkMode::Awsvpc => "awsvpc",
+            NetworkMode::None => "none",
+            NetworkMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NetworkMode {
+    fn from(value: &'a str) -> NetworkMode {
+        match value {
+            "bridge" => NetworkMode::Bridge,
+            "host" => NetworkMode::Host,
+            "awsvpc" => NetworkMode::Awsvpc,
+            "none" => NetworkMode::None,
+            _ => NetworkMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkMode {
+    fn from(value: String) -> NetworkMode {
+        NetworkMode::from(value.as_str())
+    }
+}
+
+impl From<NetworkMode> for String {
+    fn from(value: NetworkMode) -> String {
+        match value {
+            NetworkMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NetworkMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NetworkMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NetworkMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NetworkMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NetworkMode::from(value))
+    }
+}
+
+/// <p><p>The process namespace to use for the containers in the task. The valid values are <code>host</code> or <code>task</code>. If <code>host</code> is specified, then all containers within the tasks that specified the <code>host</code> PID mode on the same container instance share the same IPC resources with the host Amazon EC2 instance. If <code>task</code> is specified, all containers within the specified task share the same process namespace. If no value is specified, the default is a private namespace. For more information, see <a href="https://docs.docker.com/engine/reference/run/#pid-settings---pid">PID settings</a> in the <i>Docker run reference</i>.</p> <p>If the <code>host</code> PID mode is used, be aware that there is a heightened risk of undesired process namespace expose. For more information, see <a href="https://docs.docker.com/engine/security/security/">Docker security</a>.</p> <note> <p>This parameter is not supported for Windows containers or tasks using the Fargate launch type.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PidMode {
+    /// <p><code>host</code></p>
+    Host,
+    /// <p><code>task</code></p>
+    Task,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PidMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PidMode::Host => "host",
+            PidMode::Task => "task",
+            PidMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PidMode {
+    fn from(value: &'a str) -> PidMode {
+        match value {
+            "host" => PidMode::Host,
+            "task" => PidMode::Task,
+            _ => PidMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PidMode {
+    fn from(value: String) -> PidMode {
+        PidMode::from(value.as_str())
+    }
+}
+
+impl From<PidMode> for String {
+    fn from(value: PidMode) -> String {
+        match value {
+            PidMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PidMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PidMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PidMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PidMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PidMode::from(value))
+    }
+}
+
 /// <p><p>An object representing a constraint on task placement. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-constraints.html">Task Placement Constraints</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>If you are using the Fargate launch type, task placement constraints are not supported.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlacementConstraint {
@@ -1711,7 +4667,79 @@ pub struct PlacementConstraint {
     /// <p>The type of constraint. Use <code>distinctInstance</code> to ensure that each task in a particular group is running on a different container instance. Use <code>memberOf</code> to restrict the selection to a group of valid candidates.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<PlacementConstraintType>,
+}
+
+/// <p>The type of constraint. Use <code>distinctInstance</code> to ensure that each task in a particular group is running on a different container instance. Use <code>memberOf</code> to restrict the selection to a group of valid candidates.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlacementConstraintType {
+    /// <p><code>distinctInstance</code></p>
+    DistinctInstance,
+    /// <p><code>memberOf</code></p>
+    MemberOf,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PlacementConstraintType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlacementConstraintType::DistinctInstance => "distinctInstance",
+            PlacementConstraintType::MemberOf => "memberOf",
+            PlacementConstraintType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PlacementConstraintType {
+    fn from(value: &'a str) -> PlacementConstraintType {
+        match value {
+            "distinctInstance" => PlacementConstraintType::DistinctInstance,
+            "memberOf" => PlacementConstraintType::MemberOf,
+            _ => PlacementConstraintType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlacementConstraintType {
+    fn from(value: String) -> PlacementConstraintType {
+        PlacementConstraintType::from(value.as_str())
+    }
+}
+
+impl From<PlacementConstraintType> for String {
+    fn from(value: PlacementConstraintType) -> String {
+        match value {
+            PlacementConstraintType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PlacementConstraintType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PlacementConstraintType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlacementConstraintType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PlacementConstraintType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PlacementConstraintType::from(value))
+    }
 }
 
 /// <p>The task placement strategy for a task or service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-strategies.html">Task Placement Strategies</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
@@ -1724,18 +4752,162 @@ pub struct PlacementStrategy {
     /// <p>The type of placement strategy. The <code>random</code> placement strategy randomly places tasks on available candidates. The <code>spread</code> placement strategy spreads placement across available candidates evenly based on the <code>field</code> parameter. The <code>binpack</code> strategy places tasks on available candidates that have the least available amount of the resource that is specified with the <code>field</code> parameter. For example, if you binpack on memory, a task is placed on the instance with the least amount of remaining memory (but still enough to run the task).</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<PlacementStrategyType>,
+}
+
+/// <p>The type of placement strategy. The <code>random</code> placement strategy randomly places tasks on available candidates. The <code>spread</code> placement strategy spreads placement across available candidates evenly based on the <code>field</code> parameter. The <code>binpack</code> strategy places tasks on available candidates that have the least available amount of the resource that is specified with the <code>field</code> parameter. For example, if you binpack on memory, a task is placed on the instance with the least amount of remaining memory (but still enough to run the task).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlacementStrategyType {
+    /// <p><code>random</code></p>
+    Random,
+    /// <p><code>spread</code></p>
+    Spread,
+    /// <p><code>binpack</code></p>
+    Binpack,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PlacementStrategyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlacementStrategyType::Random => "random",
+            PlacementStrategyType::Spread => "spread",
+            PlacementStrategyType::Binpack => "binpack",
+            PlacementStrategyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PlacementStrategyType {
+    fn from(value: &'a str) -> PlacementStrategyType {
+        match value {
+            "random" => PlacementStrategyType::Random,
+            "spread" => PlacementStrategyType::Spread,
+            "binpack" => PlacementStrategyType::Binpack,
+            _ => PlacementStrategyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlacementStrategyType {
+    fn from(value: String) -> PlacementStrategyType {
+        PlacementStrategyType::from(value.as_str())
+    }
+}
+
+impl From<PlacementStrategyType> for String {
+    fn from(value: PlacementStrategyType) -> String {
+        match value {
+            PlacementStrategyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PlacementStrategyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PlacementStrategyType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlacementStrategyType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PlacementStrategyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PlacementStrategyType::from(value))
+    }
 }
 
 /// <p>The devices that are available on the container instance. The only supported device type is a GPU.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PlatformDevice {
     /// <p>The ID for the GPU(s) on the container instance. The available GPU IDs can also be obtained on the container instance in the <code>/var/lib/ecs/gpu/nvidia_gpu_info.json</code> file.</p>
     #[serde(rename = "id")]
     pub id: String,
     /// <p>The type of device that is available on the container instance. The only supported value is <code>GPU</code>.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: PlatformDeviceType,
+}
+
+/// <p>The type of device that is available on the container instance. The only supported value is <code>GPU</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlatformDeviceType {
+    /// <p><code>GPU</code></p>
+    Gpu,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PlatformDeviceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlatformDeviceType::Gpu => "GPU",
+            PlatformDeviceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PlatformDeviceType {
+    fn from(value: &'a str) -> PlatformDeviceType {
+        match value {
+            "GPU" => PlatformDeviceType::Gpu,
+            _ => PlatformDeviceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlatformDeviceType {
+    fn from(value: String) -> PlatformDeviceType {
+        PlatformDeviceType::from(value.as_str())
+    }
+}
+
+impl From<PlatformDeviceType> for String {
+    fn from(value: PlatformDeviceType) -> String {
+        match value {
+            PlatformDeviceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PlatformDeviceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PlatformDeviceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlatformDeviceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PlatformDeviceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PlatformDeviceType::from(value))
+    }
 }
 
 /// <p>Port mappings allow containers to access ports on the host container instance to send or receive traffic. Port mappings are specified as part of the container definition.</p> <p>If you are using containers in a task with the <code>awsvpc</code> or <code>host</code> network mode, exposed ports should be specified using <code>containerPort</code>. The <code>hostPort</code> can be left blank or it must be the same value as the <code>containerPort</code>.</p> <p>After a task reaches the <code>RUNNING</code> status, manual and automatic host and container port assignments are visible in the <code>networkBindings</code> section of <a>DescribeTasks</a> API responses.</p>
@@ -1752,7 +4924,83 @@ pub struct PortMapping {
     /// <p>The protocol used for the port mapping. Valid values are <code>tcp</code> and <code>udp</code>. The default is <code>tcp</code>.</p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<TransportProtocol>,
+}
+
+/// <p>Specifies whether to propagate the tags from the task definition or the service to the tasks in the service. If no value is specified, the tags are not propagated. Tags can only be propagated to the tasks within the service during service creation. To add tags to a task after service creation, use the <a>TagResource</a> API action.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PropagateTags {
+    /// <p><code>TASK_DEFINITION</code></p>
+    TaskDefinition,
+    /// <p><code>SERVICE</code></p>
+    Service,
+    /// <p><code>NONE</code></p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PropagateTags {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PropagateTags::TaskDefinition => "TASK_DEFINITION",
+            PropagateTags::Service => "SERVICE",
+            PropagateTags::None => "NONE",
+            PropagateTags::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PropagateTags {
+    fn from(value: &'a str) -> PropagateTags {
+        match value {
+            "TASK_DEFINITION" => PropagateTags::TaskDefinition,
+            "SERVICE" => PropagateTags::Service,
+            "NONE" => PropagateTags::None,
+            _ => PropagateTags::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PropagateTags {
+    fn from(value: String) -> PropagateTags {
+        PropagateTags::from(value.as_str())
+    }
+}
+
+impl From<PropagateTags> for String {
+    fn from(value: PropagateTags) -> String {
+        match value {
+            PropagateTags::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PropagateTags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PropagateTags {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PropagateTags {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PropagateTags, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PropagateTags::from(value))
+    }
 }
 
 /// <p>The configuration details for the App Mesh proxy.</p> <p>For tasks using the EC2 launch type, the container instances require at least version 1.26.0 of the container agent and at least version 1.26.0-1 of the <code>ecs-init</code> package to enable a proxy configuration. If your container instances are launched from the Amazon ECS-optimized AMI version <code>20190301</code> or later, then they contain the required versions of the container agent and <code>ecs-init</code>. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-optimized_AMI.html">Amazon ECS-optimized Linux AMI</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <p>For tasks using the Fargate launch type, the task or service requires platform version 1.3.0 or later.</p>
@@ -1768,19 +5016,129 @@ pub struct ProxyConfiguration {
     /// <p>The proxy type. The only supported value is <code>APPMESH</code>.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ProxyConfigurationType>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p>The proxy type. The only supported value is <code>APPMESH</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProxyConfigurationType {
+    /// <p><code>APPMESH</code></p>
+    Appmesh,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProxyConfigurationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProxyConfigurationType::Appmesh => "APPMESH",
+            ProxyConfigurationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProxyConfigurationType {
+    fn from(value: &'a str) -> ProxyConfigurationType {
+        match value {
+            "APPMESH" => ProxyConfigurationType::Appmesh,
+            _ => ProxyConfigurationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProxyConfigurationType {
+    fn from(value: String) -> ProxyConfigurationType {
+        ProxyConfigurationType::from(value.as_str())
+    }
+}
+
+impl From<ProxyConfigurationType> for String {
+    fn from(value: ProxyConfigurationType) -> String {
+        match value {
+            ProxyConfigurationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProxyConfigurationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProxyConfigurationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProxyConfigurationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProxyConfigurationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProxyConfigurationType::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutAccountSettingDefaultRequest {
     /// <p>The resource name for which to modify the account setting. If <code>serviceLongArnFormat</code> is specified, the ARN for your Amazon ECS services is affected. If <code>taskLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS tasks is affected. If <code>containerInstanceLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS container instances is affected. If <code>awsvpcTrunking</code> is specified, the ENI limit for your Amazon ECS container instances is affected. If <code>containerInsights</code> is specified, the default setting for CloudWatch Container Insights for your clusters is affected.</p>
     #[serde(rename = "name")]
-    pub name: String,
+    pub name: SettingName,
     /// <p>The account setting value for the specified principal ARN. Accepted values are <code>enabled</code> and <code>disabled</code>.</p>
     #[serde(rename = "value")]
     pub value: String,
 }
 
+impl PutAccountSettingDefaultRequest {
+    /// Returns a builder for `PutAccountSettingDefaultRequest`, checking its required members when built.
+    pub fn builder() -> PutAccountSettingDefaultRequestBuilder {
+        PutAccountSettingDefaultRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutAccountSettingDefaultRequest`](struct.PutAccountSettingDefaultRequest.html).
+#[derive(Default, Debug)]
+pub struct PutAccountSettingDefaultRequestBuilder {
+    name: Option<SettingName>,
+    value: Option<String>,
+}
+
+impl PutAccountSettingDefaultRequestBuilder {
+    /// Sets the [`name`](struct.PutAccountSettingDefaultRequest.html#structfield.name) member.
+    pub fn name<T: Into<SettingName>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`value`](struct.PutAccountSettingDefaultRequest.html#structfield.value) member.
+    pub fn value<T: Into<String>>(mut self, value: T) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutAccountSettingDefaultRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutAccountSettingDefaultRequest, ::rusoto_core::BuildError> {
+        Ok(PutAccountSettingDefaultRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            value: self
+                .value
+                .ok_or(::rusoto_core::BuildError::missing("value"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutAccountSettingDefaultResponse {
@@ -1789,11 +5147,11 @@ pub struct PutAccountSettingDefaultResponse {
     pub setting: Option<Setting>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutAccountSettingRequest {
     /// <p>The Amazon ECS resource name for which to modify the account setting. If <code>serviceLongArnFormat</code> is specified, the ARN for your Amazon ECS services is affected. If <code>taskLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS tasks is affected. If <code>containerInstanceLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS container instances is affected. If <code>awsvpcTrunking</code> is specified, the elastic network interface (ENI) limit for your Amazon ECS container instances is affected. If <code>containerInsights</code> is specified, the default setting for CloudWatch Container Insights for your clusters is affected.</p>
     #[serde(rename = "name")]
-    pub name: String,
+    pub name: SettingName,
     /// <p>The ARN of the principal, which can be an IAM user, IAM role, or the root user. If you specify the root user, it modifies the account setting for all IAM users, IAM roles, and the root user of the account unless an IAM user or role explicitly overrides these settings. If this field is omitted, the setting is changed only for the authenticated user.</p>
     #[serde(rename = "principalArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1803,6 +5161,56 @@ pub struct PutAccountSettingRequest {
     pub value: String,
 }
 
+impl PutAccountSettingRequest {
+    /// Returns a builder for `PutAccountSettingRequest`, checking its required members when built.
+    pub fn builder() -> PutAccountSettingRequestBuilder {
+        PutAccountSettingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutAccountSettingRequest`](struct.PutAccountSettingRequest.html).
+#[derive(Default, Debug)]
+pub struct PutAccountSettingRequestBuilder {
+    name: Option<SettingName>,
+    principal_arn: Option<String>,
+    value: Option<String>,
+}
+
+impl PutAccountSettingRequestBuilder {
+    /// Sets the [`name`](struct.PutAccountSettingRequest.html#structfield.name) member.
+    pub fn name<T: Into<SettingName>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal_arn`](struct.PutAccountSettingRequest.html#structfield.principal_arn) member.
+    pub fn principal_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`value`](struct.PutAccountSettingRequest.html#structfield.value) member.
+    pub fn value<T: Into<String>>(mut self, value: T) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutAccountSettingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutAccountSettingRequest, ::rusoto_core::BuildError> {
+        Ok(PutAccountSettingRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            principal_arn: self.principal_arn,
+            value: self
+                .value
+                .ok_or(::rusoto_core::BuildError::missing("value"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutAccountSettingResponse {
@@ -1823,6 +5231,44 @@ pub struct PutAttributesRequest {
     pub cluster: Option<String>,
 }
 
+impl PutAttributesRequest {
+    /// Returns a builder for `PutAttributesRequest`, checking its required members when built.
+    pub fn builder() -> PutAttributesRequestBuilder {
+        PutAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutAttributesRequest`](struct.PutAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct PutAttributesRequestBuilder {
+    attributes: Option<Vec<Attribute>>,
+    cluster: Option<String>,
+}
+
+impl PutAttributesRequestBuilder {
+    /// Sets the [`attributes`](struct.PutAttributesRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<Vec<Attribute>>>(mut self, value: T) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster`](struct.PutAttributesRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(PutAttributesRequest {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            cluster: self.cluster,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutAttributesResponse {
@@ -1872,6 +5318,100 @@ pub struct RegisterContainerInstanceRequest {
     pub version_info: Option<VersionInfo>,
 }
 
+impl RegisterContainerInstanceRequest {
+    /// Returns a builder for `RegisterContainerInstanceRequest`, checking its required members when built.
+    pub fn builder() -> RegisterContainerInstanceRequestBuilder {
+        RegisterContainerInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterContainerInstanceRequest`](struct.RegisterContainerInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterContainerInstanceRequestBuilder {
+    attributes: Option<Vec<Attribute>>,
+    cluster: Option<String>,
+    container_instance_arn: Option<String>,
+    instance_identity_document: Option<String>,
+    instance_identity_document_signature: Option<String>,
+    platform_devices: Option<Vec<PlatformDevice>>,
+    tags: Option<Vec<Tag>>,
+    total_resources: Option<Vec<Resource>>,
+    version_info: Option<VersionInfo>,
+}
+
+impl RegisterContainerInstanceRequestBuilder {
+    /// Sets the [`attributes`](struct.RegisterContainerInstanceRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<Vec<Attribute>>>(mut self, value: T) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster`](struct.RegisterContainerInstanceRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`container_instance_arn`](struct.RegisterContainerInstanceRequest.html#structfield.container_instance_arn) member.
+    pub fn container_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_identity_document`](struct.RegisterContainerInstanceRequest.html#structfield.instance_identity_document) member.
+    pub fn instance_identity_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_identity_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_identity_document_signature`](struct.RegisterContainerInstanceRequest.html#structfield.instance_identity_document_signature) member.
+    pub fn instance_identity_document_signature<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_identity_document_signature = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_devices`](struct.RegisterContainerInstanceRequest.html#structfield.platform_devices) member.
+    pub fn platform_devices<T: Into<Vec<PlatformDevice>>>(mut self, value: T) -> Self {
+        self.platform_devices = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RegisterContainerInstanceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`total_resources`](struct.RegisterContainerInstanceRequest.html#structfield.total_resources) member.
+    pub fn total_resources<T: Into<Vec<Resource>>>(mut self, value: T) -> Self {
+        self.total_resources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_info`](struct.RegisterContainerInstanceRequest.html#structfield.version_info) member.
+    pub fn version_info<T: Into<VersionInfo>>(mut self, value: T) -> Self {
+        self.version_info = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterContainerInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterContainerInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterContainerInstanceRequest {
+            attributes: self.attributes,
+            cluster: self.cluster,
+            container_instance_arn: self.container_instance_arn,
+            instance_identity_document: self.instance_identity_document,
+            instance_identity_document_signature: self.instance_identity_document_signature,
+            platform_devices: self.platform_devices,
+            tags: self.tags,
+            total_resources: self.total_resources,
+            version_info: self.version_info,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RegisterContainerInstanceResponse {
@@ -1904,7 +5444,7 @@ pub struct RegisterTaskDefinitionRequest {
     /// <p><p>The IPC resource namespace to use for the containers in the task. The valid values are <code>host</code>, <code>task</code>, or <code>none</code>. If <code>host</code> is specified, then all containers within the tasks that specified the <code>host</code> IPC mode on the same container instance share the same IPC resources with the host Amazon EC2 instance. If <code>task</code> is specified, all containers within the specified task share the same IPC resources. If <code>none</code> is specified, then IPC resources within the containers of a task are private and not shared with other containers in a task or on the container instance. If no value is specified, then the IPC resource namespace sharing depends on the Docker daemon setting on the container instance. For more information, see <a href="https://docs.docker.com/engine/reference/run/#ipc-settings---ipc">IPC settings</a> in the <i>Docker run reference</i>.</p> <p>If the <code>host</code> IPC mode is used, be aware that there is a heightened risk of undesired IPC namespace expose. For more information, see <a href="https://docs.docker.com/engine/security/security/">Docker security</a>.</p> <p>If you are setting namespaced kernel parameters using <code>systemControls</code> for the containers in the task, the following will apply to your IPC resource namespace. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task_definition_parameters.html">System Controls</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <ul> <li> <p>For tasks that use the <code>host</code> IPC mode, IPC namespace related <code>systemControls</code> are not supported.</p> </li> <li> <p>For tasks that use the <code>task</code> IPC mode, IPC namespace related <code>systemControls</code> will apply to all containers within a task.</p> </li> </ul> <note> <p>This parameter is not supported for Windows containers or tasks using the Fargate launch type.</p> </note></p>
     #[serde(rename = "ipcMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ipc_mode: Option<String>,
+    pub ipc_mode: Option<IpcMode>,
     /// <p><p>The amount of memory (in MiB) used by the task. It can be expressed as an integer using MiB, for example <code>1024</code>, or as a string using GB, for example <code>1GB</code> or <code>1 GB</code>, in a task definition. String values are converted to an integer indicating the MiB when the task definition is registered.</p> <note> <p>Task-level CPU and memory parameters are ignored for Windows containers. We recommend specifying container-level resources for Windows containers.</p> </note> <p>If using the EC2 launch type, this field is optional.</p> <p>If using the Fargate launch type, this field is required and you must use one of the following values, which determines your range of supported values for the <code>cpu</code> parameter:</p> <ul> <li> <p>512 (0.5 GB), 1024 (1 GB), 2048 (2 GB) - Available <code>cpu</code> values: 256 (.25 vCPU)</p> </li> <li> <p>1024 (1 GB), 2048 (2 GB), 3072 (3 GB), 4096 (4 GB) - Available <code>cpu</code> values: 512 (.5 vCPU)</p> </li> <li> <p>2048 (2 GB), 3072 (3 GB), 4096 (4 GB), 5120 (5 GB), 6144 (6 GB), 7168 (7 GB), 8192 (8 GB) - Available <code>cpu</code> values: 1024 (1 vCPU)</p> </li> <li> <p>Between 4096 (4 GB) and 16384 (16 GB) in increments of 1024 (1 GB) - Available <code>cpu</code> values: 2048 (2 vCPU)</p> </li> <li> <p>Between 8192 (8 GB) and 30720 (30 GB) in increments of 1024 (1 GB) - Available <code>cpu</code> values: 4096 (4 vCPU)</p> </li> </ul></p>
     #[serde(rename = "memory")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1912,11 +5452,11 @@ pub struct RegisterTaskDefinitionRequest {
     /// <p>The Docker networking mode to use for the containers in the task. The valid values are <code>none</code>, <code>bridge</code>, <code>awsvpc</code>, and <code>host</code>. The default Docker network mode is <code>bridge</code>. If you are using the Fargate launch type, the <code>awsvpc</code> network mode is required. If you are using the EC2 launch type, any network mode can be used. If the network mode is set to <code>none</code>, you cannot specify port mappings in your container definitions, and the tasks containers do not have external connectivity. The <code>host</code> and <code>awsvpc</code> network modes offer the highest networking performance for containers because they use the EC2 network stack instead of the virtualized network stack provided by the <code>bridge</code> mode.</p> <p>With the <code>host</code> and <code>awsvpc</code> network modes, exposed container ports are mapped directly to the corresponding host port (for the <code>host</code> network mode) or the attached elastic network interface port (for the <code>awsvpc</code> network mode), so you cannot take advantage of dynamic host port mappings. </p> <p>If the network mode is <code>awsvpc</code>, the task is allocated an elastic network interface, and you must specify a <a>NetworkConfiguration</a> value when you create a service or run a task with the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>Currently, only Amazon ECS-optimized AMIs, other Amazon Linux variants with the <code>ecs-init</code> package, or AWS Fargate infrastructure support the <code>awsvpc</code> network mode. </p> </note> <p>If the network mode is <code>host</code>, you cannot run multiple instantiations of the same task on a single container instance when port mappings are used.</p> <p>Docker for Windows uses different network modes than Docker for Linux. When you register a task definition with Windows containers, you must not specify a network mode. If you use the console to register a task definition with Windows containers, you must choose the <code>&lt;default&gt;</code> network mode object. </p> <p>For more information, see <a href="https://docs.docker.com/engine/reference/run/#network-settings">Network settings</a> in the <i>Docker run reference</i>.</p>
     #[serde(rename = "networkMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub network_mode: Option<String>,
+    pub network_mode: Option<NetworkMode>,
     /// <p><p>The process namespace to use for the containers in the task. The valid values are <code>host</code> or <code>task</code>. If <code>host</code> is specified, then all containers within the tasks that specified the <code>host</code> PID mode on the same container instance share the same IPC resources with the host Amazon EC2 instance. If <code>task</code> is specified, all containers within the specified task share the same process namespace. If no value is specified, the default is a private namespace. For more information, see <a href="https://docs.docker.com/engine/reference/run/#pid-settings---pid">PID settings</a> in the <i>Docker run reference</i>.</p> <p>If the <code>host</code> PID mode is used, be aware that there is a heightened risk of undesired process namespace expose. For more information, see <a href="https://docs.docker.com/engine/security/security/">Docker security</a>.</p> <note> <p>This parameter is not supported for Windows containers or tasks using the Fargate launch type.</p> </note></p>
     #[serde(rename = "pidMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pid_mode: Option<String>,
+    pub pid_mode: Option<PidMode>,
     /// <p>An array of placement constraint objects to use for the task. You can specify a maximum of 10 constraints per task (this limit includes constraints in the task definition and those specified at runtime).</p>
     #[serde(rename = "placementConstraints")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1927,7 +5467,7 @@ pub struct RegisterTaskDefinitionRequest {
     /// <p>The launch type required by the task. If no value is specified, it defaults to <code>EC2</code>.</p>
     #[serde(rename = "requiresCompatibilities")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requires_compatibilities: Option<Vec<String>>,
+    pub requires_compatibilities: Option<Vec<Compatibility>>,
     /// <p><p>The metadata that you apply to the task definition to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define.</p> <p>The following basic restrictions apply to tags:</p> <ul> <li> <p>Maximum number of tags per resource - 50</p> </li> <li> <p>For each resource, each tag key must be unique, and each tag key can have only one value.</p> </li> <li> <p>Maximum key length - 128 Unicode characters in UTF-8</p> </li> <li> <p>Maximum value length - 256 Unicode characters in UTF-8</p> </li> <li> <p>If your tagging schema is used across multiple services and resources, remember that other services may have restrictions on allowed characters. Generally allowed characters are: letters, numbers, and spaces representable in UTF-8, and the following characters: + - = . _ : / @.</p> </li> <li> <p>Tag keys and values are case-sensitive.</p> </li> <li> <p>Do not use <code>aws:</code>, <code>AWS:</code>, or any upper or lowercase combination of such as a prefix for either keys or values as it is reserved for AWS use. You cannot edit or delete tag keys or values with this prefix. Tags with this prefix do not count against your tags per resource limit.</p> </li> </ul></p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1942,6 +5482,155 @@ pub struct RegisterTaskDefinitionRequest {
     pub volumes: Option<Vec<Volume>>,
 }
 
+impl RegisterTaskDefinitionRequest {
+    /// Returns a builder for `RegisterTaskDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> RegisterTaskDefinitionRequestBuilder {
+        RegisterTaskDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterTaskDefinitionRequest`](struct.RegisterTaskDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterTaskDefinitionRequestBuilder {
+    container_definitions: Option<Vec<ContainerDefinition>>,
+    cpu: Option<String>,
+    execution_role_arn: Option<String>,
+    family: Option<String>,
+    inference_accelerators: Option<Vec<InferenceAccelerator>>,
+    ipc_mode: Option<IpcMode>,
+    memory: Option<String>,
+    network_mode: Option<NetworkMode>,
+    pid_mode: Option<PidMode>,
+    placement_constraints: Option<Vec<TaskDefinitionPlacementConstraint>>,
+    proxy_configuration: Option<ProxyConfiguration>,
+    requires_compatibilities: Option<Vec<Compatibility>>,
+    tags: Option<Vec<Tag>>,
+    task_role_arn: Option<String>,
+    volumes: Option<Vec<Volume>>,
+}
+
+impl RegisterTaskDefinitionRequestBuilder {
+    /// Sets the [`container_definitions`](struct.RegisterTaskDefinitionRequest.html#structfield.container_definitions) member.
+    pub fn container_definitions<T: Into<Vec<ContainerDefinition>>>(mut self, value: T) -> Self {
+        self.container_definitions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cpu`](struct.RegisterTaskDefinitionRequest.html#structfield.cpu) member.
+    pub fn cpu<T: Into<String>>(mut self, value: T) -> Self {
+        self.cpu = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_role_arn`](struct.RegisterTaskDefinitionRequest.html#structfield.execution_role_arn) member.
+    pub fn execution_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.execution_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`family`](struct.RegisterTaskDefinitionRequest.html#structfield.family) member.
+    pub fn family<T: Into<String>>(mut self, value: T) -> Self {
+        self.family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`inference_accelerators`](struct.RegisterTaskDefinitionRequest.html#structfield.inference_accelerators) member.
+    pub fn inference_accelerators<T: Into<Vec<InferenceAccelerator>>>(mut self, value: T) -> Self {
+        self.inference_accelerators = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ipc_mode`](struct.RegisterTaskDefinitionRequest.html#structfield.ipc_mode) member.
+    pub fn ipc_mode<T: Into<IpcMode>>(mut self, value: T) -> Self {
+        self.ipc_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`memory`](struct.RegisterTaskDefinitionRequest.html#structfield.memory) member.
+    pub fn memory<T: Into<String>>(mut self, value: T) -> Self {
+        self.memory = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_mode`](struct.RegisterTaskDefinitionRequest.html#structfield.network_mode) member.
+    pub fn network_mode<T: Into<NetworkMode>>(mut self, value: T) -> Self {
+        self.network_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pid_mode`](struct.RegisterTaskDefinitionRequest.html#structfield.pid_mode) member.
+    pub fn pid_mode<T: Into<PidMode>>(mut self, value: T) -> Self {
+        self.pid_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_constraints`](struct.RegisterTaskDefinitionRequest.html#structfield.placement_constraints) member.
+    pub fn placement_constraints<T: Into<Vec<TaskDefinitionPlacementConstraint>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.placement_constraints = Some(value.into());
+        self
+    }
+
+    /// Sets the [`proxy_configuration`](struct.RegisterTaskDefinitionRequest.html#structfield.proxy_configuration) member.
+    pub fn proxy_configuration<T: Into<ProxyConfiguration>>(mut self, value: T) -> Self {
+        self.proxy_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`requires_compatibilities`](struct.RegisterTaskDefinitionRequest.html#structfield.requires_compatibilities) member.
+    pub fn requires_compatibilities<T: Into<Vec<Compatibility>>>(mut self, value: T) -> Self {
+        self.requires_compatibilities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RegisterTaskDefinitionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_role_arn`](struct.RegisterTaskDefinitionRequest.html#structfield.task_role_arn) member.
+    pub fn task_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volumes`](struct.RegisterTaskDefinitionRequest.html#structfield.volumes) member.
+    pub fn volumes<T: Into<Vec<Volume>>>(mut self, value: T) -> Self {
+        self.volumes = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterTaskDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterTaskDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterTaskDefinitionRequest {
+            container_definitions: self
+                .container_definitions
+                .ok_or(::rusoto_core::BuildError::missing("container_definitions"))?,
+            cpu: self.cpu,
+            execution_role_arn: self.execution_role_arn,
+            family: self
+                .family
+                .ok_or(::rusoto_core::BuildError::missing("family"))?,
+            inference_accelerators: self.inference_accelerators,
+            ipc_mode: self.ipc_mode,
+            memory: self.memory,
+            network_mode: self.network_mode,
+            pid_mode: self.pid_mode,
+            placement_constraints: self.placement_constraints,
+            proxy_configuration: self.proxy_configuration,
+            requires_compatibilities: self.requires_compatibilities,
+            tags: self.tags,
+            task_role_arn: self.task_role_arn,
+            volumes: self.volumes,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RegisterTaskDefinitionResponse {
@@ -1993,16 +5682,88 @@ pub struct Resource {
 }
 
 /// <p>The type and amount of a resource to assign to a container. The supported resource types are GPUs and Elastic Inference accelerators. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-gpu.html">Working with GPUs on Amazon ECS</a> or <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs-eia.html">Working with Amazon Elastic Inference on Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i> </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceRequirement {
     /// <p>The type of resource to assign to a container. The supported values are <code>GPU</code> or <code>InferenceAccelerator</code>.</p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ResourceType,
     /// <p>The value for the specified resource type.</p> <p>If the <code>GPU</code> type is used, the value is the number of physical <code>GPUs</code> the Amazon ECS container agent will reserve for the container. The number of GPUs reserved for all containers in a task should not exceed the number of available GPUs on the container instance the task is launched on.</p> <p>If the <code>InferenceAccelerator</code> type is used, the <code>value</code> should match the <code>deviceName</code> for an <a>InferenceAccelerator</a> specified in a task definition.</p>
     #[serde(rename = "value")]
     pub value: String,
 }
 
+/// <p>The type of resource to assign to a container. The supported values are <code>GPU</code> or <code>InferenceAccelerator</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// <p><code>GPU</code></p>
+    Gpu,
+    /// <p><code>InferenceAccelerator</code></p>
+    InferenceAccelerator,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Gpu => "GPU",
+            ResourceType::InferenceAccelerator => "InferenceAccelerator",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "GPU" => ResourceType::Gpu,
+            "InferenceAccelerator" => ResourceType::InferenceAccelerator,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        ResourceType::from(value.as_str())
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(value: ResourceType) -> String {
+        match value {
+            ResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RunTaskRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster on which to run your task. If you do not specify a cluster, the default cluster is assumed.</p>
@@ -2024,7 +5785,7 @@ pub struct RunTaskRequest {
     /// <p>The launch type on which to run your task. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>The network configuration for the task. This parameter is required for task definitions that use the <code>awsvpc</code> network mode to receive their own elastic network interface, and it is not supported for other network modes. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "networkConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2048,7 +5809,7 @@ pub struct RunTaskRequest {
     /// <p><p>Specifies whether to propagate the tags from the task definition to the task. If no value is specified, the tags are not propagated. Tags can only be propagated to the task during task creation. To add tags to a task after task creation, use the <a>TagResource</a> API action.</p> <note> <p>An error will be received if you specify the <code>SERVICE</code> option when running a task.</p> </note></p>
     #[serde(rename = "propagateTags")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub propagate_tags: Option<String>,
+    pub propagate_tags: Option<PropagateTags>,
     /// <p>An optional tag specified when a task is started. For example, if you automatically trigger a task to run a batch process job, you could apply a unique identifier for that job to your task with the <code>startedBy</code> parameter. You can then identify which tasks belong to that job by filtering the results of a <a>ListTasks</a> call with the <code>startedBy</code> value. Up to 36 letters (uppercase and lowercase), numbers, hyphens, and underscores are allowed.</p> <p>If a task is started by an Amazon ECS service, then the <code>startedBy</code> parameter contains the deployment ID of the service that starts it.</p>
     #[serde(rename = "startedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2062,6 +5823,140 @@ pub struct RunTaskRequest {
     pub task_definition: String,
 }
 
+impl RunTaskRequest {
+    /// Returns a builder for `RunTaskRequest`, checking its required members when built.
+    pub fn builder() -> RunTaskRequestBuilder {
+        RunTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RunTaskRequest`](struct.RunTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct RunTaskRequestBuilder {
+    cluster: Option<String>,
+    count: Option<i64>,
+    enable_ecs_managed_tags: Option<bool>,
+    group: Option<String>,
+    launch_type: Option<LaunchType>,
+    network_configuration: Option<NetworkConfiguration>,
+    overrides: Option<TaskOverride>,
+    placement_constraints: Option<Vec<PlacementConstraint>>,
+    placement_strategy: Option<Vec<PlacementStrategy>>,
+    platform_version: Option<String>,
+    propagate_tags: Option<PropagateTags>,
+    started_by: Option<String>,
+    tags: Option<Vec<Tag>>,
+    task_definition: Option<String>,
+}
+
+impl RunTaskRequestBuilder {
+    /// Sets the [`cluster`](struct.RunTaskRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`count`](struct.RunTaskRequest.html#structfield.count) member.
+    pub fn count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_ecs_managed_tags`](struct.RunTaskRequest.html#structfield.enable_ecs_managed_tags) member.
+    pub fn enable_ecs_managed_tags<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_ecs_managed_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group`](struct.RunTaskRequest.html#structfield.group) member.
+    pub fn group<T: Into<String>>(mut self, value: T) -> Self {
+        self.group = Some(value.into());
+        self
+    }
+
+    /// Sets the [`launch_type`](struct.RunTaskRequest.html#structfield.launch_type) member.
+    pub fn launch_type<T: Into<LaunchType>>(mut self, value: T) -> Self {
+        self.launch_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_configuration`](struct.RunTaskRequest.html#structfield.network_configuration) member.
+    pub fn network_configuration<T: Into<NetworkConfiguration>>(mut self, value: T) -> Self {
+        self.network_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`overrides`](struct.RunTaskRequest.html#structfield.overrides) member.
+    pub fn overrides<T: Into<TaskOverride>>(mut self, value: T) -> Self {
+        self.overrides = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_constraints`](struct.RunTaskRequest.html#structfield.placement_constraints) member.
+    pub fn placement_constraints<T: Into<Vec<PlacementConstraint>>>(mut self, value: T) -> Self {
+        self.placement_constraints = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_strategy`](struct.RunTaskRequest.html#structfield.placement_strategy) member.
+    pub fn placement_strategy<T: Into<Vec<PlacementStrategy>>>(mut self, value: T) -> Self {
+        self.placement_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_version`](struct.RunTaskRequest.html#structfield.platform_version) member.
+    pub fn platform_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`propagate_tags`](struct.RunTaskRequest.html#structfield.propagate_tags) member.
+    pub fn propagate_tags<T: Into<PropagateTags>>(mut self, value: T) -> Self {
+        self.propagate_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`started_by`](struct.RunTaskRequest.html#structfield.started_by) member.
+    pub fn started_by<T: Into<String>>(mut self, value: T) -> Self {
+        self.started_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RunTaskRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_definition`](struct.RunTaskRequest.html#structfield.task_definition) member.
+    pub fn task_definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_definition = Some(value.into());
+        self
+    }
+
+    /// Builds the `RunTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RunTaskRequest, ::rusoto_core::BuildError> {
+        Ok(RunTaskRequest {
+            cluster: self.cluster,
+            count: self.count,
+            enable_ecs_managed_tags: self.enable_ecs_managed_tags,
+            group: self.group,
+            launch_type: self.launch_type,
+            network_configuration: self.network_configuration,
+            overrides: self.overrides,
+            placement_constraints: self.placement_constraints,
+            placement_strategy: self.placement_strategy,
+            platform_version: self.platform_version,
+            propagate_tags: self.propagate_tags,
+            started_by: self.started_by,
+            tags: self.tags,
+            task_definition: self
+                .task_definition
+                .ok_or(::rusoto_core::BuildError::missing("task_definition"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RunTaskResponse {
@@ -2081,13 +5976,225 @@ pub struct Scale {
     /// <p>The unit of measure for the scale value.</p>
     #[serde(rename = "unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<ScaleUnit>,
     /// <p>The value, specified as a percent total of a service's <code>desiredCount</code>, to scale the task set. Accepted values are numbers between 0 and 100.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<f64>,
 }
 
+/// <p>The unit of measure for the scale value.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScaleUnit {
+    /// <p><code>PERCENT</code></p>
+    Percent,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScaleUnit {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScaleUnit::Percent => "PERCENT",
+            ScaleUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScaleUnit {
+    fn from(value: &'a str) -> ScaleUnit {
+        match value {
+            "PERCENT" => ScaleUnit::Percent,
+            _ => ScaleUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScaleUnit {
+    fn from(value: String) -> ScaleUnit {
+        ScaleUnit::from(value.as_str())
+    }
+}
+
+impl From<ScaleUnit> for String {
+    fn from(value: ScaleUnit) -> String {
+        match value {
+            ScaleUnit::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScaleUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScaleUnit {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScaleUnit {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ScaleUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScaleUnit::from(value))
+    }
+}
+
+/// <p><p>The scheduling strategy to use for the service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Services</a>.</p> <p>There are two service scheduler strategies available:</p> <ul> <li> <p> <code>REPLICA</code>-The replica scheduling strategy places and maintains the desired number of tasks across your cluster. By default, the service scheduler spreads tasks across Availability Zones. You can use task placement strategies and constraints to customize task placement decisions. This scheduler strategy is required if the service is using the <code>CODE<em>DEPLOY</code> or <code>EXTERNAL</code> deployment controller types.</p> </li> <li> <p> <code>DAEMON</code>-The daemon scheduling strategy deploys exactly one task on each active container instance that meets all of the task placement constraints that you specify in your cluster. When you&#39;re using this strategy, you don&#39;t need to specify a desired number of tasks, a task placement strategy, or use Service Auto Scaling policies.</p> <note> <p>Tasks using the Fargate launch type or the <code>CODE</em>DEPLOY</code> or <code>EXTERNAL</code> deployment controller types don&#39;t support the <code>DAEMON</code> scheduling strategy.</p> </note> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SchedulingStrategy {
+    /// <p><code>REPLICA</code> - The replica scheduling strategy places and maintains the desired number of tasks across your cluster. By default, the service scheduler spreads tasks across Availability Zones. You can use task placement strategies and constraints to customize task placement decisions. This scheduler strategy is required if the service is using the <code>CODE<em>DEPLOY</code> or <code>EXTERNAL</code> deployment controller types.</p>
+    Replica,
+    /// <p><code>DAEMON</code> - The daemon scheduling strategy deploys exactly one task on each active container instance that meets all of the task placement constraints that you specify in your cluster. When you&#39;re using this strategy, you don&#39;t need to specify a desired number of tasks, a task placement strategy, or use Service Auto Scaling policies.</p>
+    Daemon,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SchedulingStrategy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SchedulingStrategy::Replica => "REPLICA",
+            SchedulingStrategy::Daemon => "DAEMON",
+            SchedulingStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SchedulingStrategy {
+    fn from(value: &'a str) -> SchedulingStrategy {
+        match value {
+            "REPLICA" => SchedulingStrategy::Replica,
+            "DAEMON" => SchedulingStrategy::Daemon,
+            _ => SchedulingStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SchedulingStrategy {
+    fn from(value: String) -> SchedulingStrategy {
+        SchedulingStrategy::from(value.as_str())
+    }
+}
+
+impl From<SchedulingStrategy> for String {
+    fn from(value: SchedulingStrategy) -> String {
+        match value {
+            SchedulingStrategy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SchedulingStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SchedulingStrategy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SchedulingStrategy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SchedulingStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SchedulingStrategy::from(value))
+    }
+}
+
+/// <p>The scope for the Docker volume that determines its lifecycle. Docker volumes that are scoped to a <code>task</code> are automatically provisioned when the task starts and destroyed when the task stops. Docker volumes that are scoped as <code>shared</code> persist after the task stops.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// <p><code>task</code></p>
+    Task,
+    /// <p><code>shared</code></p>
+    Shared,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Scope {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Scope::Task => "task",
+            Scope::Shared => "shared",
+            Scope::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Scope {
+    fn from(value: &'a str) -> Scope {
+        match value {
+            "task" => Scope::Task,
+            "shared" => Scope::Shared,
+            _ => Scope::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Scope {
+    fn from(value: String) -> Scope {
+        Scope::from(value.as_str())
+    }
+}
+
+impl From<Scope> for String {
+    fn from(value: Scope) -> String {
+        match value {
+            Scope::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Scope {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Scope {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Scope, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Scope::from(value))
+    }
+}
+
 /// <p>An object representing the secret to expose to your container. Secrets can be exposed to a container in the following ways:</p> <ul> <li> <p>To inject sensitive data into your containers as environment variables, use the <code>secrets</code> container definition parameter.</p> </li> <li> <p>To reference sensitive information in the log configuration of a container, use the <code>secretOptions</code> container definition parameter.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/specifying-sensitive-data.html">Specifying Sensitive Data</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Secret {
@@ -2109,8 +6216,9 @@ pub struct Service {
     pub cluster_arn: Option<String>,
     /// <p>The Unix timestamp for when the service was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The principal that created the service.</p>
     #[serde(rename = "createdBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2146,7 +6254,7 @@ pub struct Service {
     /// <p>The launch type on which your service is running. If no value is specified, it will default to <code>EC2</code>. Valid values include <code>EC2</code> and <code>FARGATE</code>. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>A list of Elastic Load Balancing load balancer objects, containing the load balancer name, the container name (as it appears in a container definition), and the container port to access from the load balancer.</p>
     #[serde(rename = "loadBalancers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2174,7 +6282,7 @@ pub struct Service {
     /// <p>Specifies whether to propagate the tags from the task definition or the service to the task. If no value is specified, the tags are not propagated.</p>
     #[serde(rename = "propagateTags")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub propagate_tags: Option<String>,
+    pub propagate_tags: Option<PropagateTags>,
     /// <p>The ARN of the IAM role associated with the service that allows the Amazon ECS container agent to register container instances with an Elastic Load Balancing load balancer.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2186,7 +6294,7 @@ pub struct Service {
     /// <p><p>The scheduling strategy to use for the service. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/ecs_services.html">Services</a>.</p> <p>There are two service scheduler strategies available:</p> <ul> <li> <p> <code>REPLICA</code>-The replica scheduling strategy places and maintains the desired number of tasks across your cluster. By default, the service scheduler spreads tasks across Availability Zones. You can use task placement strategies and constraints to customize task placement decisions.</p> </li> <li> <p> <code>DAEMON</code>-The daemon scheduling strategy deploys exactly one task on each container instance in your cluster. When you are using this strategy, do not specify a desired number of tasks or any task placement strategies.</p> <note> <p>Fargate tasks do not support the <code>DAEMON</code> scheduling strategy.</p> </note> </li> </ul></p>
     #[serde(rename = "schedulingStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduling_strategy: Option<String>,
+    pub scheduling_strategy: Option<SchedulingStrategy>,
     /// <p>The ARN that identifies the service. The ARN contains the <code>arn:aws:ecs</code> namespace, followed by the Region of the service, the AWS account ID of the service owner, the <code>service</code> namespace, and then the service name. For example, <code>arn:aws:ecs:region:012345678910:service/my-service</code>.</p>
     #[serde(rename = "serviceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2223,8 +6331,9 @@ pub struct Service {
 pub struct ServiceEvent {
     /// <p>The Unix timestamp for when the event was triggered.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The ID string of the event.</p>
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2235,6 +6344,74 @@ pub struct ServiceEvent {
     pub message: Option<String>,
 }
 
+/// <p>Specifies whether you want to see the resource tags for the service. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceField {
+    /// <p><code>TAGS</code></p>
+    Tags,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServiceField {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceField::Tags => "TAGS",
+            ServiceField::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServiceField {
+    fn from(value: &'a str) -> ServiceField {
+        match value {
+            "TAGS" => ServiceField::Tags,
+            _ => ServiceField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceField {
+    fn from(value: String) -> ServiceField {
+        ServiceField::from(value.as_str())
+    }
+}
+
+impl From<ServiceField> for String {
+    fn from(value: ServiceField) -> String {
+        match value {
+            ServiceField::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServiceField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ServiceField {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceField {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ServiceField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ServiceField::from(value))
+    }
+}
+
 /// <p>Details of the service registry.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ServiceRegistry {
@@ -2263,7 +6440,7 @@ pub struct Setting {
     /// <p>The Amazon ECS resource name.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<SettingName>,
     /// <p>The ARN of the principal, which can be an IAM user, IAM role, or the root user. If this field is omitted, the authenticated user is assumed.</p>
     #[serde(rename = "principalArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2274,6 +6451,250 @@ pub struct Setting {
     pub value: Option<String>,
 }
 
+/// <p>The resource name for which to disable the account setting. If <code>serviceLongArnFormat</code> is specified, the ARN for your Amazon ECS services is affected. If <code>taskLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS tasks is affected. If <code>containerInstanceLongArnFormat</code> is specified, the ARN and resource ID for your Amazon ECS container instances is affected. If <code>awsvpcTrunking</code> is specified, the ENI limit for your Amazon ECS container instances is affected.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SettingName {
+    /// <p><code>serviceLongArnFormat</code></p>
+    ServiceLongArnFormat,
+    /// <p><code>taskLongArnFormat</code></p>
+    TaskLongArnFormat,
+    /// <p><code>containerInstanceLongArnFormat</code></p>
+    ContainerInstanceLongArnFormat,
+    /// <p><code>awsvpcTrunking</code></p>
+    AwsvpcTrunking,
+    /// <p><code>containerInsights</code></p>
+    ContainerInsights,
+    /// <p><code>fargateFIPSMode</code></p>
+    FargateFIPSMode,
+    /// <p><code>tagResourceAuthorization</code></p>
+    TagResourceAuthorization,
+    /// <p><code>fargateTaskRetirementWaitPeriod</code></p>
+    FargateTaskRetirementWaitPeriod,
+    /// <p><code>guardDutyActivate</code></p>
+    GuardDutyActivate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SettingName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SettingName::ServiceLongArnFormat => "serviceLongArnFormat",
+            SettingName::TaskLongArnFormat => "taskLongArnFormat",
+            SettingName::ContainerInstanceLongArnFormat => "containerInstanceLongArnFormat",
+            SettingName::AwsvpcTrunking => "awsvpcTrunking",
+            SettingName::ContainerInsights => "containerInsights",
+            SettingName::FargateFIPSMode => "fargateFIPSMode",
+            SettingName::TagResourceAuthorization => "tagResourceAuthorization",
+            SettingName::FargateTaskRetirementWaitPeriod => "fargateTaskRetirementWaitPeriod",
+            SettingName::GuardDutyActivate => "guardDutyActivate",
+            SettingName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SettingName {
+    fn from(value: &'a str) -> SettingName {
+        match value {
+            "serviceLongArnFormat" => SettingName::ServiceLongArnFormat,
+            "taskLongArnFormat" => SettingName::TaskLongArnFormat,
+            "containerInstanceLongArnFormat" => SettingName::ContainerInstanceLongArnFormat,
+            "awsvpcTrunking" => SettingName::AwsvpcTrunking,
+            "containerInsights" => SettingName::ContainerInsights,
+            "fargateFIPSMode" => SettingName::FargateFIPSMode,
+            "tagResourceAuthorization" => SettingName::TagResourceAuthorization,
+            "fargateTaskRetirementWaitPeriod" => SettingName::FargateTaskRetirementWaitPeriod,
+            "guardDutyActivate" => SettingName::GuardDutyActivate,
+            _ => SettingName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SettingName {
+    fn from(value: String) -> SettingName {
+        SettingName::from(value.as_str())
+    }
+}
+
+impl From<SettingName> for String {
+    fn from(value: SettingName) -> String {
+        match value {
+            SettingName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SettingName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SettingName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SettingName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SettingName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SettingName::from(value))
+    }
+}
+
+/// <p>The order in which to sort the results. Valid values are <code>ASC</code> and <code>DESC</code>. By default (<code>ASC</code>), task definitions are listed lexicographically by family name and in ascending numerical order by revision so that the newest task definitions in a family are listed last. Setting this parameter to <code>DESC</code> reverses the sort order on family name and revision so that the newest task definitions in a family are listed first.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// <p><code>ASC</code></p>
+    Asc,
+    /// <p><code>DESC</code></p>
+    Desc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SortOrder {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+            SortOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SortOrder {
+    fn from(value: &'a str) -> SortOrder {
+        match value {
+            "ASC" => SortOrder::Asc,
+            "DESC" => SortOrder::Desc,
+            _ => SortOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrder {
+    fn from(value: String) -> SortOrder {
+        SortOrder::from(value.as_str())
+    }
+}
+
+impl From<SortOrder> for String {
+    fn from(value: SortOrder) -> String {
+        match value {
+            SortOrder::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SortOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SortOrder::from(value))
+    }
+}
+
+/// <p>The stability status, which indicates whether the task set has reached a steady state. If the following conditions are met, the task set will be in <code>STEADY_STATE</code>:</p> <ul> <li> <p>The task <code>runningCount</code> is equal to the <code>computedDesiredCount</code>.</p> </li> <li> <p>The <code>pendingCount</code> is <code>0</code>.</p> </li> <li> <p>There are no tasks running on container instances in the <code>DRAINING</code> status.</p> </li> <li> <p>All tasks are reporting a healthy status from the load balancers, service discovery, and container health checks.</p> </li> </ul> <p>If any of those conditions are not met, the stability status returns <code>STABILIZING</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StabilityStatus {
+    /// <p><code>STEADY_STATE</code> - </p> <ul> <li> <p>The task <code>runningCount</code> is equal to the <code>computedDesiredCount</code>.</p>
+    SteadyState,
+    /// <p><code>STABILIZING</code></p>
+    Stabilizing,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StabilityStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StabilityStatus::SteadyState => "STEADY_STATE",
+            StabilityStatus::Stabilizing => "STABILIZING",
+            StabilityStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StabilityStatus {
+    fn from(value: &'a str) -> StabilityStatus {
+        match value {
+            "STEADY_STATE" => StabilityStatus::SteadyState,
+            "STABILIZING" => StabilityStatus::Stabilizing,
+            _ => StabilityStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StabilityStatus {
+    fn from(value: String) -> StabilityStatus {
+        StabilityStatus::from(value.as_str())
+    }
+}
+
+impl From<StabilityStatus> for String {
+    fn from(value: StabilityStatus) -> String {
+        match value {
+            StabilityStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StabilityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StabilityStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StabilityStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StabilityStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StabilityStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartTaskRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster on which to start your task. If you do not specify a cluster, the default cluster is assumed.</p>
@@ -2302,7 +6723,7 @@ pub struct StartTaskRequest {
     /// <p>Specifies whether to propagate the tags from the task definition or the service to the task. If no value is specified, the tags are not propagated.</p>
     #[serde(rename = "propagateTags")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub propagate_tags: Option<String>,
+    pub propagate_tags: Option<PropagateTags>,
     /// <p>An optional tag specified when a task is started. For example, if you automatically trigger a task to run a batch process job, you could apply a unique identifier for that job to your task with the <code>startedBy</code> parameter. You can then identify which tasks belong to that job by filtering the results of a <a>ListTasks</a> call with the <code>startedBy</code> value. Up to 36 letters (uppercase and lowercase), numbers, hyphens, and underscores are allowed.</p> <p>If a task is started by an Amazon ECS service, then the <code>startedBy</code> parameter contains the deployment ID of the service that starts it.</p>
     #[serde(rename = "startedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2316,6 +6737,110 @@ pub struct StartTaskRequest {
     pub task_definition: String,
 }
 
+impl StartTaskRequest {
+    /// Returns a builder for `StartTaskRequest`, checking its required members when built.
+    pub fn builder() -> StartTaskRequestBuilder {
+        StartTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartTaskRequest`](struct.StartTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct StartTaskRequestBuilder {
+    cluster: Option<String>,
+    container_instances: Option<Vec<String>>,
+    enable_ecs_managed_tags: Option<bool>,
+    group: Option<String>,
+    network_configuration: Option<NetworkConfiguration>,
+    overrides: Option<TaskOverride>,
+    propagate_tags: Option<PropagateTags>,
+    started_by: Option<String>,
+    tags: Option<Vec<Tag>>,
+    task_definition: Option<String>,
+}
+
+impl StartTaskRequestBuilder {
+    /// Sets the [`cluster`](struct.StartTaskRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`container_instances`](struct.StartTaskRequest.html#structfield.container_instances) member.
+    pub fn container_instances<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.container_instances = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_ecs_managed_tags`](struct.StartTaskRequest.html#structfield.enable_ecs_managed_tags) member.
+    pub fn enable_ecs_managed_tags<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_ecs_managed_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group`](struct.StartTaskRequest.html#structfield.group) member.
+    pub fn group<T: Into<String>>(mut self, value: T) -> Self {
+        self.group = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_configuration`](struct.StartTaskRequest.html#structfield.network_configuration) member.
+    pub fn network_configuration<T: Into<NetworkConfiguration>>(mut self, value: T) -> Self {
+        self.network_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`overrides`](struct.StartTaskRequest.html#structfield.overrides) member.
+    pub fn overrides<T: Into<TaskOverride>>(mut self, value: T) -> Self {
+        self.overrides = Some(value.into());
+        self
+    }
+
+    /// Sets the [`propagate_tags`](struct.StartTaskRequest.html#structfield.propagate_tags) member.
+    pub fn propagate_tags<T: Into<PropagateTags>>(mut self, value: T) -> Self {
+        self.propagate_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`started_by`](struct.StartTaskRequest.html#structfield.started_by) member.
+    pub fn started_by<T: Into<String>>(mut self, value: T) -> Self {
+        self.started_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.StartTaskRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_definition`](struct.StartTaskRequest.html#structfield.task_definition) member.
+    pub fn task_definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_definition = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartTaskRequest, ::rusoto_core::BuildError> {
+        Ok(StartTaskRequest {
+            cluster: self.cluster,
+            container_instances: self
+                .container_instances
+                .ok_or(::rusoto_core::BuildError::missing("container_instances"))?,
+            enable_ecs_managed_tags: self.enable_ecs_managed_tags,
+            group: self.group,
+            network_configuration: self.network_configuration,
+            overrides: self.overrides,
+            propagate_tags: self.propagate_tags,
+            started_by: self.started_by,
+            tags: self.tags,
+            task_definition: self
+                .task_definition
+                .ok_or(::rusoto_core::BuildError::missing("task_definition"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartTaskResponse {
@@ -2344,6 +6869,52 @@ pub struct StopTaskRequest {
     pub task: String,
 }
 
+impl StopTaskRequest {
+    /// Returns a builder for `StopTaskRequest`, checking its required members when built.
+    pub fn builder() -> StopTaskRequestBuilder {
+        StopTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopTaskRequest`](struct.StopTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct StopTaskRequestBuilder {
+    cluster: Option<String>,
+    reason: Option<String>,
+    task: Option<String>,
+}
+
+impl StopTaskRequestBuilder {
+    /// Sets the [`cluster`](struct.StopTaskRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reason`](struct.StopTaskRequest.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task`](struct.StopTaskRequest.html#structfield.task) member.
+    pub fn task<T: Into<String>>(mut self, value: T) -> Self {
+        self.task = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopTaskRequest, ::rusoto_core::BuildError> {
+        Ok(StopTaskRequest {
+            cluster: self.cluster,
+            reason: self.reason,
+            task: self
+                .task
+                .ok_or(::rusoto_core::BuildError::missing("task"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopTaskResponse {
@@ -2364,6 +6935,46 @@ pub struct SubmitAttachmentStateChangesRequest {
     pub cluster: Option<String>,
 }
 
+impl SubmitAttachmentStateChangesRequest {
+    /// Returns a builder for `SubmitAttachmentStateChangesRequest`, checking its required members when built.
+    pub fn builder() -> SubmitAttachmentStateChangesRequestBuilder {
+        SubmitAttachmentStateChangesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SubmitAttachmentStateChangesRequest`](struct.SubmitAttachmentStateChangesRequest.html).
+#[derive(Default, Debug)]
+pub struct SubmitAttachmentStateChangesRequestBuilder {
+    attachments: Option<Vec<AttachmentStateChange>>,
+    cluster: Option<String>,
+}
+
+impl SubmitAttachmentStateChangesRequestBuilder {
+    /// Sets the [`attachments`](struct.SubmitAttachmentStateChangesRequest.html#structfield.attachments) member.
+    pub fn attachments<T: Into<Vec<AttachmentStateChange>>>(mut self, value: T) -> Self {
+        self.attachments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster`](struct.SubmitAttachmentStateChangesRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Builds the `SubmitAttachmentStateChangesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SubmitAttachmentStateChangesRequest, ::rusoto_core::BuildError> {
+        Ok(SubmitAttachmentStateChangesRequest {
+            attachments: self
+                .attachments
+                .ok_or(::rusoto_core::BuildError::missing("attachments"))?,
+            cluster: self.cluster,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SubmitAttachmentStateChangesResponse {
@@ -2409,6 +7020,92 @@ pub struct SubmitContainerStateChangeRequest {
     pub task: Option<String>,
 }
 
+impl SubmitContainerStateChangeRequest {
+    /// Returns a builder for `SubmitContainerStateChangeRequest`, checking its required members when built.
+    pub fn builder() -> SubmitContainerStateChangeRequestBuilder {
+        SubmitContainerStateChangeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SubmitContainerStateChangeRequest`](struct.SubmitContainerStateChangeRequest.html).
+#[derive(Default, Debug)]
+pub struct SubmitContainerStateChangeRequestBuilder {
+    cluster: Option<String>,
+    container_name: Option<String>,
+    exit_code: Option<i64>,
+    network_bindings: Option<Vec<NetworkBinding>>,
+    reason: Option<String>,
+    runtime_id: Option<String>,
+    status: Option<String>,
+    task: Option<String>,
+}
+
+impl SubmitContainerStateChangeRequestBuilder {
+    /// Sets the [`cluster`](struct.SubmitContainerStateChangeRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`container_name`](struct.SubmitContainerStateChangeRequest.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exit_code`](struct.SubmitContainerStateChangeRequest.html#structfield.exit_code) member.
+    pub fn exit_code<T: Into<i64>>(mut self, value: T) -> Self {
+        self.exit_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_bindings`](struct.SubmitContainerStateChangeRequest.html#structfield.network_bindings) member.
+    pub fn network_bindings<T: Into<Vec<NetworkBinding>>>(mut self, value: T) -> Self {
+        self.network_bindings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reason`](struct.SubmitContainerStateChangeRequest.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets the [`runtime_id`](struct.SubmitContainerStateChangeRequest.html#structfield.runtime_id) member.
+    pub fn runtime_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.runtime_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.SubmitContainerStateChangeRequest.html#structfield.status) member.
+    pub fn status<T: Into<String>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task`](struct.SubmitContainerStateChangeRequest.html#structfield.task) member.
+    pub fn task<T: Into<String>>(mut self, value: T) -> Self {
+        self.task = Some(value.into());
+        self
+    }
+
+    /// Builds the `SubmitContainerStateChangeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SubmitContainerStateChangeRequest, ::rusoto_core::BuildError> {
+        Ok(SubmitContainerStateChangeRequest {
+            cluster: self.cluster,
+            container_name: self.container_name,
+            exit_code: self.exit_code,
+            network_bindings: self.network_bindings,
+            reason: self.reason,
+            runtime_id: self.runtime_id,
+            status: self.status,
+            task: self.task,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SubmitContainerStateChangeResponse {
@@ -2434,16 +7131,19 @@ pub struct SubmitTaskStateChangeRequest {
     pub containers: Option<Vec<ContainerStateChange>>,
     /// <p>The Unix timestamp for when the task execution stopped.</p>
     #[serde(rename = "executionStoppedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_stopped_at: Option<f64>,
+    pub execution_stopped_at: Option<EpochTimestamp>,
     /// <p>The Unix timestamp for when the container image pull began.</p>
     #[serde(rename = "pullStartedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_started_at: Option<f64>,
+    pub pull_started_at: Option<EpochTimestamp>,
     /// <p>The Unix timestamp for when the container image pull completed.</p>
     #[serde(rename = "pullStoppedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_stopped_at: Option<f64>,
+    pub pull_stopped_at: Option<EpochTimestamp>,
     /// <p>The reason for the state change request.</p>
     #[serde(rename = "reason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2458,6 +7158,100 @@ pub struct SubmitTaskStateChangeRequest {
     pub task: Option<String>,
 }
 
+impl SubmitTaskStateChangeRequest {
+    /// Returns a builder for `SubmitTaskStateChangeRequest`, checking its required members when built.
+    pub fn builder() -> SubmitTaskStateChangeRequestBuilder {
+        SubmitTaskStateChangeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SubmitTaskStateChangeRequest`](struct.SubmitTaskStateChangeRequest.html).
+#[derive(Default, Debug)]
+pub struct SubmitTaskStateChangeRequestBuilder {
+    attachments: Option<Vec<AttachmentStateChange>>,
+    cluster: Option<String>,
+    containers: Option<Vec<ContainerStateChange>>,
+    execution_stopped_at: Option<EpochTimestamp>,
+    pull_started_at: Option<EpochTimestamp>,
+    pull_stopped_at: Option<EpochTimestamp>,
+    reason: Option<String>,
+    status: Option<String>,
+    task: Option<String>,
+}
+
+impl SubmitTaskStateChangeRequestBuilder {
+    /// Sets the [`attachments`](struct.SubmitTaskStateChangeRequest.html#structfield.attachments) member.
+    pub fn attachments<T: Into<Vec<AttachmentStateChange>>>(mut self, value: T) -> Self {
+        self.attachments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster`](struct.SubmitTaskStateChangeRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`containers`](struct.SubmitTaskStateChangeRequest.html#structfield.containers) member.
+    pub fn containers<T: Into<Vec<ContainerStateChange>>>(mut self, value: T) -> Self {
+        self.containers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_stopped_at`](struct.SubmitTaskStateChangeRequest.html#structfield.execution_stopped_at) member.
+    pub fn execution_stopped_at<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.execution_stopped_at = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pull_started_at`](struct.SubmitTaskStateChangeRequest.html#structfield.pull_started_at) member.
+    pub fn pull_started_at<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.pull_started_at = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pull_stopped_at`](struct.SubmitTaskStateChangeRequest.html#structfield.pull_stopped_at) member.
+    pub fn pull_stopped_at<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.pull_stopped_at = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reason`](struct.SubmitTaskStateChangeRequest.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.SubmitTaskStateChangeRequest.html#structfield.status) member.
+    pub fn status<T: Into<String>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task`](struct.SubmitTaskStateChangeRequest.html#structfield.task) member.
+    pub fn task<T: Into<String>>(mut self, value: T) -> Self {
+        self.task = Some(value.into());
+        self
+    }
+
+    /// Builds the `SubmitTaskStateChangeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SubmitTaskStateChangeRequest, ::rusoto_core::BuildError> {
+        Ok(SubmitTaskStateChangeRequest {
+            attachments: self.attachments,
+            cluster: self.cluster,
+            containers: self.containers,
+            execution_stopped_at: self.execution_stopped_at,
+            pull_started_at: self.pull_started_at,
+            pull_stopped_at: self.pull_stopped_at,
+            reason: self.reason,
+            status: self.status,
+            task: self.task,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SubmitTaskStateChangeResponse {
@@ -2503,10 +7297,118 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
 
+/// <p>The type of the target with which to attach the attribute. This parameter is required if you use the short form ID for a resource instead of the full ARN.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TargetType {
+    /// <p><code>container-instance</code></p>
+    ContainerInstance,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TargetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetType::ContainerInstance => "container-instance",
+            TargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TargetType {
+    fn from(value: &'a str) -> TargetType {
+        match value {
+            "container-instance" => TargetType::ContainerInstance,
+            _ => TargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetType {
+    fn from(value: String) -> TargetType {
+        TargetType::from(value.as_str())
+    }
+}
+
+impl From<TargetType> for String {
+    fn from(value: TargetType) -> String {
+        match value {
+            TargetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TargetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TargetType::from(value))
+    }
+}
+
 /// <p>Details on a task in a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2522,11 +7424,12 @@ pub struct Task {
     /// <p>The connectivity status of a task.</p>
     #[serde(rename = "connectivity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connectivity: Option<String>,
+    pub connectivity: Option<Connectivity>,
     /// <p>The Unix timestamp for when the task last went into <code>CONNECTED</code> status.</p>
     #[serde(rename = "connectivityAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connectivity_at: Option<f64>,
+    pub connectivity_at: Option<EpochTimestamp>,
     /// <p>The ARN of the container instances that host the task.</p>
     #[serde(rename = "containerInstanceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2541,16 +7444,18 @@ pub struct Task {
     pub cpu: Option<String>,
     /// <p>The Unix timestamp for when the task was created (the task entered the <code>PENDING</code> state).</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The desired status of the task. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-lifecycle.html">Task Lifecycle</a>.</p>
     #[serde(rename = "desiredStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub desired_status: Option<String>,
     /// <p>The Unix timestamp for when the task execution stopped.</p>
     #[serde(rename = "executionStoppedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_stopped_at: Option<f64>,
+    pub execution_stopped_at: Option<EpochTimestamp>,
     /// <p>The name of the task group associated with the task.</p>
     #[serde(rename = "group")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2558,7 +7463,7 @@ pub struct Task {
     /// <p><p>The health status for the task, which is determined by the health of the essential containers in the task. If all essential containers in the task are reporting as <code>HEALTHY</code>, then the task status also reports as <code>HEALTHY</code>. If any essential containers in the task are reporting as <code>UNHEALTHY</code> or <code>UNKNOWN</code>, then the task status also reports as <code>UNHEALTHY</code> or <code>UNKNOWN</code>, accordingly.</p> <note> <p>The Amazon ECS container agent does not monitor or report on Docker health checks that are embedded in a container image (such as those specified in a parent image or from the image&#39;s Dockerfile) and not specified in the container definition. Health check parameters that are specified in a container definition override any Docker health checks that exist in the container image.</p> </note></p>
     #[serde(rename = "healthStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_status: Option<String>,
+    pub health_status: Option<HealthStatus>,
     /// <p>The Elastic Inference accelerator associated with the task.</p>
     #[serde(rename = "inferenceAccelerators")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2570,7 +7475,7 @@ pub struct Task {
     /// <p>The launch type on which your task is running. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p><p>The amount of memory (in MiB) used by the task as expressed in a task definition. It can be expressed as an integer using MiB, for example <code>1024</code>. It can also be expressed as a string using GB, for example <code>1GB</code> or <code>1 GB</code>. String values are converted to an integer indicating the MiB when the task definition is registered.</p> <p>If you are using the EC2 launch type, this field is optional.</p> <p>If you are using the Fargate launch type, this field is required and you must use one of the following values, which determines your range of supported values for the <code>cpu</code> parameter:</p> <ul> <li> <p>512 (0.5 GB), 1024 (1 GB), 2048 (2 GB) - Available <code>cpu</code> values: 256 (.25 vCPU)</p> </li> <li> <p>1024 (1 GB), 2048 (2 GB), 3072 (3 GB), 4096 (4 GB) - Available <code>cpu</code> values: 512 (.5 vCPU)</p> </li> <li> <p>2048 (2 GB), 3072 (3 GB), 4096 (4 GB), 5120 (5 GB), 6144 (6 GB), 7168 (7 GB), 8192 (8 GB) - Available <code>cpu</code> values: 1024 (1 vCPU)</p> </li> <li> <p>Between 4096 (4 GB) and 16384 (16 GB) in increments of 1024 (1 GB) - Available <code>cpu</code> values: 2048 (2 vCPU)</p> </li> <li> <p>Between 8192 (8 GB) and 30720 (30 GB) in increments of 1024 (1 GB) - Available <code>cpu</code> values: 4096 (4 vCPU)</p> </li> </ul></p>
     #[serde(rename = "memory")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2585,16 +7490,19 @@ pub struct Task {
     pub platform_version: Option<String>,
     /// <p>The Unix timestamp for when the container image pull began.</p>
     #[serde(rename = "pullStartedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_started_at: Option<f64>,
+    pub pull_started_at: Option<EpochTimestamp>,
     /// <p>The Unix timestamp for when the container image pull completed.</p>
     #[serde(rename = "pullStoppedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pull_stopped_at: Option<f64>,
+    pub pull_stopped_at: Option<EpochTimestamp>,
     /// <p>The Unix timestamp for when the task started (the task transitioned from the <code>PENDING</code> state to the <code>RUNNING</code> state).</p>
     #[serde(rename = "startedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<EpochTimestamp>,
     /// <p>The tag specified when a task is started. If the task is started by an Amazon ECS service, then the <code>startedBy</code> parameter contains the deployment ID of the service that starts it.</p>
     #[serde(rename = "startedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2602,19 +7510,21 @@ pub struct Task {
     /// <p>The stop code indicating why a task was stopped. The <code>stoppedReason</code> may contain additional details.</p>
     #[serde(rename = "stopCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_code: Option<String>,
+    pub stop_code: Option<TaskStopCode>,
     /// <p>The Unix timestamp for when the task was stopped (the task transitioned from the <code>RUNNING</code> state to the <code>STOPPED</code> state).</p>
     #[serde(rename = "stoppedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopped_at: Option<f64>,
+    pub stopped_at: Option<EpochTimestamp>,
     /// <p>The reason that the task was stopped.</p>
     #[serde(rename = "stoppedReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stopped_reason: Option<String>,
     /// <p>The Unix timestamp for when the task stops (transitions from the <code>RUNNING</code> state to <code>STOPPED</code>).</p>
     #[serde(rename = "stoppingAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stopping_at: Option<f64>,
+    pub stopping_at: Option<EpochTimestamp>,
     /// <p><p>The metadata that you apply to the task to help you categorize and organize them. Each tag consists of a key and an optional value, both of which you define.</p> <p>The following basic restrictions apply to tags:</p> <ul> <li> <p>Maximum number of tags per resource - 50</p> </li> <li> <p>For each resource, each tag key must be unique, and each tag key can have only one value.</p> </li> <li> <p>Maximum key length - 128 Unicode characters in UTF-8</p> </li> <li> <p>Maximum value length - 256 Unicode characters in UTF-8</p> </li> <li> <p>If your tagging schema is used across multiple services and resources, remember that other services may have restrictions on allowed characters. Generally allowed characters are: letters, numbers, and spaces representable in UTF-8, and the following characters: + - = . _ : / @.</p> </li> <li> <p>Tag keys and values are case-sensitive.</p> </li> <li> <p>Do not use <code>aws:</code>, <code>AWS:</code>, or any upper or lowercase combination of such as a prefix for either keys or values as it is reserved for AWS use. You cannot edit or delete tag keys or values with this prefix. Tags with this prefix do not count against your tags per resource limit.</p> </li> </ul></p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2640,7 +7550,7 @@ pub struct TaskDefinition {
     /// <p>The launch type to use with your task. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "compatibilities")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatibilities: Option<Vec<String>>,
+    pub compatibilities: Option<Vec<Compatibility>>,
     /// <p>A list of container definitions in JSON format that describe the different containers that make up your task. For more information about container definition parameters and defaults, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task_defintions.html">Amazon ECS Task Definitions</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "containerDefinitions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2660,7 +7570,7 @@ pub struct TaskDefinition {
     /// <p><p>The IPC resource namespace to use for the containers in the task. The valid values are <code>host</code>, <code>task</code>, or <code>none</code>. If <code>host</code> is specified, then all containers within the tasks that specified the <code>host</code> IPC mode on the same container instance share the same IPC resources with the host Amazon EC2 instance. If <code>task</code> is specified, all containers within the specified task share the same IPC resources. If <code>none</code> is specified, then IPC resources within the containers of a task are private and not shared with other containers in a task or on the container instance. If no value is specified, then the IPC resource namespace sharing depends on the Docker daemon setting on the container instance. For more information, see <a href="https://docs.docker.com/engine/reference/run/#ipc-settings---ipc">IPC settings</a> in the <i>Docker run reference</i>.</p> <p>If the <code>host</code> IPC mode is used, be aware that there is a heightened risk of undesired IPC namespace expose. For more information, see <a href="https://docs.docker.com/engine/security/security/">Docker security</a>.</p> <p>If you are setting namespaced kernel parameters using <code>systemControls</code> for the containers in the task, the following will apply to your IPC resource namespace. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task_definition_parameters.html">System Controls</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <ul> <li> <p>For tasks that use the <code>host</code> IPC mode, IPC namespace related <code>systemControls</code> are not supported.</p> </li> <li> <p>For tasks that use the <code>task</code> IPC mode, IPC namespace related <code>systemControls</code> will apply to all containers within a task.</p> </li> </ul> <note> <p>This parameter is not supported for Windows containers or tasks using the Fargate launch type.</p> </note></p>
     #[serde(rename = "ipcMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ipc_mode: Option<String>,
+    pub ipc_mode: Option<IpcMode>,
     /// <p><p>The amount (in MiB) of memory used by the task.</p> <p>If using the EC2 launch type, this field is optional and any value can be used. If a task-level memory value is specified then the container-level memory value is optional.</p> <p>If using the Fargate launch type, this field is required and you must use one of the following values, which determines your range of valid values for the <code>cpu</code> parameter:</p> <ul> <li> <p>512 (0.5 GB), 1024 (1 GB), 2048 (2 GB) - Available <code>cpu</code> values: 256 (.25 vCPU)</p> </li> <li> <p>1024 (1 GB), 2048 (2 GB), 3072 (3 GB), 4096 (4 GB) - Available <code>cpu</code> values: 512 (.5 vCPU)</p> </li> <li> <p>2048 (2 GB), 3072 (3 GB), 4096 (4 GB), 5120 (5 GB), 6144 (6 GB), 7168 (7 GB), 8192 (8 GB) - Available <code>cpu</code> values: 1024 (1 vCPU)</p> </li> <li> <p>Between 4096 (4 GB) and 16384 (16 GB) in increments of 1024 (1 GB) - Available <code>cpu</code> values: 2048 (2 vCPU)</p> </li> <li> <p>Between 8192 (8 GB) and 30720 (30 GB) in increments of 1024 (1 GB) - Available <code>cpu</code> values: 4096 (4 vCPU)</p> </li> </ul></p>
     #[serde(rename = "memory")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2668,11 +7578,11 @@ pub struct TaskDefinition {
     /// <p>The Docker networking mode to use for the containers in the task. The valid values are <code>none</code>, <code>bridge</code>, <code>awsvpc</code>, and <code>host</code>. The default Docker network mode is <code>bridge</code>. If you are using the Fargate launch type, the <code>awsvpc</code> network mode is required. If you are using the EC2 launch type, any network mode can be used. If the network mode is set to <code>none</code>, you cannot specify port mappings in your container definitions, and the tasks containers do not have external connectivity. The <code>host</code> and <code>awsvpc</code> network modes offer the highest networking performance for containers because they use the EC2 network stack instead of the virtualized network stack provided by the <code>bridge</code> mode.</p> <p>With the <code>host</code> and <code>awsvpc</code> network modes, exposed container ports are mapped directly to the corresponding host port (for the <code>host</code> network mode) or the attached elastic network interface port (for the <code>awsvpc</code> network mode), so you cannot take advantage of dynamic host port mappings. </p> <p>If the network mode is <code>awsvpc</code>, the task is allocated an elastic network interface, and you must specify a <a>NetworkConfiguration</a> value when you create a service or run a task with the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-networking.html">Task Networking</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>Currently, only Amazon ECS-optimized AMIs, other Amazon Linux variants with the <code>ecs-init</code> package, or AWS Fargate infrastructure support the <code>awsvpc</code> network mode. </p> </note> <p>If the network mode is <code>host</code>, you cannot run multiple instantiations of the same task on a single container instance when port mappings are used.</p> <p>Docker for Windows uses different network modes than Docker for Linux. When you register a task definition with Windows containers, you must not specify a network mode. If you use the console to register a task definition with Windows containers, you must choose the <code>&lt;default&gt;</code> network mode object. </p> <p>For more information, see <a href="https://docs.docker.com/engine/reference/run/#network-settings">Network settings</a> in the <i>Docker run reference</i>.</p>
     #[serde(rename = "networkMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub network_mode: Option<String>,
+    pub network_mode: Option<NetworkMode>,
     /// <p><p>The process namespace to use for the containers in the task. The valid values are <code>host</code> or <code>task</code>. If <code>host</code> is specified, then all containers within the tasks that specified the <code>host</code> PID mode on the same container instance share the same IPC resources with the host Amazon EC2 instance. If <code>task</code> is specified, all containers within the specified task share the same process namespace. If no value is specified, the default is a private namespace. For more information, see <a href="https://docs.docker.com/engine/reference/run/#pid-settings---pid">PID settings</a> in the <i>Docker run reference</i>.</p> <p>If the <code>host</code> PID mode is used, be aware that there is a heightened risk of undesired process namespace expose. For more information, see <a href="https://docs.docker.com/engine/security/security/">Docker security</a>.</p> <note> <p>This parameter is not supported for Windows containers or tasks using the Fargate launch type.</p> </note></p>
     #[serde(rename = "pidMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pid_mode: Option<String>,
+    pub pid_mode: Option<PidMode>,
     /// <p>An array of placement constraint objects to use for tasks. This field is not valid if you are using the Fargate launch type for your task.</p>
     #[serde(rename = "placementConstraints")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2688,7 +7598,7 @@ pub struct TaskDefinition {
     /// <p>The launch type the task requires. If no value is specified, it will default to <code>EC2</code>. Valid values include <code>EC2</code> and <code>FARGATE</code>.</p>
     #[serde(rename = "requiresCompatibilities")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requires_compatibilities: Option<Vec<String>>,
+    pub requires_compatibilities: Option<Vec<Compatibility>>,
     /// <p>The revision of the task in a particular family. The revision is a version number of a task definition in a family. When you register a task definition for the first time, the revision is <code>1</code>. Each time that you register a new revision of a task definition in the same family, the revision value always increases by one, even if you have deregistered previous revisions in this family.</p>
     #[serde(rename = "revision")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2696,7 +7606,7 @@ pub struct TaskDefinition {
     /// <p>The status of the task definition.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskDefinitionStatus>,
     /// <p>The full Amazon Resource Name (ARN) of the task definition.</p>
     #[serde(rename = "taskDefinitionArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2711,17 +7621,377 @@ pub struct TaskDefinition {
     pub volumes: Option<Vec<Volume>>,
 }
 
-/// <p><p>An object representing a constraint on task placement in the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-constraints.html">Task Placement Constraints</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>If you are using the Fargate launch type, task placement constraints are not supported.</p> </note></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct TaskDefinitionPlacementConstraint {
-    /// <p>A cluster query language expression to apply to the constraint. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cluster-query-language.html">Cluster Query Language</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
-    #[serde(rename = "expression")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expression: Option<String>,
-    /// <p>The type of constraint. The <code>MemberOf</code> constraint restricts selection to be from a group of valid candidates.</p>
-    #[serde(rename = "type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+/// <p>The task definition family status with which to filter the <code>ListTaskDefinitionFamilies</code> results. By default, both <code>ACTIVE</code> and <code>INACTIVE</code> task definition families are listed. If this parameter is set to <code>ACTIVE</code>, only task definition families that have an <code>ACTIVE</code> task definition revision are returned. If this parameter is set to <code>INACTIVE</code>, only task definition families that do not have any <code>ACTIVE</code> task definition revisions are returned. If you paginate the resulting output, be sure to keep the <code>status</code> value constant in each subsequent request.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaskDefinitionFamilyStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>ALL</code></p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TaskDefinitionFamilyStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskDefinitionFamilyStatus::Active => "ACTIVE",
+            TaskDefinitionFamilyStatus::Inactive => "INACTIVE",
+            TaskDefinitionFamilyStatus::All => "ALL",
+            TaskDefinitionFamilyStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaskDefinitionFamilyStatus {
+    fn from(value: &'a str) -> TaskDefinitionFamilyStatus {
+        match value {
+            "ACTIVE" => TaskDefinitionFamilyStatus::Active,
+            "INACTIVE" => TaskDefinitionFamilyStatus::Inactive,
+            "ALL" => TaskDefinitionFamilyStatus::All,
+            _ => TaskDefinitionFamilyStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskDefinitionFamilyStatus {
+    fn from(value: String) -> TaskDefinitionFamilyStatus {
+        TaskDefinitionFamilyStatus::from(value.as_str())
+    }
+}
+
+impl From<TaskDefinitionFamilyStatus> for String {
+    fn from(value: TaskDefinitionFamilyStatus) -> String {
+        match value {
+            TaskDefinitionFamilyStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TaskDefinitionFamilyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TaskDefinitionFamilyStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskDefinitionFamilyStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<TaskDefinitionFamilyStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TaskDefinitionFamilyStatus::from(value))
+    }
+}
+
+/// <p>Specifies whether to see the resource tags for the task definition. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaskDefinitionField {
+    /// <p><code>TAGS</code></p>
+    Tags,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TaskDefinitionField {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskDefinitionField::Tags => "TAGS",
+            TaskDefinitionField::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaskDefinitionField {
+    fn from(value: &'a str) -> TaskDefinitionField {
+        match value {
+            "TAGS" => TaskDefinitionField::Tags,
+            _ => TaskDefinitionField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskDefinitionField {
+    fn from(value: String) -> TaskDefinitionField {
+        TaskDefinitionField::from(value.as_str())
+    }
+}
+
+impl From<TaskDefinitionField> for String {
+    fn from(value: TaskDefinitionField) -> String {
+        match value {
+            TaskDefinitionField::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TaskDefinitionField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TaskDefinitionField {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskDefinitionField {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TaskDefinitionField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TaskDefinitionField::from(value))
+    }
+}
+
+/// <p><p>An object representing a constraint on task placement in the task definition. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/task-placement-constraints.html">Task Placement Constraints</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p> <note> <p>If you are using the Fargate launch type, task placement constraints are not supported.</p> </note></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskDefinitionPlacementConstraint {
+    /// <p>A cluster query language expression to apply to the constraint. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/cluster-query-language.html">Cluster Query Language</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+    #[serde(rename = "expression")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expression: Option<String>,
+    /// <p>The type of constraint. The <code>MemberOf</code> constraint restricts selection to be from a group of valid candidates.</p>
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<TaskDefinitionPlacementConstraintType>,
+}
+
+/// <p>The type of constraint. The <code>MemberOf</code> constraint restricts selection to be from a group of valid candidates.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaskDefinitionPlacementConstraintType {
+    /// <p><code>memberOf</code></p>
+    MemberOf,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TaskDefinitionPlacementConstraintType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskDefinitionPlacementConstraintType::MemberOf => "memberOf",
+            TaskDefinitionPlacementConstraintType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaskDefinitionPlacementConstraintType {
+    fn from(value: &'a str) -> TaskDefinitionPlacementConstraintType {
+        match value {
+            "memberOf" => TaskDefinitionPlacementConstraintType::MemberOf,
+            _ => TaskDefinitionPlacementConstraintType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskDefinitionPlacementConstraintType {
+    fn from(value: String) -> TaskDefinitionPlacementConstraintType {
+        TaskDefinitionPlacementConstraintType::from(value.as_str())
+    }
+}
+
+impl From<TaskDefinitionPlacementConstraintType> for String {
+    fn from(value: TaskDefinitionPlacementConstraintType) -> String {
+        match value {
+            TaskDefinitionPlacementConstraintType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TaskDefinitionPlacementConstraintType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TaskDefinitionPlacementConstraintType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskDefinitionPlacementConstraintType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<TaskDefinitionPlacementConstraintType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TaskDefinitionPlacementConstraintType::from(value))
+    }
+}
+
+/// <p>The task definition status with which to filter the <code>ListTaskDefinitions</code> results. By default, only <code>ACTIVE</code> task definitions are listed. By setting this parameter to <code>INACTIVE</code>, you can view task definitions that are <code>INACTIVE</code> as long as an active task or service still references them. If you paginate the resulting output, be sure to keep the <code>status</code> value constant in each subsequent request.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaskDefinitionStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>DELETE_IN_PROGRESS</code></p>
+    DeleteInProgress,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TaskDefinitionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskDefinitionStatus::Active => "ACTIVE",
+            TaskDefinitionStatus::Inactive => "INACTIVE",
+            TaskDefinitionStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            TaskDefinitionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaskDefinitionStatus {
+    fn from(value: &'a str) -> TaskDefinitionStatus {
+        match value {
+            "ACTIVE" => TaskDefinitionStatus::Active,
+            "INACTIVE" => TaskDefinitionStatus::Inactive,
+            "DELETE_IN_PROGRESS" => TaskDefinitionStatus::DeleteInProgress,
+            _ => TaskDefinitionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskDefinitionStatus {
+    fn from(value: String) -> TaskDefinitionStatus {
+        TaskDefinitionStatus::from(value.as_str())
+    }
+}
+
+impl From<TaskDefinitionStatus> for String {
+    fn from(value: TaskDefinitionStatus) -> String {
+        match value {
+            TaskDefinitionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TaskDefinitionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TaskDefinitionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskDefinitionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TaskDefinitionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TaskDefinitionStatus::from(value))
+    }
+}
+
+/// <p>Specifies whether you want to see the resource tags for the task. If <code>TAGS</code> is specified, the tags are included in the response. If this field is omitted, tags are not included in the response.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaskField {
+    /// <p><code>TAGS</code></p>
+    Tags,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TaskField {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskField::Tags => "TAGS",
+            TaskField::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaskField {
+    fn from(value: &'a str) -> TaskField {
+        match value {
+            "TAGS" => TaskField::Tags,
+            _ => TaskField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskField {
+    fn from(value: String) -> TaskField {
+        TaskField::from(value.as_str())
+    }
+}
+
+impl From<TaskField> for String {
+    fn from(value: TaskField) -> String {
+        match value {
+            TaskField::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TaskField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TaskField {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskField {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TaskField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TaskField::from(value))
+    }
 }
 
 /// <p>The overrides associated with a task.</p>
@@ -2759,8 +8029,9 @@ pub struct TaskSet {
     pub computed_desired_count: Option<i64>,
     /// <p>The Unix timestamp for when the task set was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The external ID associated with the task set.</p> <p>If a task set is created by an AWS CodeDeploy deployment, the <code>externalId</code> parameter contains the AWS CodeDeploy deployment ID.</p> <p>If a task set is created for an external deployment and is associated with a service discovery registry, the <code>externalId</code> parameter contains the <code>ECS_TASK_SET_EXTERNAL_ID</code> AWS Cloud Map attribute.</p>
     #[serde(rename = "externalId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2772,7 +8043,7 @@ pub struct TaskSet {
     /// <p>The launch type the tasks in the task set are using. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/launch_types.html">Amazon ECS Launch Types</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "launchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>Details on a load balancer that is used with a task set.</p>
     #[serde(rename = "loadBalancers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2808,11 +8079,12 @@ pub struct TaskSet {
     /// <p>The stability status, which indicates whether the task set has reached a steady state. If the following conditions are met, the task set will be in <code>STEADY_STATE</code>:</p> <ul> <li> <p>The task <code>runningCount</code> is equal to the <code>computedDesiredCount</code>.</p> </li> <li> <p>The <code>pendingCount</code> is <code>0</code>.</p> </li> <li> <p>There are no tasks running on container instances in the <code>DRAINING</code> status.</p> </li> <li> <p>All tasks are reporting a healthy status from the load balancers, service discovery, and container health checks.</p> </li> </ul> <p>If any of those conditions are not met, the stability status returns <code>STABILIZING</code>.</p>
     #[serde(rename = "stabilityStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stability_status: Option<String>,
+    pub stability_status: Option<StabilityStatus>,
     /// <p>The Unix timestamp for when the task set stability status was retrieved.</p>
     #[serde(rename = "stabilityStatusAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stability_status_at: Option<f64>,
+    pub stability_status_at: Option<EpochTimestamp>,
     /// <p>The tag specified when a task set is started. If the task set is created by an AWS CodeDeploy deployment, the <code>startedBy</code> parameter is <code>CODE_DEPLOY</code>. For a task set created for an external deployment, the startedBy field isn't used.</p>
     #[serde(rename = "startedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2831,8 +8103,97 @@ pub struct TaskSet {
     pub task_set_arn: Option<String>,
     /// <p>The Unix timestamp for when the task set was last updated.</p>
     #[serde(rename = "updatedAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub updated_at: Option<f64>,
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<EpochTimestamp>,
+}
+
+/// <p>The stop code indicating why a task was stopped. The <code>stoppedReason</code> may contain additional details.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaskStopCode {
+    /// <p><code>TaskFailedToStart</code></p>
+    TaskFailedToStart,
+    /// <p><code>EssentialContainerExited</code></p>
+    EssentialContainerExited,
+    /// <p><code>UserInitiated</code></p>
+    UserInitiated,
+    /// <p><code>ServiceSchedulerInitiated</code></p>
+    ServiceSchedulerInitiated,
+    /// <p><code>SpotInterruption</code></p>
+    SpotInterruption,
+    /// <p><code>TerminationNotice</code></p>
+    TerminationNotice,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TaskStopCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskStopCode::TaskFailedToStart => "TaskFailedToStart",
+            TaskStopCode::EssentialContainerExited => "EssentialContainerExited",
+            TaskStopCode::UserInitiated => "UserInitiated",
+            TaskStopCode::ServiceSchedulerInitiated => "ServiceSchedulerInitiated",
+            TaskStopCode::SpotInterruption => "SpotInterruption",
+            TaskStopCode::TerminationNotice => "TerminationNotice",
+            TaskStopCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaskStopCode {
+    fn from(value: &'a str) -> TaskStopCode {
+        match value {
+            "TaskFailedToStart" => TaskStopCode::TaskFailedToStart,
+            "EssentialContainerExited" => TaskStopCode::EssentialContainerExited,
+            "UserInitiated" => TaskStopCode::UserInitiated,
+            "ServiceSchedulerInitiated" => TaskStopCode::ServiceSchedulerInitiated,
+            "SpotInterruption" => TaskStopCode::SpotInterruption,
+            "TerminationNotice" => TaskStopCode::TerminationNotice,
+            _ => TaskStopCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskStopCode {
+    fn from(value: String) -> TaskStopCode {
+        TaskStopCode::from(value.as_str())
+    }
+}
+
+impl From<TaskStopCode> for String {
+    fn from(value: TaskStopCode) -> String {
+        match value {
+            TaskStopCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TaskStopCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TaskStopCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskStopCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TaskStopCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TaskStopCode::from(value))
+    }
 }
 
 /// <p>The container path, mount options, and size of the tmpfs mount.</p>
@@ -2850,20 +8211,216 @@ pub struct Tmpfs {
     pub size: i64,
 }
 
+/// <p>The protocol used for the network binding.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TransportProtocol {
+    /// <p><code>tcp</code></p>
+    Tcp,
+    /// <p><code>udp</code></p>
+    Udp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TransportProtocol {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TransportProtocol::Tcp => "tcp",
+            TransportProtocol::Udp => "udp",
+            TransportProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TransportProtocol {
+    fn from(value: &'a str) -> TransportProtocol {
+        match value {
+            "tcp" => TransportProtocol::Tcp,
+            "udp" => TransportProtocol::Udp,
+            _ => TransportProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TransportProtocol {
+    fn from(value: String) -> TransportProtocol {
+        TransportProtocol::from(value.as_str())
+    }
+}
+
+impl From<TransportProtocol> for String {
+    fn from(value: TransportProtocol) -> String {
+        match value {
+            TransportProtocol::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TransportProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TransportProtocol {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TransportProtocol {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TransportProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TransportProtocol::from(value))
+    }
+}
+
 /// <p>The <code>ulimit</code> settings to pass to the container.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Ulimit {
     /// <p>The hard limit for the ulimit type.</p>
     #[serde(rename = "hardLimit")]
     pub hard_limit: i64,
     /// <p>The <code>type</code> of the <code>ulimit</code>.</p>
     #[serde(rename = "name")]
-    pub name: String,
+    pub name: UlimitName,
     /// <p>The soft limit for the ulimit type.</p>
     #[serde(rename = "softLimit")]
     pub soft_limit: i64,
 }
 
+/// <p>The <code>type</code> of the <code>ulimit</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UlimitName {
+    /// <p><code>core</code></p>
+    Core,
+    /// <p><code>cpu</code></p>
+    Cpu,
+    /// <p><code>data</code></p>
+    Data,
+    /// <p><code>fsize</code></p>
+    Fsize,
+    /// <p><code>locks</code></p>
+    Locks,
+    /// <p><code>memlock</code></p>
+    Memlock,
+    /// <p><code>msgqueue</code></p>
+    Msgqueue,
+    /// <p><code>nice</code></p>
+    Nice,
+    /// <p><code>nofile</code></p>
+    Nofile,
+    /// <p><code>nproc</code></p>
+    Nproc,
+    /// <p><code>rss</code></p>
+    Rss,
+    /// <p><code>rtprio</code></p>
+    Rtprio,
+    /// <p><code>rttime</code></p>
+    Rttime,
+    /// <p><code>sigpending</code></p>
+    Sigpending,
+    /// <p><code>stack</code></p>
+    Stack,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UlimitName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UlimitName::Core => "core",
+            UlimitName::Cpu => "cpu",
+            UlimitName::Data => "data",
+            UlimitName::Fsize => "fsize",
+            UlimitName::Locks => "locks",
+            UlimitName::Memlock => "memlock",
+            UlimitName::Msgqueue => "msgqueue",
+            UlimitName::Nice => "nice",
+            UlimitName::Nofile => "nofile",
+            UlimitName::Nproc => "nproc",
+            UlimitName::Rss => "rss",
+            UlimitName::Rtprio => "rtprio",
+            UlimitName::Rttime => "rttime",
+            UlimitName::Sigpending => "sigpending",
+            UlimitName::Stack => "stack",
+            UlimitName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UlimitName {
+    fn from(value: &'a str) -> UlimitName {
+        match value {
+            "core" => UlimitName::Core,
+            "cpu" => UlimitName::Cpu,
+            "data" => UlimitName::Data,
+            "fsize" => UlimitName::Fsize,
+            "locks" => UlimitName::Locks,
+            "memlock" => UlimitName::Memlock,
+            "msgqueue" => UlimitName::Msgqueue,
+            "nice" => UlimitName::Nice,
+            "nofile" => UlimitName::Nofile,
+            "nproc" => UlimitName::Nproc,
+            "rss" => UlimitName::Rss,
+            "rtprio" => UlimitName::Rtprio,
+            "rttime" => UlimitName::Rttime,
+            "sigpending" => UlimitName::Sigpending,
+            "stack" => UlimitName::Stack,
+            _ => UlimitName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UlimitName {
+    fn from(value: String) -> UlimitName {
+        UlimitName::from(value.as_str())
+    }
+}
+
+impl From<UlimitName> for String {
+    fn from(value: UlimitName) -> String {
+        match value {
+            UlimitName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UlimitName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UlimitName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UlimitName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UlimitName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UlimitName::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource from which to delete tags. Currently, the supported resources are Amazon ECS tasks, services, task definitions, clusters, and container instances.</p>
@@ -2874,6 +8431,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -2888,6 +8485,48 @@ pub struct UpdateClusterSettingsRequest {
     pub settings: Vec<ClusterSetting>,
 }
 
+impl UpdateClusterSettingsRequest {
+    /// Returns a builder for `UpdateClusterSettingsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateClusterSettingsRequestBuilder {
+        UpdateClusterSettingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateClusterSettingsRequest`](struct.UpdateClusterSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateClusterSettingsRequestBuilder {
+    cluster: Option<String>,
+    settings: Option<Vec<ClusterSetting>>,
+}
+
+impl UpdateClusterSettingsRequestBuilder {
+    /// Sets the [`cluster`](struct.UpdateClusterSettingsRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`settings`](struct.UpdateClusterSettingsRequest.html#structfield.settings) member.
+    pub fn settings<T: Into<Vec<ClusterSetting>>>(mut self, value: T) -> Self {
+        self.settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateClusterSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateClusterSettingsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateClusterSettingsRequest {
+            cluster: self
+                .cluster
+                .ok_or(::rusoto_core::BuildError::missing("cluster"))?,
+            settings: self
+                .settings
+                .ok_or(::rusoto_core::BuildError::missing("settings"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateClusterSettingsResponse {
@@ -2907,6 +8546,46 @@ pub struct UpdateContainerAgentRequest {
     pub container_instance: String,
 }
 
+impl UpdateContainerAgentRequest {
+    /// Returns a builder for `UpdateContainerAgentRequest`, checking its required members when built.
+    pub fn builder() -> UpdateContainerAgentRequestBuilder {
+        UpdateContainerAgentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateContainerAgentRequest`](struct.UpdateContainerAgentRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateContainerAgentRequestBuilder {
+    cluster: Option<String>,
+    container_instance: Option<String>,
+}
+
+impl UpdateContainerAgentRequestBuilder {
+    /// Sets the [`cluster`](struct.UpdateContainerAgentRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`container_instance`](struct.UpdateContainerAgentRequest.html#structfield.container_instance) member.
+    pub fn container_instance<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_instance = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateContainerAgentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateContainerAgentRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateContainerAgentRequest {
+            cluster: self.cluster,
+            container_instance: self
+                .container_instance
+                .ok_or(::rusoto_core::BuildError::missing("container_instance"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateContainerAgentResponse {
@@ -2916,7 +8595,7 @@ pub struct UpdateContainerAgentResponse {
     pub container_instance: Option<ContainerInstance>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateContainerInstancesStateRequest {
     /// <p>The short name or full Amazon Resource Name (ARN) of the cluster that hosts the container instance to update. If you do not specify a cluster, the default cluster is assumed.</p>
     #[serde(rename = "cluster")]
@@ -2927,7 +8606,58 @@ pub struct UpdateContainerInstancesStateRequest {
     pub container_instances: Vec<String>,
     /// <p>The container instance state with which to update the container instance. The only valid values for this action are <code>ACTIVE</code> and <code>DRAINING</code>. A container instance can only be updated to <code>DRAINING</code> status once it has reached an <code>ACTIVE</code> state. If a container instance is in <code>REGISTERING</code>, <code>DEREGISTERING</code>, or <code>REGISTRATION_FAILED</code> state you can describe the container instance but will be unable to update the container instance state.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: ContainerInstanceStatus,
+}
+
+impl UpdateContainerInstancesStateRequest {
+    /// Returns a builder for `UpdateContainerInstancesStateRequest`, checking its required members when built.
+    pub fn builder() -> UpdateContainerInstancesStateRequestBuilder {
+        UpdateContainerInstancesStateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateContainerInstancesStateRequest`](struct.UpdateContainerInstancesStateRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateContainerInstancesStateRequestBuilder {
+    cluster: Option<String>,
+    container_instances: Option<Vec<String>>,
+    status: Option<ContainerInstanceStatus>,
+}
+
+impl UpdateContainerInstancesStateRequestBuilder {
+    /// Sets the [`cluster`](struct.UpdateContainerInstancesStateRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`container_instances`](struct.UpdateContainerInstancesStateRequest.html#structfield.container_instances) member.
+    pub fn container_instances<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.container_instances = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.UpdateContainerInstancesStateRequest.html#structfield.status) member.
+    pub fn status<T: Into<ContainerInstanceStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateContainerInstancesStateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateContainerInstancesStateRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateContainerInstancesStateRequest {
+            cluster: self.cluster,
+            container_instances: self
+                .container_instances
+                .ok_or(::rusoto_core::BuildError::missing("container_instances"))?,
+            status: self
+                .status
+                .ok_or(::rusoto_core::BuildError::missing("status"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2956,6 +8686,58 @@ pub struct UpdateServicePrimaryTaskSetRequest {
     pub service: String,
 }
 
+impl UpdateServicePrimaryTaskSetRequest {
+    /// Returns a builder for `UpdateServicePrimaryTaskSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateServicePrimaryTaskSetRequestBuilder {
+        UpdateServicePrimaryTaskSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateServicePrimaryTaskSetRequest`](struct.UpdateServicePrimaryTaskSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateServicePrimaryTaskSetRequestBuilder {
+    cluster: Option<String>,
+    primary_task_set: Option<String>,
+    service: Option<String>,
+}
+
+impl UpdateServicePrimaryTaskSetRequestBuilder {
+    /// Sets the [`cluster`](struct.UpdateServicePrimaryTaskSetRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`primary_task_set`](struct.UpdateServicePrimaryTaskSetRequest.html#structfield.primary_task_set) member.
+    pub fn primary_task_set<T: Into<String>>(mut self, value: T) -> Self {
+        self.primary_task_set = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service`](struct.UpdateServicePrimaryTaskSetRequest.html#structfield.service) member.
+    pub fn service<T: Into<String>>(mut self, value: T) -> Self {
+        self.service = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateServicePrimaryTaskSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateServicePrimaryTaskSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateServicePrimaryTaskSetRequest {
+            cluster: self
+                .cluster
+                .ok_or(::rusoto_core::BuildError::missing("cluster"))?,
+            primary_task_set: self
+                .primary_task_set
+                .ok_or(::rusoto_core::BuildError::missing("primary_task_set"))?,
+            service: self
+                .service
+                .ok_or(::rusoto_core::BuildError::missing("service"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateServicePrimaryTaskSetResponse {
@@ -3003,6 +8785,100 @@ pub struct UpdateServiceRequest {
     pub task_definition: Option<String>,
 }
 
+impl UpdateServiceRequest {
+    /// Returns a builder for `UpdateServiceRequest`, checking its required members when built.
+    pub fn builder() -> UpdateServiceRequestBuilder {
+        UpdateServiceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateServiceRequest`](struct.UpdateServiceRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateServiceRequestBuilder {
+    cluster: Option<String>,
+    deployment_configuration: Option<DeploymentConfiguration>,
+    desired_count: Option<i64>,
+    force_new_deployment: Option<bool>,
+    health_check_grace_period_seconds: Option<i64>,
+    network_configuration: Option<NetworkConfiguration>,
+    platform_version: Option<String>,
+    service: Option<String>,
+    task_definition: Option<String>,
+}
+
+impl UpdateServiceRequestBuilder {
+    /// Sets the [`cluster`](struct.UpdateServiceRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_configuration`](struct.UpdateServiceRequest.html#structfield.deployment_configuration) member.
+    pub fn deployment_configuration<T: Into<DeploymentConfiguration>>(mut self, value: T) -> Self {
+        self.deployment_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`desired_count`](struct.UpdateServiceRequest.html#structfield.desired_count) member.
+    pub fn desired_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.desired_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_new_deployment`](struct.UpdateServiceRequest.html#structfield.force_new_deployment) member.
+    pub fn force_new_deployment<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_new_deployment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_grace_period_seconds`](struct.UpdateServiceRequest.html#structfield.health_check_grace_period_seconds) member.
+    pub fn health_check_grace_period_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.health_check_grace_period_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`network_configuration`](struct.UpdateServiceRequest.html#structfield.network_configuration) member.
+    pub fn network_configuration<T: Into<NetworkConfiguration>>(mut self, value: T) -> Self {
+        self.network_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_version`](struct.UpdateServiceRequest.html#structfield.platform_version) member.
+    pub fn platform_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service`](struct.UpdateServiceRequest.html#structfield.service) member.
+    pub fn service<T: Into<String>>(mut self, value: T) -> Self {
+        self.service = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_definition`](struct.UpdateServiceRequest.html#structfield.task_definition) member.
+    pub fn task_definition<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_definition = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateServiceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateServiceRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateServiceRequest {
+            cluster: self.cluster,
+            deployment_configuration: self.deployment_configuration,
+            desired_count: self.desired_count,
+            force_new_deployment: self.force_new_deployment,
+            health_check_grace_period_seconds: self.health_check_grace_period_seconds,
+            network_configuration: self.network_configuration,
+            platform_version: self.platform_version,
+            service: self
+                .service
+                .ok_or(::rusoto_core::BuildError::missing("service"))?,
+            task_definition: self.task_definition,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateServiceResponse {
@@ -3027,6 +8903,66 @@ pub struct UpdateTaskSetRequest {
     pub task_set: String,
 }
 
+impl UpdateTaskSetRequest {
+    /// Returns a builder for `UpdateTaskSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTaskSetRequestBuilder {
+        UpdateTaskSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTaskSetRequest`](struct.UpdateTaskSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTaskSetRequestBuilder {
+    cluster: Option<String>,
+    scale: Option<Scale>,
+    service: Option<String>,
+    task_set: Option<String>,
+}
+
+impl UpdateTaskSetRequestBuilder {
+    /// Sets the [`cluster`](struct.UpdateTaskSetRequest.html#structfield.cluster) member.
+    pub fn cluster<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scale`](struct.UpdateTaskSetRequest.html#structfield.scale) member.
+    pub fn scale<T: Into<Scale>>(mut self, value: T) -> Self {
+        self.scale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service`](struct.UpdateTaskSetRequest.html#structfield.service) member.
+    pub fn service<T: Into<String>>(mut self, value: T) -> Self {
+        self.service = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_set`](struct.UpdateTaskSetRequest.html#structfield.task_set) member.
+    pub fn task_set<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_set = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTaskSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateTaskSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTaskSetRequest {
+            cluster: self
+                .cluster
+                .ok_or(::rusoto_core::BuildError::missing("cluster"))?,
+            scale: self
+                .scale
+                .ok_or(::rusoto_core::BuildError::missing("scale"))?,
+            service: self
+                .service
+                .ok_or(::rusoto_core::BuildError::missing("service"))?,
+            task_set: self
+                .task_set
+                .ok_or(::rusoto_core::BuildError::missing("task_set"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateTaskSetResponse {
@@ -5899,6 +11835,280 @@ pub trait Ecs {
         input: UpdateTaskSetRequest,
     ) -> RusotoFuture<UpdateTaskSetResponse, UpdateTaskSetError>;
 }
+impl<T: ?Sized + Ecs> Ecs for ::std::sync::Arc<T> {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> RusotoFuture<CreateServiceResponse, CreateServiceError> {
+        (**self).create_service(input)
+    }
+    fn create_task_set(
+        &self,
+        input: CreateTaskSetRequest,
+    ) -> RusotoFuture<CreateTaskSetResponse, CreateTaskSetError> {
+        (**self).create_task_set(input)
+    }
+    fn delete_account_setting(
+        &self,
+        input: DeleteAccountSettingRequest,
+    ) -> RusotoFuture<DeleteAccountSettingResponse, DeleteAccountSettingError> {
+        (**self).delete_account_setting(input)
+    }
+    fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> RusotoFuture<DeleteAttributesResponse, DeleteAttributesError> {
+        (**self).delete_attributes(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> RusotoFuture<DeleteServiceResponse, DeleteServiceError> {
+        (**self).delete_service(input)
+    }
+    fn delete_task_set(
+        &self,
+        input: DeleteTaskSetRequest,
+    ) -> RusotoFuture<DeleteTaskSetResponse, DeleteTaskSetError> {
+        (**self).delete_task_set(input)
+    }
+    fn deregister_container_instance(
+        &self,
+        input: DeregisterContainerInstanceRequest,
+    ) -> RusotoFuture<DeregisterContainerInstanceResponse, DeregisterContainerInstanceError> {
+        (**self).deregister_container_instance(input)
+    }
+    fn deregister_task_definition(
+        &self,
+        input: DeregisterTaskDefinitionRequest,
+    ) -> RusotoFuture<DeregisterTaskDefinitionResponse, DeregisterTaskDefinitionError> {
+        (**self).deregister_task_definition(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> RusotoFuture<DescribeClustersResponse, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn describe_container_instances(
+        &self,
+        input: DescribeContainerInstancesRequest,
+    ) -> RusotoFuture<DescribeContainerInstancesResponse, DescribeContainerInstancesError> {
+        (**self).describe_container_instances(input)
+    }
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        (**self).describe_services(input)
+    }
+    fn describe_task_definition(
+        &self,
+        input: DescribeTaskDefinitionRequest,
+    ) -> RusotoFuture<DescribeTaskDefinitionResponse, DescribeTaskDefinitionError> {
+        (**self).describe_task_definition(input)
+    }
+    fn describe_task_sets(
+        &self,
+        input: DescribeTaskSetsRequest,
+    ) -> RusotoFuture<DescribeTaskSetsResponse, DescribeTaskSetsError> {
+        (**self).describe_task_sets(input)
+    }
+    fn describe_tasks(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> RusotoFuture<DescribeTasksResponse, DescribeTasksError> {
+        (**self).describe_tasks(input)
+    }
+    fn discover_poll_endpoint(
+        &self,
+        input: DiscoverPollEndpointRequest,
+    ) -> RusotoFuture<DiscoverPollEndpointResponse, DiscoverPollEndpointError> {
+        (**self).discover_poll_endpoint(input)
+    }
+    fn list_account_settings(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> RusotoFuture<ListAccountSettingsResponse, ListAccountSettingsError> {
+        (**self).list_account_settings(input)
+    }
+    fn list_attributes(
+        &self,
+        input: ListAttributesRequest,
+    ) -> RusotoFuture<ListAttributesResponse, ListAttributesError> {
+        (**self).list_attributes(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_container_instances(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> RusotoFuture<ListContainerInstancesResponse, ListContainerInstancesError> {
+        (**self).list_container_instances(input)
+    }
+    fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> RusotoFuture<ListServicesResponse, ListServicesError> {
+        (**self).list_services(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_task_definition_families(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> RusotoFuture<ListTaskDefinitionFamiliesResponse, ListTaskDefinitionFamiliesError> {
+        (**self).list_task_definition_families(input)
+    }
+    fn list_task_definitions(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> RusotoFuture<ListTaskDefinitionsResponse, ListTaskDefinitionsError> {
+        (**self).list_task_definitions(input)
+    }
+    fn list_tasks(
+        &self,
+        input: ListTasksRequest,
+    ) -> RusotoFuture<ListTasksResponse, ListTasksError> {
+        (**self).list_tasks(input)
+    }
+    fn put_account_setting(
+        &self,
+        input: PutAccountSettingRequest,
+    ) -> RusotoFuture<PutAccountSettingResponse, PutAccountSettingError> {
+        (**self).put_account_setting(input)
+    }
+    fn put_account_setting_default(
+        &self,
+        input: PutAccountSettingDefaultRequest,
+    ) -> RusotoFuture<PutAccountSettingDefaultResponse, PutAccountSettingDefaultError> {
+        (**self).put_account_setting_default(input)
+    }
+    fn put_attributes(
+        &self,
+        input: PutAttributesRequest,
+    ) -> RusotoFuture<PutAttributesResponse, PutAttributesError> {
+        (**self).put_attributes(input)
+    }
+    fn register_container_instance(
+        &self,
+        input: RegisterContainerInstanceRequest,
+    ) -> RusotoFuture<RegisterContainerInstanceResponse, RegisterContainerInstanceError> {
+        (**self).register_container_instance(input)
+    }
+    fn register_task_definition(
+        &self,
+        input: RegisterTaskDefinitionRequest,
+    ) -> RusotoFuture<RegisterTaskDefinitionResponse, RegisterTaskDefinitionError> {
+        (**self).register_task_definition(input)
+    }
+    fn run_task(&self, input: RunTaskRequest) -> RusotoFuture<RunTaskResponse, RunTaskError> {
+        (**self).run_task(input)
+    }
+    fn start_task(
+        &self,
+        input: StartTaskRequest,
+    ) -> RusotoFuture<StartTaskResponse, StartTaskError> {
+        (**self).start_task(input)
+    }
+    fn stop_task(&self, input: StopTaskRequest) -> RusotoFuture<StopTaskResponse, StopTaskError> {
+        (**self).stop_task(input)
+    }
+    fn submit_attachment_state_changes(
+        &self,
+        input: SubmitAttachmentStateChangesRequest,
+    ) -> RusotoFuture<SubmitAttachmentStateChangesResponse, SubmitAttachmentStateChangesError> {
+        (**self).submit_attachment_state_changes(input)
+    }
+    fn submit_container_state_change(
+        &self,
+        input: SubmitContainerStateChangeRequest,
+    ) -> RusotoFuture<SubmitContainerStateChangeResponse, SubmitContainerStateChangeError> {
+        (**self).submit_container_state_change(input)
+    }
+    fn submit_task_state_change(
+        &self,
+        input: SubmitTaskStateChangeRequest,
+    ) -> RusotoFuture<SubmitTaskStateChangeResponse, SubmitTaskStateChangeError> {
+        (**self).submit_task_state_change(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cluster_settings(
+        &self,
+        input: UpdateClusterSettingsRequest,
+    ) -> RusotoFuture<UpdateClusterSettingsResponse, UpdateClusterSettingsError> {
+        (**self).update_cluster_settings(input)
+    }
+    fn update_container_agent(
+        &self,
+        input: UpdateContainerAgentRequest,
+    ) -> RusotoFuture<UpdateContainerAgentResponse, UpdateContainerAgentError> {
+        (**self).update_container_agent(input)
+    }
+    fn update_container_instances_state(
+        &self,
+        input: UpdateContainerInstancesStateRequest,
+    ) -> RusotoFuture<UpdateContainerInstancesStateResponse, UpdateContainerInstancesStateError>
+    {
+        (**self).update_container_instances_state(input)
+    }
+    fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> RusotoFuture<UpdateServiceResponse, UpdateServiceError> {
+        (**self).update_service(input)
+    }
+    fn update_service_primary_task_set(
+        &self,
+        input: UpdateServicePrimaryTaskSetRequest,
+    ) -> RusotoFuture<UpdateServicePrimaryTaskSetResponse, UpdateServicePrimaryTaskSetError> {
+        (**self).update_service_primary_task_set(input)
+    }
+    fn update_task_set(
+        &self,
+        input: UpdateTaskSetRequest,
+    ) -> RusotoFuture<UpdateTaskSetResponse, UpdateTaskSetError> {
+        (**self).update_task_set(input)
+    }
+}
+/// The error codes Amazon ECS retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon ECS API.
 #[derive(Clone)]
 pub struct EcsClient {
@@ -5914,6 +12124,24 @@ impl EcsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ECS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> EcsClient {
+        Self::new(region::Region::from_env_for_service("ECS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `ecs`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> EcsClient {
+        Self::new_with_client(config.client().clone(), config.region_for("ecs", "ECS"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5932,7 +12160,49 @@ impl EcsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EcsClient {
-        EcsClient { client, region }
+        EcsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "ecs")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "ecs", "ecs", "ECS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Ecs` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Ecs + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for EcsClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon ECS"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "ecs"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> EcsClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Elastic File System clients.
+//!
+//! Makes a single read-only `DescribeFileSystems` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_efs::{DescribeFileSystemsRequest, Efs, EfsClient};
+
+fn main() {
+    let client = EfsClient::from_env();
+    match client
+        .describe_file_systems(DescribeFileSystemsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeFileSystems succeeded"),
+        Err(error) => {
+            eprintln!("DescribeFileSystems failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
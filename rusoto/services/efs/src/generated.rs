@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateFileSystemRequest {
@@ -40,7 +41,7 @@ pub struct CreateFileSystemRequest {
     /// <p>The performance mode of the file system. We recommend <code>generalPurpose</code> performance mode for most file systems. File systems using the <code>maxIO</code> performance mode can scale to higher levels of aggregate throughput and operations per second with a tradeoff of slightly higher latencies for most file operations. The performance mode can't be changed after the file system has been created.</p>
     #[serde(rename = "PerformanceMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub performance_mode: Option<String>,
+    pub performance_mode: Option<PerformanceMode>,
     /// <p>The throughput, measured in MiB/s, that you want to provision for a file system that you're creating. Valid values are 1-1024. Required if <code>ThroughputMode</code> is set to <code>provisioned</code>. The upper limit for throughput is 1024 MiB/s. You can get this limit increased by contacting AWS Support. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/limits.html#soft-limits">Amazon EFS Limits That You Can Increase</a> in the <i>Amazon EFS User Guide.</i> </p>
     #[serde(rename = "ProvisionedThroughputInMibps")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -52,7 +53,87 @@ pub struct CreateFileSystemRequest {
     /// <p>The throughput mode for the file system to be created. There are two throughput modes to choose from for your file system: <code>bursting</code> and <code>provisioned</code>. If you set <code>ThroughputMode</code> to <code>provisioned</code>, you must also set a value for <code>ProvisionedThroughPutInMibps</code>. You can decrease your file system's throughput in Provisioned Throughput mode or change between the throughput modes as long as it’s been more than 24 hours since the last decrease or throughput mode change. For more, see <a href="https://docs.aws.amazon.com/efs/latest/ug/performance.html#provisioned-throughput">Specifying Throughput with Provisioned Mode</a> in the <i>Amazon EFS User Guide.</i> </p>
     #[serde(rename = "ThroughputMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub throughput_mode: Option<String>,
+    pub throughput_mode: Option<ThroughputMode>,
+}
+
+impl CreateFileSystemRequest {
+    /// Returns a builder for `CreateFileSystemRequest`, checking its required members when built.
+    pub fn builder() -> CreateFileSystemRequestBuilder {
+        CreateFileSystemRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFileSystemRequest`](struct.CreateFileSystemRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFileSystemRequestBuilder {
+    creation_token: Option<String>,
+    encrypted: Option<bool>,
+    kms_key_id: Option<String>,
+    performance_mode: Option<PerformanceMode>,
+    provisioned_throughput_in_mibps: Option<f64>,
+    tags: Option<Vec<Tag>>,
+    throughput_mode: Option<ThroughputMode>,
+}
+
+impl CreateFileSystemRequestBuilder {
+    /// Sets the [`creation_token`](struct.CreateFileSystemRequest.html#structfield.creation_token) member.
+    pub fn creation_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.creation_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`encrypted`](struct.CreateFileSystemRequest.html#structfield.encrypted) member.
+    pub fn encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateFileSystemRequest.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`performance_mode`](struct.CreateFileSystemRequest.html#structfield.performance_mode) member.
+    pub fn performance_mode<T: Into<PerformanceMode>>(mut self, value: T) -> Self {
+        self.performance_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_throughput_in_mibps`](struct.CreateFileSystemRequest.html#structfield.provisioned_throughput_in_mibps) member.
+    pub fn provisioned_throughput_in_mibps<T: Into<f64>>(mut self, value: T) -> Self {
+        self.provisioned_throughput_in_mibps = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateFileSystemRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`throughput_mode`](struct.CreateFileSystemRequest.html#structfield.throughput_mode) member.
+    pub fn throughput_mode<T: Into<ThroughputMode>>(mut self, value: T) -> Self {
+        self.throughput_mode = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFileSystemRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateFileSystemRequest, ::rusoto_core::BuildError> {
+        Ok(CreateFileSystemRequest {
+            creation_token: self
+                .creation_token
+                .ok_or(::rusoto_core::BuildError::missing("creation_token"))?,
+            encrypted: self.encrypted,
+            kms_key_id: self.kms_key_id,
+            performance_mode: self.performance_mode,
+            provisioned_throughput_in_mibps: self.provisioned_throughput_in_mibps,
+            tags: self.tags,
+            throughput_mode: self.throughput_mode,
+        })
+    }
 }
 
 /// <p><p/></p>
@@ -74,6 +155,64 @@ pub struct CreateMountTargetRequest {
     pub subnet_id: String,
 }
 
+impl CreateMountTargetRequest {
+    /// Returns a builder for `CreateMountTargetRequest`, checking its required members when built.
+    pub fn builder() -> CreateMountTargetRequestBuilder {
+        CreateMountTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateMountTargetRequest`](struct.CreateMountTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateMountTargetRequestBuilder {
+    file_system_id: Option<String>,
+    ip_address: Option<String>,
+    security_groups: Option<Vec<String>>,
+    subnet_id: Option<String>,
+}
+
+impl CreateMountTargetRequestBuilder {
+    /// Sets the [`file_system_id`](struct.CreateMountTargetRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ip_address`](struct.CreateMountTargetRequest.html#structfield.ip_address) member.
+    pub fn ip_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.ip_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_groups`](struct.CreateMountTargetRequest.html#structfield.security_groups) member.
+    pub fn security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_id`](struct.CreateMountTargetRequest.html#structfield.subnet_id) member.
+    pub fn subnet_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subnet_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateMountTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateMountTargetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateMountTargetRequest {
+            file_system_id: self
+                .file_system_id
+                .ok_or(::rusoto_core::BuildError::missing("file_system_id"))?,
+            ip_address: self.ip_address,
+            security_groups: self.security_groups,
+            subnet_id: self
+                .subnet_id
+                .ok_or(::rusoto_core::BuildError::missing("subnet_id"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateTagsRequest {
@@ -85,6 +224,46 @@ pub struct CreateTagsRequest {
     pub tags: Vec<Tag>,
 }
 
+impl CreateTagsRequest {
+    /// Returns a builder for `CreateTagsRequest`, checking its required members when built.
+    pub fn builder() -> CreateTagsRequestBuilder {
+        CreateTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTagsRequest`](struct.CreateTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTagsRequestBuilder {
+    file_system_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateTagsRequestBuilder {
+    /// Sets the [`file_system_id`](struct.CreateTagsRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateTagsRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTagsRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTagsRequest {
+            file_system_id: self
+                .file_system_id
+                .ok_or(::rusoto_core::BuildError::missing("file_system_id"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteFileSystemRequest {
@@ -93,6 +272,38 @@ pub struct DeleteFileSystemRequest {
     pub file_system_id: String,
 }
 
+impl DeleteFileSystemRequest {
+    /// Returns a builder for `DeleteFileSystemRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFileSystemRequestBuilder {
+        DeleteFileSystemRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFileSystemRequest`](struct.DeleteFileSystemRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFileSystemRequestBuilder {
+    file_system_id: Option<String>,
+}
+
+impl DeleteFileSystemRequestBuilder {
+    /// Sets the [`file_system_id`](struct.DeleteFileSystemRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFileSystemRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteFileSystemRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFileSystemRequest {
+            file_system_id: self
+                .file_system_id
+                .ok_or(::rusoto_core::BuildError::missing("file_system_id"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteMountTargetRequest {
@@ -101,6 +312,38 @@ pub struct DeleteMountTargetRequest {
     pub mount_target_id: String,
 }
 
+impl DeleteMountTargetRequest {
+    /// Returns a builder for `DeleteMountTargetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteMountTargetRequestBuilder {
+        DeleteMountTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMountTargetRequest`](struct.DeleteMountTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteMountTargetRequestBuilder {
+    mount_target_id: Option<String>,
+}
+
+impl DeleteMountTargetRequestBuilder {
+    /// Sets the [`mount_target_id`](struct.DeleteMountTargetRequest.html#structfield.mount_target_id) member.
+    pub fn mount_target_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.mount_target_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMountTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteMountTargetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteMountTargetRequest {
+            mount_target_id: self
+                .mount_target_id
+                .ok_or(::rusoto_core::BuildError::missing("mount_target_id"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteTagsRequest {
@@ -112,6 +355,46 @@ pub struct DeleteTagsRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl DeleteTagsRequest {
+    /// Returns a builder for `DeleteTagsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTagsRequestBuilder {
+        DeleteTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTagsRequest`](struct.DeleteTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTagsRequestBuilder {
+    file_system_id: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl DeleteTagsRequestBuilder {
+    /// Sets the [`file_system_id`](struct.DeleteTagsRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DeleteTagsRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTagsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTagsRequest {
+            file_system_id: self
+                .file_system_id
+                .ok_or(::rusoto_core::BuildError::missing("file_system_id"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeFileSystemsRequest {
@@ -133,6 +416,60 @@ pub struct DescribeFileSystemsRequest {
     pub max_items: Option<i64>,
 }
 
+impl DescribeFileSystemsRequest {
+    /// Returns a builder for `DescribeFileSystemsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeFileSystemsRequestBuilder {
+        DescribeFileSystemsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeFileSystemsRequest`](struct.DescribeFileSystemsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeFileSystemsRequestBuilder {
+    creation_token: Option<String>,
+    file_system_id: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl DescribeFileSystemsRequestBuilder {
+    /// Sets the [`creation_token`](struct.DescribeFileSystemsRequest.html#structfield.creation_token) member.
+    pub fn creation_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.creation_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_system_id`](struct.DescribeFileSystemsRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeFileSystemsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.DescribeFileSystemsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeFileSystemsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeFileSystemsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeFileSystemsRequest {
+            creation_token: self.creation_token,
+            file_system_id: self.file_system_id,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeFileSystemsResponse {
@@ -157,6 +494,39 @@ pub struct DescribeLifecycleConfigurationRequest {
     pub file_system_id: String,
 }
 
+impl DescribeLifecycleConfigurationRequest {
+    /// Returns a builder for `DescribeLifecycleConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeLifecycleConfigurationRequestBuilder {
+        DescribeLifecycleConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLifecycleConfigurationRequest`](struct.DescribeLifecycleConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeLifecycleConfigurationRequestBuilder {
+    file_system_id: Option<String>,
+}
+
+impl DescribeLifecycleConfigurationRequestBuilder {
+    /// Sets the [`file_system_id`](struct.DescribeLifecycleConfigurationRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLifecycleConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLifecycleConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeLifecycleConfigurationRequest {
+            file_system_id: self
+                .file_system_id
+                .ok_or(::rusoto_core::BuildError::missing("file_system_id"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeMountTargetSecurityGroupsRequest {
@@ -165,6 +535,39 @@ pub struct DescribeMountTargetSecurityGroupsRequest {
     pub mount_target_id: String,
 }
 
+impl DescribeMountTargetSecurityGroupsRequest {
+    /// Returns a builder for `DescribeMountTargetSecurityGroupsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeMountTargetSecurityGroupsRequestBuilder {
+        DescribeMountTargetSecurityGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeMountTargetSecurityGroupsRequest`](struct.DescribeMountTargetSecurityGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeMountTargetSecurityGroupsRequestBuilder {
+    mount_target_id: Option<String>,
+}
+
+impl DescribeMountTargetSecurityGroupsRequestBuilder {
+    /// Sets the [`mount_target_id`](struct.DescribeMountTargetSecurityGroupsRequest.html#structfield.mount_target_id) member.
+    pub fn mount_target_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.mount_target_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeMountTargetSecurityGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeMountTargetSecurityGroupsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeMountTargetSecurityGroupsRequest {
+            mount_target_id: self
+                .mount_target_id
+                .ok_or(::rusoto_core::BuildError::missing("mount_target_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeMountTargetSecurityGroupsResponse {
@@ -194,6 +597,60 @@ pub struct DescribeMountTargetsRequest {
     pub mount_target_id: Option<String>,
 }
 
+impl DescribeMountTargetsRequest {
+    /// Returns a builder for `DescribeMountTargetsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeMountTargetsRequestBuilder {
+        DescribeMountTargetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeMountTargetsRequest`](struct.DescribeMountTargetsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeMountTargetsRequestBuilder {
+    file_system_id: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+    mount_target_id: Option<String>,
+}
+
+impl DescribeMountTargetsRequestBuilder {
+    /// Sets the [`file_system_id`](struct.DescribeMountTargetsRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeMountTargetsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.DescribeMountTargetsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mount_target_id`](struct.DescribeMountTargetsRequest.html#structfield.mount_target_id) member.
+    pub fn mount_target_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.mount_target_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeMountTargetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeMountTargetsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeMountTargetsRequest {
+            file_system_id: self.file_system_id,
+            marker: self.marker,
+            max_items: self.max_items,
+            mount_target_id: self.mount_target_id,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -228,6 +685,52 @@ pub struct DescribeTagsRequest {
     pub max_items: Option<i64>,
 }
 
+impl DescribeTagsRequest {
+    /// Returns a builder for `DescribeTagsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTagsRequestBuilder {
+        DescribeTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTagsRequest`](struct.DescribeTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTagsRequestBuilder {
+    file_system_id: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl DescribeTagsRequestBuilder {
+    /// Sets the [`file_system_id`](struct.DescribeTagsRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeTagsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.DescribeTagsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTagsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTagsRequest {
+            file_system_id: self
+                .file_system_id
+                .ok_or(::rusoto_core::BuildError::missing("file_system_id"))?,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -246,12 +749,13 @@ pub struct DescribeTagsResponse {
 }
 
 /// <p>A description of the file system.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct FileSystemDescription {
     /// <p>The time that the file system was created, in seconds (since 1970-01-01T00:00:00Z).</p>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_time: EpochTimestamp,
     /// <p>The opaque string specified in the request.</p>
     #[serde(rename = "CreationToken")]
     pub creation_token: String,
@@ -268,7 +772,7 @@ pub struct FileSystemDescription {
     pub kms_key_id: Option<String>,
     /// <p>The lifecycle phase of the file system.</p>
     #[serde(rename = "LifeCycleState")]
-    pub life_cycle_state: String,
+    pub life_cycle_state: LifeCycleState,
     /// <p>You can add tags to a file system, including a <code>Name</code> tag. For more information, see <a>CreateFileSystem</a>. If the file system has a <code>Name</code> tag, Amazon EFS returns the value in this field. </p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -281,7 +785,7 @@ pub struct FileSystemDescription {
     pub owner_id: String,
     /// <p>The performance mode of the file system.</p>
     #[serde(rename = "PerformanceMode")]
-    pub performance_mode: String,
+    pub performance_mode: PerformanceMode,
     /// <p>The throughput, measured in MiB/s, that you want to provision for a file system. Valid values are 1-1024. Required if <code>ThroughputMode</code> is set to <code>provisioned</code>. The limit on throughput is 1024 MiB/s. You can get these limits increased by contacting AWS Support. For more information, see <a href="https://docs.aws.amazon.com/efs/latest/ug/limits.html#soft-limits">Amazon EFS Limits That You Can Increase</a> in the <i>Amazon EFS User Guide.</i> </p>
     #[serde(rename = "ProvisionedThroughputInMibps")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -295,7 +799,7 @@ pub struct FileSystemDescription {
     /// <p>The throughput mode for a file system. There are two throughput modes to choose from for your file system: <code>bursting</code> and <code>provisioned</code>. If you set <code>ThroughputMode</code> to <code>provisioned</code>, you must also set a value for <code>ProvisionedThroughPutInMibps</code>. You can decrease your file system's throughput in Provisioned Throughput mode or change between the throughput modes as long as it’s been more than 24 hours since the last decrease or throughput mode change. </p>
     #[serde(rename = "ThroughputMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub throughput_mode: Option<String>,
+    pub throughput_mode: Option<ThroughputMode>,
 }
 
 /// <p>The latest known metered size (in bytes) of data stored in the file system, in its <code>Value</code> field, and the time at which that size was determined in its <code>Timestamp</code> field. The value doesn't represent the size of a consistent snapshot of the file system, but it is eventually consistent when there are no writes to the file system. That is, the value represents the actual size only if the file system is not modified for a period longer than a couple of hours. Otherwise, the value is not necessarily the exact size the file system was at any instant in time.</p>
@@ -304,8 +808,9 @@ pub struct FileSystemDescription {
 pub struct FileSystemSize {
     /// <p>The time at which the size of data, returned in the <code>Value</code> field, was determined. The value is the integer number of seconds since 1970-01-01T00:00:00Z.</p>
     #[serde(rename = "Timestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<EpochTimestamp>,
     /// <p>The latest known metered size (in bytes) of data stored in the file system.</p>
     #[serde(rename = "Value")]
     pub value: i64,
@@ -319,6 +824,100 @@ pub struct FileSystemSize {
     pub value_in_standard: Option<i64>,
 }
 
+/// <p>The lifecycle phase of the file system.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LifeCycleState {
+    /// <p><code>creating</code></p>
+    Creating,
+    /// <p><code>available</code></p>
+    Available,
+    /// <p><code>updating</code></p>
+    Updating,
+    /// <p><code>deleting</code></p>
+    Deleting,
+    /// <p><code>deleted</code></p>
+    Deleted,
+    /// <p><code>error</code></p>
+    Error,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LifeCycleState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LifeCycleState::Creating => "creating",
+            LifeCycleState::Available => "available",
+            LifeCycleState::Updating => "updating",
+            LifeCycleState::Deleting => "deleting",
+            LifeCycleState::Deleted => "deleted",
+            LifeCycleState::Error => "error",
+            LifeCycleState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LifeCycleState {
+    fn from(value: &'a str) -> LifeCycleState {
+        match value {
+            "creating" => LifeCycleState::Creating,
+            "available" => LifeCycleState::Available,
+            "updating" => LifeCycleState::Updating,
+            "deleting" => LifeCycleState::Deleting,
+            "deleted" => LifeCycleState::Deleted,
+            "error" => LifeCycleState::Error,
+            _ => LifeCycleState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LifeCycleState {
+    fn from(value: String) -> LifeCycleState {
+        LifeCycleState::from(value.as_str())
+    }
+}
+
+impl From<LifeCycleState> for String {
+    fn from(value: LifeCycleState) -> String {
+        match value {
+            LifeCycleState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LifeCycleState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LifeCycleState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LifeCycleState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LifeCycleState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LifeCycleState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LifeCycleState::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct LifecycleConfigurationDescription {
@@ -334,7 +933,7 @@ pub struct LifecyclePolicy {
     /// <p> A value that describes the period of time that a file is not accessed, after which it transitions to the IA storage class. Metadata operations such as listing the contents of a directory don't count as file access events.</p>
     #[serde(rename = "TransitionToIA")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transition_to_ia: Option<String>,
+    pub transition_to_ia: Option<TransitionToIARules>,
 }
 
 /// <p><p/></p>
@@ -349,8 +948,49 @@ pub struct ModifyMountTargetSecurityGroupsRequest {
     pub security_groups: Option<Vec<String>>,
 }
 
+impl ModifyMountTargetSecurityGroupsRequest {
+    /// Returns a builder for `ModifyMountTargetSecurityGroupsRequest`, checking its required members when built.
+    pub fn builder() -> ModifyMountTargetSecurityGroupsRequestBuilder {
+        ModifyMountTargetSecurityGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyMountTargetSecurityGroupsRequest`](struct.ModifyMountTargetSecurityGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct ModifyMountTargetSecurityGroupsRequestBuilder {
+    mount_target_id: Option<String>,
+    security_groups: Option<Vec<String>>,
+}
+
+impl ModifyMountTargetSecurityGroupsRequestBuilder {
+    /// Sets the [`mount_target_id`](struct.ModifyMountTargetSecurityGroupsRequest.html#structfield.mount_target_id) member.
+    pub fn mount_target_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.mount_target_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_groups`](struct.ModifyMountTargetSecurityGroupsRequest.html#structfield.security_groups) member.
+    pub fn security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_groups = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyMountTargetSecurityGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyMountTargetSecurityGroupsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ModifyMountTargetSecurityGroupsRequest {
+            mount_target_id: self
+                .mount_target_id
+                .ok_or(::rusoto_core::BuildError::missing("mount_target_id"))?,
+            security_groups: self.security_groups,
+        })
+    }
+}
+
 /// <p>Provides a description of a mount target.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct MountTargetDescription {
     /// <p>The ID of the file system for which the mount target is intended.</p>
@@ -362,7 +1002,7 @@ pub struct MountTargetDescription {
     pub ip_address: Option<String>,
     /// <p>Lifecycle state of the mount target.</p>
     #[serde(rename = "LifeCycleState")]
-    pub life_cycle_state: String,
+    pub life_cycle_state: LifeCycleState,
     /// <p>System-assigned mount target ID.</p>
     #[serde(rename = "MountTargetId")]
     pub mount_target_id: String,
@@ -379,6 +1019,84 @@ pub struct MountTargetDescription {
     pub subnet_id: String,
 }
 
+/// <p>The performance mode of the file system. We recommend <code>generalPurpose</code> performance mode for most file systems. File systems using the <code>maxIO</code> performance mode can scale to higher levels of aggregate throughput and operations per second with a tradeoff of slightly higher latencies for most file operations. The performance mode can't be changed after the file system has been created.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PerformanceMode {
+    /// <p><code>generalPurpose</code></p>
+    GeneralPurpose,
+    /// <p><code>maxIO</code></p>
+    MaxIO,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PerformanceMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PerformanceMode::GeneralPurpose => "generalPurpose",
+            PerformanceMode::MaxIO => "maxIO",
+            PerformanceMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PerformanceMode {
+    fn from(value: &'a str) -> PerformanceMode {
+        match value {
+            "generalPurpose" => PerformanceMode::GeneralPurpose,
+            "maxIO" => PerformanceMode::MaxIO,
+            _ => PerformanceMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PerformanceMode {
+    fn from(value: String) -> PerformanceMode {
+        PerformanceMode::from(value.as_str())
+    }
+}
+
+impl From<PerformanceMode> for String {
+    fn from(value: PerformanceMode) -> String {
+        match value {
+            PerformanceMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PerformanceMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PerformanceMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PerformanceMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PerformanceMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PerformanceMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PerformanceMode::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutLifecycleConfigurationRequest {
     /// <p>The ID of the file system for which you are creating the <code>LifecycleConfiguration</code> object (String).</p>
@@ -389,6 +1107,48 @@ pub struct PutLifecycleConfigurationRequest {
     pub lifecycle_policies: Vec<LifecyclePolicy>,
 }
 
+impl PutLifecycleConfigurationRequest {
+    /// Returns a builder for `PutLifecycleConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> PutLifecycleConfigurationRequestBuilder {
+        PutLifecycleConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutLifecycleConfigurationRequest`](struct.PutLifecycleConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutLifecycleConfigurationRequestBuilder {
+    file_system_id: Option<String>,
+    lifecycle_policies: Option<Vec<LifecyclePolicy>>,
+}
+
+impl PutLifecycleConfigurationRequestBuilder {
+    /// Sets the [`file_system_id`](struct.PutLifecycleConfigurationRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_policies`](struct.PutLifecycleConfigurationRequest.html#structfield.lifecycle_policies) member.
+    pub fn lifecycle_policies<T: Into<Vec<LifecyclePolicy>>>(mut self, value: T) -> Self {
+        self.lifecycle_policies = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutLifecycleConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutLifecycleConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(PutLifecycleConfigurationRequest {
+            file_system_id: self
+                .file_system_id
+                .ok_or(::rusoto_core::BuildError::missing("file_system_id"))?,
+            lifecycle_policies: self
+                .lifecycle_policies
+                .ok_or(::rusoto_core::BuildError::missing("lifecycle_policies"))?,
+        })
+    }
+}
+
 /// <p>A tag is a key-value pair. Allowed characters are letters, white space, and numbers that can be represented in UTF-8, and the following characters:<code> + - = . _ : /</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -400,6 +1160,194 @@ pub struct Tag {
     pub value: String,
 }
 
+/// <p>The throughput mode for the file system to be created. There are two throughput modes to choose from for your file system: <code>bursting</code> and <code>provisioned</code>. If you set <code>ThroughputMode</code> to <code>provisioned</code>, you must also set a value for <code>ProvisionedThroughPutInMibps</code>. You can decrease your file system's throughput in Provisioned Throughput mode or change between the throughput modes as long as it’s been more than 24 hours since the last decrease or throughput mode change. For more, see <a href="https://docs.aws.amazon.com/efs/latest/ug/performance.html#provisioned-throughput">Specifying Throughput with Provisioned Mode</a> in the <i>Amazon EFS User Guide.</i> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ThroughputMode {
+    /// <p><code>bursting</code></p>
+    Bursting,
+    /// <p><code>provisioned</code></p>
+    Provisioned,
+    /// <p><code>elastic</code></p>
+    Elastic,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ThroughputMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThroughputMode::Bursting => "bursting",
+            ThroughputMode::Provisioned => "provisioned",
+            ThroughputMode::Elastic => "elastic",
+            ThroughputMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ThroughputMode {
+    fn from(value: &'a str) -> ThroughputMode {
+        match value {
+            "bursting" => ThroughputMode::Bursting,
+            "provisioned" => ThroughputMode::Provisioned,
+            "elastic" => ThroughputMode::Elastic,
+            _ => ThroughputMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThroughputMode {
+    fn from(value: String) -> ThroughputMode {
+        ThroughputMode::from(value.as_str())
+    }
+}
+
+impl From<ThroughputMode> for String {
+    fn from(value: ThroughputMode) -> String {
+        match value {
+            ThroughputMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ThroughputMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThroughputMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThroughputMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThroughputMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ThroughputMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ThroughputMode::from(value))
+    }
+}
+
+/// <p> A value that describes the period of time that a file is not accessed, after which it transitions to the IA storage class. Metadata operations such as listing the contents of a directory don't count as file access events.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TransitionToIARules {
+    /// <p><code>AFTER_7_DAYS</code></p>
+    After7Days,
+    /// <p><code>AFTER_14_DAYS</code></p>
+    After14Days,
+    /// <p><code>AFTER_30_DAYS</code></p>
+    After30Days,
+    /// <p><code>AFTER_60_DAYS</code></p>
+    After60Days,
+    /// <p><code>AFTER_90_DAYS</code></p>
+    After90Days,
+    /// <p><code>AFTER_1_DAY</code></p>
+    After1Day,
+    /// <p><code>AFTER_180_DAYS</code></p>
+    After180Days,
+    /// <p><code>AFTER_270_DAYS</code></p>
+    After270Days,
+    /// <p><code>AFTER_365_DAYS</code></p>
+    After365Days,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TransitionToIARules {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TransitionToIARules::After7Days => "AFTER_7_DAYS",
+            TransitionToIARules::After14Days => "AFTER_14_DAYS",
+            TransitionToIARules::After30Days => "AFTER_30_DAYS",
+            TransitionToIARules::After60Days => "AFTER_60_DAYS",
+            TransitionToIARules::After90Days => "AFTER_90_DAYS",
+            TransitionToIARules::After1Day => "AFTER_1_DAY",
+            TransitionToIARules::After180Days => "AFTER_180_DAYS",
+            TransitionToIARules::After270Days => "AFTER_270_DAYS",
+            TransitionToIARules::After365Days => "AFTER_365_DAYS",
+            TransitionToIARules::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TransitionToIARules {
+    fn from(value: &'a str) -> TransitionToIARules {
+        match value {
+            "AFTER_7_DAYS" => TransitionToIARules::After7Days,
+            "AFTER_14_DAYS" => TransitionToIARules::After14Days,
+            "AFTER_30_DAYS" => TransitionToIARules::After30Days,
+            "AFTER_60_DAYS" => TransitionToIARules::After60Days,
+            "AFTER_90_DAYS" => TransitionToIARules::After90Days,
+            "AFTER_1_DAY" => TransitionToIARules::After1Day,
+            "AFTER_180_DAYS" => TransitionToIARules::After180Days,
+            "AFTER_270_DAYS" => TransitionToIARules::After270Days,
+            "AFTER_365_DAYS" => TransitionToIARules::After365Days,
+            _ => TransitionToIARules::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TransitionToIARules {
+    fn from(value: String) -> TransitionToIARules {
+        TransitionToIARules::from(value.as_str())
+    }
+}
+
+impl From<TransitionToIARules> for String {
+    fn from(value: TransitionToIARules) -> String {
+        match value {
+            TransitionToIARules::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TransitionToIARules {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TransitionToIARules {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TransitionToIARules {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TransitionToIARules {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TransitionToIARules, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TransitionToIARules::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateFileSystemRequest {
     /// <p>The ID of the file system that you want to update.</p>
@@ -412,7 +1360,55 @@ pub struct UpdateFileSystemRequest {
     /// <p>(Optional) The throughput mode that you want your file system to use. If you're not updating your throughput mode, you don't need to provide this value in your request. If you are changing the <code>ThroughputMode</code> to <code>provisioned</code>, you must also set a value for <code>ProvisionedThroughputInMibps</code>.</p>
     #[serde(rename = "ThroughputMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub throughput_mode: Option<String>,
+    pub throughput_mode: Option<ThroughputMode>,
+}
+
+impl UpdateFileSystemRequest {
+    /// Returns a builder for `UpdateFileSystemRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFileSystemRequestBuilder {
+        UpdateFileSystemRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFileSystemRequest`](struct.UpdateFileSystemRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFileSystemRequestBuilder {
+    file_system_id: Option<String>,
+    provisioned_throughput_in_mibps: Option<f64>,
+    throughput_mode: Option<ThroughputMode>,
+}
+
+impl UpdateFileSystemRequestBuilder {
+    /// Sets the [`file_system_id`](struct.UpdateFileSystemRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`provisioned_throughput_in_mibps`](struct.UpdateFileSystemRequest.html#structfield.provisioned_throughput_in_mibps) member.
+    pub fn provisioned_throughput_in_mibps<T: Into<f64>>(mut self, value: T) -> Self {
+        self.provisioned_throughput_in_mibps = Some(value.into());
+        self
+    }
+
+    /// Sets the [`throughput_mode`](struct.UpdateFileSystemRequest.html#structfield.throughput_mode) member.
+    pub fn throughput_mode<T: Into<ThroughputMode>>(mut self, value: T) -> Self {
+        self.throughput_mode = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFileSystemRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateFileSystemRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFileSystemRequest {
+            file_system_id: self
+                .file_system_id
+                .ok_or(::rusoto_core::BuildError::missing("file_system_id"))?,
+            provisioned_throughput_in_mibps: self.provisioned_throughput_in_mibps,
+            throughput_mode: self.throughput_mode,
+        })
+    }
 }
 
 /// Errors returned by CreateFileSystem
@@ -1360,6 +2356,96 @@ pub trait Efs {
         input: UpdateFileSystemRequest,
     ) -> RusotoFuture<FileSystemDescription, UpdateFileSystemError>;
 }
+impl<T: ?Sized + Efs> Efs for ::std::sync::Arc<T> {
+    fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> RusotoFuture<FileSystemDescription, CreateFileSystemError> {
+        (**self).create_file_system(input)
+    }
+    fn create_mount_target(
+        &self,
+        input: CreateMountTargetRequest,
+    ) -> RusotoFuture<MountTargetDescription, CreateMountTargetError> {
+        (**self).create_mount_target(input)
+    }
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> RusotoFuture<(), DeleteFileSystemError> {
+        (**self).delete_file_system(input)
+    }
+    fn delete_mount_target(
+        &self,
+        input: DeleteMountTargetRequest,
+    ) -> RusotoFuture<(), DeleteMountTargetError> {
+        (**self).delete_mount_target(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> RusotoFuture<DescribeFileSystemsResponse, DescribeFileSystemsError> {
+        (**self).describe_file_systems(input)
+    }
+    fn describe_lifecycle_configuration(
+        &self,
+        input: DescribeLifecycleConfigurationRequest,
+    ) -> RusotoFuture<LifecycleConfigurationDescription, DescribeLifecycleConfigurationError> {
+        (**self).describe_lifecycle_configuration(input)
+    }
+    fn describe_mount_target_security_groups(
+        &self,
+        input: DescribeMountTargetSecurityGroupsRequest,
+    ) -> RusotoFuture<
+        DescribeMountTargetSecurityGroupsResponse,
+        DescribeMountTargetSecurityGroupsError,
+    > {
+        (**self).describe_mount_target_security_groups(input)
+    }
+    fn describe_mount_targets(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> RusotoFuture<DescribeMountTargetsResponse, DescribeMountTargetsError> {
+        (**self).describe_mount_targets(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> RusotoFuture<DescribeTagsResponse, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn modify_mount_target_security_groups(
+        &self,
+        input: ModifyMountTargetSecurityGroupsRequest,
+    ) -> RusotoFuture<(), ModifyMountTargetSecurityGroupsError> {
+        (**self).modify_mount_target_security_groups(input)
+    }
+    fn put_lifecycle_configuration(
+        &self,
+        input: PutLifecycleConfigurationRequest,
+    ) -> RusotoFuture<LifecycleConfigurationDescription, PutLifecycleConfigurationError> {
+        (**self).put_lifecycle_configuration(input)
+    }
+    fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> RusotoFuture<FileSystemDescription, UpdateFileSystemError> {
+        (**self).update_file_system(input)
+    }
+}
+/// The error codes EFS retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the EFS API.
 #[derive(Clone)]
 pub struct EfsClient {
@@ -1375,6 +2461,27 @@ impl EfsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_EFS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> EfsClient {
+        Self::new(region::Region::from_env_for_service("EFS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `elasticfilesystem`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> EfsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("elasticfilesystem", "EFS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1393,7 +2500,54 @@ impl EfsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EfsClient {
-        EfsClient { client, region }
+        EfsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "elasticfilesystem")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "elasticfilesystem",
+            "elasticfilesystem",
+            "EFS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Efs` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Efs + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for EfsClient {
+    fn service_name(&self) -> &'static str {
+        "EFS"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "elasticfilesystem"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> EfsClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Elastic Kubernetes Service clients.
+//!
+//! Makes a single read-only `ListClusters` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_eks::{Eks, EksClient, ListClustersRequest};
+
+fn main() {
+    let client = EksClient::from_env();
+    match client.list_clusters(ListClustersRequest::default()).sync() {
+        Ok(_) => println!("ListClusters succeeded"),
+        Err(error) => {
+            eprintln!("ListClusters failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
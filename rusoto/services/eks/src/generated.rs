@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>An object representing the <code>certificate-authority-data</code> for your cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -52,8 +53,9 @@ pub struct Cluster {
     pub client_request_token: Option<String>,
     /// <p>The Unix epoch timestamp in seconds for when the cluster was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The endpoint for your Kubernetes API server.</p>
     #[serde(rename = "endpoint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -85,7 +87,7 @@ pub struct Cluster {
     /// <p>The current status of the cluster.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ClusterStatus>,
     /// <p>The metadata that you apply to the cluster to assist with categorization and organization. Each tag consists of a key and an optional value, both of which you define.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -96,6 +98,100 @@ pub struct Cluster {
     pub version: Option<String>,
 }
 
+/// <p>The current status of the cluster.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClusterStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ClusterStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterStatus::Creating => "CREATING",
+            ClusterStatus::Active => "ACTIVE",
+            ClusterStatus::Deleting => "DELETING",
+            ClusterStatus::Failed => "FAILED",
+            ClusterStatus::Updating => "UPDATING",
+            ClusterStatus::Pending => "PENDING",
+            ClusterStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ClusterStatus {
+    fn from(value: &'a str) -> ClusterStatus {
+        match value {
+            "CREATING" => ClusterStatus::Creating,
+            "ACTIVE" => ClusterStatus::Active,
+            "DELETING" => ClusterStatus::Deleting,
+            "FAILED" => ClusterStatus::Failed,
+            "UPDATING" => ClusterStatus::Updating,
+            "PENDING" => ClusterStatus::Pending,
+            _ => ClusterStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterStatus {
+    fn from(value: String) -> ClusterStatus {
+        ClusterStatus::from(value.as_str())
+    }
+}
+
+impl From<ClusterStatus> for String {
+    fn from(value: ClusterStatus) -> String {
+        match value {
+            ClusterStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ClusterStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClusterStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClusterStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ClusterStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ClusterStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateClusterRequest {
     /// <p>Unique, case-sensitive identifier that you provide to ensure the idempotency of the request.</p>
@@ -125,6 +221,88 @@ pub struct CreateClusterRequest {
     pub version: Option<String>,
 }
 
+impl CreateClusterRequest {
+    /// Returns a builder for `CreateClusterRequest`, checking its required members when built.
+    pub fn builder() -> CreateClusterRequestBuilder {
+        CreateClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateClusterRequest`](struct.CreateClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateClusterRequestBuilder {
+    client_request_token: Option<String>,
+    logging: Option<Logging>,
+    name: Option<String>,
+    resources_vpc_config: Option<VpcConfigRequest>,
+    role_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    version: Option<String>,
+}
+
+impl CreateClusterRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateClusterRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logging`](struct.CreateClusterRequest.html#structfield.logging) member.
+    pub fn logging<T: Into<Logging>>(mut self, value: T) -> Self {
+        self.logging = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateClusterRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resources_vpc_config`](struct.CreateClusterRequest.html#structfield.resources_vpc_config) member.
+    pub fn resources_vpc_config<T: Into<VpcConfigRequest>>(mut self, value: T) -> Self {
+        self.resources_vpc_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateClusterRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateClusterRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.CreateClusterRequest.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateClusterRequest, ::rusoto_core::BuildError> {
+        Ok(CreateClusterRequest {
+            client_request_token: self.client_request_token,
+            logging: self.logging,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            resources_vpc_config: self
+                .resources_vpc_config
+                .ok_or(::rusoto_core::BuildError::missing("resources_vpc_config"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            tags: self.tags,
+            version: self.version,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateClusterResponse {
@@ -141,6 +319,36 @@ pub struct DeleteClusterRequest {
     pub name: String,
 }
 
+impl DeleteClusterRequest {
+    /// Returns a builder for `DeleteClusterRequest`, checking its required members when built.
+    pub fn builder() -> DeleteClusterRequestBuilder {
+        DeleteClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteClusterRequest`](struct.DeleteClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteClusterRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteClusterRequestBuilder {
+    /// Sets the [`name`](struct.DeleteClusterRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteClusterRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteClusterRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteClusterResponse {
@@ -157,6 +365,36 @@ pub struct DescribeClusterRequest {
     pub name: String,
 }
 
+impl DescribeClusterRequest {
+    /// Returns a builder for `DescribeClusterRequest`, checking its required members when built.
+    pub fn builder() -> DescribeClusterRequestBuilder {
+        DescribeClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterRequest`](struct.DescribeClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterRequestBuilder {
+    name: Option<String>,
+}
+
+impl DescribeClusterRequestBuilder {
+    /// Sets the [`name`](struct.DescribeClusterRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeClusterRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeClusterRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeClusterResponse {
@@ -176,6 +414,46 @@ pub struct DescribeUpdateRequest {
     pub update_id: String,
 }
 
+impl DescribeUpdateRequest {
+    /// Returns a builder for `DescribeUpdateRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUpdateRequestBuilder {
+        DescribeUpdateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUpdateRequest`](struct.DescribeUpdateRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUpdateRequestBuilder {
+    name: Option<String>,
+    update_id: Option<String>,
+}
+
+impl DescribeUpdateRequestBuilder {
+    /// Sets the [`name`](struct.DescribeUpdateRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_id`](struct.DescribeUpdateRequest.html#structfield.update_id) member.
+    pub fn update_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.update_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUpdateRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeUpdateRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUpdateRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            update_id: self
+                .update_id
+                .ok_or(::rusoto_core::BuildError::missing("update_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUpdateResponse {
@@ -185,6 +463,144 @@ pub struct DescribeUpdateResponse {
     pub update: Option<Update>,
 }
 
+/// <p><p>A brief description of the error. </p> <ul> <li> <p> <b>SubnetNotFound</b>: We couldn&#39;t find one of the subnets associated with the cluster.</p> </li> <li> <p> <b>SecurityGroupNotFound</b>: We couldn&#39;t find one of the security groups associated with the cluster.</p> </li> <li> <p> <b>EniLimitReached</b>: You have reached the elastic network interface limit for your account.</p> </li> <li> <p> <b>IpNotAvailable</b>: A subnet associated with the cluster doesn&#39;t have any free IP addresses.</p> </li> <li> <p> <b>AccessDenied</b>: You don&#39;t have permissions to perform the specified operation.</p> </li> <li> <p> <b>OperationNotPermitted</b>: The service role associated with the cluster doesn&#39;t have the required access permissions for Amazon EKS.</p> </li> <li> <p> <b>VpcIdNotFound</b>: We couldn&#39;t find the VPC associated with the cluster.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    /// <p><code>SubnetNotFound</code></p>
+    SubnetNotFound,
+    /// <p><code>SecurityGroupNotFound</code></p>
+    SecurityGroupNotFound,
+    /// <p><code>EniLimitReached</code></p>
+    EniLimitReached,
+    /// <p><code>IpNotAvailable</code></p>
+    IpNotAvailable,
+    /// <p><code>AccessDenied</code></p>
+    AccessDenied,
+    /// <p><code>OperationNotPermitted</code></p>
+    OperationNotPermitted,
+    /// <p><code>VpcIdNotFound</code></p>
+    VpcIdNotFound,
+    /// <p><code>Unknown</code></p>
+    UnknownValue,
+    /// <p><code>NodeCreationFailure</code></p>
+    NodeCreationFailure,
+    /// <p><code>PodEvictionFailure</code></p>
+    PodEvictionFailure,
+    /// <p><code>InsufficientFreeAddresses</code></p>
+    InsufficientFreeAddresses,
+    /// <p><code>ClusterUnreachable</code></p>
+    ClusterUnreachable,
+    /// <p><code>InsufficientNumberOfReplicas</code></p>
+    InsufficientNumberOfReplicas,
+    /// <p><code>ConfigurationConflict</code></p>
+    ConfigurationConflict,
+    /// <p><code>AdmissionRequestDenied</code></p>
+    AdmissionRequestDenied,
+    /// <p><code>UnsupportedAddonModification</code></p>
+    UnsupportedAddonModification,
+    /// <p><code>K8sResourceNotFound</code></p>
+    K8sResourceNotFound,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::SubnetNotFound => "SubnetNotFound",
+            ErrorCode::SecurityGroupNotFound => "SecurityGroupNotFound",
+            ErrorCode::EniLimitReached => "EniLimitReached",
+            ErrorCode::IpNotAvailable => "IpNotAvailable",
+            ErrorCode::AccessDenied => "AccessDenied",
+            ErrorCode::OperationNotPermitted => "OperationNotPermitted",
+            ErrorCode::VpcIdNotFound => "VpcIdNotFound",
+            ErrorCode::UnknownValue => "Unknown",
+            ErrorCode::NodeCreationFailure => "NodeCreationFailure",
+            ErrorCode::PodEvictionFailure => "PodEvictionFailure",
+            ErrorCode::InsufficientFreeAddresses => "InsufficientFreeAddresses",
+            ErrorCode::ClusterUnreachable => "ClusterUnreachable",
+            ErrorCode::InsufficientNumberOfReplicas => "InsufficientNumberOfReplicas",
+            ErrorCode::ConfigurationConflict => "ConfigurationConflict",
+            ErrorCode::AdmissionRequestDenied => "AdmissionRequestDenied",
+            ErrorCode::UnsupportedAddonModification => "UnsupportedAddonModification",
+            ErrorCode::K8sResourceNotFound => "K8sResourceNotFound",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "SubnetNotFound" => ErrorCode::SubnetNotFound,
+            "SecurityGroupNotFound" => ErrorCode::SecurityGroupNotFound,
+            "EniLimitReached" => ErrorCode::EniLimitReached,
+            "IpNotAvailable" => ErrorCode::IpNotAvailable,
+            "AccessDenied" => ErrorCode::AccessDenied,
+            "OperationNotPermitted" => ErrorCode::OperationNotPermitted,
+            "VpcIdNotFound" => ErrorCode::VpcIdNotFound,
+            "Unknown" => ErrorCode::UnknownValue,
+            "NodeCreationFailure" => ErrorCode::NodeCreationFailure,
+            "PodEvictionFailure" => ErrorCode::PodEvictionFailure,
+            "InsufficientFreeAddresses" => ErrorCode::InsufficientFreeAddresses,
+            "ClusterUnreachable" => ErrorCode::ClusterUnreachable,
+            "InsufficientNumberOfReplicas" => ErrorCode::InsufficientNumberOfReplicas,
+            "ConfigurationConflict" => ErrorCode::ConfigurationConflict,
+            "AdmissionRequestDenied" => ErrorCode::AdmissionRequestDenied,
+            "UnsupportedAddonModification" => ErrorCode::UnsupportedAddonModification,
+            "K8sResourceNotFound" => ErrorCode::K8sResourceNotFound,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        ErrorCode::from(value.as_str())
+    }
+}
+
+impl From<ErrorCode> for String {
+    fn from(value: ErrorCode) -> String {
+        match value {
+            ErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ErrorCode::from(value))
+    }
+}
+
 /// <p>An object representing an error when an asynchronous operation fails.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -192,7 +608,7 @@ pub struct ErrorDetail {
     /// <p><p>A brief description of the error. </p> <ul> <li> <p> <b>SubnetNotFound</b>: We couldn&#39;t find one of the subnets associated with the cluster.</p> </li> <li> <p> <b>SecurityGroupNotFound</b>: We couldn&#39;t find one of the security groups associated with the cluster.</p> </li> <li> <p> <b>EniLimitReached</b>: You have reached the elastic network interface limit for your account.</p> </li> <li> <p> <b>IpNotAvailable</b>: A subnet associated with the cluster doesn&#39;t have any free IP addresses.</p> </li> <li> <p> <b>AccessDenied</b>: You don&#39;t have permissions to perform the specified operation.</p> </li> <li> <p> <b>OperationNotPermitted</b>: The service role associated with the cluster doesn&#39;t have the required access permissions for Amazon EKS.</p> </li> <li> <p> <b>VpcIdNotFound</b>: We couldn&#39;t find the VPC associated with the cluster.</p> </li> </ul></p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>A more complete description of the error.</p>
     #[serde(rename = "errorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -225,6 +641,42 @@ pub struct ListClustersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListClustersRequest {
+    /// Returns a builder for `ListClustersRequest`, checking its required members when built.
+    pub fn builder() -> ListClustersRequestBuilder {
+        ListClustersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListClustersRequest`](struct.ListClustersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListClustersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListClustersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListClustersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListClustersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListClustersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListClustersRequest, ::rusoto_core::BuildError> {
+        Ok(ListClustersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListClustersResponse {
@@ -245,6 +697,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -269,6 +753,52 @@ pub struct ListUpdatesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListUpdatesRequest {
+    /// Returns a builder for `ListUpdatesRequest`, checking its required members when built.
+    pub fn builder() -> ListUpdatesRequestBuilder {
+        ListUpdatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUpdatesRequest`](struct.ListUpdatesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUpdatesRequestBuilder {
+    max_results: Option<i64>,
+    name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListUpdatesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListUpdatesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.ListUpdatesRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUpdatesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUpdatesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListUpdatesRequest, ::rusoto_core::BuildError> {
+        Ok(ListUpdatesRequest {
+            max_results: self.max_results,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListUpdatesResponse {
@@ -292,7 +822,97 @@ pub struct LogSetup {
     /// <p>The available cluster control plane log types.</p>
     #[serde(rename = "types")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub types: Option<Vec<String>>,
+    pub types: Option<Vec<LogType>>,
+}
+
+/// <p>The available cluster control plane log types.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LogType {
+    /// <p><code>api</code></p>
+    Api,
+    /// <p><code>audit</code></p>
+    Audit,
+    /// <p><code>authenticator</code></p>
+    Authenticator,
+    /// <p><code>controllerManager</code></p>
+    ControllerManager,
+    /// <p><code>scheduler</code></p>
+    Scheduler,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LogType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogType::Api => "api",
+            LogType::Audit => "audit",
+            LogType::Authenticator => "authenticator",
+            LogType::ControllerManager => "controllerManager",
+            LogType::Scheduler => "scheduler",
+            LogType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LogType {
+    fn from(value: &'a str) -> LogType {
+        match value {
+            "api" => LogType::Api,
+            "audit" => LogType::Audit,
+            "authenticator" => LogType::Authenticator,
+            "controllerManager" => LogType::ControllerManager,
+            "scheduler" => LogType::Scheduler,
+            _ => LogType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogType {
+    fn from(value: String) -> LogType {
+        LogType::from(value.as_str())
+    }
+}
+
+impl From<LogType> for String {
+    fn from(value: LogType) -> String {
+        match value {
+            LogType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LogType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LogType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LogType::from(value))
+    }
 }
 
 /// <p>An object representing the logging configuration for resources in your cluster.</p>
@@ -324,6 +944,46 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -338,6 +998,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -348,8 +1048,9 @@ pub struct UntagResourceResponse {}
 pub struct Update {
     /// <p>The Unix epoch timestamp in seconds for when the update was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>Any errors associated with a <code>Failed</code> update.</p>
     #[serde(rename = "errors")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -365,11 +1066,11 @@ pub struct Update {
     /// <p>The current status of the update.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<UpdateStatus>,
     /// <p>The type of the update.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<UpdateType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -390,6 +1091,62 @@ pub struct UpdateClusterConfigRequest {
     pub resources_vpc_config: Option<VpcConfigRequest>,
 }
 
+impl UpdateClusterConfigRequest {
+    /// Returns a builder for `UpdateClusterConfigRequest`, checking its required members when built.
+    pub fn builder() -> UpdateClusterConfigRequestBuilder {
+        UpdateClusterConfigRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateClusterConfigRequest`](struct.UpdateClusterConfigRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateClusterConfigRequestBuilder {
+    client_request_token: Option<String>,
+    logging: Option<Logging>,
+    name: Option<String>,
+    resources_vpc_config: Option<VpcConfigRequest>,
+}
+
+impl UpdateClusterConfigRequestBuilder {
+    /// Sets the [`client_request_token`](struct.UpdateClusterConfigRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logging`](struct.UpdateClusterConfigRequest.html#structfield.logging) member.
+    pub fn logging<T: Into<Logging>>(mut self, value: T) -> Self {
+        self.logging = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateClusterConfigRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resources_vpc_config`](struct.UpdateClusterConfigRequest.html#structfield.resources_vpc_config) member.
+    pub fn resources_vpc_config<T: Into<VpcConfigRequest>>(mut self, value: T) -> Self {
+        self.resources_vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateClusterConfigRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateClusterConfigRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateClusterConfigRequest {
+            client_request_token: self.client_request_token,
+            logging: self.logging,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            resources_vpc_config: self.resources_vpc_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateClusterConfigResponse {
@@ -412,6 +1169,56 @@ pub struct UpdateClusterVersionRequest {
     pub version: String,
 }
 
+impl UpdateClusterVersionRequest {
+    /// Returns a builder for `UpdateClusterVersionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateClusterVersionRequestBuilder {
+        UpdateClusterVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateClusterVersionRequest`](struct.UpdateClusterVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateClusterVersionRequestBuilder {
+    client_request_token: Option<String>,
+    name: Option<String>,
+    version: Option<String>,
+}
+
+impl UpdateClusterVersionRequestBuilder {
+    /// Sets the [`client_request_token`](struct.UpdateClusterVersionRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateClusterVersionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.UpdateClusterVersionRequest.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateClusterVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateClusterVersionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateClusterVersionRequest {
+            client_request_token: self.client_request_token,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateClusterVersionResponse {
@@ -428,13 +1235,387 @@ pub struct UpdateParam {
     /// <p>The keys associated with an update request.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<UpdateParamType>,
     /// <p>The value of the keys submitted as part of an update request.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+/// <p>The keys associated with an update request.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateParamType {
+    /// <p><code>Version</code></p>
+    Version,
+    /// <p><code>PlatformVersion</code></p>
+    PlatformVersion,
+    /// <p><code>EndpointPrivateAccess</code></p>
+    EndpointPrivateAccess,
+    /// <p><code>EndpointPublicAccess</code></p>
+    EndpointPublicAccess,
+    /// <p><code>ClusterLogging</code></p>
+    ClusterLogging,
+    /// <p><code>DesiredSize</code></p>
+    DesiredSize,
+    /// <p><code>LabelsToAdd</code></p>
+    LabelsToAdd,
+    /// <p><code>LabelsToRemove</code></p>
+    LabelsToRemove,
+    /// <p><code>TaintsToAdd</code></p>
+    TaintsToAdd,
+    /// <p><code>TaintsToRemove</code></p>
+    TaintsToRemove,
+    /// <p><code>MaxSize</code></p>
+    MaxSize,
+    /// <p><code>MinSize</code></p>
+    MinSize,
+    /// <p><code>ReleaseVersion</code></p>
+    ReleaseVersion,
+    /// <p><code>PublicAccessCidrs</code></p>
+    PublicAccessCidrs,
+    /// <p><code>LaunchTemplateName</code></p>
+    LaunchTemplateName,
+    /// <p><code>LaunchTemplateVersion</code></p>
+    LaunchTemplateVersion,
+    /// <p><code>IdentityProviderConfig</code></p>
+    IdentityProviderConfig,
+    /// <p><code>EncryptionConfig</code></p>
+    EncryptionConfig,
+    /// <p><code>AddonVersion</code></p>
+    AddonVersion,
+    /// <p><code>ServiceAccountRoleArn</code></p>
+    ServiceAccountRoleArn,
+    /// <p><code>ResolveConflicts</code></p>
+    ResolveConflicts,
+    /// <p><code>MaxUnavailable</code></p>
+    MaxUnavailable,
+    /// <p><code>MaxUnavailablePercentage</code></p>
+    MaxUnavailablePercentage,
+    /// <p><code>ConfigurationValues</code></p>
+    ConfigurationValues,
+    /// <p><code>SecurityGroups</code></p>
+    SecurityGroups,
+    /// <p><code>Subnets</code></p>
+    Subnets,
+    /// <p><code>AuthenticationMode</code></p>
+    AuthenticationMode,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UpdateParamType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateParamType::Version => "Version",
+            UpdateParamType::PlatformVersion => "PlatformVersion",
+            UpdateParamType::EndpointPrivateAccess => "EndpointPrivateAccess",
+            UpdateParamType::EndpointPublicAccess => "EndpointPublicAccess",
+            UpdateParamType::ClusterLogging => "ClusterLogging",
+            UpdateParamType::DesiredSize => "DesiredSize",
+            UpdateParamType::LabelsToAdd => "LabelsToAdd",
+            UpdateParamType::LabelsToRemove => "LabelsToRemove",
+            UpdateParamType::TaintsToAdd => "TaintsToAdd",
+            UpdateParamType::TaintsToRemove => "TaintsToRemove",
+            UpdateParamType::MaxSize => "MaxSize",
+            UpdateParamType::MinSize => "MinSize",
+            UpdateParamType::ReleaseVersion => "ReleaseVersion",
+            UpdateParamType::PublicAccessCidrs => "PublicAccessCidrs",
+            UpdateParamType::LaunchTemplateName => "LaunchTemplateName",
+            UpdateParamType::LaunchTemplateVersion => "LaunchTemplateVersion",
+            UpdateParamType::IdentityProviderConfig => "IdentityProviderConfig",
+            UpdateParamType::EncryptionConfig => "EncryptionConfig",
+            UpdateParamType::AddonVersion => "AddonVersion",
+            UpdateParamType::ServiceAccountRoleArn => "ServiceAccountRoleArn",
+            UpdateParamType::ResolveConflicts => "ResolveConflicts",
+            UpdateParamType::MaxUnavailable => "MaxUnavailable",
+            UpdateParamType::MaxUnavailablePercentage => "MaxUnavailablePercentage",
+            UpdateParamType::ConfigurationValues => "ConfigurationValues",
+            UpdateParamType::SecurityGroups => "SecurityGroups",
+            UpdateParamType::Subnets => "Subnets",
+            UpdateParamType::AuthenticationMode => "AuthenticationMode",
+            UpdateParamType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UpdateParamType {
+    fn from(value: &'a str) -> UpdateParamType {
+        match value {
+            "Version" => UpdateParamType::Version,
+            "PlatformVersion" => UpdateParamType::PlatformVersion,
+            "EndpointPrivateAccess" => UpdateParamType::EndpointPrivateAccess,
+            "EndpointPublicAccess" => UpdateParamType::EndpointPublicAccess,
+            "ClusterLogging" => UpdateParamType::ClusterLogging,
+            "DesiredSize" => UpdateParamType::DesiredSize,
+            "LabelsToAdd" => UpdateParamType::LabelsToAdd,
+            "LabelsToRemove" => UpdateParamType::LabelsToRemove,
+            "TaintsToAdd" => UpdateParamType::TaintsToAdd,
+            "TaintsToRemove" => UpdateParamType::TaintsToRemove,
+            "MaxSize" => UpdateParamType::MaxSize,
+            "MinSize" => UpdateParamType::MinSize,
+            "ReleaseVersion" => UpdateParamType::ReleaseVersion,
+            "PublicAccessCidrs" => UpdateParamType::PublicAccessCidrs,
+            "LaunchTemplateName" => UpdateParamType::LaunchTemplateName,
+            "LaunchTemplateVersion" => UpdateParamType::LaunchTemplateVersion,
+            "IdentityProviderConfig" => UpdateParamType::IdentityProviderConfig,
+            "EncryptionConfig" => UpdateParamType::EncryptionConfig,
+            "AddonVersion" => UpdateParamType::AddonVersion,
+            "ServiceAccountRoleArn" => UpdateParamType::ServiceAccountRoleArn,
+            "ResolveConflicts" => UpdateParamType::ResolveConflicts,
+            "MaxUnavailable" => UpdateParamType::MaxUnavailable,
+            "MaxUnavailablePercentage" => UpdateParamType::MaxUnavailablePercentage,
+            "ConfigurationValues" => UpdateParamType::ConfigurationValues,
+            "SecurityGroups" => UpdateParamType::SecurityGroups,
+            "Subnets" => UpdateParamType::Subnets,
+            "AuthenticationMode" => UpdateParamType::AuthenticationMode,
+            _ => UpdateParamType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateParamType {
+    fn from(value: String) -> UpdateParamType {
+        UpdateParamType::from(value.as_str())
+    }
+}
+
+impl From<UpdateParamType> for String {
+    fn from(value: UpdateParamType) -> String {
+        match value {
+            UpdateParamType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateParamType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateParamType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateParamType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateParamType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UpdateParamType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UpdateParamType::from(value))
+    }
+}
+
+/// <p>The current status of the update.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateStatus {
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Cancelled</code></p>
+    Cancelled,
+    /// <p><code>Successful</code></p>
+    Successful,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UpdateStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateStatus::InProgress => "InProgress",
+            UpdateStatus::Failed => "Failed",
+            UpdateStatus::Cancelled => "Cancelled",
+            UpdateStatus::Successful => "Successful",
+            UpdateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UpdateStatus {
+    fn from(value: &'a str) -> UpdateStatus {
+        match value {
+            "InProgress" => UpdateStatus::InProgress,
+            "Failed" => UpdateStatus::Failed,
+            "Cancelled" => UpdateStatus::Cancelled,
+            "Successful" => UpdateStatus::Successful,
+            _ => UpdateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateStatus {
+    fn from(value: String) -> UpdateStatus {
+        UpdateStatus::from(value.as_str())
+    }
+}
+
+impl From<UpdateStatus> for String {
+    fn from(value: UpdateStatus) -> String {
+        match value {
+            UpdateStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UpdateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UpdateStatus::from(value))
+    }
+}
+
+/// <p>The type of the update.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateType {
+    /// <p><code>VersionUpdate</code></p>
+    VersionUpdate,
+    /// <p><code>EndpointAccessUpdate</code></p>
+    EndpointAccessUpdate,
+    /// <p><code>LoggingUpdate</code></p>
+    LoggingUpdate,
+    /// <p><code>ConfigUpdate</code></p>
+    ConfigUpdate,
+    /// <p><code>AssociateIdentityProviderConfig</code></p>
+    AssociateIdentityProviderConfig,
+    /// <p><code>DisassociateIdentityProviderConfig</code></p>
+    DisassociateIdentityProviderConfig,
+    /// <p><code>AssociateEncryptionConfig</code></p>
+    AssociateEncryptionConfig,
+    /// <p><code>AddonUpdate</code></p>
+    AddonUpdate,
+    /// <p><code>VpcConfigUpdate</code></p>
+    VpcConfigUpdate,
+    /// <p><code>AccessConfigUpdate</code></p>
+    AccessConfigUpdate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UpdateType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateType::VersionUpdate => "VersionUpdate",
+            UpdateType::EndpointAccessUpdate => "EndpointAccessUpdate",
+            UpdateType::LoggingUpdate => "LoggingUpdate",
+            UpdateType::ConfigUpdate => "ConfigUpdate",
+            UpdateType::AssociateIdentityProviderConfig => "AssociateIdentityProviderConfig",
+            UpdateType::DisassociateIdentityProviderConfig => "DisassociateIdentityProviderConfig",
+            UpdateType::AssociateEncryptionConfig => "AssociateEncryptionConfig",
+            UpdateType::AddonUpdate => "AddonUpdate",
+            UpdateType::VpcConfigUpdate => "VpcConfigUpdate",
+            UpdateType::AccessConfigUpdate => "AccessConfigUpdate",
+            UpdateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UpdateType {
+    fn from(value: &'a str) -> UpdateType {
+        match value {
+            "VersionUpdate" => UpdateType::VersionUpdate,
+            "EndpointAccessUpdate" => UpdateType::EndpointAccessUpdate,
+            "LoggingUpdate" => UpdateType::LoggingUpdate,
+            "ConfigUpdate" => UpdateType::ConfigUpdate,
+            "AssociateIdentityProviderConfig" => UpdateType::AssociateIdentityProviderConfig,
+            "DisassociateIdentityProviderConfig" => UpdateType::DisassociateIdentityProviderConfig,
+            "AssociateEncryptionConfig" => UpdateType::AssociateEncryptionConfig,
+            "AddonUpdate" => UpdateType::AddonUpdate,
+            "VpcConfigUpdate" => UpdateType::VpcConfigUpdate,
+            "AccessConfigUpdate" => UpdateType::AccessConfigUpdate,
+            _ => UpdateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateType {
+    fn from(value: String) -> UpdateType {
+        UpdateType::from(value.as_str())
+    }
+}
+
+impl From<UpdateType> for String {
+    fn from(value: UpdateType) -> String {
+        match value {
+            UpdateType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UpdateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UpdateType::from(value))
+    }
+}
+
 /// <p>An object representing the VPC configuration to use for an Amazon EKS cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct VpcConfigRequest {
@@ -1133,6 +2314,81 @@ pub trait Eks {
         input: UpdateClusterVersionRequest,
     ) -> RusotoFuture<UpdateClusterVersionResponse, UpdateClusterVersionError>;
 }
+impl<T: ?Sized + Eks> Eks for ::std::sync::Arc<T> {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResponse, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_update(
+        &self,
+        input: DescribeUpdateRequest,
+    ) -> RusotoFuture<DescribeUpdateResponse, DescribeUpdateError> {
+        (**self).describe_update(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_updates(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> RusotoFuture<ListUpdatesResponse, ListUpdatesError> {
+        (**self).list_updates(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_cluster_config(
+        &self,
+        input: UpdateClusterConfigRequest,
+    ) -> RusotoFuture<UpdateClusterConfigResponse, UpdateClusterConfigError> {
+        (**self).update_cluster_config(input)
+    }
+    fn update_cluster_version(
+        &self,
+        input: UpdateClusterVersionRequest,
+    ) -> RusotoFuture<UpdateClusterVersionResponse, UpdateClusterVersionError> {
+        (**self).update_cluster_version(input)
+    }
+}
+/// The error codes Amazon EKS retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon EKS API.
 #[derive(Clone)]
 pub struct EksClient {
@@ -1148,6 +2404,24 @@ impl EksClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_EKS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> EksClient {
+        Self::new(region::Region::from_env_for_service("EKS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `eks`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> EksClient {
+        Self::new_with_client(config.client().clone(), config.region_for("eks", "EKS"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1166,7 +2440,49 @@ impl EksClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EksClient {
-        EksClient { client, region }
+        EksClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "eks")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "eks", "eks", "EKS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Eks` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Eks + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for EksClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon EKS"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "eks"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> EksClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon ElastiCache clients.
+//!
+//! Makes a single read-only `ListAllowedNodeTypeModifications` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_elasticache::{ElastiCache, ElastiCacheClient, ListAllowedNodeTypeModificationsMessage};
+
+fn main() {
+    let client = ElastiCacheClient::from_env();
+    match client
+        .list_allowed_node_type_modifications(ListAllowedNodeTypeModificationsMessage::default())
+        .sync()
+    {
+        Ok(_) => println!("ListAllowedNodeTypeModifications succeeded"),
+        Err(error) => {
+            eprintln!("ListAllowedNodeTypeModifications failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -28,11 +28,71 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
 use xml::EventReader;
 
+/// <p>Specifies whether the nodes in this Memcached cluster are created in a single Availability Zone or created across multiple Availability Zones in the cluster's region.</p> <p>This parameter is only supported for Memcached clusters.</p> <p>If the <code>AZMode</code> and <code>PreferredAvailabilityZones</code> are not specified, ElastiCache assumes <code>single-az</code> mode.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AZMode {
+    /// <p><code>single-az</code></p>
+    SingleAz,
+    /// <p><code>cross-az</code></p>
+    CrossAz,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AZMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AZMode::SingleAz => "single-az",
+            AZMode::CrossAz => "cross-az",
+            AZMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AZMode {
+    fn from(value: &'a str) -> AZMode {
+        match value {
+            "single-az" => AZMode::SingleAz,
+            "cross-az" => AZMode::CrossAz,
+            _ => AZMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AZMode {
+    fn from(value: String) -> AZMode {
+        AZMode::from(value.as_str())
+    }
+}
+
+impl From<AZMode> for String {
+    fn from(value: AZMode) -> String {
+        match value {
+            AZMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AZMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AZMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Represents the input of an AddTagsToResource operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AddTagsToResourceMessage {
@@ -42,6 +102,48 @@ pub struct AddTagsToResourceMessage {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsToResourceMessage {
+    /// Returns a builder for `AddTagsToResourceMessage`, checking its required members when built.
+    pub fn builder() -> AddTagsToResourceMessageBuilder {
+        AddTagsToResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsToResourceMessage`](struct.AddTagsToResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct AddTagsToResourceMessageBuilder {
+    resource_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsToResourceMessageBuilder {
+    /// Sets the [`resource_name`](struct.AddTagsToResourceMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsToResourceMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsToResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddTagsToResourceMessage, ::rusoto_core::BuildError> {
+        Ok(AddTagsToResourceMessage {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `AddTagsToResourceMessage` contents to a `SignedRequest`.
 struct AddTagsToResourceMessageSerializer;
 impl AddTagsToResourceMessageSerializer {
@@ -56,10 +158,13 @@ impl AddTagsToResourceMessageSerializer {
     }
 }
 
-struct AllowedNodeGroupIdDeserializer;
+pub(crate) struct AllowedNodeGroupIdDeserializer;
 impl AllowedNodeGroupIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -76,10 +181,10 @@ pub struct AllowedNodeTypeModificationsMessage {
     pub scale_up_modifications: Option<Vec<String>>,
 }
 
-struct AllowedNodeTypeModificationsMessageDeserializer;
+pub(crate) struct AllowedNodeTypeModificationsMessageDeserializer;
 impl AllowedNodeTypeModificationsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AllowedNodeTypeModificationsMessage, XmlParseError> {
@@ -116,6 +221,59 @@ pub struct AuthorizeCacheSecurityGroupIngressMessage {
     pub ec2_security_group_owner_id: String,
 }
 
+impl AuthorizeCacheSecurityGroupIngressMessage {
+    /// Returns a builder for `AuthorizeCacheSecurityGroupIngressMessage`, checking its required members when built.
+    pub fn builder() -> AuthorizeCacheSecurityGroupIngressMessageBuilder {
+        AuthorizeCacheSecurityGroupIngressMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AuthorizeCacheSecurityGroupIngressMessage`](struct.AuthorizeCacheSecurityGroupIngressMessage.html).
+#[derive(Default, Debug)]
+pub struct AuthorizeCacheSecurityGroupIngressMessageBuilder {
+    cache_security_group_name: Option<String>,
+    ec2_security_group_name: Option<String>,
+    ec2_security_group_owner_id: Option<String>,
+}
+
+impl AuthorizeCacheSecurityGroupIngressMessageBuilder {
+    /// Sets the [`cache_security_group_name`](struct.AuthorizeCacheSecurityGroupIngressMessage.html#structfield.cache_security_group_name) member.
+    pub fn cache_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_name`](struct.AuthorizeCacheSecurityGroupIngressMessage.html#structfield.ec2_security_group_name) member.
+    pub fn ec2_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_owner_id`](struct.AuthorizeCacheSecurityGroupIngressMessage.html#structfield.ec2_security_group_owner_id) member.
+    pub fn ec2_security_group_owner_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_owner_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AuthorizeCacheSecurityGroupIngressMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AuthorizeCacheSecurityGroupIngressMessage, ::rusoto_core::BuildError>
+    {
+        Ok(AuthorizeCacheSecurityGroupIngressMessage {
+            cache_security_group_name: self.cache_security_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cache_security_group_name"),
+            )?,
+            ec2_security_group_name: self.ec2_security_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("ec2_security_group_name"),
+            )?,
+            ec2_security_group_owner_id: self.ec2_security_group_owner_id.ok_or(
+                ::rusoto_core::BuildError::missing("ec2_security_group_owner_id"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `AuthorizeCacheSecurityGroupIngressMessage` contents to a `SignedRequest`.
 struct AuthorizeCacheSecurityGroupIngressMessageSerializer;
 impl AuthorizeCacheSecurityGroupIngressMessageSerializer {
@@ -145,10 +303,10 @@ pub struct AuthorizeCacheSecurityGroupIngressResult {
     pub cache_security_group: Option<CacheSecurityGroup>,
 }
 
-struct AuthorizeCacheSecurityGroupIngressResultDeserializer;
+pub(crate) struct AuthorizeCacheSecurityGroupIngressResultDeserializer;
 impl AuthorizeCacheSecurityGroupIngressResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AuthorizeCacheSecurityGroupIngressResult, XmlParseError> {
@@ -171,17 +329,87 @@ impl AuthorizeCacheSecurityGroupIngressResultDeserializer {
         )
     }
 }
-struct AutomaticFailoverStatusDeserializer;
+pub(crate) struct AutomaticFailoverStatusDeserializer;
 impl AutomaticFailoverStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AutomaticFailoverStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p><p>Indicates the status of Multi-AZ with automatic failover for this Redis replication group.</p> <p>Amazon ElastiCache for Redis does not support Multi-AZ with automatic failover on:</p> <ul> <li> <p>Redis versions earlier than 2.8.6.</p> </li> <li> <p>Redis (cluster mode disabled): T1 node types.</p> </li> <li> <p>Redis (cluster mode enabled): T1 node types.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AutomaticFailoverStatus {
+    /// <p><code>enabled</code></p>
+    Enabled,
+    /// <p><code>disabled</code></p>
+    Disabled,
+    /// <p><code>enabling</code></p>
+    Enabling,
+    /// <p><code>disabling</code></p>
+    Disabling,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AutomaticFailoverStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutomaticFailoverStatus::Enabled => "enabled",
+            AutomaticFailoverStatus::Disabled => "disabled",
+            AutomaticFailoverStatus::Enabling => "enabling",
+            AutomaticFailoverStatus::Disabling => "disabling",
+            AutomaticFailoverStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AutomaticFailoverStatus {
+    fn from(value: &'a str) -> AutomaticFailoverStatus {
+        match value {
+            "enabled" => AutomaticFailoverStatus::Enabled,
+            "disabled" => AutomaticFailoverStatus::Disabled,
+            "enabling" => AutomaticFailoverStatus::Enabling,
+            "disabling" => AutomaticFailoverStatus::Disabling,
+            _ => AutomaticFailoverStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutomaticFailoverStatus {
+    fn from(value: String) -> AutomaticFailoverStatus {
+        AutomaticFailoverStatus::from(value.as_str())
+    }
+}
+
+impl From<AutomaticFailoverStatus> for String {
+    fn from(value: AutomaticFailoverStatus) -> String {
+        match value {
+            AutomaticFailoverStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AutomaticFailoverStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutomaticFailoverStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Describes an Availability Zone in which the cluster is launched.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AvailabilityZone {
@@ -189,10 +417,10 @@ pub struct AvailabilityZone {
     pub name: Option<String>,
 }
 
-struct AvailabilityZoneDeserializer;
+pub(crate) struct AvailabilityZoneDeserializer;
 impl AvailabilityZoneDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AvailabilityZone, XmlParseError> {
@@ -207,10 +435,10 @@ impl AvailabilityZoneDeserializer {
         })
     }
 }
-struct AvailabilityZonesListDeserializer;
+pub(crate) struct AvailabilityZonesListDeserializer;
 impl AvailabilityZonesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -244,6 +472,48 @@ pub struct BatchApplyUpdateActionMessage {
     pub service_update_name: String,
 }
 
+impl BatchApplyUpdateActionMessage {
+    /// Returns a builder for `BatchApplyUpdateActionMessage`, checking its required members when built.
+    pub fn builder() -> BatchApplyUpdateActionMessageBuilder {
+        BatchApplyUpdateActionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`BatchApplyUpdateActionMessage`](struct.BatchApplyUpdateActionMessage.html).
+#[derive(Default, Debug)]
+pub struct BatchApplyUpdateActionMessageBuilder {
+    replication_group_ids: Option<Vec<String>>,
+    service_update_name: Option<String>,
+}
+
+impl BatchApplyUpdateActionMessageBuilder {
+    /// Sets the [`replication_group_ids`](struct.BatchApplyUpdateActionMessage.html#structfield.replication_group_ids) member.
+    pub fn replication_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.replication_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_update_name`](struct.BatchApplyUpdateActionMessage.html#structfield.service_update_name) member.
+    pub fn service_update_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_update_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchApplyUpdateActionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchApplyUpdateActionMessage, ::rusoto_core::BuildError> {
+        Ok(BatchApplyUpdateActionMessage {
+            replication_group_ids: self
+                .replication_group_ids
+                .ok_or(::rusoto_core::BuildError::missing("replication_group_ids"))?,
+            service_update_name: self
+                .service_update_name
+                .ok_or(::rusoto_core::BuildError::missing("service_update_name"))?,
+        })
+    }
+}
+
 /// Serialize `BatchApplyUpdateActionMessage` contents to a `SignedRequest`.
 struct BatchApplyUpdateActionMessageSerializer;
 impl BatchApplyUpdateActionMessageSerializer {
@@ -273,6 +543,48 @@ pub struct BatchStopUpdateActionMessage {
     pub service_update_name: String,
 }
 
+impl BatchStopUpdateActionMessage {
+    /// Returns a builder for `BatchStopUpdateActionMessage`, checking its required members when built.
+    pub fn builder() -> BatchStopUpdateActionMessageBuilder {
+        BatchStopUpdateActionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`BatchStopUpdateActionMessage`](struct.BatchStopUpdateActionMessage.html).
+#[derive(Default, Debug)]
+pub struct BatchStopUpdateActionMessageBuilder {
+    replication_group_ids: Option<Vec<String>>,
+    service_update_name: Option<String>,
+}
+
+impl BatchStopUpdateActionMessageBuilder {
+    /// Sets the [`replication_group_ids`](struct.BatchStopUpdateActionMessage.html#structfield.replication_group_ids) member.
+    pub fn replication_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.replication_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_update_name`](struct.BatchStopUpdateActionMessage.html#structfield.service_update_name) member.
+    pub fn service_update_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_update_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchStopUpdateActionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchStopUpdateActionMessage, ::rusoto_core::BuildError> {
+        Ok(BatchStopUpdateActionMessage {
+            replication_group_ids: self
+                .replication_group_ids
+                .ok_or(::rusoto_core::BuildError::missing("replication_group_ids"))?,
+            service_update_name: self
+                .service_update_name
+                .ok_or(::rusoto_core::BuildError::missing("service_update_name"))?,
+        })
+    }
+}
+
 /// Serialize `BatchStopUpdateActionMessage` contents to a `SignedRequest`.
 struct BatchStopUpdateActionMessageSerializer;
 impl BatchStopUpdateActionMessageSerializer {
@@ -294,10 +606,13 @@ impl BatchStopUpdateActionMessageSerializer {
     }
 }
 
-struct BooleanDeserializer;
+pub(crate) struct BooleanDeserializer;
 impl BooleanDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -305,10 +620,13 @@ impl BooleanDeserializer {
         Ok(obj)
     }
 }
-struct BooleanOptionalDeserializer;
+pub(crate) struct BooleanOptionalDeserializer;
 impl BooleanOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -326,7 +644,7 @@ pub struct CacheCluster {
     /// <p>This parameter is currently disabled.</p>
     pub auto_minor_version_upgrade: Option<bool>,
     /// <p>The date and time when the cluster was created.</p>
-    pub cache_cluster_create_time: Option<String>,
+    pub cache_cluster_create_time: Option<IsoTimestamp>,
     /// <p>The user-supplied identifier of the cluster. This identifier is a unique key that identifies a cluster.</p>
     pub cache_cluster_id: Option<String>,
     /// <p>The current state of this cluster, one of the following values: <code>available</code>, <code>creating</code>, <code>deleted</code>, <code>deleting</code>, <code>incompatible-network</code>, <code>modifying</code>, <code>rebooting cluster nodes</code>, <code>restore-failed</code>, or <code>snapshotting</code>.</p>
@@ -370,10 +688,10 @@ pub struct CacheCluster {
     pub transit_encryption_enabled: Option<bool>,
 }
 
-struct CacheClusterDeserializer;
+pub(crate) struct CacheClusterDeserializer;
 impl CacheClusterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheCluster, XmlParseError> {
@@ -530,10 +848,10 @@ impl CacheClusterDeserializer {
         })
     }
 }
-struct CacheClusterListDeserializer;
+pub(crate) struct CacheClusterListDeserializer;
 impl CacheClusterListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CacheCluster>, XmlParseError> {
@@ -559,10 +877,10 @@ pub struct CacheClusterMessage {
     pub marker: Option<String>,
 }
 
-struct CacheClusterMessageDeserializer;
+pub(crate) struct CacheClusterMessageDeserializer;
 impl CacheClusterMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheClusterMessage, XmlParseError> {
@@ -597,10 +915,10 @@ pub struct CacheEngineVersion {
     pub engine_version: Option<String>,
 }
 
-struct CacheEngineVersionDeserializer;
+pub(crate) struct CacheEngineVersionDeserializer;
 impl CacheEngineVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheEngineVersion, XmlParseError> {
@@ -637,10 +955,10 @@ impl CacheEngineVersionDeserializer {
         })
     }
 }
-struct CacheEngineVersionListDeserializer;
+pub(crate) struct CacheEngineVersionListDeserializer;
 impl CacheEngineVersionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CacheEngineVersion>, XmlParseError> {
@@ -666,10 +984,10 @@ pub struct CacheEngineVersionMessage {
     pub marker: Option<String>,
 }
 
-struct CacheEngineVersionMessageDeserializer;
+pub(crate) struct CacheEngineVersionMessageDeserializer;
 impl CacheEngineVersionMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheEngineVersionMessage, XmlParseError> {
@@ -700,7 +1018,7 @@ impl CacheEngineVersionMessageDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CacheNode {
     /// <p>The date and time when the cache node was created.</p>
-    pub cache_node_create_time: Option<String>,
+    pub cache_node_create_time: Option<IsoTimestamp>,
     /// <p>The cache node identifier. A node ID is a numeric identifier (0001, 0002, etc.). The combination of cluster ID and node ID uniquely identifies every cache node used in a customer's AWS account.</p>
     pub cache_node_id: Option<String>,
     /// <p>The current state of this cache node.</p>
@@ -715,10 +1033,10 @@ pub struct CacheNode {
     pub source_cache_node_id: Option<String>,
 }
 
-struct CacheNodeDeserializer;
+pub(crate) struct CacheNodeDeserializer;
 impl CacheNodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheNode, XmlParseError> {
@@ -763,10 +1081,10 @@ impl CacheNodeDeserializer {
         })
     }
 }
-struct CacheNodeIdsListDeserializer;
+pub(crate) struct CacheNodeIdsListDeserializer;
 impl CacheNodeIdsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -792,10 +1110,10 @@ impl CacheNodeIdsListSerializer {
     }
 }
 
-struct CacheNodeListDeserializer;
+pub(crate) struct CacheNodeListDeserializer;
 impl CacheNodeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CacheNode>, XmlParseError> {
@@ -817,7 +1135,7 @@ pub struct CacheNodeTypeSpecificParameter {
     /// <p>A list of cache node types and their corresponding values for this parameter.</p>
     pub cache_node_type_specific_values: Option<Vec<CacheNodeTypeSpecificValue>>,
     /// <p>Indicates whether a change to the parameter is applied immediately or requires a reboot for the change to be applied. You can force a reboot or wait until the next maintenance window's reboot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Clusters.Rebooting.html">Rebooting a Cluster</a>.</p>
-    pub change_type: Option<String>,
+    pub change_type: Option<ChangeType>,
     /// <p>The valid data type for the parameter.</p>
     pub data_type: Option<String>,
     /// <p>A description of the parameter.</p>
@@ -832,10 +1150,10 @@ pub struct CacheNodeTypeSpecificParameter {
     pub source: Option<String>,
 }
 
-struct CacheNodeTypeSpecificParameterDeserializer;
+pub(crate) struct CacheNodeTypeSpecificParameterDeserializer;
 impl CacheNodeTypeSpecificParameterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheNodeTypeSpecificParameter, XmlParseError> {
@@ -891,10 +1209,10 @@ impl CacheNodeTypeSpecificParameterDeserializer {
         )
     }
 }
-struct CacheNodeTypeSpecificParametersListDeserializer;
+pub(crate) struct CacheNodeTypeSpecificParametersListDeserializer;
 impl CacheNodeTypeSpecificParametersListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CacheNodeTypeSpecificParameter>, XmlParseError> {
@@ -920,10 +1238,10 @@ pub struct CacheNodeTypeSpecificValue {
     pub value: Option<String>,
 }
 
-struct CacheNodeTypeSpecificValueDeserializer;
+pub(crate) struct CacheNodeTypeSpecificValueDeserializer;
 impl CacheNodeTypeSpecificValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheNodeTypeSpecificValue, XmlParseError> {
@@ -946,10 +1264,10 @@ impl CacheNodeTypeSpecificValueDeserializer {
         )
     }
 }
-struct CacheNodeTypeSpecificValueListDeserializer;
+pub(crate) struct CacheNodeTypeSpecificValueListDeserializer;
 impl CacheNodeTypeSpecificValueListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CacheNodeTypeSpecificValue>, XmlParseError> {
@@ -977,10 +1295,10 @@ pub struct CacheParameterGroup {
     pub description: Option<String>,
 }
 
-struct CacheParameterGroupDeserializer;
+pub(crate) struct CacheParameterGroupDeserializer;
 impl CacheParameterGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheParameterGroup, XmlParseError> {
@@ -1018,10 +1336,10 @@ pub struct CacheParameterGroupDetails {
     pub parameters: Option<Vec<Parameter>>,
 }
 
-struct CacheParameterGroupDetailsDeserializer;
+pub(crate) struct CacheParameterGroupDetailsDeserializer;
 impl CacheParameterGroupDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheParameterGroupDetails, XmlParseError> {
@@ -1055,10 +1373,10 @@ impl CacheParameterGroupDetailsDeserializer {
         )
     }
 }
-struct CacheParameterGroupListDeserializer;
+pub(crate) struct CacheParameterGroupListDeserializer;
 impl CacheParameterGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CacheParameterGroup>, XmlParseError> {
@@ -1082,10 +1400,10 @@ pub struct CacheParameterGroupNameMessage {
     pub cache_parameter_group_name: Option<String>,
 }
 
-struct CacheParameterGroupNameMessageDeserializer;
+pub(crate) struct CacheParameterGroupNameMessageDeserializer;
 impl CacheParameterGroupNameMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheParameterGroupNameMessage, XmlParseError> {
@@ -1118,10 +1436,10 @@ pub struct CacheParameterGroupStatus {
     pub parameter_apply_status: Option<String>,
 }
 
-struct CacheParameterGroupStatusDeserializer;
+pub(crate) struct CacheParameterGroupStatusDeserializer;
 impl CacheParameterGroupStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheParameterGroupStatus, XmlParseError> {
@@ -1166,10 +1484,10 @@ pub struct CacheParameterGroupsMessage {
     pub marker: Option<String>,
 }
 
-struct CacheParameterGroupsMessageDeserializer;
+pub(crate) struct CacheParameterGroupsMessageDeserializer;
 impl CacheParameterGroupsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheParameterGroupsMessage, XmlParseError> {
@@ -1209,10 +1527,10 @@ pub struct CacheSecurityGroup {
     pub owner_id: Option<String>,
 }
 
-struct CacheSecurityGroupDeserializer;
+pub(crate) struct CacheSecurityGroupDeserializer;
 impl CacheSecurityGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheSecurityGroup, XmlParseError> {
@@ -1250,10 +1568,10 @@ pub struct CacheSecurityGroupMembership {
     pub status: Option<String>,
 }
 
-struct CacheSecurityGroupMembershipDeserializer;
+pub(crate) struct CacheSecurityGroupMembershipDeserializer;
 impl CacheSecurityGroupMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheSecurityGroupMembership, XmlParseError> {
@@ -1278,10 +1596,10 @@ impl CacheSecurityGroupMembershipDeserializer {
         )
     }
 }
-struct CacheSecurityGroupMembershipListDeserializer;
+pub(crate) struct CacheSecurityGroupMembershipListDeserializer;
 impl CacheSecurityGroupMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CacheSecurityGroupMembership>, XmlParseError> {
@@ -1307,10 +1625,10 @@ pub struct CacheSecurityGroupMessage {
     pub marker: Option<String>,
 }
 
-struct CacheSecurityGroupMessageDeserializer;
+pub(crate) struct CacheSecurityGroupMessageDeserializer;
 impl CacheSecurityGroupMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheSecurityGroupMessage, XmlParseError> {
@@ -1349,10 +1667,10 @@ impl CacheSecurityGroupNameListSerializer {
     }
 }
 
-struct CacheSecurityGroupsDeserializer;
+pub(crate) struct CacheSecurityGroupsDeserializer;
 impl CacheSecurityGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CacheSecurityGroup>, XmlParseError> {
@@ -1382,10 +1700,10 @@ pub struct CacheSubnetGroup {
     pub vpc_id: Option<String>,
 }
 
-struct CacheSubnetGroupDeserializer;
+pub(crate) struct CacheSubnetGroupDeserializer;
 impl CacheSubnetGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheSubnetGroup, XmlParseError> {
@@ -1426,10 +1744,10 @@ pub struct CacheSubnetGroupMessage {
     pub marker: Option<String>,
 }
 
-struct CacheSubnetGroupMessageDeserializer;
+pub(crate) struct CacheSubnetGroupMessageDeserializer;
 impl CacheSubnetGroupMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CacheSubnetGroupMessage, XmlParseError> {
@@ -1453,10 +1771,10 @@ impl CacheSubnetGroupMessageDeserializer {
         )
     }
 }
-struct CacheSubnetGroupsDeserializer;
+pub(crate) struct CacheSubnetGroupsDeserializer;
 impl CacheSubnetGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CacheSubnetGroup>, XmlParseError> {
@@ -1473,21 +1791,83 @@ impl CacheSubnetGroupsDeserializer {
         })
     }
 }
-struct ChangeTypeDeserializer;
+pub(crate) struct ChangeTypeDeserializer;
 impl ChangeTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ChangeType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ClusterIdListDeserializer;
+/// <p>Indicates whether a change to the parameter is applied immediately or requires a reboot for the change to be applied. You can force a reboot or wait until the next maintenance window's reboot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Clusters.Rebooting.html">Rebooting a Cluster</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeType {
+    /// <p><code>immediate</code></p>
+    Immediate,
+    /// <p><code>requires-reboot</code></p>
+    RequiresReboot,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeType::Immediate => "immediate",
+            ChangeType::RequiresReboot => "requires-reboot",
+            ChangeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeType {
+    fn from(value: &'a str) -> ChangeType {
+        match value {
+            "immediate" => ChangeType::Immediate,
+            "requires-reboot" => ChangeType::RequiresReboot,
+            _ => ChangeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeType {
+    fn from(value: String) -> ChangeType {
+        ChangeType::from(value.as_str())
+    }
+}
+
+impl From<ChangeType> for String {
+    fn from(value: ChangeType) -> String {
+        match value {
+            ChangeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ClusterIdListDeserializer;
 impl ClusterIdListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -1549,6 +1929,62 @@ pub struct CopySnapshotMessage {
     pub target_snapshot_name: String,
 }
 
+impl CopySnapshotMessage {
+    /// Returns a builder for `CopySnapshotMessage`, checking its required members when built.
+    pub fn builder() -> CopySnapshotMessageBuilder {
+        CopySnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CopySnapshotMessage`](struct.CopySnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct CopySnapshotMessageBuilder {
+    kms_key_id: Option<String>,
+    source_snapshot_name: Option<String>,
+    target_bucket: Option<String>,
+    target_snapshot_name: Option<String>,
+}
+
+impl CopySnapshotMessageBuilder {
+    /// Sets the [`kms_key_id`](struct.CopySnapshotMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_snapshot_name`](struct.CopySnapshotMessage.html#structfield.source_snapshot_name) member.
+    pub fn source_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_bucket`](struct.CopySnapshotMessage.html#structfield.target_bucket) member.
+    pub fn target_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_bucket = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_snapshot_name`](struct.CopySnapshotMessage.html#structfield.target_snapshot_name) member.
+    pub fn target_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopySnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CopySnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(CopySnapshotMessage {
+            kms_key_id: self.kms_key_id,
+            source_snapshot_name: self
+                .source_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("source_snapshot_name"))?,
+            target_bucket: self.target_bucket,
+            target_snapshot_name: self
+                .target_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("target_snapshot_name"))?,
+        })
+    }
+}
+
 /// Serialize `CopySnapshotMessage` contents to a `SignedRequest`.
 struct CopySnapshotMessageSerializer;
 impl CopySnapshotMessageSerializer {
@@ -1580,10 +2016,10 @@ pub struct CopySnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
 
-struct CopySnapshotResultDeserializer;
+pub(crate) struct CopySnapshotResultDeserializer;
 impl CopySnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CopySnapshotResult, XmlParseError> {
@@ -1602,7 +2038,7 @@ impl CopySnapshotResultDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateCacheClusterMessage {
     /// <p>Specifies whether the nodes in this Memcached cluster are created in a single Availability Zone or created across multiple Availability Zones in the cluster's region.</p> <p>This parameter is only supported for Memcached clusters.</p> <p>If the <code>AZMode</code> and <code>PreferredAvailabilityZones</code> are not specified, ElastiCache assumes <code>single-az</code> mode.</p>
-    pub az_mode: Option<String>,
+    pub az_mode: Option<AZMode>,
     /// <p> <b>Reserved parameter.</b> The password used to access a password protected server.</p> <p>Password constraints:</p> <ul> <li> <p>Must be only printable ASCII characters.</p> </li> <li> <p>Must be at least 16 characters and no more than 128 characters in length.</p> </li> <li> <p>Cannot contain any of the following characters: '/', '"', or '@'. </p> </li> </ul> <p>For more information, see <a href="http://redis.io/commands/AUTH">AUTH password</a> at http://redis.io/commands/AUTH.</p>
     pub auth_token: Option<String>,
     /// <p>This parameter is currently disabled.</p>
@@ -1649,6 +2085,214 @@ pub struct CreateCacheClusterMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateCacheClusterMessage {
+    /// Returns a builder for `CreateCacheClusterMessage`, checking its required members when built.
+    pub fn builder() -> CreateCacheClusterMessageBuilder {
+        CreateCacheClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCacheClusterMessage`](struct.CreateCacheClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateCacheClusterMessageBuilder {
+    az_mode: Option<AZMode>,
+    auth_token: Option<String>,
+    auto_minor_version_upgrade: Option<bool>,
+    cache_cluster_id: Option<String>,
+    cache_node_type: Option<String>,
+    cache_parameter_group_name: Option<String>,
+    cache_security_group_names: Option<Vec<String>>,
+    cache_subnet_group_name: Option<String>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    notification_topic_arn: Option<String>,
+    num_cache_nodes: Option<i64>,
+    port: Option<i64>,
+    preferred_availability_zone: Option<String>,
+    preferred_availability_zones: Option<Vec<String>>,
+    preferred_maintenance_window: Option<String>,
+    replication_group_id: Option<String>,
+    security_group_ids: Option<Vec<String>>,
+    snapshot_arns: Option<Vec<String>>,
+    snapshot_name: Option<String>,
+    snapshot_retention_limit: Option<i64>,
+    snapshot_window: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateCacheClusterMessageBuilder {
+    /// Sets the [`az_mode`](struct.CreateCacheClusterMessage.html#structfield.az_mode) member.
+    pub fn az_mode<T: Into<AZMode>>(mut self, value: T) -> Self {
+        self.az_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auth_token`](struct.CreateCacheClusterMessage.html#structfield.auth_token) member.
+    pub fn auth_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.auth_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_minor_version_upgrade`](struct.CreateCacheClusterMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_cluster_id`](struct.CreateCacheClusterMessage.html#structfield.cache_cluster_id) member.
+    pub fn cache_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_node_type`](struct.CreateCacheClusterMessage.html#structfield.cache_node_type) member.
+    pub fn cache_node_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_node_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_parameter_group_name`](struct.CreateCacheClusterMessage.html#structfield.cache_parameter_group_name) member.
+    pub fn cache_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_security_group_names`](struct.CreateCacheClusterMessage.html#structfield.cache_security_group_names) member.
+    pub fn cache_security_group_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cache_security_group_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_subnet_group_name`](struct.CreateCacheClusterMessage.html#structfield.cache_subnet_group_name) member.
+    pub fn cache_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.CreateCacheClusterMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.CreateCacheClusterMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_topic_arn`](struct.CreateCacheClusterMessage.html#structfield.notification_topic_arn) member.
+    pub fn notification_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`num_cache_nodes`](struct.CreateCacheClusterMessage.html#structfield.num_cache_nodes) member.
+    pub fn num_cache_nodes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.num_cache_nodes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.CreateCacheClusterMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_availability_zone`](struct.CreateCacheClusterMessage.html#structfield.preferred_availability_zone) member.
+    pub fn preferred_availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_availability_zones`](struct.CreateCacheClusterMessage.html#structfield.preferred_availability_zones) member.
+    pub fn preferred_availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.preferred_availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateCacheClusterMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_id`](struct.CreateCacheClusterMessage.html#structfield.replication_group_id) member.
+    pub fn replication_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_group_ids`](struct.CreateCacheClusterMessage.html#structfield.security_group_ids) member.
+    pub fn security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_arns`](struct.CreateCacheClusterMessage.html#structfield.snapshot_arns) member.
+    pub fn snapshot_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.snapshot_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_name`](struct.CreateCacheClusterMessage.html#structfield.snapshot_name) member.
+    pub fn snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_retention_limit`](struct.CreateCacheClusterMessage.html#structfield.snapshot_retention_limit) member.
+    pub fn snapshot_retention_limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.snapshot_retention_limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_window`](struct.CreateCacheClusterMessage.html#structfield.snapshot_window) member.
+    pub fn snapshot_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateCacheClusterMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCacheClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCacheClusterMessage, ::rusoto_core::BuildError> {
+        Ok(CreateCacheClusterMessage {
+            az_mode: self.az_mode,
+            auth_token: self.auth_token,
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            cache_cluster_id: self
+                .cache_cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cache_cluster_id"))?,
+            cache_node_type: self.cache_node_type,
+            cache_parameter_group_name: self.cache_parameter_group_name,
+            cache_security_group_names: self.cache_security_group_names,
+            cache_subnet_group_name: self.cache_subnet_group_name,
+            engine: self.engine,
+            engine_version: self.engine_version,
+            notification_topic_arn: self.notification_topic_arn,
+            num_cache_nodes: self.num_cache_nodes,
+            port: self.port,
+            preferred_availability_zone: self.preferred_availability_zone,
+            preferred_availability_zones: self.preferred_availability_zones,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            replication_group_id: self.replication_group_id,
+            security_group_ids: self.security_group_ids,
+            snapshot_arns: self.snapshot_arns,
+            snapshot_name: self.snapshot_name,
+            snapshot_retention_limit: self.snapshot_retention_limit,
+            snapshot_window: self.snapshot_window,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateCacheClusterMessage` contents to a `SignedRequest`.
 struct CreateCacheClusterMessageSerializer;
 impl CreateCacheClusterMessageSerializer {
@@ -1773,10 +2417,10 @@ pub struct CreateCacheClusterResult {
     pub cache_cluster: Option<CacheCluster>,
 }
 
-struct CreateCacheClusterResultDeserializer;
+pub(crate) struct CreateCacheClusterResultDeserializer;
 impl CreateCacheClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateCacheClusterResult, XmlParseError> {
@@ -1809,6 +2453,58 @@ pub struct CreateCacheParameterGroupMessage {
     pub description: String,
 }
 
+impl CreateCacheParameterGroupMessage {
+    /// Returns a builder for `CreateCacheParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateCacheParameterGroupMessageBuilder {
+        CreateCacheParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCacheParameterGroupMessage`](struct.CreateCacheParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateCacheParameterGroupMessageBuilder {
+    cache_parameter_group_family: Option<String>,
+    cache_parameter_group_name: Option<String>,
+    description: Option<String>,
+}
+
+impl CreateCacheParameterGroupMessageBuilder {
+    /// Sets the [`cache_parameter_group_family`](struct.CreateCacheParameterGroupMessage.html#structfield.cache_parameter_group_family) member.
+    pub fn cache_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_parameter_group_name`](struct.CreateCacheParameterGroupMessage.html#structfield.cache_parameter_group_name) member.
+    pub fn cache_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateCacheParameterGroupMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCacheParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCacheParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateCacheParameterGroupMessage {
+            cache_parameter_group_family: self.cache_parameter_group_family.ok_or(
+                ::rusoto_core::BuildError::missing("cache_parameter_group_family"),
+            )?,
+            cache_parameter_group_name: self.cache_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cache_parameter_group_name"),
+            )?,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+        })
+    }
+}
+
 /// Serialize `CreateCacheParameterGroupMessage` contents to a `SignedRequest`.
 struct CreateCacheParameterGroupMessageSerializer;
 impl CreateCacheParameterGroupMessageSerializer {
@@ -1835,10 +2531,10 @@ pub struct CreateCacheParameterGroupResult {
     pub cache_parameter_group: Option<CacheParameterGroup>,
 }
 
-struct CreateCacheParameterGroupResultDeserializer;
+pub(crate) struct CreateCacheParameterGroupResultDeserializer;
 impl CreateCacheParameterGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateCacheParameterGroupResult, XmlParseError> {
@@ -1870,6 +2566,48 @@ pub struct CreateCacheSecurityGroupMessage {
     pub description: String,
 }
 
+impl CreateCacheSecurityGroupMessage {
+    /// Returns a builder for `CreateCacheSecurityGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateCacheSecurityGroupMessageBuilder {
+        CreateCacheSecurityGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCacheSecurityGroupMessage`](struct.CreateCacheSecurityGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateCacheSecurityGroupMessageBuilder {
+    cache_security_group_name: Option<String>,
+    description: Option<String>,
+}
+
+impl CreateCacheSecurityGroupMessageBuilder {
+    /// Sets the [`cache_security_group_name`](struct.CreateCacheSecurityGroupMessage.html#structfield.cache_security_group_name) member.
+    pub fn cache_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateCacheSecurityGroupMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCacheSecurityGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCacheSecurityGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateCacheSecurityGroupMessage {
+            cache_security_group_name: self.cache_security_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cache_security_group_name"),
+            )?,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+        })
+    }
+}
+
 /// Serialize `CreateCacheSecurityGroupMessage` contents to a `SignedRequest`.
 struct CreateCacheSecurityGroupMessageSerializer;
 impl CreateCacheSecurityGroupMessageSerializer {
@@ -1892,10 +2630,10 @@ pub struct CreateCacheSecurityGroupResult {
     pub cache_security_group: Option<CacheSecurityGroup>,
 }
 
-struct CreateCacheSecurityGroupResultDeserializer;
+pub(crate) struct CreateCacheSecurityGroupResultDeserializer;
 impl CreateCacheSecurityGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateCacheSecurityGroupResult, XmlParseError> {
@@ -1929,6 +2667,58 @@ pub struct CreateCacheSubnetGroupMessage {
     pub subnet_ids: Vec<String>,
 }
 
+impl CreateCacheSubnetGroupMessage {
+    /// Returns a builder for `CreateCacheSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateCacheSubnetGroupMessageBuilder {
+        CreateCacheSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCacheSubnetGroupMessage`](struct.CreateCacheSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateCacheSubnetGroupMessageBuilder {
+    cache_subnet_group_description: Option<String>,
+    cache_subnet_group_name: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+}
+
+impl CreateCacheSubnetGroupMessageBuilder {
+    /// Sets the [`cache_subnet_group_description`](struct.CreateCacheSubnetGroupMessage.html#structfield.cache_subnet_group_description) member.
+    pub fn cache_subnet_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_subnet_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_subnet_group_name`](struct.CreateCacheSubnetGroupMessage.html#structfield.cache_subnet_group_name) member.
+    pub fn cache_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.CreateCacheSubnetGroupMessage.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCacheSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCacheSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateCacheSubnetGroupMessage {
+            cache_subnet_group_description: self.cache_subnet_group_description.ok_or(
+                ::rusoto_core::BuildError::missing("cache_subnet_group_description"),
+            )?,
+            cache_subnet_group_name: self.cache_subnet_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cache_subnet_group_name"),
+            )?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+        })
+    }
+}
+
 /// Serialize `CreateCacheSubnetGroupMessage` contents to a `SignedRequest`.
 struct CreateCacheSubnetGroupMessageSerializer;
 impl CreateCacheSubnetGroupMessageSerializer {
@@ -1959,10 +2749,10 @@ pub struct CreateCacheSubnetGroupResult {
     pub cache_subnet_group: Option<CacheSubnetGroup>,
 }
 
-struct CreateCacheSubnetGroupResultDeserializer;
+pub(crate) struct CreateCacheSubnetGroupResultDeserializer;
 impl CreateCacheSubnetGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateCacheSubnetGroupResult, XmlParseError> {
@@ -2047,8 +2837,269 @@ pub struct CreateReplicationGroupMessage {
     pub transit_encryption_enabled: Option<bool>,
 }
 
-/// Serialize `CreateReplicationGroupMessage` contents to a `SignedRequest`.
-struct CreateReplicationGroupMessageSerializer;
+impl CreateReplicationGroupMessage {
+    /// Returns a builder for `CreateReplicationGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateReplicationGroupMessageBuilder {
+        CreateReplicationGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateReplicationGroupMessage`](struct.CreateReplicationGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateReplicationGroupMessageBuilder {
+    at_rest_encryption_enabled: Option<bool>,
+    auth_token: Option<String>,
+    auto_minor_version_upgrade: Option<bool>,
+    automatic_failover_enabled: Option<bool>,
+    cache_node_type: Option<String>,
+    cache_parameter_group_name: Option<String>,
+    cache_security_group_names: Option<Vec<String>>,
+    cache_subnet_group_name: Option<String>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    kms_key_id: Option<String>,
+    node_group_configuration: Option<Vec<NodeGroupConfiguration>>,
+    notification_topic_arn: Option<String>,
+    num_cache_clusters: Option<i64>,
+    num_node_groups: Option<i64>,
+    port: Option<i64>,
+    preferred_cache_cluster_a_zs: Option<Vec<String>>,
+    preferred_maintenance_window: Option<String>,
+    primary_cluster_id: Option<String>,
+    replicas_per_node_group: Option<i64>,
+    replication_group_description: Option<String>,
+    replication_group_id: Option<String>,
+    security_group_ids: Option<Vec<String>>,
+    snapshot_arns: Option<Vec<String>>,
+    snapshot_name: Option<String>,
+    snapshot_retention_limit: Option<i64>,
+    snapshot_window: Option<String>,
+    tags: Option<Vec<Tag>>,
+    transit_encryption_enabled: Option<bool>,
+}
+
+impl CreateReplicationGroupMessageBuilder {
+    /// Sets the [`at_rest_encryption_enabled`](struct.CreateReplicationGroupMessage.html#structfield.at_rest_encryption_enabled) member.
+    pub fn at_rest_encryption_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.at_rest_encryption_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auth_token`](struct.CreateReplicationGroupMessage.html#structfield.auth_token) member.
+    pub fn auth_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.auth_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_minor_version_upgrade`](struct.CreateReplicationGroupMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`automatic_failover_enabled`](struct.CreateReplicationGroupMessage.html#structfield.automatic_failover_enabled) member.
+    pub fn automatic_failover_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.automatic_failover_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_node_type`](struct.CreateReplicationGroupMessage.html#structfield.cache_node_type) member.
+    pub fn cache_node_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_node_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_parameter_group_name`](struct.CreateReplicationGroupMessage.html#structfield.cache_parameter_group_name) member.
+    pub fn cache_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_security_group_names`](struct.CreateReplicationGroupMessage.html#structfield.cache_security_group_names) member.
+    pub fn cache_security_group_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cache_security_group_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_subnet_group_name`](struct.CreateReplicationGroupMessage.html#structfield.cache_subnet_group_name) member.
+    pub fn cache_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.CreateReplicationGroupMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.CreateReplicationGroupMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateReplicationGroupMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_group_configuration`](struct.CreateReplicationGroupMessage.html#structfield.node_group_configuration) member.
+    pub fn node_group_configuration<T: Into<Vec<NodeGroupConfiguration>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.node_group_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_topic_arn`](struct.CreateReplicationGroupMessage.html#structfield.notification_topic_arn) member.
+    pub fn notification_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`num_cache_clusters`](struct.CreateReplicationGroupMessage.html#structfield.num_cache_clusters) member.
+    pub fn num_cache_clusters<T: Into<i64>>(mut self, value: T) -> Self {
+        self.num_cache_clusters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`num_node_groups`](struct.CreateReplicationGroupMessage.html#structfield.num_node_groups) member.
+    pub fn num_node_groups<T: Into<i64>>(mut self, value: T) -> Self {
+        self.num_node_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.CreateReplicationGroupMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_cache_cluster_a_zs`](struct.CreateReplicationGroupMessage.html#structfield.preferred_cache_cluster_a_zs) member.
+    pub fn preferred_cache_cluster_a_zs<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.preferred_cache_cluster_a_zs = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateReplicationGroupMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`primary_cluster_id`](struct.CreateReplicationGroupMessage.html#structfield.primary_cluster_id) member.
+    pub fn primary_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.primary_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replicas_per_node_group`](struct.CreateReplicationGroupMessage.html#structfield.replicas_per_node_group) member.
+    pub fn replicas_per_node_group<T: Into<i64>>(mut self, value: T) -> Self {
+        self.replicas_per_node_group = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_description`](struct.CreateReplicationGroupMessage.html#structfield.replication_group_description) member.
+    pub fn replication_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_id`](struct.CreateReplicationGroupMessage.html#structfield.replication_group_id) member.
+    pub fn replication_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_group_ids`](struct.CreateReplicationGroupMessage.html#structfield.security_group_ids) member.
+    pub fn security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_arns`](struct.CreateReplicationGroupMessage.html#structfield.snapshot_arns) member.
+    pub fn snapshot_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.snapshot_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_name`](struct.CreateReplicationGroupMessage.html#structfield.snapshot_name) member.
+    pub fn snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_retention_limit`](struct.CreateReplicationGroupMessage.html#structfield.snapshot_retention_limit) member.
+    pub fn snapshot_retention_limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.snapshot_retention_limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_window`](struct.CreateReplicationGroupMessage.html#structfield.snapshot_window) member.
+    pub fn snapshot_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateReplicationGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transit_encryption_enabled`](struct.CreateReplicationGroupMessage.html#structfield.transit_encryption_enabled) member.
+    pub fn transit_encryption_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.transit_encryption_enabled = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateReplicationGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateReplicationGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateReplicationGroupMessage {
+            at_rest_encryption_enabled: self.at_rest_encryption_enabled,
+            auth_token: self.auth_token,
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            automatic_failover_enabled: self.automatic_failover_enabled,
+            cache_node_type: self.cache_node_type,
+            cache_parameter_group_name: self.cache_parameter_group_name,
+            cache_security_group_names: self.cache_security_group_names,
+            cache_subnet_group_name: self.cache_subnet_group_name,
+            engine: self.engine,
+            engine_version: self.engine_version,
+            kms_key_id: self.kms_key_id,
+            node_group_configuration: self.node_group_configuration,
+            notification_topic_arn: self.notification_topic_arn,
+            num_cache_clusters: self.num_cache_clusters,
+            num_node_groups: self.num_node_groups,
+            port: self.port,
+            preferred_cache_cluster_a_zs: self.preferred_cache_cluster_a_zs,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            primary_cluster_id: self.primary_cluster_id,
+            replicas_per_node_group: self.replicas_per_node_group,
+            replication_group_description: self.replication_group_description.ok_or(
+                ::rusoto_core::BuildError::missing("replication_group_description"),
+            )?,
+            replication_group_id: self
+                .replication_group_id
+                .ok_or(::rusoto_core::BuildError::missing("replication_group_id"))?,
+            security_group_ids: self.security_group_ids,
+            snapshot_arns: self.snapshot_arns,
+            snapshot_name: self.snapshot_name,
+            snapshot_retention_limit: self.snapshot_retention_limit,
+            snapshot_window: self.snapshot_window,
+            tags: self.tags,
+            transit_encryption_enabled: self.transit_encryption_enabled,
+        })
+    }
+}
+
+/// Serialize `CreateReplicationGroupMessage` contents to a `SignedRequest`.
+struct CreateReplicationGroupMessageSerializer;
 impl CreateReplicationGroupMessageSerializer {
     fn serialize(params: &mut Params, name: &str, obj: &CreateReplicationGroupMessage) {
         let mut prefix = name.to_string();
@@ -2203,10 +3254,10 @@ pub struct CreateReplicationGroupResult {
     pub replication_group: Option<ReplicationGroup>,
 }
 
-struct CreateReplicationGroupResultDeserializer;
+pub(crate) struct CreateReplicationGroupResultDeserializer;
 impl CreateReplicationGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateReplicationGroupResult, XmlParseError> {
@@ -2241,6 +3292,60 @@ pub struct CreateSnapshotMessage {
     pub snapshot_name: String,
 }
 
+impl CreateSnapshotMessage {
+    /// Returns a builder for `CreateSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> CreateSnapshotMessageBuilder {
+        CreateSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSnapshotMessage`](struct.CreateSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateSnapshotMessageBuilder {
+    cache_cluster_id: Option<String>,
+    kms_key_id: Option<String>,
+    replication_group_id: Option<String>,
+    snapshot_name: Option<String>,
+}
+
+impl CreateSnapshotMessageBuilder {
+    /// Sets the [`cache_cluster_id`](struct.CreateSnapshotMessage.html#structfield.cache_cluster_id) member.
+    pub fn cache_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateSnapshotMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_id`](struct.CreateSnapshotMessage.html#structfield.replication_group_id) member.
+    pub fn replication_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_name`](struct.CreateSnapshotMessage.html#structfield.snapshot_name) member.
+    pub fn snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(CreateSnapshotMessage {
+            cache_cluster_id: self.cache_cluster_id,
+            kms_key_id: self.kms_key_id,
+            replication_group_id: self.replication_group_id,
+            snapshot_name: self
+                .snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateSnapshotMessage` contents to a `SignedRequest`.
 struct CreateSnapshotMessageSerializer;
 impl CreateSnapshotMessageSerializer {
@@ -2268,10 +3373,10 @@ pub struct CreateSnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
 
-struct CreateSnapshotResultDeserializer;
+pub(crate) struct CreateSnapshotResultDeserializer;
 impl CreateSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateSnapshotResult, XmlParseError> {
@@ -2300,6 +3405,72 @@ pub struct DecreaseReplicaCountMessage {
     pub replication_group_id: String,
 }
 
+impl DecreaseReplicaCountMessage {
+    /// Returns a builder for `DecreaseReplicaCountMessage`, checking its required members when built.
+    pub fn builder() -> DecreaseReplicaCountMessageBuilder {
+        DecreaseReplicaCountMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DecreaseReplicaCountMessage`](struct.DecreaseReplicaCountMessage.html).
+#[derive(Default, Debug)]
+pub struct DecreaseReplicaCountMessageBuilder {
+    apply_immediately: Option<bool>,
+    new_replica_count: Option<i64>,
+    replica_configuration: Option<Vec<ConfigureShard>>,
+    replicas_to_remove: Option<Vec<String>>,
+    replication_group_id: Option<String>,
+}
+
+impl DecreaseReplicaCountMessageBuilder {
+    /// Sets the [`apply_immediately`](struct.DecreaseReplicaCountMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_replica_count`](struct.DecreaseReplicaCountMessage.html#structfield.new_replica_count) member.
+    pub fn new_replica_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.new_replica_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replica_configuration`](struct.DecreaseReplicaCountMessage.html#structfield.replica_configuration) member.
+    pub fn replica_configuration<T: Into<Vec<ConfigureShard>>>(mut self, value: T) -> Self {
+        self.replica_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replicas_to_remove`](struct.DecreaseReplicaCountMessage.html#structfield.replicas_to_remove) member.
+    pub fn replicas_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.replicas_to_remove = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_id`](struct.DecreaseReplicaCountMessage.html#structfield.replication_group_id) member.
+    pub fn replication_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DecreaseReplicaCountMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DecreaseReplicaCountMessage, ::rusoto_core::BuildError> {
+        Ok(DecreaseReplicaCountMessage {
+            apply_immediately: self
+                .apply_immediately
+                .ok_or(::rusoto_core::BuildError::missing("apply_immediately"))?,
+            new_replica_count: self.new_replica_count,
+            replica_configuration: self.replica_configuration,
+            replicas_to_remove: self.replicas_to_remove,
+            replication_group_id: self
+                .replication_group_id
+                .ok_or(::rusoto_core::BuildError::missing("replication_group_id"))?,
+        })
+    }
+}
+
 /// Serialize `DecreaseReplicaCountMessage` contents to a `SignedRequest`.
 struct DecreaseReplicaCountMessageSerializer;
 impl DecreaseReplicaCountMessageSerializer {
@@ -2342,10 +3513,10 @@ pub struct DecreaseReplicaCountResult {
     pub replication_group: Option<ReplicationGroup>,
 }
 
-struct DecreaseReplicaCountResultDeserializer;
+pub(crate) struct DecreaseReplicaCountResultDeserializer;
 impl DecreaseReplicaCountResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DecreaseReplicaCountResult, XmlParseError> {
@@ -2376,6 +3547,46 @@ pub struct DeleteCacheClusterMessage {
     pub final_snapshot_identifier: Option<String>,
 }
 
+impl DeleteCacheClusterMessage {
+    /// Returns a builder for `DeleteCacheClusterMessage`, checking its required members when built.
+    pub fn builder() -> DeleteCacheClusterMessageBuilder {
+        DeleteCacheClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCacheClusterMessage`](struct.DeleteCacheClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteCacheClusterMessageBuilder {
+    cache_cluster_id: Option<String>,
+    final_snapshot_identifier: Option<String>,
+}
+
+impl DeleteCacheClusterMessageBuilder {
+    /// Sets the [`cache_cluster_id`](struct.DeleteCacheClusterMessage.html#structfield.cache_cluster_id) member.
+    pub fn cache_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`final_snapshot_identifier`](struct.DeleteCacheClusterMessage.html#structfield.final_snapshot_identifier) member.
+    pub fn final_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.final_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCacheClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCacheClusterMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteCacheClusterMessage {
+            cache_cluster_id: self
+                .cache_cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cache_cluster_id"))?,
+            final_snapshot_identifier: self.final_snapshot_identifier,
+        })
+    }
+}
+
 /// Serialize `DeleteCacheClusterMessage` contents to a `SignedRequest`.
 struct DeleteCacheClusterMessageSerializer;
 impl DeleteCacheClusterMessageSerializer {
@@ -2403,10 +3614,10 @@ pub struct DeleteCacheClusterResult {
     pub cache_cluster: Option<CacheCluster>,
 }
 
-struct DeleteCacheClusterResultDeserializer;
+pub(crate) struct DeleteCacheClusterResultDeserializer;
 impl DeleteCacheClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteCacheClusterResult, XmlParseError> {
@@ -2435,6 +3646,38 @@ pub struct DeleteCacheParameterGroupMessage {
     pub cache_parameter_group_name: String,
 }
 
+impl DeleteCacheParameterGroupMessage {
+    /// Returns a builder for `DeleteCacheParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteCacheParameterGroupMessageBuilder {
+        DeleteCacheParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCacheParameterGroupMessage`](struct.DeleteCacheParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteCacheParameterGroupMessageBuilder {
+    cache_parameter_group_name: Option<String>,
+}
+
+impl DeleteCacheParameterGroupMessageBuilder {
+    /// Sets the [`cache_parameter_group_name`](struct.DeleteCacheParameterGroupMessage.html#structfield.cache_parameter_group_name) member.
+    pub fn cache_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCacheParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCacheParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteCacheParameterGroupMessage {
+            cache_parameter_group_name: self.cache_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cache_parameter_group_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteCacheParameterGroupMessage` contents to a `SignedRequest`.
 struct DeleteCacheParameterGroupMessageSerializer;
 impl DeleteCacheParameterGroupMessageSerializer {
@@ -2458,6 +3701,38 @@ pub struct DeleteCacheSecurityGroupMessage {
     pub cache_security_group_name: String,
 }
 
+impl DeleteCacheSecurityGroupMessage {
+    /// Returns a builder for `DeleteCacheSecurityGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteCacheSecurityGroupMessageBuilder {
+        DeleteCacheSecurityGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCacheSecurityGroupMessage`](struct.DeleteCacheSecurityGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteCacheSecurityGroupMessageBuilder {
+    cache_security_group_name: Option<String>,
+}
+
+impl DeleteCacheSecurityGroupMessageBuilder {
+    /// Sets the [`cache_security_group_name`](struct.DeleteCacheSecurityGroupMessage.html#structfield.cache_security_group_name) member.
+    pub fn cache_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCacheSecurityGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCacheSecurityGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteCacheSecurityGroupMessage {
+            cache_security_group_name: self.cache_security_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cache_security_group_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteCacheSecurityGroupMessage` contents to a `SignedRequest`.
 struct DeleteCacheSecurityGroupMessageSerializer;
 impl DeleteCacheSecurityGroupMessageSerializer {
@@ -2481,6 +3756,38 @@ pub struct DeleteCacheSubnetGroupMessage {
     pub cache_subnet_group_name: String,
 }
 
+impl DeleteCacheSubnetGroupMessage {
+    /// Returns a builder for `DeleteCacheSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteCacheSubnetGroupMessageBuilder {
+        DeleteCacheSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCacheSubnetGroupMessage`](struct.DeleteCacheSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteCacheSubnetGroupMessageBuilder {
+    cache_subnet_group_name: Option<String>,
+}
+
+impl DeleteCacheSubnetGroupMessageBuilder {
+    /// Sets the [`cache_subnet_group_name`](struct.DeleteCacheSubnetGroupMessage.html#structfield.cache_subnet_group_name) member.
+    pub fn cache_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCacheSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCacheSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteCacheSubnetGroupMessage {
+            cache_subnet_group_name: self.cache_subnet_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cache_subnet_group_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteCacheSubnetGroupMessage` contents to a `SignedRequest`.
 struct DeleteCacheSubnetGroupMessageSerializer;
 impl DeleteCacheSubnetGroupMessageSerializer {
@@ -2508,6 +3815,54 @@ pub struct DeleteReplicationGroupMessage {
     pub retain_primary_cluster: Option<bool>,
 }
 
+impl DeleteReplicationGroupMessage {
+    /// Returns a builder for `DeleteReplicationGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteReplicationGroupMessageBuilder {
+        DeleteReplicationGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteReplicationGroupMessage`](struct.DeleteReplicationGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteReplicationGroupMessageBuilder {
+    final_snapshot_identifier: Option<String>,
+    replication_group_id: Option<String>,
+    retain_primary_cluster: Option<bool>,
+}
+
+impl DeleteReplicationGroupMessageBuilder {
+    /// Sets the [`final_snapshot_identifier`](struct.DeleteReplicationGroupMessage.html#structfield.final_snapshot_identifier) member.
+    pub fn final_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.final_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_id`](struct.DeleteReplicationGroupMessage.html#structfield.replication_group_id) member.
+    pub fn replication_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retain_primary_cluster`](struct.DeleteReplicationGroupMessage.html#structfield.retain_primary_cluster) member.
+    pub fn retain_primary_cluster<T: Into<bool>>(mut self, value: T) -> Self {
+        self.retain_primary_cluster = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteReplicationGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteReplicationGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteReplicationGroupMessage {
+            final_snapshot_identifier: self.final_snapshot_identifier,
+            replication_group_id: self
+                .replication_group_id
+                .ok_or(::rusoto_core::BuildError::missing("replication_group_id"))?,
+            retain_primary_cluster: self.retain_primary_cluster,
+        })
+    }
+}
+
 /// Serialize `DeleteReplicationGroupMessage` contents to a `SignedRequest`.
 struct DeleteReplicationGroupMessageSerializer;
 impl DeleteReplicationGroupMessageSerializer {
@@ -2541,10 +3896,10 @@ pub struct DeleteReplicationGroupResult {
     pub replication_group: Option<ReplicationGroup>,
 }
 
-struct DeleteReplicationGroupResultDeserializer;
+pub(crate) struct DeleteReplicationGroupResultDeserializer;
 impl DeleteReplicationGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteReplicationGroupResult, XmlParseError> {
@@ -2573,6 +3928,36 @@ pub struct DeleteSnapshotMessage {
     pub snapshot_name: String,
 }
 
+impl DeleteSnapshotMessage {
+    /// Returns a builder for `DeleteSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> DeleteSnapshotMessageBuilder {
+        DeleteSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSnapshotMessage`](struct.DeleteSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteSnapshotMessageBuilder {
+    snapshot_name: Option<String>,
+}
+
+impl DeleteSnapshotMessageBuilder {
+    /// Sets the [`snapshot_name`](struct.DeleteSnapshotMessage.html#structfield.snapshot_name) member.
+    pub fn snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteSnapshotMessage {
+            snapshot_name: self
+                .snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteSnapshotMessage` contents to a `SignedRequest`.
 struct DeleteSnapshotMessageSerializer;
 impl DeleteSnapshotMessageSerializer {
@@ -2591,10 +3976,10 @@ pub struct DeleteSnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
 
-struct DeleteSnapshotResultDeserializer;
+pub(crate) struct DeleteSnapshotResultDeserializer;
 impl DeleteSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteSnapshotResult, XmlParseError> {
@@ -2624,6 +4009,72 @@ pub struct DescribeCacheClustersMessage {
     pub show_cache_node_info: Option<bool>,
 }
 
+impl DescribeCacheClustersMessage {
+    /// Returns a builder for `DescribeCacheClustersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeCacheClustersMessageBuilder {
+        DescribeCacheClustersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCacheClustersMessage`](struct.DescribeCacheClustersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeCacheClustersMessageBuilder {
+    cache_cluster_id: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    show_cache_clusters_not_in_replication_groups: Option<bool>,
+    show_cache_node_info: Option<bool>,
+}
+
+impl DescribeCacheClustersMessageBuilder {
+    /// Sets the [`cache_cluster_id`](struct.DescribeCacheClustersMessage.html#structfield.cache_cluster_id) member.
+    pub fn cache_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeCacheClustersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeCacheClustersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`show_cache_clusters_not_in_replication_groups`](struct.DescribeCacheClustersMessage.html#structfield.show_cache_clusters_not_in_replication_groups) member.
+    pub fn show_cache_clusters_not_in_replication_groups<T: Into<bool>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.show_cache_clusters_not_in_replication_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`show_cache_node_info`](struct.DescribeCacheClustersMessage.html#structfield.show_cache_node_info) member.
+    pub fn show_cache_node_info<T: Into<bool>>(mut self, value: T) -> Self {
+        self.show_cache_node_info = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCacheClustersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCacheClustersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeCacheClustersMessage {
+            cache_cluster_id: self.cache_cluster_id,
+            marker: self.marker,
+            max_records: self.max_records,
+            show_cache_clusters_not_in_replication_groups: self
+                .show_cache_clusters_not_in_replication_groups,
+            show_cache_node_info: self.show_cache_node_info,
+        })
+    }
+}
+
 /// Serialize `DescribeCacheClustersMessage` contents to a `SignedRequest`.
 struct DescribeCacheClustersMessageSerializer;
 impl DescribeCacheClustersMessageSerializer {
@@ -2671,6 +4122,76 @@ pub struct DescribeCacheEngineVersionsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeCacheEngineVersionsMessage {
+    /// Returns a builder for `DescribeCacheEngineVersionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeCacheEngineVersionsMessageBuilder {
+        DescribeCacheEngineVersionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCacheEngineVersionsMessage`](struct.DescribeCacheEngineVersionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeCacheEngineVersionsMessageBuilder {
+    cache_parameter_group_family: Option<String>,
+    default_only: Option<bool>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeCacheEngineVersionsMessageBuilder {
+    /// Sets the [`cache_parameter_group_family`](struct.DescribeCacheEngineVersionsMessage.html#structfield.cache_parameter_group_family) member.
+    pub fn cache_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_only`](struct.DescribeCacheEngineVersionsMessage.html#structfield.default_only) member.
+    pub fn default_only<T: Into<bool>>(mut self, value: T) -> Self {
+        self.default_only = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.DescribeCacheEngineVersionsMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.DescribeCacheEngineVersionsMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeCacheEngineVersionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeCacheEngineVersionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCacheEngineVersionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCacheEngineVersionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeCacheEngineVersionsMessage {
+            cache_parameter_group_family: self.cache_parameter_group_family,
+            default_only: self.default_only,
+            engine: self.engine,
+            engine_version: self.engine_version,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeCacheEngineVersionsMessage` contents to a `SignedRequest`.
 struct DescribeCacheEngineVersionsMessageSerializer;
 impl DescribeCacheEngineVersionsMessageSerializer {
@@ -2715,6 +4236,52 @@ pub struct DescribeCacheParameterGroupsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeCacheParameterGroupsMessage {
+    /// Returns a builder for `DescribeCacheParameterGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeCacheParameterGroupsMessageBuilder {
+        DescribeCacheParameterGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCacheParameterGroupsMessage`](struct.DescribeCacheParameterGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeCacheParameterGroupsMessageBuilder {
+    cache_parameter_group_name: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeCacheParameterGroupsMessageBuilder {
+    /// Sets the [`cache_parameter_group_name`](struct.DescribeCacheParameterGroupsMessage.html#structfield.cache_parameter_group_name) member.
+    pub fn cache_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeCacheParameterGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeCacheParameterGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCacheParameterGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCacheParameterGroupsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeCacheParameterGroupsMessage {
+            cache_parameter_group_name: self.cache_parameter_group_name,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeCacheParameterGroupsMessage` contents to a `SignedRequest`.
 struct DescribeCacheParameterGroupsMessageSerializer;
 impl DescribeCacheParameterGroupsMessageSerializer {
@@ -2752,6 +4319,62 @@ pub struct DescribeCacheParametersMessage {
     pub source: Option<String>,
 }
 
+impl DescribeCacheParametersMessage {
+    /// Returns a builder for `DescribeCacheParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeCacheParametersMessageBuilder {
+        DescribeCacheParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCacheParametersMessage`](struct.DescribeCacheParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeCacheParametersMessageBuilder {
+    cache_parameter_group_name: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    source: Option<String>,
+}
+
+impl DescribeCacheParametersMessageBuilder {
+    /// Sets the [`cache_parameter_group_name`](struct.DescribeCacheParametersMessage.html#structfield.cache_parameter_group_name) member.
+    pub fn cache_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeCacheParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeCacheParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.DescribeCacheParametersMessage.html#structfield.source) member.
+    pub fn source<T: Into<String>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCacheParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCacheParametersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeCacheParametersMessage {
+            cache_parameter_group_name: self.cache_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cache_parameter_group_name"),
+            )?,
+            marker: self.marker,
+            max_records: self.max_records,
+            source: self.source,
+        })
+    }
+}
+
 /// Serialize `DescribeCacheParametersMessage` contents to a `SignedRequest`.
 struct DescribeCacheParametersMessageSerializer;
 impl DescribeCacheParametersMessageSerializer {
@@ -2788,14 +4411,60 @@ pub struct DescribeCacheSecurityGroupsMessage {
     pub max_records: Option<i64>,
 }
 
-/// Serialize `DescribeCacheSecurityGroupsMessage` contents to a `SignedRequest`.
-struct DescribeCacheSecurityGroupsMessageSerializer;
-impl DescribeCacheSecurityGroupsMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &DescribeCacheSecurityGroupsMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl DescribeCacheSecurityGroupsMessage {
+    /// Returns a builder for `DescribeCacheSecurityGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeCacheSecurityGroupsMessageBuilder {
+        DescribeCacheSecurityGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCacheSecurityGroupsMessage`](struct.DescribeCacheSecurityGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeCacheSecurityGroupsMessageBuilder {
+    cache_security_group_name: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeCacheSecurityGroupsMessageBuilder {
+    /// Sets the [`cache_security_group_name`](struct.DescribeCacheSecurityGroupsMessage.html#structfield.cache_security_group_name) member.
+    pub fn cache_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeCacheSecurityGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeCacheSecurityGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCacheSecurityGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCacheSecurityGroupsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeCacheSecurityGroupsMessage {
+            cache_security_group_name: self.cache_security_group_name,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
+/// Serialize `DescribeCacheSecurityGroupsMessage` contents to a `SignedRequest`.
+struct DescribeCacheSecurityGroupsMessageSerializer;
+impl DescribeCacheSecurityGroupsMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &DescribeCacheSecurityGroupsMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
 
         if let Some(ref field_value) = obj.cache_security_group_name {
             params.put(
@@ -2823,6 +4492,52 @@ pub struct DescribeCacheSubnetGroupsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeCacheSubnetGroupsMessage {
+    /// Returns a builder for `DescribeCacheSubnetGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeCacheSubnetGroupsMessageBuilder {
+        DescribeCacheSubnetGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCacheSubnetGroupsMessage`](struct.DescribeCacheSubnetGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeCacheSubnetGroupsMessageBuilder {
+    cache_subnet_group_name: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeCacheSubnetGroupsMessageBuilder {
+    /// Sets the [`cache_subnet_group_name`](struct.DescribeCacheSubnetGroupsMessage.html#structfield.cache_subnet_group_name) member.
+    pub fn cache_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeCacheSubnetGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeCacheSubnetGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCacheSubnetGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCacheSubnetGroupsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeCacheSubnetGroupsMessage {
+            cache_subnet_group_name: self.cache_subnet_group_name,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeCacheSubnetGroupsMessage` contents to a `SignedRequest`.
 struct DescribeCacheSubnetGroupsMessageSerializer;
 impl DescribeCacheSubnetGroupsMessageSerializer {
@@ -2858,6 +4573,55 @@ pub struct DescribeEngineDefaultParametersMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeEngineDefaultParametersMessage {
+    /// Returns a builder for `DescribeEngineDefaultParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEngineDefaultParametersMessageBuilder {
+        DescribeEngineDefaultParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEngineDefaultParametersMessage`](struct.DescribeEngineDefaultParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEngineDefaultParametersMessageBuilder {
+    cache_parameter_group_family: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeEngineDefaultParametersMessageBuilder {
+    /// Sets the [`cache_parameter_group_family`](struct.DescribeEngineDefaultParametersMessage.html#structfield.cache_parameter_group_family) member.
+    pub fn cache_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEngineDefaultParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEngineDefaultParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEngineDefaultParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEngineDefaultParametersMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeEngineDefaultParametersMessage {
+            cache_parameter_group_family: self.cache_parameter_group_family.ok_or(
+                ::rusoto_core::BuildError::missing("cache_parameter_group_family"),
+            )?,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeEngineDefaultParametersMessage` contents to a `SignedRequest`.
 struct DescribeEngineDefaultParametersMessageSerializer;
 impl DescribeEngineDefaultParametersMessageSerializer {
@@ -2885,10 +4649,10 @@ pub struct DescribeEngineDefaultParametersResult {
     pub engine_defaults: Option<EngineDefaults>,
 }
 
-struct DescribeEngineDefaultParametersResultDeserializer;
+pub(crate) struct DescribeEngineDefaultParametersResultDeserializer;
 impl DescribeEngineDefaultParametersResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeEngineDefaultParametersResult, XmlParseError> {
@@ -2916,7 +4680,7 @@ pub struct DescribeEventsMessage {
     /// <p>The number of minutes worth of events to retrieve.</p>
     pub duration: Option<i64>,
     /// <p>The end of the time interval for which to retrieve events, specified in ISO 8601 format.</p> <p> <b>Example:</b> 2017-03-30T07:03:49.555Z</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>An optional marker returned from a prior request. Use this marker for pagination of results from this operation. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
     pub marker: Option<String>,
     /// <p>The maximum number of records to include in the response. If more records exist than the specified <code>MaxRecords</code> value, a marker is included in the response so that the remaining results can be retrieved.</p> <p>Default: 100</p> <p>Constraints: minimum 20; maximum 100.</p>
@@ -2924,9 +4688,85 @@ pub struct DescribeEventsMessage {
     /// <p>The identifier of the event source for which events are returned. If not specified, all sources are included in the response.</p>
     pub source_identifier: Option<String>,
     /// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p>The beginning of the time interval to retrieve events for, specified in ISO 8601 format.</p> <p> <b>Example:</b> 2017-03-30T07:03:49.555Z</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
+}
+
+impl DescribeEventsMessage {
+    /// Returns a builder for `DescribeEventsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventsMessageBuilder {
+        DescribeEventsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventsMessage`](struct.DescribeEventsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventsMessageBuilder {
+    duration: Option<i64>,
+    end_time: Option<IsoTimestamp>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    source_identifier: Option<String>,
+    source_type: Option<SourceType>,
+    start_time: Option<IsoTimestamp>,
+}
+
+impl DescribeEventsMessageBuilder {
+    /// Sets the [`duration`](struct.DescribeEventsMessage.html#structfield.duration) member.
+    pub fn duration<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.DescribeEventsMessage.html#structfield.end_time) member.
+    pub fn end_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEventsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEventsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_identifier`](struct.DescribeEventsMessage.html#structfield.source_identifier) member.
+    pub fn source_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.DescribeEventsMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<SourceType>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.DescribeEventsMessage.html#structfield.start_time) member.
+    pub fn start_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventsMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeEventsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventsMessage {
+            duration: self.duration,
+            end_time: self.end_time,
+            marker: self.marker,
+            max_records: self.max_records,
+            source_identifier: self.source_identifier,
+            source_type: self.source_type,
+            start_time: self.start_time,
+        })
+    }
 }
 
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
@@ -2973,6 +4813,52 @@ pub struct DescribeReplicationGroupsMessage {
     pub replication_group_id: Option<String>,
 }
 
+impl DescribeReplicationGroupsMessage {
+    /// Returns a builder for `DescribeReplicationGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeReplicationGroupsMessageBuilder {
+        DescribeReplicationGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReplicationGroupsMessage`](struct.DescribeReplicationGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeReplicationGroupsMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    replication_group_id: Option<String>,
+}
+
+impl DescribeReplicationGroupsMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeReplicationGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeReplicationGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_id`](struct.DescribeReplicationGroupsMessage.html#structfield.replication_group_id) member.
+    pub fn replication_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReplicationGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReplicationGroupsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeReplicationGroupsMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            replication_group_id: self.replication_group_id,
+        })
+    }
+}
+
 /// Serialize `DescribeReplicationGroupsMessage` contents to a `SignedRequest`.
 struct DescribeReplicationGroupsMessageSerializer;
 impl DescribeReplicationGroupsMessageSerializer {
@@ -3015,6 +4901,92 @@ pub struct DescribeReservedCacheNodesMessage {
     pub reserved_cache_nodes_offering_id: Option<String>,
 }
 
+impl DescribeReservedCacheNodesMessage {
+    /// Returns a builder for `DescribeReservedCacheNodesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeReservedCacheNodesMessageBuilder {
+        DescribeReservedCacheNodesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReservedCacheNodesMessage`](struct.DescribeReservedCacheNodesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeReservedCacheNodesMessageBuilder {
+    cache_node_type: Option<String>,
+    duration: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    offering_type: Option<String>,
+    product_description: Option<String>,
+    reserved_cache_node_id: Option<String>,
+    reserved_cache_nodes_offering_id: Option<String>,
+}
+
+impl DescribeReservedCacheNodesMessageBuilder {
+    /// Sets the [`cache_node_type`](struct.DescribeReservedCacheNodesMessage.html#structfield.cache_node_type) member.
+    pub fn cache_node_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_node_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`duration`](struct.DescribeReservedCacheNodesMessage.html#structfield.duration) member.
+    pub fn duration<T: Into<String>>(mut self, value: T) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeReservedCacheNodesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeReservedCacheNodesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`offering_type`](struct.DescribeReservedCacheNodesMessage.html#structfield.offering_type) member.
+    pub fn offering_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.offering_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_description`](struct.DescribeReservedCacheNodesMessage.html#structfield.product_description) member.
+    pub fn product_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_cache_node_id`](struct.DescribeReservedCacheNodesMessage.html#structfield.reserved_cache_node_id) member.
+    pub fn reserved_cache_node_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_cache_node_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_cache_nodes_offering_id`](struct.DescribeReservedCacheNodesMessage.html#structfield.reserved_cache_nodes_offering_id) member.
+    pub fn reserved_cache_nodes_offering_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_cache_nodes_offering_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReservedCacheNodesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReservedCacheNodesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeReservedCacheNodesMessage {
+            cache_node_type: self.cache_node_type,
+            duration: self.duration,
+            marker: self.marker,
+            max_records: self.max_records,
+            offering_type: self.offering_type,
+            product_description: self.product_description,
+            reserved_cache_node_id: self.reserved_cache_node_id,
+            reserved_cache_nodes_offering_id: self.reserved_cache_nodes_offering_id,
+        })
+    }
+}
+
 /// Serialize `DescribeReservedCacheNodesMessage` contents to a `SignedRequest`.
 struct DescribeReservedCacheNodesMessageSerializer;
 impl DescribeReservedCacheNodesMessageSerializer {
@@ -3076,6 +5048,85 @@ pub struct DescribeReservedCacheNodesOfferingsMessage {
     pub reserved_cache_nodes_offering_id: Option<String>,
 }
 
+impl DescribeReservedCacheNodesOfferingsMessage {
+    /// Returns a builder for `DescribeReservedCacheNodesOfferingsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeReservedCacheNodesOfferingsMessageBuilder {
+        DescribeReservedCacheNodesOfferingsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReservedCacheNodesOfferingsMessage`](struct.DescribeReservedCacheNodesOfferingsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeReservedCacheNodesOfferingsMessageBuilder {
+    cache_node_type: Option<String>,
+    duration: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    offering_type: Option<String>,
+    product_description: Option<String>,
+    reserved_cache_nodes_offering_id: Option<String>,
+}
+
+impl DescribeReservedCacheNodesOfferingsMessageBuilder {
+    /// Sets the [`cache_node_type`](struct.DescribeReservedCacheNodesOfferingsMessage.html#structfield.cache_node_type) member.
+    pub fn cache_node_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_node_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`duration`](struct.DescribeReservedCacheNodesOfferingsMessage.html#structfield.duration) member.
+    pub fn duration<T: Into<String>>(mut self, value: T) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeReservedCacheNodesOfferingsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeReservedCacheNodesOfferingsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`offering_type`](struct.DescribeReservedCacheNodesOfferingsMessage.html#structfield.offering_type) member.
+    pub fn offering_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.offering_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_description`](struct.DescribeReservedCacheNodesOfferingsMessage.html#structfield.product_description) member.
+    pub fn product_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_cache_nodes_offering_id`](struct.DescribeReservedCacheNodesOfferingsMessage.html#structfield.reserved_cache_nodes_offering_id) member.
+    pub fn reserved_cache_nodes_offering_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_cache_nodes_offering_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReservedCacheNodesOfferingsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReservedCacheNodesOfferingsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeReservedCacheNodesOfferingsMessage {
+            cache_node_type: self.cache_node_type,
+            duration: self.duration,
+            marker: self.marker,
+            max_records: self.max_records,
+            offering_type: self.offering_type,
+            product_description: self.product_description,
+            reserved_cache_nodes_offering_id: self.reserved_cache_nodes_offering_id,
+        })
+    }
+}
+
 /// Serialize `DescribeReservedCacheNodesOfferingsMessage` contents to a `SignedRequest`.
 struct DescribeReservedCacheNodesOfferingsMessageSerializer;
 impl DescribeReservedCacheNodesOfferingsMessageSerializer {
@@ -3125,7 +5176,61 @@ pub struct DescribeServiceUpdatesMessage {
     /// <p>The unique ID of the service update</p>
     pub service_update_name: Option<String>,
     /// <p>The status of the service update</p>
-    pub service_update_status: Option<Vec<String>>,
+    pub service_update_status: Option<Vec<ServiceUpdateStatus>>,
+}
+
+impl DescribeServiceUpdatesMessage {
+    /// Returns a builder for `DescribeServiceUpdatesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeServiceUpdatesMessageBuilder {
+        DescribeServiceUpdatesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeServiceUpdatesMessage`](struct.DescribeServiceUpdatesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeServiceUpdatesMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    service_update_name: Option<String>,
+    service_update_status: Option<Vec<ServiceUpdateStatus>>,
+}
+
+impl DescribeServiceUpdatesMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeServiceUpdatesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeServiceUpdatesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_update_name`](struct.DescribeServiceUpdatesMessage.html#structfield.service_update_name) member.
+    pub fn service_update_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_update_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_update_status`](struct.DescribeServiceUpdatesMessage.html#structfield.service_update_status) member.
+    pub fn service_update_status<T: Into<Vec<ServiceUpdateStatus>>>(mut self, value: T) -> Self {
+        self.service_update_status = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeServiceUpdatesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeServiceUpdatesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeServiceUpdatesMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            service_update_name: self.service_update_name,
+            service_update_status: self.service_update_status,
+        })
+    }
 }
 
 /// Serialize `DescribeServiceUpdatesMessage` contents to a `SignedRequest`.
@@ -3165,10 +5270,10 @@ pub struct DescribeSnapshotsListMessage {
     pub snapshots: Option<Vec<Snapshot>>,
 }
 
-struct DescribeSnapshotsListMessageDeserializer;
+pub(crate) struct DescribeSnapshotsListMessageDeserializer;
 impl DescribeSnapshotsListMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeSnapshotsListMessage, XmlParseError> {
@@ -3211,6 +5316,84 @@ pub struct DescribeSnapshotsMessage {
     pub snapshot_source: Option<String>,
 }
 
+impl DescribeSnapshotsMessage {
+    /// Returns a builder for `DescribeSnapshotsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeSnapshotsMessageBuilder {
+        DescribeSnapshotsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSnapshotsMessage`](struct.DescribeSnapshotsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeSnapshotsMessageBuilder {
+    cache_cluster_id: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    replication_group_id: Option<String>,
+    show_node_group_config: Option<bool>,
+    snapshot_name: Option<String>,
+    snapshot_source: Option<String>,
+}
+
+impl DescribeSnapshotsMessageBuilder {
+    /// Sets the [`cache_cluster_id`](struct.DescribeSnapshotsMessage.html#structfield.cache_cluster_id) member.
+    pub fn cache_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeSnapshotsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeSnapshotsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_id`](struct.DescribeSnapshotsMessage.html#structfield.replication_group_id) member.
+    pub fn replication_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`show_node_group_config`](struct.DescribeSnapshotsMessage.html#structfield.show_node_group_config) member.
+    pub fn show_node_group_config<T: Into<bool>>(mut self, value: T) -> Self {
+        self.show_node_group_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_name`](struct.DescribeSnapshotsMessage.html#structfield.snapshot_name) member.
+    pub fn snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_source`](struct.DescribeSnapshotsMessage.html#structfield.snapshot_source) member.
+    pub fn snapshot_source<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_source = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSnapshotsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSnapshotsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeSnapshotsMessage {
+            cache_cluster_id: self.cache_cluster_id,
+            marker: self.marker,
+            max_records: self.max_records,
+            replication_group_id: self.replication_group_id,
+            show_node_group_config: self.show_node_group_config,
+            snapshot_name: self.snapshot_name,
+            snapshot_source: self.snapshot_source,
+        })
+    }
+}
+
 /// Serialize `DescribeSnapshotsMessage` contents to a `SignedRequest`.
 struct DescribeSnapshotsMessageSerializer;
 impl DescribeSnapshotsMessageSerializer {
@@ -3258,13 +5441,99 @@ pub struct DescribeUpdateActionsMessage {
     /// <p>The unique ID of the service update</p>
     pub service_update_name: Option<String>,
     /// <p>The status of the service update</p>
-    pub service_update_status: Option<Vec<String>>,
+    pub service_update_status: Option<Vec<ServiceUpdateStatus>>,
     /// <p>The range of time specified to search for service updates that are in available status</p>
     pub service_update_time_range: Option<TimeRangeFilter>,
     /// <p>Dictates whether to include node level update status in the response </p>
     pub show_node_level_update_status: Option<bool>,
     /// <p>The status of the update action.</p>
-    pub update_action_status: Option<Vec<String>>,
+    pub update_action_status: Option<Vec<UpdateActionStatus>>,
+}
+
+impl DescribeUpdateActionsMessage {
+    /// Returns a builder for `DescribeUpdateActionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeUpdateActionsMessageBuilder {
+        DescribeUpdateActionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUpdateActionsMessage`](struct.DescribeUpdateActionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeUpdateActionsMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    replication_group_ids: Option<Vec<String>>,
+    service_update_name: Option<String>,
+    service_update_status: Option<Vec<ServiceUpdateStatus>>,
+    service_update_time_range: Option<TimeRangeFilter>,
+    show_node_level_update_status: Option<bool>,
+    update_action_status: Option<Vec<UpdateActionStatus>>,
+}
+
+impl DescribeUpdateActionsMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeUpdateActionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeUpdateActionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_ids`](struct.DescribeUpdateActionsMessage.html#structfield.replication_group_ids) member.
+    pub fn replication_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.replication_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_update_name`](struct.DescribeUpdateActionsMessage.html#structfield.service_update_name) member.
+    pub fn service_update_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_update_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_update_status`](struct.DescribeUpdateActionsMessage.html#structfield.service_update_status) member.
+    pub fn service_update_status<T: Into<Vec<ServiceUpdateStatus>>>(mut self, value: T) -> Self {
+        self.service_update_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_update_time_range`](struct.DescribeUpdateActionsMessage.html#structfield.service_update_time_range) member.
+    pub fn service_update_time_range<T: Into<TimeRangeFilter>>(mut self, value: T) -> Self {
+        self.service_update_time_range = Some(value.into());
+        self
+    }
+
+    /// Sets the [`show_node_level_update_status`](struct.DescribeUpdateActionsMessage.html#structfield.show_node_level_update_status) member.
+    pub fn show_node_level_update_status<T: Into<bool>>(mut self, value: T) -> Self {
+        self.show_node_level_update_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_action_status`](struct.DescribeUpdateActionsMessage.html#structfield.update_action_status) member.
+    pub fn update_action_status<T: Into<Vec<UpdateActionStatus>>>(mut self, value: T) -> Self {
+        self.update_action_status = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUpdateActionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeUpdateActionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeUpdateActionsMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            replication_group_ids: self.replication_group_ids,
+            service_update_name: self.service_update_name,
+            service_update_status: self.service_update_status,
+            service_update_time_range: self.service_update_time_range,
+            show_node_level_update_status: self.show_node_level_update_status,
+            update_action_status: self.update_action_status,
+        })
+    }
 }
 
 /// Serialize `DescribeUpdateActionsMessage` contents to a `SignedRequest`.
@@ -3322,10 +5591,13 @@ impl DescribeUpdateActionsMessageSerializer {
     }
 }
 
-struct DoubleDeserializer;
+pub(crate) struct DoubleDeserializer;
 impl DoubleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3344,10 +5616,10 @@ pub struct EC2SecurityGroup {
     pub status: Option<String>,
 }
 
-struct EC2SecurityGroupDeserializer;
+pub(crate) struct EC2SecurityGroupDeserializer;
 impl EC2SecurityGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EC2SecurityGroup, XmlParseError> {
@@ -3374,10 +5646,10 @@ impl EC2SecurityGroupDeserializer {
         })
     }
 }
-struct EC2SecurityGroupListDeserializer;
+pub(crate) struct EC2SecurityGroupListDeserializer;
 impl EC2SecurityGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EC2SecurityGroup>, XmlParseError> {
@@ -3403,10 +5675,10 @@ pub struct Endpoint {
     pub port: Option<i64>,
 }
 
-struct EndpointDeserializer;
+pub(crate) struct EndpointDeserializer;
 impl EndpointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Endpoint, XmlParseError> {
@@ -3437,10 +5709,10 @@ pub struct EngineDefaults {
     pub parameters: Option<Vec<Parameter>>,
 }
 
-struct EngineDefaultsDeserializer;
+pub(crate) struct EngineDefaultsDeserializer;
 impl EngineDefaultsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EngineDefaults, XmlParseError> {
@@ -3480,19 +5752,22 @@ impl EngineDefaultsDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Event {
     /// <p>The date and time when the event occurred.</p>
-    pub date: Option<String>,
+    pub date: Option<IsoTimestamp>,
     /// <p>The text of the event.</p>
     pub message: Option<String>,
     /// <p>The identifier for the source of the event. For example, if the event occurred at the cluster level, the identifier would be the name of the cluster.</p>
     pub source_identifier: Option<String>,
     /// <p>Specifies the origin of this event - a cluster, a parameter group, a security group, etc.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
-struct EventDeserializer;
+pub(crate) struct EventDeserializer;
 impl EventDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Event, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Event, XmlParseError> {
         deserialize_elements::<_, Event, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Date" => {
@@ -3515,10 +5790,10 @@ impl EventDeserializer {
         })
     }
 }
-struct EventListDeserializer;
+pub(crate) struct EventListDeserializer;
 impl EventListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Event>, XmlParseError> {
@@ -3541,10 +5816,10 @@ pub struct EventsMessage {
     pub marker: Option<String>,
 }
 
-struct EventsMessageDeserializer;
+pub(crate) struct EventsMessageDeserializer;
 impl EventsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventsMessage, XmlParseError> {
@@ -3576,6 +5851,64 @@ pub struct IncreaseReplicaCountMessage {
     pub replication_group_id: String,
 }
 
+impl IncreaseReplicaCountMessage {
+    /// Returns a builder for `IncreaseReplicaCountMessage`, checking its required members when built.
+    pub fn builder() -> IncreaseReplicaCountMessageBuilder {
+        IncreaseReplicaCountMessageBuilder::default()
+    }
+}
+
+/// Builds a [`IncreaseReplicaCountMessage`](struct.IncreaseReplicaCountMessage.html).
+#[derive(Default, Debug)]
+pub struct IncreaseReplicaCountMessageBuilder {
+    apply_immediately: Option<bool>,
+    new_replica_count: Option<i64>,
+    replica_configuration: Option<Vec<ConfigureShard>>,
+    replication_group_id: Option<String>,
+}
+
+impl IncreaseReplicaCountMessageBuilder {
+    /// Sets the [`apply_immediately`](struct.IncreaseReplicaCountMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_replica_count`](struct.IncreaseReplicaCountMessage.html#structfield.new_replica_count) member.
+    pub fn new_replica_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.new_replica_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replica_configuration`](struct.IncreaseReplicaCountMessage.html#structfield.replica_configuration) member.
+    pub fn replica_configuration<T: Into<Vec<ConfigureShard>>>(mut self, value: T) -> Self {
+        self.replica_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_id`](struct.IncreaseReplicaCountMessage.html#structfield.replication_group_id) member.
+    pub fn replication_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `IncreaseReplicaCountMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<IncreaseReplicaCountMessage, ::rusoto_core::BuildError> {
+        Ok(IncreaseReplicaCountMessage {
+            apply_immediately: self
+                .apply_immediately
+                .ok_or(::rusoto_core::BuildError::missing("apply_immediately"))?,
+            new_replica_count: self.new_replica_count,
+            replica_configuration: self.replica_configuration,
+            replication_group_id: self
+                .replication_group_id
+                .ok_or(::rusoto_core::BuildError::missing("replication_group_id"))?,
+        })
+    }
+}
+
 /// Serialize `IncreaseReplicaCountMessage` contents to a `SignedRequest`.
 struct IncreaseReplicaCountMessageSerializer;
 impl IncreaseReplicaCountMessageSerializer {
@@ -3611,10 +5944,10 @@ pub struct IncreaseReplicaCountResult {
     pub replication_group: Option<ReplicationGroup>,
 }
 
-struct IncreaseReplicaCountResultDeserializer;
+pub(crate) struct IncreaseReplicaCountResultDeserializer;
 impl IncreaseReplicaCountResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<IncreaseReplicaCountResult, XmlParseError> {
@@ -3636,10 +5969,13 @@ impl IncreaseReplicaCountResultDeserializer {
         )
     }
 }
-struct IntegerDeserializer;
+pub(crate) struct IntegerDeserializer;
 impl IntegerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3647,10 +5983,13 @@ impl IntegerDeserializer {
         Ok(obj)
     }
 }
-struct IntegerOptionalDeserializer;
+pub(crate) struct IntegerOptionalDeserializer;
 impl IntegerOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -3679,6 +6018,45 @@ pub struct ListAllowedNodeTypeModificationsMessage {
     pub replication_group_id: Option<String>,
 }
 
+impl ListAllowedNodeTypeModificationsMessage {
+    /// Returns a builder for `ListAllowedNodeTypeModificationsMessage`, checking its required members when built.
+    pub fn builder() -> ListAllowedNodeTypeModificationsMessageBuilder {
+        ListAllowedNodeTypeModificationsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ListAllowedNodeTypeModificationsMessage`](struct.ListAllowedNodeTypeModificationsMessage.html).
+#[derive(Default, Debug)]
+pub struct ListAllowedNodeTypeModificationsMessageBuilder {
+    cache_cluster_id: Option<String>,
+    replication_group_id: Option<String>,
+}
+
+impl ListAllowedNodeTypeModificationsMessageBuilder {
+    /// Sets the [`cache_cluster_id`](struct.ListAllowedNodeTypeModificationsMessage.html#structfield.cache_cluster_id) member.
+    pub fn cache_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_id`](struct.ListAllowedNodeTypeModificationsMessage.html#structfield.replication_group_id) member.
+    pub fn replication_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAllowedNodeTypeModificationsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAllowedNodeTypeModificationsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ListAllowedNodeTypeModificationsMessage {
+            cache_cluster_id: self.cache_cluster_id,
+            replication_group_id: self.replication_group_id,
+        })
+    }
+}
+
 /// Serialize `ListAllowedNodeTypeModificationsMessage` contents to a `SignedRequest`.
 struct ListAllowedNodeTypeModificationsMessageSerializer;
 impl ListAllowedNodeTypeModificationsMessageSerializer {
@@ -3704,6 +6082,38 @@ pub struct ListTagsForResourceMessage {
     pub resource_name: String,
 }
 
+impl ListTagsForResourceMessage {
+    /// Returns a builder for `ListTagsForResourceMessage`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceMessageBuilder {
+        ListTagsForResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceMessage`](struct.ListTagsForResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceMessageBuilder {
+    resource_name: Option<String>,
+}
+
+impl ListTagsForResourceMessageBuilder {
+    /// Sets the [`resource_name`](struct.ListTagsForResourceMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceMessage, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceMessage {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListTagsForResourceMessage` contents to a `SignedRequest`.
 struct ListTagsForResourceMessageSerializer;
 impl ListTagsForResourceMessageSerializer {
@@ -3721,7 +6131,7 @@ impl ListTagsForResourceMessageSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ModifyCacheClusterMessage {
     /// <p><p>Specifies whether the new nodes in this Memcached cluster are all created in a single Availability Zone or created across multiple Availability Zones.</p> <p>Valid values: <code>single-az</code> | <code>cross-az</code>.</p> <p>This option is only supported for Memcached clusters.</p> <note> <p>You cannot specify <code>single-az</code> if the Memcached cluster already has cache nodes in different Availability Zones. If <code>cross-az</code> is specified, existing Memcached nodes remain in their current Availability Zone.</p> <p>Only newly created nodes are located in different Availability Zones. For instructions on how to move existing Memcached nodes to different Availability Zones, see the <b>Availability Zone Considerations</b> section of <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/mem-ug/CacheNodes.SupportedTypes.html">Cache Node Considerations for Memcached</a>.</p> </note></p>
-    pub az_mode: Option<String>,
+    pub az_mode: Option<AZMode>,
     /// <p>If <code>true</code>, this parameter causes the modifications in this request and any pending modifications to be applied, asynchronously and as soon as possible, regardless of the <code>PreferredMaintenanceWindow</code> setting for the cluster.</p> <p>If <code>false</code>, changes to the cluster are applied on the next maintenance reboot, or the next failure reboot, whichever occurs first.</p> <important> <p>If you perform a <code>ModifyCacheCluster</code> before a pending modification is applied, the pending modification is replaced by the newer modification.</p> </important> <p>Valid values: <code>true</code> | <code>false</code> </p> <p>Default: <code>false</code> </p>
     pub apply_immediately: Option<bool>,
     /// <p>This parameter is currently disabled.</p>
@@ -3756,6 +6166,166 @@ pub struct ModifyCacheClusterMessage {
     pub snapshot_window: Option<String>,
 }
 
+impl ModifyCacheClusterMessage {
+    /// Returns a builder for `ModifyCacheClusterMessage`, checking its required members when built.
+    pub fn builder() -> ModifyCacheClusterMessageBuilder {
+        ModifyCacheClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyCacheClusterMessage`](struct.ModifyCacheClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyCacheClusterMessageBuilder {
+    az_mode: Option<AZMode>,
+    apply_immediately: Option<bool>,
+    auto_minor_version_upgrade: Option<bool>,
+    cache_cluster_id: Option<String>,
+    cache_node_ids_to_remove: Option<Vec<String>>,
+    cache_node_type: Option<String>,
+    cache_parameter_group_name: Option<String>,
+    cache_security_group_names: Option<Vec<String>>,
+    engine_version: Option<String>,
+    new_availability_zones: Option<Vec<String>>,
+    notification_topic_arn: Option<String>,
+    notification_topic_status: Option<String>,
+    num_cache_nodes: Option<i64>,
+    preferred_maintenance_window: Option<String>,
+    security_group_ids: Option<Vec<String>>,
+    snapshot_retention_limit: Option<i64>,
+    snapshot_window: Option<String>,
+}
+
+impl ModifyCacheClusterMessageBuilder {
+    /// Sets the [`az_mode`](struct.ModifyCacheClusterMessage.html#structfield.az_mode) member.
+    pub fn az_mode<T: Into<AZMode>>(mut self, value: T) -> Self {
+        self.az_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`apply_immediately`](struct.ModifyCacheClusterMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_minor_version_upgrade`](struct.ModifyCacheClusterMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_cluster_id`](struct.ModifyCacheClusterMessage.html#structfield.cache_cluster_id) member.
+    pub fn cache_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_node_ids_to_remove`](struct.ModifyCacheClusterMessage.html#structfield.cache_node_ids_to_remove) member.
+    pub fn cache_node_ids_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cache_node_ids_to_remove = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_node_type`](struct.ModifyCacheClusterMessage.html#structfield.cache_node_type) member.
+    pub fn cache_node_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_node_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_parameter_group_name`](struct.ModifyCacheClusterMessage.html#structfield.cache_parameter_group_name) member.
+    pub fn cache_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_security_group_names`](struct.ModifyCacheClusterMessage.html#structfield.cache_security_group_names) member.
+    pub fn cache_security_group_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cache_security_group_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.ModifyCacheClusterMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_availability_zones`](struct.ModifyCacheClusterMessage.html#structfield.new_availability_zones) member.
+    pub fn new_availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.new_availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_topic_arn`](struct.ModifyCacheClusterMessage.html#structfield.notification_topic_arn) member.
+    pub fn notification_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_topic_status`](struct.ModifyCacheClusterMessage.html#structfield.notification_topic_status) member.
+    pub fn notification_topic_status<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification_topic_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`num_cache_nodes`](struct.ModifyCacheClusterMessage.html#structfield.num_cache_nodes) member.
+    pub fn num_cache_nodes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.num_cache_nodes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.ModifyCacheClusterMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_group_ids`](struct.ModifyCacheClusterMessage.html#structfield.security_group_ids) member.
+    pub fn security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_retention_limit`](struct.ModifyCacheClusterMessage.html#structfield.snapshot_retention_limit) member.
+    pub fn snapshot_retention_limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.snapshot_retention_limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_window`](struct.ModifyCacheClusterMessage.html#structfield.snapshot_window) member.
+    pub fn snapshot_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_window = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyCacheClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyCacheClusterMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyCacheClusterMessage {
+            az_mode: self.az_mode,
+            apply_immediately: self.apply_immediately,
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            cache_cluster_id: self
+                .cache_cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cache_cluster_id"))?,
+            cache_node_ids_to_remove: self.cache_node_ids_to_remove,
+            cache_node_type: self.cache_node_type,
+            cache_parameter_group_name: self.cache_parameter_group_name,
+            cache_security_group_names: self.cache_security_group_names,
+            engine_version: self.engine_version,
+            new_availability_zones: self.new_availability_zones,
+            notification_topic_arn: self.notification_topic_arn,
+            notification_topic_status: self.notification_topic_status,
+            num_cache_nodes: self.num_cache_nodes,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            security_group_ids: self.security_group_ids,
+            snapshot_retention_limit: self.snapshot_retention_limit,
+            snapshot_window: self.snapshot_window,
+        })
+    }
+}
+
 /// Serialize `ModifyCacheClusterMessage` contents to a `SignedRequest`.
 struct ModifyCacheClusterMessageSerializer;
 impl ModifyCacheClusterMessageSerializer {
@@ -3859,10 +6429,10 @@ pub struct ModifyCacheClusterResult {
     pub cache_cluster: Option<CacheCluster>,
 }
 
-struct ModifyCacheClusterResultDeserializer;
+pub(crate) struct ModifyCacheClusterResultDeserializer;
 impl ModifyCacheClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyCacheClusterResult, XmlParseError> {
@@ -3893,6 +6463,48 @@ pub struct ModifyCacheParameterGroupMessage {
     pub parameter_name_values: Vec<ParameterNameValue>,
 }
 
+impl ModifyCacheParameterGroupMessage {
+    /// Returns a builder for `ModifyCacheParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyCacheParameterGroupMessageBuilder {
+        ModifyCacheParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyCacheParameterGroupMessage`](struct.ModifyCacheParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyCacheParameterGroupMessageBuilder {
+    cache_parameter_group_name: Option<String>,
+    parameter_name_values: Option<Vec<ParameterNameValue>>,
+}
+
+impl ModifyCacheParameterGroupMessageBuilder {
+    /// Sets the [`cache_parameter_group_name`](struct.ModifyCacheParameterGroupMessage.html#structfield.cache_parameter_group_name) member.
+    pub fn cache_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_name_values`](struct.ModifyCacheParameterGroupMessage.html#structfield.parameter_name_values) member.
+    pub fn parameter_name_values<T: Into<Vec<ParameterNameValue>>>(mut self, value: T) -> Self {
+        self.parameter_name_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyCacheParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyCacheParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyCacheParameterGroupMessage {
+            cache_parameter_group_name: self.cache_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cache_parameter_group_name"),
+            )?,
+            parameter_name_values: self
+                .parameter_name_values
+                .ok_or(::rusoto_core::BuildError::missing("parameter_name_values"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyCacheParameterGroupMessage` contents to a `SignedRequest`.
 struct ModifyCacheParameterGroupMessageSerializer;
 impl ModifyCacheParameterGroupMessageSerializer {
@@ -3925,6 +6537,54 @@ pub struct ModifyCacheSubnetGroupMessage {
     pub subnet_ids: Option<Vec<String>>,
 }
 
+impl ModifyCacheSubnetGroupMessage {
+    /// Returns a builder for `ModifyCacheSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyCacheSubnetGroupMessageBuilder {
+        ModifyCacheSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyCacheSubnetGroupMessage`](struct.ModifyCacheSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyCacheSubnetGroupMessageBuilder {
+    cache_subnet_group_description: Option<String>,
+    cache_subnet_group_name: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+}
+
+impl ModifyCacheSubnetGroupMessageBuilder {
+    /// Sets the [`cache_subnet_group_description`](struct.ModifyCacheSubnetGroupMessage.html#structfield.cache_subnet_group_description) member.
+    pub fn cache_subnet_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_subnet_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_subnet_group_name`](struct.ModifyCacheSubnetGroupMessage.html#structfield.cache_subnet_group_name) member.
+    pub fn cache_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.ModifyCacheSubnetGroupMessage.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyCacheSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyCacheSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyCacheSubnetGroupMessage {
+            cache_subnet_group_description: self.cache_subnet_group_description,
+            cache_subnet_group_name: self.cache_subnet_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cache_subnet_group_name"),
+            )?,
+            subnet_ids: self.subnet_ids,
+        })
+    }
+}
+
 /// Serialize `ModifyCacheSubnetGroupMessage` contents to a `SignedRequest`.
 struct ModifyCacheSubnetGroupMessageSerializer;
 impl ModifyCacheSubnetGroupMessageSerializer {
@@ -3959,10 +6619,10 @@ pub struct ModifyCacheSubnetGroupResult {
     pub cache_subnet_group: Option<CacheSubnetGroup>,
 }
 
-struct ModifyCacheSubnetGroupResultDeserializer;
+pub(crate) struct ModifyCacheSubnetGroupResultDeserializer;
 impl ModifyCacheSubnetGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyCacheSubnetGroupResult, XmlParseError> {
@@ -4023,6 +6683,166 @@ pub struct ModifyReplicationGroupMessage {
     pub snapshotting_cluster_id: Option<String>,
 }
 
+impl ModifyReplicationGroupMessage {
+    /// Returns a builder for `ModifyReplicationGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyReplicationGroupMessageBuilder {
+        ModifyReplicationGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyReplicationGroupMessage`](struct.ModifyReplicationGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyReplicationGroupMessageBuilder {
+    apply_immediately: Option<bool>,
+    auto_minor_version_upgrade: Option<bool>,
+    automatic_failover_enabled: Option<bool>,
+    cache_node_type: Option<String>,
+    cache_parameter_group_name: Option<String>,
+    cache_security_group_names: Option<Vec<String>>,
+    engine_version: Option<String>,
+    notification_topic_arn: Option<String>,
+    notification_topic_status: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    primary_cluster_id: Option<String>,
+    replication_group_description: Option<String>,
+    replication_group_id: Option<String>,
+    security_group_ids: Option<Vec<String>>,
+    snapshot_retention_limit: Option<i64>,
+    snapshot_window: Option<String>,
+    snapshotting_cluster_id: Option<String>,
+}
+
+impl ModifyReplicationGroupMessageBuilder {
+    /// Sets the [`apply_immediately`](struct.ModifyReplicationGroupMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_minor_version_upgrade`](struct.ModifyReplicationGroupMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`automatic_failover_enabled`](struct.ModifyReplicationGroupMessage.html#structfield.automatic_failover_enabled) member.
+    pub fn automatic_failover_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.automatic_failover_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_node_type`](struct.ModifyReplicationGroupMessage.html#structfield.cache_node_type) member.
+    pub fn cache_node_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_node_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_parameter_group_name`](struct.ModifyReplicationGroupMessage.html#structfield.cache_parameter_group_name) member.
+    pub fn cache_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_security_group_names`](struct.ModifyReplicationGroupMessage.html#structfield.cache_security_group_names) member.
+    pub fn cache_security_group_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cache_security_group_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.ModifyReplicationGroupMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_topic_arn`](struct.ModifyReplicationGroupMessage.html#structfield.notification_topic_arn) member.
+    pub fn notification_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_topic_status`](struct.ModifyReplicationGroupMessage.html#structfield.notification_topic_status) member.
+    pub fn notification_topic_status<T: Into<String>>(mut self, value: T) -> Self {
+        self.notification_topic_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.ModifyReplicationGroupMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`primary_cluster_id`](struct.ModifyReplicationGroupMessage.html#structfield.primary_cluster_id) member.
+    pub fn primary_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.primary_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_description`](struct.ModifyReplicationGroupMessage.html#structfield.replication_group_description) member.
+    pub fn replication_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_id`](struct.ModifyReplicationGroupMessage.html#structfield.replication_group_id) member.
+    pub fn replication_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_group_ids`](struct.ModifyReplicationGroupMessage.html#structfield.security_group_ids) member.
+    pub fn security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_retention_limit`](struct.ModifyReplicationGroupMessage.html#structfield.snapshot_retention_limit) member.
+    pub fn snapshot_retention_limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.snapshot_retention_limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_window`](struct.ModifyReplicationGroupMessage.html#structfield.snapshot_window) member.
+    pub fn snapshot_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshotting_cluster_id`](struct.ModifyReplicationGroupMessage.html#structfield.snapshotting_cluster_id) member.
+    pub fn snapshotting_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshotting_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyReplicationGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyReplicationGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyReplicationGroupMessage {
+            apply_immediately: self.apply_immediately,
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            automatic_failover_enabled: self.automatic_failover_enabled,
+            cache_node_type: self.cache_node_type,
+            cache_parameter_group_name: self.cache_parameter_group_name,
+            cache_security_group_names: self.cache_security_group_names,
+            engine_version: self.engine_version,
+            notification_topic_arn: self.notification_topic_arn,
+            notification_topic_status: self.notification_topic_status,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            primary_cluster_id: self.primary_cluster_id,
+            replication_group_description: self.replication_group_description,
+            replication_group_id: self
+                .replication_group_id
+                .ok_or(::rusoto_core::BuildError::missing("replication_group_id"))?,
+            security_group_ids: self.security_group_ids,
+            snapshot_retention_limit: self.snapshot_retention_limit,
+            snapshot_window: self.snapshot_window,
+            snapshotting_cluster_id: self.snapshotting_cluster_id,
+        })
+    }
+}
+
 /// Serialize `ModifyReplicationGroupMessage` contents to a `SignedRequest`.
 struct ModifyReplicationGroupMessageSerializer;
 impl ModifyReplicationGroupMessageSerializer {
@@ -4128,10 +6948,10 @@ pub struct ModifyReplicationGroupResult {
     pub replication_group: Option<ReplicationGroup>,
 }
 
-struct ModifyReplicationGroupResultDeserializer;
+pub(crate) struct ModifyReplicationGroupResultDeserializer;
 impl ModifyReplicationGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyReplicationGroupResult, XmlParseError> {
@@ -4170,6 +6990,88 @@ pub struct ModifyReplicationGroupShardConfigurationMessage {
     pub resharding_configuration: Option<Vec<ReshardingConfiguration>>,
 }
 
+impl ModifyReplicationGroupShardConfigurationMessage {
+    /// Returns a builder for `ModifyReplicationGroupShardConfigurationMessage`, checking its required members when built.
+    pub fn builder() -> ModifyReplicationGroupShardConfigurationMessageBuilder {
+        ModifyReplicationGroupShardConfigurationMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyReplicationGroupShardConfigurationMessage`](struct.ModifyReplicationGroupShardConfigurationMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyReplicationGroupShardConfigurationMessageBuilder {
+    apply_immediately: Option<bool>,
+    node_group_count: Option<i64>,
+    node_groups_to_remove: Option<Vec<String>>,
+    node_groups_to_retain: Option<Vec<String>>,
+    replication_group_id: Option<String>,
+    resharding_configuration: Option<Vec<ReshardingConfiguration>>,
+}
+
+impl ModifyReplicationGroupShardConfigurationMessageBuilder {
+    /// Sets the [`apply_immediately`](struct.ModifyReplicationGroupShardConfigurationMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_group_count`](struct.ModifyReplicationGroupShardConfigurationMessage.html#structfield.node_group_count) member.
+    pub fn node_group_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.node_group_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_groups_to_remove`](struct.ModifyReplicationGroupShardConfigurationMessage.html#structfield.node_groups_to_remove) member.
+    pub fn node_groups_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.node_groups_to_remove = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_groups_to_retain`](struct.ModifyReplicationGroupShardConfigurationMessage.html#structfield.node_groups_to_retain) member.
+    pub fn node_groups_to_retain<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.node_groups_to_retain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_id`](struct.ModifyReplicationGroupShardConfigurationMessage.html#structfield.replication_group_id) member.
+    pub fn replication_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resharding_configuration`](struct.ModifyReplicationGroupShardConfigurationMessage.html#structfield.resharding_configuration) member.
+    pub fn resharding_configuration<T: Into<Vec<ReshardingConfiguration>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.resharding_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyReplicationGroupShardConfigurationMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        ModifyReplicationGroupShardConfigurationMessage,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(ModifyReplicationGroupShardConfigurationMessage {
+            apply_immediately: self
+                .apply_immediately
+                .ok_or(::rusoto_core::BuildError::missing("apply_immediately"))?,
+            node_group_count: self
+                .node_group_count
+                .ok_or(::rusoto_core::BuildError::missing("node_group_count"))?,
+            node_groups_to_remove: self.node_groups_to_remove,
+            node_groups_to_retain: self.node_groups_to_retain,
+            replication_group_id: self
+                .replication_group_id
+                .ok_or(::rusoto_core::BuildError::missing("replication_group_id"))?,
+            resharding_configuration: self.resharding_configuration,
+        })
+    }
+}
+
 /// Serialize `ModifyReplicationGroupShardConfigurationMessage` contents to a `SignedRequest`.
 struct ModifyReplicationGroupShardConfigurationMessageSerializer;
 impl ModifyReplicationGroupShardConfigurationMessageSerializer {
@@ -4224,10 +7126,10 @@ pub struct ModifyReplicationGroupShardConfigurationResult {
     pub replication_group: Option<ReplicationGroup>,
 }
 
-struct ModifyReplicationGroupShardConfigurationResultDeserializer;
+pub(crate) struct ModifyReplicationGroupShardConfigurationResultDeserializer;
 impl ModifyReplicationGroupShardConfigurationResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyReplicationGroupShardConfigurationResult, XmlParseError> {
@@ -4266,10 +7168,10 @@ pub struct NodeGroup {
     pub status: Option<String>,
 }
 
-struct NodeGroupDeserializer;
+pub(crate) struct NodeGroupDeserializer;
 impl NodeGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<NodeGroup, XmlParseError> {
@@ -4319,10 +7221,10 @@ pub struct NodeGroupConfiguration {
     pub slots: Option<String>,
 }
 
-struct NodeGroupConfigurationDeserializer;
+pub(crate) struct NodeGroupConfigurationDeserializer;
 impl NodeGroupConfigurationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<NodeGroupConfiguration, XmlParseError> {
@@ -4409,10 +7311,10 @@ impl NodeGroupConfigurationListSerializer {
     }
 }
 
-struct NodeGroupListDeserializer;
+pub(crate) struct NodeGroupListDeserializer;
 impl NodeGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<NodeGroup>, XmlParseError> {
@@ -4441,10 +7343,10 @@ pub struct NodeGroupMember {
     pub read_endpoint: Option<Endpoint>,
 }
 
-struct NodeGroupMemberDeserializer;
+pub(crate) struct NodeGroupMemberDeserializer;
 impl NodeGroupMemberDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<NodeGroupMember, XmlParseError> {
@@ -4477,10 +7379,10 @@ impl NodeGroupMemberDeserializer {
         })
     }
 }
-struct NodeGroupMemberListDeserializer;
+pub(crate) struct NodeGroupMemberListDeserializer;
 impl NodeGroupMemberListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<NodeGroupMember>, XmlParseError> {
@@ -4505,25 +7407,25 @@ pub struct NodeGroupMemberUpdateStatus {
     /// <p>The node ID of the cache cluster</p>
     pub cache_node_id: Option<String>,
     /// <p>The deletion date of the node</p>
-    pub node_deletion_date: Option<String>,
+    pub node_deletion_date: Option<IsoTimestamp>,
     /// <p>The end date of the update for a node</p>
-    pub node_update_end_date: Option<String>,
+    pub node_update_end_date: Option<IsoTimestamp>,
     /// <p>Reflects whether the update was initiated by the customer or automatically applied</p>
-    pub node_update_initiated_by: Option<String>,
+    pub node_update_initiated_by: Option<NodeUpdateInitiatedBy>,
     /// <p>The date when the update is triggered</p>
-    pub node_update_initiated_date: Option<String>,
+    pub node_update_initiated_date: Option<IsoTimestamp>,
     /// <p>The start date of the update for a node</p>
-    pub node_update_start_date: Option<String>,
+    pub node_update_start_date: Option<IsoTimestamp>,
     /// <p>The update status of the node</p>
-    pub node_update_status: Option<String>,
+    pub node_update_status: Option<NodeUpdateStatus>,
     /// <p>The date when the NodeUpdateStatus was last modified</p>
-    pub node_update_status_modified_date: Option<String>,
+    pub node_update_status_modified_date: Option<IsoTimestamp>,
 }
 
-struct NodeGroupMemberUpdateStatusDeserializer;
+pub(crate) struct NodeGroupMemberUpdateStatusDeserializer;
 impl NodeGroupMemberUpdateStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<NodeGroupMemberUpdateStatus, XmlParseError> {
@@ -4585,10 +7487,10 @@ impl NodeGroupMemberUpdateStatusDeserializer {
         )
     }
 }
-struct NodeGroupMemberUpdateStatusListDeserializer;
+pub(crate) struct NodeGroupMemberUpdateStatusListDeserializer;
 impl NodeGroupMemberUpdateStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<NodeGroupMemberUpdateStatus>, XmlParseError> {
@@ -4614,10 +7516,10 @@ pub struct NodeGroupUpdateStatus {
     pub node_group_member_update_status: Option<Vec<NodeGroupMemberUpdateStatus>>,
 }
 
-struct NodeGroupUpdateStatusDeserializer;
+pub(crate) struct NodeGroupUpdateStatusDeserializer;
 impl NodeGroupUpdateStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<NodeGroupUpdateStatus, XmlParseError> {
@@ -4641,10 +7543,10 @@ impl NodeGroupUpdateStatusDeserializer {
         })
     }
 }
-struct NodeGroupUpdateStatusListDeserializer;
+pub(crate) struct NodeGroupUpdateStatusListDeserializer;
 impl NodeGroupUpdateStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<NodeGroupUpdateStatus>, XmlParseError> {
@@ -4690,7 +7592,7 @@ pub struct NodeSnapshot {
     /// <p>A unique identifier for the source cluster.</p>
     pub cache_cluster_id: Option<String>,
     /// <p>The date and time when the cache node was created in the source cluster.</p>
-    pub cache_node_create_time: Option<String>,
+    pub cache_node_create_time: Option<IsoTimestamp>,
     /// <p>The cache node identifier for the node in the source cluster.</p>
     pub cache_node_id: Option<String>,
     /// <p>The size of the cache on the source cache node.</p>
@@ -4700,13 +7602,13 @@ pub struct NodeSnapshot {
     /// <p>A unique identifier for the source node group (shard).</p>
     pub node_group_id: Option<String>,
     /// <p>The date and time when the source node's metadata and cache data set was obtained for the snapshot.</p>
-    pub snapshot_create_time: Option<String>,
+    pub snapshot_create_time: Option<IsoTimestamp>,
 }
 
-struct NodeSnapshotDeserializer;
+pub(crate) struct NodeSnapshotDeserializer;
 impl NodeSnapshotDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<NodeSnapshot, XmlParseError> {
@@ -4752,10 +7654,10 @@ impl NodeSnapshotDeserializer {
         })
     }
 }
-struct NodeSnapshotListDeserializer;
+pub(crate) struct NodeSnapshotListDeserializer;
 impl NodeSnapshotListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<NodeSnapshot>, XmlParseError> {
@@ -4772,10 +7674,10 @@ impl NodeSnapshotListDeserializer {
         })
     }
 }
-struct NodeTypeListDeserializer;
+pub(crate) struct NodeTypeListDeserializer;
 impl NodeTypeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -4789,28 +7691,168 @@ impl NodeTypeListDeserializer {
         })
     }
 }
-struct NodeUpdateInitiatedByDeserializer;
+pub(crate) struct NodeUpdateInitiatedByDeserializer;
 impl NodeUpdateInitiatedByDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<NodeUpdateInitiatedBy, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct NodeUpdateStatusDeserializer;
+/// <p>Reflects whether the update was initiated by the customer or automatically applied</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NodeUpdateInitiatedBy {
+    /// <p><code>system</code></p>
+    System,
+    /// <p><code>customer</code></p>
+    Customer,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NodeUpdateInitiatedBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodeUpdateInitiatedBy::System => "system",
+            NodeUpdateInitiatedBy::Customer => "customer",
+            NodeUpdateInitiatedBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NodeUpdateInitiatedBy {
+    fn from(value: &'a str) -> NodeUpdateInitiatedBy {
+        match value {
+            "system" => NodeUpdateInitiatedBy::System,
+            "customer" => NodeUpdateInitiatedBy::Customer,
+            _ => NodeUpdateInitiatedBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeUpdateInitiatedBy {
+    fn from(value: String) -> NodeUpdateInitiatedBy {
+        NodeUpdateInitiatedBy::from(value.as_str())
+    }
+}
+
+impl From<NodeUpdateInitiatedBy> for String {
+    fn from(value: NodeUpdateInitiatedBy) -> String {
+        match value {
+            NodeUpdateInitiatedBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NodeUpdateInitiatedBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NodeUpdateInitiatedBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct NodeUpdateStatusDeserializer;
 impl NodeUpdateStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<NodeUpdateStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The update status of the node</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NodeUpdateStatus {
+    /// <p><code>not-applied</code></p>
+    NotApplied,
+    /// <p><code>waiting-to-start</code></p>
+    WaitingToStart,
+    /// <p><code>in-progress</code></p>
+    InProgress,
+    /// <p><code>stopping</code></p>
+    Stopping,
+    /// <p><code>stopped</code></p>
+    Stopped,
+    /// <p><code>complete</code></p>
+    Complete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NodeUpdateStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodeUpdateStatus::NotApplied => "not-applied",
+            NodeUpdateStatus::WaitingToStart => "waiting-to-start",
+            NodeUpdateStatus::InProgress => "in-progress",
+            NodeUpdateStatus::Stopping => "stopping",
+            NodeUpdateStatus::Stopped => "stopped",
+            NodeUpdateStatus::Complete => "complete",
+            NodeUpdateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NodeUpdateStatus {
+    fn from(value: &'a str) -> NodeUpdateStatus {
+        match value {
+            "not-applied" => NodeUpdateStatus::NotApplied,
+            "waiting-to-start" => NodeUpdateStatus::WaitingToStart,
+            "in-progress" => NodeUpdateStatus::InProgress,
+            "stopping" => NodeUpdateStatus::Stopping,
+            "stopped" => NodeUpdateStatus::Stopped,
+            "complete" => NodeUpdateStatus::Complete,
+            _ => NodeUpdateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeUpdateStatus {
+    fn from(value: String) -> NodeUpdateStatus {
+        NodeUpdateStatus::from(value.as_str())
+    }
+}
+
+impl From<NodeUpdateStatus> for String {
+    fn from(value: NodeUpdateStatus) -> String {
+        match value {
+            NodeUpdateStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NodeUpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NodeUpdateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Describes a notification topic and its status. Notification topics are used for publishing ElastiCache events to subscribers using Amazon Simple Notification Service (SNS).</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct NotificationConfiguration {
@@ -4820,10 +7862,10 @@ pub struct NotificationConfiguration {
     pub topic_status: Option<String>,
 }
 
-struct NotificationConfigurationDeserializer;
+pub(crate) struct NotificationConfigurationDeserializer;
 impl NotificationConfigurationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<NotificationConfiguration, XmlParseError> {
@@ -4852,7 +7894,7 @@ pub struct Parameter {
     /// <p>The valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
     /// <p>Indicates whether a change to the parameter is applied immediately or requires a reboot for the change to be applied. You can force a reboot or wait until the next maintenance window's reboot. For more information, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/Clusters.Rebooting.html">Rebooting a Cluster</a>.</p>
-    pub change_type: Option<String>,
+    pub change_type: Option<ChangeType>,
     /// <p>The valid data type for the parameter.</p>
     pub data_type: Option<String>,
     /// <p>A description of the parameter.</p>
@@ -4869,10 +7911,10 @@ pub struct Parameter {
     pub source: Option<String>,
 }
 
-struct ParameterDeserializer;
+pub(crate) struct ParameterDeserializer;
 impl ParameterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Parameter, XmlParseError> {
@@ -4957,10 +7999,10 @@ impl ParameterNameValueListSerializer {
     }
 }
 
-struct ParametersListDeserializer;
+pub(crate) struct ParametersListDeserializer;
 impl ParametersListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Parameter>, XmlParseError> {
@@ -4974,17 +8016,79 @@ impl ParametersListDeserializer {
         })
     }
 }
-struct PendingAutomaticFailoverStatusDeserializer;
+pub(crate) struct PendingAutomaticFailoverStatusDeserializer;
 impl PendingAutomaticFailoverStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PendingAutomaticFailoverStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p><p>Indicates the status of Multi-AZ with automatic failover for this Redis replication group.</p> <p>Amazon ElastiCache for Redis does not support Multi-AZ with automatic failover on:</p> <ul> <li> <p>Redis versions earlier than 2.8.6.</p> </li> <li> <p>Redis (cluster mode disabled): T1 node types.</p> </li> <li> <p>Redis (cluster mode enabled): T1 node types.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PendingAutomaticFailoverStatus {
+    /// <p><code>enabled</code></p>
+    Enabled,
+    /// <p><code>disabled</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PendingAutomaticFailoverStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PendingAutomaticFailoverStatus::Enabled => "enabled",
+            PendingAutomaticFailoverStatus::Disabled => "disabled",
+            PendingAutomaticFailoverStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PendingAutomaticFailoverStatus {
+    fn from(value: &'a str) -> PendingAutomaticFailoverStatus {
+        match value {
+            "enabled" => PendingAutomaticFailoverStatus::Enabled,
+            "disabled" => PendingAutomaticFailoverStatus::Disabled,
+            _ => PendingAutomaticFailoverStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PendingAutomaticFailoverStatus {
+    fn from(value: String) -> PendingAutomaticFailoverStatus {
+        PendingAutomaticFailoverStatus::from(value.as_str())
+    }
+}
+
+impl From<PendingAutomaticFailoverStatus> for String {
+    fn from(value: PendingAutomaticFailoverStatus) -> String {
+        match value {
+            PendingAutomaticFailoverStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PendingAutomaticFailoverStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PendingAutomaticFailoverStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A group of settings that are applied to the cluster in the future, or that are currently being applied.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PendingModifiedValues {
@@ -4998,10 +8102,10 @@ pub struct PendingModifiedValues {
     pub num_cache_nodes: Option<i64>,
 }
 
-struct PendingModifiedValuesDeserializer;
+pub(crate) struct PendingModifiedValuesDeserializer;
 impl PendingModifiedValuesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingModifiedValues, XmlParseError> {
@@ -5052,13 +8156,13 @@ pub struct ProcessedUpdateAction {
     /// <p>The unique ID of the service update</p>
     pub service_update_name: Option<String>,
     /// <p>The status of the update action on the Redis cluster</p>
-    pub update_action_status: Option<String>,
+    pub update_action_status: Option<UpdateActionStatus>,
 }
 
-struct ProcessedUpdateActionDeserializer;
+pub(crate) struct ProcessedUpdateActionDeserializer;
 impl ProcessedUpdateActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ProcessedUpdateAction, XmlParseError> {
@@ -5086,10 +8190,10 @@ impl ProcessedUpdateActionDeserializer {
         })
     }
 }
-struct ProcessedUpdateActionListDeserializer;
+pub(crate) struct ProcessedUpdateActionListDeserializer;
 impl ProcessedUpdateActionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ProcessedUpdateAction>, XmlParseError> {
@@ -5117,6 +8221,55 @@ pub struct PurchaseReservedCacheNodesOfferingMessage {
     pub reserved_cache_nodes_offering_id: String,
 }
 
+impl PurchaseReservedCacheNodesOfferingMessage {
+    /// Returns a builder for `PurchaseReservedCacheNodesOfferingMessage`, checking its required members when built.
+    pub fn builder() -> PurchaseReservedCacheNodesOfferingMessageBuilder {
+        PurchaseReservedCacheNodesOfferingMessageBuilder::default()
+    }
+}
+
+/// Builds a [`PurchaseReservedCacheNodesOfferingMessage`](struct.PurchaseReservedCacheNodesOfferingMessage.html).
+#[derive(Default, Debug)]
+pub struct PurchaseReservedCacheNodesOfferingMessageBuilder {
+    cache_node_count: Option<i64>,
+    reserved_cache_node_id: Option<String>,
+    reserved_cache_nodes_offering_id: Option<String>,
+}
+
+impl PurchaseReservedCacheNodesOfferingMessageBuilder {
+    /// Sets the [`cache_node_count`](struct.PurchaseReservedCacheNodesOfferingMessage.html#structfield.cache_node_count) member.
+    pub fn cache_node_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.cache_node_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_cache_node_id`](struct.PurchaseReservedCacheNodesOfferingMessage.html#structfield.reserved_cache_node_id) member.
+    pub fn reserved_cache_node_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_cache_node_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_cache_nodes_offering_id`](struct.PurchaseReservedCacheNodesOfferingMessage.html#structfield.reserved_cache_nodes_offering_id) member.
+    pub fn reserved_cache_nodes_offering_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_cache_nodes_offering_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PurchaseReservedCacheNodesOfferingMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PurchaseReservedCacheNodesOfferingMessage, ::rusoto_core::BuildError>
+    {
+        Ok(PurchaseReservedCacheNodesOfferingMessage {
+            cache_node_count: self.cache_node_count,
+            reserved_cache_node_id: self.reserved_cache_node_id,
+            reserved_cache_nodes_offering_id: self.reserved_cache_nodes_offering_id.ok_or(
+                ::rusoto_core::BuildError::missing("reserved_cache_nodes_offering_id"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `PurchaseReservedCacheNodesOfferingMessage` contents to a `SignedRequest`.
 struct PurchaseReservedCacheNodesOfferingMessageSerializer;
 impl PurchaseReservedCacheNodesOfferingMessageSerializer {
@@ -5147,10 +8300,10 @@ pub struct PurchaseReservedCacheNodesOfferingResult {
     pub reserved_cache_node: Option<ReservedCacheNode>,
 }
 
-struct PurchaseReservedCacheNodesOfferingResultDeserializer;
+pub(crate) struct PurchaseReservedCacheNodesOfferingResultDeserializer;
 impl PurchaseReservedCacheNodesOfferingResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PurchaseReservedCacheNodesOfferingResult, XmlParseError> {
@@ -5181,6 +8334,48 @@ pub struct RebootCacheClusterMessage {
     pub cache_node_ids_to_reboot: Vec<String>,
 }
 
+impl RebootCacheClusterMessage {
+    /// Returns a builder for `RebootCacheClusterMessage`, checking its required members when built.
+    pub fn builder() -> RebootCacheClusterMessageBuilder {
+        RebootCacheClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RebootCacheClusterMessage`](struct.RebootCacheClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct RebootCacheClusterMessageBuilder {
+    cache_cluster_id: Option<String>,
+    cache_node_ids_to_reboot: Option<Vec<String>>,
+}
+
+impl RebootCacheClusterMessageBuilder {
+    /// Sets the [`cache_cluster_id`](struct.RebootCacheClusterMessage.html#structfield.cache_cluster_id) member.
+    pub fn cache_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cache_node_ids_to_reboot`](struct.RebootCacheClusterMessage.html#structfield.cache_node_ids_to_reboot) member.
+    pub fn cache_node_ids_to_reboot<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cache_node_ids_to_reboot = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebootCacheClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RebootCacheClusterMessage, ::rusoto_core::BuildError> {
+        Ok(RebootCacheClusterMessage {
+            cache_cluster_id: self
+                .cache_cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cache_cluster_id"))?,
+            cache_node_ids_to_reboot: self.cache_node_ids_to_reboot.ok_or(
+                ::rusoto_core::BuildError::missing("cache_node_ids_to_reboot"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `RebootCacheClusterMessage` contents to a `SignedRequest`.
 struct RebootCacheClusterMessageSerializer;
 impl RebootCacheClusterMessageSerializer {
@@ -5207,10 +8402,10 @@ pub struct RebootCacheClusterResult {
     pub cache_cluster: Option<CacheCluster>,
 }
 
-struct RebootCacheClusterResultDeserializer;
+pub(crate) struct RebootCacheClusterResultDeserializer;
 impl RebootCacheClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RebootCacheClusterResult, XmlParseError> {
@@ -5241,10 +8436,10 @@ pub struct RecurringCharge {
     pub recurring_charge_frequency: Option<String>,
 }
 
-struct RecurringChargeDeserializer;
+pub(crate) struct RecurringChargeDeserializer;
 impl RecurringChargeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RecurringCharge, XmlParseError> {
@@ -5268,10 +8463,10 @@ impl RecurringChargeDeserializer {
         })
     }
 }
-struct RecurringChargeListDeserializer;
+pub(crate) struct RecurringChargeListDeserializer;
 impl RecurringChargeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<RecurringCharge>, XmlParseError> {
@@ -5309,17 +8504,59 @@ pub struct RemoveTagsFromResourceMessage {
     pub tag_keys: Vec<String>,
 }
 
-/// Serialize `RemoveTagsFromResourceMessage` contents to a `SignedRequest`.
-struct RemoveTagsFromResourceMessageSerializer;
-impl RemoveTagsFromResourceMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &RemoveTagsFromResourceMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
-
-        params.put(&format!("{}{}", prefix, "ResourceName"), &obj.resource_name);
-        KeyListSerializer::serialize(params, &format!("{}{}", prefix, "TagKeys"), &obj.tag_keys);
+impl RemoveTagsFromResourceMessage {
+    /// Returns a builder for `RemoveTagsFromResourceMessage`, checking its required members when built.
+    pub fn builder() -> RemoveTagsFromResourceMessageBuilder {
+        RemoveTagsFromResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsFromResourceMessage`](struct.RemoveTagsFromResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsFromResourceMessageBuilder {
+    resource_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl RemoveTagsFromResourceMessageBuilder {
+    /// Sets the [`resource_name`](struct.RemoveTagsFromResourceMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.RemoveTagsFromResourceMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsFromResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveTagsFromResourceMessage, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsFromResourceMessage {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
+/// Serialize `RemoveTagsFromResourceMessage` contents to a `SignedRequest`.
+struct RemoveTagsFromResourceMessageSerializer;
+impl RemoveTagsFromResourceMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &RemoveTagsFromResourceMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        params.put(&format!("{}{}", prefix, "ResourceName"), &obj.resource_name);
+        KeyListSerializer::serialize(params, &format!("{}{}", prefix, "TagKeys"), &obj.tag_keys);
     }
 }
 
@@ -5342,7 +8579,7 @@ pub struct ReplicationGroup {
     /// <p>A flag that enables using an <code>AuthToken</code> (password) when issuing Redis commands.</p> <p>Default: <code>false</code> </p>
     pub auth_token_enabled: Option<bool>,
     /// <p><p>Indicates the status of Multi-AZ with automatic failover for this Redis replication group.</p> <p>Amazon ElastiCache for Redis does not support Multi-AZ with automatic failover on:</p> <ul> <li> <p>Redis versions earlier than 2.8.6.</p> </li> <li> <p>Redis (cluster mode disabled): T1 node types.</p> </li> <li> <p>Redis (cluster mode enabled): T1 node types.</p> </li> </ul></p>
-    pub automatic_failover: Option<String>,
+    pub automatic_failover: Option<AutomaticFailoverStatus>,
     /// <p>The name of the compute and memory capacity node type for each node in the replication group.</p>
     pub cache_node_type: Option<String>,
     /// <p>A flag indicating whether or not this replication group is cluster enabled; i.e., whether its data can be partitioned across multiple shards (API/CLI: node groups).</p> <p>Valid values: <code>true</code> | <code>false</code> </p>
@@ -5373,10 +8610,10 @@ pub struct ReplicationGroup {
     pub transit_encryption_enabled: Option<bool>,
 }
 
-struct ReplicationGroupDeserializer;
+pub(crate) struct ReplicationGroupDeserializer;
 impl ReplicationGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReplicationGroup, XmlParseError> {
@@ -5490,10 +8727,10 @@ impl ReplicationGroupIdListSerializer {
     }
 }
 
-struct ReplicationGroupListDeserializer;
+pub(crate) struct ReplicationGroupListDeserializer;
 impl ReplicationGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ReplicationGroup>, XmlParseError> {
@@ -5519,10 +8756,10 @@ pub struct ReplicationGroupMessage {
     pub replication_groups: Option<Vec<ReplicationGroup>>,
 }
 
-struct ReplicationGroupMessageDeserializer;
+pub(crate) struct ReplicationGroupMessageDeserializer;
 impl ReplicationGroupMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReplicationGroupMessage, XmlParseError> {
@@ -5553,17 +8790,17 @@ impl ReplicationGroupMessageDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ReplicationGroupPendingModifiedValues {
     /// <p><p>Indicates the status of Multi-AZ with automatic failover for this Redis replication group.</p> <p>Amazon ElastiCache for Redis does not support Multi-AZ with automatic failover on:</p> <ul> <li> <p>Redis versions earlier than 2.8.6.</p> </li> <li> <p>Redis (cluster mode disabled): T1 node types.</p> </li> <li> <p>Redis (cluster mode enabled): T1 node types.</p> </li> </ul></p>
-    pub automatic_failover_status: Option<String>,
+    pub automatic_failover_status: Option<PendingAutomaticFailoverStatus>,
     /// <p>The primary cluster ID that is applied immediately (if <code>--apply-immediately</code> was specified), or during the next maintenance window.</p>
     pub primary_cluster_id: Option<String>,
     /// <p>The status of an online resharding operation.</p>
     pub resharding: Option<ReshardingStatus>,
 }
 
-struct ReplicationGroupPendingModifiedValuesDeserializer;
+pub(crate) struct ReplicationGroupPendingModifiedValuesDeserializer;
 impl ReplicationGroupPendingModifiedValuesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReplicationGroupPendingModifiedValues, XmlParseError> {
@@ -5620,17 +8857,17 @@ pub struct ReservedCacheNode {
     /// <p>The offering identifier.</p>
     pub reserved_cache_nodes_offering_id: Option<String>,
     /// <p>The time the reservation started.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
     /// <p>The state of the reserved cache node.</p>
     pub state: Option<String>,
     /// <p>The hourly price charged for this reserved cache node.</p>
     pub usage_price: Option<f64>,
 }
 
-struct ReservedCacheNodeDeserializer;
+pub(crate) struct ReservedCacheNodeDeserializer;
 impl ReservedCacheNodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReservedCacheNode, XmlParseError> {
@@ -5696,10 +8933,10 @@ impl ReservedCacheNodeDeserializer {
         })
     }
 }
-struct ReservedCacheNodeListDeserializer;
+pub(crate) struct ReservedCacheNodeListDeserializer;
 impl ReservedCacheNodeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ReservedCacheNode>, XmlParseError> {
@@ -5725,10 +8962,10 @@ pub struct ReservedCacheNodeMessage {
     pub reserved_cache_nodes: Option<Vec<ReservedCacheNode>>,
 }
 
-struct ReservedCacheNodeMessageDeserializer;
+pub(crate) struct ReservedCacheNodeMessageDeserializer;
 impl ReservedCacheNodeMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReservedCacheNodeMessage, XmlParseError> {
@@ -5776,10 +9013,10 @@ pub struct ReservedCacheNodesOffering {
     pub usage_price: Option<f64>,
 }
 
-struct ReservedCacheNodesOfferingDeserializer;
+pub(crate) struct ReservedCacheNodesOfferingDeserializer;
 impl ReservedCacheNodesOfferingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReservedCacheNodesOffering, XmlParseError> {
@@ -5833,10 +9070,10 @@ impl ReservedCacheNodesOfferingDeserializer {
         )
     }
 }
-struct ReservedCacheNodesOfferingListDeserializer;
+pub(crate) struct ReservedCacheNodesOfferingListDeserializer;
 impl ReservedCacheNodesOfferingListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ReservedCacheNodesOffering>, XmlParseError> {
@@ -5862,10 +9099,10 @@ pub struct ReservedCacheNodesOfferingMessage {
     pub reserved_cache_nodes_offerings: Option<Vec<ReservedCacheNodesOffering>>,
 }
 
-struct ReservedCacheNodesOfferingMessageDeserializer;
+pub(crate) struct ReservedCacheNodesOfferingMessageDeserializer;
 impl ReservedCacheNodesOfferingMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReservedCacheNodesOfferingMessage, XmlParseError> {
@@ -5903,6 +9140,54 @@ pub struct ResetCacheParameterGroupMessage {
     pub reset_all_parameters: Option<bool>,
 }
 
+impl ResetCacheParameterGroupMessage {
+    /// Returns a builder for `ResetCacheParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ResetCacheParameterGroupMessageBuilder {
+        ResetCacheParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ResetCacheParameterGroupMessage`](struct.ResetCacheParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ResetCacheParameterGroupMessageBuilder {
+    cache_parameter_group_name: Option<String>,
+    parameter_name_values: Option<Vec<ParameterNameValue>>,
+    reset_all_parameters: Option<bool>,
+}
+
+impl ResetCacheParameterGroupMessageBuilder {
+    /// Sets the [`cache_parameter_group_name`](struct.ResetCacheParameterGroupMessage.html#structfield.cache_parameter_group_name) member.
+    pub fn cache_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_name_values`](struct.ResetCacheParameterGroupMessage.html#structfield.parameter_name_values) member.
+    pub fn parameter_name_values<T: Into<Vec<ParameterNameValue>>>(mut self, value: T) -> Self {
+        self.parameter_name_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reset_all_parameters`](struct.ResetCacheParameterGroupMessage.html#structfield.reset_all_parameters) member.
+    pub fn reset_all_parameters<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reset_all_parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetCacheParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResetCacheParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ResetCacheParameterGroupMessage {
+            cache_parameter_group_name: self.cache_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cache_parameter_group_name"),
+            )?,
+            parameter_name_values: self.parameter_name_values,
+            reset_all_parameters: self.reset_all_parameters,
+        })
+    }
+}
+
 /// Serialize `ResetCacheParameterGroupMessage` contents to a `SignedRequest`.
 struct ResetCacheParameterGroupMessageSerializer;
 impl ResetCacheParameterGroupMessageSerializer {
@@ -5978,10 +9263,10 @@ pub struct ReshardingStatus {
     pub slot_migration: Option<SlotMigration>,
 }
 
-struct ReshardingStatusDeserializer;
+pub(crate) struct ReshardingStatusDeserializer;
 impl ReshardingStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReshardingStatus, XmlParseError> {
@@ -6010,6 +9295,59 @@ pub struct RevokeCacheSecurityGroupIngressMessage {
     pub ec2_security_group_owner_id: String,
 }
 
+impl RevokeCacheSecurityGroupIngressMessage {
+    /// Returns a builder for `RevokeCacheSecurityGroupIngressMessage`, checking its required members when built.
+    pub fn builder() -> RevokeCacheSecurityGroupIngressMessageBuilder {
+        RevokeCacheSecurityGroupIngressMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RevokeCacheSecurityGroupIngressMessage`](struct.RevokeCacheSecurityGroupIngressMessage.html).
+#[derive(Default, Debug)]
+pub struct RevokeCacheSecurityGroupIngressMessageBuilder {
+    cache_security_group_name: Option<String>,
+    ec2_security_group_name: Option<String>,
+    ec2_security_group_owner_id: Option<String>,
+}
+
+impl RevokeCacheSecurityGroupIngressMessageBuilder {
+    /// Sets the [`cache_security_group_name`](struct.RevokeCacheSecurityGroupIngressMessage.html#structfield.cache_security_group_name) member.
+    pub fn cache_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cache_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_name`](struct.RevokeCacheSecurityGroupIngressMessage.html#structfield.ec2_security_group_name) member.
+    pub fn ec2_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_owner_id`](struct.RevokeCacheSecurityGroupIngressMessage.html#structfield.ec2_security_group_owner_id) member.
+    pub fn ec2_security_group_owner_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_owner_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RevokeCacheSecurityGroupIngressMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RevokeCacheSecurityGroupIngressMessage, ::rusoto_core::BuildError>
+    {
+        Ok(RevokeCacheSecurityGroupIngressMessage {
+            cache_security_group_name: self.cache_security_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cache_security_group_name"),
+            )?,
+            ec2_security_group_name: self.ec2_security_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("ec2_security_group_name"),
+            )?,
+            ec2_security_group_owner_id: self.ec2_security_group_owner_id.ok_or(
+                ::rusoto_core::BuildError::missing("ec2_security_group_owner_id"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `RevokeCacheSecurityGroupIngressMessage` contents to a `SignedRequest`.
 struct RevokeCacheSecurityGroupIngressMessageSerializer;
 impl RevokeCacheSecurityGroupIngressMessageSerializer {
@@ -6039,10 +9377,10 @@ pub struct RevokeCacheSecurityGroupIngressResult {
     pub cache_security_group: Option<CacheSecurityGroup>,
 }
 
-struct RevokeCacheSecurityGroupIngressResultDeserializer;
+pub(crate) struct RevokeCacheSecurityGroupIngressResultDeserializer;
 impl RevokeCacheSecurityGroupIngressResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RevokeCacheSecurityGroupIngressResult, XmlParseError> {
@@ -6086,10 +9424,10 @@ pub struct SecurityGroupMembership {
     pub status: Option<String>,
 }
 
-struct SecurityGroupMembershipDeserializer;
+pub(crate) struct SecurityGroupMembershipDeserializer;
 impl SecurityGroupMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SecurityGroupMembership, XmlParseError> {
@@ -6112,10 +9450,10 @@ impl SecurityGroupMembershipDeserializer {
         )
     }
 }
-struct SecurityGroupMembershipListDeserializer;
+pub(crate) struct SecurityGroupMembershipListDeserializer;
 impl SecurityGroupMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SecurityGroupMembership>, XmlParseError> {
@@ -6145,25 +9483,25 @@ pub struct ServiceUpdate {
     /// <p>Provides details of the service update</p>
     pub service_update_description: Option<String>,
     /// <p>The date after which the service update is no longer available</p>
-    pub service_update_end_date: Option<String>,
+    pub service_update_end_date: Option<IsoTimestamp>,
     /// <p>The unique ID of the service update</p>
     pub service_update_name: Option<String>,
     /// <p>The recommendend date to apply the service update in order to ensure compliance. For information on compliance, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/elasticache-compliance.html#elasticache-compliance-self-service">Self-Service Security Updates for Compliance</a>.</p>
-    pub service_update_recommended_apply_by_date: Option<String>,
+    pub service_update_recommended_apply_by_date: Option<IsoTimestamp>,
     /// <p>The date when the service update is initially available</p>
-    pub service_update_release_date: Option<String>,
+    pub service_update_release_date: Option<IsoTimestamp>,
     /// <p>The severity of the service update</p>
-    pub service_update_severity: Option<String>,
+    pub service_update_severity: Option<ServiceUpdateSeverity>,
     /// <p>The status of the service update</p>
-    pub service_update_status: Option<String>,
+    pub service_update_status: Option<ServiceUpdateStatus>,
     /// <p>Reflects the nature of the service update</p>
-    pub service_update_type: Option<String>,
+    pub service_update_type: Option<ServiceUpdateType>,
 }
 
-struct ServiceUpdateDeserializer;
+pub(crate) struct ServiceUpdateDeserializer;
 impl ServiceUpdateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ServiceUpdate, XmlParseError> {
@@ -6243,10 +9581,10 @@ impl ServiceUpdateDeserializer {
         })
     }
 }
-struct ServiceUpdateListDeserializer;
+pub(crate) struct ServiceUpdateListDeserializer;
 impl ServiceUpdateListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ServiceUpdate>, XmlParseError> {
@@ -6263,33 +9601,169 @@ impl ServiceUpdateListDeserializer {
         })
     }
 }
-struct ServiceUpdateSeverityDeserializer;
+pub(crate) struct ServiceUpdateSeverityDeserializer;
 impl ServiceUpdateSeverityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ServiceUpdateSeverity, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ServiceUpdateStatusDeserializer;
+/// <p>The severity of the service update</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceUpdateSeverity {
+    /// <p><code>critical</code></p>
+    Critical,
+    /// <p><code>important</code></p>
+    Important,
+    /// <p><code>medium</code></p>
+    Medium,
+    /// <p><code>low</code></p>
+    Low,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServiceUpdateSeverity {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceUpdateSeverity::Critical => "critical",
+            ServiceUpdateSeverity::Important => "important",
+            ServiceUpdateSeverity::Medium => "medium",
+            ServiceUpdateSeverity::Low => "low",
+            ServiceUpdateSeverity::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServiceUpdateSeverity {
+    fn from(value: &'a str) -> ServiceUpdateSeverity {
+        match value {
+            "critical" => ServiceUpdateSeverity::Critical,
+            "important" => ServiceUpdateSeverity::Important,
+            "medium" => ServiceUpdateSeverity::Medium,
+            "low" => ServiceUpdateSeverity::Low,
+            _ => ServiceUpdateSeverity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceUpdateSeverity {
+    fn from(value: String) -> ServiceUpdateSeverity {
+        ServiceUpdateSeverity::from(value.as_str())
+    }
+}
+
+impl From<ServiceUpdateSeverity> for String {
+    fn from(value: ServiceUpdateSeverity) -> String {
+        match value {
+            ServiceUpdateSeverity::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServiceUpdateSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceUpdateSeverity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ServiceUpdateStatusDeserializer;
 impl ServiceUpdateStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ServiceUpdateStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 
+/// <p>The status of the service update</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceUpdateStatus {
+    /// <p><code>available</code></p>
+    Available,
+    /// <p><code>cancelled</code></p>
+    Cancelled,
+    /// <p><code>expired</code></p>
+    Expired,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServiceUpdateStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceUpdateStatus::Available => "available",
+            ServiceUpdateStatus::Cancelled => "cancelled",
+            ServiceUpdateStatus::Expired => "expired",
+            ServiceUpdateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServiceUpdateStatus {
+    fn from(value: &'a str) -> ServiceUpdateStatus {
+        match value {
+            "available" => ServiceUpdateStatus::Available,
+            "cancelled" => ServiceUpdateStatus::Cancelled,
+            "expired" => ServiceUpdateStatus::Expired,
+            _ => ServiceUpdateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceUpdateStatus {
+    fn from(value: String) -> ServiceUpdateStatus {
+        ServiceUpdateStatus::from(value.as_str())
+    }
+}
+
+impl From<ServiceUpdateStatus> for String {
+    fn from(value: ServiceUpdateStatus) -> String {
+        match value {
+            ServiceUpdateStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServiceUpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceUpdateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// Serialize `ServiceUpdateStatusList` contents to a `SignedRequest`.
 struct ServiceUpdateStatusListSerializer;
 impl ServiceUpdateStatusListSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<ServiceUpdateStatus>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -6297,17 +9771,75 @@ impl ServiceUpdateStatusListSerializer {
     }
 }
 
-struct ServiceUpdateTypeDeserializer;
+pub(crate) struct ServiceUpdateTypeDeserializer;
 impl ServiceUpdateTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ServiceUpdateType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>Reflects the nature of the service update</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceUpdateType {
+    /// <p><code>security-update</code></p>
+    SecurityUpdate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServiceUpdateType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceUpdateType::SecurityUpdate => "security-update",
+            ServiceUpdateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServiceUpdateType {
+    fn from(value: &'a str) -> ServiceUpdateType {
+        match value {
+            "security-update" => ServiceUpdateType::SecurityUpdate,
+            _ => ServiceUpdateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceUpdateType {
+    fn from(value: String) -> ServiceUpdateType {
+        ServiceUpdateType::from(value.as_str())
+    }
+}
+
+impl From<ServiceUpdateType> for String {
+    fn from(value: ServiceUpdateType) -> String {
+        match value {
+            ServiceUpdateType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServiceUpdateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ServiceUpdateType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ServiceUpdatesMessage {
     /// <p>An optional marker returned from a prior request. Use this marker for pagination of results from this operation. If this parameter is specified, the response includes only records beyond the marker, up to the value specified by <code>MaxRecords</code>.</p>
@@ -6316,10 +9848,10 @@ pub struct ServiceUpdatesMessage {
     pub service_updates: Option<Vec<ServiceUpdate>>,
 }
 
-struct ServiceUpdatesMessageDeserializer;
+pub(crate) struct ServiceUpdatesMessageDeserializer;
 impl ServiceUpdatesMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ServiceUpdatesMessage, XmlParseError> {
@@ -6339,17 +9871,83 @@ impl ServiceUpdatesMessageDeserializer {
         })
     }
 }
-struct SlaMetDeserializer;
+pub(crate) struct SlaMetDeserializer;
 impl SlaMetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SlaMet, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>If yes, all nodes in the replication group have been updated by the recommended apply-by date. If no, at least one node in the replication group have not been updated by the recommended apply-by date. If N/A, the replication group was created after the recommended apply-by date.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SlaMet {
+    /// <p><code>yes</code></p>
+    Yes,
+    /// <p><code>no</code></p>
+    No,
+    /// <p><code>n/a</code></p>
+    NA,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SlaMet {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SlaMet::Yes => "yes",
+            SlaMet::No => "no",
+            SlaMet::NA => "n/a",
+            SlaMet::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SlaMet {
+    fn from(value: &'a str) -> SlaMet {
+        match value {
+            "yes" => SlaMet::Yes,
+            "no" => SlaMet::No,
+            "n/a" => SlaMet::NA,
+            _ => SlaMet::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SlaMet {
+    fn from(value: String) -> SlaMet {
+        SlaMet::from(value.as_str())
+    }
+}
+
+impl From<SlaMet> for String {
+    fn from(value: SlaMet) -> String {
+        match value {
+            SlaMet::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SlaMet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SlaMet {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Represents the progress of an online resharding operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SlotMigration {
@@ -6357,10 +9955,10 @@ pub struct SlotMigration {
     pub progress_percentage: Option<f64>,
 }
 
-struct SlotMigrationDeserializer;
+pub(crate) struct SlotMigrationDeserializer;
 impl SlotMigrationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SlotMigration, XmlParseError> {
@@ -6384,9 +9982,9 @@ pub struct Snapshot {
     /// <p>This parameter is currently disabled.</p>
     pub auto_minor_version_upgrade: Option<bool>,
     /// <p><p>Indicates the status of Multi-AZ with automatic failover for the source Redis replication group.</p> <p>Amazon ElastiCache for Redis does not support Multi-AZ with automatic failover on:</p> <ul> <li> <p>Redis versions earlier than 2.8.6.</p> </li> <li> <p>Redis (cluster mode disabled): T1 node types.</p> </li> <li> <p>Redis (cluster mode enabled): T1 node types.</p> </li> </ul></p>
-    pub automatic_failover: Option<String>,
+    pub automatic_failover: Option<AutomaticFailoverStatus>,
     /// <p>The date and time when the source cluster was created.</p>
-    pub cache_cluster_create_time: Option<String>,
+    pub cache_cluster_create_time: Option<IsoTimestamp>,
     /// <p>The user-supplied identifier of the source cluster.</p>
     pub cache_cluster_id: Option<String>,
     /// <p><p>The name of the compute and memory capacity node type for the source cluster.</p> <p>The following node types are supported by ElastiCache. Generally speaking, the current generation types provide more memory and computational power at lower cost when compared to their equivalent previous generation counterparts.</p> <ul> <li> <p>General purpose:</p> <ul> <li> <p>Current generation: </p> <p> <b>M5 node types:</b> <code>cache.m5.large</code>, <code>cache.m5.xlarge</code>, <code>cache.m5.2xlarge</code>, <code>cache.m5.4xlarge</code>, <code>cache.m5.12xlarge</code>, <code>cache.m5.24xlarge</code> </p> <p> <b>M4 node types:</b> <code>cache.m4.large</code>, <code>cache.m4.xlarge</code>, <code>cache.m4.2xlarge</code>, <code>cache.m4.4xlarge</code>, <code>cache.m4.10xlarge</code> </p> <p> <b>T2 node types:</b> <code>cache.t2.micro</code>, <code>cache.t2.small</code>, <code>cache.t2.medium</code> </p> </li> <li> <p>Previous generation: (not recommended)</p> <p> <b>T1 node types:</b> <code>cache.t1.micro</code> </p> <p> <b>M1 node types:</b> <code>cache.m1.small</code>, <code>cache.m1.medium</code>, <code>cache.m1.large</code>, <code>cache.m1.xlarge</code> </p> <p> <b>M3 node types:</b> <code>cache.m3.medium</code>, <code>cache.m3.large</code>, <code>cache.m3.xlarge</code>, <code>cache.m3.2xlarge</code> </p> </li> </ul> </li> <li> <p>Compute optimized:</p> <ul> <li> <p>Previous generation: (not recommended)</p> <p> <b>C1 node types:</b> <code>cache.c1.xlarge</code> </p> </li> </ul> </li> <li> <p>Memory optimized:</p> <ul> <li> <p>Current generation: </p> <p> <b>R5 node types:</b> <code>cache.r5.large</code>, <code>cache.r5.xlarge</code>, <code>cache.r5.2xlarge</code>, <code>cache.r5.4xlarge</code>, <code>cache.r5.12xlarge</code>, <code>cache.r5.24xlarge</code> </p> <p> <b>R4 node types:</b> <code>cache.r4.large</code>, <code>cache.r4.xlarge</code>, <code>cache.r4.2xlarge</code>, <code>cache.r4.4xlarge</code>, <code>cache.r4.8xlarge</code>, <code>cache.r4.16xlarge</code> </p> </li> <li> <p>Previous generation: (not recommended)</p> <p> <b>M2 node types:</b> <code>cache.m2.xlarge</code>, <code>cache.m2.2xlarge</code>, <code>cache.m2.4xlarge</code> </p> <p> <b>R3 node types:</b> <code>cache.r3.large</code>, <code>cache.r3.xlarge</code>, <code>cache.r3.2xlarge</code>, <code>cache.r3.4xlarge</code>, <code>cache.r3.8xlarge</code> </p> </li> </ul> </li> </ul> <p> <b>Additional node type info</b> </p> <ul> <li> <p>All current generation instance types are created in Amazon VPC by default.</p> </li> <li> <p>Redis append-only files (AOF) are not supported for T1 or T2 instances.</p> </li> <li> <p>Redis Multi-AZ with automatic failover is not supported on T1 instances.</p> </li> <li> <p>Redis configuration variables <code>appendonly</code> and <code>appendfsync</code> are not supported on Redis version 2.8.22 and later.</p> </li> </ul></p>
@@ -6433,10 +10031,10 @@ pub struct Snapshot {
     pub vpc_id: Option<String>,
 }
 
-struct SnapshotDeserializer;
+pub(crate) struct SnapshotDeserializer;
 impl SnapshotDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Snapshot, XmlParseError> {
@@ -6581,10 +10179,10 @@ impl SnapshotArnsListSerializer {
     }
 }
 
-struct SnapshotListDeserializer;
+pub(crate) struct SnapshotListDeserializer;
 impl SnapshotListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Snapshot>, XmlParseError> {
@@ -6598,21 +10196,114 @@ impl SnapshotListDeserializer {
         })
     }
 }
-struct SourceTypeDeserializer;
+pub(crate) struct SourceTypeDeserializer;
 impl SourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct StringDeserializer;
+/// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceType {
+    /// <p><code>cache-cluster</code></p>
+    CacheCluster,
+    /// <p><code>cache-parameter-group</code></p>
+    CacheParameterGroup,
+    /// <p><code>cache-security-group</code></p>
+    CacheSecurityGroup,
+    /// <p><code>cache-subnet-group</code></p>
+    CacheSubnetGroup,
+    /// <p><code>replication-group</code></p>
+    ReplicationGroup,
+    /// <p><code>serverless-cache</code></p>
+    ServerlessCache,
+    /// <p><code>serverless-cache-snapshot</code></p>
+    ServerlessCacheSnapshot,
+    /// <p><code>user</code></p>
+    User,
+    /// <p><code>user-group</code></p>
+    UserGroup,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::CacheCluster => "cache-cluster",
+            SourceType::CacheParameterGroup => "cache-parameter-group",
+            SourceType::CacheSecurityGroup => "cache-security-group",
+            SourceType::CacheSubnetGroup => "cache-subnet-group",
+            SourceType::ReplicationGroup => "replication-group",
+            SourceType::ServerlessCache => "serverless-cache",
+            SourceType::ServerlessCacheSnapshot => "serverless-cache-snapshot",
+            SourceType::User => "user",
+            SourceType::UserGroup => "user-group",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "cache-cluster" => SourceType::CacheCluster,
+            "cache-parameter-group" => SourceType::CacheParameterGroup,
+            "cache-security-group" => SourceType::CacheSecurityGroup,
+            "cache-subnet-group" => SourceType::CacheSubnetGroup,
+            "replication-group" => SourceType::ReplicationGroup,
+            "serverless-cache" => SourceType::ServerlessCache,
+            "serverless-cache-snapshot" => SourceType::ServerlessCacheSnapshot,
+            "user" => SourceType::User,
+            "user-group" => SourceType::UserGroup,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        SourceType::from(value.as_str())
+    }
+}
+
+impl From<SourceType> for String {
+    fn from(value: SourceType) -> String {
+        match value {
+            SourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct StringDeserializer;
 impl StringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6629,10 +10320,13 @@ pub struct Subnet {
     pub subnet_identifier: Option<String>,
 }
 
-struct SubnetDeserializer;
+pub(crate) struct SubnetDeserializer;
 impl SubnetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Subnet, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Subnet, XmlParseError> {
         deserialize_elements::<_, Subnet, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "SubnetAvailabilityZone" => {
@@ -6663,10 +10357,10 @@ impl SubnetIdentifierListSerializer {
     }
 }
 
-struct SubnetListDeserializer;
+pub(crate) struct SubnetListDeserializer;
 impl SubnetListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Subnet>, XmlParseError> {
@@ -6680,12 +10374,15 @@ impl SubnetListDeserializer {
         })
     }
 }
-struct TStampDeserializer;
+pub(crate) struct TStampDeserializer;
 impl TStampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6700,10 +10397,13 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -6737,10 +10437,10 @@ impl TagSerializer {
     }
 }
 
-struct TagListDeserializer;
+pub(crate) struct TagListDeserializer;
 impl TagListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -6773,10 +10473,10 @@ pub struct TagListMessage {
     pub tag_list: Option<Vec<Tag>>,
 }
 
-struct TagListMessageDeserializer;
+pub(crate) struct TagListMessageDeserializer;
 impl TagListMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TagListMessage, XmlParseError> {
@@ -6801,6 +10501,46 @@ pub struct TestFailoverMessage {
     pub replication_group_id: String,
 }
 
+impl TestFailoverMessage {
+    /// Returns a builder for `TestFailoverMessage`, checking its required members when built.
+    pub fn builder() -> TestFailoverMessageBuilder {
+        TestFailoverMessageBuilder::default()
+    }
+}
+
+/// Builds a [`TestFailoverMessage`](struct.TestFailoverMessage.html).
+#[derive(Default, Debug)]
+pub struct TestFailoverMessageBuilder {
+    node_group_id: Option<String>,
+    replication_group_id: Option<String>,
+}
+
+impl TestFailoverMessageBuilder {
+    /// Sets the [`node_group_id`](struct.TestFailoverMessage.html#structfield.node_group_id) member.
+    pub fn node_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.node_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_group_id`](struct.TestFailoverMessage.html#structfield.replication_group_id) member.
+    pub fn replication_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestFailoverMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TestFailoverMessage, ::rusoto_core::BuildError> {
+        Ok(TestFailoverMessage {
+            node_group_id: self
+                .node_group_id
+                .ok_or(::rusoto_core::BuildError::missing("node_group_id"))?,
+            replication_group_id: self
+                .replication_group_id
+                .ok_or(::rusoto_core::BuildError::missing("replication_group_id"))?,
+        })
+    }
+}
+
 /// Serialize `TestFailoverMessage` contents to a `SignedRequest`.
 struct TestFailoverMessageSerializer;
 impl TestFailoverMessageSerializer {
@@ -6823,10 +10563,10 @@ pub struct TestFailoverResult {
     pub replication_group: Option<ReplicationGroup>,
 }
 
-struct TestFailoverResultDeserializer;
+pub(crate) struct TestFailoverResultDeserializer;
 impl TestFailoverResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TestFailoverResult, XmlParseError> {
@@ -6848,9 +10588,9 @@ impl TestFailoverResultDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct TimeRangeFilter {
     /// <p>The end time of the time range filter</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>The start time of the time range filter</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
 }
 
 /// Serialize `TimeRangeFilter` contents to a `SignedRequest`.
@@ -6884,10 +10624,10 @@ pub struct UnprocessedUpdateAction {
     pub service_update_name: Option<String>,
 }
 
-struct UnprocessedUpdateActionDeserializer;
+pub(crate) struct UnprocessedUpdateActionDeserializer;
 impl UnprocessedUpdateActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UnprocessedUpdateAction, XmlParseError> {
@@ -6920,10 +10660,10 @@ impl UnprocessedUpdateActionDeserializer {
         )
     }
 }
-struct UnprocessedUpdateActionListDeserializer;
+pub(crate) struct UnprocessedUpdateActionListDeserializer;
 impl UnprocessedUpdateActionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<UnprocessedUpdateAction>, XmlParseError> {
@@ -6954,29 +10694,29 @@ pub struct UpdateAction {
     /// <p>The unique ID of the service update</p>
     pub service_update_name: Option<String>,
     /// <p>The recommended date to apply the service update to ensure compliance. For information on compliance, see <a href="https://docs.aws.amazon.com/AmazonElastiCache/latest/red-ug/elasticache-compliance.html#elasticache-compliance-self-service">Self-Service Security Updates for Compliance</a>.</p>
-    pub service_update_recommended_apply_by_date: Option<String>,
+    pub service_update_recommended_apply_by_date: Option<IsoTimestamp>,
     /// <p>The date the update is first available</p>
-    pub service_update_release_date: Option<String>,
+    pub service_update_release_date: Option<IsoTimestamp>,
     /// <p>The severity of the service update</p>
-    pub service_update_severity: Option<String>,
+    pub service_update_severity: Option<ServiceUpdateSeverity>,
     /// <p>The status of the service update</p>
-    pub service_update_status: Option<String>,
+    pub service_update_status: Option<ServiceUpdateStatus>,
     /// <p>Reflects the nature of the service update </p>
-    pub service_update_type: Option<String>,
+    pub service_update_type: Option<ServiceUpdateType>,
     /// <p>If yes, all nodes in the replication group have been updated by the recommended apply-by date. If no, at least one node in the replication group have not been updated by the recommended apply-by date. If N/A, the replication group was created after the recommended apply-by date.</p>
-    pub sla_met: Option<String>,
+    pub sla_met: Option<SlaMet>,
     /// <p>The date that the service update is available to a replication group</p>
-    pub update_action_available_date: Option<String>,
+    pub update_action_available_date: Option<IsoTimestamp>,
     /// <p>The status of the update action</p>
-    pub update_action_status: Option<String>,
+    pub update_action_status: Option<UpdateActionStatus>,
     /// <p>The date when the UpdateActionStatus was last modified</p>
-    pub update_action_status_modified_date: Option<String>,
+    pub update_action_status_modified_date: Option<IsoTimestamp>,
 }
 
-struct UpdateActionDeserializer;
+pub(crate) struct UpdateActionDeserializer;
 impl UpdateActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateAction, XmlParseError> {
@@ -7069,10 +10809,10 @@ impl UpdateActionDeserializer {
         })
     }
 }
-struct UpdateActionListDeserializer;
+pub(crate) struct UpdateActionListDeserializer;
 impl UpdateActionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<UpdateAction>, XmlParseError> {
@@ -7097,10 +10837,10 @@ pub struct UpdateActionResultsMessage {
     pub unprocessed_update_actions: Option<Vec<UnprocessedUpdateAction>>,
 }
 
-struct UpdateActionResultsMessageDeserializer;
+pub(crate) struct UpdateActionResultsMessageDeserializer;
 impl UpdateActionResultsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateActionResultsMessage, XmlParseError> {
@@ -7132,22 +10872,112 @@ impl UpdateActionResultsMessageDeserializer {
         )
     }
 }
-struct UpdateActionStatusDeserializer;
+pub(crate) struct UpdateActionStatusDeserializer;
 impl UpdateActionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<UpdateActionStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 
+/// <p>The status of the update action.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateActionStatus {
+    /// <p><code>not-applied</code></p>
+    NotApplied,
+    /// <p><code>waiting-to-start</code></p>
+    WaitingToStart,
+    /// <p><code>in-progress</code></p>
+    InProgress,
+    /// <p><code>stopping</code></p>
+    Stopping,
+    /// <p><code>stopped</code></p>
+    Stopped,
+    /// <p><code>complete</code></p>
+    Complete,
+    /// <p><code>scheduling</code></p>
+    Scheduling,
+    /// <p><code>scheduled</code></p>
+    Scheduled,
+    /// <p><code>not-applicable</code></p>
+    NotApplicable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UpdateActionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateActionStatus::NotApplied => "not-applied",
+            UpdateActionStatus::WaitingToStart => "waiting-to-start",
+            UpdateActionStatus::InProgress => "in-progress",
+            UpdateActionStatus::Stopping => "stopping",
+            UpdateActionStatus::Stopped => "stopped",
+            UpdateActionStatus::Complete => "complete",
+            UpdateActionStatus::Scheduling => "scheduling",
+            UpdateActionStatus::Scheduled => "scheduled",
+            UpdateActionStatus::NotApplicable => "not-applicable",
+            UpdateActionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UpdateActionStatus {
+    fn from(value: &'a str) -> UpdateActionStatus {
+        match value {
+            "not-applied" => UpdateActionStatus::NotApplied,
+            "waiting-to-start" => UpdateActionStatus::WaitingToStart,
+            "in-progress" => UpdateActionStatus::InProgress,
+            "stopping" => UpdateActionStatus::Stopping,
+            "stopped" => UpdateActionStatus::Stopped,
+            "complete" => UpdateActionStatus::Complete,
+            "scheduling" => UpdateActionStatus::Scheduling,
+            "scheduled" => UpdateActionStatus::Scheduled,
+            "not-applicable" => UpdateActionStatus::NotApplicable,
+            _ => UpdateActionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateActionStatus {
+    fn from(value: String) -> UpdateActionStatus {
+        UpdateActionStatus::from(value.as_str())
+    }
+}
+
+impl From<UpdateActionStatus> for String {
+    fn from(value: UpdateActionStatus) -> String {
+        match value {
+            UpdateActionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateActionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateActionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// Serialize `UpdateActionStatusList` contents to a `SignedRequest`.
 struct UpdateActionStatusListSerializer;
 impl UpdateActionStatusListSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<UpdateActionStatus>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -7163,10 +10993,10 @@ pub struct UpdateActionsMessage {
     pub update_actions: Option<Vec<UpdateAction>>,
 }
 
-struct UpdateActionsMessageDeserializer;
+pub(crate) struct UpdateActionsMessageDeserializer;
 impl UpdateActionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateActionsMessage, XmlParseError> {
@@ -11319,21 +15149,340 @@ pub trait ElastiCache {
         input: TestFailoverMessage,
     ) -> RusotoFuture<TestFailoverResult, TestFailoverError>;
 }
-/// A client for the Amazon ElastiCache API.
-#[derive(Clone)]
-pub struct ElastiCacheClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl ElastiCacheClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> ElastiCacheClient {
-        Self::new_with_client(Client::shared(), region)
-    }
-
+impl<T: ?Sized + ElastiCache> ElastiCache for ::std::sync::Arc<T> {
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<TagListMessage, AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn authorize_cache_security_group_ingress(
+        &self,
+        input: AuthorizeCacheSecurityGroupIngressMessage,
+    ) -> RusotoFuture<
+        AuthorizeCacheSecurityGroupIngressResult,
+        AuthorizeCacheSecurityGroupIngressError,
+    > {
+        (**self).authorize_cache_security_group_ingress(input)
+    }
+    fn batch_apply_update_action(
+        &self,
+        input: BatchApplyUpdateActionMessage,
+    ) -> RusotoFuture<UpdateActionResultsMessage, BatchApplyUpdateActionError> {
+        (**self).batch_apply_update_action(input)
+    }
+    fn batch_stop_update_action(
+        &self,
+        input: BatchStopUpdateActionMessage,
+    ) -> RusotoFuture<UpdateActionResultsMessage, BatchStopUpdateActionError> {
+        (**self).batch_stop_update_action(input)
+    }
+    fn copy_snapshot(
+        &self,
+        input: CopySnapshotMessage,
+    ) -> RusotoFuture<CopySnapshotResult, CopySnapshotError> {
+        (**self).copy_snapshot(input)
+    }
+    fn create_cache_cluster(
+        &self,
+        input: CreateCacheClusterMessage,
+    ) -> RusotoFuture<CreateCacheClusterResult, CreateCacheClusterError> {
+        (**self).create_cache_cluster(input)
+    }
+    fn create_cache_parameter_group(
+        &self,
+        input: CreateCacheParameterGroupMessage,
+    ) -> RusotoFuture<CreateCacheParameterGroupResult, CreateCacheParameterGroupError> {
+        (**self).create_cache_parameter_group(input)
+    }
+    fn create_cache_security_group(
+        &self,
+        input: CreateCacheSecurityGroupMessage,
+    ) -> RusotoFuture<CreateCacheSecurityGroupResult, CreateCacheSecurityGroupError> {
+        (**self).create_cache_security_group(input)
+    }
+    fn create_cache_subnet_group(
+        &self,
+        input: CreateCacheSubnetGroupMessage,
+    ) -> RusotoFuture<CreateCacheSubnetGroupResult, CreateCacheSubnetGroupError> {
+        (**self).create_cache_subnet_group(input)
+    }
+    fn create_replication_group(
+        &self,
+        input: CreateReplicationGroupMessage,
+    ) -> RusotoFuture<CreateReplicationGroupResult, CreateReplicationGroupError> {
+        (**self).create_replication_group(input)
+    }
+    fn create_snapshot(
+        &self,
+        input: CreateSnapshotMessage,
+    ) -> RusotoFuture<CreateSnapshotResult, CreateSnapshotError> {
+        (**self).create_snapshot(input)
+    }
+    fn decrease_replica_count(
+        &self,
+        input: DecreaseReplicaCountMessage,
+    ) -> RusotoFuture<DecreaseReplicaCountResult, DecreaseReplicaCountError> {
+        (**self).decrease_replica_count(input)
+    }
+    fn delete_cache_cluster(
+        &self,
+        input: DeleteCacheClusterMessage,
+    ) -> RusotoFuture<DeleteCacheClusterResult, DeleteCacheClusterError> {
+        (**self).delete_cache_cluster(input)
+    }
+    fn delete_cache_parameter_group(
+        &self,
+        input: DeleteCacheParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheParameterGroupError> {
+        (**self).delete_cache_parameter_group(input)
+    }
+    fn delete_cache_security_group(
+        &self,
+        input: DeleteCacheSecurityGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheSecurityGroupError> {
+        (**self).delete_cache_security_group(input)
+    }
+    fn delete_cache_subnet_group(
+        &self,
+        input: DeleteCacheSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteCacheSubnetGroupError> {
+        (**self).delete_cache_subnet_group(input)
+    }
+    fn delete_replication_group(
+        &self,
+        input: DeleteReplicationGroupMessage,
+    ) -> RusotoFuture<DeleteReplicationGroupResult, DeleteReplicationGroupError> {
+        (**self).delete_replication_group(input)
+    }
+    fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotMessage,
+    ) -> RusotoFuture<DeleteSnapshotResult, DeleteSnapshotError> {
+        (**self).delete_snapshot(input)
+    }
+    fn describe_cache_clusters(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> RusotoFuture<CacheClusterMessage, DescribeCacheClustersError> {
+        (**self).describe_cache_clusters(input)
+    }
+    fn describe_cache_engine_versions(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> RusotoFuture<CacheEngineVersionMessage, DescribeCacheEngineVersionsError> {
+        (**self).describe_cache_engine_versions(input)
+    }
+    fn describe_cache_parameter_groups(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> RusotoFuture<CacheParameterGroupsMessage, DescribeCacheParameterGroupsError> {
+        (**self).describe_cache_parameter_groups(input)
+    }
+    fn describe_cache_parameters(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> RusotoFuture<CacheParameterGroupDetails, DescribeCacheParametersError> {
+        (**self).describe_cache_parameters(input)
+    }
+    fn describe_cache_security_groups(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> RusotoFuture<CacheSecurityGroupMessage, DescribeCacheSecurityGroupsError> {
+        (**self).describe_cache_security_groups(input)
+    }
+    fn describe_cache_subnet_groups(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> RusotoFuture<CacheSubnetGroupMessage, DescribeCacheSubnetGroupsError> {
+        (**self).describe_cache_subnet_groups(input)
+    }
+    fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> RusotoFuture<DescribeEngineDefaultParametersResult, DescribeEngineDefaultParametersError>
+    {
+        (**self).describe_engine_default_parameters(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_replication_groups(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> RusotoFuture<ReplicationGroupMessage, DescribeReplicationGroupsError> {
+        (**self).describe_replication_groups(input)
+    }
+    fn describe_reserved_cache_nodes(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> RusotoFuture<ReservedCacheNodeMessage, DescribeReservedCacheNodesError> {
+        (**self).describe_reserved_cache_nodes(input)
+    }
+    fn describe_reserved_cache_nodes_offerings(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> RusotoFuture<ReservedCacheNodesOfferingMessage, DescribeReservedCacheNodesOfferingsError>
+    {
+        (**self).describe_reserved_cache_nodes_offerings(input)
+    }
+    fn describe_service_updates(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> RusotoFuture<ServiceUpdatesMessage, DescribeServiceUpdatesError> {
+        (**self).describe_service_updates(input)
+    }
+    fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> RusotoFuture<DescribeSnapshotsListMessage, DescribeSnapshotsError> {
+        (**self).describe_snapshots(input)
+    }
+    fn describe_update_actions(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> RusotoFuture<UpdateActionsMessage, DescribeUpdateActionsError> {
+        (**self).describe_update_actions(input)
+    }
+    fn increase_replica_count(
+        &self,
+        input: IncreaseReplicaCountMessage,
+    ) -> RusotoFuture<IncreaseReplicaCountResult, IncreaseReplicaCountError> {
+        (**self).increase_replica_count(input)
+    }
+    fn list_allowed_node_type_modifications(
+        &self,
+        input: ListAllowedNodeTypeModificationsMessage,
+    ) -> RusotoFuture<AllowedNodeTypeModificationsMessage, ListAllowedNodeTypeModificationsError>
+    {
+        (**self).list_allowed_node_type_modifications(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_cache_cluster(
+        &self,
+        input: ModifyCacheClusterMessage,
+    ) -> RusotoFuture<ModifyCacheClusterResult, ModifyCacheClusterError> {
+        (**self).modify_cache_cluster(input)
+    }
+    fn modify_cache_parameter_group(
+        &self,
+        input: ModifyCacheParameterGroupMessage,
+    ) -> RusotoFuture<CacheParameterGroupNameMessage, ModifyCacheParameterGroupError> {
+        (**self).modify_cache_parameter_group(input)
+    }
+    fn modify_cache_subnet_group(
+        &self,
+        input: ModifyCacheSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyCacheSubnetGroupResult, ModifyCacheSubnetGroupError> {
+        (**self).modify_cache_subnet_group(input)
+    }
+    fn modify_replication_group(
+        &self,
+        input: ModifyReplicationGroupMessage,
+    ) -> RusotoFuture<ModifyReplicationGroupResult, ModifyReplicationGroupError> {
+        (**self).modify_replication_group(input)
+    }
+    fn modify_replication_group_shard_configuration(
+        &self,
+        input: ModifyReplicationGroupShardConfigurationMessage,
+    ) -> RusotoFuture<
+        ModifyReplicationGroupShardConfigurationResult,
+        ModifyReplicationGroupShardConfigurationError,
+    > {
+        (**self).modify_replication_group_shard_configuration(input)
+    }
+    fn purchase_reserved_cache_nodes_offering(
+        &self,
+        input: PurchaseReservedCacheNodesOfferingMessage,
+    ) -> RusotoFuture<
+        PurchaseReservedCacheNodesOfferingResult,
+        PurchaseReservedCacheNodesOfferingError,
+    > {
+        (**self).purchase_reserved_cache_nodes_offering(input)
+    }
+    fn reboot_cache_cluster(
+        &self,
+        input: RebootCacheClusterMessage,
+    ) -> RusotoFuture<RebootCacheClusterResult, RebootCacheClusterError> {
+        (**self).reboot_cache_cluster(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<TagListMessage, RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_cache_parameter_group(
+        &self,
+        input: ResetCacheParameterGroupMessage,
+    ) -> RusotoFuture<CacheParameterGroupNameMessage, ResetCacheParameterGroupError> {
+        (**self).reset_cache_parameter_group(input)
+    }
+    fn revoke_cache_security_group_ingress(
+        &self,
+        input: RevokeCacheSecurityGroupIngressMessage,
+    ) -> RusotoFuture<RevokeCacheSecurityGroupIngressResult, RevokeCacheSecurityGroupIngressError>
+    {
+        (**self).revoke_cache_security_group_ingress(input)
+    }
+    fn test_failover(
+        &self,
+        input: TestFailoverMessage,
+    ) -> RusotoFuture<TestFailoverResult, TestFailoverError> {
+        (**self).test_failover(input)
+    }
+}
+/// The error codes Amazon ElastiCache retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
+/// A client for the Amazon ElastiCache API.
+#[derive(Clone)]
+pub struct ElastiCacheClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl ElastiCacheClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> ElastiCacheClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ELASTICACHE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ElastiCacheClient {
+        Self::new(region::Region::from_env_for_service("ELASTICACHE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `elasticache`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ElastiCacheClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("elasticache", "ELASTICACHE"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -11352,7 +15501,54 @@ impl ElastiCacheClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ElastiCacheClient {
-        ElastiCacheClient { client, region }
+        ElastiCacheClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "elasticache")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "elasticache",
+            "elasticache",
+            "ELASTICACHE",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ElastiCache` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ElastiCache + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ElastiCacheClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon ElastiCache"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "elasticache"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ElastiCacheClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Elastic Beanstalk clients.
+//!
+//! Makes a single read-only `ListAvailableSolutionStacks` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_elasticbeanstalk::{ElasticBeanstalk, ElasticBeanstalkClient};
+
+fn main() {
+    let client = ElasticBeanstalkClient::from_env();
+    match client.list_available_solution_stacks().sync() {
+        Ok(_) => println!("ListAvailableSolutionStacks succeeded"),
+        Err(error) => {
+            eprintln!("ListAvailableSolutionStacks failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
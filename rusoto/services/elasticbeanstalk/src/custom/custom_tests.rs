@@ -0,0 +1,63 @@
+extern crate rusoto_mock;
+
+use std::sync::{Arc, Mutex};
+
+use crate::deploy::{DeployError, DeployExt, Deployment};
+use crate::generated::ElasticBeanstalkClient;
+
+use self::rusoto_mock::*;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::waiter::WaiterConfig;
+use rusoto_core::{Region, RusotoError};
+
+/// The `Action` parameter of an Elastic Beanstalk request.
+fn action(request: &SignedRequest) -> String {
+    let payload = match request.payload {
+        Some(SignedRequestPayload::Buffer(ref buffer)) => String::from_utf8_lossy(buffer),
+        _ => panic!("request payload is not a buffer"),
+    };
+    payload
+        .split('&')
+        .find(|param| param.starts_with("Action="))
+        .map(|param| param["Action=".len()..].to_owned())
+        .unwrap()
+}
+
+#[test]
+fn deploying_a_missing_version_requires_a_source_bundle() {
+    let actions = Arc::new(Mutex::new(Vec::new()));
+    let recorded = actions.clone();
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(
+            r#"<DescribeApplicationVersionsResponse xmlns="http://elasticbeanstalk.amazonaws.com/docs/2010-12-01/">
+                <DescribeApplicationVersionsResult>
+                    <ApplicationVersions/>
+                </DescribeApplicationVersionsResult>
+                <ResponseMetadata>
+                    <RequestId>5f1e9d2a-0c7b-11e9-8e4a-example</RequestId>
+                </ResponseMetadata>
+            </DescribeApplicationVersionsResponse>"#,
+        )
+        .with_request_checker(move |request: &SignedRequest| {
+            recorded.lock().unwrap().push(action(request));
+        });
+    let client = ElasticBeanstalkClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let deployment = Deployment {
+        application_name: "my-app".to_owned(),
+        environment_name: "my-app-prod".to_owned(),
+        version_label: "v42".to_owned(),
+        ..Default::default()
+    };
+
+    let result = client
+        .deploy_version_and_wait(deployment, WaiterConfig::default(), |_| {})
+        .sync();
+    match result {
+        Err(RusotoError::Service(DeployError::MissingSourceBundle(_))) => {}
+        other => panic!("expected a missing source bundle, got {:?}", other),
+    }
+    assert_eq!(
+        *actions.lock().unwrap(),
+        vec!["DescribeApplicationVersions"]
+    );
+}
@@ -0,0 +1,594 @@
+//! Deploying application versions and following their progress.
+//!
+//! `deploy_version_and_wait` creates an application version, unless it already exists, points an
+//! environment at it and polls the environment until it has finished updating. Environment events
+//! emitted in the meantime, such as instances being deployed to or health checks failing, are
+//! passed to a callback as they come in, oldest first.
+//!
+//! A finished update doesn't mean the deployment succeeded: Elastic Beanstalk reports an
+//! environment as `Ready` once it stops updating, including after a failed deployment was rolled
+//! back. The outcome therefore also checks the environment's health and the version it runs.
+//!
+//! With the `upload` feature enabled, `upload_and_deploy_version` uploads the source bundle to S3
+//! first.
+
+use std::error::Error;
+use std::fmt;
+
+use futures::future::{self, Either, Loop};
+use futures::Future;
+use rusoto_core::timestamp::{self, IsoTimestamp};
+use rusoto_core::waiter::{sleep, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+#[cfg(feature = "upload")]
+use rusoto_s3::{PutObjectError, PutObjectRequest, S3};
+
+#[cfg(feature = "upload")]
+use crate::generated::CreateStorageLocationError;
+use crate::generated::{
+    CreateApplicationVersionError, CreateApplicationVersionMessage,
+    DescribeApplicationVersionsError, DescribeApplicationVersionsMessage,
+    DescribeEnvironmentsError, DescribeEnvironmentsMessage, DescribeEventsError,
+    DescribeEventsMessage, ElasticBeanstalk, EnvironmentDescription, EnvironmentHealth,
+    EnvironmentHealthStatus, EnvironmentStatus, EventDescription, S3Location,
+    UpdateEnvironmentError, UpdateEnvironmentMessage,
+};
+
+/// The application version to deploy, and where.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Deployment {
+    /// The application the version belongs to
+    pub application_name: String,
+    /// The environment to deploy the version to
+    pub environment_name: String,
+    /// The label of the version
+    pub version_label: String,
+    /// A description of the version, used when it's created
+    pub description: Option<String>,
+    /// Where the source bundle of the version is stored. Only needed if the version doesn't
+    /// exist yet; when uploading, where to upload the bundle to.
+    pub source_bundle: Option<S3Location>,
+}
+
+/// How a deployment ended.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DeploymentOutcome {
+    /// The environment runs the new version and is healthy.
+    Ready(EnvironmentDescription),
+    /// The environment finished updating but is unhealthy, or doesn't run the new version
+    /// because the deployment failed and was rolled back.
+    Degraded(EnvironmentDescription),
+}
+
+impl DeploymentOutcome {
+    /// Whether the environment runs the new version and is healthy.
+    pub fn is_ready(&self) -> bool {
+        match *self {
+            DeploymentOutcome::Ready(_) => true,
+            DeploymentOutcome::Degraded(_) => false,
+        }
+    }
+
+    /// The environment as it was when the update finished.
+    pub fn environment(&self) -> &EnvironmentDescription {
+        match *self {
+            DeploymentOutcome::Ready(ref environment)
+            | DeploymentOutcome::Degraded(ref environment) => environment,
+        }
+    }
+}
+
+/// Errors returned while deploying an application version.
+#[derive(Debug, PartialEq)]
+pub enum DeployError {
+    /// Checking whether the version exists failed.
+    DescribeApplicationVersions(DescribeApplicationVersionsError),
+    /// The version doesn't exist and no source bundle was given to create it from.
+    MissingSourceBundle(String),
+    /// Looking up the Elastic Beanstalk storage bucket failed.
+    #[cfg(feature = "upload")]
+    CreateStorageLocation(CreateStorageLocationError),
+    /// Uploading the source bundle failed.
+    #[cfg(feature = "upload")]
+    PutObject(PutObjectError),
+    /// Creating the version failed.
+    CreateApplicationVersion(CreateApplicationVersionError),
+    /// Updating the environment failed.
+    UpdateEnvironment(UpdateEnvironmentError),
+    /// Describing the environment's events failed.
+    DescribeEvents(DescribeEventsError),
+    /// Describing the environment failed.
+    DescribeEnvironments(DescribeEnvironmentsError),
+    /// The environment no longer exists.
+    EnvironmentNotFound(String),
+    /// The environment did not finish updating within the configured number of attempts.
+    TimedOut(String),
+}
+
+impl fmt::Display for DeployError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for DeployError {
+    fn description(&self) -> &str {
+        match *self {
+            DeployError::DescribeApplicationVersions(ref cause) => cause.description(),
+            DeployError::MissingSourceBundle(ref cause) => cause,
+            #[cfg(feature = "upload")]
+            DeployError::CreateStorageLocation(ref cause) => cause.description(),
+            #[cfg(feature = "upload")]
+            DeployError::PutObject(ref cause) => cause.description(),
+            DeployError::CreateApplicationVersion(ref cause) => cause.description(),
+            DeployError::UpdateEnvironment(ref cause) => cause.description(),
+            DeployError::DescribeEvents(ref cause) => cause.description(),
+            DeployError::DescribeEnvironments(ref cause) => cause.description(),
+            DeployError::EnvironmentNotFound(ref cause) => cause,
+            DeployError::TimedOut(ref cause) => cause,
+        }
+    }
+}
+
+/// Event dates are ISO 8601 timestamps, with or without milliseconds. Normalizing them to always
+/// include milliseconds makes them sort chronologically.
+fn sortable_date(date: &IsoTimestamp) -> String {
+    let date = timestamp::to_iso8601(date);
+    let date = date.trim_end_matches('Z');
+    if date.contains('.') {
+        date.to_owned()
+    } else {
+        format!("{}.000", date)
+    }
+}
+
+/// Keeps track of the events already reported.
+///
+/// `DescribeEvents` returns the events since a given date, newest first. Events are requested
+/// since the date of the last one seen, which is inclusive, so the events seen at that date are
+/// remembered to skip them the next time.
+#[derive(Debug)]
+struct EventTail {
+    since: Option<IsoTimestamp>,
+    seen: Vec<(String, String)>,
+}
+
+impl EventTail {
+    fn new(since: Option<IsoTimestamp>) -> EventTail {
+        EventTail {
+            since,
+            seen: Vec::new(),
+        }
+    }
+
+    fn request(&self, environment_name: &str) -> DescribeEventsMessage {
+        DescribeEventsMessage {
+            environment_name: Some(environment_name.to_owned()),
+            start_time: self.since.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the events which haven't been reported yet, oldest first.
+    fn new_events(&mut self, mut events: Vec<EventDescription>) -> Vec<EventDescription> {
+        let mut since = self.since.as_ref().map(sortable_date);
+        events.reverse();
+        events.sort_by_key(|event| event.event_date.as_ref().map(sortable_date));
+
+        let mut new_events = Vec::new();
+        for event in events {
+            let date = event
+                .event_date
+                .as_ref()
+                .map(sortable_date)
+                .unwrap_or_default();
+            let key = (
+                event.message.clone().unwrap_or_default(),
+                event.request_id.clone().unwrap_or_default(),
+            );
+            match since {
+                Some(ref since) if date < *since => continue,
+                Some(ref since) if date == *since => {
+                    if self.seen.contains(&key) {
+                        continue;
+                    }
+                    self.seen.push(key);
+                }
+                _ => {
+                    since = Some(date);
+                    self.since = event.event_date.clone();
+                    self.seen = vec![key];
+                }
+            }
+            new_events.push(event);
+        }
+        new_events
+    }
+}
+
+/// Decides whether the update of `environment` has finished, and how.
+fn deployment_outcome(
+    environment: EnvironmentDescription,
+    version_label: &str,
+) -> Option<DeploymentOutcome> {
+    if environment.status != Some(EnvironmentStatus::Ready) {
+        return None;
+    }
+    let runs_version =
+        environment.version_label.as_ref().map(String::as_str) == Some(version_label);
+    let unhealthy = environment.health == Some(EnvironmentHealth::Red)
+        || environment.health_status == Some(EnvironmentHealthStatus::Degraded)
+        || environment.health_status == Some(EnvironmentHealthStatus::Severe);
+    if runs_version && !unhealthy {
+        Some(DeploymentOutcome::Ready(environment))
+    } else {
+        Some(DeploymentOutcome::Degraded(environment))
+    }
+}
+
+/// Extension methods for deploying application versions.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::waiter::WaiterConfig;
+/// use rusoto_core::Region;
+/// use rusoto_elasticbeanstalk::deploy::{DeployExt, Deployment};
+/// use rusoto_elasticbeanstalk::{ElasticBeanstalkClient, S3Location};
+///
+/// let client = ElasticBeanstalkClient::new(Region::UsEast1);
+/// let deployment = Deployment {
+///     application_name: "my-app".to_owned(),
+///     environment_name: "my-app-prod".to_owned(),
+///     version_label: "v42".to_owned(),
+///     source_bundle: Some(S3Location {
+///         s3_bucket: Some("my-bundles".to_owned()),
+///         s3_key: Some("my-app/v42.zip".to_owned()),
+///     }),
+///     ..Default::default()
+/// };
+/// let outcome = client
+///     .deploy_version_and_wait(deployment, WaiterConfig::default(), |event| {
+///         println!("{}", event.message.as_ref().map_or("", |m| m))
+///     })
+///     .sync()
+///     .unwrap();
+/// assert!(outcome.is_ready());
+/// ```
+pub trait DeployExt: ElasticBeanstalk + Clone + Send + Sized + 'static {
+    /// Creates the application version unless it already exists, deploys it to the environment
+    /// and waits for the environment to finish updating, calling `on_event` with every
+    /// environment event emitted in the meantime.
+    fn deploy_version_and_wait<F>(
+        &self,
+        deployment: Deployment,
+        config: WaiterConfig,
+        on_event: F,
+    ) -> RusotoFuture<DeploymentOutcome, DeployError>
+    where
+        F: FnMut(&EventDescription) + Send + 'static,
+    {
+        let create_client = self.clone();
+        let update_client = self.clone();
+        let wait_client = self.clone();
+        let Deployment {
+            application_name,
+            environment_name,
+            version_label,
+            description,
+            source_bundle,
+        } = deployment;
+        let wait_version_label = version_label.clone();
+        let update = UpdateEnvironmentMessage {
+            environment_name: Some(environment_name.clone()),
+            version_label: Some(version_label.clone()),
+            ..Default::default()
+        };
+
+        let future = self
+            .describe_application_versions(DescribeApplicationVersionsMessage {
+                application_name: Some(application_name.clone()),
+                version_labels: Some(vec![version_label.clone()]),
+                ..Default::default()
+            })
+            .map_err(|e| e.map_service(DeployError::DescribeApplicationVersions))
+            .and_then(move |versions| {
+                if versions
+                    .application_versions
+                    .map_or(false, |v| !v.is_empty())
+                {
+                    return Either::A(future::ok(()));
+                }
+                if source_bundle.is_none() {
+                    return Either::A(future::err(RusotoError::Service(
+                        DeployError::MissingSourceBundle(format!(
+                            "Version {} of {} doesn't exist and no source bundle was given",
+                            version_label, application_name
+                        )),
+                    )));
+                }
+                let create = CreateApplicationVersionMessage {
+                    application_name,
+                    version_label,
+                    description,
+                    source_bundle,
+                    ..Default::default()
+                };
+                Either::B(
+                    create_client
+                        .create_application_version(create)
+                        .map(|_| ())
+                        .map_err(|e| e.map_service(DeployError::CreateApplicationVersion)),
+                )
+            })
+            .and_then(move |_| {
+                update_client
+                    .update_environment(update)
+                    .map_err(|e| e.map_service(DeployError::UpdateEnvironment))
+            })
+            .and_then(move |environment| {
+                wait_client.wait_for_deployment(
+                    &environment_name,
+                    &wait_version_label,
+                    environment.date_updated,
+                    config,
+                    on_event,
+                )
+            });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Polls an environment until it has finished updating to `version_label`, calling
+    /// `on_event` with the environment events emitted since `since`, an ISO 8601 date.
+    fn wait_for_deployment<F>(
+        &self,
+        environment_name: &str,
+        version_label: &str,
+        since: Option<IsoTimestamp>,
+        config: WaiterConfig,
+        on_event: F,
+    ) -> RusotoFuture<DeploymentOutcome, DeployError>
+    where
+        F: FnMut(&EventDescription) + Send + 'static,
+    {
+        let state = (self.clone(), EventTail::new(since), on_event, 1);
+        let environment_name = environment_name.to_owned();
+        let version_label = version_label.to_owned();
+
+        let future = future::loop_fn(state, move |(client, mut tail, mut on_event, attempts)| {
+            let config = config.clone();
+            let environment_name = environment_name.clone();
+            let version_label = version_label.clone();
+            let describe_client = client.clone();
+
+            client
+                .describe_events(tail.request(&environment_name))
+                .map_err(|e| e.map_service(DeployError::DescribeEvents))
+                .and_then(move |events| {
+                    for event in tail.new_events(events.events.unwrap_or_default()) {
+                        on_event(&event);
+                    }
+                    describe_client
+                        .describe_environments(DescribeEnvironmentsMessage {
+                            environment_names: Some(vec![environment_name.clone()]),
+                            ..Default::default()
+                        })
+                        .map_err(|e| e.map_service(DeployError::DescribeEnvironments))
+                        .and_then(move |environments| {
+                            let environment = environments
+                                .environments
+                                .and_then(|environments| environments.into_iter().next());
+                            let error = match environment {
+                                None => DeployError::EnvironmentNotFound(format!(
+                                    "Environment {} doesn't exist",
+                                    environment_name
+                                )),
+                                Some(environment) => {
+                                    match deployment_outcome(environment, &version_label) {
+                                        Some(outcome) => {
+                                            return Either::A(future::ok(Loop::Break(outcome)))
+                                        }
+                                        None if attempts >= config.max_attempts => {
+                                            DeployError::TimedOut(format!(
+                                                "Environment {} did not finish updating",
+                                                environment_name
+                                            ))
+                                        }
+                                        None => {
+                                            return Either::B(sleep(config.delay).map(move |_| {
+                                                Loop::Continue((
+                                                    client,
+                                                    tail,
+                                                    on_event,
+                                                    attempts + 1,
+                                                ))
+                                            }))
+                                        }
+                                    }
+                                }
+                            };
+                            Either::A(future::err(RusotoError::Service(error)))
+                        })
+                })
+        });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Uploads `bundle` as the source bundle of the version, then deploys it as
+    /// `deploy_version_and_wait` does.
+    ///
+    /// The bundle is uploaded to `deployment.source_bundle` if set, and otherwise to
+    /// `<application>/<version>.zip` in the storage bucket Elastic Beanstalk creates for the
+    /// account and region. Nothing is uploaded if the version already exists.
+    ///
+    /// Only available with the `upload` feature enabled.
+    #[cfg(feature = "upload")]
+    fn upload_and_deploy_version<S, F>(
+        &self,
+        s3: &S,
+        bundle: Vec<u8>,
+        deployment: Deployment,
+        config: WaiterConfig,
+        on_event: F,
+    ) -> RusotoFuture<DeploymentOutcome, DeployError>
+    where
+        S: S3 + Clone + Send + 'static,
+        F: FnMut(&EventDescription) + Send + 'static,
+    {
+        let storage_client = self.clone();
+        let deploy_client = self.clone();
+        let s3 = s3.clone();
+
+        let future = self
+            .describe_application_versions(DescribeApplicationVersionsMessage {
+                application_name: Some(deployment.application_name.clone()),
+                version_labels: Some(vec![deployment.version_label.clone()]),
+                ..Default::default()
+            })
+            .map_err(|e| e.map_service(DeployError::DescribeApplicationVersions))
+            .and_then(move |versions| {
+                if versions
+                    .application_versions
+                    .map_or(false, |v| !v.is_empty())
+                {
+                    return Either::A(future::ok(deployment));
+                }
+                let key = deployment
+                    .source_bundle
+                    .as_ref()
+                    .and_then(|location| location.s3_key.clone())
+                    .unwrap_or_else(|| {
+                        format!(
+                            "{}/{}.zip",
+                            deployment.application_name, deployment.version_label
+                        )
+                    });
+                let bucket = match deployment
+                    .source_bundle
+                    .as_ref()
+                    .and_then(|location| location.s3_bucket.clone())
+                {
+                    Some(bucket) => Either::A(future::ok(bucket)),
+                    None => Either::B(
+                        storage_client
+                            .create_storage_location()
+                            .map_err(|e| e.map_service(DeployError::CreateStorageLocation))
+                            .map(|location| location.s3_bucket.unwrap_or_default()),
+                    ),
+                };
+                Either::B(bucket.and_then(move |bucket| {
+                    let upload = PutObjectRequest {
+                        bucket: bucket.clone(),
+                        key: key.clone(),
+                        body: Some(bundle.into()),
+                        content_type: Some("application/zip".to_owned()),
+                        ..Default::default()
+                    };
+                    s3.put_object(upload)
+                        .map_err(|e| e.map_service(DeployError::PutObject))
+                        .map(move |_| Deployment {
+                            source_bundle: Some(S3Location {
+                                s3_bucket: Some(bucket),
+                                s3_key: Some(key),
+                            }),
+                            ..deployment
+                        })
+                }))
+            })
+            .and_then(move |deployment| {
+                deploy_client.deploy_version_and_wait(deployment, config, on_event)
+            });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: ElasticBeanstalk + Clone + Send + 'static> DeployExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(date: &str, message: &str) -> EventDescription {
+        EventDescription {
+            event_date: timestamp::from_iso8601(date.to_owned()).ok(),
+            message: Some(message.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn messages(events: Vec<EventDescription>) -> Vec<String> {
+        events
+            .into_iter()
+            .map(|event| event.message.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn tails_events_oldest_first_without_repeating_them() {
+        let mut tail =
+            EventTail::new(timestamp::from_iso8601("2019-11-02T10:00:00Z".to_owned()).ok());
+        let first = tail.new_events(vec![
+            event(
+                "2019-11-02T10:00:05.120Z",
+                "Deploying new version to instance(s).",
+            ),
+            event(
+                "2019-11-02T10:00:00.500Z",
+                "Environment update is starting.",
+            ),
+            event(
+                "2019-11-02T09:59:00Z",
+                "Environment health has transitioned to Ok.",
+            ),
+        ]);
+        assert_eq!(
+            messages(first),
+            vec![
+                "Environment update is starting.",
+                "Deploying new version to instance(s)."
+            ]
+        );
+        assert_eq!(
+            tail.request("my-env").start_time,
+            timestamp::from_iso8601("2019-11-02T10:00:05.120Z".to_owned()).ok()
+        );
+
+        let second = tail.new_events(vec![
+            event("2019-11-02T10:00:05.120Z", "Instance deployment completed."),
+            event(
+                "2019-11-02T10:00:05.120Z",
+                "Deploying new version to instance(s).",
+            ),
+        ]);
+        assert_eq!(messages(second), vec!["Instance deployment completed."]);
+    }
+
+    #[test]
+    fn failed_deployments_are_degraded() {
+        let environment = EnvironmentDescription {
+            status: Some(EnvironmentStatus::Ready),
+            health: Some(EnvironmentHealth::Green),
+            version_label: Some("v42".to_owned()),
+            ..Default::default()
+        };
+        assert!(deployment_outcome(environment.clone(), "v42")
+            .unwrap()
+            .is_ready());
+        assert!(!deployment_outcome(environment.clone(), "v43")
+            .unwrap()
+            .is_ready());
+        let unhealthy = EnvironmentDescription {
+            health_status: Some(EnvironmentHealthStatus::Severe),
+            ..environment.clone()
+        };
+        assert!(!deployment_outcome(unhealthy, "v42").unwrap().is_ready());
+        let updating = EnvironmentDescription {
+            status: Some(EnvironmentStatus::Updating),
+            ..environment
+        };
+        assert_eq!(deployment_outcome(updating, "v42"), None);
+    }
+}
@@ -1 +1,5 @@
+/// Deploying application versions and following their progress
+pub mod deploy;
 
+#[cfg(test)]
+mod custom_tests;
@@ -28,15 +28,19 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
 use xml::EventReader;
 
-struct ARNDeserializer;
+pub(crate) struct ARNDeserializer;
 impl ARNDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -53,6 +57,44 @@ pub struct AbortEnvironmentUpdateMessage {
     pub environment_name: Option<String>,
 }
 
+impl AbortEnvironmentUpdateMessage {
+    /// Returns a builder for `AbortEnvironmentUpdateMessage`, checking its required members when built.
+    pub fn builder() -> AbortEnvironmentUpdateMessageBuilder {
+        AbortEnvironmentUpdateMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AbortEnvironmentUpdateMessage`](struct.AbortEnvironmentUpdateMessage.html).
+#[derive(Default, Debug)]
+pub struct AbortEnvironmentUpdateMessageBuilder {
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+}
+
+impl AbortEnvironmentUpdateMessageBuilder {
+    /// Sets the [`environment_id`](struct.AbortEnvironmentUpdateMessage.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.AbortEnvironmentUpdateMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AbortEnvironmentUpdateMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AbortEnvironmentUpdateMessage, ::rusoto_core::BuildError> {
+        Ok(AbortEnvironmentUpdateMessage {
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+        })
+    }
+}
+
 /// Serialize `AbortEnvironmentUpdateMessage` contents to a `SignedRequest`.
 struct AbortEnvironmentUpdateMessageSerializer;
 impl AbortEnvironmentUpdateMessageSerializer {
@@ -71,10 +113,13 @@ impl AbortEnvironmentUpdateMessageSerializer {
     }
 }
 
-struct AbortableOperationInProgressDeserializer;
+pub(crate) struct AbortableOperationInProgressDeserializer;
 impl AbortableOperationInProgressDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -82,43 +127,248 @@ impl AbortableOperationInProgressDeserializer {
         Ok(obj)
     }
 }
-struct ActionHistoryStatusDeserializer;
+pub(crate) struct ActionHistoryStatusDeserializer;
 impl ActionHistoryStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ActionHistoryStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ActionStatusDeserializer;
+/// <p>The status of the action.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionHistoryStatus {
+    /// <p><code>Completed</code></p>
+    Completed,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Unknown</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionHistoryStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionHistoryStatus::Completed => "Completed",
+            ActionHistoryStatus::Failed => "Failed",
+            ActionHistoryStatus::UnknownValue => "Unknown",
+            ActionHistoryStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionHistoryStatus {
+    fn from(value: &'a str) -> ActionHistoryStatus {
+        match value {
+            "Completed" => ActionHistoryStatus::Completed,
+            "Failed" => ActionHistoryStatus::Failed,
+            "Unknown" => ActionHistoryStatus::UnknownValue,
+            _ => ActionHistoryStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionHistoryStatus {
+    fn from(value: String) -> ActionHistoryStatus {
+        ActionHistoryStatus::from(value.as_str())
+    }
+}
+
+impl From<ActionHistoryStatus> for String {
+    fn from(value: ActionHistoryStatus) -> String {
+        match value {
+            ActionHistoryStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionHistoryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionHistoryStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ActionStatusDeserializer;
 impl ActionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ActionStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ActionTypeDeserializer;
+/// <p>To show only actions with a particular status, specify a status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionStatus {
+    /// <p><code>Scheduled</code></p>
+    Scheduled,
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>Running</code></p>
+    Running,
+    /// <p><code>Unknown</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionStatus::Scheduled => "Scheduled",
+            ActionStatus::Pending => "Pending",
+            ActionStatus::Running => "Running",
+            ActionStatus::UnknownValue => "Unknown",
+            ActionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionStatus {
+    fn from(value: &'a str) -> ActionStatus {
+        match value {
+            "Scheduled" => ActionStatus::Scheduled,
+            "Pending" => ActionStatus::Pending,
+            "Running" => ActionStatus::Running,
+            "Unknown" => ActionStatus::UnknownValue,
+            _ => ActionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionStatus {
+    fn from(value: String) -> ActionStatus {
+        ActionStatus::from(value.as_str())
+    }
+}
+
+impl From<ActionStatus> for String {
+    fn from(value: ActionStatus) -> String {
+        match value {
+            ActionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ActionTypeDeserializer;
 impl ActionTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ActionType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ApplicationArnDeserializer;
+/// <p>The type of managed action.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionType {
+    /// <p><code>InstanceRefresh</code></p>
+    InstanceRefresh,
+    /// <p><code>PlatformUpdate</code></p>
+    PlatformUpdate,
+    /// <p><code>Unknown</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionType::InstanceRefresh => "InstanceRefresh",
+            ActionType::PlatformUpdate => "PlatformUpdate",
+            ActionType::UnknownValue => "Unknown",
+            ActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionType {
+    fn from(value: &'a str) -> ActionType {
+        match value {
+            "InstanceRefresh" => ActionType::InstanceRefresh,
+            "PlatformUpdate" => ActionType::PlatformUpdate,
+            "Unknown" => ActionType::UnknownValue,
+            _ => ActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionType {
+    fn from(value: String) -> ActionType {
+        ActionType::from(value.as_str())
+    }
+}
+
+impl From<ActionType> for String {
+    fn from(value: ActionType) -> String {
+        match value {
+            ActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ApplicationArnDeserializer;
 impl ApplicationArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -136,9 +386,9 @@ pub struct ApplicationDescription {
     /// <p>The names of the configuration templates associated with this application.</p>
     pub configuration_templates: Option<Vec<String>>,
     /// <p>The date when the application was created.</p>
-    pub date_created: Option<String>,
+    pub date_created: Option<IsoTimestamp>,
     /// <p>The date when the application was last modified.</p>
-    pub date_updated: Option<String>,
+    pub date_updated: Option<IsoTimestamp>,
     /// <p>User-defined description of the application.</p>
     pub description: Option<String>,
     /// <p>The lifecycle settings for the application.</p>
@@ -147,10 +397,10 @@ pub struct ApplicationDescription {
     pub versions: Option<Vec<String>>,
 }
 
-struct ApplicationDescriptionDeserializer;
+pub(crate) struct ApplicationDescriptionDeserializer;
 impl ApplicationDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplicationDescription, XmlParseError> {
@@ -206,10 +456,10 @@ impl ApplicationDescriptionDeserializer {
         })
     }
 }
-struct ApplicationDescriptionListDeserializer;
+pub(crate) struct ApplicationDescriptionListDeserializer;
 impl ApplicationDescriptionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ApplicationDescription>, XmlParseError> {
@@ -232,10 +482,10 @@ pub struct ApplicationDescriptionMessage {
     pub application: Option<ApplicationDescription>,
 }
 
-struct ApplicationDescriptionMessageDeserializer;
+pub(crate) struct ApplicationDescriptionMessageDeserializer;
 impl ApplicationDescriptionMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplicationDescriptionMessage, XmlParseError> {
@@ -264,10 +514,10 @@ pub struct ApplicationDescriptionsMessage {
     pub applications: Option<Vec<ApplicationDescription>>,
 }
 
-struct ApplicationDescriptionsMessageDeserializer;
+pub(crate) struct ApplicationDescriptionsMessageDeserializer;
 impl ApplicationDescriptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplicationDescriptionsMessage, XmlParseError> {
@@ -304,10 +554,10 @@ pub struct ApplicationMetrics {
     pub status_codes: Option<StatusCodes>,
 }
 
-struct ApplicationMetricsDeserializer;
+pub(crate) struct ApplicationMetricsDeserializer;
 impl ApplicationMetricsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplicationMetrics, XmlParseError> {
@@ -336,10 +586,13 @@ impl ApplicationMetricsDeserializer {
         })
     }
 }
-struct ApplicationNameDeserializer;
+pub(crate) struct ApplicationNameDeserializer;
 impl ApplicationNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -368,10 +621,10 @@ pub struct ApplicationResourceLifecycleConfig {
     pub version_lifecycle_config: Option<ApplicationVersionLifecycleConfig>,
 }
 
-struct ApplicationResourceLifecycleConfigDeserializer;
+pub(crate) struct ApplicationResourceLifecycleConfigDeserializer;
 impl ApplicationResourceLifecycleConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplicationResourceLifecycleConfig, XmlParseError> {
@@ -429,10 +682,10 @@ pub struct ApplicationResourceLifecycleDescriptionMessage {
     pub resource_lifecycle_config: Option<ApplicationResourceLifecycleConfig>,
 }
 
-struct ApplicationResourceLifecycleDescriptionMessageDeserializer;
+pub(crate) struct ApplicationResourceLifecycleDescriptionMessageDeserializer;
 impl ApplicationResourceLifecycleDescriptionMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplicationResourceLifecycleDescriptionMessage, XmlParseError> {
@@ -461,10 +714,13 @@ impl ApplicationResourceLifecycleDescriptionMessageDeserializer {
         )
     }
 }
-struct ApplicationVersionArnDeserializer;
+pub(crate) struct ApplicationVersionArnDeserializer;
 impl ApplicationVersionArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -482,9 +738,9 @@ pub struct ApplicationVersionDescription {
     /// <p>Reference to the artifact from the AWS CodeBuild build.</p>
     pub build_arn: Option<String>,
     /// <p>The creation date of the application version.</p>
-    pub date_created: Option<String>,
+    pub date_created: Option<IsoTimestamp>,
     /// <p>The last modified date of the application version.</p>
-    pub date_updated: Option<String>,
+    pub date_updated: Option<IsoTimestamp>,
     /// <p>The description of the application version.</p>
     pub description: Option<String>,
     /// <p>If the version's source code was retrieved from AWS CodeCommit, the location of the source code for the application version.</p>
@@ -492,15 +748,15 @@ pub struct ApplicationVersionDescription {
     /// <p>The storage location of the application version's source bundle in Amazon S3.</p>
     pub source_bundle: Option<S3Location>,
     /// <p><p>The processing status of the application version. Reflects the state of the application version during its creation. Many of the values are only applicable if you specified <code>True</code> for the <code>Process</code> parameter of the <code>CreateApplicationVersion</code> action. The following list describes the possible values.</p> <ul> <li> <p> <code>Unprocessed</code> – Application version wasn&#39;t pre-processed or validated. Elastic Beanstalk will validate configuration files during deployment of the application version to an environment.</p> </li> <li> <p> <code>Processing</code> – Elastic Beanstalk is currently processing the application version.</p> </li> <li> <p> <code>Building</code> – Application version is currently undergoing an AWS CodeBuild build.</p> </li> <li> <p> <code>Processed</code> – Elastic Beanstalk was successfully pre-processed and validated.</p> </li> <li> <p> <code>Failed</code> – Either the AWS CodeBuild build failed or configuration files didn&#39;t pass validation. This application version isn&#39;t usable.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<ApplicationVersionStatus>,
     /// <p>A unique identifier for the application version.</p>
     pub version_label: Option<String>,
 }
 
-struct ApplicationVersionDescriptionDeserializer;
+pub(crate) struct ApplicationVersionDescriptionDeserializer;
 impl ApplicationVersionDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplicationVersionDescription, XmlParseError> {
@@ -566,10 +822,10 @@ impl ApplicationVersionDescriptionDeserializer {
         )
     }
 }
-struct ApplicationVersionDescriptionListDeserializer;
+pub(crate) struct ApplicationVersionDescriptionListDeserializer;
 impl ApplicationVersionDescriptionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ApplicationVersionDescription>, XmlParseError> {
@@ -592,10 +848,10 @@ pub struct ApplicationVersionDescriptionMessage {
     pub application_version: Option<ApplicationVersionDescription>,
 }
 
-struct ApplicationVersionDescriptionMessageDeserializer;
+pub(crate) struct ApplicationVersionDescriptionMessageDeserializer;
 impl ApplicationVersionDescriptionMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplicationVersionDescriptionMessage, XmlParseError> {
@@ -627,10 +883,10 @@ pub struct ApplicationVersionDescriptionsMessage {
     pub next_token: Option<String>,
 }
 
-struct ApplicationVersionDescriptionsMessageDeserializer;
+pub(crate) struct ApplicationVersionDescriptionsMessageDeserializer;
 impl ApplicationVersionDescriptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplicationVersionDescriptionsMessage, XmlParseError> {
@@ -666,10 +922,10 @@ pub struct ApplicationVersionLifecycleConfig {
     pub max_count_rule: Option<MaxCountRule>,
 }
 
-struct ApplicationVersionLifecycleConfigDeserializer;
+pub(crate) struct ApplicationVersionLifecycleConfigDeserializer;
 impl ApplicationVersionLifecycleConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplicationVersionLifecycleConfig, XmlParseError> {
@@ -722,17 +978,91 @@ impl ApplicationVersionLifecycleConfigSerializer {
     }
 }
 
-struct ApplicationVersionStatusDeserializer;
+pub(crate) struct ApplicationVersionStatusDeserializer;
 impl ApplicationVersionStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ApplicationVersionStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p><p>The processing status of the application version. Reflects the state of the application version during its creation. Many of the values are only applicable if you specified <code>True</code> for the <code>Process</code> parameter of the <code>CreateApplicationVersion</code> action. The following list describes the possible values.</p> <ul> <li> <p> <code>Unprocessed</code> – Application version wasn&#39;t pre-processed or validated. Elastic Beanstalk will validate configuration files during deployment of the application version to an environment.</p> </li> <li> <p> <code>Processing</code> – Elastic Beanstalk is currently processing the application version.</p> </li> <li> <p> <code>Building</code> – Application version is currently undergoing an AWS CodeBuild build.</p> </li> <li> <p> <code>Processed</code> – Elastic Beanstalk was successfully pre-processed and validated.</p> </li> <li> <p> <code>Failed</code> – Either the AWS CodeBuild build failed or configuration files didn&#39;t pass validation. This application version isn&#39;t usable.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApplicationVersionStatus {
+    /// <p><code>Processed</code></p>
+    Processed,
+    /// <p><code>Unprocessed</code></p>
+    Unprocessed,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Processing</code></p>
+    Processing,
+    /// <p><code>Building</code></p>
+    Building,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ApplicationVersionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplicationVersionStatus::Processed => "Processed",
+            ApplicationVersionStatus::Unprocessed => "Unprocessed",
+            ApplicationVersionStatus::Failed => "Failed",
+            ApplicationVersionStatus::Processing => "Processing",
+            ApplicationVersionStatus::Building => "Building",
+            ApplicationVersionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ApplicationVersionStatus {
+    fn from(value: &'a str) -> ApplicationVersionStatus {
+        match value {
+            "Processed" => ApplicationVersionStatus::Processed,
+            "Unprocessed" => ApplicationVersionStatus::Unprocessed,
+            "Failed" => ApplicationVersionStatus::Failed,
+            "Processing" => ApplicationVersionStatus::Processing,
+            "Building" => ApplicationVersionStatus::Building,
+            _ => ApplicationVersionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplicationVersionStatus {
+    fn from(value: String) -> ApplicationVersionStatus {
+        ApplicationVersionStatus::from(value.as_str())
+    }
+}
+
+impl From<ApplicationVersionStatus> for String {
+    fn from(value: ApplicationVersionStatus) -> String {
+        match value {
+            ApplicationVersionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ApplicationVersionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplicationVersionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Request to execute a scheduled managed action immediately.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ApplyEnvironmentManagedActionRequest {
@@ -744,6 +1074,55 @@ pub struct ApplyEnvironmentManagedActionRequest {
     pub environment_name: Option<String>,
 }
 
+impl ApplyEnvironmentManagedActionRequest {
+    /// Returns a builder for `ApplyEnvironmentManagedActionRequest`, checking its required members when built.
+    pub fn builder() -> ApplyEnvironmentManagedActionRequestBuilder {
+        ApplyEnvironmentManagedActionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ApplyEnvironmentManagedActionRequest`](struct.ApplyEnvironmentManagedActionRequest.html).
+#[derive(Default, Debug)]
+pub struct ApplyEnvironmentManagedActionRequestBuilder {
+    action_id: Option<String>,
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+}
+
+impl ApplyEnvironmentManagedActionRequestBuilder {
+    /// Sets the [`action_id`](struct.ApplyEnvironmentManagedActionRequest.html#structfield.action_id) member.
+    pub fn action_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.action_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_id`](struct.ApplyEnvironmentManagedActionRequest.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.ApplyEnvironmentManagedActionRequest.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ApplyEnvironmentManagedActionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ApplyEnvironmentManagedActionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ApplyEnvironmentManagedActionRequest {
+            action_id: self
+                .action_id
+                .ok_or(::rusoto_core::BuildError::missing("action_id"))?,
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+        })
+    }
+}
+
 /// Serialize `ApplyEnvironmentManagedActionRequest` contents to a `SignedRequest`.
 struct ApplyEnvironmentManagedActionRequestSerializer;
 impl ApplyEnvironmentManagedActionRequestSerializer {
@@ -771,15 +1150,15 @@ pub struct ApplyEnvironmentManagedActionResult {
     /// <p>The action ID of the managed action.</p>
     pub action_id: Option<String>,
     /// <p>The type of managed action.</p>
-    pub action_type: Option<String>,
+    pub action_type: Option<ActionType>,
     /// <p>The status of the managed action.</p>
     pub status: Option<String>,
 }
 
-struct ApplyEnvironmentManagedActionResultDeserializer;
+pub(crate) struct ApplyEnvironmentManagedActionResultDeserializer;
 impl ApplyEnvironmentManagedActionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplyEnvironmentManagedActionResult, XmlParseError> {
@@ -816,10 +1195,10 @@ pub struct AutoScalingGroup {
     pub name: Option<String>,
 }
 
-struct AutoScalingGroupDeserializer;
+pub(crate) struct AutoScalingGroupDeserializer;
 impl AutoScalingGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AutoScalingGroup, XmlParseError> {
@@ -834,10 +1213,10 @@ impl AutoScalingGroupDeserializer {
         })
     }
 }
-struct AutoScalingGroupListDeserializer;
+pub(crate) struct AutoScalingGroupListDeserializer;
 impl AutoScalingGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AutoScalingGroup>, XmlParseError> {
@@ -851,10 +1230,10 @@ impl AutoScalingGroupListDeserializer {
         })
     }
 }
-struct AvailableSolutionStackDetailsListDeserializer;
+pub(crate) struct AvailableSolutionStackDetailsListDeserializer;
 impl AvailableSolutionStackDetailsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SolutionStackDescription>, XmlParseError> {
@@ -870,10 +1249,10 @@ impl AvailableSolutionStackDetailsListDeserializer {
         })
     }
 }
-struct AvailableSolutionStackNamesListDeserializer;
+pub(crate) struct AvailableSolutionStackNamesListDeserializer;
 impl AvailableSolutionStackNamesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -887,10 +1266,13 @@ impl AvailableSolutionStackNamesListDeserializer {
         })
     }
 }
-struct BoxedBooleanDeserializer;
+pub(crate) struct BoxedBooleanDeserializer;
 impl BoxedBooleanDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -898,10 +1280,13 @@ impl BoxedBooleanDeserializer {
         Ok(obj)
     }
 }
-struct BoxedIntDeserializer;
+pub(crate) struct BoxedIntDeserializer;
 impl BoxedIntDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -917,7 +1302,7 @@ pub struct BuildConfiguration {
     /// <p>The Amazon Resource Name (ARN) of the AWS Identity and Access Management (IAM) role that enables AWS CodeBuild to interact with dependent AWS services on behalf of the AWS account.</p>
     pub code_build_service_role: String,
     /// <p><p>Information about the compute resources the build project will use.</p> <ul> <li> <p> <code>BUILD<em>GENERAL1</em>SMALL: Use up to 3 GB memory and 2 vCPUs for builds</code> </p> </li> <li> <p> <code>BUILD<em>GENERAL1</em>MEDIUM: Use up to 7 GB memory and 4 vCPUs for builds</code> </p> </li> <li> <p> <code>BUILD<em>GENERAL1</em>LARGE: Use up to 15 GB memory and 8 vCPUs for builds</code> </p> </li> </ul></p>
-    pub compute_type: Option<String>,
+    pub compute_type: Option<ComputeType>,
     /// <p>The ID of the Docker image to use for this build project.</p>
     pub image: String,
     /// <p>How long in minutes, from 5 to 480 (8 hours), for AWS CodeBuild to wait until timing out any related build that does not get marked as completed. The default is 60 minutes.</p>
@@ -957,10 +1342,10 @@ pub struct Builder {
     pub arn: Option<String>,
 }
 
-struct BuilderDeserializer;
+pub(crate) struct BuilderDeserializer;
 impl BuilderDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Builder, XmlParseError> {
@@ -996,10 +1381,10 @@ pub struct CPUUtilization {
     pub user: Option<f64>,
 }
 
-struct CPUUtilizationDeserializer;
+pub(crate) struct CPUUtilizationDeserializer;
 impl CPUUtilizationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CPUUtilization, XmlParseError> {
@@ -1038,10 +1423,13 @@ impl CPUUtilizationDeserializer {
         })
     }
 }
-struct CauseDeserializer;
+pub(crate) struct CauseDeserializer;
 impl CauseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1049,10 +1437,10 @@ impl CauseDeserializer {
         Ok(obj)
     }
 }
-struct CausesDeserializer;
+pub(crate) struct CausesDeserializer;
 impl CausesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -1073,6 +1461,38 @@ pub struct CheckDNSAvailabilityMessage {
     pub cname_prefix: String,
 }
 
+impl CheckDNSAvailabilityMessage {
+    /// Returns a builder for `CheckDNSAvailabilityMessage`, checking its required members when built.
+    pub fn builder() -> CheckDNSAvailabilityMessageBuilder {
+        CheckDNSAvailabilityMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CheckDNSAvailabilityMessage`](struct.CheckDNSAvailabilityMessage.html).
+#[derive(Default, Debug)]
+pub struct CheckDNSAvailabilityMessageBuilder {
+    cname_prefix: Option<String>,
+}
+
+impl CheckDNSAvailabilityMessageBuilder {
+    /// Sets the [`cname_prefix`](struct.CheckDNSAvailabilityMessage.html#structfield.cname_prefix) member.
+    pub fn cname_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.cname_prefix = Some(value.into());
+        self
+    }
+
+    /// Builds the `CheckDNSAvailabilityMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CheckDNSAvailabilityMessage, ::rusoto_core::BuildError> {
+        Ok(CheckDNSAvailabilityMessage {
+            cname_prefix: self
+                .cname_prefix
+                .ok_or(::rusoto_core::BuildError::missing("cname_prefix"))?,
+        })
+    }
+}
+
 /// Serialize `CheckDNSAvailabilityMessage` contents to a `SignedRequest`.
 struct CheckDNSAvailabilityMessageSerializer;
 impl CheckDNSAvailabilityMessageSerializer {
@@ -1095,10 +1515,10 @@ pub struct CheckDNSAvailabilityResultMessage {
     pub fully_qualified_cname: Option<String>,
 }
 
-struct CheckDNSAvailabilityResultMessageDeserializer;
+pub(crate) struct CheckDNSAvailabilityResultMessageDeserializer;
 impl CheckDNSAvailabilityResultMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CheckDNSAvailabilityResultMessage, XmlParseError> {
@@ -1126,10 +1546,13 @@ impl CheckDNSAvailabilityResultMessageDeserializer {
         )
     }
 }
-struct CnameAvailabilityDeserializer;
+pub(crate) struct CnameAvailabilityDeserializer;
 impl CnameAvailabilityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1148,6 +1571,52 @@ pub struct ComposeEnvironmentsMessage {
     pub version_labels: Option<Vec<String>>,
 }
 
+impl ComposeEnvironmentsMessage {
+    /// Returns a builder for `ComposeEnvironmentsMessage`, checking its required members when built.
+    pub fn builder() -> ComposeEnvironmentsMessageBuilder {
+        ComposeEnvironmentsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ComposeEnvironmentsMessage`](struct.ComposeEnvironmentsMessage.html).
+#[derive(Default, Debug)]
+pub struct ComposeEnvironmentsMessageBuilder {
+    application_name: Option<String>,
+    group_name: Option<String>,
+    version_labels: Option<Vec<String>>,
+}
+
+impl ComposeEnvironmentsMessageBuilder {
+    /// Sets the [`application_name`](struct.ComposeEnvironmentsMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.ComposeEnvironmentsMessage.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_labels`](struct.ComposeEnvironmentsMessage.html#structfield.version_labels) member.
+    pub fn version_labels<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.version_labels = Some(value.into());
+        self
+    }
+
+    /// Builds the `ComposeEnvironmentsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ComposeEnvironmentsMessage, ::rusoto_core::BuildError> {
+        Ok(ComposeEnvironmentsMessage {
+            application_name: self.application_name,
+            group_name: self.group_name,
+            version_labels: self.version_labels,
+        })
+    }
+}
+
 /// Serialize `ComposeEnvironmentsMessage` contents to a `SignedRequest`.
 struct ComposeEnvironmentsMessageSerializer;
 impl ComposeEnvironmentsMessageSerializer {
@@ -1173,21 +1642,153 @@ impl ComposeEnvironmentsMessageSerializer {
     }
 }
 
-struct ConfigurationDeploymentStatusDeserializer;
+/// <p><p>Information about the compute resources the build project will use.</p> <ul> <li> <p> <code>BUILD<em>GENERAL1</em>SMALL: Use up to 3 GB memory and 2 vCPUs for builds</code> </p> </li> <li> <p> <code>BUILD<em>GENERAL1</em>MEDIUM: Use up to 7 GB memory and 4 vCPUs for builds</code> </p> </li> <li> <p> <code>BUILD<em>GENERAL1</em>LARGE: Use up to 15 GB memory and 8 vCPUs for builds</code> </p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComputeType {
+    /// <p><code>BUILD_GENERAL1_SMALL</code></p>
+    BuildGeneral1Small,
+    /// <p><code>BUILD_GENERAL1_MEDIUM</code></p>
+    BuildGeneral1Medium,
+    /// <p><code>BUILD_GENERAL1_LARGE</code></p>
+    BuildGeneral1Large,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComputeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComputeType::BuildGeneral1Small => "BUILD_GENERAL1_SMALL",
+            ComputeType::BuildGeneral1Medium => "BUILD_GENERAL1_MEDIUM",
+            ComputeType::BuildGeneral1Large => "BUILD_GENERAL1_LARGE",
+            ComputeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComputeType {
+    fn from(value: &'a str) -> ComputeType {
+        match value {
+            "BUILD_GENERAL1_SMALL" => ComputeType::BuildGeneral1Small,
+            "BUILD_GENERAL1_MEDIUM" => ComputeType::BuildGeneral1Medium,
+            "BUILD_GENERAL1_LARGE" => ComputeType::BuildGeneral1Large,
+            _ => ComputeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComputeType {
+    fn from(value: String) -> ComputeType {
+        ComputeType::from(value.as_str())
+    }
+}
+
+impl From<ComputeType> for String {
+    fn from(value: ComputeType) -> String {
+        match value {
+            ComputeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComputeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComputeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ConfigurationDeploymentStatusDeserializer;
 impl ConfigurationDeploymentStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ConfigurationDeploymentStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ConfigurationOptionDefaultValueDeserializer;
+/// <p><p> If this configuration set is associated with an environment, the <code>DeploymentStatus</code> parameter indicates the deployment status of this configuration set: </p> <ul> <li> <p> <code>null</code>: This configuration is not associated with a running environment.</p> </li> <li> <p> <code>pending</code>: This is a draft configuration that is not deployed to the associated environment but is in the process of deploying.</p> </li> <li> <p> <code>deployed</code>: This is the configuration that is currently deployed to the associated running environment.</p> </li> <li> <p> <code>failed</code>: This is a draft configuration that failed to successfully deploy.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConfigurationDeploymentStatus {
+    /// <p><code>deployed</code> - This is the configuration that is currently deployed to the associated running environment.</p>
+    Deployed,
+    /// <p><code>pending</code> - This is a draft configuration that is not deployed to the associated environment but is in the process of deploying.</p>
+    Pending,
+    /// <p><code>failed</code> - This is a draft configuration that failed to successfully deploy.</p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConfigurationDeploymentStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationDeploymentStatus::Deployed => "deployed",
+            ConfigurationDeploymentStatus::Pending => "pending",
+            ConfigurationDeploymentStatus::Failed => "failed",
+            ConfigurationDeploymentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationDeploymentStatus {
+    fn from(value: &'a str) -> ConfigurationDeploymentStatus {
+        match value {
+            "deployed" => ConfigurationDeploymentStatus::Deployed,
+            "pending" => ConfigurationDeploymentStatus::Pending,
+            "failed" => ConfigurationDeploymentStatus::Failed,
+            _ => ConfigurationDeploymentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationDeploymentStatus {
+    fn from(value: String) -> ConfigurationDeploymentStatus {
+        ConfigurationDeploymentStatus::from(value.as_str())
+    }
+}
+
+impl From<ConfigurationDeploymentStatus> for String {
+    fn from(value: ConfigurationDeploymentStatus) -> String {
+        match value {
+            ConfigurationDeploymentStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigurationDeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigurationDeploymentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ConfigurationOptionDefaultValueDeserializer;
 impl ConfigurationOptionDefaultValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1219,13 +1820,13 @@ pub struct ConfigurationOptionDescription {
     /// <p>If specified, values for the configuration option are selected from this list.</p>
     pub value_options: Option<Vec<String>>,
     /// <p><p>An indication of which type of values this option has and whether it is allowable to select one or more than one of the possible values:</p> <ul> <li> <p> <code>Scalar</code> : Values for this option are a single selection from the possible values, or an unformatted string, or numeric value governed by the <code>MIN/MAX/Regex</code> constraints.</p> </li> <li> <p> <code>List</code> : Values for this option are multiple selections from the possible values.</p> </li> <li> <p> <code>Boolean</code> : Values for this option are either <code>true</code> or <code>false</code> .</p> </li> <li> <p> <code>Json</code> : Values for this option are a JSON representation of a <code>ConfigDocument</code>.</p> </li> </ul></p>
-    pub value_type: Option<String>,
+    pub value_type: Option<ConfigurationOptionValueType>,
 }
 
-struct ConfigurationOptionDescriptionDeserializer;
+pub(crate) struct ConfigurationOptionDescriptionDeserializer;
 impl ConfigurationOptionDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ConfigurationOptionDescription, XmlParseError> {
@@ -1308,10 +1909,10 @@ impl ConfigurationOptionDescriptionDeserializer {
         )
     }
 }
-struct ConfigurationOptionDescriptionsListDeserializer;
+pub(crate) struct ConfigurationOptionDescriptionsListDeserializer;
 impl ConfigurationOptionDescriptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ConfigurationOptionDescription>, XmlParseError> {
@@ -1327,10 +1928,13 @@ impl ConfigurationOptionDescriptionsListDeserializer {
         })
     }
 }
-struct ConfigurationOptionNameDeserializer;
+pub(crate) struct ConfigurationOptionNameDeserializer;
 impl ConfigurationOptionNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1338,10 +1942,13 @@ impl ConfigurationOptionNameDeserializer {
         Ok(obj)
     }
 }
-struct ConfigurationOptionPossibleValueDeserializer;
+pub(crate) struct ConfigurationOptionPossibleValueDeserializer;
 impl ConfigurationOptionPossibleValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1349,10 +1956,10 @@ impl ConfigurationOptionPossibleValueDeserializer {
         Ok(obj)
     }
 }
-struct ConfigurationOptionPossibleValuesDeserializer;
+pub(crate) struct ConfigurationOptionPossibleValuesDeserializer;
 impl ConfigurationOptionPossibleValuesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -1381,10 +1988,10 @@ pub struct ConfigurationOptionSetting {
     pub value: Option<String>,
 }
 
-struct ConfigurationOptionSettingDeserializer;
+pub(crate) struct ConfigurationOptionSettingDeserializer;
 impl ConfigurationOptionSettingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ConfigurationOptionSetting, XmlParseError> {
@@ -1448,10 +2055,10 @@ impl ConfigurationOptionSettingSerializer {
     }
 }
 
-struct ConfigurationOptionSettingsListDeserializer;
+pub(crate) struct ConfigurationOptionSettingsListDeserializer;
 impl ConfigurationOptionSettingsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ConfigurationOptionSetting>, XmlParseError> {
@@ -1479,10 +2086,13 @@ impl ConfigurationOptionSettingsListSerializer {
     }
 }
 
-struct ConfigurationOptionSeverityDeserializer;
+pub(crate) struct ConfigurationOptionSeverityDeserializer;
 impl ConfigurationOptionSeverityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1490,10 +2100,13 @@ impl ConfigurationOptionSeverityDeserializer {
         Ok(obj)
     }
 }
-struct ConfigurationOptionValueDeserializer;
+pub(crate) struct ConfigurationOptionValueDeserializer;
 impl ConfigurationOptionValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1501,32 +2114,94 @@ impl ConfigurationOptionValueDeserializer {
         Ok(obj)
     }
 }
-struct ConfigurationOptionValueTypeDeserializer;
+pub(crate) struct ConfigurationOptionValueTypeDeserializer;
 impl ConfigurationOptionValueTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ConfigurationOptionValueType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-/// <p>Describes the settings for a specified configuration set.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct ConfigurationOptionsDescription {
-    /// <p> A list of <a>ConfigurationOptionDescription</a>. </p>
-    pub options: Option<Vec<ConfigurationOptionDescription>>,
-    /// <p>The ARN of the platform.</p>
-    pub platform_arn: Option<String>,
-    /// <p>The name of the solution stack these configuration options belong to.</p>
+/// <p><p>An indication of which type of values this option has and whether it is allowable to select one or more than one of the possible values:</p> <ul> <li> <p> <code>Scalar</code> : Values for this option are a single selection from the possible values, or an unformatted string, or numeric value governed by the <code>MIN/MAX/Regex</code> constraints.</p> </li> <li> <p> <code>List</code> : Values for this option are multiple selections from the possible values.</p> </li> <li> <p> <code>Boolean</code> : Values for this option are either <code>true</code> or <code>false</code> .</p> </li> <li> <p> <code>Json</code> : Values for this option are a JSON representation of a <code>ConfigDocument</code>.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConfigurationOptionValueType {
+    /// <p><code>Scalar</code> - Values for this option are a single selection from the possible values, or an unformatted string, or numeric value governed by the <code>MIN/MAX/Regex</code> constraints.</p>
+    Scalar,
+    /// <p><code>List</code> - Values for this option are multiple selections from the possible values.</p>
+    List,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConfigurationOptionValueType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfigurationOptionValueType::Scalar => "Scalar",
+            ConfigurationOptionValueType::List => "List",
+            ConfigurationOptionValueType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConfigurationOptionValueType {
+    fn from(value: &'a str) -> ConfigurationOptionValueType {
+        match value {
+            "Scalar" => ConfigurationOptionValueType::Scalar,
+            "List" => ConfigurationOptionValueType::List,
+            _ => ConfigurationOptionValueType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfigurationOptionValueType {
+    fn from(value: String) -> ConfigurationOptionValueType {
+        ConfigurationOptionValueType::from(value.as_str())
+    }
+}
+
+impl From<ConfigurationOptionValueType> for String {
+    fn from(value: ConfigurationOptionValueType) -> String {
+        match value {
+            ConfigurationOptionValueType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigurationOptionValueType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfigurationOptionValueType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// <p>Describes the settings for a specified configuration set.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ConfigurationOptionsDescription {
+    /// <p> A list of <a>ConfigurationOptionDescription</a>. </p>
+    pub options: Option<Vec<ConfigurationOptionDescription>>,
+    /// <p>The ARN of the platform.</p>
+    pub platform_arn: Option<String>,
+    /// <p>The name of the solution stack these configuration options belong to.</p>
     pub solution_stack_name: Option<String>,
 }
 
-struct ConfigurationOptionsDescriptionDeserializer;
+pub(crate) struct ConfigurationOptionsDescriptionDeserializer;
 impl ConfigurationOptionsDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ConfigurationOptionsDescription, XmlParseError> {
@@ -1565,11 +2240,11 @@ pub struct ConfigurationSettingsDescription {
     /// <p>The name of the application associated with this configuration set.</p>
     pub application_name: Option<String>,
     /// <p>The date (in UTC time) when this configuration set was created.</p>
-    pub date_created: Option<String>,
+    pub date_created: Option<IsoTimestamp>,
     /// <p>The date (in UTC time) when this configuration set was last modified.</p>
-    pub date_updated: Option<String>,
+    pub date_updated: Option<IsoTimestamp>,
     /// <p><p> If this configuration set is associated with an environment, the <code>DeploymentStatus</code> parameter indicates the deployment status of this configuration set: </p> <ul> <li> <p> <code>null</code>: This configuration is not associated with a running environment.</p> </li> <li> <p> <code>pending</code>: This is a draft configuration that is not deployed to the associated environment but is in the process of deploying.</p> </li> <li> <p> <code>deployed</code>: This is the configuration that is currently deployed to the associated running environment.</p> </li> <li> <p> <code>failed</code>: This is a draft configuration that failed to successfully deploy.</p> </li> </ul></p>
-    pub deployment_status: Option<String>,
+    pub deployment_status: Option<ConfigurationDeploymentStatus>,
     /// <p>Describes this configuration set.</p>
     pub description: Option<String>,
     /// <p> If not <code>null</code>, the name of the environment for this configuration set. </p>
@@ -1584,10 +2259,10 @@ pub struct ConfigurationSettingsDescription {
     pub template_name: Option<String>,
 }
 
-struct ConfigurationSettingsDescriptionDeserializer;
+pub(crate) struct ConfigurationSettingsDescriptionDeserializer;
 impl ConfigurationSettingsDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ConfigurationSettingsDescription, XmlParseError> {
@@ -1659,10 +2334,10 @@ impl ConfigurationSettingsDescriptionDeserializer {
         )
     }
 }
-struct ConfigurationSettingsDescriptionListDeserializer;
+pub(crate) struct ConfigurationSettingsDescriptionListDeserializer;
 impl ConfigurationSettingsDescriptionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ConfigurationSettingsDescription>, XmlParseError> {
@@ -1685,10 +2360,10 @@ pub struct ConfigurationSettingsDescriptions {
     pub configuration_settings: Option<Vec<ConfigurationSettingsDescription>>,
 }
 
-struct ConfigurationSettingsDescriptionsDeserializer;
+pub(crate) struct ConfigurationSettingsDescriptionsDeserializer;
 impl ConfigurationSettingsDescriptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ConfigurationSettingsDescriptions, XmlParseError> {
@@ -1719,10 +2394,10 @@ pub struct ConfigurationSettingsValidationMessages {
     pub messages: Option<Vec<ValidationMessage>>,
 }
 
-struct ConfigurationSettingsValidationMessagesDeserializer;
+pub(crate) struct ConfigurationSettingsValidationMessagesDeserializer;
 impl ConfigurationSettingsValidationMessagesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ConfigurationSettingsValidationMessages, XmlParseError> {
@@ -1743,10 +2418,13 @@ impl ConfigurationSettingsValidationMessagesDeserializer {
         )
     }
 }
-struct ConfigurationTemplateNameDeserializer;
+pub(crate) struct ConfigurationTemplateNameDeserializer;
 impl ConfigurationTemplateNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1754,10 +2432,10 @@ impl ConfigurationTemplateNameDeserializer {
         Ok(obj)
     }
 }
-struct ConfigurationTemplateNamesListDeserializer;
+pub(crate) struct ConfigurationTemplateNamesListDeserializer;
 impl ConfigurationTemplateNamesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -1786,6 +2464,65 @@ pub struct CreateApplicationMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateApplicationMessage {
+    /// Returns a builder for `CreateApplicationMessage`, checking its required members when built.
+    pub fn builder() -> CreateApplicationMessageBuilder {
+        CreateApplicationMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApplicationMessage`](struct.CreateApplicationMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateApplicationMessageBuilder {
+    application_name: Option<String>,
+    description: Option<String>,
+    resource_lifecycle_config: Option<ApplicationResourceLifecycleConfig>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateApplicationMessageBuilder {
+    /// Sets the [`application_name`](struct.CreateApplicationMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateApplicationMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_lifecycle_config`](struct.CreateApplicationMessage.html#structfield.resource_lifecycle_config) member.
+    pub fn resource_lifecycle_config<T: Into<ApplicationResourceLifecycleConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.resource_lifecycle_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateApplicationMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApplicationMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateApplicationMessage, ::rusoto_core::BuildError> {
+        Ok(CreateApplicationMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            description: self.description,
+            resource_lifecycle_config: self.resource_lifecycle_config,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateApplicationMessage` contents to a `SignedRequest`.
 struct CreateApplicationMessageSerializer;
 impl CreateApplicationMessageSerializer {
@@ -1838,6 +2575,104 @@ pub struct CreateApplicationVersionMessage {
     pub version_label: String,
 }
 
+impl CreateApplicationVersionMessage {
+    /// Returns a builder for `CreateApplicationVersionMessage`, checking its required members when built.
+    pub fn builder() -> CreateApplicationVersionMessageBuilder {
+        CreateApplicationVersionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApplicationVersionMessage`](struct.CreateApplicationVersionMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateApplicationVersionMessageBuilder {
+    application_name: Option<String>,
+    auto_create_application: Option<bool>,
+    build_configuration: Option<BuildConfiguration>,
+    description: Option<String>,
+    process: Option<bool>,
+    source_build_information: Option<SourceBuildInformation>,
+    source_bundle: Option<S3Location>,
+    tags: Option<Vec<Tag>>,
+    version_label: Option<String>,
+}
+
+impl CreateApplicationVersionMessageBuilder {
+    /// Sets the [`application_name`](struct.CreateApplicationVersionMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_create_application`](struct.CreateApplicationVersionMessage.html#structfield.auto_create_application) member.
+    pub fn auto_create_application<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_create_application = Some(value.into());
+        self
+    }
+
+    /// Sets the [`build_configuration`](struct.CreateApplicationVersionMessage.html#structfield.build_configuration) member.
+    pub fn build_configuration<T: Into<BuildConfiguration>>(mut self, value: T) -> Self {
+        self.build_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateApplicationVersionMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`process`](struct.CreateApplicationVersionMessage.html#structfield.process) member.
+    pub fn process<T: Into<bool>>(mut self, value: T) -> Self {
+        self.process = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_build_information`](struct.CreateApplicationVersionMessage.html#structfield.source_build_information) member.
+    pub fn source_build_information<T: Into<SourceBuildInformation>>(mut self, value: T) -> Self {
+        self.source_build_information = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_bundle`](struct.CreateApplicationVersionMessage.html#structfield.source_bundle) member.
+    pub fn source_bundle<T: Into<S3Location>>(mut self, value: T) -> Self {
+        self.source_bundle = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateApplicationVersionMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_label`](struct.CreateApplicationVersionMessage.html#structfield.version_label) member.
+    pub fn version_label<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_label = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApplicationVersionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateApplicationVersionMessage, ::rusoto_core::BuildError> {
+        Ok(CreateApplicationVersionMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            auto_create_application: self.auto_create_application,
+            build_configuration: self.build_configuration,
+            description: self.description,
+            process: self.process,
+            source_build_information: self.source_build_information,
+            source_bundle: self.source_bundle,
+            tags: self.tags,
+            version_label: self
+                .version_label
+                .ok_or(::rusoto_core::BuildError::missing("version_label"))?,
+        })
+    }
+}
+
 /// Serialize `CreateApplicationVersionMessage` contents to a `SignedRequest`.
 struct CreateApplicationVersionMessageSerializer;
 impl CreateApplicationVersionMessageSerializer {
@@ -1914,6 +2749,104 @@ pub struct CreateConfigurationTemplateMessage {
     pub template_name: String,
 }
 
+impl CreateConfigurationTemplateMessage {
+    /// Returns a builder for `CreateConfigurationTemplateMessage`, checking its required members when built.
+    pub fn builder() -> CreateConfigurationTemplateMessageBuilder {
+        CreateConfigurationTemplateMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConfigurationTemplateMessage`](struct.CreateConfigurationTemplateMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateConfigurationTemplateMessageBuilder {
+    application_name: Option<String>,
+    description: Option<String>,
+    environment_id: Option<String>,
+    option_settings: Option<Vec<ConfigurationOptionSetting>>,
+    platform_arn: Option<String>,
+    solution_stack_name: Option<String>,
+    source_configuration: Option<SourceConfiguration>,
+    tags: Option<Vec<Tag>>,
+    template_name: Option<String>,
+}
+
+impl CreateConfigurationTemplateMessageBuilder {
+    /// Sets the [`application_name`](struct.CreateConfigurationTemplateMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateConfigurationTemplateMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_id`](struct.CreateConfigurationTemplateMessage.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_settings`](struct.CreateConfigurationTemplateMessage.html#structfield.option_settings) member.
+    pub fn option_settings<T: Into<Vec<ConfigurationOptionSetting>>>(mut self, value: T) -> Self {
+        self.option_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_arn`](struct.CreateConfigurationTemplateMessage.html#structfield.platform_arn) member.
+    pub fn platform_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`solution_stack_name`](struct.CreateConfigurationTemplateMessage.html#structfield.solution_stack_name) member.
+    pub fn solution_stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.solution_stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_configuration`](struct.CreateConfigurationTemplateMessage.html#structfield.source_configuration) member.
+    pub fn source_configuration<T: Into<SourceConfiguration>>(mut self, value: T) -> Self {
+        self.source_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateConfigurationTemplateMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.CreateConfigurationTemplateMessage.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConfigurationTemplateMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateConfigurationTemplateMessage, ::rusoto_core::BuildError> {
+        Ok(CreateConfigurationTemplateMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            description: self.description,
+            environment_id: self.environment_id,
+            option_settings: self.option_settings,
+            platform_arn: self.platform_arn,
+            solution_stack_name: self.solution_stack_name,
+            source_configuration: self.source_configuration,
+            tags: self.tags,
+            template_name: self
+                .template_name
+                .ok_or(::rusoto_core::BuildError::missing("template_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateConfigurationTemplateMessage` contents to a `SignedRequest`.
 struct CreateConfigurationTemplateMessageSerializer;
 impl CreateConfigurationTemplateMessageSerializer {
@@ -1991,6 +2924,134 @@ pub struct CreateEnvironmentMessage {
     pub version_label: Option<String>,
 }
 
+impl CreateEnvironmentMessage {
+    /// Returns a builder for `CreateEnvironmentMessage`, checking its required members when built.
+    pub fn builder() -> CreateEnvironmentMessageBuilder {
+        CreateEnvironmentMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateEnvironmentMessage`](struct.CreateEnvironmentMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateEnvironmentMessageBuilder {
+    application_name: Option<String>,
+    cname_prefix: Option<String>,
+    description: Option<String>,
+    environment_name: Option<String>,
+    group_name: Option<String>,
+    option_settings: Option<Vec<ConfigurationOptionSetting>>,
+    options_to_remove: Option<Vec<OptionSpecification>>,
+    platform_arn: Option<String>,
+    solution_stack_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    template_name: Option<String>,
+    tier: Option<EnvironmentTier>,
+    version_label: Option<String>,
+}
+
+impl CreateEnvironmentMessageBuilder {
+    /// Sets the [`application_name`](struct.CreateEnvironmentMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cname_prefix`](struct.CreateEnvironmentMessage.html#structfield.cname_prefix) member.
+    pub fn cname_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.cname_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateEnvironmentMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.CreateEnvironmentMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.CreateEnvironmentMessage.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_settings`](struct.CreateEnvironmentMessage.html#structfield.option_settings) member.
+    pub fn option_settings<T: Into<Vec<ConfigurationOptionSetting>>>(mut self, value: T) -> Self {
+        self.option_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`options_to_remove`](struct.CreateEnvironmentMessage.html#structfield.options_to_remove) member.
+    pub fn options_to_remove<T: Into<Vec<OptionSpecification>>>(mut self, value: T) -> Self {
+        self.options_to_remove = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_arn`](struct.CreateEnvironmentMessage.html#structfield.platform_arn) member.
+    pub fn platform_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`solution_stack_name`](struct.CreateEnvironmentMessage.html#structfield.solution_stack_name) member.
+    pub fn solution_stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.solution_stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateEnvironmentMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.CreateEnvironmentMessage.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tier`](struct.CreateEnvironmentMessage.html#structfield.tier) member.
+    pub fn tier<T: Into<EnvironmentTier>>(mut self, value: T) -> Self {
+        self.tier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_label`](struct.CreateEnvironmentMessage.html#structfield.version_label) member.
+    pub fn version_label<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_label = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateEnvironmentMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateEnvironmentMessage, ::rusoto_core::BuildError> {
+        Ok(CreateEnvironmentMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            cname_prefix: self.cname_prefix,
+            description: self.description,
+            environment_name: self.environment_name,
+            group_name: self.group_name,
+            option_settings: self.option_settings,
+            options_to_remove: self.options_to_remove,
+            platform_arn: self.platform_arn,
+            solution_stack_name: self.solution_stack_name,
+            tags: self.tags,
+            template_name: self.template_name,
+            tier: self.tier,
+            version_label: self.version_label,
+        })
+    }
+}
+
 /// Serialize `CreateEnvironmentMessage` contents to a `SignedRequest`.
 struct CreateEnvironmentMessageSerializer;
 impl CreateEnvironmentMessageSerializer {
@@ -2072,6 +3133,82 @@ pub struct CreatePlatformVersionRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreatePlatformVersionRequest {
+    /// Returns a builder for `CreatePlatformVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreatePlatformVersionRequestBuilder {
+        CreatePlatformVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePlatformVersionRequest`](struct.CreatePlatformVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePlatformVersionRequestBuilder {
+    environment_name: Option<String>,
+    option_settings: Option<Vec<ConfigurationOptionSetting>>,
+    platform_definition_bundle: Option<S3Location>,
+    platform_name: Option<String>,
+    platform_version: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreatePlatformVersionRequestBuilder {
+    /// Sets the [`environment_name`](struct.CreatePlatformVersionRequest.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_settings`](struct.CreatePlatformVersionRequest.html#structfield.option_settings) member.
+    pub fn option_settings<T: Into<Vec<ConfigurationOptionSetting>>>(mut self, value: T) -> Self {
+        self.option_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_definition_bundle`](struct.CreatePlatformVersionRequest.html#structfield.platform_definition_bundle) member.
+    pub fn platform_definition_bundle<T: Into<S3Location>>(mut self, value: T) -> Self {
+        self.platform_definition_bundle = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_name`](struct.CreatePlatformVersionRequest.html#structfield.platform_name) member.
+    pub fn platform_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_version`](struct.CreatePlatformVersionRequest.html#structfield.platform_version) member.
+    pub fn platform_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreatePlatformVersionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePlatformVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePlatformVersionRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePlatformVersionRequest {
+            environment_name: self.environment_name,
+            option_settings: self.option_settings,
+            platform_definition_bundle: self.platform_definition_bundle.ok_or(
+                ::rusoto_core::BuildError::missing("platform_definition_bundle"),
+            )?,
+            platform_name: self
+                .platform_name
+                .ok_or(::rusoto_core::BuildError::missing("platform_name"))?,
+            platform_version: self
+                .platform_version
+                .ok_or(::rusoto_core::BuildError::missing("platform_version"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreatePlatformVersionRequest` contents to a `SignedRequest`.
 struct CreatePlatformVersionRequestSerializer;
 impl CreatePlatformVersionRequestSerializer {
@@ -2115,10 +3252,10 @@ pub struct CreatePlatformVersionResult {
     pub platform_summary: Option<PlatformSummary>,
 }
 
-struct CreatePlatformVersionResultDeserializer;
+pub(crate) struct CreatePlatformVersionResultDeserializer;
 impl CreatePlatformVersionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreatePlatformVersionResult, XmlParseError> {
@@ -2150,10 +3287,10 @@ pub struct CreateStorageLocationResultMessage {
     pub s3_bucket: Option<String>,
 }
 
-struct CreateStorageLocationResultMessageDeserializer;
+pub(crate) struct CreateStorageLocationResultMessageDeserializer;
 impl CreateStorageLocationResultMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateStorageLocationResultMessage, XmlParseError> {
@@ -2172,12 +3309,15 @@ impl CreateStorageLocationResultMessageDeserializer {
         )
     }
 }
-struct CreationDateDeserializer;
+pub(crate) struct CreationDateDeserializer;
 impl CreationDateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2192,10 +3332,10 @@ pub struct CustomAmi {
     pub virtualization_type: Option<String>,
 }
 
-struct CustomAmiDeserializer;
+pub(crate) struct CustomAmiDeserializer;
 impl CustomAmiDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CustomAmi, XmlParseError> {
@@ -2216,10 +3356,10 @@ impl CustomAmiDeserializer {
         })
     }
 }
-struct CustomAmiListDeserializer;
+pub(crate) struct CustomAmiListDeserializer;
 impl CustomAmiListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CustomAmi>, XmlParseError> {
@@ -2233,10 +3373,13 @@ impl CustomAmiListDeserializer {
         })
     }
 }
-struct DNSCnameDeserializer;
+pub(crate) struct DNSCnameDeserializer;
 impl DNSCnameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2253,6 +3396,46 @@ pub struct DeleteApplicationMessage {
     pub terminate_env_by_force: Option<bool>,
 }
 
+impl DeleteApplicationMessage {
+    /// Returns a builder for `DeleteApplicationMessage`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationMessageBuilder {
+        DeleteApplicationMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationMessage`](struct.DeleteApplicationMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationMessageBuilder {
+    application_name: Option<String>,
+    terminate_env_by_force: Option<bool>,
+}
+
+impl DeleteApplicationMessageBuilder {
+    /// Sets the [`application_name`](struct.DeleteApplicationMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`terminate_env_by_force`](struct.DeleteApplicationMessage.html#structfield.terminate_env_by_force) member.
+    pub fn terminate_env_by_force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.terminate_env_by_force = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteApplicationMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteApplicationMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            terminate_env_by_force: self.terminate_env_by_force,
+        })
+    }
+}
+
 /// Serialize `DeleteApplicationMessage` contents to a `SignedRequest`.
 struct DeleteApplicationMessageSerializer;
 impl DeleteApplicationMessageSerializer {
@@ -2286,6 +3469,56 @@ pub struct DeleteApplicationVersionMessage {
     pub version_label: String,
 }
 
+impl DeleteApplicationVersionMessage {
+    /// Returns a builder for `DeleteApplicationVersionMessage`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationVersionMessageBuilder {
+        DeleteApplicationVersionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationVersionMessage`](struct.DeleteApplicationVersionMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationVersionMessageBuilder {
+    application_name: Option<String>,
+    delete_source_bundle: Option<bool>,
+    version_label: Option<String>,
+}
+
+impl DeleteApplicationVersionMessageBuilder {
+    /// Sets the [`application_name`](struct.DeleteApplicationVersionMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delete_source_bundle`](struct.DeleteApplicationVersionMessage.html#structfield.delete_source_bundle) member.
+    pub fn delete_source_bundle<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_source_bundle = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_label`](struct.DeleteApplicationVersionMessage.html#structfield.version_label) member.
+    pub fn version_label<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_label = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationVersionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteApplicationVersionMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteApplicationVersionMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            delete_source_bundle: self.delete_source_bundle,
+            version_label: self
+                .version_label
+                .ok_or(::rusoto_core::BuildError::missing("version_label"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteApplicationVersionMessage` contents to a `SignedRequest`.
 struct DeleteApplicationVersionMessageSerializer;
 impl DeleteApplicationVersionMessageSerializer {
@@ -2315,6 +3548,48 @@ pub struct DeleteConfigurationTemplateMessage {
     pub template_name: String,
 }
 
+impl DeleteConfigurationTemplateMessage {
+    /// Returns a builder for `DeleteConfigurationTemplateMessage`, checking its required members when built.
+    pub fn builder() -> DeleteConfigurationTemplateMessageBuilder {
+        DeleteConfigurationTemplateMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConfigurationTemplateMessage`](struct.DeleteConfigurationTemplateMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteConfigurationTemplateMessageBuilder {
+    application_name: Option<String>,
+    template_name: Option<String>,
+}
+
+impl DeleteConfigurationTemplateMessageBuilder {
+    /// Sets the [`application_name`](struct.DeleteConfigurationTemplateMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.DeleteConfigurationTemplateMessage.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConfigurationTemplateMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteConfigurationTemplateMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteConfigurationTemplateMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            template_name: self
+                .template_name
+                .ok_or(::rusoto_core::BuildError::missing("template_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteConfigurationTemplateMessage` contents to a `SignedRequest`.
 struct DeleteConfigurationTemplateMessageSerializer;
 impl DeleteConfigurationTemplateMessageSerializer {
@@ -2341,6 +3616,49 @@ pub struct DeleteEnvironmentConfigurationMessage {
     pub environment_name: String,
 }
 
+impl DeleteEnvironmentConfigurationMessage {
+    /// Returns a builder for `DeleteEnvironmentConfigurationMessage`, checking its required members when built.
+    pub fn builder() -> DeleteEnvironmentConfigurationMessageBuilder {
+        DeleteEnvironmentConfigurationMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEnvironmentConfigurationMessage`](struct.DeleteEnvironmentConfigurationMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteEnvironmentConfigurationMessageBuilder {
+    application_name: Option<String>,
+    environment_name: Option<String>,
+}
+
+impl DeleteEnvironmentConfigurationMessageBuilder {
+    /// Sets the [`application_name`](struct.DeleteEnvironmentConfigurationMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.DeleteEnvironmentConfigurationMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEnvironmentConfigurationMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteEnvironmentConfigurationMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteEnvironmentConfigurationMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            environment_name: self
+                .environment_name
+                .ok_or(::rusoto_core::BuildError::missing("environment_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteEnvironmentConfigurationMessage` contents to a `SignedRequest`.
 struct DeleteEnvironmentConfigurationMessageSerializer;
 impl DeleteEnvironmentConfigurationMessageSerializer {
@@ -2367,6 +3685,36 @@ pub struct DeletePlatformVersionRequest {
     pub platform_arn: Option<String>,
 }
 
+impl DeletePlatformVersionRequest {
+    /// Returns a builder for `DeletePlatformVersionRequest`, checking its required members when built.
+    pub fn builder() -> DeletePlatformVersionRequestBuilder {
+        DeletePlatformVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePlatformVersionRequest`](struct.DeletePlatformVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePlatformVersionRequestBuilder {
+    platform_arn: Option<String>,
+}
+
+impl DeletePlatformVersionRequestBuilder {
+    /// Sets the [`platform_arn`](struct.DeletePlatformVersionRequest.html#structfield.platform_arn) member.
+    pub fn platform_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePlatformVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeletePlatformVersionRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePlatformVersionRequest {
+            platform_arn: self.platform_arn,
+        })
+    }
+}
+
 /// Serialize `DeletePlatformVersionRequest` contents to a `SignedRequest`.
 struct DeletePlatformVersionRequestSerializer;
 impl DeletePlatformVersionRequestSerializer {
@@ -2388,10 +3736,10 @@ pub struct DeletePlatformVersionResult {
     pub platform_summary: Option<PlatformSummary>,
 }
 
-struct DeletePlatformVersionResultDeserializer;
+pub(crate) struct DeletePlatformVersionResultDeserializer;
 impl DeletePlatformVersionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeletePlatformVersionResult, XmlParseError> {
@@ -2419,17 +3767,17 @@ pub struct Deployment {
     /// <p>The ID of the deployment. This number increases by one each time that you deploy source code or change instance configuration settings.</p>
     pub deployment_id: Option<i64>,
     /// <p>For in-progress deployments, the time that the deployment started.</p> <p>For completed deployments, the time that the deployment ended.</p>
-    pub deployment_time: Option<String>,
+    pub deployment_time: Option<IsoTimestamp>,
     /// <p><p>The status of the deployment:</p> <ul> <li> <p> <code>In Progress</code> : The deployment is in progress.</p> </li> <li> <p> <code>Deployed</code> : The deployment succeeded.</p> </li> <li> <p> <code>Failed</code> : The deployment failed.</p> </li> </ul></p>
     pub status: Option<String>,
     /// <p>The version label of the application version in the deployment.</p>
     pub version_label: Option<String>,
 }
 
-struct DeploymentDeserializer;
+pub(crate) struct DeploymentDeserializer;
 impl DeploymentDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Deployment, XmlParseError> {
@@ -2460,12 +3808,15 @@ impl DeploymentDeserializer {
         })
     }
 }
-struct DeploymentTimestampDeserializer;
+pub(crate) struct DeploymentTimestampDeserializer;
 impl DeploymentTimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -2477,10 +3828,10 @@ pub struct DescribeAccountAttributesResult {
     pub resource_quotas: Option<ResourceQuotas>,
 }
 
-struct DescribeAccountAttributesResultDeserializer;
+pub(crate) struct DescribeAccountAttributesResultDeserializer;
 impl DescribeAccountAttributesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAccountAttributesResult, XmlParseError> {
@@ -2515,6 +3866,60 @@ pub struct DescribeApplicationVersionsMessage {
     pub version_labels: Option<Vec<String>>,
 }
 
+impl DescribeApplicationVersionsMessage {
+    /// Returns a builder for `DescribeApplicationVersionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeApplicationVersionsMessageBuilder {
+        DescribeApplicationVersionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeApplicationVersionsMessage`](struct.DescribeApplicationVersionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeApplicationVersionsMessageBuilder {
+    application_name: Option<String>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+    version_labels: Option<Vec<String>>,
+}
+
+impl DescribeApplicationVersionsMessageBuilder {
+    /// Sets the [`application_name`](struct.DescribeApplicationVersionsMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeApplicationVersionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeApplicationVersionsMessage.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_labels`](struct.DescribeApplicationVersionsMessage.html#structfield.version_labels) member.
+    pub fn version_labels<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.version_labels = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeApplicationVersionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeApplicationVersionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeApplicationVersionsMessage {
+            application_name: self.application_name,
+            max_records: self.max_records,
+            next_token: self.next_token,
+            version_labels: self.version_labels,
+        })
+    }
+}
+
 /// Serialize `DescribeApplicationVersionsMessage` contents to a `SignedRequest`.
 struct DescribeApplicationVersionsMessageSerializer;
 impl DescribeApplicationVersionsMessageSerializer {
@@ -2550,9 +3955,39 @@ pub struct DescribeApplicationsMessage {
     pub application_names: Option<Vec<String>>,
 }
 
-/// Serialize `DescribeApplicationsMessage` contents to a `SignedRequest`.
-struct DescribeApplicationsMessageSerializer;
-impl DescribeApplicationsMessageSerializer {
+impl DescribeApplicationsMessage {
+    /// Returns a builder for `DescribeApplicationsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeApplicationsMessageBuilder {
+        DescribeApplicationsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeApplicationsMessage`](struct.DescribeApplicationsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeApplicationsMessageBuilder {
+    application_names: Option<Vec<String>>,
+}
+
+impl DescribeApplicationsMessageBuilder {
+    /// Sets the [`application_names`](struct.DescribeApplicationsMessage.html#structfield.application_names) member.
+    pub fn application_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.application_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeApplicationsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeApplicationsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeApplicationsMessage {
+            application_names: self.application_names,
+        })
+    }
+}
+
+/// Serialize `DescribeApplicationsMessage` contents to a `SignedRequest`.
+struct DescribeApplicationsMessageSerializer;
+impl DescribeApplicationsMessageSerializer {
     fn serialize(params: &mut Params, name: &str, obj: &DescribeApplicationsMessage) {
         let mut prefix = name.to_string();
         if prefix != "" {
@@ -2586,6 +4021,76 @@ pub struct DescribeConfigurationOptionsMessage {
     pub template_name: Option<String>,
 }
 
+impl DescribeConfigurationOptionsMessage {
+    /// Returns a builder for `DescribeConfigurationOptionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeConfigurationOptionsMessageBuilder {
+        DescribeConfigurationOptionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigurationOptionsMessage`](struct.DescribeConfigurationOptionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigurationOptionsMessageBuilder {
+    application_name: Option<String>,
+    environment_name: Option<String>,
+    options: Option<Vec<OptionSpecification>>,
+    platform_arn: Option<String>,
+    solution_stack_name: Option<String>,
+    template_name: Option<String>,
+}
+
+impl DescribeConfigurationOptionsMessageBuilder {
+    /// Sets the [`application_name`](struct.DescribeConfigurationOptionsMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.DescribeConfigurationOptionsMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`options`](struct.DescribeConfigurationOptionsMessage.html#structfield.options) member.
+    pub fn options<T: Into<Vec<OptionSpecification>>>(mut self, value: T) -> Self {
+        self.options = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_arn`](struct.DescribeConfigurationOptionsMessage.html#structfield.platform_arn) member.
+    pub fn platform_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`solution_stack_name`](struct.DescribeConfigurationOptionsMessage.html#structfield.solution_stack_name) member.
+    pub fn solution_stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.solution_stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.DescribeConfigurationOptionsMessage.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigurationOptionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigurationOptionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeConfigurationOptionsMessage {
+            application_name: self.application_name,
+            environment_name: self.environment_name,
+            options: self.options,
+            platform_arn: self.platform_arn,
+            solution_stack_name: self.solution_stack_name,
+            template_name: self.template_name,
+        })
+    }
+}
+
 /// Serialize `DescribeConfigurationOptionsMessage` contents to a `SignedRequest`.
 struct DescribeConfigurationOptionsMessageSerializer;
 impl DescribeConfigurationOptionsMessageSerializer {
@@ -2631,6 +4136,55 @@ pub struct DescribeConfigurationSettingsMessage {
     pub template_name: Option<String>,
 }
 
+impl DescribeConfigurationSettingsMessage {
+    /// Returns a builder for `DescribeConfigurationSettingsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeConfigurationSettingsMessageBuilder {
+        DescribeConfigurationSettingsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigurationSettingsMessage`](struct.DescribeConfigurationSettingsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigurationSettingsMessageBuilder {
+    application_name: Option<String>,
+    environment_name: Option<String>,
+    template_name: Option<String>,
+}
+
+impl DescribeConfigurationSettingsMessageBuilder {
+    /// Sets the [`application_name`](struct.DescribeConfigurationSettingsMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.DescribeConfigurationSettingsMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.DescribeConfigurationSettingsMessage.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigurationSettingsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigurationSettingsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeConfigurationSettingsMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            environment_name: self.environment_name,
+            template_name: self.template_name,
+        })
+    }
+}
+
 /// Serialize `DescribeConfigurationSettingsMessage` contents to a `SignedRequest`.
 struct DescribeConfigurationSettingsMessageSerializer;
 impl DescribeConfigurationSettingsMessageSerializer {
@@ -2657,13 +4211,59 @@ impl DescribeConfigurationSettingsMessageSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DescribeEnvironmentHealthRequest {
     /// <p>Specify the response elements to return. To retrieve all attributes, set to <code>All</code>. If no attribute names are specified, returns the name of the environment.</p>
-    pub attribute_names: Option<Vec<String>>,
+    pub attribute_names: Option<Vec<EnvironmentHealthAttribute>>,
     /// <p>Specify the environment by ID.</p> <p>You must specify either this or an EnvironmentName, or both.</p>
     pub environment_id: Option<String>,
     /// <p>Specify the environment by name.</p> <p>You must specify either this or an EnvironmentName, or both.</p>
     pub environment_name: Option<String>,
 }
 
+impl DescribeEnvironmentHealthRequest {
+    /// Returns a builder for `DescribeEnvironmentHealthRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEnvironmentHealthRequestBuilder {
+        DescribeEnvironmentHealthRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEnvironmentHealthRequest`](struct.DescribeEnvironmentHealthRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEnvironmentHealthRequestBuilder {
+    attribute_names: Option<Vec<EnvironmentHealthAttribute>>,
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+}
+
+impl DescribeEnvironmentHealthRequestBuilder {
+    /// Sets the [`attribute_names`](struct.DescribeEnvironmentHealthRequest.html#structfield.attribute_names) member.
+    pub fn attribute_names<T: Into<Vec<EnvironmentHealthAttribute>>>(mut self, value: T) -> Self {
+        self.attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_id`](struct.DescribeEnvironmentHealthRequest.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.DescribeEnvironmentHealthRequest.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEnvironmentHealthRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEnvironmentHealthRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEnvironmentHealthRequest {
+            attribute_names: self.attribute_names,
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+        })
+    }
+}
+
 /// Serialize `DescribeEnvironmentHealthRequest` contents to a `SignedRequest`.
 struct DescribeEnvironmentHealthRequestSerializer;
 impl DescribeEnvironmentHealthRequestSerializer {
@@ -2705,15 +4305,15 @@ pub struct DescribeEnvironmentHealthResult {
     /// <p>Summary health information for the instances in the environment.</p>
     pub instances_health: Option<InstanceHealthSummary>,
     /// <p>The date and time that the health information was retrieved.</p>
-    pub refreshed_at: Option<String>,
+    pub refreshed_at: Option<IsoTimestamp>,
     /// <p>The environment's operational status. <code>Ready</code>, <code>Launching</code>, <code>Updating</code>, <code>Terminating</code>, or <code>Terminated</code>.</p>
-    pub status: Option<String>,
+    pub status: Option<EnvironmentHealth>,
 }
 
-struct DescribeEnvironmentHealthResultDeserializer;
+pub(crate) struct DescribeEnvironmentHealthResultDeserializer;
 impl DescribeEnvironmentHealthResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeEnvironmentHealthResult, XmlParseError> {
@@ -2782,6 +4382,63 @@ pub struct DescribeEnvironmentManagedActionHistoryRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeEnvironmentManagedActionHistoryRequest {
+    /// Returns a builder for `DescribeEnvironmentManagedActionHistoryRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEnvironmentManagedActionHistoryRequestBuilder {
+        DescribeEnvironmentManagedActionHistoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEnvironmentManagedActionHistoryRequest`](struct.DescribeEnvironmentManagedActionHistoryRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEnvironmentManagedActionHistoryRequestBuilder {
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+    max_items: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeEnvironmentManagedActionHistoryRequestBuilder {
+    /// Sets the [`environment_id`](struct.DescribeEnvironmentManagedActionHistoryRequest.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.DescribeEnvironmentManagedActionHistoryRequest.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.DescribeEnvironmentManagedActionHistoryRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeEnvironmentManagedActionHistoryRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEnvironmentManagedActionHistoryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeEnvironmentManagedActionHistoryRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeEnvironmentManagedActionHistoryRequest {
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+            max_items: self.max_items,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `DescribeEnvironmentManagedActionHistoryRequest` contents to a `SignedRequest`.
 struct DescribeEnvironmentManagedActionHistoryRequestSerializer;
 impl DescribeEnvironmentManagedActionHistoryRequestSerializer {
@@ -2819,10 +4476,10 @@ pub struct DescribeEnvironmentManagedActionHistoryResult {
     pub next_token: Option<String>,
 }
 
-struct DescribeEnvironmentManagedActionHistoryResultDeserializer;
+pub(crate) struct DescribeEnvironmentManagedActionHistoryResultDeserializer;
 impl DescribeEnvironmentManagedActionHistoryResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeEnvironmentManagedActionHistoryResult, XmlParseError> {
@@ -2857,7 +4514,54 @@ pub struct DescribeEnvironmentManagedActionsRequest {
     /// <p>The name of the target environment.</p>
     pub environment_name: Option<String>,
     /// <p>To show only actions with a particular status, specify a status.</p>
-    pub status: Option<String>,
+    pub status: Option<ActionStatus>,
+}
+
+impl DescribeEnvironmentManagedActionsRequest {
+    /// Returns a builder for `DescribeEnvironmentManagedActionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEnvironmentManagedActionsRequestBuilder {
+        DescribeEnvironmentManagedActionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEnvironmentManagedActionsRequest`](struct.DescribeEnvironmentManagedActionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEnvironmentManagedActionsRequestBuilder {
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+    status: Option<ActionStatus>,
+}
+
+impl DescribeEnvironmentManagedActionsRequestBuilder {
+    /// Sets the [`environment_id`](struct.DescribeEnvironmentManagedActionsRequest.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.DescribeEnvironmentManagedActionsRequest.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.DescribeEnvironmentManagedActionsRequest.html#structfield.status) member.
+    pub fn status<T: Into<ActionStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEnvironmentManagedActionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEnvironmentManagedActionsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeEnvironmentManagedActionsRequest {
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+            status: self.status,
+        })
+    }
 }
 
 /// Serialize `DescribeEnvironmentManagedActionsRequest` contents to a `SignedRequest`.
@@ -2888,10 +4592,10 @@ pub struct DescribeEnvironmentManagedActionsResult {
     pub managed_actions: Option<Vec<ManagedAction>>,
 }
 
-struct DescribeEnvironmentManagedActionsResultDeserializer;
+pub(crate) struct DescribeEnvironmentManagedActionsResultDeserializer;
 impl DescribeEnvironmentManagedActionsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeEnvironmentManagedActionsResult, XmlParseError> {
@@ -2921,6 +4625,44 @@ pub struct DescribeEnvironmentResourcesMessage {
     pub environment_name: Option<String>,
 }
 
+impl DescribeEnvironmentResourcesMessage {
+    /// Returns a builder for `DescribeEnvironmentResourcesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEnvironmentResourcesMessageBuilder {
+        DescribeEnvironmentResourcesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEnvironmentResourcesMessage`](struct.DescribeEnvironmentResourcesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEnvironmentResourcesMessageBuilder {
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+}
+
+impl DescribeEnvironmentResourcesMessageBuilder {
+    /// Sets the [`environment_id`](struct.DescribeEnvironmentResourcesMessage.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.DescribeEnvironmentResourcesMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEnvironmentResourcesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEnvironmentResourcesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEnvironmentResourcesMessage {
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+        })
+    }
+}
+
 /// Serialize `DescribeEnvironmentResourcesMessage` contents to a `SignedRequest`.
 struct DescribeEnvironmentResourcesMessageSerializer;
 impl DescribeEnvironmentResourcesMessageSerializer {
@@ -2951,7 +4693,7 @@ pub struct DescribeEnvironmentsMessage {
     /// <p>Indicates whether to include deleted environments:</p> <p> <code>true</code>: Environments that have been deleted after <code>IncludedDeletedBackTo</code> are displayed.</p> <p> <code>false</code>: Do not include deleted environments.</p>
     pub include_deleted: Option<bool>,
     /// <p> If specified when <code>IncludeDeleted</code> is set to <code>true</code>, then environments deleted after this date are displayed. </p>
-    pub included_deleted_back_to: Option<String>,
+    pub included_deleted_back_to: Option<IsoTimestamp>,
     /// <p>For a paginated request. Specify a maximum number of environments to include in each response.</p> <p>If no <code>MaxRecords</code> is specified, all available environments are retrieved in a single response.</p>
     pub max_records: Option<i64>,
     /// <p>For a paginated request. Specify a token from a previous response page to retrieve the next response page. All other parameter values must be identical to the ones specified in the initial request.</p> <p>If no <code>NextToken</code> is specified, the first page is retrieved.</p>
@@ -2960,6 +4702,92 @@ pub struct DescribeEnvironmentsMessage {
     pub version_label: Option<String>,
 }
 
+impl DescribeEnvironmentsMessage {
+    /// Returns a builder for `DescribeEnvironmentsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEnvironmentsMessageBuilder {
+        DescribeEnvironmentsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEnvironmentsMessage`](struct.DescribeEnvironmentsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEnvironmentsMessageBuilder {
+    application_name: Option<String>,
+    environment_ids: Option<Vec<String>>,
+    environment_names: Option<Vec<String>>,
+    include_deleted: Option<bool>,
+    included_deleted_back_to: Option<IsoTimestamp>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+    version_label: Option<String>,
+}
+
+impl DescribeEnvironmentsMessageBuilder {
+    /// Sets the [`application_name`](struct.DescribeEnvironmentsMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_ids`](struct.DescribeEnvironmentsMessage.html#structfield.environment_ids) member.
+    pub fn environment_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.environment_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_names`](struct.DescribeEnvironmentsMessage.html#structfield.environment_names) member.
+    pub fn environment_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.environment_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_deleted`](struct.DescribeEnvironmentsMessage.html#structfield.include_deleted) member.
+    pub fn include_deleted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_deleted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`included_deleted_back_to`](struct.DescribeEnvironmentsMessage.html#structfield.included_deleted_back_to) member.
+    pub fn included_deleted_back_to<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.included_deleted_back_to = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEnvironmentsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeEnvironmentsMessage.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_label`](struct.DescribeEnvironmentsMessage.html#structfield.version_label) member.
+    pub fn version_label<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_label = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEnvironmentsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEnvironmentsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEnvironmentsMessage {
+            application_name: self.application_name,
+            environment_ids: self.environment_ids,
+            environment_names: self.environment_names,
+            include_deleted: self.include_deleted,
+            included_deleted_back_to: self.included_deleted_back_to,
+            max_records: self.max_records,
+            next_token: self.next_token,
+            version_label: self.version_label,
+        })
+    }
+}
+
 /// Serialize `DescribeEnvironmentsMessage` contents to a `SignedRequest`.
 struct DescribeEnvironmentsMessageSerializer;
 impl DescribeEnvironmentsMessageSerializer {
@@ -3013,7 +4841,7 @@ pub struct DescribeEventsMessage {
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to include only those associated with this application.</p>
     pub application_name: Option<String>,
     /// <p> If specified, AWS Elastic Beanstalk restricts the returned descriptions to those that occur up to, but not including, the <code>EndTime</code>. </p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to those associated with this environment.</p>
     pub environment_id: Option<String>,
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to those associated with this environment.</p>
@@ -3027,15 +4855,131 @@ pub struct DescribeEventsMessage {
     /// <p>If specified, AWS Elastic Beanstalk restricts the described events to include only those associated with this request ID.</p>
     pub request_id: Option<String>,
     /// <p>If specified, limits the events returned from this call to include only those with the specified severity or higher.</p>
-    pub severity: Option<String>,
+    pub severity: Option<EventSeverity>,
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to those that occur on or after this time.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to those that are associated with this environment configuration.</p>
     pub template_name: Option<String>,
     /// <p>If specified, AWS Elastic Beanstalk restricts the returned descriptions to those associated with this application version.</p>
     pub version_label: Option<String>,
 }
 
+impl DescribeEventsMessage {
+    /// Returns a builder for `DescribeEventsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventsMessageBuilder {
+        DescribeEventsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventsMessage`](struct.DescribeEventsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventsMessageBuilder {
+    application_name: Option<String>,
+    end_time: Option<IsoTimestamp>,
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+    platform_arn: Option<String>,
+    request_id: Option<String>,
+    severity: Option<EventSeverity>,
+    start_time: Option<IsoTimestamp>,
+    template_name: Option<String>,
+    version_label: Option<String>,
+}
+
+impl DescribeEventsMessageBuilder {
+    /// Sets the [`application_name`](struct.DescribeEventsMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.DescribeEventsMessage.html#structfield.end_time) member.
+    pub fn end_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_id`](struct.DescribeEventsMessage.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.DescribeEventsMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEventsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeEventsMessage.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_arn`](struct.DescribeEventsMessage.html#structfield.platform_arn) member.
+    pub fn platform_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_id`](struct.DescribeEventsMessage.html#structfield.request_id) member.
+    pub fn request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`severity`](struct.DescribeEventsMessage.html#structfield.severity) member.
+    pub fn severity<T: Into<EventSeverity>>(mut self, value: T) -> Self {
+        self.severity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.DescribeEventsMessage.html#structfield.start_time) member.
+    pub fn start_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.DescribeEventsMessage.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_label`](struct.DescribeEventsMessage.html#structfield.version_label) member.
+    pub fn version_label<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_label = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventsMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeEventsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventsMessage {
+            application_name: self.application_name,
+            end_time: self.end_time,
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+            max_records: self.max_records,
+            next_token: self.next_token,
+            platform_arn: self.platform_arn,
+            request_id: self.request_id,
+            severity: self.severity,
+            start_time: self.start_time,
+            template_name: self.template_name,
+            version_label: self.version_label,
+        })
+    }
+}
+
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
 struct DescribeEventsMessageSerializer;
 impl DescribeEventsMessageSerializer {
@@ -3088,7 +5032,7 @@ impl DescribeEventsMessageSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DescribeInstancesHealthRequest {
     /// <p>Specifies the response elements you wish to receive. To retrieve all attributes, set to <code>All</code>. If no attribute names are specified, returns a list of instances.</p>
-    pub attribute_names: Option<Vec<String>>,
+    pub attribute_names: Option<Vec<InstancesHealthAttribute>>,
     /// <p>Specify the AWS Elastic Beanstalk environment by ID.</p>
     pub environment_id: Option<String>,
     /// <p>Specify the AWS Elastic Beanstalk environment by name.</p>
@@ -3097,6 +5041,60 @@ pub struct DescribeInstancesHealthRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeInstancesHealthRequest {
+    /// Returns a builder for `DescribeInstancesHealthRequest`, checking its required members when built.
+    pub fn builder() -> DescribeInstancesHealthRequestBuilder {
+        DescribeInstancesHealthRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeInstancesHealthRequest`](struct.DescribeInstancesHealthRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeInstancesHealthRequestBuilder {
+    attribute_names: Option<Vec<InstancesHealthAttribute>>,
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl DescribeInstancesHealthRequestBuilder {
+    /// Sets the [`attribute_names`](struct.DescribeInstancesHealthRequest.html#structfield.attribute_names) member.
+    pub fn attribute_names<T: Into<Vec<InstancesHealthAttribute>>>(mut self, value: T) -> Self {
+        self.attribute_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_id`](struct.DescribeInstancesHealthRequest.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.DescribeInstancesHealthRequest.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeInstancesHealthRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeInstancesHealthRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeInstancesHealthRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeInstancesHealthRequest {
+            attribute_names: self.attribute_names,
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `DescribeInstancesHealthRequest` contents to a `SignedRequest`.
 struct DescribeInstancesHealthRequestSerializer;
 impl DescribeInstancesHealthRequestSerializer {
@@ -3133,13 +5131,13 @@ pub struct DescribeInstancesHealthResult {
     /// <p>Pagination token for the next page of results, if available.</p>
     pub next_token: Option<String>,
     /// <p>The date and time that the health information was retrieved.</p>
-    pub refreshed_at: Option<String>,
+    pub refreshed_at: Option<IsoTimestamp>,
 }
 
-struct DescribeInstancesHealthResultDeserializer;
+pub(crate) struct DescribeInstancesHealthResultDeserializer;
 impl DescribeInstancesHealthResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeInstancesHealthResult, XmlParseError> {
@@ -3177,6 +5175,36 @@ pub struct DescribePlatformVersionRequest {
     pub platform_arn: Option<String>,
 }
 
+impl DescribePlatformVersionRequest {
+    /// Returns a builder for `DescribePlatformVersionRequest`, checking its required members when built.
+    pub fn builder() -> DescribePlatformVersionRequestBuilder {
+        DescribePlatformVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePlatformVersionRequest`](struct.DescribePlatformVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribePlatformVersionRequestBuilder {
+    platform_arn: Option<String>,
+}
+
+impl DescribePlatformVersionRequestBuilder {
+    /// Sets the [`platform_arn`](struct.DescribePlatformVersionRequest.html#structfield.platform_arn) member.
+    pub fn platform_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePlatformVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePlatformVersionRequest, ::rusoto_core::BuildError> {
+        Ok(DescribePlatformVersionRequest {
+            platform_arn: self.platform_arn,
+        })
+    }
+}
+
 /// Serialize `DescribePlatformVersionRequest` contents to a `SignedRequest`.
 struct DescribePlatformVersionRequestSerializer;
 impl DescribePlatformVersionRequestSerializer {
@@ -3198,10 +5226,10 @@ pub struct DescribePlatformVersionResult {
     pub platform_description: Option<PlatformDescription>,
 }
 
-struct DescribePlatformVersionResultDeserializer;
+pub(crate) struct DescribePlatformVersionResultDeserializer;
 impl DescribePlatformVersionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribePlatformVersionResult, XmlParseError> {
@@ -3224,10 +5252,13 @@ impl DescribePlatformVersionResultDeserializer {
         )
     }
 }
-struct DescriptionDeserializer;
+pub(crate) struct DescriptionDeserializer;
 impl DescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3235,10 +5266,13 @@ impl DescriptionDeserializer {
         Ok(obj)
     }
 }
-struct Ec2InstanceIdDeserializer;
+pub(crate) struct Ec2InstanceIdDeserializer;
 impl Ec2InstanceIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3246,10 +5280,13 @@ impl Ec2InstanceIdDeserializer {
         Ok(obj)
     }
 }
-struct EndpointURLDeserializer;
+pub(crate) struct EndpointURLDeserializer;
 impl EndpointURLDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3257,10 +5294,13 @@ impl EndpointURLDeserializer {
         Ok(obj)
     }
 }
-struct EnvironmentArnDeserializer;
+pub(crate) struct EnvironmentArnDeserializer;
 impl EnvironmentArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3278,9 +5318,9 @@ pub struct EnvironmentDescription {
     /// <p>The URL to the CNAME for this environment.</p>
     pub cname: Option<String>,
     /// <p>The creation date for this environment.</p>
-    pub date_created: Option<String>,
+    pub date_created: Option<IsoTimestamp>,
     /// <p>The last modified date for this environment.</p>
-    pub date_updated: Option<String>,
+    pub date_updated: Option<IsoTimestamp>,
     /// <p>Describes this environment.</p>
     pub description: Option<String>,
     /// <p>For load-balanced, autoscaling environments, the URL to the LoadBalancer. For single-instance environments, the IP address of the instance.</p>
@@ -3294,9 +5334,9 @@ pub struct EnvironmentDescription {
     /// <p>The name of this environment.</p>
     pub environment_name: Option<String>,
     /// <p>Describes the health status of the environment. AWS Elastic Beanstalk indicates the failure levels for a running environment:</p> <ul> <li> <p> <code>Red</code>: Indicates the environment is not responsive. Occurs when three or more consecutive failures occur for an environment.</p> </li> <li> <p> <code>Yellow</code>: Indicates that something is wrong. Occurs when two consecutive failures occur for an environment.</p> </li> <li> <p> <code>Green</code>: Indicates the environment is healthy and fully functional.</p> </li> <li> <p> <code>Grey</code>: Default health for a new environment. The environment is not fully launched and health checks have not started or health checks are suspended during an <code>UpdateEnvironment</code> or <code>RestartEnvironment</code> request.</p> </li> </ul> <p> Default: <code>Grey</code> </p>
-    pub health: Option<String>,
+    pub health: Option<EnvironmentHealth>,
     /// <p>Returns the health status of the application running in your environment. For more information, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/health-enhanced-status.html">Health Colors and Statuses</a>.</p>
-    pub health_status: Option<String>,
+    pub health_status: Option<EnvironmentHealthStatus>,
     /// <p>The ARN of the platform.</p>
     pub platform_arn: Option<String>,
     /// <p>The description of the AWS resources used by this environment.</p>
@@ -3304,7 +5344,7 @@ pub struct EnvironmentDescription {
     /// <p> The name of the <code>SolutionStack</code> deployed with this environment. </p>
     pub solution_stack_name: Option<String>,
     /// <p><p>The current operational status of the environment:</p> <ul> <li> <p> <code>Launching</code>: Environment is in the process of initial deployment.</p> </li> <li> <p> <code>Updating</code>: Environment is in the process of updating its configuration settings or application version.</p> </li> <li> <p> <code>Ready</code>: Environment is available to have an action performed on it, such as update or terminate.</p> </li> <li> <p> <code>Terminating</code>: Environment is in the shut-down process.</p> </li> <li> <p> <code>Terminated</code>: Environment is not running.</p> </li> </ul></p>
-    pub status: Option<String>,
+    pub status: Option<EnvironmentStatus>,
     /// <p>The name of the configuration template used to originally launch this environment.</p>
     pub template_name: Option<String>,
     /// <p>Describes the current tier of this environment.</p>
@@ -3313,10 +5353,10 @@ pub struct EnvironmentDescription {
     pub version_label: Option<String>,
 }
 
-struct EnvironmentDescriptionDeserializer;
+pub(crate) struct EnvironmentDescriptionDeserializer;
 impl EnvironmentDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EnvironmentDescription, XmlParseError> {
@@ -3426,10 +5466,10 @@ impl EnvironmentDescriptionDeserializer {
         })
     }
 }
-struct EnvironmentDescriptionsListDeserializer;
+pub(crate) struct EnvironmentDescriptionsListDeserializer;
 impl EnvironmentDescriptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EnvironmentDescription>, XmlParseError> {
@@ -3454,10 +5494,10 @@ pub struct EnvironmentDescriptionsMessage {
     pub next_token: Option<String>,
 }
 
-struct EnvironmentDescriptionsMessageDeserializer;
+pub(crate) struct EnvironmentDescriptionsMessageDeserializer;
 impl EnvironmentDescriptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EnvironmentDescriptionsMessage, XmlParseError> {
@@ -3484,80 +5524,326 @@ impl EnvironmentDescriptionsMessageDeserializer {
         )
     }
 }
-struct EnvironmentHealthDeserializer;
+pub(crate) struct EnvironmentHealthDeserializer;
 impl EnvironmentHealthDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EnvironmentHealth, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
 
-/// Serialize `EnvironmentHealthAttributes` contents to a `SignedRequest`.
-struct EnvironmentHealthAttributesSerializer;
-impl EnvironmentHealthAttributesSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
-        for (index, obj) in obj.iter().enumerate() {
-            let key = format!("{}.member.{}", name, index + 1);
-            params.put(&key, &obj);
+/// <p>The environment's operational status. <code>Ready</code>, <code>Launching</code>, <code>Updating</code>, <code>Terminating</code>, or <code>Terminated</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnvironmentHealth {
+    /// <p><code>Green</code> - Indicates the environment is healthy and fully functional.</p>
+    Green,
+    /// <p><code>Yellow</code> - Indicates that something is wrong. Occurs when two consecutive failures occur for an environment.</p>
+    Yellow,
+    /// <p><code>Red</code> - Indicates the environment is not responsive. Occurs when three or more consecutive failures occur for an environment.</p>
+    Red,
+    /// <p><code>Grey</code> - Default health for a new environment. The environment is not fully launched and health checks have not started or health checks are suspended during an <code>UpdateEnvironment</code> or <code>RestartEnvironment</code> request.</p>
+    Grey,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnvironmentHealth {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentHealth::Green => "Green",
+            EnvironmentHealth::Yellow => "Yellow",
+            EnvironmentHealth::Red => "Red",
+            EnvironmentHealth::Grey => "Grey",
+            EnvironmentHealth::Unknown(ref value) => value,
         }
     }
 }
 
-struct EnvironmentHealthStatusDeserializer;
-impl EnvironmentHealthStatusDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+impl<'a> From<&'a str> for EnvironmentHealth {
+    fn from(value: &'a str) -> EnvironmentHealth {
+        match value {
+            "Green" => EnvironmentHealth::Green,
+            "Yellow" => EnvironmentHealth::Yellow,
+            "Red" => EnvironmentHealth::Red,
+            "Grey" => EnvironmentHealth::Grey,
+            _ => EnvironmentHealth::Unknown(value.to_owned()),
+        }
+    }
+}
 
-        Ok(obj)
+impl From<String> for EnvironmentHealth {
+    fn from(value: String) -> EnvironmentHealth {
+        EnvironmentHealth::from(value.as_str())
     }
 }
-struct EnvironmentIdDeserializer;
-impl EnvironmentIdDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl From<EnvironmentHealth> for String {
+    fn from(value: EnvironmentHealth) -> String {
+        match value {
+            EnvironmentHealth::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
     }
 }
 
-/// Serialize `EnvironmentIdList` contents to a `SignedRequest`.
-struct EnvironmentIdListSerializer;
-impl EnvironmentIdListSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
-        for (index, obj) in obj.iter().enumerate() {
-            let key = format!("{}.member.{}", name, index + 1);
-            params.put(&key, &obj);
+impl fmt::Display for EnvironmentHealth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentHealth {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// <p>Specify the response elements to return. To retrieve all attributes, set to <code>All</code>. If no attribute names are specified, returns the name of the environment.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnvironmentHealthAttribute {
+    /// <p><code>Status</code></p>
+    Status,
+    /// <p><code>Color</code></p>
+    Color,
+    /// <p><code>Causes</code></p>
+    Causes,
+    /// <p><code>ApplicationMetrics</code></p>
+    ApplicationMetrics,
+    /// <p><code>InstancesHealth</code></p>
+    InstancesHealth,
+    /// <p><code>All</code></p>
+    All,
+    /// <p><code>HealthStatus</code></p>
+    HealthStatus,
+    /// <p><code>RefreshedAt</code></p>
+    RefreshedAt,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnvironmentHealthAttribute {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentHealthAttribute::Status => "Status",
+            EnvironmentHealthAttribute::Color => "Color",
+            EnvironmentHealthAttribute::Causes => "Causes",
+            EnvironmentHealthAttribute::ApplicationMetrics => "ApplicationMetrics",
+            EnvironmentHealthAttribute::InstancesHealth => "InstancesHealth",
+            EnvironmentHealthAttribute::All => "All",
+            EnvironmentHealthAttribute::HealthStatus => "HealthStatus",
+            EnvironmentHealthAttribute::RefreshedAt => "RefreshedAt",
+            EnvironmentHealthAttribute::Unknown(ref value) => value,
         }
     }
 }
 
-/// <p>The information retrieved from the Amazon EC2 instances.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct EnvironmentInfoDescription {
-    /// <p>The Amazon EC2 Instance ID for this information.</p>
-    pub ec_2_instance_id: Option<String>,
-    /// <p>The type of information retrieved.</p>
-    pub info_type: Option<String>,
-    /// <p>The retrieved information. Currently contains a presigned Amazon S3 URL. The files are deleted after 15 minutes.</p> <p>Anyone in possession of this URL can access the files before they are deleted. Make the URL available only to trusted parties.</p>
+impl<'a> From<&'a str> for EnvironmentHealthAttribute {
+    fn from(value: &'a str) -> EnvironmentHealthAttribute {
+        match value {
+            "Status" => EnvironmentHealthAttribute::Status,
+            "Color" => EnvironmentHealthAttribute::Color,
+            "Causes" => EnvironmentHealthAttribute::Causes,
+            "ApplicationMetrics" => EnvironmentHealthAttribute::ApplicationMetrics,
+            "InstancesHealth" => EnvironmentHealthAttribute::InstancesHealth,
+            "All" => EnvironmentHealthAttribute::All,
+            "HealthStatus" => EnvironmentHealthAttribute::HealthStatus,
+            "RefreshedAt" => EnvironmentHealthAttribute::RefreshedAt,
+            _ => EnvironmentHealthAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentHealthAttribute {
+    fn from(value: String) -> EnvironmentHealthAttribute {
+        EnvironmentHealthAttribute::from(value.as_str())
+    }
+}
+
+impl From<EnvironmentHealthAttribute> for String {
+    fn from(value: EnvironmentHealthAttribute) -> String {
+        match value {
+            EnvironmentHealthAttribute::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnvironmentHealthAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentHealthAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// Serialize `EnvironmentHealthAttributes` contents to a `SignedRequest`.
+struct EnvironmentHealthAttributesSerializer;
+impl EnvironmentHealthAttributesSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<EnvironmentHealthAttribute>) {
+        for (index, obj) in obj.iter().enumerate() {
+            let key = format!("{}.member.{}", name, index + 1);
+            params.put(&key, &obj);
+        }
+    }
+}
+
+pub(crate) struct EnvironmentHealthStatusDeserializer;
+impl EnvironmentHealthStatusDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EnvironmentHealthStatus, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+/// <p>Returns the health status of the application running in your environment. For more information, see <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/health-enhanced-status.html">Health Colors and Statuses</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnvironmentHealthStatus {
+    /// <p><code>NoData</code></p>
+    NoData,
+    /// <p><code>Unknown</code></p>
+    UnknownValue,
+    /// <p><code>Pending</code></p>
+    Pending,
+    /// <p><code>Ok</code></p>
+    Ok,
+    /// <p><code>Info</code></p>
+    Info,
+    /// <p><code>Warning</code></p>
+    Warning,
+    /// <p><code>Degraded</code></p>
+    Degraded,
+    /// <p><code>Severe</code></p>
+    Severe,
+    /// <p><code>Suspended</code></p>
+    Suspended,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnvironmentHealthStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentHealthStatus::NoData => "NoData",
+            EnvironmentHealthStatus::UnknownValue => "Unknown",
+            EnvironmentHealthStatus::Pending => "Pending",
+            EnvironmentHealthStatus::Ok => "Ok",
+            EnvironmentHealthStatus::Info => "Info",
+            EnvironmentHealthStatus::Warning => "Warning",
+            EnvironmentHealthStatus::Degraded => "Degraded",
+            EnvironmentHealthStatus::Severe => "Severe",
+            EnvironmentHealthStatus::Suspended => "Suspended",
+            EnvironmentHealthStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentHealthStatus {
+    fn from(value: &'a str) -> EnvironmentHealthStatus {
+        match value {
+            "NoData" => EnvironmentHealthStatus::NoData,
+            "Unknown" => EnvironmentHealthStatus::UnknownValue,
+            "Pending" => EnvironmentHealthStatus::Pending,
+            "Ok" => EnvironmentHealthStatus::Ok,
+            "Info" => EnvironmentHealthStatus::Info,
+            "Warning" => EnvironmentHealthStatus::Warning,
+            "Degraded" => EnvironmentHealthStatus::Degraded,
+            "Severe" => EnvironmentHealthStatus::Severe,
+            "Suspended" => EnvironmentHealthStatus::Suspended,
+            _ => EnvironmentHealthStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentHealthStatus {
+    fn from(value: String) -> EnvironmentHealthStatus {
+        EnvironmentHealthStatus::from(value.as_str())
+    }
+}
+
+impl From<EnvironmentHealthStatus> for String {
+    fn from(value: EnvironmentHealthStatus) -> String {
+        match value {
+            EnvironmentHealthStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnvironmentHealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentHealthStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct EnvironmentIdDeserializer;
+impl EnvironmentIdDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+
+/// Serialize `EnvironmentIdList` contents to a `SignedRequest`.
+struct EnvironmentIdListSerializer;
+impl EnvironmentIdListSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+        for (index, obj) in obj.iter().enumerate() {
+            let key = format!("{}.member.{}", name, index + 1);
+            params.put(&key, &obj);
+        }
+    }
+}
+
+/// <p>The information retrieved from the Amazon EC2 instances.</p>
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct EnvironmentInfoDescription {
+    /// <p>The Amazon EC2 Instance ID for this information.</p>
+    pub ec_2_instance_id: Option<String>,
+    /// <p>The type of information retrieved.</p>
+    pub info_type: Option<EnvironmentInfoType>,
+    /// <p>The retrieved information. Currently contains a presigned Amazon S3 URL. The files are deleted after 15 minutes.</p> <p>Anyone in possession of this URL can access the files before they are deleted. Make the URL available only to trusted parties.</p>
     pub message: Option<String>,
     /// <p>The time stamp when this information was retrieved.</p>
-    pub sample_timestamp: Option<String>,
+    pub sample_timestamp: Option<IsoTimestamp>,
 }
 
-struct EnvironmentInfoDescriptionDeserializer;
+pub(crate) struct EnvironmentInfoDescriptionDeserializer;
 impl EnvironmentInfoDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EnvironmentInfoDescription, XmlParseError> {
@@ -3593,10 +5879,10 @@ impl EnvironmentInfoDescriptionDeserializer {
         )
     }
 }
-struct EnvironmentInfoDescriptionListDeserializer;
+pub(crate) struct EnvironmentInfoDescriptionListDeserializer;
 impl EnvironmentInfoDescriptionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EnvironmentInfoDescription>, XmlParseError> {
@@ -3612,17 +5898,79 @@ impl EnvironmentInfoDescriptionListDeserializer {
         })
     }
 }
-struct EnvironmentInfoTypeDeserializer;
+pub(crate) struct EnvironmentInfoTypeDeserializer;
 impl EnvironmentInfoTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EnvironmentInfoType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The type of information retrieved.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnvironmentInfoType {
+    /// <p><code>tail</code></p>
+    Tail,
+    /// <p><code>bundle</code></p>
+    Bundle,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnvironmentInfoType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentInfoType::Tail => "tail",
+            EnvironmentInfoType::Bundle => "bundle",
+            EnvironmentInfoType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentInfoType {
+    fn from(value: &'a str) -> EnvironmentInfoType {
+        match value {
+            "tail" => EnvironmentInfoType::Tail,
+            "bundle" => EnvironmentInfoType::Bundle,
+            _ => EnvironmentInfoType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentInfoType {
+    fn from(value: String) -> EnvironmentInfoType {
+        EnvironmentInfoType::from(value.as_str())
+    }
+}
+
+impl From<EnvironmentInfoType> for String {
+    fn from(value: EnvironmentInfoType) -> String {
+        match value {
+            EnvironmentInfoType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnvironmentInfoType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentInfoType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A link to another environment, defined in the environment's manifest. Links provide connection information in system properties that can be used to connect to another environment in the same group. See <a href="https://docs.aws.amazon.com/elasticbeanstalk/latest/dg/environment-cfg-manifest.html">Environment Manifest (env.yaml)</a> for details.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct EnvironmentLink {
@@ -3632,10 +5980,10 @@ pub struct EnvironmentLink {
     pub link_name: Option<String>,
 }
 
-struct EnvironmentLinkDeserializer;
+pub(crate) struct EnvironmentLinkDeserializer;
 impl EnvironmentLinkDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EnvironmentLink, XmlParseError> {
@@ -3654,10 +6002,10 @@ impl EnvironmentLinkDeserializer {
         })
     }
 }
-struct EnvironmentLinksDeserializer;
+pub(crate) struct EnvironmentLinksDeserializer;
 impl EnvironmentLinksDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EnvironmentLink>, XmlParseError> {
@@ -3671,10 +6019,13 @@ impl EnvironmentLinksDeserializer {
         })
     }
 }
-struct EnvironmentNameDeserializer;
+pub(crate) struct EnvironmentNameDeserializer;
 impl EnvironmentNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3715,10 +6066,10 @@ pub struct EnvironmentResourceDescription {
     pub triggers: Option<Vec<Trigger>>,
 }
 
-struct EnvironmentResourceDescriptionDeserializer;
+pub(crate) struct EnvironmentResourceDescriptionDeserializer;
 impl EnvironmentResourceDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EnvironmentResourceDescription, XmlParseError> {
@@ -3788,10 +6139,10 @@ pub struct EnvironmentResourceDescriptionsMessage {
     pub environment_resources: Option<EnvironmentResourceDescription>,
 }
 
-struct EnvironmentResourceDescriptionsMessageDeserializer;
+pub(crate) struct EnvironmentResourceDescriptionsMessageDeserializer;
 impl EnvironmentResourceDescriptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EnvironmentResourceDescriptionsMessage, XmlParseError> {
@@ -3821,10 +6172,10 @@ pub struct EnvironmentResourcesDescription {
     pub load_balancer: Option<LoadBalancerDescription>,
 }
 
-struct EnvironmentResourcesDescriptionDeserializer;
+pub(crate) struct EnvironmentResourcesDescriptionDeserializer;
 impl EnvironmentResourcesDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EnvironmentResourcesDescription, XmlParseError> {
@@ -3846,17 +6197,103 @@ impl EnvironmentResourcesDescriptionDeserializer {
         )
     }
 }
-struct EnvironmentStatusDeserializer;
+pub(crate) struct EnvironmentStatusDeserializer;
 impl EnvironmentStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EnvironmentStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p><p>The current operational status of the environment:</p> <ul> <li> <p> <code>Launching</code>: Environment is in the process of initial deployment.</p> </li> <li> <p> <code>Updating</code>: Environment is in the process of updating its configuration settings or application version.</p> </li> <li> <p> <code>Ready</code>: Environment is available to have an action performed on it, such as update or terminate.</p> </li> <li> <p> <code>Terminating</code>: Environment is in the shut-down process.</p> </li> <li> <p> <code>Terminated</code>: Environment is not running.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnvironmentStatus {
+    /// <p><code>Aborting</code></p>
+    Aborting,
+    /// <p><code>Launching</code> - Environment is in the process of initial deployment.</p>
+    Launching,
+    /// <p><code>Updating</code> - Environment is in the process of updating its configuration settings or application version.</p>
+    Updating,
+    /// <p><code>LinkingFrom</code></p>
+    LinkingFrom,
+    /// <p><code>LinkingTo</code></p>
+    LinkingTo,
+    /// <p><code>Ready</code> - Environment is available to have an action performed on it, such as update or terminate.</p>
+    Ready,
+    /// <p><code>Terminating</code> - Environment is in the shut-down process.</p>
+    Terminating,
+    /// <p><code>Terminated</code> - Environment is not running.</p>
+    Terminated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnvironmentStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnvironmentStatus::Aborting => "Aborting",
+            EnvironmentStatus::Launching => "Launching",
+            EnvironmentStatus::Updating => "Updating",
+            EnvironmentStatus::LinkingFrom => "LinkingFrom",
+            EnvironmentStatus::LinkingTo => "LinkingTo",
+            EnvironmentStatus::Ready => "Ready",
+            EnvironmentStatus::Terminating => "Terminating",
+            EnvironmentStatus::Terminated => "Terminated",
+            EnvironmentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnvironmentStatus {
+    fn from(value: &'a str) -> EnvironmentStatus {
+        match value {
+            "Aborting" => EnvironmentStatus::Aborting,
+            "Launching" => EnvironmentStatus::Launching,
+            "Updating" => EnvironmentStatus::Updating,
+            "LinkingFrom" => EnvironmentStatus::LinkingFrom,
+            "LinkingTo" => EnvironmentStatus::LinkingTo,
+            "Ready" => EnvironmentStatus::Ready,
+            "Terminating" => EnvironmentStatus::Terminating,
+            "Terminated" => EnvironmentStatus::Terminated,
+            _ => EnvironmentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnvironmentStatus {
+    fn from(value: String) -> EnvironmentStatus {
+        EnvironmentStatus::from(value.as_str())
+    }
+}
+
+impl From<EnvironmentStatus> for String {
+    fn from(value: EnvironmentStatus) -> String {
+        match value {
+            EnvironmentStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnvironmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnvironmentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Describes the properties of an environment tier</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct EnvironmentTier {
@@ -3868,10 +6305,10 @@ pub struct EnvironmentTier {
     pub version: Option<String>,
 }
 
-struct EnvironmentTierDeserializer;
+pub(crate) struct EnvironmentTierDeserializer;
 impl EnvironmentTierDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EnvironmentTier, XmlParseError> {
@@ -3914,12 +6351,15 @@ impl EnvironmentTierSerializer {
     }
 }
 
-struct EventDateDeserializer;
+pub(crate) struct EventDateDeserializer;
 impl EventDateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -3933,7 +6373,7 @@ pub struct EventDescription {
     /// <p>The name of the environment associated with this event.</p>
     pub environment_name: Option<String>,
     /// <p>The date when the event occurred.</p>
-    pub event_date: Option<String>,
+    pub event_date: Option<IsoTimestamp>,
     /// <p>The event message.</p>
     pub message: Option<String>,
     /// <p>The ARN of the platform.</p>
@@ -3941,17 +6381,17 @@ pub struct EventDescription {
     /// <p>The web service request ID for the activity of this event.</p>
     pub request_id: Option<String>,
     /// <p>The severity level of this event.</p>
-    pub severity: Option<String>,
+    pub severity: Option<EventSeverity>,
     /// <p>The name of the configuration associated with this event.</p>
     pub template_name: Option<String>,
     /// <p>The release label for the application version associated with this event.</p>
     pub version_label: Option<String>,
 }
 
-struct EventDescriptionDeserializer;
+pub(crate) struct EventDescriptionDeserializer;
 impl EventDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventDescription, XmlParseError> {
@@ -4003,10 +6443,10 @@ impl EventDescriptionDeserializer {
         })
     }
 }
-struct EventDescriptionListDeserializer;
+pub(crate) struct EventDescriptionListDeserializer;
 impl EventDescriptionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EventDescription>, XmlParseError> {
@@ -4029,10 +6469,10 @@ pub struct EventDescriptionsMessage {
     pub next_token: Option<String>,
 }
 
-struct EventDescriptionsMessageDeserializer;
+pub(crate) struct EventDescriptionsMessageDeserializer;
 impl EventDescriptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventDescriptionsMessage, XmlParseError> {
@@ -4056,10 +6496,13 @@ impl EventDescriptionsMessageDeserializer {
         )
     }
 }
-struct EventMessageDeserializer;
+pub(crate) struct EventMessageDeserializer;
 impl EventMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4067,32 +6510,195 @@ impl EventMessageDeserializer {
         Ok(obj)
     }
 }
-struct EventSeverityDeserializer;
+pub(crate) struct EventSeverityDeserializer;
 impl EventSeverityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<EventSeverity, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct FailureTypeDeserializer;
+/// <p>If specified, limits the events returned from this call to include only those with the specified severity or higher.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventSeverity {
+    /// <p><code>TRACE</code></p>
+    Trace,
+    /// <p><code>DEBUG</code></p>
+    Debug,
+    /// <p><code>INFO</code></p>
+    Info,
+    /// <p><code>WARN</code></p>
+    Warn,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// <p><code>FATAL</code></p>
+    Fatal,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventSeverity {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventSeverity::Trace => "TRACE",
+            EventSeverity::Debug => "DEBUG",
+            EventSeverity::Info => "INFO",
+            EventSeverity::Warn => "WARN",
+            EventSeverity::Error => "ERROR",
+            EventSeverity::Fatal => "FATAL",
+            EventSeverity::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventSeverity {
+    fn from(value: &'a str) -> EventSeverity {
+        match value {
+            "TRACE" => EventSeverity::Trace,
+            "DEBUG" => EventSeverity::Debug,
+            "INFO" => EventSeverity::Info,
+            "WARN" => EventSeverity::Warn,
+            "ERROR" => EventSeverity::Error,
+            "FATAL" => EventSeverity::Fatal,
+            _ => EventSeverity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventSeverity {
+    fn from(value: String) -> EventSeverity {
+        EventSeverity::from(value.as_str())
+    }
+}
+
+impl From<EventSeverity> for String {
+    fn from(value: EventSeverity) -> String {
+        match value {
+            EventSeverity::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventSeverity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct FailureTypeDeserializer;
 impl FailureTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<FailureType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct FileTypeExtensionDeserializer;
+/// <p>If the action failed, the type of failure.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FailureType {
+    /// <p><code>UpdateCancelled</code></p>
+    UpdateCancelled,
+    /// <p><code>CancellationFailed</code></p>
+    CancellationFailed,
+    /// <p><code>RollbackFailed</code></p>
+    RollbackFailed,
+    /// <p><code>RollbackSuccessful</code></p>
+    RollbackSuccessful,
+    /// <p><code>InternalFailure</code></p>
+    InternalFailure,
+    /// <p><code>InvalidEnvironmentState</code></p>
+    InvalidEnvironmentState,
+    /// <p><code>PermissionsError</code></p>
+    PermissionsError,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FailureType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailureType::UpdateCancelled => "UpdateCancelled",
+            FailureType::CancellationFailed => "CancellationFailed",
+            FailureType::RollbackFailed => "RollbackFailed",
+            FailureType::RollbackSuccessful => "RollbackSuccessful",
+            FailureType::InternalFailure => "InternalFailure",
+            FailureType::InvalidEnvironmentState => "InvalidEnvironmentState",
+            FailureType::PermissionsError => "PermissionsError",
+            FailureType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FailureType {
+    fn from(value: &'a str) -> FailureType {
+        match value {
+            "UpdateCancelled" => FailureType::UpdateCancelled,
+            "CancellationFailed" => FailureType::CancellationFailed,
+            "RollbackFailed" => FailureType::RollbackFailed,
+            "RollbackSuccessful" => FailureType::RollbackSuccessful,
+            "InternalFailure" => FailureType::InternalFailure,
+            "InvalidEnvironmentState" => FailureType::InvalidEnvironmentState,
+            "PermissionsError" => FailureType::PermissionsError,
+            _ => FailureType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailureType {
+    fn from(value: String) -> FailureType {
+        FailureType::from(value.as_str())
+    }
+}
+
+impl From<FailureType> for String {
+    fn from(value: FailureType) -> String {
+        match value {
+            FailureType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FailureType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FailureType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct FileTypeExtensionDeserializer;
 impl FileTypeExtensionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4100,10 +6706,13 @@ impl FileTypeExtensionDeserializer {
         Ok(obj)
     }
 }
-struct ImageIdDeserializer;
+pub(crate) struct ImageIdDeserializer;
 impl ImageIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4118,10 +6727,10 @@ pub struct Instance {
     pub id: Option<String>,
 }
 
-struct InstanceDeserializer;
+pub(crate) struct InstanceDeserializer;
 impl InstanceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Instance, XmlParseError> {
@@ -4136,10 +6745,10 @@ impl InstanceDeserializer {
         })
     }
 }
-struct InstanceHealthListDeserializer;
+pub(crate) struct InstanceHealthListDeserializer;
 impl InstanceHealthListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SingleInstanceHealth>, XmlParseError> {
@@ -4176,10 +6785,10 @@ pub struct InstanceHealthSummary {
     pub warning: Option<i64>,
 }
 
-struct InstanceHealthSummaryDeserializer;
+pub(crate) struct InstanceHealthSummaryDeserializer;
 impl InstanceHealthSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<InstanceHealthSummary, XmlParseError> {
@@ -4216,10 +6825,13 @@ impl InstanceHealthSummaryDeserializer {
         })
     }
 }
-struct InstanceIdDeserializer;
+pub(crate) struct InstanceIdDeserializer;
 impl InstanceIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4227,10 +6839,10 @@ impl InstanceIdDeserializer {
         Ok(obj)
     }
 }
-struct InstanceListDeserializer;
+pub(crate) struct InstanceListDeserializer;
 impl InstanceListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Instance>, XmlParseError> {
@@ -4245,10 +6857,105 @@ impl InstanceListDeserializer {
     }
 }
 
+/// <p>Specifies the response elements you wish to receive. To retrieve all attributes, set to <code>All</code>. If no attribute names are specified, returns a list of instances.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstancesHealthAttribute {
+    /// <p><code>HealthStatus</code></p>
+    HealthStatus,
+    /// <p><code>Color</code></p>
+    Color,
+    /// <p><code>Causes</code></p>
+    Causes,
+    /// <p><code>ApplicationMetrics</code></p>
+    ApplicationMetrics,
+    /// <p><code>RefreshedAt</code></p>
+    RefreshedAt,
+    /// <p><code>LaunchedAt</code></p>
+    LaunchedAt,
+    /// <p><code>System</code></p>
+    System,
+    /// <p><code>Deployment</code></p>
+    Deployment,
+    /// <p><code>AvailabilityZone</code></p>
+    AvailabilityZone,
+    /// <p><code>InstanceType</code></p>
+    InstanceType,
+    /// <p><code>All</code></p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstancesHealthAttribute {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstancesHealthAttribute::HealthStatus => "HealthStatus",
+            InstancesHealthAttribute::Color => "Color",
+            InstancesHealthAttribute::Causes => "Causes",
+            InstancesHealthAttribute::ApplicationMetrics => "ApplicationMetrics",
+            InstancesHealthAttribute::RefreshedAt => "RefreshedAt",
+            InstancesHealthAttribute::LaunchedAt => "LaunchedAt",
+            InstancesHealthAttribute::System => "System",
+            InstancesHealthAttribute::Deployment => "Deployment",
+            InstancesHealthAttribute::AvailabilityZone => "AvailabilityZone",
+            InstancesHealthAttribute::InstanceType => "InstanceType",
+            InstancesHealthAttribute::All => "All",
+            InstancesHealthAttribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstancesHealthAttribute {
+    fn from(value: &'a str) -> InstancesHealthAttribute {
+        match value {
+            "HealthStatus" => InstancesHealthAttribute::HealthStatus,
+            "Color" => InstancesHealthAttribute::Color,
+            "Causes" => InstancesHealthAttribute::Causes,
+            "ApplicationMetrics" => InstancesHealthAttribute::ApplicationMetrics,
+            "RefreshedAt" => InstancesHealthAttribute::RefreshedAt,
+            "LaunchedAt" => InstancesHealthAttribute::LaunchedAt,
+            "System" => InstancesHealthAttribute::System,
+            "Deployment" => InstancesHealthAttribute::Deployment,
+            "AvailabilityZone" => InstancesHealthAttribute::AvailabilityZone,
+            "InstanceType" => InstancesHealthAttribute::InstanceType,
+            "All" => InstancesHealthAttribute::All,
+            _ => InstancesHealthAttribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstancesHealthAttribute {
+    fn from(value: String) -> InstancesHealthAttribute {
+        InstancesHealthAttribute::from(value.as_str())
+    }
+}
+
+impl From<InstancesHealthAttribute> for String {
+    fn from(value: InstancesHealthAttribute) -> String {
+        match value {
+            InstancesHealthAttribute::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstancesHealthAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InstancesHealthAttribute {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// Serialize `InstancesHealthAttributes` contents to a `SignedRequest`.
 struct InstancesHealthAttributesSerializer;
 impl InstancesHealthAttributesSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<InstancesHealthAttribute>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -4256,10 +6963,13 @@ impl InstancesHealthAttributesSerializer {
     }
 }
 
-struct IntegerDeserializer;
+pub(crate) struct IntegerDeserializer;
 impl IntegerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4288,10 +6998,10 @@ pub struct Latency {
     pub p999: Option<f64>,
 }
 
-struct LatencyDeserializer;
+pub(crate) struct LatencyDeserializer;
 impl LatencyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Latency, XmlParseError> {
@@ -4334,10 +7044,10 @@ pub struct LaunchConfiguration {
     pub name: Option<String>,
 }
 
-struct LaunchConfigurationDeserializer;
+pub(crate) struct LaunchConfigurationDeserializer;
 impl LaunchConfigurationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LaunchConfiguration, XmlParseError> {
@@ -4352,10 +7062,10 @@ impl LaunchConfigurationDeserializer {
         })
     }
 }
-struct LaunchConfigurationListDeserializer;
+pub(crate) struct LaunchConfigurationListDeserializer;
 impl LaunchConfigurationListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LaunchConfiguration>, XmlParseError> {
@@ -4378,10 +7088,10 @@ pub struct LaunchTemplate {
     pub id: Option<String>,
 }
 
-struct LaunchTemplateDeserializer;
+pub(crate) struct LaunchTemplateDeserializer;
 impl LaunchTemplateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LaunchTemplate, XmlParseError> {
@@ -4396,10 +7106,10 @@ impl LaunchTemplateDeserializer {
         })
     }
 }
-struct LaunchTemplateListDeserializer;
+pub(crate) struct LaunchTemplateListDeserializer;
 impl LaunchTemplateListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LaunchTemplate>, XmlParseError> {
@@ -4413,12 +7123,15 @@ impl LaunchTemplateListDeserializer {
         })
     }
 }
-struct LaunchedAtDeserializer;
+pub(crate) struct LaunchedAtDeserializer;
 impl LaunchedAtDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -4433,10 +7146,10 @@ pub struct ListAvailableSolutionStacksResultMessage {
     pub solution_stacks: Option<Vec<String>>,
 }
 
-struct ListAvailableSolutionStacksResultMessageDeserializer;
+pub(crate) struct ListAvailableSolutionStacksResultMessageDeserializer;
 impl ListAvailableSolutionStacksResultMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListAvailableSolutionStacksResultMessage, XmlParseError> {
@@ -4478,6 +7191,52 @@ pub struct ListPlatformVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListPlatformVersionsRequest {
+    /// Returns a builder for `ListPlatformVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListPlatformVersionsRequestBuilder {
+        ListPlatformVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPlatformVersionsRequest`](struct.ListPlatformVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPlatformVersionsRequestBuilder {
+    filters: Option<Vec<PlatformFilter>>,
+    max_records: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListPlatformVersionsRequestBuilder {
+    /// Sets the [`filters`](struct.ListPlatformVersionsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<PlatformFilter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.ListPlatformVersionsRequest.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPlatformVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPlatformVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPlatformVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPlatformVersionsRequest {
+            filters: self.filters,
+            max_records: self.max_records,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// Serialize `ListPlatformVersionsRequest` contents to a `SignedRequest`.
 struct ListPlatformVersionsRequestSerializer;
 impl ListPlatformVersionsRequestSerializer {
@@ -4511,10 +7270,10 @@ pub struct ListPlatformVersionsResult {
     pub platform_summary_list: Option<Vec<PlatformSummary>>,
 }
 
-struct ListPlatformVersionsResultDeserializer;
+pub(crate) struct ListPlatformVersionsResultDeserializer;
 impl ListPlatformVersionsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListPlatformVersionsResult, XmlParseError> {
@@ -4547,8 +7306,40 @@ pub struct ListTagsForResourceMessage {
     pub resource_arn: String,
 }
 
-/// Serialize `ListTagsForResourceMessage` contents to a `SignedRequest`.
-struct ListTagsForResourceMessageSerializer;
+impl ListTagsForResourceMessage {
+    /// Returns a builder for `ListTagsForResourceMessage`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceMessageBuilder {
+        ListTagsForResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceMessage`](struct.ListTagsForResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceMessageBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceMessageBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceMessage.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceMessage, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceMessage {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
+/// Serialize `ListTagsForResourceMessage` contents to a `SignedRequest`.
+struct ListTagsForResourceMessageSerializer;
 impl ListTagsForResourceMessageSerializer {
     fn serialize(params: &mut Params, name: &str, obj: &ListTagsForResourceMessage) {
         let mut prefix = name.to_string();
@@ -4569,10 +7360,10 @@ pub struct Listener {
     pub protocol: Option<String>,
 }
 
-struct ListenerDeserializer;
+pub(crate) struct ListenerDeserializer;
 impl ListenerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Listener, XmlParseError> {
@@ -4590,10 +7381,10 @@ impl ListenerDeserializer {
         })
     }
 }
-struct LoadAverageDeserializer;
+pub(crate) struct LoadAverageDeserializer;
 impl LoadAverageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<f64>, XmlParseError> {
@@ -4607,10 +7398,13 @@ impl LoadAverageDeserializer {
         })
     }
 }
-struct LoadAverageValueDeserializer;
+pub(crate) struct LoadAverageValueDeserializer;
 impl LoadAverageValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4625,10 +7419,10 @@ pub struct LoadBalancer {
     pub name: Option<String>,
 }
 
-struct LoadBalancerDeserializer;
+pub(crate) struct LoadBalancerDeserializer;
 impl LoadBalancerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoadBalancer, XmlParseError> {
@@ -4654,10 +7448,10 @@ pub struct LoadBalancerDescription {
     pub load_balancer_name: Option<String>,
 }
 
-struct LoadBalancerDescriptionDeserializer;
+pub(crate) struct LoadBalancerDescriptionDeserializer;
 impl LoadBalancerDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoadBalancerDescription, XmlParseError> {
@@ -4688,10 +7482,10 @@ impl LoadBalancerDescriptionDeserializer {
         )
     }
 }
-struct LoadBalancerListDeserializer;
+pub(crate) struct LoadBalancerListDeserializer;
 impl LoadBalancerListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LoadBalancer>, XmlParseError> {
@@ -4705,10 +7499,10 @@ impl LoadBalancerListDeserializer {
         })
     }
 }
-struct LoadBalancerListenersDescriptionDeserializer;
+pub(crate) struct LoadBalancerListenersDescriptionDeserializer;
 impl LoadBalancerListenersDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Listener>, XmlParseError> {
@@ -4722,10 +7516,13 @@ impl LoadBalancerListenersDescriptionDeserializer {
         })
     }
 }
-struct MaintainerDeserializer;
+pub(crate) struct MaintainerDeserializer;
 impl MaintainerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4741,17 +7538,17 @@ pub struct ManagedAction {
     /// <p>A unique identifier for the managed action.</p>
     pub action_id: Option<String>,
     /// <p>The type of managed action.</p>
-    pub action_type: Option<String>,
+    pub action_type: Option<ActionType>,
     /// <p>The status of the managed action. If the action is <code>Scheduled</code>, you can apply it immediately with <a>ApplyEnvironmentManagedAction</a>.</p>
-    pub status: Option<String>,
+    pub status: Option<ActionStatus>,
     /// <p>The start time of the maintenance window in which the managed action will execute.</p>
-    pub window_start_time: Option<String>,
+    pub window_start_time: Option<IsoTimestamp>,
 }
 
-struct ManagedActionDeserializer;
+pub(crate) struct ManagedActionDeserializer;
 impl ManagedActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ManagedAction, XmlParseError> {
@@ -4791,23 +7588,23 @@ pub struct ManagedActionHistoryItem {
     /// <p>A unique identifier for the managed action.</p>
     pub action_id: Option<String>,
     /// <p>The type of the managed action.</p>
-    pub action_type: Option<String>,
+    pub action_type: Option<ActionType>,
     /// <p>The date and time that the action started executing.</p>
-    pub executed_time: Option<String>,
+    pub executed_time: Option<IsoTimestamp>,
     /// <p>If the action failed, a description of the failure.</p>
     pub failure_description: Option<String>,
     /// <p>If the action failed, the type of failure.</p>
-    pub failure_type: Option<String>,
+    pub failure_type: Option<FailureType>,
     /// <p>The date and time that the action finished executing.</p>
-    pub finished_time: Option<String>,
+    pub finished_time: Option<IsoTimestamp>,
     /// <p>The status of the action.</p>
-    pub status: Option<String>,
+    pub status: Option<ActionHistoryStatus>,
 }
 
-struct ManagedActionHistoryItemDeserializer;
+pub(crate) struct ManagedActionHistoryItemDeserializer;
 impl ManagedActionHistoryItemDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ManagedActionHistoryItem, XmlParseError> {
@@ -4857,10 +7654,10 @@ impl ManagedActionHistoryItemDeserializer {
         )
     }
 }
-struct ManagedActionHistoryItemsDeserializer;
+pub(crate) struct ManagedActionHistoryItemsDeserializer;
 impl ManagedActionHistoryItemsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ManagedActionHistoryItem>, XmlParseError> {
@@ -4876,10 +7673,10 @@ impl ManagedActionHistoryItemsDeserializer {
         })
     }
 }
-struct ManagedActionsDeserializer;
+pub(crate) struct ManagedActionsDeserializer;
 impl ManagedActionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ManagedAction>, XmlParseError> {
@@ -4904,10 +7701,10 @@ pub struct MaxAgeRule {
     pub max_age_in_days: Option<i64>,
 }
 
-struct MaxAgeRuleDeserializer;
+pub(crate) struct MaxAgeRuleDeserializer;
 impl MaxAgeRuleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MaxAgeRule, XmlParseError> {
@@ -4963,10 +7760,10 @@ pub struct MaxCountRule {
     pub max_count: Option<i64>,
 }
 
-struct MaxCountRuleDeserializer;
+pub(crate) struct MaxCountRuleDeserializer;
 impl MaxCountRuleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MaxCountRule, XmlParseError> {
@@ -5010,10 +7807,13 @@ impl MaxCountRuleSerializer {
     }
 }
 
-struct MessageDeserializer;
+pub(crate) struct MessageDeserializer;
 impl MessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5021,10 +7821,13 @@ impl MessageDeserializer {
         Ok(obj)
     }
 }
-struct NextTokenDeserializer;
+pub(crate) struct NextTokenDeserializer;
 impl NextTokenDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5032,10 +7835,13 @@ impl NextTokenDeserializer {
         Ok(obj)
     }
 }
-struct NullableDoubleDeserializer;
+pub(crate) struct NullableDoubleDeserializer;
 impl NullableDoubleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5043,10 +7849,13 @@ impl NullableDoubleDeserializer {
         Ok(obj)
     }
 }
-struct NullableIntegerDeserializer;
+pub(crate) struct NullableIntegerDeserializer;
 impl NullableIntegerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5054,10 +7863,13 @@ impl NullableIntegerDeserializer {
         Ok(obj)
     }
 }
-struct NullableLongDeserializer;
+pub(crate) struct NullableLongDeserializer;
 impl NullableLongDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5065,10 +7877,13 @@ impl NullableLongDeserializer {
         Ok(obj)
     }
 }
-struct OperatingSystemNameDeserializer;
+pub(crate) struct OperatingSystemNameDeserializer;
 impl OperatingSystemNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5076,10 +7891,13 @@ impl OperatingSystemNameDeserializer {
         Ok(obj)
     }
 }
-struct OperatingSystemVersionDeserializer;
+pub(crate) struct OperatingSystemVersionDeserializer;
 impl OperatingSystemVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5087,10 +7905,13 @@ impl OperatingSystemVersionDeserializer {
         Ok(obj)
     }
 }
-struct OptionNamespaceDeserializer;
+pub(crate) struct OptionNamespaceDeserializer;
 impl OptionNamespaceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5098,10 +7919,13 @@ impl OptionNamespaceDeserializer {
         Ok(obj)
     }
 }
-struct OptionRestrictionMaxLengthDeserializer;
+pub(crate) struct OptionRestrictionMaxLengthDeserializer;
 impl OptionRestrictionMaxLengthDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5109,10 +7933,13 @@ impl OptionRestrictionMaxLengthDeserializer {
         Ok(obj)
     }
 }
-struct OptionRestrictionMaxValueDeserializer;
+pub(crate) struct OptionRestrictionMaxValueDeserializer;
 impl OptionRestrictionMaxValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5120,10 +7947,13 @@ impl OptionRestrictionMaxValueDeserializer {
         Ok(obj)
     }
 }
-struct OptionRestrictionMinValueDeserializer;
+pub(crate) struct OptionRestrictionMinValueDeserializer;
 impl OptionRestrictionMinValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5140,10 +7970,10 @@ pub struct OptionRestrictionRegex {
     pub pattern: Option<String>,
 }
 
-struct OptionRestrictionRegexDeserializer;
+pub(crate) struct OptionRestrictionRegexDeserializer;
 impl OptionRestrictionRegexDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OptionRestrictionRegex, XmlParseError> {
@@ -5204,10 +8034,13 @@ impl OptionsSpecifierListSerializer {
     }
 }
 
-struct PlatformArnDeserializer;
+pub(crate) struct PlatformArnDeserializer;
 impl PlatformArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5215,10 +8048,13 @@ impl PlatformArnDeserializer {
         Ok(obj)
     }
 }
-struct PlatformCategoryDeserializer;
+pub(crate) struct PlatformCategoryDeserializer;
 impl PlatformCategoryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5232,9 +8068,9 @@ pub struct PlatformDescription {
     /// <p>The custom AMIs supported by the platform.</p>
     pub custom_ami_list: Option<Vec<CustomAmi>>,
     /// <p>The date when the platform was created.</p>
-    pub date_created: Option<String>,
+    pub date_created: Option<IsoTimestamp>,
     /// <p>The date when the platform was last updated.</p>
-    pub date_updated: Option<String>,
+    pub date_updated: Option<IsoTimestamp>,
     /// <p>The description of the platform.</p>
     pub description: Option<String>,
     /// <p>The frameworks supported by the platform.</p>
@@ -5254,7 +8090,7 @@ pub struct PlatformDescription {
     /// <p>The AWS account ID of the person who created the platform.</p>
     pub platform_owner: Option<String>,
     /// <p>The status of the platform.</p>
-    pub platform_status: Option<String>,
+    pub platform_status: Option<PlatformStatus>,
     /// <p>The version of the platform.</p>
     pub platform_version: Option<String>,
     /// <p>The programming languages supported by the platform.</p>
@@ -5267,10 +8103,10 @@ pub struct PlatformDescription {
     pub supported_tier_list: Option<Vec<String>>,
 }
 
-struct PlatformDescriptionDeserializer;
+pub(crate) struct PlatformDescriptionDeserializer;
 impl PlatformDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PlatformDescription, XmlParseError> {
@@ -5446,10 +8282,10 @@ pub struct PlatformFramework {
     pub version: Option<String>,
 }
 
-struct PlatformFrameworkDeserializer;
+pub(crate) struct PlatformFrameworkDeserializer;
 impl PlatformFrameworkDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PlatformFramework, XmlParseError> {
@@ -5467,10 +8303,10 @@ impl PlatformFrameworkDeserializer {
         })
     }
 }
-struct PlatformFrameworksDeserializer;
+pub(crate) struct PlatformFrameworksDeserializer;
 impl PlatformFrameworksDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PlatformFramework>, XmlParseError> {
@@ -5484,10 +8320,13 @@ impl PlatformFrameworksDeserializer {
         })
     }
 }
-struct PlatformNameDeserializer;
+pub(crate) struct PlatformNameDeserializer;
 impl PlatformNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5495,10 +8334,13 @@ impl PlatformNameDeserializer {
         Ok(obj)
     }
 }
-struct PlatformOwnerDeserializer;
+pub(crate) struct PlatformOwnerDeserializer;
 impl PlatformOwnerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5515,10 +8357,10 @@ pub struct PlatformProgrammingLanguage {
     pub version: Option<String>,
 }
 
-struct PlatformProgrammingLanguageDeserializer;
+pub(crate) struct PlatformProgrammingLanguageDeserializer;
 impl PlatformProgrammingLanguageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PlatformProgrammingLanguage, XmlParseError> {
@@ -5540,10 +8382,10 @@ impl PlatformProgrammingLanguageDeserializer {
         )
     }
 }
-struct PlatformProgrammingLanguagesDeserializer;
+pub(crate) struct PlatformProgrammingLanguagesDeserializer;
 impl PlatformProgrammingLanguagesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PlatformProgrammingLanguage>, XmlParseError> {
@@ -5559,17 +8401,91 @@ impl PlatformProgrammingLanguagesDeserializer {
         })
     }
 }
-struct PlatformStatusDeserializer;
+pub(crate) struct PlatformStatusDeserializer;
 impl PlatformStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PlatformStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The status of the platform.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlatformStatus {
+    /// <p><code>Creating</code></p>
+    Creating,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Ready</code></p>
+    Ready,
+    /// <p><code>Deleting</code></p>
+    Deleting,
+    /// <p><code>Deleted</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PlatformStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlatformStatus::Creating => "Creating",
+            PlatformStatus::Failed => "Failed",
+            PlatformStatus::Ready => "Ready",
+            PlatformStatus::Deleting => "Deleting",
+            PlatformStatus::Deleted => "Deleted",
+            PlatformStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PlatformStatus {
+    fn from(value: &'a str) -> PlatformStatus {
+        match value {
+            "Creating" => PlatformStatus::Creating,
+            "Failed" => PlatformStatus::Failed,
+            "Ready" => PlatformStatus::Ready,
+            "Deleting" => PlatformStatus::Deleting,
+            "Deleted" => PlatformStatus::Deleted,
+            _ => PlatformStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlatformStatus {
+    fn from(value: String) -> PlatformStatus {
+        PlatformStatus::from(value.as_str())
+    }
+}
+
+impl From<PlatformStatus> for String {
+    fn from(value: PlatformStatus) -> String {
+        match value {
+            PlatformStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PlatformStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PlatformStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Detailed information about a platform.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PlatformSummary {
@@ -5584,17 +8500,17 @@ pub struct PlatformSummary {
     /// <p>The AWS account ID of the person who created the platform.</p>
     pub platform_owner: Option<String>,
     /// <p>The status of the platform. You can create an environment from the platform once it is ready.</p>
-    pub platform_status: Option<String>,
+    pub platform_status: Option<PlatformStatus>,
     /// <p>The additions associated with the platform.</p>
     pub supported_addon_list: Option<Vec<String>>,
     /// <p>The tiers in which the platform runs.</p>
     pub supported_tier_list: Option<Vec<String>>,
 }
 
-struct PlatformSummaryDeserializer;
+pub(crate) struct PlatformSummaryDeserializer;
 impl PlatformSummaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PlatformSummary, XmlParseError> {
@@ -5651,10 +8567,10 @@ impl PlatformSummaryDeserializer {
         })
     }
 }
-struct PlatformSummaryListDeserializer;
+pub(crate) struct PlatformSummaryListDeserializer;
 impl PlatformSummaryListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PlatformSummary>, XmlParseError> {
@@ -5668,10 +8584,13 @@ impl PlatformSummaryListDeserializer {
         })
     }
 }
-struct PlatformVersionDeserializer;
+pub(crate) struct PlatformVersionDeserializer;
 impl PlatformVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5688,10 +8607,13 @@ pub struct Queue {
     pub url: Option<String>,
 }
 
-struct QueueDeserializer;
+pub(crate) struct QueueDeserializer;
 impl QueueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Queue, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Queue, XmlParseError> {
         deserialize_elements::<_, Queue, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Name" => {
@@ -5706,10 +8628,10 @@ impl QueueDeserializer {
         })
     }
 }
-struct QueueListDeserializer;
+pub(crate) struct QueueListDeserializer;
 impl QueueListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Queue>, XmlParseError> {
@@ -5732,6 +8654,44 @@ pub struct RebuildEnvironmentMessage {
     pub environment_name: Option<String>,
 }
 
+impl RebuildEnvironmentMessage {
+    /// Returns a builder for `RebuildEnvironmentMessage`, checking its required members when built.
+    pub fn builder() -> RebuildEnvironmentMessageBuilder {
+        RebuildEnvironmentMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RebuildEnvironmentMessage`](struct.RebuildEnvironmentMessage.html).
+#[derive(Default, Debug)]
+pub struct RebuildEnvironmentMessageBuilder {
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+}
+
+impl RebuildEnvironmentMessageBuilder {
+    /// Sets the [`environment_id`](struct.RebuildEnvironmentMessage.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.RebuildEnvironmentMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebuildEnvironmentMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RebuildEnvironmentMessage, ::rusoto_core::BuildError> {
+        Ok(RebuildEnvironmentMessage {
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+        })
+    }
+}
+
 /// Serialize `RebuildEnvironmentMessage` contents to a `SignedRequest`.
 struct RebuildEnvironmentMessageSerializer;
 impl RebuildEnvironmentMessageSerializer {
@@ -5750,21 +8710,27 @@ impl RebuildEnvironmentMessageSerializer {
     }
 }
 
-struct RefreshedAtDeserializer;
+pub(crate) struct RefreshedAtDeserializer;
 impl RefreshedAtDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct RegexLabelDeserializer;
+pub(crate) struct RegexLabelDeserializer;
 impl RegexLabelDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5772,10 +8738,13 @@ impl RegexLabelDeserializer {
         Ok(obj)
     }
 }
-struct RegexPatternDeserializer;
+pub(crate) struct RegexPatternDeserializer;
 impl RegexPatternDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5783,10 +8752,13 @@ impl RegexPatternDeserializer {
         Ok(obj)
     }
 }
-struct RequestCountDeserializer;
+pub(crate) struct RequestCountDeserializer;
 impl RequestCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5795,14 +8767,62 @@ impl RequestCountDeserializer {
     }
 }
 /// <p>Request to retrieve logs from an environment and store them in your Elastic Beanstalk storage bucket.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RequestEnvironmentInfoMessage {
     /// <p>The ID of the environment of the requested data.</p> <p>If no such environment is found, <code>RequestEnvironmentInfo</code> returns an <code>InvalidParameterValue</code> error. </p> <p>Condition: You must specify either this or an EnvironmentName, or both. If you do not specify either, AWS Elastic Beanstalk returns <code>MissingRequiredParameter</code> error. </p>
     pub environment_id: Option<String>,
     /// <p>The name of the environment of the requested data.</p> <p>If no such environment is found, <code>RequestEnvironmentInfo</code> returns an <code>InvalidParameterValue</code> error. </p> <p>Condition: You must specify either this or an EnvironmentId, or both. If you do not specify either, AWS Elastic Beanstalk returns <code>MissingRequiredParameter</code> error. </p>
     pub environment_name: Option<String>,
     /// <p>The type of information to request.</p>
-    pub info_type: String,
+    pub info_type: EnvironmentInfoType,
+}
+
+impl RequestEnvironmentInfoMessage {
+    /// Returns a builder for `RequestEnvironmentInfoMessage`, checking its required members when built.
+    pub fn builder() -> RequestEnvironmentInfoMessageBuilder {
+        RequestEnvironmentInfoMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RequestEnvironmentInfoMessage`](struct.RequestEnvironmentInfoMessage.html).
+#[derive(Default, Debug)]
+pub struct RequestEnvironmentInfoMessageBuilder {
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+    info_type: Option<EnvironmentInfoType>,
+}
+
+impl RequestEnvironmentInfoMessageBuilder {
+    /// Sets the [`environment_id`](struct.RequestEnvironmentInfoMessage.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.RequestEnvironmentInfoMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`info_type`](struct.RequestEnvironmentInfoMessage.html#structfield.info_type) member.
+    pub fn info_type<T: Into<EnvironmentInfoType>>(mut self, value: T) -> Self {
+        self.info_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `RequestEnvironmentInfoMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RequestEnvironmentInfoMessage, ::rusoto_core::BuildError> {
+        Ok(RequestEnvironmentInfoMessage {
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+            info_type: self
+                .info_type
+                .ok_or(::rusoto_core::BuildError::missing("info_type"))?,
+        })
+    }
 }
 
 /// Serialize `RequestEnvironmentInfoMessage` contents to a `SignedRequest`.
@@ -5824,10 +8844,13 @@ impl RequestEnvironmentInfoMessageSerializer {
     }
 }
 
-struct RequestIdDeserializer;
+pub(crate) struct RequestIdDeserializer;
 impl RequestIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5835,10 +8858,13 @@ impl RequestIdDeserializer {
         Ok(obj)
     }
 }
-struct ResourceArnDeserializer;
+pub(crate) struct ResourceArnDeserializer;
 impl ResourceArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5846,10 +8872,13 @@ impl ResourceArnDeserializer {
         Ok(obj)
     }
 }
-struct ResourceIdDeserializer;
+pub(crate) struct ResourceIdDeserializer;
 impl ResourceIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5857,10 +8886,13 @@ impl ResourceIdDeserializer {
         Ok(obj)
     }
 }
-struct ResourceNameDeserializer;
+pub(crate) struct ResourceNameDeserializer;
 impl ResourceNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5875,10 +8907,10 @@ pub struct ResourceQuota {
     pub maximum: Option<i64>,
 }
 
-struct ResourceQuotaDeserializer;
+pub(crate) struct ResourceQuotaDeserializer;
 impl ResourceQuotaDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourceQuota, XmlParseError> {
@@ -5908,10 +8940,10 @@ pub struct ResourceQuotas {
     pub environment_quota: Option<ResourceQuota>,
 }
 
-struct ResourceQuotasDeserializer;
+pub(crate) struct ResourceQuotasDeserializer;
 impl ResourceQuotasDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourceQuotas, XmlParseError> {
@@ -5962,10 +8994,10 @@ pub struct ResourceTagsDescriptionMessage {
     pub resource_tags: Option<Vec<Tag>>,
 }
 
-struct ResourceTagsDescriptionMessageDeserializer;
+pub(crate) struct ResourceTagsDescriptionMessageDeserializer;
 impl ResourceTagsDescriptionMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourceTagsDescriptionMessage, XmlParseError> {
@@ -5999,6 +9031,44 @@ pub struct RestartAppServerMessage {
     pub environment_name: Option<String>,
 }
 
+impl RestartAppServerMessage {
+    /// Returns a builder for `RestartAppServerMessage`, checking its required members when built.
+    pub fn builder() -> RestartAppServerMessageBuilder {
+        RestartAppServerMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RestartAppServerMessage`](struct.RestartAppServerMessage.html).
+#[derive(Default, Debug)]
+pub struct RestartAppServerMessageBuilder {
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+}
+
+impl RestartAppServerMessageBuilder {
+    /// Sets the [`environment_id`](struct.RestartAppServerMessage.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.RestartAppServerMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestartAppServerMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestartAppServerMessage, ::rusoto_core::BuildError> {
+        Ok(RestartAppServerMessage {
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+        })
+    }
+}
+
 /// Serialize `RestartAppServerMessage` contents to a `SignedRequest`.
 struct RestartAppServerMessageSerializer;
 impl RestartAppServerMessageSerializer {
@@ -6018,14 +9088,62 @@ impl RestartAppServerMessageSerializer {
 }
 
 /// <p>Request to download logs retrieved with <a>RequestEnvironmentInfo</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RetrieveEnvironmentInfoMessage {
     /// <p>The ID of the data's environment.</p> <p>If no such environment is found, returns an <code>InvalidParameterValue</code> error.</p> <p>Condition: You must specify either this or an EnvironmentName, or both. If you do not specify either, AWS Elastic Beanstalk returns <code>MissingRequiredParameter</code> error.</p>
     pub environment_id: Option<String>,
     /// <p>The name of the data's environment.</p> <p> If no such environment is found, returns an <code>InvalidParameterValue</code> error. </p> <p> Condition: You must specify either this or an EnvironmentId, or both. If you do not specify either, AWS Elastic Beanstalk returns <code>MissingRequiredParameter</code> error. </p>
     pub environment_name: Option<String>,
     /// <p>The type of information to retrieve.</p>
-    pub info_type: String,
+    pub info_type: EnvironmentInfoType,
+}
+
+impl RetrieveEnvironmentInfoMessage {
+    /// Returns a builder for `RetrieveEnvironmentInfoMessage`, checking its required members when built.
+    pub fn builder() -> RetrieveEnvironmentInfoMessageBuilder {
+        RetrieveEnvironmentInfoMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RetrieveEnvironmentInfoMessage`](struct.RetrieveEnvironmentInfoMessage.html).
+#[derive(Default, Debug)]
+pub struct RetrieveEnvironmentInfoMessageBuilder {
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+    info_type: Option<EnvironmentInfoType>,
+}
+
+impl RetrieveEnvironmentInfoMessageBuilder {
+    /// Sets the [`environment_id`](struct.RetrieveEnvironmentInfoMessage.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.RetrieveEnvironmentInfoMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`info_type`](struct.RetrieveEnvironmentInfoMessage.html#structfield.info_type) member.
+    pub fn info_type<T: Into<EnvironmentInfoType>>(mut self, value: T) -> Self {
+        self.info_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `RetrieveEnvironmentInfoMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RetrieveEnvironmentInfoMessage, ::rusoto_core::BuildError> {
+        Ok(RetrieveEnvironmentInfoMessage {
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+            info_type: self
+                .info_type
+                .ok_or(::rusoto_core::BuildError::missing("info_type"))?,
+        })
+    }
 }
 
 /// Serialize `RetrieveEnvironmentInfoMessage` contents to a `SignedRequest`.
@@ -6054,10 +9172,10 @@ pub struct RetrieveEnvironmentInfoResultMessage {
     pub environment_info: Option<Vec<EnvironmentInfoDescription>>,
 }
 
-struct RetrieveEnvironmentInfoResultMessageDeserializer;
+pub(crate) struct RetrieveEnvironmentInfoResultMessageDeserializer;
 impl RetrieveEnvironmentInfoResultMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RetrieveEnvironmentInfoResultMessage, XmlParseError> {
@@ -6081,10 +9199,13 @@ impl RetrieveEnvironmentInfoResultMessageDeserializer {
         )
     }
 }
-struct S3BucketDeserializer;
+pub(crate) struct S3BucketDeserializer;
 impl S3BucketDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6092,10 +9213,13 @@ impl S3BucketDeserializer {
         Ok(obj)
     }
 }
-struct S3KeyDeserializer;
+pub(crate) struct S3KeyDeserializer;
 impl S3KeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6112,10 +9236,10 @@ pub struct S3Location {
     pub s3_key: Option<String>,
 }
 
-struct S3LocationDeserializer;
+pub(crate) struct S3LocationDeserializer;
 impl S3LocationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<S3Location, XmlParseError> {
@@ -6152,12 +9276,15 @@ impl S3LocationSerializer {
     }
 }
 
-struct SampleTimestampDeserializer;
+pub(crate) struct SampleTimestampDeserializer;
 impl SampleTimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -6183,15 +9310,15 @@ pub struct SingleInstanceHealth {
     /// <p>The instance's type.</p>
     pub instance_type: Option<String>,
     /// <p>The time at which the EC2 instance was launched.</p>
-    pub launched_at: Option<String>,
+    pub launched_at: Option<IsoTimestamp>,
     /// <p>Operating system metrics from the instance.</p>
     pub system: Option<SystemStatus>,
 }
 
-struct SingleInstanceHealthDeserializer;
+pub(crate) struct SingleInstanceHealthDeserializer;
 impl SingleInstanceHealthDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SingleInstanceHealth, XmlParseError> {
@@ -6253,10 +9380,10 @@ pub struct SolutionStackDescription {
     pub solution_stack_name: Option<String>,
 }
 
-struct SolutionStackDescriptionDeserializer;
+pub(crate) struct SolutionStackDescriptionDeserializer;
 impl SolutionStackDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SolutionStackDescription, XmlParseError> {
@@ -6286,10 +9413,10 @@ impl SolutionStackDescriptionDeserializer {
         )
     }
 }
-struct SolutionStackFileTypeListDeserializer;
+pub(crate) struct SolutionStackFileTypeListDeserializer;
 impl SolutionStackFileTypeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6303,10 +9430,13 @@ impl SolutionStackFileTypeListDeserializer {
         })
     }
 }
-struct SolutionStackNameDeserializer;
+pub(crate) struct SolutionStackNameDeserializer;
 impl SolutionStackNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6315,40 +9445,49 @@ impl SolutionStackNameDeserializer {
     }
 }
 /// <p>Location of the source code for an application version.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SourceBuildInformation {
     /// <p><p>The location of the source code, as a formatted string, depending on the value of <code>SourceRepository</code> </p> <ul> <li> <p>For <code>CodeCommit</code>, the format is the repository name and commit ID, separated by a forward slash. For example, <code>my-git-repo/265cfa0cf6af46153527f55d6503ec030551f57a</code>.</p> </li> <li> <p>For <code>S3</code>, the format is the S3 bucket name and object key, separated by a forward slash. For example, <code>my-s3-bucket/Folders/my-source-file</code>.</p> </li> </ul></p>
     pub source_location: String,
     /// <p><p>Location where the repository is stored.</p> <ul> <li> <p> <code>CodeCommit</code> </p> </li> <li> <p> <code>S3</code> </p> </li> </ul></p>
-    pub source_repository: String,
+    pub source_repository: SourceRepository,
     /// <p><p>The type of repository.</p> <ul> <li> <p> <code>Git</code> </p> </li> <li> <p> <code>Zip</code> </p> </li> </ul></p>
-    pub source_type: String,
+    pub source_type: SourceType,
 }
 
-struct SourceBuildInformationDeserializer;
+pub(crate) struct SourceBuildInformationDeserializer;
 impl SourceBuildInformationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SourceBuildInformation, XmlParseError> {
-        deserialize_elements::<_, SourceBuildInformation, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "SourceLocation" => {
-                    obj.source_location =
-                        SourceLocationDeserializer::deserialize("SourceLocation", stack)?;
-                }
-                "SourceRepository" => {
-                    obj.source_repository =
-                        SourceRepositoryDeserializer::deserialize("SourceRepository", stack)?;
-                }
-                "SourceType" => {
-                    obj.source_type = SourceTypeDeserializer::deserialize("SourceType", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            SourceBuildInformation {
+                source_location: Default::default(),
+                source_repository: SourceRepository::Unknown(String::new()),
+                source_type: SourceType::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "SourceLocation" => {
+                        obj.source_location =
+                            SourceLocationDeserializer::deserialize("SourceLocation", stack)?;
+                    }
+                    "SourceRepository" => {
+                        obj.source_repository =
+                            SourceRepositoryDeserializer::deserialize("SourceRepository", stack)?;
+                    }
+                    "SourceType" => {
+                        obj.source_type = SourceTypeDeserializer::deserialize("SourceType", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -6400,10 +9539,13 @@ impl SourceConfigurationSerializer {
     }
 }
 
-struct SourceLocationDeserializer;
+pub(crate) struct SourceLocationDeserializer;
 impl SourceLocationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6411,28 +9553,152 @@ impl SourceLocationDeserializer {
         Ok(obj)
     }
 }
-struct SourceRepositoryDeserializer;
+pub(crate) struct SourceRepositoryDeserializer;
 impl SourceRepositoryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceRepository, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct SourceTypeDeserializer;
+/// <p><p>Location where the repository is stored.</p> <ul> <li> <p> <code>CodeCommit</code> </p> </li> <li> <p> <code>S3</code> </p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceRepository {
+    /// <p><code>CodeCommit</code></p>
+    CodeCommit,
+    /// <p><code>S3</code></p>
+    S3,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SourceRepository {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceRepository::CodeCommit => "CodeCommit",
+            SourceRepository::S3 => "S3",
+            SourceRepository::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceRepository {
+    fn from(value: &'a str) -> SourceRepository {
+        match value {
+            "CodeCommit" => SourceRepository::CodeCommit,
+            "S3" => SourceRepository::S3,
+            _ => SourceRepository::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceRepository {
+    fn from(value: String) -> SourceRepository {
+        SourceRepository::from(value.as_str())
+    }
+}
+
+impl From<SourceRepository> for String {
+    fn from(value: SourceRepository) -> String {
+        match value {
+            SourceRepository::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SourceRepository {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceRepository {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct SourceTypeDeserializer;
 impl SourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p><p>The type of repository.</p> <ul> <li> <p> <code>Git</code> </p> </li> <li> <p> <code>Zip</code> </p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceType {
+    /// <p><code>Git</code></p>
+    Git,
+    /// <p><code>Zip</code></p>
+    Zip,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::Git => "Git",
+            SourceType::Zip => "Zip",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "Git" => SourceType::Git,
+            "Zip" => SourceType::Zip,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        SourceType::from(value.as_str())
+    }
+}
+
+impl From<SourceType> for String {
+    fn from(value: SourceType) -> String {
+        match value {
+            SourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Represents the percentage of requests over the last 10 seconds that resulted in each type of status code response. For more information, see <a href="http://www.w3.org/Protocols/rfc2616/rfc2616-sec10.html">Status Code Definitions</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct StatusCodes {
@@ -6446,10 +9712,10 @@ pub struct StatusCodes {
     pub status_5xx: Option<i64>,
 }
 
-struct StatusCodesDeserializer;
+pub(crate) struct StatusCodesDeserializer;
 impl StatusCodesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StatusCodes, XmlParseError> {
@@ -6485,10 +9751,13 @@ impl StatusCodesDeserializer {
         })
     }
 }
-struct StringDeserializer;
+pub(crate) struct StringDeserializer;
 impl StringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6496,10 +9765,13 @@ impl StringDeserializer {
         Ok(obj)
     }
 }
-struct SupportedAddonDeserializer;
+pub(crate) struct SupportedAddonDeserializer;
 impl SupportedAddonDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6507,10 +9779,10 @@ impl SupportedAddonDeserializer {
         Ok(obj)
     }
 }
-struct SupportedAddonListDeserializer;
+pub(crate) struct SupportedAddonListDeserializer;
 impl SupportedAddonListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6524,10 +9796,13 @@ impl SupportedAddonListDeserializer {
         })
     }
 }
-struct SupportedTierDeserializer;
+pub(crate) struct SupportedTierDeserializer;
 impl SupportedTierDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6535,10 +9810,10 @@ impl SupportedTierDeserializer {
         Ok(obj)
     }
 }
-struct SupportedTierListDeserializer;
+pub(crate) struct SupportedTierListDeserializer;
 impl SupportedTierListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6565,15 +9840,69 @@ pub struct SwapEnvironmentCNAMEsMessage {
     pub source_environment_name: Option<String>,
 }
 
-/// Serialize `SwapEnvironmentCNAMEsMessage` contents to a `SignedRequest`.
-struct SwapEnvironmentCNAMEsMessageSerializer;
-impl SwapEnvironmentCNAMEsMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &SwapEnvironmentCNAMEsMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
-
+impl SwapEnvironmentCNAMEsMessage {
+    /// Returns a builder for `SwapEnvironmentCNAMEsMessage`, checking its required members when built.
+    pub fn builder() -> SwapEnvironmentCNAMEsMessageBuilder {
+        SwapEnvironmentCNAMEsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`SwapEnvironmentCNAMEsMessage`](struct.SwapEnvironmentCNAMEsMessage.html).
+#[derive(Default, Debug)]
+pub struct SwapEnvironmentCNAMEsMessageBuilder {
+    destination_environment_id: Option<String>,
+    destination_environment_name: Option<String>,
+    source_environment_id: Option<String>,
+    source_environment_name: Option<String>,
+}
+
+impl SwapEnvironmentCNAMEsMessageBuilder {
+    /// Sets the [`destination_environment_id`](struct.SwapEnvironmentCNAMEsMessage.html#structfield.destination_environment_id) member.
+    pub fn destination_environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_environment_name`](struct.SwapEnvironmentCNAMEsMessage.html#structfield.destination_environment_name) member.
+    pub fn destination_environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_environment_id`](struct.SwapEnvironmentCNAMEsMessage.html#structfield.source_environment_id) member.
+    pub fn source_environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_environment_name`](struct.SwapEnvironmentCNAMEsMessage.html#structfield.source_environment_name) member.
+    pub fn source_environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_environment_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `SwapEnvironmentCNAMEsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SwapEnvironmentCNAMEsMessage, ::rusoto_core::BuildError> {
+        Ok(SwapEnvironmentCNAMEsMessage {
+            destination_environment_id: self.destination_environment_id,
+            destination_environment_name: self.destination_environment_name,
+            source_environment_id: self.source_environment_id,
+            source_environment_name: self.source_environment_name,
+        })
+    }
+}
+
+/// Serialize `SwapEnvironmentCNAMEsMessage` contents to a `SignedRequest`.
+struct SwapEnvironmentCNAMEsMessageSerializer;
+impl SwapEnvironmentCNAMEsMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &SwapEnvironmentCNAMEsMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
         if let Some(ref field_value) = obj.destination_environment_id {
             params.put(
                 &format!("{}{}", prefix, "DestinationEnvironmentId"),
@@ -6610,10 +9939,10 @@ pub struct SystemStatus {
     pub load_average: Option<Vec<f64>>,
 }
 
-struct SystemStatusDeserializer;
+pub(crate) struct SystemStatusDeserializer;
 impl SystemStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SystemStatus, XmlParseError> {
@@ -6645,10 +9974,13 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -6682,10 +10014,13 @@ impl TagSerializer {
     }
 }
 
-struct TagKeyDeserializer;
+pub(crate) struct TagKeyDeserializer;
 impl TagKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6705,10 +10040,10 @@ impl TagKeyListSerializer {
     }
 }
 
-struct TagListDeserializer;
+pub(crate) struct TagListDeserializer;
 impl TagListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -6734,10 +10069,13 @@ impl TagListSerializer {
     }
 }
 
-struct TagValueDeserializer;
+pub(crate) struct TagValueDeserializer;
 impl TagValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6770,6 +10108,60 @@ pub struct TerminateEnvironmentMessage {
     pub terminate_resources: Option<bool>,
 }
 
+impl TerminateEnvironmentMessage {
+    /// Returns a builder for `TerminateEnvironmentMessage`, checking its required members when built.
+    pub fn builder() -> TerminateEnvironmentMessageBuilder {
+        TerminateEnvironmentMessageBuilder::default()
+    }
+}
+
+/// Builds a [`TerminateEnvironmentMessage`](struct.TerminateEnvironmentMessage.html).
+#[derive(Default, Debug)]
+pub struct TerminateEnvironmentMessageBuilder {
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+    force_terminate: Option<bool>,
+    terminate_resources: Option<bool>,
+}
+
+impl TerminateEnvironmentMessageBuilder {
+    /// Sets the [`environment_id`](struct.TerminateEnvironmentMessage.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.TerminateEnvironmentMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_terminate`](struct.TerminateEnvironmentMessage.html#structfield.force_terminate) member.
+    pub fn force_terminate<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_terminate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`terminate_resources`](struct.TerminateEnvironmentMessage.html#structfield.terminate_resources) member.
+    pub fn terminate_resources<T: Into<bool>>(mut self, value: T) -> Self {
+        self.terminate_resources = Some(value.into());
+        self
+    }
+
+    /// Builds the `TerminateEnvironmentMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TerminateEnvironmentMessage, ::rusoto_core::BuildError> {
+        Ok(TerminateEnvironmentMessage {
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+            force_terminate: self.force_terminate,
+            terminate_resources: self.terminate_resources,
+        })
+    }
+}
+
 /// Serialize `TerminateEnvironmentMessage` contents to a `SignedRequest`.
 struct TerminateEnvironmentMessageSerializer;
 impl TerminateEnvironmentMessageSerializer {
@@ -6794,21 +10186,27 @@ impl TerminateEnvironmentMessageSerializer {
     }
 }
 
-struct TimestampDeserializer;
+pub(crate) struct TimestampDeserializer;
 impl TimestampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct TokenDeserializer;
+pub(crate) struct TokenDeserializer;
 impl TokenDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6823,10 +10221,10 @@ pub struct Trigger {
     pub name: Option<String>,
 }
 
-struct TriggerDeserializer;
+pub(crate) struct TriggerDeserializer;
 impl TriggerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Trigger, XmlParseError> {
@@ -6841,10 +10239,10 @@ impl TriggerDeserializer {
         })
     }
 }
-struct TriggerListDeserializer;
+pub(crate) struct TriggerListDeserializer;
 impl TriggerListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Trigger>, XmlParseError> {
@@ -6867,6 +10265,46 @@ pub struct UpdateApplicationMessage {
     pub description: Option<String>,
 }
 
+impl UpdateApplicationMessage {
+    /// Returns a builder for `UpdateApplicationMessage`, checking its required members when built.
+    pub fn builder() -> UpdateApplicationMessageBuilder {
+        UpdateApplicationMessageBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateApplicationMessage`](struct.UpdateApplicationMessage.html).
+#[derive(Default, Debug)]
+pub struct UpdateApplicationMessageBuilder {
+    application_name: Option<String>,
+    description: Option<String>,
+}
+
+impl UpdateApplicationMessageBuilder {
+    /// Sets the [`application_name`](struct.UpdateApplicationMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateApplicationMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateApplicationMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateApplicationMessage, ::rusoto_core::BuildError> {
+        Ok(UpdateApplicationMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            description: self.description,
+        })
+    }
+}
+
 /// Serialize `UpdateApplicationMessage` contents to a `SignedRequest`.
 struct UpdateApplicationMessageSerializer;
 impl UpdateApplicationMessageSerializer {
@@ -6894,6 +10332,52 @@ pub struct UpdateApplicationResourceLifecycleMessage {
     pub resource_lifecycle_config: ApplicationResourceLifecycleConfig,
 }
 
+impl UpdateApplicationResourceLifecycleMessage {
+    /// Returns a builder for `UpdateApplicationResourceLifecycleMessage`, checking its required members when built.
+    pub fn builder() -> UpdateApplicationResourceLifecycleMessageBuilder {
+        UpdateApplicationResourceLifecycleMessageBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateApplicationResourceLifecycleMessage`](struct.UpdateApplicationResourceLifecycleMessage.html).
+#[derive(Default, Debug)]
+pub struct UpdateApplicationResourceLifecycleMessageBuilder {
+    application_name: Option<String>,
+    resource_lifecycle_config: Option<ApplicationResourceLifecycleConfig>,
+}
+
+impl UpdateApplicationResourceLifecycleMessageBuilder {
+    /// Sets the [`application_name`](struct.UpdateApplicationResourceLifecycleMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_lifecycle_config`](struct.UpdateApplicationResourceLifecycleMessage.html#structfield.resource_lifecycle_config) member.
+    pub fn resource_lifecycle_config<T: Into<ApplicationResourceLifecycleConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.resource_lifecycle_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateApplicationResourceLifecycleMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateApplicationResourceLifecycleMessage, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateApplicationResourceLifecycleMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            resource_lifecycle_config: self.resource_lifecycle_config.ok_or(
+                ::rusoto_core::BuildError::missing("resource_lifecycle_config"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `UpdateApplicationResourceLifecycleMessage` contents to a `SignedRequest`.
 struct UpdateApplicationResourceLifecycleMessageSerializer;
 impl UpdateApplicationResourceLifecycleMessageSerializer {
@@ -6926,6 +10410,56 @@ pub struct UpdateApplicationVersionMessage {
     pub version_label: String,
 }
 
+impl UpdateApplicationVersionMessage {
+    /// Returns a builder for `UpdateApplicationVersionMessage`, checking its required members when built.
+    pub fn builder() -> UpdateApplicationVersionMessageBuilder {
+        UpdateApplicationVersionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateApplicationVersionMessage`](struct.UpdateApplicationVersionMessage.html).
+#[derive(Default, Debug)]
+pub struct UpdateApplicationVersionMessageBuilder {
+    application_name: Option<String>,
+    description: Option<String>,
+    version_label: Option<String>,
+}
+
+impl UpdateApplicationVersionMessageBuilder {
+    /// Sets the [`application_name`](struct.UpdateApplicationVersionMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateApplicationVersionMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_label`](struct.UpdateApplicationVersionMessage.html#structfield.version_label) member.
+    pub fn version_label<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_label = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateApplicationVersionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateApplicationVersionMessage, ::rusoto_core::BuildError> {
+        Ok(UpdateApplicationVersionMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            description: self.description,
+            version_label: self
+                .version_label
+                .ok_or(::rusoto_core::BuildError::missing("version_label"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateApplicationVersionMessage` contents to a `SignedRequest`.
 struct UpdateApplicationVersionMessageSerializer;
 impl UpdateApplicationVersionMessageSerializer {
@@ -6961,6 +10495,72 @@ pub struct UpdateConfigurationTemplateMessage {
     pub template_name: String,
 }
 
+impl UpdateConfigurationTemplateMessage {
+    /// Returns a builder for `UpdateConfigurationTemplateMessage`, checking its required members when built.
+    pub fn builder() -> UpdateConfigurationTemplateMessageBuilder {
+        UpdateConfigurationTemplateMessageBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateConfigurationTemplateMessage`](struct.UpdateConfigurationTemplateMessage.html).
+#[derive(Default, Debug)]
+pub struct UpdateConfigurationTemplateMessageBuilder {
+    application_name: Option<String>,
+    description: Option<String>,
+    option_settings: Option<Vec<ConfigurationOptionSetting>>,
+    options_to_remove: Option<Vec<OptionSpecification>>,
+    template_name: Option<String>,
+}
+
+impl UpdateConfigurationTemplateMessageBuilder {
+    /// Sets the [`application_name`](struct.UpdateConfigurationTemplateMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateConfigurationTemplateMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_settings`](struct.UpdateConfigurationTemplateMessage.html#structfield.option_settings) member.
+    pub fn option_settings<T: Into<Vec<ConfigurationOptionSetting>>>(mut self, value: T) -> Self {
+        self.option_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`options_to_remove`](struct.UpdateConfigurationTemplateMessage.html#structfield.options_to_remove) member.
+    pub fn options_to_remove<T: Into<Vec<OptionSpecification>>>(mut self, value: T) -> Self {
+        self.options_to_remove = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.UpdateConfigurationTemplateMessage.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateConfigurationTemplateMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateConfigurationTemplateMessage, ::rusoto_core::BuildError> {
+        Ok(UpdateConfigurationTemplateMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            description: self.description,
+            option_settings: self.option_settings,
+            options_to_remove: self.options_to_remove,
+            template_name: self
+                .template_name
+                .ok_or(::rusoto_core::BuildError::missing("template_name"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateConfigurationTemplateMessage` contents to a `SignedRequest`.
 struct UpdateConfigurationTemplateMessageSerializer;
 impl UpdateConfigurationTemplateMessageSerializer {
@@ -6995,12 +10595,15 @@ impl UpdateConfigurationTemplateMessageSerializer {
     }
 }
 
-struct UpdateDateDeserializer;
+pub(crate) struct UpdateDateDeserializer;
 impl UpdateDateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7035,6 +10638,124 @@ pub struct UpdateEnvironmentMessage {
     pub version_label: Option<String>,
 }
 
+impl UpdateEnvironmentMessage {
+    /// Returns a builder for `UpdateEnvironmentMessage`, checking its required members when built.
+    pub fn builder() -> UpdateEnvironmentMessageBuilder {
+        UpdateEnvironmentMessageBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateEnvironmentMessage`](struct.UpdateEnvironmentMessage.html).
+#[derive(Default, Debug)]
+pub struct UpdateEnvironmentMessageBuilder {
+    application_name: Option<String>,
+    description: Option<String>,
+    environment_id: Option<String>,
+    environment_name: Option<String>,
+    group_name: Option<String>,
+    option_settings: Option<Vec<ConfigurationOptionSetting>>,
+    options_to_remove: Option<Vec<OptionSpecification>>,
+    platform_arn: Option<String>,
+    solution_stack_name: Option<String>,
+    template_name: Option<String>,
+    tier: Option<EnvironmentTier>,
+    version_label: Option<String>,
+}
+
+impl UpdateEnvironmentMessageBuilder {
+    /// Sets the [`application_name`](struct.UpdateEnvironmentMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateEnvironmentMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_id`](struct.UpdateEnvironmentMessage.html#structfield.environment_id) member.
+    pub fn environment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.UpdateEnvironmentMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.UpdateEnvironmentMessage.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_settings`](struct.UpdateEnvironmentMessage.html#structfield.option_settings) member.
+    pub fn option_settings<T: Into<Vec<ConfigurationOptionSetting>>>(mut self, value: T) -> Self {
+        self.option_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`options_to_remove`](struct.UpdateEnvironmentMessage.html#structfield.options_to_remove) member.
+    pub fn options_to_remove<T: Into<Vec<OptionSpecification>>>(mut self, value: T) -> Self {
+        self.options_to_remove = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform_arn`](struct.UpdateEnvironmentMessage.html#structfield.platform_arn) member.
+    pub fn platform_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.platform_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`solution_stack_name`](struct.UpdateEnvironmentMessage.html#structfield.solution_stack_name) member.
+    pub fn solution_stack_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.solution_stack_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.UpdateEnvironmentMessage.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tier`](struct.UpdateEnvironmentMessage.html#structfield.tier) member.
+    pub fn tier<T: Into<EnvironmentTier>>(mut self, value: T) -> Self {
+        self.tier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_label`](struct.UpdateEnvironmentMessage.html#structfield.version_label) member.
+    pub fn version_label<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_label = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateEnvironmentMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateEnvironmentMessage, ::rusoto_core::BuildError> {
+        Ok(UpdateEnvironmentMessage {
+            application_name: self.application_name,
+            description: self.description,
+            environment_id: self.environment_id,
+            environment_name: self.environment_name,
+            group_name: self.group_name,
+            option_settings: self.option_settings,
+            options_to_remove: self.options_to_remove,
+            platform_arn: self.platform_arn,
+            solution_stack_name: self.solution_stack_name,
+            template_name: self.template_name,
+            tier: self.tier,
+            version_label: self.version_label,
+        })
+    }
+}
+
 /// Serialize `UpdateEnvironmentMessage` contents to a `SignedRequest`.
 struct UpdateEnvironmentMessageSerializer;
 impl UpdateEnvironmentMessageSerializer {
@@ -7105,6 +10826,54 @@ pub struct UpdateTagsForResourceMessage {
     pub tags_to_remove: Option<Vec<String>>,
 }
 
+impl UpdateTagsForResourceMessage {
+    /// Returns a builder for `UpdateTagsForResourceMessage`, checking its required members when built.
+    pub fn builder() -> UpdateTagsForResourceMessageBuilder {
+        UpdateTagsForResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTagsForResourceMessage`](struct.UpdateTagsForResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct UpdateTagsForResourceMessageBuilder {
+    resource_arn: Option<String>,
+    tags_to_add: Option<Vec<Tag>>,
+    tags_to_remove: Option<Vec<String>>,
+}
+
+impl UpdateTagsForResourceMessageBuilder {
+    /// Sets the [`resource_arn`](struct.UpdateTagsForResourceMessage.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags_to_add`](struct.UpdateTagsForResourceMessage.html#structfield.tags_to_add) member.
+    pub fn tags_to_add<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags_to_add = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags_to_remove`](struct.UpdateTagsForResourceMessage.html#structfield.tags_to_remove) member.
+    pub fn tags_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tags_to_remove = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTagsForResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateTagsForResourceMessage, ::rusoto_core::BuildError> {
+        Ok(UpdateTagsForResourceMessage {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags_to_add: self.tags_to_add,
+            tags_to_remove: self.tags_to_remove,
+        })
+    }
+}
+
 /// Serialize `UpdateTagsForResourceMessage` contents to a `SignedRequest`.
 struct UpdateTagsForResourceMessageSerializer;
 impl UpdateTagsForResourceMessageSerializer {
@@ -7132,10 +10901,13 @@ impl UpdateTagsForResourceMessageSerializer {
     }
 }
 
-struct UserDefinedOptionDeserializer;
+pub(crate) struct UserDefinedOptionDeserializer;
 impl UserDefinedOptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -7156,6 +10928,65 @@ pub struct ValidateConfigurationSettingsMessage {
     pub template_name: Option<String>,
 }
 
+impl ValidateConfigurationSettingsMessage {
+    /// Returns a builder for `ValidateConfigurationSettingsMessage`, checking its required members when built.
+    pub fn builder() -> ValidateConfigurationSettingsMessageBuilder {
+        ValidateConfigurationSettingsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ValidateConfigurationSettingsMessage`](struct.ValidateConfigurationSettingsMessage.html).
+#[derive(Default, Debug)]
+pub struct ValidateConfigurationSettingsMessageBuilder {
+    application_name: Option<String>,
+    environment_name: Option<String>,
+    option_settings: Option<Vec<ConfigurationOptionSetting>>,
+    template_name: Option<String>,
+}
+
+impl ValidateConfigurationSettingsMessageBuilder {
+    /// Sets the [`application_name`](struct.ValidateConfigurationSettingsMessage.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment_name`](struct.ValidateConfigurationSettingsMessage.html#structfield.environment_name) member.
+    pub fn environment_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.environment_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_settings`](struct.ValidateConfigurationSettingsMessage.html#structfield.option_settings) member.
+    pub fn option_settings<T: Into<Vec<ConfigurationOptionSetting>>>(mut self, value: T) -> Self {
+        self.option_settings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_name`](struct.ValidateConfigurationSettingsMessage.html#structfield.template_name) member.
+    pub fn template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ValidateConfigurationSettingsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ValidateConfigurationSettingsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ValidateConfigurationSettingsMessage {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            environment_name: self.environment_name,
+            option_settings: self
+                .option_settings
+                .ok_or(::rusoto_core::BuildError::missing("option_settings"))?,
+            template_name: self.template_name,
+        })
+    }
+}
+
 /// Serialize `ValidateConfigurationSettingsMessage` contents to a `SignedRequest`.
 struct ValidateConfigurationSettingsMessageSerializer;
 impl ValidateConfigurationSettingsMessageSerializer {
@@ -7193,13 +11024,13 @@ pub struct ValidationMessage {
     /// <p>The name of the option.</p>
     pub option_name: Option<String>,
     /// <p><p>An indication of the severity of this message:</p> <ul> <li> <p> <code>error</code>: This message indicates that this is not a valid setting for an option.</p> </li> <li> <p> <code>warning</code>: This message is providing information you should take into account.</p> </li> </ul></p>
-    pub severity: Option<String>,
+    pub severity: Option<ValidationSeverity>,
 }
 
-struct ValidationMessageDeserializer;
+pub(crate) struct ValidationMessageDeserializer;
 impl ValidationMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ValidationMessage, XmlParseError> {
@@ -7233,10 +11064,13 @@ impl ValidationMessageDeserializer {
         })
     }
 }
-struct ValidationMessageStringDeserializer;
+pub(crate) struct ValidationMessageStringDeserializer;
 impl ValidationMessageStringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7244,10 +11078,10 @@ impl ValidationMessageStringDeserializer {
         Ok(obj)
     }
 }
-struct ValidationMessagesListDeserializer;
+pub(crate) struct ValidationMessagesListDeserializer;
 impl ValidationMessagesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ValidationMessage>, XmlParseError> {
@@ -7261,21 +11095,86 @@ impl ValidationMessagesListDeserializer {
         })
     }
 }
-struct ValidationSeverityDeserializer;
+pub(crate) struct ValidationSeverityDeserializer;
 impl ValidationSeverityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ValidationSeverity, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct VersionLabelDeserializer;
+/// <p><p>An indication of the severity of this message:</p> <ul> <li> <p> <code>error</code>: This message indicates that this is not a valid setting for an option.</p> </li> <li> <p> <code>warning</code>: This message is providing information you should take into account.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ValidationSeverity {
+    /// <p><code>error</code> - This message indicates that this is not a valid setting for an option.</p>
+    Error,
+    /// <p><code>warning</code> - This message is providing information you should take into account.</p>
+    Warning,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ValidationSeverity {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ValidationSeverity::Error => "error",
+            ValidationSeverity::Warning => "warning",
+            ValidationSeverity::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ValidationSeverity {
+    fn from(value: &'a str) -> ValidationSeverity {
+        match value {
+            "error" => ValidationSeverity::Error,
+            "warning" => ValidationSeverity::Warning,
+            _ => ValidationSeverity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ValidationSeverity {
+    fn from(value: String) -> ValidationSeverity {
+        ValidationSeverity::from(value.as_str())
+    }
+}
+
+impl From<ValidationSeverity> for String {
+    fn from(value: ValidationSeverity) -> String {
+        match value {
+            ValidationSeverity::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ValidationSeverity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct VersionLabelDeserializer;
 impl VersionLabelDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7295,10 +11194,10 @@ impl VersionLabelsSerializer {
     }
 }
 
-struct VersionLabelsListDeserializer;
+pub(crate) struct VersionLabelsListDeserializer;
 impl VersionLabelsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -7324,10 +11223,13 @@ impl VersionLabelsListSerializer {
     }
 }
 
-struct VirtualizationTypeDeserializer;
+pub(crate) struct VirtualizationTypeDeserializer;
 impl VirtualizationTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -9906,6 +13808,286 @@ pub trait ElasticBeanstalk {
         input: ValidateConfigurationSettingsMessage,
     ) -> RusotoFuture<ConfigurationSettingsValidationMessages, ValidateConfigurationSettingsError>;
 }
+impl<T: ?Sized + ElasticBeanstalk> ElasticBeanstalk for ::std::sync::Arc<T> {
+    fn abort_environment_update(
+        &self,
+        input: AbortEnvironmentUpdateMessage,
+    ) -> RusotoFuture<(), AbortEnvironmentUpdateError> {
+        (**self).abort_environment_update(input)
+    }
+    fn apply_environment_managed_action(
+        &self,
+        input: ApplyEnvironmentManagedActionRequest,
+    ) -> RusotoFuture<ApplyEnvironmentManagedActionResult, ApplyEnvironmentManagedActionError> {
+        (**self).apply_environment_managed_action(input)
+    }
+    fn check_dns_availability(
+        &self,
+        input: CheckDNSAvailabilityMessage,
+    ) -> RusotoFuture<CheckDNSAvailabilityResultMessage, CheckDNSAvailabilityError> {
+        (**self).check_dns_availability(input)
+    }
+    fn compose_environments(
+        &self,
+        input: ComposeEnvironmentsMessage,
+    ) -> RusotoFuture<EnvironmentDescriptionsMessage, ComposeEnvironmentsError> {
+        (**self).compose_environments(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationMessage,
+    ) -> RusotoFuture<ApplicationDescriptionMessage, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn create_application_version(
+        &self,
+        input: CreateApplicationVersionMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionMessage, CreateApplicationVersionError> {
+        (**self).create_application_version(input)
+    }
+    fn create_configuration_template(
+        &self,
+        input: CreateConfigurationTemplateMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescription, CreateConfigurationTemplateError> {
+        (**self).create_configuration_template(input)
+    }
+    fn create_environment(
+        &self,
+        input: CreateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, CreateEnvironmentError> {
+        (**self).create_environment(input)
+    }
+    fn create_platform_version(
+        &self,
+        input: CreatePlatformVersionRequest,
+    ) -> RusotoFuture<CreatePlatformVersionResult, CreatePlatformVersionError> {
+        (**self).create_platform_version(input)
+    }
+    fn create_storage_location(
+        &self,
+    ) -> RusotoFuture<CreateStorageLocationResultMessage, CreateStorageLocationError> {
+        (**self).create_storage_location()
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationMessage,
+    ) -> RusotoFuture<(), DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn delete_application_version(
+        &self,
+        input: DeleteApplicationVersionMessage,
+    ) -> RusotoFuture<(), DeleteApplicationVersionError> {
+        (**self).delete_application_version(input)
+    }
+    fn delete_configuration_template(
+        &self,
+        input: DeleteConfigurationTemplateMessage,
+    ) -> RusotoFuture<(), DeleteConfigurationTemplateError> {
+        (**self).delete_configuration_template(input)
+    }
+    fn delete_environment_configuration(
+        &self,
+        input: DeleteEnvironmentConfigurationMessage,
+    ) -> RusotoFuture<(), DeleteEnvironmentConfigurationError> {
+        (**self).delete_environment_configuration(input)
+    }
+    fn delete_platform_version(
+        &self,
+        input: DeletePlatformVersionRequest,
+    ) -> RusotoFuture<DeletePlatformVersionResult, DeletePlatformVersionError> {
+        (**self).delete_platform_version(input)
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResult, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes()
+    }
+    fn describe_application_versions(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionsMessage, DescribeApplicationVersionsError> {
+        (**self).describe_application_versions(input)
+    }
+    fn describe_applications(
+        &self,
+        input: DescribeApplicationsMessage,
+    ) -> RusotoFuture<ApplicationDescriptionsMessage, DescribeApplicationsError> {
+        (**self).describe_applications(input)
+    }
+    fn describe_configuration_options(
+        &self,
+        input: DescribeConfigurationOptionsMessage,
+    ) -> RusotoFuture<ConfigurationOptionsDescription, DescribeConfigurationOptionsError> {
+        (**self).describe_configuration_options(input)
+    }
+    fn describe_configuration_settings(
+        &self,
+        input: DescribeConfigurationSettingsMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescriptions, DescribeConfigurationSettingsError> {
+        (**self).describe_configuration_settings(input)
+    }
+    fn describe_environment_health(
+        &self,
+        input: DescribeEnvironmentHealthRequest,
+    ) -> RusotoFuture<DescribeEnvironmentHealthResult, DescribeEnvironmentHealthError> {
+        (**self).describe_environment_health(input)
+    }
+    fn describe_environment_managed_action_history(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> RusotoFuture<
+        DescribeEnvironmentManagedActionHistoryResult,
+        DescribeEnvironmentManagedActionHistoryError,
+    > {
+        (**self).describe_environment_managed_action_history(input)
+    }
+    fn describe_environment_managed_actions(
+        &self,
+        input: DescribeEnvironmentManagedActionsRequest,
+    ) -> RusotoFuture<DescribeEnvironmentManagedActionsResult, DescribeEnvironmentManagedActionsError>
+    {
+        (**self).describe_environment_managed_actions(input)
+    }
+    fn describe_environment_resources(
+        &self,
+        input: DescribeEnvironmentResourcesMessage,
+    ) -> RusotoFuture<EnvironmentResourceDescriptionsMessage, DescribeEnvironmentResourcesError>
+    {
+        (**self).describe_environment_resources(input)
+    }
+    fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> RusotoFuture<EnvironmentDescriptionsMessage, DescribeEnvironmentsError> {
+        (**self).describe_environments(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventDescriptionsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_instances_health(
+        &self,
+        input: DescribeInstancesHealthRequest,
+    ) -> RusotoFuture<DescribeInstancesHealthResult, DescribeInstancesHealthError> {
+        (**self).describe_instances_health(input)
+    }
+    fn describe_platform_version(
+        &self,
+        input: DescribePlatformVersionRequest,
+    ) -> RusotoFuture<DescribePlatformVersionResult, DescribePlatformVersionError> {
+        (**self).describe_platform_version(input)
+    }
+    fn list_available_solution_stacks(
+        &self,
+    ) -> RusotoFuture<ListAvailableSolutionStacksResultMessage, ListAvailableSolutionStacksError>
+    {
+        (**self).list_available_solution_stacks()
+    }
+    fn list_platform_versions(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> RusotoFuture<ListPlatformVersionsResult, ListPlatformVersionsError> {
+        (**self).list_platform_versions(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<ResourceTagsDescriptionMessage, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn rebuild_environment(
+        &self,
+        input: RebuildEnvironmentMessage,
+    ) -> RusotoFuture<(), RebuildEnvironmentError> {
+        (**self).rebuild_environment(input)
+    }
+    fn request_environment_info(
+        &self,
+        input: RequestEnvironmentInfoMessage,
+    ) -> RusotoFuture<(), RequestEnvironmentInfoError> {
+        (**self).request_environment_info(input)
+    }
+    fn restart_app_server(
+        &self,
+        input: RestartAppServerMessage,
+    ) -> RusotoFuture<(), RestartAppServerError> {
+        (**self).restart_app_server(input)
+    }
+    fn retrieve_environment_info(
+        &self,
+        input: RetrieveEnvironmentInfoMessage,
+    ) -> RusotoFuture<RetrieveEnvironmentInfoResultMessage, RetrieveEnvironmentInfoError> {
+        (**self).retrieve_environment_info(input)
+    }
+    fn swap_environment_cnam_es(
+        &self,
+        input: SwapEnvironmentCNAMEsMessage,
+    ) -> RusotoFuture<(), SwapEnvironmentCNAMEsError> {
+        (**self).swap_environment_cnam_es(input)
+    }
+    fn terminate_environment(
+        &self,
+        input: TerminateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, TerminateEnvironmentError> {
+        (**self).terminate_environment(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationMessage,
+    ) -> RusotoFuture<ApplicationDescriptionMessage, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+    fn update_application_resource_lifecycle(
+        &self,
+        input: UpdateApplicationResourceLifecycleMessage,
+    ) -> RusotoFuture<
+        ApplicationResourceLifecycleDescriptionMessage,
+        UpdateApplicationResourceLifecycleError,
+    > {
+        (**self).update_application_resource_lifecycle(input)
+    }
+    fn update_application_version(
+        &self,
+        input: UpdateApplicationVersionMessage,
+    ) -> RusotoFuture<ApplicationVersionDescriptionMessage, UpdateApplicationVersionError> {
+        (**self).update_application_version(input)
+    }
+    fn update_configuration_template(
+        &self,
+        input: UpdateConfigurationTemplateMessage,
+    ) -> RusotoFuture<ConfigurationSettingsDescription, UpdateConfigurationTemplateError> {
+        (**self).update_configuration_template(input)
+    }
+    fn update_environment(
+        &self,
+        input: UpdateEnvironmentMessage,
+    ) -> RusotoFuture<EnvironmentDescription, UpdateEnvironmentError> {
+        (**self).update_environment(input)
+    }
+    fn update_tags_for_resource(
+        &self,
+        input: UpdateTagsForResourceMessage,
+    ) -> RusotoFuture<(), UpdateTagsForResourceError> {
+        (**self).update_tags_for_resource(input)
+    }
+    fn validate_configuration_settings(
+        &self,
+        input: ValidateConfigurationSettingsMessage,
+    ) -> RusotoFuture<ConfigurationSettingsValidationMessages, ValidateConfigurationSettingsError>
+    {
+        (**self).validate_configuration_settings(input)
+    }
+}
+/// The error codes Elastic Beanstalk retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Elastic Beanstalk API.
 #[derive(Clone)]
 pub struct ElasticBeanstalkClient {
@@ -9921,6 +14103,27 @@ impl ElasticBeanstalkClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ELASTICBEANSTALK`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ElasticBeanstalkClient {
+        Self::new(region::Region::from_env_for_service("ELASTICBEANSTALK"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `elasticbeanstalk`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ElasticBeanstalkClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("elasticbeanstalk", "ELASTICBEANSTALK"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -9939,7 +14142,54 @@ impl ElasticBeanstalkClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ElasticBeanstalkClient {
-        ElasticBeanstalkClient { client, region }
+        ElasticBeanstalkClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "elasticbeanstalk")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "elasticbeanstalk",
+            "elasticbeanstalk",
+            "ELASTICBEANSTALK",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ElasticBeanstalk` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ElasticBeanstalk + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ElasticBeanstalkClient {
+    fn service_name(&self) -> &'static str {
+        "Elastic Beanstalk"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "elasticbeanstalk"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ElasticBeanstalkClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -12084,7 +16334,11 @@ mod protocol_tests {
         let mock = MockRequestDispatcher::with_status(200).with_body(&mock_response);
         let client =
             ElasticBeanstalkClient::new_with(mock, MockCredentialsProvider, rusoto_region::UsEast1);
-        let request = RetrieveEnvironmentInfoMessage::default();
+        let request = RetrieveEnvironmentInfoMessage {
+            environment_id: Default::default(),
+            environment_name: Default::default(),
+            info_type: EnvironmentInfoType::Unknown(String::new()),
+        };
         let result = client.retrieve_environment_info(request).sync();
         assert!(result.is_ok(), "parse error: {:?}", result);
     }
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>The file to be used as album art. There can be multiple artworks associated with an audio file, to a maximum of 20.</p> <p>To remove artwork or leave the artwork empty, you can either set <code>Artwork</code> to null, or set the <code>Merge Policy</code> to "Replace" and use an empty <code>Artwork</code> array.</p> <p>To pass through existing artwork unchanged, set the <code>Merge Policy</code> to "Prepend", "Append", or "Fallback", and use an empty <code>Artwork</code> array.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -115,6 +116,34 @@ pub struct CancelJobRequest {
     pub id: String,
 }
 
+impl CancelJobRequest {
+    /// Returns a builder for `CancelJobRequest`, checking its required members when built.
+    pub fn builder() -> CancelJobRequestBuilder {
+        CancelJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelJobRequest`](struct.CancelJobRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelJobRequestBuilder {
+    id: Option<String>,
+}
+
+impl CancelJobRequestBuilder {
+    /// Sets the [`id`](struct.CancelJobRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelJobRequest, ::rusoto_core::BuildError> {
+        Ok(CancelJobRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>The response body contains a JSON object. If the job is successfully canceled, the value of <code>Success</code> is <code>true</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -284,6 +313,95 @@ pub struct CreateJobRequest {
     pub user_metadata: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateJobRequest {
+    /// Returns a builder for `CreateJobRequest`, checking its required members when built.
+    pub fn builder() -> CreateJobRequestBuilder {
+        CreateJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateJobRequest`](struct.CreateJobRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateJobRequestBuilder {
+    input: Option<JobInput>,
+    inputs: Option<Vec<JobInput>>,
+    output: Option<CreateJobOutput>,
+    output_key_prefix: Option<String>,
+    outputs: Option<Vec<CreateJobOutput>>,
+    pipeline_id: Option<String>,
+    playlists: Option<Vec<CreateJobPlaylist>>,
+    user_metadata: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateJobRequestBuilder {
+    /// Sets the [`input`](struct.CreateJobRequest.html#structfield.input) member.
+    pub fn input<T: Into<JobInput>>(mut self, value: T) -> Self {
+        self.input = Some(value.into());
+        self
+    }
+
+    /// Sets the [`inputs`](struct.CreateJobRequest.html#structfield.inputs) member.
+    pub fn inputs<T: Into<Vec<JobInput>>>(mut self, value: T) -> Self {
+        self.inputs = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output`](struct.CreateJobRequest.html#structfield.output) member.
+    pub fn output<T: Into<CreateJobOutput>>(mut self, value: T) -> Self {
+        self.output = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_key_prefix`](struct.CreateJobRequest.html#structfield.output_key_prefix) member.
+    pub fn output_key_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.output_key_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`outputs`](struct.CreateJobRequest.html#structfield.outputs) member.
+    pub fn outputs<T: Into<Vec<CreateJobOutput>>>(mut self, value: T) -> Self {
+        self.outputs = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_id`](struct.CreateJobRequest.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`playlists`](struct.CreateJobRequest.html#structfield.playlists) member.
+    pub fn playlists<T: Into<Vec<CreateJobPlaylist>>>(mut self, value: T) -> Self {
+        self.playlists = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_metadata`](struct.CreateJobRequest.html#structfield.user_metadata) member.
+    pub fn user_metadata<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.user_metadata = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateJobRequest, ::rusoto_core::BuildError> {
+        Ok(CreateJobRequest {
+            input: self.input,
+            inputs: self.inputs,
+            output: self.output,
+            output_key_prefix: self.output_key_prefix,
+            outputs: self.outputs,
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+            playlists: self.playlists,
+            user_metadata: self.user_metadata,
+        })
+    }
+}
+
 /// <p>The CreateJobResponse structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -328,6 +446,96 @@ pub struct CreatePipelineRequest {
     pub thumbnail_config: Option<PipelineOutputConfig>,
 }
 
+impl CreatePipelineRequest {
+    /// Returns a builder for `CreatePipelineRequest`, checking its required members when built.
+    pub fn builder() -> CreatePipelineRequestBuilder {
+        CreatePipelineRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePipelineRequest`](struct.CreatePipelineRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePipelineRequestBuilder {
+    aws_kms_key_arn: Option<String>,
+    content_config: Option<PipelineOutputConfig>,
+    input_bucket: Option<String>,
+    name: Option<String>,
+    notifications: Option<Notifications>,
+    output_bucket: Option<String>,
+    role: Option<String>,
+    thumbnail_config: Option<PipelineOutputConfig>,
+}
+
+impl CreatePipelineRequestBuilder {
+    /// Sets the [`aws_kms_key_arn`](struct.CreatePipelineRequest.html#structfield.aws_kms_key_arn) member.
+    pub fn aws_kms_key_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.aws_kms_key_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_config`](struct.CreatePipelineRequest.html#structfield.content_config) member.
+    pub fn content_config<T: Into<PipelineOutputConfig>>(mut self, value: T) -> Self {
+        self.content_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_bucket`](struct.CreatePipelineRequest.html#structfield.input_bucket) member.
+    pub fn input_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.input_bucket = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreatePipelineRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notifications`](struct.CreatePipelineRequest.html#structfield.notifications) member.
+    pub fn notifications<T: Into<Notifications>>(mut self, value: T) -> Self {
+        self.notifications = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_bucket`](struct.CreatePipelineRequest.html#structfield.output_bucket) member.
+    pub fn output_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.output_bucket = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.CreatePipelineRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thumbnail_config`](struct.CreatePipelineRequest.html#structfield.thumbnail_config) member.
+    pub fn thumbnail_config<T: Into<PipelineOutputConfig>>(mut self, value: T) -> Self {
+        self.thumbnail_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePipelineRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePipelineRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePipelineRequest {
+            aws_kms_key_arn: self.aws_kms_key_arn,
+            content_config: self.content_config,
+            input_bucket: self
+                .input_bucket
+                .ok_or(::rusoto_core::BuildError::missing("input_bucket"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            notifications: self.notifications,
+            output_bucket: self.output_bucket,
+            role: self
+                .role
+                .ok_or(::rusoto_core::BuildError::missing("role"))?,
+            thumbnail_config: self.thumbnail_config,
+        })
+    }
+}
+
 /// <p>When you create a pipeline, Elastic Transcoder returns the values that you specified in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -369,6 +577,78 @@ pub struct CreatePresetRequest {
     pub video: Option<VideoParameters>,
 }
 
+impl CreatePresetRequest {
+    /// Returns a builder for `CreatePresetRequest`, checking its required members when built.
+    pub fn builder() -> CreatePresetRequestBuilder {
+        CreatePresetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePresetRequest`](struct.CreatePresetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePresetRequestBuilder {
+    audio: Option<AudioParameters>,
+    container: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+    thumbnails: Option<Thumbnails>,
+    video: Option<VideoParameters>,
+}
+
+impl CreatePresetRequestBuilder {
+    /// Sets the [`audio`](struct.CreatePresetRequest.html#structfield.audio) member.
+    pub fn audio<T: Into<AudioParameters>>(mut self, value: T) -> Self {
+        self.audio = Some(value.into());
+        self
+    }
+
+    /// Sets the [`container`](struct.CreatePresetRequest.html#structfield.container) member.
+    pub fn container<T: Into<String>>(mut self, value: T) -> Self {
+        self.container = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreatePresetRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreatePresetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thumbnails`](struct.CreatePresetRequest.html#structfield.thumbnails) member.
+    pub fn thumbnails<T: Into<Thumbnails>>(mut self, value: T) -> Self {
+        self.thumbnails = Some(value.into());
+        self
+    }
+
+    /// Sets the [`video`](struct.CreatePresetRequest.html#structfield.video) member.
+    pub fn video<T: Into<VideoParameters>>(mut self, value: T) -> Self {
+        self.video = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePresetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePresetRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePresetRequest {
+            audio: self.audio,
+            container: self
+                .container
+                .ok_or(::rusoto_core::BuildError::missing("container"))?,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            thumbnails: self.thumbnails,
+            video: self.video,
+        })
+    }
+}
+
 /// <p>The <code>CreatePresetResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -391,6 +671,34 @@ pub struct DeletePipelineRequest {
     pub id: String,
 }
 
+impl DeletePipelineRequest {
+    /// Returns a builder for `DeletePipelineRequest`, checking its required members when built.
+    pub fn builder() -> DeletePipelineRequestBuilder {
+        DeletePipelineRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePipelineRequest`](struct.DeletePipelineRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePipelineRequestBuilder {
+    id: Option<String>,
+}
+
+impl DeletePipelineRequestBuilder {
+    /// Sets the [`id`](struct.DeletePipelineRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePipelineRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePipelineRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePipelineRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>The <code>DeletePipelineResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -404,6 +712,34 @@ pub struct DeletePresetRequest {
     pub id: String,
 }
 
+impl DeletePresetRequest {
+    /// Returns a builder for `DeletePresetRequest`, checking its required members when built.
+    pub fn builder() -> DeletePresetRequestBuilder {
+        DeletePresetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePresetRequest`](struct.DeletePresetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePresetRequestBuilder {
+    id: Option<String>,
+}
+
+impl DeletePresetRequestBuilder {
+    /// Sets the [`id`](struct.DeletePresetRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePresetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePresetRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePresetRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>The <code>DeletePresetResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -728,6 +1064,54 @@ pub struct ListJobsByPipelineRequest {
     pub pipeline_id: String,
 }
 
+impl ListJobsByPipelineRequest {
+    /// Returns a builder for `ListJobsByPipelineRequest`, checking its required members when built.
+    pub fn builder() -> ListJobsByPipelineRequestBuilder {
+        ListJobsByPipelineRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListJobsByPipelineRequest`](struct.ListJobsByPipelineRequest.html).
+#[derive(Default, Debug)]
+pub struct ListJobsByPipelineRequestBuilder {
+    ascending: Option<String>,
+    page_token: Option<String>,
+    pipeline_id: Option<String>,
+}
+
+impl ListJobsByPipelineRequestBuilder {
+    /// Sets the [`ascending`](struct.ListJobsByPipelineRequest.html#structfield.ascending) member.
+    pub fn ascending<T: Into<String>>(mut self, value: T) -> Self {
+        self.ascending = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListJobsByPipelineRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_id`](struct.ListJobsByPipelineRequest.html#structfield.pipeline_id) member.
+    pub fn pipeline_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJobsByPipelineRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListJobsByPipelineRequest, ::rusoto_core::BuildError> {
+        Ok(ListJobsByPipelineRequest {
+            ascending: self.ascending,
+            page_token: self.page_token,
+            pipeline_id: self
+                .pipeline_id
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_id"))?,
+        })
+    }
+}
+
 /// <p>The <code>ListJobsByPipelineResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -758,6 +1142,54 @@ pub struct ListJobsByStatusRequest {
     pub status: String,
 }
 
+impl ListJobsByStatusRequest {
+    /// Returns a builder for `ListJobsByStatusRequest`, checking its required members when built.
+    pub fn builder() -> ListJobsByStatusRequestBuilder {
+        ListJobsByStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListJobsByStatusRequest`](struct.ListJobsByStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct ListJobsByStatusRequestBuilder {
+    ascending: Option<String>,
+    page_token: Option<String>,
+    status: Option<String>,
+}
+
+impl ListJobsByStatusRequestBuilder {
+    /// Sets the [`ascending`](struct.ListJobsByStatusRequest.html#structfield.ascending) member.
+    pub fn ascending<T: Into<String>>(mut self, value: T) -> Self {
+        self.ascending = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListJobsByStatusRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListJobsByStatusRequest.html#structfield.status) member.
+    pub fn status<T: Into<String>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJobsByStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListJobsByStatusRequest, ::rusoto_core::BuildError> {
+        Ok(ListJobsByStatusRequest {
+            ascending: self.ascending,
+            page_token: self.page_token,
+            status: self
+                .status
+                .ok_or(::rusoto_core::BuildError::missing("status"))?,
+        })
+    }
+}
+
 /// <p> The <code>ListJobsByStatusResponse</code> structure. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -785,6 +1217,42 @@ pub struct ListPipelinesRequest {
     pub page_token: Option<String>,
 }
 
+impl ListPipelinesRequest {
+    /// Returns a builder for `ListPipelinesRequest`, checking its required members when built.
+    pub fn builder() -> ListPipelinesRequestBuilder {
+        ListPipelinesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPipelinesRequest`](struct.ListPipelinesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPipelinesRequestBuilder {
+    ascending: Option<String>,
+    page_token: Option<String>,
+}
+
+impl ListPipelinesRequestBuilder {
+    /// Sets the [`ascending`](struct.ListPipelinesRequest.html#structfield.ascending) member.
+    pub fn ascending<T: Into<String>>(mut self, value: T) -> Self {
+        self.ascending = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListPipelinesRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPipelinesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPipelinesRequest, ::rusoto_core::BuildError> {
+        Ok(ListPipelinesRequest {
+            ascending: self.ascending,
+            page_token: self.page_token,
+        })
+    }
+}
+
 /// <p>A list of the pipelines associated with the current AWS account.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -812,6 +1280,42 @@ pub struct ListPresetsRequest {
     pub page_token: Option<String>,
 }
 
+impl ListPresetsRequest {
+    /// Returns a builder for `ListPresetsRequest`, checking its required members when built.
+    pub fn builder() -> ListPresetsRequestBuilder {
+        ListPresetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPresetsRequest`](struct.ListPresetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPresetsRequestBuilder {
+    ascending: Option<String>,
+    page_token: Option<String>,
+}
+
+impl ListPresetsRequestBuilder {
+    /// Sets the [`ascending`](struct.ListPresetsRequest.html#structfield.ascending) member.
+    pub fn ascending<T: Into<String>>(mut self, value: T) -> Self {
+        self.ascending = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.ListPresetsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPresetsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPresetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPresetsRequest {
+            ascending: self.ascending,
+            page_token: self.page_token,
+        })
+    }
+}
+
 /// <p>The <code>ListPresetsResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1089,6 +1593,34 @@ pub struct ReadJobRequest {
     pub id: String,
 }
 
+impl ReadJobRequest {
+    /// Returns a builder for `ReadJobRequest`, checking its required members when built.
+    pub fn builder() -> ReadJobRequestBuilder {
+        ReadJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ReadJobRequest`](struct.ReadJobRequest.html).
+#[derive(Default, Debug)]
+pub struct ReadJobRequestBuilder {
+    id: Option<String>,
+}
+
+impl ReadJobRequestBuilder {
+    /// Sets the [`id`](struct.ReadJobRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReadJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ReadJobRequest, ::rusoto_core::BuildError> {
+        Ok(ReadJobRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>The <code>ReadJobResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1107,6 +1639,34 @@ pub struct ReadPipelineRequest {
     pub id: String,
 }
 
+impl ReadPipelineRequest {
+    /// Returns a builder for `ReadPipelineRequest`, checking its required members when built.
+    pub fn builder() -> ReadPipelineRequestBuilder {
+        ReadPipelineRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ReadPipelineRequest`](struct.ReadPipelineRequest.html).
+#[derive(Default, Debug)]
+pub struct ReadPipelineRequestBuilder {
+    id: Option<String>,
+}
+
+impl ReadPipelineRequestBuilder {
+    /// Sets the [`id`](struct.ReadPipelineRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReadPipelineRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ReadPipelineRequest, ::rusoto_core::BuildError> {
+        Ok(ReadPipelineRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>The <code>ReadPipelineResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1129,6 +1689,34 @@ pub struct ReadPresetRequest {
     pub id: String,
 }
 
+impl ReadPresetRequest {
+    /// Returns a builder for `ReadPresetRequest`, checking its required members when built.
+    pub fn builder() -> ReadPresetRequestBuilder {
+        ReadPresetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ReadPresetRequest`](struct.ReadPresetRequest.html).
+#[derive(Default, Debug)]
+pub struct ReadPresetRequestBuilder {
+    id: Option<String>,
+}
+
+impl ReadPresetRequestBuilder {
+    /// Sets the [`id`](struct.ReadPresetRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReadPresetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ReadPresetRequest, ::rusoto_core::BuildError> {
+        Ok(ReadPresetRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 /// <p>The <code>ReadPresetResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1156,6 +1744,66 @@ pub struct TestRoleRequest {
     pub topics: Vec<String>,
 }
 
+impl TestRoleRequest {
+    /// Returns a builder for `TestRoleRequest`, checking its required members when built.
+    pub fn builder() -> TestRoleRequestBuilder {
+        TestRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TestRoleRequest`](struct.TestRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct TestRoleRequestBuilder {
+    input_bucket: Option<String>,
+    output_bucket: Option<String>,
+    role: Option<String>,
+    topics: Option<Vec<String>>,
+}
+
+impl TestRoleRequestBuilder {
+    /// Sets the [`input_bucket`](struct.TestRoleRequest.html#structfield.input_bucket) member.
+    pub fn input_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.input_bucket = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_bucket`](struct.TestRoleRequest.html#structfield.output_bucket) member.
+    pub fn output_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.output_bucket = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.TestRoleRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topics`](struct.TestRoleRequest.html#structfield.topics) member.
+    pub fn topics<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.topics = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TestRoleRequest, ::rusoto_core::BuildError> {
+        Ok(TestRoleRequest {
+            input_bucket: self
+                .input_bucket
+                .ok_or(::rusoto_core::BuildError::missing("input_bucket"))?,
+            output_bucket: self
+                .output_bucket
+                .ok_or(::rusoto_core::BuildError::missing("output_bucket"))?,
+            role: self
+                .role
+                .ok_or(::rusoto_core::BuildError::missing("role"))?,
+            topics: self
+                .topics
+                .ok_or(::rusoto_core::BuildError::missing("topics"))?,
+        })
+    }
+}
+
 /// <p>The <code>TestRoleResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1249,6 +1897,46 @@ pub struct UpdatePipelineNotificationsRequest {
     pub notifications: Notifications,
 }
 
+impl UpdatePipelineNotificationsRequest {
+    /// Returns a builder for `UpdatePipelineNotificationsRequest`, checking its required members when built.
+    pub fn builder() -> UpdatePipelineNotificationsRequestBuilder {
+        UpdatePipelineNotificationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePipelineNotificationsRequest`](struct.UpdatePipelineNotificationsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdatePipelineNotificationsRequestBuilder {
+    id: Option<String>,
+    notifications: Option<Notifications>,
+}
+
+impl UpdatePipelineNotificationsRequestBuilder {
+    /// Sets the [`id`](struct.UpdatePipelineNotificationsRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notifications`](struct.UpdatePipelineNotificationsRequest.html#structfield.notifications) member.
+    pub fn notifications<T: Into<Notifications>>(mut self, value: T) -> Self {
+        self.notifications = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePipelineNotificationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdatePipelineNotificationsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdatePipelineNotificationsRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            notifications: self
+                .notifications
+                .ok_or(::rusoto_core::BuildError::missing("notifications"))?,
+        })
+    }
+}
+
 /// <p>The <code>UpdatePipelineNotificationsResponse</code> structure.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1295,6 +1983,90 @@ pub struct UpdatePipelineRequest {
     pub thumbnail_config: Option<PipelineOutputConfig>,
 }
 
+impl UpdatePipelineRequest {
+    /// Returns a builder for `UpdatePipelineRequest`, checking its required members when built.
+    pub fn builder() -> UpdatePipelineRequestBuilder {
+        UpdatePipelineRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePipelineRequest`](struct.UpdatePipelineRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdatePipelineRequestBuilder {
+    aws_kms_key_arn: Option<String>,
+    content_config: Option<PipelineOutputConfig>,
+    id: Option<String>,
+    input_bucket: Option<String>,
+    name: Option<String>,
+    notifications: Option<Notifications>,
+    role: Option<String>,
+    thumbnail_config: Option<PipelineOutputConfig>,
+}
+
+impl UpdatePipelineRequestBuilder {
+    /// Sets the [`aws_kms_key_arn`](struct.UpdatePipelineRequest.html#structfield.aws_kms_key_arn) member.
+    pub fn aws_kms_key_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.aws_kms_key_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_config`](struct.UpdatePipelineRequest.html#structfield.content_config) member.
+    pub fn content_config<T: Into<PipelineOutputConfig>>(mut self, value: T) -> Self {
+        self.content_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdatePipelineRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_bucket`](struct.UpdatePipelineRequest.html#structfield.input_bucket) member.
+    pub fn input_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.input_bucket = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdatePipelineRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notifications`](struct.UpdatePipelineRequest.html#structfield.notifications) member.
+    pub fn notifications<T: Into<Notifications>>(mut self, value: T) -> Self {
+        self.notifications = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.UpdatePipelineRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thumbnail_config`](struct.UpdatePipelineRequest.html#structfield.thumbnail_config) member.
+    pub fn thumbnail_config<T: Into<PipelineOutputConfig>>(mut self, value: T) -> Self {
+        self.thumbnail_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePipelineRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdatePipelineRequest, ::rusoto_core::BuildError> {
+        Ok(UpdatePipelineRequest {
+            aws_kms_key_arn: self.aws_kms_key_arn,
+            content_config: self.content_config,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            input_bucket: self.input_bucket,
+            name: self.name,
+            notifications: self.notifications,
+            role: self.role,
+            thumbnail_config: self.thumbnail_config,
+        })
+    }
+}
+
 /// <p>When you update a pipeline, Elastic Transcoder returns the values that you specified in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1320,6 +2092,46 @@ pub struct UpdatePipelineStatusRequest {
     pub status: String,
 }
 
+impl UpdatePipelineStatusRequest {
+    /// Returns a builder for `UpdatePipelineStatusRequest`, checking its required members when built.
+    pub fn builder() -> UpdatePipelineStatusRequestBuilder {
+        UpdatePipelineStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePipelineStatusRequest`](struct.UpdatePipelineStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdatePipelineStatusRequestBuilder {
+    id: Option<String>,
+    status: Option<String>,
+}
+
+impl UpdatePipelineStatusRequestBuilder {
+    /// Sets the [`id`](struct.UpdatePipelineStatusRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.UpdatePipelineStatusRequest.html#structfield.status) member.
+    pub fn status<T: Into<String>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePipelineStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdatePipelineStatusRequest, ::rusoto_core::BuildError> {
+        Ok(UpdatePipelineStatusRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            status: self
+                .status
+                .ok_or(::rusoto_core::BuildError::missing("status"))?,
+        })
+    }
+}
+
 /// <p>When you update status for a pipeline, Elastic Transcoder returns the values that you specified in the request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2426,6 +3238,111 @@ pub trait Ets {
         input: UpdatePipelineStatusRequest,
     ) -> RusotoFuture<UpdatePipelineStatusResponse, UpdatePipelineStatusError>;
 }
+impl<T: ?Sized + Ets> Ets for ::std::sync::Arc<T> {
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> RusotoFuture<CreatePipelineResponse, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> RusotoFuture<CreatePresetResponse, CreatePresetError> {
+        (**self).create_preset(input)
+    }
+    fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> RusotoFuture<DeletePipelineResponse, DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> RusotoFuture<DeletePresetResponse, DeletePresetError> {
+        (**self).delete_preset(input)
+    }
+    fn list_jobs_by_pipeline(
+        &self,
+        input: ListJobsByPipelineRequest,
+    ) -> RusotoFuture<ListJobsByPipelineResponse, ListJobsByPipelineError> {
+        (**self).list_jobs_by_pipeline(input)
+    }
+    fn list_jobs_by_status(
+        &self,
+        input: ListJobsByStatusRequest,
+    ) -> RusotoFuture<ListJobsByStatusResponse, ListJobsByStatusError> {
+        (**self).list_jobs_by_status(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> RusotoFuture<ListPipelinesResponse, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> RusotoFuture<ListPresetsResponse, ListPresetsError> {
+        (**self).list_presets(input)
+    }
+    fn read_job(&self, input: ReadJobRequest) -> RusotoFuture<ReadJobResponse, ReadJobError> {
+        (**self).read_job(input)
+    }
+    fn read_pipeline(
+        &self,
+        input: ReadPipelineRequest,
+    ) -> RusotoFuture<ReadPipelineResponse, ReadPipelineError> {
+        (**self).read_pipeline(input)
+    }
+    fn read_preset(
+        &self,
+        input: ReadPresetRequest,
+    ) -> RusotoFuture<ReadPresetResponse, ReadPresetError> {
+        (**self).read_preset(input)
+    }
+    fn test_role(&self, input: TestRoleRequest) -> RusotoFuture<TestRoleResponse, TestRoleError> {
+        (**self).test_role(input)
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> RusotoFuture<UpdatePipelineResponse, UpdatePipelineError> {
+        (**self).update_pipeline(input)
+    }
+    fn update_pipeline_notifications(
+        &self,
+        input: UpdatePipelineNotificationsRequest,
+    ) -> RusotoFuture<UpdatePipelineNotificationsResponse, UpdatePipelineNotificationsError> {
+        (**self).update_pipeline_notifications(input)
+    }
+    fn update_pipeline_status(
+        &self,
+        input: UpdatePipelineStatusRequest,
+    ) -> RusotoFuture<UpdatePipelineStatusResponse, UpdatePipelineStatusError> {
+        (**self).update_pipeline_status(input)
+    }
+}
+/// The error codes Amazon Elastic Transcoder retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Elastic Transcoder API.
 #[derive(Clone)]
 pub struct EtsClient {
@@ -2441,6 +3358,27 @@ impl EtsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ELASTICTRANSCODER`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> EtsClient {
+        Self::new(region::Region::from_env_for_service("ELASTICTRANSCODER"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `elastictranscoder`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> EtsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("elastictranscoder", "ELASTICTRANSCODER"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2459,7 +3397,54 @@ impl EtsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EtsClient {
-        EtsClient { client, region }
+        EtsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "elastictranscoder")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "elastictranscoder",
+            "elastictranscoder",
+            "ELASTICTRANSCODER",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Ets` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Ets + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for EtsClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Elastic Transcoder"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "elastictranscoder"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> EtsClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -28,6 +28,7 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -46,10 +47,10 @@ pub struct AccessLog {
     pub s3_bucket_prefix: Option<String>,
 }
 
-struct AccessLogDeserializer;
+pub(crate) struct AccessLogDeserializer;
 impl AccessLogDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccessLog, XmlParseError> {
@@ -105,10 +106,13 @@ impl AccessLogSerializer {
     }
 }
 
-struct AccessLogEnabledDeserializer;
+pub(crate) struct AccessLogEnabledDeserializer;
 impl AccessLogEnabledDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -116,10 +120,13 @@ impl AccessLogEnabledDeserializer {
         Ok(obj)
     }
 }
-struct AccessLogIntervalDeserializer;
+pub(crate) struct AccessLogIntervalDeserializer;
 impl AccessLogIntervalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -127,10 +134,13 @@ impl AccessLogIntervalDeserializer {
         Ok(obj)
     }
 }
-struct AccessLogPrefixDeserializer;
+pub(crate) struct AccessLogPrefixDeserializer;
 impl AccessLogPrefixDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -138,10 +148,13 @@ impl AccessLogPrefixDeserializer {
         Ok(obj)
     }
 }
-struct AccessPointNameDeserializer;
+pub(crate) struct AccessPointNameDeserializer;
 impl AccessPointNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -149,10 +162,13 @@ impl AccessPointNameDeserializer {
         Ok(obj)
     }
 }
-struct AccessPointPortDeserializer;
+pub(crate) struct AccessPointPortDeserializer;
 impl AccessPointPortDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -169,6 +185,48 @@ pub struct AddAvailabilityZonesInput {
     pub load_balancer_name: String,
 }
 
+impl AddAvailabilityZonesInput {
+    /// Returns a builder for `AddAvailabilityZonesInput`, checking its required members when built.
+    pub fn builder() -> AddAvailabilityZonesInputBuilder {
+        AddAvailabilityZonesInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddAvailabilityZonesInput`](struct.AddAvailabilityZonesInput.html).
+#[derive(Default, Debug)]
+pub struct AddAvailabilityZonesInputBuilder {
+    availability_zones: Option<Vec<String>>,
+    load_balancer_name: Option<String>,
+}
+
+impl AddAvailabilityZonesInputBuilder {
+    /// Sets the [`availability_zones`](struct.AddAvailabilityZonesInput.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.AddAvailabilityZonesInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddAvailabilityZonesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddAvailabilityZonesInput, ::rusoto_core::BuildError> {
+        Ok(AddAvailabilityZonesInput {
+            availability_zones: self
+                .availability_zones
+                .ok_or(::rusoto_core::BuildError::missing("availability_zones"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 /// Serialize `AddAvailabilityZonesInput` contents to a `SignedRequest`.
 struct AddAvailabilityZonesInputSerializer;
 impl AddAvailabilityZonesInputSerializer {
@@ -197,10 +255,10 @@ pub struct AddAvailabilityZonesOutput {
     pub availability_zones: Option<Vec<String>>,
 }
 
-struct AddAvailabilityZonesOutputDeserializer;
+pub(crate) struct AddAvailabilityZonesOutputDeserializer;
 impl AddAvailabilityZonesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AddAvailabilityZonesOutput, XmlParseError> {
@@ -230,6 +288,46 @@ pub struct AddTagsInput {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsInput {
+    /// Returns a builder for `AddTagsInput`, checking its required members when built.
+    pub fn builder() -> AddTagsInputBuilder {
+        AddTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsInput`](struct.AddTagsInput.html).
+#[derive(Default, Debug)]
+pub struct AddTagsInputBuilder {
+    load_balancer_names: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsInputBuilder {
+    /// Sets the [`load_balancer_names`](struct.AddTagsInput.html#structfield.load_balancer_names) member.
+    pub fn load_balancer_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.load_balancer_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddTagsInput, ::rusoto_core::BuildError> {
+        Ok(AddTagsInput {
+            load_balancer_names: self
+                .load_balancer_names
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_names"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `AddTagsInput` contents to a `SignedRequest`.
 struct AddTagsInputSerializer;
 impl AddTagsInputSerializer {
@@ -252,10 +350,10 @@ impl AddTagsInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AddTagsOutput {}
 
-struct AddTagsOutputDeserializer;
+pub(crate) struct AddTagsOutputDeserializer;
 impl AddTagsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AddTagsOutput, XmlParseError> {
@@ -277,10 +375,10 @@ pub struct AdditionalAttribute {
     pub value: Option<String>,
 }
 
-struct AdditionalAttributeDeserializer;
+pub(crate) struct AdditionalAttributeDeserializer;
 impl AdditionalAttributeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AdditionalAttribute, XmlParseError> {
@@ -321,10 +419,13 @@ impl AdditionalAttributeSerializer {
     }
 }
 
-struct AdditionalAttributeKeyDeserializer;
+pub(crate) struct AdditionalAttributeKeyDeserializer;
 impl AdditionalAttributeKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -332,10 +433,13 @@ impl AdditionalAttributeKeyDeserializer {
         Ok(obj)
     }
 }
-struct AdditionalAttributeValueDeserializer;
+pub(crate) struct AdditionalAttributeValueDeserializer;
 impl AdditionalAttributeValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -343,10 +447,10 @@ impl AdditionalAttributeValueDeserializer {
         Ok(obj)
     }
 }
-struct AdditionalAttributesDeserializer;
+pub(crate) struct AdditionalAttributesDeserializer;
 impl AdditionalAttributesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AdditionalAttribute>, XmlParseError> {
@@ -374,10 +478,10 @@ impl AdditionalAttributesSerializer {
     }
 }
 
-struct AppCookieStickinessPoliciesDeserializer;
+pub(crate) struct AppCookieStickinessPoliciesDeserializer;
 impl AppCookieStickinessPoliciesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AppCookieStickinessPolicy>, XmlParseError> {
@@ -402,10 +506,10 @@ pub struct AppCookieStickinessPolicy {
     pub policy_name: Option<String>,
 }
 
-struct AppCookieStickinessPolicyDeserializer;
+pub(crate) struct AppCookieStickinessPolicyDeserializer;
 impl AppCookieStickinessPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AppCookieStickinessPolicy, XmlParseError> {
@@ -438,6 +542,49 @@ pub struct ApplySecurityGroupsToLoadBalancerInput {
     pub security_groups: Vec<String>,
 }
 
+impl ApplySecurityGroupsToLoadBalancerInput {
+    /// Returns a builder for `ApplySecurityGroupsToLoadBalancerInput`, checking its required members when built.
+    pub fn builder() -> ApplySecurityGroupsToLoadBalancerInputBuilder {
+        ApplySecurityGroupsToLoadBalancerInputBuilder::default()
+    }
+}
+
+/// Builds a [`ApplySecurityGroupsToLoadBalancerInput`](struct.ApplySecurityGroupsToLoadBalancerInput.html).
+#[derive(Default, Debug)]
+pub struct ApplySecurityGroupsToLoadBalancerInputBuilder {
+    load_balancer_name: Option<String>,
+    security_groups: Option<Vec<String>>,
+}
+
+impl ApplySecurityGroupsToLoadBalancerInputBuilder {
+    /// Sets the [`load_balancer_name`](struct.ApplySecurityGroupsToLoadBalancerInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_groups`](struct.ApplySecurityGroupsToLoadBalancerInput.html#structfield.security_groups) member.
+    pub fn security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_groups = Some(value.into());
+        self
+    }
+
+    /// Builds the `ApplySecurityGroupsToLoadBalancerInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ApplySecurityGroupsToLoadBalancerInput, ::rusoto_core::BuildError>
+    {
+        Ok(ApplySecurityGroupsToLoadBalancerInput {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            security_groups: self
+                .security_groups
+                .ok_or(::rusoto_core::BuildError::missing("security_groups"))?,
+        })
+    }
+}
+
 /// Serialize `ApplySecurityGroupsToLoadBalancerInput` contents to a `SignedRequest`.
 struct ApplySecurityGroupsToLoadBalancerInputSerializer;
 impl ApplySecurityGroupsToLoadBalancerInputSerializer {
@@ -466,10 +613,10 @@ pub struct ApplySecurityGroupsToLoadBalancerOutput {
     pub security_groups: Option<Vec<String>>,
 }
 
-struct ApplySecurityGroupsToLoadBalancerOutputDeserializer;
+pub(crate) struct ApplySecurityGroupsToLoadBalancerOutputDeserializer;
 impl ApplySecurityGroupsToLoadBalancerOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplySecurityGroupsToLoadBalancerOutput, XmlParseError> {
@@ -499,6 +646,48 @@ pub struct AttachLoadBalancerToSubnetsInput {
     pub subnets: Vec<String>,
 }
 
+impl AttachLoadBalancerToSubnetsInput {
+    /// Returns a builder for `AttachLoadBalancerToSubnetsInput`, checking its required members when built.
+    pub fn builder() -> AttachLoadBalancerToSubnetsInputBuilder {
+        AttachLoadBalancerToSubnetsInputBuilder::default()
+    }
+}
+
+/// Builds a [`AttachLoadBalancerToSubnetsInput`](struct.AttachLoadBalancerToSubnetsInput.html).
+#[derive(Default, Debug)]
+pub struct AttachLoadBalancerToSubnetsInputBuilder {
+    load_balancer_name: Option<String>,
+    subnets: Option<Vec<String>>,
+}
+
+impl AttachLoadBalancerToSubnetsInputBuilder {
+    /// Sets the [`load_balancer_name`](struct.AttachLoadBalancerToSubnetsInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnets`](struct.AttachLoadBalancerToSubnetsInput.html#structfield.subnets) member.
+    pub fn subnets<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnets = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachLoadBalancerToSubnetsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachLoadBalancerToSubnetsInput, ::rusoto_core::BuildError> {
+        Ok(AttachLoadBalancerToSubnetsInput {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            subnets: self
+                .subnets
+                .ok_or(::rusoto_core::BuildError::missing("subnets"))?,
+        })
+    }
+}
+
 /// Serialize `AttachLoadBalancerToSubnetsInput` contents to a `SignedRequest`.
 struct AttachLoadBalancerToSubnetsInputSerializer;
 impl AttachLoadBalancerToSubnetsInputSerializer {
@@ -523,10 +712,10 @@ pub struct AttachLoadBalancerToSubnetsOutput {
     pub subnets: Option<Vec<String>>,
 }
 
-struct AttachLoadBalancerToSubnetsOutputDeserializer;
+pub(crate) struct AttachLoadBalancerToSubnetsOutputDeserializer;
 impl AttachLoadBalancerToSubnetsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AttachLoadBalancerToSubnetsOutput, XmlParseError> {
@@ -547,10 +736,13 @@ impl AttachLoadBalancerToSubnetsOutputDeserializer {
         )
     }
 }
-struct AttributeNameDeserializer;
+pub(crate) struct AttributeNameDeserializer;
 impl AttributeNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -558,10 +750,13 @@ impl AttributeNameDeserializer {
         Ok(obj)
     }
 }
-struct AttributeTypeDeserializer;
+pub(crate) struct AttributeTypeDeserializer;
 impl AttributeTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -569,10 +764,13 @@ impl AttributeTypeDeserializer {
         Ok(obj)
     }
 }
-struct AttributeValueDeserializer;
+pub(crate) struct AttributeValueDeserializer;
 impl AttributeValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -580,10 +778,13 @@ impl AttributeValueDeserializer {
         Ok(obj)
     }
 }
-struct AvailabilityZoneDeserializer;
+pub(crate) struct AvailabilityZoneDeserializer;
 impl AvailabilityZoneDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -591,10 +792,10 @@ impl AvailabilityZoneDeserializer {
         Ok(obj)
     }
 }
-struct AvailabilityZonesDeserializer;
+pub(crate) struct AvailabilityZonesDeserializer;
 impl AvailabilityZonesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -629,10 +830,10 @@ pub struct BackendServerDescription {
     pub policy_names: Option<Vec<String>>,
 }
 
-struct BackendServerDescriptionDeserializer;
+pub(crate) struct BackendServerDescriptionDeserializer;
 impl BackendServerDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<BackendServerDescription, XmlParseError> {
@@ -659,10 +860,10 @@ impl BackendServerDescriptionDeserializer {
         )
     }
 }
-struct BackendServerDescriptionsDeserializer;
+pub(crate) struct BackendServerDescriptionsDeserializer;
 impl BackendServerDescriptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<BackendServerDescription>, XmlParseError> {
@@ -678,10 +879,13 @@ impl BackendServerDescriptionsDeserializer {
         })
     }
 }
-struct CardinalityDeserializer;
+pub(crate) struct CardinalityDeserializer;
 impl CardinalityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -698,6 +902,48 @@ pub struct ConfigureHealthCheckInput {
     pub load_balancer_name: String,
 }
 
+impl ConfigureHealthCheckInput {
+    /// Returns a builder for `ConfigureHealthCheckInput`, checking its required members when built.
+    pub fn builder() -> ConfigureHealthCheckInputBuilder {
+        ConfigureHealthCheckInputBuilder::default()
+    }
+}
+
+/// Builds a [`ConfigureHealthCheckInput`](struct.ConfigureHealthCheckInput.html).
+#[derive(Default, Debug)]
+pub struct ConfigureHealthCheckInputBuilder {
+    health_check: Option<HealthCheck>,
+    load_balancer_name: Option<String>,
+}
+
+impl ConfigureHealthCheckInputBuilder {
+    /// Sets the [`health_check`](struct.ConfigureHealthCheckInput.html#structfield.health_check) member.
+    pub fn health_check<T: Into<HealthCheck>>(mut self, value: T) -> Self {
+        self.health_check = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.ConfigureHealthCheckInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConfigureHealthCheckInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ConfigureHealthCheckInput, ::rusoto_core::BuildError> {
+        Ok(ConfigureHealthCheckInput {
+            health_check: self
+                .health_check
+                .ok_or(::rusoto_core::BuildError::missing("health_check"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 /// Serialize `ConfigureHealthCheckInput` contents to a `SignedRequest`.
 struct ConfigureHealthCheckInputSerializer;
 impl ConfigureHealthCheckInputSerializer {
@@ -726,10 +972,10 @@ pub struct ConfigureHealthCheckOutput {
     pub health_check: Option<HealthCheck>,
 }
 
-struct ConfigureHealthCheckOutputDeserializer;
+pub(crate) struct ConfigureHealthCheckOutputDeserializer;
 impl ConfigureHealthCheckOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ConfigureHealthCheckOutput, XmlParseError> {
@@ -758,10 +1004,10 @@ pub struct ConnectionDraining {
     pub timeout: Option<i64>,
 }
 
-struct ConnectionDrainingDeserializer;
+pub(crate) struct ConnectionDrainingDeserializer;
 impl ConnectionDrainingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ConnectionDraining, XmlParseError> {
@@ -799,10 +1045,13 @@ impl ConnectionDrainingSerializer {
     }
 }
 
-struct ConnectionDrainingEnabledDeserializer;
+pub(crate) struct ConnectionDrainingEnabledDeserializer;
 impl ConnectionDrainingEnabledDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -810,10 +1059,13 @@ impl ConnectionDrainingEnabledDeserializer {
         Ok(obj)
     }
 }
-struct ConnectionDrainingTimeoutDeserializer;
+pub(crate) struct ConnectionDrainingTimeoutDeserializer;
 impl ConnectionDrainingTimeoutDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -828,10 +1080,10 @@ pub struct ConnectionSettings {
     pub idle_timeout: i64,
 }
 
-struct ConnectionSettingsDeserializer;
+pub(crate) struct ConnectionSettingsDeserializer;
 impl ConnectionSettingsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ConnectionSettings, XmlParseError> {
@@ -860,10 +1112,13 @@ impl ConnectionSettingsSerializer {
     }
 }
 
-struct CookieExpirationPeriodDeserializer;
+pub(crate) struct CookieExpirationPeriodDeserializer;
 impl CookieExpirationPeriodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -871,10 +1126,13 @@ impl CookieExpirationPeriodDeserializer {
         Ok(obj)
     }
 }
-struct CookieNameDeserializer;
+pub(crate) struct CookieNameDeserializer;
 impl CookieNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -901,6 +1159,86 @@ pub struct CreateAccessPointInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateAccessPointInput {
+    /// Returns a builder for `CreateAccessPointInput`, checking its required members when built.
+    pub fn builder() -> CreateAccessPointInputBuilder {
+        CreateAccessPointInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAccessPointInput`](struct.CreateAccessPointInput.html).
+#[derive(Default, Debug)]
+pub struct CreateAccessPointInputBuilder {
+    availability_zones: Option<Vec<String>>,
+    listeners: Option<Vec<Listener>>,
+    load_balancer_name: Option<String>,
+    scheme: Option<String>,
+    security_groups: Option<Vec<String>>,
+    subnets: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateAccessPointInputBuilder {
+    /// Sets the [`availability_zones`](struct.CreateAccessPointInput.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`listeners`](struct.CreateAccessPointInput.html#structfield.listeners) member.
+    pub fn listeners<T: Into<Vec<Listener>>>(mut self, value: T) -> Self {
+        self.listeners = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.CreateAccessPointInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheme`](struct.CreateAccessPointInput.html#structfield.scheme) member.
+    pub fn scheme<T: Into<String>>(mut self, value: T) -> Self {
+        self.scheme = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_groups`](struct.CreateAccessPointInput.html#structfield.security_groups) member.
+    pub fn security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnets`](struct.CreateAccessPointInput.html#structfield.subnets) member.
+    pub fn subnets<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateAccessPointInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAccessPointInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateAccessPointInput, ::rusoto_core::BuildError> {
+        Ok(CreateAccessPointInput {
+            availability_zones: self.availability_zones,
+            listeners: self
+                .listeners
+                .ok_or(::rusoto_core::BuildError::missing("listeners"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            scheme: self.scheme,
+            security_groups: self.security_groups,
+            subnets: self.subnets,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateAccessPointInput` contents to a `SignedRequest`.
 struct CreateAccessPointInputSerializer;
 impl CreateAccessPointInputSerializer {
@@ -952,10 +1290,10 @@ pub struct CreateAccessPointOutput {
     pub dns_name: Option<String>,
 }
 
-struct CreateAccessPointOutputDeserializer;
+pub(crate) struct CreateAccessPointOutputDeserializer;
 impl CreateAccessPointOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateAccessPointOutput, XmlParseError> {
@@ -985,6 +1323,59 @@ pub struct CreateAppCookieStickinessPolicyInput {
     pub policy_name: String,
 }
 
+impl CreateAppCookieStickinessPolicyInput {
+    /// Returns a builder for `CreateAppCookieStickinessPolicyInput`, checking its required members when built.
+    pub fn builder() -> CreateAppCookieStickinessPolicyInputBuilder {
+        CreateAppCookieStickinessPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAppCookieStickinessPolicyInput`](struct.CreateAppCookieStickinessPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct CreateAppCookieStickinessPolicyInputBuilder {
+    cookie_name: Option<String>,
+    load_balancer_name: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl CreateAppCookieStickinessPolicyInputBuilder {
+    /// Sets the [`cookie_name`](struct.CreateAppCookieStickinessPolicyInput.html#structfield.cookie_name) member.
+    pub fn cookie_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cookie_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.CreateAppCookieStickinessPolicyInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.CreateAppCookieStickinessPolicyInput.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAppCookieStickinessPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateAppCookieStickinessPolicyInput, ::rusoto_core::BuildError>
+    {
+        Ok(CreateAppCookieStickinessPolicyInput {
+            cookie_name: self
+                .cookie_name
+                .ok_or(::rusoto_core::BuildError::missing("cookie_name"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateAppCookieStickinessPolicyInput` contents to a `SignedRequest`.
 struct CreateAppCookieStickinessPolicyInputSerializer;
 impl CreateAppCookieStickinessPolicyInputSerializer {
@@ -1007,10 +1398,10 @@ impl CreateAppCookieStickinessPolicyInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateAppCookieStickinessPolicyOutput {}
 
-struct CreateAppCookieStickinessPolicyOutputDeserializer;
+pub(crate) struct CreateAppCookieStickinessPolicyOutputDeserializer;
 impl CreateAppCookieStickinessPolicyOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateAppCookieStickinessPolicyOutput, XmlParseError> {
@@ -1034,6 +1425,56 @@ pub struct CreateLBCookieStickinessPolicyInput {
     pub policy_name: String,
 }
 
+impl CreateLBCookieStickinessPolicyInput {
+    /// Returns a builder for `CreateLBCookieStickinessPolicyInput`, checking its required members when built.
+    pub fn builder() -> CreateLBCookieStickinessPolicyInputBuilder {
+        CreateLBCookieStickinessPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLBCookieStickinessPolicyInput`](struct.CreateLBCookieStickinessPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct CreateLBCookieStickinessPolicyInputBuilder {
+    cookie_expiration_period: Option<i64>,
+    load_balancer_name: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl CreateLBCookieStickinessPolicyInputBuilder {
+    /// Sets the [`cookie_expiration_period`](struct.CreateLBCookieStickinessPolicyInput.html#structfield.cookie_expiration_period) member.
+    pub fn cookie_expiration_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.cookie_expiration_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.CreateLBCookieStickinessPolicyInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.CreateLBCookieStickinessPolicyInput.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLBCookieStickinessPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLBCookieStickinessPolicyInput, ::rusoto_core::BuildError> {
+        Ok(CreateLBCookieStickinessPolicyInput {
+            cookie_expiration_period: self.cookie_expiration_period,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateLBCookieStickinessPolicyInput` contents to a `SignedRequest`.
 struct CreateLBCookieStickinessPolicyInputSerializer;
 impl CreateLBCookieStickinessPolicyInputSerializer {
@@ -1061,10 +1502,10 @@ impl CreateLBCookieStickinessPolicyInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateLBCookieStickinessPolicyOutput {}
 
-struct CreateLBCookieStickinessPolicyOutputDeserializer;
+pub(crate) struct CreateLBCookieStickinessPolicyOutputDeserializer;
 impl CreateLBCookieStickinessPolicyOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateLBCookieStickinessPolicyOutput, XmlParseError> {
@@ -1086,6 +1527,48 @@ pub struct CreateLoadBalancerListenerInput {
     pub load_balancer_name: String,
 }
 
+impl CreateLoadBalancerListenerInput {
+    /// Returns a builder for `CreateLoadBalancerListenerInput`, checking its required members when built.
+    pub fn builder() -> CreateLoadBalancerListenerInputBuilder {
+        CreateLoadBalancerListenerInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLoadBalancerListenerInput`](struct.CreateLoadBalancerListenerInput.html).
+#[derive(Default, Debug)]
+pub struct CreateLoadBalancerListenerInputBuilder {
+    listeners: Option<Vec<Listener>>,
+    load_balancer_name: Option<String>,
+}
+
+impl CreateLoadBalancerListenerInputBuilder {
+    /// Sets the [`listeners`](struct.CreateLoadBalancerListenerInput.html#structfield.listeners) member.
+    pub fn listeners<T: Into<Vec<Listener>>>(mut self, value: T) -> Self {
+        self.listeners = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.CreateLoadBalancerListenerInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLoadBalancerListenerInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLoadBalancerListenerInput, ::rusoto_core::BuildError> {
+        Ok(CreateLoadBalancerListenerInput {
+            listeners: self
+                .listeners
+                .ok_or(::rusoto_core::BuildError::missing("listeners"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateLoadBalancerListenerInput` contents to a `SignedRequest`.
 struct CreateLoadBalancerListenerInputSerializer;
 impl CreateLoadBalancerListenerInputSerializer {
@@ -1111,10 +1594,10 @@ impl CreateLoadBalancerListenerInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateLoadBalancerListenerOutput {}
 
-struct CreateLoadBalancerListenerOutputDeserializer;
+pub(crate) struct CreateLoadBalancerListenerOutputDeserializer;
 impl CreateLoadBalancerListenerOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateLoadBalancerListenerOutput, XmlParseError> {
@@ -1140,6 +1623,66 @@ pub struct CreateLoadBalancerPolicyInput {
     pub policy_type_name: String,
 }
 
+impl CreateLoadBalancerPolicyInput {
+    /// Returns a builder for `CreateLoadBalancerPolicyInput`, checking its required members when built.
+    pub fn builder() -> CreateLoadBalancerPolicyInputBuilder {
+        CreateLoadBalancerPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLoadBalancerPolicyInput`](struct.CreateLoadBalancerPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct CreateLoadBalancerPolicyInputBuilder {
+    load_balancer_name: Option<String>,
+    policy_attributes: Option<Vec<PolicyAttribute>>,
+    policy_name: Option<String>,
+    policy_type_name: Option<String>,
+}
+
+impl CreateLoadBalancerPolicyInputBuilder {
+    /// Sets the [`load_balancer_name`](struct.CreateLoadBalancerPolicyInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_attributes`](struct.CreateLoadBalancerPolicyInput.html#structfield.policy_attributes) member.
+    pub fn policy_attributes<T: Into<Vec<PolicyAttribute>>>(mut self, value: T) -> Self {
+        self.policy_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.CreateLoadBalancerPolicyInput.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_type_name`](struct.CreateLoadBalancerPolicyInput.html#structfield.policy_type_name) member.
+    pub fn policy_type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLoadBalancerPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLoadBalancerPolicyInput, ::rusoto_core::BuildError> {
+        Ok(CreateLoadBalancerPolicyInput {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            policy_attributes: self.policy_attributes,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            policy_type_name: self
+                .policy_type_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_type_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateLoadBalancerPolicyInput` contents to a `SignedRequest`.
 struct CreateLoadBalancerPolicyInputSerializer;
 impl CreateLoadBalancerPolicyInputSerializer {
@@ -1172,10 +1715,10 @@ impl CreateLoadBalancerPolicyInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateLoadBalancerPolicyOutput {}
 
-struct CreateLoadBalancerPolicyOutputDeserializer;
+pub(crate) struct CreateLoadBalancerPolicyOutputDeserializer;
 impl CreateLoadBalancerPolicyOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateLoadBalancerPolicyOutput, XmlParseError> {
@@ -1188,12 +1731,15 @@ impl CreateLoadBalancerPolicyOutputDeserializer {
         Ok(obj)
     }
 }
-struct CreatedTimeDeserializer;
+pub(crate) struct CreatedTimeDeserializer;
 impl CreatedTimeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1206,10 +1752,10 @@ pub struct CrossZoneLoadBalancing {
     pub enabled: bool,
 }
 
-struct CrossZoneLoadBalancingDeserializer;
+pub(crate) struct CrossZoneLoadBalancingDeserializer;
 impl CrossZoneLoadBalancingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CrossZoneLoadBalancing, XmlParseError> {
@@ -1239,10 +1785,13 @@ impl CrossZoneLoadBalancingSerializer {
     }
 }
 
-struct CrossZoneLoadBalancingEnabledDeserializer;
+pub(crate) struct CrossZoneLoadBalancingEnabledDeserializer;
 impl CrossZoneLoadBalancingEnabledDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1250,10 +1799,13 @@ impl CrossZoneLoadBalancingEnabledDeserializer {
         Ok(obj)
     }
 }
-struct DNSNameDeserializer;
+pub(crate) struct DNSNameDeserializer;
 impl DNSNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1261,10 +1813,13 @@ impl DNSNameDeserializer {
         Ok(obj)
     }
 }
-struct DefaultValueDeserializer;
+pub(crate) struct DefaultValueDeserializer;
 impl DefaultValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1279,6 +1834,36 @@ pub struct DeleteAccessPointInput {
     pub load_balancer_name: String,
 }
 
+impl DeleteAccessPointInput {
+    /// Returns a builder for `DeleteAccessPointInput`, checking its required members when built.
+    pub fn builder() -> DeleteAccessPointInputBuilder {
+        DeleteAccessPointInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAccessPointInput`](struct.DeleteAccessPointInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteAccessPointInputBuilder {
+    load_balancer_name: Option<String>,
+}
+
+impl DeleteAccessPointInputBuilder {
+    /// Sets the [`load_balancer_name`](struct.DeleteAccessPointInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAccessPointInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteAccessPointInput, ::rusoto_core::BuildError> {
+        Ok(DeleteAccessPointInput {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteAccessPointInput` contents to a `SignedRequest`.
 struct DeleteAccessPointInputSerializer;
 impl DeleteAccessPointInputSerializer {
@@ -1299,10 +1884,10 @@ impl DeleteAccessPointInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteAccessPointOutput {}
 
-struct DeleteAccessPointOutputDeserializer;
+pub(crate) struct DeleteAccessPointOutputDeserializer;
 impl DeleteAccessPointOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteAccessPointOutput, XmlParseError> {
@@ -1324,6 +1909,48 @@ pub struct DeleteLoadBalancerListenerInput {
     pub load_balancer_ports: Vec<i64>,
 }
 
+impl DeleteLoadBalancerListenerInput {
+    /// Returns a builder for `DeleteLoadBalancerListenerInput`, checking its required members when built.
+    pub fn builder() -> DeleteLoadBalancerListenerInputBuilder {
+        DeleteLoadBalancerListenerInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLoadBalancerListenerInput`](struct.DeleteLoadBalancerListenerInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteLoadBalancerListenerInputBuilder {
+    load_balancer_name: Option<String>,
+    load_balancer_ports: Option<Vec<i64>>,
+}
+
+impl DeleteLoadBalancerListenerInputBuilder {
+    /// Sets the [`load_balancer_name`](struct.DeleteLoadBalancerListenerInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_ports`](struct.DeleteLoadBalancerListenerInput.html#structfield.load_balancer_ports) member.
+    pub fn load_balancer_ports<T: Into<Vec<i64>>>(mut self, value: T) -> Self {
+        self.load_balancer_ports = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLoadBalancerListenerInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLoadBalancerListenerInput, ::rusoto_core::BuildError> {
+        Ok(DeleteLoadBalancerListenerInput {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            load_balancer_ports: self
+                .load_balancer_ports
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_ports"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteLoadBalancerListenerInput` contents to a `SignedRequest`.
 struct DeleteLoadBalancerListenerInputSerializer;
 impl DeleteLoadBalancerListenerInputSerializer {
@@ -1349,10 +1976,10 @@ impl DeleteLoadBalancerListenerInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteLoadBalancerListenerOutput {}
 
-struct DeleteLoadBalancerListenerOutputDeserializer;
+pub(crate) struct DeleteLoadBalancerListenerOutputDeserializer;
 impl DeleteLoadBalancerListenerOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteLoadBalancerListenerOutput, XmlParseError> {
@@ -1374,6 +2001,48 @@ pub struct DeleteLoadBalancerPolicyInput {
     pub policy_name: String,
 }
 
+impl DeleteLoadBalancerPolicyInput {
+    /// Returns a builder for `DeleteLoadBalancerPolicyInput`, checking its required members when built.
+    pub fn builder() -> DeleteLoadBalancerPolicyInputBuilder {
+        DeleteLoadBalancerPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLoadBalancerPolicyInput`](struct.DeleteLoadBalancerPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteLoadBalancerPolicyInputBuilder {
+    load_balancer_name: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl DeleteLoadBalancerPolicyInputBuilder {
+    /// Sets the [`load_balancer_name`](struct.DeleteLoadBalancerPolicyInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.DeleteLoadBalancerPolicyInput.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLoadBalancerPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLoadBalancerPolicyInput, ::rusoto_core::BuildError> {
+        Ok(DeleteLoadBalancerPolicyInput {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteLoadBalancerPolicyInput` contents to a `SignedRequest`.
 struct DeleteLoadBalancerPolicyInputSerializer;
 impl DeleteLoadBalancerPolicyInputSerializer {
@@ -1395,10 +2064,10 @@ impl DeleteLoadBalancerPolicyInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteLoadBalancerPolicyOutput {}
 
-struct DeleteLoadBalancerPolicyOutputDeserializer;
+pub(crate) struct DeleteLoadBalancerPolicyOutputDeserializer;
 impl DeleteLoadBalancerPolicyOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteLoadBalancerPolicyOutput, XmlParseError> {
@@ -1420,6 +2089,48 @@ pub struct DeregisterEndPointsInput {
     pub load_balancer_name: String,
 }
 
+impl DeregisterEndPointsInput {
+    /// Returns a builder for `DeregisterEndPointsInput`, checking its required members when built.
+    pub fn builder() -> DeregisterEndPointsInputBuilder {
+        DeregisterEndPointsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterEndPointsInput`](struct.DeregisterEndPointsInput.html).
+#[derive(Default, Debug)]
+pub struct DeregisterEndPointsInputBuilder {
+    instances: Option<Vec<Instance>>,
+    load_balancer_name: Option<String>,
+}
+
+impl DeregisterEndPointsInputBuilder {
+    /// Sets the [`instances`](struct.DeregisterEndPointsInput.html#structfield.instances) member.
+    pub fn instances<T: Into<Vec<Instance>>>(mut self, value: T) -> Self {
+        self.instances = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.DeregisterEndPointsInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterEndPointsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterEndPointsInput, ::rusoto_core::BuildError> {
+        Ok(DeregisterEndPointsInput {
+            instances: self
+                .instances
+                .ok_or(::rusoto_core::BuildError::missing("instances"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeregisterEndPointsInput` contents to a `SignedRequest`.
 struct DeregisterEndPointsInputSerializer;
 impl DeregisterEndPointsInputSerializer {
@@ -1448,10 +2159,10 @@ pub struct DeregisterEndPointsOutput {
     pub instances: Option<Vec<Instance>>,
 }
 
-struct DeregisterEndPointsOutputDeserializer;
+pub(crate) struct DeregisterEndPointsOutputDeserializer;
 impl DeregisterEndPointsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeregisterEndPointsOutput, XmlParseError> {
@@ -1483,6 +2194,52 @@ pub struct DescribeAccessPointsInput {
     pub page_size: Option<i64>,
 }
 
+impl DescribeAccessPointsInput {
+    /// Returns a builder for `DescribeAccessPointsInput`, checking its required members when built.
+    pub fn builder() -> DescribeAccessPointsInputBuilder {
+        DescribeAccessPointsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAccessPointsInput`](struct.DescribeAccessPointsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeAccessPointsInputBuilder {
+    load_balancer_names: Option<Vec<String>>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+}
+
+impl DescribeAccessPointsInputBuilder {
+    /// Sets the [`load_balancer_names`](struct.DescribeAccessPointsInput.html#structfield.load_balancer_names) member.
+    pub fn load_balancer_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.load_balancer_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeAccessPointsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.DescribeAccessPointsInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAccessPointsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAccessPointsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeAccessPointsInput {
+            load_balancer_names: self.load_balancer_names,
+            marker: self.marker,
+            page_size: self.page_size,
+        })
+    }
+}
+
 /// Serialize `DescribeAccessPointsInput` contents to a `SignedRequest`.
 struct DescribeAccessPointsInputSerializer;
 impl DescribeAccessPointsInputSerializer {
@@ -1517,10 +2274,10 @@ pub struct DescribeAccessPointsOutput {
     pub next_marker: Option<String>,
 }
 
-struct DescribeAccessPointsOutputDeserializer;
+pub(crate) struct DescribeAccessPointsOutputDeserializer;
 impl DescribeAccessPointsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAccessPointsOutput, XmlParseError> {
@@ -1556,6 +2313,44 @@ pub struct DescribeAccountLimitsInput {
     pub page_size: Option<i64>,
 }
 
+impl DescribeAccountLimitsInput {
+    /// Returns a builder for `DescribeAccountLimitsInput`, checking its required members when built.
+    pub fn builder() -> DescribeAccountLimitsInputBuilder {
+        DescribeAccountLimitsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAccountLimitsInput`](struct.DescribeAccountLimitsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeAccountLimitsInputBuilder {
+    marker: Option<String>,
+    page_size: Option<i64>,
+}
+
+impl DescribeAccountLimitsInputBuilder {
+    /// Sets the [`marker`](struct.DescribeAccountLimitsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.DescribeAccountLimitsInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAccountLimitsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAccountLimitsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeAccountLimitsInput {
+            marker: self.marker,
+            page_size: self.page_size,
+        })
+    }
+}
+
 /// Serialize `DescribeAccountLimitsInput` contents to a `SignedRequest`.
 struct DescribeAccountLimitsInputSerializer;
 impl DescribeAccountLimitsInputSerializer {
@@ -1582,10 +2377,10 @@ pub struct DescribeAccountLimitsOutput {
     pub next_marker: Option<String>,
 }
 
-struct DescribeAccountLimitsOutputDeserializer;
+pub(crate) struct DescribeAccountLimitsOutputDeserializer;
 impl DescribeAccountLimitsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAccountLimitsOutput, XmlParseError> {
@@ -1619,6 +2414,46 @@ pub struct DescribeEndPointStateInput {
     pub load_balancer_name: String,
 }
 
+impl DescribeEndPointStateInput {
+    /// Returns a builder for `DescribeEndPointStateInput`, checking its required members when built.
+    pub fn builder() -> DescribeEndPointStateInputBuilder {
+        DescribeEndPointStateInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEndPointStateInput`](struct.DescribeEndPointStateInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeEndPointStateInputBuilder {
+    instances: Option<Vec<Instance>>,
+    load_balancer_name: Option<String>,
+}
+
+impl DescribeEndPointStateInputBuilder {
+    /// Sets the [`instances`](struct.DescribeEndPointStateInput.html#structfield.instances) member.
+    pub fn instances<T: Into<Vec<Instance>>>(mut self, value: T) -> Self {
+        self.instances = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.DescribeEndPointStateInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEndPointStateInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEndPointStateInput, ::rusoto_core::BuildError> {
+        Ok(DescribeEndPointStateInput {
+            instances: self.instances,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeEndPointStateInput` contents to a `SignedRequest`.
 struct DescribeEndPointStateInputSerializer;
 impl DescribeEndPointStateInputSerializer {
@@ -1649,10 +2484,10 @@ pub struct DescribeEndPointStateOutput {
     pub instance_states: Option<Vec<InstanceState>>,
 }
 
-struct DescribeEndPointStateOutputDeserializer;
+pub(crate) struct DescribeEndPointStateOutputDeserializer;
 impl DescribeEndPointStateOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeEndPointStateOutput, XmlParseError> {
@@ -1680,6 +2515,38 @@ pub struct DescribeLoadBalancerAttributesInput {
     pub load_balancer_name: String,
 }
 
+impl DescribeLoadBalancerAttributesInput {
+    /// Returns a builder for `DescribeLoadBalancerAttributesInput`, checking its required members when built.
+    pub fn builder() -> DescribeLoadBalancerAttributesInputBuilder {
+        DescribeLoadBalancerAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLoadBalancerAttributesInput`](struct.DescribeLoadBalancerAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeLoadBalancerAttributesInputBuilder {
+    load_balancer_name: Option<String>,
+}
+
+impl DescribeLoadBalancerAttributesInputBuilder {
+    /// Sets the [`load_balancer_name`](struct.DescribeLoadBalancerAttributesInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLoadBalancerAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLoadBalancerAttributesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeLoadBalancerAttributesInput {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeLoadBalancerAttributesInput` contents to a `SignedRequest`.
 struct DescribeLoadBalancerAttributesInputSerializer;
 impl DescribeLoadBalancerAttributesInputSerializer {
@@ -1703,10 +2570,10 @@ pub struct DescribeLoadBalancerAttributesOutput {
     pub load_balancer_attributes: Option<LoadBalancerAttributes>,
 }
 
-struct DescribeLoadBalancerAttributesOutputDeserializer;
+pub(crate) struct DescribeLoadBalancerAttributesOutputDeserializer;
 impl DescribeLoadBalancerAttributesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeLoadBalancerAttributesOutput, XmlParseError> {
@@ -1738,6 +2605,44 @@ pub struct DescribeLoadBalancerPoliciesInput {
     pub policy_names: Option<Vec<String>>,
 }
 
+impl DescribeLoadBalancerPoliciesInput {
+    /// Returns a builder for `DescribeLoadBalancerPoliciesInput`, checking its required members when built.
+    pub fn builder() -> DescribeLoadBalancerPoliciesInputBuilder {
+        DescribeLoadBalancerPoliciesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLoadBalancerPoliciesInput`](struct.DescribeLoadBalancerPoliciesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeLoadBalancerPoliciesInputBuilder {
+    load_balancer_name: Option<String>,
+    policy_names: Option<Vec<String>>,
+}
+
+impl DescribeLoadBalancerPoliciesInputBuilder {
+    /// Sets the [`load_balancer_name`](struct.DescribeLoadBalancerPoliciesInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_names`](struct.DescribeLoadBalancerPoliciesInput.html#structfield.policy_names) member.
+    pub fn policy_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLoadBalancerPoliciesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLoadBalancerPoliciesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeLoadBalancerPoliciesInput {
+            load_balancer_name: self.load_balancer_name,
+            policy_names: self.policy_names,
+        })
+    }
+}
+
 /// Serialize `DescribeLoadBalancerPoliciesInput` contents to a `SignedRequest`.
 struct DescribeLoadBalancerPoliciesInputSerializer;
 impl DescribeLoadBalancerPoliciesInputSerializer {
@@ -1767,10 +2672,10 @@ pub struct DescribeLoadBalancerPoliciesOutput {
     pub policy_descriptions: Option<Vec<PolicyDescription>>,
 }
 
-struct DescribeLoadBalancerPoliciesOutputDeserializer;
+pub(crate) struct DescribeLoadBalancerPoliciesOutputDeserializer;
 impl DescribeLoadBalancerPoliciesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeLoadBalancerPoliciesOutput, XmlParseError> {
@@ -1801,6 +2706,37 @@ pub struct DescribeLoadBalancerPolicyTypesInput {
     pub policy_type_names: Option<Vec<String>>,
 }
 
+impl DescribeLoadBalancerPolicyTypesInput {
+    /// Returns a builder for `DescribeLoadBalancerPolicyTypesInput`, checking its required members when built.
+    pub fn builder() -> DescribeLoadBalancerPolicyTypesInputBuilder {
+        DescribeLoadBalancerPolicyTypesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLoadBalancerPolicyTypesInput`](struct.DescribeLoadBalancerPolicyTypesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeLoadBalancerPolicyTypesInputBuilder {
+    policy_type_names: Option<Vec<String>>,
+}
+
+impl DescribeLoadBalancerPolicyTypesInputBuilder {
+    /// Sets the [`policy_type_names`](struct.DescribeLoadBalancerPolicyTypesInput.html#structfield.policy_type_names) member.
+    pub fn policy_type_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_type_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLoadBalancerPolicyTypesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLoadBalancerPolicyTypesInput, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeLoadBalancerPolicyTypesInput {
+            policy_type_names: self.policy_type_names,
+        })
+    }
+}
+
 /// Serialize `DescribeLoadBalancerPolicyTypesInput` contents to a `SignedRequest`.
 struct DescribeLoadBalancerPolicyTypesInputSerializer;
 impl DescribeLoadBalancerPolicyTypesInputSerializer {
@@ -1827,10 +2763,10 @@ pub struct DescribeLoadBalancerPolicyTypesOutput {
     pub policy_type_descriptions: Option<Vec<PolicyTypeDescription>>,
 }
 
-struct DescribeLoadBalancerPolicyTypesOutputDeserializer;
+pub(crate) struct DescribeLoadBalancerPolicyTypesOutputDeserializer;
 impl DescribeLoadBalancerPolicyTypesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeLoadBalancerPolicyTypesOutput, XmlParseError> {
@@ -1861,6 +2797,36 @@ pub struct DescribeTagsInput {
     pub load_balancer_names: Vec<String>,
 }
 
+impl DescribeTagsInput {
+    /// Returns a builder for `DescribeTagsInput`, checking its required members when built.
+    pub fn builder() -> DescribeTagsInputBuilder {
+        DescribeTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTagsInput`](struct.DescribeTagsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeTagsInputBuilder {
+    load_balancer_names: Option<Vec<String>>,
+}
+
+impl DescribeTagsInputBuilder {
+    /// Sets the [`load_balancer_names`](struct.DescribeTagsInput.html#structfield.load_balancer_names) member.
+    pub fn load_balancer_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.load_balancer_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTagsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeTagsInput {
+            load_balancer_names: self
+                .load_balancer_names
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_names"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeTagsInput` contents to a `SignedRequest`.
 struct DescribeTagsInputSerializer;
 impl DescribeTagsInputSerializer {
@@ -1885,10 +2851,10 @@ pub struct DescribeTagsOutput {
     pub tag_descriptions: Option<Vec<TagDescription>>,
 }
 
-struct DescribeTagsOutputDeserializer;
+pub(crate) struct DescribeTagsOutputDeserializer;
 impl DescribeTagsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeTagsOutput, XmlParseError> {
@@ -1905,10 +2871,13 @@ impl DescribeTagsOutputDeserializer {
         })
     }
 }
-struct DescriptionDeserializer;
+pub(crate) struct DescriptionDeserializer;
 impl DescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1925,6 +2894,48 @@ pub struct DetachLoadBalancerFromSubnetsInput {
     pub subnets: Vec<String>,
 }
 
+impl DetachLoadBalancerFromSubnetsInput {
+    /// Returns a builder for `DetachLoadBalancerFromSubnetsInput`, checking its required members when built.
+    pub fn builder() -> DetachLoadBalancerFromSubnetsInputBuilder {
+        DetachLoadBalancerFromSubnetsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DetachLoadBalancerFromSubnetsInput`](struct.DetachLoadBalancerFromSubnetsInput.html).
+#[derive(Default, Debug)]
+pub struct DetachLoadBalancerFromSubnetsInputBuilder {
+    load_balancer_name: Option<String>,
+    subnets: Option<Vec<String>>,
+}
+
+impl DetachLoadBalancerFromSubnetsInputBuilder {
+    /// Sets the [`load_balancer_name`](struct.DetachLoadBalancerFromSubnetsInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnets`](struct.DetachLoadBalancerFromSubnetsInput.html#structfield.subnets) member.
+    pub fn subnets<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnets = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachLoadBalancerFromSubnetsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetachLoadBalancerFromSubnetsInput, ::rusoto_core::BuildError> {
+        Ok(DetachLoadBalancerFromSubnetsInput {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            subnets: self
+                .subnets
+                .ok_or(::rusoto_core::BuildError::missing("subnets"))?,
+        })
+    }
+}
+
 /// Serialize `DetachLoadBalancerFromSubnetsInput` contents to a `SignedRequest`.
 struct DetachLoadBalancerFromSubnetsInputSerializer;
 impl DetachLoadBalancerFromSubnetsInputSerializer {
@@ -1949,10 +2960,10 @@ pub struct DetachLoadBalancerFromSubnetsOutput {
     pub subnets: Option<Vec<String>>,
 }
 
-struct DetachLoadBalancerFromSubnetsOutputDeserializer;
+pub(crate) struct DetachLoadBalancerFromSubnetsOutputDeserializer;
 impl DetachLoadBalancerFromSubnetsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DetachLoadBalancerFromSubnetsOutput, XmlParseError> {
@@ -1988,10 +2999,10 @@ pub struct HealthCheck {
     pub unhealthy_threshold: i64,
 }
 
-struct HealthCheckDeserializer;
+pub(crate) struct HealthCheckDeserializer;
 impl HealthCheckDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HealthCheck, XmlParseError> {
@@ -2044,10 +3055,13 @@ impl HealthCheckSerializer {
     }
 }
 
-struct HealthCheckIntervalDeserializer;
+pub(crate) struct HealthCheckIntervalDeserializer;
 impl HealthCheckIntervalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2055,10 +3069,13 @@ impl HealthCheckIntervalDeserializer {
         Ok(obj)
     }
 }
-struct HealthCheckTargetDeserializer;
+pub(crate) struct HealthCheckTargetDeserializer;
 impl HealthCheckTargetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2066,10 +3083,13 @@ impl HealthCheckTargetDeserializer {
         Ok(obj)
     }
 }
-struct HealthCheckTimeoutDeserializer;
+pub(crate) struct HealthCheckTimeoutDeserializer;
 impl HealthCheckTimeoutDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2077,10 +3097,13 @@ impl HealthCheckTimeoutDeserializer {
         Ok(obj)
     }
 }
-struct HealthyThresholdDeserializer;
+pub(crate) struct HealthyThresholdDeserializer;
 impl HealthyThresholdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2088,10 +3111,13 @@ impl HealthyThresholdDeserializer {
         Ok(obj)
     }
 }
-struct IdleTimeoutDeserializer;
+pub(crate) struct IdleTimeoutDeserializer;
 impl IdleTimeoutDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2106,10 +3132,10 @@ pub struct Instance {
     pub instance_id: Option<String>,
 }
 
-struct InstanceDeserializer;
+pub(crate) struct InstanceDeserializer;
 impl InstanceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Instance, XmlParseError> {
@@ -2141,10 +3167,13 @@ impl InstanceSerializer {
     }
 }
 
-struct InstanceIdDeserializer;
+pub(crate) struct InstanceIdDeserializer;
 impl InstanceIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2152,10 +3181,13 @@ impl InstanceIdDeserializer {
         Ok(obj)
     }
 }
-struct InstancePortDeserializer;
+pub(crate) struct InstancePortDeserializer;
 impl InstancePortDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2176,10 +3208,10 @@ pub struct InstanceState {
     pub state: Option<String>,
 }
 
-struct InstanceStateDeserializer;
+pub(crate) struct InstanceStateDeserializer;
 impl InstanceStateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<InstanceState, XmlParseError> {
@@ -2206,10 +3238,10 @@ impl InstanceStateDeserializer {
         })
     }
 }
-struct InstanceStatesDeserializer;
+pub(crate) struct InstanceStatesDeserializer;
 impl InstanceStatesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<InstanceState>, XmlParseError> {
@@ -2223,10 +3255,10 @@ impl InstanceStatesDeserializer {
         })
     }
 }
-struct InstancesDeserializer;
+pub(crate) struct InstancesDeserializer;
 impl InstancesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Instance>, XmlParseError> {
@@ -2252,10 +3284,10 @@ impl InstancesSerializer {
     }
 }
 
-struct LBCookieStickinessPoliciesDeserializer;
+pub(crate) struct LBCookieStickinessPoliciesDeserializer;
 impl LBCookieStickinessPoliciesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LBCookieStickinessPolicy>, XmlParseError> {
@@ -2280,10 +3312,10 @@ pub struct LBCookieStickinessPolicy {
     pub policy_name: Option<String>,
 }
 
-struct LBCookieStickinessPolicyDeserializer;
+pub(crate) struct LBCookieStickinessPolicyDeserializer;
 impl LBCookieStickinessPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LBCookieStickinessPolicy, XmlParseError> {
@@ -2319,10 +3351,13 @@ pub struct Limit {
     pub name: Option<String>,
 }
 
-struct LimitDeserializer;
+pub(crate) struct LimitDeserializer;
 impl LimitDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Limit, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Limit, XmlParseError> {
         deserialize_elements::<_, Limit, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Max" => {
@@ -2337,10 +3372,10 @@ impl LimitDeserializer {
         })
     }
 }
-struct LimitsDeserializer;
+pub(crate) struct LimitsDeserializer;
 impl LimitsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Limit>, XmlParseError> {
@@ -2369,10 +3404,10 @@ pub struct Listener {
     pub ssl_certificate_id: Option<String>,
 }
 
-struct ListenerDeserializer;
+pub(crate) struct ListenerDeserializer;
 impl ListenerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Listener, XmlParseError> {
@@ -2441,10 +3476,10 @@ pub struct ListenerDescription {
     pub policy_names: Option<Vec<String>>,
 }
 
-struct ListenerDescriptionDeserializer;
+pub(crate) struct ListenerDescriptionDeserializer;
 impl ListenerDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListenerDescription, XmlParseError> {
@@ -2464,10 +3499,10 @@ impl ListenerDescriptionDeserializer {
         })
     }
 }
-struct ListenerDescriptionsDeserializer;
+pub(crate) struct ListenerDescriptionsDeserializer;
 impl ListenerDescriptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ListenerDescription>, XmlParseError> {
@@ -2510,10 +3545,10 @@ pub struct LoadBalancerAttributes {
     pub cross_zone_load_balancing: Option<CrossZoneLoadBalancing>,
 }
 
-struct LoadBalancerAttributesDeserializer;
+pub(crate) struct LoadBalancerAttributesDeserializer;
 impl LoadBalancerAttributesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoadBalancerAttributes, XmlParseError> {
@@ -2615,7 +3650,7 @@ pub struct LoadBalancerDescription {
     /// <p>The ID of the Amazon Route 53 hosted zone for the load balancer.</p>
     pub canonical_hosted_zone_name_id: Option<String>,
     /// <p>The date and time the load balancer was created.</p>
-    pub created_time: Option<String>,
+    pub created_time: Option<IsoTimestamp>,
     /// <p>The DNS name of the load balancer.</p>
     pub dns_name: Option<String>,
     /// <p>Information about the health checks conducted on the load balancer.</p>
@@ -2640,10 +3675,10 @@ pub struct LoadBalancerDescription {
     pub vpc_id: Option<String>,
 }
 
-struct LoadBalancerDescriptionDeserializer;
+pub(crate) struct LoadBalancerDescriptionDeserializer;
 impl LoadBalancerDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoadBalancerDescription, XmlParseError> {
@@ -2742,10 +3777,10 @@ impl LoadBalancerDescriptionDeserializer {
         )
     }
 }
-struct LoadBalancerDescriptionsDeserializer;
+pub(crate) struct LoadBalancerDescriptionsDeserializer;
 impl LoadBalancerDescriptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LoadBalancerDescription>, XmlParseError> {
@@ -2784,10 +3819,13 @@ impl LoadBalancerNamesMax20Serializer {
     }
 }
 
-struct LoadBalancerSchemeDeserializer;
+pub(crate) struct LoadBalancerSchemeDeserializer;
 impl LoadBalancerSchemeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2795,10 +3833,13 @@ impl LoadBalancerSchemeDeserializer {
         Ok(obj)
     }
 }
-struct MarkerDeserializer;
+pub(crate) struct MarkerDeserializer;
 impl MarkerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2806,10 +3847,13 @@ impl MarkerDeserializer {
         Ok(obj)
     }
 }
-struct MaxDeserializer;
+pub(crate) struct MaxDeserializer;
 impl MaxDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2826,6 +3870,48 @@ pub struct ModifyLoadBalancerAttributesInput {
     pub load_balancer_name: String,
 }
 
+impl ModifyLoadBalancerAttributesInput {
+    /// Returns a builder for `ModifyLoadBalancerAttributesInput`, checking its required members when built.
+    pub fn builder() -> ModifyLoadBalancerAttributesInputBuilder {
+        ModifyLoadBalancerAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyLoadBalancerAttributesInput`](struct.ModifyLoadBalancerAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct ModifyLoadBalancerAttributesInputBuilder {
+    load_balancer_attributes: Option<LoadBalancerAttributes>,
+    load_balancer_name: Option<String>,
+}
+
+impl ModifyLoadBalancerAttributesInputBuilder {
+    /// Sets the [`load_balancer_attributes`](struct.ModifyLoadBalancerAttributesInput.html#structfield.load_balancer_attributes) member.
+    pub fn load_balancer_attributes<T: Into<LoadBalancerAttributes>>(mut self, value: T) -> Self {
+        self.load_balancer_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.ModifyLoadBalancerAttributesInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyLoadBalancerAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyLoadBalancerAttributesInput, ::rusoto_core::BuildError> {
+        Ok(ModifyLoadBalancerAttributesInput {
+            load_balancer_attributes: self.load_balancer_attributes.ok_or(
+                ::rusoto_core::BuildError::missing("load_balancer_attributes"),
+            )?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyLoadBalancerAttributesInput` contents to a `SignedRequest`.
 struct ModifyLoadBalancerAttributesInputSerializer;
 impl ModifyLoadBalancerAttributesInputSerializer {
@@ -2856,10 +3942,10 @@ pub struct ModifyLoadBalancerAttributesOutput {
     pub load_balancer_name: Option<String>,
 }
 
-struct ModifyLoadBalancerAttributesOutputDeserializer;
+pub(crate) struct ModifyLoadBalancerAttributesOutputDeserializer;
 impl ModifyLoadBalancerAttributesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyLoadBalancerAttributesOutput, XmlParseError> {
@@ -2888,10 +3974,13 @@ impl ModifyLoadBalancerAttributesOutputDeserializer {
         )
     }
 }
-struct NameDeserializer;
+pub(crate) struct NameDeserializer;
 impl NameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2910,10 +3999,10 @@ pub struct Policies {
     pub other_policies: Option<Vec<String>>,
 }
 
-struct PoliciesDeserializer;
+pub(crate) struct PoliciesDeserializer;
 impl PoliciesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Policies, XmlParseError> {
@@ -2982,10 +4071,10 @@ pub struct PolicyAttributeDescription {
     pub attribute_value: Option<String>,
 }
 
-struct PolicyAttributeDescriptionDeserializer;
+pub(crate) struct PolicyAttributeDescriptionDeserializer;
 impl PolicyAttributeDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PolicyAttributeDescription, XmlParseError> {
@@ -3013,10 +4102,10 @@ impl PolicyAttributeDescriptionDeserializer {
         )
     }
 }
-struct PolicyAttributeDescriptionsDeserializer;
+pub(crate) struct PolicyAttributeDescriptionsDeserializer;
 impl PolicyAttributeDescriptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PolicyAttributeDescription>, XmlParseError> {
@@ -3047,10 +4136,10 @@ pub struct PolicyAttributeTypeDescription {
     pub description: Option<String>,
 }
 
-struct PolicyAttributeTypeDescriptionDeserializer;
+pub(crate) struct PolicyAttributeTypeDescriptionDeserializer;
 impl PolicyAttributeTypeDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PolicyAttributeTypeDescription, XmlParseError> {
@@ -3092,10 +4181,10 @@ impl PolicyAttributeTypeDescriptionDeserializer {
         )
     }
 }
-struct PolicyAttributeTypeDescriptionsDeserializer;
+pub(crate) struct PolicyAttributeTypeDescriptionsDeserializer;
 impl PolicyAttributeTypeDescriptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PolicyAttributeTypeDescription>, XmlParseError> {
@@ -3134,10 +4223,10 @@ pub struct PolicyDescription {
     pub policy_type_name: Option<String>,
 }
 
-struct PolicyDescriptionDeserializer;
+pub(crate) struct PolicyDescriptionDeserializer;
 impl PolicyDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PolicyDescription, XmlParseError> {
@@ -3167,10 +4256,10 @@ impl PolicyDescriptionDeserializer {
         })
     }
 }
-struct PolicyDescriptionsDeserializer;
+pub(crate) struct PolicyDescriptionsDeserializer;
 impl PolicyDescriptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PolicyDescription>, XmlParseError> {
@@ -3184,10 +4273,13 @@ impl PolicyDescriptionsDeserializer {
         })
     }
 }
-struct PolicyNameDeserializer;
+pub(crate) struct PolicyNameDeserializer;
 impl PolicyNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3195,10 +4287,10 @@ impl PolicyNameDeserializer {
         Ok(obj)
     }
 }
-struct PolicyNamesDeserializer;
+pub(crate) struct PolicyNamesDeserializer;
 impl PolicyNamesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -3235,10 +4327,10 @@ pub struct PolicyTypeDescription {
     pub policy_type_name: Option<String>,
 }
 
-struct PolicyTypeDescriptionDeserializer;
+pub(crate) struct PolicyTypeDescriptionDeserializer;
 impl PolicyTypeDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PolicyTypeDescription, XmlParseError> {
@@ -3268,10 +4360,10 @@ impl PolicyTypeDescriptionDeserializer {
         })
     }
 }
-struct PolicyTypeDescriptionsDeserializer;
+pub(crate) struct PolicyTypeDescriptionsDeserializer;
 impl PolicyTypeDescriptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PolicyTypeDescription>, XmlParseError> {
@@ -3287,10 +4379,13 @@ impl PolicyTypeDescriptionsDeserializer {
         })
     }
 }
-struct PolicyTypeNameDeserializer;
+pub(crate) struct PolicyTypeNameDeserializer;
 impl PolicyTypeNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3321,10 +4416,13 @@ impl PortsSerializer {
     }
 }
 
-struct ProtocolDeserializer;
+pub(crate) struct ProtocolDeserializer;
 impl ProtocolDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3332,10 +4430,13 @@ impl ProtocolDeserializer {
         Ok(obj)
     }
 }
-struct ReasonCodeDeserializer;
+pub(crate) struct ReasonCodeDeserializer;
 impl ReasonCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3352,6 +4453,46 @@ pub struct RegisterEndPointsInput {
     pub load_balancer_name: String,
 }
 
+impl RegisterEndPointsInput {
+    /// Returns a builder for `RegisterEndPointsInput`, checking its required members when built.
+    pub fn builder() -> RegisterEndPointsInputBuilder {
+        RegisterEndPointsInputBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterEndPointsInput`](struct.RegisterEndPointsInput.html).
+#[derive(Default, Debug)]
+pub struct RegisterEndPointsInputBuilder {
+    instances: Option<Vec<Instance>>,
+    load_balancer_name: Option<String>,
+}
+
+impl RegisterEndPointsInputBuilder {
+    /// Sets the [`instances`](struct.RegisterEndPointsInput.html#structfield.instances) member.
+    pub fn instances<T: Into<Vec<Instance>>>(mut self, value: T) -> Self {
+        self.instances = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.RegisterEndPointsInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterEndPointsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RegisterEndPointsInput, ::rusoto_core::BuildError> {
+        Ok(RegisterEndPointsInput {
+            instances: self
+                .instances
+                .ok_or(::rusoto_core::BuildError::missing("instances"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 /// Serialize `RegisterEndPointsInput` contents to a `SignedRequest`.
 struct RegisterEndPointsInputSerializer;
 impl RegisterEndPointsInputSerializer {
@@ -3380,10 +4521,10 @@ pub struct RegisterEndPointsOutput {
     pub instances: Option<Vec<Instance>>,
 }
 
-struct RegisterEndPointsOutputDeserializer;
+pub(crate) struct RegisterEndPointsOutputDeserializer;
 impl RegisterEndPointsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RegisterEndPointsOutput, XmlParseError> {
@@ -3413,6 +4554,48 @@ pub struct RemoveAvailabilityZonesInput {
     pub load_balancer_name: String,
 }
 
+impl RemoveAvailabilityZonesInput {
+    /// Returns a builder for `RemoveAvailabilityZonesInput`, checking its required members when built.
+    pub fn builder() -> RemoveAvailabilityZonesInputBuilder {
+        RemoveAvailabilityZonesInputBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveAvailabilityZonesInput`](struct.RemoveAvailabilityZonesInput.html).
+#[derive(Default, Debug)]
+pub struct RemoveAvailabilityZonesInputBuilder {
+    availability_zones: Option<Vec<String>>,
+    load_balancer_name: Option<String>,
+}
+
+impl RemoveAvailabilityZonesInputBuilder {
+    /// Sets the [`availability_zones`](struct.RemoveAvailabilityZonesInput.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.RemoveAvailabilityZonesInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveAvailabilityZonesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveAvailabilityZonesInput, ::rusoto_core::BuildError> {
+        Ok(RemoveAvailabilityZonesInput {
+            availability_zones: self
+                .availability_zones
+                .ok_or(::rusoto_core::BuildError::missing("availability_zones"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveAvailabilityZonesInput` contents to a `SignedRequest`.
 struct RemoveAvailabilityZonesInputSerializer;
 impl RemoveAvailabilityZonesInputSerializer {
@@ -3441,10 +4624,10 @@ pub struct RemoveAvailabilityZonesOutput {
     pub availability_zones: Option<Vec<String>>,
 }
 
-struct RemoveAvailabilityZonesOutputDeserializer;
+pub(crate) struct RemoveAvailabilityZonesOutputDeserializer;
 impl RemoveAvailabilityZonesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RemoveAvailabilityZonesOutput, XmlParseError> {
@@ -3474,6 +4657,46 @@ pub struct RemoveTagsInput {
     pub tags: Vec<TagKeyOnly>,
 }
 
+impl RemoveTagsInput {
+    /// Returns a builder for `RemoveTagsInput`, checking its required members when built.
+    pub fn builder() -> RemoveTagsInputBuilder {
+        RemoveTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsInput`](struct.RemoveTagsInput.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsInputBuilder {
+    load_balancer_names: Option<Vec<String>>,
+    tags: Option<Vec<TagKeyOnly>>,
+}
+
+impl RemoveTagsInputBuilder {
+    /// Sets the [`load_balancer_names`](struct.RemoveTagsInput.html#structfield.load_balancer_names) member.
+    pub fn load_balancer_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.load_balancer_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RemoveTagsInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<TagKeyOnly>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RemoveTagsInput, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsInput {
+            load_balancer_names: self
+                .load_balancer_names
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_names"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveTagsInput` contents to a `SignedRequest`.
 struct RemoveTagsInputSerializer;
 impl RemoveTagsInputSerializer {
@@ -3496,10 +4719,10 @@ impl RemoveTagsInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct RemoveTagsOutput {}
 
-struct RemoveTagsOutputDeserializer;
+pub(crate) struct RemoveTagsOutputDeserializer;
 impl RemoveTagsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RemoveTagsOutput, XmlParseError> {
@@ -3512,10 +4735,13 @@ impl RemoveTagsOutputDeserializer {
         Ok(obj)
     }
 }
-struct S3BucketNameDeserializer;
+pub(crate) struct S3BucketNameDeserializer;
 impl S3BucketNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3523,10 +4749,13 @@ impl S3BucketNameDeserializer {
         Ok(obj)
     }
 }
-struct SSLCertificateIdDeserializer;
+pub(crate) struct SSLCertificateIdDeserializer;
 impl SSLCertificateIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3534,10 +4763,13 @@ impl SSLCertificateIdDeserializer {
         Ok(obj)
     }
 }
-struct SecurityGroupIdDeserializer;
+pub(crate) struct SecurityGroupIdDeserializer;
 impl SecurityGroupIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3545,10 +4777,13 @@ impl SecurityGroupIdDeserializer {
         Ok(obj)
     }
 }
-struct SecurityGroupNameDeserializer;
+pub(crate) struct SecurityGroupNameDeserializer;
 impl SecurityGroupNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3556,10 +4791,13 @@ impl SecurityGroupNameDeserializer {
         Ok(obj)
     }
 }
-struct SecurityGroupOwnerAliasDeserializer;
+pub(crate) struct SecurityGroupOwnerAliasDeserializer;
 impl SecurityGroupOwnerAliasDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3567,10 +4805,10 @@ impl SecurityGroupOwnerAliasDeserializer {
         Ok(obj)
     }
 }
-struct SecurityGroupsDeserializer;
+pub(crate) struct SecurityGroupsDeserializer;
 impl SecurityGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -3607,6 +4845,59 @@ pub struct SetLoadBalancerListenerSSLCertificateInput {
     pub ssl_certificate_id: String,
 }
 
+impl SetLoadBalancerListenerSSLCertificateInput {
+    /// Returns a builder for `SetLoadBalancerListenerSSLCertificateInput`, checking its required members when built.
+    pub fn builder() -> SetLoadBalancerListenerSSLCertificateInputBuilder {
+        SetLoadBalancerListenerSSLCertificateInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetLoadBalancerListenerSSLCertificateInput`](struct.SetLoadBalancerListenerSSLCertificateInput.html).
+#[derive(Default, Debug)]
+pub struct SetLoadBalancerListenerSSLCertificateInputBuilder {
+    load_balancer_name: Option<String>,
+    load_balancer_port: Option<i64>,
+    ssl_certificate_id: Option<String>,
+}
+
+impl SetLoadBalancerListenerSSLCertificateInputBuilder {
+    /// Sets the [`load_balancer_name`](struct.SetLoadBalancerListenerSSLCertificateInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_port`](struct.SetLoadBalancerListenerSSLCertificateInput.html#structfield.load_balancer_port) member.
+    pub fn load_balancer_port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.load_balancer_port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssl_certificate_id`](struct.SetLoadBalancerListenerSSLCertificateInput.html#structfield.ssl_certificate_id) member.
+    pub fn ssl_certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssl_certificate_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetLoadBalancerListenerSSLCertificateInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetLoadBalancerListenerSSLCertificateInput, ::rusoto_core::BuildError>
+    {
+        Ok(SetLoadBalancerListenerSSLCertificateInput {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            load_balancer_port: self
+                .load_balancer_port
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_port"))?,
+            ssl_certificate_id: self
+                .ssl_certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("ssl_certificate_id"))?,
+        })
+    }
+}
+
 /// Serialize `SetLoadBalancerListenerSSLCertificateInput` contents to a `SignedRequest`.
 struct SetLoadBalancerListenerSSLCertificateInputSerializer;
 impl SetLoadBalancerListenerSSLCertificateInputSerializer {
@@ -3639,10 +4930,10 @@ impl SetLoadBalancerListenerSSLCertificateInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetLoadBalancerListenerSSLCertificateOutput {}
 
-struct SetLoadBalancerListenerSSLCertificateOutputDeserializer;
+pub(crate) struct SetLoadBalancerListenerSSLCertificateOutputDeserializer;
 impl SetLoadBalancerListenerSSLCertificateOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetLoadBalancerListenerSSLCertificateOutput, XmlParseError> {
@@ -3666,6 +4957,61 @@ pub struct SetLoadBalancerPoliciesForBackendServerInput {
     pub policy_names: Vec<String>,
 }
 
+impl SetLoadBalancerPoliciesForBackendServerInput {
+    /// Returns a builder for `SetLoadBalancerPoliciesForBackendServerInput`, checking its required members when built.
+    pub fn builder() -> SetLoadBalancerPoliciesForBackendServerInputBuilder {
+        SetLoadBalancerPoliciesForBackendServerInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetLoadBalancerPoliciesForBackendServerInput`](struct.SetLoadBalancerPoliciesForBackendServerInput.html).
+#[derive(Default, Debug)]
+pub struct SetLoadBalancerPoliciesForBackendServerInputBuilder {
+    instance_port: Option<i64>,
+    load_balancer_name: Option<String>,
+    policy_names: Option<Vec<String>>,
+}
+
+impl SetLoadBalancerPoliciesForBackendServerInputBuilder {
+    /// Sets the [`instance_port`](struct.SetLoadBalancerPoliciesForBackendServerInput.html#structfield.instance_port) member.
+    pub fn instance_port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.instance_port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.SetLoadBalancerPoliciesForBackendServerInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_names`](struct.SetLoadBalancerPoliciesForBackendServerInput.html#structfield.policy_names) member.
+    pub fn policy_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetLoadBalancerPoliciesForBackendServerInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        SetLoadBalancerPoliciesForBackendServerInput,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(SetLoadBalancerPoliciesForBackendServerInput {
+            instance_port: self
+                .instance_port
+                .ok_or(::rusoto_core::BuildError::missing("instance_port"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            policy_names: self
+                .policy_names
+                .ok_or(::rusoto_core::BuildError::missing("policy_names"))?,
+        })
+    }
+}
+
 /// Serialize `SetLoadBalancerPoliciesForBackendServerInput` contents to a `SignedRequest`.
 struct SetLoadBalancerPoliciesForBackendServerInputSerializer;
 impl SetLoadBalancerPoliciesForBackendServerInputSerializer {
@@ -3696,10 +5042,10 @@ impl SetLoadBalancerPoliciesForBackendServerInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetLoadBalancerPoliciesForBackendServerOutput {}
 
-struct SetLoadBalancerPoliciesForBackendServerOutputDeserializer;
+pub(crate) struct SetLoadBalancerPoliciesForBackendServerOutputDeserializer;
 impl SetLoadBalancerPoliciesForBackendServerOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetLoadBalancerPoliciesForBackendServerOutput, XmlParseError> {
@@ -3723,6 +5069,59 @@ pub struct SetLoadBalancerPoliciesOfListenerInput {
     pub policy_names: Vec<String>,
 }
 
+impl SetLoadBalancerPoliciesOfListenerInput {
+    /// Returns a builder for `SetLoadBalancerPoliciesOfListenerInput`, checking its required members when built.
+    pub fn builder() -> SetLoadBalancerPoliciesOfListenerInputBuilder {
+        SetLoadBalancerPoliciesOfListenerInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetLoadBalancerPoliciesOfListenerInput`](struct.SetLoadBalancerPoliciesOfListenerInput.html).
+#[derive(Default, Debug)]
+pub struct SetLoadBalancerPoliciesOfListenerInputBuilder {
+    load_balancer_name: Option<String>,
+    load_balancer_port: Option<i64>,
+    policy_names: Option<Vec<String>>,
+}
+
+impl SetLoadBalancerPoliciesOfListenerInputBuilder {
+    /// Sets the [`load_balancer_name`](struct.SetLoadBalancerPoliciesOfListenerInput.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_port`](struct.SetLoadBalancerPoliciesOfListenerInput.html#structfield.load_balancer_port) member.
+    pub fn load_balancer_port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.load_balancer_port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_names`](struct.SetLoadBalancerPoliciesOfListenerInput.html#structfield.policy_names) member.
+    pub fn policy_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetLoadBalancerPoliciesOfListenerInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetLoadBalancerPoliciesOfListenerInput, ::rusoto_core::BuildError>
+    {
+        Ok(SetLoadBalancerPoliciesOfListenerInput {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            load_balancer_port: self
+                .load_balancer_port
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_port"))?,
+            policy_names: self
+                .policy_names
+                .ok_or(::rusoto_core::BuildError::missing("policy_names"))?,
+        })
+    }
+}
+
 /// Serialize `SetLoadBalancerPoliciesOfListenerInput` contents to a `SignedRequest`.
 struct SetLoadBalancerPoliciesOfListenerInputSerializer;
 impl SetLoadBalancerPoliciesOfListenerInputSerializer {
@@ -3752,10 +5151,10 @@ impl SetLoadBalancerPoliciesOfListenerInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetLoadBalancerPoliciesOfListenerOutput {}
 
-struct SetLoadBalancerPoliciesOfListenerOutputDeserializer;
+pub(crate) struct SetLoadBalancerPoliciesOfListenerOutputDeserializer;
 impl SetLoadBalancerPoliciesOfListenerOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetLoadBalancerPoliciesOfListenerOutput, XmlParseError> {
@@ -3777,10 +5176,10 @@ pub struct SourceSecurityGroup {
     pub owner_alias: Option<String>,
 }
 
-struct SourceSecurityGroupDeserializer;
+pub(crate) struct SourceSecurityGroupDeserializer;
 impl SourceSecurityGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SourceSecurityGroup, XmlParseError> {
@@ -3804,10 +5203,13 @@ impl SourceSecurityGroupDeserializer {
         })
     }
 }
-struct StateDeserializer;
+pub(crate) struct StateDeserializer;
 impl StateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3815,10 +5217,13 @@ impl StateDeserializer {
         Ok(obj)
     }
 }
-struct SubnetIdDeserializer;
+pub(crate) struct SubnetIdDeserializer;
 impl SubnetIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3826,10 +5231,10 @@ impl SubnetIdDeserializer {
         Ok(obj)
     }
 }
-struct SubnetsDeserializer;
+pub(crate) struct SubnetsDeserializer;
 impl SubnetsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -3864,10 +5269,13 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -3908,10 +5316,10 @@ pub struct TagDescription {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct TagDescriptionDeserializer;
+pub(crate) struct TagDescriptionDeserializer;
 impl TagDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TagDescription, XmlParseError> {
@@ -3934,10 +5342,10 @@ impl TagDescriptionDeserializer {
         })
     }
 }
-struct TagDescriptionsDeserializer;
+pub(crate) struct TagDescriptionsDeserializer;
 impl TagDescriptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TagDescription>, XmlParseError> {
@@ -3951,10 +5359,13 @@ impl TagDescriptionsDeserializer {
         })
     }
 }
-struct TagKeyDeserializer;
+pub(crate) struct TagKeyDeserializer;
 impl TagKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3996,10 +5407,10 @@ impl TagKeyOnlySerializer {
     }
 }
 
-struct TagListDeserializer;
+pub(crate) struct TagListDeserializer;
 impl TagListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -4025,10 +5436,13 @@ impl TagListSerializer {
     }
 }
 
-struct TagValueDeserializer;
+pub(crate) struct TagValueDeserializer;
 impl TagValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4036,10 +5450,13 @@ impl TagValueDeserializer {
         Ok(obj)
     }
 }
-struct UnhealthyThresholdDeserializer;
+pub(crate) struct UnhealthyThresholdDeserializer;
 impl UnhealthyThresholdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4047,10 +5464,13 @@ impl UnhealthyThresholdDeserializer {
         Ok(obj)
     }
 }
-struct VPCIdDeserializer;
+pub(crate) struct VPCIdDeserializer;
 impl VPCIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6140,6 +7560,199 @@ pub trait Elb {
         input: SetLoadBalancerPoliciesOfListenerInput,
     ) -> RusotoFuture<SetLoadBalancerPoliciesOfListenerOutput, SetLoadBalancerPoliciesOfListenerError>;
 }
+impl<T: ?Sized + Elb> Elb for ::std::sync::Arc<T> {
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn apply_security_groups_to_load_balancer(
+        &self,
+        input: ApplySecurityGroupsToLoadBalancerInput,
+    ) -> RusotoFuture<ApplySecurityGroupsToLoadBalancerOutput, ApplySecurityGroupsToLoadBalancerError>
+    {
+        (**self).apply_security_groups_to_load_balancer(input)
+    }
+    fn attach_load_balancer_to_subnets(
+        &self,
+        input: AttachLoadBalancerToSubnetsInput,
+    ) -> RusotoFuture<AttachLoadBalancerToSubnetsOutput, AttachLoadBalancerToSubnetsError> {
+        (**self).attach_load_balancer_to_subnets(input)
+    }
+    fn configure_health_check(
+        &self,
+        input: ConfigureHealthCheckInput,
+    ) -> RusotoFuture<ConfigureHealthCheckOutput, ConfigureHealthCheckError> {
+        (**self).configure_health_check(input)
+    }
+    fn create_app_cookie_stickiness_policy(
+        &self,
+        input: CreateAppCookieStickinessPolicyInput,
+    ) -> RusotoFuture<CreateAppCookieStickinessPolicyOutput, CreateAppCookieStickinessPolicyError>
+    {
+        (**self).create_app_cookie_stickiness_policy(input)
+    }
+    fn create_lb_cookie_stickiness_policy(
+        &self,
+        input: CreateLBCookieStickinessPolicyInput,
+    ) -> RusotoFuture<CreateLBCookieStickinessPolicyOutput, CreateLBCookieStickinessPolicyError>
+    {
+        (**self).create_lb_cookie_stickiness_policy(input)
+    }
+    fn create_load_balancer(
+        &self,
+        input: CreateAccessPointInput,
+    ) -> RusotoFuture<CreateAccessPointOutput, CreateLoadBalancerError> {
+        (**self).create_load_balancer(input)
+    }
+    fn create_load_balancer_listeners(
+        &self,
+        input: CreateLoadBalancerListenerInput,
+    ) -> RusotoFuture<CreateLoadBalancerListenerOutput, CreateLoadBalancerListenersError> {
+        (**self).create_load_balancer_listeners(input)
+    }
+    fn create_load_balancer_policy(
+        &self,
+        input: CreateLoadBalancerPolicyInput,
+    ) -> RusotoFuture<CreateLoadBalancerPolicyOutput, CreateLoadBalancerPolicyError> {
+        (**self).create_load_balancer_policy(input)
+    }
+    fn delete_load_balancer(
+        &self,
+        input: DeleteAccessPointInput,
+    ) -> RusotoFuture<DeleteAccessPointOutput, DeleteLoadBalancerError> {
+        (**self).delete_load_balancer(input)
+    }
+    fn delete_load_balancer_listeners(
+        &self,
+        input: DeleteLoadBalancerListenerInput,
+    ) -> RusotoFuture<DeleteLoadBalancerListenerOutput, DeleteLoadBalancerListenersError> {
+        (**self).delete_load_balancer_listeners(input)
+    }
+    fn delete_load_balancer_policy(
+        &self,
+        input: DeleteLoadBalancerPolicyInput,
+    ) -> RusotoFuture<DeleteLoadBalancerPolicyOutput, DeleteLoadBalancerPolicyError> {
+        (**self).delete_load_balancer_policy(input)
+    }
+    fn deregister_instances_from_load_balancer(
+        &self,
+        input: DeregisterEndPointsInput,
+    ) -> RusotoFuture<DeregisterEndPointsOutput, DeregisterInstancesFromLoadBalancerError> {
+        (**self).deregister_instances_from_load_balancer(input)
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        (**self).describe_account_limits(input)
+    }
+    fn describe_instance_health(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> RusotoFuture<DescribeEndPointStateOutput, DescribeInstanceHealthError> {
+        (**self).describe_instance_health(input)
+    }
+    fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
+    {
+        (**self).describe_load_balancer_attributes(input)
+    }
+    fn describe_load_balancer_policies(
+        &self,
+        input: DescribeLoadBalancerPoliciesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerPoliciesOutput, DescribeLoadBalancerPoliciesError> {
+        (**self).describe_load_balancer_policies(input)
+    }
+    fn describe_load_balancer_policy_types(
+        &self,
+        input: DescribeLoadBalancerPolicyTypesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerPolicyTypesOutput, DescribeLoadBalancerPolicyTypesError>
+    {
+        (**self).describe_load_balancer_policy_types(input)
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> RusotoFuture<DescribeAccessPointsOutput, DescribeLoadBalancersError> {
+        (**self).describe_load_balancers(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn detach_load_balancer_from_subnets(
+        &self,
+        input: DetachLoadBalancerFromSubnetsInput,
+    ) -> RusotoFuture<DetachLoadBalancerFromSubnetsOutput, DetachLoadBalancerFromSubnetsError> {
+        (**self).detach_load_balancer_from_subnets(input)
+    }
+    fn disable_availability_zones_for_load_balancer(
+        &self,
+        input: RemoveAvailabilityZonesInput,
+    ) -> RusotoFuture<RemoveAvailabilityZonesOutput, DisableAvailabilityZonesForLoadBalancerError>
+    {
+        (**self).disable_availability_zones_for_load_balancer(input)
+    }
+    fn enable_availability_zones_for_load_balancer(
+        &self,
+        input: AddAvailabilityZonesInput,
+    ) -> RusotoFuture<AddAvailabilityZonesOutput, EnableAvailabilityZonesForLoadBalancerError> {
+        (**self).enable_availability_zones_for_load_balancer(input)
+    }
+    fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
+        (**self).modify_load_balancer_attributes(input)
+    }
+    fn register_instances_with_load_balancer(
+        &self,
+        input: RegisterEndPointsInput,
+    ) -> RusotoFuture<RegisterEndPointsOutput, RegisterInstancesWithLoadBalancerError> {
+        (**self).register_instances_with_load_balancer(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn set_load_balancer_listener_ssl_certificate(
+        &self,
+        input: SetLoadBalancerListenerSSLCertificateInput,
+    ) -> RusotoFuture<
+        SetLoadBalancerListenerSSLCertificateOutput,
+        SetLoadBalancerListenerSSLCertificateError,
+    > {
+        (**self).set_load_balancer_listener_ssl_certificate(input)
+    }
+    fn set_load_balancer_policies_for_backend_server(
+        &self,
+        input: SetLoadBalancerPoliciesForBackendServerInput,
+    ) -> RusotoFuture<
+        SetLoadBalancerPoliciesForBackendServerOutput,
+        SetLoadBalancerPoliciesForBackendServerError,
+    > {
+        (**self).set_load_balancer_policies_for_backend_server(input)
+    }
+    fn set_load_balancer_policies_of_listener(
+        &self,
+        input: SetLoadBalancerPoliciesOfListenerInput,
+    ) -> RusotoFuture<SetLoadBalancerPoliciesOfListenerOutput, SetLoadBalancerPoliciesOfListenerError>
+    {
+        (**self).set_load_balancer_policies_of_listener(input)
+    }
+}
+/// The error codes Elastic Load Balancing retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Elastic Load Balancing API.
 #[derive(Clone)]
 pub struct ElbClient {
@@ -6155,6 +7768,27 @@ impl ElbClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ELB`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ElbClient {
+        Self::new(region::Region::from_env_for_service("ELB"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `elasticloadbalancing`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ElbClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("elasticloadbalancing", "ELB"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6173,7 +7807,54 @@ impl ElbClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ElbClient {
-        ElbClient { client, region }
+        ElbClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "elasticloadbalancing")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "elasticloadbalancing",
+            "elasticloadbalancing",
+            "ELB",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Elb` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Elb + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ElbClient {
+    fn service_name(&self) -> &'static str {
+        "Elastic Load Balancing"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "elasticloadbalancing"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ElbClient {
+        Self::new_with_client(client, region)
     }
 }
 
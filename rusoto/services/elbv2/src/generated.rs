@@ -28,13 +28,14 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
 use xml::EventReader;
 
 /// <p>Information about an action.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Action {
     /// <p>[HTTPS listeners] Information for using Amazon Cognito to authenticate users. Specify only when <code>Type</code> is <code>authenticate-cognito</code>.</p>
     pub authenticate_cognito_config: Option<AuthenticateCognitoActionConfig>,
@@ -49,58 +50,74 @@ pub struct Action {
     /// <p>The Amazon Resource Name (ARN) of the target group. Specify only when <code>Type</code> is <code>forward</code>.</p>
     pub target_group_arn: Option<String>,
     /// <p>The type of action. Each rule must include exactly one of the following types of actions: <code>forward</code>, <code>fixed-response</code>, or <code>redirect</code>.</p>
-    pub type_: String,
+    pub type_: ActionTypeEnum,
 }
 
-struct ActionDeserializer;
+pub(crate) struct ActionDeserializer;
 impl ActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Action, XmlParseError> {
-        deserialize_elements::<_, Action, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "AuthenticateCognitoConfig" => {
-                    obj.authenticate_cognito_config =
-                        Some(AuthenticateCognitoActionConfigDeserializer::deserialize(
-                            "AuthenticateCognitoConfig",
-                            stack,
-                        )?);
-                }
-                "AuthenticateOidcConfig" => {
-                    obj.authenticate_oidc_config =
-                        Some(AuthenticateOidcActionConfigDeserializer::deserialize(
-                            "AuthenticateOidcConfig",
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Action, XmlParseError> {
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            Action {
+                authenticate_cognito_config: Default::default(),
+                authenticate_oidc_config: Default::default(),
+                fixed_response_config: Default::default(),
+                order: Default::default(),
+                redirect_config: Default::default(),
+                target_group_arn: Default::default(),
+                type_: ActionTypeEnum::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "AuthenticateCognitoConfig" => {
+                        obj.authenticate_cognito_config =
+                            Some(AuthenticateCognitoActionConfigDeserializer::deserialize(
+                                "AuthenticateCognitoConfig",
+                                stack,
+                            )?);
+                    }
+                    "AuthenticateOidcConfig" => {
+                        obj.authenticate_oidc_config =
+                            Some(AuthenticateOidcActionConfigDeserializer::deserialize(
+                                "AuthenticateOidcConfig",
+                                stack,
+                            )?);
+                    }
+                    "FixedResponseConfig" => {
+                        obj.fixed_response_config =
+                            Some(FixedResponseActionConfigDeserializer::deserialize(
+                                "FixedResponseConfig",
+                                stack,
+                            )?);
+                    }
+                    "Order" => {
+                        obj.order = Some(ActionOrderDeserializer::deserialize("Order", stack)?);
+                    }
+                    "RedirectConfig" => {
+                        obj.redirect_config = Some(RedirectActionConfigDeserializer::deserialize(
+                            "RedirectConfig",
                             stack,
                         )?);
-                }
-                "FixedResponseConfig" => {
-                    obj.fixed_response_config =
-                        Some(FixedResponseActionConfigDeserializer::deserialize(
-                            "FixedResponseConfig",
+                    }
+                    "TargetGroupArn" => {
+                        obj.target_group_arn = Some(TargetGroupArnDeserializer::deserialize(
+                            "TargetGroupArn",
                             stack,
                         )?);
+                    }
+                    "Type" => {
+                        obj.type_ = ActionTypeEnumDeserializer::deserialize("Type", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                "Order" => {
-                    obj.order = Some(ActionOrderDeserializer::deserialize("Order", stack)?);
-                }
-                "RedirectConfig" => {
-                    obj.redirect_config = Some(RedirectActionConfigDeserializer::deserialize(
-                        "RedirectConfig",
-                        stack,
-                    )?);
-                }
-                "TargetGroupArn" => {
-                    obj.target_group_arn = Some(TargetGroupArnDeserializer::deserialize(
-                        "TargetGroupArn",
-                        stack,
-                    )?);
-                }
-                "Type" => {
-                    obj.type_ = ActionTypeEnumDeserializer::deserialize("Type", stack)?;
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -151,10 +168,13 @@ impl ActionSerializer {
     }
 }
 
-struct ActionOrderDeserializer;
+pub(crate) struct ActionOrderDeserializer;
 impl ActionOrderDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -162,21 +182,95 @@ impl ActionOrderDeserializer {
         Ok(obj)
     }
 }
-struct ActionTypeEnumDeserializer;
+pub(crate) struct ActionTypeEnumDeserializer;
 impl ActionTypeEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ActionTypeEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ActionsDeserializer;
+/// <p>The type of action. Each rule must include exactly one of the following types of actions: <code>forward</code>, <code>fixed-response</code>, or <code>redirect</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionTypeEnum {
+    /// <p><code>forward</code></p>
+    Forward,
+    /// <p><code>authenticate-oidc</code></p>
+    AuthenticateOidc,
+    /// <p><code>authenticate-cognito</code></p>
+    AuthenticateCognito,
+    /// <p><code>redirect</code></p>
+    Redirect,
+    /// <p><code>fixed-response</code></p>
+    FixedResponse,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionTypeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionTypeEnum::Forward => "forward",
+            ActionTypeEnum::AuthenticateOidc => "authenticate-oidc",
+            ActionTypeEnum::AuthenticateCognito => "authenticate-cognito",
+            ActionTypeEnum::Redirect => "redirect",
+            ActionTypeEnum::FixedResponse => "fixed-response",
+            ActionTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionTypeEnum {
+    fn from(value: &'a str) -> ActionTypeEnum {
+        match value {
+            "forward" => ActionTypeEnum::Forward,
+            "authenticate-oidc" => ActionTypeEnum::AuthenticateOidc,
+            "authenticate-cognito" => ActionTypeEnum::AuthenticateCognito,
+            "redirect" => ActionTypeEnum::Redirect,
+            "fixed-response" => ActionTypeEnum::FixedResponse,
+            _ => ActionTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionTypeEnum {
+    fn from(value: String) -> ActionTypeEnum {
+        ActionTypeEnum::from(value.as_str())
+    }
+}
+
+impl From<ActionTypeEnum> for String {
+    fn from(value: ActionTypeEnum) -> String {
+        match value {
+            ActionTypeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ActionsDeserializer;
 impl ActionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Action>, XmlParseError> {
@@ -210,6 +304,48 @@ pub struct AddListenerCertificatesInput {
     pub listener_arn: String,
 }
 
+impl AddListenerCertificatesInput {
+    /// Returns a builder for `AddListenerCertificatesInput`, checking its required members when built.
+    pub fn builder() -> AddListenerCertificatesInputBuilder {
+        AddListenerCertificatesInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddListenerCertificatesInput`](struct.AddListenerCertificatesInput.html).
+#[derive(Default, Debug)]
+pub struct AddListenerCertificatesInputBuilder {
+    certificates: Option<Vec<Certificate>>,
+    listener_arn: Option<String>,
+}
+
+impl AddListenerCertificatesInputBuilder {
+    /// Sets the [`certificates`](struct.AddListenerCertificatesInput.html#structfield.certificates) member.
+    pub fn certificates<T: Into<Vec<Certificate>>>(mut self, value: T) -> Self {
+        self.certificates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`listener_arn`](struct.AddListenerCertificatesInput.html#structfield.listener_arn) member.
+    pub fn listener_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.listener_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddListenerCertificatesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddListenerCertificatesInput, ::rusoto_core::BuildError> {
+        Ok(AddListenerCertificatesInput {
+            certificates: self
+                .certificates
+                .ok_or(::rusoto_core::BuildError::missing("certificates"))?,
+            listener_arn: self
+                .listener_arn
+                .ok_or(::rusoto_core::BuildError::missing("listener_arn"))?,
+        })
+    }
+}
+
 /// Serialize `AddListenerCertificatesInput` contents to a `SignedRequest`.
 struct AddListenerCertificatesInputSerializer;
 impl AddListenerCertificatesInputSerializer {
@@ -234,10 +370,10 @@ pub struct AddListenerCertificatesOutput {
     pub certificates: Option<Vec<Certificate>>,
 }
 
-struct AddListenerCertificatesOutputDeserializer;
+pub(crate) struct AddListenerCertificatesOutputDeserializer;
 impl AddListenerCertificatesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AddListenerCertificatesOutput, XmlParseError> {
@@ -266,6 +402,46 @@ pub struct AddTagsInput {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsInput {
+    /// Returns a builder for `AddTagsInput`, checking its required members when built.
+    pub fn builder() -> AddTagsInputBuilder {
+        AddTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsInput`](struct.AddTagsInput.html).
+#[derive(Default, Debug)]
+pub struct AddTagsInputBuilder {
+    resource_arns: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsInputBuilder {
+    /// Sets the [`resource_arns`](struct.AddTagsInput.html#structfield.resource_arns) member.
+    pub fn resource_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddTagsInput, ::rusoto_core::BuildError> {
+        Ok(AddTagsInput {
+            resource_arns: self
+                .resource_arns
+                .ok_or(::rusoto_core::BuildError::missing("resource_arns"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `AddTagsInput` contents to a `SignedRequest`.
 struct AddTagsInputSerializer;
 impl AddTagsInputSerializer {
@@ -287,10 +463,10 @@ impl AddTagsInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AddTagsOutput {}
 
-struct AddTagsOutputDeserializer;
+pub(crate) struct AddTagsOutputDeserializer;
 impl AddTagsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AddTagsOutput, XmlParseError> {
@@ -303,10 +479,13 @@ impl AddTagsOutputDeserializer {
         Ok(obj)
     }
 }
-struct AllocationIdDeserializer;
+pub(crate) struct AllocationIdDeserializer;
 impl AllocationIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -314,10 +493,10 @@ impl AllocationIdDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateCognitoActionAuthenticationRequestExtraParamsDeserializer;
+pub(crate) struct AuthenticateCognitoActionAuthenticationRequestExtraParamsDeserializer;
 impl AuthenticateCognitoActionAuthenticationRequestExtraParamsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
@@ -360,10 +539,13 @@ impl AuthenticateCognitoActionAuthenticationRequestExtraParamsSerializer {
     }
 }
 
-struct AuthenticateCognitoActionAuthenticationRequestParamNameDeserializer;
+pub(crate) struct AuthenticateCognitoActionAuthenticationRequestParamNameDeserializer;
 impl AuthenticateCognitoActionAuthenticationRequestParamNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -371,10 +553,13 @@ impl AuthenticateCognitoActionAuthenticationRequestParamNameDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateCognitoActionAuthenticationRequestParamValueDeserializer;
+pub(crate) struct AuthenticateCognitoActionAuthenticationRequestParamValueDeserializer;
 impl AuthenticateCognitoActionAuthenticationRequestParamValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -382,24 +567,90 @@ impl AuthenticateCognitoActionAuthenticationRequestParamValueDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateCognitoActionConditionalBehaviorEnumDeserializer;
+pub(crate) struct AuthenticateCognitoActionConditionalBehaviorEnumDeserializer;
 impl AuthenticateCognitoActionConditionalBehaviorEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AuthenticateCognitoActionConditionalBehaviorEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p><p>The behavior if the user is not authenticated. The following are possible values:</p> <ul> <li> <p>deny<code/> - Return an HTTP 401 Unauthorized error.</p> </li> <li> <p>allow<code/> - Allow the request to be forwarded to the target.</p> </li> <li> <p>authenticate<code/> - Redirect the request to the IdP authorization endpoint. This is the default value.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthenticateCognitoActionConditionalBehaviorEnum {
+    /// <p><code>deny</code></p>
+    Deny,
+    /// <p><code>allow</code></p>
+    Allow,
+    /// <p><code>authenticate</code></p>
+    Authenticate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthenticateCognitoActionConditionalBehaviorEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthenticateCognitoActionConditionalBehaviorEnum::Deny => "deny",
+            AuthenticateCognitoActionConditionalBehaviorEnum::Allow => "allow",
+            AuthenticateCognitoActionConditionalBehaviorEnum::Authenticate => "authenticate",
+            AuthenticateCognitoActionConditionalBehaviorEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthenticateCognitoActionConditionalBehaviorEnum {
+    fn from(value: &'a str) -> AuthenticateCognitoActionConditionalBehaviorEnum {
+        match value {
+            "deny" => AuthenticateCognitoActionConditionalBehaviorEnum::Deny,
+            "allow" => AuthenticateCognitoActionConditionalBehaviorEnum::Allow,
+            "authenticate" => AuthenticateCognitoActionConditionalBehaviorEnum::Authenticate,
+            _ => AuthenticateCognitoActionConditionalBehaviorEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthenticateCognitoActionConditionalBehaviorEnum {
+    fn from(value: String) -> AuthenticateCognitoActionConditionalBehaviorEnum {
+        AuthenticateCognitoActionConditionalBehaviorEnum::from(value.as_str())
+    }
+}
+
+impl From<AuthenticateCognitoActionConditionalBehaviorEnum> for String {
+    fn from(value: AuthenticateCognitoActionConditionalBehaviorEnum) -> String {
+        match value {
+            AuthenticateCognitoActionConditionalBehaviorEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthenticateCognitoActionConditionalBehaviorEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthenticateCognitoActionConditionalBehaviorEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Request parameters to use when integrating with Amazon Cognito to authenticate users.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AuthenticateCognitoActionConfig {
     /// <p>The query parameters (up to 10) to include in the redirect request to the authorization endpoint.</p>
     pub authentication_request_extra_params: Option<::std::collections::HashMap<String, String>>,
     /// <p><p>The behavior if the user is not authenticated. The following are possible values:</p> <ul> <li> <p>deny<code/> - Return an HTTP 401 Unauthorized error.</p> </li> <li> <p>allow<code/> - Allow the request to be forwarded to the target.</p> </li> <li> <p>authenticate<code/> - Redirect the request to the IdP authorization endpoint. This is the default value.</p> </li> </ul></p>
-    pub on_unauthenticated_request: Option<String>,
+    pub on_unauthenticated_request: Option<AuthenticateCognitoActionConditionalBehaviorEnum>,
     /// <p>The set of user claims to be requested from the IdP. The default is <code>openid</code>.</p> <p>To verify which scope values your IdP supports and how to separate multiple values, see the documentation for your IdP.</p>
     pub scope: Option<String>,
     /// <p>The name of the cookie used to maintain session information. The default is AWSELBAuthSessionCookie.</p>
@@ -414,10 +665,10 @@ pub struct AuthenticateCognitoActionConfig {
     pub user_pool_domain: String,
 }
 
-struct AuthenticateCognitoActionConfigDeserializer;
+pub(crate) struct AuthenticateCognitoActionConfigDeserializer;
 impl AuthenticateCognitoActionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AuthenticateCognitoActionConfig, XmlParseError> {
@@ -525,10 +776,13 @@ impl AuthenticateCognitoActionConfigSerializer {
     }
 }
 
-struct AuthenticateCognitoActionScopeDeserializer;
+pub(crate) struct AuthenticateCognitoActionScopeDeserializer;
 impl AuthenticateCognitoActionScopeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -536,10 +790,13 @@ impl AuthenticateCognitoActionScopeDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateCognitoActionSessionCookieNameDeserializer;
+pub(crate) struct AuthenticateCognitoActionSessionCookieNameDeserializer;
 impl AuthenticateCognitoActionSessionCookieNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -547,10 +804,13 @@ impl AuthenticateCognitoActionSessionCookieNameDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateCognitoActionSessionTimeoutDeserializer;
+pub(crate) struct AuthenticateCognitoActionSessionTimeoutDeserializer;
 impl AuthenticateCognitoActionSessionTimeoutDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -558,10 +818,13 @@ impl AuthenticateCognitoActionSessionTimeoutDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateCognitoActionUserPoolArnDeserializer;
+pub(crate) struct AuthenticateCognitoActionUserPoolArnDeserializer;
 impl AuthenticateCognitoActionUserPoolArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -569,10 +832,13 @@ impl AuthenticateCognitoActionUserPoolArnDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateCognitoActionUserPoolClientIdDeserializer;
+pub(crate) struct AuthenticateCognitoActionUserPoolClientIdDeserializer;
 impl AuthenticateCognitoActionUserPoolClientIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -580,10 +846,13 @@ impl AuthenticateCognitoActionUserPoolClientIdDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateCognitoActionUserPoolDomainDeserializer;
+pub(crate) struct AuthenticateCognitoActionUserPoolDomainDeserializer;
 impl AuthenticateCognitoActionUserPoolDomainDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -591,10 +860,10 @@ impl AuthenticateCognitoActionUserPoolDomainDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateOidcActionAuthenticationRequestExtraParamsDeserializer;
+pub(crate) struct AuthenticateOidcActionAuthenticationRequestExtraParamsDeserializer;
 impl AuthenticateOidcActionAuthenticationRequestExtraParamsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
@@ -637,10 +906,13 @@ impl AuthenticateOidcActionAuthenticationRequestExtraParamsSerializer {
     }
 }
 
-struct AuthenticateOidcActionAuthenticationRequestParamNameDeserializer;
+pub(crate) struct AuthenticateOidcActionAuthenticationRequestParamNameDeserializer;
 impl AuthenticateOidcActionAuthenticationRequestParamNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -648,10 +920,13 @@ impl AuthenticateOidcActionAuthenticationRequestParamNameDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateOidcActionAuthenticationRequestParamValueDeserializer;
+pub(crate) struct AuthenticateOidcActionAuthenticationRequestParamValueDeserializer;
 impl AuthenticateOidcActionAuthenticationRequestParamValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -659,10 +934,13 @@ impl AuthenticateOidcActionAuthenticationRequestParamValueDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateOidcActionAuthorizationEndpointDeserializer;
+pub(crate) struct AuthenticateOidcActionAuthorizationEndpointDeserializer;
 impl AuthenticateOidcActionAuthorizationEndpointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -670,10 +948,13 @@ impl AuthenticateOidcActionAuthorizationEndpointDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateOidcActionClientIdDeserializer;
+pub(crate) struct AuthenticateOidcActionClientIdDeserializer;
 impl AuthenticateOidcActionClientIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -681,10 +962,13 @@ impl AuthenticateOidcActionClientIdDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateOidcActionClientSecretDeserializer;
+pub(crate) struct AuthenticateOidcActionClientSecretDeserializer;
 impl AuthenticateOidcActionClientSecretDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -692,17 +976,83 @@ impl AuthenticateOidcActionClientSecretDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateOidcActionConditionalBehaviorEnumDeserializer;
+pub(crate) struct AuthenticateOidcActionConditionalBehaviorEnumDeserializer;
 impl AuthenticateOidcActionConditionalBehaviorEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<AuthenticateOidcActionConditionalBehaviorEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p><p>The behavior if the user is not authenticated. The following are possible values:</p> <ul> <li> <p>deny<code/> - Return an HTTP 401 Unauthorized error.</p> </li> <li> <p>allow<code/> - Allow the request to be forwarded to the target.</p> </li> <li> <p>authenticate<code/> - Redirect the request to the IdP authorization endpoint. This is the default value.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthenticateOidcActionConditionalBehaviorEnum {
+    /// <p><code>deny</code></p>
+    Deny,
+    /// <p><code>allow</code></p>
+    Allow,
+    /// <p><code>authenticate</code></p>
+    Authenticate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthenticateOidcActionConditionalBehaviorEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthenticateOidcActionConditionalBehaviorEnum::Deny => "deny",
+            AuthenticateOidcActionConditionalBehaviorEnum::Allow => "allow",
+            AuthenticateOidcActionConditionalBehaviorEnum::Authenticate => "authenticate",
+            AuthenticateOidcActionConditionalBehaviorEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthenticateOidcActionConditionalBehaviorEnum {
+    fn from(value: &'a str) -> AuthenticateOidcActionConditionalBehaviorEnum {
+        match value {
+            "deny" => AuthenticateOidcActionConditionalBehaviorEnum::Deny,
+            "allow" => AuthenticateOidcActionConditionalBehaviorEnum::Allow,
+            "authenticate" => AuthenticateOidcActionConditionalBehaviorEnum::Authenticate,
+            _ => AuthenticateOidcActionConditionalBehaviorEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthenticateOidcActionConditionalBehaviorEnum {
+    fn from(value: String) -> AuthenticateOidcActionConditionalBehaviorEnum {
+        AuthenticateOidcActionConditionalBehaviorEnum::from(value.as_str())
+    }
+}
+
+impl From<AuthenticateOidcActionConditionalBehaviorEnum> for String {
+    fn from(value: AuthenticateOidcActionConditionalBehaviorEnum) -> String {
+        match value {
+            AuthenticateOidcActionConditionalBehaviorEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthenticateOidcActionConditionalBehaviorEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthenticateOidcActionConditionalBehaviorEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Request parameters when using an identity provider (IdP) that is compliant with OpenID Connect (OIDC) to authenticate users.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AuthenticateOidcActionConfig {
@@ -717,7 +1067,7 @@ pub struct AuthenticateOidcActionConfig {
     /// <p>The OIDC issuer identifier of the IdP. This must be a full URL, including the HTTPS protocol, the domain, and the path.</p>
     pub issuer: String,
     /// <p><p>The behavior if the user is not authenticated. The following are possible values:</p> <ul> <li> <p>deny<code/> - Return an HTTP 401 Unauthorized error.</p> </li> <li> <p>allow<code/> - Allow the request to be forwarded to the target.</p> </li> <li> <p>authenticate<code/> - Redirect the request to the IdP authorization endpoint. This is the default value.</p> </li> </ul></p>
-    pub on_unauthenticated_request: Option<String>,
+    pub on_unauthenticated_request: Option<AuthenticateOidcActionConditionalBehaviorEnum>,
     /// <p>The set of user claims to be requested from the IdP. The default is <code>openid</code>.</p> <p>To verify which scope values your IdP supports and how to separate multiple values, see the documentation for your IdP.</p>
     pub scope: Option<String>,
     /// <p>The name of the cookie used to maintain session information. The default is AWSELBAuthSessionCookie.</p>
@@ -732,10 +1082,10 @@ pub struct AuthenticateOidcActionConfig {
     pub user_info_endpoint: String,
 }
 
-struct AuthenticateOidcActionConfigDeserializer;
+pub(crate) struct AuthenticateOidcActionConfigDeserializer;
 impl AuthenticateOidcActionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AuthenticateOidcActionConfig, XmlParseError> {
@@ -886,10 +1236,13 @@ impl AuthenticateOidcActionConfigSerializer {
     }
 }
 
-struct AuthenticateOidcActionIssuerDeserializer;
+pub(crate) struct AuthenticateOidcActionIssuerDeserializer;
 impl AuthenticateOidcActionIssuerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -897,10 +1250,13 @@ impl AuthenticateOidcActionIssuerDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateOidcActionScopeDeserializer;
+pub(crate) struct AuthenticateOidcActionScopeDeserializer;
 impl AuthenticateOidcActionScopeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -908,10 +1264,13 @@ impl AuthenticateOidcActionScopeDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateOidcActionSessionCookieNameDeserializer;
+pub(crate) struct AuthenticateOidcActionSessionCookieNameDeserializer;
 impl AuthenticateOidcActionSessionCookieNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -919,10 +1278,13 @@ impl AuthenticateOidcActionSessionCookieNameDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateOidcActionSessionTimeoutDeserializer;
+pub(crate) struct AuthenticateOidcActionSessionTimeoutDeserializer;
 impl AuthenticateOidcActionSessionTimeoutDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -930,10 +1292,13 @@ impl AuthenticateOidcActionSessionTimeoutDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateOidcActionTokenEndpointDeserializer;
+pub(crate) struct AuthenticateOidcActionTokenEndpointDeserializer;
 impl AuthenticateOidcActionTokenEndpointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -941,10 +1306,13 @@ impl AuthenticateOidcActionTokenEndpointDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateOidcActionUseExistingClientSecretDeserializer;
+pub(crate) struct AuthenticateOidcActionUseExistingClientSecretDeserializer;
 impl AuthenticateOidcActionUseExistingClientSecretDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -952,10 +1320,13 @@ impl AuthenticateOidcActionUseExistingClientSecretDeserializer {
         Ok(obj)
     }
 }
-struct AuthenticateOidcActionUserInfoEndpointDeserializer;
+pub(crate) struct AuthenticateOidcActionUserInfoEndpointDeserializer;
 impl AuthenticateOidcActionUserInfoEndpointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -974,10 +1345,10 @@ pub struct AvailabilityZone {
     pub zone_name: Option<String>,
 }
 
-struct AvailabilityZoneDeserializer;
+pub(crate) struct AvailabilityZoneDeserializer;
 impl AvailabilityZoneDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AvailabilityZone, XmlParseError> {
@@ -1003,10 +1374,10 @@ impl AvailabilityZoneDeserializer {
         })
     }
 }
-struct AvailabilityZonesDeserializer;
+pub(crate) struct AvailabilityZonesDeserializer;
 impl AvailabilityZonesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AvailabilityZone>, XmlParseError> {
@@ -1020,10 +1391,13 @@ impl AvailabilityZonesDeserializer {
         })
     }
 }
-struct CanonicalHostedZoneIdDeserializer;
+pub(crate) struct CanonicalHostedZoneIdDeserializer;
 impl CanonicalHostedZoneIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1040,10 +1414,10 @@ pub struct Certificate {
     pub is_default: Option<bool>,
 }
 
-struct CertificateDeserializer;
+pub(crate) struct CertificateDeserializer;
 impl CertificateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Certificate, XmlParseError> {
@@ -1083,10 +1457,13 @@ impl CertificateSerializer {
     }
 }
 
-struct CertificateArnDeserializer;
+pub(crate) struct CertificateArnDeserializer;
 impl CertificateArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1094,10 +1471,10 @@ impl CertificateArnDeserializer {
         Ok(obj)
     }
 }
-struct CertificateListDeserializer;
+pub(crate) struct CertificateListDeserializer;
 impl CertificateListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Certificate>, XmlParseError> {
@@ -1132,10 +1509,13 @@ pub struct Cipher {
     pub priority: Option<i64>,
 }
 
-struct CipherDeserializer;
+pub(crate) struct CipherDeserializer;
 impl CipherDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Cipher, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Cipher, XmlParseError> {
         deserialize_elements::<_, Cipher, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Name" => {
@@ -1151,10 +1531,13 @@ impl CipherDeserializer {
         })
     }
 }
-struct CipherNameDeserializer;
+pub(crate) struct CipherNameDeserializer;
 impl CipherNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1162,10 +1545,13 @@ impl CipherNameDeserializer {
         Ok(obj)
     }
 }
-struct CipherPriorityDeserializer;
+pub(crate) struct CipherPriorityDeserializer;
 impl CipherPriorityDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1173,10 +1559,10 @@ impl CipherPriorityDeserializer {
         Ok(obj)
     }
 }
-struct CiphersDeserializer;
+pub(crate) struct CiphersDeserializer;
 impl CiphersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Cipher>, XmlParseError> {
@@ -1190,10 +1576,13 @@ impl CiphersDeserializer {
         })
     }
 }
-struct ConditionFieldNameDeserializer;
+pub(crate) struct ConditionFieldNameDeserializer;
 impl ConditionFieldNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1201,7 +1590,7 @@ impl ConditionFieldNameDeserializer {
         Ok(obj)
     }
 }
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreateListenerInput {
     /// <p>[HTTPS and TLS listeners] The default certificate for the listener. You must provide exactly one certificate. Set <code>CertificateArn</code> to the certificate ARN but do not set <code>IsDefault</code>.</p> <p>To create a certificate list for the listener, use <a>AddListenerCertificates</a>.</p>
     pub certificates: Option<Vec<Certificate>>,
@@ -1212,11 +1601,87 @@ pub struct CreateListenerInput {
     /// <p>The port on which the load balancer is listening.</p>
     pub port: i64,
     /// <p>The protocol for connections from clients to the load balancer. For Application Load Balancers, the supported protocols are HTTP and HTTPS. For Network Load Balancers, the supported protocols are TCP, TLS, UDP, and TCP_UDP.</p>
-    pub protocol: String,
+    pub protocol: ProtocolEnum,
     /// <p>[HTTPS and TLS listeners] The security policy that defines which ciphers and protocols are supported. The default is the current predefined security policy.</p>
     pub ssl_policy: Option<String>,
 }
 
+impl CreateListenerInput {
+    /// Returns a builder for `CreateListenerInput`, checking its required members when built.
+    pub fn builder() -> CreateListenerInputBuilder {
+        CreateListenerInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateListenerInput`](struct.CreateListenerInput.html).
+#[derive(Default, Debug)]
+pub struct CreateListenerInputBuilder {
+    certificates: Option<Vec<Certificate>>,
+    default_actions: Option<Vec<Action>>,
+    load_balancer_arn: Option<String>,
+    port: Option<i64>,
+    protocol: Option<ProtocolEnum>,
+    ssl_policy: Option<String>,
+}
+
+impl CreateListenerInputBuilder {
+    /// Sets the [`certificates`](struct.CreateListenerInput.html#structfield.certificates) member.
+    pub fn certificates<T: Into<Vec<Certificate>>>(mut self, value: T) -> Self {
+        self.certificates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_actions`](struct.CreateListenerInput.html#structfield.default_actions) member.
+    pub fn default_actions<T: Into<Vec<Action>>>(mut self, value: T) -> Self {
+        self.default_actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_arn`](struct.CreateListenerInput.html#structfield.load_balancer_arn) member.
+    pub fn load_balancer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.CreateListenerInput.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`protocol`](struct.CreateListenerInput.html#structfield.protocol) member.
+    pub fn protocol<T: Into<ProtocolEnum>>(mut self, value: T) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssl_policy`](struct.CreateListenerInput.html#structfield.ssl_policy) member.
+    pub fn ssl_policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssl_policy = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateListenerInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateListenerInput, ::rusoto_core::BuildError> {
+        Ok(CreateListenerInput {
+            certificates: self.certificates,
+            default_actions: self
+                .default_actions
+                .ok_or(::rusoto_core::BuildError::missing("default_actions"))?,
+            load_balancer_arn: self
+                .load_balancer_arn
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_arn"))?,
+            port: self
+                .port
+                .ok_or(::rusoto_core::BuildError::missing("port"))?,
+            protocol: self
+                .protocol
+                .ok_or(::rusoto_core::BuildError::missing("protocol"))?,
+            ssl_policy: self.ssl_policy,
+        })
+    }
+}
+
 /// Serialize `CreateListenerInput` contents to a `SignedRequest`.
 struct CreateListenerInputSerializer;
 impl CreateListenerInputSerializer {
@@ -1256,10 +1721,10 @@ pub struct CreateListenerOutput {
     pub listeners: Option<Vec<Listener>>,
 }
 
-struct CreateListenerOutputDeserializer;
+pub(crate) struct CreateListenerOutputDeserializer;
 impl CreateListenerOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateListenerOutput, XmlParseError> {
@@ -1279,11 +1744,11 @@ impl CreateListenerOutputDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct CreateLoadBalancerInput {
     /// <p>[Application Load Balancers] The type of IP addresses used by the subnets for your load balancer. The possible values are <code>ipv4</code> (for IPv4 addresses) and <code>dualstack</code> (for IPv4 and IPv6 addresses). Internal load balancers must use <code>ipv4</code>.</p>
-    pub ip_address_type: Option<String>,
+    pub ip_address_type: Option<IpAddressType>,
     /// <p>The name of the load balancer.</p> <p>This name must be unique per region per account, can have a maximum of 32 characters, must contain only alphanumeric characters or hyphens, must not begin or end with a hyphen, and must not begin with "internal-".</p>
     pub name: String,
     /// <p>The nodes of an Internet-facing load balancer have public IP addresses. The DNS name of an Internet-facing load balancer is publicly resolvable to the public IP addresses of the nodes. Therefore, Internet-facing load balancers can route requests from clients over the internet.</p> <p>The nodes of an internal load balancer have only private IP addresses. The DNS name of an internal load balancer is publicly resolvable to the private IP addresses of the nodes. Therefore, internal load balancers can only route requests from clients with access to the VPC for the load balancer.</p> <p>The default is an Internet-facing load balancer.</p>
-    pub scheme: Option<String>,
+    pub scheme: Option<LoadBalancerSchemeEnum>,
     /// <p>[Application Load Balancers] The IDs of the security groups for the load balancer.</p>
     pub security_groups: Option<Vec<String>>,
     /// <p>The IDs of the public subnets. You can specify only one subnet per Availability Zone. You must specify either subnets or subnet mappings.</p> <p>[Application Load Balancers] You must specify subnets from at least two Availability Zones. You cannot specify Elastic IP addresses for your subnets.</p> <p>[Network Load Balancers] You can specify subnets from one or more Availability Zones. You can specify one Elastic IP address per subnet if you need static IP addresses for your load balancer.</p>
@@ -1293,32 +1758,120 @@ pub struct CreateLoadBalancerInput {
     /// <p>One or more tags to assign to the load balancer.</p>
     pub tags: Option<Vec<Tag>>,
     /// <p>The type of load balancer. The default is <code>application</code>.</p>
-    pub type_: Option<String>,
+    pub type_: Option<LoadBalancerTypeEnum>,
 }
 
-/// Serialize `CreateLoadBalancerInput` contents to a `SignedRequest`.
-struct CreateLoadBalancerInputSerializer;
-impl CreateLoadBalancerInputSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &CreateLoadBalancerInput) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl CreateLoadBalancerInput {
+    /// Returns a builder for `CreateLoadBalancerInput`, checking its required members when built.
+    pub fn builder() -> CreateLoadBalancerInputBuilder {
+        CreateLoadBalancerInputBuilder::default()
+    }
+}
 
-        if let Some(ref field_value) = obj.ip_address_type {
-            params.put(&format!("{}{}", prefix, "IpAddressType"), &field_value);
-        }
-        params.put(&format!("{}{}", prefix, "Name"), &obj.name);
-        if let Some(ref field_value) = obj.scheme {
-            params.put(&format!("{}{}", prefix, "Scheme"), &field_value);
-        }
-        if let Some(ref field_value) = obj.security_groups {
-            SecurityGroupsSerializer::serialize(
-                params,
-                &format!("{}{}", prefix, "SecurityGroups"),
-                field_value,
-            );
-        }
+/// Builds a [`CreateLoadBalancerInput`](struct.CreateLoadBalancerInput.html).
+#[derive(Default, Debug)]
+pub struct CreateLoadBalancerInputBuilder {
+    ip_address_type: Option<IpAddressType>,
+    name: Option<String>,
+    scheme: Option<LoadBalancerSchemeEnum>,
+    security_groups: Option<Vec<String>>,
+    subnet_mappings: Option<Vec<SubnetMapping>>,
+    subnets: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+    type_: Option<LoadBalancerTypeEnum>,
+}
+
+impl CreateLoadBalancerInputBuilder {
+    /// Sets the [`ip_address_type`](struct.CreateLoadBalancerInput.html#structfield.ip_address_type) member.
+    pub fn ip_address_type<T: Into<IpAddressType>>(mut self, value: T) -> Self {
+        self.ip_address_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateLoadBalancerInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheme`](struct.CreateLoadBalancerInput.html#structfield.scheme) member.
+    pub fn scheme<T: Into<LoadBalancerSchemeEnum>>(mut self, value: T) -> Self {
+        self.scheme = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_groups`](struct.CreateLoadBalancerInput.html#structfield.security_groups) member.
+    pub fn security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_mappings`](struct.CreateLoadBalancerInput.html#structfield.subnet_mappings) member.
+    pub fn subnet_mappings<T: Into<Vec<SubnetMapping>>>(mut self, value: T) -> Self {
+        self.subnet_mappings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnets`](struct.CreateLoadBalancerInput.html#structfield.subnets) member.
+    pub fn subnets<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateLoadBalancerInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.CreateLoadBalancerInput.html#structfield.type_) member.
+    pub fn type_<T: Into<LoadBalancerTypeEnum>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLoadBalancerInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLoadBalancerInput, ::rusoto_core::BuildError> {
+        Ok(CreateLoadBalancerInput {
+            ip_address_type: self.ip_address_type,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            scheme: self.scheme,
+            security_groups: self.security_groups,
+            subnet_mappings: self.subnet_mappings,
+            subnets: self.subnets,
+            tags: self.tags,
+            type_: self.type_,
+        })
+    }
+}
+
+/// Serialize `CreateLoadBalancerInput` contents to a `SignedRequest`.
+struct CreateLoadBalancerInputSerializer;
+impl CreateLoadBalancerInputSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &CreateLoadBalancerInput) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.ip_address_type {
+            params.put(&format!("{}{}", prefix, "IpAddressType"), &field_value);
+        }
+        params.put(&format!("{}{}", prefix, "Name"), &obj.name);
+        if let Some(ref field_value) = obj.scheme {
+            params.put(&format!("{}{}", prefix, "Scheme"), &field_value);
+        }
+        if let Some(ref field_value) = obj.security_groups {
+            SecurityGroupsSerializer::serialize(
+                params,
+                &format!("{}{}", prefix, "SecurityGroups"),
+                field_value,
+            );
+        }
         if let Some(ref field_value) = obj.subnet_mappings {
             SubnetMappingsSerializer::serialize(
                 params,
@@ -1344,10 +1897,10 @@ pub struct CreateLoadBalancerOutput {
     pub load_balancers: Option<Vec<LoadBalancer>>,
 }
 
-struct CreateLoadBalancerOutputDeserializer;
+pub(crate) struct CreateLoadBalancerOutputDeserializer;
 impl CreateLoadBalancerOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateLoadBalancerOutput, XmlParseError> {
@@ -1380,6 +1933,66 @@ pub struct CreateRuleInput {
     pub priority: i64,
 }
 
+impl CreateRuleInput {
+    /// Returns a builder for `CreateRuleInput`, checking its required members when built.
+    pub fn builder() -> CreateRuleInputBuilder {
+        CreateRuleInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRuleInput`](struct.CreateRuleInput.html).
+#[derive(Default, Debug)]
+pub struct CreateRuleInputBuilder {
+    actions: Option<Vec<Action>>,
+    conditions: Option<Vec<RuleCondition>>,
+    listener_arn: Option<String>,
+    priority: Option<i64>,
+}
+
+impl CreateRuleInputBuilder {
+    /// Sets the [`actions`](struct.CreateRuleInput.html#structfield.actions) member.
+    pub fn actions<T: Into<Vec<Action>>>(mut self, value: T) -> Self {
+        self.actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conditions`](struct.CreateRuleInput.html#structfield.conditions) member.
+    pub fn conditions<T: Into<Vec<RuleCondition>>>(mut self, value: T) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`listener_arn`](struct.CreateRuleInput.html#structfield.listener_arn) member.
+    pub fn listener_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.listener_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`priority`](struct.CreateRuleInput.html#structfield.priority) member.
+    pub fn priority<T: Into<i64>>(mut self, value: T) -> Self {
+        self.priority = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRuleInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateRuleInput, ::rusoto_core::BuildError> {
+        Ok(CreateRuleInput {
+            actions: self
+                .actions
+                .ok_or(::rusoto_core::BuildError::missing("actions"))?,
+            conditions: self
+                .conditions
+                .ok_or(::rusoto_core::BuildError::missing("conditions"))?,
+            listener_arn: self
+                .listener_arn
+                .ok_or(::rusoto_core::BuildError::missing("listener_arn"))?,
+            priority: self
+                .priority
+                .ok_or(::rusoto_core::BuildError::missing("priority"))?,
+        })
+    }
+}
+
 /// Serialize `CreateRuleInput` contents to a `SignedRequest`.
 struct CreateRuleInputSerializer;
 impl CreateRuleInputSerializer {
@@ -1406,10 +2019,10 @@ pub struct CreateRuleOutput {
     pub rules: Option<Vec<Rule>>,
 }
 
-struct CreateRuleOutputDeserializer;
+pub(crate) struct CreateRuleOutputDeserializer;
 impl CreateRuleOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateRuleOutput, XmlParseError> {
@@ -1437,7 +2050,7 @@ pub struct CreateTargetGroupInput {
     /// <p>The port the load balancer uses when performing health checks on targets. The default is <code>traffic-port</code>, which is the port on which each target receives traffic from the load balancer.</p>
     pub health_check_port: Option<String>,
     /// <p>The protocol the load balancer uses when performing health checks on targets. For Application Load Balancers, the default is HTTP. For Network Load Balancers, the default is TCP. The TCP protocol is supported for health checks only if the protocol of the target group is TCP, TLS, UDP, or TCP_UDP. The TLS, UDP, and TCP_UDP protocols are not supported for health checks.</p>
-    pub health_check_protocol: Option<String>,
+    pub health_check_protocol: Option<ProtocolEnum>,
     /// <p>The amount of time, in seconds, during which no response from a target means a failed health check. For target groups with a protocol of HTTP or HTTPS, the default is 5 seconds. For target groups with a protocol of TCP or TLS, this value must be 6 seconds for HTTP health checks and 10 seconds for TCP and HTTPS health checks. If the target type is <code>lambda</code>, the default is 30 seconds.</p>
     pub health_check_timeout_seconds: Option<i64>,
     /// <p>The number of consecutive health checks successes required before considering an unhealthy target healthy. For target groups with a protocol of HTTP or HTTPS, the default is 5. For target groups with a protocol of TCP or TLS, the default is 3. If the target type is <code>lambda</code>, the default is 5.</p>
@@ -1449,15 +2062,149 @@ pub struct CreateTargetGroupInput {
     /// <p>The port on which the targets receive traffic. This port is used unless you specify a port override when registering the target. If the target is a Lambda function, this parameter does not apply.</p>
     pub port: Option<i64>,
     /// <p>The protocol to use for routing traffic to the targets. For Application Load Balancers, the supported protocols are HTTP and HTTPS. For Network Load Balancers, the supported protocols are TCP, TLS, UDP, or TCP_UDP. A TCP_UDP listener must be associated with a TCP_UDP target group. If the target is a Lambda function, this parameter does not apply.</p>
-    pub protocol: Option<String>,
+    pub protocol: Option<ProtocolEnum>,
     /// <p><p>The type of target that you must specify when registering targets with this target group. You can&#39;t specify targets for a target group using more than one target type.</p> <ul> <li> <p> <code>instance</code> - Targets are specified by instance ID. This is the default value. If the target group protocol is UDP or TCP_UDP, the target type must be <code>instance</code>.</p> </li> <li> <p> <code>ip</code> - Targets are specified by IP address. You can specify IP addresses from the subnets of the virtual private cloud (VPC) for the target group, the RFC 1918 range (10.0.0.0/8, 172.16.0.0/12, and 192.168.0.0/16), and the RFC 6598 range (100.64.0.0/10). You can&#39;t specify publicly routable IP addresses.</p> </li> <li> <p> <code>lambda</code> - The target groups contains a single Lambda function.</p> </li> </ul></p>
-    pub target_type: Option<String>,
+    pub target_type: Option<TargetTypeEnum>,
     /// <p>The number of consecutive health check failures required before considering a target unhealthy. For target groups with a protocol of HTTP or HTTPS, the default is 2. For target groups with a protocol of TCP or TLS, this value must be the same as the healthy threshold count. If the target type is <code>lambda</code>, the default is 2.</p>
     pub unhealthy_threshold_count: Option<i64>,
     /// <p>The identifier of the virtual private cloud (VPC). If the target is a Lambda function, this parameter does not apply. Otherwise, this parameter is required.</p>
     pub vpc_id: Option<String>,
 }
 
+impl CreateTargetGroupInput {
+    /// Returns a builder for `CreateTargetGroupInput`, checking its required members when built.
+    pub fn builder() -> CreateTargetGroupInputBuilder {
+        CreateTargetGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTargetGroupInput`](struct.CreateTargetGroupInput.html).
+#[derive(Default, Debug)]
+pub struct CreateTargetGroupInputBuilder {
+    health_check_enabled: Option<bool>,
+    health_check_interval_seconds: Option<i64>,
+    health_check_path: Option<String>,
+    health_check_port: Option<String>,
+    health_check_protocol: Option<ProtocolEnum>,
+    health_check_timeout_seconds: Option<i64>,
+    healthy_threshold_count: Option<i64>,
+    matcher: Option<Matcher>,
+    name: Option<String>,
+    port: Option<i64>,
+    protocol: Option<ProtocolEnum>,
+    target_type: Option<TargetTypeEnum>,
+    unhealthy_threshold_count: Option<i64>,
+    vpc_id: Option<String>,
+}
+
+impl CreateTargetGroupInputBuilder {
+    /// Sets the [`health_check_enabled`](struct.CreateTargetGroupInput.html#structfield.health_check_enabled) member.
+    pub fn health_check_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.health_check_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_interval_seconds`](struct.CreateTargetGroupInput.html#structfield.health_check_interval_seconds) member.
+    pub fn health_check_interval_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.health_check_interval_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_path`](struct.CreateTargetGroupInput.html#structfield.health_check_path) member.
+    pub fn health_check_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_check_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_port`](struct.CreateTargetGroupInput.html#structfield.health_check_port) member.
+    pub fn health_check_port<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_check_port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_protocol`](struct.CreateTargetGroupInput.html#structfield.health_check_protocol) member.
+    pub fn health_check_protocol<T: Into<ProtocolEnum>>(mut self, value: T) -> Self {
+        self.health_check_protocol = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_timeout_seconds`](struct.CreateTargetGroupInput.html#structfield.health_check_timeout_seconds) member.
+    pub fn health_check_timeout_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.health_check_timeout_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`healthy_threshold_count`](struct.CreateTargetGroupInput.html#structfield.healthy_threshold_count) member.
+    pub fn healthy_threshold_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.healthy_threshold_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`matcher`](struct.CreateTargetGroupInput.html#structfield.matcher) member.
+    pub fn matcher<T: Into<Matcher>>(mut self, value: T) -> Self {
+        self.matcher = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateTargetGroupInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.CreateTargetGroupInput.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`protocol`](struct.CreateTargetGroupInput.html#structfield.protocol) member.
+    pub fn protocol<T: Into<ProtocolEnum>>(mut self, value: T) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_type`](struct.CreateTargetGroupInput.html#structfield.target_type) member.
+    pub fn target_type<T: Into<TargetTypeEnum>>(mut self, value: T) -> Self {
+        self.target_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unhealthy_threshold_count`](struct.CreateTargetGroupInput.html#structfield.unhealthy_threshold_count) member.
+    pub fn unhealthy_threshold_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.unhealthy_threshold_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_id`](struct.CreateTargetGroupInput.html#structfield.vpc_id) member.
+    pub fn vpc_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpc_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTargetGroupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTargetGroupInput, ::rusoto_core::BuildError> {
+        Ok(CreateTargetGroupInput {
+            health_check_enabled: self.health_check_enabled,
+            health_check_interval_seconds: self.health_check_interval_seconds,
+            health_check_path: self.health_check_path,
+            health_check_port: self.health_check_port,
+            health_check_protocol: self.health_check_protocol,
+            health_check_timeout_seconds: self.health_check_timeout_seconds,
+            healthy_threshold_count: self.healthy_threshold_count,
+            matcher: self.matcher,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            port: self.port,
+            protocol: self.protocol,
+            target_type: self.target_type,
+            unhealthy_threshold_count: self.unhealthy_threshold_count,
+            vpc_id: self.vpc_id,
+        })
+    }
+}
+
 /// Serialize `CreateTargetGroupInput` contents to a `SignedRequest`.
 struct CreateTargetGroupInputSerializer;
 impl CreateTargetGroupInputSerializer {
@@ -1531,10 +2278,10 @@ pub struct CreateTargetGroupOutput {
     pub target_groups: Option<Vec<TargetGroup>>,
 }
 
-struct CreateTargetGroupOutputDeserializer;
+pub(crate) struct CreateTargetGroupOutputDeserializer;
 impl CreateTargetGroupOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateTargetGroupOutput, XmlParseError> {
@@ -1555,21 +2302,27 @@ impl CreateTargetGroupOutputDeserializer {
         )
     }
 }
-struct CreatedTimeDeserializer;
+pub(crate) struct CreatedTimeDeserializer;
 impl CreatedTimeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct DNSNameDeserializer;
+pub(crate) struct DNSNameDeserializer;
 impl DNSNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1577,10 +2330,13 @@ impl DNSNameDeserializer {
         Ok(obj)
     }
 }
-struct DefaultDeserializer;
+pub(crate) struct DefaultDeserializer;
 impl DefaultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -1594,6 +2350,36 @@ pub struct DeleteListenerInput {
     pub listener_arn: String,
 }
 
+impl DeleteListenerInput {
+    /// Returns a builder for `DeleteListenerInput`, checking its required members when built.
+    pub fn builder() -> DeleteListenerInputBuilder {
+        DeleteListenerInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteListenerInput`](struct.DeleteListenerInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteListenerInputBuilder {
+    listener_arn: Option<String>,
+}
+
+impl DeleteListenerInputBuilder {
+    /// Sets the [`listener_arn`](struct.DeleteListenerInput.html#structfield.listener_arn) member.
+    pub fn listener_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.listener_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteListenerInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteListenerInput, ::rusoto_core::BuildError> {
+        Ok(DeleteListenerInput {
+            listener_arn: self
+                .listener_arn
+                .ok_or(::rusoto_core::BuildError::missing("listener_arn"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteListenerInput` contents to a `SignedRequest`.
 struct DeleteListenerInputSerializer;
 impl DeleteListenerInputSerializer {
@@ -1610,10 +2396,10 @@ impl DeleteListenerInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteListenerOutput {}
 
-struct DeleteListenerOutputDeserializer;
+pub(crate) struct DeleteListenerOutputDeserializer;
 impl DeleteListenerOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteListenerOutput, XmlParseError> {
@@ -1632,6 +2418,38 @@ pub struct DeleteLoadBalancerInput {
     pub load_balancer_arn: String,
 }
 
+impl DeleteLoadBalancerInput {
+    /// Returns a builder for `DeleteLoadBalancerInput`, checking its required members when built.
+    pub fn builder() -> DeleteLoadBalancerInputBuilder {
+        DeleteLoadBalancerInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLoadBalancerInput`](struct.DeleteLoadBalancerInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteLoadBalancerInputBuilder {
+    load_balancer_arn: Option<String>,
+}
+
+impl DeleteLoadBalancerInputBuilder {
+    /// Sets the [`load_balancer_arn`](struct.DeleteLoadBalancerInput.html#structfield.load_balancer_arn) member.
+    pub fn load_balancer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLoadBalancerInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLoadBalancerInput, ::rusoto_core::BuildError> {
+        Ok(DeleteLoadBalancerInput {
+            load_balancer_arn: self
+                .load_balancer_arn
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_arn"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteLoadBalancerInput` contents to a `SignedRequest`.
 struct DeleteLoadBalancerInputSerializer;
 impl DeleteLoadBalancerInputSerializer {
@@ -1651,10 +2469,10 @@ impl DeleteLoadBalancerInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteLoadBalancerOutput {}
 
-struct DeleteLoadBalancerOutputDeserializer;
+pub(crate) struct DeleteLoadBalancerOutputDeserializer;
 impl DeleteLoadBalancerOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteLoadBalancerOutput, XmlParseError> {
@@ -1673,6 +2491,36 @@ pub struct DeleteRuleInput {
     pub rule_arn: String,
 }
 
+impl DeleteRuleInput {
+    /// Returns a builder for `DeleteRuleInput`, checking its required members when built.
+    pub fn builder() -> DeleteRuleInputBuilder {
+        DeleteRuleInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRuleInput`](struct.DeleteRuleInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteRuleInputBuilder {
+    rule_arn: Option<String>,
+}
+
+impl DeleteRuleInputBuilder {
+    /// Sets the [`rule_arn`](struct.DeleteRuleInput.html#structfield.rule_arn) member.
+    pub fn rule_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRuleInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteRuleInput, ::rusoto_core::BuildError> {
+        Ok(DeleteRuleInput {
+            rule_arn: self
+                .rule_arn
+                .ok_or(::rusoto_core::BuildError::missing("rule_arn"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteRuleInput` contents to a `SignedRequest`.
 struct DeleteRuleInputSerializer;
 impl DeleteRuleInputSerializer {
@@ -1689,10 +2537,10 @@ impl DeleteRuleInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteRuleOutput {}
 
-struct DeleteRuleOutputDeserializer;
+pub(crate) struct DeleteRuleOutputDeserializer;
 impl DeleteRuleOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteRuleOutput, XmlParseError> {
@@ -1711,6 +2559,36 @@ pub struct DeleteTargetGroupInput {
     pub target_group_arn: String,
 }
 
+impl DeleteTargetGroupInput {
+    /// Returns a builder for `DeleteTargetGroupInput`, checking its required members when built.
+    pub fn builder() -> DeleteTargetGroupInputBuilder {
+        DeleteTargetGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTargetGroupInput`](struct.DeleteTargetGroupInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteTargetGroupInputBuilder {
+    target_group_arn: Option<String>,
+}
+
+impl DeleteTargetGroupInputBuilder {
+    /// Sets the [`target_group_arn`](struct.DeleteTargetGroupInput.html#structfield.target_group_arn) member.
+    pub fn target_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_group_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTargetGroupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTargetGroupInput, ::rusoto_core::BuildError> {
+        Ok(DeleteTargetGroupInput {
+            target_group_arn: self
+                .target_group_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_group_arn"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteTargetGroupInput` contents to a `SignedRequest`.
 struct DeleteTargetGroupInputSerializer;
 impl DeleteTargetGroupInputSerializer {
@@ -1730,10 +2608,10 @@ impl DeleteTargetGroupInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteTargetGroupOutput {}
 
-struct DeleteTargetGroupOutputDeserializer;
+pub(crate) struct DeleteTargetGroupOutputDeserializer;
 impl DeleteTargetGroupOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteTargetGroupOutput, XmlParseError> {
@@ -1754,6 +2632,46 @@ pub struct DeregisterTargetsInput {
     pub targets: Vec<TargetDescription>,
 }
 
+impl DeregisterTargetsInput {
+    /// Returns a builder for `DeregisterTargetsInput`, checking its required members when built.
+    pub fn builder() -> DeregisterTargetsInputBuilder {
+        DeregisterTargetsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterTargetsInput`](struct.DeregisterTargetsInput.html).
+#[derive(Default, Debug)]
+pub struct DeregisterTargetsInputBuilder {
+    target_group_arn: Option<String>,
+    targets: Option<Vec<TargetDescription>>,
+}
+
+impl DeregisterTargetsInputBuilder {
+    /// Sets the [`target_group_arn`](struct.DeregisterTargetsInput.html#structfield.target_group_arn) member.
+    pub fn target_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`targets`](struct.DeregisterTargetsInput.html#structfield.targets) member.
+    pub fn targets<T: Into<Vec<TargetDescription>>>(mut self, value: T) -> Self {
+        self.targets = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterTargetsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeregisterTargetsInput, ::rusoto_core::BuildError> {
+        Ok(DeregisterTargetsInput {
+            target_group_arn: self
+                .target_group_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_group_arn"))?,
+            targets: self
+                .targets
+                .ok_or(::rusoto_core::BuildError::missing("targets"))?,
+        })
+    }
+}
+
 /// Serialize `DeregisterTargetsInput` contents to a `SignedRequest`.
 struct DeregisterTargetsInputSerializer;
 impl DeregisterTargetsInputSerializer {
@@ -1778,10 +2696,10 @@ impl DeregisterTargetsInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeregisterTargetsOutput {}
 
-struct DeregisterTargetsOutputDeserializer;
+pub(crate) struct DeregisterTargetsOutputDeserializer;
 impl DeregisterTargetsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeregisterTargetsOutput, XmlParseError> {
@@ -1802,6 +2720,44 @@ pub struct DescribeAccountLimitsInput {
     pub page_size: Option<i64>,
 }
 
+impl DescribeAccountLimitsInput {
+    /// Returns a builder for `DescribeAccountLimitsInput`, checking its required members when built.
+    pub fn builder() -> DescribeAccountLimitsInputBuilder {
+        DescribeAccountLimitsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAccountLimitsInput`](struct.DescribeAccountLimitsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeAccountLimitsInputBuilder {
+    marker: Option<String>,
+    page_size: Option<i64>,
+}
+
+impl DescribeAccountLimitsInputBuilder {
+    /// Sets the [`marker`](struct.DescribeAccountLimitsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.DescribeAccountLimitsInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAccountLimitsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAccountLimitsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeAccountLimitsInput {
+            marker: self.marker,
+            page_size: self.page_size,
+        })
+    }
+}
+
 /// Serialize `DescribeAccountLimitsInput` contents to a `SignedRequest`.
 struct DescribeAccountLimitsInputSerializer;
 impl DescribeAccountLimitsInputSerializer {
@@ -1828,10 +2784,10 @@ pub struct DescribeAccountLimitsOutput {
     pub next_marker: Option<String>,
 }
 
-struct DescribeAccountLimitsOutputDeserializer;
+pub(crate) struct DescribeAccountLimitsOutputDeserializer;
 impl DescribeAccountLimitsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeAccountLimitsOutput, XmlParseError> {
@@ -1866,6 +2822,54 @@ pub struct DescribeListenerCertificatesInput {
     pub page_size: Option<i64>,
 }
 
+impl DescribeListenerCertificatesInput {
+    /// Returns a builder for `DescribeListenerCertificatesInput`, checking its required members when built.
+    pub fn builder() -> DescribeListenerCertificatesInputBuilder {
+        DescribeListenerCertificatesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeListenerCertificatesInput`](struct.DescribeListenerCertificatesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeListenerCertificatesInputBuilder {
+    listener_arn: Option<String>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+}
+
+impl DescribeListenerCertificatesInputBuilder {
+    /// Sets the [`listener_arn`](struct.DescribeListenerCertificatesInput.html#structfield.listener_arn) member.
+    pub fn listener_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.listener_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeListenerCertificatesInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.DescribeListenerCertificatesInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeListenerCertificatesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeListenerCertificatesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeListenerCertificatesInput {
+            listener_arn: self
+                .listener_arn
+                .ok_or(::rusoto_core::BuildError::missing("listener_arn"))?,
+            marker: self.marker,
+            page_size: self.page_size,
+        })
+    }
+}
+
 /// Serialize `DescribeListenerCertificatesInput` contents to a `SignedRequest`.
 struct DescribeListenerCertificatesInputSerializer;
 impl DescribeListenerCertificatesInputSerializer {
@@ -1893,10 +2897,10 @@ pub struct DescribeListenerCertificatesOutput {
     pub next_marker: Option<String>,
 }
 
-struct DescribeListenerCertificatesOutputDeserializer;
+pub(crate) struct DescribeListenerCertificatesOutputDeserializer;
 impl DescribeListenerCertificatesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeListenerCertificatesOutput, XmlParseError> {
@@ -1933,6 +2937,58 @@ pub struct DescribeListenersInput {
     pub page_size: Option<i64>,
 }
 
+impl DescribeListenersInput {
+    /// Returns a builder for `DescribeListenersInput`, checking its required members when built.
+    pub fn builder() -> DescribeListenersInputBuilder {
+        DescribeListenersInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeListenersInput`](struct.DescribeListenersInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeListenersInputBuilder {
+    listener_arns: Option<Vec<String>>,
+    load_balancer_arn: Option<String>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+}
+
+impl DescribeListenersInputBuilder {
+    /// Sets the [`listener_arns`](struct.DescribeListenersInput.html#structfield.listener_arns) member.
+    pub fn listener_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.listener_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_arn`](struct.DescribeListenersInput.html#structfield.load_balancer_arn) member.
+    pub fn load_balancer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeListenersInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.DescribeListenersInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeListenersInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeListenersInput, ::rusoto_core::BuildError> {
+        Ok(DescribeListenersInput {
+            listener_arns: self.listener_arns,
+            load_balancer_arn: self.load_balancer_arn,
+            marker: self.marker,
+            page_size: self.page_size,
+        })
+    }
+}
+
 /// Serialize `DescribeListenersInput` contents to a `SignedRequest`.
 struct DescribeListenersInputSerializer;
 impl DescribeListenersInputSerializer {
@@ -1969,10 +3025,10 @@ pub struct DescribeListenersOutput {
     pub next_marker: Option<String>,
 }
 
-struct DescribeListenersOutputDeserializer;
+pub(crate) struct DescribeListenersOutputDeserializer;
 impl DescribeListenersOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeListenersOutput, XmlParseError> {
@@ -2003,6 +3059,38 @@ pub struct DescribeLoadBalancerAttributesInput {
     pub load_balancer_arn: String,
 }
 
+impl DescribeLoadBalancerAttributesInput {
+    /// Returns a builder for `DescribeLoadBalancerAttributesInput`, checking its required members when built.
+    pub fn builder() -> DescribeLoadBalancerAttributesInputBuilder {
+        DescribeLoadBalancerAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLoadBalancerAttributesInput`](struct.DescribeLoadBalancerAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeLoadBalancerAttributesInputBuilder {
+    load_balancer_arn: Option<String>,
+}
+
+impl DescribeLoadBalancerAttributesInputBuilder {
+    /// Sets the [`load_balancer_arn`](struct.DescribeLoadBalancerAttributesInput.html#structfield.load_balancer_arn) member.
+    pub fn load_balancer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLoadBalancerAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLoadBalancerAttributesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeLoadBalancerAttributesInput {
+            load_balancer_arn: self
+                .load_balancer_arn
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_arn"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeLoadBalancerAttributesInput` contents to a `SignedRequest`.
 struct DescribeLoadBalancerAttributesInputSerializer;
 impl DescribeLoadBalancerAttributesInputSerializer {
@@ -2025,10 +3113,10 @@ pub struct DescribeLoadBalancerAttributesOutput {
     pub attributes: Option<Vec<LoadBalancerAttribute>>,
 }
 
-struct DescribeLoadBalancerAttributesOutputDeserializer;
+pub(crate) struct DescribeLoadBalancerAttributesOutputDeserializer;
 impl DescribeLoadBalancerAttributesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeLoadBalancerAttributesOutput, XmlParseError> {
@@ -2061,6 +3149,60 @@ pub struct DescribeLoadBalancersInput {
     pub page_size: Option<i64>,
 }
 
+impl DescribeLoadBalancersInput {
+    /// Returns a builder for `DescribeLoadBalancersInput`, checking its required members when built.
+    pub fn builder() -> DescribeLoadBalancersInputBuilder {
+        DescribeLoadBalancersInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLoadBalancersInput`](struct.DescribeLoadBalancersInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeLoadBalancersInputBuilder {
+    load_balancer_arns: Option<Vec<String>>,
+    marker: Option<String>,
+    names: Option<Vec<String>>,
+    page_size: Option<i64>,
+}
+
+impl DescribeLoadBalancersInputBuilder {
+    /// Sets the [`load_balancer_arns`](struct.DescribeLoadBalancersInput.html#structfield.load_balancer_arns) member.
+    pub fn load_balancer_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.load_balancer_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeLoadBalancersInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`names`](struct.DescribeLoadBalancersInput.html#structfield.names) member.
+    pub fn names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.DescribeLoadBalancersInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLoadBalancersInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLoadBalancersInput, ::rusoto_core::BuildError> {
+        Ok(DescribeLoadBalancersInput {
+            load_balancer_arns: self.load_balancer_arns,
+            marker: self.marker,
+            names: self.names,
+            page_size: self.page_size,
+        })
+    }
+}
+
 /// Serialize `DescribeLoadBalancersInput` contents to a `SignedRequest`.
 struct DescribeLoadBalancersInputSerializer;
 impl DescribeLoadBalancersInputSerializer {
@@ -2101,10 +3243,10 @@ pub struct DescribeLoadBalancersOutput {
     pub next_marker: Option<String>,
 }
 
-struct DescribeLoadBalancersOutputDeserializer;
+pub(crate) struct DescribeLoadBalancersOutputDeserializer;
 impl DescribeLoadBalancersOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeLoadBalancersOutput, XmlParseError> {
@@ -2141,6 +3283,58 @@ pub struct DescribeRulesInput {
     pub rule_arns: Option<Vec<String>>,
 }
 
+impl DescribeRulesInput {
+    /// Returns a builder for `DescribeRulesInput`, checking its required members when built.
+    pub fn builder() -> DescribeRulesInputBuilder {
+        DescribeRulesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRulesInput`](struct.DescribeRulesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeRulesInputBuilder {
+    listener_arn: Option<String>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+    rule_arns: Option<Vec<String>>,
+}
+
+impl DescribeRulesInputBuilder {
+    /// Sets the [`listener_arn`](struct.DescribeRulesInput.html#structfield.listener_arn) member.
+    pub fn listener_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.listener_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeRulesInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.DescribeRulesInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_arns`](struct.DescribeRulesInput.html#structfield.rule_arns) member.
+    pub fn rule_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.rule_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRulesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeRulesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeRulesInput {
+            listener_arn: self.listener_arn,
+            marker: self.marker,
+            page_size: self.page_size,
+            rule_arns: self.rule_arns,
+        })
+    }
+}
+
 /// Serialize `DescribeRulesInput` contents to a `SignedRequest`.
 struct DescribeRulesInputSerializer;
 impl DescribeRulesInputSerializer {
@@ -2177,10 +3371,10 @@ pub struct DescribeRulesOutput {
     pub rules: Option<Vec<Rule>>,
 }
 
-struct DescribeRulesOutputDeserializer;
+pub(crate) struct DescribeRulesOutputDeserializer;
 impl DescribeRulesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeRulesOutput, XmlParseError> {
@@ -2210,6 +3404,52 @@ pub struct DescribeSSLPoliciesInput {
     pub page_size: Option<i64>,
 }
 
+impl DescribeSSLPoliciesInput {
+    /// Returns a builder for `DescribeSSLPoliciesInput`, checking its required members when built.
+    pub fn builder() -> DescribeSSLPoliciesInputBuilder {
+        DescribeSSLPoliciesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSSLPoliciesInput`](struct.DescribeSSLPoliciesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeSSLPoliciesInputBuilder {
+    marker: Option<String>,
+    names: Option<Vec<String>>,
+    page_size: Option<i64>,
+}
+
+impl DescribeSSLPoliciesInputBuilder {
+    /// Sets the [`marker`](struct.DescribeSSLPoliciesInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`names`](struct.DescribeSSLPoliciesInput.html#structfield.names) member.
+    pub fn names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.DescribeSSLPoliciesInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSSLPoliciesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSSLPoliciesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeSSLPoliciesInput {
+            marker: self.marker,
+            names: self.names,
+            page_size: self.page_size,
+        })
+    }
+}
+
 /// Serialize `DescribeSSLPoliciesInput` contents to a `SignedRequest`.
 struct DescribeSSLPoliciesInputSerializer;
 impl DescribeSSLPoliciesInputSerializer {
@@ -2243,10 +3483,10 @@ pub struct DescribeSSLPoliciesOutput {
     pub ssl_policies: Option<Vec<SslPolicy>>,
 }
 
-struct DescribeSSLPoliciesOutputDeserializer;
+pub(crate) struct DescribeSSLPoliciesOutputDeserializer;
 impl DescribeSSLPoliciesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeSSLPoliciesOutput, XmlParseError> {
@@ -2277,6 +3517,36 @@ pub struct DescribeTagsInput {
     pub resource_arns: Vec<String>,
 }
 
+impl DescribeTagsInput {
+    /// Returns a builder for `DescribeTagsInput`, checking its required members when built.
+    pub fn builder() -> DescribeTagsInputBuilder {
+        DescribeTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTagsInput`](struct.DescribeTagsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeTagsInputBuilder {
+    resource_arns: Option<Vec<String>>,
+}
+
+impl DescribeTagsInputBuilder {
+    /// Sets the [`resource_arns`](struct.DescribeTagsInput.html#structfield.resource_arns) member.
+    pub fn resource_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTagsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeTagsInput {
+            resource_arns: self
+                .resource_arns
+                .ok_or(::rusoto_core::BuildError::missing("resource_arns"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeTagsInput` contents to a `SignedRequest`.
 struct DescribeTagsInputSerializer;
 impl DescribeTagsInputSerializer {
@@ -2300,10 +3570,10 @@ pub struct DescribeTagsOutput {
     pub tag_descriptions: Option<Vec<TagDescription>>,
 }
 
-struct DescribeTagsOutputDeserializer;
+pub(crate) struct DescribeTagsOutputDeserializer;
 impl DescribeTagsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeTagsOutput, XmlParseError> {
@@ -2326,6 +3596,38 @@ pub struct DescribeTargetGroupAttributesInput {
     pub target_group_arn: String,
 }
 
+impl DescribeTargetGroupAttributesInput {
+    /// Returns a builder for `DescribeTargetGroupAttributesInput`, checking its required members when built.
+    pub fn builder() -> DescribeTargetGroupAttributesInputBuilder {
+        DescribeTargetGroupAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTargetGroupAttributesInput`](struct.DescribeTargetGroupAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeTargetGroupAttributesInputBuilder {
+    target_group_arn: Option<String>,
+}
+
+impl DescribeTargetGroupAttributesInputBuilder {
+    /// Sets the [`target_group_arn`](struct.DescribeTargetGroupAttributesInput.html#structfield.target_group_arn) member.
+    pub fn target_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_group_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTargetGroupAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTargetGroupAttributesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeTargetGroupAttributesInput {
+            target_group_arn: self
+                .target_group_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_group_arn"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeTargetGroupAttributesInput` contents to a `SignedRequest`.
 struct DescribeTargetGroupAttributesInputSerializer;
 impl DescribeTargetGroupAttributesInputSerializer {
@@ -2348,10 +3650,10 @@ pub struct DescribeTargetGroupAttributesOutput {
     pub attributes: Option<Vec<TargetGroupAttribute>>,
 }
 
-struct DescribeTargetGroupAttributesOutputDeserializer;
+pub(crate) struct DescribeTargetGroupAttributesOutputDeserializer;
 impl DescribeTargetGroupAttributesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeTargetGroupAttributesOutput, XmlParseError> {
@@ -2386,6 +3688,68 @@ pub struct DescribeTargetGroupsInput {
     pub target_group_arns: Option<Vec<String>>,
 }
 
+impl DescribeTargetGroupsInput {
+    /// Returns a builder for `DescribeTargetGroupsInput`, checking its required members when built.
+    pub fn builder() -> DescribeTargetGroupsInputBuilder {
+        DescribeTargetGroupsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTargetGroupsInput`](struct.DescribeTargetGroupsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeTargetGroupsInputBuilder {
+    load_balancer_arn: Option<String>,
+    marker: Option<String>,
+    names: Option<Vec<String>>,
+    page_size: Option<i64>,
+    target_group_arns: Option<Vec<String>>,
+}
+
+impl DescribeTargetGroupsInputBuilder {
+    /// Sets the [`load_balancer_arn`](struct.DescribeTargetGroupsInput.html#structfield.load_balancer_arn) member.
+    pub fn load_balancer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeTargetGroupsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`names`](struct.DescribeTargetGroupsInput.html#structfield.names) member.
+    pub fn names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.DescribeTargetGroupsInput.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_group_arns`](struct.DescribeTargetGroupsInput.html#structfield.target_group_arns) member.
+    pub fn target_group_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.target_group_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTargetGroupsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTargetGroupsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeTargetGroupsInput {
+            load_balancer_arn: self.load_balancer_arn,
+            marker: self.marker,
+            names: self.names,
+            page_size: self.page_size,
+            target_group_arns: self.target_group_arns,
+        })
+    }
+}
+
 /// Serialize `DescribeTargetGroupsInput` contents to a `SignedRequest`.
 struct DescribeTargetGroupsInputSerializer;
 impl DescribeTargetGroupsInputSerializer {
@@ -2429,10 +3793,10 @@ pub struct DescribeTargetGroupsOutput {
     pub target_groups: Option<Vec<TargetGroup>>,
 }
 
-struct DescribeTargetGroupsOutputDeserializer;
+pub(crate) struct DescribeTargetGroupsOutputDeserializer;
 impl DescribeTargetGroupsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeTargetGroupsOutput, XmlParseError> {
@@ -2457,13 +3821,53 @@ impl DescribeTargetGroupsOutputDeserializer {
         )
     }
 }
-#[derive(Default, Debug, Clone, PartialEq)]
-pub struct DescribeTargetHealthInput {
-    /// <p>The Amazon Resource Name (ARN) of the target group.</p>
-    pub target_group_arn: String,
-    /// <p>The targets.</p>
-    pub targets: Option<Vec<TargetDescription>>,
-}
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct DescribeTargetHealthInput {
+    /// <p>The Amazon Resource Name (ARN) of the target group.</p>
+    pub target_group_arn: String,
+    /// <p>The targets.</p>
+    pub targets: Option<Vec<TargetDescription>>,
+}
+
+impl DescribeTargetHealthInput {
+    /// Returns a builder for `DescribeTargetHealthInput`, checking its required members when built.
+    pub fn builder() -> DescribeTargetHealthInputBuilder {
+        DescribeTargetHealthInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTargetHealthInput`](struct.DescribeTargetHealthInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeTargetHealthInputBuilder {
+    target_group_arn: Option<String>,
+    targets: Option<Vec<TargetDescription>>,
+}
+
+impl DescribeTargetHealthInputBuilder {
+    /// Sets the [`target_group_arn`](struct.DescribeTargetHealthInput.html#structfield.target_group_arn) member.
+    pub fn target_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`targets`](struct.DescribeTargetHealthInput.html#structfield.targets) member.
+    pub fn targets<T: Into<Vec<TargetDescription>>>(mut self, value: T) -> Self {
+        self.targets = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTargetHealthInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTargetHealthInput, ::rusoto_core::BuildError> {
+        Ok(DescribeTargetHealthInput {
+            target_group_arn: self
+                .target_group_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_group_arn"))?,
+            targets: self.targets,
+        })
+    }
+}
 
 /// Serialize `DescribeTargetHealthInput` contents to a `SignedRequest`.
 struct DescribeTargetHealthInputSerializer;
@@ -2494,10 +3898,10 @@ pub struct DescribeTargetHealthOutput {
     pub target_health_descriptions: Option<Vec<TargetHealthDescription>>,
 }
 
-struct DescribeTargetHealthOutputDeserializer;
+pub(crate) struct DescribeTargetHealthOutputDeserializer;
 impl DescribeTargetHealthOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeTargetHealthOutput, XmlParseError> {
@@ -2521,10 +3925,13 @@ impl DescribeTargetHealthOutputDeserializer {
         )
     }
 }
-struct DescriptionDeserializer;
+pub(crate) struct DescriptionDeserializer;
 impl DescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2543,10 +3950,10 @@ pub struct FixedResponseActionConfig {
     pub status_code: String,
 }
 
-struct FixedResponseActionConfigDeserializer;
+pub(crate) struct FixedResponseActionConfigDeserializer;
 impl FixedResponseActionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FixedResponseActionConfig, XmlParseError> {
@@ -2602,10 +4009,13 @@ impl FixedResponseActionConfigSerializer {
     }
 }
 
-struct FixedResponseActionContentTypeDeserializer;
+pub(crate) struct FixedResponseActionContentTypeDeserializer;
 impl FixedResponseActionContentTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2613,10 +4023,13 @@ impl FixedResponseActionContentTypeDeserializer {
         Ok(obj)
     }
 }
-struct FixedResponseActionMessageDeserializer;
+pub(crate) struct FixedResponseActionMessageDeserializer;
 impl FixedResponseActionMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2624,10 +4037,13 @@ impl FixedResponseActionMessageDeserializer {
         Ok(obj)
     }
 }
-struct FixedResponseActionStatusCodeDeserializer;
+pub(crate) struct FixedResponseActionStatusCodeDeserializer;
 impl FixedResponseActionStatusCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2635,10 +4051,13 @@ impl FixedResponseActionStatusCodeDeserializer {
         Ok(obj)
     }
 }
-struct HealthCheckEnabledDeserializer;
+pub(crate) struct HealthCheckEnabledDeserializer;
 impl HealthCheckEnabledDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2646,10 +4065,13 @@ impl HealthCheckEnabledDeserializer {
         Ok(obj)
     }
 }
-struct HealthCheckIntervalSecondsDeserializer;
+pub(crate) struct HealthCheckIntervalSecondsDeserializer;
 impl HealthCheckIntervalSecondsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2657,10 +4079,13 @@ impl HealthCheckIntervalSecondsDeserializer {
         Ok(obj)
     }
 }
-struct HealthCheckPortDeserializer;
+pub(crate) struct HealthCheckPortDeserializer;
 impl HealthCheckPortDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2668,10 +4093,13 @@ impl HealthCheckPortDeserializer {
         Ok(obj)
     }
 }
-struct HealthCheckThresholdCountDeserializer;
+pub(crate) struct HealthCheckThresholdCountDeserializer;
 impl HealthCheckThresholdCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2679,10 +4107,13 @@ impl HealthCheckThresholdCountDeserializer {
         Ok(obj)
     }
 }
-struct HealthCheckTimeoutSecondsDeserializer;
+pub(crate) struct HealthCheckTimeoutSecondsDeserializer;
 impl HealthCheckTimeoutSecondsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2697,10 +4128,10 @@ pub struct HostHeaderConditionConfig {
     pub values: Option<Vec<String>>,
 }
 
-struct HostHeaderConditionConfigDeserializer;
+pub(crate) struct HostHeaderConditionConfigDeserializer;
 impl HostHeaderConditionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HostHeaderConditionConfig, XmlParseError> {
@@ -2741,10 +4172,13 @@ impl HostHeaderConditionConfigSerializer {
     }
 }
 
-struct HttpCodeDeserializer;
+pub(crate) struct HttpCodeDeserializer;
 impl HttpCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2761,10 +4195,10 @@ pub struct HttpHeaderConditionConfig {
     pub values: Option<Vec<String>>,
 }
 
-struct HttpHeaderConditionConfigDeserializer;
+pub(crate) struct HttpHeaderConditionConfigDeserializer;
 impl HttpHeaderConditionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HttpHeaderConditionConfig, XmlParseError> {
@@ -2815,10 +4249,13 @@ impl HttpHeaderConditionConfigSerializer {
     }
 }
 
-struct HttpHeaderConditionNameDeserializer;
+pub(crate) struct HttpHeaderConditionNameDeserializer;
 impl HttpHeaderConditionNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2833,10 +4270,10 @@ pub struct HttpRequestMethodConditionConfig {
     pub values: Option<Vec<String>>,
 }
 
-struct HttpRequestMethodConditionConfigDeserializer;
+pub(crate) struct HttpRequestMethodConditionConfigDeserializer;
 impl HttpRequestMethodConditionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HttpRequestMethodConditionConfig, XmlParseError> {
@@ -2877,10 +4314,13 @@ impl HttpRequestMethodConditionConfigSerializer {
     }
 }
 
-struct IpAddressDeserializer;
+pub(crate) struct IpAddressDeserializer;
 impl IpAddressDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2888,21 +4328,86 @@ impl IpAddressDeserializer {
         Ok(obj)
     }
 }
-struct IpAddressTypeDeserializer;
+pub(crate) struct IpAddressTypeDeserializer;
 impl IpAddressTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IpAddressType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct IsDefaultDeserializer;
+/// <p>[Application Load Balancers] The type of IP addresses used by the subnets for your load balancer. The possible values are <code>ipv4</code> (for IPv4 addresses) and <code>dualstack</code> (for IPv4 and IPv6 addresses). Internal load balancers must use <code>ipv4</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IpAddressType {
+    /// <p><code>ipv4</code></p>
+    Ipv4,
+    /// <p><code>dualstack</code></p>
+    Dualstack,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IpAddressType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpAddressType::Ipv4 => "ipv4",
+            IpAddressType::Dualstack => "dualstack",
+            IpAddressType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IpAddressType {
+    fn from(value: &'a str) -> IpAddressType {
+        match value {
+            "ipv4" => IpAddressType::Ipv4,
+            "dualstack" => IpAddressType::Dualstack,
+            _ => IpAddressType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpAddressType {
+    fn from(value: String) -> IpAddressType {
+        IpAddressType::from(value.as_str())
+    }
+}
+
+impl From<IpAddressType> for String {
+    fn from(value: IpAddressType) -> String {
+        match value {
+            IpAddressType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IpAddressType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IpAddressType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct IsDefaultDeserializer;
 impl IsDefaultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -2919,10 +4424,13 @@ pub struct Limit {
     pub name: Option<String>,
 }
 
-struct LimitDeserializer;
+pub(crate) struct LimitDeserializer;
 impl LimitDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Limit, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Limit, XmlParseError> {
         deserialize_elements::<_, Limit, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Max" => {
@@ -2937,10 +4445,10 @@ impl LimitDeserializer {
         })
     }
 }
-struct LimitsDeserializer;
+pub(crate) struct LimitsDeserializer;
 impl LimitsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Limit>, XmlParseError> {
@@ -2954,10 +4462,10 @@ impl LimitsDeserializer {
         })
     }
 }
-struct ListOfStringDeserializer;
+pub(crate) struct ListOfStringDeserializer;
 impl ListOfStringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -2997,15 +4505,15 @@ pub struct Listener {
     /// <p>The port on which the load balancer is listening.</p>
     pub port: Option<i64>,
     /// <p>The protocol for connections from clients to the load balancer.</p>
-    pub protocol: Option<String>,
+    pub protocol: Option<ProtocolEnum>,
     /// <p>[HTTPS or TLS listener] The security policy that defines which ciphers and protocols are supported. The default is the current predefined security policy.</p>
     pub ssl_policy: Option<String>,
 }
 
-struct ListenerDeserializer;
+pub(crate) struct ListenerDeserializer;
 impl ListenerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Listener, XmlParseError> {
@@ -3047,10 +4555,13 @@ impl ListenerDeserializer {
         })
     }
 }
-struct ListenerArnDeserializer;
+pub(crate) struct ListenerArnDeserializer;
 impl ListenerArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3070,10 +4581,10 @@ impl ListenerArnsSerializer {
     }
 }
 
-struct ListenersDeserializer;
+pub(crate) struct ListenersDeserializer;
 impl ListenersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Listener>, XmlParseError> {
@@ -3095,31 +4606,31 @@ pub struct LoadBalancer {
     /// <p>The ID of the Amazon Route 53 hosted zone associated with the load balancer.</p>
     pub canonical_hosted_zone_id: Option<String>,
     /// <p>The date and time the load balancer was created.</p>
-    pub created_time: Option<String>,
+    pub created_time: Option<IsoTimestamp>,
     /// <p>The public DNS name of the load balancer.</p>
     pub dns_name: Option<String>,
     /// <p>The type of IP addresses used by the subnets for your load balancer. The possible values are <code>ipv4</code> (for IPv4 addresses) and <code>dualstack</code> (for IPv4 and IPv6 addresses).</p>
-    pub ip_address_type: Option<String>,
+    pub ip_address_type: Option<IpAddressType>,
     /// <p>The Amazon Resource Name (ARN) of the load balancer.</p>
     pub load_balancer_arn: Option<String>,
     /// <p>The name of the load balancer.</p>
     pub load_balancer_name: Option<String>,
     /// <p>The nodes of an Internet-facing load balancer have public IP addresses. The DNS name of an Internet-facing load balancer is publicly resolvable to the public IP addresses of the nodes. Therefore, Internet-facing load balancers can route requests from clients over the internet.</p> <p>The nodes of an internal load balancer have only private IP addresses. The DNS name of an internal load balancer is publicly resolvable to the private IP addresses of the nodes. Therefore, internal load balancers can only route requests from clients with access to the VPC for the load balancer.</p>
-    pub scheme: Option<String>,
+    pub scheme: Option<LoadBalancerSchemeEnum>,
     /// <p>The IDs of the security groups for the load balancer.</p>
     pub security_groups: Option<Vec<String>>,
     /// <p>The state of the load balancer.</p>
     pub state: Option<LoadBalancerState>,
     /// <p>The type of load balancer.</p>
-    pub type_: Option<String>,
+    pub type_: Option<LoadBalancerTypeEnum>,
     /// <p>The ID of the VPC for the load balancer.</p>
     pub vpc_id: Option<String>,
 }
 
-struct LoadBalancerDeserializer;
+pub(crate) struct LoadBalancerDeserializer;
 impl LoadBalancerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoadBalancer, XmlParseError> {
@@ -3198,10 +4709,10 @@ pub struct LoadBalancerAddress {
     pub ip_address: Option<String>,
 }
 
-struct LoadBalancerAddressDeserializer;
+pub(crate) struct LoadBalancerAddressDeserializer;
 impl LoadBalancerAddressDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoadBalancerAddress, XmlParseError> {
@@ -3222,10 +4733,10 @@ impl LoadBalancerAddressDeserializer {
         })
     }
 }
-struct LoadBalancerAddressesDeserializer;
+pub(crate) struct LoadBalancerAddressesDeserializer;
 impl LoadBalancerAddressesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LoadBalancerAddress>, XmlParseError> {
@@ -3241,10 +4752,13 @@ impl LoadBalancerAddressesDeserializer {
         })
     }
 }
-struct LoadBalancerArnDeserializer;
+pub(crate) struct LoadBalancerArnDeserializer;
 impl LoadBalancerArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3252,10 +4766,10 @@ impl LoadBalancerArnDeserializer {
         Ok(obj)
     }
 }
-struct LoadBalancerArnsDeserializer;
+pub(crate) struct LoadBalancerArnsDeserializer;
 impl LoadBalancerArnsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -3290,10 +4804,10 @@ pub struct LoadBalancerAttribute {
     pub value: Option<String>,
 }
 
-struct LoadBalancerAttributeDeserializer;
+pub(crate) struct LoadBalancerAttributeDeserializer;
 impl LoadBalancerAttributeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoadBalancerAttribute, XmlParseError> {
@@ -3334,10 +4848,13 @@ impl LoadBalancerAttributeSerializer {
     }
 }
 
-struct LoadBalancerAttributeKeyDeserializer;
+pub(crate) struct LoadBalancerAttributeKeyDeserializer;
 impl LoadBalancerAttributeKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3345,10 +4862,13 @@ impl LoadBalancerAttributeKeyDeserializer {
         Ok(obj)
     }
 }
-struct LoadBalancerAttributeValueDeserializer;
+pub(crate) struct LoadBalancerAttributeValueDeserializer;
 impl LoadBalancerAttributeValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3356,10 +4876,10 @@ impl LoadBalancerAttributeValueDeserializer {
         Ok(obj)
     }
 }
-struct LoadBalancerAttributesDeserializer;
+pub(crate) struct LoadBalancerAttributesDeserializer;
 impl LoadBalancerAttributesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LoadBalancerAttribute>, XmlParseError> {
@@ -3387,10 +4907,13 @@ impl LoadBalancerAttributesSerializer {
     }
 }
 
-struct LoadBalancerNameDeserializer;
+pub(crate) struct LoadBalancerNameDeserializer;
 impl LoadBalancerNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3410,30 +4933,92 @@ impl LoadBalancerNamesSerializer {
     }
 }
 
-struct LoadBalancerSchemeEnumDeserializer;
+pub(crate) struct LoadBalancerSchemeEnumDeserializer;
 impl LoadBalancerSchemeEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<LoadBalancerSchemeEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The nodes of an Internet-facing load balancer have public IP addresses. The DNS name of an Internet-facing load balancer is publicly resolvable to the public IP addresses of the nodes. Therefore, Internet-facing load balancers can route requests from clients over the internet.</p> <p>The nodes of an internal load balancer have only private IP addresses. The DNS name of an internal load balancer is publicly resolvable to the private IP addresses of the nodes. Therefore, internal load balancers can only route requests from clients with access to the VPC for the load balancer.</p> <p>The default is an Internet-facing load balancer.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadBalancerSchemeEnum {
+    /// <p><code>internet-facing</code></p>
+    InternetFacing,
+    /// <p><code>internal</code></p>
+    Internal,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadBalancerSchemeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerSchemeEnum::InternetFacing => "internet-facing",
+            LoadBalancerSchemeEnum::Internal => "internal",
+            LoadBalancerSchemeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerSchemeEnum {
+    fn from(value: &'a str) -> LoadBalancerSchemeEnum {
+        match value {
+            "internet-facing" => LoadBalancerSchemeEnum::InternetFacing,
+            "internal" => LoadBalancerSchemeEnum::Internal,
+            _ => LoadBalancerSchemeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerSchemeEnum {
+    fn from(value: String) -> LoadBalancerSchemeEnum {
+        LoadBalancerSchemeEnum::from(value.as_str())
+    }
+}
+
+impl From<LoadBalancerSchemeEnum> for String {
+    fn from(value: LoadBalancerSchemeEnum) -> String {
+        match value {
+            LoadBalancerSchemeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadBalancerSchemeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerSchemeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Information about the state of the load balancer.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct LoadBalancerState {
     /// <p>The state code. The initial state of the load balancer is <code>provisioning</code>. After the load balancer is fully set up and ready to route traffic, its state is <code>active</code>. If the load balancer could not be set up, its state is <code>failed</code>.</p>
-    pub code: Option<String>,
+    pub code: Option<LoadBalancerStateEnum>,
     /// <p>A description of the state.</p>
     pub reason: Option<String>,
 }
 
-struct LoadBalancerStateDeserializer;
+pub(crate) struct LoadBalancerStateDeserializer;
 impl LoadBalancerStateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoadBalancerState, XmlParseError> {
@@ -3453,32 +5038,168 @@ impl LoadBalancerStateDeserializer {
         })
     }
 }
-struct LoadBalancerStateEnumDeserializer;
+pub(crate) struct LoadBalancerStateEnumDeserializer;
 impl LoadBalancerStateEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<LoadBalancerStateEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct LoadBalancerTypeEnumDeserializer;
+/// <p>The state code. The initial state of the load balancer is <code>provisioning</code>. After the load balancer is fully set up and ready to route traffic, its state is <code>active</code>. If the load balancer could not be set up, its state is <code>failed</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadBalancerStateEnum {
+    /// <p><code>active</code></p>
+    Active,
+    /// <p><code>provisioning</code></p>
+    Provisioning,
+    /// <p><code>active_impaired</code></p>
+    ActiveImpaired,
+    /// <p><code>failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadBalancerStateEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerStateEnum::Active => "active",
+            LoadBalancerStateEnum::Provisioning => "provisioning",
+            LoadBalancerStateEnum::ActiveImpaired => "active_impaired",
+            LoadBalancerStateEnum::Failed => "failed",
+            LoadBalancerStateEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerStateEnum {
+    fn from(value: &'a str) -> LoadBalancerStateEnum {
+        match value {
+            "active" => LoadBalancerStateEnum::Active,
+            "provisioning" => LoadBalancerStateEnum::Provisioning,
+            "active_impaired" => LoadBalancerStateEnum::ActiveImpaired,
+            "failed" => LoadBalancerStateEnum::Failed,
+            _ => LoadBalancerStateEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerStateEnum {
+    fn from(value: String) -> LoadBalancerStateEnum {
+        LoadBalancerStateEnum::from(value.as_str())
+    }
+}
+
+impl From<LoadBalancerStateEnum> for String {
+    fn from(value: LoadBalancerStateEnum) -> String {
+        match value {
+            LoadBalancerStateEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadBalancerStateEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerStateEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct LoadBalancerTypeEnumDeserializer;
 impl LoadBalancerTypeEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<LoadBalancerTypeEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct LoadBalancersDeserializer;
+/// <p>The type of load balancer. The default is <code>application</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadBalancerTypeEnum {
+    /// <p><code>application</code></p>
+    Application,
+    /// <p><code>network</code></p>
+    Network,
+    /// <p><code>gateway</code></p>
+    Gateway,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadBalancerTypeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerTypeEnum::Application => "application",
+            LoadBalancerTypeEnum::Network => "network",
+            LoadBalancerTypeEnum::Gateway => "gateway",
+            LoadBalancerTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerTypeEnum {
+    fn from(value: &'a str) -> LoadBalancerTypeEnum {
+        match value {
+            "application" => LoadBalancerTypeEnum::Application,
+            "network" => LoadBalancerTypeEnum::Network,
+            "gateway" => LoadBalancerTypeEnum::Gateway,
+            _ => LoadBalancerTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerTypeEnum {
+    fn from(value: String) -> LoadBalancerTypeEnum {
+        LoadBalancerTypeEnum::from(value.as_str())
+    }
+}
+
+impl From<LoadBalancerTypeEnum> for String {
+    fn from(value: LoadBalancerTypeEnum) -> String {
+        match value {
+            LoadBalancerTypeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadBalancerTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoadBalancerTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct LoadBalancersDeserializer;
 impl LoadBalancersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<LoadBalancer>, XmlParseError> {
@@ -3492,10 +5213,13 @@ impl LoadBalancersDeserializer {
         })
     }
 }
-struct MarkerDeserializer;
+pub(crate) struct MarkerDeserializer;
 impl MarkerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3510,10 +5234,10 @@ pub struct Matcher {
     pub http_code: String,
 }
 
-struct MatcherDeserializer;
+pub(crate) struct MatcherDeserializer;
 impl MatcherDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Matcher, XmlParseError> {
@@ -3542,10 +5266,13 @@ impl MatcherSerializer {
     }
 }
 
-struct MaxDeserializer;
+pub(crate) struct MaxDeserializer;
 impl MaxDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3564,11 +5291,81 @@ pub struct ModifyListenerInput {
     /// <p>The port for connections from clients to the load balancer.</p>
     pub port: Option<i64>,
     /// <p>The protocol for connections from clients to the load balancer. Application Load Balancers support the HTTP and HTTPS protocols. Network Load Balancers support the TCP, TLS, UDP, and TCP_UDP protocols.</p>
-    pub protocol: Option<String>,
+    pub protocol: Option<ProtocolEnum>,
     /// <p>[HTTPS and TLS listeners] The security policy that defines which protocols and ciphers are supported. For more information, see <a href="https://docs.aws.amazon.com/elasticloadbalancing/latest/application/create-https-listener.html#describe-ssl-policies">Security Policies</a> in the <i>Application Load Balancers Guide</i>.</p>
     pub ssl_policy: Option<String>,
 }
 
+impl ModifyListenerInput {
+    /// Returns a builder for `ModifyListenerInput`, checking its required members when built.
+    pub fn builder() -> ModifyListenerInputBuilder {
+        ModifyListenerInputBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyListenerInput`](struct.ModifyListenerInput.html).
+#[derive(Default, Debug)]
+pub struct ModifyListenerInputBuilder {
+    certificates: Option<Vec<Certificate>>,
+    default_actions: Option<Vec<Action>>,
+    listener_arn: Option<String>,
+    port: Option<i64>,
+    protocol: Option<ProtocolEnum>,
+    ssl_policy: Option<String>,
+}
+
+impl ModifyListenerInputBuilder {
+    /// Sets the [`certificates`](struct.ModifyListenerInput.html#structfield.certificates) member.
+    pub fn certificates<T: Into<Vec<Certificate>>>(mut self, value: T) -> Self {
+        self.certificates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_actions`](struct.ModifyListenerInput.html#structfield.default_actions) member.
+    pub fn default_actions<T: Into<Vec<Action>>>(mut self, value: T) -> Self {
+        self.default_actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`listener_arn`](struct.ModifyListenerInput.html#structfield.listener_arn) member.
+    pub fn listener_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.listener_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.ModifyListenerInput.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`protocol`](struct.ModifyListenerInput.html#structfield.protocol) member.
+    pub fn protocol<T: Into<ProtocolEnum>>(mut self, value: T) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssl_policy`](struct.ModifyListenerInput.html#structfield.ssl_policy) member.
+    pub fn ssl_policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssl_policy = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyListenerInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ModifyListenerInput, ::rusoto_core::BuildError> {
+        Ok(ModifyListenerInput {
+            certificates: self.certificates,
+            default_actions: self.default_actions,
+            listener_arn: self
+                .listener_arn
+                .ok_or(::rusoto_core::BuildError::missing("listener_arn"))?,
+            port: self.port,
+            protocol: self.protocol,
+            ssl_policy: self.ssl_policy,
+        })
+    }
+}
+
 /// Serialize `ModifyListenerInput` contents to a `SignedRequest`.
 struct ModifyListenerInputSerializer;
 impl ModifyListenerInputSerializer {
@@ -3611,10 +5408,10 @@ pub struct ModifyListenerOutput {
     pub listeners: Option<Vec<Listener>>,
 }
 
-struct ModifyListenerOutputDeserializer;
+pub(crate) struct ModifyListenerOutputDeserializer;
 impl ModifyListenerOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyListenerOutput, XmlParseError> {
@@ -3639,6 +5436,48 @@ pub struct ModifyLoadBalancerAttributesInput {
     pub load_balancer_arn: String,
 }
 
+impl ModifyLoadBalancerAttributesInput {
+    /// Returns a builder for `ModifyLoadBalancerAttributesInput`, checking its required members when built.
+    pub fn builder() -> ModifyLoadBalancerAttributesInputBuilder {
+        ModifyLoadBalancerAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyLoadBalancerAttributesInput`](struct.ModifyLoadBalancerAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct ModifyLoadBalancerAttributesInputBuilder {
+    attributes: Option<Vec<LoadBalancerAttribute>>,
+    load_balancer_arn: Option<String>,
+}
+
+impl ModifyLoadBalancerAttributesInputBuilder {
+    /// Sets the [`attributes`](struct.ModifyLoadBalancerAttributesInput.html#structfield.attributes) member.
+    pub fn attributes<T: Into<Vec<LoadBalancerAttribute>>>(mut self, value: T) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_arn`](struct.ModifyLoadBalancerAttributesInput.html#structfield.load_balancer_arn) member.
+    pub fn load_balancer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyLoadBalancerAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyLoadBalancerAttributesInput, ::rusoto_core::BuildError> {
+        Ok(ModifyLoadBalancerAttributesInput {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            load_balancer_arn: self
+                .load_balancer_arn
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_arn"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyLoadBalancerAttributesInput` contents to a `SignedRequest`.
 struct ModifyLoadBalancerAttributesInputSerializer;
 impl ModifyLoadBalancerAttributesInputSerializer {
@@ -3666,10 +5505,10 @@ pub struct ModifyLoadBalancerAttributesOutput {
     pub attributes: Option<Vec<LoadBalancerAttribute>>,
 }
 
-struct ModifyLoadBalancerAttributesOutputDeserializer;
+pub(crate) struct ModifyLoadBalancerAttributesOutputDeserializer;
 impl ModifyLoadBalancerAttributesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyLoadBalancerAttributesOutput, XmlParseError> {
@@ -3700,6 +5539,52 @@ pub struct ModifyRuleInput {
     pub rule_arn: String,
 }
 
+impl ModifyRuleInput {
+    /// Returns a builder for `ModifyRuleInput`, checking its required members when built.
+    pub fn builder() -> ModifyRuleInputBuilder {
+        ModifyRuleInputBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyRuleInput`](struct.ModifyRuleInput.html).
+#[derive(Default, Debug)]
+pub struct ModifyRuleInputBuilder {
+    actions: Option<Vec<Action>>,
+    conditions: Option<Vec<RuleCondition>>,
+    rule_arn: Option<String>,
+}
+
+impl ModifyRuleInputBuilder {
+    /// Sets the [`actions`](struct.ModifyRuleInput.html#structfield.actions) member.
+    pub fn actions<T: Into<Vec<Action>>>(mut self, value: T) -> Self {
+        self.actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conditions`](struct.ModifyRuleInput.html#structfield.conditions) member.
+    pub fn conditions<T: Into<Vec<RuleCondition>>>(mut self, value: T) -> Self {
+        self.conditions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_arn`](struct.ModifyRuleInput.html#structfield.rule_arn) member.
+    pub fn rule_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyRuleInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ModifyRuleInput, ::rusoto_core::BuildError> {
+        Ok(ModifyRuleInput {
+            actions: self.actions,
+            conditions: self.conditions,
+            rule_arn: self
+                .rule_arn
+                .ok_or(::rusoto_core::BuildError::missing("rule_arn"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyRuleInput` contents to a `SignedRequest`.
 struct ModifyRuleInputSerializer;
 impl ModifyRuleInputSerializer {
@@ -3729,10 +5614,10 @@ pub struct ModifyRuleOutput {
     pub rules: Option<Vec<Rule>>,
 }
 
-struct ModifyRuleOutputDeserializer;
+pub(crate) struct ModifyRuleOutputDeserializer;
 impl ModifyRuleOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyRuleOutput, XmlParseError> {
@@ -3757,6 +5642,48 @@ pub struct ModifyTargetGroupAttributesInput {
     pub target_group_arn: String,
 }
 
+impl ModifyTargetGroupAttributesInput {
+    /// Returns a builder for `ModifyTargetGroupAttributesInput`, checking its required members when built.
+    pub fn builder() -> ModifyTargetGroupAttributesInputBuilder {
+        ModifyTargetGroupAttributesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyTargetGroupAttributesInput`](struct.ModifyTargetGroupAttributesInput.html).
+#[derive(Default, Debug)]
+pub struct ModifyTargetGroupAttributesInputBuilder {
+    attributes: Option<Vec<TargetGroupAttribute>>,
+    target_group_arn: Option<String>,
+}
+
+impl ModifyTargetGroupAttributesInputBuilder {
+    /// Sets the [`attributes`](struct.ModifyTargetGroupAttributesInput.html#structfield.attributes) member.
+    pub fn attributes<T: Into<Vec<TargetGroupAttribute>>>(mut self, value: T) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_group_arn`](struct.ModifyTargetGroupAttributesInput.html#structfield.target_group_arn) member.
+    pub fn target_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_group_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyTargetGroupAttributesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyTargetGroupAttributesInput, ::rusoto_core::BuildError> {
+        Ok(ModifyTargetGroupAttributesInput {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            target_group_arn: self
+                .target_group_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_group_arn"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyTargetGroupAttributesInput` contents to a `SignedRequest`.
 struct ModifyTargetGroupAttributesInputSerializer;
 impl ModifyTargetGroupAttributesInputSerializer {
@@ -3784,10 +5711,10 @@ pub struct ModifyTargetGroupAttributesOutput {
     pub attributes: Option<Vec<TargetGroupAttribute>>,
 }
 
-struct ModifyTargetGroupAttributesOutputDeserializer;
+pub(crate) struct ModifyTargetGroupAttributesOutputDeserializer;
 impl ModifyTargetGroupAttributesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyTargetGroupAttributesOutput, XmlParseError> {
@@ -3819,7 +5746,7 @@ pub struct ModifyTargetGroupInput {
     /// <p>The port the load balancer uses when performing health checks on targets.</p>
     pub health_check_port: Option<String>,
     /// <p>The protocol the load balancer uses when performing health checks on targets. The TCP protocol is supported for health checks only if the protocol of the target group is TCP, TLS, UDP, or TCP_UDP. The TLS, UDP, and TCP_UDP protocols are not supported for health checks.</p> <p>If the protocol of the target group is TCP, you can't modify this setting.</p>
-    pub health_check_protocol: Option<String>,
+    pub health_check_protocol: Option<ProtocolEnum>,
     /// <p>[HTTP/HTTPS health checks] The amount of time, in seconds, during which no response means a failed health check.</p> <p>If the protocol of the target group is TCP, you can't modify this setting.</p>
     pub health_check_timeout_seconds: Option<i64>,
     /// <p>The number of consecutive health checks successes required before considering an unhealthy target healthy.</p>
@@ -3832,6 +5759,108 @@ pub struct ModifyTargetGroupInput {
     pub unhealthy_threshold_count: Option<i64>,
 }
 
+impl ModifyTargetGroupInput {
+    /// Returns a builder for `ModifyTargetGroupInput`, checking its required members when built.
+    pub fn builder() -> ModifyTargetGroupInputBuilder {
+        ModifyTargetGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyTargetGroupInput`](struct.ModifyTargetGroupInput.html).
+#[derive(Default, Debug)]
+pub struct ModifyTargetGroupInputBuilder {
+    health_check_enabled: Option<bool>,
+    health_check_interval_seconds: Option<i64>,
+    health_check_path: Option<String>,
+    health_check_port: Option<String>,
+    health_check_protocol: Option<ProtocolEnum>,
+    health_check_timeout_seconds: Option<i64>,
+    healthy_threshold_count: Option<i64>,
+    matcher: Option<Matcher>,
+    target_group_arn: Option<String>,
+    unhealthy_threshold_count: Option<i64>,
+}
+
+impl ModifyTargetGroupInputBuilder {
+    /// Sets the [`health_check_enabled`](struct.ModifyTargetGroupInput.html#structfield.health_check_enabled) member.
+    pub fn health_check_enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.health_check_enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_interval_seconds`](struct.ModifyTargetGroupInput.html#structfield.health_check_interval_seconds) member.
+    pub fn health_check_interval_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.health_check_interval_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_path`](struct.ModifyTargetGroupInput.html#structfield.health_check_path) member.
+    pub fn health_check_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_check_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_port`](struct.ModifyTargetGroupInput.html#structfield.health_check_port) member.
+    pub fn health_check_port<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_check_port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_protocol`](struct.ModifyTargetGroupInput.html#structfield.health_check_protocol) member.
+    pub fn health_check_protocol<T: Into<ProtocolEnum>>(mut self, value: T) -> Self {
+        self.health_check_protocol = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_timeout_seconds`](struct.ModifyTargetGroupInput.html#structfield.health_check_timeout_seconds) member.
+    pub fn health_check_timeout_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.health_check_timeout_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`healthy_threshold_count`](struct.ModifyTargetGroupInput.html#structfield.healthy_threshold_count) member.
+    pub fn healthy_threshold_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.healthy_threshold_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`matcher`](struct.ModifyTargetGroupInput.html#structfield.matcher) member.
+    pub fn matcher<T: Into<Matcher>>(mut self, value: T) -> Self {
+        self.matcher = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_group_arn`](struct.ModifyTargetGroupInput.html#structfield.target_group_arn) member.
+    pub fn target_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unhealthy_threshold_count`](struct.ModifyTargetGroupInput.html#structfield.unhealthy_threshold_count) member.
+    pub fn unhealthy_threshold_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.unhealthy_threshold_count = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyTargetGroupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ModifyTargetGroupInput, ::rusoto_core::BuildError> {
+        Ok(ModifyTargetGroupInput {
+            health_check_enabled: self.health_check_enabled,
+            health_check_interval_seconds: self.health_check_interval_seconds,
+            health_check_path: self.health_check_path,
+            health_check_port: self.health_check_port,
+            health_check_protocol: self.health_check_protocol,
+            health_check_timeout_seconds: self.health_check_timeout_seconds,
+            healthy_threshold_count: self.healthy_threshold_count,
+            matcher: self.matcher,
+            target_group_arn: self
+                .target_group_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_group_arn"))?,
+            unhealthy_threshold_count: self.unhealthy_threshold_count,
+        })
+    }
+}
+
 /// Serialize `ModifyTargetGroupInput` contents to a `SignedRequest`.
 struct ModifyTargetGroupInputSerializer;
 impl ModifyTargetGroupInputSerializer {
@@ -3896,10 +5925,10 @@ pub struct ModifyTargetGroupOutput {
     pub target_groups: Option<Vec<TargetGroup>>,
 }
 
-struct ModifyTargetGroupOutputDeserializer;
+pub(crate) struct ModifyTargetGroupOutputDeserializer;
 impl ModifyTargetGroupOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyTargetGroupOutput, XmlParseError> {
@@ -3920,10 +5949,13 @@ impl ModifyTargetGroupOutputDeserializer {
         )
     }
 }
-struct NameDeserializer;
+pub(crate) struct NameDeserializer;
 impl NameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3931,10 +5963,13 @@ impl NameDeserializer {
         Ok(obj)
     }
 }
-struct PathDeserializer;
+pub(crate) struct PathDeserializer;
 impl PathDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -3949,10 +5984,10 @@ pub struct PathPatternConditionConfig {
     pub values: Option<Vec<String>>,
 }
 
-struct PathPatternConditionConfigDeserializer;
+pub(crate) struct PathPatternConditionConfigDeserializer;
 impl PathPatternConditionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PathPatternConditionConfig, XmlParseError> {
@@ -3993,10 +6028,13 @@ impl PathPatternConditionConfigSerializer {
     }
 }
 
-struct PortDeserializer;
+pub(crate) struct PortDeserializer;
 impl PortDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4004,17 +6042,99 @@ impl PortDeserializer {
         Ok(obj)
     }
 }
-struct ProtocolEnumDeserializer;
+pub(crate) struct ProtocolEnumDeserializer;
 impl ProtocolEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ProtocolEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The protocol for connections from clients to the load balancer. For Application Load Balancers, the supported protocols are HTTP and HTTPS. For Network Load Balancers, the supported protocols are TCP, TLS, UDP, and TCP_UDP.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProtocolEnum {
+    /// <p><code>HTTP</code></p>
+    Http,
+    /// <p><code>HTTPS</code></p>
+    Https,
+    /// <p><code>TCP</code></p>
+    Tcp,
+    /// <p><code>TLS</code></p>
+    Tls,
+    /// <p><code>UDP</code></p>
+    Udp,
+    /// <p><code>TCP_UDP</code></p>
+    TcpUdp,
+    /// <p><code>GENEVE</code></p>
+    Geneve,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProtocolEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProtocolEnum::Http => "HTTP",
+            ProtocolEnum::Https => "HTTPS",
+            ProtocolEnum::Tcp => "TCP",
+            ProtocolEnum::Tls => "TLS",
+            ProtocolEnum::Udp => "UDP",
+            ProtocolEnum::TcpUdp => "TCP_UDP",
+            ProtocolEnum::Geneve => "GENEVE",
+            ProtocolEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProtocolEnum {
+    fn from(value: &'a str) -> ProtocolEnum {
+        match value {
+            "HTTP" => ProtocolEnum::Http,
+            "HTTPS" => ProtocolEnum::Https,
+            "TCP" => ProtocolEnum::Tcp,
+            "TLS" => ProtocolEnum::Tls,
+            "UDP" => ProtocolEnum::Udp,
+            "TCP_UDP" => ProtocolEnum::TcpUdp,
+            "GENEVE" => ProtocolEnum::Geneve,
+            _ => ProtocolEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProtocolEnum {
+    fn from(value: String) -> ProtocolEnum {
+        ProtocolEnum::from(value.as_str())
+    }
+}
+
+impl From<ProtocolEnum> for String {
+    fn from(value: ProtocolEnum) -> String {
+        match value {
+            ProtocolEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProtocolEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProtocolEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Information about a query string condition.</p> <p>The query string component of a URI starts after the first '?' character and is terminated by either a '#' character or the end of the URI. A typical query string contains key/value pairs separated by '&amp;' characters. The allowed characters are specified by RFC 3986. Any character can be percentage encoded.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct QueryStringConditionConfig {
@@ -4022,10 +6142,10 @@ pub struct QueryStringConditionConfig {
     pub values: Option<Vec<QueryStringKeyValuePair>>,
 }
 
-struct QueryStringConditionConfigDeserializer;
+pub(crate) struct QueryStringConditionConfigDeserializer;
 impl QueryStringConditionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<QueryStringConditionConfig, XmlParseError> {
@@ -4075,10 +6195,10 @@ pub struct QueryStringKeyValuePair {
     pub value: Option<String>,
 }
 
-struct QueryStringKeyValuePairDeserializer;
+pub(crate) struct QueryStringKeyValuePairDeserializer;
 impl QueryStringKeyValuePairDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<QueryStringKeyValuePair, XmlParseError> {
@@ -4119,10 +6239,10 @@ impl QueryStringKeyValuePairSerializer {
     }
 }
 
-struct QueryStringKeyValuePairListDeserializer;
+pub(crate) struct QueryStringKeyValuePairListDeserializer;
 impl QueryStringKeyValuePairListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<QueryStringKeyValuePair>, XmlParseError> {
@@ -4151,7 +6271,7 @@ impl QueryStringKeyValuePairListSerializer {
 }
 
 /// <p>Information about a redirect action.</p> <p>A URI consists of the following components: protocol://hostname:port/path?query. You must modify at least one of the following components to avoid a redirect loop: protocol, hostname, port, or path. Any components that you do not modify retain their original values.</p> <p>You can reuse URI components using the following reserved keywords:</p> <ul> <li> <p>#{protocol}</p> </li> <li> <p>#{host}</p> </li> <li> <p>#{port}</p> </li> <li> <p>#{path} (the leading "/" is removed)</p> </li> <li> <p>#{query}</p> </li> </ul> <p>For example, you can change the path to "/new/#{path}", the hostname to "example.#{host}", or the query to "#{query}&amp;value=xyz".</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RedirectActionConfig {
     /// <p>The hostname. This component is not percent-encoded. The hostname can contain #{host}.</p>
     pub host: Option<String>,
@@ -4164,45 +6284,62 @@ pub struct RedirectActionConfig {
     /// <p>The query parameters, URL-encoded when necessary, but not percent-encoded. Do not include the leading "?", as it is automatically added. You can specify any of the reserved keywords.</p>
     pub query: Option<String>,
     /// <p>The HTTP redirect code. The redirect is either permanent (HTTP 301) or temporary (HTTP 302).</p>
-    pub status_code: String,
+    pub status_code: RedirectActionStatusCodeEnum,
 }
 
-struct RedirectActionConfigDeserializer;
+pub(crate) struct RedirectActionConfigDeserializer;
 impl RedirectActionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RedirectActionConfig, XmlParseError> {
-        deserialize_elements::<_, RedirectActionConfig, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Host" => {
-                    obj.host = Some(RedirectActionHostDeserializer::deserialize("Host", stack)?);
-                }
-                "Path" => {
-                    obj.path = Some(RedirectActionPathDeserializer::deserialize("Path", stack)?);
-                }
-                "Port" => {
-                    obj.port = Some(RedirectActionPortDeserializer::deserialize("Port", stack)?);
-                }
-                "Protocol" => {
-                    obj.protocol = Some(RedirectActionProtocolDeserializer::deserialize(
-                        "Protocol", stack,
-                    )?);
-                }
-                "Query" => {
-                    obj.query = Some(RedirectActionQueryDeserializer::deserialize(
-                        "Query", stack,
-                    )?);
-                }
-                "StatusCode" => {
-                    obj.status_code =
-                        RedirectActionStatusCodeEnumDeserializer::deserialize("StatusCode", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            RedirectActionConfig {
+                host: Default::default(),
+                path: Default::default(),
+                port: Default::default(),
+                protocol: Default::default(),
+                query: Default::default(),
+                status_code: RedirectActionStatusCodeEnum::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Host" => {
+                        obj.host =
+                            Some(RedirectActionHostDeserializer::deserialize("Host", stack)?);
+                    }
+                    "Path" => {
+                        obj.path =
+                            Some(RedirectActionPathDeserializer::deserialize("Path", stack)?);
+                    }
+                    "Port" => {
+                        obj.port =
+                            Some(RedirectActionPortDeserializer::deserialize("Port", stack)?);
+                    }
+                    "Protocol" => {
+                        obj.protocol = Some(RedirectActionProtocolDeserializer::deserialize(
+                            "Protocol", stack,
+                        )?);
+                    }
+                    "Query" => {
+                        obj.query = Some(RedirectActionQueryDeserializer::deserialize(
+                            "Query", stack,
+                        )?);
+                    }
+                    "StatusCode" => {
+                        obj.status_code = RedirectActionStatusCodeEnumDeserializer::deserialize(
+                            "StatusCode",
+                            stack,
+                        )?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
@@ -4234,10 +6371,13 @@ impl RedirectActionConfigSerializer {
     }
 }
 
-struct RedirectActionHostDeserializer;
+pub(crate) struct RedirectActionHostDeserializer;
 impl RedirectActionHostDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4245,10 +6385,13 @@ impl RedirectActionHostDeserializer {
         Ok(obj)
     }
 }
-struct RedirectActionPathDeserializer;
+pub(crate) struct RedirectActionPathDeserializer;
 impl RedirectActionPathDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4256,10 +6399,13 @@ impl RedirectActionPathDeserializer {
         Ok(obj)
     }
 }
-struct RedirectActionPortDeserializer;
+pub(crate) struct RedirectActionPortDeserializer;
 impl RedirectActionPortDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4267,10 +6413,13 @@ impl RedirectActionPortDeserializer {
         Ok(obj)
     }
 }
-struct RedirectActionProtocolDeserializer;
+pub(crate) struct RedirectActionProtocolDeserializer;
 impl RedirectActionProtocolDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4278,10 +6427,13 @@ impl RedirectActionProtocolDeserializer {
         Ok(obj)
     }
 }
-struct RedirectActionQueryDeserializer;
+pub(crate) struct RedirectActionQueryDeserializer;
 impl RedirectActionQueryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4289,17 +6441,79 @@ impl RedirectActionQueryDeserializer {
         Ok(obj)
     }
 }
-struct RedirectActionStatusCodeEnumDeserializer;
+pub(crate) struct RedirectActionStatusCodeEnumDeserializer;
 impl RedirectActionStatusCodeEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<RedirectActionStatusCodeEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The HTTP redirect code. The redirect is either permanent (HTTP 301) or temporary (HTTP 302).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RedirectActionStatusCodeEnum {
+    /// <p><code>HTTP_301</code></p>
+    Http301,
+    /// <p><code>HTTP_302</code></p>
+    Http302,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RedirectActionStatusCodeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RedirectActionStatusCodeEnum::Http301 => "HTTP_301",
+            RedirectActionStatusCodeEnum::Http302 => "HTTP_302",
+            RedirectActionStatusCodeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RedirectActionStatusCodeEnum {
+    fn from(value: &'a str) -> RedirectActionStatusCodeEnum {
+        match value {
+            "HTTP_301" => RedirectActionStatusCodeEnum::Http301,
+            "HTTP_302" => RedirectActionStatusCodeEnum::Http302,
+            _ => RedirectActionStatusCodeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RedirectActionStatusCodeEnum {
+    fn from(value: String) -> RedirectActionStatusCodeEnum {
+        RedirectActionStatusCodeEnum::from(value.as_str())
+    }
+}
+
+impl From<RedirectActionStatusCodeEnum> for String {
+    fn from(value: RedirectActionStatusCodeEnum) -> String {
+        match value {
+            RedirectActionStatusCodeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RedirectActionStatusCodeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for RedirectActionStatusCodeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct RegisterTargetsInput {
     /// <p>The Amazon Resource Name (ARN) of the target group.</p>
@@ -4308,6 +6522,46 @@ pub struct RegisterTargetsInput {
     pub targets: Vec<TargetDescription>,
 }
 
+impl RegisterTargetsInput {
+    /// Returns a builder for `RegisterTargetsInput`, checking its required members when built.
+    pub fn builder() -> RegisterTargetsInputBuilder {
+        RegisterTargetsInputBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterTargetsInput`](struct.RegisterTargetsInput.html).
+#[derive(Default, Debug)]
+pub struct RegisterTargetsInputBuilder {
+    target_group_arn: Option<String>,
+    targets: Option<Vec<TargetDescription>>,
+}
+
+impl RegisterTargetsInputBuilder {
+    /// Sets the [`target_group_arn`](struct.RegisterTargetsInput.html#structfield.target_group_arn) member.
+    pub fn target_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`targets`](struct.RegisterTargetsInput.html#structfield.targets) member.
+    pub fn targets<T: Into<Vec<TargetDescription>>>(mut self, value: T) -> Self {
+        self.targets = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterTargetsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RegisterTargetsInput, ::rusoto_core::BuildError> {
+        Ok(RegisterTargetsInput {
+            target_group_arn: self
+                .target_group_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_group_arn"))?,
+            targets: self
+                .targets
+                .ok_or(::rusoto_core::BuildError::missing("targets"))?,
+        })
+    }
+}
+
 /// Serialize `RegisterTargetsInput` contents to a `SignedRequest`.
 struct RegisterTargetsInputSerializer;
 impl RegisterTargetsInputSerializer {
@@ -4332,10 +6586,10 @@ impl RegisterTargetsInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct RegisterTargetsOutput {}
 
-struct RegisterTargetsOutputDeserializer;
+pub(crate) struct RegisterTargetsOutputDeserializer;
 impl RegisterTargetsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RegisterTargetsOutput, XmlParseError> {
@@ -4356,6 +6610,48 @@ pub struct RemoveListenerCertificatesInput {
     pub listener_arn: String,
 }
 
+impl RemoveListenerCertificatesInput {
+    /// Returns a builder for `RemoveListenerCertificatesInput`, checking its required members when built.
+    pub fn builder() -> RemoveListenerCertificatesInputBuilder {
+        RemoveListenerCertificatesInputBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveListenerCertificatesInput`](struct.RemoveListenerCertificatesInput.html).
+#[derive(Default, Debug)]
+pub struct RemoveListenerCertificatesInputBuilder {
+    certificates: Option<Vec<Certificate>>,
+    listener_arn: Option<String>,
+}
+
+impl RemoveListenerCertificatesInputBuilder {
+    /// Sets the [`certificates`](struct.RemoveListenerCertificatesInput.html#structfield.certificates) member.
+    pub fn certificates<T: Into<Vec<Certificate>>>(mut self, value: T) -> Self {
+        self.certificates = Some(value.into());
+        self
+    }
+
+    /// Sets the [`listener_arn`](struct.RemoveListenerCertificatesInput.html#structfield.listener_arn) member.
+    pub fn listener_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.listener_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveListenerCertificatesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveListenerCertificatesInput, ::rusoto_core::BuildError> {
+        Ok(RemoveListenerCertificatesInput {
+            certificates: self
+                .certificates
+                .ok_or(::rusoto_core::BuildError::missing("certificates"))?,
+            listener_arn: self
+                .listener_arn
+                .ok_or(::rusoto_core::BuildError::missing("listener_arn"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveListenerCertificatesInput` contents to a `SignedRequest`.
 struct RemoveListenerCertificatesInputSerializer;
 impl RemoveListenerCertificatesInputSerializer {
@@ -4377,10 +6673,10 @@ impl RemoveListenerCertificatesInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct RemoveListenerCertificatesOutput {}
 
-struct RemoveListenerCertificatesOutputDeserializer;
+pub(crate) struct RemoveListenerCertificatesOutputDeserializer;
 impl RemoveListenerCertificatesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RemoveListenerCertificatesOutput, XmlParseError> {
@@ -4401,6 +6697,46 @@ pub struct RemoveTagsInput {
     pub tag_keys: Vec<String>,
 }
 
+impl RemoveTagsInput {
+    /// Returns a builder for `RemoveTagsInput`, checking its required members when built.
+    pub fn builder() -> RemoveTagsInputBuilder {
+        RemoveTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsInput`](struct.RemoveTagsInput.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsInputBuilder {
+    resource_arns: Option<Vec<String>>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl RemoveTagsInputBuilder {
+    /// Sets the [`resource_arns`](struct.RemoveTagsInput.html#structfield.resource_arns) member.
+    pub fn resource_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.RemoveTagsInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RemoveTagsInput, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsInput {
+            resource_arns: self
+                .resource_arns
+                .ok_or(::rusoto_core::BuildError::missing("resource_arns"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveTagsInput` contents to a `SignedRequest`.
 struct RemoveTagsInputSerializer;
 impl RemoveTagsInputSerializer {
@@ -4422,10 +6758,10 @@ impl RemoveTagsInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct RemoveTagsOutput {}
 
-struct RemoveTagsOutputDeserializer;
+pub(crate) struct RemoveTagsOutputDeserializer;
 impl RemoveTagsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RemoveTagsOutput, XmlParseError> {
@@ -4438,10 +6774,13 @@ impl RemoveTagsOutputDeserializer {
         Ok(obj)
     }
 }
-struct ResourceArnDeserializer;
+pub(crate) struct ResourceArnDeserializer;
 impl ResourceArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4476,10 +6815,13 @@ pub struct Rule {
     pub rule_arn: Option<String>,
 }
 
-struct RuleDeserializer;
+pub(crate) struct RuleDeserializer;
 impl RuleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Rule, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Rule, XmlParseError> {
         deserialize_elements::<_, Rule, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Actions" => {
@@ -4507,10 +6849,13 @@ impl RuleDeserializer {
         })
     }
 }
-struct RuleArnDeserializer;
+pub(crate) struct RuleArnDeserializer;
 impl RuleArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4551,10 +6896,10 @@ pub struct RuleCondition {
     pub values: Option<Vec<String>>,
 }
 
-struct RuleConditionDeserializer;
+pub(crate) struct RuleConditionDeserializer;
 impl RuleConditionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RuleCondition, XmlParseError> {
@@ -4680,10 +7025,10 @@ impl RuleConditionSerializer {
     }
 }
 
-struct RuleConditionListDeserializer;
+pub(crate) struct RuleConditionListDeserializer;
 impl RuleConditionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<RuleCondition>, XmlParseError> {
@@ -4747,10 +7092,10 @@ impl RulePriorityPairSerializer {
     }
 }
 
-struct RulesDeserializer;
+pub(crate) struct RulesDeserializer;
 impl RulesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Rule>, XmlParseError> {
@@ -4764,10 +7109,13 @@ impl RulesDeserializer {
         })
     }
 }
-struct SecurityGroupIdDeserializer;
+pub(crate) struct SecurityGroupIdDeserializer;
 impl SecurityGroupIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4775,10 +7123,10 @@ impl SecurityGroupIdDeserializer {
         Ok(obj)
     }
 }
-struct SecurityGroupsDeserializer;
+pub(crate) struct SecurityGroupsDeserializer;
 impl SecurityGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -4804,14 +7152,54 @@ impl SecurityGroupsSerializer {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SetIpAddressTypeInput {
     /// <p>The IP address type. The possible values are <code>ipv4</code> (for IPv4 addresses) and <code>dualstack</code> (for IPv4 and IPv6 addresses). Internal load balancers must use <code>ipv4</code>. Network Load Balancers must use <code>ipv4</code>.</p>
-    pub ip_address_type: String,
+    pub ip_address_type: IpAddressType,
     /// <p>The Amazon Resource Name (ARN) of the load balancer.</p>
     pub load_balancer_arn: String,
 }
 
+impl SetIpAddressTypeInput {
+    /// Returns a builder for `SetIpAddressTypeInput`, checking its required members when built.
+    pub fn builder() -> SetIpAddressTypeInputBuilder {
+        SetIpAddressTypeInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetIpAddressTypeInput`](struct.SetIpAddressTypeInput.html).
+#[derive(Default, Debug)]
+pub struct SetIpAddressTypeInputBuilder {
+    ip_address_type: Option<IpAddressType>,
+    load_balancer_arn: Option<String>,
+}
+
+impl SetIpAddressTypeInputBuilder {
+    /// Sets the [`ip_address_type`](struct.SetIpAddressTypeInput.html#structfield.ip_address_type) member.
+    pub fn ip_address_type<T: Into<IpAddressType>>(mut self, value: T) -> Self {
+        self.ip_address_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_arn`](struct.SetIpAddressTypeInput.html#structfield.load_balancer_arn) member.
+    pub fn load_balancer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetIpAddressTypeInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetIpAddressTypeInput, ::rusoto_core::BuildError> {
+        Ok(SetIpAddressTypeInput {
+            ip_address_type: self
+                .ip_address_type
+                .ok_or(::rusoto_core::BuildError::missing("ip_address_type"))?,
+            load_balancer_arn: self
+                .load_balancer_arn
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_arn"))?,
+        })
+    }
+}
+
 /// Serialize `SetIpAddressTypeInput` contents to a `SignedRequest`.
 struct SetIpAddressTypeInputSerializer;
 impl SetIpAddressTypeInputSerializer {
@@ -4835,13 +7223,13 @@ impl SetIpAddressTypeInputSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SetIpAddressTypeOutput {
     /// <p>The IP address type.</p>
-    pub ip_address_type: Option<String>,
+    pub ip_address_type: Option<IpAddressType>,
 }
 
-struct SetIpAddressTypeOutputDeserializer;
+pub(crate) struct SetIpAddressTypeOutputDeserializer;
 impl SetIpAddressTypeOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetIpAddressTypeOutput, XmlParseError> {
@@ -4865,6 +7253,36 @@ pub struct SetRulePrioritiesInput {
     pub rule_priorities: Vec<RulePriorityPair>,
 }
 
+impl SetRulePrioritiesInput {
+    /// Returns a builder for `SetRulePrioritiesInput`, checking its required members when built.
+    pub fn builder() -> SetRulePrioritiesInputBuilder {
+        SetRulePrioritiesInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetRulePrioritiesInput`](struct.SetRulePrioritiesInput.html).
+#[derive(Default, Debug)]
+pub struct SetRulePrioritiesInputBuilder {
+    rule_priorities: Option<Vec<RulePriorityPair>>,
+}
+
+impl SetRulePrioritiesInputBuilder {
+    /// Sets the [`rule_priorities`](struct.SetRulePrioritiesInput.html#structfield.rule_priorities) member.
+    pub fn rule_priorities<T: Into<Vec<RulePriorityPair>>>(mut self, value: T) -> Self {
+        self.rule_priorities = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetRulePrioritiesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetRulePrioritiesInput, ::rusoto_core::BuildError> {
+        Ok(SetRulePrioritiesInput {
+            rule_priorities: self
+                .rule_priorities
+                .ok_or(::rusoto_core::BuildError::missing("rule_priorities"))?,
+        })
+    }
+}
+
 /// Serialize `SetRulePrioritiesInput` contents to a `SignedRequest`.
 struct SetRulePrioritiesInputSerializer;
 impl SetRulePrioritiesInputSerializer {
@@ -4888,10 +7306,10 @@ pub struct SetRulePrioritiesOutput {
     pub rules: Option<Vec<Rule>>,
 }
 
-struct SetRulePrioritiesOutputDeserializer;
+pub(crate) struct SetRulePrioritiesOutputDeserializer;
 impl SetRulePrioritiesOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetRulePrioritiesOutput, XmlParseError> {
@@ -4920,6 +7338,46 @@ pub struct SetSecurityGroupsInput {
     pub security_groups: Vec<String>,
 }
 
+impl SetSecurityGroupsInput {
+    /// Returns a builder for `SetSecurityGroupsInput`, checking its required members when built.
+    pub fn builder() -> SetSecurityGroupsInputBuilder {
+        SetSecurityGroupsInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetSecurityGroupsInput`](struct.SetSecurityGroupsInput.html).
+#[derive(Default, Debug)]
+pub struct SetSecurityGroupsInputBuilder {
+    load_balancer_arn: Option<String>,
+    security_groups: Option<Vec<String>>,
+}
+
+impl SetSecurityGroupsInputBuilder {
+    /// Sets the [`load_balancer_arn`](struct.SetSecurityGroupsInput.html#structfield.load_balancer_arn) member.
+    pub fn load_balancer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_groups`](struct.SetSecurityGroupsInput.html#structfield.security_groups) member.
+    pub fn security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_groups = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetSecurityGroupsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetSecurityGroupsInput, ::rusoto_core::BuildError> {
+        Ok(SetSecurityGroupsInput {
+            load_balancer_arn: self
+                .load_balancer_arn
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_arn"))?,
+            security_groups: self
+                .security_groups
+                .ok_or(::rusoto_core::BuildError::missing("security_groups"))?,
+        })
+    }
+}
+
 /// Serialize `SetSecurityGroupsInput` contents to a `SignedRequest`.
 struct SetSecurityGroupsInputSerializer;
 impl SetSecurityGroupsInputSerializer {
@@ -4947,10 +7405,10 @@ pub struct SetSecurityGroupsOutput {
     pub security_group_ids: Option<Vec<String>>,
 }
 
-struct SetSecurityGroupsOutputDeserializer;
+pub(crate) struct SetSecurityGroupsOutputDeserializer;
 impl SetSecurityGroupsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetSecurityGroupsOutput, XmlParseError> {
@@ -4981,6 +7439,52 @@ pub struct SetSubnetsInput {
     pub subnets: Option<Vec<String>>,
 }
 
+impl SetSubnetsInput {
+    /// Returns a builder for `SetSubnetsInput`, checking its required members when built.
+    pub fn builder() -> SetSubnetsInputBuilder {
+        SetSubnetsInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetSubnetsInput`](struct.SetSubnetsInput.html).
+#[derive(Default, Debug)]
+pub struct SetSubnetsInputBuilder {
+    load_balancer_arn: Option<String>,
+    subnet_mappings: Option<Vec<SubnetMapping>>,
+    subnets: Option<Vec<String>>,
+}
+
+impl SetSubnetsInputBuilder {
+    /// Sets the [`load_balancer_arn`](struct.SetSubnetsInput.html#structfield.load_balancer_arn) member.
+    pub fn load_balancer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_mappings`](struct.SetSubnetsInput.html#structfield.subnet_mappings) member.
+    pub fn subnet_mappings<T: Into<Vec<SubnetMapping>>>(mut self, value: T) -> Self {
+        self.subnet_mappings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnets`](struct.SetSubnetsInput.html#structfield.subnets) member.
+    pub fn subnets<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnets = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetSubnetsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetSubnetsInput, ::rusoto_core::BuildError> {
+        Ok(SetSubnetsInput {
+            load_balancer_arn: self
+                .load_balancer_arn
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_arn"))?,
+            subnet_mappings: self.subnet_mappings,
+            subnets: self.subnets,
+        })
+    }
+}
+
 /// Serialize `SetSubnetsInput` contents to a `SignedRequest`.
 struct SetSubnetsInputSerializer;
 impl SetSubnetsInputSerializer {
@@ -5013,10 +7517,10 @@ pub struct SetSubnetsOutput {
     pub availability_zones: Option<Vec<AvailabilityZone>>,
 }
 
-struct SetSubnetsOutputDeserializer;
+pub(crate) struct SetSubnetsOutputDeserializer;
 impl SetSubnetsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SetSubnetsOutput, XmlParseError> {
@@ -5040,10 +7544,10 @@ pub struct SourceIpConditionConfig {
     pub values: Option<Vec<String>>,
 }
 
-struct SourceIpConditionConfigDeserializer;
+pub(crate) struct SourceIpConditionConfigDeserializer;
 impl SourceIpConditionConfigDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SourceIpConditionConfig, XmlParseError> {
@@ -5084,10 +7588,10 @@ impl SourceIpConditionConfigSerializer {
     }
 }
 
-struct SslPoliciesDeserializer;
+pub(crate) struct SslPoliciesDeserializer;
 impl SslPoliciesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SslPolicy>, XmlParseError> {
@@ -5112,10 +7616,10 @@ pub struct SslPolicy {
     pub ssl_protocols: Option<Vec<String>>,
 }
 
-struct SslPolicyDeserializer;
+pub(crate) struct SslPolicyDeserializer;
 impl SslPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SslPolicy, XmlParseError> {
@@ -5140,10 +7644,13 @@ impl SslPolicyDeserializer {
         })
     }
 }
-struct SslPolicyNameDeserializer;
+pub(crate) struct SslPolicyNameDeserializer;
 impl SslPolicyNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5163,10 +7670,13 @@ impl SslPolicyNamesSerializer {
     }
 }
 
-struct SslProtocolDeserializer;
+pub(crate) struct SslProtocolDeserializer;
 impl SslProtocolDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5174,10 +7684,10 @@ impl SslProtocolDeserializer {
         Ok(obj)
     }
 }
-struct SslProtocolsDeserializer;
+pub(crate) struct SslProtocolsDeserializer;
 impl SslProtocolsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -5191,10 +7701,13 @@ impl SslProtocolsDeserializer {
         })
     }
 }
-struct StateReasonDeserializer;
+pub(crate) struct StateReasonDeserializer;
 impl StateReasonDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5202,10 +7715,13 @@ impl StateReasonDeserializer {
         Ok(obj)
     }
 }
-struct StringDeserializer;
+pub(crate) struct StringDeserializer;
 impl StringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5213,10 +7729,13 @@ impl StringDeserializer {
         Ok(obj)
     }
 }
-struct StringValueDeserializer;
+pub(crate) struct StringValueDeserializer;
 impl StringValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5224,10 +7743,13 @@ impl StringValueDeserializer {
         Ok(obj)
     }
 }
-struct SubnetIdDeserializer;
+pub(crate) struct SubnetIdDeserializer;
 impl SubnetIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5293,10 +7815,13 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -5337,10 +7862,10 @@ pub struct TagDescription {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct TagDescriptionDeserializer;
+pub(crate) struct TagDescriptionDeserializer;
 impl TagDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TagDescription, XmlParseError> {
@@ -5361,10 +7886,10 @@ impl TagDescriptionDeserializer {
         })
     }
 }
-struct TagDescriptionsDeserializer;
+pub(crate) struct TagDescriptionsDeserializer;
 impl TagDescriptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TagDescription>, XmlParseError> {
@@ -5378,10 +7903,13 @@ impl TagDescriptionsDeserializer {
         })
     }
 }
-struct TagKeyDeserializer;
+pub(crate) struct TagKeyDeserializer;
 impl TagKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5401,10 +7929,10 @@ impl TagKeysSerializer {
     }
 }
 
-struct TagListDeserializer;
+pub(crate) struct TagListDeserializer;
 impl TagListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -5430,10 +7958,13 @@ impl TagListSerializer {
     }
 }
 
-struct TagValueDeserializer;
+pub(crate) struct TagValueDeserializer;
 impl TagValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5452,10 +7983,10 @@ pub struct TargetDescription {
     pub port: Option<i64>,
 }
 
-struct TargetDescriptionDeserializer;
+pub(crate) struct TargetDescriptionDeserializer;
 impl TargetDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TargetDescription, XmlParseError> {
@@ -5522,7 +8053,7 @@ pub struct TargetGroup {
     /// <p>The port to use to connect with the target.</p>
     pub health_check_port: Option<String>,
     /// <p>The protocol to use to connect with the target.</p>
-    pub health_check_protocol: Option<String>,
+    pub health_check_protocol: Option<ProtocolEnum>,
     /// <p>The amount of time, in seconds, during which no response means a failed health check.</p>
     pub health_check_timeout_seconds: Option<i64>,
     /// <p>The number of consecutive health checks successes required before considering an unhealthy target healthy.</p>
@@ -5534,23 +8065,23 @@ pub struct TargetGroup {
     /// <p>The port on which the targets are listening.</p>
     pub port: Option<i64>,
     /// <p>The protocol to use for routing traffic to the targets.</p>
-    pub protocol: Option<String>,
+    pub protocol: Option<ProtocolEnum>,
     /// <p>The Amazon Resource Name (ARN) of the target group.</p>
     pub target_group_arn: Option<String>,
     /// <p>The name of the target group.</p>
     pub target_group_name: Option<String>,
     /// <p>The type of target that you must specify when registering targets with this target group. The possible values are <code>instance</code> (targets are specified by instance ID) or <code>ip</code> (targets are specified by IP address).</p>
-    pub target_type: Option<String>,
+    pub target_type: Option<TargetTypeEnum>,
     /// <p>The number of consecutive health check failures required before considering the target unhealthy.</p>
     pub unhealthy_threshold_count: Option<i64>,
     /// <p>The ID of the VPC for the targets.</p>
     pub vpc_id: Option<String>,
 }
 
-struct TargetGroupDeserializer;
+pub(crate) struct TargetGroupDeserializer;
 impl TargetGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TargetGroup, XmlParseError> {
@@ -5647,10 +8178,13 @@ impl TargetGroupDeserializer {
         })
     }
 }
-struct TargetGroupArnDeserializer;
+pub(crate) struct TargetGroupArnDeserializer;
 impl TargetGroupArnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5679,10 +8213,10 @@ pub struct TargetGroupAttribute {
     pub value: Option<String>,
 }
 
-struct TargetGroupAttributeDeserializer;
+pub(crate) struct TargetGroupAttributeDeserializer;
 impl TargetGroupAttributeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TargetGroupAttribute, XmlParseError> {
@@ -5723,10 +8257,13 @@ impl TargetGroupAttributeSerializer {
     }
 }
 
-struct TargetGroupAttributeKeyDeserializer;
+pub(crate) struct TargetGroupAttributeKeyDeserializer;
 impl TargetGroupAttributeKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5734,10 +8271,13 @@ impl TargetGroupAttributeKeyDeserializer {
         Ok(obj)
     }
 }
-struct TargetGroupAttributeValueDeserializer;
+pub(crate) struct TargetGroupAttributeValueDeserializer;
 impl TargetGroupAttributeValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5745,10 +8285,10 @@ impl TargetGroupAttributeValueDeserializer {
         Ok(obj)
     }
 }
-struct TargetGroupAttributesDeserializer;
+pub(crate) struct TargetGroupAttributesDeserializer;
 impl TargetGroupAttributesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TargetGroupAttribute>, XmlParseError> {
@@ -5776,10 +8316,13 @@ impl TargetGroupAttributesSerializer {
     }
 }
 
-struct TargetGroupNameDeserializer;
+pub(crate) struct TargetGroupNameDeserializer;
 impl TargetGroupNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5799,10 +8342,10 @@ impl TargetGroupNamesSerializer {
     }
 }
 
-struct TargetGroupsDeserializer;
+pub(crate) struct TargetGroupsDeserializer;
 impl TargetGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TargetGroup>, XmlParseError> {
@@ -5822,15 +8365,15 @@ pub struct TargetHealth {
     /// <p>A description of the target health that provides additional details. If the state is <code>healthy</code>, a description is not provided.</p>
     pub description: Option<String>,
     /// <p><p>The reason code.</p> <p>If the target state is <code>healthy</code>, a reason code is not provided.</p> <p>If the target state is <code>initial</code>, the reason code can be one of the following values:</p> <ul> <li> <p> <code>Elb.RegistrationInProgress</code> - The target is in the process of being registered with the load balancer.</p> </li> <li> <p> <code>Elb.InitialHealthChecking</code> - The load balancer is still sending the target the minimum number of health checks required to determine its health status.</p> </li> </ul> <p>If the target state is <code>unhealthy</code>, the reason code can be one of the following values:</p> <ul> <li> <p> <code>Target.ResponseCodeMismatch</code> - The health checks did not return an expected HTTP code.</p> </li> <li> <p> <code>Target.Timeout</code> - The health check requests timed out.</p> </li> <li> <p> <code>Target.FailedHealthChecks</code> - The load balancer received an error while establishing a connection to the target or the target response was malformed.</p> </li> <li> <p> <code>Elb.InternalError</code> - The health checks failed due to an internal error.</p> </li> </ul> <p>If the target state is <code>unused</code>, the reason code can be one of the following values:</p> <ul> <li> <p> <code>Target.NotRegistered</code> - The target is not registered with the target group.</p> </li> <li> <p> <code>Target.NotInUse</code> - The target group is not used by any load balancer or the target is in an Availability Zone that is not enabled for its load balancer.</p> </li> <li> <p> <code>Target.IpUnusable</code> - The target IP address is reserved for use by a load balancer.</p> </li> <li> <p> <code>Target.InvalidState</code> - The target is in the stopped or terminated state.</p> </li> </ul> <p>If the target state is <code>draining</code>, the reason code can be the following value:</p> <ul> <li> <p> <code>Target.DeregistrationInProgress</code> - The target is in the process of being deregistered and the deregistration delay period has not expired.</p> </li> </ul> <p>If the target state is <code>unavailable</code>, the reason code can be the following value:</p> <ul> <li> <p> <code>Target.HealthCheckDisabled</code> - Health checks are disabled for the target group.</p> </li> </ul></p>
-    pub reason: Option<String>,
+    pub reason: Option<TargetHealthReasonEnum>,
     /// <p>The state of the target.</p>
-    pub state: Option<String>,
+    pub state: Option<TargetHealthStateEnum>,
 }
 
-struct TargetHealthDeserializer;
+pub(crate) struct TargetHealthDeserializer;
 impl TargetHealthDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TargetHealth, XmlParseError> {
@@ -5867,10 +8410,10 @@ pub struct TargetHealthDescription {
     pub target_health: Option<TargetHealth>,
 }
 
-struct TargetHealthDescriptionDeserializer;
+pub(crate) struct TargetHealthDescriptionDeserializer;
 impl TargetHealthDescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TargetHealthDescription, XmlParseError> {
@@ -5902,10 +8445,10 @@ impl TargetHealthDescriptionDeserializer {
         )
     }
 }
-struct TargetHealthDescriptionsDeserializer;
+pub(crate) struct TargetHealthDescriptionsDeserializer;
 impl TargetHealthDescriptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TargetHealthDescription>, XmlParseError> {
@@ -5921,32 +8464,219 @@ impl TargetHealthDescriptionsDeserializer {
         })
     }
 }
-struct TargetHealthReasonEnumDeserializer;
+pub(crate) struct TargetHealthReasonEnumDeserializer;
 impl TargetHealthReasonEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TargetHealthReasonEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct TargetHealthStateEnumDeserializer;
+/// <p><p>The reason code.</p> <p>If the target state is <code>healthy</code>, a reason code is not provided.</p> <p>If the target state is <code>initial</code>, the reason code can be one of the following values:</p> <ul> <li> <p> <code>Elb.RegistrationInProgress</code> - The target is in the process of being registered with the load balancer.</p> </li> <li> <p> <code>Elb.InitialHealthChecking</code> - The load balancer is still sending the target the minimum number of health checks required to determine its health status.</p> </li> </ul> <p>If the target state is <code>unhealthy</code>, the reason code can be one of the following values:</p> <ul> <li> <p> <code>Target.ResponseCodeMismatch</code> - The health checks did not return an expected HTTP code.</p> </li> <li> <p> <code>Target.Timeout</code> - The health check requests timed out.</p> </li> <li> <p> <code>Target.FailedHealthChecks</code> - The load balancer received an error while establishing a connection to the target or the target response was malformed.</p> </li> <li> <p> <code>Elb.InternalError</code> - The health checks failed due to an internal error.</p> </li> </ul> <p>If the target state is <code>unused</code>, the reason code can be one of the following values:</p> <ul> <li> <p> <code>Target.NotRegistered</code> - The target is not registered with the target group.</p> </li> <li> <p> <code>Target.NotInUse</code> - The target group is not used by any load balancer or the target is in an Availability Zone that is not enabled for its load balancer.</p> </li> <li> <p> <code>Target.IpUnusable</code> - The target IP address is reserved for use by a load balancer.</p> </li> <li> <p> <code>Target.InvalidState</code> - The target is in the stopped or terminated state.</p> </li> </ul> <p>If the target state is <code>draining</code>, the reason code can be the following value:</p> <ul> <li> <p> <code>Target.DeregistrationInProgress</code> - The target is in the process of being deregistered and the deregistration delay period has not expired.</p> </li> </ul> <p>If the target state is <code>unavailable</code>, the reason code can be the following value:</p> <ul> <li> <p> <code>Target.HealthCheckDisabled</code> - Health checks are disabled for the target group.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TargetHealthReasonEnum {
+    /// <p><code>Elb.RegistrationInProgress</code> - The target is in the process of being registered with the load balancer.</p>
+    ElbRegistrationInProgress,
+    /// <p><code>Elb.InitialHealthChecking</code> - The load balancer is still sending the target the minimum number of health checks required to determine its health status.</p>
+    ElbInitialHealthChecking,
+    /// <p><code>Target.ResponseCodeMismatch</code> - The health checks did not return an expected HTTP code.</p>
+    TargetResponseCodeMismatch,
+    /// <p><code>Target.Timeout</code> - The health check requests timed out.</p>
+    TargetTimeout,
+    /// <p><code>Target.FailedHealthChecks</code> - The load balancer received an error while establishing a connection to the target or the target response was malformed.</p>
+    TargetFailedHealthChecks,
+    /// <p><code>Target.NotRegistered</code> - The target is not registered with the target group.</p>
+    TargetNotRegistered,
+    /// <p><code>Target.NotInUse</code> - The target group is not used by any load balancer or the target is in an Availability Zone that is not enabled for its load balancer.</p>
+    TargetNotInUse,
+    /// <p><code>Target.DeregistrationInProgress</code> - The target is in the process of being deregistered and the deregistration delay period has not expired.</p>
+    TargetDeregistrationInProgress,
+    /// <p><code>Target.InvalidState</code> - The target is in the stopped or terminated state.</p>
+    TargetInvalidState,
+    /// <p><code>Target.IpUnusable</code> - The target IP address is reserved for use by a load balancer.</p>
+    TargetIpUnusable,
+    /// <p><code>Target.HealthCheckDisabled</code> - Health checks are disabled for the target group.</p>
+    TargetHealthCheckDisabled,
+    /// <p><code>Elb.InternalError</code> - The health checks failed due to an internal error.</p>
+    ElbInternalError,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TargetHealthReasonEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetHealthReasonEnum::ElbRegistrationInProgress => "Elb.RegistrationInProgress",
+            TargetHealthReasonEnum::ElbInitialHealthChecking => "Elb.InitialHealthChecking",
+            TargetHealthReasonEnum::TargetResponseCodeMismatch => "Target.ResponseCodeMismatch",
+            TargetHealthReasonEnum::TargetTimeout => "Target.Timeout",
+            TargetHealthReasonEnum::TargetFailedHealthChecks => "Target.FailedHealthChecks",
+            TargetHealthReasonEnum::TargetNotRegistered => "Target.NotRegistered",
+            TargetHealthReasonEnum::TargetNotInUse => "Target.NotInUse",
+            TargetHealthReasonEnum::TargetDeregistrationInProgress => {
+                "Target.DeregistrationInProgress"
+            }
+            TargetHealthReasonEnum::TargetInvalidState => "Target.InvalidState",
+            TargetHealthReasonEnum::TargetIpUnusable => "Target.IpUnusable",
+            TargetHealthReasonEnum::TargetHealthCheckDisabled => "Target.HealthCheckDisabled",
+            TargetHealthReasonEnum::ElbInternalError => "Elb.InternalError",
+            TargetHealthReasonEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TargetHealthReasonEnum {
+    fn from(value: &'a str) -> TargetHealthReasonEnum {
+        match value {
+            "Elb.RegistrationInProgress" => TargetHealthReasonEnum::ElbRegistrationInProgress,
+            "Elb.InitialHealthChecking" => TargetHealthReasonEnum::ElbInitialHealthChecking,
+            "Target.ResponseCodeMismatch" => TargetHealthReasonEnum::TargetResponseCodeMismatch,
+            "Target.Timeout" => TargetHealthReasonEnum::TargetTimeout,
+            "Target.FailedHealthChecks" => TargetHealthReasonEnum::TargetFailedHealthChecks,
+            "Target.NotRegistered" => TargetHealthReasonEnum::TargetNotRegistered,
+            "Target.NotInUse" => TargetHealthReasonEnum::TargetNotInUse,
+            "Target.DeregistrationInProgress" => {
+                TargetHealthReasonEnum::TargetDeregistrationInProgress
+            }
+            "Target.InvalidState" => TargetHealthReasonEnum::TargetInvalidState,
+            "Target.IpUnusable" => TargetHealthReasonEnum::TargetIpUnusable,
+            "Target.HealthCheckDisabled" => TargetHealthReasonEnum::TargetHealthCheckDisabled,
+            "Elb.InternalError" => TargetHealthReasonEnum::ElbInternalError,
+            _ => TargetHealthReasonEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetHealthReasonEnum {
+    fn from(value: String) -> TargetHealthReasonEnum {
+        TargetHealthReasonEnum::from(value.as_str())
+    }
+}
+
+impl From<TargetHealthReasonEnum> for String {
+    fn from(value: TargetHealthReasonEnum) -> String {
+        match value {
+            TargetHealthReasonEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TargetHealthReasonEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetHealthReasonEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct TargetHealthStateEnumDeserializer;
 impl TargetHealthStateEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TargetHealthStateEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct TargetIdDeserializer;
+/// <p>The state of the target.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TargetHealthStateEnum {
+    /// <p><code>initial</code></p>
+    Initial,
+    /// <p><code>healthy</code></p>
+    Healthy,
+    /// <p><code>unhealthy</code></p>
+    Unhealthy,
+    /// <p><code>unused</code></p>
+    Unused,
+    /// <p><code>draining</code></p>
+    Draining,
+    /// <p><code>unavailable</code></p>
+    Unavailable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TargetHealthStateEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetHealthStateEnum::Initial => "initial",
+            TargetHealthStateEnum::Healthy => "healthy",
+            TargetHealthStateEnum::Unhealthy => "unhealthy",
+            TargetHealthStateEnum::Unused => "unused",
+            TargetHealthStateEnum::Draining => "draining",
+            TargetHealthStateEnum::Unavailable => "unavailable",
+            TargetHealthStateEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TargetHealthStateEnum {
+    fn from(value: &'a str) -> TargetHealthStateEnum {
+        match value {
+            "initial" => TargetHealthStateEnum::Initial,
+            "healthy" => TargetHealthStateEnum::Healthy,
+            "unhealthy" => TargetHealthStateEnum::Unhealthy,
+            "unused" => TargetHealthStateEnum::Unused,
+            "draining" => TargetHealthStateEnum::Draining,
+            "unavailable" => TargetHealthStateEnum::Unavailable,
+            _ => TargetHealthStateEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetHealthStateEnum {
+    fn from(value: String) -> TargetHealthStateEnum {
+        TargetHealthStateEnum::from(value.as_str())
+    }
+}
+
+impl From<TargetHealthStateEnum> for String {
+    fn from(value: TargetHealthStateEnum) -> String {
+        match value {
+            TargetHealthStateEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TargetHealthStateEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetHealthStateEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct TargetIdDeserializer;
 impl TargetIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5954,21 +8684,94 @@ impl TargetIdDeserializer {
         Ok(obj)
     }
 }
-struct TargetTypeEnumDeserializer;
+pub(crate) struct TargetTypeEnumDeserializer;
 impl TargetTypeEnumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TargetTypeEnum, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct VpcIdDeserializer;
+/// <p><p>The type of target that you must specify when registering targets with this target group. You can&#39;t specify targets for a target group using more than one target type.</p> <ul> <li> <p> <code>instance</code> - Targets are specified by instance ID. This is the default value. If the target group protocol is UDP or TCP_UDP, the target type must be <code>instance</code>.</p> </li> <li> <p> <code>ip</code> - Targets are specified by IP address. You can specify IP addresses from the subnets of the virtual private cloud (VPC) for the target group, the RFC 1918 range (10.0.0.0/8, 172.16.0.0/12, and 192.168.0.0/16), and the RFC 6598 range (100.64.0.0/10). You can&#39;t specify publicly routable IP addresses.</p> </li> <li> <p> <code>lambda</code> - The target groups contains a single Lambda function.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TargetTypeEnum {
+    /// <p><code>instance</code> - Targets are specified by instance ID. This is the default value. If the target group protocol is UDP or TCP_UDP, the target type must be <code>instance</code>.</p>
+    Instance,
+    /// <p><code>ip</code> - Targets are specified by IP address. You can specify IP addresses from the subnets of the virtual private cloud (VPC) for the target group, the RFC 1918 range (10.0.0.0/8, 172.16.0.0/12, and 192.168.0.0/16), and the RFC 6598 range (100.64.0.0/10). You can&#39;t specify publicly routable IP addresses.</p>
+    Ip,
+    /// <p><code>lambda</code> - The target groups contains a single Lambda function.</p>
+    Lambda,
+    /// <p><code>alb</code></p>
+    Alb,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TargetTypeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetTypeEnum::Instance => "instance",
+            TargetTypeEnum::Ip => "ip",
+            TargetTypeEnum::Lambda => "lambda",
+            TargetTypeEnum::Alb => "alb",
+            TargetTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TargetTypeEnum {
+    fn from(value: &'a str) -> TargetTypeEnum {
+        match value {
+            "instance" => TargetTypeEnum::Instance,
+            "ip" => TargetTypeEnum::Ip,
+            "lambda" => TargetTypeEnum::Lambda,
+            "alb" => TargetTypeEnum::Alb,
+            _ => TargetTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetTypeEnum {
+    fn from(value: String) -> TargetTypeEnum {
+        TargetTypeEnum::from(value.as_str())
+    }
+}
+
+impl From<TargetTypeEnum> for String {
+    fn from(value: TargetTypeEnum) -> String {
+        match value {
+            TargetTypeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TargetTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct VpcIdDeserializer;
 impl VpcIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -5976,10 +8779,13 @@ impl VpcIdDeserializer {
         Ok(obj)
     }
 }
-struct ZoneNameDeserializer;
+pub(crate) struct ZoneNameDeserializer;
 impl ZoneNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -8658,6 +11464,217 @@ pub trait Elb {
         input: SetSubnetsInput,
     ) -> RusotoFuture<SetSubnetsOutput, SetSubnetsError>;
 }
+impl<T: ?Sized + Elb> Elb for ::std::sync::Arc<T> {
+    fn add_listener_certificates(
+        &self,
+        input: AddListenerCertificatesInput,
+    ) -> RusotoFuture<AddListenerCertificatesOutput, AddListenerCertificatesError> {
+        (**self).add_listener_certificates(input)
+    }
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_listener(
+        &self,
+        input: CreateListenerInput,
+    ) -> RusotoFuture<CreateListenerOutput, CreateListenerError> {
+        (**self).create_listener(input)
+    }
+    fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerInput,
+    ) -> RusotoFuture<CreateLoadBalancerOutput, CreateLoadBalancerError> {
+        (**self).create_load_balancer(input)
+    }
+    fn create_rule(
+        &self,
+        input: CreateRuleInput,
+    ) -> RusotoFuture<CreateRuleOutput, CreateRuleError> {
+        (**self).create_rule(input)
+    }
+    fn create_target_group(
+        &self,
+        input: CreateTargetGroupInput,
+    ) -> RusotoFuture<CreateTargetGroupOutput, CreateTargetGroupError> {
+        (**self).create_target_group(input)
+    }
+    fn delete_listener(
+        &self,
+        input: DeleteListenerInput,
+    ) -> RusotoFuture<DeleteListenerOutput, DeleteListenerError> {
+        (**self).delete_listener(input)
+    }
+    fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerInput,
+    ) -> RusotoFuture<DeleteLoadBalancerOutput, DeleteLoadBalancerError> {
+        (**self).delete_load_balancer(input)
+    }
+    fn delete_rule(
+        &self,
+        input: DeleteRuleInput,
+    ) -> RusotoFuture<DeleteRuleOutput, DeleteRuleError> {
+        (**self).delete_rule(input)
+    }
+    fn delete_target_group(
+        &self,
+        input: DeleteTargetGroupInput,
+    ) -> RusotoFuture<DeleteTargetGroupOutput, DeleteTargetGroupError> {
+        (**self).delete_target_group(input)
+    }
+    fn deregister_targets(
+        &self,
+        input: DeregisterTargetsInput,
+    ) -> RusotoFuture<DeregisterTargetsOutput, DeregisterTargetsError> {
+        (**self).deregister_targets(input)
+    }
+    fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> RusotoFuture<DescribeAccountLimitsOutput, DescribeAccountLimitsError> {
+        (**self).describe_account_limits(input)
+    }
+    fn describe_listener_certificates(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> RusotoFuture<DescribeListenerCertificatesOutput, DescribeListenerCertificatesError> {
+        (**self).describe_listener_certificates(input)
+    }
+    fn describe_listeners(
+        &self,
+        input: DescribeListenersInput,
+    ) -> RusotoFuture<DescribeListenersOutput, DescribeListenersError> {
+        (**self).describe_listeners(input)
+    }
+    fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> RusotoFuture<DescribeLoadBalancerAttributesOutput, DescribeLoadBalancerAttributesError>
+    {
+        (**self).describe_load_balancer_attributes(input)
+    }
+    fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> RusotoFuture<DescribeLoadBalancersOutput, DescribeLoadBalancersError> {
+        (**self).describe_load_balancers(input)
+    }
+    fn describe_rules(
+        &self,
+        input: DescribeRulesInput,
+    ) -> RusotoFuture<DescribeRulesOutput, DescribeRulesError> {
+        (**self).describe_rules(input)
+    }
+    fn describe_ssl_policies(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> RusotoFuture<DescribeSSLPoliciesOutput, DescribeSSLPoliciesError> {
+        (**self).describe_ssl_policies(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn describe_target_group_attributes(
+        &self,
+        input: DescribeTargetGroupAttributesInput,
+    ) -> RusotoFuture<DescribeTargetGroupAttributesOutput, DescribeTargetGroupAttributesError> {
+        (**self).describe_target_group_attributes(input)
+    }
+    fn describe_target_groups(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> RusotoFuture<DescribeTargetGroupsOutput, DescribeTargetGroupsError> {
+        (**self).describe_target_groups(input)
+    }
+    fn describe_target_health(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> RusotoFuture<DescribeTargetHealthOutput, DescribeTargetHealthError> {
+        (**self).describe_target_health(input)
+    }
+    fn modify_listener(
+        &self,
+        input: ModifyListenerInput,
+    ) -> RusotoFuture<ModifyListenerOutput, ModifyListenerError> {
+        (**self).modify_listener(input)
+    }
+    fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> RusotoFuture<ModifyLoadBalancerAttributesOutput, ModifyLoadBalancerAttributesError> {
+        (**self).modify_load_balancer_attributes(input)
+    }
+    fn modify_rule(
+        &self,
+        input: ModifyRuleInput,
+    ) -> RusotoFuture<ModifyRuleOutput, ModifyRuleError> {
+        (**self).modify_rule(input)
+    }
+    fn modify_target_group(
+        &self,
+        input: ModifyTargetGroupInput,
+    ) -> RusotoFuture<ModifyTargetGroupOutput, ModifyTargetGroupError> {
+        (**self).modify_target_group(input)
+    }
+    fn modify_target_group_attributes(
+        &self,
+        input: ModifyTargetGroupAttributesInput,
+    ) -> RusotoFuture<ModifyTargetGroupAttributesOutput, ModifyTargetGroupAttributesError> {
+        (**self).modify_target_group_attributes(input)
+    }
+    fn register_targets(
+        &self,
+        input: RegisterTargetsInput,
+    ) -> RusotoFuture<RegisterTargetsOutput, RegisterTargetsError> {
+        (**self).register_targets(input)
+    }
+    fn remove_listener_certificates(
+        &self,
+        input: RemoveListenerCertificatesInput,
+    ) -> RusotoFuture<RemoveListenerCertificatesOutput, RemoveListenerCertificatesError> {
+        (**self).remove_listener_certificates(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn set_ip_address_type(
+        &self,
+        input: SetIpAddressTypeInput,
+    ) -> RusotoFuture<SetIpAddressTypeOutput, SetIpAddressTypeError> {
+        (**self).set_ip_address_type(input)
+    }
+    fn set_rule_priorities(
+        &self,
+        input: SetRulePrioritiesInput,
+    ) -> RusotoFuture<SetRulePrioritiesOutput, SetRulePrioritiesError> {
+        (**self).set_rule_priorities(input)
+    }
+    fn set_security_groups(
+        &self,
+        input: SetSecurityGroupsInput,
+    ) -> RusotoFuture<SetSecurityGroupsOutput, SetSecurityGroupsError> {
+        (**self).set_security_groups(input)
+    }
+    fn set_subnets(
+        &self,
+        input: SetSubnetsInput,
+    ) -> RusotoFuture<SetSubnetsOutput, SetSubnetsError> {
+        (**self).set_subnets(input)
+    }
+}
+/// The error codes Elastic Load Balancing v2 retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Elastic Load Balancing v2 API.
 #[derive(Clone)]
 pub struct ElbClient {
@@ -8673,6 +11690,27 @@ impl ElbClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ELBV2`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ElbClient {
+        Self::new(region::Region::from_env_for_service("ELBV2"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `elasticloadbalancing`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ElbClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("elasticloadbalancing", "ELBV2"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -8691,7 +11729,54 @@ impl ElbClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ElbClient {
-        ElbClient { client, region }
+        ElbClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "elasticloadbalancing")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "elasticloadbalancing",
+            "elasticloadbalancing",
+            "ELBV2",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Elb` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Elb + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ElbClient {
+    fn service_name(&self) -> &'static str {
+        "Elastic Load Balancing v2"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "elasticloadbalancing"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ElbClient {
+        Self::new_with_client(client, region)
     }
 }
 
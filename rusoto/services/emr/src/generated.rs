@@ -22,8 +22,89 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p>The action to take when the cluster step fails. Possible values are TERMINATE_CLUSTER, CANCEL_AND_WAIT, and CONTINUE. TERMINATE_JOB_FLOW is provided for backward compatibility. We recommend using TERMINATE_CLUSTER instead.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionOnFailure {
+    /// <p><code>TERMINATE_JOB_FLOW</code></p>
+    TerminateJobFlow,
+    /// <p><code>TERMINATE_CLUSTER</code></p>
+    TerminateCluster,
+    /// <p><code>CANCEL_AND_WAIT</code></p>
+    CancelAndWait,
+    /// <p><code>CONTINUE</code></p>
+    Continue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionOnFailure {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionOnFailure::TerminateJobFlow => "TERMINATE_JOB_FLOW",
+            ActionOnFailure::TerminateCluster => "TERMINATE_CLUSTER",
+            ActionOnFailure::CancelAndWait => "CANCEL_AND_WAIT",
+            ActionOnFailure::Continue => "CONTINUE",
+            ActionOnFailure::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionOnFailure {
+    fn from(value: &'a str) -> ActionOnFailure {
+        match value {
+            "TERMINATE_JOB_FLOW" => ActionOnFailure::TerminateJobFlow,
+            "TERMINATE_CLUSTER" => ActionOnFailure::TerminateCluster,
+            "CANCEL_AND_WAIT" => ActionOnFailure::CancelAndWait,
+            "CONTINUE" => ActionOnFailure::Continue,
+            _ => ActionOnFailure::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionOnFailure {
+    fn from(value: String) -> ActionOnFailure {
+        ActionOnFailure::from(value.as_str())
+    }
+}
+
+impl From<ActionOnFailure> for String {
+    fn from(value: ActionOnFailure) -> String {
+        match value {
+            ActionOnFailure::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionOnFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ActionOnFailure {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionOnFailure {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ActionOnFailure, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ActionOnFailure::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AddInstanceFleetInput {
     /// <p>The unique identifier of the cluster.</p>
     #[serde(rename = "ClusterId")]
@@ -33,6 +114,46 @@ pub struct AddInstanceFleetInput {
     pub instance_fleet: InstanceFleetConfig,
 }
 
+impl AddInstanceFleetInput {
+    /// Returns a builder for `AddInstanceFleetInput`, checking its required members when built.
+    pub fn builder() -> AddInstanceFleetInputBuilder {
+        AddInstanceFleetInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddInstanceFleetInput`](struct.AddInstanceFleetInput.html).
+#[derive(Default, Debug)]
+pub struct AddInstanceFleetInputBuilder {
+    cluster_id: Option<String>,
+    instance_fleet: Option<InstanceFleetConfig>,
+}
+
+impl AddInstanceFleetInputBuilder {
+    /// Sets the [`cluster_id`](struct.AddInstanceFleetInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_fleet`](struct.AddInstanceFleetInput.html#structfield.instance_fleet) member.
+    pub fn instance_fleet<T: Into<InstanceFleetConfig>>(mut self, value: T) -> Self {
+        self.instance_fleet = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddInstanceFleetInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddInstanceFleetInput, ::rusoto_core::BuildError> {
+        Ok(AddInstanceFleetInput {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            instance_fleet: self
+                .instance_fleet
+                .ok_or(::rusoto_core::BuildError::missing("instance_fleet"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddInstanceFleetOutput {
@@ -57,6 +178,46 @@ pub struct AddInstanceGroupsInput {
     pub job_flow_id: String,
 }
 
+impl AddInstanceGroupsInput {
+    /// Returns a builder for `AddInstanceGroupsInput`, checking its required members when built.
+    pub fn builder() -> AddInstanceGroupsInputBuilder {
+        AddInstanceGroupsInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddInstanceGroupsInput`](struct.AddInstanceGroupsInput.html).
+#[derive(Default, Debug)]
+pub struct AddInstanceGroupsInputBuilder {
+    instance_groups: Option<Vec<InstanceGroupConfig>>,
+    job_flow_id: Option<String>,
+}
+
+impl AddInstanceGroupsInputBuilder {
+    /// Sets the [`instance_groups`](struct.AddInstanceGroupsInput.html#structfield.instance_groups) member.
+    pub fn instance_groups<T: Into<Vec<InstanceGroupConfig>>>(mut self, value: T) -> Self {
+        self.instance_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_flow_id`](struct.AddInstanceGroupsInput.html#structfield.job_flow_id) member.
+    pub fn job_flow_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_flow_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddInstanceGroupsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddInstanceGroupsInput, ::rusoto_core::BuildError> {
+        Ok(AddInstanceGroupsInput {
+            instance_groups: self
+                .instance_groups
+                .ok_or(::rusoto_core::BuildError::missing("instance_groups"))?,
+            job_flow_id: self
+                .job_flow_id
+                .ok_or(::rusoto_core::BuildError::missing("job_flow_id"))?,
+        })
+    }
+}
+
 /// <p>Output from an AddInstanceGroups call.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -82,6 +243,46 @@ pub struct AddJobFlowStepsInput {
     pub steps: Vec<StepConfig>,
 }
 
+impl AddJobFlowStepsInput {
+    /// Returns a builder for `AddJobFlowStepsInput`, checking its required members when built.
+    pub fn builder() -> AddJobFlowStepsInputBuilder {
+        AddJobFlowStepsInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddJobFlowStepsInput`](struct.AddJobFlowStepsInput.html).
+#[derive(Default, Debug)]
+pub struct AddJobFlowStepsInputBuilder {
+    job_flow_id: Option<String>,
+    steps: Option<Vec<StepConfig>>,
+}
+
+impl AddJobFlowStepsInputBuilder {
+    /// Sets the [`job_flow_id`](struct.AddJobFlowStepsInput.html#structfield.job_flow_id) member.
+    pub fn job_flow_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_flow_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`steps`](struct.AddJobFlowStepsInput.html#structfield.steps) member.
+    pub fn steps<T: Into<Vec<StepConfig>>>(mut self, value: T) -> Self {
+        self.steps = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddJobFlowStepsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddJobFlowStepsInput, ::rusoto_core::BuildError> {
+        Ok(AddJobFlowStepsInput {
+            job_flow_id: self
+                .job_flow_id
+                .ok_or(::rusoto_core::BuildError::missing("job_flow_id"))?,
+            steps: self
+                .steps
+                .ok_or(::rusoto_core::BuildError::missing("steps"))?,
+        })
+    }
+}
+
 /// <p> The output for the <a>AddJobFlowSteps</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -103,11 +304,127 @@ pub struct AddTagsInput {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsInput {
+    /// Returns a builder for `AddTagsInput`, checking its required members when built.
+    pub fn builder() -> AddTagsInputBuilder {
+        AddTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsInput`](struct.AddTagsInput.html).
+#[derive(Default, Debug)]
+pub struct AddTagsInputBuilder {
+    resource_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsInputBuilder {
+    /// Sets the [`resource_id`](struct.AddTagsInput.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddTagsInput, ::rusoto_core::BuildError> {
+        Ok(AddTagsInput {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>This output indicates the result of adding tags to a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddTagsOutput {}
 
+/// <p>The way in which EC2 instances are added (if <code>ScalingAdjustment</code> is a positive number) or terminated (if <code>ScalingAdjustment</code> is a negative number) each time the scaling activity is triggered. <code>CHANGE_IN_CAPACITY</code> is the default. <code>CHANGE_IN_CAPACITY</code> indicates that the EC2 instance count increments or decrements by <code>ScalingAdjustment</code>, which should be expressed as an integer. <code>PERCENT_CHANGE_IN_CAPACITY</code> indicates the instance count increments or decrements by the percentage specified by <code>ScalingAdjustment</code>, which should be expressed as an integer. For example, 20 indicates an increase in 20% increments of cluster capacity. <code>EXACT_CAPACITY</code> indicates the scaling activity results in an instance group with the number of EC2 instances specified by <code>ScalingAdjustment</code>, which should be expressed as a positive integer.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AdjustmentType {
+    /// <p><code>CHANGE_IN_CAPACITY</code></p>
+    ChangeInCapacity,
+    /// <p><code>PERCENT_CHANGE_IN_CAPACITY</code></p>
+    PercentChangeInCapacity,
+    /// <p><code>EXACT_CAPACITY</code></p>
+    ExactCapacity,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AdjustmentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdjustmentType::ChangeInCapacity => "CHANGE_IN_CAPACITY",
+            AdjustmentType::PercentChangeInCapacity => "PERCENT_CHANGE_IN_CAPACITY",
+            AdjustmentType::ExactCapacity => "EXACT_CAPACITY",
+            AdjustmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AdjustmentType {
+    fn from(value: &'a str) -> AdjustmentType {
+        match value {
+            "CHANGE_IN_CAPACITY" => AdjustmentType::ChangeInCapacity,
+            "PERCENT_CHANGE_IN_CAPACITY" => AdjustmentType::PercentChangeInCapacity,
+            "EXACT_CAPACITY" => AdjustmentType::ExactCapacity,
+            _ => AdjustmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdjustmentType {
+    fn from(value: String) -> AdjustmentType {
+        AdjustmentType::from(value.as_str())
+    }
+}
+
+impl From<AdjustmentType> for String {
+    fn from(value: AdjustmentType) -> String {
+        match value {
+            AdjustmentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AdjustmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AdjustmentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdjustmentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AdjustmentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AdjustmentType::from(value))
+    }
+}
+
 /// <p>With Amazon EMR release version 4.0 and later, the only accepted parameter is the application name. To pass arguments to applications, you use configuration classifications specified using configuration JSON objects. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ReleaseGuide/emr-configure-apps.html">Configuring Applications</a>.</p> <p>With earlier Amazon EMR releases, the application is any Amazon or third-party software that you can add to the cluster. This structure contains a list of strings that indicates the software to use with the cluster and accepts a user argument list. Amazon EMR accepts and forwards the argument list to the corresponding installation script as bootstrap action argument.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Application {
@@ -158,6 +475,94 @@ pub struct AutoScalingPolicyDescription {
     pub status: Option<AutoScalingPolicyStatus>,
 }
 
+/// <p>Indicates the status of the automatic scaling policy.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AutoScalingPolicyState {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>ATTACHING</code></p>
+    Attaching,
+    /// <p><code>ATTACHED</code></p>
+    Attached,
+    /// <p><code>DETACHING</code></p>
+    Detaching,
+    /// <p><code>DETACHED</code></p>
+    Detached,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AutoScalingPolicyState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoScalingPolicyState::Pending => "PENDING",
+            AutoScalingPolicyState::Attaching => "ATTACHING",
+            AutoScalingPolicyState::Attached => "ATTACHED",
+            AutoScalingPolicyState::Detaching => "DETACHING",
+            AutoScalingPolicyState::Detached => "DETACHED",
+            AutoScalingPolicyState::Failed => "FAILED",
+            AutoScalingPolicyState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AutoScalingPolicyState {
+    fn from(value: &'a str) -> AutoScalingPolicyState {
+        match value {
+            "PENDING" => AutoScalingPolicyState::Pending,
+            "ATTACHING" => AutoScalingPolicyState::Attaching,
+            "ATTACHED" => AutoScalingPolicyState::Attached,
+            "DETACHING" => AutoScalingPolicyState::Detaching,
+            "DETACHED" => AutoScalingPolicyState::Detached,
+            "FAILED" => AutoScalingPolicyState::Failed,
+            _ => AutoScalingPolicyState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoScalingPolicyState {
+    fn from(value: String) -> AutoScalingPolicyState {
+        AutoScalingPolicyState::from(value.as_str())
+    }
+}
+
+impl From<AutoScalingPolicyState> for String {
+    fn from(value: AutoScalingPolicyState) -> String {
+        match value {
+            AutoScalingPolicyState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AutoScalingPolicyState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AutoScalingPolicyState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutoScalingPolicyState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AutoScalingPolicyState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AutoScalingPolicyState::from(value))
+    }
+}
+
 /// <p>The reason for an <a>AutoScalingPolicyStatus</a> change.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -165,13 +570,91 @@ pub struct AutoScalingPolicyStateChangeReason {
     /// <p>The code indicating the reason for the change in status.<code>USER_REQUEST</code> indicates that the scaling policy status was changed by a user. <code>PROVISION_FAILURE</code> indicates that the status change was because the policy failed to provision. <code>CLEANUP_FAILURE</code> indicates an error.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<AutoScalingPolicyStateChangeReasonCode>,
     /// <p>A friendly, more verbose message that accompanies an automatic scaling policy state change.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+/// <p>The code indicating the reason for the change in status.<code>USER_REQUEST</code> indicates that the scaling policy status was changed by a user. <code>PROVISION_FAILURE</code> indicates that the status change was because the policy failed to provision. <code>CLEANUP_FAILURE</code> indicates an error.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AutoScalingPolicyStateChangeReasonCode {
+    /// <p><code>USER_REQUEST</code></p>
+    UserRequest,
+    /// <p><code>PROVISION_FAILURE</code></p>
+    ProvisionFailure,
+    /// <p><code>CLEANUP_FAILURE</code></p>
+    CleanupFailure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AutoScalingPolicyStateChangeReasonCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoScalingPolicyStateChangeReasonCode::UserRequest => "USER_REQUEST",
+            AutoScalingPolicyStateChangeReasonCode::ProvisionFailure => "PROVISION_FAILURE",
+            AutoScalingPolicyStateChangeReasonCode::CleanupFailure => "CLEANUP_FAILURE",
+            AutoScalingPolicyStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AutoScalingPolicyStateChangeReasonCode {
+    fn from(value: &'a str) -> AutoScalingPolicyStateChangeReasonCode {
+        match value {
+            "USER_REQUEST" => AutoScalingPolicyStateChangeReasonCode::UserRequest,
+            "PROVISION_FAILURE" => AutoScalingPolicyStateChangeReasonCode::ProvisionFailure,
+            "CLEANUP_FAILURE" => AutoScalingPolicyStateChangeReasonCode::CleanupFailure,
+            _ => AutoScalingPolicyStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoScalingPolicyStateChangeReasonCode {
+    fn from(value: String) -> AutoScalingPolicyStateChangeReasonCode {
+        AutoScalingPolicyStateChangeReasonCode::from(value.as_str())
+    }
+}
+
+impl From<AutoScalingPolicyStateChangeReasonCode> for String {
+    fn from(value: AutoScalingPolicyStateChangeReasonCode) -> String {
+        match value {
+            AutoScalingPolicyStateChangeReasonCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AutoScalingPolicyStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AutoScalingPolicyStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutoScalingPolicyStateChangeReasonCode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<AutoScalingPolicyStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AutoScalingPolicyStateChangeReasonCode::from(value))
+    }
+}
+
 /// <p>The status of an automatic scaling policy. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -179,7 +662,7 @@ pub struct AutoScalingPolicyStatus {
     /// <p>Indicates the status of the automatic scaling policy.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<AutoScalingPolicyState>,
     /// <p>The reason for a change in status.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -207,7 +690,8 @@ pub struct BlockPublicAccessConfigurationMetadata {
     pub created_by_arn: String,
     /// <p>The date and time that the configuration was created.</p>
     #[serde(rename = "CreationDateTime")]
-    pub creation_date_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date_time: EpochTimestamp,
 }
 
 /// <p>Configuration of a bootstrap action.</p>
@@ -242,7 +726,7 @@ pub struct CancelStepsInfo {
     /// <p>The status of a CancelSteps Request. The value may be SUBMITTED or FAILED.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CancelStepsRequestStatus>,
     /// <p>The encrypted StepId of a step.</p>
     #[serde(rename = "StepId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -262,6 +746,42 @@ pub struct CancelStepsInput {
     pub step_ids: Option<Vec<String>>,
 }
 
+impl CancelStepsInput {
+    /// Returns a builder for `CancelStepsInput`, checking its required members when built.
+    pub fn builder() -> CancelStepsInputBuilder {
+        CancelStepsInputBuilder::default()
+    }
+}
+
+/// Builds a [`CancelStepsInput`](struct.CancelStepsInput.html).
+#[derive(Default, Debug)]
+pub struct CancelStepsInputBuilder {
+    cluster_id: Option<String>,
+    step_ids: Option<Vec<String>>,
+}
+
+impl CancelStepsInputBuilder {
+    /// Sets the [`cluster_id`](struct.CancelStepsInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`step_ids`](struct.CancelStepsInput.html#structfield.step_ids) member.
+    pub fn step_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.step_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelStepsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelStepsInput, ::rusoto_core::BuildError> {
+        Ok(CancelStepsInput {
+            cluster_id: self.cluster_id,
+            step_ids: self.step_ids,
+        })
+    }
+}
+
 /// <p> The output for the <a>CancelSteps</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -272,12 +792,84 @@ pub struct CancelStepsOutput {
     pub cancel_steps_info_list: Option<Vec<CancelStepsInfo>>,
 }
 
+/// <p>The status of a CancelSteps Request. The value may be SUBMITTED or FAILED.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CancelStepsRequestStatus {
+    /// <p><code>SUBMITTED</code></p>
+    Submitted,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CancelStepsRequestStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CancelStepsRequestStatus::Submitted => "SUBMITTED",
+            CancelStepsRequestStatus::Failed => "FAILED",
+            CancelStepsRequestStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CancelStepsRequestStatus {
+    fn from(value: &'a str) -> CancelStepsRequestStatus {
+        match value {
+            "SUBMITTED" => CancelStepsRequestStatus::Submitted,
+            "FAILED" => CancelStepsRequestStatus::Failed,
+            _ => CancelStepsRequestStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CancelStepsRequestStatus {
+    fn from(value: String) -> CancelStepsRequestStatus {
+        CancelStepsRequestStatus::from(value.as_str())
+    }
+}
+
+impl From<CancelStepsRequestStatus> for String {
+    fn from(value: CancelStepsRequestStatus) -> String {
+        match value {
+            CancelStepsRequestStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CancelStepsRequestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CancelStepsRequestStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CancelStepsRequestStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CancelStepsRequestStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CancelStepsRequestStatus::from(value))
+    }
+}
+
 /// <p>The definition of a CloudWatch metric alarm, which determines when an automatic scaling activity is triggered. When the defined alarm conditions are satisfied, scaling activity begins.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CloudWatchAlarmDefinition {
     /// <p>Determines how the metric specified by <code>MetricName</code> is compared to the value specified by <code>Threshold</code>.</p>
     #[serde(rename = "ComparisonOperator")]
-    pub comparison_operator: String,
+    pub comparison_operator: ComparisonOperator,
     /// <p>A CloudWatch metric dimension.</p>
     #[serde(rename = "Dimensions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -299,14 +891,14 @@ pub struct CloudWatchAlarmDefinition {
     /// <p>The statistic to apply to the metric associated with the alarm. The default is <code>AVERAGE</code>.</p>
     #[serde(rename = "Statistic")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub statistic: Option<String>,
+    pub statistic: Option<Statistic>,
     /// <p>The value against which the specified statistic is compared.</p>
     #[serde(rename = "Threshold")]
     pub threshold: f64,
     /// <p>The unit of measure associated with the CloudWatch metric being watched. The value specified for <code>Unit</code> must correspond to the units specified in the CloudWatch metric.</p>
     #[serde(rename = "Unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<Unit>,
 }
 
 /// <p>The detailed description of the cluster.</p>
@@ -348,7 +940,7 @@ pub struct Cluster {
     /// <p><note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note> <p>The instance group configuration of the cluster. A value of <code>INSTANCE<em>GROUP</code> indicates a uniform instance group configuration. A value of <code>INSTANCE</em>FLEET</code> indicates an instance fleets configuration.</p></p>
     #[serde(rename = "InstanceCollectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_collection_type: Option<String>,
+    pub instance_collection_type: Option<InstanceCollectionType>,
     /// <p>Attributes for Kerberos configuration when Kerberos authentication is enabled using a security configuration. For more information see <a href="https://docs.aws.amazon.com/emr/latest/ManagementGuide/emr-kerberos.html">Use Kerberos Authentication</a> in the <i>EMR Management Guide</i>.</p>
     #[serde(rename = "KerberosAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -376,7 +968,7 @@ pub struct Cluster {
     /// <p>Applies only when <code>CustomAmiID</code> is used. Specifies the type of updates that are applied from the Amazon Linux AMI package repositories when an instance boots using the AMI.</p>
     #[serde(rename = "RepoUpgradeOnBoot")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub repo_upgrade_on_boot: Option<String>,
+    pub repo_upgrade_on_boot: Option<RepoUpgradeOnBoot>,
     /// <p>The AMI version requested for this cluster.</p>
     #[serde(rename = "RequestedAmiVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -388,7 +980,7 @@ pub struct Cluster {
     /// <p>The way that individual Amazon EC2 instances terminate when an automatic scale-in activity occurs or an instance group is resized. <code>TERMINATE_AT_INSTANCE_HOUR</code> indicates that Amazon EMR terminates nodes at the instance-hour boundary, regardless of when the request to terminate the instance was submitted. This option is only available with Amazon EMR 5.1.0 and later and is the default for clusters created using that version. <code>TERMINATE_AT_TASK_COMPLETION</code> indicates that Amazon EMR blacklists and drains tasks from nodes before terminating the Amazon EC2 instances, regardless of the instance-hour boundary. With either behavior, Amazon EMR removes the least active nodes first and blocks instance termination if it could lead to HDFS corruption. <code>TERMINATE_AT_TASK_COMPLETION</code> is available only in Amazon EMR version 4.1.0 and later, and is the default for versions of Amazon EMR earlier than 5.1.0.</p>
     #[serde(rename = "ScaleDownBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scale_down_behavior: Option<String>,
+    pub scale_down_behavior: Option<ScaleDownBehavior>,
     /// <p>The name of the security configuration applied to the cluster.</p>
     #[serde(rename = "SecurityConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -415,6 +1007,98 @@ pub struct Cluster {
     pub visible_to_all_users: Option<bool>,
 }
 
+/// <p>The current state of the cluster.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClusterState {
+    /// <p><code>STARTING</code></p>
+    Starting,
+    /// <p><code>BOOTSTRAPPING</code></p>
+    Bootstrapping,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>WAITING</code></p>
+    Waiting,
+    /// <p><code>TERMINATING</code></p>
+    Terminating,
+    /// <p><code>TERMINATED</code></p>
+    Terminated,
+    /// <p><code>TERMINATED_WITH_ERRORS</code></p>
+    TerminatedWithErrors,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ClusterState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterState::Starting => "STARTING",
+            ClusterState::Bootstrapping => "BOOTSTRAPPING",
+            ClusterState::Running => "RUNNING",
+            ClusterState::Waiting => "WAITING",
+            ClusterState::Terminating => "TERMINATING",
+            ClusterState::Terminated => "TERMINATED",
+            ClusterState::TerminatedWithErrors => "TERMINATED_WITH_ERRORS",
+            ClusterState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ClusterState {
+    fn from(value: &'a str) -> ClusterState {
+        match value {
+            "STARTING" => ClusterState::Starting,
+            "BOOTSTRAPPING" => ClusterState::Bootstrapping,
+            "RUNNING" => ClusterState::Running,
+            "WAITING" => ClusterState::Waiting,
+            "TERMINATING" => ClusterState::Terminating,
+            "TERMINATED" => ClusterState::Terminated,
+            "TERMINATED_WITH_ERRORS" => ClusterState::TerminatedWithErrors,
+            _ => ClusterState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterState {
+    fn from(value: String) -> ClusterState {
+        ClusterState::from(value.as_str())
+    }
+}
+
+impl From<ClusterState> for String {
+    fn from(value: ClusterState) -> String {
+        match value {
+            ClusterState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ClusterState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ClusterState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ClusterState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ClusterState::from(value))
+    }
+}
+
 /// <p>The reason that the cluster changed to its current state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -422,21 +1106,119 @@ pub struct ClusterStateChangeReason {
     /// <p>The programmatic code for the state change reason.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<ClusterStateChangeReasonCode>,
     /// <p>The descriptive message for the state change reason.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
-/// <p>The detailed status of the cluster.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ClusterStatus {
-    /// <p>The current state of the cluster.</p>
+/// <p>The programmatic code for the state change reason.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClusterStateChangeReasonCode {
+    /// <p><code>INTERNAL_ERROR</code></p>
+    InternalError,
+    /// <p><code>VALIDATION_ERROR</code></p>
+    ValidationError,
+    /// <p><code>INSTANCE_FAILURE</code></p>
+    InstanceFailure,
+    /// <p><code>INSTANCE_FLEET_TIMEOUT</code></p>
+    InstanceFleetTimeout,
+    /// <p><code>BOOTSTRAP_FAILURE</code></p>
+    BootstrapFailure,
+    /// <p><code>USER_REQUEST</code></p>
+    UserRequest,
+    /// <p><code>STEP_FAILURE</code></p>
+    StepFailure,
+    /// <p><code>ALL_STEPS_COMPLETED</code></p>
+    AllStepsCompleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ClusterStateChangeReasonCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterStateChangeReasonCode::InternalError => "INTERNAL_ERROR",
+            ClusterStateChangeReasonCode::ValidationError => "VALIDATION_ERROR",
+            ClusterStateChangeReasonCode::InstanceFailure => "INSTANCE_FAILURE",
+            ClusterStateChangeReasonCode::InstanceFleetTimeout => "INSTANCE_FLEET_TIMEOUT",
+            ClusterStateChangeReasonCode::BootstrapFailure => "BOOTSTRAP_FAILURE",
+            ClusterStateChangeReasonCode::UserRequest => "USER_REQUEST",
+            ClusterStateChangeReasonCode::StepFailure => "STEP_FAILURE",
+            ClusterStateChangeReasonCode::AllStepsCompleted => "ALL_STEPS_COMPLETED",
+            ClusterStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ClusterStateChangeReasonCode {
+    fn from(value: &'a str) -> ClusterStateChangeReasonCode {
+        match value {
+            "INTERNAL_ERROR" => ClusterStateChangeReasonCode::InternalError,
+            "VALIDATION_ERROR" => ClusterStateChangeReasonCode::ValidationError,
+            "INSTANCE_FAILURE" => ClusterStateChangeReasonCode::InstanceFailure,
+            "INSTANCE_FLEET_TIMEOUT" => ClusterStateChangeReasonCode::InstanceFleetTimeout,
+            "BOOTSTRAP_FAILURE" => ClusterStateChangeReasonCode::BootstrapFailure,
+            "USER_REQUEST" => ClusterStateChangeReasonCode::UserRequest,
+            "STEP_FAILURE" => ClusterStateChangeReasonCode::StepFailure,
+            "ALL_STEPS_COMPLETED" => ClusterStateChangeReasonCode::AllStepsCompleted,
+            _ => ClusterStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterStateChangeReasonCode {
+    fn from(value: String) -> ClusterStateChangeReasonCode {
+        ClusterStateChangeReasonCode::from(value.as_str())
+    }
+}
+
+impl From<ClusterStateChangeReasonCode> for String {
+    fn from(value: ClusterStateChangeReasonCode) -> String {
+        match value {
+            ClusterStateChangeReasonCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ClusterStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ClusterStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterStateChangeReasonCode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ClusterStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ClusterStateChangeReasonCode::from(value))
+    }
+}
+
+/// <p>The detailed status of the cluster.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ClusterStatus {
+    /// <p>The current state of the cluster.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ClusterState>,
     /// <p>The reason for the cluster status change.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -475,16 +1257,19 @@ pub struct ClusterSummary {
 pub struct ClusterTimeline {
     /// <p>The creation date and time of the cluster.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The date and time when the cluster was terminated.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<EpochTimestamp>,
     /// <p>The date and time when the cluster was ready to execute steps.</p>
     #[serde(rename = "ReadyDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ready_date_time: Option<f64>,
+    pub ready_date_time: Option<EpochTimestamp>,
 }
 
 /// <p>An entity describing an executable that runs on a cluster.</p>
@@ -505,6 +1290,86 @@ pub struct Command {
     pub script_path: Option<String>,
 }
 
+/// <p>Determines how the metric specified by <code>MetricName</code> is compared to the value specified by <code>Threshold</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonOperator {
+    /// <p><code>GREATER_THAN_OR_EQUAL</code></p>
+    GreaterThanOrEqual,
+    /// <p><code>GREATER_THAN</code></p>
+    GreaterThan,
+    /// <p><code>LESS_THAN</code></p>
+    LessThan,
+    /// <p><code>LESS_THAN_OR_EQUAL</code></p>
+    LessThanOrEqual,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::GreaterThanOrEqual => "GREATER_THAN_OR_EQUAL",
+            ComparisonOperator::GreaterThan => "GREATER_THAN",
+            ComparisonOperator::LessThan => "LESS_THAN",
+            ComparisonOperator::LessThanOrEqual => "LESS_THAN_OR_EQUAL",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "GREATER_THAN_OR_EQUAL" => ComparisonOperator::GreaterThanOrEqual,
+            "GREATER_THAN" => ComparisonOperator::GreaterThan,
+            "LESS_THAN" => ComparisonOperator::LessThan,
+            "LESS_THAN_OR_EQUAL" => ComparisonOperator::LessThanOrEqual,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        ComparisonOperator::from(value.as_str())
+    }
+}
+
+impl From<ComparisonOperator> for String {
+    fn from(value: ComparisonOperator) -> String {
+        match value {
+            ComparisonOperator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ComparisonOperator::from(value))
+    }
+}
+
 /// <p><note> <p>Amazon EMR releases 4.x or later.</p> </note> <p>An optional configuration specification to be used when provisioning cluster instances, which can include configurations for applications and software bundled with Amazon EMR. A configuration consists of a classification, properties, and optional nested configurations. A classification refers to an application-specific configuration file. Properties are the settings you want to change in that file. For more information, see <a href="https://docs.aws.amazon.com/emr/latest/ReleaseGuide/emr-configure-apps.html">Configuring Applications</a>.</p></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Configuration {
@@ -532,12 +1397,55 @@ pub struct CreateSecurityConfigurationInput {
     pub security_configuration: String,
 }
 
+impl CreateSecurityConfigurationInput {
+    /// Returns a builder for `CreateSecurityConfigurationInput`, checking its required members when built.
+    pub fn builder() -> CreateSecurityConfigurationInputBuilder {
+        CreateSecurityConfigurationInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSecurityConfigurationInput`](struct.CreateSecurityConfigurationInput.html).
+#[derive(Default, Debug)]
+pub struct CreateSecurityConfigurationInputBuilder {
+    name: Option<String>,
+    security_configuration: Option<String>,
+}
+
+impl CreateSecurityConfigurationInputBuilder {
+    /// Sets the [`name`](struct.CreateSecurityConfigurationInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_configuration`](struct.CreateSecurityConfigurationInput.html#structfield.security_configuration) member.
+    pub fn security_configuration<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSecurityConfigurationInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSecurityConfigurationInput, ::rusoto_core::BuildError> {
+        Ok(CreateSecurityConfigurationInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            security_configuration: self
+                .security_configuration
+                .ok_or(::rusoto_core::BuildError::missing("security_configuration"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateSecurityConfigurationOutput {
     /// <p>The date and time the security configuration was created.</p>
     #[serde(rename = "CreationDateTime")]
-    pub creation_date_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date_time: EpochTimestamp,
     /// <p>The name of the security configuration.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -550,6 +1458,38 @@ pub struct DeleteSecurityConfigurationInput {
     pub name: String,
 }
 
+impl DeleteSecurityConfigurationInput {
+    /// Returns a builder for `DeleteSecurityConfigurationInput`, checking its required members when built.
+    pub fn builder() -> DeleteSecurityConfigurationInputBuilder {
+        DeleteSecurityConfigurationInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSecurityConfigurationInput`](struct.DeleteSecurityConfigurationInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteSecurityConfigurationInputBuilder {
+    name: Option<String>,
+}
+
+impl DeleteSecurityConfigurationInputBuilder {
+    /// Sets the [`name`](struct.DeleteSecurityConfigurationInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSecurityConfigurationInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSecurityConfigurationInput, ::rusoto_core::BuildError> {
+        Ok(DeleteSecurityConfigurationInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSecurityConfigurationOutput {}
@@ -562,6 +1502,36 @@ pub struct DescribeClusterInput {
     pub cluster_id: String,
 }
 
+impl DescribeClusterInput {
+    /// Returns a builder for `DescribeClusterInput`, checking its required members when built.
+    pub fn builder() -> DescribeClusterInputBuilder {
+        DescribeClusterInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterInput`](struct.DescribeClusterInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterInputBuilder {
+    cluster_id: Option<String>,
+}
+
+impl DescribeClusterInputBuilder {
+    /// Sets the [`cluster_id`](struct.DescribeClusterInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeClusterInput, ::rusoto_core::BuildError> {
+        Ok(DescribeClusterInput {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+        })
+    }
+}
+
 /// <p>This output contains the description of the cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -577,12 +1547,14 @@ pub struct DescribeClusterOutput {
 pub struct DescribeJobFlowsInput {
     /// <p>Return only job flows created after this date and time.</p>
     #[serde(rename = "CreatedAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_after: Option<f64>,
+    pub created_after: Option<EpochTimestamp>,
     /// <p>Return only job flows created before this date and time.</p>
     #[serde(rename = "CreatedBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_before: Option<f64>,
+    pub created_before: Option<EpochTimestamp>,
     /// <p>Return only job flows whose job flow ID is contained in this list.</p>
     #[serde(rename = "JobFlowIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -590,7 +1562,59 @@ pub struct DescribeJobFlowsInput {
     /// <p>Return only job flows whose state is contained in this list.</p>
     #[serde(rename = "JobFlowStates")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_flow_states: Option<Vec<String>>,
+    pub job_flow_states: Option<Vec<JobFlowExecutionState>>,
+}
+
+impl DescribeJobFlowsInput {
+    /// Returns a builder for `DescribeJobFlowsInput`, checking its required members when built.
+    pub fn builder() -> DescribeJobFlowsInputBuilder {
+        DescribeJobFlowsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeJobFlowsInput`](struct.DescribeJobFlowsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeJobFlowsInputBuilder {
+    created_after: Option<EpochTimestamp>,
+    created_before: Option<EpochTimestamp>,
+    job_flow_ids: Option<Vec<String>>,
+    job_flow_states: Option<Vec<JobFlowExecutionState>>,
+}
+
+impl DescribeJobFlowsInputBuilder {
+    /// Sets the [`created_after`](struct.DescribeJobFlowsInput.html#structfield.created_after) member.
+    pub fn created_after<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.created_after = Some(value.into());
+        self
+    }
+
+    /// Sets the [`created_before`](struct.DescribeJobFlowsInput.html#structfield.created_before) member.
+    pub fn created_before<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.created_before = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_flow_ids`](struct.DescribeJobFlowsInput.html#structfield.job_flow_ids) member.
+    pub fn job_flow_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.job_flow_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_flow_states`](struct.DescribeJobFlowsInput.html#structfield.job_flow_states) member.
+    pub fn job_flow_states<T: Into<Vec<JobFlowExecutionState>>>(mut self, value: T) -> Self {
+        self.job_flow_states = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeJobFlowsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeJobFlowsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeJobFlowsInput {
+            created_after: self.created_after,
+            created_before: self.created_before,
+            job_flow_ids: self.job_flow_ids,
+            job_flow_states: self.job_flow_states,
+        })
+    }
 }
 
 /// <p> The output for the <a>DescribeJobFlows</a> operation. </p>
@@ -610,13 +1634,46 @@ pub struct DescribeSecurityConfigurationInput {
     pub name: String,
 }
 
+impl DescribeSecurityConfigurationInput {
+    /// Returns a builder for `DescribeSecurityConfigurationInput`, checking its required members when built.
+    pub fn builder() -> DescribeSecurityConfigurationInputBuilder {
+        DescribeSecurityConfigurationInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSecurityConfigurationInput`](struct.DescribeSecurityConfigurationInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeSecurityConfigurationInputBuilder {
+    name: Option<String>,
+}
+
+impl DescribeSecurityConfigurationInputBuilder {
+    /// Sets the [`name`](struct.DescribeSecurityConfigurationInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSecurityConfigurationInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSecurityConfigurationInput, ::rusoto_core::BuildError> {
+        Ok(DescribeSecurityConfigurationInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeSecurityConfigurationOutput {
     /// <p>The date and time the security configuration was created</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The name of the security configuration.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -638,6 +1695,46 @@ pub struct DescribeStepInput {
     pub step_id: String,
 }
 
+impl DescribeStepInput {
+    /// Returns a builder for `DescribeStepInput`, checking its required members when built.
+    pub fn builder() -> DescribeStepInputBuilder {
+        DescribeStepInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStepInput`](struct.DescribeStepInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStepInputBuilder {
+    cluster_id: Option<String>,
+    step_id: Option<String>,
+}
+
+impl DescribeStepInputBuilder {
+    /// Sets the [`cluster_id`](struct.DescribeStepInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`step_id`](struct.DescribeStepInput.html#structfield.step_id) member.
+    pub fn step_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.step_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStepInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeStepInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStepInput {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            step_id: self
+                .step_id
+                .ok_or(::rusoto_core::BuildError::missing("step_id"))?,
+        })
+    }
+}
+
 /// <p>This output contains the description of the cluster step.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -856,7 +1953,7 @@ pub struct Instance {
     /// <p>The instance purchasing option. Valid values are <code>ON_DEMAND</code> or <code>SPOT</code>. </p>
     #[serde(rename = "Market")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub market: Option<String>,
+    pub market: Option<MarketType>,
     /// <p>The private DNS name of the instance.</p>
     #[serde(rename = "PrivateDnsName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -879,6 +1976,78 @@ pub struct Instance {
     pub status: Option<InstanceStatus>,
 }
 
+/// <p><note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note> <p>The instance group configuration of the cluster. A value of <code>INSTANCE<em>GROUP</code> indicates a uniform instance group configuration. A value of <code>INSTANCE</em>FLEET</code> indicates an instance fleets configuration.</p></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceCollectionType {
+    /// <p><code>INSTANCE_FLEET</code></p>
+    InstanceFleet,
+    /// <p><code>INSTANCE_GROUP</code></p>
+    InstanceGroup,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceCollectionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceCollectionType::InstanceFleet => "INSTANCE_FLEET",
+            InstanceCollectionType::InstanceGroup => "INSTANCE_GROUP",
+            InstanceCollectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceCollectionType {
+    fn from(value: &'a str) -> InstanceCollectionType {
+        match value {
+            "INSTANCE_FLEET" => InstanceCollectionType::InstanceFleet,
+            "INSTANCE_GROUP" => InstanceCollectionType::InstanceGroup,
+            _ => InstanceCollectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceCollectionType {
+    fn from(value: String) -> InstanceCollectionType {
+        InstanceCollectionType::from(value.as_str())
+    }
+}
+
+impl From<InstanceCollectionType> for String {
+    fn from(value: InstanceCollectionType) -> String {
+        match value {
+            InstanceCollectionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceCollectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceCollectionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceCollectionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceCollectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceCollectionType::from(value))
+    }
+}
+
 /// <p><p>Describes an instance fleet, which is a group of EC2 instances that host a particular node type (master, core, or task) in an Amazon EMR cluster. Instance fleets can consist of a mix of instance types and On-Demand and Spot instances, which are provisioned to meet a defined target capacity. </p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -890,7 +2059,7 @@ pub struct InstanceFleet {
     /// <p>The node type that the instance fleet hosts. Valid values are MASTER, CORE, or TASK. </p>
     #[serde(rename = "InstanceFleetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_fleet_type: Option<String>,
+    pub instance_fleet_type: Option<InstanceFleetType>,
     /// <p>The specification for the instance types that comprise an instance fleet. Up to five unique instance specifications may be defined for each instance fleet. </p>
     #[serde(rename = "InstanceTypeSpecifications")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -926,11 +2095,11 @@ pub struct InstanceFleet {
 }
 
 /// <p><p>The configuration that defines an instance fleet.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InstanceFleetConfig {
     /// <p>The node type that the instance fleet hosts. Valid values are MASTER,CORE,and TASK.</p>
     #[serde(rename = "InstanceFleetType")]
-    pub instance_fleet_type: String,
+    pub instance_fleet_type: InstanceFleetType,
     /// <p>The instance type configurations that define the EC2 instances in the instance fleet.</p>
     #[serde(rename = "InstanceTypeConfigs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -970,13 +2139,105 @@ pub struct InstanceFleetModifyConfig {
 }
 
 /// <p><p>The launch specification for Spot instances in the fleet, which determines the defined duration and provisioning timeout behavior.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InstanceFleetProvisioningSpecifications {
     /// <p>The launch specification for Spot instances in the fleet, which determines the defined duration and provisioning timeout behavior.</p>
     #[serde(rename = "SpotSpecification")]
     pub spot_specification: SpotProvisioningSpecification,
 }
 
+/// <p><p>A code representing the instance fleet status.</p> <ul> <li> <p> <code>PROVISIONING</code>—The instance fleet is provisioning EC2 resources and is not yet ready to run jobs.</p> </li> <li> <p> <code>BOOTSTRAPPING</code>—EC2 instances and other resources have been provisioned and the bootstrap actions specified for the instances are underway.</p> </li> <li> <p> <code>RUNNING</code>—EC2 instances and other resources are running. They are either executing jobs or waiting to execute jobs.</p> </li> <li> <p> <code>RESIZING</code>—A resize operation is underway. EC2 instances are either being added or removed.</p> </li> <li> <p> <code>SUSPENDED</code>—A resize operation could not complete. Existing EC2 instances are running, but instances can&#39;t be added or removed.</p> </li> <li> <p> <code>TERMINATING</code>—The instance fleet is terminating EC2 instances.</p> </li> <li> <p> <code>TERMINATED</code>—The instance fleet is no longer active, and all EC2 instances have been terminated.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceFleetState {
+    /// <p><code>PROVISIONING</code></p>
+    Provisioning,
+    /// <p><code>BOOTSTRAPPING</code></p>
+    Bootstrapping,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>RESIZING</code></p>
+    Resizing,
+    /// <p><code>SUSPENDED</code></p>
+    Suspended,
+    /// <p><code>TERMINATING</code></p>
+    Terminating,
+    /// <p><code>TERMINATED</code></p>
+    Terminated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceFleetState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceFleetState::Provisioning => "PROVISIONING",
+            InstanceFleetState::Bootstrapping => "BOOTSTRAPPING",
+            InstanceFleetState::Running => "RUNNING",
+            InstanceFleetState::Resizing => "RESIZING",
+            InstanceFleetState::Suspended => "SUSPENDED",
+            InstanceFleetState::Terminating => "TERMINATING",
+            InstanceFleetState::Terminated => "TERMINATED",
+            InstanceFleetState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceFleetState {
+    fn from(value: &'a str) -> InstanceFleetState {
+        match value {
+            "PROVISIONING" => InstanceFleetState::Provisioning,
+            "BOOTSTRAPPING" => InstanceFleetState::Bootstrapping,
+            "RUNNING" => InstanceFleetState::Running,
+            "RESIZING" => InstanceFleetState::Resizing,
+            "SUSPENDED" => InstanceFleetState::Suspended,
+            "TERMINATING" => InstanceFleetState::Terminating,
+            "TERMINATED" => InstanceFleetState::Terminated,
+            _ => InstanceFleetState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceFleetState {
+    fn from(value: String) -> InstanceFleetState {
+        InstanceFleetState::from(value.as_str())
+    }
+}
+
+impl From<InstanceFleetState> for String {
+    fn from(value: InstanceFleetState) -> String {
+        match value {
+            InstanceFleetState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceFleetState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceFleetState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceFleetState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceFleetState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceFleetState::from(value))
+    }
+}
+
 /// <p><p>Provides status change reason details for the instance fleet.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -984,13 +2245,95 @@ pub struct InstanceFleetStateChangeReason {
     /// <p>A code corresponding to the reason the state change occurred.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<InstanceFleetStateChangeReasonCode>,
     /// <p>An explanatory message.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+/// <p>A code corresponding to the reason the state change occurred.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceFleetStateChangeReasonCode {
+    /// <p><code>INTERNAL_ERROR</code></p>
+    InternalError,
+    /// <p><code>VALIDATION_ERROR</code></p>
+    ValidationError,
+    /// <p><code>INSTANCE_FAILURE</code></p>
+    InstanceFailure,
+    /// <p><code>CLUSTER_TERMINATED</code></p>
+    ClusterTerminated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceFleetStateChangeReasonCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceFleetStateChangeReasonCode::InternalError => "INTERNAL_ERROR",
+            InstanceFleetStateChangeReasonCode::ValidationError => "VALIDATION_ERROR",
+            InstanceFleetStateChangeReasonCode::InstanceFailure => "INSTANCE_FAILURE",
+            InstanceFleetStateChangeReasonCode::ClusterTerminated => "CLUSTER_TERMINATED",
+            InstanceFleetStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceFleetStateChangeReasonCode {
+    fn from(value: &'a str) -> InstanceFleetStateChangeReasonCode {
+        match value {
+            "INTERNAL_ERROR" => InstanceFleetStateChangeReasonCode::InternalError,
+            "VALIDATION_ERROR" => InstanceFleetStateChangeReasonCode::ValidationError,
+            "INSTANCE_FAILURE" => InstanceFleetStateChangeReasonCode::InstanceFailure,
+            "CLUSTER_TERMINATED" => InstanceFleetStateChangeReasonCode::ClusterTerminated,
+            _ => InstanceFleetStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceFleetStateChangeReasonCode {
+    fn from(value: String) -> InstanceFleetStateChangeReasonCode {
+        InstanceFleetStateChangeReasonCode::from(value.as_str())
+    }
+}
+
+impl From<InstanceFleetStateChangeReasonCode> for String {
+    fn from(value: InstanceFleetStateChangeReasonCode) -> String {
+        match value {
+            InstanceFleetStateChangeReasonCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceFleetStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceFleetStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceFleetStateChangeReasonCode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<InstanceFleetStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceFleetStateChangeReasonCode::from(value))
+    }
+}
+
 /// <p><p>The status of the instance fleet.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -998,7 +2341,7 @@ pub struct InstanceFleetStatus {
     /// <p><p>A code representing the instance fleet status.</p> <ul> <li> <p> <code>PROVISIONING</code>—The instance fleet is provisioning EC2 resources and is not yet ready to run jobs.</p> </li> <li> <p> <code>BOOTSTRAPPING</code>—EC2 instances and other resources have been provisioned and the bootstrap actions specified for the instances are underway.</p> </li> <li> <p> <code>RUNNING</code>—EC2 instances and other resources are running. They are either executing jobs or waiting to execute jobs.</p> </li> <li> <p> <code>RESIZING</code>—A resize operation is underway. EC2 instances are either being added or removed.</p> </li> <li> <p> <code>SUSPENDED</code>—A resize operation could not complete. Existing EC2 instances are running, but instances can&#39;t be added or removed.</p> </li> <li> <p> <code>TERMINATING</code>—The instance fleet is terminating EC2 instances.</p> </li> <li> <p> <code>TERMINATED</code>—The instance fleet is no longer active, and all EC2 instances have been terminated.</p> </li> </ul></p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<InstanceFleetState>,
     /// <p>Provides status change reason details for the instance fleet.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1015,39 +2358,118 @@ pub struct InstanceFleetStatus {
 pub struct InstanceFleetTimeline {
     /// <p>The time and date the instance fleet was created.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The time and date the instance fleet terminated.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<EpochTimestamp>,
     /// <p>The time and date the instance fleet was ready to run jobs.</p>
     #[serde(rename = "ReadyDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ready_date_time: Option<f64>,
+    pub ready_date_time: Option<EpochTimestamp>,
 }
 
-/// <p>This entity represents an instance group, which is a group of instances that have common purpose. For example, CORE instance group is used for HDFS.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct InstanceGroup {
-    /// <p>An automatic scaling policy for a core instance group or task instance group in an Amazon EMR cluster. The automatic scaling policy defines how an instance group dynamically adds and terminates EC2 instances in response to the value of a CloudWatch metric. See PutAutoScalingPolicy.</p>
-    #[serde(rename = "AutoScalingPolicy")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_scaling_policy: Option<AutoScalingPolicyDescription>,
-    /// <p>The maximum Spot price your are willing to pay for EC2 instances.</p> <p>An optional, nullable field that applies if the <code>MarketType</code> for the instance group is specified as <code>SPOT</code>. Specify the maximum spot price in USD. If the value is NULL and <code>SPOT</code> is specified, the maximum Spot price is set equal to the On-Demand price.</p>
-    #[serde(rename = "BidPrice")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bid_price: Option<String>,
-    /// <p><note> <p>Amazon EMR releases 4.x or later.</p> </note> <p>The list of configurations supplied for an EMR cluster instance group. You can specify a separate configuration for each instance group (master, core, and task).</p></p>
-    #[serde(rename = "Configurations")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub configurations: Option<Vec<Configuration>>,
-    /// <p>The version number of the requested configuration specification for this instance group.</p>
-    #[serde(rename = "ConfigurationsVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub configurations_version: Option<i64>,
-    /// <p>The EBS block devices that are mapped to this instance group.</p>
+/// <p>The node type that the instance fleet hosts. Valid values are MASTER, CORE, or TASK. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceFleetType {
+    /// <p><code>MASTER</code></p>
+    Master,
+    /// <p><code>CORE</code></p>
+    Core,
+    /// <p><code>TASK</code></p>
+    Task,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceFleetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceFleetType::Master => "MASTER",
+            InstanceFleetType::Core => "CORE",
+            InstanceFleetType::Task => "TASK",
+            InstanceFleetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceFleetType {
+    fn from(value: &'a str) -> InstanceFleetType {
+        match value {
+            "MASTER" => InstanceFleetType::Master,
+            "CORE" => InstanceFleetType::Core,
+            "TASK" => InstanceFleetType::Task,
+            _ => InstanceFleetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceFleetType {
+    fn from(value: String) -> InstanceFleetType {
+        InstanceFleetType::from(value.as_str())
+    }
+}
+
+impl From<InstanceFleetType> for String {
+    fn from(value: InstanceFleetType) -> String {
+        match value {
+            InstanceFleetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceFleetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceFleetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceFleetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceFleetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceFleetType::from(value))
+    }
+}
+
+/// <p>This entity represents an instance group, which is a group of instances that have common purpose. For example, CORE instance group is used for HDFS.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct InstanceGroup {
+    /// <p>An automatic scaling policy for a core instance group or task instance group in an Amazon EMR cluster. The automatic scaling policy defines how an instance group dynamically adds and terminates EC2 instances in response to the value of a CloudWatch metric. See PutAutoScalingPolicy.</p>
+    #[serde(rename = "AutoScalingPolicy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_scaling_policy: Option<AutoScalingPolicyDescription>,
+    /// <p>The maximum Spot price your are willing to pay for EC2 instances.</p> <p>An optional, nullable field that applies if the <code>MarketType</code> for the instance group is specified as <code>SPOT</code>. Specify the maximum spot price in USD. If the value is NULL and <code>SPOT</code> is specified, the maximum Spot price is set equal to the On-Demand price.</p>
+    #[serde(rename = "BidPrice")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_price: Option<String>,
+    /// <p><note> <p>Amazon EMR releases 4.x or later.</p> </note> <p>The list of configurations supplied for an EMR cluster instance group. You can specify a separate configuration for each instance group (master, core, and task).</p></p>
+    #[serde(rename = "Configurations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configurations: Option<Vec<Configuration>>,
+    /// <p>The version number of the requested configuration specification for this instance group.</p>
+    #[serde(rename = "ConfigurationsVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configurations_version: Option<i64>,
+    /// <p>The EBS block devices that are mapped to this instance group.</p>
     #[serde(rename = "EbsBlockDevices")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ebs_block_devices: Option<Vec<EbsBlockDevice>>,
@@ -1062,7 +2484,7 @@ pub struct InstanceGroup {
     /// <p>The type of the instance group. Valid values are MASTER, CORE or TASK.</p>
     #[serde(rename = "InstanceGroupType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_group_type: Option<String>,
+    pub instance_group_type: Option<InstanceGroupType>,
     /// <p>The EC2 instance type for all instances in the instance group.</p>
     #[serde(rename = "InstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1078,7 +2500,7 @@ pub struct InstanceGroup {
     /// <p>The marketplace to provision instances for this group. Valid values are ON_DEMAND or SPOT.</p>
     #[serde(rename = "Market")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub market: Option<String>,
+    pub market: Option<MarketType>,
     /// <p>The name of the instance group.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1102,7 +2524,7 @@ pub struct InstanceGroup {
 }
 
 /// <p>Configuration defining a new instance group.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InstanceGroupConfig {
     /// <p>An automatic scaling policy for a core instance group or task instance group in an Amazon EMR cluster. The automatic scaling policy defines how an instance group dynamically adds and terminates EC2 instances in response to the value of a CloudWatch metric. See <a>PutAutoScalingPolicy</a>.</p>
     #[serde(rename = "AutoScalingPolicy")]
@@ -1125,14 +2547,14 @@ pub struct InstanceGroupConfig {
     pub instance_count: i64,
     /// <p>The role of the instance group in the cluster.</p>
     #[serde(rename = "InstanceRole")]
-    pub instance_role: String,
+    pub instance_role: InstanceRoleType,
     /// <p>The EC2 instance type for all instances in the instance group.</p>
     #[serde(rename = "InstanceType")]
     pub instance_type: String,
     /// <p>Market type of the EC2 instances used to create a cluster node.</p>
     #[serde(rename = "Market")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub market: Option<String>,
+    pub market: Option<MarketType>,
     /// <p>Friendly name given to the instance group.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1140,7 +2562,7 @@ pub struct InstanceGroupConfig {
 }
 
 /// <p>Detailed information about an instance group.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct InstanceGroupDetail {
     /// <p>The maximum Spot price your are willing to pay for EC2 instances.</p> <p>An optional, nullable field that applies if the <code>MarketType</code> for the instance group is specified as <code>SPOT</code>. Specified in USD. If the value is NULL and <code>SPOT</code> is specified, the maximum Spot price is set equal to the On-Demand price.</p>
@@ -1149,11 +2571,13 @@ pub struct InstanceGroupDetail {
     pub bid_price: Option<String>,
     /// <p>The date/time the instance group was created.</p>
     #[serde(rename = "CreationDateTime")]
-    pub creation_date_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date_time: EpochTimestamp,
     /// <p>The date/time the instance group was terminated.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<EpochTimestamp>,
     /// <p>Unique identifier for the instance group.</p>
     #[serde(rename = "InstanceGroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1163,7 +2587,7 @@ pub struct InstanceGroupDetail {
     pub instance_request_count: i64,
     /// <p>Instance group role in the cluster</p>
     #[serde(rename = "InstanceRole")]
-    pub instance_role: String,
+    pub instance_role: InstanceRoleType,
     /// <p>Actual count of running instances.</p>
     #[serde(rename = "InstanceRunningCount")]
     pub instance_running_count: i64,
@@ -1176,22 +2600,24 @@ pub struct InstanceGroupDetail {
     pub last_state_change_reason: Option<String>,
     /// <p>Market type of the EC2 instances used to create a cluster node.</p>
     #[serde(rename = "Market")]
-    pub market: String,
+    pub market: MarketType,
     /// <p>Friendly name for the instance group.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// <p>The date/time the instance group was available to the cluster.</p>
     #[serde(rename = "ReadyDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ready_date_time: Option<f64>,
+    pub ready_date_time: Option<EpochTimestamp>,
     /// <p>The date/time the instance group was started.</p>
     #[serde(rename = "StartDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date_time: Option<f64>,
+    pub start_date_time: Option<EpochTimestamp>,
     /// <p>State of instance group. The following values are deprecated: STARTING, TERMINATED, and FAILED.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: InstanceGroupState,
 }
 
 /// <p>Modify the size or configurations of an instance group.</p>
@@ -1218,6 +2644,114 @@ pub struct InstanceGroupModifyConfig {
     pub shrink_policy: Option<ShrinkPolicy>,
 }
 
+/// <p>State of instance group. The following values are deprecated: STARTING, TERMINATED, and FAILED.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceGroupState {
+    /// <p><code>PROVISIONING</code></p>
+    Provisioning,
+    /// <p><code>BOOTSTRAPPING</code></p>
+    Bootstrapping,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>RECONFIGURING</code></p>
+    Reconfiguring,
+    /// <p><code>RESIZING</code></p>
+    Resizing,
+    /// <p><code>SUSPENDED</code></p>
+    Suspended,
+    /// <p><code>TERMINATING</code></p>
+    Terminating,
+    /// <p><code>TERMINATED</code></p>
+    Terminated,
+    /// <p><code>ARRESTED</code></p>
+    Arrested,
+    /// <p><code>SHUTTING_DOWN</code></p>
+    ShuttingDown,
+    /// <p><code>ENDED</code></p>
+    Ended,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceGroupState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceGroupState::Provisioning => "PROVISIONING",
+            InstanceGroupState::Bootstrapping => "BOOTSTRAPPING",
+            InstanceGroupState::Running => "RUNNING",
+            InstanceGroupState::Reconfiguring => "RECONFIGURING",
+            InstanceGroupState::Resizing => "RESIZING",
+            InstanceGroupState::Suspended => "SUSPENDED",
+            InstanceGroupState::Terminating => "TERMINATING",
+            InstanceGroupState::Terminated => "TERMINATED",
+            InstanceGroupState::Arrested => "ARRESTED",
+            InstanceGroupState::ShuttingDown => "SHUTTING_DOWN",
+            InstanceGroupState::Ended => "ENDED",
+            InstanceGroupState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceGroupState {
+    fn from(value: &'a str) -> InstanceGroupState {
+        match value {
+            "PROVISIONING" => InstanceGroupState::Provisioning,
+            "BOOTSTRAPPING" => InstanceGroupState::Bootstrapping,
+            "RUNNING" => InstanceGroupState::Running,
+            "RECONFIGURING" => InstanceGroupState::Reconfiguring,
+            "RESIZING" => InstanceGroupState::Resizing,
+            "SUSPENDED" => InstanceGroupState::Suspended,
+            "TERMINATING" => InstanceGroupState::Terminating,
+            "TERMINATED" => InstanceGroupState::Terminated,
+            "ARRESTED" => InstanceGroupState::Arrested,
+            "SHUTTING_DOWN" => InstanceGroupState::ShuttingDown,
+            "ENDED" => InstanceGroupState::Ended,
+            _ => InstanceGroupState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceGroupState {
+    fn from(value: String) -> InstanceGroupState {
+        InstanceGroupState::from(value.as_str())
+    }
+}
+
+impl From<InstanceGroupState> for String {
+    fn from(value: InstanceGroupState) -> String {
+        match value {
+            InstanceGroupState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceGroupState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceGroupState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceGroupState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceGroupState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceGroupState::from(value))
+    }
+}
+
 /// <p>The status change reason details for the instance group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1225,13 +2759,95 @@ pub struct InstanceGroupStateChangeReason {
     /// <p>The programmable code for the state change reason.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<InstanceGroupStateChangeReasonCode>,
     /// <p>The status change reason description.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+/// <p>The programmable code for the state change reason.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceGroupStateChangeReasonCode {
+    /// <p><code>INTERNAL_ERROR</code></p>
+    InternalError,
+    /// <p><code>VALIDATION_ERROR</code></p>
+    ValidationError,
+    /// <p><code>INSTANCE_FAILURE</code></p>
+    InstanceFailure,
+    /// <p><code>CLUSTER_TERMINATED</code></p>
+    ClusterTerminated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceGroupStateChangeReasonCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceGroupStateChangeReasonCode::InternalError => "INTERNAL_ERROR",
+            InstanceGroupStateChangeReasonCode::ValidationError => "VALIDATION_ERROR",
+            InstanceGroupStateChangeReasonCode::InstanceFailure => "INSTANCE_FAILURE",
+            InstanceGroupStateChangeReasonCode::ClusterTerminated => "CLUSTER_TERMINATED",
+            InstanceGroupStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceGroupStateChangeReasonCode {
+    fn from(value: &'a str) -> InstanceGroupStateChangeReasonCode {
+        match value {
+            "INTERNAL_ERROR" => InstanceGroupStateChangeReasonCode::InternalError,
+            "VALIDATION_ERROR" => InstanceGroupStateChangeReasonCode::ValidationError,
+            "INSTANCE_FAILURE" => InstanceGroupStateChangeReasonCode::InstanceFailure,
+            "CLUSTER_TERMINATED" => InstanceGroupStateChangeReasonCode::ClusterTerminated,
+            _ => InstanceGroupStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceGroupStateChangeReasonCode {
+    fn from(value: String) -> InstanceGroupStateChangeReasonCode {
+        InstanceGroupStateChangeReasonCode::from(value.as_str())
+    }
+}
+
+impl From<InstanceGroupStateChangeReasonCode> for String {
+    fn from(value: InstanceGroupStateChangeReasonCode) -> String {
+        match value {
+            InstanceGroupStateChangeReasonCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceGroupStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceGroupStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceGroupStateChangeReasonCode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<InstanceGroupStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceGroupStateChangeReasonCode::from(value))
+    }
+}
+
 /// <p>The details of the instance group status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1239,7 +2855,7 @@ pub struct InstanceGroupStatus {
     /// <p>The current state of the instance group.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<InstanceGroupState>,
     /// <p>The status change reason details for the instance group.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1256,16 +2872,95 @@ pub struct InstanceGroupStatus {
 pub struct InstanceGroupTimeline {
     /// <p>The creation date and time of the instance group.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The date and time when the instance group terminated.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<EpochTimestamp>,
     /// <p>The date and time when the instance group became ready to perform tasks.</p>
     #[serde(rename = "ReadyDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ready_date_time: Option<f64>,
+    pub ready_date_time: Option<EpochTimestamp>,
+}
+
+/// <p>The type of the instance group. Valid values are MASTER, CORE or TASK.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceGroupType {
+    /// <p><code>MASTER</code></p>
+    Master,
+    /// <p><code>CORE</code></p>
+    Core,
+    /// <p><code>TASK</code></p>
+    Task,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceGroupType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceGroupType::Master => "MASTER",
+            InstanceGroupType::Core => "CORE",
+            InstanceGroupType::Task => "TASK",
+            InstanceGroupType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceGroupType {
+    fn from(value: &'a str) -> InstanceGroupType {
+        match value {
+            "MASTER" => InstanceGroupType::Master,
+            "CORE" => InstanceGroupType::Core,
+            "TASK" => InstanceGroupType::Task,
+            _ => InstanceGroupType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceGroupType {
+    fn from(value: String) -> InstanceGroupType {
+        InstanceGroupType::from(value.as_str())
+    }
+}
+
+impl From<InstanceGroupType> for String {
+    fn from(value: InstanceGroupType) -> String {
+        match value {
+            InstanceGroupType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceGroupType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceGroupType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceGroupType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceGroupType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceGroupType::from(value))
+    }
 }
 
 /// <p>Custom policy for requesting termination protection or termination of specific instances when shrinking an instance group.</p>
@@ -1285,6 +2980,166 @@ pub struct InstanceResizePolicy {
     pub instances_to_terminate: Option<Vec<String>>,
 }
 
+/// <p>The role of the instance group in the cluster.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceRoleType {
+    /// <p><code>MASTER</code></p>
+    Master,
+    /// <p><code>CORE</code></p>
+    Core,
+    /// <p><code>TASK</code></p>
+    Task,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceRoleType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceRoleType::Master => "MASTER",
+            InstanceRoleType::Core => "CORE",
+            InstanceRoleType::Task => "TASK",
+            InstanceRoleType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceRoleType {
+    fn from(value: &'a str) -> InstanceRoleType {
+        match value {
+            "MASTER" => InstanceRoleType::Master,
+            "CORE" => InstanceRoleType::Core,
+            "TASK" => InstanceRoleType::Task,
+            _ => InstanceRoleType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceRoleType {
+    fn from(value: String) -> InstanceRoleType {
+        InstanceRoleType::from(value.as_str())
+    }
+}
+
+impl From<InstanceRoleType> for String {
+    fn from(value: InstanceRoleType) -> String {
+        match value {
+            InstanceRoleType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceRoleType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceRoleType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceRoleType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceRoleType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceRoleType::from(value))
+    }
+}
+
+/// <p>The current state of the instance.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceState {
+    /// <p><code>AWAITING_FULFILLMENT</code></p>
+    AwaitingFulfillment,
+    /// <p><code>PROVISIONING</code></p>
+    Provisioning,
+    /// <p><code>BOOTSTRAPPING</code></p>
+    Bootstrapping,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>TERMINATED</code></p>
+    Terminated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceState::AwaitingFulfillment => "AWAITING_FULFILLMENT",
+            InstanceState::Provisioning => "PROVISIONING",
+            InstanceState::Bootstrapping => "BOOTSTRAPPING",
+            InstanceState::Running => "RUNNING",
+            InstanceState::Terminated => "TERMINATED",
+            InstanceState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceState {
+    fn from(value: &'a str) -> InstanceState {
+        match value {
+            "AWAITING_FULFILLMENT" => InstanceState::AwaitingFulfillment,
+            "PROVISIONING" => InstanceState::Provisioning,
+            "BOOTSTRAPPING" => InstanceState::Bootstrapping,
+            "RUNNING" => InstanceState::Running,
+            "TERMINATED" => InstanceState::Terminated,
+            _ => InstanceState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceState {
+    fn from(value: String) -> InstanceState {
+        InstanceState::from(value.as_str())
+    }
+}
+
+impl From<InstanceState> for String {
+    fn from(value: InstanceState) -> String {
+        match value {
+            InstanceState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceState::from(value))
+    }
+}
+
 /// <p>The details of the status change reason for the instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1292,13 +3147,99 @@ pub struct InstanceStateChangeReason {
     /// <p>The programmable code for the state change reason.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<InstanceStateChangeReasonCode>,
     /// <p>The status change reason description.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+/// <p>The programmable code for the state change reason.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceStateChangeReasonCode {
+    /// <p><code>INTERNAL_ERROR</code></p>
+    InternalError,
+    /// <p><code>VALIDATION_ERROR</code></p>
+    ValidationError,
+    /// <p><code>INSTANCE_FAILURE</code></p>
+    InstanceFailure,
+    /// <p><code>BOOTSTRAP_FAILURE</code></p>
+    BootstrapFailure,
+    /// <p><code>CLUSTER_TERMINATED</code></p>
+    ClusterTerminated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceStateChangeReasonCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceStateChangeReasonCode::InternalError => "INTERNAL_ERROR",
+            InstanceStateChangeReasonCode::ValidationError => "VALIDATION_ERROR",
+            InstanceStateChangeReasonCode::InstanceFailure => "INSTANCE_FAILURE",
+            InstanceStateChangeReasonCode::BootstrapFailure => "BOOTSTRAP_FAILURE",
+            InstanceStateChangeReasonCode::ClusterTerminated => "CLUSTER_TERMINATED",
+            InstanceStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceStateChangeReasonCode {
+    fn from(value: &'a str) -> InstanceStateChangeReasonCode {
+        match value {
+            "INTERNAL_ERROR" => InstanceStateChangeReasonCode::InternalError,
+            "VALIDATION_ERROR" => InstanceStateChangeReasonCode::ValidationError,
+            "INSTANCE_FAILURE" => InstanceStateChangeReasonCode::InstanceFailure,
+            "BOOTSTRAP_FAILURE" => InstanceStateChangeReasonCode::BootstrapFailure,
+            "CLUSTER_TERMINATED" => InstanceStateChangeReasonCode::ClusterTerminated,
+            _ => InstanceStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceStateChangeReasonCode {
+    fn from(value: String) -> InstanceStateChangeReasonCode {
+        InstanceStateChangeReasonCode::from(value.as_str())
+    }
+}
+
+impl From<InstanceStateChangeReasonCode> for String {
+    fn from(value: InstanceStateChangeReasonCode) -> String {
+        match value {
+            InstanceStateChangeReasonCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceStateChangeReasonCode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<InstanceStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceStateChangeReasonCode::from(value))
+    }
+}
+
 /// <p>The instance status details.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1306,7 +3247,7 @@ pub struct InstanceStatus {
     /// <p>The current state of the instance.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<InstanceState>,
     /// <p>The details of the status change reason for the instance.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1323,16 +3264,19 @@ pub struct InstanceStatus {
 pub struct InstanceTimeline {
     /// <p>The creation date and time of the instance.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The date and time when the instance was terminated.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<EpochTimestamp>,
     /// <p>The date and time when the instance was ready to perform tasks.</p>
     #[serde(rename = "ReadyDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ready_date_time: Option<f64>,
+    pub ready_date_time: Option<EpochTimestamp>,
 }
 
 /// <p><p>An instance type configuration for each instance type in an instance fleet, which determines the EC2 instances Amazon EMR attempts to provision to fulfill On-Demand and Spot target capacities. There can be a maximum of 5 instance type configurations in a fleet.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
@@ -1398,7 +3342,7 @@ pub struct InstanceTypeSpecification {
 }
 
 /// <p>A description of a cluster (job flow).</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct JobFlowDetail {
     /// <p>Applies only to Amazon EMR AMI versions 3.x and 2.x. For Amazon EMR releases 4.0 and later, <code>ReleaseLabel</code> is used. To specify a custom AMI, use <code>CustomAmiID</code>.</p>
@@ -1436,7 +3380,7 @@ pub struct JobFlowDetail {
     /// <p>The way that individual Amazon EC2 instances terminate when an automatic scale-in activity occurs or an instance group is resized. <code>TERMINATE_AT_INSTANCE_HOUR</code> indicates that Amazon EMR terminates nodes at the instance-hour boundary, regardless of when the request to terminate the instance was submitted. This option is only available with Amazon EMR 5.1.0 and later and is the default for clusters created using that version. <code>TERMINATE_AT_TASK_COMPLETION</code> indicates that Amazon EMR blacklists and drains tasks from nodes before terminating the Amazon EC2 instances, regardless of the instance-hour boundary. With either behavior, Amazon EMR removes the least active nodes first and blocks instance termination if it could lead to HDFS corruption. <code>TERMINATE_AT_TASK_COMPLETION</code> available only in Amazon EMR version 4.1.0 and later, and is the default for versions of Amazon EMR earlier than 5.1.0.</p>
     #[serde(rename = "ScaleDownBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scale_down_behavior: Option<String>,
+    pub scale_down_behavior: Option<ScaleDownBehavior>,
     /// <p>The IAM role that will be assumed by the Amazon EMR service to access AWS resources on your behalf.</p>
     #[serde(rename = "ServiceRole")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1455,32 +3399,132 @@ pub struct JobFlowDetail {
     pub visible_to_all_users: Option<bool>,
 }
 
-/// <p>Describes the status of the cluster (job flow).</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct JobFlowExecutionStatusDetail {
-    /// <p>The creation date and time of the job flow.</p>
-    #[serde(rename = "CreationDateTime")]
-    pub creation_date_time: f64,
-    /// <p>The completion date and time of the job flow.</p>
-    #[serde(rename = "EndDateTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
-    /// <p>Description of the job flow last changed state.</p>
-    #[serde(rename = "LastStateChangeReason")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_state_change_reason: Option<String>,
-    /// <p>The date and time when the job flow was ready to start running bootstrap actions.</p>
-    #[serde(rename = "ReadyDateTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ready_date_time: Option<f64>,
+/// <p>The type of instance.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobFlowExecutionState {
+    /// <p><code>STARTING</code></p>
+    Starting,
+    /// <p><code>BOOTSTRAPPING</code></p>
+    Bootstrapping,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>WAITING</code></p>
+    Waiting,
+    /// <p><code>SHUTTING_DOWN</code></p>
+    ShuttingDown,
+    /// <p><code>TERMINATED</code></p>
+    Terminated,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobFlowExecutionState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobFlowExecutionState::Starting => "STARTING",
+            JobFlowExecutionState::Bootstrapping => "BOOTSTRAPPING",
+            JobFlowExecutionState::Running => "RUNNING",
+            JobFlowExecutionState::Waiting => "WAITING",
+            JobFlowExecutionState::ShuttingDown => "SHUTTING_DOWN",
+            JobFlowExecutionState::Terminated => "TERMINATED",
+            JobFlowExecutionState::Completed => "COMPLETED",
+            JobFlowExecutionState::Failed => "FAILED",
+            JobFlowExecutionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobFlowExecutionState {
+    fn from(value: &'a str) -> JobFlowExecutionState {
+        match value {
+            "STARTING" => JobFlowExecutionState::Starting,
+            "BOOTSTRAPPING" => JobFlowExecutionState::Bootstrapping,
+            "RUNNING" => JobFlowExecutionState::Running,
+            "WAITING" => JobFlowExecutionState::Waiting,
+            "SHUTTING_DOWN" => JobFlowExecutionState::ShuttingDown,
+            "TERMINATED" => JobFlowExecutionState::Terminated,
+            "COMPLETED" => JobFlowExecutionState::Completed,
+            "FAILED" => JobFlowExecutionState::Failed,
+            _ => JobFlowExecutionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobFlowExecutionState {
+    fn from(value: String) -> JobFlowExecutionState {
+        JobFlowExecutionState::from(value.as_str())
+    }
+}
+
+impl From<JobFlowExecutionState> for String {
+    fn from(value: JobFlowExecutionState) -> String {
+        match value {
+            JobFlowExecutionState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobFlowExecutionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for JobFlowExecutionState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobFlowExecutionState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobFlowExecutionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobFlowExecutionState::from(value))
+    }
+}
+
+/// <p>Describes the status of the cluster (job flow).</p>
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct JobFlowExecutionStatusDetail {
+    /// <p>The creation date and time of the job flow.</p>
+    #[serde(rename = "CreationDateTime")]
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date_time: EpochTimestamp,
+    /// <p>The completion date and time of the job flow.</p>
+    #[serde(rename = "EndDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date_time: Option<EpochTimestamp>,
+    /// <p>Description of the job flow last changed state.</p>
+    #[serde(rename = "LastStateChangeReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_state_change_reason: Option<String>,
+    /// <p>The date and time when the job flow was ready to start running bootstrap actions.</p>
+    #[serde(rename = "ReadyDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ready_date_time: Option<EpochTimestamp>,
     /// <p>The start date and time of the job flow.</p>
     #[serde(rename = "StartDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date_time: Option<f64>,
+    pub start_date_time: Option<EpochTimestamp>,
     /// <p>The state of the job flow.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: JobFlowExecutionState,
 }
 
 /// <p>A description of the Amazon EC2 instance on which the cluster (job flow) runs. A valid JobFlowInstancesConfig must contain either InstanceGroups or InstanceFleets, which is the recommended configuration. They cannot be used together. You may also have MasterInstanceType, SlaveInstanceType, and InstanceCount (all three must be present), but we don't recommend this configuration.</p>
@@ -1659,6 +3703,46 @@ pub struct ListBootstrapActionsInput {
     pub marker: Option<String>,
 }
 
+impl ListBootstrapActionsInput {
+    /// Returns a builder for `ListBootstrapActionsInput`, checking its required members when built.
+    pub fn builder() -> ListBootstrapActionsInputBuilder {
+        ListBootstrapActionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListBootstrapActionsInput`](struct.ListBootstrapActionsInput.html).
+#[derive(Default, Debug)]
+pub struct ListBootstrapActionsInputBuilder {
+    cluster_id: Option<String>,
+    marker: Option<String>,
+}
+
+impl ListBootstrapActionsInputBuilder {
+    /// Sets the [`cluster_id`](struct.ListBootstrapActionsInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListBootstrapActionsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBootstrapActionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListBootstrapActionsInput, ::rusoto_core::BuildError> {
+        Ok(ListBootstrapActionsInput {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            marker: self.marker,
+        })
+    }
+}
+
 /// <p>This output contains the bootstrap actions detail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1679,21 +3763,75 @@ pub struct ListClustersInput {
     /// <p>The cluster state filters to apply when listing clusters.</p>
     #[serde(rename = "ClusterStates")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cluster_states: Option<Vec<String>>,
+    pub cluster_states: Option<Vec<ClusterState>>,
     /// <p>The creation date and time beginning value filter for listing clusters.</p>
     #[serde(rename = "CreatedAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_after: Option<f64>,
+    pub created_after: Option<EpochTimestamp>,
     /// <p>The creation date and time end value filter for listing clusters.</p>
     #[serde(rename = "CreatedBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_before: Option<f64>,
+    pub created_before: Option<EpochTimestamp>,
     /// <p>The pagination token that indicates the next set of results to retrieve.</p>
     #[serde(rename = "Marker")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub marker: Option<String>,
 }
 
+impl ListClustersInput {
+    /// Returns a builder for `ListClustersInput`, checking its required members when built.
+    pub fn builder() -> ListClustersInputBuilder {
+        ListClustersInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListClustersInput`](struct.ListClustersInput.html).
+#[derive(Default, Debug)]
+pub struct ListClustersInputBuilder {
+    cluster_states: Option<Vec<ClusterState>>,
+    created_after: Option<EpochTimestamp>,
+    created_before: Option<EpochTimestamp>,
+    marker: Option<String>,
+}
+
+impl ListClustersInputBuilder {
+    /// Sets the [`cluster_states`](struct.ListClustersInput.html#structfield.cluster_states) member.
+    pub fn cluster_states<T: Into<Vec<ClusterState>>>(mut self, value: T) -> Self {
+        self.cluster_states = Some(value.into());
+        self
+    }
+
+    /// Sets the [`created_after`](struct.ListClustersInput.html#structfield.created_after) member.
+    pub fn created_after<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.created_after = Some(value.into());
+        self
+    }
+
+    /// Sets the [`created_before`](struct.ListClustersInput.html#structfield.created_before) member.
+    pub fn created_before<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.created_before = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListClustersInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListClustersInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListClustersInput, ::rusoto_core::BuildError> {
+        Ok(ListClustersInput {
+            cluster_states: self.cluster_states,
+            created_after: self.created_after,
+            created_before: self.created_before,
+            marker: self.marker,
+        })
+    }
+}
+
 /// <p>This contains a ClusterSummaryList with the cluster details; for example, the cluster IDs, names, and status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1719,6 +3857,46 @@ pub struct ListInstanceFleetsInput {
     pub marker: Option<String>,
 }
 
+impl ListInstanceFleetsInput {
+    /// Returns a builder for `ListInstanceFleetsInput`, checking its required members when built.
+    pub fn builder() -> ListInstanceFleetsInputBuilder {
+        ListInstanceFleetsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListInstanceFleetsInput`](struct.ListInstanceFleetsInput.html).
+#[derive(Default, Debug)]
+pub struct ListInstanceFleetsInputBuilder {
+    cluster_id: Option<String>,
+    marker: Option<String>,
+}
+
+impl ListInstanceFleetsInputBuilder {
+    /// Sets the [`cluster_id`](struct.ListInstanceFleetsInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListInstanceFleetsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListInstanceFleetsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListInstanceFleetsInput, ::rusoto_core::BuildError> {
+        Ok(ListInstanceFleetsInput {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListInstanceFleetsOutput {
@@ -1744,6 +3922,46 @@ pub struct ListInstanceGroupsInput {
     pub marker: Option<String>,
 }
 
+impl ListInstanceGroupsInput {
+    /// Returns a builder for `ListInstanceGroupsInput`, checking its required members when built.
+    pub fn builder() -> ListInstanceGroupsInputBuilder {
+        ListInstanceGroupsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListInstanceGroupsInput`](struct.ListInstanceGroupsInput.html).
+#[derive(Default, Debug)]
+pub struct ListInstanceGroupsInputBuilder {
+    cluster_id: Option<String>,
+    marker: Option<String>,
+}
+
+impl ListInstanceGroupsInputBuilder {
+    /// Sets the [`cluster_id`](struct.ListInstanceGroupsInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListInstanceGroupsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListInstanceGroupsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListInstanceGroupsInput, ::rusoto_core::BuildError> {
+        Ok(ListInstanceGroupsInput {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            marker: self.marker,
+        })
+    }
+}
+
 /// <p>This input determines which instance groups to retrieve.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1771,7 +3989,7 @@ pub struct ListInstancesInput {
     /// <p>The node type of the instance fleet. For example MASTER, CORE, or TASK.</p>
     #[serde(rename = "InstanceFleetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_fleet_type: Option<String>,
+    pub instance_fleet_type: Option<InstanceFleetType>,
     /// <p>The identifier of the instance group for which to list the instances.</p>
     #[serde(rename = "InstanceGroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1779,17 +3997,95 @@ pub struct ListInstancesInput {
     /// <p>The type of instance group for which to list the instances.</p>
     #[serde(rename = "InstanceGroupTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_group_types: Option<Vec<String>>,
+    pub instance_group_types: Option<Vec<InstanceGroupType>>,
     /// <p>A list of instance states that will filter the instances returned with this request.</p>
     #[serde(rename = "InstanceStates")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_states: Option<Vec<String>>,
+    pub instance_states: Option<Vec<InstanceState>>,
     /// <p>The pagination token that indicates the next set of results to retrieve.</p>
     #[serde(rename = "Marker")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub marker: Option<String>,
 }
 
+impl ListInstancesInput {
+    /// Returns a builder for `ListInstancesInput`, checking its required members when built.
+    pub fn builder() -> ListInstancesInputBuilder {
+        ListInstancesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListInstancesInput`](struct.ListInstancesInput.html).
+#[derive(Default, Debug)]
+pub struct ListInstancesInputBuilder {
+    cluster_id: Option<String>,
+    instance_fleet_id: Option<String>,
+    instance_fleet_type: Option<InstanceFleetType>,
+    instance_group_id: Option<String>,
+    instance_group_types: Option<Vec<InstanceGroupType>>,
+    instance_states: Option<Vec<InstanceState>>,
+    marker: Option<String>,
+}
+
+impl ListInstancesInputBuilder {
+    /// Sets the [`cluster_id`](struct.ListInstancesInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_fleet_id`](struct.ListInstancesInput.html#structfield.instance_fleet_id) member.
+    pub fn instance_fleet_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_fleet_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_fleet_type`](struct.ListInstancesInput.html#structfield.instance_fleet_type) member.
+    pub fn instance_fleet_type<T: Into<InstanceFleetType>>(mut self, value: T) -> Self {
+        self.instance_fleet_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_group_id`](struct.ListInstancesInput.html#structfield.instance_group_id) member.
+    pub fn instance_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_group_types`](struct.ListInstancesInput.html#structfield.instance_group_types) member.
+    pub fn instance_group_types<T: Into<Vec<InstanceGroupType>>>(mut self, value: T) -> Self {
+        self.instance_group_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_states`](struct.ListInstancesInput.html#structfield.instance_states) member.
+    pub fn instance_states<T: Into<Vec<InstanceState>>>(mut self, value: T) -> Self {
+        self.instance_states = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListInstancesInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListInstancesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListInstancesInput, ::rusoto_core::BuildError> {
+        Ok(ListInstancesInput {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            instance_fleet_id: self.instance_fleet_id,
+            instance_fleet_type: self.instance_fleet_type,
+            instance_group_id: self.instance_group_id,
+            instance_group_types: self.instance_group_types,
+            instance_states: self.instance_states,
+            marker: self.marker,
+        })
+    }
+}
+
 /// <p>This output contains the list of instances.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1812,6 +4108,36 @@ pub struct ListSecurityConfigurationsInput {
     pub marker: Option<String>,
 }
 
+impl ListSecurityConfigurationsInput {
+    /// Returns a builder for `ListSecurityConfigurationsInput`, checking its required members when built.
+    pub fn builder() -> ListSecurityConfigurationsInputBuilder {
+        ListSecurityConfigurationsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListSecurityConfigurationsInput`](struct.ListSecurityConfigurationsInput.html).
+#[derive(Default, Debug)]
+pub struct ListSecurityConfigurationsInputBuilder {
+    marker: Option<String>,
+}
+
+impl ListSecurityConfigurationsInputBuilder {
+    /// Sets the [`marker`](struct.ListSecurityConfigurationsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSecurityConfigurationsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSecurityConfigurationsInput, ::rusoto_core::BuildError> {
+        Ok(ListSecurityConfigurationsInput {
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSecurityConfigurationsOutput {
@@ -1842,7 +4168,61 @@ pub struct ListStepsInput {
     /// <p>The filter to limit the step list based on certain states.</p>
     #[serde(rename = "StepStates")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub step_states: Option<Vec<String>>,
+    pub step_states: Option<Vec<StepState>>,
+}
+
+impl ListStepsInput {
+    /// Returns a builder for `ListStepsInput`, checking its required members when built.
+    pub fn builder() -> ListStepsInputBuilder {
+        ListStepsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStepsInput`](struct.ListStepsInput.html).
+#[derive(Default, Debug)]
+pub struct ListStepsInputBuilder {
+    cluster_id: Option<String>,
+    marker: Option<String>,
+    step_ids: Option<Vec<String>>,
+    step_states: Option<Vec<StepState>>,
+}
+
+impl ListStepsInputBuilder {
+    /// Sets the [`cluster_id`](struct.ListStepsInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListStepsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`step_ids`](struct.ListStepsInput.html#structfield.step_ids) member.
+    pub fn step_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.step_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`step_states`](struct.ListStepsInput.html#structfield.step_states) member.
+    pub fn step_states<T: Into<Vec<StepState>>>(mut self, value: T) -> Self {
+        self.step_states = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStepsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListStepsInput, ::rusoto_core::BuildError> {
+        Ok(ListStepsInput {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            marker: self.marker,
+            step_ids: self.step_ids,
+            step_states: self.step_states,
+        })
+    }
 }
 
 /// <p>This output contains the list of steps returned in reverse order. This means that the last step is the first element in the list.</p>
@@ -1859,6 +4239,78 @@ pub struct ListStepsOutput {
     pub steps: Option<Vec<StepSummary>>,
 }
 
+/// <p>The instance purchasing option. Valid values are <code>ON_DEMAND</code> or <code>SPOT</code>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MarketType {
+    /// <p><code>ON_DEMAND</code></p>
+    OnDemand,
+    /// <p><code>SPOT</code></p>
+    Spot,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MarketType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MarketType::OnDemand => "ON_DEMAND",
+            MarketType::Spot => "SPOT",
+            MarketType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MarketType {
+    fn from(value: &'a str) -> MarketType {
+        match value {
+            "ON_DEMAND" => MarketType::OnDemand,
+            "SPOT" => MarketType::Spot,
+            _ => MarketType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MarketType {
+    fn from(value: String) -> MarketType {
+        MarketType::from(value.as_str())
+    }
+}
+
+impl From<MarketType> for String {
+    fn from(value: MarketType) -> String {
+        match value {
+            MarketType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MarketType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MarketType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MarketType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MarketType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MarketType::from(value))
+    }
+}
+
 /// <p>A CloudWatch dimension, which is specified using a <code>Key</code> (known as a <code>Name</code> in CloudWatch), <code>Value</code> pair. By default, Amazon EMR uses one dimension whose <code>Key</code> is <code>JobFlowID</code> and <code>Value</code> is a variable representing the cluster ID, which is <code>${emr.clusterId}</code>. This enables the rule to bootstrap when the cluster ID becomes available.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetricDimension {
@@ -1882,6 +4334,48 @@ pub struct ModifyInstanceFleetInput {
     pub instance_fleet: InstanceFleetModifyConfig,
 }
 
+impl ModifyInstanceFleetInput {
+    /// Returns a builder for `ModifyInstanceFleetInput`, checking its required members when built.
+    pub fn builder() -> ModifyInstanceFleetInputBuilder {
+        ModifyInstanceFleetInputBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyInstanceFleetInput`](struct.ModifyInstanceFleetInput.html).
+#[derive(Default, Debug)]
+pub struct ModifyInstanceFleetInputBuilder {
+    cluster_id: Option<String>,
+    instance_fleet: Option<InstanceFleetModifyConfig>,
+}
+
+impl ModifyInstanceFleetInputBuilder {
+    /// Sets the [`cluster_id`](struct.ModifyInstanceFleetInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_fleet`](struct.ModifyInstanceFleetInput.html#structfield.instance_fleet) member.
+    pub fn instance_fleet<T: Into<InstanceFleetModifyConfig>>(mut self, value: T) -> Self {
+        self.instance_fleet = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyInstanceFleetInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyInstanceFleetInput, ::rusoto_core::BuildError> {
+        Ok(ModifyInstanceFleetInput {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            instance_fleet: self
+                .instance_fleet
+                .ok_or(::rusoto_core::BuildError::missing("instance_fleet"))?,
+        })
+    }
+}
+
 /// <p>Change the size of some instance groups.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ModifyInstanceGroupsInput {
@@ -1895,6 +4389,44 @@ pub struct ModifyInstanceGroupsInput {
     pub instance_groups: Option<Vec<InstanceGroupModifyConfig>>,
 }
 
+impl ModifyInstanceGroupsInput {
+    /// Returns a builder for `ModifyInstanceGroupsInput`, checking its required members when built.
+    pub fn builder() -> ModifyInstanceGroupsInputBuilder {
+        ModifyInstanceGroupsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyInstanceGroupsInput`](struct.ModifyInstanceGroupsInput.html).
+#[derive(Default, Debug)]
+pub struct ModifyInstanceGroupsInputBuilder {
+    cluster_id: Option<String>,
+    instance_groups: Option<Vec<InstanceGroupModifyConfig>>,
+}
+
+impl ModifyInstanceGroupsInputBuilder {
+    /// Sets the [`cluster_id`](struct.ModifyInstanceGroupsInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_groups`](struct.ModifyInstanceGroupsInput.html#structfield.instance_groups) member.
+    pub fn instance_groups<T: Into<Vec<InstanceGroupModifyConfig>>>(mut self, value: T) -> Self {
+        self.instance_groups = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyInstanceGroupsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyInstanceGroupsInput, ::rusoto_core::BuildError> {
+        Ok(ModifyInstanceGroupsInput {
+            cluster_id: self.cluster_id,
+            instance_groups: self.instance_groups,
+        })
+    }
+}
+
 /// <p>The Amazon EC2 Availability Zone configuration of the cluster (job flow).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PlacementType {
@@ -1933,6 +4465,58 @@ pub struct PutAutoScalingPolicyInput {
     pub instance_group_id: String,
 }
 
+impl PutAutoScalingPolicyInput {
+    /// Returns a builder for `PutAutoScalingPolicyInput`, checking its required members when built.
+    pub fn builder() -> PutAutoScalingPolicyInputBuilder {
+        PutAutoScalingPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutAutoScalingPolicyInput`](struct.PutAutoScalingPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct PutAutoScalingPolicyInputBuilder {
+    auto_scaling_policy: Option<AutoScalingPolicy>,
+    cluster_id: Option<String>,
+    instance_group_id: Option<String>,
+}
+
+impl PutAutoScalingPolicyInputBuilder {
+    /// Sets the [`auto_scaling_policy`](struct.PutAutoScalingPolicyInput.html#structfield.auto_scaling_policy) member.
+    pub fn auto_scaling_policy<T: Into<AutoScalingPolicy>>(mut self, value: T) -> Self {
+        self.auto_scaling_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_id`](struct.PutAutoScalingPolicyInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_group_id`](struct.PutAutoScalingPolicyInput.html#structfield.instance_group_id) member.
+    pub fn instance_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutAutoScalingPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutAutoScalingPolicyInput, ::rusoto_core::BuildError> {
+        Ok(PutAutoScalingPolicyInput {
+            auto_scaling_policy: self
+                .auto_scaling_policy
+                .ok_or(::rusoto_core::BuildError::missing("auto_scaling_policy"))?,
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            instance_group_id: self
+                .instance_group_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutAutoScalingPolicyOutput {
@@ -1957,6 +4541,42 @@ pub struct PutBlockPublicAccessConfigurationInput {
     pub block_public_access_configuration: BlockPublicAccessConfiguration,
 }
 
+impl PutBlockPublicAccessConfigurationInput {
+    /// Returns a builder for `PutBlockPublicAccessConfigurationInput`, checking its required members when built.
+    pub fn builder() -> PutBlockPublicAccessConfigurationInputBuilder {
+        PutBlockPublicAccessConfigurationInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutBlockPublicAccessConfigurationInput`](struct.PutBlockPublicAccessConfigurationInput.html).
+#[derive(Default, Debug)]
+pub struct PutBlockPublicAccessConfigurationInputBuilder {
+    block_public_access_configuration: Option<BlockPublicAccessConfiguration>,
+}
+
+impl PutBlockPublicAccessConfigurationInputBuilder {
+    /// Sets the [`block_public_access_configuration`](struct.PutBlockPublicAccessConfigurationInput.html#structfield.block_public_access_configuration) member.
+    pub fn block_public_access_configuration<T: Into<BlockPublicAccessConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.block_public_access_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutBlockPublicAccessConfigurationInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutBlockPublicAccessConfigurationInput, ::rusoto_core::BuildError>
+    {
+        Ok(PutBlockPublicAccessConfigurationInput {
+            block_public_access_configuration: self.block_public_access_configuration.ok_or(
+                ::rusoto_core::BuildError::missing("block_public_access_configuration"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutBlockPublicAccessConfigurationOutput {}
@@ -1971,6 +4591,48 @@ pub struct RemoveAutoScalingPolicyInput {
     pub instance_group_id: String,
 }
 
+impl RemoveAutoScalingPolicyInput {
+    /// Returns a builder for `RemoveAutoScalingPolicyInput`, checking its required members when built.
+    pub fn builder() -> RemoveAutoScalingPolicyInputBuilder {
+        RemoveAutoScalingPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveAutoScalingPolicyInput`](struct.RemoveAutoScalingPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct RemoveAutoScalingPolicyInputBuilder {
+    cluster_id: Option<String>,
+    instance_group_id: Option<String>,
+}
+
+impl RemoveAutoScalingPolicyInputBuilder {
+    /// Sets the [`cluster_id`](struct.RemoveAutoScalingPolicyInput.html#structfield.cluster_id) member.
+    pub fn cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_group_id`](struct.RemoveAutoScalingPolicyInput.html#structfield.instance_group_id) member.
+    pub fn instance_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveAutoScalingPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveAutoScalingPolicyInput, ::rusoto_core::BuildError> {
+        Ok(RemoveAutoScalingPolicyInput {
+            cluster_id: self
+                .cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cluster_id"))?,
+            instance_group_id: self
+                .instance_group_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RemoveAutoScalingPolicyOutput {}
@@ -1986,10 +4648,122 @@ pub struct RemoveTagsInput {
     pub tag_keys: Vec<String>,
 }
 
-/// <p>This output indicates the result of removing tags from a resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct RemoveTagsOutput {}
+impl RemoveTagsInput {
+    /// Returns a builder for `RemoveTagsInput`, checking its required members when built.
+    pub fn builder() -> RemoveTagsInputBuilder {
+        RemoveTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsInput`](struct.RemoveTagsInput.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsInputBuilder {
+    resource_id: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl RemoveTagsInputBuilder {
+    /// Sets the [`resource_id`](struct.RemoveTagsInput.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.RemoveTagsInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RemoveTagsInput, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsInput {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
+/// <p>This output indicates the result of removing tags from a resource.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct RemoveTagsOutput {}
+
+/// <p>Applies only when <code>CustomAmiID</code> is used. Specifies the type of updates that are applied from the Amazon Linux AMI package repositories when an instance boots using the AMI.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RepoUpgradeOnBoot {
+    /// <p><code>SECURITY</code></p>
+    Security,
+    /// <p><code>NONE</code></p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RepoUpgradeOnBoot {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RepoUpgradeOnBoot::Security => "SECURITY",
+            RepoUpgradeOnBoot::None => "NONE",
+            RepoUpgradeOnBoot::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RepoUpgradeOnBoot {
+    fn from(value: &'a str) -> RepoUpgradeOnBoot {
+        match value {
+            "SECURITY" => RepoUpgradeOnBoot::Security,
+            "NONE" => RepoUpgradeOnBoot::None,
+            _ => RepoUpgradeOnBoot::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RepoUpgradeOnBoot {
+    fn from(value: String) -> RepoUpgradeOnBoot {
+        RepoUpgradeOnBoot::from(value.as_str())
+    }
+}
+
+impl From<RepoUpgradeOnBoot> for String {
+    fn from(value: RepoUpgradeOnBoot) -> String {
+        match value {
+            RepoUpgradeOnBoot::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RepoUpgradeOnBoot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RepoUpgradeOnBoot {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RepoUpgradeOnBoot {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RepoUpgradeOnBoot, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RepoUpgradeOnBoot::from(value))
+    }
+}
 
 /// <p> Input to the <a>RunJobFlow</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2055,11 +4829,11 @@ pub struct RunJobFlowInput {
     /// <p>Applies only when <code>CustomAmiID</code> is used. Specifies which updates from the Amazon Linux AMI package repositories to apply automatically when the instance boots using the AMI. If omitted, the default is <code>SECURITY</code>, which indicates that only security updates are applied. If <code>NONE</code> is specified, no updates are applied, and all updates must be applied manually.</p>
     #[serde(rename = "RepoUpgradeOnBoot")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub repo_upgrade_on_boot: Option<String>,
+    pub repo_upgrade_on_boot: Option<RepoUpgradeOnBoot>,
     /// <p>Specifies the way that individual Amazon EC2 instances terminate when an automatic scale-in activity occurs or an instance group is resized. <code>TERMINATE_AT_INSTANCE_HOUR</code> indicates that Amazon EMR terminates nodes at the instance-hour boundary, regardless of when the request to terminate the instance was submitted. This option is only available with Amazon EMR 5.1.0 and later and is the default for clusters created using that version. <code>TERMINATE_AT_TASK_COMPLETION</code> indicates that Amazon EMR blacklists and drains tasks from nodes before terminating the Amazon EC2 instances, regardless of the instance-hour boundary. With either behavior, Amazon EMR removes the least active nodes first and blocks instance termination if it could lead to HDFS corruption. <code>TERMINATE_AT_TASK_COMPLETION</code> available only in Amazon EMR version 4.1.0 and later, and is the default for versions of Amazon EMR earlier than 5.1.0.</p>
     #[serde(rename = "ScaleDownBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scale_down_behavior: Option<String>,
+    pub scale_down_behavior: Option<ScaleDownBehavior>,
     /// <p>The name of a security configuration to apply to the cluster.</p>
     #[serde(rename = "SecurityConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2086,6 +4860,217 @@ pub struct RunJobFlowInput {
     pub visible_to_all_users: Option<bool>,
 }
 
+impl RunJobFlowInput {
+    /// Returns a builder for `RunJobFlowInput`, checking its required members when built.
+    pub fn builder() -> RunJobFlowInputBuilder {
+        RunJobFlowInputBuilder::default()
+    }
+}
+
+/// Builds a [`RunJobFlowInput`](struct.RunJobFlowInput.html).
+#[derive(Default, Debug)]
+pub struct RunJobFlowInputBuilder {
+    additional_info: Option<String>,
+    ami_version: Option<String>,
+    applications: Option<Vec<Application>>,
+    auto_scaling_role: Option<String>,
+    bootstrap_actions: Option<Vec<BootstrapActionConfig>>,
+    configurations: Option<Vec<Configuration>>,
+    custom_ami_id: Option<String>,
+    ebs_root_volume_size: Option<i64>,
+    instances: Option<JobFlowInstancesConfig>,
+    job_flow_role: Option<String>,
+    kerberos_attributes: Option<KerberosAttributes>,
+    log_uri: Option<String>,
+    name: Option<String>,
+    new_supported_products: Option<Vec<SupportedProductConfig>>,
+    release_label: Option<String>,
+    repo_upgrade_on_boot: Option<RepoUpgradeOnBoot>,
+    scale_down_behavior: Option<ScaleDownBehavior>,
+    security_configuration: Option<String>,
+    service_role: Option<String>,
+    steps: Option<Vec<StepConfig>>,
+    supported_products: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+    visible_to_all_users: Option<bool>,
+}
+
+impl RunJobFlowInputBuilder {
+    /// Sets the [`additional_info`](struct.RunJobFlowInput.html#structfield.additional_info) member.
+    pub fn additional_info<T: Into<String>>(mut self, value: T) -> Self {
+        self.additional_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ami_version`](struct.RunJobFlowInput.html#structfield.ami_version) member.
+    pub fn ami_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.ami_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`applications`](struct.RunJobFlowInput.html#structfield.applications) member.
+    pub fn applications<T: Into<Vec<Application>>>(mut self, value: T) -> Self {
+        self.applications = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_scaling_role`](struct.RunJobFlowInput.html#structfield.auto_scaling_role) member.
+    pub fn auto_scaling_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.auto_scaling_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bootstrap_actions`](struct.RunJobFlowInput.html#structfield.bootstrap_actions) member.
+    pub fn bootstrap_actions<T: Into<Vec<BootstrapActionConfig>>>(mut self, value: T) -> Self {
+        self.bootstrap_actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configurations`](struct.RunJobFlowInput.html#structfield.configurations) member.
+    pub fn configurations<T: Into<Vec<Configuration>>>(mut self, value: T) -> Self {
+        self.configurations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_ami_id`](struct.RunJobFlowInput.html#structfield.custom_ami_id) member.
+    pub fn custom_ami_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_ami_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ebs_root_volume_size`](struct.RunJobFlowInput.html#structfield.ebs_root_volume_size) member.
+    pub fn ebs_root_volume_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.ebs_root_volume_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instances`](struct.RunJobFlowInput.html#structfield.instances) member.
+    pub fn instances<T: Into<JobFlowInstancesConfig>>(mut self, value: T) -> Self {
+        self.instances = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_flow_role`](struct.RunJobFlowInput.html#structfield.job_flow_role) member.
+    pub fn job_flow_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_flow_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kerberos_attributes`](struct.RunJobFlowInput.html#structfield.kerberos_attributes) member.
+    pub fn kerberos_attributes<T: Into<KerberosAttributes>>(mut self, value: T) -> Self {
+        self.kerberos_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_uri`](struct.RunJobFlowInput.html#structfield.log_uri) member.
+    pub fn log_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.RunJobFlowInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_supported_products`](struct.RunJobFlowInput.html#structfield.new_supported_products) member.
+    pub fn new_supported_products<T: Into<Vec<SupportedProductConfig>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.new_supported_products = Some(value.into());
+        self
+    }
+
+    /// Sets the [`release_label`](struct.RunJobFlowInput.html#structfield.release_label) member.
+    pub fn release_label<T: Into<String>>(mut self, value: T) -> Self {
+        self.release_label = Some(value.into());
+        self
+    }
+
+    /// Sets the [`repo_upgrade_on_boot`](struct.RunJobFlowInput.html#structfield.repo_upgrade_on_boot) member.
+    pub fn repo_upgrade_on_boot<T: Into<RepoUpgradeOnBoot>>(mut self, value: T) -> Self {
+        self.repo_upgrade_on_boot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scale_down_behavior`](struct.RunJobFlowInput.html#structfield.scale_down_behavior) member.
+    pub fn scale_down_behavior<T: Into<ScaleDownBehavior>>(mut self, value: T) -> Self {
+        self.scale_down_behavior = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_configuration`](struct.RunJobFlowInput.html#structfield.security_configuration) member.
+    pub fn security_configuration<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role`](struct.RunJobFlowInput.html#structfield.service_role) member.
+    pub fn service_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`steps`](struct.RunJobFlowInput.html#structfield.steps) member.
+    pub fn steps<T: Into<Vec<StepConfig>>>(mut self, value: T) -> Self {
+        self.steps = Some(value.into());
+        self
+    }
+
+    /// Sets the [`supported_products`](struct.RunJobFlowInput.html#structfield.supported_products) member.
+    pub fn supported_products<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.supported_products = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RunJobFlowInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`visible_to_all_users`](struct.RunJobFlowInput.html#structfield.visible_to_all_users) member.
+    pub fn visible_to_all_users<T: Into<bool>>(mut self, value: T) -> Self {
+        self.visible_to_all_users = Some(value.into());
+        self
+    }
+
+    /// Builds the `RunJobFlowInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RunJobFlowInput, ::rusoto_core::BuildError> {
+        Ok(RunJobFlowInput {
+            additional_info: self.additional_info,
+            ami_version: self.ami_version,
+            applications: self.applications,
+            auto_scaling_role: self.auto_scaling_role,
+            bootstrap_actions: self.bootstrap_actions,
+            configurations: self.configurations,
+            custom_ami_id: self.custom_ami_id,
+            ebs_root_volume_size: self.ebs_root_volume_size,
+            instances: self
+                .instances
+                .ok_or(::rusoto_core::BuildError::missing("instances"))?,
+            job_flow_role: self.job_flow_role,
+            kerberos_attributes: self.kerberos_attributes,
+            log_uri: self.log_uri,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            new_supported_products: self.new_supported_products,
+            release_label: self.release_label,
+            repo_upgrade_on_boot: self.repo_upgrade_on_boot,
+            scale_down_behavior: self.scale_down_behavior,
+            security_configuration: self.security_configuration,
+            service_role: self.service_role,
+            steps: self.steps,
+            supported_products: self.supported_products,
+            tags: self.tags,
+            visible_to_all_users: self.visible_to_all_users,
+        })
+    }
+}
+
 /// <p> The result of the <a>RunJobFlow</a> operation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2096,13 +5081,85 @@ pub struct RunJobFlowOutput {
     pub job_flow_id: Option<String>,
 }
 
+/// <p>The way that individual Amazon EC2 instances terminate when an automatic scale-in activity occurs or an instance group is resized. <code>TERMINATE_AT_INSTANCE_HOUR</code> indicates that Amazon EMR terminates nodes at the instance-hour boundary, regardless of when the request to terminate the instance was submitted. This option is only available with Amazon EMR 5.1.0 and later and is the default for clusters created using that version. <code>TERMINATE_AT_TASK_COMPLETION</code> indicates that Amazon EMR blacklists and drains tasks from nodes before terminating the Amazon EC2 instances, regardless of the instance-hour boundary. With either behavior, Amazon EMR removes the least active nodes first and blocks instance termination if it could lead to HDFS corruption. <code>TERMINATE_AT_TASK_COMPLETION</code> is available only in Amazon EMR version 4.1.0 and later, and is the default for versions of Amazon EMR earlier than 5.1.0.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScaleDownBehavior {
+    /// <p><code>TERMINATE_AT_INSTANCE_HOUR</code></p>
+    TerminateAtInstanceHour,
+    /// <p><code>TERMINATE_AT_TASK_COMPLETION</code></p>
+    TerminateAtTaskCompletion,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScaleDownBehavior {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScaleDownBehavior::TerminateAtInstanceHour => "TERMINATE_AT_INSTANCE_HOUR",
+            ScaleDownBehavior::TerminateAtTaskCompletion => "TERMINATE_AT_TASK_COMPLETION",
+            ScaleDownBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScaleDownBehavior {
+    fn from(value: &'a str) -> ScaleDownBehavior {
+        match value {
+            "TERMINATE_AT_INSTANCE_HOUR" => ScaleDownBehavior::TerminateAtInstanceHour,
+            "TERMINATE_AT_TASK_COMPLETION" => ScaleDownBehavior::TerminateAtTaskCompletion,
+            _ => ScaleDownBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScaleDownBehavior {
+    fn from(value: String) -> ScaleDownBehavior {
+        ScaleDownBehavior::from(value.as_str())
+    }
+}
+
+impl From<ScaleDownBehavior> for String {
+    fn from(value: ScaleDownBehavior) -> String {
+        match value {
+            ScaleDownBehavior::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScaleDownBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScaleDownBehavior {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScaleDownBehavior {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ScaleDownBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScaleDownBehavior::from(value))
+    }
+}
+
 /// <p>The type of adjustment the automatic scaling activity makes when triggered, and the periodicity of the adjustment.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScalingAction {
     /// <p>Not available for instance groups. Instance groups use the market type specified for the group.</p>
     #[serde(rename = "Market")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub market: Option<String>,
+    pub market: Option<MarketType>,
     /// <p>The type of adjustment the automatic scaling activity makes when triggered, and the periodicity of the adjustment.</p>
     #[serde(rename = "SimpleScalingPolicyConfiguration")]
     pub simple_scaling_policy_configuration: SimpleScalingPolicyConfiguration,
@@ -2120,7 +5177,7 @@ pub struct ScalingConstraints {
 }
 
 /// <p>A scale-in or scale-out rule that defines scaling activity, including the CloudWatch metric alarm that triggers activity, how EC2 instances are added or removed, and the periodicity of adjustments. The automatic scaling policy for an instance group can comprise one or more automatic scaling rules.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScalingRule {
     /// <p>The conditions that trigger an automatic scaling activity.</p>
     #[serde(rename = "Action")]
@@ -2138,7 +5195,7 @@ pub struct ScalingRule {
 }
 
 /// <p>The conditions that trigger an automatic scaling activity.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScalingTrigger {
     /// <p>The definition of a CloudWatch metric alarm. When the defined alarm conditions are met along with other trigger parameters, scaling activity begins.</p>
     #[serde(rename = "CloudWatchAlarmDefinition")]
@@ -2163,8 +5220,9 @@ pub struct ScriptBootstrapActionConfig {
 pub struct SecurityConfigurationSummary {
     /// <p>The date and time the security configuration was created.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The name of the security configuration.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2182,6 +5240,48 @@ pub struct SetTerminationProtectionInput {
     pub termination_protected: bool,
 }
 
+impl SetTerminationProtectionInput {
+    /// Returns a builder for `SetTerminationProtectionInput`, checking its required members when built.
+    pub fn builder() -> SetTerminationProtectionInputBuilder {
+        SetTerminationProtectionInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetTerminationProtectionInput`](struct.SetTerminationProtectionInput.html).
+#[derive(Default, Debug)]
+pub struct SetTerminationProtectionInputBuilder {
+    job_flow_ids: Option<Vec<String>>,
+    termination_protected: Option<bool>,
+}
+
+impl SetTerminationProtectionInputBuilder {
+    /// Sets the [`job_flow_ids`](struct.SetTerminationProtectionInput.html#structfield.job_flow_ids) member.
+    pub fn job_flow_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.job_flow_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`termination_protected`](struct.SetTerminationProtectionInput.html#structfield.termination_protected) member.
+    pub fn termination_protected<T: Into<bool>>(mut self, value: T) -> Self {
+        self.termination_protected = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetTerminationProtectionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetTerminationProtectionInput, ::rusoto_core::BuildError> {
+        Ok(SetTerminationProtectionInput {
+            job_flow_ids: self
+                .job_flow_ids
+                .ok_or(::rusoto_core::BuildError::missing("job_flow_ids"))?,
+            termination_protected: self
+                .termination_protected
+                .ok_or(::rusoto_core::BuildError::missing("termination_protected"))?,
+        })
+    }
+}
+
 /// <p> <i>This member will be deprecated.</i> </p> <p>The input to the SetVisibleToAllUsers action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SetVisibleToAllUsersInput {
@@ -2193,6 +5293,48 @@ pub struct SetVisibleToAllUsersInput {
     pub visible_to_all_users: bool,
 }
 
+impl SetVisibleToAllUsersInput {
+    /// Returns a builder for `SetVisibleToAllUsersInput`, checking its required members when built.
+    pub fn builder() -> SetVisibleToAllUsersInputBuilder {
+        SetVisibleToAllUsersInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetVisibleToAllUsersInput`](struct.SetVisibleToAllUsersInput.html).
+#[derive(Default, Debug)]
+pub struct SetVisibleToAllUsersInputBuilder {
+    job_flow_ids: Option<Vec<String>>,
+    visible_to_all_users: Option<bool>,
+}
+
+impl SetVisibleToAllUsersInputBuilder {
+    /// Sets the [`job_flow_ids`](struct.SetVisibleToAllUsersInput.html#structfield.job_flow_ids) member.
+    pub fn job_flow_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.job_flow_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`visible_to_all_users`](struct.SetVisibleToAllUsersInput.html#structfield.visible_to_all_users) member.
+    pub fn visible_to_all_users<T: Into<bool>>(mut self, value: T) -> Self {
+        self.visible_to_all_users = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetVisibleToAllUsersInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetVisibleToAllUsersInput, ::rusoto_core::BuildError> {
+        Ok(SetVisibleToAllUsersInput {
+            job_flow_ids: self
+                .job_flow_ids
+                .ok_or(::rusoto_core::BuildError::missing("job_flow_ids"))?,
+            visible_to_all_users: self
+                .visible_to_all_users
+                .ok_or(::rusoto_core::BuildError::missing("visible_to_all_users"))?,
+        })
+    }
+}
+
 /// <p>Policy for customizing shrink operations. Allows configuration of decommissioning timeout and targeted instance shrinking.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ShrinkPolicy {
@@ -2212,7 +5354,7 @@ pub struct SimpleScalingPolicyConfiguration {
     /// <p>The way in which EC2 instances are added (if <code>ScalingAdjustment</code> is a positive number) or terminated (if <code>ScalingAdjustment</code> is a negative number) each time the scaling activity is triggered. <code>CHANGE_IN_CAPACITY</code> is the default. <code>CHANGE_IN_CAPACITY</code> indicates that the EC2 instance count increments or decrements by <code>ScalingAdjustment</code>, which should be expressed as an integer. <code>PERCENT_CHANGE_IN_CAPACITY</code> indicates the instance count increments or decrements by the percentage specified by <code>ScalingAdjustment</code>, which should be expressed as an integer. For example, 20 indicates an increase in 20% increments of cluster capacity. <code>EXACT_CAPACITY</code> indicates the scaling activity results in an instance group with the number of EC2 instances specified by <code>ScalingAdjustment</code>, which should be expressed as a positive integer.</p>
     #[serde(rename = "AdjustmentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub adjustment_type: Option<String>,
+    pub adjustment_type: Option<AdjustmentType>,
     /// <p>The amount of time, in seconds, after a scaling activity completes before any further trigger-related scaling activities can start. The default value is 0.</p>
     #[serde(rename = "CoolDown")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2223,7 +5365,7 @@ pub struct SimpleScalingPolicyConfiguration {
 }
 
 /// <p><p>The launch specification for Spot instances in the instance fleet, which determines the defined duration and provisioning timeout behavior.</p> <note> <p>The instance fleet configuration is available only in Amazon EMR versions 4.8.0 and later, excluding 5.0.x versions.</p> </note></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SpotProvisioningSpecification {
     /// <p>The defined duration for Spot instances (also known as Spot blocks) in minutes. When specified, the Spot instance does not terminate before the defined duration expires, and defined duration pricing for Spot instances applies. Valid values are 60, 120, 180, 240, 300, or 360. The duration period starts as soon as a Spot instance receives its instance ID. At the end of the duration, Amazon EC2 marks the Spot instance for termination and provides a Spot instance termination notice, which gives the instance a two-minute warning before it terminates. </p>
     #[serde(rename = "BlockDurationMinutes")]
@@ -2231,12 +5373,170 @@ pub struct SpotProvisioningSpecification {
     pub block_duration_minutes: Option<i64>,
     /// <p>The action to take when <code>TargetSpotCapacity</code> has not been fulfilled when the <code>TimeoutDurationMinutes</code> has expired; that is, when all Spot instances could not be provisioned within the Spot provisioning timeout. Valid values are <code>TERMINATE_CLUSTER</code> and <code>SWITCH_TO_ON_DEMAND</code>. SWITCH_TO_ON_DEMAND specifies that if no Spot instances are available, On-Demand Instances should be provisioned to fulfill any remaining Spot capacity.</p>
     #[serde(rename = "TimeoutAction")]
-    pub timeout_action: String,
+    pub timeout_action: SpotProvisioningTimeoutAction,
     /// <p>The spot provisioning timeout period in minutes. If Spot instances are not provisioned within this time period, the <code>TimeOutAction</code> is taken. Minimum value is 5 and maximum value is 1440. The timeout applies only during initial provisioning, when the cluster is first created.</p>
     #[serde(rename = "TimeoutDurationMinutes")]
     pub timeout_duration_minutes: i64,
 }
 
+/// <p>The action to take when <code>TargetSpotCapacity</code> has not been fulfilled when the <code>TimeoutDurationMinutes</code> has expired; that is, when all Spot instances could not be provisioned within the Spot provisioning timeout. Valid values are <code>TERMINATE_CLUSTER</code> and <code>SWITCH_TO_ON_DEMAND</code>. SWITCH_TO_ON_DEMAND specifies that if no Spot instances are available, On-Demand Instances should be provisioned to fulfill any remaining Spot capacity.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SpotProvisioningTimeoutAction {
+    /// <p><code>SWITCH_TO_ON_DEMAND</code></p>
+    SwitchToOnDemand,
+    /// <p><code>TERMINATE_CLUSTER</code></p>
+    TerminateCluster,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SpotProvisioningTimeoutAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SpotProvisioningTimeoutAction::SwitchToOnDemand => "SWITCH_TO_ON_DEMAND",
+            SpotProvisioningTimeoutAction::TerminateCluster => "TERMINATE_CLUSTER",
+            SpotProvisioningTimeoutAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SpotProvisioningTimeoutAction {
+    fn from(value: &'a str) -> SpotProvisioningTimeoutAction {
+        match value {
+            "SWITCH_TO_ON_DEMAND" => SpotProvisioningTimeoutAction::SwitchToOnDemand,
+            "TERMINATE_CLUSTER" => SpotProvisioningTimeoutAction::TerminateCluster,
+            _ => SpotProvisioningTimeoutAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SpotProvisioningTimeoutAction {
+    fn from(value: String) -> SpotProvisioningTimeoutAction {
+        SpotProvisioningTimeoutAction::from(value.as_str())
+    }
+}
+
+impl From<SpotProvisioningTimeoutAction> for String {
+    fn from(value: SpotProvisioningTimeoutAction) -> String {
+        match value {
+            SpotProvisioningTimeoutAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SpotProvisioningTimeoutAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SpotProvisioningTimeoutAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SpotProvisioningTimeoutAction {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<SpotProvisioningTimeoutAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SpotProvisioningTimeoutAction::from(value))
+    }
+}
+
+/// <p>The statistic to apply to the metric associated with the alarm. The default is <code>AVERAGE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Statistic {
+    /// <p><code>SAMPLE_COUNT</code></p>
+    SampleCount,
+    /// <p><code>AVERAGE</code></p>
+    Average,
+    /// <p><code>SUM</code></p>
+    Sum,
+    /// <p><code>MINIMUM</code></p>
+    Minimum,
+    /// <p><code>MAXIMUM</code></p>
+    Maximum,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Statistic {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Statistic::SampleCount => "SAMPLE_COUNT",
+            Statistic::Average => "AVERAGE",
+            Statistic::Sum => "SUM",
+            Statistic::Minimum => "MINIMUM",
+            Statistic::Maximum => "MAXIMUM",
+            Statistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Statistic {
+    fn from(value: &'a str) -> Statistic {
+        match value {
+            "SAMPLE_COUNT" => Statistic::SampleCount,
+            "AVERAGE" => Statistic::Average,
+            "SUM" => Statistic::Sum,
+            "MINIMUM" => Statistic::Minimum,
+            "MAXIMUM" => Statistic::Maximum,
+            _ => Statistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Statistic {
+    fn from(value: String) -> Statistic {
+        Statistic::from(value.as_str())
+    }
+}
+
+impl From<Statistic> for String {
+    fn from(value: Statistic) -> String {
+        match value {
+            Statistic::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Statistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Statistic {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Statistic {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Statistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Statistic::from(value))
+    }
+}
+
 /// <p>This represents a step in a cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2244,7 +5544,7 @@ pub struct Step {
     /// <p>The action to take when the cluster step fails. Possible values are TERMINATE_CLUSTER, CANCEL_AND_WAIT, and CONTINUE. TERMINATE_JOB_FLOW is provided for backward compatibility. We recommend using TERMINATE_CLUSTER instead.</p>
     #[serde(rename = "ActionOnFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_on_failure: Option<String>,
+    pub action_on_failure: Option<ActionOnFailure>,
     /// <p>The Hadoop job configuration of the cluster step.</p>
     #[serde(rename = "Config")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2269,7 +5569,7 @@ pub struct StepConfig {
     /// <p>The action to take when the cluster step fails. Possible values are TERMINATE_CLUSTER, CANCEL_AND_WAIT, and CONTINUE. TERMINATE_JOB_FLOW is provided for backward compatibility. We recommend using TERMINATE_CLUSTER instead.</p>
     #[serde(rename = "ActionOnFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_on_failure: Option<String>,
+    pub action_on_failure: Option<ActionOnFailure>,
     /// <p>The JAR file used for the step.</p>
     #[serde(rename = "HadoopJarStep")]
     pub hadoop_jar_step: HadoopJarStepConfig,
@@ -2279,7 +5579,7 @@ pub struct StepConfig {
 }
 
 /// <p>Combines the execution state and configuration of a step.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StepDetail {
     /// <p>The description of the step status.</p>
@@ -2290,28 +5590,215 @@ pub struct StepDetail {
     pub step_config: StepConfig,
 }
 
+/// <p>The state of the step.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StepExecutionState {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>CONTINUE</code></p>
+    Continue,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>CANCELLED</code></p>
+    Cancelled,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>INTERRUPTED</code></p>
+    Interrupted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StepExecutionState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StepExecutionState::Pending => "PENDING",
+            StepExecutionState::Running => "RUNNING",
+            StepExecutionState::Continue => "CONTINUE",
+            StepExecutionState::Completed => "COMPLETED",
+            StepExecutionState::Cancelled => "CANCELLED",
+            StepExecutionState::Failed => "FAILED",
+            StepExecutionState::Interrupted => "INTERRUPTED",
+            StepExecutionState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StepExecutionState {
+    fn from(value: &'a str) -> StepExecutionState {
+        match value {
+            "PENDING" => StepExecutionState::Pending,
+            "RUNNING" => StepExecutionState::Running,
+            "CONTINUE" => StepExecutionState::Continue,
+            "COMPLETED" => StepExecutionState::Completed,
+            "CANCELLED" => StepExecutionState::Cancelled,
+            "FAILED" => StepExecutionState::Failed,
+            "INTERRUPTED" => StepExecutionState::Interrupted,
+            _ => StepExecutionState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StepExecutionState {
+    fn from(value: String) -> StepExecutionState {
+        StepExecutionState::from(value.as_str())
+    }
+}
+
+impl From<StepExecutionState> for String {
+    fn from(value: StepExecutionState) -> String {
+        match value {
+            StepExecutionState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StepExecutionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StepExecutionState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StepExecutionState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StepExecutionState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StepExecutionState::from(value))
+    }
+}
+
 /// <p>The execution state of a step.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StepExecutionStatusDetail {
     /// <p>The creation date and time of the step.</p>
     #[serde(rename = "CreationDateTime")]
-    pub creation_date_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_date_time: EpochTimestamp,
     /// <p>The completion date and time of the step.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<EpochTimestamp>,
     /// <p>A description of the step's current state.</p>
     #[serde(rename = "LastStateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_state_change_reason: Option<String>,
     /// <p>The start date and time of the step.</p>
     #[serde(rename = "StartDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date_time: Option<f64>,
+    pub start_date_time: Option<EpochTimestamp>,
     /// <p>The state of the step.</p>
     #[serde(rename = "State")]
-    pub state: String,
+    pub state: StepExecutionState,
+}
+
+/// <p>The filter to limit the step list based on certain states.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StepState {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>CANCEL_PENDING</code></p>
+    CancelPending,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>CANCELLED</code></p>
+    Cancelled,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>INTERRUPTED</code></p>
+    Interrupted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StepState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StepState::Pending => "PENDING",
+            StepState::CancelPending => "CANCEL_PENDING",
+            StepState::Running => "RUNNING",
+            StepState::Completed => "COMPLETED",
+            StepState::Cancelled => "CANCELLED",
+            StepState::Failed => "FAILED",
+            StepState::Interrupted => "INTERRUPTED",
+            StepState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StepState {
+    fn from(value: &'a str) -> StepState {
+        match value {
+            "PENDING" => StepState::Pending,
+            "CANCEL_PENDING" => StepState::CancelPending,
+            "RUNNING" => StepState::Running,
+            "COMPLETED" => StepState::Completed,
+            "CANCELLED" => StepState::Cancelled,
+            "FAILED" => StepState::Failed,
+            "INTERRUPTED" => StepState::Interrupted,
+            _ => StepState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StepState {
+    fn from(value: String) -> StepState {
+        StepState::from(value.as_str())
+    }
+}
+
+impl From<StepState> for String {
+    fn from(value: StepState) -> String {
+        match value {
+            StepState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StepState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StepState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StepState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StepState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StepState::from(value))
+    }
 }
 
 /// <p>The details of the step state change reason.</p>
@@ -2321,13 +5808,81 @@ pub struct StepStateChangeReason {
     /// <p>The programmable code for the state change reason. Note: Currently, the service provides no code for the state change.</p>
     #[serde(rename = "Code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<StepStateChangeReasonCode>,
     /// <p>The descriptive message for the state change reason.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+/// <p>The programmable code for the state change reason. Note: Currently, the service provides no code for the state change.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StepStateChangeReasonCode {
+    /// <p><code>NONE</code></p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StepStateChangeReasonCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StepStateChangeReasonCode::None => "NONE",
+            StepStateChangeReasonCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StepStateChangeReasonCode {
+    fn from(value: &'a str) -> StepStateChangeReasonCode {
+        match value {
+            "NONE" => StepStateChangeReasonCode::None,
+            _ => StepStateChangeReasonCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StepStateChangeReasonCode {
+    fn from(value: String) -> StepStateChangeReasonCode {
+        StepStateChangeReasonCode::from(value.as_str())
+    }
+}
+
+impl From<StepStateChangeReasonCode> for String {
+    fn from(value: StepStateChangeReasonCode) -> String {
+        match value {
+            StepStateChangeReasonCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StepStateChangeReasonCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StepStateChangeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StepStateChangeReasonCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StepStateChangeReasonCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StepStateChangeReasonCode::from(value))
+    }
+}
+
 /// <p>The execution status details of the cluster step.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2339,7 +5894,7 @@ pub struct StepStatus {
     /// <p>The execution state of the cluster step.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<StepState>,
     /// <p>The reason for the step execution status change.</p>
     #[serde(rename = "StateChangeReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2357,7 +5912,7 @@ pub struct StepSummary {
     /// <p>The action to take when the cluster step fails. Possible values are TERMINATE_CLUSTER, CANCEL_AND_WAIT, and CONTINUE. TERMINATE_JOB_FLOW is available for backward compatibility. We recommend using TERMINATE_CLUSTER instead.</p>
     #[serde(rename = "ActionOnFailure")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_on_failure: Option<String>,
+    pub action_on_failure: Option<ActionOnFailure>,
     /// <p>The Hadoop job configuration of the cluster step.</p>
     #[serde(rename = "Config")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2382,16 +5937,19 @@ pub struct StepSummary {
 pub struct StepTimeline {
     /// <p>The date and time when the cluster step was created.</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The date and time when the cluster step execution completed or failed.</p>
     #[serde(rename = "EndDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date_time: Option<f64>,
+    pub end_date_time: Option<EpochTimestamp>,
     /// <p>The date and time when the cluster step execution started.</p>
     #[serde(rename = "StartDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_date_time: Option<f64>,
+    pub start_date_time: Option<EpochTimestamp>,
 }
 
 /// <p>The list of supported product configurations which allow user-supplied arguments. EMR accepts these arguments and forwards them to the corresponding installation script as bootstrap action arguments.</p>
@@ -2428,6 +5986,208 @@ pub struct TerminateJobFlowsInput {
     pub job_flow_ids: Vec<String>,
 }
 
+impl TerminateJobFlowsInput {
+    /// Returns a builder for `TerminateJobFlowsInput`, checking its required members when built.
+    pub fn builder() -> TerminateJobFlowsInputBuilder {
+        TerminateJobFlowsInputBuilder::default()
+    }
+}
+
+/// Builds a [`TerminateJobFlowsInput`](struct.TerminateJobFlowsInput.html).
+#[derive(Default, Debug)]
+pub struct TerminateJobFlowsInputBuilder {
+    job_flow_ids: Option<Vec<String>>,
+}
+
+impl TerminateJobFlowsInputBuilder {
+    /// Sets the [`job_flow_ids`](struct.TerminateJobFlowsInput.html#structfield.job_flow_ids) member.
+    pub fn job_flow_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.job_flow_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `TerminateJobFlowsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TerminateJobFlowsInput, ::rusoto_core::BuildError> {
+        Ok(TerminateJobFlowsInput {
+            job_flow_ids: self
+                .job_flow_ids
+                .ok_or(::rusoto_core::BuildError::missing("job_flow_ids"))?,
+        })
+    }
+}
+
+/// <p>The unit of measure associated with the CloudWatch metric being watched. The value specified for <code>Unit</code> must correspond to the units specified in the CloudWatch metric.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>SECONDS</code></p>
+    Seconds,
+    /// <p><code>MICRO_SECONDS</code></p>
+    MicroSeconds,
+    /// <p><code>MILLI_SECONDS</code></p>
+    MilliSeconds,
+    /// <p><code>BYTES</code></p>
+    Bytes,
+    /// <p><code>KILO_BYTES</code></p>
+    KiloBytes,
+    /// <p><code>MEGA_BYTES</code></p>
+    MegaBytes,
+    /// <p><code>GIGA_BYTES</code></p>
+    GigaBytes,
+    /// <p><code>TERA_BYTES</code></p>
+    TeraBytes,
+    /// <p><code>BITS</code></p>
+    Bits,
+    /// <p><code>KILO_BITS</code></p>
+    KiloBits,
+    /// <p><code>MEGA_BITS</code></p>
+    MegaBits,
+    /// <p><code>GIGA_BITS</code></p>
+    GigaBits,
+    /// <p><code>TERA_BITS</code></p>
+    TeraBits,
+    /// <p><code>PERCENT</code></p>
+    Percent,
+    /// <p><code>COUNT</code></p>
+    Count,
+    /// <p><code>BYTES_PER_SECOND</code></p>
+    BytesPerSecond,
+    /// <p><code>KILO_BYTES_PER_SECOND</code></p>
+    KiloBytesPerSecond,
+    /// <p><code>MEGA_BYTES_PER_SECOND</code></p>
+    MegaBytesPerSecond,
+    /// <p><code>GIGA_BYTES_PER_SECOND</code></p>
+    GigaBytesPerSecond,
+    /// <p><code>TERA_BYTES_PER_SECOND</code></p>
+    TeraBytesPerSecond,
+    /// <p><code>BITS_PER_SECOND</code></p>
+    BitsPerSecond,
+    /// <p><code>KILO_BITS_PER_SECOND</code></p>
+    KiloBitsPerSecond,
+    /// <p><code>MEGA_BITS_PER_SECOND</code></p>
+    MegaBitsPerSecond,
+    /// <p><code>GIGA_BITS_PER_SECOND</code></p>
+    GigaBitsPerSecond,
+    /// <p><code>TERA_BITS_PER_SECOND</code></p>
+    TeraBitsPerSecond,
+    /// <p><code>COUNT_PER_SECOND</code></p>
+    CountPerSecond,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Unit {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Unit::None => "NONE",
+            Unit::Seconds => "SECONDS",
+            Unit::MicroSeconds => "MICRO_SECONDS",
+            Unit::MilliSeconds => "MILLI_SECONDS",
+            Unit::Bytes => "BYTES",
+            Unit::KiloBytes => "KILO_BYTES",
+            Unit::MegaBytes => "MEGA_BYTES",
+            Unit::GigaBytes => "GIGA_BYTES",
+            Unit::TeraBytes => "TERA_BYTES",
+            Unit::Bits => "BITS",
+            Unit::KiloBits => "KILO_BITS",
+            Unit::MegaBits => "MEGA_BITS",
+            Unit::GigaBits => "GIGA_BITS",
+            Unit::TeraBits => "TERA_BITS",
+            Unit::Percent => "PERCENT",
+            Unit::Count => "COUNT",
+            Unit::BytesPerSecond => "BYTES_PER_SECOND",
+            Unit::KiloBytesPerSecond => "KILO_BYTES_PER_SECOND",
+            Unit::MegaBytesPerSecond => "MEGA_BYTES_PER_SECOND",
+            Unit::GigaBytesPerSecond => "GIGA_BYTES_PER_SECOND",
+            Unit::TeraBytesPerSecond => "TERA_BYTES_PER_SECOND",
+            Unit::BitsPerSecond => "BITS_PER_SECOND",
+            Unit::KiloBitsPerSecond => "KILO_BITS_PER_SECOND",
+            Unit::MegaBitsPerSecond => "MEGA_BITS_PER_SECOND",
+            Unit::GigaBitsPerSecond => "GIGA_BITS_PER_SECOND",
+            Unit::TeraBitsPerSecond => "TERA_BITS_PER_SECOND",
+            Unit::CountPerSecond => "COUNT_PER_SECOND",
+            Unit::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Unit {
+    fn from(value: &'a str) -> Unit {
+        match value {
+            "NONE" => Unit::None,
+            "SECONDS" => Unit::Seconds,
+            "MICRO_SECONDS" => Unit::MicroSeconds,
+            "MILLI_SECONDS" => Unit::MilliSeconds,
+            "BYTES" => Unit::Bytes,
+            "KILO_BYTES" => Unit::KiloBytes,
+            "MEGA_BYTES" => Unit::MegaBytes,
+            "GIGA_BYTES" => Unit::GigaBytes,
+            "TERA_BYTES" => Unit::TeraBytes,
+            "BITS" => Unit::Bits,
+            "KILO_BITS" => Unit::KiloBits,
+            "MEGA_BITS" => Unit::MegaBits,
+            "GIGA_BITS" => Unit::GigaBits,
+            "TERA_BITS" => Unit::TeraBits,
+            "PERCENT" => Unit::Percent,
+            "COUNT" => Unit::Count,
+            "BYTES_PER_SECOND" => Unit::BytesPerSecond,
+            "KILO_BYTES_PER_SECOND" => Unit::KiloBytesPerSecond,
+            "MEGA_BYTES_PER_SECOND" => Unit::MegaBytesPerSecond,
+            "GIGA_BYTES_PER_SECOND" => Unit::GigaBytesPerSecond,
+            "TERA_BYTES_PER_SECOND" => Unit::TeraBytesPerSecond,
+            "BITS_PER_SECOND" => Unit::BitsPerSecond,
+            "KILO_BITS_PER_SECOND" => Unit::KiloBitsPerSecond,
+            "MEGA_BITS_PER_SECOND" => Unit::MegaBitsPerSecond,
+            "GIGA_BITS_PER_SECOND" => Unit::GigaBitsPerSecond,
+            "TERA_BITS_PER_SECOND" => Unit::TeraBitsPerSecond,
+            "COUNT_PER_SECOND" => Unit::CountPerSecond,
+            _ => Unit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Unit {
+    fn from(value: String) -> Unit {
+        Unit::from(value.as_str())
+    }
+}
+
+impl From<Unit> for String {
+    fn from(value: Unit) -> String {
+        match value {
+            Unit::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Unit {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Unit {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Unit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Unit::from(value))
+    }
+}
+
 /// <p>EBS volume specifications such as volume type, IOPS, and size (GiB) that will be requested for the EBS volume attached to an EC2 instance in the cluster.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VolumeSpecification {
@@ -3715,6 +7475,184 @@ pub trait Emr {
         input: TerminateJobFlowsInput,
     ) -> RusotoFuture<(), TerminateJobFlowsError>;
 }
+impl<T: ?Sized + Emr> Emr for ::std::sync::Arc<T> {
+    fn add_instance_fleet(
+        &self,
+        input: AddInstanceFleetInput,
+    ) -> RusotoFuture<AddInstanceFleetOutput, AddInstanceFleetError> {
+        (**self).add_instance_fleet(input)
+    }
+    fn add_instance_groups(
+        &self,
+        input: AddInstanceGroupsInput,
+    ) -> RusotoFuture<AddInstanceGroupsOutput, AddInstanceGroupsError> {
+        (**self).add_instance_groups(input)
+    }
+    fn add_job_flow_steps(
+        &self,
+        input: AddJobFlowStepsInput,
+    ) -> RusotoFuture<AddJobFlowStepsOutput, AddJobFlowStepsError> {
+        (**self).add_job_flow_steps(input)
+    }
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn cancel_steps(
+        &self,
+        input: CancelStepsInput,
+    ) -> RusotoFuture<CancelStepsOutput, CancelStepsError> {
+        (**self).cancel_steps(input)
+    }
+    fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationInput,
+    ) -> RusotoFuture<CreateSecurityConfigurationOutput, CreateSecurityConfigurationError> {
+        (**self).create_security_configuration(input)
+    }
+    fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationInput,
+    ) -> RusotoFuture<DeleteSecurityConfigurationOutput, DeleteSecurityConfigurationError> {
+        (**self).delete_security_configuration(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterInput,
+    ) -> RusotoFuture<DescribeClusterOutput, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_job_flows(
+        &self,
+        input: DescribeJobFlowsInput,
+    ) -> RusotoFuture<DescribeJobFlowsOutput, DescribeJobFlowsError> {
+        (**self).describe_job_flows(input)
+    }
+    fn describe_security_configuration(
+        &self,
+        input: DescribeSecurityConfigurationInput,
+    ) -> RusotoFuture<DescribeSecurityConfigurationOutput, DescribeSecurityConfigurationError> {
+        (**self).describe_security_configuration(input)
+    }
+    fn describe_step(
+        &self,
+        input: DescribeStepInput,
+    ) -> RusotoFuture<DescribeStepOutput, DescribeStepError> {
+        (**self).describe_step(input)
+    }
+    fn get_block_public_access_configuration(
+        &self,
+    ) -> RusotoFuture<GetBlockPublicAccessConfigurationOutput, GetBlockPublicAccessConfigurationError>
+    {
+        (**self).get_block_public_access_configuration()
+    }
+    fn list_bootstrap_actions(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> RusotoFuture<ListBootstrapActionsOutput, ListBootstrapActionsError> {
+        (**self).list_bootstrap_actions(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersInput,
+    ) -> RusotoFuture<ListClustersOutput, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_instance_fleets(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> RusotoFuture<ListInstanceFleetsOutput, ListInstanceFleetsError> {
+        (**self).list_instance_fleets(input)
+    }
+    fn list_instance_groups(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> RusotoFuture<ListInstanceGroupsOutput, ListInstanceGroupsError> {
+        (**self).list_instance_groups(input)
+    }
+    fn list_instances(
+        &self,
+        input: ListInstancesInput,
+    ) -> RusotoFuture<ListInstancesOutput, ListInstancesError> {
+        (**self).list_instances(input)
+    }
+    fn list_security_configurations(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> RusotoFuture<ListSecurityConfigurationsOutput, ListSecurityConfigurationsError> {
+        (**self).list_security_configurations(input)
+    }
+    fn list_steps(&self, input: ListStepsInput) -> RusotoFuture<ListStepsOutput, ListStepsError> {
+        (**self).list_steps(input)
+    }
+    fn modify_instance_fleet(
+        &self,
+        input: ModifyInstanceFleetInput,
+    ) -> RusotoFuture<(), ModifyInstanceFleetError> {
+        (**self).modify_instance_fleet(input)
+    }
+    fn modify_instance_groups(
+        &self,
+        input: ModifyInstanceGroupsInput,
+    ) -> RusotoFuture<(), ModifyInstanceGroupsError> {
+        (**self).modify_instance_groups(input)
+    }
+    fn put_auto_scaling_policy(
+        &self,
+        input: PutAutoScalingPolicyInput,
+    ) -> RusotoFuture<PutAutoScalingPolicyOutput, PutAutoScalingPolicyError> {
+        (**self).put_auto_scaling_policy(input)
+    }
+    fn put_block_public_access_configuration(
+        &self,
+        input: PutBlockPublicAccessConfigurationInput,
+    ) -> RusotoFuture<PutBlockPublicAccessConfigurationOutput, PutBlockPublicAccessConfigurationError>
+    {
+        (**self).put_block_public_access_configuration(input)
+    }
+    fn remove_auto_scaling_policy(
+        &self,
+        input: RemoveAutoScalingPolicyInput,
+    ) -> RusotoFuture<RemoveAutoScalingPolicyOutput, RemoveAutoScalingPolicyError> {
+        (**self).remove_auto_scaling_policy(input)
+    }
+    fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> RusotoFuture<RemoveTagsOutput, RemoveTagsError> {
+        (**self).remove_tags(input)
+    }
+    fn run_job_flow(
+        &self,
+        input: RunJobFlowInput,
+    ) -> RusotoFuture<RunJobFlowOutput, RunJobFlowError> {
+        (**self).run_job_flow(input)
+    }
+    fn set_termination_protection(
+        &self,
+        input: SetTerminationProtectionInput,
+    ) -> RusotoFuture<(), SetTerminationProtectionError> {
+        (**self).set_termination_protection(input)
+    }
+    fn set_visible_to_all_users(
+        &self,
+        input: SetVisibleToAllUsersInput,
+    ) -> RusotoFuture<(), SetVisibleToAllUsersError> {
+        (**self).set_visible_to_all_users(input)
+    }
+    fn terminate_job_flows(
+        &self,
+        input: TerminateJobFlowsInput,
+    ) -> RusotoFuture<(), TerminateJobFlowsError> {
+        (**self).terminate_job_flows(input)
+    }
+}
+/// The error codes Amazon EMR retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon EMR API.
 #[derive(Clone)]
 pub struct EmrClient {
@@ -3730,6 +7668,27 @@ impl EmrClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_EMR`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> EmrClient {
+        Self::new(region::Region::from_env_for_service("EMR"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `elasticmapreduce`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> EmrClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("elasticmapreduce", "EMR"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3748,7 +7707,54 @@ impl EmrClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EmrClient {
-        EmrClient { client, region }
+        EmrClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "elasticmapreduce")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "elasticmapreduce",
+            "elasticmapreduce",
+            "EMR",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Emr` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Emr + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for EmrClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon EMR"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "elasticmapreduce"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> EmrClient {
+        Self::new_with_client(client, region)
     }
 }
 
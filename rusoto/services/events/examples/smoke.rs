@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon EventBridge clients.
+//!
+//! Makes a single read-only `ListEventBuses` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_events::{EventBridge, EventBridgeClient, ListEventBusesRequest};
+
+fn main() {
+    let client = EventBridgeClient::from_env();
+    match client
+        .list_event_buses(ListEventBusesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListEventBuses succeeded"),
+        Err(error) => {
+            eprintln!("ListEventBuses failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+extern crate rusoto_mock;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::custom::put_events::{PutEventsConfig, PutEventsExt, PutEventsFailureReason};
+use crate::generated::{EventBridgeClient, PutEventsRequestEntry};
+
+use self::rusoto_mock::*;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::Region;
+
+fn entry(detail: String) -> PutEventsRequestEntry {
+    PutEventsRequestEntry {
+        source: Some("app".to_owned()),
+        detail_type: Some("test".to_owned()),
+        detail: Some(detail),
+        ..Default::default()
+    }
+}
+
+fn sent_entries(request: &SignedRequest) -> usize {
+    let body = match request.payload {
+        Some(SignedRequestPayload::Buffer(ref body)) => body,
+        _ => panic!("expected a buffered payload"),
+    };
+    let input: serde_json::Value = serde_json::from_slice(body).unwrap();
+    input["Entries"].as_array().unwrap().len()
+}
+
+#[test]
+fn put_all_events_splits_entries_and_rejects_oversized_ones() {
+    let body = format!(
+        r#"{{"FailedEntryCount": 0, "Entries": [{}]}}"#,
+        vec![r#"{"EventId": "id"}"#; 10].join(", ")
+    );
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(&body)
+        .with_request_checker(|request: &SignedRequest| assert!(sent_entries(request) <= 10));
+    let client = EventBridgeClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let mut entries: Vec<_> = (0..25).map(|id| entry(id.to_string())).collect();
+    entries.push(entry("x".repeat(256 * 1024)));
+    let outcome = client
+        .put_all_events(entries, PutEventsConfig::default())
+        .sync()
+        .unwrap();
+
+    assert_eq!(outcome.event_ids.len(), 25);
+    assert_eq!(outcome.failures.len(), 1);
+    assert_eq!(
+        outcome.failures[0].reason,
+        PutEventsFailureReason::TooLarge(3 + 4 + 256 * 1024)
+    );
+}
+
+#[test]
+fn put_all_events_retries_only_failed_entries() {
+    let body = r#"{
+        "FailedEntryCount": 1,
+        "Entries": [
+            {"EventId": "first"},
+            {"ErrorCode": "ThrottlingException", "ErrorMessage": "Rate exceeded"}
+        ]
+    }"#;
+    let sent = Arc::new(AtomicUsize::new(0));
+    let counter = sent.clone();
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(body)
+        .with_request_checker(move |request: &SignedRequest| {
+            counter.fetch_add(sent_entries(request), Ordering::SeqCst);
+        });
+    let client = EventBridgeClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let config = PutEventsConfig {
+        max_attempts: 3,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(1),
+    };
+
+    let entries = vec![entry("0".to_owned()), entry("1".to_owned())];
+    let outcome = client.put_all_events(entries, config).sync().unwrap();
+
+    assert!(outcome.is_complete());
+    assert_eq!(outcome.event_ids, vec!["first", "first"]);
+    assert_eq!(sent.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn put_all_events_reports_permanently_failed_entries() {
+    let body = r#"{
+        "FailedEntryCount": 1,
+        "Entries": [{"ErrorCode": "MalformedDetail", "ErrorMessage": "Detail is malformed."}]
+    }"#;
+    let mock = MockRequestDispatcher::with_status(200).with_body(body);
+    let client = EventBridgeClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let outcome = client
+        .put_all_events(vec![entry("{".to_owned())], PutEventsConfig::default())
+        .sync()
+        .unwrap();
+
+    assert!(outcome.event_ids.is_empty());
+    assert_eq!(
+        outcome.failures[0].reason,
+        PutEventsFailureReason::Failed {
+            error_code: "MalformedDetail".to_owned(),
+            error_message: Some("Detail is malformed.".to_owned()),
+        }
+    );
+}
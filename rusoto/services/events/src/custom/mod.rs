@@ -1 +1,5 @@
+/// Putting any number of events, with size validation and retries of failed entries
+pub mod put_events;
 
+#[cfg(test)]
+mod custom_tests;
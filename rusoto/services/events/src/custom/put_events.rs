@@ -0,0 +1,401 @@
+//! Putting any number of events with `PutEvents`.
+//!
+//! A single `PutEvents` call accepts at most 10 entries totalling 256 KiB, and reports the
+//! entries it could not ingest in its response rather than failing the call. `put_all_events`
+//! rejects oversized entries before sending anything, splits the remaining entries into
+//! batches, retries the entries which failed transiently with exponential backoff and reports
+//! the entries which could not be put.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use futures::future::{self, Loop};
+use futures::Future;
+use rusoto_core::waiter::sleep;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    EventBridge, PutEventsError, PutEventsRequest, PutEventsRequestEntry, PutEventsResultEntry,
+};
+
+/// The maximum number of entries accepted by a single `PutEvents` call.
+pub const MAX_PUT_EVENTS_ENTRIES: usize = 10;
+
+/// The maximum total size of the entries of a single `PutEvents` call, which also bounds the
+/// size of a single entry.
+pub const MAX_PUT_EVENTS_BYTES: usize = 256 * 1024;
+
+/// The per-entry error codes worth retrying.
+const RETRYABLE_ENTRY_ERRORS: &[&str] = &["InternalFailure", "ThrottlingException"];
+
+/// Returns the size of an entry as EventBridge counts it against the `PutEvents` limits: the
+/// UTF-8 bytes of its source, detail type, detail and resources, plus 14 bytes for its time.
+pub fn entry_size(entry: &PutEventsRequestEntry) -> usize {
+    let text = |value: &Option<String>| value.as_ref().map_or(0, String::len);
+    let time = if entry.time.is_some() { 14 } else { 0 };
+    let resources = entry
+        .resources
+        .as_ref()
+        .map_or(0, |resources| resources.iter().map(String::len).sum());
+    time + text(&entry.source) + text(&entry.detail_type) + text(&entry.detail) + resources
+}
+
+/// Controls how `put_all_events` retries failed entries.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PutEventsConfig {
+    /// Number of attempts made for each batch before its remaining entries are reported as
+    /// failed
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for every subsequent retry
+    pub base_delay: Duration,
+    /// Upper bound for the delay between two retries
+    pub max_delay: Duration,
+}
+
+impl PutEventsConfig {
+    /// The delay to wait before the given retry, starting at 1.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(retry.saturating_sub(1))
+            .unwrap_or(u32::max_value());
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+impl Default for PutEventsConfig {
+    fn default() -> PutEventsConfig {
+        PutEventsConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Why an entry could not be put.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PutEventsFailureReason {
+    /// The entry is larger than `MAX_PUT_EVENTS_BYTES`, with this size, and was not sent.
+    TooLarge(usize),
+    /// EventBridge rejected the entry, permanently or on the last attempt.
+    Failed {
+        /// The error code returned for the entry
+        error_code: String,
+        /// The error message returned for the entry
+        error_message: Option<String>,
+    },
+    /// The `PutEvents` call containing the entry failed with this error.
+    Error(String),
+}
+
+/// An entry which could not be put.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PutEventsFailure {
+    /// The entry itself
+    pub entry: PutEventsRequestEntry,
+    /// Why the entry failed
+    pub reason: PutEventsFailureReason,
+}
+
+/// The result of `put_all_events`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PutEventsOutcome {
+    /// The ids of the events which were put, in the order they were ingested
+    pub event_ids: Vec<String>,
+    /// Entries which could not be put
+    pub failures: Vec<PutEventsFailure>,
+}
+
+impl PutEventsOutcome {
+    /// Returns true if every entry was put.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+struct PutEventsState {
+    pending: VecDeque<PutEventsRequestEntry>,
+    batch: Vec<PutEventsRequestEntry>,
+    attempt: u32,
+    outcome: PutEventsOutcome,
+}
+
+impl PutEventsState {
+    /// Takes the next entries off `pending` which fit in a single call.
+    fn next_batch(&mut self) {
+        let mut size = 0;
+        while self.batch.len() < MAX_PUT_EVENTS_ENTRIES {
+            let next = match self.pending.front() {
+                Some(entry) => entry_size(entry),
+                None => break,
+            };
+            if !self.batch.is_empty() && size + next > MAX_PUT_EVENTS_BYTES {
+                break;
+            }
+            size += next;
+            self.batch.extend(self.pending.pop_front());
+        }
+    }
+
+    fn fail(&mut self, entry: PutEventsRequestEntry, reason: PutEventsFailureReason) {
+        self.outcome
+            .failures
+            .push(PutEventsFailure { entry, reason });
+    }
+
+    fn fail_batch(&mut self, reason: PutEventsFailureReason) {
+        for entry in self.batch.split_off(0) {
+            self.fail(entry, reason.clone());
+        }
+        self.attempt = 0;
+    }
+}
+
+fn entry_failure(result: &PutEventsResultEntry) -> Option<PutEventsFailureReason> {
+    result
+        .error_code
+        .as_ref()
+        .map(|error_code| PutEventsFailureReason::Failed {
+            error_code: error_code.clone(),
+            error_message: result.error_message.clone(),
+        })
+}
+
+fn is_retryable_entry(reason: &PutEventsFailureReason) -> bool {
+    match *reason {
+        PutEventsFailureReason::Failed { ref error_code, .. } => {
+            RETRYABLE_ENTRY_ERRORS.contains(&error_code.as_str())
+        }
+        _ => false,
+    }
+}
+
+fn is_retryable(error: &RusotoError<PutEventsError>) -> bool {
+    match *error {
+        RusotoError::Service(PutEventsError::Internal(_)) | RusotoError::HttpDispatch(_) => true,
+        RusotoError::Unknown(ref response) => {
+            response.status.as_u16() == 429
+                || response.status.is_server_error()
+                || response.body_as_str().contains("Throttling")
+        }
+        _ => false,
+    }
+}
+
+/// Extension methods for putting an arbitrary number of events.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::Region;
+/// use rusoto_events::put_events::{PutEventsConfig, PutEventsExt};
+/// use rusoto_events::{EventBridgeClient, PutEventsRequestEntry};
+///
+/// let client = EventBridgeClient::new(Region::UsEast1);
+/// let entries = (0..25)
+///     .map(|id| PutEventsRequestEntry {
+///         source: Some("com.example.orders".to_owned()),
+///         detail_type: Some("OrderPlaced".to_owned()),
+///         detail: Some(format!(r#"{{"order_id": {}}}"#, id)),
+///         ..Default::default()
+///     })
+///     .collect();
+///
+/// let outcome = client
+///     .put_all_events(entries, PutEventsConfig::default())
+///     .sync()
+///     .unwrap();
+/// assert!(outcome.is_complete());
+/// ```
+pub trait PutEventsExt: EventBridge + Clone + Send + Sized + 'static {
+    /// Puts all `entries`, in batches of at most 10 entries and 256 KiB.
+    ///
+    /// Entries larger than 256 KiB are reported as `TooLarge` without being sent. Entries
+    /// failing with `InternalFailure` or `ThrottlingException`, and throttled batches, are
+    /// retried with exponential backoff, up to `config.max_attempts` times per batch; only the
+    /// failed entries of a batch are sent again. Entries which still can't be put, or which
+    /// were part of a batch failing with a non retryable error, are reported in the returned
+    /// `PutEventsOutcome` rather than failing the whole future. The future must be run on a
+    /// Tokio runtime, which `sync()` provides.
+    fn put_all_events(
+        &self,
+        entries: Vec<PutEventsRequestEntry>,
+        config: PutEventsConfig,
+    ) -> RusotoFuture<PutEventsOutcome, PutEventsError> {
+        let client = self.clone();
+        let mut state = PutEventsState {
+            pending: VecDeque::with_capacity(entries.len()),
+            batch: Vec::new(),
+            attempt: 0,
+            outcome: PutEventsOutcome::default(),
+        };
+        for entry in entries {
+            let size = entry_size(&entry);
+            if size > MAX_PUT_EVENTS_BYTES {
+                state.fail(entry, PutEventsFailureReason::TooLarge(size));
+            } else {
+                state.pending.push_back(entry);
+            }
+        }
+
+        let future = future::loop_fn(state, move |mut state| {
+            if state.batch.is_empty() {
+                state.next_batch();
+                if state.batch.is_empty() {
+                    return future::Either::A(future::ok(Loop::Break(state.outcome)));
+                }
+            }
+
+            let config = config.clone();
+            state.attempt += 1;
+            let input = PutEventsRequest {
+                entries: state.batch.clone(),
+            };
+
+            future::Either::B(client.put_events(input).then(move |result| {
+                let mut retries = Vec::new();
+                let mut retry_error = None;
+                match result {
+                    Ok(response) => {
+                        let results = response.entries.unwrap_or_default();
+                        for (index, entry) in state.batch.split_off(0).into_iter().enumerate() {
+                            // Results are in the order of the entries; an entry without one
+                            // was not ingested.
+                            let failure = match results.get(index) {
+                                Some(result) => entry_failure(result),
+                                None => Some(PutEventsFailureReason::Failed {
+                                    error_code: "InternalFailure".to_owned(),
+                                    error_message: None,
+                                }),
+                            };
+                            match failure {
+                                None => state.outcome.event_ids.extend(
+                                    results
+                                        .get(index)
+                                        .and_then(|result| result.event_id.clone()),
+                                ),
+                                Some(reason) if is_retryable_entry(&reason) => {
+                                    retries.push((entry, reason))
+                                }
+                                Some(reason) => state.fail(entry, reason),
+                            }
+                        }
+                    }
+                    Err(ref error) if is_retryable(error) => retry_error = Some(error.to_string()),
+                    Err(error) => {
+                        state.fail_batch(PutEventsFailureReason::Error(error.to_string()))
+                    }
+                }
+
+                if let Some(error) = retry_error {
+                    if state.attempt >= config.max_attempts {
+                        state.fail_batch(PutEventsFailureReason::Error(error));
+                        return future::Either::A(future::ok(Loop::Continue(state)));
+                    }
+                } else if retries.is_empty() {
+                    state.attempt = 0;
+                    return future::Either::A(future::ok(Loop::Continue(state)));
+                } else if state.attempt >= config.max_attempts {
+                    for (entry, reason) in retries {
+                        state.fail(entry, reason);
+                    }
+                    state.attempt = 0;
+                    return future::Either::A(future::ok(Loop::Continue(state)));
+                } else {
+                    state.batch = retries.into_iter().map(|(entry, _)| entry).collect();
+                }
+
+                let delay = config.backoff(state.attempt);
+                future::Either::B(sleep(delay).map(move |_| Loop::Continue(state)))
+            }))
+        });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: EventBridge + Clone + Send + 'static> PutEventsExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(detail: &str) -> PutEventsRequestEntry {
+        PutEventsRequestEntry {
+            source: Some("app".to_owned()),
+            detail_type: Some("test".to_owned()),
+            detail: Some(detail.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn state(entries: Vec<PutEventsRequestEntry>) -> PutEventsState {
+        PutEventsState {
+            pending: entries.into(),
+            batch: Vec::new(),
+            attempt: 0,
+            outcome: PutEventsOutcome::default(),
+        }
+    }
+
+    #[test]
+    fn entry_size_counts_every_sized_field() {
+        let entry = PutEventsRequestEntry {
+            resources: Some(vec!["arn:a".to_owned(), "arn:bc".to_owned()]),
+            event_bus_name: Some("not-counted".to_owned()),
+            ..entry("{}")
+        };
+        assert_eq!(entry_size(&entry), 3 + 4 + 2 + 5 + 6);
+    }
+
+    #[test]
+    #[cfg(not(feature = "chrono"))]
+    fn entry_size_counts_the_time() {
+        let entry = PutEventsRequestEntry {
+            time: Some(1_570_000_000.0),
+            ..entry("{}")
+        };
+        assert_eq!(entry_size(&entry), 14 + 3 + 4 + 2);
+    }
+
+    #[test]
+    fn batches_hold_at_most_ten_entries() {
+        let mut state = state(vec![entry("{}"); 25]);
+        let mut sizes = Vec::new();
+        loop {
+            state.next_batch();
+            if state.batch.is_empty() {
+                break;
+            }
+            sizes.push(state.batch.len());
+            state.batch.clear();
+        }
+        assert_eq!(sizes, vec![10, 10, 5]);
+    }
+
+    #[test]
+    fn batches_stay_within_the_request_size() {
+        let large = "x".repeat(100 * 1024);
+        let mut state = state(vec![entry(&large); 3]);
+
+        state.next_batch();
+        assert_eq!(state.batch.len(), 2);
+        state.batch.clear();
+        state.next_batch();
+        assert_eq!(state.batch.len(), 1);
+    }
+
+    #[test]
+    fn only_transient_entry_errors_are_retried() {
+        let failed = |code: &str| PutEventsFailureReason::Failed {
+            error_code: code.to_owned(),
+            error_message: None,
+        };
+        assert!(is_retryable_entry(&failed("ThrottlingException")));
+        assert!(is_retryable_entry(&failed("InternalFailure")));
+        assert!(!is_retryable_entry(&failed("MalformedDetail")));
+        assert!(!is_retryable_entry(&PutEventsFailureReason::TooLarge(1)));
+    }
+}
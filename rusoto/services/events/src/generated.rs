@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ActivateEventSourceRequest {
@@ -30,13 +31,117 @@ pub struct ActivateEventSourceRequest {
     pub name: String,
 }
 
+impl ActivateEventSourceRequest {
+    /// Returns a builder for `ActivateEventSourceRequest`, checking its required members when built.
+    pub fn builder() -> ActivateEventSourceRequestBuilder {
+        ActivateEventSourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ActivateEventSourceRequest`](struct.ActivateEventSourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ActivateEventSourceRequestBuilder {
+    name: Option<String>,
+}
+
+impl ActivateEventSourceRequestBuilder {
+    /// Sets the [`name`](struct.ActivateEventSourceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ActivateEventSourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ActivateEventSourceRequest, ::rusoto_core::BuildError> {
+        Ok(ActivateEventSourceRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
+/// <p>Specifies whether the task's elastic network interface receives a public IP address. You can specify <code>ENABLED</code> only when <code>LaunchType</code> in <code>EcsParameters</code> is set to <code>FARGATE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AssignPublicIp {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AssignPublicIp {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssignPublicIp::Enabled => "ENABLED",
+            AssignPublicIp::Disabled => "DISABLED",
+            AssignPublicIp::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AssignPublicIp {
+    fn from(value: &'a str) -> AssignPublicIp {
+        match value {
+            "ENABLED" => AssignPublicIp::Enabled,
+            "DISABLED" => AssignPublicIp::Disabled,
+            _ => AssignPublicIp::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssignPublicIp {
+    fn from(value: String) -> AssignPublicIp {
+        AssignPublicIp::from(value.as_str())
+    }
+}
+
+impl From<AssignPublicIp> for String {
+    fn from(value: AssignPublicIp) -> String {
+        match value {
+            AssignPublicIp::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AssignPublicIp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AssignPublicIp {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssignPublicIp {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AssignPublicIp, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AssignPublicIp::from(value))
+    }
+}
+
 /// <p>This structure specifies the VPC subnets and security groups for the task and whether a public IP address is to be used. This structure is relevant only for ECS tasks that use the <code>awsvpc</code> network mode.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsVpcConfiguration {
     /// <p>Specifies whether the task's elastic network interface receives a public IP address. You can specify <code>ENABLED</code> only when <code>LaunchType</code> in <code>EcsParameters</code> is set to <code>FARGATE</code>.</p>
     #[serde(rename = "AssignPublicIp")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assign_public_ip: Option<String>,
+    pub assign_public_ip: Option<AssignPublicIp>,
     /// <p>Specifies the security groups associated with the task. These security groups must all be in the same VPC. You can specify as many as five security groups. If you don't specify a security group, the default security group for the VPC is used.</p>
     #[serde(rename = "SecurityGroups")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -108,6 +213,44 @@ pub struct CreateEventBusRequest {
     pub name: String,
 }
 
+impl CreateEventBusRequest {
+    /// Returns a builder for `CreateEventBusRequest`, checking its required members when built.
+    pub fn builder() -> CreateEventBusRequestBuilder {
+        CreateEventBusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateEventBusRequest`](struct.CreateEventBusRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateEventBusRequestBuilder {
+    event_source_name: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateEventBusRequestBuilder {
+    /// Sets the [`event_source_name`](struct.CreateEventBusRequest.html#structfield.event_source_name) member.
+    pub fn event_source_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_source_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateEventBusRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateEventBusRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateEventBusRequest, ::rusoto_core::BuildError> {
+        Ok(CreateEventBusRequest {
+            event_source_name: self.event_source_name,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateEventBusResponse {
@@ -127,6 +270,48 @@ pub struct CreatePartnerEventSourceRequest {
     pub name: String,
 }
 
+impl CreatePartnerEventSourceRequest {
+    /// Returns a builder for `CreatePartnerEventSourceRequest`, checking its required members when built.
+    pub fn builder() -> CreatePartnerEventSourceRequestBuilder {
+        CreatePartnerEventSourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePartnerEventSourceRequest`](struct.CreatePartnerEventSourceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePartnerEventSourceRequestBuilder {
+    account: Option<String>,
+    name: Option<String>,
+}
+
+impl CreatePartnerEventSourceRequestBuilder {
+    /// Sets the [`account`](struct.CreatePartnerEventSourceRequest.html#structfield.account) member.
+    pub fn account<T: Into<String>>(mut self, value: T) -> Self {
+        self.account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreatePartnerEventSourceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePartnerEventSourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePartnerEventSourceRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePartnerEventSourceRequest {
+            account: self
+                .account
+                .ok_or(::rusoto_core::BuildError::missing("account"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreatePartnerEventSourceResponse {
@@ -143,6 +328,38 @@ pub struct DeactivateEventSourceRequest {
     pub name: String,
 }
 
+impl DeactivateEventSourceRequest {
+    /// Returns a builder for `DeactivateEventSourceRequest`, checking its required members when built.
+    pub fn builder() -> DeactivateEventSourceRequestBuilder {
+        DeactivateEventSourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeactivateEventSourceRequest`](struct.DeactivateEventSourceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeactivateEventSourceRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeactivateEventSourceRequestBuilder {
+    /// Sets the [`name`](struct.DeactivateEventSourceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeactivateEventSourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeactivateEventSourceRequest, ::rusoto_core::BuildError> {
+        Ok(DeactivateEventSourceRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteEventBusRequest {
     /// <p>The name of the event bus to delete.</p>
@@ -150,6 +367,36 @@ pub struct DeleteEventBusRequest {
     pub name: String,
 }
 
+impl DeleteEventBusRequest {
+    /// Returns a builder for `DeleteEventBusRequest`, checking its required members when built.
+    pub fn builder() -> DeleteEventBusRequestBuilder {
+        DeleteEventBusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEventBusRequest`](struct.DeleteEventBusRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteEventBusRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteEventBusRequestBuilder {
+    /// Sets the [`name`](struct.DeleteEventBusRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEventBusRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteEventBusRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteEventBusRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeletePartnerEventSourceRequest {
     /// <p>The AWS account ID of the AWS customer that the event source was created for.</p>
@@ -160,6 +407,48 @@ pub struct DeletePartnerEventSourceRequest {
     pub name: String,
 }
 
+impl DeletePartnerEventSourceRequest {
+    /// Returns a builder for `DeletePartnerEventSourceRequest`, checking its required members when built.
+    pub fn builder() -> DeletePartnerEventSourceRequestBuilder {
+        DeletePartnerEventSourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePartnerEventSourceRequest`](struct.DeletePartnerEventSourceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePartnerEventSourceRequestBuilder {
+    account: Option<String>,
+    name: Option<String>,
+}
+
+impl DeletePartnerEventSourceRequestBuilder {
+    /// Sets the [`account`](struct.DeletePartnerEventSourceRequest.html#structfield.account) member.
+    pub fn account<T: Into<String>>(mut self, value: T) -> Self {
+        self.account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DeletePartnerEventSourceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePartnerEventSourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeletePartnerEventSourceRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePartnerEventSourceRequest {
+            account: self
+                .account
+                .ok_or(::rusoto_core::BuildError::missing("account"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteRuleRequest {
     /// <p>The event bus associated with the rule. If you omit this, the default event bus is used.</p>
@@ -175,6 +464,52 @@ pub struct DeleteRuleRequest {
     pub name: String,
 }
 
+impl DeleteRuleRequest {
+    /// Returns a builder for `DeleteRuleRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRuleRequestBuilder {
+        DeleteRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRuleRequest`](struct.DeleteRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRuleRequestBuilder {
+    event_bus_name: Option<String>,
+    force: Option<bool>,
+    name: Option<String>,
+}
+
+impl DeleteRuleRequestBuilder {
+    /// Sets the [`event_bus_name`](struct.DeleteRuleRequest.html#structfield.event_bus_name) member.
+    pub fn event_bus_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_bus_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force`](struct.DeleteRuleRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DeleteRuleRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteRuleRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRuleRequest {
+            event_bus_name: self.event_bus_name,
+            force: self.force,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeEventBusRequest {
     /// <p>The name of the event bus to show details for. If you omit this, the default event bus is displayed.</p>
@@ -183,6 +518,34 @@ pub struct DescribeEventBusRequest {
     pub name: Option<String>,
 }
 
+impl DescribeEventBusRequest {
+    /// Returns a builder for `DescribeEventBusRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEventBusRequestBuilder {
+        DescribeEventBusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventBusRequest`](struct.DescribeEventBusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventBusRequestBuilder {
+    name: Option<String>,
+}
+
+impl DescribeEventBusRequestBuilder {
+    /// Sets the [`name`](struct.DescribeEventBusRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventBusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventBusRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEventBusRequest { name: self.name })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEventBusResponse {
@@ -207,6 +570,38 @@ pub struct DescribeEventSourceRequest {
     pub name: String,
 }
 
+impl DescribeEventSourceRequest {
+    /// Returns a builder for `DescribeEventSourceRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEventSourceRequestBuilder {
+        DescribeEventSourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventSourceRequest`](struct.DescribeEventSourceRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventSourceRequestBuilder {
+    name: Option<String>,
+}
+
+impl DescribeEventSourceRequestBuilder {
+    /// Sets the [`name`](struct.DescribeEventSourceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventSourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventSourceRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEventSourceRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEventSourceResponse {
@@ -220,12 +615,14 @@ pub struct DescribeEventSourceResponse {
     pub created_by: Option<String>,
     /// <p>The date and time that the event source was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The date and time that the event source will expire if you don't create a matching event bus.</p>
     #[serde(rename = "ExpirationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<f64>,
+    pub expiration_time: Option<EpochTimestamp>,
     /// <p>The name of the partner event source.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -233,7 +630,7 @@ pub struct DescribeEventSourceResponse {
     /// <p>The state of the event source. If it's <code>ACTIVE</code>, you have already created a matching event bus for this event source, and that event bus is active. If it's <code>PENDING</code>, either you haven't yet created a matching event bus, or that event bus is deactivated. If it's <code>DELETED</code>, you have created a matching event bus, but the event source has since been deleted.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EventSourceState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -243,6 +640,38 @@ pub struct DescribePartnerEventSourceRequest {
     pub name: String,
 }
 
+impl DescribePartnerEventSourceRequest {
+    /// Returns a builder for `DescribePartnerEventSourceRequest`, checking its required members when built.
+    pub fn builder() -> DescribePartnerEventSourceRequestBuilder {
+        DescribePartnerEventSourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePartnerEventSourceRequest`](struct.DescribePartnerEventSourceRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribePartnerEventSourceRequestBuilder {
+    name: Option<String>,
+}
+
+impl DescribePartnerEventSourceRequestBuilder {
+    /// Sets the [`name`](struct.DescribePartnerEventSourceRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePartnerEventSourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePartnerEventSourceRequest, ::rusoto_core::BuildError> {
+        Ok(DescribePartnerEventSourceRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribePartnerEventSourceResponse {
@@ -267,6 +696,44 @@ pub struct DescribeRuleRequest {
     pub name: String,
 }
 
+impl DescribeRuleRequest {
+    /// Returns a builder for `DescribeRuleRequest`, checking its required members when built.
+    pub fn builder() -> DescribeRuleRequestBuilder {
+        DescribeRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRuleRequest`](struct.DescribeRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeRuleRequestBuilder {
+    event_bus_name: Option<String>,
+    name: Option<String>,
+}
+
+impl DescribeRuleRequestBuilder {
+    /// Sets the [`event_bus_name`](struct.DescribeRuleRequest.html#structfield.event_bus_name) member.
+    pub fn event_bus_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_bus_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DescribeRuleRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeRuleRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeRuleRequest {
+            event_bus_name: self.event_bus_name,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeRuleResponse {
@@ -305,7 +772,7 @@ pub struct DescribeRuleResponse {
     /// <p>Specifies whether the rule is enabled or disabled.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RuleState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -319,6 +786,44 @@ pub struct DisableRuleRequest {
     pub name: String,
 }
 
+impl DisableRuleRequest {
+    /// Returns a builder for `DisableRuleRequest`, checking its required members when built.
+    pub fn builder() -> DisableRuleRequestBuilder {
+        DisableRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisableRuleRequest`](struct.DisableRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DisableRuleRequestBuilder {
+    event_bus_name: Option<String>,
+    name: Option<String>,
+}
+
+impl DisableRuleRequestBuilder {
+    /// Sets the [`event_bus_name`](struct.DisableRuleRequest.html#structfield.event_bus_name) member.
+    pub fn event_bus_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_bus_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DisableRuleRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DisableRuleRequest, ::rusoto_core::BuildError> {
+        Ok(DisableRuleRequest {
+            event_bus_name: self.event_bus_name,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 /// <p>The custom parameters to be used when the target is an Amazon ECS task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EcsParameters {
@@ -329,7 +834,7 @@ pub struct EcsParameters {
     /// <p>Specifies the launch type on which your task is running. The launch type that you specify here must match one of the launch type (compatibilities) of the target task. The <code>FARGATE</code> value is supported only in the Regions where AWS Fargate with Amazon ECS is supported. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/AWS-Fargate.html">AWS Fargate on Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
     #[serde(rename = "LaunchType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub launch_type: Option<String>,
+    pub launch_type: Option<LaunchType>,
     /// <p>Use this structure if the ECS task uses the <code>awsvpc</code> network mode. This structure specifies the VPC subnets and security groups associated with the task and whether a public IP address is to be used. This structure is required if <code>LaunchType</code> is <code>FARGATE</code> because the <code>awsvpc</code> mode is required for Fargate tasks.</p> <p>If you specify <code>NetworkConfiguration</code> when the target ECS task doesn't use the <code>awsvpc</code> network mode, the task fails.</p>
     #[serde(rename = "NetworkConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -358,6 +863,44 @@ pub struct EnableRuleRequest {
     pub name: String,
 }
 
+impl EnableRuleRequest {
+    /// Returns a builder for `EnableRuleRequest`, checking its required members when built.
+    pub fn builder() -> EnableRuleRequestBuilder {
+        EnableRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableRuleRequest`](struct.EnableRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableRuleRequestBuilder {
+    event_bus_name: Option<String>,
+    name: Option<String>,
+}
+
+impl EnableRuleRequestBuilder {
+    /// Sets the [`event_bus_name`](struct.EnableRuleRequest.html#structfield.event_bus_name) member.
+    pub fn event_bus_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_bus_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.EnableRuleRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<EnableRuleRequest, ::rusoto_core::BuildError> {
+        Ok(EnableRuleRequest {
+            event_bus_name: self.event_bus_name,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 /// <p>An event bus receives events from a source and routes them to rules associated with that event bus. Your account's default event bus receives rules from AWS services. A custom event bus can receive rules from AWS services as well as your custom applications and services. A partner event bus receives events from an event source created by an SaaS partner. These events come from the partners services or applications.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -390,12 +933,14 @@ pub struct EventSource {
     pub created_by: Option<String>,
     /// <p>The date and time when the event source was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The date and time when the event source will expire if the AWS account doesn't create a matching event bus for it.</p>
     #[serde(rename = "ExpirationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<f64>,
+    pub expiration_time: Option<EpochTimestamp>,
     /// <p>The name of the event source.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -403,7 +948,83 @@ pub struct EventSource {
     /// <p>The state of the event source. If it's <code>ACTIVE</code>, you have already created a matching event bus for this event source, and that event bus is active. If it's <code>PENDING</code>, either you haven't yet created a matching event bus, or that event bus is deactivated. If it's <code>DELETED</code>, you have created a matching event bus, but the event source has since been deleted.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EventSourceState>,
+}
+
+/// <p>The state of the event source. If it's <code>ACTIVE</code>, you have already created a matching event bus for this event source, and that event bus is active. If it's <code>PENDING</code>, either you haven't yet created a matching event bus, or that event bus is deactivated. If it's <code>DELETED</code>, you have created a matching event bus, but the event source has since been deleted.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventSourceState {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventSourceState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventSourceState::Pending => "PENDING",
+            EventSourceState::Active => "ACTIVE",
+            EventSourceState::Deleted => "DELETED",
+            EventSourceState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventSourceState {
+    fn from(value: &'a str) -> EventSourceState {
+        match value {
+            "PENDING" => EventSourceState::Pending,
+            "ACTIVE" => EventSourceState::Active,
+            "DELETED" => EventSourceState::Deleted,
+            _ => EventSourceState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventSourceState {
+    fn from(value: String) -> EventSourceState {
+        EventSourceState::from(value.as_str())
+    }
+}
+
+impl From<EventSourceState> for String {
+    fn from(value: EventSourceState) -> String {
+        match value {
+            EventSourceState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventSourceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EventSourceState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventSourceState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EventSourceState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EventSourceState::from(value))
+    }
 }
 
 /// <p>Contains the parameters needed for you to provide custom input to a target based on one or more pieces of data extracted from the event.</p>
@@ -426,6 +1047,82 @@ pub struct KinesisParameters {
     pub partition_key_path: String,
 }
 
+/// <p>Specifies the launch type on which your task is running. The launch type that you specify here must match one of the launch type (compatibilities) of the target task. The <code>FARGATE</code> value is supported only in the Regions where AWS Fargate with Amazon ECS is supported. For more information, see <a href="https://docs.aws.amazon.com/AmazonECS/latest/developerguide/AWS-Fargate.html">AWS Fargate on Amazon ECS</a> in the <i>Amazon Elastic Container Service Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LaunchType {
+    /// <p><code>EC2</code></p>
+    Ec2,
+    /// <p><code>FARGATE</code></p>
+    Fargate,
+    /// <p><code>EXTERNAL</code></p>
+    External,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LaunchType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LaunchType::Ec2 => "EC2",
+            LaunchType::Fargate => "FARGATE",
+            LaunchType::External => "EXTERNAL",
+            LaunchType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LaunchType {
+    fn from(value: &'a str) -> LaunchType {
+        match value {
+            "EC2" => LaunchType::Ec2,
+            "FARGATE" => LaunchType::Fargate,
+            "EXTERNAL" => LaunchType::External,
+            _ => LaunchType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LaunchType {
+    fn from(value: String) -> LaunchType {
+        LaunchType::from(value.as_str())
+    }
+}
+
+impl From<LaunchType> for String {
+    fn from(value: LaunchType) -> String {
+        match value {
+            LaunchType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LaunchType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LaunchType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LaunchType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LaunchType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LaunchType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListEventBusesRequest {
     /// <p>Specifying this limits the number of results returned by this operation. The operation also returns a <code>NextToken</code> that you can use in a subsequent operation to retrieve the next set of results.</p>
@@ -442,6 +1139,50 @@ pub struct ListEventBusesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListEventBusesRequest {
+    /// Returns a builder for `ListEventBusesRequest`, checking its required members when built.
+    pub fn builder() -> ListEventBusesRequestBuilder {
+        ListEventBusesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListEventBusesRequest`](struct.ListEventBusesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListEventBusesRequestBuilder {
+    limit: Option<i64>,
+    name_prefix: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListEventBusesRequestBuilder {
+    /// Sets the [`limit`](struct.ListEventBusesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_prefix`](struct.ListEventBusesRequest.html#structfield.name_prefix) member.
+    pub fn name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListEventBusesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListEventBusesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListEventBusesRequest, ::rusoto_core::BuildError> {
+        Ok(ListEventBusesRequest {
+            limit: self.limit,
+            name_prefix: self.name_prefix,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListEventBusesResponse {
@@ -471,6 +1212,52 @@ pub struct ListEventSourcesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListEventSourcesRequest {
+    /// Returns a builder for `ListEventSourcesRequest`, checking its required members when built.
+    pub fn builder() -> ListEventSourcesRequestBuilder {
+        ListEventSourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListEventSourcesRequest`](struct.ListEventSourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListEventSourcesRequestBuilder {
+    limit: Option<i64>,
+    name_prefix: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListEventSourcesRequestBuilder {
+    /// Sets the [`limit`](struct.ListEventSourcesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_prefix`](struct.ListEventSourcesRequest.html#structfield.name_prefix) member.
+    pub fn name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListEventSourcesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListEventSourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListEventSourcesRequest, ::rusoto_core::BuildError> {
+        Ok(ListEventSourcesRequest {
+            limit: self.limit,
+            name_prefix: self.name_prefix,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListEventSourcesResponse {
@@ -499,6 +1286,55 @@ pub struct ListPartnerEventSourceAccountsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListPartnerEventSourceAccountsRequest {
+    /// Returns a builder for `ListPartnerEventSourceAccountsRequest`, checking its required members when built.
+    pub fn builder() -> ListPartnerEventSourceAccountsRequestBuilder {
+        ListPartnerEventSourceAccountsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPartnerEventSourceAccountsRequest`](struct.ListPartnerEventSourceAccountsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPartnerEventSourceAccountsRequestBuilder {
+    event_source_name: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListPartnerEventSourceAccountsRequestBuilder {
+    /// Sets the [`event_source_name`](struct.ListPartnerEventSourceAccountsRequest.html#structfield.event_source_name) member.
+    pub fn event_source_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_source_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListPartnerEventSourceAccountsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPartnerEventSourceAccountsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPartnerEventSourceAccountsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPartnerEventSourceAccountsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListPartnerEventSourceAccountsRequest {
+            event_source_name: self
+                .event_source_name
+                .ok_or(::rusoto_core::BuildError::missing("event_source_name"))?,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPartnerEventSourceAccountsResponse {
@@ -527,6 +1363,54 @@ pub struct ListPartnerEventSourcesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListPartnerEventSourcesRequest {
+    /// Returns a builder for `ListPartnerEventSourcesRequest`, checking its required members when built.
+    pub fn builder() -> ListPartnerEventSourcesRequestBuilder {
+        ListPartnerEventSourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPartnerEventSourcesRequest`](struct.ListPartnerEventSourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPartnerEventSourcesRequestBuilder {
+    limit: Option<i64>,
+    name_prefix: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListPartnerEventSourcesRequestBuilder {
+    /// Sets the [`limit`](struct.ListPartnerEventSourcesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_prefix`](struct.ListPartnerEventSourcesRequest.html#structfield.name_prefix) member.
+    pub fn name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPartnerEventSourcesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPartnerEventSourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPartnerEventSourcesRequest, ::rusoto_core::BuildError> {
+        Ok(ListPartnerEventSourcesRequest {
+            limit: self.limit,
+            name_prefix: self
+                .name_prefix
+                .ok_or(::rusoto_core::BuildError::missing("name_prefix"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPartnerEventSourcesResponse {
@@ -559,6 +1443,62 @@ pub struct ListRuleNamesByTargetRequest {
     pub target_arn: String,
 }
 
+impl ListRuleNamesByTargetRequest {
+    /// Returns a builder for `ListRuleNamesByTargetRequest`, checking its required members when built.
+    pub fn builder() -> ListRuleNamesByTargetRequestBuilder {
+        ListRuleNamesByTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRuleNamesByTargetRequest`](struct.ListRuleNamesByTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRuleNamesByTargetRequestBuilder {
+    event_bus_name: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    target_arn: Option<String>,
+}
+
+impl ListRuleNamesByTargetRequestBuilder {
+    /// Sets the [`event_bus_name`](struct.ListRuleNamesByTargetRequest.html#structfield.event_bus_name) member.
+    pub fn event_bus_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_bus_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListRuleNamesByTargetRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListRuleNamesByTargetRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_arn`](struct.ListRuleNamesByTargetRequest.html#structfield.target_arn) member.
+    pub fn target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRuleNamesByTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListRuleNamesByTargetRequest, ::rusoto_core::BuildError> {
+        Ok(ListRuleNamesByTargetRequest {
+            event_bus_name: self.event_bus_name,
+            limit: self.limit,
+            next_token: self.next_token,
+            target_arn: self
+                .target_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRuleNamesByTargetResponse {
@@ -592,6 +1532,58 @@ pub struct ListRulesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListRulesRequest {
+    /// Returns a builder for `ListRulesRequest`, checking its required members when built.
+    pub fn builder() -> ListRulesRequestBuilder {
+        ListRulesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRulesRequest`](struct.ListRulesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRulesRequestBuilder {
+    event_bus_name: Option<String>,
+    limit: Option<i64>,
+    name_prefix: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListRulesRequestBuilder {
+    /// Sets the [`event_bus_name`](struct.ListRulesRequest.html#structfield.event_bus_name) member.
+    pub fn event_bus_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_bus_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListRulesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_prefix`](struct.ListRulesRequest.html#structfield.name_prefix) member.
+    pub fn name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListRulesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRulesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListRulesRequest, ::rusoto_core::BuildError> {
+        Ok(ListRulesRequest {
+            event_bus_name: self.event_bus_name,
+            limit: self.limit,
+            name_prefix: self.name_prefix,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRulesResponse {
@@ -612,6 +1604,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -640,6 +1664,62 @@ pub struct ListTargetsByRuleRequest {
     pub rule: String,
 }
 
+impl ListTargetsByRuleRequest {
+    /// Returns a builder for `ListTargetsByRuleRequest`, checking its required members when built.
+    pub fn builder() -> ListTargetsByRuleRequestBuilder {
+        ListTargetsByRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTargetsByRuleRequest`](struct.ListTargetsByRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTargetsByRuleRequestBuilder {
+    event_bus_name: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+    rule: Option<String>,
+}
+
+impl ListTargetsByRuleRequestBuilder {
+    /// Sets the [`event_bus_name`](struct.ListTargetsByRuleRequest.html#structfield.event_bus_name) member.
+    pub fn event_bus_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_bus_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListTargetsByRuleRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTargetsByRuleRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule`](struct.ListTargetsByRuleRequest.html#structfield.rule) member.
+    pub fn rule<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTargetsByRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTargetsByRuleRequest, ::rusoto_core::BuildError> {
+        Ok(ListTargetsByRuleRequest {
+            event_bus_name: self.event_bus_name,
+            limit: self.limit,
+            next_token: self.next_token,
+            rule: self
+                .rule
+                .ok_or(::rusoto_core::BuildError::missing("rule"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTargetsByRuleResponse {
@@ -686,16 +1766,18 @@ pub struct PartnerEventSourceAccount {
     pub account: Option<String>,
     /// <p>The date and time when the event source was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The date and time when the event source will expire if the AWS account doesn't create a matching event bus for it.</p>
     #[serde(rename = "ExpirationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_time: Option<f64>,
+    pub expiration_time: Option<EpochTimestamp>,
     /// <p>The state of the event source. If it's <code>ACTIVE</code>, you have already created a matching event bus for this event source, and that event bus is active. If it's <code>PENDING</code>, either you haven't yet created a matching event bus, or that event bus is deactivated. If it's <code>DELETED</code>, you have created a matching event bus, but the event source has since been deleted.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<EventSourceState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -705,6 +1787,36 @@ pub struct PutEventsRequest {
     pub entries: Vec<PutEventsRequestEntry>,
 }
 
+impl PutEventsRequest {
+    /// Returns a builder for `PutEventsRequest`, checking its required members when built.
+    pub fn builder() -> PutEventsRequestBuilder {
+        PutEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutEventsRequest`](struct.PutEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutEventsRequestBuilder {
+    entries: Option<Vec<PutEventsRequestEntry>>,
+}
+
+impl PutEventsRequestBuilder {
+    /// Sets the [`entries`](struct.PutEventsRequest.html#structfield.entries) member.
+    pub fn entries<T: Into<Vec<PutEventsRequestEntry>>>(mut self, value: T) -> Self {
+        self.entries = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutEventsRequest, ::rusoto_core::BuildError> {
+        Ok(PutEventsRequest {
+            entries: self
+                .entries
+                .ok_or(::rusoto_core::BuildError::missing("entries"))?,
+        })
+    }
+}
+
 /// <p>Represents an event to be submitted.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutEventsRequestEntry {
@@ -730,8 +1842,9 @@ pub struct PutEventsRequestEntry {
     pub source: Option<String>,
     /// <p>The timestamp of the event, per <a href="https://www.rfc-editor.org/rfc/rfc3339.txt">RFC3339</a>. If no timestamp is provided, the timestamp of the <a>PutEvents</a> call is used.</p>
     #[serde(rename = "Time")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time: Option<f64>,
+    pub time: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -772,6 +1885,38 @@ pub struct PutPartnerEventsRequest {
     pub entries: Vec<PutPartnerEventsRequestEntry>,
 }
 
+impl PutPartnerEventsRequest {
+    /// Returns a builder for `PutPartnerEventsRequest`, checking its required members when built.
+    pub fn builder() -> PutPartnerEventsRequestBuilder {
+        PutPartnerEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutPartnerEventsRequest`](struct.PutPartnerEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutPartnerEventsRequestBuilder {
+    entries: Option<Vec<PutPartnerEventsRequestEntry>>,
+}
+
+impl PutPartnerEventsRequestBuilder {
+    /// Sets the [`entries`](struct.PutPartnerEventsRequest.html#structfield.entries) member.
+    pub fn entries<T: Into<Vec<PutPartnerEventsRequestEntry>>>(mut self, value: T) -> Self {
+        self.entries = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutPartnerEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutPartnerEventsRequest, ::rusoto_core::BuildError> {
+        Ok(PutPartnerEventsRequest {
+            entries: self
+                .entries
+                .ok_or(::rusoto_core::BuildError::missing("entries"))?,
+        })
+    }
+}
+
 /// <p>The details about an event generated by an SaaS partner.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutPartnerEventsRequestEntry {
@@ -793,8 +1938,9 @@ pub struct PutPartnerEventsRequestEntry {
     pub source: Option<String>,
     /// <p>The date and time of the event.</p>
     #[serde(rename = "Time")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time: Option<f64>,
+    pub time: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -849,6 +1995,72 @@ pub struct PutPermissionRequest {
     pub statement_id: String,
 }
 
+impl PutPermissionRequest {
+    /// Returns a builder for `PutPermissionRequest`, checking its required members when built.
+    pub fn builder() -> PutPermissionRequestBuilder {
+        PutPermissionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutPermissionRequest`](struct.PutPermissionRequest.html).
+#[derive(Default, Debug)]
+pub struct PutPermissionRequestBuilder {
+    action: Option<String>,
+    condition: Option<Condition>,
+    event_bus_name: Option<String>,
+    principal: Option<String>,
+    statement_id: Option<String>,
+}
+
+impl PutPermissionRequestBuilder {
+    /// Sets the [`action`](struct.PutPermissionRequest.html#structfield.action) member.
+    pub fn action<T: Into<String>>(mut self, value: T) -> Self {
+        self.action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`condition`](struct.PutPermissionRequest.html#structfield.condition) member.
+    pub fn condition<T: Into<Condition>>(mut self, value: T) -> Self {
+        self.condition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_bus_name`](struct.PutPermissionRequest.html#structfield.event_bus_name) member.
+    pub fn event_bus_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_bus_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.PutPermissionRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statement_id`](struct.PutPermissionRequest.html#structfield.statement_id) member.
+    pub fn statement_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.statement_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutPermissionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutPermissionRequest, ::rusoto_core::BuildError> {
+        Ok(PutPermissionRequest {
+            action: self
+                .action
+                .ok_or(::rusoto_core::BuildError::missing("action"))?,
+            condition: self.condition,
+            event_bus_name: self.event_bus_name,
+            principal: self
+                .principal
+                .ok_or(::rusoto_core::BuildError::missing("principal"))?,
+            statement_id: self
+                .statement_id
+                .ok_or(::rusoto_core::BuildError::missing("statement_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutRuleRequest {
     /// <p>A description of the rule.</p>
@@ -877,13 +2089,99 @@ pub struct PutRuleRequest {
     /// <p>Indicates whether the rule is enabled or disabled.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RuleState>,
     /// <p>The list of key-value pairs to associate with the rule.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<Tag>>,
 }
 
+impl PutRuleRequest {
+    /// Returns a builder for `PutRuleRequest`, checking its required members when built.
+    pub fn builder() -> PutRuleRequestBuilder {
+        PutRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutRuleRequest`](struct.PutRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct PutRuleRequestBuilder {
+    description: Option<String>,
+    event_bus_name: Option<String>,
+    event_pattern: Option<String>,
+    name: Option<String>,
+    role_arn: Option<String>,
+    schedule_expression: Option<String>,
+    state: Option<RuleState>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl PutRuleRequestBuilder {
+    /// Sets the [`description`](struct.PutRuleRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_bus_name`](struct.PutRuleRequest.html#structfield.event_bus_name) member.
+    pub fn event_bus_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_bus_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_pattern`](struct.PutRuleRequest.html#structfield.event_pattern) member.
+    pub fn event_pattern<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_pattern = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.PutRuleRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.PutRuleRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule_expression`](struct.PutRuleRequest.html#structfield.schedule_expression) member.
+    pub fn schedule_expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule_expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state`](struct.PutRuleRequest.html#structfield.state) member.
+    pub fn state<T: Into<RuleState>>(mut self, value: T) -> Self {
+        self.state = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.PutRuleRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutRuleRequest, ::rusoto_core::BuildError> {
+        Ok(PutRuleRequest {
+            description: self.description,
+            event_bus_name: self.event_bus_name,
+            event_pattern: self.event_pattern,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            role_arn: self.role_arn,
+            schedule_expression: self.schedule_expression,
+            state: self.state,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutRuleResponse {
@@ -907,6 +2205,54 @@ pub struct PutTargetsRequest {
     pub targets: Vec<Target>,
 }
 
+impl PutTargetsRequest {
+    /// Returns a builder for `PutTargetsRequest`, checking its required members when built.
+    pub fn builder() -> PutTargetsRequestBuilder {
+        PutTargetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutTargetsRequest`](struct.PutTargetsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutTargetsRequestBuilder {
+    event_bus_name: Option<String>,
+    rule: Option<String>,
+    targets: Option<Vec<Target>>,
+}
+
+impl PutTargetsRequestBuilder {
+    /// Sets the [`event_bus_name`](struct.PutTargetsRequest.html#structfield.event_bus_name) member.
+    pub fn event_bus_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_bus_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule`](struct.PutTargetsRequest.html#structfield.rule) member.
+    pub fn rule<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule = Some(value.into());
+        self
+    }
+
+    /// Sets the [`targets`](struct.PutTargetsRequest.html#structfield.targets) member.
+    pub fn targets<T: Into<Vec<Target>>>(mut self, value: T) -> Self {
+        self.targets = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutTargetsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutTargetsRequest, ::rusoto_core::BuildError> {
+        Ok(PutTargetsRequest {
+            event_bus_name: self.event_bus_name,
+            rule: self
+                .rule
+                .ok_or(::rusoto_core::BuildError::missing("rule"))?,
+            targets: self
+                .targets
+                .ok_or(::rusoto_core::BuildError::missing("targets"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutTargetsResponse {
@@ -949,6 +2295,46 @@ pub struct RemovePermissionRequest {
     pub statement_id: String,
 }
 
+impl RemovePermissionRequest {
+    /// Returns a builder for `RemovePermissionRequest`, checking its required members when built.
+    pub fn builder() -> RemovePermissionRequestBuilder {
+        RemovePermissionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemovePermissionRequest`](struct.RemovePermissionRequest.html).
+#[derive(Default, Debug)]
+pub struct RemovePermissionRequestBuilder {
+    event_bus_name: Option<String>,
+    statement_id: Option<String>,
+}
+
+impl RemovePermissionRequestBuilder {
+    /// Sets the [`event_bus_name`](struct.RemovePermissionRequest.html#structfield.event_bus_name) member.
+    pub fn event_bus_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_bus_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statement_id`](struct.RemovePermissionRequest.html#structfield.statement_id) member.
+    pub fn statement_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.statement_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemovePermissionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemovePermissionRequest, ::rusoto_core::BuildError> {
+        Ok(RemovePermissionRequest {
+            event_bus_name: self.event_bus_name,
+            statement_id: self
+                .statement_id
+                .ok_or(::rusoto_core::BuildError::missing("statement_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RemoveTargetsRequest {
     /// <p>The name of the event bus associated with the rule.</p>
@@ -967,6 +2353,60 @@ pub struct RemoveTargetsRequest {
     pub rule: String,
 }
 
+impl RemoveTargetsRequest {
+    /// Returns a builder for `RemoveTargetsRequest`, checking its required members when built.
+    pub fn builder() -> RemoveTargetsRequestBuilder {
+        RemoveTargetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTargetsRequest`](struct.RemoveTargetsRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveTargetsRequestBuilder {
+    event_bus_name: Option<String>,
+    force: Option<bool>,
+    ids: Option<Vec<String>>,
+    rule: Option<String>,
+}
+
+impl RemoveTargetsRequestBuilder {
+    /// Sets the [`event_bus_name`](struct.RemoveTargetsRequest.html#structfield.event_bus_name) member.
+    pub fn event_bus_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_bus_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force`](struct.RemoveTargetsRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ids`](struct.RemoveTargetsRequest.html#structfield.ids) member.
+    pub fn ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule`](struct.RemoveTargetsRequest.html#structfield.rule) member.
+    pub fn rule<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTargetsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RemoveTargetsRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveTargetsRequest {
+            event_bus_name: self.event_bus_name,
+            force: self.force,
+            ids: self.ids.ok_or(::rusoto_core::BuildError::missing("ids"))?,
+            rule: self
+                .rule
+                .ok_or(::rusoto_core::BuildError::missing("rule"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RemoveTargetsResponse {
@@ -1037,7 +2477,87 @@ pub struct Rule {
     /// <p>The state of the rule.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RuleState>,
+}
+
+/// <p>Specifies whether the rule is enabled or disabled.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RuleState {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// <p><code>ENABLED_WITH_ALL_CLOUDTRAIL_MANAGEMENT_EVENTS</code></p>
+    EnabledWithAllCloudtrailManagementEvents,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RuleState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RuleState::Enabled => "ENABLED",
+            RuleState::Disabled => "DISABLED",
+            RuleState::EnabledWithAllCloudtrailManagementEvents => {
+                "ENABLED_WITH_ALL_CLOUDTRAIL_MANAGEMENT_EVENTS"
+            }
+            RuleState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RuleState {
+    fn from(value: &'a str) -> RuleState {
+        match value {
+            "ENABLED" => RuleState::Enabled,
+            "DISABLED" => RuleState::Disabled,
+            "ENABLED_WITH_ALL_CLOUDTRAIL_MANAGEMENT_EVENTS" => {
+                RuleState::EnabledWithAllCloudtrailManagementEvents
+            }
+            _ => RuleState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RuleState {
+    fn from(value: String) -> RuleState {
+        RuleState::from(value.as_str())
+    }
+}
+
+impl From<RuleState> for String {
+    fn from(value: RuleState) -> String {
+        match value {
+            RuleState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RuleState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RuleState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RuleState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RuleState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RuleState::from(value))
+    }
 }
 
 /// <p>This parameter contains the criteria (either <code>InstanceIds</code> or a tag) used to specify which EC2 instances are to be sent the command. </p>
@@ -1089,6 +2609,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -1150,6 +2710,48 @@ pub struct TestEventPatternRequest {
     pub event_pattern: String,
 }
 
+impl TestEventPatternRequest {
+    /// Returns a builder for `TestEventPatternRequest`, checking its required members when built.
+    pub fn builder() -> TestEventPatternRequestBuilder {
+        TestEventPatternRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TestEventPatternRequest`](struct.TestEventPatternRequest.html).
+#[derive(Default, Debug)]
+pub struct TestEventPatternRequestBuilder {
+    event: Option<String>,
+    event_pattern: Option<String>,
+}
+
+impl TestEventPatternRequestBuilder {
+    /// Sets the [`event`](struct.TestEventPatternRequest.html#structfield.event) member.
+    pub fn event<T: Into<String>>(mut self, value: T) -> Self {
+        self.event = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_pattern`](struct.TestEventPatternRequest.html#structfield.event_pattern) member.
+    pub fn event_pattern<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_pattern = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestEventPatternRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TestEventPatternRequest, ::rusoto_core::BuildError> {
+        Ok(TestEventPatternRequest {
+            event: self
+                .event
+                .ok_or(::rusoto_core::BuildError::missing("event"))?,
+            event_pattern: self
+                .event_pattern
+                .ok_or(::rusoto_core::BuildError::missing("event_pattern"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TestEventPatternResponse {
@@ -1169,6 +2771,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -2733,6 +4375,187 @@ pub trait EventBridge {
         input: UntagResourceRequest,
     ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
 }
+impl<T: ?Sized + EventBridge> EventBridge for ::std::sync::Arc<T> {
+    fn activate_event_source(
+        &self,
+        input: ActivateEventSourceRequest,
+    ) -> RusotoFuture<(), ActivateEventSourceError> {
+        (**self).activate_event_source(input)
+    }
+    fn create_event_bus(
+        &self,
+        input: CreateEventBusRequest,
+    ) -> RusotoFuture<CreateEventBusResponse, CreateEventBusError> {
+        (**self).create_event_bus(input)
+    }
+    fn create_partner_event_source(
+        &self,
+        input: CreatePartnerEventSourceRequest,
+    ) -> RusotoFuture<CreatePartnerEventSourceResponse, CreatePartnerEventSourceError> {
+        (**self).create_partner_event_source(input)
+    }
+    fn deactivate_event_source(
+        &self,
+        input: DeactivateEventSourceRequest,
+    ) -> RusotoFuture<(), DeactivateEventSourceError> {
+        (**self).deactivate_event_source(input)
+    }
+    fn delete_event_bus(
+        &self,
+        input: DeleteEventBusRequest,
+    ) -> RusotoFuture<(), DeleteEventBusError> {
+        (**self).delete_event_bus(input)
+    }
+    fn delete_partner_event_source(
+        &self,
+        input: DeletePartnerEventSourceRequest,
+    ) -> RusotoFuture<(), DeletePartnerEventSourceError> {
+        (**self).delete_partner_event_source(input)
+    }
+    fn delete_rule(&self, input: DeleteRuleRequest) -> RusotoFuture<(), DeleteRuleError> {
+        (**self).delete_rule(input)
+    }
+    fn describe_event_bus(
+        &self,
+        input: DescribeEventBusRequest,
+    ) -> RusotoFuture<DescribeEventBusResponse, DescribeEventBusError> {
+        (**self).describe_event_bus(input)
+    }
+    fn describe_event_source(
+        &self,
+        input: DescribeEventSourceRequest,
+    ) -> RusotoFuture<DescribeEventSourceResponse, DescribeEventSourceError> {
+        (**self).describe_event_source(input)
+    }
+    fn describe_partner_event_source(
+        &self,
+        input: DescribePartnerEventSourceRequest,
+    ) -> RusotoFuture<DescribePartnerEventSourceResponse, DescribePartnerEventSourceError> {
+        (**self).describe_partner_event_source(input)
+    }
+    fn describe_rule(
+        &self,
+        input: DescribeRuleRequest,
+    ) -> RusotoFuture<DescribeRuleResponse, DescribeRuleError> {
+        (**self).describe_rule(input)
+    }
+    fn disable_rule(&self, input: DisableRuleRequest) -> RusotoFuture<(), DisableRuleError> {
+        (**self).disable_rule(input)
+    }
+    fn enable_rule(&self, input: EnableRuleRequest) -> RusotoFuture<(), EnableRuleError> {
+        (**self).enable_rule(input)
+    }
+    fn list_event_buses(
+        &self,
+        input: ListEventBusesRequest,
+    ) -> RusotoFuture<ListEventBusesResponse, ListEventBusesError> {
+        (**self).list_event_buses(input)
+    }
+    fn list_event_sources(
+        &self,
+        input: ListEventSourcesRequest,
+    ) -> RusotoFuture<ListEventSourcesResponse, ListEventSourcesError> {
+        (**self).list_event_sources(input)
+    }
+    fn list_partner_event_source_accounts(
+        &self,
+        input: ListPartnerEventSourceAccountsRequest,
+    ) -> RusotoFuture<ListPartnerEventSourceAccountsResponse, ListPartnerEventSourceAccountsError>
+    {
+        (**self).list_partner_event_source_accounts(input)
+    }
+    fn list_partner_event_sources(
+        &self,
+        input: ListPartnerEventSourcesRequest,
+    ) -> RusotoFuture<ListPartnerEventSourcesResponse, ListPartnerEventSourcesError> {
+        (**self).list_partner_event_sources(input)
+    }
+    fn list_rule_names_by_target(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> RusotoFuture<ListRuleNamesByTargetResponse, ListRuleNamesByTargetError> {
+        (**self).list_rule_names_by_target(input)
+    }
+    fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> RusotoFuture<ListRulesResponse, ListRulesError> {
+        (**self).list_rules(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_targets_by_rule(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> RusotoFuture<ListTargetsByRuleResponse, ListTargetsByRuleError> {
+        (**self).list_targets_by_rule(input)
+    }
+    fn put_events(
+        &self,
+        input: PutEventsRequest,
+    ) -> RusotoFuture<PutEventsResponse, PutEventsError> {
+        (**self).put_events(input)
+    }
+    fn put_partner_events(
+        &self,
+        input: PutPartnerEventsRequest,
+    ) -> RusotoFuture<PutPartnerEventsResponse, PutPartnerEventsError> {
+        (**self).put_partner_events(input)
+    }
+    fn put_permission(&self, input: PutPermissionRequest) -> RusotoFuture<(), PutPermissionError> {
+        (**self).put_permission(input)
+    }
+    fn put_rule(&self, input: PutRuleRequest) -> RusotoFuture<PutRuleResponse, PutRuleError> {
+        (**self).put_rule(input)
+    }
+    fn put_targets(
+        &self,
+        input: PutTargetsRequest,
+    ) -> RusotoFuture<PutTargetsResponse, PutTargetsError> {
+        (**self).put_targets(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn remove_targets(
+        &self,
+        input: RemoveTargetsRequest,
+    ) -> RusotoFuture<RemoveTargetsResponse, RemoveTargetsError> {
+        (**self).remove_targets(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn test_event_pattern(
+        &self,
+        input: TestEventPatternRequest,
+    ) -> RusotoFuture<TestEventPatternResponse, TestEventPatternError> {
+        (**self).test_event_pattern(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// The error codes Amazon EventBridge retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon EventBridge API.
 #[derive(Clone)]
 pub struct EventBridgeClient {
@@ -2748,6 +4571,27 @@ impl EventBridgeClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_EVENTS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> EventBridgeClient {
+        Self::new(region::Region::from_env_for_service("EVENTS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `events`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> EventBridgeClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("events", "EVENTS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2766,7 +4610,49 @@ impl EventBridgeClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EventBridgeClient {
-        EventBridgeClient { client, region }
+        EventBridgeClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "events")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "events", "events", "EVENTS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `EventBridge` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn EventBridge + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for EventBridgeClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon EventBridge"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "events"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> EventBridgeClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Kinesis Firehose clients.
+//!
+//! Makes a single read-only `ListDeliveryStreams` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_firehose::{KinesisFirehose, KinesisFirehoseClient, ListDeliveryStreamsInput};
+
+fn main() {
+    let client = KinesisFirehoseClient::from_env();
+    match client
+        .list_delivery_streams(ListDeliveryStreamsInput::default())
+        .sync()
+    {
+        Ok(_) => println!("ListDeliveryStreams succeeded"),
+        Err(error) => {
+            eprintln!("ListDeliveryStreams failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
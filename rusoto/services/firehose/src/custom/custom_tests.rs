@@ -0,0 +1,93 @@
+extern crate rusoto_mock;
+
+use std::sync::{Arc, Mutex};
+
+use crate::custom::producer::{
+    firehose_producer, FirehoseProducerConfig, FirehoseProducerError, FirehoseProducerSummary,
+};
+use crate::generated::KinesisFirehoseClient;
+
+use self::rusoto_mock::*;
+use futures::Future;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::{Region, RusotoError};
+use serde_json::Value;
+use std::time::Duration;
+
+#[test]
+fn should_put_aggregated_records() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = requests.clone();
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(r#"{"FailedPutCount": 0, "RequestResponses": [{"RecordId": "1"}]}"#)
+        .with_request_checker(move |request: &SignedRequest| {
+            let body: Value = match request.payload {
+                Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                    serde_json::from_slice(buffer).unwrap()
+                }
+                _ => panic!("expected a buffered payload"),
+            };
+            recorded.lock().unwrap().push(body);
+        });
+    let client = KinesisFirehoseClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let mut config = FirehoseProducerConfig::new("clickstream");
+    config.aggregation_delimiter = Some("\n".into());
+    let (producer, sender) = firehose_producer(client, config);
+
+    let producer = producer
+        .send("a")
+        .and_then(|producer| producer.send("b"))
+        .wait()
+        .unwrap();
+    drop(producer);
+
+    let summary = sender.sync().unwrap();
+    assert_eq!(
+        summary,
+        FirehoseProducerSummary {
+            batches: 1,
+            records: 2,
+            firehose_records: 1,
+            retried_records: 0,
+        }
+    );
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0]["DeliveryStreamName"], "clickstream");
+    // "a\nb\n", base64 encoded
+    assert_eq!(requests[0]["Records"][0]["Data"], "YQpiCg==");
+}
+
+#[test]
+fn should_fail_when_records_keep_failing() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(
+        r#"{
+            "FailedPutCount": 2,
+            "RequestResponses": [
+                {"ErrorCode": "ServiceUnavailableException", "ErrorMessage": "Slow down."},
+                {"ErrorCode": "ServiceUnavailableException", "ErrorMessage": "Slow down."}
+            ]
+        }"#,
+    );
+    let client = KinesisFirehoseClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let mut config = FirehoseProducerConfig::new("clickstream");
+    config.max_attempts = 2;
+    config.base_delay = Duration::from_millis(1);
+    let (producer, sender) = firehose_producer(client, config);
+
+    let producer = producer
+        .send("a")
+        .and_then(|producer| producer.send("b"))
+        .wait()
+        .unwrap();
+    drop(producer);
+
+    match sender.sync() {
+        Err(RusotoError::Service(FirehoseProducerError::FailedRecords(message))) => {
+            assert!(message.contains("ServiceUnavailableException"), "{}", message)
+        }
+        other => panic!("expected failed records, got {:?}", other),
+    }
+}
@@ -1 +1,5 @@
+/// Batching records for `PutRecordBatch` and resending the ones it fails to put
+pub mod producer;
 
+#[cfg(test)]
+mod custom_tests;
@@ -0,0 +1,492 @@
+//! Producing records to a delivery stream.
+//!
+//! `PutRecordBatch` accepts at most 500 records and 4 MiB per call, and may accept only some of
+//! them, reporting the others as failed in its response. `firehose_producer` returns a
+//! `FirehoseProducer` handle and a future which batches the records sent through it, resends the
+//! records a call failed to put and retries throttled calls. The handle's `send` waits while the
+//! producer's buffer is full, so a fast source slows down to the pace of the delivery stream.
+//!
+//! Firehose bills every record by rounding its size up to the next 5 KB. Small records, such as
+//! lines of JSON, can be aggregated into fewer, larger Firehose records by setting a delimiter
+//! with `FirehoseProducerConfig::aggregation_delimiter`.
+
+use std::error::Error;
+use std::fmt;
+use std::mem;
+use std::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+use futures::future::{self, Loop};
+use futures::sink;
+use futures::sync::mpsc;
+use futures::{Async, Future, Poll, Sink, Stream};
+use rusoto_core::batch::{backoff, batched, is_transient, Batcher};
+use rusoto_core::waiter::sleep;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    KinesisFirehose, PutRecordBatchError, PutRecordBatchInput, PutRecordBatchResponseEntry, Record,
+};
+
+/// The maximum number of records in a single `PutRecordBatch` call.
+pub const MAX_BATCH_RECORDS: usize = 500;
+
+/// The maximum size of the records of a single `PutRecordBatch` call.
+pub const MAX_BATCH_BYTES: usize = 4 * 1024 * 1024;
+
+/// The maximum size of a single record, before base64 encoding.
+pub const MAX_RECORD_BYTES: usize = 1000 * 1024;
+
+/// Controls how `firehose_producer` batches and sends records.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FirehoseProducerConfig {
+    /// The delivery stream the records are sent to
+    pub delivery_stream_name: String,
+    /// Maximum number of Firehose records per batch, at most `MAX_BATCH_RECORDS`
+    pub max_batch_records: usize,
+    /// Maximum size of a batch, at most `MAX_BATCH_BYTES`
+    pub max_batch_bytes: usize,
+    /// Pack records into Firehose records of up to `MAX_RECORD_BYTES`, each record followed by
+    /// this delimiter, such as a newline for JSON lines
+    pub aggregation_delimiter: Option<Bytes>,
+    /// How long a record waits for a batch to fill up before the batch is sent anyway
+    pub flush_interval: Duration,
+    /// Number of records the producer buffers while a batch is being sent
+    pub channel_capacity: usize,
+    /// Number of attempts made for each record before producing fails
+    pub max_attempts: u32,
+    /// Delay before the first retry of a throttled call or failed records, doubled for every
+    /// subsequent retry
+    pub base_delay: Duration,
+}
+
+impl FirehoseProducerConfig {
+    /// Creates a configuration sending records to the given delivery stream, with the default
+    /// limits and no aggregation.
+    pub fn new<S>(delivery_stream_name: S) -> FirehoseProducerConfig
+    where
+        S: Into<String>,
+    {
+        FirehoseProducerConfig {
+            delivery_stream_name: delivery_stream_name.into(),
+            max_batch_records: MAX_BATCH_RECORDS,
+            max_batch_bytes: MAX_BATCH_BYTES,
+            aggregation_delimiter: None,
+            flush_interval: Duration::from_secs(1),
+            channel_capacity: 1024,
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+        }
+    }
+}
+
+/// Statistics of a finished `firehose_producer` future.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FirehoseProducerSummary {
+    /// Number of `PutRecordBatch` calls which succeeded
+    pub batches: usize,
+    /// Number of records sent through the producer
+    pub records: usize,
+    /// Number of Firehose records put, which is lower than `records` with aggregation
+    pub firehose_records: usize,
+    /// Number of Firehose records which a call failed to put and were sent again
+    pub retried_records: usize,
+}
+
+/// Errors returned by the `firehose_producer` future.
+#[derive(Debug, PartialEq)]
+pub enum FirehoseProducerError {
+    /// Sending a batch failed
+    PutRecordBatch(PutRecordBatchError),
+    /// Some records still failed to be put after the configured number of attempts
+    FailedRecords(String),
+}
+
+impl fmt::Display for FirehoseProducerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for FirehoseProducerError {
+    fn description(&self) -> &str {
+        match *self {
+            FirehoseProducerError::PutRecordBatch(ref cause) => cause.description(),
+            FirehoseProducerError::FailedRecords(ref cause) => cause,
+        }
+    }
+}
+
+/// A handle sending records to a `firehose_producer` future.
+///
+/// Clones send to the same producer.
+#[derive(Clone, Debug)]
+pub struct FirehoseProducer {
+    sender: mpsc::Sender<Bytes>,
+}
+
+impl FirehoseProducer {
+    /// Sends a record to the producer, resolving to the handle once the producer has room for
+    /// it.
+    ///
+    /// Fails, returning the record, once the producer's future has failed or was dropped.
+    pub fn send<D>(self, data: D) -> ProducerSend
+    where
+        D: Into<Bytes>,
+    {
+        ProducerSend {
+            inner: self.sender.send(data.into()),
+        }
+    }
+}
+
+/// Future returned by `FirehoseProducer::send`.
+#[derive(Debug)]
+pub struct ProducerSend {
+    inner: sink::Send<mpsc::Sender<Bytes>>,
+}
+
+impl Future for ProducerSend {
+    type Item = FirehoseProducer;
+    type Error = Bytes;
+
+    fn poll(&mut self) -> Poll<FirehoseProducer, Bytes> {
+        match self.inner.poll() {
+            Ok(Async::Ready(sender)) => Ok(Async::Ready(FirehoseProducer { sender })),
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+}
+
+/// Returns a producer accepting records, and a future sending them to the delivery stream named
+/// in `config`.
+///
+/// The future must be run for records to be sent. It resolves once every handle of the producer
+/// is dropped and all records sent until then are put, and fails if a batch can't be sent or
+/// some of its records can't be put.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::Future;
+/// use rusoto_core::Region;
+/// use rusoto_firehose::producer::{firehose_producer, FirehoseProducerConfig};
+/// use rusoto_firehose::KinesisFirehoseClient;
+///
+/// let client = KinesisFirehoseClient::new(Region::UsEast1);
+/// let mut config = FirehoseProducerConfig::new("clickstream");
+/// config.aggregation_delimiter = Some("\n".into());
+/// let (producer, sender) = firehose_producer(client, config);
+///
+/// // the producer buffers records until the future runs
+/// let producer = producer
+///     .send(r#"{"page": "/", "user": 42}"#)
+///     .wait()
+///     .expect("producer stopped");
+///
+/// // dropping the last handle lets the future finish once all records are put
+/// drop(producer);
+/// let summary = sender.sync().expect("failed to produce records");
+/// println!("put {} records", summary.records);
+/// ```
+pub fn firehose_producer<C>(
+    client: C,
+    config: FirehoseProducerConfig,
+) -> (
+    FirehoseProducer,
+    RusotoFuture<FirehoseProducerSummary, FirehoseProducerError>,
+)
+where
+    C: KinesisFirehose + Clone + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel(config.channel_capacity);
+    let batches = batched::<_, _, RusotoError<FirehoseProducerError>>(
+        receiver,
+        RecordBatcher::new(&config),
+        config.flush_interval,
+    );
+
+    let future = batches.fold(
+        FirehoseProducerSummary::default(),
+        move |mut summary, (batch, records)| {
+            let firehose_records = batch.len();
+            put_batch(client.clone(), config.clone(), batch).map(move |retried| {
+                summary.batches += 1;
+                summary.records += records;
+                summary.firehose_records += firehose_records;
+                summary.retried_records += retried;
+                summary
+            })
+        },
+    );
+
+    (
+        FirehoseProducer { sender },
+        RusotoFuture::from_future(future),
+    )
+}
+
+/// Groups records into batches within the limits of `PutRecordBatch`, batched with the number
+/// of records aggregated into them.
+struct RecordBatcher {
+    pending: Vec<BytesMut>,
+    pending_bytes: usize,
+    pending_records: usize,
+    max_records: usize,
+    max_bytes: usize,
+    delimiter: Option<Bytes>,
+}
+
+impl RecordBatcher {
+    fn new(config: &FirehoseProducerConfig) -> RecordBatcher {
+        RecordBatcher {
+            pending: Vec::new(),
+            pending_bytes: 0,
+            pending_records: 0,
+            max_records: config.max_batch_records.min(MAX_BATCH_RECORDS).max(1),
+            max_bytes: config.max_batch_bytes.min(MAX_BATCH_BYTES),
+            delimiter: config.aggregation_delimiter.clone(),
+        }
+    }
+
+    fn take_batch(&mut self) -> (Vec<Record>, usize) {
+        self.pending_bytes = 0;
+        let batch = mem::replace(&mut self.pending, Vec::new())
+            .into_iter()
+            .map(|data| Record {
+                data: data.freeze(),
+            })
+            .collect();
+        (batch, mem::replace(&mut self.pending_records, 0))
+    }
+}
+
+impl Batcher for RecordBatcher {
+    type Item = Bytes;
+    type Batch = (Vec<Record>, usize);
+
+    fn push(&mut self, data: Bytes) -> Option<(Vec<Record>, usize)> {
+        let mut record = BytesMut::from(data);
+        if let Some(ref delimiter) = self.delimiter {
+            let size = record.len() + delimiter.len();
+            if let Some(last) = self.pending.last_mut() {
+                if last.len() + size <= MAX_RECORD_BYTES
+                    && self.pending_bytes + size <= self.max_bytes
+                {
+                    last.extend_from_slice(&record);
+                    last.extend_from_slice(delimiter);
+                    self.pending_bytes += size;
+                    self.pending_records += 1;
+                    return None;
+                }
+            }
+            record.extend_from_slice(delimiter);
+        }
+
+        let size = record.len();
+        let full = !self.pending.is_empty()
+            && (self.pending.len() >= self.max_records
+                || self.pending_bytes + size > self.max_bytes);
+        let batch = if full { Some(self.take_batch()) } else { None };
+        self.pending_bytes += size;
+        self.pending_records += 1;
+        self.pending.push(record);
+        batch
+    }
+
+    fn flush(&mut self) -> Option<(Vec<Record>, usize)> {
+        if self.pending.is_empty() {
+            None
+        } else {
+            Some(self.take_batch())
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+type PutAttempt = Box<
+    dyn Future<Item = Loop<usize, PutState>, Error = RusotoError<FirehoseProducerError>> + Send,
+>;
+
+struct PutState {
+    records: Vec<Record>,
+    attempts: u32,
+    retried: usize,
+}
+
+/// Sends a batch, resending the records a call failed to put, and returns the number of
+/// records which were resent.
+fn put_batch<C>(
+    client: C,
+    config: FirehoseProducerConfig,
+    batch: Vec<Record>,
+) -> impl Future<Item = usize, Error = RusotoError<FirehoseProducerError>> + Send
+where
+    C: KinesisFirehose + Clone + Send + 'static,
+{
+    let initial = PutState {
+        records: batch,
+        attempts: 0,
+        retried: 0,
+    };
+
+    future::loop_fn(initial, move |mut state| {
+        state.attempts += 1;
+        let request = PutRecordBatchInput {
+            delivery_stream_name: config.delivery_stream_name.clone(),
+            records: state.records.clone(),
+        };
+        let last_attempt = state.attempts >= config.max_attempts;
+        let base_delay = config.base_delay;
+
+        client.put_record_batch(request).then(move |result| {
+            let next: PutAttempt = match result {
+                Ok(ref output) if output.failed_put_count == 0 => {
+                    Box::new(future::ok(Loop::Break(state.retried)))
+                }
+                Ok(output) => {
+                    let records = mem::replace(&mut state.records, Vec::new());
+                    let failed = failed_records(records, &output.request_responses);
+                    if failed.is_empty() {
+                        Box::new(future::ok(Loop::Break(state.retried)))
+                    } else if last_attempt {
+                        Box::new(future::err(RusotoError::Service(
+                            FirehoseProducerError::FailedRecords(failure_message(
+                                &failed,
+                                state.attempts,
+                            )),
+                        )))
+                    } else {
+                        state.retried += failed.len();
+                        state.records = failed.into_iter().map(|(record, _)| record).collect();
+                        let delay = backoff(base_delay, state.attempts);
+                        Box::new(sleep(delay).map(|_| Loop::Continue(state)))
+                    }
+                }
+                Err(ref err) if is_throttled(err) && !last_attempt => {
+                    let delay = backoff(base_delay, state.attempts);
+                    Box::new(sleep(delay).map(|_| Loop::Continue(state)))
+                }
+                Err(err) => Box::new(future::err(
+                    err.map_service(FirehoseProducerError::PutRecordBatch),
+                )),
+            };
+            next
+        })
+    })
+}
+
+/// Pairs the records a call failed to put with their response entries, which are in the order
+/// of the records.
+fn failed_records(
+    records: Vec<Record>,
+    responses: &[PutRecordBatchResponseEntry],
+) -> Vec<(Record, PutRecordBatchResponseEntry)> {
+    records
+        .into_iter()
+        .zip(responses.iter())
+        .filter(|&(_, response)| response.error_code.is_some())
+        .map(|(record, response)| (record, response.clone()))
+        .collect()
+}
+
+fn failure_message(failed: &[(Record, PutRecordBatchResponseEntry)], attempts: u32) -> String {
+    let (code, message) = failed
+        .first()
+        .map(|&(_, ref response)| {
+            (
+                response.error_code.clone().unwrap_or_default(),
+                response.error_message.clone().unwrap_or_default(),
+            )
+        })
+        .unwrap_or_default();
+    format!(
+        "{} records failed to be put after {} attempts, the first with {}: {}",
+        failed.len(),
+        attempts,
+        code,
+        message
+    )
+}
+
+fn is_throttled(err: &RusotoError<PutRecordBatchError>) -> bool {
+    match *err {
+        RusotoError::Service(PutRecordBatchError::ServiceUnavailable(_)) => true,
+        ref err => is_transient(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(batch: &[Record]) -> Vec<&[u8]> {
+        batch.iter().map(|record| &record.data[..]).collect()
+    }
+
+    #[test]
+    fn splits_batches_by_count_and_size() {
+        let mut config = FirehoseProducerConfig::new("stream");
+        config.max_batch_records = 2;
+        config.max_batch_bytes = 10;
+        let mut batcher = RecordBatcher::new(&config);
+
+        assert!(batcher.push(Bytes::from("a")).is_none());
+        assert!(batcher.push(Bytes::from("b")).is_none());
+        let (batch, records) = batcher.push(Bytes::from("c")).unwrap();
+        assert_eq!(data(&batch), vec![&b"a"[..], &b"b"[..]]);
+        assert_eq!(records, 2);
+
+        let (batch, _) = batcher.push(Bytes::from("0123456789")).unwrap();
+        assert_eq!(data(&batch), vec![&b"c"[..]]);
+    }
+
+    #[test]
+    fn aggregates_records_with_a_delimiter() {
+        let mut config = FirehoseProducerConfig::new("stream");
+        config.aggregation_delimiter = Some(Bytes::from("\n"));
+        config.max_batch_bytes = 8;
+        let mut batcher = RecordBatcher::new(&config);
+
+        assert!(batcher.push(Bytes::from("{}")).is_none());
+        assert!(batcher.push(Bytes::from("[]")).is_none());
+        let (batch, records) = batcher.push(Bytes::from("{1}")).unwrap();
+        assert_eq!(data(&batch), vec![&b"{}\n[]\n"[..]]);
+        assert_eq!(records, 2);
+
+        let (batch, records) = batcher.flush().unwrap();
+        assert_eq!(data(&batch), vec![&b"{1}\n"[..]]);
+        assert_eq!(records, 1);
+    }
+
+    #[test]
+    fn picks_failed_records() {
+        let records = vec!["a", "b", "c"]
+            .into_iter()
+            .map(|data| Record { data: data.into() })
+            .collect();
+        let failure = PutRecordBatchResponseEntry {
+            error_code: Some("ServiceUnavailableException".to_owned()),
+            error_message: Some("Slow down.".to_owned()),
+            record_id: None,
+        };
+        let success = PutRecordBatchResponseEntry {
+            record_id: Some("49543463076548007577105092703039560359975228518395019266".to_owned()),
+            ..Default::default()
+        };
+        let failed = failed_records(records, &[failure.clone(), success, failure]);
+
+        assert_eq!(failed.len(), 2);
+        assert_eq!(&failed[0].0.data[..], b"a");
+        assert_eq!(&failed[1].0.data[..], b"c");
+        assert_eq!(
+            failure_message(&failed, 5),
+            "2 records failed to be put after 5 attempts, the first with \
+             ServiceUnavailableException: Slow down."
+        );
+    }
+}
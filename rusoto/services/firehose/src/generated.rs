@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Describes hints for the buffering to perform before delivering data to the destination. These options are treated as hints, and therefore Kinesis Data Firehose might choose to use different values when it is optimal.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -53,6 +54,90 @@ pub struct CloudWatchLoggingOptions {
     pub log_stream_name: Option<String>,
 }
 
+/// <p>The compression format. If no value is specified, the default is UNCOMPRESSED.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CompressionFormat {
+    /// <p><code>UNCOMPRESSED</code></p>
+    Uncompressed,
+    /// <p><code>GZIP</code></p>
+    Gzip,
+    /// <p><code>ZIP</code></p>
+    Zip,
+    /// <p><code>Snappy</code></p>
+    Snappy,
+    /// <p><code>HADOOP_SNAPPY</code></p>
+    HadoopSnappy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CompressionFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CompressionFormat::Uncompressed => "UNCOMPRESSED",
+            CompressionFormat::Gzip => "GZIP",
+            CompressionFormat::Zip => "ZIP",
+            CompressionFormat::Snappy => "Snappy",
+            CompressionFormat::HadoopSnappy => "HADOOP_SNAPPY",
+            CompressionFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CompressionFormat {
+    fn from(value: &'a str) -> CompressionFormat {
+        match value {
+            "UNCOMPRESSED" => CompressionFormat::Uncompressed,
+            "GZIP" => CompressionFormat::Gzip,
+            "ZIP" => CompressionFormat::Zip,
+            "Snappy" => CompressionFormat::Snappy,
+            "HADOOP_SNAPPY" => CompressionFormat::HadoopSnappy,
+            _ => CompressionFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CompressionFormat {
+    fn from(value: String) -> CompressionFormat {
+        CompressionFormat::from(value.as_str())
+    }
+}
+
+impl From<CompressionFormat> for String {
+    fn from(value: CompressionFormat) -> String {
+        match value {
+            CompressionFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CompressionFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CompressionFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CompressionFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CompressionFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CompressionFormat::from(value))
+    }
+}
+
 /// <p>Describes a <code>COPY</code> command for Amazon Redshift.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CopyCommand {
@@ -77,7 +162,7 @@ pub struct CreateDeliveryStreamInput {
     /// <p><p>The delivery stream type. This parameter can be one of the following values:</p> <ul> <li> <p> <code>DirectPut</code>: Provider applications access the delivery stream directly.</p> </li> <li> <p> <code>KinesisStreamAsSource</code>: The delivery stream uses a Kinesis data stream as a source.</p> </li> </ul></p>
     #[serde(rename = "DeliveryStreamType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_stream_type: Option<String>,
+    pub delivery_stream_type: Option<DeliveryStreamType>,
     /// <p>The destination in Amazon ES. You can specify only one destination.</p>
     #[serde(rename = "ElasticsearchDestinationConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -104,6 +189,111 @@ pub struct CreateDeliveryStreamInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDeliveryStreamInput {
+    /// Returns a builder for `CreateDeliveryStreamInput`, checking its required members when built.
+    pub fn builder() -> CreateDeliveryStreamInputBuilder {
+        CreateDeliveryStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDeliveryStreamInput`](struct.CreateDeliveryStreamInput.html).
+#[derive(Default, Debug)]
+pub struct CreateDeliveryStreamInputBuilder {
+    delivery_stream_name: Option<String>,
+    delivery_stream_type: Option<DeliveryStreamType>,
+    elasticsearch_destination_configuration: Option<ElasticsearchDestinationConfiguration>,
+    extended_s3_destination_configuration: Option<ExtendedS3DestinationConfiguration>,
+    kinesis_stream_source_configuration: Option<KinesisStreamSourceConfiguration>,
+    redshift_destination_configuration: Option<RedshiftDestinationConfiguration>,
+    splunk_destination_configuration: Option<SplunkDestinationConfiguration>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDeliveryStreamInputBuilder {
+    /// Sets the [`delivery_stream_name`](struct.CreateDeliveryStreamInput.html#structfield.delivery_stream_name) member.
+    pub fn delivery_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delivery_stream_type`](struct.CreateDeliveryStreamInput.html#structfield.delivery_stream_type) member.
+    pub fn delivery_stream_type<T: Into<DeliveryStreamType>>(mut self, value: T) -> Self {
+        self.delivery_stream_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`elasticsearch_destination_configuration`](struct.CreateDeliveryStreamInput.html#structfield.elasticsearch_destination_configuration) member.
+    pub fn elasticsearch_destination_configuration<
+        T: Into<ElasticsearchDestinationConfiguration>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.elasticsearch_destination_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`extended_s3_destination_configuration`](struct.CreateDeliveryStreamInput.html#structfield.extended_s3_destination_configuration) member.
+    pub fn extended_s3_destination_configuration<T: Into<ExtendedS3DestinationConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.extended_s3_destination_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kinesis_stream_source_configuration`](struct.CreateDeliveryStreamInput.html#structfield.kinesis_stream_source_configuration) member.
+    pub fn kinesis_stream_source_configuration<T: Into<KinesisStreamSourceConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.kinesis_stream_source_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`redshift_destination_configuration`](struct.CreateDeliveryStreamInput.html#structfield.redshift_destination_configuration) member.
+    pub fn redshift_destination_configuration<T: Into<RedshiftDestinationConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.redshift_destination_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`splunk_destination_configuration`](struct.CreateDeliveryStreamInput.html#structfield.splunk_destination_configuration) member.
+    pub fn splunk_destination_configuration<T: Into<SplunkDestinationConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.splunk_destination_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDeliveryStreamInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDeliveryStreamInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDeliveryStreamInput, ::rusoto_core::BuildError> {
+        Ok(CreateDeliveryStreamInput {
+            delivery_stream_name: self
+                .delivery_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_stream_name"))?,
+            delivery_stream_type: self.delivery_stream_type,
+            elasticsearch_destination_configuration: self.elasticsearch_destination_configuration,
+            extended_s3_destination_configuration: self.extended_s3_destination_configuration,
+            kinesis_stream_source_configuration: self.kinesis_stream_source_configuration,
+            redshift_destination_configuration: self.redshift_destination_configuration,
+            splunk_destination_configuration: self.splunk_destination_configuration,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDeliveryStreamOutput {
@@ -141,18 +331,51 @@ pub struct DeleteDeliveryStreamInput {
     pub delivery_stream_name: String,
 }
 
+impl DeleteDeliveryStreamInput {
+    /// Returns a builder for `DeleteDeliveryStreamInput`, checking its required members when built.
+    pub fn builder() -> DeleteDeliveryStreamInputBuilder {
+        DeleteDeliveryStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDeliveryStreamInput`](struct.DeleteDeliveryStreamInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteDeliveryStreamInputBuilder {
+    delivery_stream_name: Option<String>,
+}
+
+impl DeleteDeliveryStreamInputBuilder {
+    /// Sets the [`delivery_stream_name`](struct.DeleteDeliveryStreamInput.html#structfield.delivery_stream_name) member.
+    pub fn delivery_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDeliveryStreamInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDeliveryStreamInput, ::rusoto_core::BuildError> {
+        Ok(DeleteDeliveryStreamInput {
+            delivery_stream_name: self
+                .delivery_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_stream_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDeliveryStreamOutput {}
 
 /// <p>Contains information about a delivery stream.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeliveryStreamDescription {
     /// <p>The date and time that the delivery stream was created.</p>
     #[serde(rename = "CreateTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_timestamp: Option<f64>,
+    pub create_timestamp: Option<EpochTimestamp>,
     /// <p>The Amazon Resource Name (ARN) of the delivery stream. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
     #[serde(rename = "DeliveryStreamARN")]
     pub delivery_stream_arn: String,
@@ -165,10 +388,10 @@ pub struct DeliveryStreamDescription {
     pub delivery_stream_name: String,
     /// <p>The status of the delivery stream.</p>
     #[serde(rename = "DeliveryStreamStatus")]
-    pub delivery_stream_status: String,
+    pub delivery_stream_status: DeliveryStreamStatus,
     /// <p><p>The delivery stream type. This can be one of the following values:</p> <ul> <li> <p> <code>DirectPut</code>: Provider applications access the delivery stream directly.</p> </li> <li> <p> <code>KinesisStreamAsSource</code>: The delivery stream uses a Kinesis data stream as a source.</p> </li> </ul></p>
     #[serde(rename = "DeliveryStreamType")]
-    pub delivery_stream_type: String,
+    pub delivery_stream_type: DeliveryStreamType,
     /// <p>The destinations.</p>
     #[serde(rename = "Destinations")]
     pub destinations: Vec<DestinationDescription>,
@@ -177,8 +400,9 @@ pub struct DeliveryStreamDescription {
     pub has_more_destinations: bool,
     /// <p>The date and time that the delivery stream was last updated.</p>
     #[serde(rename = "LastUpdateTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_timestamp: Option<f64>,
+    pub last_update_timestamp: Option<EpochTimestamp>,
     /// <p>If the <code>DeliveryStreamType</code> parameter is <code>KinesisStreamAsSource</code>, a <a>SourceDescription</a> object describing the source Kinesis data stream.</p>
     #[serde(rename = "Source")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -195,7 +419,257 @@ pub struct DeliveryStreamEncryptionConfiguration {
     /// <p>For a full description of the different values of this status, see <a>StartDeliveryStreamEncryption</a> and <a>StopDeliveryStreamEncryption</a>.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DeliveryStreamEncryptionStatus>,
+}
+
+/// <p>For a full description of the different values of this status, see <a>StartDeliveryStreamEncryption</a> and <a>StopDeliveryStreamEncryption</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeliveryStreamEncryptionStatus {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>ENABLING</code></p>
+    Enabling,
+    /// <p><code>ENABLING_FAILED</code></p>
+    EnablingFailed,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// <p><code>DISABLING</code></p>
+    Disabling,
+    /// <p><code>DISABLING_FAILED</code></p>
+    DisablingFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeliveryStreamEncryptionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliveryStreamEncryptionStatus::Enabled => "ENABLED",
+            DeliveryStreamEncryptionStatus::Enabling => "ENABLING",
+            DeliveryStreamEncryptionStatus::EnablingFailed => "ENABLING_FAILED",
+            DeliveryStreamEncryptionStatus::Disabled => "DISABLED",
+            DeliveryStreamEncryptionStatus::Disabling => "DISABLING",
+            DeliveryStreamEncryptionStatus::DisablingFailed => "DISABLING_FAILED",
+            DeliveryStreamEncryptionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeliveryStreamEncryptionStatus {
+    fn from(value: &'a str) -> DeliveryStreamEncryptionStatus {
+        match value {
+            "ENABLED" => DeliveryStreamEncryptionStatus::Enabled,
+            "ENABLING" => DeliveryStreamEncryptionStatus::Enabling,
+            "ENABLING_FAILED" => DeliveryStreamEncryptionStatus::EnablingFailed,
+            "DISABLED" => DeliveryStreamEncryptionStatus::Disabled,
+            "DISABLING" => DeliveryStreamEncryptionStatus::Disabling,
+            "DISABLING_FAILED" => DeliveryStreamEncryptionStatus::DisablingFailed,
+            _ => DeliveryStreamEncryptionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliveryStreamEncryptionStatus {
+    fn from(value: String) -> DeliveryStreamEncryptionStatus {
+        DeliveryStreamEncryptionStatus::from(value.as_str())
+    }
+}
+
+impl From<DeliveryStreamEncryptionStatus> for String {
+    fn from(value: DeliveryStreamEncryptionStatus) -> String {
+        match value {
+            DeliveryStreamEncryptionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeliveryStreamEncryptionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeliveryStreamEncryptionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliveryStreamEncryptionStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<DeliveryStreamEncryptionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeliveryStreamEncryptionStatus::from(value))
+    }
+}
+
+/// <p>The status of the delivery stream.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeliveryStreamStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>CREATING_FAILED</code></p>
+    CreatingFailed,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>DELETING_FAILED</code></p>
+    DeletingFailed,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeliveryStreamStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliveryStreamStatus::Creating => "CREATING",
+            DeliveryStreamStatus::CreatingFailed => "CREATING_FAILED",
+            DeliveryStreamStatus::Deleting => "DELETING",
+            DeliveryStreamStatus::DeletingFailed => "DELETING_FAILED",
+            DeliveryStreamStatus::Active => "ACTIVE",
+            DeliveryStreamStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeliveryStreamStatus {
+    fn from(value: &'a str) -> DeliveryStreamStatus {
+        match value {
+            "CREATING" => DeliveryStreamStatus::Creating,
+            "CREATING_FAILED" => DeliveryStreamStatus::CreatingFailed,
+            "DELETING" => DeliveryStreamStatus::Deleting,
+            "DELETING_FAILED" => DeliveryStreamStatus::DeletingFailed,
+            "ACTIVE" => DeliveryStreamStatus::Active,
+            _ => DeliveryStreamStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliveryStreamStatus {
+    fn from(value: String) -> DeliveryStreamStatus {
+        DeliveryStreamStatus::from(value.as_str())
+    }
+}
+
+impl From<DeliveryStreamStatus> for String {
+    fn from(value: DeliveryStreamStatus) -> String {
+        match value {
+            DeliveryStreamStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeliveryStreamStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeliveryStreamStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliveryStreamStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeliveryStreamStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeliveryStreamStatus::from(value))
+    }
+}
+
+/// <p><p>The delivery stream type. This parameter can be one of the following values:</p> <ul> <li> <p> <code>DirectPut</code>: Provider applications access the delivery stream directly.</p> </li> <li> <p> <code>KinesisStreamAsSource</code>: The delivery stream uses a Kinesis data stream as a source.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeliveryStreamType {
+    /// <p><code>DirectPut</code> - Provider applications access the delivery stream directly.</p>
+    DirectPut,
+    /// <p><code>KinesisStreamAsSource</code> - The delivery stream uses a Kinesis data stream as a source.</p>
+    KinesisStreamAsSource,
+    /// <p><code>MSKAsSource</code></p>
+    MSKAsSource,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeliveryStreamType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeliveryStreamType::DirectPut => "DirectPut",
+            DeliveryStreamType::KinesisStreamAsSource => "KinesisStreamAsSource",
+            DeliveryStreamType::MSKAsSource => "MSKAsSource",
+            DeliveryStreamType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeliveryStreamType {
+    fn from(value: &'a str) -> DeliveryStreamType {
+        match value {
+            "DirectPut" => DeliveryStreamType::DirectPut,
+            "KinesisStreamAsSource" => DeliveryStreamType::KinesisStreamAsSource,
+            "MSKAsSource" => DeliveryStreamType::MSKAsSource,
+            _ => DeliveryStreamType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeliveryStreamType {
+    fn from(value: String) -> DeliveryStreamType {
+        DeliveryStreamType::from(value.as_str())
+    }
+}
+
+impl From<DeliveryStreamType> for String {
+    fn from(value: DeliveryStreamType) -> String {
+        match value {
+            DeliveryStreamType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeliveryStreamType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeliveryStreamType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeliveryStreamType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeliveryStreamType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeliveryStreamType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -213,7 +687,55 @@ pub struct DescribeDeliveryStreamInput {
     pub limit: Option<i64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DescribeDeliveryStreamInput {
+    /// Returns a builder for `DescribeDeliveryStreamInput`, checking its required members when built.
+    pub fn builder() -> DescribeDeliveryStreamInputBuilder {
+        DescribeDeliveryStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDeliveryStreamInput`](struct.DescribeDeliveryStreamInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeDeliveryStreamInputBuilder {
+    delivery_stream_name: Option<String>,
+    exclusive_start_destination_id: Option<String>,
+    limit: Option<i64>,
+}
+
+impl DescribeDeliveryStreamInputBuilder {
+    /// Sets the [`delivery_stream_name`](struct.DescribeDeliveryStreamInput.html#structfield.delivery_stream_name) member.
+    pub fn delivery_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exclusive_start_destination_id`](struct.DescribeDeliveryStreamInput.html#structfield.exclusive_start_destination_id) member.
+    pub fn exclusive_start_destination_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_destination_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeDeliveryStreamInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDeliveryStreamInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDeliveryStreamInput, ::rusoto_core::BuildError> {
+        Ok(DescribeDeliveryStreamInput {
+            delivery_stream_name: self
+                .delivery_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_stream_name"))?,
+            exclusive_start_destination_id: self.exclusive_start_destination_id,
+            limit: self.limit,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDeliveryStreamOutput {
     /// <p>Information about the delivery stream.</p>
@@ -296,7 +818,7 @@ pub struct ElasticsearchDestinationConfiguration {
     /// <p>The Elasticsearch index rotation period. Index rotation appends a timestamp to the <code>IndexName</code> to facilitate the expiration of old data. For more information, see <a href="http://docs.aws.amazon.com/firehose/latest/dev/basic-deliver.html#es-index-rotation">Index Rotation for the Amazon ES Destination</a>. The default value is <code>OneDay</code>.</p>
     #[serde(rename = "IndexRotationPeriod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_rotation_period: Option<String>,
+    pub index_rotation_period: Option<ElasticsearchIndexRotationPeriod>,
     /// <p>The data processing configuration.</p>
     #[serde(rename = "ProcessingConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -311,7 +833,7 @@ pub struct ElasticsearchDestinationConfiguration {
     /// <p>Defines how documents should be delivered to Amazon S3. When it is set to <code>FailedDocumentsOnly</code>, Kinesis Data Firehose writes any documents that could not be indexed to the configured Amazon S3 destination, with <code>elasticsearch-failed/</code> appended to the key prefix. When set to <code>AllDocuments</code>, Kinesis Data Firehose delivers all incoming records to Amazon S3, and also writes failed documents with <code>elasticsearch-failed/</code> appended to the prefix. For more information, see <a href="http://docs.aws.amazon.com/firehose/latest/dev/basic-deliver.html#es-s3-backup">Amazon S3 Backup for the Amazon ES Destination</a>. Default value is <code>FailedDocumentsOnly</code>.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<ElasticsearchS3BackupMode>,
     /// <p>The configuration for the backup Amazon S3 location.</p>
     #[serde(rename = "S3Configuration")]
     pub s3_configuration: S3DestinationConfiguration,
@@ -343,7 +865,7 @@ pub struct ElasticsearchDestinationDescription {
     /// <p>The Elasticsearch index rotation period</p>
     #[serde(rename = "IndexRotationPeriod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_rotation_period: Option<String>,
+    pub index_rotation_period: Option<ElasticsearchIndexRotationPeriod>,
     /// <p>The data processing configuration.</p>
     #[serde(rename = "ProcessingConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -359,7 +881,7 @@ pub struct ElasticsearchDestinationDescription {
     /// <p>The Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<ElasticsearchS3BackupMode>,
     /// <p>The Amazon S3 destination.</p>
     #[serde(rename = "S3DestinationDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -392,7 +914,7 @@ pub struct ElasticsearchDestinationUpdate {
     /// <p>The Elasticsearch index rotation period. Index rotation appends a timestamp to <code>IndexName</code> to facilitate the expiration of old data. For more information, see <a href="http://docs.aws.amazon.com/firehose/latest/dev/basic-deliver.html#es-index-rotation">Index Rotation for the Amazon ES Destination</a>. Default value is <code>OneDay</code>.</p>
     #[serde(rename = "IndexRotationPeriod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_rotation_period: Option<String>,
+    pub index_rotation_period: Option<ElasticsearchIndexRotationPeriod>,
     /// <p>The data processing configuration.</p>
     #[serde(rename = "ProcessingConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -415,6 +937,92 @@ pub struct ElasticsearchDestinationUpdate {
     pub type_name: Option<String>,
 }
 
+/// <p>The Elasticsearch index rotation period. Index rotation appends a timestamp to the <code>IndexName</code> to facilitate the expiration of old data. For more information, see <a href="http://docs.aws.amazon.com/firehose/latest/dev/basic-deliver.html#es-index-rotation">Index Rotation for the Amazon ES Destination</a>. The default value is <code>OneDay</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ElasticsearchIndexRotationPeriod {
+    /// <p><code>NoRotation</code></p>
+    NoRotation,
+    /// <p><code>OneHour</code></p>
+    OneHour,
+    /// <p><code>OneDay</code></p>
+    OneDay,
+    /// <p><code>OneWeek</code></p>
+    OneWeek,
+    /// <p><code>OneMonth</code></p>
+    OneMonth,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ElasticsearchIndexRotationPeriod {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ElasticsearchIndexRotationPeriod::NoRotation => "NoRotation",
+            ElasticsearchIndexRotationPeriod::OneHour => "OneHour",
+            ElasticsearchIndexRotationPeriod::OneDay => "OneDay",
+            ElasticsearchIndexRotationPeriod::OneWeek => "OneWeek",
+            ElasticsearchIndexRotationPeriod::OneMonth => "OneMonth",
+            ElasticsearchIndexRotationPeriod::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ElasticsearchIndexRotationPeriod {
+    fn from(value: &'a str) -> ElasticsearchIndexRotationPeriod {
+        match value {
+            "NoRotation" => ElasticsearchIndexRotationPeriod::NoRotation,
+            "OneHour" => ElasticsearchIndexRotationPeriod::OneHour,
+            "OneDay" => ElasticsearchIndexRotationPeriod::OneDay,
+            "OneWeek" => ElasticsearchIndexRotationPeriod::OneWeek,
+            "OneMonth" => ElasticsearchIndexRotationPeriod::OneMonth,
+            _ => ElasticsearchIndexRotationPeriod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ElasticsearchIndexRotationPeriod {
+    fn from(value: String) -> ElasticsearchIndexRotationPeriod {
+        ElasticsearchIndexRotationPeriod::from(value.as_str())
+    }
+}
+
+impl From<ElasticsearchIndexRotationPeriod> for String {
+    fn from(value: ElasticsearchIndexRotationPeriod) -> String {
+        match value {
+            ElasticsearchIndexRotationPeriod::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ElasticsearchIndexRotationPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ElasticsearchIndexRotationPeriod {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ElasticsearchIndexRotationPeriod {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ElasticsearchIndexRotationPeriod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ElasticsearchIndexRotationPeriod::from(value))
+    }
+}
+
 /// <p>Configures retry behavior in case Kinesis Data Firehose is unable to deliver documents to Amazon ES.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ElasticsearchRetryOptions {
@@ -424,6 +1032,78 @@ pub struct ElasticsearchRetryOptions {
     pub duration_in_seconds: Option<i64>,
 }
 
+/// <p>Defines how documents should be delivered to Amazon S3. When it is set to <code>FailedDocumentsOnly</code>, Kinesis Data Firehose writes any documents that could not be indexed to the configured Amazon S3 destination, with <code>elasticsearch-failed/</code> appended to the key prefix. When set to <code>AllDocuments</code>, Kinesis Data Firehose delivers all incoming records to Amazon S3, and also writes failed documents with <code>elasticsearch-failed/</code> appended to the prefix. For more information, see <a href="http://docs.aws.amazon.com/firehose/latest/dev/basic-deliver.html#es-s3-backup">Amazon S3 Backup for the Amazon ES Destination</a>. Default value is <code>FailedDocumentsOnly</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ElasticsearchS3BackupMode {
+    /// <p><code>FailedDocumentsOnly</code></p>
+    FailedDocumentsOnly,
+    /// <p><code>AllDocuments</code></p>
+    AllDocuments,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ElasticsearchS3BackupMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ElasticsearchS3BackupMode::FailedDocumentsOnly => "FailedDocumentsOnly",
+            ElasticsearchS3BackupMode::AllDocuments => "AllDocuments",
+            ElasticsearchS3BackupMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ElasticsearchS3BackupMode {
+    fn from(value: &'a str) -> ElasticsearchS3BackupMode {
+        match value {
+            "FailedDocumentsOnly" => ElasticsearchS3BackupMode::FailedDocumentsOnly,
+            "AllDocuments" => ElasticsearchS3BackupMode::AllDocuments,
+            _ => ElasticsearchS3BackupMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ElasticsearchS3BackupMode {
+    fn from(value: String) -> ElasticsearchS3BackupMode {
+        ElasticsearchS3BackupMode::from(value.as_str())
+    }
+}
+
+impl From<ElasticsearchS3BackupMode> for String {
+    fn from(value: ElasticsearchS3BackupMode) -> String {
+        match value {
+            ElasticsearchS3BackupMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ElasticsearchS3BackupMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ElasticsearchS3BackupMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ElasticsearchS3BackupMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ElasticsearchS3BackupMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ElasticsearchS3BackupMode::from(value))
+    }
+}
+
 /// <p>Describes the encryption for a destination in Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptionConfiguration {
@@ -434,7 +1114,7 @@ pub struct EncryptionConfiguration {
     /// <p>Specifically override existing encryption information to ensure that no encryption is used.</p>
     #[serde(rename = "NoEncryptionConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub no_encryption_config: Option<String>,
+    pub no_encryption_config: Option<NoEncryptionConfig>,
 }
 
 /// <p>Describes the configuration of a destination in Amazon S3.</p>
@@ -454,7 +1134,7 @@ pub struct ExtendedS3DestinationConfiguration {
     /// <p>The compression format. If no value is specified, the default is UNCOMPRESSED.</p>
     #[serde(rename = "CompressionFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression_format: Option<String>,
+    pub compression_format: Option<CompressionFormat>,
     /// <p>The serializer, deserializer, and schema for converting data from the JSON format to the Parquet or ORC format before writing it to Amazon S3.</p>
     #[serde(rename = "DataFormatConversionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -485,11 +1165,11 @@ pub struct ExtendedS3DestinationConfiguration {
     /// <p>The Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<S3BackupMode>,
 }
 
 /// <p>Describes a destination in Amazon S3.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ExtendedS3DestinationDescription {
     /// <p>The ARN of the S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
@@ -504,7 +1184,7 @@ pub struct ExtendedS3DestinationDescription {
     pub cloud_watch_logging_options: Option<CloudWatchLoggingOptions>,
     /// <p>The compression format. If no value is specified, the default is <code>UNCOMPRESSED</code>.</p>
     #[serde(rename = "CompressionFormat")]
-    pub compression_format: String,
+    pub compression_format: CompressionFormat,
     /// <p>The serializer, deserializer, and schema for converting data from the JSON format to the Parquet or ORC format before writing it to Amazon S3.</p>
     #[serde(rename = "DataFormatConversionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -534,7 +1214,7 @@ pub struct ExtendedS3DestinationDescription {
     /// <p>The Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<S3BackupMode>,
 }
 
 /// <p>Describes an update for a destination in Amazon S3.</p>
@@ -555,7 +1235,7 @@ pub struct ExtendedS3DestinationUpdate {
     /// <p>The compression format. If no value is specified, the default is <code>UNCOMPRESSED</code>. </p>
     #[serde(rename = "CompressionFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression_format: Option<String>,
+    pub compression_format: Option<CompressionFormat>,
     /// <p>The serializer, deserializer, and schema for converting data from the JSON format to the Parquet or ORC format before writing it to Amazon S3.</p>
     #[serde(rename = "DataFormatConversionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -583,25 +1263,97 @@ pub struct ExtendedS3DestinationUpdate {
     /// <p>Enables or disables Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<S3BackupMode>,
     /// <p>The Amazon S3 destination for backup.</p>
     #[serde(rename = "S3BackupUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub s3_backup_update: Option<S3DestinationUpdate>,
 }
 
-/// <p>The native Hive / HCatalog JsonSerDe. Used by Kinesis Data Firehose for deserializing data, which means converting it from the JSON format in preparation for serializing it to the Parquet or ORC format. This is one of two deserializers you can choose, depending on which one offers the functionality you need. The other option is the OpenX SerDe.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct HiveJsonSerDe {
-    /// <p>Indicates how you want Kinesis Data Firehose to parse the date and timestamps that may be present in your input data JSON. To specify these format strings, follow the pattern syntax of JodaTime's DateTimeFormat format strings. For more information, see <a href="https://www.joda.org/joda-time/apidocs/org/joda/time/format/DateTimeFormat.html">Class DateTimeFormat</a>. You can also use the special value <code>millis</code> to parse timestamps in epoch milliseconds. If you don't specify a format, Kinesis Data Firehose uses <code>java.sql.Timestamp::valueOf</code> by default.</p>
-    #[serde(rename = "TimestampFormats")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp_formats: Option<Vec<String>>,
+/// <p>This type can be either "Raw" or "Event."</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HECEndpointType {
+    /// <p><code>Raw</code></p>
+    Raw,
+    /// <p><code>Event</code></p>
+    Event,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Specifies the deserializer you want to use to convert the format of the input data.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct InputFormatConfiguration {
+impl HECEndpointType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HECEndpointType::Raw => "Raw",
+            HECEndpointType::Event => "Event",
+            HECEndpointType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HECEndpointType {
+    fn from(value: &'a str) -> HECEndpointType {
+        match value {
+            "Raw" => HECEndpointType::Raw,
+            "Event" => HECEndpointType::Event,
+            _ => HECEndpointType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HECEndpointType {
+    fn from(value: String) -> HECEndpointType {
+        HECEndpointType::from(value.as_str())
+    }
+}
+
+impl From<HECEndpointType> for String {
+    fn from(value: HECEndpointType) -> String {
+        match value {
+            HECEndpointType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HECEndpointType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HECEndpointType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HECEndpointType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HECEndpointType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HECEndpointType::from(value))
+    }
+}
+
+/// <p>The native Hive / HCatalog JsonSerDe. Used by Kinesis Data Firehose for deserializing data, which means converting it from the JSON format in preparation for serializing it to the Parquet or ORC format. This is one of two deserializers you can choose, depending on which one offers the functionality you need. The other option is the OpenX SerDe.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HiveJsonSerDe {
+    /// <p>Indicates how you want Kinesis Data Firehose to parse the date and timestamps that may be present in your input data JSON. To specify these format strings, follow the pattern syntax of JodaTime's DateTimeFormat format strings. For more information, see <a href="https://www.joda.org/joda-time/apidocs/org/joda/time/format/DateTimeFormat.html">Class DateTimeFormat</a>. You can also use the special value <code>millis</code> to parse timestamps in epoch milliseconds. If you don't specify a format, Kinesis Data Firehose uses <code>java.sql.Timestamp::valueOf</code> by default.</p>
+    #[serde(rename = "TimestampFormats")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp_formats: Option<Vec<String>>,
+}
+
+/// <p>Specifies the deserializer you want to use to convert the format of the input data.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputFormatConfiguration {
     /// <p>Specifies which deserializer to use. You can choose either the Apache Hive JSON SerDe or the OpenX JSON SerDe. If both are non-null, the server rejects the request.</p>
     #[serde(rename = "Deserializer")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -633,8 +1385,9 @@ pub struct KinesisStreamSourceConfiguration {
 pub struct KinesisStreamSourceDescription {
     /// <p>Kinesis Data Firehose starts retrieving records from the Kinesis data stream starting with this timestamp.</p>
     #[serde(rename = "DeliveryStartTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_start_timestamp: Option<f64>,
+    pub delivery_start_timestamp: Option<EpochTimestamp>,
     /// <p>The Amazon Resource Name (ARN) of the source Kinesis data stream. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-kinesis-streams">Amazon Kinesis Data Streams ARN Format</a>.</p>
     #[serde(rename = "KinesisStreamARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -650,7 +1403,7 @@ pub struct ListDeliveryStreamsInput {
     /// <p>The delivery stream type. This can be one of the following values:</p> <ul> <li> <p> <code>DirectPut</code>: Provider applications access the delivery stream directly.</p> </li> <li> <p> <code>KinesisStreamAsSource</code>: The delivery stream uses a Kinesis data stream as a source.</p> </li> </ul> <p>This parameter is optional. If this parameter is omitted, delivery streams of all types are returned.</p>
     #[serde(rename = "DeliveryStreamType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delivery_stream_type: Option<String>,
+    pub delivery_stream_type: Option<DeliveryStreamType>,
     /// <p>The list of delivery streams returned by this call to <code>ListDeliveryStreams</code> will start with the delivery stream whose name comes alphabetically immediately after the name you specify in <code>ExclusiveStartDeliveryStreamName</code>.</p>
     #[serde(rename = "ExclusiveStartDeliveryStreamName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -661,6 +1414,52 @@ pub struct ListDeliveryStreamsInput {
     pub limit: Option<i64>,
 }
 
+impl ListDeliveryStreamsInput {
+    /// Returns a builder for `ListDeliveryStreamsInput`, checking its required members when built.
+    pub fn builder() -> ListDeliveryStreamsInputBuilder {
+        ListDeliveryStreamsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeliveryStreamsInput`](struct.ListDeliveryStreamsInput.html).
+#[derive(Default, Debug)]
+pub struct ListDeliveryStreamsInputBuilder {
+    delivery_stream_type: Option<DeliveryStreamType>,
+    exclusive_start_delivery_stream_name: Option<String>,
+    limit: Option<i64>,
+}
+
+impl ListDeliveryStreamsInputBuilder {
+    /// Sets the [`delivery_stream_type`](struct.ListDeliveryStreamsInput.html#structfield.delivery_stream_type) member.
+    pub fn delivery_stream_type<T: Into<DeliveryStreamType>>(mut self, value: T) -> Self {
+        self.delivery_stream_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exclusive_start_delivery_stream_name`](struct.ListDeliveryStreamsInput.html#structfield.exclusive_start_delivery_stream_name) member.
+    pub fn exclusive_start_delivery_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_delivery_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListDeliveryStreamsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeliveryStreamsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDeliveryStreamsInput, ::rusoto_core::BuildError> {
+        Ok(ListDeliveryStreamsInput {
+            delivery_stream_type: self.delivery_stream_type,
+            exclusive_start_delivery_stream_name: self.exclusive_start_delivery_stream_name,
+            limit: self.limit,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDeliveryStreamsOutput {
@@ -687,6 +1486,54 @@ pub struct ListTagsForDeliveryStreamInput {
     pub limit: Option<i64>,
 }
 
+impl ListTagsForDeliveryStreamInput {
+    /// Returns a builder for `ListTagsForDeliveryStreamInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForDeliveryStreamInputBuilder {
+        ListTagsForDeliveryStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForDeliveryStreamInput`](struct.ListTagsForDeliveryStreamInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForDeliveryStreamInputBuilder {
+    delivery_stream_name: Option<String>,
+    exclusive_start_tag_key: Option<String>,
+    limit: Option<i64>,
+}
+
+impl ListTagsForDeliveryStreamInputBuilder {
+    /// Sets the [`delivery_stream_name`](struct.ListTagsForDeliveryStreamInput.html#structfield.delivery_stream_name) member.
+    pub fn delivery_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`exclusive_start_tag_key`](struct.ListTagsForDeliveryStreamInput.html#structfield.exclusive_start_tag_key) member.
+    pub fn exclusive_start_tag_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_tag_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListTagsForDeliveryStreamInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForDeliveryStreamInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForDeliveryStreamInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForDeliveryStreamInput {
+            delivery_stream_name: self
+                .delivery_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_stream_name"))?,
+            exclusive_start_tag_key: self.exclusive_start_tag_key,
+            limit: self.limit,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForDeliveryStreamOutput {
@@ -698,6 +1545,74 @@ pub struct ListTagsForDeliveryStreamOutput {
     pub tags: Vec<Tag>,
 }
 
+/// <p>Specifically override existing encryption information to ensure that no encryption is used.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NoEncryptionConfig {
+    /// <p><code>NoEncryption</code></p>
+    NoEncryption,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NoEncryptionConfig {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NoEncryptionConfig::NoEncryption => "NoEncryption",
+            NoEncryptionConfig::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NoEncryptionConfig {
+    fn from(value: &'a str) -> NoEncryptionConfig {
+        match value {
+            "NoEncryption" => NoEncryptionConfig::NoEncryption,
+            _ => NoEncryptionConfig::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NoEncryptionConfig {
+    fn from(value: String) -> NoEncryptionConfig {
+        NoEncryptionConfig::from(value.as_str())
+    }
+}
+
+impl From<NoEncryptionConfig> for String {
+    fn from(value: NoEncryptionConfig) -> String {
+        match value {
+            NoEncryptionConfig::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NoEncryptionConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NoEncryptionConfig {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NoEncryptionConfig {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NoEncryptionConfig, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NoEncryptionConfig::from(value))
+    }
+}
+
 /// <p>The OpenX SerDe. Used by Kinesis Data Firehose for deserializing data, which means converting it from the JSON format in preparation for serializing it to the Parquet or ORC format. This is one of two deserializers you can choose, depending on which one offers the functionality you need. The other option is the native Hive / HCatalog JsonSerDe.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OpenXJsonSerDe {
@@ -715,6 +1630,154 @@ pub struct OpenXJsonSerDe {
     pub convert_dots_in_json_keys_to_underscores: Option<bool>,
 }
 
+/// <p>The compression code to use over data blocks. The default is <code>SNAPPY</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrcCompression {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>ZLIB</code></p>
+    Zlib,
+    /// <p><code>SNAPPY</code></p>
+    Snappy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OrcCompression {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrcCompression::None => "NONE",
+            OrcCompression::Zlib => "ZLIB",
+            OrcCompression::Snappy => "SNAPPY",
+            OrcCompression::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OrcCompression {
+    fn from(value: &'a str) -> OrcCompression {
+        match value {
+            "NONE" => OrcCompression::None,
+            "ZLIB" => OrcCompression::Zlib,
+            "SNAPPY" => OrcCompression::Snappy,
+            _ => OrcCompression::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrcCompression {
+    fn from(value: String) -> OrcCompression {
+        OrcCompression::from(value.as_str())
+    }
+}
+
+impl From<OrcCompression> for String {
+    fn from(value: OrcCompression) -> String {
+        match value {
+            OrcCompression::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OrcCompression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OrcCompression {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrcCompression {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OrcCompression, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrcCompression::from(value))
+    }
+}
+
+/// <p>The version of the file to write. The possible values are <code>V0_11</code> and <code>V0_12</code>. The default is <code>V0_12</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrcFormatVersion {
+    /// <p><code>V0_11</code></p>
+    V011,
+    /// <p><code>V0_12</code></p>
+    V012,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OrcFormatVersion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrcFormatVersion::V011 => "V0_11",
+            OrcFormatVersion::V012 => "V0_12",
+            OrcFormatVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OrcFormatVersion {
+    fn from(value: &'a str) -> OrcFormatVersion {
+        match value {
+            "V0_11" => OrcFormatVersion::V011,
+            "V0_12" => OrcFormatVersion::V012,
+            _ => OrcFormatVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrcFormatVersion {
+    fn from(value: String) -> OrcFormatVersion {
+        OrcFormatVersion::from(value.as_str())
+    }
+}
+
+impl From<OrcFormatVersion> for String {
+    fn from(value: OrcFormatVersion) -> String {
+        match value {
+            OrcFormatVersion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OrcFormatVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OrcFormatVersion {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrcFormatVersion {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OrcFormatVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrcFormatVersion::from(value))
+    }
+}
+
 /// <p>A serializer to use for converting data to the ORC format before storing it in Amazon S3. For more information, see <a href="https://orc.apache.org/docs/">Apache ORC</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OrcSerDe {
@@ -733,7 +1796,7 @@ pub struct OrcSerDe {
     /// <p>The compression code to use over data blocks. The default is <code>SNAPPY</code>.</p>
     #[serde(rename = "Compression")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression: Option<String>,
+    pub compression: Option<OrcCompression>,
     /// <p>Represents the fraction of the total number of non-null rows. To turn off dictionary encoding, set this fraction to a number that is less than the number of distinct keys in a dictionary. To always use dictionary encoding, set this threshold to 1.</p>
     #[serde(rename = "DictionaryKeyThreshold")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -745,7 +1808,7 @@ pub struct OrcSerDe {
     /// <p>The version of the file to write. The possible values are <code>V0_11</code> and <code>V0_12</code>. The default is <code>V0_12</code>.</p>
     #[serde(rename = "FormatVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub format_version: Option<String>,
+    pub format_version: Option<OrcFormatVersion>,
     /// <p>A number between 0 and 1 that defines the tolerance for block padding as a decimal fraction of stripe size. The default value is 0.05, which means 5 percent of stripe size.</p> <p>For the default values of 64 MiB ORC stripes and 256 MiB HDFS blocks, the default block padding tolerance of 5 percent reserves a maximum of 3.2 MiB for padding within the 256 MiB block. In such a case, if the available size within the block is more than 3.2 MiB, a new, smaller stripe is inserted to fit within that space. This ensures that no stripe crosses block boundaries and causes remote reads within a node-local task.</p> <p>Kinesis Data Firehose ignores this parameter when <a>OrcSerDe$EnablePadding</a> is <code>false</code>.</p>
     #[serde(rename = "PaddingTolerance")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -769,6 +1832,82 @@ pub struct OutputFormatConfiguration {
     pub serializer: Option<Serializer>,
 }
 
+/// <p>The compression code to use over data blocks. The possible values are <code>UNCOMPRESSED</code>, <code>SNAPPY</code>, and <code>GZIP</code>, with the default being <code>SNAPPY</code>. Use <code>SNAPPY</code> for higher decompression speed. Use <code>GZIP</code> if the compression ration is more important than speed.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParquetCompression {
+    /// <p><code>UNCOMPRESSED</code></p>
+    Uncompressed,
+    /// <p><code>GZIP</code></p>
+    Gzip,
+    /// <p><code>SNAPPY</code></p>
+    Snappy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ParquetCompression {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParquetCompression::Uncompressed => "UNCOMPRESSED",
+            ParquetCompression::Gzip => "GZIP",
+            ParquetCompression::Snappy => "SNAPPY",
+            ParquetCompression::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ParquetCompression {
+    fn from(value: &'a str) -> ParquetCompression {
+        match value {
+            "UNCOMPRESSED" => ParquetCompression::Uncompressed,
+            "GZIP" => ParquetCompression::Gzip,
+            "SNAPPY" => ParquetCompression::Snappy,
+            _ => ParquetCompression::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParquetCompression {
+    fn from(value: String) -> ParquetCompression {
+        ParquetCompression::from(value.as_str())
+    }
+}
+
+impl From<ParquetCompression> for String {
+    fn from(value: ParquetCompression) -> String {
+        match value {
+            ParquetCompression::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParquetCompression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ParquetCompression {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParquetCompression {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ParquetCompression, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ParquetCompression::from(value))
+    }
+}
+
 /// <p>A serializer to use for converting data to the Parquet format before storing it in Amazon S3. For more information, see <a href="https://parquet.apache.org/documentation/latest/">Apache Parquet</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParquetSerDe {
@@ -779,7 +1918,7 @@ pub struct ParquetSerDe {
     /// <p>The compression code to use over data blocks. The possible values are <code>UNCOMPRESSED</code>, <code>SNAPPY</code>, and <code>GZIP</code>, with the default being <code>SNAPPY</code>. Use <code>SNAPPY</code> for higher decompression speed. Use <code>GZIP</code> if the compression ration is more important than speed.</p>
     #[serde(rename = "Compression")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression: Option<String>,
+    pub compression: Option<ParquetCompression>,
     /// <p>Indicates whether to enable dictionary compression.</p>
     #[serde(rename = "EnableDictionaryCompression")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -795,7 +1934,79 @@ pub struct ParquetSerDe {
     /// <p>Indicates the version of row format to output. The possible values are <code>V1</code> and <code>V2</code>. The default is <code>V1</code>.</p>
     #[serde(rename = "WriterVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub writer_version: Option<String>,
+    pub writer_version: Option<ParquetWriterVersion>,
+}
+
+/// <p>Indicates the version of row format to output. The possible values are <code>V1</code> and <code>V2</code>. The default is <code>V1</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParquetWriterVersion {
+    /// <p><code>V1</code></p>
+    V1,
+    /// <p><code>V2</code></p>
+    V2,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ParquetWriterVersion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParquetWriterVersion::V1 => "V1",
+            ParquetWriterVersion::V2 => "V2",
+            ParquetWriterVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ParquetWriterVersion {
+    fn from(value: &'a str) -> ParquetWriterVersion {
+        match value {
+            "V1" => ParquetWriterVersion::V1,
+            "V2" => ParquetWriterVersion::V2,
+            _ => ParquetWriterVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParquetWriterVersion {
+    fn from(value: String) -> ParquetWriterVersion {
+        ParquetWriterVersion::from(value.as_str())
+    }
+}
+
+impl From<ParquetWriterVersion> for String {
+    fn from(value: ParquetWriterVersion) -> String {
+        match value {
+            ParquetWriterVersion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParquetWriterVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ParquetWriterVersion {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParquetWriterVersion {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ParquetWriterVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ParquetWriterVersion::from(value))
+    }
 }
 
 /// <p>Describes a data processing configuration.</p>
@@ -812,7 +2023,7 @@ pub struct ProcessingConfiguration {
 }
 
 /// <p>Describes a data processor.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Processor {
     /// <p>The processor parameters.</p>
     #[serde(rename = "Parameters")]
@@ -820,20 +2031,208 @@ pub struct Processor {
     pub parameters: Option<Vec<ProcessorParameter>>,
     /// <p>The type of processor.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: ProcessorType,
 }
 
 /// <p>Describes the processor parameter.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProcessorParameter {
     /// <p>The name of the parameter.</p>
     #[serde(rename = "ParameterName")]
-    pub parameter_name: String,
+    pub parameter_name: ProcessorParameterName,
     /// <p>The parameter value.</p>
     #[serde(rename = "ParameterValue")]
     pub parameter_value: String,
 }
 
+/// <p>The name of the parameter.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProcessorParameterName {
+    /// <p><code>LambdaArn</code></p>
+    LambdaArn,
+    /// <p><code>NumberOfRetries</code></p>
+    NumberOfRetries,
+    /// <p><code>MetadataExtractionQuery</code></p>
+    MetadataExtractionQuery,
+    /// <p><code>JsonParsingEngine</code></p>
+    JsonParsingEngine,
+    /// <p><code>RoleArn</code></p>
+    RoleArn,
+    /// <p><code>BufferSizeInMBs</code></p>
+    BufferSizeInMBs,
+    /// <p><code>BufferIntervalInSeconds</code></p>
+    BufferIntervalInSeconds,
+    /// <p><code>SubRecordType</code></p>
+    SubRecordType,
+    /// <p><code>Delimiter</code></p>
+    Delimiter,
+    /// <p><code>CompressionFormat</code></p>
+    CompressionFormat,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProcessorParameterName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProcessorParameterName::LambdaArn => "LambdaArn",
+            ProcessorParameterName::NumberOfRetries => "NumberOfRetries",
+            ProcessorParameterName::MetadataExtractionQuery => "MetadataExtractionQuery",
+            ProcessorParameterName::JsonParsingEngine => "JsonParsingEngine",
+            ProcessorParameterName::RoleArn => "RoleArn",
+            ProcessorParameterName::BufferSizeInMBs => "BufferSizeInMBs",
+            ProcessorParameterName::BufferIntervalInSeconds => "BufferIntervalInSeconds",
+            ProcessorParameterName::SubRecordType => "SubRecordType",
+            ProcessorParameterName::Delimiter => "Delimiter",
+            ProcessorParameterName::CompressionFormat => "CompressionFormat",
+            ProcessorParameterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProcessorParameterName {
+    fn from(value: &'a str) -> ProcessorParameterName {
+        match value {
+            "LambdaArn" => ProcessorParameterName::LambdaArn,
+            "NumberOfRetries" => ProcessorParameterName::NumberOfRetries,
+            "MetadataExtractionQuery" => ProcessorParameterName::MetadataExtractionQuery,
+            "JsonParsingEngine" => ProcessorParameterName::JsonParsingEngine,
+            "RoleArn" => ProcessorParameterName::RoleArn,
+            "BufferSizeInMBs" => ProcessorParameterName::BufferSizeInMBs,
+            "BufferIntervalInSeconds" => ProcessorParameterName::BufferIntervalInSeconds,
+            "SubRecordType" => ProcessorParameterName::SubRecordType,
+            "Delimiter" => ProcessorParameterName::Delimiter,
+            "CompressionFormat" => ProcessorParameterName::CompressionFormat,
+            _ => ProcessorParameterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProcessorParameterName {
+    fn from(value: String) -> ProcessorParameterName {
+        ProcessorParameterName::from(value.as_str())
+    }
+}
+
+impl From<ProcessorParameterName> for String {
+    fn from(value: ProcessorParameterName) -> String {
+        match value {
+            ProcessorParameterName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProcessorParameterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProcessorParameterName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProcessorParameterName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProcessorParameterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProcessorParameterName::from(value))
+    }
+}
+
+/// <p>The type of processor.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProcessorType {
+    /// <p><code>RecordDeAggregation</code></p>
+    RecordDeAggregation,
+    /// <p><code>Decompression</code></p>
+    Decompression,
+    /// <p><code>Lambda</code></p>
+    Lambda,
+    /// <p><code>MetadataExtraction</code></p>
+    MetadataExtraction,
+    /// <p><code>AppendDelimiterToRecord</code></p>
+    AppendDelimiterToRecord,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProcessorType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProcessorType::RecordDeAggregation => "RecordDeAggregation",
+            ProcessorType::Decompression => "Decompression",
+            ProcessorType::Lambda => "Lambda",
+            ProcessorType::MetadataExtraction => "MetadataExtraction",
+            ProcessorType::AppendDelimiterToRecord => "AppendDelimiterToRecord",
+            ProcessorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProcessorType {
+    fn from(value: &'a str) -> ProcessorType {
+        match value {
+            "RecordDeAggregation" => ProcessorType::RecordDeAggregation,
+            "Decompression" => ProcessorType::Decompression,
+            "Lambda" => ProcessorType::Lambda,
+            "MetadataExtraction" => ProcessorType::MetadataExtraction,
+            "AppendDelimiterToRecord" => ProcessorType::AppendDelimiterToRecord,
+            _ => ProcessorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProcessorType {
+    fn from(value: String) -> ProcessorType {
+        ProcessorType::from(value.as_str())
+    }
+}
+
+impl From<ProcessorType> for String {
+    fn from(value: ProcessorType) -> String {
+        match value {
+            ProcessorType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProcessorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ProcessorType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProcessorType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProcessorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProcessorType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutRecordBatchInput {
     /// <p>The name of the delivery stream.</p>
@@ -844,6 +2243,46 @@ pub struct PutRecordBatchInput {
     pub records: Vec<Record>,
 }
 
+impl PutRecordBatchInput {
+    /// Returns a builder for `PutRecordBatchInput`, checking its required members when built.
+    pub fn builder() -> PutRecordBatchInputBuilder {
+        PutRecordBatchInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutRecordBatchInput`](struct.PutRecordBatchInput.html).
+#[derive(Default, Debug)]
+pub struct PutRecordBatchInputBuilder {
+    delivery_stream_name: Option<String>,
+    records: Option<Vec<Record>>,
+}
+
+impl PutRecordBatchInputBuilder {
+    /// Sets the [`delivery_stream_name`](struct.PutRecordBatchInput.html#structfield.delivery_stream_name) member.
+    pub fn delivery_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`records`](struct.PutRecordBatchInput.html#structfield.records) member.
+    pub fn records<T: Into<Vec<Record>>>(mut self, value: T) -> Self {
+        self.records = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRecordBatchInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutRecordBatchInput, ::rusoto_core::BuildError> {
+        Ok(PutRecordBatchInput {
+            delivery_stream_name: self
+                .delivery_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_stream_name"))?,
+            records: self
+                .records
+                .ok_or(::rusoto_core::BuildError::missing("records"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutRecordBatchOutput {
@@ -887,6 +2326,46 @@ pub struct PutRecordInput {
     pub record: Record,
 }
 
+impl PutRecordInput {
+    /// Returns a builder for `PutRecordInput`, checking its required members when built.
+    pub fn builder() -> PutRecordInputBuilder {
+        PutRecordInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutRecordInput`](struct.PutRecordInput.html).
+#[derive(Default, Debug)]
+pub struct PutRecordInputBuilder {
+    delivery_stream_name: Option<String>,
+    record: Option<Record>,
+}
+
+impl PutRecordInputBuilder {
+    /// Sets the [`delivery_stream_name`](struct.PutRecordInput.html#structfield.delivery_stream_name) member.
+    pub fn delivery_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`record`](struct.PutRecordInput.html#structfield.record) member.
+    pub fn record<T: Into<Record>>(mut self, value: T) -> Self {
+        self.record = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRecordInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutRecordInput, ::rusoto_core::BuildError> {
+        Ok(PutRecordInput {
+            delivery_stream_name: self
+                .delivery_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_stream_name"))?,
+            record: self
+                .record
+                .ok_or(::rusoto_core::BuildError::missing("record"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutRecordOutput {
@@ -946,7 +2425,7 @@ pub struct RedshiftDestinationConfiguration {
     /// <p>The Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<RedshiftS3BackupMode>,
     /// <p>The configuration for the intermediate Amazon S3 location from which Amazon Redshift obtains data. Restrictions are described in the topic for <a>CreateDeliveryStream</a>.</p> <p>The compression formats <code>SNAPPY</code> or <code>ZIP</code> cannot be specified in <code>RedshiftDestinationConfiguration.S3Configuration</code> because the Amazon Redshift <code>COPY</code> operation that reads from the S3 bucket doesn't support these compression formats.</p>
     #[serde(rename = "S3Configuration")]
     pub s3_configuration: S3DestinationConfiguration,
@@ -956,7 +2435,7 @@ pub struct RedshiftDestinationConfiguration {
 }
 
 /// <p>Describes a destination in Amazon Redshift.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RedshiftDestinationDescription {
     /// <p>The Amazon CloudWatch logging options for your delivery stream.</p>
@@ -987,7 +2466,7 @@ pub struct RedshiftDestinationDescription {
     /// <p>The Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<RedshiftS3BackupMode>,
     /// <p>The Amazon S3 destination.</p>
     #[serde(rename = "S3DestinationDescription")]
     pub s3_destination_description: S3DestinationDescription,
@@ -1030,7 +2509,7 @@ pub struct RedshiftDestinationUpdate {
     /// <p>The Amazon S3 backup mode.</p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<RedshiftS3BackupMode>,
     /// <p>The Amazon S3 destination for backup.</p>
     #[serde(rename = "S3BackupUpdate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1054,6 +2533,150 @@ pub struct RedshiftRetryOptions {
     pub duration_in_seconds: Option<i64>,
 }
 
+/// <p>The Amazon S3 backup mode.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RedshiftS3BackupMode {
+    /// <p><code>Disabled</code></p>
+    Disabled,
+    /// <p><code>Enabled</code></p>
+    Enabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RedshiftS3BackupMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RedshiftS3BackupMode::Disabled => "Disabled",
+            RedshiftS3BackupMode::Enabled => "Enabled",
+            RedshiftS3BackupMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RedshiftS3BackupMode {
+    fn from(value: &'a str) -> RedshiftS3BackupMode {
+        match value {
+            "Disabled" => RedshiftS3BackupMode::Disabled,
+            "Enabled" => RedshiftS3BackupMode::Enabled,
+            _ => RedshiftS3BackupMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RedshiftS3BackupMode {
+    fn from(value: String) -> RedshiftS3BackupMode {
+        RedshiftS3BackupMode::from(value.as_str())
+    }
+}
+
+impl From<RedshiftS3BackupMode> for String {
+    fn from(value: RedshiftS3BackupMode) -> String {
+        match value {
+            RedshiftS3BackupMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RedshiftS3BackupMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RedshiftS3BackupMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RedshiftS3BackupMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RedshiftS3BackupMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RedshiftS3BackupMode::from(value))
+    }
+}
+
+/// <p>The Amazon S3 backup mode.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum S3BackupMode {
+    /// <p><code>Disabled</code></p>
+    Disabled,
+    /// <p><code>Enabled</code></p>
+    Enabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl S3BackupMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            S3BackupMode::Disabled => "Disabled",
+            S3BackupMode::Enabled => "Enabled",
+            S3BackupMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for S3BackupMode {
+    fn from(value: &'a str) -> S3BackupMode {
+        match value {
+            "Disabled" => S3BackupMode::Disabled,
+            "Enabled" => S3BackupMode::Enabled,
+            _ => S3BackupMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for S3BackupMode {
+    fn from(value: String) -> S3BackupMode {
+        S3BackupMode::from(value.as_str())
+    }
+}
+
+impl From<S3BackupMode> for String {
+    fn from(value: S3BackupMode) -> String {
+        match value {
+            S3BackupMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for S3BackupMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for S3BackupMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for S3BackupMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<S3BackupMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(S3BackupMode::from(value))
+    }
+}
+
 /// <p>Describes the configuration of a destination in Amazon S3.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct S3DestinationConfiguration {
@@ -1071,7 +2694,7 @@ pub struct S3DestinationConfiguration {
     /// <p>The compression format. If no value is specified, the default is <code>UNCOMPRESSED</code>.</p> <p>The compression formats <code>SNAPPY</code> or <code>ZIP</code> cannot be specified for Amazon Redshift destinations because they are not supported by the Amazon Redshift <code>COPY</code> operation that reads from the S3 bucket.</p>
     #[serde(rename = "CompressionFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression_format: Option<String>,
+    pub compression_format: Option<CompressionFormat>,
     /// <p>The encryption configuration. If no value is specified, the default is no encryption.</p>
     #[serde(rename = "EncryptionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1090,7 +2713,7 @@ pub struct S3DestinationConfiguration {
 }
 
 /// <p>Describes a destination in Amazon S3.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct S3DestinationDescription {
     /// <p>The ARN of the S3 bucket. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a>.</p>
@@ -1105,7 +2728,7 @@ pub struct S3DestinationDescription {
     pub cloud_watch_logging_options: Option<CloudWatchLoggingOptions>,
     /// <p>The compression format. If no value is specified, the default is <code>UNCOMPRESSED</code>.</p>
     #[serde(rename = "CompressionFormat")]
-    pub compression_format: String,
+    pub compression_format: CompressionFormat,
     /// <p>The encryption configuration. If no value is specified, the default is no encryption.</p>
     #[serde(rename = "EncryptionConfiguration")]
     pub encryption_configuration: EncryptionConfiguration,
@@ -1140,7 +2763,7 @@ pub struct S3DestinationUpdate {
     /// <p>The compression format. If no value is specified, the default is <code>UNCOMPRESSED</code>.</p> <p>The compression formats <code>SNAPPY</code> or <code>ZIP</code> cannot be specified for Amazon Redshift destinations because they are not supported by the Amazon Redshift <code>COPY</code> operation that reads from the S3 bucket.</p>
     #[serde(rename = "CompressionFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compression_format: Option<String>,
+    pub compression_format: Option<CompressionFormat>,
     /// <p>The encryption configuration. If no value is specified, the default is no encryption.</p>
     #[serde(rename = "EncryptionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1212,7 +2835,7 @@ pub struct SourceDescription {
 }
 
 /// <p>Describes the configuration of a destination in Splunk.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SplunkDestinationConfiguration {
     /// <p>The Amazon CloudWatch logging options for your delivery stream.</p>
     #[serde(rename = "CloudWatchLoggingOptions")]
@@ -1227,7 +2850,7 @@ pub struct SplunkDestinationConfiguration {
     pub hec_endpoint: String,
     /// <p>This type can be either "Raw" or "Event."</p>
     #[serde(rename = "HECEndpointType")]
-    pub hec_endpoint_type: String,
+    pub hec_endpoint_type: HECEndpointType,
     /// <p>This is a GUID that you obtain from your Splunk cluster when you create a new HEC endpoint.</p>
     #[serde(rename = "HECToken")]
     pub hec_token: String,
@@ -1242,7 +2865,7 @@ pub struct SplunkDestinationConfiguration {
     /// <p>Defines how documents should be delivered to Amazon S3. When set to <code>FailedDocumentsOnly</code>, Kinesis Data Firehose writes any data that could not be indexed to the configured Amazon S3 destination. When set to <code>AllDocuments</code>, Kinesis Data Firehose delivers all incoming records to Amazon S3, and also writes failed documents to Amazon S3. Default value is <code>FailedDocumentsOnly</code>. </p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<SplunkS3BackupMode>,
     /// <p>The configuration for the backup Amazon S3 location.</p>
     #[serde(rename = "S3Configuration")]
     pub s3_configuration: S3DestinationConfiguration,
@@ -1267,7 +2890,7 @@ pub struct SplunkDestinationDescription {
     /// <p>This type can be either "Raw" or "Event."</p>
     #[serde(rename = "HECEndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hec_endpoint_type: Option<String>,
+    pub hec_endpoint_type: Option<HECEndpointType>,
     /// <p>A GUID you obtain from your Splunk cluster when you create a new HEC endpoint.</p>
     #[serde(rename = "HECToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1283,7 +2906,7 @@ pub struct SplunkDestinationDescription {
     /// <p>Defines how documents should be delivered to Amazon S3. When set to <code>FailedDocumentsOnly</code>, Kinesis Data Firehose writes any data that could not be indexed to the configured Amazon S3 destination. When set to <code>AllDocuments</code>, Kinesis Data Firehose delivers all incoming records to Amazon S3, and also writes failed documents to Amazon S3. Default value is <code>FailedDocumentsOnly</code>. </p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<SplunkS3BackupMode>,
     /// <p>The Amazon S3 destination.&gt;</p>
     #[serde(rename = "S3DestinationDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1308,7 +2931,7 @@ pub struct SplunkDestinationUpdate {
     /// <p>This type can be either "Raw" or "Event."</p>
     #[serde(rename = "HECEndpointType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hec_endpoint_type: Option<String>,
+    pub hec_endpoint_type: Option<HECEndpointType>,
     /// <p>A GUID that you obtain from your Splunk cluster when you create a new HEC endpoint.</p>
     #[serde(rename = "HECToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1324,7 +2947,7 @@ pub struct SplunkDestinationUpdate {
     /// <p>Defines how documents should be delivered to Amazon S3. When set to <code>FailedDocumentsOnly</code>, Kinesis Data Firehose writes any data that could not be indexed to the configured Amazon S3 destination. When set to <code>AllDocuments</code>, Kinesis Data Firehose delivers all incoming records to Amazon S3, and also writes failed documents to Amazon S3. Default value is <code>FailedDocumentsOnly</code>. </p>
     #[serde(rename = "S3BackupMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_backup_mode: Option<String>,
+    pub s3_backup_mode: Option<SplunkS3BackupMode>,
     /// <p>Your update to the configuration of the backup Amazon S3 location.</p>
     #[serde(rename = "S3Update")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1340,6 +2963,78 @@ pub struct SplunkRetryOptions {
     pub duration_in_seconds: Option<i64>,
 }
 
+/// <p>Defines how documents should be delivered to Amazon S3. When set to <code>FailedDocumentsOnly</code>, Kinesis Data Firehose writes any data that could not be indexed to the configured Amazon S3 destination. When set to <code>AllDocuments</code>, Kinesis Data Firehose delivers all incoming records to Amazon S3, and also writes failed documents to Amazon S3. Default value is <code>FailedDocumentsOnly</code>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SplunkS3BackupMode {
+    /// <p><code>FailedEventsOnly</code></p>
+    FailedEventsOnly,
+    /// <p><code>AllEvents</code></p>
+    AllEvents,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SplunkS3BackupMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SplunkS3BackupMode::FailedEventsOnly => "FailedEventsOnly",
+            SplunkS3BackupMode::AllEvents => "AllEvents",
+            SplunkS3BackupMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SplunkS3BackupMode {
+    fn from(value: &'a str) -> SplunkS3BackupMode {
+        match value {
+            "FailedEventsOnly" => SplunkS3BackupMode::FailedEventsOnly,
+            "AllEvents" => SplunkS3BackupMode::AllEvents,
+            _ => SplunkS3BackupMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SplunkS3BackupMode {
+    fn from(value: String) -> SplunkS3BackupMode {
+        SplunkS3BackupMode::from(value.as_str())
+    }
+}
+
+impl From<SplunkS3BackupMode> for String {
+    fn from(value: SplunkS3BackupMode) -> String {
+        match value {
+            SplunkS3BackupMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SplunkS3BackupMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SplunkS3BackupMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SplunkS3BackupMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SplunkS3BackupMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SplunkS3BackupMode::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartDeliveryStreamEncryptionInput {
     /// <p>The name of the delivery stream for which you want to enable server-side encryption (SSE).</p>
@@ -1347,6 +3042,38 @@ pub struct StartDeliveryStreamEncryptionInput {
     pub delivery_stream_name: String,
 }
 
+impl StartDeliveryStreamEncryptionInput {
+    /// Returns a builder for `StartDeliveryStreamEncryptionInput`, checking its required members when built.
+    pub fn builder() -> StartDeliveryStreamEncryptionInputBuilder {
+        StartDeliveryStreamEncryptionInputBuilder::default()
+    }
+}
+
+/// Builds a [`StartDeliveryStreamEncryptionInput`](struct.StartDeliveryStreamEncryptionInput.html).
+#[derive(Default, Debug)]
+pub struct StartDeliveryStreamEncryptionInputBuilder {
+    delivery_stream_name: Option<String>,
+}
+
+impl StartDeliveryStreamEncryptionInputBuilder {
+    /// Sets the [`delivery_stream_name`](struct.StartDeliveryStreamEncryptionInput.html#structfield.delivery_stream_name) member.
+    pub fn delivery_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartDeliveryStreamEncryptionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartDeliveryStreamEncryptionInput, ::rusoto_core::BuildError> {
+        Ok(StartDeliveryStreamEncryptionInput {
+            delivery_stream_name: self
+                .delivery_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_stream_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartDeliveryStreamEncryptionOutput {}
@@ -1358,6 +3085,38 @@ pub struct StopDeliveryStreamEncryptionInput {
     pub delivery_stream_name: String,
 }
 
+impl StopDeliveryStreamEncryptionInput {
+    /// Returns a builder for `StopDeliveryStreamEncryptionInput`, checking its required members when built.
+    pub fn builder() -> StopDeliveryStreamEncryptionInputBuilder {
+        StopDeliveryStreamEncryptionInputBuilder::default()
+    }
+}
+
+/// Builds a [`StopDeliveryStreamEncryptionInput`](struct.StopDeliveryStreamEncryptionInput.html).
+#[derive(Default, Debug)]
+pub struct StopDeliveryStreamEncryptionInputBuilder {
+    delivery_stream_name: Option<String>,
+}
+
+impl StopDeliveryStreamEncryptionInputBuilder {
+    /// Sets the [`delivery_stream_name`](struct.StopDeliveryStreamEncryptionInput.html#structfield.delivery_stream_name) member.
+    pub fn delivery_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopDeliveryStreamEncryptionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopDeliveryStreamEncryptionInput, ::rusoto_core::BuildError> {
+        Ok(StopDeliveryStreamEncryptionInput {
+            delivery_stream_name: self
+                .delivery_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_stream_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopDeliveryStreamEncryptionOutput {}
@@ -1384,6 +3143,46 @@ pub struct TagDeliveryStreamInput {
     pub tags: Vec<Tag>,
 }
 
+impl TagDeliveryStreamInput {
+    /// Returns a builder for `TagDeliveryStreamInput`, checking its required members when built.
+    pub fn builder() -> TagDeliveryStreamInputBuilder {
+        TagDeliveryStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagDeliveryStreamInput`](struct.TagDeliveryStreamInput.html).
+#[derive(Default, Debug)]
+pub struct TagDeliveryStreamInputBuilder {
+    delivery_stream_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagDeliveryStreamInputBuilder {
+    /// Sets the [`delivery_stream_name`](struct.TagDeliveryStreamInput.html#structfield.delivery_stream_name) member.
+    pub fn delivery_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagDeliveryStreamInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagDeliveryStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagDeliveryStreamInput, ::rusoto_core::BuildError> {
+        Ok(TagDeliveryStreamInput {
+            delivery_stream_name: self
+                .delivery_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_stream_name"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagDeliveryStreamOutput {}
@@ -1398,6 +3197,48 @@ pub struct UntagDeliveryStreamInput {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagDeliveryStreamInput {
+    /// Returns a builder for `UntagDeliveryStreamInput`, checking its required members when built.
+    pub fn builder() -> UntagDeliveryStreamInputBuilder {
+        UntagDeliveryStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagDeliveryStreamInput`](struct.UntagDeliveryStreamInput.html).
+#[derive(Default, Debug)]
+pub struct UntagDeliveryStreamInputBuilder {
+    delivery_stream_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagDeliveryStreamInputBuilder {
+    /// Sets the [`delivery_stream_name`](struct.UntagDeliveryStreamInput.html#structfield.delivery_stream_name) member.
+    pub fn delivery_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagDeliveryStreamInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagDeliveryStreamInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UntagDeliveryStreamInput, ::rusoto_core::BuildError> {
+        Ok(UntagDeliveryStreamInput {
+            delivery_stream_name: self
+                .delivery_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_stream_name"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagDeliveryStreamOutput {}
@@ -1431,6 +3272,97 @@ pub struct UpdateDestinationInput {
     pub splunk_destination_update: Option<SplunkDestinationUpdate>,
 }
 
+impl UpdateDestinationInput {
+    /// Returns a builder for `UpdateDestinationInput`, checking its required members when built.
+    pub fn builder() -> UpdateDestinationInputBuilder {
+        UpdateDestinationInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDestinationInput`](struct.UpdateDestinationInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateDestinationInputBuilder {
+    current_delivery_stream_version_id: Option<String>,
+    delivery_stream_name: Option<String>,
+    destination_id: Option<String>,
+    elasticsearch_destination_update: Option<ElasticsearchDestinationUpdate>,
+    extended_s3_destination_update: Option<ExtendedS3DestinationUpdate>,
+    redshift_destination_update: Option<RedshiftDestinationUpdate>,
+    splunk_destination_update: Option<SplunkDestinationUpdate>,
+}
+
+impl UpdateDestinationInputBuilder {
+    /// Sets the [`current_delivery_stream_version_id`](struct.UpdateDestinationInput.html#structfield.current_delivery_stream_version_id) member.
+    pub fn current_delivery_stream_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.current_delivery_stream_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delivery_stream_name`](struct.UpdateDestinationInput.html#structfield.delivery_stream_name) member.
+    pub fn delivery_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.delivery_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_id`](struct.UpdateDestinationInput.html#structfield.destination_id) member.
+    pub fn destination_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`elasticsearch_destination_update`](struct.UpdateDestinationInput.html#structfield.elasticsearch_destination_update) member.
+    pub fn elasticsearch_destination_update<T: Into<ElasticsearchDestinationUpdate>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.elasticsearch_destination_update = Some(value.into());
+        self
+    }
+
+    /// Sets the [`extended_s3_destination_update`](struct.UpdateDestinationInput.html#structfield.extended_s3_destination_update) member.
+    pub fn extended_s3_destination_update<T: Into<ExtendedS3DestinationUpdate>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.extended_s3_destination_update = Some(value.into());
+        self
+    }
+
+    /// Sets the [`redshift_destination_update`](struct.UpdateDestinationInput.html#structfield.redshift_destination_update) member.
+    pub fn redshift_destination_update<T: Into<RedshiftDestinationUpdate>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.redshift_destination_update = Some(value.into());
+        self
+    }
+
+    /// Sets the [`splunk_destination_update`](struct.UpdateDestinationInput.html#structfield.splunk_destination_update) member.
+    pub fn splunk_destination_update<T: Into<SplunkDestinationUpdate>>(mut self, value: T) -> Self {
+        self.splunk_destination_update = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDestinationInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateDestinationInput, ::rusoto_core::BuildError> {
+        Ok(UpdateDestinationInput {
+            current_delivery_stream_version_id: self.current_delivery_stream_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("current_delivery_stream_version_id"),
+            )?,
+            delivery_stream_name: self
+                .delivery_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("delivery_stream_name"))?,
+            destination_id: self
+                .destination_id
+                .ok_or(::rusoto_core::BuildError::missing("destination_id"))?,
+            elasticsearch_destination_update: self.elasticsearch_destination_update,
+            extended_s3_destination_update: self.extended_s3_destination_update,
+            redshift_destination_update: self.redshift_destination_update,
+            splunk_destination_update: self.splunk_destination_update,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDestinationOutput {}
@@ -2074,6 +4006,84 @@ pub trait KinesisFirehose {
         input: UpdateDestinationInput,
     ) -> RusotoFuture<UpdateDestinationOutput, UpdateDestinationError>;
 }
+impl<T: ?Sized + KinesisFirehose> KinesisFirehose for ::std::sync::Arc<T> {
+    fn create_delivery_stream(
+        &self,
+        input: CreateDeliveryStreamInput,
+    ) -> RusotoFuture<CreateDeliveryStreamOutput, CreateDeliveryStreamError> {
+        (**self).create_delivery_stream(input)
+    }
+    fn delete_delivery_stream(
+        &self,
+        input: DeleteDeliveryStreamInput,
+    ) -> RusotoFuture<DeleteDeliveryStreamOutput, DeleteDeliveryStreamError> {
+        (**self).delete_delivery_stream(input)
+    }
+    fn describe_delivery_stream(
+        &self,
+        input: DescribeDeliveryStreamInput,
+    ) -> RusotoFuture<DescribeDeliveryStreamOutput, DescribeDeliveryStreamError> {
+        (**self).describe_delivery_stream(input)
+    }
+    fn list_delivery_streams(
+        &self,
+        input: ListDeliveryStreamsInput,
+    ) -> RusotoFuture<ListDeliveryStreamsOutput, ListDeliveryStreamsError> {
+        (**self).list_delivery_streams(input)
+    }
+    fn list_tags_for_delivery_stream(
+        &self,
+        input: ListTagsForDeliveryStreamInput,
+    ) -> RusotoFuture<ListTagsForDeliveryStreamOutput, ListTagsForDeliveryStreamError> {
+        (**self).list_tags_for_delivery_stream(input)
+    }
+    fn put_record(&self, input: PutRecordInput) -> RusotoFuture<PutRecordOutput, PutRecordError> {
+        (**self).put_record(input)
+    }
+    fn put_record_batch(
+        &self,
+        input: PutRecordBatchInput,
+    ) -> RusotoFuture<PutRecordBatchOutput, PutRecordBatchError> {
+        (**self).put_record_batch(input)
+    }
+    fn start_delivery_stream_encryption(
+        &self,
+        input: StartDeliveryStreamEncryptionInput,
+    ) -> RusotoFuture<StartDeliveryStreamEncryptionOutput, StartDeliveryStreamEncryptionError> {
+        (**self).start_delivery_stream_encryption(input)
+    }
+    fn stop_delivery_stream_encryption(
+        &self,
+        input: StopDeliveryStreamEncryptionInput,
+    ) -> RusotoFuture<StopDeliveryStreamEncryptionOutput, StopDeliveryStreamEncryptionError> {
+        (**self).stop_delivery_stream_encryption(input)
+    }
+    fn tag_delivery_stream(
+        &self,
+        input: TagDeliveryStreamInput,
+    ) -> RusotoFuture<TagDeliveryStreamOutput, TagDeliveryStreamError> {
+        (**self).tag_delivery_stream(input)
+    }
+    fn untag_delivery_stream(
+        &self,
+        input: UntagDeliveryStreamInput,
+    ) -> RusotoFuture<UntagDeliveryStreamOutput, UntagDeliveryStreamError> {
+        (**self).untag_delivery_stream(input)
+    }
+    fn update_destination(
+        &self,
+        input: UpdateDestinationInput,
+    ) -> RusotoFuture<UpdateDestinationOutput, UpdateDestinationError> {
+        (**self).update_destination(input)
+    }
+}
+/// The error codes Firehose retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Firehose API.
 #[derive(Clone)]
 pub struct KinesisFirehoseClient {
@@ -2089,6 +4099,27 @@ impl KinesisFirehoseClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_FIREHOSE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> KinesisFirehoseClient {
+        Self::new(region::Region::from_env_for_service("FIREHOSE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `firehose`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> KinesisFirehoseClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("firehose", "FIREHOSE"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2107,7 +4138,54 @@ impl KinesisFirehoseClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisFirehoseClient {
-        KinesisFirehoseClient { client, region }
+        KinesisFirehoseClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "firehose")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "firehose",
+            "firehose",
+            "FIREHOSE",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `KinesisFirehose` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn KinesisFirehose + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for KinesisFirehoseClient {
+    fn service_name(&self) -> &'static str {
+        "Firehose"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "firehose"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> KinesisFirehoseClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Firewall Management Service clients.
+//!
+//! Makes a single read-only `ListMemberAccounts` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_fms::{Fms, FmsClient, ListMemberAccountsRequest};
+
+fn main() {
+    let client = FmsClient::from_env();
+    match client
+        .list_member_accounts(ListMemberAccountsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListMemberAccounts succeeded"),
+        Err(error) => {
+            eprintln!("ListMemberAccounts failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,7 +22,92 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The status of the AWS account that you set as the AWS Firewall Manager administrator.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccountRoleStatus {
+    /// <p><code>READY</code></p>
+    Ready,
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>PENDING_DELETION</code></p>
+    PendingDeletion,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AccountRoleStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountRoleStatus::Ready => "READY",
+            AccountRoleStatus::Creating => "CREATING",
+            AccountRoleStatus::PendingDeletion => "PENDING_DELETION",
+            AccountRoleStatus::Deleting => "DELETING",
+            AccountRoleStatus::Deleted => "DELETED",
+            AccountRoleStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AccountRoleStatus {
+    fn from(value: &'a str) -> AccountRoleStatus {
+        match value {
+            "READY" => AccountRoleStatus::Ready,
+            "CREATING" => AccountRoleStatus::Creating,
+            "PENDING_DELETION" => AccountRoleStatus::PendingDeletion,
+            "DELETING" => AccountRoleStatus::Deleting,
+            "DELETED" => AccountRoleStatus::Deleted,
+            _ => AccountRoleStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountRoleStatus {
+    fn from(value: String) -> AccountRoleStatus {
+        AccountRoleStatus::from(value.as_str())
+    }
+}
+
+impl From<AccountRoleStatus> for String {
+    fn from(value: AccountRoleStatus) -> String {
+        match value {
+            AccountRoleStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AccountRoleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AccountRoleStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccountRoleStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AccountRoleStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AccountRoleStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateAdminAccountRequest {
     /// <p>The AWS account ID to associate with AWS Firewall Manager as the AWS Firewall Manager administrator account. This can be an AWS Organizations master account or a member account. For more information about AWS Organizations and master accounts, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts.html">Managing the AWS Accounts in Your Organization</a>.</p>
@@ -30,6 +115,38 @@ pub struct AssociateAdminAccountRequest {
     pub admin_account: String,
 }
 
+impl AssociateAdminAccountRequest {
+    /// Returns a builder for `AssociateAdminAccountRequest`, checking its required members when built.
+    pub fn builder() -> AssociateAdminAccountRequestBuilder {
+        AssociateAdminAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateAdminAccountRequest`](struct.AssociateAdminAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateAdminAccountRequestBuilder {
+    admin_account: Option<String>,
+}
+
+impl AssociateAdminAccountRequestBuilder {
+    /// Sets the [`admin_account`](struct.AssociateAdminAccountRequest.html#structfield.admin_account) member.
+    pub fn admin_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.admin_account = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateAdminAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateAdminAccountRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateAdminAccountRequest {
+            admin_account: self
+                .admin_account
+                .ok_or(::rusoto_core::BuildError::missing("admin_account"))?,
+        })
+    }
+}
+
 /// <p>Details of the resource that is not protected by the policy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -45,7 +162,79 @@ pub struct ComplianceViolator {
     /// <p>The reason that the resource is not protected by the policy.</p>
     #[serde(rename = "ViolationReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub violation_reason: Option<String>,
+    pub violation_reason: Option<ViolationReason>,
+}
+
+/// <p>Specifies the AWS account IDs to exclude from the policy. The <code>IncludeMap</code> values are evaluated first, with all the appropriate account IDs added to the policy. Then the accounts listed in <code>ExcludeMap</code> are removed, resulting in the final list of accounts to add to the policy.</p> <p>The key to the map is <code>ACCOUNT</code>. For example, a valid <code>ExcludeMap</code> would be <code>{“ACCOUNT” : [“accountID1”, “accountID2”]}</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CustomerPolicyScopeIdType {
+    /// <p><code>ACCOUNT</code></p>
+    Account,
+    /// <p><code>ORG_UNIT</code></p>
+    OrgUnit,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CustomerPolicyScopeIdType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CustomerPolicyScopeIdType::Account => "ACCOUNT",
+            CustomerPolicyScopeIdType::OrgUnit => "ORG_UNIT",
+            CustomerPolicyScopeIdType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CustomerPolicyScopeIdType {
+    fn from(value: &'a str) -> CustomerPolicyScopeIdType {
+        match value {
+            "ACCOUNT" => CustomerPolicyScopeIdType::Account,
+            "ORG_UNIT" => CustomerPolicyScopeIdType::OrgUnit,
+            _ => CustomerPolicyScopeIdType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CustomerPolicyScopeIdType {
+    fn from(value: String) -> CustomerPolicyScopeIdType {
+        CustomerPolicyScopeIdType::from(value.as_str())
+    }
+}
+
+impl From<CustomerPolicyScopeIdType> for String {
+    fn from(value: CustomerPolicyScopeIdType) -> String {
+        match value {
+            CustomerPolicyScopeIdType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CustomerPolicyScopeIdType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CustomerPolicyScopeIdType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CustomerPolicyScopeIdType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CustomerPolicyScopeIdType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CustomerPolicyScopeIdType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -62,6 +251,124 @@ pub struct DeletePolicyRequest {
     pub policy_id: String,
 }
 
+impl DeletePolicyRequest {
+    /// Returns a builder for `DeletePolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeletePolicyRequestBuilder {
+        DeletePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePolicyRequest`](struct.DeletePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePolicyRequestBuilder {
+    delete_all_policy_resources: Option<bool>,
+    policy_id: Option<String>,
+}
+
+impl DeletePolicyRequestBuilder {
+    /// Sets the [`delete_all_policy_resources`](struct.DeletePolicyRequest.html#structfield.delete_all_policy_resources) member.
+    pub fn delete_all_policy_resources<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_all_policy_resources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_id`](struct.DeletePolicyRequest.html#structfield.policy_id) member.
+    pub fn policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePolicyRequest {
+            delete_all_policy_resources: self.delete_all_policy_resources,
+            policy_id: self
+                .policy_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_id"))?,
+        })
+    }
+}
+
+/// <p>Details about problems with dependent services, such as AWS WAF or AWS Config, that are causing a resource to be non-compliant. The details include the name of the dependent service and the error message received that indicates the problem with the service.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DependentServiceName {
+    /// <p><code>AWSCONFIG</code></p>
+    Awsconfig,
+    /// <p><code>AWSWAF</code></p>
+    Awswaf,
+    /// <p><code>AWSSHIELD_ADVANCED</code></p>
+    AwsshieldAdvanced,
+    /// <p><code>AWSVPC</code></p>
+    Awsvpc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DependentServiceName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DependentServiceName::Awsconfig => "AWSCONFIG",
+            DependentServiceName::Awswaf => "AWSWAF",
+            DependentServiceName::AwsshieldAdvanced => "AWSSHIELD_ADVANCED",
+            DependentServiceName::Awsvpc => "AWSVPC",
+            DependentServiceName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DependentServiceName {
+    fn from(value: &'a str) -> DependentServiceName {
+        match value {
+            "AWSCONFIG" => DependentServiceName::Awsconfig,
+            "AWSWAF" => DependentServiceName::Awswaf,
+            "AWSSHIELD_ADVANCED" => DependentServiceName::AwsshieldAdvanced,
+            "AWSVPC" => DependentServiceName::Awsvpc,
+            _ => DependentServiceName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DependentServiceName {
+    fn from(value: String) -> DependentServiceName {
+        DependentServiceName::from(value.as_str())
+    }
+}
+
+impl From<DependentServiceName> for String {
+    fn from(value: DependentServiceName) -> String {
+        match value {
+            DependentServiceName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DependentServiceName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DependentServiceName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DependentServiceName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DependentServiceName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DependentServiceName::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DisassociateAdminAccountRequest {}
 
@@ -72,7 +379,7 @@ pub struct EvaluationResult {
     /// <p>Describes an AWS account's compliance with the AWS Firewall Manager policy.</p>
     #[serde(rename = "ComplianceStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compliance_status: Option<String>,
+    pub compliance_status: Option<PolicyComplianceStatusType>,
     /// <p>Indicates that over 100 resources are non-compliant with the AWS Firewall Manager policy.</p>
     #[serde(rename = "EvaluationLimitExceeded")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -96,7 +403,7 @@ pub struct GetAdminAccountResponse {
     /// <p>The status of the AWS account that you set as the AWS Firewall Manager administrator.</p>
     #[serde(rename = "RoleStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role_status: Option<String>,
+    pub role_status: Option<AccountRoleStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -109,6 +416,48 @@ pub struct GetComplianceDetailRequest {
     pub policy_id: String,
 }
 
+impl GetComplianceDetailRequest {
+    /// Returns a builder for `GetComplianceDetailRequest`, checking its required members when built.
+    pub fn builder() -> GetComplianceDetailRequestBuilder {
+        GetComplianceDetailRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetComplianceDetailRequest`](struct.GetComplianceDetailRequest.html).
+#[derive(Default, Debug)]
+pub struct GetComplianceDetailRequestBuilder {
+    member_account: Option<String>,
+    policy_id: Option<String>,
+}
+
+impl GetComplianceDetailRequestBuilder {
+    /// Sets the [`member_account`](struct.GetComplianceDetailRequest.html#structfield.member_account) member.
+    pub fn member_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.member_account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_id`](struct.GetComplianceDetailRequest.html#structfield.policy_id) member.
+    pub fn policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetComplianceDetailRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetComplianceDetailRequest, ::rusoto_core::BuildError> {
+        Ok(GetComplianceDetailRequest {
+            member_account: self
+                .member_account
+                .ok_or(::rusoto_core::BuildError::missing("member_account"))?,
+            policy_id: self
+                .policy_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetComplianceDetailResponse {
@@ -141,6 +490,36 @@ pub struct GetPolicyRequest {
     pub policy_id: String,
 }
 
+impl GetPolicyRequest {
+    /// Returns a builder for `GetPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetPolicyRequestBuilder {
+        GetPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPolicyRequest`](struct.GetPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPolicyRequestBuilder {
+    policy_id: Option<String>,
+}
+
+impl GetPolicyRequestBuilder {
+    /// Sets the [`policy_id`](struct.GetPolicyRequest.html#structfield.policy_id) member.
+    pub fn policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetPolicyRequest {
+            policy_id: self
+                .policy_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPolicyResponse {
@@ -158,8 +537,9 @@ pub struct GetPolicyResponse {
 pub struct GetProtectionStatusRequest {
     /// <p>The end of the time period to query for the attacks. This is a <code>timestamp</code> type. The sample request above indicates a number type because the default used by AWS Firewall Manager is Unix time in seconds. However, any valid <code>timestamp</code> format is allowed.</p>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>Specifies the number of objects that you want AWS Firewall Manager to return for this request. If you have more objects than the number that you specify for <code>MaxResults</code>, the response includes a <code>NextToken</code> value that you can use to get another batch of objects.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -177,8 +557,81 @@ pub struct GetProtectionStatusRequest {
     pub policy_id: String,
     /// <p>The start of the time period to query for the attacks. This is a <code>timestamp</code> type. The sample request above indicates a number type because the default used by AWS Firewall Manager is Unix time in seconds. However, any valid <code>timestamp</code> format is allowed.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
+}
+
+impl GetProtectionStatusRequest {
+    /// Returns a builder for `GetProtectionStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetProtectionStatusRequestBuilder {
+        GetProtectionStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetProtectionStatusRequest`](struct.GetProtectionStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetProtectionStatusRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    max_results: Option<i64>,
+    member_account_id: Option<String>,
+    next_token: Option<String>,
+    policy_id: Option<String>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl GetProtectionStatusRequestBuilder {
+    /// Sets the [`end_time`](struct.GetProtectionStatusRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetProtectionStatusRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`member_account_id`](struct.GetProtectionStatusRequest.html#structfield.member_account_id) member.
+    pub fn member_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.member_account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetProtectionStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_id`](struct.GetProtectionStatusRequest.html#structfield.policy_id) member.
+    pub fn policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetProtectionStatusRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetProtectionStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetProtectionStatusRequest, ::rusoto_core::BuildError> {
+        Ok(GetProtectionStatusRequest {
+            end_time: self.end_time,
+            max_results: self.max_results,
+            member_account_id: self.member_account_id,
+            next_token: self.next_token,
+            policy_id: self
+                .policy_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_id"))?,
+            start_time: self.start_time,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -199,7 +652,7 @@ pub struct GetProtectionStatusResponse {
     /// <p>The service type that is protected by the policy. Currently, this is always <code>SHIELD_ADVANCED</code>.</p>
     #[serde(rename = "ServiceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub service_type: Option<String>,
+    pub service_type: Option<SecurityServiceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -217,6 +670,54 @@ pub struct ListComplianceStatusRequest {
     pub policy_id: String,
 }
 
+impl ListComplianceStatusRequest {
+    /// Returns a builder for `ListComplianceStatusRequest`, checking its required members when built.
+    pub fn builder() -> ListComplianceStatusRequestBuilder {
+        ListComplianceStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListComplianceStatusRequest`](struct.ListComplianceStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct ListComplianceStatusRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    policy_id: Option<String>,
+}
+
+impl ListComplianceStatusRequestBuilder {
+    /// Sets the [`max_results`](struct.ListComplianceStatusRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListComplianceStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_id`](struct.ListComplianceStatusRequest.html#structfield.policy_id) member.
+    pub fn policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListComplianceStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListComplianceStatusRequest, ::rusoto_core::BuildError> {
+        Ok(ListComplianceStatusRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            policy_id: self
+                .policy_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListComplianceStatusResponse {
@@ -242,6 +743,44 @@ pub struct ListMemberAccountsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListMemberAccountsRequest {
+    /// Returns a builder for `ListMemberAccountsRequest`, checking its required members when built.
+    pub fn builder() -> ListMemberAccountsRequestBuilder {
+        ListMemberAccountsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListMemberAccountsRequest`](struct.ListMemberAccountsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListMemberAccountsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListMemberAccountsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListMemberAccountsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListMemberAccountsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListMemberAccountsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListMemberAccountsRequest, ::rusoto_core::BuildError> {
+        Ok(ListMemberAccountsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListMemberAccountsResponse {
@@ -267,6 +806,42 @@ pub struct ListPoliciesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListPoliciesRequest {
+    /// Returns a builder for `ListPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListPoliciesRequestBuilder {
+        ListPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPoliciesRequest`](struct.ListPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPoliciesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListPoliciesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListPoliciesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPoliciesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListPoliciesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPoliciesResponse {
@@ -281,19 +856,19 @@ pub struct ListPoliciesResponse {
 }
 
 /// <p>An AWS Firewall Manager policy.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Policy {
     /// <p>Specifies the AWS account IDs to exclude from the policy. The <code>IncludeMap</code> values are evaluated first, with all the appropriate account IDs added to the policy. Then the accounts listed in <code>ExcludeMap</code> are removed, resulting in the final list of accounts to add to the policy.</p> <p>The key to the map is <code>ACCOUNT</code>. For example, a valid <code>ExcludeMap</code> would be <code>{“ACCOUNT” : [“accountID1”, “accountID2”]}</code>.</p>
     #[serde(rename = "ExcludeMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub exclude_map: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub exclude_map: Option<::std::collections::HashMap<CustomerPolicyScopeIdType, Vec<String>>>,
     /// <p>If set to <code>True</code>, resources with the tags that are specified in the <code>ResourceTag</code> array are not protected by the policy. If set to <code>False</code>, and the <code>ResourceTag</code> array is not null, only resources with the specified tags are associated with the policy.</p>
     #[serde(rename = "ExcludeResourceTags")]
     pub exclude_resource_tags: bool,
     /// <p>Specifies the AWS account IDs to include in the policy. If <code>IncludeMap</code> is null, all accounts in the organization in AWS Organizations are included in the policy. If <code>IncludeMap</code> is not null, only values listed in <code>IncludeMap</code> are included in the policy.</p> <p>The key to the map is <code>ACCOUNT</code>. For example, a valid <code>IncludeMap</code> would be <code>{“ACCOUNT” : [“accountID1”, “accountID2”]}</code>.</p>
     #[serde(rename = "IncludeMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub include_map: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub include_map: Option<::std::collections::HashMap<CustomerPolicyScopeIdType, Vec<String>>>,
     /// <p>The ID of the AWS Firewall Manager policy.</p>
     #[serde(rename = "PolicyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -334,12 +909,13 @@ pub struct PolicyComplianceDetail {
     pub evaluation_limit_exceeded: Option<bool>,
     /// <p>A time stamp that indicates when the returned information should be considered out-of-date.</p>
     #[serde(rename = "ExpiredAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expired_at: Option<f64>,
+    pub expired_at: Option<EpochTimestamp>,
     /// <p>Details about problems with dependent services, such as AWS WAF or AWS Config, that are causing a resource to be non-compliant. The details include the name of the dependent service and the error message received that indicates the problem with the service.</p>
     #[serde(rename = "IssueInfoMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issue_info_map: Option<::std::collections::HashMap<String, String>>,
+    pub issue_info_map: Option<::std::collections::HashMap<DependentServiceName, String>>,
     /// <p>The AWS account ID.</p>
     #[serde(rename = "MemberAccount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -369,11 +945,12 @@ pub struct PolicyComplianceStatus {
     /// <p>Details about problems with dependent services, such as AWS WAF or AWS Config, that are causing a resource to be non-compliant. The details include the name of the dependent service and the error message received that indicates the problem with the service.</p>
     #[serde(rename = "IssueInfoMap")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issue_info_map: Option<::std::collections::HashMap<String, String>>,
+    pub issue_info_map: Option<::std::collections::HashMap<DependentServiceName, String>>,
     /// <p>Time stamp of the last update to the <code>EvaluationResult</code> objects.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<EpochTimestamp>,
     /// <p>The member account ID.</p>
     #[serde(rename = "MemberAccount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -392,6 +969,80 @@ pub struct PolicyComplianceStatus {
     pub policy_owner: Option<String>,
 }
 
+/// <p>Describes an AWS account's compliance with the AWS Firewall Manager policy.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyComplianceStatusType {
+    /// <p><code>COMPLIANT</code></p>
+    Compliant,
+    /// <p><code>NON_COMPLIANT</code></p>
+    NonCompliant,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PolicyComplianceStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyComplianceStatusType::Compliant => "COMPLIANT",
+            PolicyComplianceStatusType::NonCompliant => "NON_COMPLIANT",
+            PolicyComplianceStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PolicyComplianceStatusType {
+    fn from(value: &'a str) -> PolicyComplianceStatusType {
+        match value {
+            "COMPLIANT" => PolicyComplianceStatusType::Compliant,
+            "NON_COMPLIANT" => PolicyComplianceStatusType::NonCompliant,
+            _ => PolicyComplianceStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyComplianceStatusType {
+    fn from(value: String) -> PolicyComplianceStatusType {
+        PolicyComplianceStatusType::from(value.as_str())
+    }
+}
+
+impl From<PolicyComplianceStatusType> for String {
+    fn from(value: PolicyComplianceStatusType) -> String {
+        match value {
+            PolicyComplianceStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PolicyComplianceStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PolicyComplianceStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyComplianceStatusType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<PolicyComplianceStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PolicyComplianceStatusType::from(value))
+    }
+}
+
 /// <p>Details of the AWS Firewall Manager policy. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -419,7 +1070,7 @@ pub struct PolicySummary {
     /// <p>The service that the policy is using to protect the resources. This specifies the type of policy that is created, either a WAF policy or Shield Advanced policy.</p>
     #[serde(rename = "SecurityServiceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub security_service_type: Option<String>,
+    pub security_service_type: Option<SecurityServiceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -432,13 +1083,85 @@ pub struct PutNotificationChannelRequest {
     pub sns_topic_arn: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl PutNotificationChannelRequest {
+    /// Returns a builder for `PutNotificationChannelRequest`, checking its required members when built.
+    pub fn builder() -> PutNotificationChannelRequestBuilder {
+        PutNotificationChannelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutNotificationChannelRequest`](struct.PutNotificationChannelRequest.html).
+#[derive(Default, Debug)]
+pub struct PutNotificationChannelRequestBuilder {
+    sns_role_name: Option<String>,
+    sns_topic_arn: Option<String>,
+}
+
+impl PutNotificationChannelRequestBuilder {
+    /// Sets the [`sns_role_name`](struct.PutNotificationChannelRequest.html#structfield.sns_role_name) member.
+    pub fn sns_role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.PutNotificationChannelRequest.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutNotificationChannelRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutNotificationChannelRequest, ::rusoto_core::BuildError> {
+        Ok(PutNotificationChannelRequest {
+            sns_role_name: self
+                .sns_role_name
+                .ok_or(::rusoto_core::BuildError::missing("sns_role_name"))?,
+            sns_topic_arn: self
+                .sns_topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("sns_topic_arn"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutPolicyRequest {
     /// <p>The details of the AWS Firewall Manager policy to be created.</p>
     #[serde(rename = "Policy")]
     pub policy: Policy,
 }
 
+impl PutPolicyRequest {
+    /// Returns a builder for `PutPolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutPolicyRequestBuilder {
+        PutPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutPolicyRequest`](struct.PutPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutPolicyRequestBuilder {
+    policy: Option<Policy>,
+}
+
+impl PutPolicyRequestBuilder {
+    /// Sets the [`policy`](struct.PutPolicyRequest.html#structfield.policy) member.
+    pub fn policy<T: Into<Policy>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutPolicyRequest {
+            policy: self
+                .policy
+                .ok_or(::rusoto_core::BuildError::missing("policy"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutPolicyResponse {
@@ -465,7 +1188,7 @@ pub struct ResourceTag {
 }
 
 /// <p>Details about the security service that is being used to protect the resources.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SecurityServicePolicyData {
     /// <p>Details about the service. This contains <code>WAF</code> data in JSON format, as shown in the following example:</p> <p> <code>ManagedServiceData": "{\"type\": \"WAF\", \"ruleGroups\": [{\"id\": \"12345678-1bcd-9012-efga-0987654321ab\", \"overrideAction\" : {\"type\": \"COUNT\"}}], \"defaultAction\": {\"type\": \"BLOCK\"}}</code> </p> <p>If this is a Shield Advanced policy, this string will be empty.</p>
     #[serde(rename = "ManagedServiceData")]
@@ -473,7 +1196,319 @@ pub struct SecurityServicePolicyData {
     pub managed_service_data: Option<String>,
     /// <p>The service that the policy is using to protect the resources. This specifies the type of policy that is created, either a WAF policy or Shield Advanced policy.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: SecurityServiceType,
+}
+
+/// <p>The service type that is protected by the policy. Currently, this is always <code>SHIELD_ADVANCED</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SecurityServiceType {
+    /// <p><code>WAF</code></p>
+    Waf,
+    /// <p><code>WAFV2</code></p>
+    Wafv2,
+    /// <p><code>SHIELD_ADVANCED</code></p>
+    ShieldAdvanced,
+    /// <p><code>SECURITY_GROUPS_COMMON</code></p>
+    SecurityGroupsCommon,
+    /// <p><code>SECURITY_GROUPS_CONTENT_AUDIT</code></p>
+    SecurityGroupsContentAudit,
+    /// <p><code>SECURITY_GROUPS_USAGE_AUDIT</code></p>
+    SecurityGroupsUsageAudit,
+    /// <p><code>NETWORK_FIREWALL</code></p>
+    NetworkFirewall,
+    /// <p><code>DNS_FIREWALL</code></p>
+    DnsFirewall,
+    /// <p><code>THIRD_PARTY_FIREWALL</code></p>
+    ThirdPartyFirewall,
+    /// <p><code>IMPORT_NETWORK_FIREWALL</code></p>
+    ImportNetworkFirewall,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SecurityServiceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SecurityServiceType::Waf => "WAF",
+            SecurityServiceType::Wafv2 => "WAFV2",
+            SecurityServiceType::ShieldAdvanced => "SHIELD_ADVANCED",
+            SecurityServiceType::SecurityGroupsCommon => "SECURITY_GROUPS_COMMON",
+            SecurityServiceType::SecurityGroupsContentAudit => "SECURITY_GROUPS_CONTENT_AUDIT",
+            SecurityServiceType::SecurityGroupsUsageAudit => "SECURITY_GROUPS_USAGE_AUDIT",
+            SecurityServiceType::NetworkFirewall => "NETWORK_FIREWALL",
+            SecurityServiceType::DnsFirewall => "DNS_FIREWALL",
+            SecurityServiceType::ThirdPartyFirewall => "THIRD_PARTY_FIREWALL",
+            SecurityServiceType::ImportNetworkFirewall => "IMPORT_NETWORK_FIREWALL",
+            SecurityServiceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SecurityServiceType {
+    fn from(value: &'a str) -> SecurityServiceType {
+        match value {
+            "WAF" => SecurityServiceType::Waf,
+            "WAFV2" => SecurityServiceType::Wafv2,
+            "SHIELD_ADVANCED" => SecurityServiceType::ShieldAdvanced,
+            "SECURITY_GROUPS_COMMON" => SecurityServiceType::SecurityGroupsCommon,
+            "SECURITY_GROUPS_CONTENT_AUDIT" => SecurityServiceType::SecurityGroupsContentAudit,
+            "SECURITY_GROUPS_USAGE_AUDIT" => SecurityServiceType::SecurityGroupsUsageAudit,
+            "NETWORK_FIREWALL" => SecurityServiceType::NetworkFirewall,
+            "DNS_FIREWALL" => SecurityServiceType::DnsFirewall,
+            "THIRD_PARTY_FIREWALL" => SecurityServiceType::ThirdPartyFirewall,
+            "IMPORT_NETWORK_FIREWALL" => SecurityServiceType::ImportNetworkFirewall,
+            _ => SecurityServiceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SecurityServiceType {
+    fn from(value: String) -> SecurityServiceType {
+        SecurityServiceType::from(value.as_str())
+    }
+}
+
+impl From<SecurityServiceType> for String {
+    fn from(value: SecurityServiceType) -> String {
+        match value {
+            SecurityServiceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SecurityServiceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SecurityServiceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SecurityServiceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SecurityServiceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SecurityServiceType::from(value))
+    }
+}
+
+/// <p>The reason that the resource is not protected by the policy.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ViolationReason {
+    /// <p><code>WEB_ACL_MISSING_RULE_GROUP</code></p>
+    WebAclMissingRuleGroup,
+    /// <p><code>RESOURCE_MISSING_WEB_ACL</code></p>
+    ResourceMissingWebAcl,
+    /// <p><code>RESOURCE_INCORRECT_WEB_ACL</code></p>
+    ResourceIncorrectWebAcl,
+    /// <p><code>RESOURCE_MISSING_SHIELD_PROTECTION</code></p>
+    ResourceMissingShieldProtection,
+    /// <p><code>RESOURCE_MISSING_WEB_ACL_OR_SHIELD_PROTECTION</code></p>
+    ResourceMissingWebAclOrShieldProtection,
+    /// <p><code>RESOURCE_MISSING_SECURITY_GROUP</code></p>
+    ResourceMissingSecurityGroup,
+    /// <p><code>RESOURCE_VIOLATES_AUDIT_SECURITY_GROUP</code></p>
+    ResourceViolatesAuditSecurityGroup,
+    /// <p><code>SECURITY_GROUP_UNUSED</code></p>
+    SecurityGroupUnused,
+    /// <p><code>SECURITY_GROUP_REDUNDANT</code></p>
+    SecurityGroupRedundant,
+    /// <p><code>FMS_CREATED_SECURITY_GROUP_EDITED</code></p>
+    FmsCreatedSecurityGroupEdited,
+    /// <p><code>MISSING_FIREWALL</code></p>
+    MissingFirewall,
+    /// <p><code>MISSING_FIREWALL_SUBNET_IN_AZ</code></p>
+    MissingFirewallSubnetInAz,
+    /// <p><code>MISSING_EXPECTED_ROUTE_TABLE</code></p>
+    MissingExpectedRouteTable,
+    /// <p><code>NETWORK_FIREWALL_POLICY_MODIFIED</code></p>
+    NetworkFirewallPolicyModified,
+    /// <p><code>FIREWALL_SUBNET_IS_OUT_OF_SCOPE</code></p>
+    FirewallSubnetIsOutOfScope,
+    /// <p><code>INTERNET_GATEWAY_MISSING_EXPECTED_ROUTE</code></p>
+    InternetGatewayMissingExpectedRoute,
+    /// <p><code>FIREWALL_SUBNET_MISSING_EXPECTED_ROUTE</code></p>
+    FirewallSubnetMissingExpectedRoute,
+    /// <p><code>UNEXPECTED_FIREWALL_ROUTES</code></p>
+    UnexpectedFirewallRoutes,
+    /// <p><code>UNEXPECTED_TARGET_GATEWAY_ROUTES</code></p>
+    UnexpectedTargetGatewayRoutes,
+    /// <p><code>TRAFFIC_INSPECTION_CROSSES_AZ_BOUNDARY</code></p>
+    TrafficInspectionCrossesAzBoundary,
+    /// <p><code>INVALID_ROUTE_CONFIGURATION</code></p>
+    InvalidRouteConfiguration,
+    /// <p><code>MISSING_TARGET_GATEWAY</code></p>
+    MissingTargetGateway,
+    /// <p><code>INTERNET_TRAFFIC_NOT_INSPECTED</code></p>
+    InternetTrafficNotInspected,
+    /// <p><code>BLACK_HOLE_ROUTE_DETECTED</code></p>
+    BlackHoleRouteDetected,
+    /// <p><code>BLACK_HOLE_ROUTE_DETECTED_IN_FIREWALL_SUBNET</code></p>
+    BlackHoleRouteDetectedInFirewallSubnet,
+    /// <p><code>RESOURCE_MISSING_DNS_FIREWALL</code></p>
+    ResourceMissingDnsFirewall,
+    /// <p><code>ROUTE_HAS_OUT_OF_SCOPE_ENDPOINT</code></p>
+    RouteHasOutOfScopeEndpoint,
+    /// <p><code>FIREWALL_SUBNET_MISSING_VPCE_ENDPOINT</code></p>
+    FirewallSubnetMissingVpceEndpoint,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ViolationReason {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ViolationReason::WebAclMissingRuleGroup => "WEB_ACL_MISSING_RULE_GROUP",
+            ViolationReason::ResourceMissingWebAcl => "RESOURCE_MISSING_WEB_ACL",
+            ViolationReason::ResourceIncorrectWebAcl => "RESOURCE_INCORRECT_WEB_ACL",
+            ViolationReason::ResourceMissingShieldProtection => {
+                "RESOURCE_MISSING_SHIELD_PROTECTION"
+            }
+            ViolationReason::ResourceMissingWebAclOrShieldProtection => {
+                "RESOURCE_MISSING_WEB_ACL_OR_SHIELD_PROTECTION"
+            }
+            ViolationReason::ResourceMissingSecurityGroup => "RESOURCE_MISSING_SECURITY_GROUP",
+            ViolationReason::ResourceViolatesAuditSecurityGroup => {
+                "RESOURCE_VIOLATES_AUDIT_SECURITY_GROUP"
+            }
+            ViolationReason::SecurityGroupUnused => "SECURITY_GROUP_UNUSED",
+            ViolationReason::SecurityGroupRedundant => "SECURITY_GROUP_REDUNDANT",
+            ViolationReason::FmsCreatedSecurityGroupEdited => "FMS_CREATED_SECURITY_GROUP_EDITED",
+            ViolationReason::MissingFirewall => "MISSING_FIREWALL",
+            ViolationReason::MissingFirewallSubnetInAz => "MISSING_FIREWALL_SUBNET_IN_AZ",
+            ViolationReason::MissingExpectedRouteTable => "MISSING_EXPECTED_ROUTE_TABLE",
+            ViolationReason::NetworkFirewallPolicyModified => "NETWORK_FIREWALL_POLICY_MODIFIED",
+            ViolationReason::FirewallSubnetIsOutOfScope => "FIREWALL_SUBNET_IS_OUT_OF_SCOPE",
+            ViolationReason::InternetGatewayMissingExpectedRoute => {
+                "INTERNET_GATEWAY_MISSING_EXPECTED_ROUTE"
+            }
+            ViolationReason::FirewallSubnetMissingExpectedRoute => {
+                "FIREWALL_SUBNET_MISSING_EXPECTED_ROUTE"
+            }
+            ViolationReason::UnexpectedFirewallRoutes => "UNEXPECTED_FIREWALL_ROUTES",
+            ViolationReason::UnexpectedTargetGatewayRoutes => "UNEXPECTED_TARGET_GATEWAY_ROUTES",
+            ViolationReason::TrafficInspectionCrossesAzBoundary => {
+                "TRAFFIC_INSPECTION_CROSSES_AZ_BOUNDARY"
+            }
+            ViolationReason::InvalidRouteConfiguration => "INVALID_ROUTE_CONFIGURATION",
+            ViolationReason::MissingTargetGateway => "MISSING_TARGET_GATEWAY",
+            ViolationReason::InternetTrafficNotInspected => "INTERNET_TRAFFIC_NOT_INSPECTED",
+            ViolationReason::BlackHoleRouteDetected => "BLACK_HOLE_ROUTE_DETECTED",
+            ViolationReason::BlackHoleRouteDetectedInFirewallSubnet => {
+                "BLACK_HOLE_ROUTE_DETECTED_IN_FIREWALL_SUBNET"
+            }
+            ViolationReason::ResourceMissingDnsFirewall => "RESOURCE_MISSING_DNS_FIREWALL",
+            ViolationReason::RouteHasOutOfScopeEndpoint => "ROUTE_HAS_OUT_OF_SCOPE_ENDPOINT",
+            ViolationReason::FirewallSubnetMissingVpceEndpoint => {
+                "FIREWALL_SUBNET_MISSING_VPCE_ENDPOINT"
+            }
+            ViolationReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ViolationReason {
+    fn from(value: &'a str) -> ViolationReason {
+        match value {
+            "WEB_ACL_MISSING_RULE_GROUP" => ViolationReason::WebAclMissingRuleGroup,
+            "RESOURCE_MISSING_WEB_ACL" => ViolationReason::ResourceMissingWebAcl,
+            "RESOURCE_INCORRECT_WEB_ACL" => ViolationReason::ResourceIncorrectWebAcl,
+            "RESOURCE_MISSING_SHIELD_PROTECTION" => {
+                ViolationReason::ResourceMissingShieldProtection
+            }
+            "RESOURCE_MISSING_WEB_ACL_OR_SHIELD_PROTECTION" => {
+                ViolationReason::ResourceMissingWebAclOrShieldProtection
+            }
+            "RESOURCE_MISSING_SECURITY_GROUP" => ViolationReason::ResourceMissingSecurityGroup,
+            "RESOURCE_VIOLATES_AUDIT_SECURITY_GROUP" => {
+                ViolationReason::ResourceViolatesAuditSecurityGroup
+            }
+            "SECURITY_GROUP_UNUSED" => ViolationReason::SecurityGroupUnused,
+            "SECURITY_GROUP_REDUNDANT" => ViolationReason::SecurityGroupRedundant,
+            "FMS_CREATED_SECURITY_GROUP_EDITED" => ViolationReason::FmsCreatedSecurityGroupEdited,
+            "MISSING_FIREWALL" => ViolationReason::MissingFirewall,
+            "MISSING_FIREWALL_SUBNET_IN_AZ" => ViolationReason::MissingFirewallSubnetInAz,
+            "MISSING_EXPECTED_ROUTE_TABLE" => ViolationReason::MissingExpectedRouteTable,
+            "NETWORK_FIREWALL_POLICY_MODIFIED" => ViolationReason::NetworkFirewallPolicyModified,
+            "FIREWALL_SUBNET_IS_OUT_OF_SCOPE" => ViolationReason::FirewallSubnetIsOutOfScope,
+            "INTERNET_GATEWAY_MISSING_EXPECTED_ROUTE" => {
+                ViolationReason::InternetGatewayMissingExpectedRoute
+            }
+            "FIREWALL_SUBNET_MISSING_EXPECTED_ROUTE" => {
+                ViolationReason::FirewallSubnetMissingExpectedRoute
+            }
+            "UNEXPECTED_FIREWALL_ROUTES" => ViolationReason::UnexpectedFirewallRoutes,
+            "UNEXPECTED_TARGET_GATEWAY_ROUTES" => ViolationReason::UnexpectedTargetGatewayRoutes,
+            "TRAFFIC_INSPECTION_CROSSES_AZ_BOUNDARY" => {
+                ViolationReason::TrafficInspectionCrossesAzBoundary
+            }
+            "INVALID_ROUTE_CONFIGURATION" => ViolationReason::InvalidRouteConfiguration,
+            "MISSING_TARGET_GATEWAY" => ViolationReason::MissingTargetGateway,
+            "INTERNET_TRAFFIC_NOT_INSPECTED" => ViolationReason::InternetTrafficNotInspected,
+            "BLACK_HOLE_ROUTE_DETECTED" => ViolationReason::BlackHoleRouteDetected,
+            "BLACK_HOLE_ROUTE_DETECTED_IN_FIREWALL_SUBNET" => {
+                ViolationReason::BlackHoleRouteDetectedInFirewallSubnet
+            }
+            "RESOURCE_MISSING_DNS_FIREWALL" => ViolationReason::ResourceMissingDnsFirewall,
+            "ROUTE_HAS_OUT_OF_SCOPE_ENDPOINT" => ViolationReason::RouteHasOutOfScopeEndpoint,
+            "FIREWALL_SUBNET_MISSING_VPCE_ENDPOINT" => {
+                ViolationReason::FirewallSubnetMissingVpceEndpoint
+            }
+            _ => ViolationReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ViolationReason {
+    fn from(value: String) -> ViolationReason {
+        ViolationReason::from(value.as_str())
+    }
+}
+
+impl From<ViolationReason> for String {
+    fn from(value: ViolationReason) -> String {
+        match value {
+            ViolationReason::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ViolationReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ViolationReason {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ViolationReason {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ViolationReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ViolationReason::from(value))
+    }
 }
 
 /// Errors returned by AssociateAdminAccount
@@ -1231,6 +2266,86 @@ pub trait Fms {
         input: PutPolicyRequest,
     ) -> RusotoFuture<PutPolicyResponse, PutPolicyError>;
 }
+impl<T: ?Sized + Fms> Fms for ::std::sync::Arc<T> {
+    fn associate_admin_account(
+        &self,
+        input: AssociateAdminAccountRequest,
+    ) -> RusotoFuture<(), AssociateAdminAccountError> {
+        (**self).associate_admin_account(input)
+    }
+    fn delete_notification_channel(&self) -> RusotoFuture<(), DeleteNotificationChannelError> {
+        (**self).delete_notification_channel()
+    }
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        (**self).delete_policy(input)
+    }
+    fn disassociate_admin_account(&self) -> RusotoFuture<(), DisassociateAdminAccountError> {
+        (**self).disassociate_admin_account()
+    }
+    fn get_admin_account(&self) -> RusotoFuture<GetAdminAccountResponse, GetAdminAccountError> {
+        (**self).get_admin_account()
+    }
+    fn get_compliance_detail(
+        &self,
+        input: GetComplianceDetailRequest,
+    ) -> RusotoFuture<GetComplianceDetailResponse, GetComplianceDetailError> {
+        (**self).get_compliance_detail(input)
+    }
+    fn get_notification_channel(
+        &self,
+    ) -> RusotoFuture<GetNotificationChannelResponse, GetNotificationChannelError> {
+        (**self).get_notification_channel()
+    }
+    fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        (**self).get_policy(input)
+    }
+    fn get_protection_status(
+        &self,
+        input: GetProtectionStatusRequest,
+    ) -> RusotoFuture<GetProtectionStatusResponse, GetProtectionStatusError> {
+        (**self).get_protection_status(input)
+    }
+    fn list_compliance_status(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> RusotoFuture<ListComplianceStatusResponse, ListComplianceStatusError> {
+        (**self).list_compliance_status(input)
+    }
+    fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> RusotoFuture<ListMemberAccountsResponse, ListMemberAccountsError> {
+        (**self).list_member_accounts(input)
+    }
+    fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        (**self).list_policies(input)
+    }
+    fn put_notification_channel(
+        &self,
+        input: PutNotificationChannelRequest,
+    ) -> RusotoFuture<(), PutNotificationChannelError> {
+        (**self).put_notification_channel(input)
+    }
+    fn put_policy(
+        &self,
+        input: PutPolicyRequest,
+    ) -> RusotoFuture<PutPolicyResponse, PutPolicyError> {
+        (**self).put_policy(input)
+    }
+}
+/// The error codes FMS retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the FMS API.
 #[derive(Clone)]
 pub struct FmsClient {
@@ -1246,6 +2361,24 @@ impl FmsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_FMS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> FmsClient {
+        Self::new(region::Region::from_env_for_service("FMS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `fms`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> FmsClient {
+        Self::new_with_client(config.client().clone(), config.region_for("fms", "FMS"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1264,7 +2397,49 @@ impl FmsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> FmsClient {
-        FmsClient { client, region }
+        FmsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "fms")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "fms", "fms", "FMS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Fms` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Fms + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for FmsClient {
+    fn service_name(&self) -> &'static str {
+        "FMS"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "fms"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> FmsClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>The Microsoft AD attributes of the Amazon FSx for Windows File Server file system.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -38,7 +39,7 @@ pub struct ActiveDirectoryBackupAttributes {
 }
 
 /// <p>A backup of an Amazon FSx for Windows File Server file system. You can create a new file system from a backup to protect against data loss.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Backup {
     /// <p>The ID of the backup.</p>
@@ -46,7 +47,8 @@ pub struct Backup {
     pub backup_id: String,
     /// <p>The time when a particular backup was created.</p>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub creation_time: EpochTimestamp,
     /// <p>The configuration of the self-managed Microsoft Active Directory (AD) to which the Windows File Server instance is joined.</p>
     #[serde(rename = "DirectoryInformation")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -64,7 +66,7 @@ pub struct Backup {
     pub kms_key_id: Option<String>,
     /// <p>The lifecycle status of the backup.</p>
     #[serde(rename = "Lifecycle")]
-    pub lifecycle: String,
+    pub lifecycle: BackupLifecycle,
     #[serde(rename = "ProgressPercent")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub progress_percent: Option<i64>,
@@ -78,7 +80,7 @@ pub struct Backup {
     pub tags: Option<Vec<Tag>>,
     /// <p>The type of the backup.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: BackupType,
 }
 
 /// <p>If backup creation fails, this structure contains the details of that failure.</p>
@@ -91,6 +93,174 @@ pub struct BackupFailureDetails {
     pub message: Option<String>,
 }
 
+/// <p>The lifecycle status of the backup.</p> <ul> <li> <p> <code>AVAILABLE</code> - The backup is fully available.</p> </li> <li> <p> <code>PENDING</code> - For user-initiated backups on Lustre file systems only; Amazon FSx hasn't started creating the backup.</p> </li> <li> <p> <code>CREATING</code> - Amazon FSx is creating the new user-initiated backup.</p> </li> <li> <p> <code>TRANSFERRING</code> - For user-initiated backups on Lustre file systems only; Amazon FSx is backing up the file system.</p> </li> <li> <p> <code>COPYING</code> - Amazon FSx is copying the backup.</p> </li> <li> <p> <code>DELETED</code> - Amazon FSx deleted the backup and it's no longer available.</p> </li> <li> <p> <code>FAILED</code> - Amazon FSx couldn't finish the backup.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BackupLifecycle {
+    /// <p><code>AVAILABLE</code> - The backup is fully available.</p>
+    Available,
+    /// <p><code>CREATING</code> - Amazon FSx is creating the new user-initiated backup.</p>
+    Creating,
+    /// <p><code>TRANSFERRING</code> - For user-initiated backups on Lustre file systems only; Amazon FSx is backing up the file system.</p>
+    Transferring,
+    /// <p><code>DELETED</code> - Amazon FSx deleted the backup and it's no longer available.</p>
+    Deleted,
+    /// <p><code>FAILED</code> - Amazon FSx couldn't finish the backup.</p>
+    Failed,
+    /// <p><code>PENDING</code> - For user-initiated backups on Lustre file systems only; Amazon FSx hasn't started creating the backup.</p>
+    Pending,
+    /// <p><code>COPYING</code> - Amazon FSx is copying the backup.</p>
+    Copying,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BackupLifecycle {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupLifecycle::Available => "AVAILABLE",
+            BackupLifecycle::Creating => "CREATING",
+            BackupLifecycle::Transferring => "TRANSFERRING",
+            BackupLifecycle::Deleted => "DELETED",
+            BackupLifecycle::Failed => "FAILED",
+            BackupLifecycle::Pending => "PENDING",
+            BackupLifecycle::Copying => "COPYING",
+            BackupLifecycle::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BackupLifecycle {
+    fn from(value: &'a str) -> BackupLifecycle {
+        match value {
+            "AVAILABLE" => BackupLifecycle::Available,
+            "CREATING" => BackupLifecycle::Creating,
+            "TRANSFERRING" => BackupLifecycle::Transferring,
+            "DELETED" => BackupLifecycle::Deleted,
+            "FAILED" => BackupLifecycle::Failed,
+            "PENDING" => BackupLifecycle::Pending,
+            "COPYING" => BackupLifecycle::Copying,
+            _ => BackupLifecycle::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupLifecycle {
+    fn from(value: String) -> BackupLifecycle {
+        BackupLifecycle::from(value.as_str())
+    }
+}
+
+impl From<BackupLifecycle> for String {
+    fn from(value: BackupLifecycle) -> String {
+        match value {
+            BackupLifecycle::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BackupLifecycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BackupLifecycle {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupLifecycle {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BackupLifecycle, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BackupLifecycle::from(value))
+    }
+}
+
+/// <p>The type of the backup.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BackupType {
+    /// <p><code>AUTOMATIC</code></p>
+    Automatic,
+    /// <p><code>USER_INITIATED</code></p>
+    UserInitiated,
+    /// <p><code>AWS_BACKUP</code></p>
+    AwsBackup,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BackupType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupType::Automatic => "AUTOMATIC",
+            BackupType::UserInitiated => "USER_INITIATED",
+            BackupType::AwsBackup => "AWS_BACKUP",
+            BackupType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BackupType {
+    fn from(value: &'a str) -> BackupType {
+        match value {
+            "AUTOMATIC" => BackupType::Automatic,
+            "USER_INITIATED" => BackupType::UserInitiated,
+            "AWS_BACKUP" => BackupType::AwsBackup,
+            _ => BackupType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupType {
+    fn from(value: String) -> BackupType {
+        BackupType::from(value.as_str())
+    }
+}
+
+impl From<BackupType> for String {
+    fn from(value: BackupType) -> String {
+        match value {
+            BackupType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BackupType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BackupType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BackupType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BackupType::from(value))
+    }
+}
+
 /// <p>The request object for the <code>CreateBackup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateBackupRequest {
@@ -107,6 +277,52 @@ pub struct CreateBackupRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateBackupRequest {
+    /// Returns a builder for `CreateBackupRequest`, checking its required members when built.
+    pub fn builder() -> CreateBackupRequestBuilder {
+        CreateBackupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBackupRequest`](struct.CreateBackupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateBackupRequestBuilder {
+    client_request_token: Option<String>,
+    file_system_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateBackupRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateBackupRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_system_id`](struct.CreateBackupRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateBackupRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBackupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateBackupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateBackupRequest {
+            client_request_token: self.client_request_token,
+            file_system_id: self
+                .file_system_id
+                .ok_or(::rusoto_core::BuildError::missing("file_system_id"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p>The response object for the <code>CreateBackup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -143,6 +359,83 @@ pub struct CreateFileSystemFromBackupRequest {
     pub windows_configuration: Option<CreateFileSystemWindowsConfiguration>,
 }
 
+impl CreateFileSystemFromBackupRequest {
+    /// Returns a builder for `CreateFileSystemFromBackupRequest`, checking its required members when built.
+    pub fn builder() -> CreateFileSystemFromBackupRequestBuilder {
+        CreateFileSystemFromBackupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFileSystemFromBackupRequest`](struct.CreateFileSystemFromBackupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFileSystemFromBackupRequestBuilder {
+    backup_id: Option<String>,
+    client_request_token: Option<String>,
+    security_group_ids: Option<Vec<String>>,
+    subnet_ids: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+    windows_configuration: Option<CreateFileSystemWindowsConfiguration>,
+}
+
+impl CreateFileSystemFromBackupRequestBuilder {
+    /// Sets the [`backup_id`](struct.CreateFileSystemFromBackupRequest.html#structfield.backup_id) member.
+    pub fn backup_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_request_token`](struct.CreateFileSystemFromBackupRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_group_ids`](struct.CreateFileSystemFromBackupRequest.html#structfield.security_group_ids) member.
+    pub fn security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.CreateFileSystemFromBackupRequest.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateFileSystemFromBackupRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`windows_configuration`](struct.CreateFileSystemFromBackupRequest.html#structfield.windows_configuration) member.
+    pub fn windows_configuration<T: Into<CreateFileSystemWindowsConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.windows_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFileSystemFromBackupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateFileSystemFromBackupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateFileSystemFromBackupRequest {
+            backup_id: self
+                .backup_id
+                .ok_or(::rusoto_core::BuildError::missing("backup_id"))?,
+            client_request_token: self.client_request_token,
+            security_group_ids: self.security_group_ids,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+            tags: self.tags,
+            windows_configuration: self.windows_configuration,
+        })
+    }
+}
+
 /// <p>The response object for the <code>CreateFileSystemFromBackup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -175,7 +468,7 @@ pub struct CreateFileSystemLustreConfiguration {
 }
 
 /// <p>The request object used to create a new Amazon FSx file system.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateFileSystemRequest {
     /// <p>(Optional) A string of up to 64 ASCII characters that Amazon FSx uses to ensure idempotent creation. This string is automatically filled on your behalf when you use the AWS Command Line Interface (AWS CLI) or an AWS SDK.</p>
     #[serde(rename = "ClientRequestToken")]
@@ -183,7 +476,7 @@ pub struct CreateFileSystemRequest {
     pub client_request_token: Option<String>,
     /// <p>The type of Amazon FSx file system to create.</p>
     #[serde(rename = "FileSystemType")]
-    pub file_system_type: String,
+    pub file_system_type: FileSystemType,
     #[serde(rename = "KmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key_id: Option<String>,
@@ -210,6 +503,112 @@ pub struct CreateFileSystemRequest {
     pub windows_configuration: Option<CreateFileSystemWindowsConfiguration>,
 }
 
+impl CreateFileSystemRequest {
+    /// Returns a builder for `CreateFileSystemRequest`, checking its required members when built.
+    pub fn builder() -> CreateFileSystemRequestBuilder {
+        CreateFileSystemRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFileSystemRequest`](struct.CreateFileSystemRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFileSystemRequestBuilder {
+    client_request_token: Option<String>,
+    file_system_type: Option<FileSystemType>,
+    kms_key_id: Option<String>,
+    lustre_configuration: Option<CreateFileSystemLustreConfiguration>,
+    security_group_ids: Option<Vec<String>>,
+    storage_capacity: Option<i64>,
+    subnet_ids: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+    windows_configuration: Option<CreateFileSystemWindowsConfiguration>,
+}
+
+impl CreateFileSystemRequestBuilder {
+    /// Sets the [`client_request_token`](struct.CreateFileSystemRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_system_type`](struct.CreateFileSystemRequest.html#structfield.file_system_type) member.
+    pub fn file_system_type<T: Into<FileSystemType>>(mut self, value: T) -> Self {
+        self.file_system_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateFileSystemRequest.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lustre_configuration`](struct.CreateFileSystemRequest.html#structfield.lustre_configuration) member.
+    pub fn lustre_configuration<T: Into<CreateFileSystemLustreConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.lustre_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_group_ids`](struct.CreateFileSystemRequest.html#structfield.security_group_ids) member.
+    pub fn security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_capacity`](struct.CreateFileSystemRequest.html#structfield.storage_capacity) member.
+    pub fn storage_capacity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.storage_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.CreateFileSystemRequest.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateFileSystemRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`windows_configuration`](struct.CreateFileSystemRequest.html#structfield.windows_configuration) member.
+    pub fn windows_configuration<T: Into<CreateFileSystemWindowsConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.windows_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFileSystemRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateFileSystemRequest, ::rusoto_core::BuildError> {
+        Ok(CreateFileSystemRequest {
+            client_request_token: self.client_request_token,
+            file_system_type: self
+                .file_system_type
+                .ok_or(::rusoto_core::BuildError::missing("file_system_type"))?,
+            kms_key_id: self.kms_key_id,
+            lustre_configuration: self.lustre_configuration,
+            security_group_ids: self.security_group_ids,
+            storage_capacity: self
+                .storage_capacity
+                .ok_or(::rusoto_core::BuildError::missing("storage_capacity"))?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+            tags: self.tags,
+            windows_configuration: self.windows_configuration,
+        })
+    }
+}
+
 /// <p>The response object returned after the file system is created.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -282,6 +681,44 @@ pub struct DeleteBackupRequest {
     pub client_request_token: Option<String>,
 }
 
+impl DeleteBackupRequest {
+    /// Returns a builder for `DeleteBackupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBackupRequestBuilder {
+        DeleteBackupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBackupRequest`](struct.DeleteBackupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBackupRequestBuilder {
+    backup_id: Option<String>,
+    client_request_token: Option<String>,
+}
+
+impl DeleteBackupRequestBuilder {
+    /// Sets the [`backup_id`](struct.DeleteBackupRequest.html#structfield.backup_id) member.
+    pub fn backup_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_request_token`](struct.DeleteBackupRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBackupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteBackupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBackupRequest {
+            backup_id: self
+                .backup_id
+                .ok_or(::rusoto_core::BuildError::missing("backup_id"))?,
+            client_request_token: self.client_request_token,
+        })
+    }
+}
+
 /// <p>The response object for <code>DeleteBackup</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -293,7 +730,7 @@ pub struct DeleteBackupResponse {
     /// <p>The lifecycle of the backup. Should be <code>DELETED</code>.</p>
     #[serde(rename = "Lifecycle")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lifecycle: Option<String>,
+    pub lifecycle: Option<BackupLifecycle>,
 }
 
 /// <p>The request object for <code>DeleteFileSystem</code> operation.</p>
@@ -311,6 +748,57 @@ pub struct DeleteFileSystemRequest {
     pub windows_configuration: Option<DeleteFileSystemWindowsConfiguration>,
 }
 
+impl DeleteFileSystemRequest {
+    /// Returns a builder for `DeleteFileSystemRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFileSystemRequestBuilder {
+        DeleteFileSystemRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFileSystemRequest`](struct.DeleteFileSystemRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFileSystemRequestBuilder {
+    client_request_token: Option<String>,
+    file_system_id: Option<String>,
+    windows_configuration: Option<DeleteFileSystemWindowsConfiguration>,
+}
+
+impl DeleteFileSystemRequestBuilder {
+    /// Sets the [`client_request_token`](struct.DeleteFileSystemRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_system_id`](struct.DeleteFileSystemRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`windows_configuration`](struct.DeleteFileSystemRequest.html#structfield.windows_configuration) member.
+    pub fn windows_configuration<T: Into<DeleteFileSystemWindowsConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.windows_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFileSystemRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteFileSystemRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFileSystemRequest {
+            client_request_token: self.client_request_token,
+            file_system_id: self
+                .file_system_id
+                .ok_or(::rusoto_core::BuildError::missing("file_system_id"))?,
+            windows_configuration: self.windows_configuration,
+        })
+    }
+}
+
 /// <p>The response object for the <code>DeleteFileSystem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -322,7 +810,7 @@ pub struct DeleteFileSystemResponse {
     /// <p>The file system lifecycle for the deletion request. Should be <code>DELETING</code>.</p>
     #[serde(rename = "Lifecycle")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lifecycle: Option<String>,
+    pub lifecycle: Option<FileSystemLifecycle>,
     #[serde(rename = "WindowsResponse")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub windows_response: Option<DeleteFileSystemWindowsResponse>,
@@ -376,6 +864,58 @@ pub struct DescribeBackupsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeBackupsRequest {
+    /// Returns a builder for `DescribeBackupsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeBackupsRequestBuilder {
+        DescribeBackupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBackupsRequest`](struct.DescribeBackupsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeBackupsRequestBuilder {
+    backup_ids: Option<Vec<String>>,
+    filters: Option<Vec<Filter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeBackupsRequestBuilder {
+    /// Sets the [`backup_ids`](struct.DescribeBackupsRequest.html#structfield.backup_ids) member.
+    pub fn backup_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.backup_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeBackupsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeBackupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeBackupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBackupsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeBackupsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeBackupsRequest {
+            backup_ids: self.backup_ids,
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>Response object for <code>DescribeBackups</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -407,6 +947,52 @@ pub struct DescribeFileSystemsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeFileSystemsRequest {
+    /// Returns a builder for `DescribeFileSystemsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeFileSystemsRequestBuilder {
+        DescribeFileSystemsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeFileSystemsRequest`](struct.DescribeFileSystemsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeFileSystemsRequestBuilder {
+    file_system_ids: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeFileSystemsRequestBuilder {
+    /// Sets the [`file_system_ids`](struct.DescribeFileSystemsRequest.html#structfield.file_system_ids) member.
+    pub fn file_system_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.file_system_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeFileSystemsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeFileSystemsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeFileSystemsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeFileSystemsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeFileSystemsRequest {
+            file_system_ids: self.file_system_ids,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p>The response object for <code>DescribeFileSystems</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -427,8 +1013,9 @@ pub struct DescribeFileSystemsResponse {
 pub struct FileSystem {
     /// <p>The time that the file system was created, in seconds (since 1970-01-01T00:00:00Z), also known as Unix time.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The DNS name for the file system.</p>
     #[serde(rename = "DNSName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -443,7 +1030,7 @@ pub struct FileSystem {
     /// <p>The type of Amazon FSx file system, either <code>LUSTRE</code> or <code>WINDOWS</code>.</p>
     #[serde(rename = "FileSystemType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_system_type: Option<String>,
+    pub file_system_type: Option<FileSystemType>,
     /// <p>The ID of the AWS Key Management Service (AWS KMS) key used to encrypt the file system's data for an Amazon FSx for Windows File Server file system.</p>
     #[serde(rename = "KmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -451,7 +1038,7 @@ pub struct FileSystem {
     /// <p><p>The lifecycle status of the file system:</p> <ul> <li> <p> <code>AVAILABLE</code> indicates that the file system is reachable and available for use.</p> </li> <li> <p> <code>CREATING</code> indicates that Amazon FSx is in the process of creating the new file system.</p> </li> <li> <p> <code>DELETING</code> indicates that Amazon FSx is in the process of deleting the file system.</p> </li> <li> <p> <code>FAILED</code> indicates that Amazon FSx was not able to create the file system.</p> </li> <li> <p> <code>MISCONFIGURED</code> indicates that the file system is in a failed but recoverable state.</p> </li> <li> <p> <code>UPDATING</code> indicates that the file system is undergoing a customer initiated update.</p> </li> </ul></p>
     #[serde(rename = "Lifecycle")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lifecycle: Option<String>,
+    pub lifecycle: Option<FileSystemLifecycle>,
     #[serde(rename = "LustreConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lustre_configuration: Option<LustreFileSystemConfiguration>,
@@ -499,19 +1086,357 @@ pub struct FileSystemFailureDetails {
     pub message: Option<String>,
 }
 
+/// <p>The lifecycle status of the file system.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FileSystemLifecycle {
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>MISCONFIGURED</code></p>
+    Misconfigured,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>MISCONFIGURED_UNAVAILABLE</code></p>
+    MisconfiguredUnavailable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FileSystemLifecycle {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileSystemLifecycle::Available => "AVAILABLE",
+            FileSystemLifecycle::Creating => "CREATING",
+            FileSystemLifecycle::Failed => "FAILED",
+            FileSystemLifecycle::Deleting => "DELETING",
+            FileSystemLifecycle::Misconfigured => "MISCONFIGURED",
+            FileSystemLifecycle::Updating => "UPDATING",
+            FileSystemLifecycle::MisconfiguredUnavailable => "MISCONFIGURED_UNAVAILABLE",
+            FileSystemLifecycle::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FileSystemLifecycle {
+    fn from(value: &'a str) -> FileSystemLifecycle {
+        match value {
+            "AVAILABLE" => FileSystemLifecycle::Available,
+            "CREATING" => FileSystemLifecycle::Creating,
+            "FAILED" => FileSystemLifecycle::Failed,
+            "DELETING" => FileSystemLifecycle::Deleting,
+            "MISCONFIGURED" => FileSystemLifecycle::Misconfigured,
+            "UPDATING" => FileSystemLifecycle::Updating,
+            "MISCONFIGURED_UNAVAILABLE" => FileSystemLifecycle::MisconfiguredUnavailable,
+            _ => FileSystemLifecycle::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileSystemLifecycle {
+    fn from(value: String) -> FileSystemLifecycle {
+        FileSystemLifecycle::from(value.as_str())
+    }
+}
+
+impl From<FileSystemLifecycle> for String {
+    fn from(value: FileSystemLifecycle) -> String {
+        match value {
+            FileSystemLifecycle::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FileSystemLifecycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FileSystemLifecycle {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileSystemLifecycle {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FileSystemLifecycle, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FileSystemLifecycle::from(value))
+    }
+}
+
+/// <p>An enumeration specifying the currently ongoing maintenance operation.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FileSystemMaintenanceOperation {
+    /// <p><code>PATCHING</code></p>
+    Patching,
+    /// <p><code>BACKING_UP</code></p>
+    BackingUp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FileSystemMaintenanceOperation {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileSystemMaintenanceOperation::Patching => "PATCHING",
+            FileSystemMaintenanceOperation::BackingUp => "BACKING_UP",
+            FileSystemMaintenanceOperation::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FileSystemMaintenanceOperation {
+    fn from(value: &'a str) -> FileSystemMaintenanceOperation {
+        match value {
+            "PATCHING" => FileSystemMaintenanceOperation::Patching,
+            "BACKING_UP" => FileSystemMaintenanceOperation::BackingUp,
+            _ => FileSystemMaintenanceOperation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileSystemMaintenanceOperation {
+    fn from(value: String) -> FileSystemMaintenanceOperation {
+        FileSystemMaintenanceOperation::from(value.as_str())
+    }
+}
+
+impl From<FileSystemMaintenanceOperation> for String {
+    fn from(value: FileSystemMaintenanceOperation) -> String {
+        match value {
+            FileSystemMaintenanceOperation::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FileSystemMaintenanceOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FileSystemMaintenanceOperation {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileSystemMaintenanceOperation {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<FileSystemMaintenanceOperation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FileSystemMaintenanceOperation::from(value))
+    }
+}
+
+/// <p>The type of file system.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FileSystemType {
+    /// <p><code>WINDOWS</code></p>
+    Windows,
+    /// <p><code>LUSTRE</code></p>
+    Lustre,
+    /// <p><code>ONTAP</code></p>
+    Ontap,
+    /// <p><code>OPENZFS</code></p>
+    Openzfs,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FileSystemType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileSystemType::Windows => "WINDOWS",
+            FileSystemType::Lustre => "LUSTRE",
+            FileSystemType::Ontap => "ONTAP",
+            FileSystemType::Openzfs => "OPENZFS",
+            FileSystemType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FileSystemType {
+    fn from(value: &'a str) -> FileSystemType {
+        match value {
+            "WINDOWS" => FileSystemType::Windows,
+            "LUSTRE" => FileSystemType::Lustre,
+            "ONTAP" => FileSystemType::Ontap,
+            "OPENZFS" => FileSystemType::Openzfs,
+            _ => FileSystemType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileSystemType {
+    fn from(value: String) -> FileSystemType {
+        FileSystemType::from(value.as_str())
+    }
+}
+
+impl From<FileSystemType> for String {
+    fn from(value: FileSystemType) -> String {
+        match value {
+            FileSystemType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FileSystemType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FileSystemType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileSystemType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FileSystemType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FileSystemType::from(value))
+    }
+}
+
 /// <p>A filter used to restrict the results of describe calls. You can use multiple filters to return results that meet all applied filter requirements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct Filter {
     /// <p>The name for this filter.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<FilterName>,
     /// <p>The values of the filter. These are all the values for any of the applied filters.</p>
     #[serde(rename = "Values")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<String>>,
 }
 
+/// <p>The name for a filter.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FilterName {
+    /// <p><code>file-system-id</code></p>
+    FileSystemId,
+    /// <p><code>backup-type</code></p>
+    BackupType,
+    /// <p><code>file-system-type</code></p>
+    FileSystemType,
+    /// <p><code>volume-id</code></p>
+    VolumeId,
+    /// <p><code>data-repository-type</code></p>
+    DataRepositoryType,
+    /// <p><code>file-cache-id</code></p>
+    FileCacheId,
+    /// <p><code>file-cache-type</code></p>
+    FileCacheType,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FilterName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FilterName::FileSystemId => "file-system-id",
+            FilterName::BackupType => "backup-type",
+            FilterName::FileSystemType => "file-system-type",
+            FilterName::VolumeId => "volume-id",
+            FilterName::DataRepositoryType => "data-repository-type",
+            FilterName::FileCacheId => "file-cache-id",
+            FilterName::FileCacheType => "file-cache-type",
+            FilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FilterName {
+    fn from(value: &'a str) -> FilterName {
+        match value {
+            "file-system-id" => FilterName::FileSystemId,
+            "backup-type" => FilterName::BackupType,
+            "file-system-type" => FilterName::FileSystemType,
+            "volume-id" => FilterName::VolumeId,
+            "data-repository-type" => FilterName::DataRepositoryType,
+            "file-cache-id" => FilterName::FileCacheId,
+            "file-cache-type" => FilterName::FileCacheType,
+            _ => FilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FilterName {
+    fn from(value: String) -> FilterName {
+        FilterName::from(value.as_str())
+    }
+}
+
+impl From<FilterName> for String {
+    fn from(value: FilterName) -> String {
+        match value {
+            FilterName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FilterName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FilterName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FilterName::from(value))
+    }
+}
+
 /// <p>The request object for <code>ListTagsForResource</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListTagsForResourceRequest {
@@ -528,6 +1453,54 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`max_results`](struct.ListTagsForResourceRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsForResourceRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 /// <p>The response object for <code>ListTagsForResource</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -647,6 +1620,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>The response object for the <code>TagResource</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -663,6 +1676,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p>The response object for <code>UntagResource</code> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -695,6 +1748,68 @@ pub struct UpdateFileSystemRequest {
     pub windows_configuration: Option<UpdateFileSystemWindowsConfiguration>,
 }
 
+impl UpdateFileSystemRequest {
+    /// Returns a builder for `UpdateFileSystemRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFileSystemRequestBuilder {
+        UpdateFileSystemRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFileSystemRequest`](struct.UpdateFileSystemRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFileSystemRequestBuilder {
+    client_request_token: Option<String>,
+    file_system_id: Option<String>,
+    lustre_configuration: Option<UpdateFileSystemLustreConfiguration>,
+    windows_configuration: Option<UpdateFileSystemWindowsConfiguration>,
+}
+
+impl UpdateFileSystemRequestBuilder {
+    /// Sets the [`client_request_token`](struct.UpdateFileSystemRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_system_id`](struct.UpdateFileSystemRequest.html#structfield.file_system_id) member.
+    pub fn file_system_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.file_system_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lustre_configuration`](struct.UpdateFileSystemRequest.html#structfield.lustre_configuration) member.
+    pub fn lustre_configuration<T: Into<UpdateFileSystemLustreConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.lustre_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`windows_configuration`](struct.UpdateFileSystemRequest.html#structfield.windows_configuration) member.
+    pub fn windows_configuration<T: Into<UpdateFileSystemWindowsConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.windows_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFileSystemRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateFileSystemRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFileSystemRequest {
+            client_request_token: self.client_request_token,
+            file_system_id: self
+                .file_system_id
+                .ok_or(::rusoto_core::BuildError::missing("file_system_id"))?,
+            lustre_configuration: self.lustre_configuration,
+            windows_configuration: self.windows_configuration,
+        })
+    }
+}
+
 /// <p>The response object for the <code>UpdateFileSystem</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -750,7 +1865,7 @@ pub struct WindowsFileSystemConfiguration {
     /// <p>The list of maintenance operations in progress for this file system.</p>
     #[serde(rename = "MaintenanceOperationsInProgress")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maintenance_operations_in_progress: Option<Vec<String>>,
+    pub maintenance_operations_in_progress: Option<Vec<FileSystemMaintenanceOperation>>,
     #[serde(rename = "SelfManagedActiveDirectoryConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub self_managed_active_directory_configuration: Option<SelfManagedActiveDirectoryAttributes>,
@@ -1571,6 +2686,81 @@ pub trait Fsx {
         input: UpdateFileSystemRequest,
     ) -> RusotoFuture<UpdateFileSystemResponse, UpdateFileSystemError>;
 }
+impl<T: ?Sized + Fsx> Fsx for ::std::sync::Arc<T> {
+    fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> RusotoFuture<CreateBackupResponse, CreateBackupError> {
+        (**self).create_backup(input)
+    }
+    fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> RusotoFuture<CreateFileSystemResponse, CreateFileSystemError> {
+        (**self).create_file_system(input)
+    }
+    fn create_file_system_from_backup(
+        &self,
+        input: CreateFileSystemFromBackupRequest,
+    ) -> RusotoFuture<CreateFileSystemFromBackupResponse, CreateFileSystemFromBackupError> {
+        (**self).create_file_system_from_backup(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> RusotoFuture<DeleteFileSystemResponse, DeleteFileSystemError> {
+        (**self).delete_file_system(input)
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        (**self).describe_backups(input)
+    }
+    fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> RusotoFuture<DescribeFileSystemsResponse, DescribeFileSystemsError> {
+        (**self).describe_file_systems(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> RusotoFuture<UpdateFileSystemResponse, UpdateFileSystemError> {
+        (**self).update_file_system(input)
+    }
+}
+/// The error codes Amazon FSx retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon FSx API.
 #[derive(Clone)]
 pub struct FsxClient {
@@ -1586,6 +2776,24 @@ impl FsxClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_FSX`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> FsxClient {
+        Self::new(region::Region::from_env_for_service("FSX"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `fsx`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> FsxClient {
+        Self::new_with_client(config.client().clone(), config.region_for("fsx", "FSX"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1604,7 +2812,49 @@ impl FsxClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> FsxClient {
-        FsxClient { client, region }
+        FsxClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "fsx")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "fsx", "fsx", "FSX")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Fsx` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Fsx + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for FsxClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon FSx"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "fsx"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> FsxClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon GameLift clients.
+//!
+//! Makes a single read-only `ListAliases` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_gamelift::{GameLift, GameLiftClient, ListAliasesInput};
+
+fn main() {
+    let client = GameLiftClient::from_env();
+    match client.list_aliases(ListAliasesInput::default()).sync() {
+        Ok(_) => println!("ListAliases succeeded"),
+        Err(error) => {
+            eprintln!("ListAliases failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
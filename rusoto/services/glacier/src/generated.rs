@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Provides options to abort a multipart upload identified by the upload ID.</p> <p>For information about the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-multipart-abort-upload.html">Abort Multipart Upload</a>. For conceptual information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -38,6 +39,58 @@ pub struct AbortMultipartUploadInput {
     pub vault_name: String,
 }
 
+impl AbortMultipartUploadInput {
+    /// Returns a builder for `AbortMultipartUploadInput`, checking its required members when built.
+    pub fn builder() -> AbortMultipartUploadInputBuilder {
+        AbortMultipartUploadInputBuilder::default()
+    }
+}
+
+/// Builds a [`AbortMultipartUploadInput`](struct.AbortMultipartUploadInput.html).
+#[derive(Default, Debug)]
+pub struct AbortMultipartUploadInputBuilder {
+    account_id: Option<String>,
+    upload_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl AbortMultipartUploadInputBuilder {
+    /// Sets the [`account_id`](struct.AbortMultipartUploadInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`upload_id`](struct.AbortMultipartUploadInput.html#structfield.upload_id) member.
+    pub fn upload_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.upload_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.AbortMultipartUploadInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AbortMultipartUploadInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AbortMultipartUploadInput, ::rusoto_core::BuildError> {
+        Ok(AbortMultipartUploadInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            upload_id: self
+                .upload_id
+                .ok_or(::rusoto_core::BuildError::missing("upload_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>The input values for <code>AbortVaultLock</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AbortVaultLockInput {
@@ -49,6 +102,128 @@ pub struct AbortVaultLockInput {
     pub vault_name: String,
 }
 
+impl AbortVaultLockInput {
+    /// Returns a builder for `AbortVaultLockInput`, checking its required members when built.
+    pub fn builder() -> AbortVaultLockInputBuilder {
+        AbortVaultLockInputBuilder::default()
+    }
+}
+
+/// Builds a [`AbortVaultLockInput`](struct.AbortVaultLockInput.html).
+#[derive(Default, Debug)]
+pub struct AbortVaultLockInputBuilder {
+    account_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl AbortVaultLockInputBuilder {
+    /// Sets the [`account_id`](struct.AbortVaultLockInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.AbortVaultLockInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AbortVaultLockInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AbortVaultLockInput, ::rusoto_core::BuildError> {
+        Ok(AbortVaultLockInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
+/// <p>The job type. This value is either <code>ArchiveRetrieval</code>, <code>InventoryRetrieval</code>, or <code>Select</code>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionCode {
+    /// <p><code>ArchiveRetrieval</code></p>
+    ArchiveRetrieval,
+    /// <p><code>InventoryRetrieval</code></p>
+    InventoryRetrieval,
+    /// <p><code>Select</code></p>
+    Select,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionCode::ArchiveRetrieval => "ArchiveRetrieval",
+            ActionCode::InventoryRetrieval => "InventoryRetrieval",
+            ActionCode::Select => "Select",
+            ActionCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionCode {
+    fn from(value: &'a str) -> ActionCode {
+        match value {
+            "ArchiveRetrieval" => ActionCode::ArchiveRetrieval,
+            "InventoryRetrieval" => ActionCode::InventoryRetrieval,
+            "Select" => ActionCode::Select,
+            _ => ActionCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionCode {
+    fn from(value: String) -> ActionCode {
+        ActionCode::from(value.as_str())
+    }
+}
+
+impl From<ActionCode> for String {
+    fn from(value: ActionCode) -> String {
+        match value {
+            ActionCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActionCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ActionCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ActionCode::from(value))
+    }
+}
+
 /// <p>The input values for <code>AddTagsToVault</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AddTagsToVaultInput {
@@ -64,6 +239,54 @@ pub struct AddTagsToVaultInput {
     pub vault_name: String,
 }
 
+impl AddTagsToVaultInput {
+    /// Returns a builder for `AddTagsToVaultInput`, checking its required members when built.
+    pub fn builder() -> AddTagsToVaultInputBuilder {
+        AddTagsToVaultInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsToVaultInput`](struct.AddTagsToVaultInput.html).
+#[derive(Default, Debug)]
+pub struct AddTagsToVaultInputBuilder {
+    tags: Option<::std::collections::HashMap<String, String>>,
+    account_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl AddTagsToVaultInputBuilder {
+    /// Sets the [`tags`](struct.AddTagsToVaultInput.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`account_id`](struct.AddTagsToVaultInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.AddTagsToVaultInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsToVaultInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddTagsToVaultInput, ::rusoto_core::BuildError> {
+        Ok(AddTagsToVaultInput {
+            tags: self.tags,
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p> <p>For information about the underlying REST API, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/api-archive-post.html">Upload Archive</a>. For conceptual information, see <a href="https://docs.aws.amazon.com/amazonglacier/latest/dev/working-with-archives.html">Working with Archives in Amazon S3 Glacier</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -96,7 +319,7 @@ pub struct CSVInput {
     /// <p>Describes the first line of input. Valid values are <code>None</code>, <code>Ignore</code>, and <code>Use</code>.</p>
     #[serde(rename = "FileHeaderInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub file_header_info: Option<String>,
+    pub file_header_info: Option<FileHeaderInfo>,
     /// <p>A value used as an escape character where the field delimiter is part of the value.</p>
     #[serde(rename = "QuoteCharacter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -129,13 +352,111 @@ pub struct CSVOutput {
     /// <p>A value that indicates whether all output fields should be contained within quotation marks.</p>
     #[serde(rename = "QuoteFields")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quote_fields: Option<String>,
+    pub quote_fields: Option<QuoteFields>,
     /// <p>A value used to separate individual records from each other.</p>
     #[serde(rename = "RecordDelimiter")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub record_delimiter: Option<String>,
 }
 
+/// <p>The canned access control list (ACL) to apply to the job results.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CannedACL {
+    /// <p><code>private</code></p>
+    Private,
+    /// <p><code>public-read</code></p>
+    PublicRead,
+    /// <p><code>public-read-write</code></p>
+    PublicReadWrite,
+    /// <p><code>aws-exec-read</code></p>
+    AwsExecRead,
+    /// <p><code>authenticated-read</code></p>
+    AuthenticatedRead,
+    /// <p><code>bucket-owner-read</code></p>
+    BucketOwnerRead,
+    /// <p><code>bucket-owner-full-control</code></p>
+    BucketOwnerFullControl,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CannedACL {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CannedACL::Private => "private",
+            CannedACL::PublicRead => "public-read",
+            CannedACL::PublicReadWrite => "public-read-write",
+            CannedACL::AwsExecRead => "aws-exec-read",
+            CannedACL::AuthenticatedRead => "authenticated-read",
+            CannedACL::BucketOwnerRead => "bucket-owner-read",
+            CannedACL::BucketOwnerFullControl => "bucket-owner-full-control",
+            CannedACL::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CannedACL {
+    fn from(value: &'a str) -> CannedACL {
+        match value {
+            "private" => CannedACL::Private,
+            "public-read" => CannedACL::PublicRead,
+            "public-read-write" => CannedACL::PublicReadWrite,
+            "aws-exec-read" => CannedACL::AwsExecRead,
+            "authenticated-read" => CannedACL::AuthenticatedRead,
+            "bucket-owner-read" => CannedACL::BucketOwnerRead,
+            "bucket-owner-full-control" => CannedACL::BucketOwnerFullControl,
+            _ => CannedACL::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CannedACL {
+    fn from(value: String) -> CannedACL {
+        CannedACL::from(value.as_str())
+    }
+}
+
+impl From<CannedACL> for String {
+    fn from(value: CannedACL) -> String {
+        match value {
+            CannedACL::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CannedACL {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CannedACL {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CannedACL {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CannedACL {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CannedACL, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CannedACL::from(value))
+    }
+}
+
 /// <p>Provides options to complete a multipart upload operation. This informs Amazon Glacier that all the archive parts have been uploaded and Amazon S3 Glacier (Glacier) can now assemble the archive from the uploaded parts. After assembling and saving the archive to the vault, Glacier returns the URI path of the newly created archive resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CompleteMultipartUploadInput {
@@ -158,6 +479,74 @@ pub struct CompleteMultipartUploadInput {
     pub vault_name: String,
 }
 
+impl CompleteMultipartUploadInput {
+    /// Returns a builder for `CompleteMultipartUploadInput`, checking its required members when built.
+    pub fn builder() -> CompleteMultipartUploadInputBuilder {
+        CompleteMultipartUploadInputBuilder::default()
+    }
+}
+
+/// Builds a [`CompleteMultipartUploadInput`](struct.CompleteMultipartUploadInput.html).
+#[derive(Default, Debug)]
+pub struct CompleteMultipartUploadInputBuilder {
+    account_id: Option<String>,
+    archive_size: Option<String>,
+    checksum: Option<String>,
+    upload_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl CompleteMultipartUploadInputBuilder {
+    /// Sets the [`account_id`](struct.CompleteMultipartUploadInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`archive_size`](struct.CompleteMultipartUploadInput.html#structfield.archive_size) member.
+    pub fn archive_size<T: Into<String>>(mut self, value: T) -> Self {
+        self.archive_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`checksum`](struct.CompleteMultipartUploadInput.html#structfield.checksum) member.
+    pub fn checksum<T: Into<String>>(mut self, value: T) -> Self {
+        self.checksum = Some(value.into());
+        self
+    }
+
+    /// Sets the [`upload_id`](struct.CompleteMultipartUploadInput.html#structfield.upload_id) member.
+    pub fn upload_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.upload_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.CompleteMultipartUploadInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CompleteMultipartUploadInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CompleteMultipartUploadInput, ::rusoto_core::BuildError> {
+        Ok(CompleteMultipartUploadInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            archive_size: self.archive_size,
+            checksum: self.checksum,
+            upload_id: self
+                .upload_id
+                .ok_or(::rusoto_core::BuildError::missing("upload_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>The input values for <code>CompleteVaultLock</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CompleteVaultLockInput {
@@ -172,6 +561,56 @@ pub struct CompleteVaultLockInput {
     pub vault_name: String,
 }
 
+impl CompleteVaultLockInput {
+    /// Returns a builder for `CompleteVaultLockInput`, checking its required members when built.
+    pub fn builder() -> CompleteVaultLockInputBuilder {
+        CompleteVaultLockInputBuilder::default()
+    }
+}
+
+/// Builds a [`CompleteVaultLockInput`](struct.CompleteVaultLockInput.html).
+#[derive(Default, Debug)]
+pub struct CompleteVaultLockInputBuilder {
+    account_id: Option<String>,
+    lock_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl CompleteVaultLockInputBuilder {
+    /// Sets the [`account_id`](struct.CompleteVaultLockInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lock_id`](struct.CompleteVaultLockInput.html#structfield.lock_id) member.
+    pub fn lock_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.lock_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.CompleteVaultLockInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CompleteVaultLockInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CompleteVaultLockInput, ::rusoto_core::BuildError> {
+        Ok(CompleteVaultLockInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            lock_id: self
+                .lock_id
+                .ok_or(::rusoto_core::BuildError::missing("lock_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Provides options to create a vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateVaultInput {
@@ -183,6 +622,46 @@ pub struct CreateVaultInput {
     pub vault_name: String,
 }
 
+impl CreateVaultInput {
+    /// Returns a builder for `CreateVaultInput`, checking its required members when built.
+    pub fn builder() -> CreateVaultInputBuilder {
+        CreateVaultInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateVaultInput`](struct.CreateVaultInput.html).
+#[derive(Default, Debug)]
+pub struct CreateVaultInputBuilder {
+    account_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl CreateVaultInputBuilder {
+    /// Sets the [`account_id`](struct.CreateVaultInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.CreateVaultInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateVaultInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateVaultInput, ::rusoto_core::BuildError> {
+        Ok(CreateVaultInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -229,6 +708,56 @@ pub struct DeleteArchiveInput {
     pub vault_name: String,
 }
 
+impl DeleteArchiveInput {
+    /// Returns a builder for `DeleteArchiveInput`, checking its required members when built.
+    pub fn builder() -> DeleteArchiveInputBuilder {
+        DeleteArchiveInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteArchiveInput`](struct.DeleteArchiveInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteArchiveInputBuilder {
+    account_id: Option<String>,
+    archive_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl DeleteArchiveInputBuilder {
+    /// Sets the [`account_id`](struct.DeleteArchiveInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`archive_id`](struct.DeleteArchiveInput.html#structfield.archive_id) member.
+    pub fn archive_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.archive_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.DeleteArchiveInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteArchiveInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteArchiveInput, ::rusoto_core::BuildError> {
+        Ok(DeleteArchiveInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            archive_id: self
+                .archive_id
+                .ok_or(::rusoto_core::BuildError::missing("archive_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>DeleteVaultAccessPolicy input.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteVaultAccessPolicyInput {
@@ -240,6 +769,48 @@ pub struct DeleteVaultAccessPolicyInput {
     pub vault_name: String,
 }
 
+impl DeleteVaultAccessPolicyInput {
+    /// Returns a builder for `DeleteVaultAccessPolicyInput`, checking its required members when built.
+    pub fn builder() -> DeleteVaultAccessPolicyInputBuilder {
+        DeleteVaultAccessPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVaultAccessPolicyInput`](struct.DeleteVaultAccessPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteVaultAccessPolicyInputBuilder {
+    account_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl DeleteVaultAccessPolicyInputBuilder {
+    /// Sets the [`account_id`](struct.DeleteVaultAccessPolicyInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.DeleteVaultAccessPolicyInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVaultAccessPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVaultAccessPolicyInput, ::rusoto_core::BuildError> {
+        Ok(DeleteVaultAccessPolicyInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Provides options for deleting a vault from Amazon S3 Glacier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteVaultInput {
@@ -251,6 +822,46 @@ pub struct DeleteVaultInput {
     pub vault_name: String,
 }
 
+impl DeleteVaultInput {
+    /// Returns a builder for `DeleteVaultInput`, checking its required members when built.
+    pub fn builder() -> DeleteVaultInputBuilder {
+        DeleteVaultInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVaultInput`](struct.DeleteVaultInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteVaultInputBuilder {
+    account_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl DeleteVaultInputBuilder {
+    /// Sets the [`account_id`](struct.DeleteVaultInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.DeleteVaultInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVaultInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteVaultInput, ::rusoto_core::BuildError> {
+        Ok(DeleteVaultInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Provides options for deleting a vault notification configuration from an Amazon Glacier vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteVaultNotificationsInput {
@@ -262,6 +873,48 @@ pub struct DeleteVaultNotificationsInput {
     pub vault_name: String,
 }
 
+impl DeleteVaultNotificationsInput {
+    /// Returns a builder for `DeleteVaultNotificationsInput`, checking its required members when built.
+    pub fn builder() -> DeleteVaultNotificationsInputBuilder {
+        DeleteVaultNotificationsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVaultNotificationsInput`](struct.DeleteVaultNotificationsInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteVaultNotificationsInputBuilder {
+    account_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl DeleteVaultNotificationsInputBuilder {
+    /// Sets the [`account_id`](struct.DeleteVaultNotificationsInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.DeleteVaultNotificationsInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVaultNotificationsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVaultNotificationsInput, ::rusoto_core::BuildError> {
+        Ok(DeleteVaultNotificationsInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Provides options for retrieving a job description.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeJobInput {
@@ -276,6 +929,56 @@ pub struct DescribeJobInput {
     pub vault_name: String,
 }
 
+impl DescribeJobInput {
+    /// Returns a builder for `DescribeJobInput`, checking its required members when built.
+    pub fn builder() -> DescribeJobInputBuilder {
+        DescribeJobInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeJobInput`](struct.DescribeJobInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeJobInputBuilder {
+    account_id: Option<String>,
+    job_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl DescribeJobInputBuilder {
+    /// Sets the [`account_id`](struct.DescribeJobInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.DescribeJobInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.DescribeJobInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeJobInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeJobInput, ::rusoto_core::BuildError> {
+        Ok(DescribeJobInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Provides options for retrieving metadata for a specific vault in Amazon Glacier.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeVaultInput {
@@ -287,6 +990,46 @@ pub struct DescribeVaultInput {
     pub vault_name: String,
 }
 
+impl DescribeVaultInput {
+    /// Returns a builder for `DescribeVaultInput`, checking its required members when built.
+    pub fn builder() -> DescribeVaultInputBuilder {
+        DescribeVaultInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeVaultInput`](struct.DescribeVaultInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeVaultInputBuilder {
+    account_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl DescribeVaultInputBuilder {
+    /// Sets the [`account_id`](struct.DescribeVaultInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.DescribeVaultInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeVaultInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeVaultInput, ::rusoto_core::BuildError> {
+        Ok(DescribeVaultInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -323,7 +1066,7 @@ pub struct Encryption {
     /// <p>The server-side encryption algorithm used when storing job results in Amazon S3, for example <code>AES256</code> or <code>aws:kms</code>.</p>
     #[serde(rename = "EncryptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_type: Option<String>,
+    pub encryption_type: Option<EncryptionType>,
     /// <p>Optional. If the encryption type is <code>aws:kms</code>, you can use this value to specify the encryption context for the job results.</p>
     #[serde(rename = "KMSContext")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -334,22 +1077,288 @@ pub struct Encryption {
     pub kms_key_id: Option<String>,
 }
 
-/// <p>Input for GetDataRetrievalPolicy.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetDataRetrievalPolicyInput {
-    /// <p>The <code>AccountId</code> value is the AWS account ID. This value must match the AWS account ID associated with the credentials used to sign the request. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon Glacier uses the AWS account ID associated with the credentials used to sign the request. If you specify your account ID, do not include any hyphens ('-') in the ID. </p>
-    #[serde(rename = "accountId")]
-    pub account_id: String,
-}
-
-/// <p>Contains the Amazon S3 Glacier response to the <code>GetDataRetrievalPolicy</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetDataRetrievalPolicyOutput {
-    /// <p>Contains the returned data retrieval policy in JSON format.</p>
-    #[serde(rename = "Policy")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy: Option<DataRetrievalPolicy>,
+/// <p>The server-side encryption algorithm used when storing job results in Amazon S3, for example <code>AES256</code> or <code>aws:kms</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EncryptionType {
+    /// <p><code>aws:kms</code></p>
+    AwsKms,
+    /// <p><code>AES256</code></p>
+    Aes256,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EncryptionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionType::AwsKms => "aws:kms",
+            EncryptionType::Aes256 => "AES256",
+            EncryptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionType {
+    fn from(value: &'a str) -> EncryptionType {
+        match value {
+            "aws:kms" => EncryptionType::AwsKms,
+            "AES256" => EncryptionType::Aes256,
+            _ => EncryptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionType {
+    fn from(value: String) -> EncryptionType {
+        EncryptionType::from(value.as_str())
+    }
+}
+
+impl From<EncryptionType> for String {
+    fn from(value: EncryptionType) -> String {
+        match value {
+            EncryptionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncryptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EncryptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EncryptionType::from(value))
+    }
+}
+
+/// <p>The type of the provided expression, for example <code>SQL</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExpressionType {
+    /// <p><code>SQL</code></p>
+    Sql,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExpressionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExpressionType::Sql => "SQL",
+            ExpressionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExpressionType {
+    fn from(value: &'a str) -> ExpressionType {
+        match value {
+            "SQL" => ExpressionType::Sql,
+            _ => ExpressionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExpressionType {
+    fn from(value: String) -> ExpressionType {
+        ExpressionType::from(value.as_str())
+    }
+}
+
+impl From<ExpressionType> for String {
+    fn from(value: ExpressionType) -> String {
+        match value {
+            ExpressionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExpressionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExpressionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExpressionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExpressionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExpressionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExpressionType::from(value))
+    }
+}
+
+/// <p>Describes the first line of input. Valid values are <code>None</code>, <code>Ignore</code>, and <code>Use</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FileHeaderInfo {
+    /// <p><code>USE</code></p>
+    Use,
+    /// <p><code>IGNORE</code></p>
+    Ignore,
+    /// <p><code>NONE</code></p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FileHeaderInfo {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FileHeaderInfo::Use => "USE",
+            FileHeaderInfo::Ignore => "IGNORE",
+            FileHeaderInfo::None => "NONE",
+            FileHeaderInfo::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FileHeaderInfo {
+    fn from(value: &'a str) -> FileHeaderInfo {
+        match value {
+            "USE" => FileHeaderInfo::Use,
+            "IGNORE" => FileHeaderInfo::Ignore,
+            "NONE" => FileHeaderInfo::None,
+            _ => FileHeaderInfo::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FileHeaderInfo {
+    fn from(value: String) -> FileHeaderInfo {
+        FileHeaderInfo::from(value.as_str())
+    }
+}
+
+impl From<FileHeaderInfo> for String {
+    fn from(value: FileHeaderInfo) -> String {
+        match value {
+            FileHeaderInfo::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FileHeaderInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FileHeaderInfo {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FileHeaderInfo {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FileHeaderInfo {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FileHeaderInfo, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FileHeaderInfo::from(value))
+    }
+}
+
+/// <p>Input for GetDataRetrievalPolicy.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetDataRetrievalPolicyInput {
+    /// <p>The <code>AccountId</code> value is the AWS account ID. This value must match the AWS account ID associated with the credentials used to sign the request. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon Glacier uses the AWS account ID associated with the credentials used to sign the request. If you specify your account ID, do not include any hyphens ('-') in the ID. </p>
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+}
+
+impl GetDataRetrievalPolicyInput {
+    /// Returns a builder for `GetDataRetrievalPolicyInput`, checking its required members when built.
+    pub fn builder() -> GetDataRetrievalPolicyInputBuilder {
+        GetDataRetrievalPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetDataRetrievalPolicyInput`](struct.GetDataRetrievalPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct GetDataRetrievalPolicyInputBuilder {
+    account_id: Option<String>,
+}
+
+impl GetDataRetrievalPolicyInputBuilder {
+    /// Sets the [`account_id`](struct.GetDataRetrievalPolicyInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDataRetrievalPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDataRetrievalPolicyInput, ::rusoto_core::BuildError> {
+        Ok(GetDataRetrievalPolicyInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+        })
+    }
+}
+
+/// <p>Contains the Amazon S3 Glacier response to the <code>GetDataRetrievalPolicy</code> request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetDataRetrievalPolicyOutput {
+    /// <p>Contains the returned data retrieval policy in JSON format.</p>
+    #[serde(rename = "Policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<DataRetrievalPolicy>,
 }
 
 /// <p>Provides options for downloading output of an Amazon S3 Glacier job.</p>
@@ -370,6 +1379,64 @@ pub struct GetJobOutputInput {
     pub vault_name: String,
 }
 
+impl GetJobOutputInput {
+    /// Returns a builder for `GetJobOutputInput`, checking its required members when built.
+    pub fn builder() -> GetJobOutputInputBuilder {
+        GetJobOutputInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetJobOutputInput`](struct.GetJobOutputInput.html).
+#[derive(Default, Debug)]
+pub struct GetJobOutputInputBuilder {
+    account_id: Option<String>,
+    job_id: Option<String>,
+    range: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl GetJobOutputInputBuilder {
+    /// Sets the [`account_id`](struct.GetJobOutputInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.GetJobOutputInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`range`](struct.GetJobOutputInput.html#structfield.range) member.
+    pub fn range<T: Into<String>>(mut self, value: T) -> Self {
+        self.range = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.GetJobOutputInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetJobOutputInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetJobOutputInput, ::rusoto_core::BuildError> {
+        Ok(GetJobOutputInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            range: self.range,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetJobOutputOutput {
@@ -400,6 +1467,48 @@ pub struct GetVaultAccessPolicyInput {
     pub vault_name: String,
 }
 
+impl GetVaultAccessPolicyInput {
+    /// Returns a builder for `GetVaultAccessPolicyInput`, checking its required members when built.
+    pub fn builder() -> GetVaultAccessPolicyInputBuilder {
+        GetVaultAccessPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetVaultAccessPolicyInput`](struct.GetVaultAccessPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct GetVaultAccessPolicyInputBuilder {
+    account_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl GetVaultAccessPolicyInputBuilder {
+    /// Sets the [`account_id`](struct.GetVaultAccessPolicyInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.GetVaultAccessPolicyInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetVaultAccessPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetVaultAccessPolicyInput, ::rusoto_core::BuildError> {
+        Ok(GetVaultAccessPolicyInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Output for GetVaultAccessPolicy.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -421,6 +1530,46 @@ pub struct GetVaultLockInput {
     pub vault_name: String,
 }
 
+impl GetVaultLockInput {
+    /// Returns a builder for `GetVaultLockInput`, checking its required members when built.
+    pub fn builder() -> GetVaultLockInputBuilder {
+        GetVaultLockInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetVaultLockInput`](struct.GetVaultLockInput.html).
+#[derive(Default, Debug)]
+pub struct GetVaultLockInputBuilder {
+    account_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl GetVaultLockInputBuilder {
+    /// Sets the [`account_id`](struct.GetVaultLockInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.GetVaultLockInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetVaultLockInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetVaultLockInput, ::rusoto_core::BuildError> {
+        Ok(GetVaultLockInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -454,6 +1603,48 @@ pub struct GetVaultNotificationsInput {
     pub vault_name: String,
 }
 
+impl GetVaultNotificationsInput {
+    /// Returns a builder for `GetVaultNotificationsInput`, checking its required members when built.
+    pub fn builder() -> GetVaultNotificationsInputBuilder {
+        GetVaultNotificationsInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetVaultNotificationsInput`](struct.GetVaultNotificationsInput.html).
+#[derive(Default, Debug)]
+pub struct GetVaultNotificationsInputBuilder {
+    account_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl GetVaultNotificationsInputBuilder {
+    /// Sets the [`account_id`](struct.GetVaultNotificationsInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.GetVaultNotificationsInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetVaultNotificationsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetVaultNotificationsInput, ::rusoto_core::BuildError> {
+        Ok(GetVaultNotificationsInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -471,7 +1662,7 @@ pub struct GlacierJobDescription {
     /// <p>The job type. This value is either <code>ArchiveRetrieval</code>, <code>InventoryRetrieval</code>, or <code>Select</code>. </p>
     #[serde(rename = "Action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<ActionCode>,
     /// <p>The archive ID requested for a select job or archive retrieval. Otherwise, this field is null.</p>
     #[serde(rename = "ArchiveId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -539,7 +1730,7 @@ pub struct GlacierJobDescription {
     /// <p>The status code can be <code>InProgress</code>, <code>Succeeded</code>, or <code>Failed</code>, and indicates the status of the job.</p>
     #[serde(rename = "StatusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_code: Option<String>,
+    pub status_code: Option<StatusCode>,
     /// <p>A friendly message that describes the job status.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -564,11 +1755,11 @@ pub struct Grant {
     /// <p>Specifies the permission given to the grantee. </p>
     #[serde(rename = "Permission")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permission: Option<String>,
+    pub permission: Option<Permission>,
 }
 
 /// <p>Contains information about the grantee.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Grantee {
     /// <p>Screen name of the grantee.</p>
     #[serde(rename = "DisplayName")]
@@ -584,7 +1775,7 @@ pub struct Grantee {
     pub id: Option<String>,
     /// <p>Type of grantee</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: Type,
     /// <p>URI of the grantee group.</p>
     #[serde(rename = "URI")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -606,6 +1797,54 @@ pub struct InitiateJobInput {
     pub vault_name: String,
 }
 
+impl InitiateJobInput {
+    /// Returns a builder for `InitiateJobInput`, checking its required members when built.
+    pub fn builder() -> InitiateJobInputBuilder {
+        InitiateJobInputBuilder::default()
+    }
+}
+
+/// Builds a [`InitiateJobInput`](struct.InitiateJobInput.html).
+#[derive(Default, Debug)]
+pub struct InitiateJobInputBuilder {
+    account_id: Option<String>,
+    job_parameters: Option<JobParameters>,
+    vault_name: Option<String>,
+}
+
+impl InitiateJobInputBuilder {
+    /// Sets the [`account_id`](struct.InitiateJobInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_parameters`](struct.InitiateJobInput.html#structfield.job_parameters) member.
+    pub fn job_parameters<T: Into<JobParameters>>(mut self, value: T) -> Self {
+        self.job_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.InitiateJobInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `InitiateJobInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<InitiateJobInput, ::rusoto_core::BuildError> {
+        Ok(InitiateJobInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            job_parameters: self.job_parameters,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -643,6 +1882,64 @@ pub struct InitiateMultipartUploadInput {
     pub vault_name: String,
 }
 
+impl InitiateMultipartUploadInput {
+    /// Returns a builder for `InitiateMultipartUploadInput`, checking its required members when built.
+    pub fn builder() -> InitiateMultipartUploadInputBuilder {
+        InitiateMultipartUploadInputBuilder::default()
+    }
+}
+
+/// Builds a [`InitiateMultipartUploadInput`](struct.InitiateMultipartUploadInput.html).
+#[derive(Default, Debug)]
+pub struct InitiateMultipartUploadInputBuilder {
+    account_id: Option<String>,
+    archive_description: Option<String>,
+    part_size: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl InitiateMultipartUploadInputBuilder {
+    /// Sets the [`account_id`](struct.InitiateMultipartUploadInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`archive_description`](struct.InitiateMultipartUploadInput.html#structfield.archive_description) member.
+    pub fn archive_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.archive_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`part_size`](struct.InitiateMultipartUploadInput.html#structfield.part_size) member.
+    pub fn part_size<T: Into<String>>(mut self, value: T) -> Self {
+        self.part_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.InitiateMultipartUploadInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `InitiateMultipartUploadInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<InitiateMultipartUploadInput, ::rusoto_core::BuildError> {
+        Ok(InitiateMultipartUploadInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            archive_description: self.archive_description,
+            part_size: self.part_size,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>The Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -672,6 +1969,54 @@ pub struct InitiateVaultLockInput {
     pub vault_name: String,
 }
 
+impl InitiateVaultLockInput {
+    /// Returns a builder for `InitiateVaultLockInput`, checking its required members when built.
+    pub fn builder() -> InitiateVaultLockInputBuilder {
+        InitiateVaultLockInputBuilder::default()
+    }
+}
+
+/// Builds a [`InitiateVaultLockInput`](struct.InitiateVaultLockInput.html).
+#[derive(Default, Debug)]
+pub struct InitiateVaultLockInputBuilder {
+    account_id: Option<String>,
+    policy: Option<VaultLockPolicy>,
+    vault_name: Option<String>,
+}
+
+impl InitiateVaultLockInputBuilder {
+    /// Sets the [`account_id`](struct.InitiateVaultLockInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.InitiateVaultLockInput.html#structfield.policy) member.
+    pub fn policy<T: Into<VaultLockPolicy>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.InitiateVaultLockInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `InitiateVaultLockInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<InitiateVaultLockInput, ::rusoto_core::BuildError> {
+        Ok(InitiateVaultLockInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            policy: self.policy,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -810,27 +2155,99 @@ pub struct ListJobsInput {
     pub vault_name: String,
 }
 
-/// <p>Contains the Amazon S3 Glacier response to your request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListJobsOutput {
-    /// <p>A list of job objects. Each job object contains metadata describing the job.</p>
-    #[serde(rename = "JobList")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_list: Option<Vec<GlacierJobDescription>>,
-    /// <p> An opaque string used for pagination that specifies the job at which the listing of jobs should begin. You get the <code>marker</code> value from a previous List Jobs response. You only need to include the marker if you are continuing the pagination of the results started in a previous List Jobs request. </p>
-    #[serde(rename = "Marker")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub marker: Option<String>,
+impl ListJobsInput {
+    /// Returns a builder for `ListJobsInput`, checking its required members when built.
+    pub fn builder() -> ListJobsInputBuilder {
+        ListJobsInputBuilder::default()
+    }
 }
 
-/// <p>Provides options for retrieving list of in-progress multipart uploads for an Amazon Glacier vault.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ListMultipartUploadsInput {
-    /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID. </p>
-    #[serde(rename = "accountId")]
-    pub account_id: String,
-    /// <p>Specifies the maximum number of uploads returned in the response body. If this value is not specified, the List Uploads operation returns up to 50 uploads.</p>
+/// Builds a [`ListJobsInput`](struct.ListJobsInput.html).
+#[derive(Default, Debug)]
+pub struct ListJobsInputBuilder {
+    account_id: Option<String>,
+    completed: Option<String>,
+    limit: Option<String>,
+    marker: Option<String>,
+    statuscode: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl ListJobsInputBuilder {
+    /// Sets the [`account_id`](struct.ListJobsInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`completed`](struct.ListJobsInput.html#structfield.completed) member.
+    pub fn completed<T: Into<String>>(mut self, value: T) -> Self {
+        self.completed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListJobsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<String>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListJobsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statuscode`](struct.ListJobsInput.html#structfield.statuscode) member.
+    pub fn statuscode<T: Into<String>>(mut self, value: T) -> Self {
+        self.statuscode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.ListJobsInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJobsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListJobsInput, ::rusoto_core::BuildError> {
+        Ok(ListJobsInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            completed: self.completed,
+            limit: self.limit,
+            marker: self.marker,
+            statuscode: self.statuscode,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
+/// <p>Contains the Amazon S3 Glacier response to your request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListJobsOutput {
+    /// <p>A list of job objects. Each job object contains metadata describing the job.</p>
+    #[serde(rename = "JobList")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_list: Option<Vec<GlacierJobDescription>>,
+    /// <p> An opaque string used for pagination that specifies the job at which the listing of jobs should begin. You get the <code>marker</code> value from a previous List Jobs response. You only need to include the marker if you are continuing the pagination of the results started in a previous List Jobs request. </p>
+    #[serde(rename = "Marker")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub marker: Option<String>,
+}
+
+/// <p>Provides options for retrieving list of in-progress multipart uploads for an Amazon Glacier vault.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListMultipartUploadsInput {
+    /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID. </p>
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    /// <p>Specifies the maximum number of uploads returned in the response body. If this value is not specified, the List Uploads operation returns up to 50 uploads.</p>
     #[serde(rename = "limit")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<String>,
@@ -843,6 +2260,64 @@ pub struct ListMultipartUploadsInput {
     pub vault_name: String,
 }
 
+impl ListMultipartUploadsInput {
+    /// Returns a builder for `ListMultipartUploadsInput`, checking its required members when built.
+    pub fn builder() -> ListMultipartUploadsInputBuilder {
+        ListMultipartUploadsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListMultipartUploadsInput`](struct.ListMultipartUploadsInput.html).
+#[derive(Default, Debug)]
+pub struct ListMultipartUploadsInputBuilder {
+    account_id: Option<String>,
+    limit: Option<String>,
+    marker: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl ListMultipartUploadsInputBuilder {
+    /// Sets the [`account_id`](struct.ListMultipartUploadsInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListMultipartUploadsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<String>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListMultipartUploadsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.ListMultipartUploadsInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListMultipartUploadsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListMultipartUploadsInput, ::rusoto_core::BuildError> {
+        Ok(ListMultipartUploadsInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            limit: self.limit,
+            marker: self.marker,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -879,6 +2354,72 @@ pub struct ListPartsInput {
     pub vault_name: String,
 }
 
+impl ListPartsInput {
+    /// Returns a builder for `ListPartsInput`, checking its required members when built.
+    pub fn builder() -> ListPartsInputBuilder {
+        ListPartsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListPartsInput`](struct.ListPartsInput.html).
+#[derive(Default, Debug)]
+pub struct ListPartsInputBuilder {
+    account_id: Option<String>,
+    limit: Option<String>,
+    marker: Option<String>,
+    upload_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl ListPartsInputBuilder {
+    /// Sets the [`account_id`](struct.ListPartsInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListPartsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<String>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListPartsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`upload_id`](struct.ListPartsInput.html#structfield.upload_id) member.
+    pub fn upload_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.upload_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.ListPartsInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPartsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPartsInput, ::rusoto_core::BuildError> {
+        Ok(ListPartsInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            limit: self.limit,
+            marker: self.marker,
+            upload_id: self
+                .upload_id
+                .ok_or(::rusoto_core::BuildError::missing("upload_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -920,6 +2461,38 @@ pub struct ListProvisionedCapacityInput {
     pub account_id: String,
 }
 
+impl ListProvisionedCapacityInput {
+    /// Returns a builder for `ListProvisionedCapacityInput`, checking its required members when built.
+    pub fn builder() -> ListProvisionedCapacityInputBuilder {
+        ListProvisionedCapacityInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListProvisionedCapacityInput`](struct.ListProvisionedCapacityInput.html).
+#[derive(Default, Debug)]
+pub struct ListProvisionedCapacityInputBuilder {
+    account_id: Option<String>,
+}
+
+impl ListProvisionedCapacityInputBuilder {
+    /// Sets the [`account_id`](struct.ListProvisionedCapacityInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListProvisionedCapacityInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListProvisionedCapacityInput, ::rusoto_core::BuildError> {
+        Ok(ListProvisionedCapacityInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListProvisionedCapacityOutput {
@@ -940,6 +2513,46 @@ pub struct ListTagsForVaultInput {
     pub vault_name: String,
 }
 
+impl ListTagsForVaultInput {
+    /// Returns a builder for `ListTagsForVaultInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForVaultInputBuilder {
+        ListTagsForVaultInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForVaultInput`](struct.ListTagsForVaultInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForVaultInputBuilder {
+    account_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl ListTagsForVaultInputBuilder {
+    /// Sets the [`account_id`](struct.ListTagsForVaultInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.ListTagsForVaultInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForVaultInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTagsForVaultInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForVaultInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -966,6 +2579,52 @@ pub struct ListVaultsInput {
     pub marker: Option<String>,
 }
 
+impl ListVaultsInput {
+    /// Returns a builder for `ListVaultsInput`, checking its required members when built.
+    pub fn builder() -> ListVaultsInputBuilder {
+        ListVaultsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListVaultsInput`](struct.ListVaultsInput.html).
+#[derive(Default, Debug)]
+pub struct ListVaultsInputBuilder {
+    account_id: Option<String>,
+    limit: Option<String>,
+    marker: Option<String>,
+}
+
+impl ListVaultsInputBuilder {
+    /// Sets the [`account_id`](struct.ListVaultsInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListVaultsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<String>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListVaultsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVaultsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListVaultsInput, ::rusoto_core::BuildError> {
+        Ok(ListVaultsInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1012,6 +2671,96 @@ pub struct PartListElement {
     pub sha256_tree_hash: Option<String>,
 }
 
+/// <p>Specifies the permission given to the grantee. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Permission {
+    /// <p><code>FULL_CONTROL</code></p>
+    FullControl,
+    /// <p><code>WRITE</code></p>
+    Write,
+    /// <p><code>WRITE_ACP</code></p>
+    WriteAcp,
+    /// <p><code>READ</code></p>
+    Read,
+    /// <p><code>READ_ACP</code></p>
+    ReadAcp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Permission {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permission::FullControl => "FULL_CONTROL",
+            Permission::Write => "WRITE",
+            Permission::WriteAcp => "WRITE_ACP",
+            Permission::Read => "READ",
+            Permission::ReadAcp => "READ_ACP",
+            Permission::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Permission {
+    fn from(value: &'a str) -> Permission {
+        match value {
+            "FULL_CONTROL" => Permission::FullControl,
+            "WRITE" => Permission::Write,
+            "WRITE_ACP" => Permission::WriteAcp,
+            "READ" => Permission::Read,
+            "READ_ACP" => Permission::ReadAcp,
+            _ => Permission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Permission {
+    fn from(value: String) -> Permission {
+        Permission::from(value.as_str())
+    }
+}
+
+impl From<Permission> for String {
+    fn from(value: Permission) -> String {
+        match value {
+            Permission::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Permission {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Permission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Permission::from(value))
+    }
+}
+
 /// <p>The definition for a provisioned capacity unit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1037,6 +2786,38 @@ pub struct PurchaseProvisionedCapacityInput {
     pub account_id: String,
 }
 
+impl PurchaseProvisionedCapacityInput {
+    /// Returns a builder for `PurchaseProvisionedCapacityInput`, checking its required members when built.
+    pub fn builder() -> PurchaseProvisionedCapacityInputBuilder {
+        PurchaseProvisionedCapacityInputBuilder::default()
+    }
+}
+
+/// Builds a [`PurchaseProvisionedCapacityInput`](struct.PurchaseProvisionedCapacityInput.html).
+#[derive(Default, Debug)]
+pub struct PurchaseProvisionedCapacityInputBuilder {
+    account_id: Option<String>,
+}
+
+impl PurchaseProvisionedCapacityInputBuilder {
+    /// Sets the [`account_id`](struct.PurchaseProvisionedCapacityInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PurchaseProvisionedCapacityInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PurchaseProvisionedCapacityInput, ::rusoto_core::BuildError> {
+        Ok(PurchaseProvisionedCapacityInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PurchaseProvisionedCapacityOutput {
@@ -1046,6 +2827,84 @@ pub struct PurchaseProvisionedCapacityOutput {
     pub capacity_id: Option<String>,
 }
 
+/// <p>A value that indicates whether all output fields should be contained within quotation marks.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QuoteFields {
+    /// <p><code>ALWAYS</code></p>
+    Always,
+    /// <p><code>ASNEEDED</code></p>
+    Asneeded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl QuoteFields {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QuoteFields::Always => "ALWAYS",
+            QuoteFields::Asneeded => "ASNEEDED",
+            QuoteFields::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for QuoteFields {
+    fn from(value: &'a str) -> QuoteFields {
+        match value {
+            "ALWAYS" => QuoteFields::Always,
+            "ASNEEDED" => QuoteFields::Asneeded,
+            _ => QuoteFields::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QuoteFields {
+    fn from(value: String) -> QuoteFields {
+        QuoteFields::from(value.as_str())
+    }
+}
+
+impl From<QuoteFields> for String {
+    fn from(value: QuoteFields) -> String {
+        match value {
+            QuoteFields::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for QuoteFields {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QuoteFields {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QuoteFields {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QuoteFields {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<QuoteFields, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(QuoteFields::from(value))
+    }
+}
+
 /// <p>The input value for <code>RemoveTagsFromVaultInput</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RemoveTagsFromVaultInput {
@@ -1061,6 +2920,56 @@ pub struct RemoveTagsFromVaultInput {
     pub vault_name: String,
 }
 
+impl RemoveTagsFromVaultInput {
+    /// Returns a builder for `RemoveTagsFromVaultInput`, checking its required members when built.
+    pub fn builder() -> RemoveTagsFromVaultInputBuilder {
+        RemoveTagsFromVaultInputBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsFromVaultInput`](struct.RemoveTagsFromVaultInput.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsFromVaultInputBuilder {
+    tag_keys: Option<Vec<String>>,
+    account_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl RemoveTagsFromVaultInputBuilder {
+    /// Sets the [`tag_keys`](struct.RemoveTagsFromVaultInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`account_id`](struct.RemoveTagsFromVaultInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.RemoveTagsFromVaultInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsFromVaultInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveTagsFromVaultInput, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsFromVaultInput {
+            tag_keys: self.tag_keys,
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains information about the location in Amazon S3 where the select job results are stored.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3Location {
@@ -1075,7 +2984,7 @@ pub struct S3Location {
     /// <p>The canned access control list (ACL) to apply to the job results.</p>
     #[serde(rename = "CannedACL")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub canned_acl: Option<String>,
+    pub canned_acl: Option<CannedACL>,
     /// <p>Contains information about the encryption used to store the job results in Amazon S3.</p>
     #[serde(rename = "Encryption")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1087,7 +2996,7 @@ pub struct S3Location {
     /// <p>The storage class used to store the job results.</p>
     #[serde(rename = "StorageClass")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub storage_class: Option<String>,
+    pub storage_class: Option<StorageClass>,
     /// <p>The tag-set that is applied to the job results.</p>
     #[serde(rename = "Tagging")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1108,7 +3017,7 @@ pub struct SelectParameters {
     /// <p>The type of the provided expression, for example <code>SQL</code>.</p>
     #[serde(rename = "ExpressionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expression_type: Option<String>,
+    pub expression_type: Option<ExpressionType>,
     /// <p>Describes the serialization format of the object.</p>
     #[serde(rename = "InputSerialization")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1131,34 +3040,420 @@ pub struct SetDataRetrievalPolicyInput {
     pub account_id: String,
 }
 
-/// <p>SetVaultAccessPolicy input.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct SetVaultAccessPolicyInput {
-    /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
-    #[serde(rename = "accountId")]
-    pub account_id: String,
-    /// <p>The vault access policy as a JSON string.</p>
-    #[serde(rename = "policy")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy: Option<VaultAccessPolicy>,
-    /// <p>The name of the vault.</p>
-    #[serde(rename = "vaultName")]
-    pub vault_name: String,
+impl SetDataRetrievalPolicyInput {
+    /// Returns a builder for `SetDataRetrievalPolicyInput`, checking its required members when built.
+    pub fn builder() -> SetDataRetrievalPolicyInputBuilder {
+        SetDataRetrievalPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetDataRetrievalPolicyInput`](struct.SetDataRetrievalPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct SetDataRetrievalPolicyInputBuilder {
+    policy: Option<DataRetrievalPolicy>,
+    account_id: Option<String>,
+}
+
+impl SetDataRetrievalPolicyInputBuilder {
+    /// Sets the [`policy`](struct.SetDataRetrievalPolicyInput.html#structfield.policy) member.
+    pub fn policy<T: Into<DataRetrievalPolicy>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`account_id`](struct.SetDataRetrievalPolicyInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetDataRetrievalPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetDataRetrievalPolicyInput, ::rusoto_core::BuildError> {
+        Ok(SetDataRetrievalPolicyInput {
+            policy: self.policy,
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+        })
+    }
+}
+
+/// <p>SetVaultAccessPolicy input.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct SetVaultAccessPolicyInput {
+    /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    /// <p>The vault access policy as a JSON string.</p>
+    #[serde(rename = "policy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<VaultAccessPolicy>,
+    /// <p>The name of the vault.</p>
+    #[serde(rename = "vaultName")]
+    pub vault_name: String,
+}
+
+impl SetVaultAccessPolicyInput {
+    /// Returns a builder for `SetVaultAccessPolicyInput`, checking its required members when built.
+    pub fn builder() -> SetVaultAccessPolicyInputBuilder {
+        SetVaultAccessPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetVaultAccessPolicyInput`](struct.SetVaultAccessPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct SetVaultAccessPolicyInputBuilder {
+    account_id: Option<String>,
+    policy: Option<VaultAccessPolicy>,
+    vault_name: Option<String>,
+}
+
+impl SetVaultAccessPolicyInputBuilder {
+    /// Sets the [`account_id`](struct.SetVaultAccessPolicyInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.SetVaultAccessPolicyInput.html#structfield.policy) member.
+    pub fn policy<T: Into<VaultAccessPolicy>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.SetVaultAccessPolicyInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetVaultAccessPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetVaultAccessPolicyInput, ::rusoto_core::BuildError> {
+        Ok(SetVaultAccessPolicyInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            policy: self.policy,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
+/// <p>Provides options to configure notifications that will be sent when specific events happen to a vault.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct SetVaultNotificationsInput {
+    /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    /// <p>The name of the vault.</p>
+    #[serde(rename = "vaultName")]
+    pub vault_name: String,
+    /// <p>Provides options for specifying notification configuration.</p>
+    #[serde(rename = "vaultNotificationConfig")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vault_notification_config: Option<VaultNotificationConfig>,
+}
+
+impl SetVaultNotificationsInput {
+    /// Returns a builder for `SetVaultNotificationsInput`, checking its required members when built.
+    pub fn builder() -> SetVaultNotificationsInputBuilder {
+        SetVaultNotificationsInputBuilder::default()
+    }
+}
+
+/// Builds a [`SetVaultNotificationsInput`](struct.SetVaultNotificationsInput.html).
+#[derive(Default, Debug)]
+pub struct SetVaultNotificationsInputBuilder {
+    account_id: Option<String>,
+    vault_name: Option<String>,
+    vault_notification_config: Option<VaultNotificationConfig>,
+}
+
+impl SetVaultNotificationsInputBuilder {
+    /// Sets the [`account_id`](struct.SetVaultNotificationsInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.SetVaultNotificationsInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_notification_config`](struct.SetVaultNotificationsInput.html#structfield.vault_notification_config) member.
+    pub fn vault_notification_config<T: Into<VaultNotificationConfig>>(mut self, value: T) -> Self {
+        self.vault_notification_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetVaultNotificationsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetVaultNotificationsInput, ::rusoto_core::BuildError> {
+        Ok(SetVaultNotificationsInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+            vault_notification_config: self.vault_notification_config,
+        })
+    }
+}
+
+/// <p>The status code can be <code>InProgress</code>, <code>Succeeded</code>, or <code>Failed</code>, and indicates the status of the job.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StatusCode {
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusCode::InProgress => "InProgress",
+            StatusCode::Succeeded => "Succeeded",
+            StatusCode::Failed => "Failed",
+            StatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StatusCode {
+    fn from(value: &'a str) -> StatusCode {
+        match value {
+            "InProgress" => StatusCode::InProgress,
+            "Succeeded" => StatusCode::Succeeded,
+            "Failed" => StatusCode::Failed,
+            _ => StatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusCode {
+    fn from(value: String) -> StatusCode {
+        StatusCode::from(value.as_str())
+    }
+}
+
+impl From<StatusCode> for String {
+    fn from(value: StatusCode) -> String {
+        match value {
+            StatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StatusCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StatusCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StatusCode::from(value))
+    }
+}
+
+/// <p>The storage class used to store the job results.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StorageClass {
+    /// <p><code>STANDARD</code></p>
+    Standard,
+    /// <p><code>REDUCED_REDUNDANCY</code></p>
+    ReducedRedundancy,
+    /// <p><code>STANDARD_IA</code></p>
+    StandardIa,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StorageClass {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StorageClass::Standard => "STANDARD",
+            StorageClass::ReducedRedundancy => "REDUCED_REDUNDANCY",
+            StorageClass::StandardIa => "STANDARD_IA",
+            StorageClass::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StorageClass {
+    fn from(value: &'a str) -> StorageClass {
+        match value {
+            "STANDARD" => StorageClass::Standard,
+            "REDUCED_REDUNDANCY" => StorageClass::ReducedRedundancy,
+            "STANDARD_IA" => StorageClass::StandardIa,
+            _ => StorageClass::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StorageClass {
+    fn from(value: String) -> StorageClass {
+        StorageClass::from(value.as_str())
+    }
+}
+
+impl From<StorageClass> for String {
+    fn from(value: StorageClass) -> String {
+        match value {
+            StorageClass::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StorageClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StorageClass {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StorageClass {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StorageClass {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StorageClass, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StorageClass::from(value))
+    }
+}
+
+/// <p>Type of grantee</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Type {
+    /// <p><code>AmazonCustomerByEmail</code></p>
+    AmazonCustomerByEmail,
+    /// <p><code>CanonicalUser</code></p>
+    CanonicalUser,
+    /// <p><code>Group</code></p>
+    Group,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Type {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Type::AmazonCustomerByEmail => "AmazonCustomerByEmail",
+            Type::CanonicalUser => "CanonicalUser",
+            Type::Group => "Group",
+            Type::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Type {
+    fn from(value: &'a str) -> Type {
+        match value {
+            "AmazonCustomerByEmail" => Type::AmazonCustomerByEmail,
+            "CanonicalUser" => Type::CanonicalUser,
+            "Group" => Type::Group,
+            _ => Type::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Type {
+    fn from(value: String) -> Type {
+        Type::from(value.as_str())
+    }
+}
+
+impl From<Type> for String {
+    fn from(value: Type) -> String {
+        match value {
+            Type::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Type {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-/// <p>Provides options to configure notifications that will be sent when specific events happen to a vault.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct SetVaultNotificationsInput {
-    /// <p>The <code>AccountId</code> value is the AWS account ID of the account that owns the vault. You can either specify an AWS account ID or optionally a single '<code>-</code>' (hyphen), in which case Amazon S3 Glacier uses the AWS account ID associated with the credentials used to sign the request. If you use an account ID, do not include any hyphens ('-') in the ID.</p>
-    #[serde(rename = "accountId")]
-    pub account_id: String,
-    /// <p>The name of the vault.</p>
-    #[serde(rename = "vaultName")]
-    pub vault_name: String,
-    /// <p>Provides options for specifying notification configuration.</p>
-    #[serde(rename = "vaultNotificationConfig")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub vault_notification_config: Option<VaultNotificationConfig>,
+impl<'de> ::serde::Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Type, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Type::from(value))
+    }
 }
 
 /// <p>Provides options to add an archive to a vault.</p>
@@ -1189,6 +3484,70 @@ pub struct UploadArchiveInput {
     pub vault_name: String,
 }
 
+impl UploadArchiveInput {
+    /// Returns a builder for `UploadArchiveInput`, checking its required members when built.
+    pub fn builder() -> UploadArchiveInputBuilder {
+        UploadArchiveInputBuilder::default()
+    }
+}
+
+/// Builds a [`UploadArchiveInput`](struct.UploadArchiveInput.html).
+#[derive(Default, Debug)]
+pub struct UploadArchiveInputBuilder {
+    account_id: Option<String>,
+    archive_description: Option<String>,
+    body: Option<bytes::Bytes>,
+    checksum: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl UploadArchiveInputBuilder {
+    /// Sets the [`account_id`](struct.UploadArchiveInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`archive_description`](struct.UploadArchiveInput.html#structfield.archive_description) member.
+    pub fn archive_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.archive_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`body`](struct.UploadArchiveInput.html#structfield.body) member.
+    pub fn body<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`checksum`](struct.UploadArchiveInput.html#structfield.checksum) member.
+    pub fn checksum<T: Into<String>>(mut self, value: T) -> Self {
+        self.checksum = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.UploadArchiveInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UploadArchiveInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UploadArchiveInput, ::rusoto_core::BuildError> {
+        Ok(UploadArchiveInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            archive_description: self.archive_description,
+            body: self.body,
+            checksum: self.checksum,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>A list of in-progress multipart uploads for a vault.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1246,6 +3605,82 @@ pub struct UploadMultipartPartInput {
     pub vault_name: String,
 }
 
+impl UploadMultipartPartInput {
+    /// Returns a builder for `UploadMultipartPartInput`, checking its required members when built.
+    pub fn builder() -> UploadMultipartPartInputBuilder {
+        UploadMultipartPartInputBuilder::default()
+    }
+}
+
+/// Builds a [`UploadMultipartPartInput`](struct.UploadMultipartPartInput.html).
+#[derive(Default, Debug)]
+pub struct UploadMultipartPartInputBuilder {
+    account_id: Option<String>,
+    body: Option<bytes::Bytes>,
+    checksum: Option<String>,
+    range: Option<String>,
+    upload_id: Option<String>,
+    vault_name: Option<String>,
+}
+
+impl UploadMultipartPartInputBuilder {
+    /// Sets the [`account_id`](struct.UploadMultipartPartInput.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`body`](struct.UploadMultipartPartInput.html#structfield.body) member.
+    pub fn body<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`checksum`](struct.UploadMultipartPartInput.html#structfield.checksum) member.
+    pub fn checksum<T: Into<String>>(mut self, value: T) -> Self {
+        self.checksum = Some(value.into());
+        self
+    }
+
+    /// Sets the [`range`](struct.UploadMultipartPartInput.html#structfield.range) member.
+    pub fn range<T: Into<String>>(mut self, value: T) -> Self {
+        self.range = Some(value.into());
+        self
+    }
+
+    /// Sets the [`upload_id`](struct.UploadMultipartPartInput.html#structfield.upload_id) member.
+    pub fn upload_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.upload_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vault_name`](struct.UploadMultipartPartInput.html#structfield.vault_name) member.
+    pub fn vault_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.vault_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UploadMultipartPartInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UploadMultipartPartInput, ::rusoto_core::BuildError> {
+        Ok(UploadMultipartPartInput {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            body: self.body,
+            checksum: self.checksum,
+            range: self.range,
+            upload_id: self
+                .upload_id
+                .ok_or(::rusoto_core::BuildError::missing("upload_id"))?,
+            vault_name: self
+                .vault_name
+                .ok_or(::rusoto_core::BuildError::missing("vault_name"))?,
+        })
+    }
+}
+
 /// <p>Contains the Amazon S3 Glacier response to your request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3316,6 +5751,201 @@ pub trait Glacier {
         input: UploadMultipartPartInput,
     ) -> RusotoFuture<UploadMultipartPartOutput, UploadMultipartPartError>;
 }
+impl<T: ?Sized + Glacier> Glacier for ::std::sync::Arc<T> {
+    fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadInput,
+    ) -> RusotoFuture<(), AbortMultipartUploadError> {
+        (**self).abort_multipart_upload(input)
+    }
+    fn abort_vault_lock(
+        &self,
+        input: AbortVaultLockInput,
+    ) -> RusotoFuture<(), AbortVaultLockError> {
+        (**self).abort_vault_lock(input)
+    }
+    fn add_tags_to_vault(
+        &self,
+        input: AddTagsToVaultInput,
+    ) -> RusotoFuture<(), AddTagsToVaultError> {
+        (**self).add_tags_to_vault(input)
+    }
+    fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadInput,
+    ) -> RusotoFuture<ArchiveCreationOutput, CompleteMultipartUploadError> {
+        (**self).complete_multipart_upload(input)
+    }
+    fn complete_vault_lock(
+        &self,
+        input: CompleteVaultLockInput,
+    ) -> RusotoFuture<(), CompleteVaultLockError> {
+        (**self).complete_vault_lock(input)
+    }
+    fn create_vault(
+        &self,
+        input: CreateVaultInput,
+    ) -> RusotoFuture<CreateVaultOutput, CreateVaultError> {
+        (**self).create_vault(input)
+    }
+    fn delete_archive(&self, input: DeleteArchiveInput) -> RusotoFuture<(), DeleteArchiveError> {
+        (**self).delete_archive(input)
+    }
+    fn delete_vault(&self, input: DeleteVaultInput) -> RusotoFuture<(), DeleteVaultError> {
+        (**self).delete_vault(input)
+    }
+    fn delete_vault_access_policy(
+        &self,
+        input: DeleteVaultAccessPolicyInput,
+    ) -> RusotoFuture<(), DeleteVaultAccessPolicyError> {
+        (**self).delete_vault_access_policy(input)
+    }
+    fn delete_vault_notifications(
+        &self,
+        input: DeleteVaultNotificationsInput,
+    ) -> RusotoFuture<(), DeleteVaultNotificationsError> {
+        (**self).delete_vault_notifications(input)
+    }
+    fn describe_job(
+        &self,
+        input: DescribeJobInput,
+    ) -> RusotoFuture<GlacierJobDescription, DescribeJobError> {
+        (**self).describe_job(input)
+    }
+    fn describe_vault(
+        &self,
+        input: DescribeVaultInput,
+    ) -> RusotoFuture<DescribeVaultOutput, DescribeVaultError> {
+        (**self).describe_vault(input)
+    }
+    fn get_data_retrieval_policy(
+        &self,
+        input: GetDataRetrievalPolicyInput,
+    ) -> RusotoFuture<GetDataRetrievalPolicyOutput, GetDataRetrievalPolicyError> {
+        (**self).get_data_retrieval_policy(input)
+    }
+    fn get_job_output(
+        &self,
+        input: GetJobOutputInput,
+    ) -> RusotoFuture<GetJobOutputOutput, GetJobOutputError> {
+        (**self).get_job_output(input)
+    }
+    fn get_vault_access_policy(
+        &self,
+        input: GetVaultAccessPolicyInput,
+    ) -> RusotoFuture<GetVaultAccessPolicyOutput, GetVaultAccessPolicyError> {
+        (**self).get_vault_access_policy(input)
+    }
+    fn get_vault_lock(
+        &self,
+        input: GetVaultLockInput,
+    ) -> RusotoFuture<GetVaultLockOutput, GetVaultLockError> {
+        (**self).get_vault_lock(input)
+    }
+    fn get_vault_notifications(
+        &self,
+        input: GetVaultNotificationsInput,
+    ) -> RusotoFuture<GetVaultNotificationsOutput, GetVaultNotificationsError> {
+        (**self).get_vault_notifications(input)
+    }
+    fn initiate_job(
+        &self,
+        input: InitiateJobInput,
+    ) -> RusotoFuture<InitiateJobOutput, InitiateJobError> {
+        (**self).initiate_job(input)
+    }
+    fn initiate_multipart_upload(
+        &self,
+        input: InitiateMultipartUploadInput,
+    ) -> RusotoFuture<InitiateMultipartUploadOutput, InitiateMultipartUploadError> {
+        (**self).initiate_multipart_upload(input)
+    }
+    fn initiate_vault_lock(
+        &self,
+        input: InitiateVaultLockInput,
+    ) -> RusotoFuture<InitiateVaultLockOutput, InitiateVaultLockError> {
+        (**self).initiate_vault_lock(input)
+    }
+    fn list_jobs(&self, input: ListJobsInput) -> RusotoFuture<ListJobsOutput, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> RusotoFuture<ListMultipartUploadsOutput, ListMultipartUploadsError> {
+        (**self).list_multipart_uploads(input)
+    }
+    fn list_parts(&self, input: ListPartsInput) -> RusotoFuture<ListPartsOutput, ListPartsError> {
+        (**self).list_parts(input)
+    }
+    fn list_provisioned_capacity(
+        &self,
+        input: ListProvisionedCapacityInput,
+    ) -> RusotoFuture<ListProvisionedCapacityOutput, ListProvisionedCapacityError> {
+        (**self).list_provisioned_capacity(input)
+    }
+    fn list_tags_for_vault(
+        &self,
+        input: ListTagsForVaultInput,
+    ) -> RusotoFuture<ListTagsForVaultOutput, ListTagsForVaultError> {
+        (**self).list_tags_for_vault(input)
+    }
+    fn list_vaults(
+        &self,
+        input: ListVaultsInput,
+    ) -> RusotoFuture<ListVaultsOutput, ListVaultsError> {
+        (**self).list_vaults(input)
+    }
+    fn purchase_provisioned_capacity(
+        &self,
+        input: PurchaseProvisionedCapacityInput,
+    ) -> RusotoFuture<PurchaseProvisionedCapacityOutput, PurchaseProvisionedCapacityError> {
+        (**self).purchase_provisioned_capacity(input)
+    }
+    fn remove_tags_from_vault(
+        &self,
+        input: RemoveTagsFromVaultInput,
+    ) -> RusotoFuture<(), RemoveTagsFromVaultError> {
+        (**self).remove_tags_from_vault(input)
+    }
+    fn set_data_retrieval_policy(
+        &self,
+        input: SetDataRetrievalPolicyInput,
+    ) -> RusotoFuture<(), SetDataRetrievalPolicyError> {
+        (**self).set_data_retrieval_policy(input)
+    }
+    fn set_vault_access_policy(
+        &self,
+        input: SetVaultAccessPolicyInput,
+    ) -> RusotoFuture<(), SetVaultAccessPolicyError> {
+        (**self).set_vault_access_policy(input)
+    }
+    fn set_vault_notifications(
+        &self,
+        input: SetVaultNotificationsInput,
+    ) -> RusotoFuture<(), SetVaultNotificationsError> {
+        (**self).set_vault_notifications(input)
+    }
+    fn upload_archive(
+        &self,
+        input: UploadArchiveInput,
+    ) -> RusotoFuture<ArchiveCreationOutput, UploadArchiveError> {
+        (**self).upload_archive(input)
+    }
+    fn upload_multipart_part(
+        &self,
+        input: UploadMultipartPartInput,
+    ) -> RusotoFuture<UploadMultipartPartOutput, UploadMultipartPartError> {
+        (**self).upload_multipart_part(input)
+    }
+}
+/// The error codes Amazon Glacier retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &["RequestTimeoutException"],
+    };
+
 /// A client for the Amazon Glacier API.
 #[derive(Clone)]
 pub struct GlacierClient {
@@ -3331,6 +5961,27 @@ impl GlacierClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_GLACIER`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> GlacierClient {
+        Self::new(region::Region::from_env_for_service("GLACIER"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `glacier`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> GlacierClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("glacier", "GLACIER"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3349,7 +6000,49 @@ impl GlacierClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> GlacierClient {
-        GlacierClient { client, region }
+        GlacierClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "glacier")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "glacier", "glacier", "GLACIER")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Glacier` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Glacier + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for GlacierClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Glacier"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "glacier"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> GlacierClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Glue clients.
+//!
+//! Makes a single read-only `ListCrawlers` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_glue::{Glue, GlueClient, ListCrawlersRequest};
+
+fn main() {
+    let client = GlueClient::from_env();
+    match client.list_crawlers(ListCrawlersRequest::default()).sync() {
+        Ok(_) => println!("ListCrawlers succeeded"),
+        Err(error) => {
+            eprintln!("ListCrawlers failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
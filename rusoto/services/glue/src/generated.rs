@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Defines an action to be initiated by a trigger.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -69,6 +70,66 @@ pub struct BatchCreatePartitionRequest {
     pub table_name: String,
 }
 
+impl BatchCreatePartitionRequest {
+    /// Returns a builder for `BatchCreatePartitionRequest`, checking its required members when built.
+    pub fn builder() -> BatchCreatePartitionRequestBuilder {
+        BatchCreatePartitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchCreatePartitionRequest`](struct.BatchCreatePartitionRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchCreatePartitionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    partition_input_list: Option<Vec<PartitionInput>>,
+    table_name: Option<String>,
+}
+
+impl BatchCreatePartitionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.BatchCreatePartitionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.BatchCreatePartitionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`partition_input_list`](struct.BatchCreatePartitionRequest.html#structfield.partition_input_list) member.
+    pub fn partition_input_list<T: Into<Vec<PartitionInput>>>(mut self, value: T) -> Self {
+        self.partition_input_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.BatchCreatePartitionRequest.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchCreatePartitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchCreatePartitionRequest, ::rusoto_core::BuildError> {
+        Ok(BatchCreatePartitionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            partition_input_list: self
+                .partition_input_list
+                .ok_or(::rusoto_core::BuildError::missing("partition_input_list"))?,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchCreatePartitionResponse {
@@ -89,6 +150,46 @@ pub struct BatchDeleteConnectionRequest {
     pub connection_name_list: Vec<String>,
 }
 
+impl BatchDeleteConnectionRequest {
+    /// Returns a builder for `BatchDeleteConnectionRequest`, checking its required members when built.
+    pub fn builder() -> BatchDeleteConnectionRequestBuilder {
+        BatchDeleteConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDeleteConnectionRequest`](struct.BatchDeleteConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDeleteConnectionRequestBuilder {
+    catalog_id: Option<String>,
+    connection_name_list: Option<Vec<String>>,
+}
+
+impl BatchDeleteConnectionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.BatchDeleteConnectionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_name_list`](struct.BatchDeleteConnectionRequest.html#structfield.connection_name_list) member.
+    pub fn connection_name_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.connection_name_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDeleteConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDeleteConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDeleteConnectionRequest {
+            catalog_id: self.catalog_id,
+            connection_name_list: self
+                .connection_name_list
+                .ok_or(::rusoto_core::BuildError::missing("connection_name_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDeleteConnectionResponse {
@@ -119,6 +220,66 @@ pub struct BatchDeletePartitionRequest {
     pub table_name: String,
 }
 
+impl BatchDeletePartitionRequest {
+    /// Returns a builder for `BatchDeletePartitionRequest`, checking its required members when built.
+    pub fn builder() -> BatchDeletePartitionRequestBuilder {
+        BatchDeletePartitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDeletePartitionRequest`](struct.BatchDeletePartitionRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDeletePartitionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    partitions_to_delete: Option<Vec<PartitionValueList>>,
+    table_name: Option<String>,
+}
+
+impl BatchDeletePartitionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.BatchDeletePartitionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.BatchDeletePartitionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`partitions_to_delete`](struct.BatchDeletePartitionRequest.html#structfield.partitions_to_delete) member.
+    pub fn partitions_to_delete<T: Into<Vec<PartitionValueList>>>(mut self, value: T) -> Self {
+        self.partitions_to_delete = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.BatchDeletePartitionRequest.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDeletePartitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDeletePartitionRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDeletePartitionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            partitions_to_delete: self
+                .partitions_to_delete
+                .ok_or(::rusoto_core::BuildError::missing("partitions_to_delete"))?,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDeletePartitionResponse {
@@ -142,6 +303,56 @@ pub struct BatchDeleteTableRequest {
     pub tables_to_delete: Vec<String>,
 }
 
+impl BatchDeleteTableRequest {
+    /// Returns a builder for `BatchDeleteTableRequest`, checking its required members when built.
+    pub fn builder() -> BatchDeleteTableRequestBuilder {
+        BatchDeleteTableRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDeleteTableRequest`](struct.BatchDeleteTableRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDeleteTableRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    tables_to_delete: Option<Vec<String>>,
+}
+
+impl BatchDeleteTableRequestBuilder {
+    /// Sets the [`catalog_id`](struct.BatchDeleteTableRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.BatchDeleteTableRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tables_to_delete`](struct.BatchDeleteTableRequest.html#structfield.tables_to_delete) member.
+    pub fn tables_to_delete<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tables_to_delete = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDeleteTableRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDeleteTableRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDeleteTableRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            tables_to_delete: self
+                .tables_to_delete
+                .ok_or(::rusoto_core::BuildError::missing("tables_to_delete"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDeleteTableResponse {
@@ -168,6 +379,66 @@ pub struct BatchDeleteTableVersionRequest {
     pub version_ids: Vec<String>,
 }
 
+impl BatchDeleteTableVersionRequest {
+    /// Returns a builder for `BatchDeleteTableVersionRequest`, checking its required members when built.
+    pub fn builder() -> BatchDeleteTableVersionRequestBuilder {
+        BatchDeleteTableVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDeleteTableVersionRequest`](struct.BatchDeleteTableVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDeleteTableVersionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    table_name: Option<String>,
+    version_ids: Option<Vec<String>>,
+}
+
+impl BatchDeleteTableVersionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.BatchDeleteTableVersionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.BatchDeleteTableVersionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.BatchDeleteTableVersionRequest.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_ids`](struct.BatchDeleteTableVersionRequest.html#structfield.version_ids) member.
+    pub fn version_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.version_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDeleteTableVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDeleteTableVersionRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDeleteTableVersionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+            version_ids: self
+                .version_ids
+                .ok_or(::rusoto_core::BuildError::missing("version_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchDeleteTableVersionResponse {
@@ -184,6 +455,38 @@ pub struct BatchGetCrawlersRequest {
     pub crawler_names: Vec<String>,
 }
 
+impl BatchGetCrawlersRequest {
+    /// Returns a builder for `BatchGetCrawlersRequest`, checking its required members when built.
+    pub fn builder() -> BatchGetCrawlersRequestBuilder {
+        BatchGetCrawlersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetCrawlersRequest`](struct.BatchGetCrawlersRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchGetCrawlersRequestBuilder {
+    crawler_names: Option<Vec<String>>,
+}
+
+impl BatchGetCrawlersRequestBuilder {
+    /// Sets the [`crawler_names`](struct.BatchGetCrawlersRequest.html#structfield.crawler_names) member.
+    pub fn crawler_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.crawler_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetCrawlersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetCrawlersRequest, ::rusoto_core::BuildError> {
+        Ok(BatchGetCrawlersRequest {
+            crawler_names: self
+                .crawler_names
+                .ok_or(::rusoto_core::BuildError::missing("crawler_names"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetCrawlersResponse {
@@ -204,6 +507,38 @@ pub struct BatchGetDevEndpointsRequest {
     pub dev_endpoint_names: Vec<String>,
 }
 
+impl BatchGetDevEndpointsRequest {
+    /// Returns a builder for `BatchGetDevEndpointsRequest`, checking its required members when built.
+    pub fn builder() -> BatchGetDevEndpointsRequestBuilder {
+        BatchGetDevEndpointsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetDevEndpointsRequest`](struct.BatchGetDevEndpointsRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchGetDevEndpointsRequestBuilder {
+    dev_endpoint_names: Option<Vec<String>>,
+}
+
+impl BatchGetDevEndpointsRequestBuilder {
+    /// Sets the [`dev_endpoint_names`](struct.BatchGetDevEndpointsRequest.html#structfield.dev_endpoint_names) member.
+    pub fn dev_endpoint_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.dev_endpoint_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetDevEndpointsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetDevEndpointsRequest, ::rusoto_core::BuildError> {
+        Ok(BatchGetDevEndpointsRequest {
+            dev_endpoint_names: self
+                .dev_endpoint_names
+                .ok_or(::rusoto_core::BuildError::missing("dev_endpoint_names"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetDevEndpointsResponse {
@@ -224,6 +559,36 @@ pub struct BatchGetJobsRequest {
     pub job_names: Vec<String>,
 }
 
+impl BatchGetJobsRequest {
+    /// Returns a builder for `BatchGetJobsRequest`, checking its required members when built.
+    pub fn builder() -> BatchGetJobsRequestBuilder {
+        BatchGetJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetJobsRequest`](struct.BatchGetJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchGetJobsRequestBuilder {
+    job_names: Option<Vec<String>>,
+}
+
+impl BatchGetJobsRequestBuilder {
+    /// Sets the [`job_names`](struct.BatchGetJobsRequest.html#structfield.job_names) member.
+    pub fn job_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.job_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchGetJobsRequest, ::rusoto_core::BuildError> {
+        Ok(BatchGetJobsRequest {
+            job_names: self
+                .job_names
+                .ok_or(::rusoto_core::BuildError::missing("job_names"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetJobsResponse {
@@ -254,6 +619,66 @@ pub struct BatchGetPartitionRequest {
     pub table_name: String,
 }
 
+impl BatchGetPartitionRequest {
+    /// Returns a builder for `BatchGetPartitionRequest`, checking its required members when built.
+    pub fn builder() -> BatchGetPartitionRequestBuilder {
+        BatchGetPartitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetPartitionRequest`](struct.BatchGetPartitionRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchGetPartitionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    partitions_to_get: Option<Vec<PartitionValueList>>,
+    table_name: Option<String>,
+}
+
+impl BatchGetPartitionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.BatchGetPartitionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.BatchGetPartitionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`partitions_to_get`](struct.BatchGetPartitionRequest.html#structfield.partitions_to_get) member.
+    pub fn partitions_to_get<T: Into<Vec<PartitionValueList>>>(mut self, value: T) -> Self {
+        self.partitions_to_get = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.BatchGetPartitionRequest.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetPartitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetPartitionRequest, ::rusoto_core::BuildError> {
+        Ok(BatchGetPartitionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            partitions_to_get: self
+                .partitions_to_get
+                .ok_or(::rusoto_core::BuildError::missing("partitions_to_get"))?,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetPartitionResponse {
@@ -274,6 +699,38 @@ pub struct BatchGetTriggersRequest {
     pub trigger_names: Vec<String>,
 }
 
+impl BatchGetTriggersRequest {
+    /// Returns a builder for `BatchGetTriggersRequest`, checking its required members when built.
+    pub fn builder() -> BatchGetTriggersRequestBuilder {
+        BatchGetTriggersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetTriggersRequest`](struct.BatchGetTriggersRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchGetTriggersRequestBuilder {
+    trigger_names: Option<Vec<String>>,
+}
+
+impl BatchGetTriggersRequestBuilder {
+    /// Sets the [`trigger_names`](struct.BatchGetTriggersRequest.html#structfield.trigger_names) member.
+    pub fn trigger_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.trigger_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetTriggersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetTriggersRequest, ::rusoto_core::BuildError> {
+        Ok(BatchGetTriggersRequest {
+            trigger_names: self
+                .trigger_names
+                .ok_or(::rusoto_core::BuildError::missing("trigger_names"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetTriggersResponse {
@@ -298,6 +755,46 @@ pub struct BatchGetWorkflowsRequest {
     pub names: Vec<String>,
 }
 
+impl BatchGetWorkflowsRequest {
+    /// Returns a builder for `BatchGetWorkflowsRequest`, checking its required members when built.
+    pub fn builder() -> BatchGetWorkflowsRequestBuilder {
+        BatchGetWorkflowsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchGetWorkflowsRequest`](struct.BatchGetWorkflowsRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchGetWorkflowsRequestBuilder {
+    include_graph: Option<bool>,
+    names: Option<Vec<String>>,
+}
+
+impl BatchGetWorkflowsRequestBuilder {
+    /// Sets the [`include_graph`](struct.BatchGetWorkflowsRequest.html#structfield.include_graph) member.
+    pub fn include_graph<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_graph = Some(value.into());
+        self
+    }
+
+    /// Sets the [`names`](struct.BatchGetWorkflowsRequest.html#structfield.names) member.
+    pub fn names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.names = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchGetWorkflowsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchGetWorkflowsRequest, ::rusoto_core::BuildError> {
+        Ok(BatchGetWorkflowsRequest {
+            include_graph: self.include_graph,
+            names: self
+                .names
+                .ok_or(::rusoto_core::BuildError::missing("names"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchGetWorkflowsResponse {
@@ -339,6 +836,46 @@ pub struct BatchStopJobRunRequest {
     pub job_run_ids: Vec<String>,
 }
 
+impl BatchStopJobRunRequest {
+    /// Returns a builder for `BatchStopJobRunRequest`, checking its required members when built.
+    pub fn builder() -> BatchStopJobRunRequestBuilder {
+        BatchStopJobRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchStopJobRunRequest`](struct.BatchStopJobRunRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchStopJobRunRequestBuilder {
+    job_name: Option<String>,
+    job_run_ids: Option<Vec<String>>,
+}
+
+impl BatchStopJobRunRequestBuilder {
+    /// Sets the [`job_name`](struct.BatchStopJobRunRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_run_ids`](struct.BatchStopJobRunRequest.html#structfield.job_run_ids) member.
+    pub fn job_run_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.job_run_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchStopJobRunRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchStopJobRunRequest, ::rusoto_core::BuildError> {
+        Ok(BatchStopJobRunRequest {
+            job_name: self
+                .job_name
+                .ok_or(::rusoto_core::BuildError::missing("job_name"))?,
+            job_run_ids: self
+                .job_run_ids
+                .ok_or(::rusoto_core::BuildError::missing("job_run_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchStopJobRunResponse {
@@ -376,13 +913,53 @@ pub struct CancelMLTaskRunRequest {
     pub transform_id: String,
 }
 
+impl CancelMLTaskRunRequest {
+    /// Returns a builder for `CancelMLTaskRunRequest`, checking its required members when built.
+    pub fn builder() -> CancelMLTaskRunRequestBuilder {
+        CancelMLTaskRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelMLTaskRunRequest`](struct.CancelMLTaskRunRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelMLTaskRunRequestBuilder {
+    task_run_id: Option<String>,
+    transform_id: Option<String>,
+}
+
+impl CancelMLTaskRunRequestBuilder {
+    /// Sets the [`task_run_id`](struct.CancelMLTaskRunRequest.html#structfield.task_run_id) member.
+    pub fn task_run_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_run_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transform_id`](struct.CancelMLTaskRunRequest.html#structfield.transform_id) member.
+    pub fn transform_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transform_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelMLTaskRunRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelMLTaskRunRequest, ::rusoto_core::BuildError> {
+        Ok(CancelMLTaskRunRequest {
+            task_run_id: self
+                .task_run_id
+                .ok_or(::rusoto_core::BuildError::missing("task_run_id"))?,
+            transform_id: self
+                .transform_id
+                .ok_or(::rusoto_core::BuildError::missing("transform_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelMLTaskRunResponse {
     /// <p>The status for this run.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskStatusType>,
     /// <p>The unique identifier for the task run.</p>
     #[serde(rename = "TaskRunId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -393,6 +970,78 @@ pub struct CancelMLTaskRunResponse {
     pub transform_id: Option<String>,
 }
 
+/// <p>The encryption-at-rest mode for encrypting Data Catalog data.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CatalogEncryptionMode {
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// <p><code>SSE-KMS</code></p>
+    SseKms,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CatalogEncryptionMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CatalogEncryptionMode::Disabled => "DISABLED",
+            CatalogEncryptionMode::SseKms => "SSE-KMS",
+            CatalogEncryptionMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CatalogEncryptionMode {
+    fn from(value: &'a str) -> CatalogEncryptionMode {
+        match value {
+            "DISABLED" => CatalogEncryptionMode::Disabled,
+            "SSE-KMS" => CatalogEncryptionMode::SseKms,
+            _ => CatalogEncryptionMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CatalogEncryptionMode {
+    fn from(value: String) -> CatalogEncryptionMode {
+        CatalogEncryptionMode::from(value.as_str())
+    }
+}
+
+impl From<CatalogEncryptionMode> for String {
+    fn from(value: CatalogEncryptionMode) -> String {
+        match value {
+            CatalogEncryptionMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CatalogEncryptionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CatalogEncryptionMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CatalogEncryptionMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CatalogEncryptionMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CatalogEncryptionMode::from(value))
+    }
+}
+
 /// <p>Specifies a table definition in the AWS Glue Data Catalog.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CatalogEntry {
@@ -414,8 +1063,9 @@ pub struct CatalogImportStatus {
     pub import_completed: Option<bool>,
     /// <p>The time that the migration was started.</p>
     #[serde(rename = "ImportTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_time: Option<f64>,
+    pub import_time: Option<EpochTimestamp>,
     /// <p>The name of the person who initiated the migration.</p>
     #[serde(rename = "ImportedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -461,13 +1111,85 @@ pub struct CloudWatchEncryption {
     /// <p>The encryption mode to use for CloudWatch data.</p>
     #[serde(rename = "CloudWatchEncryptionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cloud_watch_encryption_mode: Option<String>,
+    pub cloud_watch_encryption_mode: Option<CloudWatchEncryptionMode>,
     /// <p>The Amazon Resource Name (ARN) of the KMS key to be used to encrypt the data.</p>
     #[serde(rename = "KmsKeyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key_arn: Option<String>,
 }
 
+/// <p>The encryption mode to use for CloudWatch data.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CloudWatchEncryptionMode {
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// <p><code>SSE-KMS</code></p>
+    SseKms,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CloudWatchEncryptionMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudWatchEncryptionMode::Disabled => "DISABLED",
+            CloudWatchEncryptionMode::SseKms => "SSE-KMS",
+            CloudWatchEncryptionMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CloudWatchEncryptionMode {
+    fn from(value: &'a str) -> CloudWatchEncryptionMode {
+        match value {
+            "DISABLED" => CloudWatchEncryptionMode::Disabled,
+            "SSE-KMS" => CloudWatchEncryptionMode::SseKms,
+            _ => CloudWatchEncryptionMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudWatchEncryptionMode {
+    fn from(value: String) -> CloudWatchEncryptionMode {
+        CloudWatchEncryptionMode::from(value.as_str())
+    }
+}
+
+impl From<CloudWatchEncryptionMode> for String {
+    fn from(value: CloudWatchEncryptionMode) -> String {
+        match value {
+            CloudWatchEncryptionMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CloudWatchEncryptionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CloudWatchEncryptionMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CloudWatchEncryptionMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CloudWatchEncryptionMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CloudWatchEncryptionMode::from(value))
+    }
+}
+
 /// <p>Represents a directional edge in a directed acyclic graph (DAG).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CodeGenEdge {
@@ -536,29 +1258,113 @@ pub struct Column {
     pub type_: Option<String>,
 }
 
-/// <p>Defines a condition under which a trigger fires.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Condition {
-    /// <p>The state of the crawler to which this condition applies.</p>
-    #[serde(rename = "CrawlState")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub crawl_state: Option<String>,
-    /// <p>The name of the crawler to which this condition applies.</p>
-    #[serde(rename = "CrawlerName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub crawler_name: Option<String>,
-    /// <p>The name of the job whose <code>JobRuns</code> this condition applies to, and on which this trigger waits.</p>
-    #[serde(rename = "JobName")]
+/// <p>The comparator used to compare this property to others.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Comparator {
+    /// <p><code>EQUALS</code></p>
+    Equals,
+    /// <p><code>GREATER_THAN</code></p>
+    GreaterThan,
+    /// <p><code>LESS_THAN</code></p>
+    LessThan,
+    /// <p><code>GREATER_THAN_EQUALS</code></p>
+    GreaterThanEquals,
+    /// <p><code>LESS_THAN_EQUALS</code></p>
+    LessThanEquals,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Comparator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Comparator::Equals => "EQUALS",
+            Comparator::GreaterThan => "GREATER_THAN",
+            Comparator::LessThan => "LESS_THAN",
+            Comparator::GreaterThanEquals => "GREATER_THAN_EQUALS",
+            Comparator::LessThanEquals => "LESS_THAN_EQUALS",
+            Comparator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Comparator {
+    fn from(value: &'a str) -> Comparator {
+        match value {
+            "EQUALS" => Comparator::Equals,
+            "GREATER_THAN" => Comparator::GreaterThan,
+            "LESS_THAN" => Comparator::LessThan,
+            "GREATER_THAN_EQUALS" => Comparator::GreaterThanEquals,
+            "LESS_THAN_EQUALS" => Comparator::LessThanEquals,
+            _ => Comparator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Comparator {
+    fn from(value: String) -> Comparator {
+        Comparator::from(value.as_str())
+    }
+}
+
+impl From<Comparator> for String {
+    fn from(value: Comparator) -> String {
+        match value {
+            Comparator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Comparator {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Comparator {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Comparator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Comparator::from(value))
+    }
+}
+
+/// <p>Defines a condition under which a trigger fires.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Condition {
+    /// <p>The state of the crawler to which this condition applies.</p>
+    #[serde(rename = "CrawlState")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crawl_state: Option<CrawlState>,
+    /// <p>The name of the crawler to which this condition applies.</p>
+    #[serde(rename = "CrawlerName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crawler_name: Option<String>,
+    /// <p>The name of the job whose <code>JobRuns</code> this condition applies to, and on which this trigger waits.</p>
+    #[serde(rename = "JobName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub job_name: Option<String>,
     /// <p>A logical operator.</p>
     #[serde(rename = "LogicalOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub logical_operator: Option<String>,
+    pub logical_operator: Option<LogicalOperator>,
     /// <p>The condition state. Currently, the values supported are <code>SUCCEEDED</code>, <code>STOPPED</code>, <code>TIMEOUT</code>, and <code>FAILED</code>.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<JobRunState>,
 }
 
 /// <p>The confusion matrix shows you what your transform is predicting accurately and what types of errors it is making.</p> <p>For more information, see <a href="https://en.wikipedia.org/wiki/Confusion_matrix">Confusion matrix</a> in Wikipedia.</p>
@@ -590,15 +1396,16 @@ pub struct Connection {
     /// <p><p>These key-value pairs define parameters for the connection:</p> <ul> <li> <p> <code>HOST</code> - The host URI: either the fully qualified domain name (FQDN) or the IPv4 address of the database host.</p> </li> <li> <p> <code>PORT</code> - The port number, between 1024 and 65535, of the port on which the database host is listening for database connections.</p> </li> <li> <p> <code>USER<em>NAME</code> - The name under which to log in to the database. The value string for <code>USER</em>NAME</code> is &quot;<code>USERNAME</code>&quot;.</p> </li> <li> <p> <code>PASSWORD</code> - A password, if one is used, for the user name.</p> </li> <li> <p> <code>ENCRYPTED<em>PASSWORD</code> - When you enable connection password protection by setting <code>ConnectionPasswordEncryption</code> in the Data Catalog encryption settings, this field stores the encrypted password.</p> </li> <li> <p> <code>JDBC</em>DRIVER<em>JAR</em>URI</code> - The Amazon Simple Storage Service (Amazon S3) path of the JAR file that contains the JDBC driver to use.</p> </li> <li> <p> <code>JDBC<em>DRIVER</em>CLASS<em>NAME</code> - The class name of the JDBC driver to use.</p> </li> <li> <p> <code>JDBC</em>ENGINE</code> - The name of the JDBC engine to use.</p> </li> <li> <p> <code>JDBC<em>ENGINE</em>VERSION</code> - The version of the JDBC engine to use.</p> </li> <li> <p> <code>CONFIG<em>FILES</code> - (Reserved for future use.)</p> </li> <li> <p> <code>INSTANCE</em>ID</code> - The instance ID to use.</p> </li> <li> <p> <code>JDBC<em>CONNECTION</em>URL</code> - The URL for the JDBC connection.</p> </li> <li> <p> <code>JDBC<em>ENFORCE</em>SSL</code> - A Boolean string (true, false) specifying whether Secure Sockets Layer (SSL) with hostname matching is enforced for the JDBC connection on the client. The default is false.</p> </li> </ul></p>
     #[serde(rename = "ConnectionProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_properties: Option<::std::collections::HashMap<String, String>>,
+    pub connection_properties: Option<::std::collections::HashMap<ConnectionPropertyKey, String>>,
     /// <p>The type of the connection. Currently, only JDBC is supported; SFTP is not supported.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>The time that this connection definition was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The description of the connection.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -609,8 +1416,9 @@ pub struct Connection {
     pub last_updated_by: Option<String>,
     /// <p>The last time that this connection definition was updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<EpochTimestamp>,
     /// <p>A list of criteria that can be used in selecting this connection.</p>
     #[serde(rename = "MatchCriteria")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -626,14 +1434,14 @@ pub struct Connection {
 }
 
 /// <p>A structure that is used to specify a connection to create or update.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ConnectionInput {
     /// <p>These key-value pairs define parameters for the connection.</p>
     #[serde(rename = "ConnectionProperties")]
-    pub connection_properties: ::std::collections::HashMap<String, String>,
+    pub connection_properties: ::std::collections::HashMap<ConnectionPropertyKey, String>,
     /// <p>The type of the connection. Currently, only JDBC is supported; SFTP is not supported.</p>
     #[serde(rename = "ConnectionType")]
-    pub connection_type: String,
+    pub connection_type: ConnectionType,
     /// <p>The description of the connection.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -663,6 +1471,338 @@ pub struct ConnectionPasswordEncryption {
     pub return_connection_password_encrypted: bool,
 }
 
+/// <p><p>These key-value pairs define parameters for the connection:</p> <ul> <li> <p> <code>HOST</code> - The host URI: either the fully qualified domain name (FQDN) or the IPv4 address of the database host.</p> </li> <li> <p> <code>PORT</code> - The port number, between 1024 and 65535, of the port on which the database host is listening for database connections.</p> </li> <li> <p> <code>USER<em>NAME</code> - The name under which to log in to the database. The value string for <code>USER</em>NAME</code> is &quot;<code>USERNAME</code>&quot;.</p> </li> <li> <p> <code>PASSWORD</code> - A password, if one is used, for the user name.</p> </li> <li> <p> <code>ENCRYPTED<em>PASSWORD</code> - When you enable connection password protection by setting <code>ConnectionPasswordEncryption</code> in the Data Catalog encryption settings, this field stores the encrypted password.</p> </li> <li> <p> <code>JDBC</em>DRIVER<em>JAR</em>URI</code> - The Amazon Simple Storage Service (Amazon S3) path of the JAR file that contains the JDBC driver to use.</p> </li> <li> <p> <code>JDBC<em>DRIVER</em>CLASS<em>NAME</code> - The class name of the JDBC driver to use.</p> </li> <li> <p> <code>JDBC</em>ENGINE</code> - The name of the JDBC engine to use.</p> </li> <li> <p> <code>JDBC<em>ENGINE</em>VERSION</code> - The version of the JDBC engine to use.</p> </li> <li> <p> <code>CONFIG<em>FILES</code> - (Reserved for future use.)</p> </li> <li> <p> <code>INSTANCE</em>ID</code> - The instance ID to use.</p> </li> <li> <p> <code>JDBC<em>CONNECTION</em>URL</code> - The URL for the JDBC connection.</p> </li> <li> <p> <code>JDBC<em>ENFORCE</em>SSL</code> - A Boolean string (true, false) specifying whether Secure Sockets Layer (SSL) with hostname matching is enforced for the JDBC connection on the client. The default is false.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionPropertyKey {
+    /// <p><code>HOST</code> - The host URI: either the fully qualified domain name (FQDN) or the IPv4 address of the database host.</p>
+    Host,
+    /// <p><code>PORT</code> - The port number, between 1024 and 65535, of the port on which the database host is listening for database connections.</p>
+    Port,
+    /// <p><code>USERNAME</code></p>
+    Username,
+    /// <p><code>PASSWORD</code> - A password, if one is used, for the user name.</p>
+    Password,
+    /// <p><code>ENCRYPTED_PASSWORD</code></p>
+    EncryptedPassword,
+    /// <p><code>JDBC_DRIVER_JAR_URI</code></p>
+    JdbcDriverJarUri,
+    /// <p><code>JDBC_DRIVER_CLASS_NAME</code></p>
+    JdbcDriverClassName,
+    /// <p><code>JDBC_ENGINE</code></p>
+    JdbcEngine,
+    /// <p><code>JDBC_ENGINE_VERSION</code></p>
+    JdbcEngineVersion,
+    /// <p><code>CONFIG_FILES</code></p>
+    ConfigFiles,
+    /// <p><code>INSTANCE_ID</code></p>
+    InstanceId,
+    /// <p><code>JDBC_CONNECTION_URL</code></p>
+    JdbcConnectionUrl,
+    /// <p><code>JDBC_ENFORCE_SSL</code></p>
+    JdbcEnforceSsl,
+    /// <p><code>CUSTOM_JDBC_CERT</code></p>
+    CustomJdbcCert,
+    /// <p><code>SKIP_CUSTOM_JDBC_CERT_VALIDATION</code></p>
+    SkipCustomJdbcCertValidation,
+    /// <p><code>CUSTOM_JDBC_CERT_STRING</code></p>
+    CustomJdbcCertString,
+    /// <p><code>CONNECTION_URL</code></p>
+    ConnectionUrl,
+    /// <p><code>KAFKA_BOOTSTRAP_SERVERS</code></p>
+    KafkaBootstrapServers,
+    /// <p><code>KAFKA_SSL_ENABLED</code></p>
+    KafkaSslEnabled,
+    /// <p><code>KAFKA_CUSTOM_CERT</code></p>
+    KafkaCustomCert,
+    /// <p><code>KAFKA_SKIP_CUSTOM_CERT_VALIDATION</code></p>
+    KafkaSkipCustomCertValidation,
+    /// <p><code>KAFKA_CLIENT_KEYSTORE</code></p>
+    KafkaClientKeystore,
+    /// <p><code>KAFKA_CLIENT_KEYSTORE_PASSWORD</code></p>
+    KafkaClientKeystorePassword,
+    /// <p><code>KAFKA_CLIENT_KEY_PASSWORD</code></p>
+    KafkaClientKeyPassword,
+    /// <p><code>ENCRYPTED_KAFKA_CLIENT_KEYSTORE_PASSWORD</code></p>
+    EncryptedKafkaClientKeystorePassword,
+    /// <p><code>ENCRYPTED_KAFKA_CLIENT_KEY_PASSWORD</code></p>
+    EncryptedKafkaClientKeyPassword,
+    /// <p><code>SECRET_ID</code></p>
+    SecretId,
+    /// <p><code>CONNECTOR_URL</code></p>
+    ConnectorUrl,
+    /// <p><code>CONNECTOR_TYPE</code></p>
+    ConnectorType,
+    /// <p><code>CONNECTOR_CLASS_NAME</code></p>
+    ConnectorClassName,
+    /// <p><code>KAFKA_SASL_MECHANISM</code></p>
+    KafkaSaslMechanism,
+    /// <p><code>KAFKA_SASL_SCRAM_USERNAME</code></p>
+    KafkaSaslScramUsername,
+    /// <p><code>KAFKA_SASL_SCRAM_PASSWORD</code></p>
+    KafkaSaslScramPassword,
+    /// <p><code>KAFKA_SASL_SCRAM_SECRETS_ARN</code></p>
+    KafkaSaslScramSecretsArn,
+    /// <p><code>ENCRYPTED_KAFKA_SASL_SCRAM_PASSWORD</code></p>
+    EncryptedKafkaSaslScramPassword,
+    /// <p><code>KAFKA_SASL_GSSAPI_KEYTAB</code></p>
+    KafkaSaslGssapiKeytab,
+    /// <p><code>KAFKA_SASL_GSSAPI_KRB5_CONF</code></p>
+    KafkaSaslGssapiKrb5Conf,
+    /// <p><code>KAFKA_SASL_GSSAPI_SERVICE</code></p>
+    KafkaSaslGssapiService,
+    /// <p><code>KAFKA_SASL_GSSAPI_PRINCIPAL</code></p>
+    KafkaSaslGssapiPrincipal,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConnectionPropertyKey {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionPropertyKey::Host => "HOST",
+            ConnectionPropertyKey::Port => "PORT",
+            ConnectionPropertyKey::Username => "USERNAME",
+            ConnectionPropertyKey::Password => "PASSWORD",
+            ConnectionPropertyKey::EncryptedPassword => "ENCRYPTED_PASSWORD",
+            ConnectionPropertyKey::JdbcDriverJarUri => "JDBC_DRIVER_JAR_URI",
+            ConnectionPropertyKey::JdbcDriverClassName => "JDBC_DRIVER_CLASS_NAME",
+            ConnectionPropertyKey::JdbcEngine => "JDBC_ENGINE",
+            ConnectionPropertyKey::JdbcEngineVersion => "JDBC_ENGINE_VERSION",
+            ConnectionPropertyKey::ConfigFiles => "CONFIG_FILES",
+            ConnectionPropertyKey::InstanceId => "INSTANCE_ID",
+            ConnectionPropertyKey::JdbcConnectionUrl => "JDBC_CONNECTION_URL",
+            ConnectionPropertyKey::JdbcEnforceSsl => "JDBC_ENFORCE_SSL",
+            ConnectionPropertyKey::CustomJdbcCert => "CUSTOM_JDBC_CERT",
+            ConnectionPropertyKey::SkipCustomJdbcCertValidation => {
+                "SKIP_CUSTOM_JDBC_CERT_VALIDATION"
+            }
+            ConnectionPropertyKey::CustomJdbcCertString => "CUSTOM_JDBC_CERT_STRING",
+            ConnectionPropertyKey::ConnectionUrl => "CONNECTION_URL",
+            ConnectionPropertyKey::KafkaBootstrapServers => "KAFKA_BOOTSTRAP_SERVERS",
+            ConnectionPropertyKey::KafkaSslEnabled => "KAFKA_SSL_ENABLED",
+            ConnectionPropertyKey::KafkaCustomCert => "KAFKA_CUSTOM_CERT",
+            ConnectionPropertyKey::KafkaSkipCustomCertValidation => {
+                "KAFKA_SKIP_CUSTOM_CERT_VALIDATION"
+            }
+            ConnectionPropertyKey::KafkaClientKeystore => "KAFKA_CLIENT_KEYSTORE",
+            ConnectionPropertyKey::KafkaClientKeystorePassword => "KAFKA_CLIENT_KEYSTORE_PASSWORD",
+            ConnectionPropertyKey::KafkaClientKeyPassword => "KAFKA_CLIENT_KEY_PASSWORD",
+            ConnectionPropertyKey::EncryptedKafkaClientKeystorePassword => {
+                "ENCRYPTED_KAFKA_CLIENT_KEYSTORE_PASSWORD"
+            }
+            ConnectionPropertyKey::EncryptedKafkaClientKeyPassword => {
+                "ENCRYPTED_KAFKA_CLIENT_KEY_PASSWORD"
+            }
+            ConnectionPropertyKey::SecretId => "SECRET_ID",
+            ConnectionPropertyKey::ConnectorUrl => "CONNECTOR_URL",
+            ConnectionPropertyKey::ConnectorType => "CONNECTOR_TYPE",
+            ConnectionPropertyKey::ConnectorClassName => "CONNECTOR_CLASS_NAME",
+            ConnectionPropertyKey::KafkaSaslMechanism => "KAFKA_SASL_MECHANISM",
+            ConnectionPropertyKey::KafkaSaslScramUsername => "KAFKA_SASL_SCRAM_USERNAME",
+            ConnectionPropertyKey::KafkaSaslScramPassword => "KAFKA_SASL_SCRAM_PASSWORD",
+            ConnectionPropertyKey::KafkaSaslScramSecretsArn => "KAFKA_SASL_SCRAM_SECRETS_ARN",
+            ConnectionPropertyKey::EncryptedKafkaSaslScramPassword => {
+                "ENCRYPTED_KAFKA_SASL_SCRAM_PASSWORD"
+            }
+            ConnectionPropertyKey::KafkaSaslGssapiKeytab => "KAFKA_SASL_GSSAPI_KEYTAB",
+            ConnectionPropertyKey::KafkaSaslGssapiKrb5Conf => "KAFKA_SASL_GSSAPI_KRB5_CONF",
+            ConnectionPropertyKey::KafkaSaslGssapiService => "KAFKA_SASL_GSSAPI_SERVICE",
+            ConnectionPropertyKey::KafkaSaslGssapiPrincipal => "KAFKA_SASL_GSSAPI_PRINCIPAL",
+            ConnectionPropertyKey::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionPropertyKey {
+    fn from(value: &'a str) -> ConnectionPropertyKey {
+        match value {
+            "HOST" => ConnectionPropertyKey::Host,
+            "PORT" => ConnectionPropertyKey::Port,
+            "USERNAME" => ConnectionPropertyKey::Username,
+            "PASSWORD" => ConnectionPropertyKey::Password,
+            "ENCRYPTED_PASSWORD" => ConnectionPropertyKey::EncryptedPassword,
+            "JDBC_DRIVER_JAR_URI" => ConnectionPropertyKey::JdbcDriverJarUri,
+            "JDBC_DRIVER_CLASS_NAME" => ConnectionPropertyKey::JdbcDriverClassName,
+            "JDBC_ENGINE" => ConnectionPropertyKey::JdbcEngine,
+            "JDBC_ENGINE_VERSION" => ConnectionPropertyKey::JdbcEngineVersion,
+            "CONFIG_FILES" => ConnectionPropertyKey::ConfigFiles,
+            "INSTANCE_ID" => ConnectionPropertyKey::InstanceId,
+            "JDBC_CONNECTION_URL" => ConnectionPropertyKey::JdbcConnectionUrl,
+            "JDBC_ENFORCE_SSL" => ConnectionPropertyKey::JdbcEnforceSsl,
+            "CUSTOM_JDBC_CERT" => ConnectionPropertyKey::CustomJdbcCert,
+            "SKIP_CUSTOM_JDBC_CERT_VALIDATION" => {
+                ConnectionPropertyKey::SkipCustomJdbcCertValidation
+            }
+            "CUSTOM_JDBC_CERT_STRING" => ConnectionPropertyKey::CustomJdbcCertString,
+            "CONNECTION_URL" => ConnectionPropertyKey::ConnectionUrl,
+            "KAFKA_BOOTSTRAP_SERVERS" => ConnectionPropertyKey::KafkaBootstrapServers,
+            "KAFKA_SSL_ENABLED" => ConnectionPropertyKey::KafkaSslEnabled,
+            "KAFKA_CUSTOM_CERT" => ConnectionPropertyKey::KafkaCustomCert,
+            "KAFKA_SKIP_CUSTOM_CERT_VALIDATION" => {
+                ConnectionPropertyKey::KafkaSkipCustomCertValidation
+            }
+            "KAFKA_CLIENT_KEYSTORE" => ConnectionPropertyKey::KafkaClientKeystore,
+            "KAFKA_CLIENT_KEYSTORE_PASSWORD" => ConnectionPropertyKey::KafkaClientKeystorePassword,
+            "KAFKA_CLIENT_KEY_PASSWORD" => ConnectionPropertyKey::KafkaClientKeyPassword,
+            "ENCRYPTED_KAFKA_CLIENT_KEYSTORE_PASSWORD" => {
+                ConnectionPropertyKey::EncryptedKafkaClientKeystorePassword
+            }
+            "ENCRYPTED_KAFKA_CLIENT_KEY_PASSWORD" => {
+                ConnectionPropertyKey::EncryptedKafkaClientKeyPassword
+            }
+            "SECRET_ID" => ConnectionPropertyKey::SecretId,
+            "CONNECTOR_URL" => ConnectionPropertyKey::ConnectorUrl,
+            "CONNECTOR_TYPE" => ConnectionPropertyKey::ConnectorType,
+            "CONNECTOR_CLASS_NAME" => ConnectionPropertyKey::ConnectorClassName,
+            "KAFKA_SASL_MECHANISM" => ConnectionPropertyKey::KafkaSaslMechanism,
+            "KAFKA_SASL_SCRAM_USERNAME" => ConnectionPropertyKey::KafkaSaslScramUsername,
+            "KAFKA_SASL_SCRAM_PASSWORD" => ConnectionPropertyKey::KafkaSaslScramPassword,
+            "KAFKA_SASL_SCRAM_SECRETS_ARN" => ConnectionPropertyKey::KafkaSaslScramSecretsArn,
+            "ENCRYPTED_KAFKA_SASL_SCRAM_PASSWORD" => {
+                ConnectionPropertyKey::EncryptedKafkaSaslScramPassword
+            }
+            "KAFKA_SASL_GSSAPI_KEYTAB" => ConnectionPropertyKey::KafkaSaslGssapiKeytab,
+            "KAFKA_SASL_GSSAPI_KRB5_CONF" => ConnectionPropertyKey::KafkaSaslGssapiKrb5Conf,
+            "KAFKA_SASL_GSSAPI_SERVICE" => ConnectionPropertyKey::KafkaSaslGssapiService,
+            "KAFKA_SASL_GSSAPI_PRINCIPAL" => ConnectionPropertyKey::KafkaSaslGssapiPrincipal,
+            _ => ConnectionPropertyKey::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionPropertyKey {
+    fn from(value: String) -> ConnectionPropertyKey {
+        ConnectionPropertyKey::from(value.as_str())
+    }
+}
+
+impl From<ConnectionPropertyKey> for String {
+    fn from(value: ConnectionPropertyKey) -> String {
+        match value {
+            ConnectionPropertyKey::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionPropertyKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConnectionPropertyKey {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionPropertyKey {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConnectionPropertyKey, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConnectionPropertyKey::from(value))
+    }
+}
+
+/// <p>The type of the connection. Currently, only JDBC is supported; SFTP is not supported.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionType {
+    /// <p><code>JDBC</code></p>
+    Jdbc,
+    /// <p><code>SFTP</code></p>
+    Sftp,
+    /// <p><code>MONGODB</code></p>
+    Mongodb,
+    /// <p><code>KAFKA</code></p>
+    Kafka,
+    /// <p><code>NETWORK</code></p>
+    Network,
+    /// <p><code>MARKETPLACE</code></p>
+    Marketplace,
+    /// <p><code>CUSTOM</code></p>
+    Custom,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConnectionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionType::Jdbc => "JDBC",
+            ConnectionType::Sftp => "SFTP",
+            ConnectionType::Mongodb => "MONGODB",
+            ConnectionType::Kafka => "KAFKA",
+            ConnectionType::Network => "NETWORK",
+            ConnectionType::Marketplace => "MARKETPLACE",
+            ConnectionType::Custom => "CUSTOM",
+            ConnectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionType {
+    fn from(value: &'a str) -> ConnectionType {
+        match value {
+            "JDBC" => ConnectionType::Jdbc,
+            "SFTP" => ConnectionType::Sftp,
+            "MONGODB" => ConnectionType::Mongodb,
+            "KAFKA" => ConnectionType::Kafka,
+            "NETWORK" => ConnectionType::Network,
+            "MARKETPLACE" => ConnectionType::Marketplace,
+            "CUSTOM" => ConnectionType::Custom,
+            _ => ConnectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionType {
+    fn from(value: String) -> ConnectionType {
+        ConnectionType::from(value.as_str())
+    }
+}
+
+impl From<ConnectionType> for String {
+    fn from(value: ConnectionType) -> String {
+        match value {
+            ConnectionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConnectionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConnectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConnectionType::from(value))
+    }
+}
+
 /// <p>Specifies the connections used by a job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConnectionsList {
@@ -678,8 +1818,9 @@ pub struct ConnectionsList {
 pub struct Crawl {
     /// <p>The date and time on which the crawl completed.</p>
     #[serde(rename = "CompletedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_on: Option<f64>,
+    pub completed_on: Option<EpochTimestamp>,
     /// <p>The error message associated with the crawl.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -694,12 +1835,101 @@ pub struct Crawl {
     pub log_stream: Option<String>,
     /// <p>The date and time on which the crawl started.</p>
     #[serde(rename = "StartedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_on: Option<f64>,
+    pub started_on: Option<EpochTimestamp>,
     /// <p>The state of the crawler.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CrawlState>,
+}
+
+/// <p>The state of the crawler to which this condition applies.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CrawlState {
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>CANCELLING</code></p>
+    Cancelling,
+    /// <p><code>CANCELLED</code></p>
+    Cancelled,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CrawlState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CrawlState::Running => "RUNNING",
+            CrawlState::Cancelling => "CANCELLING",
+            CrawlState::Cancelled => "CANCELLED",
+            CrawlState::Succeeded => "SUCCEEDED",
+            CrawlState::Failed => "FAILED",
+            CrawlState::Error => "ERROR",
+            CrawlState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CrawlState {
+    fn from(value: &'a str) -> CrawlState {
+        match value {
+            "RUNNING" => CrawlState::Running,
+            "CANCELLING" => CrawlState::Cancelling,
+            "CANCELLED" => CrawlState::Cancelled,
+            "SUCCEEDED" => CrawlState::Succeeded,
+            "FAILED" => CrawlState::Failed,
+            "ERROR" => CrawlState::Error,
+            _ => CrawlState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CrawlState {
+    fn from(value: String) -> CrawlState {
+        CrawlState::from(value.as_str())
+    }
+}
+
+impl From<CrawlState> for String {
+    fn from(value: CrawlState) -> String {
+        match value {
+            CrawlState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CrawlState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CrawlState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CrawlState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CrawlState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CrawlState::from(value))
+    }
 }
 
 /// <p>Specifies a crawler program that examines a data source and uses classifiers to try to determine its schema. If successful, the crawler records metadata concerning the data source in the AWS Glue Data Catalog.</p>
@@ -724,8 +1954,9 @@ pub struct Crawler {
     pub crawler_security_configuration: Option<String>,
     /// <p>The time that the crawler was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The name of the database in which the crawler's output is stored.</p>
     #[serde(rename = "DatabaseName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -740,8 +1971,9 @@ pub struct Crawler {
     pub last_crawl: Option<LastCrawlInfo>,
     /// <p>The time that the crawler was last updated.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<EpochTimestamp>,
     /// <p>The name of the crawler.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -761,7 +1993,7 @@ pub struct Crawler {
     /// <p>Indicates whether the crawler is running, or whether a run is pending.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CrawlerState>,
     /// <p>The prefix added to the names of tables that are created.</p>
     #[serde(rename = "TablePrefix")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -824,6 +2056,82 @@ pub struct CrawlerNodeDetails {
     pub crawls: Option<Vec<Crawl>>,
 }
 
+/// <p>Indicates whether the crawler is running, or whether a run is pending.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CrawlerState {
+    /// <p><code>READY</code></p>
+    Ready,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>STOPPING</code></p>
+    Stopping,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CrawlerState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CrawlerState::Ready => "READY",
+            CrawlerState::Running => "RUNNING",
+            CrawlerState::Stopping => "STOPPING",
+            CrawlerState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CrawlerState {
+    fn from(value: &'a str) -> CrawlerState {
+        match value {
+            "READY" => CrawlerState::Ready,
+            "RUNNING" => CrawlerState::Running,
+            "STOPPING" => CrawlerState::Stopping,
+            _ => CrawlerState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CrawlerState {
+    fn from(value: String) -> CrawlerState {
+        CrawlerState::from(value.as_str())
+    }
+}
+
+impl From<CrawlerState> for String {
+    fn from(value: CrawlerState) -> String {
+        match value {
+            CrawlerState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CrawlerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CrawlerState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CrawlerState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CrawlerState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CrawlerState::from(value))
+    }
+}
+
 /// <p>Specifies data stores to crawl.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CrawlerTargets {
@@ -865,11 +2173,65 @@ pub struct CreateClassifierRequest {
     pub xml_classifier: Option<CreateXMLClassifierRequest>,
 }
 
+impl CreateClassifierRequest {
+    /// Returns a builder for `CreateClassifierRequest`, checking its required members when built.
+    pub fn builder() -> CreateClassifierRequestBuilder {
+        CreateClassifierRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateClassifierRequest`](struct.CreateClassifierRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateClassifierRequestBuilder {
+    csv_classifier: Option<CreateCsvClassifierRequest>,
+    grok_classifier: Option<CreateGrokClassifierRequest>,
+    json_classifier: Option<CreateJsonClassifierRequest>,
+    xml_classifier: Option<CreateXMLClassifierRequest>,
+}
+
+impl CreateClassifierRequestBuilder {
+    /// Sets the [`csv_classifier`](struct.CreateClassifierRequest.html#structfield.csv_classifier) member.
+    pub fn csv_classifier<T: Into<CreateCsvClassifierRequest>>(mut self, value: T) -> Self {
+        self.csv_classifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grok_classifier`](struct.CreateClassifierRequest.html#structfield.grok_classifier) member.
+    pub fn grok_classifier<T: Into<CreateGrokClassifierRequest>>(mut self, value: T) -> Self {
+        self.grok_classifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`json_classifier`](struct.CreateClassifierRequest.html#structfield.json_classifier) member.
+    pub fn json_classifier<T: Into<CreateJsonClassifierRequest>>(mut self, value: T) -> Self {
+        self.json_classifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`xml_classifier`](struct.CreateClassifierRequest.html#structfield.xml_classifier) member.
+    pub fn xml_classifier<T: Into<CreateXMLClassifierRequest>>(mut self, value: T) -> Self {
+        self.xml_classifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateClassifierRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateClassifierRequest, ::rusoto_core::BuildError> {
+        Ok(CreateClassifierRequest {
+            csv_classifier: self.csv_classifier,
+            grok_classifier: self.grok_classifier,
+            json_classifier: self.json_classifier,
+            xml_classifier: self.xml_classifier,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateClassifierResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateConnectionRequest {
     /// <p>The ID of the Data Catalog in which to create the connection. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -880,6 +2242,46 @@ pub struct CreateConnectionRequest {
     pub connection_input: ConnectionInput,
 }
 
+impl CreateConnectionRequest {
+    /// Returns a builder for `CreateConnectionRequest`, checking its required members when built.
+    pub fn builder() -> CreateConnectionRequestBuilder {
+        CreateConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConnectionRequest`](struct.CreateConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateConnectionRequestBuilder {
+    catalog_id: Option<String>,
+    connection_input: Option<ConnectionInput>,
+}
+
+impl CreateConnectionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.CreateConnectionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_input`](struct.CreateConnectionRequest.html#structfield.connection_input) member.
+    pub fn connection_input<T: Into<ConnectionInput>>(mut self, value: T) -> Self {
+        self.connection_input = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateConnectionRequest {
+            catalog_id: self.catalog_id,
+            connection_input: self
+                .connection_input
+                .ok_or(::rusoto_core::BuildError::missing("connection_input"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateConnectionResponse {}
@@ -933,6 +2335,128 @@ pub struct CreateCrawlerRequest {
     pub targets: CrawlerTargets,
 }
 
+impl CreateCrawlerRequest {
+    /// Returns a builder for `CreateCrawlerRequest`, checking its required members when built.
+    pub fn builder() -> CreateCrawlerRequestBuilder {
+        CreateCrawlerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCrawlerRequest`](struct.CreateCrawlerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCrawlerRequestBuilder {
+    classifiers: Option<Vec<String>>,
+    configuration: Option<String>,
+    crawler_security_configuration: Option<String>,
+    database_name: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+    role: Option<String>,
+    schedule: Option<String>,
+    schema_change_policy: Option<SchemaChangePolicy>,
+    table_prefix: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    targets: Option<CrawlerTargets>,
+}
+
+impl CreateCrawlerRequestBuilder {
+    /// Sets the [`classifiers`](struct.CreateCrawlerRequest.html#structfield.classifiers) member.
+    pub fn classifiers<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.classifiers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration`](struct.CreateCrawlerRequest.html#structfield.configuration) member.
+    pub fn configuration<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`crawler_security_configuration`](struct.CreateCrawlerRequest.html#structfield.crawler_security_configuration) member.
+    pub fn crawler_security_configuration<T: Into<String>>(mut self, value: T) -> Self {
+        self.crawler_security_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.CreateCrawlerRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateCrawlerRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateCrawlerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.CreateCrawlerRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule`](struct.CreateCrawlerRequest.html#structfield.schedule) member.
+    pub fn schedule<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_change_policy`](struct.CreateCrawlerRequest.html#structfield.schema_change_policy) member.
+    pub fn schema_change_policy<T: Into<SchemaChangePolicy>>(mut self, value: T) -> Self {
+        self.schema_change_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_prefix`](struct.CreateCrawlerRequest.html#structfield.table_prefix) member.
+    pub fn table_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateCrawlerRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`targets`](struct.CreateCrawlerRequest.html#structfield.targets) member.
+    pub fn targets<T: Into<CrawlerTargets>>(mut self, value: T) -> Self {
+        self.targets = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCrawlerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateCrawlerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateCrawlerRequest {
+            classifiers: self.classifiers,
+            configuration: self.configuration,
+            crawler_security_configuration: self.crawler_security_configuration,
+            database_name: self.database_name,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            role: self
+                .role
+                .ok_or(::rusoto_core::BuildError::missing("role"))?,
+            schedule: self.schedule,
+            schema_change_policy: self.schema_change_policy,
+            table_prefix: self.table_prefix,
+            tags: self.tags,
+            targets: self
+                .targets
+                .ok_or(::rusoto_core::BuildError::missing("targets"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCrawlerResponse {}
@@ -947,7 +2471,7 @@ pub struct CreateCsvClassifierRequest {
     /// <p>Indicates whether the CSV file contains a header.</p>
     #[serde(rename = "ContainsHeader")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contains_header: Option<String>,
+    pub contains_header: Option<CsvHeaderOption>,
     /// <p>A custom symbol to denote what separates each column entry in the row.</p>
     #[serde(rename = "Delimiter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -980,6 +2504,44 @@ pub struct CreateDatabaseRequest {
     pub database_input: DatabaseInput,
 }
 
+impl CreateDatabaseRequest {
+    /// Returns a builder for `CreateDatabaseRequest`, checking its required members when built.
+    pub fn builder() -> CreateDatabaseRequestBuilder {
+        CreateDatabaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDatabaseRequest`](struct.CreateDatabaseRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDatabaseRequestBuilder {
+    catalog_id: Option<String>,
+    database_input: Option<DatabaseInput>,
+}
+
+impl CreateDatabaseRequestBuilder {
+    /// Sets the [`catalog_id`](struct.CreateDatabaseRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_input`](struct.CreateDatabaseRequest.html#structfield.database_input) member.
+    pub fn database_input<T: Into<DatabaseInput>>(mut self, value: T) -> Self {
+        self.database_input = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDatabaseRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDatabaseRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDatabaseRequest {
+            catalog_id: self.catalog_id,
+            database_input: self
+                .database_input
+                .ok_or(::rusoto_core::BuildError::missing("database_input"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDatabaseResponse {}
@@ -1039,24 +2601,166 @@ pub struct CreateDevEndpointRequest {
     /// <p>The type of predefined worker that is allocated to the development endpoint. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker maps to 1 DPU (4 vCPU, 16 GB of memory, 64 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker maps to 2 DPU (8 vCPU, 32 GB of memory, 128 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> </ul> <p>Known issue: when a development endpoint is created with the <code>G.2X</code> <code>WorkerType</code> configuration, the Spark drivers for the development endpoint will run on 4 vCPU, 16 GB of memory, and a 64 GB disk. </p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CreateDevEndpointResponse {
-    /// <p>The map of arguments used to configure this <code>DevEndpoint</code>.</p>
-    #[serde(rename = "Arguments")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arguments: Option<::std::collections::HashMap<String, String>>,
-    /// <p>The AWS Availability Zone where this <code>DevEndpoint</code> is located.</p>
-    #[serde(rename = "AvailabilityZone")]
+impl CreateDevEndpointRequest {
+    /// Returns a builder for `CreateDevEndpointRequest`, checking its required members when built.
+    pub fn builder() -> CreateDevEndpointRequestBuilder {
+        CreateDevEndpointRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDevEndpointRequest`](struct.CreateDevEndpointRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDevEndpointRequestBuilder {
+    arguments: Option<::std::collections::HashMap<String, String>>,
+    endpoint_name: Option<String>,
+    extra_jars_s3_path: Option<String>,
+    extra_python_libs_s3_path: Option<String>,
+    number_of_nodes: Option<i64>,
+    number_of_workers: Option<i64>,
+    public_key: Option<String>,
+    public_keys: Option<Vec<String>>,
+    role_arn: Option<String>,
+    security_configuration: Option<String>,
+    security_group_ids: Option<Vec<String>>,
+    subnet_id: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    worker_type: Option<WorkerType>,
+}
+
+impl CreateDevEndpointRequestBuilder {
+    /// Sets the [`arguments`](struct.CreateDevEndpointRequest.html#structfield.arguments) member.
+    pub fn arguments<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.arguments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_name`](struct.CreateDevEndpointRequest.html#structfield.endpoint_name) member.
+    pub fn endpoint_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`extra_jars_s3_path`](struct.CreateDevEndpointRequest.html#structfield.extra_jars_s3_path) member.
+    pub fn extra_jars_s3_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.extra_jars_s3_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`extra_python_libs_s3_path`](struct.CreateDevEndpointRequest.html#structfield.extra_python_libs_s3_path) member.
+    pub fn extra_python_libs_s3_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.extra_python_libs_s3_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_nodes`](struct.CreateDevEndpointRequest.html#structfield.number_of_nodes) member.
+    pub fn number_of_nodes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_nodes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_workers`](struct.CreateDevEndpointRequest.html#structfield.number_of_workers) member.
+    pub fn number_of_workers<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_workers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`public_key`](struct.CreateDevEndpointRequest.html#structfield.public_key) member.
+    pub fn public_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.public_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`public_keys`](struct.CreateDevEndpointRequest.html#structfield.public_keys) member.
+    pub fn public_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.public_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateDevEndpointRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_configuration`](struct.CreateDevEndpointRequest.html#structfield.security_configuration) member.
+    pub fn security_configuration<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_group_ids`](struct.CreateDevEndpointRequest.html#structfield.security_group_ids) member.
+    pub fn security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_id`](struct.CreateDevEndpointRequest.html#structfield.subnet_id) member.
+    pub fn subnet_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subnet_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDevEndpointRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_type`](struct.CreateDevEndpointRequest.html#structfield.worker_type) member.
+    pub fn worker_type<T: Into<WorkerType>>(mut self, value: T) -> Self {
+        self.worker_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDevEndpointRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDevEndpointRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDevEndpointRequest {
+            arguments: self.arguments,
+            endpoint_name: self
+                .endpoint_name
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_name"))?,
+            extra_jars_s3_path: self.extra_jars_s3_path,
+            extra_python_libs_s3_path: self.extra_python_libs_s3_path,
+            number_of_nodes: self.number_of_nodes,
+            number_of_workers: self.number_of_workers,
+            public_key: self.public_key,
+            public_keys: self.public_keys,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            security_configuration: self.security_configuration,
+            security_group_ids: self.security_group_ids,
+            subnet_id: self.subnet_id,
+            tags: self.tags,
+            worker_type: self.worker_type,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateDevEndpointResponse {
+    /// <p>The map of arguments used to configure this <code>DevEndpoint</code>.</p>
+    #[serde(rename = "Arguments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arguments: Option<::std::collections::HashMap<String, String>>,
+    /// <p>The AWS Availability Zone where this <code>DevEndpoint</code> is located.</p>
+    #[serde(rename = "AvailabilityZone")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub availability_zone: Option<String>,
     /// <p>The point in time at which this <code>DevEndpoint</code> was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The name assigned to the new <code>DevEndpoint</code>.</p>
     #[serde(rename = "EndpointName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1108,7 +2812,7 @@ pub struct CreateDevEndpointResponse {
     /// <p>The type of predefined worker that is allocated to the development endpoint. May be a value of Standard, G.1X, or G.2X.</p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
     /// <p>The address of the YARN endpoint used by this <code>DevEndpoint</code>.</p>
     #[serde(rename = "YarnEndpointAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1203,7 +2907,172 @@ pub struct CreateJobRequest {
     /// <p><p>The type of predefined worker that is allocated when a job runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker maps to 1 DPU (4 vCPU, 16 GB of memory, 64 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker maps to 2 DPU (8 vCPU, 32 GB of memory, 128 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
+}
+
+impl CreateJobRequest {
+    /// Returns a builder for `CreateJobRequest`, checking its required members when built.
+    pub fn builder() -> CreateJobRequestBuilder {
+        CreateJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateJobRequest`](struct.CreateJobRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateJobRequestBuilder {
+    command: Option<JobCommand>,
+    connections: Option<ConnectionsList>,
+    default_arguments: Option<::std::collections::HashMap<String, String>>,
+    description: Option<String>,
+    execution_property: Option<ExecutionProperty>,
+    glue_version: Option<String>,
+    log_uri: Option<String>,
+    max_capacity: Option<f64>,
+    max_retries: Option<i64>,
+    name: Option<String>,
+    notification_property: Option<NotificationProperty>,
+    number_of_workers: Option<i64>,
+    role: Option<String>,
+    security_configuration: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    timeout: Option<i64>,
+    worker_type: Option<WorkerType>,
+}
+
+impl CreateJobRequestBuilder {
+    /// Sets the [`command`](struct.CreateJobRequest.html#structfield.command) member.
+    pub fn command<T: Into<JobCommand>>(mut self, value: T) -> Self {
+        self.command = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connections`](struct.CreateJobRequest.html#structfield.connections) member.
+    pub fn connections<T: Into<ConnectionsList>>(mut self, value: T) -> Self {
+        self.connections = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_arguments`](struct.CreateJobRequest.html#structfield.default_arguments) member.
+    pub fn default_arguments<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.default_arguments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateJobRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_property`](struct.CreateJobRequest.html#structfield.execution_property) member.
+    pub fn execution_property<T: Into<ExecutionProperty>>(mut self, value: T) -> Self {
+        self.execution_property = Some(value.into());
+        self
+    }
+
+    /// Sets the [`glue_version`](struct.CreateJobRequest.html#structfield.glue_version) member.
+    pub fn glue_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.glue_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_uri`](struct.CreateJobRequest.html#structfield.log_uri) member.
+    pub fn log_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_capacity`](struct.CreateJobRequest.html#structfield.max_capacity) member.
+    pub fn max_capacity<T: Into<f64>>(mut self, value: T) -> Self {
+        self.max_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_retries`](struct.CreateJobRequest.html#structfield.max_retries) member.
+    pub fn max_retries<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_retries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateJobRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_property`](struct.CreateJobRequest.html#structfield.notification_property) member.
+    pub fn notification_property<T: Into<NotificationProperty>>(mut self, value: T) -> Self {
+        self.notification_property = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_workers`](struct.CreateJobRequest.html#structfield.number_of_workers) member.
+    pub fn number_of_workers<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_workers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.CreateJobRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_configuration`](struct.CreateJobRequest.html#structfield.security_configuration) member.
+    pub fn security_configuration<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateJobRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout`](struct.CreateJobRequest.html#structfield.timeout) member.
+    pub fn timeout<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_type`](struct.CreateJobRequest.html#structfield.worker_type) member.
+    pub fn worker_type<T: Into<WorkerType>>(mut self, value: T) -> Self {
+        self.worker_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateJobRequest, ::rusoto_core::BuildError> {
+        Ok(CreateJobRequest {
+            command: self
+                .command
+                .ok_or(::rusoto_core::BuildError::missing("command"))?,
+            connections: self.connections,
+            default_arguments: self.default_arguments,
+            description: self.description,
+            execution_property: self.execution_property,
+            glue_version: self.glue_version,
+            log_uri: self.log_uri,
+            max_capacity: self.max_capacity,
+            max_retries: self.max_retries,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            notification_property: self.notification_property,
+            number_of_workers: self.number_of_workers,
+            role: self
+                .role
+                .ok_or(::rusoto_core::BuildError::missing("role"))?,
+            security_configuration: self.security_configuration,
+            tags: self.tags,
+            timeout: self.timeout,
+            worker_type: self.worker_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1226,7 +3095,7 @@ pub struct CreateJsonClassifierRequest {
     pub name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateMLTransformRequest {
     /// <p>A description of the machine learning transform that is being defined. The default is an empty string.</p>
     #[serde(rename = "Description")]
@@ -1263,7 +3132,117 @@ pub struct CreateMLTransformRequest {
     /// <p><p>The type of predefined worker that is allocated when this task runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 64GB disk, and 1 executor per worker.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker provides 8 vCPU, 32 GB of memory and a 128GB disk, and 1 executor per worker.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
+}
+
+impl CreateMLTransformRequest {
+    /// Returns a builder for `CreateMLTransformRequest`, checking its required members when built.
+    pub fn builder() -> CreateMLTransformRequestBuilder {
+        CreateMLTransformRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateMLTransformRequest`](struct.CreateMLTransformRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateMLTransformRequestBuilder {
+    description: Option<String>,
+    input_record_tables: Option<Vec<GlueTable>>,
+    max_capacity: Option<f64>,
+    max_retries: Option<i64>,
+    name: Option<String>,
+    number_of_workers: Option<i64>,
+    parameters: Option<TransformParameters>,
+    role: Option<String>,
+    timeout: Option<i64>,
+    worker_type: Option<WorkerType>,
+}
+
+impl CreateMLTransformRequestBuilder {
+    /// Sets the [`description`](struct.CreateMLTransformRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_record_tables`](struct.CreateMLTransformRequest.html#structfield.input_record_tables) member.
+    pub fn input_record_tables<T: Into<Vec<GlueTable>>>(mut self, value: T) -> Self {
+        self.input_record_tables = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_capacity`](struct.CreateMLTransformRequest.html#structfield.max_capacity) member.
+    pub fn max_capacity<T: Into<f64>>(mut self, value: T) -> Self {
+        self.max_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_retries`](struct.CreateMLTransformRequest.html#structfield.max_retries) member.
+    pub fn max_retries<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_retries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateMLTransformRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_workers`](struct.CreateMLTransformRequest.html#structfield.number_of_workers) member.
+    pub fn number_of_workers<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_workers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.CreateMLTransformRequest.html#structfield.parameters) member.
+    pub fn parameters<T: Into<TransformParameters>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.CreateMLTransformRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout`](struct.CreateMLTransformRequest.html#structfield.timeout) member.
+    pub fn timeout<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_type`](struct.CreateMLTransformRequest.html#structfield.worker_type) member.
+    pub fn worker_type<T: Into<WorkerType>>(mut self, value: T) -> Self {
+        self.worker_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateMLTransformRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateMLTransformRequest, ::rusoto_core::BuildError> {
+        Ok(CreateMLTransformRequest {
+            description: self.description,
+            input_record_tables: self
+                .input_record_tables
+                .ok_or(::rusoto_core::BuildError::missing("input_record_tables"))?,
+            max_capacity: self.max_capacity,
+            max_retries: self.max_retries,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            number_of_workers: self.number_of_workers,
+            parameters: self
+                .parameters
+                .ok_or(::rusoto_core::BuildError::missing("parameters"))?,
+            role: self
+                .role
+                .ok_or(::rusoto_core::BuildError::missing("role"))?,
+            timeout: self.timeout,
+            worker_type: self.worker_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1292,6 +3271,64 @@ pub struct CreatePartitionRequest {
     pub table_name: String,
 }
 
+impl CreatePartitionRequest {
+    /// Returns a builder for `CreatePartitionRequest`, checking its required members when built.
+    pub fn builder() -> CreatePartitionRequestBuilder {
+        CreatePartitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePartitionRequest`](struct.CreatePartitionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePartitionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    partition_input: Option<PartitionInput>,
+    table_name: Option<String>,
+}
+
+impl CreatePartitionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.CreatePartitionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.CreatePartitionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`partition_input`](struct.CreatePartitionRequest.html#structfield.partition_input) member.
+    pub fn partition_input<T: Into<PartitionInput>>(mut self, value: T) -> Self {
+        self.partition_input = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.CreatePartitionRequest.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePartitionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePartitionRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePartitionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            partition_input: self
+                .partition_input
+                .ok_or(::rusoto_core::BuildError::missing("partition_input"))?,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreatePartitionResponse {}
@@ -1309,7 +3346,51 @@ pub struct CreateScriptRequest {
     /// <p>The programming language of the resulting code from the DAG.</p>
     #[serde(rename = "Language")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
+    pub language: Option<Language>,
+}
+
+impl CreateScriptRequest {
+    /// Returns a builder for `CreateScriptRequest`, checking its required members when built.
+    pub fn builder() -> CreateScriptRequestBuilder {
+        CreateScriptRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateScriptRequest`](struct.CreateScriptRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateScriptRequestBuilder {
+    dag_edges: Option<Vec<CodeGenEdge>>,
+    dag_nodes: Option<Vec<CodeGenNode>>,
+    language: Option<Language>,
+}
+
+impl CreateScriptRequestBuilder {
+    /// Sets the [`dag_edges`](struct.CreateScriptRequest.html#structfield.dag_edges) member.
+    pub fn dag_edges<T: Into<Vec<CodeGenEdge>>>(mut self, value: T) -> Self {
+        self.dag_edges = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dag_nodes`](struct.CreateScriptRequest.html#structfield.dag_nodes) member.
+    pub fn dag_nodes<T: Into<Vec<CodeGenNode>>>(mut self, value: T) -> Self {
+        self.dag_nodes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`language`](struct.CreateScriptRequest.html#structfield.language) member.
+    pub fn language<T: Into<Language>>(mut self, value: T) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateScriptRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateScriptRequest, ::rusoto_core::BuildError> {
+        Ok(CreateScriptRequest {
+            dag_edges: self.dag_edges,
+            dag_nodes: self.dag_nodes,
+            language: self.language,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1335,13 +3416,56 @@ pub struct CreateSecurityConfigurationRequest {
     pub name: String,
 }
 
+impl CreateSecurityConfigurationRequest {
+    /// Returns a builder for `CreateSecurityConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> CreateSecurityConfigurationRequestBuilder {
+        CreateSecurityConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSecurityConfigurationRequest`](struct.CreateSecurityConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSecurityConfigurationRequestBuilder {
+    encryption_configuration: Option<EncryptionConfiguration>,
+    name: Option<String>,
+}
+
+impl CreateSecurityConfigurationRequestBuilder {
+    /// Sets the [`encryption_configuration`](struct.CreateSecurityConfigurationRequest.html#structfield.encryption_configuration) member.
+    pub fn encryption_configuration<T: Into<EncryptionConfiguration>>(mut self, value: T) -> Self {
+        self.encryption_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateSecurityConfigurationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSecurityConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSecurityConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSecurityConfigurationRequest {
+            encryption_configuration: self.encryption_configuration.ok_or(
+                ::rusoto_core::BuildError::missing("encryption_configuration"),
+            )?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateSecurityConfigurationResponse {
     /// <p>The time at which the new security configuration was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The name assigned to the new security configuration.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1362,11 +3486,59 @@ pub struct CreateTableRequest {
     pub table_input: TableInput,
 }
 
+impl CreateTableRequest {
+    /// Returns a builder for `CreateTableRequest`, checking its required members when built.
+    pub fn builder() -> CreateTableRequestBuilder {
+        CreateTableRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTableRequest`](struct.CreateTableRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTableRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    table_input: Option<TableInput>,
+}
+
+impl CreateTableRequestBuilder {
+    /// Sets the [`catalog_id`](struct.CreateTableRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.CreateTableRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_input`](struct.CreateTableRequest.html#structfield.table_input) member.
+    pub fn table_input<T: Into<TableInput>>(mut self, value: T) -> Self {
+        self.table_input = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTableRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTableRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTableRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            table_input: self
+                .table_input
+                .ok_or(::rusoto_core::BuildError::missing("table_input"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateTableResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateTriggerRequest {
     /// <p>The actions initiated by this trigger when it fires.</p>
     #[serde(rename = "Actions")]
@@ -1396,13 +3568,111 @@ pub struct CreateTriggerRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The type of the new trigger.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: TriggerType,
     /// <p>The name of the workflow associated with the trigger.</p>
     #[serde(rename = "WorkflowName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub workflow_name: Option<String>,
 }
 
+impl CreateTriggerRequest {
+    /// Returns a builder for `CreateTriggerRequest`, checking its required members when built.
+    pub fn builder() -> CreateTriggerRequestBuilder {
+        CreateTriggerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTriggerRequest`](struct.CreateTriggerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTriggerRequestBuilder {
+    actions: Option<Vec<Action>>,
+    description: Option<String>,
+    name: Option<String>,
+    predicate: Option<Predicate>,
+    schedule: Option<String>,
+    start_on_creation: Option<bool>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    type_: Option<TriggerType>,
+    workflow_name: Option<String>,
+}
+
+impl CreateTriggerRequestBuilder {
+    /// Sets the [`actions`](struct.CreateTriggerRequest.html#structfield.actions) member.
+    pub fn actions<T: Into<Vec<Action>>>(mut self, value: T) -> Self {
+        self.actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateTriggerRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateTriggerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`predicate`](struct.CreateTriggerRequest.html#structfield.predicate) member.
+    pub fn predicate<T: Into<Predicate>>(mut self, value: T) -> Self {
+        self.predicate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule`](struct.CreateTriggerRequest.html#structfield.schedule) member.
+    pub fn schedule<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_on_creation`](struct.CreateTriggerRequest.html#structfield.start_on_creation) member.
+    pub fn start_on_creation<T: Into<bool>>(mut self, value: T) -> Self {
+        self.start_on_creation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateTriggerRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.CreateTriggerRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<TriggerType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets the [`workflow_name`](struct.CreateTriggerRequest.html#structfield.workflow_name) member.
+    pub fn workflow_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.workflow_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTriggerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTriggerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTriggerRequest {
+            actions: self
+                .actions
+                .ok_or(::rusoto_core::BuildError::missing("actions"))?,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            predicate: self.predicate,
+            schedule: self.schedule,
+            start_on_creation: self.start_on_creation,
+            tags: self.tags,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+            workflow_name: self.workflow_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateTriggerResponse {
@@ -1426,6 +3696,56 @@ pub struct CreateUserDefinedFunctionRequest {
     pub function_input: UserDefinedFunctionInput,
 }
 
+impl CreateUserDefinedFunctionRequest {
+    /// Returns a builder for `CreateUserDefinedFunctionRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserDefinedFunctionRequestBuilder {
+        CreateUserDefinedFunctionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserDefinedFunctionRequest`](struct.CreateUserDefinedFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserDefinedFunctionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    function_input: Option<UserDefinedFunctionInput>,
+}
+
+impl CreateUserDefinedFunctionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.CreateUserDefinedFunctionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.CreateUserDefinedFunctionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_input`](struct.CreateUserDefinedFunctionRequest.html#structfield.function_input) member.
+    pub fn function_input<T: Into<UserDefinedFunctionInput>>(mut self, value: T) -> Self {
+        self.function_input = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserDefinedFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateUserDefinedFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserDefinedFunctionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            function_input: self
+                .function_input
+                .ok_or(::rusoto_core::BuildError::missing("function_input"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateUserDefinedFunctionResponse {}
@@ -1449,6 +3769,63 @@ pub struct CreateWorkflowRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateWorkflowRequest {
+    /// Returns a builder for `CreateWorkflowRequest`, checking its required members when built.
+    pub fn builder() -> CreateWorkflowRequestBuilder {
+        CreateWorkflowRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateWorkflowRequest`](struct.CreateWorkflowRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateWorkflowRequestBuilder {
+    default_run_properties: Option<::std::collections::HashMap<String, String>>,
+    description: Option<String>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateWorkflowRequestBuilder {
+    /// Sets the [`default_run_properties`](struct.CreateWorkflowRequest.html#structfield.default_run_properties) member.
+    pub fn default_run_properties<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.default_run_properties = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateWorkflowRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateWorkflowRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateWorkflowRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateWorkflowRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateWorkflowRequest, ::rusoto_core::BuildError> {
+        Ok(CreateWorkflowRequest {
+            default_run_properties: self.default_run_properties,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateWorkflowResponse {
@@ -1484,11 +3861,12 @@ pub struct CsvClassifier {
     /// <p>Indicates whether the CSV file contains a header.</p>
     #[serde(rename = "ContainsHeader")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contains_header: Option<String>,
+    pub contains_header: Option<CsvHeaderOption>,
     /// <p>The time that this classifier was registered.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>A custom symbol to denote what separates each column entry in the row.</p>
     #[serde(rename = "Delimiter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1503,8 +3881,9 @@ pub struct CsvClassifier {
     pub header: Option<Vec<String>>,
     /// <p>The time that this classifier was last updated.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<EpochTimestamp>,
     /// <p>The name of the classifier.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -1518,40 +3897,117 @@ pub struct CsvClassifier {
     pub version: Option<i64>,
 }
 
-/// <p>Contains configuration information for maintaining Data Catalog security.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DataCatalogEncryptionSettings {
-    /// <p>When connection password protection is enabled, the Data Catalog uses a customer-provided key to encrypt the password as part of <code>CreateConnection</code> or <code>UpdateConnection</code> and store it in the <code>ENCRYPTED_PASSWORD</code> field in the connection properties. You can enable catalog encryption or only password encryption.</p>
-    #[serde(rename = "ConnectionPasswordEncryption")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_password_encryption: Option<ConnectionPasswordEncryption>,
-    /// <p>Specifies the encryption-at-rest configuration for the Data Catalog.</p>
-    #[serde(rename = "EncryptionAtRest")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_at_rest: Option<EncryptionAtRest>,
+/// <p>Indicates whether the CSV file contains a header.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CsvHeaderOption {
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// <p><code>PRESENT</code></p>
+    Present,
+    /// <p><code>ABSENT</code></p>
+    Absent,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>The AWS Lake Formation principal.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DataLakePrincipal {
-    /// <p>An identifier for the AWS Lake Formation principal.</p>
-    #[serde(rename = "DataLakePrincipalIdentifier")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_lake_principal_identifier: Option<String>,
+impl CsvHeaderOption {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CsvHeaderOption::UnknownValue => "UNKNOWN",
+            CsvHeaderOption::Present => "PRESENT",
+            CsvHeaderOption::Absent => "ABSENT",
+            CsvHeaderOption::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>The <code>Database</code> object represents a logical grouping of tables that might reside in a Hive metastore or an RDBMS.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct Database {
+impl<'a> From<&'a str> for CsvHeaderOption {
+    fn from(value: &'a str) -> CsvHeaderOption {
+        match value {
+            "UNKNOWN" => CsvHeaderOption::UnknownValue,
+            "PRESENT" => CsvHeaderOption::Present,
+            "ABSENT" => CsvHeaderOption::Absent,
+            _ => CsvHeaderOption::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CsvHeaderOption {
+    fn from(value: String) -> CsvHeaderOption {
+        CsvHeaderOption::from(value.as_str())
+    }
+}
+
+impl From<CsvHeaderOption> for String {
+    fn from(value: CsvHeaderOption) -> String {
+        match value {
+            CsvHeaderOption::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CsvHeaderOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CsvHeaderOption {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CsvHeaderOption {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CsvHeaderOption, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CsvHeaderOption::from(value))
+    }
+}
+
+/// <p>Contains configuration information for maintaining Data Catalog security.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataCatalogEncryptionSettings {
+    /// <p>When connection password protection is enabled, the Data Catalog uses a customer-provided key to encrypt the password as part of <code>CreateConnection</code> or <code>UpdateConnection</code> and store it in the <code>ENCRYPTED_PASSWORD</code> field in the connection properties. You can enable catalog encryption or only password encryption.</p>
+    #[serde(rename = "ConnectionPasswordEncryption")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_password_encryption: Option<ConnectionPasswordEncryption>,
+    /// <p>Specifies the encryption-at-rest configuration for the Data Catalog.</p>
+    #[serde(rename = "EncryptionAtRest")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption_at_rest: Option<EncryptionAtRest>,
+}
+
+/// <p>The AWS Lake Formation principal.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataLakePrincipal {
+    /// <p>An identifier for the AWS Lake Formation principal.</p>
+    #[serde(rename = "DataLakePrincipalIdentifier")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_lake_principal_identifier: Option<String>,
+}
+
+/// <p>The <code>Database</code> object represents a logical grouping of tables that might reside in a Hive metastore or an RDBMS.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Database {
     /// <p>Creates a set of default permissions on the table for principals. </p>
     #[serde(rename = "CreateTableDefaultPermissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub create_table_default_permissions: Option<Vec<PrincipalPermissions>>,
     /// <p>The time at which the metadata database was created in the catalog.</p>
     #[serde(rename = "CreateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<EpochTimestamp>,
     /// <p>A description of the database.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1593,6 +4049,82 @@ pub struct DatabaseInput {
     pub parameters: Option<::std::collections::HashMap<String, String>>,
 }
 
+/// <p>The deletion behavior when the crawler finds a deleted object.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeleteBehavior {
+    /// <p><code>LOG</code></p>
+    Log,
+    /// <p><code>DELETE_FROM_DATABASE</code></p>
+    DeleteFromDatabase,
+    /// <p><code>DEPRECATE_IN_DATABASE</code></p>
+    DeprecateInDatabase,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeleteBehavior {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeleteBehavior::Log => "LOG",
+            DeleteBehavior::DeleteFromDatabase => "DELETE_FROM_DATABASE",
+            DeleteBehavior::DeprecateInDatabase => "DEPRECATE_IN_DATABASE",
+            DeleteBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeleteBehavior {
+    fn from(value: &'a str) -> DeleteBehavior {
+        match value {
+            "LOG" => DeleteBehavior::Log,
+            "DELETE_FROM_DATABASE" => DeleteBehavior::DeleteFromDatabase,
+            "DEPRECATE_IN_DATABASE" => DeleteBehavior::DeprecateInDatabase,
+            _ => DeleteBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeleteBehavior {
+    fn from(value: String) -> DeleteBehavior {
+        DeleteBehavior::from(value.as_str())
+    }
+}
+
+impl From<DeleteBehavior> for String {
+    fn from(value: DeleteBehavior) -> String {
+        match value {
+            DeleteBehavior::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeleteBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeleteBehavior {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeleteBehavior {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeleteBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeleteBehavior::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteClassifierRequest {
     /// <p>Name of the classifier to remove.</p>
@@ -1600,6 +4132,38 @@ pub struct DeleteClassifierRequest {
     pub name: String,
 }
 
+impl DeleteClassifierRequest {
+    /// Returns a builder for `DeleteClassifierRequest`, checking its required members when built.
+    pub fn builder() -> DeleteClassifierRequestBuilder {
+        DeleteClassifierRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteClassifierRequest`](struct.DeleteClassifierRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteClassifierRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteClassifierRequestBuilder {
+    /// Sets the [`name`](struct.DeleteClassifierRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteClassifierRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteClassifierRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteClassifierRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteClassifierResponse {}
@@ -1615,6 +4179,46 @@ pub struct DeleteConnectionRequest {
     pub connection_name: String,
 }
 
+impl DeleteConnectionRequest {
+    /// Returns a builder for `DeleteConnectionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteConnectionRequestBuilder {
+        DeleteConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConnectionRequest`](struct.DeleteConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteConnectionRequestBuilder {
+    catalog_id: Option<String>,
+    connection_name: Option<String>,
+}
+
+impl DeleteConnectionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.DeleteConnectionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_name`](struct.DeleteConnectionRequest.html#structfield.connection_name) member.
+    pub fn connection_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.connection_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteConnectionRequest {
+            catalog_id: self.catalog_id,
+            connection_name: self
+                .connection_name
+                .ok_or(::rusoto_core::BuildError::missing("connection_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteConnectionResponse {}
@@ -1626,6 +4230,36 @@ pub struct DeleteCrawlerRequest {
     pub name: String,
 }
 
+impl DeleteCrawlerRequest {
+    /// Returns a builder for `DeleteCrawlerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteCrawlerRequestBuilder {
+        DeleteCrawlerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCrawlerRequest`](struct.DeleteCrawlerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteCrawlerRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteCrawlerRequestBuilder {
+    /// Sets the [`name`](struct.DeleteCrawlerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCrawlerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteCrawlerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteCrawlerRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteCrawlerResponse {}
@@ -1641,6 +4275,44 @@ pub struct DeleteDatabaseRequest {
     pub name: String,
 }
 
+impl DeleteDatabaseRequest {
+    /// Returns a builder for `DeleteDatabaseRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDatabaseRequestBuilder {
+        DeleteDatabaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDatabaseRequest`](struct.DeleteDatabaseRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDatabaseRequestBuilder {
+    catalog_id: Option<String>,
+    name: Option<String>,
+}
+
+impl DeleteDatabaseRequestBuilder {
+    /// Sets the [`catalog_id`](struct.DeleteDatabaseRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DeleteDatabaseRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDatabaseRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDatabaseRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDatabaseRequest {
+            catalog_id: self.catalog_id,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDatabaseResponse {}
@@ -1652,6 +4324,38 @@ pub struct DeleteDevEndpointRequest {
     pub endpoint_name: String,
 }
 
+impl DeleteDevEndpointRequest {
+    /// Returns a builder for `DeleteDevEndpointRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDevEndpointRequestBuilder {
+        DeleteDevEndpointRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDevEndpointRequest`](struct.DeleteDevEndpointRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDevEndpointRequestBuilder {
+    endpoint_name: Option<String>,
+}
+
+impl DeleteDevEndpointRequestBuilder {
+    /// Sets the [`endpoint_name`](struct.DeleteDevEndpointRequest.html#structfield.endpoint_name) member.
+    pub fn endpoint_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDevEndpointRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDevEndpointRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDevEndpointRequest {
+            endpoint_name: self
+                .endpoint_name
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDevEndpointResponse {}
@@ -1663,6 +4367,36 @@ pub struct DeleteJobRequest {
     pub job_name: String,
 }
 
+impl DeleteJobRequest {
+    /// Returns a builder for `DeleteJobRequest`, checking its required members when built.
+    pub fn builder() -> DeleteJobRequestBuilder {
+        DeleteJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteJobRequest`](struct.DeleteJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteJobRequestBuilder {
+    job_name: Option<String>,
+}
+
+impl DeleteJobRequestBuilder {
+    /// Sets the [`job_name`](struct.DeleteJobRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteJobRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteJobRequest {
+            job_name: self
+                .job_name
+                .ok_or(::rusoto_core::BuildError::missing("job_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteJobResponse {
@@ -1679,6 +4413,38 @@ pub struct DeleteMLTransformRequest {
     pub transform_id: String,
 }
 
+impl DeleteMLTransformRequest {
+    /// Returns a builder for `DeleteMLTransformRequest`, checking its required members when built.
+    pub fn builder() -> DeleteMLTransformRequestBuilder {
+        DeleteMLTransformRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMLTransformRequest`](struct.DeleteMLTransformRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteMLTransformRequestBuilder {
+    transform_id: Option<String>,
+}
+
+impl DeleteMLTransformRequestBuilder {
+    /// Sets the [`transform_id`](struct.DeleteMLTransformRequest.html#structfield.transform_id) member.
+    pub fn transform_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transform_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMLTransformRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteMLTransformRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteMLTransformRequest {
+            transform_id: self
+                .transform_id
+                .ok_or(::rusoto_core::BuildError::missing("transform_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteMLTransformResponse {
@@ -1705,6 +4471,64 @@ pub struct DeletePartitionRequest {
     pub table_name: String,
 }
 
+impl DeletePartitionRequest {
+    /// Returns a builder for `DeletePartitionRequest`, checking its required members when built.
+    pub fn builder() -> DeletePartitionRequestBuilder {
+        DeletePartitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePartitionRequest`](struct.DeletePartitionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePartitionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    partition_values: Option<Vec<String>>,
+    table_name: Option<String>,
+}
+
+impl DeletePartitionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.DeletePartitionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.DeletePartitionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`partition_values`](struct.DeletePartitionRequest.html#structfield.partition_values) member.
+    pub fn partition_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.partition_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.DeletePartitionRequest.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePartitionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePartitionRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePartitionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            partition_values: self
+                .partition_values
+                .ok_or(::rusoto_core::BuildError::missing("partition_values"))?,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeletePartitionResponse {}
@@ -1717,6 +4541,36 @@ pub struct DeleteResourcePolicyRequest {
     pub policy_hash_condition: Option<String>,
 }
 
+impl DeleteResourcePolicyRequest {
+    /// Returns a builder for `DeleteResourcePolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteResourcePolicyRequestBuilder {
+        DeleteResourcePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteResourcePolicyRequest`](struct.DeleteResourcePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteResourcePolicyRequestBuilder {
+    policy_hash_condition: Option<String>,
+}
+
+impl DeleteResourcePolicyRequestBuilder {
+    /// Sets the [`policy_hash_condition`](struct.DeleteResourcePolicyRequest.html#structfield.policy_hash_condition) member.
+    pub fn policy_hash_condition<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_hash_condition = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteResourcePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteResourcePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteResourcePolicyRequest {
+            policy_hash_condition: self.policy_hash_condition,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteResourcePolicyResponse {}
@@ -1728,6 +4582,38 @@ pub struct DeleteSecurityConfigurationRequest {
     pub name: String,
 }
 
+impl DeleteSecurityConfigurationRequest {
+    /// Returns a builder for `DeleteSecurityConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSecurityConfigurationRequestBuilder {
+        DeleteSecurityConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSecurityConfigurationRequest`](struct.DeleteSecurityConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSecurityConfigurationRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteSecurityConfigurationRequestBuilder {
+    /// Sets the [`name`](struct.DeleteSecurityConfigurationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSecurityConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSecurityConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSecurityConfigurationRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSecurityConfigurationResponse {}
@@ -1746,6 +4632,54 @@ pub struct DeleteTableRequest {
     pub name: String,
 }
 
+impl DeleteTableRequest {
+    /// Returns a builder for `DeleteTableRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTableRequestBuilder {
+        DeleteTableRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTableRequest`](struct.DeleteTableRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTableRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    name: Option<String>,
+}
+
+impl DeleteTableRequestBuilder {
+    /// Sets the [`catalog_id`](struct.DeleteTableRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.DeleteTableRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DeleteTableRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTableRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTableRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTableRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteTableResponse {}
@@ -1767,6 +4701,66 @@ pub struct DeleteTableVersionRequest {
     pub version_id: String,
 }
 
+impl DeleteTableVersionRequest {
+    /// Returns a builder for `DeleteTableVersionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTableVersionRequestBuilder {
+        DeleteTableVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTableVersionRequest`](struct.DeleteTableVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTableVersionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    table_name: Option<String>,
+    version_id: Option<String>,
+}
+
+impl DeleteTableVersionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.DeleteTableVersionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.DeleteTableVersionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.DeleteTableVersionRequest.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.DeleteTableVersionRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTableVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteTableVersionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTableVersionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+            version_id: self
+                .version_id
+                .ok_or(::rusoto_core::BuildError::missing("version_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteTableVersionResponse {}
@@ -1778,6 +4772,36 @@ pub struct DeleteTriggerRequest {
     pub name: String,
 }
 
+impl DeleteTriggerRequest {
+    /// Returns a builder for `DeleteTriggerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTriggerRequestBuilder {
+        DeleteTriggerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTriggerRequest`](struct.DeleteTriggerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTriggerRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteTriggerRequestBuilder {
+    /// Sets the [`name`](struct.DeleteTriggerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTriggerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTriggerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTriggerRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteTriggerResponse {
@@ -1801,15 +4825,95 @@ pub struct DeleteUserDefinedFunctionRequest {
     pub function_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DeleteUserDefinedFunctionResponse {}
+impl DeleteUserDefinedFunctionRequest {
+    /// Returns a builder for `DeleteUserDefinedFunctionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserDefinedFunctionRequestBuilder {
+        DeleteUserDefinedFunctionRequestBuilder::default()
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DeleteWorkflowRequest {
-    /// <p>Name of the workflow to be deleted.</p>
-    #[serde(rename = "Name")]
-    pub name: String,
+/// Builds a [`DeleteUserDefinedFunctionRequest`](struct.DeleteUserDefinedFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserDefinedFunctionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    function_name: Option<String>,
+}
+
+impl DeleteUserDefinedFunctionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.DeleteUserDefinedFunctionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.DeleteUserDefinedFunctionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.DeleteUserDefinedFunctionRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserDefinedFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteUserDefinedFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserDefinedFunctionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteUserDefinedFunctionResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteWorkflowRequest {
+    /// <p>Name of the workflow to be deleted.</p>
+    #[serde(rename = "Name")]
+    pub name: String,
+}
+
+impl DeleteWorkflowRequest {
+    /// Returns a builder for `DeleteWorkflowRequest`, checking its required members when built.
+    pub fn builder() -> DeleteWorkflowRequestBuilder {
+        DeleteWorkflowRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteWorkflowRequest`](struct.DeleteWorkflowRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteWorkflowRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteWorkflowRequestBuilder {
+    /// Sets the [`name`](struct.DeleteWorkflowRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteWorkflowRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteWorkflowRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteWorkflowRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1835,8 +4939,9 @@ pub struct DevEndpoint {
     pub availability_zone: Option<String>,
     /// <p>The point in time at which this DevEndpoint was created.</p>
     #[serde(rename = "CreatedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_timestamp: Option<f64>,
+    pub created_timestamp: Option<EpochTimestamp>,
     /// <p>The name of the <code>DevEndpoint</code>.</p>
     #[serde(rename = "EndpointName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1855,8 +4960,9 @@ pub struct DevEndpoint {
     pub failure_reason: Option<String>,
     /// <p>The point in time at which this <code>DevEndpoint</code> was last modified.</p>
     #[serde(rename = "LastModifiedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_timestamp: Option<f64>,
+    pub last_modified_timestamp: Option<EpochTimestamp>,
     /// <p>The status of the last update.</p>
     #[serde(rename = "LastUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1912,7 +5018,7 @@ pub struct DevEndpoint {
     /// <p>The type of predefined worker that is allocated to the development endpoint. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker maps to 1 DPU (4 vCPU, 16 GB of memory, 64 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker maps to 2 DPU (8 vCPU, 32 GB of memory, 128 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> </ul> <p>Known issue: when a development endpoint is created with the <code>G.2X</code> <code>WorkerType</code> configuration, the Spark drivers for the development endpoint will run on 4 vCPU, 16 GB of memory, and a 64 GB disk. </p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
     /// <p>The YARN endpoint address used by this <code>DevEndpoint</code>.</p>
     #[serde(rename = "YarnEndpointAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1960,11 +5066,11 @@ pub struct Edge {
 }
 
 /// <p>Specifies the encryption-at-rest configuration for the Data Catalog.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EncryptionAtRest {
     /// <p>The encryption-at-rest mode for encrypting Data Catalog data.</p>
     #[serde(rename = "CatalogEncryptionMode")]
-    pub catalog_encryption_mode: String,
+    pub catalog_encryption_mode: CatalogEncryptionMode,
     /// <p>The ID of the AWS KMS key to use for encryption at rest.</p>
     #[serde(rename = "SseAwsKmsKeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2003,7 +5109,7 @@ pub struct ErrorDetail {
 }
 
 /// <p>Evaluation metrics provide an estimate of the quality of your machine learning transform.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct EvaluationMetrics {
     /// <p>The evaluation metrics for the find matches algorithm.</p>
@@ -2012,7 +5118,7 @@ pub struct EvaluationMetrics {
     pub find_matches_metrics: Option<FindMatchesMetrics>,
     /// <p>The type of machine learning transform.</p>
     #[serde(rename = "TransformType")]
-    pub transform_type: String,
+    pub transform_type: TransformType,
 }
 
 /// <p>An execution property of a job.</p>
@@ -2024,6 +5130,82 @@ pub struct ExecutionProperty {
     pub max_concurrent_runs: Option<i64>,
 }
 
+/// <p>A value of <code>MUST_EXIST</code> is used to update a policy. A value of <code>NOT_EXIST</code> is used to create a new policy. If a value of <code>NONE</code> or a null value is used, the call will not depend on the existence of a policy.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExistCondition {
+    /// <p><code>MUST_EXIST</code></p>
+    MustExist,
+    /// <p><code>NOT_EXIST</code></p>
+    NotExist,
+    /// <p><code>NONE</code></p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExistCondition {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExistCondition::MustExist => "MUST_EXIST",
+            ExistCondition::NotExist => "NOT_EXIST",
+            ExistCondition::None => "NONE",
+            ExistCondition::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExistCondition {
+    fn from(value: &'a str) -> ExistCondition {
+        match value {
+            "MUST_EXIST" => ExistCondition::MustExist,
+            "NOT_EXIST" => ExistCondition::NotExist,
+            "NONE" => ExistCondition::None,
+            _ => ExistCondition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExistCondition {
+    fn from(value: String) -> ExistCondition {
+        ExistCondition::from(value.as_str())
+    }
+}
+
+impl From<ExistCondition> for String {
+    fn from(value: ExistCondition) -> String {
+        match value {
+            ExistCondition::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExistCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExistCondition {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExistCondition {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExistCondition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExistCondition::from(value))
+    }
+}
+
 /// <p>Specifies configuration properties for an exporting labels task run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2107,6 +5289,36 @@ pub struct GetCatalogImportStatusRequest {
     pub catalog_id: Option<String>,
 }
 
+impl GetCatalogImportStatusRequest {
+    /// Returns a builder for `GetCatalogImportStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetCatalogImportStatusRequestBuilder {
+        GetCatalogImportStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCatalogImportStatusRequest`](struct.GetCatalogImportStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCatalogImportStatusRequestBuilder {
+    catalog_id: Option<String>,
+}
+
+impl GetCatalogImportStatusRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetCatalogImportStatusRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCatalogImportStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCatalogImportStatusRequest, ::rusoto_core::BuildError> {
+        Ok(GetCatalogImportStatusRequest {
+            catalog_id: self.catalog_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCatalogImportStatusResponse {
@@ -2123,6 +5335,36 @@ pub struct GetClassifierRequest {
     pub name: String,
 }
 
+impl GetClassifierRequest {
+    /// Returns a builder for `GetClassifierRequest`, checking its required members when built.
+    pub fn builder() -> GetClassifierRequestBuilder {
+        GetClassifierRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetClassifierRequest`](struct.GetClassifierRequest.html).
+#[derive(Default, Debug)]
+pub struct GetClassifierRequestBuilder {
+    name: Option<String>,
+}
+
+impl GetClassifierRequestBuilder {
+    /// Sets the [`name`](struct.GetClassifierRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetClassifierRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetClassifierRequest, ::rusoto_core::BuildError> {
+        Ok(GetClassifierRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetClassifierResponse {
@@ -2144,6 +5386,42 @@ pub struct GetClassifiersRequest {
     pub next_token: Option<String>,
 }
 
+impl GetClassifiersRequest {
+    /// Returns a builder for `GetClassifiersRequest`, checking its required members when built.
+    pub fn builder() -> GetClassifiersRequestBuilder {
+        GetClassifiersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetClassifiersRequest`](struct.GetClassifiersRequest.html).
+#[derive(Default, Debug)]
+pub struct GetClassifiersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetClassifiersRequestBuilder {
+    /// Sets the [`max_results`](struct.GetClassifiersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetClassifiersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetClassifiersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetClassifiersRequest, ::rusoto_core::BuildError> {
+        Ok(GetClassifiersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetClassifiersResponse {
@@ -2172,6 +5450,52 @@ pub struct GetConnectionRequest {
     pub name: String,
 }
 
+impl GetConnectionRequest {
+    /// Returns a builder for `GetConnectionRequest`, checking its required members when built.
+    pub fn builder() -> GetConnectionRequestBuilder {
+        GetConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetConnectionRequest`](struct.GetConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetConnectionRequestBuilder {
+    catalog_id: Option<String>,
+    hide_password: Option<bool>,
+    name: Option<String>,
+}
+
+impl GetConnectionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetConnectionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hide_password`](struct.GetConnectionRequest.html#structfield.hide_password) member.
+    pub fn hide_password<T: Into<bool>>(mut self, value: T) -> Self {
+        self.hide_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetConnectionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(GetConnectionRequest {
+            catalog_id: self.catalog_id,
+            hide_password: self.hide_password,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetConnectionResponse {
@@ -2187,7 +5511,7 @@ pub struct GetConnectionsFilter {
     /// <p>The type of connections to return. Currently, only JDBC is supported; SFTP is not supported.</p>
     #[serde(rename = "ConnectionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
     /// <p>A criteria string that must match the criteria recorded in the connection definition for that connection definition to be returned.</p>
     #[serde(rename = "MatchCriteria")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2218,6 +5542,66 @@ pub struct GetConnectionsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetConnectionsRequest {
+    /// Returns a builder for `GetConnectionsRequest`, checking its required members when built.
+    pub fn builder() -> GetConnectionsRequestBuilder {
+        GetConnectionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetConnectionsRequest`](struct.GetConnectionsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetConnectionsRequestBuilder {
+    catalog_id: Option<String>,
+    filter: Option<GetConnectionsFilter>,
+    hide_password: Option<bool>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetConnectionsRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetConnectionsRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter`](struct.GetConnectionsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<GetConnectionsFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hide_password`](struct.GetConnectionsRequest.html#structfield.hide_password) member.
+    pub fn hide_password<T: Into<bool>>(mut self, value: T) -> Self {
+        self.hide_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetConnectionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetConnectionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetConnectionsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetConnectionsRequest, ::rusoto_core::BuildError> {
+        Ok(GetConnectionsRequest {
+            catalog_id: self.catalog_id,
+            filter: self.filter,
+            hide_password: self.hide_password,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetConnectionsResponse {
@@ -2247,6 +5631,52 @@ pub struct GetCrawlerMetricsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetCrawlerMetricsRequest {
+    /// Returns a builder for `GetCrawlerMetricsRequest`, checking its required members when built.
+    pub fn builder() -> GetCrawlerMetricsRequestBuilder {
+        GetCrawlerMetricsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCrawlerMetricsRequest`](struct.GetCrawlerMetricsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCrawlerMetricsRequestBuilder {
+    crawler_name_list: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetCrawlerMetricsRequestBuilder {
+    /// Sets the [`crawler_name_list`](struct.GetCrawlerMetricsRequest.html#structfield.crawler_name_list) member.
+    pub fn crawler_name_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.crawler_name_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetCrawlerMetricsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetCrawlerMetricsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCrawlerMetricsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCrawlerMetricsRequest, ::rusoto_core::BuildError> {
+        Ok(GetCrawlerMetricsRequest {
+            crawler_name_list: self.crawler_name_list,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCrawlerMetricsResponse {
@@ -2267,6 +5697,36 @@ pub struct GetCrawlerRequest {
     pub name: String,
 }
 
+impl GetCrawlerRequest {
+    /// Returns a builder for `GetCrawlerRequest`, checking its required members when built.
+    pub fn builder() -> GetCrawlerRequestBuilder {
+        GetCrawlerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCrawlerRequest`](struct.GetCrawlerRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCrawlerRequestBuilder {
+    name: Option<String>,
+}
+
+impl GetCrawlerRequestBuilder {
+    /// Sets the [`name`](struct.GetCrawlerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCrawlerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetCrawlerRequest, ::rusoto_core::BuildError> {
+        Ok(GetCrawlerRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCrawlerResponse {
@@ -2288,6 +5748,42 @@ pub struct GetCrawlersRequest {
     pub next_token: Option<String>,
 }
 
+impl GetCrawlersRequest {
+    /// Returns a builder for `GetCrawlersRequest`, checking its required members when built.
+    pub fn builder() -> GetCrawlersRequestBuilder {
+        GetCrawlersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCrawlersRequest`](struct.GetCrawlersRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCrawlersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetCrawlersRequestBuilder {
+    /// Sets the [`max_results`](struct.GetCrawlersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetCrawlersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCrawlersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetCrawlersRequest, ::rusoto_core::BuildError> {
+        Ok(GetCrawlersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCrawlersResponse {
@@ -2309,6 +5805,37 @@ pub struct GetDataCatalogEncryptionSettingsRequest {
     pub catalog_id: Option<String>,
 }
 
+impl GetDataCatalogEncryptionSettingsRequest {
+    /// Returns a builder for `GetDataCatalogEncryptionSettingsRequest`, checking its required members when built.
+    pub fn builder() -> GetDataCatalogEncryptionSettingsRequestBuilder {
+        GetDataCatalogEncryptionSettingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDataCatalogEncryptionSettingsRequest`](struct.GetDataCatalogEncryptionSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDataCatalogEncryptionSettingsRequestBuilder {
+    catalog_id: Option<String>,
+}
+
+impl GetDataCatalogEncryptionSettingsRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetDataCatalogEncryptionSettingsRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDataCatalogEncryptionSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDataCatalogEncryptionSettingsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetDataCatalogEncryptionSettingsRequest {
+            catalog_id: self.catalog_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDataCatalogEncryptionSettingsResponse {
@@ -2329,6 +5856,44 @@ pub struct GetDatabaseRequest {
     pub name: String,
 }
 
+impl GetDatabaseRequest {
+    /// Returns a builder for `GetDatabaseRequest`, checking its required members when built.
+    pub fn builder() -> GetDatabaseRequestBuilder {
+        GetDatabaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDatabaseRequest`](struct.GetDatabaseRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDatabaseRequestBuilder {
+    catalog_id: Option<String>,
+    name: Option<String>,
+}
+
+impl GetDatabaseRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetDatabaseRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetDatabaseRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDatabaseRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDatabaseRequest, ::rusoto_core::BuildError> {
+        Ok(GetDatabaseRequest {
+            catalog_id: self.catalog_id,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDatabaseResponse {
@@ -2354,6 +5919,50 @@ pub struct GetDatabasesRequest {
     pub next_token: Option<String>,
 }
 
+impl GetDatabasesRequest {
+    /// Returns a builder for `GetDatabasesRequest`, checking its required members when built.
+    pub fn builder() -> GetDatabasesRequestBuilder {
+        GetDatabasesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDatabasesRequest`](struct.GetDatabasesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDatabasesRequestBuilder {
+    catalog_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetDatabasesRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetDatabasesRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetDatabasesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetDatabasesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDatabasesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDatabasesRequest, ::rusoto_core::BuildError> {
+        Ok(GetDatabasesRequest {
+            catalog_id: self.catalog_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDatabasesResponse {
@@ -2374,6 +5983,36 @@ pub struct GetDataflowGraphRequest {
     pub python_script: Option<String>,
 }
 
+impl GetDataflowGraphRequest {
+    /// Returns a builder for `GetDataflowGraphRequest`, checking its required members when built.
+    pub fn builder() -> GetDataflowGraphRequestBuilder {
+        GetDataflowGraphRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDataflowGraphRequest`](struct.GetDataflowGraphRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDataflowGraphRequestBuilder {
+    python_script: Option<String>,
+}
+
+impl GetDataflowGraphRequestBuilder {
+    /// Sets the [`python_script`](struct.GetDataflowGraphRequest.html#structfield.python_script) member.
+    pub fn python_script<T: Into<String>>(mut self, value: T) -> Self {
+        self.python_script = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDataflowGraphRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDataflowGraphRequest, ::rusoto_core::BuildError> {
+        Ok(GetDataflowGraphRequest {
+            python_script: self.python_script,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDataflowGraphResponse {
@@ -2394,6 +6033,36 @@ pub struct GetDevEndpointRequest {
     pub endpoint_name: String,
 }
 
+impl GetDevEndpointRequest {
+    /// Returns a builder for `GetDevEndpointRequest`, checking its required members when built.
+    pub fn builder() -> GetDevEndpointRequestBuilder {
+        GetDevEndpointRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDevEndpointRequest`](struct.GetDevEndpointRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDevEndpointRequestBuilder {
+    endpoint_name: Option<String>,
+}
+
+impl GetDevEndpointRequestBuilder {
+    /// Sets the [`endpoint_name`](struct.GetDevEndpointRequest.html#structfield.endpoint_name) member.
+    pub fn endpoint_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDevEndpointRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDevEndpointRequest, ::rusoto_core::BuildError> {
+        Ok(GetDevEndpointRequest {
+            endpoint_name: self
+                .endpoint_name
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDevEndpointResponse {
@@ -2415,6 +6084,42 @@ pub struct GetDevEndpointsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetDevEndpointsRequest {
+    /// Returns a builder for `GetDevEndpointsRequest`, checking its required members when built.
+    pub fn builder() -> GetDevEndpointsRequestBuilder {
+        GetDevEndpointsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDevEndpointsRequest`](struct.GetDevEndpointsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDevEndpointsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetDevEndpointsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetDevEndpointsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetDevEndpointsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDevEndpointsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDevEndpointsRequest, ::rusoto_core::BuildError> {
+        Ok(GetDevEndpointsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDevEndpointsResponse {
@@ -2439,13 +6144,51 @@ pub struct GetJobBookmarkRequest {
     pub run_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetJobBookmarkResponse {
-    /// <p>A structure that defines a point that a job can resume processing.</p>
-    #[serde(rename = "JobBookmarkEntry")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_bookmark_entry: Option<JobBookmarkEntry>,
+impl GetJobBookmarkRequest {
+    /// Returns a builder for `GetJobBookmarkRequest`, checking its required members when built.
+    pub fn builder() -> GetJobBookmarkRequestBuilder {
+        GetJobBookmarkRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetJobBookmarkRequest`](struct.GetJobBookmarkRequest.html).
+#[derive(Default, Debug)]
+pub struct GetJobBookmarkRequestBuilder {
+    job_name: Option<String>,
+    run_id: Option<String>,
+}
+
+impl GetJobBookmarkRequestBuilder {
+    /// Sets the [`job_name`](struct.GetJobBookmarkRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`run_id`](struct.GetJobBookmarkRequest.html#structfield.run_id) member.
+    pub fn run_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.run_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetJobBookmarkRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetJobBookmarkRequest, ::rusoto_core::BuildError> {
+        Ok(GetJobBookmarkRequest {
+            job_name: self
+                .job_name
+                .ok_or(::rusoto_core::BuildError::missing("job_name"))?,
+            run_id: self.run_id,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetJobBookmarkResponse {
+    /// <p>A structure that defines a point that a job can resume processing.</p>
+    #[serde(rename = "JobBookmarkEntry")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_bookmark_entry: Option<JobBookmarkEntry>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2455,6 +6198,36 @@ pub struct GetJobRequest {
     pub job_name: String,
 }
 
+impl GetJobRequest {
+    /// Returns a builder for `GetJobRequest`, checking its required members when built.
+    pub fn builder() -> GetJobRequestBuilder {
+        GetJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetJobRequest`](struct.GetJobRequest.html).
+#[derive(Default, Debug)]
+pub struct GetJobRequestBuilder {
+    job_name: Option<String>,
+}
+
+impl GetJobRequestBuilder {
+    /// Sets the [`job_name`](struct.GetJobRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetJobRequest, ::rusoto_core::BuildError> {
+        Ok(GetJobRequest {
+            job_name: self
+                .job_name
+                .ok_or(::rusoto_core::BuildError::missing("job_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetJobResponse {
@@ -2478,6 +6251,54 @@ pub struct GetJobRunRequest {
     pub run_id: String,
 }
 
+impl GetJobRunRequest {
+    /// Returns a builder for `GetJobRunRequest`, checking its required members when built.
+    pub fn builder() -> GetJobRunRequestBuilder {
+        GetJobRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetJobRunRequest`](struct.GetJobRunRequest.html).
+#[derive(Default, Debug)]
+pub struct GetJobRunRequestBuilder {
+    job_name: Option<String>,
+    predecessors_included: Option<bool>,
+    run_id: Option<String>,
+}
+
+impl GetJobRunRequestBuilder {
+    /// Sets the [`job_name`](struct.GetJobRunRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`predecessors_included`](struct.GetJobRunRequest.html#structfield.predecessors_included) member.
+    pub fn predecessors_included<T: Into<bool>>(mut self, value: T) -> Self {
+        self.predecessors_included = Some(value.into());
+        self
+    }
+
+    /// Sets the [`run_id`](struct.GetJobRunRequest.html#structfield.run_id) member.
+    pub fn run_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.run_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetJobRunRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetJobRunRequest, ::rusoto_core::BuildError> {
+        Ok(GetJobRunRequest {
+            job_name: self
+                .job_name
+                .ok_or(::rusoto_core::BuildError::missing("job_name"))?,
+            predecessors_included: self.predecessors_included,
+            run_id: self
+                .run_id
+                .ok_or(::rusoto_core::BuildError::missing("run_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetJobRunResponse {
@@ -2502,6 +6323,52 @@ pub struct GetJobRunsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetJobRunsRequest {
+    /// Returns a builder for `GetJobRunsRequest`, checking its required members when built.
+    pub fn builder() -> GetJobRunsRequestBuilder {
+        GetJobRunsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetJobRunsRequest`](struct.GetJobRunsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetJobRunsRequestBuilder {
+    job_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetJobRunsRequestBuilder {
+    /// Sets the [`job_name`](struct.GetJobRunsRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetJobRunsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetJobRunsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetJobRunsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetJobRunsRequest, ::rusoto_core::BuildError> {
+        Ok(GetJobRunsRequest {
+            job_name: self
+                .job_name
+                .ok_or(::rusoto_core::BuildError::missing("job_name"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetJobRunsResponse {
@@ -2527,6 +6394,42 @@ pub struct GetJobsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetJobsRequest {
+    /// Returns a builder for `GetJobsRequest`, checking its required members when built.
+    pub fn builder() -> GetJobsRequestBuilder {
+        GetJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetJobsRequest`](struct.GetJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetJobsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetJobsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetJobsRequest, ::rusoto_core::BuildError> {
+        Ok(GetJobsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetJobsResponse {
@@ -2550,13 +6453,54 @@ pub struct GetMLTaskRunRequest {
     pub transform_id: String,
 }
 
+impl GetMLTaskRunRequest {
+    /// Returns a builder for `GetMLTaskRunRequest`, checking its required members when built.
+    pub fn builder() -> GetMLTaskRunRequestBuilder {
+        GetMLTaskRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetMLTaskRunRequest`](struct.GetMLTaskRunRequest.html).
+#[derive(Default, Debug)]
+pub struct GetMLTaskRunRequestBuilder {
+    task_run_id: Option<String>,
+    transform_id: Option<String>,
+}
+
+impl GetMLTaskRunRequestBuilder {
+    /// Sets the [`task_run_id`](struct.GetMLTaskRunRequest.html#structfield.task_run_id) member.
+    pub fn task_run_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_run_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transform_id`](struct.GetMLTaskRunRequest.html#structfield.transform_id) member.
+    pub fn transform_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transform_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMLTaskRunRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMLTaskRunRequest, ::rusoto_core::BuildError> {
+        Ok(GetMLTaskRunRequest {
+            task_run_id: self
+                .task_run_id
+                .ok_or(::rusoto_core::BuildError::missing("task_run_id"))?,
+            transform_id: self
+                .transform_id
+                .ok_or(::rusoto_core::BuildError::missing("transform_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetMLTaskRunResponse {
     /// <p>The date and time when this task run was completed.</p>
     #[serde(rename = "CompletedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_on: Option<f64>,
+    pub completed_on: Option<EpochTimestamp>,
     /// <p>The error strings that are associated with the task run.</p>
     #[serde(rename = "ErrorString")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2567,8 +6511,9 @@ pub struct GetMLTaskRunResponse {
     pub execution_time: Option<i64>,
     /// <p>The date and time when this task run was last modified.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<EpochTimestamp>,
     /// <p>The names of the log groups that are associated with the task run.</p>
     #[serde(rename = "LogGroupName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2579,12 +6524,13 @@ pub struct GetMLTaskRunResponse {
     pub properties: Option<TaskRunProperties>,
     /// <p>The date and time when this task run started.</p>
     #[serde(rename = "StartedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_on: Option<f64>,
+    pub started_on: Option<EpochTimestamp>,
     /// <p>The status for this task run.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskStatusType>,
     /// <p>The unique run identifier associated with this run.</p>
     #[serde(rename = "TaskRunId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2618,6 +6564,68 @@ pub struct GetMLTaskRunsRequest {
     pub transform_id: String,
 }
 
+impl GetMLTaskRunsRequest {
+    /// Returns a builder for `GetMLTaskRunsRequest`, checking its required members when built.
+    pub fn builder() -> GetMLTaskRunsRequestBuilder {
+        GetMLTaskRunsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetMLTaskRunsRequest`](struct.GetMLTaskRunsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetMLTaskRunsRequestBuilder {
+    filter: Option<TaskRunFilterCriteria>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort: Option<TaskRunSortCriteria>,
+    transform_id: Option<String>,
+}
+
+impl GetMLTaskRunsRequestBuilder {
+    /// Sets the [`filter`](struct.GetMLTaskRunsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<TaskRunFilterCriteria>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetMLTaskRunsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetMLTaskRunsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort`](struct.GetMLTaskRunsRequest.html#structfield.sort) member.
+    pub fn sort<T: Into<TaskRunSortCriteria>>(mut self, value: T) -> Self {
+        self.sort = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transform_id`](struct.GetMLTaskRunsRequest.html#structfield.transform_id) member.
+    pub fn transform_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transform_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMLTaskRunsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMLTaskRunsRequest, ::rusoto_core::BuildError> {
+        Ok(GetMLTaskRunsRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort: self.sort,
+            transform_id: self
+                .transform_id
+                .ok_or(::rusoto_core::BuildError::missing("transform_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetMLTaskRunsResponse {
@@ -2638,13 +6646,44 @@ pub struct GetMLTransformRequest {
     pub transform_id: String,
 }
 
+impl GetMLTransformRequest {
+    /// Returns a builder for `GetMLTransformRequest`, checking its required members when built.
+    pub fn builder() -> GetMLTransformRequestBuilder {
+        GetMLTransformRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetMLTransformRequest`](struct.GetMLTransformRequest.html).
+#[derive(Default, Debug)]
+pub struct GetMLTransformRequestBuilder {
+    transform_id: Option<String>,
+}
+
+impl GetMLTransformRequestBuilder {
+    /// Sets the [`transform_id`](struct.GetMLTransformRequest.html#structfield.transform_id) member.
+    pub fn transform_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transform_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMLTransformRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMLTransformRequest, ::rusoto_core::BuildError> {
+        Ok(GetMLTransformRequest {
+            transform_id: self
+                .transform_id
+                .ok_or(::rusoto_core::BuildError::missing("transform_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetMLTransformResponse {
     /// <p>The date and time when the transform was created.</p>
     #[serde(rename = "CreatedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_on: Option<f64>,
+    pub created_on: Option<EpochTimestamp>,
     /// <p>A description of the transform.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2663,8 +6702,9 @@ pub struct GetMLTransformResponse {
     pub label_count: Option<i64>,
     /// <p>The date and time when the transform was last modified.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<EpochTimestamp>,
     /// <p>The number of AWS Glue data processing units (DPUs) that are allocated to task runs for this transform. You can allocate from 2 to 100 DPUs; the default is 10. A DPU is a relative measure of processing power that consists of 4 vCPUs of compute capacity and 16 GB of memory. For more information, see the <a href="https://aws.amazon.com/glue/pricing/">AWS Glue pricing page</a>. </p> <p>When the <code>WorkerType</code> field is set to a value other than <code>Standard</code>, the <code>MaxCapacity</code> field is set automatically and becomes read-only.</p>
     #[serde(rename = "MaxCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2696,7 +6736,7 @@ pub struct GetMLTransformResponse {
     /// <p>The last known status of the transform (to indicate whether it can be used or not). One of "NOT_READY", "READY", or "DELETING".</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TransformStatusType>,
     /// <p>The timeout for a task run for this transform in minutes. This is the maximum time that a task run for this transform can consume resources before it is terminated and enters <code>TIMEOUT</code> status. The default is 2,880 minutes (48 hours).</p>
     #[serde(rename = "Timeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2708,7 +6748,7 @@ pub struct GetMLTransformResponse {
     /// <p><p>The type of predefined worker that is allocated when this task runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 64GB disk, and 1 executor per worker.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker provides 8 vCPU, 32 GB of memory and a 128GB disk, and 1 executor per worker.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2731,6 +6771,58 @@ pub struct GetMLTransformsRequest {
     pub sort: Option<TransformSortCriteria>,
 }
 
+impl GetMLTransformsRequest {
+    /// Returns a builder for `GetMLTransformsRequest`, checking its required members when built.
+    pub fn builder() -> GetMLTransformsRequestBuilder {
+        GetMLTransformsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetMLTransformsRequest`](struct.GetMLTransformsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetMLTransformsRequestBuilder {
+    filter: Option<TransformFilterCriteria>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort: Option<TransformSortCriteria>,
+}
+
+impl GetMLTransformsRequestBuilder {
+    /// Sets the [`filter`](struct.GetMLTransformsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<TransformFilterCriteria>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetMLTransformsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetMLTransformsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort`](struct.GetMLTransformsRequest.html#structfield.sort) member.
+    pub fn sort<T: Into<TransformSortCriteria>>(mut self, value: T) -> Self {
+        self.sort = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMLTransformsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMLTransformsRequest, ::rusoto_core::BuildError> {
+        Ok(GetMLTransformsRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort: self.sort,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetMLTransformsResponse {
@@ -2758,6 +6850,52 @@ pub struct GetMappingRequest {
     pub source: CatalogEntry,
 }
 
+impl GetMappingRequest {
+    /// Returns a builder for `GetMappingRequest`, checking its required members when built.
+    pub fn builder() -> GetMappingRequestBuilder {
+        GetMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetMappingRequest`](struct.GetMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct GetMappingRequestBuilder {
+    location: Option<Location>,
+    sinks: Option<Vec<CatalogEntry>>,
+    source: Option<CatalogEntry>,
+}
+
+impl GetMappingRequestBuilder {
+    /// Sets the [`location`](struct.GetMappingRequest.html#structfield.location) member.
+    pub fn location<T: Into<Location>>(mut self, value: T) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sinks`](struct.GetMappingRequest.html#structfield.sinks) member.
+    pub fn sinks<T: Into<Vec<CatalogEntry>>>(mut self, value: T) -> Self {
+        self.sinks = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.GetMappingRequest.html#structfield.source) member.
+    pub fn source<T: Into<CatalogEntry>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMappingRequest, ::rusoto_core::BuildError> {
+        Ok(GetMappingRequest {
+            location: self.location,
+            sinks: self.sinks,
+            source: self
+                .source
+                .ok_or(::rusoto_core::BuildError::missing("source"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetMappingResponse {
@@ -2783,6 +6921,64 @@ pub struct GetPartitionRequest {
     pub table_name: String,
 }
 
+impl GetPartitionRequest {
+    /// Returns a builder for `GetPartitionRequest`, checking its required members when built.
+    pub fn builder() -> GetPartitionRequestBuilder {
+        GetPartitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPartitionRequest`](struct.GetPartitionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPartitionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    partition_values: Option<Vec<String>>,
+    table_name: Option<String>,
+}
+
+impl GetPartitionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetPartitionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.GetPartitionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`partition_values`](struct.GetPartitionRequest.html#structfield.partition_values) member.
+    pub fn partition_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.partition_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.GetPartitionRequest.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPartitionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetPartitionRequest, ::rusoto_core::BuildError> {
+        Ok(GetPartitionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            partition_values: self
+                .partition_values
+                .ok_or(::rusoto_core::BuildError::missing("partition_values"))?,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPartitionResponse {
@@ -2822,6 +7018,86 @@ pub struct GetPartitionsRequest {
     pub table_name: String,
 }
 
+impl GetPartitionsRequest {
+    /// Returns a builder for `GetPartitionsRequest`, checking its required members when built.
+    pub fn builder() -> GetPartitionsRequestBuilder {
+        GetPartitionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPartitionsRequest`](struct.GetPartitionsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPartitionsRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    expression: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    segment: Option<Segment>,
+    table_name: Option<String>,
+}
+
+impl GetPartitionsRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetPartitionsRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.GetPartitionsRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression`](struct.GetPartitionsRequest.html#structfield.expression) member.
+    pub fn expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetPartitionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetPartitionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`segment`](struct.GetPartitionsRequest.html#structfield.segment) member.
+    pub fn segment<T: Into<Segment>>(mut self, value: T) -> Self {
+        self.segment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.GetPartitionsRequest.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPartitionsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetPartitionsRequest, ::rusoto_core::BuildError> {
+        Ok(GetPartitionsRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            expression: self.expression,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            segment: self.segment,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPartitionsResponse {
@@ -2840,7 +7116,7 @@ pub struct GetPlanRequest {
     /// <p>The programming language of the code to perform the mapping.</p>
     #[serde(rename = "Language")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<String>,
+    pub language: Option<Language>,
     /// <p>The parameters for the mapping.</p>
     #[serde(rename = "Location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2857,6 +7133,70 @@ pub struct GetPlanRequest {
     pub source: CatalogEntry,
 }
 
+impl GetPlanRequest {
+    /// Returns a builder for `GetPlanRequest`, checking its required members when built.
+    pub fn builder() -> GetPlanRequestBuilder {
+        GetPlanRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPlanRequest`](struct.GetPlanRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPlanRequestBuilder {
+    language: Option<Language>,
+    location: Option<Location>,
+    mapping: Option<Vec<MappingEntry>>,
+    sinks: Option<Vec<CatalogEntry>>,
+    source: Option<CatalogEntry>,
+}
+
+impl GetPlanRequestBuilder {
+    /// Sets the [`language`](struct.GetPlanRequest.html#structfield.language) member.
+    pub fn language<T: Into<Language>>(mut self, value: T) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location`](struct.GetPlanRequest.html#structfield.location) member.
+    pub fn location<T: Into<Location>>(mut self, value: T) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mapping`](struct.GetPlanRequest.html#structfield.mapping) member.
+    pub fn mapping<T: Into<Vec<MappingEntry>>>(mut self, value: T) -> Self {
+        self.mapping = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sinks`](struct.GetPlanRequest.html#structfield.sinks) member.
+    pub fn sinks<T: Into<Vec<CatalogEntry>>>(mut self, value: T) -> Self {
+        self.sinks = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.GetPlanRequest.html#structfield.source) member.
+    pub fn source<T: Into<CatalogEntry>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPlanRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetPlanRequest, ::rusoto_core::BuildError> {
+        Ok(GetPlanRequest {
+            language: self.language,
+            location: self.location,
+            mapping: self
+                .mapping
+                .ok_or(::rusoto_core::BuildError::missing("mapping"))?,
+            sinks: self.sinks,
+            source: self
+                .source
+                .ok_or(::rusoto_core::BuildError::missing("source"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPlanResponse {
@@ -2878,8 +7218,9 @@ pub struct GetResourcePolicyRequest {}
 pub struct GetResourcePolicyResponse {
     /// <p>The date and time at which the policy was created.</p>
     #[serde(rename = "CreateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<EpochTimestamp>,
     /// <p>Contains the hash value associated with this policy.</p>
     #[serde(rename = "PolicyHash")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2890,8 +7231,9 @@ pub struct GetResourcePolicyResponse {
     pub policy_in_json: Option<String>,
     /// <p>The date and time at which the policy was last updated.</p>
     #[serde(rename = "UpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_time: Option<f64>,
+    pub update_time: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2901,6 +7243,38 @@ pub struct GetSecurityConfigurationRequest {
     pub name: String,
 }
 
+impl GetSecurityConfigurationRequest {
+    /// Returns a builder for `GetSecurityConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> GetSecurityConfigurationRequestBuilder {
+        GetSecurityConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSecurityConfigurationRequest`](struct.GetSecurityConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSecurityConfigurationRequestBuilder {
+    name: Option<String>,
+}
+
+impl GetSecurityConfigurationRequestBuilder {
+    /// Sets the [`name`](struct.GetSecurityConfigurationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSecurityConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSecurityConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(GetSecurityConfigurationRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSecurityConfigurationResponse {
@@ -2922,6 +7296,44 @@ pub struct GetSecurityConfigurationsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetSecurityConfigurationsRequest {
+    /// Returns a builder for `GetSecurityConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> GetSecurityConfigurationsRequestBuilder {
+        GetSecurityConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSecurityConfigurationsRequest`](struct.GetSecurityConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSecurityConfigurationsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetSecurityConfigurationsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetSecurityConfigurationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetSecurityConfigurationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSecurityConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSecurityConfigurationsRequest, ::rusoto_core::BuildError> {
+        Ok(GetSecurityConfigurationsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSecurityConfigurationsResponse {
@@ -2949,6 +7361,54 @@ pub struct GetTableRequest {
     pub name: String,
 }
 
+impl GetTableRequest {
+    /// Returns a builder for `GetTableRequest`, checking its required members when built.
+    pub fn builder() -> GetTableRequestBuilder {
+        GetTableRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTableRequest`](struct.GetTableRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTableRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    name: Option<String>,
+}
+
+impl GetTableRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetTableRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.GetTableRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetTableRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTableRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTableRequest, ::rusoto_core::BuildError> {
+        Ok(GetTableRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTableResponse {
@@ -2976,13 +7436,69 @@ pub struct GetTableVersionRequest {
     pub version_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetTableVersionResponse {
-    /// <p>The requested table version.</p>
-    #[serde(rename = "TableVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub table_version: Option<TableVersion>,
+impl GetTableVersionRequest {
+    /// Returns a builder for `GetTableVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetTableVersionRequestBuilder {
+        GetTableVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTableVersionRequest`](struct.GetTableVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTableVersionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    table_name: Option<String>,
+    version_id: Option<String>,
+}
+
+impl GetTableVersionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetTableVersionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.GetTableVersionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.GetTableVersionRequest.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.GetTableVersionRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTableVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTableVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetTableVersionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+            version_id: self.version_id,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetTableVersionResponse {
+    /// <p>The requested table version.</p>
+    #[serde(rename = "TableVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_version: Option<TableVersion>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -3007,6 +7523,72 @@ pub struct GetTableVersionsRequest {
     pub table_name: String,
 }
 
+impl GetTableVersionsRequest {
+    /// Returns a builder for `GetTableVersionsRequest`, checking its required members when built.
+    pub fn builder() -> GetTableVersionsRequestBuilder {
+        GetTableVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTableVersionsRequest`](struct.GetTableVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTableVersionsRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    table_name: Option<String>,
+}
+
+impl GetTableVersionsRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetTableVersionsRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.GetTableVersionsRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetTableVersionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetTableVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.GetTableVersionsRequest.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTableVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetTableVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(GetTableVersionsRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTableVersionsResponse {
@@ -3043,6 +7625,68 @@ pub struct GetTablesRequest {
     pub next_token: Option<String>,
 }
 
+impl GetTablesRequest {
+    /// Returns a builder for `GetTablesRequest`, checking its required members when built.
+    pub fn builder() -> GetTablesRequestBuilder {
+        GetTablesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTablesRequest`](struct.GetTablesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTablesRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    expression: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetTablesRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetTablesRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.GetTablesRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expression`](struct.GetTablesRequest.html#structfield.expression) member.
+    pub fn expression<T: Into<String>>(mut self, value: T) -> Self {
+        self.expression = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetTablesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetTablesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTablesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTablesRequest, ::rusoto_core::BuildError> {
+        Ok(GetTablesRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            expression: self.expression,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTablesResponse {
@@ -3063,6 +7707,36 @@ pub struct GetTagsRequest {
     pub resource_arn: String,
 }
 
+impl GetTagsRequest {
+    /// Returns a builder for `GetTagsRequest`, checking its required members when built.
+    pub fn builder() -> GetTagsRequestBuilder {
+        GetTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTagsRequest`](struct.GetTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTagsRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl GetTagsRequestBuilder {
+    /// Sets the [`resource_arn`](struct.GetTagsRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTagsRequest, ::rusoto_core::BuildError> {
+        Ok(GetTagsRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTagsResponse {
@@ -3079,6 +7753,36 @@ pub struct GetTriggerRequest {
     pub name: String,
 }
 
+impl GetTriggerRequest {
+    /// Returns a builder for `GetTriggerRequest`, checking its required members when built.
+    pub fn builder() -> GetTriggerRequestBuilder {
+        GetTriggerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTriggerRequest`](struct.GetTriggerRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTriggerRequestBuilder {
+    name: Option<String>,
+}
+
+impl GetTriggerRequestBuilder {
+    /// Sets the [`name`](struct.GetTriggerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTriggerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTriggerRequest, ::rusoto_core::BuildError> {
+        Ok(GetTriggerRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTriggerResponse {
@@ -3104,6 +7808,50 @@ pub struct GetTriggersRequest {
     pub next_token: Option<String>,
 }
 
+impl GetTriggersRequest {
+    /// Returns a builder for `GetTriggersRequest`, checking its required members when built.
+    pub fn builder() -> GetTriggersRequestBuilder {
+        GetTriggersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTriggersRequest`](struct.GetTriggersRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTriggersRequestBuilder {
+    dependent_job_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetTriggersRequestBuilder {
+    /// Sets the [`dependent_job_name`](struct.GetTriggersRequest.html#structfield.dependent_job_name) member.
+    pub fn dependent_job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dependent_job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetTriggersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetTriggersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTriggersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTriggersRequest, ::rusoto_core::BuildError> {
+        Ok(GetTriggersRequest {
+            dependent_job_name: self.dependent_job_name,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTriggersResponse {
@@ -3131,6 +7879,56 @@ pub struct GetUserDefinedFunctionRequest {
     pub function_name: String,
 }
 
+impl GetUserDefinedFunctionRequest {
+    /// Returns a builder for `GetUserDefinedFunctionRequest`, checking its required members when built.
+    pub fn builder() -> GetUserDefinedFunctionRequestBuilder {
+        GetUserDefinedFunctionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUserDefinedFunctionRequest`](struct.GetUserDefinedFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUserDefinedFunctionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    function_name: Option<String>,
+}
+
+impl GetUserDefinedFunctionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetUserDefinedFunctionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.GetUserDefinedFunctionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.GetUserDefinedFunctionRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUserDefinedFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetUserDefinedFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(GetUserDefinedFunctionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetUserDefinedFunctionResponse {
@@ -3162,6 +7960,72 @@ pub struct GetUserDefinedFunctionsRequest {
     pub pattern: String,
 }
 
+impl GetUserDefinedFunctionsRequest {
+    /// Returns a builder for `GetUserDefinedFunctionsRequest`, checking its required members when built.
+    pub fn builder() -> GetUserDefinedFunctionsRequestBuilder {
+        GetUserDefinedFunctionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUserDefinedFunctionsRequest`](struct.GetUserDefinedFunctionsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUserDefinedFunctionsRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    pattern: Option<String>,
+}
+
+impl GetUserDefinedFunctionsRequestBuilder {
+    /// Sets the [`catalog_id`](struct.GetUserDefinedFunctionsRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.GetUserDefinedFunctionsRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetUserDefinedFunctionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetUserDefinedFunctionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pattern`](struct.GetUserDefinedFunctionsRequest.html#structfield.pattern) member.
+    pub fn pattern<T: Into<String>>(mut self, value: T) -> Self {
+        self.pattern = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUserDefinedFunctionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetUserDefinedFunctionsRequest, ::rusoto_core::BuildError> {
+        Ok(GetUserDefinedFunctionsRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            pattern: self
+                .pattern
+                .ok_or(::rusoto_core::BuildError::missing("pattern"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetUserDefinedFunctionsResponse {
@@ -3186,6 +8050,44 @@ pub struct GetWorkflowRequest {
     pub name: String,
 }
 
+impl GetWorkflowRequest {
+    /// Returns a builder for `GetWorkflowRequest`, checking its required members when built.
+    pub fn builder() -> GetWorkflowRequestBuilder {
+        GetWorkflowRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetWorkflowRequest`](struct.GetWorkflowRequest.html).
+#[derive(Default, Debug)]
+pub struct GetWorkflowRequestBuilder {
+    include_graph: Option<bool>,
+    name: Option<String>,
+}
+
+impl GetWorkflowRequestBuilder {
+    /// Sets the [`include_graph`](struct.GetWorkflowRequest.html#structfield.include_graph) member.
+    pub fn include_graph<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_graph = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetWorkflowRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetWorkflowRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetWorkflowRequest, ::rusoto_core::BuildError> {
+        Ok(GetWorkflowRequest {
+            include_graph: self.include_graph,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetWorkflowResponse {
@@ -3205,6 +8107,48 @@ pub struct GetWorkflowRunPropertiesRequest {
     pub run_id: String,
 }
 
+impl GetWorkflowRunPropertiesRequest {
+    /// Returns a builder for `GetWorkflowRunPropertiesRequest`, checking its required members when built.
+    pub fn builder() -> GetWorkflowRunPropertiesRequestBuilder {
+        GetWorkflowRunPropertiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetWorkflowRunPropertiesRequest`](struct.GetWorkflowRunPropertiesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetWorkflowRunPropertiesRequestBuilder {
+    name: Option<String>,
+    run_id: Option<String>,
+}
+
+impl GetWorkflowRunPropertiesRequestBuilder {
+    /// Sets the [`name`](struct.GetWorkflowRunPropertiesRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`run_id`](struct.GetWorkflowRunPropertiesRequest.html#structfield.run_id) member.
+    pub fn run_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.run_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetWorkflowRunPropertiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetWorkflowRunPropertiesRequest, ::rusoto_core::BuildError> {
+        Ok(GetWorkflowRunPropertiesRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            run_id: self
+                .run_id
+                .ok_or(::rusoto_core::BuildError::missing("run_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetWorkflowRunPropertiesResponse {
@@ -3228,6 +8172,54 @@ pub struct GetWorkflowRunRequest {
     pub run_id: String,
 }
 
+impl GetWorkflowRunRequest {
+    /// Returns a builder for `GetWorkflowRunRequest`, checking its required members when built.
+    pub fn builder() -> GetWorkflowRunRequestBuilder {
+        GetWorkflowRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetWorkflowRunRequest`](struct.GetWorkflowRunRequest.html).
+#[derive(Default, Debug)]
+pub struct GetWorkflowRunRequestBuilder {
+    include_graph: Option<bool>,
+    name: Option<String>,
+    run_id: Option<String>,
+}
+
+impl GetWorkflowRunRequestBuilder {
+    /// Sets the [`include_graph`](struct.GetWorkflowRunRequest.html#structfield.include_graph) member.
+    pub fn include_graph<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_graph = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetWorkflowRunRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`run_id`](struct.GetWorkflowRunRequest.html#structfield.run_id) member.
+    pub fn run_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.run_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetWorkflowRunRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetWorkflowRunRequest, ::rusoto_core::BuildError> {
+        Ok(GetWorkflowRunRequest {
+            include_graph: self.include_graph,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            run_id: self
+                .run_id
+                .ok_or(::rusoto_core::BuildError::missing("run_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetWorkflowRunResponse {
@@ -3256,6 +8248,60 @@ pub struct GetWorkflowRunsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetWorkflowRunsRequest {
+    /// Returns a builder for `GetWorkflowRunsRequest`, checking its required members when built.
+    pub fn builder() -> GetWorkflowRunsRequestBuilder {
+        GetWorkflowRunsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetWorkflowRunsRequest`](struct.GetWorkflowRunsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetWorkflowRunsRequestBuilder {
+    include_graph: Option<bool>,
+    max_results: Option<i64>,
+    name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetWorkflowRunsRequestBuilder {
+    /// Sets the [`include_graph`](struct.GetWorkflowRunsRequest.html#structfield.include_graph) member.
+    pub fn include_graph<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_graph = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetWorkflowRunsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetWorkflowRunsRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetWorkflowRunsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetWorkflowRunsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetWorkflowRunsRequest, ::rusoto_core::BuildError> {
+        Ok(GetWorkflowRunsRequest {
+            include_graph: self.include_graph,
+            max_results: self.max_results,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetWorkflowRunsResponse {
@@ -3297,8 +8343,9 @@ pub struct GrokClassifier {
     pub classification: String,
     /// <p>The time that this classifier was registered.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>Optional custom grok patterns defined by this classifier. For more information, see custom patterns in <a href="http://docs.aws.amazon.com/glue/latest/dg/custom-classifier.html">Writing Custom Classifiers</a>.</p>
     #[serde(rename = "CustomPatterns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3308,8 +8355,9 @@ pub struct GrokClassifier {
     pub grok_pattern: String,
     /// <p>The time that this classifier was last updated.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<EpochTimestamp>,
     /// <p>The name of the classifier.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -3327,6 +8375,36 @@ pub struct ImportCatalogToGlueRequest {
     pub catalog_id: Option<String>,
 }
 
+impl ImportCatalogToGlueRequest {
+    /// Returns a builder for `ImportCatalogToGlueRequest`, checking its required members when built.
+    pub fn builder() -> ImportCatalogToGlueRequestBuilder {
+        ImportCatalogToGlueRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ImportCatalogToGlueRequest`](struct.ImportCatalogToGlueRequest.html).
+#[derive(Default, Debug)]
+pub struct ImportCatalogToGlueRequestBuilder {
+    catalog_id: Option<String>,
+}
+
+impl ImportCatalogToGlueRequestBuilder {
+    /// Sets the [`catalog_id`](struct.ImportCatalogToGlueRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportCatalogToGlueRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ImportCatalogToGlueRequest, ::rusoto_core::BuildError> {
+        Ok(ImportCatalogToGlueRequest {
+            catalog_id: self.catalog_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ImportCatalogToGlueResponse {}
@@ -3376,8 +8454,9 @@ pub struct Job {
     pub connections: Option<ConnectionsList>,
     /// <p>The time and date that this job definition was created.</p>
     #[serde(rename = "CreatedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_on: Option<f64>,
+    pub created_on: Option<EpochTimestamp>,
     /// <p>The default arguments for this job, specified as name-value pairs.</p> <p>You can specify arguments here that your own job-execution script consumes, as well as arguments that AWS Glue itself consumes.</p> <p>For information about how to specify and consume your own Job arguments, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-python-calling.html">Calling AWS Glue APIs in Python</a> topic in the developer guide.</p> <p>For information about the key-value pairs that AWS Glue consumes to set up your job, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-etl-glue-arguments.html">Special Parameters Used by AWS Glue</a> topic in the developer guide.</p>
     #[serde(rename = "DefaultArguments")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3396,8 +8475,9 @@ pub struct Job {
     pub glue_version: Option<String>,
     /// <p>The last point in time when this job definition was modified.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<EpochTimestamp>,
     /// <p>This field is reserved for future use.</p>
     #[serde(rename = "LogUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3437,7 +8517,7 @@ pub struct Job {
     /// <p><p>The type of predefined worker that is allocated when a job runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker maps to 1 DPU (4 vCPU, 16 GB of memory, 64 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker maps to 2 DPU (8 vCPU, 32 GB of memory, 128 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 /// <p>Defines a point that a job can resume processing.</p>
@@ -3480,13 +8560,87 @@ pub struct JobBookmarksEncryption {
     /// <p>The encryption mode to use for job bookmarks data.</p>
     #[serde(rename = "JobBookmarksEncryptionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_bookmarks_encryption_mode: Option<String>,
+    pub job_bookmarks_encryption_mode: Option<JobBookmarksEncryptionMode>,
     /// <p>The Amazon Resource Name (ARN) of the KMS key to be used to encrypt the data.</p>
     #[serde(rename = "KmsKeyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kms_key_arn: Option<String>,
 }
 
+/// <p>The encryption mode to use for job bookmarks data.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobBookmarksEncryptionMode {
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// <p><code>CSE-KMS</code></p>
+    CseKms,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobBookmarksEncryptionMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobBookmarksEncryptionMode::Disabled => "DISABLED",
+            JobBookmarksEncryptionMode::CseKms => "CSE-KMS",
+            JobBookmarksEncryptionMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobBookmarksEncryptionMode {
+    fn from(value: &'a str) -> JobBookmarksEncryptionMode {
+        match value {
+            "DISABLED" => JobBookmarksEncryptionMode::Disabled,
+            "CSE-KMS" => JobBookmarksEncryptionMode::CseKms,
+            _ => JobBookmarksEncryptionMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobBookmarksEncryptionMode {
+    fn from(value: String) -> JobBookmarksEncryptionMode {
+        JobBookmarksEncryptionMode::from(value.as_str())
+    }
+}
+
+impl From<JobBookmarksEncryptionMode> for String {
+    fn from(value: JobBookmarksEncryptionMode) -> String {
+        match value {
+            JobBookmarksEncryptionMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobBookmarksEncryptionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for JobBookmarksEncryptionMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobBookmarksEncryptionMode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<JobBookmarksEncryptionMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobBookmarksEncryptionMode::from(value))
+    }
+}
+
 /// <p>Specifies code executed when a job is run.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JobCommand {
@@ -3528,8 +8682,9 @@ pub struct JobRun {
     pub attempt: Option<i64>,
     /// <p>The date and time that this job run completed.</p>
     #[serde(rename = "CompletedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_on: Option<f64>,
+    pub completed_on: Option<EpochTimestamp>,
     /// <p>An error message associated with this job run.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3553,11 +8708,12 @@ pub struct JobRun {
     /// <p>The current state of the job run.</p>
     #[serde(rename = "JobRunState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_run_state: Option<String>,
+    pub job_run_state: Option<JobRunState>,
     /// <p>The last time that this job run was modified.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<EpochTimestamp>,
     /// <p>The name of the log group for secure logging that can be server-side encrypted in Amazon CloudWatch using AWS KMS. This name can be <code>/aws-glue/jobs/</code>, in which case the default encryption is <code>NONE</code>. If you add a role name and <code>SecurityConfiguration</code> name (in other words, <code>/aws-glue/jobs-yourRoleName-yourSecurityConfigurationName/</code>), then that security configuration is used to encrypt the log group.</p>
     #[serde(rename = "LogGroupName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3588,8 +8744,9 @@ pub struct JobRun {
     pub security_configuration: Option<String>,
     /// <p>The date and time at which this job run was started.</p>
     #[serde(rename = "StartedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_on: Option<f64>,
+    pub started_on: Option<EpochTimestamp>,
     /// <p>The <code>JobRun</code> timeout in minutes. This is the maximum time that a job run can consume resources before it is terminated and enters <code>TIMEOUT</code> status. The default is 2,880 minutes (48 hours). This overrides the timeout value set in the parent job.</p>
     #[serde(rename = "Timeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3601,28 +8758,128 @@ pub struct JobRun {
     /// <p><p>The type of predefined worker that is allocated when a job runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 64GB disk, and 1 executor per worker.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker provides 8 vCPU, 32 GB of memory and a 128GB disk, and 1 executor per worker.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
+}
+
+/// <p>The condition state. Currently, the values supported are <code>SUCCEEDED</code>, <code>STOPPED</code>, <code>TIMEOUT</code>, and <code>FAILED</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobRunState {
+    /// <p><code>STARTING</code></p>
+    Starting,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>STOPPING</code></p>
+    Stopping,
+    /// <p><code>STOPPED</code></p>
+    Stopped,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>TIMEOUT</code></p>
+    Timeout,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// <p><code>WAITING</code></p>
+    Waiting,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobRunState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobRunState::Starting => "STARTING",
+            JobRunState::Running => "RUNNING",
+            JobRunState::Stopping => "STOPPING",
+            JobRunState::Stopped => "STOPPED",
+            JobRunState::Succeeded => "SUCCEEDED",
+            JobRunState::Failed => "FAILED",
+            JobRunState::Timeout => "TIMEOUT",
+            JobRunState::Error => "ERROR",
+            JobRunState::Waiting => "WAITING",
+            JobRunState::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Specifies information used to update an existing job definition. The previous job definition is completely overwritten by this information.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct JobUpdate {
-    /// <p>The <code>JobCommand</code> that executes this job (required).</p>
-    #[serde(rename = "Command")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub command: Option<JobCommand>,
-    /// <p>The connections used for this job.</p>
-    #[serde(rename = "Connections")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub connections: Option<ConnectionsList>,
-    /// <p>The default arguments for this job.</p> <p>You can specify arguments here that your own job-execution script consumes, as well as arguments that AWS Glue itself consumes.</p> <p>For information about how to specify and consume your own Job arguments, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-python-calling.html">Calling AWS Glue APIs in Python</a> topic in the developer guide.</p> <p>For information about the key-value pairs that AWS Glue consumes to set up your job, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-etl-glue-arguments.html">Special Parameters Used by AWS Glue</a> topic in the developer guide.</p>
-    #[serde(rename = "DefaultArguments")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_arguments: Option<::std::collections::HashMap<String, String>>,
-    /// <p>Description of the job being defined.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
+impl<'a> From<&'a str> for JobRunState {
+    fn from(value: &'a str) -> JobRunState {
+        match value {
+            "STARTING" => JobRunState::Starting,
+            "RUNNING" => JobRunState::Running,
+            "STOPPING" => JobRunState::Stopping,
+            "STOPPED" => JobRunState::Stopped,
+            "SUCCEEDED" => JobRunState::Succeeded,
+            "FAILED" => JobRunState::Failed,
+            "TIMEOUT" => JobRunState::Timeout,
+            "ERROR" => JobRunState::Error,
+            "WAITING" => JobRunState::Waiting,
+            _ => JobRunState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobRunState {
+    fn from(value: String) -> JobRunState {
+        JobRunState::from(value.as_str())
+    }
+}
+
+impl From<JobRunState> for String {
+    fn from(value: JobRunState) -> String {
+        match value {
+            JobRunState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobRunState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for JobRunState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobRunState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobRunState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobRunState::from(value))
+    }
+}
+
+/// <p>Specifies information used to update an existing job definition. The previous job definition is completely overwritten by this information.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct JobUpdate {
+    /// <p>The <code>JobCommand</code> that executes this job (required).</p>
+    #[serde(rename = "Command")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<JobCommand>,
+    /// <p>The connections used for this job.</p>
+    #[serde(rename = "Connections")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connections: Option<ConnectionsList>,
+    /// <p>The default arguments for this job.</p> <p>You can specify arguments here that your own job-execution script consumes, as well as arguments that AWS Glue itself consumes.</p> <p>For information about how to specify and consume your own Job arguments, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-python-calling.html">Calling AWS Glue APIs in Python</a> topic in the developer guide.</p> <p>For information about the key-value pairs that AWS Glue consumes to set up your job, see the <a href="https://docs.aws.amazon.com/glue/latest/dg/aws-glue-programming-etl-glue-arguments.html">Special Parameters Used by AWS Glue</a> topic in the developer guide.</p>
+    #[serde(rename = "DefaultArguments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_arguments: Option<::std::collections::HashMap<String, String>>,
+    /// <p>Description of the job being defined.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /// <p>An <code>ExecutionProperty</code> specifying the maximum number of concurrent runs allowed for this job.</p>
     #[serde(rename = "ExecutionProperty")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3666,7 +8923,7 @@ pub struct JobUpdate {
     /// <p><p>The type of predefined worker that is allocated when a job runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker maps to 1 DPU (4 vCPU, 16 GB of memory, 64 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker maps to 2 DPU (8 vCPU, 32 GB of memory, 128 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 /// <p>A classifier for <code>JSON</code> content.</p>
@@ -3675,15 +8932,17 @@ pub struct JobUpdate {
 pub struct JsonClassifier {
     /// <p>The time that this classifier was registered.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>A <code>JsonPath</code> string defining the JSON data for the classifier to classify. AWS Glue supports a subset of <code>JsonPath</code>, as described in <a href="https://docs.aws.amazon.com/glue/latest/dg/custom-classifier.html#custom-classifier-json">Writing JsonPath Custom Classifiers</a>.</p>
     #[serde(rename = "JsonPath")]
     pub json_path: String,
     /// <p>The time that this classifier was last updated.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<EpochTimestamp>,
     /// <p>The name of the classifier.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -3703,6 +8962,78 @@ pub struct LabelingSetGenerationTaskRunProperties {
     pub output_s3_path: Option<String>,
 }
 
+/// <p>The programming language of the resulting code from the DAG.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// <p><code>PYTHON</code></p>
+    Python,
+    /// <p><code>SCALA</code></p>
+    Scala,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Language {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Language::Python => "PYTHON",
+            Language::Scala => "SCALA",
+            Language::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Language {
+    fn from(value: &'a str) -> Language {
+        match value {
+            "PYTHON" => Language::Python,
+            "SCALA" => Language::Scala,
+            _ => Language::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Language {
+    fn from(value: String) -> Language {
+        Language::from(value.as_str())
+    }
+}
+
+impl From<Language> for String {
+    fn from(value: Language) -> String {
+        match value {
+            Language::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Language {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Language {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Language, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Language::from(value))
+    }
+}
+
 /// <p>Status and error information about the most recent crawl.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3725,12 +9056,89 @@ pub struct LastCrawlInfo {
     pub message_prefix: Option<String>,
     /// <p>The time at which the crawl started.</p>
     #[serde(rename = "StartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>Status of the last crawl.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<LastCrawlStatus>,
+}
+
+/// <p>Status of the last crawl.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LastCrawlStatus {
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>CANCELLED</code></p>
+    Cancelled,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LastCrawlStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LastCrawlStatus::Succeeded => "SUCCEEDED",
+            LastCrawlStatus::Cancelled => "CANCELLED",
+            LastCrawlStatus::Failed => "FAILED",
+            LastCrawlStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LastCrawlStatus {
+    fn from(value: &'a str) -> LastCrawlStatus {
+        match value {
+            "SUCCEEDED" => LastCrawlStatus::Succeeded,
+            "CANCELLED" => LastCrawlStatus::Cancelled,
+            "FAILED" => LastCrawlStatus::Failed,
+            _ => LastCrawlStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LastCrawlStatus {
+    fn from(value: String) -> LastCrawlStatus {
+        LastCrawlStatus::from(value.as_str())
+    }
+}
+
+impl From<LastCrawlStatus> for String {
+    fn from(value: LastCrawlStatus) -> String {
+        match value {
+            LastCrawlStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LastCrawlStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LastCrawlStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LastCrawlStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LastCrawlStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LastCrawlStatus::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -3749,6 +9157,50 @@ pub struct ListCrawlersRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl ListCrawlersRequest {
+    /// Returns a builder for `ListCrawlersRequest`, checking its required members when built.
+    pub fn builder() -> ListCrawlersRequestBuilder {
+        ListCrawlersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCrawlersRequest`](struct.ListCrawlersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCrawlersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl ListCrawlersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListCrawlersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListCrawlersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.ListCrawlersRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCrawlersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListCrawlersRequest, ::rusoto_core::BuildError> {
+        Ok(ListCrawlersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListCrawlersResponse {
@@ -3778,6 +9230,52 @@ pub struct ListDevEndpointsRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl ListDevEndpointsRequest {
+    /// Returns a builder for `ListDevEndpointsRequest`, checking its required members when built.
+    pub fn builder() -> ListDevEndpointsRequestBuilder {
+        ListDevEndpointsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDevEndpointsRequest`](struct.ListDevEndpointsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDevEndpointsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl ListDevEndpointsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListDevEndpointsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDevEndpointsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.ListDevEndpointsRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDevEndpointsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDevEndpointsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDevEndpointsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDevEndpointsResponse {
@@ -3807,6 +9305,50 @@ pub struct ListJobsRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl ListJobsRequest {
+    /// Returns a builder for `ListJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListJobsRequestBuilder {
+        ListJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListJobsRequest`](struct.ListJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListJobsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl ListJobsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.ListJobsRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListJobsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListJobsResponse {
@@ -3840,6 +9382,58 @@ pub struct ListTriggersRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl ListTriggersRequest {
+    /// Returns a builder for `ListTriggersRequest`, checking its required members when built.
+    pub fn builder() -> ListTriggersRequestBuilder {
+        ListTriggersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTriggersRequest`](struct.ListTriggersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTriggersRequestBuilder {
+    dependent_job_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl ListTriggersRequestBuilder {
+    /// Sets the [`dependent_job_name`](struct.ListTriggersRequest.html#structfield.dependent_job_name) member.
+    pub fn dependent_job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dependent_job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListTriggersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTriggersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.ListTriggersRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTriggersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTriggersRequest, ::rusoto_core::BuildError> {
+        Ok(ListTriggersRequest {
+            dependent_job_name: self.dependent_job_name,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTriggersResponse {
@@ -3865,6 +9459,42 @@ pub struct ListWorkflowsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListWorkflowsRequest {
+    /// Returns a builder for `ListWorkflowsRequest`, checking its required members when built.
+    pub fn builder() -> ListWorkflowsRequestBuilder {
+        ListWorkflowsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListWorkflowsRequest`](struct.ListWorkflowsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListWorkflowsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListWorkflowsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListWorkflowsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListWorkflowsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListWorkflowsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListWorkflowsRequest, ::rusoto_core::BuildError> {
+        Ok(ListWorkflowsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListWorkflowsResponse {
@@ -3895,14 +9525,155 @@ pub struct Location {
     pub s3: Option<Vec<CodeGenNodeArg>>,
 }
 
+/// <p>An optional field if only one condition is listed. If multiple conditions are listed, then this field is required.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Logical {
+    /// <p><code>AND</code></p>
+    And,
+    /// <p><code>ANY</code></p>
+    Any,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Logical {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Logical::And => "AND",
+            Logical::Any => "ANY",
+            Logical::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Logical {
+    fn from(value: &'a str) -> Logical {
+        match value {
+            "AND" => Logical::And,
+            "ANY" => Logical::Any,
+            _ => Logical::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Logical {
+    fn from(value: String) -> Logical {
+        Logical::from(value.as_str())
+    }
+}
+
+impl From<Logical> for String {
+    fn from(value: Logical) -> String {
+        match value {
+            Logical::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Logical {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Logical {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Logical {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Logical, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Logical::from(value))
+    }
+}
+
+/// <p>A logical operator.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LogicalOperator {
+    /// <p><code>EQUALS</code></p>
+    Equals,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LogicalOperator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogicalOperator::Equals => "EQUALS",
+            LogicalOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LogicalOperator {
+    fn from(value: &'a str) -> LogicalOperator {
+        match value {
+            "EQUALS" => LogicalOperator::Equals,
+            _ => LogicalOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogicalOperator {
+    fn from(value: String) -> LogicalOperator {
+        LogicalOperator::from(value.as_str())
+    }
+}
+
+impl From<LogicalOperator> for String {
+    fn from(value: LogicalOperator) -> String {
+        match value {
+            LogicalOperator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LogicalOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LogicalOperator {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogicalOperator {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LogicalOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LogicalOperator::from(value))
+    }
+}
+
 /// <p>A structure for a machine learning transform.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct MLTransform {
     /// <p>A timestamp. The time and date that this machine learning transform was created.</p>
     #[serde(rename = "CreatedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_on: Option<f64>,
+    pub created_on: Option<EpochTimestamp>,
     /// <p>A user-defined, long-form description text for the machine learning transform. Descriptions are not guaranteed to be unique and can be changed at any time.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3921,8 +9692,9 @@ pub struct MLTransform {
     pub label_count: Option<i64>,
     /// <p>A timestamp. The last point in time when this machine learning transform was modified.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<EpochTimestamp>,
     /// <p>The number of AWS Glue data processing units (DPUs) that are allocated to task runs for this transform. You can allocate from 2 to 100 DPUs; the default is 10. A DPU is a relative measure of processing power that consists of 4 vCPUs of compute capacity and 16 GB of memory. For more information, see the <a href="https://aws.amazon.com/glue/pricing/">AWS Glue pricing page</a>. </p> <p>When the <code>WorkerType</code> field is set to a value other than <code>Standard</code>, the <code>MaxCapacity</code> field is set automatically and becomes read-only.</p>
     #[serde(rename = "MaxCapacity")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3954,7 +9726,7 @@ pub struct MLTransform {
     /// <p>The current status of the machine learning transform.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TransformStatusType>,
     /// <p>The timeout in minutes of the machine learning transform.</p>
     #[serde(rename = "Timeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3966,7 +9738,7 @@ pub struct MLTransform {
     /// <p><p>The type of predefined worker that is allocated when a task of this transform runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 64GB disk, and 1 executor per worker.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker provides 8 vCPU, 32 GB of memory and a 128GB disk, and 1 executor per worker.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
 }
 
 /// <p>Defines a mapping.</p>
@@ -4021,19 +9793,95 @@ pub struct Node {
     /// <p>The type of AWS Glue component represented by the node.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<NodeType>,
     /// <p>The unique Id assigned to the node within the workflow.</p>
     #[serde(rename = "UniqueId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub unique_id: Option<String>,
 }
 
-/// <p>Specifies configuration properties of a notification.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct NotificationProperty {
-    /// <p>After a job run starts, the number of minutes to wait before sending a job run delay notification.</p>
-    #[serde(rename = "NotifyDelayAfter")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+/// <p>The type of AWS Glue component represented by the node.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NodeType {
+    /// <p><code>CRAWLER</code></p>
+    Crawler,
+    /// <p><code>JOB</code></p>
+    Job,
+    /// <p><code>TRIGGER</code></p>
+    Trigger,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NodeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodeType::Crawler => "CRAWLER",
+            NodeType::Job => "JOB",
+            NodeType::Trigger => "TRIGGER",
+            NodeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NodeType {
+    fn from(value: &'a str) -> NodeType {
+        match value {
+            "CRAWLER" => NodeType::Crawler,
+            "JOB" => NodeType::Job,
+            "TRIGGER" => NodeType::Trigger,
+            _ => NodeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeType {
+    fn from(value: String) -> NodeType {
+        NodeType::from(value.as_str())
+    }
+}
+
+impl From<NodeType> for String {
+    fn from(value: NodeType) -> String {
+        match value {
+            NodeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NodeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NodeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NodeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NodeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NodeType::from(value))
+    }
+}
+
+/// <p>Specifies configuration properties of a notification.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationProperty {
+    /// <p>After a job run starts, the number of minutes to wait before sending a job run delay notification.</p>
+    #[serde(rename = "NotifyDelayAfter")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notify_delay_after: Option<i64>,
 }
 
@@ -4054,20 +9902,23 @@ pub struct Order {
 pub struct Partition {
     /// <p>The time at which the partition was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The name of the catalog database in which to create the partition.</p>
     #[serde(rename = "DatabaseName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub database_name: Option<String>,
     /// <p>The last time at which the partition was accessed.</p>
     #[serde(rename = "LastAccessTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_access_time: Option<f64>,
+    pub last_access_time: Option<EpochTimestamp>,
     /// <p>The last time at which column statistics were computed for this partition.</p>
     #[serde(rename = "LastAnalyzedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_analyzed_time: Option<f64>,
+    pub last_analyzed_time: Option<EpochTimestamp>,
     /// <p>These key-value pairs define partition parameters.</p>
     #[serde(rename = "Parameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4105,12 +9956,14 @@ pub struct PartitionError {
 pub struct PartitionInput {
     /// <p>The last time at which the partition was accessed.</p>
     #[serde(rename = "LastAccessTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_access_time: Option<f64>,
+    pub last_access_time: Option<EpochTimestamp>,
     /// <p>The last time at which column statistics were computed for this partition.</p>
     #[serde(rename = "LastAnalyzedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_analyzed_time: Option<f64>,
+    pub last_analyzed_time: Option<EpochTimestamp>,
     /// <p>These key-value pairs define partition parameters.</p>
     #[serde(rename = "Parameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4133,6 +9986,106 @@ pub struct PartitionValueList {
     pub values: Vec<String>,
 }
 
+/// <p>The permissions that are granted to the principal.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Permission {
+    /// <p><code>ALL</code></p>
+    All,
+    /// <p><code>SELECT</code></p>
+    Select,
+    /// <p><code>ALTER</code></p>
+    Alter,
+    /// <p><code>DROP</code></p>
+    Drop,
+    /// <p><code>DELETE</code></p>
+    Delete,
+    /// <p><code>INSERT</code></p>
+    Insert,
+    /// <p><code>CREATE_DATABASE</code></p>
+    CreateDatabase,
+    /// <p><code>CREATE_TABLE</code></p>
+    CreateTable,
+    /// <p><code>DATA_LOCATION_ACCESS</code></p>
+    DataLocationAccess,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Permission {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permission::All => "ALL",
+            Permission::Select => "SELECT",
+            Permission::Alter => "ALTER",
+            Permission::Drop => "DROP",
+            Permission::Delete => "DELETE",
+            Permission::Insert => "INSERT",
+            Permission::CreateDatabase => "CREATE_DATABASE",
+            Permission::CreateTable => "CREATE_TABLE",
+            Permission::DataLocationAccess => "DATA_LOCATION_ACCESS",
+            Permission::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Permission {
+    fn from(value: &'a str) -> Permission {
+        match value {
+            "ALL" => Permission::All,
+            "SELECT" => Permission::Select,
+            "ALTER" => Permission::Alter,
+            "DROP" => Permission::Drop,
+            "DELETE" => Permission::Delete,
+            "INSERT" => Permission::Insert,
+            "CREATE_DATABASE" => Permission::CreateDatabase,
+            "CREATE_TABLE" => Permission::CreateTable,
+            "DATA_LOCATION_ACCESS" => Permission::DataLocationAccess,
+            _ => Permission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Permission {
+    fn from(value: String) -> Permission {
+        Permission::from(value.as_str())
+    }
+}
+
+impl From<Permission> for String {
+    fn from(value: Permission) -> String {
+        match value {
+            Permission::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Permission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Permission::from(value))
+    }
+}
+
 /// <p>Specifies the physical requirements for a connection.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PhysicalConnectionRequirements {
@@ -4174,7 +10127,7 @@ pub struct Predicate {
     /// <p>An optional field if only one condition is listed. If multiple conditions are listed, then this field is required.</p>
     #[serde(rename = "Logical")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub logical: Option<String>,
+    pub logical: Option<Logical>,
 }
 
 /// <p>Permissions granted to a principal.</p>
@@ -4183,20 +10136,96 @@ pub struct PrincipalPermissions {
     /// <p>The permissions that are granted to the principal.</p>
     #[serde(rename = "Permissions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Vec<String>>,
+    pub permissions: Option<Vec<Permission>>,
     /// <p>The principal who is granted permissions.</p>
     #[serde(rename = "Principal")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub principal: Option<DataLakePrincipal>,
 }
 
+/// <p>The owner type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PrincipalType {
+    /// <p><code>USER</code></p>
+    User,
+    /// <p><code>ROLE</code></p>
+    Role,
+    /// <p><code>GROUP</code></p>
+    Group,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PrincipalType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PrincipalType::User => "USER",
+            PrincipalType::Role => "ROLE",
+            PrincipalType::Group => "GROUP",
+            PrincipalType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PrincipalType {
+    fn from(value: &'a str) -> PrincipalType {
+        match value {
+            "USER" => PrincipalType::User,
+            "ROLE" => PrincipalType::Role,
+            "GROUP" => PrincipalType::Group,
+            _ => PrincipalType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PrincipalType {
+    fn from(value: String) -> PrincipalType {
+        PrincipalType::from(value.as_str())
+    }
+}
+
+impl From<PrincipalType> for String {
+    fn from(value: PrincipalType) -> String {
+        match value {
+            PrincipalType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PrincipalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PrincipalType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PrincipalType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PrincipalType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PrincipalType::from(value))
+    }
+}
+
 /// <p>Defines a property predicate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PropertyPredicate {
     /// <p>The comparator used to compare this property to others.</p>
     #[serde(rename = "Comparator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparator: Option<String>,
+    pub comparator: Option<Comparator>,
     /// <p>The key of the property.</p>
     #[serde(rename = "Key")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4218,6 +10247,50 @@ pub struct PutDataCatalogEncryptionSettingsRequest {
     pub data_catalog_encryption_settings: DataCatalogEncryptionSettings,
 }
 
+impl PutDataCatalogEncryptionSettingsRequest {
+    /// Returns a builder for `PutDataCatalogEncryptionSettingsRequest`, checking its required members when built.
+    pub fn builder() -> PutDataCatalogEncryptionSettingsRequestBuilder {
+        PutDataCatalogEncryptionSettingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutDataCatalogEncryptionSettingsRequest`](struct.PutDataCatalogEncryptionSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutDataCatalogEncryptionSettingsRequestBuilder {
+    catalog_id: Option<String>,
+    data_catalog_encryption_settings: Option<DataCatalogEncryptionSettings>,
+}
+
+impl PutDataCatalogEncryptionSettingsRequestBuilder {
+    /// Sets the [`catalog_id`](struct.PutDataCatalogEncryptionSettingsRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_catalog_encryption_settings`](struct.PutDataCatalogEncryptionSettingsRequest.html#structfield.data_catalog_encryption_settings) member.
+    pub fn data_catalog_encryption_settings<T: Into<DataCatalogEncryptionSettings>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.data_catalog_encryption_settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutDataCatalogEncryptionSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutDataCatalogEncryptionSettingsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(PutDataCatalogEncryptionSettingsRequest {
+            catalog_id: self.catalog_id,
+            data_catalog_encryption_settings: self.data_catalog_encryption_settings.ok_or(
+                ::rusoto_core::BuildError::missing("data_catalog_encryption_settings"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutDataCatalogEncryptionSettingsResponse {}
@@ -4227,7 +10300,7 @@ pub struct PutResourcePolicyRequest {
     /// <p>A value of <code>MUST_EXIST</code> is used to update a policy. A value of <code>NOT_EXIST</code> is used to create a new policy. If a value of <code>NONE</code> or a null value is used, the call will not depend on the existence of a policy.</p>
     #[serde(rename = "PolicyExistsCondition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy_exists_condition: Option<String>,
+    pub policy_exists_condition: Option<ExistCondition>,
     /// <p>The hash value returned when the previous policy was set using <code>PutResourcePolicy</code>. Its purpose is to prevent concurrent modifications of a policy. Do not use this parameter if no previous policy has been set.</p>
     #[serde(rename = "PolicyHashCondition")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4237,6 +10310,54 @@ pub struct PutResourcePolicyRequest {
     pub policy_in_json: String,
 }
 
+impl PutResourcePolicyRequest {
+    /// Returns a builder for `PutResourcePolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutResourcePolicyRequestBuilder {
+        PutResourcePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutResourcePolicyRequest`](struct.PutResourcePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutResourcePolicyRequestBuilder {
+    policy_exists_condition: Option<ExistCondition>,
+    policy_hash_condition: Option<String>,
+    policy_in_json: Option<String>,
+}
+
+impl PutResourcePolicyRequestBuilder {
+    /// Sets the [`policy_exists_condition`](struct.PutResourcePolicyRequest.html#structfield.policy_exists_condition) member.
+    pub fn policy_exists_condition<T: Into<ExistCondition>>(mut self, value: T) -> Self {
+        self.policy_exists_condition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_hash_condition`](struct.PutResourcePolicyRequest.html#structfield.policy_hash_condition) member.
+    pub fn policy_hash_condition<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_hash_condition = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_in_json`](struct.PutResourcePolicyRequest.html#structfield.policy_in_json) member.
+    pub fn policy_in_json<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_in_json = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutResourcePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutResourcePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutResourcePolicyRequest {
+            policy_exists_condition: self.policy_exists_condition,
+            policy_hash_condition: self.policy_hash_condition,
+            policy_in_json: self
+                .policy_in_json
+                .ok_or(::rusoto_core::BuildError::missing("policy_in_json"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutResourcePolicyResponse {
@@ -4259,6 +10380,61 @@ pub struct PutWorkflowRunPropertiesRequest {
     pub run_properties: ::std::collections::HashMap<String, String>,
 }
 
+impl PutWorkflowRunPropertiesRequest {
+    /// Returns a builder for `PutWorkflowRunPropertiesRequest`, checking its required members when built.
+    pub fn builder() -> PutWorkflowRunPropertiesRequestBuilder {
+        PutWorkflowRunPropertiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutWorkflowRunPropertiesRequest`](struct.PutWorkflowRunPropertiesRequest.html).
+#[derive(Default, Debug)]
+pub struct PutWorkflowRunPropertiesRequestBuilder {
+    name: Option<String>,
+    run_id: Option<String>,
+    run_properties: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl PutWorkflowRunPropertiesRequestBuilder {
+    /// Sets the [`name`](struct.PutWorkflowRunPropertiesRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`run_id`](struct.PutWorkflowRunPropertiesRequest.html#structfield.run_id) member.
+    pub fn run_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.run_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`run_properties`](struct.PutWorkflowRunPropertiesRequest.html#structfield.run_properties) member.
+    pub fn run_properties<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.run_properties = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutWorkflowRunPropertiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutWorkflowRunPropertiesRequest, ::rusoto_core::BuildError> {
+        Ok(PutWorkflowRunPropertiesRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            run_id: self
+                .run_id
+                .ok_or(::rusoto_core::BuildError::missing("run_id"))?,
+            run_properties: self
+                .run_properties
+                .ok_or(::rusoto_core::BuildError::missing("run_properties"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutWorkflowRunPropertiesResponse {}
@@ -4274,6 +10450,46 @@ pub struct ResetJobBookmarkRequest {
     pub run_id: Option<String>,
 }
 
+impl ResetJobBookmarkRequest {
+    /// Returns a builder for `ResetJobBookmarkRequest`, checking its required members when built.
+    pub fn builder() -> ResetJobBookmarkRequestBuilder {
+        ResetJobBookmarkRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ResetJobBookmarkRequest`](struct.ResetJobBookmarkRequest.html).
+#[derive(Default, Debug)]
+pub struct ResetJobBookmarkRequestBuilder {
+    job_name: Option<String>,
+    run_id: Option<String>,
+}
+
+impl ResetJobBookmarkRequestBuilder {
+    /// Sets the [`job_name`](struct.ResetJobBookmarkRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`run_id`](struct.ResetJobBookmarkRequest.html#structfield.run_id) member.
+    pub fn run_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.run_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetJobBookmarkRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResetJobBookmarkRequest, ::rusoto_core::BuildError> {
+        Ok(ResetJobBookmarkRequest {
+            job_name: self
+                .job_name
+                .ok_or(::rusoto_core::BuildError::missing("job_name"))?,
+            run_id: self.run_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ResetJobBookmarkResponse {
@@ -4283,13 +10499,89 @@ pub struct ResetJobBookmarkResponse {
     pub job_bookmark_entry: Option<JobBookmarkEntry>,
 }
 
+/// <p>The type of the resource.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// <p><code>JAR</code></p>
+    Jar,
+    /// <p><code>FILE</code></p>
+    File,
+    /// <p><code>ARCHIVE</code></p>
+    Archive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Jar => "JAR",
+            ResourceType::File => "FILE",
+            ResourceType::Archive => "ARCHIVE",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "JAR" => ResourceType::Jar,
+            "FILE" => ResourceType::File,
+            "ARCHIVE" => ResourceType::Archive,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        ResourceType::from(value.as_str())
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(value: ResourceType) -> String {
+        match value {
+            ResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceType::from(value))
+    }
+}
+
 /// <p>The URIs for function resources.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceUri {
     /// <p>The type of the resource.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The URI for accessing the resource.</p>
     #[serde(rename = "Uri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4306,7 +10598,83 @@ pub struct S3Encryption {
     /// <p>The encryption mode to use for Amazon S3 data.</p>
     #[serde(rename = "S3EncryptionMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub s3_encryption_mode: Option<String>,
+    pub s3_encryption_mode: Option<S3EncryptionMode>,
+}
+
+/// <p>The encryption mode to use for Amazon S3 data.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum S3EncryptionMode {
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// <p><code>SSE-KMS</code></p>
+    SseKms,
+    /// <p><code>SSE-S3</code></p>
+    SseS3,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl S3EncryptionMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            S3EncryptionMode::Disabled => "DISABLED",
+            S3EncryptionMode::SseKms => "SSE-KMS",
+            S3EncryptionMode::SseS3 => "SSE-S3",
+            S3EncryptionMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for S3EncryptionMode {
+    fn from(value: &'a str) -> S3EncryptionMode {
+        match value {
+            "DISABLED" => S3EncryptionMode::Disabled,
+            "SSE-KMS" => S3EncryptionMode::SseKms,
+            "SSE-S3" => S3EncryptionMode::SseS3,
+            _ => S3EncryptionMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for S3EncryptionMode {
+    fn from(value: String) -> S3EncryptionMode {
+        S3EncryptionMode::from(value.as_str())
+    }
+}
+
+impl From<S3EncryptionMode> for String {
+    fn from(value: S3EncryptionMode) -> String {
+        match value {
+            S3EncryptionMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for S3EncryptionMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for S3EncryptionMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for S3EncryptionMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<S3EncryptionMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(S3EncryptionMode::from(value))
+    }
 }
 
 /// <p>Specifies a data store in Amazon Simple Storage Service (Amazon S3).</p>
@@ -4333,7 +10701,83 @@ pub struct Schedule {
     /// <p>The state of the schedule.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ScheduleState>,
+}
+
+/// <p>The state of the schedule.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScheduleState {
+    /// <p><code>SCHEDULED</code></p>
+    Scheduled,
+    /// <p><code>NOT_SCHEDULED</code></p>
+    NotScheduled,
+    /// <p><code>TRANSITIONING</code></p>
+    Transitioning,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScheduleState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScheduleState::Scheduled => "SCHEDULED",
+            ScheduleState::NotScheduled => "NOT_SCHEDULED",
+            ScheduleState::Transitioning => "TRANSITIONING",
+            ScheduleState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScheduleState {
+    fn from(value: &'a str) -> ScheduleState {
+        match value {
+            "SCHEDULED" => ScheduleState::Scheduled,
+            "NOT_SCHEDULED" => ScheduleState::NotScheduled,
+            "TRANSITIONING" => ScheduleState::Transitioning,
+            _ => ScheduleState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScheduleState {
+    fn from(value: String) -> ScheduleState {
+        ScheduleState::from(value.as_str())
+    }
+}
+
+impl From<ScheduleState> for String {
+    fn from(value: ScheduleState) -> String {
+        match value {
+            ScheduleState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScheduleState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScheduleState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScheduleState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ScheduleState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScheduleState::from(value))
+    }
 }
 
 /// <p>A policy that specifies update and deletion behaviors for the crawler.</p>
@@ -4342,11 +10786,11 @@ pub struct SchemaChangePolicy {
     /// <p>The deletion behavior when the crawler finds a deleted object.</p>
     #[serde(rename = "DeleteBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub delete_behavior: Option<String>,
+    pub delete_behavior: Option<DeleteBehavior>,
     /// <p>The update behavior when the crawler finds a changed schema.</p>
     #[serde(rename = "UpdateBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_behavior: Option<String>,
+    pub update_behavior: Option<UpdateBehavior>,
 }
 
 /// <p>A key-value pair representing a column and data type that this transform can run against. The <code>Schema</code> parameter of the <code>MLTransform</code> may contain up to 100 of these structures.</p>
@@ -4390,9 +10834,77 @@ pub struct SearchTablesRequest {
     pub sort_criteria: Option<Vec<SortCriterion>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct SearchTablesResponse {
+impl SearchTablesRequest {
+    /// Returns a builder for `SearchTablesRequest`, checking its required members when built.
+    pub fn builder() -> SearchTablesRequestBuilder {
+        SearchTablesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchTablesRequest`](struct.SearchTablesRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchTablesRequestBuilder {
+    catalog_id: Option<String>,
+    filters: Option<Vec<PropertyPredicate>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    search_text: Option<String>,
+    sort_criteria: Option<Vec<SortCriterion>>,
+}
+
+impl SearchTablesRequestBuilder {
+    /// Sets the [`catalog_id`](struct.SearchTablesRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.SearchTablesRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<PropertyPredicate>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.SearchTablesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SearchTablesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`search_text`](struct.SearchTablesRequest.html#structfield.search_text) member.
+    pub fn search_text<T: Into<String>>(mut self, value: T) -> Self {
+        self.search_text = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_criteria`](struct.SearchTablesRequest.html#structfield.sort_criteria) member.
+    pub fn sort_criteria<T: Into<Vec<SortCriterion>>>(mut self, value: T) -> Self {
+        self.sort_criteria = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchTablesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SearchTablesRequest, ::rusoto_core::BuildError> {
+        Ok(SearchTablesRequest {
+            catalog_id: self.catalog_id,
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            search_text: self.search_text,
+            sort_criteria: self.sort_criteria,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct SearchTablesResponse {
     /// <p>A continuation token, present if the current list segment is not the last.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4409,8 +10921,9 @@ pub struct SearchTablesResponse {
 pub struct SecurityConfiguration {
     /// <p>The time at which this security configuration was created.</p>
     #[serde(rename = "CreatedTimeStamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_time_stamp: Option<f64>,
+    pub created_time_stamp: Option<EpochTimestamp>,
     /// <p>The encryption configuration associated with this security configuration.</p>
     #[serde(rename = "EncryptionConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4466,6 +10979,77 @@ pub struct SkewedInfo {
     pub skewed_column_values: Option<Vec<String>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Sort {
+    /// <p><code>ASC</code></p>
+    Asc,
+    /// <p><code>DESC</code></p>
+    Desc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Sort {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Sort::Asc => "ASC",
+            Sort::Desc => "DESC",
+            Sort::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Sort {
+    fn from(value: &'a str) -> Sort {
+        match value {
+            "ASC" => Sort::Asc,
+            "DESC" => Sort::Desc,
+            _ => Sort::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Sort {
+    fn from(value: String) -> Sort {
+        Sort::from(value.as_str())
+    }
+}
+
+impl From<Sort> for String {
+    fn from(value: Sort) -> String {
+        match value {
+            Sort::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Sort {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Sort {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Sort, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Sort::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SortCriterion {
     #[serde(rename = "FieldName")]
@@ -4473,7 +11057,79 @@ pub struct SortCriterion {
     pub field_name: Option<String>,
     #[serde(rename = "Sort")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<String>,
+    pub sort: Option<Sort>,
+}
+
+/// <p>The sort direction to be used to sort the list of task runs for the machine learning transform.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortDirectionType {
+    /// <p><code>DESCENDING</code></p>
+    Descending,
+    /// <p><code>ASCENDING</code></p>
+    Ascending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SortDirectionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortDirectionType::Descending => "DESCENDING",
+            SortDirectionType::Ascending => "ASCENDING",
+            SortDirectionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SortDirectionType {
+    fn from(value: &'a str) -> SortDirectionType {
+        match value {
+            "DESCENDING" => SortDirectionType::Descending,
+            "ASCENDING" => SortDirectionType::Ascending,
+            _ => SortDirectionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortDirectionType {
+    fn from(value: String) -> SortDirectionType {
+        SortDirectionType::from(value.as_str())
+    }
+}
+
+impl From<SortDirectionType> for String {
+    fn from(value: SortDirectionType) -> String {
+        match value {
+            SortDirectionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SortDirectionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SortDirectionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortDirectionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SortDirectionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SortDirectionType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -4483,6 +11139,36 @@ pub struct StartCrawlerRequest {
     pub name: String,
 }
 
+impl StartCrawlerRequest {
+    /// Returns a builder for `StartCrawlerRequest`, checking its required members when built.
+    pub fn builder() -> StartCrawlerRequestBuilder {
+        StartCrawlerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartCrawlerRequest`](struct.StartCrawlerRequest.html).
+#[derive(Default, Debug)]
+pub struct StartCrawlerRequestBuilder {
+    name: Option<String>,
+}
+
+impl StartCrawlerRequestBuilder {
+    /// Sets the [`name`](struct.StartCrawlerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartCrawlerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartCrawlerRequest, ::rusoto_core::BuildError> {
+        Ok(StartCrawlerRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartCrawlerResponse {}
@@ -4494,6 +11180,38 @@ pub struct StartCrawlerScheduleRequest {
     pub crawler_name: String,
 }
 
+impl StartCrawlerScheduleRequest {
+    /// Returns a builder for `StartCrawlerScheduleRequest`, checking its required members when built.
+    pub fn builder() -> StartCrawlerScheduleRequestBuilder {
+        StartCrawlerScheduleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartCrawlerScheduleRequest`](struct.StartCrawlerScheduleRequest.html).
+#[derive(Default, Debug)]
+pub struct StartCrawlerScheduleRequestBuilder {
+    crawler_name: Option<String>,
+}
+
+impl StartCrawlerScheduleRequestBuilder {
+    /// Sets the [`crawler_name`](struct.StartCrawlerScheduleRequest.html#structfield.crawler_name) member.
+    pub fn crawler_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.crawler_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartCrawlerScheduleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartCrawlerScheduleRequest, ::rusoto_core::BuildError> {
+        Ok(StartCrawlerScheduleRequest {
+            crawler_name: self
+                .crawler_name
+                .ok_or(::rusoto_core::BuildError::missing("crawler_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartCrawlerScheduleResponse {}
@@ -4508,6 +11226,48 @@ pub struct StartExportLabelsTaskRunRequest {
     pub transform_id: String,
 }
 
+impl StartExportLabelsTaskRunRequest {
+    /// Returns a builder for `StartExportLabelsTaskRunRequest`, checking its required members when built.
+    pub fn builder() -> StartExportLabelsTaskRunRequestBuilder {
+        StartExportLabelsTaskRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartExportLabelsTaskRunRequest`](struct.StartExportLabelsTaskRunRequest.html).
+#[derive(Default, Debug)]
+pub struct StartExportLabelsTaskRunRequestBuilder {
+    output_s3_path: Option<String>,
+    transform_id: Option<String>,
+}
+
+impl StartExportLabelsTaskRunRequestBuilder {
+    /// Sets the [`output_s3_path`](struct.StartExportLabelsTaskRunRequest.html#structfield.output_s3_path) member.
+    pub fn output_s3_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.output_s3_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transform_id`](struct.StartExportLabelsTaskRunRequest.html#structfield.transform_id) member.
+    pub fn transform_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transform_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartExportLabelsTaskRunRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartExportLabelsTaskRunRequest, ::rusoto_core::BuildError> {
+        Ok(StartExportLabelsTaskRunRequest {
+            output_s3_path: self
+                .output_s3_path
+                .ok_or(::rusoto_core::BuildError::missing("output_s3_path"))?,
+            transform_id: self
+                .transform_id
+                .ok_or(::rusoto_core::BuildError::missing("transform_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartExportLabelsTaskRunResponse {
@@ -4531,6 +11291,56 @@ pub struct StartImportLabelsTaskRunRequest {
     pub transform_id: String,
 }
 
+impl StartImportLabelsTaskRunRequest {
+    /// Returns a builder for `StartImportLabelsTaskRunRequest`, checking its required members when built.
+    pub fn builder() -> StartImportLabelsTaskRunRequestBuilder {
+        StartImportLabelsTaskRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartImportLabelsTaskRunRequest`](struct.StartImportLabelsTaskRunRequest.html).
+#[derive(Default, Debug)]
+pub struct StartImportLabelsTaskRunRequestBuilder {
+    input_s3_path: Option<String>,
+    replace_all_labels: Option<bool>,
+    transform_id: Option<String>,
+}
+
+impl StartImportLabelsTaskRunRequestBuilder {
+    /// Sets the [`input_s3_path`](struct.StartImportLabelsTaskRunRequest.html#structfield.input_s3_path) member.
+    pub fn input_s3_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.input_s3_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replace_all_labels`](struct.StartImportLabelsTaskRunRequest.html#structfield.replace_all_labels) member.
+    pub fn replace_all_labels<T: Into<bool>>(mut self, value: T) -> Self {
+        self.replace_all_labels = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transform_id`](struct.StartImportLabelsTaskRunRequest.html#structfield.transform_id) member.
+    pub fn transform_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transform_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartImportLabelsTaskRunRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartImportLabelsTaskRunRequest, ::rusoto_core::BuildError> {
+        Ok(StartImportLabelsTaskRunRequest {
+            input_s3_path: self
+                .input_s3_path
+                .ok_or(::rusoto_core::BuildError::missing("input_s3_path"))?,
+            replace_all_labels: self.replace_all_labels,
+            transform_id: self
+                .transform_id
+                .ok_or(::rusoto_core::BuildError::missing("transform_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartImportLabelsTaskRunResponse {
@@ -4576,7 +11386,104 @@ pub struct StartJobRunRequest {
     /// <p><p>The type of predefined worker that is allocated when a job runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 64GB disk, and 1 executor per worker.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker provides 8 vCPU, 32 GB of memory and a 128GB disk, and 1 executor per worker.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
+}
+
+impl StartJobRunRequest {
+    /// Returns a builder for `StartJobRunRequest`, checking its required members when built.
+    pub fn builder() -> StartJobRunRequestBuilder {
+        StartJobRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartJobRunRequest`](struct.StartJobRunRequest.html).
+#[derive(Default, Debug)]
+pub struct StartJobRunRequestBuilder {
+    arguments: Option<::std::collections::HashMap<String, String>>,
+    job_name: Option<String>,
+    job_run_id: Option<String>,
+    max_capacity: Option<f64>,
+    notification_property: Option<NotificationProperty>,
+    number_of_workers: Option<i64>,
+    security_configuration: Option<String>,
+    timeout: Option<i64>,
+    worker_type: Option<WorkerType>,
+}
+
+impl StartJobRunRequestBuilder {
+    /// Sets the [`arguments`](struct.StartJobRunRequest.html#structfield.arguments) member.
+    pub fn arguments<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.arguments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_name`](struct.StartJobRunRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_run_id`](struct.StartJobRunRequest.html#structfield.job_run_id) member.
+    pub fn job_run_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_run_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_capacity`](struct.StartJobRunRequest.html#structfield.max_capacity) member.
+    pub fn max_capacity<T: Into<f64>>(mut self, value: T) -> Self {
+        self.max_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_property`](struct.StartJobRunRequest.html#structfield.notification_property) member.
+    pub fn notification_property<T: Into<NotificationProperty>>(mut self, value: T) -> Self {
+        self.notification_property = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_workers`](struct.StartJobRunRequest.html#structfield.number_of_workers) member.
+    pub fn number_of_workers<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_workers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_configuration`](struct.StartJobRunRequest.html#structfield.security_configuration) member.
+    pub fn security_configuration<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout`](struct.StartJobRunRequest.html#structfield.timeout) member.
+    pub fn timeout<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_type`](struct.StartJobRunRequest.html#structfield.worker_type) member.
+    pub fn worker_type<T: Into<WorkerType>>(mut self, value: T) -> Self {
+        self.worker_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartJobRunRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartJobRunRequest, ::rusoto_core::BuildError> {
+        Ok(StartJobRunRequest {
+            arguments: self.arguments,
+            job_name: self
+                .job_name
+                .ok_or(::rusoto_core::BuildError::missing("job_name"))?,
+            job_run_id: self.job_run_id,
+            max_capacity: self.max_capacity,
+            notification_property: self.notification_property,
+            number_of_workers: self.number_of_workers,
+            security_configuration: self.security_configuration,
+            timeout: self.timeout,
+            worker_type: self.worker_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -4595,6 +11502,38 @@ pub struct StartMLEvaluationTaskRunRequest {
     pub transform_id: String,
 }
 
+impl StartMLEvaluationTaskRunRequest {
+    /// Returns a builder for `StartMLEvaluationTaskRunRequest`, checking its required members when built.
+    pub fn builder() -> StartMLEvaluationTaskRunRequestBuilder {
+        StartMLEvaluationTaskRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartMLEvaluationTaskRunRequest`](struct.StartMLEvaluationTaskRunRequest.html).
+#[derive(Default, Debug)]
+pub struct StartMLEvaluationTaskRunRequestBuilder {
+    transform_id: Option<String>,
+}
+
+impl StartMLEvaluationTaskRunRequestBuilder {
+    /// Sets the [`transform_id`](struct.StartMLEvaluationTaskRunRequest.html#structfield.transform_id) member.
+    pub fn transform_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transform_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartMLEvaluationTaskRunRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartMLEvaluationTaskRunRequest, ::rusoto_core::BuildError> {
+        Ok(StartMLEvaluationTaskRunRequest {
+            transform_id: self
+                .transform_id
+                .ok_or(::rusoto_core::BuildError::missing("transform_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartMLEvaluationTaskRunResponse {
@@ -4614,6 +11553,49 @@ pub struct StartMLLabelingSetGenerationTaskRunRequest {
     pub transform_id: String,
 }
 
+impl StartMLLabelingSetGenerationTaskRunRequest {
+    /// Returns a builder for `StartMLLabelingSetGenerationTaskRunRequest`, checking its required members when built.
+    pub fn builder() -> StartMLLabelingSetGenerationTaskRunRequestBuilder {
+        StartMLLabelingSetGenerationTaskRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartMLLabelingSetGenerationTaskRunRequest`](struct.StartMLLabelingSetGenerationTaskRunRequest.html).
+#[derive(Default, Debug)]
+pub struct StartMLLabelingSetGenerationTaskRunRequestBuilder {
+    output_s3_path: Option<String>,
+    transform_id: Option<String>,
+}
+
+impl StartMLLabelingSetGenerationTaskRunRequestBuilder {
+    /// Sets the [`output_s3_path`](struct.StartMLLabelingSetGenerationTaskRunRequest.html#structfield.output_s3_path) member.
+    pub fn output_s3_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.output_s3_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transform_id`](struct.StartMLLabelingSetGenerationTaskRunRequest.html#structfield.transform_id) member.
+    pub fn transform_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transform_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartMLLabelingSetGenerationTaskRunRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartMLLabelingSetGenerationTaskRunRequest, ::rusoto_core::BuildError>
+    {
+        Ok(StartMLLabelingSetGenerationTaskRunRequest {
+            output_s3_path: self
+                .output_s3_path
+                .ok_or(::rusoto_core::BuildError::missing("output_s3_path"))?,
+            transform_id: self
+                .transform_id
+                .ok_or(::rusoto_core::BuildError::missing("transform_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartMLLabelingSetGenerationTaskRunResponse {
@@ -4630,6 +11612,36 @@ pub struct StartTriggerRequest {
     pub name: String,
 }
 
+impl StartTriggerRequest {
+    /// Returns a builder for `StartTriggerRequest`, checking its required members when built.
+    pub fn builder() -> StartTriggerRequestBuilder {
+        StartTriggerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartTriggerRequest`](struct.StartTriggerRequest.html).
+#[derive(Default, Debug)]
+pub struct StartTriggerRequestBuilder {
+    name: Option<String>,
+}
+
+impl StartTriggerRequestBuilder {
+    /// Sets the [`name`](struct.StartTriggerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartTriggerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartTriggerRequest, ::rusoto_core::BuildError> {
+        Ok(StartTriggerRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartTriggerResponse {
@@ -4646,6 +11658,38 @@ pub struct StartWorkflowRunRequest {
     pub name: String,
 }
 
+impl StartWorkflowRunRequest {
+    /// Returns a builder for `StartWorkflowRunRequest`, checking its required members when built.
+    pub fn builder() -> StartWorkflowRunRequestBuilder {
+        StartWorkflowRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartWorkflowRunRequest`](struct.StartWorkflowRunRequest.html).
+#[derive(Default, Debug)]
+pub struct StartWorkflowRunRequestBuilder {
+    name: Option<String>,
+}
+
+impl StartWorkflowRunRequestBuilder {
+    /// Sets the [`name`](struct.StartWorkflowRunRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartWorkflowRunRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartWorkflowRunRequest, ::rusoto_core::BuildError> {
+        Ok(StartWorkflowRunRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartWorkflowRunResponse {
@@ -4662,6 +11706,36 @@ pub struct StopCrawlerRequest {
     pub name: String,
 }
 
+impl StopCrawlerRequest {
+    /// Returns a builder for `StopCrawlerRequest`, checking its required members when built.
+    pub fn builder() -> StopCrawlerRequestBuilder {
+        StopCrawlerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopCrawlerRequest`](struct.StopCrawlerRequest.html).
+#[derive(Default, Debug)]
+pub struct StopCrawlerRequestBuilder {
+    name: Option<String>,
+}
+
+impl StopCrawlerRequestBuilder {
+    /// Sets the [`name`](struct.StopCrawlerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopCrawlerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopCrawlerRequest, ::rusoto_core::BuildError> {
+        Ok(StopCrawlerRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopCrawlerResponse {}
@@ -4673,6 +11747,38 @@ pub struct StopCrawlerScheduleRequest {
     pub crawler_name: String,
 }
 
+impl StopCrawlerScheduleRequest {
+    /// Returns a builder for `StopCrawlerScheduleRequest`, checking its required members when built.
+    pub fn builder() -> StopCrawlerScheduleRequestBuilder {
+        StopCrawlerScheduleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopCrawlerScheduleRequest`](struct.StopCrawlerScheduleRequest.html).
+#[derive(Default, Debug)]
+pub struct StopCrawlerScheduleRequestBuilder {
+    crawler_name: Option<String>,
+}
+
+impl StopCrawlerScheduleRequestBuilder {
+    /// Sets the [`crawler_name`](struct.StopCrawlerScheduleRequest.html#structfield.crawler_name) member.
+    pub fn crawler_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.crawler_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopCrawlerScheduleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopCrawlerScheduleRequest, ::rusoto_core::BuildError> {
+        Ok(StopCrawlerScheduleRequest {
+            crawler_name: self
+                .crawler_name
+                .ok_or(::rusoto_core::BuildError::missing("crawler_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopCrawlerScheduleResponse {}
@@ -4684,6 +11790,36 @@ pub struct StopTriggerRequest {
     pub name: String,
 }
 
+impl StopTriggerRequest {
+    /// Returns a builder for `StopTriggerRequest`, checking its required members when built.
+    pub fn builder() -> StopTriggerRequestBuilder {
+        StopTriggerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopTriggerRequest`](struct.StopTriggerRequest.html).
+#[derive(Default, Debug)]
+pub struct StopTriggerRequestBuilder {
+    name: Option<String>,
+}
+
+impl StopTriggerRequestBuilder {
+    /// Sets the [`name`](struct.StopTriggerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopTriggerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopTriggerRequest, ::rusoto_core::BuildError> {
+        Ok(StopTriggerRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopTriggerResponse {
@@ -4752,8 +11888,9 @@ pub struct StorageDescriptor {
 pub struct Table {
     /// <p>The time when the table definition was created in the Data Catalog.</p>
     #[serde(rename = "CreateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<EpochTimestamp>,
     /// <p>The person or entity who created the table.</p>
     #[serde(rename = "CreatedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4772,12 +11909,14 @@ pub struct Table {
     pub is_registered_with_lake_formation: Option<bool>,
     /// <p>The last time that the table was accessed. This is usually taken from HDFS, and might not be reliable.</p>
     #[serde(rename = "LastAccessTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_access_time: Option<f64>,
+    pub last_access_time: Option<EpochTimestamp>,
     /// <p>The last time that column statistics were computed for this table.</p>
     #[serde(rename = "LastAnalyzedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_analyzed_time: Option<f64>,
+    pub last_analyzed_time: Option<EpochTimestamp>,
     /// <p>The table name. For Hive compatibility, this must be entirely lowercase.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -4807,8 +11946,9 @@ pub struct Table {
     pub table_type: Option<String>,
     /// <p>The last time that the table was updated.</p>
     #[serde(rename = "UpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_time: Option<f64>,
+    pub update_time: Option<EpochTimestamp>,
     /// <p>If the table is a view, the expanded text of the view; otherwise <code>null</code>.</p>
     #[serde(rename = "ViewExpandedText")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4842,12 +11982,14 @@ pub struct TableInput {
     pub description: Option<String>,
     /// <p>The last time that the table was accessed.</p>
     #[serde(rename = "LastAccessTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_access_time: Option<f64>,
+    pub last_access_time: Option<EpochTimestamp>,
     /// <p>The last time that column statistics were computed for this table.</p>
     #[serde(rename = "LastAnalyzedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_analyzed_time: Option<f64>,
+    pub last_analyzed_time: Option<EpochTimestamp>,
     /// <p>The table name. For Hive compatibility, this is folded to lowercase when it is stored.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -4927,18 +12069,62 @@ pub struct TagResourceRequest {
     pub tags_to_add: ::std::collections::HashMap<String, String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct TagResourceResponse {}
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
 
-/// <p>The sampling parameters that are associated with the machine learning transform.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct TaskRun {
-    /// <p>The last point in time that the requested task run was completed.</p>
-    #[serde(rename = "CompletedOn")]
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags_to_add: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags_to_add`](struct.TagResourceRequest.html#structfield.tags_to_add) member.
+    pub fn tags_to_add<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.tags_to_add = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags_to_add: self
+                .tags_to_add
+                .ok_or(::rusoto_core::BuildError::missing("tags_to_add"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct TagResourceResponse {}
+
+/// <p>The sampling parameters that are associated with the machine learning transform.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct TaskRun {
+    /// <p>The last point in time that the requested task run was completed.</p>
+    #[serde(rename = "CompletedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_on: Option<f64>,
+    pub completed_on: Option<EpochTimestamp>,
     /// <p>The list of error strings associated with this task run.</p>
     #[serde(rename = "ErrorString")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4949,8 +12135,9 @@ pub struct TaskRun {
     pub execution_time: Option<i64>,
     /// <p>The last point in time that the requested task run was updated.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<EpochTimestamp>,
     /// <p>The names of the log group for secure logging, associated with this task run.</p>
     #[serde(rename = "LogGroupName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4961,12 +12148,13 @@ pub struct TaskRun {
     pub properties: Option<TaskRunProperties>,
     /// <p>The date and time that this task run started.</p>
     #[serde(rename = "StartedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_on: Option<f64>,
+    pub started_on: Option<EpochTimestamp>,
     /// <p>The current status of the requested task run.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskStatusType>,
     /// <p>The unique identifier for this task run.</p>
     #[serde(rename = "TaskRunId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4982,20 +12170,22 @@ pub struct TaskRun {
 pub struct TaskRunFilterCriteria {
     /// <p>Filter on task runs started after this date.</p>
     #[serde(rename = "StartedAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_after: Option<f64>,
+    pub started_after: Option<EpochTimestamp>,
     /// <p>Filter on task runs started before this date.</p>
     #[serde(rename = "StartedBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_before: Option<f64>,
+    pub started_before: Option<EpochTimestamp>,
     /// <p>The current status of the task run.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskStatusType>,
     /// <p>The type of task run.</p>
     #[serde(rename = "TaskRunType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_run_type: Option<String>,
+    pub task_run_type: Option<TaskType>,
 }
 
 /// <p>The configuration properties for the task run.</p>
@@ -5021,18 +12211,270 @@ pub struct TaskRunProperties {
     /// <p>The type of task run.</p>
     #[serde(rename = "TaskType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_type: Option<String>,
+    pub task_type: Option<TaskType>,
+}
+
+/// <p>The column to be used to sort the list of task runs for the machine learning transform.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaskRunSortColumnType {
+    /// <p><code>TASK_RUN_TYPE</code></p>
+    TaskRunType,
+    /// <p><code>STATUS</code></p>
+    Status,
+    /// <p><code>STARTED</code></p>
+    Started,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TaskRunSortColumnType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskRunSortColumnType::TaskRunType => "TASK_RUN_TYPE",
+            TaskRunSortColumnType::Status => "STATUS",
+            TaskRunSortColumnType::Started => "STARTED",
+            TaskRunSortColumnType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaskRunSortColumnType {
+    fn from(value: &'a str) -> TaskRunSortColumnType {
+        match value {
+            "TASK_RUN_TYPE" => TaskRunSortColumnType::TaskRunType,
+            "STATUS" => TaskRunSortColumnType::Status,
+            "STARTED" => TaskRunSortColumnType::Started,
+            _ => TaskRunSortColumnType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskRunSortColumnType {
+    fn from(value: String) -> TaskRunSortColumnType {
+        TaskRunSortColumnType::from(value.as_str())
+    }
+}
+
+impl From<TaskRunSortColumnType> for String {
+    fn from(value: TaskRunSortColumnType) -> String {
+        match value {
+            TaskRunSortColumnType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TaskRunSortColumnType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TaskRunSortColumnType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskRunSortColumnType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TaskRunSortColumnType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TaskRunSortColumnType::from(value))
+    }
 }
 
 /// <p>The sorting criteria that are used to sort the list of task runs for the machine learning transform.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct TaskRunSortCriteria {
     /// <p>The column to be used to sort the list of task runs for the machine learning transform.</p>
     #[serde(rename = "Column")]
-    pub column: String,
+    pub column: TaskRunSortColumnType,
     /// <p>The sort direction to be used to sort the list of task runs for the machine learning transform.</p>
     #[serde(rename = "SortDirection")]
-    pub sort_direction: String,
+    pub sort_direction: SortDirectionType,
+}
+
+/// <p>The status for this run.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaskStatusType {
+    /// <p><code>STARTING</code></p>
+    Starting,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>STOPPING</code></p>
+    Stopping,
+    /// <p><code>STOPPED</code></p>
+    Stopped,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>TIMEOUT</code></p>
+    Timeout,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TaskStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskStatusType::Starting => "STARTING",
+            TaskStatusType::Running => "RUNNING",
+            TaskStatusType::Stopping => "STOPPING",
+            TaskStatusType::Stopped => "STOPPED",
+            TaskStatusType::Succeeded => "SUCCEEDED",
+            TaskStatusType::Failed => "FAILED",
+            TaskStatusType::Timeout => "TIMEOUT",
+            TaskStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaskStatusType {
+    fn from(value: &'a str) -> TaskStatusType {
+        match value {
+            "STARTING" => TaskStatusType::Starting,
+            "RUNNING" => TaskStatusType::Running,
+            "STOPPING" => TaskStatusType::Stopping,
+            "STOPPED" => TaskStatusType::Stopped,
+            "SUCCEEDED" => TaskStatusType::Succeeded,
+            "FAILED" => TaskStatusType::Failed,
+            "TIMEOUT" => TaskStatusType::Timeout,
+            _ => TaskStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskStatusType {
+    fn from(value: String) -> TaskStatusType {
+        TaskStatusType::from(value.as_str())
+    }
+}
+
+impl From<TaskStatusType> for String {
+    fn from(value: TaskStatusType) -> String {
+        match value {
+            TaskStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TaskStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TaskStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskStatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TaskStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TaskStatusType::from(value))
+    }
+}
+
+/// <p>The type of task run.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaskType {
+    /// <p><code>EVALUATION</code></p>
+    Evaluation,
+    /// <p><code>LABELING_SET_GENERATION</code></p>
+    LabelingSetGeneration,
+    /// <p><code>IMPORT_LABELS</code></p>
+    ImportLabels,
+    /// <p><code>EXPORT_LABELS</code></p>
+    ExportLabels,
+    /// <p><code>FIND_MATCHES</code></p>
+    FindMatches,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TaskType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskType::Evaluation => "EVALUATION",
+            TaskType::LabelingSetGeneration => "LABELING_SET_GENERATION",
+            TaskType::ImportLabels => "IMPORT_LABELS",
+            TaskType::ExportLabels => "EXPORT_LABELS",
+            TaskType::FindMatches => "FIND_MATCHES",
+            TaskType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaskType {
+    fn from(value: &'a str) -> TaskType {
+        match value {
+            "EVALUATION" => TaskType::Evaluation,
+            "LABELING_SET_GENERATION" => TaskType::LabelingSetGeneration,
+            "IMPORT_LABELS" => TaskType::ImportLabels,
+            "EXPORT_LABELS" => TaskType::ExportLabels,
+            "FIND_MATCHES" => TaskType::FindMatches,
+            _ => TaskType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskType {
+    fn from(value: String) -> TaskType {
+        TaskType::from(value.as_str())
+    }
+}
+
+impl From<TaskType> for String {
+    fn from(value: TaskType) -> String {
+        match value {
+            TaskType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TaskType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TaskType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TaskType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TaskType::from(value))
+    }
 }
 
 /// <p>The criteria used to filter the machine learning transforms.</p>
@@ -5040,20 +12482,24 @@ pub struct TaskRunSortCriteria {
 pub struct TransformFilterCriteria {
     /// <p>The time and date after which the transforms were created.</p>
     #[serde(rename = "CreatedAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_after: Option<f64>,
+    pub created_after: Option<EpochTimestamp>,
     /// <p>The time and date before which the transforms were created.</p>
     #[serde(rename = "CreatedBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_before: Option<f64>,
+    pub created_before: Option<EpochTimestamp>,
     /// <p>Filter on transforms last modified after this date.</p>
     #[serde(rename = "LastModifiedAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_after: Option<f64>,
+    pub last_modified_after: Option<EpochTimestamp>,
     /// <p>Filter on transforms last modified before this date.</p>
     #[serde(rename = "LastModifiedBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_before: Option<f64>,
+    pub last_modified_before: Option<EpochTimestamp>,
     /// <p>A unique transform name that is used to filter the machine learning transforms.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5065,15 +12511,15 @@ pub struct TransformFilterCriteria {
     /// <p>Filters the list of machine learning transforms by the last known status of the transforms (to indicate whether a transform can be used or not). One of "NOT_READY", "READY", or "DELETING".</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TransformStatusType>,
     /// <p>The type of machine learning transform that is used to filter the machine learning transforms.</p>
     #[serde(rename = "TransformType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transform_type: Option<String>,
+    pub transform_type: Option<TransformType>,
 }
 
 /// <p>The algorithm-specific parameters that are associated with the machine learning transform.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransformParameters {
     /// <p>The parameters for the find matches algorithm.</p>
     #[serde(rename = "FindMatchesParameters")]
@@ -5081,18 +12527,246 @@ pub struct TransformParameters {
     pub find_matches_parameters: Option<FindMatchesParameters>,
     /// <p>The type of machine learning transform.</p> <p>For information about the types of machine learning transforms, see <a href="http://docs.aws.amazon.com/glue/latest/dg/add-job-machine-learning-transform.html">Creating Machine Learning Transforms</a>.</p>
     #[serde(rename = "TransformType")]
-    pub transform_type: String,
+    pub transform_type: TransformType,
+}
+
+/// <p>The column to be used in the sorting criteria that are associated with the machine learning transform.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TransformSortColumnType {
+    /// <p><code>NAME</code></p>
+    Name,
+    /// <p><code>TRANSFORM_TYPE</code></p>
+    TransformType,
+    /// <p><code>STATUS</code></p>
+    Status,
+    /// <p><code>CREATED</code></p>
+    Created,
+    /// <p><code>LAST_MODIFIED</code></p>
+    LastModified,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TransformSortColumnType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TransformSortColumnType::Name => "NAME",
+            TransformSortColumnType::TransformType => "TRANSFORM_TYPE",
+            TransformSortColumnType::Status => "STATUS",
+            TransformSortColumnType::Created => "CREATED",
+            TransformSortColumnType::LastModified => "LAST_MODIFIED",
+            TransformSortColumnType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TransformSortColumnType {
+    fn from(value: &'a str) -> TransformSortColumnType {
+        match value {
+            "NAME" => TransformSortColumnType::Name,
+            "TRANSFORM_TYPE" => TransformSortColumnType::TransformType,
+            "STATUS" => TransformSortColumnType::Status,
+            "CREATED" => TransformSortColumnType::Created,
+            "LAST_MODIFIED" => TransformSortColumnType::LastModified,
+            _ => TransformSortColumnType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TransformSortColumnType {
+    fn from(value: String) -> TransformSortColumnType {
+        TransformSortColumnType::from(value.as_str())
+    }
+}
+
+impl From<TransformSortColumnType> for String {
+    fn from(value: TransformSortColumnType) -> String {
+        match value {
+            TransformSortColumnType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TransformSortColumnType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TransformSortColumnType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TransformSortColumnType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TransformSortColumnType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TransformSortColumnType::from(value))
+    }
 }
 
 /// <p>The sorting criteria that are associated with the machine learning transform.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct TransformSortCriteria {
     /// <p>The column to be used in the sorting criteria that are associated with the machine learning transform.</p>
     #[serde(rename = "Column")]
-    pub column: String,
+    pub column: TransformSortColumnType,
     /// <p>The sort direction to be used in the sorting criteria that are associated with the machine learning transform.</p>
     #[serde(rename = "SortDirection")]
-    pub sort_direction: String,
+    pub sort_direction: SortDirectionType,
+}
+
+/// <p>The last known status of the transform (to indicate whether it can be used or not). One of "NOT_READY", "READY", or "DELETING".</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TransformStatusType {
+    /// <p><code>NOT_READY</code></p>
+    NotReady,
+    /// <p><code>READY</code></p>
+    Ready,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TransformStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TransformStatusType::NotReady => "NOT_READY",
+            TransformStatusType::Ready => "READY",
+            TransformStatusType::Deleting => "DELETING",
+            TransformStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TransformStatusType {
+    fn from(value: &'a str) -> TransformStatusType {
+        match value {
+            "NOT_READY" => TransformStatusType::NotReady,
+            "READY" => TransformStatusType::Ready,
+            "DELETING" => TransformStatusType::Deleting,
+            _ => TransformStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TransformStatusType {
+    fn from(value: String) -> TransformStatusType {
+        TransformStatusType::from(value.as_str())
+    }
+}
+
+impl From<TransformStatusType> for String {
+    fn from(value: TransformStatusType) -> String {
+        match value {
+            TransformStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TransformStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TransformStatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TransformStatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TransformStatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TransformStatusType::from(value))
+    }
+}
+
+/// <p>The type of machine learning transform.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TransformType {
+    /// <p><code>FIND_MATCHES</code></p>
+    FindMatches,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TransformType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TransformType::FindMatches => "FIND_MATCHES",
+            TransformType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TransformType {
+    fn from(value: &'a str) -> TransformType {
+        match value {
+            "FIND_MATCHES" => TransformType::FindMatches,
+            _ => TransformType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TransformType {
+    fn from(value: String) -> TransformType {
+        TransformType::from(value.as_str())
+    }
+}
+
+impl From<TransformType> for String {
+    fn from(value: TransformType) -> String {
+        match value {
+            TransformType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TransformType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TransformType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TransformType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TransformType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TransformType::from(value))
+    }
 }
 
 /// <p>Information about a specific trigger.</p>
@@ -5126,11 +12800,11 @@ pub struct Trigger {
     /// <p>The current state of the trigger.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<TriggerState>,
     /// <p>The type of trigger that this is.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TriggerType>,
     /// <p>The name of the workflow associated with the trigger.</p>
     #[serde(rename = "WorkflowName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5147,52 +12821,340 @@ pub struct TriggerNodeDetails {
     pub trigger: Option<Trigger>,
 }
 
-/// <p>A structure used to provide information used to update a trigger. This object updates the previous trigger definition by overwriting it completely.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct TriggerUpdate {
-    /// <p>The actions initiated by this trigger.</p>
-    #[serde(rename = "Actions")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub actions: Option<Vec<Action>>,
-    /// <p>A description of this trigger.</p>
-    #[serde(rename = "Description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>Reserved for future use.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The predicate of this trigger, which defines when it will fire.</p>
-    #[serde(rename = "Predicate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub predicate: Option<Predicate>,
-    /// <p>A <code>cron</code> expression used to specify the schedule (see <a href="https://docs.aws.amazon.com/glue/latest/dg/monitor-data-warehouse-schedule.html">Time-Based Schedules for Jobs and Crawlers</a>. For example, to run something every day at 12:15 UTC, you would specify: <code>cron(15 12 * * ? *)</code>.</p>
-    #[serde(rename = "Schedule")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub schedule: Option<String>,
+/// <p>The current state of the trigger.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TriggerState {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>CREATED</code></p>
+    Created,
+    /// <p><code>ACTIVATING</code></p>
+    Activating,
+    /// <p><code>ACTIVATED</code></p>
+    Activated,
+    /// <p><code>DEACTIVATING</code></p>
+    Deactivating,
+    /// <p><code>DEACTIVATED</code></p>
+    Deactivated,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TriggerState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TriggerState::Creating => "CREATING",
+            TriggerState::Created => "CREATED",
+            TriggerState::Activating => "ACTIVATING",
+            TriggerState::Activated => "ACTIVATED",
+            TriggerState::Deactivating => "DEACTIVATING",
+            TriggerState::Deactivated => "DEACTIVATED",
+            TriggerState::Deleting => "DELETING",
+            TriggerState::Updating => "UPDATING",
+            TriggerState::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UntagResourceRequest {
-    /// <p>The Amazon Resource Name (ARN) of the resource from which to remove the tags.</p>
-    #[serde(rename = "ResourceArn")]
-    pub resource_arn: String,
-    /// <p>Tags to remove from this resource.</p>
-    #[serde(rename = "TagsToRemove")]
-    pub tags_to_remove: Vec<String>,
+impl<'a> From<&'a str> for TriggerState {
+    fn from(value: &'a str) -> TriggerState {
+        match value {
+            "CREATING" => TriggerState::Creating,
+            "CREATED" => TriggerState::Created,
+            "ACTIVATING" => TriggerState::Activating,
+            "ACTIVATED" => TriggerState::Activated,
+            "DEACTIVATING" => TriggerState::Deactivating,
+            "DEACTIVATED" => TriggerState::Deactivated,
+            "DELETING" => TriggerState::Deleting,
+            "UPDATING" => TriggerState::Updating,
+            _ => TriggerState::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UntagResourceResponse {}
+impl From<String> for TriggerState {
+    fn from(value: String) -> TriggerState {
+        TriggerState::from(value.as_str())
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateClassifierRequest {
-    /// <p>A <code>CsvClassifier</code> object with updated fields.</p>
-    #[serde(rename = "CsvClassifier")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub csv_classifier: Option<UpdateCsvClassifierRequest>,
-    /// <p>A <code>GrokClassifier</code> object with updated fields.</p>
+impl From<TriggerState> for String {
+    fn from(value: TriggerState) -> String {
+        match value {
+            TriggerState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TriggerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TriggerState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TriggerState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TriggerState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TriggerState::from(value))
+    }
+}
+
+/// <p>The type of the new trigger.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TriggerType {
+    /// <p><code>SCHEDULED</code></p>
+    Scheduled,
+    /// <p><code>CONDITIONAL</code></p>
+    Conditional,
+    /// <p><code>ON_DEMAND</code></p>
+    OnDemand,
+    /// <p><code>EVENT</code></p>
+    Event,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TriggerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TriggerType::Scheduled => "SCHEDULED",
+            TriggerType::Conditional => "CONDITIONAL",
+            TriggerType::OnDemand => "ON_DEMAND",
+            TriggerType::Event => "EVENT",
+            TriggerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TriggerType {
+    fn from(value: &'a str) -> TriggerType {
+        match value {
+            "SCHEDULED" => TriggerType::Scheduled,
+            "CONDITIONAL" => TriggerType::Conditional,
+            "ON_DEMAND" => TriggerType::OnDemand,
+            "EVENT" => TriggerType::Event,
+            _ => TriggerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TriggerType {
+    fn from(value: String) -> TriggerType {
+        TriggerType::from(value.as_str())
+    }
+}
+
+impl From<TriggerType> for String {
+    fn from(value: TriggerType) -> String {
+        match value {
+            TriggerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TriggerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TriggerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TriggerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TriggerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TriggerType::from(value))
+    }
+}
+
+/// <p>A structure used to provide information used to update a trigger. This object updates the previous trigger definition by overwriting it completely.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct TriggerUpdate {
+    /// <p>The actions initiated by this trigger.</p>
+    #[serde(rename = "Actions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<Action>>,
+    /// <p>A description of this trigger.</p>
+    #[serde(rename = "Description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>Reserved for future use.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The predicate of this trigger, which defines when it will fire.</p>
+    #[serde(rename = "Predicate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<Predicate>,
+    /// <p>A <code>cron</code> expression used to specify the schedule (see <a href="https://docs.aws.amazon.com/glue/latest/dg/monitor-data-warehouse-schedule.html">Time-Based Schedules for Jobs and Crawlers</a>. For example, to run something every day at 12:15 UTC, you would specify: <code>cron(15 12 * * ? *)</code>.</p>
+    #[serde(rename = "Schedule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UntagResourceRequest {
+    /// <p>The Amazon Resource Name (ARN) of the resource from which to remove the tags.</p>
+    #[serde(rename = "ResourceArn")]
+    pub resource_arn: String,
+    /// <p>Tags to remove from this resource.</p>
+    #[serde(rename = "TagsToRemove")]
+    pub tags_to_remove: Vec<String>,
+}
+
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags_to_remove: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags_to_remove`](struct.UntagResourceRequest.html#structfield.tags_to_remove) member.
+    pub fn tags_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tags_to_remove = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags_to_remove: self
+                .tags_to_remove
+                .ok_or(::rusoto_core::BuildError::missing("tags_to_remove"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UntagResourceResponse {}
+
+/// <p>The update behavior when the crawler finds a changed schema.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateBehavior {
+    /// <p><code>LOG</code></p>
+    Log,
+    /// <p><code>UPDATE_IN_DATABASE</code></p>
+    UpdateInDatabase,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UpdateBehavior {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateBehavior::Log => "LOG",
+            UpdateBehavior::UpdateInDatabase => "UPDATE_IN_DATABASE",
+            UpdateBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UpdateBehavior {
+    fn from(value: &'a str) -> UpdateBehavior {
+        match value {
+            "LOG" => UpdateBehavior::Log,
+            "UPDATE_IN_DATABASE" => UpdateBehavior::UpdateInDatabase,
+            _ => UpdateBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateBehavior {
+    fn from(value: String) -> UpdateBehavior {
+        UpdateBehavior::from(value.as_str())
+    }
+}
+
+impl From<UpdateBehavior> for String {
+    fn from(value: UpdateBehavior) -> String {
+        match value {
+            UpdateBehavior::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UpdateBehavior {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateBehavior {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UpdateBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UpdateBehavior::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateClassifierRequest {
+    /// <p>A <code>CsvClassifier</code> object with updated fields.</p>
+    #[serde(rename = "CsvClassifier")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csv_classifier: Option<UpdateCsvClassifierRequest>,
+    /// <p>A <code>GrokClassifier</code> object with updated fields.</p>
     #[serde(rename = "GrokClassifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub grok_classifier: Option<UpdateGrokClassifierRequest>,
@@ -5206,11 +13168,65 @@ pub struct UpdateClassifierRequest {
     pub xml_classifier: Option<UpdateXMLClassifierRequest>,
 }
 
+impl UpdateClassifierRequest {
+    /// Returns a builder for `UpdateClassifierRequest`, checking its required members when built.
+    pub fn builder() -> UpdateClassifierRequestBuilder {
+        UpdateClassifierRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateClassifierRequest`](struct.UpdateClassifierRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateClassifierRequestBuilder {
+    csv_classifier: Option<UpdateCsvClassifierRequest>,
+    grok_classifier: Option<UpdateGrokClassifierRequest>,
+    json_classifier: Option<UpdateJsonClassifierRequest>,
+    xml_classifier: Option<UpdateXMLClassifierRequest>,
+}
+
+impl UpdateClassifierRequestBuilder {
+    /// Sets the [`csv_classifier`](struct.UpdateClassifierRequest.html#structfield.csv_classifier) member.
+    pub fn csv_classifier<T: Into<UpdateCsvClassifierRequest>>(mut self, value: T) -> Self {
+        self.csv_classifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grok_classifier`](struct.UpdateClassifierRequest.html#structfield.grok_classifier) member.
+    pub fn grok_classifier<T: Into<UpdateGrokClassifierRequest>>(mut self, value: T) -> Self {
+        self.grok_classifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`json_classifier`](struct.UpdateClassifierRequest.html#structfield.json_classifier) member.
+    pub fn json_classifier<T: Into<UpdateJsonClassifierRequest>>(mut self, value: T) -> Self {
+        self.json_classifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`xml_classifier`](struct.UpdateClassifierRequest.html#structfield.xml_classifier) member.
+    pub fn xml_classifier<T: Into<UpdateXMLClassifierRequest>>(mut self, value: T) -> Self {
+        self.xml_classifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateClassifierRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateClassifierRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateClassifierRequest {
+            csv_classifier: self.csv_classifier,
+            grok_classifier: self.grok_classifier,
+            json_classifier: self.json_classifier,
+            xml_classifier: self.xml_classifier,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateClassifierResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateConnectionRequest {
     /// <p>The ID of the Data Catalog in which the connection resides. If none is provided, the AWS account ID is used by default.</p>
     #[serde(rename = "CatalogId")]
@@ -5224,6 +13240,56 @@ pub struct UpdateConnectionRequest {
     pub name: String,
 }
 
+impl UpdateConnectionRequest {
+    /// Returns a builder for `UpdateConnectionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateConnectionRequestBuilder {
+        UpdateConnectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateConnectionRequest`](struct.UpdateConnectionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateConnectionRequestBuilder {
+    catalog_id: Option<String>,
+    connection_input: Option<ConnectionInput>,
+    name: Option<String>,
+}
+
+impl UpdateConnectionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.UpdateConnectionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connection_input`](struct.UpdateConnectionRequest.html#structfield.connection_input) member.
+    pub fn connection_input<T: Into<ConnectionInput>>(mut self, value: T) -> Self {
+        self.connection_input = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateConnectionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateConnectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateConnectionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateConnectionRequest {
+            catalog_id: self.catalog_id,
+            connection_input: self
+                .connection_input
+                .ok_or(::rusoto_core::BuildError::missing("connection_input"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateConnectionResponse {}
@@ -5275,6 +13341,116 @@ pub struct UpdateCrawlerRequest {
     pub targets: Option<CrawlerTargets>,
 }
 
+impl UpdateCrawlerRequest {
+    /// Returns a builder for `UpdateCrawlerRequest`, checking its required members when built.
+    pub fn builder() -> UpdateCrawlerRequestBuilder {
+        UpdateCrawlerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateCrawlerRequest`](struct.UpdateCrawlerRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateCrawlerRequestBuilder {
+    classifiers: Option<Vec<String>>,
+    configuration: Option<String>,
+    crawler_security_configuration: Option<String>,
+    database_name: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+    role: Option<String>,
+    schedule: Option<String>,
+    schema_change_policy: Option<SchemaChangePolicy>,
+    table_prefix: Option<String>,
+    targets: Option<CrawlerTargets>,
+}
+
+impl UpdateCrawlerRequestBuilder {
+    /// Sets the [`classifiers`](struct.UpdateCrawlerRequest.html#structfield.classifiers) member.
+    pub fn classifiers<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.classifiers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration`](struct.UpdateCrawlerRequest.html#structfield.configuration) member.
+    pub fn configuration<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`crawler_security_configuration`](struct.UpdateCrawlerRequest.html#structfield.crawler_security_configuration) member.
+    pub fn crawler_security_configuration<T: Into<String>>(mut self, value: T) -> Self {
+        self.crawler_security_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.UpdateCrawlerRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateCrawlerRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateCrawlerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.UpdateCrawlerRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule`](struct.UpdateCrawlerRequest.html#structfield.schedule) member.
+    pub fn schedule<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema_change_policy`](struct.UpdateCrawlerRequest.html#structfield.schema_change_policy) member.
+    pub fn schema_change_policy<T: Into<SchemaChangePolicy>>(mut self, value: T) -> Self {
+        self.schema_change_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_prefix`](struct.UpdateCrawlerRequest.html#structfield.table_prefix) member.
+    pub fn table_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`targets`](struct.UpdateCrawlerRequest.html#structfield.targets) member.
+    pub fn targets<T: Into<CrawlerTargets>>(mut self, value: T) -> Self {
+        self.targets = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateCrawlerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateCrawlerRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateCrawlerRequest {
+            classifiers: self.classifiers,
+            configuration: self.configuration,
+            crawler_security_configuration: self.crawler_security_configuration,
+            database_name: self.database_name,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            role: self.role,
+            schedule: self.schedule,
+            schema_change_policy: self.schema_change_policy,
+            table_prefix: self.table_prefix,
+            targets: self.targets,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateCrawlerResponse {}
@@ -5290,6 +13466,46 @@ pub struct UpdateCrawlerScheduleRequest {
     pub schedule: Option<String>,
 }
 
+impl UpdateCrawlerScheduleRequest {
+    /// Returns a builder for `UpdateCrawlerScheduleRequest`, checking its required members when built.
+    pub fn builder() -> UpdateCrawlerScheduleRequestBuilder {
+        UpdateCrawlerScheduleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateCrawlerScheduleRequest`](struct.UpdateCrawlerScheduleRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateCrawlerScheduleRequestBuilder {
+    crawler_name: Option<String>,
+    schedule: Option<String>,
+}
+
+impl UpdateCrawlerScheduleRequestBuilder {
+    /// Sets the [`crawler_name`](struct.UpdateCrawlerScheduleRequest.html#structfield.crawler_name) member.
+    pub fn crawler_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.crawler_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule`](struct.UpdateCrawlerScheduleRequest.html#structfield.schedule) member.
+    pub fn schedule<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateCrawlerScheduleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateCrawlerScheduleRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateCrawlerScheduleRequest {
+            crawler_name: self
+                .crawler_name
+                .ok_or(::rusoto_core::BuildError::missing("crawler_name"))?,
+            schedule: self.schedule,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateCrawlerScheduleResponse {}
@@ -5304,7 +13520,7 @@ pub struct UpdateCsvClassifierRequest {
     /// <p>Indicates whether the CSV file contains a header.</p>
     #[serde(rename = "ContainsHeader")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contains_header: Option<String>,
+    pub contains_header: Option<CsvHeaderOption>,
     /// <p>A custom symbol to denote what separates each column entry in the row.</p>
     #[serde(rename = "Delimiter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5340,6 +13556,54 @@ pub struct UpdateDatabaseRequest {
     pub name: String,
 }
 
+impl UpdateDatabaseRequest {
+    /// Returns a builder for `UpdateDatabaseRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDatabaseRequestBuilder {
+        UpdateDatabaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDatabaseRequest`](struct.UpdateDatabaseRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDatabaseRequestBuilder {
+    catalog_id: Option<String>,
+    database_input: Option<DatabaseInput>,
+    name: Option<String>,
+}
+
+impl UpdateDatabaseRequestBuilder {
+    /// Sets the [`catalog_id`](struct.UpdateDatabaseRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_input`](struct.UpdateDatabaseRequest.html#structfield.database_input) member.
+    pub fn database_input<T: Into<DatabaseInput>>(mut self, value: T) -> Self {
+        self.database_input = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateDatabaseRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDatabaseRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateDatabaseRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDatabaseRequest {
+            catalog_id: self.catalog_id,
+            database_input: self
+                .database_input
+                .ok_or(::rusoto_core::BuildError::missing("database_input"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDatabaseResponse {}
@@ -5379,28 +13643,119 @@ pub struct UpdateDevEndpointRequest {
     pub update_etl_libraries: Option<bool>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateDevEndpointResponse {}
+impl UpdateDevEndpointRequest {
+    /// Returns a builder for `UpdateDevEndpointRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDevEndpointRequestBuilder {
+        UpdateDevEndpointRequestBuilder::default()
+    }
+}
 
-/// <p>Specifies a grok classifier to update when passed to <code>UpdateClassifier</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateGrokClassifierRequest {
-    /// <p>An identifier of the data format that the classifier matches, such as Twitter, JSON, Omniture logs, Amazon CloudWatch Logs, and so on.</p>
-    #[serde(rename = "Classification")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub classification: Option<String>,
-    /// <p>Optional custom grok patterns used by this classifier.</p>
-    #[serde(rename = "CustomPatterns")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_patterns: Option<String>,
-    /// <p>The grok pattern used by this classifier.</p>
-    #[serde(rename = "GrokPattern")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub grok_pattern: Option<String>,
-    /// <p>The name of the <code>GrokClassifier</code>.</p>
-    #[serde(rename = "Name")]
-    pub name: String,
+/// Builds a [`UpdateDevEndpointRequest`](struct.UpdateDevEndpointRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDevEndpointRequestBuilder {
+    add_arguments: Option<::std::collections::HashMap<String, String>>,
+    add_public_keys: Option<Vec<String>>,
+    custom_libraries: Option<DevEndpointCustomLibraries>,
+    delete_arguments: Option<Vec<String>>,
+    delete_public_keys: Option<Vec<String>>,
+    endpoint_name: Option<String>,
+    public_key: Option<String>,
+    update_etl_libraries: Option<bool>,
+}
+
+impl UpdateDevEndpointRequestBuilder {
+    /// Sets the [`add_arguments`](struct.UpdateDevEndpointRequest.html#structfield.add_arguments) member.
+    pub fn add_arguments<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.add_arguments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`add_public_keys`](struct.UpdateDevEndpointRequest.html#structfield.add_public_keys) member.
+    pub fn add_public_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.add_public_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_libraries`](struct.UpdateDevEndpointRequest.html#structfield.custom_libraries) member.
+    pub fn custom_libraries<T: Into<DevEndpointCustomLibraries>>(mut self, value: T) -> Self {
+        self.custom_libraries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delete_arguments`](struct.UpdateDevEndpointRequest.html#structfield.delete_arguments) member.
+    pub fn delete_arguments<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.delete_arguments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delete_public_keys`](struct.UpdateDevEndpointRequest.html#structfield.delete_public_keys) member.
+    pub fn delete_public_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.delete_public_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_name`](struct.UpdateDevEndpointRequest.html#structfield.endpoint_name) member.
+    pub fn endpoint_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`public_key`](struct.UpdateDevEndpointRequest.html#structfield.public_key) member.
+    pub fn public_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.public_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_etl_libraries`](struct.UpdateDevEndpointRequest.html#structfield.update_etl_libraries) member.
+    pub fn update_etl_libraries<T: Into<bool>>(mut self, value: T) -> Self {
+        self.update_etl_libraries = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDevEndpointRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDevEndpointRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDevEndpointRequest {
+            add_arguments: self.add_arguments,
+            add_public_keys: self.add_public_keys,
+            custom_libraries: self.custom_libraries,
+            delete_arguments: self.delete_arguments,
+            delete_public_keys: self.delete_public_keys,
+            endpoint_name: self
+                .endpoint_name
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_name"))?,
+            public_key: self.public_key,
+            update_etl_libraries: self.update_etl_libraries,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateDevEndpointResponse {}
+
+/// <p>Specifies a grok classifier to update when passed to <code>UpdateClassifier</code>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateGrokClassifierRequest {
+    /// <p>An identifier of the data format that the classifier matches, such as Twitter, JSON, Omniture logs, Amazon CloudWatch Logs, and so on.</p>
+    #[serde(rename = "Classification")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+    /// <p>Optional custom grok patterns used by this classifier.</p>
+    #[serde(rename = "CustomPatterns")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_patterns: Option<String>,
+    /// <p>The grok pattern used by this classifier.</p>
+    #[serde(rename = "GrokPattern")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grok_pattern: Option<String>,
+    /// <p>The name of the <code>GrokClassifier</code>.</p>
+    #[serde(rename = "Name")]
+    pub name: String,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -5413,6 +13768,46 @@ pub struct UpdateJobRequest {
     pub job_update: JobUpdate,
 }
 
+impl UpdateJobRequest {
+    /// Returns a builder for `UpdateJobRequest`, checking its required members when built.
+    pub fn builder() -> UpdateJobRequestBuilder {
+        UpdateJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateJobRequest`](struct.UpdateJobRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateJobRequestBuilder {
+    job_name: Option<String>,
+    job_update: Option<JobUpdate>,
+}
+
+impl UpdateJobRequestBuilder {
+    /// Sets the [`job_name`](struct.UpdateJobRequest.html#structfield.job_name) member.
+    pub fn job_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_update`](struct.UpdateJobRequest.html#structfield.job_update) member.
+    pub fn job_update<T: Into<JobUpdate>>(mut self, value: T) -> Self {
+        self.job_update = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateJobRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateJobRequest {
+            job_name: self
+                .job_name
+                .ok_or(::rusoto_core::BuildError::missing("job_name"))?,
+            job_update: self
+                .job_update
+                .ok_or(::rusoto_core::BuildError::missing("job_update"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateJobResponse {
@@ -5474,7 +13869,111 @@ pub struct UpdateMLTransformRequest {
     /// <p><p>The type of predefined worker that is allocated when this task runs. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 64GB disk, and 1 executor per worker.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker provides 8 vCPU, 32 GB of memory and a 128GB disk, and 1 executor per worker.</p> </li> </ul></p>
     #[serde(rename = "WorkerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_type: Option<String>,
+    pub worker_type: Option<WorkerType>,
+}
+
+impl UpdateMLTransformRequest {
+    /// Returns a builder for `UpdateMLTransformRequest`, checking its required members when built.
+    pub fn builder() -> UpdateMLTransformRequestBuilder {
+        UpdateMLTransformRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateMLTransformRequest`](struct.UpdateMLTransformRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateMLTransformRequestBuilder {
+    description: Option<String>,
+    max_capacity: Option<f64>,
+    max_retries: Option<i64>,
+    name: Option<String>,
+    number_of_workers: Option<i64>,
+    parameters: Option<TransformParameters>,
+    role: Option<String>,
+    timeout: Option<i64>,
+    transform_id: Option<String>,
+    worker_type: Option<WorkerType>,
+}
+
+impl UpdateMLTransformRequestBuilder {
+    /// Sets the [`description`](struct.UpdateMLTransformRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_capacity`](struct.UpdateMLTransformRequest.html#structfield.max_capacity) member.
+    pub fn max_capacity<T: Into<f64>>(mut self, value: T) -> Self {
+        self.max_capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_retries`](struct.UpdateMLTransformRequest.html#structfield.max_retries) member.
+    pub fn max_retries<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_retries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateMLTransformRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_workers`](struct.UpdateMLTransformRequest.html#structfield.number_of_workers) member.
+    pub fn number_of_workers<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_workers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.UpdateMLTransformRequest.html#structfield.parameters) member.
+    pub fn parameters<T: Into<TransformParameters>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.UpdateMLTransformRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout`](struct.UpdateMLTransformRequest.html#structfield.timeout) member.
+    pub fn timeout<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transform_id`](struct.UpdateMLTransformRequest.html#structfield.transform_id) member.
+    pub fn transform_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transform_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_type`](struct.UpdateMLTransformRequest.html#structfield.worker_type) member.
+    pub fn worker_type<T: Into<WorkerType>>(mut self, value: T) -> Self {
+        self.worker_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateMLTransformRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateMLTransformRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateMLTransformRequest {
+            description: self.description,
+            max_capacity: self.max_capacity,
+            max_retries: self.max_retries,
+            name: self.name,
+            number_of_workers: self.number_of_workers,
+            parameters: self.parameters,
+            role: self.role,
+            timeout: self.timeout,
+            transform_id: self
+                .transform_id
+                .ok_or(::rusoto_core::BuildError::missing("transform_id"))?,
+            worker_type: self.worker_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -5506,6 +14005,74 @@ pub struct UpdatePartitionRequest {
     pub table_name: String,
 }
 
+impl UpdatePartitionRequest {
+    /// Returns a builder for `UpdatePartitionRequest`, checking its required members when built.
+    pub fn builder() -> UpdatePartitionRequestBuilder {
+        UpdatePartitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePartitionRequest`](struct.UpdatePartitionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdatePartitionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    partition_input: Option<PartitionInput>,
+    partition_value_list: Option<Vec<String>>,
+    table_name: Option<String>,
+}
+
+impl UpdatePartitionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.UpdatePartitionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.UpdatePartitionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`partition_input`](struct.UpdatePartitionRequest.html#structfield.partition_input) member.
+    pub fn partition_input<T: Into<PartitionInput>>(mut self, value: T) -> Self {
+        self.partition_input = Some(value.into());
+        self
+    }
+
+    /// Sets the [`partition_value_list`](struct.UpdatePartitionRequest.html#structfield.partition_value_list) member.
+    pub fn partition_value_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.partition_value_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_name`](struct.UpdatePartitionRequest.html#structfield.table_name) member.
+    pub fn table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePartitionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdatePartitionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdatePartitionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            partition_input: self
+                .partition_input
+                .ok_or(::rusoto_core::BuildError::missing("partition_input"))?,
+            partition_value_list: self
+                .partition_value_list
+                .ok_or(::rusoto_core::BuildError::missing("partition_value_list"))?,
+            table_name: self
+                .table_name
+                .ok_or(::rusoto_core::BuildError::missing("table_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdatePartitionResponse {}
@@ -5528,6 +14095,62 @@ pub struct UpdateTableRequest {
     pub table_input: TableInput,
 }
 
+impl UpdateTableRequest {
+    /// Returns a builder for `UpdateTableRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTableRequestBuilder {
+        UpdateTableRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTableRequest`](struct.UpdateTableRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTableRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    skip_archive: Option<bool>,
+    table_input: Option<TableInput>,
+}
+
+impl UpdateTableRequestBuilder {
+    /// Sets the [`catalog_id`](struct.UpdateTableRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.UpdateTableRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skip_archive`](struct.UpdateTableRequest.html#structfield.skip_archive) member.
+    pub fn skip_archive<T: Into<bool>>(mut self, value: T) -> Self {
+        self.skip_archive = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_input`](struct.UpdateTableRequest.html#structfield.table_input) member.
+    pub fn table_input<T: Into<TableInput>>(mut self, value: T) -> Self {
+        self.table_input = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTableRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateTableRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTableRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            skip_archive: self.skip_archive,
+            table_input: self
+                .table_input
+                .ok_or(::rusoto_core::BuildError::missing("table_input"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateTableResponse {}
@@ -5542,6 +14165,46 @@ pub struct UpdateTriggerRequest {
     pub trigger_update: TriggerUpdate,
 }
 
+impl UpdateTriggerRequest {
+    /// Returns a builder for `UpdateTriggerRequest`, checking its required members when built.
+    pub fn builder() -> UpdateTriggerRequestBuilder {
+        UpdateTriggerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateTriggerRequest`](struct.UpdateTriggerRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateTriggerRequestBuilder {
+    name: Option<String>,
+    trigger_update: Option<TriggerUpdate>,
+}
+
+impl UpdateTriggerRequestBuilder {
+    /// Sets the [`name`](struct.UpdateTriggerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trigger_update`](struct.UpdateTriggerRequest.html#structfield.trigger_update) member.
+    pub fn trigger_update<T: Into<TriggerUpdate>>(mut self, value: T) -> Self {
+        self.trigger_update = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateTriggerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateTriggerRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateTriggerRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            trigger_update: self
+                .trigger_update
+                .ok_or(::rusoto_core::BuildError::missing("trigger_update"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateTriggerResponse {
@@ -5568,6 +14231,66 @@ pub struct UpdateUserDefinedFunctionRequest {
     pub function_name: String,
 }
 
+impl UpdateUserDefinedFunctionRequest {
+    /// Returns a builder for `UpdateUserDefinedFunctionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserDefinedFunctionRequestBuilder {
+        UpdateUserDefinedFunctionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserDefinedFunctionRequest`](struct.UpdateUserDefinedFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserDefinedFunctionRequestBuilder {
+    catalog_id: Option<String>,
+    database_name: Option<String>,
+    function_input: Option<UserDefinedFunctionInput>,
+    function_name: Option<String>,
+}
+
+impl UpdateUserDefinedFunctionRequestBuilder {
+    /// Sets the [`catalog_id`](struct.UpdateUserDefinedFunctionRequest.html#structfield.catalog_id) member.
+    pub fn catalog_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.catalog_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.UpdateUserDefinedFunctionRequest.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_input`](struct.UpdateUserDefinedFunctionRequest.html#structfield.function_input) member.
+    pub fn function_input<T: Into<UserDefinedFunctionInput>>(mut self, value: T) -> Self {
+        self.function_input = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.UpdateUserDefinedFunctionRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserDefinedFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateUserDefinedFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserDefinedFunctionRequest {
+            catalog_id: self.catalog_id,
+            database_name: self
+                .database_name
+                .ok_or(::rusoto_core::BuildError::missing("database_name"))?,
+            function_input: self
+                .function_input
+                .ok_or(::rusoto_core::BuildError::missing("function_input"))?,
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateUserDefinedFunctionResponse {}
@@ -5587,6 +14310,55 @@ pub struct UpdateWorkflowRequest {
     pub name: String,
 }
 
+impl UpdateWorkflowRequest {
+    /// Returns a builder for `UpdateWorkflowRequest`, checking its required members when built.
+    pub fn builder() -> UpdateWorkflowRequestBuilder {
+        UpdateWorkflowRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateWorkflowRequest`](struct.UpdateWorkflowRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateWorkflowRequestBuilder {
+    default_run_properties: Option<::std::collections::HashMap<String, String>>,
+    description: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateWorkflowRequestBuilder {
+    /// Sets the [`default_run_properties`](struct.UpdateWorkflowRequest.html#structfield.default_run_properties) member.
+    pub fn default_run_properties<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.default_run_properties = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateWorkflowRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateWorkflowRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateWorkflowRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateWorkflowRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateWorkflowRequest {
+            default_run_properties: self.default_run_properties,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateWorkflowResponse {
@@ -5622,8 +14394,9 @@ pub struct UserDefinedFunction {
     pub class_name: Option<String>,
     /// <p>The time at which the function was created.</p>
     #[serde(rename = "CreateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_time: Option<f64>,
+    pub create_time: Option<EpochTimestamp>,
     /// <p>The name of the function.</p>
     #[serde(rename = "FunctionName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5635,7 +14408,7 @@ pub struct UserDefinedFunction {
     /// <p>The owner type.</p>
     #[serde(rename = "OwnerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub owner_type: Option<String>,
+    pub owner_type: Option<PrincipalType>,
     /// <p>The resource URIs for the function.</p>
     #[serde(rename = "ResourceUris")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5660,21 +14433,114 @@ pub struct UserDefinedFunctionInput {
     /// <p>The owner type.</p>
     #[serde(rename = "OwnerType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub owner_type: Option<String>,
+    pub owner_type: Option<PrincipalType>,
     /// <p>The resource URIs for the function.</p>
     #[serde(rename = "ResourceUris")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_uris: Option<Vec<ResourceUri>>,
 }
 
+/// <p>The type of predefined worker that is allocated to the development endpoint. Accepts a value of Standard, G.1X, or G.2X.</p> <ul> <li> <p>For the <code>Standard</code> worker type, each worker provides 4 vCPU, 16 GB of memory and a 50GB disk, and 2 executors per worker.</p> </li> <li> <p>For the <code>G.1X</code> worker type, each worker maps to 1 DPU (4 vCPU, 16 GB of memory, 64 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> <li> <p>For the <code>G.2X</code> worker type, each worker maps to 2 DPU (8 vCPU, 32 GB of memory, 128 GB disk), and provides 1 executor per worker. We recommend this worker type for memory-intensive jobs.</p> </li> </ul> <p>Known issue: when a development endpoint is created with the <code>G.2X</code> <code>WorkerType</code> configuration, the Spark drivers for the development endpoint will run on 4 vCPU, 16 GB of memory, and a 64 GB disk. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkerType {
+    /// <p><code>Standard</code></p>
+    Standard,
+    /// <p><code>G.1X</code></p>
+    G1x,
+    /// <p><code>G.2X</code></p>
+    G2x,
+    /// <p><code>G.025X</code></p>
+    G025x,
+    /// <p><code>G.4X</code></p>
+    G4x,
+    /// <p><code>G.8X</code></p>
+    G8x,
+    /// <p><code>Z.2X</code></p>
+    Z2x,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkerType::Standard => "Standard",
+            WorkerType::G1x => "G.1X",
+            WorkerType::G2x => "G.2X",
+            WorkerType::G025x => "G.025X",
+            WorkerType::G4x => "G.4X",
+            WorkerType::G8x => "G.8X",
+            WorkerType::Z2x => "Z.2X",
+            WorkerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkerType {
+    fn from(value: &'a str) -> WorkerType {
+        match value {
+            "Standard" => WorkerType::Standard,
+            "G.1X" => WorkerType::G1x,
+            "G.2X" => WorkerType::G2x,
+            "G.025X" => WorkerType::G025x,
+            "G.4X" => WorkerType::G4x,
+            "G.8X" => WorkerType::G8x,
+            "Z.2X" => WorkerType::Z2x,
+            _ => WorkerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkerType {
+    fn from(value: String) -> WorkerType {
+        WorkerType::from(value.as_str())
+    }
+}
+
+impl From<WorkerType> for String {
+    fn from(value: WorkerType) -> String {
+        match value {
+            WorkerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WorkerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WorkerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WorkerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkerType::from(value))
+    }
+}
+
 /// <p>A workflow represents a flow in which AWS Glue components should be executed to complete a logical task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Workflow {
     /// <p>The date and time when the workflow was created.</p>
     #[serde(rename = "CreatedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_on: Option<f64>,
+    pub created_on: Option<EpochTimestamp>,
     /// <p>A collection of properties to be used as part of each execution of the workflow.</p>
     #[serde(rename = "DefaultRunProperties")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5689,8 +14555,9 @@ pub struct Workflow {
     pub graph: Option<WorkflowGraph>,
     /// <p>The date and time when the workflow was last modified.</p>
     #[serde(rename = "LastModifiedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_on: Option<f64>,
+    pub last_modified_on: Option<EpochTimestamp>,
     /// <p>The information about the last execution of the workflow.</p>
     #[serde(rename = "LastRun")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5721,8 +14588,9 @@ pub struct WorkflowGraph {
 pub struct WorkflowRun {
     /// <p>The date and time when the workflow run completed.</p>
     #[serde(rename = "CompletedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_on: Option<f64>,
+    pub completed_on: Option<EpochTimestamp>,
     /// <p>The graph representing all the AWS Glue components that belong to the workflow as nodes and directed connections between them as edges.</p>
     #[serde(rename = "Graph")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5733,8 +14601,9 @@ pub struct WorkflowRun {
     pub name: Option<String>,
     /// <p>The date and time when the workflow run was started.</p>
     #[serde(rename = "StartedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_on: Option<f64>,
+    pub started_on: Option<EpochTimestamp>,
     /// <p>The statistics of the run.</p>
     #[serde(rename = "Statistics")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5742,7 +14611,7 @@ pub struct WorkflowRun {
     /// <p>The status of the workflow run.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<WorkflowRunStatus>,
     /// <p>The ID of this workflow run.</p>
     #[serde(rename = "WorkflowRunId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5783,6 +14652,90 @@ pub struct WorkflowRunStatistics {
     pub total_actions: Option<i64>,
 }
 
+/// <p>The status of the workflow run.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkflowRunStatus {
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>STOPPING</code></p>
+    Stopping,
+    /// <p><code>STOPPED</code></p>
+    Stopped,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WorkflowRunStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WorkflowRunStatus::Running => "RUNNING",
+            WorkflowRunStatus::Completed => "COMPLETED",
+            WorkflowRunStatus::Stopping => "STOPPING",
+            WorkflowRunStatus::Stopped => "STOPPED",
+            WorkflowRunStatus::Error => "ERROR",
+            WorkflowRunStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WorkflowRunStatus {
+    fn from(value: &'a str) -> WorkflowRunStatus {
+        match value {
+            "RUNNING" => WorkflowRunStatus::Running,
+            "COMPLETED" => WorkflowRunStatus::Completed,
+            "STOPPING" => WorkflowRunStatus::Stopping,
+            "STOPPED" => WorkflowRunStatus::Stopped,
+            "ERROR" => WorkflowRunStatus::Error,
+            _ => WorkflowRunStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WorkflowRunStatus {
+    fn from(value: String) -> WorkflowRunStatus {
+        WorkflowRunStatus::from(value.as_str())
+    }
+}
+
+impl From<WorkflowRunStatus> for String {
+    fn from(value: WorkflowRunStatus) -> String {
+        match value {
+            WorkflowRunStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WorkflowRunStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WorkflowRunStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WorkflowRunStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WorkflowRunStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WorkflowRunStatus::from(value))
+    }
+}
+
 /// <p>A classifier for <code>XML</code> content.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -5792,12 +14745,14 @@ pub struct XMLClassifier {
     pub classification: String,
     /// <p>The time that this classifier was registered.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The time that this classifier was last updated.</p>
     #[serde(rename = "LastUpdated")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated: Option<f64>,
+    pub last_updated: Option<EpochTimestamp>,
     /// <p>The name of the classifier.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -13258,31 +22213,782 @@ pub trait Glue {
         input: UpdateWorkflowRequest,
     ) -> RusotoFuture<UpdateWorkflowResponse, UpdateWorkflowError>;
 }
-/// A client for the AWS Glue API.
-#[derive(Clone)]
-pub struct GlueClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl GlueClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> GlueClient {
-        Self::new_with_client(Client::shared(), region)
+impl<T: ?Sized + Glue> Glue for ::std::sync::Arc<T> {
+    fn batch_create_partition(
+        &self,
+        input: BatchCreatePartitionRequest,
+    ) -> RusotoFuture<BatchCreatePartitionResponse, BatchCreatePartitionError> {
+        (**self).batch_create_partition(input)
     }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> GlueClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
+    fn batch_delete_connection(
+        &self,
+        input: BatchDeleteConnectionRequest,
+    ) -> RusotoFuture<BatchDeleteConnectionResponse, BatchDeleteConnectionError> {
+        (**self).batch_delete_connection(input)
+    }
+    fn batch_delete_partition(
+        &self,
+        input: BatchDeletePartitionRequest,
+    ) -> RusotoFuture<BatchDeletePartitionResponse, BatchDeletePartitionError> {
+        (**self).batch_delete_partition(input)
+    }
+    fn batch_delete_table(
+        &self,
+        input: BatchDeleteTableRequest,
+    ) -> RusotoFuture<BatchDeleteTableResponse, BatchDeleteTableError> {
+        (**self).batch_delete_table(input)
+    }
+    fn batch_delete_table_version(
+        &self,
+        input: BatchDeleteTableVersionRequest,
+    ) -> RusotoFuture<BatchDeleteTableVersionResponse, BatchDeleteTableVersionError> {
+        (**self).batch_delete_table_version(input)
+    }
+    fn batch_get_crawlers(
+        &self,
+        input: BatchGetCrawlersRequest,
+    ) -> RusotoFuture<BatchGetCrawlersResponse, BatchGetCrawlersError> {
+        (**self).batch_get_crawlers(input)
+    }
+    fn batch_get_dev_endpoints(
+        &self,
+        input: BatchGetDevEndpointsRequest,
+    ) -> RusotoFuture<BatchGetDevEndpointsResponse, BatchGetDevEndpointsError> {
+        (**self).batch_get_dev_endpoints(input)
+    }
+    fn batch_get_jobs(
+        &self,
+        input: BatchGetJobsRequest,
+    ) -> RusotoFuture<BatchGetJobsResponse, BatchGetJobsError> {
+        (**self).batch_get_jobs(input)
+    }
+    fn batch_get_partition(
+        &self,
+        input: BatchGetPartitionRequest,
+    ) -> RusotoFuture<BatchGetPartitionResponse, BatchGetPartitionError> {
+        (**self).batch_get_partition(input)
+    }
+    fn batch_get_triggers(
+        &self,
+        input: BatchGetTriggersRequest,
+    ) -> RusotoFuture<BatchGetTriggersResponse, BatchGetTriggersError> {
+        (**self).batch_get_triggers(input)
+    }
+    fn batch_get_workflows(
+        &self,
+        input: BatchGetWorkflowsRequest,
+    ) -> RusotoFuture<BatchGetWorkflowsResponse, BatchGetWorkflowsError> {
+        (**self).batch_get_workflows(input)
+    }
+    fn batch_stop_job_run(
+        &self,
+        input: BatchStopJobRunRequest,
+    ) -> RusotoFuture<BatchStopJobRunResponse, GlueBatchStopJobRunError> {
+        (**self).batch_stop_job_run(input)
+    }
+    fn cancel_ml_task_run(
+        &self,
+        input: CancelMLTaskRunRequest,
+    ) -> RusotoFuture<CancelMLTaskRunResponse, CancelMLTaskRunError> {
+        (**self).cancel_ml_task_run(input)
+    }
+    fn create_classifier(
+        &self,
+        input: CreateClassifierRequest,
+    ) -> RusotoFuture<CreateClassifierResponse, CreateClassifierError> {
+        (**self).create_classifier(input)
+    }
+    fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> RusotoFuture<CreateConnectionResponse, CreateConnectionError> {
+        (**self).create_connection(input)
+    }
+    fn create_crawler(
+        &self,
+        input: CreateCrawlerRequest,
+    ) -> RusotoFuture<CreateCrawlerResponse, CreateCrawlerError> {
+        (**self).create_crawler(input)
+    }
+    fn create_database(
+        &self,
+        input: CreateDatabaseRequest,
+    ) -> RusotoFuture<CreateDatabaseResponse, CreateDatabaseError> {
+        (**self).create_database(input)
+    }
+    fn create_dev_endpoint(
+        &self,
+        input: CreateDevEndpointRequest,
+    ) -> RusotoFuture<CreateDevEndpointResponse, CreateDevEndpointError> {
+        (**self).create_dev_endpoint(input)
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn create_ml_transform(
+        &self,
+        input: CreateMLTransformRequest,
+    ) -> RusotoFuture<CreateMLTransformResponse, CreateMLTransformError> {
+        (**self).create_ml_transform(input)
+    }
+    fn create_partition(
+        &self,
+        input: CreatePartitionRequest,
+    ) -> RusotoFuture<CreatePartitionResponse, CreatePartitionError> {
+        (**self).create_partition(input)
+    }
+    fn create_script(
+        &self,
+        input: CreateScriptRequest,
+    ) -> RusotoFuture<CreateScriptResponse, CreateScriptError> {
+        (**self).create_script(input)
+    }
+    fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationRequest,
+    ) -> RusotoFuture<CreateSecurityConfigurationResponse, CreateSecurityConfigurationError> {
+        (**self).create_security_configuration(input)
+    }
+    fn create_table(
+        &self,
+        input: CreateTableRequest,
+    ) -> RusotoFuture<CreateTableResponse, CreateTableError> {
+        (**self).create_table(input)
+    }
+    fn create_trigger(
+        &self,
+        input: CreateTriggerRequest,
+    ) -> RusotoFuture<CreateTriggerResponse, CreateTriggerError> {
+        (**self).create_trigger(input)
+    }
+    fn create_user_defined_function(
+        &self,
+        input: CreateUserDefinedFunctionRequest,
+    ) -> RusotoFuture<CreateUserDefinedFunctionResponse, CreateUserDefinedFunctionError> {
+        (**self).create_user_defined_function(input)
+    }
+    fn create_workflow(
+        &self,
+        input: CreateWorkflowRequest,
+    ) -> RusotoFuture<CreateWorkflowResponse, CreateWorkflowError> {
+        (**self).create_workflow(input)
+    }
+    fn delete_classifier(
+        &self,
+        input: DeleteClassifierRequest,
+    ) -> RusotoFuture<DeleteClassifierResponse, DeleteClassifierError> {
+        (**self).delete_classifier(input)
+    }
+    fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> RusotoFuture<DeleteConnectionResponse, DeleteConnectionError> {
+        (**self).delete_connection(input)
+    }
+    fn delete_crawler(
+        &self,
+        input: DeleteCrawlerRequest,
+    ) -> RusotoFuture<DeleteCrawlerResponse, DeleteCrawlerError> {
+        (**self).delete_crawler(input)
+    }
+    fn delete_database(
+        &self,
+        input: DeleteDatabaseRequest,
+    ) -> RusotoFuture<DeleteDatabaseResponse, DeleteDatabaseError> {
+        (**self).delete_database(input)
+    }
+    fn delete_dev_endpoint(
+        &self,
+        input: DeleteDevEndpointRequest,
+    ) -> RusotoFuture<DeleteDevEndpointResponse, DeleteDevEndpointError> {
+        (**self).delete_dev_endpoint(input)
+    }
+    fn delete_job(
+        &self,
+        input: DeleteJobRequest,
+    ) -> RusotoFuture<DeleteJobResponse, DeleteJobError> {
+        (**self).delete_job(input)
+    }
+    fn delete_ml_transform(
+        &self,
+        input: DeleteMLTransformRequest,
+    ) -> RusotoFuture<DeleteMLTransformResponse, DeleteMLTransformError> {
+        (**self).delete_ml_transform(input)
+    }
+    fn delete_partition(
+        &self,
+        input: DeletePartitionRequest,
+    ) -> RusotoFuture<DeletePartitionResponse, DeletePartitionError> {
+        (**self).delete_partition(input)
+    }
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<DeleteResourcePolicyResponse, DeleteResourcePolicyError> {
+        (**self).delete_resource_policy(input)
+    }
+    fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationRequest,
+    ) -> RusotoFuture<DeleteSecurityConfigurationResponse, DeleteSecurityConfigurationError> {
+        (**self).delete_security_configuration(input)
+    }
+    fn delete_table(
+        &self,
+        input: DeleteTableRequest,
+    ) -> RusotoFuture<DeleteTableResponse, DeleteTableError> {
+        (**self).delete_table(input)
+    }
+    fn delete_table_version(
+        &self,
+        input: DeleteTableVersionRequest,
+    ) -> RusotoFuture<DeleteTableVersionResponse, DeleteTableVersionError> {
+        (**self).delete_table_version(input)
+    }
+    fn delete_trigger(
+        &self,
+        input: DeleteTriggerRequest,
+    ) -> RusotoFuture<DeleteTriggerResponse, DeleteTriggerError> {
+        (**self).delete_trigger(input)
+    }
+    fn delete_user_defined_function(
+        &self,
+        input: DeleteUserDefinedFunctionRequest,
+    ) -> RusotoFuture<DeleteUserDefinedFunctionResponse, DeleteUserDefinedFunctionError> {
+        (**self).delete_user_defined_function(input)
+    }
+    fn delete_workflow(
+        &self,
+        input: DeleteWorkflowRequest,
+    ) -> RusotoFuture<DeleteWorkflowResponse, DeleteWorkflowError> {
+        (**self).delete_workflow(input)
+    }
+    fn get_catalog_import_status(
+        &self,
+        input: GetCatalogImportStatusRequest,
+    ) -> RusotoFuture<GetCatalogImportStatusResponse, GetCatalogImportStatusError> {
+        (**self).get_catalog_import_status(input)
+    }
+    fn get_classifier(
+        &self,
+        input: GetClassifierRequest,
+    ) -> RusotoFuture<GetClassifierResponse, GetClassifierError> {
+        (**self).get_classifier(input)
+    }
+    fn get_classifiers(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> RusotoFuture<GetClassifiersResponse, GetClassifiersError> {
+        (**self).get_classifiers(input)
+    }
+    fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> RusotoFuture<GetConnectionResponse, GetConnectionError> {
+        (**self).get_connection(input)
+    }
+    fn get_connections(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> RusotoFuture<GetConnectionsResponse, GetConnectionsError> {
+        (**self).get_connections(input)
+    }
+    fn get_crawler(
+        &self,
+        input: GetCrawlerRequest,
+    ) -> RusotoFuture<GetCrawlerResponse, GetCrawlerError> {
+        (**self).get_crawler(input)
+    }
+    fn get_crawler_metrics(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> RusotoFuture<GetCrawlerMetricsResponse, GetCrawlerMetricsError> {
+        (**self).get_crawler_metrics(input)
+    }
+    fn get_crawlers(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> RusotoFuture<GetCrawlersResponse, GetCrawlersError> {
+        (**self).get_crawlers(input)
+    }
+    fn get_data_catalog_encryption_settings(
+        &self,
+        input: GetDataCatalogEncryptionSettingsRequest,
+    ) -> RusotoFuture<GetDataCatalogEncryptionSettingsResponse, GetDataCatalogEncryptionSettingsError>
+    {
+        (**self).get_data_catalog_encryption_settings(input)
+    }
+    fn get_database(
+        &self,
+        input: GetDatabaseRequest,
+    ) -> RusotoFuture<GetDatabaseResponse, GetDatabaseError> {
+        (**self).get_database(input)
+    }
+    fn get_databases(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> RusotoFuture<GetDatabasesResponse, GetDatabasesError> {
+        (**self).get_databases(input)
+    }
+    fn get_dataflow_graph(
+        &self,
+        input: GetDataflowGraphRequest,
+    ) -> RusotoFuture<GetDataflowGraphResponse, GetDataflowGraphError> {
+        (**self).get_dataflow_graph(input)
+    }
+    fn get_dev_endpoint(
+        &self,
+        input: GetDevEndpointRequest,
+    ) -> RusotoFuture<GetDevEndpointResponse, GetDevEndpointError> {
+        (**self).get_dev_endpoint(input)
+    }
+    fn get_dev_endpoints(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> RusotoFuture<GetDevEndpointsResponse, GetDevEndpointsError> {
+        (**self).get_dev_endpoints(input)
+    }
+    fn get_job(&self, input: GetJobRequest) -> RusotoFuture<GetJobResponse, GetJobError> {
+        (**self).get_job(input)
+    }
+    fn get_job_bookmark(
+        &self,
+        input: GetJobBookmarkRequest,
+    ) -> RusotoFuture<GetJobBookmarkResponse, GetJobBookmarkError> {
+        (**self).get_job_bookmark(input)
+    }
+    fn get_job_run(
+        &self,
+        input: GetJobRunRequest,
+    ) -> RusotoFuture<GetJobRunResponse, GetJobRunError> {
+        (**self).get_job_run(input)
+    }
+    fn get_job_runs(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> RusotoFuture<GetJobRunsResponse, GetJobRunsError> {
+        (**self).get_job_runs(input)
+    }
+    fn get_jobs(&self, input: GetJobsRequest) -> RusotoFuture<GetJobsResponse, GetJobsError> {
+        (**self).get_jobs(input)
+    }
+    fn get_ml_task_run(
+        &self,
+        input: GetMLTaskRunRequest,
+    ) -> RusotoFuture<GetMLTaskRunResponse, GetMLTaskRunError> {
+        (**self).get_ml_task_run(input)
+    }
+    fn get_ml_task_runs(
+        &self,
+        input: GetMLTaskRunsRequest,
+    ) -> RusotoFuture<GetMLTaskRunsResponse, GetMLTaskRunsError> {
+        (**self).get_ml_task_runs(input)
+    }
+    fn get_ml_transform(
+        &self,
+        input: GetMLTransformRequest,
+    ) -> RusotoFuture<GetMLTransformResponse, GetMLTransformError> {
+        (**self).get_ml_transform(input)
+    }
+    fn get_ml_transforms(
+        &self,
+        input: GetMLTransformsRequest,
+    ) -> RusotoFuture<GetMLTransformsResponse, GetMLTransformsError> {
+        (**self).get_ml_transforms(input)
+    }
+    fn get_mapping(
+        &self,
+        input: GetMappingRequest,
+    ) -> RusotoFuture<GetMappingResponse, GetMappingError> {
+        (**self).get_mapping(input)
+    }
+    fn get_partition(
+        &self,
+        input: GetPartitionRequest,
+    ) -> RusotoFuture<GetPartitionResponse, GetPartitionError> {
+        (**self).get_partition(input)
+    }
+    fn get_partitions(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> RusotoFuture<GetPartitionsResponse, GetPartitionsError> {
+        (**self).get_partitions(input)
+    }
+    fn get_plan(&self, input: GetPlanRequest) -> RusotoFuture<GetPlanResponse, GetPlanError> {
+        (**self).get_plan(input)
+    }
+    fn get_resource_policy(
+        &self,
+    ) -> RusotoFuture<GetResourcePolicyResponse, GetResourcePolicyError> {
+        (**self).get_resource_policy()
+    }
+    fn get_security_configuration(
+        &self,
+        input: GetSecurityConfigurationRequest,
+    ) -> RusotoFuture<GetSecurityConfigurationResponse, GetSecurityConfigurationError> {
+        (**self).get_security_configuration(input)
+    }
+    fn get_security_configurations(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> RusotoFuture<GetSecurityConfigurationsResponse, GetSecurityConfigurationsError> {
+        (**self).get_security_configurations(input)
+    }
+    fn get_table(&self, input: GetTableRequest) -> RusotoFuture<GetTableResponse, GetTableError> {
+        (**self).get_table(input)
+    }
+    fn get_table_version(
+        &self,
+        input: GetTableVersionRequest,
+    ) -> RusotoFuture<GetTableVersionResponse, GetTableVersionError> {
+        (**self).get_table_version(input)
+    }
+    fn get_table_versions(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> RusotoFuture<GetTableVersionsResponse, GetTableVersionsError> {
+        (**self).get_table_versions(input)
+    }
+    fn get_tables(
+        &self,
+        input: GetTablesRequest,
+    ) -> RusotoFuture<GetTablesResponse, GetTablesError> {
+        (**self).get_tables(input)
+    }
+    fn get_tags(&self, input: GetTagsRequest) -> RusotoFuture<GetTagsResponse, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn get_trigger(
+        &self,
+        input: GetTriggerRequest,
+    ) -> RusotoFuture<GetTriggerResponse, GetTriggerError> {
+        (**self).get_trigger(input)
+    }
+    fn get_triggers(
+        &self,
+        input: GetTriggersRequest,
+    ) -> RusotoFuture<GetTriggersResponse, GetTriggersError> {
+        (**self).get_triggers(input)
+    }
+    fn get_user_defined_function(
+        &self,
+        input: GetUserDefinedFunctionRequest,
+    ) -> RusotoFuture<GetUserDefinedFunctionResponse, GetUserDefinedFunctionError> {
+        (**self).get_user_defined_function(input)
+    }
+    fn get_user_defined_functions(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> RusotoFuture<GetUserDefinedFunctionsResponse, GetUserDefinedFunctionsError> {
+        (**self).get_user_defined_functions(input)
+    }
+    fn get_workflow(
+        &self,
+        input: GetWorkflowRequest,
+    ) -> RusotoFuture<GetWorkflowResponse, GetWorkflowError> {
+        (**self).get_workflow(input)
+    }
+    fn get_workflow_run(
+        &self,
+        input: GetWorkflowRunRequest,
+    ) -> RusotoFuture<GetWorkflowRunResponse, GetWorkflowRunError> {
+        (**self).get_workflow_run(input)
+    }
+    fn get_workflow_run_properties(
+        &self,
+        input: GetWorkflowRunPropertiesRequest,
+    ) -> RusotoFuture<GetWorkflowRunPropertiesResponse, GetWorkflowRunPropertiesError> {
+        (**self).get_workflow_run_properties(input)
+    }
+    fn get_workflow_runs(
+        &self,
+        input: GetWorkflowRunsRequest,
+    ) -> RusotoFuture<GetWorkflowRunsResponse, GetWorkflowRunsError> {
+        (**self).get_workflow_runs(input)
+    }
+    fn import_catalog_to_glue(
+        &self,
+        input: ImportCatalogToGlueRequest,
+    ) -> RusotoFuture<ImportCatalogToGlueResponse, ImportCatalogToGlueError> {
+        (**self).import_catalog_to_glue(input)
+    }
+    fn list_crawlers(
+        &self,
+        input: ListCrawlersRequest,
+    ) -> RusotoFuture<ListCrawlersResponse, ListCrawlersError> {
+        (**self).list_crawlers(input)
+    }
+    fn list_dev_endpoints(
+        &self,
+        input: ListDevEndpointsRequest,
+    ) -> RusotoFuture<ListDevEndpointsResponse, ListDevEndpointsError> {
+        (**self).list_dev_endpoints(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn list_triggers(
+        &self,
+        input: ListTriggersRequest,
+    ) -> RusotoFuture<ListTriggersResponse, ListTriggersError> {
+        (**self).list_triggers(input)
+    }
+    fn list_workflows(
+        &self,
+        input: ListWorkflowsRequest,
+    ) -> RusotoFuture<ListWorkflowsResponse, ListWorkflowsError> {
+        (**self).list_workflows(input)
+    }
+    fn put_data_catalog_encryption_settings(
+        &self,
+        input: PutDataCatalogEncryptionSettingsRequest,
+    ) -> RusotoFuture<PutDataCatalogEncryptionSettingsResponse, PutDataCatalogEncryptionSettingsError>
+    {
+        (**self).put_data_catalog_encryption_settings(input)
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        (**self).put_resource_policy(input)
+    }
+    fn put_workflow_run_properties(
+        &self,
+        input: PutWorkflowRunPropertiesRequest,
+    ) -> RusotoFuture<PutWorkflowRunPropertiesResponse, PutWorkflowRunPropertiesError> {
+        (**self).put_workflow_run_properties(input)
+    }
+    fn reset_job_bookmark(
+        &self,
+        input: ResetJobBookmarkRequest,
+    ) -> RusotoFuture<ResetJobBookmarkResponse, ResetJobBookmarkError> {
+        (**self).reset_job_bookmark(input)
+    }
+    fn search_tables(
+        &self,
+        input: SearchTablesRequest,
+    ) -> RusotoFuture<SearchTablesResponse, SearchTablesError> {
+        (**self).search_tables(input)
+    }
+    fn start_crawler(
+        &self,
+        input: StartCrawlerRequest,
+    ) -> RusotoFuture<StartCrawlerResponse, StartCrawlerError> {
+        (**self).start_crawler(input)
+    }
+    fn start_crawler_schedule(
+        &self,
+        input: StartCrawlerScheduleRequest,
+    ) -> RusotoFuture<StartCrawlerScheduleResponse, StartCrawlerScheduleError> {
+        (**self).start_crawler_schedule(input)
+    }
+    fn start_export_labels_task_run(
+        &self,
+        input: StartExportLabelsTaskRunRequest,
+    ) -> RusotoFuture<StartExportLabelsTaskRunResponse, StartExportLabelsTaskRunError> {
+        (**self).start_export_labels_task_run(input)
+    }
+    fn start_import_labels_task_run(
+        &self,
+        input: StartImportLabelsTaskRunRequest,
+    ) -> RusotoFuture<StartImportLabelsTaskRunResponse, StartImportLabelsTaskRunError> {
+        (**self).start_import_labels_task_run(input)
+    }
+    fn start_job_run(
+        &self,
+        input: StartJobRunRequest,
+    ) -> RusotoFuture<StartJobRunResponse, StartJobRunError> {
+        (**self).start_job_run(input)
+    }
+    fn start_ml_evaluation_task_run(
+        &self,
+        input: StartMLEvaluationTaskRunRequest,
+    ) -> RusotoFuture<StartMLEvaluationTaskRunResponse, StartMLEvaluationTaskRunError> {
+        (**self).start_ml_evaluation_task_run(input)
+    }
+    fn start_ml_labeling_set_generation_task_run(
+        &self,
+        input: StartMLLabelingSetGenerationTaskRunRequest,
+    ) -> RusotoFuture<
+        StartMLLabelingSetGenerationTaskRunResponse,
+        StartMLLabelingSetGenerationTaskRunError,
+    > {
+        (**self).start_ml_labeling_set_generation_task_run(input)
+    }
+    fn start_trigger(
+        &self,
+        input: StartTriggerRequest,
+    ) -> RusotoFuture<StartTriggerResponse, StartTriggerError> {
+        (**self).start_trigger(input)
+    }
+    fn start_workflow_run(
+        &self,
+        input: StartWorkflowRunRequest,
+    ) -> RusotoFuture<StartWorkflowRunResponse, StartWorkflowRunError> {
+        (**self).start_workflow_run(input)
+    }
+    fn stop_crawler(
+        &self,
+        input: StopCrawlerRequest,
+    ) -> RusotoFuture<StopCrawlerResponse, StopCrawlerError> {
+        (**self).stop_crawler(input)
+    }
+    fn stop_crawler_schedule(
+        &self,
+        input: StopCrawlerScheduleRequest,
+    ) -> RusotoFuture<StopCrawlerScheduleResponse, StopCrawlerScheduleError> {
+        (**self).stop_crawler_schedule(input)
+    }
+    fn stop_trigger(
+        &self,
+        input: StopTriggerRequest,
+    ) -> RusotoFuture<StopTriggerResponse, StopTriggerError> {
+        (**self).stop_trigger(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_classifier(
+        &self,
+        input: UpdateClassifierRequest,
+    ) -> RusotoFuture<UpdateClassifierResponse, UpdateClassifierError> {
+        (**self).update_classifier(input)
+    }
+    fn update_connection(
+        &self,
+        input: UpdateConnectionRequest,
+    ) -> RusotoFuture<UpdateConnectionResponse, UpdateConnectionError> {
+        (**self).update_connection(input)
+    }
+    fn update_crawler(
+        &self,
+        input: UpdateCrawlerRequest,
+    ) -> RusotoFuture<UpdateCrawlerResponse, UpdateCrawlerError> {
+        (**self).update_crawler(input)
+    }
+    fn update_crawler_schedule(
+        &self,
+        input: UpdateCrawlerScheduleRequest,
+    ) -> RusotoFuture<UpdateCrawlerScheduleResponse, UpdateCrawlerScheduleError> {
+        (**self).update_crawler_schedule(input)
+    }
+    fn update_database(
+        &self,
+        input: UpdateDatabaseRequest,
+    ) -> RusotoFuture<UpdateDatabaseResponse, UpdateDatabaseError> {
+        (**self).update_database(input)
+    }
+    fn update_dev_endpoint(
+        &self,
+        input: UpdateDevEndpointRequest,
+    ) -> RusotoFuture<UpdateDevEndpointResponse, UpdateDevEndpointError> {
+        (**self).update_dev_endpoint(input)
+    }
+    fn update_job(
+        &self,
+        input: UpdateJobRequest,
+    ) -> RusotoFuture<UpdateJobResponse, UpdateJobError> {
+        (**self).update_job(input)
+    }
+    fn update_ml_transform(
+        &self,
+        input: UpdateMLTransformRequest,
+    ) -> RusotoFuture<UpdateMLTransformResponse, UpdateMLTransformError> {
+        (**self).update_ml_transform(input)
+    }
+    fn update_partition(
+        &self,
+        input: UpdatePartitionRequest,
+    ) -> RusotoFuture<UpdatePartitionResponse, UpdatePartitionError> {
+        (**self).update_partition(input)
+    }
+    fn update_table(
+        &self,
+        input: UpdateTableRequest,
+    ) -> RusotoFuture<UpdateTableResponse, UpdateTableError> {
+        (**self).update_table(input)
+    }
+    fn update_trigger(
+        &self,
+        input: UpdateTriggerRequest,
+    ) -> RusotoFuture<UpdateTriggerResponse, UpdateTriggerError> {
+        (**self).update_trigger(input)
+    }
+    fn update_user_defined_function(
+        &self,
+        input: UpdateUserDefinedFunctionRequest,
+    ) -> RusotoFuture<UpdateUserDefinedFunctionResponse, UpdateUserDefinedFunctionError> {
+        (**self).update_user_defined_function(input)
+    }
+    fn update_workflow(
+        &self,
+        input: UpdateWorkflowRequest,
+    ) -> RusotoFuture<UpdateWorkflowResponse, UpdateWorkflowError> {
+        (**self).update_workflow(input)
+    }
+}
+/// The error codes AWS Glue retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
+/// A client for the AWS Glue API.
+#[derive(Clone)]
+pub struct GlueClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl GlueClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> GlueClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_GLUE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> GlueClient {
+        Self::new(region::Region::from_env_for_service("GLUE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `glue`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> GlueClient {
+        Self::new_with_client(config.client().clone(), config.region_for("glue", "GLUE"))
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GlueClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
     {
         Self::new_with_client(
             Client::new_with(credentials_provider, request_dispatcher),
@@ -13291,7 +22997,49 @@ impl GlueClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> GlueClient {
-        GlueClient { client, region }
+        GlueClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "glue")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "glue", "glue", "GLUE")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Glue` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Glue + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for GlueClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Glue"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "glue"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> GlueClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Greengrass clients.
+//!
+//! Makes a single read-only `ListBulkDeployments` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_greengrass::{GreenGrass, GreenGrassClient, ListBulkDeploymentsRequest};
+
+fn main() {
+    let client = GreenGrassClient::from_env();
+    match client
+        .list_bulk_deployments(ListBulkDeploymentsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListBulkDeployments succeeded"),
+        Err(error) => {
+            eprintln!("ListBulkDeployments failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateRoleToGroupRequest {
@@ -34,6 +35,48 @@ pub struct AssociateRoleToGroupRequest {
     pub role_arn: String,
 }
 
+impl AssociateRoleToGroupRequest {
+    /// Returns a builder for `AssociateRoleToGroupRequest`, checking its required members when built.
+    pub fn builder() -> AssociateRoleToGroupRequestBuilder {
+        AssociateRoleToGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateRoleToGroupRequest`](struct.AssociateRoleToGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateRoleToGroupRequestBuilder {
+    group_id: Option<String>,
+    role_arn: Option<String>,
+}
+
+impl AssociateRoleToGroupRequestBuilder {
+    /// Sets the [`group_id`](struct.AssociateRoleToGroupRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.AssociateRoleToGroupRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateRoleToGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateRoleToGroupRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateRoleToGroupRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateRoleToGroupResponse {
@@ -50,6 +93,39 @@ pub struct AssociateServiceRoleToAccountRequest {
     pub role_arn: String,
 }
 
+impl AssociateServiceRoleToAccountRequest {
+    /// Returns a builder for `AssociateServiceRoleToAccountRequest`, checking its required members when built.
+    pub fn builder() -> AssociateServiceRoleToAccountRequestBuilder {
+        AssociateServiceRoleToAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateServiceRoleToAccountRequest`](struct.AssociateServiceRoleToAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateServiceRoleToAccountRequestBuilder {
+    role_arn: Option<String>,
+}
+
+impl AssociateServiceRoleToAccountRequestBuilder {
+    /// Sets the [`role_arn`](struct.AssociateServiceRoleToAccountRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateServiceRoleToAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateServiceRoleToAccountRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AssociateServiceRoleToAccountRequest {
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateServiceRoleToAccountResponse {
@@ -118,7 +194,7 @@ pub struct BulkDeploymentResult {
     /// <p>The type of the deployment.</p>
     #[serde(rename = "DeploymentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_type: Option<String>,
+    pub deployment_type: Option<DeploymentType>,
     /// <p>Details about the error.</p>
     #[serde(rename = "ErrorDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -133,6 +209,100 @@ pub struct BulkDeploymentResult {
     pub group_arn: Option<String>,
 }
 
+/// The current status of the bulk deployment.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BulkDeploymentStatus {
+    /// <p><code>Initializing</code></p>
+    Initializing,
+    /// <p><code>Running</code></p>
+    Running,
+    /// <p><code>Completed</code></p>
+    Completed,
+    /// <p><code>Stopping</code></p>
+    Stopping,
+    /// <p><code>Stopped</code></p>
+    Stopped,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BulkDeploymentStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BulkDeploymentStatus::Initializing => "Initializing",
+            BulkDeploymentStatus::Running => "Running",
+            BulkDeploymentStatus::Completed => "Completed",
+            BulkDeploymentStatus::Stopping => "Stopping",
+            BulkDeploymentStatus::Stopped => "Stopped",
+            BulkDeploymentStatus::Failed => "Failed",
+            BulkDeploymentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BulkDeploymentStatus {
+    fn from(value: &'a str) -> BulkDeploymentStatus {
+        match value {
+            "Initializing" => BulkDeploymentStatus::Initializing,
+            "Running" => BulkDeploymentStatus::Running,
+            "Completed" => BulkDeploymentStatus::Completed,
+            "Stopping" => BulkDeploymentStatus::Stopping,
+            "Stopped" => BulkDeploymentStatus::Stopped,
+            "Failed" => BulkDeploymentStatus::Failed,
+            _ => BulkDeploymentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BulkDeploymentStatus {
+    fn from(value: String) -> BulkDeploymentStatus {
+        BulkDeploymentStatus::from(value.as_str())
+    }
+}
+
+impl From<BulkDeploymentStatus> for String {
+    fn from(value: BulkDeploymentStatus) -> String {
+        match value {
+            BulkDeploymentStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BulkDeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BulkDeploymentStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BulkDeploymentStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BulkDeploymentStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BulkDeploymentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BulkDeploymentStatus::from(value))
+    }
+}
+
 /// <p>Information about a Greengrass core&#39;s connectivity.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConnectivityInfo {
@@ -225,6 +395,60 @@ pub struct CreateConnectorDefinitionRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateConnectorDefinitionRequest {
+    /// Returns a builder for `CreateConnectorDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> CreateConnectorDefinitionRequestBuilder {
+        CreateConnectorDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConnectorDefinitionRequest`](struct.CreateConnectorDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateConnectorDefinitionRequestBuilder {
+    amzn_client_token: Option<String>,
+    initial_version: Option<ConnectorDefinitionVersion>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateConnectorDefinitionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateConnectorDefinitionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`initial_version`](struct.CreateConnectorDefinitionRequest.html#structfield.initial_version) member.
+    pub fn initial_version<T: Into<ConnectorDefinitionVersion>>(mut self, value: T) -> Self {
+        self.initial_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateConnectorDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateConnectorDefinitionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConnectorDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateConnectorDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateConnectorDefinitionRequest {
+            amzn_client_token: self.amzn_client_token,
+            initial_version: self.initial_version,
+            name: self.name,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateConnectorDefinitionResponse {
@@ -273,6 +497,55 @@ pub struct CreateConnectorDefinitionVersionRequest {
     pub connectors: Option<Vec<Connector>>,
 }
 
+impl CreateConnectorDefinitionVersionRequest {
+    /// Returns a builder for `CreateConnectorDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateConnectorDefinitionVersionRequestBuilder {
+        CreateConnectorDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConnectorDefinitionVersionRequest`](struct.CreateConnectorDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateConnectorDefinitionVersionRequestBuilder {
+    amzn_client_token: Option<String>,
+    connector_definition_id: Option<String>,
+    connectors: Option<Vec<Connector>>,
+}
+
+impl CreateConnectorDefinitionVersionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateConnectorDefinitionVersionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connector_definition_id`](struct.CreateConnectorDefinitionVersionRequest.html#structfield.connector_definition_id) member.
+    pub fn connector_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connector_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connectors`](struct.CreateConnectorDefinitionVersionRequest.html#structfield.connectors) member.
+    pub fn connectors<T: Into<Vec<Connector>>>(mut self, value: T) -> Self {
+        self.connectors = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConnectorDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateConnectorDefinitionVersionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateConnectorDefinitionVersionRequest {
+            amzn_client_token: self.amzn_client_token,
+            connector_definition_id: self.connector_definition_id.ok_or(
+                ::rusoto_core::BuildError::missing("connector_definition_id"),
+            )?,
+            connectors: self.connectors,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateConnectorDefinitionVersionResponse {
@@ -315,6 +588,60 @@ pub struct CreateCoreDefinitionRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateCoreDefinitionRequest {
+    /// Returns a builder for `CreateCoreDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> CreateCoreDefinitionRequestBuilder {
+        CreateCoreDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCoreDefinitionRequest`](struct.CreateCoreDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCoreDefinitionRequestBuilder {
+    amzn_client_token: Option<String>,
+    initial_version: Option<CoreDefinitionVersion>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateCoreDefinitionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateCoreDefinitionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`initial_version`](struct.CreateCoreDefinitionRequest.html#structfield.initial_version) member.
+    pub fn initial_version<T: Into<CoreDefinitionVersion>>(mut self, value: T) -> Self {
+        self.initial_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateCoreDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateCoreDefinitionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCoreDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCoreDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateCoreDefinitionRequest {
+            amzn_client_token: self.amzn_client_token,
+            initial_version: self.initial_version,
+            name: self.name,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCoreDefinitionResponse {
@@ -363,6 +690,54 @@ pub struct CreateCoreDefinitionVersionRequest {
     pub cores: Option<Vec<Core>>,
 }
 
+impl CreateCoreDefinitionVersionRequest {
+    /// Returns a builder for `CreateCoreDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateCoreDefinitionVersionRequestBuilder {
+        CreateCoreDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCoreDefinitionVersionRequest`](struct.CreateCoreDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCoreDefinitionVersionRequestBuilder {
+    amzn_client_token: Option<String>,
+    core_definition_id: Option<String>,
+    cores: Option<Vec<Core>>,
+}
+
+impl CreateCoreDefinitionVersionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateCoreDefinitionVersionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`core_definition_id`](struct.CreateCoreDefinitionVersionRequest.html#structfield.core_definition_id) member.
+    pub fn core_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.core_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cores`](struct.CreateCoreDefinitionVersionRequest.html#structfield.cores) member.
+    pub fn cores<T: Into<Vec<Core>>>(mut self, value: T) -> Self {
+        self.cores = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCoreDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCoreDefinitionVersionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateCoreDefinitionVersionRequest {
+            amzn_client_token: self.amzn_client_token,
+            core_definition_id: self
+                .core_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("core_definition_id"))?,
+            cores: self.cores,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCoreDefinitionVersionResponse {
@@ -384,7 +759,7 @@ pub struct CreateCoreDefinitionVersionResponse {
     pub version: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateDeploymentRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -396,7 +771,7 @@ pub struct CreateDeploymentRequest {
     pub deployment_id: Option<String>,
     /// <p>The type of deployment. When used for &#39;&#39;CreateDeployment&#39;&#39;, only &#39;&#39;NewDeployment&#39;&#39; and &#39;&#39;Redeployment&#39;&#39; are valid.</p>
     #[serde(rename = "DeploymentType")]
-    pub deployment_type: String,
+    pub deployment_type: DeploymentType,
     /// <p>The ID of the Greengrass group.</p>
     #[serde(rename = "GroupId")]
     pub group_id: String,
@@ -406,6 +781,72 @@ pub struct CreateDeploymentRequest {
     pub group_version_id: Option<String>,
 }
 
+impl CreateDeploymentRequest {
+    /// Returns a builder for `CreateDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> CreateDeploymentRequestBuilder {
+        CreateDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDeploymentRequest`](struct.CreateDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDeploymentRequestBuilder {
+    amzn_client_token: Option<String>,
+    deployment_id: Option<String>,
+    deployment_type: Option<DeploymentType>,
+    group_id: Option<String>,
+    group_version_id: Option<String>,
+}
+
+impl CreateDeploymentRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateDeploymentRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_id`](struct.CreateDeploymentRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_type`](struct.CreateDeploymentRequest.html#structfield.deployment_type) member.
+    pub fn deployment_type<T: Into<DeploymentType>>(mut self, value: T) -> Self {
+        self.deployment_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_id`](struct.CreateDeploymentRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_version_id`](struct.CreateDeploymentRequest.html#structfield.group_version_id) member.
+    pub fn group_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDeploymentRequest {
+            amzn_client_token: self.amzn_client_token,
+            deployment_id: self.deployment_id,
+            deployment_type: self
+                .deployment_type
+                .ok_or(::rusoto_core::BuildError::missing("deployment_type"))?,
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            group_version_id: self.group_version_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDeploymentResponse {
@@ -439,6 +880,60 @@ pub struct CreateDeviceDefinitionRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateDeviceDefinitionRequest {
+    /// Returns a builder for `CreateDeviceDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> CreateDeviceDefinitionRequestBuilder {
+        CreateDeviceDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDeviceDefinitionRequest`](struct.CreateDeviceDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDeviceDefinitionRequestBuilder {
+    amzn_client_token: Option<String>,
+    initial_version: Option<DeviceDefinitionVersion>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateDeviceDefinitionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateDeviceDefinitionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`initial_version`](struct.CreateDeviceDefinitionRequest.html#structfield.initial_version) member.
+    pub fn initial_version<T: Into<DeviceDefinitionVersion>>(mut self, value: T) -> Self {
+        self.initial_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateDeviceDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDeviceDefinitionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDeviceDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDeviceDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDeviceDefinitionRequest {
+            amzn_client_token: self.amzn_client_token,
+            initial_version: self.initial_version,
+            name: self.name,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDeviceDefinitionResponse {
@@ -487,6 +982,55 @@ pub struct CreateDeviceDefinitionVersionRequest {
     pub devices: Option<Vec<Device>>,
 }
 
+impl CreateDeviceDefinitionVersionRequest {
+    /// Returns a builder for `CreateDeviceDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateDeviceDefinitionVersionRequestBuilder {
+        CreateDeviceDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDeviceDefinitionVersionRequest`](struct.CreateDeviceDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDeviceDefinitionVersionRequestBuilder {
+    amzn_client_token: Option<String>,
+    device_definition_id: Option<String>,
+    devices: Option<Vec<Device>>,
+}
+
+impl CreateDeviceDefinitionVersionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateDeviceDefinitionVersionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_definition_id`](struct.CreateDeviceDefinitionVersionRequest.html#structfield.device_definition_id) member.
+    pub fn device_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`devices`](struct.CreateDeviceDefinitionVersionRequest.html#structfield.devices) member.
+    pub fn devices<T: Into<Vec<Device>>>(mut self, value: T) -> Self {
+        self.devices = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDeviceDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDeviceDefinitionVersionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateDeviceDefinitionVersionRequest {
+            amzn_client_token: self.amzn_client_token,
+            device_definition_id: self
+                .device_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("device_definition_id"))?,
+            devices: self.devices,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDeviceDefinitionVersionResponse {
@@ -528,6 +1072,60 @@ pub struct CreateFunctionDefinitionRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateFunctionDefinitionRequest {
+    /// Returns a builder for `CreateFunctionDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> CreateFunctionDefinitionRequestBuilder {
+        CreateFunctionDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFunctionDefinitionRequest`](struct.CreateFunctionDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFunctionDefinitionRequestBuilder {
+    amzn_client_token: Option<String>,
+    initial_version: Option<FunctionDefinitionVersion>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateFunctionDefinitionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateFunctionDefinitionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`initial_version`](struct.CreateFunctionDefinitionRequest.html#structfield.initial_version) member.
+    pub fn initial_version<T: Into<FunctionDefinitionVersion>>(mut self, value: T) -> Self {
+        self.initial_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateFunctionDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateFunctionDefinitionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFunctionDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateFunctionDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateFunctionDefinitionRequest {
+            amzn_client_token: self.amzn_client_token,
+            initial_version: self.initial_version,
+            name: self.name,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateFunctionDefinitionResponse {
@@ -581,6 +1179,63 @@ pub struct CreateFunctionDefinitionVersionRequest {
     pub functions: Option<Vec<Function>>,
 }
 
+impl CreateFunctionDefinitionVersionRequest {
+    /// Returns a builder for `CreateFunctionDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateFunctionDefinitionVersionRequestBuilder {
+        CreateFunctionDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFunctionDefinitionVersionRequest`](struct.CreateFunctionDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFunctionDefinitionVersionRequestBuilder {
+    amzn_client_token: Option<String>,
+    default_config: Option<FunctionDefaultConfig>,
+    function_definition_id: Option<String>,
+    functions: Option<Vec<Function>>,
+}
+
+impl CreateFunctionDefinitionVersionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateFunctionDefinitionVersionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_config`](struct.CreateFunctionDefinitionVersionRequest.html#structfield.default_config) member.
+    pub fn default_config<T: Into<FunctionDefaultConfig>>(mut self, value: T) -> Self {
+        self.default_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_definition_id`](struct.CreateFunctionDefinitionVersionRequest.html#structfield.function_definition_id) member.
+    pub fn function_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`functions`](struct.CreateFunctionDefinitionVersionRequest.html#structfield.functions) member.
+    pub fn functions<T: Into<Vec<Function>>>(mut self, value: T) -> Self {
+        self.functions = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFunctionDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateFunctionDefinitionVersionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateFunctionDefinitionVersionRequest {
+            amzn_client_token: self.amzn_client_token,
+            default_config: self.default_config,
+            function_definition_id: self
+                .function_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("function_definition_id"))?,
+            functions: self.functions,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateFunctionDefinitionVersionResponse {
@@ -613,6 +1268,47 @@ pub struct CreateGroupCertificateAuthorityRequest {
     pub group_id: String,
 }
 
+impl CreateGroupCertificateAuthorityRequest {
+    /// Returns a builder for `CreateGroupCertificateAuthorityRequest`, checking its required members when built.
+    pub fn builder() -> CreateGroupCertificateAuthorityRequestBuilder {
+        CreateGroupCertificateAuthorityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGroupCertificateAuthorityRequest`](struct.CreateGroupCertificateAuthorityRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateGroupCertificateAuthorityRequestBuilder {
+    amzn_client_token: Option<String>,
+    group_id: Option<String>,
+}
+
+impl CreateGroupCertificateAuthorityRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateGroupCertificateAuthorityRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_id`](struct.CreateGroupCertificateAuthorityRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGroupCertificateAuthorityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateGroupCertificateAuthorityRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateGroupCertificateAuthorityRequest {
+            amzn_client_token: self.amzn_client_token,
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateGroupCertificateAuthorityResponse {
@@ -642,6 +1338,58 @@ pub struct CreateGroupRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateGroupRequest {
+    /// Returns a builder for `CreateGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateGroupRequestBuilder {
+        CreateGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGroupRequest`](struct.CreateGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateGroupRequestBuilder {
+    amzn_client_token: Option<String>,
+    initial_version: Option<GroupVersion>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateGroupRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateGroupRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`initial_version`](struct.CreateGroupRequest.html#structfield.initial_version) member.
+    pub fn initial_version<T: Into<GroupVersion>>(mut self, value: T) -> Self {
+        self.initial_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateGroupRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateGroupRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateGroupRequest {
+            amzn_client_token: self.amzn_client_token,
+            initial_version: self.initial_version,
+            name: self.name,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateGroupResponse {
@@ -714,20 +1462,116 @@ pub struct CreateGroupVersionRequest {
     pub subscription_definition_version_arn: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CreateGroupVersionResponse {
-    /// <p>The ARN of the version.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The time, in milliseconds since the epoch, when the version was created.</p>
-    #[serde(rename = "CreationTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_timestamp: Option<String>,
-    /// <p>The ID of the version.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl CreateGroupVersionRequest {
+    /// Returns a builder for `CreateGroupVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateGroupVersionRequestBuilder {
+        CreateGroupVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGroupVersionRequest`](struct.CreateGroupVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateGroupVersionRequestBuilder {
+    amzn_client_token: Option<String>,
+    connector_definition_version_arn: Option<String>,
+    core_definition_version_arn: Option<String>,
+    device_definition_version_arn: Option<String>,
+    function_definition_version_arn: Option<String>,
+    group_id: Option<String>,
+    logger_definition_version_arn: Option<String>,
+    resource_definition_version_arn: Option<String>,
+    subscription_definition_version_arn: Option<String>,
+}
+
+impl CreateGroupVersionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateGroupVersionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connector_definition_version_arn`](struct.CreateGroupVersionRequest.html#structfield.connector_definition_version_arn) member.
+    pub fn connector_definition_version_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.connector_definition_version_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`core_definition_version_arn`](struct.CreateGroupVersionRequest.html#structfield.core_definition_version_arn) member.
+    pub fn core_definition_version_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.core_definition_version_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_definition_version_arn`](struct.CreateGroupVersionRequest.html#structfield.device_definition_version_arn) member.
+    pub fn device_definition_version_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_definition_version_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_definition_version_arn`](struct.CreateGroupVersionRequest.html#structfield.function_definition_version_arn) member.
+    pub fn function_definition_version_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_definition_version_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_id`](struct.CreateGroupVersionRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logger_definition_version_arn`](struct.CreateGroupVersionRequest.html#structfield.logger_definition_version_arn) member.
+    pub fn logger_definition_version_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.logger_definition_version_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_definition_version_arn`](struct.CreateGroupVersionRequest.html#structfield.resource_definition_version_arn) member.
+    pub fn resource_definition_version_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_definition_version_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_definition_version_arn`](struct.CreateGroupVersionRequest.html#structfield.subscription_definition_version_arn) member.
+    pub fn subscription_definition_version_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_definition_version_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGroupVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateGroupVersionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateGroupVersionRequest {
+            amzn_client_token: self.amzn_client_token,
+            connector_definition_version_arn: self.connector_definition_version_arn,
+            core_definition_version_arn: self.core_definition_version_arn,
+            device_definition_version_arn: self.device_definition_version_arn,
+            function_definition_version_arn: self.function_definition_version_arn,
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            logger_definition_version_arn: self.logger_definition_version_arn,
+            resource_definition_version_arn: self.resource_definition_version_arn,
+            subscription_definition_version_arn: self.subscription_definition_version_arn,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateGroupVersionResponse {
+    /// <p>The ARN of the version.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The time, in milliseconds since the epoch, when the version was created.</p>
+    #[serde(rename = "CreationTimestamp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_timestamp: Option<String>,
+    /// <p>The ID of the version.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     /// <p>The unique ID of the version.</p>
     #[serde(rename = "Version")]
@@ -755,6 +1599,60 @@ pub struct CreateLoggerDefinitionRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateLoggerDefinitionRequest {
+    /// Returns a builder for `CreateLoggerDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> CreateLoggerDefinitionRequestBuilder {
+        CreateLoggerDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLoggerDefinitionRequest`](struct.CreateLoggerDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLoggerDefinitionRequestBuilder {
+    amzn_client_token: Option<String>,
+    initial_version: Option<LoggerDefinitionVersion>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateLoggerDefinitionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateLoggerDefinitionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`initial_version`](struct.CreateLoggerDefinitionRequest.html#structfield.initial_version) member.
+    pub fn initial_version<T: Into<LoggerDefinitionVersion>>(mut self, value: T) -> Self {
+        self.initial_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateLoggerDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateLoggerDefinitionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLoggerDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLoggerDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateLoggerDefinitionRequest {
+            amzn_client_token: self.amzn_client_token,
+            initial_version: self.initial_version,
+            name: self.name,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateLoggerDefinitionResponse {
@@ -803,6 +1701,55 @@ pub struct CreateLoggerDefinitionVersionRequest {
     pub loggers: Option<Vec<Logger>>,
 }
 
+impl CreateLoggerDefinitionVersionRequest {
+    /// Returns a builder for `CreateLoggerDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateLoggerDefinitionVersionRequestBuilder {
+        CreateLoggerDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLoggerDefinitionVersionRequest`](struct.CreateLoggerDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLoggerDefinitionVersionRequestBuilder {
+    amzn_client_token: Option<String>,
+    logger_definition_id: Option<String>,
+    loggers: Option<Vec<Logger>>,
+}
+
+impl CreateLoggerDefinitionVersionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateLoggerDefinitionVersionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logger_definition_id`](struct.CreateLoggerDefinitionVersionRequest.html#structfield.logger_definition_id) member.
+    pub fn logger_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.logger_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`loggers`](struct.CreateLoggerDefinitionVersionRequest.html#structfield.loggers) member.
+    pub fn loggers<T: Into<Vec<Logger>>>(mut self, value: T) -> Self {
+        self.loggers = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLoggerDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLoggerDefinitionVersionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateLoggerDefinitionVersionRequest {
+            amzn_client_token: self.amzn_client_token,
+            logger_definition_id: self
+                .logger_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("logger_definition_id"))?,
+            loggers: self.loggers,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateLoggerDefinitionVersionResponse {
@@ -844,6 +1791,60 @@ pub struct CreateResourceDefinitionRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateResourceDefinitionRequest {
+    /// Returns a builder for `CreateResourceDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> CreateResourceDefinitionRequestBuilder {
+        CreateResourceDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateResourceDefinitionRequest`](struct.CreateResourceDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateResourceDefinitionRequestBuilder {
+    amzn_client_token: Option<String>,
+    initial_version: Option<ResourceDefinitionVersion>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateResourceDefinitionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateResourceDefinitionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`initial_version`](struct.CreateResourceDefinitionRequest.html#structfield.initial_version) member.
+    pub fn initial_version<T: Into<ResourceDefinitionVersion>>(mut self, value: T) -> Self {
+        self.initial_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateResourceDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateResourceDefinitionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateResourceDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateResourceDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateResourceDefinitionRequest {
+            amzn_client_token: self.amzn_client_token,
+            initial_version: self.initial_version,
+            name: self.name,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateResourceDefinitionResponse {
@@ -892,6 +1893,55 @@ pub struct CreateResourceDefinitionVersionRequest {
     pub resources: Option<Vec<Resource>>,
 }
 
+impl CreateResourceDefinitionVersionRequest {
+    /// Returns a builder for `CreateResourceDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateResourceDefinitionVersionRequestBuilder {
+        CreateResourceDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateResourceDefinitionVersionRequest`](struct.CreateResourceDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateResourceDefinitionVersionRequestBuilder {
+    amzn_client_token: Option<String>,
+    resource_definition_id: Option<String>,
+    resources: Option<Vec<Resource>>,
+}
+
+impl CreateResourceDefinitionVersionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateResourceDefinitionVersionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_definition_id`](struct.CreateResourceDefinitionVersionRequest.html#structfield.resource_definition_id) member.
+    pub fn resource_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resources`](struct.CreateResourceDefinitionVersionRequest.html#structfield.resources) member.
+    pub fn resources<T: Into<Vec<Resource>>>(mut self, value: T) -> Self {
+        self.resources = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateResourceDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateResourceDefinitionVersionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateResourceDefinitionVersionRequest {
+            amzn_client_token: self.amzn_client_token,
+            resource_definition_id: self
+                .resource_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_definition_id"))?,
+            resources: self.resources,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateResourceDefinitionVersionResponse {
@@ -913,7 +1963,7 @@ pub struct CreateResourceDefinitionVersionResponse {
     pub version: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateSoftwareUpdateJobRequest {
     /// <p>A client token used to correlate requests and responses.</p>
     #[serde(rename = "AmznClientToken")]
@@ -922,16 +1972,110 @@ pub struct CreateSoftwareUpdateJobRequest {
     #[serde(rename = "S3UrlSignerRole")]
     pub s3_url_signer_role: String,
     #[serde(rename = "SoftwareToUpdate")]
-    pub software_to_update: String,
+    pub software_to_update: SoftwareToUpdate,
     #[serde(rename = "UpdateAgentLogLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_agent_log_level: Option<String>,
+    pub update_agent_log_level: Option<UpdateAgentLogLevel>,
     #[serde(rename = "UpdateTargets")]
     pub update_targets: Vec<String>,
     #[serde(rename = "UpdateTargetsArchitecture")]
-    pub update_targets_architecture: String,
+    pub update_targets_architecture: UpdateTargetsArchitecture,
     #[serde(rename = "UpdateTargetsOperatingSystem")]
-    pub update_targets_operating_system: String,
+    pub update_targets_operating_system: UpdateTargetsOperatingSystem,
+}
+
+impl CreateSoftwareUpdateJobRequest {
+    /// Returns a builder for `CreateSoftwareUpdateJobRequest`, checking its required members when built.
+    pub fn builder() -> CreateSoftwareUpdateJobRequestBuilder {
+        CreateSoftwareUpdateJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSoftwareUpdateJobRequest`](struct.CreateSoftwareUpdateJobRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSoftwareUpdateJobRequestBuilder {
+    amzn_client_token: Option<String>,
+    s3_url_signer_role: Option<String>,
+    software_to_update: Option<SoftwareToUpdate>,
+    update_agent_log_level: Option<UpdateAgentLogLevel>,
+    update_targets: Option<Vec<String>>,
+    update_targets_architecture: Option<UpdateTargetsArchitecture>,
+    update_targets_operating_system: Option<UpdateTargetsOperatingSystem>,
+}
+
+impl CreateSoftwareUpdateJobRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateSoftwareUpdateJobRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_url_signer_role`](struct.CreateSoftwareUpdateJobRequest.html#structfield.s3_url_signer_role) member.
+    pub fn s3_url_signer_role<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_url_signer_role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`software_to_update`](struct.CreateSoftwareUpdateJobRequest.html#structfield.software_to_update) member.
+    pub fn software_to_update<T: Into<SoftwareToUpdate>>(mut self, value: T) -> Self {
+        self.software_to_update = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_agent_log_level`](struct.CreateSoftwareUpdateJobRequest.html#structfield.update_agent_log_level) member.
+    pub fn update_agent_log_level<T: Into<UpdateAgentLogLevel>>(mut self, value: T) -> Self {
+        self.update_agent_log_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_targets`](struct.CreateSoftwareUpdateJobRequest.html#structfield.update_targets) member.
+    pub fn update_targets<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.update_targets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_targets_architecture`](struct.CreateSoftwareUpdateJobRequest.html#structfield.update_targets_architecture) member.
+    pub fn update_targets_architecture<T: Into<UpdateTargetsArchitecture>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.update_targets_architecture = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_targets_operating_system`](struct.CreateSoftwareUpdateJobRequest.html#structfield.update_targets_operating_system) member.
+    pub fn update_targets_operating_system<T: Into<UpdateTargetsOperatingSystem>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.update_targets_operating_system = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSoftwareUpdateJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSoftwareUpdateJobRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSoftwareUpdateJobRequest {
+            amzn_client_token: self.amzn_client_token,
+            s3_url_signer_role: self
+                .s3_url_signer_role
+                .ok_or(::rusoto_core::BuildError::missing("s3_url_signer_role"))?,
+            software_to_update: self
+                .software_to_update
+                .ok_or(::rusoto_core::BuildError::missing("software_to_update"))?,
+            update_agent_log_level: self.update_agent_log_level,
+            update_targets: self
+                .update_targets
+                .ok_or(::rusoto_core::BuildError::missing("update_targets"))?,
+            update_targets_architecture: self.update_targets_architecture.ok_or(
+                ::rusoto_core::BuildError::missing("update_targets_architecture"),
+            )?,
+            update_targets_operating_system: self.update_targets_operating_system.ok_or(
+                ::rusoto_core::BuildError::missing("update_targets_operating_system"),
+            )?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -967,6 +2111,60 @@ pub struct CreateSubscriptionDefinitionRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateSubscriptionDefinitionRequest {
+    /// Returns a builder for `CreateSubscriptionDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> CreateSubscriptionDefinitionRequestBuilder {
+        CreateSubscriptionDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSubscriptionDefinitionRequest`](struct.CreateSubscriptionDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSubscriptionDefinitionRequestBuilder {
+    amzn_client_token: Option<String>,
+    initial_version: Option<SubscriptionDefinitionVersion>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateSubscriptionDefinitionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateSubscriptionDefinitionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`initial_version`](struct.CreateSubscriptionDefinitionRequest.html#structfield.initial_version) member.
+    pub fn initial_version<T: Into<SubscriptionDefinitionVersion>>(mut self, value: T) -> Self {
+        self.initial_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateSubscriptionDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateSubscriptionDefinitionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSubscriptionDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSubscriptionDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSubscriptionDefinitionRequest {
+            amzn_client_token: self.amzn_client_token,
+            initial_version: self.initial_version,
+            name: self.name,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateSubscriptionDefinitionResponse {
@@ -1015,6 +2213,55 @@ pub struct CreateSubscriptionDefinitionVersionRequest {
     pub subscriptions: Option<Vec<Subscription>>,
 }
 
+impl CreateSubscriptionDefinitionVersionRequest {
+    /// Returns a builder for `CreateSubscriptionDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateSubscriptionDefinitionVersionRequestBuilder {
+        CreateSubscriptionDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSubscriptionDefinitionVersionRequest`](struct.CreateSubscriptionDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSubscriptionDefinitionVersionRequestBuilder {
+    amzn_client_token: Option<String>,
+    subscription_definition_id: Option<String>,
+    subscriptions: Option<Vec<Subscription>>,
+}
+
+impl CreateSubscriptionDefinitionVersionRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.CreateSubscriptionDefinitionVersionRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_definition_id`](struct.CreateSubscriptionDefinitionVersionRequest.html#structfield.subscription_definition_id) member.
+    pub fn subscription_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscriptions`](struct.CreateSubscriptionDefinitionVersionRequest.html#structfield.subscriptions) member.
+    pub fn subscriptions<T: Into<Vec<Subscription>>>(mut self, value: T) -> Self {
+        self.subscriptions = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSubscriptionDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSubscriptionDefinitionVersionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateSubscriptionDefinitionVersionRequest {
+            amzn_client_token: self.amzn_client_token,
+            subscription_definition_id: self.subscription_definition_id.ok_or(
+                ::rusoto_core::BuildError::missing("subscription_definition_id"),
+            )?,
+            subscriptions: self.subscriptions,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateSubscriptionDefinitionVersionResponse {
@@ -1081,6 +2328,38 @@ pub struct DeleteConnectorDefinitionRequest {
     pub connector_definition_id: String,
 }
 
+impl DeleteConnectorDefinitionRequest {
+    /// Returns a builder for `DeleteConnectorDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteConnectorDefinitionRequestBuilder {
+        DeleteConnectorDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteConnectorDefinitionRequest`](struct.DeleteConnectorDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteConnectorDefinitionRequestBuilder {
+    connector_definition_id: Option<String>,
+}
+
+impl DeleteConnectorDefinitionRequestBuilder {
+    /// Sets the [`connector_definition_id`](struct.DeleteConnectorDefinitionRequest.html#structfield.connector_definition_id) member.
+    pub fn connector_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connector_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteConnectorDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteConnectorDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteConnectorDefinitionRequest {
+            connector_definition_id: self.connector_definition_id.ok_or(
+                ::rusoto_core::BuildError::missing("connector_definition_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteConnectorDefinitionResponse {}
@@ -1092,6 +2371,38 @@ pub struct DeleteCoreDefinitionRequest {
     pub core_definition_id: String,
 }
 
+impl DeleteCoreDefinitionRequest {
+    /// Returns a builder for `DeleteCoreDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteCoreDefinitionRequestBuilder {
+        DeleteCoreDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCoreDefinitionRequest`](struct.DeleteCoreDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteCoreDefinitionRequestBuilder {
+    core_definition_id: Option<String>,
+}
+
+impl DeleteCoreDefinitionRequestBuilder {
+    /// Sets the [`core_definition_id`](struct.DeleteCoreDefinitionRequest.html#structfield.core_definition_id) member.
+    pub fn core_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.core_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCoreDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCoreDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteCoreDefinitionRequest {
+            core_definition_id: self
+                .core_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("core_definition_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteCoreDefinitionResponse {}
@@ -1103,6 +2414,38 @@ pub struct DeleteDeviceDefinitionRequest {
     pub device_definition_id: String,
 }
 
+impl DeleteDeviceDefinitionRequest {
+    /// Returns a builder for `DeleteDeviceDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDeviceDefinitionRequestBuilder {
+        DeleteDeviceDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDeviceDefinitionRequest`](struct.DeleteDeviceDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDeviceDefinitionRequestBuilder {
+    device_definition_id: Option<String>,
+}
+
+impl DeleteDeviceDefinitionRequestBuilder {
+    /// Sets the [`device_definition_id`](struct.DeleteDeviceDefinitionRequest.html#structfield.device_definition_id) member.
+    pub fn device_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDeviceDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDeviceDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDeviceDefinitionRequest {
+            device_definition_id: self
+                .device_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("device_definition_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDeviceDefinitionResponse {}
@@ -1114,6 +2457,38 @@ pub struct DeleteFunctionDefinitionRequest {
     pub function_definition_id: String,
 }
 
+impl DeleteFunctionDefinitionRequest {
+    /// Returns a builder for `DeleteFunctionDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFunctionDefinitionRequestBuilder {
+        DeleteFunctionDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFunctionDefinitionRequest`](struct.DeleteFunctionDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFunctionDefinitionRequestBuilder {
+    function_definition_id: Option<String>,
+}
+
+impl DeleteFunctionDefinitionRequestBuilder {
+    /// Sets the [`function_definition_id`](struct.DeleteFunctionDefinitionRequest.html#structfield.function_definition_id) member.
+    pub fn function_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFunctionDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteFunctionDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFunctionDefinitionRequest {
+            function_definition_id: self
+                .function_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("function_definition_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteFunctionDefinitionResponse {}
@@ -1125,6 +2500,36 @@ pub struct DeleteGroupRequest {
     pub group_id: String,
 }
 
+impl DeleteGroupRequest {
+    /// Returns a builder for `DeleteGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteGroupRequestBuilder {
+        DeleteGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGroupRequest`](struct.DeleteGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteGroupRequestBuilder {
+    group_id: Option<String>,
+}
+
+impl DeleteGroupRequestBuilder {
+    /// Sets the [`group_id`](struct.DeleteGroupRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteGroupRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteGroupResponse {}
@@ -1136,6 +2541,38 @@ pub struct DeleteLoggerDefinitionRequest {
     pub logger_definition_id: String,
 }
 
+impl DeleteLoggerDefinitionRequest {
+    /// Returns a builder for `DeleteLoggerDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLoggerDefinitionRequestBuilder {
+        DeleteLoggerDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLoggerDefinitionRequest`](struct.DeleteLoggerDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLoggerDefinitionRequestBuilder {
+    logger_definition_id: Option<String>,
+}
+
+impl DeleteLoggerDefinitionRequestBuilder {
+    /// Sets the [`logger_definition_id`](struct.DeleteLoggerDefinitionRequest.html#structfield.logger_definition_id) member.
+    pub fn logger_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.logger_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLoggerDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLoggerDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLoggerDefinitionRequest {
+            logger_definition_id: self
+                .logger_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("logger_definition_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteLoggerDefinitionResponse {}
@@ -1147,6 +2584,38 @@ pub struct DeleteResourceDefinitionRequest {
     pub resource_definition_id: String,
 }
 
+impl DeleteResourceDefinitionRequest {
+    /// Returns a builder for `DeleteResourceDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteResourceDefinitionRequestBuilder {
+        DeleteResourceDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteResourceDefinitionRequest`](struct.DeleteResourceDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteResourceDefinitionRequestBuilder {
+    resource_definition_id: Option<String>,
+}
+
+impl DeleteResourceDefinitionRequestBuilder {
+    /// Sets the [`resource_definition_id`](struct.DeleteResourceDefinitionRequest.html#structfield.resource_definition_id) member.
+    pub fn resource_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteResourceDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteResourceDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteResourceDefinitionRequest {
+            resource_definition_id: self
+                .resource_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_definition_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteResourceDefinitionResponse {}
@@ -1158,6 +2627,38 @@ pub struct DeleteSubscriptionDefinitionRequest {
     pub subscription_definition_id: String,
 }
 
+impl DeleteSubscriptionDefinitionRequest {
+    /// Returns a builder for `DeleteSubscriptionDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSubscriptionDefinitionRequestBuilder {
+        DeleteSubscriptionDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSubscriptionDefinitionRequest`](struct.DeleteSubscriptionDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSubscriptionDefinitionRequestBuilder {
+    subscription_definition_id: Option<String>,
+}
+
+impl DeleteSubscriptionDefinitionRequestBuilder {
+    /// Sets the [`subscription_definition_id`](struct.DeleteSubscriptionDefinitionRequest.html#structfield.subscription_definition_id) member.
+    pub fn subscription_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSubscriptionDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSubscriptionDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSubscriptionDefinitionRequest {
+            subscription_definition_id: self.subscription_definition_id.ok_or(
+                ::rusoto_core::BuildError::missing("subscription_definition_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSubscriptionDefinitionResponse {}
@@ -1181,37 +2682,123 @@ pub struct Deployment {
     /// <p>The type of the deployment.</p>
     #[serde(rename = "DeploymentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_type: Option<String>,
+    pub deployment_type: Option<DeploymentType>,
     /// <p>The ARN of the group for this deployment.</p>
     #[serde(rename = "GroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group_arn: Option<String>,
 }
 
-/// <p>Information about a device.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Device {
-    /// <p>The ARN of the certificate associated with the device.</p>
-    #[serde(rename = "CertificateArn")]
-    pub certificate_arn: String,
-    /// <p>A descriptive or arbitrary ID for the device. This value must be unique within the device definition version. Max length is 128 characters with pattern &#39;&#39;[a-zA-Z0-9:_-]+&#39;&#39;.</p>
-    #[serde(rename = "Id")]
-    pub id: String,
-    /// <p>If true, the device&#39;s local shadow will be automatically synced with the cloud.</p>
-    #[serde(rename = "SyncShadow")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sync_shadow: Option<bool>,
-    /// <p>The thing ARN of the device.</p>
-    #[serde(rename = "ThingArn")]
-    pub thing_arn: String,
+/// The type of deployment. When used for ''CreateDeployment'', only ''NewDeployment'' and ''Redeployment'' are valid.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentType {
+    /// <p><code>NewDeployment</code></p>
+    NewDeployment,
+    /// <p><code>Redeployment</code></p>
+    Redeployment,
+    /// <p><code>ResetDeployment</code></p>
+    ResetDeployment,
+    /// <p><code>ForceResetDeployment</code></p>
+    ForceResetDeployment,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeploymentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentType::NewDeployment => "NewDeployment",
+            DeploymentType::Redeployment => "Redeployment",
+            DeploymentType::ResetDeployment => "ResetDeployment",
+            DeploymentType::ForceResetDeployment => "ForceResetDeployment",
+            DeploymentType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Information about a device definition version.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct DeviceDefinitionVersion {
-    /// <p>A list of devices in the definition version.</p>
-    #[serde(rename = "Devices")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl<'a> From<&'a str> for DeploymentType {
+    fn from(value: &'a str) -> DeploymentType {
+        match value {
+            "NewDeployment" => DeploymentType::NewDeployment,
+            "Redeployment" => DeploymentType::Redeployment,
+            "ResetDeployment" => DeploymentType::ResetDeployment,
+            "ForceResetDeployment" => DeploymentType::ForceResetDeployment,
+            _ => DeploymentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentType {
+    fn from(value: String) -> DeploymentType {
+        DeploymentType::from(value.as_str())
+    }
+}
+
+impl From<DeploymentType> for String {
+    fn from(value: DeploymentType) -> String {
+        match value {
+            DeploymentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeploymentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeploymentType::from(value))
+    }
+}
+
+/// <p>Information about a device.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Device {
+    /// <p>The ARN of the certificate associated with the device.</p>
+    #[serde(rename = "CertificateArn")]
+    pub certificate_arn: String,
+    /// <p>A descriptive or arbitrary ID for the device. This value must be unique within the device definition version. Max length is 128 characters with pattern &#39;&#39;[a-zA-Z0-9:_-]+&#39;&#39;.</p>
+    #[serde(rename = "Id")]
+    pub id: String,
+    /// <p>If true, the device&#39;s local shadow will be automatically synced with the cloud.</p>
+    #[serde(rename = "SyncShadow")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_shadow: Option<bool>,
+    /// <p>The thing ARN of the device.</p>
+    #[serde(rename = "ThingArn")]
+    pub thing_arn: String,
+}
+
+/// <p>Information about a device definition version.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeviceDefinitionVersion {
+    /// <p>A list of devices in the definition version.</p>
+    #[serde(rename = "Devices")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub devices: Option<Vec<Device>>,
 }
 
@@ -1222,6 +2809,38 @@ pub struct DisassociateRoleFromGroupRequest {
     pub group_id: String,
 }
 
+impl DisassociateRoleFromGroupRequest {
+    /// Returns a builder for `DisassociateRoleFromGroupRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateRoleFromGroupRequestBuilder {
+        DisassociateRoleFromGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateRoleFromGroupRequest`](struct.DisassociateRoleFromGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateRoleFromGroupRequestBuilder {
+    group_id: Option<String>,
+}
+
+impl DisassociateRoleFromGroupRequestBuilder {
+    /// Sets the [`group_id`](struct.DisassociateRoleFromGroupRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateRoleFromGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateRoleFromGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateRoleFromGroupRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateRoleFromGroupResponse {
@@ -1243,6 +2862,84 @@ pub struct DisassociateServiceRoleFromAccountResponse {
     pub disassociated_at: Option<String>,
 }
 
+/// <p>The expected encoding type of the input payload for the function. The default is &#39;&#39;json&#39;&#39;.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EncodingType {
+    /// <p><code>binary</code></p>
+    Binary,
+    /// <p><code>json</code></p>
+    Json,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EncodingType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncodingType::Binary => "binary",
+            EncodingType::Json => "json",
+            EncodingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EncodingType {
+    fn from(value: &'a str) -> EncodingType {
+        match value {
+            "binary" => EncodingType::Binary,
+            "json" => EncodingType::Json,
+            _ => EncodingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncodingType {
+    fn from(value: String) -> EncodingType {
+        EncodingType::from(value.as_str())
+    }
+}
+
+impl From<EncodingType> for String {
+    fn from(value: EncodingType) -> String {
+        match value {
+            EncodingType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncodingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncodingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncodingType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncodingType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EncodingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EncodingType::from(value))
+    }
+}
+
 /// <p>Details about the error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1279,7 +2976,7 @@ pub struct FunctionConfiguration {
     /// <p>The expected encoding type of the input payload for the function. The default is &#39;&#39;json&#39;&#39;.</p>
     #[serde(rename = "EncodingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encoding_type: Option<String>,
+    pub encoding_type: Option<EncodingType>,
     /// <p>The environment configuration of the function.</p>
     #[serde(rename = "Environment")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1340,7 +3037,7 @@ pub struct FunctionDefaultConfig {
 pub struct FunctionDefaultExecutionConfig {
     #[serde(rename = "IsolationMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub isolation_mode: Option<String>,
+    pub isolation_mode: Option<FunctionIsolationMode>,
     #[serde(rename = "RunAs")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub run_as: Option<FunctionRunAsConfig>,
@@ -1364,12 +3061,90 @@ pub struct FunctionDefinitionVersion {
 pub struct FunctionExecutionConfig {
     #[serde(rename = "IsolationMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub isolation_mode: Option<String>,
+    pub isolation_mode: Option<FunctionIsolationMode>,
     #[serde(rename = "RunAs")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub run_as: Option<FunctionRunAsConfig>,
 }
 
+/// Specifies whether the Lambda function runs in a Greengrass container (default) or without containerization. Unless your scenario requires that you run without containerization, we recommend that you run in a Greengrass container. Omit this value to run the Lambda function with the default containerization for the group.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FunctionIsolationMode {
+    /// <p><code>GreengrassContainer</code></p>
+    GreengrassContainer,
+    /// <p><code>NoContainer</code></p>
+    NoContainer,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FunctionIsolationMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FunctionIsolationMode::GreengrassContainer => "GreengrassContainer",
+            FunctionIsolationMode::NoContainer => "NoContainer",
+            FunctionIsolationMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FunctionIsolationMode {
+    fn from(value: &'a str) -> FunctionIsolationMode {
+        match value {
+            "GreengrassContainer" => FunctionIsolationMode::GreengrassContainer,
+            "NoContainer" => FunctionIsolationMode::NoContainer,
+            _ => FunctionIsolationMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FunctionIsolationMode {
+    fn from(value: String) -> FunctionIsolationMode {
+        FunctionIsolationMode::from(value.as_str())
+    }
+}
+
+impl From<FunctionIsolationMode> for String {
+    fn from(value: FunctionIsolationMode) -> String {
+        match value {
+            FunctionIsolationMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FunctionIsolationMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FunctionIsolationMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FunctionIsolationMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FunctionIsolationMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FunctionIsolationMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FunctionIsolationMode::from(value))
+    }
+}
+
 /// <p>Specifies the user and group whose permissions are used when running the Lambda function. You can specify one or both values to override the default values. We recommend that you avoid running as root unless absolutely necessary to minimize the risk of unintended changes or malicious attacks. To run as root, you must set &#39;&#39;IsolationMode&#39;&#39; to &#39;&#39;NoContainer&#39;&#39; and update config.json in &#39;&#39;greengrass-root/config&#39;&#39; to set &#39;&#39;allowFunctionsToRunAsRoot&#39;&#39; to &#39;&#39;yes&#39;&#39;.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FunctionRunAsConfig {
@@ -1390,6 +3165,38 @@ pub struct GetAssociatedRoleRequest {
     pub group_id: String,
 }
 
+impl GetAssociatedRoleRequest {
+    /// Returns a builder for `GetAssociatedRoleRequest`, checking its required members when built.
+    pub fn builder() -> GetAssociatedRoleRequestBuilder {
+        GetAssociatedRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAssociatedRoleRequest`](struct.GetAssociatedRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAssociatedRoleRequestBuilder {
+    group_id: Option<String>,
+}
+
+impl GetAssociatedRoleRequestBuilder {
+    /// Sets the [`group_id`](struct.GetAssociatedRoleRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAssociatedRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAssociatedRoleRequest, ::rusoto_core::BuildError> {
+        Ok(GetAssociatedRoleRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAssociatedRoleResponse {
@@ -1410,6 +3217,38 @@ pub struct GetBulkDeploymentStatusRequest {
     pub bulk_deployment_id: String,
 }
 
+impl GetBulkDeploymentStatusRequest {
+    /// Returns a builder for `GetBulkDeploymentStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetBulkDeploymentStatusRequestBuilder {
+        GetBulkDeploymentStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBulkDeploymentStatusRequest`](struct.GetBulkDeploymentStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBulkDeploymentStatusRequestBuilder {
+    bulk_deployment_id: Option<String>,
+}
+
+impl GetBulkDeploymentStatusRequestBuilder {
+    /// Sets the [`bulk_deployment_id`](struct.GetBulkDeploymentStatusRequest.html#structfield.bulk_deployment_id) member.
+    pub fn bulk_deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bulk_deployment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBulkDeploymentStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetBulkDeploymentStatusRequest, ::rusoto_core::BuildError> {
+        Ok(GetBulkDeploymentStatusRequest {
+            bulk_deployment_id: self
+                .bulk_deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("bulk_deployment_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBulkDeploymentStatusResponse {
@@ -1420,7 +3259,7 @@ pub struct GetBulkDeploymentStatusResponse {
     /// <p>The status of the bulk deployment.</p>
     #[serde(rename = "BulkDeploymentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bulk_deployment_status: Option<String>,
+    pub bulk_deployment_status: Option<BulkDeploymentStatus>,
     /// <p>The time, in ISO format, when the deployment was created.</p>
     #[serde(rename = "CreatedAt")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1446,6 +3285,38 @@ pub struct GetConnectivityInfoRequest {
     pub thing_name: String,
 }
 
+impl GetConnectivityInfoRequest {
+    /// Returns a builder for `GetConnectivityInfoRequest`, checking its required members when built.
+    pub fn builder() -> GetConnectivityInfoRequestBuilder {
+        GetConnectivityInfoRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetConnectivityInfoRequest`](struct.GetConnectivityInfoRequest.html).
+#[derive(Default, Debug)]
+pub struct GetConnectivityInfoRequestBuilder {
+    thing_name: Option<String>,
+}
+
+impl GetConnectivityInfoRequestBuilder {
+    /// Sets the [`thing_name`](struct.GetConnectivityInfoRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetConnectivityInfoRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetConnectivityInfoRequest, ::rusoto_core::BuildError> {
+        Ok(GetConnectivityInfoRequest {
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetConnectivityInfoResponse {
@@ -1466,6 +3337,38 @@ pub struct GetConnectorDefinitionRequest {
     pub connector_definition_id: String,
 }
 
+impl GetConnectorDefinitionRequest {
+    /// Returns a builder for `GetConnectorDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> GetConnectorDefinitionRequestBuilder {
+        GetConnectorDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetConnectorDefinitionRequest`](struct.GetConnectorDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetConnectorDefinitionRequestBuilder {
+    connector_definition_id: Option<String>,
+}
+
+impl GetConnectorDefinitionRequestBuilder {
+    /// Sets the [`connector_definition_id`](struct.GetConnectorDefinitionRequest.html#structfield.connector_definition_id) member.
+    pub fn connector_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connector_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetConnectorDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetConnectorDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(GetConnectorDefinitionRequest {
+            connector_definition_id: self.connector_definition_id.ok_or(
+                ::rusoto_core::BuildError::missing("connector_definition_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetConnectorDefinitionResponse {
@@ -1517,6 +3420,57 @@ pub struct GetConnectorDefinitionVersionRequest {
     pub next_token: Option<String>,
 }
 
+impl GetConnectorDefinitionVersionRequest {
+    /// Returns a builder for `GetConnectorDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetConnectorDefinitionVersionRequestBuilder {
+        GetConnectorDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetConnectorDefinitionVersionRequest`](struct.GetConnectorDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetConnectorDefinitionVersionRequestBuilder {
+    connector_definition_id: Option<String>,
+    connector_definition_version_id: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetConnectorDefinitionVersionRequestBuilder {
+    /// Sets the [`connector_definition_id`](struct.GetConnectorDefinitionVersionRequest.html#structfield.connector_definition_id) member.
+    pub fn connector_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connector_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`connector_definition_version_id`](struct.GetConnectorDefinitionVersionRequest.html#structfield.connector_definition_version_id) member.
+    pub fn connector_definition_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connector_definition_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetConnectorDefinitionVersionRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetConnectorDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetConnectorDefinitionVersionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetConnectorDefinitionVersionRequest {
+            connector_definition_id: self.connector_definition_id.ok_or(
+                ::rusoto_core::BuildError::missing("connector_definition_id"),
+            )?,
+            connector_definition_version_id: self.connector_definition_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("connector_definition_version_id"),
+            )?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetConnectorDefinitionVersionResponse {
@@ -1553,6 +3507,38 @@ pub struct GetCoreDefinitionRequest {
     pub core_definition_id: String,
 }
 
+impl GetCoreDefinitionRequest {
+    /// Returns a builder for `GetCoreDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> GetCoreDefinitionRequestBuilder {
+        GetCoreDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCoreDefinitionRequest`](struct.GetCoreDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCoreDefinitionRequestBuilder {
+    core_definition_id: Option<String>,
+}
+
+impl GetCoreDefinitionRequestBuilder {
+    /// Sets the [`core_definition_id`](struct.GetCoreDefinitionRequest.html#structfield.core_definition_id) member.
+    pub fn core_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.core_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCoreDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCoreDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(GetCoreDefinitionRequest {
+            core_definition_id: self
+                .core_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("core_definition_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCoreDefinitionResponse {
@@ -1600,6 +3586,48 @@ pub struct GetCoreDefinitionVersionRequest {
     pub core_definition_version_id: String,
 }
 
+impl GetCoreDefinitionVersionRequest {
+    /// Returns a builder for `GetCoreDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetCoreDefinitionVersionRequestBuilder {
+        GetCoreDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCoreDefinitionVersionRequest`](struct.GetCoreDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCoreDefinitionVersionRequestBuilder {
+    core_definition_id: Option<String>,
+    core_definition_version_id: Option<String>,
+}
+
+impl GetCoreDefinitionVersionRequestBuilder {
+    /// Sets the [`core_definition_id`](struct.GetCoreDefinitionVersionRequest.html#structfield.core_definition_id) member.
+    pub fn core_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.core_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`core_definition_version_id`](struct.GetCoreDefinitionVersionRequest.html#structfield.core_definition_version_id) member.
+    pub fn core_definition_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.core_definition_version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCoreDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCoreDefinitionVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetCoreDefinitionVersionRequest {
+            core_definition_id: self
+                .core_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("core_definition_id"))?,
+            core_definition_version_id: self.core_definition_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("core_definition_version_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCoreDefinitionVersionResponse {
@@ -1639,6 +3667,48 @@ pub struct GetDeploymentStatusRequest {
     pub group_id: String,
 }
 
+impl GetDeploymentStatusRequest {
+    /// Returns a builder for `GetDeploymentStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetDeploymentStatusRequestBuilder {
+        GetDeploymentStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeploymentStatusRequest`](struct.GetDeploymentStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDeploymentStatusRequestBuilder {
+    deployment_id: Option<String>,
+    group_id: Option<String>,
+}
+
+impl GetDeploymentStatusRequestBuilder {
+    /// Sets the [`deployment_id`](struct.GetDeploymentStatusRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_id`](struct.GetDeploymentStatusRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeploymentStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDeploymentStatusRequest, ::rusoto_core::BuildError> {
+        Ok(GetDeploymentStatusRequest {
+            deployment_id: self
+                .deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("deployment_id"))?,
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDeploymentStatusResponse {
@@ -1649,7 +3719,7 @@ pub struct GetDeploymentStatusResponse {
     /// <p>The type of the deployment.</p>
     #[serde(rename = "DeploymentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_type: Option<String>,
+    pub deployment_type: Option<DeploymentType>,
     /// <p>Error details</p>
     #[serde(rename = "ErrorDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1671,6 +3741,38 @@ pub struct GetDeviceDefinitionRequest {
     pub device_definition_id: String,
 }
 
+impl GetDeviceDefinitionRequest {
+    /// Returns a builder for `GetDeviceDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> GetDeviceDefinitionRequestBuilder {
+        GetDeviceDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeviceDefinitionRequest`](struct.GetDeviceDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDeviceDefinitionRequestBuilder {
+    device_definition_id: Option<String>,
+}
+
+impl GetDeviceDefinitionRequestBuilder {
+    /// Sets the [`device_definition_id`](struct.GetDeviceDefinitionRequest.html#structfield.device_definition_id) member.
+    pub fn device_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeviceDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDeviceDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(GetDeviceDefinitionRequest {
+            device_definition_id: self
+                .device_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("device_definition_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDeviceDefinitionResponse {
@@ -1722,6 +3824,56 @@ pub struct GetDeviceDefinitionVersionRequest {
     pub next_token: Option<String>,
 }
 
+impl GetDeviceDefinitionVersionRequest {
+    /// Returns a builder for `GetDeviceDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetDeviceDefinitionVersionRequestBuilder {
+        GetDeviceDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeviceDefinitionVersionRequest`](struct.GetDeviceDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDeviceDefinitionVersionRequestBuilder {
+    device_definition_id: Option<String>,
+    device_definition_version_id: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetDeviceDefinitionVersionRequestBuilder {
+    /// Sets the [`device_definition_id`](struct.GetDeviceDefinitionVersionRequest.html#structfield.device_definition_id) member.
+    pub fn device_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_definition_version_id`](struct.GetDeviceDefinitionVersionRequest.html#structfield.device_definition_version_id) member.
+    pub fn device_definition_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_definition_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetDeviceDefinitionVersionRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeviceDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDeviceDefinitionVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetDeviceDefinitionVersionRequest {
+            device_definition_id: self
+                .device_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("device_definition_id"))?,
+            device_definition_version_id: self.device_definition_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("device_definition_version_id"),
+            )?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDeviceDefinitionVersionResponse {
@@ -1758,6 +3910,38 @@ pub struct GetFunctionDefinitionRequest {
     pub function_definition_id: String,
 }
 
+impl GetFunctionDefinitionRequest {
+    /// Returns a builder for `GetFunctionDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> GetFunctionDefinitionRequestBuilder {
+        GetFunctionDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFunctionDefinitionRequest`](struct.GetFunctionDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFunctionDefinitionRequestBuilder {
+    function_definition_id: Option<String>,
+}
+
+impl GetFunctionDefinitionRequestBuilder {
+    /// Sets the [`function_definition_id`](struct.GetFunctionDefinitionRequest.html#structfield.function_definition_id) member.
+    pub fn function_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFunctionDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetFunctionDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(GetFunctionDefinitionRequest {
+            function_definition_id: self
+                .function_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("function_definition_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFunctionDefinitionResponse {
@@ -1809,6 +3993,56 @@ pub struct GetFunctionDefinitionVersionRequest {
     pub next_token: Option<String>,
 }
 
+impl GetFunctionDefinitionVersionRequest {
+    /// Returns a builder for `GetFunctionDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetFunctionDefinitionVersionRequestBuilder {
+        GetFunctionDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFunctionDefinitionVersionRequest`](struct.GetFunctionDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFunctionDefinitionVersionRequestBuilder {
+    function_definition_id: Option<String>,
+    function_definition_version_id: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetFunctionDefinitionVersionRequestBuilder {
+    /// Sets the [`function_definition_id`](struct.GetFunctionDefinitionVersionRequest.html#structfield.function_definition_id) member.
+    pub fn function_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_definition_version_id`](struct.GetFunctionDefinitionVersionRequest.html#structfield.function_definition_version_id) member.
+    pub fn function_definition_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_definition_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetFunctionDefinitionVersionRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFunctionDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetFunctionDefinitionVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetFunctionDefinitionVersionRequest {
+            function_definition_id: self
+                .function_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("function_definition_id"))?,
+            function_definition_version_id: self.function_definition_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("function_definition_version_id"),
+            )?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFunctionDefinitionVersionResponse {
@@ -1848,6 +4082,48 @@ pub struct GetGroupCertificateAuthorityRequest {
     pub group_id: String,
 }
 
+impl GetGroupCertificateAuthorityRequest {
+    /// Returns a builder for `GetGroupCertificateAuthorityRequest`, checking its required members when built.
+    pub fn builder() -> GetGroupCertificateAuthorityRequestBuilder {
+        GetGroupCertificateAuthorityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGroupCertificateAuthorityRequest`](struct.GetGroupCertificateAuthorityRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGroupCertificateAuthorityRequestBuilder {
+    certificate_authority_id: Option<String>,
+    group_id: Option<String>,
+}
+
+impl GetGroupCertificateAuthorityRequestBuilder {
+    /// Sets the [`certificate_authority_id`](struct.GetGroupCertificateAuthorityRequest.html#structfield.certificate_authority_id) member.
+    pub fn certificate_authority_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_authority_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_id`](struct.GetGroupCertificateAuthorityRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGroupCertificateAuthorityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetGroupCertificateAuthorityRequest, ::rusoto_core::BuildError> {
+        Ok(GetGroupCertificateAuthorityRequest {
+            certificate_authority_id: self.certificate_authority_id.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_authority_id"),
+            )?,
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetGroupCertificateAuthorityResponse {
@@ -1872,6 +4148,39 @@ pub struct GetGroupCertificateConfigurationRequest {
     pub group_id: String,
 }
 
+impl GetGroupCertificateConfigurationRequest {
+    /// Returns a builder for `GetGroupCertificateConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> GetGroupCertificateConfigurationRequestBuilder {
+        GetGroupCertificateConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGroupCertificateConfigurationRequest`](struct.GetGroupCertificateConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGroupCertificateConfigurationRequestBuilder {
+    group_id: Option<String>,
+}
+
+impl GetGroupCertificateConfigurationRequestBuilder {
+    /// Sets the [`group_id`](struct.GetGroupCertificateConfigurationRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGroupCertificateConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetGroupCertificateConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetGroupCertificateConfigurationRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetGroupCertificateConfigurationResponse {
@@ -1896,6 +4205,36 @@ pub struct GetGroupRequest {
     pub group_id: String,
 }
 
+impl GetGroupRequest {
+    /// Returns a builder for `GetGroupRequest`, checking its required members when built.
+    pub fn builder() -> GetGroupRequestBuilder {
+        GetGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGroupRequest`](struct.GetGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGroupRequestBuilder {
+    group_id: Option<String>,
+}
+
+impl GetGroupRequestBuilder {
+    /// Sets the [`group_id`](struct.GetGroupRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGroupRequest, ::rusoto_core::BuildError> {
+        Ok(GetGroupRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetGroupResponse {
@@ -1943,6 +4282,46 @@ pub struct GetGroupVersionRequest {
     pub group_version_id: String,
 }
 
+impl GetGroupVersionRequest {
+    /// Returns a builder for `GetGroupVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetGroupVersionRequestBuilder {
+        GetGroupVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGroupVersionRequest`](struct.GetGroupVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGroupVersionRequestBuilder {
+    group_id: Option<String>,
+    group_version_id: Option<String>,
+}
+
+impl GetGroupVersionRequestBuilder {
+    /// Sets the [`group_id`](struct.GetGroupVersionRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_version_id`](struct.GetGroupVersionRequest.html#structfield.group_version_id) member.
+    pub fn group_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGroupVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGroupVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetGroupVersionRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            group_version_id: self
+                .group_version_id
+                .ok_or(::rusoto_core::BuildError::missing("group_version_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetGroupVersionResponse {
@@ -1975,6 +4354,38 @@ pub struct GetLoggerDefinitionRequest {
     pub logger_definition_id: String,
 }
 
+impl GetLoggerDefinitionRequest {
+    /// Returns a builder for `GetLoggerDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> GetLoggerDefinitionRequestBuilder {
+        GetLoggerDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLoggerDefinitionRequest`](struct.GetLoggerDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLoggerDefinitionRequestBuilder {
+    logger_definition_id: Option<String>,
+}
+
+impl GetLoggerDefinitionRequestBuilder {
+    /// Sets the [`logger_definition_id`](struct.GetLoggerDefinitionRequest.html#structfield.logger_definition_id) member.
+    pub fn logger_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.logger_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLoggerDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLoggerDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(GetLoggerDefinitionRequest {
+            logger_definition_id: self
+                .logger_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("logger_definition_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLoggerDefinitionResponse {
@@ -2026,6 +4437,56 @@ pub struct GetLoggerDefinitionVersionRequest {
     pub next_token: Option<String>,
 }
 
+impl GetLoggerDefinitionVersionRequest {
+    /// Returns a builder for `GetLoggerDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetLoggerDefinitionVersionRequestBuilder {
+        GetLoggerDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLoggerDefinitionVersionRequest`](struct.GetLoggerDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLoggerDefinitionVersionRequestBuilder {
+    logger_definition_id: Option<String>,
+    logger_definition_version_id: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetLoggerDefinitionVersionRequestBuilder {
+    /// Sets the [`logger_definition_id`](struct.GetLoggerDefinitionVersionRequest.html#structfield.logger_definition_id) member.
+    pub fn logger_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.logger_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logger_definition_version_id`](struct.GetLoggerDefinitionVersionRequest.html#structfield.logger_definition_version_id) member.
+    pub fn logger_definition_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.logger_definition_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetLoggerDefinitionVersionRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLoggerDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLoggerDefinitionVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetLoggerDefinitionVersionRequest {
+            logger_definition_id: self
+                .logger_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("logger_definition_id"))?,
+            logger_definition_version_id: self.logger_definition_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("logger_definition_version_id"),
+            )?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLoggerDefinitionVersionResponse {
@@ -2058,6 +4519,38 @@ pub struct GetResourceDefinitionRequest {
     pub resource_definition_id: String,
 }
 
+impl GetResourceDefinitionRequest {
+    /// Returns a builder for `GetResourceDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> GetResourceDefinitionRequestBuilder {
+        GetResourceDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourceDefinitionRequest`](struct.GetResourceDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResourceDefinitionRequestBuilder {
+    resource_definition_id: Option<String>,
+}
+
+impl GetResourceDefinitionRequestBuilder {
+    /// Sets the [`resource_definition_id`](struct.GetResourceDefinitionRequest.html#structfield.resource_definition_id) member.
+    pub fn resource_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourceDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetResourceDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(GetResourceDefinitionRequest {
+            resource_definition_id: self
+                .resource_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_definition_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetResourceDefinitionResponse {
@@ -2105,6 +4598,48 @@ pub struct GetResourceDefinitionVersionRequest {
     pub resource_definition_version_id: String,
 }
 
+impl GetResourceDefinitionVersionRequest {
+    /// Returns a builder for `GetResourceDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetResourceDefinitionVersionRequestBuilder {
+        GetResourceDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourceDefinitionVersionRequest`](struct.GetResourceDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResourceDefinitionVersionRequestBuilder {
+    resource_definition_id: Option<String>,
+    resource_definition_version_id: Option<String>,
+}
+
+impl GetResourceDefinitionVersionRequestBuilder {
+    /// Sets the [`resource_definition_id`](struct.GetResourceDefinitionVersionRequest.html#structfield.resource_definition_id) member.
+    pub fn resource_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_definition_version_id`](struct.GetResourceDefinitionVersionRequest.html#structfield.resource_definition_version_id) member.
+    pub fn resource_definition_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_definition_version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourceDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetResourceDefinitionVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetResourceDefinitionVersionRequest {
+            resource_definition_id: self
+                .resource_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_definition_id"))?,
+            resource_definition_version_id: self.resource_definition_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("resource_definition_version_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetResourceDefinitionVersionResponse {
@@ -2153,6 +4688,38 @@ pub struct GetSubscriptionDefinitionRequest {
     pub subscription_definition_id: String,
 }
 
+impl GetSubscriptionDefinitionRequest {
+    /// Returns a builder for `GetSubscriptionDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> GetSubscriptionDefinitionRequestBuilder {
+        GetSubscriptionDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSubscriptionDefinitionRequest`](struct.GetSubscriptionDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSubscriptionDefinitionRequestBuilder {
+    subscription_definition_id: Option<String>,
+}
+
+impl GetSubscriptionDefinitionRequestBuilder {
+    /// Sets the [`subscription_definition_id`](struct.GetSubscriptionDefinitionRequest.html#structfield.subscription_definition_id) member.
+    pub fn subscription_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSubscriptionDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSubscriptionDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(GetSubscriptionDefinitionRequest {
+            subscription_definition_id: self.subscription_definition_id.ok_or(
+                ::rusoto_core::BuildError::missing("subscription_definition_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSubscriptionDefinitionResponse {
@@ -2204,6 +4771,57 @@ pub struct GetSubscriptionDefinitionVersionRequest {
     pub subscription_definition_version_id: String,
 }
 
+impl GetSubscriptionDefinitionVersionRequest {
+    /// Returns a builder for `GetSubscriptionDefinitionVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetSubscriptionDefinitionVersionRequestBuilder {
+        GetSubscriptionDefinitionVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSubscriptionDefinitionVersionRequest`](struct.GetSubscriptionDefinitionVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSubscriptionDefinitionVersionRequestBuilder {
+    next_token: Option<String>,
+    subscription_definition_id: Option<String>,
+    subscription_definition_version_id: Option<String>,
+}
+
+impl GetSubscriptionDefinitionVersionRequestBuilder {
+    /// Sets the [`next_token`](struct.GetSubscriptionDefinitionVersionRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_definition_id`](struct.GetSubscriptionDefinitionVersionRequest.html#structfield.subscription_definition_id) member.
+    pub fn subscription_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_definition_version_id`](struct.GetSubscriptionDefinitionVersionRequest.html#structfield.subscription_definition_version_id) member.
+    pub fn subscription_definition_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_definition_version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSubscriptionDefinitionVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSubscriptionDefinitionVersionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetSubscriptionDefinitionVersionRequest {
+            next_token: self.next_token,
+            subscription_definition_id: self.subscription_definition_id.ok_or(
+                ::rusoto_core::BuildError::missing("subscription_definition_id"),
+            )?,
+            subscription_definition_version_id: self.subscription_definition_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("subscription_definition_version_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSubscriptionDefinitionVersionResponse {
@@ -2342,6 +4960,55 @@ pub struct ListBulkDeploymentDetailedReportsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListBulkDeploymentDetailedReportsRequest {
+    /// Returns a builder for `ListBulkDeploymentDetailedReportsRequest`, checking its required members when built.
+    pub fn builder() -> ListBulkDeploymentDetailedReportsRequestBuilder {
+        ListBulkDeploymentDetailedReportsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListBulkDeploymentDetailedReportsRequest`](struct.ListBulkDeploymentDetailedReportsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListBulkDeploymentDetailedReportsRequestBuilder {
+    bulk_deployment_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListBulkDeploymentDetailedReportsRequestBuilder {
+    /// Sets the [`bulk_deployment_id`](struct.ListBulkDeploymentDetailedReportsRequest.html#structfield.bulk_deployment_id) member.
+    pub fn bulk_deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bulk_deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListBulkDeploymentDetailedReportsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListBulkDeploymentDetailedReportsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBulkDeploymentDetailedReportsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListBulkDeploymentDetailedReportsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListBulkDeploymentDetailedReportsRequest {
+            bulk_deployment_id: self
+                .bulk_deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("bulk_deployment_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListBulkDeploymentDetailedReportsResponse {
@@ -2367,6 +5034,44 @@ pub struct ListBulkDeploymentsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListBulkDeploymentsRequest {
+    /// Returns a builder for `ListBulkDeploymentsRequest`, checking its required members when built.
+    pub fn builder() -> ListBulkDeploymentsRequestBuilder {
+        ListBulkDeploymentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListBulkDeploymentsRequest`](struct.ListBulkDeploymentsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListBulkDeploymentsRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListBulkDeploymentsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListBulkDeploymentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListBulkDeploymentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBulkDeploymentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListBulkDeploymentsRequest, ::rusoto_core::BuildError> {
+        Ok(ListBulkDeploymentsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListBulkDeploymentsResponse {
@@ -2395,6 +5100,55 @@ pub struct ListConnectorDefinitionVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListConnectorDefinitionVersionsRequest {
+    /// Returns a builder for `ListConnectorDefinitionVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListConnectorDefinitionVersionsRequestBuilder {
+        ListConnectorDefinitionVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListConnectorDefinitionVersionsRequest`](struct.ListConnectorDefinitionVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListConnectorDefinitionVersionsRequestBuilder {
+    connector_definition_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListConnectorDefinitionVersionsRequestBuilder {
+    /// Sets the [`connector_definition_id`](struct.ListConnectorDefinitionVersionsRequest.html#structfield.connector_definition_id) member.
+    pub fn connector_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connector_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListConnectorDefinitionVersionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListConnectorDefinitionVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListConnectorDefinitionVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListConnectorDefinitionVersionsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListConnectorDefinitionVersionsRequest {
+            connector_definition_id: self.connector_definition_id.ok_or(
+                ::rusoto_core::BuildError::missing("connector_definition_id"),
+            )?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListConnectorDefinitionVersionsResponse {
@@ -2420,6 +5174,44 @@ pub struct ListConnectorDefinitionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListConnectorDefinitionsRequest {
+    /// Returns a builder for `ListConnectorDefinitionsRequest`, checking its required members when built.
+    pub fn builder() -> ListConnectorDefinitionsRequestBuilder {
+        ListConnectorDefinitionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListConnectorDefinitionsRequest`](struct.ListConnectorDefinitionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListConnectorDefinitionsRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListConnectorDefinitionsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListConnectorDefinitionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListConnectorDefinitionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListConnectorDefinitionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListConnectorDefinitionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListConnectorDefinitionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListConnectorDefinitionsResponse {
@@ -2448,6 +5240,54 @@ pub struct ListCoreDefinitionVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListCoreDefinitionVersionsRequest {
+    /// Returns a builder for `ListCoreDefinitionVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListCoreDefinitionVersionsRequestBuilder {
+        ListCoreDefinitionVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCoreDefinitionVersionsRequest`](struct.ListCoreDefinitionVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCoreDefinitionVersionsRequestBuilder {
+    core_definition_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListCoreDefinitionVersionsRequestBuilder {
+    /// Sets the [`core_definition_id`](struct.ListCoreDefinitionVersionsRequest.html#structfield.core_definition_id) member.
+    pub fn core_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.core_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListCoreDefinitionVersionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListCoreDefinitionVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCoreDefinitionVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListCoreDefinitionVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListCoreDefinitionVersionsRequest {
+            core_definition_id: self
+                .core_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("core_definition_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListCoreDefinitionVersionsResponse {
@@ -2473,6 +5313,44 @@ pub struct ListCoreDefinitionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListCoreDefinitionsRequest {
+    /// Returns a builder for `ListCoreDefinitionsRequest`, checking its required members when built.
+    pub fn builder() -> ListCoreDefinitionsRequestBuilder {
+        ListCoreDefinitionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCoreDefinitionsRequest`](struct.ListCoreDefinitionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCoreDefinitionsRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListCoreDefinitionsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListCoreDefinitionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListCoreDefinitionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCoreDefinitionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListCoreDefinitionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListCoreDefinitionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListCoreDefinitionsResponse {
@@ -2501,6 +5379,52 @@ pub struct ListDeploymentsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDeploymentsRequest {
+    /// Returns a builder for `ListDeploymentsRequest`, checking its required members when built.
+    pub fn builder() -> ListDeploymentsRequestBuilder {
+        ListDeploymentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeploymentsRequest`](struct.ListDeploymentsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDeploymentsRequestBuilder {
+    group_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListDeploymentsRequestBuilder {
+    /// Sets the [`group_id`](struct.ListDeploymentsRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDeploymentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDeploymentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeploymentsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDeploymentsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDeploymentsRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDeploymentsResponse {
@@ -2529,9 +5453,57 @@ pub struct ListDeviceDefinitionVersionsRequest {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListDeviceDefinitionVersionsResponse {
+impl ListDeviceDefinitionVersionsRequest {
+    /// Returns a builder for `ListDeviceDefinitionVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListDeviceDefinitionVersionsRequestBuilder {
+        ListDeviceDefinitionVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeviceDefinitionVersionsRequest`](struct.ListDeviceDefinitionVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDeviceDefinitionVersionsRequestBuilder {
+    device_definition_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListDeviceDefinitionVersionsRequestBuilder {
+    /// Sets the [`device_definition_id`](struct.ListDeviceDefinitionVersionsRequest.html#structfield.device_definition_id) member.
+    pub fn device_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDeviceDefinitionVersionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDeviceDefinitionVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeviceDefinitionVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDeviceDefinitionVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDeviceDefinitionVersionsRequest {
+            device_definition_id: self
+                .device_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("device_definition_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListDeviceDefinitionVersionsResponse {
     /// <p>The token for the next set of results, or &#39;&#39;null&#39;&#39; if there are no additional results.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2554,6 +5526,44 @@ pub struct ListDeviceDefinitionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDeviceDefinitionsRequest {
+    /// Returns a builder for `ListDeviceDefinitionsRequest`, checking its required members when built.
+    pub fn builder() -> ListDeviceDefinitionsRequestBuilder {
+        ListDeviceDefinitionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeviceDefinitionsRequest`](struct.ListDeviceDefinitionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDeviceDefinitionsRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListDeviceDefinitionsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListDeviceDefinitionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDeviceDefinitionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeviceDefinitionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDeviceDefinitionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDeviceDefinitionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDeviceDefinitionsResponse {
@@ -2582,6 +5592,55 @@ pub struct ListFunctionDefinitionVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListFunctionDefinitionVersionsRequest {
+    /// Returns a builder for `ListFunctionDefinitionVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListFunctionDefinitionVersionsRequestBuilder {
+        ListFunctionDefinitionVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFunctionDefinitionVersionsRequest`](struct.ListFunctionDefinitionVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFunctionDefinitionVersionsRequestBuilder {
+    function_definition_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListFunctionDefinitionVersionsRequestBuilder {
+    /// Sets the [`function_definition_id`](struct.ListFunctionDefinitionVersionsRequest.html#structfield.function_definition_id) member.
+    pub fn function_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListFunctionDefinitionVersionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListFunctionDefinitionVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFunctionDefinitionVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListFunctionDefinitionVersionsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListFunctionDefinitionVersionsRequest {
+            function_definition_id: self
+                .function_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("function_definition_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListFunctionDefinitionVersionsResponse {
@@ -2607,6 +5666,44 @@ pub struct ListFunctionDefinitionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListFunctionDefinitionsRequest {
+    /// Returns a builder for `ListFunctionDefinitionsRequest`, checking its required members when built.
+    pub fn builder() -> ListFunctionDefinitionsRequestBuilder {
+        ListFunctionDefinitionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFunctionDefinitionsRequest`](struct.ListFunctionDefinitionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFunctionDefinitionsRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListFunctionDefinitionsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListFunctionDefinitionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListFunctionDefinitionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFunctionDefinitionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListFunctionDefinitionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListFunctionDefinitionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListFunctionDefinitionsResponse {
@@ -2627,6 +5724,39 @@ pub struct ListGroupCertificateAuthoritiesRequest {
     pub group_id: String,
 }
 
+impl ListGroupCertificateAuthoritiesRequest {
+    /// Returns a builder for `ListGroupCertificateAuthoritiesRequest`, checking its required members when built.
+    pub fn builder() -> ListGroupCertificateAuthoritiesRequestBuilder {
+        ListGroupCertificateAuthoritiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGroupCertificateAuthoritiesRequest`](struct.ListGroupCertificateAuthoritiesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGroupCertificateAuthoritiesRequestBuilder {
+    group_id: Option<String>,
+}
+
+impl ListGroupCertificateAuthoritiesRequestBuilder {
+    /// Sets the [`group_id`](struct.ListGroupCertificateAuthoritiesRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGroupCertificateAuthoritiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListGroupCertificateAuthoritiesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListGroupCertificateAuthoritiesRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGroupCertificateAuthoritiesResponse {
@@ -2651,6 +5781,54 @@ pub struct ListGroupVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListGroupVersionsRequest {
+    /// Returns a builder for `ListGroupVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListGroupVersionsRequestBuilder {
+        ListGroupVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGroupVersionsRequest`](struct.ListGroupVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGroupVersionsRequestBuilder {
+    group_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListGroupVersionsRequestBuilder {
+    /// Sets the [`group_id`](struct.ListGroupVersionsRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListGroupVersionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListGroupVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGroupVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListGroupVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListGroupVersionsRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGroupVersionsResponse {
@@ -2676,6 +5854,42 @@ pub struct ListGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListGroupsRequest {
+    /// Returns a builder for `ListGroupsRequest`, checking its required members when built.
+    pub fn builder() -> ListGroupsRequestBuilder {
+        ListGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGroupsRequest`](struct.ListGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGroupsRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListGroupsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListGroupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(ListGroupsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGroupsResponse {
@@ -2704,6 +5918,54 @@ pub struct ListLoggerDefinitionVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListLoggerDefinitionVersionsRequest {
+    /// Returns a builder for `ListLoggerDefinitionVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListLoggerDefinitionVersionsRequestBuilder {
+        ListLoggerDefinitionVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListLoggerDefinitionVersionsRequest`](struct.ListLoggerDefinitionVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListLoggerDefinitionVersionsRequestBuilder {
+    logger_definition_id: Option<String>,
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListLoggerDefinitionVersionsRequestBuilder {
+    /// Sets the [`logger_definition_id`](struct.ListLoggerDefinitionVersionsRequest.html#structfield.logger_definition_id) member.
+    pub fn logger_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.logger_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListLoggerDefinitionVersionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListLoggerDefinitionVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLoggerDefinitionVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListLoggerDefinitionVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListLoggerDefinitionVersionsRequest {
+            logger_definition_id: self
+                .logger_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("logger_definition_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLoggerDefinitionVersionsResponse {
@@ -2729,6 +5991,44 @@ pub struct ListLoggerDefinitionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListLoggerDefinitionsRequest {
+    /// Returns a builder for `ListLoggerDefinitionsRequest`, checking its required members when built.
+    pub fn builder() -> ListLoggerDefinitionsRequestBuilder {
+        ListLoggerDefinitionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListLoggerDefinitionsRequest`](struct.ListLoggerDefinitionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListLoggerDefinitionsRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListLoggerDefinitionsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListLoggerDefinitionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListLoggerDefinitionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLoggerDefinitionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListLoggerDefinitionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListLoggerDefinitionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLoggerDefinitionsResponse {
@@ -2757,6 +6057,55 @@ pub struct ListResourceDefinitionVersionsRequest {
     pub resource_definition_id: String,
 }
 
+impl ListResourceDefinitionVersionsRequest {
+    /// Returns a builder for `ListResourceDefinitionVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListResourceDefinitionVersionsRequestBuilder {
+        ListResourceDefinitionVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResourceDefinitionVersionsRequest`](struct.ListResourceDefinitionVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResourceDefinitionVersionsRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+    resource_definition_id: Option<String>,
+}
+
+impl ListResourceDefinitionVersionsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListResourceDefinitionVersionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListResourceDefinitionVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_definition_id`](struct.ListResourceDefinitionVersionsRequest.html#structfield.resource_definition_id) member.
+    pub fn resource_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResourceDefinitionVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListResourceDefinitionVersionsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListResourceDefinitionVersionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_definition_id: self
+                .resource_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_definition_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResourceDefinitionVersionsResponse {
@@ -2782,6 +6131,44 @@ pub struct ListResourceDefinitionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListResourceDefinitionsRequest {
+    /// Returns a builder for `ListResourceDefinitionsRequest`, checking its required members when built.
+    pub fn builder() -> ListResourceDefinitionsRequestBuilder {
+        ListResourceDefinitionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResourceDefinitionsRequest`](struct.ListResourceDefinitionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResourceDefinitionsRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListResourceDefinitionsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListResourceDefinitionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListResourceDefinitionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResourceDefinitionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListResourceDefinitionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListResourceDefinitionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResourceDefinitionsResponse {
@@ -2810,6 +6197,55 @@ pub struct ListSubscriptionDefinitionVersionsRequest {
     pub subscription_definition_id: String,
 }
 
+impl ListSubscriptionDefinitionVersionsRequest {
+    /// Returns a builder for `ListSubscriptionDefinitionVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListSubscriptionDefinitionVersionsRequestBuilder {
+        ListSubscriptionDefinitionVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSubscriptionDefinitionVersionsRequest`](struct.ListSubscriptionDefinitionVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSubscriptionDefinitionVersionsRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+    subscription_definition_id: Option<String>,
+}
+
+impl ListSubscriptionDefinitionVersionsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListSubscriptionDefinitionVersionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSubscriptionDefinitionVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_definition_id`](struct.ListSubscriptionDefinitionVersionsRequest.html#structfield.subscription_definition_id) member.
+    pub fn subscription_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSubscriptionDefinitionVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSubscriptionDefinitionVersionsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListSubscriptionDefinitionVersionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            subscription_definition_id: self.subscription_definition_id.ok_or(
+                ::rusoto_core::BuildError::missing("subscription_definition_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSubscriptionDefinitionVersionsResponse {
@@ -2835,6 +6271,44 @@ pub struct ListSubscriptionDefinitionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListSubscriptionDefinitionsRequest {
+    /// Returns a builder for `ListSubscriptionDefinitionsRequest`, checking its required members when built.
+    pub fn builder() -> ListSubscriptionDefinitionsRequestBuilder {
+        ListSubscriptionDefinitionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSubscriptionDefinitionsRequest`](struct.ListSubscriptionDefinitionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSubscriptionDefinitionsRequestBuilder {
+    max_results: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListSubscriptionDefinitionsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListSubscriptionDefinitionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<String>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSubscriptionDefinitionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSubscriptionDefinitionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSubscriptionDefinitionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListSubscriptionDefinitionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSubscriptionDefinitionsResponse {
@@ -2855,6 +6329,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -2894,24 +6400,102 @@ pub struct LocalVolumeResourceData {
 }
 
 /// <p>Information about a logger</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Logger {
     /// <p>The component that will be subject to logging.</p>
     #[serde(rename = "Component")]
-    pub component: String,
+    pub component: LoggerComponent,
     /// <p>A descriptive or arbitrary ID for the logger. This value must be unique within the logger definition version. Max length is 128 characters with pattern &#39;&#39;[a-zA-Z0-9:_-]+&#39;&#39;.</p>
     #[serde(rename = "Id")]
     pub id: String,
     /// <p>The level of the logs.</p>
     #[serde(rename = "Level")]
-    pub level: String,
+    pub level: LoggerLevel,
     /// <p>The amount of file space, in KB, to use if the local file system is used for logging purposes.</p>
     #[serde(rename = "Space")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub space: Option<i64>,
     /// <p>The type of log output which will be used.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: LoggerType,
+}
+
+/// <p>The component that will be subject to logging.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoggerComponent {
+    /// <p><code>GreengrassSystem</code></p>
+    GreengrassSystem,
+    /// <p><code>Lambda</code></p>
+    Lambda,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoggerComponent {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoggerComponent::GreengrassSystem => "GreengrassSystem",
+            LoggerComponent::Lambda => "Lambda",
+            LoggerComponent::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoggerComponent {
+    fn from(value: &'a str) -> LoggerComponent {
+        match value {
+            "GreengrassSystem" => LoggerComponent::GreengrassSystem,
+            "Lambda" => LoggerComponent::Lambda,
+            _ => LoggerComponent::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoggerComponent {
+    fn from(value: String) -> LoggerComponent {
+        LoggerComponent::from(value.as_str())
+    }
+}
+
+impl From<LoggerComponent> for String {
+    fn from(value: LoggerComponent) -> String {
+        match value {
+            LoggerComponent::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoggerComponent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoggerComponent {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoggerComponent {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoggerComponent {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LoggerComponent, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoggerComponent::from(value))
+    }
 }
 
 /// <p>Information about a logger definition version.</p>
@@ -2923,28 +6507,322 @@ pub struct LoggerDefinitionVersion {
     pub loggers: Option<Vec<Logger>>,
 }
 
-/// <p>Information needed to reset deployments.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ResetDeploymentsRequest {
-    /// <p>A client token used to correlate requests and responses.</p>
-    #[serde(rename = "AmznClientToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub amzn_client_token: Option<String>,
-    /// <p>If true, performs a best-effort only core reset.</p>
-    #[serde(rename = "Force")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub force: Option<bool>,
-    /// <p>The ID of the Greengrass group.</p>
-    #[serde(rename = "GroupId")]
-    pub group_id: String,
+/// <p>The level of the logs.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoggerLevel {
+    /// <p><code>DEBUG</code></p>
+    Debug,
+    /// <p><code>INFO</code></p>
+    Info,
+    /// <p><code>WARN</code></p>
+    Warn,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// <p><code>FATAL</code></p>
+    Fatal,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoggerLevel {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoggerLevel::Debug => "DEBUG",
+            LoggerLevel::Info => "INFO",
+            LoggerLevel::Warn => "WARN",
+            LoggerLevel::Error => "ERROR",
+            LoggerLevel::Fatal => "FATAL",
+            LoggerLevel::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ResetDeploymentsResponse {
-    /// <p>The ARN of the deployment.</p>
-    #[serde(rename = "DeploymentArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl<'a> From<&'a str> for LoggerLevel {
+    fn from(value: &'a str) -> LoggerLevel {
+        match value {
+            "DEBUG" => LoggerLevel::Debug,
+            "INFO" => LoggerLevel::Info,
+            "WARN" => LoggerLevel::Warn,
+            "ERROR" => LoggerLevel::Error,
+            "FATAL" => LoggerLevel::Fatal,
+            _ => LoggerLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoggerLevel {
+    fn from(value: String) -> LoggerLevel {
+        LoggerLevel::from(value.as_str())
+    }
+}
+
+impl From<LoggerLevel> for String {
+    fn from(value: LoggerLevel) -> String {
+        match value {
+            LoggerLevel::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoggerLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoggerLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoggerLevel {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoggerLevel {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LoggerLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoggerLevel::from(value))
+    }
+}
+
+/// <p>The type of log output which will be used.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoggerType {
+    /// <p><code>FileSystem</code></p>
+    FileSystem,
+    /// <p><code>AWSCloudWatch</code></p>
+    AWSCloudWatch,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoggerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoggerType::FileSystem => "FileSystem",
+            LoggerType::AWSCloudWatch => "AWSCloudWatch",
+            LoggerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoggerType {
+    fn from(value: &'a str) -> LoggerType {
+        match value {
+            "FileSystem" => LoggerType::FileSystem,
+            "AWSCloudWatch" => LoggerType::AWSCloudWatch,
+            _ => LoggerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoggerType {
+    fn from(value: String) -> LoggerType {
+        LoggerType::from(value.as_str())
+    }
+}
+
+impl From<LoggerType> for String {
+    fn from(value: LoggerType) -> String {
+        match value {
+            LoggerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoggerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoggerType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoggerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoggerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LoggerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoggerType::from(value))
+    }
+}
+
+/// The type of permission a function has to access a resource.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Permission {
+    /// <p><code>ro</code></p>
+    Ro,
+    /// <p><code>rw</code></p>
+    Rw,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Permission {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Permission::Ro => "ro",
+            Permission::Rw => "rw",
+            Permission::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Permission {
+    fn from(value: &'a str) -> Permission {
+        match value {
+            "ro" => Permission::Ro,
+            "rw" => Permission::Rw,
+            _ => Permission::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Permission {
+    fn from(value: String) -> Permission {
+        Permission::from(value.as_str())
+    }
+}
+
+impl From<Permission> for String {
+    fn from(value: Permission) -> String {
+        match value {
+            Permission::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Permission {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Permission {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Permission {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Permission, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Permission::from(value))
+    }
+}
+
+/// <p>Information needed to reset deployments.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ResetDeploymentsRequest {
+    /// <p>A client token used to correlate requests and responses.</p>
+    #[serde(rename = "AmznClientToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amzn_client_token: Option<String>,
+    /// <p>If true, performs a best-effort only core reset.</p>
+    #[serde(rename = "Force")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    /// <p>The ID of the Greengrass group.</p>
+    #[serde(rename = "GroupId")]
+    pub group_id: String,
+}
+
+impl ResetDeploymentsRequest {
+    /// Returns a builder for `ResetDeploymentsRequest`, checking its required members when built.
+    pub fn builder() -> ResetDeploymentsRequestBuilder {
+        ResetDeploymentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ResetDeploymentsRequest`](struct.ResetDeploymentsRequest.html).
+#[derive(Default, Debug)]
+pub struct ResetDeploymentsRequestBuilder {
+    amzn_client_token: Option<String>,
+    force: Option<bool>,
+    group_id: Option<String>,
+}
+
+impl ResetDeploymentsRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.ResetDeploymentsRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force`](struct.ResetDeploymentsRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_id`](struct.ResetDeploymentsRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetDeploymentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResetDeploymentsRequest, ::rusoto_core::BuildError> {
+        Ok(ResetDeploymentsRequest {
+            amzn_client_token: self.amzn_client_token,
+            force: self.force,
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ResetDeploymentsResponse {
+    /// <p>The ARN of the deployment.</p>
+    #[serde(rename = "DeploymentArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deployment_arn: Option<String>,
     /// <p>The ID of the deployment.</p>
     #[serde(rename = "DeploymentId")]
@@ -2972,7 +6850,7 @@ pub struct ResourceAccessPolicy {
     /// <p>The permissions that the Lambda function has to the resource. Can be one of &#39;&#39;rw&#39;&#39; (read/write) or &#39;&#39;ro&#39;&#39; (read-only).</p>
     #[serde(rename = "Permission")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub permission: Option<String>,
+    pub permission: Option<Permission>,
     /// <p>The ID of the resource. (This ID is assigned to the resource when you create the resource definiton.)</p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
@@ -3052,6 +6930,84 @@ pub struct SecretsManagerSecretResourceData {
     pub additional_staging_labels_to_download: Option<Vec<String>>,
 }
 
+/// The piece of software on the Greengrass core that will be updated.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SoftwareToUpdate {
+    /// <p><code>core</code></p>
+    Core,
+    /// <p><code>ota_agent</code></p>
+    OtaAgent,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SoftwareToUpdate {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SoftwareToUpdate::Core => "core",
+            SoftwareToUpdate::OtaAgent => "ota_agent",
+            SoftwareToUpdate::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SoftwareToUpdate {
+    fn from(value: &'a str) -> SoftwareToUpdate {
+        match value {
+            "core" => SoftwareToUpdate::Core,
+            "ota_agent" => SoftwareToUpdate::OtaAgent,
+            _ => SoftwareToUpdate::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SoftwareToUpdate {
+    fn from(value: String) -> SoftwareToUpdate {
+        SoftwareToUpdate::from(value.as_str())
+    }
+}
+
+impl From<SoftwareToUpdate> for String {
+    fn from(value: SoftwareToUpdate) -> String {
+        match value {
+            SoftwareToUpdate::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SoftwareToUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SoftwareToUpdate {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SoftwareToUpdate {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SoftwareToUpdate {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SoftwareToUpdate, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SoftwareToUpdate::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartBulkDeploymentRequest {
     /// <p>A client token used to correlate requests and responses.</p>
@@ -3070,6 +7026,64 @@ pub struct StartBulkDeploymentRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl StartBulkDeploymentRequest {
+    /// Returns a builder for `StartBulkDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> StartBulkDeploymentRequestBuilder {
+        StartBulkDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartBulkDeploymentRequest`](struct.StartBulkDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct StartBulkDeploymentRequestBuilder {
+    amzn_client_token: Option<String>,
+    execution_role_arn: Option<String>,
+    input_file_uri: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl StartBulkDeploymentRequestBuilder {
+    /// Sets the [`amzn_client_token`](struct.StartBulkDeploymentRequest.html#structfield.amzn_client_token) member.
+    pub fn amzn_client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.amzn_client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execution_role_arn`](struct.StartBulkDeploymentRequest.html#structfield.execution_role_arn) member.
+    pub fn execution_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.execution_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_file_uri`](struct.StartBulkDeploymentRequest.html#structfield.input_file_uri) member.
+    pub fn input_file_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.input_file_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.StartBulkDeploymentRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartBulkDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartBulkDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(StartBulkDeploymentRequest {
+            amzn_client_token: self.amzn_client_token,
+            execution_role_arn: self
+                .execution_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("execution_role_arn"))?,
+            input_file_uri: self
+                .input_file_uri
+                .ok_or(::rusoto_core::BuildError::missing("input_file_uri"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartBulkDeploymentResponse {
@@ -3090,6 +7104,38 @@ pub struct StopBulkDeploymentRequest {
     pub bulk_deployment_id: String,
 }
 
+impl StopBulkDeploymentRequest {
+    /// Returns a builder for `StopBulkDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> StopBulkDeploymentRequestBuilder {
+        StopBulkDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopBulkDeploymentRequest`](struct.StopBulkDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct StopBulkDeploymentRequestBuilder {
+    bulk_deployment_id: Option<String>,
+}
+
+impl StopBulkDeploymentRequestBuilder {
+    /// Sets the [`bulk_deployment_id`](struct.StopBulkDeploymentRequest.html#structfield.bulk_deployment_id) member.
+    pub fn bulk_deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bulk_deployment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopBulkDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopBulkDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(StopBulkDeploymentRequest {
+            bulk_deployment_id: self
+                .bulk_deployment_id
+                .ok_or(::rusoto_core::BuildError::missing("bulk_deployment_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopBulkDeploymentResponse {}
@@ -3131,6 +7177,44 @@ pub struct TagResourceRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) of the resource.</p>
@@ -3141,47 +7225,269 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
-/// <p>Connectivity information.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateConnectivityInfoRequest {
-    /// <p>A list of connectivity info.</p>
-    #[serde(rename = "ConnectivityInfo")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub connectivity_info: Option<Vec<ConnectivityInfo>>,
-    /// <p>The thing name.</p>
-    #[serde(rename = "ThingName")]
-    pub thing_name: String,
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateConnectivityInfoResponse {
-    /// <p>A message about the connectivity info update request.</p>
-    #[serde(rename = "Message")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
-    /// <p>The new version of the connectivity info.</p>
-    #[serde(rename = "Version")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub version: Option<String>,
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateConnectorDefinitionRequest {
-    /// <p>The ID of the connector definition.</p>
-    #[serde(rename = "ConnectorDefinitionId")]
-    pub connector_definition_id: String,
-    /// <p>The name of the definition.</p>
-    #[serde(rename = "Name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateConnectorDefinitionResponse {}
+/// The minimum level of log statements that should be logged by the OTA Agent during an update.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateAgentLogLevel {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>TRACE</code></p>
+    Trace,
+    /// <p><code>DEBUG</code></p>
+    Debug,
+    /// <p><code>VERBOSE</code></p>
+    Verbose,
+    /// <p><code>INFO</code></p>
+    Info,
+    /// <p><code>WARN</code></p>
+    Warn,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// <p><code>FATAL</code></p>
+    Fatal,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UpdateAgentLogLevel {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateAgentLogLevel::None => "NONE",
+            UpdateAgentLogLevel::Trace => "TRACE",
+            UpdateAgentLogLevel::Debug => "DEBUG",
+            UpdateAgentLogLevel::Verbose => "VERBOSE",
+            UpdateAgentLogLevel::Info => "INFO",
+            UpdateAgentLogLevel::Warn => "WARN",
+            UpdateAgentLogLevel::Error => "ERROR",
+            UpdateAgentLogLevel::Fatal => "FATAL",
+            UpdateAgentLogLevel::Unknown(ref value) => value,
+        }
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl<'a> From<&'a str> for UpdateAgentLogLevel {
+    fn from(value: &'a str) -> UpdateAgentLogLevel {
+        match value {
+            "NONE" => UpdateAgentLogLevel::None,
+            "TRACE" => UpdateAgentLogLevel::Trace,
+            "DEBUG" => UpdateAgentLogLevel::Debug,
+            "VERBOSE" => UpdateAgentLogLevel::Verbose,
+            "INFO" => UpdateAgentLogLevel::Info,
+            "WARN" => UpdateAgentLogLevel::Warn,
+            "ERROR" => UpdateAgentLogLevel::Error,
+            "FATAL" => UpdateAgentLogLevel::Fatal,
+            _ => UpdateAgentLogLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateAgentLogLevel {
+    fn from(value: String) -> UpdateAgentLogLevel {
+        UpdateAgentLogLevel::from(value.as_str())
+    }
+}
+
+impl From<UpdateAgentLogLevel> for String {
+    fn from(value: UpdateAgentLogLevel) -> String {
+        match value {
+            UpdateAgentLogLevel::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateAgentLogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateAgentLogLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateAgentLogLevel {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateAgentLogLevel {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UpdateAgentLogLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UpdateAgentLogLevel::from(value))
+    }
+}
+
+/// <p>Connectivity information.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateConnectivityInfoRequest {
+    /// <p>A list of connectivity info.</p>
+    #[serde(rename = "ConnectivityInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connectivity_info: Option<Vec<ConnectivityInfo>>,
+    /// <p>The thing name.</p>
+    #[serde(rename = "ThingName")]
+    pub thing_name: String,
+}
+
+impl UpdateConnectivityInfoRequest {
+    /// Returns a builder for `UpdateConnectivityInfoRequest`, checking its required members when built.
+    pub fn builder() -> UpdateConnectivityInfoRequestBuilder {
+        UpdateConnectivityInfoRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateConnectivityInfoRequest`](struct.UpdateConnectivityInfoRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateConnectivityInfoRequestBuilder {
+    connectivity_info: Option<Vec<ConnectivityInfo>>,
+    thing_name: Option<String>,
+}
+
+impl UpdateConnectivityInfoRequestBuilder {
+    /// Sets the [`connectivity_info`](struct.UpdateConnectivityInfoRequest.html#structfield.connectivity_info) member.
+    pub fn connectivity_info<T: Into<Vec<ConnectivityInfo>>>(mut self, value: T) -> Self {
+        self.connectivity_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.UpdateConnectivityInfoRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateConnectivityInfoRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateConnectivityInfoRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateConnectivityInfoRequest {
+            connectivity_info: self.connectivity_info,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateConnectivityInfoResponse {
+    /// <p>A message about the connectivity info update request.</p>
+    #[serde(rename = "Message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// <p>The new version of the connectivity info.</p>
+    #[serde(rename = "Version")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateConnectorDefinitionRequest {
+    /// <p>The ID of the connector definition.</p>
+    #[serde(rename = "ConnectorDefinitionId")]
+    pub connector_definition_id: String,
+    /// <p>The name of the definition.</p>
+    #[serde(rename = "Name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl UpdateConnectorDefinitionRequest {
+    /// Returns a builder for `UpdateConnectorDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateConnectorDefinitionRequestBuilder {
+        UpdateConnectorDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateConnectorDefinitionRequest`](struct.UpdateConnectorDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateConnectorDefinitionRequestBuilder {
+    connector_definition_id: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateConnectorDefinitionRequestBuilder {
+    /// Sets the [`connector_definition_id`](struct.UpdateConnectorDefinitionRequest.html#structfield.connector_definition_id) member.
+    pub fn connector_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.connector_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateConnectorDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateConnectorDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateConnectorDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateConnectorDefinitionRequest {
+            connector_definition_id: self.connector_definition_id.ok_or(
+                ::rusoto_core::BuildError::missing("connector_definition_id"),
+            )?,
+            name: self.name,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateConnectorDefinitionResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateCoreDefinitionRequest {
     /// <p>The ID of the core definition.</p>
     #[serde(rename = "CoreDefinitionId")]
@@ -3192,6 +7498,46 @@ pub struct UpdateCoreDefinitionRequest {
     pub name: Option<String>,
 }
 
+impl UpdateCoreDefinitionRequest {
+    /// Returns a builder for `UpdateCoreDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateCoreDefinitionRequestBuilder {
+        UpdateCoreDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateCoreDefinitionRequest`](struct.UpdateCoreDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateCoreDefinitionRequestBuilder {
+    core_definition_id: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateCoreDefinitionRequestBuilder {
+    /// Sets the [`core_definition_id`](struct.UpdateCoreDefinitionRequest.html#structfield.core_definition_id) member.
+    pub fn core_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.core_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateCoreDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateCoreDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateCoreDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateCoreDefinitionRequest {
+            core_definition_id: self
+                .core_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("core_definition_id"))?,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateCoreDefinitionResponse {}
@@ -3207,6 +7553,46 @@ pub struct UpdateDeviceDefinitionRequest {
     pub name: Option<String>,
 }
 
+impl UpdateDeviceDefinitionRequest {
+    /// Returns a builder for `UpdateDeviceDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDeviceDefinitionRequestBuilder {
+        UpdateDeviceDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDeviceDefinitionRequest`](struct.UpdateDeviceDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDeviceDefinitionRequestBuilder {
+    device_definition_id: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateDeviceDefinitionRequestBuilder {
+    /// Sets the [`device_definition_id`](struct.UpdateDeviceDefinitionRequest.html#structfield.device_definition_id) member.
+    pub fn device_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateDeviceDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDeviceDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDeviceDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDeviceDefinitionRequest {
+            device_definition_id: self
+                .device_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("device_definition_id"))?,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDeviceDefinitionResponse {}
@@ -3222,6 +7608,46 @@ pub struct UpdateFunctionDefinitionRequest {
     pub name: Option<String>,
 }
 
+impl UpdateFunctionDefinitionRequest {
+    /// Returns a builder for `UpdateFunctionDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFunctionDefinitionRequestBuilder {
+        UpdateFunctionDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFunctionDefinitionRequest`](struct.UpdateFunctionDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFunctionDefinitionRequestBuilder {
+    function_definition_id: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateFunctionDefinitionRequestBuilder {
+    /// Sets the [`function_definition_id`](struct.UpdateFunctionDefinitionRequest.html#structfield.function_definition_id) member.
+    pub fn function_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateFunctionDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFunctionDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateFunctionDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFunctionDefinitionRequest {
+            function_definition_id: self
+                .function_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("function_definition_id"))?,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateFunctionDefinitionResponse {}
@@ -3237,6 +7663,47 @@ pub struct UpdateGroupCertificateConfigurationRequest {
     pub group_id: String,
 }
 
+impl UpdateGroupCertificateConfigurationRequest {
+    /// Returns a builder for `UpdateGroupCertificateConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateGroupCertificateConfigurationRequestBuilder {
+        UpdateGroupCertificateConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGroupCertificateConfigurationRequest`](struct.UpdateGroupCertificateConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateGroupCertificateConfigurationRequestBuilder {
+    certificate_expiry_in_milliseconds: Option<String>,
+    group_id: Option<String>,
+}
+
+impl UpdateGroupCertificateConfigurationRequestBuilder {
+    /// Sets the [`certificate_expiry_in_milliseconds`](struct.UpdateGroupCertificateConfigurationRequest.html#structfield.certificate_expiry_in_milliseconds) member.
+    pub fn certificate_expiry_in_milliseconds<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_expiry_in_milliseconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_id`](struct.UpdateGroupCertificateConfigurationRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGroupCertificateConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateGroupCertificateConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateGroupCertificateConfigurationRequest {
+            certificate_expiry_in_milliseconds: self.certificate_expiry_in_milliseconds,
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateGroupCertificateConfigurationResponse {
@@ -3265,6 +7732,44 @@ pub struct UpdateGroupRequest {
     pub name: Option<String>,
 }
 
+impl UpdateGroupRequest {
+    /// Returns a builder for `UpdateGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateGroupRequestBuilder {
+        UpdateGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGroupRequest`](struct.UpdateGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateGroupRequestBuilder {
+    group_id: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateGroupRequestBuilder {
+    /// Sets the [`group_id`](struct.UpdateGroupRequest.html#structfield.group_id) member.
+    pub fn group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateGroupRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateGroupRequest {
+            group_id: self
+                .group_id
+                .ok_or(::rusoto_core::BuildError::missing("group_id"))?,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateGroupResponse {}
@@ -3280,6 +7785,46 @@ pub struct UpdateLoggerDefinitionRequest {
     pub name: Option<String>,
 }
 
+impl UpdateLoggerDefinitionRequest {
+    /// Returns a builder for `UpdateLoggerDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateLoggerDefinitionRequestBuilder {
+        UpdateLoggerDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateLoggerDefinitionRequest`](struct.UpdateLoggerDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateLoggerDefinitionRequestBuilder {
+    logger_definition_id: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateLoggerDefinitionRequestBuilder {
+    /// Sets the [`logger_definition_id`](struct.UpdateLoggerDefinitionRequest.html#structfield.logger_definition_id) member.
+    pub fn logger_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.logger_definition_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateLoggerDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateLoggerDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateLoggerDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateLoggerDefinitionRequest {
+            logger_definition_id: self
+                .logger_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("logger_definition_id"))?,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateLoggerDefinitionResponse {}
@@ -3295,6 +7840,46 @@ pub struct UpdateResourceDefinitionRequest {
     pub resource_definition_id: String,
 }
 
+impl UpdateResourceDefinitionRequest {
+    /// Returns a builder for `UpdateResourceDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateResourceDefinitionRequestBuilder {
+        UpdateResourceDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateResourceDefinitionRequest`](struct.UpdateResourceDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateResourceDefinitionRequestBuilder {
+    name: Option<String>,
+    resource_definition_id: Option<String>,
+}
+
+impl UpdateResourceDefinitionRequestBuilder {
+    /// Sets the [`name`](struct.UpdateResourceDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_definition_id`](struct.UpdateResourceDefinitionRequest.html#structfield.resource_definition_id) member.
+    pub fn resource_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateResourceDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateResourceDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateResourceDefinitionRequest {
+            name: self.name,
+            resource_definition_id: self
+                .resource_definition_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_definition_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateResourceDefinitionResponse {}
@@ -3310,67 +7895,281 @@ pub struct UpdateSubscriptionDefinitionRequest {
     pub subscription_definition_id: String,
 }
 
+impl UpdateSubscriptionDefinitionRequest {
+    /// Returns a builder for `UpdateSubscriptionDefinitionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSubscriptionDefinitionRequestBuilder {
+        UpdateSubscriptionDefinitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSubscriptionDefinitionRequest`](struct.UpdateSubscriptionDefinitionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSubscriptionDefinitionRequestBuilder {
+    name: Option<String>,
+    subscription_definition_id: Option<String>,
+}
+
+impl UpdateSubscriptionDefinitionRequestBuilder {
+    /// Sets the [`name`](struct.UpdateSubscriptionDefinitionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_definition_id`](struct.UpdateSubscriptionDefinitionRequest.html#structfield.subscription_definition_id) member.
+    pub fn subscription_definition_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_definition_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSubscriptionDefinitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSubscriptionDefinitionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSubscriptionDefinitionRequest {
+            name: self.name,
+            subscription_definition_id: self.subscription_definition_id.ok_or(
+                ::rusoto_core::BuildError::missing("subscription_definition_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateSubscriptionDefinitionResponse {}
 
-/// <p>Information about a version.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct VersionInformation {
-    /// <p>The ARN of the version.</p>
-    #[serde(rename = "Arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The time, in milliseconds since the epoch, when the version was created.</p>
-    #[serde(rename = "CreationTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_timestamp: Option<String>,
-    /// <p>The ID of the version.</p>
-    #[serde(rename = "Id")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
-    /// <p>The unique ID of the version.</p>
-    #[serde(rename = "Version")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub version: Option<String>,
+/// The architecture of the cores which are the targets of an update.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateTargetsArchitecture {
+    /// <p><code>armv6l</code></p>
+    Armv6l,
+    /// <p><code>armv7l</code></p>
+    Armv7l,
+    /// <p><code>x86_64</code></p>
+    X8664,
+    /// <p><code>aarch64</code></p>
+    Aarch64,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UpdateTargetsArchitecture {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateTargetsArchitecture::Armv6l => "armv6l",
+            UpdateTargetsArchitecture::Armv7l => "armv7l",
+            UpdateTargetsArchitecture::X8664 => "x86_64",
+            UpdateTargetsArchitecture::Aarch64 => "aarch64",
+            UpdateTargetsArchitecture::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// Errors returned by AssociateRoleToGroup
-#[derive(Debug, PartialEq)]
-pub enum AssociateRoleToGroupError {
-    /// <p>General error information.</p>
-    BadRequest(String),
-    /// <p>General error information.</p>
-    InternalServerError(String),
+impl<'a> From<&'a str> for UpdateTargetsArchitecture {
+    fn from(value: &'a str) -> UpdateTargetsArchitecture {
+        match value {
+            "armv6l" => UpdateTargetsArchitecture::Armv6l,
+            "armv7l" => UpdateTargetsArchitecture::Armv7l,
+            "x86_64" => UpdateTargetsArchitecture::X8664,
+            "aarch64" => UpdateTargetsArchitecture::Aarch64,
+            _ => UpdateTargetsArchitecture::Unknown(value.to_owned()),
+        }
+    }
 }
 
-impl AssociateRoleToGroupError {
-    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<AssociateRoleToGroupError> {
-        if let Some(err) = proto::json::Error::parse_rest(&res) {
-            match err.typ.as_str() {
-                "BadRequestException" => {
-                    return RusotoError::Service(AssociateRoleToGroupError::BadRequest(err.msg))
-                }
-                "InternalServerErrorException" => {
-                    return RusotoError::Service(AssociateRoleToGroupError::InternalServerError(
-                        err.msg,
-                    ))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
-                _ => {}
-            }
+impl From<String> for UpdateTargetsArchitecture {
+    fn from(value: String) -> UpdateTargetsArchitecture {
+        UpdateTargetsArchitecture::from(value.as_str())
+    }
+}
+
+impl From<UpdateTargetsArchitecture> for String {
+    fn from(value: UpdateTargetsArchitecture) -> String {
+        match value {
+            UpdateTargetsArchitecture::Unknown(value) => value,
+            value => value.as_str().to_owned(),
         }
-        return RusotoError::Unknown(res);
     }
 }
-impl fmt::Display for AssociateRoleToGroupError {
+
+impl fmt::Display for UpdateTargetsArchitecture {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        write!(f, "{}", self.as_str())
     }
 }
-impl Error for AssociateRoleToGroupError {
-    fn description(&self) -> &str {
+
+impl ::rusoto_core::param::ToParam for UpdateTargetsArchitecture {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateTargetsArchitecture {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateTargetsArchitecture {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UpdateTargetsArchitecture, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UpdateTargetsArchitecture::from(value))
+    }
+}
+
+/// The operating system of the cores which are the targets of an update.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateTargetsOperatingSystem {
+    /// <p><code>ubuntu</code></p>
+    Ubuntu,
+    /// <p><code>raspbian</code></p>
+    Raspbian,
+    /// <p><code>amazon_linux</code></p>
+    AmazonLinux,
+    /// <p><code>openwrt</code></p>
+    Openwrt,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UpdateTargetsOperatingSystem {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateTargetsOperatingSystem::Ubuntu => "ubuntu",
+            UpdateTargetsOperatingSystem::Raspbian => "raspbian",
+            UpdateTargetsOperatingSystem::AmazonLinux => "amazon_linux",
+            UpdateTargetsOperatingSystem::Openwrt => "openwrt",
+            UpdateTargetsOperatingSystem::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UpdateTargetsOperatingSystem {
+    fn from(value: &'a str) -> UpdateTargetsOperatingSystem {
+        match value {
+            "ubuntu" => UpdateTargetsOperatingSystem::Ubuntu,
+            "raspbian" => UpdateTargetsOperatingSystem::Raspbian,
+            "amazon_linux" => UpdateTargetsOperatingSystem::AmazonLinux,
+            "openwrt" => UpdateTargetsOperatingSystem::Openwrt,
+            _ => UpdateTargetsOperatingSystem::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateTargetsOperatingSystem {
+    fn from(value: String) -> UpdateTargetsOperatingSystem {
+        UpdateTargetsOperatingSystem::from(value.as_str())
+    }
+}
+
+impl From<UpdateTargetsOperatingSystem> for String {
+    fn from(value: UpdateTargetsOperatingSystem) -> String {
+        match value {
+            UpdateTargetsOperatingSystem::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateTargetsOperatingSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateTargetsOperatingSystem {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateTargetsOperatingSystem {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateTargetsOperatingSystem {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<UpdateTargetsOperatingSystem, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UpdateTargetsOperatingSystem::from(value))
+    }
+}
+
+/// <p>Information about a version.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct VersionInformation {
+    /// <p>The ARN of the version.</p>
+    #[serde(rename = "Arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The time, in milliseconds since the epoch, when the version was created.</p>
+    #[serde(rename = "CreationTimestamp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_timestamp: Option<String>,
+    /// <p>The ID of the version.</p>
+    #[serde(rename = "Id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// <p>The unique ID of the version.</p>
+    #[serde(rename = "Version")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// Errors returned by AssociateRoleToGroup
+#[derive(Debug, PartialEq)]
+pub enum AssociateRoleToGroupError {
+    /// <p>General error information.</p>
+    BadRequest(String),
+    /// <p>General error information.</p>
+    InternalServerError(String),
+}
+
+impl AssociateRoleToGroupError {
+    pub fn from_response(res: BufferedHttpResponse) -> RusotoError<AssociateRoleToGroupError> {
+        if let Some(err) = proto::json::Error::parse_rest(&res) {
+            match err.typ.as_str() {
+                "BadRequestException" => {
+                    return RusotoError::Service(AssociateRoleToGroupError::BadRequest(err.msg))
+                }
+                "InternalServerErrorException" => {
+                    return RusotoError::Service(AssociateRoleToGroupError::InternalServerError(
+                        err.msg,
+                    ))
+                }
+                "ValidationException" => return RusotoError::Validation(err.msg),
+                _ => {}
+            }
+        }
+        return RusotoError::Unknown(res);
+    }
+}
+impl fmt::Display for AssociateRoleToGroupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+impl Error for AssociateRoleToGroupError {
+    fn description(&self) -> &str {
         match *self {
             AssociateRoleToGroupError::BadRequest(ref cause) => cause,
             AssociateRoleToGroupError::InternalServerError(ref cause) => cause,
@@ -6760,268 +11559,835 @@ pub trait GreenGrass {
     fn get_group_version(
         &self,
         input: GetGroupVersionRequest,
-    ) -> RusotoFuture<GetGroupVersionResponse, GetGroupVersionError>;
-
-    /// <p>Retrieves information about a logger definition.</p>
+    ) -> RusotoFuture<GetGroupVersionResponse, GetGroupVersionError>;
+
+    /// <p>Retrieves information about a logger definition.</p>
+    fn get_logger_definition(
+        &self,
+        input: GetLoggerDefinitionRequest,
+    ) -> RusotoFuture<GetLoggerDefinitionResponse, GetLoggerDefinitionError>;
+
+    /// <p>Retrieves information about a logger definition version.</p>
+    fn get_logger_definition_version(
+        &self,
+        input: GetLoggerDefinitionVersionRequest,
+    ) -> RusotoFuture<GetLoggerDefinitionVersionResponse, GetLoggerDefinitionVersionError>;
+
+    /// <p>Retrieves information about a resource definition, including its creation time and latest version.</p>
+    fn get_resource_definition(
+        &self,
+        input: GetResourceDefinitionRequest,
+    ) -> RusotoFuture<GetResourceDefinitionResponse, GetResourceDefinitionError>;
+
+    /// <p>Retrieves information about a resource definition version, including which resources are included in the version.</p>
+    fn get_resource_definition_version(
+        &self,
+        input: GetResourceDefinitionVersionRequest,
+    ) -> RusotoFuture<GetResourceDefinitionVersionResponse, GetResourceDefinitionVersionError>;
+
+    /// <p>Retrieves the service role that is attached to your account.</p>
+    fn get_service_role_for_account(
+        &self,
+    ) -> RusotoFuture<GetServiceRoleForAccountResponse, GetServiceRoleForAccountError>;
+
+    /// <p>Retrieves information about a subscription definition.</p>
+    fn get_subscription_definition(
+        &self,
+        input: GetSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<GetSubscriptionDefinitionResponse, GetSubscriptionDefinitionError>;
+
+    /// <p>Retrieves information about a subscription definition version.</p>
+    fn get_subscription_definition_version(
+        &self,
+        input: GetSubscriptionDefinitionVersionRequest,
+    ) -> RusotoFuture<GetSubscriptionDefinitionVersionResponse, GetSubscriptionDefinitionVersionError>;
+
+    /// <p>Gets a paginated list of the deployments that have been started in a bulk deployment operation, and their current deployment status.</p>
+    fn list_bulk_deployment_detailed_reports(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> RusotoFuture<
+        ListBulkDeploymentDetailedReportsResponse,
+        ListBulkDeploymentDetailedReportsError,
+    >;
+
+    /// <p>Returns a list of bulk deployments.</p>
+    fn list_bulk_deployments(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> RusotoFuture<ListBulkDeploymentsResponse, ListBulkDeploymentsError>;
+
+    /// <p>Lists the versions of a connector definition, which are containers for connectors. Connectors run on the Greengrass core and contain built-in integration with local infrastructure, device protocols, AWS, and other cloud services.</p>
+    fn list_connector_definition_versions(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListConnectorDefinitionVersionsResponse, ListConnectorDefinitionVersionsError>;
+
+    /// <p>Retrieves a list of connector definitions.</p>
+    fn list_connector_definitions(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> RusotoFuture<ListConnectorDefinitionsResponse, ListConnectorDefinitionsError>;
+
+    /// <p>Lists the versions of a core definition.</p>
+    fn list_core_definition_versions(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListCoreDefinitionVersionsResponse, ListCoreDefinitionVersionsError>;
+
+    /// <p>Retrieves a list of core definitions.</p>
+    fn list_core_definitions(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> RusotoFuture<ListCoreDefinitionsResponse, ListCoreDefinitionsError>;
+
+    /// <p>Returns a history of deployments for the group.</p>
+    fn list_deployments(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> RusotoFuture<ListDeploymentsResponse, ListDeploymentsError>;
+
+    /// <p>Lists the versions of a device definition.</p>
+    fn list_device_definition_versions(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListDeviceDefinitionVersionsResponse, ListDeviceDefinitionVersionsError>;
+
+    /// <p>Retrieves a list of device definitions.</p>
+    fn list_device_definitions(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> RusotoFuture<ListDeviceDefinitionsResponse, ListDeviceDefinitionsError>;
+
+    /// <p>Lists the versions of a Lambda function definition.</p>
+    fn list_function_definition_versions(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListFunctionDefinitionVersionsResponse, ListFunctionDefinitionVersionsError>;
+
+    /// <p>Retrieves a list of Lambda function definitions.</p>
+    fn list_function_definitions(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> RusotoFuture<ListFunctionDefinitionsResponse, ListFunctionDefinitionsError>;
+
+    /// <p>Retrieves the current CAs for a group.</p>
+    fn list_group_certificate_authorities(
+        &self,
+        input: ListGroupCertificateAuthoritiesRequest,
+    ) -> RusotoFuture<ListGroupCertificateAuthoritiesResponse, ListGroupCertificateAuthoritiesError>;
+
+    /// <p>Lists the versions of a group.</p>
+    fn list_group_versions(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> RusotoFuture<ListGroupVersionsResponse, ListGroupVersionsError>;
+
+    /// <p>Retrieves a list of groups.</p>
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError>;
+
+    /// <p>Lists the versions of a logger definition.</p>
+    fn list_logger_definition_versions(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListLoggerDefinitionVersionsResponse, ListLoggerDefinitionVersionsError>;
+
+    /// <p>Retrieves a list of logger definitions.</p>
+    fn list_logger_definitions(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> RusotoFuture<ListLoggerDefinitionsResponse, ListLoggerDefinitionsError>;
+
+    /// <p>Lists the versions of a resource definition.</p>
+    fn list_resource_definition_versions(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> RusotoFuture<ListResourceDefinitionVersionsResponse, ListResourceDefinitionVersionsError>;
+
+    /// <p>Retrieves a list of resource definitions.</p>
+    fn list_resource_definitions(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> RusotoFuture<ListResourceDefinitionsResponse, ListResourceDefinitionsError>;
+
+    /// <p>Lists the versions of a subscription definition.</p>
+    fn list_subscription_definition_versions(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> RusotoFuture<
+        ListSubscriptionDefinitionVersionsResponse,
+        ListSubscriptionDefinitionVersionsError,
+    >;
+
+    /// <p>Retrieves a list of subscription definitions.</p>
+    fn list_subscription_definitions(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> RusotoFuture<ListSubscriptionDefinitionsResponse, ListSubscriptionDefinitionsError>;
+
+    /// <p>Retrieves a list of resource tags for a resource arn.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
+
+    /// <p>Resets a group&#39;s deployments.</p>
+    fn reset_deployments(
+        &self,
+        input: ResetDeploymentsRequest,
+    ) -> RusotoFuture<ResetDeploymentsResponse, ResetDeploymentsError>;
+
+    /// <p>Deploys multiple groups in one operation. This action starts the bulk deployment of a specified set of group versions. Each group version deployment will be triggered with an adaptive rate that has a fixed upper limit. We recommend that you include an &#39;&#39;X-Amzn-Client-Token&#39;&#39; token in every &#39;&#39;StartBulkDeployment&#39;&#39; request. These requests are idempotent with respect to the token and the request parameters.</p>
+    fn start_bulk_deployment(
+        &self,
+        input: StartBulkDeploymentRequest,
+    ) -> RusotoFuture<StartBulkDeploymentResponse, StartBulkDeploymentError>;
+
+    /// <p>Stops the execution of a bulk deployment. This action returns a status of &#39;&#39;Stopping&#39;&#39; until the deployment is stopped. You cannot start a new bulk deployment while a previous deployment is in the &#39;&#39;Stopping&#39;&#39; state. This action doesn&#39;t rollback completed deployments or cancel pending deployments.</p>
+    fn stop_bulk_deployment(
+        &self,
+        input: StopBulkDeploymentRequest,
+    ) -> RusotoFuture<StopBulkDeploymentResponse, StopBulkDeploymentError>;
+
+    /// <p>Add resource tags to a Greengrass Resource. Valid resources are Group, Connector, Core, Device, Function, Logger, Subscription, and Resource Defintions, and also BulkDeploymentIds.</p>
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError>;
+
+    /// <p>Remove resource tags from a Greengrass Resource.</p>
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError>;
+
+    /// <p>Updates the connectivity information for the core. Any devices that belong to the group which has this core will receive this information in order to find the location of the core and connect to it.</p>
+    fn update_connectivity_info(
+        &self,
+        input: UpdateConnectivityInfoRequest,
+    ) -> RusotoFuture<UpdateConnectivityInfoResponse, UpdateConnectivityInfoError>;
+
+    /// <p>Updates a connector definition.</p>
+    fn update_connector_definition(
+        &self,
+        input: UpdateConnectorDefinitionRequest,
+    ) -> RusotoFuture<UpdateConnectorDefinitionResponse, UpdateConnectorDefinitionError>;
+
+    /// <p>Updates a core definition.</p>
+    fn update_core_definition(
+        &self,
+        input: UpdateCoreDefinitionRequest,
+    ) -> RusotoFuture<UpdateCoreDefinitionResponse, UpdateCoreDefinitionError>;
+
+    /// <p>Updates a device definition.</p>
+    fn update_device_definition(
+        &self,
+        input: UpdateDeviceDefinitionRequest,
+    ) -> RusotoFuture<UpdateDeviceDefinitionResponse, UpdateDeviceDefinitionError>;
+
+    /// <p>Updates a Lambda function definition.</p>
+    fn update_function_definition(
+        &self,
+        input: UpdateFunctionDefinitionRequest,
+    ) -> RusotoFuture<UpdateFunctionDefinitionResponse, UpdateFunctionDefinitionError>;
+
+    /// <p>Updates a group.</p>
+    fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError>;
+
+    /// <p>Updates the Certificate expiry time for a group.</p>
+    fn update_group_certificate_configuration(
+        &self,
+        input: UpdateGroupCertificateConfigurationRequest,
+    ) -> RusotoFuture<
+        UpdateGroupCertificateConfigurationResponse,
+        UpdateGroupCertificateConfigurationError,
+    >;
+
+    /// <p>Updates a logger definition.</p>
+    fn update_logger_definition(
+        &self,
+        input: UpdateLoggerDefinitionRequest,
+    ) -> RusotoFuture<UpdateLoggerDefinitionResponse, UpdateLoggerDefinitionError>;
+
+    /// <p>Updates a resource definition.</p>
+    fn update_resource_definition(
+        &self,
+        input: UpdateResourceDefinitionRequest,
+    ) -> RusotoFuture<UpdateResourceDefinitionResponse, UpdateResourceDefinitionError>;
+
+    /// <p>Updates a subscription definition.</p>
+    fn update_subscription_definition(
+        &self,
+        input: UpdateSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<UpdateSubscriptionDefinitionResponse, UpdateSubscriptionDefinitionError>;
+}
+impl<T: ?Sized + GreenGrass> GreenGrass for ::std::sync::Arc<T> {
+    fn associate_role_to_group(
+        &self,
+        input: AssociateRoleToGroupRequest,
+    ) -> RusotoFuture<AssociateRoleToGroupResponse, AssociateRoleToGroupError> {
+        (**self).associate_role_to_group(input)
+    }
+    fn associate_service_role_to_account(
+        &self,
+        input: AssociateServiceRoleToAccountRequest,
+    ) -> RusotoFuture<AssociateServiceRoleToAccountResponse, AssociateServiceRoleToAccountError>
+    {
+        (**self).associate_service_role_to_account(input)
+    }
+    fn create_connector_definition(
+        &self,
+        input: CreateConnectorDefinitionRequest,
+    ) -> RusotoFuture<CreateConnectorDefinitionResponse, CreateConnectorDefinitionError> {
+        (**self).create_connector_definition(input)
+    }
+    fn create_connector_definition_version(
+        &self,
+        input: CreateConnectorDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateConnectorDefinitionVersionResponse, CreateConnectorDefinitionVersionError>
+    {
+        (**self).create_connector_definition_version(input)
+    }
+    fn create_core_definition(
+        &self,
+        input: CreateCoreDefinitionRequest,
+    ) -> RusotoFuture<CreateCoreDefinitionResponse, CreateCoreDefinitionError> {
+        (**self).create_core_definition(input)
+    }
+    fn create_core_definition_version(
+        &self,
+        input: CreateCoreDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateCoreDefinitionVersionResponse, CreateCoreDefinitionVersionError> {
+        (**self).create_core_definition_version(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResponse, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_device_definition(
+        &self,
+        input: CreateDeviceDefinitionRequest,
+    ) -> RusotoFuture<CreateDeviceDefinitionResponse, CreateDeviceDefinitionError> {
+        (**self).create_device_definition(input)
+    }
+    fn create_device_definition_version(
+        &self,
+        input: CreateDeviceDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateDeviceDefinitionVersionResponse, CreateDeviceDefinitionVersionError>
+    {
+        (**self).create_device_definition_version(input)
+    }
+    fn create_function_definition(
+        &self,
+        input: CreateFunctionDefinitionRequest,
+    ) -> RusotoFuture<CreateFunctionDefinitionResponse, CreateFunctionDefinitionError> {
+        (**self).create_function_definition(input)
+    }
+    fn create_function_definition_version(
+        &self,
+        input: CreateFunctionDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateFunctionDefinitionVersionResponse, CreateFunctionDefinitionVersionError>
+    {
+        (**self).create_function_definition_version(input)
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn create_group_certificate_authority(
+        &self,
+        input: CreateGroupCertificateAuthorityRequest,
+    ) -> RusotoFuture<CreateGroupCertificateAuthorityResponse, CreateGroupCertificateAuthorityError>
+    {
+        (**self).create_group_certificate_authority(input)
+    }
+    fn create_group_version(
+        &self,
+        input: CreateGroupVersionRequest,
+    ) -> RusotoFuture<CreateGroupVersionResponse, CreateGroupVersionError> {
+        (**self).create_group_version(input)
+    }
+    fn create_logger_definition(
+        &self,
+        input: CreateLoggerDefinitionRequest,
+    ) -> RusotoFuture<CreateLoggerDefinitionResponse, CreateLoggerDefinitionError> {
+        (**self).create_logger_definition(input)
+    }
+    fn create_logger_definition_version(
+        &self,
+        input: CreateLoggerDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateLoggerDefinitionVersionResponse, CreateLoggerDefinitionVersionError>
+    {
+        (**self).create_logger_definition_version(input)
+    }
+    fn create_resource_definition(
+        &self,
+        input: CreateResourceDefinitionRequest,
+    ) -> RusotoFuture<CreateResourceDefinitionResponse, CreateResourceDefinitionError> {
+        (**self).create_resource_definition(input)
+    }
+    fn create_resource_definition_version(
+        &self,
+        input: CreateResourceDefinitionVersionRequest,
+    ) -> RusotoFuture<CreateResourceDefinitionVersionResponse, CreateResourceDefinitionVersionError>
+    {
+        (**self).create_resource_definition_version(input)
+    }
+    fn create_software_update_job(
+        &self,
+        input: CreateSoftwareUpdateJobRequest,
+    ) -> RusotoFuture<CreateSoftwareUpdateJobResponse, CreateSoftwareUpdateJobError> {
+        (**self).create_software_update_job(input)
+    }
+    fn create_subscription_definition(
+        &self,
+        input: CreateSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<CreateSubscriptionDefinitionResponse, CreateSubscriptionDefinitionError> {
+        (**self).create_subscription_definition(input)
+    }
+    fn create_subscription_definition_version(
+        &self,
+        input: CreateSubscriptionDefinitionVersionRequest,
+    ) -> RusotoFuture<
+        CreateSubscriptionDefinitionVersionResponse,
+        CreateSubscriptionDefinitionVersionError,
+    > {
+        (**self).create_subscription_definition_version(input)
+    }
+    fn delete_connector_definition(
+        &self,
+        input: DeleteConnectorDefinitionRequest,
+    ) -> RusotoFuture<DeleteConnectorDefinitionResponse, DeleteConnectorDefinitionError> {
+        (**self).delete_connector_definition(input)
+    }
+    fn delete_core_definition(
+        &self,
+        input: DeleteCoreDefinitionRequest,
+    ) -> RusotoFuture<DeleteCoreDefinitionResponse, DeleteCoreDefinitionError> {
+        (**self).delete_core_definition(input)
+    }
+    fn delete_device_definition(
+        &self,
+        input: DeleteDeviceDefinitionRequest,
+    ) -> RusotoFuture<DeleteDeviceDefinitionResponse, DeleteDeviceDefinitionError> {
+        (**self).delete_device_definition(input)
+    }
+    fn delete_function_definition(
+        &self,
+        input: DeleteFunctionDefinitionRequest,
+    ) -> RusotoFuture<DeleteFunctionDefinitionResponse, DeleteFunctionDefinitionError> {
+        (**self).delete_function_definition(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> RusotoFuture<DeleteGroupResponse, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn delete_logger_definition(
+        &self,
+        input: DeleteLoggerDefinitionRequest,
+    ) -> RusotoFuture<DeleteLoggerDefinitionResponse, DeleteLoggerDefinitionError> {
+        (**self).delete_logger_definition(input)
+    }
+    fn delete_resource_definition(
+        &self,
+        input: DeleteResourceDefinitionRequest,
+    ) -> RusotoFuture<DeleteResourceDefinitionResponse, DeleteResourceDefinitionError> {
+        (**self).delete_resource_definition(input)
+    }
+    fn delete_subscription_definition(
+        &self,
+        input: DeleteSubscriptionDefinitionRequest,
+    ) -> RusotoFuture<DeleteSubscriptionDefinitionResponse, DeleteSubscriptionDefinitionError> {
+        (**self).delete_subscription_definition(input)
+    }
+    fn disassociate_role_from_group(
+        &self,
+        input: DisassociateRoleFromGroupRequest,
+    ) -> RusotoFuture<DisassociateRoleFromGroupResponse, DisassociateRoleFromGroupError> {
+        (**self).disassociate_role_from_group(input)
+    }
+    fn disassociate_service_role_from_account(
+        &self,
+    ) -> RusotoFuture<
+        DisassociateServiceRoleFromAccountResponse,
+        DisassociateServiceRoleFromAccountError,
+    > {
+        (**self).disassociate_service_role_from_account()
+    }
+    fn get_associated_role(
+        &self,
+        input: GetAssociatedRoleRequest,
+    ) -> RusotoFuture<GetAssociatedRoleResponse, GetAssociatedRoleError> {
+        (**self).get_associated_role(input)
+    }
+    fn get_bulk_deployment_status(
+        &self,
+        input: GetBulkDeploymentStatusRequest,
+    ) -> RusotoFuture<GetBulkDeploymentStatusResponse, GetBulkDeploymentStatusError> {
+        (**self).get_bulk_deployment_status(input)
+    }
+    fn get_connectivity_info(
+        &self,
+        input: GetConnectivityInfoRequest,
+    ) -> RusotoFuture<GetConnectivityInfoResponse, GetConnectivityInfoError> {
+        (**self).get_connectivity_info(input)
+    }
+    fn get_connector_definition(
+        &self,
+        input: GetConnectorDefinitionRequest,
+    ) -> RusotoFuture<GetConnectorDefinitionResponse, GetConnectorDefinitionError> {
+        (**self).get_connector_definition(input)
+    }
+    fn get_connector_definition_version(
+        &self,
+        input: GetConnectorDefinitionVersionRequest,
+    ) -> RusotoFuture<GetConnectorDefinitionVersionResponse, GetConnectorDefinitionVersionError>
+    {
+        (**self).get_connector_definition_version(input)
+    }
+    fn get_core_definition(
+        &self,
+        input: GetCoreDefinitionRequest,
+    ) -> RusotoFuture<GetCoreDefinitionResponse, GetCoreDefinitionError> {
+        (**self).get_core_definition(input)
+    }
+    fn get_core_definition_version(
+        &self,
+        input: GetCoreDefinitionVersionRequest,
+    ) -> RusotoFuture<GetCoreDefinitionVersionResponse, GetCoreDefinitionVersionError> {
+        (**self).get_core_definition_version(input)
+    }
+    fn get_deployment_status(
+        &self,
+        input: GetDeploymentStatusRequest,
+    ) -> RusotoFuture<GetDeploymentStatusResponse, GetDeploymentStatusError> {
+        (**self).get_deployment_status(input)
+    }
+    fn get_device_definition(
+        &self,
+        input: GetDeviceDefinitionRequest,
+    ) -> RusotoFuture<GetDeviceDefinitionResponse, GetDeviceDefinitionError> {
+        (**self).get_device_definition(input)
+    }
+    fn get_device_definition_version(
+        &self,
+        input: GetDeviceDefinitionVersionRequest,
+    ) -> RusotoFuture<GetDeviceDefinitionVersionResponse, GetDeviceDefinitionVersionError> {
+        (**self).get_device_definition_version(input)
+    }
+    fn get_function_definition(
+        &self,
+        input: GetFunctionDefinitionRequest,
+    ) -> RusotoFuture<GetFunctionDefinitionResponse, GetFunctionDefinitionError> {
+        (**self).get_function_definition(input)
+    }
+    fn get_function_definition_version(
+        &self,
+        input: GetFunctionDefinitionVersionRequest,
+    ) -> RusotoFuture<GetFunctionDefinitionVersionResponse, GetFunctionDefinitionVersionError> {
+        (**self).get_function_definition_version(input)
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_group_certificate_authority(
+        &self,
+        input: GetGroupCertificateAuthorityRequest,
+    ) -> RusotoFuture<GetGroupCertificateAuthorityResponse, GetGroupCertificateAuthorityError> {
+        (**self).get_group_certificate_authority(input)
+    }
+    fn get_group_certificate_configuration(
+        &self,
+        input: GetGroupCertificateConfigurationRequest,
+    ) -> RusotoFuture<GetGroupCertificateConfigurationResponse, GetGroupCertificateConfigurationError>
+    {
+        (**self).get_group_certificate_configuration(input)
+    }
+    fn get_group_version(
+        &self,
+        input: GetGroupVersionRequest,
+    ) -> RusotoFuture<GetGroupVersionResponse, GetGroupVersionError> {
+        (**self).get_group_version(input)
+    }
     fn get_logger_definition(
         &self,
         input: GetLoggerDefinitionRequest,
-    ) -> RusotoFuture<GetLoggerDefinitionResponse, GetLoggerDefinitionError>;
-
-    /// <p>Retrieves information about a logger definition version.</p>
+    ) -> RusotoFuture<GetLoggerDefinitionResponse, GetLoggerDefinitionError> {
+        (**self).get_logger_definition(input)
+    }
     fn get_logger_definition_version(
         &self,
         input: GetLoggerDefinitionVersionRequest,
-    ) -> RusotoFuture<GetLoggerDefinitionVersionResponse, GetLoggerDefinitionVersionError>;
-
-    /// <p>Retrieves information about a resource definition, including its creation time and latest version.</p>
+    ) -> RusotoFuture<GetLoggerDefinitionVersionResponse, GetLoggerDefinitionVersionError> {
+        (**self).get_logger_definition_version(input)
+    }
     fn get_resource_definition(
         &self,
         input: GetResourceDefinitionRequest,
-    ) -> RusotoFuture<GetResourceDefinitionResponse, GetResourceDefinitionError>;
-
-    /// <p>Retrieves information about a resource definition version, including which resources are included in the version.</p>
+    ) -> RusotoFuture<GetResourceDefinitionResponse, GetResourceDefinitionError> {
+        (**self).get_resource_definition(input)
+    }
     fn get_resource_definition_version(
         &self,
         input: GetResourceDefinitionVersionRequest,
-    ) -> RusotoFuture<GetResourceDefinitionVersionResponse, GetResourceDefinitionVersionError>;
-
-    /// <p>Retrieves the service role that is attached to your account.</p>
+    ) -> RusotoFuture<GetResourceDefinitionVersionResponse, GetResourceDefinitionVersionError> {
+        (**self).get_resource_definition_version(input)
+    }
     fn get_service_role_for_account(
         &self,
-    ) -> RusotoFuture<GetServiceRoleForAccountResponse, GetServiceRoleForAccountError>;
-
-    /// <p>Retrieves information about a subscription definition.</p>
+    ) -> RusotoFuture<GetServiceRoleForAccountResponse, GetServiceRoleForAccountError> {
+        (**self).get_service_role_for_account()
+    }
     fn get_subscription_definition(
         &self,
         input: GetSubscriptionDefinitionRequest,
-    ) -> RusotoFuture<GetSubscriptionDefinitionResponse, GetSubscriptionDefinitionError>;
-
-    /// <p>Retrieves information about a subscription definition version.</p>
+    ) -> RusotoFuture<GetSubscriptionDefinitionResponse, GetSubscriptionDefinitionError> {
+        (**self).get_subscription_definition(input)
+    }
     fn get_subscription_definition_version(
         &self,
         input: GetSubscriptionDefinitionVersionRequest,
-    ) -> RusotoFuture<GetSubscriptionDefinitionVersionResponse, GetSubscriptionDefinitionVersionError>;
-
-    /// <p>Gets a paginated list of the deployments that have been started in a bulk deployment operation, and their current deployment status.</p>
+    ) -> RusotoFuture<GetSubscriptionDefinitionVersionResponse, GetSubscriptionDefinitionVersionError>
+    {
+        (**self).get_subscription_definition_version(input)
+    }
     fn list_bulk_deployment_detailed_reports(
         &self,
         input: ListBulkDeploymentDetailedReportsRequest,
     ) -> RusotoFuture<
         ListBulkDeploymentDetailedReportsResponse,
         ListBulkDeploymentDetailedReportsError,
-    >;
-
-    /// <p>Returns a list of bulk deployments.</p>
+    > {
+        (**self).list_bulk_deployment_detailed_reports(input)
+    }
     fn list_bulk_deployments(
         &self,
         input: ListBulkDeploymentsRequest,
-    ) -> RusotoFuture<ListBulkDeploymentsResponse, ListBulkDeploymentsError>;
-
-    /// <p>Lists the versions of a connector definition, which are containers for connectors. Connectors run on the Greengrass core and contain built-in integration with local infrastructure, device protocols, AWS, and other cloud services.</p>
+    ) -> RusotoFuture<ListBulkDeploymentsResponse, ListBulkDeploymentsError> {
+        (**self).list_bulk_deployments(input)
+    }
     fn list_connector_definition_versions(
         &self,
         input: ListConnectorDefinitionVersionsRequest,
-    ) -> RusotoFuture<ListConnectorDefinitionVersionsResponse, ListConnectorDefinitionVersionsError>;
-
-    /// <p>Retrieves a list of connector definitions.</p>
+    ) -> RusotoFuture<ListConnectorDefinitionVersionsResponse, ListConnectorDefinitionVersionsError>
+    {
+        (**self).list_connector_definition_versions(input)
+    }
     fn list_connector_definitions(
         &self,
         input: ListConnectorDefinitionsRequest,
-    ) -> RusotoFuture<ListConnectorDefinitionsResponse, ListConnectorDefinitionsError>;
-
-    /// <p>Lists the versions of a core definition.</p>
+    ) -> RusotoFuture<ListConnectorDefinitionsResponse, ListConnectorDefinitionsError> {
+        (**self).list_connector_definitions(input)
+    }
     fn list_core_definition_versions(
         &self,
         input: ListCoreDefinitionVersionsRequest,
-    ) -> RusotoFuture<ListCoreDefinitionVersionsResponse, ListCoreDefinitionVersionsError>;
-
-    /// <p>Retrieves a list of core definitions.</p>
+    ) -> RusotoFuture<ListCoreDefinitionVersionsResponse, ListCoreDefinitionVersionsError> {
+        (**self).list_core_definition_versions(input)
+    }
     fn list_core_definitions(
         &self,
         input: ListCoreDefinitionsRequest,
-    ) -> RusotoFuture<ListCoreDefinitionsResponse, ListCoreDefinitionsError>;
-
-    /// <p>Returns a history of deployments for the group.</p>
+    ) -> RusotoFuture<ListCoreDefinitionsResponse, ListCoreDefinitionsError> {
+        (**self).list_core_definitions(input)
+    }
     fn list_deployments(
         &self,
         input: ListDeploymentsRequest,
-    ) -> RusotoFuture<ListDeploymentsResponse, ListDeploymentsError>;
-
-    /// <p>Lists the versions of a device definition.</p>
+    ) -> RusotoFuture<ListDeploymentsResponse, ListDeploymentsError> {
+        (**self).list_deployments(input)
+    }
     fn list_device_definition_versions(
         &self,
         input: ListDeviceDefinitionVersionsRequest,
-    ) -> RusotoFuture<ListDeviceDefinitionVersionsResponse, ListDeviceDefinitionVersionsError>;
-
-    /// <p>Retrieves a list of device definitions.</p>
+    ) -> RusotoFuture<ListDeviceDefinitionVersionsResponse, ListDeviceDefinitionVersionsError> {
+        (**self).list_device_definition_versions(input)
+    }
     fn list_device_definitions(
         &self,
         input: ListDeviceDefinitionsRequest,
-    ) -> RusotoFuture<ListDeviceDefinitionsResponse, ListDeviceDefinitionsError>;
-
-    /// <p>Lists the versions of a Lambda function definition.</p>
+    ) -> RusotoFuture<ListDeviceDefinitionsResponse, ListDeviceDefinitionsError> {
+        (**self).list_device_definitions(input)
+    }
     fn list_function_definition_versions(
         &self,
         input: ListFunctionDefinitionVersionsRequest,
-    ) -> RusotoFuture<ListFunctionDefinitionVersionsResponse, ListFunctionDefinitionVersionsError>;
-
-    /// <p>Retrieves a list of Lambda function definitions.</p>
+    ) -> RusotoFuture<ListFunctionDefinitionVersionsResponse, ListFunctionDefinitionVersionsError>
+    {
+        (**self).list_function_definition_versions(input)
+    }
     fn list_function_definitions(
         &self,
         input: ListFunctionDefinitionsRequest,
-    ) -> RusotoFuture<ListFunctionDefinitionsResponse, ListFunctionDefinitionsError>;
-
-    /// <p>Retrieves the current CAs for a group.</p>
+    ) -> RusotoFuture<ListFunctionDefinitionsResponse, ListFunctionDefinitionsError> {
+        (**self).list_function_definitions(input)
+    }
     fn list_group_certificate_authorities(
         &self,
         input: ListGroupCertificateAuthoritiesRequest,
-    ) -> RusotoFuture<ListGroupCertificateAuthoritiesResponse, ListGroupCertificateAuthoritiesError>;
-
-    /// <p>Lists the versions of a group.</p>
+    ) -> RusotoFuture<ListGroupCertificateAuthoritiesResponse, ListGroupCertificateAuthoritiesError>
+    {
+        (**self).list_group_certificate_authorities(input)
+    }
     fn list_group_versions(
         &self,
         input: ListGroupVersionsRequest,
-    ) -> RusotoFuture<ListGroupVersionsResponse, ListGroupVersionsError>;
-
-    /// <p>Retrieves a list of groups.</p>
+    ) -> RusotoFuture<ListGroupVersionsResponse, ListGroupVersionsError> {
+        (**self).list_group_versions(input)
+    }
     fn list_groups(
         &self,
         input: ListGroupsRequest,
-    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError>;
-
-    /// <p>Lists the versions of a logger definition.</p>
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        (**self).list_groups(input)
+    }
     fn list_logger_definition_versions(
         &self,
         input: ListLoggerDefinitionVersionsRequest,
-    ) -> RusotoFuture<ListLoggerDefinitionVersionsResponse, ListLoggerDefinitionVersionsError>;
-
-    /// <p>Retrieves a list of logger definitions.</p>
+    ) -> RusotoFuture<ListLoggerDefinitionVersionsResponse, ListLoggerDefinitionVersionsError> {
+        (**self).list_logger_definition_versions(input)
+    }
     fn list_logger_definitions(
         &self,
         input: ListLoggerDefinitionsRequest,
-    ) -> RusotoFuture<ListLoggerDefinitionsResponse, ListLoggerDefinitionsError>;
-
-    /// <p>Lists the versions of a resource definition.</p>
+    ) -> RusotoFuture<ListLoggerDefinitionsResponse, ListLoggerDefinitionsError> {
+        (**self).list_logger_definitions(input)
+    }
     fn list_resource_definition_versions(
         &self,
         input: ListResourceDefinitionVersionsRequest,
-    ) -> RusotoFuture<ListResourceDefinitionVersionsResponse, ListResourceDefinitionVersionsError>;
-
-    /// <p>Retrieves a list of resource definitions.</p>
+    ) -> RusotoFuture<ListResourceDefinitionVersionsResponse, ListResourceDefinitionVersionsError>
+    {
+        (**self).list_resource_definition_versions(input)
+    }
     fn list_resource_definitions(
         &self,
         input: ListResourceDefinitionsRequest,
-    ) -> RusotoFuture<ListResourceDefinitionsResponse, ListResourceDefinitionsError>;
-
-    /// <p>Lists the versions of a subscription definition.</p>
+    ) -> RusotoFuture<ListResourceDefinitionsResponse, ListResourceDefinitionsError> {
+        (**self).list_resource_definitions(input)
+    }
     fn list_subscription_definition_versions(
         &self,
         input: ListSubscriptionDefinitionVersionsRequest,
     ) -> RusotoFuture<
         ListSubscriptionDefinitionVersionsResponse,
         ListSubscriptionDefinitionVersionsError,
-    >;
-
-    /// <p>Retrieves a list of subscription definitions.</p>
+    > {
+        (**self).list_subscription_definition_versions(input)
+    }
     fn list_subscription_definitions(
         &self,
         input: ListSubscriptionDefinitionsRequest,
-    ) -> RusotoFuture<ListSubscriptionDefinitionsResponse, ListSubscriptionDefinitionsError>;
-
-    /// <p>Retrieves a list of resource tags for a resource arn.</p>
+    ) -> RusotoFuture<ListSubscriptionDefinitionsResponse, ListSubscriptionDefinitionsError> {
+        (**self).list_subscription_definitions(input)
+    }
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
-    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
-
-    /// <p>Resets a group&#39;s deployments.</p>
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
     fn reset_deployments(
         &self,
         input: ResetDeploymentsRequest,
-    ) -> RusotoFuture<ResetDeploymentsResponse, ResetDeploymentsError>;
-
-    /// <p>Deploys multiple groups in one operation. This action starts the bulk deployment of a specified set of group versions. Each group version deployment will be triggered with an adaptive rate that has a fixed upper limit. We recommend that you include an &#39;&#39;X-Amzn-Client-Token&#39;&#39; token in every &#39;&#39;StartBulkDeployment&#39;&#39; request. These requests are idempotent with respect to the token and the request parameters.</p>
+    ) -> RusotoFuture<ResetDeploymentsResponse, ResetDeploymentsError> {
+        (**self).reset_deployments(input)
+    }
     fn start_bulk_deployment(
         &self,
         input: StartBulkDeploymentRequest,
-    ) -> RusotoFuture<StartBulkDeploymentResponse, StartBulkDeploymentError>;
-
-    /// <p>Stops the execution of a bulk deployment. This action returns a status of &#39;&#39;Stopping&#39;&#39; until the deployment is stopped. You cannot start a new bulk deployment while a previous deployment is in the &#39;&#39;Stopping&#39;&#39; state. This action doesn&#39;t rollback completed deployments or cancel pending deployments.</p>
+    ) -> RusotoFuture<StartBulkDeploymentResponse, StartBulkDeploymentError> {
+        (**self).start_bulk_deployment(input)
+    }
     fn stop_bulk_deployment(
         &self,
         input: StopBulkDeploymentRequest,
-    ) -> RusotoFuture<StopBulkDeploymentResponse, StopBulkDeploymentError>;
-
-    /// <p>Add resource tags to a Greengrass Resource. Valid resources are Group, Connector, Core, Device, Function, Logger, Subscription, and Resource Defintions, and also BulkDeploymentIds.</p>
-    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError>;
-
-    /// <p>Remove resource tags from a Greengrass Resource.</p>
-    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError>;
-
-    /// <p>Updates the connectivity information for the core. Any devices that belong to the group which has this core will receive this information in order to find the location of the core and connect to it.</p>
+    ) -> RusotoFuture<StopBulkDeploymentResponse, StopBulkDeploymentError> {
+        (**self).stop_bulk_deployment(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
     fn update_connectivity_info(
         &self,
         input: UpdateConnectivityInfoRequest,
-    ) -> RusotoFuture<UpdateConnectivityInfoResponse, UpdateConnectivityInfoError>;
-
-    /// <p>Updates a connector definition.</p>
+    ) -> RusotoFuture<UpdateConnectivityInfoResponse, UpdateConnectivityInfoError> {
+        (**self).update_connectivity_info(input)
+    }
     fn update_connector_definition(
         &self,
         input: UpdateConnectorDefinitionRequest,
-    ) -> RusotoFuture<UpdateConnectorDefinitionResponse, UpdateConnectorDefinitionError>;
-
-    /// <p>Updates a core definition.</p>
+    ) -> RusotoFuture<UpdateConnectorDefinitionResponse, UpdateConnectorDefinitionError> {
+        (**self).update_connector_definition(input)
+    }
     fn update_core_definition(
         &self,
         input: UpdateCoreDefinitionRequest,
-    ) -> RusotoFuture<UpdateCoreDefinitionResponse, UpdateCoreDefinitionError>;
-
-    /// <p>Updates a device definition.</p>
+    ) -> RusotoFuture<UpdateCoreDefinitionResponse, UpdateCoreDefinitionError> {
+        (**self).update_core_definition(input)
+    }
     fn update_device_definition(
         &self,
         input: UpdateDeviceDefinitionRequest,
-    ) -> RusotoFuture<UpdateDeviceDefinitionResponse, UpdateDeviceDefinitionError>;
-
-    /// <p>Updates a Lambda function definition.</p>
+    ) -> RusotoFuture<UpdateDeviceDefinitionResponse, UpdateDeviceDefinitionError> {
+        (**self).update_device_definition(input)
+    }
     fn update_function_definition(
         &self,
         input: UpdateFunctionDefinitionRequest,
-    ) -> RusotoFuture<UpdateFunctionDefinitionResponse, UpdateFunctionDefinitionError>;
-
-    /// <p>Updates a group.</p>
+    ) -> RusotoFuture<UpdateFunctionDefinitionResponse, UpdateFunctionDefinitionError> {
+        (**self).update_function_definition(input)
+    }
     fn update_group(
         &self,
         input: UpdateGroupRequest,
-    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError>;
-
-    /// <p>Updates the Certificate expiry time for a group.</p>
+    ) -> RusotoFuture<UpdateGroupResponse, UpdateGroupError> {
+        (**self).update_group(input)
+    }
     fn update_group_certificate_configuration(
         &self,
         input: UpdateGroupCertificateConfigurationRequest,
     ) -> RusotoFuture<
         UpdateGroupCertificateConfigurationResponse,
         UpdateGroupCertificateConfigurationError,
-    >;
-
-    /// <p>Updates a logger definition.</p>
+    > {
+        (**self).update_group_certificate_configuration(input)
+    }
     fn update_logger_definition(
         &self,
         input: UpdateLoggerDefinitionRequest,
-    ) -> RusotoFuture<UpdateLoggerDefinitionResponse, UpdateLoggerDefinitionError>;
-
-    /// <p>Updates a resource definition.</p>
+    ) -> RusotoFuture<UpdateLoggerDefinitionResponse, UpdateLoggerDefinitionError> {
+        (**self).update_logger_definition(input)
+    }
     fn update_resource_definition(
         &self,
         input: UpdateResourceDefinitionRequest,
-    ) -> RusotoFuture<UpdateResourceDefinitionResponse, UpdateResourceDefinitionError>;
-
-    /// <p>Updates a subscription definition.</p>
+    ) -> RusotoFuture<UpdateResourceDefinitionResponse, UpdateResourceDefinitionError> {
+        (**self).update_resource_definition(input)
+    }
     fn update_subscription_definition(
         &self,
         input: UpdateSubscriptionDefinitionRequest,
-    ) -> RusotoFuture<UpdateSubscriptionDefinitionResponse, UpdateSubscriptionDefinitionError>;
+    ) -> RusotoFuture<UpdateSubscriptionDefinitionResponse, UpdateSubscriptionDefinitionError> {
+        (**self).update_subscription_definition(input)
+    }
 }
+/// The error codes AWS Greengrass retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Greengrass API.
 #[derive(Clone)]
 pub struct GreenGrassClient {
@@ -7037,6 +12403,27 @@ impl GreenGrassClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_GREENGRASS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> GreenGrassClient {
+        Self::new(region::Region::from_env_for_service("GREENGRASS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `greengrass`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> GreenGrassClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("greengrass", "GREENGRASS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -7055,7 +12442,54 @@ impl GreenGrassClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> GreenGrassClient {
-        GreenGrassClient { client, region }
+        GreenGrassClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "greengrass")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "greengrass",
+            "greengrass",
+            "GREENGRASS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `GreenGrass` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn GreenGrass + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for GreenGrassClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Greengrass"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "greengrass"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> GreenGrassClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon GuardDuty clients.
+//!
+//! Makes a single read-only `ListDetectors` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_guardduty::{GuardDuty, GuardDutyClient, ListDetectorsRequest};
+
+fn main() {
+    let client = GuardDutyClient::from_env();
+    match client
+        .list_detectors(ListDetectorsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListDetectors succeeded"),
+        Err(error) => {
+            eprintln!("ListDetectors failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AcceptInvitationRequest {
@@ -37,6 +38,58 @@ pub struct AcceptInvitationRequest {
     pub master_id: String,
 }
 
+impl AcceptInvitationRequest {
+    /// Returns a builder for `AcceptInvitationRequest`, checking its required members when built.
+    pub fn builder() -> AcceptInvitationRequestBuilder {
+        AcceptInvitationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AcceptInvitationRequest`](struct.AcceptInvitationRequest.html).
+#[derive(Default, Debug)]
+pub struct AcceptInvitationRequestBuilder {
+    detector_id: Option<String>,
+    invitation_id: Option<String>,
+    master_id: Option<String>,
+}
+
+impl AcceptInvitationRequestBuilder {
+    /// Sets the [`detector_id`](struct.AcceptInvitationRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`invitation_id`](struct.AcceptInvitationRequest.html#structfield.invitation_id) member.
+    pub fn invitation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.invitation_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_id`](struct.AcceptInvitationRequest.html#structfield.master_id) member.
+    pub fn master_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AcceptInvitationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AcceptInvitationRequest, ::rusoto_core::BuildError> {
+        Ok(AcceptInvitationRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            invitation_id: self
+                .invitation_id
+                .ok_or(::rusoto_core::BuildError::missing("invitation_id"))?,
+            master_id: self
+                .master_id
+                .ok_or(::rusoto_core::BuildError::missing("master_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AcceptInvitationResponse {}
@@ -110,6 +163,46 @@ pub struct ArchiveFindingsRequest {
     pub finding_ids: Vec<String>,
 }
 
+impl ArchiveFindingsRequest {
+    /// Returns a builder for `ArchiveFindingsRequest`, checking its required members when built.
+    pub fn builder() -> ArchiveFindingsRequestBuilder {
+        ArchiveFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ArchiveFindingsRequest`](struct.ArchiveFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct ArchiveFindingsRequestBuilder {
+    detector_id: Option<String>,
+    finding_ids: Option<Vec<String>>,
+}
+
+impl ArchiveFindingsRequestBuilder {
+    /// Sets the [`detector_id`](struct.ArchiveFindingsRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_ids`](struct.ArchiveFindingsRequest.html#structfield.finding_ids) member.
+    pub fn finding_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.finding_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ArchiveFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ArchiveFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(ArchiveFindingsRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            finding_ids: self
+                .finding_ids
+                .ok_or(::rusoto_core::BuildError::missing("finding_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ArchiveFindingsResponse {}
@@ -205,13 +298,70 @@ pub struct CreateDetectorRequest {
     /// <p>A enum value that specifies how frequently customer got Finding updates published.</p>
     #[serde(rename = "FindingPublishingFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finding_publishing_frequency: Option<String>,
+    pub finding_publishing_frequency: Option<FindingPublishingFrequency>,
     /// <p>The tags to be added to a new detector resource.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateDetectorRequest {
+    /// Returns a builder for `CreateDetectorRequest`, checking its required members when built.
+    pub fn builder() -> CreateDetectorRequestBuilder {
+        CreateDetectorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDetectorRequest`](struct.CreateDetectorRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDetectorRequestBuilder {
+    client_token: Option<String>,
+    enable: Option<bool>,
+    finding_publishing_frequency: Option<FindingPublishingFrequency>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateDetectorRequestBuilder {
+    /// Sets the [`client_token`](struct.CreateDetectorRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable`](struct.CreateDetectorRequest.html#structfield.enable) member.
+    pub fn enable<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_publishing_frequency`](struct.CreateDetectorRequest.html#structfield.finding_publishing_frequency) member.
+    pub fn finding_publishing_frequency<T: Into<FindingPublishingFrequency>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.finding_publishing_frequency = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDetectorRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDetectorRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDetectorRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDetectorRequest {
+            client_token: self.client_token,
+            enable: self
+                .enable
+                .ok_or(::rusoto_core::BuildError::missing("enable"))?,
+            finding_publishing_frequency: self.finding_publishing_frequency,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDetectorResponse {
@@ -226,7 +376,7 @@ pub struct CreateFilterRequest {
     /// <p>Specifies the action that is to be applied to the findings that match the filter.</p>
     #[serde(rename = "Action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<FilterAction>,
     /// <p>The idempotency token for the create request.</p>
     #[serde(rename = "ClientToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -254,6 +404,96 @@ pub struct CreateFilterRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateFilterRequest {
+    /// Returns a builder for `CreateFilterRequest`, checking its required members when built.
+    pub fn builder() -> CreateFilterRequestBuilder {
+        CreateFilterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFilterRequest`](struct.CreateFilterRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFilterRequestBuilder {
+    action: Option<FilterAction>,
+    client_token: Option<String>,
+    description: Option<String>,
+    detector_id: Option<String>,
+    finding_criteria: Option<FindingCriteria>,
+    name: Option<String>,
+    rank: Option<i64>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateFilterRequestBuilder {
+    /// Sets the [`action`](struct.CreateFilterRequest.html#structfield.action) member.
+    pub fn action<T: Into<FilterAction>>(mut self, value: T) -> Self {
+        self.action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_token`](struct.CreateFilterRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateFilterRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.CreateFilterRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_criteria`](struct.CreateFilterRequest.html#structfield.finding_criteria) member.
+    pub fn finding_criteria<T: Into<FindingCriteria>>(mut self, value: T) -> Self {
+        self.finding_criteria = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateFilterRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rank`](struct.CreateFilterRequest.html#structfield.rank) member.
+    pub fn rank<T: Into<i64>>(mut self, value: T) -> Self {
+        self.rank = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateFilterRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFilterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateFilterRequest, ::rusoto_core::BuildError> {
+        Ok(CreateFilterRequest {
+            action: self.action,
+            client_token: self.client_token,
+            description: self.description,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            finding_criteria: self
+                .finding_criteria
+                .ok_or(::rusoto_core::BuildError::missing("finding_criteria"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            rank: self.rank,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateFilterResponse {
@@ -262,7 +502,7 @@ pub struct CreateFilterResponse {
     pub name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateIPSetRequest {
     /// <p>A boolean value that indicates whether GuardDuty is to start using the uploaded IPSet.</p>
     #[serde(rename = "Activate")]
@@ -276,7 +516,7 @@ pub struct CreateIPSetRequest {
     pub detector_id: String,
     /// <p>The format of the file that contains the IPSet.</p>
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: IpSetFormat,
     /// <p>The URI of the file that contains the IPSet. For example (https://s3.us-west-2.amazonaws.com/my-bucket/my-object-key)</p>
     #[serde(rename = "Location")]
     pub location: String,
@@ -289,6 +529,92 @@ pub struct CreateIPSetRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateIPSetRequest {
+    /// Returns a builder for `CreateIPSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateIPSetRequestBuilder {
+        CreateIPSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateIPSetRequest`](struct.CreateIPSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateIPSetRequestBuilder {
+    activate: Option<bool>,
+    client_token: Option<String>,
+    detector_id: Option<String>,
+    format: Option<IpSetFormat>,
+    location: Option<String>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateIPSetRequestBuilder {
+    /// Sets the [`activate`](struct.CreateIPSetRequest.html#structfield.activate) member.
+    pub fn activate<T: Into<bool>>(mut self, value: T) -> Self {
+        self.activate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_token`](struct.CreateIPSetRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.CreateIPSetRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`format`](struct.CreateIPSetRequest.html#structfield.format) member.
+    pub fn format<T: Into<IpSetFormat>>(mut self, value: T) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location`](struct.CreateIPSetRequest.html#structfield.location) member.
+    pub fn location<T: Into<String>>(mut self, value: T) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateIPSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateIPSetRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateIPSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateIPSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateIPSetRequest {
+            activate: self
+                .activate
+                .ok_or(::rusoto_core::BuildError::missing("activate"))?,
+            client_token: self.client_token,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            format: self
+                .format
+                .ok_or(::rusoto_core::BuildError::missing("format"))?,
+            location: self
+                .location
+                .ok_or(::rusoto_core::BuildError::missing("location"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateIPSetResponse {
@@ -307,6 +633,46 @@ pub struct CreateMembersRequest {
     pub detector_id: String,
 }
 
+impl CreateMembersRequest {
+    /// Returns a builder for `CreateMembersRequest`, checking its required members when built.
+    pub fn builder() -> CreateMembersRequestBuilder {
+        CreateMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateMembersRequest`](struct.CreateMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateMembersRequestBuilder {
+    account_details: Option<Vec<AccountDetail>>,
+    detector_id: Option<String>,
+}
+
+impl CreateMembersRequestBuilder {
+    /// Sets the [`account_details`](struct.CreateMembersRequest.html#structfield.account_details) member.
+    pub fn account_details<T: Into<Vec<AccountDetail>>>(mut self, value: T) -> Self {
+        self.account_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.CreateMembersRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateMembersRequest, ::rusoto_core::BuildError> {
+        Ok(CreateMembersRequest {
+            account_details: self
+                .account_details
+                .ok_or(::rusoto_core::BuildError::missing("account_details"))?,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateMembersResponse {
@@ -326,11 +692,51 @@ pub struct CreateSampleFindingsRequest {
     pub finding_types: Option<Vec<String>>,
 }
 
+impl CreateSampleFindingsRequest {
+    /// Returns a builder for `CreateSampleFindingsRequest`, checking its required members when built.
+    pub fn builder() -> CreateSampleFindingsRequestBuilder {
+        CreateSampleFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSampleFindingsRequest`](struct.CreateSampleFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSampleFindingsRequestBuilder {
+    detector_id: Option<String>,
+    finding_types: Option<Vec<String>>,
+}
+
+impl CreateSampleFindingsRequestBuilder {
+    /// Sets the [`detector_id`](struct.CreateSampleFindingsRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_types`](struct.CreateSampleFindingsRequest.html#structfield.finding_types) member.
+    pub fn finding_types<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.finding_types = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSampleFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSampleFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSampleFindingsRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            finding_types: self.finding_types,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateSampleFindingsResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateThreatIntelSetRequest {
     /// <p>A boolean value that indicates whether GuardDuty is to start using the uploaded ThreatIntelSet.</p>
     #[serde(rename = "Activate")]
@@ -344,7 +750,7 @@ pub struct CreateThreatIntelSetRequest {
     pub detector_id: String,
     /// <p>The format of the file that contains the ThreatIntelSet.</p>
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: ThreatIntelSetFormat,
     /// <p>The URI of the file that contains the ThreatIntelSet. For example (https://s3.us-west-2.amazonaws.com/my-bucket/my-object-key).</p>
     #[serde(rename = "Location")]
     pub location: String,
@@ -357,6 +763,94 @@ pub struct CreateThreatIntelSetRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateThreatIntelSetRequest {
+    /// Returns a builder for `CreateThreatIntelSetRequest`, checking its required members when built.
+    pub fn builder() -> CreateThreatIntelSetRequestBuilder {
+        CreateThreatIntelSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateThreatIntelSetRequest`](struct.CreateThreatIntelSetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateThreatIntelSetRequestBuilder {
+    activate: Option<bool>,
+    client_token: Option<String>,
+    detector_id: Option<String>,
+    format: Option<ThreatIntelSetFormat>,
+    location: Option<String>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateThreatIntelSetRequestBuilder {
+    /// Sets the [`activate`](struct.CreateThreatIntelSetRequest.html#structfield.activate) member.
+    pub fn activate<T: Into<bool>>(mut self, value: T) -> Self {
+        self.activate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_token`](struct.CreateThreatIntelSetRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.CreateThreatIntelSetRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`format`](struct.CreateThreatIntelSetRequest.html#structfield.format) member.
+    pub fn format<T: Into<ThreatIntelSetFormat>>(mut self, value: T) -> Self {
+        self.format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location`](struct.CreateThreatIntelSetRequest.html#structfield.location) member.
+    pub fn location<T: Into<String>>(mut self, value: T) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateThreatIntelSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateThreatIntelSetRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateThreatIntelSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateThreatIntelSetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateThreatIntelSetRequest {
+            activate: self
+                .activate
+                .ok_or(::rusoto_core::BuildError::missing("activate"))?,
+            client_token: self.client_token,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            format: self
+                .format
+                .ok_or(::rusoto_core::BuildError::missing("format"))?,
+            location: self
+                .location
+                .ok_or(::rusoto_core::BuildError::missing("location"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateThreatIntelSetResponse {
@@ -372,6 +866,38 @@ pub struct DeclineInvitationsRequest {
     pub account_ids: Vec<String>,
 }
 
+impl DeclineInvitationsRequest {
+    /// Returns a builder for `DeclineInvitationsRequest`, checking its required members when built.
+    pub fn builder() -> DeclineInvitationsRequestBuilder {
+        DeclineInvitationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeclineInvitationsRequest`](struct.DeclineInvitationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeclineInvitationsRequestBuilder {
+    account_ids: Option<Vec<String>>,
+}
+
+impl DeclineInvitationsRequestBuilder {
+    /// Sets the [`account_ids`](struct.DeclineInvitationsRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeclineInvitationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeclineInvitationsRequest, ::rusoto_core::BuildError> {
+        Ok(DeclineInvitationsRequest {
+            account_ids: self
+                .account_ids
+                .ok_or(::rusoto_core::BuildError::missing("account_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeclineInvitationsResponse {
@@ -387,6 +913,36 @@ pub struct DeleteDetectorRequest {
     pub detector_id: String,
 }
 
+impl DeleteDetectorRequest {
+    /// Returns a builder for `DeleteDetectorRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDetectorRequestBuilder {
+        DeleteDetectorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDetectorRequest`](struct.DeleteDetectorRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDetectorRequestBuilder {
+    detector_id: Option<String>,
+}
+
+impl DeleteDetectorRequestBuilder {
+    /// Sets the [`detector_id`](struct.DeleteDetectorRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDetectorRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDetectorRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDetectorRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDetectorResponse {}
@@ -401,6 +957,46 @@ pub struct DeleteFilterRequest {
     pub filter_name: String,
 }
 
+impl DeleteFilterRequest {
+    /// Returns a builder for `DeleteFilterRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFilterRequestBuilder {
+        DeleteFilterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFilterRequest`](struct.DeleteFilterRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFilterRequestBuilder {
+    detector_id: Option<String>,
+    filter_name: Option<String>,
+}
+
+impl DeleteFilterRequestBuilder {
+    /// Sets the [`detector_id`](struct.DeleteFilterRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_name`](struct.DeleteFilterRequest.html#structfield.filter_name) member.
+    pub fn filter_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFilterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteFilterRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFilterRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            filter_name: self
+                .filter_name
+                .ok_or(::rusoto_core::BuildError::missing("filter_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteFilterResponse {}
@@ -415,6 +1011,46 @@ pub struct DeleteIPSetRequest {
     pub ip_set_id: String,
 }
 
+impl DeleteIPSetRequest {
+    /// Returns a builder for `DeleteIPSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIPSetRequestBuilder {
+        DeleteIPSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIPSetRequest`](struct.DeleteIPSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIPSetRequestBuilder {
+    detector_id: Option<String>,
+    ip_set_id: Option<String>,
+}
+
+impl DeleteIPSetRequestBuilder {
+    /// Sets the [`detector_id`](struct.DeleteIPSetRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ip_set_id`](struct.DeleteIPSetRequest.html#structfield.ip_set_id) member.
+    pub fn ip_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ip_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIPSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteIPSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIPSetRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            ip_set_id: self
+                .ip_set_id
+                .ok_or(::rusoto_core::BuildError::missing("ip_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteIPSetResponse {}
@@ -426,6 +1062,38 @@ pub struct DeleteInvitationsRequest {
     pub account_ids: Vec<String>,
 }
 
+impl DeleteInvitationsRequest {
+    /// Returns a builder for `DeleteInvitationsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteInvitationsRequestBuilder {
+        DeleteInvitationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteInvitationsRequest`](struct.DeleteInvitationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteInvitationsRequestBuilder {
+    account_ids: Option<Vec<String>>,
+}
+
+impl DeleteInvitationsRequestBuilder {
+    /// Sets the [`account_ids`](struct.DeleteInvitationsRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteInvitationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteInvitationsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteInvitationsRequest {
+            account_ids: self
+                .account_ids
+                .ok_or(::rusoto_core::BuildError::missing("account_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteInvitationsResponse {
@@ -444,6 +1112,46 @@ pub struct DeleteMembersRequest {
     pub detector_id: String,
 }
 
+impl DeleteMembersRequest {
+    /// Returns a builder for `DeleteMembersRequest`, checking its required members when built.
+    pub fn builder() -> DeleteMembersRequestBuilder {
+        DeleteMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMembersRequest`](struct.DeleteMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteMembersRequestBuilder {
+    account_ids: Option<Vec<String>>,
+    detector_id: Option<String>,
+}
+
+impl DeleteMembersRequestBuilder {
+    /// Sets the [`account_ids`](struct.DeleteMembersRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.DeleteMembersRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteMembersRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteMembersRequest {
+            account_ids: self
+                .account_ids
+                .ok_or(::rusoto_core::BuildError::missing("account_ids"))?,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteMembersResponse {
@@ -462,47 +1170,242 @@ pub struct DeleteThreatIntelSetRequest {
     pub threat_intel_set_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DeleteThreatIntelSetResponse {}
+impl DeleteThreatIntelSetRequest {
+    /// Returns a builder for `DeleteThreatIntelSetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteThreatIntelSetRequestBuilder {
+        DeleteThreatIntelSetRequestBuilder::default()
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DisassociateFromMasterAccountRequest {
-    /// <p>The unique ID of the detector of the GuardDuty member account.</p>
-    #[serde(rename = "DetectorId")]
-    pub detector_id: String,
+/// Builds a [`DeleteThreatIntelSetRequest`](struct.DeleteThreatIntelSetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteThreatIntelSetRequestBuilder {
+    detector_id: Option<String>,
+    threat_intel_set_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DisassociateFromMasterAccountResponse {}
+impl DeleteThreatIntelSetRequestBuilder {
+    /// Sets the [`detector_id`](struct.DeleteThreatIntelSetRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DisassociateMembersRequest {
-    /// <p>A list of account IDs of the GuardDuty member accounts that you want to disassociate from master.</p>
-    #[serde(rename = "AccountIds")]
-    pub account_ids: Vec<String>,
-    /// <p>The unique ID of the detector of the GuardDuty account whose members you want to disassociate from master.</p>
-    #[serde(rename = "DetectorId")]
-    pub detector_id: String,
-}
+    /// Sets the [`threat_intel_set_id`](struct.DeleteThreatIntelSetRequest.html#structfield.threat_intel_set_id) member.
+    pub fn threat_intel_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.threat_intel_set_id = Some(value.into());
+        self
+    }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DisassociateMembersResponse {
-    /// <p>A list of objects containing the unprocessed account and a result string explaining why it was unprocessed.</p>
-    #[serde(rename = "UnprocessedAccounts")]
-    pub unprocessed_accounts: Vec<UnprocessedAccount>,
+    /// Builds the `DeleteThreatIntelSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteThreatIntelSetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteThreatIntelSetRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            threat_intel_set_id: self
+                .threat_intel_set_id
+                .ok_or(::rusoto_core::BuildError::missing("threat_intel_set_id"))?,
+        })
+    }
 }
 
-/// <p>Contains information about the DNS request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
-pub struct DnsRequestAction {
-    /// <p>Domain information for the DNS request.</p>
-    #[serde(rename = "Domain")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain: Option<String>,
+pub struct DeleteThreatIntelSetResponse {}
+
+/// <p>The detector status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DetectorStatus {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DetectorStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DetectorStatus::Enabled => "ENABLED",
+            DetectorStatus::Disabled => "DISABLED",
+            DetectorStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DetectorStatus {
+    fn from(value: &'a str) -> DetectorStatus {
+        match value {
+            "ENABLED" => DetectorStatus::Enabled,
+            "DISABLED" => DetectorStatus::Disabled,
+            _ => DetectorStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DetectorStatus {
+    fn from(value: String) -> DetectorStatus {
+        DetectorStatus::from(value.as_str())
+    }
+}
+
+impl From<DetectorStatus> for String {
+    fn from(value: DetectorStatus) -> String {
+        match value {
+            DetectorStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DetectorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DetectorStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DetectorStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DetectorStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DetectorStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DetectorStatus::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DisassociateFromMasterAccountRequest {
+    /// <p>The unique ID of the detector of the GuardDuty member account.</p>
+    #[serde(rename = "DetectorId")]
+    pub detector_id: String,
+}
+
+impl DisassociateFromMasterAccountRequest {
+    /// Returns a builder for `DisassociateFromMasterAccountRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateFromMasterAccountRequestBuilder {
+        DisassociateFromMasterAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateFromMasterAccountRequest`](struct.DisassociateFromMasterAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateFromMasterAccountRequestBuilder {
+    detector_id: Option<String>,
+}
+
+impl DisassociateFromMasterAccountRequestBuilder {
+    /// Sets the [`detector_id`](struct.DisassociateFromMasterAccountRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateFromMasterAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateFromMasterAccountRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociateFromMasterAccountRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DisassociateFromMasterAccountResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DisassociateMembersRequest {
+    /// <p>A list of account IDs of the GuardDuty member accounts that you want to disassociate from master.</p>
+    #[serde(rename = "AccountIds")]
+    pub account_ids: Vec<String>,
+    /// <p>The unique ID of the detector of the GuardDuty account whose members you want to disassociate from master.</p>
+    #[serde(rename = "DetectorId")]
+    pub detector_id: String,
+}
+
+impl DisassociateMembersRequest {
+    /// Returns a builder for `DisassociateMembersRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateMembersRequestBuilder {
+        DisassociateMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateMembersRequest`](struct.DisassociateMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateMembersRequestBuilder {
+    account_ids: Option<Vec<String>>,
+    detector_id: Option<String>,
+}
+
+impl DisassociateMembersRequestBuilder {
+    /// Sets the [`account_ids`](struct.DisassociateMembersRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.DisassociateMembersRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateMembersRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateMembersRequest {
+            account_ids: self
+                .account_ids
+                .ok_or(::rusoto_core::BuildError::missing("account_ids"))?,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DisassociateMembersResponse {
+    /// <p>A list of objects containing the unprocessed account and a result string explaining why it was unprocessed.</p>
+    #[serde(rename = "UnprocessedAccounts")]
+    pub unprocessed_accounts: Vec<UnprocessedAccount>,
+}
+
+/// <p>Contains information about the DNS request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DnsRequestAction {
+    /// <p>Domain information for the DNS request.</p>
+    #[serde(rename = "Domain")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
 }
 
 /// <p>Contains information about the domain.</p>
@@ -525,6 +1428,162 @@ pub struct Evidence {
     pub threat_intelligence_details: Option<Vec<ThreatIntelligenceDetail>>,
 }
 
+/// <p>Valid values: USEFUL | NOT_USEFUL</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Feedback {
+    /// <p><code>USEFUL</code></p>
+    Useful,
+    /// <p><code>NOT_USEFUL</code></p>
+    NotUseful,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Feedback {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Feedback::Useful => "USEFUL",
+            Feedback::NotUseful => "NOT_USEFUL",
+            Feedback::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Feedback {
+    fn from(value: &'a str) -> Feedback {
+        match value {
+            "USEFUL" => Feedback::Useful,
+            "NOT_USEFUL" => Feedback::NotUseful,
+            _ => Feedback::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Feedback {
+    fn from(value: String) -> Feedback {
+        Feedback::from(value.as_str())
+    }
+}
+
+impl From<Feedback> for String {
+    fn from(value: Feedback) -> String {
+        match value {
+            Feedback::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Feedback {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Feedback {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Feedback {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Feedback, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Feedback::from(value))
+    }
+}
+
+/// <p>Specifies the action that is to be applied to the findings that match the filter.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FilterAction {
+    /// <p><code>NOOP</code></p>
+    Noop,
+    /// <p><code>ARCHIVE</code></p>
+    Archive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FilterAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FilterAction::Noop => "NOOP",
+            FilterAction::Archive => "ARCHIVE",
+            FilterAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FilterAction {
+    fn from(value: &'a str) -> FilterAction {
+        match value {
+            "NOOP" => FilterAction::Noop,
+            "ARCHIVE" => FilterAction::Archive,
+            _ => FilterAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FilterAction {
+    fn from(value: String) -> FilterAction {
+        FilterAction::from(value.as_str())
+    }
+}
+
+impl From<FilterAction> for String {
+    fn from(value: FilterAction) -> String {
+        match value {
+            FilterAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FilterAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FilterAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FilterAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FilterAction {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FilterAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FilterAction::from(value))
+    }
+}
+
 /// <p>Contains information about the finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -588,6 +1647,164 @@ pub struct FindingCriteria {
     pub criterion: Option<::std::collections::HashMap<String, Condition>>,
 }
 
+/// <p>A enum value that specifies how frequently customer got Finding updates published.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FindingPublishingFrequency {
+    /// <p><code>FIFTEEN_MINUTES</code></p>
+    FifteenMinutes,
+    /// <p><code>ONE_HOUR</code></p>
+    OneHour,
+    /// <p><code>SIX_HOURS</code></p>
+    SixHours,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FindingPublishingFrequency {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FindingPublishingFrequency::FifteenMinutes => "FIFTEEN_MINUTES",
+            FindingPublishingFrequency::OneHour => "ONE_HOUR",
+            FindingPublishingFrequency::SixHours => "SIX_HOURS",
+            FindingPublishingFrequency::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FindingPublishingFrequency {
+    fn from(value: &'a str) -> FindingPublishingFrequency {
+        match value {
+            "FIFTEEN_MINUTES" => FindingPublishingFrequency::FifteenMinutes,
+            "ONE_HOUR" => FindingPublishingFrequency::OneHour,
+            "SIX_HOURS" => FindingPublishingFrequency::SixHours,
+            _ => FindingPublishingFrequency::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FindingPublishingFrequency {
+    fn from(value: String) -> FindingPublishingFrequency {
+        FindingPublishingFrequency::from(value.as_str())
+    }
+}
+
+impl From<FindingPublishingFrequency> for String {
+    fn from(value: FindingPublishingFrequency) -> String {
+        match value {
+            FindingPublishingFrequency::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FindingPublishingFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FindingPublishingFrequency {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FindingPublishingFrequency {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FindingPublishingFrequency {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<FindingPublishingFrequency, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FindingPublishingFrequency::from(value))
+    }
+}
+
+/// <p>Types of finding statistics to retrieve.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FindingStatisticType {
+    /// <p><code>COUNT_BY_SEVERITY</code></p>
+    CountBySeverity,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FindingStatisticType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FindingStatisticType::CountBySeverity => "COUNT_BY_SEVERITY",
+            FindingStatisticType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FindingStatisticType {
+    fn from(value: &'a str) -> FindingStatisticType {
+        match value {
+            "COUNT_BY_SEVERITY" => FindingStatisticType::CountBySeverity,
+            _ => FindingStatisticType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FindingStatisticType {
+    fn from(value: String) -> FindingStatisticType {
+        FindingStatisticType::from(value.as_str())
+    }
+}
+
+impl From<FindingStatisticType> for String {
+    fn from(value: FindingStatisticType) -> String {
+        match value {
+            FindingStatisticType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FindingStatisticType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FindingStatisticType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FindingStatisticType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FindingStatisticType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FindingStatisticType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FindingStatisticType::from(value))
+    }
+}
+
 /// <p>Contains information about finding statistics.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -619,7 +1836,37 @@ pub struct GetDetectorRequest {
     pub detector_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl GetDetectorRequest {
+    /// Returns a builder for `GetDetectorRequest`, checking its required members when built.
+    pub fn builder() -> GetDetectorRequestBuilder {
+        GetDetectorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDetectorRequest`](struct.GetDetectorRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDetectorRequestBuilder {
+    detector_id: Option<String>,
+}
+
+impl GetDetectorRequestBuilder {
+    /// Sets the [`detector_id`](struct.GetDetectorRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDetectorRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDetectorRequest, ::rusoto_core::BuildError> {
+        Ok(GetDetectorRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDetectorResponse {
     /// <p>Detector creation timestamp.</p>
@@ -629,13 +1876,13 @@ pub struct GetDetectorResponse {
     /// <p>Finding publishing frequency.</p>
     #[serde(rename = "FindingPublishingFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finding_publishing_frequency: Option<String>,
+    pub finding_publishing_frequency: Option<FindingPublishingFrequency>,
     /// <p>The GuardDuty service role.</p>
     #[serde(rename = "ServiceRole")]
     pub service_role: String,
     /// <p>The detector status.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: DetectorStatus,
     /// <p>The tags of the detector resource.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -656,12 +1903,52 @@ pub struct GetFilterRequest {
     pub filter_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl GetFilterRequest {
+    /// Returns a builder for `GetFilterRequest`, checking its required members when built.
+    pub fn builder() -> GetFilterRequestBuilder {
+        GetFilterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFilterRequest`](struct.GetFilterRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFilterRequestBuilder {
+    detector_id: Option<String>,
+    filter_name: Option<String>,
+}
+
+impl GetFilterRequestBuilder {
+    /// Sets the [`detector_id`](struct.GetFilterRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_name`](struct.GetFilterRequest.html#structfield.filter_name) member.
+    pub fn filter_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFilterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetFilterRequest, ::rusoto_core::BuildError> {
+        Ok(GetFilterRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            filter_name: self
+                .filter_name
+                .ok_or(::rusoto_core::BuildError::missing("filter_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFilterResponse {
     /// <p>Specifies the action that is to be applied to the findings that match the filter.</p>
     #[serde(rename = "Action")]
-    pub action: String,
+    pub action: FilterAction,
     /// <p>The description of the filter.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -696,6 +1983,54 @@ pub struct GetFindingsRequest {
     pub sort_criteria: Option<SortCriteria>,
 }
 
+impl GetFindingsRequest {
+    /// Returns a builder for `GetFindingsRequest`, checking its required members when built.
+    pub fn builder() -> GetFindingsRequestBuilder {
+        GetFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFindingsRequest`](struct.GetFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFindingsRequestBuilder {
+    detector_id: Option<String>,
+    finding_ids: Option<Vec<String>>,
+    sort_criteria: Option<SortCriteria>,
+}
+
+impl GetFindingsRequestBuilder {
+    /// Sets the [`detector_id`](struct.GetFindingsRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_ids`](struct.GetFindingsRequest.html#structfield.finding_ids) member.
+    pub fn finding_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.finding_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_criteria`](struct.GetFindingsRequest.html#structfield.sort_criteria) member.
+    pub fn sort_criteria<T: Into<SortCriteria>>(mut self, value: T) -> Self {
+        self.sort_criteria = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(GetFindingsRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            finding_ids: self
+                .finding_ids
+                .ok_or(::rusoto_core::BuildError::missing("finding_ids"))?,
+            sort_criteria: self.sort_criteria,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFindingsResponse {
@@ -715,7 +2050,57 @@ pub struct GetFindingsStatisticsRequest {
     pub finding_criteria: Option<FindingCriteria>,
     /// <p>Types of finding statistics to retrieve.</p>
     #[serde(rename = "FindingStatisticTypes")]
-    pub finding_statistic_types: Vec<String>,
+    pub finding_statistic_types: Vec<FindingStatisticType>,
+}
+
+impl GetFindingsStatisticsRequest {
+    /// Returns a builder for `GetFindingsStatisticsRequest`, checking its required members when built.
+    pub fn builder() -> GetFindingsStatisticsRequestBuilder {
+        GetFindingsStatisticsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFindingsStatisticsRequest`](struct.GetFindingsStatisticsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFindingsStatisticsRequestBuilder {
+    detector_id: Option<String>,
+    finding_criteria: Option<FindingCriteria>,
+    finding_statistic_types: Option<Vec<FindingStatisticType>>,
+}
+
+impl GetFindingsStatisticsRequestBuilder {
+    /// Sets the [`detector_id`](struct.GetFindingsStatisticsRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_criteria`](struct.GetFindingsStatisticsRequest.html#structfield.finding_criteria) member.
+    pub fn finding_criteria<T: Into<FindingCriteria>>(mut self, value: T) -> Self {
+        self.finding_criteria = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_statistic_types`](struct.GetFindingsStatisticsRequest.html#structfield.finding_statistic_types) member.
+    pub fn finding_statistic_types<T: Into<Vec<FindingStatisticType>>>(mut self, value: T) -> Self {
+        self.finding_statistic_types = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFindingsStatisticsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetFindingsStatisticsRequest, ::rusoto_core::BuildError> {
+        Ok(GetFindingsStatisticsRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            finding_criteria: self.finding_criteria,
+            finding_statistic_types: self.finding_statistic_types.ok_or(
+                ::rusoto_core::BuildError::missing("finding_statistic_types"),
+            )?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -736,12 +2121,52 @@ pub struct GetIPSetRequest {
     pub ip_set_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl GetIPSetRequest {
+    /// Returns a builder for `GetIPSetRequest`, checking its required members when built.
+    pub fn builder() -> GetIPSetRequestBuilder {
+        GetIPSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIPSetRequest`](struct.GetIPSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIPSetRequestBuilder {
+    detector_id: Option<String>,
+    ip_set_id: Option<String>,
+}
+
+impl GetIPSetRequestBuilder {
+    /// Sets the [`detector_id`](struct.GetIPSetRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ip_set_id`](struct.GetIPSetRequest.html#structfield.ip_set_id) member.
+    pub fn ip_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ip_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIPSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetIPSetRequest, ::rusoto_core::BuildError> {
+        Ok(GetIPSetRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            ip_set_id: self
+                .ip_set_id
+                .ok_or(::rusoto_core::BuildError::missing("ip_set_id"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetIPSetResponse {
     /// <p>The format of the file that contains the IPSet.</p>
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: IpSetFormat,
     /// <p>The URI of the file that contains the IPSet. For example (https://s3.us-west-2.amazonaws.com/my-bucket/my-object-key)</p>
     #[serde(rename = "Location")]
     pub location: String,
@@ -750,7 +2175,7 @@ pub struct GetIPSetResponse {
     pub name: String,
     /// <p>The status of ipSet file uploaded.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: IpSetStatus,
     /// <p>The tags of the IP set resource.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -776,6 +2201,38 @@ pub struct GetMasterAccountRequest {
     pub detector_id: String,
 }
 
+impl GetMasterAccountRequest {
+    /// Returns a builder for `GetMasterAccountRequest`, checking its required members when built.
+    pub fn builder() -> GetMasterAccountRequestBuilder {
+        GetMasterAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetMasterAccountRequest`](struct.GetMasterAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct GetMasterAccountRequestBuilder {
+    detector_id: Option<String>,
+}
+
+impl GetMasterAccountRequestBuilder {
+    /// Sets the [`detector_id`](struct.GetMasterAccountRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMasterAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetMasterAccountRequest, ::rusoto_core::BuildError> {
+        Ok(GetMasterAccountRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetMasterAccountResponse {
@@ -794,6 +2251,46 @@ pub struct GetMembersRequest {
     pub detector_id: String,
 }
 
+impl GetMembersRequest {
+    /// Returns a builder for `GetMembersRequest`, checking its required members when built.
+    pub fn builder() -> GetMembersRequestBuilder {
+        GetMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetMembersRequest`](struct.GetMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct GetMembersRequestBuilder {
+    account_ids: Option<Vec<String>>,
+    detector_id: Option<String>,
+}
+
+impl GetMembersRequestBuilder {
+    /// Sets the [`account_ids`](struct.GetMembersRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.GetMembersRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMembersRequest, ::rusoto_core::BuildError> {
+        Ok(GetMembersRequest {
+            account_ids: self
+                .account_ids
+                .ok_or(::rusoto_core::BuildError::missing("account_ids"))?,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetMembersResponse {
@@ -815,12 +2312,54 @@ pub struct GetThreatIntelSetRequest {
     pub threat_intel_set_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl GetThreatIntelSetRequest {
+    /// Returns a builder for `GetThreatIntelSetRequest`, checking its required members when built.
+    pub fn builder() -> GetThreatIntelSetRequestBuilder {
+        GetThreatIntelSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetThreatIntelSetRequest`](struct.GetThreatIntelSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GetThreatIntelSetRequestBuilder {
+    detector_id: Option<String>,
+    threat_intel_set_id: Option<String>,
+}
+
+impl GetThreatIntelSetRequestBuilder {
+    /// Sets the [`detector_id`](struct.GetThreatIntelSetRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`threat_intel_set_id`](struct.GetThreatIntelSetRequest.html#structfield.threat_intel_set_id) member.
+    pub fn threat_intel_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.threat_intel_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetThreatIntelSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetThreatIntelSetRequest, ::rusoto_core::BuildError> {
+        Ok(GetThreatIntelSetRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            threat_intel_set_id: self
+                .threat_intel_set_id
+                .ok_or(::rusoto_core::BuildError::missing("threat_intel_set_id"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetThreatIntelSetResponse {
     /// <p>The format of the threatIntelSet.</p>
     #[serde(rename = "Format")]
-    pub format: String,
+    pub format: ThreatIntelSetFormat,
     /// <p>The URI of the file that contains the ThreatIntelSet. For example (https://s3.us-west-2.amazonaws.com/my-bucket/my-object-key).</p>
     #[serde(rename = "Location")]
     pub location: String,
@@ -829,7 +2368,7 @@ pub struct GetThreatIntelSetResponse {
     pub name: String,
     /// <p>The status of threatIntelSet file uploaded.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: ThreatIntelSetStatus,
     /// <p>The tags of the Threat List resource.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -944,6 +2483,62 @@ pub struct InviteMembersRequest {
     pub message: Option<String>,
 }
 
+impl InviteMembersRequest {
+    /// Returns a builder for `InviteMembersRequest`, checking its required members when built.
+    pub fn builder() -> InviteMembersRequestBuilder {
+        InviteMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InviteMembersRequest`](struct.InviteMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct InviteMembersRequestBuilder {
+    account_ids: Option<Vec<String>>,
+    detector_id: Option<String>,
+    disable_email_notification: Option<bool>,
+    message: Option<String>,
+}
+
+impl InviteMembersRequestBuilder {
+    /// Sets the [`account_ids`](struct.InviteMembersRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.InviteMembersRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disable_email_notification`](struct.InviteMembersRequest.html#structfield.disable_email_notification) member.
+    pub fn disable_email_notification<T: Into<bool>>(mut self, value: T) -> Self {
+        self.disable_email_notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`message`](struct.InviteMembersRequest.html#structfield.message) member.
+    pub fn message<T: Into<String>>(mut self, value: T) -> Self {
+        self.message = Some(value.into());
+        self
+    }
+
+    /// Builds the `InviteMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<InviteMembersRequest, ::rusoto_core::BuildError> {
+        Ok(InviteMembersRequest {
+            account_ids: self
+                .account_ids
+                .ok_or(::rusoto_core::BuildError::missing("account_ids"))?,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            disable_email_notification: self.disable_email_notification,
+            message: self.message,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct InviteMembersResponse {
@@ -952,6 +2547,198 @@ pub struct InviteMembersResponse {
     pub unprocessed_accounts: Vec<UnprocessedAccount>,
 }
 
+/// <p>The format of the file that contains the IPSet.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IpSetFormat {
+    /// <p><code>TXT</code></p>
+    Txt,
+    /// <p><code>STIX</code></p>
+    Stix,
+    /// <p><code>OTX_CSV</code></p>
+    OtxCsv,
+    /// <p><code>ALIEN_VAULT</code></p>
+    AlienVault,
+    /// <p><code>PROOF_POINT</code></p>
+    ProofPoint,
+    /// <p><code>FIRE_EYE</code></p>
+    FireEye,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IpSetFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpSetFormat::Txt => "TXT",
+            IpSetFormat::Stix => "STIX",
+            IpSetFormat::OtxCsv => "OTX_CSV",
+            IpSetFormat::AlienVault => "ALIEN_VAULT",
+            IpSetFormat::ProofPoint => "PROOF_POINT",
+            IpSetFormat::FireEye => "FIRE_EYE",
+            IpSetFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IpSetFormat {
+    fn from(value: &'a str) -> IpSetFormat {
+        match value {
+            "TXT" => IpSetFormat::Txt,
+            "STIX" => IpSetFormat::Stix,
+            "OTX_CSV" => IpSetFormat::OtxCsv,
+            "ALIEN_VAULT" => IpSetFormat::AlienVault,
+            "PROOF_POINT" => IpSetFormat::ProofPoint,
+            "FIRE_EYE" => IpSetFormat::FireEye,
+            _ => IpSetFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpSetFormat {
+    fn from(value: String) -> IpSetFormat {
+        IpSetFormat::from(value.as_str())
+    }
+}
+
+impl From<IpSetFormat> for String {
+    fn from(value: IpSetFormat) -> String {
+        match value {
+            IpSetFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IpSetFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IpSetFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IpSetFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IpSetFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IpSetFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IpSetFormat::from(value))
+    }
+}
+
+/// <p>The status of ipSet file uploaded.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IpSetStatus {
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>ACTIVATING</code></p>
+    Activating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DEACTIVATING</code></p>
+    Deactivating,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// <p><code>DELETE_PENDING</code></p>
+    DeletePending,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IpSetStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IpSetStatus::Inactive => "INACTIVE",
+            IpSetStatus::Activating => "ACTIVATING",
+            IpSetStatus::Active => "ACTIVE",
+            IpSetStatus::Deactivating => "DEACTIVATING",
+            IpSetStatus::Error => "ERROR",
+            IpSetStatus::DeletePending => "DELETE_PENDING",
+            IpSetStatus::Deleted => "DELETED",
+            IpSetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IpSetStatus {
+    fn from(value: &'a str) -> IpSetStatus {
+        match value {
+            "INACTIVE" => IpSetStatus::Inactive,
+            "ACTIVATING" => IpSetStatus::Activating,
+            "ACTIVE" => IpSetStatus::Active,
+            "DEACTIVATING" => IpSetStatus::Deactivating,
+            "ERROR" => IpSetStatus::Error,
+            "DELETE_PENDING" => IpSetStatus::DeletePending,
+            "DELETED" => IpSetStatus::Deleted,
+            _ => IpSetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IpSetStatus {
+    fn from(value: String) -> IpSetStatus {
+        IpSetStatus::from(value.as_str())
+    }
+}
+
+impl From<IpSetStatus> for String {
+    fn from(value: IpSetStatus) -> String {
+        match value {
+            IpSetStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IpSetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IpSetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IpSetStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IpSetStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IpSetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IpSetStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListDetectorsRequest {
     /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 50. The maximum value is 50.</p>
@@ -964,6 +2751,42 @@ pub struct ListDetectorsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDetectorsRequest {
+    /// Returns a builder for `ListDetectorsRequest`, checking its required members when built.
+    pub fn builder() -> ListDetectorsRequestBuilder {
+        ListDetectorsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDetectorsRequest`](struct.ListDetectorsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDetectorsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDetectorsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListDetectorsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDetectorsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDetectorsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDetectorsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDetectorsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDetectorsResponse {
@@ -991,6 +2814,52 @@ pub struct ListFiltersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListFiltersRequest {
+    /// Returns a builder for `ListFiltersRequest`, checking its required members when built.
+    pub fn builder() -> ListFiltersRequestBuilder {
+        ListFiltersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFiltersRequest`](struct.ListFiltersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFiltersRequestBuilder {
+    detector_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListFiltersRequestBuilder {
+    /// Sets the [`detector_id`](struct.ListFiltersRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListFiltersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListFiltersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFiltersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListFiltersRequest, ::rusoto_core::BuildError> {
+        Ok(ListFiltersRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListFiltersResponse {
@@ -1026,6 +2895,68 @@ pub struct ListFindingsRequest {
     pub sort_criteria: Option<SortCriteria>,
 }
 
+impl ListFindingsRequest {
+    /// Returns a builder for `ListFindingsRequest`, checking its required members when built.
+    pub fn builder() -> ListFindingsRequestBuilder {
+        ListFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFindingsRequest`](struct.ListFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFindingsRequestBuilder {
+    detector_id: Option<String>,
+    finding_criteria: Option<FindingCriteria>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_criteria: Option<SortCriteria>,
+}
+
+impl ListFindingsRequestBuilder {
+    /// Sets the [`detector_id`](struct.ListFindingsRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_criteria`](struct.ListFindingsRequest.html#structfield.finding_criteria) member.
+    pub fn finding_criteria<T: Into<FindingCriteria>>(mut self, value: T) -> Self {
+        self.finding_criteria = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListFindingsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListFindingsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_criteria`](struct.ListFindingsRequest.html#structfield.sort_criteria) member.
+    pub fn sort_criteria<T: Into<SortCriteria>>(mut self, value: T) -> Self {
+        self.sort_criteria = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(ListFindingsRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            finding_criteria: self.finding_criteria,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_criteria: self.sort_criteria,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListFindingsResponse {
@@ -1053,6 +2984,52 @@ pub struct ListIPSetsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListIPSetsRequest {
+    /// Returns a builder for `ListIPSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListIPSetsRequestBuilder {
+        ListIPSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListIPSetsRequest`](struct.ListIPSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListIPSetsRequestBuilder {
+    detector_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListIPSetsRequestBuilder {
+    /// Sets the [`detector_id`](struct.ListIPSetsRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListIPSetsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListIPSetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListIPSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListIPSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListIPSetsRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListIPSetsResponse {
@@ -1077,6 +3054,42 @@ pub struct ListInvitationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListInvitationsRequest {
+    /// Returns a builder for `ListInvitationsRequest`, checking its required members when built.
+    pub fn builder() -> ListInvitationsRequestBuilder {
+        ListInvitationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListInvitationsRequest`](struct.ListInvitationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListInvitationsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListInvitationsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListInvitationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListInvitationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListInvitationsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListInvitationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListInvitationsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListInvitationsResponse {
@@ -1109,6 +3122,60 @@ pub struct ListMembersRequest {
     pub only_associated: Option<String>,
 }
 
+impl ListMembersRequest {
+    /// Returns a builder for `ListMembersRequest`, checking its required members when built.
+    pub fn builder() -> ListMembersRequestBuilder {
+        ListMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListMembersRequest`](struct.ListMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListMembersRequestBuilder {
+    detector_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    only_associated: Option<String>,
+}
+
+impl ListMembersRequestBuilder {
+    /// Sets the [`detector_id`](struct.ListMembersRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListMembersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListMembersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`only_associated`](struct.ListMembersRequest.html#structfield.only_associated) member.
+    pub fn only_associated<T: Into<String>>(mut self, value: T) -> Self {
+        self.only_associated = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListMembersRequest, ::rusoto_core::BuildError> {
+        Ok(ListMembersRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            only_associated: self.only_associated,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListMembersResponse {
@@ -1129,6 +3196,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -1153,6 +3252,54 @@ pub struct ListThreatIntelSetsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListThreatIntelSetsRequest {
+    /// Returns a builder for `ListThreatIntelSetsRequest`, checking its required members when built.
+    pub fn builder() -> ListThreatIntelSetsRequestBuilder {
+        ListThreatIntelSetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListThreatIntelSetsRequest`](struct.ListThreatIntelSetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListThreatIntelSetsRequestBuilder {
+    detector_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListThreatIntelSetsRequestBuilder {
+    /// Sets the [`detector_id`](struct.ListThreatIntelSetsRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListThreatIntelSetsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListThreatIntelSetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListThreatIntelSetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListThreatIntelSetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListThreatIntelSetsRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListThreatIntelSetsResponse {
@@ -1306,6 +3453,84 @@ pub struct NetworkInterface {
     pub vpc_id: Option<String>,
 }
 
+/// <p>Order by which the sorted findings are to be displayed.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrderBy {
+    /// <p><code>ASC</code></p>
+    Asc,
+    /// <p><code>DESC</code></p>
+    Desc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OrderBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderBy::Asc => "ASC",
+            OrderBy::Desc => "DESC",
+            OrderBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OrderBy {
+    fn from(value: &'a str) -> OrderBy {
+        match value {
+            "ASC" => OrderBy::Asc,
+            "DESC" => OrderBy::Desc,
+            _ => OrderBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderBy {
+    fn from(value: String) -> OrderBy {
+        OrderBy::from(value.as_str())
+    }
+}
+
+impl From<OrderBy> for String {
+    fn from(value: OrderBy) -> String {
+        match value {
+            OrderBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OrderBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OrderBy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderBy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OrderBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrderBy::from(value))
+    }
+}
+
 /// <p>Continas information about the organization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1512,7 +3737,7 @@ pub struct SortCriteria {
     /// <p>Order by which the sorted findings are to be displayed.</p>
     #[serde(rename = "OrderBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order_by: Option<String>,
+    pub order_by: Option<OrderBy>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1525,6 +3750,48 @@ pub struct StartMonitoringMembersRequest {
     pub detector_id: String,
 }
 
+impl StartMonitoringMembersRequest {
+    /// Returns a builder for `StartMonitoringMembersRequest`, checking its required members when built.
+    pub fn builder() -> StartMonitoringMembersRequestBuilder {
+        StartMonitoringMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartMonitoringMembersRequest`](struct.StartMonitoringMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct StartMonitoringMembersRequestBuilder {
+    account_ids: Option<Vec<String>>,
+    detector_id: Option<String>,
+}
+
+impl StartMonitoringMembersRequestBuilder {
+    /// Sets the [`account_ids`](struct.StartMonitoringMembersRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.StartMonitoringMembersRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartMonitoringMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartMonitoringMembersRequest, ::rusoto_core::BuildError> {
+        Ok(StartMonitoringMembersRequest {
+            account_ids: self
+                .account_ids
+                .ok_or(::rusoto_core::BuildError::missing("account_ids"))?,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartMonitoringMembersResponse {
@@ -1543,6 +3810,48 @@ pub struct StopMonitoringMembersRequest {
     pub detector_id: String,
 }
 
+impl StopMonitoringMembersRequest {
+    /// Returns a builder for `StopMonitoringMembersRequest`, checking its required members when built.
+    pub fn builder() -> StopMonitoringMembersRequestBuilder {
+        StopMonitoringMembersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopMonitoringMembersRequest`](struct.StopMonitoringMembersRequest.html).
+#[derive(Default, Debug)]
+pub struct StopMonitoringMembersRequestBuilder {
+    account_ids: Option<Vec<String>>,
+    detector_id: Option<String>,
+}
+
+impl StopMonitoringMembersRequestBuilder {
+    /// Sets the [`account_ids`](struct.StopMonitoringMembersRequest.html#structfield.account_ids) member.
+    pub fn account_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.account_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.StopMonitoringMembersRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopMonitoringMembersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopMonitoringMembersRequest, ::rusoto_core::BuildError> {
+        Ok(StopMonitoringMembersRequest {
+            account_ids: self
+                .account_ids
+                .ok_or(::rusoto_core::BuildError::missing("account_ids"))?,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopMonitoringMembersResponse {
@@ -1575,10 +3884,242 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
 
+/// <p>The format of the file that contains the ThreatIntelSet.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ThreatIntelSetFormat {
+    /// <p><code>TXT</code></p>
+    Txt,
+    /// <p><code>STIX</code></p>
+    Stix,
+    /// <p><code>OTX_CSV</code></p>
+    OtxCsv,
+    /// <p><code>ALIEN_VAULT</code></p>
+    AlienVault,
+    /// <p><code>PROOF_POINT</code></p>
+    ProofPoint,
+    /// <p><code>FIRE_EYE</code></p>
+    FireEye,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ThreatIntelSetFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThreatIntelSetFormat::Txt => "TXT",
+            ThreatIntelSetFormat::Stix => "STIX",
+            ThreatIntelSetFormat::OtxCsv => "OTX_CSV",
+            ThreatIntelSetFormat::AlienVault => "ALIEN_VAULT",
+            ThreatIntelSetFormat::ProofPoint => "PROOF_POINT",
+            ThreatIntelSetFormat::FireEye => "FIRE_EYE",
+            ThreatIntelSetFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ThreatIntelSetFormat {
+    fn from(value: &'a str) -> ThreatIntelSetFormat {
+        match value {
+            "TXT" => ThreatIntelSetFormat::Txt,
+            "STIX" => ThreatIntelSetFormat::Stix,
+            "OTX_CSV" => ThreatIntelSetFormat::OtxCsv,
+            "ALIEN_VAULT" => ThreatIntelSetFormat::AlienVault,
+            "PROOF_POINT" => ThreatIntelSetFormat::ProofPoint,
+            "FIRE_EYE" => ThreatIntelSetFormat::FireEye,
+            _ => ThreatIntelSetFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThreatIntelSetFormat {
+    fn from(value: String) -> ThreatIntelSetFormat {
+        ThreatIntelSetFormat::from(value.as_str())
+    }
+}
+
+impl From<ThreatIntelSetFormat> for String {
+    fn from(value: ThreatIntelSetFormat) -> String {
+        match value {
+            ThreatIntelSetFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ThreatIntelSetFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThreatIntelSetFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThreatIntelSetFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThreatIntelSetFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ThreatIntelSetFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ThreatIntelSetFormat::from(value))
+    }
+}
+
+/// <p>The status of threatIntelSet file uploaded.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ThreatIntelSetStatus {
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>ACTIVATING</code></p>
+    Activating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DEACTIVATING</code></p>
+    Deactivating,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// <p><code>DELETE_PENDING</code></p>
+    DeletePending,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ThreatIntelSetStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThreatIntelSetStatus::Inactive => "INACTIVE",
+            ThreatIntelSetStatus::Activating => "ACTIVATING",
+            ThreatIntelSetStatus::Active => "ACTIVE",
+            ThreatIntelSetStatus::Deactivating => "DEACTIVATING",
+            ThreatIntelSetStatus::Error => "ERROR",
+            ThreatIntelSetStatus::DeletePending => "DELETE_PENDING",
+            ThreatIntelSetStatus::Deleted => "DELETED",
+            ThreatIntelSetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ThreatIntelSetStatus {
+    fn from(value: &'a str) -> ThreatIntelSetStatus {
+        match value {
+            "INACTIVE" => ThreatIntelSetStatus::Inactive,
+            "ACTIVATING" => ThreatIntelSetStatus::Activating,
+            "ACTIVE" => ThreatIntelSetStatus::Active,
+            "DEACTIVATING" => ThreatIntelSetStatus::Deactivating,
+            "ERROR" => ThreatIntelSetStatus::Error,
+            "DELETE_PENDING" => ThreatIntelSetStatus::DeletePending,
+            "DELETED" => ThreatIntelSetStatus::Deleted,
+            _ => ThreatIntelSetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThreatIntelSetStatus {
+    fn from(value: String) -> ThreatIntelSetStatus {
+        ThreatIntelSetStatus::from(value.as_str())
+    }
+}
+
+impl From<ThreatIntelSetStatus> for String {
+    fn from(value: ThreatIntelSetStatus) -> String {
+        match value {
+            ThreatIntelSetStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ThreatIntelSetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThreatIntelSetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThreatIntelSetStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThreatIntelSetStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ThreatIntelSetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ThreatIntelSetStatus::from(value))
+    }
+}
+
 /// <p>An instance of a threat intelligence detail that constitutes evidence for the finding.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1603,6 +4144,48 @@ pub struct UnarchiveFindingsRequest {
     pub finding_ids: Vec<String>,
 }
 
+impl UnarchiveFindingsRequest {
+    /// Returns a builder for `UnarchiveFindingsRequest`, checking its required members when built.
+    pub fn builder() -> UnarchiveFindingsRequestBuilder {
+        UnarchiveFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UnarchiveFindingsRequest`](struct.UnarchiveFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct UnarchiveFindingsRequestBuilder {
+    detector_id: Option<String>,
+    finding_ids: Option<Vec<String>>,
+}
+
+impl UnarchiveFindingsRequestBuilder {
+    /// Sets the [`detector_id`](struct.UnarchiveFindingsRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_ids`](struct.UnarchiveFindingsRequest.html#structfield.finding_ids) member.
+    pub fn finding_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.finding_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `UnarchiveFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UnarchiveFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(UnarchiveFindingsRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            finding_ids: self
+                .finding_ids
+                .ok_or(::rusoto_core::BuildError::missing("finding_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UnarchiveFindingsResponse {}
@@ -1629,6 +4212,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -1645,7 +4268,56 @@ pub struct UpdateDetectorRequest {
     /// <p>A enum value that specifies how frequently customer got Finding updates published.</p>
     #[serde(rename = "FindingPublishingFrequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finding_publishing_frequency: Option<String>,
+    pub finding_publishing_frequency: Option<FindingPublishingFrequency>,
+}
+
+impl UpdateDetectorRequest {
+    /// Returns a builder for `UpdateDetectorRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDetectorRequestBuilder {
+        UpdateDetectorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDetectorRequest`](struct.UpdateDetectorRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDetectorRequestBuilder {
+    detector_id: Option<String>,
+    enable: Option<bool>,
+    finding_publishing_frequency: Option<FindingPublishingFrequency>,
+}
+
+impl UpdateDetectorRequestBuilder {
+    /// Sets the [`detector_id`](struct.UpdateDetectorRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable`](struct.UpdateDetectorRequest.html#structfield.enable) member.
+    pub fn enable<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_publishing_frequency`](struct.UpdateDetectorRequest.html#structfield.finding_publishing_frequency) member.
+    pub fn finding_publishing_frequency<T: Into<FindingPublishingFrequency>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.finding_publishing_frequency = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDetectorRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateDetectorRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDetectorRequest {
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            enable: self.enable,
+            finding_publishing_frequency: self.finding_publishing_frequency,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1657,7 +4329,7 @@ pub struct UpdateFilterRequest {
     /// <p>Specifies the action that is to be applied to the findings that match the filter.</p>
     #[serde(rename = "Action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<FilterAction>,
     /// <p>The description of the filter.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1678,6 +4350,78 @@ pub struct UpdateFilterRequest {
     pub rank: Option<i64>,
 }
 
+impl UpdateFilterRequest {
+    /// Returns a builder for `UpdateFilterRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFilterRequestBuilder {
+        UpdateFilterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFilterRequest`](struct.UpdateFilterRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFilterRequestBuilder {
+    action: Option<FilterAction>,
+    description: Option<String>,
+    detector_id: Option<String>,
+    filter_name: Option<String>,
+    finding_criteria: Option<FindingCriteria>,
+    rank: Option<i64>,
+}
+
+impl UpdateFilterRequestBuilder {
+    /// Sets the [`action`](struct.UpdateFilterRequest.html#structfield.action) member.
+    pub fn action<T: Into<FilterAction>>(mut self, value: T) -> Self {
+        self.action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateFilterRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.UpdateFilterRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_name`](struct.UpdateFilterRequest.html#structfield.filter_name) member.
+    pub fn filter_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_criteria`](struct.UpdateFilterRequest.html#structfield.finding_criteria) member.
+    pub fn finding_criteria<T: Into<FindingCriteria>>(mut self, value: T) -> Self {
+        self.finding_criteria = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rank`](struct.UpdateFilterRequest.html#structfield.rank) member.
+    pub fn rank<T: Into<i64>>(mut self, value: T) -> Self {
+        self.rank = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFilterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateFilterRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFilterRequest {
+            action: self.action,
+            description: self.description,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            filter_name: self
+                .filter_name
+                .ok_or(::rusoto_core::BuildError::missing("filter_name"))?,
+            finding_criteria: self.finding_criteria,
+            rank: self.rank,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateFilterResponse {
@@ -1686,7 +4430,7 @@ pub struct UpdateFilterResponse {
     pub name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateFindingsFeedbackRequest {
     /// <p>Additional feedback about the GuardDuty findings.</p>
     #[serde(rename = "Comments")]
@@ -1697,12 +4441,72 @@ pub struct UpdateFindingsFeedbackRequest {
     pub detector_id: String,
     /// <p>Valid values: USEFUL | NOT_USEFUL</p>
     #[serde(rename = "Feedback")]
-    pub feedback: String,
+    pub feedback: Feedback,
     /// <p>IDs of the findings that you want to mark as useful or not useful.</p>
     #[serde(rename = "FindingIds")]
     pub finding_ids: Vec<String>,
 }
 
+impl UpdateFindingsFeedbackRequest {
+    /// Returns a builder for `UpdateFindingsFeedbackRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFindingsFeedbackRequestBuilder {
+        UpdateFindingsFeedbackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFindingsFeedbackRequest`](struct.UpdateFindingsFeedbackRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFindingsFeedbackRequestBuilder {
+    comments: Option<String>,
+    detector_id: Option<String>,
+    feedback: Option<Feedback>,
+    finding_ids: Option<Vec<String>>,
+}
+
+impl UpdateFindingsFeedbackRequestBuilder {
+    /// Sets the [`comments`](struct.UpdateFindingsFeedbackRequest.html#structfield.comments) member.
+    pub fn comments<T: Into<String>>(mut self, value: T) -> Self {
+        self.comments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.UpdateFindingsFeedbackRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`feedback`](struct.UpdateFindingsFeedbackRequest.html#structfield.feedback) member.
+    pub fn feedback<T: Into<Feedback>>(mut self, value: T) -> Self {
+        self.feedback = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_ids`](struct.UpdateFindingsFeedbackRequest.html#structfield.finding_ids) member.
+    pub fn finding_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.finding_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFindingsFeedbackRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateFindingsFeedbackRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFindingsFeedbackRequest {
+            comments: self.comments,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            feedback: self
+                .feedback
+                .ok_or(::rusoto_core::BuildError::missing("feedback"))?,
+            finding_ids: self
+                .finding_ids
+                .ok_or(::rusoto_core::BuildError::missing("finding_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateFindingsFeedbackResponse {}
@@ -1729,6 +4533,70 @@ pub struct UpdateIPSetRequest {
     pub name: Option<String>,
 }
 
+impl UpdateIPSetRequest {
+    /// Returns a builder for `UpdateIPSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateIPSetRequestBuilder {
+        UpdateIPSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateIPSetRequest`](struct.UpdateIPSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateIPSetRequestBuilder {
+    activate: Option<bool>,
+    detector_id: Option<String>,
+    ip_set_id: Option<String>,
+    location: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateIPSetRequestBuilder {
+    /// Sets the [`activate`](struct.UpdateIPSetRequest.html#structfield.activate) member.
+    pub fn activate<T: Into<bool>>(mut self, value: T) -> Self {
+        self.activate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.UpdateIPSetRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ip_set_id`](struct.UpdateIPSetRequest.html#structfield.ip_set_id) member.
+    pub fn ip_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ip_set_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location`](struct.UpdateIPSetRequest.html#structfield.location) member.
+    pub fn location<T: Into<String>>(mut self, value: T) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateIPSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateIPSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateIPSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateIPSetRequest {
+            activate: self.activate,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            ip_set_id: self
+                .ip_set_id
+                .ok_or(::rusoto_core::BuildError::missing("ip_set_id"))?,
+            location: self.location,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateIPSetResponse {}
@@ -1755,6 +4623,72 @@ pub struct UpdateThreatIntelSetRequest {
     pub threat_intel_set_id: String,
 }
 
+impl UpdateThreatIntelSetRequest {
+    /// Returns a builder for `UpdateThreatIntelSetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateThreatIntelSetRequestBuilder {
+        UpdateThreatIntelSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateThreatIntelSetRequest`](struct.UpdateThreatIntelSetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateThreatIntelSetRequestBuilder {
+    activate: Option<bool>,
+    detector_id: Option<String>,
+    location: Option<String>,
+    name: Option<String>,
+    threat_intel_set_id: Option<String>,
+}
+
+impl UpdateThreatIntelSetRequestBuilder {
+    /// Sets the [`activate`](struct.UpdateThreatIntelSetRequest.html#structfield.activate) member.
+    pub fn activate<T: Into<bool>>(mut self, value: T) -> Self {
+        self.activate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detector_id`](struct.UpdateThreatIntelSetRequest.html#structfield.detector_id) member.
+    pub fn detector_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.detector_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`location`](struct.UpdateThreatIntelSetRequest.html#structfield.location) member.
+    pub fn location<T: Into<String>>(mut self, value: T) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateThreatIntelSetRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`threat_intel_set_id`](struct.UpdateThreatIntelSetRequest.html#structfield.threat_intel_set_id) member.
+    pub fn threat_intel_set_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.threat_intel_set_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateThreatIntelSetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateThreatIntelSetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateThreatIntelSetRequest {
+            activate: self.activate,
+            detector_id: self
+                .detector_id
+                .ok_or(::rusoto_core::BuildError::missing("detector_id"))?,
+            location: self.location,
+            name: self.name,
+            threat_intel_set_id: self
+                .threat_intel_set_id
+                .ok_or(::rusoto_core::BuildError::missing("threat_intel_set_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateThreatIntelSetResponse {}
@@ -3824,6 +6758,282 @@ pub trait GuardDuty {
         input: UpdateThreatIntelSetRequest,
     ) -> RusotoFuture<UpdateThreatIntelSetResponse, UpdateThreatIntelSetError>;
 }
+impl<T: ?Sized + GuardDuty> GuardDuty for ::std::sync::Arc<T> {
+    fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> RusotoFuture<AcceptInvitationResponse, AcceptInvitationError> {
+        (**self).accept_invitation(input)
+    }
+    fn archive_findings(
+        &self,
+        input: ArchiveFindingsRequest,
+    ) -> RusotoFuture<ArchiveFindingsResponse, ArchiveFindingsError> {
+        (**self).archive_findings(input)
+    }
+    fn create_detector(
+        &self,
+        input: CreateDetectorRequest,
+    ) -> RusotoFuture<CreateDetectorResponse, CreateDetectorError> {
+        (**self).create_detector(input)
+    }
+    fn create_filter(
+        &self,
+        input: CreateFilterRequest,
+    ) -> RusotoFuture<CreateFilterResponse, CreateFilterError> {
+        (**self).create_filter(input)
+    }
+    fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> RusotoFuture<CreateIPSetResponse, CreateIPSetError> {
+        (**self).create_ip_set(input)
+    }
+    fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> RusotoFuture<CreateMembersResponse, CreateMembersError> {
+        (**self).create_members(input)
+    }
+    fn create_sample_findings(
+        &self,
+        input: CreateSampleFindingsRequest,
+    ) -> RusotoFuture<CreateSampleFindingsResponse, CreateSampleFindingsError> {
+        (**self).create_sample_findings(input)
+    }
+    fn create_threat_intel_set(
+        &self,
+        input: CreateThreatIntelSetRequest,
+    ) -> RusotoFuture<CreateThreatIntelSetResponse, CreateThreatIntelSetError> {
+        (**self).create_threat_intel_set(input)
+    }
+    fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> RusotoFuture<DeclineInvitationsResponse, DeclineInvitationsError> {
+        (**self).decline_invitations(input)
+    }
+    fn delete_detector(
+        &self,
+        input: DeleteDetectorRequest,
+    ) -> RusotoFuture<DeleteDetectorResponse, DeleteDetectorError> {
+        (**self).delete_detector(input)
+    }
+    fn delete_filter(
+        &self,
+        input: DeleteFilterRequest,
+    ) -> RusotoFuture<DeleteFilterResponse, DeleteFilterError> {
+        (**self).delete_filter(input)
+    }
+    fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> RusotoFuture<DeleteIPSetResponse, DeleteIPSetError> {
+        (**self).delete_ip_set(input)
+    }
+    fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> RusotoFuture<DeleteInvitationsResponse, DeleteInvitationsError> {
+        (**self).delete_invitations(input)
+    }
+    fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> RusotoFuture<DeleteMembersResponse, DeleteMembersError> {
+        (**self).delete_members(input)
+    }
+    fn delete_threat_intel_set(
+        &self,
+        input: DeleteThreatIntelSetRequest,
+    ) -> RusotoFuture<DeleteThreatIntelSetResponse, DeleteThreatIntelSetError> {
+        (**self).delete_threat_intel_set(input)
+    }
+    fn disassociate_from_master_account(
+        &self,
+        input: DisassociateFromMasterAccountRequest,
+    ) -> RusotoFuture<DisassociateFromMasterAccountResponse, DisassociateFromMasterAccountError>
+    {
+        (**self).disassociate_from_master_account(input)
+    }
+    fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> RusotoFuture<DisassociateMembersResponse, DisassociateMembersError> {
+        (**self).disassociate_members(input)
+    }
+    fn get_detector(
+        &self,
+        input: GetDetectorRequest,
+    ) -> RusotoFuture<GetDetectorResponse, GetDetectorError> {
+        (**self).get_detector(input)
+    }
+    fn get_filter(
+        &self,
+        input: GetFilterRequest,
+    ) -> RusotoFuture<GetFilterResponse, GetFilterError> {
+        (**self).get_filter(input)
+    }
+    fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> RusotoFuture<GetFindingsResponse, GetFindingsError> {
+        (**self).get_findings(input)
+    }
+    fn get_findings_statistics(
+        &self,
+        input: GetFindingsStatisticsRequest,
+    ) -> RusotoFuture<GetFindingsStatisticsResponse, GetFindingsStatisticsError> {
+        (**self).get_findings_statistics(input)
+    }
+    fn get_ip_set(&self, input: GetIPSetRequest) -> RusotoFuture<GetIPSetResponse, GetIPSetError> {
+        (**self).get_ip_set(input)
+    }
+    fn get_invitations_count(
+        &self,
+    ) -> RusotoFuture<GetInvitationsCountResponse, GetInvitationsCountError> {
+        (**self).get_invitations_count()
+    }
+    fn get_master_account(
+        &self,
+        input: GetMasterAccountRequest,
+    ) -> RusotoFuture<GetMasterAccountResponse, GetMasterAccountError> {
+        (**self).get_master_account(input)
+    }
+    fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> RusotoFuture<GetMembersResponse, GetMembersError> {
+        (**self).get_members(input)
+    }
+    fn get_threat_intel_set(
+        &self,
+        input: GetThreatIntelSetRequest,
+    ) -> RusotoFuture<GetThreatIntelSetResponse, GetThreatIntelSetError> {
+        (**self).get_threat_intel_set(input)
+    }
+    fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> RusotoFuture<InviteMembersResponse, InviteMembersError> {
+        (**self).invite_members(input)
+    }
+    fn list_detectors(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> RusotoFuture<ListDetectorsResponse, ListDetectorsError> {
+        (**self).list_detectors(input)
+    }
+    fn list_filters(
+        &self,
+        input: ListFiltersRequest,
+    ) -> RusotoFuture<ListFiltersResponse, ListFiltersError> {
+        (**self).list_filters(input)
+    }
+    fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> RusotoFuture<ListFindingsResponse, ListFindingsError> {
+        (**self).list_findings(input)
+    }
+    fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> RusotoFuture<ListIPSetsResponse, ListIPSetsError> {
+        (**self).list_ip_sets(input)
+    }
+    fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> RusotoFuture<ListInvitationsResponse, ListInvitationsError> {
+        (**self).list_invitations(input)
+    }
+    fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> RusotoFuture<ListMembersResponse, ListMembersError> {
+        (**self).list_members(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_threat_intel_sets(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> RusotoFuture<ListThreatIntelSetsResponse, ListThreatIntelSetsError> {
+        (**self).list_threat_intel_sets(input)
+    }
+    fn start_monitoring_members(
+        &self,
+        input: StartMonitoringMembersRequest,
+    ) -> RusotoFuture<StartMonitoringMembersResponse, StartMonitoringMembersError> {
+        (**self).start_monitoring_members(input)
+    }
+    fn stop_monitoring_members(
+        &self,
+        input: StopMonitoringMembersRequest,
+    ) -> RusotoFuture<StopMonitoringMembersResponse, StopMonitoringMembersError> {
+        (**self).stop_monitoring_members(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unarchive_findings(
+        &self,
+        input: UnarchiveFindingsRequest,
+    ) -> RusotoFuture<UnarchiveFindingsResponse, UnarchiveFindingsError> {
+        (**self).unarchive_findings(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_detector(
+        &self,
+        input: UpdateDetectorRequest,
+    ) -> RusotoFuture<UpdateDetectorResponse, UpdateDetectorError> {
+        (**self).update_detector(input)
+    }
+    fn update_filter(
+        &self,
+        input: UpdateFilterRequest,
+    ) -> RusotoFuture<UpdateFilterResponse, UpdateFilterError> {
+        (**self).update_filter(input)
+    }
+    fn update_findings_feedback(
+        &self,
+        input: UpdateFindingsFeedbackRequest,
+    ) -> RusotoFuture<UpdateFindingsFeedbackResponse, UpdateFindingsFeedbackError> {
+        (**self).update_findings_feedback(input)
+    }
+    fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> RusotoFuture<UpdateIPSetResponse, UpdateIPSetError> {
+        (**self).update_ip_set(input)
+    }
+    fn update_threat_intel_set(
+        &self,
+        input: UpdateThreatIntelSetRequest,
+    ) -> RusotoFuture<UpdateThreatIntelSetResponse, UpdateThreatIntelSetError> {
+        (**self).update_threat_intel_set(input)
+    }
+}
+/// The error codes Amazon GuardDuty retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon GuardDuty API.
 #[derive(Clone)]
 pub struct GuardDutyClient {
@@ -3839,6 +7049,27 @@ impl GuardDutyClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_GUARDDUTY`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> GuardDutyClient {
+        Self::new(region::Region::from_env_for_service("GUARDDUTY"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `guardduty`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> GuardDutyClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("guardduty", "GUARDDUTY"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3857,7 +7088,54 @@ impl GuardDutyClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> GuardDutyClient {
-        GuardDutyClient { client, region }
+        GuardDutyClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "guardduty")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "guardduty",
+            "guardduty",
+            "GUARDDUTY",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `GuardDuty` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn GuardDuty + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for GuardDutyClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon GuardDuty"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "guardduty"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> GuardDutyClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Health APIs and Notifications clients.
+//!
+//! Makes a single read-only `DescribeEntityAggregates` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_health::{AWSHealth, AWSHealthClient, DescribeEntityAggregatesRequest};
+
+fn main() {
+    let client = AWSHealthClient::from_env();
+    match client
+        .describe_entity_aggregates(DescribeEntityAggregatesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeEntityAggregates succeeded"),
+        Err(error) => {
+            eprintln!("DescribeEntityAggregates failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
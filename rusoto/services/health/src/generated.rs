@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Information about an entity that is affected by a Health event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -48,12 +49,13 @@ pub struct AffectedEntity {
     pub event_arn: Option<String>,
     /// <p>The most recent time that the entity was updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<EpochTimestamp>,
     /// <p>The most recent status of the entity affected by the event. The possible values are <code>IMPAIRED</code>, <code>UNIMPAIRED</code>, and <code>UNKNOWN</code>.</p>
     #[serde(rename = "statusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_code: Option<String>,
+    pub status_code: Option<EntityStatusCode>,
     /// <p>A map of entity tags attached to the affected entity.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -65,12 +67,14 @@ pub struct AffectedEntity {
 pub struct DateTimeRange {
     /// <p>The starting date and time of a time range.</p>
     #[serde(rename = "from")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub from: Option<f64>,
+    pub from: Option<EpochTimestamp>,
     /// <p>The ending date and time of a time range.</p>
     #[serde(rename = "to")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub to: Option<f64>,
+    pub to: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -92,6 +96,62 @@ pub struct DescribeAffectedEntitiesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeAffectedEntitiesRequest {
+    /// Returns a builder for `DescribeAffectedEntitiesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAffectedEntitiesRequestBuilder {
+        DescribeAffectedEntitiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAffectedEntitiesRequest`](struct.DescribeAffectedEntitiesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAffectedEntitiesRequestBuilder {
+    filter: Option<EntityFilter>,
+    locale: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeAffectedEntitiesRequestBuilder {
+    /// Sets the [`filter`](struct.DescribeAffectedEntitiesRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<EntityFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`locale`](struct.DescribeAffectedEntitiesRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<String>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeAffectedEntitiesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeAffectedEntitiesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAffectedEntitiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAffectedEntitiesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAffectedEntitiesRequest {
+            filter: self
+                .filter
+                .ok_or(::rusoto_core::BuildError::missing("filter"))?,
+            locale: self.locale,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAffectedEntitiesResponse {
@@ -113,6 +173,36 @@ pub struct DescribeEntityAggregatesRequest {
     pub event_arns: Option<Vec<String>>,
 }
 
+impl DescribeEntityAggregatesRequest {
+    /// Returns a builder for `DescribeEntityAggregatesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEntityAggregatesRequestBuilder {
+        DescribeEntityAggregatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEntityAggregatesRequest`](struct.DescribeEntityAggregatesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEntityAggregatesRequestBuilder {
+    event_arns: Option<Vec<String>>,
+}
+
+impl DescribeEntityAggregatesRequestBuilder {
+    /// Sets the [`event_arns`](struct.DescribeEntityAggregatesRequest.html#structfield.event_arns) member.
+    pub fn event_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEntityAggregatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEntityAggregatesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEntityAggregatesRequest {
+            event_arns: self.event_arns,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEntityAggregatesResponse {
@@ -122,11 +212,11 @@ pub struct DescribeEntityAggregatesResponse {
     pub entity_aggregates: Option<Vec<EntityAggregate>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeEventAggregatesRequest {
     /// <p>The only currently supported value is <code>eventTypeCategory</code>.</p>
     #[serde(rename = "aggregateField")]
-    pub aggregate_field: String,
+    pub aggregate_field: EventAggregateField,
     /// <p>Values to narrow the results returned.</p>
     #[serde(rename = "filter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -141,6 +231,62 @@ pub struct DescribeEventAggregatesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeEventAggregatesRequest {
+    /// Returns a builder for `DescribeEventAggregatesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEventAggregatesRequestBuilder {
+        DescribeEventAggregatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventAggregatesRequest`](struct.DescribeEventAggregatesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventAggregatesRequestBuilder {
+    aggregate_field: Option<EventAggregateField>,
+    filter: Option<EventFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeEventAggregatesRequestBuilder {
+    /// Sets the [`aggregate_field`](struct.DescribeEventAggregatesRequest.html#structfield.aggregate_field) member.
+    pub fn aggregate_field<T: Into<EventAggregateField>>(mut self, value: T) -> Self {
+        self.aggregate_field = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter`](struct.DescribeEventAggregatesRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<EventFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeEventAggregatesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeEventAggregatesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventAggregatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventAggregatesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEventAggregatesRequest {
+            aggregate_field: self
+                .aggregate_field
+                .ok_or(::rusoto_core::BuildError::missing("aggregate_field"))?,
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEventAggregatesResponse {
@@ -165,6 +311,46 @@ pub struct DescribeEventDetailsRequest {
     pub locale: Option<String>,
 }
 
+impl DescribeEventDetailsRequest {
+    /// Returns a builder for `DescribeEventDetailsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEventDetailsRequestBuilder {
+        DescribeEventDetailsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventDetailsRequest`](struct.DescribeEventDetailsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventDetailsRequestBuilder {
+    event_arns: Option<Vec<String>>,
+    locale: Option<String>,
+}
+
+impl DescribeEventDetailsRequestBuilder {
+    /// Sets the [`event_arns`](struct.DescribeEventDetailsRequest.html#structfield.event_arns) member.
+    pub fn event_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`locale`](struct.DescribeEventDetailsRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<String>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventDetailsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventDetailsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEventDetailsRequest {
+            event_arns: self
+                .event_arns
+                .ok_or(::rusoto_core::BuildError::missing("event_arns"))?,
+            locale: self.locale,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEventDetailsResponse {
@@ -198,6 +384,60 @@ pub struct DescribeEventTypesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeEventTypesRequest {
+    /// Returns a builder for `DescribeEventTypesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEventTypesRequestBuilder {
+        DescribeEventTypesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventTypesRequest`](struct.DescribeEventTypesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventTypesRequestBuilder {
+    filter: Option<EventTypeFilter>,
+    locale: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeEventTypesRequestBuilder {
+    /// Sets the [`filter`](struct.DescribeEventTypesRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<EventTypeFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`locale`](struct.DescribeEventTypesRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<String>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeEventTypesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeEventTypesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventTypesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventTypesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEventTypesRequest {
+            filter: self.filter,
+            locale: self.locale,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEventTypesResponse {
@@ -231,6 +471,58 @@ pub struct DescribeEventsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeEventsRequest {
+    /// Returns a builder for `DescribeEventsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEventsRequestBuilder {
+        DescribeEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventsRequest`](struct.DescribeEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventsRequestBuilder {
+    filter: Option<EventFilter>,
+    locale: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeEventsRequestBuilder {
+    /// Sets the [`filter`](struct.DescribeEventsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<EventFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`locale`](struct.DescribeEventsRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<String>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeEventsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeEventsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeEventsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEventsRequest {
+            filter: self.filter,
+            locale: self.locale,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEventsResponse {
@@ -279,13 +571,97 @@ pub struct EntityFilter {
     /// <p>A list of entity status codes (<code>IMPAIRED</code>, <code>UNIMPAIRED</code>, or <code>UNKNOWN</code>).</p>
     #[serde(rename = "statusCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_codes: Option<Vec<String>>,
+    pub status_codes: Option<Vec<EntityStatusCode>>,
     /// <p>A map of entity tags attached to the affected entity.</p>
     #[serde(rename = "tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<::std::collections::HashMap<String, String>>>,
 }
 
+/// <p>The most recent status of the entity affected by the event. The possible values are <code>IMPAIRED</code>, <code>UNIMPAIRED</code>, and <code>UNKNOWN</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EntityStatusCode {
+    /// <p><code>IMPAIRED</code></p>
+    Impaired,
+    /// <p><code>UNIMPAIRED</code></p>
+    Unimpaired,
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>RESOLVED</code></p>
+    Resolved,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EntityStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityStatusCode::Impaired => "IMPAIRED",
+            EntityStatusCode::Unimpaired => "UNIMPAIRED",
+            EntityStatusCode::UnknownValue => "UNKNOWN",
+            EntityStatusCode::Pending => "PENDING",
+            EntityStatusCode::Resolved => "RESOLVED",
+            EntityStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EntityStatusCode {
+    fn from(value: &'a str) -> EntityStatusCode {
+        match value {
+            "IMPAIRED" => EntityStatusCode::Impaired,
+            "UNIMPAIRED" => EntityStatusCode::Unimpaired,
+            "UNKNOWN" => EntityStatusCode::UnknownValue,
+            "PENDING" => EntityStatusCode::Pending,
+            "RESOLVED" => EntityStatusCode::Resolved,
+            _ => EntityStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityStatusCode {
+    fn from(value: String) -> EntityStatusCode {
+        EntityStatusCode::from(value.as_str())
+    }
+}
+
+impl From<EntityStatusCode> for String {
+    fn from(value: EntityStatusCode) -> String {
+        match value {
+            EntityStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EntityStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EntityStatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityStatusCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EntityStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EntityStatusCode::from(value))
+    }
+}
+
 /// <p>Summary information about an event, returned by the <a>DescribeEvents</a> operation. The <a>DescribeEventDetails</a> operation also returns this information, as well as the <a>EventDescription</a> and additional event metadata.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -300,20 +676,22 @@ pub struct Event {
     pub availability_zone: Option<String>,
     /// <p>The date and time that the event ended.</p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The category of the event. Possible values are <code>issue</code>, <code>scheduledChange</code>, and <code>accountNotification</code>.</p>
     #[serde(rename = "eventTypeCategory")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type_category: Option<String>,
+    pub event_type_category: Option<EventTypeCategory>,
     /// <p>The unique identifier for the event type. The format is <code>AWS_<i>SERVICE</i>_<i>DESCRIPTION</i> </code>; for example, <code>AWS_EC2_SYSTEM_MAINTENANCE_EVENT</code>.</p>
     #[serde(rename = "eventTypeCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_type_code: Option<String>,
     /// <p>The most recent date and time that the event was updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<EpochTimestamp>,
     /// <p>The AWS region name of the event.</p>
     #[serde(rename = "region")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -324,12 +702,13 @@ pub struct Event {
     pub service: Option<String>,
     /// <p>The date and time that the event began.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>The most recent status of the event. Possible values are <code>open</code>, <code>closed</code>, and <code>upcoming</code>.</p>
     #[serde(rename = "statusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_code: Option<String>,
+    pub status_code: Option<EventStatusCode>,
 }
 
 /// <p>The number of events of each issue type. Returned by the <a>DescribeEventAggregates</a> operation.</p>
@@ -346,6 +725,74 @@ pub struct EventAggregate {
     pub count: Option<i64>,
 }
 
+/// <p>The only currently supported value is <code>eventTypeCategory</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventAggregateField {
+    /// <p><code>eventTypeCategory</code></p>
+    EventTypeCategory,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventAggregateField {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventAggregateField::EventTypeCategory => "eventTypeCategory",
+            EventAggregateField::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventAggregateField {
+    fn from(value: &'a str) -> EventAggregateField {
+        match value {
+            "eventTypeCategory" => EventAggregateField::EventTypeCategory,
+            _ => EventAggregateField::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventAggregateField {
+    fn from(value: String) -> EventAggregateField {
+        EventAggregateField::from(value.as_str())
+    }
+}
+
+impl From<EventAggregateField> for String {
+    fn from(value: EventAggregateField) -> String {
+        match value {
+            EventAggregateField::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventAggregateField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EventAggregateField {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventAggregateField {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EventAggregateField, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EventAggregateField::from(value))
+    }
+}
+
 /// <p>Detailed information about an event. A combination of an <a>Event</a> object, an <a>EventDescription</a> object, and additional metadata about the event. Returned by the <a>DescribeEventDetails</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -408,11 +855,11 @@ pub struct EventFilter {
     /// <p>A list of event status codes.</p>
     #[serde(rename = "eventStatusCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_status_codes: Option<Vec<String>>,
+    pub event_status_codes: Option<Vec<EventStatusCode>>,
     /// <p>A list of event type category codes (<code>issue</code>, <code>scheduledChange</code>, or <code>accountNotification</code>).</p>
     #[serde(rename = "eventTypeCategories")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type_categories: Option<Vec<String>>,
+    pub event_type_categories: Option<Vec<EventTypeCategory>>,
     /// <p>A list of unique identifiers for event types. For example, <code>"AWS_EC2_SYSTEM_MAINTENANCE_EVENT","AWS_RDS_MAINTENANCE_SCHEDULED"</code> </p>
     #[serde(rename = "eventTypeCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -439,13 +886,169 @@ pub struct EventFilter {
     pub tags: Option<Vec<::std::collections::HashMap<String, String>>>,
 }
 
+/// <p>The most recent status of the event. Possible values are <code>open</code>, <code>closed</code>, and <code>upcoming</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventStatusCode {
+    /// <p><code>open</code></p>
+    Open,
+    /// <p><code>closed</code></p>
+    Closed,
+    /// <p><code>upcoming</code></p>
+    Upcoming,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventStatusCode::Open => "open",
+            EventStatusCode::Closed => "closed",
+            EventStatusCode::Upcoming => "upcoming",
+            EventStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventStatusCode {
+    fn from(value: &'a str) -> EventStatusCode {
+        match value {
+            "open" => EventStatusCode::Open,
+            "closed" => EventStatusCode::Closed,
+            "upcoming" => EventStatusCode::Upcoming,
+            _ => EventStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventStatusCode {
+    fn from(value: String) -> EventStatusCode {
+        EventStatusCode::from(value.as_str())
+    }
+}
+
+impl From<EventStatusCode> for String {
+    fn from(value: EventStatusCode) -> String {
+        match value {
+            EventStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EventStatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventStatusCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EventStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EventStatusCode::from(value))
+    }
+}
+
+/// <p>The category of the event. Possible values are <code>issue</code>, <code>scheduledChange</code>, and <code>accountNotification</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventTypeCategory {
+    /// <p><code>issue</code></p>
+    Issue,
+    /// <p><code>accountNotification</code></p>
+    AccountNotification,
+    /// <p><code>scheduledChange</code></p>
+    ScheduledChange,
+    /// <p><code>investigation</code></p>
+    Investigation,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventTypeCategory {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventTypeCategory::Issue => "issue",
+            EventTypeCategory::AccountNotification => "accountNotification",
+            EventTypeCategory::ScheduledChange => "scheduledChange",
+            EventTypeCategory::Investigation => "investigation",
+            EventTypeCategory::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventTypeCategory {
+    fn from(value: &'a str) -> EventTypeCategory {
+        match value {
+            "issue" => EventTypeCategory::Issue,
+            "accountNotification" => EventTypeCategory::AccountNotification,
+            "scheduledChange" => EventTypeCategory::ScheduledChange,
+            "investigation" => EventTypeCategory::Investigation,
+            _ => EventTypeCategory::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventTypeCategory {
+    fn from(value: String) -> EventTypeCategory {
+        EventTypeCategory::from(value.as_str())
+    }
+}
+
+impl From<EventTypeCategory> for String {
+    fn from(value: EventTypeCategory) -> String {
+        match value {
+            EventTypeCategory::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventTypeCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EventTypeCategory {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventTypeCategory {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EventTypeCategory, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EventTypeCategory::from(value))
+    }
+}
+
 /// <p>The values to use to filter results from the <a>DescribeEventTypes</a> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct EventTypeFilter {
     /// <p>A list of event type category codes (<code>issue</code>, <code>scheduledChange</code>, or <code>accountNotification</code>).</p>
     #[serde(rename = "eventTypeCategories")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_type_categories: Option<Vec<String>>,
+    pub event_type_categories: Option<Vec<EventTypeCategory>>,
     /// <p>A list of event type codes.</p>
     #[serde(rename = "eventTypeCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -716,6 +1319,51 @@ pub trait AWSHealth {
         input: DescribeEventsRequest,
     ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError>;
 }
+impl<T: ?Sized + AWSHealth> AWSHealth for ::std::sync::Arc<T> {
+    fn describe_affected_entities(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> RusotoFuture<DescribeAffectedEntitiesResponse, DescribeAffectedEntitiesError> {
+        (**self).describe_affected_entities(input)
+    }
+    fn describe_entity_aggregates(
+        &self,
+        input: DescribeEntityAggregatesRequest,
+    ) -> RusotoFuture<DescribeEntityAggregatesResponse, DescribeEntityAggregatesError> {
+        (**self).describe_entity_aggregates(input)
+    }
+    fn describe_event_aggregates(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> RusotoFuture<DescribeEventAggregatesResponse, DescribeEventAggregatesError> {
+        (**self).describe_event_aggregates(input)
+    }
+    fn describe_event_details(
+        &self,
+        input: DescribeEventDetailsRequest,
+    ) -> RusotoFuture<DescribeEventDetailsResponse, DescribeEventDetailsError> {
+        (**self).describe_event_details(input)
+    }
+    fn describe_event_types(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> RusotoFuture<DescribeEventTypesResponse, DescribeEventTypesError> {
+        (**self).describe_event_types(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+}
+/// The error codes AWSHealth retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWSHealth API.
 #[derive(Clone)]
 pub struct AWSHealthClient {
@@ -731,6 +1379,27 @@ impl AWSHealthClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_HEALTH`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> AWSHealthClient {
+        Self::new(region::Region::from_env_for_service("HEALTH"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `health`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> AWSHealthClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("health", "HEALTH"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -749,7 +1418,49 @@ impl AWSHealthClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AWSHealthClient {
-        AWSHealthClient { client, region }
+        AWSHealthClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "health")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "health", "health", "HEALTH")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `AWSHealth` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn AWSHealth + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for AWSHealthClient {
+    fn service_name(&self) -> &'static str {
+        "AWSHealth"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "health"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> AWSHealthClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Identity and Access Management clients.
+//!
+//! Makes a single read-only `ListAccessKeys` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_iam::{Iam, IamClient, ListAccessKeysRequest};
+
+fn main() {
+    let client = IamClient::from_env();
+    match client
+        .list_access_keys(ListAccessKeysRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListAccessKeys succeeded"),
+        Err(error) => {
+            eprintln!("ListAccessKeys failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
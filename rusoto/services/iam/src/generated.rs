@@ -28,6 +28,7 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -39,7 +40,7 @@ pub struct AccessDetail {
     /// <p>The path of the Organizations entity (root, organizational unit, or account) from which an authenticated principal last attempted to access the service. AWS does not report unauthenticated requests.</p> <p>This field is null if no principals (IAM users, IAM roles, or root users) in the reported Organizations entity attempted to access the service within the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#service-last-accessed-reporting-period">reporting period</a>.</p>
     pub entity_path: Option<String>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when an authenticated principal most recently attempted to access the service. AWS does not report unauthenticated requests.</p> <p>This field is null if no principals in the reported Organizations entity attempted to access the service within the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#service-last-accessed-reporting-period">reporting period</a>.</p>
-    pub last_authenticated_time: Option<String>,
+    pub last_authenticated_time: Option<IsoTimestamp>,
     /// <p>The Region where the last service access attempt occurred.</p> <p>This field is null if no principals in the reported Organizations entity attempted to access the service within the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#service-last-accessed-reporting-period">reporting period</a>.</p>
     pub region: Option<String>,
     /// <p>The name of the service in which access was attempted.</p>
@@ -50,10 +51,10 @@ pub struct AccessDetail {
     pub total_authenticated_entities: Option<i64>,
 }
 
-struct AccessDetailDeserializer;
+pub(crate) struct AccessDetailDeserializer;
 impl AccessDetailDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccessDetail, XmlParseError> {
@@ -94,10 +95,10 @@ impl AccessDetailDeserializer {
         })
     }
 }
-struct AccessDetailsDeserializer;
+pub(crate) struct AccessDetailsDeserializer;
 impl AccessDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AccessDetail>, XmlParseError> {
@@ -112,56 +113,71 @@ impl AccessDetailsDeserializer {
     }
 }
 /// <p><p>Contains information about an AWS access key.</p> <p> This data type is used as a response element in the <a>CreateAccessKey</a> and <a>ListAccessKeys</a> operations. </p> <note> <p>The <code>SecretAccessKey</code> value is returned only in response to <a>CreateAccessKey</a>. You can get a secret access key only when you first create an access key; you cannot recover the secret access key later. If you lose a secret access key, you must create a new access key.</p> </note></p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AccessKey {
     /// <p>The ID for this access key.</p>
     pub access_key_id: String,
     /// <p>The date when the access key was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<IsoTimestamp>,
     /// <p>The secret key used to sign requests.</p>
     pub secret_access_key: String,
     /// <p>The status of the access key. <code>Active</code> means that the key is valid for API calls, while <code>Inactive</code> means it is not. </p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the IAM user that the access key is associated with.</p>
     pub user_name: String,
 }
 
-struct AccessKeyDeserializer;
+pub(crate) struct AccessKeyDeserializer;
 impl AccessKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccessKey, XmlParseError> {
-        deserialize_elements::<_, AccessKey, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "AccessKeyId" => {
-                    obj.access_key_id =
-                        AccessKeyIdTypeDeserializer::deserialize("AccessKeyId", stack)?;
-                }
-                "CreateDate" => {
-                    obj.create_date = Some(DateTypeDeserializer::deserialize("CreateDate", stack)?);
-                }
-                "SecretAccessKey" => {
-                    obj.secret_access_key =
-                        AccessKeySecretTypeDeserializer::deserialize("SecretAccessKey", stack)?;
-                }
-                "Status" => {
-                    obj.status = StatusTypeDeserializer::deserialize("Status", stack)?;
-                }
-                "UserName" => {
-                    obj.user_name = UserNameTypeDeserializer::deserialize("UserName", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            AccessKey {
+                access_key_id: Default::default(),
+                create_date: Default::default(),
+                secret_access_key: Default::default(),
+                status: StatusType::Unknown(String::new()),
+                user_name: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "AccessKeyId" => {
+                        obj.access_key_id =
+                            AccessKeyIdTypeDeserializer::deserialize("AccessKeyId", stack)?;
+                    }
+                    "CreateDate" => {
+                        obj.create_date =
+                            Some(DateTypeDeserializer::deserialize("CreateDate", stack)?);
+                    }
+                    "SecretAccessKey" => {
+                        obj.secret_access_key =
+                            AccessKeySecretTypeDeserializer::deserialize("SecretAccessKey", stack)?;
+                    }
+                    "Status" => {
+                        obj.status = StatusTypeDeserializer::deserialize("Status", stack)?;
+                    }
+                    "UserName" => {
+                        obj.user_name = UserNameTypeDeserializer::deserialize("UserName", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct AccessKeyIdTypeDeserializer;
+pub(crate) struct AccessKeyIdTypeDeserializer;
 impl AccessKeyIdTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -173,17 +189,17 @@ impl AccessKeyIdTypeDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AccessKeyLastUsed {
     /// <p><p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the access key was most recently used. This field is null in the following situations:</p> <ul> <li> <p>The user does not have an access key.</p> </li> <li> <p>An access key exists but has not been used since IAM began tracking this information.</p> </li> <li> <p>There is no sign-in data associated with the user.</p> </li> </ul></p>
-    pub last_used_date: String,
+    pub last_used_date: IsoTimestamp,
     /// <p>The AWS Region where this access key was most recently used. The value for this field is "N/A" in the following situations:</p> <ul> <li> <p>The user does not have an access key.</p> </li> <li> <p>An access key exists but has not been used since IAM began tracking this information.</p> </li> <li> <p>There is no sign-in data associated with the user.</p> </li> </ul> <p>For more information about AWS Regions, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html">Regions and Endpoints</a> in the Amazon Web Services General Reference.</p>
     pub region: String,
     /// <p><p>The name of the AWS service with which this access key was most recently used. The value of this field is &quot;N/A&quot; in the following situations:</p> <ul> <li> <p>The user does not have an access key.</p> </li> <li> <p>An access key exists but has not been used since IAM started tracking this information.</p> </li> <li> <p>There is no sign-in data associated with the user.</p> </li> </ul></p>
     pub service_name: String,
 }
 
-struct AccessKeyLastUsedDeserializer;
+pub(crate) struct AccessKeyLastUsedDeserializer;
 impl AccessKeyLastUsedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccessKeyLastUsed, XmlParseError> {
@@ -210,17 +226,17 @@ pub struct AccessKeyMetadata {
     /// <p>The ID for this access key.</p>
     pub access_key_id: Option<String>,
     /// <p>The date when the access key was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<IsoTimestamp>,
     /// <p>The status of the access key. <code>Active</code> means that the key is valid for API calls; <code>Inactive</code> means it is not.</p>
-    pub status: Option<String>,
+    pub status: Option<StatusType>,
     /// <p>The name of the IAM user that the key is associated with.</p>
     pub user_name: Option<String>,
 }
 
-struct AccessKeyMetadataDeserializer;
+pub(crate) struct AccessKeyMetadataDeserializer;
 impl AccessKeyMetadataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccessKeyMetadata, XmlParseError> {
@@ -247,10 +263,10 @@ impl AccessKeyMetadataDeserializer {
         })
     }
 }
-struct AccessKeyMetadataListTypeDeserializer;
+pub(crate) struct AccessKeyMetadataListTypeDeserializer;
 impl AccessKeyMetadataListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AccessKeyMetadata>, XmlParseError> {
@@ -264,10 +280,13 @@ impl AccessKeyMetadataListTypeDeserializer {
         })
     }
 }
-struct AccessKeySecretTypeDeserializer;
+pub(crate) struct AccessKeySecretTypeDeserializer;
 impl AccessKeySecretTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -275,10 +294,10 @@ impl AccessKeySecretTypeDeserializer {
         Ok(obj)
     }
 }
-struct AccountAliasListTypeDeserializer;
+pub(crate) struct AccountAliasListTypeDeserializer;
 impl AccountAliasListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -292,10 +311,13 @@ impl AccountAliasListTypeDeserializer {
         })
     }
 }
-struct AccountAliasTypeDeserializer;
+pub(crate) struct AccountAliasTypeDeserializer;
 impl AccountAliasTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -315,10 +337,13 @@ impl ActionNameListTypeSerializer {
     }
 }
 
-struct ActionNameTypeDeserializer;
+pub(crate) struct ActionNameTypeDeserializer;
 impl ActionNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -334,6 +359,49 @@ pub struct AddClientIDToOpenIDConnectProviderRequest {
     pub open_id_connect_provider_arn: String,
 }
 
+impl AddClientIDToOpenIDConnectProviderRequest {
+    /// Returns a builder for `AddClientIDToOpenIDConnectProviderRequest`, checking its required members when built.
+    pub fn builder() -> AddClientIDToOpenIDConnectProviderRequestBuilder {
+        AddClientIDToOpenIDConnectProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddClientIDToOpenIDConnectProviderRequest`](struct.AddClientIDToOpenIDConnectProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct AddClientIDToOpenIDConnectProviderRequestBuilder {
+    client_id: Option<String>,
+    open_id_connect_provider_arn: Option<String>,
+}
+
+impl AddClientIDToOpenIDConnectProviderRequestBuilder {
+    /// Sets the [`client_id`](struct.AddClientIDToOpenIDConnectProviderRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`open_id_connect_provider_arn`](struct.AddClientIDToOpenIDConnectProviderRequest.html#structfield.open_id_connect_provider_arn) member.
+    pub fn open_id_connect_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.open_id_connect_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddClientIDToOpenIDConnectProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddClientIDToOpenIDConnectProviderRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AddClientIDToOpenIDConnectProviderRequest {
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            open_id_connect_provider_arn: self.open_id_connect_provider_arn.ok_or(
+                ::rusoto_core::BuildError::missing("open_id_connect_provider_arn"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `AddClientIDToOpenIDConnectProviderRequest` contents to a `SignedRequest`.
 struct AddClientIDToOpenIDConnectProviderRequestSerializer;
 impl AddClientIDToOpenIDConnectProviderRequestSerializer {
@@ -359,6 +427,48 @@ pub struct AddRoleToInstanceProfileRequest {
     pub role_name: String,
 }
 
+impl AddRoleToInstanceProfileRequest {
+    /// Returns a builder for `AddRoleToInstanceProfileRequest`, checking its required members when built.
+    pub fn builder() -> AddRoleToInstanceProfileRequestBuilder {
+        AddRoleToInstanceProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddRoleToInstanceProfileRequest`](struct.AddRoleToInstanceProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct AddRoleToInstanceProfileRequestBuilder {
+    instance_profile_name: Option<String>,
+    role_name: Option<String>,
+}
+
+impl AddRoleToInstanceProfileRequestBuilder {
+    /// Sets the [`instance_profile_name`](struct.AddRoleToInstanceProfileRequest.html#structfield.instance_profile_name) member.
+    pub fn instance_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.AddRoleToInstanceProfileRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddRoleToInstanceProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddRoleToInstanceProfileRequest, ::rusoto_core::BuildError> {
+        Ok(AddRoleToInstanceProfileRequest {
+            instance_profile_name: self
+                .instance_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_profile_name"))?,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `AddRoleToInstanceProfileRequest` contents to a `SignedRequest`.
 struct AddRoleToInstanceProfileRequestSerializer;
 impl AddRoleToInstanceProfileRequestSerializer {
@@ -384,6 +494,46 @@ pub struct AddUserToGroupRequest {
     pub user_name: String,
 }
 
+impl AddUserToGroupRequest {
+    /// Returns a builder for `AddUserToGroupRequest`, checking its required members when built.
+    pub fn builder() -> AddUserToGroupRequestBuilder {
+        AddUserToGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddUserToGroupRequest`](struct.AddUserToGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct AddUserToGroupRequestBuilder {
+    group_name: Option<String>,
+    user_name: Option<String>,
+}
+
+impl AddUserToGroupRequestBuilder {
+    /// Sets the [`group_name`](struct.AddUserToGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.AddUserToGroupRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddUserToGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddUserToGroupRequest, ::rusoto_core::BuildError> {
+        Ok(AddUserToGroupRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `AddUserToGroupRequest` contents to a `SignedRequest`.
 struct AddUserToGroupRequestSerializer;
 impl AddUserToGroupRequestSerializer {
@@ -398,10 +548,10 @@ impl AddUserToGroupRequestSerializer {
     }
 }
 
-struct ArnListTypeDeserializer;
+pub(crate) struct ArnListTypeDeserializer;
 impl ArnListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -415,10 +565,13 @@ impl ArnListTypeDeserializer {
         })
     }
 }
-struct ArnTypeDeserializer;
+pub(crate) struct ArnTypeDeserializer;
 impl ArnTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -426,6 +579,69 @@ impl ArnTypeDeserializer {
         Ok(obj)
     }
 }
+/// <p> The status (<code>Unassigned</code> or <code>Assigned</code>) of the devices to list. If you do not specify an <code>AssignmentStatus</code>, the operation defaults to <code>Any</code>, which lists both assigned and unassigned virtual MFA devices.,</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AssignmentStatusType {
+    /// <p><code>Assigned</code></p>
+    Assigned,
+    /// <p><code>Unassigned</code></p>
+    Unassigned,
+    /// <p><code>Any</code></p>
+    Any,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AssignmentStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssignmentStatusType::Assigned => "Assigned",
+            AssignmentStatusType::Unassigned => "Unassigned",
+            AssignmentStatusType::Any => "Any",
+            AssignmentStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AssignmentStatusType {
+    fn from(value: &'a str) -> AssignmentStatusType {
+        match value {
+            "Assigned" => AssignmentStatusType::Assigned,
+            "Unassigned" => AssignmentStatusType::Unassigned,
+            "Any" => AssignmentStatusType::Any,
+            _ => AssignmentStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssignmentStatusType {
+    fn from(value: String) -> AssignmentStatusType {
+        AssignmentStatusType::from(value.as_str())
+    }
+}
+
+impl From<AssignmentStatusType> for String {
+    fn from(value: AssignmentStatusType) -> String {
+        match value {
+            AssignmentStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AssignmentStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AssignmentStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AttachGroupPolicyRequest {
     /// <p>The name (friendly name, not ARN) of the group to attach the policy to.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
@@ -434,6 +650,48 @@ pub struct AttachGroupPolicyRequest {
     pub policy_arn: String,
 }
 
+impl AttachGroupPolicyRequest {
+    /// Returns a builder for `AttachGroupPolicyRequest`, checking its required members when built.
+    pub fn builder() -> AttachGroupPolicyRequestBuilder {
+        AttachGroupPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachGroupPolicyRequest`](struct.AttachGroupPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachGroupPolicyRequestBuilder {
+    group_name: Option<String>,
+    policy_arn: Option<String>,
+}
+
+impl AttachGroupPolicyRequestBuilder {
+    /// Sets the [`group_name`](struct.AttachGroupPolicyRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_arn`](struct.AttachGroupPolicyRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachGroupPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachGroupPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(AttachGroupPolicyRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+        })
+    }
+}
+
 /// Serialize `AttachGroupPolicyRequest` contents to a `SignedRequest`.
 struct AttachGroupPolicyRequestSerializer;
 impl AttachGroupPolicyRequestSerializer {
@@ -456,6 +714,48 @@ pub struct AttachRolePolicyRequest {
     pub role_name: String,
 }
 
+impl AttachRolePolicyRequest {
+    /// Returns a builder for `AttachRolePolicyRequest`, checking its required members when built.
+    pub fn builder() -> AttachRolePolicyRequestBuilder {
+        AttachRolePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachRolePolicyRequest`](struct.AttachRolePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachRolePolicyRequestBuilder {
+    policy_arn: Option<String>,
+    role_name: Option<String>,
+}
+
+impl AttachRolePolicyRequestBuilder {
+    /// Sets the [`policy_arn`](struct.AttachRolePolicyRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.AttachRolePolicyRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachRolePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachRolePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(AttachRolePolicyRequest {
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `AttachRolePolicyRequest` contents to a `SignedRequest`.
 struct AttachRolePolicyRequestSerializer;
 impl AttachRolePolicyRequestSerializer {
@@ -478,6 +778,48 @@ pub struct AttachUserPolicyRequest {
     pub user_name: String,
 }
 
+impl AttachUserPolicyRequest {
+    /// Returns a builder for `AttachUserPolicyRequest`, checking its required members when built.
+    pub fn builder() -> AttachUserPolicyRequestBuilder {
+        AttachUserPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachUserPolicyRequest`](struct.AttachUserPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachUserPolicyRequestBuilder {
+    policy_arn: Option<String>,
+    user_name: Option<String>,
+}
+
+impl AttachUserPolicyRequestBuilder {
+    /// Sets the [`policy_arn`](struct.AttachUserPolicyRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.AttachUserPolicyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachUserPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachUserPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(AttachUserPolicyRequest {
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `AttachUserPolicyRequest` contents to a `SignedRequest`.
 struct AttachUserPolicyRequestSerializer;
 impl AttachUserPolicyRequestSerializer {
@@ -498,13 +840,13 @@ pub struct AttachedPermissionsBoundary {
     /// <p> The ARN of the policy used to set the permissions boundary for the user or role.</p>
     pub permissions_boundary_arn: Option<String>,
     /// <p> The permissions boundary usage type that indicates what type of IAM resource is used as the permissions boundary for an entity. This data type can only have a value of <code>Policy</code>.</p>
-    pub permissions_boundary_type: Option<String>,
+    pub permissions_boundary_type: Option<PermissionsBoundaryAttachmentType>,
 }
 
-struct AttachedPermissionsBoundaryDeserializer;
+pub(crate) struct AttachedPermissionsBoundaryDeserializer;
 impl AttachedPermissionsBoundaryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AttachedPermissionsBoundary, XmlParseError> {
@@ -533,10 +875,10 @@ impl AttachedPermissionsBoundaryDeserializer {
         )
     }
 }
-struct AttachedPoliciesListTypeDeserializer;
+pub(crate) struct AttachedPoliciesListTypeDeserializer;
 impl AttachedPoliciesListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AttachedPolicy>, XmlParseError> {
@@ -558,10 +900,10 @@ pub struct AttachedPolicy {
     pub policy_name: Option<String>,
 }
 
-struct AttachedPolicyDeserializer;
+pub(crate) struct AttachedPolicyDeserializer;
 impl AttachedPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AttachedPolicy, XmlParseError> {
@@ -582,10 +924,13 @@ impl AttachedPolicyDeserializer {
         })
     }
 }
-struct AttachmentCountTypeDeserializer;
+pub(crate) struct AttachmentCountTypeDeserializer;
 impl AttachmentCountTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -593,10 +938,13 @@ impl AttachmentCountTypeDeserializer {
         Ok(obj)
     }
 }
-struct BooleanObjectTypeDeserializer;
+pub(crate) struct BooleanObjectTypeDeserializer;
 impl BooleanObjectTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -604,10 +952,13 @@ impl BooleanObjectTypeDeserializer {
         Ok(obj)
     }
 }
-struct BooleanTypeDeserializer;
+pub(crate) struct BooleanTypeDeserializer;
 impl BooleanTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -615,10 +966,10 @@ impl BooleanTypeDeserializer {
         Ok(obj)
     }
 }
-struct BootstrapDatumDeserializer;
+pub(crate) struct BootstrapDatumDeserializer;
 impl BootstrapDatumDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<bytes::Bytes, XmlParseError> {
@@ -629,10 +980,13 @@ impl BootstrapDatumDeserializer {
         Ok(obj)
     }
 }
-struct CertificateBodyTypeDeserializer;
+pub(crate) struct CertificateBodyTypeDeserializer;
 impl CertificateBodyTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -640,10 +994,13 @@ impl CertificateBodyTypeDeserializer {
         Ok(obj)
     }
 }
-struct CertificateChainTypeDeserializer;
+pub(crate) struct CertificateChainTypeDeserializer;
 impl CertificateChainTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -651,10 +1008,13 @@ impl CertificateChainTypeDeserializer {
         Ok(obj)
     }
 }
-struct CertificateIdTypeDeserializer;
+pub(crate) struct CertificateIdTypeDeserializer;
 impl CertificateIdTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -662,10 +1022,10 @@ impl CertificateIdTypeDeserializer {
         Ok(obj)
     }
 }
-struct CertificateListTypeDeserializer;
+pub(crate) struct CertificateListTypeDeserializer;
 impl CertificateListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SigningCertificate>, XmlParseError> {
@@ -689,6 +1049,46 @@ pub struct ChangePasswordRequest {
     pub old_password: String,
 }
 
+impl ChangePasswordRequest {
+    /// Returns a builder for `ChangePasswordRequest`, checking its required members when built.
+    pub fn builder() -> ChangePasswordRequestBuilder {
+        ChangePasswordRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ChangePasswordRequest`](struct.ChangePasswordRequest.html).
+#[derive(Default, Debug)]
+pub struct ChangePasswordRequestBuilder {
+    new_password: Option<String>,
+    old_password: Option<String>,
+}
+
+impl ChangePasswordRequestBuilder {
+    /// Sets the [`new_password`](struct.ChangePasswordRequest.html#structfield.new_password) member.
+    pub fn new_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`old_password`](struct.ChangePasswordRequest.html#structfield.old_password) member.
+    pub fn old_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.old_password = Some(value.into());
+        self
+    }
+
+    /// Builds the `ChangePasswordRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ChangePasswordRequest, ::rusoto_core::BuildError> {
+        Ok(ChangePasswordRequest {
+            new_password: self
+                .new_password
+                .ok_or(::rusoto_core::BuildError::missing("new_password"))?,
+            old_password: self
+                .old_password
+                .ok_or(::rusoto_core::BuildError::missing("old_password"))?,
+        })
+    }
+}
+
 /// Serialize `ChangePasswordRequest` contents to a `SignedRequest`.
 struct ChangePasswordRequestSerializer;
 impl ChangePasswordRequestSerializer {
@@ -703,10 +1103,10 @@ impl ChangePasswordRequestSerializer {
     }
 }
 
-struct ClientIDListTypeDeserializer;
+pub(crate) struct ClientIDListTypeDeserializer;
 impl ClientIDListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -732,10 +1132,13 @@ impl ClientIDListTypeSerializer {
     }
 }
 
-struct ClientIDTypeDeserializer;
+pub(crate) struct ClientIDTypeDeserializer;
 impl ClientIDTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -743,10 +1146,13 @@ impl ClientIDTypeDeserializer {
         Ok(obj)
     }
 }
-struct ColumnNumberDeserializer;
+pub(crate) struct ColumnNumberDeserializer;
 impl ColumnNumberDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -760,7 +1166,7 @@ pub struct ContextEntry {
     /// <p>The full name of a condition context key, including the service prefix. For example, <code>aws:SourceIp</code> or <code>s3:VersionId</code>.</p>
     pub context_key_name: Option<String>,
     /// <p>The data type of the value (or values) specified in the <code>ContextKeyValues</code> parameter.</p>
-    pub context_key_type: Option<String>,
+    pub context_key_type: Option<ContextKeyTypeEnum>,
     /// <p>The value (or values, if the condition context key supports multiple values) to provide to the simulation when the key is referenced by a <code>Condition</code> element in an input policy.</p>
     pub context_key_values: Option<Vec<String>>,
 }
@@ -801,10 +1207,13 @@ impl ContextEntryListTypeSerializer {
     }
 }
 
-struct ContextKeyNameTypeDeserializer;
+pub(crate) struct ContextKeyNameTypeDeserializer;
 impl ContextKeyNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -812,10 +1221,10 @@ impl ContextKeyNameTypeDeserializer {
         Ok(obj)
     }
 }
-struct ContextKeyNamesResultListTypeDeserializer;
+pub(crate) struct ContextKeyNamesResultListTypeDeserializer;
 impl ContextKeyNamesResultListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -832,6 +1241,105 @@ impl ContextKeyNamesResultListTypeDeserializer {
     }
 }
 
+/// <p>The data type of the value (or values) specified in the <code>ContextKeyValues</code> parameter.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContextKeyTypeEnum {
+    /// <p><code>string</code></p>
+    String,
+    /// <p><code>stringList</code></p>
+    StringList,
+    /// <p><code>numeric</code></p>
+    Numeric,
+    /// <p><code>numericList</code></p>
+    NumericList,
+    /// <p><code>boolean</code></p>
+    Boolean,
+    /// <p><code>booleanList</code></p>
+    BooleanList,
+    /// <p><code>ip</code></p>
+    Ip,
+    /// <p><code>ipList</code></p>
+    IpList,
+    /// <p><code>binary</code></p>
+    Binary,
+    /// <p><code>binaryList</code></p>
+    BinaryList,
+    /// <p><code>date</code></p>
+    Date,
+    /// <p><code>dateList</code></p>
+    DateList,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContextKeyTypeEnum {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContextKeyTypeEnum::String => "string",
+            ContextKeyTypeEnum::StringList => "stringList",
+            ContextKeyTypeEnum::Numeric => "numeric",
+            ContextKeyTypeEnum::NumericList => "numericList",
+            ContextKeyTypeEnum::Boolean => "boolean",
+            ContextKeyTypeEnum::BooleanList => "booleanList",
+            ContextKeyTypeEnum::Ip => "ip",
+            ContextKeyTypeEnum::IpList => "ipList",
+            ContextKeyTypeEnum::Binary => "binary",
+            ContextKeyTypeEnum::BinaryList => "binaryList",
+            ContextKeyTypeEnum::Date => "date",
+            ContextKeyTypeEnum::DateList => "dateList",
+            ContextKeyTypeEnum::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContextKeyTypeEnum {
+    fn from(value: &'a str) -> ContextKeyTypeEnum {
+        match value {
+            "string" => ContextKeyTypeEnum::String,
+            "stringList" => ContextKeyTypeEnum::StringList,
+            "numeric" => ContextKeyTypeEnum::Numeric,
+            "numericList" => ContextKeyTypeEnum::NumericList,
+            "boolean" => ContextKeyTypeEnum::Boolean,
+            "booleanList" => ContextKeyTypeEnum::BooleanList,
+            "ip" => ContextKeyTypeEnum::Ip,
+            "ipList" => ContextKeyTypeEnum::IpList,
+            "binary" => ContextKeyTypeEnum::Binary,
+            "binaryList" => ContextKeyTypeEnum::BinaryList,
+            "date" => ContextKeyTypeEnum::Date,
+            "dateList" => ContextKeyTypeEnum::DateList,
+            _ => ContextKeyTypeEnum::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContextKeyTypeEnum {
+    fn from(value: String) -> ContextKeyTypeEnum {
+        ContextKeyTypeEnum::from(value.as_str())
+    }
+}
+
+impl From<ContextKeyTypeEnum> for String {
+    fn from(value: ContextKeyTypeEnum) -> String {
+        match value {
+            ContextKeyTypeEnum::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContextKeyTypeEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContextKeyTypeEnum {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// Serialize `ContextKeyValueListType` contents to a `SignedRequest`.
 struct ContextKeyValueListTypeSerializer;
 impl ContextKeyValueListTypeSerializer {
@@ -849,6 +1357,34 @@ pub struct CreateAccessKeyRequest {
     pub user_name: Option<String>,
 }
 
+impl CreateAccessKeyRequest {
+    /// Returns a builder for `CreateAccessKeyRequest`, checking its required members when built.
+    pub fn builder() -> CreateAccessKeyRequestBuilder {
+        CreateAccessKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAccessKeyRequest`](struct.CreateAccessKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAccessKeyRequestBuilder {
+    user_name: Option<String>,
+}
+
+impl CreateAccessKeyRequestBuilder {
+    /// Sets the [`user_name`](struct.CreateAccessKeyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAccessKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateAccessKeyRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAccessKeyRequest {
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `CreateAccessKeyRequest` contents to a `SignedRequest`.
 struct CreateAccessKeyRequestSerializer;
 impl CreateAccessKeyRequestSerializer {
@@ -865,22 +1401,31 @@ impl CreateAccessKeyRequestSerializer {
 }
 
 /// <p>Contains the response to a successful <a>CreateAccessKey</a> request. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreateAccessKeyResponse {
     /// <p>A structure with details about the access key.</p>
     pub access_key: AccessKey,
 }
 
-struct CreateAccessKeyResponseDeserializer;
+pub(crate) struct CreateAccessKeyResponseDeserializer;
 impl CreateAccessKeyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateAccessKeyResponse, XmlParseError> {
-        deserialize_elements::<_, CreateAccessKeyResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            CreateAccessKeyResponse {
+                access_key: AccessKey {
+                    access_key_id: Default::default(),
+                    create_date: Default::default(),
+                    secret_access_key: Default::default(),
+                    status: StatusType::Unknown(String::new()),
+                    user_name: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "AccessKey" => {
@@ -899,6 +1444,38 @@ pub struct CreateAccountAliasRequest {
     pub account_alias: String,
 }
 
+impl CreateAccountAliasRequest {
+    /// Returns a builder for `CreateAccountAliasRequest`, checking its required members when built.
+    pub fn builder() -> CreateAccountAliasRequestBuilder {
+        CreateAccountAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAccountAliasRequest`](struct.CreateAccountAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAccountAliasRequestBuilder {
+    account_alias: Option<String>,
+}
+
+impl CreateAccountAliasRequestBuilder {
+    /// Sets the [`account_alias`](struct.CreateAccountAliasRequest.html#structfield.account_alias) member.
+    pub fn account_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_alias = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAccountAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateAccountAliasRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAccountAliasRequest {
+            account_alias: self
+                .account_alias
+                .ok_or(::rusoto_core::BuildError::missing("account_alias"))?,
+        })
+    }
+}
+
 /// Serialize `CreateAccountAliasRequest` contents to a `SignedRequest`.
 struct CreateAccountAliasRequestSerializer;
 impl CreateAccountAliasRequestSerializer {
@@ -920,6 +1497,44 @@ pub struct CreateGroupRequest {
     pub path: Option<String>,
 }
 
+impl CreateGroupRequest {
+    /// Returns a builder for `CreateGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateGroupRequestBuilder {
+        CreateGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGroupRequest`](struct.CreateGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateGroupRequestBuilder {
+    group_name: Option<String>,
+    path: Option<String>,
+}
+
+impl CreateGroupRequestBuilder {
+    /// Sets the [`group_name`](struct.CreateGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path`](struct.CreateGroupRequest.html#structfield.path) member.
+    pub fn path<T: Into<String>>(mut self, value: T) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateGroupRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            path: self.path,
+        })
+    }
+}
+
 /// Serialize `CreateGroupRequest` contents to a `SignedRequest`.
 struct CreateGroupRequestSerializer;
 impl CreateGroupRequestSerializer {
@@ -943,10 +1558,10 @@ pub struct CreateGroupResponse {
     pub group: Group,
 }
 
-struct CreateGroupResponseDeserializer;
+pub(crate) struct CreateGroupResponseDeserializer;
 impl CreateGroupResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateGroupResponse, XmlParseError> {
@@ -969,6 +1584,46 @@ pub struct CreateInstanceProfileRequest {
     pub path: Option<String>,
 }
 
+impl CreateInstanceProfileRequest {
+    /// Returns a builder for `CreateInstanceProfileRequest`, checking its required members when built.
+    pub fn builder() -> CreateInstanceProfileRequestBuilder {
+        CreateInstanceProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateInstanceProfileRequest`](struct.CreateInstanceProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateInstanceProfileRequestBuilder {
+    instance_profile_name: Option<String>,
+    path: Option<String>,
+}
+
+impl CreateInstanceProfileRequestBuilder {
+    /// Sets the [`instance_profile_name`](struct.CreateInstanceProfileRequest.html#structfield.instance_profile_name) member.
+    pub fn instance_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path`](struct.CreateInstanceProfileRequest.html#structfield.path) member.
+    pub fn path<T: Into<String>>(mut self, value: T) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateInstanceProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateInstanceProfileRequest, ::rusoto_core::BuildError> {
+        Ok(CreateInstanceProfileRequest {
+            instance_profile_name: self
+                .instance_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_profile_name"))?,
+            path: self.path,
+        })
+    }
+}
+
 /// Serialize `CreateInstanceProfileRequest` contents to a `SignedRequest`.
 struct CreateInstanceProfileRequestSerializer;
 impl CreateInstanceProfileRequestSerializer {
@@ -995,10 +1650,10 @@ pub struct CreateInstanceProfileResponse {
     pub instance_profile: InstanceProfile,
 }
 
-struct CreateInstanceProfileResponseDeserializer;
+pub(crate) struct CreateInstanceProfileResponseDeserializer;
 impl CreateInstanceProfileResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateInstanceProfileResponse, XmlParseError> {
@@ -1028,7 +1683,57 @@ pub struct CreateLoginProfileRequest {
     pub user_name: String,
 }
 
-/// Serialize `CreateLoginProfileRequest` contents to a `SignedRequest`.
+impl CreateLoginProfileRequest {
+    /// Returns a builder for `CreateLoginProfileRequest`, checking its required members when built.
+    pub fn builder() -> CreateLoginProfileRequestBuilder {
+        CreateLoginProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLoginProfileRequest`](struct.CreateLoginProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLoginProfileRequestBuilder {
+    password: Option<String>,
+    password_reset_required: Option<bool>,
+    user_name: Option<String>,
+}
+
+impl CreateLoginProfileRequestBuilder {
+    /// Sets the [`password`](struct.CreateLoginProfileRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password_reset_required`](struct.CreateLoginProfileRequest.html#structfield.password_reset_required) member.
+    pub fn password_reset_required<T: Into<bool>>(mut self, value: T) -> Self {
+        self.password_reset_required = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.CreateLoginProfileRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLoginProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLoginProfileRequest, ::rusoto_core::BuildError> {
+        Ok(CreateLoginProfileRequest {
+            password: self
+                .password
+                .ok_or(::rusoto_core::BuildError::missing("password"))?,
+            password_reset_required: self.password_reset_required,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
+/// Serialize `CreateLoginProfileRequest` contents to a `SignedRequest`.
 struct CreateLoginProfileRequestSerializer;
 impl CreateLoginProfileRequestSerializer {
     fn serialize(params: &mut Params, name: &str, obj: &CreateLoginProfileRequest) {
@@ -1055,10 +1760,10 @@ pub struct CreateLoginProfileResponse {
     pub login_profile: LoginProfile,
 }
 
-struct CreateLoginProfileResponseDeserializer;
+pub(crate) struct CreateLoginProfileResponseDeserializer;
 impl CreateLoginProfileResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateLoginProfileResponse, XmlParseError> {
@@ -1088,6 +1793,54 @@ pub struct CreateOpenIDConnectProviderRequest {
     pub url: String,
 }
 
+impl CreateOpenIDConnectProviderRequest {
+    /// Returns a builder for `CreateOpenIDConnectProviderRequest`, checking its required members when built.
+    pub fn builder() -> CreateOpenIDConnectProviderRequestBuilder {
+        CreateOpenIDConnectProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateOpenIDConnectProviderRequest`](struct.CreateOpenIDConnectProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateOpenIDConnectProviderRequestBuilder {
+    client_id_list: Option<Vec<String>>,
+    thumbprint_list: Option<Vec<String>>,
+    url: Option<String>,
+}
+
+impl CreateOpenIDConnectProviderRequestBuilder {
+    /// Sets the [`client_id_list`](struct.CreateOpenIDConnectProviderRequest.html#structfield.client_id_list) member.
+    pub fn client_id_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.client_id_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thumbprint_list`](struct.CreateOpenIDConnectProviderRequest.html#structfield.thumbprint_list) member.
+    pub fn thumbprint_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.thumbprint_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`url`](struct.CreateOpenIDConnectProviderRequest.html#structfield.url) member.
+    pub fn url<T: Into<String>>(mut self, value: T) -> Self {
+        self.url = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateOpenIDConnectProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateOpenIDConnectProviderRequest, ::rusoto_core::BuildError> {
+        Ok(CreateOpenIDConnectProviderRequest {
+            client_id_list: self.client_id_list,
+            thumbprint_list: self
+                .thumbprint_list
+                .ok_or(::rusoto_core::BuildError::missing("thumbprint_list"))?,
+            url: self.url.ok_or(::rusoto_core::BuildError::missing("url"))?,
+        })
+    }
+}
+
 /// Serialize `CreateOpenIDConnectProviderRequest` contents to a `SignedRequest`.
 struct CreateOpenIDConnectProviderRequestSerializer;
 impl CreateOpenIDConnectProviderRequestSerializer {
@@ -1120,10 +1873,10 @@ pub struct CreateOpenIDConnectProviderResponse {
     pub open_id_connect_provider_arn: Option<String>,
 }
 
-struct CreateOpenIDConnectProviderResponseDeserializer;
+pub(crate) struct CreateOpenIDConnectProviderResponseDeserializer;
 impl CreateOpenIDConnectProviderResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateOpenIDConnectProviderResponse, XmlParseError> {
@@ -1157,6 +1910,62 @@ pub struct CreatePolicyRequest {
     pub policy_name: String,
 }
 
+impl CreatePolicyRequest {
+    /// Returns a builder for `CreatePolicyRequest`, checking its required members when built.
+    pub fn builder() -> CreatePolicyRequestBuilder {
+        CreatePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePolicyRequest`](struct.CreatePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePolicyRequestBuilder {
+    description: Option<String>,
+    path: Option<String>,
+    policy_document: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl CreatePolicyRequestBuilder {
+    /// Sets the [`description`](struct.CreatePolicyRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path`](struct.CreatePolicyRequest.html#structfield.path) member.
+    pub fn path<T: Into<String>>(mut self, value: T) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_document`](struct.CreatePolicyRequest.html#structfield.policy_document) member.
+    pub fn policy_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.CreatePolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePolicyRequest {
+            description: self.description,
+            path: self.path,
+            policy_document: self
+                .policy_document
+                .ok_or(::rusoto_core::BuildError::missing("policy_document"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreatePolicyRequest` contents to a `SignedRequest`.
 struct CreatePolicyRequestSerializer;
 impl CreatePolicyRequestSerializer {
@@ -1187,10 +1996,10 @@ pub struct CreatePolicyResponse {
     pub policy: Option<Policy>,
 }
 
-struct CreatePolicyResponseDeserializer;
+pub(crate) struct CreatePolicyResponseDeserializer;
 impl CreatePolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreatePolicyResponse, XmlParseError> {
@@ -1215,6 +2024,56 @@ pub struct CreatePolicyVersionRequest {
     pub set_as_default: Option<bool>,
 }
 
+impl CreatePolicyVersionRequest {
+    /// Returns a builder for `CreatePolicyVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreatePolicyVersionRequestBuilder {
+        CreatePolicyVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePolicyVersionRequest`](struct.CreatePolicyVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePolicyVersionRequestBuilder {
+    policy_arn: Option<String>,
+    policy_document: Option<String>,
+    set_as_default: Option<bool>,
+}
+
+impl CreatePolicyVersionRequestBuilder {
+    /// Sets the [`policy_arn`](struct.CreatePolicyVersionRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_document`](struct.CreatePolicyVersionRequest.html#structfield.policy_document) member.
+    pub fn policy_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`set_as_default`](struct.CreatePolicyVersionRequest.html#structfield.set_as_default) member.
+    pub fn set_as_default<T: Into<bool>>(mut self, value: T) -> Self {
+        self.set_as_default = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePolicyVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePolicyVersionRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePolicyVersionRequest {
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+            policy_document: self
+                .policy_document
+                .ok_or(::rusoto_core::BuildError::missing("policy_document"))?,
+            set_as_default: self.set_as_default,
+        })
+    }
+}
+
 /// Serialize `CreatePolicyVersionRequest` contents to a `SignedRequest`.
 struct CreatePolicyVersionRequestSerializer;
 impl CreatePolicyVersionRequestSerializer {
@@ -1242,10 +2101,10 @@ pub struct CreatePolicyVersionResponse {
     pub policy_version: Option<PolicyVersion>,
 }
 
-struct CreatePolicyVersionResponseDeserializer;
+pub(crate) struct CreatePolicyVersionResponseDeserializer;
 impl CreatePolicyVersionResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreatePolicyVersionResponse, XmlParseError> {
@@ -1285,6 +2144,86 @@ pub struct CreateRoleRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateRoleRequest {
+    /// Returns a builder for `CreateRoleRequest`, checking its required members when built.
+    pub fn builder() -> CreateRoleRequestBuilder {
+        CreateRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRoleRequest`](struct.CreateRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRoleRequestBuilder {
+    assume_role_policy_document: Option<String>,
+    description: Option<String>,
+    max_session_duration: Option<i64>,
+    path: Option<String>,
+    permissions_boundary: Option<String>,
+    role_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateRoleRequestBuilder {
+    /// Sets the [`assume_role_policy_document`](struct.CreateRoleRequest.html#structfield.assume_role_policy_document) member.
+    pub fn assume_role_policy_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.assume_role_policy_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateRoleRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_session_duration`](struct.CreateRoleRequest.html#structfield.max_session_duration) member.
+    pub fn max_session_duration<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_session_duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path`](struct.CreateRoleRequest.html#structfield.path) member.
+    pub fn path<T: Into<String>>(mut self, value: T) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permissions_boundary`](struct.CreateRoleRequest.html#structfield.permissions_boundary) member.
+    pub fn permissions_boundary<T: Into<String>>(mut self, value: T) -> Self {
+        self.permissions_boundary = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.CreateRoleRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateRoleRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateRoleRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRoleRequest {
+            assume_role_policy_document: self.assume_role_policy_document.ok_or(
+                ::rusoto_core::BuildError::missing("assume_role_policy_document"),
+            )?,
+            description: self.description,
+            max_session_duration: self.max_session_duration,
+            path: self.path,
+            permissions_boundary: self.permissions_boundary,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateRoleRequest` contents to a `SignedRequest`.
 struct CreateRoleRequestSerializer;
 impl CreateRoleRequestSerializer {
@@ -1327,10 +2266,10 @@ pub struct CreateRoleResponse {
     pub role: Role,
 }
 
-struct CreateRoleResponseDeserializer;
+pub(crate) struct CreateRoleResponseDeserializer;
 impl CreateRoleResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateRoleResponse, XmlParseError> {
@@ -1353,6 +2292,48 @@ pub struct CreateSAMLProviderRequest {
     pub saml_metadata_document: String,
 }
 
+impl CreateSAMLProviderRequest {
+    /// Returns a builder for `CreateSAMLProviderRequest`, checking its required members when built.
+    pub fn builder() -> CreateSAMLProviderRequestBuilder {
+        CreateSAMLProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSAMLProviderRequest`](struct.CreateSAMLProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSAMLProviderRequestBuilder {
+    name: Option<String>,
+    saml_metadata_document: Option<String>,
+}
+
+impl CreateSAMLProviderRequestBuilder {
+    /// Sets the [`name`](struct.CreateSAMLProviderRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`saml_metadata_document`](struct.CreateSAMLProviderRequest.html#structfield.saml_metadata_document) member.
+    pub fn saml_metadata_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.saml_metadata_document = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSAMLProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSAMLProviderRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSAMLProviderRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            saml_metadata_document: self
+                .saml_metadata_document
+                .ok_or(::rusoto_core::BuildError::missing("saml_metadata_document"))?,
+        })
+    }
+}
+
 /// Serialize `CreateSAMLProviderRequest` contents to a `SignedRequest`.
 struct CreateSAMLProviderRequestSerializer;
 impl CreateSAMLProviderRequestSerializer {
@@ -1377,10 +2358,10 @@ pub struct CreateSAMLProviderResponse {
     pub saml_provider_arn: Option<String>,
 }
 
-struct CreateSAMLProviderResponseDeserializer;
+pub(crate) struct CreateSAMLProviderResponseDeserializer;
 impl CreateSAMLProviderResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateSAMLProviderResponse, XmlParseError> {
@@ -1410,6 +2391,54 @@ pub struct CreateServiceLinkedRoleRequest {
     pub description: Option<String>,
 }
 
+impl CreateServiceLinkedRoleRequest {
+    /// Returns a builder for `CreateServiceLinkedRoleRequest`, checking its required members when built.
+    pub fn builder() -> CreateServiceLinkedRoleRequestBuilder {
+        CreateServiceLinkedRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateServiceLinkedRoleRequest`](struct.CreateServiceLinkedRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateServiceLinkedRoleRequestBuilder {
+    aws_service_name: Option<String>,
+    custom_suffix: Option<String>,
+    description: Option<String>,
+}
+
+impl CreateServiceLinkedRoleRequestBuilder {
+    /// Sets the [`aws_service_name`](struct.CreateServiceLinkedRoleRequest.html#structfield.aws_service_name) member.
+    pub fn aws_service_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.aws_service_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_suffix`](struct.CreateServiceLinkedRoleRequest.html#structfield.custom_suffix) member.
+    pub fn custom_suffix<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_suffix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateServiceLinkedRoleRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateServiceLinkedRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateServiceLinkedRoleRequest, ::rusoto_core::BuildError> {
+        Ok(CreateServiceLinkedRoleRequest {
+            aws_service_name: self
+                .aws_service_name
+                .ok_or(::rusoto_core::BuildError::missing("aws_service_name"))?,
+            custom_suffix: self.custom_suffix,
+            description: self.description,
+        })
+    }
+}
+
 /// Serialize `CreateServiceLinkedRoleRequest` contents to a `SignedRequest`.
 struct CreateServiceLinkedRoleRequestSerializer;
 impl CreateServiceLinkedRoleRequestSerializer {
@@ -1438,10 +2467,10 @@ pub struct CreateServiceLinkedRoleResponse {
     pub role: Option<Role>,
 }
 
-struct CreateServiceLinkedRoleResponseDeserializer;
+pub(crate) struct CreateServiceLinkedRoleResponseDeserializer;
 impl CreateServiceLinkedRoleResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateServiceLinkedRoleResponse, XmlParseError> {
@@ -1468,6 +2497,49 @@ pub struct CreateServiceSpecificCredentialRequest {
     pub user_name: String,
 }
 
+impl CreateServiceSpecificCredentialRequest {
+    /// Returns a builder for `CreateServiceSpecificCredentialRequest`, checking its required members when built.
+    pub fn builder() -> CreateServiceSpecificCredentialRequestBuilder {
+        CreateServiceSpecificCredentialRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateServiceSpecificCredentialRequest`](struct.CreateServiceSpecificCredentialRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateServiceSpecificCredentialRequestBuilder {
+    service_name: Option<String>,
+    user_name: Option<String>,
+}
+
+impl CreateServiceSpecificCredentialRequestBuilder {
+    /// Sets the [`service_name`](struct.CreateServiceSpecificCredentialRequest.html#structfield.service_name) member.
+    pub fn service_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.CreateServiceSpecificCredentialRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateServiceSpecificCredentialRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateServiceSpecificCredentialRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateServiceSpecificCredentialRequest {
+            service_name: self
+                .service_name
+                .ok_or(::rusoto_core::BuildError::missing("service_name"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateServiceSpecificCredentialRequest` contents to a `SignedRequest`.
 struct CreateServiceSpecificCredentialRequestSerializer;
 impl CreateServiceSpecificCredentialRequestSerializer {
@@ -1488,10 +2560,10 @@ pub struct CreateServiceSpecificCredentialResponse {
     pub service_specific_credential: Option<ServiceSpecificCredential>,
 }
 
-struct CreateServiceSpecificCredentialResponseDeserializer;
+pub(crate) struct CreateServiceSpecificCredentialResponseDeserializer;
 impl CreateServiceSpecificCredentialResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateServiceSpecificCredentialResponse, XmlParseError> {
@@ -1526,6 +2598,60 @@ pub struct CreateUserRequest {
     pub user_name: String,
 }
 
+impl CreateUserRequest {
+    /// Returns a builder for `CreateUserRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserRequestBuilder {
+        CreateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserRequest`](struct.CreateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserRequestBuilder {
+    path: Option<String>,
+    permissions_boundary: Option<String>,
+    tags: Option<Vec<Tag>>,
+    user_name: Option<String>,
+}
+
+impl CreateUserRequestBuilder {
+    /// Sets the [`path`](struct.CreateUserRequest.html#structfield.path) member.
+    pub fn path<T: Into<String>>(mut self, value: T) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permissions_boundary`](struct.CreateUserRequest.html#structfield.permissions_boundary) member.
+    pub fn permissions_boundary<T: Into<String>>(mut self, value: T) -> Self {
+        self.permissions_boundary = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateUserRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.CreateUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateUserRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserRequest {
+            path: self.path,
+            permissions_boundary: self.permissions_boundary,
+            tags: self.tags,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `CreateUserRequest` contents to a `SignedRequest`.
 struct CreateUserRequestSerializer;
 impl CreateUserRequestSerializer {
@@ -1558,10 +2684,10 @@ pub struct CreateUserResponse {
     pub user: Option<User>,
 }
 
-struct CreateUserResponseDeserializer;
+pub(crate) struct CreateUserResponseDeserializer;
 impl CreateUserResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateUserResponse, XmlParseError> {
@@ -1584,6 +2710,46 @@ pub struct CreateVirtualMFADeviceRequest {
     pub virtual_mfa_device_name: String,
 }
 
+impl CreateVirtualMFADeviceRequest {
+    /// Returns a builder for `CreateVirtualMFADeviceRequest`, checking its required members when built.
+    pub fn builder() -> CreateVirtualMFADeviceRequestBuilder {
+        CreateVirtualMFADeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateVirtualMFADeviceRequest`](struct.CreateVirtualMFADeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateVirtualMFADeviceRequestBuilder {
+    path: Option<String>,
+    virtual_mfa_device_name: Option<String>,
+}
+
+impl CreateVirtualMFADeviceRequestBuilder {
+    /// Sets the [`path`](struct.CreateVirtualMFADeviceRequest.html#structfield.path) member.
+    pub fn path<T: Into<String>>(mut self, value: T) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtual_mfa_device_name`](struct.CreateVirtualMFADeviceRequest.html#structfield.virtual_mfa_device_name) member.
+    pub fn virtual_mfa_device_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtual_mfa_device_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateVirtualMFADeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateVirtualMFADeviceRequest, ::rusoto_core::BuildError> {
+        Ok(CreateVirtualMFADeviceRequest {
+            path: self.path,
+            virtual_mfa_device_name: self.virtual_mfa_device_name.ok_or(
+                ::rusoto_core::BuildError::missing("virtual_mfa_device_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `CreateVirtualMFADeviceRequest` contents to a `SignedRequest`.
 struct CreateVirtualMFADeviceRequestSerializer;
 impl CreateVirtualMFADeviceRequestSerializer {
@@ -1610,10 +2776,10 @@ pub struct CreateVirtualMFADeviceResponse {
     pub virtual_mfa_device: VirtualMFADevice,
 }
 
-struct CreateVirtualMFADeviceResponseDeserializer;
+pub(crate) struct CreateVirtualMFADeviceResponseDeserializer;
 impl CreateVirtualMFADeviceResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateVirtualMFADeviceResponse, XmlParseError> {
@@ -1633,12 +2799,15 @@ impl CreateVirtualMFADeviceResponseDeserializer {
         )
     }
 }
-struct DateTypeDeserializer;
+pub(crate) struct DateTypeDeserializer;
 impl DateTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -1652,6 +2821,48 @@ pub struct DeactivateMFADeviceRequest {
     pub user_name: String,
 }
 
+impl DeactivateMFADeviceRequest {
+    /// Returns a builder for `DeactivateMFADeviceRequest`, checking its required members when built.
+    pub fn builder() -> DeactivateMFADeviceRequestBuilder {
+        DeactivateMFADeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeactivateMFADeviceRequest`](struct.DeactivateMFADeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeactivateMFADeviceRequestBuilder {
+    serial_number: Option<String>,
+    user_name: Option<String>,
+}
+
+impl DeactivateMFADeviceRequestBuilder {
+    /// Sets the [`serial_number`](struct.DeactivateMFADeviceRequest.html#structfield.serial_number) member.
+    pub fn serial_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.serial_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DeactivateMFADeviceRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeactivateMFADeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeactivateMFADeviceRequest, ::rusoto_core::BuildError> {
+        Ok(DeactivateMFADeviceRequest {
+            serial_number: self
+                .serial_number
+                .ok_or(::rusoto_core::BuildError::missing("serial_number"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeactivateMFADeviceRequest` contents to a `SignedRequest`.
 struct DeactivateMFADeviceRequestSerializer;
 impl DeactivateMFADeviceRequestSerializer {
@@ -1674,6 +2885,44 @@ pub struct DeleteAccessKeyRequest {
     pub user_name: Option<String>,
 }
 
+impl DeleteAccessKeyRequest {
+    /// Returns a builder for `DeleteAccessKeyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAccessKeyRequestBuilder {
+        DeleteAccessKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAccessKeyRequest`](struct.DeleteAccessKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAccessKeyRequestBuilder {
+    access_key_id: Option<String>,
+    user_name: Option<String>,
+}
+
+impl DeleteAccessKeyRequestBuilder {
+    /// Sets the [`access_key_id`](struct.DeleteAccessKeyRequest.html#structfield.access_key_id) member.
+    pub fn access_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DeleteAccessKeyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAccessKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteAccessKeyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAccessKeyRequest {
+            access_key_id: self
+                .access_key_id
+                .ok_or(::rusoto_core::BuildError::missing("access_key_id"))?,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `DeleteAccessKeyRequest` contents to a `SignedRequest`.
 struct DeleteAccessKeyRequestSerializer;
 impl DeleteAccessKeyRequestSerializer {
@@ -1696,6 +2945,38 @@ pub struct DeleteAccountAliasRequest {
     pub account_alias: String,
 }
 
+impl DeleteAccountAliasRequest {
+    /// Returns a builder for `DeleteAccountAliasRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAccountAliasRequestBuilder {
+        DeleteAccountAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAccountAliasRequest`](struct.DeleteAccountAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAccountAliasRequestBuilder {
+    account_alias: Option<String>,
+}
+
+impl DeleteAccountAliasRequestBuilder {
+    /// Sets the [`account_alias`](struct.DeleteAccountAliasRequest.html#structfield.account_alias) member.
+    pub fn account_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_alias = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAccountAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAccountAliasRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAccountAliasRequest {
+            account_alias: self
+                .account_alias
+                .ok_or(::rusoto_core::BuildError::missing("account_alias"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteAccountAliasRequest` contents to a `SignedRequest`.
 struct DeleteAccountAliasRequestSerializer;
 impl DeleteAccountAliasRequestSerializer {
@@ -1717,6 +2998,48 @@ pub struct DeleteGroupPolicyRequest {
     pub policy_name: String,
 }
 
+impl DeleteGroupPolicyRequest {
+    /// Returns a builder for `DeleteGroupPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteGroupPolicyRequestBuilder {
+        DeleteGroupPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGroupPolicyRequest`](struct.DeleteGroupPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteGroupPolicyRequestBuilder {
+    group_name: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl DeleteGroupPolicyRequestBuilder {
+    /// Sets the [`group_name`](struct.DeleteGroupPolicyRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.DeleteGroupPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGroupPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteGroupPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteGroupPolicyRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteGroupPolicyRequest` contents to a `SignedRequest`.
 struct DeleteGroupPolicyRequestSerializer;
 impl DeleteGroupPolicyRequestSerializer {
@@ -1737,6 +3060,36 @@ pub struct DeleteGroupRequest {
     pub group_name: String,
 }
 
+impl DeleteGroupRequest {
+    /// Returns a builder for `DeleteGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteGroupRequestBuilder {
+        DeleteGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGroupRequest`](struct.DeleteGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteGroupRequestBuilder {
+    group_name: Option<String>,
+}
+
+impl DeleteGroupRequestBuilder {
+    /// Sets the [`group_name`](struct.DeleteGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteGroupRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteGroupRequest` contents to a `SignedRequest`.
 struct DeleteGroupRequestSerializer;
 impl DeleteGroupRequestSerializer {
@@ -1756,6 +3109,38 @@ pub struct DeleteInstanceProfileRequest {
     pub instance_profile_name: String,
 }
 
+impl DeleteInstanceProfileRequest {
+    /// Returns a builder for `DeleteInstanceProfileRequest`, checking its required members when built.
+    pub fn builder() -> DeleteInstanceProfileRequestBuilder {
+        DeleteInstanceProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteInstanceProfileRequest`](struct.DeleteInstanceProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteInstanceProfileRequestBuilder {
+    instance_profile_name: Option<String>,
+}
+
+impl DeleteInstanceProfileRequestBuilder {
+    /// Sets the [`instance_profile_name`](struct.DeleteInstanceProfileRequest.html#structfield.instance_profile_name) member.
+    pub fn instance_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_profile_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteInstanceProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteInstanceProfileRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteInstanceProfileRequest {
+            instance_profile_name: self
+                .instance_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_profile_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteInstanceProfileRequest` contents to a `SignedRequest`.
 struct DeleteInstanceProfileRequestSerializer;
 impl DeleteInstanceProfileRequestSerializer {
@@ -1778,6 +3163,38 @@ pub struct DeleteLoginProfileRequest {
     pub user_name: String,
 }
 
+impl DeleteLoginProfileRequest {
+    /// Returns a builder for `DeleteLoginProfileRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLoginProfileRequestBuilder {
+        DeleteLoginProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLoginProfileRequest`](struct.DeleteLoginProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLoginProfileRequestBuilder {
+    user_name: Option<String>,
+}
+
+impl DeleteLoginProfileRequestBuilder {
+    /// Sets the [`user_name`](struct.DeleteLoginProfileRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLoginProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLoginProfileRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLoginProfileRequest {
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteLoginProfileRequest` contents to a `SignedRequest`.
 struct DeleteLoginProfileRequestSerializer;
 impl DeleteLoginProfileRequestSerializer {
@@ -1797,6 +3214,38 @@ pub struct DeleteOpenIDConnectProviderRequest {
     pub open_id_connect_provider_arn: String,
 }
 
+impl DeleteOpenIDConnectProviderRequest {
+    /// Returns a builder for `DeleteOpenIDConnectProviderRequest`, checking its required members when built.
+    pub fn builder() -> DeleteOpenIDConnectProviderRequestBuilder {
+        DeleteOpenIDConnectProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteOpenIDConnectProviderRequest`](struct.DeleteOpenIDConnectProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteOpenIDConnectProviderRequestBuilder {
+    open_id_connect_provider_arn: Option<String>,
+}
+
+impl DeleteOpenIDConnectProviderRequestBuilder {
+    /// Sets the [`open_id_connect_provider_arn`](struct.DeleteOpenIDConnectProviderRequest.html#structfield.open_id_connect_provider_arn) member.
+    pub fn open_id_connect_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.open_id_connect_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteOpenIDConnectProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteOpenIDConnectProviderRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteOpenIDConnectProviderRequest {
+            open_id_connect_provider_arn: self.open_id_connect_provider_arn.ok_or(
+                ::rusoto_core::BuildError::missing("open_id_connect_provider_arn"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteOpenIDConnectProviderRequest` contents to a `SignedRequest`.
 struct DeleteOpenIDConnectProviderRequestSerializer;
 impl DeleteOpenIDConnectProviderRequestSerializer {
@@ -1819,7 +3268,37 @@ pub struct DeletePolicyRequest {
     pub policy_arn: String,
 }
 
-/// Serialize `DeletePolicyRequest` contents to a `SignedRequest`.
+impl DeletePolicyRequest {
+    /// Returns a builder for `DeletePolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeletePolicyRequestBuilder {
+        DeletePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePolicyRequest`](struct.DeletePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePolicyRequestBuilder {
+    policy_arn: Option<String>,
+}
+
+impl DeletePolicyRequestBuilder {
+    /// Sets the [`policy_arn`](struct.DeletePolicyRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePolicyRequest {
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+        })
+    }
+}
+
+/// Serialize `DeletePolicyRequest` contents to a `SignedRequest`.
 struct DeletePolicyRequestSerializer;
 impl DeletePolicyRequestSerializer {
     fn serialize(params: &mut Params, name: &str, obj: &DeletePolicyRequest) {
@@ -1840,6 +3319,48 @@ pub struct DeletePolicyVersionRequest {
     pub version_id: String,
 }
 
+impl DeletePolicyVersionRequest {
+    /// Returns a builder for `DeletePolicyVersionRequest`, checking its required members when built.
+    pub fn builder() -> DeletePolicyVersionRequestBuilder {
+        DeletePolicyVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePolicyVersionRequest`](struct.DeletePolicyVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePolicyVersionRequestBuilder {
+    policy_arn: Option<String>,
+    version_id: Option<String>,
+}
+
+impl DeletePolicyVersionRequestBuilder {
+    /// Sets the [`policy_arn`](struct.DeletePolicyVersionRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.DeletePolicyVersionRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePolicyVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeletePolicyVersionRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePolicyVersionRequest {
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+            version_id: self
+                .version_id
+                .ok_or(::rusoto_core::BuildError::missing("version_id"))?,
+        })
+    }
+}
+
 /// Serialize `DeletePolicyVersionRequest` contents to a `SignedRequest`.
 struct DeletePolicyVersionRequestSerializer;
 impl DeletePolicyVersionRequestSerializer {
@@ -1860,6 +3381,39 @@ pub struct DeleteRolePermissionsBoundaryRequest {
     pub role_name: String,
 }
 
+impl DeleteRolePermissionsBoundaryRequest {
+    /// Returns a builder for `DeleteRolePermissionsBoundaryRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRolePermissionsBoundaryRequestBuilder {
+        DeleteRolePermissionsBoundaryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRolePermissionsBoundaryRequest`](struct.DeleteRolePermissionsBoundaryRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRolePermissionsBoundaryRequestBuilder {
+    role_name: Option<String>,
+}
+
+impl DeleteRolePermissionsBoundaryRequestBuilder {
+    /// Sets the [`role_name`](struct.DeleteRolePermissionsBoundaryRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRolePermissionsBoundaryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRolePermissionsBoundaryRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteRolePermissionsBoundaryRequest {
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteRolePermissionsBoundaryRequest` contents to a `SignedRequest`.
 struct DeleteRolePermissionsBoundaryRequestSerializer;
 impl DeleteRolePermissionsBoundaryRequestSerializer {
@@ -1881,6 +3435,48 @@ pub struct DeleteRolePolicyRequest {
     pub role_name: String,
 }
 
+impl DeleteRolePolicyRequest {
+    /// Returns a builder for `DeleteRolePolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRolePolicyRequestBuilder {
+        DeleteRolePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRolePolicyRequest`](struct.DeleteRolePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRolePolicyRequestBuilder {
+    policy_name: Option<String>,
+    role_name: Option<String>,
+}
+
+impl DeleteRolePolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.DeleteRolePolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.DeleteRolePolicyRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRolePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRolePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRolePolicyRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteRolePolicyRequest` contents to a `SignedRequest`.
 struct DeleteRolePolicyRequestSerializer;
 impl DeleteRolePolicyRequestSerializer {
@@ -1901,6 +3497,36 @@ pub struct DeleteRoleRequest {
     pub role_name: String,
 }
 
+impl DeleteRoleRequest {
+    /// Returns a builder for `DeleteRoleRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRoleRequestBuilder {
+        DeleteRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRoleRequest`](struct.DeleteRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRoleRequestBuilder {
+    role_name: Option<String>,
+}
+
+impl DeleteRoleRequestBuilder {
+    /// Sets the [`role_name`](struct.DeleteRoleRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteRoleRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRoleRequest {
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteRoleRequest` contents to a `SignedRequest`.
 struct DeleteRoleRequestSerializer;
 impl DeleteRoleRequestSerializer {
@@ -1920,6 +3546,38 @@ pub struct DeleteSAMLProviderRequest {
     pub saml_provider_arn: String,
 }
 
+impl DeleteSAMLProviderRequest {
+    /// Returns a builder for `DeleteSAMLProviderRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSAMLProviderRequestBuilder {
+        DeleteSAMLProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSAMLProviderRequest`](struct.DeleteSAMLProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSAMLProviderRequestBuilder {
+    saml_provider_arn: Option<String>,
+}
+
+impl DeleteSAMLProviderRequestBuilder {
+    /// Sets the [`saml_provider_arn`](struct.DeleteSAMLProviderRequest.html#structfield.saml_provider_arn) member.
+    pub fn saml_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.saml_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSAMLProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSAMLProviderRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSAMLProviderRequest {
+            saml_provider_arn: self
+                .saml_provider_arn
+                .ok_or(::rusoto_core::BuildError::missing("saml_provider_arn"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteSAMLProviderRequest` contents to a `SignedRequest`.
 struct DeleteSAMLProviderRequestSerializer;
 impl DeleteSAMLProviderRequestSerializer {
@@ -1944,6 +3602,48 @@ pub struct DeleteSSHPublicKeyRequest {
     pub user_name: String,
 }
 
+impl DeleteSSHPublicKeyRequest {
+    /// Returns a builder for `DeleteSSHPublicKeyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSSHPublicKeyRequestBuilder {
+        DeleteSSHPublicKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSSHPublicKeyRequest`](struct.DeleteSSHPublicKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSSHPublicKeyRequestBuilder {
+    ssh_public_key_id: Option<String>,
+    user_name: Option<String>,
+}
+
+impl DeleteSSHPublicKeyRequestBuilder {
+    /// Sets the [`ssh_public_key_id`](struct.DeleteSSHPublicKeyRequest.html#structfield.ssh_public_key_id) member.
+    pub fn ssh_public_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DeleteSSHPublicKeyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSSHPublicKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSSHPublicKeyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSSHPublicKeyRequest {
+            ssh_public_key_id: self
+                .ssh_public_key_id
+                .ok_or(::rusoto_core::BuildError::missing("ssh_public_key_id"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteSSHPublicKeyRequest` contents to a `SignedRequest`.
 struct DeleteSSHPublicKeyRequestSerializer;
 impl DeleteSSHPublicKeyRequestSerializer {
@@ -1967,6 +3667,38 @@ pub struct DeleteServerCertificateRequest {
     pub server_certificate_name: String,
 }
 
+impl DeleteServerCertificateRequest {
+    /// Returns a builder for `DeleteServerCertificateRequest`, checking its required members when built.
+    pub fn builder() -> DeleteServerCertificateRequestBuilder {
+        DeleteServerCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteServerCertificateRequest`](struct.DeleteServerCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteServerCertificateRequestBuilder {
+    server_certificate_name: Option<String>,
+}
+
+impl DeleteServerCertificateRequestBuilder {
+    /// Sets the [`server_certificate_name`](struct.DeleteServerCertificateRequest.html#structfield.server_certificate_name) member.
+    pub fn server_certificate_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_certificate_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteServerCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteServerCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteServerCertificateRequest {
+            server_certificate_name: self.server_certificate_name.ok_or(
+                ::rusoto_core::BuildError::missing("server_certificate_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteServerCertificateRequest` contents to a `SignedRequest`.
 struct DeleteServerCertificateRequestSerializer;
 impl DeleteServerCertificateRequestSerializer {
@@ -1989,6 +3721,38 @@ pub struct DeleteServiceLinkedRoleRequest {
     pub role_name: String,
 }
 
+impl DeleteServiceLinkedRoleRequest {
+    /// Returns a builder for `DeleteServiceLinkedRoleRequest`, checking its required members when built.
+    pub fn builder() -> DeleteServiceLinkedRoleRequestBuilder {
+        DeleteServiceLinkedRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteServiceLinkedRoleRequest`](struct.DeleteServiceLinkedRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteServiceLinkedRoleRequestBuilder {
+    role_name: Option<String>,
+}
+
+impl DeleteServiceLinkedRoleRequestBuilder {
+    /// Sets the [`role_name`](struct.DeleteServiceLinkedRoleRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteServiceLinkedRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteServiceLinkedRoleRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteServiceLinkedRoleRequest {
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteServiceLinkedRoleRequest` contents to a `SignedRequest`.
 struct DeleteServiceLinkedRoleRequestSerializer;
 impl DeleteServiceLinkedRoleRequestSerializer {
@@ -2008,10 +3772,10 @@ pub struct DeleteServiceLinkedRoleResponse {
     pub deletion_task_id: String,
 }
 
-struct DeleteServiceLinkedRoleResponseDeserializer;
+pub(crate) struct DeleteServiceLinkedRoleResponseDeserializer;
 impl DeleteServiceLinkedRoleResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteServiceLinkedRoleResponse, XmlParseError> {
@@ -2039,6 +3803,47 @@ pub struct DeleteServiceSpecificCredentialRequest {
     pub user_name: Option<String>,
 }
 
+impl DeleteServiceSpecificCredentialRequest {
+    /// Returns a builder for `DeleteServiceSpecificCredentialRequest`, checking its required members when built.
+    pub fn builder() -> DeleteServiceSpecificCredentialRequestBuilder {
+        DeleteServiceSpecificCredentialRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteServiceSpecificCredentialRequest`](struct.DeleteServiceSpecificCredentialRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteServiceSpecificCredentialRequestBuilder {
+    service_specific_credential_id: Option<String>,
+    user_name: Option<String>,
+}
+
+impl DeleteServiceSpecificCredentialRequestBuilder {
+    /// Sets the [`service_specific_credential_id`](struct.DeleteServiceSpecificCredentialRequest.html#structfield.service_specific_credential_id) member.
+    pub fn service_specific_credential_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_specific_credential_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DeleteServiceSpecificCredentialRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteServiceSpecificCredentialRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteServiceSpecificCredentialRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteServiceSpecificCredentialRequest {
+            service_specific_credential_id: self.service_specific_credential_id.ok_or(
+                ::rusoto_core::BuildError::missing("service_specific_credential_id"),
+            )?,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `DeleteServiceSpecificCredentialRequest` contents to a `SignedRequest`.
 struct DeleteServiceSpecificCredentialRequestSerializer;
 impl DeleteServiceSpecificCredentialRequestSerializer {
@@ -2066,6 +3871,46 @@ pub struct DeleteSigningCertificateRequest {
     pub user_name: Option<String>,
 }
 
+impl DeleteSigningCertificateRequest {
+    /// Returns a builder for `DeleteSigningCertificateRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSigningCertificateRequestBuilder {
+        DeleteSigningCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSigningCertificateRequest`](struct.DeleteSigningCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSigningCertificateRequestBuilder {
+    certificate_id: Option<String>,
+    user_name: Option<String>,
+}
+
+impl DeleteSigningCertificateRequestBuilder {
+    /// Sets the [`certificate_id`](struct.DeleteSigningCertificateRequest.html#structfield.certificate_id) member.
+    pub fn certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DeleteSigningCertificateRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSigningCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSigningCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSigningCertificateRequest {
+            certificate_id: self
+                .certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("certificate_id"))?,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `DeleteSigningCertificateRequest` contents to a `SignedRequest`.
 struct DeleteSigningCertificateRequestSerializer;
 impl DeleteSigningCertificateRequestSerializer {
@@ -2091,6 +3936,39 @@ pub struct DeleteUserPermissionsBoundaryRequest {
     pub user_name: String,
 }
 
+impl DeleteUserPermissionsBoundaryRequest {
+    /// Returns a builder for `DeleteUserPermissionsBoundaryRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserPermissionsBoundaryRequestBuilder {
+        DeleteUserPermissionsBoundaryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserPermissionsBoundaryRequest`](struct.DeleteUserPermissionsBoundaryRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserPermissionsBoundaryRequestBuilder {
+    user_name: Option<String>,
+}
+
+impl DeleteUserPermissionsBoundaryRequestBuilder {
+    /// Sets the [`user_name`](struct.DeleteUserPermissionsBoundaryRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserPermissionsBoundaryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteUserPermissionsBoundaryRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteUserPermissionsBoundaryRequest {
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteUserPermissionsBoundaryRequest` contents to a `SignedRequest`.
 struct DeleteUserPermissionsBoundaryRequestSerializer;
 impl DeleteUserPermissionsBoundaryRequestSerializer {
@@ -2112,6 +3990,48 @@ pub struct DeleteUserPolicyRequest {
     pub user_name: String,
 }
 
+impl DeleteUserPolicyRequest {
+    /// Returns a builder for `DeleteUserPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserPolicyRequestBuilder {
+        DeleteUserPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserPolicyRequest`](struct.DeleteUserPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserPolicyRequestBuilder {
+    policy_name: Option<String>,
+    user_name: Option<String>,
+}
+
+impl DeleteUserPolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.DeleteUserPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DeleteUserPolicyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteUserPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserPolicyRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteUserPolicyRequest` contents to a `SignedRequest`.
 struct DeleteUserPolicyRequestSerializer;
 impl DeleteUserPolicyRequestSerializer {
@@ -2132,6 +4052,36 @@ pub struct DeleteUserRequest {
     pub user_name: String,
 }
 
+impl DeleteUserRequest {
+    /// Returns a builder for `DeleteUserRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserRequestBuilder {
+        DeleteUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserRequest`](struct.DeleteUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserRequestBuilder {
+    user_name: Option<String>,
+}
+
+impl DeleteUserRequestBuilder {
+    /// Sets the [`user_name`](struct.DeleteUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteUserRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserRequest {
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteUserRequest` contents to a `SignedRequest`.
 struct DeleteUserRequestSerializer;
 impl DeleteUserRequestSerializer {
@@ -2151,6 +4101,38 @@ pub struct DeleteVirtualMFADeviceRequest {
     pub serial_number: String,
 }
 
+impl DeleteVirtualMFADeviceRequest {
+    /// Returns a builder for `DeleteVirtualMFADeviceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteVirtualMFADeviceRequestBuilder {
+        DeleteVirtualMFADeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteVirtualMFADeviceRequest`](struct.DeleteVirtualMFADeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteVirtualMFADeviceRequestBuilder {
+    serial_number: Option<String>,
+}
+
+impl DeleteVirtualMFADeviceRequestBuilder {
+    /// Sets the [`serial_number`](struct.DeleteVirtualMFADeviceRequest.html#structfield.serial_number) member.
+    pub fn serial_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.serial_number = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteVirtualMFADeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteVirtualMFADeviceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteVirtualMFADeviceRequest {
+            serial_number: self
+                .serial_number
+                .ok_or(::rusoto_core::BuildError::missing("serial_number"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteVirtualMFADeviceRequest` contents to a `SignedRequest`.
 struct DeleteVirtualMFADeviceRequestSerializer;
 impl DeleteVirtualMFADeviceRequestSerializer {
@@ -2173,10 +4155,10 @@ pub struct DeletionTaskFailureReasonType {
     pub role_usage_list: Option<Vec<RoleUsageType>>,
 }
 
-struct DeletionTaskFailureReasonTypeDeserializer;
+pub(crate) struct DeletionTaskFailureReasonTypeDeserializer;
 impl DeletionTaskFailureReasonTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeletionTaskFailureReasonType, XmlParseError> {
@@ -2200,10 +4182,13 @@ impl DeletionTaskFailureReasonTypeDeserializer {
         )
     }
 }
-struct DeletionTaskIdTypeDeserializer;
+pub(crate) struct DeletionTaskIdTypeDeserializer;
 impl DeletionTaskIdTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2211,17 +4196,87 @@ impl DeletionTaskIdTypeDeserializer {
         Ok(obj)
     }
 }
-struct DeletionTaskStatusTypeDeserializer;
+pub(crate) struct DeletionTaskStatusTypeDeserializer;
 impl DeletionTaskStatusTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<DeletionTaskStatusType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The status of the deletion.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeletionTaskStatusType {
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>NOT_STARTED</code></p>
+    NotStarted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeletionTaskStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeletionTaskStatusType::Succeeded => "SUCCEEDED",
+            DeletionTaskStatusType::InProgress => "IN_PROGRESS",
+            DeletionTaskStatusType::Failed => "FAILED",
+            DeletionTaskStatusType::NotStarted => "NOT_STARTED",
+            DeletionTaskStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeletionTaskStatusType {
+    fn from(value: &'a str) -> DeletionTaskStatusType {
+        match value {
+            "SUCCEEDED" => DeletionTaskStatusType::Succeeded,
+            "IN_PROGRESS" => DeletionTaskStatusType::InProgress,
+            "FAILED" => DeletionTaskStatusType::Failed,
+            "NOT_STARTED" => DeletionTaskStatusType::NotStarted,
+            _ => DeletionTaskStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeletionTaskStatusType {
+    fn from(value: String) -> DeletionTaskStatusType {
+        DeletionTaskStatusType::from(value.as_str())
+    }
+}
+
+impl From<DeletionTaskStatusType> for String {
+    fn from(value: DeletionTaskStatusType) -> String {
+        match value {
+            DeletionTaskStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeletionTaskStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeletionTaskStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DetachGroupPolicyRequest {
     /// <p>The name (friendly name, not ARN) of the IAM group to detach the policy from.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
@@ -2230,6 +4285,48 @@ pub struct DetachGroupPolicyRequest {
     pub policy_arn: String,
 }
 
+impl DetachGroupPolicyRequest {
+    /// Returns a builder for `DetachGroupPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DetachGroupPolicyRequestBuilder {
+        DetachGroupPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachGroupPolicyRequest`](struct.DetachGroupPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachGroupPolicyRequestBuilder {
+    group_name: Option<String>,
+    policy_arn: Option<String>,
+}
+
+impl DetachGroupPolicyRequestBuilder {
+    /// Sets the [`group_name`](struct.DetachGroupPolicyRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_arn`](struct.DetachGroupPolicyRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachGroupPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetachGroupPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DetachGroupPolicyRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+        })
+    }
+}
+
 /// Serialize `DetachGroupPolicyRequest` contents to a `SignedRequest`.
 struct DetachGroupPolicyRequestSerializer;
 impl DetachGroupPolicyRequestSerializer {
@@ -2252,6 +4349,48 @@ pub struct DetachRolePolicyRequest {
     pub role_name: String,
 }
 
+impl DetachRolePolicyRequest {
+    /// Returns a builder for `DetachRolePolicyRequest`, checking its required members when built.
+    pub fn builder() -> DetachRolePolicyRequestBuilder {
+        DetachRolePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachRolePolicyRequest`](struct.DetachRolePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachRolePolicyRequestBuilder {
+    policy_arn: Option<String>,
+    role_name: Option<String>,
+}
+
+impl DetachRolePolicyRequestBuilder {
+    /// Sets the [`policy_arn`](struct.DetachRolePolicyRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.DetachRolePolicyRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachRolePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetachRolePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DetachRolePolicyRequest {
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `DetachRolePolicyRequest` contents to a `SignedRequest`.
 struct DetachRolePolicyRequestSerializer;
 impl DetachRolePolicyRequestSerializer {
@@ -2274,6 +4413,48 @@ pub struct DetachUserPolicyRequest {
     pub user_name: String,
 }
 
+impl DetachUserPolicyRequest {
+    /// Returns a builder for `DetachUserPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DetachUserPolicyRequestBuilder {
+        DetachUserPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachUserPolicyRequest`](struct.DetachUserPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachUserPolicyRequestBuilder {
+    policy_arn: Option<String>,
+    user_name: Option<String>,
+}
+
+impl DetachUserPolicyRequestBuilder {
+    /// Sets the [`policy_arn`](struct.DetachUserPolicyRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.DetachUserPolicyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachUserPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetachUserPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DetachUserPolicyRequest {
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `DetachUserPolicyRequest` contents to a `SignedRequest`.
 struct DetachUserPolicyRequestSerializer;
 impl DetachUserPolicyRequestSerializer {
@@ -2300,13 +4481,73 @@ pub struct EnableMFADeviceRequest {
     pub user_name: String,
 }
 
-/// Serialize `EnableMFADeviceRequest` contents to a `SignedRequest`.
-struct EnableMFADeviceRequestSerializer;
-impl EnableMFADeviceRequestSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &EnableMFADeviceRequest) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
+impl EnableMFADeviceRequest {
+    /// Returns a builder for `EnableMFADeviceRequest`, checking its required members when built.
+    pub fn builder() -> EnableMFADeviceRequestBuilder {
+        EnableMFADeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableMFADeviceRequest`](struct.EnableMFADeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableMFADeviceRequestBuilder {
+    authentication_code_1: Option<String>,
+    authentication_code_2: Option<String>,
+    serial_number: Option<String>,
+    user_name: Option<String>,
+}
+
+impl EnableMFADeviceRequestBuilder {
+    /// Sets the [`authentication_code_1`](struct.EnableMFADeviceRequest.html#structfield.authentication_code_1) member.
+    pub fn authentication_code_1<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_code_1 = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authentication_code_2`](struct.EnableMFADeviceRequest.html#structfield.authentication_code_2) member.
+    pub fn authentication_code_2<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_code_2 = Some(value.into());
+        self
+    }
+
+    /// Sets the [`serial_number`](struct.EnableMFADeviceRequest.html#structfield.serial_number) member.
+    pub fn serial_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.serial_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.EnableMFADeviceRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableMFADeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<EnableMFADeviceRequest, ::rusoto_core::BuildError> {
+        Ok(EnableMFADeviceRequest {
+            authentication_code_1: self
+                .authentication_code_1
+                .ok_or(::rusoto_core::BuildError::missing("authentication_code_1"))?,
+            authentication_code_2: self
+                .authentication_code_2
+                .ok_or(::rusoto_core::BuildError::missing("authentication_code_2"))?,
+            serial_number: self
+                .serial_number
+                .ok_or(::rusoto_core::BuildError::missing("serial_number"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
+/// Serialize `EnableMFADeviceRequest` contents to a `SignedRequest`.
+struct EnableMFADeviceRequestSerializer;
+impl EnableMFADeviceRequestSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &EnableMFADeviceRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
         }
 
         params.put(
@@ -2322,43 +4563,116 @@ impl EnableMFADeviceRequestSerializer {
     }
 }
 
+/// <p>Specifies the public key encoding format to use in the response. To retrieve the public key in ssh-rsa format, use <code>SSH</code>. To retrieve the public key in PEM format, use <code>PEM</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EncodingType {
+    /// <p><code>SSH</code></p>
+    Ssh,
+    /// <p><code>PEM</code></p>
+    Pem,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EncodingType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncodingType::Ssh => "SSH",
+            EncodingType::Pem => "PEM",
+            EncodingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EncodingType {
+    fn from(value: &'a str) -> EncodingType {
+        match value {
+            "SSH" => EncodingType::Ssh,
+            "PEM" => EncodingType::Pem,
+            _ => EncodingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncodingType {
+    fn from(value: String) -> EncodingType {
+        EncodingType::from(value.as_str())
+    }
+}
+
+impl From<EncodingType> for String {
+    fn from(value: EncodingType) -> String {
+        match value {
+            EncodingType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncodingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncodingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>An object that contains details about when the IAM entities (users or roles) were last used in an attempt to access the specified AWS service.</p> <p>This data type is a response element in the <a>GetServiceLastAccessedDetailsWithEntities</a> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EntityDetails {
     /// <p>The <code>EntityInfo</code> object that contains details about the entity (user or role).</p>
     pub entity_info: EntityInfo,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the authenticated entity last attempted to access AWS. AWS does not report unauthenticated requests.</p> <p>This field is null if no IAM entities attempted to access the service within the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#service-last-accessed-reporting-period">reporting period</a>.</p>
-    pub last_authenticated: Option<String>,
+    pub last_authenticated: Option<IsoTimestamp>,
 }
 
-struct EntityDetailsDeserializer;
+pub(crate) struct EntityDetailsDeserializer;
 impl EntityDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EntityDetails, XmlParseError> {
-        deserialize_elements::<_, EntityDetails, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "EntityInfo" => {
-                    obj.entity_info = EntityInfoDeserializer::deserialize("EntityInfo", stack)?;
-                }
-                "LastAuthenticated" => {
-                    obj.last_authenticated = Some(DateTypeDeserializer::deserialize(
-                        "LastAuthenticated",
-                        stack,
-                    )?);
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            EntityDetails {
+                entity_info: EntityInfo {
+                    arn: Default::default(),
+                    id: Default::default(),
+                    name: Default::default(),
+                    path: Default::default(),
+                    type_: PolicyOwnerEntityType::Unknown(String::new()),
+                },
+                last_authenticated: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "EntityInfo" => {
+                        obj.entity_info = EntityInfoDeserializer::deserialize("EntityInfo", stack)?;
+                    }
+                    "LastAuthenticated" => {
+                        obj.last_authenticated = Some(DateTypeDeserializer::deserialize(
+                            "LastAuthenticated",
+                            stack,
+                        )?);
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct EntityDetailsListTypeDeserializer;
+pub(crate) struct EntityDetailsListTypeDeserializer;
 impl EntityDetailsListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EntityDetails>, XmlParseError> {
@@ -2373,7 +4687,7 @@ impl EntityDetailsListTypeDeserializer {
     }
 }
 /// <p>Contains details about the specified entity (user or role).</p> <p>This data type is an element of the <a>EntityDetails</a> object.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EntityInfo {
     pub arn: String,
     /// <p>The identifier of the entity (user or role).</p>
@@ -2383,44 +4697,55 @@ pub struct EntityInfo {
     /// <p>The path to the entity (user or role). For more information about paths, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide. </p>
     pub path: Option<String>,
     /// <p>The type of entity (user or role).</p>
-    pub type_: String,
+    pub type_: PolicyOwnerEntityType,
 }
 
-struct EntityInfoDeserializer;
+pub(crate) struct EntityInfoDeserializer;
 impl EntityInfoDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EntityInfo, XmlParseError> {
-        deserialize_elements::<_, EntityInfo, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Arn" => {
-                    obj.arn = ArnTypeDeserializer::deserialize("Arn", stack)?;
-                }
-                "Id" => {
-                    obj.id = IdTypeDeserializer::deserialize("Id", stack)?;
-                }
-                "Name" => {
-                    obj.name = UserNameTypeDeserializer::deserialize("Name", stack)?;
-                }
-                "Path" => {
-                    obj.path = Some(PathTypeDeserializer::deserialize("Path", stack)?);
-                }
-                "Type" => {
-                    obj.type_ = PolicyOwnerEntityTypeDeserializer::deserialize("Type", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            EntityInfo {
+                arn: Default::default(),
+                id: Default::default(),
+                name: Default::default(),
+                path: Default::default(),
+                type_: PolicyOwnerEntityType::Unknown(String::new()),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Arn" => {
+                        obj.arn = ArnTypeDeserializer::deserialize("Arn", stack)?;
+                    }
+                    "Id" => {
+                        obj.id = IdTypeDeserializer::deserialize("Id", stack)?;
+                    }
+                    "Name" => {
+                        obj.name = UserNameTypeDeserializer::deserialize("Name", stack)?;
+                    }
+                    "Path" => {
+                        obj.path = Some(PathTypeDeserializer::deserialize("Path", stack)?);
+                    }
+                    "Type" => {
+                        obj.type_ = PolicyOwnerEntityTypeDeserializer::deserialize("Type", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 
 /// Serialize `EntityListType` contents to a `SignedRequest`.
 struct EntityListTypeSerializer;
 impl EntityListTypeSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &Vec<String>) {
+    fn serialize(params: &mut Params, name: &str, obj: &Vec<EntityType>) {
         for (index, obj) in obj.iter().enumerate() {
             let key = format!("{}.member.{}", name, index + 1);
             params.put(&key, &obj);
@@ -2428,10 +4753,13 @@ impl EntityListTypeSerializer {
     }
 }
 
-struct EntityNameTypeDeserializer;
+pub(crate) struct EntityNameTypeDeserializer;
 impl EntityNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2439,6 +4767,77 @@ impl EntityNameTypeDeserializer {
         Ok(obj)
     }
 }
+/// <p>A list of entity types used to filter the results. Only the entities that match the types you specify are included in the output. Use the value <code>LocalManagedPolicy</code> to include customer managed policies.</p> <p>The format for this parameter is a comma-separated (if more than one) list of strings. Each string value in the list must be one of the valid values listed below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EntityType {
+    /// <p><code>User</code></p>
+    User,
+    /// <p><code>Role</code></p>
+    Role,
+    /// <p><code>Group</code></p>
+    Group,
+    /// <p><code>LocalManagedPolicy</code></p>
+    LocalManagedPolicy,
+    /// <p><code>AWSManagedPolicy</code></p>
+    AWSManagedPolicy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EntityType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityType::User => "User",
+            EntityType::Role => "Role",
+            EntityType::Group => "Group",
+            EntityType::LocalManagedPolicy => "LocalManagedPolicy",
+            EntityType::AWSManagedPolicy => "AWSManagedPolicy",
+            EntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EntityType {
+    fn from(value: &'a str) -> EntityType {
+        match value {
+            "User" => EntityType::User,
+            "Role" => EntityType::Role,
+            "Group" => EntityType::Group,
+            "LocalManagedPolicy" => EntityType::LocalManagedPolicy,
+            "AWSManagedPolicy" => EntityType::AWSManagedPolicy,
+            _ => EntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EntityType {
+    fn from(value: String) -> EntityType {
+        EntityType::from(value.as_str())
+    }
+}
+
+impl From<EntityType> for String {
+    fn from(value: EntityType) -> String {
+        match value {
+            EntityType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EntityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Contains information about the reason that the operation failed.</p> <p>This data type is used as a response element in the <a>GetOrganizationsAccessReport</a>, <a>GetServiceLastAccessedDetails</a>, and <a>GetServiceLastAccessedDetailsWithEntities</a> operations.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ErrorDetails {
@@ -2448,10 +4847,10 @@ pub struct ErrorDetails {
     pub message: String,
 }
 
-struct ErrorDetailsDeserializer;
+pub(crate) struct ErrorDetailsDeserializer;
 impl ErrorDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ErrorDetails, XmlParseError> {
@@ -2469,13 +4868,14 @@ impl ErrorDetailsDeserializer {
         })
     }
 }
-struct EvalDecisionDetailsTypeDeserializer;
+pub(crate) struct EvalDecisionDetailsTypeDeserializer;
 impl EvalDecisionDetailsTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<::std::collections::HashMap<String, String>, XmlParseError> {
+    ) -> Result<::std::collections::HashMap<String, PolicyEvaluationDecisionType>, XmlParseError>
+    {
         start_element(tag_name, stack)?;
 
         let mut obj = ::std::collections::HashMap::new();
@@ -2492,10 +4892,13 @@ impl EvalDecisionDetailsTypeDeserializer {
         Ok(obj)
     }
 }
-struct EvalDecisionSourceTypeDeserializer;
+pub(crate) struct EvalDecisionSourceTypeDeserializer;
 impl EvalDecisionSourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2504,14 +4907,15 @@ impl EvalDecisionSourceTypeDeserializer {
     }
 }
 /// <p>Contains the results of a simulation.</p> <p>This data type is used by the return parameter of <code> <a>SimulateCustomPolicy</a> </code> and <code> <a>SimulatePrincipalPolicy</a> </code>.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct EvaluationResult {
     /// <p>The name of the API operation tested on the indicated resource.</p>
     pub eval_action_name: String,
     /// <p>The result of the simulation.</p>
-    pub eval_decision: String,
+    pub eval_decision: PolicyEvaluationDecisionType,
     /// <p>Additional details about the results of the evaluation decision. When there are both IAM policies and resource policies, this parameter explains how each set of policies contributes to the final evaluation decision. When simulating cross-account access to a resource, both the resource-based policy and the caller's IAM policy must grant access. See <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_roles_compare-resource-policies.html">How IAM Roles Differ from Resource-based Policies</a> </p>
-    pub eval_decision_details: Option<::std::collections::HashMap<String, String>>,
+    pub eval_decision_details:
+        Option<::std::collections::HashMap<String, PolicyEvaluationDecisionType>>,
     /// <p>The ARN of the resource that the indicated API operation was tested on.</p>
     pub eval_resource_name: Option<String>,
     /// <p>A list of the statements in the input policies that determine the result for this scenario. Remember that even if multiple statements allow the operation on the resource, if only one statement denies that operation, then the explicit deny overrides any allow. In addition, the deny statement is the only entry included in the result.</p>
@@ -2524,76 +4928,90 @@ pub struct EvaluationResult {
     pub resource_specific_results: Option<Vec<ResourceSpecificResult>>,
 }
 
-struct EvaluationResultDeserializer;
+pub(crate) struct EvaluationResultDeserializer;
 impl EvaluationResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EvaluationResult, XmlParseError> {
-        deserialize_elements::<_, EvaluationResult, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "EvalActionName" => {
-                    obj.eval_action_name =
-                        ActionNameTypeDeserializer::deserialize("EvalActionName", stack)?;
-                }
-                "EvalDecision" => {
-                    obj.eval_decision = PolicyEvaluationDecisionTypeDeserializer::deserialize(
-                        "EvalDecision",
-                        stack,
-                    )?;
-                }
-                "EvalDecisionDetails" => {
-                    obj.eval_decision_details =
-                        Some(EvalDecisionDetailsTypeDeserializer::deserialize(
-                            "EvalDecisionDetails",
-                            stack,
-                        )?);
-                }
-                "EvalResourceName" => {
-                    obj.eval_resource_name = Some(ResourceNameTypeDeserializer::deserialize(
-                        "EvalResourceName",
-                        stack,
-                    )?);
-                }
-                "MatchedStatements" => {
-                    obj.matched_statements.get_or_insert(vec![]).extend(
-                        StatementListTypeDeserializer::deserialize("MatchedStatements", stack)?,
-                    );
-                }
-                "MissingContextValues" => {
-                    obj.missing_context_values.get_or_insert(vec![]).extend(
-                        ContextKeyNamesResultListTypeDeserializer::deserialize(
-                            "MissingContextValues",
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            EvaluationResult {
+                eval_action_name: Default::default(),
+                eval_decision: PolicyEvaluationDecisionType::Unknown(String::new()),
+                eval_decision_details: Default::default(),
+                eval_resource_name: Default::default(),
+                matched_statements: Default::default(),
+                missing_context_values: Default::default(),
+                organizations_decision_detail: Default::default(),
+                resource_specific_results: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "EvalActionName" => {
+                        obj.eval_action_name =
+                            ActionNameTypeDeserializer::deserialize("EvalActionName", stack)?;
+                    }
+                    "EvalDecision" => {
+                        obj.eval_decision = PolicyEvaluationDecisionTypeDeserializer::deserialize(
+                            "EvalDecision",
                             stack,
-                        )?,
-                    );
-                }
-                "OrganizationsDecisionDetail" => {
-                    obj.organizations_decision_detail =
-                        Some(OrganizationsDecisionDetailDeserializer::deserialize(
-                            "OrganizationsDecisionDetail",
+                        )?;
+                    }
+                    "EvalDecisionDetails" => {
+                        obj.eval_decision_details =
+                            Some(EvalDecisionDetailsTypeDeserializer::deserialize(
+                                "EvalDecisionDetails",
+                                stack,
+                            )?);
+                    }
+                    "EvalResourceName" => {
+                        obj.eval_resource_name = Some(ResourceNameTypeDeserializer::deserialize(
+                            "EvalResourceName",
                             stack,
                         )?);
+                    }
+                    "MatchedStatements" => {
+                        obj.matched_statements.get_or_insert(vec![]).extend(
+                            StatementListTypeDeserializer::deserialize("MatchedStatements", stack)?,
+                        );
+                    }
+                    "MissingContextValues" => {
+                        obj.missing_context_values.get_or_insert(vec![]).extend(
+                            ContextKeyNamesResultListTypeDeserializer::deserialize(
+                                "MissingContextValues",
+                                stack,
+                            )?,
+                        );
+                    }
+                    "OrganizationsDecisionDetail" => {
+                        obj.organizations_decision_detail =
+                            Some(OrganizationsDecisionDetailDeserializer::deserialize(
+                                "OrganizationsDecisionDetail",
+                                stack,
+                            )?);
+                    }
+                    "ResourceSpecificResults" => {
+                        obj.resource_specific_results.get_or_insert(vec![]).extend(
+                            ResourceSpecificResultListTypeDeserializer::deserialize(
+                                "ResourceSpecificResults",
+                                stack,
+                            )?,
+                        );
+                    }
+                    _ => skip_tree(stack),
                 }
-                "ResourceSpecificResults" => {
-                    obj.resource_specific_results.get_or_insert(vec![]).extend(
-                        ResourceSpecificResultListTypeDeserializer::deserialize(
-                            "ResourceSpecificResults",
-                            stack,
-                        )?,
-                    );
-                }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct EvaluationResultsListTypeDeserializer;
+pub(crate) struct EvaluationResultsListTypeDeserializer;
 impl EvaluationResultsListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EvaluationResult>, XmlParseError> {
@@ -2607,10 +5025,13 @@ impl EvaluationResultsListTypeDeserializer {
         })
     }
 }
-struct ExistingUserNameTypeDeserializer;
+pub(crate) struct ExistingUserNameTypeDeserializer;
 impl ExistingUserNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -2624,13 +5045,13 @@ pub struct GenerateCredentialReportResponse {
     /// <p>Information about the credential report.</p>
     pub description: Option<String>,
     /// <p>Information about the state of the credential report.</p>
-    pub state: Option<String>,
+    pub state: Option<ReportStateType>,
 }
 
-struct GenerateCredentialReportResponseDeserializer;
+pub(crate) struct GenerateCredentialReportResponseDeserializer;
 impl GenerateCredentialReportResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GenerateCredentialReportResponse, XmlParseError> {
@@ -2664,6 +5085,47 @@ pub struct GenerateOrganizationsAccessReportRequest {
     pub organizations_policy_id: Option<String>,
 }
 
+impl GenerateOrganizationsAccessReportRequest {
+    /// Returns a builder for `GenerateOrganizationsAccessReportRequest`, checking its required members when built.
+    pub fn builder() -> GenerateOrganizationsAccessReportRequestBuilder {
+        GenerateOrganizationsAccessReportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GenerateOrganizationsAccessReportRequest`](struct.GenerateOrganizationsAccessReportRequest.html).
+#[derive(Default, Debug)]
+pub struct GenerateOrganizationsAccessReportRequestBuilder {
+    entity_path: Option<String>,
+    organizations_policy_id: Option<String>,
+}
+
+impl GenerateOrganizationsAccessReportRequestBuilder {
+    /// Sets the [`entity_path`](struct.GenerateOrganizationsAccessReportRequest.html#structfield.entity_path) member.
+    pub fn entity_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.entity_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organizations_policy_id`](struct.GenerateOrganizationsAccessReportRequest.html#structfield.organizations_policy_id) member.
+    pub fn organizations_policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organizations_policy_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GenerateOrganizationsAccessReportRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GenerateOrganizationsAccessReportRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GenerateOrganizationsAccessReportRequest {
+            entity_path: self
+                .entity_path
+                .ok_or(::rusoto_core::BuildError::missing("entity_path"))?,
+            organizations_policy_id: self.organizations_policy_id,
+        })
+    }
+}
+
 /// Serialize `GenerateOrganizationsAccessReportRequest` contents to a `SignedRequest`.
 struct GenerateOrganizationsAccessReportRequestSerializer;
 impl GenerateOrganizationsAccessReportRequestSerializer {
@@ -2689,10 +5151,10 @@ pub struct GenerateOrganizationsAccessReportResponse {
     pub job_id: Option<String>,
 }
 
-struct GenerateOrganizationsAccessReportResponseDeserializer;
+pub(crate) struct GenerateOrganizationsAccessReportResponseDeserializer;
 impl GenerateOrganizationsAccessReportResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GenerateOrganizationsAccessReportResponse, XmlParseError> {
@@ -2717,6 +5179,37 @@ pub struct GenerateServiceLastAccessedDetailsRequest {
     pub arn: String,
 }
 
+impl GenerateServiceLastAccessedDetailsRequest {
+    /// Returns a builder for `GenerateServiceLastAccessedDetailsRequest`, checking its required members when built.
+    pub fn builder() -> GenerateServiceLastAccessedDetailsRequestBuilder {
+        GenerateServiceLastAccessedDetailsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GenerateServiceLastAccessedDetailsRequest`](struct.GenerateServiceLastAccessedDetailsRequest.html).
+#[derive(Default, Debug)]
+pub struct GenerateServiceLastAccessedDetailsRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GenerateServiceLastAccessedDetailsRequestBuilder {
+    /// Sets the [`arn`](struct.GenerateServiceLastAccessedDetailsRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GenerateServiceLastAccessedDetailsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GenerateServiceLastAccessedDetailsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GenerateServiceLastAccessedDetailsRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 /// Serialize `GenerateServiceLastAccessedDetailsRequest` contents to a `SignedRequest`.
 struct GenerateServiceLastAccessedDetailsRequestSerializer;
 impl GenerateServiceLastAccessedDetailsRequestSerializer {
@@ -2736,10 +5229,10 @@ pub struct GenerateServiceLastAccessedDetailsResponse {
     pub job_id: Option<String>,
 }
 
-struct GenerateServiceLastAccessedDetailsResponseDeserializer;
+pub(crate) struct GenerateServiceLastAccessedDetailsResponseDeserializer;
 impl GenerateServiceLastAccessedDetailsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GenerateServiceLastAccessedDetailsResponse, XmlParseError> {
@@ -2764,6 +5257,38 @@ pub struct GetAccessKeyLastUsedRequest {
     pub access_key_id: String,
 }
 
+impl GetAccessKeyLastUsedRequest {
+    /// Returns a builder for `GetAccessKeyLastUsedRequest`, checking its required members when built.
+    pub fn builder() -> GetAccessKeyLastUsedRequestBuilder {
+        GetAccessKeyLastUsedRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAccessKeyLastUsedRequest`](struct.GetAccessKeyLastUsedRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAccessKeyLastUsedRequestBuilder {
+    access_key_id: Option<String>,
+}
+
+impl GetAccessKeyLastUsedRequestBuilder {
+    /// Sets the [`access_key_id`](struct.GetAccessKeyLastUsedRequest.html#structfield.access_key_id) member.
+    pub fn access_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAccessKeyLastUsedRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAccessKeyLastUsedRequest, ::rusoto_core::BuildError> {
+        Ok(GetAccessKeyLastUsedRequest {
+            access_key_id: self
+                .access_key_id
+                .ok_or(::rusoto_core::BuildError::missing("access_key_id"))?,
+        })
+    }
+}
+
 /// Serialize `GetAccessKeyLastUsedRequest` contents to a `SignedRequest`.
 struct GetAccessKeyLastUsedRequestSerializer;
 impl GetAccessKeyLastUsedRequestSerializer {
@@ -2786,10 +5311,10 @@ pub struct GetAccessKeyLastUsedResponse {
     pub user_name: Option<String>,
 }
 
-struct GetAccessKeyLastUsedResponseDeserializer;
+pub(crate) struct GetAccessKeyLastUsedResponseDeserializer;
 impl GetAccessKeyLastUsedResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetAccessKeyLastUsedResponse, XmlParseError> {
@@ -2818,13 +5343,60 @@ impl GetAccessKeyLastUsedResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetAccountAuthorizationDetailsRequest {
     /// <p>A list of entity types used to filter the results. Only the entities that match the types you specify are included in the output. Use the value <code>LocalManagedPolicy</code> to include customer managed policies.</p> <p>The format for this parameter is a comma-separated (if more than one) list of strings. Each string value in the list must be one of the valid values listed below.</p>
-    pub filter: Option<Vec<String>>,
+    pub filter: Option<Vec<EntityType>>,
     /// <p>Use this parameter only when paginating results and only after you receive a response indicating that the results are truncated. Set it to the value of the <code>Marker</code> element in the response that you received to indicate where the next call should start.</p>
     pub marker: Option<String>,
     /// <p>Use this only when paginating results to indicate the maximum number of items you want in the response. If additional items exist beyond the maximum you specify, the <code>IsTruncated</code> response element is <code>true</code>.</p> <p>If you do not include this parameter, the number of items defaults to 100. Note that IAM might return fewer results, even when there are more results available. In that case, the <code>IsTruncated</code> response element returns <code>true</code>, and <code>Marker</code> contains a value to include in the subsequent call that tells the service where to continue from.</p>
     pub max_items: Option<i64>,
 }
 
+impl GetAccountAuthorizationDetailsRequest {
+    /// Returns a builder for `GetAccountAuthorizationDetailsRequest`, checking its required members when built.
+    pub fn builder() -> GetAccountAuthorizationDetailsRequestBuilder {
+        GetAccountAuthorizationDetailsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAccountAuthorizationDetailsRequest`](struct.GetAccountAuthorizationDetailsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAccountAuthorizationDetailsRequestBuilder {
+    filter: Option<Vec<EntityType>>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl GetAccountAuthorizationDetailsRequestBuilder {
+    /// Sets the [`filter`](struct.GetAccountAuthorizationDetailsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<Vec<EntityType>>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.GetAccountAuthorizationDetailsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.GetAccountAuthorizationDetailsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAccountAuthorizationDetailsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAccountAuthorizationDetailsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetAccountAuthorizationDetailsRequest {
+            filter: self.filter,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// Serialize `GetAccountAuthorizationDetailsRequest` contents to a `SignedRequest`.
 struct GetAccountAuthorizationDetailsRequestSerializer;
 impl GetAccountAuthorizationDetailsRequestSerializer {
@@ -2867,10 +5439,10 @@ pub struct GetAccountAuthorizationDetailsResponse {
     pub user_detail_list: Option<Vec<UserDetail>>,
 }
 
-struct GetAccountAuthorizationDetailsResponseDeserializer;
+pub(crate) struct GetAccountAuthorizationDetailsResponseDeserializer;
 impl GetAccountAuthorizationDetailsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetAccountAuthorizationDetailsResponse, XmlParseError> {
@@ -2924,10 +5496,10 @@ pub struct GetAccountPasswordPolicyResponse {
     pub password_policy: PasswordPolicy,
 }
 
-struct GetAccountPasswordPolicyResponseDeserializer;
+pub(crate) struct GetAccountPasswordPolicyResponseDeserializer;
 impl GetAccountPasswordPolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetAccountPasswordPolicyResponse, XmlParseError> {
@@ -2951,13 +5523,13 @@ impl GetAccountPasswordPolicyResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetAccountSummaryResponse {
     /// <p>A set of key–value pairs containing information about IAM entity usage and IAM quotas.</p>
-    pub summary_map: Option<::std::collections::HashMap<String, i64>>,
+    pub summary_map: Option<::std::collections::HashMap<SummaryKeyType, i64>>,
 }
 
-struct GetAccountSummaryResponseDeserializer;
+pub(crate) struct GetAccountSummaryResponseDeserializer;
 impl GetAccountSummaryResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetAccountSummaryResponse, XmlParseError> {
@@ -2985,6 +5557,39 @@ pub struct GetContextKeysForCustomPolicyRequest {
     pub policy_input_list: Vec<String>,
 }
 
+impl GetContextKeysForCustomPolicyRequest {
+    /// Returns a builder for `GetContextKeysForCustomPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetContextKeysForCustomPolicyRequestBuilder {
+        GetContextKeysForCustomPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetContextKeysForCustomPolicyRequest`](struct.GetContextKeysForCustomPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetContextKeysForCustomPolicyRequestBuilder {
+    policy_input_list: Option<Vec<String>>,
+}
+
+impl GetContextKeysForCustomPolicyRequestBuilder {
+    /// Sets the [`policy_input_list`](struct.GetContextKeysForCustomPolicyRequest.html#structfield.policy_input_list) member.
+    pub fn policy_input_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_input_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetContextKeysForCustomPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetContextKeysForCustomPolicyRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetContextKeysForCustomPolicyRequest {
+            policy_input_list: self
+                .policy_input_list
+                .ok_or(::rusoto_core::BuildError::missing("policy_input_list"))?,
+        })
+    }
+}
+
 /// Serialize `GetContextKeysForCustomPolicyRequest` contents to a `SignedRequest`.
 struct GetContextKeysForCustomPolicyRequestSerializer;
 impl GetContextKeysForCustomPolicyRequestSerializer {
@@ -3009,10 +5614,10 @@ pub struct GetContextKeysForPolicyResponse {
     pub context_key_names: Option<Vec<String>>,
 }
 
-struct GetContextKeysForPolicyResponseDeserializer;
+pub(crate) struct GetContextKeysForPolicyResponseDeserializer;
 impl GetContextKeysForPolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetContextKeysForPolicyResponse, XmlParseError> {
@@ -3044,6 +5649,47 @@ pub struct GetContextKeysForPrincipalPolicyRequest {
     pub policy_source_arn: String,
 }
 
+impl GetContextKeysForPrincipalPolicyRequest {
+    /// Returns a builder for `GetContextKeysForPrincipalPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetContextKeysForPrincipalPolicyRequestBuilder {
+        GetContextKeysForPrincipalPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetContextKeysForPrincipalPolicyRequest`](struct.GetContextKeysForPrincipalPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetContextKeysForPrincipalPolicyRequestBuilder {
+    policy_input_list: Option<Vec<String>>,
+    policy_source_arn: Option<String>,
+}
+
+impl GetContextKeysForPrincipalPolicyRequestBuilder {
+    /// Sets the [`policy_input_list`](struct.GetContextKeysForPrincipalPolicyRequest.html#structfield.policy_input_list) member.
+    pub fn policy_input_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_input_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_source_arn`](struct.GetContextKeysForPrincipalPolicyRequest.html#structfield.policy_source_arn) member.
+    pub fn policy_source_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_source_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetContextKeysForPrincipalPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetContextKeysForPrincipalPolicyRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetContextKeysForPrincipalPolicyRequest {
+            policy_input_list: self.policy_input_list,
+            policy_source_arn: self
+                .policy_source_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_source_arn"))?,
+        })
+    }
+}
+
 /// Serialize `GetContextKeysForPrincipalPolicyRequest` contents to a `SignedRequest`.
 struct GetContextKeysForPrincipalPolicyRequestSerializer;
 impl GetContextKeysForPrincipalPolicyRequestSerializer {
@@ -3073,15 +5719,15 @@ pub struct GetCredentialReportResponse {
     /// <p>Contains the credential report. The report is Base64-encoded.</p>
     pub content: Option<bytes::Bytes>,
     /// <p> The date and time when the credential report was created, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>.</p>
-    pub generated_time: Option<String>,
+    pub generated_time: Option<IsoTimestamp>,
     /// <p>The format (MIME type) of the credential report.</p>
-    pub report_format: Option<String>,
+    pub report_format: Option<ReportFormatType>,
 }
 
-struct GetCredentialReportResponseDeserializer;
+pub(crate) struct GetCredentialReportResponseDeserializer;
 impl GetCredentialReportResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetCredentialReportResponse, XmlParseError> {
@@ -3120,6 +5766,46 @@ pub struct GetGroupPolicyRequest {
     pub policy_name: String,
 }
 
+impl GetGroupPolicyRequest {
+    /// Returns a builder for `GetGroupPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetGroupPolicyRequestBuilder {
+        GetGroupPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGroupPolicyRequest`](struct.GetGroupPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGroupPolicyRequestBuilder {
+    group_name: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl GetGroupPolicyRequestBuilder {
+    /// Sets the [`group_name`](struct.GetGroupPolicyRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.GetGroupPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGroupPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGroupPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetGroupPolicyRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// Serialize `GetGroupPolicyRequest` contents to a `SignedRequest`.
 struct GetGroupPolicyRequestSerializer;
 impl GetGroupPolicyRequestSerializer {
@@ -3145,10 +5831,10 @@ pub struct GetGroupPolicyResponse {
     pub policy_name: String,
 }
 
-struct GetGroupPolicyResponseDeserializer;
+pub(crate) struct GetGroupPolicyResponseDeserializer;
 impl GetGroupPolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetGroupPolicyResponse, XmlParseError> {
@@ -3180,6 +5866,52 @@ pub struct GetGroupRequest {
     pub max_items: Option<i64>,
 }
 
+impl GetGroupRequest {
+    /// Returns a builder for `GetGroupRequest`, checking its required members when built.
+    pub fn builder() -> GetGroupRequestBuilder {
+        GetGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetGroupRequest`](struct.GetGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct GetGroupRequestBuilder {
+    group_name: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl GetGroupRequestBuilder {
+    /// Sets the [`group_name`](struct.GetGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.GetGroupRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.GetGroupRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGroupRequest, ::rusoto_core::BuildError> {
+        Ok(GetGroupRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// Serialize `GetGroupRequest` contents to a `SignedRequest`.
 struct GetGroupRequestSerializer;
 impl GetGroupRequestSerializer {
@@ -3212,10 +5944,10 @@ pub struct GetGroupResponse {
     pub users: Vec<User>,
 }
 
-struct GetGroupResponseDeserializer;
+pub(crate) struct GetGroupResponseDeserializer;
 impl GetGroupResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetGroupResponse, XmlParseError> {
@@ -3249,6 +5981,38 @@ pub struct GetInstanceProfileRequest {
     pub instance_profile_name: String,
 }
 
+impl GetInstanceProfileRequest {
+    /// Returns a builder for `GetInstanceProfileRequest`, checking its required members when built.
+    pub fn builder() -> GetInstanceProfileRequestBuilder {
+        GetInstanceProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInstanceProfileRequest`](struct.GetInstanceProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInstanceProfileRequestBuilder {
+    instance_profile_name: Option<String>,
+}
+
+impl GetInstanceProfileRequestBuilder {
+    /// Sets the [`instance_profile_name`](struct.GetInstanceProfileRequest.html#structfield.instance_profile_name) member.
+    pub fn instance_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_profile_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInstanceProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetInstanceProfileRequest, ::rusoto_core::BuildError> {
+        Ok(GetInstanceProfileRequest {
+            instance_profile_name: self
+                .instance_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_profile_name"))?,
+        })
+    }
+}
+
 /// Serialize `GetInstanceProfileRequest` contents to a `SignedRequest`.
 struct GetInstanceProfileRequestSerializer;
 impl GetInstanceProfileRequestSerializer {
@@ -3272,10 +6036,10 @@ pub struct GetInstanceProfileResponse {
     pub instance_profile: InstanceProfile,
 }
 
-struct GetInstanceProfileResponseDeserializer;
+pub(crate) struct GetInstanceProfileResponseDeserializer;
 impl GetInstanceProfileResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetInstanceProfileResponse, XmlParseError> {
@@ -3301,6 +6065,36 @@ pub struct GetLoginProfileRequest {
     pub user_name: String,
 }
 
+impl GetLoginProfileRequest {
+    /// Returns a builder for `GetLoginProfileRequest`, checking its required members when built.
+    pub fn builder() -> GetLoginProfileRequestBuilder {
+        GetLoginProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLoginProfileRequest`](struct.GetLoginProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLoginProfileRequestBuilder {
+    user_name: Option<String>,
+}
+
+impl GetLoginProfileRequestBuilder {
+    /// Sets the [`user_name`](struct.GetLoginProfileRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLoginProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetLoginProfileRequest, ::rusoto_core::BuildError> {
+        Ok(GetLoginProfileRequest {
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `GetLoginProfileRequest` contents to a `SignedRequest`.
 struct GetLoginProfileRequestSerializer;
 impl GetLoginProfileRequestSerializer {
@@ -3321,10 +6115,10 @@ pub struct GetLoginProfileResponse {
     pub login_profile: LoginProfile,
 }
 
-struct GetLoginProfileResponseDeserializer;
+pub(crate) struct GetLoginProfileResponseDeserializer;
 impl GetLoginProfileResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetLoginProfileResponse, XmlParseError> {
@@ -3350,6 +6144,38 @@ pub struct GetOpenIDConnectProviderRequest {
     pub open_id_connect_provider_arn: String,
 }
 
+impl GetOpenIDConnectProviderRequest {
+    /// Returns a builder for `GetOpenIDConnectProviderRequest`, checking its required members when built.
+    pub fn builder() -> GetOpenIDConnectProviderRequestBuilder {
+        GetOpenIDConnectProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetOpenIDConnectProviderRequest`](struct.GetOpenIDConnectProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct GetOpenIDConnectProviderRequestBuilder {
+    open_id_connect_provider_arn: Option<String>,
+}
+
+impl GetOpenIDConnectProviderRequestBuilder {
+    /// Sets the [`open_id_connect_provider_arn`](struct.GetOpenIDConnectProviderRequest.html#structfield.open_id_connect_provider_arn) member.
+    pub fn open_id_connect_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.open_id_connect_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOpenIDConnectProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetOpenIDConnectProviderRequest, ::rusoto_core::BuildError> {
+        Ok(GetOpenIDConnectProviderRequest {
+            open_id_connect_provider_arn: self.open_id_connect_provider_arn.ok_or(
+                ::rusoto_core::BuildError::missing("open_id_connect_provider_arn"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `GetOpenIDConnectProviderRequest` contents to a `SignedRequest`.
 struct GetOpenIDConnectProviderRequestSerializer;
 impl GetOpenIDConnectProviderRequestSerializer {
@@ -3372,17 +6198,17 @@ pub struct GetOpenIDConnectProviderResponse {
     /// <p>A list of client IDs (also known as audiences) that are associated with the specified IAM OIDC provider resource object. For more information, see <a>CreateOpenIDConnectProvider</a>.</p>
     pub client_id_list: Option<Vec<String>>,
     /// <p>The date and time when the IAM OIDC provider resource object was created in the AWS account.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<IsoTimestamp>,
     /// <p>A list of certificate thumbprints that are associated with the specified IAM OIDC provider resource object. For more information, see <a>CreateOpenIDConnectProvider</a>. </p>
     pub thumbprint_list: Option<Vec<String>>,
     /// <p>The URL that the IAM OIDC provider resource object is associated with. For more information, see <a>CreateOpenIDConnectProvider</a>.</p>
     pub url: Option<String>,
 }
 
-struct GetOpenIDConnectProviderResponseDeserializer;
+pub(crate) struct GetOpenIDConnectProviderResponseDeserializer;
 impl GetOpenIDConnectProviderResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetOpenIDConnectProviderResponse, XmlParseError> {
@@ -3426,7 +6252,63 @@ pub struct GetOrganizationsAccessReportRequest {
     /// <p>Use this only when paginating results to indicate the maximum number of items you want in the response. If additional items exist beyond the maximum you specify, the <code>IsTruncated</code> response element is <code>true</code>.</p> <p>If you do not include this parameter, the number of items defaults to 100. Note that IAM might return fewer results, even when there are more results available. In that case, the <code>IsTruncated</code> response element returns <code>true</code>, and <code>Marker</code> contains a value to include in the subsequent call that tells the service where to continue from.</p>
     pub max_items: Option<i64>,
     /// <p>The key that is used to sort the results. If you choose the namespace key, the results are returned in alphabetical order. If you choose the time key, the results are sorted numerically by the date and time.</p>
-    pub sort_key: Option<String>,
+    pub sort_key: Option<SortKeyType>,
+}
+
+impl GetOrganizationsAccessReportRequest {
+    /// Returns a builder for `GetOrganizationsAccessReportRequest`, checking its required members when built.
+    pub fn builder() -> GetOrganizationsAccessReportRequestBuilder {
+        GetOrganizationsAccessReportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetOrganizationsAccessReportRequest`](struct.GetOrganizationsAccessReportRequest.html).
+#[derive(Default, Debug)]
+pub struct GetOrganizationsAccessReportRequestBuilder {
+    job_id: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+    sort_key: Option<SortKeyType>,
+}
+
+impl GetOrganizationsAccessReportRequestBuilder {
+    /// Sets the [`job_id`](struct.GetOrganizationsAccessReportRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.GetOrganizationsAccessReportRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.GetOrganizationsAccessReportRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_key`](struct.GetOrganizationsAccessReportRequest.html#structfield.sort_key) member.
+    pub fn sort_key<T: Into<SortKeyType>>(mut self, value: T) -> Self {
+        self.sort_key = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOrganizationsAccessReportRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetOrganizationsAccessReportRequest, ::rusoto_core::BuildError> {
+        Ok(GetOrganizationsAccessReportRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            marker: self.marker,
+            max_items: self.max_items,
+            sort_key: self.sort_key,
+        })
+    }
 }
 
 /// Serialize `GetOrganizationsAccessReportRequest` contents to a `SignedRequest`.
@@ -3451,7 +6333,7 @@ impl GetOrganizationsAccessReportRequestSerializer {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetOrganizationsAccessReportResponse {
     /// <p>An object that contains details about the most recent attempt to access the service.</p>
     pub access_details: Option<Vec<AccessDetail>>,
@@ -3459,11 +6341,11 @@ pub struct GetOrganizationsAccessReportResponse {
     /// <p>A flag that indicates whether there are more items to return. If your results were truncated, you can make a subsequent pagination request using the <code>Marker</code> request parameter to retrieve more items. Note that IAM might return fewer than the <code>MaxItems</code> number of results even when there are more results available. We recommend that you check <code>IsTruncated</code> after every call to ensure that you receive all your results.</p>
     pub is_truncated: Option<bool>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the generated report job was completed or failed.</p> <p>This field is null if the job is still in progress, as indicated by a job status value of <code>IN_PROGRESS</code>.</p>
-    pub job_completion_date: Option<String>,
+    pub job_completion_date: Option<IsoTimestamp>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the report job was created.</p>
-    pub job_creation_date: String,
+    pub job_creation_date: IsoTimestamp,
     /// <p>The status of the job.</p>
-    pub job_status: String,
+    pub job_status: JobStatusType,
     /// <p>When <code>IsTruncated</code> is <code>true</code>, this element is present and contains the value to use for the <code>Marker</code> parameter in a subsequent pagination request.</p>
     pub marker: Option<String>,
     /// <p>The number of services that the applicable SCPs allow account principals to access.</p>
@@ -3472,16 +6354,27 @@ pub struct GetOrganizationsAccessReportResponse {
     pub number_of_services_not_accessed: Option<i64>,
 }
 
-struct GetOrganizationsAccessReportResponseDeserializer;
+pub(crate) struct GetOrganizationsAccessReportResponseDeserializer;
 impl GetOrganizationsAccessReportResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetOrganizationsAccessReportResponse, XmlParseError> {
-        deserialize_elements::<_, GetOrganizationsAccessReportResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            GetOrganizationsAccessReportResponse {
+                access_details: Default::default(),
+                error_details: Default::default(),
+                is_truncated: Default::default(),
+                job_completion_date: Default::default(),
+                job_creation_date: Default::default(),
+                job_status: JobStatusType::Unknown(String::new()),
+                marker: Default::default(),
+                number_of_services_accessible: Default::default(),
+                number_of_services_not_accessed: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "AccessDetails" => {
@@ -3543,6 +6436,36 @@ pub struct GetPolicyRequest {
     pub policy_arn: String,
 }
 
+impl GetPolicyRequest {
+    /// Returns a builder for `GetPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetPolicyRequestBuilder {
+        GetPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPolicyRequest`](struct.GetPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPolicyRequestBuilder {
+    policy_arn: Option<String>,
+}
+
+impl GetPolicyRequestBuilder {
+    /// Sets the [`policy_arn`](struct.GetPolicyRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetPolicyRequest {
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+        })
+    }
+}
+
 /// Serialize `GetPolicyRequest` contents to a `SignedRequest`.
 struct GetPolicyRequestSerializer;
 impl GetPolicyRequestSerializer {
@@ -3563,10 +6486,10 @@ pub struct GetPolicyResponse {
     pub policy: Option<Policy>,
 }
 
-struct GetPolicyResponseDeserializer;
+pub(crate) struct GetPolicyResponseDeserializer;
 impl GetPolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetPolicyResponse, XmlParseError> {
@@ -3589,6 +6512,48 @@ pub struct GetPolicyVersionRequest {
     pub version_id: String,
 }
 
+impl GetPolicyVersionRequest {
+    /// Returns a builder for `GetPolicyVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetPolicyVersionRequestBuilder {
+        GetPolicyVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPolicyVersionRequest`](struct.GetPolicyVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPolicyVersionRequestBuilder {
+    policy_arn: Option<String>,
+    version_id: Option<String>,
+}
+
+impl GetPolicyVersionRequestBuilder {
+    /// Sets the [`policy_arn`](struct.GetPolicyVersionRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.GetPolicyVersionRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPolicyVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetPolicyVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetPolicyVersionRequest {
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+            version_id: self
+                .version_id
+                .ok_or(::rusoto_core::BuildError::missing("version_id"))?,
+        })
+    }
+}
+
 /// Serialize `GetPolicyVersionRequest` contents to a `SignedRequest`.
 struct GetPolicyVersionRequestSerializer;
 impl GetPolicyVersionRequestSerializer {
@@ -3610,10 +6575,10 @@ pub struct GetPolicyVersionResponse {
     pub policy_version: Option<PolicyVersion>,
 }
 
-struct GetPolicyVersionResponseDeserializer;
+pub(crate) struct GetPolicyVersionResponseDeserializer;
 impl GetPolicyVersionResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetPolicyVersionResponse, XmlParseError> {
@@ -3643,6 +6608,46 @@ pub struct GetRolePolicyRequest {
     pub role_name: String,
 }
 
+impl GetRolePolicyRequest {
+    /// Returns a builder for `GetRolePolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetRolePolicyRequestBuilder {
+        GetRolePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRolePolicyRequest`](struct.GetRolePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRolePolicyRequestBuilder {
+    policy_name: Option<String>,
+    role_name: Option<String>,
+}
+
+impl GetRolePolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.GetRolePolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.GetRolePolicyRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRolePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRolePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetRolePolicyRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `GetRolePolicyRequest` contents to a `SignedRequest`.
 struct GetRolePolicyRequestSerializer;
 impl GetRolePolicyRequestSerializer {
@@ -3668,10 +6673,10 @@ pub struct GetRolePolicyResponse {
     pub role_name: String,
 }
 
-struct GetRolePolicyResponseDeserializer;
+pub(crate) struct GetRolePolicyResponseDeserializer;
 impl GetRolePolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetRolePolicyResponse, XmlParseError> {
@@ -3699,6 +6704,36 @@ pub struct GetRoleRequest {
     pub role_name: String,
 }
 
+impl GetRoleRequest {
+    /// Returns a builder for `GetRoleRequest`, checking its required members when built.
+    pub fn builder() -> GetRoleRequestBuilder {
+        GetRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRoleRequest`](struct.GetRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRoleRequestBuilder {
+    role_name: Option<String>,
+}
+
+impl GetRoleRequestBuilder {
+    /// Sets the [`role_name`](struct.GetRoleRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRoleRequest, ::rusoto_core::BuildError> {
+        Ok(GetRoleRequest {
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `GetRoleRequest` contents to a `SignedRequest`.
 struct GetRoleRequestSerializer;
 impl GetRoleRequestSerializer {
@@ -3719,10 +6754,10 @@ pub struct GetRoleResponse {
     pub role: Role,
 }
 
-struct GetRoleResponseDeserializer;
+pub(crate) struct GetRoleResponseDeserializer;
 impl GetRoleResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetRoleResponse, XmlParseError> {
@@ -3743,6 +6778,36 @@ pub struct GetSAMLProviderRequest {
     pub saml_provider_arn: String,
 }
 
+impl GetSAMLProviderRequest {
+    /// Returns a builder for `GetSAMLProviderRequest`, checking its required members when built.
+    pub fn builder() -> GetSAMLProviderRequestBuilder {
+        GetSAMLProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSAMLProviderRequest`](struct.GetSAMLProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSAMLProviderRequestBuilder {
+    saml_provider_arn: Option<String>,
+}
+
+impl GetSAMLProviderRequestBuilder {
+    /// Sets the [`saml_provider_arn`](struct.GetSAMLProviderRequest.html#structfield.saml_provider_arn) member.
+    pub fn saml_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.saml_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSAMLProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSAMLProviderRequest, ::rusoto_core::BuildError> {
+        Ok(GetSAMLProviderRequest {
+            saml_provider_arn: self
+                .saml_provider_arn
+                .ok_or(::rusoto_core::BuildError::missing("saml_provider_arn"))?,
+        })
+    }
+}
+
 /// Serialize `GetSAMLProviderRequest` contents to a `SignedRequest`.
 struct GetSAMLProviderRequestSerializer;
 impl GetSAMLProviderRequestSerializer {
@@ -3763,17 +6828,17 @@ impl GetSAMLProviderRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetSAMLProviderResponse {
     /// <p>The date and time when the SAML provider was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<IsoTimestamp>,
     /// <p>The XML metadata document that includes information about an identity provider.</p>
     pub saml_metadata_document: Option<String>,
     /// <p>The expiration date and time for the SAML provider.</p>
-    pub valid_until: Option<String>,
+    pub valid_until: Option<IsoTimestamp>,
 }
 
-struct GetSAMLProviderResponseDeserializer;
+pub(crate) struct GetSAMLProviderResponseDeserializer;
 impl GetSAMLProviderResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetSAMLProviderResponse, XmlParseError> {
@@ -3804,16 +6869,66 @@ impl GetSAMLProviderResponseDeserializer {
         )
     }
 }
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetSSHPublicKeyRequest {
     /// <p>Specifies the public key encoding format to use in the response. To retrieve the public key in ssh-rsa format, use <code>SSH</code>. To retrieve the public key in PEM format, use <code>PEM</code>.</p>
-    pub encoding: String,
+    pub encoding: EncodingType,
     /// <p>The unique identifier for the SSH public key.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
     pub ssh_public_key_id: String,
     /// <p>The name of the IAM user associated with the SSH public key.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
     pub user_name: String,
 }
 
+impl GetSSHPublicKeyRequest {
+    /// Returns a builder for `GetSSHPublicKeyRequest`, checking its required members when built.
+    pub fn builder() -> GetSSHPublicKeyRequestBuilder {
+        GetSSHPublicKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSSHPublicKeyRequest`](struct.GetSSHPublicKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSSHPublicKeyRequestBuilder {
+    encoding: Option<EncodingType>,
+    ssh_public_key_id: Option<String>,
+    user_name: Option<String>,
+}
+
+impl GetSSHPublicKeyRequestBuilder {
+    /// Sets the [`encoding`](struct.GetSSHPublicKeyRequest.html#structfield.encoding) member.
+    pub fn encoding<T: Into<EncodingType>>(mut self, value: T) -> Self {
+        self.encoding = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_public_key_id`](struct.GetSSHPublicKeyRequest.html#structfield.ssh_public_key_id) member.
+    pub fn ssh_public_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.GetSSHPublicKeyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSSHPublicKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSSHPublicKeyRequest, ::rusoto_core::BuildError> {
+        Ok(GetSSHPublicKeyRequest {
+            encoding: self
+                .encoding
+                .ok_or(::rusoto_core::BuildError::missing("encoding"))?,
+            ssh_public_key_id: self
+                .ssh_public_key_id
+                .ok_or(::rusoto_core::BuildError::missing("ssh_public_key_id"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `GetSSHPublicKeyRequest` contents to a `SignedRequest`.
 struct GetSSHPublicKeyRequestSerializer;
 impl GetSSHPublicKeyRequestSerializer {
@@ -3839,10 +6954,10 @@ pub struct GetSSHPublicKeyResponse {
     pub ssh_public_key: Option<SSHPublicKey>,
 }
 
-struct GetSSHPublicKeyResponseDeserializer;
+pub(crate) struct GetSSHPublicKeyResponseDeserializer;
 impl GetSSHPublicKeyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetSSHPublicKeyResponse, XmlParseError> {
@@ -3870,6 +6985,38 @@ pub struct GetServerCertificateRequest {
     pub server_certificate_name: String,
 }
 
+impl GetServerCertificateRequest {
+    /// Returns a builder for `GetServerCertificateRequest`, checking its required members when built.
+    pub fn builder() -> GetServerCertificateRequestBuilder {
+        GetServerCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetServerCertificateRequest`](struct.GetServerCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetServerCertificateRequestBuilder {
+    server_certificate_name: Option<String>,
+}
+
+impl GetServerCertificateRequestBuilder {
+    /// Sets the [`server_certificate_name`](struct.GetServerCertificateRequest.html#structfield.server_certificate_name) member.
+    pub fn server_certificate_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_certificate_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetServerCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetServerCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(GetServerCertificateRequest {
+            server_certificate_name: self.server_certificate_name.ok_or(
+                ::rusoto_core::BuildError::missing("server_certificate_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `GetServerCertificateRequest` contents to a `SignedRequest`.
 struct GetServerCertificateRequestSerializer;
 impl GetServerCertificateRequestSerializer {
@@ -3893,10 +7040,10 @@ pub struct GetServerCertificateResponse {
     pub server_certificate: ServerCertificate,
 }
 
-struct GetServerCertificateResponseDeserializer;
+pub(crate) struct GetServerCertificateResponseDeserializer;
 impl GetServerCertificateResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetServerCertificateResponse, XmlParseError> {
@@ -3926,6 +7073,55 @@ pub struct GetServiceLastAccessedDetailsRequest {
     pub max_items: Option<i64>,
 }
 
+impl GetServiceLastAccessedDetailsRequest {
+    /// Returns a builder for `GetServiceLastAccessedDetailsRequest`, checking its required members when built.
+    pub fn builder() -> GetServiceLastAccessedDetailsRequestBuilder {
+        GetServiceLastAccessedDetailsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetServiceLastAccessedDetailsRequest`](struct.GetServiceLastAccessedDetailsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetServiceLastAccessedDetailsRequestBuilder {
+    job_id: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl GetServiceLastAccessedDetailsRequestBuilder {
+    /// Sets the [`job_id`](struct.GetServiceLastAccessedDetailsRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.GetServiceLastAccessedDetailsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.GetServiceLastAccessedDetailsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetServiceLastAccessedDetailsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetServiceLastAccessedDetailsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetServiceLastAccessedDetailsRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// Serialize `GetServiceLastAccessedDetailsRequest` contents to a `SignedRequest`.
 struct GetServiceLastAccessedDetailsRequestSerializer;
 impl GetServiceLastAccessedDetailsRequestSerializer {
@@ -3945,34 +7141,43 @@ impl GetServiceLastAccessedDetailsRequestSerializer {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetServiceLastAccessedDetailsResponse {
     /// <p>An object that contains details about the reason the operation failed.</p>
     pub error: Option<ErrorDetails>,
     /// <p><p/> <p>A flag that indicates whether there are more items to return. If your results were truncated, you can make a subsequent pagination request using the <code>Marker</code> request parameter to retrieve more items. Note that IAM might return fewer than the <code>MaxItems</code> number of results even when there are more results available. We recommend that you check <code>IsTruncated</code> after every call to ensure that you receive all your results.</p></p>
     pub is_truncated: Option<bool>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the generated report job was completed or failed.</p> <p>This field is null if the job is still in progress, as indicated by a job status value of <code>IN_PROGRESS</code>.</p>
-    pub job_completion_date: String,
+    pub job_completion_date: IsoTimestamp,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the report job was created.</p>
-    pub job_creation_date: String,
+    pub job_creation_date: IsoTimestamp,
     /// <p>The status of the job.</p>
-    pub job_status: String,
+    pub job_status: JobStatusType,
     /// <p>When <code>IsTruncated</code> is <code>true</code>, this element is present and contains the value to use for the <code>Marker</code> parameter in a subsequent pagination request.</p>
     pub marker: Option<String>,
     /// <p> A <code>ServiceLastAccessed</code> object that contains details about the most recent attempt to access the service.</p>
     pub services_last_accessed: Vec<ServiceLastAccessed>,
 }
 
-struct GetServiceLastAccessedDetailsResponseDeserializer;
+pub(crate) struct GetServiceLastAccessedDetailsResponseDeserializer;
 impl GetServiceLastAccessedDetailsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetServiceLastAccessedDetailsResponse, XmlParseError> {
-        deserialize_elements::<_, GetServiceLastAccessedDetailsResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            GetServiceLastAccessedDetailsResponse {
+                error: Default::default(),
+                is_truncated: Default::default(),
+                job_completion_date: Default::default(),
+                job_creation_date: Default::default(),
+                job_status: JobStatusType::Unknown(String::new()),
+                marker: Default::default(),
+                services_last_accessed: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "Error" => {
@@ -4026,6 +7231,67 @@ pub struct GetServiceLastAccessedDetailsWithEntitiesRequest {
     pub service_namespace: String,
 }
 
+impl GetServiceLastAccessedDetailsWithEntitiesRequest {
+    /// Returns a builder for `GetServiceLastAccessedDetailsWithEntitiesRequest`, checking its required members when built.
+    pub fn builder() -> GetServiceLastAccessedDetailsWithEntitiesRequestBuilder {
+        GetServiceLastAccessedDetailsWithEntitiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetServiceLastAccessedDetailsWithEntitiesRequest`](struct.GetServiceLastAccessedDetailsWithEntitiesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetServiceLastAccessedDetailsWithEntitiesRequestBuilder {
+    job_id: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+    service_namespace: Option<String>,
+}
+
+impl GetServiceLastAccessedDetailsWithEntitiesRequestBuilder {
+    /// Sets the [`job_id`](struct.GetServiceLastAccessedDetailsWithEntitiesRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.GetServiceLastAccessedDetailsWithEntitiesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.GetServiceLastAccessedDetailsWithEntitiesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespace`](struct.GetServiceLastAccessedDetailsWithEntitiesRequest.html#structfield.service_namespace) member.
+    pub fn service_namespace<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_namespace = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetServiceLastAccessedDetailsWithEntitiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        GetServiceLastAccessedDetailsWithEntitiesRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(GetServiceLastAccessedDetailsWithEntitiesRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            marker: self.marker,
+            max_items: self.max_items,
+            service_namespace: self
+                .service_namespace
+                .ok_or(::rusoto_core::BuildError::missing("service_namespace"))?,
+        })
+    }
+}
+
 /// Serialize `GetServiceLastAccessedDetailsWithEntitiesRequest` contents to a `SignedRequest`.
 struct GetServiceLastAccessedDetailsWithEntitiesRequestSerializer;
 impl GetServiceLastAccessedDetailsWithEntitiesRequestSerializer {
@@ -4053,7 +7319,7 @@ impl GetServiceLastAccessedDetailsWithEntitiesRequestSerializer {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetServiceLastAccessedDetailsWithEntitiesResponse {
     /// <p>An <code>EntityDetailsList</code> object that contains details about when an IAM entity (user or role) used group or policy permissions in an attempt to access the specified AWS service.</p>
     pub entity_details_list: Vec<EntityDetails>,
@@ -4062,25 +7328,34 @@ pub struct GetServiceLastAccessedDetailsWithEntitiesResponse {
     /// <p>A flag that indicates whether there are more items to return. If your results were truncated, you can make a subsequent pagination request using the <code>Marker</code> request parameter to retrieve more items. Note that IAM might return fewer than the <code>MaxItems</code> number of results even when there are more results available. We recommend that you check <code>IsTruncated</code> after every call to ensure that you receive all your results.</p>
     pub is_truncated: Option<bool>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the generated report job was completed or failed.</p> <p>This field is null if the job is still in progress, as indicated by a job status value of <code>IN_PROGRESS</code>.</p>
-    pub job_completion_date: String,
+    pub job_completion_date: IsoTimestamp,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the report job was created.</p>
-    pub job_creation_date: String,
+    pub job_creation_date: IsoTimestamp,
     /// <p>The status of the job.</p>
-    pub job_status: String,
+    pub job_status: JobStatusType,
     /// <p>When <code>IsTruncated</code> is <code>true</code>, this element is present and contains the value to use for the <code>Marker</code> parameter in a subsequent pagination request.</p>
     pub marker: Option<String>,
 }
 
-struct GetServiceLastAccessedDetailsWithEntitiesResponseDeserializer;
+pub(crate) struct GetServiceLastAccessedDetailsWithEntitiesResponseDeserializer;
 impl GetServiceLastAccessedDetailsWithEntitiesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetServiceLastAccessedDetailsWithEntitiesResponse, XmlParseError> {
-        deserialize_elements::<_, GetServiceLastAccessedDetailsWithEntitiesResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            GetServiceLastAccessedDetailsWithEntitiesResponse {
+                entity_details_list: Default::default(),
+                error: Default::default(),
+                is_truncated: Default::default(),
+                job_completion_date: Default::default(),
+                job_creation_date: Default::default(),
+                job_status: JobStatusType::Unknown(String::new()),
+                marker: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "EntityDetailsList" => {
@@ -4128,6 +7403,39 @@ pub struct GetServiceLinkedRoleDeletionStatusRequest {
     pub deletion_task_id: String,
 }
 
+impl GetServiceLinkedRoleDeletionStatusRequest {
+    /// Returns a builder for `GetServiceLinkedRoleDeletionStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetServiceLinkedRoleDeletionStatusRequestBuilder {
+        GetServiceLinkedRoleDeletionStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetServiceLinkedRoleDeletionStatusRequest`](struct.GetServiceLinkedRoleDeletionStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetServiceLinkedRoleDeletionStatusRequestBuilder {
+    deletion_task_id: Option<String>,
+}
+
+impl GetServiceLinkedRoleDeletionStatusRequestBuilder {
+    /// Sets the [`deletion_task_id`](struct.GetServiceLinkedRoleDeletionStatusRequest.html#structfield.deletion_task_id) member.
+    pub fn deletion_task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deletion_task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetServiceLinkedRoleDeletionStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetServiceLinkedRoleDeletionStatusRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetServiceLinkedRoleDeletionStatusRequest {
+            deletion_task_id: self
+                .deletion_task_id
+                .ok_or(::rusoto_core::BuildError::missing("deletion_task_id"))?,
+        })
+    }
+}
+
 /// Serialize `GetServiceLinkedRoleDeletionStatusRequest` contents to a `SignedRequest`.
 struct GetServiceLinkedRoleDeletionStatusRequestSerializer;
 impl GetServiceLinkedRoleDeletionStatusRequestSerializer {
@@ -4144,24 +7452,28 @@ impl GetServiceLinkedRoleDeletionStatusRequestSerializer {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GetServiceLinkedRoleDeletionStatusResponse {
     /// <p>An object that contains details about the reason the deletion failed.</p>
     pub reason: Option<DeletionTaskFailureReasonType>,
     /// <p>The status of the deletion.</p>
-    pub status: String,
+    pub status: DeletionTaskStatusType,
 }
 
-struct GetServiceLinkedRoleDeletionStatusResponseDeserializer;
+pub(crate) struct GetServiceLinkedRoleDeletionStatusResponseDeserializer;
 impl GetServiceLinkedRoleDeletionStatusResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetServiceLinkedRoleDeletionStatusResponse, XmlParseError> {
-        deserialize_elements::<_, GetServiceLinkedRoleDeletionStatusResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            GetServiceLinkedRoleDeletionStatusResponse {
+                reason: Default::default(),
+                status: DeletionTaskStatusType::Unknown(String::new()),
+            },
             |name, stack, obj| {
                 match name {
                     "Reason" => {
@@ -4188,6 +7500,46 @@ pub struct GetUserPolicyRequest {
     pub user_name: String,
 }
 
+impl GetUserPolicyRequest {
+    /// Returns a builder for `GetUserPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetUserPolicyRequestBuilder {
+        GetUserPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUserPolicyRequest`](struct.GetUserPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUserPolicyRequestBuilder {
+    policy_name: Option<String>,
+    user_name: Option<String>,
+}
+
+impl GetUserPolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.GetUserPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.GetUserPolicyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUserPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetUserPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetUserPolicyRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `GetUserPolicyRequest` contents to a `SignedRequest`.
 struct GetUserPolicyRequestSerializer;
 impl GetUserPolicyRequestSerializer {
@@ -4213,10 +7565,10 @@ pub struct GetUserPolicyResponse {
     pub user_name: String,
 }
 
-struct GetUserPolicyResponseDeserializer;
+pub(crate) struct GetUserPolicyResponseDeserializer;
 impl GetUserPolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetUserPolicyResponse, XmlParseError> {
@@ -4245,6 +7597,34 @@ pub struct GetUserRequest {
     pub user_name: Option<String>,
 }
 
+impl GetUserRequest {
+    /// Returns a builder for `GetUserRequest`, checking its required members when built.
+    pub fn builder() -> GetUserRequestBuilder {
+        GetUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUserRequest`](struct.GetUserRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUserRequestBuilder {
+    user_name: Option<String>,
+}
+
+impl GetUserRequestBuilder {
+    /// Sets the [`user_name`](struct.GetUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetUserRequest, ::rusoto_core::BuildError> {
+        Ok(GetUserRequest {
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `GetUserRequest` contents to a `SignedRequest`.
 struct GetUserRequestSerializer;
 impl GetUserRequestSerializer {
@@ -4267,10 +7647,10 @@ pub struct GetUserResponse {
     pub user: User,
 }
 
-struct GetUserResponseDeserializer;
+pub(crate) struct GetUserResponseDeserializer;
 impl GetUserResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetUserResponse, XmlParseError> {
@@ -4285,13 +7665,72 @@ impl GetUserResponseDeserializer {
         })
     }
 }
+/// <p>The version of the global endpoint token. Version 1 tokens are valid only in AWS Regions that are available by default. These tokens do not work in manually enabled Regions, such as Asia Pacific (Hong Kong). Version 2 tokens are valid in all Regions. However, version 2 tokens are longer and might affect systems where you temporarily store tokens.</p> <p>For information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating STS in an AWS Region</a> in the <i>IAM User Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GlobalEndpointTokenVersion {
+    /// <p><code>v1Token</code></p>
+    V1Token,
+    /// <p><code>v2Token</code></p>
+    V2Token,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl GlobalEndpointTokenVersion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GlobalEndpointTokenVersion::V1Token => "v1Token",
+            GlobalEndpointTokenVersion::V2Token => "v2Token",
+            GlobalEndpointTokenVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GlobalEndpointTokenVersion {
+    fn from(value: &'a str) -> GlobalEndpointTokenVersion {
+        match value {
+            "v1Token" => GlobalEndpointTokenVersion::V1Token,
+            "v2Token" => GlobalEndpointTokenVersion::V2Token,
+            _ => GlobalEndpointTokenVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GlobalEndpointTokenVersion {
+    fn from(value: String) -> GlobalEndpointTokenVersion {
+        GlobalEndpointTokenVersion::from(value.as_str())
+    }
+}
+
+impl From<GlobalEndpointTokenVersion> for String {
+    fn from(value: GlobalEndpointTokenVersion) -> String {
+        match value {
+            GlobalEndpointTokenVersion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GlobalEndpointTokenVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GlobalEndpointTokenVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p><p>Contains information about an IAM group entity.</p> <p>This data type is used as a response element in the following operations:</p> <ul> <li> <p> <a>CreateGroup</a> </p> </li> <li> <p> <a>GetGroup</a> </p> </li> <li> <p> <a>ListGroups</a> </p> </li> </ul></p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Group {
     /// <p> The Amazon Resource Name (ARN) specifying the group. For more information about ARNs and how to use them in policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide. </p>
     pub arn: String,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the group was created.</p>
-    pub create_date: String,
+    pub create_date: IsoTimestamp,
     /// <p> The stable and unique string identifying the group. For more information about IDs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide. </p>
     pub group_id: String,
     /// <p>The friendly name that identifies the group.</p>
@@ -4300,10 +7739,13 @@ pub struct Group {
     pub path: String,
 }
 
-struct GroupDeserializer;
+pub(crate) struct GroupDeserializer;
 impl GroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Group, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Group, XmlParseError> {
         deserialize_elements::<_, Group, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Arn" => {
@@ -4334,7 +7776,7 @@ pub struct GroupDetail {
     /// <p>A list of the managed policies attached to the group.</p>
     pub attached_managed_policies: Option<Vec<AttachedPolicy>>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the group was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<IsoTimestamp>,
     /// <p>The stable and unique string identifying the group. For more information about IDs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide.</p>
     pub group_id: Option<String>,
     /// <p>The friendly name that identifies the group.</p>
@@ -4345,10 +7787,10 @@ pub struct GroupDetail {
     pub path: Option<String>,
 }
 
-struct GroupDetailDeserializer;
+pub(crate) struct GroupDetailDeserializer;
 impl GroupDetailDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GroupDetail, XmlParseError> {
@@ -4389,10 +7831,10 @@ impl GroupDetailDeserializer {
         })
     }
 }
-struct GroupDetailListTypeDeserializer;
+pub(crate) struct GroupDetailListTypeDeserializer;
 impl GroupDetailListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<GroupDetail>, XmlParseError> {
@@ -4406,10 +7848,10 @@ impl GroupDetailListTypeDeserializer {
         })
     }
 }
-struct GroupListTypeDeserializer;
+pub(crate) struct GroupListTypeDeserializer;
 impl GroupListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Group>, XmlParseError> {
@@ -4423,10 +7865,10 @@ impl GroupListTypeDeserializer {
         })
     }
 }
-struct GroupNameListTypeDeserializer;
+pub(crate) struct GroupNameListTypeDeserializer;
 impl GroupNameListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -4440,10 +7882,13 @@ impl GroupNameListTypeDeserializer {
         })
     }
 }
-struct GroupNameTypeDeserializer;
+pub(crate) struct GroupNameTypeDeserializer;
 impl GroupNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4451,10 +7896,13 @@ impl GroupNameTypeDeserializer {
         Ok(obj)
     }
 }
-struct IdTypeDeserializer;
+pub(crate) struct IdTypeDeserializer;
 impl IdTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4468,7 +7916,7 @@ pub struct InstanceProfile {
     /// <p> The Amazon Resource Name (ARN) specifying the instance profile. For more information about ARNs and how to use them in policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide. </p>
     pub arn: String,
     /// <p>The date when the instance profile was created.</p>
-    pub create_date: String,
+    pub create_date: IsoTimestamp,
     /// <p> The stable and unique string identifying the instance profile. For more information about IDs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide. </p>
     pub instance_profile_id: String,
     /// <p>The name identifying the instance profile.</p>
@@ -4479,10 +7927,10 @@ pub struct InstanceProfile {
     pub roles: Vec<Role>,
 }
 
-struct InstanceProfileDeserializer;
+pub(crate) struct InstanceProfileDeserializer;
 impl InstanceProfileDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<InstanceProfile, XmlParseError> {
@@ -4517,10 +7965,10 @@ impl InstanceProfileDeserializer {
         })
     }
 }
-struct InstanceProfileListTypeDeserializer;
+pub(crate) struct InstanceProfileListTypeDeserializer;
 impl InstanceProfileListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<InstanceProfile>, XmlParseError> {
@@ -4534,10 +7982,13 @@ impl InstanceProfileListTypeDeserializer {
         })
     }
 }
-struct InstanceProfileNameTypeDeserializer;
+pub(crate) struct InstanceProfileNameTypeDeserializer;
 impl InstanceProfileNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4545,10 +7996,13 @@ impl InstanceProfileNameTypeDeserializer {
         Ok(obj)
     }
 }
-struct IntegerTypeDeserializer;
+pub(crate) struct IntegerTypeDeserializer;
 impl IntegerTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4556,10 +8010,13 @@ impl IntegerTypeDeserializer {
         Ok(obj)
     }
 }
-struct JobIDTypeDeserializer;
+pub(crate) struct JobIDTypeDeserializer;
 impl JobIDTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -4567,21 +8024,90 @@ impl JobIDTypeDeserializer {
         Ok(obj)
     }
 }
-struct JobStatusTypeDeserializer;
+pub(crate) struct JobStatusTypeDeserializer;
 impl JobStatusTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<JobStatusType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct LineNumberDeserializer;
+/// <p>The status of the job.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobStatusType {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatusType::InProgress => "IN_PROGRESS",
+            JobStatusType::Completed => "COMPLETED",
+            JobStatusType::Failed => "FAILED",
+            JobStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobStatusType {
+    fn from(value: &'a str) -> JobStatusType {
+        match value {
+            "IN_PROGRESS" => JobStatusType::InProgress,
+            "COMPLETED" => JobStatusType::Completed,
+            "FAILED" => JobStatusType::Failed,
+            _ => JobStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatusType {
+    fn from(value: String) -> JobStatusType {
+        JobStatusType::from(value.as_str())
+    }
+}
+
+impl From<JobStatusType> for String {
+    fn from(value: JobStatusType) -> String {
+        match value {
+            JobStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct LineNumberDeserializer;
 impl LineNumberDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4599,6 +8125,50 @@ pub struct ListAccessKeysRequest {
     pub user_name: Option<String>,
 }
 
+impl ListAccessKeysRequest {
+    /// Returns a builder for `ListAccessKeysRequest`, checking its required members when built.
+    pub fn builder() -> ListAccessKeysRequestBuilder {
+        ListAccessKeysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAccessKeysRequest`](struct.ListAccessKeysRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAccessKeysRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    user_name: Option<String>,
+}
+
+impl ListAccessKeysRequestBuilder {
+    /// Sets the [`marker`](struct.ListAccessKeysRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListAccessKeysRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ListAccessKeysRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAccessKeysRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListAccessKeysRequest, ::rusoto_core::BuildError> {
+        Ok(ListAccessKeysRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `ListAccessKeysRequest` contents to a `SignedRequest`.
 struct ListAccessKeysRequestSerializer;
 impl ListAccessKeysRequestSerializer {
@@ -4631,10 +8201,10 @@ pub struct ListAccessKeysResponse {
     pub marker: Option<String>,
 }
 
-struct ListAccessKeysResponseDeserializer;
+pub(crate) struct ListAccessKeysResponseDeserializer;
 impl ListAccessKeysResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListAccessKeysResponse, XmlParseError> {
@@ -4671,6 +8241,44 @@ pub struct ListAccountAliasesRequest {
     pub max_items: Option<i64>,
 }
 
+impl ListAccountAliasesRequest {
+    /// Returns a builder for `ListAccountAliasesRequest`, checking its required members when built.
+    pub fn builder() -> ListAccountAliasesRequestBuilder {
+        ListAccountAliasesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAccountAliasesRequest`](struct.ListAccountAliasesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAccountAliasesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl ListAccountAliasesRequestBuilder {
+    /// Sets the [`marker`](struct.ListAccountAliasesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListAccountAliasesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAccountAliasesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAccountAliasesRequest, ::rusoto_core::BuildError> {
+        Ok(ListAccountAliasesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// Serialize `ListAccountAliasesRequest` contents to a `SignedRequest`.
 struct ListAccountAliasesRequestSerializer;
 impl ListAccountAliasesRequestSerializer {
@@ -4700,10 +8308,10 @@ pub struct ListAccountAliasesResponse {
     pub marker: Option<String>,
 }
 
-struct ListAccountAliasesResponseDeserializer;
+pub(crate) struct ListAccountAliasesResponseDeserializer;
 impl ListAccountAliasesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListAccountAliasesResponse, XmlParseError> {
@@ -4747,6 +8355,62 @@ pub struct ListAttachedGroupPoliciesRequest {
     pub path_prefix: Option<String>,
 }
 
+impl ListAttachedGroupPoliciesRequest {
+    /// Returns a builder for `ListAttachedGroupPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListAttachedGroupPoliciesRequestBuilder {
+        ListAttachedGroupPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAttachedGroupPoliciesRequest`](struct.ListAttachedGroupPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAttachedGroupPoliciesRequestBuilder {
+    group_name: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+    path_prefix: Option<String>,
+}
+
+impl ListAttachedGroupPoliciesRequestBuilder {
+    /// Sets the [`group_name`](struct.ListAttachedGroupPoliciesRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListAttachedGroupPoliciesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListAttachedGroupPoliciesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_prefix`](struct.ListAttachedGroupPoliciesRequest.html#structfield.path_prefix) member.
+    pub fn path_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_prefix = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAttachedGroupPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAttachedGroupPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListAttachedGroupPoliciesRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            marker: self.marker,
+            max_items: self.max_items,
+            path_prefix: self.path_prefix,
+        })
+    }
+}
+
 /// Serialize `ListAttachedGroupPoliciesRequest` contents to a `SignedRequest`.
 struct ListAttachedGroupPoliciesRequestSerializer;
 impl ListAttachedGroupPoliciesRequestSerializer {
@@ -4780,10 +8444,10 @@ pub struct ListAttachedGroupPoliciesResponse {
     pub marker: Option<String>,
 }
 
-struct ListAttachedGroupPoliciesResponseDeserializer;
+pub(crate) struct ListAttachedGroupPoliciesResponseDeserializer;
 impl ListAttachedGroupPoliciesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListAttachedGroupPoliciesResponse, XmlParseError> {
@@ -4828,6 +8492,62 @@ pub struct ListAttachedRolePoliciesRequest {
     pub role_name: String,
 }
 
+impl ListAttachedRolePoliciesRequest {
+    /// Returns a builder for `ListAttachedRolePoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListAttachedRolePoliciesRequestBuilder {
+        ListAttachedRolePoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAttachedRolePoliciesRequest`](struct.ListAttachedRolePoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAttachedRolePoliciesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    path_prefix: Option<String>,
+    role_name: Option<String>,
+}
+
+impl ListAttachedRolePoliciesRequestBuilder {
+    /// Sets the [`marker`](struct.ListAttachedRolePoliciesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListAttachedRolePoliciesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_prefix`](struct.ListAttachedRolePoliciesRequest.html#structfield.path_prefix) member.
+    pub fn path_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.ListAttachedRolePoliciesRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAttachedRolePoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAttachedRolePoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListAttachedRolePoliciesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            path_prefix: self.path_prefix,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListAttachedRolePoliciesRequest` contents to a `SignedRequest`.
 struct ListAttachedRolePoliciesRequestSerializer;
 impl ListAttachedRolePoliciesRequestSerializer {
@@ -4861,10 +8581,10 @@ pub struct ListAttachedRolePoliciesResponse {
     pub marker: Option<String>,
 }
 
-struct ListAttachedRolePoliciesResponseDeserializer;
+pub(crate) struct ListAttachedRolePoliciesResponseDeserializer;
 impl ListAttachedRolePoliciesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListAttachedRolePoliciesResponse, XmlParseError> {
@@ -4909,6 +8629,62 @@ pub struct ListAttachedUserPoliciesRequest {
     pub user_name: String,
 }
 
+impl ListAttachedUserPoliciesRequest {
+    /// Returns a builder for `ListAttachedUserPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListAttachedUserPoliciesRequestBuilder {
+        ListAttachedUserPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAttachedUserPoliciesRequest`](struct.ListAttachedUserPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAttachedUserPoliciesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    path_prefix: Option<String>,
+    user_name: Option<String>,
+}
+
+impl ListAttachedUserPoliciesRequestBuilder {
+    /// Sets the [`marker`](struct.ListAttachedUserPoliciesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListAttachedUserPoliciesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_prefix`](struct.ListAttachedUserPoliciesRequest.html#structfield.path_prefix) member.
+    pub fn path_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ListAttachedUserPoliciesRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAttachedUserPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAttachedUserPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListAttachedUserPoliciesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            path_prefix: self.path_prefix,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListAttachedUserPoliciesRequest` contents to a `SignedRequest`.
 struct ListAttachedUserPoliciesRequestSerializer;
 impl ListAttachedUserPoliciesRequestSerializer {
@@ -4942,10 +8718,10 @@ pub struct ListAttachedUserPoliciesResponse {
     pub marker: Option<String>,
 }
 
-struct ListAttachedUserPoliciesResponseDeserializer;
+pub(crate) struct ListAttachedUserPoliciesResponseDeserializer;
 impl ListAttachedUserPoliciesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListAttachedUserPoliciesResponse, XmlParseError> {
@@ -4981,7 +8757,7 @@ impl ListAttachedUserPoliciesResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListEntitiesForPolicyRequest {
     /// <p>The entity type to use for filtering the results.</p> <p>For example, when <code>EntityFilter</code> is <code>Role</code>, only the roles that are attached to the specified policy are returned. This parameter is optional. If it is not included, all attached entities (users, groups, and roles) are returned. The argument for this parameter must be one of the valid values listed below.</p>
-    pub entity_filter: Option<String>,
+    pub entity_filter: Option<EntityType>,
     /// <p>Use this parameter only when paginating results and only after you receive a response indicating that the results are truncated. Set it to the value of the <code>Marker</code> element in the response that you received to indicate where the next call should start.</p>
     pub marker: Option<String>,
     /// <p>Use this only when paginating results to indicate the maximum number of items you want in the response. If additional items exist beyond the maximum you specify, the <code>IsTruncated</code> response element is <code>true</code>.</p> <p>If you do not include this parameter, the number of items defaults to 100. Note that IAM might return fewer results, even when there are more results available. In that case, the <code>IsTruncated</code> response element returns <code>true</code>, and <code>Marker</code> contains a value to include in the subsequent call that tells the service where to continue from.</p>
@@ -4991,7 +8767,79 @@ pub struct ListEntitiesForPolicyRequest {
     /// <p>The Amazon Resource Name (ARN) of the IAM policy for which you want the versions.</p> <p>For more information about ARNs, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Names (ARNs) and AWS Service Namespaces</a> in the <i>AWS General Reference</i>.</p>
     pub policy_arn: String,
     /// <p>The policy usage method to use for filtering the results.</p> <p>To list only permissions policies, set <code>PolicyUsageFilter</code> to <code>PermissionsPolicy</code>. To list only the policies used to set permissions boundaries, set the value to <code>PermissionsBoundary</code>.</p> <p>This parameter is optional. If it is not included, all policies are returned. </p>
-    pub policy_usage_filter: Option<String>,
+    pub policy_usage_filter: Option<PolicyUsageType>,
+}
+
+impl ListEntitiesForPolicyRequest {
+    /// Returns a builder for `ListEntitiesForPolicyRequest`, checking its required members when built.
+    pub fn builder() -> ListEntitiesForPolicyRequestBuilder {
+        ListEntitiesForPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListEntitiesForPolicyRequest`](struct.ListEntitiesForPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct ListEntitiesForPolicyRequestBuilder {
+    entity_filter: Option<EntityType>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+    path_prefix: Option<String>,
+    policy_arn: Option<String>,
+    policy_usage_filter: Option<PolicyUsageType>,
+}
+
+impl ListEntitiesForPolicyRequestBuilder {
+    /// Sets the [`entity_filter`](struct.ListEntitiesForPolicyRequest.html#structfield.entity_filter) member.
+    pub fn entity_filter<T: Into<EntityType>>(mut self, value: T) -> Self {
+        self.entity_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListEntitiesForPolicyRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListEntitiesForPolicyRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_prefix`](struct.ListEntitiesForPolicyRequest.html#structfield.path_prefix) member.
+    pub fn path_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_arn`](struct.ListEntitiesForPolicyRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_usage_filter`](struct.ListEntitiesForPolicyRequest.html#structfield.policy_usage_filter) member.
+    pub fn policy_usage_filter<T: Into<PolicyUsageType>>(mut self, value: T) -> Self {
+        self.policy_usage_filter = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListEntitiesForPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListEntitiesForPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(ListEntitiesForPolicyRequest {
+            entity_filter: self.entity_filter,
+            marker: self.marker,
+            max_items: self.max_items,
+            path_prefix: self.path_prefix,
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+            policy_usage_filter: self.policy_usage_filter,
+        })
+    }
 }
 
 /// Serialize `ListEntitiesForPolicyRequest` contents to a `SignedRequest`.
@@ -5037,10 +8885,10 @@ pub struct ListEntitiesForPolicyResponse {
     pub policy_users: Option<Vec<PolicyUser>>,
 }
 
-struct ListEntitiesForPolicyResponseDeserializer;
+pub(crate) struct ListEntitiesForPolicyResponseDeserializer;
 impl ListEntitiesForPolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListEntitiesForPolicyResponse, XmlParseError> {
@@ -5090,6 +8938,54 @@ pub struct ListGroupPoliciesRequest {
     pub max_items: Option<i64>,
 }
 
+impl ListGroupPoliciesRequest {
+    /// Returns a builder for `ListGroupPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListGroupPoliciesRequestBuilder {
+        ListGroupPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGroupPoliciesRequest`](struct.ListGroupPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGroupPoliciesRequestBuilder {
+    group_name: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl ListGroupPoliciesRequestBuilder {
+    /// Sets the [`group_name`](struct.ListGroupPoliciesRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListGroupPoliciesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListGroupPoliciesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGroupPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListGroupPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListGroupPoliciesRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// Serialize `ListGroupPoliciesRequest` contents to a `SignedRequest`.
 struct ListGroupPoliciesRequestSerializer;
 impl ListGroupPoliciesRequestSerializer {
@@ -5120,10 +9016,10 @@ pub struct ListGroupPoliciesResponse {
     pub policy_names: Vec<String>,
 }
 
-struct ListGroupPoliciesResponseDeserializer;
+pub(crate) struct ListGroupPoliciesResponseDeserializer;
 impl ListGroupPoliciesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListGroupPoliciesResponse, XmlParseError> {
@@ -5165,6 +9061,54 @@ pub struct ListGroupsForUserRequest {
     pub user_name: String,
 }
 
+impl ListGroupsForUserRequest {
+    /// Returns a builder for `ListGroupsForUserRequest`, checking its required members when built.
+    pub fn builder() -> ListGroupsForUserRequestBuilder {
+        ListGroupsForUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGroupsForUserRequest`](struct.ListGroupsForUserRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGroupsForUserRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    user_name: Option<String>,
+}
+
+impl ListGroupsForUserRequestBuilder {
+    /// Sets the [`marker`](struct.ListGroupsForUserRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListGroupsForUserRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ListGroupsForUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGroupsForUserRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListGroupsForUserRequest, ::rusoto_core::BuildError> {
+        Ok(ListGroupsForUserRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListGroupsForUserRequest` contents to a `SignedRequest`.
 struct ListGroupsForUserRequestSerializer;
 impl ListGroupsForUserRequestSerializer {
@@ -5195,10 +9139,10 @@ pub struct ListGroupsForUserResponse {
     pub marker: Option<String>,
 }
 
-struct ListGroupsForUserResponseDeserializer;
+pub(crate) struct ListGroupsForUserResponseDeserializer;
 impl ListGroupsForUserResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListGroupsForUserResponse, XmlParseError> {
@@ -5237,6 +9181,50 @@ pub struct ListGroupsRequest {
     pub path_prefix: Option<String>,
 }
 
+impl ListGroupsRequest {
+    /// Returns a builder for `ListGroupsRequest`, checking its required members when built.
+    pub fn builder() -> ListGroupsRequestBuilder {
+        ListGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGroupsRequest`](struct.ListGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGroupsRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    path_prefix: Option<String>,
+}
+
+impl ListGroupsRequestBuilder {
+    /// Sets the [`marker`](struct.ListGroupsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListGroupsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_prefix`](struct.ListGroupsRequest.html#structfield.path_prefix) member.
+    pub fn path_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_prefix = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(ListGroupsRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            path_prefix: self.path_prefix,
+        })
+    }
+}
+
 /// Serialize `ListGroupsRequest` contents to a `SignedRequest`.
 struct ListGroupsRequestSerializer;
 impl ListGroupsRequestSerializer {
@@ -5269,10 +9257,10 @@ pub struct ListGroupsResponse {
     pub marker: Option<String>,
 }
 
-struct ListGroupsResponseDeserializer;
+pub(crate) struct ListGroupsResponseDeserializer;
 impl ListGroupsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListGroupsResponse, XmlParseError> {
@@ -5307,6 +9295,54 @@ pub struct ListInstanceProfilesForRoleRequest {
     pub role_name: String,
 }
 
+impl ListInstanceProfilesForRoleRequest {
+    /// Returns a builder for `ListInstanceProfilesForRoleRequest`, checking its required members when built.
+    pub fn builder() -> ListInstanceProfilesForRoleRequestBuilder {
+        ListInstanceProfilesForRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListInstanceProfilesForRoleRequest`](struct.ListInstanceProfilesForRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct ListInstanceProfilesForRoleRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    role_name: Option<String>,
+}
+
+impl ListInstanceProfilesForRoleRequestBuilder {
+    /// Sets the [`marker`](struct.ListInstanceProfilesForRoleRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListInstanceProfilesForRoleRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.ListInstanceProfilesForRoleRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListInstanceProfilesForRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListInstanceProfilesForRoleRequest, ::rusoto_core::BuildError> {
+        Ok(ListInstanceProfilesForRoleRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListInstanceProfilesForRoleRequest` contents to a `SignedRequest`.
 struct ListInstanceProfilesForRoleRequestSerializer;
 impl ListInstanceProfilesForRoleRequestSerializer {
@@ -5337,10 +9373,10 @@ pub struct ListInstanceProfilesForRoleResponse {
     pub marker: Option<String>,
 }
 
-struct ListInstanceProfilesForRoleResponseDeserializer;
+pub(crate) struct ListInstanceProfilesForRoleResponseDeserializer;
 impl ListInstanceProfilesForRoleResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListInstanceProfilesForRoleResponse, XmlParseError> {
@@ -5383,6 +9419,52 @@ pub struct ListInstanceProfilesRequest {
     pub path_prefix: Option<String>,
 }
 
+impl ListInstanceProfilesRequest {
+    /// Returns a builder for `ListInstanceProfilesRequest`, checking its required members when built.
+    pub fn builder() -> ListInstanceProfilesRequestBuilder {
+        ListInstanceProfilesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListInstanceProfilesRequest`](struct.ListInstanceProfilesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListInstanceProfilesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    path_prefix: Option<String>,
+}
+
+impl ListInstanceProfilesRequestBuilder {
+    /// Sets the [`marker`](struct.ListInstanceProfilesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListInstanceProfilesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_prefix`](struct.ListInstanceProfilesRequest.html#structfield.path_prefix) member.
+    pub fn path_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_prefix = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListInstanceProfilesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListInstanceProfilesRequest, ::rusoto_core::BuildError> {
+        Ok(ListInstanceProfilesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            path_prefix: self.path_prefix,
+        })
+    }
+}
+
 /// Serialize `ListInstanceProfilesRequest` contents to a `SignedRequest`.
 struct ListInstanceProfilesRequestSerializer;
 impl ListInstanceProfilesRequestSerializer {
@@ -5415,10 +9497,10 @@ pub struct ListInstanceProfilesResponse {
     pub marker: Option<String>,
 }
 
-struct ListInstanceProfilesResponseDeserializer;
+pub(crate) struct ListInstanceProfilesResponseDeserializer;
 impl ListInstanceProfilesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListInstanceProfilesResponse, XmlParseError> {
@@ -5461,18 +9543,62 @@ pub struct ListMFADevicesRequest {
     pub user_name: Option<String>,
 }
 
-/// Serialize `ListMFADevicesRequest` contents to a `SignedRequest`.
-struct ListMFADevicesRequestSerializer;
-impl ListMFADevicesRequestSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &ListMFADevicesRequest) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl ListMFADevicesRequest {
+    /// Returns a builder for `ListMFADevicesRequest`, checking its required members when built.
+    pub fn builder() -> ListMFADevicesRequestBuilder {
+        ListMFADevicesRequestBuilder::default()
+    }
+}
 
-        if let Some(ref field_value) = obj.marker {
-            params.put(&format!("{}{}", prefix, "Marker"), &field_value);
-        }
+/// Builds a [`ListMFADevicesRequest`](struct.ListMFADevicesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListMFADevicesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    user_name: Option<String>,
+}
+
+impl ListMFADevicesRequestBuilder {
+    /// Sets the [`marker`](struct.ListMFADevicesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListMFADevicesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ListMFADevicesRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListMFADevicesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListMFADevicesRequest, ::rusoto_core::BuildError> {
+        Ok(ListMFADevicesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            user_name: self.user_name,
+        })
+    }
+}
+
+/// Serialize `ListMFADevicesRequest` contents to a `SignedRequest`.
+struct ListMFADevicesRequestSerializer;
+impl ListMFADevicesRequestSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &ListMFADevicesRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.marker {
+            params.put(&format!("{}{}", prefix, "Marker"), &field_value);
+        }
         if let Some(ref field_value) = obj.max_items {
             params.put(&format!("{}{}", prefix, "MaxItems"), &field_value);
         }
@@ -5493,10 +9619,10 @@ pub struct ListMFADevicesResponse {
     pub marker: Option<String>,
 }
 
-struct ListMFADevicesResponseDeserializer;
+pub(crate) struct ListMFADevicesResponseDeserializer;
 impl ListMFADevicesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListMFADevicesResponse, XmlParseError> {
@@ -5527,6 +9653,26 @@ impl ListMFADevicesResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListOpenIDConnectProvidersRequest {}
 
+impl ListOpenIDConnectProvidersRequest {
+    /// Returns a builder for `ListOpenIDConnectProvidersRequest`, checking its required members when built.
+    pub fn builder() -> ListOpenIDConnectProvidersRequestBuilder {
+        ListOpenIDConnectProvidersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListOpenIDConnectProvidersRequest`](struct.ListOpenIDConnectProvidersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListOpenIDConnectProvidersRequestBuilder {}
+
+impl ListOpenIDConnectProvidersRequestBuilder {
+    /// Builds the `ListOpenIDConnectProvidersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListOpenIDConnectProvidersRequest, ::rusoto_core::BuildError> {
+        Ok(ListOpenIDConnectProvidersRequest {})
+    }
+}
+
 /// Serialize `ListOpenIDConnectProvidersRequest` contents to a `SignedRequest`.
 struct ListOpenIDConnectProvidersRequestSerializer;
 impl ListOpenIDConnectProvidersRequestSerializer {
@@ -5545,10 +9691,10 @@ pub struct ListOpenIDConnectProvidersResponse {
     pub open_id_connect_provider_list: Option<Vec<OpenIDConnectProviderListEntry>>,
 }
 
-struct ListOpenIDConnectProvidersResponseDeserializer;
+pub(crate) struct ListOpenIDConnectProvidersResponseDeserializer;
 impl ListOpenIDConnectProvidersResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListOpenIDConnectProvidersResponse, XmlParseError> {
@@ -5581,10 +9727,10 @@ pub struct ListPoliciesGrantingServiceAccessEntry {
     pub service_namespace: Option<String>,
 }
 
-struct ListPoliciesGrantingServiceAccessEntryDeserializer;
+pub(crate) struct ListPoliciesGrantingServiceAccessEntryDeserializer;
 impl ListPoliciesGrantingServiceAccessEntryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListPoliciesGrantingServiceAccessEntry, XmlParseError> {
@@ -5624,6 +9770,55 @@ pub struct ListPoliciesGrantingServiceAccessRequest {
     pub service_namespaces: Vec<String>,
 }
 
+impl ListPoliciesGrantingServiceAccessRequest {
+    /// Returns a builder for `ListPoliciesGrantingServiceAccessRequest`, checking its required members when built.
+    pub fn builder() -> ListPoliciesGrantingServiceAccessRequestBuilder {
+        ListPoliciesGrantingServiceAccessRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPoliciesGrantingServiceAccessRequest`](struct.ListPoliciesGrantingServiceAccessRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPoliciesGrantingServiceAccessRequestBuilder {
+    arn: Option<String>,
+    marker: Option<String>,
+    service_namespaces: Option<Vec<String>>,
+}
+
+impl ListPoliciesGrantingServiceAccessRequestBuilder {
+    /// Sets the [`arn`](struct.ListPoliciesGrantingServiceAccessRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListPoliciesGrantingServiceAccessRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_namespaces`](struct.ListPoliciesGrantingServiceAccessRequest.html#structfield.service_namespaces) member.
+    pub fn service_namespaces<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.service_namespaces = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPoliciesGrantingServiceAccessRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPoliciesGrantingServiceAccessRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListPoliciesGrantingServiceAccessRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            marker: self.marker,
+            service_namespaces: self
+                .service_namespaces
+                .ok_or(::rusoto_core::BuildError::missing("service_namespaces"))?,
+        })
+    }
+}
+
 /// Serialize `ListPoliciesGrantingServiceAccessRequest` contents to a `SignedRequest`.
 struct ListPoliciesGrantingServiceAccessRequestSerializer;
 impl ListPoliciesGrantingServiceAccessRequestSerializer {
@@ -5655,10 +9850,10 @@ pub struct ListPoliciesGrantingServiceAccessResponse {
     pub policies_granting_service_access: Vec<ListPoliciesGrantingServiceAccessEntry>,
 }
 
-struct ListPoliciesGrantingServiceAccessResponseDeserializer;
+pub(crate) struct ListPoliciesGrantingServiceAccessResponseDeserializer;
 impl ListPoliciesGrantingServiceAccessResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListPoliciesGrantingServiceAccessResponse, XmlParseError> {
@@ -5697,9 +9892,77 @@ pub struct ListPoliciesRequest {
     /// <p>The path prefix for filtering the results. This parameter is optional. If it is not included, it defaults to a slash (/), listing all policies. This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of either a forward slash (/) by itself or a string that must begin and end with forward slashes. In addition, it can contain any ASCII character from the ! (\u0021) through the DEL character (\u007F), including most punctuation characters, digits, and upper and lowercased letters.</p>
     pub path_prefix: Option<String>,
     /// <p>The policy usage method to use for filtering the results.</p> <p>To list only permissions policies, set <code>PolicyUsageFilter</code> to <code>PermissionsPolicy</code>. To list only the policies used to set permissions boundaries, set the value to <code>PermissionsBoundary</code>.</p> <p>This parameter is optional. If it is not included, all policies are returned. </p>
-    pub policy_usage_filter: Option<String>,
+    pub policy_usage_filter: Option<PolicyUsageType>,
     /// <p>The scope to use for filtering the results.</p> <p>To list only AWS managed policies, set <code>Scope</code> to <code>AWS</code>. To list only the customer managed policies in your AWS account, set <code>Scope</code> to <code>Local</code>.</p> <p>This parameter is optional. If it is not included, or if it is set to <code>All</code>, all policies are returned.</p>
-    pub scope: Option<String>,
+    pub scope: Option<PolicyScopeType>,
+}
+
+impl ListPoliciesRequest {
+    /// Returns a builder for `ListPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListPoliciesRequestBuilder {
+        ListPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPoliciesRequest`](struct.ListPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPoliciesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    only_attached: Option<bool>,
+    path_prefix: Option<String>,
+    policy_usage_filter: Option<PolicyUsageType>,
+    scope: Option<PolicyScopeType>,
+}
+
+impl ListPoliciesRequestBuilder {
+    /// Sets the [`marker`](struct.ListPoliciesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListPoliciesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`only_attached`](struct.ListPoliciesRequest.html#structfield.only_attached) member.
+    pub fn only_attached<T: Into<bool>>(mut self, value: T) -> Self {
+        self.only_attached = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_prefix`](struct.ListPoliciesRequest.html#structfield.path_prefix) member.
+    pub fn path_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_usage_filter`](struct.ListPoliciesRequest.html#structfield.policy_usage_filter) member.
+    pub fn policy_usage_filter<T: Into<PolicyUsageType>>(mut self, value: T) -> Self {
+        self.policy_usage_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scope`](struct.ListPoliciesRequest.html#structfield.scope) member.
+    pub fn scope<T: Into<PolicyScopeType>>(mut self, value: T) -> Self {
+        self.scope = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListPoliciesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            only_attached: self.only_attached,
+            path_prefix: self.path_prefix,
+            policy_usage_filter: self.policy_usage_filter,
+            scope: self.scope,
+        })
+    }
 }
 
 /// Serialize `ListPoliciesRequest` contents to a `SignedRequest`.
@@ -5743,10 +10006,10 @@ pub struct ListPoliciesResponse {
     pub policies: Option<Vec<Policy>>,
 }
 
-struct ListPoliciesResponseDeserializer;
+pub(crate) struct ListPoliciesResponseDeserializer;
 impl ListPoliciesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListPoliciesResponse, XmlParseError> {
@@ -5772,10 +10035,10 @@ impl ListPoliciesResponseDeserializer {
         })
     }
 }
-struct ListPolicyGrantingServiceAccessResponseListTypeDeserializer;
+pub(crate) struct ListPolicyGrantingServiceAccessResponseListTypeDeserializer;
 impl ListPolicyGrantingServiceAccessResponseListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ListPoliciesGrantingServiceAccessEntry>, XmlParseError> {
@@ -5803,6 +10066,54 @@ pub struct ListPolicyVersionsRequest {
     pub policy_arn: String,
 }
 
+impl ListPolicyVersionsRequest {
+    /// Returns a builder for `ListPolicyVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListPolicyVersionsRequestBuilder {
+        ListPolicyVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPolicyVersionsRequest`](struct.ListPolicyVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPolicyVersionsRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    policy_arn: Option<String>,
+}
+
+impl ListPolicyVersionsRequestBuilder {
+    /// Sets the [`marker`](struct.ListPolicyVersionsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListPolicyVersionsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_arn`](struct.ListPolicyVersionsRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPolicyVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPolicyVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPolicyVersionsRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+        })
+    }
+}
+
 /// Serialize `ListPolicyVersionsRequest` contents to a `SignedRequest`.
 struct ListPolicyVersionsRequestSerializer;
 impl ListPolicyVersionsRequestSerializer {
@@ -5833,10 +10144,10 @@ pub struct ListPolicyVersionsResponse {
     pub versions: Option<Vec<PolicyVersion>>,
 }
 
-struct ListPolicyVersionsResponseDeserializer;
+pub(crate) struct ListPolicyVersionsResponseDeserializer;
 impl ListPolicyVersionsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListPolicyVersionsResponse, XmlParseError> {
@@ -5878,6 +10189,54 @@ pub struct ListRolePoliciesRequest {
     pub role_name: String,
 }
 
+impl ListRolePoliciesRequest {
+    /// Returns a builder for `ListRolePoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListRolePoliciesRequestBuilder {
+        ListRolePoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRolePoliciesRequest`](struct.ListRolePoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRolePoliciesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    role_name: Option<String>,
+}
+
+impl ListRolePoliciesRequestBuilder {
+    /// Sets the [`marker`](struct.ListRolePoliciesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListRolePoliciesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.ListRolePoliciesRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRolePoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListRolePoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListRolePoliciesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListRolePoliciesRequest` contents to a `SignedRequest`.
 struct ListRolePoliciesRequestSerializer;
 impl ListRolePoliciesRequestSerializer {
@@ -5908,10 +10267,10 @@ pub struct ListRolePoliciesResponse {
     pub policy_names: Vec<String>,
 }
 
-struct ListRolePoliciesResponseDeserializer;
+pub(crate) struct ListRolePoliciesResponseDeserializer;
 impl ListRolePoliciesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListRolePoliciesResponse, XmlParseError> {
@@ -5953,6 +10312,52 @@ pub struct ListRoleTagsRequest {
     pub role_name: String,
 }
 
+impl ListRoleTagsRequest {
+    /// Returns a builder for `ListRoleTagsRequest`, checking its required members when built.
+    pub fn builder() -> ListRoleTagsRequestBuilder {
+        ListRoleTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRoleTagsRequest`](struct.ListRoleTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRoleTagsRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    role_name: Option<String>,
+}
+
+impl ListRoleTagsRequestBuilder {
+    /// Sets the [`marker`](struct.ListRoleTagsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListRoleTagsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.ListRoleTagsRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRoleTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListRoleTagsRequest, ::rusoto_core::BuildError> {
+        Ok(ListRoleTagsRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListRoleTagsRequest` contents to a `SignedRequest`.
 struct ListRoleTagsRequestSerializer;
 impl ListRoleTagsRequestSerializer {
@@ -5982,10 +10387,10 @@ pub struct ListRoleTagsResponse {
     pub tags: Vec<Tag>,
 }
 
-struct ListRoleTagsResponseDeserializer;
+pub(crate) struct ListRoleTagsResponseDeserializer;
 impl ListRoleTagsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListRoleTagsResponse, XmlParseError> {
@@ -6020,6 +10425,50 @@ pub struct ListRolesRequest {
     pub path_prefix: Option<String>,
 }
 
+impl ListRolesRequest {
+    /// Returns a builder for `ListRolesRequest`, checking its required members when built.
+    pub fn builder() -> ListRolesRequestBuilder {
+        ListRolesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRolesRequest`](struct.ListRolesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRolesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    path_prefix: Option<String>,
+}
+
+impl ListRolesRequestBuilder {
+    /// Sets the [`marker`](struct.ListRolesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListRolesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_prefix`](struct.ListRolesRequest.html#structfield.path_prefix) member.
+    pub fn path_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_prefix = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRolesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListRolesRequest, ::rusoto_core::BuildError> {
+        Ok(ListRolesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            path_prefix: self.path_prefix,
+        })
+    }
+}
+
 /// Serialize `ListRolesRequest` contents to a `SignedRequest`.
 struct ListRolesRequestSerializer;
 impl ListRolesRequestSerializer {
@@ -6052,10 +10501,10 @@ pub struct ListRolesResponse {
     pub roles: Vec<Role>,
 }
 
-struct ListRolesResponseDeserializer;
+pub(crate) struct ListRolesResponseDeserializer;
 impl ListRolesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListRolesResponse, XmlParseError> {
@@ -6083,6 +10532,26 @@ impl ListRolesResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListSAMLProvidersRequest {}
 
+impl ListSAMLProvidersRequest {
+    /// Returns a builder for `ListSAMLProvidersRequest`, checking its required members when built.
+    pub fn builder() -> ListSAMLProvidersRequestBuilder {
+        ListSAMLProvidersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSAMLProvidersRequest`](struct.ListSAMLProvidersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSAMLProvidersRequestBuilder {}
+
+impl ListSAMLProvidersRequestBuilder {
+    /// Builds the `ListSAMLProvidersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSAMLProvidersRequest, ::rusoto_core::BuildError> {
+        Ok(ListSAMLProvidersRequest {})
+    }
+}
+
 /// Serialize `ListSAMLProvidersRequest` contents to a `SignedRequest`.
 struct ListSAMLProvidersRequestSerializer;
 impl ListSAMLProvidersRequestSerializer {
@@ -6101,10 +10570,10 @@ pub struct ListSAMLProvidersResponse {
     pub saml_provider_list: Option<Vec<SAMLProviderListEntry>>,
 }
 
-struct ListSAMLProvidersResponseDeserializer;
+pub(crate) struct ListSAMLProvidersResponseDeserializer;
 impl ListSAMLProvidersResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListSAMLProvidersResponse, XmlParseError> {
@@ -6138,6 +10607,52 @@ pub struct ListSSHPublicKeysRequest {
     pub user_name: Option<String>,
 }
 
+impl ListSSHPublicKeysRequest {
+    /// Returns a builder for `ListSSHPublicKeysRequest`, checking its required members when built.
+    pub fn builder() -> ListSSHPublicKeysRequestBuilder {
+        ListSSHPublicKeysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSSHPublicKeysRequest`](struct.ListSSHPublicKeysRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSSHPublicKeysRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    user_name: Option<String>,
+}
+
+impl ListSSHPublicKeysRequestBuilder {
+    /// Sets the [`marker`](struct.ListSSHPublicKeysRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListSSHPublicKeysRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ListSSHPublicKeysRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSSHPublicKeysRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSSHPublicKeysRequest, ::rusoto_core::BuildError> {
+        Ok(ListSSHPublicKeysRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `ListSSHPublicKeysRequest` contents to a `SignedRequest`.
 struct ListSSHPublicKeysRequestSerializer;
 impl ListSSHPublicKeysRequestSerializer {
@@ -6170,10 +10685,10 @@ pub struct ListSSHPublicKeysResponse {
     pub ssh_public_keys: Option<Vec<SSHPublicKeyMetadata>>,
 }
 
-struct ListSSHPublicKeysResponseDeserializer;
+pub(crate) struct ListSSHPublicKeysResponseDeserializer;
 impl ListSSHPublicKeysResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListSSHPublicKeysResponse, XmlParseError> {
@@ -6213,6 +10728,52 @@ pub struct ListServerCertificatesRequest {
     pub path_prefix: Option<String>,
 }
 
+impl ListServerCertificatesRequest {
+    /// Returns a builder for `ListServerCertificatesRequest`, checking its required members when built.
+    pub fn builder() -> ListServerCertificatesRequestBuilder {
+        ListServerCertificatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListServerCertificatesRequest`](struct.ListServerCertificatesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListServerCertificatesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    path_prefix: Option<String>,
+}
+
+impl ListServerCertificatesRequestBuilder {
+    /// Sets the [`marker`](struct.ListServerCertificatesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListServerCertificatesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_prefix`](struct.ListServerCertificatesRequest.html#structfield.path_prefix) member.
+    pub fn path_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_prefix = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListServerCertificatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListServerCertificatesRequest, ::rusoto_core::BuildError> {
+        Ok(ListServerCertificatesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            path_prefix: self.path_prefix,
+        })
+    }
+}
+
 /// Serialize `ListServerCertificatesRequest` contents to a `SignedRequest`.
 struct ListServerCertificatesRequestSerializer;
 impl ListServerCertificatesRequestSerializer {
@@ -6245,10 +10806,10 @@ pub struct ListServerCertificatesResponse {
     pub server_certificate_metadata_list: Vec<ServerCertificateMetadata>,
 }
 
-struct ListServerCertificatesResponseDeserializer;
+pub(crate) struct ListServerCertificatesResponseDeserializer;
 impl ListServerCertificatesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListServerCertificatesResponse, XmlParseError> {
@@ -6289,6 +10850,45 @@ pub struct ListServiceSpecificCredentialsRequest {
     pub user_name: Option<String>,
 }
 
+impl ListServiceSpecificCredentialsRequest {
+    /// Returns a builder for `ListServiceSpecificCredentialsRequest`, checking its required members when built.
+    pub fn builder() -> ListServiceSpecificCredentialsRequestBuilder {
+        ListServiceSpecificCredentialsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListServiceSpecificCredentialsRequest`](struct.ListServiceSpecificCredentialsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListServiceSpecificCredentialsRequestBuilder {
+    service_name: Option<String>,
+    user_name: Option<String>,
+}
+
+impl ListServiceSpecificCredentialsRequestBuilder {
+    /// Sets the [`service_name`](struct.ListServiceSpecificCredentialsRequest.html#structfield.service_name) member.
+    pub fn service_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ListServiceSpecificCredentialsRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListServiceSpecificCredentialsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListServiceSpecificCredentialsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListServiceSpecificCredentialsRequest {
+            service_name: self.service_name,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `ListServiceSpecificCredentialsRequest` contents to a `SignedRequest`.
 struct ListServiceSpecificCredentialsRequestSerializer;
 impl ListServiceSpecificCredentialsRequestSerializer {
@@ -6313,10 +10913,10 @@ pub struct ListServiceSpecificCredentialsResponse {
     pub service_specific_credentials: Option<Vec<ServiceSpecificCredentialMetadata>>,
 }
 
-struct ListServiceSpecificCredentialsResponseDeserializer;
+pub(crate) struct ListServiceSpecificCredentialsResponseDeserializer;
 impl ListServiceSpecificCredentialsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListServiceSpecificCredentialsResponse, XmlParseError> {
@@ -6350,6 +10950,52 @@ pub struct ListSigningCertificatesRequest {
     pub user_name: Option<String>,
 }
 
+impl ListSigningCertificatesRequest {
+    /// Returns a builder for `ListSigningCertificatesRequest`, checking its required members when built.
+    pub fn builder() -> ListSigningCertificatesRequestBuilder {
+        ListSigningCertificatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSigningCertificatesRequest`](struct.ListSigningCertificatesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSigningCertificatesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    user_name: Option<String>,
+}
+
+impl ListSigningCertificatesRequestBuilder {
+    /// Sets the [`marker`](struct.ListSigningCertificatesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListSigningCertificatesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ListSigningCertificatesRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSigningCertificatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSigningCertificatesRequest, ::rusoto_core::BuildError> {
+        Ok(ListSigningCertificatesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `ListSigningCertificatesRequest` contents to a `SignedRequest`.
 struct ListSigningCertificatesRequestSerializer;
 impl ListSigningCertificatesRequestSerializer {
@@ -6382,10 +11028,10 @@ pub struct ListSigningCertificatesResponse {
     pub marker: Option<String>,
 }
 
-struct ListSigningCertificatesResponseDeserializer;
+pub(crate) struct ListSigningCertificatesResponseDeserializer;
 impl ListSigningCertificatesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListSigningCertificatesResponse, XmlParseError> {
@@ -6427,6 +11073,54 @@ pub struct ListUserPoliciesRequest {
     pub user_name: String,
 }
 
+impl ListUserPoliciesRequest {
+    /// Returns a builder for `ListUserPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListUserPoliciesRequestBuilder {
+        ListUserPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUserPoliciesRequest`](struct.ListUserPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUserPoliciesRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    user_name: Option<String>,
+}
+
+impl ListUserPoliciesRequestBuilder {
+    /// Sets the [`marker`](struct.ListUserPoliciesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListUserPoliciesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ListUserPoliciesRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUserPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListUserPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListUserPoliciesRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListUserPoliciesRequest` contents to a `SignedRequest`.
 struct ListUserPoliciesRequestSerializer;
 impl ListUserPoliciesRequestSerializer {
@@ -6457,10 +11151,10 @@ pub struct ListUserPoliciesResponse {
     pub policy_names: Vec<String>,
 }
 
-struct ListUserPoliciesResponseDeserializer;
+pub(crate) struct ListUserPoliciesResponseDeserializer;
 impl ListUserPoliciesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListUserPoliciesResponse, XmlParseError> {
@@ -6502,6 +11196,52 @@ pub struct ListUserTagsRequest {
     pub user_name: String,
 }
 
+impl ListUserTagsRequest {
+    /// Returns a builder for `ListUserTagsRequest`, checking its required members when built.
+    pub fn builder() -> ListUserTagsRequestBuilder {
+        ListUserTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUserTagsRequest`](struct.ListUserTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUserTagsRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    user_name: Option<String>,
+}
+
+impl ListUserTagsRequestBuilder {
+    /// Sets the [`marker`](struct.ListUserTagsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListUserTagsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ListUserTagsRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUserTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListUserTagsRequest, ::rusoto_core::BuildError> {
+        Ok(ListUserTagsRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListUserTagsRequest` contents to a `SignedRequest`.
 struct ListUserTagsRequestSerializer;
 impl ListUserTagsRequestSerializer {
@@ -6531,10 +11271,10 @@ pub struct ListUserTagsResponse {
     pub tags: Vec<Tag>,
 }
 
-struct ListUserTagsResponseDeserializer;
+pub(crate) struct ListUserTagsResponseDeserializer;
 impl ListUserTagsResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListUserTagsResponse, XmlParseError> {
@@ -6569,6 +11309,50 @@ pub struct ListUsersRequest {
     pub path_prefix: Option<String>,
 }
 
+impl ListUsersRequest {
+    /// Returns a builder for `ListUsersRequest`, checking its required members when built.
+    pub fn builder() -> ListUsersRequestBuilder {
+        ListUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUsersRequest`](struct.ListUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUsersRequestBuilder {
+    marker: Option<String>,
+    max_items: Option<i64>,
+    path_prefix: Option<String>,
+}
+
+impl ListUsersRequestBuilder {
+    /// Sets the [`marker`](struct.ListUsersRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListUsersRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path_prefix`](struct.ListUsersRequest.html#structfield.path_prefix) member.
+    pub fn path_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.path_prefix = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListUsersRequest, ::rusoto_core::BuildError> {
+        Ok(ListUsersRequest {
+            marker: self.marker,
+            max_items: self.max_items,
+            path_prefix: self.path_prefix,
+        })
+    }
+}
+
 /// Serialize `ListUsersRequest` contents to a `SignedRequest`.
 struct ListUsersRequestSerializer;
 impl ListUsersRequestSerializer {
@@ -6601,10 +11385,10 @@ pub struct ListUsersResponse {
     pub users: Vec<User>,
 }
 
-struct ListUsersResponseDeserializer;
+pub(crate) struct ListUsersResponseDeserializer;
 impl ListUsersResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListUsersResponse, XmlParseError> {
@@ -6632,13 +11416,59 @@ impl ListUsersResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ListVirtualMFADevicesRequest {
     /// <p> The status (<code>Unassigned</code> or <code>Assigned</code>) of the devices to list. If you do not specify an <code>AssignmentStatus</code>, the operation defaults to <code>Any</code>, which lists both assigned and unassigned virtual MFA devices.,</p>
-    pub assignment_status: Option<String>,
+    pub assignment_status: Option<AssignmentStatusType>,
     /// <p>Use this parameter only when paginating results and only after you receive a response indicating that the results are truncated. Set it to the value of the <code>Marker</code> element in the response that you received to indicate where the next call should start.</p>
     pub marker: Option<String>,
     /// <p>Use this only when paginating results to indicate the maximum number of items you want in the response. If additional items exist beyond the maximum you specify, the <code>IsTruncated</code> response element is <code>true</code>.</p> <p>If you do not include this parameter, the number of items defaults to 100. Note that IAM might return fewer results, even when there are more results available. In that case, the <code>IsTruncated</code> response element returns <code>true</code>, and <code>Marker</code> contains a value to include in the subsequent call that tells the service where to continue from.</p>
     pub max_items: Option<i64>,
 }
 
+impl ListVirtualMFADevicesRequest {
+    /// Returns a builder for `ListVirtualMFADevicesRequest`, checking its required members when built.
+    pub fn builder() -> ListVirtualMFADevicesRequestBuilder {
+        ListVirtualMFADevicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListVirtualMFADevicesRequest`](struct.ListVirtualMFADevicesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListVirtualMFADevicesRequestBuilder {
+    assignment_status: Option<AssignmentStatusType>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl ListVirtualMFADevicesRequestBuilder {
+    /// Sets the [`assignment_status`](struct.ListVirtualMFADevicesRequest.html#structfield.assignment_status) member.
+    pub fn assignment_status<T: Into<AssignmentStatusType>>(mut self, value: T) -> Self {
+        self.assignment_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListVirtualMFADevicesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListVirtualMFADevicesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVirtualMFADevicesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListVirtualMFADevicesRequest, ::rusoto_core::BuildError> {
+        Ok(ListVirtualMFADevicesRequest {
+            assignment_status: self.assignment_status,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// Serialize `ListVirtualMFADevicesRequest` contents to a `SignedRequest`.
 struct ListVirtualMFADevicesRequestSerializer;
 impl ListVirtualMFADevicesRequestSerializer {
@@ -6671,10 +11501,10 @@ pub struct ListVirtualMFADevicesResponse {
     pub virtual_mfa_devices: Vec<VirtualMFADevice>,
 }
 
-struct ListVirtualMFADevicesResponseDeserializer;
+pub(crate) struct ListVirtualMFADevicesResponseDeserializer;
 impl ListVirtualMFADevicesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListVirtualMFADevicesResponse, XmlParseError> {
@@ -6711,17 +11541,17 @@ impl ListVirtualMFADevicesResponseDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct LoginProfile {
     /// <p>The date when the password for the user was created.</p>
-    pub create_date: String,
+    pub create_date: IsoTimestamp,
     /// <p>Specifies whether the user is required to set a new password on next sign-in.</p>
     pub password_reset_required: Option<bool>,
     /// <p>The name of the user, which can be used for signing in to the AWS Management Console.</p>
     pub user_name: String,
 }
 
-struct LoginProfileDeserializer;
+pub(crate) struct LoginProfileDeserializer;
 impl LoginProfileDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoginProfile, XmlParseError> {
@@ -6749,17 +11579,17 @@ impl LoginProfileDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct MFADevice {
     /// <p>The date when the MFA device was enabled for the user.</p>
-    pub enable_date: String,
+    pub enable_date: IsoTimestamp,
     /// <p>The serial number that uniquely identifies the MFA device. For virtual MFA devices, the serial number is the device ARN.</p>
     pub serial_number: String,
     /// <p>The user with whom the MFA device is associated.</p>
     pub user_name: String,
 }
 
-struct MFADeviceDeserializer;
+pub(crate) struct MFADeviceDeserializer;
 impl MFADeviceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MFADevice, XmlParseError> {
@@ -6788,7 +11618,7 @@ pub struct ManagedPolicyDetail {
     /// <p>The number of principal entities (users, groups, and roles) that the policy is attached to.</p>
     pub attachment_count: Option<i64>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the policy was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<IsoTimestamp>,
     /// <p>The identifier for the version of the policy that is set as the default (operative) version.</p> <p>For more information about policy versions, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-versions.html">Versioning for Managed Policies</a> in the <i>Using IAM</i> guide. </p>
     pub default_version_id: Option<String>,
     /// <p>A friendly description of the policy.</p>
@@ -6806,13 +11636,13 @@ pub struct ManagedPolicyDetail {
     /// <p>A list containing information about the versions of the policy.</p>
     pub policy_version_list: Option<Vec<PolicyVersion>>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the policy was last updated.</p> <p>When a policy has only one version, this field contains the date and time when the policy was created. When a policy has more than one version, this field contains the date and time when the most recent policy version was created.</p>
-    pub update_date: Option<String>,
+    pub update_date: Option<IsoTimestamp>,
 }
 
-struct ManagedPolicyDetailDeserializer;
+pub(crate) struct ManagedPolicyDetailDeserializer;
 impl ManagedPolicyDetailDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ManagedPolicyDetail, XmlParseError> {
@@ -6882,10 +11712,10 @@ impl ManagedPolicyDetailDeserializer {
         })
     }
 }
-struct ManagedPolicyDetailListTypeDeserializer;
+pub(crate) struct ManagedPolicyDetailListTypeDeserializer;
 impl ManagedPolicyDetailListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ManagedPolicyDetail>, XmlParseError> {
@@ -6901,10 +11731,13 @@ impl ManagedPolicyDetailListTypeDeserializer {
         })
     }
 }
-struct MarkerTypeDeserializer;
+pub(crate) struct MarkerTypeDeserializer;
 impl MarkerTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -6912,10 +11745,13 @@ impl MarkerTypeDeserializer {
         Ok(obj)
     }
 }
-struct MaxPasswordAgeTypeDeserializer;
+pub(crate) struct MaxPasswordAgeTypeDeserializer;
 impl MaxPasswordAgeTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -6923,10 +11759,10 @@ impl MaxPasswordAgeTypeDeserializer {
         Ok(obj)
     }
 }
-struct MfaDeviceListTypeDeserializer;
+pub(crate) struct MfaDeviceListTypeDeserializer;
 impl MfaDeviceListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<MFADevice>, XmlParseError> {
@@ -6940,10 +11776,13 @@ impl MfaDeviceListTypeDeserializer {
         })
     }
 }
-struct MinimumPasswordLengthTypeDeserializer;
+pub(crate) struct MinimumPasswordLengthTypeDeserializer;
 impl MinimumPasswordLengthTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -6957,10 +11796,10 @@ pub struct OpenIDConnectProviderListEntry {
     pub arn: Option<String>,
 }
 
-struct OpenIDConnectProviderListEntryDeserializer;
+pub(crate) struct OpenIDConnectProviderListEntryDeserializer;
 impl OpenIDConnectProviderListEntryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OpenIDConnectProviderListEntry, XmlParseError> {
@@ -6979,10 +11818,10 @@ impl OpenIDConnectProviderListEntryDeserializer {
         )
     }
 }
-struct OpenIDConnectProviderListTypeDeserializer;
+pub(crate) struct OpenIDConnectProviderListTypeDeserializer;
 impl OpenIDConnectProviderListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OpenIDConnectProviderListEntry>, XmlParseError> {
@@ -6998,10 +11837,13 @@ impl OpenIDConnectProviderListTypeDeserializer {
         })
     }
 }
-struct OpenIDConnectProviderUrlTypeDeserializer;
+pub(crate) struct OpenIDConnectProviderUrlTypeDeserializer;
 impl OpenIDConnectProviderUrlTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7016,10 +11858,10 @@ pub struct OrganizationsDecisionDetail {
     pub allowed_by_organizations: Option<bool>,
 }
 
-struct OrganizationsDecisionDetailDeserializer;
+pub(crate) struct OrganizationsDecisionDetailDeserializer;
 impl OrganizationsDecisionDetailDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OrganizationsDecisionDetail, XmlParseError> {
@@ -7041,10 +11883,13 @@ impl OrganizationsDecisionDetailDeserializer {
         )
     }
 }
-struct OrganizationsEntityPathTypeDeserializer;
+pub(crate) struct OrganizationsEntityPathTypeDeserializer;
 impl OrganizationsEntityPathTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7077,10 +11922,10 @@ pub struct PasswordPolicy {
     pub require_uppercase_characters: Option<bool>,
 }
 
-struct PasswordPolicyDeserializer;
+pub(crate) struct PasswordPolicyDeserializer;
 impl PasswordPolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PasswordPolicy, XmlParseError> {
@@ -7153,10 +11998,13 @@ impl PasswordPolicyDeserializer {
         })
     }
 }
-struct PasswordReusePreventionTypeDeserializer;
+pub(crate) struct PasswordReusePreventionTypeDeserializer;
 impl PasswordReusePreventionTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -7164,10 +12012,13 @@ impl PasswordReusePreventionTypeDeserializer {
         Ok(obj)
     }
 }
-struct PathTypeDeserializer;
+pub(crate) struct PathTypeDeserializer;
 impl PathTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7175,17 +12026,79 @@ impl PathTypeDeserializer {
         Ok(obj)
     }
 }
-struct PermissionsBoundaryAttachmentTypeDeserializer;
+pub(crate) struct PermissionsBoundaryAttachmentTypeDeserializer;
 impl PermissionsBoundaryAttachmentTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PermissionsBoundaryAttachmentType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p> The permissions boundary usage type that indicates what type of IAM resource is used as the permissions boundary for an entity. This data type can only have a value of <code>Policy</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PermissionsBoundaryAttachmentType {
+    /// <p><code>PermissionsBoundaryPolicy</code></p>
+    PermissionsBoundaryPolicy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PermissionsBoundaryAttachmentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PermissionsBoundaryAttachmentType::PermissionsBoundaryPolicy => {
+                "PermissionsBoundaryPolicy"
+            }
+            PermissionsBoundaryAttachmentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PermissionsBoundaryAttachmentType {
+    fn from(value: &'a str) -> PermissionsBoundaryAttachmentType {
+        match value {
+            "PermissionsBoundaryPolicy" => {
+                PermissionsBoundaryAttachmentType::PermissionsBoundaryPolicy
+            }
+            _ => PermissionsBoundaryAttachmentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PermissionsBoundaryAttachmentType {
+    fn from(value: String) -> PermissionsBoundaryAttachmentType {
+        PermissionsBoundaryAttachmentType::from(value.as_str())
+    }
+}
+
+impl From<PermissionsBoundaryAttachmentType> for String {
+    fn from(value: PermissionsBoundaryAttachmentType) -> String {
+        match value {
+            PermissionsBoundaryAttachmentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PermissionsBoundaryAttachmentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PermissionsBoundaryAttachmentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Contains information about a managed policy.</p> <p>This data type is used as a response element in the <a>CreatePolicy</a>, <a>GetPolicy</a>, and <a>ListPolicies</a> operations. </p> <p>For more information about managed policies, refer to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>Using IAM</i> guide. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Policy {
@@ -7193,7 +12106,7 @@ pub struct Policy {
     /// <p>The number of entities (users, groups, and roles) that the policy is attached to.</p>
     pub attachment_count: Option<i64>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the policy was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<IsoTimestamp>,
     /// <p>The identifier for the version of the policy that is set as the default version.</p>
     pub default_version_id: Option<String>,
     /// <p>A friendly description of the policy.</p> <p>This element is included in the response to the <a>GetPolicy</a> operation. It is not included in the response to the <a>ListPolicies</a> operation. </p>
@@ -7209,13 +12122,16 @@ pub struct Policy {
     /// <p>The friendly name (not ARN) identifying the policy.</p>
     pub policy_name: Option<String>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the policy was last updated.</p> <p>When a policy has only one version, this field contains the date and time when the policy was created. When a policy has more than one version, this field contains the date and time when the most recent policy version was created.</p>
-    pub update_date: Option<String>,
+    pub update_date: Option<IsoTimestamp>,
 }
 
-struct PolicyDeserializer;
+pub(crate) struct PolicyDeserializer;
 impl PolicyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Policy, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Policy, XmlParseError> {
         deserialize_elements::<_, Policy, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Arn" => {
@@ -7274,10 +12190,13 @@ impl PolicyDeserializer {
         })
     }
 }
-struct PolicyDescriptionTypeDeserializer;
+pub(crate) struct PolicyDescriptionTypeDeserializer;
 impl PolicyDescriptionTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7294,10 +12213,10 @@ pub struct PolicyDetail {
     pub policy_name: Option<String>,
 }
 
-struct PolicyDetailDeserializer;
+pub(crate) struct PolicyDetailDeserializer;
 impl PolicyDetailDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PolicyDetail, XmlParseError> {
@@ -7321,10 +12240,10 @@ impl PolicyDetailDeserializer {
         })
     }
 }
-struct PolicyDetailListTypeDeserializer;
+pub(crate) struct PolicyDetailListTypeDeserializer;
 impl PolicyDetailListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PolicyDetail>, XmlParseError> {
@@ -7338,10 +12257,13 @@ impl PolicyDetailListTypeDeserializer {
         })
     }
 }
-struct PolicyDocumentTypeDeserializer;
+pub(crate) struct PolicyDocumentTypeDeserializer;
 impl PolicyDocumentTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = rusoto_core::signature::decode_uri(&characters(stack)?);
         end_element(tag_name, stack)?;
@@ -7349,10 +12271,10 @@ impl PolicyDocumentTypeDeserializer {
         Ok(obj)
     }
 }
-struct PolicyDocumentVersionListTypeDeserializer;
+pub(crate) struct PolicyDocumentVersionListTypeDeserializer;
 impl PolicyDocumentVersionListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PolicyVersion>, XmlParseError> {
@@ -7366,41 +12288,114 @@ impl PolicyDocumentVersionListTypeDeserializer {
         })
     }
 }
-struct PolicyEvaluationDecisionTypeDeserializer;
+pub(crate) struct PolicyEvaluationDecisionTypeDeserializer;
 impl PolicyEvaluationDecisionTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PolicyEvaluationDecisionType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The result of the simulation.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyEvaluationDecisionType {
+    /// <p><code>allowed</code></p>
+    Allowed,
+    /// <p><code>explicitDeny</code></p>
+    ExplicitDeny,
+    /// <p><code>implicitDeny</code></p>
+    ImplicitDeny,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PolicyEvaluationDecisionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyEvaluationDecisionType::Allowed => "allowed",
+            PolicyEvaluationDecisionType::ExplicitDeny => "explicitDeny",
+            PolicyEvaluationDecisionType::ImplicitDeny => "implicitDeny",
+            PolicyEvaluationDecisionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PolicyEvaluationDecisionType {
+    fn from(value: &'a str) -> PolicyEvaluationDecisionType {
+        match value {
+            "allowed" => PolicyEvaluationDecisionType::Allowed,
+            "explicitDeny" => PolicyEvaluationDecisionType::ExplicitDeny,
+            "implicitDeny" => PolicyEvaluationDecisionType::ImplicitDeny,
+            _ => PolicyEvaluationDecisionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyEvaluationDecisionType {
+    fn from(value: String) -> PolicyEvaluationDecisionType {
+        PolicyEvaluationDecisionType::from(value.as_str())
+    }
+}
+
+impl From<PolicyEvaluationDecisionType> for String {
+    fn from(value: PolicyEvaluationDecisionType) -> String {
+        match value {
+            PolicyEvaluationDecisionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PolicyEvaluationDecisionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyEvaluationDecisionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Contains details about the permissions policies that are attached to the specified identity (user, group, or role).</p> <p>This data type is an element of the <a>ListPoliciesGrantingServiceAccessEntry</a> object.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PolicyGrantingServiceAccess {
     /// <p>The name of the entity (user or role) to which the inline policy is attached.</p> <p>This field is null for managed policies. For more information about these policy types, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
     pub entity_name: Option<String>,
     /// <p>The type of entity (user or role) that used the policy to access the service to which the inline policy is attached.</p> <p>This field is null for managed policies. For more information about these policy types, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
-    pub entity_type: Option<String>,
+    pub entity_type: Option<PolicyOwnerEntityType>,
     pub policy_arn: Option<String>,
     /// <p>The policy name.</p>
     pub policy_name: String,
     /// <p>The policy type. For more information about these policy types, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
-    pub policy_type: String,
+    pub policy_type: PolicyType,
 }
 
-struct PolicyGrantingServiceAccessDeserializer;
+pub(crate) struct PolicyGrantingServiceAccessDeserializer;
 impl PolicyGrantingServiceAccessDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PolicyGrantingServiceAccess, XmlParseError> {
-        deserialize_elements::<_, PolicyGrantingServiceAccess, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            PolicyGrantingServiceAccess {
+                entity_name: Default::default(),
+                entity_type: Default::default(),
+                policy_arn: Default::default(),
+                policy_name: Default::default(),
+                policy_type: PolicyType::Unknown(String::new()),
+            },
             |name, stack, obj| {
                 match name {
                     "EntityName" => {
@@ -7433,10 +12428,10 @@ impl PolicyGrantingServiceAccessDeserializer {
         )
     }
 }
-struct PolicyGrantingServiceAccessListTypeDeserializer;
+pub(crate) struct PolicyGrantingServiceAccessListTypeDeserializer;
 impl PolicyGrantingServiceAccessListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PolicyGrantingServiceAccess>, XmlParseError> {
@@ -7461,10 +12456,10 @@ pub struct PolicyGroup {
     pub group_name: Option<String>,
 }
 
-struct PolicyGroupDeserializer;
+pub(crate) struct PolicyGroupDeserializer;
 impl PolicyGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PolicyGroup, XmlParseError> {
@@ -7483,10 +12478,10 @@ impl PolicyGroupDeserializer {
         })
     }
 }
-struct PolicyGroupListTypeDeserializer;
+pub(crate) struct PolicyGroupListTypeDeserializer;
 impl PolicyGroupListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PolicyGroup>, XmlParseError> {
@@ -7500,10 +12495,13 @@ impl PolicyGroupListTypeDeserializer {
         })
     }
 }
-struct PolicyIdentifierTypeDeserializer;
+pub(crate) struct PolicyIdentifierTypeDeserializer;
 impl PolicyIdentifierTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7511,10 +12509,10 @@ impl PolicyIdentifierTypeDeserializer {
         Ok(obj)
     }
 }
-struct PolicyListTypeDeserializer;
+pub(crate) struct PolicyListTypeDeserializer;
 impl PolicyListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Policy>, XmlParseError> {
@@ -7528,10 +12526,10 @@ impl PolicyListTypeDeserializer {
         })
     }
 }
-struct PolicyNameListTypeDeserializer;
+pub(crate) struct PolicyNameListTypeDeserializer;
 impl PolicyNameListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -7545,10 +12543,13 @@ impl PolicyNameListTypeDeserializer {
         })
     }
 }
-struct PolicyNameTypeDeserializer;
+pub(crate) struct PolicyNameTypeDeserializer;
 impl PolicyNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7556,21 +12557,90 @@ impl PolicyNameTypeDeserializer {
         Ok(obj)
     }
 }
-struct PolicyOwnerEntityTypeDeserializer;
+pub(crate) struct PolicyOwnerEntityTypeDeserializer;
 impl PolicyOwnerEntityTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PolicyOwnerEntityType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct PolicyPathTypeDeserializer;
+/// <p>The type of entity (user or role).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyOwnerEntityType {
+    /// <p><code>USER</code></p>
+    User,
+    /// <p><code>ROLE</code></p>
+    Role,
+    /// <p><code>GROUP</code></p>
+    Group,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PolicyOwnerEntityType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyOwnerEntityType::User => "USER",
+            PolicyOwnerEntityType::Role => "ROLE",
+            PolicyOwnerEntityType::Group => "GROUP",
+            PolicyOwnerEntityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PolicyOwnerEntityType {
+    fn from(value: &'a str) -> PolicyOwnerEntityType {
+        match value {
+            "USER" => PolicyOwnerEntityType::User,
+            "ROLE" => PolicyOwnerEntityType::Role,
+            "GROUP" => PolicyOwnerEntityType::Group,
+            _ => PolicyOwnerEntityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyOwnerEntityType {
+    fn from(value: String) -> PolicyOwnerEntityType {
+        PolicyOwnerEntityType::from(value.as_str())
+    }
+}
+
+impl From<PolicyOwnerEntityType> for String {
+    fn from(value: PolicyOwnerEntityType) -> String {
+        match value {
+            PolicyOwnerEntityType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PolicyOwnerEntityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyOwnerEntityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct PolicyPathTypeDeserializer;
 impl PolicyPathTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7587,10 +12657,10 @@ pub struct PolicyRole {
     pub role_name: Option<String>,
 }
 
-struct PolicyRoleDeserializer;
+pub(crate) struct PolicyRoleDeserializer;
 impl PolicyRoleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PolicyRole, XmlParseError> {
@@ -7608,10 +12678,10 @@ impl PolicyRoleDeserializer {
         })
     }
 }
-struct PolicyRoleListTypeDeserializer;
+pub(crate) struct PolicyRoleListTypeDeserializer;
 impl PolicyRoleListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PolicyRole>, XmlParseError> {
@@ -7625,28 +12695,294 @@ impl PolicyRoleListTypeDeserializer {
         })
     }
 }
-struct PolicySourceTypeDeserializer;
+/// <p>The scope to use for filtering the results.</p> <p>To list only AWS managed policies, set <code>Scope</code> to <code>AWS</code>. To list only the customer managed policies in your AWS account, set <code>Scope</code> to <code>Local</code>.</p> <p>This parameter is optional. If it is not included, or if it is set to <code>All</code>, all policies are returned.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyScopeType {
+    /// <p><code>All</code></p>
+    All,
+    /// <p><code>AWS</code></p>
+    Aws,
+    /// <p><code>Local</code></p>
+    Local,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PolicyScopeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyScopeType::All => "All",
+            PolicyScopeType::Aws => "AWS",
+            PolicyScopeType::Local => "Local",
+            PolicyScopeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PolicyScopeType {
+    fn from(value: &'a str) -> PolicyScopeType {
+        match value {
+            "All" => PolicyScopeType::All,
+            "AWS" => PolicyScopeType::Aws,
+            "Local" => PolicyScopeType::Local,
+            _ => PolicyScopeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyScopeType {
+    fn from(value: String) -> PolicyScopeType {
+        PolicyScopeType::from(value.as_str())
+    }
+}
+
+impl From<PolicyScopeType> for String {
+    fn from(value: PolicyScopeType) -> String {
+        match value {
+            PolicyScopeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PolicyScopeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyScopeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct PolicySourceTypeDeserializer;
 impl PolicySourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PolicySourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct PolicyTypeDeserializer;
+/// <p>The type of the policy.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicySourceType {
+    /// <p><code>user</code></p>
+    User,
+    /// <p><code>group</code></p>
+    Group,
+    /// <p><code>role</code></p>
+    Role,
+    /// <p><code>aws-managed</code></p>
+    AwsManaged,
+    /// <p><code>user-managed</code></p>
+    UserManaged,
+    /// <p><code>resource</code></p>
+    Resource,
+    /// <p><code>none</code></p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PolicySourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicySourceType::User => "user",
+            PolicySourceType::Group => "group",
+            PolicySourceType::Role => "role",
+            PolicySourceType::AwsManaged => "aws-managed",
+            PolicySourceType::UserManaged => "user-managed",
+            PolicySourceType::Resource => "resource",
+            PolicySourceType::None => "none",
+            PolicySourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PolicySourceType {
+    fn from(value: &'a str) -> PolicySourceType {
+        match value {
+            "user" => PolicySourceType::User,
+            "group" => PolicySourceType::Group,
+            "role" => PolicySourceType::Role,
+            "aws-managed" => PolicySourceType::AwsManaged,
+            "user-managed" => PolicySourceType::UserManaged,
+            "resource" => PolicySourceType::Resource,
+            "none" => PolicySourceType::None,
+            _ => PolicySourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicySourceType {
+    fn from(value: String) -> PolicySourceType {
+        PolicySourceType::from(value.as_str())
+    }
+}
+
+impl From<PolicySourceType> for String {
+    fn from(value: PolicySourceType) -> String {
+        match value {
+            PolicySourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PolicySourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicySourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct PolicyTypeDeserializer;
 impl PolicyTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<PolicyType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The policy usage type that indicates whether the policy is used as a permissions policy or as the permissions boundary for an entity.</p> <p>For more information about permissions boundaries, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_boundaries.html">Permissions boundaries for IAM identities </a> in the <i>IAM User Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyUsageType {
+    /// <p><code>PermissionsPolicy</code></p>
+    PermissionsPolicy,
+    /// <p><code>PermissionsBoundary</code></p>
+    PermissionsBoundary,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PolicyUsageType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyUsageType::PermissionsPolicy => "PermissionsPolicy",
+            PolicyUsageType::PermissionsBoundary => "PermissionsBoundary",
+            PolicyUsageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PolicyUsageType {
+    fn from(value: &'a str) -> PolicyUsageType {
+        match value {
+            "PermissionsPolicy" => PolicyUsageType::PermissionsPolicy,
+            "PermissionsBoundary" => PolicyUsageType::PermissionsBoundary,
+            _ => PolicyUsageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyUsageType {
+    fn from(value: String) -> PolicyUsageType {
+        PolicyUsageType::from(value.as_str())
+    }
+}
+
+impl From<PolicyUsageType> for String {
+    fn from(value: PolicyUsageType) -> String {
+        match value {
+            PolicyUsageType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PolicyUsageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyUsageType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+/// <p>The policy type. For more information about these policy types, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>IAM User Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyType {
+    /// <p><code>INLINE</code></p>
+    Inline,
+    /// <p><code>MANAGED</code></p>
+    Managed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PolicyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyType::Inline => "INLINE",
+            PolicyType::Managed => "MANAGED",
+            PolicyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PolicyType {
+    fn from(value: &'a str) -> PolicyType {
+        match value {
+            "INLINE" => PolicyType::Inline,
+            "MANAGED" => PolicyType::Managed,
+            _ => PolicyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyType {
+    fn from(value: String) -> PolicyType {
+        PolicyType::from(value.as_str())
+    }
+}
+
+impl From<PolicyType> for String {
+    fn from(value: PolicyType) -> String {
+        match value {
+            PolicyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PolicyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Contains information about a user that a managed policy is attached to.</p> <p>This data type is used as a response element in the <a>ListEntitiesForPolicy</a> operation. </p> <p>For more information about managed policies, refer to <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/policies-managed-vs-inline.html">Managed Policies and Inline Policies</a> in the <i>Using IAM</i> guide. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PolicyUser {
@@ -7656,10 +12992,10 @@ pub struct PolicyUser {
     pub user_name: Option<String>,
 }
 
-struct PolicyUserDeserializer;
+pub(crate) struct PolicyUserDeserializer;
 impl PolicyUserDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PolicyUser, XmlParseError> {
@@ -7677,10 +13013,10 @@ impl PolicyUserDeserializer {
         })
     }
 }
-struct PolicyUserListTypeDeserializer;
+pub(crate) struct PolicyUserListTypeDeserializer;
 impl PolicyUserListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PolicyUser>, XmlParseError> {
@@ -7698,7 +13034,7 @@ impl PolicyUserListTypeDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PolicyVersion {
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the policy version was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<IsoTimestamp>,
     /// <p>The policy document.</p> <p>The policy document is returned in the response to the <a>GetPolicyVersion</a> and <a>GetAccountAuthorizationDetails</a> operations. It is not returned in the response to the <a>CreatePolicyVersion</a> or <a>ListPolicyVersions</a> operations. </p> <p>The policy document returned in this structure is URL-encoded compliant with <a href="https://tools.ietf.org/html/rfc3986">RFC 3986</a>. You can use a URL decoding method to convert the policy back to plain JSON text. For example, if you use Java, you can use the <code>decode</code> method of the <code>java.net.URLDecoder</code> utility class in the Java SDK. Other languages and SDKs provide similar functionality.</p>
     pub document: Option<String>,
     /// <p>Specifies whether the policy version is set as the policy's default version.</p>
@@ -7707,10 +13043,10 @@ pub struct PolicyVersion {
     pub version_id: Option<String>,
 }
 
-struct PolicyVersionDeserializer;
+pub(crate) struct PolicyVersionDeserializer;
 impl PolicyVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PolicyVersion, XmlParseError> {
@@ -7742,10 +13078,13 @@ impl PolicyVersionDeserializer {
         })
     }
 }
-struct PolicyVersionIdTypeDeserializer;
+pub(crate) struct PolicyVersionIdTypeDeserializer;
 impl PolicyVersionIdTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7762,10 +13101,10 @@ pub struct Position {
     pub line: Option<i64>,
 }
 
-struct PositionDeserializer;
+pub(crate) struct PositionDeserializer;
 impl PositionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Position, XmlParseError> {
@@ -7783,10 +13122,13 @@ impl PositionDeserializer {
         })
     }
 }
-struct PublicKeyFingerprintTypeDeserializer;
+pub(crate) struct PublicKeyFingerprintTypeDeserializer;
 impl PublicKeyFingerprintTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7794,10 +13136,13 @@ impl PublicKeyFingerprintTypeDeserializer {
         Ok(obj)
     }
 }
-struct PublicKeyIdTypeDeserializer;
+pub(crate) struct PublicKeyIdTypeDeserializer;
 impl PublicKeyIdTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7805,10 +13150,13 @@ impl PublicKeyIdTypeDeserializer {
         Ok(obj)
     }
 }
-struct PublicKeyMaterialTypeDeserializer;
+pub(crate) struct PublicKeyMaterialTypeDeserializer;
 impl PublicKeyMaterialTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7826,6 +13174,56 @@ pub struct PutGroupPolicyRequest {
     pub policy_name: String,
 }
 
+impl PutGroupPolicyRequest {
+    /// Returns a builder for `PutGroupPolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutGroupPolicyRequestBuilder {
+        PutGroupPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutGroupPolicyRequest`](struct.PutGroupPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutGroupPolicyRequestBuilder {
+    group_name: Option<String>,
+    policy_document: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl PutGroupPolicyRequestBuilder {
+    /// Sets the [`group_name`](struct.PutGroupPolicyRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_document`](struct.PutGroupPolicyRequest.html#structfield.policy_document) member.
+    pub fn policy_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.PutGroupPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutGroupPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutGroupPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutGroupPolicyRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            policy_document: self
+                .policy_document
+                .ok_or(::rusoto_core::BuildError::missing("policy_document"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// Serialize `PutGroupPolicyRequest` contents to a `SignedRequest`.
 struct PutGroupPolicyRequestSerializer;
 impl PutGroupPolicyRequestSerializer {
@@ -7852,17 +13250,59 @@ pub struct PutRolePermissionsBoundaryRequest {
     pub role_name: String,
 }
 
-/// Serialize `PutRolePermissionsBoundaryRequest` contents to a `SignedRequest`.
-struct PutRolePermissionsBoundaryRequestSerializer;
-impl PutRolePermissionsBoundaryRequestSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &PutRolePermissionsBoundaryRequest) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl PutRolePermissionsBoundaryRequest {
+    /// Returns a builder for `PutRolePermissionsBoundaryRequest`, checking its required members when built.
+    pub fn builder() -> PutRolePermissionsBoundaryRequestBuilder {
+        PutRolePermissionsBoundaryRequestBuilder::default()
+    }
+}
 
-        params.put(
-            &format!("{}{}", prefix, "PermissionsBoundary"),
+/// Builds a [`PutRolePermissionsBoundaryRequest`](struct.PutRolePermissionsBoundaryRequest.html).
+#[derive(Default, Debug)]
+pub struct PutRolePermissionsBoundaryRequestBuilder {
+    permissions_boundary: Option<String>,
+    role_name: Option<String>,
+}
+
+impl PutRolePermissionsBoundaryRequestBuilder {
+    /// Sets the [`permissions_boundary`](struct.PutRolePermissionsBoundaryRequest.html#structfield.permissions_boundary) member.
+    pub fn permissions_boundary<T: Into<String>>(mut self, value: T) -> Self {
+        self.permissions_boundary = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.PutRolePermissionsBoundaryRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRolePermissionsBoundaryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutRolePermissionsBoundaryRequest, ::rusoto_core::BuildError> {
+        Ok(PutRolePermissionsBoundaryRequest {
+            permissions_boundary: self
+                .permissions_boundary
+                .ok_or(::rusoto_core::BuildError::missing("permissions_boundary"))?,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
+/// Serialize `PutRolePermissionsBoundaryRequest` contents to a `SignedRequest`.
+struct PutRolePermissionsBoundaryRequestSerializer;
+impl PutRolePermissionsBoundaryRequestSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &PutRolePermissionsBoundaryRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        params.put(
+            &format!("{}{}", prefix, "PermissionsBoundary"),
             &obj.permissions_boundary,
         );
         params.put(&format!("{}{}", prefix, "RoleName"), &obj.role_name);
@@ -7879,6 +13319,56 @@ pub struct PutRolePolicyRequest {
     pub role_name: String,
 }
 
+impl PutRolePolicyRequest {
+    /// Returns a builder for `PutRolePolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutRolePolicyRequestBuilder {
+        PutRolePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutRolePolicyRequest`](struct.PutRolePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutRolePolicyRequestBuilder {
+    policy_document: Option<String>,
+    policy_name: Option<String>,
+    role_name: Option<String>,
+}
+
+impl PutRolePolicyRequestBuilder {
+    /// Sets the [`policy_document`](struct.PutRolePolicyRequest.html#structfield.policy_document) member.
+    pub fn policy_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.PutRolePolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.PutRolePolicyRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRolePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutRolePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutRolePolicyRequest {
+            policy_document: self
+                .policy_document
+                .ok_or(::rusoto_core::BuildError::missing("policy_document"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `PutRolePolicyRequest` contents to a `SignedRequest`.
 struct PutRolePolicyRequestSerializer;
 impl PutRolePolicyRequestSerializer {
@@ -7905,6 +13395,48 @@ pub struct PutUserPermissionsBoundaryRequest {
     pub user_name: String,
 }
 
+impl PutUserPermissionsBoundaryRequest {
+    /// Returns a builder for `PutUserPermissionsBoundaryRequest`, checking its required members when built.
+    pub fn builder() -> PutUserPermissionsBoundaryRequestBuilder {
+        PutUserPermissionsBoundaryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutUserPermissionsBoundaryRequest`](struct.PutUserPermissionsBoundaryRequest.html).
+#[derive(Default, Debug)]
+pub struct PutUserPermissionsBoundaryRequestBuilder {
+    permissions_boundary: Option<String>,
+    user_name: Option<String>,
+}
+
+impl PutUserPermissionsBoundaryRequestBuilder {
+    /// Sets the [`permissions_boundary`](struct.PutUserPermissionsBoundaryRequest.html#structfield.permissions_boundary) member.
+    pub fn permissions_boundary<T: Into<String>>(mut self, value: T) -> Self {
+        self.permissions_boundary = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.PutUserPermissionsBoundaryRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutUserPermissionsBoundaryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutUserPermissionsBoundaryRequest, ::rusoto_core::BuildError> {
+        Ok(PutUserPermissionsBoundaryRequest {
+            permissions_boundary: self
+                .permissions_boundary
+                .ok_or(::rusoto_core::BuildError::missing("permissions_boundary"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `PutUserPermissionsBoundaryRequest` contents to a `SignedRequest`.
 struct PutUserPermissionsBoundaryRequestSerializer;
 impl PutUserPermissionsBoundaryRequestSerializer {
@@ -7932,6 +13464,56 @@ pub struct PutUserPolicyRequest {
     pub user_name: String,
 }
 
+impl PutUserPolicyRequest {
+    /// Returns a builder for `PutUserPolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutUserPolicyRequestBuilder {
+        PutUserPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutUserPolicyRequest`](struct.PutUserPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutUserPolicyRequestBuilder {
+    policy_document: Option<String>,
+    policy_name: Option<String>,
+    user_name: Option<String>,
+}
+
+impl PutUserPolicyRequestBuilder {
+    /// Sets the [`policy_document`](struct.PutUserPolicyRequest.html#structfield.policy_document) member.
+    pub fn policy_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.PutUserPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.PutUserPolicyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutUserPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutUserPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutUserPolicyRequest {
+            policy_document: self
+                .policy_document
+                .ok_or(::rusoto_core::BuildError::missing("policy_document"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `PutUserPolicyRequest` contents to a `SignedRequest`.
 struct PutUserPolicyRequestSerializer;
 impl PutUserPolicyRequestSerializer {
@@ -7950,10 +13532,13 @@ impl PutUserPolicyRequestSerializer {
     }
 }
 
-struct ReasonTypeDeserializer;
+pub(crate) struct ReasonTypeDeserializer;
 impl ReasonTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7961,10 +13546,13 @@ impl ReasonTypeDeserializer {
         Ok(obj)
     }
 }
-struct RegionNameTypeDeserializer;
+pub(crate) struct RegionNameTypeDeserializer;
 impl RegionNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7980,6 +13568,51 @@ pub struct RemoveClientIDFromOpenIDConnectProviderRequest {
     pub open_id_connect_provider_arn: String,
 }
 
+impl RemoveClientIDFromOpenIDConnectProviderRequest {
+    /// Returns a builder for `RemoveClientIDFromOpenIDConnectProviderRequest`, checking its required members when built.
+    pub fn builder() -> RemoveClientIDFromOpenIDConnectProviderRequestBuilder {
+        RemoveClientIDFromOpenIDConnectProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveClientIDFromOpenIDConnectProviderRequest`](struct.RemoveClientIDFromOpenIDConnectProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveClientIDFromOpenIDConnectProviderRequestBuilder {
+    client_id: Option<String>,
+    open_id_connect_provider_arn: Option<String>,
+}
+
+impl RemoveClientIDFromOpenIDConnectProviderRequestBuilder {
+    /// Sets the [`client_id`](struct.RemoveClientIDFromOpenIDConnectProviderRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`open_id_connect_provider_arn`](struct.RemoveClientIDFromOpenIDConnectProviderRequest.html#structfield.open_id_connect_provider_arn) member.
+    pub fn open_id_connect_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.open_id_connect_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveClientIDFromOpenIDConnectProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        RemoveClientIDFromOpenIDConnectProviderRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(RemoveClientIDFromOpenIDConnectProviderRequest {
+            client_id: self
+                .client_id
+                .ok_or(::rusoto_core::BuildError::missing("client_id"))?,
+            open_id_connect_provider_arn: self.open_id_connect_provider_arn.ok_or(
+                ::rusoto_core::BuildError::missing("open_id_connect_provider_arn"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `RemoveClientIDFromOpenIDConnectProviderRequest` contents to a `SignedRequest`.
 struct RemoveClientIDFromOpenIDConnectProviderRequestSerializer;
 impl RemoveClientIDFromOpenIDConnectProviderRequestSerializer {
@@ -8009,6 +13642,49 @@ pub struct RemoveRoleFromInstanceProfileRequest {
     pub role_name: String,
 }
 
+impl RemoveRoleFromInstanceProfileRequest {
+    /// Returns a builder for `RemoveRoleFromInstanceProfileRequest`, checking its required members when built.
+    pub fn builder() -> RemoveRoleFromInstanceProfileRequestBuilder {
+        RemoveRoleFromInstanceProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveRoleFromInstanceProfileRequest`](struct.RemoveRoleFromInstanceProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveRoleFromInstanceProfileRequestBuilder {
+    instance_profile_name: Option<String>,
+    role_name: Option<String>,
+}
+
+impl RemoveRoleFromInstanceProfileRequestBuilder {
+    /// Sets the [`instance_profile_name`](struct.RemoveRoleFromInstanceProfileRequest.html#structfield.instance_profile_name) member.
+    pub fn instance_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.RemoveRoleFromInstanceProfileRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveRoleFromInstanceProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveRoleFromInstanceProfileRequest, ::rusoto_core::BuildError>
+    {
+        Ok(RemoveRoleFromInstanceProfileRequest {
+            instance_profile_name: self
+                .instance_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_profile_name"))?,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveRoleFromInstanceProfileRequest` contents to a `SignedRequest`.
 struct RemoveRoleFromInstanceProfileRequestSerializer;
 impl RemoveRoleFromInstanceProfileRequestSerializer {
@@ -8034,6 +13710,48 @@ pub struct RemoveUserFromGroupRequest {
     pub user_name: String,
 }
 
+impl RemoveUserFromGroupRequest {
+    /// Returns a builder for `RemoveUserFromGroupRequest`, checking its required members when built.
+    pub fn builder() -> RemoveUserFromGroupRequestBuilder {
+        RemoveUserFromGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveUserFromGroupRequest`](struct.RemoveUserFromGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveUserFromGroupRequestBuilder {
+    group_name: Option<String>,
+    user_name: Option<String>,
+}
+
+impl RemoveUserFromGroupRequestBuilder {
+    /// Sets the [`group_name`](struct.RemoveUserFromGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.RemoveUserFromGroupRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveUserFromGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveUserFromGroupRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveUserFromGroupRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveUserFromGroupRequest` contents to a `SignedRequest`.
 struct RemoveUserFromGroupRequestSerializer;
 impl RemoveUserFromGroupRequestSerializer {
@@ -8048,10 +13766,10 @@ impl RemoveUserFromGroupRequestSerializer {
     }
 }
 
-struct ReportContentTypeDeserializer;
+pub(crate) struct ReportContentTypeDeserializer;
 impl ReportContentTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<bytes::Bytes, XmlParseError> {
@@ -8062,21 +13780,82 @@ impl ReportContentTypeDeserializer {
         Ok(obj)
     }
 }
-struct ReportFormatTypeDeserializer;
+pub(crate) struct ReportFormatTypeDeserializer;
 impl ReportFormatTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReportFormatType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ReportStateDescriptionTypeDeserializer;
+/// <p>The format (MIME type) of the credential report.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReportFormatType {
+    /// <p><code>text/csv</code></p>
+    TextCsv,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReportFormatType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportFormatType::TextCsv => "text/csv",
+            ReportFormatType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReportFormatType {
+    fn from(value: &'a str) -> ReportFormatType {
+        match value {
+            "text/csv" => ReportFormatType::TextCsv,
+            _ => ReportFormatType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportFormatType {
+    fn from(value: String) -> ReportFormatType {
+        ReportFormatType::from(value.as_str())
+    }
+}
+
+impl From<ReportFormatType> for String {
+    fn from(value: ReportFormatType) -> String {
+        match value {
+            ReportFormatType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReportFormatType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportFormatType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ReportStateDescriptionTypeDeserializer;
 impl ReportStateDescriptionTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -8084,17 +13863,83 @@ impl ReportStateDescriptionTypeDeserializer {
         Ok(obj)
     }
 }
-struct ReportStateTypeDeserializer;
+pub(crate) struct ReportStateTypeDeserializer;
 impl ReportStateTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReportStateType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>Information about the state of the credential report.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReportStateType {
+    /// <p><code>STARTED</code></p>
+    Started,
+    /// <p><code>INPROGRESS</code></p>
+    Inprogress,
+    /// <p><code>COMPLETE</code></p>
+    Complete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReportStateType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportStateType::Started => "STARTED",
+            ReportStateType::Inprogress => "INPROGRESS",
+            ReportStateType::Complete => "COMPLETE",
+            ReportStateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReportStateType {
+    fn from(value: &'a str) -> ReportStateType {
+        match value {
+            "STARTED" => ReportStateType::Started,
+            "INPROGRESS" => ReportStateType::Inprogress,
+            "COMPLETE" => ReportStateType::Complete,
+            _ => ReportStateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportStateType {
+    fn from(value: String) -> ReportStateType {
+        ReportStateType::from(value.as_str())
+    }
+}
+
+impl From<ReportStateType> for String {
+    fn from(value: ReportStateType) -> String {
+        match value {
+            ReportStateType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReportStateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportStateType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ResetServiceSpecificCredentialRequest {
     /// <p>The unique identifier of the service-specific credential.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
@@ -8103,6 +13948,47 @@ pub struct ResetServiceSpecificCredentialRequest {
     pub user_name: Option<String>,
 }
 
+impl ResetServiceSpecificCredentialRequest {
+    /// Returns a builder for `ResetServiceSpecificCredentialRequest`, checking its required members when built.
+    pub fn builder() -> ResetServiceSpecificCredentialRequestBuilder {
+        ResetServiceSpecificCredentialRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ResetServiceSpecificCredentialRequest`](struct.ResetServiceSpecificCredentialRequest.html).
+#[derive(Default, Debug)]
+pub struct ResetServiceSpecificCredentialRequestBuilder {
+    service_specific_credential_id: Option<String>,
+    user_name: Option<String>,
+}
+
+impl ResetServiceSpecificCredentialRequestBuilder {
+    /// Sets the [`service_specific_credential_id`](struct.ResetServiceSpecificCredentialRequest.html#structfield.service_specific_credential_id) member.
+    pub fn service_specific_credential_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_specific_credential_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ResetServiceSpecificCredentialRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetServiceSpecificCredentialRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResetServiceSpecificCredentialRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ResetServiceSpecificCredentialRequest {
+            service_specific_credential_id: self.service_specific_credential_id.ok_or(
+                ::rusoto_core::BuildError::missing("service_specific_credential_id"),
+            )?,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `ResetServiceSpecificCredentialRequest` contents to a `SignedRequest`.
 struct ResetServiceSpecificCredentialRequestSerializer;
 impl ResetServiceSpecificCredentialRequestSerializer {
@@ -8128,10 +14014,10 @@ pub struct ResetServiceSpecificCredentialResponse {
     pub service_specific_credential: Option<ServiceSpecificCredential>,
 }
 
-struct ResetServiceSpecificCredentialResponseDeserializer;
+pub(crate) struct ResetServiceSpecificCredentialResponseDeserializer;
 impl ResetServiceSpecificCredentialResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResetServiceSpecificCredentialResponse, XmlParseError> {
@@ -8166,10 +14052,13 @@ impl ResourceNameListTypeSerializer {
     }
 }
 
-struct ResourceNameTypeDeserializer;
+pub(crate) struct ResourceNameTypeDeserializer;
 impl ResourceNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -8178,12 +14067,13 @@ impl ResourceNameTypeDeserializer {
     }
 }
 /// <p>Contains the result of the simulation of a single API operation call on a single resource.</p> <p>This data type is used by a member of the <a>EvaluationResult</a> data type.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ResourceSpecificResult {
     /// <p>Additional details about the results of the evaluation decision. When there are both IAM policies and resource policies, this parameter explains how each set of policies contributes to the final evaluation decision. When simulating cross-account access to a resource, both the resource-based policy and the caller's IAM policy must grant access.</p>
-    pub eval_decision_details: Option<::std::collections::HashMap<String, String>>,
+    pub eval_decision_details:
+        Option<::std::collections::HashMap<String, PolicyEvaluationDecisionType>>,
     /// <p>The result of the simulation of the simulated API operation on the resource specified in <code>EvalResourceName</code>.</p>
-    pub eval_resource_decision: String,
+    pub eval_resource_decision: PolicyEvaluationDecisionType,
     /// <p>The name of the simulated resource, in Amazon Resource Name (ARN) format.</p>
     pub eval_resource_name: String,
     /// <p>A list of the statements in the input policies that determine the result for this part of the simulation. Remember that even if multiple statements allow the operation on the resource, if <i>any</i> statement denies that operation, then the explicit deny overrides any allow. In addition, the deny statement is the only entry included in the result.</p>
@@ -8192,56 +14082,67 @@ pub struct ResourceSpecificResult {
     pub missing_context_values: Option<Vec<String>>,
 }
 
-struct ResourceSpecificResultDeserializer;
+pub(crate) struct ResourceSpecificResultDeserializer;
 impl ResourceSpecificResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourceSpecificResult, XmlParseError> {
-        deserialize_elements::<_, ResourceSpecificResult, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "EvalDecisionDetails" => {
-                    obj.eval_decision_details =
-                        Some(EvalDecisionDetailsTypeDeserializer::deserialize(
-                            "EvalDecisionDetails",
-                            stack,
-                        )?);
-                }
-                "EvalResourceDecision" => {
-                    obj.eval_resource_decision =
-                        PolicyEvaluationDecisionTypeDeserializer::deserialize(
-                            "EvalResourceDecision",
-                            stack,
-                        )?;
-                }
-                "EvalResourceName" => {
-                    obj.eval_resource_name =
-                        ResourceNameTypeDeserializer::deserialize("EvalResourceName", stack)?;
-                }
-                "MatchedStatements" => {
-                    obj.matched_statements.get_or_insert(vec![]).extend(
-                        StatementListTypeDeserializer::deserialize("MatchedStatements", stack)?,
-                    );
-                }
-                "MissingContextValues" => {
-                    obj.missing_context_values.get_or_insert(vec![]).extend(
-                        ContextKeyNamesResultListTypeDeserializer::deserialize(
-                            "MissingContextValues",
-                            stack,
-                        )?,
-                    );
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            ResourceSpecificResult {
+                eval_decision_details: Default::default(),
+                eval_resource_decision: PolicyEvaluationDecisionType::Unknown(String::new()),
+                eval_resource_name: Default::default(),
+                matched_statements: Default::default(),
+                missing_context_values: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "EvalDecisionDetails" => {
+                        obj.eval_decision_details =
+                            Some(EvalDecisionDetailsTypeDeserializer::deserialize(
+                                "EvalDecisionDetails",
+                                stack,
+                            )?);
+                    }
+                    "EvalResourceDecision" => {
+                        obj.eval_resource_decision =
+                            PolicyEvaluationDecisionTypeDeserializer::deserialize(
+                                "EvalResourceDecision",
+                                stack,
+                            )?;
+                    }
+                    "EvalResourceName" => {
+                        obj.eval_resource_name =
+                            ResourceNameTypeDeserializer::deserialize("EvalResourceName", stack)?;
+                    }
+                    "MatchedStatements" => {
+                        obj.matched_statements.get_or_insert(vec![]).extend(
+                            StatementListTypeDeserializer::deserialize("MatchedStatements", stack)?,
+                        );
+                    }
+                    "MissingContextValues" => {
+                        obj.missing_context_values.get_or_insert(vec![]).extend(
+                            ContextKeyNamesResultListTypeDeserializer::deserialize(
+                                "MissingContextValues",
+                                stack,
+                            )?,
+                        );
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct ResourceSpecificResultListTypeDeserializer;
+pub(crate) struct ResourceSpecificResultListTypeDeserializer;
 impl ResourceSpecificResultListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ResourceSpecificResult>, XmlParseError> {
@@ -8257,10 +14158,13 @@ impl ResourceSpecificResultListTypeDeserializer {
         })
     }
 }
-struct ResponseMarkerTypeDeserializer;
+pub(crate) struct ResponseMarkerTypeDeserializer;
 impl ResponseMarkerTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -8280,6 +14184,66 @@ pub struct ResyncMFADeviceRequest {
     pub user_name: String,
 }
 
+impl ResyncMFADeviceRequest {
+    /// Returns a builder for `ResyncMFADeviceRequest`, checking its required members when built.
+    pub fn builder() -> ResyncMFADeviceRequestBuilder {
+        ResyncMFADeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ResyncMFADeviceRequest`](struct.ResyncMFADeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct ResyncMFADeviceRequestBuilder {
+    authentication_code_1: Option<String>,
+    authentication_code_2: Option<String>,
+    serial_number: Option<String>,
+    user_name: Option<String>,
+}
+
+impl ResyncMFADeviceRequestBuilder {
+    /// Sets the [`authentication_code_1`](struct.ResyncMFADeviceRequest.html#structfield.authentication_code_1) member.
+    pub fn authentication_code_1<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_code_1 = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authentication_code_2`](struct.ResyncMFADeviceRequest.html#structfield.authentication_code_2) member.
+    pub fn authentication_code_2<T: Into<String>>(mut self, value: T) -> Self {
+        self.authentication_code_2 = Some(value.into());
+        self
+    }
+
+    /// Sets the [`serial_number`](struct.ResyncMFADeviceRequest.html#structfield.serial_number) member.
+    pub fn serial_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.serial_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.ResyncMFADeviceRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResyncMFADeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ResyncMFADeviceRequest, ::rusoto_core::BuildError> {
+        Ok(ResyncMFADeviceRequest {
+            authentication_code_1: self
+                .authentication_code_1
+                .ok_or(::rusoto_core::BuildError::missing("authentication_code_1"))?,
+            authentication_code_2: self
+                .authentication_code_2
+                .ok_or(::rusoto_core::BuildError::missing("authentication_code_2"))?,
+            serial_number: self
+                .serial_number
+                .ok_or(::rusoto_core::BuildError::missing("serial_number"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `ResyncMFADeviceRequest` contents to a `SignedRequest`.
 struct ResyncMFADeviceRequestSerializer;
 impl ResyncMFADeviceRequestSerializer {
@@ -8310,7 +14274,7 @@ pub struct Role {
     /// <p>The policy that grants an entity permission to assume the role.</p>
     pub assume_role_policy_document: Option<String>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the role was created.</p>
-    pub create_date: String,
+    pub create_date: IsoTimestamp,
     /// <p>A description of the role that you provide.</p>
     pub description: Option<String>,
     /// <p>The maximum session duration (in seconds) for the specified role. Anyone who uses the AWS CLI, or API to assume the role can specify the duration using the optional <code>DurationSeconds</code> API parameter or <code>duration-seconds</code> CLI parameter.</p>
@@ -8327,10 +14291,13 @@ pub struct Role {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct RoleDeserializer;
+pub(crate) struct RoleDeserializer;
 impl RoleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Role, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Role, XmlParseError> {
         deserialize_elements::<_, Role, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Arn" => {
@@ -8386,10 +14353,13 @@ impl RoleDeserializer {
         })
     }
 }
-struct RoleDescriptionTypeDeserializer;
+pub(crate) struct RoleDescriptionTypeDeserializer;
 impl RoleDescriptionTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -8406,7 +14376,7 @@ pub struct RoleDetail {
     /// <p>A list of managed policies attached to the role. These policies are the role's access (permissions) policies.</p>
     pub attached_managed_policies: Option<Vec<AttachedPolicy>>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the role was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<IsoTimestamp>,
     /// <p>A list of instance profiles that contain this role.</p>
     pub instance_profile_list: Option<Vec<InstanceProfile>>,
     /// <p>The path to the role. For more information about paths, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide.</p>
@@ -8423,10 +14393,10 @@ pub struct RoleDetail {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct RoleDetailDeserializer;
+pub(crate) struct RoleDetailDeserializer;
 impl RoleDetailDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RoleDetail, XmlParseError> {
@@ -8493,10 +14463,10 @@ impl RoleDetailDeserializer {
         })
     }
 }
-struct RoleDetailListTypeDeserializer;
+pub(crate) struct RoleDetailListTypeDeserializer;
 impl RoleDetailListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<RoleDetail>, XmlParseError> {
@@ -8510,10 +14480,10 @@ impl RoleDetailListTypeDeserializer {
         })
     }
 }
-struct RoleListTypeDeserializer;
+pub(crate) struct RoleListTypeDeserializer;
 impl RoleListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Role>, XmlParseError> {
@@ -8527,10 +14497,13 @@ impl RoleListTypeDeserializer {
         })
     }
 }
-struct RoleMaxSessionDurationTypeDeserializer;
+pub(crate) struct RoleMaxSessionDurationTypeDeserializer;
 impl RoleMaxSessionDurationTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -8538,10 +14511,13 @@ impl RoleMaxSessionDurationTypeDeserializer {
         Ok(obj)
     }
 }
-struct RoleNameTypeDeserializer;
+pub(crate) struct RoleNameTypeDeserializer;
 impl RoleNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -8549,10 +14525,10 @@ impl RoleNameTypeDeserializer {
         Ok(obj)
     }
 }
-struct RoleUsageListTypeDeserializer;
+pub(crate) struct RoleUsageListTypeDeserializer;
 impl RoleUsageListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<RoleUsageType>, XmlParseError> {
@@ -8575,10 +14551,10 @@ pub struct RoleUsageType {
     pub resources: Option<Vec<String>>,
 }
 
-struct RoleUsageTypeDeserializer;
+pub(crate) struct RoleUsageTypeDeserializer;
 impl RoleUsageTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RoleUsageType, XmlParseError> {
@@ -8598,10 +14574,13 @@ impl RoleUsageTypeDeserializer {
         })
     }
 }
-struct SAMLMetadataDocumentTypeDeserializer;
+pub(crate) struct SAMLMetadataDocumentTypeDeserializer;
 impl SAMLMetadataDocumentTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -8615,15 +14594,15 @@ pub struct SAMLProviderListEntry {
     /// <p>The Amazon Resource Name (ARN) of the SAML provider.</p>
     pub arn: Option<String>,
     /// <p>The date and time when the SAML provider was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<IsoTimestamp>,
     /// <p>The expiration date and time for the SAML provider.</p>
-    pub valid_until: Option<String>,
+    pub valid_until: Option<IsoTimestamp>,
 }
 
-struct SAMLProviderListEntryDeserializer;
+pub(crate) struct SAMLProviderListEntryDeserializer;
 impl SAMLProviderListEntryDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SAMLProviderListEntry, XmlParseError> {
@@ -8644,10 +14623,10 @@ impl SAMLProviderListEntryDeserializer {
         })
     }
 }
-struct SAMLProviderListTypeDeserializer;
+pub(crate) struct SAMLProviderListTypeDeserializer;
 impl SAMLProviderListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SAMLProviderListEntry>, XmlParseError> {
@@ -8664,7 +14643,7 @@ impl SAMLProviderListTypeDeserializer {
     }
 }
 /// <p>Contains information about an SSH public key.</p> <p>This data type is used as a response element in the <a>GetSSHPublicKey</a> and <a>UploadSSHPublicKey</a> operations. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SSHPublicKey {
     /// <p>The MD5 message digest of the SSH public key.</p>
     pub fingerprint: String,
@@ -8673,53 +14652,70 @@ pub struct SSHPublicKey {
     /// <p>The unique identifier for the SSH public key.</p>
     pub ssh_public_key_id: String,
     /// <p>The status of the SSH public key. <code>Active</code> means that the key can be used for authentication with an AWS CodeCommit repository. <code>Inactive</code> means that the key cannot be used.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the SSH public key was uploaded.</p>
-    pub upload_date: Option<String>,
+    pub upload_date: Option<IsoTimestamp>,
     /// <p>The name of the IAM user associated with the SSH public key.</p>
     pub user_name: String,
 }
 
-struct SSHPublicKeyDeserializer;
+pub(crate) struct SSHPublicKeyDeserializer;
 impl SSHPublicKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SSHPublicKey, XmlParseError> {
-        deserialize_elements::<_, SSHPublicKey, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "Fingerprint" => {
-                    obj.fingerprint =
-                        PublicKeyFingerprintTypeDeserializer::deserialize("Fingerprint", stack)?;
-                }
-                "SSHPublicKeyBody" => {
-                    obj.ssh_public_key_body =
-                        PublicKeyMaterialTypeDeserializer::deserialize("SSHPublicKeyBody", stack)?;
-                }
-                "SSHPublicKeyId" => {
-                    obj.ssh_public_key_id =
-                        PublicKeyIdTypeDeserializer::deserialize("SSHPublicKeyId", stack)?;
-                }
-                "Status" => {
-                    obj.status = StatusTypeDeserializer::deserialize("Status", stack)?;
-                }
-                "UploadDate" => {
-                    obj.upload_date = Some(DateTypeDeserializer::deserialize("UploadDate", stack)?);
-                }
-                "UserName" => {
-                    obj.user_name = UserNameTypeDeserializer::deserialize("UserName", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            SSHPublicKey {
+                fingerprint: Default::default(),
+                ssh_public_key_body: Default::default(),
+                ssh_public_key_id: Default::default(),
+                status: StatusType::Unknown(String::new()),
+                upload_date: Default::default(),
+                user_name: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "Fingerprint" => {
+                        obj.fingerprint = PublicKeyFingerprintTypeDeserializer::deserialize(
+                            "Fingerprint",
+                            stack,
+                        )?;
+                    }
+                    "SSHPublicKeyBody" => {
+                        obj.ssh_public_key_body = PublicKeyMaterialTypeDeserializer::deserialize(
+                            "SSHPublicKeyBody",
+                            stack,
+                        )?;
+                    }
+                    "SSHPublicKeyId" => {
+                        obj.ssh_public_key_id =
+                            PublicKeyIdTypeDeserializer::deserialize("SSHPublicKeyId", stack)?;
+                    }
+                    "Status" => {
+                        obj.status = StatusTypeDeserializer::deserialize("Status", stack)?;
+                    }
+                    "UploadDate" => {
+                        obj.upload_date =
+                            Some(DateTypeDeserializer::deserialize("UploadDate", stack)?);
+                    }
+                    "UserName" => {
+                        obj.user_name = UserNameTypeDeserializer::deserialize("UserName", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct SSHPublicKeyListTypeDeserializer;
+pub(crate) struct SSHPublicKeyListTypeDeserializer;
 impl SSHPublicKeyListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SSHPublicKeyMetadata>, XmlParseError> {
@@ -8736,50 +14732,63 @@ impl SSHPublicKeyListTypeDeserializer {
     }
 }
 /// <p>Contains information about an SSH public key, without the key's body or fingerprint.</p> <p>This data type is used as a response element in the <a>ListSSHPublicKeys</a> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SSHPublicKeyMetadata {
     /// <p>The unique identifier for the SSH public key.</p>
     pub ssh_public_key_id: String,
     /// <p>The status of the SSH public key. <code>Active</code> means that the key can be used for authentication with an AWS CodeCommit repository. <code>Inactive</code> means that the key cannot be used.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the SSH public key was uploaded.</p>
-    pub upload_date: String,
+    pub upload_date: IsoTimestamp,
     /// <p>The name of the IAM user associated with the SSH public key.</p>
     pub user_name: String,
 }
 
-struct SSHPublicKeyMetadataDeserializer;
+pub(crate) struct SSHPublicKeyMetadataDeserializer;
 impl SSHPublicKeyMetadataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SSHPublicKeyMetadata, XmlParseError> {
-        deserialize_elements::<_, SSHPublicKeyMetadata, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "SSHPublicKeyId" => {
-                    obj.ssh_public_key_id =
-                        PublicKeyIdTypeDeserializer::deserialize("SSHPublicKeyId", stack)?;
-                }
-                "Status" => {
-                    obj.status = StatusTypeDeserializer::deserialize("Status", stack)?;
-                }
-                "UploadDate" => {
-                    obj.upload_date = DateTypeDeserializer::deserialize("UploadDate", stack)?;
-                }
-                "UserName" => {
-                    obj.user_name = UserNameTypeDeserializer::deserialize("UserName", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            SSHPublicKeyMetadata {
+                ssh_public_key_id: Default::default(),
+                status: StatusType::Unknown(String::new()),
+                upload_date: Default::default(),
+                user_name: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "SSHPublicKeyId" => {
+                        obj.ssh_public_key_id =
+                            PublicKeyIdTypeDeserializer::deserialize("SSHPublicKeyId", stack)?;
+                    }
+                    "Status" => {
+                        obj.status = StatusTypeDeserializer::deserialize("Status", stack)?;
+                    }
+                    "UploadDate" => {
+                        obj.upload_date = DateTypeDeserializer::deserialize("UploadDate", stack)?;
+                    }
+                    "UserName" => {
+                        obj.user_name = UserNameTypeDeserializer::deserialize("UserName", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
-struct SerialNumberTypeDeserializer;
+pub(crate) struct SerialNumberTypeDeserializer;
 impl SerialNumberTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -8798,10 +14807,10 @@ pub struct ServerCertificate {
     pub server_certificate_metadata: ServerCertificateMetadata,
 }
 
-struct ServerCertificateDeserializer;
+pub(crate) struct ServerCertificateDeserializer;
 impl ServerCertificateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ServerCertificate, XmlParseError> {
@@ -8836,7 +14845,7 @@ pub struct ServerCertificateMetadata {
     /// <p> The Amazon Resource Name (ARN) specifying the server certificate. For more information about ARNs and how to use them in policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide. </p>
     pub arn: String,
     /// <p>The date on which the certificate is set to expire.</p>
-    pub expiration: Option<String>,
+    pub expiration: Option<IsoTimestamp>,
     /// <p> The path to the server certificate. For more information about paths, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide. </p>
     pub path: String,
     /// <p> The stable and unique string identifying the server certificate. For more information about IDs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide. </p>
@@ -8844,13 +14853,13 @@ pub struct ServerCertificateMetadata {
     /// <p>The name that identifies the server certificate.</p>
     pub server_certificate_name: String,
     /// <p>The date when the server certificate was uploaded.</p>
-    pub upload_date: Option<String>,
+    pub upload_date: Option<IsoTimestamp>,
 }
 
-struct ServerCertificateMetadataDeserializer;
+pub(crate) struct ServerCertificateMetadataDeserializer;
 impl ServerCertificateMetadataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ServerCertificateMetadata, XmlParseError> {
@@ -8891,10 +14900,10 @@ impl ServerCertificateMetadataDeserializer {
         )
     }
 }
-struct ServerCertificateMetadataListTypeDeserializer;
+pub(crate) struct ServerCertificateMetadataListTypeDeserializer;
 impl ServerCertificateMetadataListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ServerCertificateMetadata>, XmlParseError> {
@@ -8910,10 +14919,13 @@ impl ServerCertificateMetadataListTypeDeserializer {
         })
     }
 }
-struct ServerCertificateNameTypeDeserializer;
+pub(crate) struct ServerCertificateNameTypeDeserializer;
 impl ServerCertificateNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -8925,7 +14937,7 @@ impl ServerCertificateNameTypeDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ServiceLastAccessed {
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when an authenticated entity most recently attempted to access the service. AWS does not report unauthenticated requests.</p> <p>This field is null if no IAM entities attempted to access the service within the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#service-last-accessed-reporting-period">reporting period</a>.</p>
-    pub last_authenticated: Option<String>,
+    pub last_authenticated: Option<IsoTimestamp>,
     /// <p>The ARN of the authenticated entity (user or role) that last attempted to access the service. AWS does not report unauthenticated requests.</p> <p>This field is null if no IAM entities attempted to access the service within the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_access-advisor.html#service-last-accessed-reporting-period">reporting period</a>.</p>
     pub last_authenticated_entity: Option<String>,
     /// <p>The name of the service in which access was attempted.</p>
@@ -8936,10 +14948,10 @@ pub struct ServiceLastAccessed {
     pub total_authenticated_entities: Option<i64>,
 }
 
-struct ServiceLastAccessedDeserializer;
+pub(crate) struct ServiceLastAccessedDeserializer;
 impl ServiceLastAccessedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ServiceLastAccessed, XmlParseError> {
@@ -8977,10 +14989,13 @@ impl ServiceLastAccessedDeserializer {
         })
     }
 }
-struct ServiceNameDeserializer;
+pub(crate) struct ServiceNameDeserializer;
 impl ServiceNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -8988,10 +15003,13 @@ impl ServiceNameDeserializer {
         Ok(obj)
     }
 }
-struct ServiceNameTypeDeserializer;
+pub(crate) struct ServiceNameTypeDeserializer;
 impl ServiceNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -9011,10 +15029,13 @@ impl ServiceNamespaceListTypeSerializer {
     }
 }
 
-struct ServiceNamespaceTypeDeserializer;
+pub(crate) struct ServiceNamespaceTypeDeserializer;
 impl ServiceNamespaceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -9022,10 +15043,13 @@ impl ServiceNamespaceTypeDeserializer {
         Ok(obj)
     }
 }
-struct ServicePasswordDeserializer;
+pub(crate) struct ServicePasswordDeserializer;
 impl ServicePasswordDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -9034,10 +15058,10 @@ impl ServicePasswordDeserializer {
     }
 }
 /// <p>Contains the details of a service-specific credential.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ServiceSpecificCredential {
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the service-specific credential were created.</p>
-    pub create_date: String,
+    pub create_date: IsoTimestamp,
     /// <p>The name of the service associated with the service-specific credential.</p>
     pub service_name: String,
     /// <p>The generated password for the service-specific credential.</p>
@@ -9047,21 +15071,30 @@ pub struct ServiceSpecificCredential {
     /// <p>The generated user name for the service-specific credential. This value is generated by combining the IAM user's name combined with the ID number of the AWS account, as in <code>jane-at-123456789012</code>, for example. This value cannot be configured by the user.</p>
     pub service_user_name: String,
     /// <p>The status of the service-specific credential. <code>Active</code> means that the key is valid for API calls, while <code>Inactive</code> means it is not.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the IAM user associated with the service-specific credential.</p>
     pub user_name: String,
 }
 
-struct ServiceSpecificCredentialDeserializer;
+pub(crate) struct ServiceSpecificCredentialDeserializer;
 impl ServiceSpecificCredentialDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ServiceSpecificCredential, XmlParseError> {
-        deserialize_elements::<_, ServiceSpecificCredential, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            ServiceSpecificCredential {
+                create_date: Default::default(),
+                service_name: Default::default(),
+                service_password: Default::default(),
+                service_specific_credential_id: Default::default(),
+                service_user_name: Default::default(),
+                status: StatusType::Unknown(String::new()),
+                user_name: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "CreateDate" => {
@@ -9099,10 +15132,13 @@ impl ServiceSpecificCredentialDeserializer {
         )
     }
 }
-struct ServiceSpecificCredentialIdDeserializer;
+pub(crate) struct ServiceSpecificCredentialIdDeserializer;
 impl ServiceSpecificCredentialIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -9111,10 +15147,10 @@ impl ServiceSpecificCredentialIdDeserializer {
     }
 }
 /// <p>Contains additional details about a service-specific credential.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ServiceSpecificCredentialMetadata {
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the service-specific credential were created.</p>
-    pub create_date: String,
+    pub create_date: IsoTimestamp,
     /// <p>The name of the service associated with the service-specific credential.</p>
     pub service_name: String,
     /// <p>The unique identifier for the service-specific credential.</p>
@@ -9122,21 +15158,29 @@ pub struct ServiceSpecificCredentialMetadata {
     /// <p>The generated user name for the service-specific credential.</p>
     pub service_user_name: String,
     /// <p>The status of the service-specific credential. <code>Active</code> means that the key is valid for API calls, while <code>Inactive</code> means it is not.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the IAM user associated with the service-specific credential.</p>
     pub user_name: String,
 }
 
-struct ServiceSpecificCredentialMetadataDeserializer;
+pub(crate) struct ServiceSpecificCredentialMetadataDeserializer;
 impl ServiceSpecificCredentialMetadataDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ServiceSpecificCredentialMetadata, XmlParseError> {
-        deserialize_elements::<_, ServiceSpecificCredentialMetadata, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            ServiceSpecificCredentialMetadata {
+                create_date: Default::default(),
+                service_name: Default::default(),
+                service_specific_credential_id: Default::default(),
+                service_user_name: Default::default(),
+                status: StatusType::Unknown(String::new()),
+                user_name: Default::default(),
+            },
             |name, stack, obj| {
                 match name {
                     "CreateDate" => {
@@ -9170,10 +15214,10 @@ impl ServiceSpecificCredentialMetadataDeserializer {
         )
     }
 }
-struct ServiceSpecificCredentialsListTypeDeserializer;
+pub(crate) struct ServiceSpecificCredentialsListTypeDeserializer;
 impl ServiceSpecificCredentialsListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ServiceSpecificCredentialMetadata>, XmlParseError> {
@@ -9189,10 +15233,13 @@ impl ServiceSpecificCredentialsListTypeDeserializer {
         })
     }
 }
-struct ServiceUserNameDeserializer;
+pub(crate) struct ServiceUserNameDeserializer;
 impl ServiceUserNameDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -9200,10 +15247,10 @@ impl ServiceUserNameDeserializer {
         Ok(obj)
     }
 }
-struct ServicesLastAccessedDeserializer;
+pub(crate) struct ServicesLastAccessedDeserializer;
 impl ServicesLastAccessedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ServiceLastAccessed>, XmlParseError> {
@@ -9227,6 +15274,48 @@ pub struct SetDefaultPolicyVersionRequest {
     pub version_id: String,
 }
 
+impl SetDefaultPolicyVersionRequest {
+    /// Returns a builder for `SetDefaultPolicyVersionRequest`, checking its required members when built.
+    pub fn builder() -> SetDefaultPolicyVersionRequestBuilder {
+        SetDefaultPolicyVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetDefaultPolicyVersionRequest`](struct.SetDefaultPolicyVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct SetDefaultPolicyVersionRequestBuilder {
+    policy_arn: Option<String>,
+    version_id: Option<String>,
+}
+
+impl SetDefaultPolicyVersionRequestBuilder {
+    /// Sets the [`policy_arn`](struct.SetDefaultPolicyVersionRequest.html#structfield.policy_arn) member.
+    pub fn policy_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.SetDefaultPolicyVersionRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetDefaultPolicyVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetDefaultPolicyVersionRequest, ::rusoto_core::BuildError> {
+        Ok(SetDefaultPolicyVersionRequest {
+            policy_arn: self
+                .policy_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_arn"))?,
+            version_id: self
+                .version_id
+                .ok_or(::rusoto_core::BuildError::missing("version_id"))?,
+        })
+    }
+}
+
 /// Serialize `SetDefaultPolicyVersionRequest` contents to a `SignedRequest`.
 struct SetDefaultPolicyVersionRequestSerializer;
 impl SetDefaultPolicyVersionRequestSerializer {
@@ -9241,10 +15330,46 @@ impl SetDefaultPolicyVersionRequestSerializer {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SetSecurityTokenServicePreferencesRequest {
     /// <p>The version of the global endpoint token. Version 1 tokens are valid only in AWS Regions that are available by default. These tokens do not work in manually enabled Regions, such as Asia Pacific (Hong Kong). Version 2 tokens are valid in all Regions. However, version 2 tokens are longer and might affect systems where you temporarily store tokens.</p> <p>For information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_credentials_temp_enable-regions.html">Activating and Deactivating STS in an AWS Region</a> in the <i>IAM User Guide</i>.</p>
-    pub global_endpoint_token_version: String,
+    pub global_endpoint_token_version: GlobalEndpointTokenVersion,
+}
+
+impl SetSecurityTokenServicePreferencesRequest {
+    /// Returns a builder for `SetSecurityTokenServicePreferencesRequest`, checking its required members when built.
+    pub fn builder() -> SetSecurityTokenServicePreferencesRequestBuilder {
+        SetSecurityTokenServicePreferencesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetSecurityTokenServicePreferencesRequest`](struct.SetSecurityTokenServicePreferencesRequest.html).
+#[derive(Default, Debug)]
+pub struct SetSecurityTokenServicePreferencesRequestBuilder {
+    global_endpoint_token_version: Option<GlobalEndpointTokenVersion>,
+}
+
+impl SetSecurityTokenServicePreferencesRequestBuilder {
+    /// Sets the [`global_endpoint_token_version`](struct.SetSecurityTokenServicePreferencesRequest.html#structfield.global_endpoint_token_version) member.
+    pub fn global_endpoint_token_version<T: Into<GlobalEndpointTokenVersion>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.global_endpoint_token_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetSecurityTokenServicePreferencesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetSecurityTokenServicePreferencesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(SetSecurityTokenServicePreferencesRequest {
+            global_endpoint_token_version: self.global_endpoint_token_version.ok_or(
+                ::rusoto_core::BuildError::missing("global_endpoint_token_version"),
+            )?,
+        })
+    }
 }
 
 /// Serialize `SetSecurityTokenServicePreferencesRequest` contents to a `SignedRequest`.
@@ -9264,50 +15389,62 @@ impl SetSecurityTokenServicePreferencesRequestSerializer {
 }
 
 /// <p>Contains information about an X.509 signing certificate.</p> <p>This data type is used as a response element in the <a>UploadSigningCertificate</a> and <a>ListSigningCertificates</a> operations. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SigningCertificate {
     /// <p>The contents of the signing certificate.</p>
     pub certificate_body: String,
     /// <p>The ID for the signing certificate.</p>
     pub certificate_id: String,
     /// <p>The status of the signing certificate. <code>Active</code> means that the key is valid for API calls, while <code>Inactive</code> means it is not.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The date when the signing certificate was uploaded.</p>
-    pub upload_date: Option<String>,
+    pub upload_date: Option<IsoTimestamp>,
     /// <p>The name of the user the signing certificate is associated with.</p>
     pub user_name: String,
 }
 
-struct SigningCertificateDeserializer;
+pub(crate) struct SigningCertificateDeserializer;
 impl SigningCertificateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SigningCertificate, XmlParseError> {
-        deserialize_elements::<_, SigningCertificate, _>(tag_name, stack, |name, stack, obj| {
-            match name {
-                "CertificateBody" => {
-                    obj.certificate_body =
-                        CertificateBodyTypeDeserializer::deserialize("CertificateBody", stack)?;
-                }
-                "CertificateId" => {
-                    obj.certificate_id =
-                        CertificateIdTypeDeserializer::deserialize("CertificateId", stack)?;
-                }
-                "Status" => {
-                    obj.status = StatusTypeDeserializer::deserialize("Status", stack)?;
-                }
-                "UploadDate" => {
-                    obj.upload_date = Some(DateTypeDeserializer::deserialize("UploadDate", stack)?);
-                }
-                "UserName" => {
-                    obj.user_name = UserNameTypeDeserializer::deserialize("UserName", stack)?;
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
+            tag_name,
+            stack,
+            SigningCertificate {
+                certificate_body: Default::default(),
+                certificate_id: Default::default(),
+                status: StatusType::Unknown(String::new()),
+                upload_date: Default::default(),
+                user_name: Default::default(),
+            },
+            |name, stack, obj| {
+                match name {
+                    "CertificateBody" => {
+                        obj.certificate_body =
+                            CertificateBodyTypeDeserializer::deserialize("CertificateBody", stack)?;
+                    }
+                    "CertificateId" => {
+                        obj.certificate_id =
+                            CertificateIdTypeDeserializer::deserialize("CertificateId", stack)?;
+                    }
+                    "Status" => {
+                        obj.status = StatusTypeDeserializer::deserialize("Status", stack)?;
+                    }
+                    "UploadDate" => {
+                        obj.upload_date =
+                            Some(DateTypeDeserializer::deserialize("UploadDate", stack)?);
+                    }
+                    "UserName" => {
+                        obj.user_name = UserNameTypeDeserializer::deserialize("UserName", stack)?;
+                    }
+                    _ => skip_tree(stack),
                 }
-                _ => skip_tree(stack),
-            }
-            Ok(())
-        })
+                Ok(())
+            },
+        )
     }
 }
 #[derive(Default, Debug, Clone, PartialEq)]
@@ -9334,6 +15471,112 @@ pub struct SimulateCustomPolicyRequest {
     pub resource_policy: Option<String>,
 }
 
+impl SimulateCustomPolicyRequest {
+    /// Returns a builder for `SimulateCustomPolicyRequest`, checking its required members when built.
+    pub fn builder() -> SimulateCustomPolicyRequestBuilder {
+        SimulateCustomPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SimulateCustomPolicyRequest`](struct.SimulateCustomPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct SimulateCustomPolicyRequestBuilder {
+    action_names: Option<Vec<String>>,
+    caller_arn: Option<String>,
+    context_entries: Option<Vec<ContextEntry>>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+    policy_input_list: Option<Vec<String>>,
+    resource_arns: Option<Vec<String>>,
+    resource_handling_option: Option<String>,
+    resource_owner: Option<String>,
+    resource_policy: Option<String>,
+}
+
+impl SimulateCustomPolicyRequestBuilder {
+    /// Sets the [`action_names`](struct.SimulateCustomPolicyRequest.html#structfield.action_names) member.
+    pub fn action_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.action_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`caller_arn`](struct.SimulateCustomPolicyRequest.html#structfield.caller_arn) member.
+    pub fn caller_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.caller_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`context_entries`](struct.SimulateCustomPolicyRequest.html#structfield.context_entries) member.
+    pub fn context_entries<T: Into<Vec<ContextEntry>>>(mut self, value: T) -> Self {
+        self.context_entries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.SimulateCustomPolicyRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.SimulateCustomPolicyRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_input_list`](struct.SimulateCustomPolicyRequest.html#structfield.policy_input_list) member.
+    pub fn policy_input_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_input_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arns`](struct.SimulateCustomPolicyRequest.html#structfield.resource_arns) member.
+    pub fn resource_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_handling_option`](struct.SimulateCustomPolicyRequest.html#structfield.resource_handling_option) member.
+    pub fn resource_handling_option<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_handling_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_owner`](struct.SimulateCustomPolicyRequest.html#structfield.resource_owner) member.
+    pub fn resource_owner<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_owner = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_policy`](struct.SimulateCustomPolicyRequest.html#structfield.resource_policy) member.
+    pub fn resource_policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_policy = Some(value.into());
+        self
+    }
+
+    /// Builds the `SimulateCustomPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SimulateCustomPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(SimulateCustomPolicyRequest {
+            action_names: self
+                .action_names
+                .ok_or(::rusoto_core::BuildError::missing("action_names"))?,
+            caller_arn: self.caller_arn,
+            context_entries: self.context_entries,
+            marker: self.marker,
+            max_items: self.max_items,
+            policy_input_list: self
+                .policy_input_list
+                .ok_or(::rusoto_core::BuildError::missing("policy_input_list"))?,
+            resource_arns: self.resource_arns,
+            resource_handling_option: self.resource_handling_option,
+            resource_owner: self.resource_owner,
+            resource_policy: self.resource_policy,
+        })
+    }
+}
+
 /// Serialize `SimulateCustomPolicyRequest` contents to a `SignedRequest`.
 struct SimulateCustomPolicyRequestSerializer;
 impl SimulateCustomPolicyRequestSerializer {
@@ -9402,10 +15645,10 @@ pub struct SimulatePolicyResponse {
     pub marker: Option<String>,
 }
 
-struct SimulatePolicyResponseDeserializer;
+pub(crate) struct SimulatePolicyResponseDeserializer;
 impl SimulatePolicyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SimulatePolicyResponse, XmlParseError> {
@@ -9460,6 +15703,120 @@ pub struct SimulatePrincipalPolicyRequest {
     pub resource_policy: Option<String>,
 }
 
+impl SimulatePrincipalPolicyRequest {
+    /// Returns a builder for `SimulatePrincipalPolicyRequest`, checking its required members when built.
+    pub fn builder() -> SimulatePrincipalPolicyRequestBuilder {
+        SimulatePrincipalPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SimulatePrincipalPolicyRequest`](struct.SimulatePrincipalPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct SimulatePrincipalPolicyRequestBuilder {
+    action_names: Option<Vec<String>>,
+    caller_arn: Option<String>,
+    context_entries: Option<Vec<ContextEntry>>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+    policy_input_list: Option<Vec<String>>,
+    policy_source_arn: Option<String>,
+    resource_arns: Option<Vec<String>>,
+    resource_handling_option: Option<String>,
+    resource_owner: Option<String>,
+    resource_policy: Option<String>,
+}
+
+impl SimulatePrincipalPolicyRequestBuilder {
+    /// Sets the [`action_names`](struct.SimulatePrincipalPolicyRequest.html#structfield.action_names) member.
+    pub fn action_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.action_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`caller_arn`](struct.SimulatePrincipalPolicyRequest.html#structfield.caller_arn) member.
+    pub fn caller_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.caller_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`context_entries`](struct.SimulatePrincipalPolicyRequest.html#structfield.context_entries) member.
+    pub fn context_entries<T: Into<Vec<ContextEntry>>>(mut self, value: T) -> Self {
+        self.context_entries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.SimulatePrincipalPolicyRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.SimulatePrincipalPolicyRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_input_list`](struct.SimulatePrincipalPolicyRequest.html#structfield.policy_input_list) member.
+    pub fn policy_input_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_input_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_source_arn`](struct.SimulatePrincipalPolicyRequest.html#structfield.policy_source_arn) member.
+    pub fn policy_source_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_source_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arns`](struct.SimulatePrincipalPolicyRequest.html#structfield.resource_arns) member.
+    pub fn resource_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_handling_option`](struct.SimulatePrincipalPolicyRequest.html#structfield.resource_handling_option) member.
+    pub fn resource_handling_option<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_handling_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_owner`](struct.SimulatePrincipalPolicyRequest.html#structfield.resource_owner) member.
+    pub fn resource_owner<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_owner = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_policy`](struct.SimulatePrincipalPolicyRequest.html#structfield.resource_policy) member.
+    pub fn resource_policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_policy = Some(value.into());
+        self
+    }
+
+    /// Builds the `SimulatePrincipalPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SimulatePrincipalPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(SimulatePrincipalPolicyRequest {
+            action_names: self
+                .action_names
+                .ok_or(::rusoto_core::BuildError::missing("action_names"))?,
+            caller_arn: self.caller_arn,
+            context_entries: self.context_entries,
+            marker: self.marker,
+            max_items: self.max_items,
+            policy_input_list: self.policy_input_list,
+            policy_source_arn: self
+                .policy_source_arn
+                .ok_or(::rusoto_core::BuildError::missing("policy_source_arn"))?,
+            resource_arns: self.resource_arns,
+            resource_handling_option: self.resource_handling_option,
+            resource_owner: self.resource_owner,
+            resource_policy: self.resource_policy,
+        })
+    }
+}
+
 /// Serialize `SimulatePrincipalPolicyRequest` contents to a `SignedRequest`.
 struct SimulatePrincipalPolicyRequestSerializer;
 impl SimulatePrincipalPolicyRequestSerializer {
@@ -9534,6 +15891,73 @@ impl SimulationPolicyListTypeSerializer {
     }
 }
 
+/// <p>The key that is used to sort the results. If you choose the namespace key, the results are returned in alphabetical order. If you choose the time key, the results are sorted numerically by the date and time.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortKeyType {
+    /// <p><code>SERVICE_NAMESPACE_ASCENDING</code></p>
+    ServiceNamespaceAscending,
+    /// <p><code>SERVICE_NAMESPACE_DESCENDING</code></p>
+    ServiceNamespaceDescending,
+    /// <p><code>LAST_AUTHENTICATED_TIME_ASCENDING</code></p>
+    LastAuthenticatedTimeAscending,
+    /// <p><code>LAST_AUTHENTICATED_TIME_DESCENDING</code></p>
+    LastAuthenticatedTimeDescending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SortKeyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortKeyType::ServiceNamespaceAscending => "SERVICE_NAMESPACE_ASCENDING",
+            SortKeyType::ServiceNamespaceDescending => "SERVICE_NAMESPACE_DESCENDING",
+            SortKeyType::LastAuthenticatedTimeAscending => "LAST_AUTHENTICATED_TIME_ASCENDING",
+            SortKeyType::LastAuthenticatedTimeDescending => "LAST_AUTHENTICATED_TIME_DESCENDING",
+            SortKeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SortKeyType {
+    fn from(value: &'a str) -> SortKeyType {
+        match value {
+            "SERVICE_NAMESPACE_ASCENDING" => SortKeyType::ServiceNamespaceAscending,
+            "SERVICE_NAMESPACE_DESCENDING" => SortKeyType::ServiceNamespaceDescending,
+            "LAST_AUTHENTICATED_TIME_ASCENDING" => SortKeyType::LastAuthenticatedTimeAscending,
+            "LAST_AUTHENTICATED_TIME_DESCENDING" => SortKeyType::LastAuthenticatedTimeDescending,
+            _ => SortKeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortKeyType {
+    fn from(value: String) -> SortKeyType {
+        SortKeyType::from(value.as_str())
+    }
+}
+
+impl From<SortKeyType> for String {
+    fn from(value: SortKeyType) -> String {
+        match value {
+            SortKeyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SortKeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortKeyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>Contains a reference to a <code>Statement</code> element in a policy document that determines the result of the simulation.</p> <p>This data type is used by the <code>MatchedStatements</code> member of the <code> <a>EvaluationResult</a> </code> type.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Statement {
@@ -9542,15 +15966,15 @@ pub struct Statement {
     /// <p>The identifier of the policy that was provided as an input.</p>
     pub source_policy_id: Option<String>,
     /// <p>The type of the policy.</p>
-    pub source_policy_type: Option<String>,
+    pub source_policy_type: Option<PolicySourceType>,
     /// <p>The row and column of the beginning of the <code>Statement</code> in an IAM policy.</p>
     pub start_position: Option<Position>,
 }
 
-struct StatementDeserializer;
+pub(crate) struct StatementDeserializer;
 impl StatementDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Statement, XmlParseError> {
@@ -9582,10 +16006,10 @@ impl StatementDeserializer {
         })
     }
 }
-struct StatementListTypeDeserializer;
+pub(crate) struct StatementListTypeDeserializer;
 impl StatementListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Statement>, XmlParseError> {
@@ -9599,46 +16023,273 @@ impl StatementListTypeDeserializer {
         })
     }
 }
-struct StatusTypeDeserializer;
+pub(crate) struct StatusTypeDeserializer;
 impl StatusTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<StatusType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct StringTypeDeserializer;
-impl StringTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
+/// <p>The status of the access key. <code>Active</code> means that the key is valid for API calls, while <code>Inactive</code> means it is not. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StatusType {
+    /// <p><code>Active</code></p>
+    Active,
+    /// <p><code>Inactive</code></p>
+    Inactive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
 
-        Ok(obj)
+impl StatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusType::Active => "Active",
+            StatusType::Inactive => "Inactive",
+            StatusType::Unknown(ref value) => value,
+        }
     }
 }
-struct SummaryKeyTypeDeserializer;
-impl SummaryKeyTypeDeserializer {
-    #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
-        start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
-        end_element(tag_name, stack)?;
 
-        Ok(obj)
+impl<'a> From<&'a str> for StatusType {
+    fn from(value: &'a str) -> StatusType {
+        match value {
+            "Active" => StatusType::Active,
+            "Inactive" => StatusType::Inactive,
+            _ => StatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusType {
+    fn from(value: String) -> StatusType {
+        StatusType::from(value.as_str())
+    }
+}
+
+impl From<StatusType> for String {
+    fn from(value: StatusType) -> String {
+        match value {
+            StatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct StringTypeDeserializer;
+impl StringTypeDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?;
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+pub(crate) struct SummaryKeyTypeDeserializer;
+impl SummaryKeyTypeDeserializer {
+    #[allow(unused_variables)]
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SummaryKeyType, XmlParseError> {
+        start_element(tag_name, stack)?;
+        let obj = characters(stack)?.into();
+        end_element(tag_name, stack)?;
+
+        Ok(obj)
+    }
+}
+/// <p>A set of key–value pairs containing information about IAM entity usage and IAM quotas.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SummaryKeyType {
+    /// <p><code>Users</code></p>
+    Users,
+    /// <p><code>UsersQuota</code></p>
+    UsersQuota,
+    /// <p><code>Groups</code></p>
+    Groups,
+    /// <p><code>GroupsQuota</code></p>
+    GroupsQuota,
+    /// <p><code>ServerCertificates</code></p>
+    ServerCertificates,
+    /// <p><code>ServerCertificatesQuota</code></p>
+    ServerCertificatesQuota,
+    /// <p><code>UserPolicySizeQuota</code></p>
+    UserPolicySizeQuota,
+    /// <p><code>GroupPolicySizeQuota</code></p>
+    GroupPolicySizeQuota,
+    /// <p><code>GroupsPerUserQuota</code></p>
+    GroupsPerUserQuota,
+    /// <p><code>SigningCertificatesPerUserQuota</code></p>
+    SigningCertificatesPerUserQuota,
+    /// <p><code>AccessKeysPerUserQuota</code></p>
+    AccessKeysPerUserQuota,
+    /// <p><code>MFADevices</code></p>
+    MFADevices,
+    /// <p><code>MFADevicesInUse</code></p>
+    MFADevicesInUse,
+    /// <p><code>AccountMFAEnabled</code></p>
+    AccountMFAEnabled,
+    /// <p><code>AccountAccessKeysPresent</code></p>
+    AccountAccessKeysPresent,
+    /// <p><code>AccountSigningCertificatesPresent</code></p>
+    AccountSigningCertificatesPresent,
+    /// <p><code>AttachedPoliciesPerGroupQuota</code></p>
+    AttachedPoliciesPerGroupQuota,
+    /// <p><code>AttachedPoliciesPerRoleQuota</code></p>
+    AttachedPoliciesPerRoleQuota,
+    /// <p><code>AttachedPoliciesPerUserQuota</code></p>
+    AttachedPoliciesPerUserQuota,
+    /// <p><code>Policies</code></p>
+    Policies,
+    /// <p><code>PoliciesQuota</code></p>
+    PoliciesQuota,
+    /// <p><code>PolicySizeQuota</code></p>
+    PolicySizeQuota,
+    /// <p><code>PolicyVersionsInUse</code></p>
+    PolicyVersionsInUse,
+    /// <p><code>PolicyVersionsInUseQuota</code></p>
+    PolicyVersionsInUseQuota,
+    /// <p><code>VersionsPerPolicyQuota</code></p>
+    VersionsPerPolicyQuota,
+    /// <p><code>GlobalEndpointTokenVersion</code></p>
+    GlobalEndpointTokenVersion,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SummaryKeyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SummaryKeyType::Users => "Users",
+            SummaryKeyType::UsersQuota => "UsersQuota",
+            SummaryKeyType::Groups => "Groups",
+            SummaryKeyType::GroupsQuota => "GroupsQuota",
+            SummaryKeyType::ServerCertificates => "ServerCertificates",
+            SummaryKeyType::ServerCertificatesQuota => "ServerCertificatesQuota",
+            SummaryKeyType::UserPolicySizeQuota => "UserPolicySizeQuota",
+            SummaryKeyType::GroupPolicySizeQuota => "GroupPolicySizeQuota",
+            SummaryKeyType::GroupsPerUserQuota => "GroupsPerUserQuota",
+            SummaryKeyType::SigningCertificatesPerUserQuota => "SigningCertificatesPerUserQuota",
+            SummaryKeyType::AccessKeysPerUserQuota => "AccessKeysPerUserQuota",
+            SummaryKeyType::MFADevices => "MFADevices",
+            SummaryKeyType::MFADevicesInUse => "MFADevicesInUse",
+            SummaryKeyType::AccountMFAEnabled => "AccountMFAEnabled",
+            SummaryKeyType::AccountAccessKeysPresent => "AccountAccessKeysPresent",
+            SummaryKeyType::AccountSigningCertificatesPresent => {
+                "AccountSigningCertificatesPresent"
+            }
+            SummaryKeyType::AttachedPoliciesPerGroupQuota => "AttachedPoliciesPerGroupQuota",
+            SummaryKeyType::AttachedPoliciesPerRoleQuota => "AttachedPoliciesPerRoleQuota",
+            SummaryKeyType::AttachedPoliciesPerUserQuota => "AttachedPoliciesPerUserQuota",
+            SummaryKeyType::Policies => "Policies",
+            SummaryKeyType::PoliciesQuota => "PoliciesQuota",
+            SummaryKeyType::PolicySizeQuota => "PolicySizeQuota",
+            SummaryKeyType::PolicyVersionsInUse => "PolicyVersionsInUse",
+            SummaryKeyType::PolicyVersionsInUseQuota => "PolicyVersionsInUseQuota",
+            SummaryKeyType::VersionsPerPolicyQuota => "VersionsPerPolicyQuota",
+            SummaryKeyType::GlobalEndpointTokenVersion => "GlobalEndpointTokenVersion",
+            SummaryKeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SummaryKeyType {
+    fn from(value: &'a str) -> SummaryKeyType {
+        match value {
+            "Users" => SummaryKeyType::Users,
+            "UsersQuota" => SummaryKeyType::UsersQuota,
+            "Groups" => SummaryKeyType::Groups,
+            "GroupsQuota" => SummaryKeyType::GroupsQuota,
+            "ServerCertificates" => SummaryKeyType::ServerCertificates,
+            "ServerCertificatesQuota" => SummaryKeyType::ServerCertificatesQuota,
+            "UserPolicySizeQuota" => SummaryKeyType::UserPolicySizeQuota,
+            "GroupPolicySizeQuota" => SummaryKeyType::GroupPolicySizeQuota,
+            "GroupsPerUserQuota" => SummaryKeyType::GroupsPerUserQuota,
+            "SigningCertificatesPerUserQuota" => SummaryKeyType::SigningCertificatesPerUserQuota,
+            "AccessKeysPerUserQuota" => SummaryKeyType::AccessKeysPerUserQuota,
+            "MFADevices" => SummaryKeyType::MFADevices,
+            "MFADevicesInUse" => SummaryKeyType::MFADevicesInUse,
+            "AccountMFAEnabled" => SummaryKeyType::AccountMFAEnabled,
+            "AccountAccessKeysPresent" => SummaryKeyType::AccountAccessKeysPresent,
+            "AccountSigningCertificatesPresent" => {
+                SummaryKeyType::AccountSigningCertificatesPresent
+            }
+            "AttachedPoliciesPerGroupQuota" => SummaryKeyType::AttachedPoliciesPerGroupQuota,
+            "AttachedPoliciesPerRoleQuota" => SummaryKeyType::AttachedPoliciesPerRoleQuota,
+            "AttachedPoliciesPerUserQuota" => SummaryKeyType::AttachedPoliciesPerUserQuota,
+            "Policies" => SummaryKeyType::Policies,
+            "PoliciesQuota" => SummaryKeyType::PoliciesQuota,
+            "PolicySizeQuota" => SummaryKeyType::PolicySizeQuota,
+            "PolicyVersionsInUse" => SummaryKeyType::PolicyVersionsInUse,
+            "PolicyVersionsInUseQuota" => SummaryKeyType::PolicyVersionsInUseQuota,
+            "VersionsPerPolicyQuota" => SummaryKeyType::VersionsPerPolicyQuota,
+            "GlobalEndpointTokenVersion" => SummaryKeyType::GlobalEndpointTokenVersion,
+            _ => SummaryKeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SummaryKeyType {
+    fn from(value: String) -> SummaryKeyType {
+        SummaryKeyType::from(value.as_str())
+    }
+}
+
+impl From<SummaryKeyType> for String {
+    fn from(value: SummaryKeyType) -> String {
+        match value {
+            SummaryKeyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SummaryKeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SummaryKeyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
     }
 }
-struct SummaryMapTypeDeserializer;
+
+pub(crate) struct SummaryMapTypeDeserializer;
 impl SummaryMapTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<::std::collections::HashMap<String, i64>, XmlParseError> {
+    ) -> Result<::std::collections::HashMap<SummaryKeyType, i64>, XmlParseError> {
         start_element(tag_name, stack)?;
 
         let mut obj = ::std::collections::HashMap::new();
@@ -9655,10 +16306,13 @@ impl SummaryMapTypeDeserializer {
         Ok(obj)
     }
 }
-struct SummaryValueTypeDeserializer;
+pub(crate) struct SummaryValueTypeDeserializer;
 impl SummaryValueTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -9675,10 +16329,13 @@ pub struct Tag {
     pub value: String,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -9719,10 +16376,13 @@ impl TagKeyListTypeSerializer {
     }
 }
 
-struct TagKeyTypeDeserializer;
+pub(crate) struct TagKeyTypeDeserializer;
 impl TagKeyTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -9730,10 +16390,10 @@ impl TagKeyTypeDeserializer {
         Ok(obj)
     }
 }
-struct TagListTypeDeserializer;
+pub(crate) struct TagListTypeDeserializer;
 impl TagListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -9767,6 +16427,46 @@ pub struct TagRoleRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagRoleRequest {
+    /// Returns a builder for `TagRoleRequest`, checking its required members when built.
+    pub fn builder() -> TagRoleRequestBuilder {
+        TagRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagRoleRequest`](struct.TagRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct TagRoleRequestBuilder {
+    role_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagRoleRequestBuilder {
+    /// Sets the [`role_name`](struct.TagRoleRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagRoleRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagRoleRequest, ::rusoto_core::BuildError> {
+        Ok(TagRoleRequest {
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `TagRoleRequest` contents to a `SignedRequest`.
 struct TagRoleRequestSerializer;
 impl TagRoleRequestSerializer {
@@ -9789,6 +16489,46 @@ pub struct TagUserRequest {
     pub user_name: String,
 }
 
+impl TagUserRequest {
+    /// Returns a builder for `TagUserRequest`, checking its required members when built.
+    pub fn builder() -> TagUserRequestBuilder {
+        TagUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagUserRequest`](struct.TagUserRequest.html).
+#[derive(Default, Debug)]
+pub struct TagUserRequestBuilder {
+    tags: Option<Vec<Tag>>,
+    user_name: Option<String>,
+}
+
+impl TagUserRequestBuilder {
+    /// Sets the [`tags`](struct.TagUserRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.TagUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagUserRequest, ::rusoto_core::BuildError> {
+        Ok(TagUserRequest {
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `TagUserRequest` contents to a `SignedRequest`.
 struct TagUserRequestSerializer;
 impl TagUserRequestSerializer {
@@ -9803,10 +16543,13 @@ impl TagUserRequestSerializer {
     }
 }
 
-struct TagValueTypeDeserializer;
+pub(crate) struct TagValueTypeDeserializer;
 impl TagValueTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -9814,10 +16557,10 @@ impl TagValueTypeDeserializer {
         Ok(obj)
     }
 }
-struct ThumbprintListTypeDeserializer;
+pub(crate) struct ThumbprintListTypeDeserializer;
 impl ThumbprintListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -9843,10 +16586,13 @@ impl ThumbprintListTypeSerializer {
     }
 }
 
-struct ThumbprintTypeDeserializer;
+pub(crate) struct ThumbprintTypeDeserializer;
 impl ThumbprintTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -9862,6 +16608,46 @@ pub struct UntagRoleRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagRoleRequest {
+    /// Returns a builder for `UntagRoleRequest`, checking its required members when built.
+    pub fn builder() -> UntagRoleRequestBuilder {
+        UntagRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagRoleRequest`](struct.UntagRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagRoleRequestBuilder {
+    role_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagRoleRequestBuilder {
+    /// Sets the [`role_name`](struct.UntagRoleRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagRoleRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagRoleRequest, ::rusoto_core::BuildError> {
+        Ok(UntagRoleRequest {
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// Serialize `UntagRoleRequest` contents to a `SignedRequest`.
 struct UntagRoleRequestSerializer;
 impl UntagRoleRequestSerializer {
@@ -9888,6 +16674,46 @@ pub struct UntagUserRequest {
     pub user_name: String,
 }
 
+impl UntagUserRequest {
+    /// Returns a builder for `UntagUserRequest`, checking its required members when built.
+    pub fn builder() -> UntagUserRequestBuilder {
+        UntagUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagUserRequest`](struct.UntagUserRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagUserRequestBuilder {
+    tag_keys: Option<Vec<String>>,
+    user_name: Option<String>,
+}
+
+impl UntagUserRequestBuilder {
+    /// Sets the [`tag_keys`](struct.UntagUserRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.UntagUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagUserRequest, ::rusoto_core::BuildError> {
+        Ok(UntagUserRequest {
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `UntagUserRequest` contents to a `SignedRequest`.
 struct UntagUserRequestSerializer;
 impl UntagUserRequestSerializer {
@@ -9906,16 +16732,64 @@ impl UntagUserRequestSerializer {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UpdateAccessKeyRequest {
     /// <p>The access key ID of the secret access key you want to update.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
     pub access_key_id: String,
     /// <p> The status you want to assign to the secret access key. <code>Active</code> means that the key can be used for API calls to AWS, while <code>Inactive</code> means that the key cannot be used.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the user whose key you want to update.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
     pub user_name: Option<String>,
 }
 
+impl UpdateAccessKeyRequest {
+    /// Returns a builder for `UpdateAccessKeyRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAccessKeyRequestBuilder {
+        UpdateAccessKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAccessKeyRequest`](struct.UpdateAccessKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAccessKeyRequestBuilder {
+    access_key_id: Option<String>,
+    status: Option<StatusType>,
+    user_name: Option<String>,
+}
+
+impl UpdateAccessKeyRequestBuilder {
+    /// Sets the [`access_key_id`](struct.UpdateAccessKeyRequest.html#structfield.access_key_id) member.
+    pub fn access_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.UpdateAccessKeyRequest.html#structfield.status) member.
+    pub fn status<T: Into<StatusType>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.UpdateAccessKeyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAccessKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateAccessKeyRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAccessKeyRequest {
+            access_key_id: self
+                .access_key_id
+                .ok_or(::rusoto_core::BuildError::missing("access_key_id"))?,
+            status: self
+                .status
+                .ok_or(::rusoto_core::BuildError::missing("status"))?,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `UpdateAccessKeyRequest` contents to a `SignedRequest`.
 struct UpdateAccessKeyRequestSerializer;
 impl UpdateAccessKeyRequestSerializer {
@@ -9955,6 +16829,100 @@ pub struct UpdateAccountPasswordPolicyRequest {
     pub require_uppercase_characters: Option<bool>,
 }
 
+impl UpdateAccountPasswordPolicyRequest {
+    /// Returns a builder for `UpdateAccountPasswordPolicyRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAccountPasswordPolicyRequestBuilder {
+        UpdateAccountPasswordPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAccountPasswordPolicyRequest`](struct.UpdateAccountPasswordPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAccountPasswordPolicyRequestBuilder {
+    allow_users_to_change_password: Option<bool>,
+    hard_expiry: Option<bool>,
+    max_password_age: Option<i64>,
+    minimum_password_length: Option<i64>,
+    password_reuse_prevention: Option<i64>,
+    require_lowercase_characters: Option<bool>,
+    require_numbers: Option<bool>,
+    require_symbols: Option<bool>,
+    require_uppercase_characters: Option<bool>,
+}
+
+impl UpdateAccountPasswordPolicyRequestBuilder {
+    /// Sets the [`allow_users_to_change_password`](struct.UpdateAccountPasswordPolicyRequest.html#structfield.allow_users_to_change_password) member.
+    pub fn allow_users_to_change_password<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_users_to_change_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hard_expiry`](struct.UpdateAccountPasswordPolicyRequest.html#structfield.hard_expiry) member.
+    pub fn hard_expiry<T: Into<bool>>(mut self, value: T) -> Self {
+        self.hard_expiry = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_password_age`](struct.UpdateAccountPasswordPolicyRequest.html#structfield.max_password_age) member.
+    pub fn max_password_age<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_password_age = Some(value.into());
+        self
+    }
+
+    /// Sets the [`minimum_password_length`](struct.UpdateAccountPasswordPolicyRequest.html#structfield.minimum_password_length) member.
+    pub fn minimum_password_length<T: Into<i64>>(mut self, value: T) -> Self {
+        self.minimum_password_length = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password_reuse_prevention`](struct.UpdateAccountPasswordPolicyRequest.html#structfield.password_reuse_prevention) member.
+    pub fn password_reuse_prevention<T: Into<i64>>(mut self, value: T) -> Self {
+        self.password_reuse_prevention = Some(value.into());
+        self
+    }
+
+    /// Sets the [`require_lowercase_characters`](struct.UpdateAccountPasswordPolicyRequest.html#structfield.require_lowercase_characters) member.
+    pub fn require_lowercase_characters<T: Into<bool>>(mut self, value: T) -> Self {
+        self.require_lowercase_characters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`require_numbers`](struct.UpdateAccountPasswordPolicyRequest.html#structfield.require_numbers) member.
+    pub fn require_numbers<T: Into<bool>>(mut self, value: T) -> Self {
+        self.require_numbers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`require_symbols`](struct.UpdateAccountPasswordPolicyRequest.html#structfield.require_symbols) member.
+    pub fn require_symbols<T: Into<bool>>(mut self, value: T) -> Self {
+        self.require_symbols = Some(value.into());
+        self
+    }
+
+    /// Sets the [`require_uppercase_characters`](struct.UpdateAccountPasswordPolicyRequest.html#structfield.require_uppercase_characters) member.
+    pub fn require_uppercase_characters<T: Into<bool>>(mut self, value: T) -> Self {
+        self.require_uppercase_characters = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAccountPasswordPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAccountPasswordPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAccountPasswordPolicyRequest {
+            allow_users_to_change_password: self.allow_users_to_change_password,
+            hard_expiry: self.hard_expiry,
+            max_password_age: self.max_password_age,
+            minimum_password_length: self.minimum_password_length,
+            password_reuse_prevention: self.password_reuse_prevention,
+            require_lowercase_characters: self.require_lowercase_characters,
+            require_numbers: self.require_numbers,
+            require_symbols: self.require_symbols,
+            require_uppercase_characters: self.require_uppercase_characters,
+        })
+    }
+}
+
 /// Serialize `UpdateAccountPasswordPolicyRequest` contents to a `SignedRequest`.
 struct UpdateAccountPasswordPolicyRequestSerializer;
 impl UpdateAccountPasswordPolicyRequestSerializer {
@@ -10017,6 +16985,48 @@ pub struct UpdateAssumeRolePolicyRequest {
     pub role_name: String,
 }
 
+impl UpdateAssumeRolePolicyRequest {
+    /// Returns a builder for `UpdateAssumeRolePolicyRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAssumeRolePolicyRequestBuilder {
+        UpdateAssumeRolePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAssumeRolePolicyRequest`](struct.UpdateAssumeRolePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAssumeRolePolicyRequestBuilder {
+    policy_document: Option<String>,
+    role_name: Option<String>,
+}
+
+impl UpdateAssumeRolePolicyRequestBuilder {
+    /// Sets the [`policy_document`](struct.UpdateAssumeRolePolicyRequest.html#structfield.policy_document) member.
+    pub fn policy_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.UpdateAssumeRolePolicyRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAssumeRolePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAssumeRolePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAssumeRolePolicyRequest {
+            policy_document: self
+                .policy_document
+                .ok_or(::rusoto_core::BuildError::missing("policy_document"))?,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateAssumeRolePolicyRequest` contents to a `SignedRequest`.
 struct UpdateAssumeRolePolicyRequestSerializer;
 impl UpdateAssumeRolePolicyRequestSerializer {
@@ -10044,6 +17054,52 @@ pub struct UpdateGroupRequest {
     pub new_path: Option<String>,
 }
 
+impl UpdateGroupRequest {
+    /// Returns a builder for `UpdateGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateGroupRequestBuilder {
+        UpdateGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGroupRequest`](struct.UpdateGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateGroupRequestBuilder {
+    group_name: Option<String>,
+    new_group_name: Option<String>,
+    new_path: Option<String>,
+}
+
+impl UpdateGroupRequestBuilder {
+    /// Sets the [`group_name`](struct.UpdateGroupRequest.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_group_name`](struct.UpdateGroupRequest.html#structfield.new_group_name) member.
+    pub fn new_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_path`](struct.UpdateGroupRequest.html#structfield.new_path) member.
+    pub fn new_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_path = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateGroupRequest {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            new_group_name: self.new_group_name,
+            new_path: self.new_path,
+        })
+    }
+}
+
 /// Serialize `UpdateGroupRequest` contents to a `SignedRequest`.
 struct UpdateGroupRequestSerializer;
 impl UpdateGroupRequestSerializer {
@@ -10073,6 +17129,54 @@ pub struct UpdateLoginProfileRequest {
     pub user_name: String,
 }
 
+impl UpdateLoginProfileRequest {
+    /// Returns a builder for `UpdateLoginProfileRequest`, checking its required members when built.
+    pub fn builder() -> UpdateLoginProfileRequestBuilder {
+        UpdateLoginProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateLoginProfileRequest`](struct.UpdateLoginProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateLoginProfileRequestBuilder {
+    password: Option<String>,
+    password_reset_required: Option<bool>,
+    user_name: Option<String>,
+}
+
+impl UpdateLoginProfileRequestBuilder {
+    /// Sets the [`password`](struct.UpdateLoginProfileRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password_reset_required`](struct.UpdateLoginProfileRequest.html#structfield.password_reset_required) member.
+    pub fn password_reset_required<T: Into<bool>>(mut self, value: T) -> Self {
+        self.password_reset_required = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.UpdateLoginProfileRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateLoginProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateLoginProfileRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateLoginProfileRequest {
+            password: self.password,
+            password_reset_required: self.password_reset_required,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateLoginProfileRequest` contents to a `SignedRequest`.
 struct UpdateLoginProfileRequestSerializer;
 impl UpdateLoginProfileRequestSerializer {
@@ -10103,6 +17207,51 @@ pub struct UpdateOpenIDConnectProviderThumbprintRequest {
     pub thumbprint_list: Vec<String>,
 }
 
+impl UpdateOpenIDConnectProviderThumbprintRequest {
+    /// Returns a builder for `UpdateOpenIDConnectProviderThumbprintRequest`, checking its required members when built.
+    pub fn builder() -> UpdateOpenIDConnectProviderThumbprintRequestBuilder {
+        UpdateOpenIDConnectProviderThumbprintRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateOpenIDConnectProviderThumbprintRequest`](struct.UpdateOpenIDConnectProviderThumbprintRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateOpenIDConnectProviderThumbprintRequestBuilder {
+    open_id_connect_provider_arn: Option<String>,
+    thumbprint_list: Option<Vec<String>>,
+}
+
+impl UpdateOpenIDConnectProviderThumbprintRequestBuilder {
+    /// Sets the [`open_id_connect_provider_arn`](struct.UpdateOpenIDConnectProviderThumbprintRequest.html#structfield.open_id_connect_provider_arn) member.
+    pub fn open_id_connect_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.open_id_connect_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thumbprint_list`](struct.UpdateOpenIDConnectProviderThumbprintRequest.html#structfield.thumbprint_list) member.
+    pub fn thumbprint_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.thumbprint_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateOpenIDConnectProviderThumbprintRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        UpdateOpenIDConnectProviderThumbprintRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(UpdateOpenIDConnectProviderThumbprintRequest {
+            open_id_connect_provider_arn: self.open_id_connect_provider_arn.ok_or(
+                ::rusoto_core::BuildError::missing("open_id_connect_provider_arn"),
+            )?,
+            thumbprint_list: self
+                .thumbprint_list
+                .ok_or(::rusoto_core::BuildError::missing("thumbprint_list"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateOpenIDConnectProviderThumbprintRequest` contents to a `SignedRequest`.
 struct UpdateOpenIDConnectProviderThumbprintRequestSerializer;
 impl UpdateOpenIDConnectProviderThumbprintRequestSerializer {
@@ -10136,6 +17285,48 @@ pub struct UpdateRoleDescriptionRequest {
     pub role_name: String,
 }
 
+impl UpdateRoleDescriptionRequest {
+    /// Returns a builder for `UpdateRoleDescriptionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRoleDescriptionRequestBuilder {
+        UpdateRoleDescriptionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRoleDescriptionRequest`](struct.UpdateRoleDescriptionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRoleDescriptionRequestBuilder {
+    description: Option<String>,
+    role_name: Option<String>,
+}
+
+impl UpdateRoleDescriptionRequestBuilder {
+    /// Sets the [`description`](struct.UpdateRoleDescriptionRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.UpdateRoleDescriptionRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRoleDescriptionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateRoleDescriptionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRoleDescriptionRequest {
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateRoleDescriptionRequest` contents to a `SignedRequest`.
 struct UpdateRoleDescriptionRequestSerializer;
 impl UpdateRoleDescriptionRequestSerializer {
@@ -10156,10 +17347,10 @@ pub struct UpdateRoleDescriptionResponse {
     pub role: Option<Role>,
 }
 
-struct UpdateRoleDescriptionResponseDeserializer;
+pub(crate) struct UpdateRoleDescriptionResponseDeserializer;
 impl UpdateRoleDescriptionResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateRoleDescriptionResponse, XmlParseError> {
@@ -10188,6 +17379,52 @@ pub struct UpdateRoleRequest {
     pub role_name: String,
 }
 
+impl UpdateRoleRequest {
+    /// Returns a builder for `UpdateRoleRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRoleRequestBuilder {
+        UpdateRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRoleRequest`](struct.UpdateRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRoleRequestBuilder {
+    description: Option<String>,
+    max_session_duration: Option<i64>,
+    role_name: Option<String>,
+}
+
+impl UpdateRoleRequestBuilder {
+    /// Sets the [`description`](struct.UpdateRoleRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_session_duration`](struct.UpdateRoleRequest.html#structfield.max_session_duration) member.
+    pub fn max_session_duration<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_session_duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.UpdateRoleRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateRoleRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRoleRequest {
+            description: self.description,
+            max_session_duration: self.max_session_duration,
+            role_name: self
+                .role_name
+                .ok_or(::rusoto_core::BuildError::missing("role_name"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateRoleRequest` contents to a `SignedRequest`.
 struct UpdateRoleRequestSerializer;
 impl UpdateRoleRequestSerializer {
@@ -10210,10 +17447,10 @@ impl UpdateRoleRequestSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateRoleResponse {}
 
-struct UpdateRoleResponseDeserializer;
+pub(crate) struct UpdateRoleResponseDeserializer;
 impl UpdateRoleResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateRoleResponse, XmlParseError> {
@@ -10234,6 +17471,48 @@ pub struct UpdateSAMLProviderRequest {
     pub saml_provider_arn: String,
 }
 
+impl UpdateSAMLProviderRequest {
+    /// Returns a builder for `UpdateSAMLProviderRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSAMLProviderRequestBuilder {
+        UpdateSAMLProviderRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSAMLProviderRequest`](struct.UpdateSAMLProviderRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSAMLProviderRequestBuilder {
+    saml_metadata_document: Option<String>,
+    saml_provider_arn: Option<String>,
+}
+
+impl UpdateSAMLProviderRequestBuilder {
+    /// Sets the [`saml_metadata_document`](struct.UpdateSAMLProviderRequest.html#structfield.saml_metadata_document) member.
+    pub fn saml_metadata_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.saml_metadata_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`saml_provider_arn`](struct.UpdateSAMLProviderRequest.html#structfield.saml_provider_arn) member.
+    pub fn saml_provider_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.saml_provider_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSAMLProviderRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSAMLProviderRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSAMLProviderRequest {
+            saml_metadata_document: self
+                .saml_metadata_document
+                .ok_or(::rusoto_core::BuildError::missing("saml_metadata_document"))?,
+            saml_provider_arn: self
+                .saml_provider_arn
+                .ok_or(::rusoto_core::BuildError::missing("saml_provider_arn"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateSAMLProviderRequest` contents to a `SignedRequest`.
 struct UpdateSAMLProviderRequestSerializer;
 impl UpdateSAMLProviderRequestSerializer {
@@ -10261,10 +17540,10 @@ pub struct UpdateSAMLProviderResponse {
     pub saml_provider_arn: Option<String>,
 }
 
-struct UpdateSAMLProviderResponseDeserializer;
+pub(crate) struct UpdateSAMLProviderResponseDeserializer;
 impl UpdateSAMLProviderResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateSAMLProviderResponse, XmlParseError> {
@@ -10284,22 +17563,74 @@ impl UpdateSAMLProviderResponseDeserializer {
         )
     }
 }
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UpdateSSHPublicKeyRequest {
     /// <p>The unique identifier for the SSH public key.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
     pub ssh_public_key_id: String,
     /// <p>The status to assign to the SSH public key. <code>Active</code> means that the key can be used for authentication with an AWS CodeCommit repository. <code>Inactive</code> means that the key cannot be used.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the IAM user associated with the SSH public key.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
     pub user_name: String,
 }
 
-/// Serialize `UpdateSSHPublicKeyRequest` contents to a `SignedRequest`.
-struct UpdateSSHPublicKeyRequestSerializer;
-impl UpdateSSHPublicKeyRequestSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &UpdateSSHPublicKeyRequest) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
+impl UpdateSSHPublicKeyRequest {
+    /// Returns a builder for `UpdateSSHPublicKeyRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSSHPublicKeyRequestBuilder {
+        UpdateSSHPublicKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSSHPublicKeyRequest`](struct.UpdateSSHPublicKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSSHPublicKeyRequestBuilder {
+    ssh_public_key_id: Option<String>,
+    status: Option<StatusType>,
+    user_name: Option<String>,
+}
+
+impl UpdateSSHPublicKeyRequestBuilder {
+    /// Sets the [`ssh_public_key_id`](struct.UpdateSSHPublicKeyRequest.html#structfield.ssh_public_key_id) member.
+    pub fn ssh_public_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.UpdateSSHPublicKeyRequest.html#structfield.status) member.
+    pub fn status<T: Into<StatusType>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.UpdateSSHPublicKeyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSSHPublicKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSSHPublicKeyRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSSHPublicKeyRequest {
+            ssh_public_key_id: self
+                .ssh_public_key_id
+                .ok_or(::rusoto_core::BuildError::missing("ssh_public_key_id"))?,
+            status: self
+                .status
+                .ok_or(::rusoto_core::BuildError::missing("status"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
+/// Serialize `UpdateSSHPublicKeyRequest` contents to a `SignedRequest`.
+struct UpdateSSHPublicKeyRequestSerializer;
+impl UpdateSSHPublicKeyRequestSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &UpdateSSHPublicKeyRequest) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
             prefix.push_str(".");
         }
 
@@ -10322,6 +17653,54 @@ pub struct UpdateServerCertificateRequest {
     pub server_certificate_name: String,
 }
 
+impl UpdateServerCertificateRequest {
+    /// Returns a builder for `UpdateServerCertificateRequest`, checking its required members when built.
+    pub fn builder() -> UpdateServerCertificateRequestBuilder {
+        UpdateServerCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateServerCertificateRequest`](struct.UpdateServerCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateServerCertificateRequestBuilder {
+    new_path: Option<String>,
+    new_server_certificate_name: Option<String>,
+    server_certificate_name: Option<String>,
+}
+
+impl UpdateServerCertificateRequestBuilder {
+    /// Sets the [`new_path`](struct.UpdateServerCertificateRequest.html#structfield.new_path) member.
+    pub fn new_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_server_certificate_name`](struct.UpdateServerCertificateRequest.html#structfield.new_server_certificate_name) member.
+    pub fn new_server_certificate_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_server_certificate_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_certificate_name`](struct.UpdateServerCertificateRequest.html#structfield.server_certificate_name) member.
+    pub fn server_certificate_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_certificate_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateServerCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateServerCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateServerCertificateRequest {
+            new_path: self.new_path,
+            new_server_certificate_name: self.new_server_certificate_name,
+            server_certificate_name: self.server_certificate_name.ok_or(
+                ::rusoto_core::BuildError::missing("server_certificate_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `UpdateServerCertificateRequest` contents to a `SignedRequest`.
 struct UpdateServerCertificateRequestSerializer;
 impl UpdateServerCertificateRequestSerializer {
@@ -10347,16 +17726,67 @@ impl UpdateServerCertificateRequestSerializer {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UpdateServiceSpecificCredentialRequest {
     /// <p>The unique identifier of the service-specific credential.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
     pub service_specific_credential_id: String,
     /// <p>The status to be assigned to the service-specific credential.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the IAM user associated with the service-specific credential. If you do not specify this value, then the operation assumes the user whose credentials are used to call the operation.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
     pub user_name: Option<String>,
 }
 
+impl UpdateServiceSpecificCredentialRequest {
+    /// Returns a builder for `UpdateServiceSpecificCredentialRequest`, checking its required members when built.
+    pub fn builder() -> UpdateServiceSpecificCredentialRequestBuilder {
+        UpdateServiceSpecificCredentialRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateServiceSpecificCredentialRequest`](struct.UpdateServiceSpecificCredentialRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateServiceSpecificCredentialRequestBuilder {
+    service_specific_credential_id: Option<String>,
+    status: Option<StatusType>,
+    user_name: Option<String>,
+}
+
+impl UpdateServiceSpecificCredentialRequestBuilder {
+    /// Sets the [`service_specific_credential_id`](struct.UpdateServiceSpecificCredentialRequest.html#structfield.service_specific_credential_id) member.
+    pub fn service_specific_credential_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_specific_credential_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.UpdateServiceSpecificCredentialRequest.html#structfield.status) member.
+    pub fn status<T: Into<StatusType>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.UpdateServiceSpecificCredentialRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateServiceSpecificCredentialRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateServiceSpecificCredentialRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateServiceSpecificCredentialRequest {
+            service_specific_credential_id: self.service_specific_credential_id.ok_or(
+                ::rusoto_core::BuildError::missing("service_specific_credential_id"),
+            )?,
+            status: self
+                .status
+                .ok_or(::rusoto_core::BuildError::missing("status"))?,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `UpdateServiceSpecificCredentialRequest` contents to a `SignedRequest`.
 struct UpdateServiceSpecificCredentialRequestSerializer;
 impl UpdateServiceSpecificCredentialRequestSerializer {
@@ -10377,16 +17807,66 @@ impl UpdateServiceSpecificCredentialRequestSerializer {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UpdateSigningCertificateRequest {
     /// <p>The ID of the signing certificate you want to update.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters that can consist of any upper or lowercased letter or digit.</p>
     pub certificate_id: String,
     /// <p> The status you want to assign to the certificate. <code>Active</code> means that the certificate can be used for API calls to AWS <code>Inactive</code> means that the certificate cannot be used.</p>
-    pub status: String,
+    pub status: StatusType,
     /// <p>The name of the IAM user the signing certificate belongs to.</p> <p>This parameter allows (through its <a href="http://wikipedia.org/wiki/regex">regex pattern</a>) a string of characters consisting of upper and lowercase alphanumeric characters with no spaces. You can also include any of the following characters: _+=,.@-</p>
     pub user_name: Option<String>,
 }
 
+impl UpdateSigningCertificateRequest {
+    /// Returns a builder for `UpdateSigningCertificateRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSigningCertificateRequestBuilder {
+        UpdateSigningCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSigningCertificateRequest`](struct.UpdateSigningCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSigningCertificateRequestBuilder {
+    certificate_id: Option<String>,
+    status: Option<StatusType>,
+    user_name: Option<String>,
+}
+
+impl UpdateSigningCertificateRequestBuilder {
+    /// Sets the [`certificate_id`](struct.UpdateSigningCertificateRequest.html#structfield.certificate_id) member.
+    pub fn certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.UpdateSigningCertificateRequest.html#structfield.status) member.
+    pub fn status<T: Into<StatusType>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.UpdateSigningCertificateRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSigningCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSigningCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSigningCertificateRequest {
+            certificate_id: self
+                .certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("certificate_id"))?,
+            status: self
+                .status
+                .ok_or(::rusoto_core::BuildError::missing("status"))?,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `UpdateSigningCertificateRequest` contents to a `SignedRequest`.
 struct UpdateSigningCertificateRequestSerializer;
 impl UpdateSigningCertificateRequestSerializer {
@@ -10417,6 +17897,52 @@ pub struct UpdateUserRequest {
     pub user_name: String,
 }
 
+impl UpdateUserRequest {
+    /// Returns a builder for `UpdateUserRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserRequestBuilder {
+        UpdateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserRequest`](struct.UpdateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserRequestBuilder {
+    new_path: Option<String>,
+    new_user_name: Option<String>,
+    user_name: Option<String>,
+}
+
+impl UpdateUserRequestBuilder {
+    /// Sets the [`new_path`](struct.UpdateUserRequest.html#structfield.new_path) member.
+    pub fn new_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_user_name`](struct.UpdateUserRequest.html#structfield.new_user_name) member.
+    pub fn new_user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_user_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.UpdateUserRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateUserRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserRequest {
+            new_path: self.new_path,
+            new_user_name: self.new_user_name,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateUserRequest` contents to a `SignedRequest`.
 struct UpdateUserRequestSerializer;
 impl UpdateUserRequestSerializer {
@@ -10444,6 +17970,48 @@ pub struct UploadSSHPublicKeyRequest {
     pub user_name: String,
 }
 
+impl UploadSSHPublicKeyRequest {
+    /// Returns a builder for `UploadSSHPublicKeyRequest`, checking its required members when built.
+    pub fn builder() -> UploadSSHPublicKeyRequestBuilder {
+        UploadSSHPublicKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UploadSSHPublicKeyRequest`](struct.UploadSSHPublicKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct UploadSSHPublicKeyRequestBuilder {
+    ssh_public_key_body: Option<String>,
+    user_name: Option<String>,
+}
+
+impl UploadSSHPublicKeyRequestBuilder {
+    /// Sets the [`ssh_public_key_body`](struct.UploadSSHPublicKeyRequest.html#structfield.ssh_public_key_body) member.
+    pub fn ssh_public_key_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.UploadSSHPublicKeyRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UploadSSHPublicKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UploadSSHPublicKeyRequest, ::rusoto_core::BuildError> {
+        Ok(UploadSSHPublicKeyRequest {
+            ssh_public_key_body: self
+                .ssh_public_key_body
+                .ok_or(::rusoto_core::BuildError::missing("ssh_public_key_body"))?,
+            user_name: self
+                .user_name
+                .ok_or(::rusoto_core::BuildError::missing("user_name"))?,
+        })
+    }
+}
+
 /// Serialize `UploadSSHPublicKeyRequest` contents to a `SignedRequest`.
 struct UploadSSHPublicKeyRequestSerializer;
 impl UploadSSHPublicKeyRequestSerializer {
@@ -10468,10 +18036,10 @@ pub struct UploadSSHPublicKeyResponse {
     pub ssh_public_key: Option<SSHPublicKey>,
 }
 
-struct UploadSSHPublicKeyResponseDeserializer;
+pub(crate) struct UploadSSHPublicKeyResponseDeserializer;
 impl UploadSSHPublicKeyResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UploadSSHPublicKeyResponse, XmlParseError> {
@@ -10507,6 +18075,74 @@ pub struct UploadServerCertificateRequest {
     pub server_certificate_name: String,
 }
 
+impl UploadServerCertificateRequest {
+    /// Returns a builder for `UploadServerCertificateRequest`, checking its required members when built.
+    pub fn builder() -> UploadServerCertificateRequestBuilder {
+        UploadServerCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UploadServerCertificateRequest`](struct.UploadServerCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct UploadServerCertificateRequestBuilder {
+    certificate_body: Option<String>,
+    certificate_chain: Option<String>,
+    path: Option<String>,
+    private_key: Option<String>,
+    server_certificate_name: Option<String>,
+}
+
+impl UploadServerCertificateRequestBuilder {
+    /// Sets the [`certificate_body`](struct.UploadServerCertificateRequest.html#structfield.certificate_body) member.
+    pub fn certificate_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_chain`](struct.UploadServerCertificateRequest.html#structfield.certificate_chain) member.
+    pub fn certificate_chain<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_chain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`path`](struct.UploadServerCertificateRequest.html#structfield.path) member.
+    pub fn path<T: Into<String>>(mut self, value: T) -> Self {
+        self.path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`private_key`](struct.UploadServerCertificateRequest.html#structfield.private_key) member.
+    pub fn private_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.private_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_certificate_name`](struct.UploadServerCertificateRequest.html#structfield.server_certificate_name) member.
+    pub fn server_certificate_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_certificate_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UploadServerCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UploadServerCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(UploadServerCertificateRequest {
+            certificate_body: self
+                .certificate_body
+                .ok_or(::rusoto_core::BuildError::missing("certificate_body"))?,
+            certificate_chain: self.certificate_chain,
+            path: self.path,
+            private_key: self
+                .private_key
+                .ok_or(::rusoto_core::BuildError::missing("private_key"))?,
+            server_certificate_name: self.server_certificate_name.ok_or(
+                ::rusoto_core::BuildError::missing("server_certificate_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `UploadServerCertificateRequest` contents to a `SignedRequest`.
 struct UploadServerCertificateRequestSerializer;
 impl UploadServerCertificateRequestSerializer {
@@ -10541,10 +18177,10 @@ pub struct UploadServerCertificateResponse {
     pub server_certificate_metadata: Option<ServerCertificateMetadata>,
 }
 
-struct UploadServerCertificateResponseDeserializer;
+pub(crate) struct UploadServerCertificateResponseDeserializer;
 impl UploadServerCertificateResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UploadServerCertificateResponse, XmlParseError> {
@@ -10575,6 +18211,46 @@ pub struct UploadSigningCertificateRequest {
     pub user_name: Option<String>,
 }
 
+impl UploadSigningCertificateRequest {
+    /// Returns a builder for `UploadSigningCertificateRequest`, checking its required members when built.
+    pub fn builder() -> UploadSigningCertificateRequestBuilder {
+        UploadSigningCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UploadSigningCertificateRequest`](struct.UploadSigningCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct UploadSigningCertificateRequestBuilder {
+    certificate_body: Option<String>,
+    user_name: Option<String>,
+}
+
+impl UploadSigningCertificateRequestBuilder {
+    /// Sets the [`certificate_body`](struct.UploadSigningCertificateRequest.html#structfield.certificate_body) member.
+    pub fn certificate_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_body = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_name`](struct.UploadSigningCertificateRequest.html#structfield.user_name) member.
+    pub fn user_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UploadSigningCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UploadSigningCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(UploadSigningCertificateRequest {
+            certificate_body: self
+                .certificate_body
+                .ok_or(::rusoto_core::BuildError::missing("certificate_body"))?,
+            user_name: self.user_name,
+        })
+    }
+}
+
 /// Serialize `UploadSigningCertificateRequest` contents to a `SignedRequest`.
 struct UploadSigningCertificateRequestSerializer;
 impl UploadSigningCertificateRequestSerializer {
@@ -10595,22 +18271,31 @@ impl UploadSigningCertificateRequestSerializer {
 }
 
 /// <p>Contains the response to a successful <a>UploadSigningCertificate</a> request. </p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UploadSigningCertificateResponse {
     /// <p>Information about the certificate.</p>
     pub certificate: SigningCertificate,
 }
 
-struct UploadSigningCertificateResponseDeserializer;
+pub(crate) struct UploadSigningCertificateResponseDeserializer;
 impl UploadSigningCertificateResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UploadSigningCertificateResponse, XmlParseError> {
-        deserialize_elements::<_, UploadSigningCertificateResponse, _>(
+        ::rusoto_core::proto::xml::util::deserialize_elements_into(
             tag_name,
             stack,
+            UploadSigningCertificateResponse {
+                certificate: SigningCertificate {
+                    certificate_body: Default::default(),
+                    certificate_id: Default::default(),
+                    status: StatusType::Unknown(String::new()),
+                    upload_date: Default::default(),
+                    user_name: Default::default(),
+                },
+            },
             |name, stack, obj| {
                 match name {
                     "Certificate" => {
@@ -10630,9 +18315,9 @@ pub struct User {
     /// <p>The Amazon Resource Name (ARN) that identifies the user. For more information about ARNs and how to use ARNs in policies, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide. </p>
     pub arn: String,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the user was created.</p>
-    pub create_date: String,
+    pub create_date: IsoTimestamp,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the user's password was last used to sign in to an AWS website. For a list of AWS websites that capture a user's last sign-in time, see the <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/credential-reports.html">Credential Reports</a> topic in the <i>Using IAM</i> guide. If a password is used more than once in a five-minute span, only the first use is returned in this field. If the field is null (no value), then it indicates that they never signed in with a password. This can be because:</p> <ul> <li> <p>The user never had a password.</p> </li> <li> <p>A password exists but has not been used since IAM started tracking this information on October 20, 2014.</p> </li> </ul> <p>A null value does not mean that the user <i>never</i> had a password. Also, if the user does not currently have a password, but had one in the past, then this field contains the date and time the most recent password was used.</p> <p>This value is returned only in the <a>GetUser</a> and <a>ListUsers</a> operations. </p>
-    pub password_last_used: Option<String>,
+    pub password_last_used: Option<IsoTimestamp>,
     /// <p>The path to the user. For more information about paths, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide.</p>
     pub path: String,
     /// <p>The ARN of the policy used to set the permissions boundary for the user.</p> <p>For more information about permissions boundaries, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies_boundaries.html">Permissions Boundaries for IAM Identities </a> in the <i>IAM User Guide</i>.</p>
@@ -10645,10 +18330,13 @@ pub struct User {
     pub user_name: String,
 }
 
-struct UserDeserializer;
+pub(crate) struct UserDeserializer;
 impl UserDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<User, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<User, XmlParseError> {
         deserialize_elements::<_, User, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Arn" => {
@@ -10697,7 +18385,7 @@ pub struct UserDetail {
     /// <p>A list of the managed policies attached to the user.</p>
     pub attached_managed_policies: Option<Vec<AttachedPolicy>>,
     /// <p>The date and time, in <a href="http://www.iso.org/iso/iso8601">ISO 8601 date-time format</a>, when the user was created.</p>
-    pub create_date: Option<String>,
+    pub create_date: Option<IsoTimestamp>,
     /// <p>A list of IAM groups that the user is in.</p>
     pub group_list: Option<Vec<String>>,
     /// <p>The path to the user. For more information about paths, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">IAM Identifiers</a> in the <i>Using IAM</i> guide.</p>
@@ -10714,10 +18402,10 @@ pub struct UserDetail {
     pub user_policy_list: Option<Vec<PolicyDetail>>,
 }
 
-struct UserDetailDeserializer;
+pub(crate) struct UserDetailDeserializer;
 impl UserDetailDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UserDetail, XmlParseError> {
@@ -10774,10 +18462,10 @@ impl UserDetailDeserializer {
         })
     }
 }
-struct UserDetailListTypeDeserializer;
+pub(crate) struct UserDetailListTypeDeserializer;
 impl UserDetailListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<UserDetail>, XmlParseError> {
@@ -10791,10 +18479,10 @@ impl UserDetailListTypeDeserializer {
         })
     }
 }
-struct UserListTypeDeserializer;
+pub(crate) struct UserListTypeDeserializer;
 impl UserListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<User>, XmlParseError> {
@@ -10808,10 +18496,13 @@ impl UserListTypeDeserializer {
         })
     }
 }
-struct UserNameTypeDeserializer;
+pub(crate) struct UserNameTypeDeserializer;
 impl UserNameTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -10825,7 +18516,7 @@ pub struct VirtualMFADevice {
     /// <p> The base32 seed defined as specified in <a href="https://tools.ietf.org/html/rfc3548.txt">RFC3548</a>. The <code>Base32StringSeed</code> is base64-encoded. </p>
     pub base_32_string_seed: Option<bytes::Bytes>,
     /// <p>The date and time on which the virtual MFA device was enabled.</p>
-    pub enable_date: Option<String>,
+    pub enable_date: Option<IsoTimestamp>,
     /// <p> A QR code PNG image that encodes <code>otpauth://totp/$virtualMFADeviceName@$AccountName?secret=$Base32String</code> where <code>$virtualMFADeviceName</code> is one of the create call arguments. <code>AccountName</code> is the user name if set (otherwise, the account ID otherwise), and <code>Base32String</code> is the seed in base32 format. The <code>Base32String</code> value is base64-encoded. </p>
     pub qr_code_png: Option<bytes::Bytes>,
     /// <p>The serial number associated with <code>VirtualMFADevice</code>.</p>
@@ -10834,10 +18525,10 @@ pub struct VirtualMFADevice {
     pub user: Option<User>,
 }
 
-struct VirtualMFADeviceDeserializer;
+pub(crate) struct VirtualMFADeviceDeserializer;
 impl VirtualMFADeviceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<VirtualMFADevice, XmlParseError> {
@@ -10869,10 +18560,10 @@ impl VirtualMFADeviceDeserializer {
         })
     }
 }
-struct VirtualMFADeviceListTypeDeserializer;
+pub(crate) struct VirtualMFADeviceListTypeDeserializer;
 impl VirtualMFADeviceListTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<VirtualMFADevice>, XmlParseError> {
@@ -20751,40 +28442,917 @@ pub trait Iam {
         input: UploadSigningCertificateRequest,
     ) -> RusotoFuture<UploadSigningCertificateResponse, UploadSigningCertificateError>;
 }
-/// A client for the IAM API.
-#[derive(Clone)]
-pub struct IamClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl IamClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> IamClient {
-        Self::new_with_client(Client::shared(), region)
+impl<T: ?Sized + Iam> Iam for ::std::sync::Arc<T> {
+    fn add_client_id_to_open_id_connect_provider(
+        &self,
+        input: AddClientIDToOpenIDConnectProviderRequest,
+    ) -> RusotoFuture<(), AddClientIDToOpenIDConnectProviderError> {
+        (**self).add_client_id_to_open_id_connect_provider(input)
     }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> IamClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
+    fn add_role_to_instance_profile(
+        &self,
+        input: AddRoleToInstanceProfileRequest,
+    ) -> RusotoFuture<(), AddRoleToInstanceProfileError> {
+        (**self).add_role_to_instance_profile(input)
+    }
+    fn add_user_to_group(
+        &self,
+        input: AddUserToGroupRequest,
+    ) -> RusotoFuture<(), AddUserToGroupError> {
+        (**self).add_user_to_group(input)
+    }
+    fn attach_group_policy(
+        &self,
+        input: AttachGroupPolicyRequest,
+    ) -> RusotoFuture<(), AttachGroupPolicyError> {
+        (**self).attach_group_policy(input)
+    }
+    fn attach_role_policy(
+        &self,
+        input: AttachRolePolicyRequest,
+    ) -> RusotoFuture<(), AttachRolePolicyError> {
+        (**self).attach_role_policy(input)
+    }
+    fn attach_user_policy(
+        &self,
+        input: AttachUserPolicyRequest,
+    ) -> RusotoFuture<(), AttachUserPolicyError> {
+        (**self).attach_user_policy(input)
+    }
+    fn change_password(
+        &self,
+        input: ChangePasswordRequest,
+    ) -> RusotoFuture<(), ChangePasswordError> {
+        (**self).change_password(input)
+    }
+    fn create_access_key(
+        &self,
+        input: CreateAccessKeyRequest,
+    ) -> RusotoFuture<CreateAccessKeyResponse, CreateAccessKeyError> {
+        (**self).create_access_key(input)
+    }
+    fn create_account_alias(
+        &self,
+        input: CreateAccountAliasRequest,
+    ) -> RusotoFuture<(), CreateAccountAliasError> {
+        (**self).create_account_alias(input)
+    }
+    fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> RusotoFuture<CreateGroupResponse, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn create_instance_profile(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> RusotoFuture<CreateInstanceProfileResponse, CreateInstanceProfileError> {
+        (**self).create_instance_profile(input)
+    }
+    fn create_login_profile(
+        &self,
+        input: CreateLoginProfileRequest,
+    ) -> RusotoFuture<CreateLoginProfileResponse, CreateLoginProfileError> {
+        (**self).create_login_profile(input)
+    }
+    fn create_open_id_connect_provider(
+        &self,
+        input: CreateOpenIDConnectProviderRequest,
+    ) -> RusotoFuture<CreateOpenIDConnectProviderResponse, CreateOpenIDConnectProviderError> {
+        (**self).create_open_id_connect_provider(input)
+    }
+    fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> RusotoFuture<CreatePolicyResponse, CreatePolicyError> {
+        (**self).create_policy(input)
+    }
+    fn create_policy_version(
+        &self,
+        input: CreatePolicyVersionRequest,
+    ) -> RusotoFuture<CreatePolicyVersionResponse, CreatePolicyVersionError> {
+        (**self).create_policy_version(input)
+    }
+    fn create_role(
+        &self,
+        input: CreateRoleRequest,
+    ) -> RusotoFuture<CreateRoleResponse, CreateRoleError> {
+        (**self).create_role(input)
+    }
+    fn create_saml_provider(
+        &self,
+        input: CreateSAMLProviderRequest,
+    ) -> RusotoFuture<CreateSAMLProviderResponse, CreateSAMLProviderError> {
+        (**self).create_saml_provider(input)
+    }
+    fn create_service_linked_role(
+        &self,
+        input: CreateServiceLinkedRoleRequest,
+    ) -> RusotoFuture<CreateServiceLinkedRoleResponse, CreateServiceLinkedRoleError> {
+        (**self).create_service_linked_role(input)
+    }
+    fn create_service_specific_credential(
+        &self,
+        input: CreateServiceSpecificCredentialRequest,
+    ) -> RusotoFuture<CreateServiceSpecificCredentialResponse, CreateServiceSpecificCredentialError>
     {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
+        (**self).create_service_specific_credential(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn create_virtual_mfa_device(
+        &self,
+        input: CreateVirtualMFADeviceRequest,
+    ) -> RusotoFuture<CreateVirtualMFADeviceResponse, CreateVirtualMFADeviceError> {
+        (**self).create_virtual_mfa_device(input)
+    }
+    fn deactivate_mfa_device(
+        &self,
+        input: DeactivateMFADeviceRequest,
+    ) -> RusotoFuture<(), DeactivateMFADeviceError> {
+        (**self).deactivate_mfa_device(input)
+    }
+    fn delete_access_key(
+        &self,
+        input: DeleteAccessKeyRequest,
+    ) -> RusotoFuture<(), DeleteAccessKeyError> {
+        (**self).delete_access_key(input)
+    }
+    fn delete_account_alias(
+        &self,
+        input: DeleteAccountAliasRequest,
+    ) -> RusotoFuture<(), DeleteAccountAliasError> {
+        (**self).delete_account_alias(input)
+    }
+    fn delete_account_password_policy(&self) -> RusotoFuture<(), DeleteAccountPasswordPolicyError> {
+        (**self).delete_account_password_policy()
+    }
+    fn delete_group(&self, input: DeleteGroupRequest) -> RusotoFuture<(), DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn delete_group_policy(
+        &self,
+        input: DeleteGroupPolicyRequest,
+    ) -> RusotoFuture<(), DeleteGroupPolicyError> {
+        (**self).delete_group_policy(input)
+    }
+    fn delete_instance_profile(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> RusotoFuture<(), DeleteInstanceProfileError> {
+        (**self).delete_instance_profile(input)
+    }
+    fn delete_login_profile(
+        &self,
+        input: DeleteLoginProfileRequest,
+    ) -> RusotoFuture<(), DeleteLoginProfileError> {
+        (**self).delete_login_profile(input)
+    }
+    fn delete_open_id_connect_provider(
+        &self,
+        input: DeleteOpenIDConnectProviderRequest,
+    ) -> RusotoFuture<(), DeleteOpenIDConnectProviderError> {
+        (**self).delete_open_id_connect_provider(input)
+    }
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        (**self).delete_policy(input)
+    }
+    fn delete_policy_version(
+        &self,
+        input: DeletePolicyVersionRequest,
+    ) -> RusotoFuture<(), DeletePolicyVersionError> {
+        (**self).delete_policy_version(input)
+    }
+    fn delete_role(&self, input: DeleteRoleRequest) -> RusotoFuture<(), DeleteRoleError> {
+        (**self).delete_role(input)
+    }
+    fn delete_role_permissions_boundary(
+        &self,
+        input: DeleteRolePermissionsBoundaryRequest,
+    ) -> RusotoFuture<(), DeleteRolePermissionsBoundaryError> {
+        (**self).delete_role_permissions_boundary(input)
+    }
+    fn delete_role_policy(
+        &self,
+        input: DeleteRolePolicyRequest,
+    ) -> RusotoFuture<(), DeleteRolePolicyError> {
+        (**self).delete_role_policy(input)
+    }
+    fn delete_saml_provider(
+        &self,
+        input: DeleteSAMLProviderRequest,
+    ) -> RusotoFuture<(), DeleteSAMLProviderError> {
+        (**self).delete_saml_provider(input)
+    }
+    fn delete_ssh_public_key(
+        &self,
+        input: DeleteSSHPublicKeyRequest,
+    ) -> RusotoFuture<(), DeleteSSHPublicKeyError> {
+        (**self).delete_ssh_public_key(input)
+    }
+    fn delete_server_certificate(
+        &self,
+        input: DeleteServerCertificateRequest,
+    ) -> RusotoFuture<(), DeleteServerCertificateError> {
+        (**self).delete_server_certificate(input)
+    }
+    fn delete_service_linked_role(
+        &self,
+        input: DeleteServiceLinkedRoleRequest,
+    ) -> RusotoFuture<DeleteServiceLinkedRoleResponse, DeleteServiceLinkedRoleError> {
+        (**self).delete_service_linked_role(input)
+    }
+    fn delete_service_specific_credential(
+        &self,
+        input: DeleteServiceSpecificCredentialRequest,
+    ) -> RusotoFuture<(), DeleteServiceSpecificCredentialError> {
+        (**self).delete_service_specific_credential(input)
+    }
+    fn delete_signing_certificate(
+        &self,
+        input: DeleteSigningCertificateRequest,
+    ) -> RusotoFuture<(), DeleteSigningCertificateError> {
+        (**self).delete_signing_certificate(input)
+    }
+    fn delete_user(&self, input: DeleteUserRequest) -> RusotoFuture<(), DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn delete_user_permissions_boundary(
+        &self,
+        input: DeleteUserPermissionsBoundaryRequest,
+    ) -> RusotoFuture<(), DeleteUserPermissionsBoundaryError> {
+        (**self).delete_user_permissions_boundary(input)
+    }
+    fn delete_user_policy(
+        &self,
+        input: DeleteUserPolicyRequest,
+    ) -> RusotoFuture<(), DeleteUserPolicyError> {
+        (**self).delete_user_policy(input)
+    }
+    fn delete_virtual_mfa_device(
+        &self,
+        input: DeleteVirtualMFADeviceRequest,
+    ) -> RusotoFuture<(), DeleteVirtualMFADeviceError> {
+        (**self).delete_virtual_mfa_device(input)
+    }
+    fn detach_group_policy(
+        &self,
+        input: DetachGroupPolicyRequest,
+    ) -> RusotoFuture<(), DetachGroupPolicyError> {
+        (**self).detach_group_policy(input)
+    }
+    fn detach_role_policy(
+        &self,
+        input: DetachRolePolicyRequest,
+    ) -> RusotoFuture<(), DetachRolePolicyError> {
+        (**self).detach_role_policy(input)
+    }
+    fn detach_user_policy(
+        &self,
+        input: DetachUserPolicyRequest,
+    ) -> RusotoFuture<(), DetachUserPolicyError> {
+        (**self).detach_user_policy(input)
+    }
+    fn enable_mfa_device(
+        &self,
+        input: EnableMFADeviceRequest,
+    ) -> RusotoFuture<(), EnableMFADeviceError> {
+        (**self).enable_mfa_device(input)
+    }
+    fn generate_credential_report(
+        &self,
+    ) -> RusotoFuture<GenerateCredentialReportResponse, GenerateCredentialReportError> {
+        (**self).generate_credential_report()
+    }
+    fn generate_organizations_access_report(
+        &self,
+        input: GenerateOrganizationsAccessReportRequest,
+    ) -> RusotoFuture<
+        GenerateOrganizationsAccessReportResponse,
+        GenerateOrganizationsAccessReportError,
+    > {
+        (**self).generate_organizations_access_report(input)
+    }
+    fn generate_service_last_accessed_details(
+        &self,
+        input: GenerateServiceLastAccessedDetailsRequest,
+    ) -> RusotoFuture<
+        GenerateServiceLastAccessedDetailsResponse,
+        GenerateServiceLastAccessedDetailsError,
+    > {
+        (**self).generate_service_last_accessed_details(input)
+    }
+    fn get_access_key_last_used(
+        &self,
+        input: GetAccessKeyLastUsedRequest,
+    ) -> RusotoFuture<GetAccessKeyLastUsedResponse, GetAccessKeyLastUsedError> {
+        (**self).get_access_key_last_used(input)
+    }
+    fn get_account_authorization_details(
+        &self,
+        input: GetAccountAuthorizationDetailsRequest,
+    ) -> RusotoFuture<GetAccountAuthorizationDetailsResponse, GetAccountAuthorizationDetailsError>
+    {
+        (**self).get_account_authorization_details(input)
+    }
+    fn get_account_password_policy(
+        &self,
+    ) -> RusotoFuture<GetAccountPasswordPolicyResponse, GetAccountPasswordPolicyError> {
+        (**self).get_account_password_policy()
+    }
+    fn get_account_summary(
+        &self,
+    ) -> RusotoFuture<GetAccountSummaryResponse, GetAccountSummaryError> {
+        (**self).get_account_summary()
+    }
+    fn get_context_keys_for_custom_policy(
+        &self,
+        input: GetContextKeysForCustomPolicyRequest,
+    ) -> RusotoFuture<GetContextKeysForPolicyResponse, GetContextKeysForCustomPolicyError> {
+        (**self).get_context_keys_for_custom_policy(input)
+    }
+    fn get_context_keys_for_principal_policy(
+        &self,
+        input: GetContextKeysForPrincipalPolicyRequest,
+    ) -> RusotoFuture<GetContextKeysForPolicyResponse, GetContextKeysForPrincipalPolicyError> {
+        (**self).get_context_keys_for_principal_policy(input)
+    }
+    fn get_credential_report(
+        &self,
+    ) -> RusotoFuture<GetCredentialReportResponse, GetCredentialReportError> {
+        (**self).get_credential_report()
+    }
+    fn get_group(&self, input: GetGroupRequest) -> RusotoFuture<GetGroupResponse, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_group_policy(
+        &self,
+        input: GetGroupPolicyRequest,
+    ) -> RusotoFuture<GetGroupPolicyResponse, GetGroupPolicyError> {
+        (**self).get_group_policy(input)
+    }
+    fn get_instance_profile(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> RusotoFuture<GetInstanceProfileResponse, GetInstanceProfileError> {
+        (**self).get_instance_profile(input)
+    }
+    fn get_login_profile(
+        &self,
+        input: GetLoginProfileRequest,
+    ) -> RusotoFuture<GetLoginProfileResponse, GetLoginProfileError> {
+        (**self).get_login_profile(input)
+    }
+    fn get_open_id_connect_provider(
+        &self,
+        input: GetOpenIDConnectProviderRequest,
+    ) -> RusotoFuture<GetOpenIDConnectProviderResponse, GetOpenIDConnectProviderError> {
+        (**self).get_open_id_connect_provider(input)
+    }
+    fn get_organizations_access_report(
+        &self,
+        input: GetOrganizationsAccessReportRequest,
+    ) -> RusotoFuture<GetOrganizationsAccessReportResponse, GetOrganizationsAccessReportError> {
+        (**self).get_organizations_access_report(input)
+    }
+    fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        (**self).get_policy(input)
+    }
+    fn get_policy_version(
+        &self,
+        input: GetPolicyVersionRequest,
+    ) -> RusotoFuture<GetPolicyVersionResponse, GetPolicyVersionError> {
+        (**self).get_policy_version(input)
+    }
+    fn get_role(&self, input: GetRoleRequest) -> RusotoFuture<GetRoleResponse, GetRoleError> {
+        (**self).get_role(input)
+    }
+    fn get_role_policy(
+        &self,
+        input: GetRolePolicyRequest,
+    ) -> RusotoFuture<GetRolePolicyResponse, GetRolePolicyError> {
+        (**self).get_role_policy(input)
+    }
+    fn get_saml_provider(
+        &self,
+        input: GetSAMLProviderRequest,
+    ) -> RusotoFuture<GetSAMLProviderResponse, GetSAMLProviderError> {
+        (**self).get_saml_provider(input)
+    }
+    fn get_ssh_public_key(
+        &self,
+        input: GetSSHPublicKeyRequest,
+    ) -> RusotoFuture<GetSSHPublicKeyResponse, GetSSHPublicKeyError> {
+        (**self).get_ssh_public_key(input)
+    }
+    fn get_server_certificate(
+        &self,
+        input: GetServerCertificateRequest,
+    ) -> RusotoFuture<GetServerCertificateResponse, GetServerCertificateError> {
+        (**self).get_server_certificate(input)
+    }
+    fn get_service_last_accessed_details(
+        &self,
+        input: GetServiceLastAccessedDetailsRequest,
+    ) -> RusotoFuture<GetServiceLastAccessedDetailsResponse, GetServiceLastAccessedDetailsError>
+    {
+        (**self).get_service_last_accessed_details(input)
+    }
+    fn get_service_last_accessed_details_with_entities(
+        &self,
+        input: GetServiceLastAccessedDetailsWithEntitiesRequest,
+    ) -> RusotoFuture<
+        GetServiceLastAccessedDetailsWithEntitiesResponse,
+        GetServiceLastAccessedDetailsWithEntitiesError,
+    > {
+        (**self).get_service_last_accessed_details_with_entities(input)
+    }
+    fn get_service_linked_role_deletion_status(
+        &self,
+        input: GetServiceLinkedRoleDeletionStatusRequest,
+    ) -> RusotoFuture<
+        GetServiceLinkedRoleDeletionStatusResponse,
+        GetServiceLinkedRoleDeletionStatusError,
+    > {
+        (**self).get_service_linked_role_deletion_status(input)
+    }
+    fn get_user(&self, input: GetUserRequest) -> RusotoFuture<GetUserResponse, GetUserError> {
+        (**self).get_user(input)
+    }
+    fn get_user_policy(
+        &self,
+        input: GetUserPolicyRequest,
+    ) -> RusotoFuture<GetUserPolicyResponse, GetUserPolicyError> {
+        (**self).get_user_policy(input)
+    }
+    fn list_access_keys(
+        &self,
+        input: ListAccessKeysRequest,
+    ) -> RusotoFuture<ListAccessKeysResponse, ListAccessKeysError> {
+        (**self).list_access_keys(input)
+    }
+    fn list_account_aliases(
+        &self,
+        input: ListAccountAliasesRequest,
+    ) -> RusotoFuture<ListAccountAliasesResponse, ListAccountAliasesError> {
+        (**self).list_account_aliases(input)
+    }
+    fn list_attached_group_policies(
+        &self,
+        input: ListAttachedGroupPoliciesRequest,
+    ) -> RusotoFuture<ListAttachedGroupPoliciesResponse, ListAttachedGroupPoliciesError> {
+        (**self).list_attached_group_policies(input)
+    }
+    fn list_attached_role_policies(
+        &self,
+        input: ListAttachedRolePoliciesRequest,
+    ) -> RusotoFuture<ListAttachedRolePoliciesResponse, ListAttachedRolePoliciesError> {
+        (**self).list_attached_role_policies(input)
+    }
+    fn list_attached_user_policies(
+        &self,
+        input: ListAttachedUserPoliciesRequest,
+    ) -> RusotoFuture<ListAttachedUserPoliciesResponse, ListAttachedUserPoliciesError> {
+        (**self).list_attached_user_policies(input)
+    }
+    fn list_entities_for_policy(
+        &self,
+        input: ListEntitiesForPolicyRequest,
+    ) -> RusotoFuture<ListEntitiesForPolicyResponse, ListEntitiesForPolicyError> {
+        (**self).list_entities_for_policy(input)
+    }
+    fn list_group_policies(
+        &self,
+        input: ListGroupPoliciesRequest,
+    ) -> RusotoFuture<ListGroupPoliciesResponse, ListGroupPoliciesError> {
+        (**self).list_group_policies(input)
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> RusotoFuture<ListGroupsResponse, ListGroupsError> {
+        (**self).list_groups(input)
+    }
+    fn list_groups_for_user(
+        &self,
+        input: ListGroupsForUserRequest,
+    ) -> RusotoFuture<ListGroupsForUserResponse, ListGroupsForUserError> {
+        (**self).list_groups_for_user(input)
+    }
+    fn list_instance_profiles(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> RusotoFuture<ListInstanceProfilesResponse, ListInstanceProfilesError> {
+        (**self).list_instance_profiles(input)
+    }
+    fn list_instance_profiles_for_role(
+        &self,
+        input: ListInstanceProfilesForRoleRequest,
+    ) -> RusotoFuture<ListInstanceProfilesForRoleResponse, ListInstanceProfilesForRoleError> {
+        (**self).list_instance_profiles_for_role(input)
+    }
+    fn list_mfa_devices(
+        &self,
+        input: ListMFADevicesRequest,
+    ) -> RusotoFuture<ListMFADevicesResponse, ListMFADevicesError> {
+        (**self).list_mfa_devices(input)
+    }
+    fn list_open_id_connect_providers(
+        &self,
+        input: ListOpenIDConnectProvidersRequest,
+    ) -> RusotoFuture<ListOpenIDConnectProvidersResponse, ListOpenIDConnectProvidersError> {
+        (**self).list_open_id_connect_providers(input)
+    }
+    fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        (**self).list_policies(input)
+    }
+    fn list_policies_granting_service_access(
+        &self,
+        input: ListPoliciesGrantingServiceAccessRequest,
+    ) -> RusotoFuture<
+        ListPoliciesGrantingServiceAccessResponse,
+        ListPoliciesGrantingServiceAccessError,
+    > {
+        (**self).list_policies_granting_service_access(input)
+    }
+    fn list_policy_versions(
+        &self,
+        input: ListPolicyVersionsRequest,
+    ) -> RusotoFuture<ListPolicyVersionsResponse, ListPolicyVersionsError> {
+        (**self).list_policy_versions(input)
+    }
+    fn list_role_policies(
+        &self,
+        input: ListRolePoliciesRequest,
+    ) -> RusotoFuture<ListRolePoliciesResponse, ListRolePoliciesError> {
+        (**self).list_role_policies(input)
+    }
+    fn list_role_tags(
+        &self,
+        input: ListRoleTagsRequest,
+    ) -> RusotoFuture<ListRoleTagsResponse, ListRoleTagsError> {
+        (**self).list_role_tags(input)
+    }
+    fn list_roles(
+        &self,
+        input: ListRolesRequest,
+    ) -> RusotoFuture<ListRolesResponse, ListRolesError> {
+        (**self).list_roles(input)
+    }
+    fn list_saml_providers(
+        &self,
+        input: ListSAMLProvidersRequest,
+    ) -> RusotoFuture<ListSAMLProvidersResponse, ListSAMLProvidersError> {
+        (**self).list_saml_providers(input)
+    }
+    fn list_ssh_public_keys(
+        &self,
+        input: ListSSHPublicKeysRequest,
+    ) -> RusotoFuture<ListSSHPublicKeysResponse, ListSSHPublicKeysError> {
+        (**self).list_ssh_public_keys(input)
+    }
+    fn list_server_certificates(
+        &self,
+        input: ListServerCertificatesRequest,
+    ) -> RusotoFuture<ListServerCertificatesResponse, ListServerCertificatesError> {
+        (**self).list_server_certificates(input)
+    }
+    fn list_service_specific_credentials(
+        &self,
+        input: ListServiceSpecificCredentialsRequest,
+    ) -> RusotoFuture<ListServiceSpecificCredentialsResponse, ListServiceSpecificCredentialsError>
+    {
+        (**self).list_service_specific_credentials(input)
+    }
+    fn list_signing_certificates(
+        &self,
+        input: ListSigningCertificatesRequest,
+    ) -> RusotoFuture<ListSigningCertificatesResponse, ListSigningCertificatesError> {
+        (**self).list_signing_certificates(input)
+    }
+    fn list_user_policies(
+        &self,
+        input: ListUserPoliciesRequest,
+    ) -> RusotoFuture<ListUserPoliciesResponse, ListUserPoliciesError> {
+        (**self).list_user_policies(input)
+    }
+    fn list_user_tags(
+        &self,
+        input: ListUserTagsRequest,
+    ) -> RusotoFuture<ListUserTagsResponse, ListUserTagsError> {
+        (**self).list_user_tags(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn list_virtual_mfa_devices(
+        &self,
+        input: ListVirtualMFADevicesRequest,
+    ) -> RusotoFuture<ListVirtualMFADevicesResponse, ListVirtualMFADevicesError> {
+        (**self).list_virtual_mfa_devices(input)
+    }
+    fn put_group_policy(
+        &self,
+        input: PutGroupPolicyRequest,
+    ) -> RusotoFuture<(), PutGroupPolicyError> {
+        (**self).put_group_policy(input)
+    }
+    fn put_role_permissions_boundary(
+        &self,
+        input: PutRolePermissionsBoundaryRequest,
+    ) -> RusotoFuture<(), PutRolePermissionsBoundaryError> {
+        (**self).put_role_permissions_boundary(input)
+    }
+    fn put_role_policy(&self, input: PutRolePolicyRequest) -> RusotoFuture<(), PutRolePolicyError> {
+        (**self).put_role_policy(input)
+    }
+    fn put_user_permissions_boundary(
+        &self,
+        input: PutUserPermissionsBoundaryRequest,
+    ) -> RusotoFuture<(), PutUserPermissionsBoundaryError> {
+        (**self).put_user_permissions_boundary(input)
+    }
+    fn put_user_policy(&self, input: PutUserPolicyRequest) -> RusotoFuture<(), PutUserPolicyError> {
+        (**self).put_user_policy(input)
+    }
+    fn remove_client_id_from_open_id_connect_provider(
+        &self,
+        input: RemoveClientIDFromOpenIDConnectProviderRequest,
+    ) -> RusotoFuture<(), RemoveClientIDFromOpenIDConnectProviderError> {
+        (**self).remove_client_id_from_open_id_connect_provider(input)
+    }
+    fn remove_role_from_instance_profile(
+        &self,
+        input: RemoveRoleFromInstanceProfileRequest,
+    ) -> RusotoFuture<(), RemoveRoleFromInstanceProfileError> {
+        (**self).remove_role_from_instance_profile(input)
+    }
+    fn remove_user_from_group(
+        &self,
+        input: RemoveUserFromGroupRequest,
+    ) -> RusotoFuture<(), RemoveUserFromGroupError> {
+        (**self).remove_user_from_group(input)
+    }
+    fn reset_service_specific_credential(
+        &self,
+        input: ResetServiceSpecificCredentialRequest,
+    ) -> RusotoFuture<ResetServiceSpecificCredentialResponse, ResetServiceSpecificCredentialError>
+    {
+        (**self).reset_service_specific_credential(input)
+    }
+    fn resync_mfa_device(
+        &self,
+        input: ResyncMFADeviceRequest,
+    ) -> RusotoFuture<(), ResyncMFADeviceError> {
+        (**self).resync_mfa_device(input)
+    }
+    fn set_default_policy_version(
+        &self,
+        input: SetDefaultPolicyVersionRequest,
+    ) -> RusotoFuture<(), SetDefaultPolicyVersionError> {
+        (**self).set_default_policy_version(input)
+    }
+    fn set_security_token_service_preferences(
+        &self,
+        input: SetSecurityTokenServicePreferencesRequest,
+    ) -> RusotoFuture<(), SetSecurityTokenServicePreferencesError> {
+        (**self).set_security_token_service_preferences(input)
+    }
+    fn simulate_custom_policy(
+        &self,
+        input: SimulateCustomPolicyRequest,
+    ) -> RusotoFuture<SimulatePolicyResponse, SimulateCustomPolicyError> {
+        (**self).simulate_custom_policy(input)
+    }
+    fn simulate_principal_policy(
+        &self,
+        input: SimulatePrincipalPolicyRequest,
+    ) -> RusotoFuture<SimulatePolicyResponse, SimulatePrincipalPolicyError> {
+        (**self).simulate_principal_policy(input)
+    }
+    fn tag_role(&self, input: TagRoleRequest) -> RusotoFuture<(), TagRoleError> {
+        (**self).tag_role(input)
+    }
+    fn tag_user(&self, input: TagUserRequest) -> RusotoFuture<(), TagUserError> {
+        (**self).tag_user(input)
+    }
+    fn untag_role(&self, input: UntagRoleRequest) -> RusotoFuture<(), UntagRoleError> {
+        (**self).untag_role(input)
+    }
+    fn untag_user(&self, input: UntagUserRequest) -> RusotoFuture<(), UntagUserError> {
+        (**self).untag_user(input)
+    }
+    fn update_access_key(
+        &self,
+        input: UpdateAccessKeyRequest,
+    ) -> RusotoFuture<(), UpdateAccessKeyError> {
+        (**self).update_access_key(input)
+    }
+    fn update_account_password_policy(
+        &self,
+        input: UpdateAccountPasswordPolicyRequest,
+    ) -> RusotoFuture<(), UpdateAccountPasswordPolicyError> {
+        (**self).update_account_password_policy(input)
+    }
+    fn update_assume_role_policy(
+        &self,
+        input: UpdateAssumeRolePolicyRequest,
+    ) -> RusotoFuture<(), UpdateAssumeRolePolicyError> {
+        (**self).update_assume_role_policy(input)
+    }
+    fn update_group(&self, input: UpdateGroupRequest) -> RusotoFuture<(), UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_login_profile(
+        &self,
+        input: UpdateLoginProfileRequest,
+    ) -> RusotoFuture<(), UpdateLoginProfileError> {
+        (**self).update_login_profile(input)
+    }
+    fn update_open_id_connect_provider_thumbprint(
+        &self,
+        input: UpdateOpenIDConnectProviderThumbprintRequest,
+    ) -> RusotoFuture<(), UpdateOpenIDConnectProviderThumbprintError> {
+        (**self).update_open_id_connect_provider_thumbprint(input)
+    }
+    fn update_role(
+        &self,
+        input: UpdateRoleRequest,
+    ) -> RusotoFuture<UpdateRoleResponse, UpdateRoleError> {
+        (**self).update_role(input)
+    }
+    fn update_role_description(
+        &self,
+        input: UpdateRoleDescriptionRequest,
+    ) -> RusotoFuture<UpdateRoleDescriptionResponse, UpdateRoleDescriptionError> {
+        (**self).update_role_description(input)
+    }
+    fn update_saml_provider(
+        &self,
+        input: UpdateSAMLProviderRequest,
+    ) -> RusotoFuture<UpdateSAMLProviderResponse, UpdateSAMLProviderError> {
+        (**self).update_saml_provider(input)
+    }
+    fn update_ssh_public_key(
+        &self,
+        input: UpdateSSHPublicKeyRequest,
+    ) -> RusotoFuture<(), UpdateSSHPublicKeyError> {
+        (**self).update_ssh_public_key(input)
+    }
+    fn update_server_certificate(
+        &self,
+        input: UpdateServerCertificateRequest,
+    ) -> RusotoFuture<(), UpdateServerCertificateError> {
+        (**self).update_server_certificate(input)
+    }
+    fn update_service_specific_credential(
+        &self,
+        input: UpdateServiceSpecificCredentialRequest,
+    ) -> RusotoFuture<(), UpdateServiceSpecificCredentialError> {
+        (**self).update_service_specific_credential(input)
+    }
+    fn update_signing_certificate(
+        &self,
+        input: UpdateSigningCertificateRequest,
+    ) -> RusotoFuture<(), UpdateSigningCertificateError> {
+        (**self).update_signing_certificate(input)
+    }
+    fn update_user(&self, input: UpdateUserRequest) -> RusotoFuture<(), UpdateUserError> {
+        (**self).update_user(input)
+    }
+    fn upload_ssh_public_key(
+        &self,
+        input: UploadSSHPublicKeyRequest,
+    ) -> RusotoFuture<UploadSSHPublicKeyResponse, UploadSSHPublicKeyError> {
+        (**self).upload_ssh_public_key(input)
+    }
+    fn upload_server_certificate(
+        &self,
+        input: UploadServerCertificateRequest,
+    ) -> RusotoFuture<UploadServerCertificateResponse, UploadServerCertificateError> {
+        (**self).upload_server_certificate(input)
+    }
+    fn upload_signing_certificate(
+        &self,
+        input: UploadSigningCertificateRequest,
+    ) -> RusotoFuture<UploadSigningCertificateResponse, UploadSigningCertificateError> {
+        (**self).upload_signing_certificate(input)
+    }
+}
+/// The error codes IAM retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
+/// A client for the IAM API.
+#[derive(Clone)]
+pub struct IamClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl IamClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> IamClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_IAM`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> IamClient {
+        Self::new(region::Region::from_env_for_service("IAM"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `iam`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> IamClient {
+        Self::new_with_client(config.client().clone(), config.region_for("iam", "IAM"))
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IamClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> IamClient {
-        IamClient { client, region }
+        IamClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "iam")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "iam", "iam", "IAM")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Iam` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Iam + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for IamClient {
+    fn service_name(&self) -> &'static str {
+        "IAM"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "iam"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> IamClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -21009,7 +29577,15 @@ impl Iam for IamClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = CreateAccessKeyResponse::default();
+                    result = CreateAccessKeyResponse {
+                        access_key: AccessKey {
+                            access_key_id: Default::default(),
+                            create_date: Default::default(),
+                            secret_access_key: Default::default(),
+                            status: StatusType::Unknown(String::new()),
+                            user_name: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -23189,7 +31765,17 @@ impl Iam for IamClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = GetOrganizationsAccessReportResponse::default();
+                    result = GetOrganizationsAccessReportResponse {
+                        access_details: Default::default(),
+                        error_details: Default::default(),
+                        is_truncated: Default::default(),
+                        job_completion_date: Default::default(),
+                        job_creation_date: Default::default(),
+                        job_status: JobStatusType::Unknown(String::new()),
+                        marker: Default::default(),
+                        number_of_services_accessible: Default::default(),
+                        number_of_services_not_accessed: Default::default(),
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -23586,7 +32172,15 @@ impl Iam for IamClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = GetServiceLastAccessedDetailsResponse::default();
+                    result = GetServiceLastAccessedDetailsResponse {
+                        error: Default::default(),
+                        is_truncated: Default::default(),
+                        job_completion_date: Default::default(),
+                        job_creation_date: Default::default(),
+                        job_status: JobStatusType::Unknown(String::new()),
+                        marker: Default::default(),
+                        services_last_accessed: Default::default(),
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -23641,7 +32235,15 @@ impl Iam for IamClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = GetServiceLastAccessedDetailsWithEntitiesResponse::default();
+                    result = GetServiceLastAccessedDetailsWithEntitiesResponse {
+                        entity_details_list: Default::default(),
+                        error: Default::default(),
+                        is_truncated: Default::default(),
+                        job_completion_date: Default::default(),
+                        job_creation_date: Default::default(),
+                        job_status: JobStatusType::Unknown(String::new()),
+                        marker: Default::default(),
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -23695,7 +32297,10 @@ impl Iam for IamClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = GetServiceLinkedRoleDeletionStatusResponse::default();
+                    result = GetServiceLinkedRoleDeletionStatusResponse {
+                        reason: Default::default(),
+                        status: DeletionTaskStatusType::Unknown(String::new()),
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -26310,7 +34915,15 @@ impl Iam for IamClient {
                 let result;
 
                 if response.body.is_empty() {
-                    result = UploadSigningCertificateResponse::default();
+                    result = UploadSigningCertificateResponse {
+                        certificate: SigningCertificate {
+                            certificate_body: Default::default(),
+                            certificate_id: Default::default(),
+                            status: StatusType::Unknown(String::new()),
+                            upload_date: Default::default(),
+                            user_name: Default::default(),
+                        },
+                    };
                 } else {
                     let reader = EventReader::new_with_config(
                         response.body.as_ref(),
@@ -28,6 +28,7 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -40,10 +41,10 @@ pub struct Artifact {
     pub url: Option<String>,
 }
 
-struct ArtifactDeserializer;
+pub(crate) struct ArtifactDeserializer;
 impl ArtifactDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Artifact, XmlParseError> {
@@ -62,10 +63,10 @@ impl ArtifactDeserializer {
         })
     }
 }
-struct ArtifactListDeserializer;
+pub(crate) struct ArtifactListDeserializer;
 impl ArtifactListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Artifact>, XmlParseError> {
@@ -86,6 +87,44 @@ pub struct CancelJobInput {
     pub job_id: String,
 }
 
+impl CancelJobInput {
+    /// Returns a builder for `CancelJobInput`, checking its required members when built.
+    pub fn builder() -> CancelJobInputBuilder {
+        CancelJobInputBuilder::default()
+    }
+}
+
+/// Builds a [`CancelJobInput`](struct.CancelJobInput.html).
+#[derive(Default, Debug)]
+pub struct CancelJobInputBuilder {
+    api_version: Option<String>,
+    job_id: Option<String>,
+}
+
+impl CancelJobInputBuilder {
+    /// Sets the [`api_version`](struct.CancelJobInput.html#structfield.api_version) member.
+    pub fn api_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.CancelJobInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelJobInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelJobInput, ::rusoto_core::BuildError> {
+        Ok(CancelJobInput {
+            api_version: self.api_version,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 /// Serialize `CancelJobInput` contents to a `SignedRequest`.
 struct CancelJobInputSerializer;
 impl CancelJobInputSerializer {
@@ -108,10 +147,10 @@ pub struct CancelJobOutput {
     pub success: Option<bool>,
 }
 
-struct CancelJobOutputDeserializer;
+pub(crate) struct CancelJobOutputDeserializer;
 impl CancelJobOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CancelJobOutput, XmlParseError> {
@@ -126,10 +165,13 @@ impl CancelJobOutputDeserializer {
         })
     }
 }
-struct CarrierDeserializer;
+pub(crate) struct CarrierDeserializer;
 impl CarrierDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -138,15 +180,81 @@ impl CarrierDeserializer {
     }
 }
 /// <p>Input structure for the CreateJob operation.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CreateJobInput {
     pub api_version: Option<String>,
-    pub job_type: String,
+    pub job_type: JobType,
     pub manifest: String,
     pub manifest_addendum: Option<String>,
     pub validate_only: bool,
 }
 
+impl CreateJobInput {
+    /// Returns a builder for `CreateJobInput`, checking its required members when built.
+    pub fn builder() -> CreateJobInputBuilder {
+        CreateJobInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateJobInput`](struct.CreateJobInput.html).
+#[derive(Default, Debug)]
+pub struct CreateJobInputBuilder {
+    api_version: Option<String>,
+    job_type: Option<JobType>,
+    manifest: Option<String>,
+    manifest_addendum: Option<String>,
+    validate_only: Option<bool>,
+}
+
+impl CreateJobInputBuilder {
+    /// Sets the [`api_version`](struct.CreateJobInput.html#structfield.api_version) member.
+    pub fn api_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_type`](struct.CreateJobInput.html#structfield.job_type) member.
+    pub fn job_type<T: Into<JobType>>(mut self, value: T) -> Self {
+        self.job_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manifest`](struct.CreateJobInput.html#structfield.manifest) member.
+    pub fn manifest<T: Into<String>>(mut self, value: T) -> Self {
+        self.manifest = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manifest_addendum`](struct.CreateJobInput.html#structfield.manifest_addendum) member.
+    pub fn manifest_addendum<T: Into<String>>(mut self, value: T) -> Self {
+        self.manifest_addendum = Some(value.into());
+        self
+    }
+
+    /// Sets the [`validate_only`](struct.CreateJobInput.html#structfield.validate_only) member.
+    pub fn validate_only<T: Into<bool>>(mut self, value: T) -> Self {
+        self.validate_only = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateJobInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateJobInput, ::rusoto_core::BuildError> {
+        Ok(CreateJobInput {
+            api_version: self.api_version,
+            job_type: self
+                .job_type
+                .ok_or(::rusoto_core::BuildError::missing("job_type"))?,
+            manifest: self
+                .manifest
+                .ok_or(::rusoto_core::BuildError::missing("manifest"))?,
+            manifest_addendum: self.manifest_addendum,
+            validate_only: self
+                .validate_only
+                .ok_or(::rusoto_core::BuildError::missing("validate_only"))?,
+        })
+    }
+}
+
 /// Serialize `CreateJobInput` contents to a `SignedRequest`.
 struct CreateJobInputSerializer;
 impl CreateJobInputSerializer {
@@ -173,16 +281,16 @@ impl CreateJobInputSerializer {
 pub struct CreateJobOutput {
     pub artifact_list: Option<Vec<Artifact>>,
     pub job_id: Option<String>,
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
     pub signature: Option<String>,
     pub signature_file_contents: Option<String>,
     pub warning_message: Option<String>,
 }
 
-struct CreateJobOutputDeserializer;
+pub(crate) struct CreateJobOutputDeserializer;
 impl CreateJobOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateJobOutput, XmlParseError> {
@@ -221,21 +329,27 @@ impl CreateJobOutputDeserializer {
         })
     }
 }
-struct CreationDateDeserializer;
+pub(crate) struct CreationDateDeserializer;
 impl CreationDateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct CurrentManifestDeserializer;
+pub(crate) struct CurrentManifestDeserializer;
 impl CurrentManifestDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -243,10 +357,13 @@ impl CurrentManifestDeserializer {
         Ok(obj)
     }
 }
-struct DescriptionDeserializer;
+pub(crate) struct DescriptionDeserializer;
 impl DescriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -254,10 +371,13 @@ impl DescriptionDeserializer {
         Ok(obj)
     }
 }
-struct ErrorCountDeserializer;
+pub(crate) struct ErrorCountDeserializer;
 impl ErrorCountDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -265,10 +385,13 @@ impl ErrorCountDeserializer {
         Ok(obj)
     }
 }
-struct GenericStringDeserializer;
+pub(crate) struct GenericStringDeserializer;
 impl GenericStringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -292,6 +415,124 @@ pub struct GetShippingLabelInput {
     pub street_3: Option<String>,
 }
 
+impl GetShippingLabelInput {
+    /// Returns a builder for `GetShippingLabelInput`, checking its required members when built.
+    pub fn builder() -> GetShippingLabelInputBuilder {
+        GetShippingLabelInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetShippingLabelInput`](struct.GetShippingLabelInput.html).
+#[derive(Default, Debug)]
+pub struct GetShippingLabelInputBuilder {
+    api_version: Option<String>,
+    city: Option<String>,
+    company: Option<String>,
+    country: Option<String>,
+    job_ids: Option<Vec<String>>,
+    name: Option<String>,
+    phone_number: Option<String>,
+    postal_code: Option<String>,
+    state_or_province: Option<String>,
+    street_1: Option<String>,
+    street_2: Option<String>,
+    street_3: Option<String>,
+}
+
+impl GetShippingLabelInputBuilder {
+    /// Sets the [`api_version`](struct.GetShippingLabelInput.html#structfield.api_version) member.
+    pub fn api_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`city`](struct.GetShippingLabelInput.html#structfield.city) member.
+    pub fn city<T: Into<String>>(mut self, value: T) -> Self {
+        self.city = Some(value.into());
+        self
+    }
+
+    /// Sets the [`company`](struct.GetShippingLabelInput.html#structfield.company) member.
+    pub fn company<T: Into<String>>(mut self, value: T) -> Self {
+        self.company = Some(value.into());
+        self
+    }
+
+    /// Sets the [`country`](struct.GetShippingLabelInput.html#structfield.country) member.
+    pub fn country<T: Into<String>>(mut self, value: T) -> Self {
+        self.country = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_ids`](struct.GetShippingLabelInput.html#structfield.job_ids) member.
+    pub fn job_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.job_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetShippingLabelInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`phone_number`](struct.GetShippingLabelInput.html#structfield.phone_number) member.
+    pub fn phone_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.phone_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`postal_code`](struct.GetShippingLabelInput.html#structfield.postal_code) member.
+    pub fn postal_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.postal_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`state_or_province`](struct.GetShippingLabelInput.html#structfield.state_or_province) member.
+    pub fn state_or_province<T: Into<String>>(mut self, value: T) -> Self {
+        self.state_or_province = Some(value.into());
+        self
+    }
+
+    /// Sets the [`street_1`](struct.GetShippingLabelInput.html#structfield.street_1) member.
+    pub fn street_1<T: Into<String>>(mut self, value: T) -> Self {
+        self.street_1 = Some(value.into());
+        self
+    }
+
+    /// Sets the [`street_2`](struct.GetShippingLabelInput.html#structfield.street_2) member.
+    pub fn street_2<T: Into<String>>(mut self, value: T) -> Self {
+        self.street_2 = Some(value.into());
+        self
+    }
+
+    /// Sets the [`street_3`](struct.GetShippingLabelInput.html#structfield.street_3) member.
+    pub fn street_3<T: Into<String>>(mut self, value: T) -> Self {
+        self.street_3 = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetShippingLabelInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetShippingLabelInput, ::rusoto_core::BuildError> {
+        Ok(GetShippingLabelInput {
+            api_version: self.api_version,
+            city: self.city,
+            company: self.company,
+            country: self.country,
+            job_ids: self
+                .job_ids
+                .ok_or(::rusoto_core::BuildError::missing("job_ids"))?,
+            name: self.name,
+            phone_number: self.phone_number,
+            postal_code: self.postal_code,
+            state_or_province: self.state_or_province,
+            street_1: self.street_1,
+            street_2: self.street_2,
+            street_3: self.street_3,
+        })
+    }
+}
+
 /// Serialize `GetShippingLabelInput` contents to a `SignedRequest`.
 struct GetShippingLabelInputSerializer;
 impl GetShippingLabelInputSerializer {
@@ -344,10 +585,10 @@ pub struct GetShippingLabelOutput {
     pub warning: Option<String>,
 }
 
-struct GetShippingLabelOutputDeserializer;
+pub(crate) struct GetShippingLabelOutputDeserializer;
 impl GetShippingLabelOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetShippingLabelOutput, XmlParseError> {
@@ -375,6 +616,44 @@ pub struct GetStatusInput {
     pub job_id: String,
 }
 
+impl GetStatusInput {
+    /// Returns a builder for `GetStatusInput`, checking its required members when built.
+    pub fn builder() -> GetStatusInputBuilder {
+        GetStatusInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetStatusInput`](struct.GetStatusInput.html).
+#[derive(Default, Debug)]
+pub struct GetStatusInputBuilder {
+    api_version: Option<String>,
+    job_id: Option<String>,
+}
+
+impl GetStatusInputBuilder {
+    /// Sets the [`api_version`](struct.GetStatusInput.html#structfield.api_version) member.
+    pub fn api_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.GetStatusInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetStatusInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetStatusInput, ::rusoto_core::BuildError> {
+        Ok(GetStatusInput {
+            api_version: self.api_version,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 /// Serialize `GetStatusInput` contents to a `SignedRequest`.
 struct GetStatusInputSerializer;
 impl GetStatusInputSerializer {
@@ -396,11 +675,11 @@ impl GetStatusInputSerializer {
 pub struct GetStatusOutput {
     pub artifact_list: Option<Vec<Artifact>>,
     pub carrier: Option<String>,
-    pub creation_date: Option<String>,
+    pub creation_date: Option<IsoTimestamp>,
     pub current_manifest: Option<String>,
     pub error_count: Option<i64>,
     pub job_id: Option<String>,
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
     pub location_code: Option<String>,
     pub location_message: Option<String>,
     pub log_bucket: Option<String>,
@@ -412,10 +691,10 @@ pub struct GetStatusOutput {
     pub tracking_number: Option<String>,
 }
 
-struct GetStatusOutputDeserializer;
+pub(crate) struct GetStatusOutputDeserializer;
 impl GetStatusOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetStatusOutput, XmlParseError> {
@@ -502,10 +781,13 @@ impl GetStatusOutputDeserializer {
         })
     }
 }
-struct IsCanceledDeserializer;
+pub(crate) struct IsCanceledDeserializer;
 impl IsCanceledDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -513,10 +795,13 @@ impl IsCanceledDeserializer {
         Ok(obj)
     }
 }
-struct IsTruncatedDeserializer;
+pub(crate) struct IsTruncatedDeserializer;
 impl IsTruncatedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -527,16 +812,19 @@ impl IsTruncatedDeserializer {
 /// <p>Representation of a job returned by the ListJobs operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Job {
-    pub creation_date: Option<String>,
+    pub creation_date: Option<IsoTimestamp>,
     pub is_canceled: Option<bool>,
     pub job_id: Option<String>,
-    pub job_type: Option<String>,
+    pub job_type: Option<JobType>,
 }
 
-struct JobDeserializer;
+pub(crate) struct JobDeserializer;
 impl JobDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Job, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Job, XmlParseError> {
         deserialize_elements::<_, Job, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "CreationDate" => {
@@ -561,10 +849,13 @@ impl JobDeserializer {
         })
     }
 }
-struct JobIdDeserializer;
+pub(crate) struct JobIdDeserializer;
 impl JobIdDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -584,21 +875,83 @@ impl JobIdListSerializer {
     }
 }
 
-struct JobTypeDeserializer;
+pub(crate) struct JobTypeDeserializer;
 impl JobTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<JobType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct JobsListDeserializer;
+/// Specifies whether the job to initiate is an import or export job.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobType {
+    /// <p><code>Import</code></p>
+    Import,
+    /// <p><code>Export</code></p>
+    Export,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobType::Import => "Import",
+            JobType::Export => "Export",
+            JobType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobType {
+    fn from(value: &'a str) -> JobType {
+        match value {
+            "Import" => JobType::Import,
+            "Export" => JobType::Export,
+            _ => JobType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobType {
+    fn from(value: String) -> JobType {
+        JobType::from(value.as_str())
+    }
+}
+
+impl From<JobType> for String {
+    fn from(value: JobType) -> String {
+        match value {
+            JobType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct JobsListDeserializer;
 impl JobsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Job>, XmlParseError> {
@@ -620,6 +973,50 @@ pub struct ListJobsInput {
     pub max_jobs: Option<i64>,
 }
 
+impl ListJobsInput {
+    /// Returns a builder for `ListJobsInput`, checking its required members when built.
+    pub fn builder() -> ListJobsInputBuilder {
+        ListJobsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListJobsInput`](struct.ListJobsInput.html).
+#[derive(Default, Debug)]
+pub struct ListJobsInputBuilder {
+    api_version: Option<String>,
+    marker: Option<String>,
+    max_jobs: Option<i64>,
+}
+
+impl ListJobsInputBuilder {
+    /// Sets the [`api_version`](struct.ListJobsInput.html#structfield.api_version) member.
+    pub fn api_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListJobsInput.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_jobs`](struct.ListJobsInput.html#structfield.max_jobs) member.
+    pub fn max_jobs<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_jobs = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJobsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListJobsInput, ::rusoto_core::BuildError> {
+        Ok(ListJobsInput {
+            api_version: self.api_version,
+            marker: self.marker,
+            max_jobs: self.max_jobs,
+        })
+    }
+}
+
 /// Serialize `ListJobsInput` contents to a `SignedRequest`.
 struct ListJobsInputSerializer;
 impl ListJobsInputSerializer {
@@ -648,10 +1045,10 @@ pub struct ListJobsOutput {
     pub jobs: Option<Vec<Job>>,
 }
 
-struct ListJobsOutputDeserializer;
+pub(crate) struct ListJobsOutputDeserializer;
 impl ListJobsOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ListJobsOutput, XmlParseError> {
@@ -672,10 +1069,13 @@ impl ListJobsOutputDeserializer {
         })
     }
 }
-struct LocationCodeDeserializer;
+pub(crate) struct LocationCodeDeserializer;
 impl LocationCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -683,10 +1083,13 @@ impl LocationCodeDeserializer {
         Ok(obj)
     }
 }
-struct LocationMessageDeserializer;
+pub(crate) struct LocationMessageDeserializer;
 impl LocationMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -694,10 +1097,13 @@ impl LocationMessageDeserializer {
         Ok(obj)
     }
 }
-struct LogBucketDeserializer;
+pub(crate) struct LogBucketDeserializer;
 impl LogBucketDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -705,10 +1111,13 @@ impl LogBucketDeserializer {
         Ok(obj)
     }
 }
-struct LogKeyDeserializer;
+pub(crate) struct LogKeyDeserializer;
 impl LogKeyDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -716,10 +1125,13 @@ impl LogKeyDeserializer {
         Ok(obj)
     }
 }
-struct ProgressCodeDeserializer;
+pub(crate) struct ProgressCodeDeserializer;
 impl ProgressCodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -727,10 +1139,13 @@ impl ProgressCodeDeserializer {
         Ok(obj)
     }
 }
-struct ProgressMessageDeserializer;
+pub(crate) struct ProgressMessageDeserializer;
 impl ProgressMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -738,10 +1153,13 @@ impl ProgressMessageDeserializer {
         Ok(obj)
     }
 }
-struct SignatureDeserializer;
+pub(crate) struct SignatureDeserializer;
 impl SignatureDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -749,10 +1167,13 @@ impl SignatureDeserializer {
         Ok(obj)
     }
 }
-struct SignatureFileContentsDeserializer;
+pub(crate) struct SignatureFileContentsDeserializer;
 impl SignatureFileContentsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -760,10 +1181,13 @@ impl SignatureFileContentsDeserializer {
         Ok(obj)
     }
 }
-struct SuccessDeserializer;
+pub(crate) struct SuccessDeserializer;
 impl SuccessDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -771,10 +1195,13 @@ impl SuccessDeserializer {
         Ok(obj)
     }
 }
-struct TrackingNumberDeserializer;
+pub(crate) struct TrackingNumberDeserializer;
 impl TrackingNumberDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -782,10 +1209,13 @@ impl TrackingNumberDeserializer {
         Ok(obj)
     }
 }
-struct URLDeserializer;
+pub(crate) struct URLDeserializer;
 impl URLDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -794,15 +1224,83 @@ impl URLDeserializer {
     }
 }
 /// <p>Input structure for the UpateJob operation.</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct UpdateJobInput {
     pub api_version: Option<String>,
     pub job_id: String,
-    pub job_type: String,
+    pub job_type: JobType,
     pub manifest: String,
     pub validate_only: bool,
 }
 
+impl UpdateJobInput {
+    /// Returns a builder for `UpdateJobInput`, checking its required members when built.
+    pub fn builder() -> UpdateJobInputBuilder {
+        UpdateJobInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateJobInput`](struct.UpdateJobInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateJobInputBuilder {
+    api_version: Option<String>,
+    job_id: Option<String>,
+    job_type: Option<JobType>,
+    manifest: Option<String>,
+    validate_only: Option<bool>,
+}
+
+impl UpdateJobInputBuilder {
+    /// Sets the [`api_version`](struct.UpdateJobInput.html#structfield.api_version) member.
+    pub fn api_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.api_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.UpdateJobInput.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_type`](struct.UpdateJobInput.html#structfield.job_type) member.
+    pub fn job_type<T: Into<JobType>>(mut self, value: T) -> Self {
+        self.job_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manifest`](struct.UpdateJobInput.html#structfield.manifest) member.
+    pub fn manifest<T: Into<String>>(mut self, value: T) -> Self {
+        self.manifest = Some(value.into());
+        self
+    }
+
+    /// Sets the [`validate_only`](struct.UpdateJobInput.html#structfield.validate_only) member.
+    pub fn validate_only<T: Into<bool>>(mut self, value: T) -> Self {
+        self.validate_only = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateJobInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateJobInput, ::rusoto_core::BuildError> {
+        Ok(UpdateJobInput {
+            api_version: self.api_version,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            job_type: self
+                .job_type
+                .ok_or(::rusoto_core::BuildError::missing("job_type"))?,
+            manifest: self
+                .manifest
+                .ok_or(::rusoto_core::BuildError::missing("manifest"))?,
+            validate_only: self
+                .validate_only
+                .ok_or(::rusoto_core::BuildError::missing("validate_only"))?,
+        })
+    }
+}
+
 /// Serialize `UpdateJobInput` contents to a `SignedRequest`.
 struct UpdateJobInputSerializer;
 impl UpdateJobInputSerializer {
@@ -830,10 +1328,10 @@ pub struct UpdateJobOutput {
     pub warning_message: Option<String>,
 }
 
-struct UpdateJobOutputDeserializer;
+pub(crate) struct UpdateJobOutputDeserializer;
 impl UpdateJobOutputDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateJobOutput, XmlParseError> {
@@ -859,10 +1357,13 @@ impl UpdateJobOutputDeserializer {
         })
     }
 }
-struct WarningMessageDeserializer;
+pub(crate) struct WarningMessageDeserializer;
 impl WarningMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -1567,6 +2068,36 @@ pub trait ImportExport {
     /// <p>You use this operation to change the parameters specified in the original manifest file by supplying a new manifest file. The manifest file attached to this request replaces the original manifest file. You can only use the operation after a CreateJob request but before the data transfer starts and you can only use it on jobs you own.</p>
     fn update_job(&self, input: UpdateJobInput) -> RusotoFuture<UpdateJobOutput, UpdateJobError>;
 }
+impl<T: ?Sized + ImportExport> ImportExport for ::std::sync::Arc<T> {
+    fn cancel_job(&self, input: CancelJobInput) -> RusotoFuture<CancelJobOutput, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn create_job(&self, input: CreateJobInput) -> RusotoFuture<CreateJobOutput, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn get_shipping_label(
+        &self,
+        input: GetShippingLabelInput,
+    ) -> RusotoFuture<GetShippingLabelOutput, GetShippingLabelError> {
+        (**self).get_shipping_label(input)
+    }
+    fn get_status(&self, input: GetStatusInput) -> RusotoFuture<GetStatusOutput, GetStatusError> {
+        (**self).get_status(input)
+    }
+    fn list_jobs(&self, input: ListJobsInput) -> RusotoFuture<ListJobsOutput, ListJobsError> {
+        (**self).list_jobs(input)
+    }
+    fn update_job(&self, input: UpdateJobInput) -> RusotoFuture<UpdateJobOutput, UpdateJobError> {
+        (**self).update_job(input)
+    }
+}
+/// The error codes AWS Import/Export retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Import/Export API.
 #[derive(Clone)]
 pub struct ImportExportClient {
@@ -1582,6 +2113,27 @@ impl ImportExportClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_IMPORTEXPORT`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ImportExportClient {
+        Self::new(region::Region::from_env_for_service("IMPORTEXPORT"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `importexport`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ImportExportClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("importexport", "IMPORTEXPORT"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1600,7 +2152,54 @@ impl ImportExportClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ImportExportClient {
-        ImportExportClient { client, region }
+        ImportExportClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "importexport")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "importexport",
+            "importexport",
+            "IMPORTEXPORT",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ImportExport` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ImportExport + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ImportExportClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Import/Export"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "importexport"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ImportExportClient {
+        Self::new_with_client(client, region)
     }
 }
 
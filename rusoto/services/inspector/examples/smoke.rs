@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Inspector clients.
+//!
+//! Makes a single read-only `ListAssessmentRuns` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_inspector::{Inspector, InspectorClient, ListAssessmentRunsRequest};
+
+fn main() {
+    let client = InspectorClient::from_env();
+    match client
+        .list_assessment_runs(ListAssessmentRunsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListAssessmentRuns succeeded"),
+        Err(error) => {
+            eprintln!("ListAssessmentRuns failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
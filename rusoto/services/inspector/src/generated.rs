@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AddAttributesToFindingsRequest {
@@ -33,6 +34,48 @@ pub struct AddAttributesToFindingsRequest {
     pub finding_arns: Vec<String>,
 }
 
+impl AddAttributesToFindingsRequest {
+    /// Returns a builder for `AddAttributesToFindingsRequest`, checking its required members when built.
+    pub fn builder() -> AddAttributesToFindingsRequestBuilder {
+        AddAttributesToFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddAttributesToFindingsRequest`](struct.AddAttributesToFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct AddAttributesToFindingsRequestBuilder {
+    attributes: Option<Vec<Attribute>>,
+    finding_arns: Option<Vec<String>>,
+}
+
+impl AddAttributesToFindingsRequestBuilder {
+    /// Sets the [`attributes`](struct.AddAttributesToFindingsRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<Vec<Attribute>>>(mut self, value: T) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_arns`](struct.AddAttributesToFindingsRequest.html#structfield.finding_arns) member.
+    pub fn finding_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.finding_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddAttributesToFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddAttributesToFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(AddAttributesToFindingsRequest {
+            attributes: self
+                .attributes
+                .ok_or(::rusoto_core::BuildError::missing("attributes"))?,
+            finding_arns: self
+                .finding_arns
+                .ok_or(::rusoto_core::BuildError::missing("finding_arns"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddAttributesToFindingsResponse {
@@ -55,10 +98,174 @@ pub struct AgentAlreadyRunningAssessment {
 pub struct AgentFilter {
     /// <p>The detailed health state of the agent. Values can be set to <b>IDLE</b>, <b>RUNNING</b>, <b>SHUTDOWN</b>, <b>UNHEALTHY</b>, <b>THROTTLED</b>, and <b>UNKNOWN</b>. </p>
     #[serde(rename = "agentHealthCodes")]
-    pub agent_health_codes: Vec<String>,
+    pub agent_health_codes: Vec<AgentHealthCode>,
     /// <p>The current health state of the agent. Values can be set to <b>HEALTHY</b> or <b>UNHEALTHY</b>.</p>
     #[serde(rename = "agentHealths")]
-    pub agent_healths: Vec<String>,
+    pub agent_healths: Vec<AgentHealth>,
+}
+
+/// <p>The current health state of the agent. Values can be set to <b>HEALTHY</b> or <b>UNHEALTHY</b>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AgentHealth {
+    /// <p><code>HEALTHY</code></p>
+    Healthy,
+    /// <p><code>UNHEALTHY</code></p>
+    Unhealthy,
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AgentHealth {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AgentHealth::Healthy => "HEALTHY",
+            AgentHealth::Unhealthy => "UNHEALTHY",
+            AgentHealth::UnknownValue => "UNKNOWN",
+            AgentHealth::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AgentHealth {
+    fn from(value: &'a str) -> AgentHealth {
+        match value {
+            "HEALTHY" => AgentHealth::Healthy,
+            "UNHEALTHY" => AgentHealth::Unhealthy,
+            "UNKNOWN" => AgentHealth::UnknownValue,
+            _ => AgentHealth::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AgentHealth {
+    fn from(value: String) -> AgentHealth {
+        AgentHealth::from(value.as_str())
+    }
+}
+
+impl From<AgentHealth> for String {
+    fn from(value: AgentHealth) -> String {
+        match value {
+            AgentHealth::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AgentHealth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AgentHealth {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AgentHealth {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AgentHealth, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AgentHealth::from(value))
+    }
+}
+
+/// <p>The detailed health state of the agent. Values can be set to <b>IDLE</b>, <b>RUNNING</b>, <b>SHUTDOWN</b>, <b>UNHEALTHY</b>, <b>THROTTLED</b>, and <b>UNKNOWN</b>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AgentHealthCode {
+    /// <p><code>IDLE</code></p>
+    Idle,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>SHUTDOWN</code></p>
+    Shutdown,
+    /// <p><code>UNHEALTHY</code></p>
+    Unhealthy,
+    /// <p><code>THROTTLED</code></p>
+    Throttled,
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AgentHealthCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AgentHealthCode::Idle => "IDLE",
+            AgentHealthCode::Running => "RUNNING",
+            AgentHealthCode::Shutdown => "SHUTDOWN",
+            AgentHealthCode::Unhealthy => "UNHEALTHY",
+            AgentHealthCode::Throttled => "THROTTLED",
+            AgentHealthCode::UnknownValue => "UNKNOWN",
+            AgentHealthCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AgentHealthCode {
+    fn from(value: &'a str) -> AgentHealthCode {
+        match value {
+            "IDLE" => AgentHealthCode::Idle,
+            "RUNNING" => AgentHealthCode::Running,
+            "SHUTDOWN" => AgentHealthCode::Shutdown,
+            "UNHEALTHY" => AgentHealthCode::Unhealthy,
+            "THROTTLED" => AgentHealthCode::Throttled,
+            "UNKNOWN" => AgentHealthCode::UnknownValue,
+            _ => AgentHealthCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AgentHealthCode {
+    fn from(value: String) -> AgentHealthCode {
+        AgentHealthCode::from(value.as_str())
+    }
+}
+
+impl From<AgentHealthCode> for String {
+    fn from(value: AgentHealthCode) -> String {
+        match value {
+            AgentHealthCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AgentHealthCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AgentHealthCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AgentHealthCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AgentHealthCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AgentHealthCode::from(value))
+    }
 }
 
 /// <p>Used as a response element in the <a>PreviewAgents</a> action.</p>
@@ -68,7 +275,7 @@ pub struct AgentPreview {
     /// <p>The health status of the Amazon Inspector Agent.</p>
     #[serde(rename = "agentHealth")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub agent_health: Option<String>,
+    pub agent_health: Option<AgentHealth>,
     /// <p>The ID of the EC2 instance where the agent is installed.</p>
     #[serde(rename = "agentId")]
     pub agent_id: String,
@@ -99,7 +306,7 @@ pub struct AgentPreview {
 }
 
 /// <p>A snapshot of an Amazon Inspector assessment run that contains the findings of the assessment run .</p> <p>Used as the response element in the <a>DescribeAssessmentRuns</a> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssessmentRun {
     /// <p>The ARN of the assessment run.</p>
@@ -110,11 +317,13 @@ pub struct AssessmentRun {
     pub assessment_template_arn: String,
     /// <p>The assessment run completion time that corresponds to the rules packages evaluation completion time or failure.</p>
     #[serde(rename = "completedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<f64>,
+    pub completed_at: Option<EpochTimestamp>,
     /// <p>The time when <a>StartAssessmentRun</a> was called.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub created_at: EpochTimestamp,
     /// <p>A Boolean value (true or false) that specifies whether the process of collecting data from the agents is completed.</p>
     #[serde(rename = "dataCollected")]
     pub data_collected: bool,
@@ -123,7 +332,7 @@ pub struct AssessmentRun {
     pub duration_in_seconds: i64,
     /// <p>Provides a total count of generated findings per severity.</p>
     #[serde(rename = "findingCounts")]
-    pub finding_counts: ::std::collections::HashMap<String, i64>,
+    pub finding_counts: ::std::collections::HashMap<Severity, i64>,
     /// <p>The auto-generated name for the assessment run.</p>
     #[serde(rename = "name")]
     pub name: String,
@@ -135,14 +344,16 @@ pub struct AssessmentRun {
     pub rules_package_arns: Vec<String>,
     /// <p>The time when <a>StartAssessmentRun</a> was called.</p>
     #[serde(rename = "startedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<EpochTimestamp>,
     /// <p>The state of the assessment run.</p>
     #[serde(rename = "state")]
-    pub state: String,
+    pub state: AssessmentRunState,
     /// <p>The last time when the assessment run's state changed.</p>
     #[serde(rename = "stateChangedAt")]
-    pub state_changed_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub state_changed_at: EpochTimestamp,
     /// <p>A list of the assessment run state changes.</p>
     #[serde(rename = "stateChanges")]
     pub state_changes: Vec<AssessmentRunStateChange>,
@@ -152,15 +363,15 @@ pub struct AssessmentRun {
 }
 
 /// <p>Contains information about an Amazon Inspector agent. This data type is used as a response element in the <a>ListAssessmentRunAgents</a> action.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssessmentRunAgent {
     /// <p>The current health state of the agent.</p>
     #[serde(rename = "agentHealth")]
-    pub agent_health: String,
+    pub agent_health: AgentHealth,
     /// <p>The detailed health state of the agent.</p>
     #[serde(rename = "agentHealthCode")]
-    pub agent_health_code: String,
+    pub agent_health_code: AgentHealthCode,
     /// <p>The description for the agent health code.</p>
     #[serde(rename = "agentHealthDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -210,22 +421,23 @@ pub struct AssessmentRunFilter {
     /// <p>For a record to match a filter, one of the values specified for this data type property must be the exact match of the value of the <b>assessmentRunState</b> property of the <a>AssessmentRun</a> data type.</p>
     #[serde(rename = "states")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub states: Option<Vec<String>>,
+    pub states: Option<Vec<AssessmentRunState>>,
 }
 
 /// <p>Used as one of the elements of the <a>AssessmentRun</a> data type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssessmentRunNotification {
     /// <p>The date of the notification.</p>
     #[serde(rename = "date")]
-    pub date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub date: EpochTimestamp,
     /// <p>The Boolean value that specifies whether the notification represents an error.</p>
     #[serde(rename = "error")]
     pub error: bool,
     /// <p>The event for which a notification is sent.</p>
     #[serde(rename = "event")]
-    pub event: String,
+    pub event: InspectorEvent,
     /// <p>The message included in the notification.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -233,23 +445,226 @@ pub struct AssessmentRunNotification {
     /// <p>The status code of the SNS notification.</p>
     #[serde(rename = "snsPublishStatusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sns_publish_status_code: Option<String>,
+    pub sns_publish_status_code: Option<AssessmentRunNotificationSnsStatusCode>,
     /// <p>The SNS topic to which the SNS notification is sent.</p>
     #[serde(rename = "snsTopicArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sns_topic_arn: Option<String>,
 }
 
+/// <p>The status code of the SNS notification.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AssessmentRunNotificationSnsStatusCode {
+    /// <p><code>SUCCESS</code></p>
+    Success,
+    /// <p><code>TOPIC_DOES_NOT_EXIST</code></p>
+    TopicDoesNotExist,
+    /// <p><code>ACCESS_DENIED</code></p>
+    AccessDenied,
+    /// <p><code>INTERNAL_ERROR</code></p>
+    InternalError,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AssessmentRunNotificationSnsStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssessmentRunNotificationSnsStatusCode::Success => "SUCCESS",
+            AssessmentRunNotificationSnsStatusCode::TopicDoesNotExist => "TOPIC_DOES_NOT_EXIST",
+            AssessmentRunNotificationSnsStatusCode::AccessDenied => "ACCESS_DENIED",
+            AssessmentRunNotificationSnsStatusCode::InternalError => "INTERNAL_ERROR",
+            AssessmentRunNotificationSnsStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AssessmentRunNotificationSnsStatusCode {
+    fn from(value: &'a str) -> AssessmentRunNotificationSnsStatusCode {
+        match value {
+            "SUCCESS" => AssessmentRunNotificationSnsStatusCode::Success,
+            "TOPIC_DOES_NOT_EXIST" => AssessmentRunNotificationSnsStatusCode::TopicDoesNotExist,
+            "ACCESS_DENIED" => AssessmentRunNotificationSnsStatusCode::AccessDenied,
+            "INTERNAL_ERROR" => AssessmentRunNotificationSnsStatusCode::InternalError,
+            _ => AssessmentRunNotificationSnsStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssessmentRunNotificationSnsStatusCode {
+    fn from(value: String) -> AssessmentRunNotificationSnsStatusCode {
+        AssessmentRunNotificationSnsStatusCode::from(value.as_str())
+    }
+}
+
+impl From<AssessmentRunNotificationSnsStatusCode> for String {
+    fn from(value: AssessmentRunNotificationSnsStatusCode) -> String {
+        match value {
+            AssessmentRunNotificationSnsStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AssessmentRunNotificationSnsStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AssessmentRunNotificationSnsStatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssessmentRunNotificationSnsStatusCode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<AssessmentRunNotificationSnsStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AssessmentRunNotificationSnsStatusCode::from(value))
+    }
+}
+
+/// <p>The state of the assessment run.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AssessmentRunState {
+    /// <p><code>CREATED</code></p>
+    Created,
+    /// <p><code>START_DATA_COLLECTION_PENDING</code></p>
+    StartDataCollectionPending,
+    /// <p><code>START_DATA_COLLECTION_IN_PROGRESS</code></p>
+    StartDataCollectionInProgress,
+    /// <p><code>COLLECTING_DATA</code></p>
+    CollectingData,
+    /// <p><code>STOP_DATA_COLLECTION_PENDING</code></p>
+    StopDataCollectionPending,
+    /// <p><code>DATA_COLLECTED</code></p>
+    DataCollected,
+    /// <p><code>START_EVALUATING_RULES_PENDING</code></p>
+    StartEvaluatingRulesPending,
+    /// <p><code>EVALUATING_RULES</code></p>
+    EvaluatingRules,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>COMPLETED_WITH_ERRORS</code></p>
+    CompletedWithErrors,
+    /// <p><code>CANCELED</code></p>
+    Canceled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AssessmentRunState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssessmentRunState::Created => "CREATED",
+            AssessmentRunState::StartDataCollectionPending => "START_DATA_COLLECTION_PENDING",
+            AssessmentRunState::StartDataCollectionInProgress => {
+                "START_DATA_COLLECTION_IN_PROGRESS"
+            }
+            AssessmentRunState::CollectingData => "COLLECTING_DATA",
+            AssessmentRunState::StopDataCollectionPending => "STOP_DATA_COLLECTION_PENDING",
+            AssessmentRunState::DataCollected => "DATA_COLLECTED",
+            AssessmentRunState::StartEvaluatingRulesPending => "START_EVALUATING_RULES_PENDING",
+            AssessmentRunState::EvaluatingRules => "EVALUATING_RULES",
+            AssessmentRunState::Failed => "FAILED",
+            AssessmentRunState::Error => "ERROR",
+            AssessmentRunState::Completed => "COMPLETED",
+            AssessmentRunState::CompletedWithErrors => "COMPLETED_WITH_ERRORS",
+            AssessmentRunState::Canceled => "CANCELED",
+            AssessmentRunState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AssessmentRunState {
+    fn from(value: &'a str) -> AssessmentRunState {
+        match value {
+            "CREATED" => AssessmentRunState::Created,
+            "START_DATA_COLLECTION_PENDING" => AssessmentRunState::StartDataCollectionPending,
+            "START_DATA_COLLECTION_IN_PROGRESS" => {
+                AssessmentRunState::StartDataCollectionInProgress
+            }
+            "COLLECTING_DATA" => AssessmentRunState::CollectingData,
+            "STOP_DATA_COLLECTION_PENDING" => AssessmentRunState::StopDataCollectionPending,
+            "DATA_COLLECTED" => AssessmentRunState::DataCollected,
+            "START_EVALUATING_RULES_PENDING" => AssessmentRunState::StartEvaluatingRulesPending,
+            "EVALUATING_RULES" => AssessmentRunState::EvaluatingRules,
+            "FAILED" => AssessmentRunState::Failed,
+            "ERROR" => AssessmentRunState::Error,
+            "COMPLETED" => AssessmentRunState::Completed,
+            "COMPLETED_WITH_ERRORS" => AssessmentRunState::CompletedWithErrors,
+            "CANCELED" => AssessmentRunState::Canceled,
+            _ => AssessmentRunState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssessmentRunState {
+    fn from(value: String) -> AssessmentRunState {
+        AssessmentRunState::from(value.as_str())
+    }
+}
+
+impl From<AssessmentRunState> for String {
+    fn from(value: AssessmentRunState) -> String {
+        match value {
+            AssessmentRunState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AssessmentRunState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AssessmentRunState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssessmentRunState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AssessmentRunState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AssessmentRunState::from(value))
+    }
+}
+
 /// <p>Used as one of the elements of the <a>AssessmentRun</a> data type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssessmentRunStateChange {
     /// <p>The assessment run state.</p>
     #[serde(rename = "state")]
-    pub state: String,
+    pub state: AssessmentRunState,
     /// <p>The last time the assessment run state changed.</p>
     #[serde(rename = "stateChangedAt")]
-    pub state_changed_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub state_changed_at: EpochTimestamp,
 }
 
 /// <p>Contains information about an Amazon Inspector application. This data type is used as the response element in the <a>DescribeAssessmentTargets</a> action.</p>
@@ -261,7 +676,8 @@ pub struct AssessmentTarget {
     pub arn: String,
     /// <p>The time at which the assessment target is created.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub created_at: EpochTimestamp,
     /// <p>The name of the Amazon Inspector assessment target.</p>
     #[serde(rename = "name")]
     pub name: String,
@@ -271,7 +687,8 @@ pub struct AssessmentTarget {
     pub resource_group_arn: Option<String>,
     /// <p>The time at which <a>UpdateAssessmentTarget</a> is called.</p>
     #[serde(rename = "updatedAt")]
-    pub updated_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub updated_at: EpochTimestamp,
 }
 
 /// <p>Used as the request parameter in the <a>ListAssessmentTargets</a> action.</p>
@@ -298,7 +715,8 @@ pub struct AssessmentTemplate {
     pub assessment_target_arn: String,
     /// <p>The time at which the assessment template is created.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub created_at: EpochTimestamp,
     /// <p>The duration in seconds specified for this assessment template. The default value is 3600 seconds (one hour). The maximum value is 86400 seconds (one day).</p>
     #[serde(rename = "durationInSeconds")]
     pub duration_in_seconds: i64,
@@ -371,6 +789,74 @@ pub struct AssetAttributes {
     pub tags: Option<Vec<Tag>>,
 }
 
+/// <p>The type of the host from which the finding is generated.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AssetType {
+    /// <p><code>ec2-instance</code></p>
+    Ec2Instance,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AssetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssetType::Ec2Instance => "ec2-instance",
+            AssetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AssetType {
+    fn from(value: &'a str) -> AssetType {
+        match value {
+            "ec2-instance" => AssetType::Ec2Instance,
+            _ => AssetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssetType {
+    fn from(value: String) -> AssetType {
+        AssetType::from(value.as_str())
+    }
+}
+
+impl From<AssetType> for String {
+    fn from(value: AssetType) -> String {
+        match value {
+            AssetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AssetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AssetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AssetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AssetType::from(value))
+    }
+}
+
 /// <p>This data type is used as a request parameter in the <a>AddAttributesToFindings</a> and <a>CreateAssessmentTemplate</a> actions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Attribute {
@@ -394,6 +880,46 @@ pub struct CreateAssessmentTargetRequest {
     pub resource_group_arn: Option<String>,
 }
 
+impl CreateAssessmentTargetRequest {
+    /// Returns a builder for `CreateAssessmentTargetRequest`, checking its required members when built.
+    pub fn builder() -> CreateAssessmentTargetRequestBuilder {
+        CreateAssessmentTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAssessmentTargetRequest`](struct.CreateAssessmentTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAssessmentTargetRequestBuilder {
+    assessment_target_name: Option<String>,
+    resource_group_arn: Option<String>,
+}
+
+impl CreateAssessmentTargetRequestBuilder {
+    /// Sets the [`assessment_target_name`](struct.CreateAssessmentTargetRequest.html#structfield.assessment_target_name) member.
+    pub fn assessment_target_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_target_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_group_arn`](struct.CreateAssessmentTargetRequest.html#structfield.resource_group_arn) member.
+    pub fn resource_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_group_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAssessmentTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateAssessmentTargetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAssessmentTargetRequest {
+            assessment_target_name: self
+                .assessment_target_name
+                .ok_or(::rusoto_core::BuildError::missing("assessment_target_name"))?,
+            resource_group_arn: self.resource_group_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateAssessmentTargetResponse {
@@ -422,6 +948,76 @@ pub struct CreateAssessmentTemplateRequest {
     pub user_attributes_for_findings: Option<Vec<Attribute>>,
 }
 
+impl CreateAssessmentTemplateRequest {
+    /// Returns a builder for `CreateAssessmentTemplateRequest`, checking its required members when built.
+    pub fn builder() -> CreateAssessmentTemplateRequestBuilder {
+        CreateAssessmentTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAssessmentTemplateRequest`](struct.CreateAssessmentTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAssessmentTemplateRequestBuilder {
+    assessment_target_arn: Option<String>,
+    assessment_template_name: Option<String>,
+    duration_in_seconds: Option<i64>,
+    rules_package_arns: Option<Vec<String>>,
+    user_attributes_for_findings: Option<Vec<Attribute>>,
+}
+
+impl CreateAssessmentTemplateRequestBuilder {
+    /// Sets the [`assessment_target_arn`](struct.CreateAssessmentTemplateRequest.html#structfield.assessment_target_arn) member.
+    pub fn assessment_target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_target_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`assessment_template_name`](struct.CreateAssessmentTemplateRequest.html#structfield.assessment_template_name) member.
+    pub fn assessment_template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_template_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`duration_in_seconds`](struct.CreateAssessmentTemplateRequest.html#structfield.duration_in_seconds) member.
+    pub fn duration_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rules_package_arns`](struct.CreateAssessmentTemplateRequest.html#structfield.rules_package_arns) member.
+    pub fn rules_package_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.rules_package_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_attributes_for_findings`](struct.CreateAssessmentTemplateRequest.html#structfield.user_attributes_for_findings) member.
+    pub fn user_attributes_for_findings<T: Into<Vec<Attribute>>>(mut self, value: T) -> Self {
+        self.user_attributes_for_findings = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAssessmentTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateAssessmentTemplateRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAssessmentTemplateRequest {
+            assessment_target_arn: self
+                .assessment_target_arn
+                .ok_or(::rusoto_core::BuildError::missing("assessment_target_arn"))?,
+            assessment_template_name: self.assessment_template_name.ok_or(
+                ::rusoto_core::BuildError::missing("assessment_template_name"),
+            )?,
+            duration_in_seconds: self
+                .duration_in_seconds
+                .ok_or(::rusoto_core::BuildError::missing("duration_in_seconds"))?,
+            rules_package_arns: self
+                .rules_package_arns
+                .ok_or(::rusoto_core::BuildError::missing("rules_package_arns"))?,
+            user_attributes_for_findings: self.user_attributes_for_findings,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateAssessmentTemplateResponse {
@@ -437,6 +1033,38 @@ pub struct CreateExclusionsPreviewRequest {
     pub assessment_template_arn: String,
 }
 
+impl CreateExclusionsPreviewRequest {
+    /// Returns a builder for `CreateExclusionsPreviewRequest`, checking its required members when built.
+    pub fn builder() -> CreateExclusionsPreviewRequestBuilder {
+        CreateExclusionsPreviewRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateExclusionsPreviewRequest`](struct.CreateExclusionsPreviewRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateExclusionsPreviewRequestBuilder {
+    assessment_template_arn: Option<String>,
+}
+
+impl CreateExclusionsPreviewRequestBuilder {
+    /// Sets the [`assessment_template_arn`](struct.CreateExclusionsPreviewRequest.html#structfield.assessment_template_arn) member.
+    pub fn assessment_template_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_template_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateExclusionsPreviewRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateExclusionsPreviewRequest, ::rusoto_core::BuildError> {
+        Ok(CreateExclusionsPreviewRequest {
+            assessment_template_arn: self.assessment_template_arn.ok_or(
+                ::rusoto_core::BuildError::missing("assessment_template_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateExclusionsPreviewResponse {
@@ -452,6 +1080,38 @@ pub struct CreateResourceGroupRequest {
     pub resource_group_tags: Vec<ResourceGroupTag>,
 }
 
+impl CreateResourceGroupRequest {
+    /// Returns a builder for `CreateResourceGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateResourceGroupRequestBuilder {
+        CreateResourceGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateResourceGroupRequest`](struct.CreateResourceGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateResourceGroupRequestBuilder {
+    resource_group_tags: Option<Vec<ResourceGroupTag>>,
+}
+
+impl CreateResourceGroupRequestBuilder {
+    /// Sets the [`resource_group_tags`](struct.CreateResourceGroupRequest.html#structfield.resource_group_tags) member.
+    pub fn resource_group_tags<T: Into<Vec<ResourceGroupTag>>>(mut self, value: T) -> Self {
+        self.resource_group_tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateResourceGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateResourceGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateResourceGroupRequest {
+            resource_group_tags: self
+                .resource_group_tags
+                .ok_or(::rusoto_core::BuildError::missing("resource_group_tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateResourceGroupResponse {
@@ -467,6 +1127,38 @@ pub struct DeleteAssessmentRunRequest {
     pub assessment_run_arn: String,
 }
 
+impl DeleteAssessmentRunRequest {
+    /// Returns a builder for `DeleteAssessmentRunRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAssessmentRunRequestBuilder {
+        DeleteAssessmentRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAssessmentRunRequest`](struct.DeleteAssessmentRunRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAssessmentRunRequestBuilder {
+    assessment_run_arn: Option<String>,
+}
+
+impl DeleteAssessmentRunRequestBuilder {
+    /// Sets the [`assessment_run_arn`](struct.DeleteAssessmentRunRequest.html#structfield.assessment_run_arn) member.
+    pub fn assessment_run_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_run_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAssessmentRunRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAssessmentRunRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAssessmentRunRequest {
+            assessment_run_arn: self
+                .assessment_run_arn
+                .ok_or(::rusoto_core::BuildError::missing("assessment_run_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteAssessmentTargetRequest {
     /// <p>The ARN that specifies the assessment target that you want to delete.</p>
@@ -474,6 +1166,38 @@ pub struct DeleteAssessmentTargetRequest {
     pub assessment_target_arn: String,
 }
 
+impl DeleteAssessmentTargetRequest {
+    /// Returns a builder for `DeleteAssessmentTargetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAssessmentTargetRequestBuilder {
+        DeleteAssessmentTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAssessmentTargetRequest`](struct.DeleteAssessmentTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAssessmentTargetRequestBuilder {
+    assessment_target_arn: Option<String>,
+}
+
+impl DeleteAssessmentTargetRequestBuilder {
+    /// Sets the [`assessment_target_arn`](struct.DeleteAssessmentTargetRequest.html#structfield.assessment_target_arn) member.
+    pub fn assessment_target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_target_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAssessmentTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAssessmentTargetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAssessmentTargetRequest {
+            assessment_target_arn: self
+                .assessment_target_arn
+                .ok_or(::rusoto_core::BuildError::missing("assessment_target_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteAssessmentTemplateRequest {
     /// <p>The ARN that specifies the assessment template that you want to delete.</p>
@@ -481,6 +1205,38 @@ pub struct DeleteAssessmentTemplateRequest {
     pub assessment_template_arn: String,
 }
 
+impl DeleteAssessmentTemplateRequest {
+    /// Returns a builder for `DeleteAssessmentTemplateRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAssessmentTemplateRequestBuilder {
+        DeleteAssessmentTemplateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAssessmentTemplateRequest`](struct.DeleteAssessmentTemplateRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAssessmentTemplateRequestBuilder {
+    assessment_template_arn: Option<String>,
+}
+
+impl DeleteAssessmentTemplateRequestBuilder {
+    /// Sets the [`assessment_template_arn`](struct.DeleteAssessmentTemplateRequest.html#structfield.assessment_template_arn) member.
+    pub fn assessment_template_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_template_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAssessmentTemplateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAssessmentTemplateRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAssessmentTemplateRequest {
+            assessment_template_arn: self.assessment_template_arn.ok_or(
+                ::rusoto_core::BuildError::missing("assessment_template_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeAssessmentRunsRequest {
     /// <p>The ARN that specifies the assessment run that you want to describe.</p>
@@ -488,8 +1244,40 @@ pub struct DescribeAssessmentRunsRequest {
     pub assessment_run_arns: Vec<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl DescribeAssessmentRunsRequest {
+    /// Returns a builder for `DescribeAssessmentRunsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAssessmentRunsRequestBuilder {
+        DescribeAssessmentRunsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAssessmentRunsRequest`](struct.DescribeAssessmentRunsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAssessmentRunsRequestBuilder {
+    assessment_run_arns: Option<Vec<String>>,
+}
+
+impl DescribeAssessmentRunsRequestBuilder {
+    /// Sets the [`assessment_run_arns`](struct.DescribeAssessmentRunsRequest.html#structfield.assessment_run_arns) member.
+    pub fn assessment_run_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.assessment_run_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAssessmentRunsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAssessmentRunsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAssessmentRunsRequest {
+            assessment_run_arns: self
+                .assessment_run_arns
+                .ok_or(::rusoto_core::BuildError::missing("assessment_run_arns"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAssessmentRunsResponse {
     /// <p>Information about the assessment run.</p>
     #[serde(rename = "assessmentRuns")]
@@ -506,6 +1294,38 @@ pub struct DescribeAssessmentTargetsRequest {
     pub assessment_target_arns: Vec<String>,
 }
 
+impl DescribeAssessmentTargetsRequest {
+    /// Returns a builder for `DescribeAssessmentTargetsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAssessmentTargetsRequestBuilder {
+        DescribeAssessmentTargetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAssessmentTargetsRequest`](struct.DescribeAssessmentTargetsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAssessmentTargetsRequestBuilder {
+    assessment_target_arns: Option<Vec<String>>,
+}
+
+impl DescribeAssessmentTargetsRequestBuilder {
+    /// Sets the [`assessment_target_arns`](struct.DescribeAssessmentTargetsRequest.html#structfield.assessment_target_arns) member.
+    pub fn assessment_target_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.assessment_target_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAssessmentTargetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAssessmentTargetsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAssessmentTargetsRequest {
+            assessment_target_arns: self
+                .assessment_target_arns
+                .ok_or(::rusoto_core::BuildError::missing("assessment_target_arns"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAssessmentTargetsResponse {
@@ -523,6 +1343,38 @@ pub struct DescribeAssessmentTemplatesRequest {
     pub assessment_template_arns: Vec<String>,
 }
 
+impl DescribeAssessmentTemplatesRequest {
+    /// Returns a builder for `DescribeAssessmentTemplatesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAssessmentTemplatesRequestBuilder {
+        DescribeAssessmentTemplatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAssessmentTemplatesRequest`](struct.DescribeAssessmentTemplatesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAssessmentTemplatesRequestBuilder {
+    assessment_template_arns: Option<Vec<String>>,
+}
+
+impl DescribeAssessmentTemplatesRequestBuilder {
+    /// Sets the [`assessment_template_arns`](struct.DescribeAssessmentTemplatesRequest.html#structfield.assessment_template_arns) member.
+    pub fn assessment_template_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.assessment_template_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAssessmentTemplatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAssessmentTemplatesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAssessmentTemplatesRequest {
+            assessment_template_arns: self.assessment_template_arns.ok_or(
+                ::rusoto_core::BuildError::missing("assessment_template_arns"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAssessmentTemplatesResponse {
@@ -539,7 +1391,8 @@ pub struct DescribeAssessmentTemplatesResponse {
 pub struct DescribeCrossAccountAccessRoleResponse {
     /// <p>The date when the cross-account access role was registered.</p>
     #[serde(rename = "registeredAt")]
-    pub registered_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub registered_at: EpochTimestamp,
     /// <p>The ARN that specifies the IAM role that Amazon Inspector uses to access your AWS account.</p>
     #[serde(rename = "roleArn")]
     pub role_arn: String,
@@ -556,7 +1409,47 @@ pub struct DescribeExclusionsRequest {
     /// <p>The locale into which you want to translate the exclusion's title, description, and recommendation.</p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
+}
+
+impl DescribeExclusionsRequest {
+    /// Returns a builder for `DescribeExclusionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeExclusionsRequestBuilder {
+        DescribeExclusionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeExclusionsRequest`](struct.DescribeExclusionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeExclusionsRequestBuilder {
+    exclusion_arns: Option<Vec<String>>,
+    locale: Option<Locale>,
+}
+
+impl DescribeExclusionsRequestBuilder {
+    /// Sets the [`exclusion_arns`](struct.DescribeExclusionsRequest.html#structfield.exclusion_arns) member.
+    pub fn exclusion_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.exclusion_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`locale`](struct.DescribeExclusionsRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<Locale>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeExclusionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeExclusionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeExclusionsRequest {
+            exclusion_arns: self
+                .exclusion_arns
+                .ok_or(::rusoto_core::BuildError::missing("exclusion_arns"))?,
+            locale: self.locale,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -578,7 +1471,47 @@ pub struct DescribeFindingsRequest {
     /// <p>The locale into which you want to translate a finding description, recommendation, and the short description that identifies the finding.</p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
+}
+
+impl DescribeFindingsRequest {
+    /// Returns a builder for `DescribeFindingsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeFindingsRequestBuilder {
+        DescribeFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeFindingsRequest`](struct.DescribeFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeFindingsRequestBuilder {
+    finding_arns: Option<Vec<String>>,
+    locale: Option<Locale>,
+}
+
+impl DescribeFindingsRequestBuilder {
+    /// Sets the [`finding_arns`](struct.DescribeFindingsRequest.html#structfield.finding_arns) member.
+    pub fn finding_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.finding_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`locale`](struct.DescribeFindingsRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<Locale>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeFindingsRequest {
+            finding_arns: self
+                .finding_arns
+                .ok_or(::rusoto_core::BuildError::missing("finding_arns"))?,
+            locale: self.locale,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -599,6 +1532,38 @@ pub struct DescribeResourceGroupsRequest {
     pub resource_group_arns: Vec<String>,
 }
 
+impl DescribeResourceGroupsRequest {
+    /// Returns a builder for `DescribeResourceGroupsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeResourceGroupsRequestBuilder {
+        DescribeResourceGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeResourceGroupsRequest`](struct.DescribeResourceGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeResourceGroupsRequestBuilder {
+    resource_group_arns: Option<Vec<String>>,
+}
+
+impl DescribeResourceGroupsRequestBuilder {
+    /// Sets the [`resource_group_arns`](struct.DescribeResourceGroupsRequest.html#structfield.resource_group_arns) member.
+    pub fn resource_group_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_group_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeResourceGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeResourceGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeResourceGroupsRequest {
+            resource_group_arns: self
+                .resource_group_arns
+                .ok_or(::rusoto_core::BuildError::missing("resource_group_arns"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeResourceGroupsResponse {
@@ -615,12 +1580,52 @@ pub struct DescribeRulesPackagesRequest {
     /// <p>The locale that you want to translate a rules package description into.</p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>The ARN that specifies the rules package that you want to describe.</p>
     #[serde(rename = "rulesPackageArns")]
     pub rules_package_arns: Vec<String>,
 }
 
+impl DescribeRulesPackagesRequest {
+    /// Returns a builder for `DescribeRulesPackagesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeRulesPackagesRequestBuilder {
+        DescribeRulesPackagesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRulesPackagesRequest`](struct.DescribeRulesPackagesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeRulesPackagesRequestBuilder {
+    locale: Option<Locale>,
+    rules_package_arns: Option<Vec<String>>,
+}
+
+impl DescribeRulesPackagesRequestBuilder {
+    /// Sets the [`locale`](struct.DescribeRulesPackagesRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<Locale>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rules_package_arns`](struct.DescribeRulesPackagesRequest.html#structfield.rules_package_arns) member.
+    pub fn rules_package_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.rules_package_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRulesPackagesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeRulesPackagesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeRulesPackagesRequest {
+            locale: self.locale,
+            rules_package_arns: self
+                .rules_package_arns
+                .ok_or(::rusoto_core::BuildError::missing("rules_package_arns"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeRulesPackagesResponse {
@@ -646,15 +1651,16 @@ pub struct DurationRange {
 }
 
 /// <p>This data type is used in the <a>Subscription</a> data type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct EventSubscription {
     /// <p>The event for which Amazon Simple Notification Service (SNS) notifications are sent.</p>
     #[serde(rename = "event")]
-    pub event: String,
+    pub event: InspectorEvent,
     /// <p>The time at which <a>SubscribeToEvent</a> is called.</p>
     #[serde(rename = "subscribedAt")]
-    pub subscribed_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub subscribed_at: EpochTimestamp,
 }
 
 /// <p>Contains information about what was excluded from an assessment run.</p>
@@ -705,17 +1711,105 @@ pub struct ExclusionPreview {
 }
 
 /// <p>Includes details about the failed items.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct FailedItemDetails {
     /// <p>The status code of a failed item.</p>
     #[serde(rename = "failureCode")]
-    pub failure_code: String,
+    pub failure_code: FailedItemErrorCode,
     /// <p>Indicates whether you can immediately retry a request for this item for a specified resource.</p>
     #[serde(rename = "retryable")]
     pub retryable: bool,
 }
 
+/// <p>The status code of a failed item.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FailedItemErrorCode {
+    /// <p><code>INVALID_ARN</code></p>
+    InvalidArn,
+    /// <p><code>DUPLICATE_ARN</code></p>
+    DuplicateArn,
+    /// <p><code>ITEM_DOES_NOT_EXIST</code></p>
+    ItemDoesNotExist,
+    /// <p><code>ACCESS_DENIED</code></p>
+    AccessDenied,
+    /// <p><code>LIMIT_EXCEEDED</code></p>
+    LimitExceeded,
+    /// <p><code>INTERNAL_ERROR</code></p>
+    InternalError,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FailedItemErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FailedItemErrorCode::InvalidArn => "INVALID_ARN",
+            FailedItemErrorCode::DuplicateArn => "DUPLICATE_ARN",
+            FailedItemErrorCode::ItemDoesNotExist => "ITEM_DOES_NOT_EXIST",
+            FailedItemErrorCode::AccessDenied => "ACCESS_DENIED",
+            FailedItemErrorCode::LimitExceeded => "LIMIT_EXCEEDED",
+            FailedItemErrorCode::InternalError => "INTERNAL_ERROR",
+            FailedItemErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FailedItemErrorCode {
+    fn from(value: &'a str) -> FailedItemErrorCode {
+        match value {
+            "INVALID_ARN" => FailedItemErrorCode::InvalidArn,
+            "DUPLICATE_ARN" => FailedItemErrorCode::DuplicateArn,
+            "ITEM_DOES_NOT_EXIST" => FailedItemErrorCode::ItemDoesNotExist,
+            "ACCESS_DENIED" => FailedItemErrorCode::AccessDenied,
+            "LIMIT_EXCEEDED" => FailedItemErrorCode::LimitExceeded,
+            "INTERNAL_ERROR" => FailedItemErrorCode::InternalError,
+            _ => FailedItemErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FailedItemErrorCode {
+    fn from(value: String) -> FailedItemErrorCode {
+        FailedItemErrorCode::from(value.as_str())
+    }
+}
+
+impl From<FailedItemErrorCode> for String {
+    fn from(value: FailedItemErrorCode) -> String {
+        match value {
+            FailedItemErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FailedItemErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FailedItemErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FailedItemErrorCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FailedItemErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FailedItemErrorCode::from(value))
+    }
+}
+
 /// <p>Contains information about an Amazon Inspector finding. This data type is used as the response element in the <a>DescribeFindings</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -730,7 +1824,7 @@ pub struct Finding {
     /// <p>The type of the host from which the finding is generated.</p>
     #[serde(rename = "assetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub asset_type: Option<String>,
+    pub asset_type: Option<AssetType>,
     /// <p>The system-defined attributes for the finding.</p>
     #[serde(rename = "attributes")]
     pub attributes: Vec<Attribute>,
@@ -740,7 +1834,8 @@ pub struct Finding {
     pub confidence: Option<i64>,
     /// <p>The time when the finding was generated.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub created_at: EpochTimestamp,
     /// <p>The description of the finding.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -776,14 +1871,15 @@ pub struct Finding {
     /// <p>The finding severity. Values can be set to High, Medium, Low, and Informational.</p>
     #[serde(rename = "severity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub severity: Option<String>,
+    pub severity: Option<Severity>,
     /// <p>The name of the finding.</p>
     #[serde(rename = "title")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
     /// <p>The time when <a>AddAttributesToFindings</a> is called.</p>
     #[serde(rename = "updatedAt")]
-    pub updated_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub updated_at: EpochTimestamp,
     /// <p>The user-defined attributes that are assigned to the finding.</p>
     #[serde(rename = "userAttributes")]
     pub user_attributes: Vec<Attribute>,
@@ -819,32 +1915,84 @@ pub struct FindingFilter {
     /// <p>For a record to match a filter, one of the values that is specified for this data type property must be the exact match of the value of the <b>severity</b> property of the <a>Finding</a> data type.</p>
     #[serde(rename = "severities")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub severities: Option<Vec<String>>,
+    pub severities: Option<Vec<Severity>>,
     /// <p>For a record to match a filter, the value that is specified for this data type property must be contained in the list of values of the <b>userAttributes</b> property of the <a>Finding</a> data type.</p>
     #[serde(rename = "userAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_attributes: Option<Vec<Attribute>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetAssessmentReportRequest {
     /// <p>The ARN that specifies the assessment run for which you want to generate a report.</p>
     #[serde(rename = "assessmentRunArn")]
     pub assessment_run_arn: String,
     /// <p>Specifies the file format (html or pdf) of the assessment report that you want to generate.</p>
     #[serde(rename = "reportFileFormat")]
-    pub report_file_format: String,
+    pub report_file_format: ReportFileFormat,
     /// <p>Specifies the type of the assessment report that you want to generate. There are two types of assessment reports: a finding report and a full report. For more information, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_reports.html">Assessment Reports</a>. </p>
     #[serde(rename = "reportType")]
-    pub report_type: String,
+    pub report_type: ReportType,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl GetAssessmentReportRequest {
+    /// Returns a builder for `GetAssessmentReportRequest`, checking its required members when built.
+    pub fn builder() -> GetAssessmentReportRequestBuilder {
+        GetAssessmentReportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAssessmentReportRequest`](struct.GetAssessmentReportRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAssessmentReportRequestBuilder {
+    assessment_run_arn: Option<String>,
+    report_file_format: Option<ReportFileFormat>,
+    report_type: Option<ReportType>,
+}
+
+impl GetAssessmentReportRequestBuilder {
+    /// Sets the [`assessment_run_arn`](struct.GetAssessmentReportRequest.html#structfield.assessment_run_arn) member.
+    pub fn assessment_run_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_run_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`report_file_format`](struct.GetAssessmentReportRequest.html#structfield.report_file_format) member.
+    pub fn report_file_format<T: Into<ReportFileFormat>>(mut self, value: T) -> Self {
+        self.report_file_format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`report_type`](struct.GetAssessmentReportRequest.html#structfield.report_type) member.
+    pub fn report_type<T: Into<ReportType>>(mut self, value: T) -> Self {
+        self.report_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAssessmentReportRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAssessmentReportRequest, ::rusoto_core::BuildError> {
+        Ok(GetAssessmentReportRequest {
+            assessment_run_arn: self
+                .assessment_run_arn
+                .ok_or(::rusoto_core::BuildError::missing("assessment_run_arn"))?,
+            report_file_format: self
+                .report_file_format
+                .ok_or(::rusoto_core::BuildError::missing("report_file_format"))?,
+            report_type: self
+                .report_type
+                .ok_or(::rusoto_core::BuildError::missing("report_type"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAssessmentReportResponse {
     /// <p>Specifies the status of the request to generate an assessment report. </p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: ReportStatus,
     /// <p>Specifies the URL where you can find the generated assessment report. This parameter is only returned if the report is successfully generated.</p>
     #[serde(rename = "url")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -859,7 +2007,7 @@ pub struct GetExclusionsPreviewRequest {
     /// <p>The locale into which you want to translate the exclusion's title, description, and recommendation.</p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 100. The maximum value is 500.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -873,7 +2021,73 @@ pub struct GetExclusionsPreviewRequest {
     pub preview_token: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl GetExclusionsPreviewRequest {
+    /// Returns a builder for `GetExclusionsPreviewRequest`, checking its required members when built.
+    pub fn builder() -> GetExclusionsPreviewRequestBuilder {
+        GetExclusionsPreviewRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetExclusionsPreviewRequest`](struct.GetExclusionsPreviewRequest.html).
+#[derive(Default, Debug)]
+pub struct GetExclusionsPreviewRequestBuilder {
+    assessment_template_arn: Option<String>,
+    locale: Option<Locale>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    preview_token: Option<String>,
+}
+
+impl GetExclusionsPreviewRequestBuilder {
+    /// Sets the [`assessment_template_arn`](struct.GetExclusionsPreviewRequest.html#structfield.assessment_template_arn) member.
+    pub fn assessment_template_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_template_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`locale`](struct.GetExclusionsPreviewRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<Locale>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetExclusionsPreviewRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetExclusionsPreviewRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preview_token`](struct.GetExclusionsPreviewRequest.html#structfield.preview_token) member.
+    pub fn preview_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.preview_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetExclusionsPreviewRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetExclusionsPreviewRequest, ::rusoto_core::BuildError> {
+        Ok(GetExclusionsPreviewRequest {
+            assessment_template_arn: self.assessment_template_arn.ok_or(
+                ::rusoto_core::BuildError::missing("assessment_template_arn"),
+            )?,
+            locale: self.locale,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            preview_token: self
+                .preview_token
+                .ok_or(::rusoto_core::BuildError::missing("preview_token"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetExclusionsPreviewResponse {
     /// <p>Information about the exclusions included in the preview.</p>
@@ -886,7 +2100,7 @@ pub struct GetExclusionsPreviewResponse {
     pub next_token: Option<String>,
     /// <p>Specifies the status of the request to generate an exclusions preview.</p>
     #[serde(rename = "previewStatus")]
-    pub preview_status: String,
+    pub preview_status: PreviewStatus,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -896,6 +2110,38 @@ pub struct GetTelemetryMetadataRequest {
     pub assessment_run_arn: String,
 }
 
+impl GetTelemetryMetadataRequest {
+    /// Returns a builder for `GetTelemetryMetadataRequest`, checking its required members when built.
+    pub fn builder() -> GetTelemetryMetadataRequestBuilder {
+        GetTelemetryMetadataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTelemetryMetadataRequest`](struct.GetTelemetryMetadataRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTelemetryMetadataRequestBuilder {
+    assessment_run_arn: Option<String>,
+}
+
+impl GetTelemetryMetadataRequestBuilder {
+    /// Sets the [`assessment_run_arn`](struct.GetTelemetryMetadataRequest.html#structfield.assessment_run_arn) member.
+    pub fn assessment_run_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_run_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTelemetryMetadataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetTelemetryMetadataRequest, ::rusoto_core::BuildError> {
+        Ok(GetTelemetryMetadataRequest {
+            assessment_run_arn: self
+                .assessment_run_arn
+                .ok_or(::rusoto_core::BuildError::missing("assessment_run_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTelemetryMetadataResponse {
@@ -904,6 +2150,90 @@ pub struct GetTelemetryMetadataResponse {
     pub telemetry_metadata: Vec<TelemetryMetadata>,
 }
 
+/// <p>The event for which a notification is sent.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InspectorEvent {
+    /// <p><code>ASSESSMENT_RUN_STARTED</code></p>
+    AssessmentRunStarted,
+    /// <p><code>ASSESSMENT_RUN_COMPLETED</code></p>
+    AssessmentRunCompleted,
+    /// <p><code>ASSESSMENT_RUN_STATE_CHANGED</code></p>
+    AssessmentRunStateChanged,
+    /// <p><code>FINDING_REPORTED</code></p>
+    FindingReported,
+    /// <p><code>OTHER</code></p>
+    Other,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InspectorEvent {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InspectorEvent::AssessmentRunStarted => "ASSESSMENT_RUN_STARTED",
+            InspectorEvent::AssessmentRunCompleted => "ASSESSMENT_RUN_COMPLETED",
+            InspectorEvent::AssessmentRunStateChanged => "ASSESSMENT_RUN_STATE_CHANGED",
+            InspectorEvent::FindingReported => "FINDING_REPORTED",
+            InspectorEvent::Other => "OTHER",
+            InspectorEvent::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InspectorEvent {
+    fn from(value: &'a str) -> InspectorEvent {
+        match value {
+            "ASSESSMENT_RUN_STARTED" => InspectorEvent::AssessmentRunStarted,
+            "ASSESSMENT_RUN_COMPLETED" => InspectorEvent::AssessmentRunCompleted,
+            "ASSESSMENT_RUN_STATE_CHANGED" => InspectorEvent::AssessmentRunStateChanged,
+            "FINDING_REPORTED" => InspectorEvent::FindingReported,
+            "OTHER" => InspectorEvent::Other,
+            _ => InspectorEvent::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InspectorEvent {
+    fn from(value: String) -> InspectorEvent {
+        InspectorEvent::from(value.as_str())
+    }
+}
+
+impl From<InspectorEvent> for String {
+    fn from(value: InspectorEvent) -> String {
+        match value {
+            InspectorEvent::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InspectorEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InspectorEvent {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InspectorEvent {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InspectorEvent, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InspectorEvent::from(value))
+    }
+}
+
 /// <p>This data type is used in the <a>Finding</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -940,6 +2270,62 @@ pub struct ListAssessmentRunAgentsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListAssessmentRunAgentsRequest {
+    /// Returns a builder for `ListAssessmentRunAgentsRequest`, checking its required members when built.
+    pub fn builder() -> ListAssessmentRunAgentsRequestBuilder {
+        ListAssessmentRunAgentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAssessmentRunAgentsRequest`](struct.ListAssessmentRunAgentsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAssessmentRunAgentsRequestBuilder {
+    assessment_run_arn: Option<String>,
+    filter: Option<AgentFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAssessmentRunAgentsRequestBuilder {
+    /// Sets the [`assessment_run_arn`](struct.ListAssessmentRunAgentsRequest.html#structfield.assessment_run_arn) member.
+    pub fn assessment_run_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_run_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter`](struct.ListAssessmentRunAgentsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<AgentFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAssessmentRunAgentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAssessmentRunAgentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAssessmentRunAgentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAssessmentRunAgentsRequest, ::rusoto_core::BuildError> {
+        Ok(ListAssessmentRunAgentsRequest {
+            assessment_run_arn: self
+                .assessment_run_arn
+                .ok_or(::rusoto_core::BuildError::missing("assessment_run_arn"))?,
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAssessmentRunAgentsResponse {
@@ -972,6 +2358,60 @@ pub struct ListAssessmentRunsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListAssessmentRunsRequest {
+    /// Returns a builder for `ListAssessmentRunsRequest`, checking its required members when built.
+    pub fn builder() -> ListAssessmentRunsRequestBuilder {
+        ListAssessmentRunsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAssessmentRunsRequest`](struct.ListAssessmentRunsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAssessmentRunsRequestBuilder {
+    assessment_template_arns: Option<Vec<String>>,
+    filter: Option<AssessmentRunFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAssessmentRunsRequestBuilder {
+    /// Sets the [`assessment_template_arns`](struct.ListAssessmentRunsRequest.html#structfield.assessment_template_arns) member.
+    pub fn assessment_template_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.assessment_template_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter`](struct.ListAssessmentRunsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<AssessmentRunFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAssessmentRunsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAssessmentRunsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAssessmentRunsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAssessmentRunsRequest, ::rusoto_core::BuildError> {
+        Ok(ListAssessmentRunsRequest {
+            assessment_template_arns: self.assessment_template_arns,
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAssessmentRunsResponse {
@@ -1000,6 +2440,52 @@ pub struct ListAssessmentTargetsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListAssessmentTargetsRequest {
+    /// Returns a builder for `ListAssessmentTargetsRequest`, checking its required members when built.
+    pub fn builder() -> ListAssessmentTargetsRequestBuilder {
+        ListAssessmentTargetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAssessmentTargetsRequest`](struct.ListAssessmentTargetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAssessmentTargetsRequestBuilder {
+    filter: Option<AssessmentTargetFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAssessmentTargetsRequestBuilder {
+    /// Sets the [`filter`](struct.ListAssessmentTargetsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<AssessmentTargetFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAssessmentTargetsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAssessmentTargetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAssessmentTargetsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAssessmentTargetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListAssessmentTargetsRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAssessmentTargetsResponse {
@@ -1032,19 +2518,73 @@ pub struct ListAssessmentTemplatesRequest {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListAssessmentTemplatesResponse {
-    /// <p>A list of ARNs that specifies the assessment templates returned by the action.</p>
-    #[serde(rename = "assessmentTemplateArns")]
-    pub assessment_template_arns: Vec<String>,
-    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
+impl ListAssessmentTemplatesRequest {
+    /// Returns a builder for `ListAssessmentTemplatesRequest`, checking its required members when built.
+    pub fn builder() -> ListAssessmentTemplatesRequestBuilder {
+        ListAssessmentTemplatesRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// Builds a [`ListAssessmentTemplatesRequest`](struct.ListAssessmentTemplatesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAssessmentTemplatesRequestBuilder {
+    assessment_target_arns: Option<Vec<String>>,
+    filter: Option<AssessmentTemplateFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAssessmentTemplatesRequestBuilder {
+    /// Sets the [`assessment_target_arns`](struct.ListAssessmentTemplatesRequest.html#structfield.assessment_target_arns) member.
+    pub fn assessment_target_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.assessment_target_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter`](struct.ListAssessmentTemplatesRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<AssessmentTemplateFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAssessmentTemplatesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAssessmentTemplatesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAssessmentTemplatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAssessmentTemplatesRequest, ::rusoto_core::BuildError> {
+        Ok(ListAssessmentTemplatesRequest {
+            assessment_target_arns: self.assessment_target_arns,
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListAssessmentTemplatesResponse {
+    /// <p>A list of ARNs that specifies the assessment templates returned by the action.</p>
+    #[serde(rename = "assessmentTemplateArns")]
+    pub assessment_template_arns: Vec<String>,
+    /// <p> When a response is generated, if there is more data to be listed, this parameter is present in the response and contains the value to use for the <b>nextToken</b> parameter in a subsequent pagination request. If there is no more data to be listed, this parameter is set to null.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListEventSubscriptionsRequest {
     /// <p>You can use this parameter to indicate the maximum number of items you want in the response. The default value is 10. The maximum value is 500.</p>
     #[serde(rename = "maxResults")]
@@ -1060,6 +2600,52 @@ pub struct ListEventSubscriptionsRequest {
     pub resource_arn: Option<String>,
 }
 
+impl ListEventSubscriptionsRequest {
+    /// Returns a builder for `ListEventSubscriptionsRequest`, checking its required members when built.
+    pub fn builder() -> ListEventSubscriptionsRequestBuilder {
+        ListEventSubscriptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListEventSubscriptionsRequest`](struct.ListEventSubscriptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListEventSubscriptionsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListEventSubscriptionsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListEventSubscriptionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListEventSubscriptionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListEventSubscriptionsRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListEventSubscriptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListEventSubscriptionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListEventSubscriptionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_arn: self.resource_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListEventSubscriptionsResponse {
@@ -1087,6 +2673,52 @@ pub struct ListExclusionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListExclusionsRequest {
+    /// Returns a builder for `ListExclusionsRequest`, checking its required members when built.
+    pub fn builder() -> ListExclusionsRequestBuilder {
+        ListExclusionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListExclusionsRequest`](struct.ListExclusionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListExclusionsRequestBuilder {
+    assessment_run_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListExclusionsRequestBuilder {
+    /// Sets the [`assessment_run_arn`](struct.ListExclusionsRequest.html#structfield.assessment_run_arn) member.
+    pub fn assessment_run_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_run_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListExclusionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListExclusionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListExclusionsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListExclusionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListExclusionsRequest {
+            assessment_run_arn: self
+                .assessment_run_arn
+                .ok_or(::rusoto_core::BuildError::missing("assessment_run_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListExclusionsResponse {
@@ -1119,6 +2751,58 @@ pub struct ListFindingsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListFindingsRequest {
+    /// Returns a builder for `ListFindingsRequest`, checking its required members when built.
+    pub fn builder() -> ListFindingsRequestBuilder {
+        ListFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFindingsRequest`](struct.ListFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFindingsRequestBuilder {
+    assessment_run_arns: Option<Vec<String>>,
+    filter: Option<FindingFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListFindingsRequestBuilder {
+    /// Sets the [`assessment_run_arns`](struct.ListFindingsRequest.html#structfield.assessment_run_arns) member.
+    pub fn assessment_run_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.assessment_run_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter`](struct.ListFindingsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<FindingFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListFindingsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListFindingsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(ListFindingsRequest {
+            assessment_run_arns: self.assessment_run_arns,
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListFindingsResponse {
@@ -1143,6 +2827,44 @@ pub struct ListRulesPackagesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListRulesPackagesRequest {
+    /// Returns a builder for `ListRulesPackagesRequest`, checking its required members when built.
+    pub fn builder() -> ListRulesPackagesRequestBuilder {
+        ListRulesPackagesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRulesPackagesRequest`](struct.ListRulesPackagesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRulesPackagesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListRulesPackagesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListRulesPackagesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListRulesPackagesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRulesPackagesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListRulesPackagesRequest, ::rusoto_core::BuildError> {
+        Ok(ListRulesPackagesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRulesPackagesResponse {
@@ -1162,6 +2884,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -1170,6 +2924,74 @@ pub struct ListTagsForResourceResponse {
     pub tags: Vec<Tag>,
 }
 
+/// <p>The locale into which you want to translate the exclusion's title, description, and recommendation.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// <p><code>EN_US</code></p>
+    EnUs,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Locale {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Locale::EnUs => "EN_US",
+            Locale::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Locale {
+    fn from(value: &'a str) -> Locale {
+        match value {
+            "EN_US" => Locale::EnUs,
+            _ => Locale::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Locale {
+    fn from(value: String) -> Locale {
+        Locale::from(value.as_str())
+    }
+}
+
+impl From<Locale> for String {
+    fn from(value: Locale) -> String {
+        match value {
+            Locale::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Locale, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Locale::from(value))
+    }
+}
+
 /// <p>Contains information about the network interfaces interacting with an EC2 instance. This data type is used as one of the elements of the <a>AssetAttributes</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1231,6 +3053,52 @@ pub struct PreviewAgentsRequest {
     pub preview_agents_arn: String,
 }
 
+impl PreviewAgentsRequest {
+    /// Returns a builder for `PreviewAgentsRequest`, checking its required members when built.
+    pub fn builder() -> PreviewAgentsRequestBuilder {
+        PreviewAgentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PreviewAgentsRequest`](struct.PreviewAgentsRequest.html).
+#[derive(Default, Debug)]
+pub struct PreviewAgentsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    preview_agents_arn: Option<String>,
+}
+
+impl PreviewAgentsRequestBuilder {
+    /// Sets the [`max_results`](struct.PreviewAgentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.PreviewAgentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preview_agents_arn`](struct.PreviewAgentsRequest.html#structfield.preview_agents_arn) member.
+    pub fn preview_agents_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.preview_agents_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `PreviewAgentsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PreviewAgentsRequest, ::rusoto_core::BuildError> {
+        Ok(PreviewAgentsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            preview_agents_arn: self
+                .preview_agents_arn
+                .ok_or(::rusoto_core::BuildError::missing("preview_agents_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PreviewAgentsResponse {
@@ -1243,6 +3111,78 @@ pub struct PreviewAgentsResponse {
     pub next_token: Option<String>,
 }
 
+/// <p>Specifies the status of the request to generate an exclusions preview.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PreviewStatus {
+    /// <p><code>WORK_IN_PROGRESS</code></p>
+    WorkInProgress,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PreviewStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PreviewStatus::WorkInProgress => "WORK_IN_PROGRESS",
+            PreviewStatus::Completed => "COMPLETED",
+            PreviewStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PreviewStatus {
+    fn from(value: &'a str) -> PreviewStatus {
+        match value {
+            "WORK_IN_PROGRESS" => PreviewStatus::WorkInProgress,
+            "COMPLETED" => PreviewStatus::Completed,
+            _ => PreviewStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PreviewStatus {
+    fn from(value: String) -> PreviewStatus {
+        PreviewStatus::from(value.as_str())
+    }
+}
+
+impl From<PreviewStatus> for String {
+    fn from(value: PreviewStatus) -> String {
+        match value {
+            PreviewStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PreviewStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PreviewStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PreviewStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PreviewStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PreviewStatus::from(value))
+    }
+}
+
 /// <p>Contains information about a private IP address associated with a network interface. This data type is used as a response element in the <a>DescribeFindings</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1264,6 +3204,39 @@ pub struct RegisterCrossAccountAccessRoleRequest {
     pub role_arn: String,
 }
 
+impl RegisterCrossAccountAccessRoleRequest {
+    /// Returns a builder for `RegisterCrossAccountAccessRoleRequest`, checking its required members when built.
+    pub fn builder() -> RegisterCrossAccountAccessRoleRequestBuilder {
+        RegisterCrossAccountAccessRoleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterCrossAccountAccessRoleRequest`](struct.RegisterCrossAccountAccessRoleRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterCrossAccountAccessRoleRequestBuilder {
+    role_arn: Option<String>,
+}
+
+impl RegisterCrossAccountAccessRoleRequestBuilder {
+    /// Sets the [`role_arn`](struct.RegisterCrossAccountAccessRoleRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterCrossAccountAccessRoleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterCrossAccountAccessRoleRequest, ::rusoto_core::BuildError>
+    {
+        Ok(RegisterCrossAccountAccessRoleRequest {
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RemoveAttributesFromFindingsRequest {
     /// <p>The array of attribute keys that you want to remove from specified findings.</p>
@@ -1274,6 +3247,48 @@ pub struct RemoveAttributesFromFindingsRequest {
     pub finding_arns: Vec<String>,
 }
 
+impl RemoveAttributesFromFindingsRequest {
+    /// Returns a builder for `RemoveAttributesFromFindingsRequest`, checking its required members when built.
+    pub fn builder() -> RemoveAttributesFromFindingsRequestBuilder {
+        RemoveAttributesFromFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveAttributesFromFindingsRequest`](struct.RemoveAttributesFromFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveAttributesFromFindingsRequestBuilder {
+    attribute_keys: Option<Vec<String>>,
+    finding_arns: Option<Vec<String>>,
+}
+
+impl RemoveAttributesFromFindingsRequestBuilder {
+    /// Sets the [`attribute_keys`](struct.RemoveAttributesFromFindingsRequest.html#structfield.attribute_keys) member.
+    pub fn attribute_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.attribute_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_arns`](struct.RemoveAttributesFromFindingsRequest.html#structfield.finding_arns) member.
+    pub fn finding_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.finding_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveAttributesFromFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveAttributesFromFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveAttributesFromFindingsRequest {
+            attribute_keys: self
+                .attribute_keys
+                .ok_or(::rusoto_core::BuildError::missing("attribute_keys"))?,
+            finding_arns: self
+                .finding_arns
+                .ok_or(::rusoto_core::BuildError::missing("finding_arns"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RemoveAttributesFromFindingsResponse {
@@ -1282,6 +3297,226 @@ pub struct RemoveAttributesFromFindingsResponse {
     pub failed_items: ::std::collections::HashMap<String, FailedItemDetails>,
 }
 
+/// <p>Specifies the file format (html or pdf) of the assessment report that you want to generate.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReportFileFormat {
+    /// <p><code>HTML</code></p>
+    Html,
+    /// <p><code>PDF</code></p>
+    Pdf,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReportFileFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportFileFormat::Html => "HTML",
+            ReportFileFormat::Pdf => "PDF",
+            ReportFileFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReportFileFormat {
+    fn from(value: &'a str) -> ReportFileFormat {
+        match value {
+            "HTML" => ReportFileFormat::Html,
+            "PDF" => ReportFileFormat::Pdf,
+            _ => ReportFileFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportFileFormat {
+    fn from(value: String) -> ReportFileFormat {
+        ReportFileFormat::from(value.as_str())
+    }
+}
+
+impl From<ReportFileFormat> for String {
+    fn from(value: ReportFileFormat) -> String {
+        match value {
+            ReportFileFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReportFileFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReportFileFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportFileFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReportFileFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReportFileFormat::from(value))
+    }
+}
+
+/// <p>Specifies the status of the request to generate an assessment report. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReportStatus {
+    /// <p><code>WORK_IN_PROGRESS</code></p>
+    WorkInProgress,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReportStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportStatus::WorkInProgress => "WORK_IN_PROGRESS",
+            ReportStatus::Failed => "FAILED",
+            ReportStatus::Completed => "COMPLETED",
+            ReportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReportStatus {
+    fn from(value: &'a str) -> ReportStatus {
+        match value {
+            "WORK_IN_PROGRESS" => ReportStatus::WorkInProgress,
+            "FAILED" => ReportStatus::Failed,
+            "COMPLETED" => ReportStatus::Completed,
+            _ => ReportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportStatus {
+    fn from(value: String) -> ReportStatus {
+        ReportStatus::from(value.as_str())
+    }
+}
+
+impl From<ReportStatus> for String {
+    fn from(value: ReportStatus) -> String {
+        match value {
+            ReportStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReportStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReportStatus::from(value))
+    }
+}
+
+/// <p>Specifies the type of the assessment report that you want to generate. There are two types of assessment reports: a finding report and a full report. For more information, see <a href="https://docs.aws.amazon.com/inspector/latest/userguide/inspector_reports.html">Assessment Reports</a>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReportType {
+    /// <p><code>FINDING</code></p>
+    Finding,
+    /// <p><code>FULL</code></p>
+    Full,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReportType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportType::Finding => "FINDING",
+            ReportType::Full => "FULL",
+            ReportType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReportType {
+    fn from(value: &'a str) -> ReportType {
+        match value {
+            "FINDING" => ReportType::Finding,
+            "FULL" => ReportType::Full,
+            _ => ReportType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportType {
+    fn from(value: String) -> ReportType {
+        ReportType::from(value.as_str())
+    }
+}
+
+impl From<ReportType> for String {
+    fn from(value: ReportType) -> String {
+        match value {
+            ReportType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReportType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReportType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReportType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReportType::from(value))
+    }
+}
+
 /// <p>Contains information about a resource group. The resource group defines a set of tags that, when queried, identify the AWS resources that make up the assessment target. This data type is used as the response element in the <a>DescribeResourceGroups</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1291,7 +3526,8 @@ pub struct ResourceGroup {
     pub arn: String,
     /// <p>The time at which resource group is created.</p>
     #[serde(rename = "createdAt")]
-    pub created_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub created_at: EpochTimestamp,
     /// <p>The tags (key and value pairs) of the resource group. This data type property is used in the <a>CreateResourceGroup</a> action.</p>
     #[serde(rename = "tags")]
     pub tags: Vec<ResourceGroupTag>,
@@ -1338,13 +3574,85 @@ pub struct Scope {
     /// <p>The type of the scope.</p>
     #[serde(rename = "key")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key: Option<String>,
+    pub key: Option<ScopeType>,
     /// <p>The resource identifier for the specified scope type.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+/// <p>The type of the scope.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScopeType {
+    /// <p><code>INSTANCE_ID</code></p>
+    InstanceId,
+    /// <p><code>RULES_PACKAGE_ARN</code></p>
+    RulesPackageArn,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScopeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScopeType::InstanceId => "INSTANCE_ID",
+            ScopeType::RulesPackageArn => "RULES_PACKAGE_ARN",
+            ScopeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScopeType {
+    fn from(value: &'a str) -> ScopeType {
+        match value {
+            "INSTANCE_ID" => ScopeType::InstanceId,
+            "RULES_PACKAGE_ARN" => ScopeType::RulesPackageArn,
+            _ => ScopeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScopeType {
+    fn from(value: String) -> ScopeType {
+        ScopeType::from(value.as_str())
+    }
+}
+
+impl From<ScopeType> for String {
+    fn from(value: ScopeType) -> String {
+        match value {
+            ScopeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScopeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScopeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScopeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ScopeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScopeType::from(value))
+    }
+}
+
 /// <p>Contains information about a security group associated with a network interface. This data type is used as one of the elements of the <a>NetworkInterface</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1370,6 +3678,130 @@ pub struct SetTagsForResourceRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl SetTagsForResourceRequest {
+    /// Returns a builder for `SetTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> SetTagsForResourceRequestBuilder {
+        SetTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetTagsForResourceRequest`](struct.SetTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct SetTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl SetTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.SetTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.SetTagsForResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(SetTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self.tags,
+        })
+    }
+}
+
+/// <p>Provides a total count of generated findings per severity.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// <p><code>Low</code></p>
+    Low,
+    /// <p><code>Medium</code></p>
+    Medium,
+    /// <p><code>High</code></p>
+    High,
+    /// <p><code>Informational</code></p>
+    Informational,
+    /// <p><code>Undefined</code></p>
+    Undefined,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Severity {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Severity::Low => "Low",
+            Severity::Medium => "Medium",
+            Severity::High => "High",
+            Severity::Informational => "Informational",
+            Severity::Undefined => "Undefined",
+            Severity::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Severity {
+    fn from(value: &'a str) -> Severity {
+        match value {
+            "Low" => Severity::Low,
+            "Medium" => Severity::Medium,
+            "High" => Severity::High,
+            "Informational" => Severity::Informational,
+            "Undefined" => Severity::Undefined,
+            _ => Severity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Severity {
+    fn from(value: String) -> Severity {
+        Severity::from(value.as_str())
+    }
+}
+
+impl From<Severity> for String {
+    fn from(value: Severity) -> String {
+        match value {
+            Severity::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Severity {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Severity {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Severity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Severity::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartAssessmentRunRequest {
     /// <p>You can specify the name for the assessment run. The name must be unique for the assessment template whose ARN is used to start the assessment run.</p>
@@ -1381,6 +3813,46 @@ pub struct StartAssessmentRunRequest {
     pub assessment_template_arn: String,
 }
 
+impl StartAssessmentRunRequest {
+    /// Returns a builder for `StartAssessmentRunRequest`, checking its required members when built.
+    pub fn builder() -> StartAssessmentRunRequestBuilder {
+        StartAssessmentRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartAssessmentRunRequest`](struct.StartAssessmentRunRequest.html).
+#[derive(Default, Debug)]
+pub struct StartAssessmentRunRequestBuilder {
+    assessment_run_name: Option<String>,
+    assessment_template_arn: Option<String>,
+}
+
+impl StartAssessmentRunRequestBuilder {
+    /// Sets the [`assessment_run_name`](struct.StartAssessmentRunRequest.html#structfield.assessment_run_name) member.
+    pub fn assessment_run_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_run_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`assessment_template_arn`](struct.StartAssessmentRunRequest.html#structfield.assessment_template_arn) member.
+    pub fn assessment_template_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_template_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartAssessmentRunRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartAssessmentRunRequest, ::rusoto_core::BuildError> {
+        Ok(StartAssessmentRunRequest {
+            assessment_run_name: self.assessment_run_name,
+            assessment_template_arn: self.assessment_template_arn.ok_or(
+                ::rusoto_core::BuildError::missing("assessment_template_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartAssessmentRunResponse {
@@ -1389,6 +3861,78 @@ pub struct StartAssessmentRunResponse {
     pub assessment_run_arn: String,
 }
 
+/// <p>An input option that can be set to either START_EVALUATION or SKIP_EVALUATION. START_EVALUATION (the default value), stops the AWS agent from collecting data and begins the results evaluation and the findings generation process. SKIP_EVALUATION cancels the assessment run immediately, after which no findings are generated.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StopAction {
+    /// <p><code>START_EVALUATION</code></p>
+    StartEvaluation,
+    /// <p><code>SKIP_EVALUATION</code></p>
+    SkipEvaluation,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StopAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StopAction::StartEvaluation => "START_EVALUATION",
+            StopAction::SkipEvaluation => "SKIP_EVALUATION",
+            StopAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StopAction {
+    fn from(value: &'a str) -> StopAction {
+        match value {
+            "START_EVALUATION" => StopAction::StartEvaluation,
+            "SKIP_EVALUATION" => StopAction::SkipEvaluation,
+            _ => StopAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StopAction {
+    fn from(value: String) -> StopAction {
+        StopAction::from(value.as_str())
+    }
+}
+
+impl From<StopAction> for String {
+    fn from(value: StopAction) -> String {
+        match value {
+            StopAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StopAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StopAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StopAction {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StopAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StopAction::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StopAssessmentRunRequest {
     /// <p>The ARN of the assessment run that you want to stop.</p>
@@ -1397,14 +3941,54 @@ pub struct StopAssessmentRunRequest {
     /// <p>An input option that can be set to either START_EVALUATION or SKIP_EVALUATION. START_EVALUATION (the default value), stops the AWS agent from collecting data and begins the results evaluation and the findings generation process. SKIP_EVALUATION cancels the assessment run immediately, after which no findings are generated.</p>
     #[serde(rename = "stopAction")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stop_action: Option<String>,
+    pub stop_action: Option<StopAction>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl StopAssessmentRunRequest {
+    /// Returns a builder for `StopAssessmentRunRequest`, checking its required members when built.
+    pub fn builder() -> StopAssessmentRunRequestBuilder {
+        StopAssessmentRunRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopAssessmentRunRequest`](struct.StopAssessmentRunRequest.html).
+#[derive(Default, Debug)]
+pub struct StopAssessmentRunRequestBuilder {
+    assessment_run_arn: Option<String>,
+    stop_action: Option<StopAction>,
+}
+
+impl StopAssessmentRunRequestBuilder {
+    /// Sets the [`assessment_run_arn`](struct.StopAssessmentRunRequest.html#structfield.assessment_run_arn) member.
+    pub fn assessment_run_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_run_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stop_action`](struct.StopAssessmentRunRequest.html#structfield.stop_action) member.
+    pub fn stop_action<T: Into<StopAction>>(mut self, value: T) -> Self {
+        self.stop_action = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopAssessmentRunRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopAssessmentRunRequest, ::rusoto_core::BuildError> {
+        Ok(StopAssessmentRunRequest {
+            assessment_run_arn: self
+                .assessment_run_arn
+                .ok_or(::rusoto_core::BuildError::missing("assessment_run_arn"))?,
+            stop_action: self.stop_action,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SubscribeToEventRequest {
     /// <p>The event for which you want to receive SNS notifications.</p>
     #[serde(rename = "event")]
-    pub event: String,
+    pub event: InspectorEvent,
     /// <p>The ARN of the assessment template that is used during the event for which you want to receive SNS notifications.</p>
     #[serde(rename = "resourceArn")]
     pub resource_arn: String,
@@ -1413,6 +3997,58 @@ pub struct SubscribeToEventRequest {
     pub topic_arn: String,
 }
 
+impl SubscribeToEventRequest {
+    /// Returns a builder for `SubscribeToEventRequest`, checking its required members when built.
+    pub fn builder() -> SubscribeToEventRequestBuilder {
+        SubscribeToEventRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SubscribeToEventRequest`](struct.SubscribeToEventRequest.html).
+#[derive(Default, Debug)]
+pub struct SubscribeToEventRequestBuilder {
+    event: Option<InspectorEvent>,
+    resource_arn: Option<String>,
+    topic_arn: Option<String>,
+}
+
+impl SubscribeToEventRequestBuilder {
+    /// Sets the [`event`](struct.SubscribeToEventRequest.html#structfield.event) member.
+    pub fn event<T: Into<InspectorEvent>>(mut self, value: T) -> Self {
+        self.event = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.SubscribeToEventRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_arn`](struct.SubscribeToEventRequest.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `SubscribeToEventRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SubscribeToEventRequest, ::rusoto_core::BuildError> {
+        Ok(SubscribeToEventRequest {
+            event: self
+                .event
+                .ok_or(::rusoto_core::BuildError::missing("event"))?,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            topic_arn: self
+                .topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("topic_arn"))?,
+        })
+    }
+}
+
 /// <p>This data type is used as a response element in the <a>ListEventSubscriptions</a> action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1461,19 +4097,21 @@ pub struct TelemetryMetadata {
 pub struct TimestampRange {
     /// <p>The minimum value of the timestamp range.</p>
     #[serde(rename = "beginDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub begin_date: Option<f64>,
+    pub begin_date: Option<EpochTimestamp>,
     /// <p>The maximum value of the timestamp range.</p>
     #[serde(rename = "endDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_date: Option<f64>,
+    pub end_date: Option<EpochTimestamp>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UnsubscribeFromEventRequest {
     /// <p>The event for which you want to stop receiving SNS notifications.</p>
     #[serde(rename = "event")]
-    pub event: String,
+    pub event: InspectorEvent,
     /// <p>The ARN of the assessment template that is used during the event for which you want to stop receiving SNS notifications.</p>
     #[serde(rename = "resourceArn")]
     pub resource_arn: String,
@@ -1482,6 +4120,58 @@ pub struct UnsubscribeFromEventRequest {
     pub topic_arn: String,
 }
 
+impl UnsubscribeFromEventRequest {
+    /// Returns a builder for `UnsubscribeFromEventRequest`, checking its required members when built.
+    pub fn builder() -> UnsubscribeFromEventRequestBuilder {
+        UnsubscribeFromEventRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UnsubscribeFromEventRequest`](struct.UnsubscribeFromEventRequest.html).
+#[derive(Default, Debug)]
+pub struct UnsubscribeFromEventRequestBuilder {
+    event: Option<InspectorEvent>,
+    resource_arn: Option<String>,
+    topic_arn: Option<String>,
+}
+
+impl UnsubscribeFromEventRequestBuilder {
+    /// Sets the [`event`](struct.UnsubscribeFromEventRequest.html#structfield.event) member.
+    pub fn event<T: Into<InspectorEvent>>(mut self, value: T) -> Self {
+        self.event = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.UnsubscribeFromEventRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_arn`](struct.UnsubscribeFromEventRequest.html#structfield.topic_arn) member.
+    pub fn topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UnsubscribeFromEventRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UnsubscribeFromEventRequest, ::rusoto_core::BuildError> {
+        Ok(UnsubscribeFromEventRequest {
+            event: self
+                .event
+                .ok_or(::rusoto_core::BuildError::missing("event"))?,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            topic_arn: self
+                .topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("topic_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateAssessmentTargetRequest {
     /// <p>The ARN of the assessment target that you want to update.</p>
@@ -1496,6 +4186,56 @@ pub struct UpdateAssessmentTargetRequest {
     pub resource_group_arn: Option<String>,
 }
 
+impl UpdateAssessmentTargetRequest {
+    /// Returns a builder for `UpdateAssessmentTargetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAssessmentTargetRequestBuilder {
+        UpdateAssessmentTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAssessmentTargetRequest`](struct.UpdateAssessmentTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAssessmentTargetRequestBuilder {
+    assessment_target_arn: Option<String>,
+    assessment_target_name: Option<String>,
+    resource_group_arn: Option<String>,
+}
+
+impl UpdateAssessmentTargetRequestBuilder {
+    /// Sets the [`assessment_target_arn`](struct.UpdateAssessmentTargetRequest.html#structfield.assessment_target_arn) member.
+    pub fn assessment_target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_target_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`assessment_target_name`](struct.UpdateAssessmentTargetRequest.html#structfield.assessment_target_name) member.
+    pub fn assessment_target_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.assessment_target_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_group_arn`](struct.UpdateAssessmentTargetRequest.html#structfield.resource_group_arn) member.
+    pub fn resource_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_group_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAssessmentTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAssessmentTargetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAssessmentTargetRequest {
+            assessment_target_arn: self
+                .assessment_target_arn
+                .ok_or(::rusoto_core::BuildError::missing("assessment_target_arn"))?,
+            assessment_target_name: self
+                .assessment_target_name
+                .ok_or(::rusoto_core::BuildError::missing("assessment_target_name"))?,
+            resource_group_arn: self.resource_group_arn,
+        })
+    }
+}
+
 /// Errors returned by AddAttributesToFindings
 #[derive(Debug, PartialEq)]
 pub enum AddAttributesToFindingsError {
@@ -3804,6 +6544,237 @@ pub trait Inspector {
         input: UpdateAssessmentTargetRequest,
     ) -> RusotoFuture<(), UpdateAssessmentTargetError>;
 }
+impl<T: ?Sized + Inspector> Inspector for ::std::sync::Arc<T> {
+    fn add_attributes_to_findings(
+        &self,
+        input: AddAttributesToFindingsRequest,
+    ) -> RusotoFuture<AddAttributesToFindingsResponse, AddAttributesToFindingsError> {
+        (**self).add_attributes_to_findings(input)
+    }
+    fn create_assessment_target(
+        &self,
+        input: CreateAssessmentTargetRequest,
+    ) -> RusotoFuture<CreateAssessmentTargetResponse, CreateAssessmentTargetError> {
+        (**self).create_assessment_target(input)
+    }
+    fn create_assessment_template(
+        &self,
+        input: CreateAssessmentTemplateRequest,
+    ) -> RusotoFuture<CreateAssessmentTemplateResponse, CreateAssessmentTemplateError> {
+        (**self).create_assessment_template(input)
+    }
+    fn create_exclusions_preview(
+        &self,
+        input: CreateExclusionsPreviewRequest,
+    ) -> RusotoFuture<CreateExclusionsPreviewResponse, CreateExclusionsPreviewError> {
+        (**self).create_exclusions_preview(input)
+    }
+    fn create_resource_group(
+        &self,
+        input: CreateResourceGroupRequest,
+    ) -> RusotoFuture<CreateResourceGroupResponse, CreateResourceGroupError> {
+        (**self).create_resource_group(input)
+    }
+    fn delete_assessment_run(
+        &self,
+        input: DeleteAssessmentRunRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentRunError> {
+        (**self).delete_assessment_run(input)
+    }
+    fn delete_assessment_target(
+        &self,
+        input: DeleteAssessmentTargetRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentTargetError> {
+        (**self).delete_assessment_target(input)
+    }
+    fn delete_assessment_template(
+        &self,
+        input: DeleteAssessmentTemplateRequest,
+    ) -> RusotoFuture<(), DeleteAssessmentTemplateError> {
+        (**self).delete_assessment_template(input)
+    }
+    fn describe_assessment_runs(
+        &self,
+        input: DescribeAssessmentRunsRequest,
+    ) -> RusotoFuture<DescribeAssessmentRunsResponse, DescribeAssessmentRunsError> {
+        (**self).describe_assessment_runs(input)
+    }
+    fn describe_assessment_targets(
+        &self,
+        input: DescribeAssessmentTargetsRequest,
+    ) -> RusotoFuture<DescribeAssessmentTargetsResponse, DescribeAssessmentTargetsError> {
+        (**self).describe_assessment_targets(input)
+    }
+    fn describe_assessment_templates(
+        &self,
+        input: DescribeAssessmentTemplatesRequest,
+    ) -> RusotoFuture<DescribeAssessmentTemplatesResponse, DescribeAssessmentTemplatesError> {
+        (**self).describe_assessment_templates(input)
+    }
+    fn describe_cross_account_access_role(
+        &self,
+    ) -> RusotoFuture<DescribeCrossAccountAccessRoleResponse, DescribeCrossAccountAccessRoleError>
+    {
+        (**self).describe_cross_account_access_role()
+    }
+    fn describe_exclusions(
+        &self,
+        input: DescribeExclusionsRequest,
+    ) -> RusotoFuture<DescribeExclusionsResponse, DescribeExclusionsError> {
+        (**self).describe_exclusions(input)
+    }
+    fn describe_findings(
+        &self,
+        input: DescribeFindingsRequest,
+    ) -> RusotoFuture<DescribeFindingsResponse, DescribeFindingsError> {
+        (**self).describe_findings(input)
+    }
+    fn describe_resource_groups(
+        &self,
+        input: DescribeResourceGroupsRequest,
+    ) -> RusotoFuture<DescribeResourceGroupsResponse, DescribeResourceGroupsError> {
+        (**self).describe_resource_groups(input)
+    }
+    fn describe_rules_packages(
+        &self,
+        input: DescribeRulesPackagesRequest,
+    ) -> RusotoFuture<DescribeRulesPackagesResponse, DescribeRulesPackagesError> {
+        (**self).describe_rules_packages(input)
+    }
+    fn get_assessment_report(
+        &self,
+        input: GetAssessmentReportRequest,
+    ) -> RusotoFuture<GetAssessmentReportResponse, GetAssessmentReportError> {
+        (**self).get_assessment_report(input)
+    }
+    fn get_exclusions_preview(
+        &self,
+        input: GetExclusionsPreviewRequest,
+    ) -> RusotoFuture<GetExclusionsPreviewResponse, GetExclusionsPreviewError> {
+        (**self).get_exclusions_preview(input)
+    }
+    fn get_telemetry_metadata(
+        &self,
+        input: GetTelemetryMetadataRequest,
+    ) -> RusotoFuture<GetTelemetryMetadataResponse, GetTelemetryMetadataError> {
+        (**self).get_telemetry_metadata(input)
+    }
+    fn list_assessment_run_agents(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> RusotoFuture<ListAssessmentRunAgentsResponse, ListAssessmentRunAgentsError> {
+        (**self).list_assessment_run_agents(input)
+    }
+    fn list_assessment_runs(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> RusotoFuture<ListAssessmentRunsResponse, ListAssessmentRunsError> {
+        (**self).list_assessment_runs(input)
+    }
+    fn list_assessment_targets(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> RusotoFuture<ListAssessmentTargetsResponse, ListAssessmentTargetsError> {
+        (**self).list_assessment_targets(input)
+    }
+    fn list_assessment_templates(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> RusotoFuture<ListAssessmentTemplatesResponse, ListAssessmentTemplatesError> {
+        (**self).list_assessment_templates(input)
+    }
+    fn list_event_subscriptions(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> RusotoFuture<ListEventSubscriptionsResponse, ListEventSubscriptionsError> {
+        (**self).list_event_subscriptions(input)
+    }
+    fn list_exclusions(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> RusotoFuture<ListExclusionsResponse, ListExclusionsError> {
+        (**self).list_exclusions(input)
+    }
+    fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> RusotoFuture<ListFindingsResponse, ListFindingsError> {
+        (**self).list_findings(input)
+    }
+    fn list_rules_packages(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> RusotoFuture<ListRulesPackagesResponse, ListRulesPackagesError> {
+        (**self).list_rules_packages(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn preview_agents(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> RusotoFuture<PreviewAgentsResponse, PreviewAgentsError> {
+        (**self).preview_agents(input)
+    }
+    fn register_cross_account_access_role(
+        &self,
+        input: RegisterCrossAccountAccessRoleRequest,
+    ) -> RusotoFuture<(), RegisterCrossAccountAccessRoleError> {
+        (**self).register_cross_account_access_role(input)
+    }
+    fn remove_attributes_from_findings(
+        &self,
+        input: RemoveAttributesFromFindingsRequest,
+    ) -> RusotoFuture<RemoveAttributesFromFindingsResponse, RemoveAttributesFromFindingsError> {
+        (**self).remove_attributes_from_findings(input)
+    }
+    fn set_tags_for_resource(
+        &self,
+        input: SetTagsForResourceRequest,
+    ) -> RusotoFuture<(), SetTagsForResourceError> {
+        (**self).set_tags_for_resource(input)
+    }
+    fn start_assessment_run(
+        &self,
+        input: StartAssessmentRunRequest,
+    ) -> RusotoFuture<StartAssessmentRunResponse, StartAssessmentRunError> {
+        (**self).start_assessment_run(input)
+    }
+    fn stop_assessment_run(
+        &self,
+        input: StopAssessmentRunRequest,
+    ) -> RusotoFuture<(), StopAssessmentRunError> {
+        (**self).stop_assessment_run(input)
+    }
+    fn subscribe_to_event(
+        &self,
+        input: SubscribeToEventRequest,
+    ) -> RusotoFuture<(), SubscribeToEventError> {
+        (**self).subscribe_to_event(input)
+    }
+    fn unsubscribe_from_event(
+        &self,
+        input: UnsubscribeFromEventRequest,
+    ) -> RusotoFuture<(), UnsubscribeFromEventError> {
+        (**self).unsubscribe_from_event(input)
+    }
+    fn update_assessment_target(
+        &self,
+        input: UpdateAssessmentTargetRequest,
+    ) -> RusotoFuture<(), UpdateAssessmentTargetError> {
+        (**self).update_assessment_target(input)
+    }
+}
+/// The error codes Amazon Inspector retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Inspector API.
 #[derive(Clone)]
 pub struct InspectorClient {
@@ -3819,6 +6790,27 @@ impl InspectorClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_INSPECTOR`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> InspectorClient {
+        Self::new(region::Region::from_env_for_service("INSPECTOR"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `inspector`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> InspectorClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("inspector", "INSPECTOR"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3837,7 +6829,54 @@ impl InspectorClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> InspectorClient {
-        InspectorClient { client, region }
+        InspectorClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "inspector")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "inspector",
+            "inspector",
+            "INSPECTOR",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Inspector` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Inspector + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for InspectorClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Inspector"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "inspector"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> InspectorClient {
+        Self::new_with_client(client, region)
     }
 }
 
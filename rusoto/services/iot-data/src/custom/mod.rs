@@ -1 +1,3 @@
-
+/// Publishing, subscribing and device shadows over MQTT
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
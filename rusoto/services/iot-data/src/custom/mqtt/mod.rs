@@ -0,0 +1,970 @@
+//! An MQTT client for AWS IoT Core.
+//!
+//! `MqttClient` connects to the account's data endpoint, either over a WebSocket presigned with
+//! SigV4 credentials, or with mutual TLS authenticated by a device certificate. It publishes and
+//! subscribes with QoS 0 or 1, and gets, updates and deletes device shadows over their reserved
+//! topics, as devices without HTTPS access to the data plane API do.
+//!
+//! The connection is driven by a task spawned on the tokio runtime the connect future runs on,
+//! and closes on `disconnect`, or once every clone of the client and every subscription is
+//! dropped. The client doesn't reconnect: once the connection is lost, its futures fail with
+//! `MqttError::Disconnected` and its subscriptions end.
+//!
+//! This module is only available with the `mqtt` feature enabled.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use futures::{Future, Stream};
+//! use rusoto_core::{DefaultCredentialsProvider, Region};
+//! use rusoto_iot_data::mqtt::{MqttClient, MqttOptions, QoS};
+//!
+//! let endpoint = "a1b2c3d4e5f6g7-ats.iot.us-east-1.amazonaws.com";
+//! let client = MqttClient::connect_websocket(
+//!     endpoint,
+//!     Region::UsEast1,
+//!     DefaultCredentialsProvider::new().unwrap(),
+//!     MqttOptions::new("thermostat"),
+//! )
+//! .sync()
+//! .unwrap();
+//! let readings = client.subscribe("sensors/+/temperature", QoS::AtLeastOnce).sync().unwrap();
+//! client
+//!     .publish("sensors/kitchen/temperature", "21.5", QoS::AtLeastOnce)
+//!     .sync()
+//!     .unwrap();
+//! for message in readings.wait().take(1) {
+//!     let message = message.unwrap();
+//!     println!("{}: {}", message.topic, String::from_utf8_lossy(&message.payload));
+//! }
+//! ```
+
+mod packet;
+mod topic;
+mod transport;
+
+pub use self::topic::{matches, ShadowTopics};
+
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use futures::sync::{mpsc, oneshot};
+use futures::{future, try_ready, Async, AsyncSink, Future, Poll, Sink, Stream};
+use rusoto_core::{ProvideAwsCredentials, Region, RusotoError, RusotoFuture};
+use serde_json::{json, Value};
+use tokio::executor::{DefaultExecutor, Executor};
+use tokio::timer::Interval;
+use uuid::Uuid;
+
+use self::packet::{Packet, Publish};
+use self::transport::{BoxTransport, Transport};
+
+/// The future returned by the operations of `MqttClient`.
+pub type MqttFuture<T> = RusotoFuture<T, MqttError>;
+
+/// The quality of service of a message. AWS IoT Core doesn't support QoS 2.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QoS {
+    /// The message is sent once, and lost if the connection drops.
+    AtMostOnce = 0,
+    /// The message is resent until it's acknowledged, and may be received more than once.
+    AtLeastOnce = 1,
+}
+
+/// The options of a connection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MqttOptions {
+    /// The client ID, which the IoT policy of the credentials must allow connecting with.
+    /// Connecting with the ID of a connected client disconnects it.
+    pub client_id: String,
+    /// How often to ping the server, which drops the connection after 1.5 times as long
+    /// without a packet from the client. AWS IoT Core requires 30 seconds to 20 minutes.
+    pub keep_alive: Duration,
+    /// Whether the server discards the session of an earlier connection with the same ID,
+    /// rather than resuming its subscriptions and delivering the QoS 1 messages it missed.
+    pub clean_session: bool,
+}
+
+impl MqttOptions {
+    /// Options to connect with `client_id`, a keep alive of 60 seconds and a clean session.
+    pub fn new(client_id: &str) -> MqttOptions {
+        MqttOptions {
+            client_id: client_id.to_owned(),
+            keep_alive: Duration::from_secs(60),
+            clean_session: true,
+        }
+    }
+}
+
+/// A message published to a topic the client subscribed to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MqttMessage {
+    /// The topic the message was published to
+    pub topic: String,
+    pub payload: Bytes,
+    /// The quality of service the message was delivered with
+    pub qos: QoS,
+    /// Whether the message was retained by the server, and delivered on subscribing
+    pub retain: bool,
+}
+
+/// The error document of a shadow request the shadow rejected.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ShadowError {
+    /// The HTTP status code of the error, e.g. 404 if the shadow doesn't exist
+    pub code: u16,
+    pub message: String,
+}
+
+/// Errors returned by `MqttClient`.
+#[derive(Debug, PartialEq)]
+pub enum MqttError {
+    /// The server refused the connection, with the CONNACK return code, e.g. 5 if the
+    /// credentials aren't authorized to connect.
+    ConnectionRefused(u8),
+    /// The server refused to subscribe to the topic filter, e.g. if the IoT policy doesn't
+    /// allow it.
+    SubscriptionRefused(String),
+    /// The shadow rejected the request.
+    ShadowRejected(ShadowError),
+    /// The server sent something the client doesn't understand.
+    Protocol(String),
+    /// Reading from or writing to the connection failed.
+    Transport(String),
+    /// The connection is closed.
+    Disconnected,
+}
+
+impl fmt::Display for MqttError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MqttError::ConnectionRefused(return_code) => {
+                write!(f, "{}: return code {}", self.description(), return_code)
+            }
+            MqttError::SubscriptionRefused(ref filter) => {
+                write!(f, "{}: {}", self.description(), filter)
+            }
+            MqttError::ShadowRejected(ref error) => write!(
+                f,
+                "{}: {} ({})",
+                self.description(),
+                error.message,
+                error.code
+            ),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for MqttError {
+    fn description(&self) -> &str {
+        match *self {
+            MqttError::ConnectionRefused(_) => "Connection refused",
+            MqttError::SubscriptionRefused(_) => "Subscription refused",
+            MqttError::ShadowRejected(_) => "Shadow request rejected",
+            MqttError::Protocol(ref cause) => cause,
+            MqttError::Transport(ref cause) => cause,
+            MqttError::Disconnected => "Disconnected",
+        }
+    }
+}
+
+impl From<io::Error> for MqttError {
+    fn from(error: io::Error) -> MqttError {
+        MqttError::Transport(error.to_string())
+    }
+}
+
+/// A client connected to AWS IoT Core.
+///
+/// Clones share the connection.
+#[derive(Clone)]
+pub struct MqttClient {
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl MqttClient {
+    /// Connects to `endpoint`, the account's data endpoint as returned by `DescribeEndpoint`
+    /// with the `iot:Data-ATS` endpoint type, over a WebSocket presigned with credentials from
+    /// `credentials`.
+    pub fn connect_websocket<P>(
+        endpoint: &str,
+        region: Region,
+        credentials: P,
+        options: MqttOptions,
+    ) -> MqttFuture<MqttClient>
+    where
+        P: ProvideAwsCredentials,
+        P::Future: Send,
+    {
+        let endpoint = endpoint.to_owned();
+        let future = credentials
+            .credentials()
+            .map_err(RusotoError::from)
+            .and_then(move |credentials| transport::websocket(&endpoint, &region, &credentials))
+            .and_then(move |transport| connect(transport, options));
+        RusotoFuture::from_future(future)
+    }
+
+    /// Connects to port 8883 of `endpoint`, the account's data endpoint, authenticated with the
+    /// certificate of `identity`.
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    pub fn connect_mutual_tls(
+        endpoint: &str,
+        identity: &rusoto_core::credential::IotIdentity,
+        options: MqttOptions,
+    ) -> MqttFuture<MqttClient> {
+        let future = transport::mutual_tls(endpoint, identity)
+            .and_then(move |transport| connect(transport, options));
+        RusotoFuture::from_future(future)
+    }
+
+    /// Publishes `payload` to `topic`.
+    ///
+    /// With QoS 0, the future resolves once the message is written to the connection, and with
+    /// QoS 1 once the server acknowledges it.
+    pub fn publish<B>(&self, topic: &str, payload: B, qos: QoS) -> MqttFuture<()>
+    where
+        B: Into<Bytes>,
+    {
+        let publish = Publish {
+            topic: topic.to_owned(),
+            payload: payload.into(),
+            qos,
+            retain: false,
+            dup: false,
+            packet_id: None,
+        };
+        self.request(|done| Command::Publish { publish, done })
+    }
+
+    /// Subscribes to `filter`, which may contain the `+` and `#` wildcards.
+    ///
+    /// The future resolves once the server acknowledges the subscription. Dropping the
+    /// subscription unsubscribes from `filter`, unless another subscription of the client uses
+    /// it too.
+    pub fn subscribe(&self, filter: &str, qos: QoS) -> MqttFuture<Subscription> {
+        self.subscribe_all(vec![filter.to_owned()], qos)
+    }
+
+    /// Subscribes to every filter of `filters` with one subscription.
+    fn subscribe_all(&self, filters: Vec<String>, qos: QoS) -> MqttFuture<Subscription> {
+        let (sender, messages) = mpsc::unbounded();
+        let id = Uuid::new_v4();
+        let commands = self.commands.clone();
+        let future = self
+            .request(|done| Command::Subscribe {
+                id,
+                filters,
+                qos,
+                sender,
+                done,
+            })
+            .map(move |()| Subscription {
+                id,
+                messages,
+                commands,
+            });
+        RusotoFuture::from_future(future)
+    }
+
+    /// Disconnects from the server, once the packets queued before are written.
+    ///
+    /// Subscriptions end, and later requests of the client or its clones fail with
+    /// `MqttError::Disconnected`.
+    pub fn disconnect(&self) -> MqttFuture<()> {
+        self.request(|done| Command::Disconnect { done })
+    }
+
+    /// Gets the shadow document of the thing `thing_name`.
+    pub fn get_shadow(&self, thing_name: &str) -> MqttFuture<Value> {
+        let topics = ShadowTopics::new(thing_name);
+        self.shadow_request(
+            topics.get(),
+            topics.get_accepted(),
+            topics.get_rejected(),
+            json!({}),
+        )
+    }
+
+    /// Updates the shadow of the thing `thing_name` with `state`, e.g.
+    /// `{"desired": {"power": "on"}}`, returning the accepted update.
+    pub fn update_shadow(&self, thing_name: &str, state: Value) -> MqttFuture<Value> {
+        let topics = ShadowTopics::new(thing_name);
+        self.shadow_request(
+            topics.update(),
+            topics.update_accepted(),
+            topics.update_rejected(),
+            json!({ "state": state }),
+        )
+    }
+
+    /// Deletes the shadow of the thing `thing_name`.
+    pub fn delete_shadow(&self, thing_name: &str) -> MqttFuture<()> {
+        let topics = ShadowTopics::new(thing_name);
+        let future = self
+            .shadow_request(
+                topics.delete(),
+                topics.delete_accepted(),
+                topics.delete_rejected(),
+                json!({}),
+            )
+            .map(|_| ());
+        RusotoFuture::from_future(future)
+    }
+
+    /// Subscribes to the differences between the desired and reported state of the shadow of
+    /// the thing `thing_name`, published after every update changing either.
+    pub fn shadow_deltas(&self, thing_name: &str) -> MqttFuture<Subscription> {
+        self.subscribe(
+            &ShadowTopics::new(thing_name).update_delta(),
+            QoS::AtLeastOnce,
+        )
+    }
+
+    /// Publishes `request` to `topic` once subscribed to the responses of the shadow, and
+    /// resolves with the first response with the request's client token.
+    fn shadow_request(
+        &self,
+        topic: String,
+        accepted: String,
+        rejected: String,
+        mut request: Value,
+    ) -> MqttFuture<Value> {
+        let client_token = Uuid::new_v4().to_simple().to_string();
+        request["clientToken"] = Value::String(client_token.clone());
+        let client = self.clone();
+        let future = self
+            .subscribe_all(vec![accepted.clone(), rejected], QoS::AtLeastOnce)
+            .and_then(move |subscription| {
+                client
+                    .publish(&topic, request.to_string(), QoS::AtLeastOnce)
+                    .map(move |()| subscription)
+            })
+            .and_then(move |subscription| {
+                subscription
+                    .filter_map(move |message| {
+                        let response: Value = serde_json::from_slice(&message.payload).ok()?;
+                        if response["clientToken"] != client_token.as_str() {
+                            return None;
+                        }
+                        Some(shadow_response(message.topic == accepted, response))
+                    })
+                    .into_future()
+                    .map_err(|(error, _)| error)
+                    .and_then(|(response, _)| response.unwrap_or(Err(MqttError::Disconnected)))
+                    .map_err(RusotoError::Service)
+            });
+        RusotoFuture::from_future(future)
+    }
+
+    /// Queues a command, and resolves once the connection reports it done.
+    fn request<F>(&self, command: F) -> MqttFuture<()>
+    where
+        F: FnOnce(oneshot::Sender<Result<(), MqttError>>) -> Command,
+    {
+        let (done, result) = oneshot::channel();
+        if self.commands.unbounded_send(command(done)).is_err() {
+            return RusotoFuture::from_future(future::err(RusotoError::Service(
+                MqttError::Disconnected,
+            )));
+        }
+        let future = result.then(|result| match result {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(error)) => Err(RusotoError::Service(error)),
+            Err(_canceled) => Err(RusotoError::Service(MqttError::Disconnected)),
+        });
+        RusotoFuture::from_future(future)
+    }
+}
+
+fn shadow_response(accepted: bool, response: Value) -> Result<Value, MqttError> {
+    if accepted {
+        return Ok(response);
+    }
+    match serde_json::from_value(response) {
+        Ok(error) => Err(MqttError::ShadowRejected(error)),
+        Err(error) => Err(MqttError::Protocol(format!(
+            "Invalid shadow error: {}",
+            error
+        ))),
+    }
+}
+
+/// The messages published to the topic filters of a subscription.
+///
+/// The stream ends when the connection closes. Dropping it unsubscribes.
+pub struct Subscription {
+    id: Uuid,
+    messages: mpsc::UnboundedReceiver<MqttMessage>,
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl Stream for Subscription {
+    type Item = MqttMessage;
+    type Error = MqttError;
+
+    fn poll(&mut self) -> Poll<Option<MqttMessage>, MqttError> {
+        Ok(self.messages.poll().unwrap_or(Async::Ready(None)))
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        let _ = self
+            .commands
+            .unbounded_send(Command::Unsubscribe { id: self.id });
+    }
+}
+
+enum Command {
+    Publish {
+        publish: Publish,
+        done: oneshot::Sender<Result<(), MqttError>>,
+    },
+    Subscribe {
+        id: Uuid,
+        filters: Vec<String>,
+        qos: QoS,
+        sender: mpsc::UnboundedSender<MqttMessage>,
+        done: oneshot::Sender<Result<(), MqttError>>,
+    },
+    Unsubscribe {
+        id: Uuid,
+    },
+    Disconnect {
+        done: oneshot::Sender<Result<(), MqttError>>,
+    },
+}
+
+/// Sends CONNECT and waits for the CONNACK, then spawns the task driving the connection.
+fn connect(
+    transport: BoxTransport,
+    options: MqttOptions,
+) -> impl Future<Item = MqttClient, Error = RusotoError<MqttError>> {
+    let keep_alive = options
+        .keep_alive
+        .as_secs()
+        .min(u64::from(u16::max_value())) as u16;
+    transport
+        .send(Packet::Connect {
+            client_id: options.client_id,
+            keep_alive,
+            clean_session: options.clean_session,
+        })
+        .and_then(|transport| transport.into_future().map_err(|(error, _)| error))
+        .and_then(move |(packet, transport)| {
+            match packet {
+                Some(Packet::ConnAck { return_code: 0, .. }) => {}
+                Some(Packet::ConnAck { return_code, .. }) => {
+                    return Err(MqttError::ConnectionRefused(return_code))
+                }
+                Some(packet) => {
+                    return Err(MqttError::Protocol(format!(
+                        "Expected CONNACK, received {:?}",
+                        packet
+                    )))
+                }
+                None => return Err(MqttError::Disconnected),
+            }
+            let (commands, receiver) = mpsc::unbounded();
+            let keep_alive = match keep_alive {
+                0 => None,
+                seconds => {
+                    let period = Duration::from_secs(u64::from(seconds));
+                    Some(Interval::new(Instant::now() + period, period))
+                }
+            };
+            let connection = Connection::new(transport, receiver, keep_alive);
+            DefaultExecutor::current()
+                .spawn(Box::new(connection))
+                .map_err(|error| MqttError::Transport(error.to_string()))?;
+            Ok(MqttClient { commands })
+        })
+        .map_err(RusotoError::Service)
+}
+
+struct Route {
+    id: Uuid,
+    filters: Vec<String>,
+    sender: mpsc::UnboundedSender<MqttMessage>,
+}
+
+/// The task driving a connection: it writes the packets of the client's commands, pings the
+/// server, acknowledges messages and routes them to subscriptions.
+struct Connection<T> {
+    transport: T,
+    commands: mpsc::UnboundedReceiver<Command>,
+    keep_alive: Option<Interval>,
+    awaiting_ping_response: bool,
+    next_packet_id: u16,
+    outgoing: VecDeque<(Packet, Option<oneshot::Sender<Result<(), MqttError>>>)>,
+    /// QoS 1 messages waiting for their PUBACK
+    unacknowledged: HashMap<u16, oneshot::Sender<Result<(), MqttError>>>,
+    /// Subscriptions waiting for their SUBACK
+    subscribing: HashMap<u16, (Route, oneshot::Sender<Result<(), MqttError>>)>,
+    routes: Vec<Route>,
+    disconnecting: Option<oneshot::Sender<Result<(), MqttError>>>,
+}
+
+impl<T: Transport> Connection<T> {
+    fn new(
+        transport: T,
+        commands: mpsc::UnboundedReceiver<Command>,
+        keep_alive: Option<Interval>,
+    ) -> Connection<T> {
+        Connection {
+            transport,
+            commands,
+            keep_alive,
+            awaiting_ping_response: false,
+            next_packet_id: 1,
+            outgoing: VecDeque::new(),
+            unacknowledged: HashMap::new(),
+            subscribing: HashMap::new(),
+            routes: Vec::new(),
+            disconnecting: None,
+        }
+    }
+
+    fn packet_id(&mut self) -> u16 {
+        loop {
+            let packet_id = self.next_packet_id;
+            self.next_packet_id = self.next_packet_id.checked_add(1).unwrap_or(1);
+            if !self.unacknowledged.contains_key(&packet_id)
+                && !self.subscribing.contains_key(&packet_id)
+            {
+                return packet_id;
+            }
+        }
+    }
+
+    fn handle_command(&mut self, command: Command) {
+        match command {
+            Command::Publish { mut publish, done } => {
+                if publish.qos == QoS::AtMostOnce {
+                    self.outgoing
+                        .push_back((Packet::Publish(publish), Some(done)));
+                } else {
+                    let packet_id = self.packet_id();
+                    publish.packet_id = Some(packet_id);
+                    self.unacknowledged.insert(packet_id, done);
+                    self.outgoing.push_back((Packet::Publish(publish), None));
+                }
+            }
+            Command::Subscribe {
+                id,
+                filters,
+                qos,
+                sender,
+                done,
+            } => {
+                let packet_id = self.packet_id();
+                let packet = Packet::Subscribe {
+                    packet_id,
+                    filters: filters.iter().map(|filter| (filter.clone(), qos)).collect(),
+                };
+                let route = Route {
+                    id,
+                    filters,
+                    sender,
+                };
+                self.subscribing.insert(packet_id, (route, done));
+                self.outgoing.push_back((packet, None));
+            }
+            Command::Unsubscribe { id } => {
+                let route = match self.routes.iter().position(|route| route.id == id) {
+                    Some(index) => self.routes.remove(index),
+                    None => return,
+                };
+                let filters: Vec<String> = route
+                    .filters
+                    .into_iter()
+                    .filter(|filter| {
+                        !self
+                            .routes
+                            .iter()
+                            .any(|route| route.filters.contains(filter))
+                    })
+                    .collect();
+                if !filters.is_empty() {
+                    let packet_id = self.packet_id();
+                    self.outgoing
+                        .push_back((Packet::Unsubscribe { packet_id, filters }, None));
+                }
+            }
+            Command::Disconnect { done } => {
+                self.outgoing.push_back((Packet::Disconnect, None));
+                self.disconnecting = Some(done);
+            }
+        }
+    }
+
+    fn handle_packet(&mut self, packet: Packet) -> Result<(), MqttError> {
+        match packet {
+            Packet::Publish(publish) => {
+                if let Some(packet_id) = publish.packet_id {
+                    self.outgoing.push_back((Packet::PubAck(packet_id), None));
+                }
+                let message = MqttMessage {
+                    topic: publish.topic,
+                    payload: publish.payload,
+                    qos: publish.qos,
+                    retain: publish.retain,
+                };
+                for route in &self.routes {
+                    if route
+                        .filters
+                        .iter()
+                        .any(|filter| matches(filter, &message.topic))
+                    {
+                        let _ = route.sender.unbounded_send(message.clone());
+                    }
+                }
+            }
+            Packet::PubAck(packet_id) => {
+                if let Some(done) = self.unacknowledged.remove(&packet_id) {
+                    let _ = done.send(Ok(()));
+                }
+            }
+            Packet::SubAck {
+                packet_id,
+                return_codes,
+            } => {
+                if let Some((route, done)) = self.subscribing.remove(&packet_id) {
+                    let refused = route
+                        .filters
+                        .iter()
+                        .zip(return_codes)
+                        .find(|&(_, return_code)| return_code == 0x80);
+                    match refused {
+                        Some((filter, _)) => {
+                            let _ = done.send(Err(MqttError::SubscriptionRefused(filter.clone())));
+                        }
+                        None => {
+                            self.routes.push(route);
+                            let _ = done.send(Ok(()));
+                        }
+                    }
+                }
+            }
+            Packet::PingResp => self.awaiting_ping_response = false,
+            Packet::UnsubAck(_) => {}
+            packet => {
+                return Err(MqttError::Protocol(format!(
+                    "Unexpected packet {:?}",
+                    packet
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    fn poll_connection(&mut self) -> Poll<(), MqttError> {
+        while self.disconnecting.is_none() {
+            match self.commands.poll() {
+                Ok(Async::Ready(Some(command))) => self.handle_command(command),
+                // every clone of the client and every subscription is dropped
+                Ok(Async::Ready(None)) | Err(()) => {
+                    let (done, _) = oneshot::channel();
+                    self.handle_command(Command::Disconnect { done });
+                }
+                Ok(Async::NotReady) => break,
+            }
+        }
+
+        if let Some(ref mut keep_alive) = self.keep_alive {
+            while let Async::Ready(Some(_)) = keep_alive
+                .poll()
+                .map_err(|error| MqttError::Transport(error.to_string()))?
+            {
+                if self.awaiting_ping_response {
+                    return Err(MqttError::Transport("No response to ping".to_owned()));
+                }
+                self.awaiting_ping_response = true;
+                self.outgoing.push_back((Packet::PingReq, None));
+            }
+        }
+
+        while let Async::Ready(packet) = self.transport.poll()? {
+            match packet {
+                Some(packet) => self.handle_packet(packet)?,
+                None => return Err(MqttError::Disconnected),
+            }
+        }
+
+        while let Some((packet, done)) = self.outgoing.pop_front() {
+            match self.transport.start_send(packet)? {
+                AsyncSink::Ready => {
+                    if let Some(done) = done {
+                        let _ = done.send(Ok(()));
+                    }
+                }
+                AsyncSink::NotReady(packet) => {
+                    self.outgoing.push_front((packet, done));
+                    break;
+                }
+            }
+        }
+        let flushed = self.transport.poll_complete()?.is_ready();
+
+        if self.disconnecting.is_some() && self.outgoing.is_empty() && flushed {
+            try_ready!(self.transport.close());
+            if let Some(done) = self.disconnecting.take() {
+                let _ = done.send(Ok(()));
+            }
+            return Ok(Async::Ready(()));
+        }
+        Ok(Async::NotReady)
+    }
+}
+
+impl<T: Transport> Future for Connection<T> {
+    type Item = ();
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<(), ()> {
+        // once the connection fails, dropping the pending requests and the routes fails the
+        // client's futures with `Disconnected` and ends its subscriptions
+        self.poll_connection().or(Ok(Async::Ready(())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A transport answering the packets the connection sends with `responses`.
+    struct MockTransport {
+        sent: Vec<Packet>,
+        responses: Box<dyn FnMut(&Packet) -> Vec<Packet> + Send>,
+        incoming: VecDeque<Packet>,
+    }
+
+    impl Stream for MockTransport {
+        type Item = Packet;
+        type Error = MqttError;
+
+        fn poll(&mut self) -> Poll<Option<Packet>, MqttError> {
+            Ok(match self.incoming.pop_front() {
+                Some(packet) => Async::Ready(Some(packet)),
+                None => Async::NotReady,
+            })
+        }
+    }
+
+    impl Sink for MockTransport {
+        type SinkItem = Packet;
+        type SinkError = MqttError;
+
+        fn start_send(&mut self, packet: Packet) -> Result<AsyncSink<Packet>, MqttError> {
+            self.incoming.extend((self.responses)(&packet));
+            self.sent.push(packet);
+            futures::task::current().notify();
+            Ok(AsyncSink::Ready)
+        }
+
+        fn poll_complete(&mut self) -> Poll<(), MqttError> {
+            Ok(Async::Ready(()))
+        }
+    }
+
+    fn mock_connection(
+        responses: Box<dyn FnMut(&Packet) -> Vec<Packet> + Send>,
+    ) -> (MqttClient, Connection<MockTransport>) {
+        let transport = MockTransport {
+            sent: Vec::new(),
+            responses,
+            incoming: VecDeque::new(),
+        };
+        let (commands, receiver) = mpsc::unbounded();
+        (
+            MqttClient { commands },
+            Connection::new(transport, receiver, None),
+        )
+    }
+
+    fn suback(packet: &Packet) -> Vec<Packet> {
+        match *packet {
+            Packet::Subscribe {
+                packet_id,
+                ref filters,
+            } => vec![Packet::SubAck {
+                packet_id,
+                return_codes: filters.iter().map(|&(_, qos)| qos as u8).collect(),
+            }],
+            Packet::Publish(Publish {
+                packet_id: Some(packet_id),
+                ..
+            }) => vec![Packet::PubAck(packet_id)],
+            _ => vec![],
+        }
+    }
+
+    /// Polls `connection` until `future` resolves.
+    fn run<F: Future>(
+        connection: &mut Connection<MockTransport>,
+        mut future: F,
+    ) -> Result<F::Item, F::Error> {
+        future::lazy(|| {
+            for _ in 0..100 {
+                connection.poll().unwrap();
+                match future.poll() {
+                    Ok(Async::NotReady) => {}
+                    Ok(Async::Ready(item)) => return Ok::<_, ()>(Ok(item)),
+                    Err(error) => return Ok(Err(error)),
+                }
+            }
+            panic!("future didn't resolve")
+        })
+        .wait()
+        .unwrap()
+    }
+
+    fn publish(topic: &str, payload: &str, packet_id: Option<u16>) -> Packet {
+        Packet::Publish(Publish {
+            topic: topic.to_owned(),
+            payload: Bytes::from(payload),
+            qos: if packet_id.is_some() {
+                QoS::AtLeastOnce
+            } else {
+                QoS::AtMostOnce
+            },
+            retain: false,
+            dup: false,
+            packet_id,
+        })
+    }
+
+    #[test]
+    fn routes_messages_to_matching_subscriptions() {
+        let (client, mut connection) = mock_connection(Box::new(suback));
+        let subscription = run(
+            &mut connection,
+            client.subscribe("sensors/+/temperature", QoS::AtLeastOnce),
+        )
+        .unwrap();
+        connection.transport.incoming.push_back(publish(
+            "sensors/kitchen/temperature",
+            "21.5",
+            Some(9),
+        ));
+        connection
+            .transport
+            .incoming
+            .push_back(publish("sensors/kitchen/humidity", "40", None));
+        let (message, _) = run(&mut connection, subscription.into_future())
+            .ok()
+            .unwrap();
+        let message = message.unwrap();
+        assert_eq!(message.topic, "sensors/kitchen/temperature");
+        assert_eq!(&message.payload[..], b"21.5");
+        assert!(connection.transport.sent.contains(&Packet::PubAck(9)));
+    }
+
+    #[test]
+    fn resolves_qos_1_publishes_on_puback() {
+        let (client, mut connection) = mock_connection(Box::new(suback));
+        run(
+            &mut connection,
+            client.publish("sensors/kitchen/temperature", "21.5", QoS::AtLeastOnce),
+        )
+        .unwrap();
+        assert_eq!(
+            connection.transport.sent,
+            vec![publish("sensors/kitchen/temperature", "21.5", Some(1))]
+        );
+    }
+
+    #[test]
+    fn refused_subscriptions_fail() {
+        let (client, mut connection) = mock_connection(Box::new(|packet: &Packet| match *packet {
+            Packet::Subscribe { packet_id, .. } => vec![Packet::SubAck {
+                packet_id,
+                return_codes: vec![0x80],
+            }],
+            _ => vec![],
+        }));
+        let result = run(
+            &mut connection,
+            client.subscribe("forbidden", QoS::AtMostOnce),
+        );
+        assert_eq!(
+            result.err(),
+            Some(RusotoError::Service(MqttError::SubscriptionRefused(
+                "forbidden".to_owned()
+            )))
+        );
+    }
+
+    #[test]
+    fn unsubscribes_when_the_last_subscription_is_dropped() {
+        let (client, mut connection) = mock_connection(Box::new(suback));
+        let first = run(&mut connection, client.subscribe("a/#", QoS::AtMostOnce)).unwrap();
+        let second = run(&mut connection, client.subscribe("a/#", QoS::AtMostOnce)).unwrap();
+        drop(first);
+        run(&mut connection, future::ok::<_, ()>(())).unwrap();
+        assert_eq!(connection.transport.sent.len(), 2);
+        drop(second);
+        run(&mut connection, future::ok::<_, ()>(())).unwrap();
+        assert_eq!(
+            connection.transport.sent.last(),
+            Some(&Packet::Unsubscribe {
+                packet_id: 3,
+                filters: vec!["a/#".to_owned()],
+            })
+        );
+    }
+
+    #[test]
+    fn matches_shadow_responses_by_client_token() {
+        let (client, mut connection) = mock_connection(Box::new(|packet: &Packet| {
+            let mut responses = suback(packet);
+            if let Packet::Publish(ref publish) = *packet {
+                let request: Value = serde_json::from_slice(&publish.payload).unwrap();
+                let other = json!({ "clientToken": "other", "state": {} });
+                let rejected = json!({
+                    "clientToken": request["clientToken"],
+                    "code": 404,
+                    "message": "No shadow exists with name: 'lamp'",
+                });
+                responses.push(publish_json("$aws/things/lamp/shadow/get/accepted", other));
+                responses.push(publish_json(
+                    "$aws/things/lamp/shadow/get/rejected",
+                    rejected,
+                ));
+            }
+            responses
+        }));
+        let result = run(&mut connection, client.get_shadow("lamp"));
+        assert_eq!(
+            result.err(),
+            Some(RusotoError::Service(MqttError::ShadowRejected(
+                ShadowError {
+                    code: 404,
+                    message: "No shadow exists with name: 'lamp'".to_owned(),
+                }
+            )))
+        );
+    }
+
+    fn publish_json(topic: &str, payload: Value) -> Packet {
+        publish(topic, &payload.to_string(), None)
+    }
+
+    #[test]
+    fn fails_requests_once_disconnected() {
+        let (client, mut connection) = mock_connection(Box::new(suback));
+        run(&mut connection, client.disconnect()).unwrap();
+        assert_eq!(connection.transport.sent, vec![Packet::Disconnect]);
+        drop(connection);
+        assert_eq!(
+            client.publish("a", "b", QoS::AtMostOnce).sync().err(),
+            Some(RusotoError::Service(MqttError::Disconnected))
+        );
+    }
+}
@@ -0,0 +1,402 @@
+//! Encoding and decoding the MQTT 3.1.1 packets the client sends and receives.
+
+use bytes::{BufMut, Bytes, BytesMut};
+use tokio::codec::{Decoder, Encoder};
+
+use super::{MqttError, QoS};
+
+const CONNECT: u8 = 1;
+const CONNACK: u8 = 2;
+const PUBLISH: u8 = 3;
+const PUBACK: u8 = 4;
+const SUBSCRIBE: u8 = 8;
+const SUBACK: u8 = 9;
+const UNSUBSCRIBE: u8 = 10;
+const UNSUBACK: u8 = 11;
+const PINGREQ: u8 = 12;
+const PINGRESP: u8 = 13;
+const DISCONNECT: u8 = 14;
+
+/// The largest remaining length the four bytes of its encoding can hold.
+const MAX_REMAINING_LENGTH: usize = 268_435_455;
+
+/// A `PUBLISH` packet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Publish {
+    pub topic: String,
+    pub payload: Bytes,
+    pub qos: QoS,
+    pub retain: bool,
+    pub dup: bool,
+    /// Only set for QoS 1
+    pub packet_id: Option<u16>,
+}
+
+/// An MQTT control packet.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Packet {
+    Connect {
+        client_id: String,
+        keep_alive: u16,
+        clean_session: bool,
+    },
+    ConnAck {
+        session_present: bool,
+        return_code: u8,
+    },
+    Publish(Publish),
+    PubAck(u16),
+    Subscribe {
+        packet_id: u16,
+        filters: Vec<(String, QoS)>,
+    },
+    SubAck {
+        packet_id: u16,
+        return_codes: Vec<u8>,
+    },
+    Unsubscribe {
+        packet_id: u16,
+        filters: Vec<String>,
+    },
+    UnsubAck(u16),
+    PingReq,
+    PingResp,
+    Disconnect,
+}
+
+/// Encodes and decodes packets, for transports carrying them as a stream of bytes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MqttCodec;
+
+impl Encoder for MqttCodec {
+    type Item = Packet;
+    type Error = MqttError;
+
+    fn encode(&mut self, packet: Packet, dst: &mut BytesMut) -> Result<(), MqttError> {
+        encode(&packet, dst)
+    }
+}
+
+impl Decoder for MqttCodec {
+    type Item = Packet;
+    type Error = MqttError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Packet>, MqttError> {
+        decode(src)
+    }
+}
+
+fn string_len(value: &str) -> usize {
+    2 + value.len()
+}
+
+fn put_string(dst: &mut BytesMut, value: &str) {
+    dst.put_u16_be(value.len() as u16);
+    dst.put_slice(value.as_bytes());
+}
+
+fn check_string(value: &str) -> Result<(), MqttError> {
+    if value.len() > u16::max_value() as usize {
+        return Err(MqttError::Protocol(format!(
+            "String of {} bytes is too long",
+            value.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Appends `packet` to `dst`.
+pub fn encode(packet: &Packet, dst: &mut BytesMut) -> Result<(), MqttError> {
+    let (header, remaining_length) = match *packet {
+        Packet::Connect { ref client_id, .. } => {
+            check_string(client_id)?;
+            (CONNECT << 4, 10 + string_len(client_id))
+        }
+        Packet::ConnAck { .. } => (CONNACK << 4, 2),
+        Packet::Publish(ref publish) => {
+            check_string(&publish.topic)?;
+            let flags = (publish.dup as u8) << 3 | (publish.qos as u8) << 1 | publish.retain as u8;
+            let packet_id_len = if publish.qos == QoS::AtMostOnce { 0 } else { 2 };
+            (
+                PUBLISH << 4 | flags,
+                string_len(&publish.topic) + packet_id_len + publish.payload.len(),
+            )
+        }
+        Packet::PubAck(_) => (PUBACK << 4, 2),
+        Packet::Subscribe { ref filters, .. } => {
+            for (filter, _) in filters {
+                check_string(filter)?;
+            }
+            let len = filters
+                .iter()
+                .map(|(filter, _)| string_len(filter) + 1)
+                .sum::<usize>();
+            (SUBSCRIBE << 4 | 0b0010, 2 + len)
+        }
+        Packet::SubAck {
+            ref return_codes, ..
+        } => (SUBACK << 4, 2 + return_codes.len()),
+        Packet::Unsubscribe { ref filters, .. } => {
+            for filter in filters {
+                check_string(filter)?;
+            }
+            let len = filters
+                .iter()
+                .map(|filter| string_len(filter))
+                .sum::<usize>();
+            (UNSUBSCRIBE << 4 | 0b0010, 2 + len)
+        }
+        Packet::UnsubAck(_) => (UNSUBACK << 4, 2),
+        Packet::PingReq => (PINGREQ << 4, 0),
+        Packet::PingResp => (PINGRESP << 4, 0),
+        Packet::Disconnect => (DISCONNECT << 4, 0),
+    };
+    if remaining_length > MAX_REMAINING_LENGTH {
+        return Err(MqttError::Protocol(format!(
+            "Packet of {} bytes is too large",
+            remaining_length
+        )));
+    }
+
+    dst.reserve(1 + 4 + remaining_length);
+    dst.put_u8(header);
+    let mut length = remaining_length;
+    loop {
+        let mut byte = (length % 128) as u8;
+        length /= 128;
+        if length > 0 {
+            byte |= 0x80;
+        }
+        dst.put_u8(byte);
+        if length == 0 {
+            break;
+        }
+    }
+
+    match *packet {
+        Packet::Connect {
+            ref client_id,
+            keep_alive,
+            clean_session,
+        } => {
+            put_string(dst, "MQTT");
+            dst.put_u8(4);
+            dst.put_u8(if clean_session { 0b0000_0010 } else { 0 });
+            dst.put_u16_be(keep_alive);
+            put_string(dst, client_id);
+        }
+        Packet::ConnAck {
+            session_present,
+            return_code,
+        } => {
+            dst.put_u8(session_present as u8);
+            dst.put_u8(return_code);
+        }
+        Packet::Publish(ref publish) => {
+            put_string(dst, &publish.topic);
+            if publish.qos != QoS::AtMostOnce {
+                dst.put_u16_be(publish.packet_id.unwrap_or_default());
+            }
+            dst.put_slice(&publish.payload);
+        }
+        Packet::PubAck(packet_id) | Packet::UnsubAck(packet_id) => dst.put_u16_be(packet_id),
+        Packet::Subscribe {
+            packet_id,
+            ref filters,
+        } => {
+            dst.put_u16_be(packet_id);
+            for (filter, qos) in filters {
+                put_string(dst, filter);
+                dst.put_u8(*qos as u8);
+            }
+        }
+        Packet::SubAck {
+            packet_id,
+            ref return_codes,
+        } => {
+            dst.put_u16_be(packet_id);
+            dst.put_slice(return_codes);
+        }
+        Packet::Unsubscribe {
+            packet_id,
+            ref filters,
+        } => {
+            dst.put_u16_be(packet_id);
+            for filter in filters {
+                put_string(dst, filter);
+            }
+        }
+        Packet::PingReq | Packet::PingResp | Packet::Disconnect => {}
+    }
+    Ok(())
+}
+
+/// Takes the first packet off `src`, or returns `None` if `src` doesn't hold a whole packet yet.
+///
+/// Only the packets a server sends to a client are decoded.
+pub fn decode(src: &mut BytesMut) -> Result<Option<Packet>, MqttError> {
+    let mut remaining_length = 0;
+    let mut header_length = 1;
+    loop {
+        let byte = match src.get(header_length) {
+            Some(&byte) => byte,
+            None => return Ok(None),
+        };
+        remaining_length += ((byte & 0x7f) as usize) << (7 * (header_length - 1));
+        header_length += 1;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        if header_length > 4 {
+            return Err(MqttError::Protocol("Malformed remaining length".to_owned()));
+        }
+    }
+    if src.len() < header_length + remaining_length {
+        src.reserve(header_length + remaining_length - src.len());
+        return Ok(None);
+    }
+
+    let header = src[0];
+    src.advance(header_length);
+    let body = src.split_to(remaining_length).freeze();
+    let packet = match header >> 4 {
+        CONNACK if body.len() == 2 => Packet::ConnAck {
+            session_present: body[0] & 1 == 1,
+            return_code: body[1],
+        },
+        PUBLISH => Packet::Publish(decode_publish(header, body)?),
+        PUBACK if body.len() == 2 => Packet::PubAck(read_u16(&body)),
+        SUBACK if body.len() >= 2 => Packet::SubAck {
+            packet_id: read_u16(&body),
+            return_codes: body[2..].to_vec(),
+        },
+        UNSUBACK if body.len() == 2 => Packet::UnsubAck(read_u16(&body)),
+        PINGRESP if body.is_empty() => Packet::PingResp,
+        packet_type => {
+            return Err(MqttError::Protocol(format!(
+                "Unexpected packet of type {} and {} bytes",
+                packet_type,
+                body.len()
+            )))
+        }
+    };
+    Ok(Some(packet))
+}
+
+fn read_u16(body: &[u8]) -> u16 {
+    u16::from(body[0]) << 8 | u16::from(body[1])
+}
+
+fn decode_publish(header: u8, mut body: Bytes) -> Result<Publish, MqttError> {
+    let malformed = || MqttError::Protocol("Malformed PUBLISH packet".to_owned());
+    let qos = match (header >> 1) & 0b11 {
+        0 => QoS::AtMostOnce,
+        1 => QoS::AtLeastOnce,
+        qos => {
+            return Err(MqttError::Protocol(format!(
+                "Unsupported QoS {} in PUBLISH packet",
+                qos
+            )))
+        }
+    };
+    if body.len() < 2 {
+        return Err(malformed());
+    }
+    let topic_length = read_u16(&body) as usize;
+    let packet_id_length = if qos == QoS::AtMostOnce { 0 } else { 2 };
+    if body.len() < 2 + topic_length + packet_id_length {
+        return Err(malformed());
+    }
+    body.advance(2);
+    let topic = String::from_utf8(body.split_to(topic_length).to_vec()).map_err(|_| malformed())?;
+    let packet_id = if qos == QoS::AtMostOnce {
+        None
+    } else {
+        Some(read_u16(&body.split_to(2)))
+    };
+    Ok(Publish {
+        topic,
+        payload: body,
+        qos,
+        retain: header & 1 == 1,
+        dup: header & 0b1000 != 0,
+        packet_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encoded(packet: &Packet) -> Vec<u8> {
+        let mut buffer = BytesMut::new();
+        encode(packet, &mut buffer).unwrap();
+        buffer.to_vec()
+    }
+
+    #[test]
+    fn encodes_connect() {
+        let packet = Packet::Connect {
+            client_id: "dev".to_owned(),
+            keep_alive: 30,
+            clean_session: true,
+        };
+        assert_eq!(
+            encoded(&packet),
+            vec![0x10, 15, 0, 4, b'M', b'Q', b'T', b'T', 4, 0b10, 0, 30, 0, 3, b'd', b'e', b'v']
+        );
+    }
+
+    #[test]
+    fn encodes_subscribe() {
+        let packet = Packet::Subscribe {
+            packet_id: 7,
+            filters: vec![("a/#".to_owned(), QoS::AtLeastOnce)],
+        };
+        assert_eq!(
+            encoded(&packet),
+            vec![0x82, 8, 0, 7, 0, 3, b'a', b'/', b'#', 1]
+        );
+    }
+
+    #[test]
+    fn round_trips_publish() {
+        let publish = Publish {
+            topic: "sensors/1".to_owned(),
+            payload: Bytes::from(vec![b'x'; 200]),
+            qos: QoS::AtLeastOnce,
+            retain: true,
+            dup: false,
+            packet_id: Some(513),
+        };
+        let mut buffer = BytesMut::from(encoded(&Packet::Publish(publish.clone())));
+        // 200 bytes of payload need two bytes of remaining length
+        assert_eq!(&buffer[..3], &[0x33, 213, 1]);
+        assert_eq!(decode(&mut buffer).unwrap(), Some(Packet::Publish(publish)));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn waits_for_whole_packets() {
+        let mut buffer = BytesMut::from(vec![0x90, 3, 0, 1]);
+        assert_eq!(decode(&mut buffer).unwrap(), None);
+        buffer.extend_from_slice(&[0x80, 0xd0]);
+        assert_eq!(
+            decode(&mut buffer).unwrap(),
+            Some(Packet::SubAck {
+                packet_id: 1,
+                return_codes: vec![0x80],
+            })
+        );
+        assert_eq!(decode(&mut buffer).unwrap(), None);
+        assert_eq!(&buffer[..], &[0xd0]);
+        buffer.extend_from_slice(&[0]);
+        assert_eq!(decode(&mut buffer).unwrap(), Some(Packet::PingResp));
+    }
+
+    #[test]
+    fn rejects_unexpected_packets() {
+        let mut buffer = BytesMut::from(vec![0xc0, 0]);
+        assert!(decode(&mut buffer).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+//! Topic filters and the reserved topics of device shadows.
+
+/// Whether the topic `topic` matches the topic filter `filter`.
+///
+/// `+` in a filter matches exactly one topic level, and a trailing `#` matches any number of
+/// them, including none. As MQTT specifies, neither matches the first level of a topic starting
+/// with `$`, so `#` doesn't match the `$aws/...` reserved topics.
+pub fn matches(filter: &str, topic: &str) -> bool {
+    if topic.starts_with('$') && (filter.starts_with('+') || filter.starts_with('#')) {
+        return false;
+    }
+    let mut filter_levels = filter.split('/');
+    let mut topic_levels = topic.split('/');
+    loop {
+        match (filter_levels.next(), topic_levels.next()) {
+            (Some("#"), _) => return filter_levels.next().is_none(),
+            (Some("+"), Some(_)) => {}
+            (Some(filter_level), Some(topic_level)) if filter_level == topic_level => {}
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// The reserved topics of the classic shadow of a thing.
+///
+/// # Examples
+///
+/// ```rust
+/// use rusoto_iot_data::mqtt::ShadowTopics;
+///
+/// let topics = ShadowTopics::new("lamp");
+/// assert_eq!(topics.update(), "$aws/things/lamp/shadow/update");
+/// assert_eq!(topics.update_delta(), "$aws/things/lamp/shadow/update/delta");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShadowTopics {
+    prefix: String,
+}
+
+macro_rules! shadow_topics {
+    ($($(#[$attr:meta])* $name:ident => $suffix:expr;)*) => {
+        $(
+            $(#[$attr])*
+            pub fn $name(&self) -> String {
+                format!("{}/{}", self.prefix, $suffix)
+            }
+        )*
+    };
+}
+
+impl ShadowTopics {
+    /// The topics of the shadow of the thing `thing_name`.
+    pub fn new(thing_name: &str) -> ShadowTopics {
+        ShadowTopics {
+            prefix: format!("$aws/things/{}/shadow", thing_name),
+        }
+    }
+
+    shadow_topics! {
+        /// Publish a state document to update the shadow.
+        update => "update";
+        /// The state documents of updates the shadow accepted.
+        update_accepted => "update/accepted";
+        /// The errors of updates the shadow rejected.
+        update_rejected => "update/rejected";
+        /// The differences between the desired and reported state, after updates changing them.
+        update_delta => "update/delta";
+        /// The previous and current shadow documents, after every accepted update.
+        update_documents => "update/documents";
+        /// Publish an empty message to get the shadow document.
+        get => "get";
+        /// The shadow documents of accepted gets.
+        get_accepted => "get/accepted";
+        /// The errors of rejected gets.
+        get_rejected => "get/rejected";
+        /// Publish an empty message to delete the shadow.
+        delete => "delete";
+        /// The confirmations of accepted deletes.
+        delete_accepted => "delete/accepted";
+        /// The errors of rejected deletes.
+        delete_rejected => "delete/rejected";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_topic_filters() {
+        assert!(matches("a/b", "a/b"));
+        assert!(!matches("a/b", "a/b/c"));
+        assert!(!matches("a/b/c", "a/b"));
+        assert!(matches("a/+/c", "a/b/c"));
+        assert!(!matches("a/+", "a/b/c"));
+        assert!(matches("a/+", "a/"));
+        assert!(matches("a/#", "a"));
+        assert!(matches("a/#", "a/b/c"));
+        assert!(matches("#", "a/b"));
+        assert!(!matches("a/#/c", "a/b/c"));
+    }
+
+    #[test]
+    fn wildcards_dont_match_reserved_topics() {
+        assert!(!matches("#", "$aws/things/lamp/shadow/update"));
+        assert!(!matches("+/things/#", "$aws/things/lamp/shadow/update"));
+        assert!(matches(
+            "$aws/things/+/shadow/#",
+            "$aws/things/lamp/shadow/update"
+        ));
+    }
+
+    #[test]
+    fn builds_shadow_topics() {
+        let topics = ShadowTopics::new("lamp");
+        assert_eq!(topics.get(), "$aws/things/lamp/shadow/get");
+        assert_eq!(
+            topics.get_rejected(),
+            "$aws/things/lamp/shadow/get/rejected"
+        );
+        assert_eq!(
+            topics.delete_accepted(),
+            "$aws/things/lamp/shadow/delete/accepted"
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! The connections MQTT packets are exchanged over: a SigV4 authenticated WebSocket, or a TLS
+//! connection authenticated with the device's certificate.
+
+use std::time::Duration;
+
+use bytes::BytesMut;
+use futures::future::{err, Either};
+use futures::{try_ready, Async, AsyncSink, Future, Poll, Sink, StartSend, Stream};
+use hyper::header::{
+    CONNECTION, SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_PROTOCOL, SEC_WEBSOCKET_VERSION, UPGRADE,
+};
+use hyper::{Body, Client, Request, StatusCode};
+use rusoto_core::credential::AwsCredentials;
+use rusoto_core::request::{default_connector, HttpDispatchError};
+use rusoto_core::signature::{SessionTokenPlacement, SignedRequest};
+use rusoto_core::{Region, RusotoError};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_tungstenite::tungstenite::protocol::Role;
+use tokio_tungstenite::tungstenite::{Error as WsError, Message};
+use tokio_tungstenite::WebSocketStream;
+use uuid::Uuid;
+
+use super::packet::{decode, encode, Packet};
+use super::MqttError;
+
+/// How long the presigned URL of the WebSocket is valid for. It only needs to outlive the
+/// upgrade request.
+const PRESIGNED_URL_EXPIRATION: Duration = Duration::from_secs(300);
+
+/// A connection exchanging MQTT packets.
+pub trait Transport:
+    Stream<Item = Packet, Error = MqttError> + Sink<SinkItem = Packet, SinkError = MqttError> + Send
+{
+}
+
+impl<T> Transport for T where
+    T: Stream<Item = Packet, Error = MqttError>
+        + Sink<SinkItem = Packet, SinkError = MqttError>
+        + Send
+{
+}
+
+pub type BoxTransport = Box<dyn Transport + Send>;
+
+pub type TransportFuture =
+    Box<dyn Future<Item = BoxTransport, Error = RusotoError<MqttError>> + Send>;
+
+/// Opens a WebSocket to the `/mqtt` path of `endpoint`, presigned with `credentials`.
+pub fn websocket(endpoint: &str, region: &Region, credentials: &AwsCredentials) -> TransportFuture {
+    let mut request = SignedRequest::new("GET", "iotdevicegateway", region, "/mqtt");
+    request.set_hostname(Some(endpoint.to_owned()));
+    let url = request.generate_presigned_websocket_url(
+        credentials,
+        &PRESIGNED_URL_EXPIRATION,
+        SessionTokenPlacement::AppendedAfterSigning,
+    );
+    // hyper makes the upgrade request over HTTPS, and hands over the connection once upgraded
+    let url = url.replacen("wss://", "https://", 1);
+
+    let connector = match default_connector() {
+        Ok(connector) => connector,
+        Err(tls_error) => {
+            return Box::new(err(HttpDispatchError::new(tls_error.to_string()).into()))
+        }
+    };
+    let key = base64::encode(Uuid::new_v4().as_bytes());
+    let request = Request::get(url.as_str())
+        .header(UPGRADE, "websocket")
+        .header(CONNECTION, "Upgrade")
+        .header(SEC_WEBSOCKET_VERSION, "13")
+        .header(SEC_WEBSOCKET_KEY, key.as_str())
+        .header(SEC_WEBSOCKET_PROTOCOL, "mqtt")
+        .body(Body::empty());
+    let request = match request {
+        Ok(request) => request,
+        Err(error) => return Box::new(err(HttpDispatchError::new(error.to_string()).into())),
+    };
+
+    let future = Client::builder()
+        .build::<_, Body>(connector)
+        .request(request)
+        .map_err(|error| HttpDispatchError::new(error.to_string()))
+        .and_then(|response| {
+            if response.status() == StatusCode::SWITCHING_PROTOCOLS {
+                Either::A(
+                    response
+                        .into_body()
+                        .on_upgrade()
+                        .map_err(|error| HttpDispatchError::new(error.to_string())),
+                )
+            } else {
+                Either::B(err(HttpDispatchError::new(format!(
+                    "WebSocket upgrade failed with status {}",
+                    response.status()
+                ))))
+            }
+        })
+        .map_err(RusotoError::<MqttError>::from)
+        .map(|upgraded| -> BoxTransport {
+            Box::new(WebSocketTransport {
+                socket: WebSocketStream::from_raw_socket(upgraded, Role::Client, None),
+                buffer: BytesMut::new(),
+            })
+        });
+    Box::new(future)
+}
+
+/// Opens a TLS connection to port 8883 of `endpoint`, authenticated with `identity`.
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+pub fn mutual_tls(
+    endpoint: &str,
+    identity: &rusoto_core::credential::IotIdentity,
+) -> TransportFuture {
+    use super::packet::MqttCodec;
+    use tokio::codec::Framed;
+
+    let future = identity
+        .connect(endpoint, 8883)
+        .map_err(|error| RusotoError::HttpDispatch(HttpDispatchError::new(error.to_string())))
+        .map(|stream| -> BoxTransport { Box::new(Framed::new(stream, MqttCodec)) });
+    Box::new(future)
+}
+
+/// Packets are sent as binary messages, which may hold several packets or part of one.
+struct WebSocketTransport<S> {
+    socket: WebSocketStream<S>,
+    buffer: BytesMut,
+}
+
+fn websocket_error(error: WsError) -> MqttError {
+    MqttError::Transport(error.to_string())
+}
+
+impl<S> Stream for WebSocketTransport<S>
+where
+    S: AsyncRead + AsyncWrite,
+{
+    type Item = Packet;
+    type Error = MqttError;
+
+    fn poll(&mut self) -> Poll<Option<Packet>, MqttError> {
+        loop {
+            if let Some(packet) = decode(&mut self.buffer)? {
+                return Ok(Async::Ready(Some(packet)));
+            }
+            match try_ready!(self.socket.poll().map_err(websocket_error)) {
+                Some(Message::Binary(data)) => self.buffer.extend_from_slice(&data),
+                Some(Message::Close(_)) | None => return Ok(Async::Ready(None)),
+                // pings are answered by tungstenite
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+impl<S> Sink for WebSocketTransport<S>
+where
+    S: AsyncRead + AsyncWrite,
+{
+    type SinkItem = Packet;
+    type SinkError = MqttError;
+
+    fn start_send(&mut self, packet: Packet) -> StartSend<Packet, MqttError> {
+        let mut data = BytesMut::new();
+        encode(&packet, &mut data)?;
+        match self
+            .socket
+            .start_send(Message::Binary(data.to_vec()))
+            .map_err(websocket_error)?
+        {
+            AsyncSink::Ready => Ok(AsyncSink::Ready),
+            AsyncSink::NotReady(_) => Ok(AsyncSink::NotReady(packet)),
+        }
+    }
+
+    fn poll_complete(&mut self) -> Poll<(), MqttError> {
+        self.socket.poll_complete().map_err(websocket_error)
+    }
+
+    fn close(&mut self) -> Poll<(), MqttError> {
+        self.socket.close().map_err(websocket_error)
+    }
+}
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 /// <p>The input for the DeleteThingShadow operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteThingShadowRequest {
@@ -31,6 +32,38 @@ pub struct DeleteThingShadowRequest {
     pub thing_name: String,
 }
 
+impl DeleteThingShadowRequest {
+    /// Returns a builder for `DeleteThingShadowRequest`, checking its required members when built.
+    pub fn builder() -> DeleteThingShadowRequestBuilder {
+        DeleteThingShadowRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteThingShadowRequest`](struct.DeleteThingShadowRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteThingShadowRequestBuilder {
+    thing_name: Option<String>,
+}
+
+impl DeleteThingShadowRequestBuilder {
+    /// Sets the [`thing_name`](struct.DeleteThingShadowRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteThingShadowRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteThingShadowRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteThingShadowRequest {
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 /// <p>The output from the DeleteThingShadow operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeleteThingShadowResponse {
@@ -46,6 +79,36 @@ pub struct GetThingShadowRequest {
     pub thing_name: String,
 }
 
+impl GetThingShadowRequest {
+    /// Returns a builder for `GetThingShadowRequest`, checking its required members when built.
+    pub fn builder() -> GetThingShadowRequestBuilder {
+        GetThingShadowRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetThingShadowRequest`](struct.GetThingShadowRequest.html).
+#[derive(Default, Debug)]
+pub struct GetThingShadowRequestBuilder {
+    thing_name: Option<String>,
+}
+
+impl GetThingShadowRequestBuilder {
+    /// Sets the [`thing_name`](struct.GetThingShadowRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetThingShadowRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetThingShadowRequest, ::rusoto_core::BuildError> {
+        Ok(GetThingShadowRequest {
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 /// <p>The output from the GetThingShadow operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetThingShadowResponse {
@@ -74,6 +137,52 @@ pub struct PublishRequest {
     pub topic: String,
 }
 
+impl PublishRequest {
+    /// Returns a builder for `PublishRequest`, checking its required members when built.
+    pub fn builder() -> PublishRequestBuilder {
+        PublishRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PublishRequest`](struct.PublishRequest.html).
+#[derive(Default, Debug)]
+pub struct PublishRequestBuilder {
+    payload: Option<bytes::Bytes>,
+    qos: Option<i64>,
+    topic: Option<String>,
+}
+
+impl PublishRequestBuilder {
+    /// Sets the [`payload`](struct.PublishRequest.html#structfield.payload) member.
+    pub fn payload<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.payload = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qos`](struct.PublishRequest.html#structfield.qos) member.
+    pub fn qos<T: Into<i64>>(mut self, value: T) -> Self {
+        self.qos = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic`](struct.PublishRequest.html#structfield.topic) member.
+    pub fn topic<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic = Some(value.into());
+        self
+    }
+
+    /// Builds the `PublishRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PublishRequest, ::rusoto_core::BuildError> {
+        Ok(PublishRequest {
+            payload: self.payload,
+            qos: self.qos,
+            topic: self
+                .topic
+                .ok_or(::rusoto_core::BuildError::missing("topic"))?,
+        })
+    }
+}
+
 /// <p>The input for the UpdateThingShadow operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateThingShadowRequest {
@@ -90,6 +199,48 @@ pub struct UpdateThingShadowRequest {
     pub thing_name: String,
 }
 
+impl UpdateThingShadowRequest {
+    /// Returns a builder for `UpdateThingShadowRequest`, checking its required members when built.
+    pub fn builder() -> UpdateThingShadowRequestBuilder {
+        UpdateThingShadowRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateThingShadowRequest`](struct.UpdateThingShadowRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateThingShadowRequestBuilder {
+    payload: Option<bytes::Bytes>,
+    thing_name: Option<String>,
+}
+
+impl UpdateThingShadowRequestBuilder {
+    /// Sets the [`payload`](struct.UpdateThingShadowRequest.html#structfield.payload) member.
+    pub fn payload<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.payload = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.UpdateThingShadowRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateThingShadowRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateThingShadowRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateThingShadowRequest {
+            payload: self
+                .payload
+                .ok_or(::rusoto_core::BuildError::missing("payload"))?,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 /// <p>The output from the UpdateThingShadow operation.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct UpdateThingShadowResponse {
@@ -414,6 +565,36 @@ pub trait IotData {
         input: UpdateThingShadowRequest,
     ) -> RusotoFuture<UpdateThingShadowResponse, UpdateThingShadowError>;
 }
+impl<T: ?Sized + IotData> IotData for ::std::sync::Arc<T> {
+    fn delete_thing_shadow(
+        &self,
+        input: DeleteThingShadowRequest,
+    ) -> RusotoFuture<DeleteThingShadowResponse, DeleteThingShadowError> {
+        (**self).delete_thing_shadow(input)
+    }
+    fn get_thing_shadow(
+        &self,
+        input: GetThingShadowRequest,
+    ) -> RusotoFuture<GetThingShadowResponse, GetThingShadowError> {
+        (**self).get_thing_shadow(input)
+    }
+    fn publish(&self, input: PublishRequest) -> RusotoFuture<(), PublishError> {
+        (**self).publish(input)
+    }
+    fn update_thing_shadow(
+        &self,
+        input: UpdateThingShadowRequest,
+    ) -> RusotoFuture<UpdateThingShadowResponse, UpdateThingShadowError> {
+        (**self).update_thing_shadow(input)
+    }
+}
+/// The error codes AWS IoT Data Plane retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS IoT Data Plane API.
 #[derive(Clone)]
 pub struct IotDataClient {
@@ -429,6 +610,27 @@ impl IotDataClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_IOT_DATA`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> IotDataClient {
+        Self::new(region::Region::from_env_for_service("IOT_DATA"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `data.iot`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> IotDataClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("data.iot", "IOT_DATA"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -447,7 +649,54 @@ impl IotDataClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> IotDataClient {
-        IotDataClient { client, region }
+        IotDataClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "data.iot")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "data.iot",
+            "iotdata",
+            "IOT_DATA",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `IotData` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn IotData + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for IotDataClient {
+    fn service_name(&self) -> &'static str {
+        "AWS IoT Data Plane"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "data.iot"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> IotDataClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,379 @@
+//! Running jobs on a device.
+//!
+//! A device agent takes the next pending job execution of its thing, runs the job document and
+//! reports the outcome. `JobAgentExt` implements that protocol on top of the Jobs data plane:
+//! it starts executions with a step timeout, hands their job document to a callback, and moves
+//! them to `SUCCEEDED`, `FAILED` or `REJECTED`. Updates carry the execution's version number;
+//! when an update is rejected because the execution changed in the meantime, the execution is
+//! described again and the update retried with the new version, unless the job was canceled or
+//! removed.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use futures::future::{self, Either, Loop};
+use futures::{Future, IntoFuture};
+use rusoto_core::waiter::sleep;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    DescribeJobExecutionError, DescribeJobExecutionRequest, IotJobsData, JobExecution,
+    JobExecutionStatus, StartNextPendingJobExecutionError, StartNextPendingJobExecutionRequest,
+    UpdateJobExecutionError, UpdateJobExecutionRequest,
+};
+
+/// Errors returned while running jobs.
+#[derive(Debug, PartialEq)]
+pub enum JobAgentError {
+    /// Starting the next pending job execution failed.
+    StartNextPendingJobExecution(StartNextPendingJobExecutionError),
+    /// Describing a job execution after a version conflict failed.
+    DescribeJobExecution(DescribeJobExecutionError),
+    /// Updating a job execution failed.
+    UpdateJobExecution(UpdateJobExecutionError),
+    /// The job execution kept changing while its status was being updated.
+    VersionConflict(String),
+}
+
+impl fmt::Display for JobAgentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for JobAgentError {
+    fn description(&self) -> &str {
+        match *self {
+            JobAgentError::StartNextPendingJobExecution(ref cause) => cause.description(),
+            JobAgentError::DescribeJobExecution(ref cause) => cause.description(),
+            JobAgentError::UpdateJobExecution(ref cause) => cause.description(),
+            JobAgentError::VersionConflict(ref cause) => cause,
+        }
+    }
+}
+
+/// How a device runs its jobs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobAgentConfig {
+    /// Name of the thing the device is
+    pub thing_name: String,
+    /// Time the device has to finish each job, after which the execution times out
+    pub step_timeout_in_minutes: Option<i64>,
+    /// Time `run_job_agent` waits before looking for jobs again when none are pending
+    pub poll_interval: Duration,
+    /// Number of times an update is retried after the execution changed in the meantime
+    pub max_version_conflicts: u32,
+}
+
+impl JobAgentConfig {
+    /// Create a new JobAgentConfig for the given thing.
+    pub fn new<S: Into<String>>(thing_name: S) -> JobAgentConfig {
+        JobAgentConfig {
+            thing_name: thing_name.into(),
+            step_timeout_in_minutes: None,
+            poll_interval: Duration::from_secs(60),
+            max_version_conflicts: 3,
+        }
+    }
+}
+
+/// The result of running a job, returned by the job callback.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JobOutcome {
+    /// The job ran successfully.
+    Succeeded(HashMap<String, String>),
+    /// The job ran and failed.
+    Failed(HashMap<String, String>),
+    /// The device can't run the job, e.g. because the job document is invalid.
+    Rejected(HashMap<String, String>),
+}
+
+impl JobOutcome {
+    fn into_update(self) -> (JobExecutionStatus, HashMap<String, String>) {
+        match self {
+            JobOutcome::Succeeded(details) => (JobExecutionStatus::Succeeded, details),
+            JobOutcome::Failed(details) => (JobExecutionStatus::Failed, details),
+            JobOutcome::Rejected(details) => (JobExecutionStatus::Rejected, details),
+        }
+    }
+}
+
+/// A job execution which was run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JobReport {
+    /// ID of the job
+    pub job_id: String,
+    /// Number of the execution on this device
+    pub execution_number: Option<i64>,
+    /// Final status of the execution, e.g. `SUCCEEDED`, or `CANCELED` if the job was canceled
+    /// while it was running
+    pub status: JobExecutionStatus,
+}
+
+fn is_terminal(status: &JobExecutionStatus) -> bool {
+    match *status {
+        JobExecutionStatus::Queued | JobExecutionStatus::InProgress => false,
+        _ => true,
+    }
+}
+
+/// Whether an update failed because the execution's version changed.
+fn is_version_conflict(error: &RusotoError<UpdateJobExecutionError>) -> bool {
+    match *error {
+        // VersionConflictException isn't part of the service model
+        RusotoError::Unknown(ref response) => response.status.as_u16() == 409,
+        RusotoError::Service(UpdateJobExecutionError::InvalidStateTransition(_)) => true,
+        _ => false,
+    }
+}
+
+/// The state of an update retried after version conflicts.
+struct Update<C> {
+    client: C,
+    request: UpdateJobExecutionRequest,
+    conflicts: u32,
+}
+
+/// Moves a job execution to `status`, retrying with the current version after conflicts.
+fn update_status<C>(
+    client: C,
+    request: UpdateJobExecutionRequest,
+    max_conflicts: u32,
+) -> impl Future<Item = JobReport, Error = RusotoError<JobAgentError>> + Send
+where
+    C: IotJobsData + Clone + Send + 'static,
+{
+    let state = Update {
+        client,
+        request,
+        conflicts: 0,
+    };
+
+    future::loop_fn(state, move |state| {
+        let Update {
+            client,
+            request,
+            conflicts,
+        } = state;
+        client
+            .update_job_execution(request.clone())
+            .then(move |result| match result {
+                Ok(_) => Either::A(future::ok(Loop::Break(JobReport {
+                    job_id: request.job_id,
+                    execution_number: request.execution_number,
+                    status: request.status,
+                }))),
+                Err(ref err) if is_version_conflict(err) && conflicts < max_conflicts => {
+                    let describe = client
+                        .describe_job_execution(DescribeJobExecutionRequest {
+                            job_id: request.job_id.clone(),
+                            thing_name: request.thing_name.clone(),
+                            execution_number: request.execution_number,
+                            include_job_document: Some(false),
+                        })
+                        .map_err(|e| e.map_service(JobAgentError::DescribeJobExecution))
+                        .map(move |response| {
+                            let execution = response.execution.unwrap_or_default();
+                            let status = execution
+                                .status
+                                .unwrap_or_else(|| JobExecutionStatus::Unknown(String::new()));
+                            if is_terminal(&status) {
+                                // e.g. canceled by an operator while it was running
+                                return Loop::Break(JobReport {
+                                    job_id: request.job_id,
+                                    execution_number: request.execution_number,
+                                    status,
+                                });
+                            }
+                            let mut request = request;
+                            request.expected_version = execution.version_number;
+                            Loop::Continue(Update {
+                                client,
+                                request,
+                                conflicts: conflicts + 1,
+                            })
+                        });
+                    Either::B(describe)
+                }
+                Err(ref err) if is_version_conflict(err) => Either::A(future::err(
+                    RusotoError::Service(JobAgentError::VersionConflict(format!(
+                        "Job execution {} kept changing while it was updated to {}",
+                        request.job_id, request.status
+                    ))),
+                )),
+                Err(err) => Either::A(future::err(
+                    err.map_service(JobAgentError::UpdateJobExecution),
+                )),
+            })
+    })
+}
+
+/// Runs the next pending job, handing the callback back along with the report.
+fn next_job<C, F, R>(
+    client: C,
+    config: JobAgentConfig,
+    mut handler: F,
+) -> impl Future<Item = (Option<JobReport>, F), Error = RusotoError<JobAgentError>> + Send
+where
+    C: IotJobsData + Clone + Send + 'static,
+    F: FnMut(&JobExecution) -> R + Send + 'static,
+    R: IntoFuture<Item = JobOutcome, Error = String>,
+    R::Future: Send + 'static,
+{
+    let request = StartNextPendingJobExecutionRequest {
+        thing_name: config.thing_name.clone(),
+        step_timeout_in_minutes: config.step_timeout_in_minutes,
+        status_details: None,
+    };
+
+    client
+        .start_next_pending_job_execution(request)
+        .map_err(|e| e.map_service(JobAgentError::StartNextPendingJobExecution))
+        .and_then(move |response| {
+            let execution = match response.execution {
+                Some(execution) => execution,
+                None => return Either::A(future::ok((None, handler))),
+            };
+            let run = handler(&execution).into_future().then(|result| {
+                Ok::<_, RusotoError<JobAgentError>>(result.unwrap_or_else(|err| {
+                    let mut details = HashMap::new();
+                    details.insert("error".to_owned(), err);
+                    JobOutcome::Failed(details)
+                }))
+            });
+            let future = run.and_then(move |outcome| {
+                let (status, details) = outcome.into_update();
+                let request = UpdateJobExecutionRequest {
+                    job_id: execution.job_id.unwrap_or_default(),
+                    thing_name: config.thing_name,
+                    execution_number: execution.execution_number,
+                    expected_version: execution.version_number,
+                    include_job_document: None,
+                    include_job_execution_state: None,
+                    status,
+                    status_details: Some(details),
+                    step_timeout_in_minutes: None,
+                };
+                update_status(client, request, config.max_version_conflicts)
+                    .map(move |report| (Some(report), handler))
+            });
+            Either::B(future)
+        })
+}
+
+/// Extension methods for running jobs on a device.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::collections::HashMap;
+///
+/// use rusoto_core::Region;
+/// use rusoto_iot_jobs_data::agent::{JobAgentConfig, JobAgentExt, JobOutcome};
+/// use rusoto_iot_jobs_data::IotJobsDataClient;
+///
+/// let client = IotJobsDataClient::new(Region::UsEast1);
+/// let report = client
+///     .run_next_job(JobAgentConfig::new("my-device"), |execution| {
+///         match execution.job_document {
+///             Some(_) => Ok(JobOutcome::Succeeded(HashMap::new())),
+///             None => Err("missing job document".to_owned()),
+///         }
+///     })
+///     .sync()
+///     .unwrap();
+/// ```
+pub trait JobAgentExt: IotJobsData + Clone + Send + Sized + 'static {
+    /// Starts the next pending job execution of the thing, runs it with `handler` and reports
+    /// the outcome.
+    ///
+    /// Resolves to `None` if no job is pending. A job whose handler fails is marked `FAILED`,
+    /// with the error in the `error` status detail.
+    fn run_next_job<F, R>(
+        &self,
+        config: JobAgentConfig,
+        handler: F,
+    ) -> RusotoFuture<Option<JobReport>, JobAgentError>
+    where
+        F: FnMut(&JobExecution) -> R + Send + 'static,
+        R: IntoFuture<Item = JobOutcome, Error = String> + 'static,
+        R::Future: Send + 'static,
+    {
+        let future = next_job(self.clone(), config, handler).map(|(report, _)| report);
+        RusotoFuture::from_future(future)
+    }
+
+    /// Runs the pending jobs of the thing one after the other, waiting `config.poll_interval`
+    /// whenever none are pending, and calls `on_report` after each job.
+    ///
+    /// The returned future only resolves if running a job fails.
+    fn run_job_agent<F, R, G>(
+        &self,
+        config: JobAgentConfig,
+        handler: F,
+        on_report: G,
+    ) -> RusotoFuture<(), JobAgentError>
+    where
+        F: FnMut(&JobExecution) -> R + Send + 'static,
+        R: IntoFuture<Item = JobOutcome, Error = String> + 'static,
+        R::Future: Send + 'static,
+        G: FnMut(&JobReport) + Send + 'static,
+    {
+        let state = (self.clone(), handler, on_report);
+
+        let future = future::loop_fn(state, move |(client, handler, mut on_report)| {
+            let config = config.clone();
+            let delay = config.poll_interval;
+            next_job(client.clone(), config, handler).and_then(
+                move |(report, handler)| match report {
+                    Some(report) => {
+                        on_report(&report);
+                        Either::A(future::ok(Loop::Continue((client, handler, on_report))))
+                    }
+                    None => Either::B(
+                        sleep(delay).map(move |_| Loop::Continue((client, handler, on_report))),
+                    ),
+                },
+            )
+        });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Reports that a job execution is still in progress, resetting its step timeout.
+    ///
+    /// Resolves to the new version of the execution, to pass as `expected_version` next time.
+    fn report_job_progress(
+        &self,
+        config: &JobAgentConfig,
+        execution: &JobExecution,
+        status_details: HashMap<String, String>,
+    ) -> RusotoFuture<Option<i64>, JobAgentError> {
+        let request = UpdateJobExecutionRequest {
+            job_id: execution.job_id.clone().unwrap_or_default(),
+            thing_name: config.thing_name.clone(),
+            execution_number: execution.execution_number,
+            expected_version: execution.version_number,
+            include_job_document: None,
+            include_job_execution_state: Some(true),
+            status: JobExecutionStatus::InProgress,
+            status_details: Some(status_details),
+            step_timeout_in_minutes: config.step_timeout_in_minutes,
+        };
+
+        let future = self
+            .update_job_execution(request)
+            .map_err(|e| e.map_service(JobAgentError::UpdateJobExecution))
+            .map(|response| {
+                response
+                    .execution_state
+                    .and_then(|state| state.version_number)
+            });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: IotJobsData + Clone + Send + 'static> JobAgentExt for T {}
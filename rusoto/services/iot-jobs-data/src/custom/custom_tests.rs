@@ -0,0 +1,70 @@
+extern crate rusoto_mock;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::agent::{JobAgentConfig, JobAgentExt, JobOutcome};
+use crate::generated::{IotJobsDataClient, JobExecutionStatus};
+
+use self::rusoto_mock::*;
+use rusoto_core::signature::SignedRequest;
+use rusoto_core::Region;
+
+#[test]
+fn should_run_the_next_pending_job() {
+    let updates = Arc::new(AtomicUsize::new(0));
+    let counter = updates.clone();
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(
+            r#"{
+                "execution": {
+                    "jobId": "update-firmware",
+                    "executionNumber": 1,
+                    "jobDocument": "{\"version\": \"2.0\"}",
+                    "status": "IN_PROGRESS",
+                    "versionNumber": 2
+                }
+            }"#,
+        )
+        .with_request_checker(move |request: &SignedRequest| {
+            if request.method == "POST" {
+                assert_eq!(request.path, "/things/my-device/jobs/update-firmware");
+                counter.fetch_add(1, Ordering::SeqCst);
+            } else {
+                assert_eq!(request.path, "/things/my-device/jobs/$next");
+            }
+        });
+    let client = IotJobsDataClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let report = client
+        .run_next_job(JobAgentConfig::new("my-device"), |execution| {
+            assert_eq!(
+                execution.job_document,
+                Some(r#"{"version": "2.0"}"#.to_owned())
+            );
+            Ok(JobOutcome::Succeeded(HashMap::new()))
+        })
+        .sync()
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(report.job_id, "update-firmware");
+    assert_eq!(report.status, JobExecutionStatus::Succeeded);
+    assert_eq!(updates.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn should_not_run_anything_without_pending_jobs() {
+    let mock = MockRequestDispatcher::with_status(200).with_body("{}");
+    let client = IotJobsDataClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let report = client
+        .run_next_job(JobAgentConfig::new("my-device"), |_| {
+            Err::<JobOutcome, _>("no job should run".to_owned())
+        })
+        .sync()
+        .unwrap();
+
+    assert_eq!(report, None);
+}
@@ -1 +1,5 @@
+/// Running job executions on a device
+pub mod agent;
 
+#[cfg(test)]
+mod custom_tests;
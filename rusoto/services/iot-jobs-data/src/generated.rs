@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeJobExecutionRequest {
@@ -42,6 +43,64 @@ pub struct DescribeJobExecutionRequest {
     pub thing_name: String,
 }
 
+impl DescribeJobExecutionRequest {
+    /// Returns a builder for `DescribeJobExecutionRequest`, checking its required members when built.
+    pub fn builder() -> DescribeJobExecutionRequestBuilder {
+        DescribeJobExecutionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeJobExecutionRequest`](struct.DescribeJobExecutionRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeJobExecutionRequestBuilder {
+    execution_number: Option<i64>,
+    include_job_document: Option<bool>,
+    job_id: Option<String>,
+    thing_name: Option<String>,
+}
+
+impl DescribeJobExecutionRequestBuilder {
+    /// Sets the [`execution_number`](struct.DescribeJobExecutionRequest.html#structfield.execution_number) member.
+    pub fn execution_number<T: Into<i64>>(mut self, value: T) -> Self {
+        self.execution_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_job_document`](struct.DescribeJobExecutionRequest.html#structfield.include_job_document) member.
+    pub fn include_job_document<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_job_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.DescribeJobExecutionRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.DescribeJobExecutionRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeJobExecutionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeJobExecutionRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeJobExecutionRequest {
+            execution_number: self.execution_number,
+            include_job_document: self.include_job_document,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeJobExecutionResponse {
@@ -58,6 +117,38 @@ pub struct GetPendingJobExecutionsRequest {
     pub thing_name: String,
 }
 
+impl GetPendingJobExecutionsRequest {
+    /// Returns a builder for `GetPendingJobExecutionsRequest`, checking its required members when built.
+    pub fn builder() -> GetPendingJobExecutionsRequestBuilder {
+        GetPendingJobExecutionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPendingJobExecutionsRequest`](struct.GetPendingJobExecutionsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPendingJobExecutionsRequestBuilder {
+    thing_name: Option<String>,
+}
+
+impl GetPendingJobExecutionsRequestBuilder {
+    /// Sets the [`thing_name`](struct.GetPendingJobExecutionsRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPendingJobExecutionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetPendingJobExecutionsRequest, ::rusoto_core::BuildError> {
+        Ok(GetPendingJobExecutionsRequest {
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPendingJobExecutionsResponse {
@@ -106,7 +197,7 @@ pub struct JobExecution {
     /// <p>The status of the job execution. Can be one of: "QUEUED", "IN_PROGRESS", "FAILED", "SUCCESS", "CANCELED", "REJECTED", or "REMOVED".</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobExecutionStatus>,
     /// <p>A collection of name/value pairs that describe the status of the job execution.</p>
     #[serde(rename = "statusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -128,7 +219,7 @@ pub struct JobExecutionState {
     /// <p>The status of the job execution. Can be one of: "QUEUED", "IN_PROGRESS", "FAILED", "SUCCESS", "CANCELED", "REJECTED", or "REMOVED".</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobExecutionStatus>,
     /// <p>A collection of name/value pairs that describe the status of the job execution.</p>
     #[serde(rename = "statusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -139,6 +230,108 @@ pub struct JobExecutionState {
     pub version_number: Option<i64>,
 }
 
+/// <p>The status of the job execution. Can be one of: "QUEUED", "IN_PROGRESS", "FAILED", "SUCCESS", "CANCELED", "REJECTED", or "REMOVED".</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobExecutionStatus {
+    /// <p><code>QUEUED</code></p>
+    Queued,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>TIMED_OUT</code></p>
+    TimedOut,
+    /// <p><code>REJECTED</code></p>
+    Rejected,
+    /// <p><code>REMOVED</code></p>
+    Removed,
+    /// <p><code>CANCELED</code></p>
+    Canceled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobExecutionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobExecutionStatus::Queued => "QUEUED",
+            JobExecutionStatus::InProgress => "IN_PROGRESS",
+            JobExecutionStatus::Succeeded => "SUCCEEDED",
+            JobExecutionStatus::Failed => "FAILED",
+            JobExecutionStatus::TimedOut => "TIMED_OUT",
+            JobExecutionStatus::Rejected => "REJECTED",
+            JobExecutionStatus::Removed => "REMOVED",
+            JobExecutionStatus::Canceled => "CANCELED",
+            JobExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobExecutionStatus {
+    fn from(value: &'a str) -> JobExecutionStatus {
+        match value {
+            "QUEUED" => JobExecutionStatus::Queued,
+            "IN_PROGRESS" => JobExecutionStatus::InProgress,
+            "SUCCEEDED" => JobExecutionStatus::Succeeded,
+            "FAILED" => JobExecutionStatus::Failed,
+            "TIMED_OUT" => JobExecutionStatus::TimedOut,
+            "REJECTED" => JobExecutionStatus::Rejected,
+            "REMOVED" => JobExecutionStatus::Removed,
+            "CANCELED" => JobExecutionStatus::Canceled,
+            _ => JobExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobExecutionStatus {
+    fn from(value: String) -> JobExecutionStatus {
+        JobExecutionStatus::from(value.as_str())
+    }
+}
+
+impl From<JobExecutionStatus> for String {
+    fn from(value: JobExecutionStatus) -> String {
+        match value {
+            JobExecutionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobExecutionStatus::from(value))
+    }
+}
+
 /// <p>Contains a subset of information about a job execution.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -184,6 +377,57 @@ pub struct StartNextPendingJobExecutionRequest {
     pub thing_name: String,
 }
 
+impl StartNextPendingJobExecutionRequest {
+    /// Returns a builder for `StartNextPendingJobExecutionRequest`, checking its required members when built.
+    pub fn builder() -> StartNextPendingJobExecutionRequestBuilder {
+        StartNextPendingJobExecutionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartNextPendingJobExecutionRequest`](struct.StartNextPendingJobExecutionRequest.html).
+#[derive(Default, Debug)]
+pub struct StartNextPendingJobExecutionRequestBuilder {
+    status_details: Option<::std::collections::HashMap<String, String>>,
+    step_timeout_in_minutes: Option<i64>,
+    thing_name: Option<String>,
+}
+
+impl StartNextPendingJobExecutionRequestBuilder {
+    /// Sets the [`status_details`](struct.StartNextPendingJobExecutionRequest.html#structfield.status_details) member.
+    pub fn status_details<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.status_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`step_timeout_in_minutes`](struct.StartNextPendingJobExecutionRequest.html#structfield.step_timeout_in_minutes) member.
+    pub fn step_timeout_in_minutes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.step_timeout_in_minutes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.StartNextPendingJobExecutionRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartNextPendingJobExecutionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartNextPendingJobExecutionRequest, ::rusoto_core::BuildError> {
+        Ok(StartNextPendingJobExecutionRequest {
+            status_details: self.status_details,
+            step_timeout_in_minutes: self.step_timeout_in_minutes,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartNextPendingJobExecutionResponse {
@@ -193,7 +437,7 @@ pub struct StartNextPendingJobExecutionResponse {
     pub execution: Option<JobExecution>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateJobExecutionRequest {
     /// <p>Optional. A number that identifies a particular job execution on a particular device.</p>
     #[serde(rename = "executionNumber")]
@@ -216,7 +460,7 @@ pub struct UpdateJobExecutionRequest {
     pub job_id: String,
     /// <p>The new status for the job execution (IN_PROGRESS, FAILED, SUCCESS, or REJECTED). This must be specified on every update.</p>
     #[serde(rename = "status")]
-    pub status: String,
+    pub status: JobExecutionStatus,
     /// <p> Optional. A collection of name/value pairs that describe the status of the job execution. If not specified, the statusDetails are unchanged.</p>
     #[serde(rename = "statusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -230,6 +474,109 @@ pub struct UpdateJobExecutionRequest {
     pub thing_name: String,
 }
 
+impl UpdateJobExecutionRequest {
+    /// Returns a builder for `UpdateJobExecutionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateJobExecutionRequestBuilder {
+        UpdateJobExecutionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateJobExecutionRequest`](struct.UpdateJobExecutionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateJobExecutionRequestBuilder {
+    execution_number: Option<i64>,
+    expected_version: Option<i64>,
+    include_job_document: Option<bool>,
+    include_job_execution_state: Option<bool>,
+    job_id: Option<String>,
+    status: Option<JobExecutionStatus>,
+    status_details: Option<::std::collections::HashMap<String, String>>,
+    step_timeout_in_minutes: Option<i64>,
+    thing_name: Option<String>,
+}
+
+impl UpdateJobExecutionRequestBuilder {
+    /// Sets the [`execution_number`](struct.UpdateJobExecutionRequest.html#structfield.execution_number) member.
+    pub fn execution_number<T: Into<i64>>(mut self, value: T) -> Self {
+        self.execution_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expected_version`](struct.UpdateJobExecutionRequest.html#structfield.expected_version) member.
+    pub fn expected_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expected_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_job_document`](struct.UpdateJobExecutionRequest.html#structfield.include_job_document) member.
+    pub fn include_job_document<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_job_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_job_execution_state`](struct.UpdateJobExecutionRequest.html#structfield.include_job_execution_state) member.
+    pub fn include_job_execution_state<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_job_execution_state = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.UpdateJobExecutionRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.UpdateJobExecutionRequest.html#structfield.status) member.
+    pub fn status<T: Into<JobExecutionStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_details`](struct.UpdateJobExecutionRequest.html#structfield.status_details) member.
+    pub fn status_details<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.status_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`step_timeout_in_minutes`](struct.UpdateJobExecutionRequest.html#structfield.step_timeout_in_minutes) member.
+    pub fn step_timeout_in_minutes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.step_timeout_in_minutes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.UpdateJobExecutionRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateJobExecutionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateJobExecutionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateJobExecutionRequest {
+            execution_number: self.execution_number,
+            expected_version: self.expected_version,
+            include_job_document: self.include_job_document,
+            include_job_execution_state: self.include_job_execution_state,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            status: self
+                .status
+                .ok_or(::rusoto_core::BuildError::missing("status"))?,
+            status_details: self.status_details,
+            step_timeout_in_minutes: self.step_timeout_in_minutes,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateJobExecutionResponse {
@@ -541,6 +888,39 @@ pub trait IotJobsData {
         input: UpdateJobExecutionRequest,
     ) -> RusotoFuture<UpdateJobExecutionResponse, UpdateJobExecutionError>;
 }
+impl<T: ?Sized + IotJobsData> IotJobsData for ::std::sync::Arc<T> {
+    fn describe_job_execution(
+        &self,
+        input: DescribeJobExecutionRequest,
+    ) -> RusotoFuture<DescribeJobExecutionResponse, DescribeJobExecutionError> {
+        (**self).describe_job_execution(input)
+    }
+    fn get_pending_job_executions(
+        &self,
+        input: GetPendingJobExecutionsRequest,
+    ) -> RusotoFuture<GetPendingJobExecutionsResponse, GetPendingJobExecutionsError> {
+        (**self).get_pending_job_executions(input)
+    }
+    fn start_next_pending_job_execution(
+        &self,
+        input: StartNextPendingJobExecutionRequest,
+    ) -> RusotoFuture<StartNextPendingJobExecutionResponse, StartNextPendingJobExecutionError> {
+        (**self).start_next_pending_job_execution(input)
+    }
+    fn update_job_execution(
+        &self,
+        input: UpdateJobExecutionRequest,
+    ) -> RusotoFuture<UpdateJobExecutionResponse, UpdateJobExecutionError> {
+        (**self).update_job_execution(input)
+    }
+}
+/// The error codes AWS IoT Jobs Data Plane retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS IoT Jobs Data Plane API.
 #[derive(Clone)]
 pub struct IotJobsDataClient {
@@ -556,6 +936,27 @@ impl IotJobsDataClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_IOT_JOBS_DATA`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> IotJobsDataClient {
+        Self::new(region::Region::from_env_for_service("IOT_JOBS_DATA"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `data.jobs.iot`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> IotJobsDataClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("data.jobs.iot", "IOT_JOBS_DATA"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -574,7 +975,54 @@ impl IotJobsDataClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> IotJobsDataClient {
-        IotJobsDataClient { client, region }
+        IotJobsDataClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "data.jobs.iot")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "data.jobs.iot",
+            "iot-jobs-data",
+            "IOT_JOBS_DATA",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `IotJobsData` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn IotJobsData + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for IotJobsDataClient {
+    fn service_name(&self) -> &'static str {
+        "AWS IoT Jobs Data Plane"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "data.jobs.iot"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> IotJobsDataClient {
+        Self::new_with_client(client, region)
     }
 }
 
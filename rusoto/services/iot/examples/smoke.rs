@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS IoT clients.
+//!
+//! Makes a single read-only `ListActiveViolations` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_iot::{Iot, IotClient, ListActiveViolationsRequest};
+
+fn main() {
+    let client = IotClient::from_env();
+    match client
+        .list_active_violations(ListActiveViolationsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListActiveViolations succeeded"),
+        Err(error) => {
+            eprintln!("ListActiveViolations failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
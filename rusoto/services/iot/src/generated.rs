@@ -23,7 +23,82 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The type of abort action to initiate a job abort.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AbortAction {
+    /// <p><code>CANCEL</code></p>
+    Cancel,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AbortAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AbortAction::Cancel => "CANCEL",
+            AbortAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AbortAction {
+    fn from(value: &'a str) -> AbortAction {
+        match value {
+            "CANCEL" => AbortAction::Cancel,
+            _ => AbortAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AbortAction {
+    fn from(value: String) -> AbortAction {
+        AbortAction::from(value.as_str())
+    }
+}
+
+impl From<AbortAction> for String {
+    fn from(value: AbortAction) -> String {
+        match value {
+            AbortAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AbortAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AbortAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AbortAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AbortAction {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AbortAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AbortAction::from(value))
+    }
+}
+
 /// <p>Details of abort criteria to abort the job.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AbortConfig {
@@ -33,14 +108,14 @@ pub struct AbortConfig {
 }
 
 /// <p>Details of abort criteria to define rules to abort the job.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AbortCriteria {
     /// <p>The type of abort action to initiate a job abort.</p>
     #[serde(rename = "action")]
-    pub action: String,
+    pub action: AbortAction,
     /// <p>The type of job execution failure to define a rule to initiate a job abort.</p>
     #[serde(rename = "failureType")]
-    pub failure_type: String,
+    pub failure_type: JobExecutionFailureType,
     /// <p>Minimum number of executed things before evaluating an abort rule.</p>
     #[serde(rename = "minNumberOfExecutedThings")]
     pub min_number_of_executed_things: i64,
@@ -61,6 +136,46 @@ pub struct AcceptCertificateTransferRequest {
     pub set_as_active: Option<bool>,
 }
 
+impl AcceptCertificateTransferRequest {
+    /// Returns a builder for `AcceptCertificateTransferRequest`, checking its required members when built.
+    pub fn builder() -> AcceptCertificateTransferRequestBuilder {
+        AcceptCertificateTransferRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AcceptCertificateTransferRequest`](struct.AcceptCertificateTransferRequest.html).
+#[derive(Default, Debug)]
+pub struct AcceptCertificateTransferRequestBuilder {
+    certificate_id: Option<String>,
+    set_as_active: Option<bool>,
+}
+
+impl AcceptCertificateTransferRequestBuilder {
+    /// Sets the [`certificate_id`](struct.AcceptCertificateTransferRequest.html#structfield.certificate_id) member.
+    pub fn certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`set_as_active`](struct.AcceptCertificateTransferRequest.html#structfield.set_as_active) member.
+    pub fn set_as_active<T: Into<bool>>(mut self, value: T) -> Self {
+        self.set_as_active = Some(value.into());
+        self
+    }
+
+    /// Builds the `AcceptCertificateTransferRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AcceptCertificateTransferRequest, ::rusoto_core::BuildError> {
+        Ok(AcceptCertificateTransferRequest {
+            certificate_id: self
+                .certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("certificate_id"))?,
+            set_as_active: self.set_as_active,
+        })
+    }
+}
+
 /// <p>Describes the actions associated with a rule.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Action {
@@ -130,6 +245,92 @@ pub struct Action {
     pub step_functions: Option<StepFunctionsAction>,
 }
 
+/// <p>The type of action for which the principal is being authorized.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionType {
+    /// <p><code>PUBLISH</code></p>
+    Publish,
+    /// <p><code>SUBSCRIBE</code></p>
+    Subscribe,
+    /// <p><code>RECEIVE</code></p>
+    Receive,
+    /// <p><code>CONNECT</code></p>
+    Connect,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionType::Publish => "PUBLISH",
+            ActionType::Subscribe => "SUBSCRIBE",
+            ActionType::Receive => "RECEIVE",
+            ActionType::Connect => "CONNECT",
+            ActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionType {
+    fn from(value: &'a str) -> ActionType {
+        match value {
+            "PUBLISH" => ActionType::Publish,
+            "SUBSCRIBE" => ActionType::Subscribe,
+            "RECEIVE" => ActionType::Receive,
+            "CONNECT" => ActionType::Connect,
+            _ => ActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionType {
+    fn from(value: String) -> ActionType {
+        ActionType::from(value.as_str())
+    }
+}
+
+impl From<ActionType> for String {
+    fn from(value: ActionType) -> String {
+        match value {
+            ActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ActionType::from(value))
+    }
+}
+
 /// <p>Information about an active Device Defender security profile behavior violation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -140,8 +341,9 @@ pub struct ActiveViolation {
     pub behavior: Option<Behavior>,
     /// <p>The time the most recent violation occurred.</p>
     #[serde(rename = "lastViolationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_violation_time: Option<f64>,
+    pub last_violation_time: Option<EpochTimestamp>,
     /// <p>The value of the metric (the measurement) which caused the most recent violation.</p>
     #[serde(rename = "lastViolationValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -160,8 +362,9 @@ pub struct ActiveViolation {
     pub violation_id: Option<String>,
     /// <p>The time the violation started.</p>
     #[serde(rename = "violationStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub violation_start_time: Option<f64>,
+    pub violation_start_time: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -184,6 +387,60 @@ pub struct AddThingToBillingGroupRequest {
     pub thing_name: Option<String>,
 }
 
+impl AddThingToBillingGroupRequest {
+    /// Returns a builder for `AddThingToBillingGroupRequest`, checking its required members when built.
+    pub fn builder() -> AddThingToBillingGroupRequestBuilder {
+        AddThingToBillingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddThingToBillingGroupRequest`](struct.AddThingToBillingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct AddThingToBillingGroupRequestBuilder {
+    billing_group_arn: Option<String>,
+    billing_group_name: Option<String>,
+    thing_arn: Option<String>,
+    thing_name: Option<String>,
+}
+
+impl AddThingToBillingGroupRequestBuilder {
+    /// Sets the [`billing_group_arn`](struct.AddThingToBillingGroupRequest.html#structfield.billing_group_arn) member.
+    pub fn billing_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.billing_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`billing_group_name`](struct.AddThingToBillingGroupRequest.html#structfield.billing_group_name) member.
+    pub fn billing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.billing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_arn`](struct.AddThingToBillingGroupRequest.html#structfield.thing_arn) member.
+    pub fn thing_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.AddThingToBillingGroupRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddThingToBillingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddThingToBillingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(AddThingToBillingGroupRequest {
+            billing_group_arn: self.billing_group_arn,
+            billing_group_name: self.billing_group_name,
+            thing_arn: self.thing_arn,
+            thing_name: self.thing_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddThingToBillingGroupResponse {}
@@ -212,6 +469,68 @@ pub struct AddThingToThingGroupRequest {
     pub thing_name: Option<String>,
 }
 
+impl AddThingToThingGroupRequest {
+    /// Returns a builder for `AddThingToThingGroupRequest`, checking its required members when built.
+    pub fn builder() -> AddThingToThingGroupRequestBuilder {
+        AddThingToThingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddThingToThingGroupRequest`](struct.AddThingToThingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct AddThingToThingGroupRequestBuilder {
+    override_dynamic_groups: Option<bool>,
+    thing_arn: Option<String>,
+    thing_group_arn: Option<String>,
+    thing_group_name: Option<String>,
+    thing_name: Option<String>,
+}
+
+impl AddThingToThingGroupRequestBuilder {
+    /// Sets the [`override_dynamic_groups`](struct.AddThingToThingGroupRequest.html#structfield.override_dynamic_groups) member.
+    pub fn override_dynamic_groups<T: Into<bool>>(mut self, value: T) -> Self {
+        self.override_dynamic_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_arn`](struct.AddThingToThingGroupRequest.html#structfield.thing_arn) member.
+    pub fn thing_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_arn`](struct.AddThingToThingGroupRequest.html#structfield.thing_group_arn) member.
+    pub fn thing_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_name`](struct.AddThingToThingGroupRequest.html#structfield.thing_group_name) member.
+    pub fn thing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.AddThingToThingGroupRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddThingToThingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddThingToThingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(AddThingToThingGroupRequest {
+            override_dynamic_groups: self.override_dynamic_groups,
+            thing_arn: self.thing_arn,
+            thing_group_arn: self.thing_group_arn,
+            thing_group_name: self.thing_group_name,
+            thing_name: self.thing_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddThingToThingGroupResponse {}
@@ -239,6 +558,80 @@ pub struct AlertTarget {
     pub role_arn: String,
 }
 
+/// <p>The type of alert target: one of "SNS".</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AlertTargetType {
+    /// <p><code>SNS</code></p>
+    Sns,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AlertTargetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AlertTargetType::Sns => "SNS",
+            AlertTargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AlertTargetType {
+    fn from(value: &'a str) -> AlertTargetType {
+        match value {
+            "SNS" => AlertTargetType::Sns,
+            _ => AlertTargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AlertTargetType {
+    fn from(value: String) -> AlertTargetType {
+        AlertTargetType::from(value.as_str())
+    }
+}
+
+impl From<AlertTargetType> for String {
+    fn from(value: AlertTargetType) -> String {
+        match value {
+            AlertTargetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AlertTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AlertTargetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AlertTargetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AlertTargetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AlertTargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AlertTargetType::from(value))
+    }
+}
+
 /// <p>Contains information that allowed the authorization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -263,6 +656,56 @@ pub struct AssociateTargetsWithJobRequest {
     pub targets: Vec<String>,
 }
 
+impl AssociateTargetsWithJobRequest {
+    /// Returns a builder for `AssociateTargetsWithJobRequest`, checking its required members when built.
+    pub fn builder() -> AssociateTargetsWithJobRequestBuilder {
+        AssociateTargetsWithJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateTargetsWithJobRequest`](struct.AssociateTargetsWithJobRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateTargetsWithJobRequestBuilder {
+    comment: Option<String>,
+    job_id: Option<String>,
+    targets: Option<Vec<String>>,
+}
+
+impl AssociateTargetsWithJobRequestBuilder {
+    /// Sets the [`comment`](struct.AssociateTargetsWithJobRequest.html#structfield.comment) member.
+    pub fn comment<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.AssociateTargetsWithJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`targets`](struct.AssociateTargetsWithJobRequest.html#structfield.targets) member.
+    pub fn targets<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.targets = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateTargetsWithJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateTargetsWithJobRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateTargetsWithJobRequest {
+            comment: self.comment,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            targets: self
+                .targets
+                .ok_or(::rusoto_core::BuildError::missing("targets"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateTargetsWithJobResponse {
@@ -290,6 +733,46 @@ pub struct AttachPolicyRequest {
     pub target: String,
 }
 
+impl AttachPolicyRequest {
+    /// Returns a builder for `AttachPolicyRequest`, checking its required members when built.
+    pub fn builder() -> AttachPolicyRequestBuilder {
+        AttachPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachPolicyRequest`](struct.AttachPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachPolicyRequestBuilder {
+    policy_name: Option<String>,
+    target: Option<String>,
+}
+
+impl AttachPolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.AttachPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target`](struct.AttachPolicyRequest.html#structfield.target) member.
+    pub fn target<T: Into<String>>(mut self, value: T) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AttachPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(AttachPolicyRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            target: self
+                .target
+                .ok_or(::rusoto_core::BuildError::missing("target"))?,
+        })
+    }
+}
+
 /// <p>The input for the AttachPrincipalPolicy operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AttachPrincipalPolicyRequest {
@@ -301,6 +784,48 @@ pub struct AttachPrincipalPolicyRequest {
     pub principal: String,
 }
 
+impl AttachPrincipalPolicyRequest {
+    /// Returns a builder for `AttachPrincipalPolicyRequest`, checking its required members when built.
+    pub fn builder() -> AttachPrincipalPolicyRequestBuilder {
+        AttachPrincipalPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachPrincipalPolicyRequest`](struct.AttachPrincipalPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachPrincipalPolicyRequestBuilder {
+    policy_name: Option<String>,
+    principal: Option<String>,
+}
+
+impl AttachPrincipalPolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.AttachPrincipalPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.AttachPrincipalPolicyRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachPrincipalPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachPrincipalPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(AttachPrincipalPolicyRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            principal: self
+                .principal
+                .ok_or(::rusoto_core::BuildError::missing("principal"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AttachSecurityProfileRequest {
     /// <p>The security profile that is attached.</p>
@@ -311,6 +836,48 @@ pub struct AttachSecurityProfileRequest {
     pub security_profile_target_arn: String,
 }
 
+impl AttachSecurityProfileRequest {
+    /// Returns a builder for `AttachSecurityProfileRequest`, checking its required members when built.
+    pub fn builder() -> AttachSecurityProfileRequestBuilder {
+        AttachSecurityProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachSecurityProfileRequest`](struct.AttachSecurityProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachSecurityProfileRequestBuilder {
+    security_profile_name: Option<String>,
+    security_profile_target_arn: Option<String>,
+}
+
+impl AttachSecurityProfileRequestBuilder {
+    /// Sets the [`security_profile_name`](struct.AttachSecurityProfileRequest.html#structfield.security_profile_name) member.
+    pub fn security_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_target_arn`](struct.AttachSecurityProfileRequest.html#structfield.security_profile_target_arn) member.
+    pub fn security_profile_target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_target_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachSecurityProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachSecurityProfileRequest, ::rusoto_core::BuildError> {
+        Ok(AttachSecurityProfileRequest {
+            security_profile_name: self
+                .security_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("security_profile_name"))?,
+            security_profile_target_arn: self.security_profile_target_arn.ok_or(
+                ::rusoto_core::BuildError::missing("security_profile_target_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AttachSecurityProfileResponse {}
@@ -326,6 +893,48 @@ pub struct AttachThingPrincipalRequest {
     pub thing_name: String,
 }
 
+impl AttachThingPrincipalRequest {
+    /// Returns a builder for `AttachThingPrincipalRequest`, checking its required members when built.
+    pub fn builder() -> AttachThingPrincipalRequestBuilder {
+        AttachThingPrincipalRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachThingPrincipalRequest`](struct.AttachThingPrincipalRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachThingPrincipalRequestBuilder {
+    principal: Option<String>,
+    thing_name: Option<String>,
+}
+
+impl AttachThingPrincipalRequestBuilder {
+    /// Sets the [`principal`](struct.AttachThingPrincipalRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.AttachThingPrincipalRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachThingPrincipalRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachThingPrincipalRequest, ::rusoto_core::BuildError> {
+        Ok(AttachThingPrincipalRequest {
+            principal: self
+                .principal
+                .ok_or(::rusoto_core::BuildError::missing("principal"))?,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 /// <p>The output from the AttachThingPrincipal operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -364,7 +973,7 @@ pub struct AuditCheckDetails {
     /// <p>The completion status of this check. One of "IN_PROGRESS", "WAITING_FOR_DATA_COLLECTION", "CANCELED", "COMPLETED_COMPLIANT", "COMPLETED_NON_COMPLIANT", or "FAILED".</p>
     #[serde(rename = "checkRunStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub check_run_status: Option<String>,
+    pub check_run_status: Option<AuditCheckRunStatus>,
     /// <p>The code of any error encountered when this check is performed during this audit. One of "INSUFFICIENT_PERMISSIONS" or "AUDIT_CHECK_DISABLED".</p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -383,6 +992,100 @@ pub struct AuditCheckDetails {
     pub total_resources_count: Option<i64>,
 }
 
+/// <p>The completion status of this check. One of "IN_PROGRESS", "WAITING_FOR_DATA_COLLECTION", "CANCELED", "COMPLETED_COMPLIANT", "COMPLETED_NON_COMPLIANT", or "FAILED".</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuditCheckRunStatus {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>WAITING_FOR_DATA_COLLECTION</code></p>
+    WaitingForDataCollection,
+    /// <p><code>CANCELED</code></p>
+    Canceled,
+    /// <p><code>COMPLETED_COMPLIANT</code></p>
+    CompletedCompliant,
+    /// <p><code>COMPLETED_NON_COMPLIANT</code></p>
+    CompletedNonCompliant,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuditCheckRunStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditCheckRunStatus::InProgress => "IN_PROGRESS",
+            AuditCheckRunStatus::WaitingForDataCollection => "WAITING_FOR_DATA_COLLECTION",
+            AuditCheckRunStatus::Canceled => "CANCELED",
+            AuditCheckRunStatus::CompletedCompliant => "COMPLETED_COMPLIANT",
+            AuditCheckRunStatus::CompletedNonCompliant => "COMPLETED_NON_COMPLIANT",
+            AuditCheckRunStatus::Failed => "FAILED",
+            AuditCheckRunStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuditCheckRunStatus {
+    fn from(value: &'a str) -> AuditCheckRunStatus {
+        match value {
+            "IN_PROGRESS" => AuditCheckRunStatus::InProgress,
+            "WAITING_FOR_DATA_COLLECTION" => AuditCheckRunStatus::WaitingForDataCollection,
+            "CANCELED" => AuditCheckRunStatus::Canceled,
+            "COMPLETED_COMPLIANT" => AuditCheckRunStatus::CompletedCompliant,
+            "COMPLETED_NON_COMPLIANT" => AuditCheckRunStatus::CompletedNonCompliant,
+            "FAILED" => AuditCheckRunStatus::Failed,
+            _ => AuditCheckRunStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditCheckRunStatus {
+    fn from(value: String) -> AuditCheckRunStatus {
+        AuditCheckRunStatus::from(value.as_str())
+    }
+}
+
+impl From<AuditCheckRunStatus> for String {
+    fn from(value: AuditCheckRunStatus) -> String {
+        match value {
+            AuditCheckRunStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuditCheckRunStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditCheckRunStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditCheckRunStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditCheckRunStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuditCheckRunStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuditCheckRunStatus::from(value))
+    }
+}
+
 /// <p>The findings (results) of the audit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -397,8 +1100,9 @@ pub struct AuditFinding {
     pub finding_id: Option<String>,
     /// <p>The time the result (finding) was discovered.</p>
     #[serde(rename = "findingTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finding_time: Option<f64>,
+    pub finding_time: Option<EpochTimestamp>,
     /// <p>The resource that was found to be noncompliant with the audit check.</p>
     #[serde(rename = "nonCompliantResource")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -418,22 +1122,195 @@ pub struct AuditFinding {
     /// <p>The severity of the result (finding).</p>
     #[serde(rename = "severity")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub severity: Option<String>,
+    pub severity: Option<AuditFindingSeverity>,
     /// <p>The ID of the audit that generated this result (finding).</p>
     #[serde(rename = "taskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub task_id: Option<String>,
     /// <p>The time the audit started.</p>
     #[serde(rename = "taskStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_start_time: Option<f64>,
+    pub task_start_time: Option<EpochTimestamp>,
 }
 
-/// <p>Returned by ListAuditMitigationActionsTask, this object contains information that describes a mitigation action that has been started.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct AuditMitigationActionExecutionMetadata {
-    /// <p>The unique identifier for the mitigation action being applied by the task.</p>
+/// <p>The severity of the result (finding).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuditFindingSeverity {
+    /// <p><code>CRITICAL</code></p>
+    Critical,
+    /// <p><code>HIGH</code></p>
+    High,
+    /// <p><code>MEDIUM</code></p>
+    Medium,
+    /// <p><code>LOW</code></p>
+    Low,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuditFindingSeverity {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditFindingSeverity::Critical => "CRITICAL",
+            AuditFindingSeverity::High => "HIGH",
+            AuditFindingSeverity::Medium => "MEDIUM",
+            AuditFindingSeverity::Low => "LOW",
+            AuditFindingSeverity::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuditFindingSeverity {
+    fn from(value: &'a str) -> AuditFindingSeverity {
+        match value {
+            "CRITICAL" => AuditFindingSeverity::Critical,
+            "HIGH" => AuditFindingSeverity::High,
+            "MEDIUM" => AuditFindingSeverity::Medium,
+            "LOW" => AuditFindingSeverity::Low,
+            _ => AuditFindingSeverity::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditFindingSeverity {
+    fn from(value: String) -> AuditFindingSeverity {
+        AuditFindingSeverity::from(value.as_str())
+    }
+}
+
+impl From<AuditFindingSeverity> for String {
+    fn from(value: AuditFindingSeverity) -> String {
+        match value {
+            AuditFindingSeverity::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuditFindingSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditFindingSeverity {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditFindingSeverity {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditFindingSeverity {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuditFindingSeverity, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuditFindingSeverity::from(value))
+    }
+}
+
+/// <p>How often the scheduled audit takes place. Can be one of "DAILY", "WEEKLY", "BIWEEKLY" or "MONTHLY". The start time of each audit is determined by the system.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuditFrequency {
+    /// <p><code>DAILY</code></p>
+    Daily,
+    /// <p><code>WEEKLY</code></p>
+    Weekly,
+    /// <p><code>BIWEEKLY</code></p>
+    Biweekly,
+    /// <p><code>MONTHLY</code></p>
+    Monthly,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuditFrequency {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditFrequency::Daily => "DAILY",
+            AuditFrequency::Weekly => "WEEKLY",
+            AuditFrequency::Biweekly => "BIWEEKLY",
+            AuditFrequency::Monthly => "MONTHLY",
+            AuditFrequency::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuditFrequency {
+    fn from(value: &'a str) -> AuditFrequency {
+        match value {
+            "DAILY" => AuditFrequency::Daily,
+            "WEEKLY" => AuditFrequency::Weekly,
+            "BIWEEKLY" => AuditFrequency::Biweekly,
+            "MONTHLY" => AuditFrequency::Monthly,
+            _ => AuditFrequency::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditFrequency {
+    fn from(value: String) -> AuditFrequency {
+        AuditFrequency::from(value.as_str())
+    }
+}
+
+impl From<AuditFrequency> for String {
+    fn from(value: AuditFrequency) -> String {
+        match value {
+            AuditFrequency::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuditFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditFrequency {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditFrequency {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditFrequency {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuditFrequency, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuditFrequency::from(value))
+    }
+}
+
+/// <p>Returned by ListAuditMitigationActionsTask, this object contains information that describes a mitigation action that has been started.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AuditMitigationActionExecutionMetadata {
+    /// <p>The unique identifier for the mitigation action being applied by the task.</p>
     #[serde(rename = "actionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub action_id: Option<String>,
@@ -443,8 +1320,9 @@ pub struct AuditMitigationActionExecutionMetadata {
     pub action_name: Option<String>,
     /// <p>The date and time when the task was completed or canceled. Blank if the task is still running.</p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>If an error occurred, the code that indicates which type of error occurred.</p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -459,26 +1337,124 @@ pub struct AuditMitigationActionExecutionMetadata {
     pub message: Option<String>,
     /// <p>The date and time when the task was started.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>The current status of the task being executed.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AuditMitigationActionsExecutionStatus>,
     /// <p>The unique identifier for the task that applies the mitigation action.</p>
     #[serde(rename = "taskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub task_id: Option<String>,
 }
 
+/// <p>The current status of the task being executed.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuditMitigationActionsExecutionStatus {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>CANCELED</code></p>
+    Canceled,
+    /// <p><code>SKIPPED</code></p>
+    Skipped,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuditMitigationActionsExecutionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditMitigationActionsExecutionStatus::InProgress => "IN_PROGRESS",
+            AuditMitigationActionsExecutionStatus::Completed => "COMPLETED",
+            AuditMitigationActionsExecutionStatus::Failed => "FAILED",
+            AuditMitigationActionsExecutionStatus::Canceled => "CANCELED",
+            AuditMitigationActionsExecutionStatus::Skipped => "SKIPPED",
+            AuditMitigationActionsExecutionStatus::Pending => "PENDING",
+            AuditMitigationActionsExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuditMitigationActionsExecutionStatus {
+    fn from(value: &'a str) -> AuditMitigationActionsExecutionStatus {
+        match value {
+            "IN_PROGRESS" => AuditMitigationActionsExecutionStatus::InProgress,
+            "COMPLETED" => AuditMitigationActionsExecutionStatus::Completed,
+            "FAILED" => AuditMitigationActionsExecutionStatus::Failed,
+            "CANCELED" => AuditMitigationActionsExecutionStatus::Canceled,
+            "SKIPPED" => AuditMitigationActionsExecutionStatus::Skipped,
+            "PENDING" => AuditMitigationActionsExecutionStatus::Pending,
+            _ => AuditMitigationActionsExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditMitigationActionsExecutionStatus {
+    fn from(value: String) -> AuditMitigationActionsExecutionStatus {
+        AuditMitigationActionsExecutionStatus::from(value.as_str())
+    }
+}
+
+impl From<AuditMitigationActionsExecutionStatus> for String {
+    fn from(value: AuditMitigationActionsExecutionStatus) -> String {
+        match value {
+            AuditMitigationActionsExecutionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuditMitigationActionsExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditMitigationActionsExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditMitigationActionsExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditMitigationActionsExecutionStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<AuditMitigationActionsExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuditMitigationActionsExecutionStatus::from(value))
+    }
+}
+
 /// <p>Information about an audit mitigation actions task that is returned by <code>ListAuditMitigationActionsTasks</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AuditMitigationActionsTaskMetadata {
     /// <p>The time at which the audit mitigation actions task was started.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>The unique identifier for the task.</p>
     #[serde(rename = "taskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -486,7 +1462,95 @@ pub struct AuditMitigationActionsTaskMetadata {
     /// <p>The current state of the audit mitigation actions task.</p>
     #[serde(rename = "taskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<AuditMitigationActionsTaskStatus>,
+}
+
+/// <p>The current state of the audit mitigation actions task.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuditMitigationActionsTaskStatus {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>CANCELED</code></p>
+    Canceled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuditMitigationActionsTaskStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditMitigationActionsTaskStatus::InProgress => "IN_PROGRESS",
+            AuditMitigationActionsTaskStatus::Completed => "COMPLETED",
+            AuditMitigationActionsTaskStatus::Failed => "FAILED",
+            AuditMitigationActionsTaskStatus::Canceled => "CANCELED",
+            AuditMitigationActionsTaskStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuditMitigationActionsTaskStatus {
+    fn from(value: &'a str) -> AuditMitigationActionsTaskStatus {
+        match value {
+            "IN_PROGRESS" => AuditMitigationActionsTaskStatus::InProgress,
+            "COMPLETED" => AuditMitigationActionsTaskStatus::Completed,
+            "FAILED" => AuditMitigationActionsTaskStatus::Failed,
+            "CANCELED" => AuditMitigationActionsTaskStatus::Canceled,
+            _ => AuditMitigationActionsTaskStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditMitigationActionsTaskStatus {
+    fn from(value: String) -> AuditMitigationActionsTaskStatus {
+        AuditMitigationActionsTaskStatus::from(value.as_str())
+    }
+}
+
+impl From<AuditMitigationActionsTaskStatus> for String {
+    fn from(value: AuditMitigationActionsTaskStatus) -> String {
+        match value {
+            AuditMitigationActionsTaskStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuditMitigationActionsTaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditMitigationActionsTaskStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditMitigationActionsTaskStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditMitigationActionsTaskStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<AuditMitigationActionsTaskStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuditMitigationActionsTaskStatus::from(value))
+    }
 }
 
 /// <p>Used in MitigationActionParams, this information identifies the target findings to which the mitigation actions are applied. Only one entry appears.</p>
@@ -523,6 +1587,79 @@ pub struct AuditNotificationTarget {
     pub target_arn: Option<String>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuditNotificationType {
+    /// <p><code>SNS</code></p>
+    Sns,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuditNotificationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditNotificationType::Sns => "SNS",
+            AuditNotificationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuditNotificationType {
+    fn from(value: &'a str) -> AuditNotificationType {
+        match value {
+            "SNS" => AuditNotificationType::Sns,
+            _ => AuditNotificationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditNotificationType {
+    fn from(value: String) -> AuditNotificationType {
+        AuditNotificationType::from(value.as_str())
+    }
+}
+
+impl From<AuditNotificationType> for String {
+    fn from(value: AuditNotificationType) -> String {
+        match value {
+            AuditNotificationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuditNotificationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditNotificationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditNotificationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditNotificationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuditNotificationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuditNotificationType::from(value))
+    }
+}
+
 /// <p>The audits that were performed.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -534,97 +1671,423 @@ pub struct AuditTaskMetadata {
     /// <p>The status of this audit. One of "IN_PROGRESS", "COMPLETED", "FAILED", or "CANCELED".</p>
     #[serde(rename = "taskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<AuditTaskStatus>,
     /// <p>The type of this audit. One of "ON_DEMAND_AUDIT_TASK" or "SCHEDULED_AUDIT_TASK".</p>
     #[serde(rename = "taskType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_type: Option<String>,
+    pub task_type: Option<AuditTaskType>,
 }
 
-/// <p>A collection of authorization information.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct AuthInfo {
-    /// <p>The type of action for which the principal is being authorized.</p>
-    #[serde(rename = "actionType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_type: Option<String>,
-    /// <p>The resources for which the principal is being authorized to perform the specified action.</p>
-    #[serde(rename = "resources")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resources: Option<Vec<String>>,
+/// <p>The status of this audit. One of "IN_PROGRESS", "COMPLETED", "FAILED", or "CANCELED".</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuditTaskStatus {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>CANCELED</code></p>
+    Canceled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>The authorizer result.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct AuthResult {
-    /// <p>The policies and statements that allowed the specified action.</p>
-    #[serde(rename = "allowed")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed: Option<Allowed>,
-    /// <p>The final authorization decision of this scenario. Multiple statements are taken into account when determining the authorization decision. An explicit deny statement can override multiple allow statements.</p>
-    #[serde(rename = "authDecision")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth_decision: Option<String>,
-    /// <p>Authorization information.</p>
-    #[serde(rename = "authInfo")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth_info: Option<AuthInfo>,
-    /// <p>The policies and statements that denied the specified action.</p>
-    #[serde(rename = "denied")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub denied: Option<Denied>,
-    /// <p>Contains any missing context values found while evaluating policy.</p>
-    #[serde(rename = "missingContextValues")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub missing_context_values: Option<Vec<String>>,
+impl AuditTaskStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditTaskStatus::InProgress => "IN_PROGRESS",
+            AuditTaskStatus::Completed => "COMPLETED",
+            AuditTaskStatus::Failed => "FAILED",
+            AuditTaskStatus::Canceled => "CANCELED",
+            AuditTaskStatus::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>The authorizer description.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct AuthorizerDescription {
-    /// <p>The authorizer ARN.</p>
-    #[serde(rename = "authorizerArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_arn: Option<String>,
-    /// <p>The authorizer's Lambda function ARN.</p>
-    #[serde(rename = "authorizerFunctionArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_function_arn: Option<String>,
-    /// <p>The authorizer name.</p>
-    #[serde(rename = "authorizerName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_name: Option<String>,
-    /// <p>The UNIX timestamp of when the authorizer was created.</p>
-    #[serde(rename = "creationDate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
-    /// <p>The UNIX timestamp of when the authorizer was last updated.</p>
-    #[serde(rename = "lastModifiedDate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
-    /// <p>The status of the authorizer.</p>
-    #[serde(rename = "status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    /// <p>The key used to extract the token from the HTTP headers.</p>
-    #[serde(rename = "tokenKeyName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub token_key_name: Option<String>,
-    /// <p>The public keys used to validate the token signature returned by your custom authentication service.</p>
-    #[serde(rename = "tokenSigningPublicKeys")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub token_signing_public_keys: Option<::std::collections::HashMap<String, String>>,
+impl<'a> From<&'a str> for AuditTaskStatus {
+    fn from(value: &'a str) -> AuditTaskStatus {
+        match value {
+            "IN_PROGRESS" => AuditTaskStatus::InProgress,
+            "COMPLETED" => AuditTaskStatus::Completed,
+            "FAILED" => AuditTaskStatus::Failed,
+            "CANCELED" => AuditTaskStatus::Canceled,
+            _ => AuditTaskStatus::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>The authorizer summary.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct AuthorizerSummary {
-    /// <p>The authorizer ARN.</p>
-    #[serde(rename = "authorizerArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl From<String> for AuditTaskStatus {
+    fn from(value: String) -> AuditTaskStatus {
+        AuditTaskStatus::from(value.as_str())
+    }
+}
+
+impl From<AuditTaskStatus> for String {
+    fn from(value: AuditTaskStatus) -> String {
+        match value {
+            AuditTaskStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuditTaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditTaskStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditTaskStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditTaskStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuditTaskStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuditTaskStatus::from(value))
+    }
+}
+
+/// <p>The type of this audit. One of "ON_DEMAND_AUDIT_TASK" or "SCHEDULED_AUDIT_TASK".</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuditTaskType {
+    /// <p><code>ON_DEMAND_AUDIT_TASK</code></p>
+    OnDemandAuditTask,
+    /// <p><code>SCHEDULED_AUDIT_TASK</code></p>
+    ScheduledAuditTask,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuditTaskType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuditTaskType::OnDemandAuditTask => "ON_DEMAND_AUDIT_TASK",
+            AuditTaskType::ScheduledAuditTask => "SCHEDULED_AUDIT_TASK",
+            AuditTaskType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuditTaskType {
+    fn from(value: &'a str) -> AuditTaskType {
+        match value {
+            "ON_DEMAND_AUDIT_TASK" => AuditTaskType::OnDemandAuditTask,
+            "SCHEDULED_AUDIT_TASK" => AuditTaskType::ScheduledAuditTask,
+            _ => AuditTaskType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuditTaskType {
+    fn from(value: String) -> AuditTaskType {
+        AuditTaskType::from(value.as_str())
+    }
+}
+
+impl From<AuditTaskType> for String {
+    fn from(value: AuditTaskType) -> String {
+        match value {
+            AuditTaskType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuditTaskType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuditTaskType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuditTaskType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuditTaskType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuditTaskType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuditTaskType::from(value))
+    }
+}
+
+/// <p>The final authorization decision of this scenario. Multiple statements are taken into account when determining the authorization decision. An explicit deny statement can override multiple allow statements.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthDecision {
+    /// <p><code>ALLOWED</code></p>
+    Allowed,
+    /// <p><code>EXPLICIT_DENY</code></p>
+    ExplicitDeny,
+    /// <p><code>IMPLICIT_DENY</code></p>
+    ImplicitDeny,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthDecision {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthDecision::Allowed => "ALLOWED",
+            AuthDecision::ExplicitDeny => "EXPLICIT_DENY",
+            AuthDecision::ImplicitDeny => "IMPLICIT_DENY",
+            AuthDecision::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthDecision {
+    fn from(value: &'a str) -> AuthDecision {
+        match value {
+            "ALLOWED" => AuthDecision::Allowed,
+            "EXPLICIT_DENY" => AuthDecision::ExplicitDeny,
+            "IMPLICIT_DENY" => AuthDecision::ImplicitDeny,
+            _ => AuthDecision::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthDecision {
+    fn from(value: String) -> AuthDecision {
+        AuthDecision::from(value.as_str())
+    }
+}
+
+impl From<AuthDecision> for String {
+    fn from(value: AuthDecision) -> String {
+        match value {
+            AuthDecision::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthDecision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthDecision {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthDecision {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthDecision {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthDecision, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthDecision::from(value))
+    }
+}
+
+/// <p>A collection of authorization information.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthInfo {
+    /// <p>The type of action for which the principal is being authorized.</p>
+    #[serde(rename = "actionType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<ActionType>,
+    /// <p>The resources for which the principal is being authorized to perform the specified action.</p>
+    #[serde(rename = "resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<String>>,
+}
+
+/// <p>The authorizer result.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AuthResult {
+    /// <p>The policies and statements that allowed the specified action.</p>
+    #[serde(rename = "allowed")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed: Option<Allowed>,
+    /// <p>The final authorization decision of this scenario. Multiple statements are taken into account when determining the authorization decision. An explicit deny statement can override multiple allow statements.</p>
+    #[serde(rename = "authDecision")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_decision: Option<AuthDecision>,
+    /// <p>Authorization information.</p>
+    #[serde(rename = "authInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth_info: Option<AuthInfo>,
+    /// <p>The policies and statements that denied the specified action.</p>
+    #[serde(rename = "denied")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub denied: Option<Denied>,
+    /// <p>Contains any missing context values found while evaluating policy.</p>
+    #[serde(rename = "missingContextValues")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_context_values: Option<Vec<String>>,
+}
+
+/// <p>The authorizer description.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AuthorizerDescription {
+    /// <p>The authorizer ARN.</p>
+    #[serde(rename = "authorizerArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizer_arn: Option<String>,
+    /// <p>The authorizer's Lambda function ARN.</p>
+    #[serde(rename = "authorizerFunctionArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizer_function_arn: Option<String>,
+    /// <p>The authorizer name.</p>
+    #[serde(rename = "authorizerName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizer_name: Option<String>,
+    /// <p>The UNIX timestamp of when the authorizer was created.</p>
+    #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_date: Option<EpochTimestamp>,
+    /// <p>The UNIX timestamp of when the authorizer was last updated.</p>
+    #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified_date: Option<EpochTimestamp>,
+    /// <p>The status of the authorizer.</p>
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AuthorizerStatus>,
+    /// <p>The key used to extract the token from the HTTP headers.</p>
+    #[serde(rename = "tokenKeyName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_key_name: Option<String>,
+    /// <p>The public keys used to validate the token signature returned by your custom authentication service.</p>
+    #[serde(rename = "tokenSigningPublicKeys")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_signing_public_keys: Option<::std::collections::HashMap<String, String>>,
+}
+
+/// <p>The status of the authorizer.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AuthorizerStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AuthorizerStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AuthorizerStatus::Active => "ACTIVE",
+            AuthorizerStatus::Inactive => "INACTIVE",
+            AuthorizerStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AuthorizerStatus {
+    fn from(value: &'a str) -> AuthorizerStatus {
+        match value {
+            "ACTIVE" => AuthorizerStatus::Active,
+            "INACTIVE" => AuthorizerStatus::Inactive,
+            _ => AuthorizerStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AuthorizerStatus {
+    fn from(value: String) -> AuthorizerStatus {
+        AuthorizerStatus::from(value.as_str())
+    }
+}
+
+impl From<AuthorizerStatus> for String {
+    fn from(value: AuthorizerStatus) -> String {
+        match value {
+            AuthorizerStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AuthorizerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AuthorizerStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AuthorizerStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AuthorizerStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AuthorizerStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AuthorizerStatus::from(value))
+    }
+}
+
+/// <p>The authorizer summary.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct AuthorizerSummary {
+    /// <p>The authorizer ARN.</p>
+    #[serde(rename = "authorizerArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub authorizer_arn: Option<String>,
     /// <p>The authorizer name.</p>
     #[serde(rename = "authorizerName")]
@@ -632,6 +2095,84 @@ pub struct AuthorizerSummary {
     pub authorizer_name: Option<String>,
 }
 
+/// <p>Whether the CA certificate configured for auto registration of device certificates. Valid values are "ENABLE" and "DISABLE"</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AutoRegistrationStatus {
+    /// <p><code>ENABLE</code></p>
+    Enable,
+    /// <p><code>DISABLE</code></p>
+    Disable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AutoRegistrationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoRegistrationStatus::Enable => "ENABLE",
+            AutoRegistrationStatus::Disable => "DISABLE",
+            AutoRegistrationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AutoRegistrationStatus {
+    fn from(value: &'a str) -> AutoRegistrationStatus {
+        match value {
+            "ENABLE" => AutoRegistrationStatus::Enable,
+            "DISABLE" => AutoRegistrationStatus::Disable,
+            _ => AutoRegistrationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoRegistrationStatus {
+    fn from(value: String) -> AutoRegistrationStatus {
+        AutoRegistrationStatus::from(value.as_str())
+    }
+}
+
+impl From<AutoRegistrationStatus> for String {
+    fn from(value: AutoRegistrationStatus) -> String {
+        match value {
+            AutoRegistrationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AutoRegistrationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AutoRegistrationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AutoRegistrationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutoRegistrationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AutoRegistrationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AutoRegistrationStatus::from(value))
+    }
+}
+
 /// <p>Configuration for the rollout of OTA updates.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwsJobExecutionsRolloutConfig {
@@ -663,7 +2204,7 @@ pub struct BehaviorCriteria {
     /// <p>The operator that relates the thing measured (<code>metric</code>) to the criteria (containing a <code>value</code> or <code>statisticalThreshold</code>).</p>
     #[serde(rename = "comparisonOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison_operator: Option<String>,
+    pub comparison_operator: Option<ComparisonOperator>,
     /// <p>If a device is in violation of the behavior for the specified number of consecutive datapoints, an alarm occurs. If not specified, the default is 1.</p>
     #[serde(rename = "consecutiveDatapointsToAlarm")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -692,8 +2233,9 @@ pub struct BehaviorCriteria {
 pub struct BillingGroupMetadata {
     /// <p>The date the billing group was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
 }
 
 /// <p>The properties of a billing group.</p>
@@ -719,12 +2261,13 @@ pub struct CACertificate {
     pub certificate_id: Option<String>,
     /// <p>The date the CA certificate was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The status of the CA certificate.</p> <p>The status value REGISTER_INACTIVE is deprecated and should not be used.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CACertificateStatus>,
 }
 
 /// <p>Describes a CA certificate.</p>
@@ -734,7 +2277,7 @@ pub struct CACertificateDescription {
     /// <p>Whether the CA certificate configured for auto registration of device certificates. Valid values are "ENABLE" and "DISABLE"</p>
     #[serde(rename = "autoRegistrationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_registration_status: Option<String>,
+    pub auto_registration_status: Option<AutoRegistrationStatus>,
     /// <p>The CA certificate ARN.</p>
     #[serde(rename = "certificateArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -749,8 +2292,9 @@ pub struct CACertificateDescription {
     pub certificate_pem: Option<String>,
     /// <p>The date the CA certificate was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The customer version of the CA certificate.</p>
     #[serde(rename = "customerVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -761,8 +2305,9 @@ pub struct CACertificateDescription {
     pub generation_id: Option<String>,
     /// <p>The date the CA certificate was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The owner of the CA certificate.</p>
     #[serde(rename = "ownedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -770,31 +2315,246 @@ pub struct CACertificateDescription {
     /// <p>The status of a CA certificate.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CACertificateStatus>,
     /// <p>When the CA certificate is valid.</p>
     #[serde(rename = "validity")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub validity: Option<CertificateValidity>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct CancelAuditMitigationActionsTaskRequest {
-    /// <p>The unique identifier for the task that you want to cancel. </p>
-    #[serde(rename = "taskId")]
-    pub task_id: String,
+/// <p>The status of the CA certificate.</p> <p>The status value REGISTER_INACTIVE is deprecated and should not be used.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CACertificateStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CancelAuditMitigationActionsTaskResponse {}
+impl CACertificateStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CACertificateStatus::Active => "ACTIVE",
+            CACertificateStatus::Inactive => "INACTIVE",
+            CACertificateStatus::Unknown(ref value) => value,
+        }
+    }
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl<'a> From<&'a str> for CACertificateStatus {
+    fn from(value: &'a str) -> CACertificateStatus {
+        match value {
+            "ACTIVE" => CACertificateStatus::Active,
+            "INACTIVE" => CACertificateStatus::Inactive,
+            _ => CACertificateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CACertificateStatus {
+    fn from(value: String) -> CACertificateStatus {
+        CACertificateStatus::from(value.as_str())
+    }
+}
+
+impl From<CACertificateStatus> for String {
+    fn from(value: CACertificateStatus) -> String {
+        match value {
+            CACertificateStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CACertificateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CACertificateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CACertificateStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CACertificateStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CACertificateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CACertificateStatus::from(value))
+    }
+}
+
+/// <p>The action that you want to apply to the CA cerrtificate. The only supported value is <code>DEACTIVATE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CACertificateUpdateAction {
+    /// <p><code>DEACTIVATE</code></p>
+    Deactivate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CACertificateUpdateAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CACertificateUpdateAction::Deactivate => "DEACTIVATE",
+            CACertificateUpdateAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CACertificateUpdateAction {
+    fn from(value: &'a str) -> CACertificateUpdateAction {
+        match value {
+            "DEACTIVATE" => CACertificateUpdateAction::Deactivate,
+            _ => CACertificateUpdateAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CACertificateUpdateAction {
+    fn from(value: String) -> CACertificateUpdateAction {
+        CACertificateUpdateAction::from(value.as_str())
+    }
+}
+
+impl From<CACertificateUpdateAction> for String {
+    fn from(value: CACertificateUpdateAction) -> String {
+        match value {
+            CACertificateUpdateAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CACertificateUpdateAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CACertificateUpdateAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CACertificateUpdateAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CACertificateUpdateAction {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CACertificateUpdateAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CACertificateUpdateAction::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct CancelAuditMitigationActionsTaskRequest {
+    /// <p>The unique identifier for the task that you want to cancel. </p>
+    #[serde(rename = "taskId")]
+    pub task_id: String,
+}
+
+impl CancelAuditMitigationActionsTaskRequest {
+    /// Returns a builder for `CancelAuditMitigationActionsTaskRequest`, checking its required members when built.
+    pub fn builder() -> CancelAuditMitigationActionsTaskRequestBuilder {
+        CancelAuditMitigationActionsTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelAuditMitigationActionsTaskRequest`](struct.CancelAuditMitigationActionsTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelAuditMitigationActionsTaskRequestBuilder {
+    task_id: Option<String>,
+}
+
+impl CancelAuditMitigationActionsTaskRequestBuilder {
+    /// Sets the [`task_id`](struct.CancelAuditMitigationActionsTaskRequest.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelAuditMitigationActionsTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CancelAuditMitigationActionsTaskRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CancelAuditMitigationActionsTaskRequest {
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CancelAuditMitigationActionsTaskResponse {}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CancelAuditTaskRequest {
     /// <p>The ID of the audit you want to cancel. You can only cancel an audit that is "IN_PROGRESS".</p>
     #[serde(rename = "taskId")]
     pub task_id: String,
 }
 
+impl CancelAuditTaskRequest {
+    /// Returns a builder for `CancelAuditTaskRequest`, checking its required members when built.
+    pub fn builder() -> CancelAuditTaskRequestBuilder {
+        CancelAuditTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelAuditTaskRequest`](struct.CancelAuditTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelAuditTaskRequestBuilder {
+    task_id: Option<String>,
+}
+
+impl CancelAuditTaskRequestBuilder {
+    /// Sets the [`task_id`](struct.CancelAuditTaskRequest.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelAuditTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelAuditTaskRequest, ::rusoto_core::BuildError> {
+        Ok(CancelAuditTaskRequest {
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelAuditTaskResponse {}
@@ -807,6 +2567,38 @@ pub struct CancelCertificateTransferRequest {
     pub certificate_id: String,
 }
 
+impl CancelCertificateTransferRequest {
+    /// Returns a builder for `CancelCertificateTransferRequest`, checking its required members when built.
+    pub fn builder() -> CancelCertificateTransferRequestBuilder {
+        CancelCertificateTransferRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelCertificateTransferRequest`](struct.CancelCertificateTransferRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelCertificateTransferRequestBuilder {
+    certificate_id: Option<String>,
+}
+
+impl CancelCertificateTransferRequestBuilder {
+    /// Sets the [`certificate_id`](struct.CancelCertificateTransferRequest.html#structfield.certificate_id) member.
+    pub fn certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelCertificateTransferRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CancelCertificateTransferRequest, ::rusoto_core::BuildError> {
+        Ok(CancelCertificateTransferRequest {
+            certificate_id: self
+                .certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("certificate_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CancelJobExecutionRequest {
     /// <p>(Optional) The expected current version of the job execution. Each time you update the job execution, its version is incremented. If the version of the job execution stored in Jobs does not match, the update is rejected with a VersionMismatch error, and an ErrorResponse that contains the current job execution status data is returned. (This makes it unnecessary to perform a separate DescribeJobExecution request in order to obtain the job execution status data.)</p>
@@ -829,6 +2621,75 @@ pub struct CancelJobExecutionRequest {
     pub thing_name: String,
 }
 
+impl CancelJobExecutionRequest {
+    /// Returns a builder for `CancelJobExecutionRequest`, checking its required members when built.
+    pub fn builder() -> CancelJobExecutionRequestBuilder {
+        CancelJobExecutionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelJobExecutionRequest`](struct.CancelJobExecutionRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelJobExecutionRequestBuilder {
+    expected_version: Option<i64>,
+    force: Option<bool>,
+    job_id: Option<String>,
+    status_details: Option<::std::collections::HashMap<String, String>>,
+    thing_name: Option<String>,
+}
+
+impl CancelJobExecutionRequestBuilder {
+    /// Sets the [`expected_version`](struct.CancelJobExecutionRequest.html#structfield.expected_version) member.
+    pub fn expected_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expected_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force`](struct.CancelJobExecutionRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.CancelJobExecutionRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_details`](struct.CancelJobExecutionRequest.html#structfield.status_details) member.
+    pub fn status_details<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.status_details = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.CancelJobExecutionRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelJobExecutionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CancelJobExecutionRequest, ::rusoto_core::BuildError> {
+        Ok(CancelJobExecutionRequest {
+            expected_version: self.expected_version,
+            force: self.force,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            status_details: self.status_details,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CancelJobRequest {
     /// <p>An optional comment string describing why the job was canceled.</p>
@@ -848,6 +2709,60 @@ pub struct CancelJobRequest {
     pub reason_code: Option<String>,
 }
 
+impl CancelJobRequest {
+    /// Returns a builder for `CancelJobRequest`, checking its required members when built.
+    pub fn builder() -> CancelJobRequestBuilder {
+        CancelJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelJobRequest`](struct.CancelJobRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelJobRequestBuilder {
+    comment: Option<String>,
+    force: Option<bool>,
+    job_id: Option<String>,
+    reason_code: Option<String>,
+}
+
+impl CancelJobRequestBuilder {
+    /// Sets the [`comment`](struct.CancelJobRequest.html#structfield.comment) member.
+    pub fn comment<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force`](struct.CancelJobRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.CancelJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reason_code`](struct.CancelJobRequest.html#structfield.reason_code) member.
+    pub fn reason_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelJobRequest, ::rusoto_core::BuildError> {
+        Ok(CancelJobRequest {
+            comment: self.comment,
+            force: self.force,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            reason_code: self.reason_code,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelJobResponse {
@@ -865,6 +2780,108 @@ pub struct CancelJobResponse {
     pub job_id: Option<String>,
 }
 
+/// <p>The Amazon S3 canned ACL that controls access to the object identified by the object key. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#canned-acl">S3 canned ACLs</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CannedAccessControlList {
+    /// <p><code>private</code></p>
+    Private,
+    /// <p><code>public-read</code></p>
+    PublicRead,
+    /// <p><code>public-read-write</code></p>
+    PublicReadWrite,
+    /// <p><code>aws-exec-read</code></p>
+    AwsExecRead,
+    /// <p><code>authenticated-read</code></p>
+    AuthenticatedRead,
+    /// <p><code>bucket-owner-read</code></p>
+    BucketOwnerRead,
+    /// <p><code>bucket-owner-full-control</code></p>
+    BucketOwnerFullControl,
+    /// <p><code>log-delivery-write</code></p>
+    LogDeliveryWrite,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CannedAccessControlList {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CannedAccessControlList::Private => "private",
+            CannedAccessControlList::PublicRead => "public-read",
+            CannedAccessControlList::PublicReadWrite => "public-read-write",
+            CannedAccessControlList::AwsExecRead => "aws-exec-read",
+            CannedAccessControlList::AuthenticatedRead => "authenticated-read",
+            CannedAccessControlList::BucketOwnerRead => "bucket-owner-read",
+            CannedAccessControlList::BucketOwnerFullControl => "bucket-owner-full-control",
+            CannedAccessControlList::LogDeliveryWrite => "log-delivery-write",
+            CannedAccessControlList::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CannedAccessControlList {
+    fn from(value: &'a str) -> CannedAccessControlList {
+        match value {
+            "private" => CannedAccessControlList::Private,
+            "public-read" => CannedAccessControlList::PublicRead,
+            "public-read-write" => CannedAccessControlList::PublicReadWrite,
+            "aws-exec-read" => CannedAccessControlList::AwsExecRead,
+            "authenticated-read" => CannedAccessControlList::AuthenticatedRead,
+            "bucket-owner-read" => CannedAccessControlList::BucketOwnerRead,
+            "bucket-owner-full-control" => CannedAccessControlList::BucketOwnerFullControl,
+            "log-delivery-write" => CannedAccessControlList::LogDeliveryWrite,
+            _ => CannedAccessControlList::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CannedAccessControlList {
+    fn from(value: String) -> CannedAccessControlList {
+        CannedAccessControlList::from(value.as_str())
+    }
+}
+
+impl From<CannedAccessControlList> for String {
+    fn from(value: CannedAccessControlList) -> String {
+        match value {
+            CannedAccessControlList::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CannedAccessControlList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CannedAccessControlList {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CannedAccessControlList {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CannedAccessControlList {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CannedAccessControlList, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CannedAccessControlList::from(value))
+    }
+}
+
 /// <p>Information about a certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -879,12 +2896,13 @@ pub struct Certificate {
     pub certificate_id: Option<String>,
     /// <p>The date and time the certificate was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The status of the certificate.</p> <p>The status value REGISTER_INACTIVE is deprecated and should not be used.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CertificateStatus>,
 }
 
 /// <p>Describes a certificate.</p>
@@ -909,8 +2927,9 @@ pub struct CertificateDescription {
     pub certificate_pem: Option<String>,
     /// <p>The date and time the certificate was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The customer version of the certificate.</p>
     #[serde(rename = "customerVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -921,8 +2940,9 @@ pub struct CertificateDescription {
     pub generation_id: Option<String>,
     /// <p>The date and time the certificate was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The ID of the AWS account that owns the certificate.</p>
     #[serde(rename = "ownedBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -934,7 +2954,7 @@ pub struct CertificateDescription {
     /// <p>The status of the certificate.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CertificateStatus>,
     /// <p>The transfer data.</p>
     #[serde(rename = "transferData")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -945,18 +2965,114 @@ pub struct CertificateDescription {
     pub validity: Option<CertificateValidity>,
 }
 
+/// <p>The status of the certificate.</p> <p>The status value REGISTER_INACTIVE is deprecated and should not be used.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CertificateStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>REVOKED</code></p>
+    Revoked,
+    /// <p><code>PENDING_TRANSFER</code></p>
+    PendingTransfer,
+    /// <p><code>REGISTER_INACTIVE</code></p>
+    RegisterInactive,
+    /// <p><code>PENDING_ACTIVATION</code></p>
+    PendingActivation,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CertificateStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CertificateStatus::Active => "ACTIVE",
+            CertificateStatus::Inactive => "INACTIVE",
+            CertificateStatus::Revoked => "REVOKED",
+            CertificateStatus::PendingTransfer => "PENDING_TRANSFER",
+            CertificateStatus::RegisterInactive => "REGISTER_INACTIVE",
+            CertificateStatus::PendingActivation => "PENDING_ACTIVATION",
+            CertificateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CertificateStatus {
+    fn from(value: &'a str) -> CertificateStatus {
+        match value {
+            "ACTIVE" => CertificateStatus::Active,
+            "INACTIVE" => CertificateStatus::Inactive,
+            "REVOKED" => CertificateStatus::Revoked,
+            "PENDING_TRANSFER" => CertificateStatus::PendingTransfer,
+            "REGISTER_INACTIVE" => CertificateStatus::RegisterInactive,
+            "PENDING_ACTIVATION" => CertificateStatus::PendingActivation,
+            _ => CertificateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CertificateStatus {
+    fn from(value: String) -> CertificateStatus {
+        CertificateStatus::from(value.as_str())
+    }
+}
+
+impl From<CertificateStatus> for String {
+    fn from(value: CertificateStatus) -> String {
+        match value {
+            CertificateStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CertificateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for CertificateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for CertificateStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CertificateStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CertificateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CertificateStatus::from(value))
+    }
+}
+
 /// <p>When the certificate is valid.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CertificateValidity {
     /// <p>The certificate is not valid after this date.</p>
     #[serde(rename = "notAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_after: Option<f64>,
+    pub not_after: Option<EpochTimestamp>,
     /// <p>The certificate is not valid before this date.</p>
     #[serde(rename = "notBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_before: Option<f64>,
+    pub not_before: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1051,6 +3167,116 @@ pub struct CodeSigningSignature {
     pub inline_document: Option<bytes::Bytes>,
 }
 
+/// <p>The operator that relates the thing measured (<code>metric</code>) to the criteria (containing a <code>value</code> or <code>statisticalThreshold</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonOperator {
+    /// <p><code>less-than</code></p>
+    LessThan,
+    /// <p><code>less-than-equals</code></p>
+    LessThanEquals,
+    /// <p><code>greater-than</code></p>
+    GreaterThan,
+    /// <p><code>greater-than-equals</code></p>
+    GreaterThanEquals,
+    /// <p><code>in-cidr-set</code></p>
+    InCidrSet,
+    /// <p><code>not-in-cidr-set</code></p>
+    NotInCidrSet,
+    /// <p><code>in-port-set</code></p>
+    InPortSet,
+    /// <p><code>not-in-port-set</code></p>
+    NotInPortSet,
+    /// <p><code>in-set</code></p>
+    InSet,
+    /// <p><code>not-in-set</code></p>
+    NotInSet,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::LessThan => "less-than",
+            ComparisonOperator::LessThanEquals => "less-than-equals",
+            ComparisonOperator::GreaterThan => "greater-than",
+            ComparisonOperator::GreaterThanEquals => "greater-than-equals",
+            ComparisonOperator::InCidrSet => "in-cidr-set",
+            ComparisonOperator::NotInCidrSet => "not-in-cidr-set",
+            ComparisonOperator::InPortSet => "in-port-set",
+            ComparisonOperator::NotInPortSet => "not-in-port-set",
+            ComparisonOperator::InSet => "in-set",
+            ComparisonOperator::NotInSet => "not-in-set",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "less-than" => ComparisonOperator::LessThan,
+            "less-than-equals" => ComparisonOperator::LessThanEquals,
+            "greater-than" => ComparisonOperator::GreaterThan,
+            "greater-than-equals" => ComparisonOperator::GreaterThanEquals,
+            "in-cidr-set" => ComparisonOperator::InCidrSet,
+            "not-in-cidr-set" => ComparisonOperator::NotInCidrSet,
+            "in-port-set" => ComparisonOperator::InPortSet,
+            "not-in-port-set" => ComparisonOperator::NotInPortSet,
+            "in-set" => ComparisonOperator::InSet,
+            "not-in-set" => ComparisonOperator::NotInSet,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        ComparisonOperator::from(value.as_str())
+    }
+}
+
+impl From<ComparisonOperator> for String {
+    fn from(value: ComparisonOperator) -> String {
+        match value {
+            ComparisonOperator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ComparisonOperator::from(value))
+    }
+}
+
 /// <p>Configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Configuration {
@@ -1071,7 +3297,7 @@ pub struct CreateAuthorizerRequest {
     /// <p>The status of the create authorizer request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AuthorizerStatus>,
     /// <p>The name of the token key used to extract the token from the HTTP headers.</p>
     #[serde(rename = "tokenKeyName")]
     pub token_key_name: String,
@@ -1080,17 +3306,90 @@ pub struct CreateAuthorizerRequest {
     pub token_signing_public_keys: ::std::collections::HashMap<String, String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CreateAuthorizerResponse {
-    /// <p>The authorizer ARN.</p>
-    #[serde(rename = "authorizerArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_arn: Option<String>,
-    /// <p>The authorizer's name.</p>
-    #[serde(rename = "authorizerName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub authorizer_name: Option<String>,
+impl CreateAuthorizerRequest {
+    /// Returns a builder for `CreateAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> CreateAuthorizerRequestBuilder {
+        CreateAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAuthorizerRequest`](struct.CreateAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAuthorizerRequestBuilder {
+    authorizer_function_arn: Option<String>,
+    authorizer_name: Option<String>,
+    status: Option<AuthorizerStatus>,
+    token_key_name: Option<String>,
+    token_signing_public_keys: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateAuthorizerRequestBuilder {
+    /// Sets the [`authorizer_function_arn`](struct.CreateAuthorizerRequest.html#structfield.authorizer_function_arn) member.
+    pub fn authorizer_function_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_function_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_name`](struct.CreateAuthorizerRequest.html#structfield.authorizer_name) member.
+    pub fn authorizer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.CreateAuthorizerRequest.html#structfield.status) member.
+    pub fn status<T: Into<AuthorizerStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token_key_name`](struct.CreateAuthorizerRequest.html#structfield.token_key_name) member.
+    pub fn token_key_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.token_key_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token_signing_public_keys`](struct.CreateAuthorizerRequest.html#structfield.token_signing_public_keys) member.
+    pub fn token_signing_public_keys<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.token_signing_public_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAuthorizerRequest {
+            authorizer_function_arn: self.authorizer_function_arn.ok_or(
+                ::rusoto_core::BuildError::missing("authorizer_function_arn"),
+            )?,
+            authorizer_name: self
+                .authorizer_name
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_name"))?,
+            status: self.status,
+            token_key_name: self
+                .token_key_name
+                .ok_or(::rusoto_core::BuildError::missing("token_key_name"))?,
+            token_signing_public_keys: self.token_signing_public_keys.ok_or(
+                ::rusoto_core::BuildError::missing("token_signing_public_keys"),
+            )?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateAuthorizerResponse {
+    /// <p>The authorizer ARN.</p>
+    #[serde(rename = "authorizerArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizer_arn: Option<String>,
+    /// <p>The authorizer's name.</p>
+    #[serde(rename = "authorizerName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorizer_name: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1108,6 +3407,54 @@ pub struct CreateBillingGroupRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateBillingGroupRequest {
+    /// Returns a builder for `CreateBillingGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateBillingGroupRequestBuilder {
+        CreateBillingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBillingGroupRequest`](struct.CreateBillingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateBillingGroupRequestBuilder {
+    billing_group_name: Option<String>,
+    billing_group_properties: Option<BillingGroupProperties>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateBillingGroupRequestBuilder {
+    /// Sets the [`billing_group_name`](struct.CreateBillingGroupRequest.html#structfield.billing_group_name) member.
+    pub fn billing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.billing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`billing_group_properties`](struct.CreateBillingGroupRequest.html#structfield.billing_group_properties) member.
+    pub fn billing_group_properties<T: Into<BillingGroupProperties>>(mut self, value: T) -> Self {
+        self.billing_group_properties = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateBillingGroupRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBillingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateBillingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateBillingGroupRequest {
+            billing_group_name: self
+                .billing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("billing_group_name"))?,
+            billing_group_properties: self.billing_group_properties,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateBillingGroupResponse {
@@ -1137,6 +3484,46 @@ pub struct CreateCertificateFromCsrRequest {
     pub set_as_active: Option<bool>,
 }
 
+impl CreateCertificateFromCsrRequest {
+    /// Returns a builder for `CreateCertificateFromCsrRequest`, checking its required members when built.
+    pub fn builder() -> CreateCertificateFromCsrRequestBuilder {
+        CreateCertificateFromCsrRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCertificateFromCsrRequest`](struct.CreateCertificateFromCsrRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCertificateFromCsrRequestBuilder {
+    certificate_signing_request: Option<String>,
+    set_as_active: Option<bool>,
+}
+
+impl CreateCertificateFromCsrRequestBuilder {
+    /// Sets the [`certificate_signing_request`](struct.CreateCertificateFromCsrRequest.html#structfield.certificate_signing_request) member.
+    pub fn certificate_signing_request<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_signing_request = Some(value.into());
+        self
+    }
+
+    /// Sets the [`set_as_active`](struct.CreateCertificateFromCsrRequest.html#structfield.set_as_active) member.
+    pub fn set_as_active<T: Into<bool>>(mut self, value: T) -> Self {
+        self.set_as_active = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCertificateFromCsrRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCertificateFromCsrRequest, ::rusoto_core::BuildError> {
+        Ok(CreateCertificateFromCsrRequest {
+            certificate_signing_request: self.certificate_signing_request.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_signing_request"),
+            )?,
+            set_as_active: self.set_as_active,
+        })
+    }
+}
+
 /// <p>The output from the CreateCertificateFromCsr operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1181,6 +3568,80 @@ pub struct CreateDynamicThingGroupRequest {
     pub thing_group_properties: Option<ThingGroupProperties>,
 }
 
+impl CreateDynamicThingGroupRequest {
+    /// Returns a builder for `CreateDynamicThingGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateDynamicThingGroupRequestBuilder {
+        CreateDynamicThingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDynamicThingGroupRequest`](struct.CreateDynamicThingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDynamicThingGroupRequestBuilder {
+    index_name: Option<String>,
+    query_string: Option<String>,
+    query_version: Option<String>,
+    tags: Option<Vec<Tag>>,
+    thing_group_name: Option<String>,
+    thing_group_properties: Option<ThingGroupProperties>,
+}
+
+impl CreateDynamicThingGroupRequestBuilder {
+    /// Sets the [`index_name`](struct.CreateDynamicThingGroupRequest.html#structfield.index_name) member.
+    pub fn index_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.index_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_string`](struct.CreateDynamicThingGroupRequest.html#structfield.query_string) member.
+    pub fn query_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_version`](struct.CreateDynamicThingGroupRequest.html#structfield.query_version) member.
+    pub fn query_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDynamicThingGroupRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_name`](struct.CreateDynamicThingGroupRequest.html#structfield.thing_group_name) member.
+    pub fn thing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_properties`](struct.CreateDynamicThingGroupRequest.html#structfield.thing_group_properties) member.
+    pub fn thing_group_properties<T: Into<ThingGroupProperties>>(mut self, value: T) -> Self {
+        self.thing_group_properties = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDynamicThingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDynamicThingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDynamicThingGroupRequest {
+            index_name: self.index_name,
+            query_string: self
+                .query_string
+                .ok_or(::rusoto_core::BuildError::missing("query_string"))?,
+            query_version: self.query_version,
+            tags: self.tags,
+            thing_group_name: self
+                .thing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_group_name"))?,
+            thing_group_properties: self.thing_group_properties,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDynamicThingGroupResponse {
@@ -1246,7 +3707,7 @@ pub struct CreateJobRequest {
     /// <p>Specifies whether the job will continue to run (CONTINUOUS), or will be complete after all those things specified as targets have completed the job (SNAPSHOT). If continuous, the job may also be run on a thing when a change is detected in a target. For example, a job will run on a thing when the thing is added to a target group, even after the job was completed by all things originally in the group.</p>
     #[serde(rename = "targetSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_selection: Option<String>,
+    pub target_selection: Option<TargetSelection>,
     /// <p>A list of things and thing groups to which the job should be sent.</p>
     #[serde(rename = "targets")]
     pub targets: Vec<String>,
@@ -1256,6 +3717,121 @@ pub struct CreateJobRequest {
     pub timeout_config: Option<TimeoutConfig>,
 }
 
+impl CreateJobRequest {
+    /// Returns a builder for `CreateJobRequest`, checking its required members when built.
+    pub fn builder() -> CreateJobRequestBuilder {
+        CreateJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateJobRequest`](struct.CreateJobRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateJobRequestBuilder {
+    abort_config: Option<AbortConfig>,
+    description: Option<String>,
+    document: Option<String>,
+    document_source: Option<String>,
+    job_executions_rollout_config: Option<JobExecutionsRolloutConfig>,
+    job_id: Option<String>,
+    presigned_url_config: Option<PresignedUrlConfig>,
+    tags: Option<Vec<Tag>>,
+    target_selection: Option<TargetSelection>,
+    targets: Option<Vec<String>>,
+    timeout_config: Option<TimeoutConfig>,
+}
+
+impl CreateJobRequestBuilder {
+    /// Sets the [`abort_config`](struct.CreateJobRequest.html#structfield.abort_config) member.
+    pub fn abort_config<T: Into<AbortConfig>>(mut self, value: T) -> Self {
+        self.abort_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateJobRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document`](struct.CreateJobRequest.html#structfield.document) member.
+    pub fn document<T: Into<String>>(mut self, value: T) -> Self {
+        self.document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_source`](struct.CreateJobRequest.html#structfield.document_source) member.
+    pub fn document_source<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_executions_rollout_config`](struct.CreateJobRequest.html#structfield.job_executions_rollout_config) member.
+    pub fn job_executions_rollout_config<T: Into<JobExecutionsRolloutConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.job_executions_rollout_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.CreateJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`presigned_url_config`](struct.CreateJobRequest.html#structfield.presigned_url_config) member.
+    pub fn presigned_url_config<T: Into<PresignedUrlConfig>>(mut self, value: T) -> Self {
+        self.presigned_url_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateJobRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_selection`](struct.CreateJobRequest.html#structfield.target_selection) member.
+    pub fn target_selection<T: Into<TargetSelection>>(mut self, value: T) -> Self {
+        self.target_selection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`targets`](struct.CreateJobRequest.html#structfield.targets) member.
+    pub fn targets<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.targets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout_config`](struct.CreateJobRequest.html#structfield.timeout_config) member.
+    pub fn timeout_config<T: Into<TimeoutConfig>>(mut self, value: T) -> Self {
+        self.timeout_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateJobRequest, ::rusoto_core::BuildError> {
+        Ok(CreateJobRequest {
+            abort_config: self.abort_config,
+            description: self.description,
+            document: self.document,
+            document_source: self.document_source,
+            job_executions_rollout_config: self.job_executions_rollout_config,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            presigned_url_config: self.presigned_url_config,
+            tags: self.tags,
+            target_selection: self.target_selection,
+            targets: self
+                .targets
+                .ok_or(::rusoto_core::BuildError::missing("targets"))?,
+            timeout_config: self.timeout_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateJobResponse {
@@ -1282,6 +3858,36 @@ pub struct CreateKeysAndCertificateRequest {
     pub set_as_active: Option<bool>,
 }
 
+impl CreateKeysAndCertificateRequest {
+    /// Returns a builder for `CreateKeysAndCertificateRequest`, checking its required members when built.
+    pub fn builder() -> CreateKeysAndCertificateRequestBuilder {
+        CreateKeysAndCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateKeysAndCertificateRequest`](struct.CreateKeysAndCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateKeysAndCertificateRequestBuilder {
+    set_as_active: Option<bool>,
+}
+
+impl CreateKeysAndCertificateRequestBuilder {
+    /// Sets the [`set_as_active`](struct.CreateKeysAndCertificateRequest.html#structfield.set_as_active) member.
+    pub fn set_as_active<T: Into<bool>>(mut self, value: T) -> Self {
+        self.set_as_active = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateKeysAndCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateKeysAndCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(CreateKeysAndCertificateRequest {
+            set_as_active: self.set_as_active,
+        })
+    }
+}
+
 /// <p>The output of the CreateKeysAndCertificate operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1321,6 +3927,66 @@ pub struct CreateMitigationActionRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateMitigationActionRequest {
+    /// Returns a builder for `CreateMitigationActionRequest`, checking its required members when built.
+    pub fn builder() -> CreateMitigationActionRequestBuilder {
+        CreateMitigationActionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateMitigationActionRequest`](struct.CreateMitigationActionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateMitigationActionRequestBuilder {
+    action_name: Option<String>,
+    action_params: Option<MitigationActionParams>,
+    role_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateMitigationActionRequestBuilder {
+    /// Sets the [`action_name`](struct.CreateMitigationActionRequest.html#structfield.action_name) member.
+    pub fn action_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.action_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`action_params`](struct.CreateMitigationActionRequest.html#structfield.action_params) member.
+    pub fn action_params<T: Into<MitigationActionParams>>(mut self, value: T) -> Self {
+        self.action_params = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateMitigationActionRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateMitigationActionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateMitigationActionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateMitigationActionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateMitigationActionRequest {
+            action_name: self
+                .action_name
+                .ok_or(::rusoto_core::BuildError::missing("action_name"))?,
+            action_params: self
+                .action_params
+                .ok_or(::rusoto_core::BuildError::missing("action_params"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateMitigationActionResponse {
@@ -1364,12 +4030,118 @@ pub struct CreateOTAUpdateRequest {
     /// <p>Specifies whether the update will continue to run (CONTINUOUS), or will be complete after all the things specified as targets have completed the update (SNAPSHOT). If continuous, the update may also be run on a thing when a change is detected in a target. For example, an update will run on a thing when the thing is added to a target group, even after the update was completed by all things originally in the group. Valid values: CONTINUOUS | SNAPSHOT.</p>
     #[serde(rename = "targetSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_selection: Option<String>,
+    pub target_selection: Option<TargetSelection>,
     /// <p>The targeted devices to receive OTA updates.</p>
     #[serde(rename = "targets")]
     pub targets: Vec<String>,
 }
 
+impl CreateOTAUpdateRequest {
+    /// Returns a builder for `CreateOTAUpdateRequest`, checking its required members when built.
+    pub fn builder() -> CreateOTAUpdateRequestBuilder {
+        CreateOTAUpdateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateOTAUpdateRequest`](struct.CreateOTAUpdateRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateOTAUpdateRequestBuilder {
+    additional_parameters: Option<::std::collections::HashMap<String, String>>,
+    aws_job_executions_rollout_config: Option<AwsJobExecutionsRolloutConfig>,
+    description: Option<String>,
+    files: Option<Vec<OTAUpdateFile>>,
+    ota_update_id: Option<String>,
+    role_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_selection: Option<TargetSelection>,
+    targets: Option<Vec<String>>,
+}
+
+impl CreateOTAUpdateRequestBuilder {
+    /// Sets the [`additional_parameters`](struct.CreateOTAUpdateRequest.html#structfield.additional_parameters) member.
+    pub fn additional_parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.additional_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`aws_job_executions_rollout_config`](struct.CreateOTAUpdateRequest.html#structfield.aws_job_executions_rollout_config) member.
+    pub fn aws_job_executions_rollout_config<T: Into<AwsJobExecutionsRolloutConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.aws_job_executions_rollout_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateOTAUpdateRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`files`](struct.CreateOTAUpdateRequest.html#structfield.files) member.
+    pub fn files<T: Into<Vec<OTAUpdateFile>>>(mut self, value: T) -> Self {
+        self.files = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ota_update_id`](struct.CreateOTAUpdateRequest.html#structfield.ota_update_id) member.
+    pub fn ota_update_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ota_update_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateOTAUpdateRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateOTAUpdateRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_selection`](struct.CreateOTAUpdateRequest.html#structfield.target_selection) member.
+    pub fn target_selection<T: Into<TargetSelection>>(mut self, value: T) -> Self {
+        self.target_selection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`targets`](struct.CreateOTAUpdateRequest.html#structfield.targets) member.
+    pub fn targets<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.targets = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateOTAUpdateRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateOTAUpdateRequest, ::rusoto_core::BuildError> {
+        Ok(CreateOTAUpdateRequest {
+            additional_parameters: self.additional_parameters,
+            aws_job_executions_rollout_config: self.aws_job_executions_rollout_config,
+            description: self.description,
+            files: self
+                .files
+                .ok_or(::rusoto_core::BuildError::missing("files"))?,
+            ota_update_id: self
+                .ota_update_id
+                .ok_or(::rusoto_core::BuildError::missing("ota_update_id"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            tags: self.tags,
+            target_selection: self.target_selection,
+            targets: self
+                .targets
+                .ok_or(::rusoto_core::BuildError::missing("targets"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateOTAUpdateResponse {
@@ -1392,7 +4164,7 @@ pub struct CreateOTAUpdateResponse {
     /// <p>The OTA update status.</p>
     #[serde(rename = "otaUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ota_update_status: Option<String>,
+    pub ota_update_status: Option<OTAUpdateStatus>,
 }
 
 /// <p>The input for the CreatePolicy operation.</p>
@@ -1406,6 +4178,46 @@ pub struct CreatePolicyRequest {
     pub policy_name: String,
 }
 
+impl CreatePolicyRequest {
+    /// Returns a builder for `CreatePolicyRequest`, checking its required members when built.
+    pub fn builder() -> CreatePolicyRequestBuilder {
+        CreatePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePolicyRequest`](struct.CreatePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePolicyRequestBuilder {
+    policy_document: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl CreatePolicyRequestBuilder {
+    /// Sets the [`policy_document`](struct.CreatePolicyRequest.html#structfield.policy_document) member.
+    pub fn policy_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.CreatePolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePolicyRequest {
+            policy_document: self
+                .policy_document
+                .ok_or(::rusoto_core::BuildError::missing("policy_document"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// <p>The output from the CreatePolicy operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1443,6 +4255,56 @@ pub struct CreatePolicyVersionRequest {
     pub set_as_default: Option<bool>,
 }
 
+impl CreatePolicyVersionRequest {
+    /// Returns a builder for `CreatePolicyVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreatePolicyVersionRequestBuilder {
+        CreatePolicyVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePolicyVersionRequest`](struct.CreatePolicyVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePolicyVersionRequestBuilder {
+    policy_document: Option<String>,
+    policy_name: Option<String>,
+    set_as_default: Option<bool>,
+}
+
+impl CreatePolicyVersionRequestBuilder {
+    /// Sets the [`policy_document`](struct.CreatePolicyVersionRequest.html#structfield.policy_document) member.
+    pub fn policy_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.CreatePolicyVersionRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`set_as_default`](struct.CreatePolicyVersionRequest.html#structfield.set_as_default) member.
+    pub fn set_as_default<T: Into<bool>>(mut self, value: T) -> Self {
+        self.set_as_default = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePolicyVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreatePolicyVersionRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePolicyVersionRequest {
+            policy_document: self
+                .policy_document
+                .ok_or(::rusoto_core::BuildError::missing("policy_document"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            set_as_default: self.set_as_default,
+        })
+    }
+}
+
 /// <p>The output of the CreatePolicyVersion operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1479,6 +4341,54 @@ pub struct CreateRoleAliasRequest {
     pub role_arn: String,
 }
 
+impl CreateRoleAliasRequest {
+    /// Returns a builder for `CreateRoleAliasRequest`, checking its required members when built.
+    pub fn builder() -> CreateRoleAliasRequestBuilder {
+        CreateRoleAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRoleAliasRequest`](struct.CreateRoleAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRoleAliasRequestBuilder {
+    credential_duration_seconds: Option<i64>,
+    role_alias: Option<String>,
+    role_arn: Option<String>,
+}
+
+impl CreateRoleAliasRequestBuilder {
+    /// Sets the [`credential_duration_seconds`](struct.CreateRoleAliasRequest.html#structfield.credential_duration_seconds) member.
+    pub fn credential_duration_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.credential_duration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_alias`](struct.CreateRoleAliasRequest.html#structfield.role_alias) member.
+    pub fn role_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateRoleAliasRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRoleAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateRoleAliasRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRoleAliasRequest {
+            credential_duration_seconds: self.credential_duration_seconds,
+            role_alias: self
+                .role_alias
+                .ok_or(::rusoto_core::BuildError::missing("role_alias"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRoleAliasResponse {
@@ -1492,7 +4402,7 @@ pub struct CreateRoleAliasResponse {
     pub role_alias_arn: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateScheduledAuditRequest {
     /// <p>The day of the month on which the scheduled audit takes place. Can be "1" through "31" or "LAST". This field is required if the "frequency" parameter is set to "MONTHLY". If days 29-31 are specified, and the month does not have that many days, the audit takes place on the "LAST" day of the month.</p>
     #[serde(rename = "dayOfMonth")]
@@ -1501,10 +4411,10 @@ pub struct CreateScheduledAuditRequest {
     /// <p>The day of the week on which the scheduled audit takes place. Can be one of "SUN", "MON", "TUE", "WED", "THU", "FRI", or "SAT". This field is required if the "frequency" parameter is set to "WEEKLY" or "BIWEEKLY".</p>
     #[serde(rename = "dayOfWeek")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub day_of_week: Option<String>,
+    pub day_of_week: Option<DayOfWeek>,
     /// <p>How often the scheduled audit takes place. Can be one of "DAILY", "WEEKLY", "BIWEEKLY" or "MONTHLY". The start time of each audit is determined by the system.</p>
     #[serde(rename = "frequency")]
-    pub frequency: String,
+    pub frequency: AuditFrequency,
     /// <p>The name you want to give to the scheduled audit. (Max. 128 chars)</p>
     #[serde(rename = "scheduledAuditName")]
     pub scheduled_audit_name: String,
@@ -1517,6 +4427,82 @@ pub struct CreateScheduledAuditRequest {
     pub target_check_names: Vec<String>,
 }
 
+impl CreateScheduledAuditRequest {
+    /// Returns a builder for `CreateScheduledAuditRequest`, checking its required members when built.
+    pub fn builder() -> CreateScheduledAuditRequestBuilder {
+        CreateScheduledAuditRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateScheduledAuditRequest`](struct.CreateScheduledAuditRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateScheduledAuditRequestBuilder {
+    day_of_month: Option<String>,
+    day_of_week: Option<DayOfWeek>,
+    frequency: Option<AuditFrequency>,
+    scheduled_audit_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_check_names: Option<Vec<String>>,
+}
+
+impl CreateScheduledAuditRequestBuilder {
+    /// Sets the [`day_of_month`](struct.CreateScheduledAuditRequest.html#structfield.day_of_month) member.
+    pub fn day_of_month<T: Into<String>>(mut self, value: T) -> Self {
+        self.day_of_month = Some(value.into());
+        self
+    }
+
+    /// Sets the [`day_of_week`](struct.CreateScheduledAuditRequest.html#structfield.day_of_week) member.
+    pub fn day_of_week<T: Into<DayOfWeek>>(mut self, value: T) -> Self {
+        self.day_of_week = Some(value.into());
+        self
+    }
+
+    /// Sets the [`frequency`](struct.CreateScheduledAuditRequest.html#structfield.frequency) member.
+    pub fn frequency<T: Into<AuditFrequency>>(mut self, value: T) -> Self {
+        self.frequency = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduled_audit_name`](struct.CreateScheduledAuditRequest.html#structfield.scheduled_audit_name) member.
+    pub fn scheduled_audit_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scheduled_audit_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateScheduledAuditRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_check_names`](struct.CreateScheduledAuditRequest.html#structfield.target_check_names) member.
+    pub fn target_check_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.target_check_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateScheduledAuditRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateScheduledAuditRequest, ::rusoto_core::BuildError> {
+        Ok(CreateScheduledAuditRequest {
+            day_of_month: self.day_of_month,
+            day_of_week: self.day_of_week,
+            frequency: self
+                .frequency
+                .ok_or(::rusoto_core::BuildError::missing("frequency"))?,
+            scheduled_audit_name: self
+                .scheduled_audit_name
+                .ok_or(::rusoto_core::BuildError::missing("scheduled_audit_name"))?,
+            tags: self.tags,
+            target_check_names: self
+                .target_check_names
+                .ok_or(::rusoto_core::BuildError::missing("target_check_names"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateScheduledAuditResponse {
@@ -1535,7 +4521,7 @@ pub struct CreateSecurityProfileRequest {
     /// <p>Specifies the destinations to which alerts are sent. (Alerts are always sent to the console.) Alerts are generated when a device (thing) violates a behavior.</p>
     #[serde(rename = "alertTargets")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alert_targets: Option<::std::collections::HashMap<String, AlertTarget>>,
+    pub alert_targets: Option<::std::collections::HashMap<AlertTargetType, AlertTarget>>,
     /// <p>Specifies the behaviors that, when violated by a device (thing), cause an alert.</p>
     #[serde(rename = "behaviors")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1553,6 +4539,81 @@ pub struct CreateSecurityProfileRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateSecurityProfileRequest {
+    /// Returns a builder for `CreateSecurityProfileRequest`, checking its required members when built.
+    pub fn builder() -> CreateSecurityProfileRequestBuilder {
+        CreateSecurityProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSecurityProfileRequest`](struct.CreateSecurityProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSecurityProfileRequestBuilder {
+    additional_metrics_to_retain: Option<Vec<String>>,
+    alert_targets: Option<::std::collections::HashMap<AlertTargetType, AlertTarget>>,
+    behaviors: Option<Vec<Behavior>>,
+    security_profile_description: Option<String>,
+    security_profile_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateSecurityProfileRequestBuilder {
+    /// Sets the [`additional_metrics_to_retain`](struct.CreateSecurityProfileRequest.html#structfield.additional_metrics_to_retain) member.
+    pub fn additional_metrics_to_retain<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.additional_metrics_to_retain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`alert_targets`](struct.CreateSecurityProfileRequest.html#structfield.alert_targets) member.
+    pub fn alert_targets<T: Into<::std::collections::HashMap<AlertTargetType, AlertTarget>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.alert_targets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`behaviors`](struct.CreateSecurityProfileRequest.html#structfield.behaviors) member.
+    pub fn behaviors<T: Into<Vec<Behavior>>>(mut self, value: T) -> Self {
+        self.behaviors = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_description`](struct.CreateSecurityProfileRequest.html#structfield.security_profile_description) member.
+    pub fn security_profile_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_name`](struct.CreateSecurityProfileRequest.html#structfield.security_profile_name) member.
+    pub fn security_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateSecurityProfileRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSecurityProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSecurityProfileRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSecurityProfileRequest {
+            additional_metrics_to_retain: self.additional_metrics_to_retain,
+            alert_targets: self.alert_targets,
+            behaviors: self.behaviors,
+            security_profile_description: self.security_profile_description,
+            security_profile_name: self
+                .security_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("security_profile_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateSecurityProfileResponse {
@@ -1587,6 +4648,72 @@ pub struct CreateStreamRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateStreamRequest {
+    /// Returns a builder for `CreateStreamRequest`, checking its required members when built.
+    pub fn builder() -> CreateStreamRequestBuilder {
+        CreateStreamRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStreamRequest`](struct.CreateStreamRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateStreamRequestBuilder {
+    description: Option<String>,
+    files: Option<Vec<StreamFile>>,
+    role_arn: Option<String>,
+    stream_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateStreamRequestBuilder {
+    /// Sets the [`description`](struct.CreateStreamRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`files`](struct.CreateStreamRequest.html#structfield.files) member.
+    pub fn files<T: Into<Vec<StreamFile>>>(mut self, value: T) -> Self {
+        self.files = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateStreamRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_id`](struct.CreateStreamRequest.html#structfield.stream_id) member.
+    pub fn stream_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateStreamRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStreamRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateStreamRequest, ::rusoto_core::BuildError> {
+        Ok(CreateStreamRequest {
+            description: self.description,
+            files: self
+                .files
+                .ok_or(::rusoto_core::BuildError::missing("files"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            stream_id: self
+                .stream_id
+                .ok_or(::rusoto_core::BuildError::missing("stream_id"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateStreamResponse {
@@ -1627,16 +4754,72 @@ pub struct CreateThingGroupRequest {
     pub thing_group_properties: Option<ThingGroupProperties>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CreateThingGroupResponse {
-    /// <p>The thing group ARN.</p>
-    #[serde(rename = "thingGroupArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub thing_group_arn: Option<String>,
-    /// <p>The thing group ID.</p>
-    #[serde(rename = "thingGroupId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl CreateThingGroupRequest {
+    /// Returns a builder for `CreateThingGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateThingGroupRequestBuilder {
+        CreateThingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateThingGroupRequest`](struct.CreateThingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateThingGroupRequestBuilder {
+    parent_group_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    thing_group_name: Option<String>,
+    thing_group_properties: Option<ThingGroupProperties>,
+}
+
+impl CreateThingGroupRequestBuilder {
+    /// Sets the [`parent_group_name`](struct.CreateThingGroupRequest.html#structfield.parent_group_name) member.
+    pub fn parent_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateThingGroupRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_name`](struct.CreateThingGroupRequest.html#structfield.thing_group_name) member.
+    pub fn thing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_properties`](struct.CreateThingGroupRequest.html#structfield.thing_group_properties) member.
+    pub fn thing_group_properties<T: Into<ThingGroupProperties>>(mut self, value: T) -> Self {
+        self.thing_group_properties = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateThingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateThingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateThingGroupRequest {
+            parent_group_name: self.parent_group_name,
+            tags: self.tags,
+            thing_group_name: self
+                .thing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_group_name"))?,
+            thing_group_properties: self.thing_group_properties,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateThingGroupResponse {
+    /// <p>The thing group ARN.</p>
+    #[serde(rename = "thingGroupArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thing_group_arn: Option<String>,
+    /// <p>The thing group ID.</p>
+    #[serde(rename = "thingGroupId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thing_group_id: Option<String>,
     /// <p>The thing group name.</p>
     #[serde(rename = "thingGroupName")]
@@ -1664,6 +4847,60 @@ pub struct CreateThingRequest {
     pub thing_type_name: Option<String>,
 }
 
+impl CreateThingRequest {
+    /// Returns a builder for `CreateThingRequest`, checking its required members when built.
+    pub fn builder() -> CreateThingRequestBuilder {
+        CreateThingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateThingRequest`](struct.CreateThingRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateThingRequestBuilder {
+    attribute_payload: Option<AttributePayload>,
+    billing_group_name: Option<String>,
+    thing_name: Option<String>,
+    thing_type_name: Option<String>,
+}
+
+impl CreateThingRequestBuilder {
+    /// Sets the [`attribute_payload`](struct.CreateThingRequest.html#structfield.attribute_payload) member.
+    pub fn attribute_payload<T: Into<AttributePayload>>(mut self, value: T) -> Self {
+        self.attribute_payload = Some(value.into());
+        self
+    }
+
+    /// Sets the [`billing_group_name`](struct.CreateThingRequest.html#structfield.billing_group_name) member.
+    pub fn billing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.billing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.CreateThingRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_type_name`](struct.CreateThingRequest.html#structfield.thing_type_name) member.
+    pub fn thing_type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateThingRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateThingRequest, ::rusoto_core::BuildError> {
+        Ok(CreateThingRequest {
+            attribute_payload: self.attribute_payload,
+            billing_group_name: self.billing_group_name,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+            thing_type_name: self.thing_type_name,
+        })
+    }
+}
+
 /// <p>The output of the CreateThing operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1698,6 +4935,52 @@ pub struct CreateThingTypeRequest {
     pub thing_type_properties: Option<ThingTypeProperties>,
 }
 
+impl CreateThingTypeRequest {
+    /// Returns a builder for `CreateThingTypeRequest`, checking its required members when built.
+    pub fn builder() -> CreateThingTypeRequestBuilder {
+        CreateThingTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateThingTypeRequest`](struct.CreateThingTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateThingTypeRequestBuilder {
+    tags: Option<Vec<Tag>>,
+    thing_type_name: Option<String>,
+    thing_type_properties: Option<ThingTypeProperties>,
+}
+
+impl CreateThingTypeRequestBuilder {
+    /// Sets the [`tags`](struct.CreateThingTypeRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_type_name`](struct.CreateThingTypeRequest.html#structfield.thing_type_name) member.
+    pub fn thing_type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_type_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_type_properties`](struct.CreateThingTypeRequest.html#structfield.thing_type_properties) member.
+    pub fn thing_type_properties<T: Into<ThingTypeProperties>>(mut self, value: T) -> Self {
+        self.thing_type_properties = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateThingTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateThingTypeRequest, ::rusoto_core::BuildError> {
+        Ok(CreateThingTypeRequest {
+            tags: self.tags,
+            thing_type_name: self
+                .thing_type_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_type_name"))?,
+            thing_type_properties: self.thing_type_properties,
+        })
+    }
+}
+
 /// <p>The output of the CreateThingType operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1731,6 +5014,54 @@ pub struct CreateTopicRuleRequest {
     pub topic_rule_payload: TopicRulePayload,
 }
 
+impl CreateTopicRuleRequest {
+    /// Returns a builder for `CreateTopicRuleRequest`, checking its required members when built.
+    pub fn builder() -> CreateTopicRuleRequestBuilder {
+        CreateTopicRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTopicRuleRequest`](struct.CreateTopicRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTopicRuleRequestBuilder {
+    rule_name: Option<String>,
+    tags: Option<String>,
+    topic_rule_payload: Option<TopicRulePayload>,
+}
+
+impl CreateTopicRuleRequestBuilder {
+    /// Sets the [`rule_name`](struct.CreateTopicRuleRequest.html#structfield.rule_name) member.
+    pub fn rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateTopicRuleRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<String>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_rule_payload`](struct.CreateTopicRuleRequest.html#structfield.topic_rule_payload) member.
+    pub fn topic_rule_payload<T: Into<TopicRulePayload>>(mut self, value: T) -> Self {
+        self.topic_rule_payload = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTopicRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTopicRuleRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTopicRuleRequest {
+            rule_name: self
+                .rule_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_name"))?,
+            tags: self.tags,
+            topic_rule_payload: self
+                .topic_rule_payload
+                .ok_or(::rusoto_core::BuildError::missing("topic_rule_payload"))?,
+        })
+    }
+}
+
 /// <p>Describes a custom method used to code sign a file.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CustomCodeSigning {
@@ -1752,6 +5083,104 @@ pub struct CustomCodeSigning {
     pub signature_algorithm: Option<String>,
 }
 
+/// <p>The day of the week on which the scheduled audit takes place. Can be one of "SUN", "MON", "TUE", "WED", "THU", "FRI", or "SAT". This field is required if the "frequency" parameter is set to "WEEKLY" or "BIWEEKLY".</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DayOfWeek {
+    /// <p><code>SUN</code></p>
+    Sun,
+    /// <p><code>MON</code></p>
+    Mon,
+    /// <p><code>TUE</code></p>
+    Tue,
+    /// <p><code>WED</code></p>
+    Wed,
+    /// <p><code>THU</code></p>
+    Thu,
+    /// <p><code>FRI</code></p>
+    Fri,
+    /// <p><code>SAT</code></p>
+    Sat,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DayOfWeek {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DayOfWeek::Sun => "SUN",
+            DayOfWeek::Mon => "MON",
+            DayOfWeek::Tue => "TUE",
+            DayOfWeek::Wed => "WED",
+            DayOfWeek::Thu => "THU",
+            DayOfWeek::Fri => "FRI",
+            DayOfWeek::Sat => "SAT",
+            DayOfWeek::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DayOfWeek {
+    fn from(value: &'a str) -> DayOfWeek {
+        match value {
+            "SUN" => DayOfWeek::Sun,
+            "MON" => DayOfWeek::Mon,
+            "TUE" => DayOfWeek::Tue,
+            "WED" => DayOfWeek::Wed,
+            "THU" => DayOfWeek::Thu,
+            "FRI" => DayOfWeek::Fri,
+            "SAT" => DayOfWeek::Sat,
+            _ => DayOfWeek::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DayOfWeek {
+    fn from(value: String) -> DayOfWeek {
+        DayOfWeek::from(value.as_str())
+    }
+}
+
+impl From<DayOfWeek> for String {
+    fn from(value: DayOfWeek) -> String {
+        match value {
+            DayOfWeek::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DayOfWeek {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DayOfWeek {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DayOfWeek {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DayOfWeek {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DayOfWeek, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DayOfWeek::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteAccountAuditConfigurationRequest {
     /// <p>If true, all scheduled audits are deleted.</p>
@@ -1760,6 +5189,37 @@ pub struct DeleteAccountAuditConfigurationRequest {
     pub delete_scheduled_audits: Option<bool>,
 }
 
+impl DeleteAccountAuditConfigurationRequest {
+    /// Returns a builder for `DeleteAccountAuditConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAccountAuditConfigurationRequestBuilder {
+        DeleteAccountAuditConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAccountAuditConfigurationRequest`](struct.DeleteAccountAuditConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAccountAuditConfigurationRequestBuilder {
+    delete_scheduled_audits: Option<bool>,
+}
+
+impl DeleteAccountAuditConfigurationRequestBuilder {
+    /// Sets the [`delete_scheduled_audits`](struct.DeleteAccountAuditConfigurationRequest.html#structfield.delete_scheduled_audits) member.
+    pub fn delete_scheduled_audits<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_scheduled_audits = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAccountAuditConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAccountAuditConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteAccountAuditConfigurationRequest {
+            delete_scheduled_audits: self.delete_scheduled_audits,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteAccountAuditConfigurationResponse {}
@@ -1771,6 +5231,38 @@ pub struct DeleteAuthorizerRequest {
     pub authorizer_name: String,
 }
 
+impl DeleteAuthorizerRequest {
+    /// Returns a builder for `DeleteAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAuthorizerRequestBuilder {
+        DeleteAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAuthorizerRequest`](struct.DeleteAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAuthorizerRequestBuilder {
+    authorizer_name: Option<String>,
+}
+
+impl DeleteAuthorizerRequestBuilder {
+    /// Sets the [`authorizer_name`](struct.DeleteAuthorizerRequest.html#structfield.authorizer_name) member.
+    pub fn authorizer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAuthorizerRequest {
+            authorizer_name: self
+                .authorizer_name
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteAuthorizerResponse {}
@@ -1786,6 +5278,46 @@ pub struct DeleteBillingGroupRequest {
     pub expected_version: Option<i64>,
 }
 
+impl DeleteBillingGroupRequest {
+    /// Returns a builder for `DeleteBillingGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBillingGroupRequestBuilder {
+        DeleteBillingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBillingGroupRequest`](struct.DeleteBillingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBillingGroupRequestBuilder {
+    billing_group_name: Option<String>,
+    expected_version: Option<i64>,
+}
+
+impl DeleteBillingGroupRequestBuilder {
+    /// Sets the [`billing_group_name`](struct.DeleteBillingGroupRequest.html#structfield.billing_group_name) member.
+    pub fn billing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.billing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expected_version`](struct.DeleteBillingGroupRequest.html#structfield.expected_version) member.
+    pub fn expected_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expected_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBillingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteBillingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBillingGroupRequest {
+            billing_group_name: self
+                .billing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("billing_group_name"))?,
+            expected_version: self.expected_version,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteBillingGroupResponse {}
@@ -1798,6 +5330,38 @@ pub struct DeleteCACertificateRequest {
     pub certificate_id: String,
 }
 
+impl DeleteCACertificateRequest {
+    /// Returns a builder for `DeleteCACertificateRequest`, checking its required members when built.
+    pub fn builder() -> DeleteCACertificateRequestBuilder {
+        DeleteCACertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCACertificateRequest`](struct.DeleteCACertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteCACertificateRequestBuilder {
+    certificate_id: Option<String>,
+}
+
+impl DeleteCACertificateRequestBuilder {
+    /// Sets the [`certificate_id`](struct.DeleteCACertificateRequest.html#structfield.certificate_id) member.
+    pub fn certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCACertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCACertificateRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteCACertificateRequest {
+            certificate_id: self
+                .certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("certificate_id"))?,
+        })
+    }
+}
+
 /// <p>The output for the DeleteCACertificate operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1815,6 +5379,46 @@ pub struct DeleteCertificateRequest {
     pub force_delete: Option<bool>,
 }
 
+impl DeleteCertificateRequest {
+    /// Returns a builder for `DeleteCertificateRequest`, checking its required members when built.
+    pub fn builder() -> DeleteCertificateRequestBuilder {
+        DeleteCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCertificateRequest`](struct.DeleteCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteCertificateRequestBuilder {
+    certificate_id: Option<String>,
+    force_delete: Option<bool>,
+}
+
+impl DeleteCertificateRequestBuilder {
+    /// Sets the [`certificate_id`](struct.DeleteCertificateRequest.html#structfield.certificate_id) member.
+    pub fn certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_delete`](struct.DeleteCertificateRequest.html#structfield.force_delete) member.
+    pub fn force_delete<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_delete = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteCertificateRequest {
+            certificate_id: self
+                .certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("certificate_id"))?,
+            force_delete: self.force_delete,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDynamicThingGroupRequest {
     /// <p>The expected version of the dynamic thing group to delete.</p>
@@ -1826,6 +5430,46 @@ pub struct DeleteDynamicThingGroupRequest {
     pub thing_group_name: String,
 }
 
+impl DeleteDynamicThingGroupRequest {
+    /// Returns a builder for `DeleteDynamicThingGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDynamicThingGroupRequestBuilder {
+        DeleteDynamicThingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDynamicThingGroupRequest`](struct.DeleteDynamicThingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDynamicThingGroupRequestBuilder {
+    expected_version: Option<i64>,
+    thing_group_name: Option<String>,
+}
+
+impl DeleteDynamicThingGroupRequestBuilder {
+    /// Sets the [`expected_version`](struct.DeleteDynamicThingGroupRequest.html#structfield.expected_version) member.
+    pub fn expected_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expected_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_name`](struct.DeleteDynamicThingGroupRequest.html#structfield.thing_group_name) member.
+    pub fn thing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDynamicThingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDynamicThingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDynamicThingGroupRequest {
+            expected_version: self.expected_version,
+            thing_group_name: self
+                .thing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDynamicThingGroupResponse {}
@@ -1847,6 +5491,66 @@ pub struct DeleteJobExecutionRequest {
     pub thing_name: String,
 }
 
+impl DeleteJobExecutionRequest {
+    /// Returns a builder for `DeleteJobExecutionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteJobExecutionRequestBuilder {
+        DeleteJobExecutionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteJobExecutionRequest`](struct.DeleteJobExecutionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteJobExecutionRequestBuilder {
+    execution_number: Option<i64>,
+    force: Option<bool>,
+    job_id: Option<String>,
+    thing_name: Option<String>,
+}
+
+impl DeleteJobExecutionRequestBuilder {
+    /// Sets the [`execution_number`](struct.DeleteJobExecutionRequest.html#structfield.execution_number) member.
+    pub fn execution_number<T: Into<i64>>(mut self, value: T) -> Self {
+        self.execution_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force`](struct.DeleteJobExecutionRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.DeleteJobExecutionRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.DeleteJobExecutionRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteJobExecutionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteJobExecutionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteJobExecutionRequest {
+            execution_number: self
+                .execution_number
+                .ok_or(::rusoto_core::BuildError::missing("execution_number"))?,
+            force: self.force,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteJobRequest {
     /// <p><p>(Optional) When true, you can delete a job which is &quot;IN<em>PROGRESS&quot;. Otherwise, you can only delete a job which is in a terminal state (&quot;COMPLETED&quot; or &quot;CANCELED&quot;) or an exception will occur. The default is false.</p> <note> <p>Deleting a job which is &quot;IN</em>PROGRESS&quot;, will cause a device which is executing the job to be unable to access job information or update the job execution status. Use caution and ensure that each device executing a job which is deleted is able to recover to a valid state.</p> </note></p>
@@ -1858,6 +5562,44 @@ pub struct DeleteJobRequest {
     pub job_id: String,
 }
 
+impl DeleteJobRequest {
+    /// Returns a builder for `DeleteJobRequest`, checking its required members when built.
+    pub fn builder() -> DeleteJobRequestBuilder {
+        DeleteJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteJobRequest`](struct.DeleteJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteJobRequestBuilder {
+    force: Option<bool>,
+    job_id: Option<String>,
+}
+
+impl DeleteJobRequestBuilder {
+    /// Sets the [`force`](struct.DeleteJobRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.DeleteJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteJobRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteJobRequest {
+            force: self.force,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteMitigationActionRequest {
     /// <p>The name of the mitigation action that you want to delete.</p>
@@ -1865,6 +5607,38 @@ pub struct DeleteMitigationActionRequest {
     pub action_name: String,
 }
 
+impl DeleteMitigationActionRequest {
+    /// Returns a builder for `DeleteMitigationActionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteMitigationActionRequestBuilder {
+        DeleteMitigationActionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMitigationActionRequest`](struct.DeleteMitigationActionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteMitigationActionRequestBuilder {
+    action_name: Option<String>,
+}
+
+impl DeleteMitigationActionRequestBuilder {
+    /// Sets the [`action_name`](struct.DeleteMitigationActionRequest.html#structfield.action_name) member.
+    pub fn action_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.action_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMitigationActionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteMitigationActionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteMitigationActionRequest {
+            action_name: self
+                .action_name
+                .ok_or(::rusoto_core::BuildError::missing("action_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteMitigationActionResponse {}
@@ -1884,6 +5658,52 @@ pub struct DeleteOTAUpdateRequest {
     pub ota_update_id: String,
 }
 
+impl DeleteOTAUpdateRequest {
+    /// Returns a builder for `DeleteOTAUpdateRequest`, checking its required members when built.
+    pub fn builder() -> DeleteOTAUpdateRequestBuilder {
+        DeleteOTAUpdateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteOTAUpdateRequest`](struct.DeleteOTAUpdateRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteOTAUpdateRequestBuilder {
+    delete_stream: Option<bool>,
+    force_delete_aws_job: Option<bool>,
+    ota_update_id: Option<String>,
+}
+
+impl DeleteOTAUpdateRequestBuilder {
+    /// Sets the [`delete_stream`](struct.DeleteOTAUpdateRequest.html#structfield.delete_stream) member.
+    pub fn delete_stream<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_stream = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_delete_aws_job`](struct.DeleteOTAUpdateRequest.html#structfield.force_delete_aws_job) member.
+    pub fn force_delete_aws_job<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_delete_aws_job = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ota_update_id`](struct.DeleteOTAUpdateRequest.html#structfield.ota_update_id) member.
+    pub fn ota_update_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ota_update_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteOTAUpdateRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteOTAUpdateRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteOTAUpdateRequest {
+            delete_stream: self.delete_stream,
+            force_delete_aws_job: self.force_delete_aws_job,
+            ota_update_id: self
+                .ota_update_id
+                .ok_or(::rusoto_core::BuildError::missing("ota_update_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteOTAUpdateResponse {}
@@ -1896,6 +5716,36 @@ pub struct DeletePolicyRequest {
     pub policy_name: String,
 }
 
+impl DeletePolicyRequest {
+    /// Returns a builder for `DeletePolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeletePolicyRequestBuilder {
+        DeletePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePolicyRequest`](struct.DeletePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePolicyRequestBuilder {
+    policy_name: Option<String>,
+}
+
+impl DeletePolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.DeletePolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePolicyRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// <p>The input for the DeletePolicyVersion operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeletePolicyVersionRequest {
@@ -1907,14 +5757,56 @@ pub struct DeletePolicyVersionRequest {
     pub policy_version_id: String,
 }
 
-/// <p>The input for the DeleteRegistrationCode operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DeleteRegistrationCodeRequest {}
+impl DeletePolicyVersionRequest {
+    /// Returns a builder for `DeletePolicyVersionRequest`, checking its required members when built.
+    pub fn builder() -> DeletePolicyVersionRequestBuilder {
+        DeletePolicyVersionRequestBuilder::default()
+    }
+}
 
-/// <p>The output for the DeleteRegistrationCode operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DeleteRegistrationCodeResponse {}
+/// Builds a [`DeletePolicyVersionRequest`](struct.DeletePolicyVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePolicyVersionRequestBuilder {
+    policy_name: Option<String>,
+    policy_version_id: Option<String>,
+}
+
+impl DeletePolicyVersionRequestBuilder {
+    /// Sets the [`policy_name`](struct.DeletePolicyVersionRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_version_id`](struct.DeletePolicyVersionRequest.html#structfield.policy_version_id) member.
+    pub fn policy_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePolicyVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeletePolicyVersionRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePolicyVersionRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            policy_version_id: self
+                .policy_version_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_version_id"))?,
+        })
+    }
+}
+
+/// <p>The input for the DeleteRegistrationCode operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct DeleteRegistrationCodeRequest {}
+
+/// <p>The output for the DeleteRegistrationCode operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DeleteRegistrationCodeResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteRoleAliasRequest {
@@ -1923,6 +5815,36 @@ pub struct DeleteRoleAliasRequest {
     pub role_alias: String,
 }
 
+impl DeleteRoleAliasRequest {
+    /// Returns a builder for `DeleteRoleAliasRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRoleAliasRequestBuilder {
+        DeleteRoleAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRoleAliasRequest`](struct.DeleteRoleAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRoleAliasRequestBuilder {
+    role_alias: Option<String>,
+}
+
+impl DeleteRoleAliasRequestBuilder {
+    /// Sets the [`role_alias`](struct.DeleteRoleAliasRequest.html#structfield.role_alias) member.
+    pub fn role_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_alias = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRoleAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteRoleAliasRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRoleAliasRequest {
+            role_alias: self
+                .role_alias
+                .ok_or(::rusoto_core::BuildError::missing("role_alias"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRoleAliasResponse {}
@@ -1934,6 +5856,38 @@ pub struct DeleteScheduledAuditRequest {
     pub scheduled_audit_name: String,
 }
 
+impl DeleteScheduledAuditRequest {
+    /// Returns a builder for `DeleteScheduledAuditRequest`, checking its required members when built.
+    pub fn builder() -> DeleteScheduledAuditRequestBuilder {
+        DeleteScheduledAuditRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteScheduledAuditRequest`](struct.DeleteScheduledAuditRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteScheduledAuditRequestBuilder {
+    scheduled_audit_name: Option<String>,
+}
+
+impl DeleteScheduledAuditRequestBuilder {
+    /// Sets the [`scheduled_audit_name`](struct.DeleteScheduledAuditRequest.html#structfield.scheduled_audit_name) member.
+    pub fn scheduled_audit_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scheduled_audit_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteScheduledAuditRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteScheduledAuditRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteScheduledAuditRequest {
+            scheduled_audit_name: self
+                .scheduled_audit_name
+                .ok_or(::rusoto_core::BuildError::missing("scheduled_audit_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteScheduledAuditResponse {}
@@ -1949,6 +5903,46 @@ pub struct DeleteSecurityProfileRequest {
     pub security_profile_name: String,
 }
 
+impl DeleteSecurityProfileRequest {
+    /// Returns a builder for `DeleteSecurityProfileRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSecurityProfileRequestBuilder {
+        DeleteSecurityProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSecurityProfileRequest`](struct.DeleteSecurityProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSecurityProfileRequestBuilder {
+    expected_version: Option<i64>,
+    security_profile_name: Option<String>,
+}
+
+impl DeleteSecurityProfileRequestBuilder {
+    /// Sets the [`expected_version`](struct.DeleteSecurityProfileRequest.html#structfield.expected_version) member.
+    pub fn expected_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expected_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_name`](struct.DeleteSecurityProfileRequest.html#structfield.security_profile_name) member.
+    pub fn security_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSecurityProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSecurityProfileRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSecurityProfileRequest {
+            expected_version: self.expected_version,
+            security_profile_name: self
+                .security_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("security_profile_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSecurityProfileResponse {}
@@ -1960,6 +5954,36 @@ pub struct DeleteStreamRequest {
     pub stream_id: String,
 }
 
+impl DeleteStreamRequest {
+    /// Returns a builder for `DeleteStreamRequest`, checking its required members when built.
+    pub fn builder() -> DeleteStreamRequestBuilder {
+        DeleteStreamRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStreamRequest`](struct.DeleteStreamRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteStreamRequestBuilder {
+    stream_id: Option<String>,
+}
+
+impl DeleteStreamRequestBuilder {
+    /// Sets the [`stream_id`](struct.DeleteStreamRequest.html#structfield.stream_id) member.
+    pub fn stream_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStreamRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteStreamRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteStreamRequest {
+            stream_id: self
+                .stream_id
+                .ok_or(::rusoto_core::BuildError::missing("stream_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteStreamResponse {}
@@ -1975,6 +5999,46 @@ pub struct DeleteThingGroupRequest {
     pub thing_group_name: String,
 }
 
+impl DeleteThingGroupRequest {
+    /// Returns a builder for `DeleteThingGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteThingGroupRequestBuilder {
+        DeleteThingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteThingGroupRequest`](struct.DeleteThingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteThingGroupRequestBuilder {
+    expected_version: Option<i64>,
+    thing_group_name: Option<String>,
+}
+
+impl DeleteThingGroupRequestBuilder {
+    /// Sets the [`expected_version`](struct.DeleteThingGroupRequest.html#structfield.expected_version) member.
+    pub fn expected_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expected_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_name`](struct.DeleteThingGroupRequest.html#structfield.thing_group_name) member.
+    pub fn thing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteThingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteThingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteThingGroupRequest {
+            expected_version: self.expected_version,
+            thing_group_name: self
+                .thing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteThingGroupResponse {}
@@ -1991,6 +6055,44 @@ pub struct DeleteThingRequest {
     pub thing_name: String,
 }
 
+impl DeleteThingRequest {
+    /// Returns a builder for `DeleteThingRequest`, checking its required members when built.
+    pub fn builder() -> DeleteThingRequestBuilder {
+        DeleteThingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteThingRequest`](struct.DeleteThingRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteThingRequestBuilder {
+    expected_version: Option<i64>,
+    thing_name: Option<String>,
+}
+
+impl DeleteThingRequestBuilder {
+    /// Sets the [`expected_version`](struct.DeleteThingRequest.html#structfield.expected_version) member.
+    pub fn expected_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expected_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.DeleteThingRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteThingRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteThingRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteThingRequest {
+            expected_version: self.expected_version,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 /// <p>The output of the DeleteThing operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2004,6 +6106,36 @@ pub struct DeleteThingTypeRequest {
     pub thing_type_name: String,
 }
 
+impl DeleteThingTypeRequest {
+    /// Returns a builder for `DeleteThingTypeRequest`, checking its required members when built.
+    pub fn builder() -> DeleteThingTypeRequestBuilder {
+        DeleteThingTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteThingTypeRequest`](struct.DeleteThingTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteThingTypeRequestBuilder {
+    thing_type_name: Option<String>,
+}
+
+impl DeleteThingTypeRequestBuilder {
+    /// Sets the [`thing_type_name`](struct.DeleteThingTypeRequest.html#structfield.thing_type_name) member.
+    pub fn thing_type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteThingTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteThingTypeRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteThingTypeRequest {
+            thing_type_name: self
+                .thing_type_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_type_name"))?,
+        })
+    }
+}
+
 /// <p>The output for the DeleteThingType operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2017,14 +6149,86 @@ pub struct DeleteTopicRuleRequest {
     pub rule_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl DeleteTopicRuleRequest {
+    /// Returns a builder for `DeleteTopicRuleRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTopicRuleRequestBuilder {
+        DeleteTopicRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTopicRuleRequest`](struct.DeleteTopicRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTopicRuleRequestBuilder {
+    rule_name: Option<String>,
+}
+
+impl DeleteTopicRuleRequestBuilder {
+    /// Sets the [`rule_name`](struct.DeleteTopicRuleRequest.html#structfield.rule_name) member.
+    pub fn rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTopicRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTopicRuleRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTopicRuleRequest {
+            rule_name: self
+                .rule_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteV2LoggingLevelRequest {
     /// <p>The name of the resource for which you are configuring logging.</p>
     #[serde(rename = "targetName")]
     pub target_name: String,
     /// <p>The type of resource for which you are configuring logging. Must be <code>THING_Group</code>.</p>
     #[serde(rename = "targetType")]
-    pub target_type: String,
+    pub target_type: LogTargetType,
+}
+
+impl DeleteV2LoggingLevelRequest {
+    /// Returns a builder for `DeleteV2LoggingLevelRequest`, checking its required members when built.
+    pub fn builder() -> DeleteV2LoggingLevelRequestBuilder {
+        DeleteV2LoggingLevelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteV2LoggingLevelRequest`](struct.DeleteV2LoggingLevelRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteV2LoggingLevelRequestBuilder {
+    target_name: Option<String>,
+    target_type: Option<LogTargetType>,
+}
+
+impl DeleteV2LoggingLevelRequestBuilder {
+    /// Sets the [`target_name`](struct.DeleteV2LoggingLevelRequest.html#structfield.target_name) member.
+    pub fn target_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_type`](struct.DeleteV2LoggingLevelRequest.html#structfield.target_type) member.
+    pub fn target_type<T: Into<LogTargetType>>(mut self, value: T) -> Self {
+        self.target_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteV2LoggingLevelRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteV2LoggingLevelRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteV2LoggingLevelRequest {
+            target_name: self
+                .target_name
+                .ok_or(::rusoto_core::BuildError::missing("target_name"))?,
+            target_type: self
+                .target_type
+                .ok_or(::rusoto_core::BuildError::missing("target_type"))?,
+        })
+    }
 }
 
 /// <p>Contains information that denied the authorization.</p>
@@ -2053,6 +6257,46 @@ pub struct DeprecateThingTypeRequest {
     pub undo_deprecate: Option<bool>,
 }
 
+impl DeprecateThingTypeRequest {
+    /// Returns a builder for `DeprecateThingTypeRequest`, checking its required members when built.
+    pub fn builder() -> DeprecateThingTypeRequestBuilder {
+        DeprecateThingTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeprecateThingTypeRequest`](struct.DeprecateThingTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct DeprecateThingTypeRequestBuilder {
+    thing_type_name: Option<String>,
+    undo_deprecate: Option<bool>,
+}
+
+impl DeprecateThingTypeRequestBuilder {
+    /// Sets the [`thing_type_name`](struct.DeprecateThingTypeRequest.html#structfield.thing_type_name) member.
+    pub fn thing_type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_type_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`undo_deprecate`](struct.DeprecateThingTypeRequest.html#structfield.undo_deprecate) member.
+    pub fn undo_deprecate<T: Into<bool>>(mut self, value: T) -> Self {
+        self.undo_deprecate = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeprecateThingTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeprecateThingTypeRequest, ::rusoto_core::BuildError> {
+        Ok(DeprecateThingTypeRequest {
+            thing_type_name: self
+                .thing_type_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_type_name"))?,
+            undo_deprecate: self.undo_deprecate,
+        })
+    }
+}
+
 /// <p>The output for the DeprecateThingType operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2087,6 +6331,38 @@ pub struct DescribeAuditFindingRequest {
     pub finding_id: String,
 }
 
+impl DescribeAuditFindingRequest {
+    /// Returns a builder for `DescribeAuditFindingRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAuditFindingRequestBuilder {
+        DescribeAuditFindingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAuditFindingRequest`](struct.DescribeAuditFindingRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAuditFindingRequestBuilder {
+    finding_id: Option<String>,
+}
+
+impl DescribeAuditFindingRequestBuilder {
+    /// Sets the [`finding_id`](struct.DescribeAuditFindingRequest.html#structfield.finding_id) member.
+    pub fn finding_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.finding_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAuditFindingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAuditFindingRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAuditFindingRequest {
+            finding_id: self
+                .finding_id
+                .ok_or(::rusoto_core::BuildError::missing("finding_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAuditFindingResponse {
@@ -2102,6 +6378,39 @@ pub struct DescribeAuditMitigationActionsTaskRequest {
     pub task_id: String,
 }
 
+impl DescribeAuditMitigationActionsTaskRequest {
+    /// Returns a builder for `DescribeAuditMitigationActionsTaskRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAuditMitigationActionsTaskRequestBuilder {
+        DescribeAuditMitigationActionsTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAuditMitigationActionsTaskRequest`](struct.DescribeAuditMitigationActionsTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAuditMitigationActionsTaskRequestBuilder {
+    task_id: Option<String>,
+}
+
+impl DescribeAuditMitigationActionsTaskRequestBuilder {
+    /// Sets the [`task_id`](struct.DescribeAuditMitigationActionsTaskRequest.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAuditMitigationActionsTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAuditMitigationActionsTaskRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeAuditMitigationActionsTaskRequest {
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAuditMitigationActionsTaskResponse {
@@ -2115,12 +6424,14 @@ pub struct DescribeAuditMitigationActionsTaskResponse {
     pub audit_check_to_actions_mapping: Option<::std::collections::HashMap<String, Vec<String>>>,
     /// <p>The date and time when the task was completed or canceled.</p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The date and time when the task was started.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>Identifies the findings to which the mitigation actions are applied. This can be by audit checks, by audit task, or a set of findings.</p>
     #[serde(rename = "target")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2132,7 +6443,7 @@ pub struct DescribeAuditMitigationActionsTaskResponse {
     /// <p>The current status of the task.</p>
     #[serde(rename = "taskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<AuditMitigationActionsTaskStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2142,6 +6453,38 @@ pub struct DescribeAuditTaskRequest {
     pub task_id: String,
 }
 
+impl DescribeAuditTaskRequest {
+    /// Returns a builder for `DescribeAuditTaskRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAuditTaskRequestBuilder {
+        DescribeAuditTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAuditTaskRequest`](struct.DescribeAuditTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAuditTaskRequestBuilder {
+    task_id: Option<String>,
+}
+
+impl DescribeAuditTaskRequestBuilder {
+    /// Sets the [`task_id`](struct.DescribeAuditTaskRequest.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAuditTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAuditTaskRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAuditTaskRequest {
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAuditTaskResponse {
@@ -2155,8 +6498,9 @@ pub struct DescribeAuditTaskResponse {
     pub scheduled_audit_name: Option<String>,
     /// <p>The time the audit started.</p>
     #[serde(rename = "taskStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_start_time: Option<f64>,
+    pub task_start_time: Option<EpochTimestamp>,
     /// <p>Statistical information about the audit.</p>
     #[serde(rename = "taskStatistics")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2164,11 +6508,11 @@ pub struct DescribeAuditTaskResponse {
     /// <p>The status of the audit: one of "IN_PROGRESS", "COMPLETED", "FAILED", or "CANCELED".</p>
     #[serde(rename = "taskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<AuditTaskStatus>,
     /// <p>The type of audit: "ON_DEMAND_AUDIT_TASK" or "SCHEDULED_AUDIT_TASK".</p>
     #[serde(rename = "taskType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_type: Option<String>,
+    pub task_type: Option<AuditTaskType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2178,6 +6522,38 @@ pub struct DescribeAuthorizerRequest {
     pub authorizer_name: String,
 }
 
+impl DescribeAuthorizerRequest {
+    /// Returns a builder for `DescribeAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAuthorizerRequestBuilder {
+        DescribeAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAuthorizerRequest`](struct.DescribeAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAuthorizerRequestBuilder {
+    authorizer_name: Option<String>,
+}
+
+impl DescribeAuthorizerRequestBuilder {
+    /// Sets the [`authorizer_name`](struct.DescribeAuthorizerRequest.html#structfield.authorizer_name) member.
+    pub fn authorizer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAuthorizerRequest {
+            authorizer_name: self
+                .authorizer_name
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAuthorizerResponse {
@@ -2194,6 +6570,38 @@ pub struct DescribeBillingGroupRequest {
     pub billing_group_name: String,
 }
 
+impl DescribeBillingGroupRequest {
+    /// Returns a builder for `DescribeBillingGroupRequest`, checking its required members when built.
+    pub fn builder() -> DescribeBillingGroupRequestBuilder {
+        DescribeBillingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBillingGroupRequest`](struct.DescribeBillingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeBillingGroupRequestBuilder {
+    billing_group_name: Option<String>,
+}
+
+impl DescribeBillingGroupRequestBuilder {
+    /// Sets the [`billing_group_name`](struct.DescribeBillingGroupRequest.html#structfield.billing_group_name) member.
+    pub fn billing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.billing_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBillingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeBillingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeBillingGroupRequest {
+            billing_group_name: self
+                .billing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("billing_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeBillingGroupResponse {
@@ -2231,6 +6639,38 @@ pub struct DescribeCACertificateRequest {
     pub certificate_id: String,
 }
 
+impl DescribeCACertificateRequest {
+    /// Returns a builder for `DescribeCACertificateRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCACertificateRequestBuilder {
+        DescribeCACertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCACertificateRequest`](struct.DescribeCACertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCACertificateRequestBuilder {
+    certificate_id: Option<String>,
+}
+
+impl DescribeCACertificateRequestBuilder {
+    /// Sets the [`certificate_id`](struct.DescribeCACertificateRequest.html#structfield.certificate_id) member.
+    pub fn certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCACertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCACertificateRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeCACertificateRequest {
+            certificate_id: self
+                .certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("certificate_id"))?,
+        })
+    }
+}
+
 /// <p>The output from the DescribeCACertificate operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2253,6 +6693,38 @@ pub struct DescribeCertificateRequest {
     pub certificate_id: String,
 }
 
+impl DescribeCertificateRequest {
+    /// Returns a builder for `DescribeCertificateRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCertificateRequestBuilder {
+        DescribeCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCertificateRequest`](struct.DescribeCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCertificateRequestBuilder {
+    certificate_id: Option<String>,
+}
+
+impl DescribeCertificateRequestBuilder {
+    /// Sets the [`certificate_id`](struct.DescribeCertificateRequest.html#structfield.certificate_id) member.
+    pub fn certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeCertificateRequest {
+            certificate_id: self
+                .certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("certificate_id"))?,
+        })
+    }
+}
+
 /// <p>The output of the DescribeCertificate operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2284,6 +6756,36 @@ pub struct DescribeEndpointRequest {
     pub endpoint_type: Option<String>,
 }
 
+impl DescribeEndpointRequest {
+    /// Returns a builder for `DescribeEndpointRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEndpointRequestBuilder {
+        DescribeEndpointRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEndpointRequest`](struct.DescribeEndpointRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEndpointRequestBuilder {
+    endpoint_type: Option<String>,
+}
+
+impl DescribeEndpointRequestBuilder {
+    /// Sets the [`endpoint_type`](struct.DescribeEndpointRequest.html#structfield.endpoint_type) member.
+    pub fn endpoint_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEndpointRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEndpointRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEndpointRequest {
+            endpoint_type: self.endpoint_type,
+        })
+    }
+}
+
 /// <p>The output from the DescribeEndpoint operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2302,16 +6804,18 @@ pub struct DescribeEventConfigurationsRequest {}
 pub struct DescribeEventConfigurationsResponse {
     /// <p>The creation date of the event configuration.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The event configurations.</p>
     #[serde(rename = "eventConfigurations")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_configurations: Option<::std::collections::HashMap<String, Configuration>>,
+    pub event_configurations: Option<::std::collections::HashMap<EventType, Configuration>>,
     /// <p>The date the event configurations were last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2321,6 +6825,36 @@ pub struct DescribeIndexRequest {
     pub index_name: String,
 }
 
+impl DescribeIndexRequest {
+    /// Returns a builder for `DescribeIndexRequest`, checking its required members when built.
+    pub fn builder() -> DescribeIndexRequestBuilder {
+        DescribeIndexRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeIndexRequest`](struct.DescribeIndexRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeIndexRequestBuilder {
+    index_name: Option<String>,
+}
+
+impl DescribeIndexRequestBuilder {
+    /// Sets the [`index_name`](struct.DescribeIndexRequest.html#structfield.index_name) member.
+    pub fn index_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.index_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeIndexRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeIndexRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeIndexRequest {
+            index_name: self
+                .index_name
+                .ok_or(::rusoto_core::BuildError::missing("index_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeIndexResponse {
@@ -2331,7 +6865,7 @@ pub struct DescribeIndexResponse {
     /// <p>The index status.</p>
     #[serde(rename = "indexStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_status: Option<String>,
+    pub index_status: Option<IndexStatus>,
     /// <p><p>Contains a value that specifies the type of indexing performed. Valid values are:</p> <ul> <li> <p>REGISTRY – Your thing index contains only registry data.</p> </li> <li> <p>REGISTRY<em>AND</em>SHADOW - Your thing index contains registry data and shadow data.</p> </li> <li> <p>REGISTRY<em>AND</em>CONNECTIVITY<em>STATUS - Your thing index contains registry data and thing connectivity status data.</p> </li> <li> <p>REGISTRY</em>AND<em>SHADOW</em>AND<em>CONNECTIVITY</em>STATUS - Your thing index contains registry data, shadow data, and thing connectivity status data.</p> </li> </ul></p>
     #[serde(rename = "schema")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2352,6 +6886,56 @@ pub struct DescribeJobExecutionRequest {
     pub thing_name: String,
 }
 
+impl DescribeJobExecutionRequest {
+    /// Returns a builder for `DescribeJobExecutionRequest`, checking its required members when built.
+    pub fn builder() -> DescribeJobExecutionRequestBuilder {
+        DescribeJobExecutionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeJobExecutionRequest`](struct.DescribeJobExecutionRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeJobExecutionRequestBuilder {
+    execution_number: Option<i64>,
+    job_id: Option<String>,
+    thing_name: Option<String>,
+}
+
+impl DescribeJobExecutionRequestBuilder {
+    /// Sets the [`execution_number`](struct.DescribeJobExecutionRequest.html#structfield.execution_number) member.
+    pub fn execution_number<T: Into<i64>>(mut self, value: T) -> Self {
+        self.execution_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.DescribeJobExecutionRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.DescribeJobExecutionRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeJobExecutionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeJobExecutionRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeJobExecutionRequest {
+            execution_number: self.execution_number,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeJobExecutionResponse {
@@ -2368,8 +6952,38 @@ pub struct DescribeJobRequest {
     pub job_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl DescribeJobRequest {
+    /// Returns a builder for `DescribeJobRequest`, checking its required members when built.
+    pub fn builder() -> DescribeJobRequestBuilder {
+        DescribeJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeJobRequest`](struct.DescribeJobRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeJobRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl DescribeJobRequestBuilder {
+    /// Sets the [`job_id`](struct.DescribeJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeJobRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
 pub struct DescribeJobResponse {
     /// <p>An S3 link to the job document.</p>
     #[serde(rename = "documentSource")]
@@ -2388,6 +7002,38 @@ pub struct DescribeMitigationActionRequest {
     pub action_name: String,
 }
 
+impl DescribeMitigationActionRequest {
+    /// Returns a builder for `DescribeMitigationActionRequest`, checking its required members when built.
+    pub fn builder() -> DescribeMitigationActionRequestBuilder {
+        DescribeMitigationActionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeMitigationActionRequest`](struct.DescribeMitigationActionRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeMitigationActionRequestBuilder {
+    action_name: Option<String>,
+}
+
+impl DescribeMitigationActionRequestBuilder {
+    /// Sets the [`action_name`](struct.DescribeMitigationActionRequest.html#structfield.action_name) member.
+    pub fn action_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.action_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeMitigationActionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeMitigationActionRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeMitigationActionRequest {
+            action_name: self
+                .action_name
+                .ok_or(::rusoto_core::BuildError::missing("action_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeMitigationActionResponse {
@@ -2410,15 +7056,17 @@ pub struct DescribeMitigationActionResponse {
     /// <p>The type of mitigation action.</p>
     #[serde(rename = "actionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_type: Option<String>,
+    pub action_type: Option<MitigationActionType>,
     /// <p>The date and time when the mitigation action was added to your AWS account.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The date and time when the mitigation action was last changed.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The ARN of the IAM role used to apply this action.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2432,6 +7080,38 @@ pub struct DescribeRoleAliasRequest {
     pub role_alias: String,
 }
 
+impl DescribeRoleAliasRequest {
+    /// Returns a builder for `DescribeRoleAliasRequest`, checking its required members when built.
+    pub fn builder() -> DescribeRoleAliasRequestBuilder {
+        DescribeRoleAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRoleAliasRequest`](struct.DescribeRoleAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeRoleAliasRequestBuilder {
+    role_alias: Option<String>,
+}
+
+impl DescribeRoleAliasRequestBuilder {
+    /// Sets the [`role_alias`](struct.DescribeRoleAliasRequest.html#structfield.role_alias) member.
+    pub fn role_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_alias = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRoleAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeRoleAliasRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeRoleAliasRequest {
+            role_alias: self
+                .role_alias
+                .ok_or(::rusoto_core::BuildError::missing("role_alias"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeRoleAliasResponse {
@@ -2448,6 +7128,38 @@ pub struct DescribeScheduledAuditRequest {
     pub scheduled_audit_name: String,
 }
 
+impl DescribeScheduledAuditRequest {
+    /// Returns a builder for `DescribeScheduledAuditRequest`, checking its required members when built.
+    pub fn builder() -> DescribeScheduledAuditRequestBuilder {
+        DescribeScheduledAuditRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeScheduledAuditRequest`](struct.DescribeScheduledAuditRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeScheduledAuditRequestBuilder {
+    scheduled_audit_name: Option<String>,
+}
+
+impl DescribeScheduledAuditRequestBuilder {
+    /// Sets the [`scheduled_audit_name`](struct.DescribeScheduledAuditRequest.html#structfield.scheduled_audit_name) member.
+    pub fn scheduled_audit_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scheduled_audit_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeScheduledAuditRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeScheduledAuditRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeScheduledAuditRequest {
+            scheduled_audit_name: self
+                .scheduled_audit_name
+                .ok_or(::rusoto_core::BuildError::missing("scheduled_audit_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeScheduledAuditResponse {
@@ -2458,11 +7170,11 @@ pub struct DescribeScheduledAuditResponse {
     /// <p>The day of the week on which the scheduled audit takes place. One of "SUN", "MON", "TUE", "WED", "THU", "FRI", or "SAT".</p>
     #[serde(rename = "dayOfWeek")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub day_of_week: Option<String>,
+    pub day_of_week: Option<DayOfWeek>,
     /// <p>How often the scheduled audit takes place. One of "DAILY", "WEEKLY", "BIWEEKLY", or "MONTHLY". The start time of each audit is determined by the system.</p>
     #[serde(rename = "frequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<String>,
+    pub frequency: Option<AuditFrequency>,
     /// <p>The ARN of the scheduled audit.</p>
     #[serde(rename = "scheduledAuditArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2484,6 +7196,38 @@ pub struct DescribeSecurityProfileRequest {
     pub security_profile_name: String,
 }
 
+impl DescribeSecurityProfileRequest {
+    /// Returns a builder for `DescribeSecurityProfileRequest`, checking its required members when built.
+    pub fn builder() -> DescribeSecurityProfileRequestBuilder {
+        DescribeSecurityProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSecurityProfileRequest`](struct.DescribeSecurityProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeSecurityProfileRequestBuilder {
+    security_profile_name: Option<String>,
+}
+
+impl DescribeSecurityProfileRequestBuilder {
+    /// Sets the [`security_profile_name`](struct.DescribeSecurityProfileRequest.html#structfield.security_profile_name) member.
+    pub fn security_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSecurityProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSecurityProfileRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeSecurityProfileRequest {
+            security_profile_name: self
+                .security_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("security_profile_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeSecurityProfileResponse {
@@ -2494,19 +7238,21 @@ pub struct DescribeSecurityProfileResponse {
     /// <p>Where the alerts are sent. (Alerts are always sent to the console.)</p>
     #[serde(rename = "alertTargets")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alert_targets: Option<::std::collections::HashMap<String, AlertTarget>>,
+    pub alert_targets: Option<::std::collections::HashMap<AlertTargetType, AlertTarget>>,
     /// <p>Specifies the behaviors that, when violated by a device (thing), cause an alert.</p>
     #[serde(rename = "behaviors")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub behaviors: Option<Vec<Behavior>>,
     /// <p>The time the security profile was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The time the security profile was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The ARN of the security profile.</p>
     #[serde(rename = "securityProfileArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2532,6 +7278,36 @@ pub struct DescribeStreamRequest {
     pub stream_id: String,
 }
 
+impl DescribeStreamRequest {
+    /// Returns a builder for `DescribeStreamRequest`, checking its required members when built.
+    pub fn builder() -> DescribeStreamRequestBuilder {
+        DescribeStreamRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStreamRequest`](struct.DescribeStreamRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeStreamRequestBuilder {
+    stream_id: Option<String>,
+}
+
+impl DescribeStreamRequestBuilder {
+    /// Sets the [`stream_id`](struct.DescribeStreamRequest.html#structfield.stream_id) member.
+    pub fn stream_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStreamRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeStreamRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeStreamRequest {
+            stream_id: self
+                .stream_id
+                .ok_or(::rusoto_core::BuildError::missing("stream_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeStreamResponse {
@@ -2548,6 +7324,38 @@ pub struct DescribeThingGroupRequest {
     pub thing_group_name: String,
 }
 
+impl DescribeThingGroupRequest {
+    /// Returns a builder for `DescribeThingGroupRequest`, checking its required members when built.
+    pub fn builder() -> DescribeThingGroupRequestBuilder {
+        DescribeThingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeThingGroupRequest`](struct.DescribeThingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeThingGroupRequestBuilder {
+    thing_group_name: Option<String>,
+}
+
+impl DescribeThingGroupRequestBuilder {
+    /// Sets the [`thing_group_name`](struct.DescribeThingGroupRequest.html#structfield.thing_group_name) member.
+    pub fn thing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeThingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeThingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeThingGroupRequest {
+            thing_group_name: self
+                .thing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeThingGroupResponse {
@@ -2566,7 +7374,7 @@ pub struct DescribeThingGroupResponse {
     /// <p>The dynamic thing group status.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DynamicGroupStatus>,
     /// <p>The thing group ARN.</p>
     #[serde(rename = "thingGroupArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2600,13 +7408,47 @@ pub struct DescribeThingRegistrationTaskRequest {
     pub task_id: String,
 }
 
+impl DescribeThingRegistrationTaskRequest {
+    /// Returns a builder for `DescribeThingRegistrationTaskRequest`, checking its required members when built.
+    pub fn builder() -> DescribeThingRegistrationTaskRequestBuilder {
+        DescribeThingRegistrationTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeThingRegistrationTaskRequest`](struct.DescribeThingRegistrationTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeThingRegistrationTaskRequestBuilder {
+    task_id: Option<String>,
+}
+
+impl DescribeThingRegistrationTaskRequestBuilder {
+    /// Sets the [`task_id`](struct.DescribeThingRegistrationTaskRequest.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeThingRegistrationTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeThingRegistrationTaskRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeThingRegistrationTaskRequest {
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeThingRegistrationTaskResponse {
     /// <p>The task creation date.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The number of things that failed to be provisioned.</p>
     #[serde(rename = "failureCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2621,8 +7463,9 @@ pub struct DescribeThingRegistrationTaskResponse {
     pub input_file_key: Option<String>,
     /// <p>The date when the task was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The message.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2638,7 +7481,7 @@ pub struct DescribeThingRegistrationTaskResponse {
     /// <p>The status of the bulk thing provisioning task.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The number of things successfully provisioned.</p>
     #[serde(rename = "successCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2661,6 +7504,36 @@ pub struct DescribeThingRequest {
     pub thing_name: String,
 }
 
+impl DescribeThingRequest {
+    /// Returns a builder for `DescribeThingRequest`, checking its required members when built.
+    pub fn builder() -> DescribeThingRequestBuilder {
+        DescribeThingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeThingRequest`](struct.DescribeThingRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeThingRequestBuilder {
+    thing_name: Option<String>,
+}
+
+impl DescribeThingRequestBuilder {
+    /// Sets the [`thing_name`](struct.DescribeThingRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeThingRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeThingRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeThingRequest {
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 /// <p>The output from the DescribeThing operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2707,6 +7580,38 @@ pub struct DescribeThingTypeRequest {
     pub thing_type_name: String,
 }
 
+impl DescribeThingTypeRequest {
+    /// Returns a builder for `DescribeThingTypeRequest`, checking its required members when built.
+    pub fn builder() -> DescribeThingTypeRequestBuilder {
+        DescribeThingTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeThingTypeRequest`](struct.DescribeThingTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeThingTypeRequestBuilder {
+    thing_type_name: Option<String>,
+}
+
+impl DescribeThingTypeRequestBuilder {
+    /// Sets the [`thing_type_name`](struct.DescribeThingTypeRequest.html#structfield.thing_type_name) member.
+    pub fn thing_type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeThingTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeThingTypeRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeThingTypeRequest {
+            thing_type_name: self
+                .thing_type_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_type_name"))?,
+        })
+    }
+}
+
 /// <p>The output for the DescribeThingType operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2752,6 +7657,46 @@ pub struct DetachPolicyRequest {
     pub target: String,
 }
 
+impl DetachPolicyRequest {
+    /// Returns a builder for `DetachPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DetachPolicyRequestBuilder {
+        DetachPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachPolicyRequest`](struct.DetachPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachPolicyRequestBuilder {
+    policy_name: Option<String>,
+    target: Option<String>,
+}
+
+impl DetachPolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.DetachPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target`](struct.DetachPolicyRequest.html#structfield.target) member.
+    pub fn target<T: Into<String>>(mut self, value: T) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetachPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DetachPolicyRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            target: self
+                .target
+                .ok_or(::rusoto_core::BuildError::missing("target"))?,
+        })
+    }
+}
+
 /// <p>The input for the DetachPrincipalPolicy operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DetachPrincipalPolicyRequest {
@@ -2763,6 +7708,48 @@ pub struct DetachPrincipalPolicyRequest {
     pub principal: String,
 }
 
+impl DetachPrincipalPolicyRequest {
+    /// Returns a builder for `DetachPrincipalPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DetachPrincipalPolicyRequestBuilder {
+        DetachPrincipalPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachPrincipalPolicyRequest`](struct.DetachPrincipalPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachPrincipalPolicyRequestBuilder {
+    policy_name: Option<String>,
+    principal: Option<String>,
+}
+
+impl DetachPrincipalPolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.DetachPrincipalPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.DetachPrincipalPolicyRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachPrincipalPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetachPrincipalPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DetachPrincipalPolicyRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            principal: self
+                .principal
+                .ok_or(::rusoto_core::BuildError::missing("principal"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DetachSecurityProfileRequest {
     /// <p>The security profile that is detached.</p>
@@ -2773,6 +7760,48 @@ pub struct DetachSecurityProfileRequest {
     pub security_profile_target_arn: String,
 }
 
+impl DetachSecurityProfileRequest {
+    /// Returns a builder for `DetachSecurityProfileRequest`, checking its required members when built.
+    pub fn builder() -> DetachSecurityProfileRequestBuilder {
+        DetachSecurityProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachSecurityProfileRequest`](struct.DetachSecurityProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachSecurityProfileRequestBuilder {
+    security_profile_name: Option<String>,
+    security_profile_target_arn: Option<String>,
+}
+
+impl DetachSecurityProfileRequestBuilder {
+    /// Sets the [`security_profile_name`](struct.DetachSecurityProfileRequest.html#structfield.security_profile_name) member.
+    pub fn security_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_target_arn`](struct.DetachSecurityProfileRequest.html#structfield.security_profile_target_arn) member.
+    pub fn security_profile_target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_target_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachSecurityProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetachSecurityProfileRequest, ::rusoto_core::BuildError> {
+        Ok(DetachSecurityProfileRequest {
+            security_profile_name: self
+                .security_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("security_profile_name"))?,
+            security_profile_target_arn: self.security_profile_target_arn.ok_or(
+                ::rusoto_core::BuildError::missing("security_profile_target_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetachSecurityProfileResponse {}
@@ -2788,11 +7817,129 @@ pub struct DetachThingPrincipalRequest {
     pub thing_name: String,
 }
 
+impl DetachThingPrincipalRequest {
+    /// Returns a builder for `DetachThingPrincipalRequest`, checking its required members when built.
+    pub fn builder() -> DetachThingPrincipalRequestBuilder {
+        DetachThingPrincipalRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachThingPrincipalRequest`](struct.DetachThingPrincipalRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachThingPrincipalRequestBuilder {
+    principal: Option<String>,
+    thing_name: Option<String>,
+}
+
+impl DetachThingPrincipalRequestBuilder {
+    /// Sets the [`principal`](struct.DetachThingPrincipalRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.DetachThingPrincipalRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachThingPrincipalRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetachThingPrincipalRequest, ::rusoto_core::BuildError> {
+        Ok(DetachThingPrincipalRequest {
+            principal: self
+                .principal
+                .ok_or(::rusoto_core::BuildError::missing("principal"))?,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 /// <p>The output from the DetachThingPrincipal operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetachThingPrincipalResponse {}
 
+/// <p>The action that you want to apply to the device cerrtificate. The only supported value is <code>DEACTIVATE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceCertificateUpdateAction {
+    /// <p><code>DEACTIVATE</code></p>
+    Deactivate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeviceCertificateUpdateAction {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeviceCertificateUpdateAction::Deactivate => "DEACTIVATE",
+            DeviceCertificateUpdateAction::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeviceCertificateUpdateAction {
+    fn from(value: &'a str) -> DeviceCertificateUpdateAction {
+        match value {
+            "DEACTIVATE" => DeviceCertificateUpdateAction::Deactivate,
+            _ => DeviceCertificateUpdateAction::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeviceCertificateUpdateAction {
+    fn from(value: String) -> DeviceCertificateUpdateAction {
+        DeviceCertificateUpdateAction::from(value.as_str())
+    }
+}
+
+impl From<DeviceCertificateUpdateAction> for String {
+    fn from(value: DeviceCertificateUpdateAction) -> String {
+        match value {
+            DeviceCertificateUpdateAction::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeviceCertificateUpdateAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeviceCertificateUpdateAction {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeviceCertificateUpdateAction {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeviceCertificateUpdateAction {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<DeviceCertificateUpdateAction, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeviceCertificateUpdateAction::from(value))
+    }
+}
+
 /// <p>The input for the DisableTopicRuleRequest operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DisableTopicRuleRequest {
@@ -2801,6 +7948,120 @@ pub struct DisableTopicRuleRequest {
     pub rule_name: String,
 }
 
+impl DisableTopicRuleRequest {
+    /// Returns a builder for `DisableTopicRuleRequest`, checking its required members when built.
+    pub fn builder() -> DisableTopicRuleRequestBuilder {
+        DisableTopicRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisableTopicRuleRequest`](struct.DisableTopicRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct DisableTopicRuleRequestBuilder {
+    rule_name: Option<String>,
+}
+
+impl DisableTopicRuleRequestBuilder {
+    /// Sets the [`rule_name`](struct.DisableTopicRuleRequest.html#structfield.rule_name) member.
+    pub fn rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableTopicRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisableTopicRuleRequest, ::rusoto_core::BuildError> {
+        Ok(DisableTopicRuleRequest {
+            rule_name: self
+                .rule_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_name"))?,
+        })
+    }
+}
+
+/// <p>The dynamic thing group status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DynamicGroupStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>BUILDING</code></p>
+    Building,
+    /// <p><code>REBUILDING</code></p>
+    Rebuilding,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DynamicGroupStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DynamicGroupStatus::Active => "ACTIVE",
+            DynamicGroupStatus::Building => "BUILDING",
+            DynamicGroupStatus::Rebuilding => "REBUILDING",
+            DynamicGroupStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DynamicGroupStatus {
+    fn from(value: &'a str) -> DynamicGroupStatus {
+        match value {
+            "ACTIVE" => DynamicGroupStatus::Active,
+            "BUILDING" => DynamicGroupStatus::Building,
+            "REBUILDING" => DynamicGroupStatus::Rebuilding,
+            _ => DynamicGroupStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DynamicGroupStatus {
+    fn from(value: String) -> DynamicGroupStatus {
+        DynamicGroupStatus::from(value.as_str())
+    }
+}
+
+impl From<DynamicGroupStatus> for String {
+    fn from(value: DynamicGroupStatus) -> String {
+        match value {
+            DynamicGroupStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DynamicGroupStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DynamicGroupStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DynamicGroupStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DynamicGroupStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DynamicGroupStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DynamicGroupStatus::from(value))
+    }
+}
+
 /// <p>Describes an action to write to a DynamoDB table.</p> <p>The <code>tableName</code>, <code>hashKeyField</code>, and <code>rangeKeyField</code> values must match the values used when you created the table.</p> <p>The <code>hashKeyValue</code> and <code>rangeKeyvalue</code> fields use a substitution template syntax. These templates provide data at runtime. The syntax is as follows: ${<i>sql-expression</i>}.</p> <p>You can specify any valid expression in a WHERE or SELECT clause, including JSON properties, comparisons, calculations, and functions. For example, the following field uses the third level of the topic:</p> <p> <code>"hashKeyValue": "${topic(3)}"</code> </p> <p>The following field uses the timestamp:</p> <p> <code>"rangeKeyValue": "${timestamp()}"</code> </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DynamoDBAction {
@@ -2810,7 +8071,7 @@ pub struct DynamoDBAction {
     /// <p>The hash key type. Valid values are "STRING" or "NUMBER"</p>
     #[serde(rename = "hashKeyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hash_key_type: Option<String>,
+    pub hash_key_type: Option<DynamoKeyType>,
     /// <p>The hash key value.</p>
     #[serde(rename = "hashKeyValue")]
     pub hash_key_value: String,
@@ -2829,7 +8090,7 @@ pub struct DynamoDBAction {
     /// <p>The range key type. Valid values are "STRING" or "NUMBER"</p>
     #[serde(rename = "rangeKeyType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub range_key_type: Option<String>,
+    pub range_key_type: Option<DynamoKeyType>,
     /// <p>The range key value.</p>
     #[serde(rename = "rangeKeyValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2853,16 +8114,94 @@ pub struct DynamoDBv2Action {
     pub role_arn: String,
 }
 
-/// <p>The policy that has the effect on the authorization results.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct EffectivePolicy {
-    /// <p>The policy ARN.</p>
-    #[serde(rename = "policyArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy_arn: Option<String>,
-    /// <p>The IAM policy document.</p>
-    #[serde(rename = "policyDocument")]
+/// <p>The hash key type. Valid values are "STRING" or "NUMBER"</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DynamoKeyType {
+    /// <p><code>STRING</code></p>
+    String,
+    /// <p><code>NUMBER</code></p>
+    Number,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DynamoKeyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DynamoKeyType::String => "STRING",
+            DynamoKeyType::Number => "NUMBER",
+            DynamoKeyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DynamoKeyType {
+    fn from(value: &'a str) -> DynamoKeyType {
+        match value {
+            "STRING" => DynamoKeyType::String,
+            "NUMBER" => DynamoKeyType::Number,
+            _ => DynamoKeyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DynamoKeyType {
+    fn from(value: String) -> DynamoKeyType {
+        DynamoKeyType::from(value.as_str())
+    }
+}
+
+impl From<DynamoKeyType> for String {
+    fn from(value: DynamoKeyType) -> String {
+        match value {
+            DynamoKeyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DynamoKeyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DynamoKeyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DynamoKeyType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DynamoKeyType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DynamoKeyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DynamoKeyType::from(value))
+    }
+}
+
+/// <p>The policy that has the effect on the authorization results.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct EffectivePolicy {
+    /// <p>The policy ARN.</p>
+    #[serde(rename = "policyArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy_arn: Option<String>,
+    /// <p>The IAM policy document.</p>
+    #[serde(rename = "policyDocument")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub policy_document: Option<String>,
     /// <p>The policy name.</p>
@@ -2892,11 +8231,11 @@ pub struct ElasticsearchAction {
 }
 
 /// <p>Parameters used when defining a mitigation action that enable AWS IoT logging.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnableIoTLoggingParams {
     /// <p>Specifies the types of information to be logged.</p>
     #[serde(rename = "logLevel")]
-    pub log_level: String,
+    pub log_level: LogLevel,
     /// <p>The ARN of the IAM role used for logging.</p>
     #[serde(rename = "roleArnForLogging")]
     pub role_arn_for_logging: String,
@@ -2910,6 +8249,36 @@ pub struct EnableTopicRuleRequest {
     pub rule_name: String,
 }
 
+impl EnableTopicRuleRequest {
+    /// Returns a builder for `EnableTopicRuleRequest`, checking its required members when built.
+    pub fn builder() -> EnableTopicRuleRequestBuilder {
+        EnableTopicRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableTopicRuleRequest`](struct.EnableTopicRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableTopicRuleRequestBuilder {
+    rule_name: Option<String>,
+}
+
+impl EnableTopicRuleRequestBuilder {
+    /// Sets the [`rule_name`](struct.EnableTopicRuleRequest.html#structfield.rule_name) member.
+    pub fn rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableTopicRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<EnableTopicRuleRequest, ::rusoto_core::BuildError> {
+        Ok(EnableTopicRuleRequest {
+            rule_name: self
+                .rule_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_name"))?,
+        })
+    }
+}
+
 /// <p>Error information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2924,6 +8293,120 @@ pub struct ErrorInfo {
     pub message: Option<String>,
 }
 
+/// <p>The event configurations.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventType {
+    /// <p><code>THING</code></p>
+    Thing,
+    /// <p><code>THING_GROUP</code></p>
+    ThingGroup,
+    /// <p><code>THING_TYPE</code></p>
+    ThingType,
+    /// <p><code>THING_GROUP_MEMBERSHIP</code></p>
+    ThingGroupMembership,
+    /// <p><code>THING_GROUP_HIERARCHY</code></p>
+    ThingGroupHierarchy,
+    /// <p><code>THING_TYPE_ASSOCIATION</code></p>
+    ThingTypeAssociation,
+    /// <p><code>JOB</code></p>
+    Job,
+    /// <p><code>JOB_EXECUTION</code></p>
+    JobExecution,
+    /// <p><code>POLICY</code></p>
+    Policy,
+    /// <p><code>CERTIFICATE</code></p>
+    Certificate,
+    /// <p><code>CA_CERTIFICATE</code></p>
+    CaCertificate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::Thing => "THING",
+            EventType::ThingGroup => "THING_GROUP",
+            EventType::ThingType => "THING_TYPE",
+            EventType::ThingGroupMembership => "THING_GROUP_MEMBERSHIP",
+            EventType::ThingGroupHierarchy => "THING_GROUP_HIERARCHY",
+            EventType::ThingTypeAssociation => "THING_TYPE_ASSOCIATION",
+            EventType::Job => "JOB",
+            EventType::JobExecution => "JOB_EXECUTION",
+            EventType::Policy => "POLICY",
+            EventType::Certificate => "CERTIFICATE",
+            EventType::CaCertificate => "CA_CERTIFICATE",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "THING" => EventType::Thing,
+            "THING_GROUP" => EventType::ThingGroup,
+            "THING_TYPE" => EventType::ThingType,
+            "THING_GROUP_MEMBERSHIP" => EventType::ThingGroupMembership,
+            "THING_GROUP_HIERARCHY" => EventType::ThingGroupHierarchy,
+            "THING_TYPE_ASSOCIATION" => EventType::ThingTypeAssociation,
+            "JOB" => EventType::Job,
+            "JOB_EXECUTION" => EventType::JobExecution,
+            "POLICY" => EventType::Policy,
+            "CERTIFICATE" => EventType::Certificate,
+            "CA_CERTIFICATE" => EventType::CaCertificate,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        EventType::from(value.as_str())
+    }
+}
+
+impl From<EventType> for String {
+    fn from(value: EventType) -> String {
+        match value {
+            EventType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EventType::from(value))
+    }
+}
+
 /// <p>Information that explicitly denies authorization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2992,6 +8475,52 @@ pub struct GetEffectivePoliciesRequest {
     pub thing_name: Option<String>,
 }
 
+impl GetEffectivePoliciesRequest {
+    /// Returns a builder for `GetEffectivePoliciesRequest`, checking its required members when built.
+    pub fn builder() -> GetEffectivePoliciesRequestBuilder {
+        GetEffectivePoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetEffectivePoliciesRequest`](struct.GetEffectivePoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetEffectivePoliciesRequestBuilder {
+    cognito_identity_pool_id: Option<String>,
+    principal: Option<String>,
+    thing_name: Option<String>,
+}
+
+impl GetEffectivePoliciesRequestBuilder {
+    /// Sets the [`cognito_identity_pool_id`](struct.GetEffectivePoliciesRequest.html#structfield.cognito_identity_pool_id) member.
+    pub fn cognito_identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cognito_identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.GetEffectivePoliciesRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.GetEffectivePoliciesRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetEffectivePoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetEffectivePoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(GetEffectivePoliciesRequest {
+            cognito_identity_pool_id: self.cognito_identity_pool_id,
+            principal: self.principal,
+            thing_name: self.thing_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetEffectivePoliciesResponse {
@@ -3024,6 +8553,36 @@ pub struct GetJobDocumentRequest {
     pub job_id: String,
 }
 
+impl GetJobDocumentRequest {
+    /// Returns a builder for `GetJobDocumentRequest`, checking its required members when built.
+    pub fn builder() -> GetJobDocumentRequestBuilder {
+        GetJobDocumentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetJobDocumentRequest`](struct.GetJobDocumentRequest.html).
+#[derive(Default, Debug)]
+pub struct GetJobDocumentRequestBuilder {
+    job_id: Option<String>,
+}
+
+impl GetJobDocumentRequestBuilder {
+    /// Sets the [`job_id`](struct.GetJobDocumentRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetJobDocumentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetJobDocumentRequest, ::rusoto_core::BuildError> {
+        Ok(GetJobDocumentRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetJobDocumentResponse {
@@ -3044,7 +8603,7 @@ pub struct GetLoggingOptionsResponse {
     /// <p>The logging level.</p>
     #[serde(rename = "logLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_level: Option<String>,
+    pub log_level: Option<LogLevel>,
     /// <p>The ARN of the IAM role that grants access.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3058,6 +8617,36 @@ pub struct GetOTAUpdateRequest {
     pub ota_update_id: String,
 }
 
+impl GetOTAUpdateRequest {
+    /// Returns a builder for `GetOTAUpdateRequest`, checking its required members when built.
+    pub fn builder() -> GetOTAUpdateRequestBuilder {
+        GetOTAUpdateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetOTAUpdateRequest`](struct.GetOTAUpdateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetOTAUpdateRequestBuilder {
+    ota_update_id: Option<String>,
+}
+
+impl GetOTAUpdateRequestBuilder {
+    /// Sets the [`ota_update_id`](struct.GetOTAUpdateRequest.html#structfield.ota_update_id) member.
+    pub fn ota_update_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ota_update_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOTAUpdateRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetOTAUpdateRequest, ::rusoto_core::BuildError> {
+        Ok(GetOTAUpdateRequest {
+            ota_update_id: self
+                .ota_update_id
+                .ok_or(::rusoto_core::BuildError::missing("ota_update_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetOTAUpdateResponse {
@@ -3075,14 +8664,45 @@ pub struct GetPolicyRequest {
     pub policy_name: String,
 }
 
+impl GetPolicyRequest {
+    /// Returns a builder for `GetPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetPolicyRequestBuilder {
+        GetPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPolicyRequest`](struct.GetPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPolicyRequestBuilder {
+    policy_name: Option<String>,
+}
+
+impl GetPolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.GetPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetPolicyRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// <p>The output from the GetPolicy operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPolicyResponse {
     /// <p>The date the policy was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The default policy version ID.</p>
     #[serde(rename = "defaultVersionId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3093,8 +8713,9 @@ pub struct GetPolicyResponse {
     pub generation_id: Option<String>,
     /// <p>The date the policy was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The policy ARN.</p>
     #[serde(rename = "policyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3120,14 +8741,57 @@ pub struct GetPolicyVersionRequest {
     pub policy_version_id: String,
 }
 
+impl GetPolicyVersionRequest {
+    /// Returns a builder for `GetPolicyVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetPolicyVersionRequestBuilder {
+        GetPolicyVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPolicyVersionRequest`](struct.GetPolicyVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPolicyVersionRequestBuilder {
+    policy_name: Option<String>,
+    policy_version_id: Option<String>,
+}
+
+impl GetPolicyVersionRequestBuilder {
+    /// Sets the [`policy_name`](struct.GetPolicyVersionRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_version_id`](struct.GetPolicyVersionRequest.html#structfield.policy_version_id) member.
+    pub fn policy_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPolicyVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetPolicyVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetPolicyVersionRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            policy_version_id: self
+                .policy_version_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_version_id"))?,
+        })
+    }
+}
+
 /// <p>The output from the GetPolicyVersion operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPolicyVersionResponse {
     /// <p>The date the policy version was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The generation ID of the policy version.</p>
     #[serde(rename = "generationId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3138,8 +8802,9 @@ pub struct GetPolicyVersionResponse {
     pub is_default_version: Option<bool>,
     /// <p>The date the policy version was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The policy ARN.</p>
     #[serde(rename = "policyArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3191,6 +8856,60 @@ pub struct GetStatisticsRequest {
     pub query_version: Option<String>,
 }
 
+impl GetStatisticsRequest {
+    /// Returns a builder for `GetStatisticsRequest`, checking its required members when built.
+    pub fn builder() -> GetStatisticsRequestBuilder {
+        GetStatisticsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetStatisticsRequest`](struct.GetStatisticsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetStatisticsRequestBuilder {
+    aggregation_field: Option<String>,
+    index_name: Option<String>,
+    query_string: Option<String>,
+    query_version: Option<String>,
+}
+
+impl GetStatisticsRequestBuilder {
+    /// Sets the [`aggregation_field`](struct.GetStatisticsRequest.html#structfield.aggregation_field) member.
+    pub fn aggregation_field<T: Into<String>>(mut self, value: T) -> Self {
+        self.aggregation_field = Some(value.into());
+        self
+    }
+
+    /// Sets the [`index_name`](struct.GetStatisticsRequest.html#structfield.index_name) member.
+    pub fn index_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.index_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_string`](struct.GetStatisticsRequest.html#structfield.query_string) member.
+    pub fn query_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_version`](struct.GetStatisticsRequest.html#structfield.query_version) member.
+    pub fn query_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetStatisticsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetStatisticsRequest, ::rusoto_core::BuildError> {
+        Ok(GetStatisticsRequest {
+            aggregation_field: self.aggregation_field,
+            index_name: self.index_name,
+            query_string: self
+                .query_string
+                .ok_or(::rusoto_core::BuildError::missing("query_string"))?,
+            query_version: self.query_version,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetStatisticsResponse {
@@ -3208,6 +8927,36 @@ pub struct GetTopicRuleRequest {
     pub rule_name: String,
 }
 
+impl GetTopicRuleRequest {
+    /// Returns a builder for `GetTopicRuleRequest`, checking its required members when built.
+    pub fn builder() -> GetTopicRuleRequestBuilder {
+        GetTopicRuleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetTopicRuleRequest`](struct.GetTopicRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct GetTopicRuleRequestBuilder {
+    rule_name: Option<String>,
+}
+
+impl GetTopicRuleRequestBuilder {
+    /// Sets the [`rule_name`](struct.GetTopicRuleRequest.html#structfield.rule_name) member.
+    pub fn rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTopicRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTopicRuleRequest, ::rusoto_core::BuildError> {
+        Ok(GetTopicRuleRequest {
+            rule_name: self
+                .rule_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_name"))?,
+        })
+    }
+}
+
 /// <p>The output from the GetTopicRule operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3231,7 +8980,7 @@ pub struct GetV2LoggingOptionsResponse {
     /// <p>The default log level.</p>
     #[serde(rename = "defaultLogLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_log_level: Option<String>,
+    pub default_log_level: Option<LogLevel>,
     /// <p>Disables all logs.</p>
     #[serde(rename = "disableAllLogs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3266,6 +9015,88 @@ pub struct ImplicitDeny {
     pub policies: Option<Vec<Policy>>,
 }
 
+/// <p>The index status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IndexStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>BUILDING</code></p>
+    Building,
+    /// <p><code>REBUILDING</code></p>
+    Rebuilding,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl IndexStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IndexStatus::Active => "ACTIVE",
+            IndexStatus::Building => "BUILDING",
+            IndexStatus::Rebuilding => "REBUILDING",
+            IndexStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for IndexStatus {
+    fn from(value: &'a str) -> IndexStatus {
+        match value {
+            "ACTIVE" => IndexStatus::Active,
+            "BUILDING" => IndexStatus::Building,
+            "REBUILDING" => IndexStatus::Rebuilding,
+            _ => IndexStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for IndexStatus {
+    fn from(value: String) -> IndexStatus {
+        IndexStatus::from(value.as_str())
+    }
+}
+
+impl From<IndexStatus> for String {
+    fn from(value: IndexStatus) -> String {
+        match value {
+            IndexStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IndexStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for IndexStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for IndexStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IndexStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IndexStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IndexStatus::from(value))
+    }
+}
+
 /// <p>Sends message data to an AWS IoT Analytics channel.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IotAnalyticsAction {
@@ -3312,12 +9143,14 @@ pub struct Job {
     pub comment: Option<String>,
     /// <p>The time, in seconds since the epoch, when the job was completed.</p>
     #[serde(rename = "completedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<f64>,
+    pub completed_at: Option<EpochTimestamp>,
     /// <p>The time, in seconds since the epoch, when the job was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>A short text description of the job.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3344,8 +9177,9 @@ pub struct Job {
     pub job_process_details: Option<JobProcessDetails>,
     /// <p>The time, in seconds since the epoch, when the job was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>Configuration for pre-signed S3 URLs.</p>
     #[serde(rename = "presignedUrlConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3357,11 +9191,11 @@ pub struct Job {
     /// <p>The status of the job, one of <code>IN_PROGRESS</code>, <code>CANCELED</code>, <code>DELETION_IN_PROGRESS</code> or <code>COMPLETED</code>. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobStatus>,
     /// <p>Specifies whether the job will continue to run (CONTINUOUS), or will be complete after all those things specified as targets have completed the job (SNAPSHOT). If continuous, the job may also be run on a thing when a change is detected in a target. For example, a job will run on a device when the thing representing the device is added to a target group, even after the job was completed by all things originally in the group. </p>
     #[serde(rename = "targetSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_selection: Option<String>,
+    pub target_selection: Option<TargetSelection>,
     /// <p>A list of IoT things and thing groups to which the job should be sent.</p>
     #[serde(rename = "targets")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3394,20 +9228,23 @@ pub struct JobExecution {
     pub job_id: Option<String>,
     /// <p>The time, in seconds since the epoch, when the job execution was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>The time, in seconds since the epoch, when the job execution was queued.</p>
     #[serde(rename = "queuedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub queued_at: Option<f64>,
+    pub queued_at: Option<EpochTimestamp>,
     /// <p>The time, in seconds since the epoch, when the job execution started.</p>
     #[serde(rename = "startedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<EpochTimestamp>,
     /// <p>The status of the job execution (IN_PROGRESS, QUEUED, FAILED, SUCCEEDED, TIMED_OUT, CANCELED, or REJECTED).</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobExecutionStatus>,
     /// <p>A collection of name/value pairs that describe the status of the job execution.</p>
     #[serde(rename = "statusDetails")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3422,51 +9259,242 @@ pub struct JobExecution {
     pub version_number: Option<i64>,
 }
 
-/// <p>Details of the job execution status.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct JobExecutionStatusDetails {
-    /// <p>The job execution status.</p>
-    #[serde(rename = "detailsMap")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub details_map: Option<::std::collections::HashMap<String, String>>,
+/// <p>The type of job execution failure to define a rule to initiate a job abort.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobExecutionFailureType {
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>REJECTED</code></p>
+    Rejected,
+    /// <p><code>TIMED_OUT</code></p>
+    TimedOut,
+    /// <p><code>ALL</code></p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobExecutionFailureType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobExecutionFailureType::Failed => "FAILED",
+            JobExecutionFailureType::Rejected => "REJECTED",
+            JobExecutionFailureType::TimedOut => "TIMED_OUT",
+            JobExecutionFailureType::All => "ALL",
+            JobExecutionFailureType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>The job execution summary.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct JobExecutionSummary {
-    /// <p>A string (consisting of the digits "0" through "9") which identifies this particular job execution on this particular device. It can be used later in commands which return or update job execution information.</p>
-    #[serde(rename = "executionNumber")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_number: Option<i64>,
-    /// <p>The time, in seconds since the epoch, when the job execution was last updated.</p>
-    #[serde(rename = "lastUpdatedAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
-    /// <p>The time, in seconds since the epoch, when the job execution was queued.</p>
-    #[serde(rename = "queuedAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub queued_at: Option<f64>,
-    /// <p>The time, in seconds since the epoch, when the job execution started.</p>
-    #[serde(rename = "startedAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
-    /// <p>The status of the job execution.</p>
-    #[serde(rename = "status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+impl<'a> From<&'a str> for JobExecutionFailureType {
+    fn from(value: &'a str) -> JobExecutionFailureType {
+        match value {
+            "FAILED" => JobExecutionFailureType::Failed,
+            "REJECTED" => JobExecutionFailureType::Rejected,
+            "TIMED_OUT" => JobExecutionFailureType::TimedOut,
+            "ALL" => JobExecutionFailureType::All,
+            _ => JobExecutionFailureType::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>Contains a summary of information about job executions for a specific job.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct JobExecutionSummaryForJob {
-    /// <p>Contains a subset of information about a job execution.</p>
-    #[serde(rename = "jobExecutionSummary")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_execution_summary: Option<JobExecutionSummary>,
-    /// <p>The ARN of the thing on which the job execution is running.</p>
+impl From<String> for JobExecutionFailureType {
+    fn from(value: String) -> JobExecutionFailureType {
+        JobExecutionFailureType::from(value.as_str())
+    }
+}
+
+impl From<JobExecutionFailureType> for String {
+    fn from(value: JobExecutionFailureType) -> String {
+        match value {
+            JobExecutionFailureType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobExecutionFailureType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobExecutionFailureType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobExecutionFailureType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobExecutionFailureType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobExecutionFailureType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobExecutionFailureType::from(value))
+    }
+}
+
+/// <p>The status of the job execution (IN_PROGRESS, QUEUED, FAILED, SUCCEEDED, TIMED_OUT, CANCELED, or REJECTED).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobExecutionStatus {
+    /// <p><code>QUEUED</code></p>
+    Queued,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>TIMED_OUT</code></p>
+    TimedOut,
+    /// <p><code>REJECTED</code></p>
+    Rejected,
+    /// <p><code>REMOVED</code></p>
+    Removed,
+    /// <p><code>CANCELED</code></p>
+    Canceled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobExecutionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobExecutionStatus::Queued => "QUEUED",
+            JobExecutionStatus::InProgress => "IN_PROGRESS",
+            JobExecutionStatus::Succeeded => "SUCCEEDED",
+            JobExecutionStatus::Failed => "FAILED",
+            JobExecutionStatus::TimedOut => "TIMED_OUT",
+            JobExecutionStatus::Rejected => "REJECTED",
+            JobExecutionStatus::Removed => "REMOVED",
+            JobExecutionStatus::Canceled => "CANCELED",
+            JobExecutionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobExecutionStatus {
+    fn from(value: &'a str) -> JobExecutionStatus {
+        match value {
+            "QUEUED" => JobExecutionStatus::Queued,
+            "IN_PROGRESS" => JobExecutionStatus::InProgress,
+            "SUCCEEDED" => JobExecutionStatus::Succeeded,
+            "FAILED" => JobExecutionStatus::Failed,
+            "TIMED_OUT" => JobExecutionStatus::TimedOut,
+            "REJECTED" => JobExecutionStatus::Rejected,
+            "REMOVED" => JobExecutionStatus::Removed,
+            "CANCELED" => JobExecutionStatus::Canceled,
+            _ => JobExecutionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobExecutionStatus {
+    fn from(value: String) -> JobExecutionStatus {
+        JobExecutionStatus::from(value.as_str())
+    }
+}
+
+impl From<JobExecutionStatus> for String {
+    fn from(value: JobExecutionStatus) -> String {
+        match value {
+            JobExecutionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobExecutionStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobExecutionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobExecutionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobExecutionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobExecutionStatus::from(value))
+    }
+}
+
+/// <p>Details of the job execution status.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct JobExecutionStatusDetails {
+    /// <p>The job execution status.</p>
+    #[serde(rename = "detailsMap")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_map: Option<::std::collections::HashMap<String, String>>,
+}
+
+/// <p>The job execution summary.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct JobExecutionSummary {
+    /// <p>A string (consisting of the digits "0" through "9") which identifies this particular job execution on this particular device. It can be used later in commands which return or update job execution information.</p>
+    #[serde(rename = "executionNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_number: Option<i64>,
+    /// <p>The time, in seconds since the epoch, when the job execution was last updated.</p>
+    #[serde(rename = "lastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_updated_at: Option<EpochTimestamp>,
+    /// <p>The time, in seconds since the epoch, when the job execution was queued.</p>
+    #[serde(rename = "queuedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queued_at: Option<EpochTimestamp>,
+    /// <p>The time, in seconds since the epoch, when the job execution started.</p>
+    #[serde(rename = "startedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<EpochTimestamp>,
+    /// <p>The status of the job execution.</p>
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<JobExecutionStatus>,
+}
+
+/// <p>Contains a summary of information about job executions for a specific job.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct JobExecutionSummaryForJob {
+    /// <p>Contains a subset of information about a job execution.</p>
+    #[serde(rename = "jobExecutionSummary")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_execution_summary: Option<JobExecutionSummary>,
+    /// <p>The ARN of the thing on which the job execution is running.</p>
     #[serde(rename = "thingArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thing_arn: Option<String>,
@@ -3541,18 +9569,110 @@ pub struct JobProcessDetails {
     pub processing_targets: Option<Vec<String>>,
 }
 
+/// <p>The status of the job, one of <code>IN_PROGRESS</code>, <code>CANCELED</code>, <code>DELETION_IN_PROGRESS</code> or <code>COMPLETED</code>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum JobStatus {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>CANCELED</code></p>
+    Canceled,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>DELETION_IN_PROGRESS</code></p>
+    DeletionInProgress,
+    /// <p><code>SCHEDULED</code></p>
+    Scheduled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl JobStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            JobStatus::InProgress => "IN_PROGRESS",
+            JobStatus::Canceled => "CANCELED",
+            JobStatus::Completed => "COMPLETED",
+            JobStatus::DeletionInProgress => "DELETION_IN_PROGRESS",
+            JobStatus::Scheduled => "SCHEDULED",
+            JobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for JobStatus {
+    fn from(value: &'a str) -> JobStatus {
+        match value {
+            "IN_PROGRESS" => JobStatus::InProgress,
+            "CANCELED" => JobStatus::Canceled,
+            "COMPLETED" => JobStatus::Completed,
+            "DELETION_IN_PROGRESS" => JobStatus::DeletionInProgress,
+            "SCHEDULED" => JobStatus::Scheduled,
+            _ => JobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for JobStatus {
+    fn from(value: String) -> JobStatus {
+        JobStatus::from(value.as_str())
+    }
+}
+
+impl From<JobStatus> for String {
+    fn from(value: JobStatus) -> String {
+        match value {
+            JobStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for JobStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for JobStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for JobStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<JobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(JobStatus::from(value))
+    }
+}
+
 /// <p>The job summary.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct JobSummary {
     /// <p>The time, in seconds since the epoch, when the job completed.</p>
     #[serde(rename = "completedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<f64>,
+    pub completed_at: Option<EpochTimestamp>,
     /// <p>The time, in seconds since the epoch, when the job was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The job ARN.</p>
     #[serde(rename = "jobArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3563,16 +9683,17 @@ pub struct JobSummary {
     pub job_id: Option<String>,
     /// <p>The time, in seconds since the epoch, when the job was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>The job summary status.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobStatus>,
     /// <p>Specifies whether the job will continue to run (CONTINUOUS), or will be complete after all those things specified as targets have completed the job (SNAPSHOT). If continuous, the job may also be run on a thing when a change is detected in a target. For example, a job will run on a thing when the thing is added to a target group, even after the job was completed by all things originally in the group.</p>
     #[serde(rename = "targetSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_selection: Option<String>,
+    pub target_selection: Option<TargetSelection>,
     /// <p>The ID of the thing group.</p>
     #[serde(rename = "thingGroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3636,6 +9757,60 @@ pub struct ListActiveViolationsRequest {
     pub thing_name: Option<String>,
 }
 
+impl ListActiveViolationsRequest {
+    /// Returns a builder for `ListActiveViolationsRequest`, checking its required members when built.
+    pub fn builder() -> ListActiveViolationsRequestBuilder {
+        ListActiveViolationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListActiveViolationsRequest`](struct.ListActiveViolationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListActiveViolationsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    security_profile_name: Option<String>,
+    thing_name: Option<String>,
+}
+
+impl ListActiveViolationsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListActiveViolationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListActiveViolationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_name`](struct.ListActiveViolationsRequest.html#structfield.security_profile_name) member.
+    pub fn security_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.ListActiveViolationsRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListActiveViolationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListActiveViolationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListActiveViolationsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            security_profile_name: self.security_profile_name,
+            thing_name: self.thing_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListActiveViolationsResponse {
@@ -3668,6 +9843,62 @@ pub struct ListAttachedPoliciesRequest {
     pub target: String,
 }
 
+impl ListAttachedPoliciesRequest {
+    /// Returns a builder for `ListAttachedPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListAttachedPoliciesRequestBuilder {
+        ListAttachedPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAttachedPoliciesRequest`](struct.ListAttachedPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAttachedPoliciesRequestBuilder {
+    marker: Option<String>,
+    page_size: Option<i64>,
+    recursive: Option<bool>,
+    target: Option<String>,
+}
+
+impl ListAttachedPoliciesRequestBuilder {
+    /// Sets the [`marker`](struct.ListAttachedPoliciesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListAttachedPoliciesRequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recursive`](struct.ListAttachedPoliciesRequest.html#structfield.recursive) member.
+    pub fn recursive<T: Into<bool>>(mut self, value: T) -> Self {
+        self.recursive = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target`](struct.ListAttachedPoliciesRequest.html#structfield.target) member.
+    pub fn target<T: Into<String>>(mut self, value: T) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAttachedPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAttachedPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListAttachedPoliciesRequest {
+            marker: self.marker,
+            page_size: self.page_size,
+            recursive: self.recursive,
+            target: self
+                .target
+                .ok_or(::rusoto_core::BuildError::missing("target"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAttachedPoliciesResponse {
@@ -3689,8 +9920,9 @@ pub struct ListAuditFindingsRequest {
     pub check_name: Option<String>,
     /// <p>A filter to limit results to those found before the specified time. You must specify either the startTime and endTime or the taskId, but not both.</p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The maximum number of results to return at one time. The default is 25.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3705,14 +9937,93 @@ pub struct ListAuditFindingsRequest {
     pub resource_identifier: Option<ResourceIdentifier>,
     /// <p>A filter to limit results to those found after the specified time. You must specify either the startTime and endTime or the taskId, but not both.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
     /// <p>A filter to limit results to the audit with the specified ID. You must specify either the taskId or the startTime and endTime, but not both.</p>
     #[serde(rename = "taskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub task_id: Option<String>,
 }
 
+impl ListAuditFindingsRequest {
+    /// Returns a builder for `ListAuditFindingsRequest`, checking its required members when built.
+    pub fn builder() -> ListAuditFindingsRequestBuilder {
+        ListAuditFindingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAuditFindingsRequest`](struct.ListAuditFindingsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAuditFindingsRequestBuilder {
+    check_name: Option<String>,
+    end_time: Option<EpochTimestamp>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_identifier: Option<ResourceIdentifier>,
+    start_time: Option<EpochTimestamp>,
+    task_id: Option<String>,
+}
+
+impl ListAuditFindingsRequestBuilder {
+    /// Sets the [`check_name`](struct.ListAuditFindingsRequest.html#structfield.check_name) member.
+    pub fn check_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.check_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.ListAuditFindingsRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAuditFindingsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAuditFindingsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_identifier`](struct.ListAuditFindingsRequest.html#structfield.resource_identifier) member.
+    pub fn resource_identifier<T: Into<ResourceIdentifier>>(mut self, value: T) -> Self {
+        self.resource_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.ListAuditFindingsRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_id`](struct.ListAuditFindingsRequest.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAuditFindingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAuditFindingsRequest, ::rusoto_core::BuildError> {
+        Ok(ListAuditFindingsRequest {
+            check_name: self.check_name,
+            end_time: self.end_time,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_identifier: self.resource_identifier,
+            start_time: self.start_time,
+            task_id: self.task_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAuditFindingsResponse {
@@ -3731,7 +10042,7 @@ pub struct ListAuditMitigationActionsExecutionsRequest {
     /// <p>Specify this filter to limit results to those with a specific status.</p>
     #[serde(rename = "actionStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_status: Option<String>,
+    pub action_status: Option<AuditMitigationActionsExecutionStatus>,
     /// <p>Specify this filter to limit results to those that were applied to a specific audit finding.</p>
     #[serde(rename = "findingId")]
     pub finding_id: String,
@@ -3748,6 +10059,76 @@ pub struct ListAuditMitigationActionsExecutionsRequest {
     pub task_id: String,
 }
 
+impl ListAuditMitigationActionsExecutionsRequest {
+    /// Returns a builder for `ListAuditMitigationActionsExecutionsRequest`, checking its required members when built.
+    pub fn builder() -> ListAuditMitigationActionsExecutionsRequestBuilder {
+        ListAuditMitigationActionsExecutionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAuditMitigationActionsExecutionsRequest`](struct.ListAuditMitigationActionsExecutionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAuditMitigationActionsExecutionsRequestBuilder {
+    action_status: Option<AuditMitigationActionsExecutionStatus>,
+    finding_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    task_id: Option<String>,
+}
+
+impl ListAuditMitigationActionsExecutionsRequestBuilder {
+    /// Sets the [`action_status`](struct.ListAuditMitigationActionsExecutionsRequest.html#structfield.action_status) member.
+    pub fn action_status<T: Into<AuditMitigationActionsExecutionStatus>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.action_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_id`](struct.ListAuditMitigationActionsExecutionsRequest.html#structfield.finding_id) member.
+    pub fn finding_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.finding_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAuditMitigationActionsExecutionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAuditMitigationActionsExecutionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_id`](struct.ListAuditMitigationActionsExecutionsRequest.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAuditMitigationActionsExecutionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAuditMitigationActionsExecutionsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListAuditMitigationActionsExecutionsRequest {
+            action_status: self.action_status,
+            finding_id: self
+                .finding_id
+                .ok_or(::rusoto_core::BuildError::missing("finding_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAuditMitigationActionsExecutionsResponse {
@@ -3769,7 +10150,8 @@ pub struct ListAuditMitigationActionsTasksRequest {
     pub audit_task_id: Option<String>,
     /// <p>Specify this filter to limit results to tasks that were completed or canceled on or before a specific date and time.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p>Specify this filter to limit results to tasks that were applied to a specific audit finding.</p>
     #[serde(rename = "findingId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3784,11 +10166,95 @@ pub struct ListAuditMitigationActionsTasksRequest {
     pub next_token: Option<String>,
     /// <p>Specify this filter to limit results to tasks that began on or after a specific date and time.</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
     /// <p>Specify this filter to limit results to tasks that are in a specific state.</p>
     #[serde(rename = "taskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<AuditMitigationActionsTaskStatus>,
+}
+
+impl ListAuditMitigationActionsTasksRequest {
+    /// Returns a builder for `ListAuditMitigationActionsTasksRequest`, checking its required members when built.
+    pub fn builder() -> ListAuditMitigationActionsTasksRequestBuilder {
+        ListAuditMitigationActionsTasksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAuditMitigationActionsTasksRequest`](struct.ListAuditMitigationActionsTasksRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAuditMitigationActionsTasksRequestBuilder {
+    audit_task_id: Option<String>,
+    end_time: Option<EpochTimestamp>,
+    finding_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    start_time: Option<EpochTimestamp>,
+    task_status: Option<AuditMitigationActionsTaskStatus>,
+}
+
+impl ListAuditMitigationActionsTasksRequestBuilder {
+    /// Sets the [`audit_task_id`](struct.ListAuditMitigationActionsTasksRequest.html#structfield.audit_task_id) member.
+    pub fn audit_task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.audit_task_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.ListAuditMitigationActionsTasksRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`finding_id`](struct.ListAuditMitigationActionsTasksRequest.html#structfield.finding_id) member.
+    pub fn finding_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.finding_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAuditMitigationActionsTasksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAuditMitigationActionsTasksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.ListAuditMitigationActionsTasksRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_status`](struct.ListAuditMitigationActionsTasksRequest.html#structfield.task_status) member.
+    pub fn task_status<T: Into<AuditMitigationActionsTaskStatus>>(mut self, value: T) -> Self {
+        self.task_status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAuditMitigationActionsTasksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAuditMitigationActionsTasksRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListAuditMitigationActionsTasksRequest {
+            audit_task_id: self.audit_task_id,
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            finding_id: self.finding_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+            task_status: self.task_status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -3808,7 +10274,8 @@ pub struct ListAuditMitigationActionsTasksResponse {
 pub struct ListAuditTasksRequest {
     /// <p>The end of the time period.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p>The maximum number of results to return at one time. The default is 25.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3819,15 +10286,88 @@ pub struct ListAuditTasksRequest {
     pub next_token: Option<String>,
     /// <p>The beginning of the time period. Audit information is retained for a limited time (180 days). Requesting a start time prior to what is retained results in an "InvalidRequestException".</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
     /// <p>A filter to limit the output to audits with the specified completion status: can be one of "IN_PROGRESS", "COMPLETED", "FAILED", or "CANCELED".</p>
     #[serde(rename = "taskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<AuditTaskStatus>,
     /// <p>A filter to limit the output to the specified type of audit: can be one of "ON_DEMAND_AUDIT_TASK" or "SCHEDULED__AUDIT_TASK".</p>
     #[serde(rename = "taskType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_type: Option<String>,
+    pub task_type: Option<AuditTaskType>,
+}
+
+impl ListAuditTasksRequest {
+    /// Returns a builder for `ListAuditTasksRequest`, checking its required members when built.
+    pub fn builder() -> ListAuditTasksRequestBuilder {
+        ListAuditTasksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAuditTasksRequest`](struct.ListAuditTasksRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAuditTasksRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    start_time: Option<EpochTimestamp>,
+    task_status: Option<AuditTaskStatus>,
+    task_type: Option<AuditTaskType>,
+}
+
+impl ListAuditTasksRequestBuilder {
+    /// Sets the [`end_time`](struct.ListAuditTasksRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAuditTasksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAuditTasksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.ListAuditTasksRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_status`](struct.ListAuditTasksRequest.html#structfield.task_status) member.
+    pub fn task_status<T: Into<AuditTaskStatus>>(mut self, value: T) -> Self {
+        self.task_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_type`](struct.ListAuditTasksRequest.html#structfield.task_type) member.
+    pub fn task_type<T: Into<AuditTaskType>>(mut self, value: T) -> Self {
+        self.task_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAuditTasksRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListAuditTasksRequest, ::rusoto_core::BuildError> {
+        Ok(ListAuditTasksRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+            task_status: self.task_status,
+            task_type: self.task_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -3860,15 +10400,67 @@ pub struct ListAuthorizersRequest {
     /// <p>The status of the list authorizers request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AuthorizerStatus>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListAuthorizersResponse {
-    /// <p>The authorizers.</p>
-    #[serde(rename = "authorizers")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl ListAuthorizersRequest {
+    /// Returns a builder for `ListAuthorizersRequest`, checking its required members when built.
+    pub fn builder() -> ListAuthorizersRequestBuilder {
+        ListAuthorizersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAuthorizersRequest`](struct.ListAuthorizersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAuthorizersRequestBuilder {
+    ascending_order: Option<bool>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+    status: Option<AuthorizerStatus>,
+}
+
+impl ListAuthorizersRequestBuilder {
+    /// Sets the [`ascending_order`](struct.ListAuthorizersRequest.html#structfield.ascending_order) member.
+    pub fn ascending_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ascending_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListAuthorizersRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListAuthorizersRequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListAuthorizersRequest.html#structfield.status) member.
+    pub fn status<T: Into<AuthorizerStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAuthorizersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListAuthorizersRequest, ::rusoto_core::BuildError> {
+        Ok(ListAuthorizersRequest {
+            ascending_order: self.ascending_order,
+            marker: self.marker,
+            page_size: self.page_size,
+            status: self.status,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListAuthorizersResponse {
+    /// <p>The authorizers.</p>
+    #[serde(rename = "authorizers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub authorizers: Option<Vec<AuthorizerSummary>>,
     /// <p>A marker used to get the next set of results.</p>
     #[serde(rename = "nextMarker")]
@@ -3892,6 +10484,52 @@ pub struct ListBillingGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListBillingGroupsRequest {
+    /// Returns a builder for `ListBillingGroupsRequest`, checking its required members when built.
+    pub fn builder() -> ListBillingGroupsRequestBuilder {
+        ListBillingGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListBillingGroupsRequest`](struct.ListBillingGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListBillingGroupsRequestBuilder {
+    max_results: Option<i64>,
+    name_prefix_filter: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListBillingGroupsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListBillingGroupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_prefix_filter`](struct.ListBillingGroupsRequest.html#structfield.name_prefix_filter) member.
+    pub fn name_prefix_filter<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_prefix_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListBillingGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBillingGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListBillingGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(ListBillingGroupsRequest {
+            max_results: self.max_results,
+            name_prefix_filter: self.name_prefix_filter,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListBillingGroupsResponse {
@@ -3922,6 +10560,52 @@ pub struct ListCACertificatesRequest {
     pub page_size: Option<i64>,
 }
 
+impl ListCACertificatesRequest {
+    /// Returns a builder for `ListCACertificatesRequest`, checking its required members when built.
+    pub fn builder() -> ListCACertificatesRequestBuilder {
+        ListCACertificatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCACertificatesRequest`](struct.ListCACertificatesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCACertificatesRequestBuilder {
+    ascending_order: Option<bool>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+}
+
+impl ListCACertificatesRequestBuilder {
+    /// Sets the [`ascending_order`](struct.ListCACertificatesRequest.html#structfield.ascending_order) member.
+    pub fn ascending_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ascending_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListCACertificatesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListCACertificatesRequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCACertificatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListCACertificatesRequest, ::rusoto_core::BuildError> {
+        Ok(ListCACertificatesRequest {
+            ascending_order: self.ascending_order,
+            marker: self.marker,
+            page_size: self.page_size,
+        })
+    }
+}
+
 /// <p>The output from the ListCACertificates operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3956,6 +10640,62 @@ pub struct ListCertificatesByCARequest {
     pub page_size: Option<i64>,
 }
 
+impl ListCertificatesByCARequest {
+    /// Returns a builder for `ListCertificatesByCARequest`, checking its required members when built.
+    pub fn builder() -> ListCertificatesByCARequestBuilder {
+        ListCertificatesByCARequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCertificatesByCARequest`](struct.ListCertificatesByCARequest.html).
+#[derive(Default, Debug)]
+pub struct ListCertificatesByCARequestBuilder {
+    ascending_order: Option<bool>,
+    ca_certificate_id: Option<String>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+}
+
+impl ListCertificatesByCARequestBuilder {
+    /// Sets the [`ascending_order`](struct.ListCertificatesByCARequest.html#structfield.ascending_order) member.
+    pub fn ascending_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ascending_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ca_certificate_id`](struct.ListCertificatesByCARequest.html#structfield.ca_certificate_id) member.
+    pub fn ca_certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ca_certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListCertificatesByCARequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListCertificatesByCARequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCertificatesByCARequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListCertificatesByCARequest, ::rusoto_core::BuildError> {
+        Ok(ListCertificatesByCARequest {
+            ascending_order: self.ascending_order,
+            ca_certificate_id: self
+                .ca_certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("ca_certificate_id"))?,
+            marker: self.marker,
+            page_size: self.page_size,
+        })
+    }
+}
+
 /// <p>The output of the ListCertificatesByCA operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3987,6 +10727,52 @@ pub struct ListCertificatesRequest {
     pub page_size: Option<i64>,
 }
 
+impl ListCertificatesRequest {
+    /// Returns a builder for `ListCertificatesRequest`, checking its required members when built.
+    pub fn builder() -> ListCertificatesRequestBuilder {
+        ListCertificatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCertificatesRequest`](struct.ListCertificatesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCertificatesRequestBuilder {
+    ascending_order: Option<bool>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+}
+
+impl ListCertificatesRequestBuilder {
+    /// Sets the [`ascending_order`](struct.ListCertificatesRequest.html#structfield.ascending_order) member.
+    pub fn ascending_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ascending_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListCertificatesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListCertificatesRequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCertificatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListCertificatesRequest, ::rusoto_core::BuildError> {
+        Ok(ListCertificatesRequest {
+            ascending_order: self.ascending_order,
+            marker: self.marker,
+            page_size: self.page_size,
+        })
+    }
+}
+
 /// <p>The output of the ListCertificates operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -4013,6 +10799,42 @@ pub struct ListIndicesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListIndicesRequest {
+    /// Returns a builder for `ListIndicesRequest`, checking its required members when built.
+    pub fn builder() -> ListIndicesRequestBuilder {
+        ListIndicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListIndicesRequest`](struct.ListIndicesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListIndicesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListIndicesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListIndicesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListIndicesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListIndicesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListIndicesRequest, ::rusoto_core::BuildError> {
+        Ok(ListIndicesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListIndicesResponse {
@@ -4042,7 +10864,63 @@ pub struct ListJobExecutionsForJobRequest {
     /// <p>The status of the job.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobExecutionStatus>,
+}
+
+impl ListJobExecutionsForJobRequest {
+    /// Returns a builder for `ListJobExecutionsForJobRequest`, checking its required members when built.
+    pub fn builder() -> ListJobExecutionsForJobRequestBuilder {
+        ListJobExecutionsForJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListJobExecutionsForJobRequest`](struct.ListJobExecutionsForJobRequest.html).
+#[derive(Default, Debug)]
+pub struct ListJobExecutionsForJobRequestBuilder {
+    job_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    status: Option<JobExecutionStatus>,
+}
+
+impl ListJobExecutionsForJobRequestBuilder {
+    /// Sets the [`job_id`](struct.ListJobExecutionsForJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListJobExecutionsForJobRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListJobExecutionsForJobRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListJobExecutionsForJobRequest.html#structfield.status) member.
+    pub fn status<T: Into<JobExecutionStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJobExecutionsForJobRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListJobExecutionsForJobRequest, ::rusoto_core::BuildError> {
+        Ok(ListJobExecutionsForJobRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            status: self.status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -4071,12 +10949,68 @@ pub struct ListJobExecutionsForThingRequest {
     /// <p>An optional filter that lets you search for jobs that have the specified status.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobExecutionStatus>,
     /// <p>The thing name.</p>
     #[serde(rename = "thingName")]
     pub thing_name: String,
 }
 
+impl ListJobExecutionsForThingRequest {
+    /// Returns a builder for `ListJobExecutionsForThingRequest`, checking its required members when built.
+    pub fn builder() -> ListJobExecutionsForThingRequestBuilder {
+        ListJobExecutionsForThingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListJobExecutionsForThingRequest`](struct.ListJobExecutionsForThingRequest.html).
+#[derive(Default, Debug)]
+pub struct ListJobExecutionsForThingRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    status: Option<JobExecutionStatus>,
+    thing_name: Option<String>,
+}
+
+impl ListJobExecutionsForThingRequestBuilder {
+    /// Sets the [`max_results`](struct.ListJobExecutionsForThingRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListJobExecutionsForThingRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListJobExecutionsForThingRequest.html#structfield.status) member.
+    pub fn status<T: Into<JobExecutionStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.ListJobExecutionsForThingRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJobExecutionsForThingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListJobExecutionsForThingRequest, ::rusoto_core::BuildError> {
+        Ok(ListJobExecutionsForThingRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            status: self.status,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListJobExecutionsForThingResponse {
@@ -4103,11 +11037,11 @@ pub struct ListJobsRequest {
     /// <p>An optional filter that lets you search for jobs that have the specified status.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<JobStatus>,
     /// <p>Specifies whether the job will continue to run (CONTINUOUS), or will be complete after all those things specified as targets have completed the job (SNAPSHOT). If continuous, the job may also be run on a thing when a change is detected in a target. For example, a job will run on a thing when the thing is added to a target group, even after the job was completed by all things originally in the group. </p>
     #[serde(rename = "targetSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_selection: Option<String>,
+    pub target_selection: Option<TargetSelection>,
     /// <p>A filter that limits the returned jobs to those for the specified group.</p>
     #[serde(rename = "thingGroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4118,6 +11052,74 @@ pub struct ListJobsRequest {
     pub thing_group_name: Option<String>,
 }
 
+impl ListJobsRequest {
+    /// Returns a builder for `ListJobsRequest`, checking its required members when built.
+    pub fn builder() -> ListJobsRequestBuilder {
+        ListJobsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListJobsRequest`](struct.ListJobsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListJobsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    status: Option<JobStatus>,
+    target_selection: Option<TargetSelection>,
+    thing_group_id: Option<String>,
+    thing_group_name: Option<String>,
+}
+
+impl ListJobsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListJobsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListJobsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListJobsRequest.html#structfield.status) member.
+    pub fn status<T: Into<JobStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_selection`](struct.ListJobsRequest.html#structfield.target_selection) member.
+    pub fn target_selection<T: Into<TargetSelection>>(mut self, value: T) -> Self {
+        self.target_selection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_id`](struct.ListJobsRequest.html#structfield.thing_group_id) member.
+    pub fn thing_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_name`](struct.ListJobsRequest.html#structfield.thing_group_name) member.
+    pub fn thing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJobsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListJobsRequest, ::rusoto_core::BuildError> {
+        Ok(ListJobsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            status: self.status,
+            target_selection: self.target_selection,
+            thing_group_id: self.thing_group_id,
+            thing_group_name: self.thing_group_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListJobsResponse {
@@ -4136,7 +11138,7 @@ pub struct ListMitigationActionsRequest {
     /// <p>Specify a value to limit the result to mitigation actions with a specific action type.</p>
     #[serde(rename = "actionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_type: Option<String>,
+    pub action_type: Option<MitigationActionType>,
     /// <p>The maximum number of results to return at one time. The default is 25.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4147,6 +11149,52 @@ pub struct ListMitigationActionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListMitigationActionsRequest {
+    /// Returns a builder for `ListMitigationActionsRequest`, checking its required members when built.
+    pub fn builder() -> ListMitigationActionsRequestBuilder {
+        ListMitigationActionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListMitigationActionsRequest`](struct.ListMitigationActionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListMitigationActionsRequestBuilder {
+    action_type: Option<MitigationActionType>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListMitigationActionsRequestBuilder {
+    /// Sets the [`action_type`](struct.ListMitigationActionsRequest.html#structfield.action_type) member.
+    pub fn action_type<T: Into<MitigationActionType>>(mut self, value: T) -> Self {
+        self.action_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListMitigationActionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListMitigationActionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListMitigationActionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListMitigationActionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListMitigationActionsRequest {
+            action_type: self.action_type,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListMitigationActionsResponse {
@@ -4173,7 +11221,51 @@ pub struct ListOTAUpdatesRequest {
     /// <p>The OTA update job status.</p>
     #[serde(rename = "otaUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ota_update_status: Option<String>,
+    pub ota_update_status: Option<OTAUpdateStatus>,
+}
+
+impl ListOTAUpdatesRequest {
+    /// Returns a builder for `ListOTAUpdatesRequest`, checking its required members when built.
+    pub fn builder() -> ListOTAUpdatesRequestBuilder {
+        ListOTAUpdatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListOTAUpdatesRequest`](struct.ListOTAUpdatesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListOTAUpdatesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    ota_update_status: Option<OTAUpdateStatus>,
+}
+
+impl ListOTAUpdatesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListOTAUpdatesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListOTAUpdatesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ota_update_status`](struct.ListOTAUpdatesRequest.html#structfield.ota_update_status) member.
+    pub fn ota_update_status<T: Into<OTAUpdateStatus>>(mut self, value: T) -> Self {
+        self.ota_update_status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOTAUpdatesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListOTAUpdatesRequest, ::rusoto_core::BuildError> {
+        Ok(ListOTAUpdatesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            ota_update_status: self.ota_update_status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -4206,6 +11298,52 @@ pub struct ListOutgoingCertificatesRequest {
     pub page_size: Option<i64>,
 }
 
+impl ListOutgoingCertificatesRequest {
+    /// Returns a builder for `ListOutgoingCertificatesRequest`, checking its required members when built.
+    pub fn builder() -> ListOutgoingCertificatesRequestBuilder {
+        ListOutgoingCertificatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListOutgoingCertificatesRequest`](struct.ListOutgoingCertificatesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListOutgoingCertificatesRequestBuilder {
+    ascending_order: Option<bool>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+}
+
+impl ListOutgoingCertificatesRequestBuilder {
+    /// Sets the [`ascending_order`](struct.ListOutgoingCertificatesRequest.html#structfield.ascending_order) member.
+    pub fn ascending_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ascending_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListOutgoingCertificatesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListOutgoingCertificatesRequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOutgoingCertificatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListOutgoingCertificatesRequest, ::rusoto_core::BuildError> {
+        Ok(ListOutgoingCertificatesRequest {
+            ascending_order: self.ascending_order,
+            marker: self.marker,
+            page_size: self.page_size,
+        })
+    }
+}
+
 /// <p>The output from the ListOutgoingCertificates operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -4237,6 +11375,50 @@ pub struct ListPoliciesRequest {
     pub page_size: Option<i64>,
 }
 
+impl ListPoliciesRequest {
+    /// Returns a builder for `ListPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListPoliciesRequestBuilder {
+        ListPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPoliciesRequest`](struct.ListPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPoliciesRequestBuilder {
+    ascending_order: Option<bool>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+}
+
+impl ListPoliciesRequestBuilder {
+    /// Sets the [`ascending_order`](struct.ListPoliciesRequest.html#structfield.ascending_order) member.
+    pub fn ascending_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ascending_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListPoliciesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListPoliciesRequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListPoliciesRequest {
+            ascending_order: self.ascending_order,
+            marker: self.marker,
+            page_size: self.page_size,
+        })
+    }
+}
+
 /// <p>The output from the ListPolicies operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -4271,6 +11453,62 @@ pub struct ListPolicyPrincipalsRequest {
     pub policy_name: String,
 }
 
+impl ListPolicyPrincipalsRequest {
+    /// Returns a builder for `ListPolicyPrincipalsRequest`, checking its required members when built.
+    pub fn builder() -> ListPolicyPrincipalsRequestBuilder {
+        ListPolicyPrincipalsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPolicyPrincipalsRequest`](struct.ListPolicyPrincipalsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPolicyPrincipalsRequestBuilder {
+    ascending_order: Option<bool>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+    policy_name: Option<String>,
+}
+
+impl ListPolicyPrincipalsRequestBuilder {
+    /// Sets the [`ascending_order`](struct.ListPolicyPrincipalsRequest.html#structfield.ascending_order) member.
+    pub fn ascending_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ascending_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListPolicyPrincipalsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListPolicyPrincipalsRequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.ListPolicyPrincipalsRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPolicyPrincipalsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPolicyPrincipalsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPolicyPrincipalsRequest {
+            ascending_order: self.ascending_order,
+            marker: self.marker,
+            page_size: self.page_size,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// <p>The output from the ListPolicyPrincipals operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -4293,6 +11531,38 @@ pub struct ListPolicyVersionsRequest {
     pub policy_name: String,
 }
 
+impl ListPolicyVersionsRequest {
+    /// Returns a builder for `ListPolicyVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListPolicyVersionsRequestBuilder {
+        ListPolicyVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPolicyVersionsRequest`](struct.ListPolicyVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPolicyVersionsRequestBuilder {
+    policy_name: Option<String>,
+}
+
+impl ListPolicyVersionsRequestBuilder {
+    /// Sets the [`policy_name`](struct.ListPolicyVersionsRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPolicyVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPolicyVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPolicyVersionsRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 /// <p>The output from the ListPolicyVersions operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -4323,22 +11593,78 @@ pub struct ListPrincipalPoliciesRequest {
     pub principal: String,
 }
 
-/// <p>The output from the ListPrincipalPolicies operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListPrincipalPoliciesResponse {
-    /// <p>The marker for the next set of results, or null if there are no additional results.</p>
-    #[serde(rename = "nextMarker")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_marker: Option<String>,
-    /// <p>The policies.</p>
-    #[serde(rename = "policies")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub policies: Option<Vec<Policy>>,
+impl ListPrincipalPoliciesRequest {
+    /// Returns a builder for `ListPrincipalPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListPrincipalPoliciesRequestBuilder {
+        ListPrincipalPoliciesRequestBuilder::default()
+    }
 }
 
-/// <p>The input for the ListPrincipalThings operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// Builds a [`ListPrincipalPoliciesRequest`](struct.ListPrincipalPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPrincipalPoliciesRequestBuilder {
+    ascending_order: Option<bool>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+    principal: Option<String>,
+}
+
+impl ListPrincipalPoliciesRequestBuilder {
+    /// Sets the [`ascending_order`](struct.ListPrincipalPoliciesRequest.html#structfield.ascending_order) member.
+    pub fn ascending_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ascending_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListPrincipalPoliciesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListPrincipalPoliciesRequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.ListPrincipalPoliciesRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPrincipalPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPrincipalPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListPrincipalPoliciesRequest {
+            ascending_order: self.ascending_order,
+            marker: self.marker,
+            page_size: self.page_size,
+            principal: self
+                .principal
+                .ok_or(::rusoto_core::BuildError::missing("principal"))?,
+        })
+    }
+}
+
+/// <p>The output from the ListPrincipalPolicies operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListPrincipalPoliciesResponse {
+    /// <p>The marker for the next set of results, or null if there are no additional results.</p>
+    #[serde(rename = "nextMarker")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_marker: Option<String>,
+    /// <p>The policies.</p>
+    #[serde(rename = "policies")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policies: Option<Vec<Policy>>,
+}
+
+/// <p>The input for the ListPrincipalThings operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListPrincipalThingsRequest {
     /// <p>The maximum number of results to return in this operation.</p>
     #[serde(rename = "maxResults")]
@@ -4353,6 +11679,54 @@ pub struct ListPrincipalThingsRequest {
     pub principal: String,
 }
 
+impl ListPrincipalThingsRequest {
+    /// Returns a builder for `ListPrincipalThingsRequest`, checking its required members when built.
+    pub fn builder() -> ListPrincipalThingsRequestBuilder {
+        ListPrincipalThingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPrincipalThingsRequest`](struct.ListPrincipalThingsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPrincipalThingsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    principal: Option<String>,
+}
+
+impl ListPrincipalThingsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListPrincipalThingsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPrincipalThingsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.ListPrincipalThingsRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPrincipalThingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPrincipalThingsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPrincipalThingsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            principal: self
+                .principal
+                .ok_or(::rusoto_core::BuildError::missing("principal"))?,
+        })
+    }
+}
+
 /// <p>The output from the ListPrincipalThings operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -4383,6 +11757,50 @@ pub struct ListRoleAliasesRequest {
     pub page_size: Option<i64>,
 }
 
+impl ListRoleAliasesRequest {
+    /// Returns a builder for `ListRoleAliasesRequest`, checking its required members when built.
+    pub fn builder() -> ListRoleAliasesRequestBuilder {
+        ListRoleAliasesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRoleAliasesRequest`](struct.ListRoleAliasesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRoleAliasesRequestBuilder {
+    ascending_order: Option<bool>,
+    marker: Option<String>,
+    page_size: Option<i64>,
+}
+
+impl ListRoleAliasesRequestBuilder {
+    /// Sets the [`ascending_order`](struct.ListRoleAliasesRequest.html#structfield.ascending_order) member.
+    pub fn ascending_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ascending_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListRoleAliasesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListRoleAliasesRequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRoleAliasesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListRoleAliasesRequest, ::rusoto_core::BuildError> {
+        Ok(ListRoleAliasesRequest {
+            ascending_order: self.ascending_order,
+            marker: self.marker,
+            page_size: self.page_size,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRoleAliasesResponse {
@@ -4408,6 +11826,44 @@ pub struct ListScheduledAuditsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListScheduledAuditsRequest {
+    /// Returns a builder for `ListScheduledAuditsRequest`, checking its required members when built.
+    pub fn builder() -> ListScheduledAuditsRequestBuilder {
+        ListScheduledAuditsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListScheduledAuditsRequest`](struct.ListScheduledAuditsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListScheduledAuditsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListScheduledAuditsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListScheduledAuditsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListScheduledAuditsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListScheduledAuditsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListScheduledAuditsRequest, ::rusoto_core::BuildError> {
+        Ok(ListScheduledAuditsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListScheduledAuditsResponse {
@@ -4440,6 +11896,63 @@ pub struct ListSecurityProfilesForTargetRequest {
     pub security_profile_target_arn: String,
 }
 
+impl ListSecurityProfilesForTargetRequest {
+    /// Returns a builder for `ListSecurityProfilesForTargetRequest`, checking its required members when built.
+    pub fn builder() -> ListSecurityProfilesForTargetRequestBuilder {
+        ListSecurityProfilesForTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSecurityProfilesForTargetRequest`](struct.ListSecurityProfilesForTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSecurityProfilesForTargetRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    recursive: Option<bool>,
+    security_profile_target_arn: Option<String>,
+}
+
+impl ListSecurityProfilesForTargetRequestBuilder {
+    /// Sets the [`max_results`](struct.ListSecurityProfilesForTargetRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSecurityProfilesForTargetRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recursive`](struct.ListSecurityProfilesForTargetRequest.html#structfield.recursive) member.
+    pub fn recursive<T: Into<bool>>(mut self, value: T) -> Self {
+        self.recursive = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_target_arn`](struct.ListSecurityProfilesForTargetRequest.html#structfield.security_profile_target_arn) member.
+    pub fn security_profile_target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_target_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSecurityProfilesForTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSecurityProfilesForTargetRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListSecurityProfilesForTargetRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            recursive: self.recursive,
+            security_profile_target_arn: self.security_profile_target_arn.ok_or(
+                ::rusoto_core::BuildError::missing("security_profile_target_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSecurityProfilesForTargetResponse {
@@ -4465,6 +11978,44 @@ pub struct ListSecurityProfilesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListSecurityProfilesRequest {
+    /// Returns a builder for `ListSecurityProfilesRequest`, checking its required members when built.
+    pub fn builder() -> ListSecurityProfilesRequestBuilder {
+        ListSecurityProfilesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListSecurityProfilesRequest`](struct.ListSecurityProfilesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListSecurityProfilesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListSecurityProfilesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListSecurityProfilesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSecurityProfilesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSecurityProfilesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSecurityProfilesRequest, ::rusoto_core::BuildError> {
+        Ok(ListSecurityProfilesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListSecurityProfilesResponse {
@@ -4494,6 +12045,50 @@ pub struct ListStreamsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListStreamsRequest {
+    /// Returns a builder for `ListStreamsRequest`, checking its required members when built.
+    pub fn builder() -> ListStreamsRequestBuilder {
+        ListStreamsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListStreamsRequest`](struct.ListStreamsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListStreamsRequestBuilder {
+    ascending_order: Option<bool>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListStreamsRequestBuilder {
+    /// Sets the [`ascending_order`](struct.ListStreamsRequest.html#structfield.ascending_order) member.
+    pub fn ascending_order<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ascending_order = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListStreamsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListStreamsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStreamsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListStreamsRequest, ::rusoto_core::BuildError> {
+        Ok(ListStreamsRequest {
+            ascending_order: self.ascending_order,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListStreamsResponse {
@@ -4518,6 +12113,46 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`next_token`](struct.ListTagsForResourceRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -4546,6 +12181,54 @@ pub struct ListTargetsForPolicyRequest {
     pub policy_name: String,
 }
 
+impl ListTargetsForPolicyRequest {
+    /// Returns a builder for `ListTargetsForPolicyRequest`, checking its required members when built.
+    pub fn builder() -> ListTargetsForPolicyRequestBuilder {
+        ListTargetsForPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTargetsForPolicyRequest`](struct.ListTargetsForPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTargetsForPolicyRequestBuilder {
+    marker: Option<String>,
+    page_size: Option<i64>,
+    policy_name: Option<String>,
+}
+
+impl ListTargetsForPolicyRequestBuilder {
+    /// Sets the [`marker`](struct.ListTargetsForPolicyRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_size`](struct.ListTargetsForPolicyRequest.html#structfield.page_size) member.
+    pub fn page_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.page_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.ListTargetsForPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTargetsForPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTargetsForPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(ListTargetsForPolicyRequest {
+            marker: self.marker,
+            page_size: self.page_size,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTargetsForPolicyResponse {
@@ -4574,6 +12257,55 @@ pub struct ListTargetsForSecurityProfileRequest {
     pub security_profile_name: String,
 }
 
+impl ListTargetsForSecurityProfileRequest {
+    /// Returns a builder for `ListTargetsForSecurityProfileRequest`, checking its required members when built.
+    pub fn builder() -> ListTargetsForSecurityProfileRequestBuilder {
+        ListTargetsForSecurityProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTargetsForSecurityProfileRequest`](struct.ListTargetsForSecurityProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTargetsForSecurityProfileRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    security_profile_name: Option<String>,
+}
+
+impl ListTargetsForSecurityProfileRequestBuilder {
+    /// Sets the [`max_results`](struct.ListTargetsForSecurityProfileRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTargetsForSecurityProfileRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_name`](struct.ListTargetsForSecurityProfileRequest.html#structfield.security_profile_name) member.
+    pub fn security_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTargetsForSecurityProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTargetsForSecurityProfileRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListTargetsForSecurityProfileRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            security_profile_name: self
+                .security_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("security_profile_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTargetsForSecurityProfileResponse {
@@ -4602,6 +12334,54 @@ pub struct ListThingGroupsForThingRequest {
     pub thing_name: String,
 }
 
+impl ListThingGroupsForThingRequest {
+    /// Returns a builder for `ListThingGroupsForThingRequest`, checking its required members when built.
+    pub fn builder() -> ListThingGroupsForThingRequestBuilder {
+        ListThingGroupsForThingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListThingGroupsForThingRequest`](struct.ListThingGroupsForThingRequest.html).
+#[derive(Default, Debug)]
+pub struct ListThingGroupsForThingRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    thing_name: Option<String>,
+}
+
+impl ListThingGroupsForThingRequestBuilder {
+    /// Sets the [`max_results`](struct.ListThingGroupsForThingRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListThingGroupsForThingRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.ListThingGroupsForThingRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListThingGroupsForThingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListThingGroupsForThingRequest, ::rusoto_core::BuildError> {
+        Ok(ListThingGroupsForThingRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListThingGroupsForThingResponse {
@@ -4639,6 +12419,66 @@ pub struct ListThingGroupsRequest {
     pub recursive: Option<bool>,
 }
 
+impl ListThingGroupsRequest {
+    /// Returns a builder for `ListThingGroupsRequest`, checking its required members when built.
+    pub fn builder() -> ListThingGroupsRequestBuilder {
+        ListThingGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListThingGroupsRequest`](struct.ListThingGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListThingGroupsRequestBuilder {
+    max_results: Option<i64>,
+    name_prefix_filter: Option<String>,
+    next_token: Option<String>,
+    parent_group: Option<String>,
+    recursive: Option<bool>,
+}
+
+impl ListThingGroupsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListThingGroupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_prefix_filter`](struct.ListThingGroupsRequest.html#structfield.name_prefix_filter) member.
+    pub fn name_prefix_filter<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_prefix_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListThingGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_group`](struct.ListThingGroupsRequest.html#structfield.parent_group) member.
+    pub fn parent_group<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_group = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recursive`](struct.ListThingGroupsRequest.html#structfield.recursive) member.
+    pub fn recursive<T: Into<bool>>(mut self, value: T) -> Self {
+        self.recursive = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListThingGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListThingGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(ListThingGroupsRequest {
+            max_results: self.max_results,
+            name_prefix_filter: self.name_prefix_filter,
+            next_token: self.next_token,
+            parent_group: self.parent_group,
+            recursive: self.recursive,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListThingGroupsResponse {
@@ -4660,6 +12500,38 @@ pub struct ListThingPrincipalsRequest {
     pub thing_name: String,
 }
 
+impl ListThingPrincipalsRequest {
+    /// Returns a builder for `ListThingPrincipalsRequest`, checking its required members when built.
+    pub fn builder() -> ListThingPrincipalsRequestBuilder {
+        ListThingPrincipalsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListThingPrincipalsRequest`](struct.ListThingPrincipalsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListThingPrincipalsRequestBuilder {
+    thing_name: Option<String>,
+}
+
+impl ListThingPrincipalsRequestBuilder {
+    /// Sets the [`thing_name`](struct.ListThingPrincipalsRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListThingPrincipalsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListThingPrincipalsRequest, ::rusoto_core::BuildError> {
+        Ok(ListThingPrincipalsRequest {
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+        })
+    }
+}
+
 /// <p>The output from the ListThingPrincipals operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -4670,7 +12542,7 @@ pub struct ListThingPrincipalsResponse {
     pub principals: Option<Vec<String>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListThingRegistrationTaskReportsRequest {
     /// <p>The maximum number of results to return per request.</p>
     #[serde(rename = "maxResults")]
@@ -4682,12 +12554,71 @@ pub struct ListThingRegistrationTaskReportsRequest {
     pub next_token: Option<String>,
     /// <p>The type of task report.</p>
     #[serde(rename = "reportType")]
-    pub report_type: String,
+    pub report_type: ReportType,
     /// <p>The id of the task.</p>
     #[serde(rename = "taskId")]
     pub task_id: String,
 }
 
+impl ListThingRegistrationTaskReportsRequest {
+    /// Returns a builder for `ListThingRegistrationTaskReportsRequest`, checking its required members when built.
+    pub fn builder() -> ListThingRegistrationTaskReportsRequestBuilder {
+        ListThingRegistrationTaskReportsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListThingRegistrationTaskReportsRequest`](struct.ListThingRegistrationTaskReportsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListThingRegistrationTaskReportsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    report_type: Option<ReportType>,
+    task_id: Option<String>,
+}
+
+impl ListThingRegistrationTaskReportsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListThingRegistrationTaskReportsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListThingRegistrationTaskReportsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`report_type`](struct.ListThingRegistrationTaskReportsRequest.html#structfield.report_type) member.
+    pub fn report_type<T: Into<ReportType>>(mut self, value: T) -> Self {
+        self.report_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_id`](struct.ListThingRegistrationTaskReportsRequest.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListThingRegistrationTaskReportsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListThingRegistrationTaskReportsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListThingRegistrationTaskReportsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            report_type: self
+                .report_type
+                .ok_or(::rusoto_core::BuildError::missing("report_type"))?,
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListThingRegistrationTaskReportsResponse {
@@ -4698,7 +12629,7 @@ pub struct ListThingRegistrationTaskReportsResponse {
     /// <p>The type of task report.</p>
     #[serde(rename = "reportType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub report_type: Option<String>,
+    pub report_type: Option<ReportType>,
     /// <p>Links to the task resources.</p>
     #[serde(rename = "resourceLinks")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4718,7 +12649,53 @@ pub struct ListThingRegistrationTasksRequest {
     /// <p>The status of the bulk thing provisioning task.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
+}
+
+impl ListThingRegistrationTasksRequest {
+    /// Returns a builder for `ListThingRegistrationTasksRequest`, checking its required members when built.
+    pub fn builder() -> ListThingRegistrationTasksRequestBuilder {
+        ListThingRegistrationTasksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListThingRegistrationTasksRequest`](struct.ListThingRegistrationTasksRequest.html).
+#[derive(Default, Debug)]
+pub struct ListThingRegistrationTasksRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    status: Option<Status>,
+}
+
+impl ListThingRegistrationTasksRequestBuilder {
+    /// Sets the [`max_results`](struct.ListThingRegistrationTasksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListThingRegistrationTasksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListThingRegistrationTasksRequest.html#structfield.status) member.
+    pub fn status<T: Into<Status>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListThingRegistrationTasksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListThingRegistrationTasksRequest, ::rusoto_core::BuildError> {
+        Ok(ListThingRegistrationTasksRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            status: self.status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -4751,21 +12728,65 @@ pub struct ListThingTypesRequest {
     pub thing_type_name: Option<String>,
 }
 
-/// <p>The output for the ListThingTypes operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListThingTypesResponse {
-    /// <p>The token for the next set of results, or <b>null</b> if there are no additional results.</p>
-    #[serde(rename = "nextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>The thing types.</p>
-    #[serde(rename = "thingTypes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub thing_types: Option<Vec<ThingTypeDefinition>>,
+impl ListThingTypesRequest {
+    /// Returns a builder for `ListThingTypesRequest`, checking its required members when built.
+    pub fn builder() -> ListThingTypesRequestBuilder {
+        ListThingTypesRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// Builds a [`ListThingTypesRequest`](struct.ListThingTypesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListThingTypesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    thing_type_name: Option<String>,
+}
+
+impl ListThingTypesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListThingTypesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListThingTypesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_type_name`](struct.ListThingTypesRequest.html#structfield.thing_type_name) member.
+    pub fn thing_type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListThingTypesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListThingTypesRequest, ::rusoto_core::BuildError> {
+        Ok(ListThingTypesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            thing_type_name: self.thing_type_name,
+        })
+    }
+}
+
+/// <p>The output for the ListThingTypes operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListThingTypesResponse {
+    /// <p>The token for the next set of results, or <b>null</b> if there are no additional results.</p>
+    #[serde(rename = "nextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>The thing types.</p>
+    #[serde(rename = "thingTypes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thing_types: Option<Vec<ThingTypeDefinition>>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListThingsInBillingGroupRequest {
     /// <p>The name of the billing group.</p>
     #[serde(rename = "billingGroupName")]
@@ -4780,6 +12801,54 @@ pub struct ListThingsInBillingGroupRequest {
     pub next_token: Option<String>,
 }
 
+impl ListThingsInBillingGroupRequest {
+    /// Returns a builder for `ListThingsInBillingGroupRequest`, checking its required members when built.
+    pub fn builder() -> ListThingsInBillingGroupRequestBuilder {
+        ListThingsInBillingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListThingsInBillingGroupRequest`](struct.ListThingsInBillingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct ListThingsInBillingGroupRequestBuilder {
+    billing_group_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListThingsInBillingGroupRequestBuilder {
+    /// Sets the [`billing_group_name`](struct.ListThingsInBillingGroupRequest.html#structfield.billing_group_name) member.
+    pub fn billing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.billing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListThingsInBillingGroupRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListThingsInBillingGroupRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListThingsInBillingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListThingsInBillingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(ListThingsInBillingGroupRequest {
+            billing_group_name: self
+                .billing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("billing_group_name"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListThingsInBillingGroupResponse {
@@ -4812,6 +12881,62 @@ pub struct ListThingsInThingGroupRequest {
     pub thing_group_name: String,
 }
 
+impl ListThingsInThingGroupRequest {
+    /// Returns a builder for `ListThingsInThingGroupRequest`, checking its required members when built.
+    pub fn builder() -> ListThingsInThingGroupRequestBuilder {
+        ListThingsInThingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListThingsInThingGroupRequest`](struct.ListThingsInThingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct ListThingsInThingGroupRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    recursive: Option<bool>,
+    thing_group_name: Option<String>,
+}
+
+impl ListThingsInThingGroupRequestBuilder {
+    /// Sets the [`max_results`](struct.ListThingsInThingGroupRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListThingsInThingGroupRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recursive`](struct.ListThingsInThingGroupRequest.html#structfield.recursive) member.
+    pub fn recursive<T: Into<bool>>(mut self, value: T) -> Self {
+        self.recursive = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_name`](struct.ListThingsInThingGroupRequest.html#structfield.thing_group_name) member.
+    pub fn thing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListThingsInThingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListThingsInThingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(ListThingsInThingGroupRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            recursive: self.recursive,
+            thing_group_name: self
+                .thing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListThingsInThingGroupResponse {
@@ -4850,6 +12975,66 @@ pub struct ListThingsRequest {
     pub thing_type_name: Option<String>,
 }
 
+impl ListThingsRequest {
+    /// Returns a builder for `ListThingsRequest`, checking its required members when built.
+    pub fn builder() -> ListThingsRequestBuilder {
+        ListThingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListThingsRequest`](struct.ListThingsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListThingsRequestBuilder {
+    attribute_name: Option<String>,
+    attribute_value: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    thing_type_name: Option<String>,
+}
+
+impl ListThingsRequestBuilder {
+    /// Sets the [`attribute_name`](struct.ListThingsRequest.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attribute_value`](struct.ListThingsRequest.html#structfield.attribute_value) member.
+    pub fn attribute_value<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListThingsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListThingsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_type_name`](struct.ListThingsRequest.html#structfield.thing_type_name) member.
+    pub fn thing_type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListThingsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListThingsRequest, ::rusoto_core::BuildError> {
+        Ok(ListThingsRequest {
+            attribute_name: self.attribute_name,
+            attribute_value: self.attribute_value,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            thing_type_name: self.thing_type_name,
+        })
+    }
+}
+
 /// <p>The output from the ListThings operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -4885,6 +13070,58 @@ pub struct ListTopicRulesRequest {
     pub topic: Option<String>,
 }
 
+impl ListTopicRulesRequest {
+    /// Returns a builder for `ListTopicRulesRequest`, checking its required members when built.
+    pub fn builder() -> ListTopicRulesRequestBuilder {
+        ListTopicRulesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTopicRulesRequest`](struct.ListTopicRulesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTopicRulesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    rule_disabled: Option<bool>,
+    topic: Option<String>,
+}
+
+impl ListTopicRulesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListTopicRulesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTopicRulesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rule_disabled`](struct.ListTopicRulesRequest.html#structfield.rule_disabled) member.
+    pub fn rule_disabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.rule_disabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic`](struct.ListTopicRulesRequest.html#structfield.topic) member.
+    pub fn topic<T: Into<String>>(mut self, value: T) -> Self {
+        self.topic = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTopicRulesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTopicRulesRequest, ::rusoto_core::BuildError> {
+        Ok(ListTopicRulesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            rule_disabled: self.rule_disabled,
+            topic: self.topic,
+        })
+    }
+}
+
 /// <p>The output from the ListTopicRules operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -4912,7 +13149,53 @@ pub struct ListV2LoggingLevelsRequest {
     /// <p>The type of resource for which you are configuring logging. Must be <code>THING_Group</code>.</p>
     #[serde(rename = "targetType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_type: Option<String>,
+    pub target_type: Option<LogTargetType>,
+}
+
+impl ListV2LoggingLevelsRequest {
+    /// Returns a builder for `ListV2LoggingLevelsRequest`, checking its required members when built.
+    pub fn builder() -> ListV2LoggingLevelsRequestBuilder {
+        ListV2LoggingLevelsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListV2LoggingLevelsRequest`](struct.ListV2LoggingLevelsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListV2LoggingLevelsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    target_type: Option<LogTargetType>,
+}
+
+impl ListV2LoggingLevelsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListV2LoggingLevelsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListV2LoggingLevelsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_type`](struct.ListV2LoggingLevelsRequest.html#structfield.target_type) member.
+    pub fn target_type<T: Into<LogTargetType>>(mut self, value: T) -> Self {
+        self.target_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListV2LoggingLevelsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListV2LoggingLevelsRequest, ::rusoto_core::BuildError> {
+        Ok(ListV2LoggingLevelsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            target_type: self.target_type,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -4932,7 +13215,8 @@ pub struct ListV2LoggingLevelsResponse {
 pub struct ListViolationEventsRequest {
     /// <p>The end time for the alerts to be listed.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p>The maximum number of results to return at one time.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4947,13 +13231,88 @@ pub struct ListViolationEventsRequest {
     pub security_profile_name: Option<String>,
     /// <p>The start time for the alerts to be listed.</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
     /// <p>A filter to limit results to those alerts caused by the specified thing.</p>
     #[serde(rename = "thingName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thing_name: Option<String>,
 }
 
+impl ListViolationEventsRequest {
+    /// Returns a builder for `ListViolationEventsRequest`, checking its required members when built.
+    pub fn builder() -> ListViolationEventsRequestBuilder {
+        ListViolationEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListViolationEventsRequest`](struct.ListViolationEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListViolationEventsRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    security_profile_name: Option<String>,
+    start_time: Option<EpochTimestamp>,
+    thing_name: Option<String>,
+}
+
+impl ListViolationEventsRequestBuilder {
+    /// Sets the [`end_time`](struct.ListViolationEventsRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListViolationEventsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListViolationEventsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_name`](struct.ListViolationEventsRequest.html#structfield.security_profile_name) member.
+    pub fn security_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.ListViolationEventsRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.ListViolationEventsRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListViolationEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListViolationEventsRequest, ::rusoto_core::BuildError> {
+        Ok(ListViolationEventsRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            security_profile_name: self.security_profile_name,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+            thing_name: self.thing_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListViolationEventsResponse {
@@ -4967,8 +13326,98 @@ pub struct ListViolationEventsResponse {
     pub violation_events: Option<Vec<ViolationEvent>>,
 }
 
+/// <p>Specifies the types of information to be logged.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LogLevel {
+    /// <p><code>DEBUG</code></p>
+    Debug,
+    /// <p><code>INFO</code></p>
+    Info,
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// <p><code>WARN</code></p>
+    Warn,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LogLevel {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Disabled => "DISABLED",
+            LogLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LogLevel {
+    fn from(value: &'a str) -> LogLevel {
+        match value {
+            "DEBUG" => LogLevel::Debug,
+            "INFO" => LogLevel::Info,
+            "ERROR" => LogLevel::Error,
+            "WARN" => LogLevel::Warn,
+            "DISABLED" => LogLevel::Disabled,
+            _ => LogLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogLevel {
+    fn from(value: String) -> LogLevel {
+        LogLevel::from(value.as_str())
+    }
+}
+
+impl From<LogLevel> for String {
+    fn from(value: LogLevel) -> String {
+        match value {
+            LogLevel::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogLevel {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogLevel {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LogLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LogLevel::from(value))
+    }
+}
+
 /// <p>A log target.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogTarget {
     /// <p>The target name.</p>
     #[serde(rename = "targetName")]
@@ -4976,7 +13425,7 @@ pub struct LogTarget {
     pub target_name: Option<String>,
     /// <p>The target type.</p>
     #[serde(rename = "targetType")]
-    pub target_type: String,
+    pub target_type: LogTargetType,
 }
 
 /// <p>The target configuration.</p>
@@ -4986,25 +13435,193 @@ pub struct LogTargetConfiguration {
     /// <p>The logging level.</p>
     #[serde(rename = "logLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_level: Option<String>,
+    pub log_level: Option<LogLevel>,
     /// <p>A log target</p>
     #[serde(rename = "logTarget")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub log_target: Option<LogTarget>,
 }
 
+/// <p>The type of resource for which you are configuring logging. Must be <code>THING_Group</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LogTargetType {
+    /// <p><code>DEFAULT</code></p>
+    Default,
+    /// <p><code>THING_GROUP</code></p>
+    ThingGroup,
+    /// <p><code>CLIENT_ID</code></p>
+    ClientId,
+    /// <p><code>SOURCE_IP</code></p>
+    SourceIp,
+    /// <p><code>PRINCIPAL_ID</code></p>
+    PrincipalId,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LogTargetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogTargetType::Default => "DEFAULT",
+            LogTargetType::ThingGroup => "THING_GROUP",
+            LogTargetType::ClientId => "CLIENT_ID",
+            LogTargetType::SourceIp => "SOURCE_IP",
+            LogTargetType::PrincipalId => "PRINCIPAL_ID",
+            LogTargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LogTargetType {
+    fn from(value: &'a str) -> LogTargetType {
+        match value {
+            "DEFAULT" => LogTargetType::Default,
+            "THING_GROUP" => LogTargetType::ThingGroup,
+            "CLIENT_ID" => LogTargetType::ClientId,
+            "SOURCE_IP" => LogTargetType::SourceIp,
+            "PRINCIPAL_ID" => LogTargetType::PrincipalId,
+            _ => LogTargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogTargetType {
+    fn from(value: String) -> LogTargetType {
+        LogTargetType::from(value.as_str())
+    }
+}
+
+impl From<LogTargetType> for String {
+    fn from(value: LogTargetType) -> String {
+        match value {
+            LogTargetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LogTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogTargetType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogTargetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogTargetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LogTargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LogTargetType::from(value))
+    }
+}
+
 /// <p>Describes the logging options payload.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct LoggingOptionsPayload {
     /// <p>The log level.</p>
     #[serde(rename = "logLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_level: Option<String>,
+    pub log_level: Option<LogLevel>,
     /// <p>The ARN of the IAM role that grants access.</p>
     #[serde(rename = "roleArn")]
     pub role_arn: String,
 }
 
+/// <p>(Optional) The message format of the message to publish. Accepted values are "JSON" and "RAW". The default value of the attribute is "RAW". SNS uses this setting to determine if the payload should be parsed and relevant platform-specific bits of the payload should be extracted. To read more about SNS message formats, see <a href="https://docs.aws.amazon.com/sns/latest/dg/json-formats.html">https://docs.aws.amazon.com/sns/latest/dg/json-formats.html</a> refer to their official documentation.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MessageFormat {
+    /// <p><code>RAW</code></p>
+    Raw,
+    /// <p><code>JSON</code></p>
+    Json,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MessageFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageFormat::Raw => "RAW",
+            MessageFormat::Json => "JSON",
+            MessageFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MessageFormat {
+    fn from(value: &'a str) -> MessageFormat {
+        match value {
+            "RAW" => MessageFormat::Raw,
+            "JSON" => MessageFormat::Json,
+            _ => MessageFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageFormat {
+    fn from(value: String) -> MessageFormat {
+        MessageFormat::from(value.as_str())
+    }
+}
+
+impl From<MessageFormat> for String {
+    fn from(value: MessageFormat) -> String {
+        match value {
+            MessageFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MessageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MessageFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MessageFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MessageFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MessageFormat::from(value))
+    }
+}
+
 /// <p>The value to be compared with the <code>metric</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetricValue {
@@ -5058,8 +13675,9 @@ pub struct MitigationActionIdentifier {
     pub action_name: Option<String>,
     /// <p>The date when this mitigation action was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
 }
 
 /// <p>The set of parameters for this mitigation action. You can specify only one type of parameter (in other words, you can apply only one action for each defined mitigation action).</p>
@@ -5091,29 +13709,123 @@ pub struct MitigationActionParams {
     pub update_device_certificate_params: Option<UpdateDeviceCertificateParams>,
 }
 
-/// <p>Information about the resource that was noncompliant with the audit check.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct NonCompliantResource {
-    /// <p>Other information about the noncompliant resource.</p>
-    #[serde(rename = "additionalInfo")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_info: Option<::std::collections::HashMap<String, String>>,
-    /// <p>Information that identifies the noncompliant resource.</p>
-    #[serde(rename = "resourceIdentifier")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_identifier: Option<ResourceIdentifier>,
-    /// <p>The type of the noncompliant resource.</p>
-    #[serde(rename = "resourceType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+/// <p>The type of mitigation action.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MitigationActionType {
+    /// <p><code>UPDATE_DEVICE_CERTIFICATE</code></p>
+    UpdateDeviceCertificate,
+    /// <p><code>UPDATE_CA_CERTIFICATE</code></p>
+    UpdateCaCertificate,
+    /// <p><code>ADD_THINGS_TO_THING_GROUP</code></p>
+    AddThingsToThingGroup,
+    /// <p><code>REPLACE_DEFAULT_POLICY_VERSION</code></p>
+    ReplaceDefaultPolicyVersion,
+    /// <p><code>ENABLE_IOT_LOGGING</code></p>
+    EnableIotLogging,
+    /// <p><code>PUBLISH_FINDING_TO_SNS</code></p>
+    PublishFindingToSns,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MitigationActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MitigationActionType::UpdateDeviceCertificate => "UPDATE_DEVICE_CERTIFICATE",
+            MitigationActionType::UpdateCaCertificate => "UPDATE_CA_CERTIFICATE",
+            MitigationActionType::AddThingsToThingGroup => "ADD_THINGS_TO_THING_GROUP",
+            MitigationActionType::ReplaceDefaultPolicyVersion => "REPLACE_DEFAULT_POLICY_VERSION",
+            MitigationActionType::EnableIotLogging => "ENABLE_IOT_LOGGING",
+            MitigationActionType::PublishFindingToSns => "PUBLISH_FINDING_TO_SNS",
+            MitigationActionType::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Describes a file to be associated with an OTA update.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct OTAUpdateFile {
-    /// <p>A list of name/attribute pairs.</p>
-    #[serde(rename = "attributes")]
+impl<'a> From<&'a str> for MitigationActionType {
+    fn from(value: &'a str) -> MitigationActionType {
+        match value {
+            "UPDATE_DEVICE_CERTIFICATE" => MitigationActionType::UpdateDeviceCertificate,
+            "UPDATE_CA_CERTIFICATE" => MitigationActionType::UpdateCaCertificate,
+            "ADD_THINGS_TO_THING_GROUP" => MitigationActionType::AddThingsToThingGroup,
+            "REPLACE_DEFAULT_POLICY_VERSION" => MitigationActionType::ReplaceDefaultPolicyVersion,
+            "ENABLE_IOT_LOGGING" => MitigationActionType::EnableIotLogging,
+            "PUBLISH_FINDING_TO_SNS" => MitigationActionType::PublishFindingToSns,
+            _ => MitigationActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MitigationActionType {
+    fn from(value: String) -> MitigationActionType {
+        MitigationActionType::from(value.as_str())
+    }
+}
+
+impl From<MitigationActionType> for String {
+    fn from(value: MitigationActionType) -> String {
+        match value {
+            MitigationActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MitigationActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MitigationActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MitigationActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MitigationActionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MitigationActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MitigationActionType::from(value))
+    }
+}
+
+/// <p>Information about the resource that was noncompliant with the audit check.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct NonCompliantResource {
+    /// <p>Other information about the noncompliant resource.</p>
+    #[serde(rename = "additionalInfo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_info: Option<::std::collections::HashMap<String, String>>,
+    /// <p>Information that identifies the noncompliant resource.</p>
+    #[serde(rename = "resourceIdentifier")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_identifier: Option<ResourceIdentifier>,
+    /// <p>The type of the noncompliant resource.</p>
+    #[serde(rename = "resourceType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<ResourceType>,
+}
+
+/// <p>Describes a file to be associated with an OTA update.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OTAUpdateFile {
+    /// <p>A list of name/attribute pairs.</p>
+    #[serde(rename = "attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attributes: Option<::std::collections::HashMap<String, String>>,
     /// <p>The code signing method of the file.</p>
@@ -5156,8 +13868,9 @@ pub struct OTAUpdateInfo {
     pub aws_job_executions_rollout_config: Option<AwsJobExecutionsRolloutConfig>,
     /// <p>The date when the OTA update was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>A description of the OTA update.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5168,8 +13881,9 @@ pub struct OTAUpdateInfo {
     pub error_info: Option<ErrorInfo>,
     /// <p>The date when the OTA update was last updated.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The OTA update ARN.</p>
     #[serde(rename = "otaUpdateArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5185,25 +13899,120 @@ pub struct OTAUpdateInfo {
     /// <p>The status of the OTA update.</p>
     #[serde(rename = "otaUpdateStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ota_update_status: Option<String>,
+    pub ota_update_status: Option<OTAUpdateStatus>,
     /// <p>Specifies whether the OTA update will continue to run (CONTINUOUS), or will be complete after all those things specified as targets have completed the OTA update (SNAPSHOT). If continuous, the OTA update may also be run on a thing when a change is detected in a target. For example, an OTA update will run on a thing when the thing is added to a target group, even after the OTA update was completed by all things originally in the group. </p>
     #[serde(rename = "targetSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_selection: Option<String>,
+    pub target_selection: Option<TargetSelection>,
     /// <p>The targets of the OTA update.</p>
     #[serde(rename = "targets")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub targets: Option<Vec<String>>,
 }
 
+/// <p>The OTA update status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OTAUpdateStatus {
+    /// <p><code>CREATE_PENDING</code></p>
+    CreatePending,
+    /// <p><code>CREATE_IN_PROGRESS</code></p>
+    CreateInProgress,
+    /// <p><code>CREATE_COMPLETE</code></p>
+    CreateComplete,
+    /// <p><code>CREATE_FAILED</code></p>
+    CreateFailed,
+    /// <p><code>DELETE_IN_PROGRESS</code></p>
+    DeleteInProgress,
+    /// <p><code>DELETE_FAILED</code></p>
+    DeleteFailed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OTAUpdateStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OTAUpdateStatus::CreatePending => "CREATE_PENDING",
+            OTAUpdateStatus::CreateInProgress => "CREATE_IN_PROGRESS",
+            OTAUpdateStatus::CreateComplete => "CREATE_COMPLETE",
+            OTAUpdateStatus::CreateFailed => "CREATE_FAILED",
+            OTAUpdateStatus::DeleteInProgress => "DELETE_IN_PROGRESS",
+            OTAUpdateStatus::DeleteFailed => "DELETE_FAILED",
+            OTAUpdateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OTAUpdateStatus {
+    fn from(value: &'a str) -> OTAUpdateStatus {
+        match value {
+            "CREATE_PENDING" => OTAUpdateStatus::CreatePending,
+            "CREATE_IN_PROGRESS" => OTAUpdateStatus::CreateInProgress,
+            "CREATE_COMPLETE" => OTAUpdateStatus::CreateComplete,
+            "CREATE_FAILED" => OTAUpdateStatus::CreateFailed,
+            "DELETE_IN_PROGRESS" => OTAUpdateStatus::DeleteInProgress,
+            "DELETE_FAILED" => OTAUpdateStatus::DeleteFailed,
+            _ => OTAUpdateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OTAUpdateStatus {
+    fn from(value: String) -> OTAUpdateStatus {
+        OTAUpdateStatus::from(value.as_str())
+    }
+}
+
+impl From<OTAUpdateStatus> for String {
+    fn from(value: OTAUpdateStatus) -> String {
+        match value {
+            OTAUpdateStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OTAUpdateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OTAUpdateStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OTAUpdateStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OTAUpdateStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OTAUpdateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OTAUpdateStatus::from(value))
+    }
+}
+
 /// <p>An OTA update summary.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct OTAUpdateSummary {
     /// <p>The date when the OTA update was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The OTA update ARN.</p>
     #[serde(rename = "otaUpdateArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5228,12 +14037,14 @@ pub struct OutgoingCertificate {
     pub certificate_id: Option<String>,
     /// <p>The certificate creation date.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The date the transfer was initiated.</p>
     #[serde(rename = "transferDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transfer_date: Option<f64>,
+    pub transfer_date: Option<EpochTimestamp>,
     /// <p>The transfer message.</p>
     #[serde(rename = "transferMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5258,14 +14069,89 @@ pub struct Policy {
     pub policy_name: Option<String>,
 }
 
+/// <p>The name of the template to be applied. The only supported value is <code>BLANK_POLICY</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyTemplateName {
+    /// <p><code>BLANK_POLICY</code></p>
+    BlankPolicy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PolicyTemplateName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyTemplateName::BlankPolicy => "BLANK_POLICY",
+            PolicyTemplateName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PolicyTemplateName {
+    fn from(value: &'a str) -> PolicyTemplateName {
+        match value {
+            "BLANK_POLICY" => PolicyTemplateName::BlankPolicy,
+            _ => PolicyTemplateName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyTemplateName {
+    fn from(value: String) -> PolicyTemplateName {
+        PolicyTemplateName::from(value.as_str())
+    }
+}
+
+impl From<PolicyTemplateName> for String {
+    fn from(value: PolicyTemplateName) -> String {
+        match value {
+            PolicyTemplateName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PolicyTemplateName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PolicyTemplateName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PolicyTemplateName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyTemplateName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PolicyTemplateName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PolicyTemplateName::from(value))
+    }
+}
+
 /// <p>Describes a policy version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PolicyVersion {
     /// <p>The date and time the policy was created.</p>
     #[serde(rename = "createDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_date: Option<f64>,
+    pub create_date: Option<EpochTimestamp>,
     /// <p>Specifies whether the policy version is the default.</p>
     #[serde(rename = "isDefaultVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5354,6 +14240,72 @@ pub struct RegisterCACertificateRequest {
     pub verification_certificate: String,
 }
 
+impl RegisterCACertificateRequest {
+    /// Returns a builder for `RegisterCACertificateRequest`, checking its required members when built.
+    pub fn builder() -> RegisterCACertificateRequestBuilder {
+        RegisterCACertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterCACertificateRequest`](struct.RegisterCACertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterCACertificateRequestBuilder {
+    allow_auto_registration: Option<bool>,
+    ca_certificate: Option<String>,
+    registration_config: Option<RegistrationConfig>,
+    set_as_active: Option<bool>,
+    verification_certificate: Option<String>,
+}
+
+impl RegisterCACertificateRequestBuilder {
+    /// Sets the [`allow_auto_registration`](struct.RegisterCACertificateRequest.html#structfield.allow_auto_registration) member.
+    pub fn allow_auto_registration<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_auto_registration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ca_certificate`](struct.RegisterCACertificateRequest.html#structfield.ca_certificate) member.
+    pub fn ca_certificate<T: Into<String>>(mut self, value: T) -> Self {
+        self.ca_certificate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registration_config`](struct.RegisterCACertificateRequest.html#structfield.registration_config) member.
+    pub fn registration_config<T: Into<RegistrationConfig>>(mut self, value: T) -> Self {
+        self.registration_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`set_as_active`](struct.RegisterCACertificateRequest.html#structfield.set_as_active) member.
+    pub fn set_as_active<T: Into<bool>>(mut self, value: T) -> Self {
+        self.set_as_active = Some(value.into());
+        self
+    }
+
+    /// Sets the [`verification_certificate`](struct.RegisterCACertificateRequest.html#structfield.verification_certificate) member.
+    pub fn verification_certificate<T: Into<String>>(mut self, value: T) -> Self {
+        self.verification_certificate = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterCACertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterCACertificateRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterCACertificateRequest {
+            allow_auto_registration: self.allow_auto_registration,
+            ca_certificate: self
+                .ca_certificate
+                .ok_or(::rusoto_core::BuildError::missing("ca_certificate"))?,
+            registration_config: self.registration_config,
+            set_as_active: self.set_as_active,
+            verification_certificate: self.verification_certificate.ok_or(
+                ::rusoto_core::BuildError::missing("verification_certificate"),
+            )?,
+        })
+    }
+}
+
 /// <p>The output from the RegisterCACertificateResponse operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -5381,7 +14333,55 @@ pub struct RegisterCertificateRequest {
     /// <p>The status of the register certificate request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<CertificateStatus>,
+}
+
+impl RegisterCertificateRequest {
+    /// Returns a builder for `RegisterCertificateRequest`, checking its required members when built.
+    pub fn builder() -> RegisterCertificateRequestBuilder {
+        RegisterCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterCertificateRequest`](struct.RegisterCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterCertificateRequestBuilder {
+    ca_certificate_pem: Option<String>,
+    certificate_pem: Option<String>,
+    status: Option<CertificateStatus>,
+}
+
+impl RegisterCertificateRequestBuilder {
+    /// Sets the [`ca_certificate_pem`](struct.RegisterCertificateRequest.html#structfield.ca_certificate_pem) member.
+    pub fn ca_certificate_pem<T: Into<String>>(mut self, value: T) -> Self {
+        self.ca_certificate_pem = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_pem`](struct.RegisterCertificateRequest.html#structfield.certificate_pem) member.
+    pub fn certificate_pem<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_pem = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.RegisterCertificateRequest.html#structfield.status) member.
+    pub fn status<T: Into<CertificateStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterCertificateRequest {
+            ca_certificate_pem: self.ca_certificate_pem,
+            certificate_pem: self
+                .certificate_pem
+                .ok_or(::rusoto_core::BuildError::missing("certificate_pem"))?,
+            status: self.status,
+        })
+    }
 }
 
 /// <p>The output from the RegisterCertificate operation.</p>
@@ -5409,6 +14409,47 @@ pub struct RegisterThingRequest {
     pub template_body: String,
 }
 
+impl RegisterThingRequest {
+    /// Returns a builder for `RegisterThingRequest`, checking its required members when built.
+    pub fn builder() -> RegisterThingRequestBuilder {
+        RegisterThingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterThingRequest`](struct.RegisterThingRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterThingRequestBuilder {
+    parameters: Option<::std::collections::HashMap<String, String>>,
+    template_body: Option<String>,
+}
+
+impl RegisterThingRequestBuilder {
+    /// Sets the [`parameters`](struct.RegisterThingRequest.html#structfield.parameters) member.
+    pub fn parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_body`](struct.RegisterThingRequest.html#structfield.template_body) member.
+    pub fn template_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_body = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterThingRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RegisterThingRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterThingRequest {
+            parameters: self.parameters,
+            template_body: self
+                .template_body
+                .ok_or(::rusoto_core::BuildError::missing("template_body"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RegisterThingResponse {
@@ -5447,6 +14488,46 @@ pub struct RejectCertificateTransferRequest {
     pub reject_reason: Option<String>,
 }
 
+impl RejectCertificateTransferRequest {
+    /// Returns a builder for `RejectCertificateTransferRequest`, checking its required members when built.
+    pub fn builder() -> RejectCertificateTransferRequestBuilder {
+        RejectCertificateTransferRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RejectCertificateTransferRequest`](struct.RejectCertificateTransferRequest.html).
+#[derive(Default, Debug)]
+pub struct RejectCertificateTransferRequestBuilder {
+    certificate_id: Option<String>,
+    reject_reason: Option<String>,
+}
+
+impl RejectCertificateTransferRequestBuilder {
+    /// Sets the [`certificate_id`](struct.RejectCertificateTransferRequest.html#structfield.certificate_id) member.
+    pub fn certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reject_reason`](struct.RejectCertificateTransferRequest.html#structfield.reject_reason) member.
+    pub fn reject_reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reject_reason = Some(value.into());
+        self
+    }
+
+    /// Builds the `RejectCertificateTransferRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RejectCertificateTransferRequest, ::rusoto_core::BuildError> {
+        Ok(RejectCertificateTransferRequest {
+            certificate_id: self
+                .certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("certificate_id"))?,
+            reject_reason: self.reject_reason,
+        })
+    }
+}
+
 /// <p>Information about a related resource.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -5462,7 +14543,7 @@ pub struct RelatedResource {
     /// <p>The type of resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -5485,6 +14566,60 @@ pub struct RemoveThingFromBillingGroupRequest {
     pub thing_name: Option<String>,
 }
 
+impl RemoveThingFromBillingGroupRequest {
+    /// Returns a builder for `RemoveThingFromBillingGroupRequest`, checking its required members when built.
+    pub fn builder() -> RemoveThingFromBillingGroupRequestBuilder {
+        RemoveThingFromBillingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveThingFromBillingGroupRequest`](struct.RemoveThingFromBillingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveThingFromBillingGroupRequestBuilder {
+    billing_group_arn: Option<String>,
+    billing_group_name: Option<String>,
+    thing_arn: Option<String>,
+    thing_name: Option<String>,
+}
+
+impl RemoveThingFromBillingGroupRequestBuilder {
+    /// Sets the [`billing_group_arn`](struct.RemoveThingFromBillingGroupRequest.html#structfield.billing_group_arn) member.
+    pub fn billing_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.billing_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`billing_group_name`](struct.RemoveThingFromBillingGroupRequest.html#structfield.billing_group_name) member.
+    pub fn billing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.billing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_arn`](struct.RemoveThingFromBillingGroupRequest.html#structfield.thing_arn) member.
+    pub fn thing_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.RemoveThingFromBillingGroupRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveThingFromBillingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveThingFromBillingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveThingFromBillingGroupRequest {
+            billing_group_arn: self.billing_group_arn,
+            billing_group_name: self.billing_group_name,
+            thing_arn: self.thing_arn,
+            thing_name: self.thing_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RemoveThingFromBillingGroupResponse {}
@@ -5509,16 +14644,70 @@ pub struct RemoveThingFromThingGroupRequest {
     pub thing_name: Option<String>,
 }
 
+impl RemoveThingFromThingGroupRequest {
+    /// Returns a builder for `RemoveThingFromThingGroupRequest`, checking its required members when built.
+    pub fn builder() -> RemoveThingFromThingGroupRequestBuilder {
+        RemoveThingFromThingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveThingFromThingGroupRequest`](struct.RemoveThingFromThingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveThingFromThingGroupRequestBuilder {
+    thing_arn: Option<String>,
+    thing_group_arn: Option<String>,
+    thing_group_name: Option<String>,
+    thing_name: Option<String>,
+}
+
+impl RemoveThingFromThingGroupRequestBuilder {
+    /// Sets the [`thing_arn`](struct.RemoveThingFromThingGroupRequest.html#structfield.thing_arn) member.
+    pub fn thing_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_arn`](struct.RemoveThingFromThingGroupRequest.html#structfield.thing_group_arn) member.
+    pub fn thing_group_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_name`](struct.RemoveThingFromThingGroupRequest.html#structfield.thing_group_name) member.
+    pub fn thing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.RemoveThingFromThingGroupRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveThingFromThingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveThingFromThingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveThingFromThingGroupRequest {
+            thing_arn: self.thing_arn,
+            thing_group_arn: self.thing_group_arn,
+            thing_group_name: self.thing_group_name,
+            thing_name: self.thing_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RemoveThingFromThingGroupResponse {}
 
 /// <p>Parameters to define a mitigation action that adds a blank policy to restrict permissions.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReplaceDefaultPolicyVersionParams {
     /// <p>The name of the template to be applied. The only supported value is <code>BLANK_POLICY</code>.</p>
     #[serde(rename = "templateName")]
-    pub template_name: String,
+    pub template_name: PolicyTemplateName,
 }
 
 /// <p>The input for the ReplaceTopicRule operation.</p>
@@ -5532,66 +14721,294 @@ pub struct ReplaceTopicRuleRequest {
     pub topic_rule_payload: TopicRulePayload,
 }
 
-/// <p>Describes an action to republish to another topic.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct RepublishAction {
-    /// <p>The Quality of Service (QoS) level to use when republishing messages.</p>
-    #[serde(rename = "qos")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub qos: Option<i64>,
-    /// <p>The ARN of the IAM role that grants access.</p>
-    #[serde(rename = "roleArn")]
-    pub role_arn: String,
-    /// <p>The name of the MQTT topic.</p>
-    #[serde(rename = "topic")]
-    pub topic: String,
+impl ReplaceTopicRuleRequest {
+    /// Returns a builder for `ReplaceTopicRuleRequest`, checking its required members when built.
+    pub fn builder() -> ReplaceTopicRuleRequestBuilder {
+        ReplaceTopicRuleRequestBuilder::default()
+    }
 }
 
-/// <p>Information that identifies the noncompliant resource.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ResourceIdentifier {
-    /// <p>The account with which the resource is associated.</p>
-    #[serde(rename = "account")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub account: Option<String>,
-    /// <p>The ID of the CA certificate used to authorize the certificate.</p>
-    #[serde(rename = "caCertificateId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub ca_certificate_id: Option<String>,
-    /// <p>The client ID.</p>
-    #[serde(rename = "clientId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_id: Option<String>,
-    /// <p>The ID of the Amazon Cognito identity pool.</p>
-    #[serde(rename = "cognitoIdentityPoolId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cognito_identity_pool_id: Option<String>,
-    /// <p>The ID of the certificate attached to the resource.</p>
-    #[serde(rename = "deviceCertificateId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub device_certificate_id: Option<String>,
-    /// <p>The version of the policy associated with the resource.</p>
-    #[serde(rename = "policyVersionIdentifier")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy_version_identifier: Option<PolicyVersionIdentifier>,
+/// Builds a [`ReplaceTopicRuleRequest`](struct.ReplaceTopicRuleRequest.html).
+#[derive(Default, Debug)]
+pub struct ReplaceTopicRuleRequestBuilder {
+    rule_name: Option<String>,
+    topic_rule_payload: Option<TopicRulePayload>,
 }
 
-/// <p>Role alias description.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl ReplaceTopicRuleRequestBuilder {
+    /// Sets the [`rule_name`](struct.ReplaceTopicRuleRequest.html#structfield.rule_name) member.
+    pub fn rule_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.rule_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`topic_rule_payload`](struct.ReplaceTopicRuleRequest.html#structfield.topic_rule_payload) member.
+    pub fn topic_rule_payload<T: Into<TopicRulePayload>>(mut self, value: T) -> Self {
+        self.topic_rule_payload = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReplaceTopicRuleRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ReplaceTopicRuleRequest, ::rusoto_core::BuildError> {
+        Ok(ReplaceTopicRuleRequest {
+            rule_name: self
+                .rule_name
+                .ok_or(::rusoto_core::BuildError::missing("rule_name"))?,
+            topic_rule_payload: self
+                .topic_rule_payload
+                .ok_or(::rusoto_core::BuildError::missing("topic_rule_payload"))?,
+        })
+    }
+}
+
+/// <p>The type of task report.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReportType {
+    /// <p><code>ERRORS</code></p>
+    Errors,
+    /// <p><code>RESULTS</code></p>
+    Results,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReportType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReportType::Errors => "ERRORS",
+            ReportType::Results => "RESULTS",
+            ReportType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReportType {
+    fn from(value: &'a str) -> ReportType {
+        match value {
+            "ERRORS" => ReportType::Errors,
+            "RESULTS" => ReportType::Results,
+            _ => ReportType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReportType {
+    fn from(value: String) -> ReportType {
+        ReportType::from(value.as_str())
+    }
+}
+
+impl From<ReportType> for String {
+    fn from(value: ReportType) -> String {
+        match value {
+            ReportType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReportType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReportType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReportType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReportType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReportType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReportType::from(value))
+    }
+}
+
+/// <p>Describes an action to republish to another topic.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepublishAction {
+    /// <p>The Quality of Service (QoS) level to use when republishing messages.</p>
+    #[serde(rename = "qos")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qos: Option<i64>,
+    /// <p>The ARN of the IAM role that grants access.</p>
+    #[serde(rename = "roleArn")]
+    pub role_arn: String,
+    /// <p>The name of the MQTT topic.</p>
+    #[serde(rename = "topic")]
+    pub topic: String,
+}
+
+/// <p>Information that identifies the noncompliant resource.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceIdentifier {
+    /// <p>The account with which the resource is associated.</p>
+    #[serde(rename = "account")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    /// <p>The ID of the CA certificate used to authorize the certificate.</p>
+    #[serde(rename = "caCertificateId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ca_certificate_id: Option<String>,
+    /// <p>The client ID.</p>
+    #[serde(rename = "clientId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    /// <p>The ID of the Amazon Cognito identity pool.</p>
+    #[serde(rename = "cognitoIdentityPoolId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cognito_identity_pool_id: Option<String>,
+    /// <p>The ID of the certificate attached to the resource.</p>
+    #[serde(rename = "deviceCertificateId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_certificate_id: Option<String>,
+    /// <p>The version of the policy associated with the resource.</p>
+    #[serde(rename = "policyVersionIdentifier")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy_version_identifier: Option<PolicyVersionIdentifier>,
+}
+
+/// <p>The type of the noncompliant resource.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// <p><code>DEVICE_CERTIFICATE</code></p>
+    DeviceCertificate,
+    /// <p><code>CA_CERTIFICATE</code></p>
+    CaCertificate,
+    /// <p><code>IOT_POLICY</code></p>
+    IotPolicy,
+    /// <p><code>COGNITO_IDENTITY_POOL</code></p>
+    CognitoIdentityPool,
+    /// <p><code>CLIENT_ID</code></p>
+    ClientId,
+    /// <p><code>ACCOUNT_SETTINGS</code></p>
+    AccountSettings,
+    /// <p><code>ROLE_ALIAS</code></p>
+    RoleAlias,
+    /// <p><code>IAM_ROLE</code></p>
+    IamRole,
+    /// <p><code>ISSUER_CERTIFICATE</code></p>
+    IssuerCertificate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::DeviceCertificate => "DEVICE_CERTIFICATE",
+            ResourceType::CaCertificate => "CA_CERTIFICATE",
+            ResourceType::IotPolicy => "IOT_POLICY",
+            ResourceType::CognitoIdentityPool => "COGNITO_IDENTITY_POOL",
+            ResourceType::ClientId => "CLIENT_ID",
+            ResourceType::AccountSettings => "ACCOUNT_SETTINGS",
+            ResourceType::RoleAlias => "ROLE_ALIAS",
+            ResourceType::IamRole => "IAM_ROLE",
+            ResourceType::IssuerCertificate => "ISSUER_CERTIFICATE",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "DEVICE_CERTIFICATE" => ResourceType::DeviceCertificate,
+            "CA_CERTIFICATE" => ResourceType::CaCertificate,
+            "IOT_POLICY" => ResourceType::IotPolicy,
+            "COGNITO_IDENTITY_POOL" => ResourceType::CognitoIdentityPool,
+            "CLIENT_ID" => ResourceType::ClientId,
+            "ACCOUNT_SETTINGS" => ResourceType::AccountSettings,
+            "ROLE_ALIAS" => ResourceType::RoleAlias,
+            "IAM_ROLE" => ResourceType::IamRole,
+            "ISSUER_CERTIFICATE" => ResourceType::IssuerCertificate,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        ResourceType::from(value.as_str())
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(value: ResourceType) -> String {
+        match value {
+            ResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceType::from(value))
+    }
+}
+
+/// <p>Role alias description.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RoleAliasDescription {
     /// <p>The UNIX timestamp of when the role alias was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The number of seconds for which the credential is valid.</p>
     #[serde(rename = "credentialDurationSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credential_duration_seconds: Option<i64>,
     /// <p>The UNIX timestamp of when the role alias was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The role alias owner.</p>
     #[serde(rename = "owner")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5619,7 +15036,7 @@ pub struct S3Action {
     /// <p>The Amazon S3 canned ACL that controls access to the object identified by the object key. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/acl-overview.html#canned-acl">S3 canned ACLs</a>.</p>
     #[serde(rename = "cannedAcl")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub canned_acl: Option<String>,
+    pub canned_acl: Option<CannedAccessControlList>,
     /// <p>The object key.</p>
     #[serde(rename = "key")]
     pub key: String,
@@ -5680,11 +15097,11 @@ pub struct ScheduledAuditMetadata {
     /// <p>The day of the week on which the scheduled audit is run (if the <code>frequency</code> is "WEEKLY" or "BIWEEKLY").</p>
     #[serde(rename = "dayOfWeek")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub day_of_week: Option<String>,
+    pub day_of_week: Option<DayOfWeek>,
     /// <p>How often the scheduled audit occurs.</p>
     #[serde(rename = "frequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<String>,
+    pub frequency: Option<AuditFrequency>,
     /// <p>The ARN of the scheduled audit.</p>
     #[serde(rename = "scheduledAuditArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5718,6 +15135,68 @@ pub struct SearchIndexRequest {
     pub query_version: Option<String>,
 }
 
+impl SearchIndexRequest {
+    /// Returns a builder for `SearchIndexRequest`, checking its required members when built.
+    pub fn builder() -> SearchIndexRequestBuilder {
+        SearchIndexRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchIndexRequest`](struct.SearchIndexRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchIndexRequestBuilder {
+    index_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    query_string: Option<String>,
+    query_version: Option<String>,
+}
+
+impl SearchIndexRequestBuilder {
+    /// Sets the [`index_name`](struct.SearchIndexRequest.html#structfield.index_name) member.
+    pub fn index_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.index_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.SearchIndexRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SearchIndexRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_string`](struct.SearchIndexRequest.html#structfield.query_string) member.
+    pub fn query_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_version`](struct.SearchIndexRequest.html#structfield.query_version) member.
+    pub fn query_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchIndexRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SearchIndexRequest, ::rusoto_core::BuildError> {
+        Ok(SearchIndexRequest {
+            index_name: self.index_name,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            query_string: self
+                .query_string
+                .ok_or(::rusoto_core::BuildError::missing("query_string"))?,
+            query_version: self.query_version,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SearchIndexResponse {
@@ -5777,6 +15256,38 @@ pub struct SetDefaultAuthorizerRequest {
     pub authorizer_name: String,
 }
 
+impl SetDefaultAuthorizerRequest {
+    /// Returns a builder for `SetDefaultAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> SetDefaultAuthorizerRequestBuilder {
+        SetDefaultAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetDefaultAuthorizerRequest`](struct.SetDefaultAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct SetDefaultAuthorizerRequestBuilder {
+    authorizer_name: Option<String>,
+}
+
+impl SetDefaultAuthorizerRequestBuilder {
+    /// Sets the [`authorizer_name`](struct.SetDefaultAuthorizerRequest.html#structfield.authorizer_name) member.
+    pub fn authorizer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetDefaultAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetDefaultAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(SetDefaultAuthorizerRequest {
+            authorizer_name: self
+                .authorizer_name
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SetDefaultAuthorizerResponse {
@@ -5801,6 +15312,48 @@ pub struct SetDefaultPolicyVersionRequest {
     pub policy_version_id: String,
 }
 
+impl SetDefaultPolicyVersionRequest {
+    /// Returns a builder for `SetDefaultPolicyVersionRequest`, checking its required members when built.
+    pub fn builder() -> SetDefaultPolicyVersionRequestBuilder {
+        SetDefaultPolicyVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetDefaultPolicyVersionRequest`](struct.SetDefaultPolicyVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct SetDefaultPolicyVersionRequestBuilder {
+    policy_name: Option<String>,
+    policy_version_id: Option<String>,
+}
+
+impl SetDefaultPolicyVersionRequestBuilder {
+    /// Sets the [`policy_name`](struct.SetDefaultPolicyVersionRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_version_id`](struct.SetDefaultPolicyVersionRequest.html#structfield.policy_version_id) member.
+    pub fn policy_version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetDefaultPolicyVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetDefaultPolicyVersionRequest, ::rusoto_core::BuildError> {
+        Ok(SetDefaultPolicyVersionRequest {
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+            policy_version_id: self
+                .policy_version_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_version_id"))?,
+        })
+    }
+}
+
 /// <p>The input for the SetLoggingOptions operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SetLoggingOptionsRequest {
@@ -5809,22 +15362,96 @@ pub struct SetLoggingOptionsRequest {
     pub logging_options_payload: LoggingOptionsPayload,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl SetLoggingOptionsRequest {
+    /// Returns a builder for `SetLoggingOptionsRequest`, checking its required members when built.
+    pub fn builder() -> SetLoggingOptionsRequestBuilder {
+        SetLoggingOptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetLoggingOptionsRequest`](struct.SetLoggingOptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct SetLoggingOptionsRequestBuilder {
+    logging_options_payload: Option<LoggingOptionsPayload>,
+}
+
+impl SetLoggingOptionsRequestBuilder {
+    /// Sets the [`logging_options_payload`](struct.SetLoggingOptionsRequest.html#structfield.logging_options_payload) member.
+    pub fn logging_options_payload<T: Into<LoggingOptionsPayload>>(mut self, value: T) -> Self {
+        self.logging_options_payload = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetLoggingOptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetLoggingOptionsRequest, ::rusoto_core::BuildError> {
+        Ok(SetLoggingOptionsRequest {
+            logging_options_payload: self.logging_options_payload.ok_or(
+                ::rusoto_core::BuildError::missing("logging_options_payload"),
+            )?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SetV2LoggingLevelRequest {
     /// <p>The log level.</p>
     #[serde(rename = "logLevel")]
-    pub log_level: String,
+    pub log_level: LogLevel,
     /// <p>The log target.</p>
     #[serde(rename = "logTarget")]
     pub log_target: LogTarget,
 }
 
+impl SetV2LoggingLevelRequest {
+    /// Returns a builder for `SetV2LoggingLevelRequest`, checking its required members when built.
+    pub fn builder() -> SetV2LoggingLevelRequestBuilder {
+        SetV2LoggingLevelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetV2LoggingLevelRequest`](struct.SetV2LoggingLevelRequest.html).
+#[derive(Default, Debug)]
+pub struct SetV2LoggingLevelRequestBuilder {
+    log_level: Option<LogLevel>,
+    log_target: Option<LogTarget>,
+}
+
+impl SetV2LoggingLevelRequestBuilder {
+    /// Sets the [`log_level`](struct.SetV2LoggingLevelRequest.html#structfield.log_level) member.
+    pub fn log_level<T: Into<LogLevel>>(mut self, value: T) -> Self {
+        self.log_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_target`](struct.SetV2LoggingLevelRequest.html#structfield.log_target) member.
+    pub fn log_target<T: Into<LogTarget>>(mut self, value: T) -> Self {
+        self.log_target = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetV2LoggingLevelRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetV2LoggingLevelRequest, ::rusoto_core::BuildError> {
+        Ok(SetV2LoggingLevelRequest {
+            log_level: self
+                .log_level
+                .ok_or(::rusoto_core::BuildError::missing("log_level"))?,
+            log_target: self
+                .log_target
+                .ok_or(::rusoto_core::BuildError::missing("log_target"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SetV2LoggingOptionsRequest {
     /// <p>The default logging level.</p>
     #[serde(rename = "defaultLogLevel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_log_level: Option<String>,
+    pub default_log_level: Option<LogLevel>,
     /// <p>If true all logs are disabled. The default is false.</p>
     #[serde(rename = "disableAllLogs")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -5835,6 +15462,52 @@ pub struct SetV2LoggingOptionsRequest {
     pub role_arn: Option<String>,
 }
 
+impl SetV2LoggingOptionsRequest {
+    /// Returns a builder for `SetV2LoggingOptionsRequest`, checking its required members when built.
+    pub fn builder() -> SetV2LoggingOptionsRequestBuilder {
+        SetV2LoggingOptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetV2LoggingOptionsRequest`](struct.SetV2LoggingOptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct SetV2LoggingOptionsRequestBuilder {
+    default_log_level: Option<LogLevel>,
+    disable_all_logs: Option<bool>,
+    role_arn: Option<String>,
+}
+
+impl SetV2LoggingOptionsRequestBuilder {
+    /// Sets the [`default_log_level`](struct.SetV2LoggingOptionsRequest.html#structfield.default_log_level) member.
+    pub fn default_log_level<T: Into<LogLevel>>(mut self, value: T) -> Self {
+        self.default_log_level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disable_all_logs`](struct.SetV2LoggingOptionsRequest.html#structfield.disable_all_logs) member.
+    pub fn disable_all_logs<T: Into<bool>>(mut self, value: T) -> Self {
+        self.disable_all_logs = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.SetV2LoggingOptionsRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetV2LoggingOptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetV2LoggingOptionsRequest, ::rusoto_core::BuildError> {
+        Ok(SetV2LoggingOptionsRequest {
+            default_log_level: self.default_log_level,
+            disable_all_logs: self.disable_all_logs,
+            role_arn: self.role_arn,
+        })
+    }
+}
+
 /// <p>Describes the code-signing profile.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SigningProfileParameter {
@@ -5858,7 +15531,7 @@ pub struct SnsAction {
     /// <p>(Optional) The message format of the message to publish. Accepted values are "JSON" and "RAW". The default value of the attribute is "RAW". SNS uses this setting to determine if the payload should be parsed and relevant platform-specific bits of the payload should be extracted. To read more about SNS message formats, see <a href="https://docs.aws.amazon.com/sns/latest/dg/json-formats.html">https://docs.aws.amazon.com/sns/latest/dg/json-formats.html</a> refer to their official documentation.</p>
     #[serde(rename = "messageFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_format: Option<String>,
+    pub message_format: Option<MessageFormat>,
     /// <p>The ARN of the IAM role that grants access.</p>
     #[serde(rename = "roleArn")]
     pub role_arn: String,
@@ -5898,6 +15571,74 @@ pub struct StartAuditMitigationActionsTaskRequest {
     pub task_id: String,
 }
 
+impl StartAuditMitigationActionsTaskRequest {
+    /// Returns a builder for `StartAuditMitigationActionsTaskRequest`, checking its required members when built.
+    pub fn builder() -> StartAuditMitigationActionsTaskRequestBuilder {
+        StartAuditMitigationActionsTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartAuditMitigationActionsTaskRequest`](struct.StartAuditMitigationActionsTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct StartAuditMitigationActionsTaskRequestBuilder {
+    audit_check_to_actions_mapping: Option<::std::collections::HashMap<String, Vec<String>>>,
+    client_request_token: Option<String>,
+    target: Option<AuditMitigationActionsTaskTarget>,
+    task_id: Option<String>,
+}
+
+impl StartAuditMitigationActionsTaskRequestBuilder {
+    /// Sets the [`audit_check_to_actions_mapping`](struct.StartAuditMitigationActionsTaskRequest.html#structfield.audit_check_to_actions_mapping) member.
+    pub fn audit_check_to_actions_mapping<
+        T: Into<::std::collections::HashMap<String, Vec<String>>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.audit_check_to_actions_mapping = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_request_token`](struct.StartAuditMitigationActionsTaskRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target`](struct.StartAuditMitigationActionsTaskRequest.html#structfield.target) member.
+    pub fn target<T: Into<AuditMitigationActionsTaskTarget>>(mut self, value: T) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_id`](struct.StartAuditMitigationActionsTaskRequest.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartAuditMitigationActionsTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartAuditMitigationActionsTaskRequest, ::rusoto_core::BuildError>
+    {
+        Ok(StartAuditMitigationActionsTaskRequest {
+            audit_check_to_actions_mapping: self.audit_check_to_actions_mapping.ok_or(
+                ::rusoto_core::BuildError::missing("audit_check_to_actions_mapping"),
+            )?,
+            client_request_token: self
+                .client_request_token
+                .ok_or(::rusoto_core::BuildError::missing("client_request_token"))?,
+            target: self
+                .target
+                .ok_or(::rusoto_core::BuildError::missing("target"))?,
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartAuditMitigationActionsTaskResponse {
@@ -5914,6 +15655,38 @@ pub struct StartOnDemandAuditTaskRequest {
     pub target_check_names: Vec<String>,
 }
 
+impl StartOnDemandAuditTaskRequest {
+    /// Returns a builder for `StartOnDemandAuditTaskRequest`, checking its required members when built.
+    pub fn builder() -> StartOnDemandAuditTaskRequestBuilder {
+        StartOnDemandAuditTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartOnDemandAuditTaskRequest`](struct.StartOnDemandAuditTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct StartOnDemandAuditTaskRequestBuilder {
+    target_check_names: Option<Vec<String>>,
+}
+
+impl StartOnDemandAuditTaskRequestBuilder {
+    /// Sets the [`target_check_names`](struct.StartOnDemandAuditTaskRequest.html#structfield.target_check_names) member.
+    pub fn target_check_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.target_check_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartOnDemandAuditTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartOnDemandAuditTaskRequest, ::rusoto_core::BuildError> {
+        Ok(StartOnDemandAuditTaskRequest {
+            target_check_names: self
+                .target_check_names
+                .ok_or(::rusoto_core::BuildError::missing("target_check_names"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartOnDemandAuditTaskResponse {
@@ -5956,6 +15729,68 @@ pub struct StartThingRegistrationTaskRequest {
     pub template_body: String,
 }
 
+impl StartThingRegistrationTaskRequest {
+    /// Returns a builder for `StartThingRegistrationTaskRequest`, checking its required members when built.
+    pub fn builder() -> StartThingRegistrationTaskRequestBuilder {
+        StartThingRegistrationTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartThingRegistrationTaskRequest`](struct.StartThingRegistrationTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct StartThingRegistrationTaskRequestBuilder {
+    input_file_bucket: Option<String>,
+    input_file_key: Option<String>,
+    role_arn: Option<String>,
+    template_body: Option<String>,
+}
+
+impl StartThingRegistrationTaskRequestBuilder {
+    /// Sets the [`input_file_bucket`](struct.StartThingRegistrationTaskRequest.html#structfield.input_file_bucket) member.
+    pub fn input_file_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.input_file_bucket = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_file_key`](struct.StartThingRegistrationTaskRequest.html#structfield.input_file_key) member.
+    pub fn input_file_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.input_file_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.StartThingRegistrationTaskRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`template_body`](struct.StartThingRegistrationTaskRequest.html#structfield.template_body) member.
+    pub fn template_body<T: Into<String>>(mut self, value: T) -> Self {
+        self.template_body = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartThingRegistrationTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartThingRegistrationTaskRequest, ::rusoto_core::BuildError> {
+        Ok(StartThingRegistrationTaskRequest {
+            input_file_bucket: self
+                .input_file_bucket
+                .ok_or(::rusoto_core::BuildError::missing("input_file_bucket"))?,
+            input_file_key: self
+                .input_file_key
+                .ok_or(::rusoto_core::BuildError::missing("input_file_key"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            template_body: self
+                .template_body
+                .ok_or(::rusoto_core::BuildError::missing("template_body"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartThingRegistrationTaskResponse {
@@ -5984,11 +15819,101 @@ pub struct Statistics {
     pub count: Option<i64>,
 }
 
-/// <p>Starts execution of a Step Functions state machine.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct StepFunctionsAction {
-    /// <p>(Optional) A name will be given to the state machine execution consisting of this prefix followed by a UUID. Step Functions automatically creates a unique name for each state machine execution if one is not provided.</p>
-    #[serde(rename = "executionNamePrefix")]
+/// <p>The status of the bulk thing provisioning task.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// <p><code>InProgress</code></p>
+    InProgress,
+    /// <p><code>Completed</code></p>
+    Completed,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Cancelled</code></p>
+    Cancelled,
+    /// <p><code>Cancelling</code></p>
+    Cancelling,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Status {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::InProgress => "InProgress",
+            Status::Completed => "Completed",
+            Status::Failed => "Failed",
+            Status::Cancelled => "Cancelled",
+            Status::Cancelling => "Cancelling",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "InProgress" => Status::InProgress,
+            "Completed" => Status::Completed,
+            "Failed" => Status::Failed,
+            "Cancelled" => Status::Cancelled,
+            "Cancelling" => Status::Cancelling,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        Status::from(value.as_str())
+    }
+}
+
+impl From<Status> for String {
+    fn from(value: Status) -> String {
+        match value {
+            Status::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Status::from(value))
+    }
+}
+
+/// <p>Starts execution of a Step Functions state machine.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepFunctionsAction {
+    /// <p>(Optional) A name will be given to the state machine execution consisting of this prefix followed by a UUID. Step Functions automatically creates a unique name for each state machine execution if one is not provided.</p>
+    #[serde(rename = "executionNamePrefix")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub execution_name_prefix: Option<String>,
     /// <p>The ARN of the role that grants IoT permission to start execution of a state machine ("Action":"states:StartExecution").</p>
@@ -6006,6 +15931,38 @@ pub struct StopThingRegistrationTaskRequest {
     pub task_id: String,
 }
 
+impl StopThingRegistrationTaskRequest {
+    /// Returns a builder for `StopThingRegistrationTaskRequest`, checking its required members when built.
+    pub fn builder() -> StopThingRegistrationTaskRequestBuilder {
+        StopThingRegistrationTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopThingRegistrationTaskRequest`](struct.StopThingRegistrationTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct StopThingRegistrationTaskRequestBuilder {
+    task_id: Option<String>,
+}
+
+impl StopThingRegistrationTaskRequestBuilder {
+    /// Sets the [`task_id`](struct.StopThingRegistrationTaskRequest.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopThingRegistrationTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopThingRegistrationTaskRequest, ::rusoto_core::BuildError> {
+        Ok(StopThingRegistrationTaskRequest {
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopThingRegistrationTaskResponse {}
@@ -6042,8 +15999,9 @@ pub struct StreamFile {
 pub struct StreamInfo {
     /// <p>The date when the stream was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The description of the stream.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6054,8 +16012,9 @@ pub struct StreamInfo {
     pub files: Option<Vec<StreamFile>>,
     /// <p>The date when the stream was last updated.</p>
     #[serde(rename = "lastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>An IAM role AWS IoT assumes to access your S3 files.</p>
     #[serde(rename = "roleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6119,10 +16078,128 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
 
+/// <p>Specifies whether the job will continue to run (CONTINUOUS), or will be complete after all those things specified as targets have completed the job (SNAPSHOT). If continuous, the job may also be run on a thing when a change is detected in a target. For example, a job will run on a thing when the thing is added to a target group, even after the job was completed by all things originally in the group.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TargetSelection {
+    /// <p><code>CONTINUOUS</code></p>
+    Continuous,
+    /// <p><code>SNAPSHOT</code></p>
+    Snapshot,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TargetSelection {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetSelection::Continuous => "CONTINUOUS",
+            TargetSelection::Snapshot => "SNAPSHOT",
+            TargetSelection::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TargetSelection {
+    fn from(value: &'a str) -> TargetSelection {
+        match value {
+            "CONTINUOUS" => TargetSelection::Continuous,
+            "SNAPSHOT" => TargetSelection::Snapshot,
+            _ => TargetSelection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetSelection {
+    fn from(value: String) -> TargetSelection {
+        TargetSelection::from(value.as_str())
+    }
+}
+
+impl From<TargetSelection> for String {
+    fn from(value: TargetSelection) -> String {
+        match value {
+            TargetSelection::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TargetSelection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TargetSelection {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TargetSelection {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetSelection {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TargetSelection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TargetSelection::from(value))
+    }
+}
+
 /// <p>Statistics for the checks performed during the audit.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -6210,6 +16287,78 @@ pub struct TestAuthorizationRequest {
     pub principal: Option<String>,
 }
 
+impl TestAuthorizationRequest {
+    /// Returns a builder for `TestAuthorizationRequest`, checking its required members when built.
+    pub fn builder() -> TestAuthorizationRequestBuilder {
+        TestAuthorizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TestAuthorizationRequest`](struct.TestAuthorizationRequest.html).
+#[derive(Default, Debug)]
+pub struct TestAuthorizationRequestBuilder {
+    auth_infos: Option<Vec<AuthInfo>>,
+    client_id: Option<String>,
+    cognito_identity_pool_id: Option<String>,
+    policy_names_to_add: Option<Vec<String>>,
+    policy_names_to_skip: Option<Vec<String>>,
+    principal: Option<String>,
+}
+
+impl TestAuthorizationRequestBuilder {
+    /// Sets the [`auth_infos`](struct.TestAuthorizationRequest.html#structfield.auth_infos) member.
+    pub fn auth_infos<T: Into<Vec<AuthInfo>>>(mut self, value: T) -> Self {
+        self.auth_infos = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_id`](struct.TestAuthorizationRequest.html#structfield.client_id) member.
+    pub fn client_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cognito_identity_pool_id`](struct.TestAuthorizationRequest.html#structfield.cognito_identity_pool_id) member.
+    pub fn cognito_identity_pool_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cognito_identity_pool_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_names_to_add`](struct.TestAuthorizationRequest.html#structfield.policy_names_to_add) member.
+    pub fn policy_names_to_add<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_names_to_add = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_names_to_skip`](struct.TestAuthorizationRequest.html#structfield.policy_names_to_skip) member.
+    pub fn policy_names_to_skip<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.policy_names_to_skip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.TestAuthorizationRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestAuthorizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TestAuthorizationRequest, ::rusoto_core::BuildError> {
+        Ok(TestAuthorizationRequest {
+            auth_infos: self
+                .auth_infos
+                .ok_or(::rusoto_core::BuildError::missing("auth_infos"))?,
+            client_id: self.client_id,
+            cognito_identity_pool_id: self.cognito_identity_pool_id,
+            policy_names_to_add: self.policy_names_to_add,
+            policy_names_to_skip: self.policy_names_to_skip,
+            principal: self.principal,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TestAuthorizationResponse {
@@ -6232,6 +16381,58 @@ pub struct TestInvokeAuthorizerRequest {
     pub token_signature: String,
 }
 
+impl TestInvokeAuthorizerRequest {
+    /// Returns a builder for `TestInvokeAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> TestInvokeAuthorizerRequestBuilder {
+        TestInvokeAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TestInvokeAuthorizerRequest`](struct.TestInvokeAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct TestInvokeAuthorizerRequestBuilder {
+    authorizer_name: Option<String>,
+    token: Option<String>,
+    token_signature: Option<String>,
+}
+
+impl TestInvokeAuthorizerRequestBuilder {
+    /// Sets the [`authorizer_name`](struct.TestInvokeAuthorizerRequest.html#structfield.authorizer_name) member.
+    pub fn authorizer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token`](struct.TestInvokeAuthorizerRequest.html#structfield.token) member.
+    pub fn token<T: Into<String>>(mut self, value: T) -> Self {
+        self.token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token_signature`](struct.TestInvokeAuthorizerRequest.html#structfield.token_signature) member.
+    pub fn token_signature<T: Into<String>>(mut self, value: T) -> Self {
+        self.token_signature = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestInvokeAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TestInvokeAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(TestInvokeAuthorizerRequest {
+            authorizer_name: self
+                .authorizer_name
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_name"))?,
+            token: self
+                .token
+                .ok_or(::rusoto_core::BuildError::missing("token"))?,
+            token_signature: self
+                .token_signature
+                .ok_or(::rusoto_core::BuildError::missing("token_signature"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TestInvokeAuthorizerResponse {
@@ -6297,6 +16498,86 @@ pub struct ThingConnectivity {
     pub timestamp: Option<i64>,
 }
 
+/// <p><p>Thing connectivity indexing mode. Valid values are: </p> <ul> <li> <p>STATUS – Your thing index contains connectivity status. To enable thing connectivity indexing, thingIndexMode must not be set to OFF.</p> </li> <li> <p>OFF - Thing connectivity status indexing is disabled.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ThingConnectivityIndexingMode {
+    /// <p><code>OFF</code></p>
+    Off,
+    /// <p><code>STATUS</code></p>
+    Status,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ThingConnectivityIndexingMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThingConnectivityIndexingMode::Off => "OFF",
+            ThingConnectivityIndexingMode::Status => "STATUS",
+            ThingConnectivityIndexingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ThingConnectivityIndexingMode {
+    fn from(value: &'a str) -> ThingConnectivityIndexingMode {
+        match value {
+            "OFF" => ThingConnectivityIndexingMode::Off,
+            "STATUS" => ThingConnectivityIndexingMode::Status,
+            _ => ThingConnectivityIndexingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThingConnectivityIndexingMode {
+    fn from(value: String) -> ThingConnectivityIndexingMode {
+        ThingConnectivityIndexingMode::from(value.as_str())
+    }
+}
+
+impl From<ThingConnectivityIndexingMode> for String {
+    fn from(value: ThingConnectivityIndexingMode) -> String {
+        match value {
+            ThingConnectivityIndexingMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ThingConnectivityIndexingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThingConnectivityIndexingMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThingConnectivityIndexingMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThingConnectivityIndexingMode {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ThingConnectivityIndexingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ThingConnectivityIndexingMode::from(value))
+    }
+}
+
 /// <p>The thing search index document.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -6358,11 +16639,89 @@ pub struct ThingGroupDocument {
 }
 
 /// <p>Thing group indexing configuration.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ThingGroupIndexingConfiguration {
     /// <p>Thing group indexing mode.</p>
     #[serde(rename = "thingGroupIndexingMode")]
-    pub thing_group_indexing_mode: String,
+    pub thing_group_indexing_mode: ThingGroupIndexingMode,
+}
+
+/// <p>Thing group indexing mode.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ThingGroupIndexingMode {
+    /// <p><code>OFF</code></p>
+    Off,
+    /// <p><code>ON</code></p>
+    On,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ThingGroupIndexingMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThingGroupIndexingMode::Off => "OFF",
+            ThingGroupIndexingMode::On => "ON",
+            ThingGroupIndexingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ThingGroupIndexingMode {
+    fn from(value: &'a str) -> ThingGroupIndexingMode {
+        match value {
+            "OFF" => ThingGroupIndexingMode::Off,
+            "ON" => ThingGroupIndexingMode::On,
+            _ => ThingGroupIndexingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThingGroupIndexingMode {
+    fn from(value: String) -> ThingGroupIndexingMode {
+        ThingGroupIndexingMode::from(value.as_str())
+    }
+}
+
+impl From<ThingGroupIndexingMode> for String {
+    fn from(value: ThingGroupIndexingMode) -> String {
+        match value {
+            ThingGroupIndexingMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ThingGroupIndexingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThingGroupIndexingMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThingGroupIndexingMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThingGroupIndexingMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ThingGroupIndexingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ThingGroupIndexingMode::from(value))
+    }
 }
 
 /// <p>Thing group metadata.</p>
@@ -6371,8 +16730,9 @@ pub struct ThingGroupIndexingConfiguration {
 pub struct ThingGroupMetadata {
     /// <p>The UNIX timestamp of when the thing group was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The parent thing group name.</p>
     #[serde(rename = "parentGroupName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6397,15 +16757,97 @@ pub struct ThingGroupProperties {
 }
 
 /// <p>The thing indexing configuration. For more information, see <a href="https://docs.aws.amazon.com/iot/latest/developerguide/managing-index.html">Managing Thing Indexing</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ThingIndexingConfiguration {
     /// <p><p>Thing connectivity indexing mode. Valid values are: </p> <ul> <li> <p>STATUS – Your thing index contains connectivity status. To enable thing connectivity indexing, thingIndexMode must not be set to OFF.</p> </li> <li> <p>OFF - Thing connectivity status indexing is disabled.</p> </li> </ul></p>
     #[serde(rename = "thingConnectivityIndexingMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub thing_connectivity_indexing_mode: Option<String>,
+    pub thing_connectivity_indexing_mode: Option<ThingConnectivityIndexingMode>,
     /// <p><p>Thing indexing mode. Valid values are:</p> <ul> <li> <p>REGISTRY – Your thing index contains registry data only.</p> </li> <li> <p>REGISTRY<em>AND</em>SHADOW - Your thing index contains registry and shadow data.</p> </li> <li> <p>OFF - Thing indexing is disabled.</p> </li> </ul></p>
     #[serde(rename = "thingIndexingMode")]
-    pub thing_indexing_mode: String,
+    pub thing_indexing_mode: ThingIndexingMode,
+}
+
+/// <p><p>Thing indexing mode. Valid values are:</p> <ul> <li> <p>REGISTRY – Your thing index contains registry data only.</p> </li> <li> <p>REGISTRY<em>AND</em>SHADOW - Your thing index contains registry and shadow data.</p> </li> <li> <p>OFF - Thing indexing is disabled.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ThingIndexingMode {
+    /// <p><code>OFF</code></p>
+    Off,
+    /// <p><code>REGISTRY</code></p>
+    Registry,
+    /// <p><code>REGISTRY_AND_SHADOW</code></p>
+    RegistryAndShadow,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ThingIndexingMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ThingIndexingMode::Off => "OFF",
+            ThingIndexingMode::Registry => "REGISTRY",
+            ThingIndexingMode::RegistryAndShadow => "REGISTRY_AND_SHADOW",
+            ThingIndexingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ThingIndexingMode {
+    fn from(value: &'a str) -> ThingIndexingMode {
+        match value {
+            "OFF" => ThingIndexingMode::Off,
+            "REGISTRY" => ThingIndexingMode::Registry,
+            "REGISTRY_AND_SHADOW" => ThingIndexingMode::RegistryAndShadow,
+            _ => ThingIndexingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ThingIndexingMode {
+    fn from(value: String) -> ThingIndexingMode {
+        ThingIndexingMode::from(value.as_str())
+    }
+}
+
+impl From<ThingIndexingMode> for String {
+    fn from(value: ThingIndexingMode) -> String {
+        match value {
+            ThingIndexingMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ThingIndexingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ThingIndexingMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ThingIndexingMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ThingIndexingMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ThingIndexingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ThingIndexingMode::from(value))
+    }
 }
 
 /// <p>The definition of the thing type, including thing type name and description.</p>
@@ -6436,16 +16878,18 @@ pub struct ThingTypeDefinition {
 pub struct ThingTypeMetadata {
     /// <p>The date and time when the thing type was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>Whether the thing type is deprecated. If <b>true</b>, no new things could be associated with this type.</p>
     #[serde(rename = "deprecated")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deprecated: Option<bool>,
     /// <p>The date and time when the thing type was deprecated.</p>
     #[serde(rename = "deprecationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deprecation_date: Option<f64>,
+    pub deprecation_date: Option<EpochTimestamp>,
 }
 
 /// <p>The ThingTypeProperties contains information about the thing type including: a thing type description, and a list of searchable thing attribute names.</p>
@@ -6484,8 +16928,9 @@ pub struct TopicRule {
     pub aws_iot_sql_version: Option<String>,
     /// <p>The date and time the rule was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The description of the rule.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6514,8 +16959,9 @@ pub struct TopicRule {
 pub struct TopicRuleListItem {
     /// <p>The date and time the rule was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The rule ARN.</p>
     #[serde(rename = "ruleArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6576,6 +17022,56 @@ pub struct TransferCertificateRequest {
     pub transfer_message: Option<String>,
 }
 
+impl TransferCertificateRequest {
+    /// Returns a builder for `TransferCertificateRequest`, checking its required members when built.
+    pub fn builder() -> TransferCertificateRequestBuilder {
+        TransferCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TransferCertificateRequest`](struct.TransferCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct TransferCertificateRequestBuilder {
+    certificate_id: Option<String>,
+    target_aws_account: Option<String>,
+    transfer_message: Option<String>,
+}
+
+impl TransferCertificateRequestBuilder {
+    /// Sets the [`certificate_id`](struct.TransferCertificateRequest.html#structfield.certificate_id) member.
+    pub fn certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_aws_account`](struct.TransferCertificateRequest.html#structfield.target_aws_account) member.
+    pub fn target_aws_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_aws_account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transfer_message`](struct.TransferCertificateRequest.html#structfield.transfer_message) member.
+    pub fn transfer_message<T: Into<String>>(mut self, value: T) -> Self {
+        self.transfer_message = Some(value.into());
+        self
+    }
+
+    /// Builds the `TransferCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TransferCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(TransferCertificateRequest {
+            certificate_id: self
+                .certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("certificate_id"))?,
+            target_aws_account: self
+                .target_aws_account
+                .ok_or(::rusoto_core::BuildError::missing("target_aws_account"))?,
+            transfer_message: self.transfer_message,
+        })
+    }
+}
+
 /// <p>The output from the TransferCertificate operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -6592,20 +17088,23 @@ pub struct TransferCertificateResponse {
 pub struct TransferData {
     /// <p>The date the transfer was accepted.</p>
     #[serde(rename = "acceptDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub accept_date: Option<f64>,
+    pub accept_date: Option<EpochTimestamp>,
     /// <p>The date the transfer was rejected.</p>
     #[serde(rename = "rejectDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reject_date: Option<f64>,
+    pub reject_date: Option<EpochTimestamp>,
     /// <p>The reason why the transfer was rejected.</p>
     #[serde(rename = "rejectReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reject_reason: Option<String>,
     /// <p>The date the transfer took place.</p>
     #[serde(rename = "transferDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub transfer_date: Option<f64>,
+    pub transfer_date: Option<EpochTimestamp>,
     /// <p>The transfer message.</p>
     #[serde(rename = "transferMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6622,7 +17121,47 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
 
@@ -6644,6 +17183,65 @@ pub struct UpdateAccountAuditConfigurationRequest {
     pub role_arn: Option<String>,
 }
 
+impl UpdateAccountAuditConfigurationRequest {
+    /// Returns a builder for `UpdateAccountAuditConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAccountAuditConfigurationRequestBuilder {
+        UpdateAccountAuditConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAccountAuditConfigurationRequest`](struct.UpdateAccountAuditConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAccountAuditConfigurationRequestBuilder {
+    audit_check_configurations:
+        Option<::std::collections::HashMap<String, AuditCheckConfiguration>>,
+    audit_notification_target_configurations:
+        Option<::std::collections::HashMap<String, AuditNotificationTarget>>,
+    role_arn: Option<String>,
+}
+
+impl UpdateAccountAuditConfigurationRequestBuilder {
+    /// Sets the [`audit_check_configurations`](struct.UpdateAccountAuditConfigurationRequest.html#structfield.audit_check_configurations) member.
+    pub fn audit_check_configurations<
+        T: Into<::std::collections::HashMap<String, AuditCheckConfiguration>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.audit_check_configurations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`audit_notification_target_configurations`](struct.UpdateAccountAuditConfigurationRequest.html#structfield.audit_notification_target_configurations) member.
+    pub fn audit_notification_target_configurations<
+        T: Into<::std::collections::HashMap<String, AuditNotificationTarget>>,
+    >(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.audit_notification_target_configurations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.UpdateAccountAuditConfigurationRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAccountAuditConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAccountAuditConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateAccountAuditConfigurationRequest {
+            audit_check_configurations: self.audit_check_configurations,
+            audit_notification_target_configurations: self.audit_notification_target_configurations,
+            role_arn: self.role_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateAccountAuditConfigurationResponse {}
@@ -6660,7 +17258,7 @@ pub struct UpdateAuthorizerRequest {
     /// <p>The status of the update authorizer request.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AuthorizerStatus>,
     /// <p>The key used to extract the token from the HTTP headers. </p>
     #[serde(rename = "tokenKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6671,6 +17269,73 @@ pub struct UpdateAuthorizerRequest {
     pub token_signing_public_keys: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl UpdateAuthorizerRequest {
+    /// Returns a builder for `UpdateAuthorizerRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAuthorizerRequestBuilder {
+        UpdateAuthorizerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAuthorizerRequest`](struct.UpdateAuthorizerRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAuthorizerRequestBuilder {
+    authorizer_function_arn: Option<String>,
+    authorizer_name: Option<String>,
+    status: Option<AuthorizerStatus>,
+    token_key_name: Option<String>,
+    token_signing_public_keys: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl UpdateAuthorizerRequestBuilder {
+    /// Sets the [`authorizer_function_arn`](struct.UpdateAuthorizerRequest.html#structfield.authorizer_function_arn) member.
+    pub fn authorizer_function_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_function_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`authorizer_name`](struct.UpdateAuthorizerRequest.html#structfield.authorizer_name) member.
+    pub fn authorizer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.authorizer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.UpdateAuthorizerRequest.html#structfield.status) member.
+    pub fn status<T: Into<AuthorizerStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token_key_name`](struct.UpdateAuthorizerRequest.html#structfield.token_key_name) member.
+    pub fn token_key_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.token_key_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`token_signing_public_keys`](struct.UpdateAuthorizerRequest.html#structfield.token_signing_public_keys) member.
+    pub fn token_signing_public_keys<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.token_signing_public_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAuthorizerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateAuthorizerRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAuthorizerRequest {
+            authorizer_function_arn: self.authorizer_function_arn,
+            authorizer_name: self
+                .authorizer_name
+                .ok_or(::rusoto_core::BuildError::missing("authorizer_name"))?,
+            status: self.status,
+            token_key_name: self.token_key_name,
+            token_signing_public_keys: self.token_signing_public_keys,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateAuthorizerResponse {
@@ -6698,6 +17363,56 @@ pub struct UpdateBillingGroupRequest {
     pub expected_version: Option<i64>,
 }
 
+impl UpdateBillingGroupRequest {
+    /// Returns a builder for `UpdateBillingGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateBillingGroupRequestBuilder {
+        UpdateBillingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateBillingGroupRequest`](struct.UpdateBillingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateBillingGroupRequestBuilder {
+    billing_group_name: Option<String>,
+    billing_group_properties: Option<BillingGroupProperties>,
+    expected_version: Option<i64>,
+}
+
+impl UpdateBillingGroupRequestBuilder {
+    /// Sets the [`billing_group_name`](struct.UpdateBillingGroupRequest.html#structfield.billing_group_name) member.
+    pub fn billing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.billing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`billing_group_properties`](struct.UpdateBillingGroupRequest.html#structfield.billing_group_properties) member.
+    pub fn billing_group_properties<T: Into<BillingGroupProperties>>(mut self, value: T) -> Self {
+        self.billing_group_properties = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expected_version`](struct.UpdateBillingGroupRequest.html#structfield.expected_version) member.
+    pub fn expected_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expected_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateBillingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateBillingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateBillingGroupRequest {
+            billing_group_name: self
+                .billing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("billing_group_name"))?,
+            billing_group_properties: self.billing_group_properties.ok_or(
+                ::rusoto_core::BuildError::missing("billing_group_properties"),
+            )?,
+            expected_version: self.expected_version,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateBillingGroupResponse {
@@ -6708,11 +17423,11 @@ pub struct UpdateBillingGroupResponse {
 }
 
 /// <p>Parameters to define a mitigation action that changes the state of the CA certificate to inactive.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UpdateCACertificateParams {
     /// <p>The action that you want to apply to the CA cerrtificate. The only supported value is <code>DEACTIVATE</code>.</p>
     #[serde(rename = "action")]
-    pub action: String,
+    pub action: CACertificateUpdateAction,
 }
 
 /// <p>The input to the UpdateCACertificate operation.</p>
@@ -6724,11 +17439,11 @@ pub struct UpdateCACertificateRequest {
     /// <p>The new value for the auto registration status. Valid values are: "ENABLE" or "DISABLE".</p>
     #[serde(rename = "newAutoRegistrationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub new_auto_registration_status: Option<String>,
+    pub new_auto_registration_status: Option<AutoRegistrationStatus>,
     /// <p>The updated status of the CA certificate.</p> <p> <b>Note:</b> The status value REGISTER_INACTIVE is deprecated and should not be used.</p>
     #[serde(rename = "newStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub new_status: Option<String>,
+    pub new_status: Option<CACertificateStatus>,
     /// <p>Information about the registration configuration.</p>
     #[serde(rename = "registrationConfig")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6739,23 +17454,132 @@ pub struct UpdateCACertificateRequest {
     pub remove_auto_registration: Option<bool>,
 }
 
+impl UpdateCACertificateRequest {
+    /// Returns a builder for `UpdateCACertificateRequest`, checking its required members when built.
+    pub fn builder() -> UpdateCACertificateRequestBuilder {
+        UpdateCACertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateCACertificateRequest`](struct.UpdateCACertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateCACertificateRequestBuilder {
+    certificate_id: Option<String>,
+    new_auto_registration_status: Option<AutoRegistrationStatus>,
+    new_status: Option<CACertificateStatus>,
+    registration_config: Option<RegistrationConfig>,
+    remove_auto_registration: Option<bool>,
+}
+
+impl UpdateCACertificateRequestBuilder {
+    /// Sets the [`certificate_id`](struct.UpdateCACertificateRequest.html#structfield.certificate_id) member.
+    pub fn certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_auto_registration_status`](struct.UpdateCACertificateRequest.html#structfield.new_auto_registration_status) member.
+    pub fn new_auto_registration_status<T: Into<AutoRegistrationStatus>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.new_auto_registration_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_status`](struct.UpdateCACertificateRequest.html#structfield.new_status) member.
+    pub fn new_status<T: Into<CACertificateStatus>>(mut self, value: T) -> Self {
+        self.new_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`registration_config`](struct.UpdateCACertificateRequest.html#structfield.registration_config) member.
+    pub fn registration_config<T: Into<RegistrationConfig>>(mut self, value: T) -> Self {
+        self.registration_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remove_auto_registration`](struct.UpdateCACertificateRequest.html#structfield.remove_auto_registration) member.
+    pub fn remove_auto_registration<T: Into<bool>>(mut self, value: T) -> Self {
+        self.remove_auto_registration = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateCACertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateCACertificateRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateCACertificateRequest {
+            certificate_id: self
+                .certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("certificate_id"))?,
+            new_auto_registration_status: self.new_auto_registration_status,
+            new_status: self.new_status,
+            registration_config: self.registration_config,
+            remove_auto_registration: self.remove_auto_registration,
+        })
+    }
+}
+
 /// <p>The input for the UpdateCertificate operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateCertificateRequest {
     /// <p>The ID of the certificate. (The last part of the certificate ARN contains the certificate ID.)</p>
     #[serde(rename = "certificateId")]
     pub certificate_id: String,
     /// <p>The new status.</p> <p> <b>Note:</b> Setting the status to PENDING_TRANSFER will result in an exception being thrown. PENDING_TRANSFER is a status used internally by AWS IoT. It is not intended for developer use.</p> <p> <b>Note:</b> The status value REGISTER_INACTIVE is deprecated and should not be used.</p>
     #[serde(rename = "newStatus")]
-    pub new_status: String,
+    pub new_status: CertificateStatus,
+}
+
+impl UpdateCertificateRequest {
+    /// Returns a builder for `UpdateCertificateRequest`, checking its required members when built.
+    pub fn builder() -> UpdateCertificateRequestBuilder {
+        UpdateCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateCertificateRequest`](struct.UpdateCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateCertificateRequestBuilder {
+    certificate_id: Option<String>,
+    new_status: Option<CertificateStatus>,
+}
+
+impl UpdateCertificateRequestBuilder {
+    /// Sets the [`certificate_id`](struct.UpdateCertificateRequest.html#structfield.certificate_id) member.
+    pub fn certificate_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_status`](struct.UpdateCertificateRequest.html#structfield.new_status) member.
+    pub fn new_status<T: Into<CertificateStatus>>(mut self, value: T) -> Self {
+        self.new_status = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateCertificateRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateCertificateRequest {
+            certificate_id: self
+                .certificate_id
+                .ok_or(::rusoto_core::BuildError::missing("certificate_id"))?,
+            new_status: self
+                .new_status
+                .ok_or(::rusoto_core::BuildError::missing("new_status"))?,
+        })
+    }
 }
 
 /// <p>Parameters to define a mitigation action that changes the state of the device certificate to inactive.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UpdateDeviceCertificateParams {
     /// <p>The action that you want to apply to the device cerrtificate. The only supported value is <code>DEACTIVATE</code>.</p>
     #[serde(rename = "action")]
-    pub action: String,
+    pub action: DeviceCertificateUpdateAction,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -6784,6 +17608,80 @@ pub struct UpdateDynamicThingGroupRequest {
     pub thing_group_properties: ThingGroupProperties,
 }
 
+impl UpdateDynamicThingGroupRequest {
+    /// Returns a builder for `UpdateDynamicThingGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDynamicThingGroupRequestBuilder {
+        UpdateDynamicThingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDynamicThingGroupRequest`](struct.UpdateDynamicThingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDynamicThingGroupRequestBuilder {
+    expected_version: Option<i64>,
+    index_name: Option<String>,
+    query_string: Option<String>,
+    query_version: Option<String>,
+    thing_group_name: Option<String>,
+    thing_group_properties: Option<ThingGroupProperties>,
+}
+
+impl UpdateDynamicThingGroupRequestBuilder {
+    /// Sets the [`expected_version`](struct.UpdateDynamicThingGroupRequest.html#structfield.expected_version) member.
+    pub fn expected_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expected_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`index_name`](struct.UpdateDynamicThingGroupRequest.html#structfield.index_name) member.
+    pub fn index_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.index_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_string`](struct.UpdateDynamicThingGroupRequest.html#structfield.query_string) member.
+    pub fn query_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_version`](struct.UpdateDynamicThingGroupRequest.html#structfield.query_version) member.
+    pub fn query_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_name`](struct.UpdateDynamicThingGroupRequest.html#structfield.thing_group_name) member.
+    pub fn thing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_properties`](struct.UpdateDynamicThingGroupRequest.html#structfield.thing_group_properties) member.
+    pub fn thing_group_properties<T: Into<ThingGroupProperties>>(mut self, value: T) -> Self {
+        self.thing_group_properties = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDynamicThingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDynamicThingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDynamicThingGroupRequest {
+            expected_version: self.expected_version,
+            index_name: self.index_name,
+            query_string: self.query_string,
+            query_version: self.query_version,
+            thing_group_name: self
+                .thing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_group_name"))?,
+            thing_group_properties: self
+                .thing_group_properties
+                .ok_or(::rusoto_core::BuildError::missing("thing_group_properties"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDynamicThingGroupResponse {
@@ -6798,7 +17696,40 @@ pub struct UpdateEventConfigurationsRequest {
     /// <p>The new event configuration values.</p>
     #[serde(rename = "eventConfigurations")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_configurations: Option<::std::collections::HashMap<String, Configuration>>,
+    pub event_configurations: Option<::std::collections::HashMap<EventType, Configuration>>,
+}
+
+impl UpdateEventConfigurationsRequest {
+    /// Returns a builder for `UpdateEventConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateEventConfigurationsRequestBuilder {
+        UpdateEventConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateEventConfigurationsRequest`](struct.UpdateEventConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateEventConfigurationsRequestBuilder {
+    event_configurations: Option<::std::collections::HashMap<EventType, Configuration>>,
+}
+
+impl UpdateEventConfigurationsRequestBuilder {
+    /// Sets the [`event_configurations`](struct.UpdateEventConfigurationsRequest.html#structfield.event_configurations) member.
+    pub fn event_configurations<T: Into<::std::collections::HashMap<EventType, Configuration>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.event_configurations = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateEventConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateEventConfigurationsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateEventConfigurationsRequest {
+            event_configurations: self.event_configurations,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -6817,6 +17748,50 @@ pub struct UpdateIndexingConfigurationRequest {
     pub thing_indexing_configuration: Option<ThingIndexingConfiguration>,
 }
 
+impl UpdateIndexingConfigurationRequest {
+    /// Returns a builder for `UpdateIndexingConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateIndexingConfigurationRequestBuilder {
+        UpdateIndexingConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateIndexingConfigurationRequest`](struct.UpdateIndexingConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateIndexingConfigurationRequestBuilder {
+    thing_group_indexing_configuration: Option<ThingGroupIndexingConfiguration>,
+    thing_indexing_configuration: Option<ThingIndexingConfiguration>,
+}
+
+impl UpdateIndexingConfigurationRequestBuilder {
+    /// Sets the [`thing_group_indexing_configuration`](struct.UpdateIndexingConfigurationRequest.html#structfield.thing_group_indexing_configuration) member.
+    pub fn thing_group_indexing_configuration<T: Into<ThingGroupIndexingConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.thing_group_indexing_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_indexing_configuration`](struct.UpdateIndexingConfigurationRequest.html#structfield.thing_indexing_configuration) member.
+    pub fn thing_indexing_configuration<T: Into<ThingIndexingConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.thing_indexing_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateIndexingConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateIndexingConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateIndexingConfigurationRequest {
+            thing_group_indexing_configuration: self.thing_group_indexing_configuration,
+            thing_indexing_configuration: self.thing_indexing_configuration,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateIndexingConfigurationResponse {}
@@ -6848,6 +17823,79 @@ pub struct UpdateJobRequest {
     pub timeout_config: Option<TimeoutConfig>,
 }
 
+impl UpdateJobRequest {
+    /// Returns a builder for `UpdateJobRequest`, checking its required members when built.
+    pub fn builder() -> UpdateJobRequestBuilder {
+        UpdateJobRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateJobRequest`](struct.UpdateJobRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateJobRequestBuilder {
+    abort_config: Option<AbortConfig>,
+    description: Option<String>,
+    job_executions_rollout_config: Option<JobExecutionsRolloutConfig>,
+    job_id: Option<String>,
+    presigned_url_config: Option<PresignedUrlConfig>,
+    timeout_config: Option<TimeoutConfig>,
+}
+
+impl UpdateJobRequestBuilder {
+    /// Sets the [`abort_config`](struct.UpdateJobRequest.html#structfield.abort_config) member.
+    pub fn abort_config<T: Into<AbortConfig>>(mut self, value: T) -> Self {
+        self.abort_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateJobRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_executions_rollout_config`](struct.UpdateJobRequest.html#structfield.job_executions_rollout_config) member.
+    pub fn job_executions_rollout_config<T: Into<JobExecutionsRolloutConfig>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.job_executions_rollout_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_id`](struct.UpdateJobRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`presigned_url_config`](struct.UpdateJobRequest.html#structfield.presigned_url_config) member.
+    pub fn presigned_url_config<T: Into<PresignedUrlConfig>>(mut self, value: T) -> Self {
+        self.presigned_url_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout_config`](struct.UpdateJobRequest.html#structfield.timeout_config) member.
+    pub fn timeout_config<T: Into<TimeoutConfig>>(mut self, value: T) -> Self {
+        self.timeout_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateJobRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateJobRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateJobRequest {
+            abort_config: self.abort_config,
+            description: self.description,
+            job_executions_rollout_config: self.job_executions_rollout_config,
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            presigned_url_config: self.presigned_url_config,
+            timeout_config: self.timeout_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateMitigationActionRequest {
     /// <p>The friendly name for the mitigation action. You can't change the name by using <code>UpdateMitigationAction</code>. Instead, you must delete and re-create the mitigation action with the new name.</p>
@@ -6863,6 +17911,54 @@ pub struct UpdateMitigationActionRequest {
     pub role_arn: Option<String>,
 }
 
+impl UpdateMitigationActionRequest {
+    /// Returns a builder for `UpdateMitigationActionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateMitigationActionRequestBuilder {
+        UpdateMitigationActionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateMitigationActionRequest`](struct.UpdateMitigationActionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateMitigationActionRequestBuilder {
+    action_name: Option<String>,
+    action_params: Option<MitigationActionParams>,
+    role_arn: Option<String>,
+}
+
+impl UpdateMitigationActionRequestBuilder {
+    /// Sets the [`action_name`](struct.UpdateMitigationActionRequest.html#structfield.action_name) member.
+    pub fn action_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.action_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`action_params`](struct.UpdateMitigationActionRequest.html#structfield.action_params) member.
+    pub fn action_params<T: Into<MitigationActionParams>>(mut self, value: T) -> Self {
+        self.action_params = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.UpdateMitigationActionRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateMitigationActionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateMitigationActionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateMitigationActionRequest {
+            action_name: self
+                .action_name
+                .ok_or(::rusoto_core::BuildError::missing("action_name"))?,
+            action_params: self.action_params,
+            role_arn: self.role_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateMitigationActionResponse {
@@ -6891,6 +17987,52 @@ pub struct UpdateRoleAliasRequest {
     pub role_arn: Option<String>,
 }
 
+impl UpdateRoleAliasRequest {
+    /// Returns a builder for `UpdateRoleAliasRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRoleAliasRequestBuilder {
+        UpdateRoleAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRoleAliasRequest`](struct.UpdateRoleAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRoleAliasRequestBuilder {
+    credential_duration_seconds: Option<i64>,
+    role_alias: Option<String>,
+    role_arn: Option<String>,
+}
+
+impl UpdateRoleAliasRequestBuilder {
+    /// Sets the [`credential_duration_seconds`](struct.UpdateRoleAliasRequest.html#structfield.credential_duration_seconds) member.
+    pub fn credential_duration_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.credential_duration_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_alias`](struct.UpdateRoleAliasRequest.html#structfield.role_alias) member.
+    pub fn role_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.UpdateRoleAliasRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRoleAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateRoleAliasRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRoleAliasRequest {
+            credential_duration_seconds: self.credential_duration_seconds,
+            role_alias: self
+                .role_alias
+                .ok_or(::rusoto_core::BuildError::missing("role_alias"))?,
+            role_arn: self.role_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRoleAliasResponse {
@@ -6913,11 +18055,11 @@ pub struct UpdateScheduledAuditRequest {
     /// <p>The day of the week on which the scheduled audit takes place. Can be one of "SUN", "MON", "TUE", "WED", "THU", "FRI", or "SAT". This field is required if the "frequency" parameter is set to "WEEKLY" or "BIWEEKLY".</p>
     #[serde(rename = "dayOfWeek")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub day_of_week: Option<String>,
+    pub day_of_week: Option<DayOfWeek>,
     /// <p>How often the scheduled audit takes place. Can be one of "DAILY", "WEEKLY", "BIWEEKLY", or "MONTHLY". The start time of each audit is determined by the system.</p>
     #[serde(rename = "frequency")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<String>,
+    pub frequency: Option<AuditFrequency>,
     /// <p>The name of the scheduled audit. (Max. 128 chars)</p>
     #[serde(rename = "scheduledAuditName")]
     pub scheduled_audit_name: String,
@@ -6927,6 +18069,70 @@ pub struct UpdateScheduledAuditRequest {
     pub target_check_names: Option<Vec<String>>,
 }
 
+impl UpdateScheduledAuditRequest {
+    /// Returns a builder for `UpdateScheduledAuditRequest`, checking its required members when built.
+    pub fn builder() -> UpdateScheduledAuditRequestBuilder {
+        UpdateScheduledAuditRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateScheduledAuditRequest`](struct.UpdateScheduledAuditRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateScheduledAuditRequestBuilder {
+    day_of_month: Option<String>,
+    day_of_week: Option<DayOfWeek>,
+    frequency: Option<AuditFrequency>,
+    scheduled_audit_name: Option<String>,
+    target_check_names: Option<Vec<String>>,
+}
+
+impl UpdateScheduledAuditRequestBuilder {
+    /// Sets the [`day_of_month`](struct.UpdateScheduledAuditRequest.html#structfield.day_of_month) member.
+    pub fn day_of_month<T: Into<String>>(mut self, value: T) -> Self {
+        self.day_of_month = Some(value.into());
+        self
+    }
+
+    /// Sets the [`day_of_week`](struct.UpdateScheduledAuditRequest.html#structfield.day_of_week) member.
+    pub fn day_of_week<T: Into<DayOfWeek>>(mut self, value: T) -> Self {
+        self.day_of_week = Some(value.into());
+        self
+    }
+
+    /// Sets the [`frequency`](struct.UpdateScheduledAuditRequest.html#structfield.frequency) member.
+    pub fn frequency<T: Into<AuditFrequency>>(mut self, value: T) -> Self {
+        self.frequency = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduled_audit_name`](struct.UpdateScheduledAuditRequest.html#structfield.scheduled_audit_name) member.
+    pub fn scheduled_audit_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.scheduled_audit_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_check_names`](struct.UpdateScheduledAuditRequest.html#structfield.target_check_names) member.
+    pub fn target_check_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.target_check_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateScheduledAuditRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateScheduledAuditRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateScheduledAuditRequest {
+            day_of_month: self.day_of_month,
+            day_of_week: self.day_of_week,
+            frequency: self.frequency,
+            scheduled_audit_name: self
+                .scheduled_audit_name
+                .ok_or(::rusoto_core::BuildError::missing("scheduled_audit_name"))?,
+            target_check_names: self.target_check_names,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateScheduledAuditResponse {
@@ -6945,7 +18151,7 @@ pub struct UpdateSecurityProfileRequest {
     /// <p>Where the alerts are sent. (Alerts are always sent to the console.)</p>
     #[serde(rename = "alertTargets")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alert_targets: Option<::std::collections::HashMap<String, AlertTarget>>,
+    pub alert_targets: Option<::std::collections::HashMap<AlertTargetType, AlertTarget>>,
     /// <p>Specifies the behaviors that, when violated by a device (thing), cause an alert.</p>
     #[serde(rename = "behaviors")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6975,6 +18181,105 @@ pub struct UpdateSecurityProfileRequest {
     pub security_profile_name: String,
 }
 
+impl UpdateSecurityProfileRequest {
+    /// Returns a builder for `UpdateSecurityProfileRequest`, checking its required members when built.
+    pub fn builder() -> UpdateSecurityProfileRequestBuilder {
+        UpdateSecurityProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateSecurityProfileRequest`](struct.UpdateSecurityProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateSecurityProfileRequestBuilder {
+    additional_metrics_to_retain: Option<Vec<String>>,
+    alert_targets: Option<::std::collections::HashMap<AlertTargetType, AlertTarget>>,
+    behaviors: Option<Vec<Behavior>>,
+    delete_additional_metrics_to_retain: Option<bool>,
+    delete_alert_targets: Option<bool>,
+    delete_behaviors: Option<bool>,
+    expected_version: Option<i64>,
+    security_profile_description: Option<String>,
+    security_profile_name: Option<String>,
+}
+
+impl UpdateSecurityProfileRequestBuilder {
+    /// Sets the [`additional_metrics_to_retain`](struct.UpdateSecurityProfileRequest.html#structfield.additional_metrics_to_retain) member.
+    pub fn additional_metrics_to_retain<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.additional_metrics_to_retain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`alert_targets`](struct.UpdateSecurityProfileRequest.html#structfield.alert_targets) member.
+    pub fn alert_targets<T: Into<::std::collections::HashMap<AlertTargetType, AlertTarget>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.alert_targets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`behaviors`](struct.UpdateSecurityProfileRequest.html#structfield.behaviors) member.
+    pub fn behaviors<T: Into<Vec<Behavior>>>(mut self, value: T) -> Self {
+        self.behaviors = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delete_additional_metrics_to_retain`](struct.UpdateSecurityProfileRequest.html#structfield.delete_additional_metrics_to_retain) member.
+    pub fn delete_additional_metrics_to_retain<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_additional_metrics_to_retain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delete_alert_targets`](struct.UpdateSecurityProfileRequest.html#structfield.delete_alert_targets) member.
+    pub fn delete_alert_targets<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_alert_targets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delete_behaviors`](struct.UpdateSecurityProfileRequest.html#structfield.delete_behaviors) member.
+    pub fn delete_behaviors<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_behaviors = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expected_version`](struct.UpdateSecurityProfileRequest.html#structfield.expected_version) member.
+    pub fn expected_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expected_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_description`](struct.UpdateSecurityProfileRequest.html#structfield.security_profile_description) member.
+    pub fn security_profile_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_profile_name`](struct.UpdateSecurityProfileRequest.html#structfield.security_profile_name) member.
+    pub fn security_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.security_profile_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateSecurityProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateSecurityProfileRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateSecurityProfileRequest {
+            additional_metrics_to_retain: self.additional_metrics_to_retain,
+            alert_targets: self.alert_targets,
+            behaviors: self.behaviors,
+            delete_additional_metrics_to_retain: self.delete_additional_metrics_to_retain,
+            delete_alert_targets: self.delete_alert_targets,
+            delete_behaviors: self.delete_behaviors,
+            expected_version: self.expected_version,
+            security_profile_description: self.security_profile_description,
+            security_profile_name: self
+                .security_profile_name
+                .ok_or(::rusoto_core::BuildError::missing("security_profile_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateSecurityProfileResponse {
@@ -6985,19 +18290,21 @@ pub struct UpdateSecurityProfileResponse {
     /// <p>Where the alerts are sent. (Alerts are always sent to the console.)</p>
     #[serde(rename = "alertTargets")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub alert_targets: Option<::std::collections::HashMap<String, AlertTarget>>,
+    pub alert_targets: Option<::std::collections::HashMap<AlertTargetType, AlertTarget>>,
     /// <p>Specifies the behaviors that, when violated by a device (thing), cause an alert.</p>
     #[serde(rename = "behaviors")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub behaviors: Option<Vec<Behavior>>,
     /// <p>The time the security profile was created.</p>
     #[serde(rename = "creationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The time the security profile was last modified.</p>
     #[serde(rename = "lastModifiedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified_date: Option<f64>,
+    pub last_modified_date: Option<EpochTimestamp>,
     /// <p>The ARN of the security profile that was updated.</p>
     #[serde(rename = "securityProfileArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -7035,6 +18342,60 @@ pub struct UpdateStreamRequest {
     pub stream_id: String,
 }
 
+impl UpdateStreamRequest {
+    /// Returns a builder for `UpdateStreamRequest`, checking its required members when built.
+    pub fn builder() -> UpdateStreamRequestBuilder {
+        UpdateStreamRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateStreamRequest`](struct.UpdateStreamRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateStreamRequestBuilder {
+    description: Option<String>,
+    files: Option<Vec<StreamFile>>,
+    role_arn: Option<String>,
+    stream_id: Option<String>,
+}
+
+impl UpdateStreamRequestBuilder {
+    /// Sets the [`description`](struct.UpdateStreamRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`files`](struct.UpdateStreamRequest.html#structfield.files) member.
+    pub fn files<T: Into<Vec<StreamFile>>>(mut self, value: T) -> Self {
+        self.files = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.UpdateStreamRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_id`](struct.UpdateStreamRequest.html#structfield.stream_id) member.
+    pub fn stream_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateStreamRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateStreamRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateStreamRequest {
+            description: self.description,
+            files: self.files,
+            role_arn: self.role_arn,
+            stream_id: self
+                .stream_id
+                .ok_or(::rusoto_core::BuildError::missing("stream_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateStreamResponse {
@@ -7070,10 +18431,60 @@ pub struct UpdateThingGroupRequest {
     pub thing_group_properties: ThingGroupProperties,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct UpdateThingGroupResponse {
-    /// <p>The version of the updated thing group.</p>
+impl UpdateThingGroupRequest {
+    /// Returns a builder for `UpdateThingGroupRequest`, checking its required members when built.
+    pub fn builder() -> UpdateThingGroupRequestBuilder {
+        UpdateThingGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateThingGroupRequest`](struct.UpdateThingGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateThingGroupRequestBuilder {
+    expected_version: Option<i64>,
+    thing_group_name: Option<String>,
+    thing_group_properties: Option<ThingGroupProperties>,
+}
+
+impl UpdateThingGroupRequestBuilder {
+    /// Sets the [`expected_version`](struct.UpdateThingGroupRequest.html#structfield.expected_version) member.
+    pub fn expected_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expected_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_name`](struct.UpdateThingGroupRequest.html#structfield.thing_group_name) member.
+    pub fn thing_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_group_properties`](struct.UpdateThingGroupRequest.html#structfield.thing_group_properties) member.
+    pub fn thing_group_properties<T: Into<ThingGroupProperties>>(mut self, value: T) -> Self {
+        self.thing_group_properties = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateThingGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateThingGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateThingGroupRequest {
+            expected_version: self.expected_version,
+            thing_group_name: self
+                .thing_group_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_group_name"))?,
+            thing_group_properties: self
+                .thing_group_properties
+                .ok_or(::rusoto_core::BuildError::missing("thing_group_properties"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct UpdateThingGroupResponse {
+    /// <p>The version of the updated thing group.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<i64>,
@@ -7099,6 +18510,60 @@ pub struct UpdateThingGroupsForThingRequest {
     pub thing_name: Option<String>,
 }
 
+impl UpdateThingGroupsForThingRequest {
+    /// Returns a builder for `UpdateThingGroupsForThingRequest`, checking its required members when built.
+    pub fn builder() -> UpdateThingGroupsForThingRequestBuilder {
+        UpdateThingGroupsForThingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateThingGroupsForThingRequest`](struct.UpdateThingGroupsForThingRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateThingGroupsForThingRequestBuilder {
+    override_dynamic_groups: Option<bool>,
+    thing_groups_to_add: Option<Vec<String>>,
+    thing_groups_to_remove: Option<Vec<String>>,
+    thing_name: Option<String>,
+}
+
+impl UpdateThingGroupsForThingRequestBuilder {
+    /// Sets the [`override_dynamic_groups`](struct.UpdateThingGroupsForThingRequest.html#structfield.override_dynamic_groups) member.
+    pub fn override_dynamic_groups<T: Into<bool>>(mut self, value: T) -> Self {
+        self.override_dynamic_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_groups_to_add`](struct.UpdateThingGroupsForThingRequest.html#structfield.thing_groups_to_add) member.
+    pub fn thing_groups_to_add<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.thing_groups_to_add = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_groups_to_remove`](struct.UpdateThingGroupsForThingRequest.html#structfield.thing_groups_to_remove) member.
+    pub fn thing_groups_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.thing_groups_to_remove = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.UpdateThingGroupsForThingRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateThingGroupsForThingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateThingGroupsForThingRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateThingGroupsForThingRequest {
+            override_dynamic_groups: self.override_dynamic_groups,
+            thing_groups_to_add: self.thing_groups_to_add,
+            thing_groups_to_remove: self.thing_groups_to_remove,
+            thing_name: self.thing_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateThingGroupsForThingResponse {}
@@ -7127,6 +18592,68 @@ pub struct UpdateThingRequest {
     pub thing_type_name: Option<String>,
 }
 
+impl UpdateThingRequest {
+    /// Returns a builder for `UpdateThingRequest`, checking its required members when built.
+    pub fn builder() -> UpdateThingRequestBuilder {
+        UpdateThingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateThingRequest`](struct.UpdateThingRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateThingRequestBuilder {
+    attribute_payload: Option<AttributePayload>,
+    expected_version: Option<i64>,
+    remove_thing_type: Option<bool>,
+    thing_name: Option<String>,
+    thing_type_name: Option<String>,
+}
+
+impl UpdateThingRequestBuilder {
+    /// Sets the [`attribute_payload`](struct.UpdateThingRequest.html#structfield.attribute_payload) member.
+    pub fn attribute_payload<T: Into<AttributePayload>>(mut self, value: T) -> Self {
+        self.attribute_payload = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expected_version`](struct.UpdateThingRequest.html#structfield.expected_version) member.
+    pub fn expected_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expected_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remove_thing_type`](struct.UpdateThingRequest.html#structfield.remove_thing_type) member.
+    pub fn remove_thing_type<T: Into<bool>>(mut self, value: T) -> Self {
+        self.remove_thing_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_name`](struct.UpdateThingRequest.html#structfield.thing_name) member.
+    pub fn thing_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`thing_type_name`](struct.UpdateThingRequest.html#structfield.thing_type_name) member.
+    pub fn thing_type_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.thing_type_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateThingRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateThingRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateThingRequest {
+            attribute_payload: self.attribute_payload,
+            expected_version: self.expected_version,
+            remove_thing_type: self.remove_thing_type,
+            thing_name: self
+                .thing_name
+                .ok_or(::rusoto_core::BuildError::missing("thing_name"))?,
+            thing_type_name: self.thing_type_name,
+        })
+    }
+}
+
 /// <p>The output from the UpdateThing operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -7139,6 +18666,39 @@ pub struct ValidateSecurityProfileBehaviorsRequest {
     pub behaviors: Vec<Behavior>,
 }
 
+impl ValidateSecurityProfileBehaviorsRequest {
+    /// Returns a builder for `ValidateSecurityProfileBehaviorsRequest`, checking its required members when built.
+    pub fn builder() -> ValidateSecurityProfileBehaviorsRequestBuilder {
+        ValidateSecurityProfileBehaviorsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ValidateSecurityProfileBehaviorsRequest`](struct.ValidateSecurityProfileBehaviorsRequest.html).
+#[derive(Default, Debug)]
+pub struct ValidateSecurityProfileBehaviorsRequestBuilder {
+    behaviors: Option<Vec<Behavior>>,
+}
+
+impl ValidateSecurityProfileBehaviorsRequestBuilder {
+    /// Sets the [`behaviors`](struct.ValidateSecurityProfileBehaviorsRequest.html#structfield.behaviors) member.
+    pub fn behaviors<T: Into<Vec<Behavior>>>(mut self, value: T) -> Self {
+        self.behaviors = Some(value.into());
+        self
+    }
+
+    /// Builds the `ValidateSecurityProfileBehaviorsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ValidateSecurityProfileBehaviorsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ValidateSecurityProfileBehaviorsRequest {
+            behaviors: self
+                .behaviors
+                .ok_or(::rusoto_core::BuildError::missing("behaviors"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ValidateSecurityProfileBehaviorsResponse {
@@ -7184,18 +18744,101 @@ pub struct ViolationEvent {
     pub thing_name: Option<String>,
     /// <p>The time the violation event occurred.</p>
     #[serde(rename = "violationEventTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub violation_event_time: Option<f64>,
+    pub violation_event_time: Option<EpochTimestamp>,
     /// <p>The type of violation event.</p>
     #[serde(rename = "violationEventType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub violation_event_type: Option<String>,
+    pub violation_event_type: Option<ViolationEventType>,
     /// <p>The ID of the violation event.</p>
     #[serde(rename = "violationId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub violation_id: Option<String>,
 }
 
+/// <p>The type of violation event.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ViolationEventType {
+    /// <p><code>in-alarm</code></p>
+    InAlarm,
+    /// <p><code>alarm-cleared</code></p>
+    AlarmCleared,
+    /// <p><code>alarm-invalidated</code></p>
+    AlarmInvalidated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ViolationEventType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ViolationEventType::InAlarm => "in-alarm",
+            ViolationEventType::AlarmCleared => "alarm-cleared",
+            ViolationEventType::AlarmInvalidated => "alarm-invalidated",
+            ViolationEventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ViolationEventType {
+    fn from(value: &'a str) -> ViolationEventType {
+        match value {
+            "in-alarm" => ViolationEventType::InAlarm,
+            "alarm-cleared" => ViolationEventType::AlarmCleared,
+            "alarm-invalidated" => ViolationEventType::AlarmInvalidated,
+            _ => ViolationEventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ViolationEventType {
+    fn from(value: String) -> ViolationEventType {
+        ViolationEventType::from(value.as_str())
+    }
+}
+
+impl From<ViolationEventType> for String {
+    fn from(value: ViolationEventType) -> String {
+        match value {
+            ViolationEventType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ViolationEventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ViolationEventType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ViolationEventType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ViolationEventType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ViolationEventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ViolationEventType::from(value))
+    }
+}
+
 /// Errors returned by AcceptCertificateTransfer
 #[derive(Debug, PartialEq)]
 pub enum AcceptCertificateTransferError {
@@ -18402,864 +30045,1933 @@ pub trait Iot {
     fn delete_account_audit_configuration(
         &self,
         input: DeleteAccountAuditConfigurationRequest,
-    ) -> RusotoFuture<DeleteAccountAuditConfigurationResponse, DeleteAccountAuditConfigurationError>;
-
-    /// <p>Deletes an authorizer.</p>
+    ) -> RusotoFuture<DeleteAccountAuditConfigurationResponse, DeleteAccountAuditConfigurationError>;
+
+    /// <p>Deletes an authorizer.</p>
+    fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> RusotoFuture<DeleteAuthorizerResponse, DeleteAuthorizerError>;
+
+    /// <p>Deletes the billing group.</p>
+    fn delete_billing_group(
+        &self,
+        input: DeleteBillingGroupRequest,
+    ) -> RusotoFuture<DeleteBillingGroupResponse, DeleteBillingGroupError>;
+
+    /// <p>Deletes a registered CA certificate.</p>
+    fn delete_ca_certificate(
+        &self,
+        input: DeleteCACertificateRequest,
+    ) -> RusotoFuture<DeleteCACertificateResponse, DeleteCACertificateError>;
+
+    /// <p>Deletes the specified certificate.</p> <p>A certificate cannot be deleted if it has a policy or IoT thing attached to it or if its status is set to ACTIVE. To delete a certificate, first use the <a>DetachPrincipalPolicy</a> API to detach all policies. Next, use the <a>UpdateCertificate</a> API to set the certificate to the INACTIVE status.</p>
+    fn delete_certificate(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> RusotoFuture<(), DeleteCertificateError>;
+
+    /// <p>Deletes a dynamic thing group.</p>
+    fn delete_dynamic_thing_group(
+        &self,
+        input: DeleteDynamicThingGroupRequest,
+    ) -> RusotoFuture<DeleteDynamicThingGroupResponse, DeleteDynamicThingGroupError>;
+
+    /// <p>Deletes a job and its related job executions.</p> <p>Deleting a job may take time, depending on the number of job executions created for the job and various other factors. While the job is being deleted, the status of the job will be shown as "DELETION_IN_PROGRESS". Attempting to delete or cancel a job whose status is already "DELETION_IN_PROGRESS" will result in an error.</p> <p>Only 10 jobs may have status "DELETION_IN_PROGRESS" at the same time, or a LimitExceededException will occur.</p>
+    fn delete_job(&self, input: DeleteJobRequest) -> RusotoFuture<(), DeleteJobError>;
+
+    /// <p>Deletes a job execution.</p>
+    fn delete_job_execution(
+        &self,
+        input: DeleteJobExecutionRequest,
+    ) -> RusotoFuture<(), DeleteJobExecutionError>;
+
+    /// <p>Deletes a defined mitigation action from your AWS account.</p>
+    fn delete_mitigation_action(
+        &self,
+        input: DeleteMitigationActionRequest,
+    ) -> RusotoFuture<DeleteMitigationActionResponse, DeleteMitigationActionError>;
+
+    /// <p>Delete an OTA update.</p>
+    fn delete_ota_update(
+        &self,
+        input: DeleteOTAUpdateRequest,
+    ) -> RusotoFuture<DeleteOTAUpdateResponse, DeleteOTAUpdateError>;
+
+    /// <p>Deletes the specified policy.</p> <p>A policy cannot be deleted if it has non-default versions or it is attached to any certificate.</p> <p>To delete a policy, use the DeletePolicyVersion API to delete all non-default versions of the policy; use the DetachPrincipalPolicy API to detach the policy from any certificate; and then use the DeletePolicy API to delete the policy.</p> <p>When a policy is deleted using DeletePolicy, its default version is deleted with it.</p>
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError>;
+
+    /// <p>Deletes the specified version of the specified policy. You cannot delete the default version of a policy using this API. To delete the default version of a policy, use <a>DeletePolicy</a>. To find out which version of a policy is marked as the default version, use ListPolicyVersions.</p>
+    fn delete_policy_version(
+        &self,
+        input: DeletePolicyVersionRequest,
+    ) -> RusotoFuture<(), DeletePolicyVersionError>;
+
+    /// <p>Deletes a CA certificate registration code.</p>
+    fn delete_registration_code(
+        &self,
+    ) -> RusotoFuture<DeleteRegistrationCodeResponse, DeleteRegistrationCodeError>;
+
+    /// <p>Deletes a role alias</p>
+    fn delete_role_alias(
+        &self,
+        input: DeleteRoleAliasRequest,
+    ) -> RusotoFuture<DeleteRoleAliasResponse, DeleteRoleAliasError>;
+
+    /// <p>Deletes a scheduled audit.</p>
+    fn delete_scheduled_audit(
+        &self,
+        input: DeleteScheduledAuditRequest,
+    ) -> RusotoFuture<DeleteScheduledAuditResponse, DeleteScheduledAuditError>;
+
+    /// <p>Deletes a Device Defender security profile.</p>
+    fn delete_security_profile(
+        &self,
+        input: DeleteSecurityProfileRequest,
+    ) -> RusotoFuture<DeleteSecurityProfileResponse, DeleteSecurityProfileError>;
+
+    /// <p>Deletes a stream.</p>
+    fn delete_stream(
+        &self,
+        input: DeleteStreamRequest,
+    ) -> RusotoFuture<DeleteStreamResponse, DeleteStreamError>;
+
+    /// <p>Deletes the specified thing. Returns successfully with no error if the deletion is successful or you specify a thing that doesn't exist.</p>
+    fn delete_thing(
+        &self,
+        input: DeleteThingRequest,
+    ) -> RusotoFuture<DeleteThingResponse, DeleteThingError>;
+
+    /// <p>Deletes a thing group.</p>
+    fn delete_thing_group(
+        &self,
+        input: DeleteThingGroupRequest,
+    ) -> RusotoFuture<DeleteThingGroupResponse, DeleteThingGroupError>;
+
+    /// <p>Deletes the specified thing type. You cannot delete a thing type if it has things associated with it. To delete a thing type, first mark it as deprecated by calling <a>DeprecateThingType</a>, then remove any associated things by calling <a>UpdateThing</a> to change the thing type on any associated thing, and finally use <a>DeleteThingType</a> to delete the thing type.</p>
+    fn delete_thing_type(
+        &self,
+        input: DeleteThingTypeRequest,
+    ) -> RusotoFuture<DeleteThingTypeResponse, DeleteThingTypeError>;
+
+    /// <p>Deletes the rule.</p>
+    fn delete_topic_rule(
+        &self,
+        input: DeleteTopicRuleRequest,
+    ) -> RusotoFuture<(), DeleteTopicRuleError>;
+
+    /// <p>Deletes a logging level.</p>
+    fn delete_v2_logging_level(
+        &self,
+        input: DeleteV2LoggingLevelRequest,
+    ) -> RusotoFuture<(), DeleteV2LoggingLevelError>;
+
+    /// <p>Deprecates a thing type. You can not associate new things with deprecated thing type.</p>
+    fn deprecate_thing_type(
+        &self,
+        input: DeprecateThingTypeRequest,
+    ) -> RusotoFuture<DeprecateThingTypeResponse, DeprecateThingTypeError>;
+
+    /// <p>Gets information about the Device Defender audit settings for this account. Settings include how audit notifications are sent and which audit checks are enabled or disabled.</p>
+    fn describe_account_audit_configuration(
+        &self,
+    ) -> RusotoFuture<
+        DescribeAccountAuditConfigurationResponse,
+        DescribeAccountAuditConfigurationError,
+    >;
+
+    /// <p>Gets information about a single audit finding. Properties include the reason for noncompliance, the severity of the issue, and when the audit that returned the finding was started.</p>
+    fn describe_audit_finding(
+        &self,
+        input: DescribeAuditFindingRequest,
+    ) -> RusotoFuture<DescribeAuditFindingResponse, DescribeAuditFindingError>;
+
+    /// <p>Gets information about an audit mitigation task that is used to apply mitigation actions to a set of audit findings. Properties include the actions being applied, the audit checks to which they're being applied, the task status, and aggregated task statistics.</p>
+    fn describe_audit_mitigation_actions_task(
+        &self,
+        input: DescribeAuditMitigationActionsTaskRequest,
+    ) -> RusotoFuture<
+        DescribeAuditMitigationActionsTaskResponse,
+        DescribeAuditMitigationActionsTaskError,
+    >;
+
+    /// <p>Gets information about a Device Defender audit.</p>
+    fn describe_audit_task(
+        &self,
+        input: DescribeAuditTaskRequest,
+    ) -> RusotoFuture<DescribeAuditTaskResponse, DescribeAuditTaskError>;
+
+    /// <p>Describes an authorizer.</p>
+    fn describe_authorizer(
+        &self,
+        input: DescribeAuthorizerRequest,
+    ) -> RusotoFuture<DescribeAuthorizerResponse, DescribeAuthorizerError>;
+
+    /// <p>Returns information about a billing group.</p>
+    fn describe_billing_group(
+        &self,
+        input: DescribeBillingGroupRequest,
+    ) -> RusotoFuture<DescribeBillingGroupResponse, DescribeBillingGroupError>;
+
+    /// <p>Describes a registered CA certificate.</p>
+    fn describe_ca_certificate(
+        &self,
+        input: DescribeCACertificateRequest,
+    ) -> RusotoFuture<DescribeCACertificateResponse, DescribeCACertificateError>;
+
+    /// <p>Gets information about the specified certificate.</p>
+    fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> RusotoFuture<DescribeCertificateResponse, DescribeCertificateError>;
+
+    /// <p>Describes the default authorizer.</p>
+    fn describe_default_authorizer(
+        &self,
+    ) -> RusotoFuture<DescribeDefaultAuthorizerResponse, DescribeDefaultAuthorizerError>;
+
+    /// <p>Returns a unique endpoint specific to the AWS account making the call.</p>
+    fn describe_endpoint(
+        &self,
+        input: DescribeEndpointRequest,
+    ) -> RusotoFuture<DescribeEndpointResponse, DescribeEndpointError>;
+
+    /// <p>Describes event configurations.</p>
+    fn describe_event_configurations(
+        &self,
+    ) -> RusotoFuture<DescribeEventConfigurationsResponse, DescribeEventConfigurationsError>;
+
+    /// <p>Describes a search index.</p>
+    fn describe_index(
+        &self,
+        input: DescribeIndexRequest,
+    ) -> RusotoFuture<DescribeIndexResponse, DescribeIndexError>;
+
+    /// <p>Describes a job.</p>
+    fn describe_job(
+        &self,
+        input: DescribeJobRequest,
+    ) -> RusotoFuture<DescribeJobResponse, DescribeJobError>;
+
+    /// <p>Describes a job execution.</p>
+    fn describe_job_execution(
+        &self,
+        input: DescribeJobExecutionRequest,
+    ) -> RusotoFuture<DescribeJobExecutionResponse, DescribeJobExecutionError>;
+
+    /// <p>Gets information about a mitigation action.</p>
+    fn describe_mitigation_action(
+        &self,
+        input: DescribeMitigationActionRequest,
+    ) -> RusotoFuture<DescribeMitigationActionResponse, DescribeMitigationActionError>;
+
+    /// <p>Describes a role alias.</p>
+    fn describe_role_alias(
+        &self,
+        input: DescribeRoleAliasRequest,
+    ) -> RusotoFuture<DescribeRoleAliasResponse, DescribeRoleAliasError>;
+
+    /// <p>Gets information about a scheduled audit.</p>
+    fn describe_scheduled_audit(
+        &self,
+        input: DescribeScheduledAuditRequest,
+    ) -> RusotoFuture<DescribeScheduledAuditResponse, DescribeScheduledAuditError>;
+
+    /// <p>Gets information about a Device Defender security profile.</p>
+    fn describe_security_profile(
+        &self,
+        input: DescribeSecurityProfileRequest,
+    ) -> RusotoFuture<DescribeSecurityProfileResponse, DescribeSecurityProfileError>;
+
+    /// <p>Gets information about a stream.</p>
+    fn describe_stream(
+        &self,
+        input: DescribeStreamRequest,
+    ) -> RusotoFuture<DescribeStreamResponse, DescribeStreamError>;
+
+    /// <p>Gets information about the specified thing.</p>
+    fn describe_thing(
+        &self,
+        input: DescribeThingRequest,
+    ) -> RusotoFuture<DescribeThingResponse, DescribeThingError>;
+
+    /// <p>Describe a thing group.</p>
+    fn describe_thing_group(
+        &self,
+        input: DescribeThingGroupRequest,
+    ) -> RusotoFuture<DescribeThingGroupResponse, DescribeThingGroupError>;
+
+    /// <p>Describes a bulk thing provisioning task.</p>
+    fn describe_thing_registration_task(
+        &self,
+        input: DescribeThingRegistrationTaskRequest,
+    ) -> RusotoFuture<DescribeThingRegistrationTaskResponse, DescribeThingRegistrationTaskError>;
+
+    /// <p>Gets information about the specified thing type.</p>
+    fn describe_thing_type(
+        &self,
+        input: DescribeThingTypeRequest,
+    ) -> RusotoFuture<DescribeThingTypeResponse, DescribeThingTypeError>;
+
+    /// <p>Detaches a policy from the specified target.</p>
+    fn detach_policy(&self, input: DetachPolicyRequest) -> RusotoFuture<(), DetachPolicyError>;
+
+    /// <p>Removes the specified policy from the specified certificate.</p> <p> <b>Note:</b> This API is deprecated. Please use <a>DetachPolicy</a> instead.</p>
+    fn detach_principal_policy(
+        &self,
+        input: DetachPrincipalPolicyRequest,
+    ) -> RusotoFuture<(), DetachPrincipalPolicyError>;
+
+    /// <p>Disassociates a Device Defender security profile from a thing group or from this account.</p>
+    fn detach_security_profile(
+        &self,
+        input: DetachSecurityProfileRequest,
+    ) -> RusotoFuture<DetachSecurityProfileResponse, DetachSecurityProfileError>;
+
+    /// <p><p>Detaches the specified principal from the specified thing. A principal can be X.509 certificates, IAM users, groups, and roles, Amazon Cognito identities or federated identities.</p> <note> <p>This call is asynchronous. It might take several seconds for the detachment to propagate.</p> </note></p>
+    fn detach_thing_principal(
+        &self,
+        input: DetachThingPrincipalRequest,
+    ) -> RusotoFuture<DetachThingPrincipalResponse, DetachThingPrincipalError>;
+
+    /// <p>Disables the rule.</p>
+    fn disable_topic_rule(
+        &self,
+        input: DisableTopicRuleRequest,
+    ) -> RusotoFuture<(), DisableTopicRuleError>;
+
+    /// <p>Enables the rule.</p>
+    fn enable_topic_rule(
+        &self,
+        input: EnableTopicRuleRequest,
+    ) -> RusotoFuture<(), EnableTopicRuleError>;
+
+    /// <p>Gets a list of the policies that have an effect on the authorization behavior of the specified device when it connects to the AWS IoT device gateway.</p>
+    fn get_effective_policies(
+        &self,
+        input: GetEffectivePoliciesRequest,
+    ) -> RusotoFuture<GetEffectivePoliciesResponse, GetEffectivePoliciesError>;
+
+    /// <p>Gets the search configuration.</p>
+    fn get_indexing_configuration(
+        &self,
+    ) -> RusotoFuture<GetIndexingConfigurationResponse, GetIndexingConfigurationError>;
+
+    /// <p>Gets a job document.</p>
+    fn get_job_document(
+        &self,
+        input: GetJobDocumentRequest,
+    ) -> RusotoFuture<GetJobDocumentResponse, GetJobDocumentError>;
+
+    /// <p>Gets the logging options.</p> <p>NOTE: use of this command is not recommended. Use <code>GetV2LoggingOptions</code> instead.</p>
+    fn get_logging_options(
+        &self,
+    ) -> RusotoFuture<GetLoggingOptionsResponse, GetLoggingOptionsError>;
+
+    /// <p>Gets an OTA update.</p>
+    fn get_ota_update(
+        &self,
+        input: GetOTAUpdateRequest,
+    ) -> RusotoFuture<GetOTAUpdateResponse, GetOTAUpdateError>;
+
+    /// <p>Gets information about the specified policy with the policy document of the default version.</p>
+    fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError>;
+
+    /// <p>Gets information about the specified policy version.</p>
+    fn get_policy_version(
+        &self,
+        input: GetPolicyVersionRequest,
+    ) -> RusotoFuture<GetPolicyVersionResponse, GetPolicyVersionError>;
+
+    /// <p>Gets a registration code used to register a CA certificate with AWS IoT.</p>
+    fn get_registration_code(
+        &self,
+    ) -> RusotoFuture<GetRegistrationCodeResponse, GetRegistrationCodeError>;
+
+    /// <p>Gets statistics about things that match the specified query.</p>
+    fn get_statistics(
+        &self,
+        input: GetStatisticsRequest,
+    ) -> RusotoFuture<GetStatisticsResponse, GetStatisticsError>;
+
+    /// <p>Gets information about the rule.</p>
+    fn get_topic_rule(
+        &self,
+        input: GetTopicRuleRequest,
+    ) -> RusotoFuture<GetTopicRuleResponse, GetTopicRuleError>;
+
+    /// <p>Gets the fine grained logging options.</p>
+    fn get_v2_logging_options(
+        &self,
+    ) -> RusotoFuture<GetV2LoggingOptionsResponse, GetV2LoggingOptionsError>;
+
+    /// <p>Lists the active violations for a given Device Defender security profile.</p>
+    fn list_active_violations(
+        &self,
+        input: ListActiveViolationsRequest,
+    ) -> RusotoFuture<ListActiveViolationsResponse, ListActiveViolationsError>;
+
+    /// <p>Lists the policies attached to the specified thing group.</p>
+    fn list_attached_policies(
+        &self,
+        input: ListAttachedPoliciesRequest,
+    ) -> RusotoFuture<ListAttachedPoliciesResponse, ListAttachedPoliciesError>;
+
+    /// <p>Lists the findings (results) of a Device Defender audit or of the audits performed during a specified time period. (Findings are retained for 180 days.)</p>
+    fn list_audit_findings(
+        &self,
+        input: ListAuditFindingsRequest,
+    ) -> RusotoFuture<ListAuditFindingsResponse, ListAuditFindingsError>;
+
+    /// <p>Gets the status of audit mitigation action tasks that were executed.</p>
+    fn list_audit_mitigation_actions_executions(
+        &self,
+        input: ListAuditMitigationActionsExecutionsRequest,
+    ) -> RusotoFuture<
+        ListAuditMitigationActionsExecutionsResponse,
+        ListAuditMitigationActionsExecutionsError,
+    >;
+
+    /// <p>Gets a list of audit mitigation action tasks that match the specified filters.</p>
+    fn list_audit_mitigation_actions_tasks(
+        &self,
+        input: ListAuditMitigationActionsTasksRequest,
+    ) -> RusotoFuture<ListAuditMitigationActionsTasksResponse, ListAuditMitigationActionsTasksError>;
+
+    /// <p>Lists the Device Defender audits that have been performed during a given time period.</p>
+    fn list_audit_tasks(
+        &self,
+        input: ListAuditTasksRequest,
+    ) -> RusotoFuture<ListAuditTasksResponse, ListAuditTasksError>;
+
+    /// <p>Lists the authorizers registered in your account.</p>
+    fn list_authorizers(
+        &self,
+        input: ListAuthorizersRequest,
+    ) -> RusotoFuture<ListAuthorizersResponse, ListAuthorizersError>;
+
+    /// <p>Lists the billing groups you have created.</p>
+    fn list_billing_groups(
+        &self,
+        input: ListBillingGroupsRequest,
+    ) -> RusotoFuture<ListBillingGroupsResponse, ListBillingGroupsError>;
+
+    /// <p>Lists the CA certificates registered for your AWS account.</p> <p>The results are paginated with a default page size of 25. You can use the returned marker to retrieve additional results.</p>
+    fn list_ca_certificates(
+        &self,
+        input: ListCACertificatesRequest,
+    ) -> RusotoFuture<ListCACertificatesResponse, ListCACertificatesError>;
+
+    /// <p>Lists the certificates registered in your AWS account.</p> <p>The results are paginated with a default page size of 25. You can use the returned marker to retrieve additional results.</p>
+    fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> RusotoFuture<ListCertificatesResponse, ListCertificatesError>;
+
+    /// <p>List the device certificates signed by the specified CA certificate.</p>
+    fn list_certificates_by_ca(
+        &self,
+        input: ListCertificatesByCARequest,
+    ) -> RusotoFuture<ListCertificatesByCAResponse, ListCertificatesByCAError>;
+
+    /// <p>Lists the search indices.</p>
+    fn list_indices(
+        &self,
+        input: ListIndicesRequest,
+    ) -> RusotoFuture<ListIndicesResponse, ListIndicesError>;
+
+    /// <p>Lists the job executions for a job.</p>
+    fn list_job_executions_for_job(
+        &self,
+        input: ListJobExecutionsForJobRequest,
+    ) -> RusotoFuture<ListJobExecutionsForJobResponse, ListJobExecutionsForJobError>;
+
+    /// <p>Lists the job executions for the specified thing.</p>
+    fn list_job_executions_for_thing(
+        &self,
+        input: ListJobExecutionsForThingRequest,
+    ) -> RusotoFuture<ListJobExecutionsForThingResponse, ListJobExecutionsForThingError>;
+
+    /// <p>Lists jobs.</p>
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError>;
+
+    /// <p>Gets a list of all mitigation actions that match the specified filter criteria.</p>
+    fn list_mitigation_actions(
+        &self,
+        input: ListMitigationActionsRequest,
+    ) -> RusotoFuture<ListMitigationActionsResponse, ListMitigationActionsError>;
+
+    /// <p>Lists OTA updates.</p>
+    fn list_ota_updates(
+        &self,
+        input: ListOTAUpdatesRequest,
+    ) -> RusotoFuture<ListOTAUpdatesResponse, ListOTAUpdatesError>;
+
+    /// <p>Lists certificates that are being transferred but not yet accepted.</p>
+    fn list_outgoing_certificates(
+        &self,
+        input: ListOutgoingCertificatesRequest,
+    ) -> RusotoFuture<ListOutgoingCertificatesResponse, ListOutgoingCertificatesError>;
+
+    /// <p>Lists your policies.</p>
+    fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError>;
+
+    /// <p>Lists the principals associated with the specified policy.</p> <p> <b>Note:</b> This API is deprecated. Please use <a>ListTargetsForPolicy</a> instead.</p>
+    fn list_policy_principals(
+        &self,
+        input: ListPolicyPrincipalsRequest,
+    ) -> RusotoFuture<ListPolicyPrincipalsResponse, ListPolicyPrincipalsError>;
+
+    /// <p>Lists the versions of the specified policy and identifies the default version.</p>
+    fn list_policy_versions(
+        &self,
+        input: ListPolicyVersionsRequest,
+    ) -> RusotoFuture<ListPolicyVersionsResponse, ListPolicyVersionsError>;
+
+    /// <p>Lists the policies attached to the specified principal. If you use an Cognito identity, the ID must be in <a href="https://docs.aws.amazon.com/cognitoidentity/latest/APIReference/API_GetCredentialsForIdentity.html#API_GetCredentialsForIdentity_RequestSyntax">AmazonCognito Identity format</a>.</p> <p> <b>Note:</b> This API is deprecated. Please use <a>ListAttachedPolicies</a> instead.</p>
+    fn list_principal_policies(
+        &self,
+        input: ListPrincipalPoliciesRequest,
+    ) -> RusotoFuture<ListPrincipalPoliciesResponse, ListPrincipalPoliciesError>;
+
+    /// <p>Lists the things associated with the specified principal. A principal can be X.509 certificates, IAM users, groups, and roles, Amazon Cognito identities or federated identities. </p>
+    fn list_principal_things(
+        &self,
+        input: ListPrincipalThingsRequest,
+    ) -> RusotoFuture<ListPrincipalThingsResponse, ListPrincipalThingsError>;
+
+    /// <p>Lists the role aliases registered in your account.</p>
+    fn list_role_aliases(
+        &self,
+        input: ListRoleAliasesRequest,
+    ) -> RusotoFuture<ListRoleAliasesResponse, ListRoleAliasesError>;
+
+    /// <p>Lists all of your scheduled audits.</p>
+    fn list_scheduled_audits(
+        &self,
+        input: ListScheduledAuditsRequest,
+    ) -> RusotoFuture<ListScheduledAuditsResponse, ListScheduledAuditsError>;
+
+    /// <p>Lists the Device Defender security profiles you have created. You can use filters to list only those security profiles associated with a thing group or only those associated with your account.</p>
+    fn list_security_profiles(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> RusotoFuture<ListSecurityProfilesResponse, ListSecurityProfilesError>;
+
+    /// <p>Lists the Device Defender security profiles attached to a target (thing group).</p>
+    fn list_security_profiles_for_target(
+        &self,
+        input: ListSecurityProfilesForTargetRequest,
+    ) -> RusotoFuture<ListSecurityProfilesForTargetResponse, ListSecurityProfilesForTargetError>;
+
+    /// <p>Lists all of the streams in your AWS account.</p>
+    fn list_streams(
+        &self,
+        input: ListStreamsRequest,
+    ) -> RusotoFuture<ListStreamsResponse, ListStreamsError>;
+
+    /// <p>Lists the tags (metadata) you have assigned to the resource.</p>
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
+
+    /// <p>List targets for the specified policy.</p>
+    fn list_targets_for_policy(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> RusotoFuture<ListTargetsForPolicyResponse, ListTargetsForPolicyError>;
+
+    /// <p>Lists the targets (thing groups) associated with a given Device Defender security profile.</p>
+    fn list_targets_for_security_profile(
+        &self,
+        input: ListTargetsForSecurityProfileRequest,
+    ) -> RusotoFuture<ListTargetsForSecurityProfileResponse, ListTargetsForSecurityProfileError>;
+
+    /// <p>List the thing groups in your account.</p>
+    fn list_thing_groups(
+        &self,
+        input: ListThingGroupsRequest,
+    ) -> RusotoFuture<ListThingGroupsResponse, ListThingGroupsError>;
+
+    /// <p>List the thing groups to which the specified thing belongs.</p>
+    fn list_thing_groups_for_thing(
+        &self,
+        input: ListThingGroupsForThingRequest,
+    ) -> RusotoFuture<ListThingGroupsForThingResponse, ListThingGroupsForThingError>;
+
+    /// <p>Lists the principals associated with the specified thing. A principal can be X.509 certificates, IAM users, groups, and roles, Amazon Cognito identities or federated identities.</p>
+    fn list_thing_principals(
+        &self,
+        input: ListThingPrincipalsRequest,
+    ) -> RusotoFuture<ListThingPrincipalsResponse, ListThingPrincipalsError>;
+
+    /// <p>Information about the thing registration tasks.</p>
+    fn list_thing_registration_task_reports(
+        &self,
+        input: ListThingRegistrationTaskReportsRequest,
+    ) -> RusotoFuture<ListThingRegistrationTaskReportsResponse, ListThingRegistrationTaskReportsError>;
+
+    /// <p>List bulk thing provisioning tasks.</p>
+    fn list_thing_registration_tasks(
+        &self,
+        input: ListThingRegistrationTasksRequest,
+    ) -> RusotoFuture<ListThingRegistrationTasksResponse, ListThingRegistrationTasksError>;
+
+    /// <p>Lists the existing thing types.</p>
+    fn list_thing_types(
+        &self,
+        input: ListThingTypesRequest,
+    ) -> RusotoFuture<ListThingTypesResponse, ListThingTypesError>;
+
+    /// <p>Lists your things. Use the <b>attributeName</b> and <b>attributeValue</b> parameters to filter your things. For example, calling <code>ListThings</code> with attributeName=Color and attributeValue=Red retrieves all things in the registry that contain an attribute <b>Color</b> with the value <b>Red</b>. </p>
+    fn list_things(
+        &self,
+        input: ListThingsRequest,
+    ) -> RusotoFuture<ListThingsResponse, ListThingsError>;
+
+    /// <p>Lists the things you have added to the given billing group.</p>
+    fn list_things_in_billing_group(
+        &self,
+        input: ListThingsInBillingGroupRequest,
+    ) -> RusotoFuture<ListThingsInBillingGroupResponse, ListThingsInBillingGroupError>;
+
+    /// <p>Lists the things in the specified group.</p>
+    fn list_things_in_thing_group(
+        &self,
+        input: ListThingsInThingGroupRequest,
+    ) -> RusotoFuture<ListThingsInThingGroupResponse, ListThingsInThingGroupError>;
+
+    /// <p>Lists the rules for the specific topic.</p>
+    fn list_topic_rules(
+        &self,
+        input: ListTopicRulesRequest,
+    ) -> RusotoFuture<ListTopicRulesResponse, ListTopicRulesError>;
+
+    /// <p>Lists logging levels.</p>
+    fn list_v2_logging_levels(
+        &self,
+        input: ListV2LoggingLevelsRequest,
+    ) -> RusotoFuture<ListV2LoggingLevelsResponse, ListV2LoggingLevelsError>;
+
+    /// <p>Lists the Device Defender security profile violations discovered during the given time period. You can use filters to limit the results to those alerts issued for a particular security profile, behavior, or thing (device).</p>
+    fn list_violation_events(
+        &self,
+        input: ListViolationEventsRequest,
+    ) -> RusotoFuture<ListViolationEventsResponse, ListViolationEventsError>;
+
+    /// <p>Registers a CA certificate with AWS IoT. This CA certificate can then be used to sign device certificates, which can be then registered with AWS IoT. You can register up to 10 CA certificates per AWS account that have the same subject field. This enables you to have up to 10 certificate authorities sign your device certificates. If you have more than one CA certificate registered, make sure you pass the CA certificate when you register your device certificates with the RegisterCertificate API.</p>
+    fn register_ca_certificate(
+        &self,
+        input: RegisterCACertificateRequest,
+    ) -> RusotoFuture<RegisterCACertificateResponse, RegisterCACertificateError>;
+
+    /// <p>Registers a device certificate with AWS IoT. If you have more than one CA certificate that has the same subject field, you must specify the CA certificate that was used to sign the device certificate being registered.</p>
+    fn register_certificate(
+        &self,
+        input: RegisterCertificateRequest,
+    ) -> RusotoFuture<RegisterCertificateResponse, RegisterCertificateError>;
+
+    /// <p>Provisions a thing.</p>
+    fn register_thing(
+        &self,
+        input: RegisterThingRequest,
+    ) -> RusotoFuture<RegisterThingResponse, RegisterThingError>;
+
+    /// <p>Rejects a pending certificate transfer. After AWS IoT rejects a certificate transfer, the certificate status changes from <b>PENDING_TRANSFER</b> to <b>INACTIVE</b>.</p> <p>To check for pending certificate transfers, call <a>ListCertificates</a> to enumerate your certificates.</p> <p>This operation can only be called by the transfer destination. After it is called, the certificate will be returned to the source's account in the INACTIVE state.</p>
+    fn reject_certificate_transfer(
+        &self,
+        input: RejectCertificateTransferRequest,
+    ) -> RusotoFuture<(), RejectCertificateTransferError>;
+
+    /// <p>Removes the given thing from the billing group.</p>
+    fn remove_thing_from_billing_group(
+        &self,
+        input: RemoveThingFromBillingGroupRequest,
+    ) -> RusotoFuture<RemoveThingFromBillingGroupResponse, RemoveThingFromBillingGroupError>;
+
+    /// <p>Remove the specified thing from the specified group.</p>
+    fn remove_thing_from_thing_group(
+        &self,
+        input: RemoveThingFromThingGroupRequest,
+    ) -> RusotoFuture<RemoveThingFromThingGroupResponse, RemoveThingFromThingGroupError>;
+
+    /// <p>Replaces the rule. You must specify all parameters for the new rule. Creating rules is an administrator-level action. Any user who has permission to create rules will be able to access data processed by the rule.</p>
+    fn replace_topic_rule(
+        &self,
+        input: ReplaceTopicRuleRequest,
+    ) -> RusotoFuture<(), ReplaceTopicRuleError>;
+
+    /// <p>The query search index.</p>
+    fn search_index(
+        &self,
+        input: SearchIndexRequest,
+    ) -> RusotoFuture<SearchIndexResponse, SearchIndexError>;
+
+    /// <p>Sets the default authorizer. This will be used if a websocket connection is made without specifying an authorizer.</p>
+    fn set_default_authorizer(
+        &self,
+        input: SetDefaultAuthorizerRequest,
+    ) -> RusotoFuture<SetDefaultAuthorizerResponse, SetDefaultAuthorizerError>;
+
+    /// <p>Sets the specified version of the specified policy as the policy's default (operative) version. This action affects all certificates to which the policy is attached. To list the principals the policy is attached to, use the ListPrincipalPolicy API.</p>
+    fn set_default_policy_version(
+        &self,
+        input: SetDefaultPolicyVersionRequest,
+    ) -> RusotoFuture<(), SetDefaultPolicyVersionError>;
+
+    /// <p>Sets the logging options.</p> <p>NOTE: use of this command is not recommended. Use <code>SetV2LoggingOptions</code> instead.</p>
+    fn set_logging_options(
+        &self,
+        input: SetLoggingOptionsRequest,
+    ) -> RusotoFuture<(), SetLoggingOptionsError>;
+
+    /// <p>Sets the logging level.</p>
+    fn set_v2_logging_level(
+        &self,
+        input: SetV2LoggingLevelRequest,
+    ) -> RusotoFuture<(), SetV2LoggingLevelError>;
+
+    /// <p>Sets the logging options for the V2 logging service.</p>
+    fn set_v2_logging_options(
+        &self,
+        input: SetV2LoggingOptionsRequest,
+    ) -> RusotoFuture<(), SetV2LoggingOptionsError>;
+
+    /// <p>Starts a task that applies a set of mitigation actions to the specified target.</p>
+    fn start_audit_mitigation_actions_task(
+        &self,
+        input: StartAuditMitigationActionsTaskRequest,
+    ) -> RusotoFuture<StartAuditMitigationActionsTaskResponse, StartAuditMitigationActionsTaskError>;
+
+    /// <p>Starts an on-demand Device Defender audit.</p>
+    fn start_on_demand_audit_task(
+        &self,
+        input: StartOnDemandAuditTaskRequest,
+    ) -> RusotoFuture<StartOnDemandAuditTaskResponse, StartOnDemandAuditTaskError>;
+
+    /// <p>Creates a bulk thing provisioning task.</p>
+    fn start_thing_registration_task(
+        &self,
+        input: StartThingRegistrationTaskRequest,
+    ) -> RusotoFuture<StartThingRegistrationTaskResponse, StartThingRegistrationTaskError>;
+
+    /// <p>Cancels a bulk thing provisioning task.</p>
+    fn stop_thing_registration_task(
+        &self,
+        input: StopThingRegistrationTaskRequest,
+    ) -> RusotoFuture<StopThingRegistrationTaskResponse, StopThingRegistrationTaskError>;
+
+    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata which can be used to manage a resource.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError>;
+
+    /// <p>Tests if a specified principal is authorized to perform an AWS IoT action on a specified resource. Use this to test and debug the authorization behavior of devices that connect to the AWS IoT device gateway.</p>
+    fn test_authorization(
+        &self,
+        input: TestAuthorizationRequest,
+    ) -> RusotoFuture<TestAuthorizationResponse, TestAuthorizationError>;
+
+    /// <p>Tests a custom authorization behavior by invoking a specified custom authorizer. Use this to test and debug the custom authorization behavior of devices that connect to the AWS IoT device gateway.</p>
+    fn test_invoke_authorizer(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> RusotoFuture<TestInvokeAuthorizerResponse, TestInvokeAuthorizerError>;
+
+    /// <p>Transfers the specified certificate to the specified AWS account.</p> <p>You can cancel the transfer until it is acknowledged by the recipient.</p> <p>No notification is sent to the transfer destination's account. It is up to the caller to notify the transfer target.</p> <p>The certificate being transferred must not be in the ACTIVE state. You can use the UpdateCertificate API to deactivate it.</p> <p>The certificate must not have any policies attached to it. You can use the DetachPrincipalPolicy API to detach them.</p>
+    fn transfer_certificate(
+        &self,
+        input: TransferCertificateRequest,
+    ) -> RusotoFuture<TransferCertificateResponse, TransferCertificateError>;
+
+    /// <p>Removes the given tags (metadata) from the resource.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
+
+    /// <p>Configures or reconfigures the Device Defender audit settings for this account. Settings include how audit notifications are sent and which audit checks are enabled or disabled.</p>
+    fn update_account_audit_configuration(
+        &self,
+        input: UpdateAccountAuditConfigurationRequest,
+    ) -> RusotoFuture<UpdateAccountAuditConfigurationResponse, UpdateAccountAuditConfigurationError>;
+
+    /// <p>Updates an authorizer.</p>
+    fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> RusotoFuture<UpdateAuthorizerResponse, UpdateAuthorizerError>;
+
+    /// <p>Updates information about the billing group.</p>
+    fn update_billing_group(
+        &self,
+        input: UpdateBillingGroupRequest,
+    ) -> RusotoFuture<UpdateBillingGroupResponse, UpdateBillingGroupError>;
+
+    /// <p>Updates a registered CA certificate.</p>
+    fn update_ca_certificate(
+        &self,
+        input: UpdateCACertificateRequest,
+    ) -> RusotoFuture<(), UpdateCACertificateError>;
+
+    /// <p>Updates the status of the specified certificate. This operation is idempotent.</p> <p>Moving a certificate from the ACTIVE state (including REVOKED) will not disconnect currently connected devices, but these devices will be unable to reconnect.</p> <p>The ACTIVE state is required to authenticate devices connecting to AWS IoT using a certificate.</p>
+    fn update_certificate(
+        &self,
+        input: UpdateCertificateRequest,
+    ) -> RusotoFuture<(), UpdateCertificateError>;
+
+    /// <p>Updates a dynamic thing group.</p>
+    fn update_dynamic_thing_group(
+        &self,
+        input: UpdateDynamicThingGroupRequest,
+    ) -> RusotoFuture<UpdateDynamicThingGroupResponse, UpdateDynamicThingGroupError>;
+
+    /// <p>Updates the event configurations.</p>
+    fn update_event_configurations(
+        &self,
+        input: UpdateEventConfigurationsRequest,
+    ) -> RusotoFuture<UpdateEventConfigurationsResponse, UpdateEventConfigurationsError>;
+
+    /// <p>Updates the search configuration.</p>
+    fn update_indexing_configuration(
+        &self,
+        input: UpdateIndexingConfigurationRequest,
+    ) -> RusotoFuture<UpdateIndexingConfigurationResponse, UpdateIndexingConfigurationError>;
+
+    /// <p>Updates supported fields of the specified job.</p>
+    fn update_job(&self, input: UpdateJobRequest) -> RusotoFuture<(), UpdateJobError>;
+
+    /// <p>Updates the definition for the specified mitigation action.</p>
+    fn update_mitigation_action(
+        &self,
+        input: UpdateMitigationActionRequest,
+    ) -> RusotoFuture<UpdateMitigationActionResponse, UpdateMitigationActionError>;
+
+    /// <p>Updates a role alias.</p>
+    fn update_role_alias(
+        &self,
+        input: UpdateRoleAliasRequest,
+    ) -> RusotoFuture<UpdateRoleAliasResponse, UpdateRoleAliasError>;
+
+    /// <p>Updates a scheduled audit, including which checks are performed and how often the audit takes place.</p>
+    fn update_scheduled_audit(
+        &self,
+        input: UpdateScheduledAuditRequest,
+    ) -> RusotoFuture<UpdateScheduledAuditResponse, UpdateScheduledAuditError>;
+
+    /// <p>Updates a Device Defender security profile.</p>
+    fn update_security_profile(
+        &self,
+        input: UpdateSecurityProfileRequest,
+    ) -> RusotoFuture<UpdateSecurityProfileResponse, UpdateSecurityProfileError>;
+
+    /// <p>Updates an existing stream. The stream version will be incremented by one.</p>
+    fn update_stream(
+        &self,
+        input: UpdateStreamRequest,
+    ) -> RusotoFuture<UpdateStreamResponse, UpdateStreamError>;
+
+    /// <p>Updates the data for a thing.</p>
+    fn update_thing(
+        &self,
+        input: UpdateThingRequest,
+    ) -> RusotoFuture<UpdateThingResponse, UpdateThingError>;
+
+    /// <p>Update a thing group.</p>
+    fn update_thing_group(
+        &self,
+        input: UpdateThingGroupRequest,
+    ) -> RusotoFuture<UpdateThingGroupResponse, UpdateThingGroupError>;
+
+    /// <p>Updates the groups to which the thing belongs.</p>
+    fn update_thing_groups_for_thing(
+        &self,
+        input: UpdateThingGroupsForThingRequest,
+    ) -> RusotoFuture<UpdateThingGroupsForThingResponse, UpdateThingGroupsForThingError>;
+
+    /// <p>Validates a Device Defender security profile behaviors specification.</p>
+    fn validate_security_profile_behaviors(
+        &self,
+        input: ValidateSecurityProfileBehaviorsRequest,
+    ) -> RusotoFuture<ValidateSecurityProfileBehaviorsResponse, ValidateSecurityProfileBehaviorsError>;
+}
+impl<T: ?Sized + Iot> Iot for ::std::sync::Arc<T> {
+    fn accept_certificate_transfer(
+        &self,
+        input: AcceptCertificateTransferRequest,
+    ) -> RusotoFuture<(), AcceptCertificateTransferError> {
+        (**self).accept_certificate_transfer(input)
+    }
+    fn add_thing_to_billing_group(
+        &self,
+        input: AddThingToBillingGroupRequest,
+    ) -> RusotoFuture<AddThingToBillingGroupResponse, AddThingToBillingGroupError> {
+        (**self).add_thing_to_billing_group(input)
+    }
+    fn add_thing_to_thing_group(
+        &self,
+        input: AddThingToThingGroupRequest,
+    ) -> RusotoFuture<AddThingToThingGroupResponse, AddThingToThingGroupError> {
+        (**self).add_thing_to_thing_group(input)
+    }
+    fn associate_targets_with_job(
+        &self,
+        input: AssociateTargetsWithJobRequest,
+    ) -> RusotoFuture<AssociateTargetsWithJobResponse, AssociateTargetsWithJobError> {
+        (**self).associate_targets_with_job(input)
+    }
+    fn attach_policy(&self, input: AttachPolicyRequest) -> RusotoFuture<(), AttachPolicyError> {
+        (**self).attach_policy(input)
+    }
+    fn attach_principal_policy(
+        &self,
+        input: AttachPrincipalPolicyRequest,
+    ) -> RusotoFuture<(), AttachPrincipalPolicyError> {
+        (**self).attach_principal_policy(input)
+    }
+    fn attach_security_profile(
+        &self,
+        input: AttachSecurityProfileRequest,
+    ) -> RusotoFuture<AttachSecurityProfileResponse, AttachSecurityProfileError> {
+        (**self).attach_security_profile(input)
+    }
+    fn attach_thing_principal(
+        &self,
+        input: AttachThingPrincipalRequest,
+    ) -> RusotoFuture<AttachThingPrincipalResponse, AttachThingPrincipalError> {
+        (**self).attach_thing_principal(input)
+    }
+    fn cancel_audit_mitigation_actions_task(
+        &self,
+        input: CancelAuditMitigationActionsTaskRequest,
+    ) -> RusotoFuture<CancelAuditMitigationActionsTaskResponse, CancelAuditMitigationActionsTaskError>
+    {
+        (**self).cancel_audit_mitigation_actions_task(input)
+    }
+    fn cancel_audit_task(
+        &self,
+        input: CancelAuditTaskRequest,
+    ) -> RusotoFuture<CancelAuditTaskResponse, CancelAuditTaskError> {
+        (**self).cancel_audit_task(input)
+    }
+    fn cancel_certificate_transfer(
+        &self,
+        input: CancelCertificateTransferRequest,
+    ) -> RusotoFuture<(), CancelCertificateTransferError> {
+        (**self).cancel_certificate_transfer(input)
+    }
+    fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> RusotoFuture<CancelJobResponse, CancelJobError> {
+        (**self).cancel_job(input)
+    }
+    fn cancel_job_execution(
+        &self,
+        input: CancelJobExecutionRequest,
+    ) -> RusotoFuture<(), CancelJobExecutionError> {
+        (**self).cancel_job_execution(input)
+    }
+    fn clear_default_authorizer(
+        &self,
+    ) -> RusotoFuture<ClearDefaultAuthorizerResponse, ClearDefaultAuthorizerError> {
+        (**self).clear_default_authorizer()
+    }
+    fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> RusotoFuture<CreateAuthorizerResponse, CreateAuthorizerError> {
+        (**self).create_authorizer(input)
+    }
+    fn create_billing_group(
+        &self,
+        input: CreateBillingGroupRequest,
+    ) -> RusotoFuture<CreateBillingGroupResponse, CreateBillingGroupError> {
+        (**self).create_billing_group(input)
+    }
+    fn create_certificate_from_csr(
+        &self,
+        input: CreateCertificateFromCsrRequest,
+    ) -> RusotoFuture<CreateCertificateFromCsrResponse, CreateCertificateFromCsrError> {
+        (**self).create_certificate_from_csr(input)
+    }
+    fn create_dynamic_thing_group(
+        &self,
+        input: CreateDynamicThingGroupRequest,
+    ) -> RusotoFuture<CreateDynamicThingGroupResponse, CreateDynamicThingGroupError> {
+        (**self).create_dynamic_thing_group(input)
+    }
+    fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> RusotoFuture<CreateJobResponse, CreateJobError> {
+        (**self).create_job(input)
+    }
+    fn create_keys_and_certificate(
+        &self,
+        input: CreateKeysAndCertificateRequest,
+    ) -> RusotoFuture<CreateKeysAndCertificateResponse, CreateKeysAndCertificateError> {
+        (**self).create_keys_and_certificate(input)
+    }
+    fn create_mitigation_action(
+        &self,
+        input: CreateMitigationActionRequest,
+    ) -> RusotoFuture<CreateMitigationActionResponse, CreateMitigationActionError> {
+        (**self).create_mitigation_action(input)
+    }
+    fn create_ota_update(
+        &self,
+        input: CreateOTAUpdateRequest,
+    ) -> RusotoFuture<CreateOTAUpdateResponse, CreateOTAUpdateError> {
+        (**self).create_ota_update(input)
+    }
+    fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> RusotoFuture<CreatePolicyResponse, CreatePolicyError> {
+        (**self).create_policy(input)
+    }
+    fn create_policy_version(
+        &self,
+        input: CreatePolicyVersionRequest,
+    ) -> RusotoFuture<CreatePolicyVersionResponse, CreatePolicyVersionError> {
+        (**self).create_policy_version(input)
+    }
+    fn create_role_alias(
+        &self,
+        input: CreateRoleAliasRequest,
+    ) -> RusotoFuture<CreateRoleAliasResponse, CreateRoleAliasError> {
+        (**self).create_role_alias(input)
+    }
+    fn create_scheduled_audit(
+        &self,
+        input: CreateScheduledAuditRequest,
+    ) -> RusotoFuture<CreateScheduledAuditResponse, CreateScheduledAuditError> {
+        (**self).create_scheduled_audit(input)
+    }
+    fn create_security_profile(
+        &self,
+        input: CreateSecurityProfileRequest,
+    ) -> RusotoFuture<CreateSecurityProfileResponse, CreateSecurityProfileError> {
+        (**self).create_security_profile(input)
+    }
+    fn create_stream(
+        &self,
+        input: CreateStreamRequest,
+    ) -> RusotoFuture<CreateStreamResponse, CreateStreamError> {
+        (**self).create_stream(input)
+    }
+    fn create_thing(
+        &self,
+        input: CreateThingRequest,
+    ) -> RusotoFuture<CreateThingResponse, CreateThingError> {
+        (**self).create_thing(input)
+    }
+    fn create_thing_group(
+        &self,
+        input: CreateThingGroupRequest,
+    ) -> RusotoFuture<CreateThingGroupResponse, CreateThingGroupError> {
+        (**self).create_thing_group(input)
+    }
+    fn create_thing_type(
+        &self,
+        input: CreateThingTypeRequest,
+    ) -> RusotoFuture<CreateThingTypeResponse, CreateThingTypeError> {
+        (**self).create_thing_type(input)
+    }
+    fn create_topic_rule(
+        &self,
+        input: CreateTopicRuleRequest,
+    ) -> RusotoFuture<(), CreateTopicRuleError> {
+        (**self).create_topic_rule(input)
+    }
+    fn delete_account_audit_configuration(
+        &self,
+        input: DeleteAccountAuditConfigurationRequest,
+    ) -> RusotoFuture<DeleteAccountAuditConfigurationResponse, DeleteAccountAuditConfigurationError>
+    {
+        (**self).delete_account_audit_configuration(input)
+    }
     fn delete_authorizer(
         &self,
         input: DeleteAuthorizerRequest,
-    ) -> RusotoFuture<DeleteAuthorizerResponse, DeleteAuthorizerError>;
-
-    /// <p>Deletes the billing group.</p>
+    ) -> RusotoFuture<DeleteAuthorizerResponse, DeleteAuthorizerError> {
+        (**self).delete_authorizer(input)
+    }
     fn delete_billing_group(
         &self,
         input: DeleteBillingGroupRequest,
-    ) -> RusotoFuture<DeleteBillingGroupResponse, DeleteBillingGroupError>;
-
-    /// <p>Deletes a registered CA certificate.</p>
+    ) -> RusotoFuture<DeleteBillingGroupResponse, DeleteBillingGroupError> {
+        (**self).delete_billing_group(input)
+    }
     fn delete_ca_certificate(
         &self,
         input: DeleteCACertificateRequest,
-    ) -> RusotoFuture<DeleteCACertificateResponse, DeleteCACertificateError>;
-
-    /// <p>Deletes the specified certificate.</p> <p>A certificate cannot be deleted if it has a policy or IoT thing attached to it or if its status is set to ACTIVE. To delete a certificate, first use the <a>DetachPrincipalPolicy</a> API to detach all policies. Next, use the <a>UpdateCertificate</a> API to set the certificate to the INACTIVE status.</p>
+    ) -> RusotoFuture<DeleteCACertificateResponse, DeleteCACertificateError> {
+        (**self).delete_ca_certificate(input)
+    }
     fn delete_certificate(
         &self,
         input: DeleteCertificateRequest,
-    ) -> RusotoFuture<(), DeleteCertificateError>;
-
-    /// <p>Deletes a dynamic thing group.</p>
+    ) -> RusotoFuture<(), DeleteCertificateError> {
+        (**self).delete_certificate(input)
+    }
     fn delete_dynamic_thing_group(
         &self,
         input: DeleteDynamicThingGroupRequest,
-    ) -> RusotoFuture<DeleteDynamicThingGroupResponse, DeleteDynamicThingGroupError>;
-
-    /// <p>Deletes a job and its related job executions.</p> <p>Deleting a job may take time, depending on the number of job executions created for the job and various other factors. While the job is being deleted, the status of the job will be shown as "DELETION_IN_PROGRESS". Attempting to delete or cancel a job whose status is already "DELETION_IN_PROGRESS" will result in an error.</p> <p>Only 10 jobs may have status "DELETION_IN_PROGRESS" at the same time, or a LimitExceededException will occur.</p>
-    fn delete_job(&self, input: DeleteJobRequest) -> RusotoFuture<(), DeleteJobError>;
-
-    /// <p>Deletes a job execution.</p>
+    ) -> RusotoFuture<DeleteDynamicThingGroupResponse, DeleteDynamicThingGroupError> {
+        (**self).delete_dynamic_thing_group(input)
+    }
+    fn delete_job(&self, input: DeleteJobRequest) -> RusotoFuture<(), DeleteJobError> {
+        (**self).delete_job(input)
+    }
     fn delete_job_execution(
         &self,
         input: DeleteJobExecutionRequest,
-    ) -> RusotoFuture<(), DeleteJobExecutionError>;
-
-    /// <p>Deletes a defined mitigation action from your AWS account.</p>
+    ) -> RusotoFuture<(), DeleteJobExecutionError> {
+        (**self).delete_job_execution(input)
+    }
     fn delete_mitigation_action(
         &self,
         input: DeleteMitigationActionRequest,
-    ) -> RusotoFuture<DeleteMitigationActionResponse, DeleteMitigationActionError>;
-
-    /// <p>Delete an OTA update.</p>
+    ) -> RusotoFuture<DeleteMitigationActionResponse, DeleteMitigationActionError> {
+        (**self).delete_mitigation_action(input)
+    }
     fn delete_ota_update(
         &self,
         input: DeleteOTAUpdateRequest,
-    ) -> RusotoFuture<DeleteOTAUpdateResponse, DeleteOTAUpdateError>;
-
-    /// <p>Deletes the specified policy.</p> <p>A policy cannot be deleted if it has non-default versions or it is attached to any certificate.</p> <p>To delete a policy, use the DeletePolicyVersion API to delete all non-default versions of the policy; use the DetachPrincipalPolicy API to detach the policy from any certificate; and then use the DeletePolicy API to delete the policy.</p> <p>When a policy is deleted using DeletePolicy, its default version is deleted with it.</p>
-    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError>;
-
-    /// <p>Deletes the specified version of the specified policy. You cannot delete the default version of a policy using this API. To delete the default version of a policy, use <a>DeletePolicy</a>. To find out which version of a policy is marked as the default version, use ListPolicyVersions.</p>
+    ) -> RusotoFuture<DeleteOTAUpdateResponse, DeleteOTAUpdateError> {
+        (**self).delete_ota_update(input)
+    }
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        (**self).delete_policy(input)
+    }
     fn delete_policy_version(
         &self,
         input: DeletePolicyVersionRequest,
-    ) -> RusotoFuture<(), DeletePolicyVersionError>;
-
-    /// <p>Deletes a CA certificate registration code.</p>
+    ) -> RusotoFuture<(), DeletePolicyVersionError> {
+        (**self).delete_policy_version(input)
+    }
     fn delete_registration_code(
         &self,
-    ) -> RusotoFuture<DeleteRegistrationCodeResponse, DeleteRegistrationCodeError>;
-
-    /// <p>Deletes a role alias</p>
+    ) -> RusotoFuture<DeleteRegistrationCodeResponse, DeleteRegistrationCodeError> {
+        (**self).delete_registration_code()
+    }
     fn delete_role_alias(
         &self,
         input: DeleteRoleAliasRequest,
-    ) -> RusotoFuture<DeleteRoleAliasResponse, DeleteRoleAliasError>;
-
-    /// <p>Deletes a scheduled audit.</p>
+    ) -> RusotoFuture<DeleteRoleAliasResponse, DeleteRoleAliasError> {
+        (**self).delete_role_alias(input)
+    }
     fn delete_scheduled_audit(
         &self,
         input: DeleteScheduledAuditRequest,
-    ) -> RusotoFuture<DeleteScheduledAuditResponse, DeleteScheduledAuditError>;
-
-    /// <p>Deletes a Device Defender security profile.</p>
+    ) -> RusotoFuture<DeleteScheduledAuditResponse, DeleteScheduledAuditError> {
+        (**self).delete_scheduled_audit(input)
+    }
     fn delete_security_profile(
         &self,
         input: DeleteSecurityProfileRequest,
-    ) -> RusotoFuture<DeleteSecurityProfileResponse, DeleteSecurityProfileError>;
-
-    /// <p>Deletes a stream.</p>
+    ) -> RusotoFuture<DeleteSecurityProfileResponse, DeleteSecurityProfileError> {
+        (**self).delete_security_profile(input)
+    }
     fn delete_stream(
         &self,
         input: DeleteStreamRequest,
-    ) -> RusotoFuture<DeleteStreamResponse, DeleteStreamError>;
-
-    /// <p>Deletes the specified thing. Returns successfully with no error if the deletion is successful or you specify a thing that doesn't exist.</p>
+    ) -> RusotoFuture<DeleteStreamResponse, DeleteStreamError> {
+        (**self).delete_stream(input)
+    }
     fn delete_thing(
         &self,
         input: DeleteThingRequest,
-    ) -> RusotoFuture<DeleteThingResponse, DeleteThingError>;
-
-    /// <p>Deletes a thing group.</p>
+    ) -> RusotoFuture<DeleteThingResponse, DeleteThingError> {
+        (**self).delete_thing(input)
+    }
     fn delete_thing_group(
         &self,
         input: DeleteThingGroupRequest,
-    ) -> RusotoFuture<DeleteThingGroupResponse, DeleteThingGroupError>;
-
-    /// <p>Deletes the specified thing type. You cannot delete a thing type if it has things associated with it. To delete a thing type, first mark it as deprecated by calling <a>DeprecateThingType</a>, then remove any associated things by calling <a>UpdateThing</a> to change the thing type on any associated thing, and finally use <a>DeleteThingType</a> to delete the thing type.</p>
+    ) -> RusotoFuture<DeleteThingGroupResponse, DeleteThingGroupError> {
+        (**self).delete_thing_group(input)
+    }
     fn delete_thing_type(
         &self,
         input: DeleteThingTypeRequest,
-    ) -> RusotoFuture<DeleteThingTypeResponse, DeleteThingTypeError>;
-
-    /// <p>Deletes the rule.</p>
+    ) -> RusotoFuture<DeleteThingTypeResponse, DeleteThingTypeError> {
+        (**self).delete_thing_type(input)
+    }
     fn delete_topic_rule(
         &self,
         input: DeleteTopicRuleRequest,
-    ) -> RusotoFuture<(), DeleteTopicRuleError>;
-
-    /// <p>Deletes a logging level.</p>
+    ) -> RusotoFuture<(), DeleteTopicRuleError> {
+        (**self).delete_topic_rule(input)
+    }
     fn delete_v2_logging_level(
         &self,
         input: DeleteV2LoggingLevelRequest,
-    ) -> RusotoFuture<(), DeleteV2LoggingLevelError>;
-
-    /// <p>Deprecates a thing type. You can not associate new things with deprecated thing type.</p>
+    ) -> RusotoFuture<(), DeleteV2LoggingLevelError> {
+        (**self).delete_v2_logging_level(input)
+    }
     fn deprecate_thing_type(
         &self,
         input: DeprecateThingTypeRequest,
-    ) -> RusotoFuture<DeprecateThingTypeResponse, DeprecateThingTypeError>;
-
-    /// <p>Gets information about the Device Defender audit settings for this account. Settings include how audit notifications are sent and which audit checks are enabled or disabled.</p>
+    ) -> RusotoFuture<DeprecateThingTypeResponse, DeprecateThingTypeError> {
+        (**self).deprecate_thing_type(input)
+    }
     fn describe_account_audit_configuration(
         &self,
     ) -> RusotoFuture<
         DescribeAccountAuditConfigurationResponse,
         DescribeAccountAuditConfigurationError,
-    >;
-
-    /// <p>Gets information about a single audit finding. Properties include the reason for noncompliance, the severity of the issue, and when the audit that returned the finding was started.</p>
+    > {
+        (**self).describe_account_audit_configuration()
+    }
     fn describe_audit_finding(
         &self,
         input: DescribeAuditFindingRequest,
-    ) -> RusotoFuture<DescribeAuditFindingResponse, DescribeAuditFindingError>;
-
-    /// <p>Gets information about an audit mitigation task that is used to apply mitigation actions to a set of audit findings. Properties include the actions being applied, the audit checks to which they're being applied, the task status, and aggregated task statistics.</p>
+    ) -> RusotoFuture<DescribeAuditFindingResponse, DescribeAuditFindingError> {
+        (**self).describe_audit_finding(input)
+    }
     fn describe_audit_mitigation_actions_task(
         &self,
         input: DescribeAuditMitigationActionsTaskRequest,
     ) -> RusotoFuture<
         DescribeAuditMitigationActionsTaskResponse,
         DescribeAuditMitigationActionsTaskError,
-    >;
-
-    /// <p>Gets information about a Device Defender audit.</p>
+    > {
+        (**self).describe_audit_mitigation_actions_task(input)
+    }
     fn describe_audit_task(
         &self,
         input: DescribeAuditTaskRequest,
-    ) -> RusotoFuture<DescribeAuditTaskResponse, DescribeAuditTaskError>;
-
-    /// <p>Describes an authorizer.</p>
+    ) -> RusotoFuture<DescribeAuditTaskResponse, DescribeAuditTaskError> {
+        (**self).describe_audit_task(input)
+    }
     fn describe_authorizer(
         &self,
         input: DescribeAuthorizerRequest,
-    ) -> RusotoFuture<DescribeAuthorizerResponse, DescribeAuthorizerError>;
-
-    /// <p>Returns information about a billing group.</p>
+    ) -> RusotoFuture<DescribeAuthorizerResponse, DescribeAuthorizerError> {
+        (**self).describe_authorizer(input)
+    }
     fn describe_billing_group(
         &self,
         input: DescribeBillingGroupRequest,
-    ) -> RusotoFuture<DescribeBillingGroupResponse, DescribeBillingGroupError>;
-
-    /// <p>Describes a registered CA certificate.</p>
+    ) -> RusotoFuture<DescribeBillingGroupResponse, DescribeBillingGroupError> {
+        (**self).describe_billing_group(input)
+    }
     fn describe_ca_certificate(
         &self,
         input: DescribeCACertificateRequest,
-    ) -> RusotoFuture<DescribeCACertificateResponse, DescribeCACertificateError>;
-
-    /// <p>Gets information about the specified certificate.</p>
+    ) -> RusotoFuture<DescribeCACertificateResponse, DescribeCACertificateError> {
+        (**self).describe_ca_certificate(input)
+    }
     fn describe_certificate(
         &self,
         input: DescribeCertificateRequest,
-    ) -> RusotoFuture<DescribeCertificateResponse, DescribeCertificateError>;
-
-    /// <p>Describes the default authorizer.</p>
+    ) -> RusotoFuture<DescribeCertificateResponse, DescribeCertificateError> {
+        (**self).describe_certificate(input)
+    }
     fn describe_default_authorizer(
         &self,
-    ) -> RusotoFuture<DescribeDefaultAuthorizerResponse, DescribeDefaultAuthorizerError>;
-
-    /// <p>Returns a unique endpoint specific to the AWS account making the call.</p>
+    ) -> RusotoFuture<DescribeDefaultAuthorizerResponse, DescribeDefaultAuthorizerError> {
+        (**self).describe_default_authorizer()
+    }
     fn describe_endpoint(
         &self,
         input: DescribeEndpointRequest,
-    ) -> RusotoFuture<DescribeEndpointResponse, DescribeEndpointError>;
-
-    /// <p>Describes event configurations.</p>
+    ) -> RusotoFuture<DescribeEndpointResponse, DescribeEndpointError> {
+        (**self).describe_endpoint(input)
+    }
     fn describe_event_configurations(
         &self,
-    ) -> RusotoFuture<DescribeEventConfigurationsResponse, DescribeEventConfigurationsError>;
-
-    /// <p>Describes a search index.</p>
+    ) -> RusotoFuture<DescribeEventConfigurationsResponse, DescribeEventConfigurationsError> {
+        (**self).describe_event_configurations()
+    }
     fn describe_index(
         &self,
         input: DescribeIndexRequest,
-    ) -> RusotoFuture<DescribeIndexResponse, DescribeIndexError>;
-
-    /// <p>Describes a job.</p>
+    ) -> RusotoFuture<DescribeIndexResponse, DescribeIndexError> {
+        (**self).describe_index(input)
+    }
     fn describe_job(
         &self,
         input: DescribeJobRequest,
-    ) -> RusotoFuture<DescribeJobResponse, DescribeJobError>;
-
-    /// <p>Describes a job execution.</p>
+    ) -> RusotoFuture<DescribeJobResponse, DescribeJobError> {
+        (**self).describe_job(input)
+    }
     fn describe_job_execution(
         &self,
         input: DescribeJobExecutionRequest,
-    ) -> RusotoFuture<DescribeJobExecutionResponse, DescribeJobExecutionError>;
-
-    /// <p>Gets information about a mitigation action.</p>
+    ) -> RusotoFuture<DescribeJobExecutionResponse, DescribeJobExecutionError> {
+        (**self).describe_job_execution(input)
+    }
     fn describe_mitigation_action(
         &self,
         input: DescribeMitigationActionRequest,
-    ) -> RusotoFuture<DescribeMitigationActionResponse, DescribeMitigationActionError>;
-
-    /// <p>Describes a role alias.</p>
+    ) -> RusotoFuture<DescribeMitigationActionResponse, DescribeMitigationActionError> {
+        (**self).describe_mitigation_action(input)
+    }
     fn describe_role_alias(
         &self,
         input: DescribeRoleAliasRequest,
-    ) -> RusotoFuture<DescribeRoleAliasResponse, DescribeRoleAliasError>;
-
-    /// <p>Gets information about a scheduled audit.</p>
+    ) -> RusotoFuture<DescribeRoleAliasResponse, DescribeRoleAliasError> {
+        (**self).describe_role_alias(input)
+    }
     fn describe_scheduled_audit(
         &self,
         input: DescribeScheduledAuditRequest,
-    ) -> RusotoFuture<DescribeScheduledAuditResponse, DescribeScheduledAuditError>;
-
-    /// <p>Gets information about a Device Defender security profile.</p>
+    ) -> RusotoFuture<DescribeScheduledAuditResponse, DescribeScheduledAuditError> {
+        (**self).describe_scheduled_audit(input)
+    }
     fn describe_security_profile(
         &self,
         input: DescribeSecurityProfileRequest,
-    ) -> RusotoFuture<DescribeSecurityProfileResponse, DescribeSecurityProfileError>;
-
-    /// <p>Gets information about a stream.</p>
+    ) -> RusotoFuture<DescribeSecurityProfileResponse, DescribeSecurityProfileError> {
+        (**self).describe_security_profile(input)
+    }
     fn describe_stream(
         &self,
         input: DescribeStreamRequest,
-    ) -> RusotoFuture<DescribeStreamResponse, DescribeStreamError>;
-
-    /// <p>Gets information about the specified thing.</p>
+    ) -> RusotoFuture<DescribeStreamResponse, DescribeStreamError> {
+        (**self).describe_stream(input)
+    }
     fn describe_thing(
         &self,
         input: DescribeThingRequest,
-    ) -> RusotoFuture<DescribeThingResponse, DescribeThingError>;
-
-    /// <p>Describe a thing group.</p>
+    ) -> RusotoFuture<DescribeThingResponse, DescribeThingError> {
+        (**self).describe_thing(input)
+    }
     fn describe_thing_group(
         &self,
         input: DescribeThingGroupRequest,
-    ) -> RusotoFuture<DescribeThingGroupResponse, DescribeThingGroupError>;
-
-    /// <p>Describes a bulk thing provisioning task.</p>
+    ) -> RusotoFuture<DescribeThingGroupResponse, DescribeThingGroupError> {
+        (**self).describe_thing_group(input)
+    }
     fn describe_thing_registration_task(
         &self,
         input: DescribeThingRegistrationTaskRequest,
-    ) -> RusotoFuture<DescribeThingRegistrationTaskResponse, DescribeThingRegistrationTaskError>;
-
-    /// <p>Gets information about the specified thing type.</p>
+    ) -> RusotoFuture<DescribeThingRegistrationTaskResponse, DescribeThingRegistrationTaskError>
+    {
+        (**self).describe_thing_registration_task(input)
+    }
     fn describe_thing_type(
         &self,
         input: DescribeThingTypeRequest,
-    ) -> RusotoFuture<DescribeThingTypeResponse, DescribeThingTypeError>;
-
-    /// <p>Detaches a policy from the specified target.</p>
-    fn detach_policy(&self, input: DetachPolicyRequest) -> RusotoFuture<(), DetachPolicyError>;
-
-    /// <p>Removes the specified policy from the specified certificate.</p> <p> <b>Note:</b> This API is deprecated. Please use <a>DetachPolicy</a> instead.</p>
+    ) -> RusotoFuture<DescribeThingTypeResponse, DescribeThingTypeError> {
+        (**self).describe_thing_type(input)
+    }
+    fn detach_policy(&self, input: DetachPolicyRequest) -> RusotoFuture<(), DetachPolicyError> {
+        (**self).detach_policy(input)
+    }
     fn detach_principal_policy(
         &self,
         input: DetachPrincipalPolicyRequest,
-    ) -> RusotoFuture<(), DetachPrincipalPolicyError>;
-
-    /// <p>Disassociates a Device Defender security profile from a thing group or from this account.</p>
+    ) -> RusotoFuture<(), DetachPrincipalPolicyError> {
+        (**self).detach_principal_policy(input)
+    }
     fn detach_security_profile(
         &self,
         input: DetachSecurityProfileRequest,
-    ) -> RusotoFuture<DetachSecurityProfileResponse, DetachSecurityProfileError>;
-
-    /// <p><p>Detaches the specified principal from the specified thing. A principal can be X.509 certificates, IAM users, groups, and roles, Amazon Cognito identities or federated identities.</p> <note> <p>This call is asynchronous. It might take several seconds for the detachment to propagate.</p> </note></p>
+    ) -> RusotoFuture<DetachSecurityProfileResponse, DetachSecurityProfileError> {
+        (**self).detach_security_profile(input)
+    }
     fn detach_thing_principal(
         &self,
         input: DetachThingPrincipalRequest,
-    ) -> RusotoFuture<DetachThingPrincipalResponse, DetachThingPrincipalError>;
-
-    /// <p>Disables the rule.</p>
+    ) -> RusotoFuture<DetachThingPrincipalResponse, DetachThingPrincipalError> {
+        (**self).detach_thing_principal(input)
+    }
     fn disable_topic_rule(
         &self,
         input: DisableTopicRuleRequest,
-    ) -> RusotoFuture<(), DisableTopicRuleError>;
-
-    /// <p>Enables the rule.</p>
+    ) -> RusotoFuture<(), DisableTopicRuleError> {
+        (**self).disable_topic_rule(input)
+    }
     fn enable_topic_rule(
         &self,
         input: EnableTopicRuleRequest,
-    ) -> RusotoFuture<(), EnableTopicRuleError>;
-
-    /// <p>Gets a list of the policies that have an effect on the authorization behavior of the specified device when it connects to the AWS IoT device gateway.</p>
+    ) -> RusotoFuture<(), EnableTopicRuleError> {
+        (**self).enable_topic_rule(input)
+    }
     fn get_effective_policies(
         &self,
         input: GetEffectivePoliciesRequest,
-    ) -> RusotoFuture<GetEffectivePoliciesResponse, GetEffectivePoliciesError>;
-
-    /// <p>Gets the search configuration.</p>
+    ) -> RusotoFuture<GetEffectivePoliciesResponse, GetEffectivePoliciesError> {
+        (**self).get_effective_policies(input)
+    }
     fn get_indexing_configuration(
         &self,
-    ) -> RusotoFuture<GetIndexingConfigurationResponse, GetIndexingConfigurationError>;
-
-    /// <p>Gets a job document.</p>
+    ) -> RusotoFuture<GetIndexingConfigurationResponse, GetIndexingConfigurationError> {
+        (**self).get_indexing_configuration()
+    }
     fn get_job_document(
         &self,
         input: GetJobDocumentRequest,
-    ) -> RusotoFuture<GetJobDocumentResponse, GetJobDocumentError>;
-
-    /// <p>Gets the logging options.</p> <p>NOTE: use of this command is not recommended. Use <code>GetV2LoggingOptions</code> instead.</p>
+    ) -> RusotoFuture<GetJobDocumentResponse, GetJobDocumentError> {
+        (**self).get_job_document(input)
+    }
     fn get_logging_options(
         &self,
-    ) -> RusotoFuture<GetLoggingOptionsResponse, GetLoggingOptionsError>;
-
-    /// <p>Gets an OTA update.</p>
+    ) -> RusotoFuture<GetLoggingOptionsResponse, GetLoggingOptionsError> {
+        (**self).get_logging_options()
+    }
     fn get_ota_update(
         &self,
         input: GetOTAUpdateRequest,
-    ) -> RusotoFuture<GetOTAUpdateResponse, GetOTAUpdateError>;
-
-    /// <p>Gets information about the specified policy with the policy document of the default version.</p>
+    ) -> RusotoFuture<GetOTAUpdateResponse, GetOTAUpdateError> {
+        (**self).get_ota_update(input)
+    }
     fn get_policy(
         &self,
         input: GetPolicyRequest,
-    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError>;
-
-    /// <p>Gets information about the specified policy version.</p>
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        (**self).get_policy(input)
+    }
     fn get_policy_version(
         &self,
         input: GetPolicyVersionRequest,
-    ) -> RusotoFuture<GetPolicyVersionResponse, GetPolicyVersionError>;
-
-    /// <p>Gets a registration code used to register a CA certificate with AWS IoT.</p>
+    ) -> RusotoFuture<GetPolicyVersionResponse, GetPolicyVersionError> {
+        (**self).get_policy_version(input)
+    }
     fn get_registration_code(
         &self,
-    ) -> RusotoFuture<GetRegistrationCodeResponse, GetRegistrationCodeError>;
-
-    /// <p>Gets statistics about things that match the specified query.</p>
+    ) -> RusotoFuture<GetRegistrationCodeResponse, GetRegistrationCodeError> {
+        (**self).get_registration_code()
+    }
     fn get_statistics(
         &self,
         input: GetStatisticsRequest,
-    ) -> RusotoFuture<GetStatisticsResponse, GetStatisticsError>;
-
-    /// <p>Gets information about the rule.</p>
+    ) -> RusotoFuture<GetStatisticsResponse, GetStatisticsError> {
+        (**self).get_statistics(input)
+    }
     fn get_topic_rule(
         &self,
         input: GetTopicRuleRequest,
-    ) -> RusotoFuture<GetTopicRuleResponse, GetTopicRuleError>;
-
-    /// <p>Gets the fine grained logging options.</p>
+    ) -> RusotoFuture<GetTopicRuleResponse, GetTopicRuleError> {
+        (**self).get_topic_rule(input)
+    }
     fn get_v2_logging_options(
         &self,
-    ) -> RusotoFuture<GetV2LoggingOptionsResponse, GetV2LoggingOptionsError>;
-
-    /// <p>Lists the active violations for a given Device Defender security profile.</p>
+    ) -> RusotoFuture<GetV2LoggingOptionsResponse, GetV2LoggingOptionsError> {
+        (**self).get_v2_logging_options()
+    }
     fn list_active_violations(
         &self,
         input: ListActiveViolationsRequest,
-    ) -> RusotoFuture<ListActiveViolationsResponse, ListActiveViolationsError>;
-
-    /// <p>Lists the policies attached to the specified thing group.</p>
+    ) -> RusotoFuture<ListActiveViolationsResponse, ListActiveViolationsError> {
+        (**self).list_active_violations(input)
+    }
     fn list_attached_policies(
         &self,
         input: ListAttachedPoliciesRequest,
-    ) -> RusotoFuture<ListAttachedPoliciesResponse, ListAttachedPoliciesError>;
-
-    /// <p>Lists the findings (results) of a Device Defender audit or of the audits performed during a specified time period. (Findings are retained for 180 days.)</p>
+    ) -> RusotoFuture<ListAttachedPoliciesResponse, ListAttachedPoliciesError> {
+        (**self).list_attached_policies(input)
+    }
     fn list_audit_findings(
         &self,
         input: ListAuditFindingsRequest,
-    ) -> RusotoFuture<ListAuditFindingsResponse, ListAuditFindingsError>;
-
-    /// <p>Gets the status of audit mitigation action tasks that were executed.</p>
+    ) -> RusotoFuture<ListAuditFindingsResponse, ListAuditFindingsError> {
+        (**self).list_audit_findings(input)
+    }
     fn list_audit_mitigation_actions_executions(
         &self,
         input: ListAuditMitigationActionsExecutionsRequest,
     ) -> RusotoFuture<
         ListAuditMitigationActionsExecutionsResponse,
         ListAuditMitigationActionsExecutionsError,
-    >;
-
-    /// <p>Gets a list of audit mitigation action tasks that match the specified filters.</p>
+    > {
+        (**self).list_audit_mitigation_actions_executions(input)
+    }
     fn list_audit_mitigation_actions_tasks(
         &self,
         input: ListAuditMitigationActionsTasksRequest,
-    ) -> RusotoFuture<ListAuditMitigationActionsTasksResponse, ListAuditMitigationActionsTasksError>;
-
-    /// <p>Lists the Device Defender audits that have been performed during a given time period.</p>
+    ) -> RusotoFuture<ListAuditMitigationActionsTasksResponse, ListAuditMitigationActionsTasksError>
+    {
+        (**self).list_audit_mitigation_actions_tasks(input)
+    }
     fn list_audit_tasks(
         &self,
         input: ListAuditTasksRequest,
-    ) -> RusotoFuture<ListAuditTasksResponse, ListAuditTasksError>;
-
-    /// <p>Lists the authorizers registered in your account.</p>
+    ) -> RusotoFuture<ListAuditTasksResponse, ListAuditTasksError> {
+        (**self).list_audit_tasks(input)
+    }
     fn list_authorizers(
         &self,
         input: ListAuthorizersRequest,
-    ) -> RusotoFuture<ListAuthorizersResponse, ListAuthorizersError>;
-
-    /// <p>Lists the billing groups you have created.</p>
+    ) -> RusotoFuture<ListAuthorizersResponse, ListAuthorizersError> {
+        (**self).list_authorizers(input)
+    }
     fn list_billing_groups(
         &self,
         input: ListBillingGroupsRequest,
-    ) -> RusotoFuture<ListBillingGroupsResponse, ListBillingGroupsError>;
-
-    /// <p>Lists the CA certificates registered for your AWS account.</p> <p>The results are paginated with a default page size of 25. You can use the returned marker to retrieve additional results.</p>
+    ) -> RusotoFuture<ListBillingGroupsResponse, ListBillingGroupsError> {
+        (**self).list_billing_groups(input)
+    }
     fn list_ca_certificates(
         &self,
         input: ListCACertificatesRequest,
-    ) -> RusotoFuture<ListCACertificatesResponse, ListCACertificatesError>;
-
-    /// <p>Lists the certificates registered in your AWS account.</p> <p>The results are paginated with a default page size of 25. You can use the returned marker to retrieve additional results.</p>
+    ) -> RusotoFuture<ListCACertificatesResponse, ListCACertificatesError> {
+        (**self).list_ca_certificates(input)
+    }
     fn list_certificates(
         &self,
         input: ListCertificatesRequest,
-    ) -> RusotoFuture<ListCertificatesResponse, ListCertificatesError>;
-
-    /// <p>List the device certificates signed by the specified CA certificate.</p>
+    ) -> RusotoFuture<ListCertificatesResponse, ListCertificatesError> {
+        (**self).list_certificates(input)
+    }
     fn list_certificates_by_ca(
         &self,
         input: ListCertificatesByCARequest,
-    ) -> RusotoFuture<ListCertificatesByCAResponse, ListCertificatesByCAError>;
-
-    /// <p>Lists the search indices.</p>
+    ) -> RusotoFuture<ListCertificatesByCAResponse, ListCertificatesByCAError> {
+        (**self).list_certificates_by_ca(input)
+    }
     fn list_indices(
         &self,
         input: ListIndicesRequest,
-    ) -> RusotoFuture<ListIndicesResponse, ListIndicesError>;
-
-    /// <p>Lists the job executions for a job.</p>
+    ) -> RusotoFuture<ListIndicesResponse, ListIndicesError> {
+        (**self).list_indices(input)
+    }
     fn list_job_executions_for_job(
         &self,
         input: ListJobExecutionsForJobRequest,
-    ) -> RusotoFuture<ListJobExecutionsForJobResponse, ListJobExecutionsForJobError>;
-
-    /// <p>Lists the job executions for the specified thing.</p>
+    ) -> RusotoFuture<ListJobExecutionsForJobResponse, ListJobExecutionsForJobError> {
+        (**self).list_job_executions_for_job(input)
+    }
     fn list_job_executions_for_thing(
         &self,
         input: ListJobExecutionsForThingRequest,
-    ) -> RusotoFuture<ListJobExecutionsForThingResponse, ListJobExecutionsForThingError>;
-
-    /// <p>Lists jobs.</p>
-    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError>;
-
-    /// <p>Gets a list of all mitigation actions that match the specified filter criteria.</p>
+    ) -> RusotoFuture<ListJobExecutionsForThingResponse, ListJobExecutionsForThingError> {
+        (**self).list_job_executions_for_thing(input)
+    }
+    fn list_jobs(&self, input: ListJobsRequest) -> RusotoFuture<ListJobsResponse, ListJobsError> {
+        (**self).list_jobs(input)
+    }
     fn list_mitigation_actions(
         &self,
         input: ListMitigationActionsRequest,
-    ) -> RusotoFuture<ListMitigationActionsResponse, ListMitigationActionsError>;
-
-    /// <p>Lists OTA updates.</p>
+    ) -> RusotoFuture<ListMitigationActionsResponse, ListMitigationActionsError> {
+        (**self).list_mitigation_actions(input)
+    }
     fn list_ota_updates(
         &self,
         input: ListOTAUpdatesRequest,
-    ) -> RusotoFuture<ListOTAUpdatesResponse, ListOTAUpdatesError>;
-
-    /// <p>Lists certificates that are being transferred but not yet accepted.</p>
+    ) -> RusotoFuture<ListOTAUpdatesResponse, ListOTAUpdatesError> {
+        (**self).list_ota_updates(input)
+    }
     fn list_outgoing_certificates(
         &self,
         input: ListOutgoingCertificatesRequest,
-    ) -> RusotoFuture<ListOutgoingCertificatesResponse, ListOutgoingCertificatesError>;
-
-    /// <p>Lists your policies.</p>
+    ) -> RusotoFuture<ListOutgoingCertificatesResponse, ListOutgoingCertificatesError> {
+        (**self).list_outgoing_certificates(input)
+    }
     fn list_policies(
         &self,
         input: ListPoliciesRequest,
-    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError>;
-
-    /// <p>Lists the principals associated with the specified policy.</p> <p> <b>Note:</b> This API is deprecated. Please use <a>ListTargetsForPolicy</a> instead.</p>
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        (**self).list_policies(input)
+    }
     fn list_policy_principals(
         &self,
         input: ListPolicyPrincipalsRequest,
-    ) -> RusotoFuture<ListPolicyPrincipalsResponse, ListPolicyPrincipalsError>;
-
-    /// <p>Lists the versions of the specified policy and identifies the default version.</p>
+    ) -> RusotoFuture<ListPolicyPrincipalsResponse, ListPolicyPrincipalsError> {
+        (**self).list_policy_principals(input)
+    }
     fn list_policy_versions(
         &self,
         input: ListPolicyVersionsRequest,
-    ) -> RusotoFuture<ListPolicyVersionsResponse, ListPolicyVersionsError>;
-
-    /// <p>Lists the policies attached to the specified principal. If you use an Cognito identity, the ID must be in <a href="https://docs.aws.amazon.com/cognitoidentity/latest/APIReference/API_GetCredentialsForIdentity.html#API_GetCredentialsForIdentity_RequestSyntax">AmazonCognito Identity format</a>.</p> <p> <b>Note:</b> This API is deprecated. Please use <a>ListAttachedPolicies</a> instead.</p>
+    ) -> RusotoFuture<ListPolicyVersionsResponse, ListPolicyVersionsError> {
+        (**self).list_policy_versions(input)
+    }
     fn list_principal_policies(
         &self,
         input: ListPrincipalPoliciesRequest,
-    ) -> RusotoFuture<ListPrincipalPoliciesResponse, ListPrincipalPoliciesError>;
-
-    /// <p>Lists the things associated with the specified principal. A principal can be X.509 certificates, IAM users, groups, and roles, Amazon Cognito identities or federated identities. </p>
+    ) -> RusotoFuture<ListPrincipalPoliciesResponse, ListPrincipalPoliciesError> {
+        (**self).list_principal_policies(input)
+    }
     fn list_principal_things(
         &self,
         input: ListPrincipalThingsRequest,
-    ) -> RusotoFuture<ListPrincipalThingsResponse, ListPrincipalThingsError>;
-
-    /// <p>Lists the role aliases registered in your account.</p>
+    ) -> RusotoFuture<ListPrincipalThingsResponse, ListPrincipalThingsError> {
+        (**self).list_principal_things(input)
+    }
     fn list_role_aliases(
         &self,
         input: ListRoleAliasesRequest,
-    ) -> RusotoFuture<ListRoleAliasesResponse, ListRoleAliasesError>;
-
-    /// <p>Lists all of your scheduled audits.</p>
+    ) -> RusotoFuture<ListRoleAliasesResponse, ListRoleAliasesError> {
+        (**self).list_role_aliases(input)
+    }
     fn list_scheduled_audits(
         &self,
         input: ListScheduledAuditsRequest,
-    ) -> RusotoFuture<ListScheduledAuditsResponse, ListScheduledAuditsError>;
-
-    /// <p>Lists the Device Defender security profiles you have created. You can use filters to list only those security profiles associated with a thing group or only those associated with your account.</p>
+    ) -> RusotoFuture<ListScheduledAuditsResponse, ListScheduledAuditsError> {
+        (**self).list_scheduled_audits(input)
+    }
     fn list_security_profiles(
         &self,
         input: ListSecurityProfilesRequest,
-    ) -> RusotoFuture<ListSecurityProfilesResponse, ListSecurityProfilesError>;
-
-    /// <p>Lists the Device Defender security profiles attached to a target (thing group).</p>
+    ) -> RusotoFuture<ListSecurityProfilesResponse, ListSecurityProfilesError> {
+        (**self).list_security_profiles(input)
+    }
     fn list_security_profiles_for_target(
         &self,
         input: ListSecurityProfilesForTargetRequest,
-    ) -> RusotoFuture<ListSecurityProfilesForTargetResponse, ListSecurityProfilesForTargetError>;
-
-    /// <p>Lists all of the streams in your AWS account.</p>
+    ) -> RusotoFuture<ListSecurityProfilesForTargetResponse, ListSecurityProfilesForTargetError>
+    {
+        (**self).list_security_profiles_for_target(input)
+    }
     fn list_streams(
         &self,
         input: ListStreamsRequest,
-    ) -> RusotoFuture<ListStreamsResponse, ListStreamsError>;
-
-    /// <p>Lists the tags (metadata) you have assigned to the resource.</p>
+    ) -> RusotoFuture<ListStreamsResponse, ListStreamsError> {
+        (**self).list_streams(input)
+    }
     fn list_tags_for_resource(
         &self,
         input: ListTagsForResourceRequest,
-    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError>;
-
-    /// <p>List targets for the specified policy.</p>
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
     fn list_targets_for_policy(
         &self,
         input: ListTargetsForPolicyRequest,
-    ) -> RusotoFuture<ListTargetsForPolicyResponse, ListTargetsForPolicyError>;
-
-    /// <p>Lists the targets (thing groups) associated with a given Device Defender security profile.</p>
+    ) -> RusotoFuture<ListTargetsForPolicyResponse, ListTargetsForPolicyError> {
+        (**self).list_targets_for_policy(input)
+    }
     fn list_targets_for_security_profile(
         &self,
         input: ListTargetsForSecurityProfileRequest,
-    ) -> RusotoFuture<ListTargetsForSecurityProfileResponse, ListTargetsForSecurityProfileError>;
-
-    /// <p>List the thing groups in your account.</p>
+    ) -> RusotoFuture<ListTargetsForSecurityProfileResponse, ListTargetsForSecurityProfileError>
+    {
+        (**self).list_targets_for_security_profile(input)
+    }
     fn list_thing_groups(
         &self,
         input: ListThingGroupsRequest,
-    ) -> RusotoFuture<ListThingGroupsResponse, ListThingGroupsError>;
-
-    /// <p>List the thing groups to which the specified thing belongs.</p>
+    ) -> RusotoFuture<ListThingGroupsResponse, ListThingGroupsError> {
+        (**self).list_thing_groups(input)
+    }
     fn list_thing_groups_for_thing(
         &self,
         input: ListThingGroupsForThingRequest,
-    ) -> RusotoFuture<ListThingGroupsForThingResponse, ListThingGroupsForThingError>;
-
-    /// <p>Lists the principals associated with the specified thing. A principal can be X.509 certificates, IAM users, groups, and roles, Amazon Cognito identities or federated identities.</p>
+    ) -> RusotoFuture<ListThingGroupsForThingResponse, ListThingGroupsForThingError> {
+        (**self).list_thing_groups_for_thing(input)
+    }
     fn list_thing_principals(
         &self,
         input: ListThingPrincipalsRequest,
-    ) -> RusotoFuture<ListThingPrincipalsResponse, ListThingPrincipalsError>;
-
-    /// <p>Information about the thing registration tasks.</p>
+    ) -> RusotoFuture<ListThingPrincipalsResponse, ListThingPrincipalsError> {
+        (**self).list_thing_principals(input)
+    }
     fn list_thing_registration_task_reports(
         &self,
         input: ListThingRegistrationTaskReportsRequest,
-    ) -> RusotoFuture<ListThingRegistrationTaskReportsResponse, ListThingRegistrationTaskReportsError>;
-
-    /// <p>List bulk thing provisioning tasks.</p>
+    ) -> RusotoFuture<ListThingRegistrationTaskReportsResponse, ListThingRegistrationTaskReportsError>
+    {
+        (**self).list_thing_registration_task_reports(input)
+    }
     fn list_thing_registration_tasks(
         &self,
         input: ListThingRegistrationTasksRequest,
-    ) -> RusotoFuture<ListThingRegistrationTasksResponse, ListThingRegistrationTasksError>;
-
-    /// <p>Lists the existing thing types.</p>
+    ) -> RusotoFuture<ListThingRegistrationTasksResponse, ListThingRegistrationTasksError> {
+        (**self).list_thing_registration_tasks(input)
+    }
     fn list_thing_types(
         &self,
         input: ListThingTypesRequest,
-    ) -> RusotoFuture<ListThingTypesResponse, ListThingTypesError>;
-
-    /// <p>Lists your things. Use the <b>attributeName</b> and <b>attributeValue</b> parameters to filter your things. For example, calling <code>ListThings</code> with attributeName=Color and attributeValue=Red retrieves all things in the registry that contain an attribute <b>Color</b> with the value <b>Red</b>. </p>
+    ) -> RusotoFuture<ListThingTypesResponse, ListThingTypesError> {
+        (**self).list_thing_types(input)
+    }
     fn list_things(
-        &self,
-        input: ListThingsRequest,
-    ) -> RusotoFuture<ListThingsResponse, ListThingsError>;
-
-    /// <p>Lists the things you have added to the given billing group.</p>
+        &self,
+        input: ListThingsRequest,
+    ) -> RusotoFuture<ListThingsResponse, ListThingsError> {
+        (**self).list_things(input)
+    }
     fn list_things_in_billing_group(
         &self,
         input: ListThingsInBillingGroupRequest,
-    ) -> RusotoFuture<ListThingsInBillingGroupResponse, ListThingsInBillingGroupError>;
-
-    /// <p>Lists the things in the specified group.</p>
+    ) -> RusotoFuture<ListThingsInBillingGroupResponse, ListThingsInBillingGroupError> {
+        (**self).list_things_in_billing_group(input)
+    }
     fn list_things_in_thing_group(
         &self,
         input: ListThingsInThingGroupRequest,
-    ) -> RusotoFuture<ListThingsInThingGroupResponse, ListThingsInThingGroupError>;
-
-    /// <p>Lists the rules for the specific topic.</p>
+    ) -> RusotoFuture<ListThingsInThingGroupResponse, ListThingsInThingGroupError> {
+        (**self).list_things_in_thing_group(input)
+    }
     fn list_topic_rules(
         &self,
         input: ListTopicRulesRequest,
-    ) -> RusotoFuture<ListTopicRulesResponse, ListTopicRulesError>;
-
-    /// <p>Lists logging levels.</p>
+    ) -> RusotoFuture<ListTopicRulesResponse, ListTopicRulesError> {
+        (**self).list_topic_rules(input)
+    }
     fn list_v2_logging_levels(
         &self,
         input: ListV2LoggingLevelsRequest,
-    ) -> RusotoFuture<ListV2LoggingLevelsResponse, ListV2LoggingLevelsError>;
-
-    /// <p>Lists the Device Defender security profile violations discovered during the given time period. You can use filters to limit the results to those alerts issued for a particular security profile, behavior, or thing (device).</p>
+    ) -> RusotoFuture<ListV2LoggingLevelsResponse, ListV2LoggingLevelsError> {
+        (**self).list_v2_logging_levels(input)
+    }
     fn list_violation_events(
         &self,
         input: ListViolationEventsRequest,
-    ) -> RusotoFuture<ListViolationEventsResponse, ListViolationEventsError>;
-
-    /// <p>Registers a CA certificate with AWS IoT. This CA certificate can then be used to sign device certificates, which can be then registered with AWS IoT. You can register up to 10 CA certificates per AWS account that have the same subject field. This enables you to have up to 10 certificate authorities sign your device certificates. If you have more than one CA certificate registered, make sure you pass the CA certificate when you register your device certificates with the RegisterCertificate API.</p>
+    ) -> RusotoFuture<ListViolationEventsResponse, ListViolationEventsError> {
+        (**self).list_violation_events(input)
+    }
     fn register_ca_certificate(
         &self,
         input: RegisterCACertificateRequest,
-    ) -> RusotoFuture<RegisterCACertificateResponse, RegisterCACertificateError>;
-
-    /// <p>Registers a device certificate with AWS IoT. If you have more than one CA certificate that has the same subject field, you must specify the CA certificate that was used to sign the device certificate being registered.</p>
+    ) -> RusotoFuture<RegisterCACertificateResponse, RegisterCACertificateError> {
+        (**self).register_ca_certificate(input)
+    }
     fn register_certificate(
         &self,
         input: RegisterCertificateRequest,
-    ) -> RusotoFuture<RegisterCertificateResponse, RegisterCertificateError>;
-
-    /// <p>Provisions a thing.</p>
+    ) -> RusotoFuture<RegisterCertificateResponse, RegisterCertificateError> {
+        (**self).register_certificate(input)
+    }
     fn register_thing(
         &self,
         input: RegisterThingRequest,
-    ) -> RusotoFuture<RegisterThingResponse, RegisterThingError>;
-
-    /// <p>Rejects a pending certificate transfer. After AWS IoT rejects a certificate transfer, the certificate status changes from <b>PENDING_TRANSFER</b> to <b>INACTIVE</b>.</p> <p>To check for pending certificate transfers, call <a>ListCertificates</a> to enumerate your certificates.</p> <p>This operation can only be called by the transfer destination. After it is called, the certificate will be returned to the source's account in the INACTIVE state.</p>
+    ) -> RusotoFuture<RegisterThingResponse, RegisterThingError> {
+        (**self).register_thing(input)
+    }
     fn reject_certificate_transfer(
         &self,
         input: RejectCertificateTransferRequest,
-    ) -> RusotoFuture<(), RejectCertificateTransferError>;
-
-    /// <p>Removes the given thing from the billing group.</p>
+    ) -> RusotoFuture<(), RejectCertificateTransferError> {
+        (**self).reject_certificate_transfer(input)
+    }
     fn remove_thing_from_billing_group(
         &self,
         input: RemoveThingFromBillingGroupRequest,
-    ) -> RusotoFuture<RemoveThingFromBillingGroupResponse, RemoveThingFromBillingGroupError>;
-
-    /// <p>Remove the specified thing from the specified group.</p>
+    ) -> RusotoFuture<RemoveThingFromBillingGroupResponse, RemoveThingFromBillingGroupError> {
+        (**self).remove_thing_from_billing_group(input)
+    }
     fn remove_thing_from_thing_group(
         &self,
         input: RemoveThingFromThingGroupRequest,
-    ) -> RusotoFuture<RemoveThingFromThingGroupResponse, RemoveThingFromThingGroupError>;
-
-    /// <p>Replaces the rule. You must specify all parameters for the new rule. Creating rules is an administrator-level action. Any user who has permission to create rules will be able to access data processed by the rule.</p>
+    ) -> RusotoFuture<RemoveThingFromThingGroupResponse, RemoveThingFromThingGroupError> {
+        (**self).remove_thing_from_thing_group(input)
+    }
     fn replace_topic_rule(
         &self,
         input: ReplaceTopicRuleRequest,
-    ) -> RusotoFuture<(), ReplaceTopicRuleError>;
-
-    /// <p>The query search index.</p>
+    ) -> RusotoFuture<(), ReplaceTopicRuleError> {
+        (**self).replace_topic_rule(input)
+    }
     fn search_index(
         &self,
         input: SearchIndexRequest,
-    ) -> RusotoFuture<SearchIndexResponse, SearchIndexError>;
-
-    /// <p>Sets the default authorizer. This will be used if a websocket connection is made without specifying an authorizer.</p>
+    ) -> RusotoFuture<SearchIndexResponse, SearchIndexError> {
+        (**self).search_index(input)
+    }
     fn set_default_authorizer(
         &self,
         input: SetDefaultAuthorizerRequest,
-    ) -> RusotoFuture<SetDefaultAuthorizerResponse, SetDefaultAuthorizerError>;
-
-    /// <p>Sets the specified version of the specified policy as the policy's default (operative) version. This action affects all certificates to which the policy is attached. To list the principals the policy is attached to, use the ListPrincipalPolicy API.</p>
+    ) -> RusotoFuture<SetDefaultAuthorizerResponse, SetDefaultAuthorizerError> {
+        (**self).set_default_authorizer(input)
+    }
     fn set_default_policy_version(
         &self,
         input: SetDefaultPolicyVersionRequest,
-    ) -> RusotoFuture<(), SetDefaultPolicyVersionError>;
-
-    /// <p>Sets the logging options.</p> <p>NOTE: use of this command is not recommended. Use <code>SetV2LoggingOptions</code> instead.</p>
+    ) -> RusotoFuture<(), SetDefaultPolicyVersionError> {
+        (**self).set_default_policy_version(input)
+    }
     fn set_logging_options(
         &self,
         input: SetLoggingOptionsRequest,
-    ) -> RusotoFuture<(), SetLoggingOptionsError>;
-
-    /// <p>Sets the logging level.</p>
+    ) -> RusotoFuture<(), SetLoggingOptionsError> {
+        (**self).set_logging_options(input)
+    }
     fn set_v2_logging_level(
         &self,
         input: SetV2LoggingLevelRequest,
-    ) -> RusotoFuture<(), SetV2LoggingLevelError>;
-
-    /// <p>Sets the logging options for the V2 logging service.</p>
+    ) -> RusotoFuture<(), SetV2LoggingLevelError> {
+        (**self).set_v2_logging_level(input)
+    }
     fn set_v2_logging_options(
         &self,
         input: SetV2LoggingOptionsRequest,
-    ) -> RusotoFuture<(), SetV2LoggingOptionsError>;
-
-    /// <p>Starts a task that applies a set of mitigation actions to the specified target.</p>
+    ) -> RusotoFuture<(), SetV2LoggingOptionsError> {
+        (**self).set_v2_logging_options(input)
+    }
     fn start_audit_mitigation_actions_task(
         &self,
         input: StartAuditMitigationActionsTaskRequest,
-    ) -> RusotoFuture<StartAuditMitigationActionsTaskResponse, StartAuditMitigationActionsTaskError>;
-
-    /// <p>Starts an on-demand Device Defender audit.</p>
+    ) -> RusotoFuture<StartAuditMitigationActionsTaskResponse, StartAuditMitigationActionsTaskError>
+    {
+        (**self).start_audit_mitigation_actions_task(input)
+    }
     fn start_on_demand_audit_task(
         &self,
         input: StartOnDemandAuditTaskRequest,
-    ) -> RusotoFuture<StartOnDemandAuditTaskResponse, StartOnDemandAuditTaskError>;
-
-    /// <p>Creates a bulk thing provisioning task.</p>
+    ) -> RusotoFuture<StartOnDemandAuditTaskResponse, StartOnDemandAuditTaskError> {
+        (**self).start_on_demand_audit_task(input)
+    }
     fn start_thing_registration_task(
         &self,
         input: StartThingRegistrationTaskRequest,
-    ) -> RusotoFuture<StartThingRegistrationTaskResponse, StartThingRegistrationTaskError>;
-
-    /// <p>Cancels a bulk thing provisioning task.</p>
+    ) -> RusotoFuture<StartThingRegistrationTaskResponse, StartThingRegistrationTaskError> {
+        (**self).start_thing_registration_task(input)
+    }
     fn stop_thing_registration_task(
         &self,
         input: StopThingRegistrationTaskRequest,
-    ) -> RusotoFuture<StopThingRegistrationTaskResponse, StopThingRegistrationTaskError>;
-
-    /// <p>Adds to or modifies the tags of the given resource. Tags are metadata which can be used to manage a resource.</p>
+    ) -> RusotoFuture<StopThingRegistrationTaskResponse, StopThingRegistrationTaskError> {
+        (**self).stop_thing_registration_task(input)
+    }
     fn tag_resource(
         &self,
         input: TagResourceRequest,
-    ) -> RusotoFuture<TagResourceResponse, TagResourceError>;
-
-    /// <p>Tests if a specified principal is authorized to perform an AWS IoT action on a specified resource. Use this to test and debug the authorization behavior of devices that connect to the AWS IoT device gateway.</p>
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
     fn test_authorization(
         &self,
         input: TestAuthorizationRequest,
-    ) -> RusotoFuture<TestAuthorizationResponse, TestAuthorizationError>;
-
-    /// <p>Tests a custom authorization behavior by invoking a specified custom authorizer. Use this to test and debug the custom authorization behavior of devices that connect to the AWS IoT device gateway.</p>
+    ) -> RusotoFuture<TestAuthorizationResponse, TestAuthorizationError> {
+        (**self).test_authorization(input)
+    }
     fn test_invoke_authorizer(
         &self,
         input: TestInvokeAuthorizerRequest,
-    ) -> RusotoFuture<TestInvokeAuthorizerResponse, TestInvokeAuthorizerError>;
-
-    /// <p>Transfers the specified certificate to the specified AWS account.</p> <p>You can cancel the transfer until it is acknowledged by the recipient.</p> <p>No notification is sent to the transfer destination's account. It is up to the caller to notify the transfer target.</p> <p>The certificate being transferred must not be in the ACTIVE state. You can use the UpdateCertificate API to deactivate it.</p> <p>The certificate must not have any policies attached to it. You can use the DetachPrincipalPolicy API to detach them.</p>
+    ) -> RusotoFuture<TestInvokeAuthorizerResponse, TestInvokeAuthorizerError> {
+        (**self).test_invoke_authorizer(input)
+    }
     fn transfer_certificate(
         &self,
         input: TransferCertificateRequest,
-    ) -> RusotoFuture<TransferCertificateResponse, TransferCertificateError>;
-
-    /// <p>Removes the given tags (metadata) from the resource.</p>
+    ) -> RusotoFuture<TransferCertificateResponse, TransferCertificateError> {
+        (**self).transfer_certificate(input)
+    }
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
-    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError>;
-
-    /// <p>Configures or reconfigures the Device Defender audit settings for this account. Settings include how audit notifications are sent and which audit checks are enabled or disabled.</p>
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
     fn update_account_audit_configuration(
         &self,
         input: UpdateAccountAuditConfigurationRequest,
-    ) -> RusotoFuture<UpdateAccountAuditConfigurationResponse, UpdateAccountAuditConfigurationError>;
-
-    /// <p>Updates an authorizer.</p>
+    ) -> RusotoFuture<UpdateAccountAuditConfigurationResponse, UpdateAccountAuditConfigurationError>
+    {
+        (**self).update_account_audit_configuration(input)
+    }
     fn update_authorizer(
         &self,
         input: UpdateAuthorizerRequest,
-    ) -> RusotoFuture<UpdateAuthorizerResponse, UpdateAuthorizerError>;
-
-    /// <p>Updates information about the billing group.</p>
+    ) -> RusotoFuture<UpdateAuthorizerResponse, UpdateAuthorizerError> {
+        (**self).update_authorizer(input)
+    }
     fn update_billing_group(
         &self,
         input: UpdateBillingGroupRequest,
-    ) -> RusotoFuture<UpdateBillingGroupResponse, UpdateBillingGroupError>;
-
-    /// <p>Updates a registered CA certificate.</p>
+    ) -> RusotoFuture<UpdateBillingGroupResponse, UpdateBillingGroupError> {
+        (**self).update_billing_group(input)
+    }
     fn update_ca_certificate(
         &self,
         input: UpdateCACertificateRequest,
-    ) -> RusotoFuture<(), UpdateCACertificateError>;
-
-    /// <p>Updates the status of the specified certificate. This operation is idempotent.</p> <p>Moving a certificate from the ACTIVE state (including REVOKED) will not disconnect currently connected devices, but these devices will be unable to reconnect.</p> <p>The ACTIVE state is required to authenticate devices connecting to AWS IoT using a certificate.</p>
+    ) -> RusotoFuture<(), UpdateCACertificateError> {
+        (**self).update_ca_certificate(input)
+    }
     fn update_certificate(
         &self,
         input: UpdateCertificateRequest,
-    ) -> RusotoFuture<(), UpdateCertificateError>;
-
-    /// <p>Updates a dynamic thing group.</p>
+    ) -> RusotoFuture<(), UpdateCertificateError> {
+        (**self).update_certificate(input)
+    }
     fn update_dynamic_thing_group(
         &self,
         input: UpdateDynamicThingGroupRequest,
-    ) -> RusotoFuture<UpdateDynamicThingGroupResponse, UpdateDynamicThingGroupError>;
-
-    /// <p>Updates the event configurations.</p>
+    ) -> RusotoFuture<UpdateDynamicThingGroupResponse, UpdateDynamicThingGroupError> {
+        (**self).update_dynamic_thing_group(input)
+    }
     fn update_event_configurations(
         &self,
         input: UpdateEventConfigurationsRequest,
-    ) -> RusotoFuture<UpdateEventConfigurationsResponse, UpdateEventConfigurationsError>;
-
-    /// <p>Updates the search configuration.</p>
+    ) -> RusotoFuture<UpdateEventConfigurationsResponse, UpdateEventConfigurationsError> {
+        (**self).update_event_configurations(input)
+    }
     fn update_indexing_configuration(
         &self,
         input: UpdateIndexingConfigurationRequest,
-    ) -> RusotoFuture<UpdateIndexingConfigurationResponse, UpdateIndexingConfigurationError>;
-
-    /// <p>Updates supported fields of the specified job.</p>
-    fn update_job(&self, input: UpdateJobRequest) -> RusotoFuture<(), UpdateJobError>;
-
-    /// <p>Updates the definition for the specified mitigation action.</p>
+    ) -> RusotoFuture<UpdateIndexingConfigurationResponse, UpdateIndexingConfigurationError> {
+        (**self).update_indexing_configuration(input)
+    }
+    fn update_job(&self, input: UpdateJobRequest) -> RusotoFuture<(), UpdateJobError> {
+        (**self).update_job(input)
+    }
     fn update_mitigation_action(
         &self,
         input: UpdateMitigationActionRequest,
-    ) -> RusotoFuture<UpdateMitigationActionResponse, UpdateMitigationActionError>;
-
-    /// <p>Updates a role alias.</p>
+    ) -> RusotoFuture<UpdateMitigationActionResponse, UpdateMitigationActionError> {
+        (**self).update_mitigation_action(input)
+    }
     fn update_role_alias(
         &self,
         input: UpdateRoleAliasRequest,
-    ) -> RusotoFuture<UpdateRoleAliasResponse, UpdateRoleAliasError>;
-
-    /// <p>Updates a scheduled audit, including which checks are performed and how often the audit takes place.</p>
+    ) -> RusotoFuture<UpdateRoleAliasResponse, UpdateRoleAliasError> {
+        (**self).update_role_alias(input)
+    }
     fn update_scheduled_audit(
         &self,
         input: UpdateScheduledAuditRequest,
-    ) -> RusotoFuture<UpdateScheduledAuditResponse, UpdateScheduledAuditError>;
-
-    /// <p>Updates a Device Defender security profile.</p>
+    ) -> RusotoFuture<UpdateScheduledAuditResponse, UpdateScheduledAuditError> {
+        (**self).update_scheduled_audit(input)
+    }
     fn update_security_profile(
         &self,
         input: UpdateSecurityProfileRequest,
-    ) -> RusotoFuture<UpdateSecurityProfileResponse, UpdateSecurityProfileError>;
-
-    /// <p>Updates an existing stream. The stream version will be incremented by one.</p>
+    ) -> RusotoFuture<UpdateSecurityProfileResponse, UpdateSecurityProfileError> {
+        (**self).update_security_profile(input)
+    }
     fn update_stream(
         &self,
         input: UpdateStreamRequest,
-    ) -> RusotoFuture<UpdateStreamResponse, UpdateStreamError>;
-
-    /// <p>Updates the data for a thing.</p>
+    ) -> RusotoFuture<UpdateStreamResponse, UpdateStreamError> {
+        (**self).update_stream(input)
+    }
     fn update_thing(
         &self,
         input: UpdateThingRequest,
-    ) -> RusotoFuture<UpdateThingResponse, UpdateThingError>;
-
-    /// <p>Update a thing group.</p>
+    ) -> RusotoFuture<UpdateThingResponse, UpdateThingError> {
+        (**self).update_thing(input)
+    }
     fn update_thing_group(
         &self,
         input: UpdateThingGroupRequest,
-    ) -> RusotoFuture<UpdateThingGroupResponse, UpdateThingGroupError>;
-
-    /// <p>Updates the groups to which the thing belongs.</p>
+    ) -> RusotoFuture<UpdateThingGroupResponse, UpdateThingGroupError> {
+        (**self).update_thing_group(input)
+    }
     fn update_thing_groups_for_thing(
         &self,
         input: UpdateThingGroupsForThingRequest,
-    ) -> RusotoFuture<UpdateThingGroupsForThingResponse, UpdateThingGroupsForThingError>;
-
-    /// <p>Validates a Device Defender security profile behaviors specification.</p>
+    ) -> RusotoFuture<UpdateThingGroupsForThingResponse, UpdateThingGroupsForThingError> {
+        (**self).update_thing_groups_for_thing(input)
+    }
     fn validate_security_profile_behaviors(
         &self,
         input: ValidateSecurityProfileBehaviorsRequest,
-    ) -> RusotoFuture<ValidateSecurityProfileBehaviorsResponse, ValidateSecurityProfileBehaviorsError>;
+    ) -> RusotoFuture<ValidateSecurityProfileBehaviorsResponse, ValidateSecurityProfileBehaviorsError>
+    {
+        (**self).validate_security_profile_behaviors(input)
+    }
 }
+/// The error codes AWS IoT retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS IoT API.
 #[derive(Clone)]
 pub struct IotClient {
@@ -19275,6 +31987,24 @@ impl IotClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_IOT`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> IotClient {
+        Self::new(region::Region::from_env_for_service("IOT"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `iot`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> IotClient {
+        Self::new_with_client(config.client().clone(), config.region_for("iot", "IOT"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -19293,7 +32023,49 @@ impl IotClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> IotClient {
-        IotClient { client, region }
+        IotClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "iot")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "iot", "execute-api", "IOT")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Iot` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Iot + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for IotClient {
+    fn service_name(&self) -> &'static str {
+        "AWS IoT"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "iot"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> IotClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS IoT 1-Click Devices Service clients.
+//!
+//! Makes a single read-only `ListDevices` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_iot1click_devices::{Iot1ClickDevices, Iot1ClickDevicesClient, ListDevicesRequest};
+
+fn main() {
+    let client = Iot1ClickDevicesClient::from_env();
+    match client.list_devices(ListDevicesRequest::default()).sync() {
+        Ok(_) => println!("ListDevices succeeded"),
+        Err(error) => {
+            eprintln!("ListDevices failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
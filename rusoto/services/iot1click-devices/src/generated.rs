@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -35,6 +36,38 @@ pub struct ClaimDevicesByClaimCodeRequest {
     pub claim_code: String,
 }
 
+impl ClaimDevicesByClaimCodeRequest {
+    /// Returns a builder for `ClaimDevicesByClaimCodeRequest`, checking its required members when built.
+    pub fn builder() -> ClaimDevicesByClaimCodeRequestBuilder {
+        ClaimDevicesByClaimCodeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ClaimDevicesByClaimCodeRequest`](struct.ClaimDevicesByClaimCodeRequest.html).
+#[derive(Default, Debug)]
+pub struct ClaimDevicesByClaimCodeRequestBuilder {
+    claim_code: Option<String>,
+}
+
+impl ClaimDevicesByClaimCodeRequestBuilder {
+    /// Sets the [`claim_code`](struct.ClaimDevicesByClaimCodeRequest.html#structfield.claim_code) member.
+    pub fn claim_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.claim_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `ClaimDevicesByClaimCodeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ClaimDevicesByClaimCodeRequest, ::rusoto_core::BuildError> {
+        Ok(ClaimDevicesByClaimCodeRequest {
+            claim_code: self
+                .claim_code
+                .ok_or(::rusoto_core::BuildError::missing("claim_code"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ClaimDevicesByClaimCodeResponse {
@@ -56,6 +89,36 @@ pub struct DescribeDeviceRequest {
     pub device_id: String,
 }
 
+impl DescribeDeviceRequest {
+    /// Returns a builder for `DescribeDeviceRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDeviceRequestBuilder {
+        DescribeDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDeviceRequest`](struct.DescribeDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDeviceRequestBuilder {
+    device_id: Option<String>,
+}
+
+impl DescribeDeviceRequestBuilder {
+    /// Sets the [`device_id`](struct.DescribeDeviceRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDeviceRequest {
+            device_id: self
+                .device_id
+                .ok_or(::rusoto_core::BuildError::missing("device_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDeviceResponse {
@@ -157,6 +220,46 @@ pub struct FinalizeDeviceClaimRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl FinalizeDeviceClaimRequest {
+    /// Returns a builder for `FinalizeDeviceClaimRequest`, checking its required members when built.
+    pub fn builder() -> FinalizeDeviceClaimRequestBuilder {
+        FinalizeDeviceClaimRequestBuilder::default()
+    }
+}
+
+/// Builds a [`FinalizeDeviceClaimRequest`](struct.FinalizeDeviceClaimRequest.html).
+#[derive(Default, Debug)]
+pub struct FinalizeDeviceClaimRequestBuilder {
+    device_id: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl FinalizeDeviceClaimRequestBuilder {
+    /// Sets the [`device_id`](struct.FinalizeDeviceClaimRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.FinalizeDeviceClaimRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `FinalizeDeviceClaimRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<FinalizeDeviceClaimRequest, ::rusoto_core::BuildError> {
+        Ok(FinalizeDeviceClaimRequest {
+            device_id: self
+                .device_id
+                .ok_or(::rusoto_core::BuildError::missing("device_id"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct FinalizeDeviceClaimResponse {
@@ -173,6 +276,38 @@ pub struct GetDeviceMethodsRequest {
     pub device_id: String,
 }
 
+impl GetDeviceMethodsRequest {
+    /// Returns a builder for `GetDeviceMethodsRequest`, checking its required members when built.
+    pub fn builder() -> GetDeviceMethodsRequestBuilder {
+        GetDeviceMethodsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDeviceMethodsRequest`](struct.GetDeviceMethodsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDeviceMethodsRequestBuilder {
+    device_id: Option<String>,
+}
+
+impl GetDeviceMethodsRequestBuilder {
+    /// Sets the [`device_id`](struct.GetDeviceMethodsRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDeviceMethodsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDeviceMethodsRequest, ::rusoto_core::BuildError> {
+        Ok(GetDeviceMethodsRequest {
+            device_id: self
+                .device_id
+                .ok_or(::rusoto_core::BuildError::missing("device_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDeviceMethodsResponse {
@@ -189,6 +324,38 @@ pub struct InitiateDeviceClaimRequest {
     pub device_id: String,
 }
 
+impl InitiateDeviceClaimRequest {
+    /// Returns a builder for `InitiateDeviceClaimRequest`, checking its required members when built.
+    pub fn builder() -> InitiateDeviceClaimRequestBuilder {
+        InitiateDeviceClaimRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InitiateDeviceClaimRequest`](struct.InitiateDeviceClaimRequest.html).
+#[derive(Default, Debug)]
+pub struct InitiateDeviceClaimRequestBuilder {
+    device_id: Option<String>,
+}
+
+impl InitiateDeviceClaimRequestBuilder {
+    /// Sets the [`device_id`](struct.InitiateDeviceClaimRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `InitiateDeviceClaimRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<InitiateDeviceClaimRequest, ::rusoto_core::BuildError> {
+        Ok(InitiateDeviceClaimRequest {
+            device_id: self
+                .device_id
+                .ok_or(::rusoto_core::BuildError::missing("device_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct InitiateDeviceClaimResponse {
@@ -213,6 +380,54 @@ pub struct InvokeDeviceMethodRequest {
     pub device_method_parameters: Option<String>,
 }
 
+impl InvokeDeviceMethodRequest {
+    /// Returns a builder for `InvokeDeviceMethodRequest`, checking its required members when built.
+    pub fn builder() -> InvokeDeviceMethodRequestBuilder {
+        InvokeDeviceMethodRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InvokeDeviceMethodRequest`](struct.InvokeDeviceMethodRequest.html).
+#[derive(Default, Debug)]
+pub struct InvokeDeviceMethodRequestBuilder {
+    device_id: Option<String>,
+    device_method: Option<DeviceMethod>,
+    device_method_parameters: Option<String>,
+}
+
+impl InvokeDeviceMethodRequestBuilder {
+    /// Sets the [`device_id`](struct.InvokeDeviceMethodRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_method`](struct.InvokeDeviceMethodRequest.html#structfield.device_method) member.
+    pub fn device_method<T: Into<DeviceMethod>>(mut self, value: T) -> Self {
+        self.device_method = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_method_parameters`](struct.InvokeDeviceMethodRequest.html#structfield.device_method_parameters) member.
+    pub fn device_method_parameters<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_method_parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `InvokeDeviceMethodRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<InvokeDeviceMethodRequest, ::rusoto_core::BuildError> {
+        Ok(InvokeDeviceMethodRequest {
+            device_id: self
+                .device_id
+                .ok_or(::rusoto_core::BuildError::missing("device_id"))?,
+            device_method: self.device_method,
+            device_method_parameters: self.device_method_parameters,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct InvokeDeviceMethodResponse {
@@ -231,7 +446,8 @@ pub struct ListDeviceEventsRequest {
     /// 2018-03-28T15:45:12.880Z
     /// </p>
     #[serde(rename = "FromTimeStamp")]
-    pub from_time_stamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::iso8601")]
+    pub from_time_stamp: EpochTimestamp,
     /// <p>The maximum number of results to return per request. If not set, a default value of
     /// 100 is used.</p>
     #[serde(rename = "MaxResults")]
@@ -245,7 +461,76 @@ pub struct ListDeviceEventsRequest {
     /// 2018-03-28T15:45:12.880Z
     /// </p>
     #[serde(rename = "ToTimeStamp")]
-    pub to_time_stamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::iso8601")]
+    pub to_time_stamp: EpochTimestamp,
+}
+
+impl ListDeviceEventsRequest {
+    /// Returns a builder for `ListDeviceEventsRequest`, checking its required members when built.
+    pub fn builder() -> ListDeviceEventsRequestBuilder {
+        ListDeviceEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDeviceEventsRequest`](struct.ListDeviceEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDeviceEventsRequestBuilder {
+    device_id: Option<String>,
+    from_time_stamp: Option<EpochTimestamp>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    to_time_stamp: Option<EpochTimestamp>,
+}
+
+impl ListDeviceEventsRequestBuilder {
+    /// Sets the [`device_id`](struct.ListDeviceEventsRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`from_time_stamp`](struct.ListDeviceEventsRequest.html#structfield.from_time_stamp) member.
+    pub fn from_time_stamp<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.from_time_stamp = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDeviceEventsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDeviceEventsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`to_time_stamp`](struct.ListDeviceEventsRequest.html#structfield.to_time_stamp) member.
+    pub fn to_time_stamp<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.to_time_stamp = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDeviceEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDeviceEventsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDeviceEventsRequest {
+            device_id: self
+                .device_id
+                .ok_or(::rusoto_core::BuildError::missing("device_id"))?,
+            from_time_stamp: self
+                .from_time_stamp
+                .ok_or(::rusoto_core::BuildError::missing("from_time_stamp"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            to_time_stamp: self
+                .to_time_stamp
+                .ok_or(::rusoto_core::BuildError::missing("to_time_stamp"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -279,6 +564,50 @@ pub struct ListDevicesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDevicesRequest {
+    /// Returns a builder for `ListDevicesRequest`, checking its required members when built.
+    pub fn builder() -> ListDevicesRequestBuilder {
+        ListDevicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDevicesRequest`](struct.ListDevicesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDevicesRequestBuilder {
+    device_type: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDevicesRequestBuilder {
+    /// Sets the [`device_type`](struct.ListDevicesRequest.html#structfield.device_type) member.
+    pub fn device_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDevicesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDevicesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDevicesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDevicesRequest, ::rusoto_core::BuildError> {
+        Ok(ListDevicesRequest {
+            device_type: self.device_type,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDevicesResponse {
@@ -299,6 +628,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -326,6 +687,46 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UnclaimDeviceRequest {
     /// <p>The unique identifier of the device.</p>
@@ -333,6 +734,36 @@ pub struct UnclaimDeviceRequest {
     pub device_id: String,
 }
 
+impl UnclaimDeviceRequest {
+    /// Returns a builder for `UnclaimDeviceRequest`, checking its required members when built.
+    pub fn builder() -> UnclaimDeviceRequestBuilder {
+        UnclaimDeviceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UnclaimDeviceRequest`](struct.UnclaimDeviceRequest.html).
+#[derive(Default, Debug)]
+pub struct UnclaimDeviceRequestBuilder {
+    device_id: Option<String>,
+}
+
+impl UnclaimDeviceRequestBuilder {
+    /// Sets the [`device_id`](struct.UnclaimDeviceRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UnclaimDeviceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UnclaimDeviceRequest, ::rusoto_core::BuildError> {
+        Ok(UnclaimDeviceRequest {
+            device_id: self
+                .device_id
+                .ok_or(::rusoto_core::BuildError::missing("device_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UnclaimDeviceResponse {
@@ -352,6 +783,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateDeviceStateRequest {
     /// <p>The unique identifier of the device.</p>
@@ -364,6 +835,46 @@ pub struct UpdateDeviceStateRequest {
     pub enabled: Option<bool>,
 }
 
+impl UpdateDeviceStateRequest {
+    /// Returns a builder for `UpdateDeviceStateRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDeviceStateRequestBuilder {
+        UpdateDeviceStateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDeviceStateRequest`](struct.UpdateDeviceStateRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDeviceStateRequestBuilder {
+    device_id: Option<String>,
+    enabled: Option<bool>,
+}
+
+impl UpdateDeviceStateRequestBuilder {
+    /// Sets the [`device_id`](struct.UpdateDeviceStateRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enabled`](struct.UpdateDeviceStateRequest.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDeviceStateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDeviceStateRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDeviceStateRequest {
+            device_id: self
+                .device_id
+                .ok_or(::rusoto_core::BuildError::missing("device_id"))?,
+            enabled: self.enabled,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDeviceStateResponse {}
@@ -1089,6 +1600,87 @@ pub trait Iot1ClickDevices {
         input: UpdateDeviceStateRequest,
     ) -> RusotoFuture<UpdateDeviceStateResponse, UpdateDeviceStateError>;
 }
+impl<T: ?Sized + Iot1ClickDevices> Iot1ClickDevices for ::std::sync::Arc<T> {
+    fn claim_devices_by_claim_code(
+        &self,
+        input: ClaimDevicesByClaimCodeRequest,
+    ) -> RusotoFuture<ClaimDevicesByClaimCodeResponse, ClaimDevicesByClaimCodeError> {
+        (**self).claim_devices_by_claim_code(input)
+    }
+    fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> RusotoFuture<DescribeDeviceResponse, DescribeDeviceError> {
+        (**self).describe_device(input)
+    }
+    fn finalize_device_claim(
+        &self,
+        input: FinalizeDeviceClaimRequest,
+    ) -> RusotoFuture<FinalizeDeviceClaimResponse, FinalizeDeviceClaimError> {
+        (**self).finalize_device_claim(input)
+    }
+    fn get_device_methods(
+        &self,
+        input: GetDeviceMethodsRequest,
+    ) -> RusotoFuture<GetDeviceMethodsResponse, GetDeviceMethodsError> {
+        (**self).get_device_methods(input)
+    }
+    fn initiate_device_claim(
+        &self,
+        input: InitiateDeviceClaimRequest,
+    ) -> RusotoFuture<InitiateDeviceClaimResponse, InitiateDeviceClaimError> {
+        (**self).initiate_device_claim(input)
+    }
+    fn invoke_device_method(
+        &self,
+        input: InvokeDeviceMethodRequest,
+    ) -> RusotoFuture<InvokeDeviceMethodResponse, InvokeDeviceMethodError> {
+        (**self).invoke_device_method(input)
+    }
+    fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> RusotoFuture<ListDeviceEventsResponse, ListDeviceEventsError> {
+        (**self).list_device_events(input)
+    }
+    fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> RusotoFuture<ListDevicesResponse, ListDevicesError> {
+        (**self).list_devices(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unclaim_device(
+        &self,
+        input: UnclaimDeviceRequest,
+    ) -> RusotoFuture<UnclaimDeviceResponse, UnclaimDeviceError> {
+        (**self).unclaim_device(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_device_state(
+        &self,
+        input: UpdateDeviceStateRequest,
+    ) -> RusotoFuture<UpdateDeviceStateResponse, UpdateDeviceStateError> {
+        (**self).update_device_state(input)
+    }
+}
+/// The error codes AWS IoT 1-Click Devices Service retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS IoT 1-Click Devices Service API.
 #[derive(Clone)]
 pub struct Iot1ClickDevicesClient {
@@ -1104,6 +1696,27 @@ impl Iot1ClickDevicesClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_IOT1CLICK_DEVICES`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> Iot1ClickDevicesClient {
+        Self::new(region::Region::from_env_for_service("IOT1CLICK_DEVICES"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `devices.iot1click`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> Iot1ClickDevicesClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("devices.iot1click", "IOT1CLICK_DEVICES"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1122,7 +1735,54 @@ impl Iot1ClickDevicesClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> Iot1ClickDevicesClient {
-        Iot1ClickDevicesClient { client, region }
+        Iot1ClickDevicesClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "devices.iot1click")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "devices.iot1click",
+            "iot1click",
+            "IOT1CLICK_DEVICES",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Iot1ClickDevices` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Iot1ClickDevices + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for Iot1ClickDevicesClient {
+    fn service_name(&self) -> &'static str {
+        "AWS IoT 1-Click Devices Service"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "devices.iot1click"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> Iot1ClickDevicesClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateDeviceWithPlacementRequest {
@@ -40,6 +41,68 @@ pub struct AssociateDeviceWithPlacementRequest {
     pub project_name: String,
 }
 
+impl AssociateDeviceWithPlacementRequest {
+    /// Returns a builder for `AssociateDeviceWithPlacementRequest`, checking its required members when built.
+    pub fn builder() -> AssociateDeviceWithPlacementRequestBuilder {
+        AssociateDeviceWithPlacementRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateDeviceWithPlacementRequest`](struct.AssociateDeviceWithPlacementRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateDeviceWithPlacementRequestBuilder {
+    device_id: Option<String>,
+    device_template_name: Option<String>,
+    placement_name: Option<String>,
+    project_name: Option<String>,
+}
+
+impl AssociateDeviceWithPlacementRequestBuilder {
+    /// Sets the [`device_id`](struct.AssociateDeviceWithPlacementRequest.html#structfield.device_id) member.
+    pub fn device_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_template_name`](struct.AssociateDeviceWithPlacementRequest.html#structfield.device_template_name) member.
+    pub fn device_template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_template_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_name`](struct.AssociateDeviceWithPlacementRequest.html#structfield.placement_name) member.
+    pub fn placement_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.placement_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.AssociateDeviceWithPlacementRequest.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateDeviceWithPlacementRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateDeviceWithPlacementRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateDeviceWithPlacementRequest {
+            device_id: self
+                .device_id
+                .ok_or(::rusoto_core::BuildError::missing("device_id"))?,
+            device_template_name: self
+                .device_template_name
+                .ok_or(::rusoto_core::BuildError::missing("device_template_name"))?,
+            placement_name: self
+                .placement_name
+                .ok_or(::rusoto_core::BuildError::missing("placement_name"))?,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateDeviceWithPlacementResponse {}
@@ -58,6 +121,57 @@ pub struct CreatePlacementRequest {
     pub project_name: String,
 }
 
+impl CreatePlacementRequest {
+    /// Returns a builder for `CreatePlacementRequest`, checking its required members when built.
+    pub fn builder() -> CreatePlacementRequestBuilder {
+        CreatePlacementRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePlacementRequest`](struct.CreatePlacementRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePlacementRequestBuilder {
+    attributes: Option<::std::collections::HashMap<String, String>>,
+    placement_name: Option<String>,
+    project_name: Option<String>,
+}
+
+impl CreatePlacementRequestBuilder {
+    /// Sets the [`attributes`](struct.CreatePlacementRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_name`](struct.CreatePlacementRequest.html#structfield.placement_name) member.
+    pub fn placement_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.placement_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.CreatePlacementRequest.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePlacementRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePlacementRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePlacementRequest {
+            attributes: self.attributes,
+            placement_name: self
+                .placement_name
+                .ok_or(::rusoto_core::BuildError::missing("placement_name"))?,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreatePlacementResponse {}
@@ -81,6 +195,60 @@ pub struct CreateProjectRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateProjectRequest {
+    /// Returns a builder for `CreateProjectRequest`, checking its required members when built.
+    pub fn builder() -> CreateProjectRequestBuilder {
+        CreateProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateProjectRequest`](struct.CreateProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateProjectRequestBuilder {
+    description: Option<String>,
+    placement_template: Option<PlacementTemplate>,
+    project_name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateProjectRequestBuilder {
+    /// Sets the [`description`](struct.CreateProjectRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_template`](struct.CreateProjectRequest.html#structfield.placement_template) member.
+    pub fn placement_template<T: Into<PlacementTemplate>>(mut self, value: T) -> Self {
+        self.placement_template = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.CreateProjectRequest.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateProjectRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateProjectRequest, ::rusoto_core::BuildError> {
+        Ok(CreateProjectRequest {
+            description: self.description,
+            placement_template: self.placement_template,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateProjectResponse {}
@@ -95,6 +263,46 @@ pub struct DeletePlacementRequest {
     pub project_name: String,
 }
 
+impl DeletePlacementRequest {
+    /// Returns a builder for `DeletePlacementRequest`, checking its required members when built.
+    pub fn builder() -> DeletePlacementRequestBuilder {
+        DeletePlacementRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePlacementRequest`](struct.DeletePlacementRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePlacementRequestBuilder {
+    placement_name: Option<String>,
+    project_name: Option<String>,
+}
+
+impl DeletePlacementRequestBuilder {
+    /// Sets the [`placement_name`](struct.DeletePlacementRequest.html#structfield.placement_name) member.
+    pub fn placement_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.placement_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.DeletePlacementRequest.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePlacementRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePlacementRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePlacementRequest {
+            placement_name: self
+                .placement_name
+                .ok_or(::rusoto_core::BuildError::missing("placement_name"))?,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeletePlacementResponse {}
@@ -106,6 +314,36 @@ pub struct DeleteProjectRequest {
     pub project_name: String,
 }
 
+impl DeleteProjectRequest {
+    /// Returns a builder for `DeleteProjectRequest`, checking its required members when built.
+    pub fn builder() -> DeleteProjectRequestBuilder {
+        DeleteProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteProjectRequest`](struct.DeleteProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteProjectRequestBuilder {
+    project_name: Option<String>,
+}
+
+impl DeleteProjectRequestBuilder {
+    /// Sets the [`project_name`](struct.DeleteProjectRequest.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteProjectRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteProjectRequest {
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteProjectResponse {}
@@ -120,6 +358,48 @@ pub struct DescribePlacementRequest {
     pub project_name: String,
 }
 
+impl DescribePlacementRequest {
+    /// Returns a builder for `DescribePlacementRequest`, checking its required members when built.
+    pub fn builder() -> DescribePlacementRequestBuilder {
+        DescribePlacementRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePlacementRequest`](struct.DescribePlacementRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribePlacementRequestBuilder {
+    placement_name: Option<String>,
+    project_name: Option<String>,
+}
+
+impl DescribePlacementRequestBuilder {
+    /// Sets the [`placement_name`](struct.DescribePlacementRequest.html#structfield.placement_name) member.
+    pub fn placement_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.placement_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.DescribePlacementRequest.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePlacementRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePlacementRequest, ::rusoto_core::BuildError> {
+        Ok(DescribePlacementRequest {
+            placement_name: self
+                .placement_name
+                .ok_or(::rusoto_core::BuildError::missing("placement_name"))?,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribePlacementResponse {
@@ -135,6 +415,36 @@ pub struct DescribeProjectRequest {
     pub project_name: String,
 }
 
+impl DescribeProjectRequest {
+    /// Returns a builder for `DescribeProjectRequest`, checking its required members when built.
+    pub fn builder() -> DescribeProjectRequestBuilder {
+        DescribeProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeProjectRequest`](struct.DescribeProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeProjectRequestBuilder {
+    project_name: Option<String>,
+}
+
+impl DescribeProjectRequestBuilder {
+    /// Sets the [`project_name`](struct.DescribeProjectRequest.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeProjectRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeProjectRequest {
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeProjectResponse {
@@ -169,6 +479,59 @@ pub struct DisassociateDeviceFromPlacementRequest {
     pub project_name: String,
 }
 
+impl DisassociateDeviceFromPlacementRequest {
+    /// Returns a builder for `DisassociateDeviceFromPlacementRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateDeviceFromPlacementRequestBuilder {
+        DisassociateDeviceFromPlacementRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateDeviceFromPlacementRequest`](struct.DisassociateDeviceFromPlacementRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateDeviceFromPlacementRequestBuilder {
+    device_template_name: Option<String>,
+    placement_name: Option<String>,
+    project_name: Option<String>,
+}
+
+impl DisassociateDeviceFromPlacementRequestBuilder {
+    /// Sets the [`device_template_name`](struct.DisassociateDeviceFromPlacementRequest.html#structfield.device_template_name) member.
+    pub fn device_template_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_template_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_name`](struct.DisassociateDeviceFromPlacementRequest.html#structfield.placement_name) member.
+    pub fn placement_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.placement_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.DisassociateDeviceFromPlacementRequest.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateDeviceFromPlacementRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateDeviceFromPlacementRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociateDeviceFromPlacementRequest {
+            device_template_name: self
+                .device_template_name
+                .ok_or(::rusoto_core::BuildError::missing("device_template_name"))?,
+            placement_name: self
+                .placement_name
+                .ok_or(::rusoto_core::BuildError::missing("placement_name"))?,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateDeviceFromPlacementResponse {}
@@ -183,6 +546,48 @@ pub struct GetDevicesInPlacementRequest {
     pub project_name: String,
 }
 
+impl GetDevicesInPlacementRequest {
+    /// Returns a builder for `GetDevicesInPlacementRequest`, checking its required members when built.
+    pub fn builder() -> GetDevicesInPlacementRequestBuilder {
+        GetDevicesInPlacementRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDevicesInPlacementRequest`](struct.GetDevicesInPlacementRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDevicesInPlacementRequestBuilder {
+    placement_name: Option<String>,
+    project_name: Option<String>,
+}
+
+impl GetDevicesInPlacementRequestBuilder {
+    /// Sets the [`placement_name`](struct.GetDevicesInPlacementRequest.html#structfield.placement_name) member.
+    pub fn placement_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.placement_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.GetDevicesInPlacementRequest.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDevicesInPlacementRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDevicesInPlacementRequest, ::rusoto_core::BuildError> {
+        Ok(GetDevicesInPlacementRequest {
+            placement_name: self
+                .placement_name
+                .ok_or(::rusoto_core::BuildError::missing("placement_name"))?,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDevicesInPlacementResponse {
@@ -206,6 +611,52 @@ pub struct ListPlacementsRequest {
     pub project_name: String,
 }
 
+impl ListPlacementsRequest {
+    /// Returns a builder for `ListPlacementsRequest`, checking its required members when built.
+    pub fn builder() -> ListPlacementsRequestBuilder {
+        ListPlacementsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPlacementsRequest`](struct.ListPlacementsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPlacementsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    project_name: Option<String>,
+}
+
+impl ListPlacementsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListPlacementsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPlacementsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.ListPlacementsRequest.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPlacementsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPlacementsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPlacementsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPlacementsResponse {
@@ -230,6 +681,42 @@ pub struct ListProjectsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListProjectsRequest {
+    /// Returns a builder for `ListProjectsRequest`, checking its required members when built.
+    pub fn builder() -> ListProjectsRequestBuilder {
+        ListProjectsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListProjectsRequest`](struct.ListProjectsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListProjectsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListProjectsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListProjectsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListProjectsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListProjectsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListProjectsRequest, ::rusoto_core::BuildError> {
+        Ok(ListProjectsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListProjectsResponse {
@@ -249,6 +736,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -267,7 +786,8 @@ pub struct PlacementDescription {
     pub attributes: ::std::collections::HashMap<String, String>,
     /// <p>The date when the placement was initially created, in UNIX epoch time format.</p>
     #[serde(rename = "createdDate")]
-    pub created_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub created_date: EpochTimestamp,
     /// <p>The name of the placement.</p>
     #[serde(rename = "placementName")]
     pub placement_name: String,
@@ -276,7 +796,8 @@ pub struct PlacementDescription {
     pub project_name: String,
     /// <p>The date when the placement was last updated, in UNIX epoch time format. If the placement was not updated, then <code>createdDate</code> and <code>updatedDate</code> are the same.</p>
     #[serde(rename = "updatedDate")]
-    pub updated_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub updated_date: EpochTimestamp,
 }
 
 /// <p>An object providing summary information for a particular placement.</p>
@@ -285,7 +806,8 @@ pub struct PlacementDescription {
 pub struct PlacementSummary {
     /// <p>The date when the placement was originally created, in UNIX epoch time format.</p>
     #[serde(rename = "createdDate")]
-    pub created_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub created_date: EpochTimestamp,
     /// <p>The name of the placement being summarized.</p>
     #[serde(rename = "placementName")]
     pub placement_name: String,
@@ -294,7 +816,8 @@ pub struct PlacementSummary {
     pub project_name: String,
     /// <p>The date when the placement was last updated, in UNIX epoch time format. If the placement was not updated, then <code>createdDate</code> and <code>updatedDate</code> are the same.</p>
     #[serde(rename = "updatedDate")]
-    pub updated_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub updated_date: EpochTimestamp,
 }
 
 /// <p>An object defining the template for a placement.</p>
@@ -320,7 +843,8 @@ pub struct ProjectDescription {
     pub arn: Option<String>,
     /// <p>The date when the project was originally created, in UNIX epoch time format.</p>
     #[serde(rename = "createdDate")]
-    pub created_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub created_date: EpochTimestamp,
     /// <p>The description of the project.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -338,7 +862,8 @@ pub struct ProjectDescription {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date when the project was last updated, in UNIX epoch time format. If the project was not updated, then <code>createdDate</code> and <code>updatedDate</code> are the same.</p>
     #[serde(rename = "updatedDate")]
-    pub updated_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub updated_date: EpochTimestamp,
 }
 
 /// <p>An object providing summary information for a particular project for an associated AWS account and region.</p>
@@ -351,7 +876,8 @@ pub struct ProjectSummary {
     pub arn: Option<String>,
     /// <p>The date when the project was originally created, in UNIX epoch time format.</p>
     #[serde(rename = "createdDate")]
-    pub created_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub created_date: EpochTimestamp,
     /// <p>The name of the project being summarized.</p>
     #[serde(rename = "projectName")]
     pub project_name: String,
@@ -361,7 +887,8 @@ pub struct ProjectSummary {
     pub tags: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date when the project was last updated, in UNIX epoch time format. If the project was not updated, then <code>createdDate</code> and <code>updatedDate</code> are the same.</p>
     #[serde(rename = "updatedDate")]
-    pub updated_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub updated_date: EpochTimestamp,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -374,6 +901,46 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -388,6 +955,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -406,6 +1013,57 @@ pub struct UpdatePlacementRequest {
     pub project_name: String,
 }
 
+impl UpdatePlacementRequest {
+    /// Returns a builder for `UpdatePlacementRequest`, checking its required members when built.
+    pub fn builder() -> UpdatePlacementRequestBuilder {
+        UpdatePlacementRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePlacementRequest`](struct.UpdatePlacementRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdatePlacementRequestBuilder {
+    attributes: Option<::std::collections::HashMap<String, String>>,
+    placement_name: Option<String>,
+    project_name: Option<String>,
+}
+
+impl UpdatePlacementRequestBuilder {
+    /// Sets the [`attributes`](struct.UpdatePlacementRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_name`](struct.UpdatePlacementRequest.html#structfield.placement_name) member.
+    pub fn placement_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.placement_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.UpdatePlacementRequest.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePlacementRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdatePlacementRequest, ::rusoto_core::BuildError> {
+        Ok(UpdatePlacementRequest {
+            attributes: self.attributes,
+            placement_name: self
+                .placement_name
+                .ok_or(::rusoto_core::BuildError::missing("placement_name"))?,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdatePlacementResponse {}
@@ -425,6 +1083,52 @@ pub struct UpdateProjectRequest {
     pub project_name: String,
 }
 
+impl UpdateProjectRequest {
+    /// Returns a builder for `UpdateProjectRequest`, checking its required members when built.
+    pub fn builder() -> UpdateProjectRequestBuilder {
+        UpdateProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateProjectRequest`](struct.UpdateProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateProjectRequestBuilder {
+    description: Option<String>,
+    placement_template: Option<PlacementTemplate>,
+    project_name: Option<String>,
+}
+
+impl UpdateProjectRequestBuilder {
+    /// Sets the [`description`](struct.UpdateProjectRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`placement_template`](struct.UpdateProjectRequest.html#structfield.placement_template) member.
+    pub fn placement_template<T: Into<PlacementTemplate>>(mut self, value: T) -> Self {
+        self.placement_template = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_name`](struct.UpdateProjectRequest.html#structfield.project_name) member.
+    pub fn project_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateProjectRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateProjectRequest {
+            description: self.description,
+            placement_template: self.placement_template,
+            project_name: self
+                .project_name
+                .ok_or(::rusoto_core::BuildError::missing("project_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateProjectResponse {}
@@ -1311,6 +2015,112 @@ pub trait Iot1ClickProjects {
         input: UpdateProjectRequest,
     ) -> RusotoFuture<UpdateProjectResponse, UpdateProjectError>;
 }
+impl<T: ?Sized + Iot1ClickProjects> Iot1ClickProjects for ::std::sync::Arc<T> {
+    fn associate_device_with_placement(
+        &self,
+        input: AssociateDeviceWithPlacementRequest,
+    ) -> RusotoFuture<AssociateDeviceWithPlacementResponse, AssociateDeviceWithPlacementError> {
+        (**self).associate_device_with_placement(input)
+    }
+    fn create_placement(
+        &self,
+        input: CreatePlacementRequest,
+    ) -> RusotoFuture<CreatePlacementResponse, CreatePlacementError> {
+        (**self).create_placement(input)
+    }
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResponse, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn delete_placement(
+        &self,
+        input: DeletePlacementRequest,
+    ) -> RusotoFuture<DeletePlacementResponse, DeletePlacementError> {
+        (**self).delete_placement(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResponse, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn describe_placement(
+        &self,
+        input: DescribePlacementRequest,
+    ) -> RusotoFuture<DescribePlacementResponse, DescribePlacementError> {
+        (**self).describe_placement(input)
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResponse, DescribeProjectError> {
+        (**self).describe_project(input)
+    }
+    fn disassociate_device_from_placement(
+        &self,
+        input: DisassociateDeviceFromPlacementRequest,
+    ) -> RusotoFuture<DisassociateDeviceFromPlacementResponse, DisassociateDeviceFromPlacementError>
+    {
+        (**self).disassociate_device_from_placement(input)
+    }
+    fn get_devices_in_placement(
+        &self,
+        input: GetDevicesInPlacementRequest,
+    ) -> RusotoFuture<GetDevicesInPlacementResponse, GetDevicesInPlacementError> {
+        (**self).get_devices_in_placement(input)
+    }
+    fn list_placements(
+        &self,
+        input: ListPlacementsRequest,
+    ) -> RusotoFuture<ListPlacementsResponse, ListPlacementsError> {
+        (**self).list_placements(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResponse, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_placement(
+        &self,
+        input: UpdatePlacementRequest,
+    ) -> RusotoFuture<UpdatePlacementResponse, UpdatePlacementError> {
+        (**self).update_placement(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResponse, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+}
+/// The error codes AWS IoT 1-Click Projects retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the AWS IoT 1-Click Projects API.
 #[derive(Clone)]
 pub struct Iot1ClickProjectsClient {
@@ -1326,6 +2136,27 @@ impl Iot1ClickProjectsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_IOT1CLICK_PROJECTS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> Iot1ClickProjectsClient {
+        Self::new(region::Region::from_env_for_service("IOT1CLICK_PROJECTS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `projects.iot1click`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> Iot1ClickProjectsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("projects.iot1click", "IOT1CLICK_PROJECTS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1344,7 +2175,54 @@ impl Iot1ClickProjectsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> Iot1ClickProjectsClient {
-        Iot1ClickProjectsClient { client, region }
+        Iot1ClickProjectsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "projects.iot1click")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "projects.iot1click",
+            "iot1click",
+            "IOT1CLICK_PROJECTS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Iot1ClickProjects` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Iot1ClickProjects + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for Iot1ClickProjectsClient {
+    fn service_name(&self) -> &'static str {
+        "AWS IoT 1-Click Projects"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "projects.iot1click"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> Iot1ClickProjectsClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>An activity that adds other attributes based on existing attributes in the message.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -67,6 +68,46 @@ pub struct BatchPutMessageRequest {
     pub messages: Vec<Message>,
 }
 
+impl BatchPutMessageRequest {
+    /// Returns a builder for `BatchPutMessageRequest`, checking its required members when built.
+    pub fn builder() -> BatchPutMessageRequestBuilder {
+        BatchPutMessageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchPutMessageRequest`](struct.BatchPutMessageRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchPutMessageRequestBuilder {
+    channel_name: Option<String>,
+    messages: Option<Vec<Message>>,
+}
+
+impl BatchPutMessageRequestBuilder {
+    /// Sets the [`channel_name`](struct.BatchPutMessageRequest.html#structfield.channel_name) member.
+    pub fn channel_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.channel_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`messages`](struct.BatchPutMessageRequest.html#structfield.messages) member.
+    pub fn messages<T: Into<Vec<Message>>>(mut self, value: T) -> Self {
+        self.messages = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchPutMessageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchPutMessageRequest, ::rusoto_core::BuildError> {
+        Ok(BatchPutMessageRequest {
+            channel_name: self
+                .channel_name
+                .ok_or(::rusoto_core::BuildError::missing("channel_name"))?,
+            messages: self
+                .messages
+                .ok_or(::rusoto_core::BuildError::missing("messages"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BatchPutMessageResponse {
@@ -86,6 +127,48 @@ pub struct CancelPipelineReprocessingRequest {
     pub reprocessing_id: String,
 }
 
+impl CancelPipelineReprocessingRequest {
+    /// Returns a builder for `CancelPipelineReprocessingRequest`, checking its required members when built.
+    pub fn builder() -> CancelPipelineReprocessingRequestBuilder {
+        CancelPipelineReprocessingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelPipelineReprocessingRequest`](struct.CancelPipelineReprocessingRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelPipelineReprocessingRequestBuilder {
+    pipeline_name: Option<String>,
+    reprocessing_id: Option<String>,
+}
+
+impl CancelPipelineReprocessingRequestBuilder {
+    /// Sets the [`pipeline_name`](struct.CancelPipelineReprocessingRequest.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reprocessing_id`](struct.CancelPipelineReprocessingRequest.html#structfield.reprocessing_id) member.
+    pub fn reprocessing_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reprocessing_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelPipelineReprocessingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CancelPipelineReprocessingRequest, ::rusoto_core::BuildError> {
+        Ok(CancelPipelineReprocessingRequest {
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+            reprocessing_id: self
+                .reprocessing_id
+                .ok_or(::rusoto_core::BuildError::missing("reprocessing_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelPipelineReprocessingResponse {}
@@ -100,12 +183,14 @@ pub struct Channel {
     pub arn: Option<String>,
     /// <p>When the channel was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>When the channel was last updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The name of the channel.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -117,7 +202,7 @@ pub struct Channel {
     /// <p>The status of the channel.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ChannelStatus>,
     /// <p>Where channel data is stored.</p>
     #[serde(rename = "storage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -149,6 +234,88 @@ pub struct ChannelStatistics {
     pub size: Option<EstimatedResourceSize>,
 }
 
+/// <p>The status of the channel.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChannelStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChannelStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChannelStatus::Creating => "CREATING",
+            ChannelStatus::Active => "ACTIVE",
+            ChannelStatus::Deleting => "DELETING",
+            ChannelStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChannelStatus {
+    fn from(value: &'a str) -> ChannelStatus {
+        match value {
+            "CREATING" => ChannelStatus::Creating,
+            "ACTIVE" => ChannelStatus::Active,
+            "DELETING" => ChannelStatus::Deleting,
+            _ => ChannelStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChannelStatus {
+    fn from(value: String) -> ChannelStatus {
+        ChannelStatus::from(value.as_str())
+    }
+}
+
+impl From<ChannelStatus> for String {
+    fn from(value: ChannelStatus) -> String {
+        match value {
+            ChannelStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChannelStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChannelStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChannelStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChannelStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChannelStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChannelStatus::from(value))
+    }
+}
+
 /// <p>Where channel data is stored.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChannelStorage {
@@ -190,20 +357,100 @@ pub struct ChannelSummary {
     pub channel_storage: Option<ChannelStorageSummary>,
     /// <p>When the channel was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The last time the channel was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The status of the channel.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ChannelStatus>,
+}
+
+/// <p>The type of the compute resource used to execute the "containerAction". Possible values are: ACU_1 (vCPU=4, memory=16GiB) or ACU_2 (vCPU=8, memory=32GiB).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComputeType {
+    /// <p><code>ACU_1</code></p>
+    Acu1,
+    /// <p><code>ACU_2</code></p>
+    Acu2,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComputeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComputeType::Acu1 => "ACU_1",
+            ComputeType::Acu2 => "ACU_2",
+            ComputeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComputeType {
+    fn from(value: &'a str) -> ComputeType {
+        match value {
+            "ACU_1" => ComputeType::Acu1,
+            "ACU_2" => ComputeType::Acu2,
+            _ => ComputeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComputeType {
+    fn from(value: String) -> ComputeType {
+        ComputeType::from(value.as_str())
+    }
+}
+
+impl From<ComputeType> for String {
+    fn from(value: ComputeType) -> String {
+        match value {
+            ComputeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComputeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComputeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComputeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComputeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ComputeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ComputeType::from(value))
+    }
 }
 
 /// <p>Information needed to run the "containerAction" to produce data set contents.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContainerDatasetAction {
     /// <p>The ARN of the role which gives permission to the system to access needed resources in order to run the "containerAction". This includes, at minimum, permission to retrieve the data set contents which are the input to the containerized application.</p>
     #[serde(rename = "executionRoleArn")]
@@ -239,6 +486,60 @@ pub struct CreateChannelRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateChannelRequest {
+    /// Returns a builder for `CreateChannelRequest`, checking its required members when built.
+    pub fn builder() -> CreateChannelRequestBuilder {
+        CreateChannelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateChannelRequest`](struct.CreateChannelRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateChannelRequestBuilder {
+    channel_name: Option<String>,
+    channel_storage: Option<ChannelStorage>,
+    retention_period: Option<RetentionPeriod>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateChannelRequestBuilder {
+    /// Sets the [`channel_name`](struct.CreateChannelRequest.html#structfield.channel_name) member.
+    pub fn channel_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.channel_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`channel_storage`](struct.CreateChannelRequest.html#structfield.channel_storage) member.
+    pub fn channel_storage<T: Into<ChannelStorage>>(mut self, value: T) -> Self {
+        self.channel_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retention_period`](struct.CreateChannelRequest.html#structfield.retention_period) member.
+    pub fn retention_period<T: Into<RetentionPeriod>>(mut self, value: T) -> Self {
+        self.retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateChannelRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateChannelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateChannelRequest, ::rusoto_core::BuildError> {
+        Ok(CreateChannelRequest {
+            channel_name: self
+                .channel_name
+                .ok_or(::rusoto_core::BuildError::missing("channel_name"))?,
+            channel_storage: self.channel_storage,
+            retention_period: self.retention_period,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateChannelResponse {
@@ -263,6 +564,38 @@ pub struct CreateDatasetContentRequest {
     pub dataset_name: String,
 }
 
+impl CreateDatasetContentRequest {
+    /// Returns a builder for `CreateDatasetContentRequest`, checking its required members when built.
+    pub fn builder() -> CreateDatasetContentRequestBuilder {
+        CreateDatasetContentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDatasetContentRequest`](struct.CreateDatasetContentRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDatasetContentRequestBuilder {
+    dataset_name: Option<String>,
+}
+
+impl CreateDatasetContentRequestBuilder {
+    /// Sets the [`dataset_name`](struct.CreateDatasetContentRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDatasetContentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDatasetContentRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDatasetContentRequest {
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDatasetContentResponse {
@@ -302,6 +635,89 @@ pub struct CreateDatasetRequest {
     pub versioning_configuration: Option<VersioningConfiguration>,
 }
 
+impl CreateDatasetRequest {
+    /// Returns a builder for `CreateDatasetRequest`, checking its required members when built.
+    pub fn builder() -> CreateDatasetRequestBuilder {
+        CreateDatasetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDatasetRequest`](struct.CreateDatasetRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDatasetRequestBuilder {
+    actions: Option<Vec<DatasetAction>>,
+    content_delivery_rules: Option<Vec<DatasetContentDeliveryRule>>,
+    dataset_name: Option<String>,
+    retention_period: Option<RetentionPeriod>,
+    tags: Option<Vec<Tag>>,
+    triggers: Option<Vec<DatasetTrigger>>,
+    versioning_configuration: Option<VersioningConfiguration>,
+}
+
+impl CreateDatasetRequestBuilder {
+    /// Sets the [`actions`](struct.CreateDatasetRequest.html#structfield.actions) member.
+    pub fn actions<T: Into<Vec<DatasetAction>>>(mut self, value: T) -> Self {
+        self.actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_delivery_rules`](struct.CreateDatasetRequest.html#structfield.content_delivery_rules) member.
+    pub fn content_delivery_rules<T: Into<Vec<DatasetContentDeliveryRule>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.content_delivery_rules = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dataset_name`](struct.CreateDatasetRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retention_period`](struct.CreateDatasetRequest.html#structfield.retention_period) member.
+    pub fn retention_period<T: Into<RetentionPeriod>>(mut self, value: T) -> Self {
+        self.retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDatasetRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`triggers`](struct.CreateDatasetRequest.html#structfield.triggers) member.
+    pub fn triggers<T: Into<Vec<DatasetTrigger>>>(mut self, value: T) -> Self {
+        self.triggers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`versioning_configuration`](struct.CreateDatasetRequest.html#structfield.versioning_configuration) member.
+    pub fn versioning_configuration<T: Into<VersioningConfiguration>>(mut self, value: T) -> Self {
+        self.versioning_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDatasetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDatasetRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDatasetRequest {
+            actions: self
+                .actions
+                .ok_or(::rusoto_core::BuildError::missing("actions"))?,
+            content_delivery_rules: self.content_delivery_rules,
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+            retention_period: self.retention_period,
+            tags: self.tags,
+            triggers: self.triggers,
+            versioning_configuration: self.versioning_configuration,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDatasetResponse {
@@ -338,6 +754,60 @@ pub struct CreateDatastoreRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDatastoreRequest {
+    /// Returns a builder for `CreateDatastoreRequest`, checking its required members when built.
+    pub fn builder() -> CreateDatastoreRequestBuilder {
+        CreateDatastoreRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDatastoreRequest`](struct.CreateDatastoreRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDatastoreRequestBuilder {
+    datastore_name: Option<String>,
+    datastore_storage: Option<DatastoreStorage>,
+    retention_period: Option<RetentionPeriod>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDatastoreRequestBuilder {
+    /// Sets the [`datastore_name`](struct.CreateDatastoreRequest.html#structfield.datastore_name) member.
+    pub fn datastore_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.datastore_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`datastore_storage`](struct.CreateDatastoreRequest.html#structfield.datastore_storage) member.
+    pub fn datastore_storage<T: Into<DatastoreStorage>>(mut self, value: T) -> Self {
+        self.datastore_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retention_period`](struct.CreateDatastoreRequest.html#structfield.retention_period) member.
+    pub fn retention_period<T: Into<RetentionPeriod>>(mut self, value: T) -> Self {
+        self.retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDatastoreRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDatastoreRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDatastoreRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDatastoreRequest {
+            datastore_name: self
+                .datastore_name
+                .ok_or(::rusoto_core::BuildError::missing("datastore_name"))?,
+            datastore_storage: self.datastore_storage,
+            retention_period: self.retention_period,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDatastoreResponse {
@@ -369,6 +839,54 @@ pub struct CreatePipelineRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreatePipelineRequest {
+    /// Returns a builder for `CreatePipelineRequest`, checking its required members when built.
+    pub fn builder() -> CreatePipelineRequestBuilder {
+        CreatePipelineRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePipelineRequest`](struct.CreatePipelineRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePipelineRequestBuilder {
+    pipeline_activities: Option<Vec<PipelineActivity>>,
+    pipeline_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreatePipelineRequestBuilder {
+    /// Sets the [`pipeline_activities`](struct.CreatePipelineRequest.html#structfield.pipeline_activities) member.
+    pub fn pipeline_activities<T: Into<Vec<PipelineActivity>>>(mut self, value: T) -> Self {
+        self.pipeline_activities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_name`](struct.CreatePipelineRequest.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreatePipelineRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePipelineRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePipelineRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePipelineRequest {
+            pipeline_activities: self
+                .pipeline_activities
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_activities"))?,
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreatePipelineResponse {
@@ -466,12 +984,14 @@ pub struct Dataset {
     pub content_delivery_rules: Option<Vec<DatasetContentDeliveryRule>>,
     /// <p>When the data set was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The last time the data set was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The name of the data set.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -483,7 +1003,7 @@ pub struct Dataset {
     /// <p>The status of the data set.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DatasetStatus>,
     /// <p>The "DatasetTrigger" objects that specify when the data set is automatically updated.</p>
     #[serde(rename = "triggers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -522,7 +1042,85 @@ pub struct DatasetActionSummary {
     /// <p>The type of action by which the data set's contents are automatically created.</p>
     #[serde(rename = "actionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_type: Option<String>,
+    pub action_type: Option<DatasetActionType>,
+}
+
+/// <p>The type of action by which the data set's contents are automatically created.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DatasetActionType {
+    /// <p><code>QUERY</code></p>
+    Query,
+    /// <p><code>CONTAINER</code></p>
+    Container,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DatasetActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DatasetActionType::Query => "QUERY",
+            DatasetActionType::Container => "CONTAINER",
+            DatasetActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DatasetActionType {
+    fn from(value: &'a str) -> DatasetActionType {
+        match value {
+            "QUERY" => DatasetActionType::Query,
+            "CONTAINER" => DatasetActionType::Container,
+            _ => DatasetActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DatasetActionType {
+    fn from(value: String) -> DatasetActionType {
+        DatasetActionType::from(value.as_str())
+    }
+}
+
+impl From<DatasetActionType> for String {
+    fn from(value: DatasetActionType) -> String {
+        match value {
+            DatasetActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DatasetActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DatasetActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DatasetActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DatasetActionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DatasetActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DatasetActionType::from(value))
+    }
 }
 
 /// <p>The destination to which data set contents are delivered.</p>
@@ -550,6 +1148,88 @@ pub struct DatasetContentDeliveryRule {
     pub entry_name: Option<String>,
 }
 
+/// <p>The state of the data set contents. Can be one of "READY", "CREATING", "SUCCEEDED" or "FAILED".</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DatasetContentState {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DatasetContentState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DatasetContentState::Creating => "CREATING",
+            DatasetContentState::Succeeded => "SUCCEEDED",
+            DatasetContentState::Failed => "FAILED",
+            DatasetContentState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DatasetContentState {
+    fn from(value: &'a str) -> DatasetContentState {
+        match value {
+            "CREATING" => DatasetContentState::Creating,
+            "SUCCEEDED" => DatasetContentState::Succeeded,
+            "FAILED" => DatasetContentState::Failed,
+            _ => DatasetContentState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DatasetContentState {
+    fn from(value: String) -> DatasetContentState {
+        DatasetContentState::from(value.as_str())
+    }
+}
+
+impl From<DatasetContentState> for String {
+    fn from(value: DatasetContentState) -> String {
+        match value {
+            DatasetContentState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DatasetContentState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DatasetContentState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DatasetContentState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DatasetContentState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DatasetContentState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DatasetContentState::from(value))
+    }
+}
+
 /// <p>The state of the data set contents and the reason they are in this state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -561,7 +1241,7 @@ pub struct DatasetContentStatus {
     /// <p>The state of the data set contents. Can be one of "READY", "CREATING", "SUCCEEDED" or "FAILED".</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DatasetContentState>,
 }
 
 /// <p>Summary information about data set contents.</p>
@@ -570,12 +1250,14 @@ pub struct DatasetContentStatus {
 pub struct DatasetContentSummary {
     /// <p>The actual time the creation of the data set contents was started.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The time the creation of the data set contents was scheduled to start.</p>
     #[serde(rename = "scheduleTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub schedule_time: Option<f64>,
+    pub schedule_time: Option<EpochTimestamp>,
     /// <p>The status of the data set contents.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -608,6 +1290,88 @@ pub struct DatasetEntry {
     pub entry_name: Option<String>,
 }
 
+/// <p>The status of the data set.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DatasetStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DatasetStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DatasetStatus::Creating => "CREATING",
+            DatasetStatus::Active => "ACTIVE",
+            DatasetStatus::Deleting => "DELETING",
+            DatasetStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DatasetStatus {
+    fn from(value: &'a str) -> DatasetStatus {
+        match value {
+            "CREATING" => DatasetStatus::Creating,
+            "ACTIVE" => DatasetStatus::Active,
+            "DELETING" => DatasetStatus::Deleting,
+            _ => DatasetStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DatasetStatus {
+    fn from(value: String) -> DatasetStatus {
+        DatasetStatus::from(value.as_str())
+    }
+}
+
+impl From<DatasetStatus> for String {
+    fn from(value: DatasetStatus) -> String {
+        match value {
+            DatasetStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DatasetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DatasetStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DatasetStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DatasetStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DatasetStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DatasetStatus::from(value))
+    }
+}
+
 /// <p>A summary of information about a data set.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -618,20 +1382,22 @@ pub struct DatasetSummary {
     pub actions: Option<Vec<DatasetActionSummary>>,
     /// <p>The time the data set was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The name of the data set.</p>
     #[serde(rename = "datasetName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dataset_name: Option<String>,
     /// <p>The last time the data set was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The status of the data set.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DatasetStatus>,
     /// <p>A list of triggers. A trigger causes data set content to be populated at a specified time interval or when another data set is populated. The list of triggers can be empty or contain up to five DataSetTrigger objects</p>
     #[serde(rename = "triggers")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -661,12 +1427,14 @@ pub struct Datastore {
     pub arn: Option<String>,
     /// <p>When the data store was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The last time the data store was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The name of the data store.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -678,7 +1446,7 @@ pub struct Datastore {
     /// <p><p>The status of a data store:</p> <dl> <dt>CREATING</dt> <dd> <p>The data store is being created.</p> </dd> <dt>ACTIVE</dt> <dd> <p>The data store has been created and can be used.</p> </dd> <dt>DELETING</dt> <dd> <p>The data store is being deleted.</p> </dd> </dl></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DatastoreStatus>,
     /// <p>Where data store data is stored.</p>
     #[serde(rename = "storage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -706,6 +1474,88 @@ pub struct DatastoreStatistics {
     pub size: Option<EstimatedResourceSize>,
 }
 
+/// <p><p>The status of a data store:</p> <dl> <dt>CREATING</dt> <dd> <p>The data store is being created.</p> </dd> <dt>ACTIVE</dt> <dd> <p>The data store has been created and can be used.</p> </dd> <dt>DELETING</dt> <dd> <p>The data store is being deleted.</p> </dd> </dl></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DatastoreStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DatastoreStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DatastoreStatus::Creating => "CREATING",
+            DatastoreStatus::Active => "ACTIVE",
+            DatastoreStatus::Deleting => "DELETING",
+            DatastoreStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DatastoreStatus {
+    fn from(value: &'a str) -> DatastoreStatus {
+        match value {
+            "CREATING" => DatastoreStatus::Creating,
+            "ACTIVE" => DatastoreStatus::Active,
+            "DELETING" => DatastoreStatus::Deleting,
+            _ => DatastoreStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DatastoreStatus {
+    fn from(value: String) -> DatastoreStatus {
+        DatastoreStatus::from(value.as_str())
+    }
+}
+
+impl From<DatastoreStatus> for String {
+    fn from(value: DatastoreStatus) -> String {
+        match value {
+            DatastoreStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DatastoreStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DatastoreStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DatastoreStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DatastoreStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DatastoreStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DatastoreStatus::from(value))
+    }
+}
+
 /// <p>Where data store data is stored.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DatastoreStorage {
@@ -739,8 +1589,9 @@ pub struct DatastoreStorageSummary {
 pub struct DatastoreSummary {
     /// <p>When the data store was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The name of the data store.</p>
     #[serde(rename = "datastoreName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -751,12 +1602,13 @@ pub struct DatastoreSummary {
     pub datastore_storage: Option<DatastoreStorageSummary>,
     /// <p>The last time the data store was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The status of the data store.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<DatastoreStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -766,6 +1618,36 @@ pub struct DeleteChannelRequest {
     pub channel_name: String,
 }
 
+impl DeleteChannelRequest {
+    /// Returns a builder for `DeleteChannelRequest`, checking its required members when built.
+    pub fn builder() -> DeleteChannelRequestBuilder {
+        DeleteChannelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteChannelRequest`](struct.DeleteChannelRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteChannelRequestBuilder {
+    channel_name: Option<String>,
+}
+
+impl DeleteChannelRequestBuilder {
+    /// Sets the [`channel_name`](struct.DeleteChannelRequest.html#structfield.channel_name) member.
+    pub fn channel_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.channel_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteChannelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteChannelRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteChannelRequest {
+            channel_name: self
+                .channel_name
+                .ok_or(::rusoto_core::BuildError::missing("channel_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDatasetContentRequest {
     /// <p>The name of the data set whose content is deleted.</p>
@@ -777,6 +1659,46 @@ pub struct DeleteDatasetContentRequest {
     pub version_id: Option<String>,
 }
 
+impl DeleteDatasetContentRequest {
+    /// Returns a builder for `DeleteDatasetContentRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDatasetContentRequestBuilder {
+        DeleteDatasetContentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDatasetContentRequest`](struct.DeleteDatasetContentRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDatasetContentRequestBuilder {
+    dataset_name: Option<String>,
+    version_id: Option<String>,
+}
+
+impl DeleteDatasetContentRequestBuilder {
+    /// Sets the [`dataset_name`](struct.DeleteDatasetContentRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.DeleteDatasetContentRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDatasetContentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDatasetContentRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDatasetContentRequest {
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+            version_id: self.version_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDatasetRequest {
     /// <p>The name of the data set to delete.</p>
@@ -784,6 +1706,36 @@ pub struct DeleteDatasetRequest {
     pub dataset_name: String,
 }
 
+impl DeleteDatasetRequest {
+    /// Returns a builder for `DeleteDatasetRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDatasetRequestBuilder {
+        DeleteDatasetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDatasetRequest`](struct.DeleteDatasetRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDatasetRequestBuilder {
+    dataset_name: Option<String>,
+}
+
+impl DeleteDatasetRequestBuilder {
+    /// Sets the [`dataset_name`](struct.DeleteDatasetRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDatasetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDatasetRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDatasetRequest {
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDatastoreRequest {
     /// <p>The name of the data store to delete.</p>
@@ -791,6 +1743,36 @@ pub struct DeleteDatastoreRequest {
     pub datastore_name: String,
 }
 
+impl DeleteDatastoreRequest {
+    /// Returns a builder for `DeleteDatastoreRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDatastoreRequestBuilder {
+        DeleteDatastoreRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDatastoreRequest`](struct.DeleteDatastoreRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDatastoreRequestBuilder {
+    datastore_name: Option<String>,
+}
+
+impl DeleteDatastoreRequestBuilder {
+    /// Sets the [`datastore_name`](struct.DeleteDatastoreRequest.html#structfield.datastore_name) member.
+    pub fn datastore_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.datastore_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDatastoreRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDatastoreRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDatastoreRequest {
+            datastore_name: self
+                .datastore_name
+                .ok_or(::rusoto_core::BuildError::missing("datastore_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeletePipelineRequest {
     /// <p>The name of the pipeline to delete.</p>
@@ -798,6 +1780,36 @@ pub struct DeletePipelineRequest {
     pub pipeline_name: String,
 }
 
+impl DeletePipelineRequest {
+    /// Returns a builder for `DeletePipelineRequest`, checking its required members when built.
+    pub fn builder() -> DeletePipelineRequestBuilder {
+        DeletePipelineRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePipelineRequest`](struct.DeletePipelineRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePipelineRequestBuilder {
+    pipeline_name: Option<String>,
+}
+
+impl DeletePipelineRequestBuilder {
+    /// Sets the [`pipeline_name`](struct.DeletePipelineRequest.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePipelineRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePipelineRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePipelineRequest {
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+        })
+    }
+}
+
 /// <p>Used to limit data to that which has arrived since the last execution of the action.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeltaTime {
@@ -820,6 +1832,44 @@ pub struct DescribeChannelRequest {
     pub include_statistics: Option<bool>,
 }
 
+impl DescribeChannelRequest {
+    /// Returns a builder for `DescribeChannelRequest`, checking its required members when built.
+    pub fn builder() -> DescribeChannelRequestBuilder {
+        DescribeChannelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeChannelRequest`](struct.DescribeChannelRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeChannelRequestBuilder {
+    channel_name: Option<String>,
+    include_statistics: Option<bool>,
+}
+
+impl DescribeChannelRequestBuilder {
+    /// Sets the [`channel_name`](struct.DescribeChannelRequest.html#structfield.channel_name) member.
+    pub fn channel_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.channel_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_statistics`](struct.DescribeChannelRequest.html#structfield.include_statistics) member.
+    pub fn include_statistics<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_statistics = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeChannelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeChannelRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeChannelRequest {
+            channel_name: self
+                .channel_name
+                .ok_or(::rusoto_core::BuildError::missing("channel_name"))?,
+            include_statistics: self.include_statistics,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeChannelResponse {
@@ -840,6 +1890,36 @@ pub struct DescribeDatasetRequest {
     pub dataset_name: String,
 }
 
+impl DescribeDatasetRequest {
+    /// Returns a builder for `DescribeDatasetRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDatasetRequestBuilder {
+        DescribeDatasetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDatasetRequest`](struct.DescribeDatasetRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDatasetRequestBuilder {
+    dataset_name: Option<String>,
+}
+
+impl DescribeDatasetRequestBuilder {
+    /// Sets the [`dataset_name`](struct.DescribeDatasetRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDatasetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeDatasetRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDatasetRequest {
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDatasetResponse {
@@ -860,6 +1940,46 @@ pub struct DescribeDatastoreRequest {
     pub include_statistics: Option<bool>,
 }
 
+impl DescribeDatastoreRequest {
+    /// Returns a builder for `DescribeDatastoreRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDatastoreRequestBuilder {
+        DescribeDatastoreRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDatastoreRequest`](struct.DescribeDatastoreRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDatastoreRequestBuilder {
+    datastore_name: Option<String>,
+    include_statistics: Option<bool>,
+}
+
+impl DescribeDatastoreRequestBuilder {
+    /// Sets the [`datastore_name`](struct.DescribeDatastoreRequest.html#structfield.datastore_name) member.
+    pub fn datastore_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.datastore_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_statistics`](struct.DescribeDatastoreRequest.html#structfield.include_statistics) member.
+    pub fn include_statistics<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_statistics = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDatastoreRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDatastoreRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDatastoreRequest {
+            datastore_name: self
+                .datastore_name
+                .ok_or(::rusoto_core::BuildError::missing("datastore_name"))?,
+            include_statistics: self.include_statistics,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDatastoreResponse {
@@ -892,6 +2012,38 @@ pub struct DescribePipelineRequest {
     pub pipeline_name: String,
 }
 
+impl DescribePipelineRequest {
+    /// Returns a builder for `DescribePipelineRequest`, checking its required members when built.
+    pub fn builder() -> DescribePipelineRequestBuilder {
+        DescribePipelineRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePipelineRequest`](struct.DescribePipelineRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribePipelineRequestBuilder {
+    pipeline_name: Option<String>,
+}
+
+impl DescribePipelineRequestBuilder {
+    /// Sets the [`pipeline_name`](struct.DescribePipelineRequest.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePipelineRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePipelineRequest, ::rusoto_core::BuildError> {
+        Ok(DescribePipelineRequest {
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribePipelineResponse {
@@ -949,8 +2101,9 @@ pub struct DeviceShadowEnrichActivity {
 pub struct EstimatedResourceSize {
     /// <p>The time when the estimate of the size of the resource was made.</p>
     #[serde(rename = "estimatedOn")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub estimated_on: Option<f64>,
+    pub estimated_on: Option<EpochTimestamp>,
     /// <p>The estimated size of the resource in bytes.</p>
     #[serde(rename = "estimatedSizeInBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -983,6 +2136,46 @@ pub struct GetDatasetContentRequest {
     pub version_id: Option<String>,
 }
 
+impl GetDatasetContentRequest {
+    /// Returns a builder for `GetDatasetContentRequest`, checking its required members when built.
+    pub fn builder() -> GetDatasetContentRequestBuilder {
+        GetDatasetContentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDatasetContentRequest`](struct.GetDatasetContentRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDatasetContentRequestBuilder {
+    dataset_name: Option<String>,
+    version_id: Option<String>,
+}
+
+impl GetDatasetContentRequestBuilder {
+    /// Sets the [`dataset_name`](struct.GetDatasetContentRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_id`](struct.GetDatasetContentRequest.html#structfield.version_id) member.
+    pub fn version_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDatasetContentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDatasetContentRequest, ::rusoto_core::BuildError> {
+        Ok(GetDatasetContentRequest {
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+            version_id: self.version_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDatasetContentResponse {
@@ -996,8 +2189,9 @@ pub struct GetDatasetContentResponse {
     pub status: Option<DatasetContentStatus>,
     /// <p>The time when the request was made.</p>
     #[serde(rename = "timestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<EpochTimestamp>,
 }
 
 /// <p>Configuration information for coordination with the AWS Glue ETL (extract, transform and load) service.</p>
@@ -1052,6 +2246,42 @@ pub struct ListChannelsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListChannelsRequest {
+    /// Returns a builder for `ListChannelsRequest`, checking its required members when built.
+    pub fn builder() -> ListChannelsRequestBuilder {
+        ListChannelsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListChannelsRequest`](struct.ListChannelsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListChannelsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListChannelsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListChannelsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListChannelsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListChannelsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListChannelsRequest, ::rusoto_core::BuildError> {
+        Ok(ListChannelsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListChannelsResponse {
@@ -1080,12 +2310,78 @@ pub struct ListDatasetContentsRequest {
     pub next_token: Option<String>,
     /// <p>A filter to limit results to those data set contents whose creation is scheduled before the given time. See the field <code>triggers.schedule</code> in the CreateDataset request. (timestamp)</p>
     #[serde(rename = "scheduledBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduled_before: Option<f64>,
+    pub scheduled_before: Option<EpochTimestamp>,
     /// <p>A filter to limit results to those data set contents whose creation is scheduled on or after the given time. See the field <code>triggers.schedule</code> in the CreateDataset request. (timestamp)</p>
     #[serde(rename = "scheduledOnOrAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduled_on_or_after: Option<f64>,
+    pub scheduled_on_or_after: Option<EpochTimestamp>,
+}
+
+impl ListDatasetContentsRequest {
+    /// Returns a builder for `ListDatasetContentsRequest`, checking its required members when built.
+    pub fn builder() -> ListDatasetContentsRequestBuilder {
+        ListDatasetContentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDatasetContentsRequest`](struct.ListDatasetContentsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDatasetContentsRequestBuilder {
+    dataset_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    scheduled_before: Option<EpochTimestamp>,
+    scheduled_on_or_after: Option<EpochTimestamp>,
+}
+
+impl ListDatasetContentsRequestBuilder {
+    /// Sets the [`dataset_name`](struct.ListDatasetContentsRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListDatasetContentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDatasetContentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduled_before`](struct.ListDatasetContentsRequest.html#structfield.scheduled_before) member.
+    pub fn scheduled_before<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.scheduled_before = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scheduled_on_or_after`](struct.ListDatasetContentsRequest.html#structfield.scheduled_on_or_after) member.
+    pub fn scheduled_on_or_after<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.scheduled_on_or_after = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDatasetContentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDatasetContentsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDatasetContentsRequest {
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            scheduled_before: self.scheduled_before,
+            scheduled_on_or_after: self.scheduled_on_or_after,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1113,6 +2409,42 @@ pub struct ListDatasetsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDatasetsRequest {
+    /// Returns a builder for `ListDatasetsRequest`, checking its required members when built.
+    pub fn builder() -> ListDatasetsRequestBuilder {
+        ListDatasetsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDatasetsRequest`](struct.ListDatasetsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDatasetsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDatasetsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListDatasetsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDatasetsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDatasetsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDatasetsRequest, ::rusoto_core::BuildError> {
+        Ok(ListDatasetsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDatasetsResponse {
@@ -1138,6 +2470,42 @@ pub struct ListDatastoresRequest {
     pub next_token: Option<String>,
 }
 
+impl ListDatastoresRequest {
+    /// Returns a builder for `ListDatastoresRequest`, checking its required members when built.
+    pub fn builder() -> ListDatastoresRequestBuilder {
+        ListDatastoresRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDatastoresRequest`](struct.ListDatastoresRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDatastoresRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListDatastoresRequestBuilder {
+    /// Sets the [`max_results`](struct.ListDatastoresRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDatastoresRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDatastoresRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListDatastoresRequest, ::rusoto_core::BuildError> {
+        Ok(ListDatastoresRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDatastoresResponse {
@@ -1163,6 +2531,42 @@ pub struct ListPipelinesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListPipelinesRequest {
+    /// Returns a builder for `ListPipelinesRequest`, checking its required members when built.
+    pub fn builder() -> ListPipelinesRequestBuilder {
+        ListPipelinesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPipelinesRequest`](struct.ListPipelinesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPipelinesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListPipelinesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListPipelinesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPipelinesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPipelinesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPipelinesRequest, ::rusoto_core::BuildError> {
+        Ok(ListPipelinesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPipelinesResponse {
@@ -1183,6 +2587,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -1192,15 +2628,89 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<Vec<Tag>>,
 }
 
+/// <p>The logging level. Currently, only "ERROR" is supported.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoggingLevel {
+    /// <p><code>ERROR</code></p>
+    Error,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoggingLevel {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoggingLevel::Error => "ERROR",
+            LoggingLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoggingLevel {
+    fn from(value: &'a str) -> LoggingLevel {
+        match value {
+            "ERROR" => LoggingLevel::Error,
+            _ => LoggingLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoggingLevel {
+    fn from(value: String) -> LoggingLevel {
+        LoggingLevel::from(value.as_str())
+    }
+}
+
+impl From<LoggingLevel> for String {
+    fn from(value: LoggingLevel) -> String {
+        match value {
+            LoggingLevel::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoggingLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LoggingLevel {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LoggingLevel {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoggingLevel {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LoggingLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoggingLevel::from(value))
+    }
+}
+
 /// <p>Information about logging options.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoggingOptions {
     /// <p>If true, logging is enabled for AWS IoT Analytics.</p>
     #[serde(rename = "enabled")]
     pub enabled: bool,
     /// <p>The logging level. Currently, only "ERROR" is supported.</p>
     #[serde(rename = "level")]
-    pub level: String,
+    pub level: LoggingLevel,
     /// <p>The ARN of the role that grants permission to AWS IoT Analytics to perform logging.</p>
     #[serde(rename = "roleArn")]
     pub role_arn: String,
@@ -1262,12 +2772,14 @@ pub struct Pipeline {
     pub arn: Option<String>,
     /// <p>When the pipeline was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The last time the pipeline was updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The name of the pipeline.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1329,12 +2841,14 @@ pub struct PipelineActivity {
 pub struct PipelineSummary {
     /// <p>When the pipeline was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>When the pipeline was last updated.</p>
     #[serde(rename = "lastUpdateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_time: Option<f64>,
+    pub last_update_time: Option<EpochTimestamp>,
     /// <p>The name of the pipeline.</p>
     #[serde(rename = "pipelineName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1345,13 +2859,45 @@ pub struct PipelineSummary {
     pub reprocessing_summaries: Option<Vec<ReprocessingSummary>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutLoggingOptionsRequest {
     /// <p>The new values of the AWS IoT Analytics logging options.</p>
     #[serde(rename = "loggingOptions")]
     pub logging_options: LoggingOptions,
 }
 
+impl PutLoggingOptionsRequest {
+    /// Returns a builder for `PutLoggingOptionsRequest`, checking its required members when built.
+    pub fn builder() -> PutLoggingOptionsRequestBuilder {
+        PutLoggingOptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutLoggingOptionsRequest`](struct.PutLoggingOptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutLoggingOptionsRequestBuilder {
+    logging_options: Option<LoggingOptions>,
+}
+
+impl PutLoggingOptionsRequestBuilder {
+    /// Sets the [`logging_options`](struct.PutLoggingOptionsRequest.html#structfield.logging_options) member.
+    pub fn logging_options<T: Into<LoggingOptions>>(mut self, value: T) -> Self {
+        self.logging_options = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutLoggingOptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutLoggingOptionsRequest, ::rusoto_core::BuildError> {
+        Ok(PutLoggingOptionsRequest {
+            logging_options: self
+                .logging_options
+                .ok_or(::rusoto_core::BuildError::missing("logging_options"))?,
+        })
+    }
+}
+
 /// <p>Information which is used to filter message data, to segregate it according to the time frame in which it arrives.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct QueryFilter {
@@ -1376,14 +2922,101 @@ pub struct RemoveAttributesActivity {
     pub next: Option<String>,
 }
 
+/// <p>The status of the pipeline reprocessing.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReprocessingStatus {
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>CANCELLED</code></p>
+    Cancelled,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReprocessingStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReprocessingStatus::Running => "RUNNING",
+            ReprocessingStatus::Succeeded => "SUCCEEDED",
+            ReprocessingStatus::Cancelled => "CANCELLED",
+            ReprocessingStatus::Failed => "FAILED",
+            ReprocessingStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReprocessingStatus {
+    fn from(value: &'a str) -> ReprocessingStatus {
+        match value {
+            "RUNNING" => ReprocessingStatus::Running,
+            "SUCCEEDED" => ReprocessingStatus::Succeeded,
+            "CANCELLED" => ReprocessingStatus::Cancelled,
+            "FAILED" => ReprocessingStatus::Failed,
+            _ => ReprocessingStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReprocessingStatus {
+    fn from(value: String) -> ReprocessingStatus {
+        ReprocessingStatus::from(value.as_str())
+    }
+}
+
+impl From<ReprocessingStatus> for String {
+    fn from(value: ReprocessingStatus) -> String {
+        match value {
+            ReprocessingStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReprocessingStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReprocessingStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ReprocessingStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReprocessingStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReprocessingStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReprocessingStatus::from(value))
+    }
+}
+
 /// <p>Information about pipeline reprocessing.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ReprocessingSummary {
     /// <p>The time the pipeline reprocessing was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The 'reprocessingId' returned by "StartPipelineReprocessing".</p>
     #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1391,15 +3024,15 @@ pub struct ReprocessingSummary {
     /// <p>The status of the pipeline reprocessing.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ReprocessingStatus>,
 }
 
 /// <p>The configuration of the resource used to execute the "containerAction".</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceConfiguration {
     /// <p>The type of the compute resource used to execute the "containerAction". Possible values are: ACU_1 (vCPU=4, memory=16GiB) or ACU_2 (vCPU=8, memory=32GiB).</p>
     #[serde(rename = "computeType")]
-    pub compute_type: String,
+    pub compute_type: ComputeType,
     /// <p>The size (in GB) of the persistent storage available to the resource instance used to execute the "containerAction" (min: 1, max: 50).</p>
     #[serde(rename = "volumeSizeInGB")]
     pub volume_size_in_gb: i64,
@@ -1433,6 +3066,48 @@ pub struct RunPipelineActivityRequest {
     pub pipeline_activity: PipelineActivity,
 }
 
+impl RunPipelineActivityRequest {
+    /// Returns a builder for `RunPipelineActivityRequest`, checking its required members when built.
+    pub fn builder() -> RunPipelineActivityRequestBuilder {
+        RunPipelineActivityRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RunPipelineActivityRequest`](struct.RunPipelineActivityRequest.html).
+#[derive(Default, Debug)]
+pub struct RunPipelineActivityRequestBuilder {
+    payloads: Option<Vec<bytes::Bytes>>,
+    pipeline_activity: Option<PipelineActivity>,
+}
+
+impl RunPipelineActivityRequestBuilder {
+    /// Sets the [`payloads`](struct.RunPipelineActivityRequest.html#structfield.payloads) member.
+    pub fn payloads<T: Into<Vec<bytes::Bytes>>>(mut self, value: T) -> Self {
+        self.payloads = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_activity`](struct.RunPipelineActivityRequest.html#structfield.pipeline_activity) member.
+    pub fn pipeline_activity<T: Into<PipelineActivity>>(mut self, value: T) -> Self {
+        self.pipeline_activity = Some(value.into());
+        self
+    }
+
+    /// Builds the `RunPipelineActivityRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RunPipelineActivityRequest, ::rusoto_core::BuildError> {
+        Ok(RunPipelineActivityRequest {
+            payloads: self
+                .payloads
+                .ok_or(::rusoto_core::BuildError::missing("payloads"))?,
+            pipeline_activity: self
+                .pipeline_activity
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_activity"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RunPipelineActivityResponse {
@@ -1476,16 +3151,74 @@ pub struct SampleChannelDataRequest {
     pub channel_name: String,
     /// <p>The end of the time window from which sample messages are retrieved.</p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The number of sample messages to be retrieved. The limit is 10, the default is also 10.</p>
     #[serde(rename = "maxMessages")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_messages: Option<i64>,
     /// <p>The start of the time window from which sample messages are retrieved.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
+}
+
+impl SampleChannelDataRequest {
+    /// Returns a builder for `SampleChannelDataRequest`, checking its required members when built.
+    pub fn builder() -> SampleChannelDataRequestBuilder {
+        SampleChannelDataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SampleChannelDataRequest`](struct.SampleChannelDataRequest.html).
+#[derive(Default, Debug)]
+pub struct SampleChannelDataRequestBuilder {
+    channel_name: Option<String>,
+    end_time: Option<EpochTimestamp>,
+    max_messages: Option<i64>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl SampleChannelDataRequestBuilder {
+    /// Sets the [`channel_name`](struct.SampleChannelDataRequest.html#structfield.channel_name) member.
+    pub fn channel_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.channel_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.SampleChannelDataRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_messages`](struct.SampleChannelDataRequest.html#structfield.max_messages) member.
+    pub fn max_messages<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_messages = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.SampleChannelDataRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `SampleChannelDataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SampleChannelDataRequest, ::rusoto_core::BuildError> {
+        Ok(SampleChannelDataRequest {
+            channel_name: self
+                .channel_name
+                .ok_or(::rusoto_core::BuildError::missing("channel_name"))?,
+            end_time: self.end_time,
+            max_messages: self.max_messages,
+            start_time: self.start_time,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1560,15 +3293,65 @@ pub struct SqlQueryDatasetAction {
 pub struct StartPipelineReprocessingRequest {
     /// <p>The end time (exclusive) of raw message data that is reprocessed.</p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The name of the pipeline on which to start reprocessing.</p>
     #[serde(rename = "pipelineName")]
     pub pipeline_name: String,
     /// <p>The start time (inclusive) of raw message data that is reprocessed.</p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
+}
+
+impl StartPipelineReprocessingRequest {
+    /// Returns a builder for `StartPipelineReprocessingRequest`, checking its required members when built.
+    pub fn builder() -> StartPipelineReprocessingRequestBuilder {
+        StartPipelineReprocessingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartPipelineReprocessingRequest`](struct.StartPipelineReprocessingRequest.html).
+#[derive(Default, Debug)]
+pub struct StartPipelineReprocessingRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    pipeline_name: Option<String>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl StartPipelineReprocessingRequestBuilder {
+    /// Sets the [`end_time`](struct.StartPipelineReprocessingRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_name`](struct.StartPipelineReprocessingRequest.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.StartPipelineReprocessingRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartPipelineReprocessingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartPipelineReprocessingRequest, ::rusoto_core::BuildError> {
+        Ok(StartPipelineReprocessingRequest {
+            end_time: self.end_time,
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+            start_time: self.start_time,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1601,6 +3384,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -1623,6 +3446,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -1642,6 +3505,52 @@ pub struct UpdateChannelRequest {
     pub retention_period: Option<RetentionPeriod>,
 }
 
+impl UpdateChannelRequest {
+    /// Returns a builder for `UpdateChannelRequest`, checking its required members when built.
+    pub fn builder() -> UpdateChannelRequestBuilder {
+        UpdateChannelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateChannelRequest`](struct.UpdateChannelRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateChannelRequestBuilder {
+    channel_name: Option<String>,
+    channel_storage: Option<ChannelStorage>,
+    retention_period: Option<RetentionPeriod>,
+}
+
+impl UpdateChannelRequestBuilder {
+    /// Sets the [`channel_name`](struct.UpdateChannelRequest.html#structfield.channel_name) member.
+    pub fn channel_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.channel_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`channel_storage`](struct.UpdateChannelRequest.html#structfield.channel_storage) member.
+    pub fn channel_storage<T: Into<ChannelStorage>>(mut self, value: T) -> Self {
+        self.channel_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retention_period`](struct.UpdateChannelRequest.html#structfield.retention_period) member.
+    pub fn retention_period<T: Into<RetentionPeriod>>(mut self, value: T) -> Self {
+        self.retention_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateChannelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateChannelRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateChannelRequest {
+            channel_name: self
+                .channel_name
+                .ok_or(::rusoto_core::BuildError::missing("channel_name"))?,
+            channel_storage: self.channel_storage,
+            retention_period: self.retention_period,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateDatasetRequest {
     /// <p>A list of "DatasetAction" objects.</p>
@@ -1668,6 +3577,81 @@ pub struct UpdateDatasetRequest {
     pub versioning_configuration: Option<VersioningConfiguration>,
 }
 
+impl UpdateDatasetRequest {
+    /// Returns a builder for `UpdateDatasetRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDatasetRequestBuilder {
+        UpdateDatasetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDatasetRequest`](struct.UpdateDatasetRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDatasetRequestBuilder {
+    actions: Option<Vec<DatasetAction>>,
+    content_delivery_rules: Option<Vec<DatasetContentDeliveryRule>>,
+    dataset_name: Option<String>,
+    retention_period: Option<RetentionPeriod>,
+    triggers: Option<Vec<DatasetTrigger>>,
+    versioning_configuration: Option<VersioningConfiguration>,
+}
+
+impl UpdateDatasetRequestBuilder {
+    /// Sets the [`actions`](struct.UpdateDatasetRequest.html#structfield.actions) member.
+    pub fn actions<T: Into<Vec<DatasetAction>>>(mut self, value: T) -> Self {
+        self.actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_delivery_rules`](struct.UpdateDatasetRequest.html#structfield.content_delivery_rules) member.
+    pub fn content_delivery_rules<T: Into<Vec<DatasetContentDeliveryRule>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.content_delivery_rules = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dataset_name`](struct.UpdateDatasetRequest.html#structfield.dataset_name) member.
+    pub fn dataset_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.dataset_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retention_period`](struct.UpdateDatasetRequest.html#structfield.retention_period) member.
+    pub fn retention_period<T: Into<RetentionPeriod>>(mut self, value: T) -> Self {
+        self.retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`triggers`](struct.UpdateDatasetRequest.html#structfield.triggers) member.
+    pub fn triggers<T: Into<Vec<DatasetTrigger>>>(mut self, value: T) -> Self {
+        self.triggers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`versioning_configuration`](struct.UpdateDatasetRequest.html#structfield.versioning_configuration) member.
+    pub fn versioning_configuration<T: Into<VersioningConfiguration>>(mut self, value: T) -> Self {
+        self.versioning_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDatasetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateDatasetRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDatasetRequest {
+            actions: self
+                .actions
+                .ok_or(::rusoto_core::BuildError::missing("actions"))?,
+            content_delivery_rules: self.content_delivery_rules,
+            dataset_name: self
+                .dataset_name
+                .ok_or(::rusoto_core::BuildError::missing("dataset_name"))?,
+            retention_period: self.retention_period,
+            triggers: self.triggers,
+            versioning_configuration: self.versioning_configuration,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateDatastoreRequest {
     /// <p>The name of the data store to be updated.</p>
@@ -1683,6 +3667,52 @@ pub struct UpdateDatastoreRequest {
     pub retention_period: Option<RetentionPeriod>,
 }
 
+impl UpdateDatastoreRequest {
+    /// Returns a builder for `UpdateDatastoreRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDatastoreRequestBuilder {
+        UpdateDatastoreRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDatastoreRequest`](struct.UpdateDatastoreRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDatastoreRequestBuilder {
+    datastore_name: Option<String>,
+    datastore_storage: Option<DatastoreStorage>,
+    retention_period: Option<RetentionPeriod>,
+}
+
+impl UpdateDatastoreRequestBuilder {
+    /// Sets the [`datastore_name`](struct.UpdateDatastoreRequest.html#structfield.datastore_name) member.
+    pub fn datastore_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.datastore_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`datastore_storage`](struct.UpdateDatastoreRequest.html#structfield.datastore_storage) member.
+    pub fn datastore_storage<T: Into<DatastoreStorage>>(mut self, value: T) -> Self {
+        self.datastore_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retention_period`](struct.UpdateDatastoreRequest.html#structfield.retention_period) member.
+    pub fn retention_period<T: Into<RetentionPeriod>>(mut self, value: T) -> Self {
+        self.retention_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDatastoreRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateDatastoreRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDatastoreRequest {
+            datastore_name: self
+                .datastore_name
+                .ok_or(::rusoto_core::BuildError::missing("datastore_name"))?,
+            datastore_storage: self.datastore_storage,
+            retention_period: self.retention_period,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdatePipelineRequest {
     /// <p>A list of "PipelineActivity" objects. Activities perform transformations on your messages, such as removing, renaming or adding message attributes; filtering messages based on attribute values; invoking your Lambda functions on messages for advanced processing; or performing mathematical transformations to normalize device data.</p> <p>The list can be 2-25 <b>PipelineActivity</b> objects and must contain both a <code>channel</code> and a <code>datastore</code> activity. Each entry in the list must contain only one activity, for example:</p> <p> <code>pipelineActivities = [ { "channel": { ... } }, { "lambda": { ... } }, ... ]</code> </p>
@@ -1693,6 +3723,46 @@ pub struct UpdatePipelineRequest {
     pub pipeline_name: String,
 }
 
+impl UpdatePipelineRequest {
+    /// Returns a builder for `UpdatePipelineRequest`, checking its required members when built.
+    pub fn builder() -> UpdatePipelineRequestBuilder {
+        UpdatePipelineRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePipelineRequest`](struct.UpdatePipelineRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdatePipelineRequestBuilder {
+    pipeline_activities: Option<Vec<PipelineActivity>>,
+    pipeline_name: Option<String>,
+}
+
+impl UpdatePipelineRequestBuilder {
+    /// Sets the [`pipeline_activities`](struct.UpdatePipelineRequest.html#structfield.pipeline_activities) member.
+    pub fn pipeline_activities<T: Into<Vec<PipelineActivity>>>(mut self, value: T) -> Self {
+        self.pipeline_activities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pipeline_name`](struct.UpdatePipelineRequest.html#structfield.pipeline_name) member.
+    pub fn pipeline_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.pipeline_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePipelineRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdatePipelineRequest, ::rusoto_core::BuildError> {
+        Ok(UpdatePipelineRequest {
+            pipeline_activities: self
+                .pipeline_activities
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_activities"))?,
+            pipeline_name: self
+                .pipeline_name
+                .ok_or(::rusoto_core::BuildError::missing("pipeline_name"))?,
+        })
+    }
+}
+
 /// <p>An instance of a variable to be passed to the "containerAction" execution. Each variable must have a name and a value given by one of "stringValue", "datasetContentVersionValue", or "outputFileUriValue".</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Variable {
@@ -3945,6 +6015,206 @@ pub trait IotAnalytics {
         input: UpdatePipelineRequest,
     ) -> RusotoFuture<(), UpdatePipelineError>;
 }
+impl<T: ?Sized + IotAnalytics> IotAnalytics for ::std::sync::Arc<T> {
+    fn batch_put_message(
+        &self,
+        input: BatchPutMessageRequest,
+    ) -> RusotoFuture<BatchPutMessageResponse, BatchPutMessageError> {
+        (**self).batch_put_message(input)
+    }
+    fn cancel_pipeline_reprocessing(
+        &self,
+        input: CancelPipelineReprocessingRequest,
+    ) -> RusotoFuture<CancelPipelineReprocessingResponse, CancelPipelineReprocessingError> {
+        (**self).cancel_pipeline_reprocessing(input)
+    }
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        (**self).create_channel(input)
+    }
+    fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> RusotoFuture<CreateDatasetResponse, CreateDatasetError> {
+        (**self).create_dataset(input)
+    }
+    fn create_dataset_content(
+        &self,
+        input: CreateDatasetContentRequest,
+    ) -> RusotoFuture<CreateDatasetContentResponse, CreateDatasetContentError> {
+        (**self).create_dataset_content(input)
+    }
+    fn create_datastore(
+        &self,
+        input: CreateDatastoreRequest,
+    ) -> RusotoFuture<CreateDatastoreResponse, CreateDatastoreError> {
+        (**self).create_datastore(input)
+    }
+    fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> RusotoFuture<CreatePipelineResponse, CreatePipelineError> {
+        (**self).create_pipeline(input)
+    }
+    fn delete_channel(&self, input: DeleteChannelRequest) -> RusotoFuture<(), DeleteChannelError> {
+        (**self).delete_channel(input)
+    }
+    fn delete_dataset(&self, input: DeleteDatasetRequest) -> RusotoFuture<(), DeleteDatasetError> {
+        (**self).delete_dataset(input)
+    }
+    fn delete_dataset_content(
+        &self,
+        input: DeleteDatasetContentRequest,
+    ) -> RusotoFuture<(), DeleteDatasetContentError> {
+        (**self).delete_dataset_content(input)
+    }
+    fn delete_datastore(
+        &self,
+        input: DeleteDatastoreRequest,
+    ) -> RusotoFuture<(), DeleteDatastoreError> {
+        (**self).delete_datastore(input)
+    }
+    fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> RusotoFuture<(), DeletePipelineError> {
+        (**self).delete_pipeline(input)
+    }
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        (**self).describe_channel(input)
+    }
+    fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> RusotoFuture<DescribeDatasetResponse, DescribeDatasetError> {
+        (**self).describe_dataset(input)
+    }
+    fn describe_datastore(
+        &self,
+        input: DescribeDatastoreRequest,
+    ) -> RusotoFuture<DescribeDatastoreResponse, DescribeDatastoreError> {
+        (**self).describe_datastore(input)
+    }
+    fn describe_logging_options(
+        &self,
+    ) -> RusotoFuture<DescribeLoggingOptionsResponse, DescribeLoggingOptionsError> {
+        (**self).describe_logging_options()
+    }
+    fn describe_pipeline(
+        &self,
+        input: DescribePipelineRequest,
+    ) -> RusotoFuture<DescribePipelineResponse, DescribePipelineError> {
+        (**self).describe_pipeline(input)
+    }
+    fn get_dataset_content(
+        &self,
+        input: GetDatasetContentRequest,
+    ) -> RusotoFuture<GetDatasetContentResponse, GetDatasetContentError> {
+        (**self).get_dataset_content(input)
+    }
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        (**self).list_channels(input)
+    }
+    fn list_dataset_contents(
+        &self,
+        input: ListDatasetContentsRequest,
+    ) -> RusotoFuture<ListDatasetContentsResponse, ListDatasetContentsError> {
+        (**self).list_dataset_contents(input)
+    }
+    fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> RusotoFuture<ListDatasetsResponse, ListDatasetsError> {
+        (**self).list_datasets(input)
+    }
+    fn list_datastores(
+        &self,
+        input: ListDatastoresRequest,
+    ) -> RusotoFuture<ListDatastoresResponse, ListDatastoresError> {
+        (**self).list_datastores(input)
+    }
+    fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> RusotoFuture<ListPipelinesResponse, ListPipelinesError> {
+        (**self).list_pipelines(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_logging_options(
+        &self,
+        input: PutLoggingOptionsRequest,
+    ) -> RusotoFuture<(), PutLoggingOptionsError> {
+        (**self).put_logging_options(input)
+    }
+    fn run_pipeline_activity(
+        &self,
+        input: RunPipelineActivityRequest,
+    ) -> RusotoFuture<RunPipelineActivityResponse, RunPipelineActivityError> {
+        (**self).run_pipeline_activity(input)
+    }
+    fn sample_channel_data(
+        &self,
+        input: SampleChannelDataRequest,
+    ) -> RusotoFuture<SampleChannelDataResponse, SampleChannelDataError> {
+        (**self).sample_channel_data(input)
+    }
+    fn start_pipeline_reprocessing(
+        &self,
+        input: StartPipelineReprocessingRequest,
+    ) -> RusotoFuture<StartPipelineReprocessingResponse, StartPipelineReprocessingError> {
+        (**self).start_pipeline_reprocessing(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_channel(&self, input: UpdateChannelRequest) -> RusotoFuture<(), UpdateChannelError> {
+        (**self).update_channel(input)
+    }
+    fn update_dataset(&self, input: UpdateDatasetRequest) -> RusotoFuture<(), UpdateDatasetError> {
+        (**self).update_dataset(input)
+    }
+    fn update_datastore(
+        &self,
+        input: UpdateDatastoreRequest,
+    ) -> RusotoFuture<(), UpdateDatastoreError> {
+        (**self).update_datastore(input)
+    }
+    fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> RusotoFuture<(), UpdatePipelineError> {
+        (**self).update_pipeline(input)
+    }
+}
+/// The error codes AWS IoT Analytics retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS IoT Analytics API.
 #[derive(Clone)]
 pub struct IotAnalyticsClient {
@@ -3960,6 +6230,27 @@ impl IotAnalyticsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_IOTANALYTICS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> IotAnalyticsClient {
+        Self::new(region::Region::from_env_for_service("IOTANALYTICS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `iotanalytics`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> IotAnalyticsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("iotanalytics", "IOTANALYTICS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3978,7 +6269,54 @@ impl IotAnalyticsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> IotAnalyticsClient {
-        IotAnalyticsClient { client, region }
+        IotAnalyticsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "iotanalytics")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "iotanalytics",
+            "iotanalytics",
+            "IOTANALYTICS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `IotAnalytics` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn IotAnalytics + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for IotAnalyticsClient {
+    fn service_name(&self) -> &'static str {
+        "AWS IoT Analytics"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "iotanalytics"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> IotAnalyticsClient {
+        Self::new_with_client(client, region)
     }
 }
 
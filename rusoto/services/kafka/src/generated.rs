@@ -23,7 +23,83 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The distribution of broker nodes across Availability Zones. This is an optional parameter. If you don't specify it, Amazon MSK gives it the value DEFAULT. You can also explicitly set this parameter to the value DEFAULT. No other values are currently allowed.</p>
+/// <p>Amazon MSK distributes the broker nodes evenly across the Availability Zones that correspond to the subnets you provide when you create the cluster.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BrokerAZDistribution {
+    /// <p><code>DEFAULT</code></p>
+    Default,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BrokerAZDistribution {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BrokerAZDistribution::Default => "DEFAULT",
+            BrokerAZDistribution::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BrokerAZDistribution {
+    fn from(value: &'a str) -> BrokerAZDistribution {
+        match value {
+            "DEFAULT" => BrokerAZDistribution::Default,
+            _ => BrokerAZDistribution::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BrokerAZDistribution {
+    fn from(value: String) -> BrokerAZDistribution {
+        BrokerAZDistribution::from(value.as_str())
+    }
+}
+
+impl From<BrokerAZDistribution> for String {
+    fn from(value: BrokerAZDistribution) -> String {
+        match value {
+            BrokerAZDistribution::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BrokerAZDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BrokerAZDistribution {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BrokerAZDistribution {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BrokerAZDistribution {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BrokerAZDistribution, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BrokerAZDistribution::from(value))
+    }
+}
+
 /// <pre><code>        &lt;p&gt;Specifies the EBS volume upgrade information. The broker identifier must be set to the keyword ALL. This means the changes apply to all the brokers in the cluster.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -46,7 +122,7 @@ pub struct BrokerNodeGroupInfo {
     /// </code></pre>
     #[serde(rename = "BrokerAZDistribution")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub broker_az_distribution: Option<String>,
+    pub broker_az_distribution: Option<BrokerAZDistribution>,
     /// <pre><code>        &lt;p&gt;The list of subnets to connect to in the client virtual private cloud (VPC). AWS creates elastic network interfaces inside these subnets. Client applications use elastic network interfaces to produce and consume data. Client subnets can&#39;t be in Availability Zone us-east-1e.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "ClientSubnets")]
@@ -139,6 +215,88 @@ pub struct ClientAuthentication {
     pub tls: Option<Tls>,
 }
 
+/// <p>Client-broker encryption in transit setting.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClientBroker {
+    /// <p><code>TLS</code></p>
+    Tls,
+    /// <p><code>TLS_PLAINTEXT</code></p>
+    TlsPlaintext,
+    /// <p><code>PLAINTEXT</code></p>
+    Plaintext,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ClientBroker {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClientBroker::Tls => "TLS",
+            ClientBroker::TlsPlaintext => "TLS_PLAINTEXT",
+            ClientBroker::Plaintext => "PLAINTEXT",
+            ClientBroker::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ClientBroker {
+    fn from(value: &'a str) -> ClientBroker {
+        match value {
+            "TLS" => ClientBroker::Tls,
+            "TLS_PLAINTEXT" => ClientBroker::TlsPlaintext,
+            "PLAINTEXT" => ClientBroker::Plaintext,
+            _ => ClientBroker::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClientBroker {
+    fn from(value: String) -> ClientBroker {
+        ClientBroker::from(value.as_str())
+    }
+}
+
+impl From<ClientBroker> for String {
+    fn from(value: ClientBroker) -> String {
+        match value {
+            ClientBroker::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ClientBroker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClientBroker {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClientBroker {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClientBroker {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ClientBroker, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ClientBroker::from(value))
+    }
+}
+
 /// <pre><code>        &lt;p&gt;Returns information about a cluster.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -172,8 +330,9 @@ pub struct ClusterInfo {
     /// <pre><code>        &lt;p&gt;The time when the cluster was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <pre><code>        &lt;p&gt;Information about the version of software currently deployed on the Kafka brokers in the cluster.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CurrentBrokerSoftwareInfo")]
@@ -193,7 +352,7 @@ pub struct ClusterInfo {
     /// </code></pre>
     #[serde(rename = "EnhancedMonitoring")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enhanced_monitoring: Option<String>,
+    pub enhanced_monitoring: Option<EnhancedMonitoring>,
     /// <pre><code>        &lt;p&gt;The number of broker nodes in the cluster.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "NumberOfBrokerNodes")]
@@ -203,7 +362,7 @@ pub struct ClusterInfo {
     /// </code></pre>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ClusterState>,
     /// <pre><code>        &lt;p&gt;Tags attached to the cluster.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "Tags")]
@@ -234,13 +393,15 @@ pub struct ClusterOperationInfo {
     /// <pre><code>        &lt;p&gt;The time that the operation was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <pre><code>        &lt;p&gt;The time at which the operation finished.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "EndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <pre><code>        &lt;p&gt;Describes the error if the operation fails.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "ErrorInfo")]
@@ -273,6 +434,108 @@ pub struct ClusterOperationInfo {
     pub target_cluster_info: Option<MutableClusterInfo>,
 }
 
+/// <p>The state of the Apache Kafka cluster.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClusterState {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>HEALING</code></p>
+    Healing,
+    /// <p><code>MAINTENANCE</code></p>
+    Maintenance,
+    /// <p><code>REBOOTING_BROKER</code></p>
+    RebootingBroker,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ClusterState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ClusterState::Active => "ACTIVE",
+            ClusterState::Creating => "CREATING",
+            ClusterState::Deleting => "DELETING",
+            ClusterState::Failed => "FAILED",
+            ClusterState::Healing => "HEALING",
+            ClusterState::Maintenance => "MAINTENANCE",
+            ClusterState::RebootingBroker => "REBOOTING_BROKER",
+            ClusterState::Updating => "UPDATING",
+            ClusterState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ClusterState {
+    fn from(value: &'a str) -> ClusterState {
+        match value {
+            "ACTIVE" => ClusterState::Active,
+            "CREATING" => ClusterState::Creating,
+            "DELETING" => ClusterState::Deleting,
+            "FAILED" => ClusterState::Failed,
+            "HEALING" => ClusterState::Healing,
+            "MAINTENANCE" => ClusterState::Maintenance,
+            "REBOOTING_BROKER" => ClusterState::RebootingBroker,
+            "UPDATING" => ClusterState::Updating,
+            _ => ClusterState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ClusterState {
+    fn from(value: String) -> ClusterState {
+        ClusterState::from(value.as_str())
+    }
+}
+
+impl From<ClusterState> for String {
+    fn from(value: ClusterState) -> String {
+        match value {
+            ClusterState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ClusterState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ClusterState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ClusterState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ClusterState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ClusterState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ClusterState::from(value))
+    }
+}
+
 /// <pre><code>        &lt;p&gt;Represents an MSK Configuration.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -285,7 +548,8 @@ pub struct Configuration {
     /// <pre><code>        &lt;p&gt;The time when the configuration was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::iso8601")]
+    pub creation_time: EpochTimestamp,
     /// <pre><code>        &lt;p&gt;The description of the configuration.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "Description")]
@@ -326,7 +590,8 @@ pub struct ConfigurationRevision {
     /// <pre><code>        &lt;p&gt;The time when the configuration revision was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
-    pub creation_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::iso8601")]
+    pub creation_time: EpochTimestamp,
     /// <pre><code>        &lt;p&gt;The description of the configuration revision.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "Description")]
@@ -367,7 +632,7 @@ pub struct CreateClusterRequest {
     /// </code></pre>
     #[serde(rename = "EnhancedMonitoring")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub enhanced_monitoring: Option<String>,
+    pub enhanced_monitoring: Option<EnhancedMonitoring>,
     /// <pre><code>        &lt;p&gt;The version of Apache Kafka.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "KafkaVersion")]
@@ -383,6 +648,106 @@ pub struct CreateClusterRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateClusterRequest {
+    /// Returns a builder for `CreateClusterRequest`, checking its required members when built.
+    pub fn builder() -> CreateClusterRequestBuilder {
+        CreateClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateClusterRequest`](struct.CreateClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateClusterRequestBuilder {
+    broker_node_group_info: Option<BrokerNodeGroupInfo>,
+    client_authentication: Option<ClientAuthentication>,
+    cluster_name: Option<String>,
+    configuration_info: Option<ConfigurationInfo>,
+    encryption_info: Option<EncryptionInfo>,
+    enhanced_monitoring: Option<EnhancedMonitoring>,
+    kafka_version: Option<String>,
+    number_of_broker_nodes: Option<i64>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateClusterRequestBuilder {
+    /// Sets the [`broker_node_group_info`](struct.CreateClusterRequest.html#structfield.broker_node_group_info) member.
+    pub fn broker_node_group_info<T: Into<BrokerNodeGroupInfo>>(mut self, value: T) -> Self {
+        self.broker_node_group_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_authentication`](struct.CreateClusterRequest.html#structfield.client_authentication) member.
+    pub fn client_authentication<T: Into<ClientAuthentication>>(mut self, value: T) -> Self {
+        self.client_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_name`](struct.CreateClusterRequest.html#structfield.cluster_name) member.
+    pub fn cluster_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration_info`](struct.CreateClusterRequest.html#structfield.configuration_info) member.
+    pub fn configuration_info<T: Into<ConfigurationInfo>>(mut self, value: T) -> Self {
+        self.configuration_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`encryption_info`](struct.CreateClusterRequest.html#structfield.encryption_info) member.
+    pub fn encryption_info<T: Into<EncryptionInfo>>(mut self, value: T) -> Self {
+        self.encryption_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enhanced_monitoring`](struct.CreateClusterRequest.html#structfield.enhanced_monitoring) member.
+    pub fn enhanced_monitoring<T: Into<EnhancedMonitoring>>(mut self, value: T) -> Self {
+        self.enhanced_monitoring = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kafka_version`](struct.CreateClusterRequest.html#structfield.kafka_version) member.
+    pub fn kafka_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.kafka_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_broker_nodes`](struct.CreateClusterRequest.html#structfield.number_of_broker_nodes) member.
+    pub fn number_of_broker_nodes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_broker_nodes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateClusterRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateClusterRequest, ::rusoto_core::BuildError> {
+        Ok(CreateClusterRequest {
+            broker_node_group_info: self
+                .broker_node_group_info
+                .ok_or(::rusoto_core::BuildError::missing("broker_node_group_info"))?,
+            client_authentication: self.client_authentication,
+            cluster_name: self
+                .cluster_name
+                .ok_or(::rusoto_core::BuildError::missing("cluster_name"))?,
+            configuration_info: self.configuration_info,
+            encryption_info: self.encryption_info,
+            enhanced_monitoring: self.enhanced_monitoring,
+            kafka_version: self
+                .kafka_version
+                .ok_or(::rusoto_core::BuildError::missing("kafka_version"))?,
+            number_of_broker_nodes: self
+                .number_of_broker_nodes
+                .ok_or(::rusoto_core::BuildError::missing("number_of_broker_nodes"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateClusterResponse {
@@ -400,7 +765,7 @@ pub struct CreateClusterResponse {
     /// </code></pre>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ClusterState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -430,6 +795,66 @@ pub struct CreateConfigurationRequest {
     pub server_properties: bytes::Bytes,
 }
 
+impl CreateConfigurationRequest {
+    /// Returns a builder for `CreateConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> CreateConfigurationRequestBuilder {
+        CreateConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConfigurationRequest`](struct.CreateConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateConfigurationRequestBuilder {
+    description: Option<String>,
+    kafka_versions: Option<Vec<String>>,
+    name: Option<String>,
+    server_properties: Option<bytes::Bytes>,
+}
+
+impl CreateConfigurationRequestBuilder {
+    /// Sets the [`description`](struct.CreateConfigurationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kafka_versions`](struct.CreateConfigurationRequest.html#structfield.kafka_versions) member.
+    pub fn kafka_versions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.kafka_versions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateConfigurationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_properties`](struct.CreateConfigurationRequest.html#structfield.server_properties) member.
+    pub fn server_properties<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.server_properties = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateConfigurationRequest {
+            description: self.description,
+            kafka_versions: self
+                .kafka_versions
+                .ok_or(::rusoto_core::BuildError::missing("kafka_versions"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            server_properties: self
+                .server_properties
+                .ok_or(::rusoto_core::BuildError::missing("server_properties"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateConfigurationResponse {
@@ -441,8 +866,9 @@ pub struct CreateConfigurationResponse {
     /// <pre><code>        &lt;p&gt;The time when the configuration was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <pre><code>        &lt;p&gt;Latest revision of the configuration.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "LatestRevision")]
@@ -468,6 +894,44 @@ pub struct DeleteClusterRequest {
     pub current_version: Option<String>,
 }
 
+impl DeleteClusterRequest {
+    /// Returns a builder for `DeleteClusterRequest`, checking its required members when built.
+    pub fn builder() -> DeleteClusterRequestBuilder {
+        DeleteClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteClusterRequest`](struct.DeleteClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteClusterRequestBuilder {
+    cluster_arn: Option<String>,
+    current_version: Option<String>,
+}
+
+impl DeleteClusterRequestBuilder {
+    /// Sets the [`cluster_arn`](struct.DeleteClusterRequest.html#structfield.cluster_arn) member.
+    pub fn cluster_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_version`](struct.DeleteClusterRequest.html#structfield.current_version) member.
+    pub fn current_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.current_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteClusterRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteClusterRequest {
+            cluster_arn: self
+                .cluster_arn
+                .ok_or(::rusoto_core::BuildError::missing("cluster_arn"))?,
+            current_version: self.current_version,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteClusterResponse {
@@ -480,7 +944,7 @@ pub struct DeleteClusterResponse {
     /// </code></pre>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ClusterState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -491,6 +955,38 @@ pub struct DescribeClusterOperationRequest {
     pub cluster_operation_arn: String,
 }
 
+impl DescribeClusterOperationRequest {
+    /// Returns a builder for `DescribeClusterOperationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeClusterOperationRequestBuilder {
+        DescribeClusterOperationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterOperationRequest`](struct.DescribeClusterOperationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterOperationRequestBuilder {
+    cluster_operation_arn: Option<String>,
+}
+
+impl DescribeClusterOperationRequestBuilder {
+    /// Sets the [`cluster_operation_arn`](struct.DescribeClusterOperationRequest.html#structfield.cluster_operation_arn) member.
+    pub fn cluster_operation_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_operation_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterOperationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClusterOperationRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeClusterOperationRequest {
+            cluster_operation_arn: self
+                .cluster_operation_arn
+                .ok_or(::rusoto_core::BuildError::missing("cluster_operation_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeClusterOperationResponse {
@@ -509,6 +1005,36 @@ pub struct DescribeClusterRequest {
     pub cluster_arn: String,
 }
 
+impl DescribeClusterRequest {
+    /// Returns a builder for `DescribeClusterRequest`, checking its required members when built.
+    pub fn builder() -> DescribeClusterRequestBuilder {
+        DescribeClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterRequest`](struct.DescribeClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterRequestBuilder {
+    cluster_arn: Option<String>,
+}
+
+impl DescribeClusterRequestBuilder {
+    /// Sets the [`cluster_arn`](struct.DescribeClusterRequest.html#structfield.cluster_arn) member.
+    pub fn cluster_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeClusterRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeClusterRequest {
+            cluster_arn: self
+                .cluster_arn
+                .ok_or(::rusoto_core::BuildError::missing("cluster_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeClusterResponse {
@@ -527,6 +1053,36 @@ pub struct DescribeConfigurationRequest {
     pub arn: String,
 }
 
+impl DescribeConfigurationRequest {
+    /// Returns a builder for `DescribeConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConfigurationRequestBuilder {
+        DescribeConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigurationRequest`](struct.DescribeConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigurationRequestBuilder {
+    arn: Option<String>,
+}
+
+impl DescribeConfigurationRequestBuilder {
+    /// Sets the [`arn`](struct.DescribeConfigurationRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeConfigurationRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeConfigurationResponse {
@@ -538,8 +1094,9 @@ pub struct DescribeConfigurationResponse {
     /// <pre><code>        &lt;p&gt;The time when the configuration was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <pre><code>        &lt;p&gt;The description of the configuration.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "Description")]
@@ -574,6 +1131,47 @@ pub struct DescribeConfigurationRevisionRequest {
     pub revision: i64,
 }
 
+impl DescribeConfigurationRevisionRequest {
+    /// Returns a builder for `DescribeConfigurationRevisionRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConfigurationRevisionRequestBuilder {
+        DescribeConfigurationRevisionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigurationRevisionRequest`](struct.DescribeConfigurationRevisionRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigurationRevisionRequestBuilder {
+    arn: Option<String>,
+    revision: Option<i64>,
+}
+
+impl DescribeConfigurationRevisionRequestBuilder {
+    /// Sets the [`arn`](struct.DescribeConfigurationRevisionRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision`](struct.DescribeConfigurationRevisionRequest.html#structfield.revision) member.
+    pub fn revision<T: Into<i64>>(mut self, value: T) -> Self {
+        self.revision = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigurationRevisionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigurationRevisionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeConfigurationRevisionRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            revision: self
+                .revision
+                .ok_or(::rusoto_core::BuildError::missing("revision"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeConfigurationRevisionResponse {
@@ -585,8 +1183,9 @@ pub struct DescribeConfigurationRevisionResponse {
     /// <pre><code>        &lt;p&gt;The time when the configuration was created.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <pre><code>        &lt;p&gt;The description of the configuration.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "Description")]
@@ -646,7 +1245,7 @@ pub struct EncryptionInTransit {
     /// </code></pre>
     #[serde(rename = "ClientBroker")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub client_broker: Option<String>,
+    pub client_broker: Option<ClientBroker>,
     /// <pre><code>        &lt;p&gt;When set to true, it indicates that data communication among the broker nodes of the cluster is encrypted. When set to false, the communication happens in plaintext.&lt;/p&gt;
     /// &lt;p&gt;The default value is true.&lt;/p&gt;
     /// </code></pre>
@@ -671,6 +1270,92 @@ pub struct EncryptionInfo {
     pub encryption_in_transit: Option<EncryptionInTransit>,
 }
 
+/// <p>Specifies which metrics are gathered for the MSK cluster. This property has the following possible values: DEFAULT, PER_BROKER, PER_TOPIC_PER_BROKER, and PER_TOPIC_PER_PARTITION. For a list of the metrics associated with each of these levels of monitoring, see <a href="https://docs.aws.amazon.com/msk/latest/developerguide/monitoring.html">Monitoring</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnhancedMonitoring {
+    /// <p><code>DEFAULT</code></p>
+    Default,
+    /// <p><code>PER_BROKER</code></p>
+    PerBroker,
+    /// <p><code>PER_TOPIC_PER_BROKER</code></p>
+    PerTopicPerBroker,
+    /// <p><code>PER_TOPIC_PER_PARTITION</code></p>
+    PerTopicPerPartition,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EnhancedMonitoring {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EnhancedMonitoring::Default => "DEFAULT",
+            EnhancedMonitoring::PerBroker => "PER_BROKER",
+            EnhancedMonitoring::PerTopicPerBroker => "PER_TOPIC_PER_BROKER",
+            EnhancedMonitoring::PerTopicPerPartition => "PER_TOPIC_PER_PARTITION",
+            EnhancedMonitoring::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EnhancedMonitoring {
+    fn from(value: &'a str) -> EnhancedMonitoring {
+        match value {
+            "DEFAULT" => EnhancedMonitoring::Default,
+            "PER_BROKER" => EnhancedMonitoring::PerBroker,
+            "PER_TOPIC_PER_BROKER" => EnhancedMonitoring::PerTopicPerBroker,
+            "PER_TOPIC_PER_PARTITION" => EnhancedMonitoring::PerTopicPerPartition,
+            _ => EnhancedMonitoring::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EnhancedMonitoring {
+    fn from(value: String) -> EnhancedMonitoring {
+        EnhancedMonitoring::from(value.as_str())
+    }
+}
+
+impl From<EnhancedMonitoring> for String {
+    fn from(value: EnhancedMonitoring) -> String {
+        match value {
+            EnhancedMonitoring::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EnhancedMonitoring {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EnhancedMonitoring {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EnhancedMonitoring {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EnhancedMonitoring {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EnhancedMonitoring, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EnhancedMonitoring::from(value))
+    }
+}
+
 /// <pre><code>        &lt;p&gt;Returns information about an error state of the cluster.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -696,6 +1381,38 @@ pub struct GetBootstrapBrokersRequest {
     pub cluster_arn: String,
 }
 
+impl GetBootstrapBrokersRequest {
+    /// Returns a builder for `GetBootstrapBrokersRequest`, checking its required members when built.
+    pub fn builder() -> GetBootstrapBrokersRequestBuilder {
+        GetBootstrapBrokersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBootstrapBrokersRequest`](struct.GetBootstrapBrokersRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBootstrapBrokersRequestBuilder {
+    cluster_arn: Option<String>,
+}
+
+impl GetBootstrapBrokersRequestBuilder {
+    /// Sets the [`cluster_arn`](struct.GetBootstrapBrokersRequest.html#structfield.cluster_arn) member.
+    pub fn cluster_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBootstrapBrokersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetBootstrapBrokersRequest, ::rusoto_core::BuildError> {
+        Ok(GetBootstrapBrokersRequest {
+            cluster_arn: self
+                .cluster_arn
+                .ok_or(::rusoto_core::BuildError::missing("cluster_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBootstrapBrokersResponse {
@@ -730,6 +1447,54 @@ pub struct ListClusterOperationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListClusterOperationsRequest {
+    /// Returns a builder for `ListClusterOperationsRequest`, checking its required members when built.
+    pub fn builder() -> ListClusterOperationsRequestBuilder {
+        ListClusterOperationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListClusterOperationsRequest`](struct.ListClusterOperationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListClusterOperationsRequestBuilder {
+    cluster_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListClusterOperationsRequestBuilder {
+    /// Sets the [`cluster_arn`](struct.ListClusterOperationsRequest.html#structfield.cluster_arn) member.
+    pub fn cluster_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListClusterOperationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListClusterOperationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListClusterOperationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListClusterOperationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListClusterOperationsRequest {
+            cluster_arn: self
+                .cluster_arn
+                .ok_or(::rusoto_core::BuildError::missing("cluster_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListClusterOperationsResponse {
@@ -765,6 +1530,50 @@ pub struct ListClustersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListClustersRequest {
+    /// Returns a builder for `ListClustersRequest`, checking its required members when built.
+    pub fn builder() -> ListClustersRequestBuilder {
+        ListClustersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListClustersRequest`](struct.ListClustersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListClustersRequestBuilder {
+    cluster_name_filter: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListClustersRequestBuilder {
+    /// Sets the [`cluster_name_filter`](struct.ListClustersRequest.html#structfield.cluster_name_filter) member.
+    pub fn cluster_name_filter<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_name_filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListClustersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListClustersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListClustersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListClustersRequest, ::rusoto_core::BuildError> {
+        Ok(ListClustersRequest {
+            cluster_name_filter: self.cluster_name_filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListClustersResponse {
@@ -800,6 +1609,52 @@ pub struct ListConfigurationRevisionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListConfigurationRevisionsRequest {
+    /// Returns a builder for `ListConfigurationRevisionsRequest`, checking its required members when built.
+    pub fn builder() -> ListConfigurationRevisionsRequestBuilder {
+        ListConfigurationRevisionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListConfigurationRevisionsRequest`](struct.ListConfigurationRevisionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListConfigurationRevisionsRequestBuilder {
+    arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListConfigurationRevisionsRequestBuilder {
+    /// Sets the [`arn`](struct.ListConfigurationRevisionsRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListConfigurationRevisionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListConfigurationRevisionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListConfigurationRevisionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListConfigurationRevisionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListConfigurationRevisionsRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListConfigurationRevisionsResponse {
@@ -830,6 +1685,44 @@ pub struct ListConfigurationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListConfigurationsRequest {
+    /// Returns a builder for `ListConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> ListConfigurationsRequestBuilder {
+        ListConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListConfigurationsRequest`](struct.ListConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListConfigurationsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListConfigurationsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListConfigurationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListConfigurationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListConfigurationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListConfigurationsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListConfigurationsResponse {
@@ -865,6 +1758,52 @@ pub struct ListNodesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListNodesRequest {
+    /// Returns a builder for `ListNodesRequest`, checking its required members when built.
+    pub fn builder() -> ListNodesRequestBuilder {
+        ListNodesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListNodesRequest`](struct.ListNodesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListNodesRequestBuilder {
+    cluster_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListNodesRequestBuilder {
+    /// Sets the [`cluster_arn`](struct.ListNodesRequest.html#structfield.cluster_arn) member.
+    pub fn cluster_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListNodesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListNodesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListNodesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListNodesRequest, ::rusoto_core::BuildError> {
+        Ok(ListNodesRequest {
+            cluster_arn: self
+                .cluster_arn
+                .ok_or(::rusoto_core::BuildError::missing("cluster_arn"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListNodesResponse {
@@ -889,6 +1828,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -950,7 +1921,7 @@ pub struct NodeInfo {
     /// </code></pre>
     #[serde(rename = "NodeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_type: Option<String>,
+    pub node_type: Option<NodeType>,
     /// <pre><code>        &lt;p&gt;The ZookeeperNodeInfo.&lt;/p&gt;
     /// </code></pre>
     #[serde(rename = "ZookeeperNodeInfo")]
@@ -958,6 +1929,80 @@ pub struct NodeInfo {
     pub zookeeper_node_info: Option<ZookeeperNodeInfo>,
 }
 
+/// <p>The broker or Zookeeper node.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NodeType {
+    /// <p><code>BROKER</code></p>
+    Broker,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NodeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodeType::Broker => "BROKER",
+            NodeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NodeType {
+    fn from(value: &'a str) -> NodeType {
+        match value {
+            "BROKER" => NodeType::Broker,
+            _ => NodeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeType {
+    fn from(value: String) -> NodeType {
+        NodeType::from(value.as_str())
+    }
+}
+
+impl From<NodeType> for String {
+    fn from(value: NodeType) -> String {
+        match value {
+            NodeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NodeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for NodeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for NodeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NodeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NodeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NodeType::from(value))
+    }
+}
+
 /// <pre><code>        &lt;p&gt;Contains information about storage volumes attached to MSK broker nodes.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -981,6 +2026,46 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <pre><code>        &lt;p&gt;Details for client authentication using TLS.&lt;/p&gt;
 /// </code></pre>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -1023,6 +2108,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateBrokerStorageRequest {
     /// <pre><code>        &lt;p&gt;The Amazon Resource Name (ARN) that uniquely identifies the cluster.&lt;/p&gt;
@@ -1039,6 +2164,61 @@ pub struct UpdateBrokerStorageRequest {
     pub target_broker_ebs_volume_info: Vec<BrokerEBSVolumeInfo>,
 }
 
+impl UpdateBrokerStorageRequest {
+    /// Returns a builder for `UpdateBrokerStorageRequest`, checking its required members when built.
+    pub fn builder() -> UpdateBrokerStorageRequestBuilder {
+        UpdateBrokerStorageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateBrokerStorageRequest`](struct.UpdateBrokerStorageRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateBrokerStorageRequestBuilder {
+    cluster_arn: Option<String>,
+    current_version: Option<String>,
+    target_broker_ebs_volume_info: Option<Vec<BrokerEBSVolumeInfo>>,
+}
+
+impl UpdateBrokerStorageRequestBuilder {
+    /// Sets the [`cluster_arn`](struct.UpdateBrokerStorageRequest.html#structfield.cluster_arn) member.
+    pub fn cluster_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_version`](struct.UpdateBrokerStorageRequest.html#structfield.current_version) member.
+    pub fn current_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.current_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_broker_ebs_volume_info`](struct.UpdateBrokerStorageRequest.html#structfield.target_broker_ebs_volume_info) member.
+    pub fn target_broker_ebs_volume_info<T: Into<Vec<BrokerEBSVolumeInfo>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.target_broker_ebs_volume_info = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateBrokerStorageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateBrokerStorageRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateBrokerStorageRequest {
+            cluster_arn: self
+                .cluster_arn
+                .ok_or(::rusoto_core::BuildError::missing("cluster_arn"))?,
+            current_version: self
+                .current_version
+                .ok_or(::rusoto_core::BuildError::missing("current_version"))?,
+            target_broker_ebs_volume_info: self.target_broker_ebs_volume_info.ok_or(
+                ::rusoto_core::BuildError::missing("target_broker_ebs_volume_info"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateBrokerStorageResponse {
@@ -1070,6 +2250,58 @@ pub struct UpdateClusterConfigurationRequest {
     pub current_version: String,
 }
 
+impl UpdateClusterConfigurationRequest {
+    /// Returns a builder for `UpdateClusterConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateClusterConfigurationRequestBuilder {
+        UpdateClusterConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateClusterConfigurationRequest`](struct.UpdateClusterConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateClusterConfigurationRequestBuilder {
+    cluster_arn: Option<String>,
+    configuration_info: Option<ConfigurationInfo>,
+    current_version: Option<String>,
+}
+
+impl UpdateClusterConfigurationRequestBuilder {
+    /// Sets the [`cluster_arn`](struct.UpdateClusterConfigurationRequest.html#structfield.cluster_arn) member.
+    pub fn cluster_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration_info`](struct.UpdateClusterConfigurationRequest.html#structfield.configuration_info) member.
+    pub fn configuration_info<T: Into<ConfigurationInfo>>(mut self, value: T) -> Self {
+        self.configuration_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_version`](struct.UpdateClusterConfigurationRequest.html#structfield.current_version) member.
+    pub fn current_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.current_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateClusterConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateClusterConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateClusterConfigurationRequest {
+            cluster_arn: self
+                .cluster_arn
+                .ok_or(::rusoto_core::BuildError::missing("cluster_arn"))?,
+            configuration_info: self
+                .configuration_info
+                .ok_or(::rusoto_core::BuildError::missing("configuration_info"))?,
+            current_version: self
+                .current_version
+                .ok_or(::rusoto_core::BuildError::missing("current_version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateClusterConfigurationResponse {
@@ -2405,6 +3637,118 @@ pub trait Kafka {
         input: UpdateClusterConfigurationRequest,
     ) -> RusotoFuture<UpdateClusterConfigurationResponse, UpdateClusterConfigurationError>;
 }
+impl<T: ?Sized + Kafka> Kafka for ::std::sync::Arc<T> {
+    fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> RusotoFuture<CreateClusterResponse, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> RusotoFuture<CreateConfigurationResponse, CreateConfigurationError> {
+        (**self).create_configuration(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> RusotoFuture<DeleteClusterResponse, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> RusotoFuture<DescribeClusterResponse, DescribeClusterError> {
+        (**self).describe_cluster(input)
+    }
+    fn describe_cluster_operation(
+        &self,
+        input: DescribeClusterOperationRequest,
+    ) -> RusotoFuture<DescribeClusterOperationResponse, DescribeClusterOperationError> {
+        (**self).describe_cluster_operation(input)
+    }
+    fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> RusotoFuture<DescribeConfigurationResponse, DescribeConfigurationError> {
+        (**self).describe_configuration(input)
+    }
+    fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> RusotoFuture<DescribeConfigurationRevisionResponse, DescribeConfigurationRevisionError>
+    {
+        (**self).describe_configuration_revision(input)
+    }
+    fn get_bootstrap_brokers(
+        &self,
+        input: GetBootstrapBrokersRequest,
+    ) -> RusotoFuture<GetBootstrapBrokersResponse, GetBootstrapBrokersError> {
+        (**self).get_bootstrap_brokers(input)
+    }
+    fn list_cluster_operations(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> RusotoFuture<ListClusterOperationsResponse, ListClusterOperationsError> {
+        (**self).list_cluster_operations(input)
+    }
+    fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> RusotoFuture<ListClustersResponse, ListClustersError> {
+        (**self).list_clusters(input)
+    }
+    fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> RusotoFuture<ListConfigurationRevisionsResponse, ListConfigurationRevisionsError> {
+        (**self).list_configuration_revisions(input)
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        (**self).list_configurations(input)
+    }
+    fn list_nodes(
+        &self,
+        input: ListNodesRequest,
+    ) -> RusotoFuture<ListNodesResponse, ListNodesError> {
+        (**self).list_nodes(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_broker_storage(
+        &self,
+        input: UpdateBrokerStorageRequest,
+    ) -> RusotoFuture<UpdateBrokerStorageResponse, UpdateBrokerStorageError> {
+        (**self).update_broker_storage(input)
+    }
+    fn update_cluster_configuration(
+        &self,
+        input: UpdateClusterConfigurationRequest,
+    ) -> RusotoFuture<UpdateClusterConfigurationResponse, UpdateClusterConfigurationError> {
+        (**self).update_cluster_configuration(input)
+    }
+}
+/// The error codes Kafka retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the Kafka API.
 #[derive(Clone)]
 pub struct KafkaClient {
@@ -2420,6 +3764,24 @@ impl KafkaClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_KAFKA`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> KafkaClient {
+        Self::new(region::Region::from_env_for_service("KAFKA"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `kafka`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> KafkaClient {
+        Self::new_with_client(config.client().clone(), config.region_for("kafka", "KAFKA"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2438,7 +3800,49 @@ impl KafkaClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KafkaClient {
-        KafkaClient { client, region }
+        KafkaClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "kafka")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "kafka", "kafka", "KAFKA")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Kafka` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Kafka + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for KafkaClient {
+    fn service_name(&self) -> &'static str {
+        "Kafka"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "kafka"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> KafkaClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,40 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Kinesis Video Streams Archived Media clients.
+//!
+//! Makes a single read-only `GetDashStreamingSessionUrl` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_kinesis_video_archived_media::{
+    GetDASHStreamingSessionURLInput, KinesisVideoArchivedMedia, KinesisVideoArchivedMediaClient,
+};
+
+fn main() {
+    let client = KinesisVideoArchivedMediaClient::from_env();
+    match client
+        .get_dash_streaming_session_url(GetDASHStreamingSessionURLInput::default())
+        .sync()
+    {
+        Ok(_) => println!("GetDashStreamingSessionUrl succeeded"),
+        Err(error) => {
+            eprintln!("GetDashStreamingSessionUrl failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
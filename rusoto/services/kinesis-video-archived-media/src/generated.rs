@@ -22,31 +22,430 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>Specifies which format should be used for packaging the media. Specifying the <code>FRAGMENTED_MP4</code> container format packages the media into MP4 fragments (fMP4 or CMAF). This is the recommended packaging because there is minimal packaging overhead. The other container format option is <code>MPEG_TS</code>. HLS has supported MPEG TS chunks since it was released and is sometimes the only supported packaging on older HLS players. MPEG TS typically has a 5-25 percent packaging overhead. This means MPEG TS typically requires 5-25 percent more bandwidth and cost than fMP4.</p> <p>The default is <code>FRAGMENTED_MP4</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContainerFormat {
+    /// <p><code>FRAGMENTED_MP4</code></p>
+    FragmentedMp4,
+    /// <p><code>MPEG_TS</code></p>
+    MpegTs,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContainerFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContainerFormat::FragmentedMp4 => "FRAGMENTED_MP4",
+            ContainerFormat::MpegTs => "MPEG_TS",
+            ContainerFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContainerFormat {
+    fn from(value: &'a str) -> ContainerFormat {
+        match value {
+            "FRAGMENTED_MP4" => ContainerFormat::FragmentedMp4,
+            "MPEG_TS" => ContainerFormat::MpegTs,
+            _ => ContainerFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContainerFormat {
+    fn from(value: String) -> ContainerFormat {
+        ContainerFormat::from(value.as_str())
+    }
+}
+
+impl From<ContainerFormat> for String {
+    fn from(value: ContainerFormat) -> String {
+        match value {
+            ContainerFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContainerFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContainerFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContainerFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContainerFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContainerFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContainerFormat::from(value))
+    }
+}
+
+/// <p>Fragments are identified in the manifest file based on their sequence number in the session. If DisplayFragmentNumber is set to <code>ALWAYS</code>, the Kinesis Video Streams fragment number is added to each S element in the manifest file with the attribute name “kvs:fn”. These fragment numbers can be used for logging or for use with other APIs (e.g. <code>GetMedia</code> and <code>GetMediaForFragmentList</code>). A custom MPEG-DASH media player is necessary to leverage these this custom attribute.</p> <p>The default value is <code>NEVER</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DASHDisplayFragmentNumber {
+    /// <p><code>ALWAYS</code></p>
+    Always,
+    /// <p><code>NEVER</code></p>
+    Never,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DASHDisplayFragmentNumber {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DASHDisplayFragmentNumber::Always => "ALWAYS",
+            DASHDisplayFragmentNumber::Never => "NEVER",
+            DASHDisplayFragmentNumber::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DASHDisplayFragmentNumber {
+    fn from(value: &'a str) -> DASHDisplayFragmentNumber {
+        match value {
+            "ALWAYS" => DASHDisplayFragmentNumber::Always,
+            "NEVER" => DASHDisplayFragmentNumber::Never,
+            _ => DASHDisplayFragmentNumber::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DASHDisplayFragmentNumber {
+    fn from(value: String) -> DASHDisplayFragmentNumber {
+        DASHDisplayFragmentNumber::from(value.as_str())
+    }
+}
+
+impl From<DASHDisplayFragmentNumber> for String {
+    fn from(value: DASHDisplayFragmentNumber) -> String {
+        match value {
+            DASHDisplayFragmentNumber::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DASHDisplayFragmentNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DASHDisplayFragmentNumber {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DASHDisplayFragmentNumber {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DASHDisplayFragmentNumber {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DASHDisplayFragmentNumber, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DASHDisplayFragmentNumber::from(value))
+    }
+}
+
+/// <p>Per the MPEG-DASH specification, the wall-clock time of fragments in the manifest file can be derived using attributes in the manifest itself. However, typically, MPEG-DASH compatible media players do not properly handle gaps in the media timeline. Kinesis Video Streams adjusts the media timeline in the manifest file to enable playback of media with discontinuities. Therefore, the wall-clock time derived from the manifest file may be inaccurate. If DisplayFragmentTimestamp is set to <code>ALWAYS</code>, the accurate fragment timestamp is added to each S element in the manifest file with the attribute name “kvs:ts”. A custom MPEG-DASH media player is necessary to leverage this custom attribute.</p> <p>The default value is <code>NEVER</code>. When <a>DASHFragmentSelector</a> is <code>SERVER_TIMESTAMP</code>, the timestamps will be the server start timestamps. Similarly, when <a>DASHFragmentSelector</a> is <code>PRODUCER_TIMESTAMP</code>, the timestamps will be the producer start timestamps. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DASHDisplayFragmentTimestamp {
+    /// <p><code>ALWAYS</code></p>
+    Always,
+    /// <p><code>NEVER</code></p>
+    Never,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DASHDisplayFragmentTimestamp {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DASHDisplayFragmentTimestamp::Always => "ALWAYS",
+            DASHDisplayFragmentTimestamp::Never => "NEVER",
+            DASHDisplayFragmentTimestamp::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DASHDisplayFragmentTimestamp {
+    fn from(value: &'a str) -> DASHDisplayFragmentTimestamp {
+        match value {
+            "ALWAYS" => DASHDisplayFragmentTimestamp::Always,
+            "NEVER" => DASHDisplayFragmentTimestamp::Never,
+            _ => DASHDisplayFragmentTimestamp::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DASHDisplayFragmentTimestamp {
+    fn from(value: String) -> DASHDisplayFragmentTimestamp {
+        DASHDisplayFragmentTimestamp::from(value.as_str())
+    }
+}
+
+impl From<DASHDisplayFragmentTimestamp> for String {
+    fn from(value: DASHDisplayFragmentTimestamp) -> String {
+        match value {
+            DASHDisplayFragmentTimestamp::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DASHDisplayFragmentTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DASHDisplayFragmentTimestamp {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DASHDisplayFragmentTimestamp {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DASHDisplayFragmentTimestamp {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<DASHDisplayFragmentTimestamp, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DASHDisplayFragmentTimestamp::from(value))
+    }
+}
+
 /// <p>Contains the range of timestamps for the requested media, and the source of the timestamps. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DASHFragmentSelector {
     /// <p>The source of the timestamps for the requested media.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetDASHStreamingSessionURLInput$PlaybackMode</a> is <code>ON_DEMAND</code> or <code>LIVE_REPLAY</code>, the first fragment ingested with a producer timestamp within the specified <a>FragmentSelector$TimestampRange</a> is included in the media playlist. In addition, the fragments with producer timestamps within the <code>TimestampRange</code> ingested immediately following the first fragment (up to the <a>GetDASHStreamingSessionURLInput$MaxManifestFragmentResults</a> value) are included. </p> <p>Fragments that have duplicate producer timestamps are deduplicated. This means that if producers are producing a stream of fragments with producer timestamps that are approximately equal to the true clock time, the MPEG-DASH manifest will contain all of the fragments within the requested timestamp range. If some fragments are ingested within the same time range and very different points in time, only the oldest ingested collection of fragments are returned.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetDASHStreamingSessionURLInput$PlaybackMode</a> is <code>LIVE</code>, the producer timestamps are used in the MP4 fragments and for deduplication. But the most recently ingested fragments based on server timestamps are included in the MPEG-DASH manifest. This means that even if fragments ingested in the past have producer timestamps with values now, they are not included in the HLS media playlist.</p> <p>The default is <code>SERVER_TIMESTAMP</code>.</p>
     #[serde(rename = "FragmentSelectorType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fragment_selector_type: Option<String>,
+    pub fragment_selector_type: Option<DASHFragmentSelectorType>,
     /// <p>The start and end of the timestamp range for the requested media.</p> <p>This value should not be present if <code>PlaybackType</code> is <code>LIVE</code>.</p>
     #[serde(rename = "TimestampRange")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp_range: Option<DASHTimestampRange>,
 }
 
+/// <p>The source of the timestamps for the requested media.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetDASHStreamingSessionURLInput$PlaybackMode</a> is <code>ON_DEMAND</code> or <code>LIVE_REPLAY</code>, the first fragment ingested with a producer timestamp within the specified <a>FragmentSelector$TimestampRange</a> is included in the media playlist. In addition, the fragments with producer timestamps within the <code>TimestampRange</code> ingested immediately following the first fragment (up to the <a>GetDASHStreamingSessionURLInput$MaxManifestFragmentResults</a> value) are included. </p> <p>Fragments that have duplicate producer timestamps are deduplicated. This means that if producers are producing a stream of fragments with producer timestamps that are approximately equal to the true clock time, the MPEG-DASH manifest will contain all of the fragments within the requested timestamp range. If some fragments are ingested within the same time range and very different points in time, only the oldest ingested collection of fragments are returned.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetDASHStreamingSessionURLInput$PlaybackMode</a> is <code>LIVE</code>, the producer timestamps are used in the MP4 fragments and for deduplication. But the most recently ingested fragments based on server timestamps are included in the MPEG-DASH manifest. This means that even if fragments ingested in the past have producer timestamps with values now, they are not included in the HLS media playlist.</p> <p>The default is <code>SERVER_TIMESTAMP</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DASHFragmentSelectorType {
+    /// <p><code>PRODUCER_TIMESTAMP</code></p>
+    ProducerTimestamp,
+    /// <p><code>SERVER_TIMESTAMP</code></p>
+    ServerTimestamp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DASHFragmentSelectorType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DASHFragmentSelectorType::ProducerTimestamp => "PRODUCER_TIMESTAMP",
+            DASHFragmentSelectorType::ServerTimestamp => "SERVER_TIMESTAMP",
+            DASHFragmentSelectorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DASHFragmentSelectorType {
+    fn from(value: &'a str) -> DASHFragmentSelectorType {
+        match value {
+            "PRODUCER_TIMESTAMP" => DASHFragmentSelectorType::ProducerTimestamp,
+            "SERVER_TIMESTAMP" => DASHFragmentSelectorType::ServerTimestamp,
+            _ => DASHFragmentSelectorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DASHFragmentSelectorType {
+    fn from(value: String) -> DASHFragmentSelectorType {
+        DASHFragmentSelectorType::from(value.as_str())
+    }
+}
+
+impl From<DASHFragmentSelectorType> for String {
+    fn from(value: DASHFragmentSelectorType) -> String {
+        match value {
+            DASHFragmentSelectorType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DASHFragmentSelectorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DASHFragmentSelectorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DASHFragmentSelectorType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DASHFragmentSelectorType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DASHFragmentSelectorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DASHFragmentSelectorType::from(value))
+    }
+}
+
+/// <p>Whether to retrieve live, live replay, or archived, on-demand data.</p> <p>Features of the three types of sessions include the following:</p> <ul> <li> <p> <b> <code>LIVE</code> </b>: For sessions of this type, the MPEG-DASH manifest is continually updated with the latest fragments as they become available. We recommend that the media player retrieve a new manifest on a one-second interval. When this type of session is played in a media player, the user interface typically displays a "live" notification, with no scrubber control for choosing the position in the playback window to display.</p> <note> <p>In <code>LIVE</code> mode, the newest available fragments are included in an MPEG-DASH manifest, even if there is a gap between fragments (that is, if a fragment is missing). A gap like this might cause a media player to halt or cause a jump in playback. In this mode, fragments are not added to the MPEG-DASH manifest if they are older than the newest fragment in the playlist. If the missing fragment becomes available after a subsequent fragment is added to the manifest, the older fragment is not added, and the gap is not filled.</p> </note> </li> <li> <p> <b> <code>LIVE_REPLAY</code> </b>: For sessions of this type, the MPEG-DASH manifest is updated similarly to how it is updated for <code>LIVE</code> mode except that it starts by including fragments from a given start time. Instead of fragments being added as they are ingested, fragments are added as the duration of the next fragment elapses. For example, if the fragments in the session are two seconds long, then a new fragment is added to the manifest every two seconds. This mode is useful to be able to start playback from when an event is detected and continue live streaming media that has not yet been ingested as of the time of the session creation. This mode is also useful to stream previously archived media without being limited by the 1,000 fragment limit in the <code>ON_DEMAND</code> mode. </p> </li> <li> <p> <b> <code>ON_DEMAND</code> </b>: For sessions of this type, the MPEG-DASH manifest contains all the fragments for the session, up to the number that is specified in <code>MaxMediaPlaylistFragmentResults</code>. The manifest must be retrieved only once for each session. When this type of session is played in a media player, the user interface typically displays a scrubber control for choosing the position in the playback window to display.</p> </li> </ul> <p>In all playback modes, if <code>FragmentSelectorType</code> is <code>PRODUCER_TIMESTAMP</code>, and if there are multiple fragments with the same start timestamp, the fragment that has the larger fragment number (that is, the newer fragment) is included in the MPEG-DASH manifest. The other fragments are not included. Fragments that have different timestamps but have overlapping durations are still included in the MPEG-DASH manifest. This can lead to unexpected behavior in the media player.</p> <p>The default is <code>LIVE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DASHPlaybackMode {
+    /// <p><code>LIVE</code></p>
+    Live,
+    /// <p><code>LIVE_REPLAY</code></p>
+    LiveReplay,
+    /// <p><code>ON_DEMAND</code></p>
+    OnDemand,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DASHPlaybackMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DASHPlaybackMode::Live => "LIVE",
+            DASHPlaybackMode::LiveReplay => "LIVE_REPLAY",
+            DASHPlaybackMode::OnDemand => "ON_DEMAND",
+            DASHPlaybackMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DASHPlaybackMode {
+    fn from(value: &'a str) -> DASHPlaybackMode {
+        match value {
+            "LIVE" => DASHPlaybackMode::Live,
+            "LIVE_REPLAY" => DASHPlaybackMode::LiveReplay,
+            "ON_DEMAND" => DASHPlaybackMode::OnDemand,
+            _ => DASHPlaybackMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DASHPlaybackMode {
+    fn from(value: String) -> DASHPlaybackMode {
+        DASHPlaybackMode::from(value.as_str())
+    }
+}
+
+impl From<DASHPlaybackMode> for String {
+    fn from(value: DASHPlaybackMode) -> String {
+        match value {
+            DASHPlaybackMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DASHPlaybackMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DASHPlaybackMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DASHPlaybackMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DASHPlaybackMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DASHPlaybackMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DASHPlaybackMode::from(value))
+    }
+}
+
 /// <p><p>The start and end of the timestamp range for the requested media.</p> <p>This value should not be present if <code>PlaybackType</code> is <code>LIVE</code>.</p> <note> <p>The values in the <code>DASHimestampRange</code> are inclusive. Fragments that begin before the start time but continue past it, or fragments that begin before the end time but continue past it, are included in the session.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DASHTimestampRange {
     /// <p><p>The end of the timestamp range for the requested media. This value must be within 3 hours of the specified <code>StartTimestamp</code>, and it must be later than the <code>StartTimestamp</code> value.</p> <p>If <code>FragmentSelectorType</code> for the request is <code>SERVER<em>TIMESTAMP</code>, this value must be in the past.</p> <p>The <code>EndTimestamp</code> value is required for <code>ON</em>DEMAND</code> mode, but optional for <code>LIVE<em>REPLAY</code> mode. If the <code>EndTimestamp</code> is not set for <code>LIVE</em>REPLAY</code> mode then the session will continue to include newly ingested fragments until the session expires.</p> <note> <p>This value is inclusive. The <code>EndTimestamp</code> is compared to the (starting) timestamp of the fragment. Fragments that start before the <code>EndTimestamp</code> value and continue past it are included in the session.</p> </note></p>
     #[serde(rename = "EndTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_timestamp: Option<f64>,
+    pub end_timestamp: Option<EpochTimestamp>,
     /// <p><p>The start of the timestamp range for the requested media.</p> <p>If the <code>DASHTimestampRange</code> value is specified, the <code>StartTimestamp</code> value is required.</p> <note> <p>This value is inclusive. Fragments that start before the <code>StartTimestamp</code> and continue past it are included in the session. If <code>FragmentSelectorType</code> is <code>SERVER_TIMESTAMP</code>, the <code>StartTimestamp</code> must be later than the stream head.</p> </note></p>
     #[serde(rename = "StartTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_timestamp: Option<f64>,
+    pub start_timestamp: Option<EpochTimestamp>,
 }
 
 /// <p>Represents a segment of video or other time-delimited data.</p>
@@ -67,25 +466,105 @@ pub struct Fragment {
     pub fragment_size_in_bytes: Option<i64>,
     /// <p>The timestamp from the producer corresponding to the fragment.</p>
     #[serde(rename = "ProducerTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub producer_timestamp: Option<f64>,
+    pub producer_timestamp: Option<EpochTimestamp>,
     /// <p>The timestamp from the AWS server corresponding to the fragment.</p>
     #[serde(rename = "ServerTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub server_timestamp: Option<f64>,
+    pub server_timestamp: Option<EpochTimestamp>,
 }
 
 /// <p>Describes the timestamp range and timestamp origin of a range of fragments.</p> <p>Only fragments with a start timestamp greater than or equal to the given start time and less than or equal to the end time are returned. For example, if a stream contains fragments with the following start timestamps: </p> <ul> <li> <p>00:00:00</p> </li> <li> <p>00:00:02</p> </li> <li> <p>00:00:04</p> </li> <li> <p>00:00:06</p> </li> </ul> <p> A fragment selector range with a start time of 00:00:01 and end time of 00:00:04 would return the fragments with start times of 00:00:02 and 00:00:04. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct FragmentSelector {
     /// <p>The origin of the timestamps to use (Server or Producer).</p>
     #[serde(rename = "FragmentSelectorType")]
-    pub fragment_selector_type: String,
+    pub fragment_selector_type: FragmentSelectorType,
     /// <p>The range of timestamps to return.</p>
     #[serde(rename = "TimestampRange")]
     pub timestamp_range: TimestampRange,
 }
 
+/// <p>The origin of the timestamps to use (Server or Producer).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FragmentSelectorType {
+    /// <p><code>PRODUCER_TIMESTAMP</code></p>
+    ProducerTimestamp,
+    /// <p><code>SERVER_TIMESTAMP</code></p>
+    ServerTimestamp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FragmentSelectorType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FragmentSelectorType::ProducerTimestamp => "PRODUCER_TIMESTAMP",
+            FragmentSelectorType::ServerTimestamp => "SERVER_TIMESTAMP",
+            FragmentSelectorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FragmentSelectorType {
+    fn from(value: &'a str) -> FragmentSelectorType {
+        match value {
+            "PRODUCER_TIMESTAMP" => FragmentSelectorType::ProducerTimestamp,
+            "SERVER_TIMESTAMP" => FragmentSelectorType::ServerTimestamp,
+            _ => FragmentSelectorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FragmentSelectorType {
+    fn from(value: String) -> FragmentSelectorType {
+        FragmentSelectorType::from(value.as_str())
+    }
+}
+
+impl From<FragmentSelectorType> for String {
+    fn from(value: FragmentSelectorType) -> String {
+        match value {
+            FragmentSelectorType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FragmentSelectorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FragmentSelectorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FragmentSelectorType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FragmentSelectorType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FragmentSelectorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FragmentSelectorType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetDASHStreamingSessionURLInput {
     /// <p>The time range of the requested fragment, and the source of the timestamps.</p> <p>This parameter is required if <code>PlaybackMode</code> is <code>ON_DEMAND</code> or <code>LIVE_REPLAY</code>. This parameter is optional if PlaybackMode is<code/> <code>LIVE</code>. If <code>PlaybackMode</code> is <code>LIVE</code>, the <code>FragmentSelectorType</code> can be set, but the <code>TimestampRange</code> should not be set. If <code>PlaybackMode</code> is <code>ON_DEMAND</code> or <code>LIVE_REPLAY</code>, both <code>FragmentSelectorType</code> and <code>TimestampRange</code> must be set.</p>
@@ -95,11 +574,11 @@ pub struct GetDASHStreamingSessionURLInput {
     /// <p>Fragments are identified in the manifest file based on their sequence number in the session. If DisplayFragmentNumber is set to <code>ALWAYS</code>, the Kinesis Video Streams fragment number is added to each S element in the manifest file with the attribute name “kvs:fn”. These fragment numbers can be used for logging or for use with other APIs (e.g. <code>GetMedia</code> and <code>GetMediaForFragmentList</code>). A custom MPEG-DASH media player is necessary to leverage these this custom attribute.</p> <p>The default value is <code>NEVER</code>.</p>
     #[serde(rename = "DisplayFragmentNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_fragment_number: Option<String>,
+    pub display_fragment_number: Option<DASHDisplayFragmentNumber>,
     /// <p>Per the MPEG-DASH specification, the wall-clock time of fragments in the manifest file can be derived using attributes in the manifest itself. However, typically, MPEG-DASH compatible media players do not properly handle gaps in the media timeline. Kinesis Video Streams adjusts the media timeline in the manifest file to enable playback of media with discontinuities. Therefore, the wall-clock time derived from the manifest file may be inaccurate. If DisplayFragmentTimestamp is set to <code>ALWAYS</code>, the accurate fragment timestamp is added to each S element in the manifest file with the attribute name “kvs:ts”. A custom MPEG-DASH media player is necessary to leverage this custom attribute.</p> <p>The default value is <code>NEVER</code>. When <a>DASHFragmentSelector</a> is <code>SERVER_TIMESTAMP</code>, the timestamps will be the server start timestamps. Similarly, when <a>DASHFragmentSelector</a> is <code>PRODUCER_TIMESTAMP</code>, the timestamps will be the producer start timestamps. </p>
     #[serde(rename = "DisplayFragmentTimestamp")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_fragment_timestamp: Option<String>,
+    pub display_fragment_timestamp: Option<DASHDisplayFragmentTimestamp>,
     /// <p>The time in seconds until the requested session expires. This value can be between 300 (5 minutes) and 43200 (12 hours).</p> <p>When a session expires, no new calls to <code>GetDashManifest</code>, <code>GetMP4InitFragment</code>, or <code>GetMP4MediaFragment</code> can be made for that session.</p> <p>The default is 300 (5 minutes).</p>
     #[serde(rename = "Expires")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -111,7 +590,7 @@ pub struct GetDASHStreamingSessionURLInput {
     /// <p>Whether to retrieve live, live replay, or archived, on-demand data.</p> <p>Features of the three types of sessions include the following:</p> <ul> <li> <p> <b> <code>LIVE</code> </b>: For sessions of this type, the MPEG-DASH manifest is continually updated with the latest fragments as they become available. We recommend that the media player retrieve a new manifest on a one-second interval. When this type of session is played in a media player, the user interface typically displays a "live" notification, with no scrubber control for choosing the position in the playback window to display.</p> <note> <p>In <code>LIVE</code> mode, the newest available fragments are included in an MPEG-DASH manifest, even if there is a gap between fragments (that is, if a fragment is missing). A gap like this might cause a media player to halt or cause a jump in playback. In this mode, fragments are not added to the MPEG-DASH manifest if they are older than the newest fragment in the playlist. If the missing fragment becomes available after a subsequent fragment is added to the manifest, the older fragment is not added, and the gap is not filled.</p> </note> </li> <li> <p> <b> <code>LIVE_REPLAY</code> </b>: For sessions of this type, the MPEG-DASH manifest is updated similarly to how it is updated for <code>LIVE</code> mode except that it starts by including fragments from a given start time. Instead of fragments being added as they are ingested, fragments are added as the duration of the next fragment elapses. For example, if the fragments in the session are two seconds long, then a new fragment is added to the manifest every two seconds. This mode is useful to be able to start playback from when an event is detected and continue live streaming media that has not yet been ingested as of the time of the session creation. This mode is also useful to stream previously archived media without being limited by the 1,000 fragment limit in the <code>ON_DEMAND</code> mode. </p> </li> <li> <p> <b> <code>ON_DEMAND</code> </b>: For sessions of this type, the MPEG-DASH manifest contains all the fragments for the session, up to the number that is specified in <code>MaxMediaPlaylistFragmentResults</code>. The manifest must be retrieved only once for each session. When this type of session is played in a media player, the user interface typically displays a scrubber control for choosing the position in the playback window to display.</p> </li> </ul> <p>In all playback modes, if <code>FragmentSelectorType</code> is <code>PRODUCER_TIMESTAMP</code>, and if there are multiple fragments with the same start timestamp, the fragment that has the larger fragment number (that is, the newer fragment) is included in the MPEG-DASH manifest. The other fragments are not included. Fragments that have different timestamps but have overlapping durations are still included in the MPEG-DASH manifest. This can lead to unexpected behavior in the media player.</p> <p>The default is <code>LIVE</code>.</p>
     #[serde(rename = "PlaybackMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub playback_mode: Option<String>,
+    pub playback_mode: Option<DASHPlaybackMode>,
     /// <p>The Amazon Resource Name (ARN) of the stream for which to retrieve the MPEG-DASH manifest URL.</p> <p>You must specify either the <code>StreamName</code> or the <code>StreamARN</code>.</p>
     #[serde(rename = "StreamARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -122,6 +601,95 @@ pub struct GetDASHStreamingSessionURLInput {
     pub stream_name: Option<String>,
 }
 
+impl GetDASHStreamingSessionURLInput {
+    /// Returns a builder for `GetDASHStreamingSessionURLInput`, checking its required members when built.
+    pub fn builder() -> GetDASHStreamingSessionURLInputBuilder {
+        GetDASHStreamingSessionURLInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetDASHStreamingSessionURLInput`](struct.GetDASHStreamingSessionURLInput.html).
+#[derive(Default, Debug)]
+pub struct GetDASHStreamingSessionURLInputBuilder {
+    dash_fragment_selector: Option<DASHFragmentSelector>,
+    display_fragment_number: Option<DASHDisplayFragmentNumber>,
+    display_fragment_timestamp: Option<DASHDisplayFragmentTimestamp>,
+    expires: Option<i64>,
+    max_manifest_fragment_results: Option<i64>,
+    playback_mode: Option<DASHPlaybackMode>,
+    stream_arn: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl GetDASHStreamingSessionURLInputBuilder {
+    /// Sets the [`dash_fragment_selector`](struct.GetDASHStreamingSessionURLInput.html#structfield.dash_fragment_selector) member.
+    pub fn dash_fragment_selector<T: Into<DASHFragmentSelector>>(mut self, value: T) -> Self {
+        self.dash_fragment_selector = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_fragment_number`](struct.GetDASHStreamingSessionURLInput.html#structfield.display_fragment_number) member.
+    pub fn display_fragment_number<T: Into<DASHDisplayFragmentNumber>>(mut self, value: T) -> Self {
+        self.display_fragment_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_fragment_timestamp`](struct.GetDASHStreamingSessionURLInput.html#structfield.display_fragment_timestamp) member.
+    pub fn display_fragment_timestamp<T: Into<DASHDisplayFragmentTimestamp>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.display_fragment_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expires`](struct.GetDASHStreamingSessionURLInput.html#structfield.expires) member.
+    pub fn expires<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expires = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_manifest_fragment_results`](struct.GetDASHStreamingSessionURLInput.html#structfield.max_manifest_fragment_results) member.
+    pub fn max_manifest_fragment_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_manifest_fragment_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`playback_mode`](struct.GetDASHStreamingSessionURLInput.html#structfield.playback_mode) member.
+    pub fn playback_mode<T: Into<DASHPlaybackMode>>(mut self, value: T) -> Self {
+        self.playback_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.GetDASHStreamingSessionURLInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.GetDASHStreamingSessionURLInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDASHStreamingSessionURLInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDASHStreamingSessionURLInput, ::rusoto_core::BuildError> {
+        Ok(GetDASHStreamingSessionURLInput {
+            dash_fragment_selector: self.dash_fragment_selector,
+            display_fragment_number: self.display_fragment_number,
+            display_fragment_timestamp: self.display_fragment_timestamp,
+            expires: self.expires,
+            max_manifest_fragment_results: self.max_manifest_fragment_results,
+            playback_mode: self.playback_mode,
+            stream_arn: self.stream_arn,
+            stream_name: self.stream_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDASHStreamingSessionURLOutput {
@@ -136,15 +704,15 @@ pub struct GetHLSStreamingSessionURLInput {
     /// <p>Specifies which format should be used for packaging the media. Specifying the <code>FRAGMENTED_MP4</code> container format packages the media into MP4 fragments (fMP4 or CMAF). This is the recommended packaging because there is minimal packaging overhead. The other container format option is <code>MPEG_TS</code>. HLS has supported MPEG TS chunks since it was released and is sometimes the only supported packaging on older HLS players. MPEG TS typically has a 5-25 percent packaging overhead. This means MPEG TS typically requires 5-25 percent more bandwidth and cost than fMP4.</p> <p>The default is <code>FRAGMENTED_MP4</code>.</p>
     #[serde(rename = "ContainerFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub container_format: Option<String>,
+    pub container_format: Option<ContainerFormat>,
     /// <p>Specifies when flags marking discontinuities between fragments will be added to the media playlists. The default is <code>ALWAYS</code> when <a>HLSFragmentSelector</a> is <code>SERVER_TIMESTAMP</code>, and <code>NEVER</code> when it is <code>PRODUCER_TIMESTAMP</code>.</p> <p>Media players typically build a timeline of media content to play, based on the timestamps of each fragment. This means that if there is any overlap between fragments (as is typical if <a>HLSFragmentSelector</a> is <code>SERVER_TIMESTAMP</code>), the media player timeline has small gaps between fragments in some places, and overwrites frames in other places. When there are discontinuity flags between fragments, the media player is expected to reset the timeline, resulting in the fragment being played immediately after the previous fragment. We recommend that you always have discontinuity flags between fragments if the fragment timestamps are not accurate or if fragments might be missing. You should not place discontinuity flags between fragments for the player timeline to accurately map to the producer timestamps.</p>
     #[serde(rename = "DiscontinuityMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub discontinuity_mode: Option<String>,
+    pub discontinuity_mode: Option<HLSDiscontinuityMode>,
     /// <p>Specifies when the fragment start timestamps should be included in the HLS media playlist. Typically, media players report the playhead position as a time relative to the start of the first fragment in the playback session. However, when the start timestamps are included in the HLS media playlist, some media players might report the current playhead as an absolute time based on the fragment timestamps. This can be useful for creating a playback experience that shows viewers the wall-clock time of the media.</p> <p>The default is <code>NEVER</code>. When <a>HLSFragmentSelector</a> is <code>SERVER_TIMESTAMP</code>, the timestamps will be the server start timestamps. Similarly, when <a>HLSFragmentSelector</a> is <code>PRODUCER_TIMESTAMP</code>, the timestamps will be the producer start timestamps. </p>
     #[serde(rename = "DisplayFragmentTimestamp")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_fragment_timestamp: Option<String>,
+    pub display_fragment_timestamp: Option<HLSDisplayFragmentTimestamp>,
     /// <p>The time in seconds until the requested session expires. This value can be between 300 (5 minutes) and 43200 (12 hours).</p> <p>When a session expires, no new calls to <code>GetHLSMasterPlaylist</code>, <code>GetHLSMediaPlaylist</code>, <code>GetMP4InitFragment</code>, <code>GetMP4MediaFragment</code>, or <code>GetTSFragment</code> can be made for that session.</p> <p>The default is 300 (5 minutes).</p>
     #[serde(rename = "Expires")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -160,7 +728,7 @@ pub struct GetHLSStreamingSessionURLInput {
     /// <p>Whether to retrieve live, live replay, or archived, on-demand data.</p> <p>Features of the three types of sessions include the following:</p> <ul> <li> <p> <b> <code>LIVE</code> </b>: For sessions of this type, the HLS media playlist is continually updated with the latest fragments as they become available. We recommend that the media player retrieve a new playlist on a one-second interval. When this type of session is played in a media player, the user interface typically displays a "live" notification, with no scrubber control for choosing the position in the playback window to display.</p> <note> <p>In <code>LIVE</code> mode, the newest available fragments are included in an HLS media playlist, even if there is a gap between fragments (that is, if a fragment is missing). A gap like this might cause a media player to halt or cause a jump in playback. In this mode, fragments are not added to the HLS media playlist if they are older than the newest fragment in the playlist. If the missing fragment becomes available after a subsequent fragment is added to the playlist, the older fragment is not added, and the gap is not filled.</p> </note> </li> <li> <p> <b> <code>LIVE_REPLAY</code> </b>: For sessions of this type, the HLS media playlist is updated similarly to how it is updated for <code>LIVE</code> mode except that it starts by including fragments from a given start time. Instead of fragments being added as they are ingested, fragments are added as the duration of the next fragment elapses. For example, if the fragments in the session are two seconds long, then a new fragment is added to the media playlist every two seconds. This mode is useful to be able to start playback from when an event is detected and continue live streaming media that has not yet been ingested as of the time of the session creation. This mode is also useful to stream previously archived media without being limited by the 1,000 fragment limit in the <code>ON_DEMAND</code> mode. </p> </li> <li> <p> <b> <code>ON_DEMAND</code> </b>: For sessions of this type, the HLS media playlist contains all the fragments for the session, up to the number that is specified in <code>MaxMediaPlaylistFragmentResults</code>. The playlist must be retrieved only once for each session. When this type of session is played in a media player, the user interface typically displays a scrubber control for choosing the position in the playback window to display.</p> </li> </ul> <p>In all playback modes, if <code>FragmentSelectorType</code> is <code>PRODUCER_TIMESTAMP</code>, and if there are multiple fragments with the same start timestamp, the fragment that has the larger fragment number (that is, the newer fragment) is included in the HLS media playlist. The other fragments are not included. Fragments that have different timestamps but have overlapping durations are still included in the HLS media playlist. This can lead to unexpected behavior in the media player.</p> <p>The default is <code>LIVE</code>.</p>
     #[serde(rename = "PlaybackMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub playback_mode: Option<String>,
+    pub playback_mode: Option<HLSPlaybackMode>,
     /// <p>The Amazon Resource Name (ARN) of the stream for which to retrieve the HLS master playlist URL.</p> <p>You must specify either the <code>StreamName</code> or the <code>StreamARN</code>.</p>
     #[serde(rename = "StreamARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -171,6 +739,103 @@ pub struct GetHLSStreamingSessionURLInput {
     pub stream_name: Option<String>,
 }
 
+impl GetHLSStreamingSessionURLInput {
+    /// Returns a builder for `GetHLSStreamingSessionURLInput`, checking its required members when built.
+    pub fn builder() -> GetHLSStreamingSessionURLInputBuilder {
+        GetHLSStreamingSessionURLInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetHLSStreamingSessionURLInput`](struct.GetHLSStreamingSessionURLInput.html).
+#[derive(Default, Debug)]
+pub struct GetHLSStreamingSessionURLInputBuilder {
+    container_format: Option<ContainerFormat>,
+    discontinuity_mode: Option<HLSDiscontinuityMode>,
+    display_fragment_timestamp: Option<HLSDisplayFragmentTimestamp>,
+    expires: Option<i64>,
+    hls_fragment_selector: Option<HLSFragmentSelector>,
+    max_media_playlist_fragment_results: Option<i64>,
+    playback_mode: Option<HLSPlaybackMode>,
+    stream_arn: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl GetHLSStreamingSessionURLInputBuilder {
+    /// Sets the [`container_format`](struct.GetHLSStreamingSessionURLInput.html#structfield.container_format) member.
+    pub fn container_format<T: Into<ContainerFormat>>(mut self, value: T) -> Self {
+        self.container_format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`discontinuity_mode`](struct.GetHLSStreamingSessionURLInput.html#structfield.discontinuity_mode) member.
+    pub fn discontinuity_mode<T: Into<HLSDiscontinuityMode>>(mut self, value: T) -> Self {
+        self.discontinuity_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`display_fragment_timestamp`](struct.GetHLSStreamingSessionURLInput.html#structfield.display_fragment_timestamp) member.
+    pub fn display_fragment_timestamp<T: Into<HLSDisplayFragmentTimestamp>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.display_fragment_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expires`](struct.GetHLSStreamingSessionURLInput.html#structfield.expires) member.
+    pub fn expires<T: Into<i64>>(mut self, value: T) -> Self {
+        self.expires = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hls_fragment_selector`](struct.GetHLSStreamingSessionURLInput.html#structfield.hls_fragment_selector) member.
+    pub fn hls_fragment_selector<T: Into<HLSFragmentSelector>>(mut self, value: T) -> Self {
+        self.hls_fragment_selector = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_media_playlist_fragment_results`](struct.GetHLSStreamingSessionURLInput.html#structfield.max_media_playlist_fragment_results) member.
+    pub fn max_media_playlist_fragment_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_media_playlist_fragment_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`playback_mode`](struct.GetHLSStreamingSessionURLInput.html#structfield.playback_mode) member.
+    pub fn playback_mode<T: Into<HLSPlaybackMode>>(mut self, value: T) -> Self {
+        self.playback_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.GetHLSStreamingSessionURLInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.GetHLSStreamingSessionURLInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetHLSStreamingSessionURLInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetHLSStreamingSessionURLInput, ::rusoto_core::BuildError> {
+        Ok(GetHLSStreamingSessionURLInput {
+            container_format: self.container_format,
+            discontinuity_mode: self.discontinuity_mode,
+            display_fragment_timestamp: self.display_fragment_timestamp,
+            expires: self.expires,
+            hls_fragment_selector: self.hls_fragment_selector,
+            max_media_playlist_fragment_results: self.max_media_playlist_fragment_results,
+            playback_mode: self.playback_mode,
+            stream_arn: self.stream_arn,
+            stream_name: self.stream_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetHLSStreamingSessionURLOutput {
@@ -190,6 +855,48 @@ pub struct GetMediaForFragmentListInput {
     pub stream_name: String,
 }
 
+impl GetMediaForFragmentListInput {
+    /// Returns a builder for `GetMediaForFragmentListInput`, checking its required members when built.
+    pub fn builder() -> GetMediaForFragmentListInputBuilder {
+        GetMediaForFragmentListInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetMediaForFragmentListInput`](struct.GetMediaForFragmentListInput.html).
+#[derive(Default, Debug)]
+pub struct GetMediaForFragmentListInputBuilder {
+    fragments: Option<Vec<String>>,
+    stream_name: Option<String>,
+}
+
+impl GetMediaForFragmentListInputBuilder {
+    /// Sets the [`fragments`](struct.GetMediaForFragmentListInput.html#structfield.fragments) member.
+    pub fn fragments<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.fragments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.GetMediaForFragmentListInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMediaForFragmentListInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetMediaForFragmentListInput, ::rusoto_core::BuildError> {
+        Ok(GetMediaForFragmentListInput {
+            fragments: self
+                .fragments
+                .ok_or(::rusoto_core::BuildError::missing("fragments"))?,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetMediaForFragmentListOutput {
     /// <p>The content type of the requested media.</p>
@@ -198,30 +905,354 @@ pub struct GetMediaForFragmentListOutput {
     pub payload: Option<bytes::Bytes>,
 }
 
+/// <p>Specifies when flags marking discontinuities between fragments will be added to the media playlists. The default is <code>ALWAYS</code> when <a>HLSFragmentSelector</a> is <code>SERVER_TIMESTAMP</code>, and <code>NEVER</code> when it is <code>PRODUCER_TIMESTAMP</code>.</p> <p>Media players typically build a timeline of media content to play, based on the timestamps of each fragment. This means that if there is any overlap between fragments (as is typical if <a>HLSFragmentSelector</a> is <code>SERVER_TIMESTAMP</code>), the media player timeline has small gaps between fragments in some places, and overwrites frames in other places. When there are discontinuity flags between fragments, the media player is expected to reset the timeline, resulting in the fragment being played immediately after the previous fragment. We recommend that you always have discontinuity flags between fragments if the fragment timestamps are not accurate or if fragments might be missing. You should not place discontinuity flags between fragments for the player timeline to accurately map to the producer timestamps.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HLSDiscontinuityMode {
+    /// <p><code>ALWAYS</code></p>
+    Always,
+    /// <p><code>NEVER</code></p>
+    Never,
+    /// <p><code>ON_DISCONTINUITY</code></p>
+    OnDiscontinuity,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HLSDiscontinuityMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HLSDiscontinuityMode::Always => "ALWAYS",
+            HLSDiscontinuityMode::Never => "NEVER",
+            HLSDiscontinuityMode::OnDiscontinuity => "ON_DISCONTINUITY",
+            HLSDiscontinuityMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HLSDiscontinuityMode {
+    fn from(value: &'a str) -> HLSDiscontinuityMode {
+        match value {
+            "ALWAYS" => HLSDiscontinuityMode::Always,
+            "NEVER" => HLSDiscontinuityMode::Never,
+            "ON_DISCONTINUITY" => HLSDiscontinuityMode::OnDiscontinuity,
+            _ => HLSDiscontinuityMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HLSDiscontinuityMode {
+    fn from(value: String) -> HLSDiscontinuityMode {
+        HLSDiscontinuityMode::from(value.as_str())
+    }
+}
+
+impl From<HLSDiscontinuityMode> for String {
+    fn from(value: HLSDiscontinuityMode) -> String {
+        match value {
+            HLSDiscontinuityMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HLSDiscontinuityMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HLSDiscontinuityMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HLSDiscontinuityMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HLSDiscontinuityMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HLSDiscontinuityMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HLSDiscontinuityMode::from(value))
+    }
+}
+
+/// <p>Specifies when the fragment start timestamps should be included in the HLS media playlist. Typically, media players report the playhead position as a time relative to the start of the first fragment in the playback session. However, when the start timestamps are included in the HLS media playlist, some media players might report the current playhead as an absolute time based on the fragment timestamps. This can be useful for creating a playback experience that shows viewers the wall-clock time of the media.</p> <p>The default is <code>NEVER</code>. When <a>HLSFragmentSelector</a> is <code>SERVER_TIMESTAMP</code>, the timestamps will be the server start timestamps. Similarly, when <a>HLSFragmentSelector</a> is <code>PRODUCER_TIMESTAMP</code>, the timestamps will be the producer start timestamps. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HLSDisplayFragmentTimestamp {
+    /// <p><code>ALWAYS</code></p>
+    Always,
+    /// <p><code>NEVER</code></p>
+    Never,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HLSDisplayFragmentTimestamp {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HLSDisplayFragmentTimestamp::Always => "ALWAYS",
+            HLSDisplayFragmentTimestamp::Never => "NEVER",
+            HLSDisplayFragmentTimestamp::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HLSDisplayFragmentTimestamp {
+    fn from(value: &'a str) -> HLSDisplayFragmentTimestamp {
+        match value {
+            "ALWAYS" => HLSDisplayFragmentTimestamp::Always,
+            "NEVER" => HLSDisplayFragmentTimestamp::Never,
+            _ => HLSDisplayFragmentTimestamp::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HLSDisplayFragmentTimestamp {
+    fn from(value: String) -> HLSDisplayFragmentTimestamp {
+        HLSDisplayFragmentTimestamp::from(value.as_str())
+    }
+}
+
+impl From<HLSDisplayFragmentTimestamp> for String {
+    fn from(value: HLSDisplayFragmentTimestamp) -> String {
+        match value {
+            HLSDisplayFragmentTimestamp::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HLSDisplayFragmentTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HLSDisplayFragmentTimestamp {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HLSDisplayFragmentTimestamp {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HLSDisplayFragmentTimestamp {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<HLSDisplayFragmentTimestamp, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HLSDisplayFragmentTimestamp::from(value))
+    }
+}
+
 /// <p>Contains the range of timestamps for the requested media, and the source of the timestamps.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct HLSFragmentSelector {
     /// <p>The source of the timestamps for the requested media.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetHLSStreamingSessionURLInput$PlaybackMode</a> is <code>ON_DEMAND</code> or <code>LIVE_REPLAY</code>, the first fragment ingested with a producer timestamp within the specified <a>FragmentSelector$TimestampRange</a> is included in the media playlist. In addition, the fragments with producer timestamps within the <code>TimestampRange</code> ingested immediately following the first fragment (up to the <a>GetHLSStreamingSessionURLInput$MaxMediaPlaylistFragmentResults</a> value) are included. </p> <p>Fragments that have duplicate producer timestamps are deduplicated. This means that if producers are producing a stream of fragments with producer timestamps that are approximately equal to the true clock time, the HLS media playlists will contain all of the fragments within the requested timestamp range. If some fragments are ingested within the same time range and very different points in time, only the oldest ingested collection of fragments are returned.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetHLSStreamingSessionURLInput$PlaybackMode</a> is <code>LIVE</code>, the producer timestamps are used in the MP4 fragments and for deduplication. But the most recently ingested fragments based on server timestamps are included in the HLS media playlist. This means that even if fragments ingested in the past have producer timestamps with values now, they are not included in the HLS media playlist.</p> <p>The default is <code>SERVER_TIMESTAMP</code>.</p>
     #[serde(rename = "FragmentSelectorType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fragment_selector_type: Option<String>,
+    pub fragment_selector_type: Option<HLSFragmentSelectorType>,
     /// <p>The start and end of the timestamp range for the requested media.</p> <p>This value should not be present if <code>PlaybackType</code> is <code>LIVE</code>.</p>
     #[serde(rename = "TimestampRange")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp_range: Option<HLSTimestampRange>,
 }
 
+/// <p>The source of the timestamps for the requested media.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetHLSStreamingSessionURLInput$PlaybackMode</a> is <code>ON_DEMAND</code> or <code>LIVE_REPLAY</code>, the first fragment ingested with a producer timestamp within the specified <a>FragmentSelector$TimestampRange</a> is included in the media playlist. In addition, the fragments with producer timestamps within the <code>TimestampRange</code> ingested immediately following the first fragment (up to the <a>GetHLSStreamingSessionURLInput$MaxMediaPlaylistFragmentResults</a> value) are included. </p> <p>Fragments that have duplicate producer timestamps are deduplicated. This means that if producers are producing a stream of fragments with producer timestamps that are approximately equal to the true clock time, the HLS media playlists will contain all of the fragments within the requested timestamp range. If some fragments are ingested within the same time range and very different points in time, only the oldest ingested collection of fragments are returned.</p> <p>When <code>FragmentSelectorType</code> is set to <code>PRODUCER_TIMESTAMP</code> and <a>GetHLSStreamingSessionURLInput$PlaybackMode</a> is <code>LIVE</code>, the producer timestamps are used in the MP4 fragments and for deduplication. But the most recently ingested fragments based on server timestamps are included in the HLS media playlist. This means that even if fragments ingested in the past have producer timestamps with values now, they are not included in the HLS media playlist.</p> <p>The default is <code>SERVER_TIMESTAMP</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HLSFragmentSelectorType {
+    /// <p><code>PRODUCER_TIMESTAMP</code></p>
+    ProducerTimestamp,
+    /// <p><code>SERVER_TIMESTAMP</code></p>
+    ServerTimestamp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HLSFragmentSelectorType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HLSFragmentSelectorType::ProducerTimestamp => "PRODUCER_TIMESTAMP",
+            HLSFragmentSelectorType::ServerTimestamp => "SERVER_TIMESTAMP",
+            HLSFragmentSelectorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HLSFragmentSelectorType {
+    fn from(value: &'a str) -> HLSFragmentSelectorType {
+        match value {
+            "PRODUCER_TIMESTAMP" => HLSFragmentSelectorType::ProducerTimestamp,
+            "SERVER_TIMESTAMP" => HLSFragmentSelectorType::ServerTimestamp,
+            _ => HLSFragmentSelectorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HLSFragmentSelectorType {
+    fn from(value: String) -> HLSFragmentSelectorType {
+        HLSFragmentSelectorType::from(value.as_str())
+    }
+}
+
+impl From<HLSFragmentSelectorType> for String {
+    fn from(value: HLSFragmentSelectorType) -> String {
+        match value {
+            HLSFragmentSelectorType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HLSFragmentSelectorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HLSFragmentSelectorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HLSFragmentSelectorType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HLSFragmentSelectorType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HLSFragmentSelectorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HLSFragmentSelectorType::from(value))
+    }
+}
+
+/// <p>Whether to retrieve live, live replay, or archived, on-demand data.</p> <p>Features of the three types of sessions include the following:</p> <ul> <li> <p> <b> <code>LIVE</code> </b>: For sessions of this type, the HLS media playlist is continually updated with the latest fragments as they become available. We recommend that the media player retrieve a new playlist on a one-second interval. When this type of session is played in a media player, the user interface typically displays a "live" notification, with no scrubber control for choosing the position in the playback window to display.</p> <note> <p>In <code>LIVE</code> mode, the newest available fragments are included in an HLS media playlist, even if there is a gap between fragments (that is, if a fragment is missing). A gap like this might cause a media player to halt or cause a jump in playback. In this mode, fragments are not added to the HLS media playlist if they are older than the newest fragment in the playlist. If the missing fragment becomes available after a subsequent fragment is added to the playlist, the older fragment is not added, and the gap is not filled.</p> </note> </li> <li> <p> <b> <code>LIVE_REPLAY</code> </b>: For sessions of this type, the HLS media playlist is updated similarly to how it is updated for <code>LIVE</code> mode except that it starts by including fragments from a given start time. Instead of fragments being added as they are ingested, fragments are added as the duration of the next fragment elapses. For example, if the fragments in the session are two seconds long, then a new fragment is added to the media playlist every two seconds. This mode is useful to be able to start playback from when an event is detected and continue live streaming media that has not yet been ingested as of the time of the session creation. This mode is also useful to stream previously archived media without being limited by the 1,000 fragment limit in the <code>ON_DEMAND</code> mode. </p> </li> <li> <p> <b> <code>ON_DEMAND</code> </b>: For sessions of this type, the HLS media playlist contains all the fragments for the session, up to the number that is specified in <code>MaxMediaPlaylistFragmentResults</code>. The playlist must be retrieved only once for each session. When this type of session is played in a media player, the user interface typically displays a scrubber control for choosing the position in the playback window to display.</p> </li> </ul> <p>In all playback modes, if <code>FragmentSelectorType</code> is <code>PRODUCER_TIMESTAMP</code>, and if there are multiple fragments with the same start timestamp, the fragment that has the larger fragment number (that is, the newer fragment) is included in the HLS media playlist. The other fragments are not included. Fragments that have different timestamps but have overlapping durations are still included in the HLS media playlist. This can lead to unexpected behavior in the media player.</p> <p>The default is <code>LIVE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HLSPlaybackMode {
+    /// <p><code>LIVE</code></p>
+    Live,
+    /// <p><code>LIVE_REPLAY</code></p>
+    LiveReplay,
+    /// <p><code>ON_DEMAND</code></p>
+    OnDemand,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HLSPlaybackMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HLSPlaybackMode::Live => "LIVE",
+            HLSPlaybackMode::LiveReplay => "LIVE_REPLAY",
+            HLSPlaybackMode::OnDemand => "ON_DEMAND",
+            HLSPlaybackMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HLSPlaybackMode {
+    fn from(value: &'a str) -> HLSPlaybackMode {
+        match value {
+            "LIVE" => HLSPlaybackMode::Live,
+            "LIVE_REPLAY" => HLSPlaybackMode::LiveReplay,
+            "ON_DEMAND" => HLSPlaybackMode::OnDemand,
+            _ => HLSPlaybackMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HLSPlaybackMode {
+    fn from(value: String) -> HLSPlaybackMode {
+        HLSPlaybackMode::from(value.as_str())
+    }
+}
+
+impl From<HLSPlaybackMode> for String {
+    fn from(value: HLSPlaybackMode) -> String {
+        match value {
+            HLSPlaybackMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HLSPlaybackMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for HLSPlaybackMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for HLSPlaybackMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HLSPlaybackMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HLSPlaybackMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HLSPlaybackMode::from(value))
+    }
+}
+
 /// <p><p>The start and end of the timestamp range for the requested media.</p> <p>This value should not be present if <code>PlaybackType</code> is <code>LIVE</code>.</p> <note> <p>The values in the <code>HLSTimestampRange</code> are inclusive. Fragments that begin before the start time but continue past it, or fragments that begin before the end time but continue past it, are included in the session.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct HLSTimestampRange {
     /// <p><p>The end of the timestamp range for the requested media. This value must be within 3 hours of the specified <code>StartTimestamp</code>, and it must be later than the <code>StartTimestamp</code> value.</p> <p>If <code>FragmentSelectorType</code> for the request is <code>SERVER<em>TIMESTAMP</code>, this value must be in the past.</p> <p>The <code>EndTimestamp</code> value is required for <code>ON</em>DEMAND</code> mode, but optional for <code>LIVE<em>REPLAY</code> mode. If the <code>EndTimestamp</code> is not set for <code>LIVE</em>REPLAY</code> mode then the session will continue to include newly ingested fragments until the session expires.</p> <note> <p>This value is inclusive. The <code>EndTimestamp</code> is compared to the (starting) timestamp of the fragment. Fragments that start before the <code>EndTimestamp</code> value and continue past it are included in the session.</p> </note></p>
     #[serde(rename = "EndTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_timestamp: Option<f64>,
+    pub end_timestamp: Option<EpochTimestamp>,
     /// <p><p>The start of the timestamp range for the requested media.</p> <p>If the <code>HLSTimestampRange</code> value is specified, the <code>StartTimestamp</code> value is required.</p> <note> <p>This value is inclusive. Fragments that start before the <code>StartTimestamp</code> and continue past it are included in the session. If <code>FragmentSelectorType</code> is <code>SERVER_TIMESTAMP</code>, the <code>StartTimestamp</code> must be later than the stream head.</p> </note></p>
     #[serde(rename = "StartTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_timestamp: Option<f64>,
+    pub start_timestamp: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -243,6 +1274,60 @@ pub struct ListFragmentsInput {
     pub stream_name: String,
 }
 
+impl ListFragmentsInput {
+    /// Returns a builder for `ListFragmentsInput`, checking its required members when built.
+    pub fn builder() -> ListFragmentsInputBuilder {
+        ListFragmentsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListFragmentsInput`](struct.ListFragmentsInput.html).
+#[derive(Default, Debug)]
+pub struct ListFragmentsInputBuilder {
+    fragment_selector: Option<FragmentSelector>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl ListFragmentsInputBuilder {
+    /// Sets the [`fragment_selector`](struct.ListFragmentsInput.html#structfield.fragment_selector) member.
+    pub fn fragment_selector<T: Into<FragmentSelector>>(mut self, value: T) -> Self {
+        self.fragment_selector = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListFragmentsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListFragmentsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.ListFragmentsInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFragmentsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListFragmentsInput, ::rusoto_core::BuildError> {
+        Ok(ListFragmentsInput {
+            fragment_selector: self.fragment_selector,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListFragmentsOutput {
@@ -261,10 +1346,12 @@ pub struct ListFragmentsOutput {
 pub struct TimestampRange {
     /// <p>The ending timestamp in the range of timestamps for which to return fragments.</p>
     #[serde(rename = "EndTimestamp")]
-    pub end_timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_timestamp: EpochTimestamp,
     /// <p>The starting timestamp in the range of timestamps for which to return fragments.</p>
     #[serde(rename = "StartTimestamp")]
-    pub start_timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_timestamp: EpochTimestamp,
 }
 
 /// Errors returned by GetDASHStreamingSessionURL
@@ -587,6 +1674,39 @@ pub trait KinesisVideoArchivedMedia {
         input: ListFragmentsInput,
     ) -> RusotoFuture<ListFragmentsOutput, ListFragmentsError>;
 }
+impl<T: ?Sized + KinesisVideoArchivedMedia> KinesisVideoArchivedMedia for ::std::sync::Arc<T> {
+    fn get_dash_streaming_session_url(
+        &self,
+        input: GetDASHStreamingSessionURLInput,
+    ) -> RusotoFuture<GetDASHStreamingSessionURLOutput, GetDASHStreamingSessionURLError> {
+        (**self).get_dash_streaming_session_url(input)
+    }
+    fn get_hls_streaming_session_url(
+        &self,
+        input: GetHLSStreamingSessionURLInput,
+    ) -> RusotoFuture<GetHLSStreamingSessionURLOutput, GetHLSStreamingSessionURLError> {
+        (**self).get_hls_streaming_session_url(input)
+    }
+    fn get_media_for_fragment_list(
+        &self,
+        input: GetMediaForFragmentListInput,
+    ) -> RusotoFuture<GetMediaForFragmentListOutput, GetMediaForFragmentListError> {
+        (**self).get_media_for_fragment_list(input)
+    }
+    fn list_fragments(
+        &self,
+        input: ListFragmentsInput,
+    ) -> RusotoFuture<ListFragmentsOutput, ListFragmentsError> {
+        (**self).list_fragments(input)
+    }
+}
+/// The error codes Kinesis Video Archived Media retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Kinesis Video Archived Media API.
 #[derive(Clone)]
 pub struct KinesisVideoArchivedMediaClient {
@@ -602,6 +1722,29 @@ impl KinesisVideoArchivedMediaClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_KINESIS_VIDEO_ARCHIVED_MEDIA`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> KinesisVideoArchivedMediaClient {
+        Self::new(region::Region::from_env_for_service(
+            "KINESIS_VIDEO_ARCHIVED_MEDIA",
+        ))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `kinesisvideo`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> KinesisVideoArchivedMediaClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("kinesisvideo", "KINESIS_VIDEO_ARCHIVED_MEDIA"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -623,7 +1766,54 @@ impl KinesisVideoArchivedMediaClient {
         client: Client,
         region: region::Region,
     ) -> KinesisVideoArchivedMediaClient {
-        KinesisVideoArchivedMediaClient { client, region }
+        KinesisVideoArchivedMediaClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "kinesisvideo")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "kinesisvideo",
+            "kinesisvideo",
+            "KINESIS_VIDEO_ARCHIVED_MEDIA",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `KinesisVideoArchivedMedia` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn KinesisVideoArchivedMedia + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for KinesisVideoArchivedMediaClient {
+    fn service_name(&self) -> &'static str {
+        "Kinesis Video Archived Media"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "kinesisvideo"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> KinesisVideoArchivedMediaClient {
+        Self::new_with_client(client, region)
     }
 }
 
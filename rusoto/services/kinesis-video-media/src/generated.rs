@@ -22,8 +22,9 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetMediaInput {
     /// <p>Identifies the starting chunk to get from the specified stream. </p>
     #[serde(rename = "StartSelector")]
@@ -38,6 +39,52 @@ pub struct GetMediaInput {
     pub stream_name: Option<String>,
 }
 
+impl GetMediaInput {
+    /// Returns a builder for `GetMediaInput`, checking its required members when built.
+    pub fn builder() -> GetMediaInputBuilder {
+        GetMediaInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetMediaInput`](struct.GetMediaInput.html).
+#[derive(Default, Debug)]
+pub struct GetMediaInputBuilder {
+    start_selector: Option<StartSelector>,
+    stream_arn: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl GetMediaInputBuilder {
+    /// Sets the [`start_selector`](struct.GetMediaInput.html#structfield.start_selector) member.
+    pub fn start_selector<T: Into<StartSelector>>(mut self, value: T) -> Self {
+        self.start_selector = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.GetMediaInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.GetMediaInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMediaInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMediaInput, ::rusoto_core::BuildError> {
+        Ok(GetMediaInput {
+            start_selector: self
+                .start_selector
+                .ok_or(::rusoto_core::BuildError::missing("start_selector"))?,
+            stream_arn: self.stream_arn,
+            stream_name: self.stream_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct GetMediaOutput {
     /// <p>The content type of the requested media.</p>
@@ -47,7 +94,7 @@ pub struct GetMediaOutput {
 }
 
 /// <p><p>Identifies the chunk on the Kinesis video stream where you want the <code>GetMedia</code> API to start returning media data. You have the following options to identify the starting chunk: </p> <ul> <li> <p>Choose the latest (or oldest) chunk.</p> </li> <li> <p>Identify a specific chunk. You can identify a specific chunk either by providing a fragment number or timestamp (server or producer). </p> </li> <li> <p>Each chunk&#39;s metadata includes a continuation token as a Matroska (MKV) tag (<code>AWS<em>KINESISVIDEO</em>CONTINUATION_TOKEN</code>). If your previous <code>GetMedia</code> request terminated, you can use this tag value in your next <code>GetMedia</code> request. The API then starts returning chunks starting where the last API ended.</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct StartSelector {
     /// <p>Specifies the fragment number from where you want the <code>GetMedia</code> API to start returning the fragments. </p>
     #[serde(rename = "AfterFragmentNumber")]
@@ -59,11 +106,106 @@ pub struct StartSelector {
     pub continuation_token: Option<String>,
     /// <p><p>Identifies the fragment on the Kinesis video stream where you want to start getting the data from.</p> <ul> <li> <p>NOW - Start with the latest chunk on the stream.</p> </li> <li> <p>EARLIEST - Start with earliest available chunk on the stream.</p> </li> <li> <p>FRAGMENT<em>NUMBER - Start with the chunk after a specific fragment. You must also specify the <code>AfterFragmentNumber</code> parameter.</p> </li> <li> <p>PRODUCER</em>TIMESTAMP or SERVER<em>TIMESTAMP - Start with the chunk containing a fragment with the specified producer or server timestamp. You specify the timestamp by adding <code>StartTimestamp</code>.</p> </li> <li> <p> CONTINUATION</em>TOKEN - Read using the specified continuation token. </p> </li> </ul> <note> <p>If you choose the NOW, EARLIEST, or CONTINUATION_TOKEN as the <code>startSelectorType</code>, you don&#39;t provide any additional information in the <code>startSelector</code>.</p> </note></p>
     #[serde(rename = "StartSelectorType")]
-    pub start_selector_type: String,
+    pub start_selector_type: StartSelectorType,
     /// <p>A timestamp value. This value is required if you choose the PRODUCER_TIMESTAMP or the SERVER_TIMESTAMP as the <code>startSelectorType</code>. The <code>GetMedia</code> API then starts with the chunk containing the fragment that has the specified timestamp.</p>
     #[serde(rename = "StartTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_timestamp: Option<f64>,
+    pub start_timestamp: Option<EpochTimestamp>,
+}
+
+/// <p><p>Identifies the fragment on the Kinesis video stream where you want to start getting the data from.</p> <ul> <li> <p>NOW - Start with the latest chunk on the stream.</p> </li> <li> <p>EARLIEST - Start with earliest available chunk on the stream.</p> </li> <li> <p>FRAGMENT<em>NUMBER - Start with the chunk after a specific fragment. You must also specify the <code>AfterFragmentNumber</code> parameter.</p> </li> <li> <p>PRODUCER</em>TIMESTAMP or SERVER<em>TIMESTAMP - Start with the chunk containing a fragment with the specified producer or server timestamp. You specify the timestamp by adding <code>StartTimestamp</code>.</p> </li> <li> <p> CONTINUATION</em>TOKEN - Read using the specified continuation token. </p> </li> </ul> <note> <p>If you choose the NOW, EARLIEST, or CONTINUATION_TOKEN as the <code>startSelectorType</code>, you don&#39;t provide any additional information in the <code>startSelector</code>.</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StartSelectorType {
+    /// <p><code>FRAGMENT_NUMBER</code></p>
+    FragmentNumber,
+    /// <p><code>SERVER_TIMESTAMP</code></p>
+    ServerTimestamp,
+    /// <p><code>PRODUCER_TIMESTAMP</code></p>
+    ProducerTimestamp,
+    /// <p><code>NOW</code></p>
+    Now,
+    /// <p><code>EARLIEST</code></p>
+    Earliest,
+    /// <p><code>CONTINUATION_TOKEN</code></p>
+    ContinuationToken,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StartSelectorType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StartSelectorType::FragmentNumber => "FRAGMENT_NUMBER",
+            StartSelectorType::ServerTimestamp => "SERVER_TIMESTAMP",
+            StartSelectorType::ProducerTimestamp => "PRODUCER_TIMESTAMP",
+            StartSelectorType::Now => "NOW",
+            StartSelectorType::Earliest => "EARLIEST",
+            StartSelectorType::ContinuationToken => "CONTINUATION_TOKEN",
+            StartSelectorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StartSelectorType {
+    fn from(value: &'a str) -> StartSelectorType {
+        match value {
+            "FRAGMENT_NUMBER" => StartSelectorType::FragmentNumber,
+            "SERVER_TIMESTAMP" => StartSelectorType::ServerTimestamp,
+            "PRODUCER_TIMESTAMP" => StartSelectorType::ProducerTimestamp,
+            "NOW" => StartSelectorType::Now,
+            "EARLIEST" => StartSelectorType::Earliest,
+            "CONTINUATION_TOKEN" => StartSelectorType::ContinuationToken,
+            _ => StartSelectorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StartSelectorType {
+    fn from(value: String) -> StartSelectorType {
+        StartSelectorType::from(value.as_str())
+    }
+}
+
+impl From<StartSelectorType> for String {
+    fn from(value: StartSelectorType) -> String {
+        match value {
+            StartSelectorType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StartSelectorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StartSelectorType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StartSelectorType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StartSelectorType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StartSelectorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StartSelectorType::from(value))
+    }
 }
 
 /// Errors returned by GetMedia
@@ -134,6 +276,18 @@ pub trait KinesisVideoMedia {
     /// <p><p> Use this API to retrieve media content from a Kinesis video stream. In the request, you identify the stream name or stream Amazon Resource Name (ARN), and the starting chunk. Kinesis Video Streams then returns a stream of chunks in order by fragment number.</p> <note> <p>You must first call the <code>GetDataEndpoint</code> API to get an endpoint. Then send the <code>GetMedia</code> requests to this endpoint using the <a href="https://docs.aws.amazon.com/cli/latest/reference/">--endpoint-url parameter</a>. </p> </note> <p>When you put media data (fragments) on a stream, Kinesis Video Streams stores each incoming fragment and related metadata in what is called a &quot;chunk.&quot; For more information, see <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/API_dataplane_PutMedia.html">PutMedia</a>. The <code>GetMedia</code> API returns a stream of these chunks starting from the chunk that you specify in the request. </p> <p>The following limits apply when using the <code>GetMedia</code> API:</p> <ul> <li> <p>A client can call <code>GetMedia</code> up to five times per second per stream. </p> </li> <li> <p>Kinesis Video Streams sends media data at a rate of up to 25 megabytes per second (or 200 megabits per second) during a <code>GetMedia</code> session. </p> </li> </ul> <note> <p>If an error is thrown after invoking a Kinesis Video Streams media API, in addition to the HTTP status code and the response body, it includes the following pieces of information: </p> <ul> <li> <p> <code>x-amz-ErrorType</code> HTTP header – contains a more specific error type in addition to what the HTTP status code provides. </p> </li> <li> <p> <code>x-amz-RequestId</code> HTTP header – if you want to report an issue to AWS, the support team can better diagnose the problem if given the Request Id.</p> </li> </ul> <p>Both the HTTP status code and the ErrorType header can be utilized to make programmatic decisions about whether errors are retry-able and under what conditions, as well as provide information on what actions the client programmer might need to take in order to successfully try again.</p> <p>For more information, see the <b>Errors</b> section at the bottom of this topic, as well as <a href="https://docs.aws.amazon.com/kinesisvideostreams/latest/dg/CommonErrors.html">Common Errors</a>. </p> </note></p>
     fn get_media(&self, input: GetMediaInput) -> RusotoFuture<GetMediaOutput, GetMediaError>;
 }
+impl<T: ?Sized + KinesisVideoMedia> KinesisVideoMedia for ::std::sync::Arc<T> {
+    fn get_media(&self, input: GetMediaInput) -> RusotoFuture<GetMediaOutput, GetMediaError> {
+        (**self).get_media(input)
+    }
+}
+/// The error codes Kinesis Video Media retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Kinesis Video Media API.
 #[derive(Clone)]
 pub struct KinesisVideoMediaClient {
@@ -149,6 +303,27 @@ impl KinesisVideoMediaClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_KINESIS_VIDEO_MEDIA`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> KinesisVideoMediaClient {
+        Self::new(region::Region::from_env_for_service("KINESIS_VIDEO_MEDIA"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `kinesisvideo`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> KinesisVideoMediaClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("kinesisvideo", "KINESIS_VIDEO_MEDIA"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -167,7 +342,54 @@ impl KinesisVideoMediaClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoMediaClient {
-        KinesisVideoMediaClient { client, region }
+        KinesisVideoMediaClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "kinesisvideo")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "kinesisvideo",
+            "kinesisvideo",
+            "KINESIS_VIDEO_MEDIA",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `KinesisVideoMedia` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn KinesisVideoMedia + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for KinesisVideoMediaClient {
+    fn service_name(&self) -> &'static str {
+        "Kinesis Video Media"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "kinesisvideo"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> KinesisVideoMediaClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Kinesis clients.
+//!
+//! Makes a single read-only `ListShards` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_kinesis::{Kinesis, KinesisClient, ListShardsInput};
+
+fn main() {
+    let client = KinesisClient::from_env();
+    match client.list_shards(ListShardsInput::default()).sync() {
+        Ok(_) => println!("ListShards succeeded"),
+        Err(error) => {
+            eprintln!("ListShards failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
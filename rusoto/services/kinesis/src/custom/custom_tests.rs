@@ -0,0 +1,116 @@
+extern crate rusoto_mock;
+
+use std::sync::{Arc, Mutex};
+
+use crate::custom::producer::{
+    kinesis_producer, KinesisProducerConfig, KinesisProducerError, KinesisProducerSummary,
+    UserRecord,
+};
+use crate::generated::KinesisClient;
+
+use self::rusoto_mock::*;
+use futures::Future;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::{Region, RusotoError};
+use serde_json::Value;
+use std::time::Duration;
+
+#[test]
+fn should_put_records_aggregated_per_shard() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = requests.clone();
+    // the same response answers ListShards and PutRecords
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(
+            r#"{
+                "Shards": [{
+                    "ShardId": "shardId-000000000000",
+                    "HashKeyRange": {
+                        "StartingHashKey": "0",
+                        "EndingHashKey": "340282366920938463463374607431768211455"
+                    },
+                    "SequenceNumberRange": {"StartingSequenceNumber": "1"}
+                }],
+                "FailedRecordCount": 0,
+                "Records": [{"SequenceNumber": "1", "ShardId": "shardId-000000000000"}]
+            }"#,
+        )
+        .with_request_checker(move |request: &SignedRequest| {
+            let body: Value = match request.payload {
+                Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                    serde_json::from_slice(buffer).unwrap()
+                }
+                _ => panic!("expected a buffered payload"),
+            };
+            recorded.lock().unwrap().push(body);
+        });
+    let client = KinesisClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let (producer, sender) = kinesis_producer(client, KinesisProducerConfig::new("clickstream"));
+
+    let producer = producer
+        .send(UserRecord::new("a", "x"))
+        .and_then(|producer| producer.send(UserRecord::new("b", "yz")))
+        .wait()
+        .unwrap();
+    drop(producer);
+
+    let summary = sender.sync().unwrap();
+    assert_eq!(
+        summary,
+        KinesisProducerSummary {
+            batches: 1,
+            records: 2,
+            kinesis_records: 1,
+            retried_records: 0,
+        }
+    );
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0]["StreamName"], "clickstream");
+    let record = &requests[1]["Records"][0];
+    assert_eq!(record["PartitionKey"], "a");
+    // the MD5 hash of "a"
+    assert_eq!(
+        record["ExplicitHashKey"],
+        "16955237001963240173058271559858726497"
+    );
+    // the aggregated record magic, base64 encoded
+    assert!(record["Data"].as_str().unwrap().starts_with("84ma"));
+}
+
+#[test]
+fn should_fail_when_records_keep_failing() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(
+        r#"{
+            "FailedRecordCount": 2,
+            "Records": [
+                {"ErrorCode": "ProvisionedThroughputExceededException", "ErrorMessage": "Rate exceeded."},
+                {"ErrorCode": "ProvisionedThroughputExceededException", "ErrorMessage": "Rate exceeded."}
+            ]
+        }"#,
+    );
+    let client = KinesisClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let mut config = KinesisProducerConfig::new("clickstream");
+    config.max_aggregated_bytes = None;
+    config.max_attempts = 2;
+    config.base_delay = Duration::from_millis(1);
+    let (producer, sender) = kinesis_producer(client, config);
+
+    let producer = producer
+        .send(UserRecord::new("a", "x"))
+        .and_then(|producer| producer.send(UserRecord::new("b", "y")))
+        .wait()
+        .unwrap();
+    drop(producer);
+
+    match sender.sync() {
+        Err(RusotoError::Service(KinesisProducerError::FailedRecords(message))) => assert!(
+            message.contains("ProvisionedThroughputExceededException"),
+            "{}",
+            message
+        ),
+        other => panic!("expected failed records, got {:?}", other),
+    }
+}
@@ -1 +1,5 @@
+/// Producing records to a stream with aggregation compatible with the Kinesis Producer Library
+pub mod producer;
 
+#[cfg(test)]
+mod custom_tests;
@@ -0,0 +1,1081 @@
+//! Producing records to a stream, compatible with the Kinesis Producer Library.
+//!
+//! `PutRecords` accepts at most 500 records and 5 MiB per call, and may accept only some of
+//! them, reporting the others as failed in its response. `kinesis_producer` returns a
+//! `KinesisProducer` handle and a future which batches the records sent through it, resends the
+//! records a call failed to put and retries throttled calls. The handle's `send` waits while the
+//! producer's buffer is full, so a fast source slows down to the pace of the stream.
+//!
+//! Kinesis bills every record by rounding its size up to the next 25 KB, and a shard accepts at
+//! most 1,000 records a second. By default the producer packs the user records bound for the
+//! same shard into aggregated records, in the format of the Kinesis Producer Library, which the
+//! Kinesis Client Library and `deaggregate` unpack. The shard of a user record is found with its
+//! explicit hash key, or the MD5 hash of its partition key, in the hash key ranges of the open
+//! shards, which are listed when the producer starts. An aggregated record is put with the
+//! partition key and hash key of its first user record, so it lands on their shard unless the
+//! stream is resharded while producing.
+
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::mem;
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::future::{self, Either, Loop};
+use futures::sink;
+use futures::sync::mpsc;
+use futures::{Async, Future, Poll, Sink, Stream};
+use rusoto_core::batch::{backoff, batched, is_transient, Batcher};
+use rusoto_core::waiter::sleep;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    Kinesis, ListShardsError, ListShardsInput, PutRecordsError, PutRecordsInput,
+    PutRecordsRequestEntry, PutRecordsResultEntry, Shard,
+};
+
+/// The maximum number of records in a single `PutRecords` call.
+pub const MAX_BATCH_RECORDS: usize = 500;
+
+/// The maximum size of the records of a single `PutRecords` call, partition keys included.
+pub const MAX_BATCH_BYTES: usize = 5 * 1024 * 1024;
+
+/// The maximum size of a single record, its data and partition key together.
+pub const MAX_RECORD_BYTES: usize = 1024 * 1024;
+
+/// The size up to which the Kinesis Producer Library aggregates records by default.
+pub const DEFAULT_AGGREGATED_BYTES: usize = 50 * 1024;
+
+/// The bytes every aggregated record starts with.
+pub const AGGREGATED_RECORD_MAGIC: [u8; 4] = [0xF3, 0x89, 0x9A, 0xC2];
+
+/// Size of the MD5 checksum every aggregated record ends with.
+const CHECKSUM_BYTES: usize = 16;
+
+/// A record sent through a `KinesisProducer`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UserRecord {
+    /// The partition key, hashed to select the shard unless an explicit hash key is set
+    pub partition_key: String,
+    /// The decimal hash key selecting the shard, overriding the hash of the partition key
+    pub explicit_hash_key: Option<String>,
+    /// The data of the record
+    pub data: Bytes,
+}
+
+impl UserRecord {
+    /// Creates a record whose shard is selected by its partition key.
+    pub fn new<K, D>(partition_key: K, data: D) -> UserRecord
+    where
+        K: Into<String>,
+        D: Into<Bytes>,
+    {
+        UserRecord {
+            partition_key: partition_key.into(),
+            explicit_hash_key: None,
+            data: data.into(),
+        }
+    }
+
+    /// Sets the hash key selecting the shard of the record.
+    pub fn with_explicit_hash_key<H>(mut self, explicit_hash_key: H) -> UserRecord
+    where
+        H: Into<String>,
+    {
+        self.explicit_hash_key = Some(explicit_hash_key.into());
+        self
+    }
+
+    /// Returns the hash key selecting the shard of the record: its explicit hash key, or the
+    /// MD5 hash of its partition key. `None` if the explicit hash key isn't a 128 bit integer.
+    pub fn hash_key(&self) -> Option<u128> {
+        match self.explicit_hash_key {
+            Some(ref explicit_hash_key) => explicit_hash_key.parse().ok(),
+            None => Some(u128::from_be_bytes(
+                md5::compute(self.partition_key.as_bytes()).0,
+            )),
+        }
+    }
+}
+
+/// Controls how `kinesis_producer` aggregates, batches and sends records.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KinesisProducerConfig {
+    /// The stream the records are sent to
+    pub stream_name: String,
+    /// Maximum number of Kinesis records per batch, at most `MAX_BATCH_RECORDS`
+    pub max_batch_records: usize,
+    /// Maximum size of a batch, at most `MAX_BATCH_BYTES`
+    pub max_batch_bytes: usize,
+    /// Aggregate the user records bound for the same shard into records of up to this size, at
+    /// most `MAX_RECORD_BYTES`, or put every user record as it is with `None`
+    pub max_aggregated_bytes: Option<usize>,
+    /// How long a record waits for a batch to fill up before the batch is sent anyway
+    pub flush_interval: Duration,
+    /// Number of records the producer buffers while a batch is being sent
+    pub channel_capacity: usize,
+    /// Number of attempts made for each record before producing fails
+    pub max_attempts: u32,
+    /// Delay before the first retry of a throttled call or failed records, doubled for every
+    /// subsequent retry
+    pub base_delay: Duration,
+}
+
+impl KinesisProducerConfig {
+    /// Creates a configuration sending records to the given stream, with the default limits and
+    /// aggregation.
+    pub fn new<S>(stream_name: S) -> KinesisProducerConfig
+    where
+        S: Into<String>,
+    {
+        KinesisProducerConfig {
+            stream_name: stream_name.into(),
+            max_batch_records: MAX_BATCH_RECORDS,
+            max_batch_bytes: MAX_BATCH_BYTES,
+            max_aggregated_bytes: Some(DEFAULT_AGGREGATED_BYTES),
+            flush_interval: Duration::from_millis(500),
+            channel_capacity: 1024,
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+        }
+    }
+}
+
+/// Statistics of a finished `kinesis_producer` future.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KinesisProducerSummary {
+    /// Number of `PutRecords` calls which succeeded
+    pub batches: usize,
+    /// Number of user records sent through the producer
+    pub records: usize,
+    /// Number of Kinesis records put, which is lower than `records` with aggregation
+    pub kinesis_records: usize,
+    /// Number of Kinesis records which a call failed to put and were sent again
+    pub retried_records: usize,
+}
+
+/// Errors returned by the `kinesis_producer` future.
+#[derive(Debug, PartialEq)]
+pub enum KinesisProducerError {
+    /// Listing the shards of the stream failed
+    ListShards(ListShardsError),
+    /// Sending a batch failed
+    PutRecords(PutRecordsError),
+    /// Some records still failed to be put after the configured number of attempts
+    FailedRecords(String),
+}
+
+impl fmt::Display for KinesisProducerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for KinesisProducerError {
+    fn description(&self) -> &str {
+        match *self {
+            KinesisProducerError::ListShards(ref cause) => cause.description(),
+            KinesisProducerError::PutRecords(ref cause) => cause.description(),
+            KinesisProducerError::FailedRecords(ref cause) => cause,
+        }
+    }
+}
+
+/// A handle sending records to a `kinesis_producer` future.
+///
+/// Clones send to the same producer.
+#[derive(Clone, Debug)]
+pub struct KinesisProducer {
+    sender: mpsc::Sender<UserRecord>,
+}
+
+impl KinesisProducer {
+    /// Sends a record to the producer, resolving to the handle once the producer has room for
+    /// it.
+    ///
+    /// Fails, returning the record, once the producer's future has failed or was dropped.
+    pub fn send(self, record: UserRecord) -> ProducerSend {
+        ProducerSend {
+            inner: self.sender.send(record),
+        }
+    }
+}
+
+/// Future returned by `KinesisProducer::send`.
+#[derive(Debug)]
+pub struct ProducerSend {
+    inner: sink::Send<mpsc::Sender<UserRecord>>,
+}
+
+impl Future for ProducerSend {
+    type Item = KinesisProducer;
+    type Error = UserRecord;
+
+    fn poll(&mut self) -> Poll<KinesisProducer, UserRecord> {
+        match self.inner.poll() {
+            Ok(Async::Ready(sender)) => Ok(Async::Ready(KinesisProducer { sender })),
+            Ok(Async::NotReady) => Ok(Async::NotReady),
+            Err(error) => Err(error.into_inner()),
+        }
+    }
+}
+
+/// Returns a producer accepting records, and a future sending them to the stream named in
+/// `config`.
+///
+/// The future must be run for records to be sent. It resolves once every handle of the producer
+/// is dropped and all records sent until then are put, and fails if the shards can't be listed,
+/// a batch can't be sent or some of its records can't be put.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::Future;
+/// use rusoto_core::Region;
+/// use rusoto_kinesis::producer::{kinesis_producer, KinesisProducerConfig, UserRecord};
+/// use rusoto_kinesis::KinesisClient;
+///
+/// let client = KinesisClient::new(Region::UsEast1);
+/// let (producer, sender) = kinesis_producer(client, KinesisProducerConfig::new("clickstream"));
+///
+/// // the producer buffers records until the future runs
+/// let producer = producer
+///     .send(UserRecord::new("user-42", r#"{"page": "/"}"#))
+///     .wait()
+///     .expect("producer stopped");
+///
+/// // dropping the last handle lets the future finish once all records are put
+/// drop(producer);
+/// let summary = sender.sync().expect("failed to produce records");
+/// println!("put {} records in {} Kinesis records", summary.records, summary.kinesis_records);
+/// ```
+pub fn kinesis_producer<C>(
+    client: C,
+    config: KinesisProducerConfig,
+) -> (
+    KinesisProducer,
+    RusotoFuture<KinesisProducerSummary, KinesisProducerError>,
+)
+where
+    C: Kinesis + Clone + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel(config.channel_capacity);
+
+    // the shards only matter to aggregation
+    let shards = if config.max_aggregated_bytes.is_some() {
+        Either::A(list_open_shards(client.clone(), config.stream_name.clone()))
+    } else {
+        Either::B(future::ok(Vec::new()))
+    };
+
+    let future = shards.and_then(move |shards| {
+        let batches = batched::<_, _, RusotoError<KinesisProducerError>>(
+            receiver,
+            RecordBatcher::new(ShardMap::new(shards), &config),
+            config.flush_interval,
+        );
+        batches.fold(
+            KinesisProducerSummary::default(),
+            move |mut summary, (batch, records)| {
+                let kinesis_records = batch.len();
+                put_batch(client.clone(), config.clone(), batch).map(move |retried| {
+                    summary.batches += 1;
+                    summary.records += records;
+                    summary.kinesis_records += kinesis_records;
+                    summary.retried_records += retried;
+                    summary
+                })
+            },
+        )
+    });
+
+    (
+        KinesisProducer { sender },
+        RusotoFuture::from_future(future),
+    )
+}
+
+/// Unpacks an aggregated record into its user records.
+///
+/// Returns `None` if `data` isn't an aggregated record, in which case it is the data of a single
+/// user record.
+pub fn deaggregate(data: &[u8]) -> Option<Vec<UserRecord>> {
+    let magic = AGGREGATED_RECORD_MAGIC.len();
+    if data.len() < magic + CHECKSUM_BYTES || data[..magic] != AGGREGATED_RECORD_MAGIC {
+        return None;
+    }
+    let (message, checksum) = data[magic..].split_at(data.len() - magic - CHECKSUM_BYTES);
+    if md5::compute(message).0[..] != *checksum {
+        return None;
+    }
+
+    let mut partition_keys = Vec::new();
+    let mut explicit_hash_keys = Vec::new();
+    let mut records = Vec::new();
+    let mut fields = Fields { remaining: message };
+    while !fields.remaining.is_empty() {
+        match fields.next_field()? {
+            (1, FieldValue::Bytes(key)) => {
+                partition_keys.push(String::from_utf8(key.to_vec()).ok()?)
+            }
+            (2, FieldValue::Bytes(key)) => {
+                explicit_hash_keys.push(String::from_utf8(key.to_vec()).ok()?)
+            }
+            (3, FieldValue::Bytes(record)) => records.push(decode_record(record)?),
+            _ => {}
+        }
+    }
+
+    records
+        .into_iter()
+        .map(|(partition_key_index, explicit_hash_key_index, data)| {
+            let explicit_hash_key = match explicit_hash_key_index {
+                Some(index) => Some(explicit_hash_keys.get(index as usize)?.clone()),
+                None => None,
+            };
+            Some(UserRecord {
+                partition_key: partition_keys.get(partition_key_index as usize)?.clone(),
+                explicit_hash_key,
+                data: Bytes::from(data),
+            })
+        })
+        .collect()
+}
+
+/// Decodes a `Record` message into its partition key index, explicit hash key index and data.
+fn decode_record(record: &[u8]) -> Option<(u64, Option<u64>, &[u8])> {
+    let mut partition_key_index = None;
+    let mut explicit_hash_key_index = None;
+    let mut data = None;
+    let mut fields = Fields { remaining: record };
+    while !fields.remaining.is_empty() {
+        match fields.next_field()? {
+            (1, FieldValue::Varint(index)) => partition_key_index = Some(index),
+            (2, FieldValue::Varint(index)) => explicit_hash_key_index = Some(index),
+            (3, FieldValue::Bytes(value)) => data = Some(value),
+            // tags
+            _ => {}
+        }
+    }
+    Some((partition_key_index?, explicit_hash_key_index, data?))
+}
+
+enum FieldValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+}
+
+/// Reads the fields of a protobuf message, whose values are varints or length-delimited in
+/// aggregated records.
+struct Fields<'a> {
+    remaining: &'a [u8],
+}
+
+impl<'a> Fields<'a> {
+    /// Returns the number and value of the next field, or `None` if the message is malformed.
+    fn next_field(&mut self) -> Option<(u64, FieldValue<'a>)> {
+        let key = self.varint()?;
+        let value = match key & 7 {
+            0 => FieldValue::Varint(self.varint()?),
+            2 => {
+                let len = self.varint()? as usize;
+                if len > self.remaining.len() {
+                    return None;
+                }
+                let (value, remaining) = self.remaining.split_at(len);
+                self.remaining = remaining;
+                FieldValue::Bytes(value)
+            }
+            _ => return None,
+        };
+        Some((key >> 3, value))
+    }
+
+    fn varint(&mut self) -> Option<u64> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let (&byte, remaining) = self.remaining.split_first()?;
+            self.remaining = remaining;
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+fn put_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn varint_size(mut value: u64) -> usize {
+    let mut size = 1;
+    while value >= 0x80 {
+        size += 1;
+        value >>= 7;
+    }
+    size
+}
+
+// the fields of aggregated records are numbered below 16, so their keys take a single byte
+
+fn put_varint_field(buffer: &mut Vec<u8>, number: u8, value: u64) {
+    buffer.push(number << 3);
+    put_varint(buffer, value);
+}
+
+fn put_bytes_field(buffer: &mut Vec<u8>, number: u8, value: &[u8]) {
+    buffer.push((number << 3) | 2);
+    put_varint(buffer, value.len() as u64);
+    buffer.extend_from_slice(value);
+}
+
+fn bytes_field_size(len: usize) -> usize {
+    1 + varint_size(len as u64) + len
+}
+
+fn record_message_size(
+    partition_key_index: u64,
+    explicit_hash_key_index: Option<u64>,
+    data_len: usize,
+) -> usize {
+    1 + varint_size(partition_key_index)
+        + explicit_hash_key_index.map_or(0, |index| 1 + varint_size(index))
+        + bytes_field_size(data_len)
+}
+
+/// Distinct keys of an aggregated record, referred to by index from its records.
+#[derive(Default)]
+struct KeyTable {
+    keys: Vec<String>,
+    indexes: HashMap<String, u64>,
+}
+
+impl KeyTable {
+    /// Returns the index of `key`, and the size the table grows by if `key` is new.
+    fn lookup(&self, key: &str) -> (u64, usize) {
+        match self.indexes.get(key) {
+            Some(&index) => (index, 0),
+            None => (self.keys.len() as u64, bytes_field_size(key.len())),
+        }
+    }
+
+    fn insert(&mut self, key: &str) -> u64 {
+        if let Some(&index) = self.indexes.get(key) {
+            return index;
+        }
+        let index = self.keys.len() as u64;
+        self.keys.push(key.to_owned());
+        self.indexes.insert(key.to_owned(), index);
+        index
+    }
+}
+
+/// User records bound for one shard, encoded as the `AggregatedRecord` message of the Kinesis
+/// Producer Library.
+struct Aggregator {
+    partition_keys: KeyTable,
+    explicit_hash_keys: KeyTable,
+    records: Vec<(u64, Option<u64>, UserRecord)>,
+    message_size: usize,
+    hash_key: u128,
+}
+
+impl Aggregator {
+    /// Creates an aggregator whose record is put with the hash key of its first user record.
+    fn new(hash_key: u128) -> Aggregator {
+        Aggregator {
+            partition_keys: KeyTable::default(),
+            explicit_hash_keys: KeyTable::default(),
+            records: Vec::new(),
+            message_size: 0,
+            hash_key,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// The size of the aggregated Kinesis record once `record` is added, partition key included.
+    fn size_with(&self, record: &UserRecord) -> usize {
+        let (partition_key_index, partition_key_size) =
+            self.partition_keys.lookup(&record.partition_key);
+        let (explicit_hash_key_index, explicit_hash_key_size) = match record.explicit_hash_key {
+            Some(ref key) => {
+                let (index, size) = self.explicit_hash_keys.lookup(key);
+                (Some(index), size)
+            }
+            None => (None, 0),
+        };
+        let record_size = record_message_size(
+            partition_key_index,
+            explicit_hash_key_index,
+            record.data.len(),
+        );
+        let first_partition_key = self
+            .records
+            .first()
+            .map_or(&record.partition_key, |&(_, _, ref first)| {
+                &first.partition_key
+            });
+
+        AGGREGATED_RECORD_MAGIC.len()
+            + self.message_size
+            + partition_key_size
+            + explicit_hash_key_size
+            + bytes_field_size(record_size)
+            + CHECKSUM_BYTES
+            + first_partition_key.len()
+    }
+
+    fn push(&mut self, record: UserRecord) {
+        let (_, partition_key_size) = self.partition_keys.lookup(&record.partition_key);
+        let partition_key_index = self.partition_keys.insert(&record.partition_key);
+        let mut explicit_hash_key_index = None;
+        if let Some(ref key) = record.explicit_hash_key {
+            let (_, size) = self.explicit_hash_keys.lookup(key);
+            explicit_hash_key_index = Some(self.explicit_hash_keys.insert(key));
+            self.message_size += size;
+        }
+        self.message_size += partition_key_size
+            + bytes_field_size(record_message_size(
+                partition_key_index,
+                explicit_hash_key_index,
+                record.data.len(),
+            ));
+        self.records
+            .push((partition_key_index, explicit_hash_key_index, record));
+    }
+
+    /// Returns the Kinesis record to put, which is the user record itself if there is only one.
+    fn finish(mut self) -> PutRecordsRequestEntry {
+        if self.records.len() == 1 {
+            let (_, _, record) = self.records.pop().unwrap();
+            return entry(record);
+        }
+
+        let mut message = Vec::with_capacity(self.message_size);
+        for key in &self.partition_keys.keys {
+            put_bytes_field(&mut message, 1, key.as_bytes());
+        }
+        for key in &self.explicit_hash_keys.keys {
+            put_bytes_field(&mut message, 2, key.as_bytes());
+        }
+        let mut encoded = Vec::new();
+        for &(partition_key_index, explicit_hash_key_index, ref record) in &self.records {
+            encoded.clear();
+            put_varint_field(&mut encoded, 1, partition_key_index);
+            if let Some(index) = explicit_hash_key_index {
+                put_varint_field(&mut encoded, 2, index);
+            }
+            put_bytes_field(&mut encoded, 3, &record.data);
+            put_bytes_field(&mut message, 3, &encoded);
+        }
+
+        let mut data =
+            Vec::with_capacity(AGGREGATED_RECORD_MAGIC.len() + message.len() + CHECKSUM_BYTES);
+        data.extend_from_slice(&AGGREGATED_RECORD_MAGIC);
+        data.extend_from_slice(&message);
+        data.extend_from_slice(&md5::compute(&message).0);
+
+        let (_, _, ref first) = self.records[0];
+        PutRecordsRequestEntry {
+            data: data.into(),
+            explicit_hash_key: Some(self.hash_key.to_string()),
+            partition_key: first.partition_key.clone(),
+        }
+    }
+}
+
+fn entry(record: UserRecord) -> PutRecordsRequestEntry {
+    PutRecordsRequestEntry {
+        data: record.data,
+        explicit_hash_key: record.explicit_hash_key,
+        partition_key: record.partition_key,
+    }
+}
+
+fn list_open_shards<C>(
+    client: C,
+    stream_name: String,
+) -> impl Future<Item = Vec<Shard>, Error = RusotoError<KinesisProducerError>> + Send
+where
+    C: Kinesis + Clone + Send + 'static,
+{
+    let first = ListShardsInput {
+        stream_name: Some(stream_name),
+        ..Default::default()
+    };
+
+    future::loop_fn((first, Vec::new()), move |(input, mut shards)| {
+        client
+            .list_shards(input)
+            .map_err(|e| e.map_service(KinesisProducerError::ListShards))
+            .map(move |output| {
+                // closed shards have an ending sequence number
+                shards.extend(
+                    output
+                        .shards
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter(|shard| {
+                            shard.sequence_number_range.ending_sequence_number.is_none()
+                        }),
+                );
+                match output.next_token {
+                    Some(next_token) => Loop::Continue((
+                        ListShardsInput {
+                            next_token: Some(next_token),
+                            ..Default::default()
+                        },
+                        shards,
+                    )),
+                    None => Loop::Break(shards),
+                }
+            })
+    })
+}
+
+/// The hash key ranges of the open shards of a stream.
+struct ShardMap {
+    ranges: Vec<(u128, u128, String)>,
+}
+
+impl ShardMap {
+    fn new(shards: Vec<Shard>) -> ShardMap {
+        let ranges = shards
+            .into_iter()
+            .filter_map(|shard| {
+                let start = shard.hash_key_range.starting_hash_key.parse().ok()?;
+                let end = shard.hash_key_range.ending_hash_key.parse().ok()?;
+                Some((start, end, shard.shard_id))
+            })
+            .collect();
+        ShardMap { ranges }
+    }
+
+    fn shard_id(&self, hash_key: u128) -> Option<&str> {
+        self.ranges
+            .iter()
+            .find(|&&(start, end, _)| start <= hash_key && hash_key <= end)
+            .map(|&(_, _, ref shard_id)| shard_id.as_str())
+    }
+}
+
+/// Aggregates records per shard and groups them into batches within the limits of `PutRecords`,
+/// batched with the number of user records in them.
+struct RecordBatcher {
+    shards: ShardMap,
+    aggregators: HashMap<String, Aggregator>,
+    pending: Vec<PutRecordsRequestEntry>,
+    pending_bytes: usize,
+    pending_records: usize,
+    ready: VecDeque<(Vec<PutRecordsRequestEntry>, usize)>,
+    max_records: usize,
+    max_bytes: usize,
+    max_aggregated_bytes: Option<usize>,
+}
+
+impl RecordBatcher {
+    fn new(shards: ShardMap, config: &KinesisProducerConfig) -> RecordBatcher {
+        RecordBatcher {
+            shards,
+            aggregators: HashMap::new(),
+            pending: Vec::new(),
+            pending_bytes: 0,
+            pending_records: 0,
+            ready: VecDeque::new(),
+            max_records: config.max_batch_records.min(MAX_BATCH_RECORDS).max(1),
+            max_bytes: config.max_batch_bytes.min(MAX_BATCH_BYTES),
+            max_aggregated_bytes: config
+                .max_aggregated_bytes
+                .map(|max| max.min(MAX_RECORD_BYTES)),
+        }
+    }
+
+    fn aggregate(&mut self, record: UserRecord) {
+        let max_aggregated_bytes = match self.max_aggregated_bytes {
+            Some(max) => max,
+            None => return self.add(entry(record), 1),
+        };
+        let hash_key = record.hash_key();
+        let shard_id = match hash_key.and_then(|hash_key| self.shards.shard_id(hash_key)) {
+            Some(shard_id) => shard_id.to_owned(),
+            // records without a valid hash key are left for Kinesis to reject
+            None => return self.add(entry(record), 1),
+        };
+
+        let full = self.aggregators.get(&shard_id).map_or(false, |aggregator| {
+            aggregator.size_with(&record) > max_aggregated_bytes
+        });
+        if full {
+            if let Some(aggregator) = self.aggregators.remove(&shard_id) {
+                self.finish(aggregator);
+            }
+        }
+        self.aggregators
+            .entry(shard_id)
+            .or_insert_with(|| Aggregator::new(hash_key.unwrap_or_default()))
+            .push(record);
+    }
+
+    fn finish(&mut self, aggregator: Aggregator) {
+        let records = aggregator.len();
+        self.add(aggregator.finish(), records);
+    }
+
+    fn add(&mut self, entry: PutRecordsRequestEntry, records: usize) {
+        let size = entry.data.len() + entry.partition_key.len();
+        let full = !self.pending.is_empty()
+            && (self.pending.len() >= self.max_records
+                || self.pending_bytes + size > self.max_bytes);
+        if full {
+            self.take_pending();
+        }
+        self.pending_bytes += size;
+        self.pending_records += records;
+        self.pending.push(entry);
+    }
+
+    fn take_pending(&mut self) {
+        self.pending_bytes = 0;
+        let batch = mem::replace(&mut self.pending, Vec::new());
+        let records = mem::replace(&mut self.pending_records, 0);
+        self.ready.push_back((batch, records));
+    }
+
+    /// Finishes every aggregated record and readies all pending records.
+    fn finish_all(&mut self) {
+        let aggregators: Vec<Aggregator> = self
+            .aggregators
+            .drain()
+            .map(|(_, aggregator)| aggregator)
+            .collect();
+        for aggregator in aggregators {
+            self.finish(aggregator);
+        }
+        if !self.pending.is_empty() {
+            self.take_pending();
+        }
+    }
+}
+
+impl Batcher for RecordBatcher {
+    type Item = UserRecord;
+    type Batch = (Vec<PutRecordsRequestEntry>, usize);
+
+    fn push(&mut self, record: UserRecord) -> Option<(Vec<PutRecordsRequestEntry>, usize)> {
+        self.aggregate(record);
+        self.ready.pop_front()
+    }
+
+    fn flush(&mut self) -> Option<(Vec<PutRecordsRequestEntry>, usize)> {
+        if self.ready.is_empty() {
+            self.finish_all();
+        }
+        self.ready.pop_front()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.aggregators.is_empty() && self.pending.is_empty() && self.ready.is_empty()
+    }
+}
+
+type PutAttempt =
+    Box<dyn Future<Item = Loop<usize, PutState>, Error = RusotoError<KinesisProducerError>> + Send>;
+
+struct PutState {
+    records: Vec<PutRecordsRequestEntry>,
+    attempts: u32,
+    retried: usize,
+}
+
+/// Sends a batch, resending the records a call failed to put, and returns the number of
+/// records which were resent.
+fn put_batch<C>(
+    client: C,
+    config: KinesisProducerConfig,
+    batch: Vec<PutRecordsRequestEntry>,
+) -> impl Future<Item = usize, Error = RusotoError<KinesisProducerError>> + Send
+where
+    C: Kinesis + Clone + Send + 'static,
+{
+    let initial = PutState {
+        records: batch,
+        attempts: 0,
+        retried: 0,
+    };
+
+    future::loop_fn(initial, move |mut state| {
+        state.attempts += 1;
+        let request = PutRecordsInput {
+            records: state.records.clone(),
+            stream_name: config.stream_name.clone(),
+        };
+        let last_attempt = state.attempts >= config.max_attempts;
+        let base_delay = config.base_delay;
+
+        client.put_records(request).then(move |result| {
+            let next: PutAttempt = match result {
+                Ok(ref output) if output.failed_record_count.unwrap_or(0) == 0 => {
+                    Box::new(future::ok(Loop::Break(state.retried)))
+                }
+                Ok(output) => {
+                    let records = mem::replace(&mut state.records, Vec::new());
+                    let failed = failed_records(records, &output.records);
+                    if failed.is_empty() {
+                        Box::new(future::ok(Loop::Break(state.retried)))
+                    } else if last_attempt {
+                        Box::new(future::err(RusotoError::Service(
+                            KinesisProducerError::FailedRecords(failure_message(
+                                &failed,
+                                state.attempts,
+                            )),
+                        )))
+                    } else {
+                        state.retried += failed.len();
+                        state.records = failed.into_iter().map(|(record, _)| record).collect();
+                        let delay = backoff(base_delay, state.attempts);
+                        Box::new(sleep(delay).map(|_| Loop::Continue(state)))
+                    }
+                }
+                Err(ref err) if is_throttled(err) && !last_attempt => {
+                    let delay = backoff(base_delay, state.attempts);
+                    Box::new(sleep(delay).map(|_| Loop::Continue(state)))
+                }
+                Err(err) => Box::new(future::err(
+                    err.map_service(KinesisProducerError::PutRecords),
+                )),
+            };
+            next
+        })
+    })
+}
+
+/// Pairs the records a call failed to put with their result entries, which are in the order of
+/// the records.
+fn failed_records(
+    records: Vec<PutRecordsRequestEntry>,
+    results: &[PutRecordsResultEntry],
+) -> Vec<(PutRecordsRequestEntry, PutRecordsResultEntry)> {
+    records
+        .into_iter()
+        .zip(results.iter())
+        .filter(|&(_, result)| result.error_code.is_some())
+        .map(|(record, result)| (record, result.clone()))
+        .collect()
+}
+
+fn failure_message(
+    failed: &[(PutRecordsRequestEntry, PutRecordsResultEntry)],
+    attempts: u32,
+) -> String {
+    let (code, message) = failed
+        .first()
+        .map(|&(_, ref result)| {
+            (
+                result.error_code.clone().unwrap_or_default(),
+                result.error_message.clone().unwrap_or_default(),
+            )
+        })
+        .unwrap_or_default();
+    format!(
+        "{} records failed to be put after {} attempts, the first with {}: {}",
+        failed.len(),
+        attempts,
+        code,
+        message
+    )
+}
+
+fn is_throttled(err: &RusotoError<PutRecordsError>) -> bool {
+    match *err {
+        RusotoError::Service(PutRecordsError::ProvisionedThroughputExceeded(_))
+        | RusotoError::Service(PutRecordsError::KMSThrottling(_)) => true,
+        ref err => is_transient(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::{HashKeyRange, SequenceNumberRange};
+
+    /// The first half of the hash key space, holding the MD5 hash of "a" but not of "b".
+    const LOWER_HALF_END: &str = "170141183460469231731687303715884105727";
+
+    fn shard(shard_id: &str, start: &str, end: &str) -> Shard {
+        Shard {
+            shard_id: shard_id.to_owned(),
+            hash_key_range: HashKeyRange {
+                starting_hash_key: start.to_owned(),
+                ending_hash_key: end.to_owned(),
+            },
+            sequence_number_range: SequenceNumberRange::default(),
+            ..Default::default()
+        }
+    }
+
+    fn batcher(config: KinesisProducerConfig) -> RecordBatcher {
+        let shards = ShardMap::new(vec![
+            shard("shardId-000000000000", "0", LOWER_HALF_END),
+            shard(
+                "shardId-000000000001",
+                "170141183460469231731687303715884105728",
+                "340282366920938463463374607431768211455",
+            ),
+        ]);
+        RecordBatcher::new(shards, &config)
+    }
+
+    #[test]
+    fn hashes_partition_keys_unless_explicit() {
+        assert_eq!(
+            UserRecord::new("a", "x").hash_key(),
+            Some(16_955_237_001_963_240_173_058_271_559_858_726_497)
+        );
+        assert_eq!(
+            UserRecord::new("a", "x")
+                .with_explicit_hash_key("42")
+                .hash_key(),
+            Some(42)
+        );
+        assert_eq!(
+            UserRecord::new("a", "x")
+                .with_explicit_hash_key("-1")
+                .hash_key(),
+            None
+        );
+    }
+
+    #[test]
+    fn encodes_aggregated_records_like_the_kpl() {
+        let mut aggregator = Aggregator::new(7);
+        let first = UserRecord::new("a", "x");
+        let second = UserRecord::new("b", "yz").with_explicit_hash_key("5");
+        let size = aggregator.size_with(&first);
+        aggregator.push(first.clone());
+        assert_eq!(aggregator.size_with(&second), 47);
+        aggregator.push(second.clone());
+
+        let entry = aggregator.finish();
+        assert_eq!(size, 31);
+        assert_eq!(entry.data.len() + entry.partition_key.len(), 47);
+        assert_eq!(entry.partition_key, "a");
+        assert_eq!(entry.explicit_hash_key, Some("7".to_owned()));
+        assert_eq!(
+            &entry.data[..],
+            &b"\xF3\x89\x9A\xC2\
+               \x0a\x01a\x0a\x01b\x12\x015\
+               \x1a\x05\x08\x00\x1a\x01x\
+               \x1a\x08\x08\x01\x10\x00\x1a\x02yz\
+               \x04\x6b\xd8\x75\x3d\x12\x65\xe3\x27\xf8\x50\x6b\x2a\xfb\x84\x0f"[..]
+        );
+        assert_eq!(deaggregate(&entry.data), Some(vec![first, second]));
+    }
+
+    #[test]
+    fn leaves_single_and_corrupt_records_alone() {
+        let mut aggregator = Aggregator::new(7);
+        aggregator.push(UserRecord::new("a", "x"));
+        let entry = aggregator.finish();
+        assert_eq!(&entry.data[..], b"x");
+        assert_eq!(entry.explicit_hash_key, None);
+
+        assert_eq!(deaggregate(b"x"), None);
+        let mut corrupt = AGGREGATED_RECORD_MAGIC.to_vec();
+        corrupt.extend_from_slice(&[0; CHECKSUM_BYTES + 1]);
+        assert_eq!(deaggregate(&corrupt), None);
+    }
+
+    #[test]
+    fn aggregates_records_per_shard() {
+        let mut batcher = batcher(KinesisProducerConfig::new("stream"));
+        batcher.aggregate(UserRecord::new("a", "1"));
+        batcher.aggregate(UserRecord::new("b", "2"));
+        batcher.aggregate(UserRecord::new("a", "3"));
+        batcher.aggregate(UserRecord::new("b", "4").with_explicit_hash_key(LOWER_HALF_END));
+
+        let (mut batch, records) = batcher.flush().unwrap();
+        assert!(batcher.flush().is_none());
+        assert_eq!(records, 4);
+        assert_eq!(batch.len(), 2);
+        batch.sort_by_key(|entry| entry.data.len());
+        assert_eq!(&batch[0].data[..], b"2");
+        let aggregated = deaggregate(&batch[1].data).unwrap();
+        let data: Vec<&[u8]> = aggregated.iter().map(|record| &record.data[..]).collect();
+        assert_eq!(data, vec![&b"1"[..], &b"3"[..], &b"4"[..]]);
+    }
+
+    #[test]
+    fn splits_aggregated_records_and_batches_by_size() {
+        let mut config = KinesisProducerConfig::new("stream");
+        config.max_aggregated_bytes = Some(45);
+        config.max_batch_records = 2;
+        let mut batcher = batcher(config);
+
+        for data in &["1", "2", "3", "4", "5", "6"] {
+            assert!(batcher.push(UserRecord::new("a", *data)).is_none());
+        }
+        // three records with the same partition key take 45 bytes, so every fourth record
+        // starts a new aggregated record
+        assert_eq!(batcher.pending.len(), 1);
+
+        let (batch, records) = batcher.flush().unwrap();
+        assert_eq!((batch.len(), records), (2, 6));
+        assert!(batch.iter().all(|entry| entry.data.len() + 1 == 45));
+    }
+
+    #[test]
+    fn puts_records_as_they_are_without_aggregation() {
+        let mut config = KinesisProducerConfig::new("stream");
+        config.max_aggregated_bytes = None;
+        config.max_batch_records = 2;
+        let mut batcher = batcher(config);
+
+        assert!(batcher.push(UserRecord::new("a", "1")).is_none());
+        assert!(batcher.push(UserRecord::new("a", "2")).is_none());
+        let (batch, records) = batcher.push(UserRecord::new("a", "3")).unwrap();
+        assert_eq!(records, 2);
+        let data: Vec<&[u8]> = batch.iter().map(|entry| &entry.data[..]).collect();
+        assert_eq!(data, vec![&b"1"[..], &b"2"[..]]);
+    }
+
+    #[test]
+    fn picks_failed_records() {
+        let records = vec!["a", "b", "c"]
+            .into_iter()
+            .map(|data| entry(UserRecord::new("key", data)))
+            .collect();
+        let failure = PutRecordsResultEntry {
+            error_code: Some("ProvisionedThroughputExceededException".to_owned()),
+            error_message: Some("Rate exceeded for shard shardId-000000000000.".to_owned()),
+            ..Default::default()
+        };
+        let success = PutRecordsResultEntry {
+            sequence_number: Some(
+                "49543463076548007577105092703039560359975228518395019266".to_owned(),
+            ),
+            shard_id: Some("shardId-000000000000".to_owned()),
+            ..Default::default()
+        };
+        let failed = failed_records(records, &[failure.clone(), success, failure]);
+
+        assert_eq!(failed.len(), 2);
+        assert_eq!(&failed[0].0.data[..], b"a");
+        assert_eq!(&failed[1].0.data[..], b"c");
+        assert_eq!(
+            failure_message(&failed, 5),
+            "2 records failed to be put after 5 attempts, the first with \
+             ProvisionedThroughputExceededException: Rate exceeded for shard \
+             shardId-000000000000."
+        );
+    }
+}
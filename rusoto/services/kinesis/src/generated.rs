@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Represents the input for <code>AddTagsToStream</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -34,8 +35,48 @@ pub struct AddTagsToStreamInput {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl AddTagsToStreamInput {
+    /// Returns a builder for `AddTagsToStreamInput`, checking its required members when built.
+    pub fn builder() -> AddTagsToStreamInputBuilder {
+        AddTagsToStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsToStreamInput`](struct.AddTagsToStreamInput.html).
+#[derive(Default, Debug)]
+pub struct AddTagsToStreamInputBuilder {
+    stream_name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl AddTagsToStreamInputBuilder {
+    /// Sets the [`stream_name`](struct.AddTagsToStreamInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsToStreamInput.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsToStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddTagsToStreamInput, ::rusoto_core::BuildError> {
+        Ok(AddTagsToStreamInput {
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>An object that represents the details of the consumer you registered.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct Consumer {
     /// <p>When you register a consumer, Kinesis Data Streams generates an ARN for it. You need this ARN to be able to call <a>SubscribeToShard</a>.</p> <p>If you delete a consumer and then create a new one with the same name, it won't have the same ARN. That's because consumer ARNs contain the creation timestamp. This is important to keep in mind if you have IAM policies that reference consumer ARNs.</p>
@@ -43,17 +84,18 @@ pub struct Consumer {
     pub consumer_arn: String,
     /// <p><p/></p>
     #[serde(rename = "ConsumerCreationTimestamp")]
-    pub consumer_creation_timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub consumer_creation_timestamp: EpochTimestamp,
     /// <p>The name of the consumer is something you choose when you register the consumer.</p>
     #[serde(rename = "ConsumerName")]
     pub consumer_name: String,
     /// <p>A consumer can't read data while in the <code>CREATING</code> or <code>DELETING</code> states.</p>
     #[serde(rename = "ConsumerStatus")]
-    pub consumer_status: String,
+    pub consumer_status: ConsumerStatus,
 }
 
 /// <p>An object that represents the details of a registered consumer.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ConsumerDescription {
     /// <p>When you register a consumer, Kinesis Data Streams generates an ARN for it. You need this ARN to be able to call <a>SubscribeToShard</a>.</p> <p>If you delete a consumer and then create a new one with the same name, it won't have the same ARN. That's because consumer ARNs contain the creation timestamp. This is important to keep in mind if you have IAM policies that reference consumer ARNs.</p>
@@ -61,18 +103,95 @@ pub struct ConsumerDescription {
     pub consumer_arn: String,
     /// <p><p/></p>
     #[serde(rename = "ConsumerCreationTimestamp")]
-    pub consumer_creation_timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub consumer_creation_timestamp: EpochTimestamp,
     /// <p>The name of the consumer is something you choose when you register the consumer.</p>
     #[serde(rename = "ConsumerName")]
     pub consumer_name: String,
     /// <p>A consumer can't read data while in the <code>CREATING</code> or <code>DELETING</code> states.</p>
     #[serde(rename = "ConsumerStatus")]
-    pub consumer_status: String,
+    pub consumer_status: ConsumerStatus,
     /// <p>The ARN of the stream with which you registered the consumer.</p>
     #[serde(rename = "StreamARN")]
     pub stream_arn: String,
 }
 
+/// <p>A consumer can't read data while in the <code>CREATING</code> or <code>DELETING</code> states.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConsumerStatus {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConsumerStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConsumerStatus::Creating => "CREATING",
+            ConsumerStatus::Deleting => "DELETING",
+            ConsumerStatus::Active => "ACTIVE",
+            ConsumerStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConsumerStatus {
+    fn from(value: &'a str) -> ConsumerStatus {
+        match value {
+            "CREATING" => ConsumerStatus::Creating,
+            "DELETING" => ConsumerStatus::Deleting,
+            "ACTIVE" => ConsumerStatus::Active,
+            _ => ConsumerStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConsumerStatus {
+    fn from(value: String) -> ConsumerStatus {
+        ConsumerStatus::from(value.as_str())
+    }
+}
+
+impl From<ConsumerStatus> for String {
+    fn from(value: ConsumerStatus) -> String {
+        match value {
+            ConsumerStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConsumerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConsumerStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConsumerStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConsumerStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConsumerStatus::from(value))
+    }
+}
+
 /// <p>Represents the input for <code>CreateStream</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateStreamInput {
@@ -84,6 +203,46 @@ pub struct CreateStreamInput {
     pub stream_name: String,
 }
 
+impl CreateStreamInput {
+    /// Returns a builder for `CreateStreamInput`, checking its required members when built.
+    pub fn builder() -> CreateStreamInputBuilder {
+        CreateStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStreamInput`](struct.CreateStreamInput.html).
+#[derive(Default, Debug)]
+pub struct CreateStreamInputBuilder {
+    shard_count: Option<i64>,
+    stream_name: Option<String>,
+}
+
+impl CreateStreamInputBuilder {
+    /// Sets the [`shard_count`](struct.CreateStreamInput.html#structfield.shard_count) member.
+    pub fn shard_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.shard_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.CreateStreamInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateStreamInput, ::rusoto_core::BuildError> {
+        Ok(CreateStreamInput {
+            shard_count: self
+                .shard_count
+                .ok_or(::rusoto_core::BuildError::missing("shard_count"))?,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the input for <a>DecreaseStreamRetentionPeriod</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DecreaseStreamRetentionPeriodInput {
@@ -95,6 +254,48 @@ pub struct DecreaseStreamRetentionPeriodInput {
     pub stream_name: String,
 }
 
+impl DecreaseStreamRetentionPeriodInput {
+    /// Returns a builder for `DecreaseStreamRetentionPeriodInput`, checking its required members when built.
+    pub fn builder() -> DecreaseStreamRetentionPeriodInputBuilder {
+        DecreaseStreamRetentionPeriodInputBuilder::default()
+    }
+}
+
+/// Builds a [`DecreaseStreamRetentionPeriodInput`](struct.DecreaseStreamRetentionPeriodInput.html).
+#[derive(Default, Debug)]
+pub struct DecreaseStreamRetentionPeriodInputBuilder {
+    retention_period_hours: Option<i64>,
+    stream_name: Option<String>,
+}
+
+impl DecreaseStreamRetentionPeriodInputBuilder {
+    /// Sets the [`retention_period_hours`](struct.DecreaseStreamRetentionPeriodInput.html#structfield.retention_period_hours) member.
+    pub fn retention_period_hours<T: Into<i64>>(mut self, value: T) -> Self {
+        self.retention_period_hours = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.DecreaseStreamRetentionPeriodInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DecreaseStreamRetentionPeriodInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DecreaseStreamRetentionPeriodInput, ::rusoto_core::BuildError> {
+        Ok(DecreaseStreamRetentionPeriodInput {
+            retention_period_hours: self
+                .retention_period_hours
+                .ok_or(::rusoto_core::BuildError::missing("retention_period_hours"))?,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the input for <a>DeleteStream</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteStreamInput {
@@ -107,6 +308,44 @@ pub struct DeleteStreamInput {
     pub stream_name: String,
 }
 
+impl DeleteStreamInput {
+    /// Returns a builder for `DeleteStreamInput`, checking its required members when built.
+    pub fn builder() -> DeleteStreamInputBuilder {
+        DeleteStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStreamInput`](struct.DeleteStreamInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteStreamInputBuilder {
+    enforce_consumer_deletion: Option<bool>,
+    stream_name: Option<String>,
+}
+
+impl DeleteStreamInputBuilder {
+    /// Sets the [`enforce_consumer_deletion`](struct.DeleteStreamInput.html#structfield.enforce_consumer_deletion) member.
+    pub fn enforce_consumer_deletion<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enforce_consumer_deletion = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.DeleteStreamInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteStreamInput, ::rusoto_core::BuildError> {
+        Ok(DeleteStreamInput {
+            enforce_consumer_deletion: self.enforce_consumer_deletion,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeregisterStreamConsumerInput {
     /// <p>The ARN returned by Kinesis Data Streams when you registered the consumer. If you don't know the ARN of the consumer that you want to deregister, you can use the ListStreamConsumers operation to get a list of the descriptions of all the consumers that are currently registered with a given data stream. The description of a consumer contains its ARN.</p>
@@ -123,6 +362,52 @@ pub struct DeregisterStreamConsumerInput {
     pub stream_arn: Option<String>,
 }
 
+impl DeregisterStreamConsumerInput {
+    /// Returns a builder for `DeregisterStreamConsumerInput`, checking its required members when built.
+    pub fn builder() -> DeregisterStreamConsumerInputBuilder {
+        DeregisterStreamConsumerInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterStreamConsumerInput`](struct.DeregisterStreamConsumerInput.html).
+#[derive(Default, Debug)]
+pub struct DeregisterStreamConsumerInputBuilder {
+    consumer_arn: Option<String>,
+    consumer_name: Option<String>,
+    stream_arn: Option<String>,
+}
+
+impl DeregisterStreamConsumerInputBuilder {
+    /// Sets the [`consumer_arn`](struct.DeregisterStreamConsumerInput.html#structfield.consumer_arn) member.
+    pub fn consumer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.consumer_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`consumer_name`](struct.DeregisterStreamConsumerInput.html#structfield.consumer_name) member.
+    pub fn consumer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.consumer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.DeregisterStreamConsumerInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterStreamConsumerInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterStreamConsumerInput, ::rusoto_core::BuildError> {
+        Ok(DeregisterStreamConsumerInput {
+            consumer_arn: self.consumer_arn,
+            consumer_name: self.consumer_name,
+            stream_arn: self.stream_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeLimitsInput {}
 
@@ -153,7 +438,53 @@ pub struct DescribeStreamConsumerInput {
     pub stream_arn: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DescribeStreamConsumerInput {
+    /// Returns a builder for `DescribeStreamConsumerInput`, checking its required members when built.
+    pub fn builder() -> DescribeStreamConsumerInputBuilder {
+        DescribeStreamConsumerInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStreamConsumerInput`](struct.DescribeStreamConsumerInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStreamConsumerInputBuilder {
+    consumer_arn: Option<String>,
+    consumer_name: Option<String>,
+    stream_arn: Option<String>,
+}
+
+impl DescribeStreamConsumerInputBuilder {
+    /// Sets the [`consumer_arn`](struct.DescribeStreamConsumerInput.html#structfield.consumer_arn) member.
+    pub fn consumer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.consumer_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`consumer_name`](struct.DescribeStreamConsumerInput.html#structfield.consumer_name) member.
+    pub fn consumer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.consumer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.DescribeStreamConsumerInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStreamConsumerInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStreamConsumerInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStreamConsumerInput {
+            consumer_arn: self.consumer_arn,
+            consumer_name: self.consumer_name,
+            stream_arn: self.stream_arn,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeStreamConsumerOutput {
     /// <p>An object that represents the details of the consumer.</p>
@@ -177,8 +508,54 @@ pub struct DescribeStreamInput {
     pub stream_name: String,
 }
 
+impl DescribeStreamInput {
+    /// Returns a builder for `DescribeStreamInput`, checking its required members when built.
+    pub fn builder() -> DescribeStreamInputBuilder {
+        DescribeStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStreamInput`](struct.DescribeStreamInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStreamInputBuilder {
+    exclusive_start_shard_id: Option<String>,
+    limit: Option<i64>,
+    stream_name: Option<String>,
+}
+
+impl DescribeStreamInputBuilder {
+    /// Sets the [`exclusive_start_shard_id`](struct.DescribeStreamInput.html#structfield.exclusive_start_shard_id) member.
+    pub fn exclusive_start_shard_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_shard_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeStreamInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.DescribeStreamInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeStreamInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStreamInput {
+            exclusive_start_shard_id: self.exclusive_start_shard_id,
+            limit: self.limit,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output for <code>DescribeStream</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeStreamOutput {
     /// <p>The current status of the stream, the stream Amazon Resource Name (ARN), an array of shard objects that comprise the stream, and whether there are more shards available.</p>
@@ -193,7 +570,39 @@ pub struct DescribeStreamSummaryInput {
     pub stream_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DescribeStreamSummaryInput {
+    /// Returns a builder for `DescribeStreamSummaryInput`, checking its required members when built.
+    pub fn builder() -> DescribeStreamSummaryInputBuilder {
+        DescribeStreamSummaryInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStreamSummaryInput`](struct.DescribeStreamSummaryInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStreamSummaryInputBuilder {
+    stream_name: Option<String>,
+}
+
+impl DescribeStreamSummaryInputBuilder {
+    /// Sets the [`stream_name`](struct.DescribeStreamSummaryInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStreamSummaryInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStreamSummaryInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStreamSummaryInput {
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeStreamSummaryOutput {
     /// <p>A <a>StreamDescriptionSummary</a> containing information about the stream.</p>
@@ -206,23 +615,179 @@ pub struct DescribeStreamSummaryOutput {
 pub struct DisableEnhancedMonitoringInput {
     /// <p>List of shard-level metrics to disable.</p> <p>The following are the valid shard-level metrics. The value "<code>ALL</code>" disables every metric.</p> <ul> <li> <p> <code>IncomingBytes</code> </p> </li> <li> <p> <code>IncomingRecords</code> </p> </li> <li> <p> <code>OutgoingBytes</code> </p> </li> <li> <p> <code>OutgoingRecords</code> </p> </li> <li> <p> <code>WriteProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>ReadProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>IteratorAgeMilliseconds</code> </p> </li> <li> <p> <code>ALL</code> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring-with-cloudwatch.html">Monitoring the Amazon Kinesis Data Streams Service with Amazon CloudWatch</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p>
     #[serde(rename = "ShardLevelMetrics")]
-    pub shard_level_metrics: Vec<String>,
+    pub shard_level_metrics: Vec<MetricsName>,
     /// <p>The name of the Kinesis data stream for which to disable enhanced monitoring.</p>
     #[serde(rename = "StreamName")]
     pub stream_name: String,
 }
 
+impl DisableEnhancedMonitoringInput {
+    /// Returns a builder for `DisableEnhancedMonitoringInput`, checking its required members when built.
+    pub fn builder() -> DisableEnhancedMonitoringInputBuilder {
+        DisableEnhancedMonitoringInputBuilder::default()
+    }
+}
+
+/// Builds a [`DisableEnhancedMonitoringInput`](struct.DisableEnhancedMonitoringInput.html).
+#[derive(Default, Debug)]
+pub struct DisableEnhancedMonitoringInputBuilder {
+    shard_level_metrics: Option<Vec<MetricsName>>,
+    stream_name: Option<String>,
+}
+
+impl DisableEnhancedMonitoringInputBuilder {
+    /// Sets the [`shard_level_metrics`](struct.DisableEnhancedMonitoringInput.html#structfield.shard_level_metrics) member.
+    pub fn shard_level_metrics<T: Into<Vec<MetricsName>>>(mut self, value: T) -> Self {
+        self.shard_level_metrics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.DisableEnhancedMonitoringInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableEnhancedMonitoringInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisableEnhancedMonitoringInput, ::rusoto_core::BuildError> {
+        Ok(DisableEnhancedMonitoringInput {
+            shard_level_metrics: self
+                .shard_level_metrics
+                .ok_or(::rusoto_core::BuildError::missing("shard_level_metrics"))?,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the input for <a>EnableEnhancedMonitoring</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct EnableEnhancedMonitoringInput {
     /// <p>List of shard-level metrics to enable.</p> <p>The following are the valid shard-level metrics. The value "<code>ALL</code>" enables every metric.</p> <ul> <li> <p> <code>IncomingBytes</code> </p> </li> <li> <p> <code>IncomingRecords</code> </p> </li> <li> <p> <code>OutgoingBytes</code> </p> </li> <li> <p> <code>OutgoingRecords</code> </p> </li> <li> <p> <code>WriteProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>ReadProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>IteratorAgeMilliseconds</code> </p> </li> <li> <p> <code>ALL</code> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring-with-cloudwatch.html">Monitoring the Amazon Kinesis Data Streams Service with Amazon CloudWatch</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p>
     #[serde(rename = "ShardLevelMetrics")]
-    pub shard_level_metrics: Vec<String>,
+    pub shard_level_metrics: Vec<MetricsName>,
     /// <p>The name of the stream for which to enable enhanced monitoring.</p>
     #[serde(rename = "StreamName")]
     pub stream_name: String,
 }
 
+impl EnableEnhancedMonitoringInput {
+    /// Returns a builder for `EnableEnhancedMonitoringInput`, checking its required members when built.
+    pub fn builder() -> EnableEnhancedMonitoringInputBuilder {
+        EnableEnhancedMonitoringInputBuilder::default()
+    }
+}
+
+/// Builds a [`EnableEnhancedMonitoringInput`](struct.EnableEnhancedMonitoringInput.html).
+#[derive(Default, Debug)]
+pub struct EnableEnhancedMonitoringInputBuilder {
+    shard_level_metrics: Option<Vec<MetricsName>>,
+    stream_name: Option<String>,
+}
+
+impl EnableEnhancedMonitoringInputBuilder {
+    /// Sets the [`shard_level_metrics`](struct.EnableEnhancedMonitoringInput.html#structfield.shard_level_metrics) member.
+    pub fn shard_level_metrics<T: Into<Vec<MetricsName>>>(mut self, value: T) -> Self {
+        self.shard_level_metrics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.EnableEnhancedMonitoringInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableEnhancedMonitoringInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EnableEnhancedMonitoringInput, ::rusoto_core::BuildError> {
+        Ok(EnableEnhancedMonitoringInput {
+            shard_level_metrics: self
+                .shard_level_metrics
+                .ok_or(::rusoto_core::BuildError::missing("shard_level_metrics"))?,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
+/// <p><p>The encryption type to use on the record. This parameter can be one of the following values:</p> <ul> <li> <p> <code>NONE</code>: Do not encrypt the records in the stream.</p> </li> <li> <p> <code>KMS</code>: Use server-side encryption on the records in the stream using a customer-managed AWS KMS key.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EncryptionType {
+    /// <p><code>NONE</code> - Do not encrypt the records in the stream.</p>
+    None,
+    /// <p><code>KMS</code> - Use server-side encryption on the records in the stream using a customer-managed AWS KMS key.</p>
+    Kms,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EncryptionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionType::None => "NONE",
+            EncryptionType::Kms => "KMS",
+            EncryptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionType {
+    fn from(value: &'a str) -> EncryptionType {
+        match value {
+            "NONE" => EncryptionType::None,
+            "KMS" => EncryptionType::Kms,
+            _ => EncryptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionType {
+    fn from(value: String) -> EncryptionType {
+        EncryptionType::from(value.as_str())
+    }
+}
+
+impl From<EncryptionType> for String {
+    fn from(value: EncryptionType) -> String {
+        match value {
+            EncryptionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncryptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EncryptionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EncryptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EncryptionType::from(value))
+    }
+}
+
 /// <p>Represents enhanced metrics types.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -230,7 +795,7 @@ pub struct EnhancedMetrics {
     /// <p>List of shard-level metrics.</p> <p>The following are the valid shard-level metrics. The value "<code>ALL</code>" enhances every metric.</p> <ul> <li> <p> <code>IncomingBytes</code> </p> </li> <li> <p> <code>IncomingRecords</code> </p> </li> <li> <p> <code>OutgoingBytes</code> </p> </li> <li> <p> <code>OutgoingRecords</code> </p> </li> <li> <p> <code>WriteProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>ReadProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>IteratorAgeMilliseconds</code> </p> </li> <li> <p> <code>ALL</code> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring-with-cloudwatch.html">Monitoring the Amazon Kinesis Data Streams Service with Amazon CloudWatch</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p>
     #[serde(rename = "ShardLevelMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub shard_level_metrics: Option<Vec<String>>,
+    pub shard_level_metrics: Option<Vec<MetricsName>>,
 }
 
 /// <p>Represents the output for <a>EnableEnhancedMonitoring</a> and <a>DisableEnhancedMonitoring</a>.</p>
@@ -240,11 +805,11 @@ pub struct EnhancedMonitoringOutput {
     /// <p>Represents the current state of the metrics that are in the enhanced state before the operation.</p>
     #[serde(rename = "CurrentShardLevelMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub current_shard_level_metrics: Option<Vec<String>>,
+    pub current_shard_level_metrics: Option<Vec<MetricsName>>,
     /// <p>Represents the list of all the metrics that would be in the enhanced state after the operation.</p>
     #[serde(rename = "DesiredShardLevelMetrics")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub desired_shard_level_metrics: Option<Vec<String>>,
+    pub desired_shard_level_metrics: Option<Vec<MetricsName>>,
     /// <p>The name of the Kinesis data stream.</p>
     #[serde(rename = "StreamName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -276,6 +841,44 @@ pub struct GetRecordsInput {
     pub shard_iterator: String,
 }
 
+impl GetRecordsInput {
+    /// Returns a builder for `GetRecordsInput`, checking its required members when built.
+    pub fn builder() -> GetRecordsInputBuilder {
+        GetRecordsInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetRecordsInput`](struct.GetRecordsInput.html).
+#[derive(Default, Debug)]
+pub struct GetRecordsInputBuilder {
+    limit: Option<i64>,
+    shard_iterator: Option<String>,
+}
+
+impl GetRecordsInputBuilder {
+    /// Sets the [`limit`](struct.GetRecordsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shard_iterator`](struct.GetRecordsInput.html#structfield.shard_iterator) member.
+    pub fn shard_iterator<T: Into<String>>(mut self, value: T) -> Self {
+        self.shard_iterator = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRecordsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRecordsInput, ::rusoto_core::BuildError> {
+        Ok(GetRecordsInput {
+            limit: self.limit,
+            shard_iterator: self
+                .shard_iterator
+                .ok_or(::rusoto_core::BuildError::missing("shard_iterator"))?,
+        })
+    }
+}
+
 /// <p>Represents the output for <a>GetRecords</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -294,14 +897,14 @@ pub struct GetRecordsOutput {
 }
 
 /// <p>Represents the input for <code>GetShardIterator</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetShardIteratorInput {
     /// <p>The shard ID of the Kinesis Data Streams shard to get the iterator for.</p>
     #[serde(rename = "ShardId")]
     pub shard_id: String,
     /// <p><p>Determines how the shard iterator is used to start reading data records from the shard.</p> <p>The following are the valid Amazon Kinesis shard iterator types:</p> <ul> <li> <p>AT<em>SEQUENCE</em>NUMBER - Start reading from the position denoted by a specific sequence number, provided in the value <code>StartingSequenceNumber</code>.</p> </li> <li> <p>AFTER<em>SEQUENCE</em>NUMBER - Start reading right after the position denoted by a specific sequence number, provided in the value <code>StartingSequenceNumber</code>.</p> </li> <li> <p>AT<em>TIMESTAMP - Start reading from the position denoted by a specific time stamp, provided in the value <code>Timestamp</code>.</p> </li> <li> <p>TRIM</em>HORIZON - Start reading at the last untrimmed record in the shard in the system, which is the oldest data record in the shard.</p> </li> <li> <p>LATEST - Start reading just after the most recent record in the shard, so that you always read the most recent data in the shard.</p> </li> </ul></p>
     #[serde(rename = "ShardIteratorType")]
-    pub shard_iterator_type: String,
+    pub shard_iterator_type: ShardIteratorType,
     /// <p>The sequence number of the data record in the shard from which to start reading. Used with shard iterator type AT_SEQUENCE_NUMBER and AFTER_SEQUENCE_NUMBER.</p>
     #[serde(rename = "StartingSequenceNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -311,8 +914,75 @@ pub struct GetShardIteratorInput {
     pub stream_name: String,
     /// <p>The time stamp of the data record from which to start reading. Used with shard iterator type AT_TIMESTAMP. A time stamp is the Unix epoch date with precision in milliseconds. For example, <code>2016-04-04T19:58:46.480-00:00</code> or <code>1459799926.480</code>. If a record with this exact time stamp does not exist, the iterator returned is for the next (later) record. If the time stamp is older than the current trim horizon, the iterator returned is for the oldest untrimmed data record (TRIM_HORIZON).</p>
     #[serde(rename = "Timestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<EpochTimestamp>,
+}
+
+impl GetShardIteratorInput {
+    /// Returns a builder for `GetShardIteratorInput`, checking its required members when built.
+    pub fn builder() -> GetShardIteratorInputBuilder {
+        GetShardIteratorInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetShardIteratorInput`](struct.GetShardIteratorInput.html).
+#[derive(Default, Debug)]
+pub struct GetShardIteratorInputBuilder {
+    shard_id: Option<String>,
+    shard_iterator_type: Option<ShardIteratorType>,
+    starting_sequence_number: Option<String>,
+    stream_name: Option<String>,
+    timestamp: Option<EpochTimestamp>,
+}
+
+impl GetShardIteratorInputBuilder {
+    /// Sets the [`shard_id`](struct.GetShardIteratorInput.html#structfield.shard_id) member.
+    pub fn shard_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.shard_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shard_iterator_type`](struct.GetShardIteratorInput.html#structfield.shard_iterator_type) member.
+    pub fn shard_iterator_type<T: Into<ShardIteratorType>>(mut self, value: T) -> Self {
+        self.shard_iterator_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`starting_sequence_number`](struct.GetShardIteratorInput.html#structfield.starting_sequence_number) member.
+    pub fn starting_sequence_number<T: Into<String>>(mut self, value: T) -> Self {
+        self.starting_sequence_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.GetShardIteratorInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timestamp`](struct.GetShardIteratorInput.html#structfield.timestamp) member.
+    pub fn timestamp<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.timestamp = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetShardIteratorInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetShardIteratorInput, ::rusoto_core::BuildError> {
+        Ok(GetShardIteratorInput {
+            shard_id: self
+                .shard_id
+                .ok_or(::rusoto_core::BuildError::missing("shard_id"))?,
+            shard_iterator_type: self
+                .shard_iterator_type
+                .ok_or(::rusoto_core::BuildError::missing("shard_iterator_type"))?,
+            starting_sequence_number: self.starting_sequence_number,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+            timestamp: self.timestamp,
+        })
+    }
 }
 
 /// <p>Represents the output for <code>GetShardIterator</code>.</p>
@@ -348,6 +1018,48 @@ pub struct IncreaseStreamRetentionPeriodInput {
     pub stream_name: String,
 }
 
+impl IncreaseStreamRetentionPeriodInput {
+    /// Returns a builder for `IncreaseStreamRetentionPeriodInput`, checking its required members when built.
+    pub fn builder() -> IncreaseStreamRetentionPeriodInputBuilder {
+        IncreaseStreamRetentionPeriodInputBuilder::default()
+    }
+}
+
+/// Builds a [`IncreaseStreamRetentionPeriodInput`](struct.IncreaseStreamRetentionPeriodInput.html).
+#[derive(Default, Debug)]
+pub struct IncreaseStreamRetentionPeriodInputBuilder {
+    retention_period_hours: Option<i64>,
+    stream_name: Option<String>,
+}
+
+impl IncreaseStreamRetentionPeriodInputBuilder {
+    /// Sets the [`retention_period_hours`](struct.IncreaseStreamRetentionPeriodInput.html#structfield.retention_period_hours) member.
+    pub fn retention_period_hours<T: Into<i64>>(mut self, value: T) -> Self {
+        self.retention_period_hours = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.IncreaseStreamRetentionPeriodInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `IncreaseStreamRetentionPeriodInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<IncreaseStreamRetentionPeriodInput, ::rusoto_core::BuildError> {
+        Ok(IncreaseStreamRetentionPeriodInput {
+            retention_period_hours: self
+                .retention_period_hours
+                .ok_or(::rusoto_core::BuildError::missing("retention_period_hours"))?,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct InternalFailureException {
@@ -446,14 +1158,75 @@ pub struct ListShardsInput {
     pub next_token: Option<String>,
     /// <p>Specify this input parameter to distinguish data streams that have the same name. For example, if you create a data stream and then delete it, and you later create another data stream with the same name, you can use this input parameter to specify which of the two streams you want to list the shards for.</p> <p>You cannot specify this parameter if you specify the <code>NextToken</code> parameter.</p>
     #[serde(rename = "StreamCreationTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_creation_timestamp: Option<f64>,
+    pub stream_creation_timestamp: Option<EpochTimestamp>,
     /// <p>The name of the data stream whose shards you want to list. </p> <p>You cannot specify this parameter if you specify the <code>NextToken</code> parameter.</p>
     #[serde(rename = "StreamName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream_name: Option<String>,
 }
 
+impl ListShardsInput {
+    /// Returns a builder for `ListShardsInput`, checking its required members when built.
+    pub fn builder() -> ListShardsInputBuilder {
+        ListShardsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListShardsInput`](struct.ListShardsInput.html).
+#[derive(Default, Debug)]
+pub struct ListShardsInputBuilder {
+    exclusive_start_shard_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    stream_creation_timestamp: Option<EpochTimestamp>,
+    stream_name: Option<String>,
+}
+
+impl ListShardsInputBuilder {
+    /// Sets the [`exclusive_start_shard_id`](struct.ListShardsInput.html#structfield.exclusive_start_shard_id) member.
+    pub fn exclusive_start_shard_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_shard_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListShardsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListShardsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_creation_timestamp`](struct.ListShardsInput.html#structfield.stream_creation_timestamp) member.
+    pub fn stream_creation_timestamp<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.stream_creation_timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.ListShardsInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListShardsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListShardsInput, ::rusoto_core::BuildError> {
+        Ok(ListShardsInput {
+            exclusive_start_shard_id: self.exclusive_start_shard_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            stream_creation_timestamp: self.stream_creation_timestamp,
+            stream_name: self.stream_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListShardsOutput {
@@ -482,8 +1255,65 @@ pub struct ListStreamConsumersInput {
     pub stream_arn: String,
     /// <p>Specify this input parameter to distinguish data streams that have the same name. For example, if you create a data stream and then delete it, and you later create another data stream with the same name, you can use this input parameter to specify which of the two streams you want to list the consumers for. </p> <p>You can't specify this parameter if you specify the NextToken parameter. </p>
     #[serde(rename = "StreamCreationTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_creation_timestamp: Option<f64>,
+    pub stream_creation_timestamp: Option<EpochTimestamp>,
+}
+
+impl ListStreamConsumersInput {
+    /// Returns a builder for `ListStreamConsumersInput`, checking its required members when built.
+    pub fn builder() -> ListStreamConsumersInputBuilder {
+        ListStreamConsumersInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStreamConsumersInput`](struct.ListStreamConsumersInput.html).
+#[derive(Default, Debug)]
+pub struct ListStreamConsumersInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    stream_arn: Option<String>,
+    stream_creation_timestamp: Option<EpochTimestamp>,
+}
+
+impl ListStreamConsumersInputBuilder {
+    /// Sets the [`max_results`](struct.ListStreamConsumersInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListStreamConsumersInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.ListStreamConsumersInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_creation_timestamp`](struct.ListStreamConsumersInput.html#structfield.stream_creation_timestamp) member.
+    pub fn stream_creation_timestamp<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.stream_creation_timestamp = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStreamConsumersInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListStreamConsumersInput, ::rusoto_core::BuildError> {
+        Ok(ListStreamConsumersInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            stream_arn: self
+                .stream_arn
+                .ok_or(::rusoto_core::BuildError::missing("stream_arn"))?,
+            stream_creation_timestamp: self.stream_creation_timestamp,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -512,6 +1342,42 @@ pub struct ListStreamsInput {
     pub limit: Option<i64>,
 }
 
+impl ListStreamsInput {
+    /// Returns a builder for `ListStreamsInput`, checking its required members when built.
+    pub fn builder() -> ListStreamsInputBuilder {
+        ListStreamsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStreamsInput`](struct.ListStreamsInput.html).
+#[derive(Default, Debug)]
+pub struct ListStreamsInputBuilder {
+    exclusive_start_stream_name: Option<String>,
+    limit: Option<i64>,
+}
+
+impl ListStreamsInputBuilder {
+    /// Sets the [`exclusive_start_stream_name`](struct.ListStreamsInput.html#structfield.exclusive_start_stream_name) member.
+    pub fn exclusive_start_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListStreamsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStreamsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListStreamsInput, ::rusoto_core::BuildError> {
+        Ok(ListStreamsInput {
+            exclusive_start_stream_name: self.exclusive_start_stream_name,
+            limit: self.limit,
+        })
+    }
+}
+
 /// <p>Represents the output for <code>ListStreams</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -540,6 +1406,52 @@ pub struct ListTagsForStreamInput {
     pub stream_name: String,
 }
 
+impl ListTagsForStreamInput {
+    /// Returns a builder for `ListTagsForStreamInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForStreamInputBuilder {
+        ListTagsForStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForStreamInput`](struct.ListTagsForStreamInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForStreamInputBuilder {
+    exclusive_start_tag_key: Option<String>,
+    limit: Option<i64>,
+    stream_name: Option<String>,
+}
+
+impl ListTagsForStreamInputBuilder {
+    /// Sets the [`exclusive_start_tag_key`](struct.ListTagsForStreamInput.html#structfield.exclusive_start_tag_key) member.
+    pub fn exclusive_start_tag_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_tag_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListTagsForStreamInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.ListTagsForStreamInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTagsForStreamInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForStreamInput {
+            exclusive_start_tag_key: self.exclusive_start_tag_key,
+            limit: self.limit,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output for <code>ListTagsForStream</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -566,6 +1478,152 @@ pub struct MergeShardsInput {
     pub stream_name: String,
 }
 
+impl MergeShardsInput {
+    /// Returns a builder for `MergeShardsInput`, checking its required members when built.
+    pub fn builder() -> MergeShardsInputBuilder {
+        MergeShardsInputBuilder::default()
+    }
+}
+
+/// Builds a [`MergeShardsInput`](struct.MergeShardsInput.html).
+#[derive(Default, Debug)]
+pub struct MergeShardsInputBuilder {
+    adjacent_shard_to_merge: Option<String>,
+    shard_to_merge: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl MergeShardsInputBuilder {
+    /// Sets the [`adjacent_shard_to_merge`](struct.MergeShardsInput.html#structfield.adjacent_shard_to_merge) member.
+    pub fn adjacent_shard_to_merge<T: Into<String>>(mut self, value: T) -> Self {
+        self.adjacent_shard_to_merge = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shard_to_merge`](struct.MergeShardsInput.html#structfield.shard_to_merge) member.
+    pub fn shard_to_merge<T: Into<String>>(mut self, value: T) -> Self {
+        self.shard_to_merge = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.MergeShardsInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `MergeShardsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<MergeShardsInput, ::rusoto_core::BuildError> {
+        Ok(MergeShardsInput {
+            adjacent_shard_to_merge: self.adjacent_shard_to_merge.ok_or(
+                ::rusoto_core::BuildError::missing("adjacent_shard_to_merge"),
+            )?,
+            shard_to_merge: self
+                .shard_to_merge
+                .ok_or(::rusoto_core::BuildError::missing("shard_to_merge"))?,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
+/// <p>List of shard-level metrics to disable.</p> <p>The following are the valid shard-level metrics. The value "<code>ALL</code>" disables every metric.</p> <ul> <li> <p> <code>IncomingBytes</code> </p> </li> <li> <p> <code>IncomingRecords</code> </p> </li> <li> <p> <code>OutgoingBytes</code> </p> </li> <li> <p> <code>OutgoingRecords</code> </p> </li> <li> <p> <code>WriteProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>ReadProvisionedThroughputExceeded</code> </p> </li> <li> <p> <code>IteratorAgeMilliseconds</code> </p> </li> <li> <p> <code>ALL</code> </p> </li> </ul> <p>For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/monitoring-with-cloudwatch.html">Monitoring the Amazon Kinesis Data Streams Service with Amazon CloudWatch</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetricsName {
+    /// <p><code>IncomingBytes</code></p>
+    IncomingBytes,
+    /// <p><code>IncomingRecords</code></p>
+    IncomingRecords,
+    /// <p><code>OutgoingBytes</code></p>
+    OutgoingBytes,
+    /// <p><code>OutgoingRecords</code></p>
+    OutgoingRecords,
+    /// <p><code>WriteProvisionedThroughputExceeded</code></p>
+    WriteProvisionedThroughputExceeded,
+    /// <p><code>ReadProvisionedThroughputExceeded</code></p>
+    ReadProvisionedThroughputExceeded,
+    /// <p><code>IteratorAgeMilliseconds</code></p>
+    IteratorAgeMilliseconds,
+    /// <p><code>ALL</code></p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MetricsName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricsName::IncomingBytes => "IncomingBytes",
+            MetricsName::IncomingRecords => "IncomingRecords",
+            MetricsName::OutgoingBytes => "OutgoingBytes",
+            MetricsName::OutgoingRecords => "OutgoingRecords",
+            MetricsName::WriteProvisionedThroughputExceeded => "WriteProvisionedThroughputExceeded",
+            MetricsName::ReadProvisionedThroughputExceeded => "ReadProvisionedThroughputExceeded",
+            MetricsName::IteratorAgeMilliseconds => "IteratorAgeMilliseconds",
+            MetricsName::All => "ALL",
+            MetricsName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MetricsName {
+    fn from(value: &'a str) -> MetricsName {
+        match value {
+            "IncomingBytes" => MetricsName::IncomingBytes,
+            "IncomingRecords" => MetricsName::IncomingRecords,
+            "OutgoingBytes" => MetricsName::OutgoingBytes,
+            "OutgoingRecords" => MetricsName::OutgoingRecords,
+            "WriteProvisionedThroughputExceeded" => MetricsName::WriteProvisionedThroughputExceeded,
+            "ReadProvisionedThroughputExceeded" => MetricsName::ReadProvisionedThroughputExceeded,
+            "IteratorAgeMilliseconds" => MetricsName::IteratorAgeMilliseconds,
+            "ALL" => MetricsName::All,
+            _ => MetricsName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricsName {
+    fn from(value: String) -> MetricsName {
+        MetricsName::from(value.as_str())
+    }
+}
+
+impl From<MetricsName> for String {
+    fn from(value: MetricsName) -> String {
+        match value {
+            MetricsName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MetricsName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MetricsName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricsName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MetricsName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MetricsName::from(value))
+    }
+}
+
 /// <p>The request rate for the stream is too high, or the requested data is too large for the available throughput. Reduce the frequency or size of your requests. For more information, see <a href="http://docs.aws.amazon.com/kinesis/latest/dev/service-sizes-and-limits.html">Streams Limits</a> in the <i>Amazon Kinesis Data Streams Developer Guide</i>, and <a href="http://docs.aws.amazon.com/general/latest/gr/api-retries.html">Error Retries and Exponential Backoff in AWS</a> in the <i>AWS General Reference</i>.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ProvisionedThroughputExceededException {
@@ -600,6 +1658,72 @@ pub struct PutRecordInput {
     pub stream_name: String,
 }
 
+impl PutRecordInput {
+    /// Returns a builder for `PutRecordInput`, checking its required members when built.
+    pub fn builder() -> PutRecordInputBuilder {
+        PutRecordInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutRecordInput`](struct.PutRecordInput.html).
+#[derive(Default, Debug)]
+pub struct PutRecordInputBuilder {
+    data: Option<bytes::Bytes>,
+    explicit_hash_key: Option<String>,
+    partition_key: Option<String>,
+    sequence_number_for_ordering: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl PutRecordInputBuilder {
+    /// Sets the [`data`](struct.PutRecordInput.html#structfield.data) member.
+    pub fn data<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`explicit_hash_key`](struct.PutRecordInput.html#structfield.explicit_hash_key) member.
+    pub fn explicit_hash_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.explicit_hash_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`partition_key`](struct.PutRecordInput.html#structfield.partition_key) member.
+    pub fn partition_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.partition_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sequence_number_for_ordering`](struct.PutRecordInput.html#structfield.sequence_number_for_ordering) member.
+    pub fn sequence_number_for_ordering<T: Into<String>>(mut self, value: T) -> Self {
+        self.sequence_number_for_ordering = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.PutRecordInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRecordInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutRecordInput, ::rusoto_core::BuildError> {
+        Ok(PutRecordInput {
+            data: self
+                .data
+                .ok_or(::rusoto_core::BuildError::missing("data"))?,
+            explicit_hash_key: self.explicit_hash_key,
+            partition_key: self
+                .partition_key
+                .ok_or(::rusoto_core::BuildError::missing("partition_key"))?,
+            sequence_number_for_ordering: self.sequence_number_for_ordering,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output for <code>PutRecord</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -607,7 +1731,7 @@ pub struct PutRecordOutput {
     /// <p><p>The encryption type to use on the record. This parameter can be one of the following values:</p> <ul> <li> <p> <code>NONE</code>: Do not encrypt the records in the stream.</p> </li> <li> <p> <code>KMS</code>: Use server-side encryption on the records in the stream using a customer-managed AWS KMS key.</p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_type: Option<String>,
+    pub encryption_type: Option<EncryptionType>,
     /// <p>The sequence number identifier that was assigned to the put data record. The sequence number for the record is unique across all records in the stream. A sequence number is the identifier associated with every record put into the stream.</p>
     #[serde(rename = "SequenceNumber")]
     pub sequence_number: String,
@@ -627,6 +1751,46 @@ pub struct PutRecordsInput {
     pub stream_name: String,
 }
 
+impl PutRecordsInput {
+    /// Returns a builder for `PutRecordsInput`, checking its required members when built.
+    pub fn builder() -> PutRecordsInputBuilder {
+        PutRecordsInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutRecordsInput`](struct.PutRecordsInput.html).
+#[derive(Default, Debug)]
+pub struct PutRecordsInputBuilder {
+    records: Option<Vec<PutRecordsRequestEntry>>,
+    stream_name: Option<String>,
+}
+
+impl PutRecordsInputBuilder {
+    /// Sets the [`records`](struct.PutRecordsInput.html#structfield.records) member.
+    pub fn records<T: Into<Vec<PutRecordsRequestEntry>>>(mut self, value: T) -> Self {
+        self.records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.PutRecordsInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRecordsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutRecordsInput, ::rusoto_core::BuildError> {
+        Ok(PutRecordsInput {
+            records: self
+                .records
+                .ok_or(::rusoto_core::BuildError::missing("records"))?,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
 /// <p> <code>PutRecords</code> results.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -634,7 +1798,7 @@ pub struct PutRecordsOutput {
     /// <p><p>The encryption type used on the records. This parameter can be one of the following values:</p> <ul> <li> <p> <code>NONE</code>: Do not encrypt the records.</p> </li> <li> <p> <code>KMS</code>: Use server-side encryption on the records using a customer-managed AWS KMS key.</p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_type: Option<String>,
+    pub encryption_type: Option<EncryptionType>,
     /// <p>The number of unsuccessfully processed records in a <code>PutRecords</code> request.</p>
     #[serde(rename = "FailedRecordCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -692,8 +1856,9 @@ pub struct PutRecordsResultEntry {
 pub struct Record {
     /// <p>The approximate time that the record was inserted into the stream.</p>
     #[serde(rename = "ApproximateArrivalTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approximate_arrival_timestamp: Option<f64>,
+    pub approximate_arrival_timestamp: Option<EpochTimestamp>,
     /// <p>The data blob. The data in the blob is both opaque and immutable to Kinesis Data Streams, which does not inspect, interpret, or change the data in the blob in any way. When the data blob (the payload before base64-encoding) is added to the partition key size, the total size must not exceed the maximum record size (1 MB).</p>
     #[serde(rename = "Data")]
     #[serde(
@@ -705,7 +1870,7 @@ pub struct Record {
     /// <p><p>The encryption type used on the record. This parameter can be one of the following values:</p> <ul> <li> <p> <code>NONE</code>: Do not encrypt the records in the stream.</p> </li> <li> <p> <code>KMS</code>: Use server-side encryption on the records in the stream using a customer-managed AWS KMS key.</p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_type: Option<String>,
+    pub encryption_type: Option<EncryptionType>,
     /// <p>Identifies which shard in the stream the data record is assigned to.</p>
     #[serde(rename = "PartitionKey")]
     pub partition_key: String,
@@ -724,7 +1889,49 @@ pub struct RegisterStreamConsumerInput {
     pub stream_arn: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl RegisterStreamConsumerInput {
+    /// Returns a builder for `RegisterStreamConsumerInput`, checking its required members when built.
+    pub fn builder() -> RegisterStreamConsumerInputBuilder {
+        RegisterStreamConsumerInputBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterStreamConsumerInput`](struct.RegisterStreamConsumerInput.html).
+#[derive(Default, Debug)]
+pub struct RegisterStreamConsumerInputBuilder {
+    consumer_name: Option<String>,
+    stream_arn: Option<String>,
+}
+
+impl RegisterStreamConsumerInputBuilder {
+    /// Sets the [`consumer_name`](struct.RegisterStreamConsumerInput.html#structfield.consumer_name) member.
+    pub fn consumer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.consumer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.RegisterStreamConsumerInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterStreamConsumerInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterStreamConsumerInput, ::rusoto_core::BuildError> {
+        Ok(RegisterStreamConsumerInput {
+            consumer_name: self
+                .consumer_name
+                .ok_or(::rusoto_core::BuildError::missing("consumer_name"))?,
+            stream_arn: self
+                .stream_arn
+                .ok_or(::rusoto_core::BuildError::missing("stream_arn"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RegisterStreamConsumerOutput {
     /// <p>An object that represents the details of the consumer you registered. When you register a consumer, it gets an ARN that is generated by Kinesis Data Streams.</p>
@@ -743,6 +1950,48 @@ pub struct RemoveTagsFromStreamInput {
     pub tag_keys: Vec<String>,
 }
 
+impl RemoveTagsFromStreamInput {
+    /// Returns a builder for `RemoveTagsFromStreamInput`, checking its required members when built.
+    pub fn builder() -> RemoveTagsFromStreamInputBuilder {
+        RemoveTagsFromStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsFromStreamInput`](struct.RemoveTagsFromStreamInput.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsFromStreamInputBuilder {
+    stream_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl RemoveTagsFromStreamInputBuilder {
+    /// Sets the [`stream_name`](struct.RemoveTagsFromStreamInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.RemoveTagsFromStreamInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsFromStreamInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveTagsFromStreamInput, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsFromStreamInput {
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p>The resource is not available for this operation. For successful operation, the resource must be in the <code>ACTIVE</code> state.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -763,6 +2012,74 @@ pub struct ResourceNotFoundException {
     pub message: Option<String>,
 }
 
+/// <p>The scaling type. Uniform scaling creates shards of equal size.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScalingType {
+    /// <p><code>UNIFORM_SCALING</code></p>
+    UniformScaling,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScalingType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScalingType::UniformScaling => "UNIFORM_SCALING",
+            ScalingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScalingType {
+    fn from(value: &'a str) -> ScalingType {
+        match value {
+            "UNIFORM_SCALING" => ScalingType::UniformScaling,
+            _ => ScalingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScalingType {
+    fn from(value: String) -> ScalingType {
+        ScalingType::from(value.as_str())
+    }
+}
+
+impl From<ScalingType> for String {
+    fn from(value: ScalingType) -> String {
+        match value {
+            ScalingType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScalingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ScalingType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ScalingType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ScalingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ScalingType::from(value))
+    }
+}
+
 /// <p>The range of possible sequence numbers for the shard.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -799,6 +2116,90 @@ pub struct Shard {
     pub shard_id: String,
 }
 
+/// <p><p>Determines how the shard iterator is used to start reading data records from the shard.</p> <p>The following are the valid Amazon Kinesis shard iterator types:</p> <ul> <li> <p>AT<em>SEQUENCE</em>NUMBER - Start reading from the position denoted by a specific sequence number, provided in the value <code>StartingSequenceNumber</code>.</p> </li> <li> <p>AFTER<em>SEQUENCE</em>NUMBER - Start reading right after the position denoted by a specific sequence number, provided in the value <code>StartingSequenceNumber</code>.</p> </li> <li> <p>AT<em>TIMESTAMP - Start reading from the position denoted by a specific time stamp, provided in the value <code>Timestamp</code>.</p> </li> <li> <p>TRIM</em>HORIZON - Start reading at the last untrimmed record in the shard in the system, which is the oldest data record in the shard.</p> </li> <li> <p>LATEST - Start reading just after the most recent record in the shard, so that you always read the most recent data in the shard.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ShardIteratorType {
+    /// <p><code>AT_SEQUENCE_NUMBER</code></p>
+    AtSequenceNumber,
+    /// <p><code>AFTER_SEQUENCE_NUMBER</code></p>
+    AfterSequenceNumber,
+    /// <p><code>TRIM_HORIZON</code></p>
+    TrimHorizon,
+    /// <p><code>LATEST</code></p>
+    Latest,
+    /// <p><code>AT_TIMESTAMP</code></p>
+    AtTimestamp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ShardIteratorType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ShardIteratorType::AtSequenceNumber => "AT_SEQUENCE_NUMBER",
+            ShardIteratorType::AfterSequenceNumber => "AFTER_SEQUENCE_NUMBER",
+            ShardIteratorType::TrimHorizon => "TRIM_HORIZON",
+            ShardIteratorType::Latest => "LATEST",
+            ShardIteratorType::AtTimestamp => "AT_TIMESTAMP",
+            ShardIteratorType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ShardIteratorType {
+    fn from(value: &'a str) -> ShardIteratorType {
+        match value {
+            "AT_SEQUENCE_NUMBER" => ShardIteratorType::AtSequenceNumber,
+            "AFTER_SEQUENCE_NUMBER" => ShardIteratorType::AfterSequenceNumber,
+            "TRIM_HORIZON" => ShardIteratorType::TrimHorizon,
+            "LATEST" => ShardIteratorType::Latest,
+            "AT_TIMESTAMP" => ShardIteratorType::AtTimestamp,
+            _ => ShardIteratorType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ShardIteratorType {
+    fn from(value: String) -> ShardIteratorType {
+        ShardIteratorType::from(value.as_str())
+    }
+}
+
+impl From<ShardIteratorType> for String {
+    fn from(value: ShardIteratorType) -> String {
+        match value {
+            ShardIteratorType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ShardIteratorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ShardIteratorType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ShardIteratorType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ShardIteratorType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ShardIteratorType::from(value))
+    }
+}
+
 /// <p>Represents the input for <code>SplitShard</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SplitShardInput {
@@ -813,11 +2214,61 @@ pub struct SplitShardInput {
     pub stream_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl SplitShardInput {
+    /// Returns a builder for `SplitShardInput`, checking its required members when built.
+    pub fn builder() -> SplitShardInputBuilder {
+        SplitShardInputBuilder::default()
+    }
+}
+
+/// Builds a [`SplitShardInput`](struct.SplitShardInput.html).
+#[derive(Default, Debug)]
+pub struct SplitShardInputBuilder {
+    new_starting_hash_key: Option<String>,
+    shard_to_split: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl SplitShardInputBuilder {
+    /// Sets the [`new_starting_hash_key`](struct.SplitShardInput.html#structfield.new_starting_hash_key) member.
+    pub fn new_starting_hash_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_starting_hash_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shard_to_split`](struct.SplitShardInput.html#structfield.shard_to_split) member.
+    pub fn shard_to_split<T: Into<String>>(mut self, value: T) -> Self {
+        self.shard_to_split = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.SplitShardInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `SplitShardInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SplitShardInput, ::rusoto_core::BuildError> {
+        Ok(SplitShardInput {
+            new_starting_hash_key: self
+                .new_starting_hash_key
+                .ok_or(::rusoto_core::BuildError::missing("new_starting_hash_key"))?,
+            shard_to_split: self
+                .shard_to_split
+                .ok_or(::rusoto_core::BuildError::missing("shard_to_split"))?,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct StartStreamEncryptionInput {
     /// <p>The encryption type to use. The only valid value is <code>KMS</code>.</p>
     #[serde(rename = "EncryptionType")]
-    pub encryption_type: String,
+    pub encryption_type: EncryptionType,
     /// <p><p>The GUID for the customer-managed AWS KMS key to use for encryption. This value can be a globally unique identifier, a fully specified Amazon Resource Name (ARN) to either an alias or a key, or an alias name prefixed by &quot;alias/&quot;.You can also use a master key owned by Kinesis Data Streams by specifying the alias <code>aws/kinesis</code>.</p> <ul> <li> <p>Key ARN example: <code>arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012</code> </p> </li> <li> <p>Alias ARN example: <code>arn:aws:kms:us-east-1:123456789012:alias/MyAliasName</code> </p> </li> <li> <p>Globally unique key ID example: <code>12345678-1234-1234-1234-123456789012</code> </p> </li> <li> <p>Alias name example: <code>alias/MyAliasName</code> </p> </li> <li> <p>Master key owned by Kinesis Data Streams: <code>alias/aws/kinesis</code> </p> </li> </ul></p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
@@ -826,23 +2277,76 @@ pub struct StartStreamEncryptionInput {
     pub stream_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl StartStreamEncryptionInput {
+    /// Returns a builder for `StartStreamEncryptionInput`, checking its required members when built.
+    pub fn builder() -> StartStreamEncryptionInputBuilder {
+        StartStreamEncryptionInputBuilder::default()
+    }
+}
+
+/// Builds a [`StartStreamEncryptionInput`](struct.StartStreamEncryptionInput.html).
+#[derive(Default, Debug)]
+pub struct StartStreamEncryptionInputBuilder {
+    encryption_type: Option<EncryptionType>,
+    key_id: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl StartStreamEncryptionInputBuilder {
+    /// Sets the [`encryption_type`](struct.StartStreamEncryptionInput.html#structfield.encryption_type) member.
+    pub fn encryption_type<T: Into<EncryptionType>>(mut self, value: T) -> Self {
+        self.encryption_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.StartStreamEncryptionInput.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.StartStreamEncryptionInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartStreamEncryptionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartStreamEncryptionInput, ::rusoto_core::BuildError> {
+        Ok(StartStreamEncryptionInput {
+            encryption_type: self
+                .encryption_type
+                .ok_or(::rusoto_core::BuildError::missing("encryption_type"))?,
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct StartingPosition {
     #[serde(rename = "SequenceNumber")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sequence_number: Option<String>,
     #[serde(rename = "Timestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<EpochTimestamp>,
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: ShardIteratorType,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct StopStreamEncryptionInput {
     /// <p>The encryption type. The only valid value is <code>KMS</code>.</p>
     #[serde(rename = "EncryptionType")]
-    pub encryption_type: String,
+    pub encryption_type: EncryptionType,
     /// <p><p>The GUID for the customer-managed AWS KMS key to use for encryption. This value can be a globally unique identifier, a fully specified Amazon Resource Name (ARN) to either an alias or a key, or an alias name prefixed by &quot;alias/&quot;.You can also use a master key owned by Kinesis Data Streams by specifying the alias <code>aws/kinesis</code>.</p> <ul> <li> <p>Key ARN example: <code>arn:aws:kms:us-east-1:123456789012:key/12345678-1234-1234-1234-123456789012</code> </p> </li> <li> <p>Alias ARN example: <code>arn:aws:kms:us-east-1:123456789012:alias/MyAliasName</code> </p> </li> <li> <p>Globally unique key ID example: <code>12345678-1234-1234-1234-123456789012</code> </p> </li> <li> <p>Alias name example: <code>alias/MyAliasName</code> </p> </li> <li> <p>Master key owned by Kinesis Data Streams: <code>alias/aws/kinesis</code> </p> </li> </ul></p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
@@ -851,14 +2355,66 @@ pub struct StopStreamEncryptionInput {
     pub stream_name: String,
 }
 
+impl StopStreamEncryptionInput {
+    /// Returns a builder for `StopStreamEncryptionInput`, checking its required members when built.
+    pub fn builder() -> StopStreamEncryptionInputBuilder {
+        StopStreamEncryptionInputBuilder::default()
+    }
+}
+
+/// Builds a [`StopStreamEncryptionInput`](struct.StopStreamEncryptionInput.html).
+#[derive(Default, Debug)]
+pub struct StopStreamEncryptionInputBuilder {
+    encryption_type: Option<EncryptionType>,
+    key_id: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl StopStreamEncryptionInputBuilder {
+    /// Sets the [`encryption_type`](struct.StopStreamEncryptionInput.html#structfield.encryption_type) member.
+    pub fn encryption_type<T: Into<EncryptionType>>(mut self, value: T) -> Self {
+        self.encryption_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.StopStreamEncryptionInput.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.StopStreamEncryptionInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopStreamEncryptionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopStreamEncryptionInput, ::rusoto_core::BuildError> {
+        Ok(StopStreamEncryptionInput {
+            encryption_type: self
+                .encryption_type
+                .ok_or(::rusoto_core::BuildError::missing("encryption_type"))?,
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output for <a>DescribeStream</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StreamDescription {
     /// <p><p>The server-side encryption type used on the stream. This parameter can be one of the following values:</p> <ul> <li> <p> <code>NONE</code>: Do not encrypt the records in the stream.</p> </li> <li> <p> <code>KMS</code>: Use server-side encryption on the records in the stream using a customer-managed AWS KMS key.</p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_type: Option<String>,
+    pub encryption_type: Option<EncryptionType>,
     /// <p>Represents the current enhanced monitoring settings of the stream.</p>
     #[serde(rename = "EnhancedMonitoring")]
     pub enhanced_monitoring: Vec<EnhancedMetrics>,
@@ -880,17 +2436,18 @@ pub struct StreamDescription {
     pub stream_arn: String,
     /// <p>The approximate time that the stream was created.</p>
     #[serde(rename = "StreamCreationTimestamp")]
-    pub stream_creation_timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub stream_creation_timestamp: EpochTimestamp,
     /// <p>The name of the stream being described.</p>
     #[serde(rename = "StreamName")]
     pub stream_name: String,
     /// <p><p>The current status of the stream being described. The stream status is one of the following states:</p> <ul> <li> <p> <code>CREATING</code> - The stream is being created. Kinesis Data Streams immediately returns and sets <code>StreamStatus</code> to <code>CREATING</code>.</p> </li> <li> <p> <code>DELETING</code> - The stream is being deleted. The specified stream is in the <code>DELETING</code> state until Kinesis Data Streams completes the deletion.</p> </li> <li> <p> <code>ACTIVE</code> - The stream exists and is ready for read and write operations or deletion. You should perform read and write operations only on an <code>ACTIVE</code> stream.</p> </li> <li> <p> <code>UPDATING</code> - Shards in the stream are being merged or split. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state.</p> </li> </ul></p>
     #[serde(rename = "StreamStatus")]
-    pub stream_status: String,
+    pub stream_status: StreamStatus,
 }
 
 /// <p>Represents the output for <a>DescribeStreamSummary</a> </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StreamDescriptionSummary {
     /// <p>The number of enhanced fan-out consumers registered with the stream.</p>
@@ -900,7 +2457,7 @@ pub struct StreamDescriptionSummary {
     /// <p><p>The encryption type used. This value is one of the following:</p> <ul> <li> <p> <code>KMS</code> </p> </li> <li> <p> <code>NONE</code> </p> </li> </ul></p>
     #[serde(rename = "EncryptionType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_type: Option<String>,
+    pub encryption_type: Option<EncryptionType>,
     /// <p>Represents the current enhanced monitoring settings of the stream.</p>
     #[serde(rename = "EnhancedMonitoring")]
     pub enhanced_monitoring: Vec<EnhancedMetrics>,
@@ -919,13 +2476,94 @@ pub struct StreamDescriptionSummary {
     pub stream_arn: String,
     /// <p>The approximate time that the stream was created.</p>
     #[serde(rename = "StreamCreationTimestamp")]
-    pub stream_creation_timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub stream_creation_timestamp: EpochTimestamp,
     /// <p>The name of the stream being described.</p>
     #[serde(rename = "StreamName")]
     pub stream_name: String,
     /// <p><p>The current status of the stream being described. The stream status is one of the following states:</p> <ul> <li> <p> <code>CREATING</code> - The stream is being created. Kinesis Data Streams immediately returns and sets <code>StreamStatus</code> to <code>CREATING</code>.</p> </li> <li> <p> <code>DELETING</code> - The stream is being deleted. The specified stream is in the <code>DELETING</code> state until Kinesis Data Streams completes the deletion.</p> </li> <li> <p> <code>ACTIVE</code> - The stream exists and is ready for read and write operations or deletion. You should perform read and write operations only on an <code>ACTIVE</code> stream.</p> </li> <li> <p> <code>UPDATING</code> - Shards in the stream are being merged or split. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state.</p> </li> </ul></p>
     #[serde(rename = "StreamStatus")]
-    pub stream_status: String,
+    pub stream_status: StreamStatus,
+}
+
+/// <p><p>The current status of the stream being described. The stream status is one of the following states:</p> <ul> <li> <p> <code>CREATING</code> - The stream is being created. Kinesis Data Streams immediately returns and sets <code>StreamStatus</code> to <code>CREATING</code>.</p> </li> <li> <p> <code>DELETING</code> - The stream is being deleted. The specified stream is in the <code>DELETING</code> state until Kinesis Data Streams completes the deletion.</p> </li> <li> <p> <code>ACTIVE</code> - The stream exists and is ready for read and write operations or deletion. You should perform read and write operations only on an <code>ACTIVE</code> stream.</p> </li> <li> <p> <code>UPDATING</code> - Shards in the stream are being merged or split. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StreamStatus {
+    /// <p><code>CREATING</code> - The stream is being created. Kinesis Data Streams immediately returns and sets <code>StreamStatus</code> to <code>CREATING</code>.</p>
+    Creating,
+    /// <p><code>DELETING</code> - The stream is being deleted. The specified stream is in the <code>DELETING</code> state until Kinesis Data Streams completes the deletion.</p>
+    Deleting,
+    /// <p><code>ACTIVE</code> - The stream exists and is ready for read and write operations or deletion. You should perform read and write operations only on an <code>ACTIVE</code> stream.</p>
+    Active,
+    /// <p><code>UPDATING</code> - Shards in the stream are being merged or split. Read and write operations continue to work while the stream is in the <code>UPDATING</code> state.</p>
+    Updating,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StreamStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamStatus::Creating => "CREATING",
+            StreamStatus::Deleting => "DELETING",
+            StreamStatus::Active => "ACTIVE",
+            StreamStatus::Updating => "UPDATING",
+            StreamStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StreamStatus {
+    fn from(value: &'a str) -> StreamStatus {
+        match value {
+            "CREATING" => StreamStatus::Creating,
+            "DELETING" => StreamStatus::Deleting,
+            "ACTIVE" => StreamStatus::Active,
+            "UPDATING" => StreamStatus::Updating,
+            _ => StreamStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamStatus {
+    fn from(value: String) -> StreamStatus {
+        StreamStatus::from(value.as_str())
+    }
+}
+
+impl From<StreamStatus> for String {
+    fn from(value: StreamStatus) -> String {
+        match value {
+            StreamStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StreamStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StreamStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StreamStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StreamStatus::from(value))
+    }
 }
 
 /// <p>After you call <a>SubscribeToShard</a>, Kinesis Data Streams sends events of this type to your consumer. </p>
@@ -977,7 +2615,7 @@ pub struct SubscribeToShardEventStream {
     pub subscribe_to_shard_event: SubscribeToShardEvent,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SubscribeToShardInput {
     /// <p>For this parameter, use the value you obtained when you called <a>RegisterStreamConsumer</a>.</p>
     #[serde(rename = "ConsumerARN")]
@@ -989,6 +2627,56 @@ pub struct SubscribeToShardInput {
     pub starting_position: StartingPosition,
 }
 
+impl SubscribeToShardInput {
+    /// Returns a builder for `SubscribeToShardInput`, checking its required members when built.
+    pub fn builder() -> SubscribeToShardInputBuilder {
+        SubscribeToShardInputBuilder::default()
+    }
+}
+
+/// Builds a [`SubscribeToShardInput`](struct.SubscribeToShardInput.html).
+#[derive(Default, Debug)]
+pub struct SubscribeToShardInputBuilder {
+    consumer_arn: Option<String>,
+    shard_id: Option<String>,
+    starting_position: Option<StartingPosition>,
+}
+
+impl SubscribeToShardInputBuilder {
+    /// Sets the [`consumer_arn`](struct.SubscribeToShardInput.html#structfield.consumer_arn) member.
+    pub fn consumer_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.consumer_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shard_id`](struct.SubscribeToShardInput.html#structfield.shard_id) member.
+    pub fn shard_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.shard_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`starting_position`](struct.SubscribeToShardInput.html#structfield.starting_position) member.
+    pub fn starting_position<T: Into<StartingPosition>>(mut self, value: T) -> Self {
+        self.starting_position = Some(value.into());
+        self
+    }
+
+    /// Builds the `SubscribeToShardInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SubscribeToShardInput, ::rusoto_core::BuildError> {
+        Ok(SubscribeToShardInput {
+            consumer_arn: self
+                .consumer_arn
+                .ok_or(::rusoto_core::BuildError::missing("consumer_arn"))?,
+            shard_id: self
+                .shard_id
+                .ok_or(::rusoto_core::BuildError::missing("shard_id"))?,
+            starting_position: self
+                .starting_position
+                .ok_or(::rusoto_core::BuildError::missing("starting_position"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SubscribeToShardOutput {
@@ -1010,11 +2698,11 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateShardCountInput {
     /// <p>The scaling type. Uniform scaling creates shards of equal size.</p>
     #[serde(rename = "ScalingType")]
-    pub scaling_type: String,
+    pub scaling_type: ScalingType,
     /// <p>The name of the stream.</p>
     #[serde(rename = "StreamName")]
     pub stream_name: String,
@@ -1023,6 +2711,56 @@ pub struct UpdateShardCountInput {
     pub target_shard_count: i64,
 }
 
+impl UpdateShardCountInput {
+    /// Returns a builder for `UpdateShardCountInput`, checking its required members when built.
+    pub fn builder() -> UpdateShardCountInputBuilder {
+        UpdateShardCountInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateShardCountInput`](struct.UpdateShardCountInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateShardCountInputBuilder {
+    scaling_type: Option<ScalingType>,
+    stream_name: Option<String>,
+    target_shard_count: Option<i64>,
+}
+
+impl UpdateShardCountInputBuilder {
+    /// Sets the [`scaling_type`](struct.UpdateShardCountInput.html#structfield.scaling_type) member.
+    pub fn scaling_type<T: Into<ScalingType>>(mut self, value: T) -> Self {
+        self.scaling_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.UpdateShardCountInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_shard_count`](struct.UpdateShardCountInput.html#structfield.target_shard_count) member.
+    pub fn target_shard_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.target_shard_count = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateShardCountInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateShardCountInput, ::rusoto_core::BuildError> {
+        Ok(UpdateShardCountInput {
+            scaling_type: self
+                .scaling_type
+                .ok_or(::rusoto_core::BuildError::missing("scaling_type"))?,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+            target_shard_count: self
+                .target_shard_count
+                .ok_or(::rusoto_core::BuildError::missing("target_shard_count"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateShardCountOutput {
@@ -2756,6 +4494,165 @@ pub trait Kinesis {
         input: UpdateShardCountInput,
     ) -> RusotoFuture<UpdateShardCountOutput, UpdateShardCountError>;
 }
+impl<T: ?Sized + Kinesis> Kinesis for ::std::sync::Arc<T> {
+    fn add_tags_to_stream(
+        &self,
+        input: AddTagsToStreamInput,
+    ) -> RusotoFuture<(), AddTagsToStreamError> {
+        (**self).add_tags_to_stream(input)
+    }
+    fn create_stream(&self, input: CreateStreamInput) -> RusotoFuture<(), CreateStreamError> {
+        (**self).create_stream(input)
+    }
+    fn decrease_stream_retention_period(
+        &self,
+        input: DecreaseStreamRetentionPeriodInput,
+    ) -> RusotoFuture<(), DecreaseStreamRetentionPeriodError> {
+        (**self).decrease_stream_retention_period(input)
+    }
+    fn delete_stream(&self, input: DeleteStreamInput) -> RusotoFuture<(), DeleteStreamError> {
+        (**self).delete_stream(input)
+    }
+    fn deregister_stream_consumer(
+        &self,
+        input: DeregisterStreamConsumerInput,
+    ) -> RusotoFuture<(), DeregisterStreamConsumerError> {
+        (**self).deregister_stream_consumer(input)
+    }
+    fn describe_limits(&self) -> RusotoFuture<DescribeLimitsOutput, DescribeLimitsError> {
+        (**self).describe_limits()
+    }
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        (**self).describe_stream(input)
+    }
+    fn describe_stream_consumer(
+        &self,
+        input: DescribeStreamConsumerInput,
+    ) -> RusotoFuture<DescribeStreamConsumerOutput, DescribeStreamConsumerError> {
+        (**self).describe_stream_consumer(input)
+    }
+    fn describe_stream_summary(
+        &self,
+        input: DescribeStreamSummaryInput,
+    ) -> RusotoFuture<DescribeStreamSummaryOutput, DescribeStreamSummaryError> {
+        (**self).describe_stream_summary(input)
+    }
+    fn disable_enhanced_monitoring(
+        &self,
+        input: DisableEnhancedMonitoringInput,
+    ) -> RusotoFuture<EnhancedMonitoringOutput, DisableEnhancedMonitoringError> {
+        (**self).disable_enhanced_monitoring(input)
+    }
+    fn enable_enhanced_monitoring(
+        &self,
+        input: EnableEnhancedMonitoringInput,
+    ) -> RusotoFuture<EnhancedMonitoringOutput, EnableEnhancedMonitoringError> {
+        (**self).enable_enhanced_monitoring(input)
+    }
+    fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> RusotoFuture<GetRecordsOutput, GetRecordsError> {
+        (**self).get_records(input)
+    }
+    fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> RusotoFuture<GetShardIteratorOutput, GetShardIteratorError> {
+        (**self).get_shard_iterator(input)
+    }
+    fn increase_stream_retention_period(
+        &self,
+        input: IncreaseStreamRetentionPeriodInput,
+    ) -> RusotoFuture<(), IncreaseStreamRetentionPeriodError> {
+        (**self).increase_stream_retention_period(input)
+    }
+    fn list_shards(
+        &self,
+        input: ListShardsInput,
+    ) -> RusotoFuture<ListShardsOutput, ListShardsError> {
+        (**self).list_shards(input)
+    }
+    fn list_stream_consumers(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> RusotoFuture<ListStreamConsumersOutput, ListStreamConsumersError> {
+        (**self).list_stream_consumers(input)
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        (**self).list_streams(input)
+    }
+    fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> RusotoFuture<ListTagsForStreamOutput, ListTagsForStreamError> {
+        (**self).list_tags_for_stream(input)
+    }
+    fn merge_shards(&self, input: MergeShardsInput) -> RusotoFuture<(), MergeShardsError> {
+        (**self).merge_shards(input)
+    }
+    fn put_record(&self, input: PutRecordInput) -> RusotoFuture<PutRecordOutput, PutRecordError> {
+        (**self).put_record(input)
+    }
+    fn put_records(
+        &self,
+        input: PutRecordsInput,
+    ) -> RusotoFuture<PutRecordsOutput, PutRecordsError> {
+        (**self).put_records(input)
+    }
+    fn register_stream_consumer(
+        &self,
+        input: RegisterStreamConsumerInput,
+    ) -> RusotoFuture<RegisterStreamConsumerOutput, RegisterStreamConsumerError> {
+        (**self).register_stream_consumer(input)
+    }
+    fn remove_tags_from_stream(
+        &self,
+        input: RemoveTagsFromStreamInput,
+    ) -> RusotoFuture<(), RemoveTagsFromStreamError> {
+        (**self).remove_tags_from_stream(input)
+    }
+    fn split_shard(&self, input: SplitShardInput) -> RusotoFuture<(), SplitShardError> {
+        (**self).split_shard(input)
+    }
+    fn start_stream_encryption(
+        &self,
+        input: StartStreamEncryptionInput,
+    ) -> RusotoFuture<(), StartStreamEncryptionError> {
+        (**self).start_stream_encryption(input)
+    }
+    fn stop_stream_encryption(
+        &self,
+        input: StopStreamEncryptionInput,
+    ) -> RusotoFuture<(), StopStreamEncryptionError> {
+        (**self).stop_stream_encryption(input)
+    }
+    fn subscribe_to_shard(
+        &self,
+        input: SubscribeToShardInput,
+    ) -> RusotoFuture<SubscribeToShardOutput, SubscribeToShardError> {
+        (**self).subscribe_to_shard(input)
+    }
+    fn update_shard_count(
+        &self,
+        input: UpdateShardCountInput,
+    ) -> RusotoFuture<UpdateShardCountOutput, UpdateShardCountError> {
+        (**self).update_shard_count(input)
+    }
+}
+/// The error codes Kinesis retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["LimitExceededException"],
+        transient: &[],
+    };
+
 /// A client for the Kinesis API.
 #[derive(Clone)]
 pub struct KinesisClient {
@@ -2771,6 +4668,27 @@ impl KinesisClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_KINESIS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> KinesisClient {
+        Self::new(region::Region::from_env_for_service("KINESIS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `kinesis`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> KinesisClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("kinesis", "KINESIS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2789,7 +4707,49 @@ impl KinesisClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisClient {
-        KinesisClient { client, region }
+        KinesisClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "kinesis")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "kinesis", "kinesis", "KINESIS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Kinesis` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Kinesis + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for KinesisClient {
+    fn service_name(&self) -> &'static str {
+        "Kinesis"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "kinesis"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> KinesisClient {
+        Self::new_with_client(client, region)
     }
 }
 
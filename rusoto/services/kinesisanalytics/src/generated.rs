@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AddApplicationCloudWatchLoggingOptionRequest {
@@ -36,6 +37,64 @@ pub struct AddApplicationCloudWatchLoggingOptionRequest {
     pub current_application_version_id: i64,
 }
 
+impl AddApplicationCloudWatchLoggingOptionRequest {
+    /// Returns a builder for `AddApplicationCloudWatchLoggingOptionRequest`, checking its required members when built.
+    pub fn builder() -> AddApplicationCloudWatchLoggingOptionRequestBuilder {
+        AddApplicationCloudWatchLoggingOptionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddApplicationCloudWatchLoggingOptionRequest`](struct.AddApplicationCloudWatchLoggingOptionRequest.html).
+#[derive(Default, Debug)]
+pub struct AddApplicationCloudWatchLoggingOptionRequestBuilder {
+    application_name: Option<String>,
+    cloud_watch_logging_option: Option<CloudWatchLoggingOption>,
+    current_application_version_id: Option<i64>,
+}
+
+impl AddApplicationCloudWatchLoggingOptionRequestBuilder {
+    /// Sets the [`application_name`](struct.AddApplicationCloudWatchLoggingOptionRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cloud_watch_logging_option`](struct.AddApplicationCloudWatchLoggingOptionRequest.html#structfield.cloud_watch_logging_option) member.
+    pub fn cloud_watch_logging_option<T: Into<CloudWatchLoggingOption>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cloud_watch_logging_option = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_application_version_id`](struct.AddApplicationCloudWatchLoggingOptionRequest.html#structfield.current_application_version_id) member.
+    pub fn current_application_version_id<T: Into<i64>>(mut self, value: T) -> Self {
+        self.current_application_version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddApplicationCloudWatchLoggingOptionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        AddApplicationCloudWatchLoggingOptionRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(AddApplicationCloudWatchLoggingOptionRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            cloud_watch_logging_option: self.cloud_watch_logging_option.ok_or(
+                ::rusoto_core::BuildError::missing("cloud_watch_logging_option"),
+            )?,
+            current_application_version_id: self.current_application_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("current_application_version_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddApplicationCloudWatchLoggingOptionResponse {}
@@ -56,12 +115,80 @@ pub struct AddApplicationInputProcessingConfigurationRequest {
     pub input_processing_configuration: InputProcessingConfiguration,
 }
 
+impl AddApplicationInputProcessingConfigurationRequest {
+    /// Returns a builder for `AddApplicationInputProcessingConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> AddApplicationInputProcessingConfigurationRequestBuilder {
+        AddApplicationInputProcessingConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddApplicationInputProcessingConfigurationRequest`](struct.AddApplicationInputProcessingConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct AddApplicationInputProcessingConfigurationRequestBuilder {
+    application_name: Option<String>,
+    current_application_version_id: Option<i64>,
+    input_id: Option<String>,
+    input_processing_configuration: Option<InputProcessingConfiguration>,
+}
+
+impl AddApplicationInputProcessingConfigurationRequestBuilder {
+    /// Sets the [`application_name`](struct.AddApplicationInputProcessingConfigurationRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_application_version_id`](struct.AddApplicationInputProcessingConfigurationRequest.html#structfield.current_application_version_id) member.
+    pub fn current_application_version_id<T: Into<i64>>(mut self, value: T) -> Self {
+        self.current_application_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_id`](struct.AddApplicationInputProcessingConfigurationRequest.html#structfield.input_id) member.
+    pub fn input_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.input_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_processing_configuration`](struct.AddApplicationInputProcessingConfigurationRequest.html#structfield.input_processing_configuration) member.
+    pub fn input_processing_configuration<T: Into<InputProcessingConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.input_processing_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddApplicationInputProcessingConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        AddApplicationInputProcessingConfigurationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(AddApplicationInputProcessingConfigurationRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            current_application_version_id: self.current_application_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("current_application_version_id"),
+            )?,
+            input_id: self
+                .input_id
+                .ok_or(::rusoto_core::BuildError::missing("input_id"))?,
+            input_processing_configuration: self.input_processing_configuration.ok_or(
+                ::rusoto_core::BuildError::missing("input_processing_configuration"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddApplicationInputProcessingConfigurationResponse {}
 
 /// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AddApplicationInputRequest {
     /// <p>Name of your existing Amazon Kinesis Analytics application to which you want to add the streaming source.</p>
     #[serde(rename = "ApplicationName")]
@@ -74,13 +201,65 @@ pub struct AddApplicationInputRequest {
     pub input: Input,
 }
 
+impl AddApplicationInputRequest {
+    /// Returns a builder for `AddApplicationInputRequest`, checking its required members when built.
+    pub fn builder() -> AddApplicationInputRequestBuilder {
+        AddApplicationInputRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddApplicationInputRequest`](struct.AddApplicationInputRequest.html).
+#[derive(Default, Debug)]
+pub struct AddApplicationInputRequestBuilder {
+    application_name: Option<String>,
+    current_application_version_id: Option<i64>,
+    input: Option<Input>,
+}
+
+impl AddApplicationInputRequestBuilder {
+    /// Sets the [`application_name`](struct.AddApplicationInputRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_application_version_id`](struct.AddApplicationInputRequest.html#structfield.current_application_version_id) member.
+    pub fn current_application_version_id<T: Into<i64>>(mut self, value: T) -> Self {
+        self.current_application_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input`](struct.AddApplicationInputRequest.html#structfield.input) member.
+    pub fn input<T: Into<Input>>(mut self, value: T) -> Self {
+        self.input = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddApplicationInputRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddApplicationInputRequest, ::rusoto_core::BuildError> {
+        Ok(AddApplicationInputRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            current_application_version_id: self.current_application_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("current_application_version_id"),
+            )?,
+            input: self
+                .input
+                .ok_or(::rusoto_core::BuildError::missing("input"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddApplicationInputResponse {}
 
 /// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AddApplicationOutputRequest {
     /// <p>Name of the application to which you want to add the output configuration.</p>
     #[serde(rename = "ApplicationName")]
@@ -93,13 +272,65 @@ pub struct AddApplicationOutputRequest {
     pub output: Output,
 }
 
+impl AddApplicationOutputRequest {
+    /// Returns a builder for `AddApplicationOutputRequest`, checking its required members when built.
+    pub fn builder() -> AddApplicationOutputRequestBuilder {
+        AddApplicationOutputRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddApplicationOutputRequest`](struct.AddApplicationOutputRequest.html).
+#[derive(Default, Debug)]
+pub struct AddApplicationOutputRequestBuilder {
+    application_name: Option<String>,
+    current_application_version_id: Option<i64>,
+    output: Option<Output>,
+}
+
+impl AddApplicationOutputRequestBuilder {
+    /// Sets the [`application_name`](struct.AddApplicationOutputRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_application_version_id`](struct.AddApplicationOutputRequest.html#structfield.current_application_version_id) member.
+    pub fn current_application_version_id<T: Into<i64>>(mut self, value: T) -> Self {
+        self.current_application_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output`](struct.AddApplicationOutputRequest.html#structfield.output) member.
+    pub fn output<T: Into<Output>>(mut self, value: T) -> Self {
+        self.output = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddApplicationOutputRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddApplicationOutputRequest, ::rusoto_core::BuildError> {
+        Ok(AddApplicationOutputRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            current_application_version_id: self.current_application_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("current_application_version_id"),
+            )?,
+            output: self
+                .output
+                .ok_or(::rusoto_core::BuildError::missing("output"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddApplicationOutputResponse {}
 
 /// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AddApplicationReferenceDataSourceRequest {
     /// <p>Name of an existing application.</p>
     #[serde(rename = "ApplicationName")]
@@ -112,13 +343,66 @@ pub struct AddApplicationReferenceDataSourceRequest {
     pub reference_data_source: ReferenceDataSource,
 }
 
+impl AddApplicationReferenceDataSourceRequest {
+    /// Returns a builder for `AddApplicationReferenceDataSourceRequest`, checking its required members when built.
+    pub fn builder() -> AddApplicationReferenceDataSourceRequestBuilder {
+        AddApplicationReferenceDataSourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddApplicationReferenceDataSourceRequest`](struct.AddApplicationReferenceDataSourceRequest.html).
+#[derive(Default, Debug)]
+pub struct AddApplicationReferenceDataSourceRequestBuilder {
+    application_name: Option<String>,
+    current_application_version_id: Option<i64>,
+    reference_data_source: Option<ReferenceDataSource>,
+}
+
+impl AddApplicationReferenceDataSourceRequestBuilder {
+    /// Sets the [`application_name`](struct.AddApplicationReferenceDataSourceRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_application_version_id`](struct.AddApplicationReferenceDataSourceRequest.html#structfield.current_application_version_id) member.
+    pub fn current_application_version_id<T: Into<i64>>(mut self, value: T) -> Self {
+        self.current_application_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reference_data_source`](struct.AddApplicationReferenceDataSourceRequest.html#structfield.reference_data_source) member.
+    pub fn reference_data_source<T: Into<ReferenceDataSource>>(mut self, value: T) -> Self {
+        self.reference_data_source = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddApplicationReferenceDataSourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddApplicationReferenceDataSourceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AddApplicationReferenceDataSourceRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            current_application_version_id: self.current_application_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("current_application_version_id"),
+            )?,
+            reference_data_source: self
+                .reference_data_source
+                .ok_or(::rusoto_core::BuildError::missing("reference_data_source"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddApplicationReferenceDataSourceResponse {}
 
 /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Provides a description of the application, including the application Amazon Resource Name (ARN), status, latest version, and input and output configuration.</p></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ApplicationDetail {
     /// <p>ARN of the application.</p>
@@ -137,7 +421,7 @@ pub struct ApplicationDetail {
     pub application_name: String,
     /// <p>Status of the application.</p>
     #[serde(rename = "ApplicationStatus")]
-    pub application_status: String,
+    pub application_status: ApplicationStatus,
     /// <p>Provides the current application version.</p>
     #[serde(rename = "ApplicationVersionId")]
     pub application_version_id: i64,
@@ -147,16 +431,18 @@ pub struct ApplicationDetail {
     pub cloud_watch_logging_option_descriptions: Option<Vec<CloudWatchLoggingOptionDescription>>,
     /// <p>Time stamp when the application version was created.</p>
     #[serde(rename = "CreateTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_timestamp: Option<f64>,
+    pub create_timestamp: Option<EpochTimestamp>,
     /// <p>Describes the application input configuration. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. </p>
     #[serde(rename = "InputDescriptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_descriptions: Option<Vec<InputDescription>>,
     /// <p>Time stamp when the application was last updated.</p>
     #[serde(rename = "LastUpdateTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_timestamp: Option<f64>,
+    pub last_update_timestamp: Option<EpochTimestamp>,
     /// <p>Describes the application output configuration. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-output.html">Configuring Application Output</a>. </p>
     #[serde(rename = "OutputDescriptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -167,8 +453,96 @@ pub struct ApplicationDetail {
     pub reference_data_source_descriptions: Option<Vec<ReferenceDataSourceDescription>>,
 }
 
+/// <p>Status of the application.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApplicationStatus {
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>STARTING</code></p>
+    Starting,
+    /// <p><code>STOPPING</code></p>
+    Stopping,
+    /// <p><code>READY</code></p>
+    Ready,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ApplicationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplicationStatus::Deleting => "DELETING",
+            ApplicationStatus::Starting => "STARTING",
+            ApplicationStatus::Stopping => "STOPPING",
+            ApplicationStatus::Ready => "READY",
+            ApplicationStatus::Running => "RUNNING",
+            ApplicationStatus::Updating => "UPDATING",
+            ApplicationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ApplicationStatus {
+    fn from(value: &'a str) -> ApplicationStatus {
+        match value {
+            "DELETING" => ApplicationStatus::Deleting,
+            "STARTING" => ApplicationStatus::Starting,
+            "STOPPING" => ApplicationStatus::Stopping,
+            "READY" => ApplicationStatus::Ready,
+            "RUNNING" => ApplicationStatus::Running,
+            "UPDATING" => ApplicationStatus::Updating,
+            _ => ApplicationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplicationStatus {
+    fn from(value: String) -> ApplicationStatus {
+        ApplicationStatus::from(value.as_str())
+    }
+}
+
+impl From<ApplicationStatus> for String {
+    fn from(value: ApplicationStatus) -> String {
+        match value {
+            ApplicationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ApplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ApplicationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApplicationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ApplicationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ApplicationStatus::from(value))
+    }
+}
+
 /// <p><note> <p>This documentation is for version 1 of the Amazon Kinesis Data Analytics API, which only supports SQL applications. Version 2 of the API supports SQL and Java applications. For more information about version 2, see <a href="/kinesisanalytics/latest/apiv2/Welcome.html">Amazon Kinesis Data Analytics API V2 Documentation</a>.</p> </note> <p>Provides application summary information, including the application Amazon Resource Name (ARN), name, and status.</p></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ApplicationSummary {
     /// <p>ARN of the application.</p>
@@ -179,7 +553,7 @@ pub struct ApplicationSummary {
     pub application_name: String,
     /// <p>Status of the application.</p>
     #[serde(rename = "ApplicationStatus")]
-    pub application_status: String,
+    pub application_status: ApplicationStatus,
 }
 
 /// <p>Describes updates to apply to an existing Amazon Kinesis Analytics application.</p>
@@ -293,8 +667,91 @@ pub struct CreateApplicationRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateApplicationRequest {
+    /// Returns a builder for `CreateApplicationRequest`, checking its required members when built.
+    pub fn builder() -> CreateApplicationRequestBuilder {
+        CreateApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateApplicationRequest`](struct.CreateApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateApplicationRequestBuilder {
+    application_code: Option<String>,
+    application_description: Option<String>,
+    application_name: Option<String>,
+    cloud_watch_logging_options: Option<Vec<CloudWatchLoggingOption>>,
+    inputs: Option<Vec<Input>>,
+    outputs: Option<Vec<Output>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateApplicationRequestBuilder {
+    /// Sets the [`application_code`](struct.CreateApplicationRequest.html#structfield.application_code) member.
+    pub fn application_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`application_description`](struct.CreateApplicationRequest.html#structfield.application_description) member.
+    pub fn application_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`application_name`](struct.CreateApplicationRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cloud_watch_logging_options`](struct.CreateApplicationRequest.html#structfield.cloud_watch_logging_options) member.
+    pub fn cloud_watch_logging_options<T: Into<Vec<CloudWatchLoggingOption>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cloud_watch_logging_options = Some(value.into());
+        self
+    }
+
+    /// Sets the [`inputs`](struct.CreateApplicationRequest.html#structfield.inputs) member.
+    pub fn inputs<T: Into<Vec<Input>>>(mut self, value: T) -> Self {
+        self.inputs = Some(value.into());
+        self
+    }
+
+    /// Sets the [`outputs`](struct.CreateApplicationRequest.html#structfield.outputs) member.
+    pub fn outputs<T: Into<Vec<Output>>>(mut self, value: T) -> Self {
+        self.outputs = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateApplicationRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateApplicationRequest {
+            application_code: self.application_code,
+            application_description: self.application_description,
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            cloud_watch_logging_options: self.cloud_watch_logging_options,
+            inputs: self.inputs,
+            outputs: self.outputs,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p>TBD</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateApplicationResponse {
     /// <p>In response to your <code>CreateApplication</code> request, Amazon Kinesis Analytics returns a response with a summary of the application it created, including the application Amazon Resource Name (ARN), name, and status.</p>
@@ -315,6 +772,61 @@ pub struct DeleteApplicationCloudWatchLoggingOptionRequest {
     pub current_application_version_id: i64,
 }
 
+impl DeleteApplicationCloudWatchLoggingOptionRequest {
+    /// Returns a builder for `DeleteApplicationCloudWatchLoggingOptionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationCloudWatchLoggingOptionRequestBuilder {
+        DeleteApplicationCloudWatchLoggingOptionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationCloudWatchLoggingOptionRequest`](struct.DeleteApplicationCloudWatchLoggingOptionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationCloudWatchLoggingOptionRequestBuilder {
+    application_name: Option<String>,
+    cloud_watch_logging_option_id: Option<String>,
+    current_application_version_id: Option<i64>,
+}
+
+impl DeleteApplicationCloudWatchLoggingOptionRequestBuilder {
+    /// Sets the [`application_name`](struct.DeleteApplicationCloudWatchLoggingOptionRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cloud_watch_logging_option_id`](struct.DeleteApplicationCloudWatchLoggingOptionRequest.html#structfield.cloud_watch_logging_option_id) member.
+    pub fn cloud_watch_logging_option_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cloud_watch_logging_option_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_application_version_id`](struct.DeleteApplicationCloudWatchLoggingOptionRequest.html#structfield.current_application_version_id) member.
+    pub fn current_application_version_id<T: Into<i64>>(mut self, value: T) -> Self {
+        self.current_application_version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationCloudWatchLoggingOptionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DeleteApplicationCloudWatchLoggingOptionRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DeleteApplicationCloudWatchLoggingOptionRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            cloud_watch_logging_option_id: self.cloud_watch_logging_option_id.ok_or(
+                ::rusoto_core::BuildError::missing("cloud_watch_logging_option_id"),
+            )?,
+            current_application_version_id: self.current_application_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("current_application_version_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteApplicationCloudWatchLoggingOptionResponse {}
@@ -332,6 +844,61 @@ pub struct DeleteApplicationInputProcessingConfigurationRequest {
     pub input_id: String,
 }
 
+impl DeleteApplicationInputProcessingConfigurationRequest {
+    /// Returns a builder for `DeleteApplicationInputProcessingConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationInputProcessingConfigurationRequestBuilder {
+        DeleteApplicationInputProcessingConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationInputProcessingConfigurationRequest`](struct.DeleteApplicationInputProcessingConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationInputProcessingConfigurationRequestBuilder {
+    application_name: Option<String>,
+    current_application_version_id: Option<i64>,
+    input_id: Option<String>,
+}
+
+impl DeleteApplicationInputProcessingConfigurationRequestBuilder {
+    /// Sets the [`application_name`](struct.DeleteApplicationInputProcessingConfigurationRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_application_version_id`](struct.DeleteApplicationInputProcessingConfigurationRequest.html#structfield.current_application_version_id) member.
+    pub fn current_application_version_id<T: Into<i64>>(mut self, value: T) -> Self {
+        self.current_application_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_id`](struct.DeleteApplicationInputProcessingConfigurationRequest.html#structfield.input_id) member.
+    pub fn input_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.input_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationInputProcessingConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DeleteApplicationInputProcessingConfigurationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DeleteApplicationInputProcessingConfigurationRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            current_application_version_id: self.current_application_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("current_application_version_id"),
+            )?,
+            input_id: self
+                .input_id
+                .ok_or(::rusoto_core::BuildError::missing("input_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteApplicationInputProcessingConfigurationResponse {}
@@ -350,6 +917,58 @@ pub struct DeleteApplicationOutputRequest {
     pub output_id: String,
 }
 
+impl DeleteApplicationOutputRequest {
+    /// Returns a builder for `DeleteApplicationOutputRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationOutputRequestBuilder {
+        DeleteApplicationOutputRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationOutputRequest`](struct.DeleteApplicationOutputRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationOutputRequestBuilder {
+    application_name: Option<String>,
+    current_application_version_id: Option<i64>,
+    output_id: Option<String>,
+}
+
+impl DeleteApplicationOutputRequestBuilder {
+    /// Sets the [`application_name`](struct.DeleteApplicationOutputRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_application_version_id`](struct.DeleteApplicationOutputRequest.html#structfield.current_application_version_id) member.
+    pub fn current_application_version_id<T: Into<i64>>(mut self, value: T) -> Self {
+        self.current_application_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_id`](struct.DeleteApplicationOutputRequest.html#structfield.output_id) member.
+    pub fn output_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.output_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationOutputRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteApplicationOutputRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteApplicationOutputRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            current_application_version_id: self.current_application_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("current_application_version_id"),
+            )?,
+            output_id: self
+                .output_id
+                .ok_or(::rusoto_core::BuildError::missing("output_id"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -368,6 +987,59 @@ pub struct DeleteApplicationReferenceDataSourceRequest {
     pub reference_id: String,
 }
 
+impl DeleteApplicationReferenceDataSourceRequest {
+    /// Returns a builder for `DeleteApplicationReferenceDataSourceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationReferenceDataSourceRequestBuilder {
+        DeleteApplicationReferenceDataSourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationReferenceDataSourceRequest`](struct.DeleteApplicationReferenceDataSourceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationReferenceDataSourceRequestBuilder {
+    application_name: Option<String>,
+    current_application_version_id: Option<i64>,
+    reference_id: Option<String>,
+}
+
+impl DeleteApplicationReferenceDataSourceRequestBuilder {
+    /// Sets the [`application_name`](struct.DeleteApplicationReferenceDataSourceRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_application_version_id`](struct.DeleteApplicationReferenceDataSourceRequest.html#structfield.current_application_version_id) member.
+    pub fn current_application_version_id<T: Into<i64>>(mut self, value: T) -> Self {
+        self.current_application_version_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reference_id`](struct.DeleteApplicationReferenceDataSourceRequest.html#structfield.reference_id) member.
+    pub fn reference_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reference_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationReferenceDataSourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteApplicationReferenceDataSourceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteApplicationReferenceDataSourceRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            current_application_version_id: self.current_application_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("current_application_version_id"),
+            )?,
+            reference_id: self
+                .reference_id
+                .ok_or(::rusoto_core::BuildError::missing("reference_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteApplicationReferenceDataSourceResponse {}
@@ -380,7 +1052,50 @@ pub struct DeleteApplicationRequest {
     pub application_name: String,
     /// <p> You can use the <code>DescribeApplication</code> operation to get this value. </p>
     #[serde(rename = "CreateTimestamp")]
-    pub create_timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub create_timestamp: EpochTimestamp,
+}
+
+impl DeleteApplicationRequest {
+    /// Returns a builder for `DeleteApplicationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteApplicationRequestBuilder {
+        DeleteApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteApplicationRequest`](struct.DeleteApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteApplicationRequestBuilder {
+    application_name: Option<String>,
+    create_timestamp: Option<EpochTimestamp>,
+}
+
+impl DeleteApplicationRequestBuilder {
+    /// Sets the [`application_name`](struct.DeleteApplicationRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`create_timestamp`](struct.DeleteApplicationRequest.html#structfield.create_timestamp) member.
+    pub fn create_timestamp<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.create_timestamp = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteApplicationRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            create_timestamp: self
+                .create_timestamp
+                .ok_or(::rusoto_core::BuildError::missing("create_timestamp"))?,
+        })
+    }
 }
 
 /// <p><p/></p>
@@ -396,8 +1111,40 @@ pub struct DescribeApplicationRequest {
     pub application_name: String,
 }
 
+impl DescribeApplicationRequest {
+    /// Returns a builder for `DescribeApplicationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeApplicationRequestBuilder {
+        DescribeApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeApplicationRequest`](struct.DescribeApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeApplicationRequestBuilder {
+    application_name: Option<String>,
+}
+
+impl DescribeApplicationRequestBuilder {
+    /// Sets the [`application_name`](struct.DescribeApplicationRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeApplicationRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeApplicationResponse {
     /// <p>Provides a description of the application, such as the application Amazon Resource Name (ARN), status, latest version, and input and output configuration details.</p>
@@ -406,11 +1153,11 @@ pub struct DescribeApplicationResponse {
 }
 
 /// <p>Describes the data format when records are written to the destination. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-output.html">Configuring Application Output</a>. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DestinationSchema {
     /// <p>Specifies the format of the records on the output stream.</p>
     #[serde(rename = "RecordFormatType")]
-    pub record_format_type: String,
+    pub record_format_type: RecordFormatType,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -437,6 +1184,74 @@ pub struct DiscoverInputSchemaRequest {
     pub s3_configuration: Option<S3Configuration>,
 }
 
+impl DiscoverInputSchemaRequest {
+    /// Returns a builder for `DiscoverInputSchemaRequest`, checking its required members when built.
+    pub fn builder() -> DiscoverInputSchemaRequestBuilder {
+        DiscoverInputSchemaRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DiscoverInputSchemaRequest`](struct.DiscoverInputSchemaRequest.html).
+#[derive(Default, Debug)]
+pub struct DiscoverInputSchemaRequestBuilder {
+    input_processing_configuration: Option<InputProcessingConfiguration>,
+    input_starting_position_configuration: Option<InputStartingPositionConfiguration>,
+    resource_arn: Option<String>,
+    role_arn: Option<String>,
+    s3_configuration: Option<S3Configuration>,
+}
+
+impl DiscoverInputSchemaRequestBuilder {
+    /// Sets the [`input_processing_configuration`](struct.DiscoverInputSchemaRequest.html#structfield.input_processing_configuration) member.
+    pub fn input_processing_configuration<T: Into<InputProcessingConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.input_processing_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_starting_position_configuration`](struct.DiscoverInputSchemaRequest.html#structfield.input_starting_position_configuration) member.
+    pub fn input_starting_position_configuration<T: Into<InputStartingPositionConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.input_starting_position_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.DiscoverInputSchemaRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.DiscoverInputSchemaRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_configuration`](struct.DiscoverInputSchemaRequest.html#structfield.s3_configuration) member.
+    pub fn s3_configuration<T: Into<S3Configuration>>(mut self, value: T) -> Self {
+        self.s3_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `DiscoverInputSchemaRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DiscoverInputSchemaRequest, ::rusoto_core::BuildError> {
+        Ok(DiscoverInputSchemaRequest {
+            input_processing_configuration: self.input_processing_configuration,
+            input_starting_position_configuration: self.input_starting_position_configuration,
+            resource_arn: self.resource_arn,
+            role_arn: self.role_arn,
+            s3_configuration: self.s3_configuration,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -460,7 +1275,7 @@ pub struct DiscoverInputSchemaResponse {
 }
 
 /// <p>When you configure the application input, you specify the streaming source, the in-application stream name that is created, and the mapping between the two. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Input {
     /// <p>Describes the number of in-application streams to create. </p> <p>Data from your source is routed to these in-application input streams.</p> <p> (see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-input.html">Configuring Application Input</a>.</p>
     #[serde(rename = "InputParallelism")]
@@ -638,13 +1453,89 @@ pub struct InputSchemaUpdate {
     pub record_format_update: Option<RecordFormat>,
 }
 
+/// <p><p>The starting position on the stream.</p> <ul> <li> <p> <code>NOW</code> - Start reading just after the most recent record in the stream, start at the request time stamp that the customer issued.</p> </li> <li> <p> <code>TRIM<em>HORIZON</code> - Start reading at the last untrimmed record in the stream, which is the oldest record available in the stream. This option is not available for an Amazon Kinesis Firehose delivery stream.</p> </li> <li> <p> <code>LAST</em>STOPPED_POINT</code> - Resume reading from where the application last stopped reading.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InputStartingPosition {
+    /// <p><code>NOW</code> - Start reading just after the most recent record in the stream, start at the request time stamp that the customer issued.</p>
+    Now,
+    /// <p><code>TRIM_HORIZON</code></p>
+    TrimHorizon,
+    /// <p><code>LAST_STOPPED_POINT</code></p>
+    LastStoppedPoint,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InputStartingPosition {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InputStartingPosition::Now => "NOW",
+            InputStartingPosition::TrimHorizon => "TRIM_HORIZON",
+            InputStartingPosition::LastStoppedPoint => "LAST_STOPPED_POINT",
+            InputStartingPosition::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InputStartingPosition {
+    fn from(value: &'a str) -> InputStartingPosition {
+        match value {
+            "NOW" => InputStartingPosition::Now,
+            "TRIM_HORIZON" => InputStartingPosition::TrimHorizon,
+            "LAST_STOPPED_POINT" => InputStartingPosition::LastStoppedPoint,
+            _ => InputStartingPosition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InputStartingPosition {
+    fn from(value: String) -> InputStartingPosition {
+        InputStartingPosition::from(value.as_str())
+    }
+}
+
+impl From<InputStartingPosition> for String {
+    fn from(value: InputStartingPosition) -> String {
+        match value {
+            InputStartingPosition::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InputStartingPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InputStartingPosition {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InputStartingPosition {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InputStartingPosition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InputStartingPosition::from(value))
+    }
+}
+
 /// <p>Describes the point at which the application reads from the streaming source.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InputStartingPositionConfiguration {
     /// <p><p>The starting position on the stream.</p> <ul> <li> <p> <code>NOW</code> - Start reading just after the most recent record in the stream, start at the request time stamp that the customer issued.</p> </li> <li> <p> <code>TRIM<em>HORIZON</code> - Start reading at the last untrimmed record in the stream, which is the oldest record available in the stream. This option is not available for an Amazon Kinesis Firehose delivery stream.</p> </li> <li> <p> <code>LAST</em>STOPPED_POINT</code> - Resume reading from where the application last stopped reading.</p> </li> </ul></p>
     #[serde(rename = "InputStartingPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub input_starting_position: Option<String>,
+    pub input_starting_position: Option<InputStartingPosition>,
 }
 
 /// <p>Describes updates to a specific input configuration (identified by the <code>InputId</code> of an application). </p>
@@ -890,6 +1781,44 @@ pub struct ListApplicationsRequest {
     pub limit: Option<i64>,
 }
 
+impl ListApplicationsRequest {
+    /// Returns a builder for `ListApplicationsRequest`, checking its required members when built.
+    pub fn builder() -> ListApplicationsRequestBuilder {
+        ListApplicationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListApplicationsRequest`](struct.ListApplicationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListApplicationsRequestBuilder {
+    exclusive_start_application_name: Option<String>,
+    limit: Option<i64>,
+}
+
+impl ListApplicationsRequestBuilder {
+    /// Sets the [`exclusive_start_application_name`](struct.ListApplicationsRequest.html#structfield.exclusive_start_application_name) member.
+    pub fn exclusive_start_application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.exclusive_start_application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListApplicationsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListApplicationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListApplicationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListApplicationsRequest {
+            exclusive_start_application_name: self.exclusive_start_application_name,
+            limit: self.limit,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -902,11 +1831,43 @@ pub struct ListApplicationsResponse {
     pub has_more_applications: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ListTagsForResourceRequest {
-    /// <p>The ARN of the application for which to retrieve tags.</p>
-    #[serde(rename = "ResourceARN")]
-    pub resource_arn: String,
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListTagsForResourceRequest {
+    /// <p>The ARN of the application for which to retrieve tags.</p>
+    #[serde(rename = "ResourceARN")]
+    pub resource_arn: String,
+}
+
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -932,7 +1893,7 @@ pub struct MappingParameters {
 }
 
 /// <p> Describes application output configuration in which you identify an in-application stream and a destination where you want the in-application stream data to be written. The destination can be an Amazon Kinesis stream or an Amazon Kinesis Firehose delivery stream. </p> <p/> <p>For limits on how many destinations an application can write and other limitations, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/limits.html">Limits</a>. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Output {
     /// <p>Describes the data format when records are written to the destination. For more information, see <a href="https://docs.aws.amazon.com/kinesisanalytics/latest/dev/how-it-works-output.html">Configuring Application Output</a>.</p>
     #[serde(rename = "DestinationSchema")]
@@ -1028,7 +1989,7 @@ pub struct RecordColumn {
 }
 
 /// <p> Describes the record format and relevant mapping information that should be applied to schematize the records on the stream. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RecordFormat {
     /// <p>When configuring application input at the time of creating or updating an application, provides additional mapping information specific to the record format (such as JSON, CSV, or record fields delimited by some delimiter) on the streaming source.</p>
     #[serde(rename = "MappingParameters")]
@@ -1036,11 +1997,83 @@ pub struct RecordFormat {
     pub mapping_parameters: Option<MappingParameters>,
     /// <p>The type of record format.</p>
     #[serde(rename = "RecordFormatType")]
-    pub record_format_type: String,
+    pub record_format_type: RecordFormatType,
+}
+
+/// <p>Specifies the format of the records on the output stream.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordFormatType {
+    /// <p><code>JSON</code></p>
+    Json,
+    /// <p><code>CSV</code></p>
+    Csv,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RecordFormatType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordFormatType::Json => "JSON",
+            RecordFormatType::Csv => "CSV",
+            RecordFormatType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RecordFormatType {
+    fn from(value: &'a str) -> RecordFormatType {
+        match value {
+            "JSON" => RecordFormatType::Json,
+            "CSV" => RecordFormatType::Csv,
+            _ => RecordFormatType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordFormatType {
+    fn from(value: String) -> RecordFormatType {
+        RecordFormatType::from(value.as_str())
+    }
+}
+
+impl From<RecordFormatType> for String {
+    fn from(value: RecordFormatType) -> String {
+        match value {
+            RecordFormatType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RecordFormatType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RecordFormatType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordFormatType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RecordFormatType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RecordFormatType::from(value))
+    }
 }
 
 /// <p>Describes the reference data source by providing the source information (S3 bucket name and object key name), the resulting in-application table name that is created, and the necessary schema to map the data elements in the Amazon S3 object to the in-application table.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ReferenceDataSource {
     /// <p>Describes the format of the data in the streaming source, and how each data element maps to corresponding columns created in the in-application stream.</p>
     #[serde(rename = "ReferenceSchema")]
@@ -1154,7 +2187,7 @@ pub struct S3ReferenceDataSourceUpdate {
 }
 
 /// <p>Describes the format of the data in the streaming source, and how each data element maps to corresponding columns created in the in-application stream.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SourceSchema {
     /// <p>A list of <code>RecordColumn</code> objects.</p>
     #[serde(rename = "RecordColumns")]
@@ -1179,6 +2212,48 @@ pub struct StartApplicationRequest {
     pub input_configurations: Vec<InputConfiguration>,
 }
 
+impl StartApplicationRequest {
+    /// Returns a builder for `StartApplicationRequest`, checking its required members when built.
+    pub fn builder() -> StartApplicationRequestBuilder {
+        StartApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartApplicationRequest`](struct.StartApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct StartApplicationRequestBuilder {
+    application_name: Option<String>,
+    input_configurations: Option<Vec<InputConfiguration>>,
+}
+
+impl StartApplicationRequestBuilder {
+    /// Sets the [`application_name`](struct.StartApplicationRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_configurations`](struct.StartApplicationRequest.html#structfield.input_configurations) member.
+    pub fn input_configurations<T: Into<Vec<InputConfiguration>>>(mut self, value: T) -> Self {
+        self.input_configurations = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(StartApplicationRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            input_configurations: self
+                .input_configurations
+                .ok_or(::rusoto_core::BuildError::missing("input_configurations"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1192,6 +2267,36 @@ pub struct StopApplicationRequest {
     pub application_name: String,
 }
 
+impl StopApplicationRequest {
+    /// Returns a builder for `StopApplicationRequest`, checking its required members when built.
+    pub fn builder() -> StopApplicationRequestBuilder {
+        StopApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopApplicationRequest`](struct.StopApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct StopApplicationRequestBuilder {
+    application_name: Option<String>,
+}
+
+impl StopApplicationRequestBuilder {
+    /// Sets the [`application_name`](struct.StopApplicationRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(StopApplicationRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+        })
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1219,6 +2324,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -1233,6 +2378,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -1250,6 +2435,58 @@ pub struct UpdateApplicationRequest {
     pub current_application_version_id: i64,
 }
 
+impl UpdateApplicationRequest {
+    /// Returns a builder for `UpdateApplicationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateApplicationRequestBuilder {
+        UpdateApplicationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateApplicationRequest`](struct.UpdateApplicationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateApplicationRequestBuilder {
+    application_name: Option<String>,
+    application_update: Option<ApplicationUpdate>,
+    current_application_version_id: Option<i64>,
+}
+
+impl UpdateApplicationRequestBuilder {
+    /// Sets the [`application_name`](struct.UpdateApplicationRequest.html#structfield.application_name) member.
+    pub fn application_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`application_update`](struct.UpdateApplicationRequest.html#structfield.application_update) member.
+    pub fn application_update<T: Into<ApplicationUpdate>>(mut self, value: T) -> Self {
+        self.application_update = Some(value.into());
+        self
+    }
+
+    /// Sets the [`current_application_version_id`](struct.UpdateApplicationRequest.html#structfield.current_application_version_id) member.
+    pub fn current_application_version_id<T: Into<i64>>(mut self, value: T) -> Self {
+        self.current_application_version_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateApplicationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateApplicationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateApplicationRequest {
+            application_name: self
+                .application_name
+                .ok_or(::rusoto_core::BuildError::missing("application_name"))?,
+            application_update: self
+                .application_update
+                .ok_or(::rusoto_core::BuildError::missing("application_update"))?,
+            current_application_version_id: self.current_application_version_id.ok_or(
+                ::rusoto_core::BuildError::missing("current_application_version_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateApplicationResponse {}
@@ -2622,6 +3859,153 @@ pub trait KinesisAnalytics {
         input: UpdateApplicationRequest,
     ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError>;
 }
+impl<T: ?Sized + KinesisAnalytics> KinesisAnalytics for ::std::sync::Arc<T> {
+    fn add_application_cloud_watch_logging_option(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> RusotoFuture<
+        AddApplicationCloudWatchLoggingOptionResponse,
+        AddApplicationCloudWatchLoggingOptionError,
+    > {
+        (**self).add_application_cloud_watch_logging_option(input)
+    }
+    fn add_application_input(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> RusotoFuture<AddApplicationInputResponse, AddApplicationInputError> {
+        (**self).add_application_input(input)
+    }
+    fn add_application_input_processing_configuration(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> RusotoFuture<
+        AddApplicationInputProcessingConfigurationResponse,
+        AddApplicationInputProcessingConfigurationError,
+    > {
+        (**self).add_application_input_processing_configuration(input)
+    }
+    fn add_application_output(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> RusotoFuture<AddApplicationOutputResponse, AddApplicationOutputError> {
+        (**self).add_application_output(input)
+    }
+    fn add_application_reference_data_source(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> RusotoFuture<
+        AddApplicationReferenceDataSourceResponse,
+        AddApplicationReferenceDataSourceError,
+    > {
+        (**self).add_application_reference_data_source(input)
+    }
+    fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> RusotoFuture<CreateApplicationResponse, CreateApplicationError> {
+        (**self).create_application(input)
+    }
+    fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> RusotoFuture<DeleteApplicationResponse, DeleteApplicationError> {
+        (**self).delete_application(input)
+    }
+    fn delete_application_cloud_watch_logging_option(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationCloudWatchLoggingOptionResponse,
+        DeleteApplicationCloudWatchLoggingOptionError,
+    > {
+        (**self).delete_application_cloud_watch_logging_option(input)
+    }
+    fn delete_application_input_processing_configuration(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationInputProcessingConfigurationResponse,
+        DeleteApplicationInputProcessingConfigurationError,
+    > {
+        (**self).delete_application_input_processing_configuration(input)
+    }
+    fn delete_application_output(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> RusotoFuture<DeleteApplicationOutputResponse, DeleteApplicationOutputError> {
+        (**self).delete_application_output(input)
+    }
+    fn delete_application_reference_data_source(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> RusotoFuture<
+        DeleteApplicationReferenceDataSourceResponse,
+        DeleteApplicationReferenceDataSourceError,
+    > {
+        (**self).delete_application_reference_data_source(input)
+    }
+    fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> RusotoFuture<DescribeApplicationResponse, DescribeApplicationError> {
+        (**self).describe_application(input)
+    }
+    fn discover_input_schema(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> RusotoFuture<DiscoverInputSchemaResponse, DiscoverInputSchemaError> {
+        (**self).discover_input_schema(input)
+    }
+    fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> RusotoFuture<ListApplicationsResponse, ListApplicationsError> {
+        (**self).list_applications(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn start_application(
+        &self,
+        input: StartApplicationRequest,
+    ) -> RusotoFuture<StartApplicationResponse, StartApplicationError> {
+        (**self).start_application(input)
+    }
+    fn stop_application(
+        &self,
+        input: StopApplicationRequest,
+    ) -> RusotoFuture<StopApplicationResponse, StopApplicationError> {
+        (**self).stop_application(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> RusotoFuture<UpdateApplicationResponse, UpdateApplicationError> {
+        (**self).update_application(input)
+    }
+}
+/// The error codes Kinesis Analytics retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Kinesis Analytics API.
 #[derive(Clone)]
 pub struct KinesisAnalyticsClient {
@@ -2637,6 +4021,27 @@ impl KinesisAnalyticsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_KINESISANALYTICS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> KinesisAnalyticsClient {
+        Self::new(region::Region::from_env_for_service("KINESISANALYTICS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `kinesisanalytics`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> KinesisAnalyticsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("kinesisanalytics", "KINESISANALYTICS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2655,7 +4060,54 @@ impl KinesisAnalyticsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisAnalyticsClient {
-        KinesisAnalyticsClient { client, region }
+        KinesisAnalyticsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "kinesisanalytics")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "kinesisanalytics",
+            "kinesisanalytics",
+            "KINESISANALYTICS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `KinesisAnalytics` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn KinesisAnalytics + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for KinesisAnalyticsClient {
+    fn service_name(&self) -> &'static str {
+        "Kinesis Analytics"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "kinesisanalytics"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> KinesisAnalyticsClient {
+        Self::new_with_client(client, region)
     }
 }
 
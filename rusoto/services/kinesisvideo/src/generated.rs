@@ -22,7 +22,184 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The name of the API action for which to get an endpoint.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum APIName {
+    /// <p><code>PUT_MEDIA</code></p>
+    PutMedia,
+    /// <p><code>GET_MEDIA</code></p>
+    GetMedia,
+    /// <p><code>LIST_FRAGMENTS</code></p>
+    ListFragments,
+    /// <p><code>GET_MEDIA_FOR_FRAGMENT_LIST</code></p>
+    GetMediaForFragmentList,
+    /// <p><code>GET_HLS_STREAMING_SESSION_URL</code></p>
+    GetHlsStreamingSessionUrl,
+    /// <p><code>GET_DASH_STREAMING_SESSION_URL</code></p>
+    GetDashStreamingSessionUrl,
+    /// <p><code>GET_CLIP</code></p>
+    GetClip,
+    /// <p><code>GET_IMAGES</code></p>
+    GetImages,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl APIName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            APIName::PutMedia => "PUT_MEDIA",
+            APIName::GetMedia => "GET_MEDIA",
+            APIName::ListFragments => "LIST_FRAGMENTS",
+            APIName::GetMediaForFragmentList => "GET_MEDIA_FOR_FRAGMENT_LIST",
+            APIName::GetHlsStreamingSessionUrl => "GET_HLS_STREAMING_SESSION_URL",
+            APIName::GetDashStreamingSessionUrl => "GET_DASH_STREAMING_SESSION_URL",
+            APIName::GetClip => "GET_CLIP",
+            APIName::GetImages => "GET_IMAGES",
+            APIName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for APIName {
+    fn from(value: &'a str) -> APIName {
+        match value {
+            "PUT_MEDIA" => APIName::PutMedia,
+            "GET_MEDIA" => APIName::GetMedia,
+            "LIST_FRAGMENTS" => APIName::ListFragments,
+            "GET_MEDIA_FOR_FRAGMENT_LIST" => APIName::GetMediaForFragmentList,
+            "GET_HLS_STREAMING_SESSION_URL" => APIName::GetHlsStreamingSessionUrl,
+            "GET_DASH_STREAMING_SESSION_URL" => APIName::GetDashStreamingSessionUrl,
+            "GET_CLIP" => APIName::GetClip,
+            "GET_IMAGES" => APIName::GetImages,
+            _ => APIName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for APIName {
+    fn from(value: String) -> APIName {
+        APIName::from(value.as_str())
+    }
+}
+
+impl From<APIName> for String {
+    fn from(value: APIName) -> String {
+        match value {
+            APIName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for APIName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for APIName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for APIName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for APIName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<APIName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(APIName::from(value))
+    }
+}
+
+/// <p>A comparison operator. Currently, you can specify only the <code>BEGINS_WITH</code> operator, which finds streams whose names start with a given prefix.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComparisonOperator {
+    /// <p><code>BEGINS_WITH</code></p>
+    BeginsWith,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ComparisonOperator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ComparisonOperator::BeginsWith => "BEGINS_WITH",
+            ComparisonOperator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ComparisonOperator {
+    fn from(value: &'a str) -> ComparisonOperator {
+        match value {
+            "BEGINS_WITH" => ComparisonOperator::BeginsWith,
+            _ => ComparisonOperator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ComparisonOperator {
+    fn from(value: String) -> ComparisonOperator {
+        ComparisonOperator::from(value.as_str())
+    }
+}
+
+impl From<ComparisonOperator> for String {
+    fn from(value: ComparisonOperator) -> String {
+        match value {
+            ComparisonOperator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ComparisonOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ComparisonOperator {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ComparisonOperator {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ComparisonOperator {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ComparisonOperator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ComparisonOperator::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateStreamInput {
     /// <p>The number of hours that you want to retain the data in the stream. Kinesis Video Streams retains the data in a data store that is associated with the stream.</p> <p>The default value is 0, indicating that the stream does not persist data.</p> <p>When the <code>DataRetentionInHours</code> value is 0, consumers can still consume the fragments that remain in the service host buffer, which has a retention time limit of 5 minutes and a retention memory limit of 200 MB. Fragments are removed from the buffer when either limit is reached.</p>
@@ -50,6 +227,76 @@ pub struct CreateStreamInput {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateStreamInput {
+    /// Returns a builder for `CreateStreamInput`, checking its required members when built.
+    pub fn builder() -> CreateStreamInputBuilder {
+        CreateStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStreamInput`](struct.CreateStreamInput.html).
+#[derive(Default, Debug)]
+pub struct CreateStreamInputBuilder {
+    data_retention_in_hours: Option<i64>,
+    device_name: Option<String>,
+    kms_key_id: Option<String>,
+    media_type: Option<String>,
+    stream_name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateStreamInputBuilder {
+    /// Sets the [`data_retention_in_hours`](struct.CreateStreamInput.html#structfield.data_retention_in_hours) member.
+    pub fn data_retention_in_hours<T: Into<i64>>(mut self, value: T) -> Self {
+        self.data_retention_in_hours = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_name`](struct.CreateStreamInput.html#structfield.device_name) member.
+    pub fn device_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateStreamInput.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`media_type`](struct.CreateStreamInput.html#structfield.media_type) member.
+    pub fn media_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.media_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.CreateStreamInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateStreamInput.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateStreamInput, ::rusoto_core::BuildError> {
+        Ok(CreateStreamInput {
+            data_retention_in_hours: self.data_retention_in_hours,
+            device_name: self.device_name,
+            kms_key_id: self.kms_key_id,
+            media_type: self.media_type,
+            stream_name: self
+                .stream_name
+                .ok_or(::rusoto_core::BuildError::missing("stream_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateStreamOutput {
@@ -70,6 +317,44 @@ pub struct DeleteStreamInput {
     pub stream_arn: String,
 }
 
+impl DeleteStreamInput {
+    /// Returns a builder for `DeleteStreamInput`, checking its required members when built.
+    pub fn builder() -> DeleteStreamInputBuilder {
+        DeleteStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStreamInput`](struct.DeleteStreamInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteStreamInputBuilder {
+    current_version: Option<String>,
+    stream_arn: Option<String>,
+}
+
+impl DeleteStreamInputBuilder {
+    /// Sets the [`current_version`](struct.DeleteStreamInput.html#structfield.current_version) member.
+    pub fn current_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.current_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.DeleteStreamInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteStreamInput, ::rusoto_core::BuildError> {
+        Ok(DeleteStreamInput {
+            current_version: self.current_version,
+            stream_arn: self
+                .stream_arn
+                .ok_or(::rusoto_core::BuildError::missing("stream_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteStreamOutput {}
@@ -86,6 +371,42 @@ pub struct DescribeStreamInput {
     pub stream_name: Option<String>,
 }
 
+impl DescribeStreamInput {
+    /// Returns a builder for `DescribeStreamInput`, checking its required members when built.
+    pub fn builder() -> DescribeStreamInputBuilder {
+        DescribeStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStreamInput`](struct.DescribeStreamInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeStreamInputBuilder {
+    stream_arn: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl DescribeStreamInputBuilder {
+    /// Sets the [`stream_arn`](struct.DescribeStreamInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.DescribeStreamInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeStreamInput, ::rusoto_core::BuildError> {
+        Ok(DescribeStreamInput {
+            stream_arn: self.stream_arn,
+            stream_name: self.stream_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeStreamOutput {
@@ -95,11 +416,11 @@ pub struct DescribeStreamOutput {
     pub stream_info: Option<StreamInfo>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetDataEndpointInput {
     /// <p>The name of the API action for which to get an endpoint.</p>
     #[serde(rename = "APIName")]
-    pub api_name: String,
+    pub api_name: APIName,
     /// <p>The Amazon Resource Name (ARN) of the stream that you want to get the endpoint for. You must specify either this parameter or a <code>StreamName</code> in the request. </p>
     #[serde(rename = "StreamARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -110,6 +431,52 @@ pub struct GetDataEndpointInput {
     pub stream_name: Option<String>,
 }
 
+impl GetDataEndpointInput {
+    /// Returns a builder for `GetDataEndpointInput`, checking its required members when built.
+    pub fn builder() -> GetDataEndpointInputBuilder {
+        GetDataEndpointInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetDataEndpointInput`](struct.GetDataEndpointInput.html).
+#[derive(Default, Debug)]
+pub struct GetDataEndpointInputBuilder {
+    api_name: Option<APIName>,
+    stream_arn: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl GetDataEndpointInputBuilder {
+    /// Sets the [`api_name`](struct.GetDataEndpointInput.html#structfield.api_name) member.
+    pub fn api_name<T: Into<APIName>>(mut self, value: T) -> Self {
+        self.api_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.GetDataEndpointInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.GetDataEndpointInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDataEndpointInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDataEndpointInput, ::rusoto_core::BuildError> {
+        Ok(GetDataEndpointInput {
+            api_name: self
+                .api_name
+                .ok_or(::rusoto_core::BuildError::missing("api_name"))?,
+            stream_arn: self.stream_arn,
+            stream_name: self.stream_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDataEndpointOutput {
@@ -135,6 +502,50 @@ pub struct ListStreamsInput {
     pub stream_name_condition: Option<StreamNameCondition>,
 }
 
+impl ListStreamsInput {
+    /// Returns a builder for `ListStreamsInput`, checking its required members when built.
+    pub fn builder() -> ListStreamsInputBuilder {
+        ListStreamsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListStreamsInput`](struct.ListStreamsInput.html).
+#[derive(Default, Debug)]
+pub struct ListStreamsInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    stream_name_condition: Option<StreamNameCondition>,
+}
+
+impl ListStreamsInputBuilder {
+    /// Sets the [`max_results`](struct.ListStreamsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListStreamsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name_condition`](struct.ListStreamsInput.html#structfield.stream_name_condition) member.
+    pub fn stream_name_condition<T: Into<StreamNameCondition>>(mut self, value: T) -> Self {
+        self.stream_name_condition = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStreamsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListStreamsInput, ::rusoto_core::BuildError> {
+        Ok(ListStreamsInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            stream_name_condition: self.stream_name_condition,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListStreamsOutput {
@@ -164,6 +575,50 @@ pub struct ListTagsForStreamInput {
     pub stream_name: Option<String>,
 }
 
+impl ListTagsForStreamInput {
+    /// Returns a builder for `ListTagsForStreamInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForStreamInputBuilder {
+        ListTagsForStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForStreamInput`](struct.ListTagsForStreamInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForStreamInputBuilder {
+    next_token: Option<String>,
+    stream_arn: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl ListTagsForStreamInputBuilder {
+    /// Sets the [`next_token`](struct.ListTagsForStreamInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.ListTagsForStreamInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.ListTagsForStreamInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTagsForStreamInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForStreamInput {
+            next_token: self.next_token,
+            stream_arn: self.stream_arn,
+            stream_name: self.stream_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForStreamOutput {
@@ -177,14 +632,101 @@ pub struct ListTagsForStreamOutput {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+/// <p>The status of the stream.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Status {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::Creating => "CREATING",
+            Status::Active => "ACTIVE",
+            Status::Updating => "UPDATING",
+            Status::Deleting => "DELETING",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "CREATING" => Status::Creating,
+            "ACTIVE" => Status::Active,
+            "UPDATING" => Status::Updating,
+            "DELETING" => Status::Deleting,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        Status::from(value.as_str())
+    }
+}
+
+impl From<Status> for String {
+    fn from(value: Status) -> String {
+        match value {
+            Status::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Status::from(value))
+    }
+}
+
 /// <p>An object describing a Kinesis video stream.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StreamInfo {
     /// <p>A time stamp that indicates when the stream was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>How long the stream retains data, in hours.</p>
     #[serde(rename = "DataRetentionInHours")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -204,7 +746,7 @@ pub struct StreamInfo {
     /// <p>The status of the stream.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The Amazon Resource Name (ARN) of the stream.</p>
     #[serde(rename = "StreamARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -225,7 +767,7 @@ pub struct StreamNameCondition {
     /// <p>A comparison operator. Currently, you can specify only the <code>BEGINS_WITH</code> operator, which finds streams whose names start with a given prefix.</p>
     #[serde(rename = "ComparisonOperator")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub comparison_operator: Option<String>,
+    pub comparison_operator: Option<ComparisonOperator>,
     /// <p>A value to compare.</p>
     #[serde(rename = "ComparisonValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -247,6 +789,52 @@ pub struct TagStreamInput {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagStreamInput {
+    /// Returns a builder for `TagStreamInput`, checking its required members when built.
+    pub fn builder() -> TagStreamInputBuilder {
+        TagStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagStreamInput`](struct.TagStreamInput.html).
+#[derive(Default, Debug)]
+pub struct TagStreamInputBuilder {
+    stream_arn: Option<String>,
+    stream_name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagStreamInputBuilder {
+    /// Sets the [`stream_arn`](struct.TagStreamInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.TagStreamInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagStreamInput.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagStreamInput, ::rusoto_core::BuildError> {
+        Ok(TagStreamInput {
+            stream_arn: self.stream_arn,
+            stream_name: self.stream_name,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagStreamOutput {}
@@ -266,11 +854,57 @@ pub struct UntagStreamInput {
     pub tag_key_list: Vec<String>,
 }
 
+impl UntagStreamInput {
+    /// Returns a builder for `UntagStreamInput`, checking its required members when built.
+    pub fn builder() -> UntagStreamInputBuilder {
+        UntagStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagStreamInput`](struct.UntagStreamInput.html).
+#[derive(Default, Debug)]
+pub struct UntagStreamInputBuilder {
+    stream_arn: Option<String>,
+    stream_name: Option<String>,
+    tag_key_list: Option<Vec<String>>,
+}
+
+impl UntagStreamInputBuilder {
+    /// Sets the [`stream_arn`](struct.UntagStreamInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.UntagStreamInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_key_list`](struct.UntagStreamInput.html#structfield.tag_key_list) member.
+    pub fn tag_key_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_key_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagStreamInput, ::rusoto_core::BuildError> {
+        Ok(UntagStreamInput {
+            stream_arn: self.stream_arn,
+            stream_name: self.stream_name,
+            tag_key_list: self
+                .tag_key_list
+                .ok_or(::rusoto_core::BuildError::missing("tag_key_list"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagStreamOutput {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateDataRetentionInput {
     /// <p>The version of the stream whose retention period you want to change. To get the version, call either the <code>DescribeStream</code> or the <code>ListStreams</code> API.</p>
     #[serde(rename = "CurrentVersion")]
@@ -280,7 +914,7 @@ pub struct UpdateDataRetentionInput {
     pub data_retention_change_in_hours: i64,
     /// <p>Indicates whether you want to increase or decrease the retention period.</p>
     #[serde(rename = "Operation")]
-    pub operation: String,
+    pub operation: UpdateDataRetentionOperation,
     /// <p>The Amazon Resource Name (ARN) of the stream whose retention period you want to change.</p>
     #[serde(rename = "StreamARN")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -291,6 +925,154 @@ pub struct UpdateDataRetentionInput {
     pub stream_name: Option<String>,
 }
 
+impl UpdateDataRetentionInput {
+    /// Returns a builder for `UpdateDataRetentionInput`, checking its required members when built.
+    pub fn builder() -> UpdateDataRetentionInputBuilder {
+        UpdateDataRetentionInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDataRetentionInput`](struct.UpdateDataRetentionInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateDataRetentionInputBuilder {
+    current_version: Option<String>,
+    data_retention_change_in_hours: Option<i64>,
+    operation: Option<UpdateDataRetentionOperation>,
+    stream_arn: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl UpdateDataRetentionInputBuilder {
+    /// Sets the [`current_version`](struct.UpdateDataRetentionInput.html#structfield.current_version) member.
+    pub fn current_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.current_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_retention_change_in_hours`](struct.UpdateDataRetentionInput.html#structfield.data_retention_change_in_hours) member.
+    pub fn data_retention_change_in_hours<T: Into<i64>>(mut self, value: T) -> Self {
+        self.data_retention_change_in_hours = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operation`](struct.UpdateDataRetentionInput.html#structfield.operation) member.
+    pub fn operation<T: Into<UpdateDataRetentionOperation>>(mut self, value: T) -> Self {
+        self.operation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.UpdateDataRetentionInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.UpdateDataRetentionInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDataRetentionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDataRetentionInput, ::rusoto_core::BuildError> {
+        Ok(UpdateDataRetentionInput {
+            current_version: self
+                .current_version
+                .ok_or(::rusoto_core::BuildError::missing("current_version"))?,
+            data_retention_change_in_hours: self.data_retention_change_in_hours.ok_or(
+                ::rusoto_core::BuildError::missing("data_retention_change_in_hours"),
+            )?,
+            operation: self
+                .operation
+                .ok_or(::rusoto_core::BuildError::missing("operation"))?,
+            stream_arn: self.stream_arn,
+            stream_name: self.stream_name,
+        })
+    }
+}
+
+/// <p>Indicates whether you want to increase or decrease the retention period.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateDataRetentionOperation {
+    /// <p><code>INCREASE_DATA_RETENTION</code></p>
+    IncreaseDataRetention,
+    /// <p><code>DECREASE_DATA_RETENTION</code></p>
+    DecreaseDataRetention,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UpdateDataRetentionOperation {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UpdateDataRetentionOperation::IncreaseDataRetention => "INCREASE_DATA_RETENTION",
+            UpdateDataRetentionOperation::DecreaseDataRetention => "DECREASE_DATA_RETENTION",
+            UpdateDataRetentionOperation::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UpdateDataRetentionOperation {
+    fn from(value: &'a str) -> UpdateDataRetentionOperation {
+        match value {
+            "INCREASE_DATA_RETENTION" => UpdateDataRetentionOperation::IncreaseDataRetention,
+            "DECREASE_DATA_RETENTION" => UpdateDataRetentionOperation::DecreaseDataRetention,
+            _ => UpdateDataRetentionOperation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UpdateDataRetentionOperation {
+    fn from(value: String) -> UpdateDataRetentionOperation {
+        UpdateDataRetentionOperation::from(value.as_str())
+    }
+}
+
+impl From<UpdateDataRetentionOperation> for String {
+    fn from(value: UpdateDataRetentionOperation) -> String {
+        match value {
+            UpdateDataRetentionOperation::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UpdateDataRetentionOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for UpdateDataRetentionOperation {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for UpdateDataRetentionOperation {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UpdateDataRetentionOperation {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<UpdateDataRetentionOperation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UpdateDataRetentionOperation::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDataRetentionOutput {}
@@ -318,6 +1100,68 @@ pub struct UpdateStreamInput {
     pub stream_name: Option<String>,
 }
 
+impl UpdateStreamInput {
+    /// Returns a builder for `UpdateStreamInput`, checking its required members when built.
+    pub fn builder() -> UpdateStreamInputBuilder {
+        UpdateStreamInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateStreamInput`](struct.UpdateStreamInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateStreamInputBuilder {
+    current_version: Option<String>,
+    device_name: Option<String>,
+    media_type: Option<String>,
+    stream_arn: Option<String>,
+    stream_name: Option<String>,
+}
+
+impl UpdateStreamInputBuilder {
+    /// Sets the [`current_version`](struct.UpdateStreamInput.html#structfield.current_version) member.
+    pub fn current_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.current_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`device_name`](struct.UpdateStreamInput.html#structfield.device_name) member.
+    pub fn device_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.device_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`media_type`](struct.UpdateStreamInput.html#structfield.media_type) member.
+    pub fn media_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.media_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_arn`](struct.UpdateStreamInput.html#structfield.stream_arn) member.
+    pub fn stream_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stream_name`](struct.UpdateStreamInput.html#structfield.stream_name) member.
+    pub fn stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateStreamInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateStreamInput, ::rusoto_core::BuildError> {
+        Ok(UpdateStreamInput {
+            current_version: self
+                .current_version
+                .ok_or(::rusoto_core::BuildError::missing("current_version"))?,
+            device_name: self.device_name,
+            media_type: self.media_type,
+            stream_arn: self.stream_arn,
+            stream_name: self.stream_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateStreamOutput {}
@@ -967,6 +1811,72 @@ pub trait KinesisVideo {
         input: UpdateStreamInput,
     ) -> RusotoFuture<UpdateStreamOutput, UpdateStreamError>;
 }
+impl<T: ?Sized + KinesisVideo> KinesisVideo for ::std::sync::Arc<T> {
+    fn create_stream(
+        &self,
+        input: CreateStreamInput,
+    ) -> RusotoFuture<CreateStreamOutput, CreateStreamError> {
+        (**self).create_stream(input)
+    }
+    fn delete_stream(
+        &self,
+        input: DeleteStreamInput,
+    ) -> RusotoFuture<DeleteStreamOutput, DeleteStreamError> {
+        (**self).delete_stream(input)
+    }
+    fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> RusotoFuture<DescribeStreamOutput, DescribeStreamError> {
+        (**self).describe_stream(input)
+    }
+    fn get_data_endpoint(
+        &self,
+        input: GetDataEndpointInput,
+    ) -> RusotoFuture<GetDataEndpointOutput, GetDataEndpointError> {
+        (**self).get_data_endpoint(input)
+    }
+    fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> RusotoFuture<ListStreamsOutput, ListStreamsError> {
+        (**self).list_streams(input)
+    }
+    fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> RusotoFuture<ListTagsForStreamOutput, ListTagsForStreamError> {
+        (**self).list_tags_for_stream(input)
+    }
+    fn tag_stream(&self, input: TagStreamInput) -> RusotoFuture<TagStreamOutput, TagStreamError> {
+        (**self).tag_stream(input)
+    }
+    fn untag_stream(
+        &self,
+        input: UntagStreamInput,
+    ) -> RusotoFuture<UntagStreamOutput, UntagStreamError> {
+        (**self).untag_stream(input)
+    }
+    fn update_data_retention(
+        &self,
+        input: UpdateDataRetentionInput,
+    ) -> RusotoFuture<UpdateDataRetentionOutput, UpdateDataRetentionError> {
+        (**self).update_data_retention(input)
+    }
+    fn update_stream(
+        &self,
+        input: UpdateStreamInput,
+    ) -> RusotoFuture<UpdateStreamOutput, UpdateStreamError> {
+        (**self).update_stream(input)
+    }
+}
+/// The error codes Kinesis Video retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Kinesis Video API.
 #[derive(Clone)]
 pub struct KinesisVideoClient {
@@ -982,6 +1892,27 @@ impl KinesisVideoClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_KINESISVIDEO`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> KinesisVideoClient {
+        Self::new(region::Region::from_env_for_service("KINESISVIDEO"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `kinesisvideo`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> KinesisVideoClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("kinesisvideo", "KINESISVIDEO"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1000,7 +1931,54 @@ impl KinesisVideoClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoClient {
-        KinesisVideoClient { client, region }
+        KinesisVideoClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "kinesisvideo")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "kinesisvideo",
+            "kinesisvideo",
+            "KINESISVIDEO",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `KinesisVideo` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn KinesisVideo + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for KinesisVideoClient {
+    fn service_name(&self) -> &'static str {
+        "Kinesis Video"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "kinesisvideo"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> KinesisVideoClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -22,7 +22,92 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The algorithm you will use to encrypt the key material before importing it with <a>ImportKeyMaterial</a>. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys-encrypt-key-material.html">Encrypt the Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AlgorithmSpec {
+    /// <p><code>RSAES_PKCS1_V1_5</code></p>
+    RsaesPkcs1V15,
+    /// <p><code>RSAES_OAEP_SHA_1</code></p>
+    RsaesOaepSha1,
+    /// <p><code>RSAES_OAEP_SHA_256</code></p>
+    RsaesOaepSha256,
+    /// <p><code>RSA_AES_KEY_WRAP_SHA_1</code></p>
+    RsaAesKeyWrapSha1,
+    /// <p><code>RSA_AES_KEY_WRAP_SHA_256</code></p>
+    RsaAesKeyWrapSha256,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AlgorithmSpec {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AlgorithmSpec::RsaesPkcs1V15 => "RSAES_PKCS1_V1_5",
+            AlgorithmSpec::RsaesOaepSha1 => "RSAES_OAEP_SHA_1",
+            AlgorithmSpec::RsaesOaepSha256 => "RSAES_OAEP_SHA_256",
+            AlgorithmSpec::RsaAesKeyWrapSha1 => "RSA_AES_KEY_WRAP_SHA_1",
+            AlgorithmSpec::RsaAesKeyWrapSha256 => "RSA_AES_KEY_WRAP_SHA_256",
+            AlgorithmSpec::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AlgorithmSpec {
+    fn from(value: &'a str) -> AlgorithmSpec {
+        match value {
+            "RSAES_PKCS1_V1_5" => AlgorithmSpec::RsaesPkcs1V15,
+            "RSAES_OAEP_SHA_1" => AlgorithmSpec::RsaesOaepSha1,
+            "RSAES_OAEP_SHA_256" => AlgorithmSpec::RsaesOaepSha256,
+            "RSA_AES_KEY_WRAP_SHA_1" => AlgorithmSpec::RsaAesKeyWrapSha1,
+            "RSA_AES_KEY_WRAP_SHA_256" => AlgorithmSpec::RsaAesKeyWrapSha256,
+            _ => AlgorithmSpec::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AlgorithmSpec {
+    fn from(value: String) -> AlgorithmSpec {
+        AlgorithmSpec::from(value.as_str())
+    }
+}
+
+impl From<AlgorithmSpec> for String {
+    fn from(value: AlgorithmSpec) -> String {
+        match value {
+            AlgorithmSpec::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AlgorithmSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AlgorithmSpec {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AlgorithmSpec {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AlgorithmSpec, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AlgorithmSpec::from(value))
+    }
+}
+
 /// <p>Contains information about an alias.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -48,6 +133,38 @@ pub struct CancelKeyDeletionRequest {
     pub key_id: String,
 }
 
+impl CancelKeyDeletionRequest {
+    /// Returns a builder for `CancelKeyDeletionRequest`, checking its required members when built.
+    pub fn builder() -> CancelKeyDeletionRequestBuilder {
+        CancelKeyDeletionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelKeyDeletionRequest`](struct.CancelKeyDeletionRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelKeyDeletionRequestBuilder {
+    key_id: Option<String>,
+}
+
+impl CancelKeyDeletionRequestBuilder {
+    /// Sets the [`key_id`](struct.CancelKeyDeletionRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelKeyDeletionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CancelKeyDeletionRequest, ::rusoto_core::BuildError> {
+        Ok(CancelKeyDeletionRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelKeyDeletionResponse {
@@ -64,10 +181,282 @@ pub struct ConnectCustomKeyStoreRequest {
     pub custom_key_store_id: String,
 }
 
+impl ConnectCustomKeyStoreRequest {
+    /// Returns a builder for `ConnectCustomKeyStoreRequest`, checking its required members when built.
+    pub fn builder() -> ConnectCustomKeyStoreRequestBuilder {
+        ConnectCustomKeyStoreRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ConnectCustomKeyStoreRequest`](struct.ConnectCustomKeyStoreRequest.html).
+#[derive(Default, Debug)]
+pub struct ConnectCustomKeyStoreRequestBuilder {
+    custom_key_store_id: Option<String>,
+}
+
+impl ConnectCustomKeyStoreRequestBuilder {
+    /// Sets the [`custom_key_store_id`](struct.ConnectCustomKeyStoreRequest.html#structfield.custom_key_store_id) member.
+    pub fn custom_key_store_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_key_store_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ConnectCustomKeyStoreRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ConnectCustomKeyStoreRequest, ::rusoto_core::BuildError> {
+        Ok(ConnectCustomKeyStoreRequest {
+            custom_key_store_id: self
+                .custom_key_store_id
+                .ok_or(::rusoto_core::BuildError::missing("custom_key_store_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ConnectCustomKeyStoreResponse {}
 
+/// <p>Describes the connection error. Valid values are:</p> <ul> <li> <p> <code>CLUSTER_NOT_FOUND</code> - AWS KMS cannot find the AWS CloudHSM cluster with the specified cluster ID.</p> </li> <li> <p> <code>INSUFFICIENT_CLOUDHSM_HSMS</code> - The associated AWS CloudHSM cluster does not contain any active HSMs. To connect a custom key store to its AWS CloudHSM cluster, the cluster must contain at least one active HSM.</p> </li> <li> <p> <code>INTERNAL_ERROR</code> - AWS KMS could not complete the request due to an internal error. Retry the request. For <code>ConnectCustomKeyStore</code> requests, disconnect the custom key store before trying to connect again.</p> </li> <li> <p> <code>INVALID_CREDENTIALS</code> - AWS KMS does not have the correct password for the <code>kmsuser</code> crypto user in the AWS CloudHSM cluster.</p> </li> <li> <p> <code>NETWORK_ERRORS</code> - Network errors are preventing AWS KMS from connecting to the custom key store.</p> </li> <li> <p> <code>USER_LOCKED_OUT</code> - The <code>kmsuser</code> CU account is locked out of the associated AWS CloudHSM cluster due to too many failed password attempts. Before you can connect your custom key store to its AWS CloudHSM cluster, you must change the <code>kmsuser</code> account password and update the password value for the custom key store.</p> </li> </ul> <p>For help with connection failures, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting Custom Key Stores</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionErrorCodeType {
+    /// <p><code>INVALID_CREDENTIALS</code> - AWS KMS does not have the correct password for the <code>kmsuser</code> crypto user in the AWS CloudHSM cluster.</p>
+    InvalidCredentials,
+    /// <p><code>CLUSTER_NOT_FOUND</code> - AWS KMS cannot find the AWS CloudHSM cluster with the specified cluster ID.</p>
+    ClusterNotFound,
+    /// <p><code>NETWORK_ERRORS</code> - Network errors are preventing AWS KMS from connecting to the custom key store.</p>
+    NetworkErrors,
+    /// <p><code>INTERNAL_ERROR</code> - AWS KMS could not complete the request due to an internal error. Retry the request. For <code>ConnectCustomKeyStore</code> requests, disconnect the custom key store before trying to connect again.</p>
+    InternalError,
+    /// <p><code>INSUFFICIENT_CLOUDHSM_HSMS</code> - The associated AWS CloudHSM cluster does not contain any active HSMs. To connect a custom key store to its AWS CloudHSM cluster, the cluster must contain at least one active HSM.</p>
+    InsufficientCloudhsmHsms,
+    /// <p><code>USER_LOCKED_OUT</code> - The <code>kmsuser</code> CU account is locked out of the associated AWS CloudHSM cluster due to too many failed password attempts. Before you can connect your custom key store to its AWS CloudHSM cluster, you must change the <code>kmsuser</code> account password and update the password value for the custom key store.</p>
+    UserLockedOut,
+    /// <p><code>USER_NOT_FOUND</code></p>
+    UserNotFound,
+    /// <p><code>USER_LOGGED_IN</code></p>
+    UserLoggedIn,
+    /// <p><code>SUBNET_NOT_FOUND</code></p>
+    SubnetNotFound,
+    /// <p><code>INSUFFICIENT_FREE_ADDRESSES_IN_SUBNET</code></p>
+    InsufficientFreeAddressesInSubnet,
+    /// <p><code>XKS_PROXY_ACCESS_DENIED</code></p>
+    XksProxyAccessDenied,
+    /// <p><code>XKS_PROXY_NOT_REACHABLE</code></p>
+    XksProxyNotReachable,
+    /// <p><code>XKS_VPC_ENDPOINT_SERVICE_NOT_FOUND</code></p>
+    XksVpcEndpointServiceNotFound,
+    /// <p><code>XKS_PROXY_INVALID_RESPONSE</code></p>
+    XksProxyInvalidResponse,
+    /// <p><code>XKS_PROXY_INVALID_CONFIGURATION</code></p>
+    XksProxyInvalidConfiguration,
+    /// <p><code>XKS_VPC_ENDPOINT_SERVICE_INVALID_CONFIGURATION</code></p>
+    XksVpcEndpointServiceInvalidConfiguration,
+    /// <p><code>XKS_PROXY_TIMED_OUT</code></p>
+    XksProxyTimedOut,
+    /// <p><code>XKS_PROXY_INVALID_TLS_CONFIGURATION</code></p>
+    XksProxyInvalidTlsConfiguration,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConnectionErrorCodeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionErrorCodeType::InvalidCredentials => "INVALID_CREDENTIALS",
+            ConnectionErrorCodeType::ClusterNotFound => "CLUSTER_NOT_FOUND",
+            ConnectionErrorCodeType::NetworkErrors => "NETWORK_ERRORS",
+            ConnectionErrorCodeType::InternalError => "INTERNAL_ERROR",
+            ConnectionErrorCodeType::InsufficientCloudhsmHsms => "INSUFFICIENT_CLOUDHSM_HSMS",
+            ConnectionErrorCodeType::UserLockedOut => "USER_LOCKED_OUT",
+            ConnectionErrorCodeType::UserNotFound => "USER_NOT_FOUND",
+            ConnectionErrorCodeType::UserLoggedIn => "USER_LOGGED_IN",
+            ConnectionErrorCodeType::SubnetNotFound => "SUBNET_NOT_FOUND",
+            ConnectionErrorCodeType::InsufficientFreeAddressesInSubnet => {
+                "INSUFFICIENT_FREE_ADDRESSES_IN_SUBNET"
+            }
+            ConnectionErrorCodeType::XksProxyAccessDenied => "XKS_PROXY_ACCESS_DENIED",
+            ConnectionErrorCodeType::XksProxyNotReachable => "XKS_PROXY_NOT_REACHABLE",
+            ConnectionErrorCodeType::XksVpcEndpointServiceNotFound => {
+                "XKS_VPC_ENDPOINT_SERVICE_NOT_FOUND"
+            }
+            ConnectionErrorCodeType::XksProxyInvalidResponse => "XKS_PROXY_INVALID_RESPONSE",
+            ConnectionErrorCodeType::XksProxyInvalidConfiguration => {
+                "XKS_PROXY_INVALID_CONFIGURATION"
+            }
+            ConnectionErrorCodeType::XksVpcEndpointServiceInvalidConfiguration => {
+                "XKS_VPC_ENDPOINT_SERVICE_INVALID_CONFIGURATION"
+            }
+            ConnectionErrorCodeType::XksProxyTimedOut => "XKS_PROXY_TIMED_OUT",
+            ConnectionErrorCodeType::XksProxyInvalidTlsConfiguration => {
+                "XKS_PROXY_INVALID_TLS_CONFIGURATION"
+            }
+            ConnectionErrorCodeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionErrorCodeType {
+    fn from(value: &'a str) -> ConnectionErrorCodeType {
+        match value {
+            "INVALID_CREDENTIALS" => ConnectionErrorCodeType::InvalidCredentials,
+            "CLUSTER_NOT_FOUND" => ConnectionErrorCodeType::ClusterNotFound,
+            "NETWORK_ERRORS" => ConnectionErrorCodeType::NetworkErrors,
+            "INTERNAL_ERROR" => ConnectionErrorCodeType::InternalError,
+            "INSUFFICIENT_CLOUDHSM_HSMS" => ConnectionErrorCodeType::InsufficientCloudhsmHsms,
+            "USER_LOCKED_OUT" => ConnectionErrorCodeType::UserLockedOut,
+            "USER_NOT_FOUND" => ConnectionErrorCodeType::UserNotFound,
+            "USER_LOGGED_IN" => ConnectionErrorCodeType::UserLoggedIn,
+            "SUBNET_NOT_FOUND" => ConnectionErrorCodeType::SubnetNotFound,
+            "INSUFFICIENT_FREE_ADDRESSES_IN_SUBNET" => {
+                ConnectionErrorCodeType::InsufficientFreeAddressesInSubnet
+            }
+            "XKS_PROXY_ACCESS_DENIED" => ConnectionErrorCodeType::XksProxyAccessDenied,
+            "XKS_PROXY_NOT_REACHABLE" => ConnectionErrorCodeType::XksProxyNotReachable,
+            "XKS_VPC_ENDPOINT_SERVICE_NOT_FOUND" => {
+                ConnectionErrorCodeType::XksVpcEndpointServiceNotFound
+            }
+            "XKS_PROXY_INVALID_RESPONSE" => ConnectionErrorCodeType::XksProxyInvalidResponse,
+            "XKS_PROXY_INVALID_CONFIGURATION" => {
+                ConnectionErrorCodeType::XksProxyInvalidConfiguration
+            }
+            "XKS_VPC_ENDPOINT_SERVICE_INVALID_CONFIGURATION" => {
+                ConnectionErrorCodeType::XksVpcEndpointServiceInvalidConfiguration
+            }
+            "XKS_PROXY_TIMED_OUT" => ConnectionErrorCodeType::XksProxyTimedOut,
+            "XKS_PROXY_INVALID_TLS_CONFIGURATION" => {
+                ConnectionErrorCodeType::XksProxyInvalidTlsConfiguration
+            }
+            _ => ConnectionErrorCodeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionErrorCodeType {
+    fn from(value: String) -> ConnectionErrorCodeType {
+        ConnectionErrorCodeType::from(value.as_str())
+    }
+}
+
+impl From<ConnectionErrorCodeType> for String {
+    fn from(value: ConnectionErrorCodeType) -> String {
+        match value {
+            ConnectionErrorCodeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionErrorCodeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConnectionErrorCodeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionErrorCodeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConnectionErrorCodeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConnectionErrorCodeType::from(value))
+    }
+}
+
+/// <p>Indicates whether the custom key store is connected to its AWS CloudHSM cluster.</p> <p>You can create and use CMKs in your custom key stores only when its connection state is <code>CONNECTED</code>.</p> <p>The value is <code>DISCONNECTED</code> if the key store has never been connected or you use the <a>DisconnectCustomKeyStore</a> operation to disconnect it. If the value is <code>CONNECTED</code> but you are having trouble using the custom key store, make sure that its associated AWS CloudHSM cluster is active and contains at least one active HSM.</p> <p>A value of <code>FAILED</code> indicates that an attempt to connect was unsuccessful. For help resolving a connection failure, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionStateType {
+    /// <p><code>CONNECTED</code></p>
+    Connected,
+    /// <p><code>CONNECTING</code></p>
+    Connecting,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>DISCONNECTED</code></p>
+    Disconnected,
+    /// <p><code>DISCONNECTING</code></p>
+    Disconnecting,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConnectionStateType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConnectionStateType::Connected => "CONNECTED",
+            ConnectionStateType::Connecting => "CONNECTING",
+            ConnectionStateType::Failed => "FAILED",
+            ConnectionStateType::Disconnected => "DISCONNECTED",
+            ConnectionStateType::Disconnecting => "DISCONNECTING",
+            ConnectionStateType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConnectionStateType {
+    fn from(value: &'a str) -> ConnectionStateType {
+        match value {
+            "CONNECTED" => ConnectionStateType::Connected,
+            "CONNECTING" => ConnectionStateType::Connecting,
+            "FAILED" => ConnectionStateType::Failed,
+            "DISCONNECTED" => ConnectionStateType::Disconnected,
+            "DISCONNECTING" => ConnectionStateType::Disconnecting,
+            _ => ConnectionStateType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConnectionStateType {
+    fn from(value: String) -> ConnectionStateType {
+        ConnectionStateType::from(value.as_str())
+    }
+}
+
+impl From<ConnectionStateType> for String {
+    fn from(value: ConnectionStateType) -> String {
+        match value {
+            ConnectionStateType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConnectionStateType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ConnectionStateType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConnectionStateType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConnectionStateType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConnectionStateType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateAliasRequest {
     /// <p>Specifies the alias name. This value must begin with <code>alias/</code> followed by a name, such as <code>alias/ExampleAlias</code>. The alias name cannot begin with <code>alias/aws/</code>. The <code>alias/aws/</code> prefix is reserved for AWS managed CMKs.</p>
@@ -78,6 +467,46 @@ pub struct CreateAliasRequest {
     pub target_key_id: String,
 }
 
+impl CreateAliasRequest {
+    /// Returns a builder for `CreateAliasRequest`, checking its required members when built.
+    pub fn builder() -> CreateAliasRequestBuilder {
+        CreateAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAliasRequest`](struct.CreateAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAliasRequestBuilder {
+    alias_name: Option<String>,
+    target_key_id: Option<String>,
+}
+
+impl CreateAliasRequestBuilder {
+    /// Sets the [`alias_name`](struct.CreateAliasRequest.html#structfield.alias_name) member.
+    pub fn alias_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.alias_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_key_id`](struct.CreateAliasRequest.html#structfield.target_key_id) member.
+    pub fn target_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateAliasRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAliasRequest {
+            alias_name: self
+                .alias_name
+                .ok_or(::rusoto_core::BuildError::missing("alias_name"))?,
+            target_key_id: self
+                .target_key_id
+                .ok_or(::rusoto_core::BuildError::missing("target_key_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateCustomKeyStoreRequest {
     /// <p>Identifies the AWS CloudHSM cluster for the custom key store. Enter the cluster ID of any active AWS CloudHSM cluster that is not already associated with a custom key store. To find the cluster ID, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_DescribeClusters.html">DescribeClusters</a> operation.</p>
@@ -94,6 +523,68 @@ pub struct CreateCustomKeyStoreRequest {
     pub trust_anchor_certificate: String,
 }
 
+impl CreateCustomKeyStoreRequest {
+    /// Returns a builder for `CreateCustomKeyStoreRequest`, checking its required members when built.
+    pub fn builder() -> CreateCustomKeyStoreRequestBuilder {
+        CreateCustomKeyStoreRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCustomKeyStoreRequest`](struct.CreateCustomKeyStoreRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCustomKeyStoreRequestBuilder {
+    cloud_hsm_cluster_id: Option<String>,
+    custom_key_store_name: Option<String>,
+    key_store_password: Option<String>,
+    trust_anchor_certificate: Option<String>,
+}
+
+impl CreateCustomKeyStoreRequestBuilder {
+    /// Sets the [`cloud_hsm_cluster_id`](struct.CreateCustomKeyStoreRequest.html#structfield.cloud_hsm_cluster_id) member.
+    pub fn cloud_hsm_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cloud_hsm_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_key_store_name`](struct.CreateCustomKeyStoreRequest.html#structfield.custom_key_store_name) member.
+    pub fn custom_key_store_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_key_store_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_store_password`](struct.CreateCustomKeyStoreRequest.html#structfield.key_store_password) member.
+    pub fn key_store_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_store_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`trust_anchor_certificate`](struct.CreateCustomKeyStoreRequest.html#structfield.trust_anchor_certificate) member.
+    pub fn trust_anchor_certificate<T: Into<String>>(mut self, value: T) -> Self {
+        self.trust_anchor_certificate = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCustomKeyStoreRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCustomKeyStoreRequest, ::rusoto_core::BuildError> {
+        Ok(CreateCustomKeyStoreRequest {
+            cloud_hsm_cluster_id: self
+                .cloud_hsm_cluster_id
+                .ok_or(::rusoto_core::BuildError::missing("cloud_hsm_cluster_id"))?,
+            custom_key_store_name: self
+                .custom_key_store_name
+                .ok_or(::rusoto_core::BuildError::missing("custom_key_store_name"))?,
+            key_store_password: self
+                .key_store_password
+                .ok_or(::rusoto_core::BuildError::missing("key_store_password"))?,
+            trust_anchor_certificate: self.trust_anchor_certificate.ok_or(
+                ::rusoto_core::BuildError::missing("trust_anchor_certificate"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCustomKeyStoreResponse {
@@ -125,13 +616,95 @@ pub struct CreateGrantRequest {
     pub name: Option<String>,
     /// <p>A list of operations that the grant permits.</p>
     #[serde(rename = "Operations")]
-    pub operations: Vec<String>,
+    pub operations: Vec<GrantOperation>,
     /// <p>The principal that is given permission to retire the grant by using <a>RetireGrant</a> operation.</p> <p>To specify the principal, use the <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html">Amazon Resource Name (ARN)</a> of an AWS principal. Valid AWS principals include AWS accounts (root), IAM users, federated users, and assumed role users. For examples of the ARN syntax to use for specifying a principal, see <a href="https://docs.aws.amazon.com/general/latest/gr/aws-arns-and-namespaces.html#arn-syntax-iam">AWS Identity and Access Management (IAM)</a> in the Example ARNs section of the <i>AWS General Reference</i>.</p>
     #[serde(rename = "RetiringPrincipal")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retiring_principal: Option<String>,
 }
 
+impl CreateGrantRequest {
+    /// Returns a builder for `CreateGrantRequest`, checking its required members when built.
+    pub fn builder() -> CreateGrantRequestBuilder {
+        CreateGrantRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGrantRequest`](struct.CreateGrantRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateGrantRequestBuilder {
+    constraints: Option<GrantConstraints>,
+    grant_tokens: Option<Vec<String>>,
+    grantee_principal: Option<String>,
+    key_id: Option<String>,
+    name: Option<String>,
+    operations: Option<Vec<GrantOperation>>,
+    retiring_principal: Option<String>,
+}
+
+impl CreateGrantRequestBuilder {
+    /// Sets the [`constraints`](struct.CreateGrantRequest.html#structfield.constraints) member.
+    pub fn constraints<T: Into<GrantConstraints>>(mut self, value: T) -> Self {
+        self.constraints = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grant_tokens`](struct.CreateGrantRequest.html#structfield.grant_tokens) member.
+    pub fn grant_tokens<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.grant_tokens = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grantee_principal`](struct.CreateGrantRequest.html#structfield.grantee_principal) member.
+    pub fn grantee_principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.grantee_principal = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.CreateGrantRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateGrantRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`operations`](struct.CreateGrantRequest.html#structfield.operations) member.
+    pub fn operations<T: Into<Vec<GrantOperation>>>(mut self, value: T) -> Self {
+        self.operations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retiring_principal`](struct.CreateGrantRequest.html#structfield.retiring_principal) member.
+    pub fn retiring_principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.retiring_principal = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGrantRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateGrantRequest, ::rusoto_core::BuildError> {
+        Ok(CreateGrantRequest {
+            constraints: self.constraints,
+            grant_tokens: self.grant_tokens,
+            grantee_principal: self
+                .grantee_principal
+                .ok_or(::rusoto_core::BuildError::missing("grantee_principal"))?,
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            name: self.name,
+            operations: self
+                .operations
+                .ok_or(::rusoto_core::BuildError::missing("operations"))?,
+            retiring_principal: self.retiring_principal,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateGrantResponse {
@@ -162,11 +735,11 @@ pub struct CreateKeyRequest {
     /// <p>The cryptographic operations for which you can use the CMK. The only valid value is <code>ENCRYPT_DECRYPT</code>, which means you can use the CMK to encrypt and decrypt data.</p>
     #[serde(rename = "KeyUsage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_usage: Option<String>,
+    pub key_usage: Option<KeyUsageType>,
     /// <p>The source of the key material for the CMK. You cannot change the origin after you create the CMK.</p> <p>The default is <code>AWS_KMS</code>, which means AWS KMS creates the key material in its own key store.</p> <p>When the parameter value is <code>EXTERNAL</code>, AWS KMS creates a CMK without key material so that you can import key material from your existing key management infrastructure. For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>When the parameter value is <code>AWS_CLOUDHSM</code>, AWS KMS creates the CMK in an AWS KMS <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> and creates its key material in the associated AWS CloudHSM cluster. You must also use the <code>CustomKeyStoreId</code> parameter to identify the custom key store.</p>
     #[serde(rename = "Origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origin: Option<String>,
+    pub origin: Option<OriginType>,
     /// <p>The key policy to attach to the CMK.</p> <p>If you provide a key policy, it must meet the following criteria:</p> <ul> <li> <p>If you don't set <code>BypassPolicyLockoutSafetyCheck</code> to true, the key policy must allow the principal that is making the <code>CreateKey</code> request to make a subsequent <a>PutKeyPolicy</a> request on the CMK. This reduces the risk that the CMK becomes unmanageable. For more information, refer to the scenario in the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html#key-policy-default-allow-root-enable-iam">Default Key Policy</a> section of the <i> <i>AWS Key Management Service Developer Guide</i> </i>.</p> </li> <li> <p>Each statement in the key policy must contain one or more principals. The principals in the key policy must exist and be visible to AWS KMS. When you create a new AWS principal (for example, an IAM user or role), you might need to enforce a delay before including the new principal in a key policy because the new principal might not be immediately visible to AWS KMS. For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/troubleshoot_general.html#troubleshoot_general_eventual-consistency">Changes that I make are not always immediately visible</a> in the <i>AWS Identity and Access Management User Guide</i>.</p> </li> </ul> <p>If you do not provide a key policy, AWS KMS attaches a default key policy to the CMK. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html#key-policy-default">Default Key Policy</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>The key policy size limit is 32 kilobytes (32768 bytes).</p>
     #[serde(rename = "Policy")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -177,6 +750,82 @@ pub struct CreateKeyRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateKeyRequest {
+    /// Returns a builder for `CreateKeyRequest`, checking its required members when built.
+    pub fn builder() -> CreateKeyRequestBuilder {
+        CreateKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateKeyRequest`](struct.CreateKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateKeyRequestBuilder {
+    bypass_policy_lockout_safety_check: Option<bool>,
+    custom_key_store_id: Option<String>,
+    description: Option<String>,
+    key_usage: Option<KeyUsageType>,
+    origin: Option<OriginType>,
+    policy: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateKeyRequestBuilder {
+    /// Sets the [`bypass_policy_lockout_safety_check`](struct.CreateKeyRequest.html#structfield.bypass_policy_lockout_safety_check) member.
+    pub fn bypass_policy_lockout_safety_check<T: Into<bool>>(mut self, value: T) -> Self {
+        self.bypass_policy_lockout_safety_check = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_key_store_id`](struct.CreateKeyRequest.html#structfield.custom_key_store_id) member.
+    pub fn custom_key_store_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_key_store_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateKeyRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_usage`](struct.CreateKeyRequest.html#structfield.key_usage) member.
+    pub fn key_usage<T: Into<KeyUsageType>>(mut self, value: T) -> Self {
+        self.key_usage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`origin`](struct.CreateKeyRequest.html#structfield.origin) member.
+    pub fn origin<T: Into<OriginType>>(mut self, value: T) -> Self {
+        self.origin = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.CreateKeyRequest.html#structfield.policy) member.
+    pub fn policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateKeyRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateKeyRequest, ::rusoto_core::BuildError> {
+        Ok(CreateKeyRequest {
+            bypass_policy_lockout_safety_check: self.bypass_policy_lockout_safety_check,
+            custom_key_store_id: self.custom_key_store_id,
+            description: self.description,
+            key_usage: self.key_usage,
+            origin: self.origin,
+            policy: self.policy,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateKeyResponse {
@@ -197,15 +846,16 @@ pub struct CustomKeyStoresListEntry {
     /// <p>Describes the connection error. Valid values are:</p> <ul> <li> <p> <code>CLUSTER_NOT_FOUND</code> - AWS KMS cannot find the AWS CloudHSM cluster with the specified cluster ID.</p> </li> <li> <p> <code>INSUFFICIENT_CLOUDHSM_HSMS</code> - The associated AWS CloudHSM cluster does not contain any active HSMs. To connect a custom key store to its AWS CloudHSM cluster, the cluster must contain at least one active HSM.</p> </li> <li> <p> <code>INTERNAL_ERROR</code> - AWS KMS could not complete the request due to an internal error. Retry the request. For <code>ConnectCustomKeyStore</code> requests, disconnect the custom key store before trying to connect again.</p> </li> <li> <p> <code>INVALID_CREDENTIALS</code> - AWS KMS does not have the correct password for the <code>kmsuser</code> crypto user in the AWS CloudHSM cluster.</p> </li> <li> <p> <code>NETWORK_ERRORS</code> - Network errors are preventing AWS KMS from connecting to the custom key store.</p> </li> <li> <p> <code>USER_LOCKED_OUT</code> - The <code>kmsuser</code> CU account is locked out of the associated AWS CloudHSM cluster due to too many failed password attempts. Before you can connect your custom key store to its AWS CloudHSM cluster, you must change the <code>kmsuser</code> account password and update the password value for the custom key store.</p> </li> </ul> <p>For help with connection failures, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting Custom Key Stores</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "ConnectionErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_error_code: Option<String>,
+    pub connection_error_code: Option<ConnectionErrorCodeType>,
     /// <p>Indicates whether the custom key store is connected to its AWS CloudHSM cluster.</p> <p>You can create and use CMKs in your custom key stores only when its connection state is <code>CONNECTED</code>.</p> <p>The value is <code>DISCONNECTED</code> if the key store has never been connected or you use the <a>DisconnectCustomKeyStore</a> operation to disconnect it. If the value is <code>CONNECTED</code> but you are having trouble using the custom key store, make sure that its associated AWS CloudHSM cluster is active and contains at least one active HSM.</p> <p>A value of <code>FAILED</code> indicates that an attempt to connect was unsuccessful. For help resolving a connection failure, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/fix-keystore.html">Troubleshooting a Custom Key Store</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "ConnectionState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub connection_state: Option<String>,
+    pub connection_state: Option<ConnectionStateType>,
     /// <p>The date and time when the custom key store was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>A unique identifier for the custom key store.</p>
     #[serde(rename = "CustomKeyStoreId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -220,6 +870,78 @@ pub struct CustomKeyStoresListEntry {
     pub trust_anchor_certificate: Option<String>,
 }
 
+/// <p>The length of the data key. Use <code>AES_128</code> to generate a 128-bit symmetric key, or <code>AES_256</code> to generate a 256-bit symmetric key.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DataKeySpec {
+    /// <p><code>AES_256</code></p>
+    Aes256,
+    /// <p><code>AES_128</code></p>
+    Aes128,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DataKeySpec {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataKeySpec::Aes256 => "AES_256",
+            DataKeySpec::Aes128 => "AES_128",
+            DataKeySpec::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DataKeySpec {
+    fn from(value: &'a str) -> DataKeySpec {
+        match value {
+            "AES_256" => DataKeySpec::Aes256,
+            "AES_128" => DataKeySpec::Aes128,
+            _ => DataKeySpec::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataKeySpec {
+    fn from(value: String) -> DataKeySpec {
+        DataKeySpec::from(value.as_str())
+    }
+}
+
+impl From<DataKeySpec> for String {
+    fn from(value: DataKeySpec) -> String {
+        match value {
+            DataKeySpec::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DataKeySpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DataKeySpec {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataKeySpec {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DataKeySpec, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DataKeySpec::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DecryptRequest {
     /// <p>Ciphertext to be decrypted. The blob includes metadata.</p>
@@ -240,6 +962,55 @@ pub struct DecryptRequest {
     pub grant_tokens: Option<Vec<String>>,
 }
 
+impl DecryptRequest {
+    /// Returns a builder for `DecryptRequest`, checking its required members when built.
+    pub fn builder() -> DecryptRequestBuilder {
+        DecryptRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DecryptRequest`](struct.DecryptRequest.html).
+#[derive(Default, Debug)]
+pub struct DecryptRequestBuilder {
+    ciphertext_blob: Option<bytes::Bytes>,
+    encryption_context: Option<::std::collections::HashMap<String, String>>,
+    grant_tokens: Option<Vec<String>>,
+}
+
+impl DecryptRequestBuilder {
+    /// Sets the [`ciphertext_blob`](struct.DecryptRequest.html#structfield.ciphertext_blob) member.
+    pub fn ciphertext_blob<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.ciphertext_blob = Some(value.into());
+        self
+    }
+
+    /// Sets the [`encryption_context`](struct.DecryptRequest.html#structfield.encryption_context) member.
+    pub fn encryption_context<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.encryption_context = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grant_tokens`](struct.DecryptRequest.html#structfield.grant_tokens) member.
+    pub fn grant_tokens<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.grant_tokens = Some(value.into());
+        self
+    }
+
+    /// Builds the `DecryptRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DecryptRequest, ::rusoto_core::BuildError> {
+        Ok(DecryptRequest {
+            ciphertext_blob: self
+                .ciphertext_blob
+                .ok_or(::rusoto_core::BuildError::missing("ciphertext_blob"))?,
+            encryption_context: self.encryption_context,
+            grant_tokens: self.grant_tokens,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DecryptResponse {
@@ -265,6 +1036,36 @@ pub struct DeleteAliasRequest {
     pub alias_name: String,
 }
 
+impl DeleteAliasRequest {
+    /// Returns a builder for `DeleteAliasRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAliasRequestBuilder {
+        DeleteAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAliasRequest`](struct.DeleteAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAliasRequestBuilder {
+    alias_name: Option<String>,
+}
+
+impl DeleteAliasRequestBuilder {
+    /// Sets the [`alias_name`](struct.DeleteAliasRequest.html#structfield.alias_name) member.
+    pub fn alias_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.alias_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteAliasRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAliasRequest {
+            alias_name: self
+                .alias_name
+                .ok_or(::rusoto_core::BuildError::missing("alias_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteCustomKeyStoreRequest {
     /// <p>Enter the ID of the custom key store you want to delete. To find the ID of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p>
@@ -272,6 +1073,38 @@ pub struct DeleteCustomKeyStoreRequest {
     pub custom_key_store_id: String,
 }
 
+impl DeleteCustomKeyStoreRequest {
+    /// Returns a builder for `DeleteCustomKeyStoreRequest`, checking its required members when built.
+    pub fn builder() -> DeleteCustomKeyStoreRequestBuilder {
+        DeleteCustomKeyStoreRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCustomKeyStoreRequest`](struct.DeleteCustomKeyStoreRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteCustomKeyStoreRequestBuilder {
+    custom_key_store_id: Option<String>,
+}
+
+impl DeleteCustomKeyStoreRequestBuilder {
+    /// Sets the [`custom_key_store_id`](struct.DeleteCustomKeyStoreRequest.html#structfield.custom_key_store_id) member.
+    pub fn custom_key_store_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_key_store_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCustomKeyStoreRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCustomKeyStoreRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteCustomKeyStoreRequest {
+            custom_key_store_id: self
+                .custom_key_store_id
+                .ok_or(::rusoto_core::BuildError::missing("custom_key_store_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteCustomKeyStoreResponse {}
@@ -283,6 +1116,38 @@ pub struct DeleteImportedKeyMaterialRequest {
     pub key_id: String,
 }
 
+impl DeleteImportedKeyMaterialRequest {
+    /// Returns a builder for `DeleteImportedKeyMaterialRequest`, checking its required members when built.
+    pub fn builder() -> DeleteImportedKeyMaterialRequestBuilder {
+        DeleteImportedKeyMaterialRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteImportedKeyMaterialRequest`](struct.DeleteImportedKeyMaterialRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteImportedKeyMaterialRequestBuilder {
+    key_id: Option<String>,
+}
+
+impl DeleteImportedKeyMaterialRequestBuilder {
+    /// Sets the [`key_id`](struct.DeleteImportedKeyMaterialRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteImportedKeyMaterialRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteImportedKeyMaterialRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteImportedKeyMaterialRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeCustomKeyStoresRequest {
     /// <p>Gets only information about the specified custom key store. Enter the key store ID.</p> <p>By default, this operation gets information about all custom key stores in the account and region. To limit the output to a particular custom key store, you can use either the <code>CustomKeyStoreId</code> or <code>CustomKeyStoreName</code> parameter, but not both.</p>
@@ -303,6 +1168,60 @@ pub struct DescribeCustomKeyStoresRequest {
     pub marker: Option<String>,
 }
 
+impl DescribeCustomKeyStoresRequest {
+    /// Returns a builder for `DescribeCustomKeyStoresRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCustomKeyStoresRequestBuilder {
+        DescribeCustomKeyStoresRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCustomKeyStoresRequest`](struct.DescribeCustomKeyStoresRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCustomKeyStoresRequestBuilder {
+    custom_key_store_id: Option<String>,
+    custom_key_store_name: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl DescribeCustomKeyStoresRequestBuilder {
+    /// Sets the [`custom_key_store_id`](struct.DescribeCustomKeyStoresRequest.html#structfield.custom_key_store_id) member.
+    pub fn custom_key_store_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_key_store_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_key_store_name`](struct.DescribeCustomKeyStoresRequest.html#structfield.custom_key_store_name) member.
+    pub fn custom_key_store_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_key_store_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeCustomKeyStoresRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeCustomKeyStoresRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCustomKeyStoresRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCustomKeyStoresRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeCustomKeyStoresRequest {
+            custom_key_store_id: self.custom_key_store_id,
+            custom_key_store_name: self.custom_key_store_name,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeCustomKeyStoresResponse {
@@ -331,6 +1250,44 @@ pub struct DescribeKeyRequest {
     pub key_id: String,
 }
 
+impl DescribeKeyRequest {
+    /// Returns a builder for `DescribeKeyRequest`, checking its required members when built.
+    pub fn builder() -> DescribeKeyRequestBuilder {
+        DescribeKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeKeyRequest`](struct.DescribeKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeKeyRequestBuilder {
+    grant_tokens: Option<Vec<String>>,
+    key_id: Option<String>,
+}
+
+impl DescribeKeyRequestBuilder {
+    /// Sets the [`grant_tokens`](struct.DescribeKeyRequest.html#structfield.grant_tokens) member.
+    pub fn grant_tokens<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.grant_tokens = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.DescribeKeyRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeKeyRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeKeyRequest {
+            grant_tokens: self.grant_tokens,
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeKeyResponse {
@@ -347,6 +1304,36 @@ pub struct DisableKeyRequest {
     pub key_id: String,
 }
 
+impl DisableKeyRequest {
+    /// Returns a builder for `DisableKeyRequest`, checking its required members when built.
+    pub fn builder() -> DisableKeyRequestBuilder {
+        DisableKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisableKeyRequest`](struct.DisableKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct DisableKeyRequestBuilder {
+    key_id: Option<String>,
+}
+
+impl DisableKeyRequestBuilder {
+    /// Sets the [`key_id`](struct.DisableKeyRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DisableKeyRequest, ::rusoto_core::BuildError> {
+        Ok(DisableKeyRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DisableKeyRotationRequest {
     /// <p>A unique identifier for the customer master key (CMK).</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
@@ -354,6 +1341,38 @@ pub struct DisableKeyRotationRequest {
     pub key_id: String,
 }
 
+impl DisableKeyRotationRequest {
+    /// Returns a builder for `DisableKeyRotationRequest`, checking its required members when built.
+    pub fn builder() -> DisableKeyRotationRequestBuilder {
+        DisableKeyRotationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisableKeyRotationRequest`](struct.DisableKeyRotationRequest.html).
+#[derive(Default, Debug)]
+pub struct DisableKeyRotationRequestBuilder {
+    key_id: Option<String>,
+}
+
+impl DisableKeyRotationRequestBuilder {
+    /// Sets the [`key_id`](struct.DisableKeyRotationRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableKeyRotationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisableKeyRotationRequest, ::rusoto_core::BuildError> {
+        Ok(DisableKeyRotationRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DisconnectCustomKeyStoreRequest {
     /// <p>Enter the ID of the custom key store you want to disconnect. To find the ID of a custom key store, use the <a>DescribeCustomKeyStores</a> operation.</p>
@@ -361,6 +1380,38 @@ pub struct DisconnectCustomKeyStoreRequest {
     pub custom_key_store_id: String,
 }
 
+impl DisconnectCustomKeyStoreRequest {
+    /// Returns a builder for `DisconnectCustomKeyStoreRequest`, checking its required members when built.
+    pub fn builder() -> DisconnectCustomKeyStoreRequestBuilder {
+        DisconnectCustomKeyStoreRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisconnectCustomKeyStoreRequest`](struct.DisconnectCustomKeyStoreRequest.html).
+#[derive(Default, Debug)]
+pub struct DisconnectCustomKeyStoreRequestBuilder {
+    custom_key_store_id: Option<String>,
+}
+
+impl DisconnectCustomKeyStoreRequestBuilder {
+    /// Sets the [`custom_key_store_id`](struct.DisconnectCustomKeyStoreRequest.html#structfield.custom_key_store_id) member.
+    pub fn custom_key_store_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_key_store_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisconnectCustomKeyStoreRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisconnectCustomKeyStoreRequest, ::rusoto_core::BuildError> {
+        Ok(DisconnectCustomKeyStoreRequest {
+            custom_key_store_id: self
+                .custom_key_store_id
+                .ok_or(::rusoto_core::BuildError::missing("custom_key_store_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisconnectCustomKeyStoreResponse {}
@@ -372,6 +1423,36 @@ pub struct EnableKeyRequest {
     pub key_id: String,
 }
 
+impl EnableKeyRequest {
+    /// Returns a builder for `EnableKeyRequest`, checking its required members when built.
+    pub fn builder() -> EnableKeyRequestBuilder {
+        EnableKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableKeyRequest`](struct.EnableKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableKeyRequestBuilder {
+    key_id: Option<String>,
+}
+
+impl EnableKeyRequestBuilder {
+    /// Sets the [`key_id`](struct.EnableKeyRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<EnableKeyRequest, ::rusoto_core::BuildError> {
+        Ok(EnableKeyRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct EnableKeyRotationRequest {
     /// <p>A unique identifier for the customer master key (CMK).</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
@@ -379,6 +1460,38 @@ pub struct EnableKeyRotationRequest {
     pub key_id: String,
 }
 
+impl EnableKeyRotationRequest {
+    /// Returns a builder for `EnableKeyRotationRequest`, checking its required members when built.
+    pub fn builder() -> EnableKeyRotationRequestBuilder {
+        EnableKeyRotationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableKeyRotationRequest`](struct.EnableKeyRotationRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableKeyRotationRequestBuilder {
+    key_id: Option<String>,
+}
+
+impl EnableKeyRotationRequestBuilder {
+    /// Sets the [`key_id`](struct.EnableKeyRotationRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableKeyRotationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EnableKeyRotationRequest, ::rusoto_core::BuildError> {
+        Ok(EnableKeyRotationRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct EncryptRequest {
     /// <p>Name-value pair that specifies the encryption context to be used for authenticated encryption. If used here, the same value must be supplied to the <code>Decrypt</code> API or decryption will fail. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a>.</p>
@@ -402,6 +1515,65 @@ pub struct EncryptRequest {
     pub plaintext: bytes::Bytes,
 }
 
+impl EncryptRequest {
+    /// Returns a builder for `EncryptRequest`, checking its required members when built.
+    pub fn builder() -> EncryptRequestBuilder {
+        EncryptRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EncryptRequest`](struct.EncryptRequest.html).
+#[derive(Default, Debug)]
+pub struct EncryptRequestBuilder {
+    encryption_context: Option<::std::collections::HashMap<String, String>>,
+    grant_tokens: Option<Vec<String>>,
+    key_id: Option<String>,
+    plaintext: Option<bytes::Bytes>,
+}
+
+impl EncryptRequestBuilder {
+    /// Sets the [`encryption_context`](struct.EncryptRequest.html#structfield.encryption_context) member.
+    pub fn encryption_context<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.encryption_context = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grant_tokens`](struct.EncryptRequest.html#structfield.grant_tokens) member.
+    pub fn grant_tokens<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.grant_tokens = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.EncryptRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`plaintext`](struct.EncryptRequest.html#structfield.plaintext) member.
+    pub fn plaintext<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.plaintext = Some(value.into());
+        self
+    }
+
+    /// Builds the `EncryptRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<EncryptRequest, ::rusoto_core::BuildError> {
+        Ok(EncryptRequest {
+            encryption_context: self.encryption_context,
+            grant_tokens: self.grant_tokens,
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            plaintext: self
+                .plaintext
+                .ok_or(::rusoto_core::BuildError::missing("plaintext"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct EncryptResponse {
@@ -420,6 +1592,78 @@ pub struct EncryptResponse {
     pub key_id: Option<String>,
 }
 
+/// <p>Specifies whether the key material expires. The default is <code>KEY_MATERIAL_EXPIRES</code>, in which case you must include the <code>ValidTo</code> parameter. When this parameter is set to <code>KEY_MATERIAL_DOES_NOT_EXPIRE</code>, you must omit the <code>ValidTo</code> parameter.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExpirationModelType {
+    /// <p><code>KEY_MATERIAL_EXPIRES</code></p>
+    KeyMaterialExpires,
+    /// <p><code>KEY_MATERIAL_DOES_NOT_EXPIRE</code></p>
+    KeyMaterialDoesNotExpire,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExpirationModelType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExpirationModelType::KeyMaterialExpires => "KEY_MATERIAL_EXPIRES",
+            ExpirationModelType::KeyMaterialDoesNotExpire => "KEY_MATERIAL_DOES_NOT_EXPIRE",
+            ExpirationModelType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExpirationModelType {
+    fn from(value: &'a str) -> ExpirationModelType {
+        match value {
+            "KEY_MATERIAL_EXPIRES" => ExpirationModelType::KeyMaterialExpires,
+            "KEY_MATERIAL_DOES_NOT_EXPIRE" => ExpirationModelType::KeyMaterialDoesNotExpire,
+            _ => ExpirationModelType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExpirationModelType {
+    fn from(value: String) -> ExpirationModelType {
+        ExpirationModelType::from(value.as_str())
+    }
+}
+
+impl From<ExpirationModelType> for String {
+    fn from(value: ExpirationModelType) -> String {
+        match value {
+            ExpirationModelType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExpirationModelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExpirationModelType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExpirationModelType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExpirationModelType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExpirationModelType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GenerateDataKeyRequest {
     /// <p>A set of key-value pairs that represents additional authenticated data.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#encrypt_context">Encryption Context</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
@@ -436,13 +1680,78 @@ pub struct GenerateDataKeyRequest {
     /// <p>The length of the data key. Use <code>AES_128</code> to generate a 128-bit symmetric key, or <code>AES_256</code> to generate a 256-bit symmetric key.</p>
     #[serde(rename = "KeySpec")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_spec: Option<String>,
+    pub key_spec: Option<DataKeySpec>,
     /// <p>The length of the data key in bytes. For example, use the value 64 to generate a 512-bit data key (64 bytes is 512 bits). For common key lengths (128-bit and 256-bit symmetric keys), we recommend that you use the <code>KeySpec</code> field instead of this one.</p>
     #[serde(rename = "NumberOfBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub number_of_bytes: Option<i64>,
 }
 
+impl GenerateDataKeyRequest {
+    /// Returns a builder for `GenerateDataKeyRequest`, checking its required members when built.
+    pub fn builder() -> GenerateDataKeyRequestBuilder {
+        GenerateDataKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GenerateDataKeyRequest`](struct.GenerateDataKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct GenerateDataKeyRequestBuilder {
+    encryption_context: Option<::std::collections::HashMap<String, String>>,
+    grant_tokens: Option<Vec<String>>,
+    key_id: Option<String>,
+    key_spec: Option<DataKeySpec>,
+    number_of_bytes: Option<i64>,
+}
+
+impl GenerateDataKeyRequestBuilder {
+    /// Sets the [`encryption_context`](struct.GenerateDataKeyRequest.html#structfield.encryption_context) member.
+    pub fn encryption_context<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.encryption_context = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grant_tokens`](struct.GenerateDataKeyRequest.html#structfield.grant_tokens) member.
+    pub fn grant_tokens<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.grant_tokens = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.GenerateDataKeyRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_spec`](struct.GenerateDataKeyRequest.html#structfield.key_spec) member.
+    pub fn key_spec<T: Into<DataKeySpec>>(mut self, value: T) -> Self {
+        self.key_spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_bytes`](struct.GenerateDataKeyRequest.html#structfield.number_of_bytes) member.
+    pub fn number_of_bytes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_bytes = Some(value.into());
+        self
+    }
+
+    /// Builds the `GenerateDataKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GenerateDataKeyRequest, ::rusoto_core::BuildError> {
+        Ok(GenerateDataKeyRequest {
+            encryption_context: self.encryption_context,
+            grant_tokens: self.grant_tokens,
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            key_spec: self.key_spec,
+            number_of_bytes: self.number_of_bytes,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GenerateDataKeyResponse {
@@ -486,13 +1795,81 @@ pub struct GenerateDataKeyWithoutPlaintextRequest {
     /// <p>The length of the data key. Use <code>AES_128</code> to generate a 128-bit symmetric key, or <code>AES_256</code> to generate a 256-bit symmetric key.</p>
     #[serde(rename = "KeySpec")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_spec: Option<String>,
+    pub key_spec: Option<DataKeySpec>,
     /// <p>The length of the data key in bytes. For example, use the value 64 to generate a 512-bit data key (64 bytes is 512 bits). For common key lengths (128-bit and 256-bit symmetric keys), we recommend that you use the <code>KeySpec</code> field instead of this one.</p>
     #[serde(rename = "NumberOfBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub number_of_bytes: Option<i64>,
 }
 
+impl GenerateDataKeyWithoutPlaintextRequest {
+    /// Returns a builder for `GenerateDataKeyWithoutPlaintextRequest`, checking its required members when built.
+    pub fn builder() -> GenerateDataKeyWithoutPlaintextRequestBuilder {
+        GenerateDataKeyWithoutPlaintextRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GenerateDataKeyWithoutPlaintextRequest`](struct.GenerateDataKeyWithoutPlaintextRequest.html).
+#[derive(Default, Debug)]
+pub struct GenerateDataKeyWithoutPlaintextRequestBuilder {
+    encryption_context: Option<::std::collections::HashMap<String, String>>,
+    grant_tokens: Option<Vec<String>>,
+    key_id: Option<String>,
+    key_spec: Option<DataKeySpec>,
+    number_of_bytes: Option<i64>,
+}
+
+impl GenerateDataKeyWithoutPlaintextRequestBuilder {
+    /// Sets the [`encryption_context`](struct.GenerateDataKeyWithoutPlaintextRequest.html#structfield.encryption_context) member.
+    pub fn encryption_context<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.encryption_context = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grant_tokens`](struct.GenerateDataKeyWithoutPlaintextRequest.html#structfield.grant_tokens) member.
+    pub fn grant_tokens<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.grant_tokens = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.GenerateDataKeyWithoutPlaintextRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_spec`](struct.GenerateDataKeyWithoutPlaintextRequest.html#structfield.key_spec) member.
+    pub fn key_spec<T: Into<DataKeySpec>>(mut self, value: T) -> Self {
+        self.key_spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_bytes`](struct.GenerateDataKeyWithoutPlaintextRequest.html#structfield.number_of_bytes) member.
+    pub fn number_of_bytes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_bytes = Some(value.into());
+        self
+    }
+
+    /// Builds the `GenerateDataKeyWithoutPlaintextRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GenerateDataKeyWithoutPlaintextRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GenerateDataKeyWithoutPlaintextRequest {
+            encryption_context: self.encryption_context,
+            grant_tokens: self.grant_tokens,
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            key_spec: self.key_spec,
+            number_of_bytes: self.number_of_bytes,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GenerateDataKeyWithoutPlaintextResponse {
@@ -523,6 +1900,42 @@ pub struct GenerateRandomRequest {
     pub number_of_bytes: Option<i64>,
 }
 
+impl GenerateRandomRequest {
+    /// Returns a builder for `GenerateRandomRequest`, checking its required members when built.
+    pub fn builder() -> GenerateRandomRequestBuilder {
+        GenerateRandomRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GenerateRandomRequest`](struct.GenerateRandomRequest.html).
+#[derive(Default, Debug)]
+pub struct GenerateRandomRequestBuilder {
+    custom_key_store_id: Option<String>,
+    number_of_bytes: Option<i64>,
+}
+
+impl GenerateRandomRequestBuilder {
+    /// Sets the [`custom_key_store_id`](struct.GenerateRandomRequest.html#structfield.custom_key_store_id) member.
+    pub fn custom_key_store_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_key_store_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_bytes`](struct.GenerateRandomRequest.html#structfield.number_of_bytes) member.
+    pub fn number_of_bytes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_bytes = Some(value.into());
+        self
+    }
+
+    /// Builds the `GenerateRandomRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GenerateRandomRequest, ::rusoto_core::BuildError> {
+        Ok(GenerateRandomRequest {
+            custom_key_store_id: self.custom_key_store_id,
+            number_of_bytes: self.number_of_bytes,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GenerateRandomResponse {
@@ -547,6 +1960,46 @@ pub struct GetKeyPolicyRequest {
     pub policy_name: String,
 }
 
+impl GetKeyPolicyRequest {
+    /// Returns a builder for `GetKeyPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetKeyPolicyRequestBuilder {
+        GetKeyPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetKeyPolicyRequest`](struct.GetKeyPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetKeyPolicyRequestBuilder {
+    key_id: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl GetKeyPolicyRequestBuilder {
+    /// Sets the [`key_id`](struct.GetKeyPolicyRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.GetKeyPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetKeyPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetKeyPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetKeyPolicyRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetKeyPolicyResponse {
@@ -563,6 +2016,38 @@ pub struct GetKeyRotationStatusRequest {
     pub key_id: String,
 }
 
+impl GetKeyRotationStatusRequest {
+    /// Returns a builder for `GetKeyRotationStatusRequest`, checking its required members when built.
+    pub fn builder() -> GetKeyRotationStatusRequestBuilder {
+        GetKeyRotationStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetKeyRotationStatusRequest`](struct.GetKeyRotationStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct GetKeyRotationStatusRequestBuilder {
+    key_id: Option<String>,
+}
+
+impl GetKeyRotationStatusRequestBuilder {
+    /// Sets the [`key_id`](struct.GetKeyRotationStatusRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetKeyRotationStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetKeyRotationStatusRequest, ::rusoto_core::BuildError> {
+        Ok(GetKeyRotationStatusRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetKeyRotationStatusResponse {
@@ -572,17 +2057,69 @@ pub struct GetKeyRotationStatusResponse {
     pub key_rotation_enabled: Option<bool>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetParametersForImportRequest {
     /// <p>The identifier of the CMK into which you will import key material. The CMK's <code>Origin</code> must be <code>EXTERNAL</code>.</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
     /// <p>The algorithm you will use to encrypt the key material before importing it with <a>ImportKeyMaterial</a>. For more information, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys-encrypt-key-material.html">Encrypt the Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "WrappingAlgorithm")]
-    pub wrapping_algorithm: String,
+    pub wrapping_algorithm: AlgorithmSpec,
     /// <p>The type of wrapping key (public key) to return in the response. Only 2048-bit RSA public keys are supported.</p>
     #[serde(rename = "WrappingKeySpec")]
-    pub wrapping_key_spec: String,
+    pub wrapping_key_spec: WrappingKeySpec,
+}
+
+impl GetParametersForImportRequest {
+    /// Returns a builder for `GetParametersForImportRequest`, checking its required members when built.
+    pub fn builder() -> GetParametersForImportRequestBuilder {
+        GetParametersForImportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetParametersForImportRequest`](struct.GetParametersForImportRequest.html).
+#[derive(Default, Debug)]
+pub struct GetParametersForImportRequestBuilder {
+    key_id: Option<String>,
+    wrapping_algorithm: Option<AlgorithmSpec>,
+    wrapping_key_spec: Option<WrappingKeySpec>,
+}
+
+impl GetParametersForImportRequestBuilder {
+    /// Sets the [`key_id`](struct.GetParametersForImportRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`wrapping_algorithm`](struct.GetParametersForImportRequest.html#structfield.wrapping_algorithm) member.
+    pub fn wrapping_algorithm<T: Into<AlgorithmSpec>>(mut self, value: T) -> Self {
+        self.wrapping_algorithm = Some(value.into());
+        self
+    }
+
+    /// Sets the [`wrapping_key_spec`](struct.GetParametersForImportRequest.html#structfield.wrapping_key_spec) member.
+    pub fn wrapping_key_spec<T: Into<WrappingKeySpec>>(mut self, value: T) -> Self {
+        self.wrapping_key_spec = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetParametersForImportRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetParametersForImportRequest, ::rusoto_core::BuildError> {
+        Ok(GetParametersForImportRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            wrapping_algorithm: self
+                .wrapping_algorithm
+                .ok_or(::rusoto_core::BuildError::missing("wrapping_algorithm"))?,
+            wrapping_key_spec: self
+                .wrapping_key_spec
+                .ok_or(::rusoto_core::BuildError::missing("wrapping_key_spec"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -603,8 +2140,9 @@ pub struct GetParametersForImportResponse {
     pub key_id: Option<String>,
     /// <p>The time at which the import token and public key are no longer valid. After this time, you cannot use them to make an <a>ImportKeyMaterial</a> request and you must send another <code>GetParametersForImport</code> request to get new ones.</p>
     #[serde(rename = "ParametersValidTo")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameters_valid_to: Option<f64>,
+    pub parameters_valid_to: Option<EpochTimestamp>,
     /// <p>The public key to use to encrypt the key material before importing it with <a>ImportKeyMaterial</a>.</p>
     #[serde(rename = "PublicKey")]
     #[serde(
@@ -639,8 +2177,9 @@ pub struct GrantListEntry {
     pub constraints: Option<GrantConstraints>,
     /// <p>The date and time when the grant was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>The unique identifier for the grant.</p>
     #[serde(rename = "GrantId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -664,13 +2203,145 @@ pub struct GrantListEntry {
     /// <p>The list of operations permitted by the grant.</p>
     #[serde(rename = "Operations")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operations: Option<Vec<String>>,
+    pub operations: Option<Vec<GrantOperation>>,
     /// <p>The principal that can retire the grant.</p>
     #[serde(rename = "RetiringPrincipal")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retiring_principal: Option<String>,
 }
 
+/// <p>A list of operations that the grant permits.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GrantOperation {
+    /// <p><code>Decrypt</code></p>
+    Decrypt,
+    /// <p><code>Encrypt</code></p>
+    Encrypt,
+    /// <p><code>GenerateDataKey</code></p>
+    GenerateDataKey,
+    /// <p><code>GenerateDataKeyWithoutPlaintext</code></p>
+    GenerateDataKeyWithoutPlaintext,
+    /// <p><code>ReEncryptFrom</code></p>
+    ReEncryptFrom,
+    /// <p><code>ReEncryptTo</code></p>
+    ReEncryptTo,
+    /// <p><code>Sign</code></p>
+    Sign,
+    /// <p><code>Verify</code></p>
+    Verify,
+    /// <p><code>GetPublicKey</code></p>
+    GetPublicKey,
+    /// <p><code>CreateGrant</code></p>
+    CreateGrant,
+    /// <p><code>RetireGrant</code></p>
+    RetireGrant,
+    /// <p><code>DescribeKey</code></p>
+    DescribeKey,
+    /// <p><code>GenerateDataKeyPair</code></p>
+    GenerateDataKeyPair,
+    /// <p><code>GenerateDataKeyPairWithoutPlaintext</code></p>
+    GenerateDataKeyPairWithoutPlaintext,
+    /// <p><code>GenerateMac</code></p>
+    GenerateMac,
+    /// <p><code>VerifyMac</code></p>
+    VerifyMac,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl GrantOperation {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GrantOperation::Decrypt => "Decrypt",
+            GrantOperation::Encrypt => "Encrypt",
+            GrantOperation::GenerateDataKey => "GenerateDataKey",
+            GrantOperation::GenerateDataKeyWithoutPlaintext => "GenerateDataKeyWithoutPlaintext",
+            GrantOperation::ReEncryptFrom => "ReEncryptFrom",
+            GrantOperation::ReEncryptTo => "ReEncryptTo",
+            GrantOperation::Sign => "Sign",
+            GrantOperation::Verify => "Verify",
+            GrantOperation::GetPublicKey => "GetPublicKey",
+            GrantOperation::CreateGrant => "CreateGrant",
+            GrantOperation::RetireGrant => "RetireGrant",
+            GrantOperation::DescribeKey => "DescribeKey",
+            GrantOperation::GenerateDataKeyPair => "GenerateDataKeyPair",
+            GrantOperation::GenerateDataKeyPairWithoutPlaintext => {
+                "GenerateDataKeyPairWithoutPlaintext"
+            }
+            GrantOperation::GenerateMac => "GenerateMac",
+            GrantOperation::VerifyMac => "VerifyMac",
+            GrantOperation::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GrantOperation {
+    fn from(value: &'a str) -> GrantOperation {
+        match value {
+            "Decrypt" => GrantOperation::Decrypt,
+            "Encrypt" => GrantOperation::Encrypt,
+            "GenerateDataKey" => GrantOperation::GenerateDataKey,
+            "GenerateDataKeyWithoutPlaintext" => GrantOperation::GenerateDataKeyWithoutPlaintext,
+            "ReEncryptFrom" => GrantOperation::ReEncryptFrom,
+            "ReEncryptTo" => GrantOperation::ReEncryptTo,
+            "Sign" => GrantOperation::Sign,
+            "Verify" => GrantOperation::Verify,
+            "GetPublicKey" => GrantOperation::GetPublicKey,
+            "CreateGrant" => GrantOperation::CreateGrant,
+            "RetireGrant" => GrantOperation::RetireGrant,
+            "DescribeKey" => GrantOperation::DescribeKey,
+            "GenerateDataKeyPair" => GrantOperation::GenerateDataKeyPair,
+            "GenerateDataKeyPairWithoutPlaintext" => {
+                GrantOperation::GenerateDataKeyPairWithoutPlaintext
+            }
+            "GenerateMac" => GrantOperation::GenerateMac,
+            "VerifyMac" => GrantOperation::VerifyMac,
+            _ => GrantOperation::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GrantOperation {
+    fn from(value: String) -> GrantOperation {
+        GrantOperation::from(value.as_str())
+    }
+}
+
+impl From<GrantOperation> for String {
+    fn from(value: GrantOperation) -> String {
+        match value {
+            GrantOperation::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GrantOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for GrantOperation {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GrantOperation {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<GrantOperation, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(GrantOperation::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ImportKeyMaterialRequest {
     /// <p>The encrypted key material to import. It must be encrypted with the public key that you received in the response to a previous <a>GetParametersForImport</a> request, using the wrapping algorithm that you specified in that request.</p>
@@ -684,7 +2355,7 @@ pub struct ImportKeyMaterialRequest {
     /// <p>Specifies whether the key material expires. The default is <code>KEY_MATERIAL_EXPIRES</code>, in which case you must include the <code>ValidTo</code> parameter. When this parameter is set to <code>KEY_MATERIAL_DOES_NOT_EXPIRE</code>, you must omit the <code>ValidTo</code> parameter.</p>
     #[serde(rename = "ExpirationModel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_model: Option<String>,
+    pub expiration_model: Option<ExpirationModelType>,
     /// <p>The import token that you received in the response to a previous <a>GetParametersForImport</a> request. It must be from the same response that contained the public key that you used to encrypt the key material.</p>
     #[serde(rename = "ImportToken")]
     #[serde(
@@ -698,8 +2369,77 @@ pub struct ImportKeyMaterialRequest {
     pub key_id: String,
     /// <p>The time at which the imported key material expires. When the key material expires, AWS KMS deletes the key material and the CMK becomes unusable. You must omit this parameter when the <code>ExpirationModel</code> parameter is set to <code>KEY_MATERIAL_DOES_NOT_EXPIRE</code>. Otherwise it is required.</p>
     #[serde(rename = "ValidTo")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub valid_to: Option<f64>,
+    pub valid_to: Option<EpochTimestamp>,
+}
+
+impl ImportKeyMaterialRequest {
+    /// Returns a builder for `ImportKeyMaterialRequest`, checking its required members when built.
+    pub fn builder() -> ImportKeyMaterialRequestBuilder {
+        ImportKeyMaterialRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ImportKeyMaterialRequest`](struct.ImportKeyMaterialRequest.html).
+#[derive(Default, Debug)]
+pub struct ImportKeyMaterialRequestBuilder {
+    encrypted_key_material: Option<bytes::Bytes>,
+    expiration_model: Option<ExpirationModelType>,
+    import_token: Option<bytes::Bytes>,
+    key_id: Option<String>,
+    valid_to: Option<EpochTimestamp>,
+}
+
+impl ImportKeyMaterialRequestBuilder {
+    /// Sets the [`encrypted_key_material`](struct.ImportKeyMaterialRequest.html#structfield.encrypted_key_material) member.
+    pub fn encrypted_key_material<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.encrypted_key_material = Some(value.into());
+        self
+    }
+
+    /// Sets the [`expiration_model`](struct.ImportKeyMaterialRequest.html#structfield.expiration_model) member.
+    pub fn expiration_model<T: Into<ExpirationModelType>>(mut self, value: T) -> Self {
+        self.expiration_model = Some(value.into());
+        self
+    }
+
+    /// Sets the [`import_token`](struct.ImportKeyMaterialRequest.html#structfield.import_token) member.
+    pub fn import_token<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.import_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.ImportKeyMaterialRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`valid_to`](struct.ImportKeyMaterialRequest.html#structfield.valid_to) member.
+    pub fn valid_to<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.valid_to = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportKeyMaterialRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ImportKeyMaterialRequest, ::rusoto_core::BuildError> {
+        Ok(ImportKeyMaterialRequest {
+            encrypted_key_material: self
+                .encrypted_key_material
+                .ok_or(::rusoto_core::BuildError::missing("encrypted_key_material"))?,
+            expiration_model: self.expiration_model,
+            import_token: self
+                .import_token
+                .ok_or(::rusoto_core::BuildError::missing("import_token"))?,
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            valid_to: self.valid_to,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -720,6 +2460,78 @@ pub struct KeyListEntry {
     pub key_id: Option<String>,
 }
 
+/// <p>The manager of the CMK. CMKs in your AWS account are either customer managed or AWS managed. For more information about the difference, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeyManagerType {
+    /// <p><code>AWS</code></p>
+    Aws,
+    /// <p><code>CUSTOMER</code></p>
+    Customer,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl KeyManagerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyManagerType::Aws => "AWS",
+            KeyManagerType::Customer => "CUSTOMER",
+            KeyManagerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for KeyManagerType {
+    fn from(value: &'a str) -> KeyManagerType {
+        match value {
+            "AWS" => KeyManagerType::Aws,
+            "CUSTOMER" => KeyManagerType::Customer,
+            _ => KeyManagerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyManagerType {
+    fn from(value: String) -> KeyManagerType {
+        KeyManagerType::from(value.as_str())
+    }
+}
+
+impl From<KeyManagerType> for String {
+    fn from(value: KeyManagerType) -> String {
+        match value {
+            KeyManagerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for KeyManagerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for KeyManagerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyManagerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<KeyManagerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(KeyManagerType::from(value))
+    }
+}
+
 /// <p>Contains metadata about a customer master key (CMK).</p> <p>This data type is used as a response element for the <a>CreateKey</a> and <a>DescribeKey</a> operations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -738,16 +2550,18 @@ pub struct KeyMetadata {
     pub cloud_hsm_cluster_id: Option<String>,
     /// <p>The date and time when the CMK was created.</p>
     #[serde(rename = "CreationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date: Option<f64>,
+    pub creation_date: Option<EpochTimestamp>,
     /// <p>A unique identifier for the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> that contains the CMK. This value is present only when the CMK is created in a custom key store.</p>
     #[serde(rename = "CustomKeyStoreId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_key_store_id: Option<String>,
     /// <p>The date and time after which AWS KMS deletes the CMK. This value is present only when <code>KeyState</code> is <code>PendingDeletion</code>.</p>
     #[serde(rename = "DeletionDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_date: Option<f64>,
+    pub deletion_date: Option<EpochTimestamp>,
     /// <p>The description of the CMK.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -759,30 +2573,203 @@ pub struct KeyMetadata {
     /// <p>Specifies whether the CMK's key material expires. This value is present only when <code>Origin</code> is <code>EXTERNAL</code>, otherwise this value is omitted.</p>
     #[serde(rename = "ExpirationModel")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_model: Option<String>,
+    pub expiration_model: Option<ExpirationModelType>,
     /// <p>The globally unique identifier for the CMK.</p>
     #[serde(rename = "KeyId")]
     pub key_id: String,
     /// <p>The manager of the CMK. CMKs in your AWS account are either customer managed or AWS managed. For more information about the difference, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#master_keys">Customer Master Keys</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "KeyManager")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_manager: Option<String>,
+    pub key_manager: Option<KeyManagerType>,
     /// <p>The state of the CMK.</p> <p>For more information about how key state affects the use of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects the Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
     #[serde(rename = "KeyState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_state: Option<String>,
+    pub key_state: Option<KeyState>,
     /// <p>The cryptographic operations for which you can use the CMK. The only valid value is <code>ENCRYPT_DECRYPT</code>, which means you can use the CMK to encrypt and decrypt data.</p>
     #[serde(rename = "KeyUsage")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub key_usage: Option<String>,
+    pub key_usage: Option<KeyUsageType>,
     /// <p>The source of the CMK's key material. When this value is <code>AWS_KMS</code>, AWS KMS created the key material. When this value is <code>EXTERNAL</code>, the key material was imported from your existing key management infrastructure or the CMK lacks key material. When this value is <code>AWS_CLOUDHSM</code>, the key material was created in the AWS CloudHSM cluster associated with a custom key store.</p>
     #[serde(rename = "Origin")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origin: Option<String>,
+    pub origin: Option<OriginType>,
     /// <p>The time at which the imported key material expires. When the key material expires, AWS KMS deletes the key material and the CMK becomes unusable. This value is present only for CMKs whose <code>Origin</code> is <code>EXTERNAL</code> and whose <code>ExpirationModel</code> is <code>KEY_MATERIAL_EXPIRES</code>, otherwise this value is omitted.</p>
     #[serde(rename = "ValidTo")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub valid_to: Option<f64>,
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub valid_to: Option<EpochTimestamp>,
+}
+
+/// <p>The state of the CMK.</p> <p>For more information about how key state affects the use of a CMK, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects the Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeyState {
+    /// <p><code>Creating</code></p>
+    Creating,
+    /// <p><code>Enabled</code></p>
+    Enabled,
+    /// <p><code>Disabled</code></p>
+    Disabled,
+    /// <p><code>PendingDeletion</code></p>
+    PendingDeletion,
+    /// <p><code>PendingImport</code></p>
+    PendingImport,
+    /// <p><code>PendingReplicaDeletion</code></p>
+    PendingReplicaDeletion,
+    /// <p><code>Unavailable</code></p>
+    Unavailable,
+    /// <p><code>Updating</code></p>
+    Updating,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl KeyState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyState::Creating => "Creating",
+            KeyState::Enabled => "Enabled",
+            KeyState::Disabled => "Disabled",
+            KeyState::PendingDeletion => "PendingDeletion",
+            KeyState::PendingImport => "PendingImport",
+            KeyState::PendingReplicaDeletion => "PendingReplicaDeletion",
+            KeyState::Unavailable => "Unavailable",
+            KeyState::Updating => "Updating",
+            KeyState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for KeyState {
+    fn from(value: &'a str) -> KeyState {
+        match value {
+            "Creating" => KeyState::Creating,
+            "Enabled" => KeyState::Enabled,
+            "Disabled" => KeyState::Disabled,
+            "PendingDeletion" => KeyState::PendingDeletion,
+            "PendingImport" => KeyState::PendingImport,
+            "PendingReplicaDeletion" => KeyState::PendingReplicaDeletion,
+            "Unavailable" => KeyState::Unavailable,
+            "Updating" => KeyState::Updating,
+            _ => KeyState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyState {
+    fn from(value: String) -> KeyState {
+        KeyState::from(value.as_str())
+    }
+}
+
+impl From<KeyState> for String {
+    fn from(value: KeyState) -> String {
+        match value {
+            KeyState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for KeyState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for KeyState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<KeyState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(KeyState::from(value))
+    }
+}
+
+/// <p>The cryptographic operations for which you can use the CMK. The only valid value is <code>ENCRYPT_DECRYPT</code>, which means you can use the CMK to encrypt and decrypt data.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeyUsageType {
+    /// <p><code>SIGN_VERIFY</code></p>
+    SignVerify,
+    /// <p><code>ENCRYPT_DECRYPT</code></p>
+    EncryptDecrypt,
+    /// <p><code>GENERATE_VERIFY_MAC</code></p>
+    GenerateVerifyMac,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl KeyUsageType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            KeyUsageType::SignVerify => "SIGN_VERIFY",
+            KeyUsageType::EncryptDecrypt => "ENCRYPT_DECRYPT",
+            KeyUsageType::GenerateVerifyMac => "GENERATE_VERIFY_MAC",
+            KeyUsageType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for KeyUsageType {
+    fn from(value: &'a str) -> KeyUsageType {
+        match value {
+            "SIGN_VERIFY" => KeyUsageType::SignVerify,
+            "ENCRYPT_DECRYPT" => KeyUsageType::EncryptDecrypt,
+            "GENERATE_VERIFY_MAC" => KeyUsageType::GenerateVerifyMac,
+            _ => KeyUsageType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for KeyUsageType {
+    fn from(value: String) -> KeyUsageType {
+        KeyUsageType::from(value.as_str())
+    }
+}
+
+impl From<KeyUsageType> for String {
+    fn from(value: KeyUsageType) -> String {
+        match value {
+            KeyUsageType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for KeyUsageType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for KeyUsageType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for KeyUsageType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<KeyUsageType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(KeyUsageType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -801,6 +2788,50 @@ pub struct ListAliasesRequest {
     pub marker: Option<String>,
 }
 
+impl ListAliasesRequest {
+    /// Returns a builder for `ListAliasesRequest`, checking its required members when built.
+    pub fn builder() -> ListAliasesRequestBuilder {
+        ListAliasesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAliasesRequest`](struct.ListAliasesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAliasesRequestBuilder {
+    key_id: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl ListAliasesRequestBuilder {
+    /// Sets the [`key_id`](struct.ListAliasesRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListAliasesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListAliasesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAliasesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListAliasesRequest, ::rusoto_core::BuildError> {
+        Ok(ListAliasesRequest {
+            key_id: self.key_id,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAliasesResponse {
@@ -833,6 +2864,52 @@ pub struct ListGrantsRequest {
     pub marker: Option<String>,
 }
 
+impl ListGrantsRequest {
+    /// Returns a builder for `ListGrantsRequest`, checking its required members when built.
+    pub fn builder() -> ListGrantsRequestBuilder {
+        ListGrantsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListGrantsRequest`](struct.ListGrantsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListGrantsRequestBuilder {
+    key_id: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl ListGrantsRequestBuilder {
+    /// Sets the [`key_id`](struct.ListGrantsRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListGrantsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListGrantsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGrantsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListGrantsRequest, ::rusoto_core::BuildError> {
+        Ok(ListGrantsRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGrantsResponse {
@@ -865,6 +2942,52 @@ pub struct ListKeyPoliciesRequest {
     pub marker: Option<String>,
 }
 
+impl ListKeyPoliciesRequest {
+    /// Returns a builder for `ListKeyPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListKeyPoliciesRequestBuilder {
+        ListKeyPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListKeyPoliciesRequest`](struct.ListKeyPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListKeyPoliciesRequestBuilder {
+    key_id: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl ListKeyPoliciesRequestBuilder {
+    /// Sets the [`key_id`](struct.ListKeyPoliciesRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListKeyPoliciesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListKeyPoliciesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListKeyPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListKeyPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListKeyPoliciesRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListKeyPoliciesResponse {
@@ -894,6 +3017,42 @@ pub struct ListKeysRequest {
     pub marker: Option<String>,
 }
 
+impl ListKeysRequest {
+    /// Returns a builder for `ListKeysRequest`, checking its required members when built.
+    pub fn builder() -> ListKeysRequestBuilder {
+        ListKeysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListKeysRequest`](struct.ListKeysRequest.html).
+#[derive(Default, Debug)]
+pub struct ListKeysRequestBuilder {
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl ListKeysRequestBuilder {
+    /// Sets the [`limit`](struct.ListKeysRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListKeysRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListKeysRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListKeysRequest, ::rusoto_core::BuildError> {
+        Ok(ListKeysRequest {
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListKeysResponse {
@@ -926,6 +3085,54 @@ pub struct ListResourceTagsRequest {
     pub marker: Option<String>,
 }
 
+impl ListResourceTagsRequest {
+    /// Returns a builder for `ListResourceTagsRequest`, checking its required members when built.
+    pub fn builder() -> ListResourceTagsRequestBuilder {
+        ListResourceTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResourceTagsRequest`](struct.ListResourceTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResourceTagsRequestBuilder {
+    key_id: Option<String>,
+    limit: Option<i64>,
+    marker: Option<String>,
+}
+
+impl ListResourceTagsRequestBuilder {
+    /// Sets the [`key_id`](struct.ListResourceTagsRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.ListResourceTagsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListResourceTagsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResourceTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListResourceTagsRequest, ::rusoto_core::BuildError> {
+        Ok(ListResourceTagsRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            limit: self.limit,
+            marker: self.marker,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResourceTagsResponse {
@@ -958,6 +3165,134 @@ pub struct ListRetirableGrantsRequest {
     pub retiring_principal: String,
 }
 
+impl ListRetirableGrantsRequest {
+    /// Returns a builder for `ListRetirableGrantsRequest`, checking its required members when built.
+    pub fn builder() -> ListRetirableGrantsRequestBuilder {
+        ListRetirableGrantsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRetirableGrantsRequest`](struct.ListRetirableGrantsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRetirableGrantsRequestBuilder {
+    limit: Option<i64>,
+    marker: Option<String>,
+    retiring_principal: Option<String>,
+}
+
+impl ListRetirableGrantsRequestBuilder {
+    /// Sets the [`limit`](struct.ListRetirableGrantsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListRetirableGrantsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retiring_principal`](struct.ListRetirableGrantsRequest.html#structfield.retiring_principal) member.
+    pub fn retiring_principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.retiring_principal = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRetirableGrantsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListRetirableGrantsRequest, ::rusoto_core::BuildError> {
+        Ok(ListRetirableGrantsRequest {
+            limit: self.limit,
+            marker: self.marker,
+            retiring_principal: self
+                .retiring_principal
+                .ok_or(::rusoto_core::BuildError::missing("retiring_principal"))?,
+        })
+    }
+}
+
+/// <p>The source of the key material for the CMK. You cannot change the origin after you create the CMK.</p> <p>The default is <code>AWS_KMS</code>, which means AWS KMS creates the key material in its own key store.</p> <p>When the parameter value is <code>EXTERNAL</code>, AWS KMS creates a CMK without key material so that you can import key material from your existing key management infrastructure. For more information about importing key material into AWS KMS, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/importing-keys.html">Importing Key Material</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>When the parameter value is <code>AWS_CLOUDHSM</code>, AWS KMS creates the CMK in an AWS KMS <a href="https://docs.aws.amazon.com/kms/latest/developerguide/custom-key-store-overview.html">custom key store</a> and creates its key material in the associated AWS CloudHSM cluster. You must also use the <code>CustomKeyStoreId</code> parameter to identify the custom key store.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OriginType {
+    /// <p><code>AWS_KMS</code></p>
+    AwsKms,
+    /// <p><code>EXTERNAL</code></p>
+    External,
+    /// <p><code>AWS_CLOUDHSM</code></p>
+    AwsCloudhsm,
+    /// <p><code>EXTERNAL_KEY_STORE</code></p>
+    ExternalKeyStore,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OriginType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OriginType::AwsKms => "AWS_KMS",
+            OriginType::External => "EXTERNAL",
+            OriginType::AwsCloudhsm => "AWS_CLOUDHSM",
+            OriginType::ExternalKeyStore => "EXTERNAL_KEY_STORE",
+            OriginType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OriginType {
+    fn from(value: &'a str) -> OriginType {
+        match value {
+            "AWS_KMS" => OriginType::AwsKms,
+            "EXTERNAL" => OriginType::External,
+            "AWS_CLOUDHSM" => OriginType::AwsCloudhsm,
+            "EXTERNAL_KEY_STORE" => OriginType::ExternalKeyStore,
+            _ => OriginType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OriginType {
+    fn from(value: String) -> OriginType {
+        OriginType::from(value.as_str())
+    }
+}
+
+impl From<OriginType> for String {
+    fn from(value: OriginType) -> String {
+        match value {
+            OriginType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OriginType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OriginType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OriginType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OriginType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OriginType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutKeyPolicyRequest {
     /// <p>A flag to indicate whether to bypass the key policy lockout safety check.</p> <important> <p>Setting this value to true increases the risk that the CMK becomes unmanageable. Do not set this value to true indiscriminately.</p> <p>For more information, refer to the scenario in the <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-policies.html#key-policy-default-allow-root-enable-iam">Default Key Policy</a> section in the <i>AWS Key Management Service Developer Guide</i>.</p> </important> <p>Use this parameter only when you intend to prevent the principal that is making the request from making a subsequent <code>PutKeyPolicy</code> request on the CMK.</p> <p>The default value is false.</p>
@@ -975,6 +3310,64 @@ pub struct PutKeyPolicyRequest {
     pub policy_name: String,
 }
 
+impl PutKeyPolicyRequest {
+    /// Returns a builder for `PutKeyPolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutKeyPolicyRequestBuilder {
+        PutKeyPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutKeyPolicyRequest`](struct.PutKeyPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutKeyPolicyRequestBuilder {
+    bypass_policy_lockout_safety_check: Option<bool>,
+    key_id: Option<String>,
+    policy: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl PutKeyPolicyRequestBuilder {
+    /// Sets the [`bypass_policy_lockout_safety_check`](struct.PutKeyPolicyRequest.html#structfield.bypass_policy_lockout_safety_check) member.
+    pub fn bypass_policy_lockout_safety_check<T: Into<bool>>(mut self, value: T) -> Self {
+        self.bypass_policy_lockout_safety_check = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.PutKeyPolicyRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.PutKeyPolicyRequest.html#structfield.policy) member.
+    pub fn policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.PutKeyPolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutKeyPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutKeyPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutKeyPolicyRequest {
+            bypass_policy_lockout_safety_check: self.bypass_policy_lockout_safety_check,
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            policy: self
+                .policy
+                .ok_or(::rusoto_core::BuildError::missing("policy"))?,
+            policy_name: self
+                .policy_name
+                .ok_or(::rusoto_core::BuildError::missing("policy_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ReEncryptRequest {
     /// <p>Ciphertext of the data to reencrypt.</p>
@@ -1002,6 +3395,76 @@ pub struct ReEncryptRequest {
     pub source_encryption_context: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl ReEncryptRequest {
+    /// Returns a builder for `ReEncryptRequest`, checking its required members when built.
+    pub fn builder() -> ReEncryptRequestBuilder {
+        ReEncryptRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ReEncryptRequest`](struct.ReEncryptRequest.html).
+#[derive(Default, Debug)]
+pub struct ReEncryptRequestBuilder {
+    ciphertext_blob: Option<bytes::Bytes>,
+    destination_encryption_context: Option<::std::collections::HashMap<String, String>>,
+    destination_key_id: Option<String>,
+    grant_tokens: Option<Vec<String>>,
+    source_encryption_context: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl ReEncryptRequestBuilder {
+    /// Sets the [`ciphertext_blob`](struct.ReEncryptRequest.html#structfield.ciphertext_blob) member.
+    pub fn ciphertext_blob<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.ciphertext_blob = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_encryption_context`](struct.ReEncryptRequest.html#structfield.destination_encryption_context) member.
+    pub fn destination_encryption_context<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.destination_encryption_context = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_key_id`](struct.ReEncryptRequest.html#structfield.destination_key_id) member.
+    pub fn destination_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grant_tokens`](struct.ReEncryptRequest.html#structfield.grant_tokens) member.
+    pub fn grant_tokens<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.grant_tokens = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_encryption_context`](struct.ReEncryptRequest.html#structfield.source_encryption_context) member.
+    pub fn source_encryption_context<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.source_encryption_context = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReEncryptRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ReEncryptRequest, ::rusoto_core::BuildError> {
+        Ok(ReEncryptRequest {
+            ciphertext_blob: self
+                .ciphertext_blob
+                .ok_or(::rusoto_core::BuildError::missing("ciphertext_blob"))?,
+            destination_encryption_context: self.destination_encryption_context,
+            destination_key_id: self
+                .destination_key_id
+                .ok_or(::rusoto_core::BuildError::missing("destination_key_id"))?,
+            grant_tokens: self.grant_tokens,
+            source_encryption_context: self.source_encryption_context,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ReEncryptResponse {
@@ -1040,6 +3503,50 @@ pub struct RetireGrantRequest {
     pub key_id: Option<String>,
 }
 
+impl RetireGrantRequest {
+    /// Returns a builder for `RetireGrantRequest`, checking its required members when built.
+    pub fn builder() -> RetireGrantRequestBuilder {
+        RetireGrantRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RetireGrantRequest`](struct.RetireGrantRequest.html).
+#[derive(Default, Debug)]
+pub struct RetireGrantRequestBuilder {
+    grant_id: Option<String>,
+    grant_token: Option<String>,
+    key_id: Option<String>,
+}
+
+impl RetireGrantRequestBuilder {
+    /// Sets the [`grant_id`](struct.RetireGrantRequest.html#structfield.grant_id) member.
+    pub fn grant_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.grant_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`grant_token`](struct.RetireGrantRequest.html#structfield.grant_token) member.
+    pub fn grant_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.grant_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.RetireGrantRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RetireGrantRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RetireGrantRequest, ::rusoto_core::BuildError> {
+        Ok(RetireGrantRequest {
+            grant_id: self.grant_id,
+            grant_token: self.grant_token,
+            key_id: self.key_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RevokeGrantRequest {
     /// <p>Identifier of the grant to be revoked.</p>
@@ -1050,6 +3557,46 @@ pub struct RevokeGrantRequest {
     pub key_id: String,
 }
 
+impl RevokeGrantRequest {
+    /// Returns a builder for `RevokeGrantRequest`, checking its required members when built.
+    pub fn builder() -> RevokeGrantRequestBuilder {
+        RevokeGrantRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RevokeGrantRequest`](struct.RevokeGrantRequest.html).
+#[derive(Default, Debug)]
+pub struct RevokeGrantRequestBuilder {
+    grant_id: Option<String>,
+    key_id: Option<String>,
+}
+
+impl RevokeGrantRequestBuilder {
+    /// Sets the [`grant_id`](struct.RevokeGrantRequest.html#structfield.grant_id) member.
+    pub fn grant_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.grant_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.RevokeGrantRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RevokeGrantRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RevokeGrantRequest, ::rusoto_core::BuildError> {
+        Ok(RevokeGrantRequest {
+            grant_id: self
+                .grant_id
+                .ok_or(::rusoto_core::BuildError::missing("grant_id"))?,
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ScheduleKeyDeletionRequest {
     /// <p>The unique identifier of the customer master key (CMK) to delete.</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
@@ -1061,13 +3608,54 @@ pub struct ScheduleKeyDeletionRequest {
     pub pending_window_in_days: Option<i64>,
 }
 
+impl ScheduleKeyDeletionRequest {
+    /// Returns a builder for `ScheduleKeyDeletionRequest`, checking its required members when built.
+    pub fn builder() -> ScheduleKeyDeletionRequestBuilder {
+        ScheduleKeyDeletionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ScheduleKeyDeletionRequest`](struct.ScheduleKeyDeletionRequest.html).
+#[derive(Default, Debug)]
+pub struct ScheduleKeyDeletionRequestBuilder {
+    key_id: Option<String>,
+    pending_window_in_days: Option<i64>,
+}
+
+impl ScheduleKeyDeletionRequestBuilder {
+    /// Sets the [`key_id`](struct.ScheduleKeyDeletionRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pending_window_in_days`](struct.ScheduleKeyDeletionRequest.html#structfield.pending_window_in_days) member.
+    pub fn pending_window_in_days<T: Into<i64>>(mut self, value: T) -> Self {
+        self.pending_window_in_days = Some(value.into());
+        self
+    }
+
+    /// Builds the `ScheduleKeyDeletionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ScheduleKeyDeletionRequest, ::rusoto_core::BuildError> {
+        Ok(ScheduleKeyDeletionRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            pending_window_in_days: self.pending_window_in_days,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ScheduleKeyDeletionResponse {
     /// <p>The date and time after which AWS KMS deletes the customer master key (CMK).</p>
     #[serde(rename = "DeletionDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deletion_date: Option<f64>,
+    pub deletion_date: Option<EpochTimestamp>,
     /// <p>The unique identifier of the customer master key (CMK) for which deletion is scheduled.</p>
     #[serde(rename = "KeyId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1095,6 +3683,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    key_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`key_id`](struct.TagResourceRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
     /// <p>A unique identifier for the CMK from which you are removing tags.</p> <p>Specify the key ID or the Amazon Resource Name (ARN) of the CMK.</p> <p>For example:</p> <ul> <li> <p>Key ID: <code>1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> <li> <p>Key ARN: <code>arn:aws:kms:us-east-2:111122223333:key/1234abcd-12ab-34cd-56ef-1234567890ab</code> </p> </li> </ul> <p>To get the key ID and key ARN for a CMK, use <a>ListKeys</a> or <a>DescribeKey</a>.</p>
@@ -1105,6 +3733,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    key_id: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`key_id`](struct.UntagResourceRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateAliasRequest {
     /// <p>Specifies the name of the alias to change. This value must begin with <code>alias/</code> followed by the alias name, such as <code>alias/ExampleAlias</code>.</p>
@@ -1115,6 +3783,46 @@ pub struct UpdateAliasRequest {
     pub target_key_id: String,
 }
 
+impl UpdateAliasRequest {
+    /// Returns a builder for `UpdateAliasRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAliasRequestBuilder {
+        UpdateAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAliasRequest`](struct.UpdateAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAliasRequestBuilder {
+    alias_name: Option<String>,
+    target_key_id: Option<String>,
+}
+
+impl UpdateAliasRequestBuilder {
+    /// Sets the [`alias_name`](struct.UpdateAliasRequest.html#structfield.alias_name) member.
+    pub fn alias_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.alias_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_key_id`](struct.UpdateAliasRequest.html#structfield.target_key_id) member.
+    pub fn target_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateAliasRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAliasRequest {
+            alias_name: self
+                .alias_name
+                .ok_or(::rusoto_core::BuildError::missing("alias_name"))?,
+            target_key_id: self
+                .target_key_id
+                .ok_or(::rusoto_core::BuildError::missing("target_key_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateCustomKeyStoreRequest {
     /// <p>Associates the custom key store with a related AWS CloudHSM cluster. </p> <p>Enter the cluster ID of the cluster that you used to create the custom key store or a cluster that shares a backup history and has the same cluster certificate as the original cluster. You cannot use this parameter to associate a custom key store with an unrelated cluster. In addition, the replacement cluster must <a href="https://docs.aws.amazon.com/kms/latest/developerguide/create-keystore.html#before-keystore">fulfill the requirements</a> for a cluster associated with a custom key store. To view the cluster certificate of a cluster, use the <a href="https://docs.aws.amazon.com/cloudhsm/latest/APIReference/API_DescribeClusters.html">DescribeClusters</a> operation.</p>
@@ -1134,6 +3842,62 @@ pub struct UpdateCustomKeyStoreRequest {
     pub new_custom_key_store_name: Option<String>,
 }
 
+impl UpdateCustomKeyStoreRequest {
+    /// Returns a builder for `UpdateCustomKeyStoreRequest`, checking its required members when built.
+    pub fn builder() -> UpdateCustomKeyStoreRequestBuilder {
+        UpdateCustomKeyStoreRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateCustomKeyStoreRequest`](struct.UpdateCustomKeyStoreRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateCustomKeyStoreRequestBuilder {
+    cloud_hsm_cluster_id: Option<String>,
+    custom_key_store_id: Option<String>,
+    key_store_password: Option<String>,
+    new_custom_key_store_name: Option<String>,
+}
+
+impl UpdateCustomKeyStoreRequestBuilder {
+    /// Sets the [`cloud_hsm_cluster_id`](struct.UpdateCustomKeyStoreRequest.html#structfield.cloud_hsm_cluster_id) member.
+    pub fn cloud_hsm_cluster_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.cloud_hsm_cluster_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_key_store_id`](struct.UpdateCustomKeyStoreRequest.html#structfield.custom_key_store_id) member.
+    pub fn custom_key_store_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_key_store_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_store_password`](struct.UpdateCustomKeyStoreRequest.html#structfield.key_store_password) member.
+    pub fn key_store_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_store_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_custom_key_store_name`](struct.UpdateCustomKeyStoreRequest.html#structfield.new_custom_key_store_name) member.
+    pub fn new_custom_key_store_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_custom_key_store_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateCustomKeyStoreRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateCustomKeyStoreRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateCustomKeyStoreRequest {
+            cloud_hsm_cluster_id: self.cloud_hsm_cluster_id,
+            custom_key_store_id: self
+                .custom_key_store_id
+                .ok_or(::rusoto_core::BuildError::missing("custom_key_store_id"))?,
+            key_store_password: self.key_store_password,
+            new_custom_key_store_name: self.new_custom_key_store_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateCustomKeyStoreResponse {}
@@ -1148,6 +3912,124 @@ pub struct UpdateKeyDescriptionRequest {
     pub key_id: String,
 }
 
+impl UpdateKeyDescriptionRequest {
+    /// Returns a builder for `UpdateKeyDescriptionRequest`, checking its required members when built.
+    pub fn builder() -> UpdateKeyDescriptionRequestBuilder {
+        UpdateKeyDescriptionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateKeyDescriptionRequest`](struct.UpdateKeyDescriptionRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateKeyDescriptionRequestBuilder {
+    description: Option<String>,
+    key_id: Option<String>,
+}
+
+impl UpdateKeyDescriptionRequestBuilder {
+    /// Sets the [`description`](struct.UpdateKeyDescriptionRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_id`](struct.UpdateKeyDescriptionRequest.html#structfield.key_id) member.
+    pub fn key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateKeyDescriptionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateKeyDescriptionRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateKeyDescriptionRequest {
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            key_id: self
+                .key_id
+                .ok_or(::rusoto_core::BuildError::missing("key_id"))?,
+        })
+    }
+}
+
+/// <p>The type of wrapping key (public key) to return in the response. Only 2048-bit RSA public keys are supported.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WrappingKeySpec {
+    /// <p><code>RSA_2048</code></p>
+    Rsa2048,
+    /// <p><code>RSA_3072</code></p>
+    Rsa3072,
+    /// <p><code>RSA_4096</code></p>
+    Rsa4096,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl WrappingKeySpec {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            WrappingKeySpec::Rsa2048 => "RSA_2048",
+            WrappingKeySpec::Rsa3072 => "RSA_3072",
+            WrappingKeySpec::Rsa4096 => "RSA_4096",
+            WrappingKeySpec::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for WrappingKeySpec {
+    fn from(value: &'a str) -> WrappingKeySpec {
+        match value {
+            "RSA_2048" => WrappingKeySpec::Rsa2048,
+            "RSA_3072" => WrappingKeySpec::Rsa3072,
+            "RSA_4096" => WrappingKeySpec::Rsa4096,
+            _ => WrappingKeySpec::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for WrappingKeySpec {
+    fn from(value: String) -> WrappingKeySpec {
+        WrappingKeySpec::from(value.as_str())
+    }
+}
+
+impl From<WrappingKeySpec> for String {
+    fn from(value: WrappingKeySpec) -> String {
+        match value {
+            WrappingKeySpec::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for WrappingKeySpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for WrappingKeySpec {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for WrappingKeySpec {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<WrappingKeySpec, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(WrappingKeySpec::from(value))
+    }
+}
+
 /// Errors returned by CancelKeyDeletion
 #[derive(Debug, PartialEq)]
 pub enum CancelKeyDeletionError {
@@ -4034,6 +6916,223 @@ pub trait Kms {
         input: UpdateKeyDescriptionRequest,
     ) -> RusotoFuture<(), UpdateKeyDescriptionError>;
 }
+impl<T: ?Sized + Kms> Kms for ::std::sync::Arc<T> {
+    fn cancel_key_deletion(
+        &self,
+        input: CancelKeyDeletionRequest,
+    ) -> RusotoFuture<CancelKeyDeletionResponse, CancelKeyDeletionError> {
+        (**self).cancel_key_deletion(input)
+    }
+    fn connect_custom_key_store(
+        &self,
+        input: ConnectCustomKeyStoreRequest,
+    ) -> RusotoFuture<ConnectCustomKeyStoreResponse, ConnectCustomKeyStoreError> {
+        (**self).connect_custom_key_store(input)
+    }
+    fn create_alias(&self, input: CreateAliasRequest) -> RusotoFuture<(), CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_custom_key_store(
+        &self,
+        input: CreateCustomKeyStoreRequest,
+    ) -> RusotoFuture<CreateCustomKeyStoreResponse, CreateCustomKeyStoreError> {
+        (**self).create_custom_key_store(input)
+    }
+    fn create_grant(
+        &self,
+        input: CreateGrantRequest,
+    ) -> RusotoFuture<CreateGrantResponse, CreateGrantError> {
+        (**self).create_grant(input)
+    }
+    fn create_key(
+        &self,
+        input: CreateKeyRequest,
+    ) -> RusotoFuture<CreateKeyResponse, CreateKeyError> {
+        (**self).create_key(input)
+    }
+    fn decrypt(&self, input: DecryptRequest) -> RusotoFuture<DecryptResponse, DecryptError> {
+        (**self).decrypt(input)
+    }
+    fn delete_alias(&self, input: DeleteAliasRequest) -> RusotoFuture<(), DeleteAliasError> {
+        (**self).delete_alias(input)
+    }
+    fn delete_custom_key_store(
+        &self,
+        input: DeleteCustomKeyStoreRequest,
+    ) -> RusotoFuture<DeleteCustomKeyStoreResponse, DeleteCustomKeyStoreError> {
+        (**self).delete_custom_key_store(input)
+    }
+    fn delete_imported_key_material(
+        &self,
+        input: DeleteImportedKeyMaterialRequest,
+    ) -> RusotoFuture<(), DeleteImportedKeyMaterialError> {
+        (**self).delete_imported_key_material(input)
+    }
+    fn describe_custom_key_stores(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> RusotoFuture<DescribeCustomKeyStoresResponse, DescribeCustomKeyStoresError> {
+        (**self).describe_custom_key_stores(input)
+    }
+    fn describe_key(
+        &self,
+        input: DescribeKeyRequest,
+    ) -> RusotoFuture<DescribeKeyResponse, DescribeKeyError> {
+        (**self).describe_key(input)
+    }
+    fn disable_key(&self, input: DisableKeyRequest) -> RusotoFuture<(), DisableKeyError> {
+        (**self).disable_key(input)
+    }
+    fn disable_key_rotation(
+        &self,
+        input: DisableKeyRotationRequest,
+    ) -> RusotoFuture<(), DisableKeyRotationError> {
+        (**self).disable_key_rotation(input)
+    }
+    fn disconnect_custom_key_store(
+        &self,
+        input: DisconnectCustomKeyStoreRequest,
+    ) -> RusotoFuture<DisconnectCustomKeyStoreResponse, DisconnectCustomKeyStoreError> {
+        (**self).disconnect_custom_key_store(input)
+    }
+    fn enable_key(&self, input: EnableKeyRequest) -> RusotoFuture<(), EnableKeyError> {
+        (**self).enable_key(input)
+    }
+    fn enable_key_rotation(
+        &self,
+        input: EnableKeyRotationRequest,
+    ) -> RusotoFuture<(), EnableKeyRotationError> {
+        (**self).enable_key_rotation(input)
+    }
+    fn encrypt(&self, input: EncryptRequest) -> RusotoFuture<EncryptResponse, EncryptError> {
+        (**self).encrypt(input)
+    }
+    fn generate_data_key(
+        &self,
+        input: GenerateDataKeyRequest,
+    ) -> RusotoFuture<GenerateDataKeyResponse, GenerateDataKeyError> {
+        (**self).generate_data_key(input)
+    }
+    fn generate_data_key_without_plaintext(
+        &self,
+        input: GenerateDataKeyWithoutPlaintextRequest,
+    ) -> RusotoFuture<GenerateDataKeyWithoutPlaintextResponse, GenerateDataKeyWithoutPlaintextError>
+    {
+        (**self).generate_data_key_without_plaintext(input)
+    }
+    fn generate_random(
+        &self,
+        input: GenerateRandomRequest,
+    ) -> RusotoFuture<GenerateRandomResponse, GenerateRandomError> {
+        (**self).generate_random(input)
+    }
+    fn get_key_policy(
+        &self,
+        input: GetKeyPolicyRequest,
+    ) -> RusotoFuture<GetKeyPolicyResponse, GetKeyPolicyError> {
+        (**self).get_key_policy(input)
+    }
+    fn get_key_rotation_status(
+        &self,
+        input: GetKeyRotationStatusRequest,
+    ) -> RusotoFuture<GetKeyRotationStatusResponse, GetKeyRotationStatusError> {
+        (**self).get_key_rotation_status(input)
+    }
+    fn get_parameters_for_import(
+        &self,
+        input: GetParametersForImportRequest,
+    ) -> RusotoFuture<GetParametersForImportResponse, GetParametersForImportError> {
+        (**self).get_parameters_for_import(input)
+    }
+    fn import_key_material(
+        &self,
+        input: ImportKeyMaterialRequest,
+    ) -> RusotoFuture<ImportKeyMaterialResponse, ImportKeyMaterialError> {
+        (**self).import_key_material(input)
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        (**self).list_aliases(input)
+    }
+    fn list_grants(
+        &self,
+        input: ListGrantsRequest,
+    ) -> RusotoFuture<ListGrantsResponse, ListGrantsError> {
+        (**self).list_grants(input)
+    }
+    fn list_key_policies(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> RusotoFuture<ListKeyPoliciesResponse, ListKeyPoliciesError> {
+        (**self).list_key_policies(input)
+    }
+    fn list_keys(&self, input: ListKeysRequest) -> RusotoFuture<ListKeysResponse, ListKeysError> {
+        (**self).list_keys(input)
+    }
+    fn list_resource_tags(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> RusotoFuture<ListResourceTagsResponse, ListResourceTagsError> {
+        (**self).list_resource_tags(input)
+    }
+    fn list_retirable_grants(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> RusotoFuture<ListGrantsResponse, ListRetirableGrantsError> {
+        (**self).list_retirable_grants(input)
+    }
+    fn put_key_policy(&self, input: PutKeyPolicyRequest) -> RusotoFuture<(), PutKeyPolicyError> {
+        (**self).put_key_policy(input)
+    }
+    fn re_encrypt(
+        &self,
+        input: ReEncryptRequest,
+    ) -> RusotoFuture<ReEncryptResponse, ReEncryptError> {
+        (**self).re_encrypt(input)
+    }
+    fn retire_grant(&self, input: RetireGrantRequest) -> RusotoFuture<(), RetireGrantError> {
+        (**self).retire_grant(input)
+    }
+    fn revoke_grant(&self, input: RevokeGrantRequest) -> RusotoFuture<(), RevokeGrantError> {
+        (**self).revoke_grant(input)
+    }
+    fn schedule_key_deletion(
+        &self,
+        input: ScheduleKeyDeletionRequest,
+    ) -> RusotoFuture<ScheduleKeyDeletionResponse, ScheduleKeyDeletionError> {
+        (**self).schedule_key_deletion(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_alias(&self, input: UpdateAliasRequest) -> RusotoFuture<(), UpdateAliasError> {
+        (**self).update_alias(input)
+    }
+    fn update_custom_key_store(
+        &self,
+        input: UpdateCustomKeyStoreRequest,
+    ) -> RusotoFuture<UpdateCustomKeyStoreResponse, UpdateCustomKeyStoreError> {
+        (**self).update_custom_key_store(input)
+    }
+    fn update_key_description(
+        &self,
+        input: UpdateKeyDescriptionRequest,
+    ) -> RusotoFuture<(), UpdateKeyDescriptionError> {
+        (**self).update_key_description(input)
+    }
+}
+/// The error codes KMS retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the KMS API.
 #[derive(Clone)]
 pub struct KmsClient {
@@ -4049,6 +7148,24 @@ impl KmsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_KMS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> KmsClient {
+        Self::new(region::Region::from_env_for_service("KMS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `kms`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> KmsClient {
+        Self::new_with_client(config.client().clone(), config.region_for("kms", "KMS"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4067,7 +7184,49 @@ impl KmsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KmsClient {
-        KmsClient { client, region }
+        KmsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "kms")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "kms", "kms", "KMS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Kms` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Kms + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for KmsClient {
+    fn service_name(&self) -> &'static str {
+        "KMS"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "kms"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> KmsClient {
+        Self::new_with_client(client, region)
     }
 }
 
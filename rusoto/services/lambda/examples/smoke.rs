@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Lambda clients.
+//!
+//! Makes a single read-only `ListEventSourceMappings` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_lambda::{Lambda, LambdaClient, ListEventSourceMappingsRequest};
+
+fn main() {
+    let client = LambdaClient::from_env();
+    match client
+        .list_event_source_mappings(ListEventSourceMappingsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListEventSourceMappings succeeded"),
+        Err(error) => {
+            eprintln!("ListEventSourceMappings failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -2,13 +2,19 @@ extern crate rusoto_mock;
 
 use bytes::Bytes;
 
+use crate::events::{
+    ApiGatewayProxyRequest, ApiGatewayProxyResponse, DynamoDbEvent, KinesisEvent, ScheduledEvent,
+    SnsEvent,
+};
 use crate::generated::{
     GetPolicyRequest, GetPolicyResponse, InvocationRequest, Lambda, LambdaClient,
 };
+use crate::invoke::{InvokeTypedError, TypedInvokeExt};
 
 use self::rusoto_mock::*;
 use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
-use rusoto_core::Region;
+use rusoto_core::{Region, RusotoError};
+use serde_json::{json, Value};
 
 #[test]
 fn serialize_get_policy_response() {
@@ -64,3 +70,197 @@ fn should_parse_invocation_response() {
     assert_eq!(Some("Handled".to_owned()), result.function_error);
     assert_eq!(Some(200), result.status_code);
 }
+
+#[test]
+fn should_invoke_with_typed_payloads() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(r#"{"greeting":"hello world"}"#)
+        .with_header("X-Amz-Executed-Version", "3")
+        .with_header("X-Amz-Log-Result", "U1RBUlQgUmVxdWVzdElkOiAx")
+        .with_request_checker(|request: &SignedRequest| {
+            assert_eq!(
+                request.headers.get("x-amz-invocation-type"),
+                Some(&vec![b"RequestResponse".to_vec()])
+            );
+            assert_eq!(
+                request.headers.get("x-amz-log-type"),
+                Some(&vec![b"Tail".to_vec()])
+            );
+            if let Some(SignedRequestPayload::Buffer(ref buffer)) = request.payload {
+                assert_eq!(br#"{"name":"world"}"#, buffer.as_ref());
+            } else {
+                panic!("request payload is not a buffer");
+            }
+        });
+
+    let client = LambdaClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let result = client
+        .invoke_typed::<_, Value>("greeter", &json!({ "name": "world" }))
+        .sync()
+        .unwrap();
+
+    assert_eq!(result.payload, json!({ "greeting": "hello world" }));
+    assert_eq!(result.executed_version, Some("3".to_owned()));
+    assert_eq!(result.log, Some("START RequestId: 1".to_owned()));
+}
+
+#[test]
+fn should_return_function_errors() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(r#"{"errorMessage":"boom","errorType":"Error","stackTrace":["at handler"]}"#)
+        .with_header("X-Amz-Function-Error", "Unhandled");
+
+    let client = LambdaClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let result = client.invoke_typed::<_, Value>("greeter", &()).sync();
+
+    match result {
+        Err(RusotoError::Service(InvokeTypedError::Function(error))) => {
+            assert_eq!(error.kind, "Unhandled");
+            assert_eq!(error.error_type, Some("Error".to_owned()));
+            assert_eq!(error.error_message, "boom");
+            assert_eq!(error.stack_trace, vec!["at handler".to_owned()]);
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn should_parse_api_gateway_proxy_requests() {
+    let request: ApiGatewayProxyRequest = serde_json::from_value(json!({
+        "resource": "/users/{id}",
+        "path": "/users/42",
+        "httpMethod": "POST",
+        "headers": {"Content-Type": "application/octet-stream"},
+        "multiValueHeaders": {"Content-Type": ["application/octet-stream"]},
+        "queryStringParameters": null,
+        "multiValueQueryStringParameters": null,
+        "pathParameters": {"id": "42"},
+        "stageVariables": null,
+        "requestContext": {
+            "accountId": "123456789012",
+            "apiId": "1234567890",
+            "stage": "prod",
+            "requestId": "c6af9ac6-7b61-11e6-9a41-93e8deadbeef",
+            "identity": {"sourceIp": "127.0.0.1", "userAgent": "curl/7.54.0"}
+        },
+        "body": "aGVsbG8=",
+        "isBase64Encoded": true
+    }))
+    .unwrap();
+
+    assert_eq!(request.http_method, "POST");
+    assert_eq!(request.path_parameters["id"], "42");
+    assert!(request.query_string_parameters.is_empty());
+    assert_eq!(
+        request.request_context.identity.source_ip,
+        Some("127.0.0.1".to_owned())
+    );
+    assert_eq!(request.body_bytes().unwrap(), b"hello".to_vec());
+
+    let response =
+        ApiGatewayProxyResponse::new(201, "created").with_header("Location", "/users/42");
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        json!({
+            "statusCode": 201,
+            "headers": {"Location": "/users/42"},
+            "body": "created",
+            "isBase64Encoded": false
+        })
+    );
+}
+
+#[test]
+fn should_parse_stream_events() {
+    let event: KinesisEvent = serde_json::from_value(json!({"Records": [{
+        "kinesis": {
+            "kinesisSchemaVersion": "1.0",
+            "partitionKey": "1",
+            "sequenceNumber": "49590338271490256608559692538361571095921575989136588898",
+            "data": "SGVsbG8sIHRoaXMgaXMgYSB0ZXN0Lg==",
+            "approximateArrivalTimestamp": 1545084650.987
+        },
+        "eventSource": "aws:kinesis",
+        "eventVersion": "1.0",
+        "eventID": "shardId-000000000006:49590338271490256608559692538361571095921575989136588898",
+        "eventName": "aws:kinesis:record",
+        "invokeIdentityArn": "arn:aws:iam::123456789012:role/lambda-role",
+        "awsRegion": "us-east-2",
+        "eventSourceARN": "arn:aws:kinesis:us-east-2:123456789012:stream/lambda-stream"
+    }]}))
+    .unwrap();
+    assert_eq!(
+        event.records[0].kinesis.data,
+        b"Hello, this is a test.".to_vec()
+    );
+    let round_trip: KinesisEvent =
+        serde_json::from_value(serde_json::to_value(&event).unwrap()).unwrap();
+    assert_eq!(round_trip, event);
+
+    let event: DynamoDbEvent = serde_json::from_value(json!({"Records": [{
+        "eventID": "1",
+        "eventVersion": "1.0",
+        "dynamodb": {
+            "Keys": {"Id": {"N": "101"}},
+            "NewImage": {"Message": {"S": "New item!"}, "Id": {"N": "101"}},
+            "StreamViewType": "NEW_AND_OLD_IMAGES",
+            "SequenceNumber": "111",
+            "SizeBytes": 26
+        },
+        "awsRegion": "us-west-2",
+        "eventName": "INSERT",
+        "eventSourceARN": "arn:aws:dynamodb:us-west-2:123456789012:table/Example/stream/2015-06-27T00:48:05.899",
+        "eventSource": "aws:dynamodb"
+    }]}))
+    .unwrap();
+    let record = &event.records[0];
+    assert_eq!(record.event_name, "INSERT");
+    assert_eq!(record.dynamodb.keys["Id"], json!({"N": "101"}));
+    assert_eq!(record.dynamodb.old_image, None);
+    assert_eq!(
+        record.dynamodb.new_image.as_ref().unwrap()["Message"],
+        json!({"S": "New item!"})
+    );
+}
+
+#[test]
+fn should_parse_notification_events() {
+    let event: SnsEvent = serde_json::from_value(json!({"Records": [{
+        "EventVersion": "1.0",
+        "EventSubscriptionArn": "arn:aws:sns:us-east-2:123456789012:sns-lambda:21be56ed-a058-49f5-8c98-aedd2564c486",
+        "EventSource": "aws:sns",
+        "Sns": {
+            "SignatureVersion": "1",
+            "Timestamp": "2019-01-02T12:45:07.000Z",
+            "Signature": "tcc6faL2yUC6dgZdmrwh1Y4cGa/ebXEkAi6RibDsvpi+tE/1+82j...65r==",
+            "SigningCertUrl": "https://sns.us-east-2.amazonaws.com/SimpleNotificationService-ac565b8b1a6c5d002d285f9598aa1d9b.pem",
+            "MessageId": "95df01b4-ee98-5cb9-9903-4c221d41eb5e",
+            "Message": "Hello from SNS!",
+            "MessageAttributes": {"Test": {"Type": "String", "Value": "TestString"}},
+            "Type": "Notification",
+            "UnsubscribeUrl": "https://sns.us-east-2.amazonaws.com/?Action=Unsubscribe",
+            "TopicArn": "arn:aws:sns:us-east-2:123456789012:sns-lambda",
+            "Subject": "TestInvoke"
+        }
+    }]}))
+    .unwrap();
+    let message = &event.records[0].sns;
+    assert_eq!(message.message, "Hello from SNS!");
+    assert_eq!(message.subject, Some("TestInvoke".to_owned()));
+    assert_eq!(message.message_attributes["Test"].value, "TestString");
+
+    let event: ScheduledEvent = serde_json::from_value(json!({
+        "version": "0",
+        "id": "53dc4d37-cffa-4f76-80c9-8b7d4a4d2eaa",
+        "detail-type": "Scheduled Event",
+        "source": "aws.events",
+        "account": "123456789012",
+        "time": "2015-10-08T16:53:06Z",
+        "region": "us-east-1",
+        "resources": ["arn:aws:events:us-east-1:123456789012:rule/my-scheduled-rule"],
+        "detail": {}
+    }))
+    .unwrap();
+    assert_eq!(event.detail_type, "Scheduled Event");
+    assert!(event.detail.is_empty());
+}
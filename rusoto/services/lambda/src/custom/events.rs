@@ -0,0 +1,488 @@
+//! Events Lambda passes to functions triggered by other services.
+//!
+//! A function receives the event which triggered it as its JSON payload. These are the events of
+//! the most common triggers: API Gateway proxy integrations, with the response they expect,
+//! SQS queues, Kinesis and DynamoDB streams, CloudWatch Events rules, including schedules, and
+//! SNS topics. They implement `Deserialize` to be read by the function, and `Serialize` to be
+//! built in tests or passed to `invoke_typed`.
+//!
+//! Binary data, such as the data of Kinesis records, is decoded from base64 while parsing. The
+//! attribute values of DynamoDB stream records are left as JSON, in the format of the DynamoDB
+//! API: `serde_json::from_value` turns them into `rusoto_dynamodb::AttributeValue`s.
+//!
+//! # Examples
+//!
+//! ```
+//! use rusoto_lambda::events::SqsEvent;
+//!
+//! let event: SqsEvent = serde_json::from_str(r#"{"Records": [{
+//!     "messageId": "059f36b4-87a3-44ab-83d2-661975830a7d",
+//!     "receiptHandle": "AQEBwJnKyrHigUMZj6rYigCgxlaS3SLy0a...",
+//!     "body": "hello",
+//!     "attributes": {"ApproximateReceiveCount": "1"},
+//!     "messageAttributes": {},
+//!     "md5OfBody": "5d41402abc4b2a76b9719d911017c592",
+//!     "eventSource": "aws:sqs",
+//!     "eventSourceARN": "arn:aws:sqs:us-east-2:123456789012:my-queue",
+//!     "awsRegion": "us-east-2"
+//! }]}"#).unwrap();
+//! assert_eq!(event.records[0].body, "hello");
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer, Serializer};
+use serde_json::Value;
+
+/// A request proxied to a function by an API Gateway REST API.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiGatewayProxyRequest {
+    /// The path of the resource, with its path parameters, e.g. `/users/{id}`
+    #[serde(default)]
+    pub resource: Option<String>,
+    /// The path of the request
+    #[serde(default)]
+    pub path: Option<String>,
+    /// The method of the request
+    pub http_method: String,
+    /// The last value of each header
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub headers: HashMap<String, String>,
+    /// All the values of each header
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub multi_value_headers: HashMap<String, Vec<String>>,
+    /// The last value of each query string parameter
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub query_string_parameters: HashMap<String, String>,
+    /// All the values of each query string parameter
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub multi_value_query_string_parameters: HashMap<String, Vec<String>>,
+    /// The values of the path parameters of the resource
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub path_parameters: HashMap<String, String>,
+    /// The variables of the stage
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub stage_variables: HashMap<String, String>,
+    /// The API, stage and caller of the request
+    #[serde(default)]
+    pub request_context: ApiGatewayRequestContext,
+    /// The body of the request, base64 encoded if `is_base64_encoded`
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Whether the body is base64 encoded, as binary bodies are
+    #[serde(default)]
+    pub is_base64_encoded: bool,
+}
+
+impl ApiGatewayProxyRequest {
+    /// The body of the request, decoded from base64 if it is binary.
+    pub fn body_bytes(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        match self.body {
+            Some(ref body) if self.is_base64_encoded => base64::decode(body),
+            Some(ref body) => Ok(body.as_bytes().to_vec()),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+/// The API, stage and caller of a request proxied by API Gateway.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiGatewayRequestContext {
+    /// The account owning the API
+    #[serde(default)]
+    pub account_id: Option<String>,
+    /// The ID of the API
+    #[serde(default)]
+    pub api_id: Option<String>,
+    /// The ID of the resource
+    #[serde(default)]
+    pub resource_id: Option<String>,
+    /// The path of the resource
+    #[serde(default)]
+    pub resource_path: Option<String>,
+    /// The stage the request was sent to
+    #[serde(default)]
+    pub stage: Option<String>,
+    /// The ID of the request
+    #[serde(default)]
+    pub request_id: Option<String>,
+    /// The method of the request
+    #[serde(default)]
+    pub http_method: Option<String>,
+    /// The path of the request, including the stage
+    #[serde(default)]
+    pub path: Option<String>,
+    /// The caller
+    #[serde(default)]
+    pub identity: ApiGatewayRequestIdentity,
+    /// The context set by a Lambda authorizer, or the claims of a Cognito user pool authorizer
+    #[serde(default)]
+    pub authorizer: Option<HashMap<String, Value>>,
+}
+
+/// The caller of a request proxied by API Gateway.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiGatewayRequestIdentity {
+    /// The IP address of the caller
+    #[serde(default)]
+    pub source_ip: Option<String>,
+    /// The user agent of the caller
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    /// The API key of the request
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// The account of the caller, for IAM authorized requests
+    #[serde(default)]
+    pub account_id: Option<String>,
+    /// The ARN of the caller, for IAM authorized requests
+    #[serde(default)]
+    pub user_arn: Option<String>,
+    /// The Cognito identity of the caller
+    #[serde(default)]
+    pub cognito_identity_id: Option<String>,
+}
+
+/// The response a function returns to an API Gateway proxy integration.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiGatewayProxyResponse {
+    /// The status code of the response
+    pub status_code: i64,
+    /// Headers with a single value
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+    /// Headers with several values
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub multi_value_headers: HashMap<String, Vec<String>>,
+    /// The body of the response, base64 encoded if `is_base64_encoded`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Whether API Gateway should decode the body from base64
+    #[serde(default)]
+    pub is_base64_encoded: bool,
+}
+
+impl ApiGatewayProxyResponse {
+    /// A response with a text body.
+    pub fn new<B>(status_code: i64, body: B) -> ApiGatewayProxyResponse
+    where
+        B: Into<String>,
+    {
+        ApiGatewayProxyResponse {
+            status_code,
+            body: Some(body.into()),
+            ..Default::default()
+        }
+    }
+
+    /// A response with a binary body, which is base64 encoded.
+    pub fn binary(status_code: i64, body: &[u8]) -> ApiGatewayProxyResponse {
+        ApiGatewayProxyResponse {
+            status_code,
+            body: Some(base64::encode(body)),
+            is_base64_encoded: true,
+            ..Default::default()
+        }
+    }
+
+    /// Adds a header to the response.
+    pub fn with_header<K, V>(mut self, name: K, value: V) -> ApiGatewayProxyResponse
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// Messages received from an SQS queue.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SqsEvent {
+    /// The messages
+    #[serde(rename = "Records")]
+    pub records: Vec<SqsMessage>,
+}
+
+/// A message received from an SQS queue.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqsMessage {
+    /// The ID of the message
+    pub message_id: String,
+    /// The handle deleting the message
+    pub receipt_handle: String,
+    /// The body of the message
+    pub body: String,
+    /// The system attributes of the message, such as `ApproximateReceiveCount`
+    #[serde(default)]
+    pub attributes: HashMap<String, String>,
+    /// The attributes set by the sender
+    #[serde(default)]
+    pub message_attributes: HashMap<String, SqsMessageAttribute>,
+    /// The MD5 digest of the body
+    #[serde(default)]
+    pub md5_of_body: Option<String>,
+    /// `aws:sqs`
+    pub event_source: String,
+    /// The ARN of the queue
+    #[serde(rename = "eventSourceARN")]
+    pub event_source_arn: String,
+    /// The region of the queue
+    pub aws_region: String,
+}
+
+/// An attribute of an SQS message set by its sender.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqsMessageAttribute {
+    /// The type of the attribute, `String`, `Number` or `Binary`
+    pub data_type: String,
+    /// The value of `String` and `Number` attributes
+    #[serde(default)]
+    pub string_value: Option<String>,
+    /// The base64 encoded value of `Binary` attributes
+    #[serde(default)]
+    pub binary_value: Option<String>,
+}
+
+/// Records read from a Kinesis stream.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct KinesisEvent {
+    /// The records
+    #[serde(rename = "Records")]
+    pub records: Vec<KinesisEventRecord>,
+}
+
+/// A record read from a Kinesis stream, and where it was read from.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KinesisEventRecord {
+    /// The record
+    pub kinesis: KinesisRecord,
+    /// `aws:kinesis`
+    pub event_source: String,
+    /// The ARN of the stream
+    #[serde(rename = "eventSourceARN")]
+    pub event_source_arn: String,
+    /// The shard and sequence number of the record
+    #[serde(rename = "eventID")]
+    pub event_id: String,
+    /// `aws:kinesis:record`
+    pub event_name: String,
+    /// The version of the event structure
+    pub event_version: String,
+    /// The role the function reads the stream with
+    pub invoke_identity_arn: String,
+    /// The region of the stream
+    pub aws_region: String,
+}
+
+/// A record of a Kinesis stream.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KinesisRecord {
+    /// The key of the shard of the record
+    pub partition_key: String,
+    /// The sequence number of the record in its shard
+    pub sequence_number: String,
+    /// The data of the record, decoded
+    #[serde(deserialize_with = "from_base64", serialize_with = "to_base64")]
+    pub data: Vec<u8>,
+    /// When the record was added to the stream, in seconds since the epoch
+    pub approximate_arrival_timestamp: f64,
+    /// The version of the record structure
+    pub kinesis_schema_version: String,
+}
+
+/// Changes read from a DynamoDB stream.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DynamoDbEvent {
+    /// The changes
+    #[serde(rename = "Records")]
+    pub records: Vec<DynamoDbEventRecord>,
+}
+
+/// A change to an item read from a DynamoDB stream.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DynamoDbEventRecord {
+    /// The ID of the change
+    #[serde(rename = "eventID")]
+    pub event_id: String,
+    /// `INSERT`, `MODIFY` or `REMOVE`
+    pub event_name: String,
+    /// The version of the event structure
+    pub event_version: String,
+    /// `aws:dynamodb`
+    pub event_source: String,
+    /// The region of the table
+    pub aws_region: String,
+    /// The change
+    pub dynamodb: DynamoDbStreamRecord,
+    /// The ARN of the stream
+    #[serde(rename = "eventSourceARN")]
+    pub event_source_arn: String,
+    /// `dynamodb.amazonaws.com` for items deleted by their time to live
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_identity: Option<DynamoDbUserIdentity>,
+}
+
+/// A change to an item, with the images the stream is configured to record.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DynamoDbStreamRecord {
+    /// When the change was made, in seconds since the epoch
+    #[serde(default)]
+    pub approximate_creation_date_time: Option<f64>,
+    /// The key attributes of the item
+    #[serde(default)]
+    pub keys: HashMap<String, Value>,
+    /// The item after the change
+    #[serde(default)]
+    pub new_image: Option<HashMap<String, Value>>,
+    /// The item before the change
+    #[serde(default)]
+    pub old_image: Option<HashMap<String, Value>>,
+    /// The sequence number of the change
+    pub sequence_number: String,
+    /// The size of the change in bytes
+    pub size_bytes: i64,
+    /// `KEYS_ONLY`, `NEW_IMAGE`, `OLD_IMAGE` or `NEW_AND_OLD_IMAGES`
+    pub stream_view_type: String,
+}
+
+/// The principal making a change in a DynamoDB stream.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct DynamoDbUserIdentity {
+    /// `Service`
+    #[serde(rename = "type")]
+    pub identity_type: String,
+    /// The principal
+    #[serde(rename = "principalId")]
+    pub principal_id: String,
+}
+
+/// An event delivered by a CloudWatch Events rule.
+///
+/// The detail is left as JSON unless a type is given for it.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct CloudWatchEvent<D = Value> {
+    /// The version of the event structure
+    pub version: String,
+    /// The ID of the event
+    pub id: String,
+    /// The type of the event, e.g. `Scheduled Event`
+    #[serde(rename = "detail-type")]
+    pub detail_type: String,
+    /// The service or application sending the event, e.g. `aws.events`
+    pub source: String,
+    /// The account the event was sent in
+    pub account: String,
+    /// When the event happened
+    pub time: String,
+    /// The region of the event
+    pub region: String,
+    /// The ARNs of the resources of the event, such as the rule of a schedule
+    #[serde(default)]
+    pub resources: Vec<String>,
+    /// The detail of the event, an empty object for schedules
+    pub detail: D,
+}
+
+/// An event delivered by a scheduled CloudWatch Events rule.
+pub type ScheduledEvent = CloudWatchEvent<HashMap<String, Value>>;
+
+/// Notifications received from an SNS topic.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SnsEvent {
+    /// The notifications
+    #[serde(rename = "Records")]
+    pub records: Vec<SnsEventRecord>,
+}
+
+/// A notification received from an SNS topic.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SnsEventRecord {
+    /// The version of the event structure
+    pub event_version: String,
+    /// The ARN of the subscription of the function
+    pub event_subscription_arn: String,
+    /// `aws:sns`
+    pub event_source: String,
+    /// The notification
+    pub sns: SnsMessage,
+}
+
+/// A message published to an SNS topic.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SnsMessage {
+    /// `Notification`
+    #[serde(rename = "Type")]
+    pub message_type: String,
+    /// The ID of the message
+    pub message_id: String,
+    /// The ARN of the topic
+    pub topic_arn: String,
+    /// The subject of the message
+    #[serde(default)]
+    pub subject: Option<String>,
+    /// The message
+    pub message: String,
+    /// When the message was published
+    pub timestamp: String,
+    /// The version of the signature
+    pub signature_version: String,
+    /// The signature of the message
+    pub signature: String,
+    /// The URL of the certificate the message was signed with
+    #[serde(rename = "SigningCertUrl")]
+    pub signing_cert_url: String,
+    /// The URL unsubscribing the function
+    #[serde(rename = "UnsubscribeUrl")]
+    pub unsubscribe_url: String,
+    /// The attributes of the message
+    #[serde(default)]
+    pub message_attributes: HashMap<String, SnsMessageAttribute>,
+}
+
+/// An attribute of an SNS message.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SnsMessageAttribute {
+    /// The type of the attribute, such as `String` or `Binary`
+    #[serde(rename = "Type")]
+    pub attribute_type: String,
+    /// The value of the attribute
+    #[serde(rename = "Value")]
+    pub value: String,
+}
+
+/// Reads `null` as the default value, as API Gateway sends `null` for empty maps.
+fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Option::unwrap_or_default)
+}
+
+fn from_base64<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let encoded = String::deserialize(deserializer)?;
+    base64::decode(&encoded).map_err(serde::de::Error::custom)
+}
+
+fn to_base64<S>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&base64::encode(data))
+}
@@ -0,0 +1,222 @@
+//! Invoking functions with typed payloads.
+//!
+//! `invoke_typed` serializes its input to JSON, invokes the function synchronously and
+//! deserializes the JSON it returns. Errors raised by the function itself don't fail the
+//! `Invoke` call; Lambda reports them with the `X-Amz-Function-Error` header and an error
+//! document as payload, which `invoke_typed` turns into `InvokeTypedError::Function`.
+//!
+//! The last 4 KB of the function's log are requested along with the result, and returned
+//! decoded.
+
+use std::error::Error;
+use std::fmt;
+
+use futures::Future;
+use rusoto_core::{RusotoError, RusotoFuture};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::generated::{InvocationRequest, InvocationType, InvokeError, Lambda, LogType};
+
+/// The result of a successful `invoke_typed` call.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypedInvocationResponse<O> {
+    /// The value returned by the function
+    pub payload: O,
+    /// The version of the function that was executed
+    pub executed_version: Option<String>,
+    /// The tail of the function's log
+    pub log: Option<String>,
+}
+
+/// An error raised by a function, as reported by Lambda.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LambdaFunctionError {
+    /// `Handled` for errors returned by the function, `Unhandled` for errors detected by the
+    /// runtime, such as timeouts or crashes
+    pub kind: String,
+    /// The type of the error, e.g. the exception class
+    pub error_type: Option<String>,
+    /// The error message, or the raw payload if it isn't an error document
+    pub error_message: String,
+    /// The stack trace, one frame per entry
+    pub stack_trace: Vec<String>,
+    /// The tail of the function's log
+    pub log: Option<String>,
+}
+
+impl LambdaFunctionError {
+    /// Reads the error document returned by a failed function.
+    pub fn from_payload(kind: &str, payload: &[u8]) -> LambdaFunctionError {
+        let mut error = LambdaFunctionError {
+            kind: kind.to_owned(),
+            ..Default::default()
+        };
+        let document = match serde_json::from_slice::<Value>(payload) {
+            Ok(Value::Object(document)) => document,
+            _ => {
+                error.error_message = String::from_utf8_lossy(payload).into_owned();
+                return error;
+            }
+        };
+        error.error_type = document
+            .get("errorType")
+            .and_then(Value::as_str)
+            .map(str::to_owned);
+        error.error_message = document
+            .get("errorMessage")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_owned();
+        if let Some(Value::Array(frames)) = document.get("stackTrace") {
+            // runtimes format frames as strings or, like older Python runtimes, as arrays
+            error.stack_trace = frames
+                .iter()
+                .map(|frame| match frame {
+                    Value::String(frame) => frame.to_owned(),
+                    frame => frame.to_string(),
+                })
+                .collect();
+        }
+        error
+    }
+}
+
+/// Errors returned by `invoke_typed`.
+#[derive(Debug, PartialEq)]
+pub enum InvokeTypedError {
+    /// Lambda rejected the invocation.
+    Invoke(InvokeError),
+    /// The function raised an error.
+    Function(LambdaFunctionError),
+}
+
+impl fmt::Display for InvokeTypedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InvokeTypedError::Function(ref error) => match error.error_type {
+                Some(ref error_type) => write!(f, "{}: {}", error_type, error.error_message),
+                None => write!(f, "{}", error.error_message),
+            },
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for InvokeTypedError {
+    fn description(&self) -> &str {
+        match *self {
+            InvokeTypedError::Invoke(ref cause) => cause.description(),
+            InvokeTypedError::Function(ref error) => &error.error_message,
+        }
+    }
+}
+
+fn decode_log(log_result: Option<String>) -> Option<String> {
+    log_result
+        .and_then(|encoded| base64::decode(&encoded).ok())
+        .map(|log| String::from_utf8_lossy(&log).into_owned())
+}
+
+/// Extension methods for invoking functions with typed payloads.
+pub trait TypedInvokeExt: Lambda {
+    /// Invokes a function synchronously with `input` serialized to JSON, and deserializes the
+    /// value it returns.
+    ///
+    /// `function_name` may be qualified with a version or alias, e.g. `my-function:prod`.
+    ///
+    /// ```rust,no_run
+    /// use rusoto_core::Region;
+    /// use rusoto_lambda::invoke::TypedInvokeExt;
+    /// use rusoto_lambda::LambdaClient;
+    /// use serde_json::{json, Value};
+    ///
+    /// let client = LambdaClient::new(Region::UsEast1);
+    /// let response = client
+    ///     .invoke_typed::<_, Value>("my-function", &json!({ "name": "world" }))
+    ///     .sync()
+    ///     .unwrap();
+    /// println!("{}", response.payload);
+    /// ```
+    fn invoke_typed<I, O>(
+        &self,
+        function_name: &str,
+        input: &I,
+    ) -> RusotoFuture<TypedInvocationResponse<O>, InvokeTypedError>
+    where
+        I: Serialize + ?Sized,
+        O: DeserializeOwned + Send + 'static,
+    {
+        let payload = match serde_json::to_vec(input) {
+            Ok(payload) => payload,
+            Err(e) => {
+                return RusotoFuture::from_future(Err(RusotoError::Validation(e.to_string())))
+            }
+        };
+        let request = InvocationRequest {
+            function_name: function_name.to_owned(),
+            invocation_type: Some(InvocationType::RequestResponse),
+            log_type: Some(LogType::Tail),
+            payload: Some(payload.into()),
+            ..Default::default()
+        };
+
+        let future = self
+            .invoke(request)
+            .map_err(|e| e.map_service(InvokeTypedError::Invoke))
+            .and_then(|response| {
+                let log = decode_log(response.log_result);
+                let payload = response.payload.unwrap_or_default();
+                if let Some(kind) = response.function_error {
+                    let error = LambdaFunctionError {
+                        log,
+                        ..LambdaFunctionError::from_payload(&kind, &payload)
+                    };
+                    return Err(RusotoError::Service(InvokeTypedError::Function(error)));
+                }
+                let payload = serde_json::from_slice(&payload)
+                    .map_err(|e| RusotoError::ParseError(e.to_string()))?;
+                Ok(TypedInvocationResponse {
+                    payload,
+                    executed_version: response.executed_version,
+                    log,
+                })
+            });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: Lambda> TypedInvokeExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_error_documents() {
+        let error = LambdaFunctionError::from_payload(
+            "Unhandled",
+            br#"{"errorMessage": "boom", "errorType": "ValueError",
+                 "stackTrace": [["index.py", 3, "handler", "raise ValueError()"], "at main"]}"#,
+        );
+        assert_eq!(error.kind, "Unhandled");
+        assert_eq!(error.error_type, Some("ValueError".to_owned()));
+        assert_eq!(error.error_message, "boom");
+        assert_eq!(
+            error.stack_trace,
+            vec![
+                r#"["index.py",3,"handler","raise ValueError()"]"#.to_owned(),
+                "at main".to_owned()
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_payloads_which_are_not_error_documents() {
+        let error = LambdaFunctionError::from_payload("Handled", b"\"Task timed out\"");
+        assert_eq!(error.error_type, None);
+        assert_eq!(error.error_message, "\"Task timed out\"");
+    }
+}
@@ -1,2 +1,8 @@
+/// Events passed to functions by the services triggering them
+pub mod events;
+
+/// Invoking functions with typed payloads
+pub mod invoke;
+
 #[cfg(test)]
 mod custom_tests;
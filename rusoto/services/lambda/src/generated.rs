@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Limits that are related to concurrency and code storage. All file and storage sizes are in bytes.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -91,6 +92,94 @@ pub struct AddLayerVersionPermissionRequest {
     pub version_number: i64,
 }
 
+impl AddLayerVersionPermissionRequest {
+    /// Returns a builder for `AddLayerVersionPermissionRequest`, checking its required members when built.
+    pub fn builder() -> AddLayerVersionPermissionRequestBuilder {
+        AddLayerVersionPermissionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddLayerVersionPermissionRequest`](struct.AddLayerVersionPermissionRequest.html).
+#[derive(Default, Debug)]
+pub struct AddLayerVersionPermissionRequestBuilder {
+    action: Option<String>,
+    layer_name: Option<String>,
+    organization_id: Option<String>,
+    principal: Option<String>,
+    revision_id: Option<String>,
+    statement_id: Option<String>,
+    version_number: Option<i64>,
+}
+
+impl AddLayerVersionPermissionRequestBuilder {
+    /// Sets the [`action`](struct.AddLayerVersionPermissionRequest.html#structfield.action) member.
+    pub fn action<T: Into<String>>(mut self, value: T) -> Self {
+        self.action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layer_name`](struct.AddLayerVersionPermissionRequest.html#structfield.layer_name) member.
+    pub fn layer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_id`](struct.AddLayerVersionPermissionRequest.html#structfield.organization_id) member.
+    pub fn organization_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organization_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.AddLayerVersionPermissionRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision_id`](struct.AddLayerVersionPermissionRequest.html#structfield.revision_id) member.
+    pub fn revision_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.revision_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statement_id`](struct.AddLayerVersionPermissionRequest.html#structfield.statement_id) member.
+    pub fn statement_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.statement_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_number`](struct.AddLayerVersionPermissionRequest.html#structfield.version_number) member.
+    pub fn version_number<T: Into<i64>>(mut self, value: T) -> Self {
+        self.version_number = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddLayerVersionPermissionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddLayerVersionPermissionRequest, ::rusoto_core::BuildError> {
+        Ok(AddLayerVersionPermissionRequest {
+            action: self
+                .action
+                .ok_or(::rusoto_core::BuildError::missing("action"))?,
+            layer_name: self
+                .layer_name
+                .ok_or(::rusoto_core::BuildError::missing("layer_name"))?,
+            organization_id: self.organization_id,
+            principal: self
+                .principal
+                .ok_or(::rusoto_core::BuildError::missing("principal"))?,
+            revision_id: self.revision_id,
+            statement_id: self
+                .statement_id
+                .ok_or(::rusoto_core::BuildError::missing("statement_id"))?,
+            version_number: self
+                .version_number
+                .ok_or(::rusoto_core::BuildError::missing("version_number"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddLayerVersionPermissionResponse {
@@ -140,6 +229,106 @@ pub struct AddPermissionRequest {
     pub statement_id: String,
 }
 
+impl AddPermissionRequest {
+    /// Returns a builder for `AddPermissionRequest`, checking its required members when built.
+    pub fn builder() -> AddPermissionRequestBuilder {
+        AddPermissionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AddPermissionRequest`](struct.AddPermissionRequest.html).
+#[derive(Default, Debug)]
+pub struct AddPermissionRequestBuilder {
+    action: Option<String>,
+    event_source_token: Option<String>,
+    function_name: Option<String>,
+    principal: Option<String>,
+    qualifier: Option<String>,
+    revision_id: Option<String>,
+    source_account: Option<String>,
+    source_arn: Option<String>,
+    statement_id: Option<String>,
+}
+
+impl AddPermissionRequestBuilder {
+    /// Sets the [`action`](struct.AddPermissionRequest.html#structfield.action) member.
+    pub fn action<T: Into<String>>(mut self, value: T) -> Self {
+        self.action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_source_token`](struct.AddPermissionRequest.html#structfield.event_source_token) member.
+    pub fn event_source_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_source_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.AddPermissionRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.AddPermissionRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualifier`](struct.AddPermissionRequest.html#structfield.qualifier) member.
+    pub fn qualifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision_id`](struct.AddPermissionRequest.html#structfield.revision_id) member.
+    pub fn revision_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.revision_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_account`](struct.AddPermissionRequest.html#structfield.source_account) member.
+    pub fn source_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_arn`](struct.AddPermissionRequest.html#structfield.source_arn) member.
+    pub fn source_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statement_id`](struct.AddPermissionRequest.html#structfield.statement_id) member.
+    pub fn statement_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.statement_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddPermissionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddPermissionRequest, ::rusoto_core::BuildError> {
+        Ok(AddPermissionRequest {
+            action: self
+                .action
+                .ok_or(::rusoto_core::BuildError::missing("action"))?,
+            event_source_token: self.event_source_token,
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            principal: self
+                .principal
+                .ok_or(::rusoto_core::BuildError::missing("principal"))?,
+            qualifier: self.qualifier,
+            revision_id: self.revision_id,
+            source_account: self.source_account,
+            source_arn: self.source_arn,
+            statement_id: self
+                .statement_id
+                .ok_or(::rusoto_core::BuildError::missing("statement_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AddPermissionResponse {
@@ -218,6 +407,72 @@ pub struct CreateAliasRequest {
     pub routing_config: Option<AliasRoutingConfiguration>,
 }
 
+impl CreateAliasRequest {
+    /// Returns a builder for `CreateAliasRequest`, checking its required members when built.
+    pub fn builder() -> CreateAliasRequestBuilder {
+        CreateAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAliasRequest`](struct.CreateAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAliasRequestBuilder {
+    description: Option<String>,
+    function_name: Option<String>,
+    function_version: Option<String>,
+    name: Option<String>,
+    routing_config: Option<AliasRoutingConfiguration>,
+}
+
+impl CreateAliasRequestBuilder {
+    /// Sets the [`description`](struct.CreateAliasRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.CreateAliasRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_version`](struct.CreateAliasRequest.html#structfield.function_version) member.
+    pub fn function_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateAliasRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`routing_config`](struct.CreateAliasRequest.html#structfield.routing_config) member.
+    pub fn routing_config<T: Into<AliasRoutingConfiguration>>(mut self, value: T) -> Self {
+        self.routing_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateAliasRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAliasRequest {
+            description: self.description,
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            function_version: self
+                .function_version
+                .ok_or(::rusoto_core::BuildError::missing("function_version"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            routing_config: self.routing_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateEventSourceMappingRequest {
     /// <p><p>The maximum number of items to retrieve in a single batch.</p> <ul> <li> <p> <b>Amazon Kinesis</b> - Default 100. Max 10,000.</p> </li> <li> <p> <b>Amazon DynamoDB Streams</b> - Default 100. Max 1,000.</p> </li> <li> <p> <b>Amazon Simple Queue Service</b> - Default 10. Max 10.</p> </li> </ul></p>
@@ -240,14 +495,97 @@ pub struct CreateEventSourceMappingRequest {
     /// <p>The position in a stream from which to start reading. Required for Amazon Kinesis and Amazon DynamoDB Streams sources. <code>AT_TIMESTAMP</code> is only supported for Amazon Kinesis streams.</p>
     #[serde(rename = "StartingPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub starting_position: Option<String>,
+    pub starting_position: Option<EventSourcePosition>,
     /// <p>With <code>StartingPosition</code> set to <code>AT_TIMESTAMP</code>, the time from which to start reading.</p>
     #[serde(rename = "StartingPositionTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub starting_position_timestamp: Option<f64>,
+    pub starting_position_timestamp: Option<EpochTimestamp>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl CreateEventSourceMappingRequest {
+    /// Returns a builder for `CreateEventSourceMappingRequest`, checking its required members when built.
+    pub fn builder() -> CreateEventSourceMappingRequestBuilder {
+        CreateEventSourceMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateEventSourceMappingRequest`](struct.CreateEventSourceMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateEventSourceMappingRequestBuilder {
+    batch_size: Option<i64>,
+    enabled: Option<bool>,
+    event_source_arn: Option<String>,
+    function_name: Option<String>,
+    maximum_batching_window_in_seconds: Option<i64>,
+    starting_position: Option<EventSourcePosition>,
+    starting_position_timestamp: Option<EpochTimestamp>,
+}
+
+impl CreateEventSourceMappingRequestBuilder {
+    /// Sets the [`batch_size`](struct.CreateEventSourceMappingRequest.html#structfield.batch_size) member.
+    pub fn batch_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.batch_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enabled`](struct.CreateEventSourceMappingRequest.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_source_arn`](struct.CreateEventSourceMappingRequest.html#structfield.event_source_arn) member.
+    pub fn event_source_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_source_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.CreateEventSourceMappingRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`maximum_batching_window_in_seconds`](struct.CreateEventSourceMappingRequest.html#structfield.maximum_batching_window_in_seconds) member.
+    pub fn maximum_batching_window_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.maximum_batching_window_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`starting_position`](struct.CreateEventSourceMappingRequest.html#structfield.starting_position) member.
+    pub fn starting_position<T: Into<EventSourcePosition>>(mut self, value: T) -> Self {
+        self.starting_position = Some(value.into());
+        self
+    }
+
+    /// Sets the [`starting_position_timestamp`](struct.CreateEventSourceMappingRequest.html#structfield.starting_position_timestamp) member.
+    pub fn starting_position_timestamp<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.starting_position_timestamp = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateEventSourceMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateEventSourceMappingRequest, ::rusoto_core::BuildError> {
+        Ok(CreateEventSourceMappingRequest {
+            batch_size: self.batch_size,
+            enabled: self.enabled,
+            event_source_arn: self
+                .event_source_arn
+                .ok_or(::rusoto_core::BuildError::missing("event_source_arn"))?,
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            maximum_batching_window_in_seconds: self.maximum_batching_window_in_seconds,
+            starting_position: self.starting_position,
+            starting_position_timestamp: self.starting_position_timestamp,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateFunctionRequest {
     /// <p>The code for the function.</p>
     #[serde(rename = "Code")]
@@ -291,7 +629,7 @@ pub struct CreateFunctionRequest {
     pub role: String,
     /// <p>The identifier of the function's <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime</a>.</p>
     #[serde(rename = "Runtime")]
-    pub runtime: String,
+    pub runtime: Runtime,
     /// <p>A list of <a href="https://docs.aws.amazon.com/lambda/latest/dg/tagging.html">tags</a> to apply to the function.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -310,6 +648,164 @@ pub struct CreateFunctionRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl CreateFunctionRequest {
+    /// Returns a builder for `CreateFunctionRequest`, checking its required members when built.
+    pub fn builder() -> CreateFunctionRequestBuilder {
+        CreateFunctionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateFunctionRequest`](struct.CreateFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateFunctionRequestBuilder {
+    code: Option<FunctionCode>,
+    dead_letter_config: Option<DeadLetterConfig>,
+    description: Option<String>,
+    environment: Option<Environment>,
+    function_name: Option<String>,
+    handler: Option<String>,
+    kms_key_arn: Option<String>,
+    layers: Option<Vec<String>>,
+    memory_size: Option<i64>,
+    publish: Option<bool>,
+    role: Option<String>,
+    runtime: Option<Runtime>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    timeout: Option<i64>,
+    tracing_config: Option<TracingConfig>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl CreateFunctionRequestBuilder {
+    /// Sets the [`code`](struct.CreateFunctionRequest.html#structfield.code) member.
+    pub fn code<T: Into<FunctionCode>>(mut self, value: T) -> Self {
+        self.code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dead_letter_config`](struct.CreateFunctionRequest.html#structfield.dead_letter_config) member.
+    pub fn dead_letter_config<T: Into<DeadLetterConfig>>(mut self, value: T) -> Self {
+        self.dead_letter_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateFunctionRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment`](struct.CreateFunctionRequest.html#structfield.environment) member.
+    pub fn environment<T: Into<Environment>>(mut self, value: T) -> Self {
+        self.environment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.CreateFunctionRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`handler`](struct.CreateFunctionRequest.html#structfield.handler) member.
+    pub fn handler<T: Into<String>>(mut self, value: T) -> Self {
+        self.handler = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_arn`](struct.CreateFunctionRequest.html#structfield.kms_key_arn) member.
+    pub fn kms_key_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layers`](struct.CreateFunctionRequest.html#structfield.layers) member.
+    pub fn layers<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.layers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`memory_size`](struct.CreateFunctionRequest.html#structfield.memory_size) member.
+    pub fn memory_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.memory_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publish`](struct.CreateFunctionRequest.html#structfield.publish) member.
+    pub fn publish<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publish = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.CreateFunctionRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`runtime`](struct.CreateFunctionRequest.html#structfield.runtime) member.
+    pub fn runtime<T: Into<Runtime>>(mut self, value: T) -> Self {
+        self.runtime = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateFunctionRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout`](struct.CreateFunctionRequest.html#structfield.timeout) member.
+    pub fn timeout<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tracing_config`](struct.CreateFunctionRequest.html#structfield.tracing_config) member.
+    pub fn tracing_config<T: Into<TracingConfig>>(mut self, value: T) -> Self {
+        self.tracing_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.CreateFunctionRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateFunctionRequest {
+            code: self
+                .code
+                .ok_or(::rusoto_core::BuildError::missing("code"))?,
+            dead_letter_config: self.dead_letter_config,
+            description: self.description,
+            environment: self.environment,
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            handler: self
+                .handler
+                .ok_or(::rusoto_core::BuildError::missing("handler"))?,
+            kms_key_arn: self.kms_key_arn,
+            layers: self.layers,
+            memory_size: self.memory_size,
+            publish: self.publish,
+            role: self
+                .role
+                .ok_or(::rusoto_core::BuildError::missing("role"))?,
+            runtime: self
+                .runtime
+                .ok_or(::rusoto_core::BuildError::missing("runtime"))?,
+            tags: self.tags,
+            timeout: self.timeout,
+            tracing_config: self.tracing_config,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 /// <p>The <a href="https://docs.aws.amazon.com/lambda/latest/dg/dlq.html">dead letter queue</a> for failed asynchronous invocations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeadLetterConfig {
@@ -329,6 +825,46 @@ pub struct DeleteAliasRequest {
     pub name: String,
 }
 
+impl DeleteAliasRequest {
+    /// Returns a builder for `DeleteAliasRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAliasRequestBuilder {
+        DeleteAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAliasRequest`](struct.DeleteAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAliasRequestBuilder {
+    function_name: Option<String>,
+    name: Option<String>,
+}
+
+impl DeleteAliasRequestBuilder {
+    /// Sets the [`function_name`](struct.DeleteAliasRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DeleteAliasRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteAliasRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAliasRequest {
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteEventSourceMappingRequest {
     /// <p>The identifier of the event source mapping.</p>
@@ -336,6 +872,38 @@ pub struct DeleteEventSourceMappingRequest {
     pub uuid: String,
 }
 
+impl DeleteEventSourceMappingRequest {
+    /// Returns a builder for `DeleteEventSourceMappingRequest`, checking its required members when built.
+    pub fn builder() -> DeleteEventSourceMappingRequestBuilder {
+        DeleteEventSourceMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEventSourceMappingRequest`](struct.DeleteEventSourceMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteEventSourceMappingRequestBuilder {
+    uuid: Option<String>,
+}
+
+impl DeleteEventSourceMappingRequestBuilder {
+    /// Sets the [`uuid`](struct.DeleteEventSourceMappingRequest.html#structfield.uuid) member.
+    pub fn uuid<T: Into<String>>(mut self, value: T) -> Self {
+        self.uuid = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEventSourceMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteEventSourceMappingRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteEventSourceMappingRequest {
+            uuid: self
+                .uuid
+                .ok_or(::rusoto_core::BuildError::missing("uuid"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteFunctionConcurrencyRequest {
     /// <p>The name of the Lambda function.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code>.</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
@@ -343,6 +911,38 @@ pub struct DeleteFunctionConcurrencyRequest {
     pub function_name: String,
 }
 
+impl DeleteFunctionConcurrencyRequest {
+    /// Returns a builder for `DeleteFunctionConcurrencyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFunctionConcurrencyRequestBuilder {
+        DeleteFunctionConcurrencyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFunctionConcurrencyRequest`](struct.DeleteFunctionConcurrencyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFunctionConcurrencyRequestBuilder {
+    function_name: Option<String>,
+}
+
+impl DeleteFunctionConcurrencyRequestBuilder {
+    /// Sets the [`function_name`](struct.DeleteFunctionConcurrencyRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFunctionConcurrencyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteFunctionConcurrencyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFunctionConcurrencyRequest {
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteFunctionRequest {
     /// <p>The name of the Lambda function or version.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code> (name-only), <code>my-function:1</code> (with version).</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>You can append a version number or alias to any of the formats. The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
@@ -354,6 +954,44 @@ pub struct DeleteFunctionRequest {
     pub qualifier: Option<String>,
 }
 
+impl DeleteFunctionRequest {
+    /// Returns a builder for `DeleteFunctionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFunctionRequestBuilder {
+        DeleteFunctionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFunctionRequest`](struct.DeleteFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFunctionRequestBuilder {
+    function_name: Option<String>,
+    qualifier: Option<String>,
+}
+
+impl DeleteFunctionRequestBuilder {
+    /// Sets the [`function_name`](struct.DeleteFunctionRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualifier`](struct.DeleteFunctionRequest.html#structfield.qualifier) member.
+    pub fn qualifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFunctionRequest {
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            qualifier: self.qualifier,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteLayerVersionRequest {
     /// <p>The name or Amazon Resource Name (ARN) of the layer.</p>
@@ -364,6 +1002,48 @@ pub struct DeleteLayerVersionRequest {
     pub version_number: i64,
 }
 
+impl DeleteLayerVersionRequest {
+    /// Returns a builder for `DeleteLayerVersionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLayerVersionRequestBuilder {
+        DeleteLayerVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLayerVersionRequest`](struct.DeleteLayerVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLayerVersionRequestBuilder {
+    layer_name: Option<String>,
+    version_number: Option<i64>,
+}
+
+impl DeleteLayerVersionRequestBuilder {
+    /// Sets the [`layer_name`](struct.DeleteLayerVersionRequest.html#structfield.layer_name) member.
+    pub fn layer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_number`](struct.DeleteLayerVersionRequest.html#structfield.version_number) member.
+    pub fn version_number<T: Into<i64>>(mut self, value: T) -> Self {
+        self.version_number = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLayerVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLayerVersionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLayerVersionRequest {
+            layer_name: self
+                .layer_name
+                .ok_or(::rusoto_core::BuildError::missing("layer_name"))?,
+            version_number: self
+                .version_number
+                .ok_or(::rusoto_core::BuildError::missing("version_number"))?,
+        })
+    }
+}
+
 /// <p>A function's environment variable settings.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct Environment {
@@ -419,8 +1099,9 @@ pub struct EventSourceMappingConfiguration {
     pub function_arn: Option<String>,
     /// <p>The date that the event source mapping was last updated.</p>
     #[serde(rename = "LastModified")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<EpochTimestamp>,
     /// <p>The result of the last AWS Lambda invocation of your Lambda function.</p>
     #[serde(rename = "LastProcessingResult")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -442,6 +1123,88 @@ pub struct EventSourceMappingConfiguration {
     pub uuid: Option<String>,
 }
 
+/// <p>The position in a stream from which to start reading. Required for Amazon Kinesis and Amazon DynamoDB Streams sources. <code>AT_TIMESTAMP</code> is only supported for Amazon Kinesis streams.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventSourcePosition {
+    /// <p><code>TRIM_HORIZON</code></p>
+    TrimHorizon,
+    /// <p><code>LATEST</code></p>
+    Latest,
+    /// <p><code>AT_TIMESTAMP</code></p>
+    AtTimestamp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventSourcePosition {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventSourcePosition::TrimHorizon => "TRIM_HORIZON",
+            EventSourcePosition::Latest => "LATEST",
+            EventSourcePosition::AtTimestamp => "AT_TIMESTAMP",
+            EventSourcePosition::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventSourcePosition {
+    fn from(value: &'a str) -> EventSourcePosition {
+        match value {
+            "TRIM_HORIZON" => EventSourcePosition::TrimHorizon,
+            "LATEST" => EventSourcePosition::Latest,
+            "AT_TIMESTAMP" => EventSourcePosition::AtTimestamp,
+            _ => EventSourcePosition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventSourcePosition {
+    fn from(value: String) -> EventSourcePosition {
+        EventSourcePosition::from(value.as_str())
+    }
+}
+
+impl From<EventSourcePosition> for String {
+    fn from(value: EventSourcePosition) -> String {
+        match value {
+            EventSourcePosition::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventSourcePosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EventSourcePosition {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EventSourcePosition {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventSourcePosition {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EventSourcePosition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EventSourcePosition::from(value))
+    }
+}
+
 /// <p>The code for the Lambda function. You can specify either an object in Amazon S3, or upload a deployment package directly.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct FunctionCode {
@@ -549,7 +1312,7 @@ pub struct FunctionConfiguration {
     /// <p>The runtime environment for the Lambda function.</p>
     #[serde(rename = "Runtime")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub runtime: Option<String>,
+    pub runtime: Option<Runtime>,
     /// <p>The amount of time that Lambda allows a function to run before stopping it.</p>
     #[serde(rename = "Timeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -568,39 +1331,185 @@ pub struct FunctionConfiguration {
     pub vpc_config: Option<VpcConfigResponse>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetAccountSettingsRequest {}
+/// <p>Set to <code>ALL</code> to include entries for all published versions of each function.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FunctionVersion {
+    /// <p><code>ALL</code></p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetAccountSettingsResponse {
-    /// <p>Limits that are related to concurrency and code storage.</p>
-    #[serde(rename = "AccountLimit")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_limit: Option<AccountLimit>,
-    /// <p>The number of functions and amount of storage in use.</p>
-    #[serde(rename = "AccountUsage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub account_usage: Option<AccountUsage>,
+impl FunctionVersion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FunctionVersion::All => "ALL",
+            FunctionVersion::Unknown(ref value) => value,
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetAliasRequest {
-    /// <p>The name of the Lambda function.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>MyFunction</code>.</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:MyFunction</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:MyFunction</code>.</p> </li> </ul> <p>The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
-    #[serde(rename = "FunctionName")]
-    pub function_name: String,
-    /// <p>The name of the alias.</p>
-    #[serde(rename = "Name")]
-    pub name: String,
+impl<'a> From<&'a str> for FunctionVersion {
+    fn from(value: &'a str) -> FunctionVersion {
+        match value {
+            "ALL" => FunctionVersion::All,
+            _ => FunctionVersion::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct GetEventSourceMappingRequest {
-    /// <p>The identifier of the event source mapping.</p>
-    #[serde(rename = "UUID")]
+impl From<String> for FunctionVersion {
+    fn from(value: String) -> FunctionVersion {
+        FunctionVersion::from(value.as_str())
+    }
+}
+
+impl From<FunctionVersion> for String {
+    fn from(value: FunctionVersion) -> String {
+        match value {
+            FunctionVersion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FunctionVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FunctionVersion {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FunctionVersion {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FunctionVersion {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FunctionVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FunctionVersion::from(value))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetAccountSettingsRequest {}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetAccountSettingsResponse {
+    /// <p>Limits that are related to concurrency and code storage.</p>
+    #[serde(rename = "AccountLimit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_limit: Option<AccountLimit>,
+    /// <p>The number of functions and amount of storage in use.</p>
+    #[serde(rename = "AccountUsage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_usage: Option<AccountUsage>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetAliasRequest {
+    /// <p>The name of the Lambda function.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>MyFunction</code>.</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:MyFunction</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:MyFunction</code>.</p> </li> </ul> <p>The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
+    #[serde(rename = "FunctionName")]
+    pub function_name: String,
+    /// <p>The name of the alias.</p>
+    #[serde(rename = "Name")]
+    pub name: String,
+}
+
+impl GetAliasRequest {
+    /// Returns a builder for `GetAliasRequest`, checking its required members when built.
+    pub fn builder() -> GetAliasRequestBuilder {
+        GetAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAliasRequest`](struct.GetAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAliasRequestBuilder {
+    function_name: Option<String>,
+    name: Option<String>,
+}
+
+impl GetAliasRequestBuilder {
+    /// Sets the [`function_name`](struct.GetAliasRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetAliasRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetAliasRequest, ::rusoto_core::BuildError> {
+        Ok(GetAliasRequest {
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct GetEventSourceMappingRequest {
+    /// <p>The identifier of the event source mapping.</p>
+    #[serde(rename = "UUID")]
     pub uuid: String,
 }
 
+impl GetEventSourceMappingRequest {
+    /// Returns a builder for `GetEventSourceMappingRequest`, checking its required members when built.
+    pub fn builder() -> GetEventSourceMappingRequestBuilder {
+        GetEventSourceMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetEventSourceMappingRequest`](struct.GetEventSourceMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct GetEventSourceMappingRequestBuilder {
+    uuid: Option<String>,
+}
+
+impl GetEventSourceMappingRequestBuilder {
+    /// Sets the [`uuid`](struct.GetEventSourceMappingRequest.html#structfield.uuid) member.
+    pub fn uuid<T: Into<String>>(mut self, value: T) -> Self {
+        self.uuid = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetEventSourceMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetEventSourceMappingRequest, ::rusoto_core::BuildError> {
+        Ok(GetEventSourceMappingRequest {
+            uuid: self
+                .uuid
+                .ok_or(::rusoto_core::BuildError::missing("uuid"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetFunctionConfigurationRequest {
     /// <p>The name of the Lambda function, version, or alias.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code> (name-only), <code>my-function:v1</code> (with alias).</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>You can append a version number or alias to any of the formats. The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
@@ -612,6 +1521,46 @@ pub struct GetFunctionConfigurationRequest {
     pub qualifier: Option<String>,
 }
 
+impl GetFunctionConfigurationRequest {
+    /// Returns a builder for `GetFunctionConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> GetFunctionConfigurationRequestBuilder {
+        GetFunctionConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFunctionConfigurationRequest`](struct.GetFunctionConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFunctionConfigurationRequestBuilder {
+    function_name: Option<String>,
+    qualifier: Option<String>,
+}
+
+impl GetFunctionConfigurationRequestBuilder {
+    /// Sets the [`function_name`](struct.GetFunctionConfigurationRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualifier`](struct.GetFunctionConfigurationRequest.html#structfield.qualifier) member.
+    pub fn qualifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFunctionConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetFunctionConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(GetFunctionConfigurationRequest {
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            qualifier: self.qualifier,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetFunctionRequest {
     /// <p>The name of the Lambda function, version, or alias.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code> (name-only), <code>my-function:v1</code> (with alias).</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>You can append a version number or alias to any of the formats. The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
@@ -623,6 +1572,44 @@ pub struct GetFunctionRequest {
     pub qualifier: Option<String>,
 }
 
+impl GetFunctionRequest {
+    /// Returns a builder for `GetFunctionRequest`, checking its required members when built.
+    pub fn builder() -> GetFunctionRequestBuilder {
+        GetFunctionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFunctionRequest`](struct.GetFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFunctionRequestBuilder {
+    function_name: Option<String>,
+    qualifier: Option<String>,
+}
+
+impl GetFunctionRequestBuilder {
+    /// Sets the [`function_name`](struct.GetFunctionRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualifier`](struct.GetFunctionRequest.html#structfield.qualifier) member.
+    pub fn qualifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(GetFunctionRequest {
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            qualifier: self.qualifier,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFunctionResponse {
@@ -651,6 +1638,36 @@ pub struct GetLayerVersionByArnRequest {
     pub arn: String,
 }
 
+impl GetLayerVersionByArnRequest {
+    /// Returns a builder for `GetLayerVersionByArnRequest`, checking its required members when built.
+    pub fn builder() -> GetLayerVersionByArnRequestBuilder {
+        GetLayerVersionByArnRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLayerVersionByArnRequest`](struct.GetLayerVersionByArnRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLayerVersionByArnRequestBuilder {
+    arn: Option<String>,
+}
+
+impl GetLayerVersionByArnRequestBuilder {
+    /// Sets the [`arn`](struct.GetLayerVersionByArnRequest.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLayerVersionByArnRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLayerVersionByArnRequest, ::rusoto_core::BuildError> {
+        Ok(GetLayerVersionByArnRequest {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetLayerVersionPolicyRequest {
     /// <p>The name or Amazon Resource Name (ARN) of the layer.</p>
@@ -661,6 +1678,48 @@ pub struct GetLayerVersionPolicyRequest {
     pub version_number: i64,
 }
 
+impl GetLayerVersionPolicyRequest {
+    /// Returns a builder for `GetLayerVersionPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetLayerVersionPolicyRequestBuilder {
+        GetLayerVersionPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLayerVersionPolicyRequest`](struct.GetLayerVersionPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLayerVersionPolicyRequestBuilder {
+    layer_name: Option<String>,
+    version_number: Option<i64>,
+}
+
+impl GetLayerVersionPolicyRequestBuilder {
+    /// Sets the [`layer_name`](struct.GetLayerVersionPolicyRequest.html#structfield.layer_name) member.
+    pub fn layer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_number`](struct.GetLayerVersionPolicyRequest.html#structfield.version_number) member.
+    pub fn version_number<T: Into<i64>>(mut self, value: T) -> Self {
+        self.version_number = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLayerVersionPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLayerVersionPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetLayerVersionPolicyRequest {
+            layer_name: self
+                .layer_name
+                .ok_or(::rusoto_core::BuildError::missing("layer_name"))?,
+            version_number: self
+                .version_number
+                .ok_or(::rusoto_core::BuildError::missing("version_number"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLayerVersionPolicyResponse {
@@ -684,13 +1743,53 @@ pub struct GetLayerVersionRequest {
     pub version_number: i64,
 }
 
+impl GetLayerVersionRequest {
+    /// Returns a builder for `GetLayerVersionRequest`, checking its required members when built.
+    pub fn builder() -> GetLayerVersionRequestBuilder {
+        GetLayerVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLayerVersionRequest`](struct.GetLayerVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLayerVersionRequestBuilder {
+    layer_name: Option<String>,
+    version_number: Option<i64>,
+}
+
+impl GetLayerVersionRequestBuilder {
+    /// Sets the [`layer_name`](struct.GetLayerVersionRequest.html#structfield.layer_name) member.
+    pub fn layer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_number`](struct.GetLayerVersionRequest.html#structfield.version_number) member.
+    pub fn version_number<T: Into<i64>>(mut self, value: T) -> Self {
+        self.version_number = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLayerVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetLayerVersionRequest, ::rusoto_core::BuildError> {
+        Ok(GetLayerVersionRequest {
+            layer_name: self
+                .layer_name
+                .ok_or(::rusoto_core::BuildError::missing("layer_name"))?,
+            version_number: self
+                .version_number
+                .ok_or(::rusoto_core::BuildError::missing("version_number"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLayerVersionResponse {
     /// <p>The layer's compatible runtimes.</p>
     #[serde(rename = "CompatibleRuntimes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_runtimes: Option<Vec<String>>,
+    pub compatible_runtimes: Option<Vec<Runtime>>,
     /// <p>Details about the layer version.</p>
     #[serde(rename = "Content")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -732,6 +1831,44 @@ pub struct GetPolicyRequest {
     pub qualifier: Option<String>,
 }
 
+impl GetPolicyRequest {
+    /// Returns a builder for `GetPolicyRequest`, checking its required members when built.
+    pub fn builder() -> GetPolicyRequestBuilder {
+        GetPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPolicyRequest`](struct.GetPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPolicyRequestBuilder {
+    function_name: Option<String>,
+    qualifier: Option<String>,
+}
+
+impl GetPolicyRequestBuilder {
+    /// Sets the [`function_name`](struct.GetPolicyRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualifier`](struct.GetPolicyRequest.html#structfield.qualifier) member.
+    pub fn qualifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(GetPolicyRequest {
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            qualifier: self.qualifier,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPolicyResponse {
@@ -757,11 +1894,11 @@ pub struct InvocationRequest {
     /// <p><p>Choose from the following options.</p> <ul> <li> <p> <code>RequestResponse</code> (default) - Invoke the function synchronously. Keep the connection open until the function returns a response or times out. The API response includes the function response and additional data.</p> </li> <li> <p> <code>Event</code> - Invoke the function asynchronously. Send events that fail multiple times to the function&#39;s dead-letter queue (if it&#39;s configured). The API response only includes a status code.</p> </li> <li> <p> <code>DryRun</code> - Validate parameter values and verify that the user or role has permission to invoke the function.</p> </li> </ul></p>
     #[serde(rename = "InvocationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invocation_type: Option<String>,
+    pub invocation_type: Option<InvocationType>,
     /// <p>Set to <code>Tail</code> to include the execution log in the response.</p>
     #[serde(rename = "LogType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub log_type: Option<String>,
+    pub log_type: Option<LogType>,
     /// <p>The JSON that you want to provide to your Lambda function as input.</p>
     #[serde(rename = "Payload")]
     #[serde(
@@ -777,6 +1914,76 @@ pub struct InvocationRequest {
     pub qualifier: Option<String>,
 }
 
+impl InvocationRequest {
+    /// Returns a builder for `InvocationRequest`, checking its required members when built.
+    pub fn builder() -> InvocationRequestBuilder {
+        InvocationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InvocationRequest`](struct.InvocationRequest.html).
+#[derive(Default, Debug)]
+pub struct InvocationRequestBuilder {
+    client_context: Option<String>,
+    function_name: Option<String>,
+    invocation_type: Option<InvocationType>,
+    log_type: Option<LogType>,
+    payload: Option<bytes::Bytes>,
+    qualifier: Option<String>,
+}
+
+impl InvocationRequestBuilder {
+    /// Sets the [`client_context`](struct.InvocationRequest.html#structfield.client_context) member.
+    pub fn client_context<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_context = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.InvocationRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`invocation_type`](struct.InvocationRequest.html#structfield.invocation_type) member.
+    pub fn invocation_type<T: Into<InvocationType>>(mut self, value: T) -> Self {
+        self.invocation_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_type`](struct.InvocationRequest.html#structfield.log_type) member.
+    pub fn log_type<T: Into<LogType>>(mut self, value: T) -> Self {
+        self.log_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`payload`](struct.InvocationRequest.html#structfield.payload) member.
+    pub fn payload<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.payload = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualifier`](struct.InvocationRequest.html#structfield.qualifier) member.
+    pub fn qualifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `InvocationRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<InvocationRequest, ::rusoto_core::BuildError> {
+        Ok(InvocationRequest {
+            client_context: self.client_context,
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            invocation_type: self.invocation_type,
+            log_type: self.log_type,
+            payload: self.payload,
+            qualifier: self.qualifier,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct InvocationResponse {
     /// <p>The version of the function that executed. When you invoke a function with an alias, this indicates which version the alias resolved to.</p>
@@ -791,6 +1998,88 @@ pub struct InvocationResponse {
     pub status_code: Option<i64>,
 }
 
+/// <p><p>Choose from the following options.</p> <ul> <li> <p> <code>RequestResponse</code> (default) - Invoke the function synchronously. Keep the connection open until the function returns a response or times out. The API response includes the function response and additional data.</p> </li> <li> <p> <code>Event</code> - Invoke the function asynchronously. Send events that fail multiple times to the function&#39;s dead-letter queue (if it&#39;s configured). The API response only includes a status code.</p> </li> <li> <p> <code>DryRun</code> - Validate parameter values and verify that the user or role has permission to invoke the function.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InvocationType {
+    /// <p><code>Event</code> - Invoke the function asynchronously. Send events that fail multiple times to the function&#39;s dead-letter queue (if it&#39;s configured). The API response only includes a status code.</p>
+    Event,
+    /// <p><code>RequestResponse</code></p>
+    RequestResponse,
+    /// <p><code>DryRun</code> - Validate parameter values and verify that the user or role has permission to invoke the function.</p>
+    DryRun,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InvocationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InvocationType::Event => "Event",
+            InvocationType::RequestResponse => "RequestResponse",
+            InvocationType::DryRun => "DryRun",
+            InvocationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InvocationType {
+    fn from(value: &'a str) -> InvocationType {
+        match value {
+            "Event" => InvocationType::Event,
+            "RequestResponse" => InvocationType::RequestResponse,
+            "DryRun" => InvocationType::DryRun,
+            _ => InvocationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InvocationType {
+    fn from(value: String) -> InvocationType {
+        InvocationType::from(value.as_str())
+    }
+}
+
+impl From<InvocationType> for String {
+    fn from(value: InvocationType) -> String {
+        match value {
+            InvocationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InvocationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for InvocationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for InvocationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InvocationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InvocationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InvocationType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct InvokeAsyncRequest {
     /// <p>The name of the Lambda function.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code>.</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
@@ -806,6 +2095,46 @@ pub struct InvokeAsyncRequest {
     pub invoke_args: bytes::Bytes,
 }
 
+impl InvokeAsyncRequest {
+    /// Returns a builder for `InvokeAsyncRequest`, checking its required members when built.
+    pub fn builder() -> InvokeAsyncRequestBuilder {
+        InvokeAsyncRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InvokeAsyncRequest`](struct.InvokeAsyncRequest.html).
+#[derive(Default, Debug)]
+pub struct InvokeAsyncRequestBuilder {
+    function_name: Option<String>,
+    invoke_args: Option<bytes::Bytes>,
+}
+
+impl InvokeAsyncRequestBuilder {
+    /// Sets the [`function_name`](struct.InvokeAsyncRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`invoke_args`](struct.InvokeAsyncRequest.html#structfield.invoke_args) member.
+    pub fn invoke_args<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.invoke_args = Some(value.into());
+        self
+    }
+
+    /// Builds the `InvokeAsyncRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<InvokeAsyncRequest, ::rusoto_core::BuildError> {
+        Ok(InvokeAsyncRequest {
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            invoke_args: self
+                .invoke_args
+                .ok_or(::rusoto_core::BuildError::missing("invoke_args"))?,
+        })
+    }
+}
+
 /// <p>A success response (<code>202 Accepted</code>) indicates that the request is queued for invocation. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -881,7 +2210,7 @@ pub struct LayerVersionsListItem {
     /// <p>The layer's compatible runtimes.</p>
     #[serde(rename = "CompatibleRuntimes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_runtimes: Option<Vec<String>>,
+    pub compatible_runtimes: Option<Vec<Runtime>>,
     /// <p>The date that the version was created, in ISO 8601 format. For example, <code>2018-11-27T15:10:45.123+0000</code>.</p>
     #[serde(rename = "CreatedDate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -941,6 +2270,60 @@ pub struct ListAliasesRequest {
     pub max_items: Option<i64>,
 }
 
+impl ListAliasesRequest {
+    /// Returns a builder for `ListAliasesRequest`, checking its required members when built.
+    pub fn builder() -> ListAliasesRequestBuilder {
+        ListAliasesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAliasesRequest`](struct.ListAliasesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAliasesRequestBuilder {
+    function_name: Option<String>,
+    function_version: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl ListAliasesRequestBuilder {
+    /// Sets the [`function_name`](struct.ListAliasesRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_version`](struct.ListAliasesRequest.html#structfield.function_version) member.
+    pub fn function_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListAliasesRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListAliasesRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAliasesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListAliasesRequest, ::rusoto_core::BuildError> {
+        Ok(ListAliasesRequest {
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            function_version: self.function_version,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAliasesResponse {
@@ -974,25 +2357,79 @@ pub struct ListEventSourceMappingsRequest {
     pub max_items: Option<i64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListEventSourceMappingsResponse {
-    /// <p>A list of event source mappings.</p>
-    #[serde(rename = "EventSourceMappings")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_source_mappings: Option<Vec<EventSourceMappingConfiguration>>,
-    /// <p>A pagination token that's returned when the response doesn't contain all event source mappings.</p>
-    #[serde(rename = "NextMarker")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_marker: Option<String>,
+impl ListEventSourceMappingsRequest {
+    /// Returns a builder for `ListEventSourceMappingsRequest`, checking its required members when built.
+    pub fn builder() -> ListEventSourceMappingsRequestBuilder {
+        ListEventSourceMappingsRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ListFunctionsRequest {
-    /// <p>Set to <code>ALL</code> to include entries for all published versions of each function.</p>
-    #[serde(rename = "FunctionVersion")]
+/// Builds a [`ListEventSourceMappingsRequest`](struct.ListEventSourceMappingsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListEventSourceMappingsRequestBuilder {
+    event_source_arn: Option<String>,
+    function_name: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl ListEventSourceMappingsRequestBuilder {
+    /// Sets the [`event_source_arn`](struct.ListEventSourceMappingsRequest.html#structfield.event_source_arn) member.
+    pub fn event_source_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.event_source_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.ListEventSourceMappingsRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListEventSourceMappingsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListEventSourceMappingsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListEventSourceMappingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListEventSourceMappingsRequest, ::rusoto_core::BuildError> {
+        Ok(ListEventSourceMappingsRequest {
+            event_source_arn: self.event_source_arn,
+            function_name: self.function_name,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListEventSourceMappingsResponse {
+    /// <p>A list of event source mappings.</p>
+    #[serde(rename = "EventSourceMappings")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub function_version: Option<String>,
+    pub event_source_mappings: Option<Vec<EventSourceMappingConfiguration>>,
+    /// <p>A pagination token that's returned when the response doesn't contain all event source mappings.</p>
+    #[serde(rename = "NextMarker")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_marker: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListFunctionsRequest {
+    /// <p>Set to <code>ALL</code> to include entries for all published versions of each function.</p>
+    #[serde(rename = "FunctionVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function_version: Option<FunctionVersion>,
     /// <p>Specify the pagination token that's returned by a previous request to retrieve the next page of results.</p>
     #[serde(rename = "Marker")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1007,6 +2444,58 @@ pub struct ListFunctionsRequest {
     pub max_items: Option<i64>,
 }
 
+impl ListFunctionsRequest {
+    /// Returns a builder for `ListFunctionsRequest`, checking its required members when built.
+    pub fn builder() -> ListFunctionsRequestBuilder {
+        ListFunctionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFunctionsRequest`](struct.ListFunctionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFunctionsRequestBuilder {
+    function_version: Option<FunctionVersion>,
+    marker: Option<String>,
+    master_region: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl ListFunctionsRequestBuilder {
+    /// Sets the [`function_version`](struct.ListFunctionsRequest.html#structfield.function_version) member.
+    pub fn function_version<T: Into<FunctionVersion>>(mut self, value: T) -> Self {
+        self.function_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListFunctionsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_region`](struct.ListFunctionsRequest.html#structfield.master_region) member.
+    pub fn master_region<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListFunctionsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFunctionsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListFunctionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListFunctionsRequest {
+            function_version: self.function_version,
+            marker: self.marker,
+            master_region: self.master_region,
+            max_items: self.max_items,
+        })
+    }
+}
+
 /// <p>A list of Lambda functions.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1026,7 +2515,7 @@ pub struct ListLayerVersionsRequest {
     /// <p>A runtime identifier. For example, <code>go1.x</code>.</p>
     #[serde(rename = "CompatibleRuntime")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_runtime: Option<String>,
+    pub compatible_runtime: Option<Runtime>,
     /// <p>The name or Amazon Resource Name (ARN) of the layer.</p>
     #[serde(rename = "LayerName")]
     pub layer_name: String,
@@ -1040,6 +2529,62 @@ pub struct ListLayerVersionsRequest {
     pub max_items: Option<i64>,
 }
 
+impl ListLayerVersionsRequest {
+    /// Returns a builder for `ListLayerVersionsRequest`, checking its required members when built.
+    pub fn builder() -> ListLayerVersionsRequestBuilder {
+        ListLayerVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListLayerVersionsRequest`](struct.ListLayerVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListLayerVersionsRequestBuilder {
+    compatible_runtime: Option<Runtime>,
+    layer_name: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl ListLayerVersionsRequestBuilder {
+    /// Sets the [`compatible_runtime`](struct.ListLayerVersionsRequest.html#structfield.compatible_runtime) member.
+    pub fn compatible_runtime<T: Into<Runtime>>(mut self, value: T) -> Self {
+        self.compatible_runtime = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layer_name`](struct.ListLayerVersionsRequest.html#structfield.layer_name) member.
+    pub fn layer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListLayerVersionsRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListLayerVersionsRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLayerVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListLayerVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListLayerVersionsRequest {
+            compatible_runtime: self.compatible_runtime,
+            layer_name: self
+                .layer_name
+                .ok_or(::rusoto_core::BuildError::missing("layer_name"))?,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLayerVersionsResponse {
@@ -1058,7 +2603,7 @@ pub struct ListLayersRequest {
     /// <p>A runtime identifier. For example, <code>go1.x</code>.</p>
     #[serde(rename = "CompatibleRuntime")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_runtime: Option<String>,
+    pub compatible_runtime: Option<Runtime>,
     /// <p>A pagination token returned by a previous call.</p>
     #[serde(rename = "Marker")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1069,6 +2614,50 @@ pub struct ListLayersRequest {
     pub max_items: Option<i64>,
 }
 
+impl ListLayersRequest {
+    /// Returns a builder for `ListLayersRequest`, checking its required members when built.
+    pub fn builder() -> ListLayersRequestBuilder {
+        ListLayersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListLayersRequest`](struct.ListLayersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListLayersRequestBuilder {
+    compatible_runtime: Option<Runtime>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl ListLayersRequestBuilder {
+    /// Sets the [`compatible_runtime`](struct.ListLayersRequest.html#structfield.compatible_runtime) member.
+    pub fn compatible_runtime<T: Into<Runtime>>(mut self, value: T) -> Self {
+        self.compatible_runtime = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListLayersRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListLayersRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLayersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListLayersRequest, ::rusoto_core::BuildError> {
+        Ok(ListLayersRequest {
+            compatible_runtime: self.compatible_runtime,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLayersResponse {
@@ -1089,6 +2678,36 @@ pub struct ListTagsRequest {
     pub resource: String,
 }
 
+impl ListTagsRequest {
+    /// Returns a builder for `ListTagsRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsRequestBuilder {
+        ListTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsRequest`](struct.ListTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsRequestBuilder {
+    resource: Option<String>,
+}
+
+impl ListTagsRequestBuilder {
+    /// Sets the [`resource`](struct.ListTagsRequest.html#structfield.resource) member.
+    pub fn resource<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTagsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsRequest {
+            resource: self
+                .resource
+                .ok_or(::rusoto_core::BuildError::missing("resource"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsResponse {
@@ -1113,6 +2732,54 @@ pub struct ListVersionsByFunctionRequest {
     pub max_items: Option<i64>,
 }
 
+impl ListVersionsByFunctionRequest {
+    /// Returns a builder for `ListVersionsByFunctionRequest`, checking its required members when built.
+    pub fn builder() -> ListVersionsByFunctionRequestBuilder {
+        ListVersionsByFunctionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListVersionsByFunctionRequest`](struct.ListVersionsByFunctionRequest.html).
+#[derive(Default, Debug)]
+pub struct ListVersionsByFunctionRequestBuilder {
+    function_name: Option<String>,
+    marker: Option<String>,
+    max_items: Option<i64>,
+}
+
+impl ListVersionsByFunctionRequestBuilder {
+    /// Sets the [`function_name`](struct.ListVersionsByFunctionRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.ListVersionsByFunctionRequest.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_items`](struct.ListVersionsByFunctionRequest.html#structfield.max_items) member.
+    pub fn max_items<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_items = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListVersionsByFunctionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListVersionsByFunctionRequest, ::rusoto_core::BuildError> {
+        Ok(ListVersionsByFunctionRequest {
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            marker: self.marker,
+            max_items: self.max_items,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListVersionsByFunctionResponse {
@@ -1126,12 +2793,90 @@ pub struct ListVersionsByFunctionResponse {
     pub versions: Option<Vec<FunctionConfiguration>>,
 }
 
+/// <p>Set to <code>Tail</code> to include the execution log in the response.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LogType {
+    /// <p><code>None</code></p>
+    None,
+    /// <p><code>Tail</code></p>
+    Tail,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LogType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LogType::None => "None",
+            LogType::Tail => "Tail",
+            LogType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LogType {
+    fn from(value: &'a str) -> LogType {
+        match value {
+            "None" => LogType::None,
+            "Tail" => LogType::Tail,
+            _ => LogType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LogType {
+    fn from(value: String) -> LogType {
+        LogType::from(value.as_str())
+    }
+}
+
+impl From<LogType> for String {
+    fn from(value: LogType) -> String {
+        match value {
+            LogType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LogType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LogType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LogType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LogType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LogType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LogType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PublishLayerVersionRequest {
     /// <p>A list of compatible <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">function runtimes</a>. Used for filtering with <a>ListLayers</a> and <a>ListLayerVersions</a>.</p>
     #[serde(rename = "CompatibleRuntimes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_runtimes: Option<Vec<String>>,
+    pub compatible_runtimes: Option<Vec<Runtime>>,
     /// <p>The function layer archive.</p>
     #[serde(rename = "Content")]
     pub content: LayerVersionContentInput,
@@ -1148,13 +2893,79 @@ pub struct PublishLayerVersionRequest {
     pub license_info: Option<String>,
 }
 
+impl PublishLayerVersionRequest {
+    /// Returns a builder for `PublishLayerVersionRequest`, checking its required members when built.
+    pub fn builder() -> PublishLayerVersionRequestBuilder {
+        PublishLayerVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PublishLayerVersionRequest`](struct.PublishLayerVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct PublishLayerVersionRequestBuilder {
+    compatible_runtimes: Option<Vec<Runtime>>,
+    content: Option<LayerVersionContentInput>,
+    description: Option<String>,
+    layer_name: Option<String>,
+    license_info: Option<String>,
+}
+
+impl PublishLayerVersionRequestBuilder {
+    /// Sets the [`compatible_runtimes`](struct.PublishLayerVersionRequest.html#structfield.compatible_runtimes) member.
+    pub fn compatible_runtimes<T: Into<Vec<Runtime>>>(mut self, value: T) -> Self {
+        self.compatible_runtimes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content`](struct.PublishLayerVersionRequest.html#structfield.content) member.
+    pub fn content<T: Into<LayerVersionContentInput>>(mut self, value: T) -> Self {
+        self.content = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.PublishLayerVersionRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layer_name`](struct.PublishLayerVersionRequest.html#structfield.layer_name) member.
+    pub fn layer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_info`](struct.PublishLayerVersionRequest.html#structfield.license_info) member.
+    pub fn license_info<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_info = Some(value.into());
+        self
+    }
+
+    /// Builds the `PublishLayerVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PublishLayerVersionRequest, ::rusoto_core::BuildError> {
+        Ok(PublishLayerVersionRequest {
+            compatible_runtimes: self.compatible_runtimes,
+            content: self
+                .content
+                .ok_or(::rusoto_core::BuildError::missing("content"))?,
+            description: self.description,
+            layer_name: self
+                .layer_name
+                .ok_or(::rusoto_core::BuildError::missing("layer_name"))?,
+            license_info: self.license_info,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PublishLayerVersionResponse {
     /// <p>The layer's compatible runtimes.</p>
     #[serde(rename = "CompatibleRuntimes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub compatible_runtimes: Option<Vec<String>>,
+    pub compatible_runtimes: Option<Vec<Runtime>>,
     /// <p>Details about the layer version.</p>
     #[serde(rename = "Content")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1204,6 +3015,60 @@ pub struct PublishVersionRequest {
     pub revision_id: Option<String>,
 }
 
+impl PublishVersionRequest {
+    /// Returns a builder for `PublishVersionRequest`, checking its required members when built.
+    pub fn builder() -> PublishVersionRequestBuilder {
+        PublishVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PublishVersionRequest`](struct.PublishVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct PublishVersionRequestBuilder {
+    code_sha_256: Option<String>,
+    description: Option<String>,
+    function_name: Option<String>,
+    revision_id: Option<String>,
+}
+
+impl PublishVersionRequestBuilder {
+    /// Sets the [`code_sha_256`](struct.PublishVersionRequest.html#structfield.code_sha_256) member.
+    pub fn code_sha_256<T: Into<String>>(mut self, value: T) -> Self {
+        self.code_sha_256 = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.PublishVersionRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.PublishVersionRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision_id`](struct.PublishVersionRequest.html#structfield.revision_id) member.
+    pub fn revision_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.revision_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PublishVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PublishVersionRequest, ::rusoto_core::BuildError> {
+        Ok(PublishVersionRequest {
+            code_sha_256: self.code_sha_256,
+            description: self.description,
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            revision_id: self.revision_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutFunctionConcurrencyRequest {
     /// <p>The name of the Lambda function.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code>.</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
@@ -1214,6 +3079,48 @@ pub struct PutFunctionConcurrencyRequest {
     pub reserved_concurrent_executions: i64,
 }
 
+impl PutFunctionConcurrencyRequest {
+    /// Returns a builder for `PutFunctionConcurrencyRequest`, checking its required members when built.
+    pub fn builder() -> PutFunctionConcurrencyRequestBuilder {
+        PutFunctionConcurrencyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutFunctionConcurrencyRequest`](struct.PutFunctionConcurrencyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutFunctionConcurrencyRequestBuilder {
+    function_name: Option<String>,
+    reserved_concurrent_executions: Option<i64>,
+}
+
+impl PutFunctionConcurrencyRequestBuilder {
+    /// Sets the [`function_name`](struct.PutFunctionConcurrencyRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_concurrent_executions`](struct.PutFunctionConcurrencyRequest.html#structfield.reserved_concurrent_executions) member.
+    pub fn reserved_concurrent_executions<T: Into<i64>>(mut self, value: T) -> Self {
+        self.reserved_concurrent_executions = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutFunctionConcurrencyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutFunctionConcurrencyRequest, ::rusoto_core::BuildError> {
+        Ok(PutFunctionConcurrencyRequest {
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            reserved_concurrent_executions: self.reserved_concurrent_executions.ok_or(
+                ::rusoto_core::BuildError::missing("reserved_concurrent_executions"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RemoveLayerVersionPermissionRequest {
     /// <p>The name or Amazon Resource Name (ARN) of the layer.</p>
@@ -1231,6 +3138,66 @@ pub struct RemoveLayerVersionPermissionRequest {
     pub version_number: i64,
 }
 
+impl RemoveLayerVersionPermissionRequest {
+    /// Returns a builder for `RemoveLayerVersionPermissionRequest`, checking its required members when built.
+    pub fn builder() -> RemoveLayerVersionPermissionRequestBuilder {
+        RemoveLayerVersionPermissionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveLayerVersionPermissionRequest`](struct.RemoveLayerVersionPermissionRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveLayerVersionPermissionRequestBuilder {
+    layer_name: Option<String>,
+    revision_id: Option<String>,
+    statement_id: Option<String>,
+    version_number: Option<i64>,
+}
+
+impl RemoveLayerVersionPermissionRequestBuilder {
+    /// Sets the [`layer_name`](struct.RemoveLayerVersionPermissionRequest.html#structfield.layer_name) member.
+    pub fn layer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision_id`](struct.RemoveLayerVersionPermissionRequest.html#structfield.revision_id) member.
+    pub fn revision_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.revision_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statement_id`](struct.RemoveLayerVersionPermissionRequest.html#structfield.statement_id) member.
+    pub fn statement_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.statement_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_number`](struct.RemoveLayerVersionPermissionRequest.html#structfield.version_number) member.
+    pub fn version_number<T: Into<i64>>(mut self, value: T) -> Self {
+        self.version_number = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveLayerVersionPermissionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveLayerVersionPermissionRequest, ::rusoto_core::BuildError> {
+        Ok(RemoveLayerVersionPermissionRequest {
+            layer_name: self
+                .layer_name
+                .ok_or(::rusoto_core::BuildError::missing("layer_name"))?,
+            revision_id: self.revision_id,
+            statement_id: self
+                .statement_id
+                .ok_or(::rusoto_core::BuildError::missing("statement_id"))?,
+            version_number: self
+                .version_number
+                .ok_or(::rusoto_core::BuildError::missing("version_number"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RemovePermissionRequest {
     /// <p>The name of the Lambda function, version, or alias.</p> <p class="title"> <b>Name formats</b> </p> <ul> <li> <p> <b>Function name</b> - <code>my-function</code> (name-only), <code>my-function:v1</code> (with alias).</p> </li> <li> <p> <b>Function ARN</b> - <code>arn:aws:lambda:us-west-2:123456789012:function:my-function</code>.</p> </li> <li> <p> <b>Partial ARN</b> - <code>123456789012:function:my-function</code>.</p> </li> </ul> <p>You can append a version number or alias to any of the formats. The length constraint applies only to the full ARN. If you specify only the function name, it is limited to 64 characters in length.</p>
@@ -1249,6 +3216,278 @@ pub struct RemovePermissionRequest {
     pub statement_id: String,
 }
 
+impl RemovePermissionRequest {
+    /// Returns a builder for `RemovePermissionRequest`, checking its required members when built.
+    pub fn builder() -> RemovePermissionRequestBuilder {
+        RemovePermissionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemovePermissionRequest`](struct.RemovePermissionRequest.html).
+#[derive(Default, Debug)]
+pub struct RemovePermissionRequestBuilder {
+    function_name: Option<String>,
+    qualifier: Option<String>,
+    revision_id: Option<String>,
+    statement_id: Option<String>,
+}
+
+impl RemovePermissionRequestBuilder {
+    /// Sets the [`function_name`](struct.RemovePermissionRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualifier`](struct.RemovePermissionRequest.html#structfield.qualifier) member.
+    pub fn qualifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision_id`](struct.RemovePermissionRequest.html#structfield.revision_id) member.
+    pub fn revision_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.revision_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statement_id`](struct.RemovePermissionRequest.html#structfield.statement_id) member.
+    pub fn statement_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.statement_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemovePermissionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemovePermissionRequest, ::rusoto_core::BuildError> {
+        Ok(RemovePermissionRequest {
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            qualifier: self.qualifier,
+            revision_id: self.revision_id,
+            statement_id: self
+                .statement_id
+                .ok_or(::rusoto_core::BuildError::missing("statement_id"))?,
+        })
+    }
+}
+
+/// <p>The identifier of the function's <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Runtime {
+    /// <p><code>nodejs</code></p>
+    Nodejs,
+    /// <p><code>nodejs4.3</code></p>
+    Nodejs43,
+    /// <p><code>nodejs6.10</code></p>
+    Nodejs610,
+    /// <p><code>nodejs8.10</code></p>
+    Nodejs810,
+    /// <p><code>nodejs10.x</code></p>
+    Nodejs10X,
+    /// <p><code>nodejs12.x</code></p>
+    Nodejs12X,
+    /// <p><code>nodejs14.x</code></p>
+    Nodejs14X,
+    /// <p><code>nodejs16.x</code></p>
+    Nodejs16X,
+    /// <p><code>java8</code></p>
+    Java8,
+    /// <p><code>java8.al2</code></p>
+    Java8Al2,
+    /// <p><code>java11</code></p>
+    Java11,
+    /// <p><code>python2.7</code></p>
+    Python27,
+    /// <p><code>python3.6</code></p>
+    Python36,
+    /// <p><code>python3.7</code></p>
+    Python37,
+    /// <p><code>python3.8</code></p>
+    Python38,
+    /// <p><code>python3.9</code></p>
+    Python39,
+    /// <p><code>dotnetcore1.0</code></p>
+    Dotnetcore10,
+    /// <p><code>dotnetcore2.0</code></p>
+    Dotnetcore20,
+    /// <p><code>dotnetcore2.1</code></p>
+    Dotnetcore21,
+    /// <p><code>dotnetcore3.1</code></p>
+    Dotnetcore31,
+    /// <p><code>dotnet6</code></p>
+    Dotnet6,
+    /// <p><code>nodejs4.3-edge</code></p>
+    Nodejs43Edge,
+    /// <p><code>go1.x</code></p>
+    Go1X,
+    /// <p><code>ruby2.5</code></p>
+    Ruby25,
+    /// <p><code>ruby2.7</code></p>
+    Ruby27,
+    /// <p><code>provided</code></p>
+    Provided,
+    /// <p><code>provided.al2</code></p>
+    ProvidedAl2,
+    /// <p><code>nodejs18.x</code></p>
+    Nodejs18X,
+    /// <p><code>python3.10</code></p>
+    Python310,
+    /// <p><code>java17</code></p>
+    Java17,
+    /// <p><code>ruby3.2</code></p>
+    Ruby32,
+    /// <p><code>python3.11</code></p>
+    Python311,
+    /// <p><code>nodejs20.x</code></p>
+    Nodejs20X,
+    /// <p><code>provided.al2023</code></p>
+    ProvidedAl2023,
+    /// <p><code>python3.12</code></p>
+    Python312,
+    /// <p><code>java21</code></p>
+    Java21,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Runtime {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Runtime::Nodejs => "nodejs",
+            Runtime::Nodejs43 => "nodejs4.3",
+            Runtime::Nodejs610 => "nodejs6.10",
+            Runtime::Nodejs810 => "nodejs8.10",
+            Runtime::Nodejs10X => "nodejs10.x",
+            Runtime::Nodejs12X => "nodejs12.x",
+            Runtime::Nodejs14X => "nodejs14.x",
+            Runtime::Nodejs16X => "nodejs16.x",
+            Runtime::Java8 => "java8",
+            Runtime::Java8Al2 => "java8.al2",
+            Runtime::Java11 => "java11",
+            Runtime::Python27 => "python2.7",
+            Runtime::Python36 => "python3.6",
+            Runtime::Python37 => "python3.7",
+            Runtime::Python38 => "python3.8",
+            Runtime::Python39 => "python3.9",
+            Runtime::Dotnetcore10 => "dotnetcore1.0",
+            Runtime::Dotnetcore20 => "dotnetcore2.0",
+            Runtime::Dotnetcore21 => "dotnetcore2.1",
+            Runtime::Dotnetcore31 => "dotnetcore3.1",
+            Runtime::Dotnet6 => "dotnet6",
+            Runtime::Nodejs43Edge => "nodejs4.3-edge",
+            Runtime::Go1X => "go1.x",
+            Runtime::Ruby25 => "ruby2.5",
+            Runtime::Ruby27 => "ruby2.7",
+            Runtime::Provided => "provided",
+            Runtime::ProvidedAl2 => "provided.al2",
+            Runtime::Nodejs18X => "nodejs18.x",
+            Runtime::Python310 => "python3.10",
+            Runtime::Java17 => "java17",
+            Runtime::Ruby32 => "ruby3.2",
+            Runtime::Python311 => "python3.11",
+            Runtime::Nodejs20X => "nodejs20.x",
+            Runtime::ProvidedAl2023 => "provided.al2023",
+            Runtime::Python312 => "python3.12",
+            Runtime::Java21 => "java21",
+            Runtime::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Runtime {
+    fn from(value: &'a str) -> Runtime {
+        match value {
+            "nodejs" => Runtime::Nodejs,
+            "nodejs4.3" => Runtime::Nodejs43,
+            "nodejs6.10" => Runtime::Nodejs610,
+            "nodejs8.10" => Runtime::Nodejs810,
+            "nodejs10.x" => Runtime::Nodejs10X,
+            "nodejs12.x" => Runtime::Nodejs12X,
+            "nodejs14.x" => Runtime::Nodejs14X,
+            "nodejs16.x" => Runtime::Nodejs16X,
+            "java8" => Runtime::Java8,
+            "java8.al2" => Runtime::Java8Al2,
+            "java11" => Runtime::Java11,
+            "python2.7" => Runtime::Python27,
+            "python3.6" => Runtime::Python36,
+            "python3.7" => Runtime::Python37,
+            "python3.8" => Runtime::Python38,
+            "python3.9" => Runtime::Python39,
+            "dotnetcore1.0" => Runtime::Dotnetcore10,
+            "dotnetcore2.0" => Runtime::Dotnetcore20,
+            "dotnetcore2.1" => Runtime::Dotnetcore21,
+            "dotnetcore3.1" => Runtime::Dotnetcore31,
+            "dotnet6" => Runtime::Dotnet6,
+            "nodejs4.3-edge" => Runtime::Nodejs43Edge,
+            "go1.x" => Runtime::Go1X,
+            "ruby2.5" => Runtime::Ruby25,
+            "ruby2.7" => Runtime::Ruby27,
+            "provided" => Runtime::Provided,
+            "provided.al2" => Runtime::ProvidedAl2,
+            "nodejs18.x" => Runtime::Nodejs18X,
+            "python3.10" => Runtime::Python310,
+            "java17" => Runtime::Java17,
+            "ruby3.2" => Runtime::Ruby32,
+            "python3.11" => Runtime::Python311,
+            "nodejs20.x" => Runtime::Nodejs20X,
+            "provided.al2023" => Runtime::ProvidedAl2023,
+            "python3.12" => Runtime::Python312,
+            "java21" => Runtime::Java21,
+            _ => Runtime::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Runtime {
+    fn from(value: String) -> Runtime {
+        Runtime::from(value.as_str())
+    }
+}
+
+impl From<Runtime> for String {
+    fn from(value: Runtime) -> String {
+        match value {
+            Runtime::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Runtime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Runtime {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Runtime {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Runtime {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Runtime, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Runtime::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TagResourceRequest {
     /// <p>The function's Amazon Resource Name (ARN).</p>
@@ -1259,13 +3498,53 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource`](struct.TagResourceRequest.html#structfield.resource) member.
+    pub fn resource<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource: self
+                .resource
+                .ok_or(::rusoto_core::BuildError::missing("resource"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>The function's AWS X-Ray tracing configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TracingConfig {
     /// <p>The tracing mode.</p>
     #[serde(rename = "Mode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
+    pub mode: Option<TracingMode>,
 }
 
 /// <p>The function's AWS X-Ray tracing configuration.</p>
@@ -1275,7 +3554,85 @@ pub struct TracingConfigResponse {
     /// <p>The tracing mode.</p>
     #[serde(rename = "Mode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mode: Option<String>,
+    pub mode: Option<TracingMode>,
+}
+
+/// <p>The tracing mode.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TracingMode {
+    /// <p><code>Active</code></p>
+    Active,
+    /// <p><code>PassThrough</code></p>
+    PassThrough,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TracingMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TracingMode::Active => "Active",
+            TracingMode::PassThrough => "PassThrough",
+            TracingMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TracingMode {
+    fn from(value: &'a str) -> TracingMode {
+        match value {
+            "Active" => TracingMode::Active,
+            "PassThrough" => TracingMode::PassThrough,
+            _ => TracingMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TracingMode {
+    fn from(value: String) -> TracingMode {
+        TracingMode::from(value.as_str())
+    }
+}
+
+impl From<TracingMode> for String {
+    fn from(value: TracingMode) -> String {
+        match value {
+            TracingMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TracingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TracingMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TracingMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TracingMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TracingMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TracingMode::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1288,6 +3645,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource`](struct.UntagResourceRequest.html#structfield.resource) member.
+    pub fn resource<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource: self
+                .resource
+                .ok_or(::rusoto_core::BuildError::missing("resource"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateAliasRequest {
     /// <p>A description of the alias.</p>
@@ -1314,6 +3711,78 @@ pub struct UpdateAliasRequest {
     pub routing_config: Option<AliasRoutingConfiguration>,
 }
 
+impl UpdateAliasRequest {
+    /// Returns a builder for `UpdateAliasRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAliasRequestBuilder {
+        UpdateAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAliasRequest`](struct.UpdateAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAliasRequestBuilder {
+    description: Option<String>,
+    function_name: Option<String>,
+    function_version: Option<String>,
+    name: Option<String>,
+    revision_id: Option<String>,
+    routing_config: Option<AliasRoutingConfiguration>,
+}
+
+impl UpdateAliasRequestBuilder {
+    /// Sets the [`description`](struct.UpdateAliasRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.UpdateAliasRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_version`](struct.UpdateAliasRequest.html#structfield.function_version) member.
+    pub fn function_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateAliasRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision_id`](struct.UpdateAliasRequest.html#structfield.revision_id) member.
+    pub fn revision_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.revision_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`routing_config`](struct.UpdateAliasRequest.html#structfield.routing_config) member.
+    pub fn routing_config<T: Into<AliasRoutingConfiguration>>(mut self, value: T) -> Self {
+        self.routing_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateAliasRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAliasRequest {
+            description: self.description,
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            function_version: self.function_version,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            revision_id: self.revision_id,
+            routing_config: self.routing_config,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateEventSourceMappingRequest {
     /// <p><p>The maximum number of items to retrieve in a single batch.</p> <ul> <li> <p> <b>Amazon Kinesis</b> - Default 100. Max 10,000.</p> </li> <li> <p> <b>Amazon DynamoDB Streams</b> - Default 100. Max 1,000.</p> </li> <li> <p> <b>Amazon Simple Queue Service</b> - Default 10. Max 10.</p> </li> </ul></p>
@@ -1336,6 +3805,70 @@ pub struct UpdateEventSourceMappingRequest {
     pub uuid: String,
 }
 
+impl UpdateEventSourceMappingRequest {
+    /// Returns a builder for `UpdateEventSourceMappingRequest`, checking its required members when built.
+    pub fn builder() -> UpdateEventSourceMappingRequestBuilder {
+        UpdateEventSourceMappingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateEventSourceMappingRequest`](struct.UpdateEventSourceMappingRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateEventSourceMappingRequestBuilder {
+    batch_size: Option<i64>,
+    enabled: Option<bool>,
+    function_name: Option<String>,
+    maximum_batching_window_in_seconds: Option<i64>,
+    uuid: Option<String>,
+}
+
+impl UpdateEventSourceMappingRequestBuilder {
+    /// Sets the [`batch_size`](struct.UpdateEventSourceMappingRequest.html#structfield.batch_size) member.
+    pub fn batch_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.batch_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enabled`](struct.UpdateEventSourceMappingRequest.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.UpdateEventSourceMappingRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`maximum_batching_window_in_seconds`](struct.UpdateEventSourceMappingRequest.html#structfield.maximum_batching_window_in_seconds) member.
+    pub fn maximum_batching_window_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.maximum_batching_window_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`uuid`](struct.UpdateEventSourceMappingRequest.html#structfield.uuid) member.
+    pub fn uuid<T: Into<String>>(mut self, value: T) -> Self {
+        self.uuid = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateEventSourceMappingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateEventSourceMappingRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateEventSourceMappingRequest {
+            batch_size: self.batch_size,
+            enabled: self.enabled,
+            function_name: self.function_name,
+            maximum_batching_window_in_seconds: self.maximum_batching_window_in_seconds,
+            uuid: self
+                .uuid
+                .ok_or(::rusoto_core::BuildError::missing("uuid"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateFunctionCodeRequest {
     /// <p>Set to true to validate the request parameters and access permissions without modifying the function code.</p>
@@ -1376,6 +3909,94 @@ pub struct UpdateFunctionCodeRequest {
     pub zip_file: Option<bytes::Bytes>,
 }
 
+impl UpdateFunctionCodeRequest {
+    /// Returns a builder for `UpdateFunctionCodeRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFunctionCodeRequestBuilder {
+        UpdateFunctionCodeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFunctionCodeRequest`](struct.UpdateFunctionCodeRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFunctionCodeRequestBuilder {
+    dry_run: Option<bool>,
+    function_name: Option<String>,
+    publish: Option<bool>,
+    revision_id: Option<String>,
+    s3_bucket: Option<String>,
+    s3_key: Option<String>,
+    s3_object_version: Option<String>,
+    zip_file: Option<bytes::Bytes>,
+}
+
+impl UpdateFunctionCodeRequestBuilder {
+    /// Sets the [`dry_run`](struct.UpdateFunctionCodeRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.UpdateFunctionCodeRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publish`](struct.UpdateFunctionCodeRequest.html#structfield.publish) member.
+    pub fn publish<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publish = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision_id`](struct.UpdateFunctionCodeRequest.html#structfield.revision_id) member.
+    pub fn revision_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.revision_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_bucket`](struct.UpdateFunctionCodeRequest.html#structfield.s3_bucket) member.
+    pub fn s3_bucket<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_bucket = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_key`](struct.UpdateFunctionCodeRequest.html#structfield.s3_key) member.
+    pub fn s3_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_object_version`](struct.UpdateFunctionCodeRequest.html#structfield.s3_object_version) member.
+    pub fn s3_object_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_object_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`zip_file`](struct.UpdateFunctionCodeRequest.html#structfield.zip_file) member.
+    pub fn zip_file<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.zip_file = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFunctionCodeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateFunctionCodeRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFunctionCodeRequest {
+            dry_run: self.dry_run,
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            publish: self.publish,
+            revision_id: self.revision_id,
+            s3_bucket: self.s3_bucket,
+            s3_key: self.s3_key,
+            s3_object_version: self.s3_object_version,
+            zip_file: self.zip_file,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateFunctionConfigurationRequest {
     /// <p>A dead letter queue configuration that specifies the queue or topic where Lambda sends asynchronous events when they fail processing. For more information, see <a href="https://docs.aws.amazon.com/lambda/latest/dg/dlq.html">Dead Letter Queues</a>.</p>
@@ -1420,7 +4041,7 @@ pub struct UpdateFunctionConfigurationRequest {
     /// <p>The identifier of the function's <a href="https://docs.aws.amazon.com/lambda/latest/dg/lambda-runtimes.html">runtime</a>.</p>
     #[serde(rename = "Runtime")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub runtime: Option<String>,
+    pub runtime: Option<Runtime>,
     /// <p>The amount of time that Lambda allows a function to run before stopping it. The default is 3 seconds. The maximum allowed value is 900 seconds.</p>
     #[serde(rename = "Timeout")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1435,6 +4056,142 @@ pub struct UpdateFunctionConfigurationRequest {
     pub vpc_config: Option<VpcConfig>,
 }
 
+impl UpdateFunctionConfigurationRequest {
+    /// Returns a builder for `UpdateFunctionConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateFunctionConfigurationRequestBuilder {
+        UpdateFunctionConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateFunctionConfigurationRequest`](struct.UpdateFunctionConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateFunctionConfigurationRequestBuilder {
+    dead_letter_config: Option<DeadLetterConfig>,
+    description: Option<String>,
+    environment: Option<Environment>,
+    function_name: Option<String>,
+    handler: Option<String>,
+    kms_key_arn: Option<String>,
+    layers: Option<Vec<String>>,
+    memory_size: Option<i64>,
+    revision_id: Option<String>,
+    role: Option<String>,
+    runtime: Option<Runtime>,
+    timeout: Option<i64>,
+    tracing_config: Option<TracingConfig>,
+    vpc_config: Option<VpcConfig>,
+}
+
+impl UpdateFunctionConfigurationRequestBuilder {
+    /// Sets the [`dead_letter_config`](struct.UpdateFunctionConfigurationRequest.html#structfield.dead_letter_config) member.
+    pub fn dead_letter_config<T: Into<DeadLetterConfig>>(mut self, value: T) -> Self {
+        self.dead_letter_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateFunctionConfigurationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment`](struct.UpdateFunctionConfigurationRequest.html#structfield.environment) member.
+    pub fn environment<T: Into<Environment>>(mut self, value: T) -> Self {
+        self.environment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`function_name`](struct.UpdateFunctionConfigurationRequest.html#structfield.function_name) member.
+    pub fn function_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.function_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`handler`](struct.UpdateFunctionConfigurationRequest.html#structfield.handler) member.
+    pub fn handler<T: Into<String>>(mut self, value: T) -> Self {
+        self.handler = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_arn`](struct.UpdateFunctionConfigurationRequest.html#structfield.kms_key_arn) member.
+    pub fn kms_key_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layers`](struct.UpdateFunctionConfigurationRequest.html#structfield.layers) member.
+    pub fn layers<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.layers = Some(value.into());
+        self
+    }
+
+    /// Sets the [`memory_size`](struct.UpdateFunctionConfigurationRequest.html#structfield.memory_size) member.
+    pub fn memory_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.memory_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision_id`](struct.UpdateFunctionConfigurationRequest.html#structfield.revision_id) member.
+    pub fn revision_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.revision_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role`](struct.UpdateFunctionConfigurationRequest.html#structfield.role) member.
+    pub fn role<T: Into<String>>(mut self, value: T) -> Self {
+        self.role = Some(value.into());
+        self
+    }
+
+    /// Sets the [`runtime`](struct.UpdateFunctionConfigurationRequest.html#structfield.runtime) member.
+    pub fn runtime<T: Into<Runtime>>(mut self, value: T) -> Self {
+        self.runtime = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout`](struct.UpdateFunctionConfigurationRequest.html#structfield.timeout) member.
+    pub fn timeout<T: Into<i64>>(mut self, value: T) -> Self {
+        self.timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tracing_config`](struct.UpdateFunctionConfigurationRequest.html#structfield.tracing_config) member.
+    pub fn tracing_config<T: Into<TracingConfig>>(mut self, value: T) -> Self {
+        self.tracing_config = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_config`](struct.UpdateFunctionConfigurationRequest.html#structfield.vpc_config) member.
+    pub fn vpc_config<T: Into<VpcConfig>>(mut self, value: T) -> Self {
+        self.vpc_config = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateFunctionConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateFunctionConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateFunctionConfigurationRequest {
+            dead_letter_config: self.dead_letter_config,
+            description: self.description,
+            environment: self.environment,
+            function_name: self
+                .function_name
+                .ok_or(::rusoto_core::BuildError::missing("function_name"))?,
+            handler: self.handler,
+            kms_key_arn: self.kms_key_arn,
+            layers: self.layers,
+            memory_size: self.memory_size,
+            revision_id: self.revision_id,
+            role: self.role,
+            runtime: self.runtime,
+            timeout: self.timeout,
+            tracing_config: self.tracing_config,
+            vpc_config: self.vpc_config,
+        })
+    }
+}
+
 /// <p>The VPC security groups and subnets that are attached to a Lambda function.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct VpcConfig {
@@ -3998,6 +6755,230 @@ pub trait Lambda {
         input: UpdateFunctionConfigurationRequest,
     ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionConfigurationError>;
 }
+impl<T: ?Sized + Lambda> Lambda for ::std::sync::Arc<T> {
+    fn add_layer_version_permission(
+        &self,
+        input: AddLayerVersionPermissionRequest,
+    ) -> RusotoFuture<AddLayerVersionPermissionResponse, AddLayerVersionPermissionError> {
+        (**self).add_layer_version_permission(input)
+    }
+    fn add_permission(
+        &self,
+        input: AddPermissionRequest,
+    ) -> RusotoFuture<AddPermissionResponse, AddPermissionError> {
+        (**self).add_permission(input)
+    }
+    fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> RusotoFuture<AliasConfiguration, CreateAliasError> {
+        (**self).create_alias(input)
+    }
+    fn create_event_source_mapping(
+        &self,
+        input: CreateEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, CreateEventSourceMappingError> {
+        (**self).create_event_source_mapping(input)
+    }
+    fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> RusotoFuture<FunctionConfiguration, CreateFunctionError> {
+        (**self).create_function(input)
+    }
+    fn delete_alias(&self, input: DeleteAliasRequest) -> RusotoFuture<(), DeleteAliasError> {
+        (**self).delete_alias(input)
+    }
+    fn delete_event_source_mapping(
+        &self,
+        input: DeleteEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, DeleteEventSourceMappingError> {
+        (**self).delete_event_source_mapping(input)
+    }
+    fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> RusotoFuture<(), DeleteFunctionError> {
+        (**self).delete_function(input)
+    }
+    fn delete_function_concurrency(
+        &self,
+        input: DeleteFunctionConcurrencyRequest,
+    ) -> RusotoFuture<(), DeleteFunctionConcurrencyError> {
+        (**self).delete_function_concurrency(input)
+    }
+    fn delete_layer_version(
+        &self,
+        input: DeleteLayerVersionRequest,
+    ) -> RusotoFuture<(), DeleteLayerVersionError> {
+        (**self).delete_layer_version(input)
+    }
+    fn get_account_settings(
+        &self,
+    ) -> RusotoFuture<GetAccountSettingsResponse, GetAccountSettingsError> {
+        (**self).get_account_settings()
+    }
+    fn get_alias(&self, input: GetAliasRequest) -> RusotoFuture<AliasConfiguration, GetAliasError> {
+        (**self).get_alias(input)
+    }
+    fn get_event_source_mapping(
+        &self,
+        input: GetEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, GetEventSourceMappingError> {
+        (**self).get_event_source_mapping(input)
+    }
+    fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> RusotoFuture<GetFunctionResponse, GetFunctionError> {
+        (**self).get_function(input)
+    }
+    fn get_function_configuration(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> RusotoFuture<FunctionConfiguration, GetFunctionConfigurationError> {
+        (**self).get_function_configuration(input)
+    }
+    fn get_layer_version(
+        &self,
+        input: GetLayerVersionRequest,
+    ) -> RusotoFuture<GetLayerVersionResponse, GetLayerVersionError> {
+        (**self).get_layer_version(input)
+    }
+    fn get_layer_version_by_arn(
+        &self,
+        input: GetLayerVersionByArnRequest,
+    ) -> RusotoFuture<GetLayerVersionResponse, GetLayerVersionByArnError> {
+        (**self).get_layer_version_by_arn(input)
+    }
+    fn get_layer_version_policy(
+        &self,
+        input: GetLayerVersionPolicyRequest,
+    ) -> RusotoFuture<GetLayerVersionPolicyResponse, GetLayerVersionPolicyError> {
+        (**self).get_layer_version_policy(input)
+    }
+    fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> RusotoFuture<GetPolicyResponse, GetPolicyError> {
+        (**self).get_policy(input)
+    }
+    fn invoke(&self, input: InvocationRequest) -> RusotoFuture<InvocationResponse, InvokeError> {
+        (**self).invoke(input)
+    }
+    fn invoke_async(
+        &self,
+        input: InvokeAsyncRequest,
+    ) -> RusotoFuture<InvokeAsyncResponse, InvokeAsyncError> {
+        (**self).invoke_async(input)
+    }
+    fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> RusotoFuture<ListAliasesResponse, ListAliasesError> {
+        (**self).list_aliases(input)
+    }
+    fn list_event_source_mappings(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> RusotoFuture<ListEventSourceMappingsResponse, ListEventSourceMappingsError> {
+        (**self).list_event_source_mappings(input)
+    }
+    fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> RusotoFuture<ListFunctionsResponse, ListFunctionsError> {
+        (**self).list_functions(input)
+    }
+    fn list_layer_versions(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> RusotoFuture<ListLayerVersionsResponse, ListLayerVersionsError> {
+        (**self).list_layer_versions(input)
+    }
+    fn list_layers(
+        &self,
+        input: ListLayersRequest,
+    ) -> RusotoFuture<ListLayersResponse, ListLayersError> {
+        (**self).list_layers(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn list_versions_by_function(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> RusotoFuture<ListVersionsByFunctionResponse, ListVersionsByFunctionError> {
+        (**self).list_versions_by_function(input)
+    }
+    fn publish_layer_version(
+        &self,
+        input: PublishLayerVersionRequest,
+    ) -> RusotoFuture<PublishLayerVersionResponse, PublishLayerVersionError> {
+        (**self).publish_layer_version(input)
+    }
+    fn publish_version(
+        &self,
+        input: PublishVersionRequest,
+    ) -> RusotoFuture<FunctionConfiguration, PublishVersionError> {
+        (**self).publish_version(input)
+    }
+    fn put_function_concurrency(
+        &self,
+        input: PutFunctionConcurrencyRequest,
+    ) -> RusotoFuture<Concurrency, PutFunctionConcurrencyError> {
+        (**self).put_function_concurrency(input)
+    }
+    fn remove_layer_version_permission(
+        &self,
+        input: RemoveLayerVersionPermissionRequest,
+    ) -> RusotoFuture<(), RemoveLayerVersionPermissionError> {
+        (**self).remove_layer_version_permission(input)
+    }
+    fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> RusotoFuture<(), RemovePermissionError> {
+        (**self).remove_permission(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_alias(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> RusotoFuture<AliasConfiguration, UpdateAliasError> {
+        (**self).update_alias(input)
+    }
+    fn update_event_source_mapping(
+        &self,
+        input: UpdateEventSourceMappingRequest,
+    ) -> RusotoFuture<EventSourceMappingConfiguration, UpdateEventSourceMappingError> {
+        (**self).update_event_source_mapping(input)
+    }
+    fn update_function_code(
+        &self,
+        input: UpdateFunctionCodeRequest,
+    ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionCodeError> {
+        (**self).update_function_code(input)
+    }
+    fn update_function_configuration(
+        &self,
+        input: UpdateFunctionConfigurationRequest,
+    ) -> RusotoFuture<FunctionConfiguration, UpdateFunctionConfigurationError> {
+        (**self).update_function_configuration(input)
+    }
+}
+/// The error codes AWS Lambda retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the AWS Lambda API.
 #[derive(Clone)]
 pub struct LambdaClient {
@@ -4013,6 +6994,27 @@ impl LambdaClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_LAMBDA`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> LambdaClient {
+        Self::new(region::Region::from_env_for_service("LAMBDA"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `lambda`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> LambdaClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("lambda", "LAMBDA"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4031,7 +7033,49 @@ impl LambdaClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> LambdaClient {
-        LambdaClient { client, region }
+        LambdaClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "lambda")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "lambda", "lambda", "LAMBDA")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Lambda` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Lambda + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for LambdaClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Lambda"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "lambda"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> LambdaClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Lex Model Building Service clients.
+//!
+//! Makes a single read-only `GetBots` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_lex_models::{GetBotsRequest, LexModels, LexModelsClient};
+
+fn main() {
+    let client = LexModelsClient::from_env();
+    match client.get_bots(GetBotsRequest::default()).sync() {
+        Ok(_) => println!("GetBots succeeded"),
+        Err(error) => {
+            eprintln!("GetBots failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
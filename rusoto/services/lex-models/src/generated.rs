@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Provides information about a bot alias.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -42,16 +43,18 @@ pub struct BotAliasMetadata {
     pub checksum: Option<String>,
     /// <p>The date that the bot alias was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the bot alias.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date that the bot alias was updated. When you create a resource, the creation date and last updated date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the bot alias.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -76,8 +79,9 @@ pub struct BotChannelAssociation {
     pub bot_name: Option<String>,
     /// <p>The date that the association between the Amazon Lex bot and the channel was created. </p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A text description of the association you are creating. </p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -93,11 +97,11 @@ pub struct BotChannelAssociation {
     /// <p><p>The status of the bot channel. </p> <ul> <li> <p> <code>CREATED</code> - The channel has been created and is ready for use.</p> </li> <li> <p> <code>IN_PROGRESS</code> - Channel creation is in progress.</p> </li> <li> <p> <code>FAILED</code> - There was an error creating the channel. For information about the reason for the failure, see the <code>failureReason</code> field.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ChannelStatus>,
     /// <p>Specifies the type of association by indicating the type of channel being established between the Amazon Lex bot and the external messaging platform.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ChannelType>,
 }
 
 /// <p>Provides information about a bot. .</p>
@@ -106,16 +110,18 @@ pub struct BotChannelAssociation {
 pub struct BotMetadata {
     /// <p>The date that the bot was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the bot.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date that the bot was updated. When you create a bot, the creation date and last updated date are the same. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the bot. </p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -123,7 +129,7 @@ pub struct BotMetadata {
     /// <p>The status of the bot.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The version of the bot. For a new bot, the version is always <code>$LATEST</code>.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -141,7 +147,7 @@ pub struct BuiltinIntentMetadata {
     /// <p>A list of identifiers for the locales that the intent supports.</p>
     #[serde(rename = "supportedLocales")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_locales: Option<Vec<String>>,
+    pub supported_locales: Option<Vec<Locale>>,
 }
 
 /// <p>Provides information about a slot used in a built-in intent.</p>
@@ -165,7 +171,175 @@ pub struct BuiltinSlotTypeMetadata {
     /// <p>A list of target locales for the slot. </p>
     #[serde(rename = "supportedLocales")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_locales: Option<Vec<String>>,
+    pub supported_locales: Option<Vec<Locale>>,
+}
+
+/// <p><p>The status of the bot channel. </p> <ul> <li> <p> <code>CREATED</code> - The channel has been created and is ready for use.</p> </li> <li> <p> <code>IN_PROGRESS</code> - Channel creation is in progress.</p> </li> <li> <p> <code>FAILED</code> - There was an error creating the channel. For information about the reason for the failure, see the <code>failureReason</code> field.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChannelStatus {
+    /// <p><code>IN_PROGRESS</code> - Channel creation is in progress.</p>
+    InProgress,
+    /// <p><code>CREATED</code> - The channel has been created and is ready for use.</p>
+    Created,
+    /// <p><code>FAILED</code> - There was an error creating the channel. For information about the reason for the failure, see the <code>failureReason</code> field.</p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChannelStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChannelStatus::InProgress => "IN_PROGRESS",
+            ChannelStatus::Created => "CREATED",
+            ChannelStatus::Failed => "FAILED",
+            ChannelStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChannelStatus {
+    fn from(value: &'a str) -> ChannelStatus {
+        match value {
+            "IN_PROGRESS" => ChannelStatus::InProgress,
+            "CREATED" => ChannelStatus::Created,
+            "FAILED" => ChannelStatus::Failed,
+            _ => ChannelStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChannelStatus {
+    fn from(value: String) -> ChannelStatus {
+        ChannelStatus::from(value.as_str())
+    }
+}
+
+impl From<ChannelStatus> for String {
+    fn from(value: ChannelStatus) -> String {
+        match value {
+            ChannelStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChannelStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChannelStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChannelStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChannelStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChannelStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChannelStatus::from(value))
+    }
+}
+
+/// <p>Specifies the type of association by indicating the type of channel being established between the Amazon Lex bot and the external messaging platform.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChannelType {
+    /// <p><code>Facebook</code></p>
+    Facebook,
+    /// <p><code>Slack</code></p>
+    Slack,
+    /// <p><code>Twilio-Sms</code></p>
+    TwilioSms,
+    /// <p><code>Kik</code></p>
+    Kik,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChannelType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChannelType::Facebook => "Facebook",
+            ChannelType::Slack => "Slack",
+            ChannelType::TwilioSms => "Twilio-Sms",
+            ChannelType::Kik => "Kik",
+            ChannelType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChannelType {
+    fn from(value: &'a str) -> ChannelType {
+        match value {
+            "Facebook" => ChannelType::Facebook,
+            "Slack" => ChannelType::Slack,
+            "Twilio-Sms" => ChannelType::TwilioSms,
+            "Kik" => ChannelType::Kik,
+            _ => ChannelType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChannelType {
+    fn from(value: String) -> ChannelType {
+        ChannelType::from(value.as_str())
+    }
+}
+
+impl From<ChannelType> for String {
+    fn from(value: ChannelType) -> String {
+        match value {
+            ChannelType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChannelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChannelType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChannelType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChannelType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChannelType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChannelType::from(value))
+    }
 }
 
 /// <p>Specifies a Lambda function that verifies requests to a bot or fulfills the user's request to a bot..</p>
@@ -179,6 +353,88 @@ pub struct CodeHook {
     pub uri: String,
 }
 
+/// <p>The content type of the message string.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContentType {
+    /// <p><code>PlainText</code></p>
+    PlainText,
+    /// <p><code>SSML</code></p>
+    Ssml,
+    /// <p><code>CustomPayload</code></p>
+    CustomPayload,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentType::PlainText => "PlainText",
+            ContentType::Ssml => "SSML",
+            ContentType::CustomPayload => "CustomPayload",
+            ContentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContentType {
+    fn from(value: &'a str) -> ContentType {
+        match value {
+            "PlainText" => ContentType::PlainText,
+            "SSML" => ContentType::Ssml,
+            "CustomPayload" => ContentType::CustomPayload,
+            _ => ContentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentType {
+    fn from(value: String) -> ContentType {
+        ContentType::from(value.as_str())
+    }
+}
+
+impl From<ContentType> for String {
+    fn from(value: ContentType) -> String {
+        match value {
+            ContentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContentType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateBotVersionRequest {
     /// <p>Identifies a specific revision of the <code>$LATEST</code> version of the bot. If you specify a checksum and the <code>$LATEST</code> version of the bot has a different checksum, a <code>PreconditionFailedException</code> exception is returned and Amazon Lex doesn't publish a new version. If you don't specify a checksum, Amazon Lex publishes the <code>$LATEST</code> version.</p>
@@ -190,6 +446,46 @@ pub struct CreateBotVersionRequest {
     pub name: String,
 }
 
+impl CreateBotVersionRequest {
+    /// Returns a builder for `CreateBotVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateBotVersionRequestBuilder {
+        CreateBotVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBotVersionRequest`](struct.CreateBotVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateBotVersionRequestBuilder {
+    checksum: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateBotVersionRequestBuilder {
+    /// Sets the [`checksum`](struct.CreateBotVersionRequest.html#structfield.checksum) member.
+    pub fn checksum<T: Into<String>>(mut self, value: T) -> Self {
+        self.checksum = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateBotVersionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBotVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateBotVersionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateBotVersionRequest {
+            checksum: self.checksum,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateBotVersionResponse {
@@ -211,8 +507,9 @@ pub struct CreateBotVersionResponse {
     pub clarification_prompt: Option<Prompt>,
     /// <p>The date when the bot version was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the bot.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -231,12 +528,13 @@ pub struct CreateBotVersionResponse {
     pub intents: Option<Vec<Intent>>,
     /// <p>The date when the <code>$LATEST</code> version of this bot was updated. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p> Specifies the target locale for the bot. </p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>The name of the bot.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -244,7 +542,7 @@ pub struct CreateBotVersionResponse {
     /// <p> When you send a request to create or update a bot, Amazon Lex sets the <code>status</code> response element to <code>BUILDING</code>. After Amazon Lex builds the bot, it sets <code>status</code> to <code>READY</code>. If Amazon Lex can't build the bot, it sets <code>status</code> to <code>FAILED</code>. Amazon Lex returns the reason for the failure in the <code>failureReason</code> response element. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The version of the bot. </p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -266,6 +564,46 @@ pub struct CreateIntentVersionRequest {
     pub name: String,
 }
 
+impl CreateIntentVersionRequest {
+    /// Returns a builder for `CreateIntentVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateIntentVersionRequestBuilder {
+        CreateIntentVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateIntentVersionRequest`](struct.CreateIntentVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateIntentVersionRequestBuilder {
+    checksum: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateIntentVersionRequestBuilder {
+    /// Sets the [`checksum`](struct.CreateIntentVersionRequest.html#structfield.checksum) member.
+    pub fn checksum<T: Into<String>>(mut self, value: T) -> Self {
+        self.checksum = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateIntentVersionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateIntentVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateIntentVersionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateIntentVersionRequest {
+            checksum: self.checksum,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateIntentVersionResponse {
@@ -283,8 +621,9 @@ pub struct CreateIntentVersionResponse {
     pub confirmation_prompt: Option<Prompt>,
     /// <p>The date that the intent was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the intent.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -303,8 +642,9 @@ pub struct CreateIntentVersionResponse {
     pub fulfillment_activity: Option<FulfillmentActivity>,
     /// <p>The date that the intent was updated. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the intent.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -342,6 +682,46 @@ pub struct CreateSlotTypeVersionRequest {
     pub name: String,
 }
 
+impl CreateSlotTypeVersionRequest {
+    /// Returns a builder for `CreateSlotTypeVersionRequest`, checking its required members when built.
+    pub fn builder() -> CreateSlotTypeVersionRequestBuilder {
+        CreateSlotTypeVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSlotTypeVersionRequest`](struct.CreateSlotTypeVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateSlotTypeVersionRequestBuilder {
+    checksum: Option<String>,
+    name: Option<String>,
+}
+
+impl CreateSlotTypeVersionRequestBuilder {
+    /// Sets the [`checksum`](struct.CreateSlotTypeVersionRequest.html#structfield.checksum) member.
+    pub fn checksum<T: Into<String>>(mut self, value: T) -> Self {
+        self.checksum = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateSlotTypeVersionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSlotTypeVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSlotTypeVersionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateSlotTypeVersionRequest {
+            checksum: self.checksum,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateSlotTypeVersionResponse {
@@ -351,8 +731,9 @@ pub struct CreateSlotTypeVersionResponse {
     pub checksum: Option<String>,
     /// <p>The date that the slot type was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the slot type.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -363,8 +744,9 @@ pub struct CreateSlotTypeVersionResponse {
     pub enumeration_values: Option<Vec<EnumerationValue>>,
     /// <p>The date that the slot type was updated. When you create a resource, the creation date and last update date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the slot type.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -372,7 +754,7 @@ pub struct CreateSlotTypeVersionResponse {
     /// <p>The strategy that Amazon Lex uses to determine the value of the slot. For more information, see <a>PutSlotType</a>.</p>
     #[serde(rename = "valueSelectionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value_selection_strategy: Option<String>,
+    pub value_selection_strategy: Option<SlotValueSelectionStrategy>,
     /// <p>The version assigned to the new slot type version. </p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -389,6 +771,46 @@ pub struct DeleteBotAliasRequest {
     pub name: String,
 }
 
+impl DeleteBotAliasRequest {
+    /// Returns a builder for `DeleteBotAliasRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBotAliasRequestBuilder {
+        DeleteBotAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBotAliasRequest`](struct.DeleteBotAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBotAliasRequestBuilder {
+    bot_name: Option<String>,
+    name: Option<String>,
+}
+
+impl DeleteBotAliasRequestBuilder {
+    /// Sets the [`bot_name`](struct.DeleteBotAliasRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DeleteBotAliasRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBotAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteBotAliasRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBotAliasRequest {
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteBotChannelAssociationRequest {
     /// <p>An alias that points to the specific version of the Amazon Lex bot to which this association is being made.</p>
@@ -402,6 +824,58 @@ pub struct DeleteBotChannelAssociationRequest {
     pub name: String,
 }
 
+impl DeleteBotChannelAssociationRequest {
+    /// Returns a builder for `DeleteBotChannelAssociationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBotChannelAssociationRequestBuilder {
+        DeleteBotChannelAssociationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBotChannelAssociationRequest`](struct.DeleteBotChannelAssociationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBotChannelAssociationRequestBuilder {
+    bot_alias: Option<String>,
+    bot_name: Option<String>,
+    name: Option<String>,
+}
+
+impl DeleteBotChannelAssociationRequestBuilder {
+    /// Sets the [`bot_alias`](struct.DeleteBotChannelAssociationRequest.html#structfield.bot_alias) member.
+    pub fn bot_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_name`](struct.DeleteBotChannelAssociationRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DeleteBotChannelAssociationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBotChannelAssociationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteBotChannelAssociationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBotChannelAssociationRequest {
+            bot_alias: self
+                .bot_alias
+                .ok_or(::rusoto_core::BuildError::missing("bot_alias"))?,
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteBotRequest {
     /// <p>The name of the bot. The name is case sensitive. </p>
@@ -409,6 +883,36 @@ pub struct DeleteBotRequest {
     pub name: String,
 }
 
+impl DeleteBotRequest {
+    /// Returns a builder for `DeleteBotRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBotRequestBuilder {
+        DeleteBotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBotRequest`](struct.DeleteBotRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBotRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteBotRequestBuilder {
+    /// Sets the [`name`](struct.DeleteBotRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBotRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteBotRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBotRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteBotVersionRequest {
     /// <p>The name of the bot.</p>
@@ -419,6 +923,48 @@ pub struct DeleteBotVersionRequest {
     pub version: String,
 }
 
+impl DeleteBotVersionRequest {
+    /// Returns a builder for `DeleteBotVersionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBotVersionRequestBuilder {
+        DeleteBotVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBotVersionRequest`](struct.DeleteBotVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBotVersionRequestBuilder {
+    name: Option<String>,
+    version: Option<String>,
+}
+
+impl DeleteBotVersionRequestBuilder {
+    /// Sets the [`name`](struct.DeleteBotVersionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.DeleteBotVersionRequest.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBotVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteBotVersionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBotVersionRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteIntentRequest {
     /// <p>The name of the intent. The name is case sensitive. </p>
@@ -426,6 +972,36 @@ pub struct DeleteIntentRequest {
     pub name: String,
 }
 
+impl DeleteIntentRequest {
+    /// Returns a builder for `DeleteIntentRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIntentRequestBuilder {
+        DeleteIntentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIntentRequest`](struct.DeleteIntentRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIntentRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteIntentRequestBuilder {
+    /// Sets the [`name`](struct.DeleteIntentRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIntentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteIntentRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIntentRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteIntentVersionRequest {
     /// <p>The name of the intent.</p>
@@ -436,6 +1012,48 @@ pub struct DeleteIntentVersionRequest {
     pub version: String,
 }
 
+impl DeleteIntentVersionRequest {
+    /// Returns a builder for `DeleteIntentVersionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteIntentVersionRequestBuilder {
+        DeleteIntentVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteIntentVersionRequest`](struct.DeleteIntentVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteIntentVersionRequestBuilder {
+    name: Option<String>,
+    version: Option<String>,
+}
+
+impl DeleteIntentVersionRequestBuilder {
+    /// Sets the [`name`](struct.DeleteIntentVersionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.DeleteIntentVersionRequest.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteIntentVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteIntentVersionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteIntentVersionRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteSlotTypeRequest {
     /// <p>The name of the slot type. The name is case sensitive. </p>
@@ -443,6 +1061,36 @@ pub struct DeleteSlotTypeRequest {
     pub name: String,
 }
 
+impl DeleteSlotTypeRequest {
+    /// Returns a builder for `DeleteSlotTypeRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSlotTypeRequestBuilder {
+        DeleteSlotTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSlotTypeRequest`](struct.DeleteSlotTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSlotTypeRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteSlotTypeRequestBuilder {
+    /// Sets the [`name`](struct.DeleteSlotTypeRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSlotTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteSlotTypeRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSlotTypeRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteSlotTypeVersionRequest {
     /// <p>The name of the slot type.</p>
@@ -453,6 +1101,48 @@ pub struct DeleteSlotTypeVersionRequest {
     pub version: String,
 }
 
+impl DeleteSlotTypeVersionRequest {
+    /// Returns a builder for `DeleteSlotTypeVersionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSlotTypeVersionRequestBuilder {
+        DeleteSlotTypeVersionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSlotTypeVersionRequest`](struct.DeleteSlotTypeVersionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSlotTypeVersionRequestBuilder {
+    name: Option<String>,
+    version: Option<String>,
+}
+
+impl DeleteSlotTypeVersionRequestBuilder {
+    /// Sets the [`name`](struct.DeleteSlotTypeVersionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.DeleteSlotTypeVersionRequest.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSlotTypeVersionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSlotTypeVersionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSlotTypeVersionRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteUtterancesRequest {
     /// <p>The name of the bot that stored the utterances.</p>
@@ -463,19 +1153,221 @@ pub struct DeleteUtterancesRequest {
     pub user_id: String,
 }
 
-/// <p><p>Each slot type can have a set of values. Each enumeration value represents a value the slot type can take. </p> <p>For example, a pizza ordering bot could have a slot type that specifies the type of crust that the pizza should have. The slot type could include the values </p> <ul> <li> <p>thick</p> </li> <li> <p>thin</p> </li> <li> <p>stuffed</p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct EnumerationValue {
-    /// <p>Additional values related to the slot type value.</p>
-    #[serde(rename = "synonyms")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub synonyms: Option<Vec<String>>,
-    /// <p>The value of the slot type.</p>
-    #[serde(rename = "value")]
-    pub value: String,
+impl DeleteUtterancesRequest {
+    /// Returns a builder for `DeleteUtterancesRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUtterancesRequestBuilder {
+        DeleteUtterancesRequestBuilder::default()
+    }
 }
 
-/// <p>A prompt for additional activity after an intent is fulfilled. For example, after the <code>OrderPizza</code> intent is fulfilled, you might prompt the user to find out whether the user wants to order drinks.</p>
+/// Builds a [`DeleteUtterancesRequest`](struct.DeleteUtterancesRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUtterancesRequestBuilder {
+    bot_name: Option<String>,
+    user_id: Option<String>,
+}
+
+impl DeleteUtterancesRequestBuilder {
+    /// Sets the [`bot_name`](struct.DeleteUtterancesRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.DeleteUtterancesRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUtterancesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteUtterancesRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUtterancesRequest {
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
+/// <p><p>Each slot type can have a set of values. Each enumeration value represents a value the slot type can take. </p> <p>For example, a pizza ordering bot could have a slot type that specifies the type of crust that the pizza should have. The slot type could include the values </p> <ul> <li> <p>thick</p> </li> <li> <p>thin</p> </li> <li> <p>stuffed</p> </li> </ul></p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnumerationValue {
+    /// <p>Additional values related to the slot type value.</p>
+    #[serde(rename = "synonyms")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synonyms: Option<Vec<String>>,
+    /// <p>The value of the slot type.</p>
+    #[serde(rename = "value")]
+    pub value: String,
+}
+
+/// <p><p>The status of the export. </p> <ul> <li> <p> <code>IN_PROGRESS</code> - The export is in progress.</p> </li> <li> <p> <code>READY</code> - The export is complete.</p> </li> <li> <p> <code>FAILED</code> - The export could not be completed.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExportStatus {
+    /// <p><code>IN_PROGRESS</code> - The export is in progress.</p>
+    InProgress,
+    /// <p><code>READY</code> - The export is complete.</p>
+    Ready,
+    /// <p><code>FAILED</code> - The export could not be completed.</p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExportStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportStatus::InProgress => "IN_PROGRESS",
+            ExportStatus::Ready => "READY",
+            ExportStatus::Failed => "FAILED",
+            ExportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExportStatus {
+    fn from(value: &'a str) -> ExportStatus {
+        match value {
+            "IN_PROGRESS" => ExportStatus::InProgress,
+            "READY" => ExportStatus::Ready,
+            "FAILED" => ExportStatus::Failed,
+            _ => ExportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportStatus {
+    fn from(value: String) -> ExportStatus {
+        ExportStatus::from(value.as_str())
+    }
+}
+
+impl From<ExportStatus> for String {
+    fn from(value: ExportStatus) -> String {
+        match value {
+            ExportStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExportStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExportStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExportStatus::from(value))
+    }
+}
+
+/// <p>The format of the exported data.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExportType {
+    /// <p><code>ALEXA_SKILLS_KIT</code></p>
+    AlexaSkillsKit,
+    /// <p><code>LEX</code></p>
+    Lex,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExportType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportType::AlexaSkillsKit => "ALEXA_SKILLS_KIT",
+            ExportType::Lex => "LEX",
+            ExportType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExportType {
+    fn from(value: &'a str) -> ExportType {
+        match value {
+            "ALEXA_SKILLS_KIT" => ExportType::AlexaSkillsKit,
+            "LEX" => ExportType::Lex,
+            _ => ExportType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportType {
+    fn from(value: String) -> ExportType {
+        ExportType::from(value.as_str())
+    }
+}
+
+impl From<ExportType> for String {
+    fn from(value: ExportType) -> String {
+        match value {
+            ExportType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExportType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExportType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExportType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExportType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExportType::from(value))
+    }
+}
+
+/// <p>A prompt for additional activity after an intent is fulfilled. For example, after the <code>OrderPizza</code> intent is fulfilled, you might prompt the user to find out whether the user wants to order drinks.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FollowUpPrompt {
     /// <p>Prompts for information from the user. </p>
@@ -487,7 +1379,7 @@ pub struct FollowUpPrompt {
 }
 
 /// <p><p> Describes how the intent is fulfilled after the user provides all of the information required for the intent. You can provide a Lambda function to process the intent, or you can return the intent information to the client application. We recommend that you use a Lambda function so that the relevant logic lives in the Cloud and limit the client-side code primarily to presentation. If you need to update the logic, you only update the Lambda function; you don&#39;t need to upgrade your client application. </p> <p>Consider the following examples:</p> <ul> <li> <p>In a pizza ordering application, after the user provides all of the information for placing an order, you use a Lambda function to place an order with a pizzeria. </p> </li> <li> <p>In a gaming application, when a user says &quot;pick up a rock,&quot; this information must go back to the client application so that it can perform the operation and update the graphics. In this case, you want Amazon Lex to return the intent data to the client. </p> </li> </ul></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FulfillmentActivity {
     /// <p> A description of the Lambda function that is run to fulfill the intent. </p>
     #[serde(rename = "codeHook")]
@@ -495,7 +1387,85 @@ pub struct FulfillmentActivity {
     pub code_hook: Option<CodeHook>,
     /// <p> How the intent should be fulfilled, either by running a Lambda function or by returning the slot data to the client application. </p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: FulfillmentActivityType,
+}
+
+/// <p> How the intent should be fulfilled, either by running a Lambda function or by returning the slot data to the client application. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FulfillmentActivityType {
+    /// <p><code>ReturnIntent</code></p>
+    ReturnIntent,
+    /// <p><code>CodeHook</code></p>
+    CodeHook,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FulfillmentActivityType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FulfillmentActivityType::ReturnIntent => "ReturnIntent",
+            FulfillmentActivityType::CodeHook => "CodeHook",
+            FulfillmentActivityType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FulfillmentActivityType {
+    fn from(value: &'a str) -> FulfillmentActivityType {
+        match value {
+            "ReturnIntent" => FulfillmentActivityType::ReturnIntent,
+            "CodeHook" => FulfillmentActivityType::CodeHook,
+            _ => FulfillmentActivityType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FulfillmentActivityType {
+    fn from(value: String) -> FulfillmentActivityType {
+        FulfillmentActivityType::from(value.as_str())
+    }
+}
+
+impl From<FulfillmentActivityType> for String {
+    fn from(value: FulfillmentActivityType) -> String {
+        match value {
+            FulfillmentActivityType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FulfillmentActivityType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FulfillmentActivityType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FulfillmentActivityType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FulfillmentActivityType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FulfillmentActivityType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FulfillmentActivityType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -508,6 +1478,46 @@ pub struct GetBotAliasRequest {
     pub name: String,
 }
 
+impl GetBotAliasRequest {
+    /// Returns a builder for `GetBotAliasRequest`, checking its required members when built.
+    pub fn builder() -> GetBotAliasRequestBuilder {
+        GetBotAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBotAliasRequest`](struct.GetBotAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBotAliasRequestBuilder {
+    bot_name: Option<String>,
+    name: Option<String>,
+}
+
+impl GetBotAliasRequestBuilder {
+    /// Sets the [`bot_name`](struct.GetBotAliasRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetBotAliasRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBotAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetBotAliasRequest, ::rusoto_core::BuildError> {
+        Ok(GetBotAliasRequest {
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBotAliasResponse {
@@ -525,16 +1535,18 @@ pub struct GetBotAliasResponse {
     pub checksum: Option<String>,
     /// <p>The date that the bot alias was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the bot alias.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date that the bot alias was updated. When you create a resource, the creation date and the last updated date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the bot alias.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -560,6 +1572,60 @@ pub struct GetBotAliasesRequest {
     pub next_token: Option<String>,
 }
 
+impl GetBotAliasesRequest {
+    /// Returns a builder for `GetBotAliasesRequest`, checking its required members when built.
+    pub fn builder() -> GetBotAliasesRequestBuilder {
+        GetBotAliasesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBotAliasesRequest`](struct.GetBotAliasesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBotAliasesRequestBuilder {
+    bot_name: Option<String>,
+    max_results: Option<i64>,
+    name_contains: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetBotAliasesRequestBuilder {
+    /// Sets the [`bot_name`](struct.GetBotAliasesRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetBotAliasesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_contains`](struct.GetBotAliasesRequest.html#structfield.name_contains) member.
+    pub fn name_contains<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_contains = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetBotAliasesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBotAliasesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetBotAliasesRequest, ::rusoto_core::BuildError> {
+        Ok(GetBotAliasesRequest {
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            max_results: self.max_results,
+            name_contains: self.name_contains,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBotAliasesResponse {
@@ -586,6 +1652,58 @@ pub struct GetBotChannelAssociationRequest {
     pub name: String,
 }
 
+impl GetBotChannelAssociationRequest {
+    /// Returns a builder for `GetBotChannelAssociationRequest`, checking its required members when built.
+    pub fn builder() -> GetBotChannelAssociationRequestBuilder {
+        GetBotChannelAssociationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBotChannelAssociationRequest`](struct.GetBotChannelAssociationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBotChannelAssociationRequestBuilder {
+    bot_alias: Option<String>,
+    bot_name: Option<String>,
+    name: Option<String>,
+}
+
+impl GetBotChannelAssociationRequestBuilder {
+    /// Sets the [`bot_alias`](struct.GetBotChannelAssociationRequest.html#structfield.bot_alias) member.
+    pub fn bot_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_name`](struct.GetBotChannelAssociationRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetBotChannelAssociationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBotChannelAssociationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetBotChannelAssociationRequest, ::rusoto_core::BuildError> {
+        Ok(GetBotChannelAssociationRequest {
+            bot_alias: self
+                .bot_alias
+                .ok_or(::rusoto_core::BuildError::missing("bot_alias"))?,
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBotChannelAssociationResponse {
@@ -603,8 +1721,9 @@ pub struct GetBotChannelAssociationResponse {
     pub bot_name: Option<String>,
     /// <p>The date that the association between the bot and the channel was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the association between the bot and the channel.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -620,11 +1739,11 @@ pub struct GetBotChannelAssociationResponse {
     /// <p><p>The status of the bot channel. </p> <ul> <li> <p> <code>CREATED</code> - The channel has been created and is ready for use.</p> </li> <li> <p> <code>IN_PROGRESS</code> - Channel creation is in progress.</p> </li> <li> <p> <code>FAILED</code> - There was an error creating the channel. For information about the reason for the failure, see the <code>failureReason</code> field.</p> </li> </ul></p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ChannelStatus>,
     /// <p>The type of the messaging platform.</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ChannelType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -649,6 +1768,72 @@ pub struct GetBotChannelAssociationsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetBotChannelAssociationsRequest {
+    /// Returns a builder for `GetBotChannelAssociationsRequest`, checking its required members when built.
+    pub fn builder() -> GetBotChannelAssociationsRequestBuilder {
+        GetBotChannelAssociationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBotChannelAssociationsRequest`](struct.GetBotChannelAssociationsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBotChannelAssociationsRequestBuilder {
+    bot_alias: Option<String>,
+    bot_name: Option<String>,
+    max_results: Option<i64>,
+    name_contains: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetBotChannelAssociationsRequestBuilder {
+    /// Sets the [`bot_alias`](struct.GetBotChannelAssociationsRequest.html#structfield.bot_alias) member.
+    pub fn bot_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_name`](struct.GetBotChannelAssociationsRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetBotChannelAssociationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_contains`](struct.GetBotChannelAssociationsRequest.html#structfield.name_contains) member.
+    pub fn name_contains<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_contains = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetBotChannelAssociationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBotChannelAssociationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetBotChannelAssociationsRequest, ::rusoto_core::BuildError> {
+        Ok(GetBotChannelAssociationsRequest {
+            bot_alias: self
+                .bot_alias
+                .ok_or(::rusoto_core::BuildError::missing("bot_alias"))?,
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            max_results: self.max_results,
+            name_contains: self.name_contains,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBotChannelAssociationsResponse {
@@ -672,6 +1857,46 @@ pub struct GetBotRequest {
     pub version_or_alias: String,
 }
 
+impl GetBotRequest {
+    /// Returns a builder for `GetBotRequest`, checking its required members when built.
+    pub fn builder() -> GetBotRequestBuilder {
+        GetBotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBotRequest`](struct.GetBotRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBotRequestBuilder {
+    name: Option<String>,
+    version_or_alias: Option<String>,
+}
+
+impl GetBotRequestBuilder {
+    /// Sets the [`name`](struct.GetBotRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version_or_alias`](struct.GetBotRequest.html#structfield.version_or_alias) member.
+    pub fn version_or_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.version_or_alias = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBotRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetBotRequest, ::rusoto_core::BuildError> {
+        Ok(GetBotRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            version_or_alias: self
+                .version_or_alias
+                .ok_or(::rusoto_core::BuildError::missing("version_or_alias"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBotResponse {
@@ -693,8 +1918,9 @@ pub struct GetBotResponse {
     pub clarification_prompt: Option<Prompt>,
     /// <p>The date that the bot was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the bot.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -713,12 +1939,13 @@ pub struct GetBotResponse {
     pub intents: Option<Vec<Intent>>,
     /// <p>The date that the bot was updated. When you create a resource, the creation date and last updated date are the same. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p> The target locale for the bot. </p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>The name of the bot.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -726,7 +1953,7 @@ pub struct GetBotResponse {
     /// <p>The status of the bot. If the bot is ready to run, the status is <code>READY</code>. If there was a problem with building the bot, the status is <code>FAILED</code> and the <code>failureReason</code> explains why the bot did not build. If the bot was saved but not built, the status is <code>NOT BUILT</code>.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The version of the bot. For a new bot, the version is always <code>$LATEST</code>.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -752,6 +1979,52 @@ pub struct GetBotVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetBotVersionsRequest {
+    /// Returns a builder for `GetBotVersionsRequest`, checking its required members when built.
+    pub fn builder() -> GetBotVersionsRequestBuilder {
+        GetBotVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBotVersionsRequest`](struct.GetBotVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBotVersionsRequestBuilder {
+    max_results: Option<i64>,
+    name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetBotVersionsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetBotVersionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetBotVersionsRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetBotVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBotVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetBotVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(GetBotVersionsRequest {
+            max_results: self.max_results,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBotVersionsResponse {
@@ -781,6 +2054,50 @@ pub struct GetBotsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetBotsRequest {
+    /// Returns a builder for `GetBotsRequest`, checking its required members when built.
+    pub fn builder() -> GetBotsRequestBuilder {
+        GetBotsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBotsRequest`](struct.GetBotsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBotsRequestBuilder {
+    max_results: Option<i64>,
+    name_contains: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetBotsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetBotsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_contains`](struct.GetBotsRequest.html#structfield.name_contains) member.
+    pub fn name_contains<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_contains = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetBotsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBotsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetBotsRequest, ::rusoto_core::BuildError> {
+        Ok(GetBotsRequest {
+            max_results: self.max_results,
+            name_contains: self.name_contains,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBotsResponse {
@@ -801,6 +2118,38 @@ pub struct GetBuiltinIntentRequest {
     pub signature: String,
 }
 
+impl GetBuiltinIntentRequest {
+    /// Returns a builder for `GetBuiltinIntentRequest`, checking its required members when built.
+    pub fn builder() -> GetBuiltinIntentRequestBuilder {
+        GetBuiltinIntentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBuiltinIntentRequest`](struct.GetBuiltinIntentRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBuiltinIntentRequestBuilder {
+    signature: Option<String>,
+}
+
+impl GetBuiltinIntentRequestBuilder {
+    /// Sets the [`signature`](struct.GetBuiltinIntentRequest.html#structfield.signature) member.
+    pub fn signature<T: Into<String>>(mut self, value: T) -> Self {
+        self.signature = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBuiltinIntentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetBuiltinIntentRequest, ::rusoto_core::BuildError> {
+        Ok(GetBuiltinIntentRequest {
+            signature: self
+                .signature
+                .ok_or(::rusoto_core::BuildError::missing("signature"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBuiltinIntentResponse {
@@ -815,7 +2164,7 @@ pub struct GetBuiltinIntentResponse {
     /// <p>A list of locales that the intent supports.</p>
     #[serde(rename = "supportedLocales")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_locales: Option<Vec<String>>,
+    pub supported_locales: Option<Vec<Locale>>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -823,7 +2172,7 @@ pub struct GetBuiltinIntentsRequest {
     /// <p>A list of locales that the intent supports.</p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>The maximum number of intents to return in the response. The default is 10.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -838,15 +2187,69 @@ pub struct GetBuiltinIntentsRequest {
     pub signature_contains: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetBuiltinIntentsResponse {
-    /// <p>An array of <code>builtinIntentMetadata</code> objects, one for each intent in the response.</p>
-    #[serde(rename = "intents")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub intents: Option<Vec<BuiltinIntentMetadata>>,
-    /// <p>A pagination token that fetches the next page of intents. If the response to this API call is truncated, Amazon Lex returns a pagination token in the response. To fetch the next page of intents, specify the pagination token in the next request.</p>
-    #[serde(rename = "nextToken")]
+impl GetBuiltinIntentsRequest {
+    /// Returns a builder for `GetBuiltinIntentsRequest`, checking its required members when built.
+    pub fn builder() -> GetBuiltinIntentsRequestBuilder {
+        GetBuiltinIntentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBuiltinIntentsRequest`](struct.GetBuiltinIntentsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBuiltinIntentsRequestBuilder {
+    locale: Option<Locale>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    signature_contains: Option<String>,
+}
+
+impl GetBuiltinIntentsRequestBuilder {
+    /// Sets the [`locale`](struct.GetBuiltinIntentsRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<Locale>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetBuiltinIntentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetBuiltinIntentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`signature_contains`](struct.GetBuiltinIntentsRequest.html#structfield.signature_contains) member.
+    pub fn signature_contains<T: Into<String>>(mut self, value: T) -> Self {
+        self.signature_contains = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBuiltinIntentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetBuiltinIntentsRequest, ::rusoto_core::BuildError> {
+        Ok(GetBuiltinIntentsRequest {
+            locale: self.locale,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            signature_contains: self.signature_contains,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetBuiltinIntentsResponse {
+    /// <p>An array of <code>builtinIntentMetadata</code> objects, one for each intent in the response.</p>
+    #[serde(rename = "intents")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intents: Option<Vec<BuiltinIntentMetadata>>,
+    /// <p>A pagination token that fetches the next page of intents. If the response to this API call is truncated, Amazon Lex returns a pagination token in the response. To fetch the next page of intents, specify the pagination token in the next request.</p>
+    #[serde(rename = "nextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_token: Option<String>,
 }
@@ -856,7 +2259,7 @@ pub struct GetBuiltinSlotTypesRequest {
     /// <p>A list of locales that the slot type supports.</p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>The maximum number of slot types to return in the response. The default is 10.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -871,6 +2274,60 @@ pub struct GetBuiltinSlotTypesRequest {
     pub signature_contains: Option<String>,
 }
 
+impl GetBuiltinSlotTypesRequest {
+    /// Returns a builder for `GetBuiltinSlotTypesRequest`, checking its required members when built.
+    pub fn builder() -> GetBuiltinSlotTypesRequestBuilder {
+        GetBuiltinSlotTypesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBuiltinSlotTypesRequest`](struct.GetBuiltinSlotTypesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBuiltinSlotTypesRequestBuilder {
+    locale: Option<Locale>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    signature_contains: Option<String>,
+}
+
+impl GetBuiltinSlotTypesRequestBuilder {
+    /// Sets the [`locale`](struct.GetBuiltinSlotTypesRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<Locale>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetBuiltinSlotTypesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetBuiltinSlotTypesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`signature_contains`](struct.GetBuiltinSlotTypesRequest.html#structfield.signature_contains) member.
+    pub fn signature_contains<T: Into<String>>(mut self, value: T) -> Self {
+        self.signature_contains = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBuiltinSlotTypesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetBuiltinSlotTypesRequest, ::rusoto_core::BuildError> {
+        Ok(GetBuiltinSlotTypesRequest {
+            locale: self.locale,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            signature_contains: self.signature_contains,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBuiltinSlotTypesResponse {
@@ -884,33 +2341,93 @@ pub struct GetBuiltinSlotTypesResponse {
     pub slot_types: Option<Vec<BuiltinSlotTypeMetadata>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetExportRequest {
     /// <p>The format of the exported data.</p>
     #[serde(rename = "exportType")]
-    pub export_type: String,
+    pub export_type: ExportType,
     /// <p>The name of the bot to export.</p>
     #[serde(rename = "name")]
     pub name: String,
     /// <p>The type of resource to export. </p>
     #[serde(rename = "resourceType")]
-    pub resource_type: String,
+    pub resource_type: ResourceType,
     /// <p>The version of the bot to export.</p>
     #[serde(rename = "version")]
     pub version: String,
 }
 
+impl GetExportRequest {
+    /// Returns a builder for `GetExportRequest`, checking its required members when built.
+    pub fn builder() -> GetExportRequestBuilder {
+        GetExportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetExportRequest`](struct.GetExportRequest.html).
+#[derive(Default, Debug)]
+pub struct GetExportRequestBuilder {
+    export_type: Option<ExportType>,
+    name: Option<String>,
+    resource_type: Option<ResourceType>,
+    version: Option<String>,
+}
+
+impl GetExportRequestBuilder {
+    /// Sets the [`export_type`](struct.GetExportRequest.html#structfield.export_type) member.
+    pub fn export_type<T: Into<ExportType>>(mut self, value: T) -> Self {
+        self.export_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetExportRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.GetExportRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<ResourceType>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.GetExportRequest.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetExportRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetExportRequest, ::rusoto_core::BuildError> {
+        Ok(GetExportRequest {
+            export_type: self
+                .export_type
+                .ok_or(::rusoto_core::BuildError::missing("export_type"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            resource_type: self
+                .resource_type
+                .ok_or(::rusoto_core::BuildError::missing("resource_type"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetExportResponse {
     /// <p><p>The status of the export. </p> <ul> <li> <p> <code>IN_PROGRESS</code> - The export is in progress.</p> </li> <li> <p> <code>READY</code> - The export is complete.</p> </li> <li> <p> <code>FAILED</code> - The export could not be completed.</p> </li> </ul></p>
     #[serde(rename = "exportStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub export_status: Option<String>,
+    pub export_status: Option<ExportStatus>,
     /// <p>The format of the exported data.</p>
     #[serde(rename = "exportType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub export_type: Option<String>,
+    pub export_type: Option<ExportType>,
     /// <p>If <code>status</code> is <code>FAILED</code>, Amazon Lex provides the reason that it failed to export the resource.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -922,7 +2439,7 @@ pub struct GetExportResponse {
     /// <p>The type of the exported resource.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>An S3 pre-signed URL that provides the location of the exported resource. The exported resource is a ZIP archive that contains the exported resource in JSON format. The structure of the archive may change. Your code should not rely on the archive structure.</p>
     #[serde(rename = "url")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -940,13 +2457,44 @@ pub struct GetImportRequest {
     pub import_id: String,
 }
 
+impl GetImportRequest {
+    /// Returns a builder for `GetImportRequest`, checking its required members when built.
+    pub fn builder() -> GetImportRequestBuilder {
+        GetImportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetImportRequest`](struct.GetImportRequest.html).
+#[derive(Default, Debug)]
+pub struct GetImportRequestBuilder {
+    import_id: Option<String>,
+}
+
+impl GetImportRequestBuilder {
+    /// Sets the [`import_id`](struct.GetImportRequest.html#structfield.import_id) member.
+    pub fn import_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.import_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetImportRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetImportRequest, ::rusoto_core::BuildError> {
+        Ok(GetImportRequest {
+            import_id: self
+                .import_id
+                .ok_or(::rusoto_core::BuildError::missing("import_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetImportResponse {
     /// <p>A timestamp for the date and time that the import job was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A string that describes why an import job failed to complete.</p>
     #[serde(rename = "failureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -958,11 +2506,11 @@ pub struct GetImportResponse {
     /// <p>The status of the import job. If the status is <code>FAILED</code>, you can get the reason for the failure from the <code>failureReason</code> field.</p>
     #[serde(rename = "importStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_status: Option<String>,
+    pub import_status: Option<ImportStatus>,
     /// <p>The action taken when there was a conflict between an existing resource and a resource in the import file.</p>
     #[serde(rename = "mergeStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub merge_strategy: Option<String>,
+    pub merge_strategy: Option<MergeStrategy>,
     /// <p>The name given to the import job.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -970,7 +2518,7 @@ pub struct GetImportResponse {
     /// <p>The type of resource imported.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -983,6 +2531,46 @@ pub struct GetIntentRequest {
     pub version: String,
 }
 
+impl GetIntentRequest {
+    /// Returns a builder for `GetIntentRequest`, checking its required members when built.
+    pub fn builder() -> GetIntentRequestBuilder {
+        GetIntentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIntentRequest`](struct.GetIntentRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIntentRequestBuilder {
+    name: Option<String>,
+    version: Option<String>,
+}
+
+impl GetIntentRequestBuilder {
+    /// Sets the [`name`](struct.GetIntentRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.GetIntentRequest.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIntentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetIntentRequest, ::rusoto_core::BuildError> {
+        Ok(GetIntentRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetIntentResponse {
@@ -1000,8 +2588,9 @@ pub struct GetIntentResponse {
     pub confirmation_prompt: Option<Prompt>,
     /// <p>The date that the intent was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the intent.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1020,8 +2609,9 @@ pub struct GetIntentResponse {
     pub fulfillment_activity: Option<FulfillmentActivity>,
     /// <p>The date that the intent was updated. When you create a resource, the creation date and the last updated date are the same. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the intent.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1063,6 +2653,54 @@ pub struct GetIntentVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetIntentVersionsRequest {
+    /// Returns a builder for `GetIntentVersionsRequest`, checking its required members when built.
+    pub fn builder() -> GetIntentVersionsRequestBuilder {
+        GetIntentVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIntentVersionsRequest`](struct.GetIntentVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIntentVersionsRequestBuilder {
+    max_results: Option<i64>,
+    name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetIntentVersionsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetIntentVersionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetIntentVersionsRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetIntentVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIntentVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetIntentVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(GetIntentVersionsRequest {
+            max_results: self.max_results,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetIntentVersionsResponse {
@@ -1092,6 +2730,50 @@ pub struct GetIntentsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetIntentsRequest {
+    /// Returns a builder for `GetIntentsRequest`, checking its required members when built.
+    pub fn builder() -> GetIntentsRequestBuilder {
+        GetIntentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetIntentsRequest`](struct.GetIntentsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetIntentsRequestBuilder {
+    max_results: Option<i64>,
+    name_contains: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetIntentsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetIntentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_contains`](struct.GetIntentsRequest.html#structfield.name_contains) member.
+    pub fn name_contains<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_contains = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetIntentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetIntentsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetIntentsRequest, ::rusoto_core::BuildError> {
+        Ok(GetIntentsRequest {
+            max_results: self.max_results,
+            name_contains: self.name_contains,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetIntentsResponse {
@@ -1115,6 +2797,46 @@ pub struct GetSlotTypeRequest {
     pub version: String,
 }
 
+impl GetSlotTypeRequest {
+    /// Returns a builder for `GetSlotTypeRequest`, checking its required members when built.
+    pub fn builder() -> GetSlotTypeRequestBuilder {
+        GetSlotTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSlotTypeRequest`](struct.GetSlotTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSlotTypeRequestBuilder {
+    name: Option<String>,
+    version: Option<String>,
+}
+
+impl GetSlotTypeRequestBuilder {
+    /// Sets the [`name`](struct.GetSlotTypeRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`version`](struct.GetSlotTypeRequest.html#structfield.version) member.
+    pub fn version<T: Into<String>>(mut self, value: T) -> Self {
+        self.version = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSlotTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSlotTypeRequest, ::rusoto_core::BuildError> {
+        Ok(GetSlotTypeRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            version: self
+                .version
+                .ok_or(::rusoto_core::BuildError::missing("version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSlotTypeResponse {
@@ -1124,8 +2846,9 @@ pub struct GetSlotTypeResponse {
     pub checksum: Option<String>,
     /// <p>The date that the slot type was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the slot type.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1136,8 +2859,9 @@ pub struct GetSlotTypeResponse {
     pub enumeration_values: Option<Vec<EnumerationValue>>,
     /// <p>The date that the slot type was updated. When you create a resource, the creation date and last update date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the slot type.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1145,7 +2869,7 @@ pub struct GetSlotTypeResponse {
     /// <p>The strategy that Amazon Lex uses to determine the value of the slot. For more information, see <a>PutSlotType</a>.</p>
     #[serde(rename = "valueSelectionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value_selection_strategy: Option<String>,
+    pub value_selection_strategy: Option<SlotValueSelectionStrategy>,
     /// <p>The version of the slot type.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1167,6 +2891,54 @@ pub struct GetSlotTypeVersionsRequest {
     pub next_token: Option<String>,
 }
 
+impl GetSlotTypeVersionsRequest {
+    /// Returns a builder for `GetSlotTypeVersionsRequest`, checking its required members when built.
+    pub fn builder() -> GetSlotTypeVersionsRequestBuilder {
+        GetSlotTypeVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSlotTypeVersionsRequest`](struct.GetSlotTypeVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSlotTypeVersionsRequestBuilder {
+    max_results: Option<i64>,
+    name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetSlotTypeVersionsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetSlotTypeVersionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetSlotTypeVersionsRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetSlotTypeVersionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSlotTypeVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSlotTypeVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(GetSlotTypeVersionsRequest {
+            max_results: self.max_results,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSlotTypeVersionsResponse {
@@ -1196,6 +2968,50 @@ pub struct GetSlotTypesRequest {
     pub next_token: Option<String>,
 }
 
+impl GetSlotTypesRequest {
+    /// Returns a builder for `GetSlotTypesRequest`, checking its required members when built.
+    pub fn builder() -> GetSlotTypesRequestBuilder {
+        GetSlotTypesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSlotTypesRequest`](struct.GetSlotTypesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSlotTypesRequestBuilder {
+    max_results: Option<i64>,
+    name_contains: Option<String>,
+    next_token: Option<String>,
+}
+
+impl GetSlotTypesRequestBuilder {
+    /// Sets the [`max_results`](struct.GetSlotTypesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name_contains`](struct.GetSlotTypesRequest.html#structfield.name_contains) member.
+    pub fn name_contains<T: Into<String>>(mut self, value: T) -> Self {
+        self.name_contains = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetSlotTypesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSlotTypesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSlotTypesRequest, ::rusoto_core::BuildError> {
+        Ok(GetSlotTypesRequest {
+            max_results: self.max_results,
+            name_contains: self.name_contains,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSlotTypesResponse {
@@ -1209,7 +3025,7 @@ pub struct GetSlotTypesResponse {
     pub slot_types: Option<Vec<SlotTypeMetadata>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetUtterancesViewRequest {
     /// <p>The name of the bot for which utterance information should be returned.</p>
     #[serde(rename = "botName")]
@@ -1219,7 +3035,59 @@ pub struct GetUtterancesViewRequest {
     pub bot_versions: Vec<String>,
     /// <p>To return utterances that were recognized and handled, use<code>Detected</code>. To return utterances that were not recognized, use <code>Missed</code>.</p>
     #[serde(rename = "statusType")]
-    pub status_type: String,
+    pub status_type: StatusType,
+}
+
+impl GetUtterancesViewRequest {
+    /// Returns a builder for `GetUtterancesViewRequest`, checking its required members when built.
+    pub fn builder() -> GetUtterancesViewRequestBuilder {
+        GetUtterancesViewRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetUtterancesViewRequest`](struct.GetUtterancesViewRequest.html).
+#[derive(Default, Debug)]
+pub struct GetUtterancesViewRequestBuilder {
+    bot_name: Option<String>,
+    bot_versions: Option<Vec<String>>,
+    status_type: Option<StatusType>,
+}
+
+impl GetUtterancesViewRequestBuilder {
+    /// Sets the [`bot_name`](struct.GetUtterancesViewRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_versions`](struct.GetUtterancesViewRequest.html#structfield.bot_versions) member.
+    pub fn bot_versions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.bot_versions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_type`](struct.GetUtterancesViewRequest.html#structfield.status_type) member.
+    pub fn status_type<T: Into<StatusType>>(mut self, value: T) -> Self {
+        self.status_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetUtterancesViewRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetUtterancesViewRequest, ::rusoto_core::BuildError> {
+        Ok(GetUtterancesViewRequest {
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            bot_versions: self
+                .bot_versions
+                .ok_or(::rusoto_core::BuildError::missing("bot_versions"))?,
+            status_type: self
+                .status_type
+                .ok_or(::rusoto_core::BuildError::missing("status_type"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1235,6 +3103,88 @@ pub struct GetUtterancesViewResponse {
     pub utterances: Option<Vec<UtteranceList>>,
 }
 
+/// <p>The status of the import job. If the status is <code>FAILED</code>, you can get the reason for the failure from the <code>failureReason</code> field.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ImportStatus {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>COMPLETE</code></p>
+    Complete,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ImportStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ImportStatus::InProgress => "IN_PROGRESS",
+            ImportStatus::Complete => "COMPLETE",
+            ImportStatus::Failed => "FAILED",
+            ImportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ImportStatus {
+    fn from(value: &'a str) -> ImportStatus {
+        match value {
+            "IN_PROGRESS" => ImportStatus::InProgress,
+            "COMPLETE" => ImportStatus::Complete,
+            "FAILED" => ImportStatus::Failed,
+            _ => ImportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ImportStatus {
+    fn from(value: String) -> ImportStatus {
+        ImportStatus::from(value.as_str())
+    }
+}
+
+impl From<ImportStatus> for String {
+    fn from(value: ImportStatus) -> String {
+        match value {
+            ImportStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ImportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ImportStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ImportStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ImportStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ImportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ImportStatus::from(value))
+    }
+}
+
 /// <p>Identifies the specific version of an intent.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Intent {
@@ -1252,16 +3202,18 @@ pub struct Intent {
 pub struct IntentMetadata {
     /// <p>The date that the intent was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the intent.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date that the intent was updated. When you create an intent, the creation date and last updated date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the intent.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1272,57 +3224,401 @@ pub struct IntentMetadata {
     pub version: Option<String>,
 }
 
-/// <p>The message object that provides the message text and its type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Message {
-    /// <p>The text of the message.</p>
-    #[serde(rename = "content")]
-    pub content: String,
-    /// <p>The content type of the message string.</p>
-    #[serde(rename = "contentType")]
-    pub content_type: String,
-    /// <p>Identifies the message group that the message belongs to. When a group is assigned to a message, Amazon Lex returns one message from each group in the response.</p>
-    #[serde(rename = "groupNumber")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub group_number: Option<i64>,
+/// <p>A list of identifiers for the locales that the intent supports.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// <p><code>de-DE</code></p>
+    DeDe,
+    /// <p><code>en-AU</code></p>
+    EnAu,
+    /// <p><code>en-GB</code></p>
+    EnGb,
+    /// <p><code>en-IN</code></p>
+    EnIn,
+    /// <p><code>en-US</code></p>
+    EnUs,
+    /// <p><code>es-419</code></p>
+    Es419,
+    /// <p><code>es-ES</code></p>
+    EsEs,
+    /// <p><code>es-US</code></p>
+    EsUs,
+    /// <p><code>fr-FR</code></p>
+    FrFr,
+    /// <p><code>fr-CA</code></p>
+    FrCa,
+    /// <p><code>it-IT</code></p>
+    ItIt,
+    /// <p><code>ja-JP</code></p>
+    JaJp,
+    /// <p><code>ko-KR</code></p>
+    KoKr,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Locale {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Locale::DeDe => "de-DE",
+            Locale::EnAu => "en-AU",
+            Locale::EnGb => "en-GB",
+            Locale::EnIn => "en-IN",
+            Locale::EnUs => "en-US",
+            Locale::Es419 => "es-419",
+            Locale::EsEs => "es-ES",
+            Locale::EsUs => "es-US",
+            Locale::FrFr => "fr-FR",
+            Locale::FrCa => "fr-CA",
+            Locale::ItIt => "it-IT",
+            Locale::JaJp => "ja-JP",
+            Locale::KoKr => "ko-KR",
+            Locale::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Obtains information from the user. To define a prompt, provide one or more messages and specify the number of attempts to get information from the user. If you provide more than one message, Amazon Lex chooses one of the messages to use to prompt the user. For more information, see <a>how-it-works</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Prompt {
-    /// <p>The number of times to prompt the user for information.</p>
-    #[serde(rename = "maxAttempts")]
-    pub max_attempts: i64,
-    /// <p>An array of objects, each of which provides a message string and its type. You can specify the message string in plain text or in Speech Synthesis Markup Language (SSML).</p>
-    #[serde(rename = "messages")]
-    pub messages: Vec<Message>,
-    /// <p>A response card. Amazon Lex uses this prompt at runtime, in the <code>PostText</code> API response. It substitutes session attributes and slot values for placeholders in the response card. For more information, see <a>ex-resp-card</a>. </p>
-    #[serde(rename = "responseCard")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub response_card: Option<String>,
+impl<'a> From<&'a str> for Locale {
+    fn from(value: &'a str) -> Locale {
+        match value {
+            "de-DE" => Locale::DeDe,
+            "en-AU" => Locale::EnAu,
+            "en-GB" => Locale::EnGb,
+            "en-IN" => Locale::EnIn,
+            "en-US" => Locale::EnUs,
+            "es-419" => Locale::Es419,
+            "es-ES" => Locale::EsEs,
+            "es-US" => Locale::EsUs,
+            "fr-FR" => Locale::FrFr,
+            "fr-CA" => Locale::FrCa,
+            "it-IT" => Locale::ItIt,
+            "ja-JP" => Locale::JaJp,
+            "ko-KR" => Locale::KoKr,
+            _ => Locale::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct PutBotAliasRequest {
-    /// <p>The name of the bot.</p>
-    #[serde(rename = "botName")]
-    pub bot_name: String,
-    /// <p>The version of the bot.</p>
-    #[serde(rename = "botVersion")]
-    pub bot_version: String,
-    /// <p>Identifies a specific revision of the <code>$LATEST</code> version.</p> <p>When you create a new bot alias, leave the <code>checksum</code> field blank. If you specify a checksum you get a <code>BadRequestException</code> exception.</p> <p>When you want to update a bot alias, set the <code>checksum</code> field to the checksum of the most recent revision of the <code>$LATEST</code> version. If you don't specify the <code> checksum</code> field, or if the checksum does not match the <code>$LATEST</code> version, you get a <code>PreconditionFailedException</code> exception.</p>
-    #[serde(rename = "checksum")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub checksum: Option<String>,
-    /// <p>A description of the alias.</p>
-    #[serde(rename = "description")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    /// <p>The name of the alias. The name is <i>not</i> case sensitive.</p>
+impl From<String> for Locale {
+    fn from(value: String) -> Locale {
+        Locale::from(value.as_str())
+    }
+}
+
+impl From<Locale> for String {
+    fn from(value: Locale) -> String {
+        match value {
+            Locale::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Locale {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Locale, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Locale::from(value))
+    }
+}
+
+/// <p>The action taken when there was a conflict between an existing resource and a resource in the import file.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MergeStrategy {
+    /// <p><code>OVERWRITE_LATEST</code></p>
+    OverwriteLatest,
+    /// <p><code>FAIL_ON_CONFLICT</code></p>
+    FailOnConflict,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MergeStrategy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MergeStrategy::OverwriteLatest => "OVERWRITE_LATEST",
+            MergeStrategy::FailOnConflict => "FAIL_ON_CONFLICT",
+            MergeStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MergeStrategy {
+    fn from(value: &'a str) -> MergeStrategy {
+        match value {
+            "OVERWRITE_LATEST" => MergeStrategy::OverwriteLatest,
+            "FAIL_ON_CONFLICT" => MergeStrategy::FailOnConflict,
+            _ => MergeStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MergeStrategy {
+    fn from(value: String) -> MergeStrategy {
+        MergeStrategy::from(value.as_str())
+    }
+}
+
+impl From<MergeStrategy> for String {
+    fn from(value: MergeStrategy) -> String {
+        match value {
+            MergeStrategy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MergeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MergeStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MergeStrategy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MergeStrategy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MergeStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MergeStrategy::from(value))
+    }
+}
+
+/// <p>The message object that provides the message text and its type.</p>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+    /// <p>The text of the message.</p>
+    #[serde(rename = "content")]
+    pub content: String,
+    /// <p>The content type of the message string.</p>
+    #[serde(rename = "contentType")]
+    pub content_type: ContentType,
+    /// <p>Identifies the message group that the message belongs to. When a group is assigned to a message, Amazon Lex returns one message from each group in the response.</p>
+    #[serde(rename = "groupNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_number: Option<i64>,
+}
+
+/// <p>If you set the <code>processBehavior</code> element to <code>BUILD</code>, Amazon Lex builds the bot so that it can be run. If you set the element to <code>SAVE</code> Amazon Lex saves the bot, but doesn't build it. </p> <p>If you don't specify this value, the default value is <code>BUILD</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProcessBehavior {
+    /// <p><code>SAVE</code></p>
+    Save,
+    /// <p><code>BUILD</code></p>
+    Build,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProcessBehavior {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProcessBehavior::Save => "SAVE",
+            ProcessBehavior::Build => "BUILD",
+            ProcessBehavior::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProcessBehavior {
+    fn from(value: &'a str) -> ProcessBehavior {
+        match value {
+            "SAVE" => ProcessBehavior::Save,
+            "BUILD" => ProcessBehavior::Build,
+            _ => ProcessBehavior::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProcessBehavior {
+    fn from(value: String) -> ProcessBehavior {
+        ProcessBehavior::from(value.as_str())
+    }
+}
+
+impl From<ProcessBehavior> for String {
+    fn from(value: ProcessBehavior) -> String {
+        match value {
+            ProcessBehavior::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProcessBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProcessBehavior {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProcessBehavior {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProcessBehavior {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProcessBehavior, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProcessBehavior::from(value))
+    }
+}
+
+/// <p>Obtains information from the user. To define a prompt, provide one or more messages and specify the number of attempts to get information from the user. If you provide more than one message, Amazon Lex chooses one of the messages to use to prompt the user. For more information, see <a>how-it-works</a>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Prompt {
+    /// <p>The number of times to prompt the user for information.</p>
+    #[serde(rename = "maxAttempts")]
+    pub max_attempts: i64,
+    /// <p>An array of objects, each of which provides a message string and its type. You can specify the message string in plain text or in Speech Synthesis Markup Language (SSML).</p>
+    #[serde(rename = "messages")]
+    pub messages: Vec<Message>,
+    /// <p>A response card. Amazon Lex uses this prompt at runtime, in the <code>PostText</code> API response. It substitutes session attributes and slot values for placeholders in the response card. For more information, see <a>ex-resp-card</a>. </p>
+    #[serde(rename = "responseCard")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_card: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct PutBotAliasRequest {
+    /// <p>The name of the bot.</p>
+    #[serde(rename = "botName")]
+    pub bot_name: String,
+    /// <p>The version of the bot.</p>
+    #[serde(rename = "botVersion")]
+    pub bot_version: String,
+    /// <p>Identifies a specific revision of the <code>$LATEST</code> version.</p> <p>When you create a new bot alias, leave the <code>checksum</code> field blank. If you specify a checksum you get a <code>BadRequestException</code> exception.</p> <p>When you want to update a bot alias, set the <code>checksum</code> field to the checksum of the most recent revision of the <code>$LATEST</code> version. If you don't specify the <code> checksum</code> field, or if the checksum does not match the <code>$LATEST</code> version, you get a <code>PreconditionFailedException</code> exception.</p>
+    #[serde(rename = "checksum")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+    /// <p>A description of the alias.</p>
+    #[serde(rename = "description")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// <p>The name of the alias. The name is <i>not</i> case sensitive.</p>
     #[serde(rename = "name")]
     pub name: String,
 }
 
+impl PutBotAliasRequest {
+    /// Returns a builder for `PutBotAliasRequest`, checking its required members when built.
+    pub fn builder() -> PutBotAliasRequestBuilder {
+        PutBotAliasRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutBotAliasRequest`](struct.PutBotAliasRequest.html).
+#[derive(Default, Debug)]
+pub struct PutBotAliasRequestBuilder {
+    bot_name: Option<String>,
+    bot_version: Option<String>,
+    checksum: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+}
+
+impl PutBotAliasRequestBuilder {
+    /// Sets the [`bot_name`](struct.PutBotAliasRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_version`](struct.PutBotAliasRequest.html#structfield.bot_version) member.
+    pub fn bot_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`checksum`](struct.PutBotAliasRequest.html#structfield.checksum) member.
+    pub fn checksum<T: Into<String>>(mut self, value: T) -> Self {
+        self.checksum = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.PutBotAliasRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.PutBotAliasRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutBotAliasRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutBotAliasRequest, ::rusoto_core::BuildError> {
+        Ok(PutBotAliasRequest {
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            bot_version: self
+                .bot_version
+                .ok_or(::rusoto_core::BuildError::missing("bot_version"))?,
+            checksum: self.checksum,
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutBotAliasResponse {
@@ -1340,23 +3636,25 @@ pub struct PutBotAliasResponse {
     pub checksum: Option<String>,
     /// <p>The date that the bot alias was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the alias.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date that the bot alias was updated. When you create a resource, the creation date and the last updated date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the alias.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PutBotRequest {
     /// <p>When Amazon Lex can't understand the user's input in context, it tries to elicit the information a few times. After that, Amazon Lex sends the message defined in <code>abortStatement</code> to the user, and then aborts the conversation. To set the number of retries, use the <code>valueElicitationPrompt</code> field for the slot type. </p> <p>For example, in a pizza ordering bot, Amazon Lex might ask a user "What type of crust would you like?" If the user's response is not one of the expected responses (for example, "thin crust, "deep dish," etc.), Amazon Lex tries to elicit a correct response a few more times. </p> <p>For example, in a pizza ordering application, <code>OrderPizza</code> might be one of the intents. This intent might require the <code>CrustType</code> slot. You specify the <code>valueElicitationPrompt</code> field when you create the <code>CrustType</code> slot.</p>
     #[serde(rename = "abortStatement")]
@@ -1390,20 +3688,142 @@ pub struct PutBotRequest {
     pub intents: Option<Vec<Intent>>,
     /// <p> Specifies the target locale for the bot. Any intent used in the bot must be compatible with the locale of the bot. </p> <p>The default is <code>en-US</code>.</p>
     #[serde(rename = "locale")]
-    pub locale: String,
+    pub locale: Locale,
     /// <p>The name of the bot. The name is <i>not</i> case sensitive. </p>
     #[serde(rename = "name")]
     pub name: String,
     /// <p>If you set the <code>processBehavior</code> element to <code>BUILD</code>, Amazon Lex builds the bot so that it can be run. If you set the element to <code>SAVE</code> Amazon Lex saves the bot, but doesn't build it. </p> <p>If you don't specify this value, the default value is <code>BUILD</code>.</p>
     #[serde(rename = "processBehavior")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub process_behavior: Option<String>,
+    pub process_behavior: Option<ProcessBehavior>,
     /// <p>The Amazon Polly voice ID that you want Amazon Lex to use for voice interactions with the user. The locale configured for the voice must match the locale of the bot. For more information, see <a href="http://docs.aws.amazon.com/polly/latest/dg/voicelist.html">Available Voices</a> in the <i>Amazon Polly Developer Guide</i>.</p>
     #[serde(rename = "voiceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub voice_id: Option<String>,
 }
 
+impl PutBotRequest {
+    /// Returns a builder for `PutBotRequest`, checking its required members when built.
+    pub fn builder() -> PutBotRequestBuilder {
+        PutBotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutBotRequest`](struct.PutBotRequest.html).
+#[derive(Default, Debug)]
+pub struct PutBotRequestBuilder {
+    abort_statement: Option<Statement>,
+    checksum: Option<String>,
+    child_directed: Option<bool>,
+    clarification_prompt: Option<Prompt>,
+    create_version: Option<bool>,
+    description: Option<String>,
+    idle_session_ttl_in_seconds: Option<i64>,
+    intents: Option<Vec<Intent>>,
+    locale: Option<Locale>,
+    name: Option<String>,
+    process_behavior: Option<ProcessBehavior>,
+    voice_id: Option<String>,
+}
+
+impl PutBotRequestBuilder {
+    /// Sets the [`abort_statement`](struct.PutBotRequest.html#structfield.abort_statement) member.
+    pub fn abort_statement<T: Into<Statement>>(mut self, value: T) -> Self {
+        self.abort_statement = Some(value.into());
+        self
+    }
+
+    /// Sets the [`checksum`](struct.PutBotRequest.html#structfield.checksum) member.
+    pub fn checksum<T: Into<String>>(mut self, value: T) -> Self {
+        self.checksum = Some(value.into());
+        self
+    }
+
+    /// Sets the [`child_directed`](struct.PutBotRequest.html#structfield.child_directed) member.
+    pub fn child_directed<T: Into<bool>>(mut self, value: T) -> Self {
+        self.child_directed = Some(value.into());
+        self
+    }
+
+    /// Sets the [`clarification_prompt`](struct.PutBotRequest.html#structfield.clarification_prompt) member.
+    pub fn clarification_prompt<T: Into<Prompt>>(mut self, value: T) -> Self {
+        self.clarification_prompt = Some(value.into());
+        self
+    }
+
+    /// Sets the [`create_version`](struct.PutBotRequest.html#structfield.create_version) member.
+    pub fn create_version<T: Into<bool>>(mut self, value: T) -> Self {
+        self.create_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.PutBotRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`idle_session_ttl_in_seconds`](struct.PutBotRequest.html#structfield.idle_session_ttl_in_seconds) member.
+    pub fn idle_session_ttl_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.idle_session_ttl_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`intents`](struct.PutBotRequest.html#structfield.intents) member.
+    pub fn intents<T: Into<Vec<Intent>>>(mut self, value: T) -> Self {
+        self.intents = Some(value.into());
+        self
+    }
+
+    /// Sets the [`locale`](struct.PutBotRequest.html#structfield.locale) member.
+    pub fn locale<T: Into<Locale>>(mut self, value: T) -> Self {
+        self.locale = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.PutBotRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`process_behavior`](struct.PutBotRequest.html#structfield.process_behavior) member.
+    pub fn process_behavior<T: Into<ProcessBehavior>>(mut self, value: T) -> Self {
+        self.process_behavior = Some(value.into());
+        self
+    }
+
+    /// Sets the [`voice_id`](struct.PutBotRequest.html#structfield.voice_id) member.
+    pub fn voice_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.voice_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutBotRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutBotRequest, ::rusoto_core::BuildError> {
+        Ok(PutBotRequest {
+            abort_statement: self.abort_statement,
+            checksum: self.checksum,
+            child_directed: self
+                .child_directed
+                .ok_or(::rusoto_core::BuildError::missing("child_directed"))?,
+            clarification_prompt: self.clarification_prompt,
+            create_version: self.create_version,
+            description: self.description,
+            idle_session_ttl_in_seconds: self.idle_session_ttl_in_seconds,
+            intents: self.intents,
+            locale: self
+                .locale
+                .ok_or(::rusoto_core::BuildError::missing("locale"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            process_behavior: self.process_behavior,
+            voice_id: self.voice_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutBotResponse {
@@ -1428,8 +3848,9 @@ pub struct PutBotResponse {
     pub create_version: Option<bool>,
     /// <p>The date that the bot was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the bot.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1448,12 +3869,13 @@ pub struct PutBotResponse {
     pub intents: Option<Vec<Intent>>,
     /// <p>The date that the bot was updated. When you create a resource, the creation date and last updated date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p> The target locale for the bot. </p>
     #[serde(rename = "locale")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// <p>The name of the bot.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1461,7 +3883,7 @@ pub struct PutBotResponse {
     /// <p> When you send a request to create a bot with <code>processBehavior</code> set to <code>BUILD</code>, Amazon Lex sets the <code>status</code> response element to <code>BUILDING</code>. After Amazon Lex builds the bot, it sets <code>status</code> to <code>READY</code>. If Amazon Lex can't build the bot, Amazon Lex sets <code>status</code> to <code>FAILED</code>. Amazon Lex returns the reason for the failure in the <code>failureReason</code> response element. </p> <p>When you set <code>processBehavior</code>to <code>SAVE</code>, Amazon Lex sets the status code to <code>NOT BUILT</code>.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>The version of the bot. For a new bot, the version is always <code>$LATEST</code>.</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1526,6 +3948,132 @@ pub struct PutIntentRequest {
     pub slots: Option<Vec<Slot>>,
 }
 
+impl PutIntentRequest {
+    /// Returns a builder for `PutIntentRequest`, checking its required members when built.
+    pub fn builder() -> PutIntentRequestBuilder {
+        PutIntentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutIntentRequest`](struct.PutIntentRequest.html).
+#[derive(Default, Debug)]
+pub struct PutIntentRequestBuilder {
+    checksum: Option<String>,
+    conclusion_statement: Option<Statement>,
+    confirmation_prompt: Option<Prompt>,
+    create_version: Option<bool>,
+    description: Option<String>,
+    dialog_code_hook: Option<CodeHook>,
+    follow_up_prompt: Option<FollowUpPrompt>,
+    fulfillment_activity: Option<FulfillmentActivity>,
+    name: Option<String>,
+    parent_intent_signature: Option<String>,
+    rejection_statement: Option<Statement>,
+    sample_utterances: Option<Vec<String>>,
+    slots: Option<Vec<Slot>>,
+}
+
+impl PutIntentRequestBuilder {
+    /// Sets the [`checksum`](struct.PutIntentRequest.html#structfield.checksum) member.
+    pub fn checksum<T: Into<String>>(mut self, value: T) -> Self {
+        self.checksum = Some(value.into());
+        self
+    }
+
+    /// Sets the [`conclusion_statement`](struct.PutIntentRequest.html#structfield.conclusion_statement) member.
+    pub fn conclusion_statement<T: Into<Statement>>(mut self, value: T) -> Self {
+        self.conclusion_statement = Some(value.into());
+        self
+    }
+
+    /// Sets the [`confirmation_prompt`](struct.PutIntentRequest.html#structfield.confirmation_prompt) member.
+    pub fn confirmation_prompt<T: Into<Prompt>>(mut self, value: T) -> Self {
+        self.confirmation_prompt = Some(value.into());
+        self
+    }
+
+    /// Sets the [`create_version`](struct.PutIntentRequest.html#structfield.create_version) member.
+    pub fn create_version<T: Into<bool>>(mut self, value: T) -> Self {
+        self.create_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.PutIntentRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dialog_code_hook`](struct.PutIntentRequest.html#structfield.dialog_code_hook) member.
+    pub fn dialog_code_hook<T: Into<CodeHook>>(mut self, value: T) -> Self {
+        self.dialog_code_hook = Some(value.into());
+        self
+    }
+
+    /// Sets the [`follow_up_prompt`](struct.PutIntentRequest.html#structfield.follow_up_prompt) member.
+    pub fn follow_up_prompt<T: Into<FollowUpPrompt>>(mut self, value: T) -> Self {
+        self.follow_up_prompt = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fulfillment_activity`](struct.PutIntentRequest.html#structfield.fulfillment_activity) member.
+    pub fn fulfillment_activity<T: Into<FulfillmentActivity>>(mut self, value: T) -> Self {
+        self.fulfillment_activity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.PutIntentRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_intent_signature`](struct.PutIntentRequest.html#structfield.parent_intent_signature) member.
+    pub fn parent_intent_signature<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_intent_signature = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rejection_statement`](struct.PutIntentRequest.html#structfield.rejection_statement) member.
+    pub fn rejection_statement<T: Into<Statement>>(mut self, value: T) -> Self {
+        self.rejection_statement = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sample_utterances`](struct.PutIntentRequest.html#structfield.sample_utterances) member.
+    pub fn sample_utterances<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.sample_utterances = Some(value.into());
+        self
+    }
+
+    /// Sets the [`slots`](struct.PutIntentRequest.html#structfield.slots) member.
+    pub fn slots<T: Into<Vec<Slot>>>(mut self, value: T) -> Self {
+        self.slots = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutIntentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutIntentRequest, ::rusoto_core::BuildError> {
+        Ok(PutIntentRequest {
+            checksum: self.checksum,
+            conclusion_statement: self.conclusion_statement,
+            confirmation_prompt: self.confirmation_prompt,
+            create_version: self.create_version,
+            description: self.description,
+            dialog_code_hook: self.dialog_code_hook,
+            follow_up_prompt: self.follow_up_prompt,
+            fulfillment_activity: self.fulfillment_activity,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            parent_intent_signature: self.parent_intent_signature,
+            rejection_statement: self.rejection_statement,
+            sample_utterances: self.sample_utterances,
+            slots: self.slots,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutIntentResponse {
@@ -1546,8 +4094,9 @@ pub struct PutIntentResponse {
     pub create_version: Option<bool>,
     /// <p>The date that the intent was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the intent.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1566,8 +4115,9 @@ pub struct PutIntentResponse {
     pub fulfillment_activity: Option<FulfillmentActivity>,
     /// <p>The date that the intent was updated. When you create a resource, the creation date and last update dates are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the intent.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1617,7 +4167,80 @@ pub struct PutSlotTypeRequest {
     /// <p>Determines the slot resolution strategy that Amazon Lex uses to return slot type values. The field can be set to one of the following values:</p> <ul> <li> <p> <code>ORIGINAL_VALUE</code> - Returns the value entered by the user, if the user value is similar to the slot value.</p> </li> <li> <p> <code>TOP_RESOLUTION</code> - If there is a resolution list for the slot, return the first value in the resolution list as the slot type value. If there is no resolution list, null is returned.</p> </li> </ul> <p>If you don't specify the <code>valueSelectionStrategy</code>, the default is <code>ORIGINAL_VALUE</code>.</p>
     #[serde(rename = "valueSelectionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value_selection_strategy: Option<String>,
+    pub value_selection_strategy: Option<SlotValueSelectionStrategy>,
+}
+
+impl PutSlotTypeRequest {
+    /// Returns a builder for `PutSlotTypeRequest`, checking its required members when built.
+    pub fn builder() -> PutSlotTypeRequestBuilder {
+        PutSlotTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutSlotTypeRequest`](struct.PutSlotTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct PutSlotTypeRequestBuilder {
+    checksum: Option<String>,
+    create_version: Option<bool>,
+    description: Option<String>,
+    enumeration_values: Option<Vec<EnumerationValue>>,
+    name: Option<String>,
+    value_selection_strategy: Option<SlotValueSelectionStrategy>,
+}
+
+impl PutSlotTypeRequestBuilder {
+    /// Sets the [`checksum`](struct.PutSlotTypeRequest.html#structfield.checksum) member.
+    pub fn checksum<T: Into<String>>(mut self, value: T) -> Self {
+        self.checksum = Some(value.into());
+        self
+    }
+
+    /// Sets the [`create_version`](struct.PutSlotTypeRequest.html#structfield.create_version) member.
+    pub fn create_version<T: Into<bool>>(mut self, value: T) -> Self {
+        self.create_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.PutSlotTypeRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enumeration_values`](struct.PutSlotTypeRequest.html#structfield.enumeration_values) member.
+    pub fn enumeration_values<T: Into<Vec<EnumerationValue>>>(mut self, value: T) -> Self {
+        self.enumeration_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.PutSlotTypeRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`value_selection_strategy`](struct.PutSlotTypeRequest.html#structfield.value_selection_strategy) member.
+    pub fn value_selection_strategy<T: Into<SlotValueSelectionStrategy>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.value_selection_strategy = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutSlotTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutSlotTypeRequest, ::rusoto_core::BuildError> {
+        Ok(PutSlotTypeRequest {
+            checksum: self.checksum,
+            create_version: self.create_version,
+            description: self.description,
+            enumeration_values: self.enumeration_values,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            value_selection_strategy: self.value_selection_strategy,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1632,8 +4255,9 @@ pub struct PutSlotTypeResponse {
     pub create_version: Option<bool>,
     /// <p>The date that the slot type was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the slot type.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1644,8 +4268,9 @@ pub struct PutSlotTypeResponse {
     pub enumeration_values: Option<Vec<EnumerationValue>>,
     /// <p>The date that the slot type was updated. When you create a slot type, the creation date and last update date are the same.</p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the slot type.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1653,7 +4278,7 @@ pub struct PutSlotTypeResponse {
     /// <p>The slot resolution strategy that Amazon Lex uses to determine the value of the slot. For more information, see <a>PutSlotType</a>.</p>
     #[serde(rename = "valueSelectionStrategy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value_selection_strategy: Option<String>,
+    pub value_selection_strategy: Option<SlotValueSelectionStrategy>,
     /// <p>The version of the slot type. For a new slot type, the version is always <code>$LATEST</code>. </p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1669,8 +4294,90 @@ pub struct ResourceReference {
     pub version: Option<String>,
 }
 
+/// <p>The type of resource to export. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// <p><code>BOT</code></p>
+    Bot,
+    /// <p><code>INTENT</code></p>
+    Intent,
+    /// <p><code>SLOT_TYPE</code></p>
+    SlotType,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Bot => "BOT",
+            ResourceType::Intent => "INTENT",
+            ResourceType::SlotType => "SLOT_TYPE",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "BOT" => ResourceType::Bot,
+            "INTENT" => ResourceType::Intent,
+            "SLOT_TYPE" => ResourceType::SlotType,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        ResourceType::from(value.as_str())
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(value: ResourceType) -> String {
+        match value {
+            ResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceType::from(value))
+    }
+}
+
 /// <p>Identifies the version of a specific slot.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Slot {
     /// <p>A description of the slot.</p>
     #[serde(rename = "description")]
@@ -1693,7 +4400,7 @@ pub struct Slot {
     pub sample_utterances: Option<Vec<String>>,
     /// <p>Specifies whether the slot is required or optional. </p>
     #[serde(rename = "slotConstraint")]
-    pub slot_constraint: String,
+    pub slot_constraint: SlotConstraint,
     /// <p>The type of the slot, either a custom slot type that you defined or one of the built-in slot types.</p>
     #[serde(rename = "slotType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1708,22 +4415,102 @@ pub struct Slot {
     pub value_elicitation_prompt: Option<Prompt>,
 }
 
+/// <p>Specifies whether the slot is required or optional. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SlotConstraint {
+    /// <p><code>Required</code></p>
+    Required,
+    /// <p><code>Optional</code></p>
+    Optional,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SlotConstraint {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SlotConstraint::Required => "Required",
+            SlotConstraint::Optional => "Optional",
+            SlotConstraint::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SlotConstraint {
+    fn from(value: &'a str) -> SlotConstraint {
+        match value {
+            "Required" => SlotConstraint::Required,
+            "Optional" => SlotConstraint::Optional,
+            _ => SlotConstraint::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SlotConstraint {
+    fn from(value: String) -> SlotConstraint {
+        SlotConstraint::from(value.as_str())
+    }
+}
+
+impl From<SlotConstraint> for String {
+    fn from(value: SlotConstraint) -> String {
+        match value {
+            SlotConstraint::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SlotConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SlotConstraint {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SlotConstraint {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SlotConstraint {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SlotConstraint, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SlotConstraint::from(value))
+    }
+}
+
 /// <p>Provides information about a slot type..</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SlotTypeMetadata {
     /// <p>The date that the slot type was created.</p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p>A description of the slot type.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date that the slot type was updated. When you create a resource, the creation date and last updated date are the same. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     /// <p>The name of the slot type.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1734,63 +4521,362 @@ pub struct SlotTypeMetadata {
     pub version: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct StartImportRequest {
-    /// <p><p>Specifies the action that the <code>StartImport</code> operation should take when there is an existing resource with the same name.</p> <ul> <li> <p>FAIL<em>ON</em>CONFLICT - The import operation is stopped on the first conflict between a resource in the import file and an existing resource. The name of the resource causing the conflict is in the <code>failureReason</code> field of the response to the <code>GetImport</code> operation.</p> <p>OVERWRITE_LATEST - The import operation proceeds even if there is a conflict with an existing resource. The $LASTEST version of the existing resource is overwritten with the data from the import file.</p> </li> </ul></p>
-    #[serde(rename = "mergeStrategy")]
-    pub merge_strategy: String,
-    /// <p>A zip archive in binary format. The archive should contain one file, a JSON file containing the resource to import. The resource should match the type specified in the <code>resourceType</code> field.</p>
-    #[serde(rename = "payload")]
-    #[serde(
-        deserialize_with = "::rusoto_core::serialization::SerdeBlob::deserialize_blob",
-        serialize_with = "::rusoto_core::serialization::SerdeBlob::serialize_blob",
-        default
-    )]
-    pub payload: bytes::Bytes,
-    /// <p><p>Specifies the type of resource to export. Each resource also exports any resources that it depends on. </p> <ul> <li> <p>A bot exports dependent intents.</p> </li> <li> <p>An intent exports dependent slot types.</p> </li> </ul></p>
-    #[serde(rename = "resourceType")]
-    pub resource_type: String,
+/// <p>The strategy that Amazon Lex uses to determine the value of the slot. For more information, see <a>PutSlotType</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SlotValueSelectionStrategy {
+    /// <p><code>ORIGINAL_VALUE</code> - Returns the value entered by the user, if the user value is similar to the slot value.</p>
+    OriginalValue,
+    /// <p><code>TOP_RESOLUTION</code> - If there is a resolution list for the slot, return the first value in the resolution list as the slot type value. If there is no resolution list, null is returned.</p>
+    TopResolution,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SlotValueSelectionStrategy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SlotValueSelectionStrategy::OriginalValue => "ORIGINAL_VALUE",
+            SlotValueSelectionStrategy::TopResolution => "TOP_RESOLUTION",
+            SlotValueSelectionStrategy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SlotValueSelectionStrategy {
+    fn from(value: &'a str) -> SlotValueSelectionStrategy {
+        match value {
+            "ORIGINAL_VALUE" => SlotValueSelectionStrategy::OriginalValue,
+            "TOP_RESOLUTION" => SlotValueSelectionStrategy::TopResolution,
+            _ => SlotValueSelectionStrategy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SlotValueSelectionStrategy {
+    fn from(value: String) -> SlotValueSelectionStrategy {
+        SlotValueSelectionStrategy::from(value.as_str())
+    }
+}
+
+impl From<SlotValueSelectionStrategy> for String {
+    fn from(value: SlotValueSelectionStrategy) -> String {
+        match value {
+            SlotValueSelectionStrategy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SlotValueSelectionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SlotValueSelectionStrategy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SlotValueSelectionStrategy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SlotValueSelectionStrategy {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<SlotValueSelectionStrategy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SlotValueSelectionStrategy::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StartImportRequest {
+    /// <p><p>Specifies the action that the <code>StartImport</code> operation should take when there is an existing resource with the same name.</p> <ul> <li> <p>FAIL<em>ON</em>CONFLICT - The import operation is stopped on the first conflict between a resource in the import file and an existing resource. The name of the resource causing the conflict is in the <code>failureReason</code> field of the response to the <code>GetImport</code> operation.</p> <p>OVERWRITE_LATEST - The import operation proceeds even if there is a conflict with an existing resource. The $LASTEST version of the existing resource is overwritten with the data from the import file.</p> </li> </ul></p>
+    #[serde(rename = "mergeStrategy")]
+    pub merge_strategy: MergeStrategy,
+    /// <p>A zip archive in binary format. The archive should contain one file, a JSON file containing the resource to import. The resource should match the type specified in the <code>resourceType</code> field.</p>
+    #[serde(rename = "payload")]
+    #[serde(
+        deserialize_with = "::rusoto_core::serialization::SerdeBlob::deserialize_blob",
+        serialize_with = "::rusoto_core::serialization::SerdeBlob::serialize_blob",
+        default
+    )]
+    pub payload: bytes::Bytes,
+    /// <p><p>Specifies the type of resource to export. Each resource also exports any resources that it depends on. </p> <ul> <li> <p>A bot exports dependent intents.</p> </li> <li> <p>An intent exports dependent slot types.</p> </li> </ul></p>
+    #[serde(rename = "resourceType")]
+    pub resource_type: ResourceType,
+}
+
+impl StartImportRequest {
+    /// Returns a builder for `StartImportRequest`, checking its required members when built.
+    pub fn builder() -> StartImportRequestBuilder {
+        StartImportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartImportRequest`](struct.StartImportRequest.html).
+#[derive(Default, Debug)]
+pub struct StartImportRequestBuilder {
+    merge_strategy: Option<MergeStrategy>,
+    payload: Option<bytes::Bytes>,
+    resource_type: Option<ResourceType>,
+}
+
+impl StartImportRequestBuilder {
+    /// Sets the [`merge_strategy`](struct.StartImportRequest.html#structfield.merge_strategy) member.
+    pub fn merge_strategy<T: Into<MergeStrategy>>(mut self, value: T) -> Self {
+        self.merge_strategy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`payload`](struct.StartImportRequest.html#structfield.payload) member.
+    pub fn payload<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.payload = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.StartImportRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<ResourceType>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartImportRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartImportRequest, ::rusoto_core::BuildError> {
+        Ok(StartImportRequest {
+            merge_strategy: self
+                .merge_strategy
+                .ok_or(::rusoto_core::BuildError::missing("merge_strategy"))?,
+            payload: self
+                .payload
+                .ok_or(::rusoto_core::BuildError::missing("payload"))?,
+            resource_type: self
+                .resource_type
+                .ok_or(::rusoto_core::BuildError::missing("resource_type"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct StartImportResponse {
+    /// <p>A timestamp for the date and time that the import job was requested.</p>
+    #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_date: Option<EpochTimestamp>,
+    /// <p>The identifier for the specific import job.</p>
+    #[serde(rename = "importId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_id: Option<String>,
+    /// <p>The status of the import job. If the status is <code>FAILED</code>, you can get the reason for the failure using the <code>GetImport</code> operation.</p>
+    #[serde(rename = "importStatus")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_status: Option<ImportStatus>,
+    /// <p>The action to take when there is a merge conflict.</p>
+    #[serde(rename = "mergeStrategy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge_strategy: Option<MergeStrategy>,
+    /// <p>The name given to the import job.</p>
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// <p>The type of resource to import.</p>
+    #[serde(rename = "resourceType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<ResourceType>,
+}
+
+/// <p>A collection of messages that convey information to the user. At runtime, Amazon Lex selects the message to convey. </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Statement {
+    /// <p>A collection of message objects.</p>
+    #[serde(rename = "messages")]
+    pub messages: Vec<Message>,
+    /// <p> At runtime, if the client is using the <a href="http://docs.aws.amazon.com/lex/latest/dg/API_runtime_PostText.html">PostText</a> API, Amazon Lex includes the response card in the response. It substitutes all of the session attributes and slot values for placeholders in the response card. </p>
+    #[serde(rename = "responseCard")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_card: Option<String>,
+}
+
+/// <p>The status of the bot.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// <p><code>BUILDING</code></p>
+    Building,
+    /// <p><code>READY</code></p>
+    Ready,
+    /// <p><code>READY_BASIC_TESTING</code></p>
+    ReadyBasicTesting,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>NOT_BUILT</code></p>
+    NotBuilt,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Status {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::Building => "BUILDING",
+            Status::Ready => "READY",
+            Status::ReadyBasicTesting => "READY_BASIC_TESTING",
+            Status::Failed => "FAILED",
+            Status::NotBuilt => "NOT_BUILT",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "BUILDING" => Status::Building,
+            "READY" => Status::Ready,
+            "READY_BASIC_TESTING" => Status::ReadyBasicTesting,
+            "FAILED" => Status::Failed,
+            "NOT_BUILT" => Status::NotBuilt,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        Status::from(value.as_str())
+    }
+}
+
+impl From<Status> for String {
+    fn from(value: Status) -> String {
+        match value {
+            Status::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Status {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Status::from(value))
+    }
+}
+
+/// <p>To return utterances that were recognized and handled, use<code>Detected</code>. To return utterances that were not recognized, use <code>Missed</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StatusType {
+    /// <p><code>Detected</code></p>
+    Detected,
+    /// <p><code>Missed</code></p>
+    Missed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StatusType::Detected => "Detected",
+            StatusType::Missed => "Missed",
+            StatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StatusType {
+    fn from(value: &'a str) -> StatusType {
+        match value {
+            "Detected" => StatusType::Detected,
+            "Missed" => StatusType::Missed,
+            _ => StatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StatusType {
+    fn from(value: String) -> StatusType {
+        StatusType::from(value.as_str())
+    }
+}
+
+impl From<StatusType> for String {
+    fn from(value: StatusType) -> String {
+        match value {
+            StatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct StartImportResponse {
-    /// <p>A timestamp for the date and time that the import job was requested.</p>
-    #[serde(rename = "createdDate")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
-    /// <p>The identifier for the specific import job.</p>
-    #[serde(rename = "importId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_id: Option<String>,
-    /// <p>The status of the import job. If the status is <code>FAILED</code>, you can get the reason for the failure using the <code>GetImport</code> operation.</p>
-    #[serde(rename = "importStatus")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub import_status: Option<String>,
-    /// <p>The action to take when there is a merge conflict.</p>
-    #[serde(rename = "mergeStrategy")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub merge_strategy: Option<String>,
-    /// <p>The name given to the import job.</p>
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// <p>The type of resource to import.</p>
-    #[serde(rename = "resourceType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+impl ::rusoto_core::param::ToParam for StatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
 }
 
-/// <p>A collection of messages that convey information to the user. At runtime, Amazon Lex selects the message to convey. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Statement {
-    /// <p>A collection of message objects.</p>
-    #[serde(rename = "messages")]
-    pub messages: Vec<Message>,
-    /// <p> At runtime, if the client is using the <a href="http://docs.aws.amazon.com/lex/latest/dg/API_runtime_PostText.html">PostText</a> API, Amazon Lex includes the response card in the response. It substitutes all of the session attributes and slot values for placeholders in the response card. </p>
-    #[serde(rename = "responseCard")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub response_card: Option<String>,
+impl ::serde::Serialize for StatusType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StatusType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StatusType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StatusType::from(value))
+    }
 }
 
 /// <p>Provides information about a single utterance that was made to your bot. </p>
@@ -1807,12 +4893,14 @@ pub struct UtteranceData {
     pub distinct_users: Option<i64>,
     /// <p>The date that the utterance was first recorded.</p>
     #[serde(rename = "firstUtteredDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_uttered_date: Option<f64>,
+    pub first_uttered_date: Option<EpochTimestamp>,
     /// <p>The date that the utterance was last recorded.</p>
     #[serde(rename = "lastUtteredDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_uttered_date: Option<f64>,
+    pub last_uttered_date: Option<EpochTimestamp>,
     /// <p>The text that was entered by the user or the text representation of an audio clip.</p>
     #[serde(rename = "utteranceString")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -4012,6 +7100,216 @@ pub trait LexModels {
         input: StartImportRequest,
     ) -> RusotoFuture<StartImportResponse, StartImportError>;
 }
+impl<T: ?Sized + LexModels> LexModels for ::std::sync::Arc<T> {
+    fn create_bot_version(
+        &self,
+        input: CreateBotVersionRequest,
+    ) -> RusotoFuture<CreateBotVersionResponse, CreateBotVersionError> {
+        (**self).create_bot_version(input)
+    }
+    fn create_intent_version(
+        &self,
+        input: CreateIntentVersionRequest,
+    ) -> RusotoFuture<CreateIntentVersionResponse, CreateIntentVersionError> {
+        (**self).create_intent_version(input)
+    }
+    fn create_slot_type_version(
+        &self,
+        input: CreateSlotTypeVersionRequest,
+    ) -> RusotoFuture<CreateSlotTypeVersionResponse, CreateSlotTypeVersionError> {
+        (**self).create_slot_type_version(input)
+    }
+    fn delete_bot(&self, input: DeleteBotRequest) -> RusotoFuture<(), DeleteBotError> {
+        (**self).delete_bot(input)
+    }
+    fn delete_bot_alias(
+        &self,
+        input: DeleteBotAliasRequest,
+    ) -> RusotoFuture<(), DeleteBotAliasError> {
+        (**self).delete_bot_alias(input)
+    }
+    fn delete_bot_channel_association(
+        &self,
+        input: DeleteBotChannelAssociationRequest,
+    ) -> RusotoFuture<(), DeleteBotChannelAssociationError> {
+        (**self).delete_bot_channel_association(input)
+    }
+    fn delete_bot_version(
+        &self,
+        input: DeleteBotVersionRequest,
+    ) -> RusotoFuture<(), DeleteBotVersionError> {
+        (**self).delete_bot_version(input)
+    }
+    fn delete_intent(&self, input: DeleteIntentRequest) -> RusotoFuture<(), DeleteIntentError> {
+        (**self).delete_intent(input)
+    }
+    fn delete_intent_version(
+        &self,
+        input: DeleteIntentVersionRequest,
+    ) -> RusotoFuture<(), DeleteIntentVersionError> {
+        (**self).delete_intent_version(input)
+    }
+    fn delete_slot_type(
+        &self,
+        input: DeleteSlotTypeRequest,
+    ) -> RusotoFuture<(), DeleteSlotTypeError> {
+        (**self).delete_slot_type(input)
+    }
+    fn delete_slot_type_version(
+        &self,
+        input: DeleteSlotTypeVersionRequest,
+    ) -> RusotoFuture<(), DeleteSlotTypeVersionError> {
+        (**self).delete_slot_type_version(input)
+    }
+    fn delete_utterances(
+        &self,
+        input: DeleteUtterancesRequest,
+    ) -> RusotoFuture<(), DeleteUtterancesError> {
+        (**self).delete_utterances(input)
+    }
+    fn get_bot(&self, input: GetBotRequest) -> RusotoFuture<GetBotResponse, GetBotError> {
+        (**self).get_bot(input)
+    }
+    fn get_bot_alias(
+        &self,
+        input: GetBotAliasRequest,
+    ) -> RusotoFuture<GetBotAliasResponse, GetBotAliasError> {
+        (**self).get_bot_alias(input)
+    }
+    fn get_bot_aliases(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> RusotoFuture<GetBotAliasesResponse, GetBotAliasesError> {
+        (**self).get_bot_aliases(input)
+    }
+    fn get_bot_channel_association(
+        &self,
+        input: GetBotChannelAssociationRequest,
+    ) -> RusotoFuture<GetBotChannelAssociationResponse, GetBotChannelAssociationError> {
+        (**self).get_bot_channel_association(input)
+    }
+    fn get_bot_channel_associations(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> RusotoFuture<GetBotChannelAssociationsResponse, GetBotChannelAssociationsError> {
+        (**self).get_bot_channel_associations(input)
+    }
+    fn get_bot_versions(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> RusotoFuture<GetBotVersionsResponse, GetBotVersionsError> {
+        (**self).get_bot_versions(input)
+    }
+    fn get_bots(&self, input: GetBotsRequest) -> RusotoFuture<GetBotsResponse, GetBotsError> {
+        (**self).get_bots(input)
+    }
+    fn get_builtin_intent(
+        &self,
+        input: GetBuiltinIntentRequest,
+    ) -> RusotoFuture<GetBuiltinIntentResponse, GetBuiltinIntentError> {
+        (**self).get_builtin_intent(input)
+    }
+    fn get_builtin_intents(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> RusotoFuture<GetBuiltinIntentsResponse, GetBuiltinIntentsError> {
+        (**self).get_builtin_intents(input)
+    }
+    fn get_builtin_slot_types(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> RusotoFuture<GetBuiltinSlotTypesResponse, GetBuiltinSlotTypesError> {
+        (**self).get_builtin_slot_types(input)
+    }
+    fn get_export(
+        &self,
+        input: GetExportRequest,
+    ) -> RusotoFuture<GetExportResponse, GetExportError> {
+        (**self).get_export(input)
+    }
+    fn get_import(
+        &self,
+        input: GetImportRequest,
+    ) -> RusotoFuture<GetImportResponse, GetImportError> {
+        (**self).get_import(input)
+    }
+    fn get_intent(
+        &self,
+        input: GetIntentRequest,
+    ) -> RusotoFuture<GetIntentResponse, GetIntentError> {
+        (**self).get_intent(input)
+    }
+    fn get_intent_versions(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> RusotoFuture<GetIntentVersionsResponse, GetIntentVersionsError> {
+        (**self).get_intent_versions(input)
+    }
+    fn get_intents(
+        &self,
+        input: GetIntentsRequest,
+    ) -> RusotoFuture<GetIntentsResponse, GetIntentsError> {
+        (**self).get_intents(input)
+    }
+    fn get_slot_type(
+        &self,
+        input: GetSlotTypeRequest,
+    ) -> RusotoFuture<GetSlotTypeResponse, GetSlotTypeError> {
+        (**self).get_slot_type(input)
+    }
+    fn get_slot_type_versions(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> RusotoFuture<GetSlotTypeVersionsResponse, GetSlotTypeVersionsError> {
+        (**self).get_slot_type_versions(input)
+    }
+    fn get_slot_types(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> RusotoFuture<GetSlotTypesResponse, GetSlotTypesError> {
+        (**self).get_slot_types(input)
+    }
+    fn get_utterances_view(
+        &self,
+        input: GetUtterancesViewRequest,
+    ) -> RusotoFuture<GetUtterancesViewResponse, GetUtterancesViewError> {
+        (**self).get_utterances_view(input)
+    }
+    fn put_bot(&self, input: PutBotRequest) -> RusotoFuture<PutBotResponse, PutBotError> {
+        (**self).put_bot(input)
+    }
+    fn put_bot_alias(
+        &self,
+        input: PutBotAliasRequest,
+    ) -> RusotoFuture<PutBotAliasResponse, PutBotAliasError> {
+        (**self).put_bot_alias(input)
+    }
+    fn put_intent(
+        &self,
+        input: PutIntentRequest,
+    ) -> RusotoFuture<PutIntentResponse, PutIntentError> {
+        (**self).put_intent(input)
+    }
+    fn put_slot_type(
+        &self,
+        input: PutSlotTypeRequest,
+    ) -> RusotoFuture<PutSlotTypeResponse, PutSlotTypeError> {
+        (**self).put_slot_type(input)
+    }
+    fn start_import(
+        &self,
+        input: StartImportRequest,
+    ) -> RusotoFuture<StartImportResponse, StartImportError> {
+        (**self).start_import(input)
+    }
+}
+/// The error codes Amazon Lex Model Building Service retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Lex Model Building Service API.
 #[derive(Clone)]
 pub struct LexModelsClient {
@@ -4027,6 +7325,27 @@ impl LexModelsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_LEX_MODELS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> LexModelsClient {
+        Self::new(region::Region::from_env_for_service("LEX_MODELS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `models.lex`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> LexModelsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("models.lex", "LEX_MODELS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4045,7 +7364,54 @@ impl LexModelsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> LexModelsClient {
-        LexModelsClient { client, region }
+        LexModelsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "models.lex")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "models.lex",
+            "lex",
+            "LEX_MODELS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `LexModels` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn LexModels + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for LexModelsClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Lex Model Building Service"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "models.lex"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> LexModelsClient {
+        Self::new_with_client(client, region)
     }
 }
 
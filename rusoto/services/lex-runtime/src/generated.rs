@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Represents an option to be shown on the client platform (Facebook, Slack, etc.)</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -35,6 +36,166 @@ pub struct Button {
     pub value: String,
 }
 
+/// <p><p>The status of the intent after the user responds to the confirmation prompt. If the user confirms the intent, Amazon Lex sets this field to <code>Confirmed</code>. If the user denies the intent, Amazon Lex sets this value to <code>Denied</code>. The possible values are:</p> <ul> <li> <p> <code>Confirmed</code> - The user has responded &quot;Yes&quot; to the confirmation prompt, confirming that the intent is complete and that it is ready to be fulfilled.</p> </li> <li> <p> <code>Denied</code> - The user has responded &quot;No&quot; to the confirmation prompt.</p> </li> <li> <p> <code>None</code> - The user has never been prompted for confirmation; or, the user was prompted but did not confirm or deny the prompt.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConfirmationStatus {
+    /// <p><code>None</code> - The user has never been prompted for confirmation; or, the user was prompted but did not confirm or deny the prompt.</p>
+    None,
+    /// <p><code>Confirmed</code> - The user has responded &quot;Yes&quot; to the confirmation prompt, confirming that the intent is complete and that it is ready to be fulfilled.</p>
+    Confirmed,
+    /// <p><code>Denied</code> - The user has responded &quot;No&quot; to the confirmation prompt.</p>
+    Denied,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ConfirmationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ConfirmationStatus::None => "None",
+            ConfirmationStatus::Confirmed => "Confirmed",
+            ConfirmationStatus::Denied => "Denied",
+            ConfirmationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ConfirmationStatus {
+    fn from(value: &'a str) -> ConfirmationStatus {
+        match value {
+            "None" => ConfirmationStatus::None,
+            "Confirmed" => ConfirmationStatus::Confirmed,
+            "Denied" => ConfirmationStatus::Denied,
+            _ => ConfirmationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ConfirmationStatus {
+    fn from(value: String) -> ConfirmationStatus {
+        ConfirmationStatus::from(value.as_str())
+    }
+}
+
+impl From<ConfirmationStatus> for String {
+    fn from(value: ConfirmationStatus) -> String {
+        match value {
+            ConfirmationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ConfirmationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ConfirmationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ConfirmationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ConfirmationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ConfirmationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ConfirmationStatus::from(value))
+    }
+}
+
+/// <p>The content type of the response.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContentType {
+    /// <p><code>application/vnd.amazonaws.card.generic</code></p>
+    ApplicationVndAmazonawsCardGeneric,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentType::ApplicationVndAmazonawsCardGeneric => {
+                "application/vnd.amazonaws.card.generic"
+            }
+            ContentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContentType {
+    fn from(value: &'a str) -> ContentType {
+        match value {
+            "application/vnd.amazonaws.card.generic" => {
+                ContentType::ApplicationVndAmazonawsCardGeneric
+            }
+            _ => ContentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentType {
+    fn from(value: String) -> ContentType {
+        ContentType::from(value.as_str())
+    }
+}
+
+impl From<ContentType> for String {
+    fn from(value: ContentType) -> String {
+        match value {
+            ContentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ContentType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ContentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContentType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteSessionRequest {
     /// <p>The alias in use for the bot that contains the session data.</p>
@@ -48,6 +209,56 @@ pub struct DeleteSessionRequest {
     pub user_id: String,
 }
 
+impl DeleteSessionRequest {
+    /// Returns a builder for `DeleteSessionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSessionRequestBuilder {
+        DeleteSessionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSessionRequest`](struct.DeleteSessionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSessionRequestBuilder {
+    bot_alias: Option<String>,
+    bot_name: Option<String>,
+    user_id: Option<String>,
+}
+
+impl DeleteSessionRequestBuilder {
+    /// Sets the [`bot_alias`](struct.DeleteSessionRequest.html#structfield.bot_alias) member.
+    pub fn bot_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_name`](struct.DeleteSessionRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.DeleteSessionRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSessionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteSessionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSessionRequest {
+            bot_alias: self
+                .bot_alias
+                .ok_or(::rusoto_core::BuildError::missing("bot_alias"))?,
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteSessionResponse {
@@ -70,12 +281,12 @@ pub struct DeleteSessionResponse {
 }
 
 /// <p>Describes the next action that the bot should take in its interaction with the user and provides information about the context in which the action takes place. Use the <code>DialogAction</code> data type to set the interaction to a specific state, or to return the interaction to a previous state.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DialogAction {
     /// <p><p>The fulfillment state of the intent. The possible values are:</p> <ul> <li> <p> <code>Failed</code> - The Lambda function associated with the intent failed to fulfill the intent.</p> </li> <li> <p> <code>Fulfilled</code> - The intent has fulfilled by the Lambda function associated with the intent. </p> </li> <li> <p> <code>ReadyForFulfillment</code> - All of the information necessary for the intent is present and the intent ready to be fulfilled by the client application.</p> </li> </ul></p>
     #[serde(rename = "fulfillmentState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fulfillment_state: Option<String>,
+    pub fulfillment_state: Option<FulfillmentState>,
     /// <p>The name of the intent.</p>
     #[serde(rename = "intentName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -87,7 +298,7 @@ pub struct DialogAction {
     /// <ul> <li> <p> <code>PlainText</code> - The message contains plain UTF-8 text.</p> </li> <li> <p> <code>CustomPayload</code> - The message is a custom format for the client.</p> </li> <li> <p> <code>SSML</code> - The message contains text formatted for voice output.</p> </li> <li> <p> <code>Composite</code> - The message contains an escaped JSON object containing one or more messages. For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/howitworks-manage-prompts.html">Message Groups</a>. </p> </li> </ul>
     #[serde(rename = "messageFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_format: Option<String>,
+    pub message_format: Option<MessageFormatType>,
     /// <p>The name of the slot that should be elicited from the user.</p>
     #[serde(rename = "slotToElicit")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -98,7 +309,273 @@ pub struct DialogAction {
     pub slots: Option<::std::collections::HashMap<String, String>>,
     /// <p><p>The next action that the bot should take in its interaction with the user. The possible values are:</p> <ul> <li> <p> <code>ConfirmIntent</code> - The next action is asking the user if the intent is complete and ready to be fulfilled. This is a yes/no question such as &quot;Place the order?&quot;</p> </li> <li> <p> <code>Close</code> - Indicates that the there will not be a response from the user. For example, the statement &quot;Your order has been placed&quot; does not require a response.</p> </li> <li> <p> <code>Delegate</code> - The next action is determined by Amazon Lex.</p> </li> <li> <p> <code>ElicitIntent</code> - The next action is to determine the intent that the user wants to fulfill.</p> </li> <li> <p> <code>ElicitSlot</code> - The next action is to elicit a slot value from the user.</p> </li> </ul></p>
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: DialogActionType,
+}
+
+/// <p><p>The next action that the bot should take in its interaction with the user. The possible values are:</p> <ul> <li> <p> <code>ConfirmIntent</code> - The next action is asking the user if the intent is complete and ready to be fulfilled. This is a yes/no question such as &quot;Place the order?&quot;</p> </li> <li> <p> <code>Close</code> - Indicates that the there will not be a response from the user. For example, the statement &quot;Your order has been placed&quot; does not require a response.</p> </li> <li> <p> <code>Delegate</code> - The next action is determined by Amazon Lex.</p> </li> <li> <p> <code>ElicitIntent</code> - The next action is to determine the intent that the user wants to fulfill.</p> </li> <li> <p> <code>ElicitSlot</code> - The next action is to elicit a slot value from the user.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DialogActionType {
+    /// <p><code>ElicitIntent</code> - The next action is to determine the intent that the user wants to fulfill.</p>
+    ElicitIntent,
+    /// <p><code>ConfirmIntent</code> - The next action is asking the user if the intent is complete and ready to be fulfilled. This is a yes/no question such as &quot;Place the order?&quot;</p>
+    ConfirmIntent,
+    /// <p><code>ElicitSlot</code> - The next action is to elicit a slot value from the user.</p>
+    ElicitSlot,
+    /// <p><code>Close</code> - Indicates that the there will not be a response from the user. For example, the statement &quot;Your order has been placed&quot; does not require a response.</p>
+    Close,
+    /// <p><code>Delegate</code> - The next action is determined by Amazon Lex.</p>
+    Delegate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DialogActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DialogActionType::ElicitIntent => "ElicitIntent",
+            DialogActionType::ConfirmIntent => "ConfirmIntent",
+            DialogActionType::ElicitSlot => "ElicitSlot",
+            DialogActionType::Close => "Close",
+            DialogActionType::Delegate => "Delegate",
+            DialogActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DialogActionType {
+    fn from(value: &'a str) -> DialogActionType {
+        match value {
+            "ElicitIntent" => DialogActionType::ElicitIntent,
+            "ConfirmIntent" => DialogActionType::ConfirmIntent,
+            "ElicitSlot" => DialogActionType::ElicitSlot,
+            "Close" => DialogActionType::Close,
+            "Delegate" => DialogActionType::Delegate,
+            _ => DialogActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DialogActionType {
+    fn from(value: String) -> DialogActionType {
+        DialogActionType::from(value.as_str())
+    }
+}
+
+impl From<DialogActionType> for String {
+    fn from(value: DialogActionType) -> String {
+        match value {
+            DialogActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DialogActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DialogActionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DialogActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DialogActionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DialogActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DialogActionType::from(value))
+    }
+}
+
+/// <p><p>Identifies the current state of the user interaction. Amazon Lex returns one of the following values as <code>dialogState</code>. The client can optionally use this information to customize the user interface. </p> <ul> <li> <p> <code>ElicitIntent</code> - Amazon Lex wants to elicit the user&#39;s intent. Consider the following examples: </p> <p> For example, a user might utter an intent (&quot;I want to order a pizza&quot;). If Amazon Lex cannot infer the user intent from this utterance, it will return this dialog state. </p> </li> <li> <p> <code>ConfirmIntent</code> - Amazon Lex is expecting a &quot;yes&quot; or &quot;no&quot; response. </p> <p>For example, Amazon Lex wants user confirmation before fulfilling an intent. Instead of a simple &quot;yes&quot; or &quot;no&quot; response, a user might respond with additional information. For example, &quot;yes, but make it a thick crust pizza&quot; or &quot;no, I want to order a drink.&quot; Amazon Lex can process such additional information (in these examples, update the crust type slot or change the intent from OrderPizza to OrderDrink). </p> </li> <li> <p> <code>ElicitSlot</code> - Amazon Lex is expecting the value of a slot for the current intent. </p> <p> For example, suppose that in the response Amazon Lex sends this message: &quot;What size pizza would you like?&quot;. A user might reply with the slot value (e.g., &quot;medium&quot;). The user might also provide additional information in the response (e.g., &quot;medium thick crust pizza&quot;). Amazon Lex can process such additional information appropriately. </p> </li> <li> <p> <code>Fulfilled</code> - Conveys that the Lambda function has successfully fulfilled the intent. </p> </li> <li> <p> <code>ReadyForFulfillment</code> - Conveys that the client has to fulfill the request. </p> </li> <li> <p> <code>Failed</code> - Conveys that the conversation with the user failed. </p> <p> This can happen for various reasons, including that the user does not provide an appropriate response to prompts from the service (you can configure how many times Amazon Lex can prompt a user for specific information), or if the Lambda function fails to fulfill the intent. </p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DialogState {
+    /// <p><code>ElicitIntent</code> - Amazon Lex wants to elicit the user&#39;s intent. Consider the following examples:</p>
+    ElicitIntent,
+    /// <p><code>ConfirmIntent</code> - Amazon Lex is expecting a &quot;yes&quot; or &quot;no&quot; response.</p>
+    ConfirmIntent,
+    /// <p><code>ElicitSlot</code> - Amazon Lex is expecting the value of a slot for the current intent.</p>
+    ElicitSlot,
+    /// <p><code>Fulfilled</code> - Conveys that the Lambda function has successfully fulfilled the intent.</p>
+    Fulfilled,
+    /// <p><code>ReadyForFulfillment</code> - Conveys that the client has to fulfill the request.</p>
+    ReadyForFulfillment,
+    /// <p><code>Failed</code> - Conveys that the conversation with the user failed.</p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DialogState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DialogState::ElicitIntent => "ElicitIntent",
+            DialogState::ConfirmIntent => "ConfirmIntent",
+            DialogState::ElicitSlot => "ElicitSlot",
+            DialogState::Fulfilled => "Fulfilled",
+            DialogState::ReadyForFulfillment => "ReadyForFulfillment",
+            DialogState::Failed => "Failed",
+            DialogState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DialogState {
+    fn from(value: &'a str) -> DialogState {
+        match value {
+            "ElicitIntent" => DialogState::ElicitIntent,
+            "ConfirmIntent" => DialogState::ConfirmIntent,
+            "ElicitSlot" => DialogState::ElicitSlot,
+            "Fulfilled" => DialogState::Fulfilled,
+            "ReadyForFulfillment" => DialogState::ReadyForFulfillment,
+            "Failed" => DialogState::Failed,
+            _ => DialogState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DialogState {
+    fn from(value: String) -> DialogState {
+        DialogState::from(value.as_str())
+    }
+}
+
+impl From<DialogState> for String {
+    fn from(value: DialogState) -> String {
+        match value {
+            DialogState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DialogState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DialogState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DialogState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DialogState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DialogState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DialogState::from(value))
+    }
+}
+
+/// <p><p>The fulfillment state of the intent. The possible values are:</p> <ul> <li> <p> <code>Failed</code> - The Lambda function associated with the intent failed to fulfill the intent.</p> </li> <li> <p> <code>Fulfilled</code> - The intent has fulfilled by the Lambda function associated with the intent. </p> </li> <li> <p> <code>ReadyForFulfillment</code> - All of the information necessary for the intent is present and the intent ready to be fulfilled by the client application.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FulfillmentState {
+    /// <p><code>Fulfilled</code> - The intent has fulfilled by the Lambda function associated with the intent.</p>
+    Fulfilled,
+    /// <p><code>Failed</code> - The Lambda function associated with the intent failed to fulfill the intent.</p>
+    Failed,
+    /// <p><code>ReadyForFulfillment</code> - All of the information necessary for the intent is present and the intent ready to be fulfilled by the client application.</p>
+    ReadyForFulfillment,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FulfillmentState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FulfillmentState::Fulfilled => "Fulfilled",
+            FulfillmentState::Failed => "Failed",
+            FulfillmentState::ReadyForFulfillment => "ReadyForFulfillment",
+            FulfillmentState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FulfillmentState {
+    fn from(value: &'a str) -> FulfillmentState {
+        match value {
+            "Fulfilled" => FulfillmentState::Fulfilled,
+            "Failed" => FulfillmentState::Failed,
+            "ReadyForFulfillment" => FulfillmentState::ReadyForFulfillment,
+            _ => FulfillmentState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FulfillmentState {
+    fn from(value: String) -> FulfillmentState {
+        FulfillmentState::from(value.as_str())
+    }
+}
+
+impl From<FulfillmentState> for String {
+    fn from(value: FulfillmentState) -> String {
+        match value {
+            FulfillmentState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FulfillmentState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for FulfillmentState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for FulfillmentState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FulfillmentState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FulfillmentState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FulfillmentState::from(value))
+    }
 }
 
 /// <p>Represents an option rendered to the user when a prompt is shown. It could be an image, a button, a link, or text. </p>
@@ -140,6 +617,56 @@ pub struct GetSessionRequest {
     pub user_id: String,
 }
 
+impl GetSessionRequest {
+    /// Returns a builder for `GetSessionRequest`, checking its required members when built.
+    pub fn builder() -> GetSessionRequestBuilder {
+        GetSessionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetSessionRequest`](struct.GetSessionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetSessionRequestBuilder {
+    bot_alias: Option<String>,
+    bot_name: Option<String>,
+    user_id: Option<String>,
+}
+
+impl GetSessionRequestBuilder {
+    /// Sets the [`bot_alias`](struct.GetSessionRequest.html#structfield.bot_alias) member.
+    pub fn bot_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_name`](struct.GetSessionRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.GetSessionRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSessionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetSessionRequest, ::rusoto_core::BuildError> {
+        Ok(GetSessionRequest {
+            bot_alias: self
+                .bot_alias
+                .ok_or(::rusoto_core::BuildError::missing("bot_alias"))?,
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSessionResponse {
@@ -162,20 +689,20 @@ pub struct GetSessionResponse {
 }
 
 /// <p>Provides information about the state of an intent. You can use this information to get the current state of an intent so that you can process the intent, or so that you can return the intent to its previous state.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct IntentSummary {
     /// <p><p>The status of the intent after the user responds to the confirmation prompt. If the user confirms the intent, Amazon Lex sets this field to <code>Confirmed</code>. If the user denies the intent, Amazon Lex sets this value to <code>Denied</code>. The possible values are:</p> <ul> <li> <p> <code>Confirmed</code> - The user has responded &quot;Yes&quot; to the confirmation prompt, confirming that the intent is complete and that it is ready to be fulfilled.</p> </li> <li> <p> <code>Denied</code> - The user has responded &quot;No&quot; to the confirmation prompt.</p> </li> <li> <p> <code>None</code> - The user has never been prompted for confirmation; or, the user was prompted but did not confirm or deny the prompt.</p> </li> </ul></p>
     #[serde(rename = "confirmationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub confirmation_status: Option<String>,
+    pub confirmation_status: Option<ConfirmationStatus>,
     /// <p><p>The next action that the bot should take in its interaction with the user. The possible values are:</p> <ul> <li> <p> <code>ConfirmIntent</code> - The next action is asking the user if the intent is complete and ready to be fulfilled. This is a yes/no question such as &quot;Place the order?&quot;</p> </li> <li> <p> <code>Close</code> - Indicates that the there will not be a response from the user. For example, the statement &quot;Your order has been placed&quot; does not require a response.</p> </li> <li> <p> <code>ElicitIntent</code> - The next action is to determine the intent that the user wants to fulfill.</p> </li> <li> <p> <code>ElicitSlot</code> - The next action is to elicit a slot value from the user.</p> </li> </ul></p>
     #[serde(rename = "dialogActionType")]
-    pub dialog_action_type: String,
+    pub dialog_action_type: DialogActionType,
     /// <p><p>The fulfillment state of the intent. The possible values are:</p> <ul> <li> <p> <code>Failed</code> - The Lambda function associated with the intent failed to fulfill the intent.</p> </li> <li> <p> <code>Fulfilled</code> - The intent has fulfilled by the Lambda function associated with the intent. </p> </li> <li> <p> <code>ReadyForFulfillment</code> - All of the information necessary for the intent is present and the intent ready to be fulfilled by the client application.</p> </li> </ul></p>
     #[serde(rename = "fulfillmentState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fulfillment_state: Option<String>,
+    pub fulfillment_state: Option<FulfillmentState>,
     /// <p>The name of the intent.</p>
     #[serde(rename = "intentName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -190,6 +717,92 @@ pub struct IntentSummary {
     pub slots: Option<::std::collections::HashMap<String, String>>,
 }
 
+/// <ul> <li> <p> <code>PlainText</code> - The message contains plain UTF-8 text.</p> </li> <li> <p> <code>CustomPayload</code> - The message is a custom format for the client.</p> </li> <li> <p> <code>SSML</code> - The message contains text formatted for voice output.</p> </li> <li> <p> <code>Composite</code> - The message contains an escaped JSON object containing one or more messages. For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/howitworks-manage-prompts.html">Message Groups</a>. </p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MessageFormatType {
+    /// <p><code>PlainText</code> - The message contains plain UTF-8 text.</p>
+    PlainText,
+    /// <p><code>CustomPayload</code> - The message is a custom format for the client.</p>
+    CustomPayload,
+    /// <p><code>SSML</code> - The message contains text formatted for voice output.</p>
+    Ssml,
+    /// <p><code>Composite</code> - The message contains an escaped JSON object containing one or more messages. For more information, see <a href="https://docs.aws.amazon.com/lex/latest/dg/howitworks-manage-prompts.html">Message Groups</a>.</p>
+    Composite,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MessageFormatType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MessageFormatType::PlainText => "PlainText",
+            MessageFormatType::CustomPayload => "CustomPayload",
+            MessageFormatType::Ssml => "SSML",
+            MessageFormatType::Composite => "Composite",
+            MessageFormatType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MessageFormatType {
+    fn from(value: &'a str) -> MessageFormatType {
+        match value {
+            "PlainText" => MessageFormatType::PlainText,
+            "CustomPayload" => MessageFormatType::CustomPayload,
+            "SSML" => MessageFormatType::Ssml,
+            "Composite" => MessageFormatType::Composite,
+            _ => MessageFormatType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MessageFormatType {
+    fn from(value: String) -> MessageFormatType {
+        MessageFormatType::from(value.as_str())
+    }
+}
+
+impl From<MessageFormatType> for String {
+    fn from(value: MessageFormatType) -> String {
+        match value {
+            MessageFormatType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MessageFormatType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for MessageFormatType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for MessageFormatType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MessageFormatType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MessageFormatType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MessageFormatType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PostContentRequest {
     /// <p><p> You pass this value as the <code>Accept</code> HTTP header. </p> <p> The message Amazon Lex returns in the response can be either text or speech based on the <code>Accept</code> HTTP header value in the request. </p> <ul> <li> <p> If the value is <code>text/plain; charset=utf-8</code>, Amazon Lex returns text in the response. </p> </li> <li> <p> If the value begins with <code>audio/</code>, Amazon Lex returns speech in the response. Amazon Lex uses Amazon Polly to generate the speech (using the configuration you specified in the <code>Accept</code> header). For example, if you specify <code>audio/mpeg</code> as the value, Amazon Lex returns speech in the MPEG format.</p> </li> <li> <p>If the value is <code>audio/pcm</code>, the speech returned is <code>audio/pcm</code> in 16-bit, little endian format. </p> </li> <li> <p>The following are the accepted values:</p> <ul> <li> <p>audio/mpeg</p> </li> <li> <p>audio/ogg</p> </li> <li> <p>audio/pcm</p> </li> <li> <p>text/plain; charset=utf-8</p> </li> <li> <p>audio/* (defaults to mpeg)</p> </li> </ul> </li> </ul></p>
@@ -226,6 +839,100 @@ pub struct PostContentRequest {
     pub user_id: String,
 }
 
+impl PostContentRequest {
+    /// Returns a builder for `PostContentRequest`, checking its required members when built.
+    pub fn builder() -> PostContentRequestBuilder {
+        PostContentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PostContentRequest`](struct.PostContentRequest.html).
+#[derive(Default, Debug)]
+pub struct PostContentRequestBuilder {
+    accept: Option<String>,
+    bot_alias: Option<String>,
+    bot_name: Option<String>,
+    content_type: Option<String>,
+    input_stream: Option<bytes::Bytes>,
+    request_attributes: Option<String>,
+    session_attributes: Option<String>,
+    user_id: Option<String>,
+}
+
+impl PostContentRequestBuilder {
+    /// Sets the [`accept`](struct.PostContentRequest.html#structfield.accept) member.
+    pub fn accept<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_alias`](struct.PostContentRequest.html#structfield.bot_alias) member.
+    pub fn bot_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_name`](struct.PostContentRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`content_type`](struct.PostContentRequest.html#structfield.content_type) member.
+    pub fn content_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.content_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_stream`](struct.PostContentRequest.html#structfield.input_stream) member.
+    pub fn input_stream<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.input_stream = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_attributes`](struct.PostContentRequest.html#structfield.request_attributes) member.
+    pub fn request_attributes<T: Into<String>>(mut self, value: T) -> Self {
+        self.request_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`session_attributes`](struct.PostContentRequest.html#structfield.session_attributes) member.
+    pub fn session_attributes<T: Into<String>>(mut self, value: T) -> Self {
+        self.session_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.PostContentRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PostContentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PostContentRequest, ::rusoto_core::BuildError> {
+        Ok(PostContentRequest {
+            accept: self.accept,
+            bot_alias: self
+                .bot_alias
+                .ok_or(::rusoto_core::BuildError::missing("bot_alias"))?,
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            content_type: self
+                .content_type
+                .ok_or(::rusoto_core::BuildError::missing("content_type"))?,
+            input_stream: self
+                .input_stream
+                .ok_or(::rusoto_core::BuildError::missing("input_stream"))?,
+            request_attributes: self.request_attributes,
+            session_attributes: self.session_attributes,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PostContentResponse {
     /// <p>The prompt (or statement) to convey to the user. This is based on the bot configuration and context. For example, if Amazon Lex did not understand the user intent, it sends the <code>clarificationPrompt</code> configured for the bot. If the intent requires confirmation before taking the fulfillment action, it sends the <code>confirmationPrompt</code>. Another example: Suppose that the Lambda function successfully fulfilled the intent, and sent a message to convey to the user. Then Amazon Lex sends that message in the response. </p>
@@ -233,7 +940,7 @@ pub struct PostContentResponse {
     /// <p>Content type as specified in the <code>Accept</code> HTTP header in the request.</p>
     pub content_type: Option<String>,
     /// <p><p>Identifies the current state of the user interaction. Amazon Lex returns one of the following values as <code>dialogState</code>. The client can optionally use this information to customize the user interface. </p> <ul> <li> <p> <code>ElicitIntent</code> - Amazon Lex wants to elicit the user&#39;s intent. Consider the following examples: </p> <p> For example, a user might utter an intent (&quot;I want to order a pizza&quot;). If Amazon Lex cannot infer the user intent from this utterance, it will return this dialog state. </p> </li> <li> <p> <code>ConfirmIntent</code> - Amazon Lex is expecting a &quot;yes&quot; or &quot;no&quot; response. </p> <p>For example, Amazon Lex wants user confirmation before fulfilling an intent. Instead of a simple &quot;yes&quot; or &quot;no&quot; response, a user might respond with additional information. For example, &quot;yes, but make it a thick crust pizza&quot; or &quot;no, I want to order a drink.&quot; Amazon Lex can process such additional information (in these examples, update the crust type slot or change the intent from OrderPizza to OrderDrink). </p> </li> <li> <p> <code>ElicitSlot</code> - Amazon Lex is expecting the value of a slot for the current intent. </p> <p> For example, suppose that in the response Amazon Lex sends this message: &quot;What size pizza would you like?&quot;. A user might reply with the slot value (e.g., &quot;medium&quot;). The user might also provide additional information in the response (e.g., &quot;medium thick crust pizza&quot;). Amazon Lex can process such additional information appropriately. </p> </li> <li> <p> <code>Fulfilled</code> - Conveys that the Lambda function has successfully fulfilled the intent. </p> </li> <li> <p> <code>ReadyForFulfillment</code> - Conveys that the client has to fulfill the request. </p> </li> <li> <p> <code>Failed</code> - Conveys that the conversation with the user failed. </p> <p> This can happen for various reasons, including that the user does not provide an appropriate response to prompts from the service (you can configure how many times Amazon Lex can prompt a user for specific information), or if the Lambda function fails to fulfill the intent. </p> </li> </ul></p>
-    pub dialog_state: Option<String>,
+    pub dialog_state: Option<DialogState>,
     /// <p>The text used to process the request.</p> <p>If the input was an audio stream, the <code>inputTranscript</code> field contains the text extracted from the audio stream. This is the text that is actually processed to recognize intents and slot values. You can use this information to determine if Amazon Lex is correctly processing the audio that you send.</p>
     pub input_transcript: Option<String>,
     /// <p>Current user intent that Amazon Lex is aware of.</p>
@@ -241,7 +948,7 @@ pub struct PostContentResponse {
     /// <p>The message to convey to the user. The message can come from the bot's configuration or from a Lambda function.</p> <p>If the intent is not configured with a Lambda function, or if the Lambda function returned <code>Delegate</code> as the <code>dialogAction.type</code> in its response, Amazon Lex decides on the next course of action and selects an appropriate message from the bot's configuration based on the current interaction context. For example, if Amazon Lex isn't able to understand user input, it uses a clarification prompt message.</p> <p>When you create an intent you can assign messages to groups. When messages are assigned to groups Amazon Lex returns one message from each group in the response. The message field is an escaped JSON string containing the messages. For more information about the structure of the JSON string returned, see <a>msg-prompts-formats</a>.</p> <p>If the Lambda function returns a message, Amazon Lex passes it to the client in its response.</p>
     pub message: Option<String>,
     /// <p><p>The format of the response message. One of the following values:</p> <ul> <li> <p> <code>PlainText</code> - The message contains plain UTF-8 text.</p> </li> <li> <p> <code>CustomPayload</code> - The message is a custom format for the client.</p> </li> <li> <p> <code>SSML</code> - The message contains text formatted for voice output.</p> </li> <li> <p> <code>Composite</code> - The message contains an escaped JSON object containing one or more messages from the groups that messages were assigned to when the intent was created.</p> </li> </ul></p>
-    pub message_format: Option<String>,
+    pub message_format: Option<MessageFormatType>,
     /// <p> Map of key/value pairs representing the session-specific context information. </p>
     pub session_attributes: Option<String>,
     /// <p> If the <code>dialogState</code> value is <code>ElicitSlot</code>, returns the name of the slot for which Amazon Lex is eliciting a value. </p>
@@ -274,13 +981,95 @@ pub struct PostTextRequest {
     pub user_id: String,
 }
 
+impl PostTextRequest {
+    /// Returns a builder for `PostTextRequest`, checking its required members when built.
+    pub fn builder() -> PostTextRequestBuilder {
+        PostTextRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PostTextRequest`](struct.PostTextRequest.html).
+#[derive(Default, Debug)]
+pub struct PostTextRequestBuilder {
+    bot_alias: Option<String>,
+    bot_name: Option<String>,
+    input_text: Option<String>,
+    request_attributes: Option<::std::collections::HashMap<String, String>>,
+    session_attributes: Option<::std::collections::HashMap<String, String>>,
+    user_id: Option<String>,
+}
+
+impl PostTextRequestBuilder {
+    /// Sets the [`bot_alias`](struct.PostTextRequest.html#structfield.bot_alias) member.
+    pub fn bot_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_name`](struct.PostTextRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_text`](struct.PostTextRequest.html#structfield.input_text) member.
+    pub fn input_text<T: Into<String>>(mut self, value: T) -> Self {
+        self.input_text = Some(value.into());
+        self
+    }
+
+    /// Sets the [`request_attributes`](struct.PostTextRequest.html#structfield.request_attributes) member.
+    pub fn request_attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.request_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`session_attributes`](struct.PostTextRequest.html#structfield.session_attributes) member.
+    pub fn session_attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.session_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.PostTextRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PostTextRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PostTextRequest, ::rusoto_core::BuildError> {
+        Ok(PostTextRequest {
+            bot_alias: self
+                .bot_alias
+                .ok_or(::rusoto_core::BuildError::missing("bot_alias"))?,
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            input_text: self
+                .input_text
+                .ok_or(::rusoto_core::BuildError::missing("input_text"))?,
+            request_attributes: self.request_attributes,
+            session_attributes: self.session_attributes,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PostTextResponse {
     /// <p><p> Identifies the current state of the user interaction. Amazon Lex returns one of the following values as <code>dialogState</code>. The client can optionally use this information to customize the user interface. </p> <ul> <li> <p> <code>ElicitIntent</code> - Amazon Lex wants to elicit user intent. </p> <p>For example, a user might utter an intent (&quot;I want to order a pizza&quot;). If Amazon Lex cannot infer the user intent from this utterance, it will return this dialogState.</p> </li> <li> <p> <code>ConfirmIntent</code> - Amazon Lex is expecting a &quot;yes&quot; or &quot;no&quot; response. </p> <p> For example, Amazon Lex wants user confirmation before fulfilling an intent. </p> <p>Instead of a simple &quot;yes&quot; or &quot;no,&quot; a user might respond with additional information. For example, &quot;yes, but make it thick crust pizza&quot; or &quot;no, I want to order a drink&quot;. Amazon Lex can process such additional information (in these examples, update the crust type slot value, or change intent from OrderPizza to OrderDrink).</p> </li> <li> <p> <code>ElicitSlot</code> - Amazon Lex is expecting a slot value for the current intent. </p> <p>For example, suppose that in the response Amazon Lex sends this message: &quot;What size pizza would you like?&quot;. A user might reply with the slot value (e.g., &quot;medium&quot;). The user might also provide additional information in the response (e.g., &quot;medium thick crust pizza&quot;). Amazon Lex can process such additional information appropriately. </p> </li> <li> <p> <code>Fulfilled</code> - Conveys that the Lambda function configured for the intent has successfully fulfilled the intent. </p> </li> <li> <p> <code>ReadyForFulfillment</code> - Conveys that the client has to fulfill the intent. </p> </li> <li> <p> <code>Failed</code> - Conveys that the conversation with the user failed. </p> <p> This can happen for various reasons including that the user did not provide an appropriate response to prompts from the service (you can configure how many times Amazon Lex can prompt a user for specific information), or the Lambda function failed to fulfill the intent. </p> </li> </ul></p>
     #[serde(rename = "dialogState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dialog_state: Option<String>,
+    pub dialog_state: Option<DialogState>,
     /// <p>The current user intent that Amazon Lex is aware of.</p>
     #[serde(rename = "intentName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -292,7 +1081,7 @@ pub struct PostTextResponse {
     /// <p><p>The format of the response message. One of the following values:</p> <ul> <li> <p> <code>PlainText</code> - The message contains plain UTF-8 text.</p> </li> <li> <p> <code>CustomPayload</code> - The message is a custom format defined by the Lambda function.</p> </li> <li> <p> <code>SSML</code> - The message contains text formatted for voice output.</p> </li> <li> <p> <code>Composite</code> - The message contains an escaped JSON object containing one or more messages from the groups that messages were assigned to when the intent was created.</p> </li> </ul></p>
     #[serde(rename = "messageFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message_format: Option<String>,
+    pub message_format: Option<MessageFormatType>,
     /// <p>Represents the options that the user has to respond to the current prompt. Response Card can come from the bot configuration (in the Amazon Lex console, choose the settings button next to a slot) or from a code hook (Lambda function). </p>
     #[serde(rename = "responseCard")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -336,6 +1125,83 @@ pub struct PutSessionRequest {
     pub user_id: String,
 }
 
+impl PutSessionRequest {
+    /// Returns a builder for `PutSessionRequest`, checking its required members when built.
+    pub fn builder() -> PutSessionRequestBuilder {
+        PutSessionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutSessionRequest`](struct.PutSessionRequest.html).
+#[derive(Default, Debug)]
+pub struct PutSessionRequestBuilder {
+    accept: Option<String>,
+    bot_alias: Option<String>,
+    bot_name: Option<String>,
+    dialog_action: Option<DialogAction>,
+    session_attributes: Option<::std::collections::HashMap<String, String>>,
+    user_id: Option<String>,
+}
+
+impl PutSessionRequestBuilder {
+    /// Sets the [`accept`](struct.PutSessionRequest.html#structfield.accept) member.
+    pub fn accept<T: Into<String>>(mut self, value: T) -> Self {
+        self.accept = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_alias`](struct.PutSessionRequest.html#structfield.bot_alias) member.
+    pub fn bot_alias<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_alias = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bot_name`](struct.PutSessionRequest.html#structfield.bot_name) member.
+    pub fn bot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dialog_action`](struct.PutSessionRequest.html#structfield.dialog_action) member.
+    pub fn dialog_action<T: Into<DialogAction>>(mut self, value: T) -> Self {
+        self.dialog_action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`session_attributes`](struct.PutSessionRequest.html#structfield.session_attributes) member.
+    pub fn session_attributes<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.session_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_id`](struct.PutSessionRequest.html#structfield.user_id) member.
+    pub fn user_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutSessionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutSessionRequest, ::rusoto_core::BuildError> {
+        Ok(PutSessionRequest {
+            accept: self.accept,
+            bot_alias: self
+                .bot_alias
+                .ok_or(::rusoto_core::BuildError::missing("bot_alias"))?,
+            bot_name: self
+                .bot_name
+                .ok_or(::rusoto_core::BuildError::missing("bot_name"))?,
+            dialog_action: self.dialog_action,
+            session_attributes: self.session_attributes,
+            user_id: self
+                .user_id
+                .ok_or(::rusoto_core::BuildError::missing("user_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct PutSessionResponse {
     /// <p>The audio version of the message to convey to the user.</p>
@@ -343,13 +1209,13 @@ pub struct PutSessionResponse {
     /// <p>Content type as specified in the <code>Accept</code> HTTP header in the request.</p>
     pub content_type: Option<String>,
     /// <p><p/> <ul> <li> <p> <code>ConfirmIntent</code> - Amazon Lex is expecting a &quot;yes&quot; or &quot;no&quot; response to confirm the intent before fulfilling an intent.</p> </li> <li> <p> <code>ElicitIntent</code> - Amazon Lex wants to elicit the user&#39;s intent.</p> </li> <li> <p> <code>ElicitSlot</code> - Amazon Lex is expecting the value of a slot for the current intent.</p> </li> <li> <p> <code>Failed</code> - Conveys that the conversation with the user has failed. This can happen for various reasons, including the user does not provide an appropriate response to prompts from the service, or if the Lambda function fails to fulfill the intent.</p> </li> <li> <p> <code>Fulfilled</code> - Conveys that the Lambda function has sucessfully fulfilled the intent.</p> </li> <li> <p> <code>ReadyForFulfillment</code> - Conveys that the client has to fulfill the intent.</p> </li> </ul></p>
-    pub dialog_state: Option<String>,
+    pub dialog_state: Option<DialogState>,
     /// <p>The name of the current intent.</p>
     pub intent_name: Option<String>,
     /// <p>The next message that should be presented to the user.</p>
     pub message: Option<String>,
     /// <p><p>The format of the response message. One of the following values:</p> <ul> <li> <p> <code>PlainText</code> - The message contains plain UTF-8 text.</p> </li> <li> <p> <code>CustomPayload</code> - The message is a custom format for the client.</p> </li> <li> <p> <code>SSML</code> - The message contains text formatted for voice output.</p> </li> <li> <p> <code>Composite</code> - The message contains an escaped JSON object containing one or more messages from the groups that messages were assigned to when the intent was created.</p> </li> </ul></p>
-    pub message_format: Option<String>,
+    pub message_format: Option<MessageFormatType>,
     /// <p>Map of key/value pairs representing session-specific context information.</p>
     pub session_attributes: Option<String>,
     /// <p>A unique identifier for the session.</p>
@@ -367,7 +1233,7 @@ pub struct ResponseCard {
     /// <p>The content type of the response.</p>
     #[serde(rename = "contentType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content_type: Option<String>,
+    pub content_type: Option<ContentType>,
     /// <p>An array of attachment objects representing options.</p>
     #[serde(rename = "genericAttachments")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -758,6 +1624,42 @@ pub trait LexRuntime {
         input: PutSessionRequest,
     ) -> RusotoFuture<PutSessionResponse, PutSessionError>;
 }
+impl<T: ?Sized + LexRuntime> LexRuntime for ::std::sync::Arc<T> {
+    fn delete_session(
+        &self,
+        input: DeleteSessionRequest,
+    ) -> RusotoFuture<DeleteSessionResponse, DeleteSessionError> {
+        (**self).delete_session(input)
+    }
+    fn get_session(
+        &self,
+        input: GetSessionRequest,
+    ) -> RusotoFuture<GetSessionResponse, GetSessionError> {
+        (**self).get_session(input)
+    }
+    fn post_content(
+        &self,
+        input: PostContentRequest,
+    ) -> RusotoFuture<PostContentResponse, PostContentError> {
+        (**self).post_content(input)
+    }
+    fn post_text(&self, input: PostTextRequest) -> RusotoFuture<PostTextResponse, PostTextError> {
+        (**self).post_text(input)
+    }
+    fn put_session(
+        &self,
+        input: PutSessionRequest,
+    ) -> RusotoFuture<PutSessionResponse, PutSessionError> {
+        (**self).put_session(input)
+    }
+}
+/// The error codes Amazon Lex Runtime Service retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Lex Runtime Service API.
 #[derive(Clone)]
 pub struct LexRuntimeClient {
@@ -773,6 +1675,27 @@ impl LexRuntimeClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_LEX_RUNTIME`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> LexRuntimeClient {
+        Self::new(region::Region::from_env_for_service("LEX_RUNTIME"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `runtime.lex`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> LexRuntimeClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("runtime.lex", "LEX_RUNTIME"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -791,7 +1714,54 @@ impl LexRuntimeClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> LexRuntimeClient {
-        LexRuntimeClient { client, region }
+        LexRuntimeClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "runtime.lex")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "runtime.lex",
+            "lex",
+            "LEX_RUNTIME",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `LexRuntime` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn LexRuntime + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for LexRuntimeClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Lex Runtime Service"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "runtime.lex"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> LexRuntimeClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -918,7 +1888,7 @@ impl LexRuntime for LexRuntimeClient {
                     };
                     if let Some(dialog_state) = response.headers.get("x-amz-lex-dialog-state") {
                         let value = dialog_state.to_owned();
-                        result.dialog_state = Some(value)
+                        result.dialog_state = Some(value.into())
                     };
                     if let Some(input_transcript) =
                         response.headers.get("x-amz-lex-input-transcript")
@@ -936,7 +1906,7 @@ impl LexRuntime for LexRuntimeClient {
                     };
                     if let Some(message_format) = response.headers.get("x-amz-lex-message-format") {
                         let value = message_format.to_owned();
-                        result.message_format = Some(value)
+                        result.message_format = Some(value.into())
                     };
                     if let Some(session_attributes) =
                         response.headers.get("x-amz-lex-session-attributes")
@@ -1036,7 +2006,7 @@ impl LexRuntime for LexRuntimeClient {
                     };
                     if let Some(dialog_state) = response.headers.get("x-amz-lex-dialog-state") {
                         let value = dialog_state.to_owned();
-                        result.dialog_state = Some(value)
+                        result.dialog_state = Some(value.into())
                     };
                     if let Some(intent_name) = response.headers.get("x-amz-lex-intent-name") {
                         let value = intent_name.to_owned();
@@ -1048,7 +2018,7 @@ impl LexRuntime for LexRuntimeClient {
                     };
                     if let Some(message_format) = response.headers.get("x-amz-lex-message-format") {
                         let value = message_format.to_owned();
-                        result.message_format = Some(value)
+                        result.message_format = Some(value.into())
                     };
                     if let Some(session_attributes) =
                         response.headers.get("x-amz-lex-session-attributes")
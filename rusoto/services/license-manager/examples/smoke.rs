@@ -0,0 +1,40 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS License Manager clients.
+//!
+//! Makes a single read-only `ListLicenseConfigurations` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_license_manager::{
+    LicenseManager, LicenseManagerClient, ListLicenseConfigurationsRequest,
+};
+
+fn main() {
+    let client = LicenseManagerClient::from_env();
+    match client
+        .list_license_configurations(ListLicenseConfigurationsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListLicenseConfigurations succeeded"),
+        Err(error) => {
+            eprintln!("ListLicenseConfigurations failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Details about license consumption.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -34,10 +35,10 @@ pub struct ConsumedLicenseSummary {
     /// <p>Resource type of the resource consuming a license (instance, host, or AMI).</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateLicenseConfigurationRequest {
     /// <p>Human-friendly description of the license configuration.</p>
     #[serde(rename = "Description")]
@@ -53,7 +54,7 @@ pub struct CreateLicenseConfigurationRequest {
     pub license_count_hard_limit: Option<bool>,
     /// <p>Dimension to use to track the license inventory.</p>
     #[serde(rename = "LicenseCountingType")]
-    pub license_counting_type: String,
+    pub license_counting_type: LicenseCountingType,
     /// <p>Array of configured License Manager rules.</p>
     #[serde(rename = "LicenseRules")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -67,6 +68,88 @@ pub struct CreateLicenseConfigurationRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateLicenseConfigurationRequest {
+    /// Returns a builder for `CreateLicenseConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> CreateLicenseConfigurationRequestBuilder {
+        CreateLicenseConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLicenseConfigurationRequest`](struct.CreateLicenseConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLicenseConfigurationRequestBuilder {
+    description: Option<String>,
+    license_count: Option<i64>,
+    license_count_hard_limit: Option<bool>,
+    license_counting_type: Option<LicenseCountingType>,
+    license_rules: Option<Vec<String>>,
+    name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateLicenseConfigurationRequestBuilder {
+    /// Sets the [`description`](struct.CreateLicenseConfigurationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_count`](struct.CreateLicenseConfigurationRequest.html#structfield.license_count) member.
+    pub fn license_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.license_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_count_hard_limit`](struct.CreateLicenseConfigurationRequest.html#structfield.license_count_hard_limit) member.
+    pub fn license_count_hard_limit<T: Into<bool>>(mut self, value: T) -> Self {
+        self.license_count_hard_limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_counting_type`](struct.CreateLicenseConfigurationRequest.html#structfield.license_counting_type) member.
+    pub fn license_counting_type<T: Into<LicenseCountingType>>(mut self, value: T) -> Self {
+        self.license_counting_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_rules`](struct.CreateLicenseConfigurationRequest.html#structfield.license_rules) member.
+    pub fn license_rules<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.license_rules = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateLicenseConfigurationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateLicenseConfigurationRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLicenseConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLicenseConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateLicenseConfigurationRequest {
+            description: self.description,
+            license_count: self.license_count,
+            license_count_hard_limit: self.license_count_hard_limit,
+            license_counting_type: self
+                .license_counting_type
+                .ok_or(::rusoto_core::BuildError::missing("license_counting_type"))?,
+            license_rules: self.license_rules,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateLicenseConfigurationResponse {
@@ -83,6 +166,38 @@ pub struct DeleteLicenseConfigurationRequest {
     pub license_configuration_arn: String,
 }
 
+impl DeleteLicenseConfigurationRequest {
+    /// Returns a builder for `DeleteLicenseConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLicenseConfigurationRequestBuilder {
+        DeleteLicenseConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLicenseConfigurationRequest`](struct.DeleteLicenseConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLicenseConfigurationRequestBuilder {
+    license_configuration_arn: Option<String>,
+}
+
+impl DeleteLicenseConfigurationRequestBuilder {
+    /// Sets the [`license_configuration_arn`](struct.DeleteLicenseConfigurationRequest.html#structfield.license_configuration_arn) member.
+    pub fn license_configuration_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_configuration_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLicenseConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLicenseConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLicenseConfigurationRequest {
+            license_configuration_arn: self.license_configuration_arn.ok_or(
+                ::rusoto_core::BuildError::missing("license_configuration_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteLicenseConfigurationResponse {}
@@ -107,6 +222,38 @@ pub struct GetLicenseConfigurationRequest {
     pub license_configuration_arn: String,
 }
 
+impl GetLicenseConfigurationRequest {
+    /// Returns a builder for `GetLicenseConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> GetLicenseConfigurationRequestBuilder {
+        GetLicenseConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLicenseConfigurationRequest`](struct.GetLicenseConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLicenseConfigurationRequestBuilder {
+    license_configuration_arn: Option<String>,
+}
+
+impl GetLicenseConfigurationRequestBuilder {
+    /// Sets the [`license_configuration_arn`](struct.GetLicenseConfigurationRequest.html#structfield.license_configuration_arn) member.
+    pub fn license_configuration_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_configuration_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLicenseConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLicenseConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(GetLicenseConfigurationRequest {
+            license_configuration_arn: self.license_configuration_arn.ok_or(
+                ::rusoto_core::BuildError::missing("license_configuration_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLicenseConfigurationResponse {
@@ -141,7 +288,7 @@ pub struct GetLicenseConfigurationResponse {
     /// <p>Dimension on which the licenses are counted (for example, instances, cores, sockets, or VCPUs).</p>
     #[serde(rename = "LicenseCountingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_counting_type: Option<String>,
+    pub license_counting_type: Option<LicenseCountingType>,
     /// <p>List of flexible text strings designating license rules.</p>
     #[serde(rename = "LicenseRules")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -193,11 +340,11 @@ pub struct GetServiceSettingsResponse {
 }
 
 /// <p>An inventory filter object.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InventoryFilter {
     /// <p>The condition of the filter.</p>
     #[serde(rename = "Condition")]
-    pub condition: String,
+    pub condition: InventoryFilterCondition,
     /// <p>The name of the filter.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -207,6 +354,86 @@ pub struct InventoryFilter {
     pub value: Option<String>,
 }
 
+/// <p>The condition of the filter.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InventoryFilterCondition {
+    /// <p><code>EQUALS</code></p>
+    Equals,
+    /// <p><code>NOT_EQUALS</code></p>
+    NotEquals,
+    /// <p><code>BEGINS_WITH</code></p>
+    BeginsWith,
+    /// <p><code>CONTAINS</code></p>
+    Contains,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InventoryFilterCondition {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InventoryFilterCondition::Equals => "EQUALS",
+            InventoryFilterCondition::NotEquals => "NOT_EQUALS",
+            InventoryFilterCondition::BeginsWith => "BEGINS_WITH",
+            InventoryFilterCondition::Contains => "CONTAINS",
+            InventoryFilterCondition::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InventoryFilterCondition {
+    fn from(value: &'a str) -> InventoryFilterCondition {
+        match value {
+            "EQUALS" => InventoryFilterCondition::Equals,
+            "NOT_EQUALS" => InventoryFilterCondition::NotEquals,
+            "BEGINS_WITH" => InventoryFilterCondition::BeginsWith,
+            "CONTAINS" => InventoryFilterCondition::Contains,
+            _ => InventoryFilterCondition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InventoryFilterCondition {
+    fn from(value: String) -> InventoryFilterCondition {
+        InventoryFilterCondition::from(value.as_str())
+    }
+}
+
+impl From<InventoryFilterCondition> for String {
+    fn from(value: InventoryFilterCondition) -> String {
+        match value {
+            InventoryFilterCondition::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InventoryFilterCondition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InventoryFilterCondition {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InventoryFilterCondition {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InventoryFilterCondition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InventoryFilterCondition::from(value))
+    }
+}
+
 /// <p>A license configuration is an abstraction of a customer license agreement that can be consumed and enforced by License Manager. Components include specifications for the license type (licensing by instance, socket, CPU, or VCPU), tenancy (shared tenancy, Amazon EC2 Dedicated Instance, Amazon EC2 Dedicated Host, or any of these), host affinity (how long a VM must be associated with a host), the number of licenses purchased and used.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -242,7 +469,7 @@ pub struct LicenseConfiguration {
     /// <p>Dimension to use to track license inventory.</p>
     #[serde(rename = "LicenseCountingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_counting_type: Option<String>,
+    pub license_counting_type: Option<LicenseCountingType>,
     /// <p>Array of configured License Manager rules.</p>
     #[serde(rename = "LicenseRules")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -271,8 +498,9 @@ pub struct LicenseConfiguration {
 pub struct LicenseConfigurationAssociation {
     /// <p>Time when the license configuration was associated with the resource.</p>
     #[serde(rename = "AssociationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_time: Option<f64>,
+    pub association_time: Option<EpochTimestamp>,
     /// <p>ARN of the resource associated with the license configuration.</p>
     #[serde(rename = "ResourceArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -284,7 +512,81 @@ pub struct LicenseConfigurationAssociation {
     /// <p>Type of server resource.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
+}
+
+/// <p>New status of the license configuration (<code>ACTIVE</code> or <code>INACTIVE</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LicenseConfigurationStatus {
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>DISABLED</code></p>
+    Disabled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LicenseConfigurationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LicenseConfigurationStatus::Available => "AVAILABLE",
+            LicenseConfigurationStatus::Disabled => "DISABLED",
+            LicenseConfigurationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LicenseConfigurationStatus {
+    fn from(value: &'a str) -> LicenseConfigurationStatus {
+        match value {
+            "AVAILABLE" => LicenseConfigurationStatus::Available,
+            "DISABLED" => LicenseConfigurationStatus::Disabled,
+            _ => LicenseConfigurationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LicenseConfigurationStatus {
+    fn from(value: String) -> LicenseConfigurationStatus {
+        LicenseConfigurationStatus::from(value.as_str())
+    }
+}
+
+impl From<LicenseConfigurationStatus> for String {
+    fn from(value: LicenseConfigurationStatus) -> String {
+        match value {
+            LicenseConfigurationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LicenseConfigurationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LicenseConfigurationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LicenseConfigurationStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<LicenseConfigurationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LicenseConfigurationStatus::from(value))
+    }
 }
 
 /// <p>Contains details of the usage of each resource from the license pool.</p>
@@ -293,8 +595,9 @@ pub struct LicenseConfigurationAssociation {
 pub struct LicenseConfigurationUsage {
     /// <p>Time when the license configuration was initially associated with a resource.</p>
     #[serde(rename = "AssociationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_time: Option<f64>,
+    pub association_time: Option<EpochTimestamp>,
     /// <p>Number of licenses consumed out of the total provisioned in the license configuration.</p>
     #[serde(rename = "ConsumedLicenses")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -314,7 +617,87 @@ pub struct LicenseConfigurationUsage {
     /// <p>Type of resource associated with athe license configuration.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
+}
+
+/// <p>Dimension to use to track the license inventory.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LicenseCountingType {
+    /// <p><code>vCPU</code></p>
+    VCPU,
+    /// <p><code>Instance</code></p>
+    Instance,
+    /// <p><code>Core</code></p>
+    Core,
+    /// <p><code>Socket</code></p>
+    Socket,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LicenseCountingType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LicenseCountingType::VCPU => "vCPU",
+            LicenseCountingType::Instance => "Instance",
+            LicenseCountingType::Core => "Core",
+            LicenseCountingType::Socket => "Socket",
+            LicenseCountingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LicenseCountingType {
+    fn from(value: &'a str) -> LicenseCountingType {
+        match value {
+            "vCPU" => LicenseCountingType::VCPU,
+            "Instance" => LicenseCountingType::Instance,
+            "Core" => LicenseCountingType::Core,
+            "Socket" => LicenseCountingType::Socket,
+            _ => LicenseCountingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LicenseCountingType {
+    fn from(value: String) -> LicenseCountingType {
+        LicenseCountingType::from(value.as_str())
+    }
+}
+
+impl From<LicenseCountingType> for String {
+    fn from(value: LicenseCountingType) -> String {
+        match value {
+            LicenseCountingType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LicenseCountingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LicenseCountingType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LicenseCountingType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LicenseCountingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LicenseCountingType::from(value))
+    }
 }
 
 /// <p>Object used for associating a license configuration with a resource.</p>
@@ -340,6 +723,57 @@ pub struct ListAssociationsForLicenseConfigurationRequest {
     pub next_token: Option<String>,
 }
 
+impl ListAssociationsForLicenseConfigurationRequest {
+    /// Returns a builder for `ListAssociationsForLicenseConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> ListAssociationsForLicenseConfigurationRequestBuilder {
+        ListAssociationsForLicenseConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAssociationsForLicenseConfigurationRequest`](struct.ListAssociationsForLicenseConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAssociationsForLicenseConfigurationRequestBuilder {
+    license_configuration_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAssociationsForLicenseConfigurationRequestBuilder {
+    /// Sets the [`license_configuration_arn`](struct.ListAssociationsForLicenseConfigurationRequest.html#structfield.license_configuration_arn) member.
+    pub fn license_configuration_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_configuration_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAssociationsForLicenseConfigurationRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAssociationsForLicenseConfigurationRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAssociationsForLicenseConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        ListAssociationsForLicenseConfigurationRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(ListAssociationsForLicenseConfigurationRequest {
+            license_configuration_arn: self.license_configuration_arn.ok_or(
+                ::rusoto_core::BuildError::missing("license_configuration_arn"),
+            )?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAssociationsForLicenseConfigurationResponse {
@@ -373,6 +807,60 @@ pub struct ListLicenseConfigurationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListLicenseConfigurationsRequest {
+    /// Returns a builder for `ListLicenseConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> ListLicenseConfigurationsRequestBuilder {
+        ListLicenseConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListLicenseConfigurationsRequest`](struct.ListLicenseConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListLicenseConfigurationsRequestBuilder {
+    filters: Option<Vec<Filter>>,
+    license_configuration_arns: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListLicenseConfigurationsRequestBuilder {
+    /// Sets the [`filters`](struct.ListLicenseConfigurationsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_configuration_arns`](struct.ListLicenseConfigurationsRequest.html#structfield.license_configuration_arns) member.
+    pub fn license_configuration_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.license_configuration_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListLicenseConfigurationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListLicenseConfigurationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLicenseConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListLicenseConfigurationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListLicenseConfigurationsRequest {
+            filters: self.filters,
+            license_configuration_arns: self.license_configuration_arns,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLicenseConfigurationsResponse {
@@ -401,6 +889,55 @@ pub struct ListLicenseSpecificationsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListLicenseSpecificationsForResourceRequest {
+    /// Returns a builder for `ListLicenseSpecificationsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListLicenseSpecificationsForResourceRequestBuilder {
+        ListLicenseSpecificationsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListLicenseSpecificationsForResourceRequest`](struct.ListLicenseSpecificationsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListLicenseSpecificationsForResourceRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListLicenseSpecificationsForResourceRequestBuilder {
+    /// Sets the [`max_results`](struct.ListLicenseSpecificationsForResourceRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListLicenseSpecificationsForResourceRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListLicenseSpecificationsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLicenseSpecificationsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListLicenseSpecificationsForResourceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListLicenseSpecificationsForResourceRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLicenseSpecificationsForResourceResponse {
@@ -430,6 +967,52 @@ pub struct ListResourceInventoryRequest {
     pub next_token: Option<String>,
 }
 
+impl ListResourceInventoryRequest {
+    /// Returns a builder for `ListResourceInventoryRequest`, checking its required members when built.
+    pub fn builder() -> ListResourceInventoryRequestBuilder {
+        ListResourceInventoryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResourceInventoryRequest`](struct.ListResourceInventoryRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResourceInventoryRequestBuilder {
+    filters: Option<Vec<InventoryFilter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListResourceInventoryRequestBuilder {
+    /// Sets the [`filters`](struct.ListResourceInventoryRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<InventoryFilter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListResourceInventoryRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListResourceInventoryRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResourceInventoryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListResourceInventoryRequest, ::rusoto_core::BuildError> {
+        Ok(ListResourceInventoryRequest {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResourceInventoryResponse {
@@ -450,6 +1033,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -478,6 +1093,63 @@ pub struct ListUsageForLicenseConfigurationRequest {
     pub next_token: Option<String>,
 }
 
+impl ListUsageForLicenseConfigurationRequest {
+    /// Returns a builder for `ListUsageForLicenseConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> ListUsageForLicenseConfigurationRequestBuilder {
+        ListUsageForLicenseConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUsageForLicenseConfigurationRequest`](struct.ListUsageForLicenseConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUsageForLicenseConfigurationRequestBuilder {
+    filters: Option<Vec<Filter>>,
+    license_configuration_arn: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListUsageForLicenseConfigurationRequestBuilder {
+    /// Sets the [`filters`](struct.ListUsageForLicenseConfigurationRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_configuration_arn`](struct.ListUsageForLicenseConfigurationRequest.html#structfield.license_configuration_arn) member.
+    pub fn license_configuration_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_configuration_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListUsageForLicenseConfigurationRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUsageForLicenseConfigurationRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUsageForLicenseConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListUsageForLicenseConfigurationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListUsageForLicenseConfigurationRequest {
+            filters: self.filters,
+            license_configuration_arn: self.license_configuration_arn.ok_or(
+                ::rusoto_core::BuildError::missing("license_configuration_arn"),
+            )?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListUsageForLicenseConfigurationResponse {
@@ -502,7 +1174,7 @@ pub struct ManagedResourceSummary {
     /// <p>Type of resource associated with a license (instance, host, or AMI).</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
 }
 
 /// <p>Object containing configuration information for AWS Organizations.</p>
@@ -540,7 +1212,91 @@ pub struct ResourceInventory {
     /// <p>The type of resource.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
+}
+
+/// <p>Resource type of the resource consuming a license (instance, host, or AMI).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// <p><code>EC2_INSTANCE</code></p>
+    Ec2Instance,
+    /// <p><code>EC2_HOST</code></p>
+    Ec2Host,
+    /// <p><code>EC2_AMI</code></p>
+    Ec2Ami,
+    /// <p><code>RDS</code></p>
+    Rds,
+    /// <p><code>SYSTEMS_MANAGER_MANAGED_INSTANCE</code></p>
+    SystemsManagerManagedInstance,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::Ec2Instance => "EC2_INSTANCE",
+            ResourceType::Ec2Host => "EC2_HOST",
+            ResourceType::Ec2Ami => "EC2_AMI",
+            ResourceType::Rds => "RDS",
+            ResourceType::SystemsManagerManagedInstance => "SYSTEMS_MANAGER_MANAGED_INSTANCE",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "EC2_INSTANCE" => ResourceType::Ec2Instance,
+            "EC2_HOST" => ResourceType::Ec2Host,
+            "EC2_AMI" => ResourceType::Ec2Ami,
+            "RDS" => ResourceType::Rds,
+            "SYSTEMS_MANAGER_MANAGED_INSTANCE" => ResourceType::SystemsManagerManagedInstance,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        ResourceType::from(value.as_str())
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(value: ResourceType) -> String {
+        match value {
+            ResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceType::from(value))
+    }
 }
 
 /// <p>Tag for a resource in a key-value format.</p>
@@ -566,6 +1322,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -580,6 +1376,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -596,7 +1432,7 @@ pub struct UpdateLicenseConfigurationRequest {
     /// <p>New status of the license configuration (<code>ACTIVE</code> or <code>INACTIVE</code>).</p>
     #[serde(rename = "LicenseConfigurationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license_configuration_status: Option<String>,
+    pub license_configuration_status: Option<LicenseConfigurationStatus>,
     /// <p>New number of licenses managed by the license configuration.</p>
     #[serde(rename = "LicenseCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -615,6 +1451,89 @@ pub struct UpdateLicenseConfigurationRequest {
     pub name: Option<String>,
 }
 
+impl UpdateLicenseConfigurationRequest {
+    /// Returns a builder for `UpdateLicenseConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateLicenseConfigurationRequestBuilder {
+        UpdateLicenseConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateLicenseConfigurationRequest`](struct.UpdateLicenseConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateLicenseConfigurationRequestBuilder {
+    description: Option<String>,
+    license_configuration_arn: Option<String>,
+    license_configuration_status: Option<LicenseConfigurationStatus>,
+    license_count: Option<i64>,
+    license_count_hard_limit: Option<bool>,
+    license_rules: Option<Vec<String>>,
+    name: Option<String>,
+}
+
+impl UpdateLicenseConfigurationRequestBuilder {
+    /// Sets the [`description`](struct.UpdateLicenseConfigurationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_configuration_arn`](struct.UpdateLicenseConfigurationRequest.html#structfield.license_configuration_arn) member.
+    pub fn license_configuration_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_configuration_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_configuration_status`](struct.UpdateLicenseConfigurationRequest.html#structfield.license_configuration_status) member.
+    pub fn license_configuration_status<T: Into<LicenseConfigurationStatus>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.license_configuration_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_count`](struct.UpdateLicenseConfigurationRequest.html#structfield.license_count) member.
+    pub fn license_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.license_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_count_hard_limit`](struct.UpdateLicenseConfigurationRequest.html#structfield.license_count_hard_limit) member.
+    pub fn license_count_hard_limit<T: Into<bool>>(mut self, value: T) -> Self {
+        self.license_count_hard_limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_rules`](struct.UpdateLicenseConfigurationRequest.html#structfield.license_rules) member.
+    pub fn license_rules<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.license_rules = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateLicenseConfigurationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateLicenseConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateLicenseConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateLicenseConfigurationRequest {
+            description: self.description,
+            license_configuration_arn: self.license_configuration_arn.ok_or(
+                ::rusoto_core::BuildError::missing("license_configuration_arn"),
+            )?,
+            license_configuration_status: self.license_configuration_status,
+            license_count: self.license_count,
+            license_count_hard_limit: self.license_count_hard_limit,
+            license_rules: self.license_rules,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateLicenseConfigurationResponse {}
@@ -634,6 +1553,63 @@ pub struct UpdateLicenseSpecificationsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl UpdateLicenseSpecificationsForResourceRequest {
+    /// Returns a builder for `UpdateLicenseSpecificationsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> UpdateLicenseSpecificationsForResourceRequestBuilder {
+        UpdateLicenseSpecificationsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateLicenseSpecificationsForResourceRequest`](struct.UpdateLicenseSpecificationsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateLicenseSpecificationsForResourceRequestBuilder {
+    add_license_specifications: Option<Vec<LicenseSpecification>>,
+    remove_license_specifications: Option<Vec<LicenseSpecification>>,
+    resource_arn: Option<String>,
+}
+
+impl UpdateLicenseSpecificationsForResourceRequestBuilder {
+    /// Sets the [`add_license_specifications`](struct.UpdateLicenseSpecificationsForResourceRequest.html#structfield.add_license_specifications) member.
+    pub fn add_license_specifications<T: Into<Vec<LicenseSpecification>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.add_license_specifications = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remove_license_specifications`](struct.UpdateLicenseSpecificationsForResourceRequest.html#structfield.remove_license_specifications) member.
+    pub fn remove_license_specifications<T: Into<Vec<LicenseSpecification>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.remove_license_specifications = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.UpdateLicenseSpecificationsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateLicenseSpecificationsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        UpdateLicenseSpecificationsForResourceRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(UpdateLicenseSpecificationsForResourceRequest {
+            add_license_specifications: self.add_license_specifications,
+            remove_license_specifications: self.remove_license_specifications,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateLicenseSpecificationsForResourceResponse {}
@@ -658,6 +1634,63 @@ pub struct UpdateServiceSettingsRequest {
     pub sns_topic_arn: Option<String>,
 }
 
+impl UpdateServiceSettingsRequest {
+    /// Returns a builder for `UpdateServiceSettingsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateServiceSettingsRequestBuilder {
+        UpdateServiceSettingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateServiceSettingsRequest`](struct.UpdateServiceSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateServiceSettingsRequestBuilder {
+    enable_cross_accounts_discovery: Option<bool>,
+    organization_configuration: Option<OrganizationConfiguration>,
+    s3_bucket_arn: Option<String>,
+    sns_topic_arn: Option<String>,
+}
+
+impl UpdateServiceSettingsRequestBuilder {
+    /// Sets the [`enable_cross_accounts_discovery`](struct.UpdateServiceSettingsRequest.html#structfield.enable_cross_accounts_discovery) member.
+    pub fn enable_cross_accounts_discovery<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_cross_accounts_discovery = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organization_configuration`](struct.UpdateServiceSettingsRequest.html#structfield.organization_configuration) member.
+    pub fn organization_configuration<T: Into<OrganizationConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.organization_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_bucket_arn`](struct.UpdateServiceSettingsRequest.html#structfield.s3_bucket_arn) member.
+    pub fn s3_bucket_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_bucket_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.UpdateServiceSettingsRequest.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateServiceSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateServiceSettingsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateServiceSettingsRequest {
+            enable_cross_accounts_discovery: self.enable_cross_accounts_discovery,
+            organization_configuration: self.organization_configuration,
+            s3_bucket_arn: self.s3_bucket_arn,
+            sns_topic_arn: self.sns_topic_arn,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateServiceSettingsResponse {}
@@ -1799,6 +2832,114 @@ pub trait LicenseManager {
         input: UpdateServiceSettingsRequest,
     ) -> RusotoFuture<UpdateServiceSettingsResponse, UpdateServiceSettingsError>;
 }
+impl<T: ?Sized + LicenseManager> LicenseManager for ::std::sync::Arc<T> {
+    fn create_license_configuration(
+        &self,
+        input: CreateLicenseConfigurationRequest,
+    ) -> RusotoFuture<CreateLicenseConfigurationResponse, CreateLicenseConfigurationError> {
+        (**self).create_license_configuration(input)
+    }
+    fn delete_license_configuration(
+        &self,
+        input: DeleteLicenseConfigurationRequest,
+    ) -> RusotoFuture<DeleteLicenseConfigurationResponse, DeleteLicenseConfigurationError> {
+        (**self).delete_license_configuration(input)
+    }
+    fn get_license_configuration(
+        &self,
+        input: GetLicenseConfigurationRequest,
+    ) -> RusotoFuture<GetLicenseConfigurationResponse, GetLicenseConfigurationError> {
+        (**self).get_license_configuration(input)
+    }
+    fn get_service_settings(
+        &self,
+    ) -> RusotoFuture<GetServiceSettingsResponse, GetServiceSettingsError> {
+        (**self).get_service_settings()
+    }
+    fn list_associations_for_license_configuration(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> RusotoFuture<
+        ListAssociationsForLicenseConfigurationResponse,
+        ListAssociationsForLicenseConfigurationError,
+    > {
+        (**self).list_associations_for_license_configuration(input)
+    }
+    fn list_license_configurations(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> RusotoFuture<ListLicenseConfigurationsResponse, ListLicenseConfigurationsError> {
+        (**self).list_license_configurations(input)
+    }
+    fn list_license_specifications_for_resource(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> RusotoFuture<
+        ListLicenseSpecificationsForResourceResponse,
+        ListLicenseSpecificationsForResourceError,
+    > {
+        (**self).list_license_specifications_for_resource(input)
+    }
+    fn list_resource_inventory(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> RusotoFuture<ListResourceInventoryResponse, ListResourceInventoryError> {
+        (**self).list_resource_inventory(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_usage_for_license_configuration(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> RusotoFuture<ListUsageForLicenseConfigurationResponse, ListUsageForLicenseConfigurationError>
+    {
+        (**self).list_usage_for_license_configuration(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_license_configuration(
+        &self,
+        input: UpdateLicenseConfigurationRequest,
+    ) -> RusotoFuture<UpdateLicenseConfigurationResponse, UpdateLicenseConfigurationError> {
+        (**self).update_license_configuration(input)
+    }
+    fn update_license_specifications_for_resource(
+        &self,
+        input: UpdateLicenseSpecificationsForResourceRequest,
+    ) -> RusotoFuture<
+        UpdateLicenseSpecificationsForResourceResponse,
+        UpdateLicenseSpecificationsForResourceError,
+    > {
+        (**self).update_license_specifications_for_resource(input)
+    }
+    fn update_service_settings(
+        &self,
+        input: UpdateServiceSettingsRequest,
+    ) -> RusotoFuture<UpdateServiceSettingsResponse, UpdateServiceSettingsError> {
+        (**self).update_service_settings(input)
+    }
+}
+/// The error codes AWS License Manager retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS License Manager API.
 #[derive(Clone)]
 pub struct LicenseManagerClient {
@@ -1814,6 +2955,27 @@ impl LicenseManagerClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_LICENSE_MANAGER`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> LicenseManagerClient {
+        Self::new(region::Region::from_env_for_service("LICENSE_MANAGER"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `license-manager`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> LicenseManagerClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("license-manager", "LICENSE_MANAGER"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1832,7 +2994,54 @@ impl LicenseManagerClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> LicenseManagerClient {
-        LicenseManagerClient { client, region }
+        LicenseManagerClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "license-manager")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "license-manager",
+            "license-manager",
+            "LICENSE_MANAGER",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `LicenseManager` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn LicenseManager + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for LicenseManagerClient {
+    fn service_name(&self) -> &'static str {
+        "AWS License Manager"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "license-manager"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> LicenseManagerClient {
+        Self::new_with_client(client, region)
     }
 }
 
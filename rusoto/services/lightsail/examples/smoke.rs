@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Lightsail clients.
+//!
+//! Makes a single read-only `GetActiveNames` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_lightsail::{GetActiveNamesRequest, Lightsail, LightsailClient};
+
+fn main() {
+    let client = LightsailClient::from_env();
+    match client
+        .get_active_names(GetActiveNamesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("GetActiveNames succeeded"),
+        Err(error) => {
+            eprintln!("GetActiveNames failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
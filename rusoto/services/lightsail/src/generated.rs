@@ -22,7 +22,80 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The access direction (<code>inbound</code> or <code>outbound</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccessDirection {
+    /// <p><code>inbound</code></p>
+    Inbound,
+    /// <p><code>outbound</code></p>
+    Outbound,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AccessDirection {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccessDirection::Inbound => "inbound",
+            AccessDirection::Outbound => "outbound",
+            AccessDirection::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AccessDirection {
+    fn from(value: &'a str) -> AccessDirection {
+        match value {
+            "inbound" => AccessDirection::Inbound,
+            "outbound" => AccessDirection::Outbound,
+            _ => AccessDirection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccessDirection {
+    fn from(value: String) -> AccessDirection {
+        AccessDirection::from(value.as_str())
+    }
+}
+
+impl From<AccessDirection> for String {
+    fn from(value: AccessDirection) -> String {
+        match value {
+            AccessDirection::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AccessDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AccessDirection {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccessDirection {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AccessDirection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AccessDirection::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AllocateStaticIpRequest {
     /// <p>The name of the static IP address.</p>
@@ -30,6 +103,38 @@ pub struct AllocateStaticIpRequest {
     pub static_ip_name: String,
 }
 
+impl AllocateStaticIpRequest {
+    /// Returns a builder for `AllocateStaticIpRequest`, checking its required members when built.
+    pub fn builder() -> AllocateStaticIpRequestBuilder {
+        AllocateStaticIpRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AllocateStaticIpRequest`](struct.AllocateStaticIpRequest.html).
+#[derive(Default, Debug)]
+pub struct AllocateStaticIpRequestBuilder {
+    static_ip_name: Option<String>,
+}
+
+impl AllocateStaticIpRequestBuilder {
+    /// Sets the [`static_ip_name`](struct.AllocateStaticIpRequest.html#structfield.static_ip_name) member.
+    pub fn static_ip_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.static_ip_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AllocateStaticIpRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AllocateStaticIpRequest, ::rusoto_core::BuildError> {
+        Ok(AllocateStaticIpRequest {
+            static_ip_name: self
+                .static_ip_name
+                .ok_or(::rusoto_core::BuildError::missing("static_ip_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AllocateStaticIpResult {
@@ -52,6 +157,56 @@ pub struct AttachDiskRequest {
     pub instance_name: String,
 }
 
+impl AttachDiskRequest {
+    /// Returns a builder for `AttachDiskRequest`, checking its required members when built.
+    pub fn builder() -> AttachDiskRequestBuilder {
+        AttachDiskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachDiskRequest`](struct.AttachDiskRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachDiskRequestBuilder {
+    disk_name: Option<String>,
+    disk_path: Option<String>,
+    instance_name: Option<String>,
+}
+
+impl AttachDiskRequestBuilder {
+    /// Sets the [`disk_name`](struct.AttachDiskRequest.html#structfield.disk_name) member.
+    pub fn disk_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disk_path`](struct.AttachDiskRequest.html#structfield.disk_path) member.
+    pub fn disk_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_name`](struct.AttachDiskRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachDiskRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AttachDiskRequest, ::rusoto_core::BuildError> {
+        Ok(AttachDiskRequest {
+            disk_name: self
+                .disk_name
+                .ok_or(::rusoto_core::BuildError::missing("disk_name"))?,
+            disk_path: self
+                .disk_path
+                .ok_or(::rusoto_core::BuildError::missing("disk_path"))?,
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AttachDiskResult {
@@ -71,6 +226,49 @@ pub struct AttachInstancesToLoadBalancerRequest {
     pub load_balancer_name: String,
 }
 
+impl AttachInstancesToLoadBalancerRequest {
+    /// Returns a builder for `AttachInstancesToLoadBalancerRequest`, checking its required members when built.
+    pub fn builder() -> AttachInstancesToLoadBalancerRequestBuilder {
+        AttachInstancesToLoadBalancerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachInstancesToLoadBalancerRequest`](struct.AttachInstancesToLoadBalancerRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachInstancesToLoadBalancerRequestBuilder {
+    instance_names: Option<Vec<String>>,
+    load_balancer_name: Option<String>,
+}
+
+impl AttachInstancesToLoadBalancerRequestBuilder {
+    /// Sets the [`instance_names`](struct.AttachInstancesToLoadBalancerRequest.html#structfield.instance_names) member.
+    pub fn instance_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.AttachInstancesToLoadBalancerRequest.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachInstancesToLoadBalancerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachInstancesToLoadBalancerRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AttachInstancesToLoadBalancerRequest {
+            instance_names: self
+                .instance_names
+                .ok_or(::rusoto_core::BuildError::missing("instance_names"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AttachInstancesToLoadBalancerResult {
@@ -90,6 +288,49 @@ pub struct AttachLoadBalancerTlsCertificateRequest {
     pub load_balancer_name: String,
 }
 
+impl AttachLoadBalancerTlsCertificateRequest {
+    /// Returns a builder for `AttachLoadBalancerTlsCertificateRequest`, checking its required members when built.
+    pub fn builder() -> AttachLoadBalancerTlsCertificateRequestBuilder {
+        AttachLoadBalancerTlsCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachLoadBalancerTlsCertificateRequest`](struct.AttachLoadBalancerTlsCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachLoadBalancerTlsCertificateRequestBuilder {
+    certificate_name: Option<String>,
+    load_balancer_name: Option<String>,
+}
+
+impl AttachLoadBalancerTlsCertificateRequestBuilder {
+    /// Sets the [`certificate_name`](struct.AttachLoadBalancerTlsCertificateRequest.html#structfield.certificate_name) member.
+    pub fn certificate_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.AttachLoadBalancerTlsCertificateRequest.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachLoadBalancerTlsCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachLoadBalancerTlsCertificateRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AttachLoadBalancerTlsCertificateRequest {
+            certificate_name: self
+                .certificate_name
+                .ok_or(::rusoto_core::BuildError::missing("certificate_name"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AttachLoadBalancerTlsCertificateResult {
@@ -109,6 +350,46 @@ pub struct AttachStaticIpRequest {
     pub static_ip_name: String,
 }
 
+impl AttachStaticIpRequest {
+    /// Returns a builder for `AttachStaticIpRequest`, checking its required members when built.
+    pub fn builder() -> AttachStaticIpRequestBuilder {
+        AttachStaticIpRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachStaticIpRequest`](struct.AttachStaticIpRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachStaticIpRequestBuilder {
+    instance_name: Option<String>,
+    static_ip_name: Option<String>,
+}
+
+impl AttachStaticIpRequestBuilder {
+    /// Sets the [`instance_name`](struct.AttachStaticIpRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`static_ip_name`](struct.AttachStaticIpRequest.html#structfield.static_ip_name) member.
+    pub fn static_ip_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.static_ip_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachStaticIpRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AttachStaticIpRequest, ::rusoto_core::BuildError> {
+        Ok(AttachStaticIpRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+            static_ip_name: self
+                .static_ip_name
+                .ok_or(::rusoto_core::BuildError::missing("static_ip_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AttachStaticIpResult {
@@ -167,7 +448,7 @@ pub struct Blueprint {
     /// <p>The operating system platform (either Linux/Unix-based or Windows Server-based) of the blueprint.</p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<InstancePlatform>,
     /// <p>The product URL to learn more about the image or blueprint.</p>
     #[serde(rename = "productUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -175,7 +456,7 @@ pub struct Blueprint {
     /// <p>The type of the blueprint (e.g., <code>os</code> or <code>app</code>).</p>
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<BlueprintType>,
     /// <p>The version number of the operating system, application, or stack (e.g., <code>2016.03.0</code>).</p>
     #[serde(rename = "version")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -186,6 +467,78 @@ pub struct Blueprint {
     pub version_code: Option<String>,
 }
 
+/// <p>The type of the blueprint (e.g., <code>os</code> or <code>app</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BlueprintType {
+    /// <p><code>os</code></p>
+    Os,
+    /// <p><code>app</code></p>
+    App,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BlueprintType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BlueprintType::Os => "os",
+            BlueprintType::App => "app",
+            BlueprintType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BlueprintType {
+    fn from(value: &'a str) -> BlueprintType {
+        match value {
+            "os" => BlueprintType::Os,
+            "app" => BlueprintType::App,
+            _ => BlueprintType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BlueprintType {
+    fn from(value: String) -> BlueprintType {
+        BlueprintType::from(value.as_str())
+    }
+}
+
+impl From<BlueprintType> for String {
+    fn from(value: BlueprintType) -> String {
+        match value {
+            BlueprintType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BlueprintType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BlueprintType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BlueprintType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BlueprintType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BlueprintType::from(value))
+    }
+}
+
 /// <p>Describes a bundle, which is a set of specs describing your virtual private server (or <i>instance</i>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -229,7 +582,7 @@ pub struct Bundle {
     /// <p>The operating system platform (Linux/Unix-based or Windows Server-based) that the bundle supports. You can only launch a <code>WINDOWS</code> bundle on a blueprint that supports the <code>WINDOWS</code> platform. <code>LINUX_UNIX</code> blueprints require a <code>LINUX_UNIX</code> bundle.</p>
     #[serde(rename = "supportedPlatforms")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_platforms: Option<Vec<String>>,
+    pub supported_platforms: Option<Vec<InstancePlatform>>,
     /// <p>The data transfer rate per month in GB (e.g., <code>2000</code>).</p>
     #[serde(rename = "transferPerMonthInGb")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -246,6 +599,48 @@ pub struct CloseInstancePublicPortsRequest {
     pub port_info: PortInfo,
 }
 
+impl CloseInstancePublicPortsRequest {
+    /// Returns a builder for `CloseInstancePublicPortsRequest`, checking its required members when built.
+    pub fn builder() -> CloseInstancePublicPortsRequestBuilder {
+        CloseInstancePublicPortsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CloseInstancePublicPortsRequest`](struct.CloseInstancePublicPortsRequest.html).
+#[derive(Default, Debug)]
+pub struct CloseInstancePublicPortsRequestBuilder {
+    instance_name: Option<String>,
+    port_info: Option<PortInfo>,
+}
+
+impl CloseInstancePublicPortsRequestBuilder {
+    /// Sets the [`instance_name`](struct.CloseInstancePublicPortsRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port_info`](struct.CloseInstancePublicPortsRequest.html#structfield.port_info) member.
+    pub fn port_info<T: Into<PortInfo>>(mut self, value: T) -> Self {
+        self.port_info = Some(value.into());
+        self
+    }
+
+    /// Builds the `CloseInstancePublicPortsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CloseInstancePublicPortsRequest, ::rusoto_core::BuildError> {
+        Ok(CloseInstancePublicPortsRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+            port_info: self
+                .port_info
+                .ok_or(::rusoto_core::BuildError::missing("port_info"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CloseInstancePublicPortsResult {
@@ -265,8 +660,9 @@ pub struct CloudFormationStackRecord {
     pub arn: Option<String>,
     /// <p>The date when the CloudFormation stack record was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>A list of objects describing the destination service, which is AWS CloudFormation, and the Amazon Resource Name (ARN) of the AWS CloudFormation stack.</p>
     #[serde(rename = "destinationInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -282,7 +678,7 @@ pub struct CloudFormationStackRecord {
     /// <p>The Lightsail resource type (e.g., <code>CloudFormationStackRecord</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>A list of objects describing the source of the CloudFormation stack record.</p>
     #[serde(rename = "sourceInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -290,7 +686,7 @@ pub struct CloudFormationStackRecord {
     /// <p>The current state of the CloudFormation stack record.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RecordState>,
 }
 
 /// <p>Describes the source of a CloudFormation stack record (i.e., the export snapshot record).</p>
@@ -308,14 +704,84 @@ pub struct CloudFormationStackRecordSourceInfo {
     /// <p>The Lightsail resource type (e.g., <code>ExportSnapshotRecord</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<CloudFormationStackRecordSourceType>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p>The Lightsail resource type (e.g., <code>ExportSnapshotRecord</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CloudFormationStackRecordSourceType {
+    /// <p><code>ExportSnapshotRecord</code></p>
+    ExportSnapshotRecord,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CloudFormationStackRecordSourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudFormationStackRecordSourceType::ExportSnapshotRecord => "ExportSnapshotRecord",
+            CloudFormationStackRecordSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CloudFormationStackRecordSourceType {
+    fn from(value: &'a str) -> CloudFormationStackRecordSourceType {
+        match value {
+            "ExportSnapshotRecord" => CloudFormationStackRecordSourceType::ExportSnapshotRecord,
+            _ => CloudFormationStackRecordSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudFormationStackRecordSourceType {
+    fn from(value: String) -> CloudFormationStackRecordSourceType {
+        CloudFormationStackRecordSourceType::from(value.as_str())
+    }
+}
+
+impl From<CloudFormationStackRecordSourceType> for String {
+    fn from(value: CloudFormationStackRecordSourceType) -> String {
+        match value {
+            CloudFormationStackRecordSourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CloudFormationStackRecordSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CloudFormationStackRecordSourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CloudFormationStackRecordSourceType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<CloudFormationStackRecordSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CloudFormationStackRecordSourceType::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CopySnapshotRequest {
     /// <p>The AWS Region where the source snapshot is located.</p>
     #[serde(rename = "sourceRegion")]
-    pub source_region: String,
+    pub source_region: RegionName,
     /// <p>The name of the source instance or disk snapshot to be copied.</p>
     #[serde(rename = "sourceSnapshotName")]
     pub source_snapshot_name: String,
@@ -324,6 +790,56 @@ pub struct CopySnapshotRequest {
     pub target_snapshot_name: String,
 }
 
+impl CopySnapshotRequest {
+    /// Returns a builder for `CopySnapshotRequest`, checking its required members when built.
+    pub fn builder() -> CopySnapshotRequestBuilder {
+        CopySnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CopySnapshotRequest`](struct.CopySnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct CopySnapshotRequestBuilder {
+    source_region: Option<RegionName>,
+    source_snapshot_name: Option<String>,
+    target_snapshot_name: Option<String>,
+}
+
+impl CopySnapshotRequestBuilder {
+    /// Sets the [`source_region`](struct.CopySnapshotRequest.html#structfield.source_region) member.
+    pub fn source_region<T: Into<RegionName>>(mut self, value: T) -> Self {
+        self.source_region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_snapshot_name`](struct.CopySnapshotRequest.html#structfield.source_snapshot_name) member.
+    pub fn source_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_snapshot_name`](struct.CopySnapshotRequest.html#structfield.target_snapshot_name) member.
+    pub fn target_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopySnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CopySnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(CopySnapshotRequest {
+            source_region: self
+                .source_region
+                .ok_or(::rusoto_core::BuildError::missing("source_region"))?,
+            source_snapshot_name: self
+                .source_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("source_snapshot_name"))?,
+            target_snapshot_name: self
+                .target_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("target_snapshot_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CopySnapshotResult {
@@ -340,6 +856,38 @@ pub struct CreateCloudFormationStackRequest {
     pub instances: Vec<InstanceEntry>,
 }
 
+impl CreateCloudFormationStackRequest {
+    /// Returns a builder for `CreateCloudFormationStackRequest`, checking its required members when built.
+    pub fn builder() -> CreateCloudFormationStackRequestBuilder {
+        CreateCloudFormationStackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCloudFormationStackRequest`](struct.CreateCloudFormationStackRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCloudFormationStackRequestBuilder {
+    instances: Option<Vec<InstanceEntry>>,
+}
+
+impl CreateCloudFormationStackRequestBuilder {
+    /// Sets the [`instances`](struct.CreateCloudFormationStackRequest.html#structfield.instances) member.
+    pub fn instances<T: Into<Vec<InstanceEntry>>>(mut self, value: T) -> Self {
+        self.instances = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCloudFormationStackRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCloudFormationStackRequest, ::rusoto_core::BuildError> {
+        Ok(CreateCloudFormationStackRequest {
+            instances: self
+                .instances
+                .ok_or(::rusoto_core::BuildError::missing("instances"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCloudFormationStackResult {
@@ -369,6 +917,76 @@ pub struct CreateDiskFromSnapshotRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDiskFromSnapshotRequest {
+    /// Returns a builder for `CreateDiskFromSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> CreateDiskFromSnapshotRequestBuilder {
+        CreateDiskFromSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDiskFromSnapshotRequest`](struct.CreateDiskFromSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDiskFromSnapshotRequestBuilder {
+    availability_zone: Option<String>,
+    disk_name: Option<String>,
+    disk_snapshot_name: Option<String>,
+    size_in_gb: Option<i64>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDiskFromSnapshotRequestBuilder {
+    /// Sets the [`availability_zone`](struct.CreateDiskFromSnapshotRequest.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disk_name`](struct.CreateDiskFromSnapshotRequest.html#structfield.disk_name) member.
+    pub fn disk_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disk_snapshot_name`](struct.CreateDiskFromSnapshotRequest.html#structfield.disk_snapshot_name) member.
+    pub fn disk_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`size_in_gb`](struct.CreateDiskFromSnapshotRequest.html#structfield.size_in_gb) member.
+    pub fn size_in_gb<T: Into<i64>>(mut self, value: T) -> Self {
+        self.size_in_gb = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDiskFromSnapshotRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDiskFromSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDiskFromSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDiskFromSnapshotRequest {
+            availability_zone: self
+                .availability_zone
+                .ok_or(::rusoto_core::BuildError::missing("availability_zone"))?,
+            disk_name: self
+                .disk_name
+                .ok_or(::rusoto_core::BuildError::missing("disk_name"))?,
+            disk_snapshot_name: self
+                .disk_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("disk_snapshot_name"))?,
+            size_in_gb: self
+                .size_in_gb
+                .ok_or(::rusoto_core::BuildError::missing("size_in_gb"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDiskFromSnapshotResult {
@@ -395,6 +1013,64 @@ pub struct CreateDiskRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDiskRequest {
+    /// Returns a builder for `CreateDiskRequest`, checking its required members when built.
+    pub fn builder() -> CreateDiskRequestBuilder {
+        CreateDiskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDiskRequest`](struct.CreateDiskRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDiskRequestBuilder {
+    availability_zone: Option<String>,
+    disk_name: Option<String>,
+    size_in_gb: Option<i64>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDiskRequestBuilder {
+    /// Sets the [`availability_zone`](struct.CreateDiskRequest.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disk_name`](struct.CreateDiskRequest.html#structfield.disk_name) member.
+    pub fn disk_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`size_in_gb`](struct.CreateDiskRequest.html#structfield.size_in_gb) member.
+    pub fn size_in_gb<T: Into<i64>>(mut self, value: T) -> Self {
+        self.size_in_gb = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDiskRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDiskRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDiskRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDiskRequest {
+            availability_zone: self
+                .availability_zone
+                .ok_or(::rusoto_core::BuildError::missing("availability_zone"))?,
+            disk_name: self
+                .disk_name
+                .ok_or(::rusoto_core::BuildError::missing("disk_name"))?,
+            size_in_gb: self
+                .size_in_gb
+                .ok_or(::rusoto_core::BuildError::missing("size_in_gb"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDiskResult {
@@ -423,6 +1099,62 @@ pub struct CreateDiskSnapshotRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDiskSnapshotRequest {
+    /// Returns a builder for `CreateDiskSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> CreateDiskSnapshotRequestBuilder {
+        CreateDiskSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDiskSnapshotRequest`](struct.CreateDiskSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDiskSnapshotRequestBuilder {
+    disk_name: Option<String>,
+    disk_snapshot_name: Option<String>,
+    instance_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDiskSnapshotRequestBuilder {
+    /// Sets the [`disk_name`](struct.CreateDiskSnapshotRequest.html#structfield.disk_name) member.
+    pub fn disk_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disk_snapshot_name`](struct.CreateDiskSnapshotRequest.html#structfield.disk_snapshot_name) member.
+    pub fn disk_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_name`](struct.CreateDiskSnapshotRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDiskSnapshotRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDiskSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDiskSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDiskSnapshotRequest {
+            disk_name: self.disk_name,
+            disk_snapshot_name: self
+                .disk_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("disk_snapshot_name"))?,
+            instance_name: self.instance_name,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDiskSnapshotResult {
@@ -442,6 +1174,48 @@ pub struct CreateDomainEntryRequest {
     pub domain_name: String,
 }
 
+impl CreateDomainEntryRequest {
+    /// Returns a builder for `CreateDomainEntryRequest`, checking its required members when built.
+    pub fn builder() -> CreateDomainEntryRequestBuilder {
+        CreateDomainEntryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDomainEntryRequest`](struct.CreateDomainEntryRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDomainEntryRequestBuilder {
+    domain_entry: Option<DomainEntry>,
+    domain_name: Option<String>,
+}
+
+impl CreateDomainEntryRequestBuilder {
+    /// Sets the [`domain_entry`](struct.CreateDomainEntryRequest.html#structfield.domain_entry) member.
+    pub fn domain_entry<T: Into<DomainEntry>>(mut self, value: T) -> Self {
+        self.domain_entry = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.CreateDomainEntryRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDomainEntryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDomainEntryRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDomainEntryRequest {
+            domain_entry: self
+                .domain_entry
+                .ok_or(::rusoto_core::BuildError::missing("domain_entry"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDomainEntryResult {
@@ -462,6 +1236,44 @@ pub struct CreateDomainRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDomainRequest {
+    /// Returns a builder for `CreateDomainRequest`, checking its required members when built.
+    pub fn builder() -> CreateDomainRequestBuilder {
+        CreateDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDomainRequest`](struct.CreateDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDomainRequestBuilder {
+    domain_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDomainRequestBuilder {
+    /// Sets the [`domain_name`](struct.CreateDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDomainRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDomainRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDomainRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateDomainResult {
@@ -485,6 +1297,56 @@ pub struct CreateInstanceSnapshotRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateInstanceSnapshotRequest {
+    /// Returns a builder for `CreateInstanceSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> CreateInstanceSnapshotRequestBuilder {
+        CreateInstanceSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateInstanceSnapshotRequest`](struct.CreateInstanceSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateInstanceSnapshotRequestBuilder {
+    instance_name: Option<String>,
+    instance_snapshot_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateInstanceSnapshotRequestBuilder {
+    /// Sets the [`instance_name`](struct.CreateInstanceSnapshotRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_snapshot_name`](struct.CreateInstanceSnapshotRequest.html#structfield.instance_snapshot_name) member.
+    pub fn instance_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateInstanceSnapshotRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateInstanceSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateInstanceSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(CreateInstanceSnapshotRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+            instance_snapshot_name: self
+                .instance_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_snapshot_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateInstanceSnapshotResult {
@@ -526,6 +1388,103 @@ pub struct CreateInstancesFromSnapshotRequest {
     pub user_data: Option<String>,
 }
 
+impl CreateInstancesFromSnapshotRequest {
+    /// Returns a builder for `CreateInstancesFromSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> CreateInstancesFromSnapshotRequestBuilder {
+        CreateInstancesFromSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateInstancesFromSnapshotRequest`](struct.CreateInstancesFromSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateInstancesFromSnapshotRequestBuilder {
+    attached_disk_mapping: Option<::std::collections::HashMap<String, Vec<DiskMap>>>,
+    availability_zone: Option<String>,
+    bundle_id: Option<String>,
+    instance_names: Option<Vec<String>>,
+    instance_snapshot_name: Option<String>,
+    key_pair_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    user_data: Option<String>,
+}
+
+impl CreateInstancesFromSnapshotRequestBuilder {
+    /// Sets the [`attached_disk_mapping`](struct.CreateInstancesFromSnapshotRequest.html#structfield.attached_disk_mapping) member.
+    pub fn attached_disk_mapping<T: Into<::std::collections::HashMap<String, Vec<DiskMap>>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attached_disk_mapping = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zone`](struct.CreateInstancesFromSnapshotRequest.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bundle_id`](struct.CreateInstancesFromSnapshotRequest.html#structfield.bundle_id) member.
+    pub fn bundle_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bundle_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_names`](struct.CreateInstancesFromSnapshotRequest.html#structfield.instance_names) member.
+    pub fn instance_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_snapshot_name`](struct.CreateInstancesFromSnapshotRequest.html#structfield.instance_snapshot_name) member.
+    pub fn instance_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_pair_name`](struct.CreateInstancesFromSnapshotRequest.html#structfield.key_pair_name) member.
+    pub fn key_pair_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_pair_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateInstancesFromSnapshotRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_data`](struct.CreateInstancesFromSnapshotRequest.html#structfield.user_data) member.
+    pub fn user_data<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_data = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateInstancesFromSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateInstancesFromSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(CreateInstancesFromSnapshotRequest {
+            attached_disk_mapping: self.attached_disk_mapping,
+            availability_zone: self
+                .availability_zone
+                .ok_or(::rusoto_core::BuildError::missing("availability_zone"))?,
+            bundle_id: self
+                .bundle_id
+                .ok_or(::rusoto_core::BuildError::missing("bundle_id"))?,
+            instance_names: self
+                .instance_names
+                .ok_or(::rusoto_core::BuildError::missing("instance_names"))?,
+            instance_snapshot_name: self
+                .instance_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_snapshot_name"))?,
+            key_pair_name: self.key_pair_name,
+            tags: self.tags,
+            user_data: self.user_data,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateInstancesFromSnapshotResult {
@@ -563,6 +1522,90 @@ pub struct CreateInstancesRequest {
     pub user_data: Option<String>,
 }
 
+impl CreateInstancesRequest {
+    /// Returns a builder for `CreateInstancesRequest`, checking its required members when built.
+    pub fn builder() -> CreateInstancesRequestBuilder {
+        CreateInstancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateInstancesRequest`](struct.CreateInstancesRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateInstancesRequestBuilder {
+    availability_zone: Option<String>,
+    blueprint_id: Option<String>,
+    bundle_id: Option<String>,
+    instance_names: Option<Vec<String>>,
+    key_pair_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    user_data: Option<String>,
+}
+
+impl CreateInstancesRequestBuilder {
+    /// Sets the [`availability_zone`](struct.CreateInstancesRequest.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`blueprint_id`](struct.CreateInstancesRequest.html#structfield.blueprint_id) member.
+    pub fn blueprint_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.blueprint_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bundle_id`](struct.CreateInstancesRequest.html#structfield.bundle_id) member.
+    pub fn bundle_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bundle_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_names`](struct.CreateInstancesRequest.html#structfield.instance_names) member.
+    pub fn instance_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_pair_name`](struct.CreateInstancesRequest.html#structfield.key_pair_name) member.
+    pub fn key_pair_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_pair_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateInstancesRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`user_data`](struct.CreateInstancesRequest.html#structfield.user_data) member.
+    pub fn user_data<T: Into<String>>(mut self, value: T) -> Self {
+        self.user_data = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateInstancesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateInstancesRequest, ::rusoto_core::BuildError> {
+        Ok(CreateInstancesRequest {
+            availability_zone: self
+                .availability_zone
+                .ok_or(::rusoto_core::BuildError::missing("availability_zone"))?,
+            blueprint_id: self
+                .blueprint_id
+                .ok_or(::rusoto_core::BuildError::missing("blueprint_id"))?,
+            bundle_id: self
+                .bundle_id
+                .ok_or(::rusoto_core::BuildError::missing("bundle_id"))?,
+            instance_names: self
+                .instance_names
+                .ok_or(::rusoto_core::BuildError::missing("instance_names"))?,
+            key_pair_name: self.key_pair_name,
+            tags: self.tags,
+            user_data: self.user_data,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateInstancesResult {
@@ -583,6 +1626,44 @@ pub struct CreateKeyPairRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateKeyPairRequest {
+    /// Returns a builder for `CreateKeyPairRequest`, checking its required members when built.
+    pub fn builder() -> CreateKeyPairRequestBuilder {
+        CreateKeyPairRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateKeyPairRequest`](struct.CreateKeyPairRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateKeyPairRequestBuilder {
+    key_pair_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateKeyPairRequestBuilder {
+    /// Sets the [`key_pair_name`](struct.CreateKeyPairRequest.html#structfield.key_pair_name) member.
+    pub fn key_pair_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_pair_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateKeyPairRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateKeyPairRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateKeyPairRequest, ::rusoto_core::BuildError> {
+        Ok(CreateKeyPairRequest {
+            key_pair_name: self
+                .key_pair_name
+                .ok_or(::rusoto_core::BuildError::missing("key_pair_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateKeyPairResult {
@@ -634,6 +1715,88 @@ pub struct CreateLoadBalancerRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateLoadBalancerRequest {
+    /// Returns a builder for `CreateLoadBalancerRequest`, checking its required members when built.
+    pub fn builder() -> CreateLoadBalancerRequestBuilder {
+        CreateLoadBalancerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLoadBalancerRequest`](struct.CreateLoadBalancerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLoadBalancerRequestBuilder {
+    certificate_alternative_names: Option<Vec<String>>,
+    certificate_domain_name: Option<String>,
+    certificate_name: Option<String>,
+    health_check_path: Option<String>,
+    instance_port: Option<i64>,
+    load_balancer_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateLoadBalancerRequestBuilder {
+    /// Sets the [`certificate_alternative_names`](struct.CreateLoadBalancerRequest.html#structfield.certificate_alternative_names) member.
+    pub fn certificate_alternative_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.certificate_alternative_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_domain_name`](struct.CreateLoadBalancerRequest.html#structfield.certificate_domain_name) member.
+    pub fn certificate_domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_name`](struct.CreateLoadBalancerRequest.html#structfield.certificate_name) member.
+    pub fn certificate_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`health_check_path`](struct.CreateLoadBalancerRequest.html#structfield.health_check_path) member.
+    pub fn health_check_path<T: Into<String>>(mut self, value: T) -> Self {
+        self.health_check_path = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_port`](struct.CreateLoadBalancerRequest.html#structfield.instance_port) member.
+    pub fn instance_port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.instance_port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.CreateLoadBalancerRequest.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateLoadBalancerRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLoadBalancerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLoadBalancerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateLoadBalancerRequest {
+            certificate_alternative_names: self.certificate_alternative_names,
+            certificate_domain_name: self.certificate_domain_name,
+            certificate_name: self.certificate_name,
+            health_check_path: self.health_check_path,
+            instance_port: self
+                .instance_port
+                .ok_or(::rusoto_core::BuildError::missing("instance_port"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateLoadBalancerResult {
@@ -664,6 +1827,75 @@ pub struct CreateLoadBalancerTlsCertificateRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateLoadBalancerTlsCertificateRequest {
+    /// Returns a builder for `CreateLoadBalancerTlsCertificateRequest`, checking its required members when built.
+    pub fn builder() -> CreateLoadBalancerTlsCertificateRequestBuilder {
+        CreateLoadBalancerTlsCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLoadBalancerTlsCertificateRequest`](struct.CreateLoadBalancerTlsCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLoadBalancerTlsCertificateRequestBuilder {
+    certificate_alternative_names: Option<Vec<String>>,
+    certificate_domain_name: Option<String>,
+    certificate_name: Option<String>,
+    load_balancer_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateLoadBalancerTlsCertificateRequestBuilder {
+    /// Sets the [`certificate_alternative_names`](struct.CreateLoadBalancerTlsCertificateRequest.html#structfield.certificate_alternative_names) member.
+    pub fn certificate_alternative_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.certificate_alternative_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_domain_name`](struct.CreateLoadBalancerTlsCertificateRequest.html#structfield.certificate_domain_name) member.
+    pub fn certificate_domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_domain_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`certificate_name`](struct.CreateLoadBalancerTlsCertificateRequest.html#structfield.certificate_name) member.
+    pub fn certificate_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.CreateLoadBalancerTlsCertificateRequest.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateLoadBalancerTlsCertificateRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLoadBalancerTlsCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateLoadBalancerTlsCertificateRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateLoadBalancerTlsCertificateRequest {
+            certificate_alternative_names: self.certificate_alternative_names,
+            certificate_domain_name: self.certificate_domain_name.ok_or(
+                ::rusoto_core::BuildError::missing("certificate_domain_name"),
+            )?,
+            certificate_name: self
+                .certificate_name
+                .ok_or(::rusoto_core::BuildError::missing("certificate_name"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateLoadBalancerTlsCertificateResult {
@@ -696,8 +1928,9 @@ pub struct CreateRelationalDatabaseFromSnapshotRequest {
     pub relational_database_snapshot_name: Option<String>,
     /// <p><p>The date and time to restore your database from.</p> <p>Constraints:</p> <ul> <li> <p>Must be before the latest restorable time for the database.</p> </li> <li> <p>Cannot be specified if the <code>use latest restorable time</code> parameter is <code>true</code>.</p> </li> <li> <p>Specified in Universal Coordinated Time (UTC).</p> </li> <li> <p>Specified in the Unix time format.</p> <p>For example, if you wish to use a restore time of October 1, 2018, at 8 PM UTC, then you input <code>1538424000</code> as the restore time.</p> </li> </ul></p>
     #[serde(rename = "restoreTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub restore_time: Option<f64>,
+    pub restore_time: Option<EpochTimestamp>,
     /// <p>The name of the source database.</p>
     #[serde(rename = "sourceRelationalDatabaseName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -712,6 +1945,103 @@ pub struct CreateRelationalDatabaseFromSnapshotRequest {
     pub use_latest_restorable_time: Option<bool>,
 }
 
+impl CreateRelationalDatabaseFromSnapshotRequest {
+    /// Returns a builder for `CreateRelationalDatabaseFromSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> CreateRelationalDatabaseFromSnapshotRequestBuilder {
+        CreateRelationalDatabaseFromSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRelationalDatabaseFromSnapshotRequest`](struct.CreateRelationalDatabaseFromSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRelationalDatabaseFromSnapshotRequestBuilder {
+    availability_zone: Option<String>,
+    publicly_accessible: Option<bool>,
+    relational_database_bundle_id: Option<String>,
+    relational_database_name: Option<String>,
+    relational_database_snapshot_name: Option<String>,
+    restore_time: Option<EpochTimestamp>,
+    source_relational_database_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+    use_latest_restorable_time: Option<bool>,
+}
+
+impl CreateRelationalDatabaseFromSnapshotRequestBuilder {
+    /// Sets the [`availability_zone`](struct.CreateRelationalDatabaseFromSnapshotRequest.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.CreateRelationalDatabaseFromSnapshotRequest.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_bundle_id`](struct.CreateRelationalDatabaseFromSnapshotRequest.html#structfield.relational_database_bundle_id) member.
+    pub fn relational_database_bundle_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_bundle_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_name`](struct.CreateRelationalDatabaseFromSnapshotRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_snapshot_name`](struct.CreateRelationalDatabaseFromSnapshotRequest.html#structfield.relational_database_snapshot_name) member.
+    pub fn relational_database_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`restore_time`](struct.CreateRelationalDatabaseFromSnapshotRequest.html#structfield.restore_time) member.
+    pub fn restore_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.restore_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_relational_database_name`](struct.CreateRelationalDatabaseFromSnapshotRequest.html#structfield.source_relational_database_name) member.
+    pub fn source_relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateRelationalDatabaseFromSnapshotRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_latest_restorable_time`](struct.CreateRelationalDatabaseFromSnapshotRequest.html#structfield.use_latest_restorable_time) member.
+    pub fn use_latest_restorable_time<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_latest_restorable_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRelationalDatabaseFromSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateRelationalDatabaseFromSnapshotRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateRelationalDatabaseFromSnapshotRequest {
+            availability_zone: self.availability_zone,
+            publicly_accessible: self.publicly_accessible,
+            relational_database_bundle_id: self.relational_database_bundle_id,
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+            relational_database_snapshot_name: self.relational_database_snapshot_name,
+            restore_time: self.restore_time,
+            source_relational_database_name: self.source_relational_database_name,
+            tags: self.tags,
+            use_latest_restorable_time: self.use_latest_restorable_time,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRelationalDatabaseFromSnapshotResult {
@@ -764,6 +2094,126 @@ pub struct CreateRelationalDatabaseRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateRelationalDatabaseRequest {
+    /// Returns a builder for `CreateRelationalDatabaseRequest`, checking its required members when built.
+    pub fn builder() -> CreateRelationalDatabaseRequestBuilder {
+        CreateRelationalDatabaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRelationalDatabaseRequest`](struct.CreateRelationalDatabaseRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRelationalDatabaseRequestBuilder {
+    availability_zone: Option<String>,
+    master_database_name: Option<String>,
+    master_user_password: Option<String>,
+    master_username: Option<String>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    publicly_accessible: Option<bool>,
+    relational_database_blueprint_id: Option<String>,
+    relational_database_bundle_id: Option<String>,
+    relational_database_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateRelationalDatabaseRequestBuilder {
+    /// Sets the [`availability_zone`](struct.CreateRelationalDatabaseRequest.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_database_name`](struct.CreateRelationalDatabaseRequest.html#structfield.master_database_name) member.
+    pub fn master_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.CreateRelationalDatabaseRequest.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_username`](struct.CreateRelationalDatabaseRequest.html#structfield.master_username) member.
+    pub fn master_username<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_username = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.CreateRelationalDatabaseRequest.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateRelationalDatabaseRequest.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.CreateRelationalDatabaseRequest.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_blueprint_id`](struct.CreateRelationalDatabaseRequest.html#structfield.relational_database_blueprint_id) member.
+    pub fn relational_database_blueprint_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_blueprint_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_bundle_id`](struct.CreateRelationalDatabaseRequest.html#structfield.relational_database_bundle_id) member.
+    pub fn relational_database_bundle_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_bundle_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_name`](struct.CreateRelationalDatabaseRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateRelationalDatabaseRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRelationalDatabaseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateRelationalDatabaseRequest, ::rusoto_core::BuildError> {
+        Ok(CreateRelationalDatabaseRequest {
+            availability_zone: self.availability_zone,
+            master_database_name: self
+                .master_database_name
+                .ok_or(::rusoto_core::BuildError::missing("master_database_name"))?,
+            master_user_password: self.master_user_password,
+            master_username: self
+                .master_username
+                .ok_or(::rusoto_core::BuildError::missing("master_username"))?,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            publicly_accessible: self.publicly_accessible,
+            relational_database_blueprint_id: self.relational_database_blueprint_id.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_blueprint_id"),
+            )?,
+            relational_database_bundle_id: self.relational_database_bundle_id.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_bundle_id"),
+            )?,
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRelationalDatabaseResult {
@@ -787,6 +2237,57 @@ pub struct CreateRelationalDatabaseSnapshotRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateRelationalDatabaseSnapshotRequest {
+    /// Returns a builder for `CreateRelationalDatabaseSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> CreateRelationalDatabaseSnapshotRequestBuilder {
+        CreateRelationalDatabaseSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRelationalDatabaseSnapshotRequest`](struct.CreateRelationalDatabaseSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateRelationalDatabaseSnapshotRequestBuilder {
+    relational_database_name: Option<String>,
+    relational_database_snapshot_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateRelationalDatabaseSnapshotRequestBuilder {
+    /// Sets the [`relational_database_name`](struct.CreateRelationalDatabaseSnapshotRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_snapshot_name`](struct.CreateRelationalDatabaseSnapshotRequest.html#structfield.relational_database_snapshot_name) member.
+    pub fn relational_database_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateRelationalDatabaseSnapshotRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRelationalDatabaseSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateRelationalDatabaseSnapshotRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateRelationalDatabaseSnapshotRequest {
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+            relational_database_snapshot_name: self.relational_database_snapshot_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_snapshot_name"),
+            )?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateRelationalDatabaseSnapshotResult {
@@ -803,6 +2304,36 @@ pub struct DeleteDiskRequest {
     pub disk_name: String,
 }
 
+impl DeleteDiskRequest {
+    /// Returns a builder for `DeleteDiskRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDiskRequestBuilder {
+        DeleteDiskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDiskRequest`](struct.DeleteDiskRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDiskRequestBuilder {
+    disk_name: Option<String>,
+}
+
+impl DeleteDiskRequestBuilder {
+    /// Sets the [`disk_name`](struct.DeleteDiskRequest.html#structfield.disk_name) member.
+    pub fn disk_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDiskRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDiskRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDiskRequest {
+            disk_name: self
+                .disk_name
+                .ok_or(::rusoto_core::BuildError::missing("disk_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDiskResult {
@@ -819,6 +2350,38 @@ pub struct DeleteDiskSnapshotRequest {
     pub disk_snapshot_name: String,
 }
 
+impl DeleteDiskSnapshotRequest {
+    /// Returns a builder for `DeleteDiskSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDiskSnapshotRequestBuilder {
+        DeleteDiskSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDiskSnapshotRequest`](struct.DeleteDiskSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDiskSnapshotRequestBuilder {
+    disk_snapshot_name: Option<String>,
+}
+
+impl DeleteDiskSnapshotRequestBuilder {
+    /// Sets the [`disk_snapshot_name`](struct.DeleteDiskSnapshotRequest.html#structfield.disk_snapshot_name) member.
+    pub fn disk_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDiskSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDiskSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDiskSnapshotRequest {
+            disk_snapshot_name: self
+                .disk_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("disk_snapshot_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDiskSnapshotResult {
@@ -838,6 +2401,48 @@ pub struct DeleteDomainEntryRequest {
     pub domain_name: String,
 }
 
+impl DeleteDomainEntryRequest {
+    /// Returns a builder for `DeleteDomainEntryRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDomainEntryRequestBuilder {
+        DeleteDomainEntryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDomainEntryRequest`](struct.DeleteDomainEntryRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDomainEntryRequestBuilder {
+    domain_entry: Option<DomainEntry>,
+    domain_name: Option<String>,
+}
+
+impl DeleteDomainEntryRequestBuilder {
+    /// Sets the [`domain_entry`](struct.DeleteDomainEntryRequest.html#structfield.domain_entry) member.
+    pub fn domain_entry<T: Into<DomainEntry>>(mut self, value: T) -> Self {
+        self.domain_entry = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.DeleteDomainEntryRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDomainEntryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDomainEntryRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDomainEntryRequest {
+            domain_entry: self
+                .domain_entry
+                .ok_or(::rusoto_core::BuildError::missing("domain_entry"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDomainEntryResult {
@@ -854,7 +2459,37 @@ pub struct DeleteDomainRequest {
     pub domain_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DeleteDomainRequest {
+    /// Returns a builder for `DeleteDomainRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDomainRequestBuilder {
+        DeleteDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDomainRequest`](struct.DeleteDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDomainRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl DeleteDomainRequestBuilder {
+    /// Sets the [`domain_name`](struct.DeleteDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDomainRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDomainRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteDomainResult {
     /// <p>An array of key-value pairs containing information about the results of your delete domain request.</p>
@@ -870,6 +2505,36 @@ pub struct DeleteInstanceRequest {
     pub instance_name: String,
 }
 
+impl DeleteInstanceRequest {
+    /// Returns a builder for `DeleteInstanceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteInstanceRequestBuilder {
+        DeleteInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteInstanceRequest`](struct.DeleteInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteInstanceRequestBuilder {
+    instance_name: Option<String>,
+}
+
+impl DeleteInstanceRequestBuilder {
+    /// Sets the [`instance_name`](struct.DeleteInstanceRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteInstanceRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteInstanceResult {
@@ -886,6 +2551,38 @@ pub struct DeleteInstanceSnapshotRequest {
     pub instance_snapshot_name: String,
 }
 
+impl DeleteInstanceSnapshotRequest {
+    /// Returns a builder for `DeleteInstanceSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> DeleteInstanceSnapshotRequestBuilder {
+        DeleteInstanceSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteInstanceSnapshotRequest`](struct.DeleteInstanceSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteInstanceSnapshotRequestBuilder {
+    instance_snapshot_name: Option<String>,
+}
+
+impl DeleteInstanceSnapshotRequestBuilder {
+    /// Sets the [`instance_snapshot_name`](struct.DeleteInstanceSnapshotRequest.html#structfield.instance_snapshot_name) member.
+    pub fn instance_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteInstanceSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteInstanceSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteInstanceSnapshotRequest {
+            instance_snapshot_name: self
+                .instance_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_snapshot_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteInstanceSnapshotResult {
@@ -902,6 +2599,36 @@ pub struct DeleteKeyPairRequest {
     pub key_pair_name: String,
 }
 
+impl DeleteKeyPairRequest {
+    /// Returns a builder for `DeleteKeyPairRequest`, checking its required members when built.
+    pub fn builder() -> DeleteKeyPairRequestBuilder {
+        DeleteKeyPairRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteKeyPairRequest`](struct.DeleteKeyPairRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteKeyPairRequestBuilder {
+    key_pair_name: Option<String>,
+}
+
+impl DeleteKeyPairRequestBuilder {
+    /// Sets the [`key_pair_name`](struct.DeleteKeyPairRequest.html#structfield.key_pair_name) member.
+    pub fn key_pair_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_pair_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteKeyPairRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteKeyPairRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteKeyPairRequest {
+            key_pair_name: self
+                .key_pair_name
+                .ok_or(::rusoto_core::BuildError::missing("key_pair_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteKeyPairResult {
@@ -918,6 +2645,38 @@ pub struct DeleteKnownHostKeysRequest {
     pub instance_name: String,
 }
 
+impl DeleteKnownHostKeysRequest {
+    /// Returns a builder for `DeleteKnownHostKeysRequest`, checking its required members when built.
+    pub fn builder() -> DeleteKnownHostKeysRequestBuilder {
+        DeleteKnownHostKeysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteKnownHostKeysRequest`](struct.DeleteKnownHostKeysRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteKnownHostKeysRequestBuilder {
+    instance_name: Option<String>,
+}
+
+impl DeleteKnownHostKeysRequestBuilder {
+    /// Sets the [`instance_name`](struct.DeleteKnownHostKeysRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteKnownHostKeysRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteKnownHostKeysRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteKnownHostKeysRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteKnownHostKeysResult {
@@ -934,6 +2693,38 @@ pub struct DeleteLoadBalancerRequest {
     pub load_balancer_name: String,
 }
 
+impl DeleteLoadBalancerRequest {
+    /// Returns a builder for `DeleteLoadBalancerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLoadBalancerRequestBuilder {
+        DeleteLoadBalancerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLoadBalancerRequest`](struct.DeleteLoadBalancerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLoadBalancerRequestBuilder {
+    load_balancer_name: Option<String>,
+}
+
+impl DeleteLoadBalancerRequestBuilder {
+    /// Sets the [`load_balancer_name`](struct.DeleteLoadBalancerRequest.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLoadBalancerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLoadBalancerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLoadBalancerRequest {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteLoadBalancerResult {
@@ -957,6 +2748,57 @@ pub struct DeleteLoadBalancerTlsCertificateRequest {
     pub load_balancer_name: String,
 }
 
+impl DeleteLoadBalancerTlsCertificateRequest {
+    /// Returns a builder for `DeleteLoadBalancerTlsCertificateRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLoadBalancerTlsCertificateRequestBuilder {
+        DeleteLoadBalancerTlsCertificateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLoadBalancerTlsCertificateRequest`](struct.DeleteLoadBalancerTlsCertificateRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLoadBalancerTlsCertificateRequestBuilder {
+    certificate_name: Option<String>,
+    force: Option<bool>,
+    load_balancer_name: Option<String>,
+}
+
+impl DeleteLoadBalancerTlsCertificateRequestBuilder {
+    /// Sets the [`certificate_name`](struct.DeleteLoadBalancerTlsCertificateRequest.html#structfield.certificate_name) member.
+    pub fn certificate_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force`](struct.DeleteLoadBalancerTlsCertificateRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.DeleteLoadBalancerTlsCertificateRequest.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLoadBalancerTlsCertificateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLoadBalancerTlsCertificateRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteLoadBalancerTlsCertificateRequest {
+            certificate_name: self
+                .certificate_name
+                .ok_or(::rusoto_core::BuildError::missing("certificate_name"))?,
+            force: self.force,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteLoadBalancerTlsCertificateResult {
@@ -981,6 +2823,54 @@ pub struct DeleteRelationalDatabaseRequest {
     pub skip_final_snapshot: Option<bool>,
 }
 
+impl DeleteRelationalDatabaseRequest {
+    /// Returns a builder for `DeleteRelationalDatabaseRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRelationalDatabaseRequestBuilder {
+        DeleteRelationalDatabaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRelationalDatabaseRequest`](struct.DeleteRelationalDatabaseRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRelationalDatabaseRequestBuilder {
+    final_relational_database_snapshot_name: Option<String>,
+    relational_database_name: Option<String>,
+    skip_final_snapshot: Option<bool>,
+}
+
+impl DeleteRelationalDatabaseRequestBuilder {
+    /// Sets the [`final_relational_database_snapshot_name`](struct.DeleteRelationalDatabaseRequest.html#structfield.final_relational_database_snapshot_name) member.
+    pub fn final_relational_database_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.final_relational_database_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_name`](struct.DeleteRelationalDatabaseRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skip_final_snapshot`](struct.DeleteRelationalDatabaseRequest.html#structfield.skip_final_snapshot) member.
+    pub fn skip_final_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.skip_final_snapshot = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRelationalDatabaseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRelationalDatabaseRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRelationalDatabaseRequest {
+            final_relational_database_snapshot_name: self.final_relational_database_snapshot_name,
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+            skip_final_snapshot: self.skip_final_snapshot,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRelationalDatabaseResult {
@@ -997,6 +2887,39 @@ pub struct DeleteRelationalDatabaseSnapshotRequest {
     pub relational_database_snapshot_name: String,
 }
 
+impl DeleteRelationalDatabaseSnapshotRequest {
+    /// Returns a builder for `DeleteRelationalDatabaseSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRelationalDatabaseSnapshotRequestBuilder {
+        DeleteRelationalDatabaseSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRelationalDatabaseSnapshotRequest`](struct.DeleteRelationalDatabaseSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRelationalDatabaseSnapshotRequestBuilder {
+    relational_database_snapshot_name: Option<String>,
+}
+
+impl DeleteRelationalDatabaseSnapshotRequestBuilder {
+    /// Sets the [`relational_database_snapshot_name`](struct.DeleteRelationalDatabaseSnapshotRequest.html#structfield.relational_database_snapshot_name) member.
+    pub fn relational_database_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRelationalDatabaseSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRelationalDatabaseSnapshotRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteRelationalDatabaseSnapshotRequest {
+            relational_database_snapshot_name: self.relational_database_snapshot_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_snapshot_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteRelationalDatabaseSnapshotResult {
@@ -1027,6 +2950,36 @@ pub struct DetachDiskRequest {
     pub disk_name: String,
 }
 
+impl DetachDiskRequest {
+    /// Returns a builder for `DetachDiskRequest`, checking its required members when built.
+    pub fn builder() -> DetachDiskRequestBuilder {
+        DetachDiskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachDiskRequest`](struct.DetachDiskRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachDiskRequestBuilder {
+    disk_name: Option<String>,
+}
+
+impl DetachDiskRequestBuilder {
+    /// Sets the [`disk_name`](struct.DetachDiskRequest.html#structfield.disk_name) member.
+    pub fn disk_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachDiskRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetachDiskRequest, ::rusoto_core::BuildError> {
+        Ok(DetachDiskRequest {
+            disk_name: self
+                .disk_name
+                .ok_or(::rusoto_core::BuildError::missing("disk_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetachDiskResult {
@@ -1046,6 +2999,49 @@ pub struct DetachInstancesFromLoadBalancerRequest {
     pub load_balancer_name: String,
 }
 
+impl DetachInstancesFromLoadBalancerRequest {
+    /// Returns a builder for `DetachInstancesFromLoadBalancerRequest`, checking its required members when built.
+    pub fn builder() -> DetachInstancesFromLoadBalancerRequestBuilder {
+        DetachInstancesFromLoadBalancerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachInstancesFromLoadBalancerRequest`](struct.DetachInstancesFromLoadBalancerRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachInstancesFromLoadBalancerRequestBuilder {
+    instance_names: Option<Vec<String>>,
+    load_balancer_name: Option<String>,
+}
+
+impl DetachInstancesFromLoadBalancerRequestBuilder {
+    /// Sets the [`instance_names`](struct.DetachInstancesFromLoadBalancerRequest.html#structfield.instance_names) member.
+    pub fn instance_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.DetachInstancesFromLoadBalancerRequest.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachInstancesFromLoadBalancerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetachInstancesFromLoadBalancerRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DetachInstancesFromLoadBalancerRequest {
+            instance_names: self
+                .instance_names
+                .ok_or(::rusoto_core::BuildError::missing("instance_names"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetachInstancesFromLoadBalancerResult {
@@ -1062,6 +3058,36 @@ pub struct DetachStaticIpRequest {
     pub static_ip_name: String,
 }
 
+impl DetachStaticIpRequest {
+    /// Returns a builder for `DetachStaticIpRequest`, checking its required members when built.
+    pub fn builder() -> DetachStaticIpRequestBuilder {
+        DetachStaticIpRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachStaticIpRequest`](struct.DetachStaticIpRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachStaticIpRequestBuilder {
+    static_ip_name: Option<String>,
+}
+
+impl DetachStaticIpRequestBuilder {
+    /// Sets the [`static_ip_name`](struct.DetachStaticIpRequest.html#structfield.static_ip_name) member.
+    pub fn static_ip_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.static_ip_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachStaticIpRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetachStaticIpRequest, ::rusoto_core::BuildError> {
+        Ok(DetachStaticIpRequest {
+            static_ip_name: self
+                .static_ip_name
+                .ok_or(::rusoto_core::BuildError::missing("static_ip_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetachStaticIpResult {
@@ -1085,8 +3111,9 @@ pub struct Disk {
     pub attached_to: Option<String>,
     /// <p>The date when the disk was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The input/output operations per second (IOPS) of the disk.</p>
     #[serde(rename = "iops")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1114,7 +3141,7 @@ pub struct Disk {
     /// <p>The Lightsail resource type (e.g., <code>Disk</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The size of the disk in GB.</p>
     #[serde(rename = "sizeInGb")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1122,7 +3149,7 @@ pub struct Disk {
     /// <p>Describes the status of the disk.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DiskState>,
     /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1178,8 +3205,9 @@ pub struct DiskSnapshot {
     pub arn: Option<String>,
     /// <p>The date when the disk snapshot was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The Amazon Resource Name (ARN) of the source disk from which the disk snapshot was created.</p>
     #[serde(rename = "fromDiskArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1211,7 +3239,7 @@ pub struct DiskSnapshot {
     /// <p>The Lightsail resource type (e.g., <code>DiskSnapshot</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The size of the disk in GB.</p>
     #[serde(rename = "sizeInGb")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1219,7 +3247,7 @@ pub struct DiskSnapshot {
     /// <p>The status of the disk snapshot operation.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<DiskSnapshotState>,
     /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1240,6 +3268,170 @@ pub struct DiskSnapshotInfo {
     pub size_in_gb: Option<i64>,
 }
 
+/// <p>The status of the disk snapshot operation.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DiskSnapshotState {
+    /// <p><code>pending</code></p>
+    Pending,
+    /// <p><code>completed</code></p>
+    Completed,
+    /// <p><code>error</code></p>
+    Error,
+    /// <p><code>unknown</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DiskSnapshotState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DiskSnapshotState::Pending => "pending",
+            DiskSnapshotState::Completed => "completed",
+            DiskSnapshotState::Error => "error",
+            DiskSnapshotState::UnknownValue => "unknown",
+            DiskSnapshotState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DiskSnapshotState {
+    fn from(value: &'a str) -> DiskSnapshotState {
+        match value {
+            "pending" => DiskSnapshotState::Pending,
+            "completed" => DiskSnapshotState::Completed,
+            "error" => DiskSnapshotState::Error,
+            "unknown" => DiskSnapshotState::UnknownValue,
+            _ => DiskSnapshotState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DiskSnapshotState {
+    fn from(value: String) -> DiskSnapshotState {
+        DiskSnapshotState::from(value.as_str())
+    }
+}
+
+impl From<DiskSnapshotState> for String {
+    fn from(value: DiskSnapshotState) -> String {
+        match value {
+            DiskSnapshotState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DiskSnapshotState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DiskSnapshotState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DiskSnapshotState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DiskSnapshotState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DiskSnapshotState::from(value))
+    }
+}
+
+/// <p>Describes the status of the disk.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DiskState {
+    /// <p><code>pending</code></p>
+    Pending,
+    /// <p><code>error</code></p>
+    Error,
+    /// <p><code>available</code></p>
+    Available,
+    /// <p><code>in-use</code></p>
+    InUse,
+    /// <p><code>unknown</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DiskState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DiskState::Pending => "pending",
+            DiskState::Error => "error",
+            DiskState::Available => "available",
+            DiskState::InUse => "in-use",
+            DiskState::UnknownValue => "unknown",
+            DiskState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DiskState {
+    fn from(value: &'a str) -> DiskState {
+        match value {
+            "pending" => DiskState::Pending,
+            "error" => DiskState::Error,
+            "available" => DiskState::Available,
+            "in-use" => DiskState::InUse,
+            "unknown" => DiskState::UnknownValue,
+            _ => DiskState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DiskState {
+    fn from(value: String) -> DiskState {
+        DiskState::from(value.as_str())
+    }
+}
+
+impl From<DiskState> for String {
+    fn from(value: DiskState) -> String {
+        match value {
+            DiskState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DiskState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DiskState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DiskState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DiskState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DiskState::from(value))
+    }
+}
+
 /// <p>Describes a domain where you are storing recordsets in Lightsail.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1250,8 +3442,9 @@ pub struct Domain {
     pub arn: Option<String>,
     /// <p>The date when the domain recordset was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>An array of key-value pairs containing information about the domain entries.</p>
     #[serde(rename = "domainEntries")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1267,7 +3460,7 @@ pub struct Domain {
     /// <p>The resource type. </p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1329,8 +3522,9 @@ pub struct ExportSnapshotRecord {
     pub arn: Option<String>,
     /// <p>The date when the export snapshot record was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>A list of objects describing the destination of the export snapshot record.</p>
     #[serde(rename = "destinationInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1346,7 +3540,7 @@ pub struct ExportSnapshotRecord {
     /// <p>The Lightsail resource type (e.g., <code>ExportSnapshotRecord</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>A list of objects describing the source of the export snapshot record.</p>
     #[serde(rename = "sourceInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1354,7 +3548,7 @@ pub struct ExportSnapshotRecord {
     /// <p>The state of the export snapshot record.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<RecordState>,
 }
 
 /// <p>Describes the source of an export snapshot record.</p>
@@ -1367,8 +3561,9 @@ pub struct ExportSnapshotRecordSourceInfo {
     pub arn: Option<String>,
     /// <p>The date when the source instance or disk snapshot was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>A list of objects describing a disk snapshot.</p>
     #[serde(rename = "diskSnapshotInfo")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1392,7 +3587,81 @@ pub struct ExportSnapshotRecordSourceInfo {
     /// <p>The Lightsail resource type (e.g., <code>InstanceSnapshot</code> or <code>DiskSnapshot</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ExportSnapshotRecordSourceType>,
+}
+
+/// <p>The Lightsail resource type (e.g., <code>InstanceSnapshot</code> or <code>DiskSnapshot</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExportSnapshotRecordSourceType {
+    /// <p><code>InstanceSnapshot</code></p>
+    InstanceSnapshot,
+    /// <p><code>DiskSnapshot</code></p>
+    DiskSnapshot,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExportSnapshotRecordSourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportSnapshotRecordSourceType::InstanceSnapshot => "InstanceSnapshot",
+            ExportSnapshotRecordSourceType::DiskSnapshot => "DiskSnapshot",
+            ExportSnapshotRecordSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExportSnapshotRecordSourceType {
+    fn from(value: &'a str) -> ExportSnapshotRecordSourceType {
+        match value {
+            "InstanceSnapshot" => ExportSnapshotRecordSourceType::InstanceSnapshot,
+            "DiskSnapshot" => ExportSnapshotRecordSourceType::DiskSnapshot,
+            _ => ExportSnapshotRecordSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportSnapshotRecordSourceType {
+    fn from(value: String) -> ExportSnapshotRecordSourceType {
+        ExportSnapshotRecordSourceType::from(value.as_str())
+    }
+}
+
+impl From<ExportSnapshotRecordSourceType> for String {
+    fn from(value: ExportSnapshotRecordSourceType) -> String {
+        match value {
+            ExportSnapshotRecordSourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExportSnapshotRecordSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExportSnapshotRecordSourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportSnapshotRecordSourceType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ExportSnapshotRecordSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExportSnapshotRecordSourceType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1402,6 +3671,36 @@ pub struct ExportSnapshotRequest {
     pub source_snapshot_name: String,
 }
 
+impl ExportSnapshotRequest {
+    /// Returns a builder for `ExportSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> ExportSnapshotRequestBuilder {
+        ExportSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ExportSnapshotRequest`](struct.ExportSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct ExportSnapshotRequestBuilder {
+    source_snapshot_name: Option<String>,
+}
+
+impl ExportSnapshotRequestBuilder {
+    /// Sets the [`source_snapshot_name`](struct.ExportSnapshotRequest.html#structfield.source_snapshot_name) member.
+    pub fn source_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExportSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ExportSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(ExportSnapshotRequest {
+            source_snapshot_name: self
+                .source_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("source_snapshot_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ExportSnapshotResult {
@@ -1419,13 +3718,41 @@ pub struct GetActiveNamesRequest {
     pub page_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetActiveNamesResult {
-    /// <p>The list of active names returned by the get active names request.</p>
-    #[serde(rename = "activeNames")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub active_names: Option<Vec<String>>,
+impl GetActiveNamesRequest {
+    /// Returns a builder for `GetActiveNamesRequest`, checking its required members when built.
+    pub fn builder() -> GetActiveNamesRequestBuilder {
+        GetActiveNamesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetActiveNamesRequest`](struct.GetActiveNamesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetActiveNamesRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetActiveNamesRequestBuilder {
+    /// Sets the [`page_token`](struct.GetActiveNamesRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetActiveNamesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetActiveNamesRequest, ::rusoto_core::BuildError> {
+        Ok(GetActiveNamesRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetActiveNamesResult {
+    /// <p>The list of active names returned by the get active names request.</p>
+    #[serde(rename = "activeNames")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_names: Option<Vec<String>>,
     /// <p>A token used for advancing to the next page of results from your get active names request.</p>
     #[serde(rename = "nextPageToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1444,6 +3771,42 @@ pub struct GetBlueprintsRequest {
     pub page_token: Option<String>,
 }
 
+impl GetBlueprintsRequest {
+    /// Returns a builder for `GetBlueprintsRequest`, checking its required members when built.
+    pub fn builder() -> GetBlueprintsRequestBuilder {
+        GetBlueprintsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBlueprintsRequest`](struct.GetBlueprintsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBlueprintsRequestBuilder {
+    include_inactive: Option<bool>,
+    page_token: Option<String>,
+}
+
+impl GetBlueprintsRequestBuilder {
+    /// Sets the [`include_inactive`](struct.GetBlueprintsRequest.html#structfield.include_inactive) member.
+    pub fn include_inactive<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_inactive = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.GetBlueprintsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBlueprintsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetBlueprintsRequest, ::rusoto_core::BuildError> {
+        Ok(GetBlueprintsRequest {
+            include_inactive: self.include_inactive,
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBlueprintsResult {
@@ -1469,6 +3832,42 @@ pub struct GetBundlesRequest {
     pub page_token: Option<String>,
 }
 
+impl GetBundlesRequest {
+    /// Returns a builder for `GetBundlesRequest`, checking its required members when built.
+    pub fn builder() -> GetBundlesRequestBuilder {
+        GetBundlesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBundlesRequest`](struct.GetBundlesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBundlesRequestBuilder {
+    include_inactive: Option<bool>,
+    page_token: Option<String>,
+}
+
+impl GetBundlesRequestBuilder {
+    /// Sets the [`include_inactive`](struct.GetBundlesRequest.html#structfield.include_inactive) member.
+    pub fn include_inactive<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_inactive = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.GetBundlesRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBundlesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetBundlesRequest, ::rusoto_core::BuildError> {
+        Ok(GetBundlesRequest {
+            include_inactive: self.include_inactive,
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBundlesResult {
@@ -1490,6 +3889,37 @@ pub struct GetCloudFormationStackRecordsRequest {
     pub page_token: Option<String>,
 }
 
+impl GetCloudFormationStackRecordsRequest {
+    /// Returns a builder for `GetCloudFormationStackRecordsRequest`, checking its required members when built.
+    pub fn builder() -> GetCloudFormationStackRecordsRequestBuilder {
+        GetCloudFormationStackRecordsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCloudFormationStackRecordsRequest`](struct.GetCloudFormationStackRecordsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCloudFormationStackRecordsRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetCloudFormationStackRecordsRequestBuilder {
+    /// Sets the [`page_token`](struct.GetCloudFormationStackRecordsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCloudFormationStackRecordsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCloudFormationStackRecordsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetCloudFormationStackRecordsRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCloudFormationStackRecordsResult {
@@ -1510,6 +3940,36 @@ pub struct GetDiskRequest {
     pub disk_name: String,
 }
 
+impl GetDiskRequest {
+    /// Returns a builder for `GetDiskRequest`, checking its required members when built.
+    pub fn builder() -> GetDiskRequestBuilder {
+        GetDiskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDiskRequest`](struct.GetDiskRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDiskRequestBuilder {
+    disk_name: Option<String>,
+}
+
+impl GetDiskRequestBuilder {
+    /// Sets the [`disk_name`](struct.GetDiskRequest.html#structfield.disk_name) member.
+    pub fn disk_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDiskRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDiskRequest, ::rusoto_core::BuildError> {
+        Ok(GetDiskRequest {
+            disk_name: self
+                .disk_name
+                .ok_or(::rusoto_core::BuildError::missing("disk_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDiskResult {
@@ -1526,6 +3986,36 @@ pub struct GetDiskSnapshotRequest {
     pub disk_snapshot_name: String,
 }
 
+impl GetDiskSnapshotRequest {
+    /// Returns a builder for `GetDiskSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> GetDiskSnapshotRequestBuilder {
+        GetDiskSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDiskSnapshotRequest`](struct.GetDiskSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDiskSnapshotRequestBuilder {
+    disk_snapshot_name: Option<String>,
+}
+
+impl GetDiskSnapshotRequestBuilder {
+    /// Sets the [`disk_snapshot_name`](struct.GetDiskSnapshotRequest.html#structfield.disk_snapshot_name) member.
+    pub fn disk_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.disk_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDiskSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDiskSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(GetDiskSnapshotRequest {
+            disk_snapshot_name: self
+                .disk_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("disk_snapshot_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDiskSnapshotResult {
@@ -1543,6 +4033,36 @@ pub struct GetDiskSnapshotsRequest {
     pub page_token: Option<String>,
 }
 
+impl GetDiskSnapshotsRequest {
+    /// Returns a builder for `GetDiskSnapshotsRequest`, checking its required members when built.
+    pub fn builder() -> GetDiskSnapshotsRequestBuilder {
+        GetDiskSnapshotsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDiskSnapshotsRequest`](struct.GetDiskSnapshotsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDiskSnapshotsRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetDiskSnapshotsRequestBuilder {
+    /// Sets the [`page_token`](struct.GetDiskSnapshotsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDiskSnapshotsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetDiskSnapshotsRequest, ::rusoto_core::BuildError> {
+        Ok(GetDiskSnapshotsRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDiskSnapshotsResult {
@@ -1564,6 +4084,34 @@ pub struct GetDisksRequest {
     pub page_token: Option<String>,
 }
 
+impl GetDisksRequest {
+    /// Returns a builder for `GetDisksRequest`, checking its required members when built.
+    pub fn builder() -> GetDisksRequestBuilder {
+        GetDisksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDisksRequest`](struct.GetDisksRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDisksRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetDisksRequestBuilder {
+    /// Sets the [`page_token`](struct.GetDisksRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDisksRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDisksRequest, ::rusoto_core::BuildError> {
+        Ok(GetDisksRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDisksResult {
@@ -1584,6 +4132,36 @@ pub struct GetDomainRequest {
     pub domain_name: String,
 }
 
+impl GetDomainRequest {
+    /// Returns a builder for `GetDomainRequest`, checking its required members when built.
+    pub fn builder() -> GetDomainRequestBuilder {
+        GetDomainRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDomainRequest`](struct.GetDomainRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDomainRequestBuilder {
+    domain_name: Option<String>,
+}
+
+impl GetDomainRequestBuilder {
+    /// Sets the [`domain_name`](struct.GetDomainRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDomainRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDomainRequest, ::rusoto_core::BuildError> {
+        Ok(GetDomainRequest {
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDomainResult {
@@ -1601,6 +4179,34 @@ pub struct GetDomainsRequest {
     pub page_token: Option<String>,
 }
 
+impl GetDomainsRequest {
+    /// Returns a builder for `GetDomainsRequest`, checking its required members when built.
+    pub fn builder() -> GetDomainsRequestBuilder {
+        GetDomainsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDomainsRequest`](struct.GetDomainsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDomainsRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetDomainsRequestBuilder {
+    /// Sets the [`page_token`](struct.GetDomainsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDomainsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDomainsRequest, ::rusoto_core::BuildError> {
+        Ok(GetDomainsRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDomainsResult {
@@ -1622,6 +4228,36 @@ pub struct GetExportSnapshotRecordsRequest {
     pub page_token: Option<String>,
 }
 
+impl GetExportSnapshotRecordsRequest {
+    /// Returns a builder for `GetExportSnapshotRecordsRequest`, checking its required members when built.
+    pub fn builder() -> GetExportSnapshotRecordsRequestBuilder {
+        GetExportSnapshotRecordsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetExportSnapshotRecordsRequest`](struct.GetExportSnapshotRecordsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetExportSnapshotRecordsRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetExportSnapshotRecordsRequestBuilder {
+    /// Sets the [`page_token`](struct.GetExportSnapshotRecordsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetExportSnapshotRecordsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetExportSnapshotRecordsRequest, ::rusoto_core::BuildError> {
+        Ok(GetExportSnapshotRecordsRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetExportSnapshotRecordsResult {
@@ -1643,7 +4279,47 @@ pub struct GetInstanceAccessDetailsRequest {
     /// <p>The protocol to use to connect to your instance. Defaults to <code>ssh</code>.</p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<InstanceAccessProtocol>,
+}
+
+impl GetInstanceAccessDetailsRequest {
+    /// Returns a builder for `GetInstanceAccessDetailsRequest`, checking its required members when built.
+    pub fn builder() -> GetInstanceAccessDetailsRequestBuilder {
+        GetInstanceAccessDetailsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInstanceAccessDetailsRequest`](struct.GetInstanceAccessDetailsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInstanceAccessDetailsRequestBuilder {
+    instance_name: Option<String>,
+    protocol: Option<InstanceAccessProtocol>,
+}
+
+impl GetInstanceAccessDetailsRequestBuilder {
+    /// Sets the [`instance_name`](struct.GetInstanceAccessDetailsRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`protocol`](struct.GetInstanceAccessDetailsRequest.html#structfield.protocol) member.
+    pub fn protocol<T: Into<InstanceAccessProtocol>>(mut self, value: T) -> Self {
+        self.protocol = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInstanceAccessDetailsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetInstanceAccessDetailsRequest, ::rusoto_core::BuildError> {
+        Ok(GetInstanceAccessDetailsRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+            protocol: self.protocol,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1655,29 +4331,123 @@ pub struct GetInstanceAccessDetailsResult {
     pub access_details: Option<InstanceAccessDetails>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetInstanceMetricDataRequest {
     /// <p>The end time of the time period.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p>The name of the instance for which you want to get metrics data.</p>
     #[serde(rename = "instanceName")]
     pub instance_name: String,
     /// <p>The metric name to get data about. </p>
     #[serde(rename = "metricName")]
-    pub metric_name: String,
+    pub metric_name: InstanceMetricName,
     /// <p>The granularity, in seconds, of the returned data points.</p>
     #[serde(rename = "period")]
     pub period: i64,
     /// <p>The start time of the time period.</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
     /// <p>The instance statistics. </p>
     #[serde(rename = "statistics")]
-    pub statistics: Vec<String>,
+    pub statistics: Vec<MetricStatistic>,
     /// <p>The unit. The list of valid values is below.</p>
     #[serde(rename = "unit")]
-    pub unit: String,
+    pub unit: MetricUnit,
+}
+
+impl GetInstanceMetricDataRequest {
+    /// Returns a builder for `GetInstanceMetricDataRequest`, checking its required members when built.
+    pub fn builder() -> GetInstanceMetricDataRequestBuilder {
+        GetInstanceMetricDataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInstanceMetricDataRequest`](struct.GetInstanceMetricDataRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInstanceMetricDataRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    instance_name: Option<String>,
+    metric_name: Option<InstanceMetricName>,
+    period: Option<i64>,
+    start_time: Option<EpochTimestamp>,
+    statistics: Option<Vec<MetricStatistic>>,
+    unit: Option<MetricUnit>,
+}
+
+impl GetInstanceMetricDataRequestBuilder {
+    /// Sets the [`end_time`](struct.GetInstanceMetricDataRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_name`](struct.GetInstanceMetricDataRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.GetInstanceMetricDataRequest.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<InstanceMetricName>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`period`](struct.GetInstanceMetricDataRequest.html#structfield.period) member.
+    pub fn period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetInstanceMetricDataRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statistics`](struct.GetInstanceMetricDataRequest.html#structfield.statistics) member.
+    pub fn statistics<T: Into<Vec<MetricStatistic>>>(mut self, value: T) -> Self {
+        self.statistics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unit`](struct.GetInstanceMetricDataRequest.html#structfield.unit) member.
+    pub fn unit<T: Into<MetricUnit>>(mut self, value: T) -> Self {
+        self.unit = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInstanceMetricDataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetInstanceMetricDataRequest, ::rusoto_core::BuildError> {
+        Ok(GetInstanceMetricDataRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+            metric_name: self
+                .metric_name
+                .ok_or(::rusoto_core::BuildError::missing("metric_name"))?,
+            period: self
+                .period
+                .ok_or(::rusoto_core::BuildError::missing("period"))?,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+            statistics: self
+                .statistics
+                .ok_or(::rusoto_core::BuildError::missing("statistics"))?,
+            unit: self
+                .unit
+                .ok_or(::rusoto_core::BuildError::missing("unit"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1690,7 +4460,7 @@ pub struct GetInstanceMetricDataResult {
     /// <p>The metric name to return data for.</p>
     #[serde(rename = "metricName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metric_name: Option<String>,
+    pub metric_name: Option<InstanceMetricName>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1700,6 +4470,38 @@ pub struct GetInstancePortStatesRequest {
     pub instance_name: String,
 }
 
+impl GetInstancePortStatesRequest {
+    /// Returns a builder for `GetInstancePortStatesRequest`, checking its required members when built.
+    pub fn builder() -> GetInstancePortStatesRequestBuilder {
+        GetInstancePortStatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInstancePortStatesRequest`](struct.GetInstancePortStatesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInstancePortStatesRequestBuilder {
+    instance_name: Option<String>,
+}
+
+impl GetInstancePortStatesRequestBuilder {
+    /// Sets the [`instance_name`](struct.GetInstancePortStatesRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInstancePortStatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetInstancePortStatesRequest, ::rusoto_core::BuildError> {
+        Ok(GetInstancePortStatesRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetInstancePortStatesResult {
@@ -1716,6 +4518,36 @@ pub struct GetInstanceRequest {
     pub instance_name: String,
 }
 
+impl GetInstanceRequest {
+    /// Returns a builder for `GetInstanceRequest`, checking its required members when built.
+    pub fn builder() -> GetInstanceRequestBuilder {
+        GetInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInstanceRequest`](struct.GetInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInstanceRequestBuilder {
+    instance_name: Option<String>,
+}
+
+impl GetInstanceRequestBuilder {
+    /// Sets the [`instance_name`](struct.GetInstanceRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(GetInstanceRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetInstanceResult {
@@ -1732,6 +4564,38 @@ pub struct GetInstanceSnapshotRequest {
     pub instance_snapshot_name: String,
 }
 
+impl GetInstanceSnapshotRequest {
+    /// Returns a builder for `GetInstanceSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> GetInstanceSnapshotRequestBuilder {
+        GetInstanceSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInstanceSnapshotRequest`](struct.GetInstanceSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInstanceSnapshotRequestBuilder {
+    instance_snapshot_name: Option<String>,
+}
+
+impl GetInstanceSnapshotRequestBuilder {
+    /// Sets the [`instance_snapshot_name`](struct.GetInstanceSnapshotRequest.html#structfield.instance_snapshot_name) member.
+    pub fn instance_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInstanceSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetInstanceSnapshotRequest, ::rusoto_core::BuildError> {
+        Ok(GetInstanceSnapshotRequest {
+            instance_snapshot_name: self
+                .instance_snapshot_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_snapshot_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetInstanceSnapshotResult {
@@ -1749,6 +4613,36 @@ pub struct GetInstanceSnapshotsRequest {
     pub page_token: Option<String>,
 }
 
+impl GetInstanceSnapshotsRequest {
+    /// Returns a builder for `GetInstanceSnapshotsRequest`, checking its required members when built.
+    pub fn builder() -> GetInstanceSnapshotsRequestBuilder {
+        GetInstanceSnapshotsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInstanceSnapshotsRequest`](struct.GetInstanceSnapshotsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInstanceSnapshotsRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetInstanceSnapshotsRequestBuilder {
+    /// Sets the [`page_token`](struct.GetInstanceSnapshotsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInstanceSnapshotsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetInstanceSnapshotsRequest, ::rusoto_core::BuildError> {
+        Ok(GetInstanceSnapshotsRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetInstanceSnapshotsResult {
@@ -1769,6 +4663,38 @@ pub struct GetInstanceStateRequest {
     pub instance_name: String,
 }
 
+impl GetInstanceStateRequest {
+    /// Returns a builder for `GetInstanceStateRequest`, checking its required members when built.
+    pub fn builder() -> GetInstanceStateRequestBuilder {
+        GetInstanceStateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInstanceStateRequest`](struct.GetInstanceStateRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInstanceStateRequestBuilder {
+    instance_name: Option<String>,
+}
+
+impl GetInstanceStateRequestBuilder {
+    /// Sets the [`instance_name`](struct.GetInstanceStateRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInstanceStateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetInstanceStateRequest, ::rusoto_core::BuildError> {
+        Ok(GetInstanceStateRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetInstanceStateResult {
@@ -1786,6 +4712,34 @@ pub struct GetInstancesRequest {
     pub page_token: Option<String>,
 }
 
+impl GetInstancesRequest {
+    /// Returns a builder for `GetInstancesRequest`, checking its required members when built.
+    pub fn builder() -> GetInstancesRequestBuilder {
+        GetInstancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetInstancesRequest`](struct.GetInstancesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetInstancesRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetInstancesRequestBuilder {
+    /// Sets the [`page_token`](struct.GetInstancesRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetInstancesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetInstancesRequest, ::rusoto_core::BuildError> {
+        Ok(GetInstancesRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetInstancesResult {
@@ -1806,6 +4760,36 @@ pub struct GetKeyPairRequest {
     pub key_pair_name: String,
 }
 
+impl GetKeyPairRequest {
+    /// Returns a builder for `GetKeyPairRequest`, checking its required members when built.
+    pub fn builder() -> GetKeyPairRequestBuilder {
+        GetKeyPairRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetKeyPairRequest`](struct.GetKeyPairRequest.html).
+#[derive(Default, Debug)]
+pub struct GetKeyPairRequestBuilder {
+    key_pair_name: Option<String>,
+}
+
+impl GetKeyPairRequestBuilder {
+    /// Sets the [`key_pair_name`](struct.GetKeyPairRequest.html#structfield.key_pair_name) member.
+    pub fn key_pair_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_pair_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetKeyPairRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetKeyPairRequest, ::rusoto_core::BuildError> {
+        Ok(GetKeyPairRequest {
+            key_pair_name: self
+                .key_pair_name
+                .ok_or(::rusoto_core::BuildError::missing("key_pair_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetKeyPairResult {
@@ -1823,6 +4807,34 @@ pub struct GetKeyPairsRequest {
     pub page_token: Option<String>,
 }
 
+impl GetKeyPairsRequest {
+    /// Returns a builder for `GetKeyPairsRequest`, checking its required members when built.
+    pub fn builder() -> GetKeyPairsRequestBuilder {
+        GetKeyPairsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetKeyPairsRequest`](struct.GetKeyPairsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetKeyPairsRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetKeyPairsRequestBuilder {
+    /// Sets the [`page_token`](struct.GetKeyPairsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetKeyPairsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetKeyPairsRequest, ::rusoto_core::BuildError> {
+        Ok(GetKeyPairsRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetKeyPairsResult {
@@ -1836,29 +4848,123 @@ pub struct GetKeyPairsResult {
     pub next_page_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetLoadBalancerMetricDataRequest {
     /// <p>The end time of the period.</p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p>The name of the load balancer.</p>
     #[serde(rename = "loadBalancerName")]
     pub load_balancer_name: String,
     /// <p><p>The metric about which you want to return information. Valid values are listed below, along with the most useful <code>statistics</code> to include in your request.</p> <ul> <li> <p> <b> <code>ClientTLSNegotiationErrorCount</code> </b> - The number of TLS connections initiated by the client that did not establish a session with the load balancer. Possible causes include a mismatch of ciphers or protocols.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>.</p> </li> <li> <p> <b> <code>HealthyHostCount</code> </b> - The number of target instances that are considered healthy.</p> <p> <code>Statistics</code>: The most useful statistic are <code>Average</code>, <code>Minimum</code>, and <code>Maximum</code>.</p> </li> <li> <p> <b> <code>UnhealthyHostCount</code> </b> - The number of target instances that are considered unhealthy.</p> <p> <code>Statistics</code>: The most useful statistic are <code>Average</code>, <code>Minimum</code>, and <code>Maximum</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>LB</em>4XX<em>Count</code> </b> - The number of HTTP 4XX client error codes that originate from the load balancer. Client errors are generated when requests are malformed or incomplete. These requests have not been received by the target instance. This count does not include any response codes generated by the target instances.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>LB<em>5XX</em>Count</code> </b> - The number of HTTP 5XX server error codes that originate from the load balancer. This count does not include any response codes generated by the target instances.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>Instance</em>2XX<em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>Instance<em>3XX</em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer. </p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>Instance</em>4XX<em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>Instance<em>5XX</em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>InstanceResponseTime</code> </b> - The time elapsed, in seconds, after the request leaves the load balancer until a response from the target instance is received.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Average</code>.</p> </li> <li> <p> <b> <code>RejectedConnectionCount</code> </b> - The number of connections that were rejected because the load balancer had reached its maximum number of connections.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>.</p> </li> <li> <p> <b> <code>RequestCount</code> </b> - The number of requests processed over IPv4. This count includes only the requests with a response generated by a target instance of the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> </ul></p>
     #[serde(rename = "metricName")]
-    pub metric_name: String,
+    pub metric_name: LoadBalancerMetricName,
     /// <p>The granularity, in seconds, of the returned data points.</p>
     #[serde(rename = "period")]
     pub period: i64,
     /// <p>The start time of the period.</p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
     /// <p><p>An array of statistics that you want to request metrics for. Valid values are listed below.</p> <ul> <li> <p> <b> <code>SampleCount</code> </b> - The count (number) of data points used for the statistical calculation.</p> </li> <li> <p> <b> <code>Average</code> </b> - The value of Sum / SampleCount during the specified period. By comparing this statistic with the Minimum and Maximum, you can determine the full scope of a metric and how close the average use is to the Minimum and Maximum. This comparison helps you to know when to increase or decrease your resources as needed.</p> </li> <li> <p> <b> <code>Sum</code> </b> - All values submitted for the matching metric added together. This statistic can be useful for determining the total volume of a metric.</p> </li> <li> <p> <b> <code>Minimum</code> </b> - The lowest value observed during the specified period. You can use this value to determine low volumes of activity for your application.</p> </li> <li> <p> <b> <code>Maximum</code> </b> - The highest value observed during the specified period. You can use this value to determine high volumes of activity for your application.</p> </li> </ul></p>
     #[serde(rename = "statistics")]
-    pub statistics: Vec<String>,
+    pub statistics: Vec<MetricStatistic>,
     /// <p>The unit for the time period request. Valid values are listed below.</p>
     #[serde(rename = "unit")]
-    pub unit: String,
+    pub unit: MetricUnit,
+}
+
+impl GetLoadBalancerMetricDataRequest {
+    /// Returns a builder for `GetLoadBalancerMetricDataRequest`, checking its required members when built.
+    pub fn builder() -> GetLoadBalancerMetricDataRequestBuilder {
+        GetLoadBalancerMetricDataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLoadBalancerMetricDataRequest`](struct.GetLoadBalancerMetricDataRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLoadBalancerMetricDataRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    load_balancer_name: Option<String>,
+    metric_name: Option<LoadBalancerMetricName>,
+    period: Option<i64>,
+    start_time: Option<EpochTimestamp>,
+    statistics: Option<Vec<MetricStatistic>>,
+    unit: Option<MetricUnit>,
+}
+
+impl GetLoadBalancerMetricDataRequestBuilder {
+    /// Sets the [`end_time`](struct.GetLoadBalancerMetricDataRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.GetLoadBalancerMetricDataRequest.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.GetLoadBalancerMetricDataRequest.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<LoadBalancerMetricName>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`period`](struct.GetLoadBalancerMetricDataRequest.html#structfield.period) member.
+    pub fn period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetLoadBalancerMetricDataRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statistics`](struct.GetLoadBalancerMetricDataRequest.html#structfield.statistics) member.
+    pub fn statistics<T: Into<Vec<MetricStatistic>>>(mut self, value: T) -> Self {
+        self.statistics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unit`](struct.GetLoadBalancerMetricDataRequest.html#structfield.unit) member.
+    pub fn unit<T: Into<MetricUnit>>(mut self, value: T) -> Self {
+        self.unit = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLoadBalancerMetricDataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLoadBalancerMetricDataRequest, ::rusoto_core::BuildError> {
+        Ok(GetLoadBalancerMetricDataRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+            metric_name: self
+                .metric_name
+                .ok_or(::rusoto_core::BuildError::missing("metric_name"))?,
+            period: self
+                .period
+                .ok_or(::rusoto_core::BuildError::missing("period"))?,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+            statistics: self
+                .statistics
+                .ok_or(::rusoto_core::BuildError::missing("statistics"))?,
+            unit: self
+                .unit
+                .ok_or(::rusoto_core::BuildError::missing("unit"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1871,7 +4977,7 @@ pub struct GetLoadBalancerMetricDataResult {
     /// <p><p>The metric about which you are receiving information. Valid values are listed below, along with the most useful <code>statistics</code> to include in your request.</p> <ul> <li> <p> <b> <code>ClientTLSNegotiationErrorCount</code> </b> - The number of TLS connections initiated by the client that did not establish a session with the load balancer. Possible causes include a mismatch of ciphers or protocols.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>.</p> </li> <li> <p> <b> <code>HealthyHostCount</code> </b> - The number of target instances that are considered healthy.</p> <p> <code>Statistics</code>: The most useful statistic are <code>Average</code>, <code>Minimum</code>, and <code>Maximum</code>.</p> </li> <li> <p> <b> <code>UnhealthyHostCount</code> </b> - The number of target instances that are considered unhealthy.</p> <p> <code>Statistics</code>: The most useful statistic are <code>Average</code>, <code>Minimum</code>, and <code>Maximum</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>LB</em>4XX<em>Count</code> </b> - The number of HTTP 4XX client error codes that originate from the load balancer. Client errors are generated when requests are malformed or incomplete. These requests have not been received by the target instance. This count does not include any response codes generated by the target instances.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>LB<em>5XX</em>Count</code> </b> - The number of HTTP 5XX server error codes that originate from the load balancer. This count does not include any response codes generated by the target instances.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>Instance</em>2XX<em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>Instance<em>3XX</em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer. </p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>Instance</em>4XX<em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>Instance<em>5XX</em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>InstanceResponseTime</code> </b> - The time elapsed, in seconds, after the request leaves the load balancer until a response from the target instance is received.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Average</code>.</p> </li> <li> <p> <b> <code>RejectedConnectionCount</code> </b> - The number of connections that were rejected because the load balancer had reached its maximum number of connections.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>.</p> </li> <li> <p> <b> <code>RequestCount</code> </b> - The number of requests processed over IPv4. This count includes only the requests with a response generated by a target instance of the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> </ul></p>
     #[serde(rename = "metricName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metric_name: Option<String>,
+    pub metric_name: Option<LoadBalancerMetricName>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1881,6 +4987,36 @@ pub struct GetLoadBalancerRequest {
     pub load_balancer_name: String,
 }
 
+impl GetLoadBalancerRequest {
+    /// Returns a builder for `GetLoadBalancerRequest`, checking its required members when built.
+    pub fn builder() -> GetLoadBalancerRequestBuilder {
+        GetLoadBalancerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLoadBalancerRequest`](struct.GetLoadBalancerRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLoadBalancerRequestBuilder {
+    load_balancer_name: Option<String>,
+}
+
+impl GetLoadBalancerRequestBuilder {
+    /// Sets the [`load_balancer_name`](struct.GetLoadBalancerRequest.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLoadBalancerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetLoadBalancerRequest, ::rusoto_core::BuildError> {
+        Ok(GetLoadBalancerRequest {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLoadBalancerResult {
@@ -1897,11 +5033,44 @@ pub struct GetLoadBalancerTlsCertificatesRequest {
     pub load_balancer_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetLoadBalancerTlsCertificatesResult {
-    /// <p>An array of LoadBalancerTlsCertificate objects describing your SSL/TLS certificates.</p>
-    #[serde(rename = "tlsCertificates")]
+impl GetLoadBalancerTlsCertificatesRequest {
+    /// Returns a builder for `GetLoadBalancerTlsCertificatesRequest`, checking its required members when built.
+    pub fn builder() -> GetLoadBalancerTlsCertificatesRequestBuilder {
+        GetLoadBalancerTlsCertificatesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLoadBalancerTlsCertificatesRequest`](struct.GetLoadBalancerTlsCertificatesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLoadBalancerTlsCertificatesRequestBuilder {
+    load_balancer_name: Option<String>,
+}
+
+impl GetLoadBalancerTlsCertificatesRequestBuilder {
+    /// Sets the [`load_balancer_name`](struct.GetLoadBalancerTlsCertificatesRequest.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLoadBalancerTlsCertificatesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLoadBalancerTlsCertificatesRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetLoadBalancerTlsCertificatesRequest {
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetLoadBalancerTlsCertificatesResult {
+    /// <p>An array of LoadBalancerTlsCertificate objects describing your SSL/TLS certificates.</p>
+    #[serde(rename = "tlsCertificates")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tls_certificates: Option<Vec<LoadBalancerTlsCertificate>>,
 }
@@ -1914,6 +5083,36 @@ pub struct GetLoadBalancersRequest {
     pub page_token: Option<String>,
 }
 
+impl GetLoadBalancersRequest {
+    /// Returns a builder for `GetLoadBalancersRequest`, checking its required members when built.
+    pub fn builder() -> GetLoadBalancersRequestBuilder {
+        GetLoadBalancersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLoadBalancersRequest`](struct.GetLoadBalancersRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLoadBalancersRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetLoadBalancersRequestBuilder {
+    /// Sets the [`page_token`](struct.GetLoadBalancersRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLoadBalancersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLoadBalancersRequest, ::rusoto_core::BuildError> {
+        Ok(GetLoadBalancersRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLoadBalancersResult {
@@ -1934,6 +5133,36 @@ pub struct GetOperationRequest {
     pub operation_id: String,
 }
 
+impl GetOperationRequest {
+    /// Returns a builder for `GetOperationRequest`, checking its required members when built.
+    pub fn builder() -> GetOperationRequestBuilder {
+        GetOperationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetOperationRequest`](struct.GetOperationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetOperationRequestBuilder {
+    operation_id: Option<String>,
+}
+
+impl GetOperationRequestBuilder {
+    /// Sets the [`operation_id`](struct.GetOperationRequest.html#structfield.operation_id) member.
+    pub fn operation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.operation_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOperationRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetOperationRequest, ::rusoto_core::BuildError> {
+        Ok(GetOperationRequest {
+            operation_id: self
+                .operation_id
+                .ok_or(::rusoto_core::BuildError::missing("operation_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetOperationResult {
@@ -1954,6 +5183,46 @@ pub struct GetOperationsForResourceRequest {
     pub resource_name: String,
 }
 
+impl GetOperationsForResourceRequest {
+    /// Returns a builder for `GetOperationsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> GetOperationsForResourceRequestBuilder {
+        GetOperationsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetOperationsForResourceRequest`](struct.GetOperationsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct GetOperationsForResourceRequestBuilder {
+    page_token: Option<String>,
+    resource_name: Option<String>,
+}
+
+impl GetOperationsForResourceRequestBuilder {
+    /// Sets the [`page_token`](struct.GetOperationsForResourceRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_name`](struct.GetOperationsForResourceRequest.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOperationsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetOperationsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(GetOperationsForResourceRequest {
+            page_token: self.page_token,
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetOperationsForResourceResult {
@@ -1975,6 +5244,34 @@ pub struct GetOperationsRequest {
     pub page_token: Option<String>,
 }
 
+impl GetOperationsRequest {
+    /// Returns a builder for `GetOperationsRequest`, checking its required members when built.
+    pub fn builder() -> GetOperationsRequestBuilder {
+        GetOperationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetOperationsRequest`](struct.GetOperationsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetOperationsRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetOperationsRequestBuilder {
+    /// Sets the [`page_token`](struct.GetOperationsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetOperationsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetOperationsRequest, ::rusoto_core::BuildError> {
+        Ok(GetOperationsRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetOperationsResult {
@@ -2000,6 +5297,46 @@ pub struct GetRegionsRequest {
     pub include_relational_database_availability_zones: Option<bool>,
 }
 
+impl GetRegionsRequest {
+    /// Returns a builder for `GetRegionsRequest`, checking its required members when built.
+    pub fn builder() -> GetRegionsRequestBuilder {
+        GetRegionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRegionsRequest`](struct.GetRegionsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRegionsRequestBuilder {
+    include_availability_zones: Option<bool>,
+    include_relational_database_availability_zones: Option<bool>,
+}
+
+impl GetRegionsRequestBuilder {
+    /// Sets the [`include_availability_zones`](struct.GetRegionsRequest.html#structfield.include_availability_zones) member.
+    pub fn include_availability_zones<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_relational_database_availability_zones`](struct.GetRegionsRequest.html#structfield.include_relational_database_availability_zones) member.
+    pub fn include_relational_database_availability_zones<T: Into<bool>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.include_relational_database_availability_zones = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRegionsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRegionsRequest, ::rusoto_core::BuildError> {
+        Ok(GetRegionsRequest {
+            include_availability_zones: self.include_availability_zones,
+            include_relational_database_availability_zones: self
+                .include_relational_database_availability_zones,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRegionsResult {
@@ -2017,6 +5354,37 @@ pub struct GetRelationalDatabaseBlueprintsRequest {
     pub page_token: Option<String>,
 }
 
+impl GetRelationalDatabaseBlueprintsRequest {
+    /// Returns a builder for `GetRelationalDatabaseBlueprintsRequest`, checking its required members when built.
+    pub fn builder() -> GetRelationalDatabaseBlueprintsRequestBuilder {
+        GetRelationalDatabaseBlueprintsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRelationalDatabaseBlueprintsRequest`](struct.GetRelationalDatabaseBlueprintsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRelationalDatabaseBlueprintsRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetRelationalDatabaseBlueprintsRequestBuilder {
+    /// Sets the [`page_token`](struct.GetRelationalDatabaseBlueprintsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRelationalDatabaseBlueprintsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRelationalDatabaseBlueprintsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetRelationalDatabaseBlueprintsRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRelationalDatabaseBlueprintsResult {
@@ -2038,6 +5406,36 @@ pub struct GetRelationalDatabaseBundlesRequest {
     pub page_token: Option<String>,
 }
 
+impl GetRelationalDatabaseBundlesRequest {
+    /// Returns a builder for `GetRelationalDatabaseBundlesRequest`, checking its required members when built.
+    pub fn builder() -> GetRelationalDatabaseBundlesRequestBuilder {
+        GetRelationalDatabaseBundlesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRelationalDatabaseBundlesRequest`](struct.GetRelationalDatabaseBundlesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRelationalDatabaseBundlesRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetRelationalDatabaseBundlesRequestBuilder {
+    /// Sets the [`page_token`](struct.GetRelationalDatabaseBundlesRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRelationalDatabaseBundlesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRelationalDatabaseBundlesRequest, ::rusoto_core::BuildError> {
+        Ok(GetRelationalDatabaseBundlesRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRelationalDatabaseBundlesResult {
@@ -2066,6 +5464,54 @@ pub struct GetRelationalDatabaseEventsRequest {
     pub relational_database_name: String,
 }
 
+impl GetRelationalDatabaseEventsRequest {
+    /// Returns a builder for `GetRelationalDatabaseEventsRequest`, checking its required members when built.
+    pub fn builder() -> GetRelationalDatabaseEventsRequestBuilder {
+        GetRelationalDatabaseEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRelationalDatabaseEventsRequest`](struct.GetRelationalDatabaseEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRelationalDatabaseEventsRequestBuilder {
+    duration_in_minutes: Option<i64>,
+    page_token: Option<String>,
+    relational_database_name: Option<String>,
+}
+
+impl GetRelationalDatabaseEventsRequestBuilder {
+    /// Sets the [`duration_in_minutes`](struct.GetRelationalDatabaseEventsRequest.html#structfield.duration_in_minutes) member.
+    pub fn duration_in_minutes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration_in_minutes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.GetRelationalDatabaseEventsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_name`](struct.GetRelationalDatabaseEventsRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRelationalDatabaseEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRelationalDatabaseEventsRequest, ::rusoto_core::BuildError> {
+        Ok(GetRelationalDatabaseEventsRequest {
+            duration_in_minutes: self.duration_in_minutes,
+            page_token: self.page_token,
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRelationalDatabaseEventsResult {
@@ -2083,8 +5529,9 @@ pub struct GetRelationalDatabaseEventsResult {
 pub struct GetRelationalDatabaseLogEventsRequest {
     /// <p><p>The end of the time interval from which to get log events.</p> <p>Constraints:</p> <ul> <li> <p>Specified in Universal Coordinated Time (UTC).</p> </li> <li> <p>Specified in the Unix time format.</p> <p>For example, if you wish to use an end time of October 1, 2018, at 8 PM UTC, then you input <code>1538424000</code> as the end time.</p> </li> </ul></p>
     #[serde(rename = "endTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end_time: Option<f64>,
+    pub end_time: Option<EpochTimestamp>,
     /// <p>The name of the log stream.</p> <p>Use the <code>get relational database log streams</code> operation to get a list of available log streams.</p>
     #[serde(rename = "logStreamName")]
     pub log_stream_name: String,
@@ -2101,8 +5548,84 @@ pub struct GetRelationalDatabaseLogEventsRequest {
     pub start_from_head: Option<bool>,
     /// <p><p>The start of the time interval from which to get log events.</p> <p>Constraints:</p> <ul> <li> <p>Specified in Universal Coordinated Time (UTC).</p> </li> <li> <p>Specified in the Unix time format.</p> <p>For example, if you wish to use a start time of October 1, 2018, at 8 PM UTC, then you input <code>1538424000</code> as the start time.</p> </li> </ul></p>
     #[serde(rename = "startTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub start_time: Option<f64>,
+    pub start_time: Option<EpochTimestamp>,
+}
+
+impl GetRelationalDatabaseLogEventsRequest {
+    /// Returns a builder for `GetRelationalDatabaseLogEventsRequest`, checking its required members when built.
+    pub fn builder() -> GetRelationalDatabaseLogEventsRequestBuilder {
+        GetRelationalDatabaseLogEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRelationalDatabaseLogEventsRequest`](struct.GetRelationalDatabaseLogEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRelationalDatabaseLogEventsRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    log_stream_name: Option<String>,
+    page_token: Option<String>,
+    relational_database_name: Option<String>,
+    start_from_head: Option<bool>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl GetRelationalDatabaseLogEventsRequestBuilder {
+    /// Sets the [`end_time`](struct.GetRelationalDatabaseLogEventsRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_stream_name`](struct.GetRelationalDatabaseLogEventsRequest.html#structfield.log_stream_name) member.
+    pub fn log_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`page_token`](struct.GetRelationalDatabaseLogEventsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_name`](struct.GetRelationalDatabaseLogEventsRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_from_head`](struct.GetRelationalDatabaseLogEventsRequest.html#structfield.start_from_head) member.
+    pub fn start_from_head<T: Into<bool>>(mut self, value: T) -> Self {
+        self.start_from_head = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetRelationalDatabaseLogEventsRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRelationalDatabaseLogEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRelationalDatabaseLogEventsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetRelationalDatabaseLogEventsRequest {
+            end_time: self.end_time,
+            log_stream_name: self
+                .log_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("log_stream_name"))?,
+            page_token: self.page_token,
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+            start_from_head: self.start_from_head,
+            start_time: self.start_time,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2129,6 +5652,39 @@ pub struct GetRelationalDatabaseLogStreamsRequest {
     pub relational_database_name: String,
 }
 
+impl GetRelationalDatabaseLogStreamsRequest {
+    /// Returns a builder for `GetRelationalDatabaseLogStreamsRequest`, checking its required members when built.
+    pub fn builder() -> GetRelationalDatabaseLogStreamsRequestBuilder {
+        GetRelationalDatabaseLogStreamsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRelationalDatabaseLogStreamsRequest`](struct.GetRelationalDatabaseLogStreamsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRelationalDatabaseLogStreamsRequestBuilder {
+    relational_database_name: Option<String>,
+}
+
+impl GetRelationalDatabaseLogStreamsRequestBuilder {
+    /// Sets the [`relational_database_name`](struct.GetRelationalDatabaseLogStreamsRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRelationalDatabaseLogStreamsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRelationalDatabaseLogStreamsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetRelationalDatabaseLogStreamsRequest {
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRelationalDatabaseLogStreamsResult {
@@ -2143,33 +5699,81 @@ pub struct GetRelationalDatabaseMasterUserPasswordRequest {
     /// <p>The password version to return.</p> <p>Specifying <code>CURRENT</code> or <code>PREVIOUS</code> returns the current or previous passwords respectively. Specifying <code>PENDING</code> returns the newest version of the password that will rotate to <code>CURRENT</code>. After the <code>PENDING</code> password rotates to <code>CURRENT</code>, the <code>PENDING</code> password is no longer available.</p> <p>Default: <code>CURRENT</code> </p>
     #[serde(rename = "passwordVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub password_version: Option<String>,
+    pub password_version: Option<RelationalDatabasePasswordVersion>,
     /// <p>The name of your database for which to get the master user password.</p>
     #[serde(rename = "relationalDatabaseName")]
     pub relational_database_name: String,
 }
 
+impl GetRelationalDatabaseMasterUserPasswordRequest {
+    /// Returns a builder for `GetRelationalDatabaseMasterUserPasswordRequest`, checking its required members when built.
+    pub fn builder() -> GetRelationalDatabaseMasterUserPasswordRequestBuilder {
+        GetRelationalDatabaseMasterUserPasswordRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRelationalDatabaseMasterUserPasswordRequest`](struct.GetRelationalDatabaseMasterUserPasswordRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRelationalDatabaseMasterUserPasswordRequestBuilder {
+    password_version: Option<RelationalDatabasePasswordVersion>,
+    relational_database_name: Option<String>,
+}
+
+impl GetRelationalDatabaseMasterUserPasswordRequestBuilder {
+    /// Sets the [`password_version`](struct.GetRelationalDatabaseMasterUserPasswordRequest.html#structfield.password_version) member.
+    pub fn password_version<T: Into<RelationalDatabasePasswordVersion>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.password_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_name`](struct.GetRelationalDatabaseMasterUserPasswordRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRelationalDatabaseMasterUserPasswordRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        GetRelationalDatabaseMasterUserPasswordRequest,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(GetRelationalDatabaseMasterUserPasswordRequest {
+            password_version: self.password_version,
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRelationalDatabaseMasterUserPasswordResult {
     /// <p>The timestamp when the specified version of the master user password was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The master user password for the <code>password version</code> specified.</p>
     #[serde(rename = "masterUserPassword")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub master_user_password: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetRelationalDatabaseMetricDataRequest {
     /// <p><p>The end of the time interval from which to get metric data.</p> <p>Constraints:</p> <ul> <li> <p>Specified in Universal Coordinated Time (UTC).</p> </li> <li> <p>Specified in the Unix time format.</p> <p>For example, if you wish to use an end time of October 1, 2018, at 8 PM UTC, then you input <code>1538424000</code> as the end time.</p> </li> </ul></p>
     #[serde(rename = "endTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p>The name of the metric data to return.</p>
     #[serde(rename = "metricName")]
-    pub metric_name: String,
+    pub metric_name: RelationalDatabaseMetricName,
     /// <p>The granularity, in seconds, of the returned data points.</p>
     #[serde(rename = "period")]
     pub period: i64,
@@ -2178,13 +5782,107 @@ pub struct GetRelationalDatabaseMetricDataRequest {
     pub relational_database_name: String,
     /// <p><p>The start of the time interval from which to get metric data.</p> <p>Constraints:</p> <ul> <li> <p>Specified in Universal Coordinated Time (UTC).</p> </li> <li> <p>Specified in the Unix time format.</p> <p>For example, if you wish to use a start time of October 1, 2018, at 8 PM UTC, then you input <code>1538424000</code> as the start time.</p> </li> </ul></p>
     #[serde(rename = "startTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
     /// <p>The array of statistics for your metric data request.</p>
     #[serde(rename = "statistics")]
-    pub statistics: Vec<String>,
+    pub statistics: Vec<MetricStatistic>,
     /// <p>The unit for the metric data request.</p>
     #[serde(rename = "unit")]
-    pub unit: String,
+    pub unit: MetricUnit,
+}
+
+impl GetRelationalDatabaseMetricDataRequest {
+    /// Returns a builder for `GetRelationalDatabaseMetricDataRequest`, checking its required members when built.
+    pub fn builder() -> GetRelationalDatabaseMetricDataRequestBuilder {
+        GetRelationalDatabaseMetricDataRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRelationalDatabaseMetricDataRequest`](struct.GetRelationalDatabaseMetricDataRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRelationalDatabaseMetricDataRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    metric_name: Option<RelationalDatabaseMetricName>,
+    period: Option<i64>,
+    relational_database_name: Option<String>,
+    start_time: Option<EpochTimestamp>,
+    statistics: Option<Vec<MetricStatistic>>,
+    unit: Option<MetricUnit>,
+}
+
+impl GetRelationalDatabaseMetricDataRequestBuilder {
+    /// Sets the [`end_time`](struct.GetRelationalDatabaseMetricDataRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.GetRelationalDatabaseMetricDataRequest.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<RelationalDatabaseMetricName>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`period`](struct.GetRelationalDatabaseMetricDataRequest.html#structfield.period) member.
+    pub fn period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_name`](struct.GetRelationalDatabaseMetricDataRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetRelationalDatabaseMetricDataRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`statistics`](struct.GetRelationalDatabaseMetricDataRequest.html#structfield.statistics) member.
+    pub fn statistics<T: Into<Vec<MetricStatistic>>>(mut self, value: T) -> Self {
+        self.statistics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unit`](struct.GetRelationalDatabaseMetricDataRequest.html#structfield.unit) member.
+    pub fn unit<T: Into<MetricUnit>>(mut self, value: T) -> Self {
+        self.unit = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRelationalDatabaseMetricDataRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRelationalDatabaseMetricDataRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetRelationalDatabaseMetricDataRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            metric_name: self
+                .metric_name
+                .ok_or(::rusoto_core::BuildError::missing("metric_name"))?,
+            period: self
+                .period
+                .ok_or(::rusoto_core::BuildError::missing("period"))?,
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+            statistics: self
+                .statistics
+                .ok_or(::rusoto_core::BuildError::missing("statistics"))?,
+            unit: self
+                .unit
+                .ok_or(::rusoto_core::BuildError::missing("unit"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -2197,7 +5895,7 @@ pub struct GetRelationalDatabaseMetricDataResult {
     /// <p>The name of the metric.</p>
     #[serde(rename = "metricName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metric_name: Option<String>,
+    pub metric_name: Option<RelationalDatabaseMetricName>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2211,6 +5909,47 @@ pub struct GetRelationalDatabaseParametersRequest {
     pub relational_database_name: String,
 }
 
+impl GetRelationalDatabaseParametersRequest {
+    /// Returns a builder for `GetRelationalDatabaseParametersRequest`, checking its required members when built.
+    pub fn builder() -> GetRelationalDatabaseParametersRequestBuilder {
+        GetRelationalDatabaseParametersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRelationalDatabaseParametersRequest`](struct.GetRelationalDatabaseParametersRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRelationalDatabaseParametersRequestBuilder {
+    page_token: Option<String>,
+    relational_database_name: Option<String>,
+}
+
+impl GetRelationalDatabaseParametersRequestBuilder {
+    /// Sets the [`page_token`](struct.GetRelationalDatabaseParametersRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_name`](struct.GetRelationalDatabaseParametersRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRelationalDatabaseParametersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRelationalDatabaseParametersRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetRelationalDatabaseParametersRequest {
+            page_token: self.page_token,
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRelationalDatabaseParametersResult {
@@ -2231,6 +5970,38 @@ pub struct GetRelationalDatabaseRequest {
     pub relational_database_name: String,
 }
 
+impl GetRelationalDatabaseRequest {
+    /// Returns a builder for `GetRelationalDatabaseRequest`, checking its required members when built.
+    pub fn builder() -> GetRelationalDatabaseRequestBuilder {
+        GetRelationalDatabaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRelationalDatabaseRequest`](struct.GetRelationalDatabaseRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRelationalDatabaseRequestBuilder {
+    relational_database_name: Option<String>,
+}
+
+impl GetRelationalDatabaseRequestBuilder {
+    /// Sets the [`relational_database_name`](struct.GetRelationalDatabaseRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRelationalDatabaseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRelationalDatabaseRequest, ::rusoto_core::BuildError> {
+        Ok(GetRelationalDatabaseRequest {
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRelationalDatabaseResult {
@@ -2247,6 +6018,39 @@ pub struct GetRelationalDatabaseSnapshotRequest {
     pub relational_database_snapshot_name: String,
 }
 
+impl GetRelationalDatabaseSnapshotRequest {
+    /// Returns a builder for `GetRelationalDatabaseSnapshotRequest`, checking its required members when built.
+    pub fn builder() -> GetRelationalDatabaseSnapshotRequestBuilder {
+        GetRelationalDatabaseSnapshotRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRelationalDatabaseSnapshotRequest`](struct.GetRelationalDatabaseSnapshotRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRelationalDatabaseSnapshotRequestBuilder {
+    relational_database_snapshot_name: Option<String>,
+}
+
+impl GetRelationalDatabaseSnapshotRequestBuilder {
+    /// Sets the [`relational_database_snapshot_name`](struct.GetRelationalDatabaseSnapshotRequest.html#structfield.relational_database_snapshot_name) member.
+    pub fn relational_database_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRelationalDatabaseSnapshotRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRelationalDatabaseSnapshotRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetRelationalDatabaseSnapshotRequest {
+            relational_database_snapshot_name: self.relational_database_snapshot_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_snapshot_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRelationalDatabaseSnapshotResult {
@@ -2264,6 +6068,37 @@ pub struct GetRelationalDatabaseSnapshotsRequest {
     pub page_token: Option<String>,
 }
 
+impl GetRelationalDatabaseSnapshotsRequest {
+    /// Returns a builder for `GetRelationalDatabaseSnapshotsRequest`, checking its required members when built.
+    pub fn builder() -> GetRelationalDatabaseSnapshotsRequestBuilder {
+        GetRelationalDatabaseSnapshotsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRelationalDatabaseSnapshotsRequest`](struct.GetRelationalDatabaseSnapshotsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRelationalDatabaseSnapshotsRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetRelationalDatabaseSnapshotsRequestBuilder {
+    /// Sets the [`page_token`](struct.GetRelationalDatabaseSnapshotsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRelationalDatabaseSnapshotsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRelationalDatabaseSnapshotsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(GetRelationalDatabaseSnapshotsRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRelationalDatabaseSnapshotsResult {
@@ -2285,6 +6120,36 @@ pub struct GetRelationalDatabasesRequest {
     pub page_token: Option<String>,
 }
 
+impl GetRelationalDatabasesRequest {
+    /// Returns a builder for `GetRelationalDatabasesRequest`, checking its required members when built.
+    pub fn builder() -> GetRelationalDatabasesRequestBuilder {
+        GetRelationalDatabasesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRelationalDatabasesRequest`](struct.GetRelationalDatabasesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRelationalDatabasesRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetRelationalDatabasesRequestBuilder {
+    /// Sets the [`page_token`](struct.GetRelationalDatabasesRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRelationalDatabasesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetRelationalDatabasesRequest, ::rusoto_core::BuildError> {
+        Ok(GetRelationalDatabasesRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRelationalDatabasesResult {
@@ -2305,16 +6170,46 @@ pub struct GetStaticIpRequest {
     pub static_ip_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct GetStaticIpResult {
-    /// <p>An array of key-value pairs containing information about the requested static IP.</p>
-    #[serde(rename = "staticIp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub static_ip: Option<StaticIp>,
+impl GetStaticIpRequest {
+    /// Returns a builder for `GetStaticIpRequest`, checking its required members when built.
+    pub fn builder() -> GetStaticIpRequestBuilder {
+        GetStaticIpRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// Builds a [`GetStaticIpRequest`](struct.GetStaticIpRequest.html).
+#[derive(Default, Debug)]
+pub struct GetStaticIpRequestBuilder {
+    static_ip_name: Option<String>,
+}
+
+impl GetStaticIpRequestBuilder {
+    /// Sets the [`static_ip_name`](struct.GetStaticIpRequest.html#structfield.static_ip_name) member.
+    pub fn static_ip_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.static_ip_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetStaticIpRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetStaticIpRequest, ::rusoto_core::BuildError> {
+        Ok(GetStaticIpRequest {
+            static_ip_name: self
+                .static_ip_name
+                .ok_or(::rusoto_core::BuildError::missing("static_ip_name"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct GetStaticIpResult {
+    /// <p>An array of key-value pairs containing information about the requested static IP.</p>
+    #[serde(rename = "staticIp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub static_ip: Option<StaticIp>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetStaticIpsRequest {
     /// <p>A token used for advancing to the next page of results from your get static IPs request.</p>
     #[serde(rename = "pageToken")]
@@ -2322,6 +6217,34 @@ pub struct GetStaticIpsRequest {
     pub page_token: Option<String>,
 }
 
+impl GetStaticIpsRequest {
+    /// Returns a builder for `GetStaticIpsRequest`, checking its required members when built.
+    pub fn builder() -> GetStaticIpsRequestBuilder {
+        GetStaticIpsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetStaticIpsRequest`](struct.GetStaticIpsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetStaticIpsRequestBuilder {
+    page_token: Option<String>,
+}
+
+impl GetStaticIpsRequestBuilder {
+    /// Sets the [`page_token`](struct.GetStaticIpsRequest.html#structfield.page_token) member.
+    pub fn page_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.page_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetStaticIpsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetStaticIpsRequest, ::rusoto_core::BuildError> {
+        Ok(GetStaticIpsRequest {
+            page_token: self.page_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetStaticIpsResult {
@@ -2353,20 +6276,23 @@ pub struct HostKeyAttributes {
     pub fingerprint_sha256: Option<String>,
     /// <p>The returned RDP certificate is not valid after this point in time.</p> <p>This value is listed only for RDP certificates.</p>
     #[serde(rename = "notValidAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_valid_after: Option<f64>,
+    pub not_valid_after: Option<EpochTimestamp>,
     /// <p>The returned RDP certificate is valid after this point in time.</p> <p>This value is listed only for RDP certificates.</p>
     #[serde(rename = "notValidBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_valid_before: Option<f64>,
+    pub not_valid_before: Option<EpochTimestamp>,
     /// <p>The public SSH host key or the RDP certificate.</p>
     #[serde(rename = "publicKey")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_key: Option<String>,
     /// <p>The time that the SSH host key or RDP certificate was recorded by Lightsail.</p>
     #[serde(rename = "witnessedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub witnessed_at: Option<f64>,
+    pub witnessed_at: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2379,6 +6305,46 @@ pub struct ImportKeyPairRequest {
     pub public_key_base_64: String,
 }
 
+impl ImportKeyPairRequest {
+    /// Returns a builder for `ImportKeyPairRequest`, checking its required members when built.
+    pub fn builder() -> ImportKeyPairRequestBuilder {
+        ImportKeyPairRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ImportKeyPairRequest`](struct.ImportKeyPairRequest.html).
+#[derive(Default, Debug)]
+pub struct ImportKeyPairRequestBuilder {
+    key_pair_name: Option<String>,
+    public_key_base_64: Option<String>,
+}
+
+impl ImportKeyPairRequestBuilder {
+    /// Sets the [`key_pair_name`](struct.ImportKeyPairRequest.html#structfield.key_pair_name) member.
+    pub fn key_pair_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_pair_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`public_key_base_64`](struct.ImportKeyPairRequest.html#structfield.public_key_base_64) member.
+    pub fn public_key_base_64<T: Into<String>>(mut self, value: T) -> Self {
+        self.public_key_base_64 = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportKeyPairRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ImportKeyPairRequest, ::rusoto_core::BuildError> {
+        Ok(ImportKeyPairRequest {
+            key_pair_name: self
+                .key_pair_name
+                .ok_or(::rusoto_core::BuildError::missing("key_pair_name"))?,
+            public_key_base_64: self
+                .public_key_base_64
+                .ok_or(::rusoto_core::BuildError::missing("public_key_base_64"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ImportKeyPairResult {
@@ -2410,8 +6376,9 @@ pub struct Instance {
     pub bundle_id: Option<String>,
     /// <p>The timestamp when the instance was created (e.g., <code>1479734909.17</code>).</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The size of the vCPU and the amount of RAM for the instance.</p>
     #[serde(rename = "hardware")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2447,7 +6414,7 @@ pub struct Instance {
     /// <p>The type of resource (usually <code>Instance</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The name of the SSH key being used to connect to the instance (e.g., <code>LightsailDefaultKeyPair</code>).</p>
     #[serde(rename = "sshKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2480,8 +6447,9 @@ pub struct InstanceAccessDetails {
     pub cert_key: Option<String>,
     /// <p>For SSH access, the date on which the temporary keys expire.</p>
     #[serde(rename = "expiresAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_at: Option<f64>,
+    pub expires_at: Option<EpochTimestamp>,
     /// <p>Describes the public SSH host keys or the RDP certificate.</p>
     #[serde(rename = "hostKeys")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2509,15 +6477,87 @@ pub struct InstanceAccessDetails {
     /// <p>The protocol for these Amazon Lightsail instance access details.</p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<InstanceAccessProtocol>,
     /// <p>The user name to use when logging in to the Amazon Lightsail instance.</p>
     #[serde(rename = "username")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
 }
 
+/// <p>The protocol to use to connect to your instance. Defaults to <code>ssh</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceAccessProtocol {
+    /// <p><code>ssh</code></p>
+    Ssh,
+    /// <p><code>rdp</code></p>
+    Rdp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceAccessProtocol {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceAccessProtocol::Ssh => "ssh",
+            InstanceAccessProtocol::Rdp => "rdp",
+            InstanceAccessProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceAccessProtocol {
+    fn from(value: &'a str) -> InstanceAccessProtocol {
+        match value {
+            "ssh" => InstanceAccessProtocol::Ssh,
+            "rdp" => InstanceAccessProtocol::Rdp,
+            _ => InstanceAccessProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceAccessProtocol {
+    fn from(value: String) -> InstanceAccessProtocol {
+        InstanceAccessProtocol::from(value.as_str())
+    }
+}
+
+impl From<InstanceAccessProtocol> for String {
+    fn from(value: InstanceAccessProtocol) -> String {
+        match value {
+            InstanceAccessProtocol::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceAccessProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceAccessProtocol {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceAccessProtocol {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceAccessProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceAccessProtocol::from(value))
+    }
+}
+
 /// <p>Describes the Amazon Elastic Compute Cloud instance and related resources to be created using the <code>create cloud formation stack</code> operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InstanceEntry {
     /// <p>The Availability Zone for the new Amazon EC2 instance.</p>
     #[serde(rename = "availabilityZone")]
@@ -2527,7 +6567,7 @@ pub struct InstanceEntry {
     pub instance_type: String,
     /// <p><p>The port configuration to use for the new Amazon EC2 instance.</p> <p>The following configuration options are available:</p> <ul> <li> <p>DEFAULT — Use the default firewall settings from the image.</p> </li> <li> <p>INSTANCE — Use the firewall settings from the source Lightsail instance.</p> </li> <li> <p>NONE — Default to Amazon EC2.</p> </li> <li> <p>CLOSED — All ports closed.</p> </li> </ul></p>
     #[serde(rename = "portInfoSource")]
-    pub port_info_source: String,
+    pub port_info_source: PortInfoSourceType,
     /// <p>The name of the export snapshot record, which contains the exported Lightsail instance snapshot that will be used as the source of the new Amazon EC2 instance.</p> <p>Use the <code>get export snapshot records</code> operation to get a list of export snapshot records that you can use to create a CloudFormation stack.</p>
     #[serde(rename = "sourceName")]
     pub source_name: String,
@@ -2555,6 +6595,206 @@ pub struct InstanceHardware {
     pub ram_size_in_gb: Option<f32>,
 }
 
+/// <p><p>More information about the instance health. If the <code>instanceHealth</code> is <code>healthy</code>, then an <code>instanceHealthReason</code> value is not provided.</p> <p>If <b> <code>instanceHealth</code> </b> is <code>initial</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Lb.RegistrationInProgress</code> </b> - The target instance is in the process of being registered with the load balancer.</p> </li> <li> <p> <b> <code>Lb.InitialHealthChecking</code> </b> - The Lightsail load balancer is still sending the target instance the minimum number of health checks required to determine its health status.</p> </li> </ul> <p>If <b> <code>instanceHealth</code> </b> is <code>unhealthy</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Instance.ResponseCodeMismatch</code> </b> - The health checks did not return an expected HTTP code.</p> </li> <li> <p> <b> <code>Instance.Timeout</code> </b> - The health check requests timed out.</p> </li> <li> <p> <b> <code>Instance.FailedHealthChecks</code> </b> - The health checks failed because the connection to the target instance timed out, the target instance response was malformed, or the target instance failed the health check for an unknown reason.</p> </li> <li> <p> <b> <code>Lb.InternalError</code> </b> - The health checks failed due to an internal error.</p> </li> </ul> <p>If <b> <code>instanceHealth</code> </b> is <code>unused</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Instance.NotRegistered</code> </b> - The target instance is not registered with the target group.</p> </li> <li> <p> <b> <code>Instance.NotInUse</code> </b> - The target group is not used by any load balancer, or the target instance is in an Availability Zone that is not enabled for its load balancer.</p> </li> <li> <p> <b> <code>Instance.IpUnusable</code> </b> - The target IP address is reserved for use by a Lightsail load balancer.</p> </li> <li> <p> <b> <code>Instance.InvalidState</code> </b> - The target is in the stopped or terminated state.</p> </li> </ul> <p>If <b> <code>instanceHealth</code> </b> is <code>draining</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Instance.DeregistrationInProgress</code> </b> - The target instance is in the process of being deregistered and the deregistration delay period has not expired.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceHealthReason {
+    /// <p><code>Lb.RegistrationInProgress</code></p>
+    LbRegistrationInProgress,
+    /// <p><code>Lb.InitialHealthChecking</code></p>
+    LbInitialHealthChecking,
+    /// <p><code>Lb.InternalError</code></p>
+    LbInternalError,
+    /// <p><code>Instance.ResponseCodeMismatch</code></p>
+    InstanceResponseCodeMismatch,
+    /// <p><code>Instance.Timeout</code></p>
+    InstanceTimeout,
+    /// <p><code>Instance.FailedHealthChecks</code></p>
+    InstanceFailedHealthChecks,
+    /// <p><code>Instance.NotRegistered</code></p>
+    InstanceNotRegistered,
+    /// <p><code>Instance.NotInUse</code></p>
+    InstanceNotInUse,
+    /// <p><code>Instance.DeregistrationInProgress</code></p>
+    InstanceDeregistrationInProgress,
+    /// <p><code>Instance.InvalidState</code></p>
+    InstanceInvalidState,
+    /// <p><code>Instance.IpUnusable</code></p>
+    InstanceIpUnusable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceHealthReason {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceHealthReason::LbRegistrationInProgress => "Lb.RegistrationInProgress",
+            InstanceHealthReason::LbInitialHealthChecking => "Lb.InitialHealthChecking",
+            InstanceHealthReason::LbInternalError => "Lb.InternalError",
+            InstanceHealthReason::InstanceResponseCodeMismatch => "Instance.ResponseCodeMismatch",
+            InstanceHealthReason::InstanceTimeout => "Instance.Timeout",
+            InstanceHealthReason::InstanceFailedHealthChecks => "Instance.FailedHealthChecks",
+            InstanceHealthReason::InstanceNotRegistered => "Instance.NotRegistered",
+            InstanceHealthReason::InstanceNotInUse => "Instance.NotInUse",
+            InstanceHealthReason::InstanceDeregistrationInProgress => {
+                "Instance.DeregistrationInProgress"
+            }
+            InstanceHealthReason::InstanceInvalidState => "Instance.InvalidState",
+            InstanceHealthReason::InstanceIpUnusable => "Instance.IpUnusable",
+            InstanceHealthReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceHealthReason {
+    fn from(value: &'a str) -> InstanceHealthReason {
+        match value {
+            "Lb.RegistrationInProgress" => InstanceHealthReason::LbRegistrationInProgress,
+            "Lb.InitialHealthChecking" => InstanceHealthReason::LbInitialHealthChecking,
+            "Lb.InternalError" => InstanceHealthReason::LbInternalError,
+            "Instance.ResponseCodeMismatch" => InstanceHealthReason::InstanceResponseCodeMismatch,
+            "Instance.Timeout" => InstanceHealthReason::InstanceTimeout,
+            "Instance.FailedHealthChecks" => InstanceHealthReason::InstanceFailedHealthChecks,
+            "Instance.NotRegistered" => InstanceHealthReason::InstanceNotRegistered,
+            "Instance.NotInUse" => InstanceHealthReason::InstanceNotInUse,
+            "Instance.DeregistrationInProgress" => {
+                InstanceHealthReason::InstanceDeregistrationInProgress
+            }
+            "Instance.InvalidState" => InstanceHealthReason::InstanceInvalidState,
+            "Instance.IpUnusable" => InstanceHealthReason::InstanceIpUnusable,
+            _ => InstanceHealthReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceHealthReason {
+    fn from(value: String) -> InstanceHealthReason {
+        InstanceHealthReason::from(value.as_str())
+    }
+}
+
+impl From<InstanceHealthReason> for String {
+    fn from(value: InstanceHealthReason) -> String {
+        match value {
+            InstanceHealthReason::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceHealthReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceHealthReason {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceHealthReason {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceHealthReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceHealthReason::from(value))
+    }
+}
+
+/// <p>Describes the overall instance health. Valid values are below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceHealthState {
+    /// <p><code>initial</code></p>
+    Initial,
+    /// <p><code>healthy</code></p>
+    Healthy,
+    /// <p><code>unhealthy</code></p>
+    Unhealthy,
+    /// <p><code>unused</code></p>
+    Unused,
+    /// <p><code>draining</code></p>
+    Draining,
+    /// <p><code>unavailable</code></p>
+    Unavailable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceHealthState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceHealthState::Initial => "initial",
+            InstanceHealthState::Healthy => "healthy",
+            InstanceHealthState::Unhealthy => "unhealthy",
+            InstanceHealthState::Unused => "unused",
+            InstanceHealthState::Draining => "draining",
+            InstanceHealthState::Unavailable => "unavailable",
+            InstanceHealthState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceHealthState {
+    fn from(value: &'a str) -> InstanceHealthState {
+        match value {
+            "initial" => InstanceHealthState::Initial,
+            "healthy" => InstanceHealthState::Healthy,
+            "unhealthy" => InstanceHealthState::Unhealthy,
+            "unused" => InstanceHealthState::Unused,
+            "draining" => InstanceHealthState::Draining,
+            "unavailable" => InstanceHealthState::Unavailable,
+            _ => InstanceHealthState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceHealthState {
+    fn from(value: String) -> InstanceHealthState {
+        InstanceHealthState::from(value.as_str())
+    }
+}
+
+impl From<InstanceHealthState> for String {
+    fn from(value: InstanceHealthState) -> String {
+        match value {
+            InstanceHealthState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceHealthState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceHealthState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceHealthState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceHealthState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceHealthState::from(value))
+    }
+}
+
 /// <p>Describes information about the health of the instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2562,17 +6802,117 @@ pub struct InstanceHealthSummary {
     /// <p>Describes the overall instance health. Valid values are below.</p>
     #[serde(rename = "instanceHealth")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_health: Option<String>,
+    pub instance_health: Option<InstanceHealthState>,
     /// <p><p>More information about the instance health. If the <code>instanceHealth</code> is <code>healthy</code>, then an <code>instanceHealthReason</code> value is not provided.</p> <p>If <b> <code>instanceHealth</code> </b> is <code>initial</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Lb.RegistrationInProgress</code> </b> - The target instance is in the process of being registered with the load balancer.</p> </li> <li> <p> <b> <code>Lb.InitialHealthChecking</code> </b> - The Lightsail load balancer is still sending the target instance the minimum number of health checks required to determine its health status.</p> </li> </ul> <p>If <b> <code>instanceHealth</code> </b> is <code>unhealthy</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Instance.ResponseCodeMismatch</code> </b> - The health checks did not return an expected HTTP code.</p> </li> <li> <p> <b> <code>Instance.Timeout</code> </b> - The health check requests timed out.</p> </li> <li> <p> <b> <code>Instance.FailedHealthChecks</code> </b> - The health checks failed because the connection to the target instance timed out, the target instance response was malformed, or the target instance failed the health check for an unknown reason.</p> </li> <li> <p> <b> <code>Lb.InternalError</code> </b> - The health checks failed due to an internal error.</p> </li> </ul> <p>If <b> <code>instanceHealth</code> </b> is <code>unused</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Instance.NotRegistered</code> </b> - The target instance is not registered with the target group.</p> </li> <li> <p> <b> <code>Instance.NotInUse</code> </b> - The target group is not used by any load balancer, or the target instance is in an Availability Zone that is not enabled for its load balancer.</p> </li> <li> <p> <b> <code>Instance.IpUnusable</code> </b> - The target IP address is reserved for use by a Lightsail load balancer.</p> </li> <li> <p> <b> <code>Instance.InvalidState</code> </b> - The target is in the stopped or terminated state.</p> </li> </ul> <p>If <b> <code>instanceHealth</code> </b> is <code>draining</code>, the <b> <code>instanceHealthReason</code> </b> value can be one of the following:</p> <ul> <li> <p> <b> <code>Instance.DeregistrationInProgress</code> </b> - The target instance is in the process of being deregistered and the deregistration delay period has not expired.</p> </li> </ul></p>
     #[serde(rename = "instanceHealthReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub instance_health_reason: Option<String>,
+    pub instance_health_reason: Option<InstanceHealthReason>,
     /// <p>The name of the Lightsail instance for which you are requesting health check data.</p>
     #[serde(rename = "instanceName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub instance_name: Option<String>,
 }
 
+/// <p>The metric name to get data about. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceMetricName {
+    /// <p><code>CPUUtilization</code></p>
+    CPUUtilization,
+    /// <p><code>NetworkIn</code></p>
+    NetworkIn,
+    /// <p><code>NetworkOut</code></p>
+    NetworkOut,
+    /// <p><code>StatusCheckFailed</code></p>
+    StatusCheckFailed,
+    /// <p><code>StatusCheckFailed_Instance</code></p>
+    StatusCheckFailedInstance,
+    /// <p><code>StatusCheckFailed_System</code></p>
+    StatusCheckFailedSystem,
+    /// <p><code>BurstCapacityTime</code></p>
+    BurstCapacityTime,
+    /// <p><code>BurstCapacityPercentage</code></p>
+    BurstCapacityPercentage,
+    /// <p><code>MetadataNoToken</code></p>
+    MetadataNoToken,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceMetricName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceMetricName::CPUUtilization => "CPUUtilization",
+            InstanceMetricName::NetworkIn => "NetworkIn",
+            InstanceMetricName::NetworkOut => "NetworkOut",
+            InstanceMetricName::StatusCheckFailed => "StatusCheckFailed",
+            InstanceMetricName::StatusCheckFailedInstance => "StatusCheckFailed_Instance",
+            InstanceMetricName::StatusCheckFailedSystem => "StatusCheckFailed_System",
+            InstanceMetricName::BurstCapacityTime => "BurstCapacityTime",
+            InstanceMetricName::BurstCapacityPercentage => "BurstCapacityPercentage",
+            InstanceMetricName::MetadataNoToken => "MetadataNoToken",
+            InstanceMetricName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceMetricName {
+    fn from(value: &'a str) -> InstanceMetricName {
+        match value {
+            "CPUUtilization" => InstanceMetricName::CPUUtilization,
+            "NetworkIn" => InstanceMetricName::NetworkIn,
+            "NetworkOut" => InstanceMetricName::NetworkOut,
+            "StatusCheckFailed" => InstanceMetricName::StatusCheckFailed,
+            "StatusCheckFailed_Instance" => InstanceMetricName::StatusCheckFailedInstance,
+            "StatusCheckFailed_System" => InstanceMetricName::StatusCheckFailedSystem,
+            "BurstCapacityTime" => InstanceMetricName::BurstCapacityTime,
+            "BurstCapacityPercentage" => InstanceMetricName::BurstCapacityPercentage,
+            "MetadataNoToken" => InstanceMetricName::MetadataNoToken,
+            _ => InstanceMetricName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceMetricName {
+    fn from(value: String) -> InstanceMetricName {
+        InstanceMetricName::from(value.as_str())
+    }
+}
+
+impl From<InstanceMetricName> for String {
+    fn from(value: InstanceMetricName) -> String {
+        match value {
+            InstanceMetricName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceMetricName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceMetricName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceMetricName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceMetricName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceMetricName::from(value))
+    }
+}
+
 /// <p>Describes monthly data transfer rates and port information for an instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2587,6 +6927,78 @@ pub struct InstanceNetworking {
     pub ports: Option<Vec<InstancePortInfo>>,
 }
 
+/// <p>The operating system platform (either Linux/Unix-based or Windows Server-based) of the blueprint.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstancePlatform {
+    /// <p><code>LINUX_UNIX</code></p>
+    LinuxUnix,
+    /// <p><code>WINDOWS</code></p>
+    Windows,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstancePlatform {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstancePlatform::LinuxUnix => "LINUX_UNIX",
+            InstancePlatform::Windows => "WINDOWS",
+            InstancePlatform::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstancePlatform {
+    fn from(value: &'a str) -> InstancePlatform {
+        match value {
+            "LINUX_UNIX" => InstancePlatform::LinuxUnix,
+            "WINDOWS" => InstancePlatform::Windows,
+            _ => InstancePlatform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstancePlatform {
+    fn from(value: String) -> InstancePlatform {
+        InstancePlatform::from(value.as_str())
+    }
+}
+
+impl From<InstancePlatform> for String {
+    fn from(value: InstancePlatform) -> String {
+        match value {
+            InstancePlatform::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstancePlatform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstancePlatform {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstancePlatform {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstancePlatform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstancePlatform::from(value))
+    }
+}
+
 /// <p>Describes information about the instance ports.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2594,7 +7006,7 @@ pub struct InstancePortInfo {
     /// <p>The access direction (<code>inbound</code> or <code>outbound</code>).</p>
     #[serde(rename = "accessDirection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_direction: Option<String>,
+    pub access_direction: Option<AccessDirection>,
     /// <p>The location from which access is allowed (e.g., <code>Anywhere (0.0.0.0/0)</code>).</p>
     #[serde(rename = "accessFrom")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2602,7 +7014,7 @@ pub struct InstancePortInfo {
     /// <p>The type of access (<code>Public</code> or <code>Private</code>).</p>
     #[serde(rename = "accessType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub access_type: Option<String>,
+    pub access_type: Option<PortAccessType>,
     /// <p>The common name.</p>
     #[serde(rename = "commonName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2614,7 +7026,7 @@ pub struct InstancePortInfo {
     /// <p><p>The protocol being used. Can be one of the following.</p> <ul> <li> <p> <code>tcp</code> - Transmission Control Protocol (TCP) provides reliable, ordered, and error-checked delivery of streamed data between applications running on hosts communicating by an IP network. If you have an application that doesn&#39;t require reliable data stream service, use UDP instead.</p> </li> <li> <p> <code>all</code> - All transport layer protocol types. For more general information, see <a href="https://en.wikipedia.org/wiki/Transport_layer">Transport layer</a> on Wikipedia.</p> </li> <li> <p> <code>udp</code> - With User Datagram Protocol (UDP), computer applications can send messages (or datagrams) to other hosts on an Internet Protocol (IP) network. Prior communications are not required to set up transmission channels or data paths. Applications that don&#39;t require reliable data stream service can use UDP, which provides a connectionless datagram service that emphasizes reduced latency over reliability. If you do require reliable data stream service, use TCP instead.</p> </li> </ul></p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<NetworkProtocol>,
     /// <p>The last port in the range.</p>
     #[serde(rename = "toPort")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2632,11 +7044,11 @@ pub struct InstancePortState {
     /// <p><p>The protocol being used. Can be one of the following.</p> <ul> <li> <p> <code>tcp</code> - Transmission Control Protocol (TCP) provides reliable, ordered, and error-checked delivery of streamed data between applications running on hosts communicating by an IP network. If you have an application that doesn&#39;t require reliable data stream service, use UDP instead.</p> </li> <li> <p> <code>all</code> - All transport layer protocol types. For more general information, see <a href="https://en.wikipedia.org/wiki/Transport_layer">Transport layer</a> on Wikipedia.</p> </li> <li> <p> <code>udp</code> - With User Datagram Protocol (UDP), computer applications can send messages (or datagrams) to other hosts on an Internet Protocol (IP) network. Prior communications are not required to set up transmission channels or data paths. Applications that don&#39;t require reliable data stream service can use UDP, which provides a connectionless datagram service that emphasizes reduced latency over reliability. If you do require reliable data stream service, use TCP instead.</p> </li> </ul></p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<NetworkProtocol>,
     /// <p>Specifies whether the instance port is <code>open</code> or <code>closed</code>.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<PortState>,
     /// <p>The last port in the range.</p>
     #[serde(rename = "toPort")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2653,8 +7065,9 @@ pub struct InstanceSnapshot {
     pub arn: Option<String>,
     /// <p>The timestamp when the snapshot was created (e.g., <code>1479907467.024</code>).</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>An array of disk objects containing information about all block storage disks.</p>
     #[serde(rename = "fromAttachedDisks")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2690,7 +7103,7 @@ pub struct InstanceSnapshot {
     /// <p>The type of resource (usually <code>InstanceSnapshot</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The size in GB of the SSD.</p>
     #[serde(rename = "sizeInGb")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2698,7 +7111,7 @@ pub struct InstanceSnapshot {
     /// <p>The state the snapshot is in.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<InstanceSnapshotState>,
     /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2727,6 +7140,82 @@ pub struct InstanceSnapshotInfo {
     pub from_disk_info: Option<Vec<DiskInfo>>,
 }
 
+/// <p>The state the snapshot is in.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InstanceSnapshotState {
+    /// <p><code>pending</code></p>
+    Pending,
+    /// <p><code>error</code></p>
+    Error,
+    /// <p><code>available</code></p>
+    Available,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl InstanceSnapshotState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            InstanceSnapshotState::Pending => "pending",
+            InstanceSnapshotState::Error => "error",
+            InstanceSnapshotState::Available => "available",
+            InstanceSnapshotState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for InstanceSnapshotState {
+    fn from(value: &'a str) -> InstanceSnapshotState {
+        match value {
+            "pending" => InstanceSnapshotState::Pending,
+            "error" => InstanceSnapshotState::Error,
+            "available" => InstanceSnapshotState::Available,
+            _ => InstanceSnapshotState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for InstanceSnapshotState {
+    fn from(value: String) -> InstanceSnapshotState {
+        InstanceSnapshotState::from(value.as_str())
+    }
+}
+
+impl From<InstanceSnapshotState> for String {
+    fn from(value: InstanceSnapshotState) -> String {
+        match value {
+            InstanceSnapshotState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for InstanceSnapshotState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for InstanceSnapshotState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InstanceSnapshotState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<InstanceSnapshotState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(InstanceSnapshotState::from(value))
+    }
+}
+
 /// <p>Describes the virtual private server (or <i>instance</i>) status.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2763,8 +7252,9 @@ pub struct KeyPair {
     pub arn: Option<String>,
     /// <p>The timestamp when the key pair was created (e.g., <code>1479816991.349</code>).</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The RSA fingerprint of the key pair.</p>
     #[serde(rename = "fingerprint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2780,7 +7270,7 @@ pub struct KeyPair {
     /// <p>The resource type (usually <code>KeyPair</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2802,11 +7292,13 @@ pub struct LoadBalancer {
     /// <p>A string to string map of the configuration options for your load balancer. Valid values are listed below.</p>
     #[serde(rename = "configurationOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub configuration_options: Option<::std::collections::HashMap<String, String>>,
+    pub configuration_options:
+        Option<::std::collections::HashMap<LoadBalancerAttributeName, String>>,
     /// <p>The date when your load balancer was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The DNS name of your Lightsail load balancer.</p>
     #[serde(rename = "dnsName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2834,7 +7326,7 @@ pub struct LoadBalancer {
     /// <p>The protocol you have enabled for your load balancer. Valid values are below.</p> <p>You can't just have <code>HTTP_HTTPS</code>, but you can have just <code>HTTP</code>.</p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<LoadBalancerProtocol>,
     /// <p>An array of public port settings for your load balancer. For HTTP, use port 80. For HTTPS, use port 443.</p>
     #[serde(rename = "publicPorts")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2842,11 +7334,11 @@ pub struct LoadBalancer {
     /// <p>The resource type (e.g., <code>LoadBalancer</code>.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The status of your load balancer. Valid values are below.</p>
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<LoadBalancerState>,
     /// <p>The support code. Include this code in your email to support when you have questions about your Lightsail load balancer. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2861,38 +7353,400 @@ pub struct LoadBalancer {
     pub tls_certificate_summaries: Option<Vec<LoadBalancerTlsCertificateSummary>>,
 }
 
-/// <p>Describes a load balancer SSL/TLS certificate.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct LoadBalancerTlsCertificate {
-    /// <p>The Amazon Resource Name (ARN) of the SSL/TLS certificate.</p>
-    #[serde(rename = "arn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub arn: Option<String>,
-    /// <p>The time when you created your SSL/TLS certificate.</p>
-    #[serde(rename = "createdAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
-    /// <p>The domain name for your SSL/TLS certificate.</p>
-    #[serde(rename = "domainName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_name: Option<String>,
-    /// <p>An array of LoadBalancerTlsCertificateDomainValidationRecord objects describing the records.</p>
-    #[serde(rename = "domainValidationRecords")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain_validation_records: Option<Vec<LoadBalancerTlsCertificateDomainValidationRecord>>,
-    /// <p>The reason for the SSL/TLS certificate validation failure.</p>
-    #[serde(rename = "failureReason")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_reason: Option<String>,
-    /// <p>When <code>true</code>, the SSL/TLS certificate is attached to the Lightsail load balancer.</p>
-    #[serde(rename = "isAttached")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+/// <p>A string to string map of the configuration options for your load balancer. Valid values are listed below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadBalancerAttributeName {
+    /// <p><code>HealthCheckPath</code></p>
+    HealthCheckPath,
+    /// <p><code>SessionStickinessEnabled</code></p>
+    SessionStickinessEnabled,
+    /// <p><code>SessionStickiness_LB_CookieDurationSeconds</code></p>
+    SessionStickinessLbCookieDurationSeconds,
+    /// <p><code>HttpsRedirectionEnabled</code></p>
+    HttpsRedirectionEnabled,
+    /// <p><code>TlsPolicyName</code></p>
+    TlsPolicyName,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadBalancerAttributeName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerAttributeName::HealthCheckPath => "HealthCheckPath",
+            LoadBalancerAttributeName::SessionStickinessEnabled => "SessionStickinessEnabled",
+            LoadBalancerAttributeName::SessionStickinessLbCookieDurationSeconds => {
+                "SessionStickiness_LB_CookieDurationSeconds"
+            }
+            LoadBalancerAttributeName::HttpsRedirectionEnabled => "HttpsRedirectionEnabled",
+            LoadBalancerAttributeName::TlsPolicyName => "TlsPolicyName",
+            LoadBalancerAttributeName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerAttributeName {
+    fn from(value: &'a str) -> LoadBalancerAttributeName {
+        match value {
+            "HealthCheckPath" => LoadBalancerAttributeName::HealthCheckPath,
+            "SessionStickinessEnabled" => LoadBalancerAttributeName::SessionStickinessEnabled,
+            "SessionStickiness_LB_CookieDurationSeconds" => {
+                LoadBalancerAttributeName::SessionStickinessLbCookieDurationSeconds
+            }
+            "HttpsRedirectionEnabled" => LoadBalancerAttributeName::HttpsRedirectionEnabled,
+            "TlsPolicyName" => LoadBalancerAttributeName::TlsPolicyName,
+            _ => LoadBalancerAttributeName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerAttributeName {
+    fn from(value: String) -> LoadBalancerAttributeName {
+        LoadBalancerAttributeName::from(value.as_str())
+    }
+}
+
+impl From<LoadBalancerAttributeName> for String {
+    fn from(value: LoadBalancerAttributeName) -> String {
+        match value {
+            LoadBalancerAttributeName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadBalancerAttributeName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerAttributeName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerAttributeName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LoadBalancerAttributeName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoadBalancerAttributeName::from(value))
+    }
+}
+
+/// <p><p>The metric about which you want to return information. Valid values are listed below, along with the most useful <code>statistics</code> to include in your request.</p> <ul> <li> <p> <b> <code>ClientTLSNegotiationErrorCount</code> </b> - The number of TLS connections initiated by the client that did not establish a session with the load balancer. Possible causes include a mismatch of ciphers or protocols.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>.</p> </li> <li> <p> <b> <code>HealthyHostCount</code> </b> - The number of target instances that are considered healthy.</p> <p> <code>Statistics</code>: The most useful statistic are <code>Average</code>, <code>Minimum</code>, and <code>Maximum</code>.</p> </li> <li> <p> <b> <code>UnhealthyHostCount</code> </b> - The number of target instances that are considered unhealthy.</p> <p> <code>Statistics</code>: The most useful statistic are <code>Average</code>, <code>Minimum</code>, and <code>Maximum</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>LB</em>4XX<em>Count</code> </b> - The number of HTTP 4XX client error codes that originate from the load balancer. Client errors are generated when requests are malformed or incomplete. These requests have not been received by the target instance. This count does not include any response codes generated by the target instances.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>LB<em>5XX</em>Count</code> </b> - The number of HTTP 5XX server error codes that originate from the load balancer. This count does not include any response codes generated by the target instances.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>Instance</em>2XX<em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>Instance<em>3XX</em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer. </p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode<em>Instance</em>4XX<em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>HTTPCode</em>Instance<em>5XX</em>Count</code> </b> - The number of HTTP response codes generated by the target instances. This does not include any response codes generated by the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> <li> <p> <b> <code>InstanceResponseTime</code> </b> - The time elapsed, in seconds, after the request leaves the load balancer until a response from the target instance is received.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Average</code>.</p> </li> <li> <p> <b> <code>RejectedConnectionCount</code> </b> - The number of connections that were rejected because the load balancer had reached its maximum number of connections.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>.</p> </li> <li> <p> <b> <code>RequestCount</code> </b> - The number of requests processed over IPv4. This count includes only the requests with a response generated by a target instance of the load balancer.</p> <p> <code>Statistics</code>: The most useful statistic is <code>Sum</code>. Note that <code>Minimum</code>, <code>Maximum</code>, and <code>Average</code> all return <code>1</code>.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadBalancerMetricName {
+    /// <p><code>ClientTLSNegotiationErrorCount</code></p>
+    ClientTLSNegotiationErrorCount,
+    /// <p><code>HealthyHostCount</code></p>
+    HealthyHostCount,
+    /// <p><code>UnhealthyHostCount</code></p>
+    UnhealthyHostCount,
+    /// <p><code>HTTPCode_LB_4XX_Count</code></p>
+    HTTPCodeLb4xxCount,
+    /// <p><code>HTTPCode_LB_5XX_Count</code></p>
+    HTTPCodeLb5xxCount,
+    /// <p><code>HTTPCode_Instance_2XX_Count</code></p>
+    HTTPCodeInstance2xxCount,
+    /// <p><code>HTTPCode_Instance_3XX_Count</code></p>
+    HTTPCodeInstance3xxCount,
+    /// <p><code>HTTPCode_Instance_4XX_Count</code></p>
+    HTTPCodeInstance4xxCount,
+    /// <p><code>HTTPCode_Instance_5XX_Count</code></p>
+    HTTPCodeInstance5xxCount,
+    /// <p><code>InstanceResponseTime</code></p>
+    InstanceResponseTime,
+    /// <p><code>RejectedConnectionCount</code></p>
+    RejectedConnectionCount,
+    /// <p><code>RequestCount</code></p>
+    RequestCount,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadBalancerMetricName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerMetricName::ClientTLSNegotiationErrorCount => {
+                "ClientTLSNegotiationErrorCount"
+            }
+            LoadBalancerMetricName::HealthyHostCount => "HealthyHostCount",
+            LoadBalancerMetricName::UnhealthyHostCount => "UnhealthyHostCount",
+            LoadBalancerMetricName::HTTPCodeLb4xxCount => "HTTPCode_LB_4XX_Count",
+            LoadBalancerMetricName::HTTPCodeLb5xxCount => "HTTPCode_LB_5XX_Count",
+            LoadBalancerMetricName::HTTPCodeInstance2xxCount => "HTTPCode_Instance_2XX_Count",
+            LoadBalancerMetricName::HTTPCodeInstance3xxCount => "HTTPCode_Instance_3XX_Count",
+            LoadBalancerMetricName::HTTPCodeInstance4xxCount => "HTTPCode_Instance_4XX_Count",
+            LoadBalancerMetricName::HTTPCodeInstance5xxCount => "HTTPCode_Instance_5XX_Count",
+            LoadBalancerMetricName::InstanceResponseTime => "InstanceResponseTime",
+            LoadBalancerMetricName::RejectedConnectionCount => "RejectedConnectionCount",
+            LoadBalancerMetricName::RequestCount => "RequestCount",
+            LoadBalancerMetricName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerMetricName {
+    fn from(value: &'a str) -> LoadBalancerMetricName {
+        match value {
+            "ClientTLSNegotiationErrorCount" => {
+                LoadBalancerMetricName::ClientTLSNegotiationErrorCount
+            }
+            "HealthyHostCount" => LoadBalancerMetricName::HealthyHostCount,
+            "UnhealthyHostCount" => LoadBalancerMetricName::UnhealthyHostCount,
+            "HTTPCode_LB_4XX_Count" => LoadBalancerMetricName::HTTPCodeLb4xxCount,
+            "HTTPCode_LB_5XX_Count" => LoadBalancerMetricName::HTTPCodeLb5xxCount,
+            "HTTPCode_Instance_2XX_Count" => LoadBalancerMetricName::HTTPCodeInstance2xxCount,
+            "HTTPCode_Instance_3XX_Count" => LoadBalancerMetricName::HTTPCodeInstance3xxCount,
+            "HTTPCode_Instance_4XX_Count" => LoadBalancerMetricName::HTTPCodeInstance4xxCount,
+            "HTTPCode_Instance_5XX_Count" => LoadBalancerMetricName::HTTPCodeInstance5xxCount,
+            "InstanceResponseTime" => LoadBalancerMetricName::InstanceResponseTime,
+            "RejectedConnectionCount" => LoadBalancerMetricName::RejectedConnectionCount,
+            "RequestCount" => LoadBalancerMetricName::RequestCount,
+            _ => LoadBalancerMetricName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerMetricName {
+    fn from(value: String) -> LoadBalancerMetricName {
+        LoadBalancerMetricName::from(value.as_str())
+    }
+}
+
+impl From<LoadBalancerMetricName> for String {
+    fn from(value: LoadBalancerMetricName) -> String {
+        match value {
+            LoadBalancerMetricName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadBalancerMetricName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerMetricName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerMetricName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LoadBalancerMetricName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoadBalancerMetricName::from(value))
+    }
+}
+
+/// <p>The protocol you have enabled for your load balancer. Valid values are below.</p> <p>You can't just have <code>HTTP_HTTPS</code>, but you can have just <code>HTTP</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadBalancerProtocol {
+    /// <p><code>HTTP_HTTPS</code></p>
+    HttpHttps,
+    /// <p><code>HTTP</code></p>
+    Http,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadBalancerProtocol {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerProtocol::HttpHttps => "HTTP_HTTPS",
+            LoadBalancerProtocol::Http => "HTTP",
+            LoadBalancerProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerProtocol {
+    fn from(value: &'a str) -> LoadBalancerProtocol {
+        match value {
+            "HTTP_HTTPS" => LoadBalancerProtocol::HttpHttps,
+            "HTTP" => LoadBalancerProtocol::Http,
+            _ => LoadBalancerProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerProtocol {
+    fn from(value: String) -> LoadBalancerProtocol {
+        LoadBalancerProtocol::from(value.as_str())
+    }
+}
+
+impl From<LoadBalancerProtocol> for String {
+    fn from(value: LoadBalancerProtocol) -> String {
+        match value {
+            LoadBalancerProtocol::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadBalancerProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerProtocol {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerProtocol {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LoadBalancerProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoadBalancerProtocol::from(value))
+    }
+}
+
+/// <p>The status of your load balancer. Valid values are below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadBalancerState {
+    /// <p><code>active</code></p>
+    Active,
+    /// <p><code>provisioning</code></p>
+    Provisioning,
+    /// <p><code>active_impaired</code></p>
+    ActiveImpaired,
+    /// <p><code>failed</code></p>
+    Failed,
+    /// <p><code>unknown</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadBalancerState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerState::Active => "active",
+            LoadBalancerState::Provisioning => "provisioning",
+            LoadBalancerState::ActiveImpaired => "active_impaired",
+            LoadBalancerState::Failed => "failed",
+            LoadBalancerState::UnknownValue => "unknown",
+            LoadBalancerState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerState {
+    fn from(value: &'a str) -> LoadBalancerState {
+        match value {
+            "active" => LoadBalancerState::Active,
+            "provisioning" => LoadBalancerState::Provisioning,
+            "active_impaired" => LoadBalancerState::ActiveImpaired,
+            "failed" => LoadBalancerState::Failed,
+            "unknown" => LoadBalancerState::UnknownValue,
+            _ => LoadBalancerState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerState {
+    fn from(value: String) -> LoadBalancerState {
+        LoadBalancerState::from(value.as_str())
+    }
+}
+
+impl From<LoadBalancerState> for String {
+    fn from(value: LoadBalancerState) -> String {
+        match value {
+            LoadBalancerState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadBalancerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LoadBalancerState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoadBalancerState::from(value))
+    }
+}
+
+/// <p>Describes a load balancer SSL/TLS certificate.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct LoadBalancerTlsCertificate {
+    /// <p>The Amazon Resource Name (ARN) of the SSL/TLS certificate.</p>
+    #[serde(rename = "arn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arn: Option<String>,
+    /// <p>The time when you created your SSL/TLS certificate.</p>
+    #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<EpochTimestamp>,
+    /// <p>The domain name for your SSL/TLS certificate.</p>
+    #[serde(rename = "domainName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_name: Option<String>,
+    /// <p>An array of LoadBalancerTlsCertificateDomainValidationRecord objects describing the records.</p>
+    #[serde(rename = "domainValidationRecords")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_validation_records: Option<Vec<LoadBalancerTlsCertificateDomainValidationRecord>>,
+    /// <p>The reason for the SSL/TLS certificate validation failure.</p>
+    #[serde(rename = "failureReason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<LoadBalancerTlsCertificateFailureReason>,
+    /// <p>When <code>true</code>, the SSL/TLS certificate is attached to the Lightsail load balancer.</p>
+    #[serde(rename = "isAttached")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_attached: Option<bool>,
     /// <p>The time when the SSL/TLS certificate was issued.</p>
     #[serde(rename = "issuedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issued_at: Option<f64>,
+    pub issued_at: Option<EpochTimestamp>,
     /// <p>The issuer of the certificate.</p>
     #[serde(rename = "issuer")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2915,12 +7769,14 @@ pub struct LoadBalancerTlsCertificate {
     pub name: Option<String>,
     /// <p>The timestamp when the SSL/TLS certificate expires.</p>
     #[serde(rename = "notAfter")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_after: Option<f64>,
+    pub not_after: Option<EpochTimestamp>,
     /// <p>The timestamp when the SSL/TLS certificate is first valid.</p>
     #[serde(rename = "notBefore")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub not_before: Option<f64>,
+    pub not_before: Option<EpochTimestamp>,
     /// <p>An object containing information about the status of Lightsail's managed renewal for the certificate.</p>
     #[serde(rename = "renewalSummary")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2928,15 +7784,16 @@ pub struct LoadBalancerTlsCertificate {
     /// <p><p>The resource type (e.g., <code>LoadBalancerTlsCertificate</code>).</p> <ul> <li> <p> <b> <code>Instance</code> </b> - A Lightsail instance (a virtual private server)</p> </li> <li> <p> <b> <code>StaticIp</code> </b> - A static IP address</p> </li> <li> <p> <b> <code>KeyPair</code> </b> - The key pair used to connect to a Lightsail instance</p> </li> <li> <p> <b> <code>InstanceSnapshot</code> </b> - A Lightsail instance snapshot</p> </li> <li> <p> <b> <code>Domain</code> </b> - A DNS zone</p> </li> <li> <p> <b> <code>PeeredVpc</code> </b> - A peered VPC</p> </li> <li> <p> <b> <code>LoadBalancer</code> </b> - A Lightsail load balancer</p> </li> <li> <p> <b> <code>LoadBalancerTlsCertificate</code> </b> - An SSL/TLS certificate associated with a Lightsail load balancer</p> </li> <li> <p> <b> <code>Disk</code> </b> - A Lightsail block storage disk</p> </li> <li> <p> <b> <code>DiskSnapshot</code> </b> - A block storage disk snapshot</p> </li> </ul></p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The reason the certificate was revoked. Valid values are below.</p>
     #[serde(rename = "revocationReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub revocation_reason: Option<String>,
+    pub revocation_reason: Option<LoadBalancerTlsCertificateRevocationReason>,
     /// <p>The timestamp when the SSL/TLS certificate was revoked.</p>
     #[serde(rename = "revokedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub revoked_at: Option<f64>,
+    pub revoked_at: Option<EpochTimestamp>,
     /// <p>The serial number of the certificate.</p>
     #[serde(rename = "serial")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2948,7 +7805,7 @@ pub struct LoadBalancerTlsCertificate {
     /// <p>The status of the SSL/TLS certificate. Valid values are below.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<LoadBalancerTlsCertificateStatus>,
     /// <p>The name of the entity that is associated with the public key contained in the certificate.</p>
     #[serde(rename = "subject")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2967,6 +7824,84 @@ pub struct LoadBalancerTlsCertificate {
     pub tags: Option<Vec<Tag>>,
 }
 
+/// <p>The status of the domain validation. Valid values are listed below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadBalancerTlsCertificateDomainStatus {
+    /// <p><code>PENDING_VALIDATION</code></p>
+    PendingValidation,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>SUCCESS</code></p>
+    Success,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadBalancerTlsCertificateDomainStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerTlsCertificateDomainStatus::PendingValidation => "PENDING_VALIDATION",
+            LoadBalancerTlsCertificateDomainStatus::Failed => "FAILED",
+            LoadBalancerTlsCertificateDomainStatus::Success => "SUCCESS",
+            LoadBalancerTlsCertificateDomainStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerTlsCertificateDomainStatus {
+    fn from(value: &'a str) -> LoadBalancerTlsCertificateDomainStatus {
+        match value {
+            "PENDING_VALIDATION" => LoadBalancerTlsCertificateDomainStatus::PendingValidation,
+            "FAILED" => LoadBalancerTlsCertificateDomainStatus::Failed,
+            "SUCCESS" => LoadBalancerTlsCertificateDomainStatus::Success,
+            _ => LoadBalancerTlsCertificateDomainStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerTlsCertificateDomainStatus {
+    fn from(value: String) -> LoadBalancerTlsCertificateDomainStatus {
+        LoadBalancerTlsCertificateDomainStatus::from(value.as_str())
+    }
+}
+
+impl From<LoadBalancerTlsCertificateDomainStatus> for String {
+    fn from(value: LoadBalancerTlsCertificateDomainStatus) -> String {
+        match value {
+            LoadBalancerTlsCertificateDomainStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadBalancerTlsCertificateDomainStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerTlsCertificateDomainStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerTlsCertificateDomainStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<LoadBalancerTlsCertificateDomainStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoadBalancerTlsCertificateDomainStatus::from(value))
+    }
+}
+
 /// <p>Contains information about the domain names on an SSL/TLS certificate that you will use to validate domain ownership.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2978,7 +7913,7 @@ pub struct LoadBalancerTlsCertificateDomainValidationOption {
     /// <p>The status of the domain validation. Valid values are listed below.</p>
     #[serde(rename = "validationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_status: Option<String>,
+    pub validation_status: Option<LoadBalancerTlsCertificateDomainStatus>,
 }
 
 /// <p>Describes the validation record of each domain name in the SSL/TLS certificate.</p>
@@ -3000,13 +7935,185 @@ pub struct LoadBalancerTlsCertificateDomainValidationRecord {
     /// <p>The validation status. Valid values are listed below.</p>
     #[serde(rename = "validationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub validation_status: Option<String>,
+    pub validation_status: Option<LoadBalancerTlsCertificateDomainStatus>,
     /// <p>The value for that type.</p>
     #[serde(rename = "value")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 }
 
+/// <p>The reason for the SSL/TLS certificate validation failure.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadBalancerTlsCertificateFailureReason {
+    /// <p><code>NO_AVAILABLE_CONTACTS</code></p>
+    NoAvailableContacts,
+    /// <p><code>ADDITIONAL_VERIFICATION_REQUIRED</code></p>
+    AdditionalVerificationRequired,
+    /// <p><code>DOMAIN_NOT_ALLOWED</code></p>
+    DomainNotAllowed,
+    /// <p><code>INVALID_PUBLIC_DOMAIN</code></p>
+    InvalidPublicDomain,
+    /// <p><code>OTHER</code></p>
+    Other,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadBalancerTlsCertificateFailureReason {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerTlsCertificateFailureReason::NoAvailableContacts => "NO_AVAILABLE_CONTACTS",
+            LoadBalancerTlsCertificateFailureReason::AdditionalVerificationRequired => {
+                "ADDITIONAL_VERIFICATION_REQUIRED"
+            }
+            LoadBalancerTlsCertificateFailureReason::DomainNotAllowed => "DOMAIN_NOT_ALLOWED",
+            LoadBalancerTlsCertificateFailureReason::InvalidPublicDomain => "INVALID_PUBLIC_DOMAIN",
+            LoadBalancerTlsCertificateFailureReason::Other => "OTHER",
+            LoadBalancerTlsCertificateFailureReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerTlsCertificateFailureReason {
+    fn from(value: &'a str) -> LoadBalancerTlsCertificateFailureReason {
+        match value {
+            "NO_AVAILABLE_CONTACTS" => LoadBalancerTlsCertificateFailureReason::NoAvailableContacts,
+            "ADDITIONAL_VERIFICATION_REQUIRED" => {
+                LoadBalancerTlsCertificateFailureReason::AdditionalVerificationRequired
+            }
+            "DOMAIN_NOT_ALLOWED" => LoadBalancerTlsCertificateFailureReason::DomainNotAllowed,
+            "INVALID_PUBLIC_DOMAIN" => LoadBalancerTlsCertificateFailureReason::InvalidPublicDomain,
+            "OTHER" => LoadBalancerTlsCertificateFailureReason::Other,
+            _ => LoadBalancerTlsCertificateFailureReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerTlsCertificateFailureReason {
+    fn from(value: String) -> LoadBalancerTlsCertificateFailureReason {
+        LoadBalancerTlsCertificateFailureReason::from(value.as_str())
+    }
+}
+
+impl From<LoadBalancerTlsCertificateFailureReason> for String {
+    fn from(value: LoadBalancerTlsCertificateFailureReason) -> String {
+        match value {
+            LoadBalancerTlsCertificateFailureReason::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadBalancerTlsCertificateFailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerTlsCertificateFailureReason {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerTlsCertificateFailureReason {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<LoadBalancerTlsCertificateFailureReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoadBalancerTlsCertificateFailureReason::from(value))
+    }
+}
+
+/// <p>The status of Lightsail's managed renewal of the certificate. Valid values are listed below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadBalancerTlsCertificateRenewalStatus {
+    /// <p><code>PENDING_AUTO_RENEWAL</code></p>
+    PendingAutoRenewal,
+    /// <p><code>PENDING_VALIDATION</code></p>
+    PendingValidation,
+    /// <p><code>SUCCESS</code></p>
+    Success,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadBalancerTlsCertificateRenewalStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerTlsCertificateRenewalStatus::PendingAutoRenewal => "PENDING_AUTO_RENEWAL",
+            LoadBalancerTlsCertificateRenewalStatus::PendingValidation => "PENDING_VALIDATION",
+            LoadBalancerTlsCertificateRenewalStatus::Success => "SUCCESS",
+            LoadBalancerTlsCertificateRenewalStatus::Failed => "FAILED",
+            LoadBalancerTlsCertificateRenewalStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerTlsCertificateRenewalStatus {
+    fn from(value: &'a str) -> LoadBalancerTlsCertificateRenewalStatus {
+        match value {
+            "PENDING_AUTO_RENEWAL" => LoadBalancerTlsCertificateRenewalStatus::PendingAutoRenewal,
+            "PENDING_VALIDATION" => LoadBalancerTlsCertificateRenewalStatus::PendingValidation,
+            "SUCCESS" => LoadBalancerTlsCertificateRenewalStatus::Success,
+            "FAILED" => LoadBalancerTlsCertificateRenewalStatus::Failed,
+            _ => LoadBalancerTlsCertificateRenewalStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerTlsCertificateRenewalStatus {
+    fn from(value: String) -> LoadBalancerTlsCertificateRenewalStatus {
+        LoadBalancerTlsCertificateRenewalStatus::from(value.as_str())
+    }
+}
+
+impl From<LoadBalancerTlsCertificateRenewalStatus> for String {
+    fn from(value: LoadBalancerTlsCertificateRenewalStatus) -> String {
+        match value {
+            LoadBalancerTlsCertificateRenewalStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadBalancerTlsCertificateRenewalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerTlsCertificateRenewalStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerTlsCertificateRenewalStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<LoadBalancerTlsCertificateRenewalStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoadBalancerTlsCertificateRenewalStatus::from(value))
+    }
+}
+
 /// <p>Contains information about the status of Lightsail's managed renewal for the certificate.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3018,20 +8125,228 @@ pub struct LoadBalancerTlsCertificateRenewalSummary {
     /// <p>The status of Lightsail's managed renewal of the certificate. Valid values are listed below.</p>
     #[serde(rename = "renewalStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub renewal_status: Option<String>,
+    pub renewal_status: Option<LoadBalancerTlsCertificateRenewalStatus>,
+}
+
+/// <p>The reason the certificate was revoked. Valid values are below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadBalancerTlsCertificateRevocationReason {
+    /// <p><code>UNSPECIFIED</code></p>
+    Unspecified,
+    /// <p><code>KEY_COMPROMISE</code></p>
+    KeyCompromise,
+    /// <p><code>CA_COMPROMISE</code></p>
+    CaCompromise,
+    /// <p><code>AFFILIATION_CHANGED</code></p>
+    AffiliationChanged,
+    /// <p><code>SUPERCEDED</code></p>
+    Superceded,
+    /// <p><code>CESSATION_OF_OPERATION</code></p>
+    CessationOfOperation,
+    /// <p><code>CERTIFICATE_HOLD</code></p>
+    CertificateHold,
+    /// <p><code>REMOVE_FROM_CRL</code></p>
+    RemoveFromCrl,
+    /// <p><code>PRIVILEGE_WITHDRAWN</code></p>
+    PrivilegeWithdrawn,
+    /// <p><code>A_A_COMPROMISE</code></p>
+    AACompromise,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadBalancerTlsCertificateRevocationReason {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerTlsCertificateRevocationReason::Unspecified => "UNSPECIFIED",
+            LoadBalancerTlsCertificateRevocationReason::KeyCompromise => "KEY_COMPROMISE",
+            LoadBalancerTlsCertificateRevocationReason::CaCompromise => "CA_COMPROMISE",
+            LoadBalancerTlsCertificateRevocationReason::AffiliationChanged => "AFFILIATION_CHANGED",
+            LoadBalancerTlsCertificateRevocationReason::Superceded => "SUPERCEDED",
+            LoadBalancerTlsCertificateRevocationReason::CessationOfOperation => {
+                "CESSATION_OF_OPERATION"
+            }
+            LoadBalancerTlsCertificateRevocationReason::CertificateHold => "CERTIFICATE_HOLD",
+            LoadBalancerTlsCertificateRevocationReason::RemoveFromCrl => "REMOVE_FROM_CRL",
+            LoadBalancerTlsCertificateRevocationReason::PrivilegeWithdrawn => "PRIVILEGE_WITHDRAWN",
+            LoadBalancerTlsCertificateRevocationReason::AACompromise => "A_A_COMPROMISE",
+            LoadBalancerTlsCertificateRevocationReason::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Provides a summary of SSL/TLS certificate metadata.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct LoadBalancerTlsCertificateSummary {
-    /// <p>When <code>true</code>, the SSL/TLS certificate is attached to the Lightsail load balancer.</p>
-    #[serde(rename = "isAttached")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_attached: Option<bool>,
-    /// <p>The name of the SSL/TLS certificate.</p>
-    #[serde(rename = "name")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+impl<'a> From<&'a str> for LoadBalancerTlsCertificateRevocationReason {
+    fn from(value: &'a str) -> LoadBalancerTlsCertificateRevocationReason {
+        match value {
+            "UNSPECIFIED" => LoadBalancerTlsCertificateRevocationReason::Unspecified,
+            "KEY_COMPROMISE" => LoadBalancerTlsCertificateRevocationReason::KeyCompromise,
+            "CA_COMPROMISE" => LoadBalancerTlsCertificateRevocationReason::CaCompromise,
+            "AFFILIATION_CHANGED" => LoadBalancerTlsCertificateRevocationReason::AffiliationChanged,
+            "SUPERCEDED" => LoadBalancerTlsCertificateRevocationReason::Superceded,
+            "CESSATION_OF_OPERATION" => {
+                LoadBalancerTlsCertificateRevocationReason::CessationOfOperation
+            }
+            "CERTIFICATE_HOLD" => LoadBalancerTlsCertificateRevocationReason::CertificateHold,
+            "REMOVE_FROM_CRL" => LoadBalancerTlsCertificateRevocationReason::RemoveFromCrl,
+            "PRIVILEGE_WITHDRAWN" => LoadBalancerTlsCertificateRevocationReason::PrivilegeWithdrawn,
+            "A_A_COMPROMISE" => LoadBalancerTlsCertificateRevocationReason::AACompromise,
+            _ => LoadBalancerTlsCertificateRevocationReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerTlsCertificateRevocationReason {
+    fn from(value: String) -> LoadBalancerTlsCertificateRevocationReason {
+        LoadBalancerTlsCertificateRevocationReason::from(value.as_str())
+    }
+}
+
+impl From<LoadBalancerTlsCertificateRevocationReason> for String {
+    fn from(value: LoadBalancerTlsCertificateRevocationReason) -> String {
+        match value {
+            LoadBalancerTlsCertificateRevocationReason::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadBalancerTlsCertificateRevocationReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerTlsCertificateRevocationReason {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerTlsCertificateRevocationReason {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<LoadBalancerTlsCertificateRevocationReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoadBalancerTlsCertificateRevocationReason::from(value))
+    }
+}
+
+/// <p>The status of the SSL/TLS certificate. Valid values are below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LoadBalancerTlsCertificateStatus {
+    /// <p><code>PENDING_VALIDATION</code></p>
+    PendingValidation,
+    /// <p><code>ISSUED</code></p>
+    Issued,
+    /// <p><code>INACTIVE</code></p>
+    Inactive,
+    /// <p><code>EXPIRED</code></p>
+    Expired,
+    /// <p><code>VALIDATION_TIMED_OUT</code></p>
+    ValidationTimedOut,
+    /// <p><code>REVOKED</code></p>
+    Revoked,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LoadBalancerTlsCertificateStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LoadBalancerTlsCertificateStatus::PendingValidation => "PENDING_VALIDATION",
+            LoadBalancerTlsCertificateStatus::Issued => "ISSUED",
+            LoadBalancerTlsCertificateStatus::Inactive => "INACTIVE",
+            LoadBalancerTlsCertificateStatus::Expired => "EXPIRED",
+            LoadBalancerTlsCertificateStatus::ValidationTimedOut => "VALIDATION_TIMED_OUT",
+            LoadBalancerTlsCertificateStatus::Revoked => "REVOKED",
+            LoadBalancerTlsCertificateStatus::Failed => "FAILED",
+            LoadBalancerTlsCertificateStatus::UnknownValue => "UNKNOWN",
+            LoadBalancerTlsCertificateStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LoadBalancerTlsCertificateStatus {
+    fn from(value: &'a str) -> LoadBalancerTlsCertificateStatus {
+        match value {
+            "PENDING_VALIDATION" => LoadBalancerTlsCertificateStatus::PendingValidation,
+            "ISSUED" => LoadBalancerTlsCertificateStatus::Issued,
+            "INACTIVE" => LoadBalancerTlsCertificateStatus::Inactive,
+            "EXPIRED" => LoadBalancerTlsCertificateStatus::Expired,
+            "VALIDATION_TIMED_OUT" => LoadBalancerTlsCertificateStatus::ValidationTimedOut,
+            "REVOKED" => LoadBalancerTlsCertificateStatus::Revoked,
+            "FAILED" => LoadBalancerTlsCertificateStatus::Failed,
+            "UNKNOWN" => LoadBalancerTlsCertificateStatus::UnknownValue,
+            _ => LoadBalancerTlsCertificateStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LoadBalancerTlsCertificateStatus {
+    fn from(value: String) -> LoadBalancerTlsCertificateStatus {
+        LoadBalancerTlsCertificateStatus::from(value.as_str())
+    }
+}
+
+impl From<LoadBalancerTlsCertificateStatus> for String {
+    fn from(value: LoadBalancerTlsCertificateStatus) -> String {
+        match value {
+            LoadBalancerTlsCertificateStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LoadBalancerTlsCertificateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LoadBalancerTlsCertificateStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LoadBalancerTlsCertificateStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<LoadBalancerTlsCertificateStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LoadBalancerTlsCertificateStatus::from(value))
+    }
+}
+
+/// <p>Provides a summary of SSL/TLS certificate metadata.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct LoadBalancerTlsCertificateSummary {
+    /// <p>When <code>true</code>, the SSL/TLS certificate is attached to the Lightsail load balancer.</p>
+    #[serde(rename = "isAttached")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_attached: Option<bool>,
+    /// <p>The name of the SSL/TLS certificate.</p>
+    #[serde(rename = "name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
@@ -3041,8 +8356,9 @@ pub struct LoadBalancerTlsCertificateSummary {
 pub struct LogEvent {
     /// <p>The timestamp when the database log event was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The message of the database log event.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3075,12 +8391,269 @@ pub struct MetricDatapoint {
     pub sum: Option<f64>,
     /// <p>The timestamp (e.g., <code>1479816991.349</code>).</p>
     #[serde(rename = "timestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<f64>,
+    pub timestamp: Option<EpochTimestamp>,
     /// <p>The unit. </p>
     #[serde(rename = "unit")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub unit: Option<String>,
+    pub unit: Option<MetricUnit>,
+}
+
+/// <p>The instance statistics. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetricStatistic {
+    /// <p><code>Minimum</code></p>
+    Minimum,
+    /// <p><code>Maximum</code></p>
+    Maximum,
+    /// <p><code>Sum</code></p>
+    Sum,
+    /// <p><code>Average</code></p>
+    Average,
+    /// <p><code>SampleCount</code></p>
+    SampleCount,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MetricStatistic {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricStatistic::Minimum => "Minimum",
+            MetricStatistic::Maximum => "Maximum",
+            MetricStatistic::Sum => "Sum",
+            MetricStatistic::Average => "Average",
+            MetricStatistic::SampleCount => "SampleCount",
+            MetricStatistic::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MetricStatistic {
+    fn from(value: &'a str) -> MetricStatistic {
+        match value {
+            "Minimum" => MetricStatistic::Minimum,
+            "Maximum" => MetricStatistic::Maximum,
+            "Sum" => MetricStatistic::Sum,
+            "Average" => MetricStatistic::Average,
+            "SampleCount" => MetricStatistic::SampleCount,
+            _ => MetricStatistic::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricStatistic {
+    fn from(value: String) -> MetricStatistic {
+        MetricStatistic::from(value.as_str())
+    }
+}
+
+impl From<MetricStatistic> for String {
+    fn from(value: MetricStatistic) -> String {
+        match value {
+            MetricStatistic::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MetricStatistic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MetricStatistic {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricStatistic {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MetricStatistic, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MetricStatistic::from(value))
+    }
+}
+
+/// <p>The unit. The list of valid values is below.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetricUnit {
+    /// <p><code>Seconds</code></p>
+    Seconds,
+    /// <p><code>Microseconds</code></p>
+    Microseconds,
+    /// <p><code>Milliseconds</code></p>
+    Milliseconds,
+    /// <p><code>Bytes</code></p>
+    Bytes,
+    /// <p><code>Kilobytes</code></p>
+    Kilobytes,
+    /// <p><code>Megabytes</code></p>
+    Megabytes,
+    /// <p><code>Gigabytes</code></p>
+    Gigabytes,
+    /// <p><code>Terabytes</code></p>
+    Terabytes,
+    /// <p><code>Bits</code></p>
+    Bits,
+    /// <p><code>Kilobits</code></p>
+    Kilobits,
+    /// <p><code>Megabits</code></p>
+    Megabits,
+    /// <p><code>Gigabits</code></p>
+    Gigabits,
+    /// <p><code>Terabits</code></p>
+    Terabits,
+    /// <p><code>Percent</code></p>
+    Percent,
+    /// <p><code>Count</code></p>
+    Count,
+    /// <p><code>Bytes/Second</code></p>
+    BytesSecond,
+    /// <p><code>Kilobytes/Second</code></p>
+    KilobytesSecond,
+    /// <p><code>Megabytes/Second</code></p>
+    MegabytesSecond,
+    /// <p><code>Gigabytes/Second</code></p>
+    GigabytesSecond,
+    /// <p><code>Terabytes/Second</code></p>
+    TerabytesSecond,
+    /// <p><code>Bits/Second</code></p>
+    BitsSecond,
+    /// <p><code>Kilobits/Second</code></p>
+    KilobitsSecond,
+    /// <p><code>Megabits/Second</code></p>
+    MegabitsSecond,
+    /// <p><code>Gigabits/Second</code></p>
+    GigabitsSecond,
+    /// <p><code>Terabits/Second</code></p>
+    TerabitsSecond,
+    /// <p><code>Count/Second</code></p>
+    CountSecond,
+    /// <p><code>None</code></p>
+    None,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MetricUnit {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MetricUnit::Seconds => "Seconds",
+            MetricUnit::Microseconds => "Microseconds",
+            MetricUnit::Milliseconds => "Milliseconds",
+            MetricUnit::Bytes => "Bytes",
+            MetricUnit::Kilobytes => "Kilobytes",
+            MetricUnit::Megabytes => "Megabytes",
+            MetricUnit::Gigabytes => "Gigabytes",
+            MetricUnit::Terabytes => "Terabytes",
+            MetricUnit::Bits => "Bits",
+            MetricUnit::Kilobits => "Kilobits",
+            MetricUnit::Megabits => "Megabits",
+            MetricUnit::Gigabits => "Gigabits",
+            MetricUnit::Terabits => "Terabits",
+            MetricUnit::Percent => "Percent",
+            MetricUnit::Count => "Count",
+            MetricUnit::BytesSecond => "Bytes/Second",
+            MetricUnit::KilobytesSecond => "Kilobytes/Second",
+            MetricUnit::MegabytesSecond => "Megabytes/Second",
+            MetricUnit::GigabytesSecond => "Gigabytes/Second",
+            MetricUnit::TerabytesSecond => "Terabytes/Second",
+            MetricUnit::BitsSecond => "Bits/Second",
+            MetricUnit::KilobitsSecond => "Kilobits/Second",
+            MetricUnit::MegabitsSecond => "Megabits/Second",
+            MetricUnit::GigabitsSecond => "Gigabits/Second",
+            MetricUnit::TerabitsSecond => "Terabits/Second",
+            MetricUnit::CountSecond => "Count/Second",
+            MetricUnit::None => "None",
+            MetricUnit::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MetricUnit {
+    fn from(value: &'a str) -> MetricUnit {
+        match value {
+            "Seconds" => MetricUnit::Seconds,
+            "Microseconds" => MetricUnit::Microseconds,
+            "Milliseconds" => MetricUnit::Milliseconds,
+            "Bytes" => MetricUnit::Bytes,
+            "Kilobytes" => MetricUnit::Kilobytes,
+            "Megabytes" => MetricUnit::Megabytes,
+            "Gigabytes" => MetricUnit::Gigabytes,
+            "Terabytes" => MetricUnit::Terabytes,
+            "Bits" => MetricUnit::Bits,
+            "Kilobits" => MetricUnit::Kilobits,
+            "Megabits" => MetricUnit::Megabits,
+            "Gigabits" => MetricUnit::Gigabits,
+            "Terabits" => MetricUnit::Terabits,
+            "Percent" => MetricUnit::Percent,
+            "Count" => MetricUnit::Count,
+            "Bytes/Second" => MetricUnit::BytesSecond,
+            "Kilobytes/Second" => MetricUnit::KilobytesSecond,
+            "Megabytes/Second" => MetricUnit::MegabytesSecond,
+            "Gigabytes/Second" => MetricUnit::GigabytesSecond,
+            "Terabytes/Second" => MetricUnit::TerabytesSecond,
+            "Bits/Second" => MetricUnit::BitsSecond,
+            "Kilobits/Second" => MetricUnit::KilobitsSecond,
+            "Megabits/Second" => MetricUnit::MegabitsSecond,
+            "Gigabits/Second" => MetricUnit::GigabitsSecond,
+            "Terabits/Second" => MetricUnit::TerabitsSecond,
+            "Count/Second" => MetricUnit::CountSecond,
+            "None" => MetricUnit::None,
+            _ => MetricUnit::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MetricUnit {
+    fn from(value: String) -> MetricUnit {
+        MetricUnit::from(value.as_str())
+    }
+}
+
+impl From<MetricUnit> for String {
+    fn from(value: MetricUnit) -> String {
+        match value {
+            MetricUnit::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MetricUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MetricUnit {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MetricUnit {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MetricUnit, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MetricUnit::from(value))
+    }
 }
 
 /// <p>Describes the monthly data transfer in and out of your virtual private server (or <i>instance</i>).</p>
@@ -3093,6 +8666,86 @@ pub struct MonthlyTransfer {
     pub gb_per_month_allocated: Option<i64>,
 }
 
+/// <p><p>The protocol being used. Can be one of the following.</p> <ul> <li> <p> <code>tcp</code> - Transmission Control Protocol (TCP) provides reliable, ordered, and error-checked delivery of streamed data between applications running on hosts communicating by an IP network. If you have an application that doesn&#39;t require reliable data stream service, use UDP instead.</p> </li> <li> <p> <code>all</code> - All transport layer protocol types. For more general information, see <a href="https://en.wikipedia.org/wiki/Transport_layer">Transport layer</a> on Wikipedia.</p> </li> <li> <p> <code>udp</code> - With User Datagram Protocol (UDP), computer applications can send messages (or datagrams) to other hosts on an Internet Protocol (IP) network. Prior communications are not required to set up transmission channels or data paths. Applications that don&#39;t require reliable data stream service can use UDP, which provides a connectionless datagram service that emphasizes reduced latency over reliability. If you do require reliable data stream service, use TCP instead.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NetworkProtocol {
+    /// <p><code>tcp</code> - Transmission Control Protocol (TCP) provides reliable, ordered, and error-checked delivery of streamed data between applications running on hosts communicating by an IP network. If you have an application that doesn&#39;t require reliable data stream service, use UDP instead.</p>
+    Tcp,
+    /// <p><code>all</code> - All transport layer protocol types. For more general information, see <a href="https://en.wikipedia.org/wiki/Transport_layer">Transport layer</a> on Wikipedia.</p>
+    All,
+    /// <p><code>udp</code> - With User Datagram Protocol (UDP), computer applications can send messages (or datagrams) to other hosts on an Internet Protocol (IP) network. Prior communications are not required to set up transmission channels or data paths. Applications that don&#39;t require reliable data stream service can use UDP, which provides a connectionless datagram service that emphasizes reduced latency over reliability. If you do require reliable data stream service, use TCP instead.</p>
+    Udp,
+    /// <p><code>icmp</code></p>
+    Icmp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NetworkProtocol {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NetworkProtocol::Tcp => "tcp",
+            NetworkProtocol::All => "all",
+            NetworkProtocol::Udp => "udp",
+            NetworkProtocol::Icmp => "icmp",
+            NetworkProtocol::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NetworkProtocol {
+    fn from(value: &'a str) -> NetworkProtocol {
+        match value {
+            "tcp" => NetworkProtocol::Tcp,
+            "all" => NetworkProtocol::All,
+            "udp" => NetworkProtocol::Udp,
+            "icmp" => NetworkProtocol::Icmp,
+            _ => NetworkProtocol::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NetworkProtocol {
+    fn from(value: String) -> NetworkProtocol {
+        NetworkProtocol::from(value.as_str())
+    }
+}
+
+impl From<NetworkProtocol> for String {
+    fn from(value: NetworkProtocol) -> String {
+        match value {
+            NetworkProtocol::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NetworkProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NetworkProtocol {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NetworkProtocol {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NetworkProtocol, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NetworkProtocol::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct OpenInstancePublicPortsRequest {
     /// <p>The name of the instance for which you want to open the public ports.</p>
@@ -3103,6 +8756,48 @@ pub struct OpenInstancePublicPortsRequest {
     pub port_info: PortInfo,
 }
 
+impl OpenInstancePublicPortsRequest {
+    /// Returns a builder for `OpenInstancePublicPortsRequest`, checking its required members when built.
+    pub fn builder() -> OpenInstancePublicPortsRequestBuilder {
+        OpenInstancePublicPortsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`OpenInstancePublicPortsRequest`](struct.OpenInstancePublicPortsRequest.html).
+#[derive(Default, Debug)]
+pub struct OpenInstancePublicPortsRequestBuilder {
+    instance_name: Option<String>,
+    port_info: Option<PortInfo>,
+}
+
+impl OpenInstancePublicPortsRequestBuilder {
+    /// Sets the [`instance_name`](struct.OpenInstancePublicPortsRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port_info`](struct.OpenInstancePublicPortsRequest.html#structfield.port_info) member.
+    pub fn port_info<T: Into<PortInfo>>(mut self, value: T) -> Self {
+        self.port_info = Some(value.into());
+        self
+    }
+
+    /// Builds the `OpenInstancePublicPortsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<OpenInstancePublicPortsRequest, ::rusoto_core::BuildError> {
+        Ok(OpenInstancePublicPortsRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+            port_info: self
+                .port_info
+                .ok_or(::rusoto_core::BuildError::missing("port_info"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct OpenInstancePublicPortsResult {
@@ -3118,8 +8813,9 @@ pub struct OpenInstancePublicPortsResult {
 pub struct Operation {
     /// <p>The timestamp when the operation was initialized (e.g., <code>1479816991.349</code>).</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The error code.</p>
     #[serde(rename = "errorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3147,7 +8843,7 @@ pub struct Operation {
     /// <p>The type of operation. </p>
     #[serde(rename = "operationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub operation_type: Option<String>,
+    pub operation_type: Option<OperationType>,
     /// <p>The resource name.</p>
     #[serde(rename = "resourceName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3155,15 +8851,508 @@ pub struct Operation {
     /// <p>The resource type. </p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The status of the operation. </p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<OperationStatus>,
     /// <p>The timestamp when the status was changed (e.g., <code>1479816991.349</code>).</p>
     #[serde(rename = "statusChangedAt")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_changed_at: Option<f64>,
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_changed_at: Option<EpochTimestamp>,
+}
+
+/// <p>The status of the operation. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperationStatus {
+    /// <p><code>NotStarted</code></p>
+    NotStarted,
+    /// <p><code>Started</code></p>
+    Started,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Completed</code></p>
+    Completed,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OperationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationStatus::NotStarted => "NotStarted",
+            OperationStatus::Started => "Started",
+            OperationStatus::Failed => "Failed",
+            OperationStatus::Completed => "Completed",
+            OperationStatus::Succeeded => "Succeeded",
+            OperationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperationStatus {
+    fn from(value: &'a str) -> OperationStatus {
+        match value {
+            "NotStarted" => OperationStatus::NotStarted,
+            "Started" => OperationStatus::Started,
+            "Failed" => OperationStatus::Failed,
+            "Completed" => OperationStatus::Completed,
+            "Succeeded" => OperationStatus::Succeeded,
+            _ => OperationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationStatus {
+    fn from(value: String) -> OperationStatus {
+        OperationStatus::from(value.as_str())
+    }
+}
+
+impl From<OperationStatus> for String {
+    fn from(value: OperationStatus) -> String {
+        match value {
+            OperationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OperationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OperationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OperationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OperationStatus::from(value))
+    }
+}
+
+/// <p>The type of operation. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OperationType {
+    /// <p><code>DeleteKnownHostKeys</code></p>
+    DeleteKnownHostKeys,
+    /// <p><code>DeleteInstance</code></p>
+    DeleteInstance,
+    /// <p><code>CreateInstance</code></p>
+    CreateInstance,
+    /// <p><code>StopInstance</code></p>
+    StopInstance,
+    /// <p><code>StartInstance</code></p>
+    StartInstance,
+    /// <p><code>RebootInstance</code></p>
+    RebootInstance,
+    /// <p><code>OpenInstancePublicPorts</code></p>
+    OpenInstancePublicPorts,
+    /// <p><code>PutInstancePublicPorts</code></p>
+    PutInstancePublicPorts,
+    /// <p><code>CloseInstancePublicPorts</code></p>
+    CloseInstancePublicPorts,
+    /// <p><code>AllocateStaticIp</code></p>
+    AllocateStaticIp,
+    /// <p><code>ReleaseStaticIp</code></p>
+    ReleaseStaticIp,
+    /// <p><code>AttachStaticIp</code></p>
+    AttachStaticIp,
+    /// <p><code>DetachStaticIp</code></p>
+    DetachStaticIp,
+    /// <p><code>UpdateDomainEntry</code></p>
+    UpdateDomainEntry,
+    /// <p><code>DeleteDomainEntry</code></p>
+    DeleteDomainEntry,
+    /// <p><code>CreateDomain</code></p>
+    CreateDomain,
+    /// <p><code>DeleteDomain</code></p>
+    DeleteDomain,
+    /// <p><code>CreateInstanceSnapshot</code></p>
+    CreateInstanceSnapshot,
+    /// <p><code>DeleteInstanceSnapshot</code></p>
+    DeleteInstanceSnapshot,
+    /// <p><code>CreateInstancesFromSnapshot</code></p>
+    CreateInstancesFromSnapshot,
+    /// <p><code>CreateLoadBalancer</code></p>
+    CreateLoadBalancer,
+    /// <p><code>DeleteLoadBalancer</code></p>
+    DeleteLoadBalancer,
+    /// <p><code>AttachInstancesToLoadBalancer</code></p>
+    AttachInstancesToLoadBalancer,
+    /// <p><code>DetachInstancesFromLoadBalancer</code></p>
+    DetachInstancesFromLoadBalancer,
+    /// <p><code>UpdateLoadBalancerAttribute</code></p>
+    UpdateLoadBalancerAttribute,
+    /// <p><code>CreateLoadBalancerTlsCertificate</code></p>
+    CreateLoadBalancerTlsCertificate,
+    /// <p><code>DeleteLoadBalancerTlsCertificate</code></p>
+    DeleteLoadBalancerTlsCertificate,
+    /// <p><code>AttachLoadBalancerTlsCertificate</code></p>
+    AttachLoadBalancerTlsCertificate,
+    /// <p><code>CreateDisk</code></p>
+    CreateDisk,
+    /// <p><code>DeleteDisk</code></p>
+    DeleteDisk,
+    /// <p><code>AttachDisk</code></p>
+    AttachDisk,
+    /// <p><code>DetachDisk</code></p>
+    DetachDisk,
+    /// <p><code>CreateDiskSnapshot</code></p>
+    CreateDiskSnapshot,
+    /// <p><code>DeleteDiskSnapshot</code></p>
+    DeleteDiskSnapshot,
+    /// <p><code>CreateDiskFromSnapshot</code></p>
+    CreateDiskFromSnapshot,
+    /// <p><code>CreateRelationalDatabase</code></p>
+    CreateRelationalDatabase,
+    /// <p><code>UpdateRelationalDatabase</code></p>
+    UpdateRelationalDatabase,
+    /// <p><code>DeleteRelationalDatabase</code></p>
+    DeleteRelationalDatabase,
+    /// <p><code>CreateRelationalDatabaseFromSnapshot</code></p>
+    CreateRelationalDatabaseFromSnapshot,
+    /// <p><code>CreateRelationalDatabaseSnapshot</code></p>
+    CreateRelationalDatabaseSnapshot,
+    /// <p><code>DeleteRelationalDatabaseSnapshot</code></p>
+    DeleteRelationalDatabaseSnapshot,
+    /// <p><code>UpdateRelationalDatabaseParameters</code></p>
+    UpdateRelationalDatabaseParameters,
+    /// <p><code>StartRelationalDatabase</code></p>
+    StartRelationalDatabase,
+    /// <p><code>RebootRelationalDatabase</code></p>
+    RebootRelationalDatabase,
+    /// <p><code>StopRelationalDatabase</code></p>
+    StopRelationalDatabase,
+    /// <p><code>EnableAddOn</code></p>
+    EnableAddOn,
+    /// <p><code>DisableAddOn</code></p>
+    DisableAddOn,
+    /// <p><code>PutAlarm</code></p>
+    PutAlarm,
+    /// <p><code>GetAlarms</code></p>
+    GetAlarms,
+    /// <p><code>DeleteAlarm</code></p>
+    DeleteAlarm,
+    /// <p><code>TestAlarm</code></p>
+    TestAlarm,
+    /// <p><code>CreateContactMethod</code></p>
+    CreateContactMethod,
+    /// <p><code>GetContactMethods</code></p>
+    GetContactMethods,
+    /// <p><code>SendContactMethodVerification</code></p>
+    SendContactMethodVerification,
+    /// <p><code>DeleteContactMethod</code></p>
+    DeleteContactMethod,
+    /// <p><code>CreateDistribution</code></p>
+    CreateDistribution,
+    /// <p><code>UpdateDistribution</code></p>
+    UpdateDistribution,
+    /// <p><code>DeleteDistribution</code></p>
+    DeleteDistribution,
+    /// <p><code>ResetDistributionCache</code></p>
+    ResetDistributionCache,
+    /// <p><code>AttachCertificateToDistribution</code></p>
+    AttachCertificateToDistribution,
+    /// <p><code>DetachCertificateFromDistribution</code></p>
+    DetachCertificateFromDistribution,
+    /// <p><code>UpdateDistributionBundle</code></p>
+    UpdateDistributionBundle,
+    /// <p><code>SetIpAddressType</code></p>
+    SetIpAddressType,
+    /// <p><code>CreateCertificate</code></p>
+    CreateCertificate,
+    /// <p><code>DeleteCertificate</code></p>
+    DeleteCertificate,
+    /// <p><code>CreateContainerService</code></p>
+    CreateContainerService,
+    /// <p><code>UpdateContainerService</code></p>
+    UpdateContainerService,
+    /// <p><code>DeleteContainerService</code></p>
+    DeleteContainerService,
+    /// <p><code>CreateContainerServiceDeployment</code></p>
+    CreateContainerServiceDeployment,
+    /// <p><code>CreateContainerServiceRegistryLogin</code></p>
+    CreateContainerServiceRegistryLogin,
+    /// <p><code>RegisterContainerImage</code></p>
+    RegisterContainerImage,
+    /// <p><code>DeleteContainerImage</code></p>
+    DeleteContainerImage,
+    /// <p><code>CreateBucket</code></p>
+    CreateBucket,
+    /// <p><code>DeleteBucket</code></p>
+    DeleteBucket,
+    /// <p><code>CreateBucketAccessKey</code></p>
+    CreateBucketAccessKey,
+    /// <p><code>DeleteBucketAccessKey</code></p>
+    DeleteBucketAccessKey,
+    /// <p><code>UpdateBucketBundle</code></p>
+    UpdateBucketBundle,
+    /// <p><code>UpdateBucket</code></p>
+    UpdateBucket,
+    /// <p><code>SetResourceAccessForBucket</code></p>
+    SetResourceAccessForBucket,
+    /// <p><code>UpdateInstanceMetadataOptions</code></p>
+    UpdateInstanceMetadataOptions,
+    /// <p><code>StartGUISession</code></p>
+    StartGUISession,
+    /// <p><code>StopGUISession</code></p>
+    StopGUISession,
+    /// <p><code>SetupInstanceHttps</code></p>
+    SetupInstanceHttps,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OperationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OperationType::DeleteKnownHostKeys => "DeleteKnownHostKeys",
+            OperationType::DeleteInstance => "DeleteInstance",
+            OperationType::CreateInstance => "CreateInstance",
+            OperationType::StopInstance => "StopInstance",
+            OperationType::StartInstance => "StartInstance",
+            OperationType::RebootInstance => "RebootInstance",
+            OperationType::OpenInstancePublicPorts => "OpenInstancePublicPorts",
+            OperationType::PutInstancePublicPorts => "PutInstancePublicPorts",
+            OperationType::CloseInstancePublicPorts => "CloseInstancePublicPorts",
+            OperationType::AllocateStaticIp => "AllocateStaticIp",
+            OperationType::ReleaseStaticIp => "ReleaseStaticIp",
+            OperationType::AttachStaticIp => "AttachStaticIp",
+            OperationType::DetachStaticIp => "DetachStaticIp",
+            OperationType::UpdateDomainEntry => "UpdateDomainEntry",
+            OperationType::DeleteDomainEntry => "DeleteDomainEntry",
+            OperationType::CreateDomain => "CreateDomain",
+            OperationType::DeleteDomain => "DeleteDomain",
+            OperationType::CreateInstanceSnapshot => "CreateInstanceSnapshot",
+            OperationType::DeleteInstanceSnapshot => "DeleteInstanceSnapshot",
+            OperationType::CreateInstancesFromSnapshot => "CreateInstancesFromSnapshot",
+            OperationType::CreateLoadBalancer => "CreateLoadBalancer",
+            OperationType::DeleteLoadBalancer => "DeleteLoadBalancer",
+            OperationType::AttachInstancesToLoadBalancer => "AttachInstancesToLoadBalancer",
+            OperationType::DetachInstancesFromLoadBalancer => "DetachInstancesFromLoadBalancer",
+            OperationType::UpdateLoadBalancerAttribute => "UpdateLoadBalancerAttribute",
+            OperationType::CreateLoadBalancerTlsCertificate => "CreateLoadBalancerTlsCertificate",
+            OperationType::DeleteLoadBalancerTlsCertificate => "DeleteLoadBalancerTlsCertificate",
+            OperationType::AttachLoadBalancerTlsCertificate => "AttachLoadBalancerTlsCertificate",
+            OperationType::CreateDisk => "CreateDisk",
+            OperationType::DeleteDisk => "DeleteDisk",
+            OperationType::AttachDisk => "AttachDisk",
+            OperationType::DetachDisk => "DetachDisk",
+            OperationType::CreateDiskSnapshot => "CreateDiskSnapshot",
+            OperationType::DeleteDiskSnapshot => "DeleteDiskSnapshot",
+            OperationType::CreateDiskFromSnapshot => "CreateDiskFromSnapshot",
+            OperationType::CreateRelationalDatabase => "CreateRelationalDatabase",
+            OperationType::UpdateRelationalDatabase => "UpdateRelationalDatabase",
+            OperationType::DeleteRelationalDatabase => "DeleteRelationalDatabase",
+            OperationType::CreateRelationalDatabaseFromSnapshot => {
+                "CreateRelationalDatabaseFromSnapshot"
+            }
+            OperationType::CreateRelationalDatabaseSnapshot => "CreateRelationalDatabaseSnapshot",
+            OperationType::DeleteRelationalDatabaseSnapshot => "DeleteRelationalDatabaseSnapshot",
+            OperationType::UpdateRelationalDatabaseParameters => {
+                "UpdateRelationalDatabaseParameters"
+            }
+            OperationType::StartRelationalDatabase => "StartRelationalDatabase",
+            OperationType::RebootRelationalDatabase => "RebootRelationalDatabase",
+            OperationType::StopRelationalDatabase => "StopRelationalDatabase",
+            OperationType::EnableAddOn => "EnableAddOn",
+            OperationType::DisableAddOn => "DisableAddOn",
+            OperationType::PutAlarm => "PutAlarm",
+            OperationType::GetAlarms => "GetAlarms",
+            OperationType::DeleteAlarm => "DeleteAlarm",
+            OperationType::TestAlarm => "TestAlarm",
+            OperationType::CreateContactMethod => "CreateContactMethod",
+            OperationType::GetContactMethods => "GetContactMethods",
+            OperationType::SendContactMethodVerification => "SendContactMethodVerification",
+            OperationType::DeleteContactMethod => "DeleteContactMethod",
+            OperationType::CreateDistribution => "CreateDistribution",
+            OperationType::UpdateDistribution => "UpdateDistribution",
+            OperationType::DeleteDistribution => "DeleteDistribution",
+            OperationType::ResetDistributionCache => "ResetDistributionCache",
+            OperationType::AttachCertificateToDistribution => "AttachCertificateToDistribution",
+            OperationType::DetachCertificateFromDistribution => "DetachCertificateFromDistribution",
+            OperationType::UpdateDistributionBundle => "UpdateDistributionBundle",
+            OperationType::SetIpAddressType => "SetIpAddressType",
+            OperationType::CreateCertificate => "CreateCertificate",
+            OperationType::DeleteCertificate => "DeleteCertificate",
+            OperationType::CreateContainerService => "CreateContainerService",
+            OperationType::UpdateContainerService => "UpdateContainerService",
+            OperationType::DeleteContainerService => "DeleteContainerService",
+            OperationType::CreateContainerServiceDeployment => "CreateContainerServiceDeployment",
+            OperationType::CreateContainerServiceRegistryLogin => {
+                "CreateContainerServiceRegistryLogin"
+            }
+            OperationType::RegisterContainerImage => "RegisterContainerImage",
+            OperationType::DeleteContainerImage => "DeleteContainerImage",
+            OperationType::CreateBucket => "CreateBucket",
+            OperationType::DeleteBucket => "DeleteBucket",
+            OperationType::CreateBucketAccessKey => "CreateBucketAccessKey",
+            OperationType::DeleteBucketAccessKey => "DeleteBucketAccessKey",
+            OperationType::UpdateBucketBundle => "UpdateBucketBundle",
+            OperationType::UpdateBucket => "UpdateBucket",
+            OperationType::SetResourceAccessForBucket => "SetResourceAccessForBucket",
+            OperationType::UpdateInstanceMetadataOptions => "UpdateInstanceMetadataOptions",
+            OperationType::StartGUISession => "StartGUISession",
+            OperationType::StopGUISession => "StopGUISession",
+            OperationType::SetupInstanceHttps => "SetupInstanceHttps",
+            OperationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OperationType {
+    fn from(value: &'a str) -> OperationType {
+        match value {
+            "DeleteKnownHostKeys" => OperationType::DeleteKnownHostKeys,
+            "DeleteInstance" => OperationType::DeleteInstance,
+            "CreateInstance" => OperationType::CreateInstance,
+            "StopInstance" => OperationType::StopInstance,
+            "StartInstance" => OperationType::StartInstance,
+            "RebootInstance" => OperationType::RebootInstance,
+            "OpenInstancePublicPorts" => OperationType::OpenInstancePublicPorts,
+            "PutInstancePublicPorts" => OperationType::PutInstancePublicPorts,
+            "CloseInstancePublicPorts" => OperationType::CloseInstancePublicPorts,
+            "AllocateStaticIp" => OperationType::AllocateStaticIp,
+            "ReleaseStaticIp" => OperationType::ReleaseStaticIp,
+            "AttachStaticIp" => OperationType::AttachStaticIp,
+            "DetachStaticIp" => OperationType::DetachStaticIp,
+            "UpdateDomainEntry" => OperationType::UpdateDomainEntry,
+            "DeleteDomainEntry" => OperationType::DeleteDomainEntry,
+            "CreateDomain" => OperationType::CreateDomain,
+            "DeleteDomain" => OperationType::DeleteDomain,
+            "CreateInstanceSnapshot" => OperationType::CreateInstanceSnapshot,
+            "DeleteInstanceSnapshot" => OperationType::DeleteInstanceSnapshot,
+            "CreateInstancesFromSnapshot" => OperationType::CreateInstancesFromSnapshot,
+            "CreateLoadBalancer" => OperationType::CreateLoadBalancer,
+            "DeleteLoadBalancer" => OperationType::DeleteLoadBalancer,
+            "AttachInstancesToLoadBalancer" => OperationType::AttachInstancesToLoadBalancer,
+            "DetachInstancesFromLoadBalancer" => OperationType::DetachInstancesFromLoadBalancer,
+            "UpdateLoadBalancerAttribute" => OperationType::UpdateLoadBalancerAttribute,
+            "CreateLoadBalancerTlsCertificate" => OperationType::CreateLoadBalancerTlsCertificate,
+            "DeleteLoadBalancerTlsCertificate" => OperationType::DeleteLoadBalancerTlsCertificate,
+            "AttachLoadBalancerTlsCertificate" => OperationType::AttachLoadBalancerTlsCertificate,
+            "CreateDisk" => OperationType::CreateDisk,
+            "DeleteDisk" => OperationType::DeleteDisk,
+            "AttachDisk" => OperationType::AttachDisk,
+            "DetachDisk" => OperationType::DetachDisk,
+            "CreateDiskSnapshot" => OperationType::CreateDiskSnapshot,
+            "DeleteDiskSnapshot" => OperationType::DeleteDiskSnapshot,
+            "CreateDiskFromSnapshot" => OperationType::CreateDiskFromSnapshot,
+            "CreateRelationalDatabase" => OperationType::CreateRelationalDatabase,
+            "UpdateRelationalDatabase" => OperationType::UpdateRelationalDatabase,
+            "DeleteRelationalDatabase" => OperationType::DeleteRelationalDatabase,
+            "CreateRelationalDatabaseFromSnapshot" => {
+                OperationType::CreateRelationalDatabaseFromSnapshot
+            }
+            "CreateRelationalDatabaseSnapshot" => OperationType::CreateRelationalDatabaseSnapshot,
+            "DeleteRelationalDatabaseSnapshot" => OperationType::DeleteRelationalDatabaseSnapshot,
+            "UpdateRelationalDatabaseParameters" => {
+                OperationType::UpdateRelationalDatabaseParameters
+            }
+            "StartRelationalDatabase" => OperationType::StartRelationalDatabase,
+            "RebootRelationalDatabase" => OperationType::RebootRelationalDatabase,
+            "StopRelationalDatabase" => OperationType::StopRelationalDatabase,
+            "EnableAddOn" => OperationType::EnableAddOn,
+            "DisableAddOn" => OperationType::DisableAddOn,
+            "PutAlarm" => OperationType::PutAlarm,
+            "GetAlarms" => OperationType::GetAlarms,
+            "DeleteAlarm" => OperationType::DeleteAlarm,
+            "TestAlarm" => OperationType::TestAlarm,
+            "CreateContactMethod" => OperationType::CreateContactMethod,
+            "GetContactMethods" => OperationType::GetContactMethods,
+            "SendContactMethodVerification" => OperationType::SendContactMethodVerification,
+            "DeleteContactMethod" => OperationType::DeleteContactMethod,
+            "CreateDistribution" => OperationType::CreateDistribution,
+            "UpdateDistribution" => OperationType::UpdateDistribution,
+            "DeleteDistribution" => OperationType::DeleteDistribution,
+            "ResetDistributionCache" => OperationType::ResetDistributionCache,
+            "AttachCertificateToDistribution" => OperationType::AttachCertificateToDistribution,
+            "DetachCertificateFromDistribution" => OperationType::DetachCertificateFromDistribution,
+            "UpdateDistributionBundle" => OperationType::UpdateDistributionBundle,
+            "SetIpAddressType" => OperationType::SetIpAddressType,
+            "CreateCertificate" => OperationType::CreateCertificate,
+            "DeleteCertificate" => OperationType::DeleteCertificate,
+            "CreateContainerService" => OperationType::CreateContainerService,
+            "UpdateContainerService" => OperationType::UpdateContainerService,
+            "DeleteContainerService" => OperationType::DeleteContainerService,
+            "CreateContainerServiceDeployment" => OperationType::CreateContainerServiceDeployment,
+            "CreateContainerServiceRegistryLogin" => {
+                OperationType::CreateContainerServiceRegistryLogin
+            }
+            "RegisterContainerImage" => OperationType::RegisterContainerImage,
+            "DeleteContainerImage" => OperationType::DeleteContainerImage,
+            "CreateBucket" => OperationType::CreateBucket,
+            "DeleteBucket" => OperationType::DeleteBucket,
+            "CreateBucketAccessKey" => OperationType::CreateBucketAccessKey,
+            "DeleteBucketAccessKey" => OperationType::DeleteBucketAccessKey,
+            "UpdateBucketBundle" => OperationType::UpdateBucketBundle,
+            "UpdateBucket" => OperationType::UpdateBucket,
+            "SetResourceAccessForBucket" => OperationType::SetResourceAccessForBucket,
+            "UpdateInstanceMetadataOptions" => OperationType::UpdateInstanceMetadataOptions,
+            "StartGUISession" => OperationType::StartGUISession,
+            "StopGUISession" => OperationType::StopGUISession,
+            "SetupInstanceHttps" => OperationType::SetupInstanceHttps,
+            _ => OperationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OperationType {
+    fn from(value: String) -> OperationType {
+        OperationType::from(value.as_str())
+    }
+}
+
+impl From<OperationType> for String {
+    fn from(value: OperationType) -> String {
+        match value {
+            OperationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OperationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OperationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OperationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OperationType::from(value))
+    }
 }
 
 /// <p>The password data for the Windows Server-based instance, including the ciphertext and the key pair name.</p>
@@ -3202,8 +9391,9 @@ pub struct PendingMaintenanceAction {
     pub action: Option<String>,
     /// <p>The effective date of the pending database maintenance action.</p>
     #[serde(rename = "currentApplyDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub current_apply_date: Option<f64>,
+    pub current_apply_date: Option<EpochTimestamp>,
     /// <p>Additional detail about the pending database maintenance action.</p>
     #[serde(rename = "description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3228,6 +9418,78 @@ pub struct PendingModifiedRelationalDatabaseValues {
     pub master_user_password: Option<String>,
 }
 
+/// <p>The type of access (<code>Public</code> or <code>Private</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PortAccessType {
+    /// <p><code>Public</code></p>
+    Public,
+    /// <p><code>Private</code></p>
+    Private,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PortAccessType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PortAccessType::Public => "Public",
+            PortAccessType::Private => "Private",
+            PortAccessType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PortAccessType {
+    fn from(value: &'a str) -> PortAccessType {
+        match value {
+            "Public" => PortAccessType::Public,
+            "Private" => PortAccessType::Private,
+            _ => PortAccessType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PortAccessType {
+    fn from(value: String) -> PortAccessType {
+        PortAccessType::from(value.as_str())
+    }
+}
+
+impl From<PortAccessType> for String {
+    fn from(value: PortAccessType) -> String {
+        match value {
+            PortAccessType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PortAccessType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PortAccessType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PortAccessType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PortAccessType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PortAccessType::from(value))
+    }
+}
+
 /// <p>Describes information about the ports on your virtual private server (or <i>instance</i>).</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PortInfo {
@@ -3238,13 +9500,165 @@ pub struct PortInfo {
     /// <p>The protocol. </p>
     #[serde(rename = "protocol")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<NetworkProtocol>,
     /// <p>The last port in the range.</p>
     #[serde(rename = "toPort")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub to_port: Option<i64>,
 }
 
+/// <p><p>The port configuration to use for the new Amazon EC2 instance.</p> <p>The following configuration options are available:</p> <ul> <li> <p>DEFAULT — Use the default firewall settings from the image.</p> </li> <li> <p>INSTANCE — Use the firewall settings from the source Lightsail instance.</p> </li> <li> <p>NONE — Default to Amazon EC2.</p> </li> <li> <p>CLOSED — All ports closed.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PortInfoSourceType {
+    /// <p><code>DEFAULT</code></p>
+    Default,
+    /// <p><code>INSTANCE</code></p>
+    Instance,
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>CLOSED</code></p>
+    Closed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PortInfoSourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PortInfoSourceType::Default => "DEFAULT",
+            PortInfoSourceType::Instance => "INSTANCE",
+            PortInfoSourceType::None => "NONE",
+            PortInfoSourceType::Closed => "CLOSED",
+            PortInfoSourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PortInfoSourceType {
+    fn from(value: &'a str) -> PortInfoSourceType {
+        match value {
+            "DEFAULT" => PortInfoSourceType::Default,
+            "INSTANCE" => PortInfoSourceType::Instance,
+            "NONE" => PortInfoSourceType::None,
+            "CLOSED" => PortInfoSourceType::Closed,
+            _ => PortInfoSourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PortInfoSourceType {
+    fn from(value: String) -> PortInfoSourceType {
+        PortInfoSourceType::from(value.as_str())
+    }
+}
+
+impl From<PortInfoSourceType> for String {
+    fn from(value: PortInfoSourceType) -> String {
+        match value {
+            PortInfoSourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PortInfoSourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PortInfoSourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PortInfoSourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PortInfoSourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PortInfoSourceType::from(value))
+    }
+}
+
+/// <p>Specifies whether the instance port is <code>open</code> or <code>closed</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PortState {
+    /// <p><code>open</code></p>
+    Open,
+    /// <p><code>closed</code></p>
+    Closed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PortState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PortState::Open => "open",
+            PortState::Closed => "closed",
+            PortState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PortState {
+    fn from(value: &'a str) -> PortState {
+        match value {
+            "open" => PortState::Open,
+            "closed" => PortState::Closed,
+            _ => PortState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PortState {
+    fn from(value: String) -> PortState {
+        PortState::from(value.as_str())
+    }
+}
+
+impl From<PortState> for String {
+    fn from(value: PortState) -> String {
+        match value {
+            PortState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PortState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PortState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PortState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PortState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PortState::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutInstancePublicPortsRequest {
     /// <p>The Lightsail instance name of the public port(s) you are setting.</p>
@@ -3255,6 +9669,48 @@ pub struct PutInstancePublicPortsRequest {
     pub port_infos: Vec<PortInfo>,
 }
 
+impl PutInstancePublicPortsRequest {
+    /// Returns a builder for `PutInstancePublicPortsRequest`, checking its required members when built.
+    pub fn builder() -> PutInstancePublicPortsRequestBuilder {
+        PutInstancePublicPortsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutInstancePublicPortsRequest`](struct.PutInstancePublicPortsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutInstancePublicPortsRequestBuilder {
+    instance_name: Option<String>,
+    port_infos: Option<Vec<PortInfo>>,
+}
+
+impl PutInstancePublicPortsRequestBuilder {
+    /// Sets the [`instance_name`](struct.PutInstancePublicPortsRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port_infos`](struct.PutInstancePublicPortsRequest.html#structfield.port_infos) member.
+    pub fn port_infos<T: Into<Vec<PortInfo>>>(mut self, value: T) -> Self {
+        self.port_infos = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutInstancePublicPortsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutInstancePublicPortsRequest, ::rusoto_core::BuildError> {
+        Ok(PutInstancePublicPortsRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+            port_infos: self
+                .port_infos
+                .ok_or(::rusoto_core::BuildError::missing("port_infos"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutInstancePublicPortsResult {
@@ -3271,6 +9727,36 @@ pub struct RebootInstanceRequest {
     pub instance_name: String,
 }
 
+impl RebootInstanceRequest {
+    /// Returns a builder for `RebootInstanceRequest`, checking its required members when built.
+    pub fn builder() -> RebootInstanceRequestBuilder {
+        RebootInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RebootInstanceRequest`](struct.RebootInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct RebootInstanceRequestBuilder {
+    instance_name: Option<String>,
+}
+
+impl RebootInstanceRequestBuilder {
+    /// Sets the [`instance_name`](struct.RebootInstanceRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebootInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RebootInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(RebootInstanceRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RebootInstanceResult {
@@ -3287,6 +9773,38 @@ pub struct RebootRelationalDatabaseRequest {
     pub relational_database_name: String,
 }
 
+impl RebootRelationalDatabaseRequest {
+    /// Returns a builder for `RebootRelationalDatabaseRequest`, checking its required members when built.
+    pub fn builder() -> RebootRelationalDatabaseRequestBuilder {
+        RebootRelationalDatabaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RebootRelationalDatabaseRequest`](struct.RebootRelationalDatabaseRequest.html).
+#[derive(Default, Debug)]
+pub struct RebootRelationalDatabaseRequestBuilder {
+    relational_database_name: Option<String>,
+}
+
+impl RebootRelationalDatabaseRequestBuilder {
+    /// Sets the [`relational_database_name`](struct.RebootRelationalDatabaseRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebootRelationalDatabaseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RebootRelationalDatabaseRequest, ::rusoto_core::BuildError> {
+        Ok(RebootRelationalDatabaseRequest {
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RebootRelationalDatabaseResult {
@@ -3296,6 +9814,82 @@ pub struct RebootRelationalDatabaseResult {
     pub operations: Option<Vec<Operation>>,
 }
 
+/// <p>The current state of the CloudFormation stack record.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordState {
+    /// <p><code>Started</code></p>
+    Started,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RecordState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RecordState::Started => "Started",
+            RecordState::Succeeded => "Succeeded",
+            RecordState::Failed => "Failed",
+            RecordState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RecordState {
+    fn from(value: &'a str) -> RecordState {
+        match value {
+            "Started" => RecordState::Started,
+            "Succeeded" => RecordState::Succeeded,
+            "Failed" => RecordState::Failed,
+            _ => RecordState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RecordState {
+    fn from(value: String) -> RecordState {
+        RecordState::from(value.as_str())
+    }
+}
+
+impl From<RecordState> for String {
+    fn from(value: RecordState) -> String {
+        match value {
+            RecordState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RecordState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RecordState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RecordState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RecordState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RecordState::from(value))
+    }
+}
+
 /// <p>Describes the AWS Region.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3319,13 +9913,137 @@ pub struct Region {
     /// <p>The region name (e.g., <code>us-east-2</code>).</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
+    pub name: Option<RegionName>,
     /// <p>The Availability Zones for databases. Follows the format <code>us-east-2a</code> (case-sensitive).</p>
     #[serde(rename = "relationalDatabaseAvailabilityZones")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub relational_database_availability_zones: Option<Vec<AvailabilityZone>>,
 }
 
+/// <p>The AWS Region where the source snapshot is located.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RegionName {
+    /// <p><code>us-east-1</code></p>
+    UsEast1,
+    /// <p><code>us-east-2</code></p>
+    UsEast2,
+    /// <p><code>us-west-1</code></p>
+    UsWest1,
+    /// <p><code>us-west-2</code></p>
+    UsWest2,
+    /// <p><code>eu-west-1</code></p>
+    EuWest1,
+    /// <p><code>eu-west-2</code></p>
+    EuWest2,
+    /// <p><code>eu-west-3</code></p>
+    EuWest3,
+    /// <p><code>eu-central-1</code></p>
+    EuCentral1,
+    /// <p><code>ca-central-1</code></p>
+    CaCentral1,
+    /// <p><code>ap-south-1</code></p>
+    ApSouth1,
+    /// <p><code>ap-southeast-1</code></p>
+    ApSoutheast1,
+    /// <p><code>ap-southeast-2</code></p>
+    ApSoutheast2,
+    /// <p><code>ap-northeast-1</code></p>
+    ApNortheast1,
+    /// <p><code>ap-northeast-2</code></p>
+    ApNortheast2,
+    /// <p><code>eu-north-1</code></p>
+    EuNorth1,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RegionName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RegionName::UsEast1 => "us-east-1",
+            RegionName::UsEast2 => "us-east-2",
+            RegionName::UsWest1 => "us-west-1",
+            RegionName::UsWest2 => "us-west-2",
+            RegionName::EuWest1 => "eu-west-1",
+            RegionName::EuWest2 => "eu-west-2",
+            RegionName::EuWest3 => "eu-west-3",
+            RegionName::EuCentral1 => "eu-central-1",
+            RegionName::CaCentral1 => "ca-central-1",
+            RegionName::ApSouth1 => "ap-south-1",
+            RegionName::ApSoutheast1 => "ap-southeast-1",
+            RegionName::ApSoutheast2 => "ap-southeast-2",
+            RegionName::ApNortheast1 => "ap-northeast-1",
+            RegionName::ApNortheast2 => "ap-northeast-2",
+            RegionName::EuNorth1 => "eu-north-1",
+            RegionName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RegionName {
+    fn from(value: &'a str) -> RegionName {
+        match value {
+            "us-east-1" => RegionName::UsEast1,
+            "us-east-2" => RegionName::UsEast2,
+            "us-west-1" => RegionName::UsWest1,
+            "us-west-2" => RegionName::UsWest2,
+            "eu-west-1" => RegionName::EuWest1,
+            "eu-west-2" => RegionName::EuWest2,
+            "eu-west-3" => RegionName::EuWest3,
+            "eu-central-1" => RegionName::EuCentral1,
+            "ca-central-1" => RegionName::CaCentral1,
+            "ap-south-1" => RegionName::ApSouth1,
+            "ap-southeast-1" => RegionName::ApSoutheast1,
+            "ap-southeast-2" => RegionName::ApSoutheast2,
+            "ap-northeast-1" => RegionName::ApNortheast1,
+            "ap-northeast-2" => RegionName::ApNortheast2,
+            "eu-north-1" => RegionName::EuNorth1,
+            _ => RegionName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RegionName {
+    fn from(value: String) -> RegionName {
+        RegionName::from(value.as_str())
+    }
+}
+
+impl From<RegionName> for String {
+    fn from(value: RegionName) -> String {
+        match value {
+            RegionName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RegionName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RegionName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RegionName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RegionName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RegionName::from(value))
+    }
+}
+
 /// <p>Describes a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3340,8 +10058,9 @@ pub struct RelationalDatabase {
     pub backup_retention_enabled: Option<bool>,
     /// <p>The timestamp when the database was created. Formatted in Unix time.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The database software (for example, <code>MySQL</code>).</p>
     #[serde(rename = "engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3356,8 +10075,9 @@ pub struct RelationalDatabase {
     pub hardware: Option<RelationalDatabaseHardware>,
     /// <p>The latest point in time to which the database can be restored. Formatted in Unix time.</p>
     #[serde(rename = "latestRestorableTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latest_restorable_time: Option<f64>,
+    pub latest_restorable_time: Option<EpochTimestamp>,
     /// <p>The Region name and Availability Zone where the database is located.</p>
     #[serde(rename = "location")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3413,7 +10133,7 @@ pub struct RelationalDatabase {
     /// <p>The Lightsail resource type for the database (for example, <code>RelationalDatabase</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>Describes the secondary Availability Zone of a high availability database.</p> <p>The secondary database is used for failover support of a high availability database.</p>
     #[serde(rename = "secondaryAvailabilityZone")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3443,7 +10163,7 @@ pub struct RelationalDatabaseBlueprint {
     /// <p>The database software of the database blueprint (for example, <code>MySQL</code>).</p>
     #[serde(rename = "engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine: Option<String>,
+    pub engine: Option<RelationalDatabaseEngine>,
     /// <p>The description of the database engine for the database blueprint.</p>
     #[serde(rename = "engineDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3518,14 +10238,83 @@ pub struct RelationalDatabaseEndpoint {
     pub port: Option<i64>,
 }
 
+/// <p>The database software of the database blueprint (for example, <code>MySQL</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RelationalDatabaseEngine {
+    /// <p><code>mysql</code></p>
+    Mysql,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RelationalDatabaseEngine {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelationalDatabaseEngine::Mysql => "mysql",
+            RelationalDatabaseEngine::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RelationalDatabaseEngine {
+    fn from(value: &'a str) -> RelationalDatabaseEngine {
+        match value {
+            "mysql" => RelationalDatabaseEngine::Mysql,
+            _ => RelationalDatabaseEngine::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelationalDatabaseEngine {
+    fn from(value: String) -> RelationalDatabaseEngine {
+        RelationalDatabaseEngine::from(value.as_str())
+    }
+}
+
+impl From<RelationalDatabaseEngine> for String {
+    fn from(value: RelationalDatabaseEngine) -> String {
+        match value {
+            RelationalDatabaseEngine::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RelationalDatabaseEngine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RelationalDatabaseEngine {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RelationalDatabaseEngine {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RelationalDatabaseEngine, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RelationalDatabaseEngine::from(value))
+    }
+}
+
 /// <p>Describes an event for a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RelationalDatabaseEvent {
     /// <p>The timestamp when the database event was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The category that the database event belongs to.</p>
     #[serde(rename = "eventCategories")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3558,6 +10347,96 @@ pub struct RelationalDatabaseHardware {
     pub ram_size_in_gb: Option<f32>,
 }
 
+/// <p>The name of the metric data to return.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RelationalDatabaseMetricName {
+    /// <p><code>CPUUtilization</code></p>
+    CPUUtilization,
+    /// <p><code>DatabaseConnections</code></p>
+    DatabaseConnections,
+    /// <p><code>DiskQueueDepth</code></p>
+    DiskQueueDepth,
+    /// <p><code>FreeStorageSpace</code></p>
+    FreeStorageSpace,
+    /// <p><code>NetworkReceiveThroughput</code></p>
+    NetworkReceiveThroughput,
+    /// <p><code>NetworkTransmitThroughput</code></p>
+    NetworkTransmitThroughput,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RelationalDatabaseMetricName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelationalDatabaseMetricName::CPUUtilization => "CPUUtilization",
+            RelationalDatabaseMetricName::DatabaseConnections => "DatabaseConnections",
+            RelationalDatabaseMetricName::DiskQueueDepth => "DiskQueueDepth",
+            RelationalDatabaseMetricName::FreeStorageSpace => "FreeStorageSpace",
+            RelationalDatabaseMetricName::NetworkReceiveThroughput => "NetworkReceiveThroughput",
+            RelationalDatabaseMetricName::NetworkTransmitThroughput => "NetworkTransmitThroughput",
+            RelationalDatabaseMetricName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RelationalDatabaseMetricName {
+    fn from(value: &'a str) -> RelationalDatabaseMetricName {
+        match value {
+            "CPUUtilization" => RelationalDatabaseMetricName::CPUUtilization,
+            "DatabaseConnections" => RelationalDatabaseMetricName::DatabaseConnections,
+            "DiskQueueDepth" => RelationalDatabaseMetricName::DiskQueueDepth,
+            "FreeStorageSpace" => RelationalDatabaseMetricName::FreeStorageSpace,
+            "NetworkReceiveThroughput" => RelationalDatabaseMetricName::NetworkReceiveThroughput,
+            "NetworkTransmitThroughput" => RelationalDatabaseMetricName::NetworkTransmitThroughput,
+            _ => RelationalDatabaseMetricName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelationalDatabaseMetricName {
+    fn from(value: String) -> RelationalDatabaseMetricName {
+        RelationalDatabaseMetricName::from(value.as_str())
+    }
+}
+
+impl From<RelationalDatabaseMetricName> for String {
+    fn from(value: RelationalDatabaseMetricName) -> String {
+        match value {
+            RelationalDatabaseMetricName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RelationalDatabaseMetricName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RelationalDatabaseMetricName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RelationalDatabaseMetricName {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<RelationalDatabaseMetricName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RelationalDatabaseMetricName::from(value))
+    }
+}
+
 /// <p>Describes the parameters of a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RelationalDatabaseParameter {
@@ -3595,6 +10474,84 @@ pub struct RelationalDatabaseParameter {
     pub parameter_value: Option<String>,
 }
 
+/// <p>The password version to return.</p> <p>Specifying <code>CURRENT</code> or <code>PREVIOUS</code> returns the current or previous passwords respectively. Specifying <code>PENDING</code> returns the newest version of the password that will rotate to <code>CURRENT</code>. After the <code>PENDING</code> password rotates to <code>CURRENT</code>, the <code>PENDING</code> password is no longer available.</p> <p>Default: <code>CURRENT</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RelationalDatabasePasswordVersion {
+    /// <p><code>CURRENT</code></p>
+    Current,
+    /// <p><code>PREVIOUS</code></p>
+    Previous,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RelationalDatabasePasswordVersion {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RelationalDatabasePasswordVersion::Current => "CURRENT",
+            RelationalDatabasePasswordVersion::Previous => "PREVIOUS",
+            RelationalDatabasePasswordVersion::Pending => "PENDING",
+            RelationalDatabasePasswordVersion::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RelationalDatabasePasswordVersion {
+    fn from(value: &'a str) -> RelationalDatabasePasswordVersion {
+        match value {
+            "CURRENT" => RelationalDatabasePasswordVersion::Current,
+            "PREVIOUS" => RelationalDatabasePasswordVersion::Previous,
+            "PENDING" => RelationalDatabasePasswordVersion::Pending,
+            _ => RelationalDatabasePasswordVersion::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RelationalDatabasePasswordVersion {
+    fn from(value: String) -> RelationalDatabasePasswordVersion {
+        RelationalDatabasePasswordVersion::from(value.as_str())
+    }
+}
+
+impl From<RelationalDatabasePasswordVersion> for String {
+    fn from(value: RelationalDatabasePasswordVersion) -> String {
+        match value {
+            RelationalDatabasePasswordVersion::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RelationalDatabasePasswordVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RelationalDatabasePasswordVersion {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RelationalDatabasePasswordVersion {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<RelationalDatabasePasswordVersion, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RelationalDatabasePasswordVersion::from(value))
+    }
+}
+
 /// <p>Describes a database snapshot.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3605,8 +10562,9 @@ pub struct RelationalDatabaseSnapshot {
     pub arn: Option<String>,
     /// <p>The timestamp when the database snapshot was created.</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The software of the database snapshot (for example, <code>MySQL</code>)</p>
     #[serde(rename = "engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3642,7 +10600,7 @@ pub struct RelationalDatabaseSnapshot {
     /// <p>The Lightsail resource type.</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The size of the disk in GB (for example, <code>32</code>) for the database snapshot.</p>
     #[serde(rename = "sizeInGb")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3668,7 +10626,37 @@ pub struct ReleaseStaticIpRequest {
     pub static_ip_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl ReleaseStaticIpRequest {
+    /// Returns a builder for `ReleaseStaticIpRequest`, checking its required members when built.
+    pub fn builder() -> ReleaseStaticIpRequestBuilder {
+        ReleaseStaticIpRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ReleaseStaticIpRequest`](struct.ReleaseStaticIpRequest.html).
+#[derive(Default, Debug)]
+pub struct ReleaseStaticIpRequestBuilder {
+    static_ip_name: Option<String>,
+}
+
+impl ReleaseStaticIpRequestBuilder {
+    /// Sets the [`static_ip_name`](struct.ReleaseStaticIpRequest.html#structfield.static_ip_name) member.
+    pub fn static_ip_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.static_ip_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ReleaseStaticIpRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ReleaseStaticIpRequest, ::rusoto_core::BuildError> {
+        Ok(ReleaseStaticIpRequest {
+            static_ip_name: self
+                .static_ip_name
+                .ok_or(::rusoto_core::BuildError::missing("static_ip_name"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ReleaseStaticIpResult {
     /// <p>An array of key-value pairs containing information about the request operation.</p>
@@ -3688,7 +10676,151 @@ pub struct ResourceLocation {
     /// <p>The AWS Region name.</p>
     #[serde(rename = "regionName")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub region_name: Option<String>,
+    pub region_name: Option<RegionName>,
+}
+
+/// <p>The Lightsail resource type (e.g., <code>CloudFormationStackRecord</code>).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceType {
+    /// <p><code>ContainerService</code></p>
+    ContainerService,
+    /// <p><code>Instance</code></p>
+    Instance,
+    /// <p><code>StaticIp</code></p>
+    StaticIp,
+    /// <p><code>KeyPair</code></p>
+    KeyPair,
+    /// <p><code>InstanceSnapshot</code></p>
+    InstanceSnapshot,
+    /// <p><code>Domain</code></p>
+    Domain,
+    /// <p><code>PeeredVpc</code></p>
+    PeeredVpc,
+    /// <p><code>LoadBalancer</code></p>
+    LoadBalancer,
+    /// <p><code>LoadBalancerTlsCertificate</code></p>
+    LoadBalancerTlsCertificate,
+    /// <p><code>Disk</code></p>
+    Disk,
+    /// <p><code>DiskSnapshot</code></p>
+    DiskSnapshot,
+    /// <p><code>RelationalDatabase</code></p>
+    RelationalDatabase,
+    /// <p><code>RelationalDatabaseSnapshot</code></p>
+    RelationalDatabaseSnapshot,
+    /// <p><code>ExportSnapshotRecord</code></p>
+    ExportSnapshotRecord,
+    /// <p><code>CloudFormationStackRecord</code></p>
+    CloudFormationStackRecord,
+    /// <p><code>Alarm</code></p>
+    Alarm,
+    /// <p><code>ContactMethod</code></p>
+    ContactMethod,
+    /// <p><code>Distribution</code></p>
+    Distribution,
+    /// <p><code>Certificate</code></p>
+    Certificate,
+    /// <p><code>Bucket</code></p>
+    Bucket,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceType::ContainerService => "ContainerService",
+            ResourceType::Instance => "Instance",
+            ResourceType::StaticIp => "StaticIp",
+            ResourceType::KeyPair => "KeyPair",
+            ResourceType::InstanceSnapshot => "InstanceSnapshot",
+            ResourceType::Domain => "Domain",
+            ResourceType::PeeredVpc => "PeeredVpc",
+            ResourceType::LoadBalancer => "LoadBalancer",
+            ResourceType::LoadBalancerTlsCertificate => "LoadBalancerTlsCertificate",
+            ResourceType::Disk => "Disk",
+            ResourceType::DiskSnapshot => "DiskSnapshot",
+            ResourceType::RelationalDatabase => "RelationalDatabase",
+            ResourceType::RelationalDatabaseSnapshot => "RelationalDatabaseSnapshot",
+            ResourceType::ExportSnapshotRecord => "ExportSnapshotRecord",
+            ResourceType::CloudFormationStackRecord => "CloudFormationStackRecord",
+            ResourceType::Alarm => "Alarm",
+            ResourceType::ContactMethod => "ContactMethod",
+            ResourceType::Distribution => "Distribution",
+            ResourceType::Certificate => "Certificate",
+            ResourceType::Bucket => "Bucket",
+            ResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceType {
+    fn from(value: &'a str) -> ResourceType {
+        match value {
+            "ContainerService" => ResourceType::ContainerService,
+            "Instance" => ResourceType::Instance,
+            "StaticIp" => ResourceType::StaticIp,
+            "KeyPair" => ResourceType::KeyPair,
+            "InstanceSnapshot" => ResourceType::InstanceSnapshot,
+            "Domain" => ResourceType::Domain,
+            "PeeredVpc" => ResourceType::PeeredVpc,
+            "LoadBalancer" => ResourceType::LoadBalancer,
+            "LoadBalancerTlsCertificate" => ResourceType::LoadBalancerTlsCertificate,
+            "Disk" => ResourceType::Disk,
+            "DiskSnapshot" => ResourceType::DiskSnapshot,
+            "RelationalDatabase" => ResourceType::RelationalDatabase,
+            "RelationalDatabaseSnapshot" => ResourceType::RelationalDatabaseSnapshot,
+            "ExportSnapshotRecord" => ResourceType::ExportSnapshotRecord,
+            "CloudFormationStackRecord" => ResourceType::CloudFormationStackRecord,
+            "Alarm" => ResourceType::Alarm,
+            "ContactMethod" => ResourceType::ContactMethod,
+            "Distribution" => ResourceType::Distribution,
+            "Certificate" => ResourceType::Certificate,
+            "Bucket" => ResourceType::Bucket,
+            _ => ResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceType {
+    fn from(value: String) -> ResourceType {
+        ResourceType::from(value.as_str())
+    }
+}
+
+impl From<ResourceType> for String {
+    fn from(value: ResourceType) -> String {
+        match value {
+            ResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -3698,6 +10830,36 @@ pub struct StartInstanceRequest {
     pub instance_name: String,
 }
 
+impl StartInstanceRequest {
+    /// Returns a builder for `StartInstanceRequest`, checking its required members when built.
+    pub fn builder() -> StartInstanceRequestBuilder {
+        StartInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartInstanceRequest`](struct.StartInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct StartInstanceRequestBuilder {
+    instance_name: Option<String>,
+}
+
+impl StartInstanceRequestBuilder {
+    /// Sets the [`instance_name`](struct.StartInstanceRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(StartInstanceRequest {
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartInstanceResult {
@@ -3714,6 +10876,38 @@ pub struct StartRelationalDatabaseRequest {
     pub relational_database_name: String,
 }
 
+impl StartRelationalDatabaseRequest {
+    /// Returns a builder for `StartRelationalDatabaseRequest`, checking its required members when built.
+    pub fn builder() -> StartRelationalDatabaseRequestBuilder {
+        StartRelationalDatabaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartRelationalDatabaseRequest`](struct.StartRelationalDatabaseRequest.html).
+#[derive(Default, Debug)]
+pub struct StartRelationalDatabaseRequestBuilder {
+    relational_database_name: Option<String>,
+}
+
+impl StartRelationalDatabaseRequestBuilder {
+    /// Sets the [`relational_database_name`](struct.StartRelationalDatabaseRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartRelationalDatabaseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartRelationalDatabaseRequest, ::rusoto_core::BuildError> {
+        Ok(StartRelationalDatabaseRequest {
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartRelationalDatabaseResult {
@@ -3737,8 +10931,9 @@ pub struct StaticIp {
     pub attached_to: Option<String>,
     /// <p>The timestamp when the static IP was created (e.g., <code>1479735304.222</code>).</p>
     #[serde(rename = "createdAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The static IP address.</p>
     #[serde(rename = "ipAddress")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3758,7 +10953,7 @@ pub struct StaticIp {
     /// <p>The resource type (usually <code>StaticIp</code>).</p>
     #[serde(rename = "resourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<ResourceType>,
     /// <p>The support code. Include this code in your email to support when you have questions about an instance or another resource in Lightsail. This code enables our support team to look up your Lightsail information more easily.</p>
     #[serde(rename = "supportCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3776,6 +10971,44 @@ pub struct StopInstanceRequest {
     pub instance_name: String,
 }
 
+impl StopInstanceRequest {
+    /// Returns a builder for `StopInstanceRequest`, checking its required members when built.
+    pub fn builder() -> StopInstanceRequestBuilder {
+        StopInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopInstanceRequest`](struct.StopInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct StopInstanceRequestBuilder {
+    force: Option<bool>,
+    instance_name: Option<String>,
+}
+
+impl StopInstanceRequestBuilder {
+    /// Sets the [`force`](struct.StopInstanceRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_name`](struct.StopInstanceRequest.html#structfield.instance_name) member.
+    pub fn instance_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(StopInstanceRequest {
+            force: self.force,
+            instance_name: self
+                .instance_name
+                .ok_or(::rusoto_core::BuildError::missing("instance_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopInstanceResult {
@@ -3796,6 +11029,46 @@ pub struct StopRelationalDatabaseRequest {
     pub relational_database_snapshot_name: Option<String>,
 }
 
+impl StopRelationalDatabaseRequest {
+    /// Returns a builder for `StopRelationalDatabaseRequest`, checking its required members when built.
+    pub fn builder() -> StopRelationalDatabaseRequestBuilder {
+        StopRelationalDatabaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopRelationalDatabaseRequest`](struct.StopRelationalDatabaseRequest.html).
+#[derive(Default, Debug)]
+pub struct StopRelationalDatabaseRequestBuilder {
+    relational_database_name: Option<String>,
+    relational_database_snapshot_name: Option<String>,
+}
+
+impl StopRelationalDatabaseRequestBuilder {
+    /// Sets the [`relational_database_name`](struct.StopRelationalDatabaseRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_snapshot_name`](struct.StopRelationalDatabaseRequest.html#structfield.relational_database_snapshot_name) member.
+    pub fn relational_database_snapshot_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_snapshot_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopRelationalDatabaseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopRelationalDatabaseRequest, ::rusoto_core::BuildError> {
+        Ok(StopRelationalDatabaseRequest {
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+            relational_database_snapshot_name: self.relational_database_snapshot_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopRelationalDatabaseResult {
@@ -3828,6 +11101,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_name`](struct.TagResourceRequest.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResult {
@@ -3859,6 +11172,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_name`](struct.UntagResourceRequest.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResult {
@@ -3878,6 +11231,48 @@ pub struct UpdateDomainEntryRequest {
     pub domain_name: String,
 }
 
+impl UpdateDomainEntryRequest {
+    /// Returns a builder for `UpdateDomainEntryRequest`, checking its required members when built.
+    pub fn builder() -> UpdateDomainEntryRequestBuilder {
+        UpdateDomainEntryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDomainEntryRequest`](struct.UpdateDomainEntryRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateDomainEntryRequestBuilder {
+    domain_entry: Option<DomainEntry>,
+    domain_name: Option<String>,
+}
+
+impl UpdateDomainEntryRequestBuilder {
+    /// Sets the [`domain_entry`](struct.UpdateDomainEntryRequest.html#structfield.domain_entry) member.
+    pub fn domain_entry<T: Into<DomainEntry>>(mut self, value: T) -> Self {
+        self.domain_entry = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_name`](struct.UpdateDomainEntryRequest.html#structfield.domain_name) member.
+    pub fn domain_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDomainEntryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateDomainEntryRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateDomainEntryRequest {
+            domain_entry: self
+                .domain_entry
+                .ok_or(::rusoto_core::BuildError::missing("domain_entry"))?,
+            domain_name: self
+                .domain_name
+                .ok_or(::rusoto_core::BuildError::missing("domain_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateDomainEntryResult {
@@ -3887,11 +11282,11 @@ pub struct UpdateDomainEntryResult {
     pub operations: Option<Vec<Operation>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateLoadBalancerAttributeRequest {
     /// <p>The name of the attribute you want to update. Valid values are below.</p>
     #[serde(rename = "attributeName")]
-    pub attribute_name: String,
+    pub attribute_name: LoadBalancerAttributeName,
     /// <p>The value that you want to specify for the attribute name.</p>
     #[serde(rename = "attributeValue")]
     pub attribute_value: String,
@@ -3900,6 +11295,58 @@ pub struct UpdateLoadBalancerAttributeRequest {
     pub load_balancer_name: String,
 }
 
+impl UpdateLoadBalancerAttributeRequest {
+    /// Returns a builder for `UpdateLoadBalancerAttributeRequest`, checking its required members when built.
+    pub fn builder() -> UpdateLoadBalancerAttributeRequestBuilder {
+        UpdateLoadBalancerAttributeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateLoadBalancerAttributeRequest`](struct.UpdateLoadBalancerAttributeRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateLoadBalancerAttributeRequestBuilder {
+    attribute_name: Option<LoadBalancerAttributeName>,
+    attribute_value: Option<String>,
+    load_balancer_name: Option<String>,
+}
+
+impl UpdateLoadBalancerAttributeRequestBuilder {
+    /// Sets the [`attribute_name`](struct.UpdateLoadBalancerAttributeRequest.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<LoadBalancerAttributeName>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attribute_value`](struct.UpdateLoadBalancerAttributeRequest.html#structfield.attribute_value) member.
+    pub fn attribute_value<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`load_balancer_name`](struct.UpdateLoadBalancerAttributeRequest.html#structfield.load_balancer_name) member.
+    pub fn load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateLoadBalancerAttributeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateLoadBalancerAttributeRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateLoadBalancerAttributeRequest {
+            attribute_name: self
+                .attribute_name
+                .ok_or(::rusoto_core::BuildError::missing("attribute_name"))?,
+            attribute_value: self
+                .attribute_value
+                .ok_or(::rusoto_core::BuildError::missing("attribute_value"))?,
+            load_balancer_name: self
+                .load_balancer_name
+                .ok_or(::rusoto_core::BuildError::missing("load_balancer_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateLoadBalancerAttributeResult {
@@ -3919,6 +11366,49 @@ pub struct UpdateRelationalDatabaseParametersRequest {
     pub relational_database_name: String,
 }
 
+impl UpdateRelationalDatabaseParametersRequest {
+    /// Returns a builder for `UpdateRelationalDatabaseParametersRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRelationalDatabaseParametersRequestBuilder {
+        UpdateRelationalDatabaseParametersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRelationalDatabaseParametersRequest`](struct.UpdateRelationalDatabaseParametersRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRelationalDatabaseParametersRequestBuilder {
+    parameters: Option<Vec<RelationalDatabaseParameter>>,
+    relational_database_name: Option<String>,
+}
+
+impl UpdateRelationalDatabaseParametersRequestBuilder {
+    /// Sets the [`parameters`](struct.UpdateRelationalDatabaseParametersRequest.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<RelationalDatabaseParameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_name`](struct.UpdateRelationalDatabaseParametersRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRelationalDatabaseParametersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateRelationalDatabaseParametersRequest, ::rusoto_core::BuildError>
+    {
+        Ok(UpdateRelationalDatabaseParametersRequest {
+            parameters: self
+                .parameters
+                .ok_or(::rusoto_core::BuildError::missing("parameters"))?,
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRelationalDatabaseParametersResult {
@@ -3967,6 +11457,102 @@ pub struct UpdateRelationalDatabaseRequest {
     pub rotate_master_user_password: Option<bool>,
 }
 
+impl UpdateRelationalDatabaseRequest {
+    /// Returns a builder for `UpdateRelationalDatabaseRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRelationalDatabaseRequestBuilder {
+        UpdateRelationalDatabaseRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRelationalDatabaseRequest`](struct.UpdateRelationalDatabaseRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRelationalDatabaseRequestBuilder {
+    apply_immediately: Option<bool>,
+    disable_backup_retention: Option<bool>,
+    enable_backup_retention: Option<bool>,
+    master_user_password: Option<String>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    publicly_accessible: Option<bool>,
+    relational_database_name: Option<String>,
+    rotate_master_user_password: Option<bool>,
+}
+
+impl UpdateRelationalDatabaseRequestBuilder {
+    /// Sets the [`apply_immediately`](struct.UpdateRelationalDatabaseRequest.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disable_backup_retention`](struct.UpdateRelationalDatabaseRequest.html#structfield.disable_backup_retention) member.
+    pub fn disable_backup_retention<T: Into<bool>>(mut self, value: T) -> Self {
+        self.disable_backup_retention = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_backup_retention`](struct.UpdateRelationalDatabaseRequest.html#structfield.enable_backup_retention) member.
+    pub fn enable_backup_retention<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_backup_retention = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.UpdateRelationalDatabaseRequest.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.UpdateRelationalDatabaseRequest.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.UpdateRelationalDatabaseRequest.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.UpdateRelationalDatabaseRequest.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`relational_database_name`](struct.UpdateRelationalDatabaseRequest.html#structfield.relational_database_name) member.
+    pub fn relational_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.relational_database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rotate_master_user_password`](struct.UpdateRelationalDatabaseRequest.html#structfield.rotate_master_user_password) member.
+    pub fn rotate_master_user_password<T: Into<bool>>(mut self, value: T) -> Self {
+        self.rotate_master_user_password = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRelationalDatabaseRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateRelationalDatabaseRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRelationalDatabaseRequest {
+            apply_immediately: self.apply_immediately,
+            disable_backup_retention: self.disable_backup_retention,
+            enable_backup_retention: self.enable_backup_retention,
+            master_user_password: self.master_user_password,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            publicly_accessible: self.publicly_accessible,
+            relational_database_name: self.relational_database_name.ok_or(
+                ::rusoto_core::BuildError::missing("relational_database_name"),
+            )?,
+            rotate_master_user_password: self.rotate_master_user_password,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateRelationalDatabaseResult {
@@ -11603,556 +19189,1174 @@ pub trait Lightsail {
     fn create_cloud_formation_stack(
         &self,
         input: CreateCloudFormationStackRequest,
-    ) -> RusotoFuture<CreateCloudFormationStackResult, CreateCloudFormationStackError>;
-
-    /// <p>Creates a block storage disk that can be attached to a Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>). The disk is created in the regional endpoint that you send the HTTP request to. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/overview/article/understanding-regions-and-availability-zones-in-amazon-lightsail">Regions and Availability Zones in Lightsail</a>.</p> <p>The <code>create disk</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateCloudFormationStackResult, CreateCloudFormationStackError>;
+
+    /// <p>Creates a block storage disk that can be attached to a Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>). The disk is created in the regional endpoint that you send the HTTP request to. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/overview/article/understanding-regions-and-availability-zones-in-amazon-lightsail">Regions and Availability Zones in Lightsail</a>.</p> <p>The <code>create disk</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_disk(
+        &self,
+        input: CreateDiskRequest,
+    ) -> RusotoFuture<CreateDiskResult, CreateDiskError>;
+
+    /// <p>Creates a block storage disk from a disk snapshot that can be attached to a Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>). The disk is created in the regional endpoint that you send the HTTP request to. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/overview/article/understanding-regions-and-availability-zones-in-amazon-lightsail">Regions and Availability Zones in Lightsail</a>.</p> <p>The <code>create disk from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by diskSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_disk_from_snapshot(
+        &self,
+        input: CreateDiskFromSnapshotRequest,
+    ) -> RusotoFuture<CreateDiskFromSnapshotResult, CreateDiskFromSnapshotError>;
+
+    /// <p>Creates a snapshot of a block storage disk. You can use snapshots for backups, to make copies of disks, and to save data before shutting down a Lightsail instance.</p> <p>You can take a snapshot of an attached disk that is in use; however, snapshots only capture data that has been written to your disk at the time the snapshot command is issued. This may exclude any data that has been cached by any applications or the operating system. If you can pause any file systems on the disk long enough to take a snapshot, your snapshot should be complete. Nevertheless, if you cannot pause all file writes to the disk, you should unmount the disk from within the Lightsail instance, issue the create disk snapshot command, and then remount the disk to ensure a consistent and complete snapshot. You may remount and use your disk while the snapshot status is pending.</p> <p>You can also use this operation to create a snapshot of an instance's system volume. You might want to do this, for example, to recover data from the system volume of a botched instance or to create a backup of the system volume like you would for a block storage disk. To create a snapshot of a system volume, just define the <code>instance name</code> parameter when issuing the snapshot command, and a snapshot of the defined instance's system volume will be created. After the snapshot is available, you can create a block storage disk from the snapshot and attach it to a running instance to access the data on the disk.</p> <p>The <code>create disk snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_disk_snapshot(
+        &self,
+        input: CreateDiskSnapshotRequest,
+    ) -> RusotoFuture<CreateDiskSnapshotResult, CreateDiskSnapshotError>;
+
+    /// <p>Creates a domain resource for the specified domain (e.g., example.com).</p> <p>The <code>create domain</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> RusotoFuture<CreateDomainResult, CreateDomainError>;
+
+    /// <p>Creates one of the following entry records associated with the domain: Address (A), canonical name (CNAME), mail exchanger (MX), name server (NS), start of authority (SOA), service locator (SRV), or text (TXT).</p> <p>The <code>create domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_domain_entry(
+        &self,
+        input: CreateDomainEntryRequest,
+    ) -> RusotoFuture<CreateDomainEntryResult, CreateDomainEntryError>;
+
+    /// <p>Creates a snapshot of a specific virtual private server, or <i>instance</i>. You can use a snapshot to create a new instance that is based on that snapshot.</p> <p>The <code>create instance snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_instance_snapshot(
+        &self,
+        input: CreateInstanceSnapshotRequest,
+    ) -> RusotoFuture<CreateInstanceSnapshotResult, CreateInstanceSnapshotError>;
+
+    /// <p>Creates one or more Amazon Lightsail virtual private servers, or <i>instances</i>. Create instances using active blueprints. Inactive blueprints are listed to support customers with existing instances but are not necessarily available for launch of new instances. Blueprints are marked inactive when they become outdated due to operating system updates or new application releases. Use the get blueprints operation to return a list of available blueprints.</p> <p>The <code>create instances</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_instances(
+        &self,
+        input: CreateInstancesRequest,
+    ) -> RusotoFuture<CreateInstancesResult, CreateInstancesError>;
+
+    /// <p>Uses a specific snapshot as a blueprint for creating one or more new instances that are based on that identical configuration.</p> <p>The <code>create instances from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by instanceSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_instances_from_snapshot(
+        &self,
+        input: CreateInstancesFromSnapshotRequest,
+    ) -> RusotoFuture<CreateInstancesFromSnapshotResult, CreateInstancesFromSnapshotError>;
+
+    /// <p>Creates an SSH key pair.</p> <p>The <code>create key pair</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_key_pair(
+        &self,
+        input: CreateKeyPairRequest,
+    ) -> RusotoFuture<CreateKeyPairResult, CreateKeyPairError>;
+
+    /// <p>Creates a Lightsail load balancer. To learn more about deciding whether to load balance your application, see <a href="https://lightsail.aws.amazon.com/ls/docs/how-to/article/configure-lightsail-instances-for-load-balancing">Configure your Lightsail instances for load balancing</a>. You can create up to 5 load balancers per AWS Region in your account.</p> <p>When you create a load balancer, you can specify a unique name and port settings. To change additional load balancer settings, use the <code>UpdateLoadBalancerAttribute</code> operation.</p> <p>The <code>create load balancer</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerRequest,
+    ) -> RusotoFuture<CreateLoadBalancerResult, CreateLoadBalancerError>;
+
+    /// <p>Creates a Lightsail load balancer TLS certificate.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>The <code>create load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_load_balancer_tls_certificate(
+        &self,
+        input: CreateLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<CreateLoadBalancerTlsCertificateResult, CreateLoadBalancerTlsCertificateError>;
+
+    /// <p>Creates a new database in Amazon Lightsail.</p> <p>The <code>create relational database</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_relational_database(
+        &self,
+        input: CreateRelationalDatabaseRequest,
+    ) -> RusotoFuture<CreateRelationalDatabaseResult, CreateRelationalDatabaseError>;
+
+    /// <p>Creates a new database from an existing database snapshot in Amazon Lightsail.</p> <p>You can create a new database from a snapshot in if something goes wrong with your original database, or to change it to a different plan, such as a high availability or standard plan.</p> <p>The <code>create relational database from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by relationalDatabaseSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_relational_database_from_snapshot(
+        &self,
+        input: CreateRelationalDatabaseFromSnapshotRequest,
+    ) -> RusotoFuture<
+        CreateRelationalDatabaseFromSnapshotResult,
+        CreateRelationalDatabaseFromSnapshotError,
+    >;
+
+    /// <p>Creates a snapshot of your database in Amazon Lightsail. You can use snapshots for backups, to make copies of a database, and to save data before deleting a database.</p> <p>The <code>create relational database snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn create_relational_database_snapshot(
+        &self,
+        input: CreateRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<CreateRelationalDatabaseSnapshotResult, CreateRelationalDatabaseSnapshotError>;
+
+    /// <p>Deletes the specified block storage disk. The disk must be in the <code>available</code> state (not attached to a Lightsail instance).</p> <note> <p>The disk may remain in the <code>deleting</code> state for several minutes.</p> </note> <p>The <code>delete disk</code> operation supports tag-based access control via resource tags applied to the resource identified by diskName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_disk(
+        &self,
+        input: DeleteDiskRequest,
+    ) -> RusotoFuture<DeleteDiskResult, DeleteDiskError>;
+
+    /// <p>Deletes the specified disk snapshot.</p> <p>When you make periodic snapshots of a disk, the snapshots are incremental, and only the blocks on the device that have changed since your last snapshot are saved in the new snapshot. When you delete a snapshot, only the data not needed for any other snapshot is removed. So regardless of which prior snapshots have been deleted, all active snapshots will have access to all the information needed to restore the disk.</p> <p>The <code>delete disk snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by diskSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_disk_snapshot(
+        &self,
+        input: DeleteDiskSnapshotRequest,
+    ) -> RusotoFuture<DeleteDiskSnapshotResult, DeleteDiskSnapshotError>;
+
+    /// <p>Deletes the specified domain recordset and all of its domain records.</p> <p>The <code>delete domain</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> RusotoFuture<DeleteDomainResult, DeleteDomainError>;
+
+    /// <p>Deletes a specific domain entry.</p> <p>The <code>delete domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_domain_entry(
+        &self,
+        input: DeleteDomainEntryRequest,
+    ) -> RusotoFuture<DeleteDomainEntryResult, DeleteDomainEntryError>;
+
+    /// <p>Deletes a specific Amazon Lightsail virtual private server, or <i>instance</i>.</p> <p>The <code>delete instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<DeleteInstanceResult, DeleteInstanceError>;
+
+    /// <p>Deletes a specific snapshot of a virtual private server (or <i>instance</i>).</p> <p>The <code>delete instance snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_instance_snapshot(
+        &self,
+        input: DeleteInstanceSnapshotRequest,
+    ) -> RusotoFuture<DeleteInstanceSnapshotResult, DeleteInstanceSnapshotError>;
+
+    /// <p>Deletes a specific SSH key pair.</p> <p>The <code>delete key pair</code> operation supports tag-based access control via resource tags applied to the resource identified by keyPairName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_key_pair(
+        &self,
+        input: DeleteKeyPairRequest,
+    ) -> RusotoFuture<DeleteKeyPairResult, DeleteKeyPairError>;
+
+    /// <p><p>Deletes the known host key or certificate used by the Amazon Lightsail browser-based SSH or RDP clients to authenticate an instance. This operation enables the Lightsail browser-based SSH or RDP clients to connect to the instance after a host key mismatch.</p> <important> <p>Perform this operation only if you were expecting the host key or certificate mismatch or if you are familiar with the new host key or certificate on the instance. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-troubleshooting-browser-based-ssh-rdp-client-connection">Troubleshooting connection issues when using the Amazon Lightsail browser-based SSH or RDP client</a>.</p> </important></p>
+    fn delete_known_host_keys(
+        &self,
+        input: DeleteKnownHostKeysRequest,
+    ) -> RusotoFuture<DeleteKnownHostKeysResult, DeleteKnownHostKeysError>;
+
+    /// <p>Deletes a Lightsail load balancer and all its associated SSL/TLS certificates. Once the load balancer is deleted, you will need to create a new load balancer, create a new certificate, and verify domain ownership again.</p> <p>The <code>delete load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerRequest,
+    ) -> RusotoFuture<DeleteLoadBalancerResult, DeleteLoadBalancerError>;
+
+    /// <p>Deletes an SSL/TLS certificate associated with a Lightsail load balancer.</p> <p>The <code>delete load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_load_balancer_tls_certificate(
+        &self,
+        input: DeleteLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<DeleteLoadBalancerTlsCertificateResult, DeleteLoadBalancerTlsCertificateError>;
+
+    /// <p>Deletes a database in Amazon Lightsail.</p> <p>The <code>delete relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_relational_database(
+        &self,
+        input: DeleteRelationalDatabaseRequest,
+    ) -> RusotoFuture<DeleteRelationalDatabaseResult, DeleteRelationalDatabaseError>;
+
+    /// <p>Deletes a database snapshot in Amazon Lightsail.</p> <p>The <code>delete relational database snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn delete_relational_database_snapshot(
+        &self,
+        input: DeleteRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<DeleteRelationalDatabaseSnapshotResult, DeleteRelationalDatabaseSnapshotError>;
+
+    /// <p>Detaches a stopped block storage disk from a Lightsail instance. Make sure to unmount any file systems on the device within your operating system before stopping the instance and detaching the disk.</p> <p>The <code>detach disk</code> operation supports tag-based access control via resource tags applied to the resource identified by diskName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn detach_disk(
+        &self,
+        input: DetachDiskRequest,
+    ) -> RusotoFuture<DetachDiskResult, DetachDiskError>;
+
+    /// <p>Detaches the specified instances from a Lightsail load balancer.</p> <p>This operation waits until the instances are no longer needed before they are detached from the load balancer.</p> <p>The <code>detach instances from load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn detach_instances_from_load_balancer(
+        &self,
+        input: DetachInstancesFromLoadBalancerRequest,
+    ) -> RusotoFuture<DetachInstancesFromLoadBalancerResult, DetachInstancesFromLoadBalancerError>;
+
+    /// <p>Detaches a static IP from the Amazon Lightsail instance to which it is attached.</p>
+    fn detach_static_ip(
+        &self,
+        input: DetachStaticIpRequest,
+    ) -> RusotoFuture<DetachStaticIpResult, DetachStaticIpError>;
+
+    /// <p>Downloads the default SSH key pair from the user's account.</p>
+    fn download_default_key_pair(
+        &self,
+    ) -> RusotoFuture<DownloadDefaultKeyPairResult, DownloadDefaultKeyPairError>;
+
+    /// <p><p>Exports an Amazon Lightsail instance or block storage disk snapshot to Amazon Elastic Compute Cloud (Amazon EC2). This operation results in an export snapshot record that can be used with the <code>create cloud formation stack</code> operation to create new Amazon EC2 instances.</p> <p>Exported instance snapshots appear in Amazon EC2 as Amazon Machine Images (AMIs), and the instance system disk appears as an Amazon Elastic Block Store (Amazon EBS) volume. Exported disk snapshots appear in Amazon EC2 as Amazon EBS volumes. Snapshots are exported to the same Amazon Web Services Region in Amazon EC2 as the source Lightsail snapshot.</p> <p/> <p>The <code>export snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by sourceSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p> <note> <p>Use the <code>get instance snapshots</code> or <code>get disk snapshots</code> operations to get a list of snapshots that you can export to Amazon EC2.</p> </note></p>
+    fn export_snapshot(
+        &self,
+        input: ExportSnapshotRequest,
+    ) -> RusotoFuture<ExportSnapshotResult, ExportSnapshotError>;
+
+    /// <p>Returns the names of all active (not deleted) resources.</p>
+    fn get_active_names(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> RusotoFuture<GetActiveNamesResult, GetActiveNamesError>;
+
+    /// <p>Returns the list of available instance images, or <i>blueprints</i>. You can use a blueprint to create a new virtual private server already running a specific operating system, as well as a preinstalled app or development stack. The software each instance is running depends on the blueprint image you choose.</p>
+    fn get_blueprints(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> RusotoFuture<GetBlueprintsResult, GetBlueprintsError>;
+
+    /// <p>Returns the list of bundles that are available for purchase. A bundle describes the specs for your virtual private server (or <i>instance</i>).</p>
+    fn get_bundles(
+        &self,
+        input: GetBundlesRequest,
+    ) -> RusotoFuture<GetBundlesResult, GetBundlesError>;
+
+    /// <p>Returns the CloudFormation stack record created as a result of the <code>create cloud formation stack</code> operation.</p> <p>An AWS CloudFormation stack is used to create a new Amazon EC2 instance from an exported Lightsail snapshot.</p>
+    fn get_cloud_formation_stack_records(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> RusotoFuture<GetCloudFormationStackRecordsResult, GetCloudFormationStackRecordsError>;
+
+    /// <p>Returns information about a specific block storage disk.</p>
+    fn get_disk(&self, input: GetDiskRequest) -> RusotoFuture<GetDiskResult, GetDiskError>;
+
+    /// <p>Returns information about a specific block storage disk snapshot.</p>
+    fn get_disk_snapshot(
+        &self,
+        input: GetDiskSnapshotRequest,
+    ) -> RusotoFuture<GetDiskSnapshotResult, GetDiskSnapshotError>;
+
+    /// <p>Returns information about all block storage disk snapshots in your AWS account and region.</p> <p>If you are describing a long list of disk snapshots, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
+    fn get_disk_snapshots(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> RusotoFuture<GetDiskSnapshotsResult, GetDiskSnapshotsError>;
+
+    /// <p>Returns information about all block storage disks in your AWS account and region.</p> <p>If you are describing a long list of disks, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
+    fn get_disks(&self, input: GetDisksRequest) -> RusotoFuture<GetDisksResult, GetDisksError>;
+
+    /// <p>Returns information about a specific domain recordset.</p>
+    fn get_domain(&self, input: GetDomainRequest) -> RusotoFuture<GetDomainResult, GetDomainError>;
+
+    /// <p>Returns a list of all domains in the user's account.</p>
+    fn get_domains(
+        &self,
+        input: GetDomainsRequest,
+    ) -> RusotoFuture<GetDomainsResult, GetDomainsError>;
+
+    /// <p>Returns the export snapshot record created as a result of the <code>export snapshot</code> operation.</p> <p>An export snapshot record can be used to create a new Amazon EC2 instance and its related resources with the <code>create cloud formation stack</code> operation.</p>
+    fn get_export_snapshot_records(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> RusotoFuture<GetExportSnapshotRecordsResult, GetExportSnapshotRecordsError>;
+
+    /// <p>Returns information about a specific Amazon Lightsail instance, which is a virtual private server.</p>
+    fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> RusotoFuture<GetInstanceResult, GetInstanceError>;
+
+    /// <p>Returns temporary SSH keys you can use to connect to a specific virtual private server, or <i>instance</i>.</p> <p>The <code>get instance access details</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn get_instance_access_details(
+        &self,
+        input: GetInstanceAccessDetailsRequest,
+    ) -> RusotoFuture<GetInstanceAccessDetailsResult, GetInstanceAccessDetailsError>;
+
+    /// <p>Returns the data points for the specified Amazon Lightsail instance metric, given an instance name.</p>
+    fn get_instance_metric_data(
+        &self,
+        input: GetInstanceMetricDataRequest,
+    ) -> RusotoFuture<GetInstanceMetricDataResult, GetInstanceMetricDataError>;
+
+    /// <p>Returns the port states for a specific virtual private server, or <i>instance</i>.</p>
+    fn get_instance_port_states(
+        &self,
+        input: GetInstancePortStatesRequest,
+    ) -> RusotoFuture<GetInstancePortStatesResult, GetInstancePortStatesError>;
+
+    /// <p>Returns information about a specific instance snapshot.</p>
+    fn get_instance_snapshot(
+        &self,
+        input: GetInstanceSnapshotRequest,
+    ) -> RusotoFuture<GetInstanceSnapshotResult, GetInstanceSnapshotError>;
+
+    /// <p>Returns all instance snapshots for the user's account.</p>
+    fn get_instance_snapshots(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> RusotoFuture<GetInstanceSnapshotsResult, GetInstanceSnapshotsError>;
+
+    /// <p>Returns the state of a specific instance. Works on one instance at a time.</p>
+    fn get_instance_state(
+        &self,
+        input: GetInstanceStateRequest,
+    ) -> RusotoFuture<GetInstanceStateResult, GetInstanceStateError>;
+
+    /// <p>Returns information about all Amazon Lightsail virtual private servers, or <i>instances</i>.</p>
+    fn get_instances(
+        &self,
+        input: GetInstancesRequest,
+    ) -> RusotoFuture<GetInstancesResult, GetInstancesError>;
+
+    /// <p>Returns information about a specific key pair.</p>
+    fn get_key_pair(
+        &self,
+        input: GetKeyPairRequest,
+    ) -> RusotoFuture<GetKeyPairResult, GetKeyPairError>;
+
+    /// <p>Returns information about all key pairs in the user's account.</p>
+    fn get_key_pairs(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> RusotoFuture<GetKeyPairsResult, GetKeyPairsError>;
+
+    /// <p>Returns information about the specified Lightsail load balancer.</p>
+    fn get_load_balancer(
+        &self,
+        input: GetLoadBalancerRequest,
+    ) -> RusotoFuture<GetLoadBalancerResult, GetLoadBalancerError>;
+
+    /// <p>Returns information about health metrics for your Lightsail load balancer.</p>
+    fn get_load_balancer_metric_data(
+        &self,
+        input: GetLoadBalancerMetricDataRequest,
+    ) -> RusotoFuture<GetLoadBalancerMetricDataResult, GetLoadBalancerMetricDataError>;
+
+    /// <p>Returns information about the TLS certificates that are associated with the specified Lightsail load balancer.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>You can have a maximum of 2 certificates associated with a Lightsail load balancer. One is active and the other is inactive.</p>
+    fn get_load_balancer_tls_certificates(
+        &self,
+        input: GetLoadBalancerTlsCertificatesRequest,
+    ) -> RusotoFuture<GetLoadBalancerTlsCertificatesResult, GetLoadBalancerTlsCertificatesError>;
+
+    /// <p>Returns information about all load balancers in an account.</p> <p>If you are describing a long list of load balancers, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
+    fn get_load_balancers(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> RusotoFuture<GetLoadBalancersResult, GetLoadBalancersError>;
+
+    /// <p>Returns information about a specific operation. Operations include events such as when you create an instance, allocate a static IP, attach a static IP, and so on.</p>
+    fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> RusotoFuture<GetOperationResult, GetOperationError>;
+
+    /// <p>Returns information about all operations.</p> <p>Results are returned from oldest to newest, up to a maximum of 200. Results can be paged by making each subsequent call to <code>GetOperations</code> use the maximum (last) <code>statusChangedAt</code> value from the previous request.</p>
+    fn get_operations(
+        &self,
+        input: GetOperationsRequest,
+    ) -> RusotoFuture<GetOperationsResult, GetOperationsError>;
+
+    /// <p>Gets operations for a specific resource (e.g., an instance or a static IP).</p>
+    fn get_operations_for_resource(
+        &self,
+        input: GetOperationsForResourceRequest,
+    ) -> RusotoFuture<GetOperationsForResourceResult, GetOperationsForResourceError>;
+
+    /// <p>Returns a list of all valid regions for Amazon Lightsail. Use the <code>include availability zones</code> parameter to also return the Availability Zones in a region.</p>
+    fn get_regions(
+        &self,
+        input: GetRegionsRequest,
+    ) -> RusotoFuture<GetRegionsResult, GetRegionsError>;
+
+    /// <p>Returns information about a specific database in Amazon Lightsail.</p>
+    fn get_relational_database(
+        &self,
+        input: GetRelationalDatabaseRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseResult, GetRelationalDatabaseError>;
+
+    /// <p>Returns a list of available database blueprints in Amazon Lightsail. A blueprint describes the major engine version of a database.</p> <p>You can use a blueprint ID to create a new database that runs a specific database engine.</p>
+    fn get_relational_database_blueprints(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseBlueprintsResult, GetRelationalDatabaseBlueprintsError>;
+
+    /// <p>Returns the list of bundles that are available in Amazon Lightsail. A bundle describes the performance specifications for a database.</p> <p>You can use a bundle ID to create a new database with explicit performance specifications.</p>
+    fn get_relational_database_bundles(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseBundlesResult, GetRelationalDatabaseBundlesError>;
+
+    /// <p>Returns a list of events for a specific database in Amazon Lightsail.</p>
+    fn get_relational_database_events(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseEventsResult, GetRelationalDatabaseEventsError>;
+
+    /// <p>Returns a list of log events for a database in Amazon Lightsail.</p>
+    fn get_relational_database_log_events(
+        &self,
+        input: GetRelationalDatabaseLogEventsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseLogEventsResult, GetRelationalDatabaseLogEventsError>;
+
+    /// <p>Returns a list of available log streams for a specific database in Amazon Lightsail.</p>
+    fn get_relational_database_log_streams(
+        &self,
+        input: GetRelationalDatabaseLogStreamsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseLogStreamsResult, GetRelationalDatabaseLogStreamsError>;
+
+    /// <p>Returns the current, previous, or pending versions of the master user password for a Lightsail database.</p> <p>The <code>asdf</code> operation GetRelationalDatabaseMasterUserPassword supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName.</p>
+    fn get_relational_database_master_user_password(
+        &self,
+        input: GetRelationalDatabaseMasterUserPasswordRequest,
+    ) -> RusotoFuture<
+        GetRelationalDatabaseMasterUserPasswordResult,
+        GetRelationalDatabaseMasterUserPasswordError,
+    >;
+
+    /// <p>Returns the data points of the specified metric for a database in Amazon Lightsail.</p>
+    fn get_relational_database_metric_data(
+        &self,
+        input: GetRelationalDatabaseMetricDataRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseMetricDataResult, GetRelationalDatabaseMetricDataError>;
+
+    /// <p>Returns all of the runtime parameters offered by the underlying database software, or engine, for a specific database in Amazon Lightsail.</p> <p>In addition to the parameter names and values, this operation returns other information about each parameter. This information includes whether changes require a reboot, whether the parameter is modifiable, the allowed values, and the data types.</p>
+    fn get_relational_database_parameters(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseParametersResult, GetRelationalDatabaseParametersError>;
+
+    /// <p>Returns information about a specific database snapshot in Amazon Lightsail.</p>
+    fn get_relational_database_snapshot(
+        &self,
+        input: GetRelationalDatabaseSnapshotRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseSnapshotResult, GetRelationalDatabaseSnapshotError>;
+
+    /// <p>Returns information about all of your database snapshots in Amazon Lightsail.</p>
+    fn get_relational_database_snapshots(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> RusotoFuture<GetRelationalDatabaseSnapshotsResult, GetRelationalDatabaseSnapshotsError>;
+
+    /// <p>Returns information about all of your databases in Amazon Lightsail.</p>
+    fn get_relational_databases(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> RusotoFuture<GetRelationalDatabasesResult, GetRelationalDatabasesError>;
+
+    /// <p>Returns information about a specific static IP.</p>
+    fn get_static_ip(
+        &self,
+        input: GetStaticIpRequest,
+    ) -> RusotoFuture<GetStaticIpResult, GetStaticIpError>;
+
+    /// <p>Returns information about all static IPs in the user's account.</p>
+    fn get_static_ips(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> RusotoFuture<GetStaticIpsResult, GetStaticIpsError>;
+
+    /// <p>Imports a public SSH key from a specific key pair.</p>
+    fn import_key_pair(
+        &self,
+        input: ImportKeyPairRequest,
+    ) -> RusotoFuture<ImportKeyPairResult, ImportKeyPairError>;
+
+    /// <p>Returns a Boolean value indicating whether your Lightsail VPC is peered.</p>
+    fn is_vpc_peered(&self) -> RusotoFuture<IsVpcPeeredResult, IsVpcPeeredError>;
+
+    /// <p>Adds public ports to an Amazon Lightsail instance.</p> <p>The <code>open instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn open_instance_public_ports(
+        &self,
+        input: OpenInstancePublicPortsRequest,
+    ) -> RusotoFuture<OpenInstancePublicPortsResult, OpenInstancePublicPortsError>;
+
+    /// <p>Tries to peer the Lightsail VPC with the user's default VPC.</p>
+    fn peer_vpc(&self) -> RusotoFuture<PeerVpcResult, PeerVpcError>;
+
+    /// <p>Sets the specified open ports for an Amazon Lightsail instance, and closes all ports for every protocol not included in the current request.</p> <p>The <code>put instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn put_instance_public_ports(
+        &self,
+        input: PutInstancePublicPortsRequest,
+    ) -> RusotoFuture<PutInstancePublicPortsResult, PutInstancePublicPortsError>;
+
+    /// <p>Restarts a specific instance.</p> <p>The <code>reboot instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> RusotoFuture<RebootInstanceResult, RebootInstanceError>;
+
+    /// <p>Restarts a specific database in Amazon Lightsail.</p> <p>The <code>reboot relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn reboot_relational_database(
+        &self,
+        input: RebootRelationalDatabaseRequest,
+    ) -> RusotoFuture<RebootRelationalDatabaseResult, RebootRelationalDatabaseError>;
+
+    /// <p>Deletes a specific static IP from your account.</p>
+    fn release_static_ip(
+        &self,
+        input: ReleaseStaticIpRequest,
+    ) -> RusotoFuture<ReleaseStaticIpResult, ReleaseStaticIpError>;
+
+    /// <p>Starts a specific Amazon Lightsail instance from a stopped state. To restart an instance, use the <code>reboot instance</code> operation.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>start instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn start_instance(
+        &self,
+        input: StartInstanceRequest,
+    ) -> RusotoFuture<StartInstanceResult, StartInstanceError>;
+
+    /// <p>Starts a specific database from a stopped state in Amazon Lightsail. To restart a database, use the <code>reboot relational database</code> operation.</p> <p>The <code>start relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn start_relational_database(
+        &self,
+        input: StartRelationalDatabaseRequest,
+    ) -> RusotoFuture<StartRelationalDatabaseResult, StartRelationalDatabaseError>;
+
+    /// <p>Stops a specific Amazon Lightsail instance that is currently running.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>stop instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn stop_instance(
+        &self,
+        input: StopInstanceRequest,
+    ) -> RusotoFuture<StopInstanceResult, StopInstanceError>;
+
+    /// <p>Stops a specific database that is currently running in Amazon Lightsail.</p> <p>The <code>stop relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn stop_relational_database(
+        &self,
+        input: StopRelationalDatabaseRequest,
+    ) -> RusotoFuture<StopRelationalDatabaseResult, StopRelationalDatabaseError>;
+
+    /// <p>Adds one or more tags to the specified Amazon Lightsail resource. Each resource can have a maximum of 50 tags. Each tag consists of a key and an optional value. Tag keys must be unique per resource. For more information about tags, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-tags">Lightsail Dev Guide</a>.</p> <p>The <code>tag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by resourceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResult, TagResourceError>;
+
+    /// <p>Attempts to unpeer the Lightsail VPC from the user's default VPC.</p>
+    fn unpeer_vpc(&self) -> RusotoFuture<UnpeerVpcResult, UnpeerVpcError>;
+
+    /// <p>Deletes the specified set of tag keys and their values from the specified Amazon Lightsail resource.</p> <p>The <code>untag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by resourceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResult, UntagResourceError>;
+
+    /// <p>Updates a domain recordset after it is created.</p> <p>The <code>update domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn update_domain_entry(
+        &self,
+        input: UpdateDomainEntryRequest,
+    ) -> RusotoFuture<UpdateDomainEntryResult, UpdateDomainEntryError>;
+
+    /// <p>Updates the specified attribute for a load balancer. You can only update one attribute at a time.</p> <p>The <code>update load balancer attribute</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn update_load_balancer_attribute(
+        &self,
+        input: UpdateLoadBalancerAttributeRequest,
+    ) -> RusotoFuture<UpdateLoadBalancerAttributeResult, UpdateLoadBalancerAttributeError>;
+
+    /// <p>Allows the update of one or more attributes of a database in Amazon Lightsail.</p> <p>Updates are applied immediately, or in cases where the updates could result in an outage, are applied during the database's predefined maintenance window.</p> <p>The <code>update relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn update_relational_database(
+        &self,
+        input: UpdateRelationalDatabaseRequest,
+    ) -> RusotoFuture<UpdateRelationalDatabaseResult, UpdateRelationalDatabaseError>;
+
+    /// <p>Allows the update of one or more parameters of a database in Amazon Lightsail.</p> <p>Parameter updates don't cause outages; therefore, their application is not subject to the preferred maintenance window. However, there are two ways in which paramater updates are applied: <code>dynamic</code> or <code>pending-reboot</code>. Parameters marked with a <code>dynamic</code> apply type are applied immediately. Parameters marked with a <code>pending-reboot</code> apply type are applied only after the database is rebooted using the <code>reboot relational database</code> operation.</p> <p>The <code>update relational database parameters</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    fn update_relational_database_parameters(
+        &self,
+        input: UpdateRelationalDatabaseParametersRequest,
+    ) -> RusotoFuture<
+        UpdateRelationalDatabaseParametersResult,
+        UpdateRelationalDatabaseParametersError,
+    >;
+}
+impl<T: ?Sized + Lightsail> Lightsail for ::std::sync::Arc<T> {
+    fn allocate_static_ip(
+        &self,
+        input: AllocateStaticIpRequest,
+    ) -> RusotoFuture<AllocateStaticIpResult, AllocateStaticIpError> {
+        (**self).allocate_static_ip(input)
+    }
+    fn attach_disk(
+        &self,
+        input: AttachDiskRequest,
+    ) -> RusotoFuture<AttachDiskResult, AttachDiskError> {
+        (**self).attach_disk(input)
+    }
+    fn attach_instances_to_load_balancer(
+        &self,
+        input: AttachInstancesToLoadBalancerRequest,
+    ) -> RusotoFuture<AttachInstancesToLoadBalancerResult, AttachInstancesToLoadBalancerError> {
+        (**self).attach_instances_to_load_balancer(input)
+    }
+    fn attach_load_balancer_tls_certificate(
+        &self,
+        input: AttachLoadBalancerTlsCertificateRequest,
+    ) -> RusotoFuture<AttachLoadBalancerTlsCertificateResult, AttachLoadBalancerTlsCertificateError>
+    {
+        (**self).attach_load_balancer_tls_certificate(input)
+    }
+    fn attach_static_ip(
+        &self,
+        input: AttachStaticIpRequest,
+    ) -> RusotoFuture<AttachStaticIpResult, AttachStaticIpError> {
+        (**self).attach_static_ip(input)
+    }
+    fn close_instance_public_ports(
+        &self,
+        input: CloseInstancePublicPortsRequest,
+    ) -> RusotoFuture<CloseInstancePublicPortsResult, CloseInstancePublicPortsError> {
+        (**self).close_instance_public_ports(input)
+    }
+    fn copy_snapshot(
+        &self,
+        input: CopySnapshotRequest,
+    ) -> RusotoFuture<CopySnapshotResult, CopySnapshotError> {
+        (**self).copy_snapshot(input)
+    }
+    fn create_cloud_formation_stack(
+        &self,
+        input: CreateCloudFormationStackRequest,
+    ) -> RusotoFuture<CreateCloudFormationStackResult, CreateCloudFormationStackError> {
+        (**self).create_cloud_formation_stack(input)
+    }
     fn create_disk(
         &self,
         input: CreateDiskRequest,
-    ) -> RusotoFuture<CreateDiskResult, CreateDiskError>;
-
-    /// <p>Creates a block storage disk from a disk snapshot that can be attached to a Lightsail instance in the same Availability Zone (e.g., <code>us-east-2a</code>). The disk is created in the regional endpoint that you send the HTTP request to. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/overview/article/understanding-regions-and-availability-zones-in-amazon-lightsail">Regions and Availability Zones in Lightsail</a>.</p> <p>The <code>create disk from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by diskSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateDiskResult, CreateDiskError> {
+        (**self).create_disk(input)
+    }
     fn create_disk_from_snapshot(
         &self,
         input: CreateDiskFromSnapshotRequest,
-    ) -> RusotoFuture<CreateDiskFromSnapshotResult, CreateDiskFromSnapshotError>;
-
-    /// <p>Creates a snapshot of a block storage disk. You can use snapshots for backups, to make copies of disks, and to save data before shutting down a Lightsail instance.</p> <p>You can take a snapshot of an attached disk that is in use; however, snapshots only capture data that has been written to your disk at the time the snapshot command is issued. This may exclude any data that has been cached by any applications or the operating system. If you can pause any file systems on the disk long enough to take a snapshot, your snapshot should be complete. Nevertheless, if you cannot pause all file writes to the disk, you should unmount the disk from within the Lightsail instance, issue the create disk snapshot command, and then remount the disk to ensure a consistent and complete snapshot. You may remount and use your disk while the snapshot status is pending.</p> <p>You can also use this operation to create a snapshot of an instance's system volume. You might want to do this, for example, to recover data from the system volume of a botched instance or to create a backup of the system volume like you would for a block storage disk. To create a snapshot of a system volume, just define the <code>instance name</code> parameter when issuing the snapshot command, and a snapshot of the defined instance's system volume will be created. After the snapshot is available, you can create a block storage disk from the snapshot and attach it to a running instance to access the data on the disk.</p> <p>The <code>create disk snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateDiskFromSnapshotResult, CreateDiskFromSnapshotError> {
+        (**self).create_disk_from_snapshot(input)
+    }
     fn create_disk_snapshot(
         &self,
         input: CreateDiskSnapshotRequest,
-    ) -> RusotoFuture<CreateDiskSnapshotResult, CreateDiskSnapshotError>;
-
-    /// <p>Creates a domain resource for the specified domain (e.g., example.com).</p> <p>The <code>create domain</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateDiskSnapshotResult, CreateDiskSnapshotError> {
+        (**self).create_disk_snapshot(input)
+    }
     fn create_domain(
         &self,
         input: CreateDomainRequest,
-    ) -> RusotoFuture<CreateDomainResult, CreateDomainError>;
-
-    /// <p>Creates one of the following entry records associated with the domain: Address (A), canonical name (CNAME), mail exchanger (MX), name server (NS), start of authority (SOA), service locator (SRV), or text (TXT).</p> <p>The <code>create domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateDomainResult, CreateDomainError> {
+        (**self).create_domain(input)
+    }
     fn create_domain_entry(
         &self,
         input: CreateDomainEntryRequest,
-    ) -> RusotoFuture<CreateDomainEntryResult, CreateDomainEntryError>;
-
-    /// <p>Creates a snapshot of a specific virtual private server, or <i>instance</i>. You can use a snapshot to create a new instance that is based on that snapshot.</p> <p>The <code>create instance snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateDomainEntryResult, CreateDomainEntryError> {
+        (**self).create_domain_entry(input)
+    }
     fn create_instance_snapshot(
         &self,
         input: CreateInstanceSnapshotRequest,
-    ) -> RusotoFuture<CreateInstanceSnapshotResult, CreateInstanceSnapshotError>;
-
-    /// <p>Creates one or more Amazon Lightsail virtual private servers, or <i>instances</i>. Create instances using active blueprints. Inactive blueprints are listed to support customers with existing instances but are not necessarily available for launch of new instances. Blueprints are marked inactive when they become outdated due to operating system updates or new application releases. Use the get blueprints operation to return a list of available blueprints.</p> <p>The <code>create instances</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateInstanceSnapshotResult, CreateInstanceSnapshotError> {
+        (**self).create_instance_snapshot(input)
+    }
     fn create_instances(
         &self,
         input: CreateInstancesRequest,
-    ) -> RusotoFuture<CreateInstancesResult, CreateInstancesError>;
-
-    /// <p>Uses a specific snapshot as a blueprint for creating one or more new instances that are based on that identical configuration.</p> <p>The <code>create instances from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by instanceSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateInstancesResult, CreateInstancesError> {
+        (**self).create_instances(input)
+    }
     fn create_instances_from_snapshot(
         &self,
         input: CreateInstancesFromSnapshotRequest,
-    ) -> RusotoFuture<CreateInstancesFromSnapshotResult, CreateInstancesFromSnapshotError>;
-
-    /// <p>Creates an SSH key pair.</p> <p>The <code>create key pair</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateInstancesFromSnapshotResult, CreateInstancesFromSnapshotError> {
+        (**self).create_instances_from_snapshot(input)
+    }
     fn create_key_pair(
         &self,
         input: CreateKeyPairRequest,
-    ) -> RusotoFuture<CreateKeyPairResult, CreateKeyPairError>;
-
-    /// <p>Creates a Lightsail load balancer. To learn more about deciding whether to load balance your application, see <a href="https://lightsail.aws.amazon.com/ls/docs/how-to/article/configure-lightsail-instances-for-load-balancing">Configure your Lightsail instances for load balancing</a>. You can create up to 5 load balancers per AWS Region in your account.</p> <p>When you create a load balancer, you can specify a unique name and port settings. To change additional load balancer settings, use the <code>UpdateLoadBalancerAttribute</code> operation.</p> <p>The <code>create load balancer</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateKeyPairResult, CreateKeyPairError> {
+        (**self).create_key_pair(input)
+    }
     fn create_load_balancer(
         &self,
         input: CreateLoadBalancerRequest,
-    ) -> RusotoFuture<CreateLoadBalancerResult, CreateLoadBalancerError>;
-
-    /// <p>Creates a Lightsail load balancer TLS certificate.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>The <code>create load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateLoadBalancerResult, CreateLoadBalancerError> {
+        (**self).create_load_balancer(input)
+    }
     fn create_load_balancer_tls_certificate(
         &self,
         input: CreateLoadBalancerTlsCertificateRequest,
-    ) -> RusotoFuture<CreateLoadBalancerTlsCertificateResult, CreateLoadBalancerTlsCertificateError>;
-
-    /// <p>Creates a new database in Amazon Lightsail.</p> <p>The <code>create relational database</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateLoadBalancerTlsCertificateResult, CreateLoadBalancerTlsCertificateError>
+    {
+        (**self).create_load_balancer_tls_certificate(input)
+    }
     fn create_relational_database(
         &self,
         input: CreateRelationalDatabaseRequest,
-    ) -> RusotoFuture<CreateRelationalDatabaseResult, CreateRelationalDatabaseError>;
-
-    /// <p>Creates a new database from an existing database snapshot in Amazon Lightsail.</p> <p>You can create a new database from a snapshot in if something goes wrong with your original database, or to change it to a different plan, such as a high availability or standard plan.</p> <p>The <code>create relational database from snapshot</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by relationalDatabaseSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateRelationalDatabaseResult, CreateRelationalDatabaseError> {
+        (**self).create_relational_database(input)
+    }
     fn create_relational_database_from_snapshot(
         &self,
         input: CreateRelationalDatabaseFromSnapshotRequest,
     ) -> RusotoFuture<
         CreateRelationalDatabaseFromSnapshotResult,
         CreateRelationalDatabaseFromSnapshotError,
-    >;
-
-    /// <p>Creates a snapshot of your database in Amazon Lightsail. You can use snapshots for backups, to make copies of a database, and to save data before deleting a database.</p> <p>The <code>create relational database snapshot</code> operation supports tag-based access control via request tags. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    > {
+        (**self).create_relational_database_from_snapshot(input)
+    }
     fn create_relational_database_snapshot(
         &self,
         input: CreateRelationalDatabaseSnapshotRequest,
-    ) -> RusotoFuture<CreateRelationalDatabaseSnapshotResult, CreateRelationalDatabaseSnapshotError>;
-
-    /// <p>Deletes the specified block storage disk. The disk must be in the <code>available</code> state (not attached to a Lightsail instance).</p> <note> <p>The disk may remain in the <code>deleting</code> state for several minutes.</p> </note> <p>The <code>delete disk</code> operation supports tag-based access control via resource tags applied to the resource identified by diskName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<CreateRelationalDatabaseSnapshotResult, CreateRelationalDatabaseSnapshotError>
+    {
+        (**self).create_relational_database_snapshot(input)
+    }
     fn delete_disk(
         &self,
         input: DeleteDiskRequest,
-    ) -> RusotoFuture<DeleteDiskResult, DeleteDiskError>;
-
-    /// <p>Deletes the specified disk snapshot.</p> <p>When you make periodic snapshots of a disk, the snapshots are incremental, and only the blocks on the device that have changed since your last snapshot are saved in the new snapshot. When you delete a snapshot, only the data not needed for any other snapshot is removed. So regardless of which prior snapshots have been deleted, all active snapshots will have access to all the information needed to restore the disk.</p> <p>The <code>delete disk snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by diskSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<DeleteDiskResult, DeleteDiskError> {
+        (**self).delete_disk(input)
+    }
     fn delete_disk_snapshot(
         &self,
         input: DeleteDiskSnapshotRequest,
-    ) -> RusotoFuture<DeleteDiskSnapshotResult, DeleteDiskSnapshotError>;
-
-    /// <p>Deletes the specified domain recordset and all of its domain records.</p> <p>The <code>delete domain</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<DeleteDiskSnapshotResult, DeleteDiskSnapshotError> {
+        (**self).delete_disk_snapshot(input)
+    }
     fn delete_domain(
         &self,
         input: DeleteDomainRequest,
-    ) -> RusotoFuture<DeleteDomainResult, DeleteDomainError>;
-
-    /// <p>Deletes a specific domain entry.</p> <p>The <code>delete domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<DeleteDomainResult, DeleteDomainError> {
+        (**self).delete_domain(input)
+    }
     fn delete_domain_entry(
         &self,
         input: DeleteDomainEntryRequest,
-    ) -> RusotoFuture<DeleteDomainEntryResult, DeleteDomainEntryError>;
-
-    /// <p>Deletes a specific Amazon Lightsail virtual private server, or <i>instance</i>.</p> <p>The <code>delete instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<DeleteDomainEntryResult, DeleteDomainEntryError> {
+        (**self).delete_domain_entry(input)
+    }
     fn delete_instance(
         &self,
         input: DeleteInstanceRequest,
-    ) -> RusotoFuture<DeleteInstanceResult, DeleteInstanceError>;
-
-    /// <p>Deletes a specific snapshot of a virtual private server (or <i>instance</i>).</p> <p>The <code>delete instance snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<DeleteInstanceResult, DeleteInstanceError> {
+        (**self).delete_instance(input)
+    }
     fn delete_instance_snapshot(
         &self,
         input: DeleteInstanceSnapshotRequest,
-    ) -> RusotoFuture<DeleteInstanceSnapshotResult, DeleteInstanceSnapshotError>;
-
-    /// <p>Deletes a specific SSH key pair.</p> <p>The <code>delete key pair</code> operation supports tag-based access control via resource tags applied to the resource identified by keyPairName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<DeleteInstanceSnapshotResult, DeleteInstanceSnapshotError> {
+        (**self).delete_instance_snapshot(input)
+    }
     fn delete_key_pair(
         &self,
         input: DeleteKeyPairRequest,
-    ) -> RusotoFuture<DeleteKeyPairResult, DeleteKeyPairError>;
-
-    /// <p><p>Deletes the known host key or certificate used by the Amazon Lightsail browser-based SSH or RDP clients to authenticate an instance. This operation enables the Lightsail browser-based SSH or RDP clients to connect to the instance after a host key mismatch.</p> <important> <p>Perform this operation only if you were expecting the host key or certificate mismatch or if you are familiar with the new host key or certificate on the instance. For more information, see <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-troubleshooting-browser-based-ssh-rdp-client-connection">Troubleshooting connection issues when using the Amazon Lightsail browser-based SSH or RDP client</a>.</p> </important></p>
+    ) -> RusotoFuture<DeleteKeyPairResult, DeleteKeyPairError> {
+        (**self).delete_key_pair(input)
+    }
     fn delete_known_host_keys(
         &self,
         input: DeleteKnownHostKeysRequest,
-    ) -> RusotoFuture<DeleteKnownHostKeysResult, DeleteKnownHostKeysError>;
-
-    /// <p>Deletes a Lightsail load balancer and all its associated SSL/TLS certificates. Once the load balancer is deleted, you will need to create a new load balancer, create a new certificate, and verify domain ownership again.</p> <p>The <code>delete load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<DeleteKnownHostKeysResult, DeleteKnownHostKeysError> {
+        (**self).delete_known_host_keys(input)
+    }
     fn delete_load_balancer(
         &self,
         input: DeleteLoadBalancerRequest,
-    ) -> RusotoFuture<DeleteLoadBalancerResult, DeleteLoadBalancerError>;
-
-    /// <p>Deletes an SSL/TLS certificate associated with a Lightsail load balancer.</p> <p>The <code>delete load balancer tls certificate</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<DeleteLoadBalancerResult, DeleteLoadBalancerError> {
+        (**self).delete_load_balancer(input)
+    }
     fn delete_load_balancer_tls_certificate(
         &self,
         input: DeleteLoadBalancerTlsCertificateRequest,
-    ) -> RusotoFuture<DeleteLoadBalancerTlsCertificateResult, DeleteLoadBalancerTlsCertificateError>;
-
-    /// <p>Deletes a database in Amazon Lightsail.</p> <p>The <code>delete relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<DeleteLoadBalancerTlsCertificateResult, DeleteLoadBalancerTlsCertificateError>
+    {
+        (**self).delete_load_balancer_tls_certificate(input)
+    }
     fn delete_relational_database(
         &self,
         input: DeleteRelationalDatabaseRequest,
-    ) -> RusotoFuture<DeleteRelationalDatabaseResult, DeleteRelationalDatabaseError>;
-
-    /// <p>Deletes a database snapshot in Amazon Lightsail.</p> <p>The <code>delete relational database snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<DeleteRelationalDatabaseResult, DeleteRelationalDatabaseError> {
+        (**self).delete_relational_database(input)
+    }
     fn delete_relational_database_snapshot(
         &self,
         input: DeleteRelationalDatabaseSnapshotRequest,
-    ) -> RusotoFuture<DeleteRelationalDatabaseSnapshotResult, DeleteRelationalDatabaseSnapshotError>;
-
-    /// <p>Detaches a stopped block storage disk from a Lightsail instance. Make sure to unmount any file systems on the device within your operating system before stopping the instance and detaching the disk.</p> <p>The <code>detach disk</code> operation supports tag-based access control via resource tags applied to the resource identified by diskName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<DeleteRelationalDatabaseSnapshotResult, DeleteRelationalDatabaseSnapshotError>
+    {
+        (**self).delete_relational_database_snapshot(input)
+    }
     fn detach_disk(
         &self,
         input: DetachDiskRequest,
-    ) -> RusotoFuture<DetachDiskResult, DetachDiskError>;
-
-    /// <p>Detaches the specified instances from a Lightsail load balancer.</p> <p>This operation waits until the instances are no longer needed before they are detached from the load balancer.</p> <p>The <code>detach instances from load balancer</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<DetachDiskResult, DetachDiskError> {
+        (**self).detach_disk(input)
+    }
     fn detach_instances_from_load_balancer(
         &self,
         input: DetachInstancesFromLoadBalancerRequest,
-    ) -> RusotoFuture<DetachInstancesFromLoadBalancerResult, DetachInstancesFromLoadBalancerError>;
-
-    /// <p>Detaches a static IP from the Amazon Lightsail instance to which it is attached.</p>
+    ) -> RusotoFuture<DetachInstancesFromLoadBalancerResult, DetachInstancesFromLoadBalancerError>
+    {
+        (**self).detach_instances_from_load_balancer(input)
+    }
     fn detach_static_ip(
         &self,
         input: DetachStaticIpRequest,
-    ) -> RusotoFuture<DetachStaticIpResult, DetachStaticIpError>;
-
-    /// <p>Downloads the default SSH key pair from the user's account.</p>
+    ) -> RusotoFuture<DetachStaticIpResult, DetachStaticIpError> {
+        (**self).detach_static_ip(input)
+    }
     fn download_default_key_pair(
         &self,
-    ) -> RusotoFuture<DownloadDefaultKeyPairResult, DownloadDefaultKeyPairError>;
-
-    /// <p><p>Exports an Amazon Lightsail instance or block storage disk snapshot to Amazon Elastic Compute Cloud (Amazon EC2). This operation results in an export snapshot record that can be used with the <code>create cloud formation stack</code> operation to create new Amazon EC2 instances.</p> <p>Exported instance snapshots appear in Amazon EC2 as Amazon Machine Images (AMIs), and the instance system disk appears as an Amazon Elastic Block Store (Amazon EBS) volume. Exported disk snapshots appear in Amazon EC2 as Amazon EBS volumes. Snapshots are exported to the same Amazon Web Services Region in Amazon EC2 as the source Lightsail snapshot.</p> <p/> <p>The <code>export snapshot</code> operation supports tag-based access control via resource tags applied to the resource identified by sourceSnapshotName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p> <note> <p>Use the <code>get instance snapshots</code> or <code>get disk snapshots</code> operations to get a list of snapshots that you can export to Amazon EC2.</p> </note></p>
+    ) -> RusotoFuture<DownloadDefaultKeyPairResult, DownloadDefaultKeyPairError> {
+        (**self).download_default_key_pair()
+    }
     fn export_snapshot(
         &self,
         input: ExportSnapshotRequest,
-    ) -> RusotoFuture<ExportSnapshotResult, ExportSnapshotError>;
-
-    /// <p>Returns the names of all active (not deleted) resources.</p>
+    ) -> RusotoFuture<ExportSnapshotResult, ExportSnapshotError> {
+        (**self).export_snapshot(input)
+    }
     fn get_active_names(
         &self,
         input: GetActiveNamesRequest,
-    ) -> RusotoFuture<GetActiveNamesResult, GetActiveNamesError>;
-
-    /// <p>Returns the list of available instance images, or <i>blueprints</i>. You can use a blueprint to create a new virtual private server already running a specific operating system, as well as a preinstalled app or development stack. The software each instance is running depends on the blueprint image you choose.</p>
+    ) -> RusotoFuture<GetActiveNamesResult, GetActiveNamesError> {
+        (**self).get_active_names(input)
+    }
     fn get_blueprints(
         &self,
         input: GetBlueprintsRequest,
-    ) -> RusotoFuture<GetBlueprintsResult, GetBlueprintsError>;
-
-    /// <p>Returns the list of bundles that are available for purchase. A bundle describes the specs for your virtual private server (or <i>instance</i>).</p>
+    ) -> RusotoFuture<GetBlueprintsResult, GetBlueprintsError> {
+        (**self).get_blueprints(input)
+    }
     fn get_bundles(
         &self,
         input: GetBundlesRequest,
-    ) -> RusotoFuture<GetBundlesResult, GetBundlesError>;
-
-    /// <p>Returns the CloudFormation stack record created as a result of the <code>create cloud formation stack</code> operation.</p> <p>An AWS CloudFormation stack is used to create a new Amazon EC2 instance from an exported Lightsail snapshot.</p>
+    ) -> RusotoFuture<GetBundlesResult, GetBundlesError> {
+        (**self).get_bundles(input)
+    }
     fn get_cloud_formation_stack_records(
         &self,
         input: GetCloudFormationStackRecordsRequest,
-    ) -> RusotoFuture<GetCloudFormationStackRecordsResult, GetCloudFormationStackRecordsError>;
-
-    /// <p>Returns information about a specific block storage disk.</p>
-    fn get_disk(&self, input: GetDiskRequest) -> RusotoFuture<GetDiskResult, GetDiskError>;
-
-    /// <p>Returns information about a specific block storage disk snapshot.</p>
+    ) -> RusotoFuture<GetCloudFormationStackRecordsResult, GetCloudFormationStackRecordsError> {
+        (**self).get_cloud_formation_stack_records(input)
+    }
+    fn get_disk(&self, input: GetDiskRequest) -> RusotoFuture<GetDiskResult, GetDiskError> {
+        (**self).get_disk(input)
+    }
     fn get_disk_snapshot(
         &self,
         input: GetDiskSnapshotRequest,
-    ) -> RusotoFuture<GetDiskSnapshotResult, GetDiskSnapshotError>;
-
-    /// <p>Returns information about all block storage disk snapshots in your AWS account and region.</p> <p>If you are describing a long list of disk snapshots, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
+    ) -> RusotoFuture<GetDiskSnapshotResult, GetDiskSnapshotError> {
+        (**self).get_disk_snapshot(input)
+    }
     fn get_disk_snapshots(
         &self,
         input: GetDiskSnapshotsRequest,
-    ) -> RusotoFuture<GetDiskSnapshotsResult, GetDiskSnapshotsError>;
-
-    /// <p>Returns information about all block storage disks in your AWS account and region.</p> <p>If you are describing a long list of disks, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
-    fn get_disks(&self, input: GetDisksRequest) -> RusotoFuture<GetDisksResult, GetDisksError>;
-
-    /// <p>Returns information about a specific domain recordset.</p>
-    fn get_domain(&self, input: GetDomainRequest) -> RusotoFuture<GetDomainResult, GetDomainError>;
-
-    /// <p>Returns a list of all domains in the user's account.</p>
+    ) -> RusotoFuture<GetDiskSnapshotsResult, GetDiskSnapshotsError> {
+        (**self).get_disk_snapshots(input)
+    }
+    fn get_disks(&self, input: GetDisksRequest) -> RusotoFuture<GetDisksResult, GetDisksError> {
+        (**self).get_disks(input)
+    }
+    fn get_domain(&self, input: GetDomainRequest) -> RusotoFuture<GetDomainResult, GetDomainError> {
+        (**self).get_domain(input)
+    }
     fn get_domains(
         &self,
         input: GetDomainsRequest,
-    ) -> RusotoFuture<GetDomainsResult, GetDomainsError>;
-
-    /// <p>Returns the export snapshot record created as a result of the <code>export snapshot</code> operation.</p> <p>An export snapshot record can be used to create a new Amazon EC2 instance and its related resources with the <code>create cloud formation stack</code> operation.</p>
+    ) -> RusotoFuture<GetDomainsResult, GetDomainsError> {
+        (**self).get_domains(input)
+    }
     fn get_export_snapshot_records(
         &self,
         input: GetExportSnapshotRecordsRequest,
-    ) -> RusotoFuture<GetExportSnapshotRecordsResult, GetExportSnapshotRecordsError>;
-
-    /// <p>Returns information about a specific Amazon Lightsail instance, which is a virtual private server.</p>
+    ) -> RusotoFuture<GetExportSnapshotRecordsResult, GetExportSnapshotRecordsError> {
+        (**self).get_export_snapshot_records(input)
+    }
     fn get_instance(
         &self,
         input: GetInstanceRequest,
-    ) -> RusotoFuture<GetInstanceResult, GetInstanceError>;
-
-    /// <p>Returns temporary SSH keys you can use to connect to a specific virtual private server, or <i>instance</i>.</p> <p>The <code>get instance access details</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<GetInstanceResult, GetInstanceError> {
+        (**self).get_instance(input)
+    }
     fn get_instance_access_details(
         &self,
         input: GetInstanceAccessDetailsRequest,
-    ) -> RusotoFuture<GetInstanceAccessDetailsResult, GetInstanceAccessDetailsError>;
-
-    /// <p>Returns the data points for the specified Amazon Lightsail instance metric, given an instance name.</p>
+    ) -> RusotoFuture<GetInstanceAccessDetailsResult, GetInstanceAccessDetailsError> {
+        (**self).get_instance_access_details(input)
+    }
     fn get_instance_metric_data(
         &self,
         input: GetInstanceMetricDataRequest,
-    ) -> RusotoFuture<GetInstanceMetricDataResult, GetInstanceMetricDataError>;
-
-    /// <p>Returns the port states for a specific virtual private server, or <i>instance</i>.</p>
+    ) -> RusotoFuture<GetInstanceMetricDataResult, GetInstanceMetricDataError> {
+        (**self).get_instance_metric_data(input)
+    }
     fn get_instance_port_states(
         &self,
         input: GetInstancePortStatesRequest,
-    ) -> RusotoFuture<GetInstancePortStatesResult, GetInstancePortStatesError>;
-
-    /// <p>Returns information about a specific instance snapshot.</p>
+    ) -> RusotoFuture<GetInstancePortStatesResult, GetInstancePortStatesError> {
+        (**self).get_instance_port_states(input)
+    }
     fn get_instance_snapshot(
         &self,
         input: GetInstanceSnapshotRequest,
-    ) -> RusotoFuture<GetInstanceSnapshotResult, GetInstanceSnapshotError>;
-
-    /// <p>Returns all instance snapshots for the user's account.</p>
+    ) -> RusotoFuture<GetInstanceSnapshotResult, GetInstanceSnapshotError> {
+        (**self).get_instance_snapshot(input)
+    }
     fn get_instance_snapshots(
         &self,
         input: GetInstanceSnapshotsRequest,
-    ) -> RusotoFuture<GetInstanceSnapshotsResult, GetInstanceSnapshotsError>;
-
-    /// <p>Returns the state of a specific instance. Works on one instance at a time.</p>
+    ) -> RusotoFuture<GetInstanceSnapshotsResult, GetInstanceSnapshotsError> {
+        (**self).get_instance_snapshots(input)
+    }
     fn get_instance_state(
         &self,
         input: GetInstanceStateRequest,
-    ) -> RusotoFuture<GetInstanceStateResult, GetInstanceStateError>;
-
-    /// <p>Returns information about all Amazon Lightsail virtual private servers, or <i>instances</i>.</p>
+    ) -> RusotoFuture<GetInstanceStateResult, GetInstanceStateError> {
+        (**self).get_instance_state(input)
+    }
     fn get_instances(
         &self,
         input: GetInstancesRequest,
-    ) -> RusotoFuture<GetInstancesResult, GetInstancesError>;
-
-    /// <p>Returns information about a specific key pair.</p>
+    ) -> RusotoFuture<GetInstancesResult, GetInstancesError> {
+        (**self).get_instances(input)
+    }
     fn get_key_pair(
         &self,
         input: GetKeyPairRequest,
-    ) -> RusotoFuture<GetKeyPairResult, GetKeyPairError>;
-
-    /// <p>Returns information about all key pairs in the user's account.</p>
+    ) -> RusotoFuture<GetKeyPairResult, GetKeyPairError> {
+        (**self).get_key_pair(input)
+    }
     fn get_key_pairs(
         &self,
         input: GetKeyPairsRequest,
-    ) -> RusotoFuture<GetKeyPairsResult, GetKeyPairsError>;
-
-    /// <p>Returns information about the specified Lightsail load balancer.</p>
+    ) -> RusotoFuture<GetKeyPairsResult, GetKeyPairsError> {
+        (**self).get_key_pairs(input)
+    }
     fn get_load_balancer(
         &self,
         input: GetLoadBalancerRequest,
-    ) -> RusotoFuture<GetLoadBalancerResult, GetLoadBalancerError>;
-
-    /// <p>Returns information about health metrics for your Lightsail load balancer.</p>
+    ) -> RusotoFuture<GetLoadBalancerResult, GetLoadBalancerError> {
+        (**self).get_load_balancer(input)
+    }
     fn get_load_balancer_metric_data(
         &self,
         input: GetLoadBalancerMetricDataRequest,
-    ) -> RusotoFuture<GetLoadBalancerMetricDataResult, GetLoadBalancerMetricDataError>;
-
-    /// <p>Returns information about the TLS certificates that are associated with the specified Lightsail load balancer.</p> <p>TLS is just an updated, more secure version of Secure Socket Layer (SSL).</p> <p>You can have a maximum of 2 certificates associated with a Lightsail load balancer. One is active and the other is inactive.</p>
+    ) -> RusotoFuture<GetLoadBalancerMetricDataResult, GetLoadBalancerMetricDataError> {
+        (**self).get_load_balancer_metric_data(input)
+    }
     fn get_load_balancer_tls_certificates(
         &self,
         input: GetLoadBalancerTlsCertificatesRequest,
-    ) -> RusotoFuture<GetLoadBalancerTlsCertificatesResult, GetLoadBalancerTlsCertificatesError>;
-
-    /// <p>Returns information about all load balancers in an account.</p> <p>If you are describing a long list of load balancers, you can paginate the output to make the list more manageable. You can use the pageToken and nextPageToken values to retrieve the next items in the list.</p>
+    ) -> RusotoFuture<GetLoadBalancerTlsCertificatesResult, GetLoadBalancerTlsCertificatesError>
+    {
+        (**self).get_load_balancer_tls_certificates(input)
+    }
     fn get_load_balancers(
         &self,
         input: GetLoadBalancersRequest,
-    ) -> RusotoFuture<GetLoadBalancersResult, GetLoadBalancersError>;
-
-    /// <p>Returns information about a specific operation. Operations include events such as when you create an instance, allocate a static IP, attach a static IP, and so on.</p>
+    ) -> RusotoFuture<GetLoadBalancersResult, GetLoadBalancersError> {
+        (**self).get_load_balancers(input)
+    }
     fn get_operation(
         &self,
         input: GetOperationRequest,
-    ) -> RusotoFuture<GetOperationResult, GetOperationError>;
-
-    /// <p>Returns information about all operations.</p> <p>Results are returned from oldest to newest, up to a maximum of 200. Results can be paged by making each subsequent call to <code>GetOperations</code> use the maximum (last) <code>statusChangedAt</code> value from the previous request.</p>
+    ) -> RusotoFuture<GetOperationResult, GetOperationError> {
+        (**self).get_operation(input)
+    }
     fn get_operations(
         &self,
         input: GetOperationsRequest,
-    ) -> RusotoFuture<GetOperationsResult, GetOperationsError>;
-
-    /// <p>Gets operations for a specific resource (e.g., an instance or a static IP).</p>
+    ) -> RusotoFuture<GetOperationsResult, GetOperationsError> {
+        (**self).get_operations(input)
+    }
     fn get_operations_for_resource(
         &self,
         input: GetOperationsForResourceRequest,
-    ) -> RusotoFuture<GetOperationsForResourceResult, GetOperationsForResourceError>;
-
-    /// <p>Returns a list of all valid regions for Amazon Lightsail. Use the <code>include availability zones</code> parameter to also return the Availability Zones in a region.</p>
+    ) -> RusotoFuture<GetOperationsForResourceResult, GetOperationsForResourceError> {
+        (**self).get_operations_for_resource(input)
+    }
     fn get_regions(
         &self,
         input: GetRegionsRequest,
-    ) -> RusotoFuture<GetRegionsResult, GetRegionsError>;
-
-    /// <p>Returns information about a specific database in Amazon Lightsail.</p>
+    ) -> RusotoFuture<GetRegionsResult, GetRegionsError> {
+        (**self).get_regions(input)
+    }
     fn get_relational_database(
         &self,
         input: GetRelationalDatabaseRequest,
-    ) -> RusotoFuture<GetRelationalDatabaseResult, GetRelationalDatabaseError>;
-
-    /// <p>Returns a list of available database blueprints in Amazon Lightsail. A blueprint describes the major engine version of a database.</p> <p>You can use a blueprint ID to create a new database that runs a specific database engine.</p>
+    ) -> RusotoFuture<GetRelationalDatabaseResult, GetRelationalDatabaseError> {
+        (**self).get_relational_database(input)
+    }
     fn get_relational_database_blueprints(
         &self,
         input: GetRelationalDatabaseBlueprintsRequest,
-    ) -> RusotoFuture<GetRelationalDatabaseBlueprintsResult, GetRelationalDatabaseBlueprintsError>;
-
-    /// <p>Returns the list of bundles that are available in Amazon Lightsail. A bundle describes the performance specifications for a database.</p> <p>You can use a bundle ID to create a new database with explicit performance specifications.</p>
+    ) -> RusotoFuture<GetRelationalDatabaseBlueprintsResult, GetRelationalDatabaseBlueprintsError>
+    {
+        (**self).get_relational_database_blueprints(input)
+    }
     fn get_relational_database_bundles(
         &self,
         input: GetRelationalDatabaseBundlesRequest,
-    ) -> RusotoFuture<GetRelationalDatabaseBundlesResult, GetRelationalDatabaseBundlesError>;
-
-    /// <p>Returns a list of events for a specific database in Amazon Lightsail.</p>
+    ) -> RusotoFuture<GetRelationalDatabaseBundlesResult, GetRelationalDatabaseBundlesError> {
+        (**self).get_relational_database_bundles(input)
+    }
     fn get_relational_database_events(
         &self,
         input: GetRelationalDatabaseEventsRequest,
-    ) -> RusotoFuture<GetRelationalDatabaseEventsResult, GetRelationalDatabaseEventsError>;
-
-    /// <p>Returns a list of log events for a database in Amazon Lightsail.</p>
+    ) -> RusotoFuture<GetRelationalDatabaseEventsResult, GetRelationalDatabaseEventsError> {
+        (**self).get_relational_database_events(input)
+    }
     fn get_relational_database_log_events(
         &self,
         input: GetRelationalDatabaseLogEventsRequest,
-    ) -> RusotoFuture<GetRelationalDatabaseLogEventsResult, GetRelationalDatabaseLogEventsError>;
-
-    /// <p>Returns a list of available log streams for a specific database in Amazon Lightsail.</p>
+    ) -> RusotoFuture<GetRelationalDatabaseLogEventsResult, GetRelationalDatabaseLogEventsError>
+    {
+        (**self).get_relational_database_log_events(input)
+    }
     fn get_relational_database_log_streams(
         &self,
         input: GetRelationalDatabaseLogStreamsRequest,
-    ) -> RusotoFuture<GetRelationalDatabaseLogStreamsResult, GetRelationalDatabaseLogStreamsError>;
-
-    /// <p>Returns the current, previous, or pending versions of the master user password for a Lightsail database.</p> <p>The <code>asdf</code> operation GetRelationalDatabaseMasterUserPassword supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName.</p>
+    ) -> RusotoFuture<GetRelationalDatabaseLogStreamsResult, GetRelationalDatabaseLogStreamsError>
+    {
+        (**self).get_relational_database_log_streams(input)
+    }
     fn get_relational_database_master_user_password(
         &self,
         input: GetRelationalDatabaseMasterUserPasswordRequest,
     ) -> RusotoFuture<
         GetRelationalDatabaseMasterUserPasswordResult,
         GetRelationalDatabaseMasterUserPasswordError,
-    >;
-
-    /// <p>Returns the data points of the specified metric for a database in Amazon Lightsail.</p>
+    > {
+        (**self).get_relational_database_master_user_password(input)
+    }
     fn get_relational_database_metric_data(
         &self,
         input: GetRelationalDatabaseMetricDataRequest,
-    ) -> RusotoFuture<GetRelationalDatabaseMetricDataResult, GetRelationalDatabaseMetricDataError>;
-
-    /// <p>Returns all of the runtime parameters offered by the underlying database software, or engine, for a specific database in Amazon Lightsail.</p> <p>In addition to the parameter names and values, this operation returns other information about each parameter. This information includes whether changes require a reboot, whether the parameter is modifiable, the allowed values, and the data types.</p>
+    ) -> RusotoFuture<GetRelationalDatabaseMetricDataResult, GetRelationalDatabaseMetricDataError>
+    {
+        (**self).get_relational_database_metric_data(input)
+    }
     fn get_relational_database_parameters(
         &self,
         input: GetRelationalDatabaseParametersRequest,
-    ) -> RusotoFuture<GetRelationalDatabaseParametersResult, GetRelationalDatabaseParametersError>;
-
-    /// <p>Returns information about a specific database snapshot in Amazon Lightsail.</p>
+    ) -> RusotoFuture<GetRelationalDatabaseParametersResult, GetRelationalDatabaseParametersError>
+    {
+        (**self).get_relational_database_parameters(input)
+    }
     fn get_relational_database_snapshot(
         &self,
         input: GetRelationalDatabaseSnapshotRequest,
-    ) -> RusotoFuture<GetRelationalDatabaseSnapshotResult, GetRelationalDatabaseSnapshotError>;
-
-    /// <p>Returns information about all of your database snapshots in Amazon Lightsail.</p>
+    ) -> RusotoFuture<GetRelationalDatabaseSnapshotResult, GetRelationalDatabaseSnapshotError> {
+        (**self).get_relational_database_snapshot(input)
+    }
     fn get_relational_database_snapshots(
         &self,
         input: GetRelationalDatabaseSnapshotsRequest,
-    ) -> RusotoFuture<GetRelationalDatabaseSnapshotsResult, GetRelationalDatabaseSnapshotsError>;
-
-    /// <p>Returns information about all of your databases in Amazon Lightsail.</p>
+    ) -> RusotoFuture<GetRelationalDatabaseSnapshotsResult, GetRelationalDatabaseSnapshotsError>
+    {
+        (**self).get_relational_database_snapshots(input)
+    }
     fn get_relational_databases(
         &self,
         input: GetRelationalDatabasesRequest,
-    ) -> RusotoFuture<GetRelationalDatabasesResult, GetRelationalDatabasesError>;
-
-    /// <p>Returns information about a specific static IP.</p>
+    ) -> RusotoFuture<GetRelationalDatabasesResult, GetRelationalDatabasesError> {
+        (**self).get_relational_databases(input)
+    }
     fn get_static_ip(
         &self,
         input: GetStaticIpRequest,
-    ) -> RusotoFuture<GetStaticIpResult, GetStaticIpError>;
-
-    /// <p>Returns information about all static IPs in the user's account.</p>
+    ) -> RusotoFuture<GetStaticIpResult, GetStaticIpError> {
+        (**self).get_static_ip(input)
+    }
     fn get_static_ips(
         &self,
         input: GetStaticIpsRequest,
-    ) -> RusotoFuture<GetStaticIpsResult, GetStaticIpsError>;
-
-    /// <p>Imports a public SSH key from a specific key pair.</p>
+    ) -> RusotoFuture<GetStaticIpsResult, GetStaticIpsError> {
+        (**self).get_static_ips(input)
+    }
     fn import_key_pair(
         &self,
         input: ImportKeyPairRequest,
-    ) -> RusotoFuture<ImportKeyPairResult, ImportKeyPairError>;
-
-    /// <p>Returns a Boolean value indicating whether your Lightsail VPC is peered.</p>
-    fn is_vpc_peered(&self) -> RusotoFuture<IsVpcPeeredResult, IsVpcPeeredError>;
-
-    /// <p>Adds public ports to an Amazon Lightsail instance.</p> <p>The <code>open instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<ImportKeyPairResult, ImportKeyPairError> {
+        (**self).import_key_pair(input)
+    }
+    fn is_vpc_peered(&self) -> RusotoFuture<IsVpcPeeredResult, IsVpcPeeredError> {
+        (**self).is_vpc_peered()
+    }
     fn open_instance_public_ports(
         &self,
         input: OpenInstancePublicPortsRequest,
-    ) -> RusotoFuture<OpenInstancePublicPortsResult, OpenInstancePublicPortsError>;
-
-    /// <p>Tries to peer the Lightsail VPC with the user's default VPC.</p>
-    fn peer_vpc(&self) -> RusotoFuture<PeerVpcResult, PeerVpcError>;
-
-    /// <p>Sets the specified open ports for an Amazon Lightsail instance, and closes all ports for every protocol not included in the current request.</p> <p>The <code>put instance public ports</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<OpenInstancePublicPortsResult, OpenInstancePublicPortsError> {
+        (**self).open_instance_public_ports(input)
+    }
+    fn peer_vpc(&self) -> RusotoFuture<PeerVpcResult, PeerVpcError> {
+        (**self).peer_vpc()
+    }
     fn put_instance_public_ports(
         &self,
         input: PutInstancePublicPortsRequest,
-    ) -> RusotoFuture<PutInstancePublicPortsResult, PutInstancePublicPortsError>;
-
-    /// <p>Restarts a specific instance.</p> <p>The <code>reboot instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<PutInstancePublicPortsResult, PutInstancePublicPortsError> {
+        (**self).put_instance_public_ports(input)
+    }
     fn reboot_instance(
         &self,
         input: RebootInstanceRequest,
-    ) -> RusotoFuture<RebootInstanceResult, RebootInstanceError>;
-
-    /// <p>Restarts a specific database in Amazon Lightsail.</p> <p>The <code>reboot relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<RebootInstanceResult, RebootInstanceError> {
+        (**self).reboot_instance(input)
+    }
     fn reboot_relational_database(
         &self,
         input: RebootRelationalDatabaseRequest,
-    ) -> RusotoFuture<RebootRelationalDatabaseResult, RebootRelationalDatabaseError>;
-
-    /// <p>Deletes a specific static IP from your account.</p>
+    ) -> RusotoFuture<RebootRelationalDatabaseResult, RebootRelationalDatabaseError> {
+        (**self).reboot_relational_database(input)
+    }
     fn release_static_ip(
         &self,
         input: ReleaseStaticIpRequest,
-    ) -> RusotoFuture<ReleaseStaticIpResult, ReleaseStaticIpError>;
-
-    /// <p>Starts a specific Amazon Lightsail instance from a stopped state. To restart an instance, use the <code>reboot instance</code> operation.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>start instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<ReleaseStaticIpResult, ReleaseStaticIpError> {
+        (**self).release_static_ip(input)
+    }
     fn start_instance(
         &self,
         input: StartInstanceRequest,
-    ) -> RusotoFuture<StartInstanceResult, StartInstanceError>;
-
-    /// <p>Starts a specific database from a stopped state in Amazon Lightsail. To restart a database, use the <code>reboot relational database</code> operation.</p> <p>The <code>start relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<StartInstanceResult, StartInstanceError> {
+        (**self).start_instance(input)
+    }
     fn start_relational_database(
         &self,
         input: StartRelationalDatabaseRequest,
-    ) -> RusotoFuture<StartRelationalDatabaseResult, StartRelationalDatabaseError>;
-
-    /// <p>Stops a specific Amazon Lightsail instance that is currently running.</p> <note> <p>When you start a stopped instance, Lightsail assigns a new public IP address to the instance. To use the same IP address after stopping and starting an instance, create a static IP address and attach it to the instance. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/lightsail-create-static-ip">Lightsail Dev Guide</a>.</p> </note> <p>The <code>stop instance</code> operation supports tag-based access control via resource tags applied to the resource identified by instanceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<StartRelationalDatabaseResult, StartRelationalDatabaseError> {
+        (**self).start_relational_database(input)
+    }
     fn stop_instance(
         &self,
         input: StopInstanceRequest,
-    ) -> RusotoFuture<StopInstanceResult, StopInstanceError>;
-
-    /// <p>Stops a specific database that is currently running in Amazon Lightsail.</p> <p>The <code>stop relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<StopInstanceResult, StopInstanceError> {
+        (**self).stop_instance(input)
+    }
     fn stop_relational_database(
         &self,
         input: StopRelationalDatabaseRequest,
-    ) -> RusotoFuture<StopRelationalDatabaseResult, StopRelationalDatabaseError>;
-
-    /// <p>Adds one or more tags to the specified Amazon Lightsail resource. Each resource can have a maximum of 50 tags. Each tag consists of a key and an optional value. Tag keys must be unique per resource. For more information about tags, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-tags">Lightsail Dev Guide</a>.</p> <p>The <code>tag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by resourceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<StopRelationalDatabaseResult, StopRelationalDatabaseError> {
+        (**self).stop_relational_database(input)
+    }
     fn tag_resource(
         &self,
         input: TagResourceRequest,
-    ) -> RusotoFuture<TagResourceResult, TagResourceError>;
-
-    /// <p>Attempts to unpeer the Lightsail VPC from the user's default VPC.</p>
-    fn unpeer_vpc(&self) -> RusotoFuture<UnpeerVpcResult, UnpeerVpcError>;
-
-    /// <p>Deletes the specified set of tag keys and their values from the specified Amazon Lightsail resource.</p> <p>The <code>untag resource</code> operation supports tag-based access control via request tags and resource tags applied to the resource identified by resourceName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<TagResourceResult, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unpeer_vpc(&self) -> RusotoFuture<UnpeerVpcResult, UnpeerVpcError> {
+        (**self).unpeer_vpc()
+    }
     fn untag_resource(
         &self,
         input: UntagResourceRequest,
-    ) -> RusotoFuture<UntagResourceResult, UntagResourceError>;
-
-    /// <p>Updates a domain recordset after it is created.</p> <p>The <code>update domain entry</code> operation supports tag-based access control via resource tags applied to the resource identified by domainName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<UntagResourceResult, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
     fn update_domain_entry(
         &self,
         input: UpdateDomainEntryRequest,
-    ) -> RusotoFuture<UpdateDomainEntryResult, UpdateDomainEntryError>;
-
-    /// <p>Updates the specified attribute for a load balancer. You can only update one attribute at a time.</p> <p>The <code>update load balancer attribute</code> operation supports tag-based access control via resource tags applied to the resource identified by loadBalancerName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<UpdateDomainEntryResult, UpdateDomainEntryError> {
+        (**self).update_domain_entry(input)
+    }
     fn update_load_balancer_attribute(
         &self,
         input: UpdateLoadBalancerAttributeRequest,
-    ) -> RusotoFuture<UpdateLoadBalancerAttributeResult, UpdateLoadBalancerAttributeError>;
-
-    /// <p>Allows the update of one or more attributes of a database in Amazon Lightsail.</p> <p>Updates are applied immediately, or in cases where the updates could result in an outage, are applied during the database's predefined maintenance window.</p> <p>The <code>update relational database</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<UpdateLoadBalancerAttributeResult, UpdateLoadBalancerAttributeError> {
+        (**self).update_load_balancer_attribute(input)
+    }
     fn update_relational_database(
         &self,
         input: UpdateRelationalDatabaseRequest,
-    ) -> RusotoFuture<UpdateRelationalDatabaseResult, UpdateRelationalDatabaseError>;
-
-    /// <p>Allows the update of one or more parameters of a database in Amazon Lightsail.</p> <p>Parameter updates don't cause outages; therefore, their application is not subject to the preferred maintenance window. However, there are two ways in which paramater updates are applied: <code>dynamic</code> or <code>pending-reboot</code>. Parameters marked with a <code>dynamic</code> apply type are applied immediately. Parameters marked with a <code>pending-reboot</code> apply type are applied only after the database is rebooted using the <code>reboot relational database</code> operation.</p> <p>The <code>update relational database parameters</code> operation supports tag-based access control via resource tags applied to the resource identified by relationalDatabaseName. For more information, see the <a href="https://lightsail.aws.amazon.com/ls/docs/en/articles/amazon-lightsail-controlling-access-using-tags">Lightsail Dev Guide</a>.</p>
+    ) -> RusotoFuture<UpdateRelationalDatabaseResult, UpdateRelationalDatabaseError> {
+        (**self).update_relational_database(input)
+    }
     fn update_relational_database_parameters(
         &self,
         input: UpdateRelationalDatabaseParametersRequest,
     ) -> RusotoFuture<
         UpdateRelationalDatabaseParametersResult,
         UpdateRelationalDatabaseParametersError,
-    >;
+    > {
+        (**self).update_relational_database_parameters(input)
+    }
 }
+/// The error codes Amazon Lightsail retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Lightsail API.
 #[derive(Clone)]
 pub struct LightsailClient {
@@ -12168,6 +20372,27 @@ impl LightsailClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_LIGHTSAIL`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> LightsailClient {
+        Self::new(region::Region::from_env_for_service("LIGHTSAIL"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `lightsail`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> LightsailClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("lightsail", "LIGHTSAIL"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -12186,7 +20411,54 @@ impl LightsailClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> LightsailClient {
-        LightsailClient { client, region }
+        LightsailClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "lightsail")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "lightsail",
+            "lightsail",
+            "LIGHTSAIL",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Lightsail` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Lightsail + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for LightsailClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Lightsail"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "lightsail"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> LightsailClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon CloudWatch Logs clients.
+//!
+//! Makes a single read-only `DescribeDestinations` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_logs::{CloudWatchLogs, CloudWatchLogsClient, DescribeDestinationsRequest};
+
+fn main() {
+    let client = CloudWatchLogsClient::from_env();
+    match client
+        .describe_destinations(DescribeDestinationsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeDestinations succeeded"),
+        Err(error) => {
+            eprintln!("DescribeDestinations failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
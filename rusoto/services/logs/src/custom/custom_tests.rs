@@ -0,0 +1,85 @@
+extern crate rusoto_mock;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::{Future, Sink};
+use serde_json::Value;
+
+use crate::generated::{CloudWatchLogsClient, InputLogEvent};
+use crate::shipper::{log_shipper, LogShipperConfig, LogShipperSummary};
+
+use self::rusoto_mock::*;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::Region;
+
+fn event(message: &str, timestamp: i64) -> InputLogEvent {
+    InputLogEvent {
+        message: message.to_owned(),
+        timestamp,
+    }
+}
+
+#[test]
+fn should_ship_events_in_batches() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = requests.clone();
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(r#"{"nextSequenceToken": "49590"}"#)
+        .with_request_checker(move |request: &SignedRequest| {
+            let body: Value = match request.payload {
+                Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                    serde_json::from_slice(buffer).unwrap()
+                }
+                _ => panic!("expected a buffered payload"),
+            };
+            recorded.lock().unwrap().push(body);
+        });
+    let client = CloudWatchLogsClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let mut config = LogShipperConfig::new("my-app", "instance-1");
+    config.max_batch_events = 2;
+    config.flush_interval = Duration::from_millis(10);
+    let (sender, shipper) = log_shipper(client, config);
+
+    let events = vec![event("b", 2), event("a", 1), event("c", 3)];
+    let sender = sender
+        .send_all(futures::stream::iter_ok(events))
+        .wait()
+        .unwrap()
+        .0;
+    drop(sender);
+
+    let summary = shipper.sync().unwrap();
+    assert_eq!(
+        summary,
+        LogShipperSummary {
+            batches: 2,
+            events: 3,
+            batches_with_rejected_events: 0,
+        }
+    );
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests[0]["logGroupName"], "my-app");
+    assert_eq!(requests[0]["logStreamName"], "instance-1");
+    assert_eq!(requests[0].get("sequenceToken"), None);
+    // batches are sorted by timestamp
+    assert_eq!(requests[0]["logEvents"][0]["message"], "a");
+    assert_eq!(requests[0]["logEvents"][1]["message"], "b");
+    assert_eq!(requests[1]["sequenceToken"], "49590");
+    assert_eq!(requests[1]["logEvents"][0]["message"], "c");
+}
+
+#[test]
+fn should_fail_on_rejected_batches() {
+    let mock = MockRequestDispatcher::with_status(400)
+        .with_body(r#"{"__type": "InvalidParameterException", "message": "Log event too large"}"#);
+    let client = CloudWatchLogsClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let (sender, shipper) = log_shipper(client, LogShipperConfig::new("my-app", "instance-1"));
+
+    let sender = sender.send(event("a", 1)).wait().unwrap();
+    drop(sender);
+
+    assert!(shipper.sync().is_err());
+}
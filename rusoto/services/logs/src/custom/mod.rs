@@ -1 +1,5 @@
+/// Batching and sending log events with sequence token management
+pub mod shipper;
 
+#[cfg(test)]
+mod custom_tests;
@@ -0,0 +1,419 @@
+//! Shipping log events to a log stream.
+//!
+//! `PutLogEvents` accepts batches of at most 10,000 events and 1,048,576 bytes, sorted by
+//! timestamp, and every call must carry the sequence token returned by the previous one.
+//! `log_shipper` returns a channel accepting `InputLogEvent`s and a future which batches them,
+//! keeps track of the sequence token and retries throttled calls. The future completes once
+//! every sender is dropped and the remaining events are sent.
+
+use std::error::Error;
+use std::fmt;
+use std::mem;
+use std::time::Duration;
+
+use futures::future::{self, Loop};
+use futures::sync::mpsc;
+use futures::{Future, Stream};
+use rusoto_core::batch::{backoff, batched, is_transient, Batcher};
+use rusoto_core::waiter::sleep;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    CloudWatchLogs, CreateLogStreamError, CreateLogStreamRequest, DescribeLogStreamsError,
+    DescribeLogStreamsRequest, InputLogEvent, PutLogEventsError, PutLogEventsRequest,
+};
+
+/// The maximum number of events in a single `PutLogEvents` call.
+pub const MAX_BATCH_EVENTS: usize = 10_000;
+
+/// The maximum size of a single `PutLogEvents` call, counting 26 bytes of overhead per event.
+pub const MAX_BATCH_BYTES: usize = 1_048_576;
+
+const EVENT_OVERHEAD: usize = 26;
+
+/// Controls how `log_shipper` batches and sends events.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogShipperConfig {
+    /// The log group the events are sent to
+    pub log_group_name: String,
+    /// The log stream the events are sent to
+    pub log_stream_name: String,
+    /// Create the log stream if it doesn't exist
+    pub create_log_stream: bool,
+    /// Maximum number of events per batch, at most `MAX_BATCH_EVENTS`
+    pub max_batch_events: usize,
+    /// Maximum size of a batch, at most `MAX_BATCH_BYTES`
+    pub max_batch_bytes: usize,
+    /// How long an event waits for a batch to fill up before the batch is sent anyway
+    pub flush_interval: Duration,
+    /// Number of events the channel buffers while a batch is being sent
+    pub channel_capacity: usize,
+    /// Number of attempts made for each batch before shipping fails
+    pub max_attempts: u32,
+    /// Delay before the first retry of a throttled call, doubled for every subsequent retry
+    pub base_delay: Duration,
+}
+
+impl LogShipperConfig {
+    /// Creates a configuration sending events to the given log stream, with the default limits.
+    pub fn new<G, S>(log_group_name: G, log_stream_name: S) -> LogShipperConfig
+    where
+        G: Into<String>,
+        S: Into<String>,
+    {
+        LogShipperConfig {
+            log_group_name: log_group_name.into(),
+            log_stream_name: log_stream_name.into(),
+            create_log_stream: false,
+            max_batch_events: MAX_BATCH_EVENTS,
+            max_batch_bytes: MAX_BATCH_BYTES,
+            flush_interval: Duration::from_secs(5),
+            channel_capacity: 1024,
+            max_attempts: 5,
+            base_delay: Duration::from_millis(200),
+        }
+    }
+}
+
+/// Statistics of a finished `log_shipper` future.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LogShipperSummary {
+    /// Number of `PutLogEvents` calls which succeeded
+    pub batches: usize,
+    /// Number of events sent
+    pub events: usize,
+    /// Number of batches in which CloudWatch Logs rejected some events as too old, too new or
+    /// expired
+    pub batches_with_rejected_events: usize,
+}
+
+/// Errors returned by the `log_shipper` future.
+#[derive(Debug, PartialEq)]
+pub enum LogShipperError {
+    /// Sending a batch failed
+    PutLogEvents(PutLogEventsError),
+    /// Looking up the sequence token failed
+    DescribeLogStreams(DescribeLogStreamsError),
+    /// Creating the log stream failed
+    CreateLogStream(CreateLogStreamError),
+}
+
+impl fmt::Display for LogShipperError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for LogShipperError {
+    fn description(&self) -> &str {
+        match *self {
+            LogShipperError::PutLogEvents(ref cause) => cause.description(),
+            LogShipperError::DescribeLogStreams(ref cause) => cause.description(),
+            LogShipperError::CreateLogStream(ref cause) => cause.description(),
+        }
+    }
+}
+
+/// Returns a channel accepting log events, and a future sending them to the log stream named
+/// in `config`.
+///
+/// The future must be run for events to be sent. It resolves once every sender is dropped and
+/// all events received until then are sent, and fails if a batch can't be sent.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::{Future, Sink};
+/// use rusoto_core::Region;
+/// use rusoto_logs::shipper::{log_shipper, LogShipperConfig};
+/// use rusoto_logs::{CloudWatchLogsClient, InputLogEvent};
+///
+/// let client = CloudWatchLogsClient::new(Region::UsEast1);
+/// let (sender, shipper) = log_shipper(client, LogShipperConfig::new("my-app", "instance-1"));
+///
+/// let event = InputLogEvent {
+///     message: "started".to_owned(),
+///     timestamp: 1_565_000_000_000,
+/// };
+/// // the channel buffers events until the shipper runs
+/// let sender = sender.send(event).wait().expect("shipper stopped");
+///
+/// // dropping the last sender lets the shipper finish once all events are sent
+/// drop(sender);
+/// let summary = shipper.sync().expect("failed to ship events");
+/// println!("sent {} events", summary.events);
+/// ```
+pub fn log_shipper<C>(
+    client: C,
+    config: LogShipperConfig,
+) -> (
+    mpsc::Sender<InputLogEvent>,
+    RusotoFuture<LogShipperSummary, LogShipperError>,
+)
+where
+    C: CloudWatchLogs + Clone + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel(config.channel_capacity);
+    let batches = batched::<_, _, RusotoError<LogShipperError>>(
+        receiver,
+        EventBatcher::new(&config),
+        config.flush_interval,
+    );
+
+    let future = batches.fold(
+        (LogShipperSummary::default(), None),
+        move |(mut summary, token), batch| {
+            let events = batch.len();
+            put_batch(client.clone(), config.clone(), token, batch).map(move |(token, rejected)| {
+                summary.batches += 1;
+                summary.events += events;
+                if rejected {
+                    summary.batches_with_rejected_events += 1;
+                }
+                (summary, token)
+            })
+        },
+    );
+
+    (
+        sender,
+        RusotoFuture::from_future(future.map(|(summary, _)| summary)),
+    )
+}
+
+/// Groups events into batches within the limits of `PutLogEvents`.
+struct EventBatcher {
+    pending: Vec<InputLogEvent>,
+    pending_bytes: usize,
+    max_events: usize,
+    max_bytes: usize,
+}
+
+impl EventBatcher {
+    fn new(config: &LogShipperConfig) -> EventBatcher {
+        EventBatcher {
+            pending: Vec::new(),
+            pending_bytes: 0,
+            max_events: config.max_batch_events.min(MAX_BATCH_EVENTS).max(1),
+            max_bytes: config.max_batch_bytes.min(MAX_BATCH_BYTES),
+        }
+    }
+
+    fn take_batch(&mut self) -> Vec<InputLogEvent> {
+        self.pending_bytes = 0;
+        let mut batch = mem::replace(&mut self.pending, Vec::new());
+        // events must be in chronological order; the sort is stable so ties keep their order
+        batch.sort_by_key(|event| event.timestamp);
+        batch
+    }
+}
+
+impl Batcher for EventBatcher {
+    type Item = InputLogEvent;
+    type Batch = Vec<InputLogEvent>;
+
+    fn push(&mut self, event: InputLogEvent) -> Option<Vec<InputLogEvent>> {
+        let size = event.message.len() + EVENT_OVERHEAD;
+        let full = !self.pending.is_empty()
+            && (self.pending.len() >= self.max_events
+                || self.pending_bytes + size > self.max_bytes);
+        let batch = if full { Some(self.take_batch()) } else { None };
+        self.pending_bytes += size;
+        self.pending.push(event);
+        batch
+    }
+
+    fn flush(&mut self) -> Option<Vec<InputLogEvent>> {
+        if self.pending.is_empty() {
+            None
+        } else {
+            Some(self.take_batch())
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+type PutAttempt = Box<
+    dyn Future<Item = Loop<(Option<String>, bool), PutState>, Error = RusotoError<LogShipperError>>
+        + Send,
+>;
+
+struct PutState {
+    token: Option<String>,
+    attempts: u32,
+    created: bool,
+}
+
+/// Sends a batch, returning the next sequence token and whether events were rejected.
+fn put_batch<C>(
+    client: C,
+    config: LogShipperConfig,
+    token: Option<String>,
+    batch: Vec<InputLogEvent>,
+) -> impl Future<Item = (Option<String>, bool), Error = RusotoError<LogShipperError>> + Send
+where
+    C: CloudWatchLogs + Clone + Send + 'static,
+{
+    let initial = PutState {
+        token,
+        attempts: 0,
+        created: false,
+    };
+
+    future::loop_fn(initial, move |mut state| {
+        state.attempts += 1;
+        let client = client.clone();
+        let config = config.clone();
+        let request = PutLogEventsRequest {
+            log_events: batch.clone(),
+            log_group_name: config.log_group_name.clone(),
+            log_stream_name: config.log_stream_name.clone(),
+            sequence_token: state.token.clone(),
+        };
+
+        client.put_log_events(request).then(move |result| {
+            let last_attempt = state.attempts >= config.max_attempts;
+            let next: PutAttempt = match result {
+                Ok(response) => Box::new(future::ok(Loop::Break((
+                    response.next_sequence_token,
+                    response.rejected_log_events_info.is_some(),
+                )))),
+                // an earlier attempt went through after all
+                Err(RusotoError::Service(PutLogEventsError::DataAlreadyAccepted(message))) => {
+                    let token = expected_sequence_token(&message);
+                    Box::new(future::ok(Loop::Break((token, false))))
+                }
+                Err(RusotoError::Service(PutLogEventsError::InvalidSequenceToken(ref message)))
+                    if !last_attempt =>
+                {
+                    match expected_sequence_token(message) {
+                        Some(token) => {
+                            state.token = Some(token);
+                            Box::new(future::ok(Loop::Continue(state)))
+                        }
+                        None => Box::new(sequence_token(client, &config).map(move |token| {
+                            state.token = token;
+                            Loop::Continue(state)
+                        })),
+                    }
+                }
+                Err(RusotoError::Service(PutLogEventsError::ResourceNotFound(_)))
+                    if config.create_log_stream && !state.created && !last_attempt =>
+                {
+                    state.created = true;
+                    state.token = None;
+                    Box::new(create_log_stream(client, &config).map(|_| Loop::Continue(state)))
+                }
+                Err(ref err) if is_throttled(err) && !last_attempt => {
+                    let delay = backoff(config.base_delay, state.attempts);
+                    Box::new(sleep(delay).map(|_| Loop::Continue(state)))
+                }
+                Err(err) => Box::new(future::err(err.map_service(LogShipperError::PutLogEvents))),
+            };
+            next
+        })
+    })
+}
+
+/// Looks up the sequence token of the log stream.
+fn sequence_token<C>(
+    client: C,
+    config: &LogShipperConfig,
+) -> impl Future<Item = Option<String>, Error = RusotoError<LogShipperError>> + Send
+where
+    C: CloudWatchLogs,
+{
+    let stream_name = config.log_stream_name.clone();
+    let request = DescribeLogStreamsRequest {
+        log_group_name: config.log_group_name.clone(),
+        log_stream_name_prefix: Some(stream_name.clone()),
+        ..Default::default()
+    };
+
+    client
+        .describe_log_streams(request)
+        .map_err(|e| e.map_service(LogShipperError::DescribeLogStreams))
+        .map(move |response| {
+            response
+                .log_streams
+                .unwrap_or_default()
+                .into_iter()
+                .find(|stream| stream.log_stream_name.as_ref() == Some(&stream_name))
+                .and_then(|stream| stream.upload_sequence_token)
+        })
+}
+
+/// Creates the log stream, succeeding if it already exists.
+fn create_log_stream<C>(
+    client: C,
+    config: &LogShipperConfig,
+) -> impl Future<Item = (), Error = RusotoError<LogShipperError>> + Send
+where
+    C: CloudWatchLogs,
+{
+    let request = CreateLogStreamRequest {
+        log_group_name: config.log_group_name.clone(),
+        log_stream_name: config.log_stream_name.clone(),
+    };
+
+    client
+        .create_log_stream(request)
+        .then(|result| match result {
+            Ok(()) | Err(RusotoError::Service(CreateLogStreamError::ResourceAlreadyExists(_))) => {
+                Ok(())
+            }
+            Err(err) => Err(err.map_service(LogShipperError::CreateLogStream)),
+        })
+}
+
+/// Extracts the token from messages like "The given sequenceToken is invalid. The next expected
+/// sequenceToken is: 4960...". A token of `null` means the stream expects no token.
+fn expected_sequence_token(message: &str) -> Option<String> {
+    let index = message.rfind("sequenceToken is: ")?;
+    let token = message[index + "sequenceToken is: ".len()..].trim();
+    if token.is_empty() || token == "null" {
+        None
+    } else {
+        Some(token.to_owned())
+    }
+}
+
+fn is_throttled(err: &RusotoError<PutLogEventsError>) -> bool {
+    match *err {
+        RusotoError::Service(PutLogEventsError::ServiceUnavailable(_)) => true,
+        ref err => is_transient(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_expected_sequence_tokens() {
+        assert_eq!(
+            expected_sequence_token(
+                "The given sequenceToken is invalid. The next expected sequenceToken is: 49590"
+            ),
+            Some("49590".to_owned())
+        );
+        assert_eq!(
+            expected_sequence_token(
+                "The given sequenceToken is invalid. The next expected sequenceToken is: null"
+            ),
+            None
+        );
+        assert_eq!(expected_sequence_token("Rate exceeded"), None);
+    }
+
+    #[test]
+    fn backs_off_exponentially() {
+        let base = Duration::from_millis(200);
+        assert_eq!(backoff(base, 1), Duration::from_millis(200));
+        assert_eq!(backoff(base, 3), Duration::from_millis(800));
+        assert_eq!(backoff(base, 40), Duration::from_secs(30));
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateKmsKeyRequest {
@@ -33,6 +34,46 @@ pub struct AssociateKmsKeyRequest {
     pub log_group_name: String,
 }
 
+impl AssociateKmsKeyRequest {
+    /// Returns a builder for `AssociateKmsKeyRequest`, checking its required members when built.
+    pub fn builder() -> AssociateKmsKeyRequestBuilder {
+        AssociateKmsKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateKmsKeyRequest`](struct.AssociateKmsKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateKmsKeyRequestBuilder {
+    kms_key_id: Option<String>,
+    log_group_name: Option<String>,
+}
+
+impl AssociateKmsKeyRequestBuilder {
+    /// Sets the [`kms_key_id`](struct.AssociateKmsKeyRequest.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.AssociateKmsKeyRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateKmsKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AssociateKmsKeyRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateKmsKeyRequest {
+            kms_key_id: self
+                .kms_key_id
+                .ok_or(::rusoto_core::BuildError::missing("kms_key_id"))?,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CancelExportTaskRequest {
     /// <p>The ID of the export task.</p>
@@ -40,6 +81,38 @@ pub struct CancelExportTaskRequest {
     pub task_id: String,
 }
 
+impl CancelExportTaskRequest {
+    /// Returns a builder for `CancelExportTaskRequest`, checking its required members when built.
+    pub fn builder() -> CancelExportTaskRequestBuilder {
+        CancelExportTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelExportTaskRequest`](struct.CancelExportTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelExportTaskRequestBuilder {
+    task_id: Option<String>,
+}
+
+impl CancelExportTaskRequestBuilder {
+    /// Sets the [`task_id`](struct.CancelExportTaskRequest.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelExportTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CancelExportTaskRequest, ::rusoto_core::BuildError> {
+        Ok(CancelExportTaskRequest {
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateExportTaskRequest {
     /// <p>The name of S3 bucket for the exported log data. The bucket must be in the same AWS region.</p>
@@ -68,6 +141,90 @@ pub struct CreateExportTaskRequest {
     pub to: i64,
 }
 
+impl CreateExportTaskRequest {
+    /// Returns a builder for `CreateExportTaskRequest`, checking its required members when built.
+    pub fn builder() -> CreateExportTaskRequestBuilder {
+        CreateExportTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateExportTaskRequest`](struct.CreateExportTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateExportTaskRequestBuilder {
+    destination: Option<String>,
+    destination_prefix: Option<String>,
+    from: Option<i64>,
+    log_group_name: Option<String>,
+    log_stream_name_prefix: Option<String>,
+    task_name: Option<String>,
+    to: Option<i64>,
+}
+
+impl CreateExportTaskRequestBuilder {
+    /// Sets the [`destination`](struct.CreateExportTaskRequest.html#structfield.destination) member.
+    pub fn destination<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_prefix`](struct.CreateExportTaskRequest.html#structfield.destination_prefix) member.
+    pub fn destination_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`from`](struct.CreateExportTaskRequest.html#structfield.from) member.
+    pub fn from<T: Into<i64>>(mut self, value: T) -> Self {
+        self.from = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.CreateExportTaskRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_stream_name_prefix`](struct.CreateExportTaskRequest.html#structfield.log_stream_name_prefix) member.
+    pub fn log_stream_name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_stream_name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_name`](struct.CreateExportTaskRequest.html#structfield.task_name) member.
+    pub fn task_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`to`](struct.CreateExportTaskRequest.html#structfield.to) member.
+    pub fn to<T: Into<i64>>(mut self, value: T) -> Self {
+        self.to = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateExportTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateExportTaskRequest, ::rusoto_core::BuildError> {
+        Ok(CreateExportTaskRequest {
+            destination: self
+                .destination
+                .ok_or(::rusoto_core::BuildError::missing("destination"))?,
+            destination_prefix: self.destination_prefix,
+            from: self
+                .from
+                .ok_or(::rusoto_core::BuildError::missing("from"))?,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            log_stream_name_prefix: self.log_stream_name_prefix,
+            task_name: self.task_name,
+            to: self.to.ok_or(::rusoto_core::BuildError::missing("to"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateExportTaskResponse {
@@ -92,6 +249,52 @@ pub struct CreateLogGroupRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateLogGroupRequest {
+    /// Returns a builder for `CreateLogGroupRequest`, checking its required members when built.
+    pub fn builder() -> CreateLogGroupRequestBuilder {
+        CreateLogGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLogGroupRequest`](struct.CreateLogGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLogGroupRequestBuilder {
+    kms_key_id: Option<String>,
+    log_group_name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateLogGroupRequestBuilder {
+    /// Sets the [`kms_key_id`](struct.CreateLogGroupRequest.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.CreateLogGroupRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateLogGroupRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLogGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateLogGroupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateLogGroupRequest {
+            kms_key_id: self.kms_key_id,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateLogStreamRequest {
     /// <p>The name of the log group.</p>
@@ -102,6 +305,46 @@ pub struct CreateLogStreamRequest {
     pub log_stream_name: String,
 }
 
+impl CreateLogStreamRequest {
+    /// Returns a builder for `CreateLogStreamRequest`, checking its required members when built.
+    pub fn builder() -> CreateLogStreamRequestBuilder {
+        CreateLogStreamRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLogStreamRequest`](struct.CreateLogStreamRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLogStreamRequestBuilder {
+    log_group_name: Option<String>,
+    log_stream_name: Option<String>,
+}
+
+impl CreateLogStreamRequestBuilder {
+    /// Sets the [`log_group_name`](struct.CreateLogStreamRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_stream_name`](struct.CreateLogStreamRequest.html#structfield.log_stream_name) member.
+    pub fn log_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLogStreamRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateLogStreamRequest, ::rusoto_core::BuildError> {
+        Ok(CreateLogStreamRequest {
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            log_stream_name: self
+                .log_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("log_stream_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteDestinationRequest {
     /// <p>The name of the destination.</p>
@@ -109,6 +352,38 @@ pub struct DeleteDestinationRequest {
     pub destination_name: String,
 }
 
+impl DeleteDestinationRequest {
+    /// Returns a builder for `DeleteDestinationRequest`, checking its required members when built.
+    pub fn builder() -> DeleteDestinationRequestBuilder {
+        DeleteDestinationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDestinationRequest`](struct.DeleteDestinationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteDestinationRequestBuilder {
+    destination_name: Option<String>,
+}
+
+impl DeleteDestinationRequestBuilder {
+    /// Sets the [`destination_name`](struct.DeleteDestinationRequest.html#structfield.destination_name) member.
+    pub fn destination_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDestinationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDestinationRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteDestinationRequest {
+            destination_name: self
+                .destination_name
+                .ok_or(::rusoto_core::BuildError::missing("destination_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteLogGroupRequest {
     /// <p>The name of the log group.</p>
@@ -116,6 +391,36 @@ pub struct DeleteLogGroupRequest {
     pub log_group_name: String,
 }
 
+impl DeleteLogGroupRequest {
+    /// Returns a builder for `DeleteLogGroupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLogGroupRequestBuilder {
+        DeleteLogGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLogGroupRequest`](struct.DeleteLogGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLogGroupRequestBuilder {
+    log_group_name: Option<String>,
+}
+
+impl DeleteLogGroupRequestBuilder {
+    /// Sets the [`log_group_name`](struct.DeleteLogGroupRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLogGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteLogGroupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLogGroupRequest {
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteLogStreamRequest {
     /// <p>The name of the log group.</p>
@@ -126,6 +431,46 @@ pub struct DeleteLogStreamRequest {
     pub log_stream_name: String,
 }
 
+impl DeleteLogStreamRequest {
+    /// Returns a builder for `DeleteLogStreamRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLogStreamRequestBuilder {
+        DeleteLogStreamRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLogStreamRequest`](struct.DeleteLogStreamRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLogStreamRequestBuilder {
+    log_group_name: Option<String>,
+    log_stream_name: Option<String>,
+}
+
+impl DeleteLogStreamRequestBuilder {
+    /// Sets the [`log_group_name`](struct.DeleteLogStreamRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_stream_name`](struct.DeleteLogStreamRequest.html#structfield.log_stream_name) member.
+    pub fn log_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLogStreamRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteLogStreamRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLogStreamRequest {
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            log_stream_name: self
+                .log_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("log_stream_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteMetricFilterRequest {
     /// <p>The name of the metric filter.</p>
@@ -136,6 +481,48 @@ pub struct DeleteMetricFilterRequest {
     pub log_group_name: String,
 }
 
+impl DeleteMetricFilterRequest {
+    /// Returns a builder for `DeleteMetricFilterRequest`, checking its required members when built.
+    pub fn builder() -> DeleteMetricFilterRequestBuilder {
+        DeleteMetricFilterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMetricFilterRequest`](struct.DeleteMetricFilterRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteMetricFilterRequestBuilder {
+    filter_name: Option<String>,
+    log_group_name: Option<String>,
+}
+
+impl DeleteMetricFilterRequestBuilder {
+    /// Sets the [`filter_name`](struct.DeleteMetricFilterRequest.html#structfield.filter_name) member.
+    pub fn filter_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.DeleteMetricFilterRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMetricFilterRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteMetricFilterRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteMetricFilterRequest {
+            filter_name: self
+                .filter_name
+                .ok_or(::rusoto_core::BuildError::missing("filter_name"))?,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteResourcePolicyRequest {
     /// <p>The name of the policy to be revoked. This parameter is required.</p>
@@ -144,6 +531,36 @@ pub struct DeleteResourcePolicyRequest {
     pub policy_name: Option<String>,
 }
 
+impl DeleteResourcePolicyRequest {
+    /// Returns a builder for `DeleteResourcePolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteResourcePolicyRequestBuilder {
+        DeleteResourcePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteResourcePolicyRequest`](struct.DeleteResourcePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteResourcePolicyRequestBuilder {
+    policy_name: Option<String>,
+}
+
+impl DeleteResourcePolicyRequestBuilder {
+    /// Sets the [`policy_name`](struct.DeleteResourcePolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteResourcePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteResourcePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteResourcePolicyRequest {
+            policy_name: self.policy_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteRetentionPolicyRequest {
     /// <p>The name of the log group.</p>
@@ -151,6 +568,38 @@ pub struct DeleteRetentionPolicyRequest {
     pub log_group_name: String,
 }
 
+impl DeleteRetentionPolicyRequest {
+    /// Returns a builder for `DeleteRetentionPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeleteRetentionPolicyRequestBuilder {
+        DeleteRetentionPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRetentionPolicyRequest`](struct.DeleteRetentionPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteRetentionPolicyRequestBuilder {
+    log_group_name: Option<String>,
+}
+
+impl DeleteRetentionPolicyRequestBuilder {
+    /// Sets the [`log_group_name`](struct.DeleteRetentionPolicyRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRetentionPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRetentionPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteRetentionPolicyRequest {
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteSubscriptionFilterRequest {
     /// <p>The name of the subscription filter.</p>
@@ -161,6 +610,48 @@ pub struct DeleteSubscriptionFilterRequest {
     pub log_group_name: String,
 }
 
+impl DeleteSubscriptionFilterRequest {
+    /// Returns a builder for `DeleteSubscriptionFilterRequest`, checking its required members when built.
+    pub fn builder() -> DeleteSubscriptionFilterRequestBuilder {
+        DeleteSubscriptionFilterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSubscriptionFilterRequest`](struct.DeleteSubscriptionFilterRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteSubscriptionFilterRequestBuilder {
+    filter_name: Option<String>,
+    log_group_name: Option<String>,
+}
+
+impl DeleteSubscriptionFilterRequestBuilder {
+    /// Sets the [`filter_name`](struct.DeleteSubscriptionFilterRequest.html#structfield.filter_name) member.
+    pub fn filter_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.DeleteSubscriptionFilterRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSubscriptionFilterRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSubscriptionFilterRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteSubscriptionFilterRequest {
+            filter_name: self
+                .filter_name
+                .ok_or(::rusoto_core::BuildError::missing("filter_name"))?,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeDestinationsRequest {
     /// <p>The prefix to match. If you don't specify a value, no prefix filter is applied.</p>
@@ -177,6 +668,52 @@ pub struct DescribeDestinationsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeDestinationsRequest {
+    /// Returns a builder for `DescribeDestinationsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDestinationsRequestBuilder {
+        DescribeDestinationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDestinationsRequest`](struct.DescribeDestinationsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDestinationsRequestBuilder {
+    destination_name_prefix: Option<String>,
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeDestinationsRequestBuilder {
+    /// Sets the [`destination_name_prefix`](struct.DescribeDestinationsRequest.html#structfield.destination_name_prefix) member.
+    pub fn destination_name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeDestinationsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeDestinationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDestinationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDestinationsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDestinationsRequest {
+            destination_name_prefix: self.destination_name_prefix,
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeDestinationsResponse {
@@ -202,13 +739,67 @@ pub struct DescribeExportTasksRequest {
     /// <p>The status code of the export task. Specifying a status code filters the results to zero or more export tasks.</p>
     #[serde(rename = "statusCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_code: Option<String>,
+    pub status_code: Option<ExportTaskStatusCode>,
     /// <p>The ID of the export task. Specifying a task ID filters the results to zero or one export tasks.</p>
     #[serde(rename = "taskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub task_id: Option<String>,
 }
 
+impl DescribeExportTasksRequest {
+    /// Returns a builder for `DescribeExportTasksRequest`, checking its required members when built.
+    pub fn builder() -> DescribeExportTasksRequestBuilder {
+        DescribeExportTasksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeExportTasksRequest`](struct.DescribeExportTasksRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeExportTasksRequestBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+    status_code: Option<ExportTaskStatusCode>,
+    task_id: Option<String>,
+}
+
+impl DescribeExportTasksRequestBuilder {
+    /// Sets the [`limit`](struct.DescribeExportTasksRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeExportTasksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status_code`](struct.DescribeExportTasksRequest.html#structfield.status_code) member.
+    pub fn status_code<T: Into<ExportTaskStatusCode>>(mut self, value: T) -> Self {
+        self.status_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task_id`](struct.DescribeExportTasksRequest.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeExportTasksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeExportTasksRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeExportTasksRequest {
+            limit: self.limit,
+            next_token: self.next_token,
+            status_code: self.status_code,
+            task_id: self.task_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeExportTasksResponse {
@@ -237,6 +828,52 @@ pub struct DescribeLogGroupsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeLogGroupsRequest {
+    /// Returns a builder for `DescribeLogGroupsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeLogGroupsRequestBuilder {
+        DescribeLogGroupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLogGroupsRequest`](struct.DescribeLogGroupsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeLogGroupsRequestBuilder {
+    limit: Option<i64>,
+    log_group_name_prefix: Option<String>,
+    next_token: Option<String>,
+}
+
+impl DescribeLogGroupsRequestBuilder {
+    /// Sets the [`limit`](struct.DescribeLogGroupsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name_prefix`](struct.DescribeLogGroupsRequest.html#structfield.log_group_name_prefix) member.
+    pub fn log_group_name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeLogGroupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLogGroupsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLogGroupsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeLogGroupsRequest {
+            limit: self.limit,
+            log_group_name_prefix: self.log_group_name_prefix,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeLogGroupsResponse {
@@ -273,7 +910,79 @@ pub struct DescribeLogStreamsRequest {
     /// <p>If the value is <code>LogStreamName</code>, the results are ordered by log stream name. If the value is <code>LastEventTime</code>, the results are ordered by the event time. The default value is <code>LogStreamName</code>.</p> <p>If you order the results by event time, you cannot specify the <code>logStreamNamePrefix</code> parameter.</p> <p>lastEventTimestamp represents the time of the most recent log event in the log stream in CloudWatch Logs. This number is expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC. lastEventTimeStamp updates on an eventual consistency basis. It typically updates in less than an hour from ingestion, but may take longer in some rare situations.</p>
     #[serde(rename = "orderBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub order_by: Option<String>,
+    pub order_by: Option<OrderBy>,
+}
+
+impl DescribeLogStreamsRequest {
+    /// Returns a builder for `DescribeLogStreamsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeLogStreamsRequestBuilder {
+        DescribeLogStreamsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLogStreamsRequest`](struct.DescribeLogStreamsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeLogStreamsRequestBuilder {
+    descending: Option<bool>,
+    limit: Option<i64>,
+    log_group_name: Option<String>,
+    log_stream_name_prefix: Option<String>,
+    next_token: Option<String>,
+    order_by: Option<OrderBy>,
+}
+
+impl DescribeLogStreamsRequestBuilder {
+    /// Sets the [`descending`](struct.DescribeLogStreamsRequest.html#structfield.descending) member.
+    pub fn descending<T: Into<bool>>(mut self, value: T) -> Self {
+        self.descending = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeLogStreamsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.DescribeLogStreamsRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_stream_name_prefix`](struct.DescribeLogStreamsRequest.html#structfield.log_stream_name_prefix) member.
+    pub fn log_stream_name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_stream_name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeLogStreamsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`order_by`](struct.DescribeLogStreamsRequest.html#structfield.order_by) member.
+    pub fn order_by<T: Into<OrderBy>>(mut self, value: T) -> Self {
+        self.order_by = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLogStreamsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLogStreamsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeLogStreamsRequest {
+            descending: self.descending,
+            limit: self.limit,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            log_stream_name_prefix: self.log_stream_name_prefix,
+            next_token: self.next_token,
+            order_by: self.order_by,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -316,6 +1025,76 @@ pub struct DescribeMetricFiltersRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeMetricFiltersRequest {
+    /// Returns a builder for `DescribeMetricFiltersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeMetricFiltersRequestBuilder {
+        DescribeMetricFiltersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeMetricFiltersRequest`](struct.DescribeMetricFiltersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeMetricFiltersRequestBuilder {
+    filter_name_prefix: Option<String>,
+    limit: Option<i64>,
+    log_group_name: Option<String>,
+    metric_name: Option<String>,
+    metric_namespace: Option<String>,
+    next_token: Option<String>,
+}
+
+impl DescribeMetricFiltersRequestBuilder {
+    /// Sets the [`filter_name_prefix`](struct.DescribeMetricFiltersRequest.html#structfield.filter_name_prefix) member.
+    pub fn filter_name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeMetricFiltersRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.DescribeMetricFiltersRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_name`](struct.DescribeMetricFiltersRequest.html#structfield.metric_name) member.
+    pub fn metric_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_namespace`](struct.DescribeMetricFiltersRequest.html#structfield.metric_namespace) member.
+    pub fn metric_namespace<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric_namespace = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeMetricFiltersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeMetricFiltersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeMetricFiltersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeMetricFiltersRequest {
+            filter_name_prefix: self.filter_name_prefix,
+            limit: self.limit,
+            log_group_name: self.log_group_name,
+            metric_name: self.metric_name,
+            metric_namespace: self.metric_namespace,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeMetricFiltersResponse {
@@ -344,7 +1123,59 @@ pub struct DescribeQueriesRequest {
     /// <p>Limits the returned queries to only those that have the specified status. Valid values are <code>Cancelled</code>, <code>Complete</code>, <code>Failed</code>, <code>Running</code>, and <code>Scheduled</code>.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<QueryStatus>,
+}
+
+impl DescribeQueriesRequest {
+    /// Returns a builder for `DescribeQueriesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeQueriesRequestBuilder {
+        DescribeQueriesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeQueriesRequest`](struct.DescribeQueriesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeQueriesRequestBuilder {
+    log_group_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    status: Option<QueryStatus>,
+}
+
+impl DescribeQueriesRequestBuilder {
+    /// Sets the [`log_group_name`](struct.DescribeQueriesRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeQueriesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeQueriesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.DescribeQueriesRequest.html#structfield.status) member.
+    pub fn status<T: Into<QueryStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeQueriesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeQueriesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeQueriesRequest {
+            log_group_name: self.log_group_name,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            status: self.status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -370,6 +1201,44 @@ pub struct DescribeResourcePoliciesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeResourcePoliciesRequest {
+    /// Returns a builder for `DescribeResourcePoliciesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeResourcePoliciesRequestBuilder {
+        DescribeResourcePoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeResourcePoliciesRequest`](struct.DescribeResourcePoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeResourcePoliciesRequestBuilder {
+    limit: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeResourcePoliciesRequestBuilder {
+    /// Sets the [`limit`](struct.DescribeResourcePoliciesRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeResourcePoliciesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeResourcePoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeResourcePoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeResourcePoliciesRequest {
+            limit: self.limit,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeResourcePoliciesResponse {
@@ -401,6 +1270,62 @@ pub struct DescribeSubscriptionFiltersRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeSubscriptionFiltersRequest {
+    /// Returns a builder for `DescribeSubscriptionFiltersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeSubscriptionFiltersRequestBuilder {
+        DescribeSubscriptionFiltersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSubscriptionFiltersRequest`](struct.DescribeSubscriptionFiltersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeSubscriptionFiltersRequestBuilder {
+    filter_name_prefix: Option<String>,
+    limit: Option<i64>,
+    log_group_name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl DescribeSubscriptionFiltersRequestBuilder {
+    /// Sets the [`filter_name_prefix`](struct.DescribeSubscriptionFiltersRequest.html#structfield.filter_name_prefix) member.
+    pub fn filter_name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeSubscriptionFiltersRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.DescribeSubscriptionFiltersRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeSubscriptionFiltersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSubscriptionFiltersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSubscriptionFiltersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeSubscriptionFiltersRequest {
+            filter_name_prefix: self.filter_name_prefix,
+            limit: self.limit,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeSubscriptionFiltersResponse {
@@ -450,6 +1375,110 @@ pub struct DisassociateKmsKeyRequest {
     pub log_group_name: String,
 }
 
+impl DisassociateKmsKeyRequest {
+    /// Returns a builder for `DisassociateKmsKeyRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateKmsKeyRequestBuilder {
+        DisassociateKmsKeyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateKmsKeyRequest`](struct.DisassociateKmsKeyRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateKmsKeyRequestBuilder {
+    log_group_name: Option<String>,
+}
+
+impl DisassociateKmsKeyRequestBuilder {
+    /// Sets the [`log_group_name`](struct.DisassociateKmsKeyRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateKmsKeyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateKmsKeyRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateKmsKeyRequest {
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+        })
+    }
+}
+
+/// <p>The method used to distribute log data to the destination, which can be either random or grouped by log stream.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Distribution {
+    /// <p><code>Random</code></p>
+    Random,
+    /// <p><code>ByLogStream</code></p>
+    ByLogStream,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Distribution {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Distribution::Random => "Random",
+            Distribution::ByLogStream => "ByLogStream",
+            Distribution::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Distribution {
+    fn from(value: &'a str) -> Distribution {
+        match value {
+            "Random" => Distribution::Random,
+            "ByLogStream" => Distribution::ByLogStream,
+            _ => Distribution::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Distribution {
+    fn from(value: String) -> Distribution {
+        Distribution::from(value.as_str())
+    }
+}
+
+impl From<Distribution> for String {
+    fn from(value: Distribution) -> String {
+        match value {
+            Distribution::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Distribution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Distribution {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Distribution {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Distribution, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Distribution::from(value))
+    }
+}
+
 /// <p>Represents an export task.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -513,13 +1542,101 @@ pub struct ExportTaskStatus {
     /// <p>The status code of the export task.</p>
     #[serde(rename = "code")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<String>,
+    pub code: Option<ExportTaskStatusCode>,
     /// <p>The status message related to the status code.</p>
     #[serde(rename = "message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+/// <p>The status code of the export task. Specifying a status code filters the results to zero or more export tasks.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExportTaskStatusCode {
+    /// <p><code>CANCELLED</code></p>
+    Cancelled,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>PENDING_CANCEL</code></p>
+    PendingCancel,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExportTaskStatusCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportTaskStatusCode::Cancelled => "CANCELLED",
+            ExportTaskStatusCode::Completed => "COMPLETED",
+            ExportTaskStatusCode::Failed => "FAILED",
+            ExportTaskStatusCode::Pending => "PENDING",
+            ExportTaskStatusCode::PendingCancel => "PENDING_CANCEL",
+            ExportTaskStatusCode::Running => "RUNNING",
+            ExportTaskStatusCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExportTaskStatusCode {
+    fn from(value: &'a str) -> ExportTaskStatusCode {
+        match value {
+            "CANCELLED" => ExportTaskStatusCode::Cancelled,
+            "COMPLETED" => ExportTaskStatusCode::Completed,
+            "FAILED" => ExportTaskStatusCode::Failed,
+            "PENDING" => ExportTaskStatusCode::Pending,
+            "PENDING_CANCEL" => ExportTaskStatusCode::PendingCancel,
+            "RUNNING" => ExportTaskStatusCode::Running,
+            _ => ExportTaskStatusCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportTaskStatusCode {
+    fn from(value: String) -> ExportTaskStatusCode {
+        ExportTaskStatusCode::from(value.as_str())
+    }
+}
+
+impl From<ExportTaskStatusCode> for String {
+    fn from(value: ExportTaskStatusCode) -> String {
+        match value {
+            ExportTaskStatusCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExportTaskStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ExportTaskStatusCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportTaskStatusCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExportTaskStatusCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExportTaskStatusCode::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct FilterLogEventsRequest {
     /// <p>The end of the time range, expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC. Events with a timestamp later than this time are not returned.</p>
@@ -555,6 +1672,92 @@ pub struct FilterLogEventsRequest {
     pub start_time: Option<i64>,
 }
 
+impl FilterLogEventsRequest {
+    /// Returns a builder for `FilterLogEventsRequest`, checking its required members when built.
+    pub fn builder() -> FilterLogEventsRequestBuilder {
+        FilterLogEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`FilterLogEventsRequest`](struct.FilterLogEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct FilterLogEventsRequestBuilder {
+    end_time: Option<i64>,
+    filter_pattern: Option<String>,
+    limit: Option<i64>,
+    log_group_name: Option<String>,
+    log_stream_name_prefix: Option<String>,
+    log_stream_names: Option<Vec<String>>,
+    next_token: Option<String>,
+    start_time: Option<i64>,
+}
+
+impl FilterLogEventsRequestBuilder {
+    /// Sets the [`end_time`](struct.FilterLogEventsRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<i64>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_pattern`](struct.FilterLogEventsRequest.html#structfield.filter_pattern) member.
+    pub fn filter_pattern<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_pattern = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.FilterLogEventsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.FilterLogEventsRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_stream_name_prefix`](struct.FilterLogEventsRequest.html#structfield.log_stream_name_prefix) member.
+    pub fn log_stream_name_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_stream_name_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_stream_names`](struct.FilterLogEventsRequest.html#structfield.log_stream_names) member.
+    pub fn log_stream_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.log_stream_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.FilterLogEventsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.FilterLogEventsRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<i64>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `FilterLogEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<FilterLogEventsRequest, ::rusoto_core::BuildError> {
+        Ok(FilterLogEventsRequest {
+            end_time: self.end_time,
+            filter_pattern: self.filter_pattern,
+            limit: self.limit,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            log_stream_name_prefix: self.log_stream_name_prefix,
+            log_stream_names: self.log_stream_names,
+            next_token: self.next_token,
+            start_time: self.start_time,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct FilterLogEventsResponse {
@@ -628,6 +1831,86 @@ pub struct GetLogEventsRequest {
     pub start_time: Option<i64>,
 }
 
+impl GetLogEventsRequest {
+    /// Returns a builder for `GetLogEventsRequest`, checking its required members when built.
+    pub fn builder() -> GetLogEventsRequestBuilder {
+        GetLogEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLogEventsRequest`](struct.GetLogEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLogEventsRequestBuilder {
+    end_time: Option<i64>,
+    limit: Option<i64>,
+    log_group_name: Option<String>,
+    log_stream_name: Option<String>,
+    next_token: Option<String>,
+    start_from_head: Option<bool>,
+    start_time: Option<i64>,
+}
+
+impl GetLogEventsRequestBuilder {
+    /// Sets the [`end_time`](struct.GetLogEventsRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<i64>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.GetLogEventsRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.GetLogEventsRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_stream_name`](struct.GetLogEventsRequest.html#structfield.log_stream_name) member.
+    pub fn log_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetLogEventsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_from_head`](struct.GetLogEventsRequest.html#structfield.start_from_head) member.
+    pub fn start_from_head<T: Into<bool>>(mut self, value: T) -> Self {
+        self.start_from_head = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetLogEventsRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<i64>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLogEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetLogEventsRequest, ::rusoto_core::BuildError> {
+        Ok(GetLogEventsRequest {
+            end_time: self.end_time,
+            limit: self.limit,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            log_stream_name: self
+                .log_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("log_stream_name"))?,
+            next_token: self.next_token,
+            start_from_head: self.start_from_head,
+            start_time: self.start_time,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLogEventsResponse {
@@ -656,6 +1939,46 @@ pub struct GetLogGroupFieldsRequest {
     pub time: Option<i64>,
 }
 
+impl GetLogGroupFieldsRequest {
+    /// Returns a builder for `GetLogGroupFieldsRequest`, checking its required members when built.
+    pub fn builder() -> GetLogGroupFieldsRequestBuilder {
+        GetLogGroupFieldsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLogGroupFieldsRequest`](struct.GetLogGroupFieldsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLogGroupFieldsRequestBuilder {
+    log_group_name: Option<String>,
+    time: Option<i64>,
+}
+
+impl GetLogGroupFieldsRequestBuilder {
+    /// Sets the [`log_group_name`](struct.GetLogGroupFieldsRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time`](struct.GetLogGroupFieldsRequest.html#structfield.time) member.
+    pub fn time<T: Into<i64>>(mut self, value: T) -> Self {
+        self.time = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLogGroupFieldsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLogGroupFieldsRequest, ::rusoto_core::BuildError> {
+        Ok(GetLogGroupFieldsRequest {
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            time: self.time,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLogGroupFieldsResponse {
@@ -672,6 +1995,36 @@ pub struct GetLogRecordRequest {
     pub log_record_pointer: String,
 }
 
+impl GetLogRecordRequest {
+    /// Returns a builder for `GetLogRecordRequest`, checking its required members when built.
+    pub fn builder() -> GetLogRecordRequestBuilder {
+        GetLogRecordRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLogRecordRequest`](struct.GetLogRecordRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLogRecordRequestBuilder {
+    log_record_pointer: Option<String>,
+}
+
+impl GetLogRecordRequestBuilder {
+    /// Sets the [`log_record_pointer`](struct.GetLogRecordRequest.html#structfield.log_record_pointer) member.
+    pub fn log_record_pointer<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_record_pointer = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLogRecordRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetLogRecordRequest, ::rusoto_core::BuildError> {
+        Ok(GetLogRecordRequest {
+            log_record_pointer: self
+                .log_record_pointer
+                .ok_or(::rusoto_core::BuildError::missing("log_record_pointer"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLogRecordResponse {
@@ -688,6 +2041,36 @@ pub struct GetQueryResultsRequest {
     pub query_id: String,
 }
 
+impl GetQueryResultsRequest {
+    /// Returns a builder for `GetQueryResultsRequest`, checking its required members when built.
+    pub fn builder() -> GetQueryResultsRequestBuilder {
+        GetQueryResultsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetQueryResultsRequest`](struct.GetQueryResultsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetQueryResultsRequestBuilder {
+    query_id: Option<String>,
+}
+
+impl GetQueryResultsRequestBuilder {
+    /// Sets the [`query_id`](struct.GetQueryResultsRequest.html#structfield.query_id) member.
+    pub fn query_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetQueryResultsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetQueryResultsRequest, ::rusoto_core::BuildError> {
+        Ok(GetQueryResultsRequest {
+            query_id: self
+                .query_id
+                .ok_or(::rusoto_core::BuildError::missing("query_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetQueryResultsResponse {
@@ -702,7 +2085,7 @@ pub struct GetQueryResultsResponse {
     /// <p>The status of the most recent running of the query. Possible values are <code>Cancelled</code>, <code>Complete</code>, <code>Failed</code>, <code>Running</code>, <code>Scheduled</code>, <code>Timeout</code>, and <code>Unknown</code>.</p> <p>Queries time out after 15 minutes of execution. To avoid having your queries time out, reduce the time range being searched, or partition your query into a number of queries.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<QueryStatus>,
 }
 
 /// <p>Represents a log event, which is a record of activity that was recorded by the application or resource being monitored.</p>
@@ -723,6 +2106,38 @@ pub struct ListTagsLogGroupRequest {
     pub log_group_name: String,
 }
 
+impl ListTagsLogGroupRequest {
+    /// Returns a builder for `ListTagsLogGroupRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsLogGroupRequestBuilder {
+        ListTagsLogGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsLogGroupRequest`](struct.ListTagsLogGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsLogGroupRequestBuilder {
+    log_group_name: Option<String>,
+}
+
+impl ListTagsLogGroupRequestBuilder {
+    /// Sets the [`log_group_name`](struct.ListTagsLogGroupRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsLogGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsLogGroupRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsLogGroupRequest {
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsLogGroupResponse {
@@ -874,6 +2289,78 @@ pub struct MetricTransformation {
     pub metric_value: String,
 }
 
+/// <p>If the value is <code>LogStreamName</code>, the results are ordered by log stream name. If the value is <code>LastEventTime</code>, the results are ordered by the event time. The default value is <code>LogStreamName</code>.</p> <p>If you order the results by event time, you cannot specify the <code>logStreamNamePrefix</code> parameter.</p> <p>lastEventTimestamp represents the time of the most recent log event in the log stream in CloudWatch Logs. This number is expressed as the number of milliseconds after Jan 1, 1970 00:00:00 UTC. lastEventTimeStamp updates on an eventual consistency basis. It typically updates in less than an hour from ingestion, but may take longer in some rare situations.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrderBy {
+    /// <p><code>LogStreamName</code></p>
+    LogStreamName,
+    /// <p><code>LastEventTime</code></p>
+    LastEventTime,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OrderBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrderBy::LogStreamName => "LogStreamName",
+            OrderBy::LastEventTime => "LastEventTime",
+            OrderBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OrderBy {
+    fn from(value: &'a str) -> OrderBy {
+        match value {
+            "LogStreamName" => OrderBy::LogStreamName,
+            "LastEventTime" => OrderBy::LastEventTime,
+            _ => OrderBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrderBy {
+    fn from(value: String) -> OrderBy {
+        OrderBy::from(value.as_str())
+    }
+}
+
+impl From<OrderBy> for String {
+    fn from(value: OrderBy) -> String {
+        match value {
+            OrderBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OrderBy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrderBy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OrderBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrderBy::from(value))
+    }
+}
+
 /// <p>Represents a log event.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -902,6 +2389,48 @@ pub struct PutDestinationPolicyRequest {
     pub destination_name: String,
 }
 
+impl PutDestinationPolicyRequest {
+    /// Returns a builder for `PutDestinationPolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutDestinationPolicyRequestBuilder {
+        PutDestinationPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutDestinationPolicyRequest`](struct.PutDestinationPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutDestinationPolicyRequestBuilder {
+    access_policy: Option<String>,
+    destination_name: Option<String>,
+}
+
+impl PutDestinationPolicyRequestBuilder {
+    /// Sets the [`access_policy`](struct.PutDestinationPolicyRequest.html#structfield.access_policy) member.
+    pub fn access_policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.access_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_name`](struct.PutDestinationPolicyRequest.html#structfield.destination_name) member.
+    pub fn destination_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutDestinationPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutDestinationPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutDestinationPolicyRequest {
+            access_policy: self
+                .access_policy
+                .ok_or(::rusoto_core::BuildError::missing("access_policy"))?,
+            destination_name: self
+                .destination_name
+                .ok_or(::rusoto_core::BuildError::missing("destination_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutDestinationRequest {
     /// <p>A name for the destination.</p>
@@ -915,6 +2444,56 @@ pub struct PutDestinationRequest {
     pub target_arn: String,
 }
 
+impl PutDestinationRequest {
+    /// Returns a builder for `PutDestinationRequest`, checking its required members when built.
+    pub fn builder() -> PutDestinationRequestBuilder {
+        PutDestinationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutDestinationRequest`](struct.PutDestinationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutDestinationRequestBuilder {
+    destination_name: Option<String>,
+    role_arn: Option<String>,
+    target_arn: Option<String>,
+}
+
+impl PutDestinationRequestBuilder {
+    /// Sets the [`destination_name`](struct.PutDestinationRequest.html#structfield.destination_name) member.
+    pub fn destination_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.PutDestinationRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_arn`](struct.PutDestinationRequest.html#structfield.target_arn) member.
+    pub fn target_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutDestinationRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutDestinationRequest, ::rusoto_core::BuildError> {
+        Ok(PutDestinationRequest {
+            destination_name: self
+                .destination_name
+                .ok_or(::rusoto_core::BuildError::missing("destination_name"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            target_arn: self
+                .target_arn
+                .ok_or(::rusoto_core::BuildError::missing("target_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutDestinationResponse {
@@ -941,6 +2520,64 @@ pub struct PutLogEventsRequest {
     pub sequence_token: Option<String>,
 }
 
+impl PutLogEventsRequest {
+    /// Returns a builder for `PutLogEventsRequest`, checking its required members when built.
+    pub fn builder() -> PutLogEventsRequestBuilder {
+        PutLogEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutLogEventsRequest`](struct.PutLogEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct PutLogEventsRequestBuilder {
+    log_events: Option<Vec<InputLogEvent>>,
+    log_group_name: Option<String>,
+    log_stream_name: Option<String>,
+    sequence_token: Option<String>,
+}
+
+impl PutLogEventsRequestBuilder {
+    /// Sets the [`log_events`](struct.PutLogEventsRequest.html#structfield.log_events) member.
+    pub fn log_events<T: Into<Vec<InputLogEvent>>>(mut self, value: T) -> Self {
+        self.log_events = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.PutLogEventsRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_stream_name`](struct.PutLogEventsRequest.html#structfield.log_stream_name) member.
+    pub fn log_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_stream_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sequence_token`](struct.PutLogEventsRequest.html#structfield.sequence_token) member.
+    pub fn sequence_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.sequence_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutLogEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutLogEventsRequest, ::rusoto_core::BuildError> {
+        Ok(PutLogEventsRequest {
+            log_events: self
+                .log_events
+                .ok_or(::rusoto_core::BuildError::missing("log_events"))?,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            log_stream_name: self
+                .log_stream_name
+                .ok_or(::rusoto_core::BuildError::missing("log_stream_name"))?,
+            sequence_token: self.sequence_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutLogEventsResponse {
@@ -970,6 +2607,66 @@ pub struct PutMetricFilterRequest {
     pub metric_transformations: Vec<MetricTransformation>,
 }
 
+impl PutMetricFilterRequest {
+    /// Returns a builder for `PutMetricFilterRequest`, checking its required members when built.
+    pub fn builder() -> PutMetricFilterRequestBuilder {
+        PutMetricFilterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutMetricFilterRequest`](struct.PutMetricFilterRequest.html).
+#[derive(Default, Debug)]
+pub struct PutMetricFilterRequestBuilder {
+    filter_name: Option<String>,
+    filter_pattern: Option<String>,
+    log_group_name: Option<String>,
+    metric_transformations: Option<Vec<MetricTransformation>>,
+}
+
+impl PutMetricFilterRequestBuilder {
+    /// Sets the [`filter_name`](struct.PutMetricFilterRequest.html#structfield.filter_name) member.
+    pub fn filter_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_pattern`](struct.PutMetricFilterRequest.html#structfield.filter_pattern) member.
+    pub fn filter_pattern<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_pattern = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.PutMetricFilterRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_transformations`](struct.PutMetricFilterRequest.html#structfield.metric_transformations) member.
+    pub fn metric_transformations<T: Into<Vec<MetricTransformation>>>(mut self, value: T) -> Self {
+        self.metric_transformations = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutMetricFilterRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutMetricFilterRequest, ::rusoto_core::BuildError> {
+        Ok(PutMetricFilterRequest {
+            filter_name: self
+                .filter_name
+                .ok_or(::rusoto_core::BuildError::missing("filter_name"))?,
+            filter_pattern: self
+                .filter_pattern
+                .ok_or(::rusoto_core::BuildError::missing("filter_pattern"))?,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            metric_transformations: self
+                .metric_transformations
+                .ok_or(::rusoto_core::BuildError::missing("metric_transformations"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutResourcePolicyRequest {
     /// <p>Details of the new policy, including the identity of the principal that is enabled to put logs to this account. This is formatted as a JSON string. This parameter is required.</p> <p>The following example creates a resource policy enabling the Route 53 service to put DNS query logs in to the specified log group. Replace "logArn" with the ARN of your CloudWatch Logs resource, such as a log group or log stream.</p> <p> <code>{ "Version": "2012-10-17", "Statement": [ { "Sid": "Route53LogsToCloudWatchLogs", "Effect": "Allow", "Principal": { "Service": [ "route53.amazonaws.com" ] }, "Action":"logs:PutLogEvents", "Resource": "logArn" } ] } </code> </p>
@@ -982,6 +2679,44 @@ pub struct PutResourcePolicyRequest {
     pub policy_name: Option<String>,
 }
 
+impl PutResourcePolicyRequest {
+    /// Returns a builder for `PutResourcePolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutResourcePolicyRequestBuilder {
+        PutResourcePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutResourcePolicyRequest`](struct.PutResourcePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutResourcePolicyRequestBuilder {
+    policy_document: Option<String>,
+    policy_name: Option<String>,
+}
+
+impl PutResourcePolicyRequestBuilder {
+    /// Sets the [`policy_document`](struct.PutResourcePolicyRequest.html#structfield.policy_document) member.
+    pub fn policy_document<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_document = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_name`](struct.PutResourcePolicyRequest.html#structfield.policy_name) member.
+    pub fn policy_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutResourcePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutResourcePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutResourcePolicyRequest {
+            policy_document: self.policy_document,
+            policy_name: self.policy_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutResourcePolicyResponse {
@@ -1000,6 +2735,48 @@ pub struct PutRetentionPolicyRequest {
     pub retention_in_days: i64,
 }
 
+impl PutRetentionPolicyRequest {
+    /// Returns a builder for `PutRetentionPolicyRequest`, checking its required members when built.
+    pub fn builder() -> PutRetentionPolicyRequestBuilder {
+        PutRetentionPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutRetentionPolicyRequest`](struct.PutRetentionPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct PutRetentionPolicyRequestBuilder {
+    log_group_name: Option<String>,
+    retention_in_days: Option<i64>,
+}
+
+impl PutRetentionPolicyRequestBuilder {
+    /// Sets the [`log_group_name`](struct.PutRetentionPolicyRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retention_in_days`](struct.PutRetentionPolicyRequest.html#structfield.retention_in_days) member.
+    pub fn retention_in_days<T: Into<i64>>(mut self, value: T) -> Self {
+        self.retention_in_days = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutRetentionPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutRetentionPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(PutRetentionPolicyRequest {
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            retention_in_days: self
+                .retention_in_days
+                .ok_or(::rusoto_core::BuildError::missing("retention_in_days"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutSubscriptionFilterRequest {
     /// <p><p>The ARN of the destination to deliver matching log events to. Currently, the supported destinations are:</p> <ul> <li> <p>An Amazon Kinesis stream belonging to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>A logical destination (specified using an ARN) belonging to a different account, for cross-account delivery.</p> </li> <li> <p>An Amazon Kinesis Firehose delivery stream belonging to the same account as the subscription filter, for same-account delivery.</p> </li> <li> <p>An AWS Lambda function belonging to the same account as the subscription filter, for same-account delivery.</p> </li> </ul></p>
@@ -1008,7 +2785,7 @@ pub struct PutSubscriptionFilterRequest {
     /// <p>The method used to distribute log data to the destination. By default log data is grouped by log stream, but the grouping can be set to random for a more even distribution. This property is only applicable when the destination is an Amazon Kinesis stream. </p>
     #[serde(rename = "distribution")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub distribution: Option<String>,
+    pub distribution: Option<Distribution>,
     /// <p>A name for the subscription filter. If you are updating an existing filter, you must specify the correct name in <code>filterName</code>. Otherwise, the call fails because you cannot associate a second filter with a log group. To find the name of the filter currently associated with a log group, use <a>DescribeSubscriptionFilters</a>.</p>
     #[serde(rename = "filterName")]
     pub filter_name: String,
@@ -1024,6 +2801,84 @@ pub struct PutSubscriptionFilterRequest {
     pub role_arn: Option<String>,
 }
 
+impl PutSubscriptionFilterRequest {
+    /// Returns a builder for `PutSubscriptionFilterRequest`, checking its required members when built.
+    pub fn builder() -> PutSubscriptionFilterRequestBuilder {
+        PutSubscriptionFilterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutSubscriptionFilterRequest`](struct.PutSubscriptionFilterRequest.html).
+#[derive(Default, Debug)]
+pub struct PutSubscriptionFilterRequestBuilder {
+    destination_arn: Option<String>,
+    distribution: Option<Distribution>,
+    filter_name: Option<String>,
+    filter_pattern: Option<String>,
+    log_group_name: Option<String>,
+    role_arn: Option<String>,
+}
+
+impl PutSubscriptionFilterRequestBuilder {
+    /// Sets the [`destination_arn`](struct.PutSubscriptionFilterRequest.html#structfield.destination_arn) member.
+    pub fn destination_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`distribution`](struct.PutSubscriptionFilterRequest.html#structfield.distribution) member.
+    pub fn distribution<T: Into<Distribution>>(mut self, value: T) -> Self {
+        self.distribution = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_name`](struct.PutSubscriptionFilterRequest.html#structfield.filter_name) member.
+    pub fn filter_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_pattern`](struct.PutSubscriptionFilterRequest.html#structfield.filter_pattern) member.
+    pub fn filter_pattern<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_pattern = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.PutSubscriptionFilterRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.PutSubscriptionFilterRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutSubscriptionFilterRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutSubscriptionFilterRequest, ::rusoto_core::BuildError> {
+        Ok(PutSubscriptionFilterRequest {
+            destination_arn: self
+                .destination_arn
+                .ok_or(::rusoto_core::BuildError::missing("destination_arn"))?,
+            distribution: self.distribution,
+            filter_name: self
+                .filter_name
+                .ok_or(::rusoto_core::BuildError::missing("filter_name"))?,
+            filter_pattern: self
+                .filter_pattern
+                .ok_or(::rusoto_core::BuildError::missing("filter_pattern"))?,
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            role_arn: self.role_arn,
+        })
+    }
+}
+
 /// <p>Reserved.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct QueryCompileError {
@@ -1065,7 +2920,7 @@ pub struct QueryInfo {
     /// <p>The status of this query. Possible values are <code>Cancelled</code>, <code>Complete</code>, <code>Failed</code>, <code>Running</code>, <code>Scheduled</code>, and <code>Unknown</code>.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<QueryStatus>,
 }
 
 /// <p>Contains the number of log events scanned by the query, the number of log events that matched the query criteria, and the total number of bytes in the log events that were scanned.</p>
@@ -1086,6 +2941,98 @@ pub struct QueryStatistics {
     pub records_scanned: Option<f64>,
 }
 
+/// <p>Limits the returned queries to only those that have the specified status. Valid values are <code>Cancelled</code>, <code>Complete</code>, <code>Failed</code>, <code>Running</code>, and <code>Scheduled</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QueryStatus {
+    /// <p><code>Scheduled</code></p>
+    Scheduled,
+    /// <p><code>Running</code></p>
+    Running,
+    /// <p><code>Complete</code></p>
+    Complete,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Cancelled</code></p>
+    Cancelled,
+    /// <p><code>Timeout</code></p>
+    Timeout,
+    /// <p><code>Unknown</code></p>
+    UnknownValue,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl QueryStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueryStatus::Scheduled => "Scheduled",
+            QueryStatus::Running => "Running",
+            QueryStatus::Complete => "Complete",
+            QueryStatus::Failed => "Failed",
+            QueryStatus::Cancelled => "Cancelled",
+            QueryStatus::Timeout => "Timeout",
+            QueryStatus::UnknownValue => "Unknown",
+            QueryStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for QueryStatus {
+    fn from(value: &'a str) -> QueryStatus {
+        match value {
+            "Scheduled" => QueryStatus::Scheduled,
+            "Running" => QueryStatus::Running,
+            "Complete" => QueryStatus::Complete,
+            "Failed" => QueryStatus::Failed,
+            "Cancelled" => QueryStatus::Cancelled,
+            "Timeout" => QueryStatus::Timeout,
+            "Unknown" => QueryStatus::UnknownValue,
+            _ => QueryStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueryStatus {
+    fn from(value: String) -> QueryStatus {
+        QueryStatus::from(value.as_str())
+    }
+}
+
+impl From<QueryStatus> for String {
+    fn from(value: QueryStatus) -> String {
+        match value {
+            QueryStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for QueryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for QueryStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QueryStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<QueryStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(QueryStatus::from(value))
+    }
+}
+
 /// <p>Represents the rejected events.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1175,6 +3122,80 @@ pub struct StartQueryRequest {
     pub start_time: i64,
 }
 
+impl StartQueryRequest {
+    /// Returns a builder for `StartQueryRequest`, checking its required members when built.
+    pub fn builder() -> StartQueryRequestBuilder {
+        StartQueryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartQueryRequest`](struct.StartQueryRequest.html).
+#[derive(Default, Debug)]
+pub struct StartQueryRequestBuilder {
+    end_time: Option<i64>,
+    limit: Option<i64>,
+    log_group_name: Option<String>,
+    log_group_names: Option<Vec<String>>,
+    query_string: Option<String>,
+    start_time: Option<i64>,
+}
+
+impl StartQueryRequestBuilder {
+    /// Sets the [`end_time`](struct.StartQueryRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<i64>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.StartQueryRequest.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_name`](struct.StartQueryRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_group_names`](struct.StartQueryRequest.html#structfield.log_group_names) member.
+    pub fn log_group_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.log_group_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query_string`](struct.StartQueryRequest.html#structfield.query_string) member.
+    pub fn query_string<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_string = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.StartQueryRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<i64>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartQueryRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartQueryRequest, ::rusoto_core::BuildError> {
+        Ok(StartQueryRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            limit: self.limit,
+            log_group_name: self.log_group_name,
+            log_group_names: self.log_group_names,
+            query_string: self
+                .query_string
+                .ok_or(::rusoto_core::BuildError::missing("query_string"))?,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartQueryResponse {
@@ -1191,6 +3212,36 @@ pub struct StopQueryRequest {
     pub query_id: String,
 }
 
+impl StopQueryRequest {
+    /// Returns a builder for `StopQueryRequest`, checking its required members when built.
+    pub fn builder() -> StopQueryRequestBuilder {
+        StopQueryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopQueryRequest`](struct.StopQueryRequest.html).
+#[derive(Default, Debug)]
+pub struct StopQueryRequestBuilder {
+    query_id: Option<String>,
+}
+
+impl StopQueryRequestBuilder {
+    /// Sets the [`query_id`](struct.StopQueryRequest.html#structfield.query_id) member.
+    pub fn query_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.query_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopQueryRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopQueryRequest, ::rusoto_core::BuildError> {
+        Ok(StopQueryRequest {
+            query_id: self
+                .query_id
+                .ok_or(::rusoto_core::BuildError::missing("query_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopQueryResponse {
@@ -1214,7 +3265,7 @@ pub struct SubscriptionFilter {
     pub destination_arn: Option<String>,
     #[serde(rename = "distribution")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub distribution: Option<String>,
+    pub distribution: Option<Distribution>,
     /// <p>The name of the subscription filter.</p>
     #[serde(rename = "filterName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1242,6 +3293,46 @@ pub struct TagLogGroupRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagLogGroupRequest {
+    /// Returns a builder for `TagLogGroupRequest`, checking its required members when built.
+    pub fn builder() -> TagLogGroupRequestBuilder {
+        TagLogGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagLogGroupRequest`](struct.TagLogGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct TagLogGroupRequestBuilder {
+    log_group_name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagLogGroupRequestBuilder {
+    /// Sets the [`log_group_name`](struct.TagLogGroupRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagLogGroupRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagLogGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagLogGroupRequest, ::rusoto_core::BuildError> {
+        Ok(TagLogGroupRequest {
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TestMetricFilterRequest {
     #[serde(rename = "filterPattern")]
@@ -1251,6 +3342,48 @@ pub struct TestMetricFilterRequest {
     pub log_event_messages: Vec<String>,
 }
 
+impl TestMetricFilterRequest {
+    /// Returns a builder for `TestMetricFilterRequest`, checking its required members when built.
+    pub fn builder() -> TestMetricFilterRequestBuilder {
+        TestMetricFilterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TestMetricFilterRequest`](struct.TestMetricFilterRequest.html).
+#[derive(Default, Debug)]
+pub struct TestMetricFilterRequestBuilder {
+    filter_pattern: Option<String>,
+    log_event_messages: Option<Vec<String>>,
+}
+
+impl TestMetricFilterRequestBuilder {
+    /// Sets the [`filter_pattern`](struct.TestMetricFilterRequest.html#structfield.filter_pattern) member.
+    pub fn filter_pattern<T: Into<String>>(mut self, value: T) -> Self {
+        self.filter_pattern = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_event_messages`](struct.TestMetricFilterRequest.html#structfield.log_event_messages) member.
+    pub fn log_event_messages<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.log_event_messages = Some(value.into());
+        self
+    }
+
+    /// Builds the `TestMetricFilterRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<TestMetricFilterRequest, ::rusoto_core::BuildError> {
+        Ok(TestMetricFilterRequest {
+            filter_pattern: self
+                .filter_pattern
+                .ok_or(::rusoto_core::BuildError::missing("filter_pattern"))?,
+            log_event_messages: self
+                .log_event_messages
+                .ok_or(::rusoto_core::BuildError::missing("log_event_messages"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TestMetricFilterResponse {
@@ -1270,6 +3403,46 @@ pub struct UntagLogGroupRequest {
     pub tags: Vec<String>,
 }
 
+impl UntagLogGroupRequest {
+    /// Returns a builder for `UntagLogGroupRequest`, checking its required members when built.
+    pub fn builder() -> UntagLogGroupRequestBuilder {
+        UntagLogGroupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagLogGroupRequest`](struct.UntagLogGroupRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagLogGroupRequestBuilder {
+    log_group_name: Option<String>,
+    tags: Option<Vec<String>>,
+}
+
+impl UntagLogGroupRequestBuilder {
+    /// Sets the [`log_group_name`](struct.UntagLogGroupRequest.html#structfield.log_group_name) member.
+    pub fn log_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.UntagLogGroupRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagLogGroupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagLogGroupRequest, ::rusoto_core::BuildError> {
+        Ok(UntagLogGroupRequest {
+            log_group_name: self
+                .log_group_name
+                .ok_or(::rusoto_core::BuildError::missing("log_group_name"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Errors returned by AssociateKmsKey
 #[derive(Debug, PartialEq)]
 pub enum AssociateKmsKeyError {
@@ -3447,6 +5620,243 @@ pub trait CloudWatchLogs {
     /// <p>Removes the specified tags from the specified log group.</p> <p>To list the tags for a log group, use <a>ListTagsLogGroup</a>. To add tags, use <a>UntagLogGroup</a>.</p>
     fn untag_log_group(&self, input: UntagLogGroupRequest) -> RusotoFuture<(), UntagLogGroupError>;
 }
+impl<T: ?Sized + CloudWatchLogs> CloudWatchLogs for ::std::sync::Arc<T> {
+    fn associate_kms_key(
+        &self,
+        input: AssociateKmsKeyRequest,
+    ) -> RusotoFuture<(), AssociateKmsKeyError> {
+        (**self).associate_kms_key(input)
+    }
+    fn cancel_export_task(
+        &self,
+        input: CancelExportTaskRequest,
+    ) -> RusotoFuture<(), CancelExportTaskError> {
+        (**self).cancel_export_task(input)
+    }
+    fn create_export_task(
+        &self,
+        input: CreateExportTaskRequest,
+    ) -> RusotoFuture<CreateExportTaskResponse, CreateExportTaskError> {
+        (**self).create_export_task(input)
+    }
+    fn create_log_group(
+        &self,
+        input: CreateLogGroupRequest,
+    ) -> RusotoFuture<(), CreateLogGroupError> {
+        (**self).create_log_group(input)
+    }
+    fn create_log_stream(
+        &self,
+        input: CreateLogStreamRequest,
+    ) -> RusotoFuture<(), CreateLogStreamError> {
+        (**self).create_log_stream(input)
+    }
+    fn delete_destination(
+        &self,
+        input: DeleteDestinationRequest,
+    ) -> RusotoFuture<(), DeleteDestinationError> {
+        (**self).delete_destination(input)
+    }
+    fn delete_log_group(
+        &self,
+        input: DeleteLogGroupRequest,
+    ) -> RusotoFuture<(), DeleteLogGroupError> {
+        (**self).delete_log_group(input)
+    }
+    fn delete_log_stream(
+        &self,
+        input: DeleteLogStreamRequest,
+    ) -> RusotoFuture<(), DeleteLogStreamError> {
+        (**self).delete_log_stream(input)
+    }
+    fn delete_metric_filter(
+        &self,
+        input: DeleteMetricFilterRequest,
+    ) -> RusotoFuture<(), DeleteMetricFilterError> {
+        (**self).delete_metric_filter(input)
+    }
+    fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> RusotoFuture<(), DeleteResourcePolicyError> {
+        (**self).delete_resource_policy(input)
+    }
+    fn delete_retention_policy(
+        &self,
+        input: DeleteRetentionPolicyRequest,
+    ) -> RusotoFuture<(), DeleteRetentionPolicyError> {
+        (**self).delete_retention_policy(input)
+    }
+    fn delete_subscription_filter(
+        &self,
+        input: DeleteSubscriptionFilterRequest,
+    ) -> RusotoFuture<(), DeleteSubscriptionFilterError> {
+        (**self).delete_subscription_filter(input)
+    }
+    fn describe_destinations(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> RusotoFuture<DescribeDestinationsResponse, DescribeDestinationsError> {
+        (**self).describe_destinations(input)
+    }
+    fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> RusotoFuture<DescribeExportTasksResponse, DescribeExportTasksError> {
+        (**self).describe_export_tasks(input)
+    }
+    fn describe_log_groups(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> RusotoFuture<DescribeLogGroupsResponse, DescribeLogGroupsError> {
+        (**self).describe_log_groups(input)
+    }
+    fn describe_log_streams(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> RusotoFuture<DescribeLogStreamsResponse, DescribeLogStreamsError> {
+        (**self).describe_log_streams(input)
+    }
+    fn describe_metric_filters(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> RusotoFuture<DescribeMetricFiltersResponse, DescribeMetricFiltersError> {
+        (**self).describe_metric_filters(input)
+    }
+    fn describe_queries(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> RusotoFuture<DescribeQueriesResponse, DescribeQueriesError> {
+        (**self).describe_queries(input)
+    }
+    fn describe_resource_policies(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> RusotoFuture<DescribeResourcePoliciesResponse, DescribeResourcePoliciesError> {
+        (**self).describe_resource_policies(input)
+    }
+    fn describe_subscription_filters(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> RusotoFuture<DescribeSubscriptionFiltersResponse, DescribeSubscriptionFiltersError> {
+        (**self).describe_subscription_filters(input)
+    }
+    fn disassociate_kms_key(
+        &self,
+        input: DisassociateKmsKeyRequest,
+    ) -> RusotoFuture<(), DisassociateKmsKeyError> {
+        (**self).disassociate_kms_key(input)
+    }
+    fn filter_log_events(
+        &self,
+        input: FilterLogEventsRequest,
+    ) -> RusotoFuture<FilterLogEventsResponse, FilterLogEventsError> {
+        (**self).filter_log_events(input)
+    }
+    fn get_log_events(
+        &self,
+        input: GetLogEventsRequest,
+    ) -> RusotoFuture<GetLogEventsResponse, GetLogEventsError> {
+        (**self).get_log_events(input)
+    }
+    fn get_log_group_fields(
+        &self,
+        input: GetLogGroupFieldsRequest,
+    ) -> RusotoFuture<GetLogGroupFieldsResponse, GetLogGroupFieldsError> {
+        (**self).get_log_group_fields(input)
+    }
+    fn get_log_record(
+        &self,
+        input: GetLogRecordRequest,
+    ) -> RusotoFuture<GetLogRecordResponse, GetLogRecordError> {
+        (**self).get_log_record(input)
+    }
+    fn get_query_results(
+        &self,
+        input: GetQueryResultsRequest,
+    ) -> RusotoFuture<GetQueryResultsResponse, GetQueryResultsError> {
+        (**self).get_query_results(input)
+    }
+    fn list_tags_log_group(
+        &self,
+        input: ListTagsLogGroupRequest,
+    ) -> RusotoFuture<ListTagsLogGroupResponse, ListTagsLogGroupError> {
+        (**self).list_tags_log_group(input)
+    }
+    fn put_destination(
+        &self,
+        input: PutDestinationRequest,
+    ) -> RusotoFuture<PutDestinationResponse, PutDestinationError> {
+        (**self).put_destination(input)
+    }
+    fn put_destination_policy(
+        &self,
+        input: PutDestinationPolicyRequest,
+    ) -> RusotoFuture<(), PutDestinationPolicyError> {
+        (**self).put_destination_policy(input)
+    }
+    fn put_log_events(
+        &self,
+        input: PutLogEventsRequest,
+    ) -> RusotoFuture<PutLogEventsResponse, PutLogEventsError> {
+        (**self).put_log_events(input)
+    }
+    fn put_metric_filter(
+        &self,
+        input: PutMetricFilterRequest,
+    ) -> RusotoFuture<(), PutMetricFilterError> {
+        (**self).put_metric_filter(input)
+    }
+    fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> RusotoFuture<PutResourcePolicyResponse, PutResourcePolicyError> {
+        (**self).put_resource_policy(input)
+    }
+    fn put_retention_policy(
+        &self,
+        input: PutRetentionPolicyRequest,
+    ) -> RusotoFuture<(), PutRetentionPolicyError> {
+        (**self).put_retention_policy(input)
+    }
+    fn put_subscription_filter(
+        &self,
+        input: PutSubscriptionFilterRequest,
+    ) -> RusotoFuture<(), PutSubscriptionFilterError> {
+        (**self).put_subscription_filter(input)
+    }
+    fn start_query(
+        &self,
+        input: StartQueryRequest,
+    ) -> RusotoFuture<StartQueryResponse, StartQueryError> {
+        (**self).start_query(input)
+    }
+    fn stop_query(
+        &self,
+        input: StopQueryRequest,
+    ) -> RusotoFuture<StopQueryResponse, StopQueryError> {
+        (**self).stop_query(input)
+    }
+    fn tag_log_group(&self, input: TagLogGroupRequest) -> RusotoFuture<(), TagLogGroupError> {
+        (**self).tag_log_group(input)
+    }
+    fn test_metric_filter(
+        &self,
+        input: TestMetricFilterRequest,
+    ) -> RusotoFuture<TestMetricFilterResponse, TestMetricFilterError> {
+        (**self).test_metric_filter(input)
+    }
+    fn untag_log_group(&self, input: UntagLogGroupRequest) -> RusotoFuture<(), UntagLogGroupError> {
+        (**self).untag_log_group(input)
+    }
+}
+/// The error codes Amazon CloudWatch Logs retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon CloudWatch Logs API.
 #[derive(Clone)]
 pub struct CloudWatchLogsClient {
@@ -3462,6 +5872,24 @@ impl CloudWatchLogsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_LOGS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> CloudWatchLogsClient {
+        Self::new(region::Region::from_env_for_service("LOGS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `logs`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> CloudWatchLogsClient {
+        Self::new_with_client(config.client().clone(), config.region_for("logs", "LOGS"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3480,7 +5908,49 @@ impl CloudWatchLogsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchLogsClient {
-        CloudWatchLogsClient { client, region }
+        CloudWatchLogsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "logs")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "logs", "logs", "LOGS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `CloudWatchLogs` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn CloudWatchLogs + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for CloudWatchLogsClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon CloudWatch Logs"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "logs"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> CloudWatchLogsClient {
+        Self::new_with_client(client, region)
     }
 }
 
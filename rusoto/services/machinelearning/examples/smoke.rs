@@ -0,0 +1,40 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Machine Learning clients.
+//!
+//! Makes a single read-only `DescribeBatchPredictions` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_machinelearning::{
+    DescribeBatchPredictionsInput, MachineLearning, MachineLearningClient,
+};
+
+fn main() {
+    let client = MachineLearningClient::from_env();
+    match client
+        .describe_batch_predictions(DescribeBatchPredictionsInput::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeBatchPredictions succeeded"),
+        Err(error) => {
+            eprintln!("DescribeBatchPredictions failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
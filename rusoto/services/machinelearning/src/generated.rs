@@ -22,20 +22,71 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AddTagsInput {
     /// <p>The ID of the ML object to tag. For example, <code>exampleModelId</code>.</p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p>The type of the ML object to tag. </p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: TaggableResourceType,
     /// <p>The key-value pairs to use to create tags. If you specify a key without specifying a value, Amazon ML creates a tag with the specified key and a value of null.</p>
     #[serde(rename = "Tags")]
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsInput {
+    /// Returns a builder for `AddTagsInput`, checking its required members when built.
+    pub fn builder() -> AddTagsInputBuilder {
+        AddTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsInput`](struct.AddTagsInput.html).
+#[derive(Default, Debug)]
+pub struct AddTagsInputBuilder {
+    resource_id: Option<String>,
+    resource_type: Option<TaggableResourceType>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsInputBuilder {
+    /// Sets the [`resource_id`](struct.AddTagsInput.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.AddTagsInput.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<TaggableResourceType>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AddTagsInput, ::rusoto_core::BuildError> {
+        Ok(AddTagsInput {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            resource_type: self
+                .resource_type
+                .ok_or(::rusoto_core::BuildError::missing("resource_type"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>Amazon ML returns the following elements. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -47,7 +98,75 @@ pub struct AddTagsOutput {
     /// <p>The type of the ML object that was tagged.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<TaggableResourceType>,
+}
+
+/// <p>The function used to train an <code>MLModel</code>. Training choices supported by Amazon ML include the following:</p> <ul> <li> <p> <code>SGD</code> - Stochastic Gradient Descent.</p> </li> <li> <p> <code>RandomForest</code> - Random forest of decision trees.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    /// <p><code>sgd</code></p>
+    Sgd,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Algorithm {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Algorithm::Sgd => "sgd",
+            Algorithm::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Algorithm {
+    fn from(value: &'a str) -> Algorithm {
+        match value {
+            "sgd" => Algorithm::Sgd,
+            _ => Algorithm::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Algorithm {
+    fn from(value: String) -> Algorithm {
+        Algorithm::from(value.as_str())
+    }
+}
+
+impl From<Algorithm> for String {
+    fn from(value: Algorithm) -> String {
+        match value {
+            Algorithm::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Algorithm {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Algorithm {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Algorithm, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Algorithm::from(value))
+    }
 }
 
 /// <p> Represents the output of a <code>GetBatchPrediction</code> operation.</p> <p> The content consists of the detailed metadata, the status, and the data file information of a <code>Batch Prediction</code>.</p>
@@ -67,15 +186,17 @@ pub struct BatchPrediction {
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>BatchPrediction</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The AWS user account that invoked the <code>BatchPrediction</code>. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by_iam_user: Option<String>,
     #[serde(rename = "FinishedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<EpochTimestamp>,
     /// <p>The location of the data file or directory in Amazon Simple Storage Service (Amazon S3).</p>
     #[serde(rename = "InputDataLocationS3")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -85,8 +206,9 @@ pub struct BatchPrediction {
     pub invalid_record_count: Option<i64>,
     /// <p>The time of the most recent edit to the <code>BatchPrediction</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>The ID of the <code>MLModel</code> that generated predictions for the <code>BatchPrediction</code> request.</p>
     #[serde(rename = "MLModelId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -104,17 +226,116 @@ pub struct BatchPrediction {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_uri: Option<String>,
     #[serde(rename = "StartedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<EpochTimestamp>,
     /// <p><p>The status of the <code>BatchPrediction</code>. This element can have one of the following values:</p> <ul> <li> <code>PENDING</code> - Amazon Machine Learning (Amazon ML) submitted a request to generate predictions for a batch of observations.</li> <li> <code>INPROGRESS</code> - The process is underway.</li> <li> <code>FAILED</code> - The request to perform a batch prediction did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The batch prediction process completed successfully.</li> <li> <code>DELETED</code> - The <code>BatchPrediction</code> is marked as deleted. It is not usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
     #[serde(rename = "TotalRecordCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_record_count: Option<i64>,
 }
 
+/// <p>A list of the variables to use in searching or filtering <code>BatchPrediction</code>.</p> <ul> <li> <p> <code>CreatedAt</code> - Sets the search criteria to <code>BatchPrediction</code> creation date.</p> </li> <li> <p> <code>Status</code> - Sets the search criteria to <code>BatchPrediction</code> status.</p> </li> <li> <p> <code>Name</code> - Sets the search criteria to the contents of <code>BatchPrediction</code> <code>Name</code>.</p> </li> <li> <p> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>BatchPrediction</code> creation.</p> </li> <li> <p> <code>MLModelId</code> - Sets the search criteria to the <code>MLModel</code> used in the <code>BatchPrediction</code>.</p> </li> <li> <p> <code>DataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used in the <code>BatchPrediction</code>.</p> </li> <li> <p> <code>DataURI</code> - Sets the search criteria to the data file(s) used in the <code>BatchPrediction</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BatchPredictionFilterVariable {
+    /// <p><code>CreatedAt</code> - Sets the search criteria to <code>BatchPrediction</code> creation date.</p>
+    CreatedAt,
+    /// <p><code>LastUpdatedAt</code></p>
+    LastUpdatedAt,
+    /// <p><code>Status</code> - Sets the search criteria to <code>BatchPrediction</code> status.</p>
+    Status,
+    /// <p><code>Name</code> - Sets the search criteria to the contents of <code>BatchPrediction</code> <code>Name</code>.</p>
+    Name,
+    /// <p><code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>BatchPrediction</code> creation.</p>
+    IAMUser,
+    /// <p><code>MLModelId</code> - Sets the search criteria to the <code>MLModel</code> used in the <code>BatchPrediction</code>.</p>
+    MLModelId,
+    /// <p><code>DataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used in the <code>BatchPrediction</code>.</p>
+    DataSourceId,
+    /// <p><code>DataURI</code> - Sets the search criteria to the data file(s) used in the <code>BatchPrediction</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</p>
+    DataURI,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BatchPredictionFilterVariable {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BatchPredictionFilterVariable::CreatedAt => "CreatedAt",
+            BatchPredictionFilterVariable::LastUpdatedAt => "LastUpdatedAt",
+            BatchPredictionFilterVariable::Status => "Status",
+            BatchPredictionFilterVariable::Name => "Name",
+            BatchPredictionFilterVariable::IAMUser => "IAMUser",
+            BatchPredictionFilterVariable::MLModelId => "MLModelId",
+            BatchPredictionFilterVariable::DataSourceId => "DataSourceId",
+            BatchPredictionFilterVariable::DataURI => "DataURI",
+            BatchPredictionFilterVariable::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BatchPredictionFilterVariable {
+    fn from(value: &'a str) -> BatchPredictionFilterVariable {
+        match value {
+            "CreatedAt" => BatchPredictionFilterVariable::CreatedAt,
+            "LastUpdatedAt" => BatchPredictionFilterVariable::LastUpdatedAt,
+            "Status" => BatchPredictionFilterVariable::Status,
+            "Name" => BatchPredictionFilterVariable::Name,
+            "IAMUser" => BatchPredictionFilterVariable::IAMUser,
+            "MLModelId" => BatchPredictionFilterVariable::MLModelId,
+            "DataSourceId" => BatchPredictionFilterVariable::DataSourceId,
+            "DataURI" => BatchPredictionFilterVariable::DataURI,
+            _ => BatchPredictionFilterVariable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BatchPredictionFilterVariable {
+    fn from(value: String) -> BatchPredictionFilterVariable {
+        BatchPredictionFilterVariable::from(value.as_str())
+    }
+}
+
+impl From<BatchPredictionFilterVariable> for String {
+    fn from(value: BatchPredictionFilterVariable) -> String {
+        match value {
+            BatchPredictionFilterVariable::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BatchPredictionFilterVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BatchPredictionFilterVariable {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BatchPredictionFilterVariable {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<BatchPredictionFilterVariable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BatchPredictionFilterVariable::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateBatchPredictionInput {
     /// <p>The ID of the <code>DataSource</code> that points to the group of observations to predict.</p>
@@ -135,6 +356,76 @@ pub struct CreateBatchPredictionInput {
     pub output_uri: String,
 }
 
+impl CreateBatchPredictionInput {
+    /// Returns a builder for `CreateBatchPredictionInput`, checking its required members when built.
+    pub fn builder() -> CreateBatchPredictionInputBuilder {
+        CreateBatchPredictionInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBatchPredictionInput`](struct.CreateBatchPredictionInput.html).
+#[derive(Default, Debug)]
+pub struct CreateBatchPredictionInputBuilder {
+    batch_prediction_data_source_id: Option<String>,
+    batch_prediction_id: Option<String>,
+    batch_prediction_name: Option<String>,
+    ml_model_id: Option<String>,
+    output_uri: Option<String>,
+}
+
+impl CreateBatchPredictionInputBuilder {
+    /// Sets the [`batch_prediction_data_source_id`](struct.CreateBatchPredictionInput.html#structfield.batch_prediction_data_source_id) member.
+    pub fn batch_prediction_data_source_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.batch_prediction_data_source_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`batch_prediction_id`](struct.CreateBatchPredictionInput.html#structfield.batch_prediction_id) member.
+    pub fn batch_prediction_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.batch_prediction_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`batch_prediction_name`](struct.CreateBatchPredictionInput.html#structfield.batch_prediction_name) member.
+    pub fn batch_prediction_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.batch_prediction_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ml_model_id`](struct.CreateBatchPredictionInput.html#structfield.ml_model_id) member.
+    pub fn ml_model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ml_model_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_uri`](struct.CreateBatchPredictionInput.html#structfield.output_uri) member.
+    pub fn output_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.output_uri = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBatchPredictionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateBatchPredictionInput, ::rusoto_core::BuildError> {
+        Ok(CreateBatchPredictionInput {
+            batch_prediction_data_source_id: self.batch_prediction_data_source_id.ok_or(
+                ::rusoto_core::BuildError::missing("batch_prediction_data_source_id"),
+            )?,
+            batch_prediction_id: self
+                .batch_prediction_id
+                .ok_or(::rusoto_core::BuildError::missing("batch_prediction_id"))?,
+            batch_prediction_name: self.batch_prediction_name,
+            ml_model_id: self
+                .ml_model_id
+                .ok_or(::rusoto_core::BuildError::missing("ml_model_id"))?,
+            output_uri: self
+                .output_uri
+                .ok_or(::rusoto_core::BuildError::missing("output_uri"))?,
+        })
+    }
+}
+
 /// <p> Represents the output of a <code>CreateBatchPrediction</code> operation, and is an acknowledgement that Amazon ML received the request.</p> <p>The <code>CreateBatchPrediction</code> operation is asynchronous. You can poll for status updates by using the <code>&gt;GetBatchPrediction</code> operation and checking the <code>Status</code> parameter of the result. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -166,6 +457,74 @@ pub struct CreateDataSourceFromRDSInput {
     pub role_arn: String,
 }
 
+impl CreateDataSourceFromRDSInput {
+    /// Returns a builder for `CreateDataSourceFromRDSInput`, checking its required members when built.
+    pub fn builder() -> CreateDataSourceFromRDSInputBuilder {
+        CreateDataSourceFromRDSInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDataSourceFromRDSInput`](struct.CreateDataSourceFromRDSInput.html).
+#[derive(Default, Debug)]
+pub struct CreateDataSourceFromRDSInputBuilder {
+    compute_statistics: Option<bool>,
+    data_source_id: Option<String>,
+    data_source_name: Option<String>,
+    rds_data: Option<RDSDataSpec>,
+    role_arn: Option<String>,
+}
+
+impl CreateDataSourceFromRDSInputBuilder {
+    /// Sets the [`compute_statistics`](struct.CreateDataSourceFromRDSInput.html#structfield.compute_statistics) member.
+    pub fn compute_statistics<T: Into<bool>>(mut self, value: T) -> Self {
+        self.compute_statistics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_source_id`](struct.CreateDataSourceFromRDSInput.html#structfield.data_source_id) member.
+    pub fn data_source_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_source_name`](struct.CreateDataSourceFromRDSInput.html#structfield.data_source_name) member.
+    pub fn data_source_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rds_data`](struct.CreateDataSourceFromRDSInput.html#structfield.rds_data) member.
+    pub fn rds_data<T: Into<RDSDataSpec>>(mut self, value: T) -> Self {
+        self.rds_data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateDataSourceFromRDSInput.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDataSourceFromRDSInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDataSourceFromRDSInput, ::rusoto_core::BuildError> {
+        Ok(CreateDataSourceFromRDSInput {
+            compute_statistics: self.compute_statistics,
+            data_source_id: self
+                .data_source_id
+                .ok_or(::rusoto_core::BuildError::missing("data_source_id"))?,
+            data_source_name: self.data_source_name,
+            rds_data: self
+                .rds_data
+                .ok_or(::rusoto_core::BuildError::missing("rds_data"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 /// <p> Represents the output of a <code>CreateDataSourceFromRDS</code> operation, and is an acknowledgement that Amazon ML received the request.</p> <p>The <code>CreateDataSourceFromRDS</code>&gt; operation is asynchronous. You can poll for updates by using the <code>GetBatchPrediction</code> operation and checking the <code>Status</code> parameter. You can inspect the <code>Message</code> when <code>Status</code> shows up as <code>FAILED</code>. You can also check the progress of the copy operation by going to the <code>DataPipeline</code> console and looking up the pipeline using the <code>pipelineId </code> from the describe call.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -197,6 +556,74 @@ pub struct CreateDataSourceFromRedshiftInput {
     pub role_arn: String,
 }
 
+impl CreateDataSourceFromRedshiftInput {
+    /// Returns a builder for `CreateDataSourceFromRedshiftInput`, checking its required members when built.
+    pub fn builder() -> CreateDataSourceFromRedshiftInputBuilder {
+        CreateDataSourceFromRedshiftInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDataSourceFromRedshiftInput`](struct.CreateDataSourceFromRedshiftInput.html).
+#[derive(Default, Debug)]
+pub struct CreateDataSourceFromRedshiftInputBuilder {
+    compute_statistics: Option<bool>,
+    data_source_id: Option<String>,
+    data_source_name: Option<String>,
+    data_spec: Option<RedshiftDataSpec>,
+    role_arn: Option<String>,
+}
+
+impl CreateDataSourceFromRedshiftInputBuilder {
+    /// Sets the [`compute_statistics`](struct.CreateDataSourceFromRedshiftInput.html#structfield.compute_statistics) member.
+    pub fn compute_statistics<T: Into<bool>>(mut self, value: T) -> Self {
+        self.compute_statistics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_source_id`](struct.CreateDataSourceFromRedshiftInput.html#structfield.data_source_id) member.
+    pub fn data_source_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_source_name`](struct.CreateDataSourceFromRedshiftInput.html#structfield.data_source_name) member.
+    pub fn data_source_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_spec`](struct.CreateDataSourceFromRedshiftInput.html#structfield.data_spec) member.
+    pub fn data_spec<T: Into<RedshiftDataSpec>>(mut self, value: T) -> Self {
+        self.data_spec = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateDataSourceFromRedshiftInput.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDataSourceFromRedshiftInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDataSourceFromRedshiftInput, ::rusoto_core::BuildError> {
+        Ok(CreateDataSourceFromRedshiftInput {
+            compute_statistics: self.compute_statistics,
+            data_source_id: self
+                .data_source_id
+                .ok_or(::rusoto_core::BuildError::missing("data_source_id"))?,
+            data_source_name: self.data_source_name,
+            data_spec: self
+                .data_spec
+                .ok_or(::rusoto_core::BuildError::missing("data_spec"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 /// <p> Represents the output of a <code>CreateDataSourceFromRedshift</code> operation, and is an acknowledgement that Amazon ML received the request.</p> <p>The <code>CreateDataSourceFromRedshift</code> operation is asynchronous. You can poll for updates by using the <code>GetBatchPrediction</code> operation and checking the <code>Status</code> parameter. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -225,6 +652,64 @@ pub struct CreateDataSourceFromS3Input {
     pub data_spec: S3DataSpec,
 }
 
+impl CreateDataSourceFromS3Input {
+    /// Returns a builder for `CreateDataSourceFromS3Input`, checking its required members when built.
+    pub fn builder() -> CreateDataSourceFromS3InputBuilder {
+        CreateDataSourceFromS3InputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDataSourceFromS3Input`](struct.CreateDataSourceFromS3Input.html).
+#[derive(Default, Debug)]
+pub struct CreateDataSourceFromS3InputBuilder {
+    compute_statistics: Option<bool>,
+    data_source_id: Option<String>,
+    data_source_name: Option<String>,
+    data_spec: Option<S3DataSpec>,
+}
+
+impl CreateDataSourceFromS3InputBuilder {
+    /// Sets the [`compute_statistics`](struct.CreateDataSourceFromS3Input.html#structfield.compute_statistics) member.
+    pub fn compute_statistics<T: Into<bool>>(mut self, value: T) -> Self {
+        self.compute_statistics = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_source_id`](struct.CreateDataSourceFromS3Input.html#structfield.data_source_id) member.
+    pub fn data_source_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_source_name`](struct.CreateDataSourceFromS3Input.html#structfield.data_source_name) member.
+    pub fn data_source_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_spec`](struct.CreateDataSourceFromS3Input.html#structfield.data_spec) member.
+    pub fn data_spec<T: Into<S3DataSpec>>(mut self, value: T) -> Self {
+        self.data_spec = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDataSourceFromS3Input`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDataSourceFromS3Input, ::rusoto_core::BuildError> {
+        Ok(CreateDataSourceFromS3Input {
+            compute_statistics: self.compute_statistics,
+            data_source_id: self
+                .data_source_id
+                .ok_or(::rusoto_core::BuildError::missing("data_source_id"))?,
+            data_source_name: self.data_source_name,
+            data_spec: self
+                .data_spec
+                .ok_or(::rusoto_core::BuildError::missing("data_spec"))?,
+        })
+    }
+}
+
 /// <p> Represents the output of a <code>CreateDataSourceFromS3</code> operation, and is an acknowledgement that Amazon ML received the request.</p> <p>The <code>CreateDataSourceFromS3</code> operation is asynchronous. You can poll for updates by using the <code>GetBatchPrediction</code> operation and checking the <code>Status</code> parameter. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -252,6 +737,64 @@ pub struct CreateEvaluationInput {
     pub ml_model_id: String,
 }
 
+impl CreateEvaluationInput {
+    /// Returns a builder for `CreateEvaluationInput`, checking its required members when built.
+    pub fn builder() -> CreateEvaluationInputBuilder {
+        CreateEvaluationInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateEvaluationInput`](struct.CreateEvaluationInput.html).
+#[derive(Default, Debug)]
+pub struct CreateEvaluationInputBuilder {
+    evaluation_data_source_id: Option<String>,
+    evaluation_id: Option<String>,
+    evaluation_name: Option<String>,
+    ml_model_id: Option<String>,
+}
+
+impl CreateEvaluationInputBuilder {
+    /// Sets the [`evaluation_data_source_id`](struct.CreateEvaluationInput.html#structfield.evaluation_data_source_id) member.
+    pub fn evaluation_data_source_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.evaluation_data_source_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`evaluation_id`](struct.CreateEvaluationInput.html#structfield.evaluation_id) member.
+    pub fn evaluation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.evaluation_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`evaluation_name`](struct.CreateEvaluationInput.html#structfield.evaluation_name) member.
+    pub fn evaluation_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.evaluation_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ml_model_id`](struct.CreateEvaluationInput.html#structfield.ml_model_id) member.
+    pub fn ml_model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ml_model_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateEvaluationInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateEvaluationInput, ::rusoto_core::BuildError> {
+        Ok(CreateEvaluationInput {
+            evaluation_data_source_id: self.evaluation_data_source_id.ok_or(
+                ::rusoto_core::BuildError::missing("evaluation_data_source_id"),
+            )?,
+            evaluation_id: self
+                .evaluation_id
+                .ok_or(::rusoto_core::BuildError::missing("evaluation_id"))?,
+            evaluation_name: self.evaluation_name,
+            ml_model_id: self
+                .ml_model_id
+                .ok_or(::rusoto_core::BuildError::missing("ml_model_id"))?,
+        })
+    }
+}
+
 /// <p> Represents the output of a <code>CreateEvaluation</code> operation, and is an acknowledgement that Amazon ML received the request.</p> <p><code>CreateEvaluation</code> operation is asynchronous. You can poll for status updates by using the <code>GetEvcaluation</code> operation and checking the <code>Status</code> parameter. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -262,7 +805,7 @@ pub struct CreateEvaluationOutput {
     pub evaluation_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateMLModelInput {
     /// <p>A user-supplied ID that uniquely identifies the <code>MLModel</code>.</p>
     #[serde(rename = "MLModelId")]
@@ -273,7 +816,7 @@ pub struct CreateMLModelInput {
     pub ml_model_name: Option<String>,
     /// <p>The category of supervised learning that this <code>MLModel</code> will address. Choose from the following types:</p> <ul> <li>Choose <code>REGRESSION</code> if the <code>MLModel</code> will be used to predict a numeric value.</li> <li>Choose <code>BINARY</code> if the <code>MLModel</code> result has two possible values.</li> <li>Choose <code>MULTICLASS</code> if the <code>MLModel</code> result has a limited number of values. </li> </ul> <p> For more information, see the <a href="http://docs.aws.amazon.com/machine-learning/latest/dg">Amazon Machine Learning Developer Guide</a>.</p>
     #[serde(rename = "MLModelType")]
-    pub ml_model_type: String,
+    pub ml_model_type: MLModelType,
     /// <p><p>A list of the training parameters in the <code>MLModel</code>. The list is implemented as a map of key-value pairs.</p> <p>The following is the current set of training parameters: </p> <ul> <li> <p><code>sgd.maxMLModelSizeInBytes</code> - The maximum allowed size of the model. Depending on the input data, the size of the model might affect its performance.</p> <p> The value is an integer that ranges from <code>100000</code> to <code>2147483648</code>. The default value is <code>33554432</code>.</p> </li> <li><p><code>sgd.maxPasses</code> - The number of times that the training process traverses the observations to build the <code>MLModel</code>. The value is an integer that ranges from <code>1</code> to <code>10000</code>. The default value is <code>10</code>.</p></li> <li> <p><code>sgd.shuffleType</code> - Whether Amazon ML shuffles the training data. Shuffling the data improves a model&#39;s ability to find the optimal solution for a variety of data types. The valid values are <code>auto</code> and <code>none</code>. The default value is <code>none</code>. We &lt;?oxy<em>insert</em>start author=&quot;laurama&quot; timestamp=&quot;20160329T131121-0700&quot;&gt;strongly recommend that you shuffle your data.&lt;?oxy<em>insert</em>end&gt;</p> </li> <li> <p><code>sgd.l1RegularizationAmount</code> - The coefficient regularization L1 norm. It controls overfitting the data by penalizing large coefficients. This tends to drive coefficients to zero, resulting in a sparse feature set. If you use this parameter, start by specifying a small value, such as <code>1.0E-08</code>.</p> <p>The value is a double that ranges from <code>0</code> to <code>MAX<em>DOUBLE</code>. The default is to not use L1 normalization. This parameter can&#39;t be used when <code>L2</code> is specified. Use this parameter sparingly.</p> </li> <li> <p><code>sgd.l2RegularizationAmount</code> - The coefficient regularization L2 norm. It controls overfitting the data by penalizing large coefficients. This tends to drive coefficients to small, nonzero values. If you use this parameter, start by specifying a small value, such as <code>1.0E-08</code>.</p> <p>The value is a double that ranges from <code>0</code> to <code>MAX</em>DOUBLE</code>. The default is to not use L2 normalization. This parameter can&#39;t be used when <code>L1</code> is specified. Use this parameter sparingly.</p> </li> </ul></p>
     #[serde(rename = "Parameters")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -291,6 +834,91 @@ pub struct CreateMLModelInput {
     pub training_data_source_id: String,
 }
 
+impl CreateMLModelInput {
+    /// Returns a builder for `CreateMLModelInput`, checking its required members when built.
+    pub fn builder() -> CreateMLModelInputBuilder {
+        CreateMLModelInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateMLModelInput`](struct.CreateMLModelInput.html).
+#[derive(Default, Debug)]
+pub struct CreateMLModelInputBuilder {
+    ml_model_id: Option<String>,
+    ml_model_name: Option<String>,
+    ml_model_type: Option<MLModelType>,
+    parameters: Option<::std::collections::HashMap<String, String>>,
+    recipe: Option<String>,
+    recipe_uri: Option<String>,
+    training_data_source_id: Option<String>,
+}
+
+impl CreateMLModelInputBuilder {
+    /// Sets the [`ml_model_id`](struct.CreateMLModelInput.html#structfield.ml_model_id) member.
+    pub fn ml_model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ml_model_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ml_model_name`](struct.CreateMLModelInput.html#structfield.ml_model_name) member.
+    pub fn ml_model_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.ml_model_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ml_model_type`](struct.CreateMLModelInput.html#structfield.ml_model_type) member.
+    pub fn ml_model_type<T: Into<MLModelType>>(mut self, value: T) -> Self {
+        self.ml_model_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.CreateMLModelInput.html#structfield.parameters) member.
+    pub fn parameters<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recipe`](struct.CreateMLModelInput.html#structfield.recipe) member.
+    pub fn recipe<T: Into<String>>(mut self, value: T) -> Self {
+        self.recipe = Some(value.into());
+        self
+    }
+
+    /// Sets the [`recipe_uri`](struct.CreateMLModelInput.html#structfield.recipe_uri) member.
+    pub fn recipe_uri<T: Into<String>>(mut self, value: T) -> Self {
+        self.recipe_uri = Some(value.into());
+        self
+    }
+
+    /// Sets the [`training_data_source_id`](struct.CreateMLModelInput.html#structfield.training_data_source_id) member.
+    pub fn training_data_source_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.training_data_source_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateMLModelInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateMLModelInput, ::rusoto_core::BuildError> {
+        Ok(CreateMLModelInput {
+            ml_model_id: self
+                .ml_model_id
+                .ok_or(::rusoto_core::BuildError::missing("ml_model_id"))?,
+            ml_model_name: self.ml_model_name,
+            ml_model_type: self
+                .ml_model_type
+                .ok_or(::rusoto_core::BuildError::missing("ml_model_type"))?,
+            parameters: self.parameters,
+            recipe: self.recipe,
+            recipe_uri: self.recipe_uri,
+            training_data_source_id: self.training_data_source_id.ok_or(
+                ::rusoto_core::BuildError::missing("training_data_source_id"),
+            )?,
+        })
+    }
+}
+
 /// <p> Represents the output of a <code>CreateMLModel</code> operation, and is an acknowledgement that Amazon ML received the request.</p> <p>The <code>CreateMLModel</code> operation is asynchronous. You can poll for status updates by using the <code>GetMLModel</code> operation and checking the <code>Status</code> parameter. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -308,6 +936,38 @@ pub struct CreateRealtimeEndpointInput {
     pub ml_model_id: String,
 }
 
+impl CreateRealtimeEndpointInput {
+    /// Returns a builder for `CreateRealtimeEndpointInput`, checking its required members when built.
+    pub fn builder() -> CreateRealtimeEndpointInputBuilder {
+        CreateRealtimeEndpointInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateRealtimeEndpointInput`](struct.CreateRealtimeEndpointInput.html).
+#[derive(Default, Debug)]
+pub struct CreateRealtimeEndpointInputBuilder {
+    ml_model_id: Option<String>,
+}
+
+impl CreateRealtimeEndpointInputBuilder {
+    /// Sets the [`ml_model_id`](struct.CreateRealtimeEndpointInput.html#structfield.ml_model_id) member.
+    pub fn ml_model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ml_model_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateRealtimeEndpointInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateRealtimeEndpointInput, ::rusoto_core::BuildError> {
+        Ok(CreateRealtimeEndpointInput {
+            ml_model_id: self
+                .ml_model_id
+                .ok_or(::rusoto_core::BuildError::missing("ml_model_id"))?,
+        })
+    }
+}
+
 /// <p><p>Represents the output of an <code>CreateRealtimeEndpoint</code> operation.</p> <p>The result contains the <code>MLModelId</code> and the endpoint information for the <code>MLModel</code>.</p> <note> <p>The endpoint information includes the URI of the <code>MLModel</code>; that is, the location to send online prediction requests for the specified <code>MLModel</code>.</p> </note></p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -335,8 +995,9 @@ pub struct DataSource {
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>DataSource</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The AWS user account from which the <code>DataSource</code> was created. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -358,12 +1019,14 @@ pub struct DataSource {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data_source_id: Option<String>,
     #[serde(rename = "FinishedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<EpochTimestamp>,
     /// <p>The time of the most recent edit to the <code>BatchPrediction</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>A description of the most recent details about creating the <code>DataSource</code>.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -386,12 +1049,101 @@ pub struct DataSource {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role_arn: Option<String>,
     #[serde(rename = "StartedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<EpochTimestamp>,
     /// <p><p>The current status of the <code>DataSource</code>. This element can have one of the following values: </p> <ul> <li>PENDING - Amazon Machine Learning (Amazon ML) submitted a request to create a <code>DataSource</code>.</li> <li>INPROGRESS - The creation process is underway.</li> <li>FAILED - The request to create a <code>DataSource</code> did not run to completion. It is not usable.</li> <li>COMPLETED - The creation process completed successfully.</li> <li>DELETED - The <code>DataSource</code> is marked as deleted. It is not usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
+}
+
+/// <p>A list of the variables to use in searching or filtering <code>DataSource</code>.</p> <ul> <li> <p> <code>CreatedAt</code> - Sets the search criteria to <code>DataSource</code> creation date.</p> </li> <li> <p> <code>Status</code> - Sets the search criteria to <code>DataSource</code> status.</p> </li> <li> <p> <code>Name</code> - Sets the search criteria to the contents of <code>DataSource</code> <code>Name</code>.</p> </li> <li> <p> <code>DataUri</code> - Sets the search criteria to the URI of data files used to create the <code>DataSource</code>. The URI can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</p> </li> <li> <p> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>DataSource</code> creation.</p> </li> </ul> <p> <b>Note:</b> The variable names should match the variable names in the <code>DataSource</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DataSourceFilterVariable {
+    /// <p><code>CreatedAt</code> - Sets the search criteria to <code>DataSource</code> creation date.</p>
+    CreatedAt,
+    /// <p><code>LastUpdatedAt</code></p>
+    LastUpdatedAt,
+    /// <p><code>Status</code> - Sets the search criteria to <code>DataSource</code> status.</p>
+    Status,
+    /// <p><code>Name</code> - Sets the search criteria to the contents of <code>DataSource</code> <code>Name</code>.</p>
+    Name,
+    /// <p><code>DataLocationS3</code></p>
+    DataLocationS3,
+    /// <p><code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>DataSource</code> creation.</p>
+    IAMUser,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DataSourceFilterVariable {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSourceFilterVariable::CreatedAt => "CreatedAt",
+            DataSourceFilterVariable::LastUpdatedAt => "LastUpdatedAt",
+            DataSourceFilterVariable::Status => "Status",
+            DataSourceFilterVariable::Name => "Name",
+            DataSourceFilterVariable::DataLocationS3 => "DataLocationS3",
+            DataSourceFilterVariable::IAMUser => "IAMUser",
+            DataSourceFilterVariable::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DataSourceFilterVariable {
+    fn from(value: &'a str) -> DataSourceFilterVariable {
+        match value {
+            "CreatedAt" => DataSourceFilterVariable::CreatedAt,
+            "LastUpdatedAt" => DataSourceFilterVariable::LastUpdatedAt,
+            "Status" => DataSourceFilterVariable::Status,
+            "Name" => DataSourceFilterVariable::Name,
+            "DataLocationS3" => DataSourceFilterVariable::DataLocationS3,
+            "IAMUser" => DataSourceFilterVariable::IAMUser,
+            _ => DataSourceFilterVariable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSourceFilterVariable {
+    fn from(value: String) -> DataSourceFilterVariable {
+        DataSourceFilterVariable::from(value.as_str())
+    }
+}
+
+impl From<DataSourceFilterVariable> for String {
+    fn from(value: DataSourceFilterVariable) -> String {
+        match value {
+            DataSourceFilterVariable::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DataSourceFilterVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DataSourceFilterVariable {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSourceFilterVariable {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DataSourceFilterVariable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DataSourceFilterVariable::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -401,6 +1153,38 @@ pub struct DeleteBatchPredictionInput {
     pub batch_prediction_id: String,
 }
 
+impl DeleteBatchPredictionInput {
+    /// Returns a builder for `DeleteBatchPredictionInput`, checking its required members when built.
+    pub fn builder() -> DeleteBatchPredictionInputBuilder {
+        DeleteBatchPredictionInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBatchPredictionInput`](struct.DeleteBatchPredictionInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteBatchPredictionInputBuilder {
+    batch_prediction_id: Option<String>,
+}
+
+impl DeleteBatchPredictionInputBuilder {
+    /// Sets the [`batch_prediction_id`](struct.DeleteBatchPredictionInput.html#structfield.batch_prediction_id) member.
+    pub fn batch_prediction_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.batch_prediction_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBatchPredictionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteBatchPredictionInput, ::rusoto_core::BuildError> {
+        Ok(DeleteBatchPredictionInput {
+            batch_prediction_id: self
+                .batch_prediction_id
+                .ok_or(::rusoto_core::BuildError::missing("batch_prediction_id"))?,
+        })
+    }
+}
+
 /// <p> Represents the output of a <code>DeleteBatchPrediction</code> operation.</p> <p>You can use the <code>GetBatchPrediction</code> operation and check the value of the <code>Status</code> parameter to see whether a <code>BatchPrediction</code> is marked as <code>DELETED</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -418,6 +1202,36 @@ pub struct DeleteDataSourceInput {
     pub data_source_id: String,
 }
 
+impl DeleteDataSourceInput {
+    /// Returns a builder for `DeleteDataSourceInput`, checking its required members when built.
+    pub fn builder() -> DeleteDataSourceInputBuilder {
+        DeleteDataSourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDataSourceInput`](struct.DeleteDataSourceInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteDataSourceInputBuilder {
+    data_source_id: Option<String>,
+}
+
+impl DeleteDataSourceInputBuilder {
+    /// Sets the [`data_source_id`](struct.DeleteDataSourceInput.html#structfield.data_source_id) member.
+    pub fn data_source_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDataSourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDataSourceInput, ::rusoto_core::BuildError> {
+        Ok(DeleteDataSourceInput {
+            data_source_id: self
+                .data_source_id
+                .ok_or(::rusoto_core::BuildError::missing("data_source_id"))?,
+        })
+    }
+}
+
 /// <p> Represents the output of a <code>DeleteDataSource</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -435,6 +1249,36 @@ pub struct DeleteEvaluationInput {
     pub evaluation_id: String,
 }
 
+impl DeleteEvaluationInput {
+    /// Returns a builder for `DeleteEvaluationInput`, checking its required members when built.
+    pub fn builder() -> DeleteEvaluationInputBuilder {
+        DeleteEvaluationInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEvaluationInput`](struct.DeleteEvaluationInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteEvaluationInputBuilder {
+    evaluation_id: Option<String>,
+}
+
+impl DeleteEvaluationInputBuilder {
+    /// Sets the [`evaluation_id`](struct.DeleteEvaluationInput.html#structfield.evaluation_id) member.
+    pub fn evaluation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.evaluation_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEvaluationInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteEvaluationInput, ::rusoto_core::BuildError> {
+        Ok(DeleteEvaluationInput {
+            evaluation_id: self
+                .evaluation_id
+                .ok_or(::rusoto_core::BuildError::missing("evaluation_id"))?,
+        })
+    }
+}
+
 /// <p> Represents the output of a <code>DeleteEvaluation</code> operation. The output indicates that Amazon Machine Learning (Amazon ML) received the request.</p> <p>You can use the <code>GetEvaluation</code> operation and check the value of the <code>Status</code> parameter to see whether an <code>Evaluation</code> is marked as <code>DELETED</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -452,6 +1296,36 @@ pub struct DeleteMLModelInput {
     pub ml_model_id: String,
 }
 
+impl DeleteMLModelInput {
+    /// Returns a builder for `DeleteMLModelInput`, checking its required members when built.
+    pub fn builder() -> DeleteMLModelInputBuilder {
+        DeleteMLModelInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteMLModelInput`](struct.DeleteMLModelInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteMLModelInputBuilder {
+    ml_model_id: Option<String>,
+}
+
+impl DeleteMLModelInputBuilder {
+    /// Sets the [`ml_model_id`](struct.DeleteMLModelInput.html#structfield.ml_model_id) member.
+    pub fn ml_model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ml_model_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteMLModelInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteMLModelInput, ::rusoto_core::BuildError> {
+        Ok(DeleteMLModelInput {
+            ml_model_id: self
+                .ml_model_id
+                .ok_or(::rusoto_core::BuildError::missing("ml_model_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>DeleteMLModel</code> operation.</p> <p>You can use the <code>GetMLModel</code> operation and check the value of the <code>Status</code> parameter to see whether an <code>MLModel</code> is marked as <code>DELETED</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -469,6 +1343,38 @@ pub struct DeleteRealtimeEndpointInput {
     pub ml_model_id: String,
 }
 
+impl DeleteRealtimeEndpointInput {
+    /// Returns a builder for `DeleteRealtimeEndpointInput`, checking its required members when built.
+    pub fn builder() -> DeleteRealtimeEndpointInputBuilder {
+        DeleteRealtimeEndpointInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteRealtimeEndpointInput`](struct.DeleteRealtimeEndpointInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteRealtimeEndpointInputBuilder {
+    ml_model_id: Option<String>,
+}
+
+impl DeleteRealtimeEndpointInputBuilder {
+    /// Sets the [`ml_model_id`](struct.DeleteRealtimeEndpointInput.html#structfield.ml_model_id) member.
+    pub fn ml_model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ml_model_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteRealtimeEndpointInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteRealtimeEndpointInput, ::rusoto_core::BuildError> {
+        Ok(DeleteRealtimeEndpointInput {
+            ml_model_id: self
+                .ml_model_id
+                .ok_or(::rusoto_core::BuildError::missing("ml_model_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of an <code>DeleteRealtimeEndpoint</code> operation.</p> <p>The result contains the <code>MLModelId</code> and the endpoint information for the <code>MLModel</code>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -483,19 +1389,69 @@ pub struct DeleteRealtimeEndpointOutput {
     pub realtime_endpoint_info: Option<RealtimeEndpointInfo>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteTagsInput {
     /// <p>The ID of the tagged ML object. For example, <code>exampleModelId</code>.</p>
     #[serde(rename = "ResourceId")]
     pub resource_id: String,
     /// <p>The type of the tagged ML object.</p>
     #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+    pub resource_type: TaggableResourceType,
     /// <p>One or more tags to delete.</p>
     #[serde(rename = "TagKeys")]
     pub tag_keys: Vec<String>,
 }
 
+impl DeleteTagsInput {
+    /// Returns a builder for `DeleteTagsInput`, checking its required members when built.
+    pub fn builder() -> DeleteTagsInputBuilder {
+        DeleteTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTagsInput`](struct.DeleteTagsInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteTagsInputBuilder {
+    resource_id: Option<String>,
+    resource_type: Option<TaggableResourceType>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl DeleteTagsInputBuilder {
+    /// Sets the [`resource_id`](struct.DeleteTagsInput.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.DeleteTagsInput.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<TaggableResourceType>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DeleteTagsInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTagsInput, ::rusoto_core::BuildError> {
+        Ok(DeleteTagsInput {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            resource_type: self
+                .resource_type
+                .ok_or(::rusoto_core::BuildError::missing("resource_type"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p>Amazon ML returns the following elements. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -507,7 +1463,7 @@ pub struct DeleteTagsOutput {
     /// <p>The type of the ML object from which tags were deleted.</p>
     #[serde(rename = "ResourceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
+    pub resource_type: Option<TaggableResourceType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -519,7 +1475,7 @@ pub struct DescribeBatchPredictionsInput {
     /// <p><p>Use one of the following variables to filter a list of <code>BatchPrediction</code>:</p> <ul> <li> <code>CreatedAt</code> - Sets the search criteria to the <code>BatchPrediction</code> creation date.</li> <li> <code>Status</code> - Sets the search criteria to the <code>BatchPrediction</code> status.</li> <li> <code>Name</code> - Sets the search criteria to the contents of the <code>BatchPrediction</code><b> </b> <code>Name</code>.</li> <li> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>BatchPrediction</code> creation.</li> <li> <code>MLModelId</code> - Sets the search criteria to the <code>MLModel</code> used in the <code>BatchPrediction</code>.</li> <li> <code>DataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used in the <code>BatchPrediction</code>.</li> <li> <code>DataURI</code> - Sets the search criteria to the data file(s) used in the <code>BatchPrediction</code>. The URL can identify either a file or an Amazon Simple Storage Solution (Amazon S3) bucket or directory.</li> </ul></p>
     #[serde(rename = "FilterVariable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_variable: Option<String>,
+    pub filter_variable: Option<BatchPredictionFilterVariable>,
     /// <p>The greater than or equal to operator. The <code>BatchPrediction</code> results will have <code>FilterVariable</code> values that are greater than or equal to the value specified with <code>GE</code>. </p>
     #[serde(rename = "GE")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -555,7 +1511,117 @@ pub struct DescribeBatchPredictionsInput {
     /// <p>A two-value parameter that determines the sequence of the resulting list of <code>MLModel</code>s.</p> <ul> <li> <code>asc</code> - Arranges the list in ascending order (A-Z, 0-9).</li> <li> <code>dsc</code> - Arranges the list in descending order (Z-A, 9-0).</li> </ul> <p>Results are sorted by <code>FilterVariable</code>.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
+}
+
+impl DescribeBatchPredictionsInput {
+    /// Returns a builder for `DescribeBatchPredictionsInput`, checking its required members when built.
+    pub fn builder() -> DescribeBatchPredictionsInputBuilder {
+        DescribeBatchPredictionsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBatchPredictionsInput`](struct.DescribeBatchPredictionsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeBatchPredictionsInputBuilder {
+    eq: Option<String>,
+    filter_variable: Option<BatchPredictionFilterVariable>,
+    ge: Option<String>,
+    gt: Option<String>,
+    le: Option<String>,
+    lt: Option<String>,
+    limit: Option<i64>,
+    ne: Option<String>,
+    next_token: Option<String>,
+    prefix: Option<String>,
+    sort_order: Option<SortOrder>,
+}
+
+impl DescribeBatchPredictionsInputBuilder {
+    /// Sets the [`eq`](struct.DescribeBatchPredictionsInput.html#structfield.eq) member.
+    pub fn eq<T: Into<String>>(mut self, value: T) -> Self {
+        self.eq = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_variable`](struct.DescribeBatchPredictionsInput.html#structfield.filter_variable) member.
+    pub fn filter_variable<T: Into<BatchPredictionFilterVariable>>(mut self, value: T) -> Self {
+        self.filter_variable = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ge`](struct.DescribeBatchPredictionsInput.html#structfield.ge) member.
+    pub fn ge<T: Into<String>>(mut self, value: T) -> Self {
+        self.ge = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gt`](struct.DescribeBatchPredictionsInput.html#structfield.gt) member.
+    pub fn gt<T: Into<String>>(mut self, value: T) -> Self {
+        self.gt = Some(value.into());
+        self
+    }
+
+    /// Sets the [`le`](struct.DescribeBatchPredictionsInput.html#structfield.le) member.
+    pub fn le<T: Into<String>>(mut self, value: T) -> Self {
+        self.le = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lt`](struct.DescribeBatchPredictionsInput.html#structfield.lt) member.
+    pub fn lt<T: Into<String>>(mut self, value: T) -> Self {
+        self.lt = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeBatchPredictionsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ne`](struct.DescribeBatchPredictionsInput.html#structfield.ne) member.
+    pub fn ne<T: Into<String>>(mut self, value: T) -> Self {
+        self.ne = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeBatchPredictionsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`prefix`](struct.DescribeBatchPredictionsInput.html#structfield.prefix) member.
+    pub fn prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_order`](struct.DescribeBatchPredictionsInput.html#structfield.sort_order) member.
+    pub fn sort_order<T: Into<SortOrder>>(mut self, value: T) -> Self {
+        self.sort_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBatchPredictionsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeBatchPredictionsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeBatchPredictionsInput {
+            eq: self.eq,
+            filter_variable: self.filter_variable,
+            ge: self.ge,
+            gt: self.gt,
+            le: self.le,
+            lt: self.lt,
+            limit: self.limit,
+            ne: self.ne,
+            next_token: self.next_token,
+            prefix: self.prefix,
+            sort_order: self.sort_order,
+        })
+    }
 }
 
 /// <p>Represents the output of a <code>DescribeBatchPredictions</code> operation. The content is essentially a list of <code>BatchPrediction</code>s.</p>
@@ -581,7 +1647,7 @@ pub struct DescribeDataSourcesInput {
     /// <p><p>Use one of the following variables to filter a list of <code>DataSource</code>:</p> <ul> <li> <code>CreatedAt</code> - Sets the search criteria to <code>DataSource</code> creation dates.</li> <li> <code>Status</code> - Sets the search criteria to <code>DataSource</code> statuses.</li> <li> <code>Name</code> - Sets the search criteria to the contents of <code>DataSource</code> <b> </b> <code>Name</code>.</li> <li> <code>DataUri</code> - Sets the search criteria to the URI of data files used to create the <code>DataSource</code>. The URI can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> <li> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>DataSource</code> creation.</li> </ul></p>
     #[serde(rename = "FilterVariable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_variable: Option<String>,
+    pub filter_variable: Option<DataSourceFilterVariable>,
     /// <p>The greater than or equal to operator. The <code>DataSource</code> results will have <code>FilterVariable</code> values that are greater than or equal to the value specified with <code>GE</code>. </p>
     #[serde(rename = "GE")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -617,7 +1683,117 @@ pub struct DescribeDataSourcesInput {
     /// <p>A two-value parameter that determines the sequence of the resulting list of <code>DataSource</code>.</p> <ul> <li> <code>asc</code> - Arranges the list in ascending order (A-Z, 0-9).</li> <li> <code>dsc</code> - Arranges the list in descending order (Z-A, 9-0).</li> </ul> <p>Results are sorted by <code>FilterVariable</code>.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
+}
+
+impl DescribeDataSourcesInput {
+    /// Returns a builder for `DescribeDataSourcesInput`, checking its required members when built.
+    pub fn builder() -> DescribeDataSourcesInputBuilder {
+        DescribeDataSourcesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDataSourcesInput`](struct.DescribeDataSourcesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeDataSourcesInputBuilder {
+    eq: Option<String>,
+    filter_variable: Option<DataSourceFilterVariable>,
+    ge: Option<String>,
+    gt: Option<String>,
+    le: Option<String>,
+    lt: Option<String>,
+    limit: Option<i64>,
+    ne: Option<String>,
+    next_token: Option<String>,
+    prefix: Option<String>,
+    sort_order: Option<SortOrder>,
+}
+
+impl DescribeDataSourcesInputBuilder {
+    /// Sets the [`eq`](struct.DescribeDataSourcesInput.html#structfield.eq) member.
+    pub fn eq<T: Into<String>>(mut self, value: T) -> Self {
+        self.eq = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_variable`](struct.DescribeDataSourcesInput.html#structfield.filter_variable) member.
+    pub fn filter_variable<T: Into<DataSourceFilterVariable>>(mut self, value: T) -> Self {
+        self.filter_variable = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ge`](struct.DescribeDataSourcesInput.html#structfield.ge) member.
+    pub fn ge<T: Into<String>>(mut self, value: T) -> Self {
+        self.ge = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gt`](struct.DescribeDataSourcesInput.html#structfield.gt) member.
+    pub fn gt<T: Into<String>>(mut self, value: T) -> Self {
+        self.gt = Some(value.into());
+        self
+    }
+
+    /// Sets the [`le`](struct.DescribeDataSourcesInput.html#structfield.le) member.
+    pub fn le<T: Into<String>>(mut self, value: T) -> Self {
+        self.le = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lt`](struct.DescribeDataSourcesInput.html#structfield.lt) member.
+    pub fn lt<T: Into<String>>(mut self, value: T) -> Self {
+        self.lt = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeDataSourcesInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ne`](struct.DescribeDataSourcesInput.html#structfield.ne) member.
+    pub fn ne<T: Into<String>>(mut self, value: T) -> Self {
+        self.ne = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeDataSourcesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`prefix`](struct.DescribeDataSourcesInput.html#structfield.prefix) member.
+    pub fn prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_order`](struct.DescribeDataSourcesInput.html#structfield.sort_order) member.
+    pub fn sort_order<T: Into<SortOrder>>(mut self, value: T) -> Self {
+        self.sort_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDataSourcesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDataSourcesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeDataSourcesInput {
+            eq: self.eq,
+            filter_variable: self.filter_variable,
+            ge: self.ge,
+            gt: self.gt,
+            le: self.le,
+            lt: self.lt,
+            limit: self.limit,
+            ne: self.ne,
+            next_token: self.next_token,
+            prefix: self.prefix,
+            sort_order: self.sort_order,
+        })
+    }
 }
 
 /// <p>Represents the query results from a <a>DescribeDataSources</a> operation. The content is essentially a list of <code>DataSource</code>.</p>
@@ -643,7 +1819,7 @@ pub struct DescribeEvaluationsInput {
     /// <p><p>Use one of the following variable to filter a list of <code>Evaluation</code> objects:</p> <ul> <li> <code>CreatedAt</code> - Sets the search criteria to the <code>Evaluation</code> creation date.</li> <li> <code>Status</code> - Sets the search criteria to the <code>Evaluation</code> status.</li> <li> <code>Name</code> - Sets the search criteria to the contents of <code>Evaluation</code> <b> </b> <code>Name</code>.</li> <li> <code>IAMUser</code> - Sets the search criteria to the user account that invoked an <code>Evaluation</code>.</li> <li> <code>MLModelId</code> - Sets the search criteria to the <code>MLModel</code> that was evaluated.</li> <li> <code>DataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used in <code>Evaluation</code>.</li> <li> <code>DataUri</code> - Sets the search criteria to the data file(s) used in <code>Evaluation</code>. The URL can identify either a file or an Amazon Simple Storage Solution (Amazon S3) bucket or directory.</li> </ul></p>
     #[serde(rename = "FilterVariable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_variable: Option<String>,
+    pub filter_variable: Option<EvaluationFilterVariable>,
     /// <p>The greater than or equal to operator. The <code>Evaluation</code> results will have <code>FilterVariable</code> values that are greater than or equal to the value specified with <code>GE</code>. </p>
     #[serde(rename = "GE")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -679,7 +1855,117 @@ pub struct DescribeEvaluationsInput {
     /// <p>A two-value parameter that determines the sequence of the resulting list of <code>Evaluation</code>.</p> <ul> <li> <code>asc</code> - Arranges the list in ascending order (A-Z, 0-9).</li> <li> <code>dsc</code> - Arranges the list in descending order (Z-A, 9-0).</li> </ul> <p>Results are sorted by <code>FilterVariable</code>.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
+}
+
+impl DescribeEvaluationsInput {
+    /// Returns a builder for `DescribeEvaluationsInput`, checking its required members when built.
+    pub fn builder() -> DescribeEvaluationsInputBuilder {
+        DescribeEvaluationsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEvaluationsInput`](struct.DescribeEvaluationsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeEvaluationsInputBuilder {
+    eq: Option<String>,
+    filter_variable: Option<EvaluationFilterVariable>,
+    ge: Option<String>,
+    gt: Option<String>,
+    le: Option<String>,
+    lt: Option<String>,
+    limit: Option<i64>,
+    ne: Option<String>,
+    next_token: Option<String>,
+    prefix: Option<String>,
+    sort_order: Option<SortOrder>,
+}
+
+impl DescribeEvaluationsInputBuilder {
+    /// Sets the [`eq`](struct.DescribeEvaluationsInput.html#structfield.eq) member.
+    pub fn eq<T: Into<String>>(mut self, value: T) -> Self {
+        self.eq = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_variable`](struct.DescribeEvaluationsInput.html#structfield.filter_variable) member.
+    pub fn filter_variable<T: Into<EvaluationFilterVariable>>(mut self, value: T) -> Self {
+        self.filter_variable = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ge`](struct.DescribeEvaluationsInput.html#structfield.ge) member.
+    pub fn ge<T: Into<String>>(mut self, value: T) -> Self {
+        self.ge = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gt`](struct.DescribeEvaluationsInput.html#structfield.gt) member.
+    pub fn gt<T: Into<String>>(mut self, value: T) -> Self {
+        self.gt = Some(value.into());
+        self
+    }
+
+    /// Sets the [`le`](struct.DescribeEvaluationsInput.html#structfield.le) member.
+    pub fn le<T: Into<String>>(mut self, value: T) -> Self {
+        self.le = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lt`](struct.DescribeEvaluationsInput.html#structfield.lt) member.
+    pub fn lt<T: Into<String>>(mut self, value: T) -> Self {
+        self.lt = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeEvaluationsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ne`](struct.DescribeEvaluationsInput.html#structfield.ne) member.
+    pub fn ne<T: Into<String>>(mut self, value: T) -> Self {
+        self.ne = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeEvaluationsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`prefix`](struct.DescribeEvaluationsInput.html#structfield.prefix) member.
+    pub fn prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_order`](struct.DescribeEvaluationsInput.html#structfield.sort_order) member.
+    pub fn sort_order<T: Into<SortOrder>>(mut self, value: T) -> Self {
+        self.sort_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEvaluationsInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEvaluationsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeEvaluationsInput {
+            eq: self.eq,
+            filter_variable: self.filter_variable,
+            ge: self.ge,
+            gt: self.gt,
+            le: self.le,
+            lt: self.lt,
+            limit: self.limit,
+            ne: self.ne,
+            next_token: self.next_token,
+            prefix: self.prefix,
+            sort_order: self.sort_order,
+        })
+    }
 }
 
 /// <p>Represents the query results from a <code>DescribeEvaluations</code> operation. The content is essentially a list of <code>Evaluation</code>.</p>
@@ -705,7 +1991,7 @@ pub struct DescribeMLModelsInput {
     /// <p><p>Use one of the following variables to filter a list of <code>MLModel</code>:</p> <ul> <li> <code>CreatedAt</code> - Sets the search criteria to <code>MLModel</code> creation date.</li> <li> <code>Status</code> - Sets the search criteria to <code>MLModel</code> status.</li> <li> <code>Name</code> - Sets the search criteria to the contents of <code>MLModel</code><b> </b> <code>Name</code>.</li> <li> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>MLModel</code> creation.</li> <li> <code>TrainingDataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used to train one or more <code>MLModel</code>.</li> <li> <code>RealtimeEndpointStatus</code> - Sets the search criteria to the <code>MLModel</code> real-time endpoint status.</li> <li> <code>MLModelType</code> - Sets the search criteria to <code>MLModel</code> type: binary, regression, or multi-class.</li> <li> <code>Algorithm</code> - Sets the search criteria to the algorithm that the <code>MLModel</code> uses.</li> <li> <code>TrainingDataURI</code> - Sets the search criteria to the data file(s) used in training a <code>MLModel</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> </ul></p>
     #[serde(rename = "FilterVariable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter_variable: Option<String>,
+    pub filter_variable: Option<MLModelFilterVariable>,
     /// <p>The greater than or equal to operator. The <code>MLModel</code> results will have <code>FilterVariable</code> values that are greater than or equal to the value specified with <code>GE</code>. </p>
     #[serde(rename = "GE")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -741,49 +2027,353 @@ pub struct DescribeMLModelsInput {
     /// <p>A two-value parameter that determines the sequence of the resulting list of <code>MLModel</code>.</p> <ul> <li> <code>asc</code> - Arranges the list in ascending order (A-Z, 0-9).</li> <li> <code>dsc</code> - Arranges the list in descending order (Z-A, 9-0).</li> </ul> <p>Results are sorted by <code>FilterVariable</code>.</p>
     #[serde(rename = "SortOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortOrder>,
+}
+
+impl DescribeMLModelsInput {
+    /// Returns a builder for `DescribeMLModelsInput`, checking its required members when built.
+    pub fn builder() -> DescribeMLModelsInputBuilder {
+        DescribeMLModelsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeMLModelsInput`](struct.DescribeMLModelsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeMLModelsInputBuilder {
+    eq: Option<String>,
+    filter_variable: Option<MLModelFilterVariable>,
+    ge: Option<String>,
+    gt: Option<String>,
+    le: Option<String>,
+    lt: Option<String>,
+    limit: Option<i64>,
+    ne: Option<String>,
+    next_token: Option<String>,
+    prefix: Option<String>,
+    sort_order: Option<SortOrder>,
+}
+
+impl DescribeMLModelsInputBuilder {
+    /// Sets the [`eq`](struct.DescribeMLModelsInput.html#structfield.eq) member.
+    pub fn eq<T: Into<String>>(mut self, value: T) -> Self {
+        self.eq = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter_variable`](struct.DescribeMLModelsInput.html#structfield.filter_variable) member.
+    pub fn filter_variable<T: Into<MLModelFilterVariable>>(mut self, value: T) -> Self {
+        self.filter_variable = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ge`](struct.DescribeMLModelsInput.html#structfield.ge) member.
+    pub fn ge<T: Into<String>>(mut self, value: T) -> Self {
+        self.ge = Some(value.into());
+        self
+    }
+
+    /// Sets the [`gt`](struct.DescribeMLModelsInput.html#structfield.gt) member.
+    pub fn gt<T: Into<String>>(mut self, value: T) -> Self {
+        self.gt = Some(value.into());
+        self
+    }
+
+    /// Sets the [`le`](struct.DescribeMLModelsInput.html#structfield.le) member.
+    pub fn le<T: Into<String>>(mut self, value: T) -> Self {
+        self.le = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lt`](struct.DescribeMLModelsInput.html#structfield.lt) member.
+    pub fn lt<T: Into<String>>(mut self, value: T) -> Self {
+        self.lt = Some(value.into());
+        self
+    }
+
+    /// Sets the [`limit`](struct.DescribeMLModelsInput.html#structfield.limit) member.
+    pub fn limit<T: Into<i64>>(mut self, value: T) -> Self {
+        self.limit = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ne`](struct.DescribeMLModelsInput.html#structfield.ne) member.
+    pub fn ne<T: Into<String>>(mut self, value: T) -> Self {
+        self.ne = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeMLModelsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`prefix`](struct.DescribeMLModelsInput.html#structfield.prefix) member.
+    pub fn prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_order`](struct.DescribeMLModelsInput.html#structfield.sort_order) member.
+    pub fn sort_order<T: Into<SortOrder>>(mut self, value: T) -> Self {
+        self.sort_order = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeMLModelsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeMLModelsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeMLModelsInput {
+            eq: self.eq,
+            filter_variable: self.filter_variable,
+            ge: self.ge,
+            gt: self.gt,
+            le: self.le,
+            lt: self.lt,
+            limit: self.limit,
+            ne: self.ne,
+            next_token: self.next_token,
+            prefix: self.prefix,
+            sort_order: self.sort_order,
+        })
+    }
+}
+
+/// <p>Represents the output of a <code>DescribeMLModels</code> operation. The content is essentially a list of <code>MLModel</code>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeMLModelsOutput {
+    /// <p>The ID of the next page in the paginated results that indicates at least one more page follows.</p>
+    #[serde(rename = "NextToken")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_token: Option<String>,
+    /// <p>A list of <code>MLModel</code> that meet the search criteria.</p>
+    #[serde(rename = "Results")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<MLModel>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DescribeTagsInput {
+    /// <p>The ID of the ML object. For example, <code>exampleModelId</code>. </p>
+    #[serde(rename = "ResourceId")]
+    pub resource_id: String,
+    /// <p>The type of the ML object.</p>
+    #[serde(rename = "ResourceType")]
+    pub resource_type: TaggableResourceType,
+}
+
+impl DescribeTagsInput {
+    /// Returns a builder for `DescribeTagsInput`, checking its required members when built.
+    pub fn builder() -> DescribeTagsInputBuilder {
+        DescribeTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTagsInput`](struct.DescribeTagsInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeTagsInputBuilder {
+    resource_id: Option<String>,
+    resource_type: Option<TaggableResourceType>,
+}
+
+impl DescribeTagsInputBuilder {
+    /// Sets the [`resource_id`](struct.DescribeTagsInput.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.DescribeTagsInput.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<TaggableResourceType>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTagsInput, ::rusoto_core::BuildError> {
+        Ok(DescribeTagsInput {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            resource_type: self
+                .resource_type
+                .ok_or(::rusoto_core::BuildError::missing("resource_type"))?,
+        })
+    }
+}
+
+/// <p>Amazon ML returns the following elements. </p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct DescribeTagsOutput {
+    /// <p>The ID of the tagged ML object.</p>
+    #[serde(rename = "ResourceId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+    /// <p>The type of the tagged ML object.</p>
+    #[serde(rename = "ResourceType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<TaggableResourceType>,
+    /// <p>A list of tags associated with the ML object.</p>
+    #[serde(rename = "Tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<Tag>>,
+}
+
+/// <p>Contains the key values of <code>DetailsMap</code>:</p> <ul> <li> <p> <code>PredictiveModelType</code> - Indicates the type of the <code>MLModel</code>.</p> </li> <li> <p> <code>Algorithm</code> - Indicates the algorithm that was used for the <code>MLModel</code>.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DetailsAttributes {
+    /// <p><code>PredictiveModelType</code> - Indicates the type of the <code>MLModel</code>.</p>
+    PredictiveModelType,
+    /// <p><code>Algorithm</code> - Indicates the algorithm that was used for the <code>MLModel</code>.</p>
+    Algorithm,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DetailsAttributes {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DetailsAttributes::PredictiveModelType => "PredictiveModelType",
+            DetailsAttributes::Algorithm => "Algorithm",
+            DetailsAttributes::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DetailsAttributes {
+    fn from(value: &'a str) -> DetailsAttributes {
+        match value {
+            "PredictiveModelType" => DetailsAttributes::PredictiveModelType,
+            "Algorithm" => DetailsAttributes::Algorithm,
+            _ => DetailsAttributes::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DetailsAttributes {
+    fn from(value: String) -> DetailsAttributes {
+        DetailsAttributes::from(value.as_str())
+    }
+}
+
+impl From<DetailsAttributes> for String {
+    fn from(value: DetailsAttributes) -> String {
+        match value {
+            DetailsAttributes::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DetailsAttributes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DetailsAttributes {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DetailsAttributes {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DetailsAttributes, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DetailsAttributes::from(value))
+    }
+}
+
+/// <p>Object status with the following possible values:</p> <ul> <li> <p> <code>PENDING</code> </p> </li> <li> <p> <code>INPROGRESS</code> </p> </li> <li> <p> <code>FAILED</code> </p> </li> <li> <p> <code>COMPLETED</code> </p> </li> <li> <p> <code>DELETED</code> </p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EntityStatus {
+    /// <p><code>PENDING</code> - Amazon Machine Learning (Amazon ML) submitted a request to generate predictions for a batch of observations.</li> <li> <code>INPROGRESS</code> - The process is underway.</li> <li> <code>FAILED</code> - The request to perform a batch prediction did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The batch prediction process completed successfully.</li> <li> <code>DELETED</code> - The <code>BatchPrediction</code> is marked as deleted. It is not usable.</li> </ul></p>
+    Pending,
+    /// <p><code>INPROGRESS</code> - The process is underway.</li> <li> <code>FAILED</code> - The request to perform a batch prediction did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The batch prediction process completed successfully.</li> <li> <code>DELETED</code> - The <code>BatchPrediction</code> is marked as deleted. It is not usable.</li> </ul></p>
+    Inprogress,
+    /// <p><code>FAILED</code> - The request to perform a batch prediction did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The batch prediction process completed successfully.</li> <li> <code>DELETED</code> - The <code>BatchPrediction</code> is marked as deleted. It is not usable.</li> </ul></p>
+    Failed,
+    /// <p><code>COMPLETED</code> - The batch prediction process completed successfully.</li> <li> <code>DELETED</code> - The <code>BatchPrediction</code> is marked as deleted. It is not usable.</li> </ul></p>
+    Completed,
+    /// <p><code>DELETED</code> - The <code>BatchPrediction</code> is marked as deleted. It is not usable.</li> </ul></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EntityStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EntityStatus::Pending => "PENDING",
+            EntityStatus::Inprogress => "INPROGRESS",
+            EntityStatus::Failed => "FAILED",
+            EntityStatus::Completed => "COMPLETED",
+            EntityStatus::Deleted => "DELETED",
+            EntityStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EntityStatus {
+    fn from(value: &'a str) -> EntityStatus {
+        match value {
+            "PENDING" => EntityStatus::Pending,
+            "INPROGRESS" => EntityStatus::Inprogress,
+            "FAILED" => EntityStatus::Failed,
+            "COMPLETED" => EntityStatus::Completed,
+            "DELETED" => EntityStatus::Deleted,
+            _ => EntityStatus::Unknown(value.to_owned()),
+        }
+    }
 }
 
-/// <p>Represents the output of a <code>DescribeMLModels</code> operation. The content is essentially a list of <code>MLModel</code>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DescribeMLModelsOutput {
-    /// <p>The ID of the next page in the paginated results that indicates at least one more page follows.</p>
-    #[serde(rename = "NextToken")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_token: Option<String>,
-    /// <p>A list of <code>MLModel</code> that meet the search criteria.</p>
-    #[serde(rename = "Results")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub results: Option<Vec<MLModel>>,
+impl From<String> for EntityStatus {
+    fn from(value: String) -> EntityStatus {
+        EntityStatus::from(value.as_str())
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct DescribeTagsInput {
-    /// <p>The ID of the ML object. For example, <code>exampleModelId</code>. </p>
-    #[serde(rename = "ResourceId")]
-    pub resource_id: String,
-    /// <p>The type of the ML object.</p>
-    #[serde(rename = "ResourceType")]
-    pub resource_type: String,
+impl From<EntityStatus> for String {
+    fn from(value: EntityStatus) -> String {
+        match value {
+            EntityStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
 }
 
-/// <p>Amazon ML returns the following elements. </p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct DescribeTagsOutput {
-    /// <p>The ID of the tagged ML object.</p>
-    #[serde(rename = "ResourceId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_id: Option<String>,
-    /// <p>The type of the tagged ML object.</p>
-    #[serde(rename = "ResourceType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_type: Option<String>,
-    /// <p>A list of tags associated with the ML object.</p>
-    #[serde(rename = "Tags")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub tags: Option<Vec<Tag>>,
+impl fmt::Display for EntityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EntityStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EntityStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EntityStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EntityStatus::from(value))
+    }
 }
 
 /// <p> Represents the output of <code>GetEvaluation</code> operation. </p> <p>The content consists of the detailed metadata and data file information and the current status of the <code>Evaluation</code>.</p>
@@ -795,8 +2385,9 @@ pub struct Evaluation {
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>Evaluation</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The AWS user account that invoked the evaluation. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -810,16 +2401,18 @@ pub struct Evaluation {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub evaluation_id: Option<String>,
     #[serde(rename = "FinishedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<EpochTimestamp>,
     /// <p>The location and name of the data in Amazon Simple Storage Server (Amazon S3) that is used in the evaluation.</p>
     #[serde(rename = "InputDataLocationS3")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_data_location_s3: Option<String>,
     /// <p>The time of the most recent edit to the <code>Evaluation</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>The ID of the <code>MLModel</code> that is the focus of the evaluation.</p>
     #[serde(rename = "MLModelId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -837,12 +2430,109 @@ pub struct Evaluation {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub performance_metrics: Option<PerformanceMetrics>,
     #[serde(rename = "StartedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<EpochTimestamp>,
     /// <p><p>The status of the evaluation. This element can have one of the following values:</p> <ul> <li> <code>PENDING</code> - Amazon Machine Learning (Amazon ML) submitted a request to evaluate an <code>MLModel</code>.</li> <li> <code>INPROGRESS</code> - The evaluation is underway.</li> <li> <code>FAILED</code> - The request to evaluate an <code>MLModel</code> did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The evaluation process completed successfully.</li> <li> <code>DELETED</code> - The <code>Evaluation</code> is marked as deleted. It is not usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
+}
+
+/// <p>A list of the variables to use in searching or filtering <code>Evaluation</code>.</p> <ul> <li> <p> <code>CreatedAt</code> - Sets the search criteria to <code>Evaluation</code> creation date.</p> </li> <li> <p> <code>Status</code> - Sets the search criteria to <code>Evaluation</code> status.</p> </li> <li> <p> <code>Name</code> - Sets the search criteria to the contents of <code>Evaluation</code> <b> </b> <code>Name</code>.</p> </li> <li> <p> <code>IAMUser</code> - Sets the search criteria to the user account that invoked an evaluation.</p> </li> <li> <p> <code>MLModelId</code> - Sets the search criteria to the <code>Predictor</code> that was evaluated.</p> </li> <li> <p> <code>DataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used in evaluation.</p> </li> <li> <p> <code>DataUri</code> - Sets the search criteria to the data file(s) used in evaluation. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EvaluationFilterVariable {
+    /// <p><code>CreatedAt</code> - Sets the search criteria to <code>Evaluation</code> creation date.</p>
+    CreatedAt,
+    /// <p><code>LastUpdatedAt</code></p>
+    LastUpdatedAt,
+    /// <p><code>Status</code> - Sets the search criteria to <code>Evaluation</code> status.</p>
+    Status,
+    /// <p><code>Name</code> - Sets the search criteria to the contents of <code>Evaluation</code> <b> </b> <code>Name</code>.</p>
+    Name,
+    /// <p><code>IAMUser</code> - Sets the search criteria to the user account that invoked an evaluation.</p>
+    IAMUser,
+    /// <p><code>MLModelId</code> - Sets the search criteria to the <code>Predictor</code> that was evaluated.</p>
+    MLModelId,
+    /// <p><code>DataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used in evaluation.</p>
+    DataSourceId,
+    /// <p><code>DataURI</code></p>
+    DataURI,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EvaluationFilterVariable {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EvaluationFilterVariable::CreatedAt => "CreatedAt",
+            EvaluationFilterVariable::LastUpdatedAt => "LastUpdatedAt",
+            EvaluationFilterVariable::Status => "Status",
+            EvaluationFilterVariable::Name => "Name",
+            EvaluationFilterVariable::IAMUser => "IAMUser",
+            EvaluationFilterVariable::MLModelId => "MLModelId",
+            EvaluationFilterVariable::DataSourceId => "DataSourceId",
+            EvaluationFilterVariable::DataURI => "DataURI",
+            EvaluationFilterVariable::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EvaluationFilterVariable {
+    fn from(value: &'a str) -> EvaluationFilterVariable {
+        match value {
+            "CreatedAt" => EvaluationFilterVariable::CreatedAt,
+            "LastUpdatedAt" => EvaluationFilterVariable::LastUpdatedAt,
+            "Status" => EvaluationFilterVariable::Status,
+            "Name" => EvaluationFilterVariable::Name,
+            "IAMUser" => EvaluationFilterVariable::IAMUser,
+            "MLModelId" => EvaluationFilterVariable::MLModelId,
+            "DataSourceId" => EvaluationFilterVariable::DataSourceId,
+            "DataURI" => EvaluationFilterVariable::DataURI,
+            _ => EvaluationFilterVariable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EvaluationFilterVariable {
+    fn from(value: String) -> EvaluationFilterVariable {
+        EvaluationFilterVariable::from(value.as_str())
+    }
+}
+
+impl From<EvaluationFilterVariable> for String {
+    fn from(value: EvaluationFilterVariable) -> String {
+        match value {
+            EvaluationFilterVariable::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EvaluationFilterVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EvaluationFilterVariable {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EvaluationFilterVariable {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EvaluationFilterVariable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EvaluationFilterVariable::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -852,6 +2542,38 @@ pub struct GetBatchPredictionInput {
     pub batch_prediction_id: String,
 }
 
+impl GetBatchPredictionInput {
+    /// Returns a builder for `GetBatchPredictionInput`, checking its required members when built.
+    pub fn builder() -> GetBatchPredictionInputBuilder {
+        GetBatchPredictionInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetBatchPredictionInput`](struct.GetBatchPredictionInput.html).
+#[derive(Default, Debug)]
+pub struct GetBatchPredictionInputBuilder {
+    batch_prediction_id: Option<String>,
+}
+
+impl GetBatchPredictionInputBuilder {
+    /// Sets the [`batch_prediction_id`](struct.GetBatchPredictionInput.html#structfield.batch_prediction_id) member.
+    pub fn batch_prediction_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.batch_prediction_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBatchPredictionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetBatchPredictionInput, ::rusoto_core::BuildError> {
+        Ok(GetBatchPredictionInput {
+            batch_prediction_id: self
+                .batch_prediction_id
+                .ok_or(::rusoto_core::BuildError::missing("batch_prediction_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>GetBatchPrediction</code> operation and describes a <code>BatchPrediction</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -870,16 +2592,18 @@ pub struct GetBatchPredictionOutput {
     pub compute_time: Option<i64>,
     /// <p>The time when the <code>BatchPrediction</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The AWS user account that invoked the <code>BatchPrediction</code>. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by_iam_user: Option<String>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>BatchPrediction</code> as <code>COMPLETED</code> or <code>FAILED</code>. <code>FinishedAt</code> is only available when the <code>BatchPrediction</code> is in the <code>COMPLETED</code> or <code>FAILED</code> state.</p>
     #[serde(rename = "FinishedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<EpochTimestamp>,
     /// <p>The location of the data file or directory in Amazon Simple Storage Service (Amazon S3).</p>
     #[serde(rename = "InputDataLocationS3")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -890,8 +2614,9 @@ pub struct GetBatchPredictionOutput {
     pub invalid_record_count: Option<i64>,
     /// <p>The time of the most recent edit to <code>BatchPrediction</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>A link to the file that contains logs of the <code>CreateBatchPrediction</code> operation.</p>
     #[serde(rename = "LogUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -914,12 +2639,13 @@ pub struct GetBatchPredictionOutput {
     pub output_uri: Option<String>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>BatchPrediction</code> as <code>INPROGRESS</code>. <code>StartedAt</code> isn't available if the <code>BatchPrediction</code> is in the <code>PENDING</code> state.</p>
     #[serde(rename = "StartedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<EpochTimestamp>,
     /// <p><p>The status of the <code>BatchPrediction</code>, which can be one of the following values:</p> <ul> <li> <code>PENDING</code> - Amazon Machine Learning (Amazon ML) submitted a request to generate batch predictions.</li> <li> <code>INPROGRESS</code> - The batch predictions are in progress.</li> <li> <code>FAILED</code> - The request to perform a batch prediction did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The batch prediction process completed successfully.</li> <li> <code>DELETED</code> - The <code>BatchPrediction</code> is marked as deleted. It is not usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
     /// <p>The number of total records that Amazon Machine Learning saw while processing the <code>BatchPrediction</code>.</p>
     #[serde(rename = "TotalRecordCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -937,6 +2663,44 @@ pub struct GetDataSourceInput {
     pub verbose: Option<bool>,
 }
 
+impl GetDataSourceInput {
+    /// Returns a builder for `GetDataSourceInput`, checking its required members when built.
+    pub fn builder() -> GetDataSourceInputBuilder {
+        GetDataSourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetDataSourceInput`](struct.GetDataSourceInput.html).
+#[derive(Default, Debug)]
+pub struct GetDataSourceInputBuilder {
+    data_source_id: Option<String>,
+    verbose: Option<bool>,
+}
+
+impl GetDataSourceInputBuilder {
+    /// Sets the [`data_source_id`](struct.GetDataSourceInput.html#structfield.data_source_id) member.
+    pub fn data_source_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`verbose`](struct.GetDataSourceInput.html#structfield.verbose) member.
+    pub fn verbose<T: Into<bool>>(mut self, value: T) -> Self {
+        self.verbose = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDataSourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDataSourceInput, ::rusoto_core::BuildError> {
+        Ok(GetDataSourceInput {
+            data_source_id: self
+                .data_source_id
+                .ok_or(::rusoto_core::BuildError::missing("data_source_id"))?,
+            verbose: self.verbose,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>GetDataSource</code> operation and describes a <code>DataSource</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -951,8 +2715,9 @@ pub struct GetDataSourceOutput {
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>DataSource</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The AWS user account from which the <code>DataSource</code> was created. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -979,12 +2744,14 @@ pub struct GetDataSourceOutput {
     pub data_source_schema: Option<String>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>DataSource</code> as <code>COMPLETED</code> or <code>FAILED</code>. <code>FinishedAt</code> is only available when the <code>DataSource</code> is in the <code>COMPLETED</code> or <code>FAILED</code> state.</p>
     #[serde(rename = "FinishedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<EpochTimestamp>,
     /// <p>The time of the most recent edit to the <code>DataSource</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>A link to the file containing logs of <code>CreateDataSourceFrom*</code> operations.</p>
     #[serde(rename = "LogUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1012,12 +2779,13 @@ pub struct GetDataSourceOutput {
     pub role_arn: Option<String>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>DataSource</code> as <code>INPROGRESS</code>. <code>StartedAt</code> isn't available if the <code>DataSource</code> is in the <code>PENDING</code> state.</p>
     #[serde(rename = "StartedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<EpochTimestamp>,
     /// <p><p>The current status of the <code>DataSource</code>. This element can have one of the following values:</p> <ul> <li> <code>PENDING</code> - Amazon ML submitted a request to create a <code>DataSource</code>.</li> <li> <code>INPROGRESS</code> - The creation process is underway.</li> <li> <code>FAILED</code> - The request to create a <code>DataSource</code> did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The creation process completed successfully.</li> <li> <code>DELETED</code> - The <code>DataSource</code> is marked as deleted. It is not usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1027,6 +2795,36 @@ pub struct GetEvaluationInput {
     pub evaluation_id: String,
 }
 
+impl GetEvaluationInput {
+    /// Returns a builder for `GetEvaluationInput`, checking its required members when built.
+    pub fn builder() -> GetEvaluationInputBuilder {
+        GetEvaluationInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetEvaluationInput`](struct.GetEvaluationInput.html).
+#[derive(Default, Debug)]
+pub struct GetEvaluationInputBuilder {
+    evaluation_id: Option<String>,
+}
+
+impl GetEvaluationInputBuilder {
+    /// Sets the [`evaluation_id`](struct.GetEvaluationInput.html#structfield.evaluation_id) member.
+    pub fn evaluation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.evaluation_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetEvaluationInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetEvaluationInput, ::rusoto_core::BuildError> {
+        Ok(GetEvaluationInput {
+            evaluation_id: self
+                .evaluation_id
+                .ok_or(::rusoto_core::BuildError::missing("evaluation_id"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>GetEvaluation</code> operation and describes an <code>Evaluation</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1037,8 +2835,9 @@ pub struct GetEvaluationOutput {
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>Evaluation</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The AWS user account that invoked the evaluation. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1053,16 +2852,18 @@ pub struct GetEvaluationOutput {
     pub evaluation_id: Option<String>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>Evaluation</code> as <code>COMPLETED</code> or <code>FAILED</code>. <code>FinishedAt</code> is only available when the <code>Evaluation</code> is in the <code>COMPLETED</code> or <code>FAILED</code> state.</p>
     #[serde(rename = "FinishedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<EpochTimestamp>,
     /// <p>The location of the data file or directory in Amazon Simple Storage Service (Amazon S3).</p>
     #[serde(rename = "InputDataLocationS3")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_data_location_s3: Option<String>,
     /// <p>The time of the most recent edit to the <code>Evaluation</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>A link to the file that contains logs of the <code>CreateEvaluation</code> operation.</p>
     #[serde(rename = "LogUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1085,12 +2886,13 @@ pub struct GetEvaluationOutput {
     pub performance_metrics: Option<PerformanceMetrics>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>Evaluation</code> as <code>INPROGRESS</code>. <code>StartedAt</code> isn't available if the <code>Evaluation</code> is in the <code>PENDING</code> state.</p>
     #[serde(rename = "StartedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<EpochTimestamp>,
     /// <p><p>The status of the evaluation. This element can have one of the following values:</p> <ul> <li> <code>PENDING</code> - Amazon Machine Language (Amazon ML) submitted a request to evaluate an <code>MLModel</code>.</li> <li> <code>INPROGRESS</code> - The evaluation is underway.</li> <li> <code>FAILED</code> - The request to evaluate an <code>MLModel</code> did not run to completion. It is not usable.</li> <li> <code>COMPLETED</code> - The evaluation process completed successfully.</li> <li> <code>DELETED</code> - The <code>Evaluation</code> is marked as deleted. It is not usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1104,6 +2906,44 @@ pub struct GetMLModelInput {
     pub verbose: Option<bool>,
 }
 
+impl GetMLModelInput {
+    /// Returns a builder for `GetMLModelInput`, checking its required members when built.
+    pub fn builder() -> GetMLModelInputBuilder {
+        GetMLModelInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetMLModelInput`](struct.GetMLModelInput.html).
+#[derive(Default, Debug)]
+pub struct GetMLModelInputBuilder {
+    ml_model_id: Option<String>,
+    verbose: Option<bool>,
+}
+
+impl GetMLModelInputBuilder {
+    /// Sets the [`ml_model_id`](struct.GetMLModelInput.html#structfield.ml_model_id) member.
+    pub fn ml_model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ml_model_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`verbose`](struct.GetMLModelInput.html#structfield.verbose) member.
+    pub fn verbose<T: Into<bool>>(mut self, value: T) -> Self {
+        self.verbose = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetMLModelInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetMLModelInput, ::rusoto_core::BuildError> {
+        Ok(GetMLModelInput {
+            ml_model_id: self
+                .ml_model_id
+                .ok_or(::rusoto_core::BuildError::missing("ml_model_id"))?,
+            verbose: self.verbose,
+        })
+    }
+}
+
 /// <p>Represents the output of a <code>GetMLModel</code> operation, and provides detailed information about a <code>MLModel</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1114,8 +2954,9 @@ pub struct GetMLModelOutput {
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>MLModel</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The AWS user account from which the <code>MLModel</code> was created. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1126,16 +2967,18 @@ pub struct GetMLModelOutput {
     pub endpoint_info: Option<RealtimeEndpointInfo>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>MLModel</code> as <code>COMPLETED</code> or <code>FAILED</code>. <code>FinishedAt</code> is only available when the <code>MLModel</code> is in the <code>COMPLETED</code> or <code>FAILED</code> state.</p>
     #[serde(rename = "FinishedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<EpochTimestamp>,
     /// <p>The location of the data file or directory in Amazon Simple Storage Service (Amazon S3).</p>
     #[serde(rename = "InputDataLocationS3")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_data_location_s3: Option<String>,
     /// <p>The time of the most recent edit to the <code>MLModel</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>A link to the file that contains logs of the <code>CreateMLModel</code> operation.</p>
     #[serde(rename = "LogUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1147,7 +2990,7 @@ pub struct GetMLModelOutput {
     /// <p><p>Identifies the <code>MLModel</code> category. The following are the available types: </p> <ul> <li>REGRESSION -- Produces a numeric result. For example, &quot;What price should a house be listed at?&quot;</li> <li>BINARY -- Produces one of two possible results. For example, &quot;Is this an e-commerce website?&quot;</li> <li>MULTICLASS -- Produces one of several possible results. For example, &quot;Is this a HIGH, LOW or MEDIUM risk trade?&quot;</li> </ul></p>
     #[serde(rename = "MLModelType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ml_model_type: Option<String>,
+    pub ml_model_type: Option<MLModelType>,
     /// <p>A description of the most recent details about accessing the <code>MLModel</code>.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1170,19 +3013,21 @@ pub struct GetMLModelOutput {
     pub score_threshold: Option<f32>,
     /// <p>The time of the most recent edit to the <code>ScoreThreshold</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "ScoreThresholdLastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub score_threshold_last_updated_at: Option<f64>,
+    pub score_threshold_last_updated_at: Option<EpochTimestamp>,
     #[serde(rename = "SizeInBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size_in_bytes: Option<i64>,
     /// <p>The epoch time when Amazon Machine Learning marked the <code>MLModel</code> as <code>INPROGRESS</code>. <code>StartedAt</code> isn't available if the <code>MLModel</code> is in the <code>PENDING</code> state.</p>
     #[serde(rename = "StartedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<EpochTimestamp>,
     /// <p><p>The current status of the <code>MLModel</code>. This element can have one of the following values:</p> <ul> <li> <code>PENDING</code> - Amazon Machine Learning (Amazon ML) submitted a request to describe a <code>MLModel</code>.</li> <li> <code>INPROGRESS</code> - The request is processing.</li> <li> <code>FAILED</code> - The request did not run to completion. The ML model isn&#39;t usable.</li> <li> <code>COMPLETED</code> - The request completed successfully.</li> <li> <code>DELETED</code> - The <code>MLModel</code> is marked as deleted. It isn&#39;t usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
     /// <p>The ID of the training <code>DataSource</code>.</p>
     #[serde(rename = "TrainingDataSourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1200,14 +3045,15 @@ pub struct MLModel {
     /// <p><p>The algorithm used to train the <code>MLModel</code>. The following algorithm is supported:</p> <ul> <li> <code>SGD</code> -- Stochastic gradient descent. The goal of <code>SGD</code> is to minimize the gradient of the loss function. </li> </ul></p>
     #[serde(rename = "Algorithm")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub algorithm: Option<String>,
+    pub algorithm: Option<Algorithm>,
     #[serde(rename = "ComputeTime")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compute_time: Option<i64>,
     /// <p>The time that the <code>MLModel</code> was created. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The AWS user account from which the <code>MLModel</code> was created. The account type can be either an AWS root account or an AWS Identity and Access Management (IAM) user account.</p>
     #[serde(rename = "CreatedByIamUser")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1217,16 +3063,18 @@ pub struct MLModel {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub endpoint_info: Option<RealtimeEndpointInfo>,
     #[serde(rename = "FinishedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub finished_at: Option<f64>,
+    pub finished_at: Option<EpochTimestamp>,
     /// <p>The location of the data file or directory in Amazon Simple Storage Service (Amazon S3).</p>
     #[serde(rename = "InputDataLocationS3")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input_data_location_s3: Option<String>,
     /// <p>The time of the most recent edit to the <code>MLModel</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "LastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_at: Option<f64>,
+    pub last_updated_at: Option<EpochTimestamp>,
     /// <p>The ID assigned to the <code>MLModel</code> at creation.</p>
     #[serde(rename = "MLModelId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1234,7 +3082,7 @@ pub struct MLModel {
     /// <p><p>Identifies the <code>MLModel</code> category. The following are the available types:</p> <ul> <li> <code>REGRESSION</code> - Produces a numeric result. For example, &quot;What price should a house be listed at?&quot;</li> <li> <code>BINARY</code> - Produces one of two possible results. For example, &quot;Is this a child-friendly web site?&quot;.</li> <li> <code>MULTICLASS</code> - Produces one of several possible results. For example, &quot;Is this a HIGH-, LOW-, or MEDIUM&lt;?oxy<em>delete author=&quot;annbech&quot; timestamp=&quot;20160328T175050-0700&quot; content=&quot; &quot;&gt;&lt;?oxy</em>insert<em>start author=&quot;annbech&quot; timestamp=&quot;20160328T175050-0700&quot;&gt;-&lt;?oxy</em>insert_end&gt;risk trade?&quot;.</li> </ul></p>
     #[serde(rename = "MLModelType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ml_model_type: Option<String>,
+    pub ml_model_type: Option<MLModelType>,
     /// <p>A description of the most recent details about accessing the <code>MLModel</code>.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1248,18 +3096,20 @@ pub struct MLModel {
     pub score_threshold: Option<f32>,
     /// <p>The time of the most recent edit to the <code>ScoreThreshold</code>. The time is expressed in epoch time.</p>
     #[serde(rename = "ScoreThresholdLastUpdatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub score_threshold_last_updated_at: Option<f64>,
+    pub score_threshold_last_updated_at: Option<EpochTimestamp>,
     #[serde(rename = "SizeInBytes")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size_in_bytes: Option<i64>,
     #[serde(rename = "StartedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<f64>,
+    pub started_at: Option<EpochTimestamp>,
     /// <p><p>The current status of an <code>MLModel</code>. This element can have one of the following values: </p> <ul> <li> <code>PENDING</code> - Amazon Machine Learning (Amazon ML) submitted a request to create an <code>MLModel</code>.</li> <li> <code>INPROGRESS</code> - The creation process is underway.</li> <li> <code>FAILED</code> - The request to create an <code>MLModel</code> didn&#39;t run to completion. The model isn&#39;t usable.</li> <li> <code>COMPLETED</code> - The creation process completed successfully.</li> <li> <code>DELETED</code> - The <code>MLModel</code> is marked as deleted. It isn&#39;t usable.</li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<EntityStatus>,
     /// <p>The ID of the training <code>DataSource</code>. The <code>CreateMLModel</code> operation uses the <code>TrainingDataSourceId</code>.</p>
     #[serde(rename = "TrainingDataSourceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1270,6 +3120,186 @@ pub struct MLModel {
     pub training_parameters: Option<::std::collections::HashMap<String, String>>,
 }
 
+/// <p><p>Use one of the following variables to filter a list of <code>MLModel</code>:</p> <ul> <li> <code>CreatedAt</code> - Sets the search criteria to <code>MLModel</code> creation date.</li> <li> <code>Status</code> - Sets the search criteria to <code>MLModel</code> status.</li> <li> <code>Name</code> - Sets the search criteria to the contents of <code>MLModel</code><b> </b> <code>Name</code>.</li> <li> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>MLModel</code> creation.</li> <li> <code>TrainingDataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used to train one or more <code>MLModel</code>.</li> <li> <code>RealtimeEndpointStatus</code> - Sets the search criteria to the <code>MLModel</code> real-time endpoint status.</li> <li> <code>MLModelType</code> - Sets the search criteria to <code>MLModel</code> type: binary, regression, or multi-class.</li> <li> <code>Algorithm</code> - Sets the search criteria to the algorithm that the <code>MLModel</code> uses.</li> <li> <code>TrainingDataURI</code> - Sets the search criteria to the data file(s) used in training a <code>MLModel</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MLModelFilterVariable {
+    /// <p><code>CreatedAt</code> - Sets the search criteria to <code>MLModel</code> creation date.</li> <li> <code>Status</code> - Sets the search criteria to <code>MLModel</code> status.</li> <li> <code>Name</code> - Sets the search criteria to the contents of <code>MLModel</code><b> </b> <code>Name</code>.</li> <li> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>MLModel</code> creation.</li> <li> <code>TrainingDataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used to train one or more <code>MLModel</code>.</li> <li> <code>RealtimeEndpointStatus</code> - Sets the search criteria to the <code>MLModel</code> real-time endpoint status.</li> <li> <code>MLModelType</code> - Sets the search criteria to <code>MLModel</code> type: binary, regression, or multi-class.</li> <li> <code>Algorithm</code> - Sets the search criteria to the algorithm that the <code>MLModel</code> uses.</li> <li> <code>TrainingDataURI</code> - Sets the search criteria to the data file(s) used in training a <code>MLModel</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> </ul></p>
+    CreatedAt,
+    /// <p><code>LastUpdatedAt</code></p>
+    LastUpdatedAt,
+    /// <p><code>Status</code> - Sets the search criteria to <code>MLModel</code> status.</li> <li> <code>Name</code> - Sets the search criteria to the contents of <code>MLModel</code><b> </b> <code>Name</code>.</li> <li> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>MLModel</code> creation.</li> <li> <code>TrainingDataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used to train one or more <code>MLModel</code>.</li> <li> <code>RealtimeEndpointStatus</code> - Sets the search criteria to the <code>MLModel</code> real-time endpoint status.</li> <li> <code>MLModelType</code> - Sets the search criteria to <code>MLModel</code> type: binary, regression, or multi-class.</li> <li> <code>Algorithm</code> - Sets the search criteria to the algorithm that the <code>MLModel</code> uses.</li> <li> <code>TrainingDataURI</code> - Sets the search criteria to the data file(s) used in training a <code>MLModel</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> </ul></p>
+    Status,
+    /// <p><code>Name</code> - Sets the search criteria to the contents of <code>MLModel</code><b> </b> <code>Name</code>.</li> <li> <code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>MLModel</code> creation.</li> <li> <code>TrainingDataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used to train one or more <code>MLModel</code>.</li> <li> <code>RealtimeEndpointStatus</code> - Sets the search criteria to the <code>MLModel</code> real-time endpoint status.</li> <li> <code>MLModelType</code> - Sets the search criteria to <code>MLModel</code> type: binary, regression, or multi-class.</li> <li> <code>Algorithm</code> - Sets the search criteria to the algorithm that the <code>MLModel</code> uses.</li> <li> <code>TrainingDataURI</code> - Sets the search criteria to the data file(s) used in training a <code>MLModel</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> </ul></p>
+    Name,
+    /// <p><code>IAMUser</code> - Sets the search criteria to the user account that invoked the <code>MLModel</code> creation.</li> <li> <code>TrainingDataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used to train one or more <code>MLModel</code>.</li> <li> <code>RealtimeEndpointStatus</code> - Sets the search criteria to the <code>MLModel</code> real-time endpoint status.</li> <li> <code>MLModelType</code> - Sets the search criteria to <code>MLModel</code> type: binary, regression, or multi-class.</li> <li> <code>Algorithm</code> - Sets the search criteria to the algorithm that the <code>MLModel</code> uses.</li> <li> <code>TrainingDataURI</code> - Sets the search criteria to the data file(s) used in training a <code>MLModel</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> </ul></p>
+    IAMUser,
+    /// <p><code>TrainingDataSourceId</code> - Sets the search criteria to the <code>DataSource</code> used to train one or more <code>MLModel</code>.</li> <li> <code>RealtimeEndpointStatus</code> - Sets the search criteria to the <code>MLModel</code> real-time endpoint status.</li> <li> <code>MLModelType</code> - Sets the search criteria to <code>MLModel</code> type: binary, regression, or multi-class.</li> <li> <code>Algorithm</code> - Sets the search criteria to the algorithm that the <code>MLModel</code> uses.</li> <li> <code>TrainingDataURI</code> - Sets the search criteria to the data file(s) used in training a <code>MLModel</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> </ul></p>
+    TrainingDataSourceId,
+    /// <p><code>RealtimeEndpointStatus</code> - Sets the search criteria to the <code>MLModel</code> real-time endpoint status.</li> <li> <code>MLModelType</code> - Sets the search criteria to <code>MLModel</code> type: binary, regression, or multi-class.</li> <li> <code>Algorithm</code> - Sets the search criteria to the algorithm that the <code>MLModel</code> uses.</li> <li> <code>TrainingDataURI</code> - Sets the search criteria to the data file(s) used in training a <code>MLModel</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> </ul></p>
+    RealtimeEndpointStatus,
+    /// <p><code>MLModelType</code> - Sets the search criteria to <code>MLModel</code> type: binary, regression, or multi-class.</li> <li> <code>Algorithm</code> - Sets the search criteria to the algorithm that the <code>MLModel</code> uses.</li> <li> <code>TrainingDataURI</code> - Sets the search criteria to the data file(s) used in training a <code>MLModel</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> </ul></p>
+    MLModelType,
+    /// <p><code>Algorithm</code> - Sets the search criteria to the algorithm that the <code>MLModel</code> uses.</li> <li> <code>TrainingDataURI</code> - Sets the search criteria to the data file(s) used in training a <code>MLModel</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> </ul></p>
+    Algorithm,
+    /// <p><code>TrainingDataURI</code> - Sets the search criteria to the data file(s) used in training a <code>MLModel</code>. The URL can identify either a file or an Amazon Simple Storage Service (Amazon S3) bucket or directory.</li> </ul></p>
+    TrainingDataURI,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MLModelFilterVariable {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MLModelFilterVariable::CreatedAt => "CreatedAt",
+            MLModelFilterVariable::LastUpdatedAt => "LastUpdatedAt",
+            MLModelFilterVariable::Status => "Status",
+            MLModelFilterVariable::Name => "Name",
+            MLModelFilterVariable::IAMUser => "IAMUser",
+            MLModelFilterVariable::TrainingDataSourceId => "TrainingDataSourceId",
+            MLModelFilterVariable::RealtimeEndpointStatus => "RealtimeEndpointStatus",
+            MLModelFilterVariable::MLModelType => "MLModelType",
+            MLModelFilterVariable::Algorithm => "Algorithm",
+            MLModelFilterVariable::TrainingDataURI => "TrainingDataURI",
+            MLModelFilterVariable::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MLModelFilterVariable {
+    fn from(value: &'a str) -> MLModelFilterVariable {
+        match value {
+            "CreatedAt" => MLModelFilterVariable::CreatedAt,
+            "LastUpdatedAt" => MLModelFilterVariable::LastUpdatedAt,
+            "Status" => MLModelFilterVariable::Status,
+            "Name" => MLModelFilterVariable::Name,
+            "IAMUser" => MLModelFilterVariable::IAMUser,
+            "TrainingDataSourceId" => MLModelFilterVariable::TrainingDataSourceId,
+            "RealtimeEndpointStatus" => MLModelFilterVariable::RealtimeEndpointStatus,
+            "MLModelType" => MLModelFilterVariable::MLModelType,
+            "Algorithm" => MLModelFilterVariable::Algorithm,
+            "TrainingDataURI" => MLModelFilterVariable::TrainingDataURI,
+            _ => MLModelFilterVariable::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MLModelFilterVariable {
+    fn from(value: String) -> MLModelFilterVariable {
+        MLModelFilterVariable::from(value.as_str())
+    }
+}
+
+impl From<MLModelFilterVariable> for String {
+    fn from(value: MLModelFilterVariable) -> String {
+        match value {
+            MLModelFilterVariable::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MLModelFilterVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MLModelFilterVariable {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MLModelFilterVariable {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MLModelFilterVariable, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MLModelFilterVariable::from(value))
+    }
+}
+
+/// <p>The category of supervised learning that this <code>MLModel</code> will address. Choose from the following types:</p> <ul> <li>Choose <code>REGRESSION</code> if the <code>MLModel</code> will be used to predict a numeric value.</li> <li>Choose <code>BINARY</code> if the <code>MLModel</code> result has two possible values.</li> <li>Choose <code>MULTICLASS</code> if the <code>MLModel</code> result has a limited number of values. </li> </ul> <p> For more information, see the <a href="http://docs.aws.amazon.com/machine-learning/latest/dg">Amazon Machine Learning Developer Guide</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MLModelType {
+    /// <p><code>REGRESSION</code> - Produces a numeric result. For example, &quot;What price should a house be listed at?&quot;</li> <li> <code>BINARY</code> - Produces one of two possible results. For example, &quot;Is this a child-friendly web site?&quot;.</li> <li> <code>MULTICLASS</code> - Produces one of several possible results. For example, &quot;Is this a HIGH-, LOW-, or MEDIUM&lt;?oxy<em>delete author=&quot;annbech&quot; timestamp=&quot;20160328T175050-0700&quot; content=&quot; &quot;&gt;&lt;?oxy</em>insert<em>start author=&quot;annbech&quot; timestamp=&quot;20160328T175050-0700&quot;&gt;-&lt;?oxy</em>insert_end&gt;risk trade?&quot;.</li> </ul></p>
+    Regression,
+    /// <p><code>BINARY</code> - Produces one of two possible results. For example, &quot;Is this a child-friendly web site?&quot;.</li> <li> <code>MULTICLASS</code> - Produces one of several possible results. For example, &quot;Is this a HIGH-, LOW-, or MEDIUM&lt;?oxy<em>delete author=&quot;annbech&quot; timestamp=&quot;20160328T175050-0700&quot; content=&quot; &quot;&gt;&lt;?oxy</em>insert<em>start author=&quot;annbech&quot; timestamp=&quot;20160328T175050-0700&quot;&gt;-&lt;?oxy</em>insert_end&gt;risk trade?&quot;.</li> </ul></p>
+    Binary,
+    /// <p><code>MULTICLASS</code> - Produces one of several possible results. For example, &quot;Is this a HIGH-, LOW-, or MEDIUM&lt;?oxy<em>delete author=&quot;annbech&quot; timestamp=&quot;20160328T175050-0700&quot; content=&quot; &quot;&gt;&lt;?oxy</em>insert<em>start author=&quot;annbech&quot; timestamp=&quot;20160328T175050-0700&quot;&gt;-&lt;?oxy</em>insert_end&gt;risk trade?&quot;.</li> </ul></p>
+    Multiclass,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MLModelType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MLModelType::Regression => "REGRESSION",
+            MLModelType::Binary => "BINARY",
+            MLModelType::Multiclass => "MULTICLASS",
+            MLModelType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MLModelType {
+    fn from(value: &'a str) -> MLModelType {
+        match value {
+            "REGRESSION" => MLModelType::Regression,
+            "BINARY" => MLModelType::Binary,
+            "MULTICLASS" => MLModelType::Multiclass,
+            _ => MLModelType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MLModelType {
+    fn from(value: String) -> MLModelType {
+        MLModelType::from(value.as_str())
+    }
+}
+
+impl From<MLModelType> for String {
+    fn from(value: MLModelType) -> String {
+        match value {
+            MLModelType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MLModelType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MLModelType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MLModelType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MLModelType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MLModelType::from(value))
+    }
+}
+
 /// <p>Measurements of how well the <code>MLModel</code> performed on known observations. One of the following metrics is returned, based on the type of the <code>MLModel</code>: </p> <ul> <li> <p>BinaryAUC: The binary <code>MLModel</code> uses the Area Under the Curve (AUC) technique to measure performance. </p> </li> <li> <p>RegressionRMSE: The regression <code>MLModel</code> uses the Root Mean Square Error (RMSE) technique to measure performance. RMSE measures the difference between predicted and actual values for a single variable.</p> </li> <li> <p>MulticlassAvgFScore: The multiclass <code>MLModel</code> uses the F1 score technique to measure performance. </p> </li> </ul> <p> For more information about performance metrics, please see the <a href="http://docs.aws.amazon.com/machine-learning/latest/dg">Amazon Machine Learning Developer Guide</a>. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1290,6 +3320,59 @@ pub struct PredictInput {
     pub record: ::std::collections::HashMap<String, String>,
 }
 
+impl PredictInput {
+    /// Returns a builder for `PredictInput`, checking its required members when built.
+    pub fn builder() -> PredictInputBuilder {
+        PredictInputBuilder::default()
+    }
+}
+
+/// Builds a [`PredictInput`](struct.PredictInput.html).
+#[derive(Default, Debug)]
+pub struct PredictInputBuilder {
+    ml_model_id: Option<String>,
+    predict_endpoint: Option<String>,
+    record: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl PredictInputBuilder {
+    /// Sets the [`ml_model_id`](struct.PredictInput.html#structfield.ml_model_id) member.
+    pub fn ml_model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ml_model_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`predict_endpoint`](struct.PredictInput.html#structfield.predict_endpoint) member.
+    pub fn predict_endpoint<T: Into<String>>(mut self, value: T) -> Self {
+        self.predict_endpoint = Some(value.into());
+        self
+    }
+
+    /// Sets the [`record`](struct.PredictInput.html#structfield.record) member.
+    pub fn record<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.record = Some(value.into());
+        self
+    }
+
+    /// Builds the `PredictInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PredictInput, ::rusoto_core::BuildError> {
+        Ok(PredictInput {
+            ml_model_id: self
+                .ml_model_id
+                .ok_or(::rusoto_core::BuildError::missing("ml_model_id"))?,
+            predict_endpoint: self
+                .predict_endpoint
+                .ok_or(::rusoto_core::BuildError::missing("predict_endpoint"))?,
+            record: self
+                .record
+                .ok_or(::rusoto_core::BuildError::missing("record"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PredictOutput {
@@ -1304,7 +3387,7 @@ pub struct PredictOutput {
 pub struct Prediction {
     #[serde(rename = "details")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub details: Option<::std::collections::HashMap<String, String>>,
+    pub details: Option<::std::collections::HashMap<DetailsAttributes, String>>,
     /// <p>The prediction label for either a <code>BINARY</code> or <code>MULTICLASS</code> <code>MLModel</code>.</p>
     #[serde(rename = "predictedLabel")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1413,12 +3496,13 @@ pub struct RDSMetadata {
 pub struct RealtimeEndpointInfo {
     /// <p>The time that the request to create the real-time endpoint for the <code>MLModel</code> was received. The time is expressed in epoch time.</p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p><p> The current status of the real-time endpoint for the <code>MLModel</code>. This element can have one of the following values: </p> <ul> <li> <code>NONE</code> - Endpoint does not exist or was previously deleted.</li> <li> <code>READY</code> - Endpoint is ready to be used for real-time predictions.</li> <li> <code>UPDATING</code> - Updating/creating the endpoint. </li> </ul></p>
     #[serde(rename = "EndpointStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub endpoint_status: Option<String>,
+    pub endpoint_status: Option<RealtimeEndpointStatus>,
     /// <p><p>The URI that specifies where to send real-time prediction requests for the <code>MLModel</code>.</p> <note><title>Note</title> <p>The application must wait until the real-time endpoint is ready before using this URI.</p> </note></p>
     #[serde(rename = "EndpointUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1429,6 +3513,86 @@ pub struct RealtimeEndpointInfo {
     pub peak_requests_per_second: Option<i64>,
 }
 
+/// <p><p> The current status of the real-time endpoint for the <code>MLModel</code>. This element can have one of the following values: </p> <ul> <li> <code>NONE</code> - Endpoint does not exist or was previously deleted.</li> <li> <code>READY</code> - Endpoint is ready to be used for real-time predictions.</li> <li> <code>UPDATING</code> - Updating/creating the endpoint. </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RealtimeEndpointStatus {
+    /// <p><code>NONE</code> - Endpoint does not exist or was previously deleted.</li> <li> <code>READY</code> - Endpoint is ready to be used for real-time predictions.</li> <li> <code>UPDATING</code> - Updating/creating the endpoint. </li> </ul></p>
+    None,
+    /// <p><code>READY</code> - Endpoint is ready to be used for real-time predictions.</li> <li> <code>UPDATING</code> - Updating/creating the endpoint. </li> </ul></p>
+    Ready,
+    /// <p><code>UPDATING</code> - Updating/creating the endpoint. </li> </ul></p>
+    Updating,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RealtimeEndpointStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RealtimeEndpointStatus::None => "NONE",
+            RealtimeEndpointStatus::Ready => "READY",
+            RealtimeEndpointStatus::Updating => "UPDATING",
+            RealtimeEndpointStatus::Failed => "FAILED",
+            RealtimeEndpointStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RealtimeEndpointStatus {
+    fn from(value: &'a str) -> RealtimeEndpointStatus {
+        match value {
+            "NONE" => RealtimeEndpointStatus::None,
+            "READY" => RealtimeEndpointStatus::Ready,
+            "UPDATING" => RealtimeEndpointStatus::Updating,
+            "FAILED" => RealtimeEndpointStatus::Failed,
+            _ => RealtimeEndpointStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RealtimeEndpointStatus {
+    fn from(value: String) -> RealtimeEndpointStatus {
+        RealtimeEndpointStatus::from(value.as_str())
+    }
+}
+
+impl From<RealtimeEndpointStatus> for String {
+    fn from(value: RealtimeEndpointStatus) -> String {
+        match value {
+            RealtimeEndpointStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RealtimeEndpointStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RealtimeEndpointStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RealtimeEndpointStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RealtimeEndpointStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RealtimeEndpointStatus::from(value))
+    }
+}
+
 /// <p>Describes the data specification of an Amazon Redshift <code>DataSource</code>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RedshiftDataSpec {
@@ -1512,6 +3676,78 @@ pub struct S3DataSpec {
     pub data_schema_location_s3: Option<String>,
 }
 
+/// <p>The sort order specified in a listing condition. Possible values include the following:</p> <ul> <li> <p> <code>asc</code> - Present the information in ascending order (from A-Z).</p> </li> <li> <p> <code>dsc</code> - Present the information in descending order (from Z-A).</p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// <p><code>asc</code> - Present the information in ascending order (from A-Z).</p>
+    Asc,
+    /// <p><code>dsc</code> - Present the information in descending order (from Z-A).</p>
+    Dsc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SortOrder {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortOrder::Asc => "asc",
+            SortOrder::Dsc => "dsc",
+            SortOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SortOrder {
+    fn from(value: &'a str) -> SortOrder {
+        match value {
+            "asc" => SortOrder::Asc,
+            "dsc" => SortOrder::Dsc,
+            _ => SortOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortOrder {
+    fn from(value: String) -> SortOrder {
+        SortOrder::from(value.as_str())
+    }
+}
+
+impl From<SortOrder> for String {
+    fn from(value: SortOrder) -> String {
+        match value {
+            SortOrder::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SortOrder {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SortOrder {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SortOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SortOrder::from(value))
+    }
+}
+
 /// <p>A custom key-value pair associated with an ML object, such as an ML model.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Tag {
@@ -1525,6 +3761,86 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
+/// <p>The type of the ML object to tag. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaggableResourceType {
+    /// <p><code>BatchPrediction</code></p>
+    BatchPrediction,
+    /// <p><code>DataSource</code></p>
+    DataSource,
+    /// <p><code>Evaluation</code></p>
+    Evaluation,
+    /// <p><code>MLModel</code></p>
+    MLModel,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TaggableResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaggableResourceType::BatchPrediction => "BatchPrediction",
+            TaggableResourceType::DataSource => "DataSource",
+            TaggableResourceType::Evaluation => "Evaluation",
+            TaggableResourceType::MLModel => "MLModel",
+            TaggableResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaggableResourceType {
+    fn from(value: &'a str) -> TaggableResourceType {
+        match value {
+            "BatchPrediction" => TaggableResourceType::BatchPrediction,
+            "DataSource" => TaggableResourceType::DataSource,
+            "Evaluation" => TaggableResourceType::Evaluation,
+            "MLModel" => TaggableResourceType::MLModel,
+            _ => TaggableResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaggableResourceType {
+    fn from(value: String) -> TaggableResourceType {
+        TaggableResourceType::from(value.as_str())
+    }
+}
+
+impl From<TaggableResourceType> for String {
+    fn from(value: TaggableResourceType) -> String {
+        match value {
+            TaggableResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TaggableResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TaggableResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaggableResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TaggableResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TaggableResourceType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateBatchPredictionInput {
     /// <p>The ID assigned to the <code>BatchPrediction</code> during creation.</p>
@@ -1535,6 +3851,48 @@ pub struct UpdateBatchPredictionInput {
     pub batch_prediction_name: String,
 }
 
+impl UpdateBatchPredictionInput {
+    /// Returns a builder for `UpdateBatchPredictionInput`, checking its required members when built.
+    pub fn builder() -> UpdateBatchPredictionInputBuilder {
+        UpdateBatchPredictionInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateBatchPredictionInput`](struct.UpdateBatchPredictionInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateBatchPredictionInputBuilder {
+    batch_prediction_id: Option<String>,
+    batch_prediction_name: Option<String>,
+}
+
+impl UpdateBatchPredictionInputBuilder {
+    /// Sets the [`batch_prediction_id`](struct.UpdateBatchPredictionInput.html#structfield.batch_prediction_id) member.
+    pub fn batch_prediction_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.batch_prediction_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`batch_prediction_name`](struct.UpdateBatchPredictionInput.html#structfield.batch_prediction_name) member.
+    pub fn batch_prediction_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.batch_prediction_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateBatchPredictionInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateBatchPredictionInput, ::rusoto_core::BuildError> {
+        Ok(UpdateBatchPredictionInput {
+            batch_prediction_id: self
+                .batch_prediction_id
+                .ok_or(::rusoto_core::BuildError::missing("batch_prediction_id"))?,
+            batch_prediction_name: self
+                .batch_prediction_name
+                .ok_or(::rusoto_core::BuildError::missing("batch_prediction_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of an <code>UpdateBatchPrediction</code> operation.</p> <p>You can see the updated content by using the <code>GetBatchPrediction</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1555,6 +3913,46 @@ pub struct UpdateDataSourceInput {
     pub data_source_name: String,
 }
 
+impl UpdateDataSourceInput {
+    /// Returns a builder for `UpdateDataSourceInput`, checking its required members when built.
+    pub fn builder() -> UpdateDataSourceInputBuilder {
+        UpdateDataSourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateDataSourceInput`](struct.UpdateDataSourceInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateDataSourceInputBuilder {
+    data_source_id: Option<String>,
+    data_source_name: Option<String>,
+}
+
+impl UpdateDataSourceInputBuilder {
+    /// Sets the [`data_source_id`](struct.UpdateDataSourceInput.html#structfield.data_source_id) member.
+    pub fn data_source_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_source_name`](struct.UpdateDataSourceInput.html#structfield.data_source_name) member.
+    pub fn data_source_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.data_source_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateDataSourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateDataSourceInput, ::rusoto_core::BuildError> {
+        Ok(UpdateDataSourceInput {
+            data_source_id: self
+                .data_source_id
+                .ok_or(::rusoto_core::BuildError::missing("data_source_id"))?,
+            data_source_name: self
+                .data_source_name
+                .ok_or(::rusoto_core::BuildError::missing("data_source_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of an <code>UpdateDataSource</code> operation.</p> <p>You can see the updated content by using the <code>GetBatchPrediction</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1575,6 +3973,46 @@ pub struct UpdateEvaluationInput {
     pub evaluation_name: String,
 }
 
+impl UpdateEvaluationInput {
+    /// Returns a builder for `UpdateEvaluationInput`, checking its required members when built.
+    pub fn builder() -> UpdateEvaluationInputBuilder {
+        UpdateEvaluationInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateEvaluationInput`](struct.UpdateEvaluationInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateEvaluationInputBuilder {
+    evaluation_id: Option<String>,
+    evaluation_name: Option<String>,
+}
+
+impl UpdateEvaluationInputBuilder {
+    /// Sets the [`evaluation_id`](struct.UpdateEvaluationInput.html#structfield.evaluation_id) member.
+    pub fn evaluation_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.evaluation_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`evaluation_name`](struct.UpdateEvaluationInput.html#structfield.evaluation_name) member.
+    pub fn evaluation_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.evaluation_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateEvaluationInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateEvaluationInput, ::rusoto_core::BuildError> {
+        Ok(UpdateEvaluationInput {
+            evaluation_id: self
+                .evaluation_id
+                .ok_or(::rusoto_core::BuildError::missing("evaluation_id"))?,
+            evaluation_name: self
+                .evaluation_name
+                .ok_or(::rusoto_core::BuildError::missing("evaluation_name"))?,
+        })
+    }
+}
+
 /// <p>Represents the output of an <code>UpdateEvaluation</code> operation.</p> <p>You can see the updated content by using the <code>GetEvaluation</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1600,6 +4038,52 @@ pub struct UpdateMLModelInput {
     pub score_threshold: Option<f32>,
 }
 
+impl UpdateMLModelInput {
+    /// Returns a builder for `UpdateMLModelInput`, checking its required members when built.
+    pub fn builder() -> UpdateMLModelInputBuilder {
+        UpdateMLModelInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateMLModelInput`](struct.UpdateMLModelInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateMLModelInputBuilder {
+    ml_model_id: Option<String>,
+    ml_model_name: Option<String>,
+    score_threshold: Option<f32>,
+}
+
+impl UpdateMLModelInputBuilder {
+    /// Sets the [`ml_model_id`](struct.UpdateMLModelInput.html#structfield.ml_model_id) member.
+    pub fn ml_model_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ml_model_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ml_model_name`](struct.UpdateMLModelInput.html#structfield.ml_model_name) member.
+    pub fn ml_model_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.ml_model_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`score_threshold`](struct.UpdateMLModelInput.html#structfield.score_threshold) member.
+    pub fn score_threshold<T: Into<f32>>(mut self, value: T) -> Self {
+        self.score_threshold = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateMLModelInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateMLModelInput, ::rusoto_core::BuildError> {
+        Ok(UpdateMLModelInput {
+            ml_model_id: self
+                .ml_model_id
+                .ok_or(::rusoto_core::BuildError::missing("ml_model_id"))?,
+            ml_model_name: self.ml_model_name,
+            score_threshold: self.score_threshold,
+        })
+    }
+}
+
 /// <p>Represents the output of an <code>UpdateMLModel</code> operation.</p> <p>You can see the updated content by using the <code>GetMLModel</code> operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3086,6 +5570,177 @@ pub trait MachineLearning {
         input: UpdateMLModelInput,
     ) -> RusotoFuture<UpdateMLModelOutput, UpdateMLModelError>;
 }
+impl<T: ?Sized + MachineLearning> MachineLearning for ::std::sync::Arc<T> {
+    fn add_tags(&self, input: AddTagsInput) -> RusotoFuture<AddTagsOutput, AddTagsError> {
+        (**self).add_tags(input)
+    }
+    fn create_batch_prediction(
+        &self,
+        input: CreateBatchPredictionInput,
+    ) -> RusotoFuture<CreateBatchPredictionOutput, CreateBatchPredictionError> {
+        (**self).create_batch_prediction(input)
+    }
+    fn create_data_source_from_rds(
+        &self,
+        input: CreateDataSourceFromRDSInput,
+    ) -> RusotoFuture<CreateDataSourceFromRDSOutput, CreateDataSourceFromRDSError> {
+        (**self).create_data_source_from_rds(input)
+    }
+    fn create_data_source_from_redshift(
+        &self,
+        input: CreateDataSourceFromRedshiftInput,
+    ) -> RusotoFuture<CreateDataSourceFromRedshiftOutput, CreateDataSourceFromRedshiftError> {
+        (**self).create_data_source_from_redshift(input)
+    }
+    fn create_data_source_from_s3(
+        &self,
+        input: CreateDataSourceFromS3Input,
+    ) -> RusotoFuture<CreateDataSourceFromS3Output, CreateDataSourceFromS3Error> {
+        (**self).create_data_source_from_s3(input)
+    }
+    fn create_evaluation(
+        &self,
+        input: CreateEvaluationInput,
+    ) -> RusotoFuture<CreateEvaluationOutput, CreateEvaluationError> {
+        (**self).create_evaluation(input)
+    }
+    fn create_ml_model(
+        &self,
+        input: CreateMLModelInput,
+    ) -> RusotoFuture<CreateMLModelOutput, CreateMLModelError> {
+        (**self).create_ml_model(input)
+    }
+    fn create_realtime_endpoint(
+        &self,
+        input: CreateRealtimeEndpointInput,
+    ) -> RusotoFuture<CreateRealtimeEndpointOutput, CreateRealtimeEndpointError> {
+        (**self).create_realtime_endpoint(input)
+    }
+    fn delete_batch_prediction(
+        &self,
+        input: DeleteBatchPredictionInput,
+    ) -> RusotoFuture<DeleteBatchPredictionOutput, DeleteBatchPredictionError> {
+        (**self).delete_batch_prediction(input)
+    }
+    fn delete_data_source(
+        &self,
+        input: DeleteDataSourceInput,
+    ) -> RusotoFuture<DeleteDataSourceOutput, DeleteDataSourceError> {
+        (**self).delete_data_source(input)
+    }
+    fn delete_evaluation(
+        &self,
+        input: DeleteEvaluationInput,
+    ) -> RusotoFuture<DeleteEvaluationOutput, DeleteEvaluationError> {
+        (**self).delete_evaluation(input)
+    }
+    fn delete_ml_model(
+        &self,
+        input: DeleteMLModelInput,
+    ) -> RusotoFuture<DeleteMLModelOutput, DeleteMLModelError> {
+        (**self).delete_ml_model(input)
+    }
+    fn delete_realtime_endpoint(
+        &self,
+        input: DeleteRealtimeEndpointInput,
+    ) -> RusotoFuture<DeleteRealtimeEndpointOutput, DeleteRealtimeEndpointError> {
+        (**self).delete_realtime_endpoint(input)
+    }
+    fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> RusotoFuture<DeleteTagsOutput, DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_batch_predictions(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> RusotoFuture<DescribeBatchPredictionsOutput, DescribeBatchPredictionsError> {
+        (**self).describe_batch_predictions(input)
+    }
+    fn describe_data_sources(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> RusotoFuture<DescribeDataSourcesOutput, DescribeDataSourcesError> {
+        (**self).describe_data_sources(input)
+    }
+    fn describe_evaluations(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> RusotoFuture<DescribeEvaluationsOutput, DescribeEvaluationsError> {
+        (**self).describe_evaluations(input)
+    }
+    fn describe_ml_models(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> RusotoFuture<DescribeMLModelsOutput, DescribeMLModelsError> {
+        (**self).describe_ml_models(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> RusotoFuture<DescribeTagsOutput, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn get_batch_prediction(
+        &self,
+        input: GetBatchPredictionInput,
+    ) -> RusotoFuture<GetBatchPredictionOutput, GetBatchPredictionError> {
+        (**self).get_batch_prediction(input)
+    }
+    fn get_data_source(
+        &self,
+        input: GetDataSourceInput,
+    ) -> RusotoFuture<GetDataSourceOutput, GetDataSourceError> {
+        (**self).get_data_source(input)
+    }
+    fn get_evaluation(
+        &self,
+        input: GetEvaluationInput,
+    ) -> RusotoFuture<GetEvaluationOutput, GetEvaluationError> {
+        (**self).get_evaluation(input)
+    }
+    fn get_ml_model(
+        &self,
+        input: GetMLModelInput,
+    ) -> RusotoFuture<GetMLModelOutput, GetMLModelError> {
+        (**self).get_ml_model(input)
+    }
+    fn predict(&self, input: PredictInput) -> RusotoFuture<PredictOutput, PredictError> {
+        (**self).predict(input)
+    }
+    fn update_batch_prediction(
+        &self,
+        input: UpdateBatchPredictionInput,
+    ) -> RusotoFuture<UpdateBatchPredictionOutput, UpdateBatchPredictionError> {
+        (**self).update_batch_prediction(input)
+    }
+    fn update_data_source(
+        &self,
+        input: UpdateDataSourceInput,
+    ) -> RusotoFuture<UpdateDataSourceOutput, UpdateDataSourceError> {
+        (**self).update_data_source(input)
+    }
+    fn update_evaluation(
+        &self,
+        input: UpdateEvaluationInput,
+    ) -> RusotoFuture<UpdateEvaluationOutput, UpdateEvaluationError> {
+        (**self).update_evaluation(input)
+    }
+    fn update_ml_model(
+        &self,
+        input: UpdateMLModelInput,
+    ) -> RusotoFuture<UpdateMLModelOutput, UpdateMLModelError> {
+        (**self).update_ml_model(input)
+    }
+}
+/// The error codes Amazon Machine Learning retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Machine Learning API.
 #[derive(Clone)]
 pub struct MachineLearningClient {
@@ -3101,6 +5756,27 @@ impl MachineLearningClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_MACHINELEARNING`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> MachineLearningClient {
+        Self::new(region::Region::from_env_for_service("MACHINELEARNING"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `machinelearning`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> MachineLearningClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("machinelearning", "MACHINELEARNING"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3119,7 +5795,54 @@ impl MachineLearningClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MachineLearningClient {
-        MachineLearningClient { client, region }
+        MachineLearningClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "machinelearning")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "machinelearning",
+            "machinelearning",
+            "MACHINELEARNING",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `MachineLearning` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn MachineLearning + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for MachineLearningClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Machine Learning"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "machinelearning"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> MachineLearningClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateMemberAccountRequest {
@@ -30,6 +31,38 @@ pub struct AssociateMemberAccountRequest {
     pub member_account_id: String,
 }
 
+impl AssociateMemberAccountRequest {
+    /// Returns a builder for `AssociateMemberAccountRequest`, checking its required members when built.
+    pub fn builder() -> AssociateMemberAccountRequestBuilder {
+        AssociateMemberAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateMemberAccountRequest`](struct.AssociateMemberAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateMemberAccountRequestBuilder {
+    member_account_id: Option<String>,
+}
+
+impl AssociateMemberAccountRequestBuilder {
+    /// Sets the [`member_account_id`](struct.AssociateMemberAccountRequest.html#structfield.member_account_id) member.
+    pub fn member_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.member_account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateMemberAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateMemberAccountRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateMemberAccountRequest {
+            member_account_id: self
+                .member_account_id
+                .ok_or(::rusoto_core::BuildError::missing("member_account_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateS3ResourcesRequest {
     /// <p>The ID of the Amazon Macie member account whose resources you want to associate with Macie. </p>
@@ -41,6 +74,46 @@ pub struct AssociateS3ResourcesRequest {
     pub s_3_resources: Vec<S3ResourceClassification>,
 }
 
+impl AssociateS3ResourcesRequest {
+    /// Returns a builder for `AssociateS3ResourcesRequest`, checking its required members when built.
+    pub fn builder() -> AssociateS3ResourcesRequestBuilder {
+        AssociateS3ResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateS3ResourcesRequest`](struct.AssociateS3ResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateS3ResourcesRequestBuilder {
+    member_account_id: Option<String>,
+    s_3_resources: Option<Vec<S3ResourceClassification>>,
+}
+
+impl AssociateS3ResourcesRequestBuilder {
+    /// Sets the [`member_account_id`](struct.AssociateS3ResourcesRequest.html#structfield.member_account_id) member.
+    pub fn member_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.member_account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s_3_resources`](struct.AssociateS3ResourcesRequest.html#structfield.s_3_resources) member.
+    pub fn s_3_resources<T: Into<Vec<S3ResourceClassification>>>(mut self, value: T) -> Self {
+        self.s_3_resources = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateS3ResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateS3ResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateS3ResourcesRequest {
+            member_account_id: self.member_account_id,
+            s_3_resources: self
+                .s_3_resources
+                .ok_or(::rusoto_core::BuildError::missing("s_3_resources"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateS3ResourcesResult {
@@ -81,6 +154,38 @@ pub struct DisassociateMemberAccountRequest {
     pub member_account_id: String,
 }
 
+impl DisassociateMemberAccountRequest {
+    /// Returns a builder for `DisassociateMemberAccountRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateMemberAccountRequestBuilder {
+        DisassociateMemberAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateMemberAccountRequest`](struct.DisassociateMemberAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateMemberAccountRequestBuilder {
+    member_account_id: Option<String>,
+}
+
+impl DisassociateMemberAccountRequestBuilder {
+    /// Sets the [`member_account_id`](struct.DisassociateMemberAccountRequest.html#structfield.member_account_id) member.
+    pub fn member_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.member_account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateMemberAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateMemberAccountRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateMemberAccountRequest {
+            member_account_id: self
+                .member_account_id
+                .ok_or(::rusoto_core::BuildError::missing("member_account_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DisassociateS3ResourcesRequest {
     /// <p>The S3 resources (buckets or prefixes) that you want to remove from being monitored and classified by Amazon Macie. </p>
@@ -92,6 +197,46 @@ pub struct DisassociateS3ResourcesRequest {
     pub member_account_id: Option<String>,
 }
 
+impl DisassociateS3ResourcesRequest {
+    /// Returns a builder for `DisassociateS3ResourcesRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateS3ResourcesRequestBuilder {
+        DisassociateS3ResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateS3ResourcesRequest`](struct.DisassociateS3ResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateS3ResourcesRequestBuilder {
+    associated_s3_resources: Option<Vec<S3Resource>>,
+    member_account_id: Option<String>,
+}
+
+impl DisassociateS3ResourcesRequestBuilder {
+    /// Sets the [`associated_s3_resources`](struct.DisassociateS3ResourcesRequest.html#structfield.associated_s3_resources) member.
+    pub fn associated_s3_resources<T: Into<Vec<S3Resource>>>(mut self, value: T) -> Self {
+        self.associated_s3_resources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`member_account_id`](struct.DisassociateS3ResourcesRequest.html#structfield.member_account_id) member.
+    pub fn member_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.member_account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateS3ResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateS3ResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateS3ResourcesRequest {
+            associated_s3_resources: self.associated_s3_resources.ok_or(
+                ::rusoto_core::BuildError::missing("associated_s3_resources"),
+            )?,
+            member_account_id: self.member_account_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateS3ResourcesResult {
@@ -131,6 +276,44 @@ pub struct ListMemberAccountsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListMemberAccountsRequest {
+    /// Returns a builder for `ListMemberAccountsRequest`, checking its required members when built.
+    pub fn builder() -> ListMemberAccountsRequestBuilder {
+        ListMemberAccountsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListMemberAccountsRequest`](struct.ListMemberAccountsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListMemberAccountsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListMemberAccountsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListMemberAccountsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListMemberAccountsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListMemberAccountsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListMemberAccountsRequest, ::rusoto_core::BuildError> {
+        Ok(ListMemberAccountsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListMemberAccountsResult {
@@ -160,6 +343,50 @@ pub struct ListS3ResourcesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListS3ResourcesRequest {
+    /// Returns a builder for `ListS3ResourcesRequest`, checking its required members when built.
+    pub fn builder() -> ListS3ResourcesRequestBuilder {
+        ListS3ResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListS3ResourcesRequest`](struct.ListS3ResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListS3ResourcesRequestBuilder {
+    max_results: Option<i64>,
+    member_account_id: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListS3ResourcesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListS3ResourcesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`member_account_id`](struct.ListS3ResourcesRequest.html#structfield.member_account_id) member.
+    pub fn member_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.member_account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListS3ResourcesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListS3ResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListS3ResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(ListS3ResourcesRequest {
+            max_results: self.max_results,
+            member_account_id: self.member_account_id,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListS3ResourcesResult {
@@ -236,6 +463,49 @@ pub struct UpdateS3ResourcesRequest {
     pub s_3_resources_update: Vec<S3ResourceClassificationUpdate>,
 }
 
+impl UpdateS3ResourcesRequest {
+    /// Returns a builder for `UpdateS3ResourcesRequest`, checking its required members when built.
+    pub fn builder() -> UpdateS3ResourcesRequestBuilder {
+        UpdateS3ResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateS3ResourcesRequest`](struct.UpdateS3ResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateS3ResourcesRequestBuilder {
+    member_account_id: Option<String>,
+    s_3_resources_update: Option<Vec<S3ResourceClassificationUpdate>>,
+}
+
+impl UpdateS3ResourcesRequestBuilder {
+    /// Sets the [`member_account_id`](struct.UpdateS3ResourcesRequest.html#structfield.member_account_id) member.
+    pub fn member_account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.member_account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s_3_resources_update`](struct.UpdateS3ResourcesRequest.html#structfield.s_3_resources_update) member.
+    pub fn s_3_resources_update<T: Into<Vec<S3ResourceClassificationUpdate>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.s_3_resources_update = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateS3ResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateS3ResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateS3ResourcesRequest {
+            member_account_id: self.member_account_id,
+            s_3_resources_update: self
+                .s_3_resources_update
+                .ok_or(::rusoto_core::BuildError::missing("s_3_resources_update"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateS3ResourcesResult {
@@ -606,6 +876,57 @@ pub trait Macie {
         input: UpdateS3ResourcesRequest,
     ) -> RusotoFuture<UpdateS3ResourcesResult, UpdateS3ResourcesError>;
 }
+impl<T: ?Sized + Macie> Macie for ::std::sync::Arc<T> {
+    fn associate_member_account(
+        &self,
+        input: AssociateMemberAccountRequest,
+    ) -> RusotoFuture<(), AssociateMemberAccountError> {
+        (**self).associate_member_account(input)
+    }
+    fn associate_s3_resources(
+        &self,
+        input: AssociateS3ResourcesRequest,
+    ) -> RusotoFuture<AssociateS3ResourcesResult, AssociateS3ResourcesError> {
+        (**self).associate_s3_resources(input)
+    }
+    fn disassociate_member_account(
+        &self,
+        input: DisassociateMemberAccountRequest,
+    ) -> RusotoFuture<(), DisassociateMemberAccountError> {
+        (**self).disassociate_member_account(input)
+    }
+    fn disassociate_s3_resources(
+        &self,
+        input: DisassociateS3ResourcesRequest,
+    ) -> RusotoFuture<DisassociateS3ResourcesResult, DisassociateS3ResourcesError> {
+        (**self).disassociate_s3_resources(input)
+    }
+    fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> RusotoFuture<ListMemberAccountsResult, ListMemberAccountsError> {
+        (**self).list_member_accounts(input)
+    }
+    fn list_s3_resources(
+        &self,
+        input: ListS3ResourcesRequest,
+    ) -> RusotoFuture<ListS3ResourcesResult, ListS3ResourcesError> {
+        (**self).list_s3_resources(input)
+    }
+    fn update_s3_resources(
+        &self,
+        input: UpdateS3ResourcesRequest,
+    ) -> RusotoFuture<UpdateS3ResourcesResult, UpdateS3ResourcesError> {
+        (**self).update_s3_resources(input)
+    }
+}
+/// The error codes Amazon Macie retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Macie API.
 #[derive(Clone)]
 pub struct MacieClient {
@@ -621,6 +942,24 @@ impl MacieClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_MACIE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> MacieClient {
+        Self::new(region::Region::from_env_for_service("MACIE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `macie`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> MacieClient {
+        Self::new_with_client(config.client().clone(), config.region_for("macie", "MACIE"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -639,7 +978,49 @@ impl MacieClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MacieClient {
-        MacieClient { client, region }
+        MacieClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "macie")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "macie", "macie", "MACIE")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Macie` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Macie + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for MacieClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Macie"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "macie"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> MacieClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>An entitlement represents capacity in a product owned by the customer. For example, a customer might own some number of users or seats in an SaaS application or some amount of data capacity in a multi-tenant database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -37,8 +38,9 @@ pub struct Entitlement {
     pub dimension: Option<String>,
     /// <p>The expiration date represents the minimum date through which this entitlement is expected to remain valid. For contractual products listed on AWS Marketplace, the expiration date is the date at which the customer will renew or cancel their contract. Customers who are opting to renew their contract will still have entitlements with an expiration date.</p>
     #[serde(rename = "ExpirationDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_date: Option<f64>,
+    pub expiration_date: Option<EpochTimestamp>,
     /// <p>The product code for which the given entitlement applies. Product codes are provided by AWS Marketplace when the product listing is created.</p>
     #[serde(rename = "ProductCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -71,13 +73,85 @@ pub struct EntitlementValue {
     pub string_value: Option<String>,
 }
 
+/// <p>Filter is used to return entitlements for a specific customer or for a specific dimension. Filters are described as keys mapped to a lists of values. Filtered requests are <i>unioned</i> for each value in the value list, and then <i>intersected</i> for each filter key.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GetEntitlementFilterName {
+    /// <p><code>CUSTOMER_IDENTIFIER</code></p>
+    CustomerIdentifier,
+    /// <p><code>DIMENSION</code></p>
+    Dimension,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl GetEntitlementFilterName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GetEntitlementFilterName::CustomerIdentifier => "CUSTOMER_IDENTIFIER",
+            GetEntitlementFilterName::Dimension => "DIMENSION",
+            GetEntitlementFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GetEntitlementFilterName {
+    fn from(value: &'a str) -> GetEntitlementFilterName {
+        match value {
+            "CUSTOMER_IDENTIFIER" => GetEntitlementFilterName::CustomerIdentifier,
+            "DIMENSION" => GetEntitlementFilterName::Dimension,
+            _ => GetEntitlementFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GetEntitlementFilterName {
+    fn from(value: String) -> GetEntitlementFilterName {
+        GetEntitlementFilterName::from(value.as_str())
+    }
+}
+
+impl From<GetEntitlementFilterName> for String {
+    fn from(value: GetEntitlementFilterName) -> String {
+        match value {
+            GetEntitlementFilterName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GetEntitlementFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for GetEntitlementFilterName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GetEntitlementFilterName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<GetEntitlementFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(GetEntitlementFilterName::from(value))
+    }
+}
+
 /// <p>The GetEntitlementsRequest contains parameters for the GetEntitlements operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetEntitlementsRequest {
     /// <p>Filter is used to return entitlements for a specific customer or for a specific dimension. Filters are described as keys mapped to a lists of values. Filtered requests are <i>unioned</i> for each value in the value list, and then <i>intersected</i> for each filter key.</p>
     #[serde(rename = "Filter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub filter: Option<::std::collections::HashMap<String, Vec<String>>>,
+    pub filter: Option<::std::collections::HashMap<GetEntitlementFilterName, Vec<String>>>,
     /// <p>The maximum number of items to retrieve from the GetEntitlements operation. For pagination, use the NextToken field in subsequent calls to GetEntitlements.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -91,6 +165,63 @@ pub struct GetEntitlementsRequest {
     pub product_code: String,
 }
 
+impl GetEntitlementsRequest {
+    /// Returns a builder for `GetEntitlementsRequest`, checking its required members when built.
+    pub fn builder() -> GetEntitlementsRequestBuilder {
+        GetEntitlementsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetEntitlementsRequest`](struct.GetEntitlementsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetEntitlementsRequestBuilder {
+    filter: Option<::std::collections::HashMap<GetEntitlementFilterName, Vec<String>>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    product_code: Option<String>,
+}
+
+impl GetEntitlementsRequestBuilder {
+    /// Sets the [`filter`](struct.GetEntitlementsRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<::std::collections::HashMap<GetEntitlementFilterName, Vec<String>>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetEntitlementsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetEntitlementsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_code`](struct.GetEntitlementsRequest.html#structfield.product_code) member.
+    pub fn product_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetEntitlementsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetEntitlementsRequest, ::rusoto_core::BuildError> {
+        Ok(GetEntitlementsRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            product_code: self
+                .product_code
+                .ok_or(::rusoto_core::BuildError::missing("product_code"))?,
+        })
+    }
+}
+
 /// <p>The GetEntitlementsRequest contains results from the GetEntitlements operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -160,6 +291,21 @@ pub trait MarketplaceEntitlement {
         input: GetEntitlementsRequest,
     ) -> RusotoFuture<GetEntitlementsResult, GetEntitlementsError>;
 }
+impl<T: ?Sized + MarketplaceEntitlement> MarketplaceEntitlement for ::std::sync::Arc<T> {
+    fn get_entitlements(
+        &self,
+        input: GetEntitlementsRequest,
+    ) -> RusotoFuture<GetEntitlementsResult, GetEntitlementsError> {
+        (**self).get_entitlements(input)
+    }
+}
+/// The error codes AWS Marketplace Entitlement Service retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Marketplace Entitlement Service API.
 #[derive(Clone)]
 pub struct MarketplaceEntitlementClient {
@@ -175,6 +321,29 @@ impl MarketplaceEntitlementClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_MARKETPLACE_ENTITLEMENT`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> MarketplaceEntitlementClient {
+        Self::new(region::Region::from_env_for_service(
+            "MARKETPLACE_ENTITLEMENT",
+        ))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `entitlement.marketplace`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> MarketplaceEntitlementClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("entitlement.marketplace", "MARKETPLACE_ENTITLEMENT"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -193,7 +362,54 @@ impl MarketplaceEntitlementClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MarketplaceEntitlementClient {
-        MarketplaceEntitlementClient { client, region }
+        MarketplaceEntitlementClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "entitlement.marketplace")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "entitlement.marketplace",
+            "aws-marketplace",
+            "MARKETPLACE_ENTITLEMENT",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `MarketplaceEntitlement` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn MarketplaceEntitlement + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for MarketplaceEntitlementClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Marketplace Entitlement Service"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "entitlement.marketplace"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> MarketplaceEntitlementClient {
+        Self::new_with_client(client, region)
     }
 }
 
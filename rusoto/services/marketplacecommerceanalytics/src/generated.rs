@@ -22,9 +22,234 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The desired data set type.</p> <p> <ul> <li> <strong>customer_subscriber_hourly_monthly_subscriptions</strong> <p>From 2014-07-21 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>customer_subscriber_annual_subscriptions</strong> <p>From 2014-07-21 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_usage_by_instance_type</strong> <p>From 2015-01-26 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_fees</strong> <p>From 2015-01-26 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_free_trial_conversions</strong> <p>From 2015-01-26 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_new_instances</strong> <p>From 2015-01-26 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_new_product_subscribers</strong> <p>From 2015-01-26 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_canceled_product_subscribers</strong> <p>From 2015-01-26 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>monthly_revenue_billing_and_revenue_data</strong> <p>From 2015-02 to 2017-06: Available monthly on the 4th day of the month by 24:00 UTC. Data includes metered transactions (e.g. hourly) from two months prior.</p> <p>From 2017-07 to present: Available monthly on the 15th day of the month by 24:00 UTC. Data includes metered transactions (e.g. hourly) from one month prior.</p> </li> <li> <strong>monthly_revenue_annual_subscriptions</strong> <p>From 2015-02 to 2017-06: Available monthly on the 4th day of the month by 24:00 UTC. Data includes up-front software charges (e.g. annual) from one month prior.</p> <p>From 2017-07 to present: Available monthly on the 15th day of the month by 24:00 UTC. Data includes up-front software charges (e.g. annual) from one month prior.</p> </li> <li> <strong>monthly_revenue_field_demonstration_usage</strong> <p>From 2018-03-15 to present: Available monthly on the 15th day of the month by 24:00 UTC.</p> </li> <li> <strong>monthly_revenue_flexible_payment_schedule</strong> <p>From 2018-11-15 to present: Available monthly on the 15th day of the month by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_product</strong> <p>From 2015-01-26 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_product_with_uncollected_funds</strong> <p>From 2012-04-19 to 2015-01-25: Available every 30 days by 24:00 UTC.</p> <p>From 2015-01-26 to present: This data set was split into three data sets: disbursed_amount_by_product, disbursed_amount_by_age_of_uncollected_funds, and disbursed_amount_by_age_of_disbursed_funds.</p> </li> <li> <strong>disbursed_amount_by_instance_hours</strong> <p>From 2012-09-04 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_customer_geo</strong> <p>From 2012-04-19 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_age_of_uncollected_funds</strong> <p>From 2015-01-26 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_age_of_disbursed_funds</strong> <p>From 2015-01-26 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>customer_profile_by_industry</strong> <p>From 2015-10-01 to 2017-06-29: Available daily by 24:00 UTC.</p> <p>From 2017-06-30 to present: This data set is no longer available.</p> </li> <li> <strong>customer_profile_by_revenue</strong> <p>From 2015-10-01 to 2017-06-29: Available daily by 24:00 UTC.</p> <p>From 2017-06-30 to present: This data set is no longer available.</p> </li> <li> <strong>customer_profile_by_geography</strong> <p>From 2015-10-01 to 2017-06-29: Available daily by 24:00 UTC.</p> <p>From 2017-06-30 to present: This data set is no longer available.</p> </li> <li> <strong>sales_compensation_billed_revenue</strong> <p>From 2016-12 to 2017-06: Available monthly on the 4th day of the month by 24:00 UTC. Data includes metered transactions (e.g. hourly) from two months prior, and up-front software charges (e.g. annual) from one month prior.</p> <p>From 2017-06 to present: Available monthly on the 15th day of the month by 24:00 UTC. Data includes metered transactions (e.g. hourly) from one month prior, and up-front software charges (e.g. annual) from one month prior.</p> </li> <li> <strong>us_sales_and_use_tax_records</strong> <p>From 2017-02-15 to present: Available monthly on the 15th day of the month by 24:00 UTC.</p> </li> </ul> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DataSetType {
+    /// <p><code>customer_subscriber_hourly_monthly_subscriptions</code></p>
+    CustomerSubscriberHourlyMonthlySubscriptions,
+    /// <p><code>customer_subscriber_annual_subscriptions</code></p>
+    CustomerSubscriberAnnualSubscriptions,
+    /// <p><code>daily_business_usage_by_instance_type</code></p>
+    DailyBusinessUsageByInstanceType,
+    /// <p><code>daily_business_fees</code></p>
+    DailyBusinessFees,
+    /// <p><code>daily_business_free_trial_conversions</code></p>
+    DailyBusinessFreeTrialConversions,
+    /// <p><code>daily_business_new_instances</code></p>
+    DailyBusinessNewInstances,
+    /// <p><code>daily_business_new_product_subscribers</code></p>
+    DailyBusinessNewProductSubscribers,
+    /// <p><code>daily_business_canceled_product_subscribers</code></p>
+    DailyBusinessCanceledProductSubscribers,
+    /// <p><code>monthly_revenue_billing_and_revenue_data</code></p>
+    MonthlyRevenueBillingAndRevenueData,
+    /// <p><code>monthly_revenue_annual_subscriptions</code></p>
+    MonthlyRevenueAnnualSubscriptions,
+    /// <p><code>monthly_revenue_field_demonstration_usage</code></p>
+    MonthlyRevenueFieldDemonstrationUsage,
+    /// <p><code>monthly_revenue_flexible_payment_schedule</code></p>
+    MonthlyRevenueFlexiblePaymentSchedule,
+    /// <p><code>disbursed_amount_by_product</code></p>
+    DisbursedAmountByProduct,
+    /// <p><code>disbursed_amount_by_product_with_uncollected_funds</code></p>
+    DisbursedAmountByProductWithUncollectedFunds,
+    /// <p><code>disbursed_amount_by_instance_hours</code></p>
+    DisbursedAmountByInstanceHours,
+    /// <p><code>disbursed_amount_by_customer_geo</code></p>
+    DisbursedAmountByCustomerGeo,
+    /// <p><code>disbursed_amount_by_age_of_uncollected_funds</code></p>
+    DisbursedAmountByAgeOfUncollectedFunds,
+    /// <p><code>disbursed_amount_by_age_of_disbursed_funds</code></p>
+    DisbursedAmountByAgeOfDisbursedFunds,
+    /// <p><code>disbursed_amount_by_age_of_past_due_funds</code></p>
+    DisbursedAmountByAgeOfPastDueFunds,
+    /// <p><code>disbursed_amount_by_uncollected_funds_breakdown</code></p>
+    DisbursedAmountByUncollectedFundsBreakdown,
+    /// <p><code>customer_profile_by_industry</code></p>
+    CustomerProfileByIndustry,
+    /// <p><code>customer_profile_by_revenue</code></p>
+    CustomerProfileByRevenue,
+    /// <p><code>customer_profile_by_geography</code></p>
+    CustomerProfileByGeography,
+    /// <p><code>sales_compensation_billed_revenue</code></p>
+    SalesCompensationBilledRevenue,
+    /// <p><code>us_sales_and_use_tax_records</code></p>
+    UsSalesAndUseTaxRecords,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DataSetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DataSetType::CustomerSubscriberHourlyMonthlySubscriptions => {
+                "customer_subscriber_hourly_monthly_subscriptions"
+            }
+            DataSetType::CustomerSubscriberAnnualSubscriptions => {
+                "customer_subscriber_annual_subscriptions"
+            }
+            DataSetType::DailyBusinessUsageByInstanceType => {
+                "daily_business_usage_by_instance_type"
+            }
+            DataSetType::DailyBusinessFees => "daily_business_fees",
+            DataSetType::DailyBusinessFreeTrialConversions => {
+                "daily_business_free_trial_conversions"
+            }
+            DataSetType::DailyBusinessNewInstances => "daily_business_new_instances",
+            DataSetType::DailyBusinessNewProductSubscribers => {
+                "daily_business_new_product_subscribers"
+            }
+            DataSetType::DailyBusinessCanceledProductSubscribers => {
+                "daily_business_canceled_product_subscribers"
+            }
+            DataSetType::MonthlyRevenueBillingAndRevenueData => {
+                "monthly_revenue_billing_and_revenue_data"
+            }
+            DataSetType::MonthlyRevenueAnnualSubscriptions => {
+                "monthly_revenue_annual_subscriptions"
+            }
+            DataSetType::MonthlyRevenueFieldDemonstrationUsage => {
+                "monthly_revenue_field_demonstration_usage"
+            }
+            DataSetType::MonthlyRevenueFlexiblePaymentSchedule => {
+                "monthly_revenue_flexible_payment_schedule"
+            }
+            DataSetType::DisbursedAmountByProduct => "disbursed_amount_by_product",
+            DataSetType::DisbursedAmountByProductWithUncollectedFunds => {
+                "disbursed_amount_by_product_with_uncollected_funds"
+            }
+            DataSetType::DisbursedAmountByInstanceHours => "disbursed_amount_by_instance_hours",
+            DataSetType::DisbursedAmountByCustomerGeo => "disbursed_amount_by_customer_geo",
+            DataSetType::DisbursedAmountByAgeOfUncollectedFunds => {
+                "disbursed_amount_by_age_of_uncollected_funds"
+            }
+            DataSetType::DisbursedAmountByAgeOfDisbursedFunds => {
+                "disbursed_amount_by_age_of_disbursed_funds"
+            }
+            DataSetType::DisbursedAmountByAgeOfPastDueFunds => {
+                "disbursed_amount_by_age_of_past_due_funds"
+            }
+            DataSetType::DisbursedAmountByUncollectedFundsBreakdown => {
+                "disbursed_amount_by_uncollected_funds_breakdown"
+            }
+            DataSetType::CustomerProfileByIndustry => "customer_profile_by_industry",
+            DataSetType::CustomerProfileByRevenue => "customer_profile_by_revenue",
+            DataSetType::CustomerProfileByGeography => "customer_profile_by_geography",
+            DataSetType::SalesCompensationBilledRevenue => "sales_compensation_billed_revenue",
+            DataSetType::UsSalesAndUseTaxRecords => "us_sales_and_use_tax_records",
+            DataSetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DataSetType {
+    fn from(value: &'a str) -> DataSetType {
+        match value {
+            "customer_subscriber_hourly_monthly_subscriptions" => {
+                DataSetType::CustomerSubscriberHourlyMonthlySubscriptions
+            }
+            "customer_subscriber_annual_subscriptions" => {
+                DataSetType::CustomerSubscriberAnnualSubscriptions
+            }
+            "daily_business_usage_by_instance_type" => {
+                DataSetType::DailyBusinessUsageByInstanceType
+            }
+            "daily_business_fees" => DataSetType::DailyBusinessFees,
+            "daily_business_free_trial_conversions" => {
+                DataSetType::DailyBusinessFreeTrialConversions
+            }
+            "daily_business_new_instances" => DataSetType::DailyBusinessNewInstances,
+            "daily_business_new_product_subscribers" => {
+                DataSetType::DailyBusinessNewProductSubscribers
+            }
+            "daily_business_canceled_product_subscribers" => {
+                DataSetType::DailyBusinessCanceledProductSubscribers
+            }
+            "monthly_revenue_billing_and_revenue_data" => {
+                DataSetType::MonthlyRevenueBillingAndRevenueData
+            }
+            "monthly_revenue_annual_subscriptions" => {
+                DataSetType::MonthlyRevenueAnnualSubscriptions
+            }
+            "monthly_revenue_field_demonstration_usage" => {
+                DataSetType::MonthlyRevenueFieldDemonstrationUsage
+            }
+            "monthly_revenue_flexible_payment_schedule" => {
+                DataSetType::MonthlyRevenueFlexiblePaymentSchedule
+            }
+            "disbursed_amount_by_product" => DataSetType::DisbursedAmountByProduct,
+            "disbursed_amount_by_product_with_uncollected_funds" => {
+                DataSetType::DisbursedAmountByProductWithUncollectedFunds
+            }
+            "disbursed_amount_by_instance_hours" => DataSetType::DisbursedAmountByInstanceHours,
+            "disbursed_amount_by_customer_geo" => DataSetType::DisbursedAmountByCustomerGeo,
+            "disbursed_amount_by_age_of_uncollected_funds" => {
+                DataSetType::DisbursedAmountByAgeOfUncollectedFunds
+            }
+            "disbursed_amount_by_age_of_disbursed_funds" => {
+                DataSetType::DisbursedAmountByAgeOfDisbursedFunds
+            }
+            "disbursed_amount_by_age_of_past_due_funds" => {
+                DataSetType::DisbursedAmountByAgeOfPastDueFunds
+            }
+            "disbursed_amount_by_uncollected_funds_breakdown" => {
+                DataSetType::DisbursedAmountByUncollectedFundsBreakdown
+            }
+            "customer_profile_by_industry" => DataSetType::CustomerProfileByIndustry,
+            "customer_profile_by_revenue" => DataSetType::CustomerProfileByRevenue,
+            "customer_profile_by_geography" => DataSetType::CustomerProfileByGeography,
+            "sales_compensation_billed_revenue" => DataSetType::SalesCompensationBilledRevenue,
+            "us_sales_and_use_tax_records" => DataSetType::UsSalesAndUseTaxRecords,
+            _ => DataSetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DataSetType {
+    fn from(value: String) -> DataSetType {
+        DataSetType::from(value.as_str())
+    }
+}
+
+impl From<DataSetType> for String {
+    fn from(value: DataSetType) -> String {
+        match value {
+            DataSetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DataSetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DataSetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DataSetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DataSetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DataSetType::from(value))
+    }
+}
+
 /// <p>Container for the parameters to the GenerateDataSet operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GenerateDataSetRequest {
     /// <p>(Optional) Key-value pairs which will be returned, unmodified, in the Amazon SNS notification message and the data set metadata file. These key-value pairs can be used to correlated responses with tracking information from other systems.</p>
     #[serde(rename = "customerDefinedValues")]
@@ -32,10 +257,11 @@ pub struct GenerateDataSetRequest {
     pub customer_defined_values: Option<::std::collections::HashMap<String, String>>,
     /// <p>The date a data set was published. For daily data sets, provide a date with day-level granularity for the desired day. For weekly data sets, provide a date with day-level granularity within the desired week (the day value will be ignored). For monthly data sets, provide a date with month-level granularity for the desired month (the day value will be ignored).</p>
     #[serde(rename = "dataSetPublicationDate")]
-    pub data_set_publication_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub data_set_publication_date: EpochTimestamp,
     /// <p>The desired data set type.</p> <p> <ul> <li> <strong>customer_subscriber_hourly_monthly_subscriptions</strong> <p>From 2014-07-21 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>customer_subscriber_annual_subscriptions</strong> <p>From 2014-07-21 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_usage_by_instance_type</strong> <p>From 2015-01-26 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_fees</strong> <p>From 2015-01-26 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_free_trial_conversions</strong> <p>From 2015-01-26 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_new_instances</strong> <p>From 2015-01-26 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_new_product_subscribers</strong> <p>From 2015-01-26 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>daily_business_canceled_product_subscribers</strong> <p>From 2015-01-26 to present: Available daily by 24:00 UTC.</p> </li> <li> <strong>monthly_revenue_billing_and_revenue_data</strong> <p>From 2015-02 to 2017-06: Available monthly on the 4th day of the month by 24:00 UTC. Data includes metered transactions (e.g. hourly) from two months prior.</p> <p>From 2017-07 to present: Available monthly on the 15th day of the month by 24:00 UTC. Data includes metered transactions (e.g. hourly) from one month prior.</p> </li> <li> <strong>monthly_revenue_annual_subscriptions</strong> <p>From 2015-02 to 2017-06: Available monthly on the 4th day of the month by 24:00 UTC. Data includes up-front software charges (e.g. annual) from one month prior.</p> <p>From 2017-07 to present: Available monthly on the 15th day of the month by 24:00 UTC. Data includes up-front software charges (e.g. annual) from one month prior.</p> </li> <li> <strong>monthly_revenue_field_demonstration_usage</strong> <p>From 2018-03-15 to present: Available monthly on the 15th day of the month by 24:00 UTC.</p> </li> <li> <strong>monthly_revenue_flexible_payment_schedule</strong> <p>From 2018-11-15 to present: Available monthly on the 15th day of the month by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_product</strong> <p>From 2015-01-26 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_product_with_uncollected_funds</strong> <p>From 2012-04-19 to 2015-01-25: Available every 30 days by 24:00 UTC.</p> <p>From 2015-01-26 to present: This data set was split into three data sets: disbursed_amount_by_product, disbursed_amount_by_age_of_uncollected_funds, and disbursed_amount_by_age_of_disbursed_funds.</p> </li> <li> <strong>disbursed_amount_by_instance_hours</strong> <p>From 2012-09-04 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_customer_geo</strong> <p>From 2012-04-19 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_age_of_uncollected_funds</strong> <p>From 2015-01-26 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>disbursed_amount_by_age_of_disbursed_funds</strong> <p>From 2015-01-26 to present: Available every 30 days by 24:00 UTC.</p> </li> <li> <strong>customer_profile_by_industry</strong> <p>From 2015-10-01 to 2017-06-29: Available daily by 24:00 UTC.</p> <p>From 2017-06-30 to present: This data set is no longer available.</p> </li> <li> <strong>customer_profile_by_revenue</strong> <p>From 2015-10-01 to 2017-06-29: Available daily by 24:00 UTC.</p> <p>From 2017-06-30 to present: This data set is no longer available.</p> </li> <li> <strong>customer_profile_by_geography</strong> <p>From 2015-10-01 to 2017-06-29: Available daily by 24:00 UTC.</p> <p>From 2017-06-30 to present: This data set is no longer available.</p> </li> <li> <strong>sales_compensation_billed_revenue</strong> <p>From 2016-12 to 2017-06: Available monthly on the 4th day of the month by 24:00 UTC. Data includes metered transactions (e.g. hourly) from two months prior, and up-front software charges (e.g. annual) from one month prior.</p> <p>From 2017-06 to present: Available monthly on the 15th day of the month by 24:00 UTC. Data includes metered transactions (e.g. hourly) from one month prior, and up-front software charges (e.g. annual) from one month prior.</p> </li> <li> <strong>us_sales_and_use_tax_records</strong> <p>From 2017-02-15 to present: Available monthly on the 15th day of the month by 24:00 UTC.</p> </li> </ul> </p>
     #[serde(rename = "dataSetType")]
-    pub data_set_type: String,
+    pub data_set_type: DataSetType,
     /// <p>The name (friendly name, not ARN) of the destination S3 bucket.</p>
     #[serde(rename = "destinationS3BucketName")]
     pub destination_s3_bucket_name: String,
@@ -51,6 +277,95 @@ pub struct GenerateDataSetRequest {
     pub sns_topic_arn: String,
 }
 
+impl GenerateDataSetRequest {
+    /// Returns a builder for `GenerateDataSetRequest`, checking its required members when built.
+    pub fn builder() -> GenerateDataSetRequestBuilder {
+        GenerateDataSetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GenerateDataSetRequest`](struct.GenerateDataSetRequest.html).
+#[derive(Default, Debug)]
+pub struct GenerateDataSetRequestBuilder {
+    customer_defined_values: Option<::std::collections::HashMap<String, String>>,
+    data_set_publication_date: Option<EpochTimestamp>,
+    data_set_type: Option<DataSetType>,
+    destination_s3_bucket_name: Option<String>,
+    destination_s3_prefix: Option<String>,
+    role_name_arn: Option<String>,
+    sns_topic_arn: Option<String>,
+}
+
+impl GenerateDataSetRequestBuilder {
+    /// Sets the [`customer_defined_values`](struct.GenerateDataSetRequest.html#structfield.customer_defined_values) member.
+    pub fn customer_defined_values<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.customer_defined_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_set_publication_date`](struct.GenerateDataSetRequest.html#structfield.data_set_publication_date) member.
+    pub fn data_set_publication_date<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.data_set_publication_date = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_set_type`](struct.GenerateDataSetRequest.html#structfield.data_set_type) member.
+    pub fn data_set_type<T: Into<DataSetType>>(mut self, value: T) -> Self {
+        self.data_set_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_s3_bucket_name`](struct.GenerateDataSetRequest.html#structfield.destination_s3_bucket_name) member.
+    pub fn destination_s3_bucket_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_s3_bucket_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_s3_prefix`](struct.GenerateDataSetRequest.html#structfield.destination_s3_prefix) member.
+    pub fn destination_s3_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_s3_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name_arn`](struct.GenerateDataSetRequest.html#structfield.role_name_arn) member.
+    pub fn role_name_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.GenerateDataSetRequest.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GenerateDataSetRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GenerateDataSetRequest, ::rusoto_core::BuildError> {
+        Ok(GenerateDataSetRequest {
+            customer_defined_values: self.customer_defined_values,
+            data_set_publication_date: self.data_set_publication_date.ok_or(
+                ::rusoto_core::BuildError::missing("data_set_publication_date"),
+            )?,
+            data_set_type: self
+                .data_set_type
+                .ok_or(::rusoto_core::BuildError::missing("data_set_type"))?,
+            destination_s3_bucket_name: self.destination_s3_bucket_name.ok_or(
+                ::rusoto_core::BuildError::missing("destination_s3_bucket_name"),
+            )?,
+            destination_s3_prefix: self.destination_s3_prefix,
+            role_name_arn: self
+                .role_name_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_name_arn"))?,
+            sns_topic_arn: self
+                .sns_topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("sns_topic_arn"))?,
+        })
+    }
+}
+
 /// <p>Container for the result of the GenerateDataSet operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -62,7 +377,7 @@ pub struct GenerateDataSetResult {
 }
 
 /// <p>Container for the parameters to the StartSupportDataExport operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct StartSupportDataExportRequest {
     /// <p>(Optional) Key-value pairs which will be returned, unmodified, in the Amazon SNS notification message and the data set metadata file.</p>
     #[serde(rename = "customerDefinedValues")]
@@ -70,7 +385,7 @@ pub struct StartSupportDataExportRequest {
     pub customer_defined_values: Option<::std::collections::HashMap<String, String>>,
     /// <p> Specifies the data set type to be written to the output csv file. The data set types customer_support_contacts_data and test_customer_support_contacts_data both result in a csv file containing the following fields: Product Id, Product Code, Customer Guid, Subscription Guid, Subscription Start Date, Organization, AWS Account Id, Given Name, Surname, Telephone Number, Email, Title, Country Code, ZIP Code, Operation Type, and Operation Time. </p> <p> <ul> <li><i>customer_support_contacts_data</i> Customer support contact data. The data set will contain all changes (Creates, Updates, and Deletes) to customer support contact data from the date specified in the from_date parameter.</li> <li><i>test_customer_support_contacts_data</i> An example data set containing static test data in the same format as customer_support_contacts_data</li> </ul> </p>
     #[serde(rename = "dataSetType")]
-    pub data_set_type: String,
+    pub data_set_type: SupportDataSetType,
     /// <p>The name (friendly name, not ARN) of the destination S3 bucket.</p>
     #[serde(rename = "destinationS3BucketName")]
     pub destination_s3_bucket_name: String,
@@ -80,7 +395,8 @@ pub struct StartSupportDataExportRequest {
     pub destination_s3_prefix: Option<String>,
     /// <p>The start date from which to retrieve the data set in UTC. This parameter only affects the customer<em>support</em>contacts_data data set type.</p>
     #[serde(rename = "fromDate")]
-    pub from_date: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub from_date: EpochTimestamp,
     /// <p>The Amazon Resource Name (ARN) of the Role with an attached permissions policy to interact with the provided AWS services.</p>
     #[serde(rename = "roleNameArn")]
     pub role_name_arn: String,
@@ -89,6 +405,97 @@ pub struct StartSupportDataExportRequest {
     pub sns_topic_arn: String,
 }
 
+impl StartSupportDataExportRequest {
+    /// Returns a builder for `StartSupportDataExportRequest`, checking its required members when built.
+    pub fn builder() -> StartSupportDataExportRequestBuilder {
+        StartSupportDataExportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartSupportDataExportRequest`](struct.StartSupportDataExportRequest.html).
+#[derive(Default, Debug)]
+pub struct StartSupportDataExportRequestBuilder {
+    customer_defined_values: Option<::std::collections::HashMap<String, String>>,
+    data_set_type: Option<SupportDataSetType>,
+    destination_s3_bucket_name: Option<String>,
+    destination_s3_prefix: Option<String>,
+    from_date: Option<EpochTimestamp>,
+    role_name_arn: Option<String>,
+    sns_topic_arn: Option<String>,
+}
+
+impl StartSupportDataExportRequestBuilder {
+    /// Sets the [`customer_defined_values`](struct.StartSupportDataExportRequest.html#structfield.customer_defined_values) member.
+    pub fn customer_defined_values<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.customer_defined_values = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_set_type`](struct.StartSupportDataExportRequest.html#structfield.data_set_type) member.
+    pub fn data_set_type<T: Into<SupportDataSetType>>(mut self, value: T) -> Self {
+        self.data_set_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_s3_bucket_name`](struct.StartSupportDataExportRequest.html#structfield.destination_s3_bucket_name) member.
+    pub fn destination_s3_bucket_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_s3_bucket_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_s3_prefix`](struct.StartSupportDataExportRequest.html#structfield.destination_s3_prefix) member.
+    pub fn destination_s3_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_s3_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`from_date`](struct.StartSupportDataExportRequest.html#structfield.from_date) member.
+    pub fn from_date<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.from_date = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name_arn`](struct.StartSupportDataExportRequest.html#structfield.role_name_arn) member.
+    pub fn role_name_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.StartSupportDataExportRequest.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartSupportDataExportRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartSupportDataExportRequest, ::rusoto_core::BuildError> {
+        Ok(StartSupportDataExportRequest {
+            customer_defined_values: self.customer_defined_values,
+            data_set_type: self
+                .data_set_type
+                .ok_or(::rusoto_core::BuildError::missing("data_set_type"))?,
+            destination_s3_bucket_name: self.destination_s3_bucket_name.ok_or(
+                ::rusoto_core::BuildError::missing("destination_s3_bucket_name"),
+            )?,
+            destination_s3_prefix: self.destination_s3_prefix,
+            from_date: self
+                .from_date
+                .ok_or(::rusoto_core::BuildError::missing("from_date"))?,
+            role_name_arn: self
+                .role_name_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_name_arn"))?,
+            sns_topic_arn: self
+                .sns_topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("sns_topic_arn"))?,
+        })
+    }
+}
+
 /// <p>Container for the result of the StartSupportDataExport operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -99,6 +506,82 @@ pub struct StartSupportDataExportResult {
     pub data_set_request_id: Option<String>,
 }
 
+/// <p> Specifies the data set type to be written to the output csv file. The data set types customer_support_contacts_data and test_customer_support_contacts_data both result in a csv file containing the following fields: Product Id, Product Code, Customer Guid, Subscription Guid, Subscription Start Date, Organization, AWS Account Id, Given Name, Surname, Telephone Number, Email, Title, Country Code, ZIP Code, Operation Type, and Operation Time. </p> <p> <ul> <li><i>customer_support_contacts_data</i> Customer support contact data. The data set will contain all changes (Creates, Updates, and Deletes) to customer support contact data from the date specified in the from_date parameter.</li> <li><i>test_customer_support_contacts_data</i> An example data set containing static test data in the same format as customer_support_contacts_data</li> </ul> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SupportDataSetType {
+    /// <p><code>customer_support_contacts_data</code></p>
+    CustomerSupportContactsData,
+    /// <p><code>test_customer_support_contacts_data</code></p>
+    TestCustomerSupportContactsData,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SupportDataSetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SupportDataSetType::CustomerSupportContactsData => "customer_support_contacts_data",
+            SupportDataSetType::TestCustomerSupportContactsData => {
+                "test_customer_support_contacts_data"
+            }
+            SupportDataSetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SupportDataSetType {
+    fn from(value: &'a str) -> SupportDataSetType {
+        match value {
+            "customer_support_contacts_data" => SupportDataSetType::CustomerSupportContactsData,
+            "test_customer_support_contacts_data" => {
+                SupportDataSetType::TestCustomerSupportContactsData
+            }
+            _ => SupportDataSetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SupportDataSetType {
+    fn from(value: String) -> SupportDataSetType {
+        SupportDataSetType::from(value.as_str())
+    }
+}
+
+impl From<SupportDataSetType> for String {
+    fn from(value: SupportDataSetType) -> String {
+        match value {
+            SupportDataSetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SupportDataSetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SupportDataSetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SupportDataSetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SupportDataSetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SupportDataSetType::from(value))
+    }
+}
+
 /// Errors returned by GenerateDataSet
 #[derive(Debug, PartialEq)]
 pub enum GenerateDataSetError {
@@ -183,6 +666,29 @@ pub trait MarketplaceCommerceAnalytics {
         input: StartSupportDataExportRequest,
     ) -> RusotoFuture<StartSupportDataExportResult, StartSupportDataExportError>;
 }
+impl<T: ?Sized + MarketplaceCommerceAnalytics> MarketplaceCommerceAnalytics
+    for ::std::sync::Arc<T>
+{
+    fn generate_data_set(
+        &self,
+        input: GenerateDataSetRequest,
+    ) -> RusotoFuture<GenerateDataSetResult, GenerateDataSetError> {
+        (**self).generate_data_set(input)
+    }
+    fn start_support_data_export(
+        &self,
+        input: StartSupportDataExportRequest,
+    ) -> RusotoFuture<StartSupportDataExportResult, StartSupportDataExportError> {
+        (**self).start_support_data_export(input)
+    }
+}
+/// The error codes AWS Marketplace Commerce Analytics retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Marketplace Commerce Analytics API.
 #[derive(Clone)]
 pub struct MarketplaceCommerceAnalyticsClient {
@@ -198,6 +704,32 @@ impl MarketplaceCommerceAnalyticsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_MARKETPLACECOMMERCEANALYTICS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> MarketplaceCommerceAnalyticsClient {
+        Self::new(region::Region::from_env_for_service(
+            "MARKETPLACECOMMERCEANALYTICS",
+        ))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `marketplacecommerceanalytics`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> MarketplaceCommerceAnalyticsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for(
+                "marketplacecommerceanalytics",
+                "MARKETPLACECOMMERCEANALYTICS",
+            ),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -219,7 +751,54 @@ impl MarketplaceCommerceAnalyticsClient {
         client: Client,
         region: region::Region,
     ) -> MarketplaceCommerceAnalyticsClient {
-        MarketplaceCommerceAnalyticsClient { client, region }
+        MarketplaceCommerceAnalyticsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "marketplacecommerceanalytics")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "marketplacecommerceanalytics",
+            "marketplacecommerceanalytics",
+            "MARKETPLACECOMMERCEANALYTICS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `MarketplaceCommerceAnalytics` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn MarketplaceCommerceAnalytics + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for MarketplaceCommerceAnalyticsClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Marketplace Commerce Analytics"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "marketplacecommerceanalytics"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> MarketplaceCommerceAnalyticsClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Elemental MediaConvert clients.
+//!
+//! Makes a single read-only `ListJobTemplates` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_mediaconvert::{ListJobTemplatesRequest, MediaConvert, MediaConvertClient};
+
+fn main() {
+    let client = MediaConvertClient::from_env();
+    match client
+        .list_job_templates(ListJobTemplatesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListJobTemplates succeeded"),
+        Err(error) => {
+            eprintln!("ListJobTemplates failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Elemental MediaPackage clients.
+//!
+//! Makes a single read-only `ListChannels` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_mediapackage::{ListChannelsRequest, MediaPackage, MediaPackageClient};
+
+fn main() {
+    let client = MediaPackageClient::from_env();
+    match client.list_channels(ListChannelsRequest::default()).sync() {
+        Ok(_) => println!("ListChannels succeeded"),
+        Err(error) => {
+            eprintln!("ListChannels failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -23,7 +23,305 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>This setting controls how ad markers are included in the packaged OriginEndpoint.
+/// &quot;NONE&quot; will omit all SCTE-35 ad markers from the output.
+/// &quot;PASSTHROUGH&quot; causes the manifest to contain a copy of the SCTE-35 ad
+/// markers (comments) taken directly from the input HTTP Live Streaming (HLS) manifest.
+/// &quot;SCTE35_ENHANCED&quot; generates ad markers and blackout tags based on SCTE-35
+/// messages in the input source.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AdMarkers {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>SCTE35_ENHANCED</code></p>
+    Scte35Enhanced,
+    /// <p><code>PASSTHROUGH</code></p>
+    Passthrough,
+    /// <p><code>DATERANGE</code></p>
+    Daterange,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AdMarkers {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdMarkers::None => "NONE",
+            AdMarkers::Scte35Enhanced => "SCTE35_ENHANCED",
+            AdMarkers::Passthrough => "PASSTHROUGH",
+            AdMarkers::Daterange => "DATERANGE",
+            AdMarkers::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AdMarkers {
+    fn from(value: &'a str) -> AdMarkers {
+        match value {
+            "NONE" => AdMarkers::None,
+            "SCTE35_ENHANCED" => AdMarkers::Scte35Enhanced,
+            "PASSTHROUGH" => AdMarkers::Passthrough,
+            "DATERANGE" => AdMarkers::Daterange,
+            _ => AdMarkers::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdMarkers {
+    fn from(value: String) -> AdMarkers {
+        AdMarkers::from(value.as_str())
+    }
+}
+
+impl From<AdMarkers> for String {
+    fn from(value: AdMarkers) -> String {
+        match value {
+            AdMarkers::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AdMarkers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AdMarkers {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AdMarkers {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdMarkers {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AdMarkers, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AdMarkers::from(value))
+    }
+}
+
+/// This setting allows the delivery restriction flags on SCTE-35 segmentation descriptors to
+/// determine whether a message signals an ad.  Choosing "NONE" means no SCTE-35 messages become
+/// ads.  Choosing "RESTRICTED" means SCTE-35 messages of the types specified in AdTriggers that
+/// contain delivery restrictions will be treated as ads.  Choosing "UNRESTRICTED" means SCTE-35
+/// messages of the types specified in AdTriggers that do not contain delivery restrictions will
+/// be treated as ads.  Choosing "BOTH" means all SCTE-35 messages of the types specified in
+/// AdTriggers will be treated as ads.  Note that Splice Insert messages do not have these flags
+/// and are always treated as ads if specified in AdTriggers.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AdsOnDeliveryRestrictions {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>RESTRICTED</code></p>
+    Restricted,
+    /// <p><code>UNRESTRICTED</code></p>
+    Unrestricted,
+    /// <p><code>BOTH</code></p>
+    Both,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AdsOnDeliveryRestrictions {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdsOnDeliveryRestrictions::None => "NONE",
+            AdsOnDeliveryRestrictions::Restricted => "RESTRICTED",
+            AdsOnDeliveryRestrictions::Unrestricted => "UNRESTRICTED",
+            AdsOnDeliveryRestrictions::Both => "BOTH",
+            AdsOnDeliveryRestrictions::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AdsOnDeliveryRestrictions {
+    fn from(value: &'a str) -> AdsOnDeliveryRestrictions {
+        match value {
+            "NONE" => AdsOnDeliveryRestrictions::None,
+            "RESTRICTED" => AdsOnDeliveryRestrictions::Restricted,
+            "UNRESTRICTED" => AdsOnDeliveryRestrictions::Unrestricted,
+            "BOTH" => AdsOnDeliveryRestrictions::Both,
+            _ => AdsOnDeliveryRestrictions::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdsOnDeliveryRestrictions {
+    fn from(value: String) -> AdsOnDeliveryRestrictions {
+        AdsOnDeliveryRestrictions::from(value.as_str())
+    }
+}
+
+impl From<AdsOnDeliveryRestrictions> for String {
+    fn from(value: AdsOnDeliveryRestrictions) -> String {
+        match value {
+            AdsOnDeliveryRestrictions::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AdsOnDeliveryRestrictions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AdsOnDeliveryRestrictions {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AdsOnDeliveryRestrictions {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdsOnDeliveryRestrictions {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AdsOnDeliveryRestrictions, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AdsOnDeliveryRestrictions::from(value))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AdTriggersElement {
+    /// <p><code>SPLICE_INSERT</code></p>
+    SpliceInsert,
+    /// <p><code>BREAK</code></p>
+    Break,
+    /// <p><code>PROVIDER_ADVERTISEMENT</code></p>
+    ProviderAdvertisement,
+    /// <p><code>DISTRIBUTOR_ADVERTISEMENT</code></p>
+    DistributorAdvertisement,
+    /// <p><code>PROVIDER_PLACEMENT_OPPORTUNITY</code></p>
+    ProviderPlacementOpportunity,
+    /// <p><code>DISTRIBUTOR_PLACEMENT_OPPORTUNITY</code></p>
+    DistributorPlacementOpportunity,
+    /// <p><code>PROVIDER_OVERLAY_PLACEMENT_OPPORTUNITY</code></p>
+    ProviderOverlayPlacementOpportunity,
+    /// <p><code>DISTRIBUTOR_OVERLAY_PLACEMENT_OPPORTUNITY</code></p>
+    DistributorOverlayPlacementOpportunity,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AdTriggersElement {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AdTriggersElement::SpliceInsert => "SPLICE_INSERT",
+            AdTriggersElement::Break => "BREAK",
+            AdTriggersElement::ProviderAdvertisement => "PROVIDER_ADVERTISEMENT",
+            AdTriggersElement::DistributorAdvertisement => "DISTRIBUTOR_ADVERTISEMENT",
+            AdTriggersElement::ProviderPlacementOpportunity => "PROVIDER_PLACEMENT_OPPORTUNITY",
+            AdTriggersElement::DistributorPlacementOpportunity => {
+                "DISTRIBUTOR_PLACEMENT_OPPORTUNITY"
+            }
+            AdTriggersElement::ProviderOverlayPlacementOpportunity => {
+                "PROVIDER_OVERLAY_PLACEMENT_OPPORTUNITY"
+            }
+            AdTriggersElement::DistributorOverlayPlacementOpportunity => {
+                "DISTRIBUTOR_OVERLAY_PLACEMENT_OPPORTUNITY"
+            }
+            AdTriggersElement::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AdTriggersElement {
+    fn from(value: &'a str) -> AdTriggersElement {
+        match value {
+            "SPLICE_INSERT" => AdTriggersElement::SpliceInsert,
+            "BREAK" => AdTriggersElement::Break,
+            "PROVIDER_ADVERTISEMENT" => AdTriggersElement::ProviderAdvertisement,
+            "DISTRIBUTOR_ADVERTISEMENT" => AdTriggersElement::DistributorAdvertisement,
+            "PROVIDER_PLACEMENT_OPPORTUNITY" => AdTriggersElement::ProviderPlacementOpportunity,
+            "DISTRIBUTOR_PLACEMENT_OPPORTUNITY" => {
+                AdTriggersElement::DistributorPlacementOpportunity
+            }
+            "PROVIDER_OVERLAY_PLACEMENT_OPPORTUNITY" => {
+                AdTriggersElement::ProviderOverlayPlacementOpportunity
+            }
+            "DISTRIBUTOR_OVERLAY_PLACEMENT_OPPORTUNITY" => {
+                AdTriggersElement::DistributorOverlayPlacementOpportunity
+            }
+            _ => AdTriggersElement::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AdTriggersElement {
+    fn from(value: String) -> AdTriggersElement {
+        AdTriggersElement::from(value.as_str())
+    }
+}
+
+impl From<AdTriggersElement> for String {
+    fn from(value: AdTriggersElement) -> String {
+        match value {
+            AdTriggersElement::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AdTriggersElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for AdTriggersElement {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for AdTriggersElement {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AdTriggersElement {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AdTriggersElement, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AdTriggersElement::from(value))
+    }
+}
+
 /// <p>A Channel resource configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -124,6 +422,50 @@ pub struct CreateChannelRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateChannelRequest {
+    /// Returns a builder for `CreateChannelRequest`, checking its required members when built.
+    pub fn builder() -> CreateChannelRequestBuilder {
+        CreateChannelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateChannelRequest`](struct.CreateChannelRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateChannelRequestBuilder {
+    description: Option<String>,
+    id: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateChannelRequestBuilder {
+    /// Sets the [`description`](struct.CreateChannelRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.CreateChannelRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateChannelRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateChannelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateChannelRequest, ::rusoto_core::BuildError> {
+        Ok(CreateChannelRequest {
+            description: self.description,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateChannelResponse {
@@ -197,6 +539,126 @@ pub struct CreateOriginEndpointRequest {
     pub whitelist: Option<Vec<String>>,
 }
 
+impl CreateOriginEndpointRequest {
+    /// Returns a builder for `CreateOriginEndpointRequest`, checking its required members when built.
+    pub fn builder() -> CreateOriginEndpointRequestBuilder {
+        CreateOriginEndpointRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateOriginEndpointRequest`](struct.CreateOriginEndpointRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateOriginEndpointRequestBuilder {
+    channel_id: Option<String>,
+    cmaf_package: Option<CmafPackageCreateOrUpdateParameters>,
+    dash_package: Option<DashPackage>,
+    description: Option<String>,
+    hls_package: Option<HlsPackage>,
+    id: Option<String>,
+    manifest_name: Option<String>,
+    mss_package: Option<MssPackage>,
+    startover_window_seconds: Option<i64>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    time_delay_seconds: Option<i64>,
+    whitelist: Option<Vec<String>>,
+}
+
+impl CreateOriginEndpointRequestBuilder {
+    /// Sets the [`channel_id`](struct.CreateOriginEndpointRequest.html#structfield.channel_id) member.
+    pub fn channel_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.channel_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cmaf_package`](struct.CreateOriginEndpointRequest.html#structfield.cmaf_package) member.
+    pub fn cmaf_package<T: Into<CmafPackageCreateOrUpdateParameters>>(mut self, value: T) -> Self {
+        self.cmaf_package = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dash_package`](struct.CreateOriginEndpointRequest.html#structfield.dash_package) member.
+    pub fn dash_package<T: Into<DashPackage>>(mut self, value: T) -> Self {
+        self.dash_package = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateOriginEndpointRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hls_package`](struct.CreateOriginEndpointRequest.html#structfield.hls_package) member.
+    pub fn hls_package<T: Into<HlsPackage>>(mut self, value: T) -> Self {
+        self.hls_package = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.CreateOriginEndpointRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manifest_name`](struct.CreateOriginEndpointRequest.html#structfield.manifest_name) member.
+    pub fn manifest_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.manifest_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mss_package`](struct.CreateOriginEndpointRequest.html#structfield.mss_package) member.
+    pub fn mss_package<T: Into<MssPackage>>(mut self, value: T) -> Self {
+        self.mss_package = Some(value.into());
+        self
+    }
+
+    /// Sets the [`startover_window_seconds`](struct.CreateOriginEndpointRequest.html#structfield.startover_window_seconds) member.
+    pub fn startover_window_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.startover_window_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateOriginEndpointRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_delay_seconds`](struct.CreateOriginEndpointRequest.html#structfield.time_delay_seconds) member.
+    pub fn time_delay_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.time_delay_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`whitelist`](struct.CreateOriginEndpointRequest.html#structfield.whitelist) member.
+    pub fn whitelist<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.whitelist = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateOriginEndpointRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateOriginEndpointRequest, ::rusoto_core::BuildError> {
+        Ok(CreateOriginEndpointRequest {
+            channel_id: self
+                .channel_id
+                .ok_or(::rusoto_core::BuildError::missing("channel_id"))?,
+            cmaf_package: self.cmaf_package,
+            dash_package: self.dash_package,
+            description: self.description,
+            hls_package: self.hls_package,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            manifest_name: self.manifest_name,
+            mss_package: self.mss_package,
+            startover_window_seconds: self.startover_window_seconds,
+            tags: self.tags,
+            time_delay_seconds: self.time_delay_seconds,
+            whitelist: self.whitelist,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateOriginEndpointResponse {
@@ -271,17 +733,17 @@ pub struct DashEncryption {
 pub struct DashPackage {
     #[serde(rename = "AdTriggers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_triggers: Option<Vec<String>>,
+    pub ad_triggers: Option<Vec<AdTriggersElement>>,
     #[serde(rename = "AdsOnDeliveryRestrictions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ads_on_delivery_restrictions: Option<String>,
+    pub ads_on_delivery_restrictions: Option<AdsOnDeliveryRestrictions>,
     #[serde(rename = "Encryption")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub encryption: Option<DashEncryption>,
     /// <p>Determines the position of some tags in the Media Presentation Description (MPD).  When set to FULL, elements like SegmentTemplate and ContentProtection are included in each Representation.  When set to COMPACT, duplicate elements are combined and presented at the AdaptationSet level.</p>
     #[serde(rename = "ManifestLayout")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub manifest_layout: Option<String>,
+    pub manifest_layout: Option<ManifestLayout>,
     /// <p>Time window (in seconds) contained in each manifest.</p>
     #[serde(rename = "ManifestWindowSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -300,11 +762,11 @@ pub struct DashPackage {
     /// the Channel source contains SCTE-35 ad markers.</p>
     #[serde(rename = "PeriodTriggers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub period_triggers: Option<Vec<String>>,
+    pub period_triggers: Option<Vec<PeriodTriggersElement>>,
     /// <p>The Dynamic Adaptive Streaming over HTTP (DASH) profile type.  When set to &quot;HBBTV<em>1</em>5&quot;, HbbTV 1.5 compliant output is enabled.</p>
     #[serde(rename = "Profile")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub profile: Option<String>,
+    pub profile: Option<Profile>,
     /// <p>Duration (in seconds) of each segment. Actual segments will be
     /// rounded to the nearest multiple of the source segment duration.</p>
     #[serde(rename = "SegmentDurationSeconds")]
@@ -313,7 +775,7 @@ pub struct DashPackage {
     /// <p>Determines the type of SegmentTemplate included in the Media Presentation Description (MPD).  When set to NUMBER<em>WITH</em>TIMELINE, a full timeline is presented in each SegmentTemplate, with $Number$ media URLs.  When set to TIME<em>WITH</em>TIMELINE, a full timeline is presented in each SegmentTemplate, with $Time$ media URLs. When set to NUMBER<em>WITH</em>DURATION, only a duration is included in each SegmentTemplate, with $Number$ media URLs.</p>
     #[serde(rename = "SegmentTemplateFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub segment_template_format: Option<String>,
+    pub segment_template_format: Option<SegmentTemplateFormat>,
     #[serde(rename = "StreamSelection")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream_selection: Option<StreamSelection>,
@@ -330,6 +792,34 @@ pub struct DeleteChannelRequest {
     pub id: String,
 }
 
+impl DeleteChannelRequest {
+    /// Returns a builder for `DeleteChannelRequest`, checking its required members when built.
+    pub fn builder() -> DeleteChannelRequestBuilder {
+        DeleteChannelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteChannelRequest`](struct.DeleteChannelRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteChannelRequestBuilder {
+    id: Option<String>,
+}
+
+impl DeleteChannelRequestBuilder {
+    /// Sets the [`id`](struct.DeleteChannelRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteChannelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteChannelRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteChannelRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteChannelResponse {}
@@ -341,6 +831,36 @@ pub struct DeleteOriginEndpointRequest {
     pub id: String,
 }
 
+impl DeleteOriginEndpointRequest {
+    /// Returns a builder for `DeleteOriginEndpointRequest`, checking its required members when built.
+    pub fn builder() -> DeleteOriginEndpointRequestBuilder {
+        DeleteOriginEndpointRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteOriginEndpointRequest`](struct.DeleteOriginEndpointRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteOriginEndpointRequestBuilder {
+    id: Option<String>,
+}
+
+impl DeleteOriginEndpointRequestBuilder {
+    /// Sets the [`id`](struct.DeleteOriginEndpointRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteOriginEndpointRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteOriginEndpointRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteOriginEndpointRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteOriginEndpointResponse {}
@@ -352,6 +872,34 @@ pub struct DescribeChannelRequest {
     pub id: String,
 }
 
+impl DescribeChannelRequest {
+    /// Returns a builder for `DescribeChannelRequest`, checking its required members when built.
+    pub fn builder() -> DescribeChannelRequestBuilder {
+        DescribeChannelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeChannelRequest`](struct.DescribeChannelRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeChannelRequestBuilder {
+    id: Option<String>,
+}
+
+impl DescribeChannelRequestBuilder {
+    /// Sets the [`id`](struct.DescribeChannelRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeChannelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeChannelRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeChannelRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeChannelResponse {
@@ -382,6 +930,36 @@ pub struct DescribeOriginEndpointRequest {
     pub id: String,
 }
 
+impl DescribeOriginEndpointRequest {
+    /// Returns a builder for `DescribeOriginEndpointRequest`, checking its required members when built.
+    pub fn builder() -> DescribeOriginEndpointRequestBuilder {
+        DescribeOriginEndpointRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeOriginEndpointRequest`](struct.DescribeOriginEndpointRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeOriginEndpointRequestBuilder {
+    id: Option<String>,
+}
+
+impl DescribeOriginEndpointRequestBuilder {
+    /// Sets the [`id`](struct.DescribeOriginEndpointRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeOriginEndpointRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeOriginEndpointRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeOriginEndpointRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeOriginEndpointResponse {
@@ -440,6 +1018,84 @@ pub struct DescribeOriginEndpointResponse {
     pub whitelist: Option<Vec<String>>,
 }
 
+/// <p>The encryption method to use.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EncryptionMethod {
+    /// <p><code>AES_128</code></p>
+    Aes128,
+    /// <p><code>SAMPLE_AES</code></p>
+    SampleAes,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EncryptionMethod {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EncryptionMethod::Aes128 => "AES_128",
+            EncryptionMethod::SampleAes => "SAMPLE_AES",
+            EncryptionMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EncryptionMethod {
+    fn from(value: &'a str) -> EncryptionMethod {
+        match value {
+            "AES_128" => EncryptionMethod::Aes128,
+            "SAMPLE_AES" => EncryptionMethod::SampleAes,
+            _ => EncryptionMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EncryptionMethod {
+    fn from(value: String) -> EncryptionMethod {
+        EncryptionMethod::from(value.as_str())
+    }
+}
+
+impl From<EncryptionMethod> for String {
+    fn from(value: EncryptionMethod) -> String {
+        match value {
+            EncryptionMethod::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EncryptionMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EncryptionMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EncryptionMethod {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EncryptionMethod {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EncryptionMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EncryptionMethod::from(value))
+    }
+}
+
 /// <p>An HTTP Live Streaming (HLS) encryption configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HlsEncryption {
@@ -451,7 +1107,7 @@ pub struct HlsEncryption {
     /// <p>The encryption method to use.</p>
     #[serde(rename = "EncryptionMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub encryption_method: Option<String>,
+    pub encryption_method: Option<EncryptionMethod>,
     /// <p>Interval (in seconds) between each encryption key rotation.</p>
     #[serde(rename = "KeyRotationIntervalSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -486,7 +1142,7 @@ pub struct HlsManifest {
     /// messages in the input source.</p>
     #[serde(rename = "AdMarkers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_markers: Option<String>,
+    pub ad_markers: Option<AdMarkers>,
     /// <p>The ID of the manifest. The ID must be unique within the OriginEndpoint and it cannot be changed after it is created.</p>
     #[serde(rename = "Id")]
     pub id: String,
@@ -503,7 +1159,7 @@ pub struct HlsManifest {
     /// entry will be included in the media playlist.</p>
     #[serde(rename = "PlaylistType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub playlist_type: Option<String>,
+    pub playlist_type: Option<PlaylistType>,
     /// <p>Time window (in seconds) contained in each parent manifest.</p>
     #[serde(rename = "PlaylistWindowSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -537,13 +1193,13 @@ pub struct HlsManifestCreateOrUpdateParameters {
     /// messages in the input source.</p>
     #[serde(rename = "AdMarkers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_markers: Option<String>,
+    pub ad_markers: Option<AdMarkers>,
     #[serde(rename = "AdTriggers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_triggers: Option<Vec<String>>,
+    pub ad_triggers: Option<Vec<AdTriggersElement>>,
     #[serde(rename = "AdsOnDeliveryRestrictions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ads_on_delivery_restrictions: Option<String>,
+    pub ads_on_delivery_restrictions: Option<AdsOnDeliveryRestrictions>,
     /// <p>The ID of the manifest. The ID must be unique within the OriginEndpoint and it cannot be changed after it is created.</p>
     #[serde(rename = "Id")]
     pub id: String,
@@ -560,7 +1216,7 @@ pub struct HlsManifestCreateOrUpdateParameters {
     /// entry will be included in the media playlist.</p>
     #[serde(rename = "PlaylistType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub playlist_type: Option<String>,
+    pub playlist_type: Option<PlaylistType>,
     /// <p>Time window (in seconds) contained in each parent manifest.</p>
     #[serde(rename = "PlaylistWindowSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -590,13 +1246,13 @@ pub struct HlsPackage {
     /// messages in the input source.</p>
     #[serde(rename = "AdMarkers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_markers: Option<String>,
+    pub ad_markers: Option<AdMarkers>,
     #[serde(rename = "AdTriggers")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ad_triggers: Option<Vec<String>>,
+    pub ad_triggers: Option<Vec<AdTriggersElement>>,
     #[serde(rename = "AdsOnDeliveryRestrictions")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ads_on_delivery_restrictions: Option<String>,
+    pub ads_on_delivery_restrictions: Option<AdsOnDeliveryRestrictions>,
     #[serde(rename = "Encryption")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub encryption: Option<HlsEncryption>,
@@ -609,7 +1265,7 @@ pub struct HlsPackage {
     /// entry will be included in the media playlist.</p>
     #[serde(rename = "PlaylistType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub playlist_type: Option<String>,
+    pub playlist_type: Option<PlaylistType>,
     /// <p>Time window (in seconds) contained in each parent manifest.</p>
     #[serde(rename = "PlaylistWindowSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -674,6 +1330,42 @@ pub struct ListChannelsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListChannelsRequest {
+    /// Returns a builder for `ListChannelsRequest`, checking its required members when built.
+    pub fn builder() -> ListChannelsRequestBuilder {
+        ListChannelsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListChannelsRequest`](struct.ListChannelsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListChannelsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListChannelsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListChannelsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListChannelsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListChannelsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListChannelsRequest, ::rusoto_core::BuildError> {
+        Ok(ListChannelsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListChannelsResponse {
@@ -703,6 +1395,52 @@ pub struct ListOriginEndpointsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListOriginEndpointsRequest {
+    /// Returns a builder for `ListOriginEndpointsRequest`, checking its required members when built.
+    pub fn builder() -> ListOriginEndpointsRequestBuilder {
+        ListOriginEndpointsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListOriginEndpointsRequest`](struct.ListOriginEndpointsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListOriginEndpointsRequestBuilder {
+    channel_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListOriginEndpointsRequestBuilder {
+    /// Sets the [`channel_id`](struct.ListOriginEndpointsRequest.html#structfield.channel_id) member.
+    pub fn channel_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.channel_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListOriginEndpointsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListOriginEndpointsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOriginEndpointsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListOriginEndpointsRequest, ::rusoto_core::BuildError> {
+        Ok(ListOriginEndpointsRequest {
+            channel_id: self.channel_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListOriginEndpointsResponse {
@@ -722,6 +1460,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -730,6 +1500,88 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+/// <p>Determines the position of some tags in the Media Presentation Description (MPD).  When set to FULL, elements like SegmentTemplate and ContentProtection are included in each Representation.  When set to COMPACT, duplicate elements are combined and presented at the AdaptationSet level.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ManifestLayout {
+    /// <p><code>FULL</code></p>
+    Full,
+    /// <p><code>COMPACT</code></p>
+    Compact,
+    /// <p><code>DRM_TOP_LEVEL_COMPACT</code></p>
+    DrmTopLevelCompact,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ManifestLayout {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ManifestLayout::Full => "FULL",
+            ManifestLayout::Compact => "COMPACT",
+            ManifestLayout::DrmTopLevelCompact => "DRM_TOP_LEVEL_COMPACT",
+            ManifestLayout::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ManifestLayout {
+    fn from(value: &'a str) -> ManifestLayout {
+        match value {
+            "FULL" => ManifestLayout::Full,
+            "COMPACT" => ManifestLayout::Compact,
+            "DRM_TOP_LEVEL_COMPACT" => ManifestLayout::DrmTopLevelCompact,
+            _ => ManifestLayout::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ManifestLayout {
+    fn from(value: String) -> ManifestLayout {
+        ManifestLayout::from(value.as_str())
+    }
+}
+
+impl From<ManifestLayout> for String {
+    fn from(value: ManifestLayout) -> String {
+        match value {
+            ManifestLayout::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ManifestLayout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ManifestLayout {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ManifestLayout {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ManifestLayout {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ManifestLayout, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ManifestLayout::from(value))
+    }
+}
+
 /// <p>A Microsoft Smooth Streaming (MSS) encryption configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MssEncryption {
@@ -815,6 +1667,253 @@ pub struct OriginEndpoint {
     pub whitelist: Option<Vec<String>>,
 }
 
+/// <p>The HTTP Live Streaming (HLS) playlist type.
+/// When either &quot;EVENT&quot; or &quot;VOD&quot; is specified, a corresponding EXT-X-PLAYLIST-TYPE
+/// entry will be included in the media playlist.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlaylistType {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>EVENT</code></p>
+    Event,
+    /// <p><code>VOD</code></p>
+    Vod,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PlaylistType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PlaylistType::None => "NONE",
+            PlaylistType::Event => "EVENT",
+            PlaylistType::Vod => "VOD",
+            PlaylistType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PlaylistType {
+    fn from(value: &'a str) -> PlaylistType {
+        match value {
+            "NONE" => PlaylistType::None,
+            "EVENT" => PlaylistType::Event,
+            "VOD" => PlaylistType::Vod,
+            _ => PlaylistType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PlaylistType {
+    fn from(value: String) -> PlaylistType {
+        PlaylistType::from(value.as_str())
+    }
+}
+
+impl From<PlaylistType> for String {
+    fn from(value: PlaylistType) -> String {
+        match value {
+            PlaylistType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PlaylistType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PlaylistType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PlaylistType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PlaylistType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PlaylistType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PlaylistType::from(value))
+    }
+}
+
+/// <p>The Dynamic Adaptive Streaming over HTTP (DASH) profile type.  When set to &quot;HBBTV<em>1</em>5&quot;, HbbTV 1.5 compliant output is enabled.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Profile {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>HBBTV_1_5</code></p>
+    Hbbtv15,
+    /// <p><code>HYBRIDCAST</code></p>
+    Hybridcast,
+    /// <p><code>DVB_DASH_2014</code></p>
+    DvbDash2014,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Profile {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Profile::None => "NONE",
+            Profile::Hbbtv15 => "HBBTV_1_5",
+            Profile::Hybridcast => "HYBRIDCAST",
+            Profile::DvbDash2014 => "DVB_DASH_2014",
+            Profile::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Profile {
+    fn from(value: &'a str) -> Profile {
+        match value {
+            "NONE" => Profile::None,
+            "HBBTV_1_5" => Profile::Hbbtv15,
+            "HYBRIDCAST" => Profile::Hybridcast,
+            "DVB_DASH_2014" => Profile::DvbDash2014,
+            _ => Profile::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Profile {
+    fn from(value: String) -> Profile {
+        Profile::from(value.as_str())
+    }
+}
+
+impl From<Profile> for String {
+    fn from(value: Profile) -> String {
+        match value {
+            Profile::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Profile {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Profile {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Profile {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Profile, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Profile::from(value))
+    }
+}
+
+/// <p>A list of triggers that controls when the outgoing Dynamic Adaptive Streaming over HTTP (DASH)
+/// Media Presentation Description (MPD) will be partitioned into multiple periods. If empty, the content will not
+/// be partitioned into more than one period. If the list contains &quot;ADS&quot;, new periods will be created where
+/// the Channel source contains SCTE-35 ad markers.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PeriodTriggersElement {
+    /// <p><code>ADS</code></p>
+    Ads,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PeriodTriggersElement {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PeriodTriggersElement::Ads => "ADS",
+            PeriodTriggersElement::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PeriodTriggersElement {
+    fn from(value: &'a str) -> PeriodTriggersElement {
+        match value {
+            "ADS" => PeriodTriggersElement::Ads,
+            _ => PeriodTriggersElement::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PeriodTriggersElement {
+    fn from(value: String) -> PeriodTriggersElement {
+        PeriodTriggersElement::from(value.as_str())
+    }
+}
+
+impl From<PeriodTriggersElement> for String {
+    fn from(value: PeriodTriggersElement) -> String {
+        match value {
+            PeriodTriggersElement::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PeriodTriggersElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PeriodTriggersElement {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PeriodTriggersElement {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PeriodTriggersElement {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PeriodTriggersElement, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PeriodTriggersElement::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RotateChannelCredentialsRequest {
     /// <p>The ID of the channel to update.</p>
@@ -822,6 +1921,36 @@ pub struct RotateChannelCredentialsRequest {
     pub id: String,
 }
 
+impl RotateChannelCredentialsRequest {
+    /// Returns a builder for `RotateChannelCredentialsRequest`, checking its required members when built.
+    pub fn builder() -> RotateChannelCredentialsRequestBuilder {
+        RotateChannelCredentialsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RotateChannelCredentialsRequest`](struct.RotateChannelCredentialsRequest.html).
+#[derive(Default, Debug)]
+pub struct RotateChannelCredentialsRequestBuilder {
+    id: Option<String>,
+}
+
+impl RotateChannelCredentialsRequestBuilder {
+    /// Sets the [`id`](struct.RotateChannelCredentialsRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RotateChannelCredentialsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RotateChannelCredentialsRequest, ::rusoto_core::BuildError> {
+        Ok(RotateChannelCredentialsRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RotateChannelCredentialsResponse {
@@ -855,6 +1984,47 @@ pub struct RotateIngestEndpointCredentialsRequest {
     pub ingest_endpoint_id: String,
 }
 
+impl RotateIngestEndpointCredentialsRequest {
+    /// Returns a builder for `RotateIngestEndpointCredentialsRequest`, checking its required members when built.
+    pub fn builder() -> RotateIngestEndpointCredentialsRequestBuilder {
+        RotateIngestEndpointCredentialsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RotateIngestEndpointCredentialsRequest`](struct.RotateIngestEndpointCredentialsRequest.html).
+#[derive(Default, Debug)]
+pub struct RotateIngestEndpointCredentialsRequestBuilder {
+    id: Option<String>,
+    ingest_endpoint_id: Option<String>,
+}
+
+impl RotateIngestEndpointCredentialsRequestBuilder {
+    /// Sets the [`id`](struct.RotateIngestEndpointCredentialsRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ingest_endpoint_id`](struct.RotateIngestEndpointCredentialsRequest.html#structfield.ingest_endpoint_id) member.
+    pub fn ingest_endpoint_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ingest_endpoint_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RotateIngestEndpointCredentialsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RotateIngestEndpointCredentialsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(RotateIngestEndpointCredentialsRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            ingest_endpoint_id: self
+                .ingest_endpoint_id
+                .ok_or(::rusoto_core::BuildError::missing("ingest_endpoint_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RotateIngestEndpointCredentialsResponse {
@@ -878,6 +2048,88 @@ pub struct RotateIngestEndpointCredentialsResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+/// <p>Determines the type of SegmentTemplate included in the Media Presentation Description (MPD).  When set to NUMBER<em>WITH</em>TIMELINE, a full timeline is presented in each SegmentTemplate, with $Number$ media URLs.  When set to TIME<em>WITH</em>TIMELINE, a full timeline is presented in each SegmentTemplate, with $Time$ media URLs. When set to NUMBER<em>WITH</em>DURATION, only a duration is included in each SegmentTemplate, with $Number$ media URLs.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SegmentTemplateFormat {
+    /// <p><code>NUMBER_WITH_TIMELINE</code></p>
+    NumberWithTimeline,
+    /// <p><code>TIME_WITH_TIMELINE</code></p>
+    TimeWithTimeline,
+    /// <p><code>NUMBER_WITH_DURATION</code></p>
+    NumberWithDuration,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SegmentTemplateFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SegmentTemplateFormat::NumberWithTimeline => "NUMBER_WITH_TIMELINE",
+            SegmentTemplateFormat::TimeWithTimeline => "TIME_WITH_TIMELINE",
+            SegmentTemplateFormat::NumberWithDuration => "NUMBER_WITH_DURATION",
+            SegmentTemplateFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SegmentTemplateFormat {
+    fn from(value: &'a str) -> SegmentTemplateFormat {
+        match value {
+            "NUMBER_WITH_TIMELINE" => SegmentTemplateFormat::NumberWithTimeline,
+            "TIME_WITH_TIMELINE" => SegmentTemplateFormat::TimeWithTimeline,
+            "NUMBER_WITH_DURATION" => SegmentTemplateFormat::NumberWithDuration,
+            _ => SegmentTemplateFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SegmentTemplateFormat {
+    fn from(value: String) -> SegmentTemplateFormat {
+        SegmentTemplateFormat::from(value.as_str())
+    }
+}
+
+impl From<SegmentTemplateFormat> for String {
+    fn from(value: SegmentTemplateFormat) -> String {
+        match value {
+            SegmentTemplateFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SegmentTemplateFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SegmentTemplateFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SegmentTemplateFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SegmentTemplateFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SegmentTemplateFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SegmentTemplateFormat::from(value))
+    }
+}
+
 /// <p>A configuration for accessing an external Secure Packager and Encoder Key Exchange (SPEKE) service that will provide encryption keys.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SpekeKeyProvider {
@@ -902,6 +2154,88 @@ pub struct SpekeKeyProvider {
     pub url: String,
 }
 
+/// <p>A directive that determines the order of streams in the output.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StreamOrder {
+    /// <p><code>ORIGINAL</code></p>
+    Original,
+    /// <p><code>VIDEO_BITRATE_ASCENDING</code></p>
+    VideoBitrateAscending,
+    /// <p><code>VIDEO_BITRATE_DESCENDING</code></p>
+    VideoBitrateDescending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StreamOrder {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamOrder::Original => "ORIGINAL",
+            StreamOrder::VideoBitrateAscending => "VIDEO_BITRATE_ASCENDING",
+            StreamOrder::VideoBitrateDescending => "VIDEO_BITRATE_DESCENDING",
+            StreamOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StreamOrder {
+    fn from(value: &'a str) -> StreamOrder {
+        match value {
+            "ORIGINAL" => StreamOrder::Original,
+            "VIDEO_BITRATE_ASCENDING" => StreamOrder::VideoBitrateAscending,
+            "VIDEO_BITRATE_DESCENDING" => StreamOrder::VideoBitrateDescending,
+            _ => StreamOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamOrder {
+    fn from(value: String) -> StreamOrder {
+        StreamOrder::from(value.as_str())
+    }
+}
+
+impl From<StreamOrder> for String {
+    fn from(value: StreamOrder) -> String {
+        match value {
+            StreamOrder::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StreamOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for StreamOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for StreamOrder {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamOrder {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StreamOrder, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StreamOrder::from(value))
+    }
+}
+
 /// <p>A StreamSelection configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StreamSelection {
@@ -916,7 +2250,7 @@ pub struct StreamSelection {
     /// <p>A directive that determines the order of streams in the output.</p>
     #[serde(rename = "StreamOrder")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub stream_order: Option<String>,
+    pub stream_order: Option<StreamOrder>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -927,6 +2261,46 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
     #[serde(rename = "ResourceArn")]
@@ -936,6 +2310,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// <p>Configuration parameters used to update the Channel.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateChannelRequest {
@@ -948,6 +2362,42 @@ pub struct UpdateChannelRequest {
     pub id: String,
 }
 
+impl UpdateChannelRequest {
+    /// Returns a builder for `UpdateChannelRequest`, checking its required members when built.
+    pub fn builder() -> UpdateChannelRequestBuilder {
+        UpdateChannelRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateChannelRequest`](struct.UpdateChannelRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateChannelRequestBuilder {
+    description: Option<String>,
+    id: Option<String>,
+}
+
+impl UpdateChannelRequestBuilder {
+    /// Sets the [`description`](struct.UpdateChannelRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateChannelRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateChannelRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateChannelRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateChannelRequest {
+            description: self.description,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateChannelResponse {
@@ -1013,6 +2463,108 @@ pub struct UpdateOriginEndpointRequest {
     pub whitelist: Option<Vec<String>>,
 }
 
+impl UpdateOriginEndpointRequest {
+    /// Returns a builder for `UpdateOriginEndpointRequest`, checking its required members when built.
+    pub fn builder() -> UpdateOriginEndpointRequestBuilder {
+        UpdateOriginEndpointRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateOriginEndpointRequest`](struct.UpdateOriginEndpointRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateOriginEndpointRequestBuilder {
+    cmaf_package: Option<CmafPackageCreateOrUpdateParameters>,
+    dash_package: Option<DashPackage>,
+    description: Option<String>,
+    hls_package: Option<HlsPackage>,
+    id: Option<String>,
+    manifest_name: Option<String>,
+    mss_package: Option<MssPackage>,
+    startover_window_seconds: Option<i64>,
+    time_delay_seconds: Option<i64>,
+    whitelist: Option<Vec<String>>,
+}
+
+impl UpdateOriginEndpointRequestBuilder {
+    /// Sets the [`cmaf_package`](struct.UpdateOriginEndpointRequest.html#structfield.cmaf_package) member.
+    pub fn cmaf_package<T: Into<CmafPackageCreateOrUpdateParameters>>(mut self, value: T) -> Self {
+        self.cmaf_package = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dash_package`](struct.UpdateOriginEndpointRequest.html#structfield.dash_package) member.
+    pub fn dash_package<T: Into<DashPackage>>(mut self, value: T) -> Self {
+        self.dash_package = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateOriginEndpointRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hls_package`](struct.UpdateOriginEndpointRequest.html#structfield.hls_package) member.
+    pub fn hls_package<T: Into<HlsPackage>>(mut self, value: T) -> Self {
+        self.hls_package = Some(value.into());
+        self
+    }
+
+    /// Sets the [`id`](struct.UpdateOriginEndpointRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manifest_name`](struct.UpdateOriginEndpointRequest.html#structfield.manifest_name) member.
+    pub fn manifest_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.manifest_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mss_package`](struct.UpdateOriginEndpointRequest.html#structfield.mss_package) member.
+    pub fn mss_package<T: Into<MssPackage>>(mut self, value: T) -> Self {
+        self.mss_package = Some(value.into());
+        self
+    }
+
+    /// Sets the [`startover_window_seconds`](struct.UpdateOriginEndpointRequest.html#structfield.startover_window_seconds) member.
+    pub fn startover_window_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.startover_window_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`time_delay_seconds`](struct.UpdateOriginEndpointRequest.html#structfield.time_delay_seconds) member.
+    pub fn time_delay_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.time_delay_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`whitelist`](struct.UpdateOriginEndpointRequest.html#structfield.whitelist) member.
+    pub fn whitelist<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.whitelist = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateOriginEndpointRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateOriginEndpointRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateOriginEndpointRequest {
+            cmaf_package: self.cmaf_package,
+            dash_package: self.dash_package,
+            description: self.description,
+            hls_package: self.hls_package,
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+            manifest_name: self.manifest_name,
+            mss_package: self.mss_package,
+            startover_window_seconds: self.startover_window_seconds,
+            time_delay_seconds: self.time_delay_seconds,
+            whitelist: self.whitelist,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateOriginEndpointResponse {
@@ -2045,6 +3597,100 @@ pub trait MediaPackage {
         input: UpdateOriginEndpointRequest,
     ) -> RusotoFuture<UpdateOriginEndpointResponse, UpdateOriginEndpointError>;
 }
+impl<T: ?Sized + MediaPackage> MediaPackage for ::std::sync::Arc<T> {
+    fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> RusotoFuture<CreateChannelResponse, CreateChannelError> {
+        (**self).create_channel(input)
+    }
+    fn create_origin_endpoint(
+        &self,
+        input: CreateOriginEndpointRequest,
+    ) -> RusotoFuture<CreateOriginEndpointResponse, CreateOriginEndpointError> {
+        (**self).create_origin_endpoint(input)
+    }
+    fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> RusotoFuture<DeleteChannelResponse, DeleteChannelError> {
+        (**self).delete_channel(input)
+    }
+    fn delete_origin_endpoint(
+        &self,
+        input: DeleteOriginEndpointRequest,
+    ) -> RusotoFuture<DeleteOriginEndpointResponse, DeleteOriginEndpointError> {
+        (**self).delete_origin_endpoint(input)
+    }
+    fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> RusotoFuture<DescribeChannelResponse, DescribeChannelError> {
+        (**self).describe_channel(input)
+    }
+    fn describe_origin_endpoint(
+        &self,
+        input: DescribeOriginEndpointRequest,
+    ) -> RusotoFuture<DescribeOriginEndpointResponse, DescribeOriginEndpointError> {
+        (**self).describe_origin_endpoint(input)
+    }
+    fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> RusotoFuture<ListChannelsResponse, ListChannelsError> {
+        (**self).list_channels(input)
+    }
+    fn list_origin_endpoints(
+        &self,
+        input: ListOriginEndpointsRequest,
+    ) -> RusotoFuture<ListOriginEndpointsResponse, ListOriginEndpointsError> {
+        (**self).list_origin_endpoints(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn rotate_channel_credentials(
+        &self,
+        input: RotateChannelCredentialsRequest,
+    ) -> RusotoFuture<RotateChannelCredentialsResponse, RotateChannelCredentialsError> {
+        (**self).rotate_channel_credentials(input)
+    }
+    fn rotate_ingest_endpoint_credentials(
+        &self,
+        input: RotateIngestEndpointCredentialsRequest,
+    ) -> RusotoFuture<RotateIngestEndpointCredentialsResponse, RotateIngestEndpointCredentialsError>
+    {
+        (**self).rotate_ingest_endpoint_credentials(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> RusotoFuture<UpdateChannelResponse, UpdateChannelError> {
+        (**self).update_channel(input)
+    }
+    fn update_origin_endpoint(
+        &self,
+        input: UpdateOriginEndpointRequest,
+    ) -> RusotoFuture<UpdateOriginEndpointResponse, UpdateOriginEndpointError> {
+        (**self).update_origin_endpoint(input)
+    }
+}
+/// The error codes MediaPackage retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the MediaPackage API.
 #[derive(Clone)]
 pub struct MediaPackageClient {
@@ -2060,6 +3706,27 @@ impl MediaPackageClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_MEDIAPACKAGE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> MediaPackageClient {
+        Self::new(region::Region::from_env_for_service("MEDIAPACKAGE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `mediapackage`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> MediaPackageClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("mediapackage", "MEDIAPACKAGE"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2078,7 +3745,54 @@ impl MediaPackageClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MediaPackageClient {
-        MediaPackageClient { client, region }
+        MediaPackageClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "mediapackage")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "mediapackage",
+            "mediapackage",
+            "MEDIAPACKAGE",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `MediaPackage` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn MediaPackage + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for MediaPackageClient {
+    fn service_name(&self) -> &'static str {
+        "MediaPackage"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "mediapackage"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> MediaPackageClient {
+        Self::new_with_client(client, region)
     }
 }
 
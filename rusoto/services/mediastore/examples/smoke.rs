@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Elemental MediaStore clients.
+//!
+//! Makes a single read-only `ListContainers` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_mediastore::{ListContainersInput, MediaStore, MediaStoreClient};
+
+fn main() {
+    let client = MediaStoreClient::from_env();
+    match client
+        .list_containers(ListContainersInput::default())
+        .sync()
+    {
+        Ok(_) => println!("ListContainers succeeded"),
+        Err(error) => {
+            eprintln!("ListContainers failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
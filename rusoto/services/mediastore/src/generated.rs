@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>This section describes operations that you can perform on an AWS Elemental MediaStore container.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -37,8 +38,9 @@ pub struct Container {
     pub access_logging_enabled: Option<bool>,
     /// <p>Unix timestamp.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The DNS endpoint of the container. Use the endpoint to identify the specific container when sending requests to the data plane. The service assigns this value when the container is created. Once the value has been assigned, it does not change.</p>
     #[serde(rename = "Endpoint")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -50,7 +52,83 @@ pub struct Container {
     /// <p>The status of container creation or deletion. The status is one of the following: <code>CREATING</code>, <code>ACTIVE</code>, or <code>DELETING</code>. While the service is creating the container, the status is <code>CREATING</code>. When the endpoint is available, the status changes to <code>ACTIVE</code>.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ContainerStatus>,
+}
+
+/// <p>The status of container creation or deletion. The status is one of the following: <code>CREATING</code>, <code>ACTIVE</code>, or <code>DELETING</code>. While the service is creating the container, the status is <code>CREATING</code>. When the endpoint is available, the status changes to <code>ACTIVE</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContainerStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContainerStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContainerStatus::Active => "ACTIVE",
+            ContainerStatus::Creating => "CREATING",
+            ContainerStatus::Deleting => "DELETING",
+            ContainerStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContainerStatus {
+    fn from(value: &'a str) -> ContainerStatus {
+        match value {
+            "ACTIVE" => ContainerStatus::Active,
+            "CREATING" => ContainerStatus::Creating,
+            "DELETING" => ContainerStatus::Deleting,
+            _ => ContainerStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContainerStatus {
+    fn from(value: String) -> ContainerStatus {
+        ContainerStatus::from(value.as_str())
+    }
+}
+
+impl From<ContainerStatus> for String {
+    fn from(value: ContainerStatus) -> String {
+        match value {
+            ContainerStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContainerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ContainerStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContainerStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContainerStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContainerStatus::from(value))
+    }
 }
 
 /// <p>A rule for a CORS policy. You can add up to 100 rules to a CORS policy. If more than one rule applies, the service uses the first applicable rule listed.</p>
@@ -62,7 +140,7 @@ pub struct CorsRule {
     /// <p>Identifies an HTTP method that the origin that is specified in the rule is allowed to execute.</p> <p>Each CORS rule must contain at least one <code>AllowedMethods</code> and one <code>AllowedOrigins</code> element.</p>
     #[serde(rename = "AllowedMethods")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub allowed_methods: Option<Vec<String>>,
+    pub allowed_methods: Option<Vec<MethodName>>,
     /// <p>One or more response headers that you want users to be able to access from their applications (for example, from a JavaScript <code>XMLHttpRequest</code> object).</p> <p>Each CORS rule must have at least one <code>AllowedOrigins</code> element. The string value can include only one wildcard character (*), for example, http://*.example.com. Additionally, you can specify only one wildcard character to allow cross-origin access for all origins.</p>
     #[serde(rename = "AllowedOrigins")]
     pub allowed_origins: Vec<String>,
@@ -87,6 +165,44 @@ pub struct CreateContainerInput {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateContainerInput {
+    /// Returns a builder for `CreateContainerInput`, checking its required members when built.
+    pub fn builder() -> CreateContainerInputBuilder {
+        CreateContainerInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateContainerInput`](struct.CreateContainerInput.html).
+#[derive(Default, Debug)]
+pub struct CreateContainerInputBuilder {
+    container_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateContainerInputBuilder {
+    /// Sets the [`container_name`](struct.CreateContainerInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateContainerInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateContainerInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateContainerInput, ::rusoto_core::BuildError> {
+        Ok(CreateContainerInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateContainerOutput {
@@ -102,6 +218,36 @@ pub struct DeleteContainerInput {
     pub container_name: String,
 }
 
+impl DeleteContainerInput {
+    /// Returns a builder for `DeleteContainerInput`, checking its required members when built.
+    pub fn builder() -> DeleteContainerInputBuilder {
+        DeleteContainerInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteContainerInput`](struct.DeleteContainerInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteContainerInputBuilder {
+    container_name: Option<String>,
+}
+
+impl DeleteContainerInputBuilder {
+    /// Sets the [`container_name`](struct.DeleteContainerInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteContainerInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteContainerInput, ::rusoto_core::BuildError> {
+        Ok(DeleteContainerInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteContainerOutput {}
@@ -113,6 +259,38 @@ pub struct DeleteContainerPolicyInput {
     pub container_name: String,
 }
 
+impl DeleteContainerPolicyInput {
+    /// Returns a builder for `DeleteContainerPolicyInput`, checking its required members when built.
+    pub fn builder() -> DeleteContainerPolicyInputBuilder {
+        DeleteContainerPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteContainerPolicyInput`](struct.DeleteContainerPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteContainerPolicyInputBuilder {
+    container_name: Option<String>,
+}
+
+impl DeleteContainerPolicyInputBuilder {
+    /// Sets the [`container_name`](struct.DeleteContainerPolicyInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteContainerPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteContainerPolicyInput, ::rusoto_core::BuildError> {
+        Ok(DeleteContainerPolicyInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteContainerPolicyOutput {}
@@ -124,6 +302,36 @@ pub struct DeleteCorsPolicyInput {
     pub container_name: String,
 }
 
+impl DeleteCorsPolicyInput {
+    /// Returns a builder for `DeleteCorsPolicyInput`, checking its required members when built.
+    pub fn builder() -> DeleteCorsPolicyInputBuilder {
+        DeleteCorsPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCorsPolicyInput`](struct.DeleteCorsPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteCorsPolicyInputBuilder {
+    container_name: Option<String>,
+}
+
+impl DeleteCorsPolicyInputBuilder {
+    /// Sets the [`container_name`](struct.DeleteCorsPolicyInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCorsPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteCorsPolicyInput, ::rusoto_core::BuildError> {
+        Ok(DeleteCorsPolicyInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteCorsPolicyOutput {}
@@ -135,6 +343,38 @@ pub struct DeleteLifecyclePolicyInput {
     pub container_name: String,
 }
 
+impl DeleteLifecyclePolicyInput {
+    /// Returns a builder for `DeleteLifecyclePolicyInput`, checking its required members when built.
+    pub fn builder() -> DeleteLifecyclePolicyInputBuilder {
+        DeleteLifecyclePolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLifecyclePolicyInput`](struct.DeleteLifecyclePolicyInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteLifecyclePolicyInputBuilder {
+    container_name: Option<String>,
+}
+
+impl DeleteLifecyclePolicyInputBuilder {
+    /// Sets the [`container_name`](struct.DeleteLifecyclePolicyInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLifecyclePolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteLifecyclePolicyInput, ::rusoto_core::BuildError> {
+        Ok(DeleteLifecyclePolicyInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteLifecyclePolicyOutput {}
@@ -147,6 +387,34 @@ pub struct DescribeContainerInput {
     pub container_name: Option<String>,
 }
 
+impl DescribeContainerInput {
+    /// Returns a builder for `DescribeContainerInput`, checking its required members when built.
+    pub fn builder() -> DescribeContainerInputBuilder {
+        DescribeContainerInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeContainerInput`](struct.DescribeContainerInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeContainerInputBuilder {
+    container_name: Option<String>,
+}
+
+impl DescribeContainerInputBuilder {
+    /// Sets the [`container_name`](struct.DescribeContainerInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeContainerInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeContainerInput, ::rusoto_core::BuildError> {
+        Ok(DescribeContainerInput {
+            container_name: self.container_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeContainerOutput {
@@ -163,6 +431,38 @@ pub struct GetContainerPolicyInput {
     pub container_name: String,
 }
 
+impl GetContainerPolicyInput {
+    /// Returns a builder for `GetContainerPolicyInput`, checking its required members when built.
+    pub fn builder() -> GetContainerPolicyInputBuilder {
+        GetContainerPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetContainerPolicyInput`](struct.GetContainerPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct GetContainerPolicyInputBuilder {
+    container_name: Option<String>,
+}
+
+impl GetContainerPolicyInputBuilder {
+    /// Sets the [`container_name`](struct.GetContainerPolicyInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetContainerPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetContainerPolicyInput, ::rusoto_core::BuildError> {
+        Ok(GetContainerPolicyInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetContainerPolicyOutput {
@@ -178,6 +478,36 @@ pub struct GetCorsPolicyInput {
     pub container_name: String,
 }
 
+impl GetCorsPolicyInput {
+    /// Returns a builder for `GetCorsPolicyInput`, checking its required members when built.
+    pub fn builder() -> GetCorsPolicyInputBuilder {
+        GetCorsPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetCorsPolicyInput`](struct.GetCorsPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct GetCorsPolicyInputBuilder {
+    container_name: Option<String>,
+}
+
+impl GetCorsPolicyInputBuilder {
+    /// Sets the [`container_name`](struct.GetCorsPolicyInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCorsPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetCorsPolicyInput, ::rusoto_core::BuildError> {
+        Ok(GetCorsPolicyInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCorsPolicyOutput {
@@ -193,6 +523,38 @@ pub struct GetLifecyclePolicyInput {
     pub container_name: String,
 }
 
+impl GetLifecyclePolicyInput {
+    /// Returns a builder for `GetLifecyclePolicyInput`, checking its required members when built.
+    pub fn builder() -> GetLifecyclePolicyInputBuilder {
+        GetLifecyclePolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetLifecyclePolicyInput`](struct.GetLifecyclePolicyInput.html).
+#[derive(Default, Debug)]
+pub struct GetLifecyclePolicyInputBuilder {
+    container_name: Option<String>,
+}
+
+impl GetLifecyclePolicyInputBuilder {
+    /// Sets the [`container_name`](struct.GetLifecyclePolicyInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLifecyclePolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLifecyclePolicyInput, ::rusoto_core::BuildError> {
+        Ok(GetLifecyclePolicyInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLifecyclePolicyOutput {
@@ -213,6 +575,42 @@ pub struct ListContainersInput {
     pub next_token: Option<String>,
 }
 
+impl ListContainersInput {
+    /// Returns a builder for `ListContainersInput`, checking its required members when built.
+    pub fn builder() -> ListContainersInputBuilder {
+        ListContainersInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListContainersInput`](struct.ListContainersInput.html).
+#[derive(Default, Debug)]
+pub struct ListContainersInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListContainersInputBuilder {
+    /// Sets the [`max_results`](struct.ListContainersInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListContainersInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListContainersInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListContainersInput, ::rusoto_core::BuildError> {
+        Ok(ListContainersInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListContainersOutput {
@@ -232,6 +630,38 @@ pub struct ListTagsForResourceInput {
     pub resource: String,
 }
 
+impl ListTagsForResourceInput {
+    /// Returns a builder for `ListTagsForResourceInput`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceInputBuilder {
+        ListTagsForResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceInput`](struct.ListTagsForResourceInput.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceInputBuilder {
+    resource: Option<String>,
+}
+
+impl ListTagsForResourceInputBuilder {
+    /// Sets the [`resource`](struct.ListTagsForResourceInput.html#structfield.resource) member.
+    pub fn resource<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceInput, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceInput {
+            resource: self
+                .resource
+                .ok_or(::rusoto_core::BuildError::missing("resource"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceOutput {
@@ -241,6 +671,86 @@ pub struct ListTagsForResourceOutput {
     pub tags: Option<Vec<Tag>>,
 }
 
+/// <p>Identifies an HTTP method that the origin that is specified in the rule is allowed to execute.</p> <p>Each CORS rule must contain at least one <code>AllowedMethods</code> and one <code>AllowedOrigins</code> element.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MethodName {
+    /// <p><code>PUT</code></p>
+    Put,
+    /// <p><code>GET</code></p>
+    Get,
+    /// <p><code>DELETE</code></p>
+    Delete,
+    /// <p><code>HEAD</code></p>
+    Head,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MethodName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MethodName::Put => "PUT",
+            MethodName::Get => "GET",
+            MethodName::Delete => "DELETE",
+            MethodName::Head => "HEAD",
+            MethodName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MethodName {
+    fn from(value: &'a str) -> MethodName {
+        match value {
+            "PUT" => MethodName::Put,
+            "GET" => MethodName::Get,
+            "DELETE" => MethodName::Delete,
+            "HEAD" => MethodName::Head,
+            _ => MethodName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MethodName {
+    fn from(value: String) -> MethodName {
+        MethodName::from(value.as_str())
+    }
+}
+
+impl From<MethodName> for String {
+    fn from(value: MethodName) -> String {
+        match value {
+            MethodName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MethodName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MethodName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MethodName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MethodName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MethodName::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutContainerPolicyInput {
     /// <p>The name of the container.</p>
@@ -251,6 +761,48 @@ pub struct PutContainerPolicyInput {
     pub policy: String,
 }
 
+impl PutContainerPolicyInput {
+    /// Returns a builder for `PutContainerPolicyInput`, checking its required members when built.
+    pub fn builder() -> PutContainerPolicyInputBuilder {
+        PutContainerPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutContainerPolicyInput`](struct.PutContainerPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct PutContainerPolicyInputBuilder {
+    container_name: Option<String>,
+    policy: Option<String>,
+}
+
+impl PutContainerPolicyInputBuilder {
+    /// Sets the [`container_name`](struct.PutContainerPolicyInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy`](struct.PutContainerPolicyInput.html#structfield.policy) member.
+    pub fn policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutContainerPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutContainerPolicyInput, ::rusoto_core::BuildError> {
+        Ok(PutContainerPolicyInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+            policy: self
+                .policy
+                .ok_or(::rusoto_core::BuildError::missing("policy"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutContainerPolicyOutput {}
@@ -265,6 +817,46 @@ pub struct PutCorsPolicyInput {
     pub cors_policy: Vec<CorsRule>,
 }
 
+impl PutCorsPolicyInput {
+    /// Returns a builder for `PutCorsPolicyInput`, checking its required members when built.
+    pub fn builder() -> PutCorsPolicyInputBuilder {
+        PutCorsPolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutCorsPolicyInput`](struct.PutCorsPolicyInput.html).
+#[derive(Default, Debug)]
+pub struct PutCorsPolicyInputBuilder {
+    container_name: Option<String>,
+    cors_policy: Option<Vec<CorsRule>>,
+}
+
+impl PutCorsPolicyInputBuilder {
+    /// Sets the [`container_name`](struct.PutCorsPolicyInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cors_policy`](struct.PutCorsPolicyInput.html#structfield.cors_policy) member.
+    pub fn cors_policy<T: Into<Vec<CorsRule>>>(mut self, value: T) -> Self {
+        self.cors_policy = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutCorsPolicyInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutCorsPolicyInput, ::rusoto_core::BuildError> {
+        Ok(PutCorsPolicyInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+            cors_policy: self
+                .cors_policy
+                .ok_or(::rusoto_core::BuildError::missing("cors_policy"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutCorsPolicyOutput {}
@@ -279,6 +871,48 @@ pub struct PutLifecyclePolicyInput {
     pub lifecycle_policy: String,
 }
 
+impl PutLifecyclePolicyInput {
+    /// Returns a builder for `PutLifecyclePolicyInput`, checking its required members when built.
+    pub fn builder() -> PutLifecyclePolicyInputBuilder {
+        PutLifecyclePolicyInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutLifecyclePolicyInput`](struct.PutLifecyclePolicyInput.html).
+#[derive(Default, Debug)]
+pub struct PutLifecyclePolicyInputBuilder {
+    container_name: Option<String>,
+    lifecycle_policy: Option<String>,
+}
+
+impl PutLifecyclePolicyInputBuilder {
+    /// Sets the [`container_name`](struct.PutLifecyclePolicyInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_policy`](struct.PutLifecyclePolicyInput.html#structfield.lifecycle_policy) member.
+    pub fn lifecycle_policy<T: Into<String>>(mut self, value: T) -> Self {
+        self.lifecycle_policy = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutLifecyclePolicyInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutLifecyclePolicyInput, ::rusoto_core::BuildError> {
+        Ok(PutLifecyclePolicyInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+            lifecycle_policy: self
+                .lifecycle_policy
+                .ok_or(::rusoto_core::BuildError::missing("lifecycle_policy"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutLifecyclePolicyOutput {}
@@ -290,6 +924,38 @@ pub struct StartAccessLoggingInput {
     pub container_name: String,
 }
 
+impl StartAccessLoggingInput {
+    /// Returns a builder for `StartAccessLoggingInput`, checking its required members when built.
+    pub fn builder() -> StartAccessLoggingInputBuilder {
+        StartAccessLoggingInputBuilder::default()
+    }
+}
+
+/// Builds a [`StartAccessLoggingInput`](struct.StartAccessLoggingInput.html).
+#[derive(Default, Debug)]
+pub struct StartAccessLoggingInputBuilder {
+    container_name: Option<String>,
+}
+
+impl StartAccessLoggingInputBuilder {
+    /// Sets the [`container_name`](struct.StartAccessLoggingInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartAccessLoggingInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartAccessLoggingInput, ::rusoto_core::BuildError> {
+        Ok(StartAccessLoggingInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartAccessLoggingOutput {}
@@ -301,6 +967,36 @@ pub struct StopAccessLoggingInput {
     pub container_name: String,
 }
 
+impl StopAccessLoggingInput {
+    /// Returns a builder for `StopAccessLoggingInput`, checking its required members when built.
+    pub fn builder() -> StopAccessLoggingInputBuilder {
+        StopAccessLoggingInputBuilder::default()
+    }
+}
+
+/// Builds a [`StopAccessLoggingInput`](struct.StopAccessLoggingInput.html).
+#[derive(Default, Debug)]
+pub struct StopAccessLoggingInputBuilder {
+    container_name: Option<String>,
+}
+
+impl StopAccessLoggingInputBuilder {
+    /// Sets the [`container_name`](struct.StopAccessLoggingInput.html#structfield.container_name) member.
+    pub fn container_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.container_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopAccessLoggingInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopAccessLoggingInput, ::rusoto_core::BuildError> {
+        Ok(StopAccessLoggingInput {
+            container_name: self
+                .container_name
+                .ok_or(::rusoto_core::BuildError::missing("container_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopAccessLoggingOutput {}
@@ -328,6 +1024,46 @@ pub struct TagResourceInput {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceInput {
+    /// Returns a builder for `TagResourceInput`, checking its required members when built.
+    pub fn builder() -> TagResourceInputBuilder {
+        TagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceInput`](struct.TagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct TagResourceInputBuilder {
+    resource: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceInputBuilder {
+    /// Sets the [`resource`](struct.TagResourceInput.html#structfield.resource) member.
+    pub fn resource<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceInput.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceInput, ::rusoto_core::BuildError> {
+        Ok(TagResourceInput {
+            resource: self
+                .resource
+                .ok_or(::rusoto_core::BuildError::missing("resource"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceOutput {}
@@ -342,6 +1078,46 @@ pub struct UntagResourceInput {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceInput {
+    /// Returns a builder for `UntagResourceInput`, checking its required members when built.
+    pub fn builder() -> UntagResourceInputBuilder {
+        UntagResourceInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceInput`](struct.UntagResourceInput.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceInputBuilder {
+    resource: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceInputBuilder {
+    /// Sets the [`resource`](struct.UntagResourceInput.html#structfield.resource) member.
+    pub fn resource<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceInput, ::rusoto_core::BuildError> {
+        Ok(UntagResourceInput {
+            resource: self
+                .resource
+                .ok_or(::rusoto_core::BuildError::missing("resource"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceOutput {}
@@ -1330,6 +2106,123 @@ pub trait MediaStore {
         input: UntagResourceInput,
     ) -> RusotoFuture<UntagResourceOutput, UntagResourceError>;
 }
+impl<T: ?Sized + MediaStore> MediaStore for ::std::sync::Arc<T> {
+    fn create_container(
+        &self,
+        input: CreateContainerInput,
+    ) -> RusotoFuture<CreateContainerOutput, CreateContainerError> {
+        (**self).create_container(input)
+    }
+    fn delete_container(
+        &self,
+        input: DeleteContainerInput,
+    ) -> RusotoFuture<DeleteContainerOutput, DeleteContainerError> {
+        (**self).delete_container(input)
+    }
+    fn delete_container_policy(
+        &self,
+        input: DeleteContainerPolicyInput,
+    ) -> RusotoFuture<DeleteContainerPolicyOutput, DeleteContainerPolicyError> {
+        (**self).delete_container_policy(input)
+    }
+    fn delete_cors_policy(
+        &self,
+        input: DeleteCorsPolicyInput,
+    ) -> RusotoFuture<DeleteCorsPolicyOutput, DeleteCorsPolicyError> {
+        (**self).delete_cors_policy(input)
+    }
+    fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyInput,
+    ) -> RusotoFuture<DeleteLifecyclePolicyOutput, DeleteLifecyclePolicyError> {
+        (**self).delete_lifecycle_policy(input)
+    }
+    fn describe_container(
+        &self,
+        input: DescribeContainerInput,
+    ) -> RusotoFuture<DescribeContainerOutput, DescribeContainerError> {
+        (**self).describe_container(input)
+    }
+    fn get_container_policy(
+        &self,
+        input: GetContainerPolicyInput,
+    ) -> RusotoFuture<GetContainerPolicyOutput, GetContainerPolicyError> {
+        (**self).get_container_policy(input)
+    }
+    fn get_cors_policy(
+        &self,
+        input: GetCorsPolicyInput,
+    ) -> RusotoFuture<GetCorsPolicyOutput, GetCorsPolicyError> {
+        (**self).get_cors_policy(input)
+    }
+    fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyInput,
+    ) -> RusotoFuture<GetLifecyclePolicyOutput, GetLifecyclePolicyError> {
+        (**self).get_lifecycle_policy(input)
+    }
+    fn list_containers(
+        &self,
+        input: ListContainersInput,
+    ) -> RusotoFuture<ListContainersOutput, ListContainersError> {
+        (**self).list_containers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> RusotoFuture<ListTagsForResourceOutput, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_container_policy(
+        &self,
+        input: PutContainerPolicyInput,
+    ) -> RusotoFuture<PutContainerPolicyOutput, PutContainerPolicyError> {
+        (**self).put_container_policy(input)
+    }
+    fn put_cors_policy(
+        &self,
+        input: PutCorsPolicyInput,
+    ) -> RusotoFuture<PutCorsPolicyOutput, PutCorsPolicyError> {
+        (**self).put_cors_policy(input)
+    }
+    fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyInput,
+    ) -> RusotoFuture<PutLifecyclePolicyOutput, PutLifecyclePolicyError> {
+        (**self).put_lifecycle_policy(input)
+    }
+    fn start_access_logging(
+        &self,
+        input: StartAccessLoggingInput,
+    ) -> RusotoFuture<StartAccessLoggingOutput, StartAccessLoggingError> {
+        (**self).start_access_logging(input)
+    }
+    fn stop_access_logging(
+        &self,
+        input: StopAccessLoggingInput,
+    ) -> RusotoFuture<StopAccessLoggingOutput, StopAccessLoggingError> {
+        (**self).stop_access_logging(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> RusotoFuture<TagResourceOutput, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> RusotoFuture<UntagResourceOutput, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// The error codes MediaStore retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the MediaStore API.
 #[derive(Clone)]
 pub struct MediaStoreClient {
@@ -1345,6 +2238,27 @@ impl MediaStoreClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_MEDIASTORE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> MediaStoreClient {
+        Self::new(region::Region::from_env_for_service("MEDIASTORE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `mediastore`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> MediaStoreClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("mediastore", "MEDIASTORE"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1363,7 +2277,54 @@ impl MediaStoreClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MediaStoreClient {
-        MediaStoreClient { client, region }
+        MediaStoreClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "mediastore")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "mediastore",
+            "mediastore",
+            "MEDIASTORE",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `MediaStore` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn MediaStore + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for MediaStoreClient {
+    fn service_name(&self) -> &'static str {
+        "MediaStore"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "mediastore"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> MediaStoreClient {
+        Self::new_with_client(client, region)
     }
 }
 
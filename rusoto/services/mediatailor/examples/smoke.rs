@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS MediaTailor clients.
+//!
+//! Makes a single read-only `ListPlaybackConfigurations` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_mediatailor::{ListPlaybackConfigurationsRequest, MediaTailor, MediaTailorClient};
+
+fn main() {
+    let client = MediaTailorClient::from_env();
+    match client
+        .list_playback_configurations(ListPlaybackConfigurationsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListPlaybackConfigurations succeeded"),
+        Err(error) => {
+            eprintln!("ListPlaybackConfigurations failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
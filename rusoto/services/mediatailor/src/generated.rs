@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>The configuration for using a content delivery network (CDN), like Amazon CloudFront, for content and ad segment management. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -52,7 +53,7 @@ pub struct DashConfiguration {
     /// <p>The setting that controls whether MediaTailor handles manifests from the origin server as multi-period manifests or single-period manifests. If your origin server produces single-period manifests, set this to SINGLE_PERIOD. The default setting is MULTI_PERIOD. For multi-period manifests, omit this setting or set it to MULTI_PERIOD. </p>
     #[serde(rename = "OriginManifestType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origin_manifest_type: Option<String>,
+    pub origin_manifest_type: Option<OriginManifestType>,
 }
 
 /// <p>The configuration for DASH PUT operations. </p>
@@ -65,7 +66,7 @@ pub struct DashConfigurationForPut {
     /// <p>The setting that controls whether MediaTailor handles manifests from the origin server as multi-period manifests or single-period manifests. If your origin server produces single-period manifests, set this to SINGLE_PERIOD. The default setting is MULTI_PERIOD. For multi-period manifests, omit this setting or set it to MULTI_PERIOD. </p>
     #[serde(rename = "OriginManifestType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub origin_manifest_type: Option<String>,
+    pub origin_manifest_type: Option<OriginManifestType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -75,6 +76,38 @@ pub struct DeletePlaybackConfigurationRequest {
     pub name: String,
 }
 
+impl DeletePlaybackConfigurationRequest {
+    /// Returns a builder for `DeletePlaybackConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DeletePlaybackConfigurationRequestBuilder {
+        DeletePlaybackConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePlaybackConfigurationRequest`](struct.DeletePlaybackConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePlaybackConfigurationRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeletePlaybackConfigurationRequestBuilder {
+    /// Sets the [`name`](struct.DeletePlaybackConfigurationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePlaybackConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeletePlaybackConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePlaybackConfigurationRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeletePlaybackConfigurationResponse {}
@@ -86,6 +119,38 @@ pub struct GetPlaybackConfigurationRequest {
     pub name: String,
 }
 
+impl GetPlaybackConfigurationRequest {
+    /// Returns a builder for `GetPlaybackConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> GetPlaybackConfigurationRequestBuilder {
+        GetPlaybackConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPlaybackConfigurationRequest`](struct.GetPlaybackConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPlaybackConfigurationRequestBuilder {
+    name: Option<String>,
+}
+
+impl GetPlaybackConfigurationRequestBuilder {
+    /// Sets the [`name`](struct.GetPlaybackConfigurationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPlaybackConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetPlaybackConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(GetPlaybackConfigurationRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetPlaybackConfigurationResponse {
@@ -161,6 +226,44 @@ pub struct ListPlaybackConfigurationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListPlaybackConfigurationsRequest {
+    /// Returns a builder for `ListPlaybackConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> ListPlaybackConfigurationsRequestBuilder {
+        ListPlaybackConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPlaybackConfigurationsRequest`](struct.ListPlaybackConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPlaybackConfigurationsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListPlaybackConfigurationsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListPlaybackConfigurationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPlaybackConfigurationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPlaybackConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPlaybackConfigurationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPlaybackConfigurationsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPlaybackConfigurationsResponse {
@@ -181,6 +284,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -195,6 +330,84 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+/// <p>The setting that controls whether MediaTailor handles manifests from the origin server as multi-period manifests or single-period manifests. If your origin server produces single-period manifests, set this to SINGLE_PERIOD. The default setting is MULTI_PERIOD. For multi-period manifests, omit this setting or set it to MULTI_PERIOD. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OriginManifestType {
+    /// <p><code>SINGLE_PERIOD</code></p>
+    SinglePeriod,
+    /// <p><code>MULTI_PERIOD</code></p>
+    MultiPeriod,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OriginManifestType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OriginManifestType::SinglePeriod => "SINGLE_PERIOD",
+            OriginManifestType::MultiPeriod => "MULTI_PERIOD",
+            OriginManifestType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OriginManifestType {
+    fn from(value: &'a str) -> OriginManifestType {
+        match value {
+            "SINGLE_PERIOD" => OriginManifestType::SinglePeriod,
+            "MULTI_PERIOD" => OriginManifestType::MultiPeriod,
+            _ => OriginManifestType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OriginManifestType {
+    fn from(value: String) -> OriginManifestType {
+        OriginManifestType::from(value.as_str())
+    }
+}
+
+impl From<OriginManifestType> for String {
+    fn from(value: OriginManifestType) -> String {
+        match value {
+            OriginManifestType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OriginManifestType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OriginManifestType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OriginManifestType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OriginManifestType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OriginManifestType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OriginManifestType::from(value))
+    }
+}
+
 /// <p>The AWSMediaTailor configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -285,6 +498,92 @@ pub struct PutPlaybackConfigurationRequest {
     pub video_content_source_url: Option<String>,
 }
 
+impl PutPlaybackConfigurationRequest {
+    /// Returns a builder for `PutPlaybackConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> PutPlaybackConfigurationRequestBuilder {
+        PutPlaybackConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutPlaybackConfigurationRequest`](struct.PutPlaybackConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct PutPlaybackConfigurationRequestBuilder {
+    ad_decision_server_url: Option<String>,
+    cdn_configuration: Option<CdnConfiguration>,
+    dash_configuration: Option<DashConfigurationForPut>,
+    name: Option<String>,
+    slate_ad_url: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    transcode_profile_name: Option<String>,
+    video_content_source_url: Option<String>,
+}
+
+impl PutPlaybackConfigurationRequestBuilder {
+    /// Sets the [`ad_decision_server_url`](struct.PutPlaybackConfigurationRequest.html#structfield.ad_decision_server_url) member.
+    pub fn ad_decision_server_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.ad_decision_server_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cdn_configuration`](struct.PutPlaybackConfigurationRequest.html#structfield.cdn_configuration) member.
+    pub fn cdn_configuration<T: Into<CdnConfiguration>>(mut self, value: T) -> Self {
+        self.cdn_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dash_configuration`](struct.PutPlaybackConfigurationRequest.html#structfield.dash_configuration) member.
+    pub fn dash_configuration<T: Into<DashConfigurationForPut>>(mut self, value: T) -> Self {
+        self.dash_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.PutPlaybackConfigurationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`slate_ad_url`](struct.PutPlaybackConfigurationRequest.html#structfield.slate_ad_url) member.
+    pub fn slate_ad_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.slate_ad_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.PutPlaybackConfigurationRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transcode_profile_name`](struct.PutPlaybackConfigurationRequest.html#structfield.transcode_profile_name) member.
+    pub fn transcode_profile_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.transcode_profile_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`video_content_source_url`](struct.PutPlaybackConfigurationRequest.html#structfield.video_content_source_url) member.
+    pub fn video_content_source_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.video_content_source_url = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutPlaybackConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutPlaybackConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(PutPlaybackConfigurationRequest {
+            ad_decision_server_url: self.ad_decision_server_url,
+            cdn_configuration: self.cdn_configuration,
+            dash_configuration: self.dash_configuration,
+            name: self.name,
+            slate_ad_url: self.slate_ad_url,
+            tags: self.tags,
+            transcode_profile_name: self.transcode_profile_name,
+            video_content_source_url: self.video_content_source_url,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutPlaybackConfigurationResponse {
@@ -353,6 +652,46 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) for the playback configuration. You can get this from the response to any playback configuration request. </p>
@@ -363,6 +702,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// Errors returned by DeletePlaybackConfiguration
 #[derive(Debug, PartialEq)]
 pub enum DeletePlaybackConfigurationError {}
@@ -604,6 +983,51 @@ pub trait MediaTailor {
     /// <p>Removes tags from the specified playback configuration resource. You can specify one or more tags to remove. </p>
     fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError>;
 }
+impl<T: ?Sized + MediaTailor> MediaTailor for ::std::sync::Arc<T> {
+    fn delete_playback_configuration(
+        &self,
+        input: DeletePlaybackConfigurationRequest,
+    ) -> RusotoFuture<DeletePlaybackConfigurationResponse, DeletePlaybackConfigurationError> {
+        (**self).delete_playback_configuration(input)
+    }
+    fn get_playback_configuration(
+        &self,
+        input: GetPlaybackConfigurationRequest,
+    ) -> RusotoFuture<GetPlaybackConfigurationResponse, GetPlaybackConfigurationError> {
+        (**self).get_playback_configuration(input)
+    }
+    fn list_playback_configurations(
+        &self,
+        input: ListPlaybackConfigurationsRequest,
+    ) -> RusotoFuture<ListPlaybackConfigurationsResponse, ListPlaybackConfigurationsError> {
+        (**self).list_playback_configurations(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn put_playback_configuration(
+        &self,
+        input: PutPlaybackConfigurationRequest,
+    ) -> RusotoFuture<PutPlaybackConfigurationResponse, PutPlaybackConfigurationError> {
+        (**self).put_playback_configuration(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+}
+/// The error codes MediaTailor retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the MediaTailor API.
 #[derive(Clone)]
 pub struct MediaTailorClient {
@@ -619,6 +1043,27 @@ impl MediaTailorClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_MEDIATAILOR`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> MediaTailorClient {
+        Self::new(region::Region::from_env_for_service("MEDIATAILOR"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `api.mediatailor`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> MediaTailorClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("api.mediatailor", "MEDIATAILOR"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -637,7 +1082,54 @@ impl MediaTailorClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MediaTailorClient {
-        MediaTailorClient { client, region }
+        MediaTailorClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "api.mediatailor")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "api.mediatailor",
+            "mediatailor",
+            "MEDIATAILOR",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `MediaTailor` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn MediaTailor + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for MediaTailorClient {
+    fn service_name(&self) -> &'static str {
+        "MediaTailor"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "api.mediatailor"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> MediaTailorClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,399 @@
+//! Batching usage records and submitting them with idempotent retries.
+//!
+//! AWS Marketplace meters usage per customer, dimension and hour: a second record for the same
+//! hour is reported as a `DuplicateRecord` rather than added to the first, and records more than
+//! a few hours old are rejected. `UsageBatcher` sums usage per hour so that a single record is
+//! sent for each, timestamped at the start of its hour. Submitting those records again, e.g.
+//! after a failure, gives the same records, which Marketplace recognizes as already metered.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::time::SystemTime;
+//!
+//! use rusoto_core::Region;
+//! use rusoto_meteringmarketplace::metering::{MeteringConfig, MeteringExt, UsageBatcher};
+//! use rusoto_meteringmarketplace::MarketplaceMeteringClient;
+//!
+//! let client = MarketplaceMeteringClient::new(Region::UsEast1);
+//! let mut batcher = UsageBatcher::new();
+//! batcher.add("customer-1", "requests", 20, SystemTime::now());
+//! batcher.add("customer-1", "requests", 5, SystemTime::now());
+//!
+//! let report = client
+//!     .submit_usage(
+//!         "my-product-code",
+//!         batcher.take_completed(SystemTime::now()),
+//!         MeteringConfig::default(),
+//!     )
+//!     .sync()
+//!     .unwrap();
+//! for record in report.dropped.iter().chain(&report.late) {
+//!     println!("not metered: {:?}", record);
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::future::{self, Either, Loop};
+use futures::{stream, Future, Stream};
+use rusoto_core::timestamp::{epoch_timestamp, to_epoch_seconds};
+use rusoto_core::waiter::sleep;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    BatchMeterUsageError, BatchMeterUsageRequest, MarketplaceMetering, UsageRecord,
+    UsageRecordResult, UsageRecordResultStatus,
+};
+
+/// The most records `BatchMeterUsage` accepts per request.
+const MAX_BATCH_SIZE: usize = 25;
+
+const SECONDS_PER_HOUR: u64 = 3600;
+
+/// The customer, dimension and hour (since the Unix epoch) usage is metered per.
+type UsageKey = (String, String, u64);
+
+/// The hour since the Unix epoch `time` is in.
+fn hour_of(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|since| since.as_secs() / SECONDS_PER_HOUR)
+        .unwrap_or(0)
+}
+
+/// The hour since the Unix epoch a record is in.
+fn hour_of_record(record: &UsageRecord) -> u64 {
+    let seconds = to_epoch_seconds(&record.timestamp);
+    if seconds > 0.0 {
+        seconds as u64 / SECONDS_PER_HOUR
+    } else {
+        0
+    }
+}
+
+/// Sums usage per customer, dimension and hour.
+#[derive(Clone, Debug, Default)]
+pub struct UsageBatcher {
+    usage: BTreeMap<UsageKey, i64>,
+}
+
+impl UsageBatcher {
+    /// An empty batcher.
+    pub fn new() -> UsageBatcher {
+        UsageBatcher::default()
+    }
+
+    /// Adds `quantity` units of `dimension` used by `customer` at `time`.
+    pub fn add(&mut self, customer: &str, dimension: &str, quantity: i64, time: SystemTime) {
+        let key = (customer.to_owned(), dimension.to_owned(), hour_of(time));
+        *self.usage.entry(key).or_insert(0) += quantity;
+    }
+
+    /// Adds the usage of `record`, in the hour of its timestamp.
+    pub fn add_record(&mut self, record: &UsageRecord) {
+        let key = (
+            record.customer_identifier.clone(),
+            record.dimension.clone(),
+            hour_of_record(record),
+        );
+        *self.usage.entry(key).or_insert(0) += record.quantity.unwrap_or(0);
+    }
+
+    /// Removes the records of the hours which ended before `now`, so that no more usage is
+    /// added to them.
+    pub fn take_completed(&mut self, now: SystemTime) -> Vec<UsageRecord> {
+        let current_hour = hour_of(now);
+        let completed = self
+            .usage
+            .keys()
+            .filter(|&&(_, _, hour)| hour < current_hour)
+            .cloned()
+            .collect::<Vec<_>>();
+        completed
+            .into_iter()
+            .map(|key| {
+                let quantity = self.usage.remove(&key).unwrap_or(0);
+                usage_record(key, quantity)
+            })
+            .collect()
+    }
+
+    /// Removes the records of every hour, including the current one.
+    pub fn take_all(&mut self) -> Vec<UsageRecord> {
+        let usage = std::mem::replace(&mut self.usage, BTreeMap::new());
+        usage
+            .into_iter()
+            .map(|(key, quantity)| usage_record(key, quantity))
+            .collect()
+    }
+
+    /// The number of records the batcher holds.
+    pub fn len(&self) -> usize {
+        self.usage.len()
+    }
+
+    /// Whether the batcher holds no usage.
+    pub fn is_empty(&self) -> bool {
+        self.usage.is_empty()
+    }
+}
+
+fn usage_record((customer, dimension, hour): UsageKey, quantity: i64) -> UsageRecord {
+    UsageRecord {
+        customer_identifier: customer,
+        dimension,
+        quantity: Some(quantity),
+        timestamp: epoch_timestamp((hour * SECONDS_PER_HOUR) as f64),
+    }
+}
+
+/// How usage is submitted.
+#[derive(Clone, Debug)]
+pub struct MeteringConfig {
+    /// The most times a batch is sent, including the first (3 by default). Batches which failed
+    /// with a transient error and records Marketplace didn't process are sent again.
+    pub max_attempts: u32,
+    /// How old records may be (6 hours by default). Older records are reported as late
+    /// without being sent, as Marketplace would reject them.
+    pub max_age: Duration,
+}
+
+impl Default for MeteringConfig {
+    fn default() -> MeteringConfig {
+        MeteringConfig {
+            max_attempts: 3,
+            max_age: Duration::from_secs(6 * SECONDS_PER_HOUR),
+        }
+    }
+}
+
+/// The outcome of submitting usage records.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeteringReport {
+    /// The records which were metered
+    pub accepted: Vec<UsageRecordResult>,
+    /// The records of hours which were already metered, e.g. by an earlier submission
+    pub duplicates: Vec<UsageRecordResult>,
+    /// The records of customers who aren't subscribed to the product
+    pub not_subscribed: Vec<UsageRecordResult>,
+    /// The records with any other status
+    pub other: Vec<UsageRecordResult>,
+    /// The records which weren't sent because they were older than `max_age`
+    pub late: Vec<UsageRecord>,
+    /// The records Marketplace still hadn't processed after `max_attempts`
+    pub dropped: Vec<UsageRecord>,
+}
+
+impl MeteringReport {
+    /// Whether every record sent was metered or had already been metered.
+    pub fn is_complete(&self) -> bool {
+        self.not_subscribed.is_empty()
+            && self.other.is_empty()
+            && self.late.is_empty()
+            && self.dropped.is_empty()
+    }
+
+    fn add_results(&mut self, results: Vec<UsageRecordResult>) {
+        for result in results {
+            match result.status {
+                Some(UsageRecordResultStatus::Success) => self.accepted.push(result),
+                Some(UsageRecordResultStatus::DuplicateRecord) => self.duplicates.push(result),
+                Some(UsageRecordResultStatus::CustomerNotSubscribed) => {
+                    self.not_subscribed.push(result)
+                }
+                _ => self.other.push(result),
+            }
+        }
+    }
+
+    fn extend(&mut self, other: MeteringReport) {
+        self.accepted.extend(other.accepted);
+        self.duplicates.extend(other.duplicates);
+        self.not_subscribed.extend(other.not_subscribed);
+        self.other.extend(other.other);
+        self.late.extend(other.late);
+        self.dropped.extend(other.dropped);
+    }
+}
+
+/// Merges the records of the same customer, dimension and hour, and separates those older than
+/// `max_age` at `now`.
+fn prepare_records(
+    records: Vec<UsageRecord>,
+    max_age: Duration,
+    now: SystemTime,
+) -> (Vec<UsageRecord>, Vec<UsageRecord>) {
+    let oldest = now
+        .checked_sub(max_age)
+        .and_then(|oldest| oldest.duration_since(UNIX_EPOCH).ok())
+        .map(|since| since.as_secs() as f64)
+        .unwrap_or(0.0);
+    let (current, late): (Vec<_>, Vec<_>) = records
+        .into_iter()
+        .partition(|record| to_epoch_seconds(&record.timestamp) >= oldest);
+    let mut batcher = UsageBatcher::new();
+    for record in &current {
+        batcher.add_record(record);
+    }
+    (batcher.take_all(), late)
+}
+
+fn is_transient(error: &RusotoError<BatchMeterUsageError>) -> bool {
+    match *error {
+        RusotoError::Service(BatchMeterUsageError::Throttling(_))
+        | RusotoError::Service(BatchMeterUsageError::InternalServiceError(_))
+        | RusotoError::HttpDispatch(_) => true,
+        RusotoError::Unknown(ref response) => response.status.is_server_error(),
+        _ => false,
+    }
+}
+
+/// Sends a batch, sending it again after transient errors and sending the records Marketplace
+/// didn't process again, unchanged, until `max_attempts`.
+fn submit_batch<C>(
+    client: C,
+    product_code: String,
+    records: Vec<UsageRecord>,
+    max_attempts: u32,
+) -> impl Future<Item = MeteringReport, Error = RusotoError<BatchMeterUsageError>>
+where
+    C: MarketplaceMetering + Send + 'static,
+{
+    let initial = (records, MeteringReport::default(), 1);
+    future::loop_fn(initial, move |(records, mut report, attempt)| {
+        let request = BatchMeterUsageRequest {
+            product_code: product_code.clone(),
+            usage_records: records.clone(),
+        };
+        let backoff = Duration::from_millis(100u64 << attempt.min(6));
+        client
+            .batch_meter_usage(request)
+            .then(move |result| match result {
+                Err(ref e) if attempt < max_attempts && is_transient(e) => Either::A(Either::A(
+                    sleep(backoff).map(move |_| Loop::Continue((records, report, attempt + 1))),
+                )),
+                Err(e) => Either::B(future::err(e)),
+                Ok(output) => {
+                    report.add_results(output.results.unwrap_or_default());
+                    let unprocessed = output.unprocessed_records.unwrap_or_default();
+                    if unprocessed.is_empty() {
+                        Either::B(future::ok(Loop::Break(report)))
+                    } else if attempt < max_attempts {
+                        Either::A(Either::B(
+                            sleep(backoff)
+                                .map(move |_| Loop::Continue((unprocessed, report, attempt + 1))),
+                        ))
+                    } else {
+                        report.dropped.extend(unprocessed);
+                        Either::B(future::ok(Loop::Break(report)))
+                    }
+                }
+            })
+    })
+}
+
+/// Extension methods for submitting usage records.
+pub trait MeteringExt: MarketplaceMetering + Clone + Send + Sized + 'static {
+    /// Submits usage `records` of the product `product_code` in batches.
+    ///
+    /// Records of the same customer, dimension and hour are merged into one timestamped at
+    /// the start of the hour, and records older than `config.max_age` are reported as late
+    /// without being sent. Batches are sent one after the other; if one fails, the usage can be
+    /// submitted again as a whole, since the records of the batches already sent aren't
+    /// metered twice.
+    fn submit_usage(
+        &self,
+        product_code: &str,
+        records: Vec<UsageRecord>,
+        config: MeteringConfig,
+    ) -> RusotoFuture<MeteringReport, BatchMeterUsageError> {
+        let (records, late) = prepare_records(records, config.max_age, SystemTime::now());
+        let initial = MeteringReport {
+            late,
+            ..Default::default()
+        };
+        let batches = records
+            .chunks(MAX_BATCH_SIZE)
+            .map(<[UsageRecord]>::to_vec)
+            .collect::<Vec<_>>();
+        let client = self.clone();
+        let product_code = product_code.to_owned();
+        let max_attempts = config.max_attempts.max(1);
+        let future = stream::iter_ok(batches).fold(initial, move |mut report, batch| {
+            submit_batch(client.clone(), product_code.clone(), batch, max_attempts).map(
+                move |batch_report| {
+                    report.extend(batch_report);
+                    report
+                },
+            )
+        });
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: MarketplaceMetering + Clone + Send + Sized + 'static> MeteringExt for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    fn record(customer: &str, seconds: u64, quantity: i64) -> UsageRecord {
+        UsageRecord {
+            customer_identifier: customer.to_owned(),
+            dimension: "requests".to_owned(),
+            quantity: Some(quantity),
+            timestamp: epoch_timestamp(seconds as f64),
+        }
+    }
+
+    #[test]
+    fn batcher_sums_usage_per_hour() {
+        let mut batcher = UsageBatcher::new();
+        batcher.add("a", "requests", 2, at(7200 + 10));
+        batcher.add("a", "requests", 3, at(7200 + 3599));
+        batcher.add("a", "requests", 4, at(10800));
+        batcher.add("b", "requests", 1, at(7200));
+        assert_eq!(batcher.len(), 3);
+
+        let completed = batcher.take_completed(at(10800 + 60));
+        assert_eq!(completed, vec![record("a", 7200, 5), record("b", 7200, 1)]);
+        assert_eq!(batcher.take_all(), vec![record("a", 10800, 4)]);
+        assert!(batcher.is_empty());
+    }
+
+    #[test]
+    fn prepares_records_for_sending() {
+        let now = at(10 * 3600 + 60);
+        let records = vec![
+            record("a", 9 * 3600 + 5, 1),
+            record("a", 9 * 3600 + 50, 2),
+            record("b", 3 * 3600, 7),
+        ];
+        let (current, late) = prepare_records(records, Duration::from_secs(6 * 3600), now);
+        assert_eq!(current, vec![record("a", 9 * 3600, 3)]);
+        assert_eq!(late, vec![record("b", 3 * 3600, 7)]);
+    }
+
+    #[test]
+    fn reports_results_by_status() {
+        let result = |status: &str| UsageRecordResult {
+            status: Some(status.into()),
+            ..Default::default()
+        };
+        let mut report = MeteringReport::default();
+        report.add_results(vec![
+            result("Success"),
+            result("DuplicateRecord"),
+            result("CustomerNotSubscribed"),
+        ]);
+        assert_eq!(report.accepted.len(), 1);
+        assert_eq!(report.duplicates.len(), 1);
+        assert_eq!(report.not_subscribed.len(), 1);
+        assert!(!report.is_complete());
+    }
+}
@@ -1 +1,2 @@
-
+/// Batching usage records and submitting them with idempotent retries
+pub mod metering;
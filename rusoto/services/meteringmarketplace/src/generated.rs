@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>A BatchMeterUsageRequest contains UsageRecords, which indicate quantities of usage within your application.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -34,6 +35,46 @@ pub struct BatchMeterUsageRequest {
     pub usage_records: Vec<UsageRecord>,
 }
 
+impl BatchMeterUsageRequest {
+    /// Returns a builder for `BatchMeterUsageRequest`, checking its required members when built.
+    pub fn builder() -> BatchMeterUsageRequestBuilder {
+        BatchMeterUsageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchMeterUsageRequest`](struct.BatchMeterUsageRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchMeterUsageRequestBuilder {
+    product_code: Option<String>,
+    usage_records: Option<Vec<UsageRecord>>,
+}
+
+impl BatchMeterUsageRequestBuilder {
+    /// Sets the [`product_code`](struct.BatchMeterUsageRequest.html#structfield.product_code) member.
+    pub fn product_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`usage_records`](struct.BatchMeterUsageRequest.html#structfield.usage_records) member.
+    pub fn usage_records<T: Into<Vec<UsageRecord>>>(mut self, value: T) -> Self {
+        self.usage_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchMeterUsageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<BatchMeterUsageRequest, ::rusoto_core::BuildError> {
+        Ok(BatchMeterUsageRequest {
+            product_code: self
+                .product_code
+                .ok_or(::rusoto_core::BuildError::missing("product_code"))?,
+            usage_records: self
+                .usage_records
+                .ok_or(::rusoto_core::BuildError::missing("usage_records"))?,
+        })
+    }
+}
+
 /// <p>Contains the UsageRecords processed by BatchMeterUsage and any records that have failed due to transient error.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -59,7 +100,8 @@ pub struct MeterUsageRequest {
     pub product_code: String,
     /// <p>Timestamp, in UTC, for which the usage is being reported. Your application can meter usage for up to one hour in the past. Make sure the timestamp value is not before the start of the software usage.</p>
     #[serde(rename = "Timestamp")]
-    pub timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub timestamp: EpochTimestamp,
     /// <p>It will be one of the fcp dimension name provided during the publishing of the product.</p>
     #[serde(rename = "UsageDimension")]
     pub usage_dimension: String,
@@ -69,6 +111,72 @@ pub struct MeterUsageRequest {
     pub usage_quantity: Option<i64>,
 }
 
+impl MeterUsageRequest {
+    /// Returns a builder for `MeterUsageRequest`, checking its required members when built.
+    pub fn builder() -> MeterUsageRequestBuilder {
+        MeterUsageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`MeterUsageRequest`](struct.MeterUsageRequest.html).
+#[derive(Default, Debug)]
+pub struct MeterUsageRequestBuilder {
+    dry_run: Option<bool>,
+    product_code: Option<String>,
+    timestamp: Option<EpochTimestamp>,
+    usage_dimension: Option<String>,
+    usage_quantity: Option<i64>,
+}
+
+impl MeterUsageRequestBuilder {
+    /// Sets the [`dry_run`](struct.MeterUsageRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_code`](struct.MeterUsageRequest.html#structfield.product_code) member.
+    pub fn product_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timestamp`](struct.MeterUsageRequest.html#structfield.timestamp) member.
+    pub fn timestamp<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.timestamp = Some(value.into());
+        self
+    }
+
+    /// Sets the [`usage_dimension`](struct.MeterUsageRequest.html#structfield.usage_dimension) member.
+    pub fn usage_dimension<T: Into<String>>(mut self, value: T) -> Self {
+        self.usage_dimension = Some(value.into());
+        self
+    }
+
+    /// Sets the [`usage_quantity`](struct.MeterUsageRequest.html#structfield.usage_quantity) member.
+    pub fn usage_quantity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.usage_quantity = Some(value.into());
+        self
+    }
+
+    /// Builds the `MeterUsageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<MeterUsageRequest, ::rusoto_core::BuildError> {
+        Ok(MeterUsageRequest {
+            dry_run: self.dry_run,
+            product_code: self
+                .product_code
+                .ok_or(::rusoto_core::BuildError::missing("product_code"))?,
+            timestamp: self
+                .timestamp
+                .ok_or(::rusoto_core::BuildError::missing("timestamp"))?,
+            usage_dimension: self
+                .usage_dimension
+                .ok_or(::rusoto_core::BuildError::missing("usage_dimension"))?,
+            usage_quantity: self.usage_quantity,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct MeterUsageResult {
@@ -92,13 +200,62 @@ pub struct RegisterUsageRequest {
     pub public_key_version: i64,
 }
 
+impl RegisterUsageRequest {
+    /// Returns a builder for `RegisterUsageRequest`, checking its required members when built.
+    pub fn builder() -> RegisterUsageRequestBuilder {
+        RegisterUsageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterUsageRequest`](struct.RegisterUsageRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterUsageRequestBuilder {
+    nonce: Option<String>,
+    product_code: Option<String>,
+    public_key_version: Option<i64>,
+}
+
+impl RegisterUsageRequestBuilder {
+    /// Sets the [`nonce`](struct.RegisterUsageRequest.html#structfield.nonce) member.
+    pub fn nonce<T: Into<String>>(mut self, value: T) -> Self {
+        self.nonce = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_code`](struct.RegisterUsageRequest.html#structfield.product_code) member.
+    pub fn product_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`public_key_version`](struct.RegisterUsageRequest.html#structfield.public_key_version) member.
+    pub fn public_key_version<T: Into<i64>>(mut self, value: T) -> Self {
+        self.public_key_version = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterUsageRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RegisterUsageRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterUsageRequest {
+            nonce: self.nonce,
+            product_code: self
+                .product_code
+                .ok_or(::rusoto_core::BuildError::missing("product_code"))?,
+            public_key_version: self
+                .public_key_version
+                .ok_or(::rusoto_core::BuildError::missing("public_key_version"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RegisterUsageResult {
     /// <p>(Optional) Only included when public key version has expired</p>
     #[serde(rename = "PublicKeyRotationTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub public_key_rotation_timestamp: Option<f64>,
+    pub public_key_rotation_timestamp: Option<EpochTimestamp>,
     /// <p>JWT Token</p>
     #[serde(rename = "Signature")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -113,6 +270,36 @@ pub struct ResolveCustomerRequest {
     pub registration_token: String,
 }
 
+impl ResolveCustomerRequest {
+    /// Returns a builder for `ResolveCustomerRequest`, checking its required members when built.
+    pub fn builder() -> ResolveCustomerRequestBuilder {
+        ResolveCustomerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ResolveCustomerRequest`](struct.ResolveCustomerRequest.html).
+#[derive(Default, Debug)]
+pub struct ResolveCustomerRequestBuilder {
+    registration_token: Option<String>,
+}
+
+impl ResolveCustomerRequestBuilder {
+    /// Sets the [`registration_token`](struct.ResolveCustomerRequest.html#structfield.registration_token) member.
+    pub fn registration_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.registration_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResolveCustomerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ResolveCustomerRequest, ::rusoto_core::BuildError> {
+        Ok(ResolveCustomerRequest {
+            registration_token: self
+                .registration_token
+                .ok_or(::rusoto_core::BuildError::missing("registration_token"))?,
+        })
+    }
+}
+
 /// <p>The result of the ResolveCustomer operation. Contains the CustomerIdentifier and product code.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -142,7 +329,8 @@ pub struct UsageRecord {
     pub quantity: Option<i64>,
     /// <p>Timestamp, in UTC, for which the usage is being reported.</p> <p>Your application can meter usage for up to one hour in the past. Make sure the timestamp value is not before the start of the software usage.</p>
     #[serde(rename = "Timestamp")]
-    pub timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub timestamp: EpochTimestamp,
 }
 
 /// <p>A UsageRecordResult indicates the status of a given UsageRecord processed by BatchMeterUsage.</p>
@@ -156,13 +344,89 @@ pub struct UsageRecordResult {
     /// <p><p>The UsageRecordResult Status indicates the status of an individual UsageRecord processed by BatchMeterUsage.</p> <ul> <li> <p> <i>Success</i>- The UsageRecord was accepted and honored by BatchMeterUsage.</p> </li> <li> <p> <i>CustomerNotSubscribed</i>- The CustomerIdentifier specified is not subscribed to your product. The UsageRecord was not honored. Future UsageRecords for this customer will fail until the customer subscribes to your product.</p> </li> <li> <p> <i>DuplicateRecord</i>- Indicates that the UsageRecord was invalid and not honored. A previously metered UsageRecord had the same customer, dimension, and time, but a different quantity.</p> </li> </ul></p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<UsageRecordResultStatus>,
     /// <p>The UsageRecord that was part of the BatchMeterUsage request.</p>
     #[serde(rename = "UsageRecord")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub usage_record: Option<UsageRecord>,
 }
 
+/// <p><p>The UsageRecordResult Status indicates the status of an individual UsageRecord processed by BatchMeterUsage.</p> <ul> <li> <p> <i>Success</i>- The UsageRecord was accepted and honored by BatchMeterUsage.</p> </li> <li> <p> <i>CustomerNotSubscribed</i>- The CustomerIdentifier specified is not subscribed to your product. The UsageRecord was not honored. Future UsageRecords for this customer will fail until the customer subscribes to your product.</p> </li> <li> <p> <i>DuplicateRecord</i>- Indicates that the UsageRecord was invalid and not honored. A previously metered UsageRecord had the same customer, dimension, and time, but a different quantity.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UsageRecordResultStatus {
+    /// <p><code>Success</code></p>
+    Success,
+    /// <p><code>CustomerNotSubscribed</code></p>
+    CustomerNotSubscribed,
+    /// <p><code>DuplicateRecord</code></p>
+    DuplicateRecord,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl UsageRecordResultStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            UsageRecordResultStatus::Success => "Success",
+            UsageRecordResultStatus::CustomerNotSubscribed => "CustomerNotSubscribed",
+            UsageRecordResultStatus::DuplicateRecord => "DuplicateRecord",
+            UsageRecordResultStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for UsageRecordResultStatus {
+    fn from(value: &'a str) -> UsageRecordResultStatus {
+        match value {
+            "Success" => UsageRecordResultStatus::Success,
+            "CustomerNotSubscribed" => UsageRecordResultStatus::CustomerNotSubscribed,
+            "DuplicateRecord" => UsageRecordResultStatus::DuplicateRecord,
+            _ => UsageRecordResultStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for UsageRecordResultStatus {
+    fn from(value: String) -> UsageRecordResultStatus {
+        UsageRecordResultStatus::from(value.as_str())
+    }
+}
+
+impl From<UsageRecordResultStatus> for String {
+    fn from(value: UsageRecordResultStatus) -> String {
+        match value {
+            UsageRecordResultStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for UsageRecordResultStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for UsageRecordResultStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for UsageRecordResultStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<UsageRecordResultStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(UsageRecordResultStatus::from(value))
+    }
+}
+
 /// Errors returned by BatchMeterUsage
 #[derive(Debug, PartialEq)]
 pub enum BatchMeterUsageError {
@@ -471,6 +735,39 @@ pub trait MarketplaceMetering {
         input: ResolveCustomerRequest,
     ) -> RusotoFuture<ResolveCustomerResult, ResolveCustomerError>;
 }
+impl<T: ?Sized + MarketplaceMetering> MarketplaceMetering for ::std::sync::Arc<T> {
+    fn batch_meter_usage(
+        &self,
+        input: BatchMeterUsageRequest,
+    ) -> RusotoFuture<BatchMeterUsageResult, BatchMeterUsageError> {
+        (**self).batch_meter_usage(input)
+    }
+    fn meter_usage(
+        &self,
+        input: MeterUsageRequest,
+    ) -> RusotoFuture<MeterUsageResult, MeterUsageError> {
+        (**self).meter_usage(input)
+    }
+    fn register_usage(
+        &self,
+        input: RegisterUsageRequest,
+    ) -> RusotoFuture<RegisterUsageResult, RegisterUsageError> {
+        (**self).register_usage(input)
+    }
+    fn resolve_customer(
+        &self,
+        input: ResolveCustomerRequest,
+    ) -> RusotoFuture<ResolveCustomerResult, ResolveCustomerError> {
+        (**self).resolve_customer(input)
+    }
+}
+/// The error codes AWSMarketplace Metering retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWSMarketplace Metering API.
 #[derive(Clone)]
 pub struct MarketplaceMeteringClient {
@@ -486,6 +783,27 @@ impl MarketplaceMeteringClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_METERINGMARKETPLACE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> MarketplaceMeteringClient {
+        Self::new(region::Region::from_env_for_service("METERINGMARKETPLACE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `metering.marketplace`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> MarketplaceMeteringClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("metering.marketplace", "METERINGMARKETPLACE"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -504,7 +822,54 @@ impl MarketplaceMeteringClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MarketplaceMeteringClient {
-        MarketplaceMeteringClient { client, region }
+        MarketplaceMeteringClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "metering.marketplace")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "metering.marketplace",
+            "aws-marketplace",
+            "METERINGMARKETPLACE",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `MarketplaceMetering` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn MarketplaceMetering + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for MarketplaceMeteringClient {
+    fn service_name(&self) -> &'static str {
+        "AWSMarketplace Metering"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "metering.marketplace"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> MarketplaceMeteringClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Migration Hub clients.
+//!
+//! Makes a single read-only `ListMigrationTasks` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_mgh::{ListMigrationTasksRequest, MigrationHub, MigrationHubClient};
+
+fn main() {
+    let client = MigrationHubClient::from_env();
+    match client
+        .list_migration_tasks(ListMigrationTasksRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListMigrationTasks succeeded"),
+        Err(error) => {
+            eprintln!("ListMigrationTasks failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
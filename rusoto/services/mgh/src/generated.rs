@@ -22,7 +22,84 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>Status of the application - Not Started, In-Progress, Complete.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApplicationStatus {
+    /// <p><code>NOT_STARTED</code></p>
+    NotStarted,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ApplicationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplicationStatus::NotStarted => "NOT_STARTED",
+            ApplicationStatus::InProgress => "IN_PROGRESS",
+            ApplicationStatus::Completed => "COMPLETED",
+            ApplicationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ApplicationStatus {
+    fn from(value: &'a str) -> ApplicationStatus {
+        match value {
+            "NOT_STARTED" => ApplicationStatus::NotStarted,
+            "IN_PROGRESS" => ApplicationStatus::InProgress,
+            "COMPLETED" => ApplicationStatus::Completed,
+            _ => ApplicationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplicationStatus {
+    fn from(value: String) -> ApplicationStatus {
+        ApplicationStatus::from(value.as_str())
+    }
+}
+
+impl From<ApplicationStatus> for String {
+    fn from(value: ApplicationStatus) -> String {
+        match value {
+            ApplicationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ApplicationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ApplicationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ApplicationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ApplicationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ApplicationStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateCreatedArtifactRequest {
     /// <p>An ARN of the AWS resource related to the migration (e.g., AMI, EC2 instance, RDS instance, etc.) </p>
@@ -40,6 +117,66 @@ pub struct AssociateCreatedArtifactRequest {
     pub progress_update_stream: String,
 }
 
+impl AssociateCreatedArtifactRequest {
+    /// Returns a builder for `AssociateCreatedArtifactRequest`, checking its required members when built.
+    pub fn builder() -> AssociateCreatedArtifactRequestBuilder {
+        AssociateCreatedArtifactRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateCreatedArtifactRequest`](struct.AssociateCreatedArtifactRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateCreatedArtifactRequestBuilder {
+    created_artifact: Option<CreatedArtifact>,
+    dry_run: Option<bool>,
+    migration_task_name: Option<String>,
+    progress_update_stream: Option<String>,
+}
+
+impl AssociateCreatedArtifactRequestBuilder {
+    /// Sets the [`created_artifact`](struct.AssociateCreatedArtifactRequest.html#structfield.created_artifact) member.
+    pub fn created_artifact<T: Into<CreatedArtifact>>(mut self, value: T) -> Self {
+        self.created_artifact = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dry_run`](struct.AssociateCreatedArtifactRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`migration_task_name`](struct.AssociateCreatedArtifactRequest.html#structfield.migration_task_name) member.
+    pub fn migration_task_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.migration_task_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`progress_update_stream`](struct.AssociateCreatedArtifactRequest.html#structfield.progress_update_stream) member.
+    pub fn progress_update_stream<T: Into<String>>(mut self, value: T) -> Self {
+        self.progress_update_stream = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateCreatedArtifactRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateCreatedArtifactRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateCreatedArtifactRequest {
+            created_artifact: self
+                .created_artifact
+                .ok_or(::rusoto_core::BuildError::missing("created_artifact"))?,
+            dry_run: self.dry_run,
+            migration_task_name: self
+                .migration_task_name
+                .ok_or(::rusoto_core::BuildError::missing("migration_task_name"))?,
+            progress_update_stream: self
+                .progress_update_stream
+                .ok_or(::rusoto_core::BuildError::missing("progress_update_stream"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateCreatedArtifactResult {}
@@ -61,6 +198,66 @@ pub struct AssociateDiscoveredResourceRequest {
     pub progress_update_stream: String,
 }
 
+impl AssociateDiscoveredResourceRequest {
+    /// Returns a builder for `AssociateDiscoveredResourceRequest`, checking its required members when built.
+    pub fn builder() -> AssociateDiscoveredResourceRequestBuilder {
+        AssociateDiscoveredResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateDiscoveredResourceRequest`](struct.AssociateDiscoveredResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateDiscoveredResourceRequestBuilder {
+    discovered_resource: Option<DiscoveredResource>,
+    dry_run: Option<bool>,
+    migration_task_name: Option<String>,
+    progress_update_stream: Option<String>,
+}
+
+impl AssociateDiscoveredResourceRequestBuilder {
+    /// Sets the [`discovered_resource`](struct.AssociateDiscoveredResourceRequest.html#structfield.discovered_resource) member.
+    pub fn discovered_resource<T: Into<DiscoveredResource>>(mut self, value: T) -> Self {
+        self.discovered_resource = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dry_run`](struct.AssociateDiscoveredResourceRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`migration_task_name`](struct.AssociateDiscoveredResourceRequest.html#structfield.migration_task_name) member.
+    pub fn migration_task_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.migration_task_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`progress_update_stream`](struct.AssociateDiscoveredResourceRequest.html#structfield.progress_update_stream) member.
+    pub fn progress_update_stream<T: Into<String>>(mut self, value: T) -> Self {
+        self.progress_update_stream = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateDiscoveredResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateDiscoveredResourceRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateDiscoveredResourceRequest {
+            discovered_resource: self
+                .discovered_resource
+                .ok_or(::rusoto_core::BuildError::missing("discovered_resource"))?,
+            dry_run: self.dry_run,
+            migration_task_name: self
+                .migration_task_name
+                .ok_or(::rusoto_core::BuildError::missing("migration_task_name"))?,
+            progress_update_stream: self
+                .progress_update_stream
+                .ok_or(::rusoto_core::BuildError::missing("progress_update_stream"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateDiscoveredResourceResult {}
@@ -76,6 +273,46 @@ pub struct CreateProgressUpdateStreamRequest {
     pub progress_update_stream_name: String,
 }
 
+impl CreateProgressUpdateStreamRequest {
+    /// Returns a builder for `CreateProgressUpdateStreamRequest`, checking its required members when built.
+    pub fn builder() -> CreateProgressUpdateStreamRequestBuilder {
+        CreateProgressUpdateStreamRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateProgressUpdateStreamRequest`](struct.CreateProgressUpdateStreamRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateProgressUpdateStreamRequestBuilder {
+    dry_run: Option<bool>,
+    progress_update_stream_name: Option<String>,
+}
+
+impl CreateProgressUpdateStreamRequestBuilder {
+    /// Sets the [`dry_run`](struct.CreateProgressUpdateStreamRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`progress_update_stream_name`](struct.CreateProgressUpdateStreamRequest.html#structfield.progress_update_stream_name) member.
+    pub fn progress_update_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.progress_update_stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateProgressUpdateStreamRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateProgressUpdateStreamRequest, ::rusoto_core::BuildError> {
+        Ok(CreateProgressUpdateStreamRequest {
+            dry_run: self.dry_run,
+            progress_update_stream_name: self.progress_update_stream_name.ok_or(
+                ::rusoto_core::BuildError::missing("progress_update_stream_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateProgressUpdateStreamResult {}
@@ -103,6 +340,46 @@ pub struct DeleteProgressUpdateStreamRequest {
     pub progress_update_stream_name: String,
 }
 
+impl DeleteProgressUpdateStreamRequest {
+    /// Returns a builder for `DeleteProgressUpdateStreamRequest`, checking its required members when built.
+    pub fn builder() -> DeleteProgressUpdateStreamRequestBuilder {
+        DeleteProgressUpdateStreamRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteProgressUpdateStreamRequest`](struct.DeleteProgressUpdateStreamRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteProgressUpdateStreamRequestBuilder {
+    dry_run: Option<bool>,
+    progress_update_stream_name: Option<String>,
+}
+
+impl DeleteProgressUpdateStreamRequestBuilder {
+    /// Sets the [`dry_run`](struct.DeleteProgressUpdateStreamRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`progress_update_stream_name`](struct.DeleteProgressUpdateStreamRequest.html#structfield.progress_update_stream_name) member.
+    pub fn progress_update_stream_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.progress_update_stream_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteProgressUpdateStreamRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteProgressUpdateStreamRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteProgressUpdateStreamRequest {
+            dry_run: self.dry_run,
+            progress_update_stream_name: self.progress_update_stream_name.ok_or(
+                ::rusoto_core::BuildError::missing("progress_update_stream_name"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteProgressUpdateStreamResult {}
@@ -114,17 +391,50 @@ pub struct DescribeApplicationStateRequest {
     pub application_id: String,
 }
 
+impl DescribeApplicationStateRequest {
+    /// Returns a builder for `DescribeApplicationStateRequest`, checking its required members when built.
+    pub fn builder() -> DescribeApplicationStateRequestBuilder {
+        DescribeApplicationStateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeApplicationStateRequest`](struct.DescribeApplicationStateRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeApplicationStateRequestBuilder {
+    application_id: Option<String>,
+}
+
+impl DescribeApplicationStateRequestBuilder {
+    /// Sets the [`application_id`](struct.DescribeApplicationStateRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeApplicationStateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeApplicationStateRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeApplicationStateRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeApplicationStateResult {
     /// <p>Status of the application - Not Started, In-Progress, Complete.</p>
     #[serde(rename = "ApplicationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub application_status: Option<String>,
+    pub application_status: Option<ApplicationStatus>,
     /// <p>The timestamp when the application status was last updated.</p>
     #[serde(rename = "LastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<EpochTimestamp>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -137,6 +447,48 @@ pub struct DescribeMigrationTaskRequest {
     pub progress_update_stream: String,
 }
 
+impl DescribeMigrationTaskRequest {
+    /// Returns a builder for `DescribeMigrationTaskRequest`, checking its required members when built.
+    pub fn builder() -> DescribeMigrationTaskRequestBuilder {
+        DescribeMigrationTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeMigrationTaskRequest`](struct.DescribeMigrationTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeMigrationTaskRequestBuilder {
+    migration_task_name: Option<String>,
+    progress_update_stream: Option<String>,
+}
+
+impl DescribeMigrationTaskRequestBuilder {
+    /// Sets the [`migration_task_name`](struct.DescribeMigrationTaskRequest.html#structfield.migration_task_name) member.
+    pub fn migration_task_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.migration_task_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`progress_update_stream`](struct.DescribeMigrationTaskRequest.html#structfield.progress_update_stream) member.
+    pub fn progress_update_stream<T: Into<String>>(mut self, value: T) -> Self {
+        self.progress_update_stream = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeMigrationTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeMigrationTaskRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeMigrationTaskRequest {
+            migration_task_name: self
+                .migration_task_name
+                .ok_or(::rusoto_core::BuildError::missing("migration_task_name"))?,
+            progress_update_stream: self
+                .progress_update_stream
+                .ok_or(::rusoto_core::BuildError::missing("progress_update_stream"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeMigrationTaskResult {
@@ -163,6 +515,66 @@ pub struct DisassociateCreatedArtifactRequest {
     pub progress_update_stream: String,
 }
 
+impl DisassociateCreatedArtifactRequest {
+    /// Returns a builder for `DisassociateCreatedArtifactRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateCreatedArtifactRequestBuilder {
+        DisassociateCreatedArtifactRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateCreatedArtifactRequest`](struct.DisassociateCreatedArtifactRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateCreatedArtifactRequestBuilder {
+    created_artifact_name: Option<String>,
+    dry_run: Option<bool>,
+    migration_task_name: Option<String>,
+    progress_update_stream: Option<String>,
+}
+
+impl DisassociateCreatedArtifactRequestBuilder {
+    /// Sets the [`created_artifact_name`](struct.DisassociateCreatedArtifactRequest.html#structfield.created_artifact_name) member.
+    pub fn created_artifact_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.created_artifact_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dry_run`](struct.DisassociateCreatedArtifactRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`migration_task_name`](struct.DisassociateCreatedArtifactRequest.html#structfield.migration_task_name) member.
+    pub fn migration_task_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.migration_task_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`progress_update_stream`](struct.DisassociateCreatedArtifactRequest.html#structfield.progress_update_stream) member.
+    pub fn progress_update_stream<T: Into<String>>(mut self, value: T) -> Self {
+        self.progress_update_stream = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateCreatedArtifactRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateCreatedArtifactRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateCreatedArtifactRequest {
+            created_artifact_name: self
+                .created_artifact_name
+                .ok_or(::rusoto_core::BuildError::missing("created_artifact_name"))?,
+            dry_run: self.dry_run,
+            migration_task_name: self
+                .migration_task_name
+                .ok_or(::rusoto_core::BuildError::missing("migration_task_name"))?,
+            progress_update_stream: self
+                .progress_update_stream
+                .ok_or(::rusoto_core::BuildError::missing("progress_update_stream"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateCreatedArtifactResult {}
@@ -184,6 +596,67 @@ pub struct DisassociateDiscoveredResourceRequest {
     pub progress_update_stream: String,
 }
 
+impl DisassociateDiscoveredResourceRequest {
+    /// Returns a builder for `DisassociateDiscoveredResourceRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateDiscoveredResourceRequestBuilder {
+        DisassociateDiscoveredResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateDiscoveredResourceRequest`](struct.DisassociateDiscoveredResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateDiscoveredResourceRequestBuilder {
+    configuration_id: Option<String>,
+    dry_run: Option<bool>,
+    migration_task_name: Option<String>,
+    progress_update_stream: Option<String>,
+}
+
+impl DisassociateDiscoveredResourceRequestBuilder {
+    /// Sets the [`configuration_id`](struct.DisassociateDiscoveredResourceRequest.html#structfield.configuration_id) member.
+    pub fn configuration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dry_run`](struct.DisassociateDiscoveredResourceRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`migration_task_name`](struct.DisassociateDiscoveredResourceRequest.html#structfield.migration_task_name) member.
+    pub fn migration_task_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.migration_task_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`progress_update_stream`](struct.DisassociateDiscoveredResourceRequest.html#structfield.progress_update_stream) member.
+    pub fn progress_update_stream<T: Into<String>>(mut self, value: T) -> Self {
+        self.progress_update_stream = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateDiscoveredResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateDiscoveredResourceRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociateDiscoveredResourceRequest {
+            configuration_id: self
+                .configuration_id
+                .ok_or(::rusoto_core::BuildError::missing("configuration_id"))?,
+            dry_run: self.dry_run,
+            migration_task_name: self
+                .migration_task_name
+                .ok_or(::rusoto_core::BuildError::missing("migration_task_name"))?,
+            progress_update_stream: self
+                .progress_update_stream
+                .ok_or(::rusoto_core::BuildError::missing("progress_update_stream"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateDiscoveredResourceResult {}
@@ -214,6 +687,56 @@ pub struct ImportMigrationTaskRequest {
     pub progress_update_stream: String,
 }
 
+impl ImportMigrationTaskRequest {
+    /// Returns a builder for `ImportMigrationTaskRequest`, checking its required members when built.
+    pub fn builder() -> ImportMigrationTaskRequestBuilder {
+        ImportMigrationTaskRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ImportMigrationTaskRequest`](struct.ImportMigrationTaskRequest.html).
+#[derive(Default, Debug)]
+pub struct ImportMigrationTaskRequestBuilder {
+    dry_run: Option<bool>,
+    migration_task_name: Option<String>,
+    progress_update_stream: Option<String>,
+}
+
+impl ImportMigrationTaskRequestBuilder {
+    /// Sets the [`dry_run`](struct.ImportMigrationTaskRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`migration_task_name`](struct.ImportMigrationTaskRequest.html#structfield.migration_task_name) member.
+    pub fn migration_task_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.migration_task_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`progress_update_stream`](struct.ImportMigrationTaskRequest.html#structfield.progress_update_stream) member.
+    pub fn progress_update_stream<T: Into<String>>(mut self, value: T) -> Self {
+        self.progress_update_stream = Some(value.into());
+        self
+    }
+
+    /// Builds the `ImportMigrationTaskRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ImportMigrationTaskRequest, ::rusoto_core::BuildError> {
+        Ok(ImportMigrationTaskRequest {
+            dry_run: self.dry_run,
+            migration_task_name: self
+                .migration_task_name
+                .ok_or(::rusoto_core::BuildError::missing("migration_task_name"))?,
+            progress_update_stream: self
+                .progress_update_stream
+                .ok_or(::rusoto_core::BuildError::missing("progress_update_stream"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ImportMigrationTaskResult {}
@@ -236,6 +759,64 @@ pub struct ListCreatedArtifactsRequest {
     pub progress_update_stream: String,
 }
 
+impl ListCreatedArtifactsRequest {
+    /// Returns a builder for `ListCreatedArtifactsRequest`, checking its required members when built.
+    pub fn builder() -> ListCreatedArtifactsRequestBuilder {
+        ListCreatedArtifactsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCreatedArtifactsRequest`](struct.ListCreatedArtifactsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCreatedArtifactsRequestBuilder {
+    max_results: Option<i64>,
+    migration_task_name: Option<String>,
+    next_token: Option<String>,
+    progress_update_stream: Option<String>,
+}
+
+impl ListCreatedArtifactsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListCreatedArtifactsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`migration_task_name`](struct.ListCreatedArtifactsRequest.html#structfield.migration_task_name) member.
+    pub fn migration_task_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.migration_task_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListCreatedArtifactsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`progress_update_stream`](struct.ListCreatedArtifactsRequest.html#structfield.progress_update_stream) member.
+    pub fn progress_update_stream<T: Into<String>>(mut self, value: T) -> Self {
+        self.progress_update_stream = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCreatedArtifactsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListCreatedArtifactsRequest, ::rusoto_core::BuildError> {
+        Ok(ListCreatedArtifactsRequest {
+            max_results: self.max_results,
+            migration_task_name: self
+                .migration_task_name
+                .ok_or(::rusoto_core::BuildError::missing("migration_task_name"))?,
+            next_token: self.next_token,
+            progress_update_stream: self
+                .progress_update_stream
+                .ok_or(::rusoto_core::BuildError::missing("progress_update_stream"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListCreatedArtifactsResult {
@@ -267,6 +848,64 @@ pub struct ListDiscoveredResourcesRequest {
     pub progress_update_stream: String,
 }
 
+impl ListDiscoveredResourcesRequest {
+    /// Returns a builder for `ListDiscoveredResourcesRequest`, checking its required members when built.
+    pub fn builder() -> ListDiscoveredResourcesRequestBuilder {
+        ListDiscoveredResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListDiscoveredResourcesRequest`](struct.ListDiscoveredResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListDiscoveredResourcesRequestBuilder {
+    max_results: Option<i64>,
+    migration_task_name: Option<String>,
+    next_token: Option<String>,
+    progress_update_stream: Option<String>,
+}
+
+impl ListDiscoveredResourcesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListDiscoveredResourcesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`migration_task_name`](struct.ListDiscoveredResourcesRequest.html#structfield.migration_task_name) member.
+    pub fn migration_task_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.migration_task_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListDiscoveredResourcesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`progress_update_stream`](struct.ListDiscoveredResourcesRequest.html#structfield.progress_update_stream) member.
+    pub fn progress_update_stream<T: Into<String>>(mut self, value: T) -> Self {
+        self.progress_update_stream = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListDiscoveredResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListDiscoveredResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(ListDiscoveredResourcesRequest {
+            max_results: self.max_results,
+            migration_task_name: self
+                .migration_task_name
+                .ok_or(::rusoto_core::BuildError::missing("migration_task_name"))?,
+            next_token: self.next_token,
+            progress_update_stream: self
+                .progress_update_stream
+                .ok_or(::rusoto_core::BuildError::missing("progress_update_stream"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListDiscoveredResourcesResult {
@@ -296,6 +935,52 @@ pub struct ListMigrationTasksRequest {
     pub resource_name: Option<String>,
 }
 
+impl ListMigrationTasksRequest {
+    /// Returns a builder for `ListMigrationTasksRequest`, checking its required members when built.
+    pub fn builder() -> ListMigrationTasksRequestBuilder {
+        ListMigrationTasksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListMigrationTasksRequest`](struct.ListMigrationTasksRequest.html).
+#[derive(Default, Debug)]
+pub struct ListMigrationTasksRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_name: Option<String>,
+}
+
+impl ListMigrationTasksRequestBuilder {
+    /// Sets the [`max_results`](struct.ListMigrationTasksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListMigrationTasksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_name`](struct.ListMigrationTasksRequest.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListMigrationTasksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListMigrationTasksRequest, ::rusoto_core::BuildError> {
+        Ok(ListMigrationTasksRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_name: self.resource_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListMigrationTasksResult {
@@ -321,6 +1006,44 @@ pub struct ListProgressUpdateStreamsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListProgressUpdateStreamsRequest {
+    /// Returns a builder for `ListProgressUpdateStreamsRequest`, checking its required members when built.
+    pub fn builder() -> ListProgressUpdateStreamsRequestBuilder {
+        ListProgressUpdateStreamsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListProgressUpdateStreamsRequest`](struct.ListProgressUpdateStreamsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListProgressUpdateStreamsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListProgressUpdateStreamsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListProgressUpdateStreamsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListProgressUpdateStreamsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListProgressUpdateStreamsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListProgressUpdateStreamsRequest, ::rusoto_core::BuildError> {
+        Ok(ListProgressUpdateStreamsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListProgressUpdateStreamsResult {
@@ -356,8 +1079,9 @@ pub struct MigrationTask {
     pub task: Option<Task>,
     /// <p>The timestamp when the task was gathered.</p>
     #[serde(rename = "UpdateDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_date_time: Option<f64>,
+    pub update_date_time: Option<EpochTimestamp>,
 }
 
 /// <p>MigrationTaskSummary includes <code>MigrationTaskName</code>, <code>ProgressPercent</code>, <code>ProgressUpdateStream</code>, <code>Status</code>, and <code>UpdateDateTime</code> for each task.</p>
@@ -379,18 +1103,19 @@ pub struct MigrationTaskSummary {
     /// <p>Status of the task.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<Status>,
     /// <p>Detail information of what is being done within the overall status state.</p>
     #[serde(rename = "StatusDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_detail: Option<String>,
     /// <p>The timestamp when the task was gathered.</p>
     #[serde(rename = "UpdateDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub update_date_time: Option<f64>,
+    pub update_date_time: Option<EpochTimestamp>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct NotifyApplicationStateRequest {
     /// <p>The configurationId in ADS that uniquely identifies the grouped application.</p>
     #[serde(rename = "ApplicationId")]
@@ -401,14 +1126,64 @@ pub struct NotifyApplicationStateRequest {
     pub dry_run: Option<bool>,
     /// <p>Status of the application - Not Started, In-Progress, Complete.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: ApplicationStatus,
+}
+
+impl NotifyApplicationStateRequest {
+    /// Returns a builder for `NotifyApplicationStateRequest`, checking its required members when built.
+    pub fn builder() -> NotifyApplicationStateRequestBuilder {
+        NotifyApplicationStateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`NotifyApplicationStateRequest`](struct.NotifyApplicationStateRequest.html).
+#[derive(Default, Debug)]
+pub struct NotifyApplicationStateRequestBuilder {
+    application_id: Option<String>,
+    dry_run: Option<bool>,
+    status: Option<ApplicationStatus>,
+}
+
+impl NotifyApplicationStateRequestBuilder {
+    /// Sets the [`application_id`](struct.NotifyApplicationStateRequest.html#structfield.application_id) member.
+    pub fn application_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.application_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dry_run`](struct.NotifyApplicationStateRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.NotifyApplicationStateRequest.html#structfield.status) member.
+    pub fn status<T: Into<ApplicationStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `NotifyApplicationStateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<NotifyApplicationStateRequest, ::rusoto_core::BuildError> {
+        Ok(NotifyApplicationStateRequest {
+            application_id: self
+                .application_id
+                .ok_or(::rusoto_core::BuildError::missing("application_id"))?,
+            dry_run: self.dry_run,
+            status: self
+                .status
+                .ok_or(::rusoto_core::BuildError::missing("status"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct NotifyApplicationStateResult {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct NotifyMigrationTaskStateRequest {
     /// <p>Optional boolean flag to indicate whether any effect should take place. Used to test if the caller has permission to make the call.</p>
     #[serde(rename = "DryRun")]
@@ -428,7 +1203,88 @@ pub struct NotifyMigrationTaskStateRequest {
     pub task: Task,
     /// <p>The timestamp when the task was gathered.</p>
     #[serde(rename = "UpdateDateTime")]
-    pub update_date_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub update_date_time: EpochTimestamp,
+}
+
+impl NotifyMigrationTaskStateRequest {
+    /// Returns a builder for `NotifyMigrationTaskStateRequest`, checking its required members when built.
+    pub fn builder() -> NotifyMigrationTaskStateRequestBuilder {
+        NotifyMigrationTaskStateRequestBuilder::default()
+    }
+}
+
+/// Builds a [`NotifyMigrationTaskStateRequest`](struct.NotifyMigrationTaskStateRequest.html).
+#[derive(Default, Debug)]
+pub struct NotifyMigrationTaskStateRequestBuilder {
+    dry_run: Option<bool>,
+    migration_task_name: Option<String>,
+    next_update_seconds: Option<i64>,
+    progress_update_stream: Option<String>,
+    task: Option<Task>,
+    update_date_time: Option<EpochTimestamp>,
+}
+
+impl NotifyMigrationTaskStateRequestBuilder {
+    /// Sets the [`dry_run`](struct.NotifyMigrationTaskStateRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`migration_task_name`](struct.NotifyMigrationTaskStateRequest.html#structfield.migration_task_name) member.
+    pub fn migration_task_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.migration_task_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_update_seconds`](struct.NotifyMigrationTaskStateRequest.html#structfield.next_update_seconds) member.
+    pub fn next_update_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.next_update_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`progress_update_stream`](struct.NotifyMigrationTaskStateRequest.html#structfield.progress_update_stream) member.
+    pub fn progress_update_stream<T: Into<String>>(mut self, value: T) -> Self {
+        self.progress_update_stream = Some(value.into());
+        self
+    }
+
+    /// Sets the [`task`](struct.NotifyMigrationTaskStateRequest.html#structfield.task) member.
+    pub fn task<T: Into<Task>>(mut self, value: T) -> Self {
+        self.task = Some(value.into());
+        self
+    }
+
+    /// Sets the [`update_date_time`](struct.NotifyMigrationTaskStateRequest.html#structfield.update_date_time) member.
+    pub fn update_date_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.update_date_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `NotifyMigrationTaskStateRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<NotifyMigrationTaskStateRequest, ::rusoto_core::BuildError> {
+        Ok(NotifyMigrationTaskStateRequest {
+            dry_run: self.dry_run,
+            migration_task_name: self
+                .migration_task_name
+                .ok_or(::rusoto_core::BuildError::missing("migration_task_name"))?,
+            next_update_seconds: self
+                .next_update_seconds
+                .ok_or(::rusoto_core::BuildError::missing("next_update_seconds"))?,
+            progress_update_stream: self
+                .progress_update_stream
+                .ok_or(::rusoto_core::BuildError::missing("progress_update_stream"))?,
+            task: self
+                .task
+                .ok_or(::rusoto_core::BuildError::missing("task"))?,
+            update_date_time: self
+                .update_date_time
+                .ok_or(::rusoto_core::BuildError::missing("update_date_time"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -462,23 +1318,267 @@ pub struct PutResourceAttributesRequest {
     pub resource_attribute_list: Vec<ResourceAttribute>,
 }
 
+impl PutResourceAttributesRequest {
+    /// Returns a builder for `PutResourceAttributesRequest`, checking its required members when built.
+    pub fn builder() -> PutResourceAttributesRequestBuilder {
+        PutResourceAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`PutResourceAttributesRequest`](struct.PutResourceAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct PutResourceAttributesRequestBuilder {
+    dry_run: Option<bool>,
+    migration_task_name: Option<String>,
+    progress_update_stream: Option<String>,
+    resource_attribute_list: Option<Vec<ResourceAttribute>>,
+}
+
+impl PutResourceAttributesRequestBuilder {
+    /// Sets the [`dry_run`](struct.PutResourceAttributesRequest.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`migration_task_name`](struct.PutResourceAttributesRequest.html#structfield.migration_task_name) member.
+    pub fn migration_task_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.migration_task_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`progress_update_stream`](struct.PutResourceAttributesRequest.html#structfield.progress_update_stream) member.
+    pub fn progress_update_stream<T: Into<String>>(mut self, value: T) -> Self {
+        self.progress_update_stream = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_attribute_list`](struct.PutResourceAttributesRequest.html#structfield.resource_attribute_list) member.
+    pub fn resource_attribute_list<T: Into<Vec<ResourceAttribute>>>(mut self, value: T) -> Self {
+        self.resource_attribute_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutResourceAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PutResourceAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(PutResourceAttributesRequest {
+            dry_run: self.dry_run,
+            migration_task_name: self
+                .migration_task_name
+                .ok_or(::rusoto_core::BuildError::missing("migration_task_name"))?,
+            progress_update_stream: self
+                .progress_update_stream
+                .ok_or(::rusoto_core::BuildError::missing("progress_update_stream"))?,
+            resource_attribute_list: self.resource_attribute_list.ok_or(
+                ::rusoto_core::BuildError::missing("resource_attribute_list"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutResourceAttributesResult {}
 
 /// <p><p>Attribute associated with a resource.</p> <p>Note the corresponding format required per type listed below:</p> <dl> <dt>IPV4</dt> <dd> <p> <code>x.x.x.x</code> </p> <p> <i>where x is an integer in the range [0,255]</i> </p> </dd> <dt>IPV6</dt> <dd> <p> <code>y : y : y : y : y : y : y : y</code> </p> <p> <i>where y is a hexadecimal between 0 and FFFF. [0, FFFF]</i> </p> </dd> <dt>MAC_ADDRESS</dt> <dd> <p> <code>^([0-9A-Fa-f]{2}[:-]){5}([0-9A-Fa-f]{2})$</code> </p> </dd> <dt>FQDN</dt> <dd> <p> <code>^[^&lt;&gt;{}\\/?,=\p{Cntrl}]{1,256}$</code> </p> </dd> </dl></p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceAttribute {
     /// <p>Type of resource.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: ResourceAttributeType,
     /// <p>Value of the resource type.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+/// <p>Type of resource.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceAttributeType {
+    /// <p><code>IPV4_ADDRESS</code></p>
+    Ipv4Address,
+    /// <p><code>IPV6_ADDRESS</code></p>
+    Ipv6Address,
+    /// <p><code>MAC_ADDRESS</code></p>
+    MacAddress,
+    /// <p><code>FQDN</code></p>
+    Fqdn,
+    /// <p><code>VM_MANAGER_ID</code></p>
+    VmManagerId,
+    /// <p><code>VM_MANAGED_OBJECT_REFERENCE</code></p>
+    VmManagedObjectReference,
+    /// <p><code>VM_NAME</code></p>
+    VmName,
+    /// <p><code>VM_PATH</code></p>
+    VmPath,
+    /// <p><code>BIOS_ID</code></p>
+    BiosId,
+    /// <p><code>MOTHERBOARD_SERIAL_NUMBER</code></p>
+    MotherboardSerialNumber,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceAttributeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceAttributeType::Ipv4Address => "IPV4_ADDRESS",
+            ResourceAttributeType::Ipv6Address => "IPV6_ADDRESS",
+            ResourceAttributeType::MacAddress => "MAC_ADDRESS",
+            ResourceAttributeType::Fqdn => "FQDN",
+            ResourceAttributeType::VmManagerId => "VM_MANAGER_ID",
+            ResourceAttributeType::VmManagedObjectReference => "VM_MANAGED_OBJECT_REFERENCE",
+            ResourceAttributeType::VmName => "VM_NAME",
+            ResourceAttributeType::VmPath => "VM_PATH",
+            ResourceAttributeType::BiosId => "BIOS_ID",
+            ResourceAttributeType::MotherboardSerialNumber => "MOTHERBOARD_SERIAL_NUMBER",
+            ResourceAttributeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceAttributeType {
+    fn from(value: &'a str) -> ResourceAttributeType {
+        match value {
+            "IPV4_ADDRESS" => ResourceAttributeType::Ipv4Address,
+            "IPV6_ADDRESS" => ResourceAttributeType::Ipv6Address,
+            "MAC_ADDRESS" => ResourceAttributeType::MacAddress,
+            "FQDN" => ResourceAttributeType::Fqdn,
+            "VM_MANAGER_ID" => ResourceAttributeType::VmManagerId,
+            "VM_MANAGED_OBJECT_REFERENCE" => ResourceAttributeType::VmManagedObjectReference,
+            "VM_NAME" => ResourceAttributeType::VmName,
+            "VM_PATH" => ResourceAttributeType::VmPath,
+            "BIOS_ID" => ResourceAttributeType::BiosId,
+            "MOTHERBOARD_SERIAL_NUMBER" => ResourceAttributeType::MotherboardSerialNumber,
+            _ => ResourceAttributeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceAttributeType {
+    fn from(value: String) -> ResourceAttributeType {
+        ResourceAttributeType::from(value.as_str())
+    }
+}
+
+impl From<ResourceAttributeType> for String {
+    fn from(value: ResourceAttributeType) -> String {
+        match value {
+            ResourceAttributeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceAttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ResourceAttributeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceAttributeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceAttributeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceAttributeType::from(value))
+    }
+}
+
+/// <p>Status of the task.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// <p><code>NOT_STARTED</code></p>
+    NotStarted,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Status {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Status::NotStarted => "NOT_STARTED",
+            Status::InProgress => "IN_PROGRESS",
+            Status::Failed => "FAILED",
+            Status::Completed => "COMPLETED",
+            Status::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Status {
+    fn from(value: &'a str) -> Status {
+        match value {
+            "NOT_STARTED" => Status::NotStarted,
+            "IN_PROGRESS" => Status::InProgress,
+            "FAILED" => Status::Failed,
+            "COMPLETED" => Status::Completed,
+            _ => Status::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Status {
+    fn from(value: String) -> Status {
+        Status::from(value.as_str())
+    }
+}
+
+impl From<Status> for String {
+    fn from(value: Status) -> String {
+        match value {
+            Status::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Status, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Status::from(value))
+    }
+}
+
 /// <p>Task object encapsulating task information.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Task {
     /// <p>Indication of the percentage completion of the task.</p>
     #[serde(rename = "ProgressPercent")]
@@ -486,7 +1586,7 @@ pub struct Task {
     pub progress_percent: Option<i64>,
     /// <p>Status of the task - Not Started, In-Progress, Complete.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: Status,
     /// <p>Details of task status as notified by a migration tool. A tool might use this field to provide clarifying information about the status that is unique to that tool or that explains an error state.</p>
     #[serde(rename = "StatusDetail")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1817,6 +2917,112 @@ pub trait MigrationHub {
         input: PutResourceAttributesRequest,
     ) -> RusotoFuture<PutResourceAttributesResult, PutResourceAttributesError>;
 }
+impl<T: ?Sized + MigrationHub> MigrationHub for ::std::sync::Arc<T> {
+    fn associate_created_artifact(
+        &self,
+        input: AssociateCreatedArtifactRequest,
+    ) -> RusotoFuture<AssociateCreatedArtifactResult, AssociateCreatedArtifactError> {
+        (**self).associate_created_artifact(input)
+    }
+    fn associate_discovered_resource(
+        &self,
+        input: AssociateDiscoveredResourceRequest,
+    ) -> RusotoFuture<AssociateDiscoveredResourceResult, AssociateDiscoveredResourceError> {
+        (**self).associate_discovered_resource(input)
+    }
+    fn create_progress_update_stream(
+        &self,
+        input: CreateProgressUpdateStreamRequest,
+    ) -> RusotoFuture<CreateProgressUpdateStreamResult, CreateProgressUpdateStreamError> {
+        (**self).create_progress_update_stream(input)
+    }
+    fn delete_progress_update_stream(
+        &self,
+        input: DeleteProgressUpdateStreamRequest,
+    ) -> RusotoFuture<DeleteProgressUpdateStreamResult, DeleteProgressUpdateStreamError> {
+        (**self).delete_progress_update_stream(input)
+    }
+    fn describe_application_state(
+        &self,
+        input: DescribeApplicationStateRequest,
+    ) -> RusotoFuture<DescribeApplicationStateResult, DescribeApplicationStateError> {
+        (**self).describe_application_state(input)
+    }
+    fn describe_migration_task(
+        &self,
+        input: DescribeMigrationTaskRequest,
+    ) -> RusotoFuture<DescribeMigrationTaskResult, DescribeMigrationTaskError> {
+        (**self).describe_migration_task(input)
+    }
+    fn disassociate_created_artifact(
+        &self,
+        input: DisassociateCreatedArtifactRequest,
+    ) -> RusotoFuture<DisassociateCreatedArtifactResult, DisassociateCreatedArtifactError> {
+        (**self).disassociate_created_artifact(input)
+    }
+    fn disassociate_discovered_resource(
+        &self,
+        input: DisassociateDiscoveredResourceRequest,
+    ) -> RusotoFuture<DisassociateDiscoveredResourceResult, DisassociateDiscoveredResourceError>
+    {
+        (**self).disassociate_discovered_resource(input)
+    }
+    fn import_migration_task(
+        &self,
+        input: ImportMigrationTaskRequest,
+    ) -> RusotoFuture<ImportMigrationTaskResult, ImportMigrationTaskError> {
+        (**self).import_migration_task(input)
+    }
+    fn list_created_artifacts(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> RusotoFuture<ListCreatedArtifactsResult, ListCreatedArtifactsError> {
+        (**self).list_created_artifacts(input)
+    }
+    fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> RusotoFuture<ListDiscoveredResourcesResult, ListDiscoveredResourcesError> {
+        (**self).list_discovered_resources(input)
+    }
+    fn list_migration_tasks(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> RusotoFuture<ListMigrationTasksResult, ListMigrationTasksError> {
+        (**self).list_migration_tasks(input)
+    }
+    fn list_progress_update_streams(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> RusotoFuture<ListProgressUpdateStreamsResult, ListProgressUpdateStreamsError> {
+        (**self).list_progress_update_streams(input)
+    }
+    fn notify_application_state(
+        &self,
+        input: NotifyApplicationStateRequest,
+    ) -> RusotoFuture<NotifyApplicationStateResult, NotifyApplicationStateError> {
+        (**self).notify_application_state(input)
+    }
+    fn notify_migration_task_state(
+        &self,
+        input: NotifyMigrationTaskStateRequest,
+    ) -> RusotoFuture<NotifyMigrationTaskStateResult, NotifyMigrationTaskStateError> {
+        (**self).notify_migration_task_state(input)
+    }
+    fn put_resource_attributes(
+        &self,
+        input: PutResourceAttributesRequest,
+    ) -> RusotoFuture<PutResourceAttributesResult, PutResourceAttributesError> {
+        (**self).put_resource_attributes(input)
+    }
+}
+/// The error codes AWS Migration Hub retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Migration Hub API.
 #[derive(Clone)]
 pub struct MigrationHubClient {
@@ -1832,6 +3038,24 @@ impl MigrationHubClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_MGH`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> MigrationHubClient {
+        Self::new(region::Region::from_env_for_service("MGH"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `mgh`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> MigrationHubClient {
+        Self::new_with_client(config.client().clone(), config.region_for("mgh", "MGH"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1850,7 +3074,49 @@ impl MigrationHubClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MigrationHubClient {
-        MigrationHubClient { client, region }
+        MigrationHubClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "mgh")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "mgh", "mgh", "MGH")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `MigrationHub` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn MigrationHub + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for MigrationHubClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Migration Hub"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "mgh"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> MigrationHubClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Mobile clients.
+//!
+//! Makes a single read-only `ListBundles` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_mobile::{ListBundlesRequest, Mobile, MobileClient};
+
+fn main() {
+    let client = MobileClient::from_env();
+    match client.list_bundles(ListBundlesRequest::default()).sync() {
+        Ok(_) => println!("ListBundles succeeded"),
+        Err(error) => {
+            eprintln!("ListBundles failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
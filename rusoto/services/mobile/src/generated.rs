@@ -23,13 +23,14 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 /// <p> The details of the bundle. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct BundleDetails {
     #[serde(rename = "availablePlatforms")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub available_platforms: Option<Vec<String>>,
+    pub available_platforms: Option<Vec<Platform>>,
     #[serde(rename = "bundleId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bundle_id: Option<String>,
@@ -73,6 +74,58 @@ pub struct CreateProjectRequest {
     pub snapshot_id: Option<String>,
 }
 
+impl CreateProjectRequest {
+    /// Returns a builder for `CreateProjectRequest`, checking its required members when built.
+    pub fn builder() -> CreateProjectRequestBuilder {
+        CreateProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateProjectRequest`](struct.CreateProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateProjectRequestBuilder {
+    contents: Option<bytes::Bytes>,
+    name: Option<String>,
+    region: Option<String>,
+    snapshot_id: Option<String>,
+}
+
+impl CreateProjectRequestBuilder {
+    /// Sets the [`contents`](struct.CreateProjectRequest.html#structfield.contents) member.
+    pub fn contents<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.contents = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateProjectRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`region`](struct.CreateProjectRequest.html#structfield.region) member.
+    pub fn region<T: Into<String>>(mut self, value: T) -> Self {
+        self.region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_id`](struct.CreateProjectRequest.html#structfield.snapshot_id) member.
+    pub fn snapshot_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateProjectRequest, ::rusoto_core::BuildError> {
+        Ok(CreateProjectRequest {
+            contents: self.contents,
+            name: self.name,
+            region: self.region,
+            snapshot_id: self.snapshot_id,
+        })
+    }
+}
+
 /// <p> Result structure used in response to a request to create a project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -91,6 +144,36 @@ pub struct DeleteProjectRequest {
     pub project_id: String,
 }
 
+impl DeleteProjectRequest {
+    /// Returns a builder for `DeleteProjectRequest`, checking its required members when built.
+    pub fn builder() -> DeleteProjectRequestBuilder {
+        DeleteProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteProjectRequest`](struct.DeleteProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteProjectRequestBuilder {
+    project_id: Option<String>,
+}
+
+impl DeleteProjectRequestBuilder {
+    /// Sets the [`project_id`](struct.DeleteProjectRequest.html#structfield.project_id) member.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteProjectRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteProjectRequest {
+            project_id: self
+                .project_id
+                .ok_or(::rusoto_core::BuildError::missing("project_id"))?,
+        })
+    }
+}
+
 /// <p> Result structure used in response to request to delete a project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -113,6 +196,36 @@ pub struct DescribeBundleRequest {
     pub bundle_id: String,
 }
 
+impl DescribeBundleRequest {
+    /// Returns a builder for `DescribeBundleRequest`, checking its required members when built.
+    pub fn builder() -> DescribeBundleRequestBuilder {
+        DescribeBundleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBundleRequest`](struct.DescribeBundleRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeBundleRequestBuilder {
+    bundle_id: Option<String>,
+}
+
+impl DescribeBundleRequestBuilder {
+    /// Sets the [`bundle_id`](struct.DescribeBundleRequest.html#structfield.bundle_id) member.
+    pub fn bundle_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bundle_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBundleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeBundleRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeBundleRequest {
+            bundle_id: self
+                .bundle_id
+                .ok_or(::rusoto_core::BuildError::missing("bundle_id"))?,
+        })
+    }
+}
+
 /// <p> Result structure contains the details of the bundle. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -135,6 +248,44 @@ pub struct DescribeProjectRequest {
     pub sync_from_resources: Option<bool>,
 }
 
+impl DescribeProjectRequest {
+    /// Returns a builder for `DescribeProjectRequest`, checking its required members when built.
+    pub fn builder() -> DescribeProjectRequestBuilder {
+        DescribeProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeProjectRequest`](struct.DescribeProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeProjectRequestBuilder {
+    project_id: Option<String>,
+    sync_from_resources: Option<bool>,
+}
+
+impl DescribeProjectRequestBuilder {
+    /// Sets the [`project_id`](struct.DescribeProjectRequest.html#structfield.project_id) member.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sync_from_resources`](struct.DescribeProjectRequest.html#structfield.sync_from_resources) member.
+    pub fn sync_from_resources<T: Into<bool>>(mut self, value: T) -> Self {
+        self.sync_from_resources = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeProjectRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeProjectRequest {
+            project_id: self
+                .project_id
+                .ok_or(::rusoto_core::BuildError::missing("project_id"))?,
+            sync_from_resources: self.sync_from_resources,
+        })
+    }
+}
+
 /// <p> Result structure used for requests of project details. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -153,13 +304,59 @@ pub struct ExportBundleRequest {
     /// <p> Developer desktop or target application platform. </p>
     #[serde(rename = "platform")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub platform: Option<String>,
+    pub platform: Option<Platform>,
     /// <p> Unique project identifier. </p>
     #[serde(rename = "projectId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project_id: Option<String>,
 }
 
+impl ExportBundleRequest {
+    /// Returns a builder for `ExportBundleRequest`, checking its required members when built.
+    pub fn builder() -> ExportBundleRequestBuilder {
+        ExportBundleRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ExportBundleRequest`](struct.ExportBundleRequest.html).
+#[derive(Default, Debug)]
+pub struct ExportBundleRequestBuilder {
+    bundle_id: Option<String>,
+    platform: Option<Platform>,
+    project_id: Option<String>,
+}
+
+impl ExportBundleRequestBuilder {
+    /// Sets the [`bundle_id`](struct.ExportBundleRequest.html#structfield.bundle_id) member.
+    pub fn bundle_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.bundle_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`platform`](struct.ExportBundleRequest.html#structfield.platform) member.
+    pub fn platform<T: Into<Platform>>(mut self, value: T) -> Self {
+        self.platform = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_id`](struct.ExportBundleRequest.html#structfield.project_id) member.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExportBundleRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ExportBundleRequest, ::rusoto_core::BuildError> {
+        Ok(ExportBundleRequest {
+            bundle_id: self
+                .bundle_id
+                .ok_or(::rusoto_core::BuildError::missing("bundle_id"))?,
+            platform: self.platform,
+            project_id: self.project_id,
+        })
+    }
+}
+
 /// <p> Result structure which contains link to download custom-generated SDK and tool packages used to integrate mobile web or app clients with backed AWS resources. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -178,6 +375,36 @@ pub struct ExportProjectRequest {
     pub project_id: String,
 }
 
+impl ExportProjectRequest {
+    /// Returns a builder for `ExportProjectRequest`, checking its required members when built.
+    pub fn builder() -> ExportProjectRequestBuilder {
+        ExportProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ExportProjectRequest`](struct.ExportProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct ExportProjectRequestBuilder {
+    project_id: Option<String>,
+}
+
+impl ExportProjectRequestBuilder {
+    /// Sets the [`project_id`](struct.ExportProjectRequest.html#structfield.project_id) member.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExportProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ExportProjectRequest, ::rusoto_core::BuildError> {
+        Ok(ExportProjectRequest {
+            project_id: self
+                .project_id
+                .ok_or(::rusoto_core::BuildError::missing("project_id"))?,
+        })
+    }
+}
+
 /// <p> Result structure used for requests to export project configuration details. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -209,6 +436,42 @@ pub struct ListBundlesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListBundlesRequest {
+    /// Returns a builder for `ListBundlesRequest`, checking its required members when built.
+    pub fn builder() -> ListBundlesRequestBuilder {
+        ListBundlesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListBundlesRequest`](struct.ListBundlesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListBundlesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListBundlesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListBundlesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListBundlesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBundlesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListBundlesRequest, ::rusoto_core::BuildError> {
+        Ok(ListBundlesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p> Result structure contains a list of all available bundles with details. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -236,6 +499,42 @@ pub struct ListProjectsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListProjectsRequest {
+    /// Returns a builder for `ListProjectsRequest`, checking its required members when built.
+    pub fn builder() -> ListProjectsRequestBuilder {
+        ListProjectsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListProjectsRequest`](struct.ListProjectsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListProjectsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListProjectsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListProjectsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListProjectsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListProjectsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListProjectsRequest, ::rusoto_core::BuildError> {
+        Ok(ListProjectsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 /// <p> Result structure used for requests to list projects in AWS Mobile Hub. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -248,6 +547,104 @@ pub struct ListProjectsResult {
     pub projects: Option<Vec<ProjectSummary>>,
 }
 
+/// <p> Developer desktop or target mobile app or website platform. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Platform {
+    /// <p><code>OSX</code></p>
+    Osx,
+    /// <p><code>WINDOWS</code></p>
+    Windows,
+    /// <p><code>LINUX</code></p>
+    Linux,
+    /// <p><code>OBJC</code></p>
+    Objc,
+    /// <p><code>SWIFT</code></p>
+    Swift,
+    /// <p><code>ANDROID</code></p>
+    Android,
+    /// <p><code>JAVASCRIPT</code></p>
+    Javascript,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Platform {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Platform::Osx => "OSX",
+            Platform::Windows => "WINDOWS",
+            Platform::Linux => "LINUX",
+            Platform::Objc => "OBJC",
+            Platform::Swift => "SWIFT",
+            Platform::Android => "ANDROID",
+            Platform::Javascript => "JAVASCRIPT",
+            Platform::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Platform {
+    fn from(value: &'a str) -> Platform {
+        match value {
+            "OSX" => Platform::Osx,
+            "WINDOWS" => Platform::Windows,
+            "LINUX" => Platform::Linux,
+            "OBJC" => Platform::Objc,
+            "SWIFT" => Platform::Swift,
+            "ANDROID" => Platform::Android,
+            "JAVASCRIPT" => Platform::Javascript,
+            _ => Platform::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Platform {
+    fn from(value: String) -> Platform {
+        Platform::from(value.as_str())
+    }
+}
+
+impl From<Platform> for String {
+    fn from(value: Platform) -> String {
+        match value {
+            Platform::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Platform {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Platform {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Platform {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Platform, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Platform::from(value))
+    }
+}
+
 /// <p> Detailed information about an AWS Mobile Hub project. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -258,12 +655,14 @@ pub struct ProjectDetails {
     pub console_url: Option<String>,
     /// <p> Date the project was created. </p>
     #[serde(rename = "createdDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_date: Option<f64>,
+    pub created_date: Option<EpochTimestamp>,
     /// <p> Date of the last modification of the project. </p>
     #[serde(rename = "lastUpdatedDate")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_date: Option<f64>,
+    pub last_updated_date: Option<EpochTimestamp>,
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -278,7 +677,89 @@ pub struct ProjectDetails {
     pub resources: Option<Vec<Resource>>,
     #[serde(rename = "state")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<ProjectState>,
+}
+
+/// <p> Synchronization state for a project. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProjectState {
+    /// <p><code>NORMAL</code></p>
+    Normal,
+    /// <p><code>SYNCING</code></p>
+    Syncing,
+    /// <p><code>IMPORTING</code></p>
+    Importing,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ProjectState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ProjectState::Normal => "NORMAL",
+            ProjectState::Syncing => "SYNCING",
+            ProjectState::Importing => "IMPORTING",
+            ProjectState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ProjectState {
+    fn from(value: &'a str) -> ProjectState {
+        match value {
+            "NORMAL" => ProjectState::Normal,
+            "SYNCING" => ProjectState::Syncing,
+            "IMPORTING" => ProjectState::Importing,
+            _ => ProjectState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ProjectState {
+    fn from(value: String) -> ProjectState {
+        ProjectState::from(value.as_str())
+    }
+}
+
+impl From<ProjectState> for String {
+    fn from(value: ProjectState) -> String {
+        match value {
+            ProjectState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ProjectState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ProjectState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ProjectState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ProjectState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ProjectState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ProjectState::from(value))
+    }
 }
 
 /// <p> Summary information about an AWS Mobile Hub project. </p>
@@ -333,6 +814,44 @@ pub struct UpdateProjectRequest {
     pub project_id: String,
 }
 
+impl UpdateProjectRequest {
+    /// Returns a builder for `UpdateProjectRequest`, checking its required members when built.
+    pub fn builder() -> UpdateProjectRequestBuilder {
+        UpdateProjectRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateProjectRequest`](struct.UpdateProjectRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateProjectRequestBuilder {
+    contents: Option<bytes::Bytes>,
+    project_id: Option<String>,
+}
+
+impl UpdateProjectRequestBuilder {
+    /// Sets the [`contents`](struct.UpdateProjectRequest.html#structfield.contents) member.
+    pub fn contents<T: Into<bytes::Bytes>>(mut self, value: T) -> Self {
+        self.contents = Some(value.into());
+        self
+    }
+
+    /// Sets the [`project_id`](struct.UpdateProjectRequest.html#structfield.project_id) member.
+    pub fn project_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.project_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateProjectRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateProjectRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateProjectRequest {
+            contents: self.contents,
+            project_id: self
+                .project_id
+                .ok_or(::rusoto_core::BuildError::missing("project_id"))?,
+        })
+    }
+}
+
 /// <p> Result structure used for requests to updated project configuration. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -966,6 +1485,69 @@ pub trait Mobile {
         input: UpdateProjectRequest,
     ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError>;
 }
+impl<T: ?Sized + Mobile> Mobile for ::std::sync::Arc<T> {
+    fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> RusotoFuture<CreateProjectResult, CreateProjectError> {
+        (**self).create_project(input)
+    }
+    fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> RusotoFuture<DeleteProjectResult, DeleteProjectError> {
+        (**self).delete_project(input)
+    }
+    fn describe_bundle(
+        &self,
+        input: DescribeBundleRequest,
+    ) -> RusotoFuture<DescribeBundleResult, DescribeBundleError> {
+        (**self).describe_bundle(input)
+    }
+    fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> RusotoFuture<DescribeProjectResult, DescribeProjectError> {
+        (**self).describe_project(input)
+    }
+    fn export_bundle(
+        &self,
+        input: ExportBundleRequest,
+    ) -> RusotoFuture<ExportBundleResult, ExportBundleError> {
+        (**self).export_bundle(input)
+    }
+    fn export_project(
+        &self,
+        input: ExportProjectRequest,
+    ) -> RusotoFuture<ExportProjectResult, ExportProjectError> {
+        (**self).export_project(input)
+    }
+    fn list_bundles(
+        &self,
+        input: ListBundlesRequest,
+    ) -> RusotoFuture<ListBundlesResult, ListBundlesError> {
+        (**self).list_bundles(input)
+    }
+    fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> RusotoFuture<ListProjectsResult, ListProjectsError> {
+        (**self).list_projects(input)
+    }
+    fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> RusotoFuture<UpdateProjectResult, UpdateProjectError> {
+        (**self).update_project(input)
+    }
+}
+/// The error codes AWS Mobile retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the AWS Mobile API.
 #[derive(Clone)]
 pub struct MobileClient {
@@ -981,6 +1563,27 @@ impl MobileClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_MOBILE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> MobileClient {
+        Self::new(region::Region::from_env_for_service("MOBILE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `mobile`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> MobileClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("mobile", "MOBILE"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -999,7 +1602,54 @@ impl MobileClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MobileClient {
-        MobileClient { client, region }
+        MobileClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "mobile")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "mobile",
+            "AWSMobileHubService",
+            "MOBILE",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Mobile` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Mobile + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for MobileClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Mobile"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "mobile"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> MobileClient {
+        Self::new_with_client(client, region)
     }
 }
 
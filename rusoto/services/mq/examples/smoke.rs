@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AmazonMQ clients.
+//!
+//! Makes a single read-only `ListBrokers` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_mq::{ListBrokersRequest, MQClient, MQ};
+
+fn main() {
+    let client = MQClient::from_env();
+    match client.list_brokers(ListBrokersRequest::default()).sync() {
+        Ok(_) => println!("ListBrokers succeeded"),
+        Err(error) => {
+            eprintln!("ListBrokers failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Name of the availability zone.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -41,7 +42,7 @@ pub struct BrokerEngineType {
     /// <p>The type of broker engine.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>The list of engine versions.</p>
     #[serde(rename = "EngineVersions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -77,7 +78,7 @@ pub struct BrokerInstanceOption {
     /// <p>The type of broker engine.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>The type of broker instance.</p>
     #[serde(rename = "HostInstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -88,6 +89,104 @@ pub struct BrokerInstanceOption {
     pub supported_engine_versions: Option<Vec<String>>,
 }
 
+/// <p>The broker's status.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BrokerState {
+    /// <p><code>CREATION_IN_PROGRESS</code></p>
+    CreationInProgress,
+    /// <p><code>CREATION_FAILED</code></p>
+    CreationFailed,
+    /// <p><code>DELETION_IN_PROGRESS</code></p>
+    DeletionInProgress,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>REBOOT_IN_PROGRESS</code></p>
+    RebootInProgress,
+    /// <p><code>CRITICAL_ACTION_REQUIRED</code></p>
+    CriticalActionRequired,
+    /// <p><code>REPLICA</code></p>
+    Replica,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BrokerState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BrokerState::CreationInProgress => "CREATION_IN_PROGRESS",
+            BrokerState::CreationFailed => "CREATION_FAILED",
+            BrokerState::DeletionInProgress => "DELETION_IN_PROGRESS",
+            BrokerState::Running => "RUNNING",
+            BrokerState::RebootInProgress => "REBOOT_IN_PROGRESS",
+            BrokerState::CriticalActionRequired => "CRITICAL_ACTION_REQUIRED",
+            BrokerState::Replica => "REPLICA",
+            BrokerState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BrokerState {
+    fn from(value: &'a str) -> BrokerState {
+        match value {
+            "CREATION_IN_PROGRESS" => BrokerState::CreationInProgress,
+            "CREATION_FAILED" => BrokerState::CreationFailed,
+            "DELETION_IN_PROGRESS" => BrokerState::DeletionInProgress,
+            "RUNNING" => BrokerState::Running,
+            "REBOOT_IN_PROGRESS" => BrokerState::RebootInProgress,
+            "CRITICAL_ACTION_REQUIRED" => BrokerState::CriticalActionRequired,
+            "REPLICA" => BrokerState::Replica,
+            _ => BrokerState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BrokerState {
+    fn from(value: String) -> BrokerState {
+        BrokerState::from(value.as_str())
+    }
+}
+
+impl From<BrokerState> for String {
+    fn from(value: BrokerState) -> String {
+        match value {
+            BrokerState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BrokerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for BrokerState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for BrokerState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BrokerState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BrokerState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BrokerState::from(value))
+    }
+}
+
 /// <p>The Amazon Resource Name (ARN) of the broker.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -107,21 +206,104 @@ pub struct BrokerSummary {
     /// <p>The status of the broker.</p>
     #[serde(rename = "BrokerState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub broker_state: Option<String>,
+    pub broker_state: Option<BrokerState>,
     /// <p>The time when the broker was created.</p>
     #[serde(rename = "Created")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>Required. The deployment mode of the broker.</p>
     #[serde(rename = "DeploymentMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_mode: Option<String>,
+    pub deployment_mode: Option<DeploymentMode>,
     /// <p>The broker&#39;s instance type.</p>
     #[serde(rename = "HostInstanceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub host_instance_type: Option<String>,
 }
 
+/// <p>The type of change pending for the ActiveMQ user.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeType {
+    /// <p><code>CREATE</code></p>
+    Create,
+    /// <p><code>UPDATE</code></p>
+    Update,
+    /// <p><code>DELETE</code></p>
+    Delete,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChangeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChangeType::Create => "CREATE",
+            ChangeType::Update => "UPDATE",
+            ChangeType::Delete => "DELETE",
+            ChangeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChangeType {
+    fn from(value: &'a str) -> ChangeType {
+        match value {
+            "CREATE" => ChangeType::Create,
+            "UPDATE" => ChangeType::Update,
+            "DELETE" => ChangeType::Delete,
+            _ => ChangeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChangeType {
+    fn from(value: String) -> ChangeType {
+        ChangeType::from(value.as_str())
+    }
+}
+
+impl From<ChangeType> for String {
+    fn from(value: ChangeType) -> String {
+        match value {
+            ChangeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChangeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ChangeType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ChangeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChangeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChangeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChangeType::from(value))
+    }
+}
+
 /// <p>Returns information about all configurations.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -132,8 +314,9 @@ pub struct Configuration {
     pub arn: Option<String>,
     /// <p>Required. The date and time of the configuration revision.</p>
     #[serde(rename = "Created")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>Required. The description of the configuration.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -141,7 +324,7 @@ pub struct Configuration {
     /// <p>Required. The type of broker engine. Note: Currently, Amazon MQ supports only ACTIVEMQ.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>Required. The version of the broker engine. For a list of supported engine versions, see https://docs.aws.amazon.com/amazon-mq/latest/developer-guide/broker-engine.html</p>
     #[serde(rename = "EngineVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -183,8 +366,9 @@ pub struct ConfigurationId {
 pub struct ConfigurationRevision {
     /// <p>Required. The date and time of the configuration revision.</p>
     #[serde(rename = "Created")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>The description of the configuration revision.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -235,7 +419,7 @@ pub struct CreateBrokerRequest {
     /// <p>Required. The deployment mode of the broker.</p>
     #[serde(rename = "DeploymentMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_mode: Option<String>,
+    pub deployment_mode: Option<DeploymentMode>,
     /// <p>Encryption options for the broker.</p>
     #[serde(rename = "EncryptionOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -243,7 +427,7 @@ pub struct CreateBrokerRequest {
     /// <p>Required. The type of broker engine. Note: Currently, Amazon MQ supports only ACTIVEMQ.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>Required. The version of the broker engine. For a list of supported engine versions, see https://docs.aws.amazon.com/amazon-mq/latest/developer-guide/broker-engine.html</p>
     #[serde(rename = "EngineVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -282,6 +466,154 @@ pub struct CreateBrokerRequest {
     pub users: Option<Vec<User>>,
 }
 
+impl CreateBrokerRequest {
+    /// Returns a builder for `CreateBrokerRequest`, checking its required members when built.
+    pub fn builder() -> CreateBrokerRequestBuilder {
+        CreateBrokerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBrokerRequest`](struct.CreateBrokerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateBrokerRequestBuilder {
+    auto_minor_version_upgrade: Option<bool>,
+    broker_name: Option<String>,
+    configuration: Option<ConfigurationId>,
+    creator_request_id: Option<String>,
+    deployment_mode: Option<DeploymentMode>,
+    encryption_options: Option<EncryptionOptions>,
+    engine_type: Option<EngineType>,
+    engine_version: Option<String>,
+    host_instance_type: Option<String>,
+    logs: Option<Logs>,
+    maintenance_window_start_time: Option<WeeklyStartTime>,
+    publicly_accessible: Option<bool>,
+    security_groups: Option<Vec<String>>,
+    subnet_ids: Option<Vec<String>>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+    users: Option<Vec<User>>,
+}
+
+impl CreateBrokerRequestBuilder {
+    /// Sets the [`auto_minor_version_upgrade`](struct.CreateBrokerRequest.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`broker_name`](struct.CreateBrokerRequest.html#structfield.broker_name) member.
+    pub fn broker_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.broker_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration`](struct.CreateBrokerRequest.html#structfield.configuration) member.
+    pub fn configuration<T: Into<ConfigurationId>>(mut self, value: T) -> Self {
+        self.configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`creator_request_id`](struct.CreateBrokerRequest.html#structfield.creator_request_id) member.
+    pub fn creator_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.creator_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_mode`](struct.CreateBrokerRequest.html#structfield.deployment_mode) member.
+    pub fn deployment_mode<T: Into<DeploymentMode>>(mut self, value: T) -> Self {
+        self.deployment_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`encryption_options`](struct.CreateBrokerRequest.html#structfield.encryption_options) member.
+    pub fn encryption_options<T: Into<EncryptionOptions>>(mut self, value: T) -> Self {
+        self.encryption_options = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_type`](struct.CreateBrokerRequest.html#structfield.engine_type) member.
+    pub fn engine_type<T: Into<EngineType>>(mut self, value: T) -> Self {
+        self.engine_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.CreateBrokerRequest.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`host_instance_type`](struct.CreateBrokerRequest.html#structfield.host_instance_type) member.
+    pub fn host_instance_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.host_instance_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logs`](struct.CreateBrokerRequest.html#structfield.logs) member.
+    pub fn logs<T: Into<Logs>>(mut self, value: T) -> Self {
+        self.logs = Some(value.into());
+        self
+    }
+
+    /// Sets the [`maintenance_window_start_time`](struct.CreateBrokerRequest.html#structfield.maintenance_window_start_time) member.
+    pub fn maintenance_window_start_time<T: Into<WeeklyStartTime>>(mut self, value: T) -> Self {
+        self.maintenance_window_start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.CreateBrokerRequest.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_groups`](struct.CreateBrokerRequest.html#structfield.security_groups) member.
+    pub fn security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.CreateBrokerRequest.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateBrokerRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`users`](struct.CreateBrokerRequest.html#structfield.users) member.
+    pub fn users<T: Into<Vec<User>>>(mut self, value: T) -> Self {
+        self.users = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBrokerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateBrokerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateBrokerRequest {
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            broker_name: self.broker_name,
+            configuration: self.configuration,
+            creator_request_id: self.creator_request_id,
+            deployment_mode: self.deployment_mode,
+            encryption_options: self.encryption_options,
+            engine_type: self.engine_type,
+            engine_version: self.engine_version,
+            host_instance_type: self.host_instance_type,
+            logs: self.logs,
+            maintenance_window_start_time: self.maintenance_window_start_time,
+            publicly_accessible: self.publicly_accessible,
+            security_groups: self.security_groups,
+            subnet_ids: self.subnet_ids,
+            tags: self.tags,
+            users: self.users,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateBrokerResponse {
@@ -301,7 +633,7 @@ pub struct CreateConfigurationRequest {
     /// <p>Required. The type of broker engine. Note: Currently, Amazon MQ supports only ACTIVEMQ.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>Required. The version of the broker engine. For a list of supported engine versions, see https://docs.aws.amazon.com/amazon-mq/latest/developer-guide/broker-engine.html</p>
     #[serde(rename = "EngineVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -316,6 +648,60 @@ pub struct CreateConfigurationRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateConfigurationRequest {
+    /// Returns a builder for `CreateConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> CreateConfigurationRequestBuilder {
+        CreateConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateConfigurationRequest`](struct.CreateConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateConfigurationRequestBuilder {
+    engine_type: Option<EngineType>,
+    engine_version: Option<String>,
+    name: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateConfigurationRequestBuilder {
+    /// Sets the [`engine_type`](struct.CreateConfigurationRequest.html#structfield.engine_type) member.
+    pub fn engine_type<T: Into<EngineType>>(mut self, value: T) -> Self {
+        self.engine_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.CreateConfigurationRequest.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateConfigurationRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateConfigurationRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateConfigurationRequest {
+            engine_type: self.engine_type,
+            engine_version: self.engine_version,
+            name: self.name,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateConfigurationResponse {
@@ -325,8 +711,9 @@ pub struct CreateConfigurationResponse {
     pub arn: Option<String>,
     /// <p>Required. The date and time of the configuration.</p>
     #[serde(rename = "Created")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>Required. The unique ID that Amazon MQ generates for the configuration.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -353,6 +740,44 @@ pub struct CreateTagsRequest {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateTagsRequest {
+    /// Returns a builder for `CreateTagsRequest`, checking its required members when built.
+    pub fn builder() -> CreateTagsRequestBuilder {
+        CreateTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTagsRequest`](struct.CreateTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateTagsRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateTagsRequestBuilder {
+    /// Sets the [`resource_arn`](struct.CreateTagsRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateTagsRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTagsRequest, ::rusoto_core::BuildError> {
+        Ok(CreateTagsRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// <p>Creates a new ActiveMQ user.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateUserRequest {
@@ -376,10 +801,172 @@ pub struct CreateUserRequest {
     pub username: String,
 }
 
+impl CreateUserRequest {
+    /// Returns a builder for `CreateUserRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserRequestBuilder {
+        CreateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserRequest`](struct.CreateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserRequestBuilder {
+    broker_id: Option<String>,
+    console_access: Option<bool>,
+    groups: Option<Vec<String>>,
+    password: Option<String>,
+    username: Option<String>,
+}
+
+impl CreateUserRequestBuilder {
+    /// Sets the [`broker_id`](struct.CreateUserRequest.html#structfield.broker_id) member.
+    pub fn broker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.broker_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`console_access`](struct.CreateUserRequest.html#structfield.console_access) member.
+    pub fn console_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.console_access = Some(value.into());
+        self
+    }
+
+    /// Sets the [`groups`](struct.CreateUserRequest.html#structfield.groups) member.
+    pub fn groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.CreateUserRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.CreateUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateUserRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserRequest {
+            broker_id: self
+                .broker_id
+                .ok_or(::rusoto_core::BuildError::missing("broker_id"))?,
+            console_access: self.console_access,
+            groups: self.groups,
+            password: self.password,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateUserResponse {}
 
+/// <p>Required. The day of the week.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DayOfWeek {
+    /// <p><code>MONDAY</code></p>
+    Monday,
+    /// <p><code>TUESDAY</code></p>
+    Tuesday,
+    /// <p><code>WEDNESDAY</code></p>
+    Wednesday,
+    /// <p><code>THURSDAY</code></p>
+    Thursday,
+    /// <p><code>FRIDAY</code></p>
+    Friday,
+    /// <p><code>SATURDAY</code></p>
+    Saturday,
+    /// <p><code>SUNDAY</code></p>
+    Sunday,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DayOfWeek {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DayOfWeek::Monday => "MONDAY",
+            DayOfWeek::Tuesday => "TUESDAY",
+            DayOfWeek::Wednesday => "WEDNESDAY",
+            DayOfWeek::Thursday => "THURSDAY",
+            DayOfWeek::Friday => "FRIDAY",
+            DayOfWeek::Saturday => "SATURDAY",
+            DayOfWeek::Sunday => "SUNDAY",
+            DayOfWeek::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DayOfWeek {
+    fn from(value: &'a str) -> DayOfWeek {
+        match value {
+            "MONDAY" => DayOfWeek::Monday,
+            "TUESDAY" => DayOfWeek::Tuesday,
+            "WEDNESDAY" => DayOfWeek::Wednesday,
+            "THURSDAY" => DayOfWeek::Thursday,
+            "FRIDAY" => DayOfWeek::Friday,
+            "SATURDAY" => DayOfWeek::Saturday,
+            "SUNDAY" => DayOfWeek::Sunday,
+            _ => DayOfWeek::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DayOfWeek {
+    fn from(value: String) -> DayOfWeek {
+        DayOfWeek::from(value.as_str())
+    }
+}
+
+impl From<DayOfWeek> for String {
+    fn from(value: DayOfWeek) -> String {
+        match value {
+            DayOfWeek::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DayOfWeek {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DayOfWeek {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DayOfWeek {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DayOfWeek {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DayOfWeek, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DayOfWeek::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteBrokerRequest {
     /// <p>The name of the broker. This value must be unique in your AWS account, 1-50 characters long, must contain only letters, numbers, dashes, and underscores, and must not contain whitespaces, brackets, wildcard characters, or special characters.</p>
@@ -387,6 +974,36 @@ pub struct DeleteBrokerRequest {
     pub broker_id: String,
 }
 
+impl DeleteBrokerRequest {
+    /// Returns a builder for `DeleteBrokerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBrokerRequestBuilder {
+        DeleteBrokerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBrokerRequest`](struct.DeleteBrokerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBrokerRequestBuilder {
+    broker_id: Option<String>,
+}
+
+impl DeleteBrokerRequestBuilder {
+    /// Sets the [`broker_id`](struct.DeleteBrokerRequest.html#structfield.broker_id) member.
+    pub fn broker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.broker_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBrokerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteBrokerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBrokerRequest {
+            broker_id: self
+                .broker_id
+                .ok_or(::rusoto_core::BuildError::missing("broker_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteBrokerResponse {
@@ -406,6 +1023,46 @@ pub struct DeleteTagsRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl DeleteTagsRequest {
+    /// Returns a builder for `DeleteTagsRequest`, checking its required members when built.
+    pub fn builder() -> DeleteTagsRequestBuilder {
+        DeleteTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTagsRequest`](struct.DeleteTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteTagsRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl DeleteTagsRequestBuilder {
+    /// Sets the [`resource_arn`](struct.DeleteTagsRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DeleteTagsRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTagsRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteTagsRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteUserRequest {
     /// <p>The unique ID that Amazon MQ generates for the broker.</p>
@@ -416,10 +1073,132 @@ pub struct DeleteUserRequest {
     pub username: String,
 }
 
+impl DeleteUserRequest {
+    /// Returns a builder for `DeleteUserRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserRequestBuilder {
+        DeleteUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserRequest`](struct.DeleteUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserRequestBuilder {
+    broker_id: Option<String>,
+    username: Option<String>,
+}
+
+impl DeleteUserRequestBuilder {
+    /// Sets the [`broker_id`](struct.DeleteUserRequest.html#structfield.broker_id) member.
+    pub fn broker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.broker_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.DeleteUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteUserRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserRequest {
+            broker_id: self
+                .broker_id
+                .ok_or(::rusoto_core::BuildError::missing("broker_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteUserResponse {}
 
+/// <p>The broker's deployment mode.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentMode {
+    /// <p><code>SINGLE_INSTANCE</code></p>
+    SingleInstance,
+    /// <p><code>ACTIVE_STANDBY_MULTI_AZ</code></p>
+    ActiveStandbyMultiAz,
+    /// <p><code>CLUSTER_MULTI_AZ</code></p>
+    ClusterMultiAz,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeploymentMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentMode::SingleInstance => "SINGLE_INSTANCE",
+            DeploymentMode::ActiveStandbyMultiAz => "ACTIVE_STANDBY_MULTI_AZ",
+            DeploymentMode::ClusterMultiAz => "CLUSTER_MULTI_AZ",
+            DeploymentMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentMode {
+    fn from(value: &'a str) -> DeploymentMode {
+        match value {
+            "SINGLE_INSTANCE" => DeploymentMode::SingleInstance,
+            "ACTIVE_STANDBY_MULTI_AZ" => DeploymentMode::ActiveStandbyMultiAz,
+            "CLUSTER_MULTI_AZ" => DeploymentMode::ClusterMultiAz,
+            _ => DeploymentMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentMode {
+    fn from(value: String) -> DeploymentMode {
+        DeploymentMode::from(value.as_str())
+    }
+}
+
+impl From<DeploymentMode> for String {
+    fn from(value: DeploymentMode) -> String {
+        match value {
+            DeploymentMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for DeploymentMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for DeploymentMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeploymentMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeploymentMode::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeBrokerEngineTypesRequest {
     /// <p>Filter response by engine type.</p>
@@ -436,6 +1215,52 @@ pub struct DescribeBrokerEngineTypesRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeBrokerEngineTypesRequest {
+    /// Returns a builder for `DescribeBrokerEngineTypesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeBrokerEngineTypesRequestBuilder {
+        DescribeBrokerEngineTypesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBrokerEngineTypesRequest`](struct.DescribeBrokerEngineTypesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeBrokerEngineTypesRequestBuilder {
+    engine_type: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeBrokerEngineTypesRequestBuilder {
+    /// Sets the [`engine_type`](struct.DescribeBrokerEngineTypesRequest.html#structfield.engine_type) member.
+    pub fn engine_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeBrokerEngineTypesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeBrokerEngineTypesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBrokerEngineTypesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeBrokerEngineTypesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeBrokerEngineTypesRequest {
+            engine_type: self.engine_type,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeBrokerEngineTypesResponse {
@@ -473,6 +1298,61 @@ pub struct DescribeBrokerInstanceOptionsRequest {
     pub next_token: Option<String>,
 }
 
+impl DescribeBrokerInstanceOptionsRequest {
+    /// Returns a builder for `DescribeBrokerInstanceOptionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeBrokerInstanceOptionsRequestBuilder {
+        DescribeBrokerInstanceOptionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBrokerInstanceOptionsRequest`](struct.DescribeBrokerInstanceOptionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeBrokerInstanceOptionsRequestBuilder {
+    engine_type: Option<String>,
+    host_instance_type: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl DescribeBrokerInstanceOptionsRequestBuilder {
+    /// Sets the [`engine_type`](struct.DescribeBrokerInstanceOptionsRequest.html#structfield.engine_type) member.
+    pub fn engine_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`host_instance_type`](struct.DescribeBrokerInstanceOptionsRequest.html#structfield.host_instance_type) member.
+    pub fn host_instance_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.host_instance_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeBrokerInstanceOptionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeBrokerInstanceOptionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBrokerInstanceOptionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeBrokerInstanceOptionsRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeBrokerInstanceOptionsRequest {
+            engine_type: self.engine_type,
+            host_instance_type: self.host_instance_type,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeBrokerInstanceOptionsResponse {
@@ -497,6 +1377,36 @@ pub struct DescribeBrokerRequest {
     pub broker_id: String,
 }
 
+impl DescribeBrokerRequest {
+    /// Returns a builder for `DescribeBrokerRequest`, checking its required members when built.
+    pub fn builder() -> DescribeBrokerRequestBuilder {
+        DescribeBrokerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBrokerRequest`](struct.DescribeBrokerRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeBrokerRequestBuilder {
+    broker_id: Option<String>,
+}
+
+impl DescribeBrokerRequestBuilder {
+    /// Sets the [`broker_id`](struct.DescribeBrokerRequest.html#structfield.broker_id) member.
+    pub fn broker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.broker_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBrokerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeBrokerRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeBrokerRequest {
+            broker_id: self
+                .broker_id
+                .ok_or(::rusoto_core::BuildError::missing("broker_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeBrokerResponse {
@@ -523,19 +1433,20 @@ pub struct DescribeBrokerResponse {
     /// <p>The status of the broker.</p>
     #[serde(rename = "BrokerState")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub broker_state: Option<String>,
+    pub broker_state: Option<BrokerState>,
     /// <p>The list of all revisions for the specified configuration.</p>
     #[serde(rename = "Configurations")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub configurations: Option<Configurations>,
     /// <p>The time when the broker was created.</p>
     #[serde(rename = "Created")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>Required. The deployment mode of the broker.</p>
     #[serde(rename = "DeploymentMode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deployment_mode: Option<String>,
+    pub deployment_mode: Option<DeploymentMode>,
     /// <p>Encryption options for the broker.</p>
     #[serde(rename = "EncryptionOptions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -543,7 +1454,7 @@ pub struct DescribeBrokerResponse {
     /// <p>Required. The type of broker engine. Note: Currently, Amazon MQ supports only ACTIVEMQ.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>The version of the broker engine. For a list of supported engine versions, see https://docs.aws.amazon.com/amazon-mq/latest/developer-guide/broker-engine.html</p>
     #[serde(rename = "EngineVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -597,6 +1508,38 @@ pub struct DescribeConfigurationRequest {
     pub configuration_id: String,
 }
 
+impl DescribeConfigurationRequest {
+    /// Returns a builder for `DescribeConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConfigurationRequestBuilder {
+        DescribeConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigurationRequest`](struct.DescribeConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigurationRequestBuilder {
+    configuration_id: Option<String>,
+}
+
+impl DescribeConfigurationRequestBuilder {
+    /// Sets the [`configuration_id`](struct.DescribeConfigurationRequest.html#structfield.configuration_id) member.
+    pub fn configuration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeConfigurationRequest {
+            configuration_id: self
+                .configuration_id
+                .ok_or(::rusoto_core::BuildError::missing("configuration_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeConfigurationResponse {
@@ -606,8 +1549,9 @@ pub struct DescribeConfigurationResponse {
     pub arn: Option<String>,
     /// <p>Required. The date and time of the configuration revision.</p>
     #[serde(rename = "Created")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>Required. The description of the configuration.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -615,7 +1559,7 @@ pub struct DescribeConfigurationResponse {
     /// <p>Required. The type of broker engine. Note: Currently, Amazon MQ supports only ACTIVEMQ.</p>
     #[serde(rename = "EngineType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine_type: Option<String>,
+    pub engine_type: Option<EngineType>,
     /// <p>Required. The version of the broker engine. For a list of supported engine versions, see https://docs.aws.amazon.com/amazon-mq/latest/developer-guide/broker-engine.html</p>
     #[serde(rename = "EngineVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -648,6 +1592,49 @@ pub struct DescribeConfigurationRevisionRequest {
     pub configuration_revision: String,
 }
 
+impl DescribeConfigurationRevisionRequest {
+    /// Returns a builder for `DescribeConfigurationRevisionRequest`, checking its required members when built.
+    pub fn builder() -> DescribeConfigurationRevisionRequestBuilder {
+        DescribeConfigurationRevisionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeConfigurationRevisionRequest`](struct.DescribeConfigurationRevisionRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeConfigurationRevisionRequestBuilder {
+    configuration_id: Option<String>,
+    configuration_revision: Option<String>,
+}
+
+impl DescribeConfigurationRevisionRequestBuilder {
+    /// Sets the [`configuration_id`](struct.DescribeConfigurationRevisionRequest.html#structfield.configuration_id) member.
+    pub fn configuration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration_revision`](struct.DescribeConfigurationRevisionRequest.html#structfield.configuration_revision) member.
+    pub fn configuration_revision<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_revision = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeConfigurationRevisionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeConfigurationRevisionRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeConfigurationRevisionRequest {
+            configuration_id: self
+                .configuration_id
+                .ok_or(::rusoto_core::BuildError::missing("configuration_id"))?,
+            configuration_revision: self
+                .configuration_revision
+                .ok_or(::rusoto_core::BuildError::missing("configuration_revision"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeConfigurationRevisionResponse {
@@ -657,8 +1644,9 @@ pub struct DescribeConfigurationRevisionResponse {
     pub configuration_id: Option<String>,
     /// <p>Required. The date and time of the configuration.</p>
     #[serde(rename = "Created")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>Required. The base64-encoded XML configuration.</p>
     #[serde(rename = "Data")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -679,6 +1667,46 @@ pub struct DescribeUserRequest {
     pub username: String,
 }
 
+impl DescribeUserRequest {
+    /// Returns a builder for `DescribeUserRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserRequestBuilder {
+        DescribeUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserRequest`](struct.DescribeUserRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserRequestBuilder {
+    broker_id: Option<String>,
+    username: Option<String>,
+}
+
+impl DescribeUserRequestBuilder {
+    /// Sets the [`broker_id`](struct.DescribeUserRequest.html#structfield.broker_id) member.
+    pub fn broker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.broker_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.DescribeUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeUserRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUserRequest {
+            broker_id: self
+                .broker_id
+                .ok_or(::rusoto_core::BuildError::missing("broker_id"))?,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeUserResponse {
@@ -716,6 +1744,84 @@ pub struct EncryptionOptions {
     pub use_aws_owned_key: bool,
 }
 
+/// <p>The type of broker engine. Amazon MQ supports ActiveMQ and RabbitMQ.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EngineType {
+    /// <p><code>ACTIVEMQ</code></p>
+    Activemq,
+    /// <p><code>RABBITMQ</code></p>
+    Rabbitmq,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EngineType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EngineType::Activemq => "ACTIVEMQ",
+            EngineType::Rabbitmq => "RABBITMQ",
+            EngineType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EngineType {
+    fn from(value: &'a str) -> EngineType {
+        match value {
+            "ACTIVEMQ" => EngineType::Activemq,
+            "RABBITMQ" => EngineType::Rabbitmq,
+            _ => EngineType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EngineType {
+    fn from(value: String) -> EngineType {
+        EngineType::from(value.as_str())
+    }
+}
+
+impl From<EngineType> for String {
+    fn from(value: EngineType) -> String {
+        match value {
+            EngineType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EngineType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for EngineType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for EngineType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EngineType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EngineType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EngineType::from(value))
+    }
+}
+
 /// <p>Id of the engine version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -738,6 +1844,42 @@ pub struct ListBrokersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListBrokersRequest {
+    /// Returns a builder for `ListBrokersRequest`, checking its required members when built.
+    pub fn builder() -> ListBrokersRequestBuilder {
+        ListBrokersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListBrokersRequest`](struct.ListBrokersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListBrokersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListBrokersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListBrokersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListBrokersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBrokersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListBrokersRequest, ::rusoto_core::BuildError> {
+        Ok(ListBrokersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListBrokersResponse {
@@ -766,6 +1908,54 @@ pub struct ListConfigurationRevisionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListConfigurationRevisionsRequest {
+    /// Returns a builder for `ListConfigurationRevisionsRequest`, checking its required members when built.
+    pub fn builder() -> ListConfigurationRevisionsRequestBuilder {
+        ListConfigurationRevisionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListConfigurationRevisionsRequest`](struct.ListConfigurationRevisionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListConfigurationRevisionsRequestBuilder {
+    configuration_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListConfigurationRevisionsRequestBuilder {
+    /// Sets the [`configuration_id`](struct.ListConfigurationRevisionsRequest.html#structfield.configuration_id) member.
+    pub fn configuration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListConfigurationRevisionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListConfigurationRevisionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListConfigurationRevisionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListConfigurationRevisionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListConfigurationRevisionsRequest {
+            configuration_id: self
+                .configuration_id
+                .ok_or(::rusoto_core::BuildError::missing("configuration_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListConfigurationRevisionsResponse {
@@ -799,6 +1989,44 @@ pub struct ListConfigurationsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListConfigurationsRequest {
+    /// Returns a builder for `ListConfigurationsRequest`, checking its required members when built.
+    pub fn builder() -> ListConfigurationsRequestBuilder {
+        ListConfigurationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListConfigurationsRequest`](struct.ListConfigurationsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListConfigurationsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListConfigurationsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListConfigurationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListConfigurationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListConfigurationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListConfigurationsRequest, ::rusoto_core::BuildError> {
+        Ok(ListConfigurationsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListConfigurationsResponse {
@@ -816,11 +2044,41 @@ pub struct ListConfigurationsResponse {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct ListTagsRequest {
-    /// <p>The Amazon Resource Name (ARN) of the resource tag.</p>
-    #[serde(rename = "ResourceArn")]
-    pub resource_arn: String,
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct ListTagsRequest {
+    /// <p>The Amazon Resource Name (ARN) of the resource tag.</p>
+    #[serde(rename = "ResourceArn")]
+    pub resource_arn: String,
+}
+
+impl ListTagsRequest {
+    /// Returns a builder for `ListTagsRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsRequestBuilder {
+        ListTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsRequest`](struct.ListTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTagsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -847,6 +2105,52 @@ pub struct ListUsersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListUsersRequest {
+    /// Returns a builder for `ListUsersRequest`, checking its required members when built.
+    pub fn builder() -> ListUsersRequestBuilder {
+        ListUsersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListUsersRequest`](struct.ListUsersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListUsersRequestBuilder {
+    broker_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListUsersRequestBuilder {
+    /// Sets the [`broker_id`](struct.ListUsersRequest.html#structfield.broker_id) member.
+    pub fn broker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.broker_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListUsersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListUsersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListUsersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListUsersRequest, ::rusoto_core::BuildError> {
+        Ok(ListUsersRequest {
+            broker_id: self
+                .broker_id
+                .ok_or(::rusoto_core::BuildError::missing("broker_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListUsersResponse {
@@ -928,6 +2232,36 @@ pub struct RebootBrokerRequest {
     pub broker_id: String,
 }
 
+impl RebootBrokerRequest {
+    /// Returns a builder for `RebootBrokerRequest`, checking its required members when built.
+    pub fn builder() -> RebootBrokerRequestBuilder {
+        RebootBrokerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RebootBrokerRequest`](struct.RebootBrokerRequest.html).
+#[derive(Default, Debug)]
+pub struct RebootBrokerRequestBuilder {
+    broker_id: Option<String>,
+}
+
+impl RebootBrokerRequestBuilder {
+    /// Sets the [`broker_id`](struct.RebootBrokerRequest.html#structfield.broker_id) member.
+    pub fn broker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.broker_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebootBrokerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RebootBrokerRequest, ::rusoto_core::BuildError> {
+        Ok(RebootBrokerRequest {
+            broker_id: self
+                .broker_id
+                .ok_or(::rusoto_core::BuildError::missing("broker_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RebootBrokerResponse {}
@@ -947,7 +2281,93 @@ pub struct SanitizationWarning {
     /// <p>Required. The reason for which the XML elements or attributes were sanitized.</p>
     #[serde(rename = "Reason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reason: Option<String>,
+    pub reason: Option<SanitizationWarningReason>,
+}
+
+/// <p>The reason for which the configuration elements or attributes were sanitized.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SanitizationWarningReason {
+    /// <p><code>DISALLOWED_ELEMENT_REMOVED</code></p>
+    DisallowedElementRemoved,
+    /// <p><code>DISALLOWED_ATTRIBUTE_REMOVED</code></p>
+    DisallowedAttributeRemoved,
+    /// <p><code>INVALID_ATTRIBUTE_VALUE_REMOVED</code></p>
+    InvalidAttributeValueRemoved,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SanitizationWarningReason {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SanitizationWarningReason::DisallowedElementRemoved => "DISALLOWED_ELEMENT_REMOVED",
+            SanitizationWarningReason::DisallowedAttributeRemoved => "DISALLOWED_ATTRIBUTE_REMOVED",
+            SanitizationWarningReason::InvalidAttributeValueRemoved => {
+                "INVALID_ATTRIBUTE_VALUE_REMOVED"
+            }
+            SanitizationWarningReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SanitizationWarningReason {
+    fn from(value: &'a str) -> SanitizationWarningReason {
+        match value {
+            "DISALLOWED_ELEMENT_REMOVED" => SanitizationWarningReason::DisallowedElementRemoved,
+            "DISALLOWED_ATTRIBUTE_REMOVED" => SanitizationWarningReason::DisallowedAttributeRemoved,
+            "INVALID_ATTRIBUTE_VALUE_REMOVED" => {
+                SanitizationWarningReason::InvalidAttributeValueRemoved
+            }
+            _ => SanitizationWarningReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SanitizationWarningReason {
+    fn from(value: String) -> SanitizationWarningReason {
+        SanitizationWarningReason::from(value.as_str())
+    }
+}
+
+impl From<SanitizationWarningReason> for String {
+    fn from(value: SanitizationWarningReason) -> String {
+        match value {
+            SanitizationWarningReason::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SanitizationWarningReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SanitizationWarningReason {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SanitizationWarningReason {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SanitizationWarningReason {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SanitizationWarningReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SanitizationWarningReason::from(value))
+    }
 }
 
 /// <p>Updates the broker using the specified properties.</p>
@@ -978,6 +2398,76 @@ pub struct UpdateBrokerRequest {
     pub security_groups: Option<Vec<String>>,
 }
 
+impl UpdateBrokerRequest {
+    /// Returns a builder for `UpdateBrokerRequest`, checking its required members when built.
+    pub fn builder() -> UpdateBrokerRequestBuilder {
+        UpdateBrokerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateBrokerRequest`](struct.UpdateBrokerRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateBrokerRequestBuilder {
+    auto_minor_version_upgrade: Option<bool>,
+    broker_id: Option<String>,
+    configuration: Option<ConfigurationId>,
+    engine_version: Option<String>,
+    logs: Option<Logs>,
+    security_groups: Option<Vec<String>>,
+}
+
+impl UpdateBrokerRequestBuilder {
+    /// Sets the [`auto_minor_version_upgrade`](struct.UpdateBrokerRequest.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`broker_id`](struct.UpdateBrokerRequest.html#structfield.broker_id) member.
+    pub fn broker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.broker_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration`](struct.UpdateBrokerRequest.html#structfield.configuration) member.
+    pub fn configuration<T: Into<ConfigurationId>>(mut self, value: T) -> Self {
+        self.configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.UpdateBrokerRequest.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`logs`](struct.UpdateBrokerRequest.html#structfield.logs) member.
+    pub fn logs<T: Into<Logs>>(mut self, value: T) -> Self {
+        self.logs = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_groups`](struct.UpdateBrokerRequest.html#structfield.security_groups) member.
+    pub fn security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_groups = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateBrokerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateBrokerRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateBrokerRequest {
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            broker_id: self
+                .broker_id
+                .ok_or(::rusoto_core::BuildError::missing("broker_id"))?,
+            configuration: self.configuration,
+            engine_version: self.engine_version,
+            logs: self.logs,
+            security_groups: self.security_groups,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateBrokerResponse {
@@ -1023,6 +2513,54 @@ pub struct UpdateConfigurationRequest {
     pub description: Option<String>,
 }
 
+impl UpdateConfigurationRequest {
+    /// Returns a builder for `UpdateConfigurationRequest`, checking its required members when built.
+    pub fn builder() -> UpdateConfigurationRequestBuilder {
+        UpdateConfigurationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateConfigurationRequest`](struct.UpdateConfigurationRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateConfigurationRequestBuilder {
+    configuration_id: Option<String>,
+    data: Option<String>,
+    description: Option<String>,
+}
+
+impl UpdateConfigurationRequestBuilder {
+    /// Sets the [`configuration_id`](struct.UpdateConfigurationRequest.html#structfield.configuration_id) member.
+    pub fn configuration_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.configuration_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data`](struct.UpdateConfigurationRequest.html#structfield.data) member.
+    pub fn data<T: Into<String>>(mut self, value: T) -> Self {
+        self.data = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateConfigurationRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateConfigurationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateConfigurationRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateConfigurationRequest {
+            configuration_id: self
+                .configuration_id
+                .ok_or(::rusoto_core::BuildError::missing("configuration_id"))?,
+            data: self.data,
+            description: self.description,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateConfigurationResponse {
@@ -1032,8 +2570,9 @@ pub struct UpdateConfigurationResponse {
     pub arn: Option<String>,
     /// <p>Required. The date and time of the configuration.</p>
     #[serde(rename = "Created")]
+    #[serde(default, with = "::rusoto_core::timestamp::iso8601::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<f64>,
+    pub created: Option<EpochTimestamp>,
     /// <p>Required. The unique ID that Amazon MQ generates for the configuration.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1075,6 +2614,70 @@ pub struct UpdateUserRequest {
     pub username: String,
 }
 
+impl UpdateUserRequest {
+    /// Returns a builder for `UpdateUserRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserRequestBuilder {
+        UpdateUserRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserRequest`](struct.UpdateUserRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserRequestBuilder {
+    broker_id: Option<String>,
+    console_access: Option<bool>,
+    groups: Option<Vec<String>>,
+    password: Option<String>,
+    username: Option<String>,
+}
+
+impl UpdateUserRequestBuilder {
+    /// Sets the [`broker_id`](struct.UpdateUserRequest.html#structfield.broker_id) member.
+    pub fn broker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.broker_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`console_access`](struct.UpdateUserRequest.html#structfield.console_access) member.
+    pub fn console_access<T: Into<bool>>(mut self, value: T) -> Self {
+        self.console_access = Some(value.into());
+        self
+    }
+
+    /// Sets the [`groups`](struct.UpdateUserRequest.html#structfield.groups) member.
+    pub fn groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`password`](struct.UpdateUserRequest.html#structfield.password) member.
+    pub fn password<T: Into<String>>(mut self, value: T) -> Self {
+        self.password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`username`](struct.UpdateUserRequest.html#structfield.username) member.
+    pub fn username<T: Into<String>>(mut self, value: T) -> Self {
+        self.username = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateUserRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserRequest {
+            broker_id: self
+                .broker_id
+                .ok_or(::rusoto_core::BuildError::missing("broker_id"))?,
+            console_access: self.console_access,
+            groups: self.groups,
+            password: self.password,
+            username: self
+                .username
+                .ok_or(::rusoto_core::BuildError::missing("username"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateUserResponse {}
@@ -1115,7 +2718,7 @@ pub struct UserPendingChanges {
     /// <p>Required. The type of change pending for the ActiveMQ user.</p>
     #[serde(rename = "PendingChange")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pending_change: Option<String>,
+    pub pending_change: Option<ChangeType>,
 }
 
 /// <p>Returns a list of all ActiveMQ users.</p>
@@ -1125,7 +2728,7 @@ pub struct UserSummary {
     /// <p>The type of change pending for the ActiveMQ user.</p>
     #[serde(rename = "PendingChange")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pending_change: Option<String>,
+    pub pending_change: Option<ChangeType>,
     /// <p>Required. The username of the ActiveMQ user. This value can contain only alphanumeric characters, dashes, periods, underscores, and tildes (- . _ ~). This value must be 2-100 characters long.</p>
     #[serde(rename = "Username")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1138,7 +2741,7 @@ pub struct WeeklyStartTime {
     /// <p>Required. The day of the week.</p>
     #[serde(rename = "DayOfWeek")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub day_of_week: Option<String>,
+    pub day_of_week: Option<DayOfWeek>,
     /// <p>Required. The time, in 24-hour format.</p>
     #[serde(rename = "TimeOfDay")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2440,6 +4043,140 @@ pub trait MQ {
         input: UpdateUserRequest,
     ) -> RusotoFuture<UpdateUserResponse, UpdateUserError>;
 }
+impl<T: ?Sized + MQ> MQ for ::std::sync::Arc<T> {
+    fn create_broker(
+        &self,
+        input: CreateBrokerRequest,
+    ) -> RusotoFuture<CreateBrokerResponse, CreateBrokerError> {
+        (**self).create_broker(input)
+    }
+    fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> RusotoFuture<CreateConfigurationResponse, CreateConfigurationError> {
+        (**self).create_configuration(input)
+    }
+    fn create_tags(&self, input: CreateTagsRequest) -> RusotoFuture<(), CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> RusotoFuture<CreateUserResponse, CreateUserError> {
+        (**self).create_user(input)
+    }
+    fn delete_broker(
+        &self,
+        input: DeleteBrokerRequest,
+    ) -> RusotoFuture<DeleteBrokerResponse, DeleteBrokerError> {
+        (**self).delete_broker(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsRequest) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> RusotoFuture<DeleteUserResponse, DeleteUserError> {
+        (**self).delete_user(input)
+    }
+    fn describe_broker(
+        &self,
+        input: DescribeBrokerRequest,
+    ) -> RusotoFuture<DescribeBrokerResponse, DescribeBrokerError> {
+        (**self).describe_broker(input)
+    }
+    fn describe_broker_engine_types(
+        &self,
+        input: DescribeBrokerEngineTypesRequest,
+    ) -> RusotoFuture<DescribeBrokerEngineTypesResponse, DescribeBrokerEngineTypesError> {
+        (**self).describe_broker_engine_types(input)
+    }
+    fn describe_broker_instance_options(
+        &self,
+        input: DescribeBrokerInstanceOptionsRequest,
+    ) -> RusotoFuture<DescribeBrokerInstanceOptionsResponse, DescribeBrokerInstanceOptionsError>
+    {
+        (**self).describe_broker_instance_options(input)
+    }
+    fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> RusotoFuture<DescribeConfigurationResponse, DescribeConfigurationError> {
+        (**self).describe_configuration(input)
+    }
+    fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> RusotoFuture<DescribeConfigurationRevisionResponse, DescribeConfigurationRevisionError>
+    {
+        (**self).describe_configuration_revision(input)
+    }
+    fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> RusotoFuture<DescribeUserResponse, DescribeUserError> {
+        (**self).describe_user(input)
+    }
+    fn list_brokers(
+        &self,
+        input: ListBrokersRequest,
+    ) -> RusotoFuture<ListBrokersResponse, ListBrokersError> {
+        (**self).list_brokers(input)
+    }
+    fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> RusotoFuture<ListConfigurationRevisionsResponse, ListConfigurationRevisionsError> {
+        (**self).list_configuration_revisions(input)
+    }
+    fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> RusotoFuture<ListConfigurationsResponse, ListConfigurationsError> {
+        (**self).list_configurations(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResponse, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> RusotoFuture<ListUsersResponse, ListUsersError> {
+        (**self).list_users(input)
+    }
+    fn reboot_broker(
+        &self,
+        input: RebootBrokerRequest,
+    ) -> RusotoFuture<RebootBrokerResponse, RebootBrokerError> {
+        (**self).reboot_broker(input)
+    }
+    fn update_broker(
+        &self,
+        input: UpdateBrokerRequest,
+    ) -> RusotoFuture<UpdateBrokerResponse, UpdateBrokerError> {
+        (**self).update_broker(input)
+    }
+    fn update_configuration(
+        &self,
+        input: UpdateConfigurationRequest,
+    ) -> RusotoFuture<UpdateConfigurationResponse, UpdateConfigurationError> {
+        (**self).update_configuration(input)
+    }
+    fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> RusotoFuture<UpdateUserResponse, UpdateUserError> {
+        (**self).update_user(input)
+    }
+}
+/// The error codes AmazonMQ retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AmazonMQ API.
 #[derive(Clone)]
 pub struct MQClient {
@@ -2455,6 +4192,24 @@ impl MQClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_MQ`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> MQClient {
+        Self::new(region::Region::from_env_for_service("MQ"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `mq`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> MQClient {
+        Self::new_with_client(config.client().clone(), config.region_for("mq", "MQ"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2473,7 +4228,49 @@ impl MQClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MQClient {
-        MQClient { client, region }
+        MQClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "mq")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "mq", "mq", "MQ")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `MQ` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn MQ + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for MQClient {
+    fn service_name(&self) -> &'static str {
+        "AmazonMQ"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "mq"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> MQClient {
+        Self::new_with_client(client, region)
     }
 }
 
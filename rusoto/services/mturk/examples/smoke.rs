@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Mechanical Turk clients.
+//!
+//! Makes a single read-only `ListBonusPayments` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_mturk::{ListBonusPaymentsRequest, MechanicalTurk, MechanicalTurkClient};
+
+fn main() {
+    let client = MechanicalTurkClient::from_env();
+    match client
+        .list_bonus_payments(ListBonusPaymentsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListBonusPayments succeeded"),
+        Err(error) => {
+            eprintln!("ListBonusPayments failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
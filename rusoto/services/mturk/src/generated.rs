@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AcceptQualificationRequestRequest {
@@ -34,6 +35,46 @@ pub struct AcceptQualificationRequestRequest {
     pub qualification_request_id: String,
 }
 
+impl AcceptQualificationRequestRequest {
+    /// Returns a builder for `AcceptQualificationRequestRequest`, checking its required members when built.
+    pub fn builder() -> AcceptQualificationRequestRequestBuilder {
+        AcceptQualificationRequestRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AcceptQualificationRequestRequest`](struct.AcceptQualificationRequestRequest.html).
+#[derive(Default, Debug)]
+pub struct AcceptQualificationRequestRequestBuilder {
+    integer_value: Option<i64>,
+    qualification_request_id: Option<String>,
+}
+
+impl AcceptQualificationRequestRequestBuilder {
+    /// Sets the [`integer_value`](struct.AcceptQualificationRequestRequest.html#structfield.integer_value) member.
+    pub fn integer_value<T: Into<i64>>(mut self, value: T) -> Self {
+        self.integer_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualification_request_id`](struct.AcceptQualificationRequestRequest.html#structfield.qualification_request_id) member.
+    pub fn qualification_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualification_request_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AcceptQualificationRequestRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AcceptQualificationRequestRequest, ::rusoto_core::BuildError> {
+        Ok(AcceptQualificationRequestRequest {
+            integer_value: self.integer_value,
+            qualification_request_id: self.qualification_request_id.ok_or(
+                ::rusoto_core::BuildError::missing("qualification_request_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AcceptQualificationRequestResponse {}
@@ -53,6 +94,54 @@ pub struct ApproveAssignmentRequest {
     pub requester_feedback: Option<String>,
 }
 
+impl ApproveAssignmentRequest {
+    /// Returns a builder for `ApproveAssignmentRequest`, checking its required members when built.
+    pub fn builder() -> ApproveAssignmentRequestBuilder {
+        ApproveAssignmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ApproveAssignmentRequest`](struct.ApproveAssignmentRequest.html).
+#[derive(Default, Debug)]
+pub struct ApproveAssignmentRequestBuilder {
+    assignment_id: Option<String>,
+    override_rejection: Option<bool>,
+    requester_feedback: Option<String>,
+}
+
+impl ApproveAssignmentRequestBuilder {
+    /// Sets the [`assignment_id`](struct.ApproveAssignmentRequest.html#structfield.assignment_id) member.
+    pub fn assignment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.assignment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`override_rejection`](struct.ApproveAssignmentRequest.html#structfield.override_rejection) member.
+    pub fn override_rejection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.override_rejection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`requester_feedback`](struct.ApproveAssignmentRequest.html#structfield.requester_feedback) member.
+    pub fn requester_feedback<T: Into<String>>(mut self, value: T) -> Self {
+        self.requester_feedback = Some(value.into());
+        self
+    }
+
+    /// Builds the `ApproveAssignmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ApproveAssignmentRequest, ::rusoto_core::BuildError> {
+        Ok(ApproveAssignmentRequest {
+            assignment_id: self
+                .assignment_id
+                .ok_or(::rusoto_core::BuildError::missing("assignment_id"))?,
+            override_rejection: self.override_rejection,
+            requester_feedback: self.requester_feedback,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ApproveAssignmentResponse {}
@@ -63,16 +152,18 @@ pub struct ApproveAssignmentResponse {}
 pub struct Assignment {
     /// <p> The date and time the Worker accepted the assignment.</p>
     #[serde(rename = "AcceptTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub accept_time: Option<f64>,
+    pub accept_time: Option<EpochTimestamp>,
     /// <p> The Worker's answers submitted for the HIT contained in a QuestionFormAnswers document, if the Worker provides an answer. If the Worker does not provide any answers, Answer may contain a QuestionFormAnswers document, or Answer may be empty.</p>
     #[serde(rename = "Answer")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub answer: Option<String>,
     /// <p> If the Worker has submitted results and the Requester has approved the results, ApprovalTime is the date and time the Requester approved the results. This value is omitted from the assignment if the Requester has not yet approved the results.</p>
     #[serde(rename = "ApprovalTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub approval_time: Option<f64>,
+    pub approval_time: Option<EpochTimestamp>,
     /// <p> A unique identifier for the assignment.</p>
     #[serde(rename = "AssignmentId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -80,37 +171,117 @@ pub struct Assignment {
     /// <p> The status of the assignment.</p>
     #[serde(rename = "AssignmentStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignment_status: Option<String>,
+    pub assignment_status: Option<AssignmentStatus>,
     /// <p> If results have been submitted, AutoApprovalTime is the date and time the results of the assignment results are considered Approved automatically if they have not already been explicitly approved or rejected by the Requester. This value is derived from the auto-approval delay specified by the Requester in the HIT. This value is omitted from the assignment if the Worker has not yet submitted results.</p>
     #[serde(rename = "AutoApprovalTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_approval_time: Option<f64>,
+    pub auto_approval_time: Option<EpochTimestamp>,
     /// <p> The date and time of the deadline for the assignment. This value is derived from the deadline specification for the HIT and the date and time the Worker accepted the HIT.</p>
     #[serde(rename = "Deadline")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub deadline: Option<f64>,
+    pub deadline: Option<EpochTimestamp>,
     /// <p> The ID of the HIT.</p>
     #[serde(rename = "HITId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hit_id: Option<String>,
     /// <p> If the Worker has submitted results and the Requester has rejected the results, RejectionTime is the date and time the Requester rejected the results.</p>
     #[serde(rename = "RejectionTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub rejection_time: Option<f64>,
+    pub rejection_time: Option<EpochTimestamp>,
     /// <p> The feedback string included with the call to the ApproveAssignment operation or the RejectAssignment operation, if the Requester approved or rejected the assignment and specified feedback.</p>
     #[serde(rename = "RequesterFeedback")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub requester_feedback: Option<String>,
     /// <p> If the Worker has submitted results, SubmitTime is the date and time the assignment was submitted. This value is omitted from the assignment if the Worker has not yet submitted results.</p>
     #[serde(rename = "SubmitTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<EpochTimestamp>,
     /// <p> The ID of the Worker who accepted the HIT.</p>
     #[serde(rename = "WorkerId")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub worker_id: Option<String>,
 }
 
+/// <p> The status of the assignment.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AssignmentStatus {
+    /// <p><code>Submitted</code></p>
+    Submitted,
+    /// <p><code>Approved</code></p>
+    Approved,
+    /// <p><code>Rejected</code></p>
+    Rejected,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AssignmentStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AssignmentStatus::Submitted => "Submitted",
+            AssignmentStatus::Approved => "Approved",
+            AssignmentStatus::Rejected => "Rejected",
+            AssignmentStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AssignmentStatus {
+    fn from(value: &'a str) -> AssignmentStatus {
+        match value {
+            "Submitted" => AssignmentStatus::Submitted,
+            "Approved" => AssignmentStatus::Approved,
+            "Rejected" => AssignmentStatus::Rejected,
+            _ => AssignmentStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AssignmentStatus {
+    fn from(value: String) -> AssignmentStatus {
+        AssignmentStatus::from(value.as_str())
+    }
+}
+
+impl From<AssignmentStatus> for String {
+    fn from(value: AssignmentStatus) -> String {
+        match value {
+            AssignmentStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AssignmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AssignmentStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AssignmentStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AssignmentStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AssignmentStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateQualificationWithWorkerRequest {
     /// <p>The value of the Qualification to assign.</p>
@@ -129,6 +300,65 @@ pub struct AssociateQualificationWithWorkerRequest {
     pub worker_id: String,
 }
 
+impl AssociateQualificationWithWorkerRequest {
+    /// Returns a builder for `AssociateQualificationWithWorkerRequest`, checking its required members when built.
+    pub fn builder() -> AssociateQualificationWithWorkerRequestBuilder {
+        AssociateQualificationWithWorkerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateQualificationWithWorkerRequest`](struct.AssociateQualificationWithWorkerRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateQualificationWithWorkerRequestBuilder {
+    integer_value: Option<i64>,
+    qualification_type_id: Option<String>,
+    send_notification: Option<bool>,
+    worker_id: Option<String>,
+}
+
+impl AssociateQualificationWithWorkerRequestBuilder {
+    /// Sets the [`integer_value`](struct.AssociateQualificationWithWorkerRequest.html#structfield.integer_value) member.
+    pub fn integer_value<T: Into<i64>>(mut self, value: T) -> Self {
+        self.integer_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualification_type_id`](struct.AssociateQualificationWithWorkerRequest.html#structfield.qualification_type_id) member.
+    pub fn qualification_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualification_type_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`send_notification`](struct.AssociateQualificationWithWorkerRequest.html#structfield.send_notification) member.
+    pub fn send_notification<T: Into<bool>>(mut self, value: T) -> Self {
+        self.send_notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_id`](struct.AssociateQualificationWithWorkerRequest.html#structfield.worker_id) member.
+    pub fn worker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.worker_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateQualificationWithWorkerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateQualificationWithWorkerRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AssociateQualificationWithWorkerRequest {
+            integer_value: self.integer_value,
+            qualification_type_id: self
+                .qualification_type_id
+                .ok_or(::rusoto_core::BuildError::missing("qualification_type_id"))?,
+            send_notification: self.send_notification,
+            worker_id: self
+                .worker_id
+                .ok_or(::rusoto_core::BuildError::missing("worker_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateQualificationWithWorkerResponse {}
@@ -146,8 +376,9 @@ pub struct BonusPayment {
     pub bonus_amount: Option<String>,
     /// <p>The date and time of when the bonus was granted.</p>
     #[serde(rename = "GrantTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub grant_time: Option<f64>,
+    pub grant_time: Option<EpochTimestamp>,
     /// <p>The Reason text given when the bonus was granted, if any.</p>
     #[serde(rename = "Reason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -158,6 +389,110 @@ pub struct BonusPayment {
     pub worker_id: Option<String>,
 }
 
+/// <p>The kind of comparison to make against a Qualification's value. You can compare a Qualification's value to an IntegerValue to see if it is LessThan, LessThanOrEqualTo, GreaterThan, GreaterThanOrEqualTo, EqualTo, or NotEqualTo the IntegerValue. You can compare it to a LocaleValue to see if it is EqualTo, or NotEqualTo the LocaleValue. You can check to see if the value is In or NotIn a set of IntegerValue or LocaleValue values. Lastly, a Qualification requirement can also test if a Qualification Exists or DoesNotExist in the user's profile, regardless of its value. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Comparator {
+    /// <p><code>LessThan</code></p>
+    LessThan,
+    /// <p><code>LessThanOrEqualTo</code></p>
+    LessThanOrEqualTo,
+    /// <p><code>GreaterThan</code></p>
+    GreaterThan,
+    /// <p><code>GreaterThanOrEqualTo</code></p>
+    GreaterThanOrEqualTo,
+    /// <p><code>EqualTo</code></p>
+    EqualTo,
+    /// <p><code>NotEqualTo</code></p>
+    NotEqualTo,
+    /// <p><code>Exists</code></p>
+    Exists,
+    /// <p><code>DoesNotExist</code></p>
+    DoesNotExist,
+    /// <p><code>In</code></p>
+    In,
+    /// <p><code>NotIn</code></p>
+    NotIn,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Comparator {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Comparator::LessThan => "LessThan",
+            Comparator::LessThanOrEqualTo => "LessThanOrEqualTo",
+            Comparator::GreaterThan => "GreaterThan",
+            Comparator::GreaterThanOrEqualTo => "GreaterThanOrEqualTo",
+            Comparator::EqualTo => "EqualTo",
+            Comparator::NotEqualTo => "NotEqualTo",
+            Comparator::Exists => "Exists",
+            Comparator::DoesNotExist => "DoesNotExist",
+            Comparator::In => "In",
+            Comparator::NotIn => "NotIn",
+            Comparator::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Comparator {
+    fn from(value: &'a str) -> Comparator {
+        match value {
+            "LessThan" => Comparator::LessThan,
+            "LessThanOrEqualTo" => Comparator::LessThanOrEqualTo,
+            "GreaterThan" => Comparator::GreaterThan,
+            "GreaterThanOrEqualTo" => Comparator::GreaterThanOrEqualTo,
+            "EqualTo" => Comparator::EqualTo,
+            "NotEqualTo" => Comparator::NotEqualTo,
+            "Exists" => Comparator::Exists,
+            "DoesNotExist" => Comparator::DoesNotExist,
+            "In" => Comparator::In,
+            "NotIn" => Comparator::NotIn,
+            _ => Comparator::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Comparator {
+    fn from(value: String) -> Comparator {
+        Comparator::from(value.as_str())
+    }
+}
+
+impl From<Comparator> for String {
+    fn from(value: Comparator) -> String {
+        match value {
+            Comparator::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Comparator {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Comparator {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Comparator, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Comparator::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateAdditionalAssignmentsForHITRequest {
     /// <p>The ID of the HIT to extend.</p>
@@ -172,6 +507,57 @@ pub struct CreateAdditionalAssignmentsForHITRequest {
     pub unique_request_token: Option<String>,
 }
 
+impl CreateAdditionalAssignmentsForHITRequest {
+    /// Returns a builder for `CreateAdditionalAssignmentsForHITRequest`, checking its required members when built.
+    pub fn builder() -> CreateAdditionalAssignmentsForHITRequestBuilder {
+        CreateAdditionalAssignmentsForHITRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAdditionalAssignmentsForHITRequest`](struct.CreateAdditionalAssignmentsForHITRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAdditionalAssignmentsForHITRequestBuilder {
+    hit_id: Option<String>,
+    number_of_additional_assignments: Option<i64>,
+    unique_request_token: Option<String>,
+}
+
+impl CreateAdditionalAssignmentsForHITRequestBuilder {
+    /// Sets the [`hit_id`](struct.CreateAdditionalAssignmentsForHITRequest.html#structfield.hit_id) member.
+    pub fn hit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_additional_assignments`](struct.CreateAdditionalAssignmentsForHITRequest.html#structfield.number_of_additional_assignments) member.
+    pub fn number_of_additional_assignments<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_additional_assignments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unique_request_token`](struct.CreateAdditionalAssignmentsForHITRequest.html#structfield.unique_request_token) member.
+    pub fn unique_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.unique_request_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAdditionalAssignmentsForHITRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateAdditionalAssignmentsForHITRequest, ::rusoto_core::BuildError>
+    {
+        Ok(CreateAdditionalAssignmentsForHITRequest {
+            hit_id: self
+                .hit_id
+                .ok_or(::rusoto_core::BuildError::missing("hit_id"))?,
+            number_of_additional_assignments: self.number_of_additional_assignments.ok_or(
+                ::rusoto_core::BuildError::missing("number_of_additional_assignments"),
+            )?,
+            unique_request_token: self.unique_request_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateAdditionalAssignmentsForHITResponse {}
@@ -239,6 +625,167 @@ pub struct CreateHITRequest {
     pub unique_request_token: Option<String>,
 }
 
+impl CreateHITRequest {
+    /// Returns a builder for `CreateHITRequest`, checking its required members when built.
+    pub fn builder() -> CreateHITRequestBuilder {
+        CreateHITRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateHITRequest`](struct.CreateHITRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateHITRequestBuilder {
+    assignment_duration_in_seconds: Option<i64>,
+    assignment_review_policy: Option<ReviewPolicy>,
+    auto_approval_delay_in_seconds: Option<i64>,
+    description: Option<String>,
+    hit_layout_id: Option<String>,
+    hit_layout_parameters: Option<Vec<HITLayoutParameter>>,
+    hit_review_policy: Option<ReviewPolicy>,
+    keywords: Option<String>,
+    lifetime_in_seconds: Option<i64>,
+    max_assignments: Option<i64>,
+    qualification_requirements: Option<Vec<QualificationRequirement>>,
+    question: Option<String>,
+    requester_annotation: Option<String>,
+    reward: Option<String>,
+    title: Option<String>,
+    unique_request_token: Option<String>,
+}
+
+impl CreateHITRequestBuilder {
+    /// Sets the [`assignment_duration_in_seconds`](struct.CreateHITRequest.html#structfield.assignment_duration_in_seconds) member.
+    pub fn assignment_duration_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.assignment_duration_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`assignment_review_policy`](struct.CreateHITRequest.html#structfield.assignment_review_policy) member.
+    pub fn assignment_review_policy<T: Into<ReviewPolicy>>(mut self, value: T) -> Self {
+        self.assignment_review_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_approval_delay_in_seconds`](struct.CreateHITRequest.html#structfield.auto_approval_delay_in_seconds) member.
+    pub fn auto_approval_delay_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.auto_approval_delay_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateHITRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hit_layout_id`](struct.CreateHITRequest.html#structfield.hit_layout_id) member.
+    pub fn hit_layout_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_layout_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hit_layout_parameters`](struct.CreateHITRequest.html#structfield.hit_layout_parameters) member.
+    pub fn hit_layout_parameters<T: Into<Vec<HITLayoutParameter>>>(mut self, value: T) -> Self {
+        self.hit_layout_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hit_review_policy`](struct.CreateHITRequest.html#structfield.hit_review_policy) member.
+    pub fn hit_review_policy<T: Into<ReviewPolicy>>(mut self, value: T) -> Self {
+        self.hit_review_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`keywords`](struct.CreateHITRequest.html#structfield.keywords) member.
+    pub fn keywords<T: Into<String>>(mut self, value: T) -> Self {
+        self.keywords = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifetime_in_seconds`](struct.CreateHITRequest.html#structfield.lifetime_in_seconds) member.
+    pub fn lifetime_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.lifetime_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_assignments`](struct.CreateHITRequest.html#structfield.max_assignments) member.
+    pub fn max_assignments<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_assignments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualification_requirements`](struct.CreateHITRequest.html#structfield.qualification_requirements) member.
+    pub fn qualification_requirements<T: Into<Vec<QualificationRequirement>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.qualification_requirements = Some(value.into());
+        self
+    }
+
+    /// Sets the [`question`](struct.CreateHITRequest.html#structfield.question) member.
+    pub fn question<T: Into<String>>(mut self, value: T) -> Self {
+        self.question = Some(value.into());
+        self
+    }
+
+    /// Sets the [`requester_annotation`](struct.CreateHITRequest.html#structfield.requester_annotation) member.
+    pub fn requester_annotation<T: Into<String>>(mut self, value: T) -> Self {
+        self.requester_annotation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reward`](struct.CreateHITRequest.html#structfield.reward) member.
+    pub fn reward<T: Into<String>>(mut self, value: T) -> Self {
+        self.reward = Some(value.into());
+        self
+    }
+
+    /// Sets the [`title`](struct.CreateHITRequest.html#structfield.title) member.
+    pub fn title<T: Into<String>>(mut self, value: T) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unique_request_token`](struct.CreateHITRequest.html#structfield.unique_request_token) member.
+    pub fn unique_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.unique_request_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateHITRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateHITRequest, ::rusoto_core::BuildError> {
+        Ok(CreateHITRequest {
+            assignment_duration_in_seconds: self.assignment_duration_in_seconds.ok_or(
+                ::rusoto_core::BuildError::missing("assignment_duration_in_seconds"),
+            )?,
+            assignment_review_policy: self.assignment_review_policy,
+            auto_approval_delay_in_seconds: self.auto_approval_delay_in_seconds,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            hit_layout_id: self.hit_layout_id,
+            hit_layout_parameters: self.hit_layout_parameters,
+            hit_review_policy: self.hit_review_policy,
+            keywords: self.keywords,
+            lifetime_in_seconds: self
+                .lifetime_in_seconds
+                .ok_or(::rusoto_core::BuildError::missing("lifetime_in_seconds"))?,
+            max_assignments: self.max_assignments,
+            qualification_requirements: self.qualification_requirements,
+            question: self.question,
+            requester_annotation: self.requester_annotation,
+            reward: self
+                .reward
+                .ok_or(::rusoto_core::BuildError::missing("reward"))?,
+            title: self
+                .title
+                .ok_or(::rusoto_core::BuildError::missing("title"))?,
+            unique_request_token: self.unique_request_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateHITResponse {
@@ -276,6 +823,93 @@ pub struct CreateHITTypeRequest {
     pub title: String,
 }
 
+impl CreateHITTypeRequest {
+    /// Returns a builder for `CreateHITTypeRequest`, checking its required members when built.
+    pub fn builder() -> CreateHITTypeRequestBuilder {
+        CreateHITTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateHITTypeRequest`](struct.CreateHITTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateHITTypeRequestBuilder {
+    assignment_duration_in_seconds: Option<i64>,
+    auto_approval_delay_in_seconds: Option<i64>,
+    description: Option<String>,
+    keywords: Option<String>,
+    qualification_requirements: Option<Vec<QualificationRequirement>>,
+    reward: Option<String>,
+    title: Option<String>,
+}
+
+impl CreateHITTypeRequestBuilder {
+    /// Sets the [`assignment_duration_in_seconds`](struct.CreateHITTypeRequest.html#structfield.assignment_duration_in_seconds) member.
+    pub fn assignment_duration_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.assignment_duration_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_approval_delay_in_seconds`](struct.CreateHITTypeRequest.html#structfield.auto_approval_delay_in_seconds) member.
+    pub fn auto_approval_delay_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.auto_approval_delay_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateHITTypeRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`keywords`](struct.CreateHITTypeRequest.html#structfield.keywords) member.
+    pub fn keywords<T: Into<String>>(mut self, value: T) -> Self {
+        self.keywords = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualification_requirements`](struct.CreateHITTypeRequest.html#structfield.qualification_requirements) member.
+    pub fn qualification_requirements<T: Into<Vec<QualificationRequirement>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.qualification_requirements = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reward`](struct.CreateHITTypeRequest.html#structfield.reward) member.
+    pub fn reward<T: Into<String>>(mut self, value: T) -> Self {
+        self.reward = Some(value.into());
+        self
+    }
+
+    /// Sets the [`title`](struct.CreateHITTypeRequest.html#structfield.title) member.
+    pub fn title<T: Into<String>>(mut self, value: T) -> Self {
+        self.title = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateHITTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateHITTypeRequest, ::rusoto_core::BuildError> {
+        Ok(CreateHITTypeRequest {
+            assignment_duration_in_seconds: self.assignment_duration_in_seconds.ok_or(
+                ::rusoto_core::BuildError::missing("assignment_duration_in_seconds"),
+            )?,
+            auto_approval_delay_in_seconds: self.auto_approval_delay_in_seconds,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            keywords: self.keywords,
+            qualification_requirements: self.qualification_requirements,
+            reward: self
+                .reward
+                .ok_or(::rusoto_core::BuildError::missing("reward"))?,
+            title: self
+                .title
+                .ok_or(::rusoto_core::BuildError::missing("title"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateHITTypeResponse {
@@ -327,6 +961,112 @@ pub struct CreateHITWithHITTypeRequest {
     pub unique_request_token: Option<String>,
 }
 
+impl CreateHITWithHITTypeRequest {
+    /// Returns a builder for `CreateHITWithHITTypeRequest`, checking its required members when built.
+    pub fn builder() -> CreateHITWithHITTypeRequestBuilder {
+        CreateHITWithHITTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateHITWithHITTypeRequest`](struct.CreateHITWithHITTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateHITWithHITTypeRequestBuilder {
+    assignment_review_policy: Option<ReviewPolicy>,
+    hit_layout_id: Option<String>,
+    hit_layout_parameters: Option<Vec<HITLayoutParameter>>,
+    hit_review_policy: Option<ReviewPolicy>,
+    hit_type_id: Option<String>,
+    lifetime_in_seconds: Option<i64>,
+    max_assignments: Option<i64>,
+    question: Option<String>,
+    requester_annotation: Option<String>,
+    unique_request_token: Option<String>,
+}
+
+impl CreateHITWithHITTypeRequestBuilder {
+    /// Sets the [`assignment_review_policy`](struct.CreateHITWithHITTypeRequest.html#structfield.assignment_review_policy) member.
+    pub fn assignment_review_policy<T: Into<ReviewPolicy>>(mut self, value: T) -> Self {
+        self.assignment_review_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hit_layout_id`](struct.CreateHITWithHITTypeRequest.html#structfield.hit_layout_id) member.
+    pub fn hit_layout_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_layout_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hit_layout_parameters`](struct.CreateHITWithHITTypeRequest.html#structfield.hit_layout_parameters) member.
+    pub fn hit_layout_parameters<T: Into<Vec<HITLayoutParameter>>>(mut self, value: T) -> Self {
+        self.hit_layout_parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hit_review_policy`](struct.CreateHITWithHITTypeRequest.html#structfield.hit_review_policy) member.
+    pub fn hit_review_policy<T: Into<ReviewPolicy>>(mut self, value: T) -> Self {
+        self.hit_review_policy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hit_type_id`](struct.CreateHITWithHITTypeRequest.html#structfield.hit_type_id) member.
+    pub fn hit_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_type_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifetime_in_seconds`](struct.CreateHITWithHITTypeRequest.html#structfield.lifetime_in_seconds) member.
+    pub fn lifetime_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.lifetime_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_assignments`](struct.CreateHITWithHITTypeRequest.html#structfield.max_assignments) member.
+    pub fn max_assignments<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_assignments = Some(value.into());
+        self
+    }
+
+    /// Sets the [`question`](struct.CreateHITWithHITTypeRequest.html#structfield.question) member.
+    pub fn question<T: Into<String>>(mut self, value: T) -> Self {
+        self.question = Some(value.into());
+        self
+    }
+
+    /// Sets the [`requester_annotation`](struct.CreateHITWithHITTypeRequest.html#structfield.requester_annotation) member.
+    pub fn requester_annotation<T: Into<String>>(mut self, value: T) -> Self {
+        self.requester_annotation = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unique_request_token`](struct.CreateHITWithHITTypeRequest.html#structfield.unique_request_token) member.
+    pub fn unique_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.unique_request_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateHITWithHITTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateHITWithHITTypeRequest, ::rusoto_core::BuildError> {
+        Ok(CreateHITWithHITTypeRequest {
+            assignment_review_policy: self.assignment_review_policy,
+            hit_layout_id: self.hit_layout_id,
+            hit_layout_parameters: self.hit_layout_parameters,
+            hit_review_policy: self.hit_review_policy,
+            hit_type_id: self
+                .hit_type_id
+                .ok_or(::rusoto_core::BuildError::missing("hit_type_id"))?,
+            lifetime_in_seconds: self
+                .lifetime_in_seconds
+                .ok_or(::rusoto_core::BuildError::missing("lifetime_in_seconds"))?,
+            max_assignments: self.max_assignments,
+            question: self.question,
+            requester_annotation: self.requester_annotation,
+            unique_request_token: self.unique_request_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateHITWithHITTypeResponse {
@@ -336,7 +1076,7 @@ pub struct CreateHITWithHITTypeResponse {
     pub hit: Option<HIT>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateQualificationTypeRequest {
     /// <p>The answers to the Qualification test specified in the Test parameter, in the form of an AnswerKey data structure.</p> <p>Constraints: Must not be longer than 65535 bytes.</p> <p>Constraints: None. If not specified, you must process Qualification requests manually.</p>
     #[serde(rename = "AnswerKey")]
@@ -362,7 +1102,7 @@ pub struct CreateQualificationTypeRequest {
     pub name: String,
     /// <p>The initial status of the Qualification type.</p> <p>Constraints: Valid values are: Active | Inactive</p>
     #[serde(rename = "QualificationTypeStatus")]
-    pub qualification_type_status: String,
+    pub qualification_type_status: QualificationTypeStatus,
     /// <p>The number of seconds that a Worker must wait after requesting a Qualification of the Qualification type before the worker can retry the Qualification request.</p> <p>Constraints: None. If not specified, retries are disabled and Workers can request a Qualification of this type only once, even if the Worker has not been granted the Qualification. It is not possible to disable retries for a Qualification type after it has been created with retries enabled. If you want to disable retries, you must delete existing retry-enabled Qualification type and then create a new Qualification type with retries disabled.</p>
     #[serde(rename = "RetryDelayInSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -377,6 +1117,114 @@ pub struct CreateQualificationTypeRequest {
     pub test_duration_in_seconds: Option<i64>,
 }
 
+impl CreateQualificationTypeRequest {
+    /// Returns a builder for `CreateQualificationTypeRequest`, checking its required members when built.
+    pub fn builder() -> CreateQualificationTypeRequestBuilder {
+        CreateQualificationTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateQualificationTypeRequest`](struct.CreateQualificationTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateQualificationTypeRequestBuilder {
+    answer_key: Option<String>,
+    auto_granted: Option<bool>,
+    auto_granted_value: Option<i64>,
+    description: Option<String>,
+    keywords: Option<String>,
+    name: Option<String>,
+    qualification_type_status: Option<QualificationTypeStatus>,
+    retry_delay_in_seconds: Option<i64>,
+    test: Option<String>,
+    test_duration_in_seconds: Option<i64>,
+}
+
+impl CreateQualificationTypeRequestBuilder {
+    /// Sets the [`answer_key`](struct.CreateQualificationTypeRequest.html#structfield.answer_key) member.
+    pub fn answer_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.answer_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_granted`](struct.CreateQualificationTypeRequest.html#structfield.auto_granted) member.
+    pub fn auto_granted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_granted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_granted_value`](struct.CreateQualificationTypeRequest.html#structfield.auto_granted_value) member.
+    pub fn auto_granted_value<T: Into<i64>>(mut self, value: T) -> Self {
+        self.auto_granted_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateQualificationTypeRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`keywords`](struct.CreateQualificationTypeRequest.html#structfield.keywords) member.
+    pub fn keywords<T: Into<String>>(mut self, value: T) -> Self {
+        self.keywords = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateQualificationTypeRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualification_type_status`](struct.CreateQualificationTypeRequest.html#structfield.qualification_type_status) member.
+    pub fn qualification_type_status<T: Into<QualificationTypeStatus>>(mut self, value: T) -> Self {
+        self.qualification_type_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retry_delay_in_seconds`](struct.CreateQualificationTypeRequest.html#structfield.retry_delay_in_seconds) member.
+    pub fn retry_delay_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.retry_delay_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`test`](struct.CreateQualificationTypeRequest.html#structfield.test) member.
+    pub fn test<T: Into<String>>(mut self, value: T) -> Self {
+        self.test = Some(value.into());
+        self
+    }
+
+    /// Sets the [`test_duration_in_seconds`](struct.CreateQualificationTypeRequest.html#structfield.test_duration_in_seconds) member.
+    pub fn test_duration_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.test_duration_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateQualificationTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateQualificationTypeRequest, ::rusoto_core::BuildError> {
+        Ok(CreateQualificationTypeRequest {
+            answer_key: self.answer_key,
+            auto_granted: self.auto_granted,
+            auto_granted_value: self.auto_granted_value,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            keywords: self.keywords,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            qualification_type_status: self.qualification_type_status.ok_or(
+                ::rusoto_core::BuildError::missing("qualification_type_status"),
+            )?,
+            retry_delay_in_seconds: self.retry_delay_in_seconds,
+            test: self.test,
+            test_duration_in_seconds: self.test_duration_in_seconds,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateQualificationTypeResponse {
@@ -396,6 +1244,48 @@ pub struct CreateWorkerBlockRequest {
     pub worker_id: String,
 }
 
+impl CreateWorkerBlockRequest {
+    /// Returns a builder for `CreateWorkerBlockRequest`, checking its required members when built.
+    pub fn builder() -> CreateWorkerBlockRequestBuilder {
+        CreateWorkerBlockRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateWorkerBlockRequest`](struct.CreateWorkerBlockRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateWorkerBlockRequestBuilder {
+    reason: Option<String>,
+    worker_id: Option<String>,
+}
+
+impl CreateWorkerBlockRequestBuilder {
+    /// Sets the [`reason`](struct.CreateWorkerBlockRequest.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_id`](struct.CreateWorkerBlockRequest.html#structfield.worker_id) member.
+    pub fn worker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.worker_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateWorkerBlockRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateWorkerBlockRequest, ::rusoto_core::BuildError> {
+        Ok(CreateWorkerBlockRequest {
+            reason: self
+                .reason
+                .ok_or(::rusoto_core::BuildError::missing("reason"))?,
+            worker_id: self
+                .worker_id
+                .ok_or(::rusoto_core::BuildError::missing("worker_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateWorkerBlockResponse {}
@@ -407,8 +1297,38 @@ pub struct DeleteHITRequest {
     pub hit_id: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl DeleteHITRequest {
+    /// Returns a builder for `DeleteHITRequest`, checking its required members when built.
+    pub fn builder() -> DeleteHITRequestBuilder {
+        DeleteHITRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteHITRequest`](struct.DeleteHITRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteHITRequestBuilder {
+    hit_id: Option<String>,
+}
+
+impl DeleteHITRequestBuilder {
+    /// Sets the [`hit_id`](struct.DeleteHITRequest.html#structfield.hit_id) member.
+    pub fn hit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteHITRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteHITRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteHITRequest {
+            hit_id: self
+                .hit_id
+                .ok_or(::rusoto_core::BuildError::missing("hit_id"))?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
 pub struct DeleteHITResponse {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -418,6 +1338,38 @@ pub struct DeleteQualificationTypeRequest {
     pub qualification_type_id: String,
 }
 
+impl DeleteQualificationTypeRequest {
+    /// Returns a builder for `DeleteQualificationTypeRequest`, checking its required members when built.
+    pub fn builder() -> DeleteQualificationTypeRequestBuilder {
+        DeleteQualificationTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteQualificationTypeRequest`](struct.DeleteQualificationTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteQualificationTypeRequestBuilder {
+    qualification_type_id: Option<String>,
+}
+
+impl DeleteQualificationTypeRequestBuilder {
+    /// Sets the [`qualification_type_id`](struct.DeleteQualificationTypeRequest.html#structfield.qualification_type_id) member.
+    pub fn qualification_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualification_type_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteQualificationTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteQualificationTypeRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteQualificationTypeRequest {
+            qualification_type_id: self
+                .qualification_type_id
+                .ok_or(::rusoto_core::BuildError::missing("qualification_type_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteQualificationTypeResponse {}
@@ -433,6 +1385,46 @@ pub struct DeleteWorkerBlockRequest {
     pub worker_id: String,
 }
 
+impl DeleteWorkerBlockRequest {
+    /// Returns a builder for `DeleteWorkerBlockRequest`, checking its required members when built.
+    pub fn builder() -> DeleteWorkerBlockRequestBuilder {
+        DeleteWorkerBlockRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteWorkerBlockRequest`](struct.DeleteWorkerBlockRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteWorkerBlockRequestBuilder {
+    reason: Option<String>,
+    worker_id: Option<String>,
+}
+
+impl DeleteWorkerBlockRequestBuilder {
+    /// Sets the [`reason`](struct.DeleteWorkerBlockRequest.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_id`](struct.DeleteWorkerBlockRequest.html#structfield.worker_id) member.
+    pub fn worker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.worker_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteWorkerBlockRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteWorkerBlockRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteWorkerBlockRequest {
+            reason: self.reason,
+            worker_id: self
+                .worker_id
+                .ok_or(::rusoto_core::BuildError::missing("worker_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteWorkerBlockResponse {}
@@ -451,10 +1443,173 @@ pub struct DisassociateQualificationFromWorkerRequest {
     pub worker_id: String,
 }
 
+impl DisassociateQualificationFromWorkerRequest {
+    /// Returns a builder for `DisassociateQualificationFromWorkerRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateQualificationFromWorkerRequestBuilder {
+        DisassociateQualificationFromWorkerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateQualificationFromWorkerRequest`](struct.DisassociateQualificationFromWorkerRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateQualificationFromWorkerRequestBuilder {
+    qualification_type_id: Option<String>,
+    reason: Option<String>,
+    worker_id: Option<String>,
+}
+
+impl DisassociateQualificationFromWorkerRequestBuilder {
+    /// Sets the [`qualification_type_id`](struct.DisassociateQualificationFromWorkerRequest.html#structfield.qualification_type_id) member.
+    pub fn qualification_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualification_type_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reason`](struct.DisassociateQualificationFromWorkerRequest.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_id`](struct.DisassociateQualificationFromWorkerRequest.html#structfield.worker_id) member.
+    pub fn worker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.worker_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateQualificationFromWorkerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateQualificationFromWorkerRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DisassociateQualificationFromWorkerRequest {
+            qualification_type_id: self
+                .qualification_type_id
+                .ok_or(::rusoto_core::BuildError::missing("qualification_type_id"))?,
+            reason: self.reason,
+            worker_id: self
+                .worker_id
+                .ok_or(::rusoto_core::BuildError::missing("worker_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateQualificationFromWorkerResponse {}
 
+/// <p> The list of events that should cause notifications to be sent. Valid Values: AssignmentAccepted | AssignmentAbandoned | AssignmentReturned | AssignmentSubmitted | AssignmentRejected | AssignmentApproved | HITCreated | HITExtended | HITDisposed | HITReviewable | HITExpired | Ping. The Ping event is only valid for the SendTestEventNotification operation. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventType {
+    /// <p><code>AssignmentAccepted</code></p>
+    AssignmentAccepted,
+    /// <p><code>AssignmentAbandoned</code></p>
+    AssignmentAbandoned,
+    /// <p><code>AssignmentReturned</code></p>
+    AssignmentReturned,
+    /// <p><code>AssignmentSubmitted</code></p>
+    AssignmentSubmitted,
+    /// <p><code>AssignmentRejected</code></p>
+    AssignmentRejected,
+    /// <p><code>AssignmentApproved</code></p>
+    AssignmentApproved,
+    /// <p><code>HITCreated</code></p>
+    HITCreated,
+    /// <p><code>HITExpired</code></p>
+    HITExpired,
+    /// <p><code>HITReviewable</code></p>
+    HITReviewable,
+    /// <p><code>HITExtended</code></p>
+    HITExtended,
+    /// <p><code>HITDisposed</code></p>
+    HITDisposed,
+    /// <p><code>Ping</code></p>
+    Ping,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EventType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EventType::AssignmentAccepted => "AssignmentAccepted",
+            EventType::AssignmentAbandoned => "AssignmentAbandoned",
+            EventType::AssignmentReturned => "AssignmentReturned",
+            EventType::AssignmentSubmitted => "AssignmentSubmitted",
+            EventType::AssignmentRejected => "AssignmentRejected",
+            EventType::AssignmentApproved => "AssignmentApproved",
+            EventType::HITCreated => "HITCreated",
+            EventType::HITExpired => "HITExpired",
+            EventType::HITReviewable => "HITReviewable",
+            EventType::HITExtended => "HITExtended",
+            EventType::HITDisposed => "HITDisposed",
+            EventType::Ping => "Ping",
+            EventType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for EventType {
+    fn from(value: &'a str) -> EventType {
+        match value {
+            "AssignmentAccepted" => EventType::AssignmentAccepted,
+            "AssignmentAbandoned" => EventType::AssignmentAbandoned,
+            "AssignmentReturned" => EventType::AssignmentReturned,
+            "AssignmentSubmitted" => EventType::AssignmentSubmitted,
+            "AssignmentRejected" => EventType::AssignmentRejected,
+            "AssignmentApproved" => EventType::AssignmentApproved,
+            "HITCreated" => EventType::HITCreated,
+            "HITExpired" => EventType::HITExpired,
+            "HITReviewable" => EventType::HITReviewable,
+            "HITExtended" => EventType::HITExtended,
+            "HITDisposed" => EventType::HITDisposed,
+            "Ping" => EventType::Ping,
+            _ => EventType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EventType {
+    fn from(value: String) -> EventType {
+        EventType::from(value.as_str())
+    }
+}
+
+impl From<EventType> for String {
+    fn from(value: EventType) -> String {
+        match value {
+            EventType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EventType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EventType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EventType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EventType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetAccountBalanceRequest {}
 
@@ -476,6 +1631,36 @@ pub struct GetAssignmentRequest {
     pub assignment_id: String,
 }
 
+impl GetAssignmentRequest {
+    /// Returns a builder for `GetAssignmentRequest`, checking its required members when built.
+    pub fn builder() -> GetAssignmentRequestBuilder {
+        GetAssignmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAssignmentRequest`](struct.GetAssignmentRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAssignmentRequestBuilder {
+    assignment_id: Option<String>,
+}
+
+impl GetAssignmentRequestBuilder {
+    /// Sets the [`assignment_id`](struct.GetAssignmentRequest.html#structfield.assignment_id) member.
+    pub fn assignment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.assignment_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAssignmentRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetAssignmentRequest, ::rusoto_core::BuildError> {
+        Ok(GetAssignmentRequest {
+            assignment_id: self
+                .assignment_id
+                .ok_or(::rusoto_core::BuildError::missing("assignment_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAssignmentResponse {
@@ -499,6 +1684,48 @@ pub struct GetFileUploadURLRequest {
     pub question_identifier: String,
 }
 
+impl GetFileUploadURLRequest {
+    /// Returns a builder for `GetFileUploadURLRequest`, checking its required members when built.
+    pub fn builder() -> GetFileUploadURLRequestBuilder {
+        GetFileUploadURLRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFileUploadURLRequest`](struct.GetFileUploadURLRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFileUploadURLRequestBuilder {
+    assignment_id: Option<String>,
+    question_identifier: Option<String>,
+}
+
+impl GetFileUploadURLRequestBuilder {
+    /// Sets the [`assignment_id`](struct.GetFileUploadURLRequest.html#structfield.assignment_id) member.
+    pub fn assignment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.assignment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`question_identifier`](struct.GetFileUploadURLRequest.html#structfield.question_identifier) member.
+    pub fn question_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.question_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFileUploadURLRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetFileUploadURLRequest, ::rusoto_core::BuildError> {
+        Ok(GetFileUploadURLRequest {
+            assignment_id: self
+                .assignment_id
+                .ok_or(::rusoto_core::BuildError::missing("assignment_id"))?,
+            question_identifier: self
+                .question_identifier
+                .ok_or(::rusoto_core::BuildError::missing("question_identifier"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFileUploadURLResponse {
@@ -515,6 +1742,36 @@ pub struct GetHITRequest {
     pub hit_id: String,
 }
 
+impl GetHITRequest {
+    /// Returns a builder for `GetHITRequest`, checking its required members when built.
+    pub fn builder() -> GetHITRequestBuilder {
+        GetHITRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetHITRequest`](struct.GetHITRequest.html).
+#[derive(Default, Debug)]
+pub struct GetHITRequestBuilder {
+    hit_id: Option<String>,
+}
+
+impl GetHITRequestBuilder {
+    /// Sets the [`hit_id`](struct.GetHITRequest.html#structfield.hit_id) member.
+    pub fn hit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetHITRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetHITRequest, ::rusoto_core::BuildError> {
+        Ok(GetHITRequest {
+            hit_id: self
+                .hit_id
+                .ok_or(::rusoto_core::BuildError::missing("hit_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetHITResponse {
@@ -534,6 +1791,48 @@ pub struct GetQualificationScoreRequest {
     pub worker_id: String,
 }
 
+impl GetQualificationScoreRequest {
+    /// Returns a builder for `GetQualificationScoreRequest`, checking its required members when built.
+    pub fn builder() -> GetQualificationScoreRequestBuilder {
+        GetQualificationScoreRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetQualificationScoreRequest`](struct.GetQualificationScoreRequest.html).
+#[derive(Default, Debug)]
+pub struct GetQualificationScoreRequestBuilder {
+    qualification_type_id: Option<String>,
+    worker_id: Option<String>,
+}
+
+impl GetQualificationScoreRequestBuilder {
+    /// Sets the [`qualification_type_id`](struct.GetQualificationScoreRequest.html#structfield.qualification_type_id) member.
+    pub fn qualification_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualification_type_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_id`](struct.GetQualificationScoreRequest.html#structfield.worker_id) member.
+    pub fn worker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.worker_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetQualificationScoreRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetQualificationScoreRequest, ::rusoto_core::BuildError> {
+        Ok(GetQualificationScoreRequest {
+            qualification_type_id: self
+                .qualification_type_id
+                .ok_or(::rusoto_core::BuildError::missing("qualification_type_id"))?,
+            worker_id: self
+                .worker_id
+                .ok_or(::rusoto_core::BuildError::missing("worker_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetQualificationScoreResponse {
@@ -550,6 +1849,38 @@ pub struct GetQualificationTypeRequest {
     pub qualification_type_id: String,
 }
 
+impl GetQualificationTypeRequest {
+    /// Returns a builder for `GetQualificationTypeRequest`, checking its required members when built.
+    pub fn builder() -> GetQualificationTypeRequestBuilder {
+        GetQualificationTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetQualificationTypeRequest`](struct.GetQualificationTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct GetQualificationTypeRequestBuilder {
+    qualification_type_id: Option<String>,
+}
+
+impl GetQualificationTypeRequestBuilder {
+    /// Sets the [`qualification_type_id`](struct.GetQualificationTypeRequest.html#structfield.qualification_type_id) member.
+    pub fn qualification_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualification_type_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetQualificationTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetQualificationTypeRequest, ::rusoto_core::BuildError> {
+        Ok(GetQualificationTypeRequest {
+            qualification_type_id: self
+                .qualification_type_id
+                .ok_or(::rusoto_core::BuildError::missing("qualification_type_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetQualificationTypeResponse {
@@ -573,16 +1904,18 @@ pub struct HIT {
     pub auto_approval_delay_in_seconds: Option<i64>,
     /// <p> The date and time the HIT was created.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p> A general description of the HIT.</p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// <p>The date and time the HIT expires.</p>
     #[serde(rename = "Expiration")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration: Option<f64>,
+    pub expiration: Option<EpochTimestamp>,
     /// <p> The ID of the HIT Group of this HIT.</p>
     #[serde(rename = "HITGroupId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -598,11 +1931,11 @@ pub struct HIT {
     /// <p> Indicates the review status of the HIT. Valid Values are NotReviewed | MarkedForReview | ReviewedAppropriate | ReviewedInappropriate.</p>
     #[serde(rename = "HITReviewStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hit_review_status: Option<String>,
+    pub hit_review_status: Option<HITReviewStatus>,
     /// <p>The status of the HIT and its assignments. Valid Values are Assignable | Unassignable | Reviewable | Reviewing | Disposed. </p>
     #[serde(rename = "HITStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub hit_status: Option<String>,
+    pub hit_status: Option<HITStatus>,
     /// <p>The ID of the HIT type of this HIT</p>
     #[serde(rename = "HITTypeId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -648,6 +1981,82 @@ pub struct HIT {
     pub title: Option<String>,
 }
 
+/// <p> Setting this attribute prevents Workers whose Qualifications do not meet this QualificationRequirement from taking the specified action. Valid arguments include "Accept" (Worker cannot accept the HIT, but can preview the HIT and see it in their search results), "PreviewAndAccept" (Worker cannot accept or preview the HIT, but can see the HIT in their search results), and "DiscoverPreviewAndAccept" (Worker cannot accept, preview, or see the HIT in their search results). It's possible for you to create a HIT with multiple QualificationRequirements (which can have different values for the ActionGuarded attribute). In this case, the Worker is only permitted to perform an action when they have met all QualificationRequirements guarding the action. The actions in the order of least restrictive to most restrictive are Discover, Preview and Accept. For example, if a Worker meets all QualificationRequirements that are set to DiscoverPreviewAndAccept, but do not meet all requirements that are set with PreviewAndAccept, then the Worker will be able to Discover, i.e. see the HIT in their search result, but will not be able to Preview or Accept the HIT. ActionsGuarded should not be used in combination with the <code>RequiredToPreview</code> field. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HITAccessActions {
+    /// <p><code>Accept</code></p>
+    Accept,
+    /// <p><code>PreviewAndAccept</code></p>
+    PreviewAndAccept,
+    /// <p><code>DiscoverPreviewAndAccept</code></p>
+    DiscoverPreviewAndAccept,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HITAccessActions {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HITAccessActions::Accept => "Accept",
+            HITAccessActions::PreviewAndAccept => "PreviewAndAccept",
+            HITAccessActions::DiscoverPreviewAndAccept => "DiscoverPreviewAndAccept",
+            HITAccessActions::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HITAccessActions {
+    fn from(value: &'a str) -> HITAccessActions {
+        match value {
+            "Accept" => HITAccessActions::Accept,
+            "PreviewAndAccept" => HITAccessActions::PreviewAndAccept,
+            "DiscoverPreviewAndAccept" => HITAccessActions::DiscoverPreviewAndAccept,
+            _ => HITAccessActions::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HITAccessActions {
+    fn from(value: String) -> HITAccessActions {
+        HITAccessActions::from(value.as_str())
+    }
+}
+
+impl From<HITAccessActions> for String {
+    fn from(value: HITAccessActions) -> String {
+        match value {
+            HITAccessActions::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HITAccessActions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HITAccessActions {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HITAccessActions {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HITAccessActions, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HITAccessActions::from(value))
+    }
+}
+
 /// <p> The HITLayoutParameter data structure defines parameter values used with a HITLayout. A HITLayout is a reusable Amazon Mechanical Turk project template used to provide Human Intelligence Task (HIT) question data for CreateHIT. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct HITLayoutParameter {
@@ -659,12 +2068,176 @@ pub struct HITLayoutParameter {
     pub value: String,
 }
 
+/// <p> Indicates the review status of the HIT. Valid Values are NotReviewed | MarkedForReview | ReviewedAppropriate | ReviewedInappropriate.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HITReviewStatus {
+    /// <p><code>NotReviewed</code></p>
+    NotReviewed,
+    /// <p><code>MarkedForReview</code></p>
+    MarkedForReview,
+    /// <p><code>ReviewedAppropriate</code></p>
+    ReviewedAppropriate,
+    /// <p><code>ReviewedInappropriate</code></p>
+    ReviewedInappropriate,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HITReviewStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HITReviewStatus::NotReviewed => "NotReviewed",
+            HITReviewStatus::MarkedForReview => "MarkedForReview",
+            HITReviewStatus::ReviewedAppropriate => "ReviewedAppropriate",
+            HITReviewStatus::ReviewedInappropriate => "ReviewedInappropriate",
+            HITReviewStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HITReviewStatus {
+    fn from(value: &'a str) -> HITReviewStatus {
+        match value {
+            "NotReviewed" => HITReviewStatus::NotReviewed,
+            "MarkedForReview" => HITReviewStatus::MarkedForReview,
+            "ReviewedAppropriate" => HITReviewStatus::ReviewedAppropriate,
+            "ReviewedInappropriate" => HITReviewStatus::ReviewedInappropriate,
+            _ => HITReviewStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HITReviewStatus {
+    fn from(value: String) -> HITReviewStatus {
+        HITReviewStatus::from(value.as_str())
+    }
+}
+
+impl From<HITReviewStatus> for String {
+    fn from(value: HITReviewStatus) -> String {
+        match value {
+            HITReviewStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HITReviewStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HITReviewStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HITReviewStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HITReviewStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HITReviewStatus::from(value))
+    }
+}
+
+/// <p>The status of the HIT and its assignments. Valid Values are Assignable | Unassignable | Reviewable | Reviewing | Disposed. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HITStatus {
+    /// <p><code>Assignable</code></p>
+    Assignable,
+    /// <p><code>Unassignable</code></p>
+    Unassignable,
+    /// <p><code>Reviewable</code></p>
+    Reviewable,
+    /// <p><code>Reviewing</code></p>
+    Reviewing,
+    /// <p><code>Disposed</code></p>
+    Disposed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HITStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HITStatus::Assignable => "Assignable",
+            HITStatus::Unassignable => "Unassignable",
+            HITStatus::Reviewable => "Reviewable",
+            HITStatus::Reviewing => "Reviewing",
+            HITStatus::Disposed => "Disposed",
+            HITStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HITStatus {
+    fn from(value: &'a str) -> HITStatus {
+        match value {
+            "Assignable" => HITStatus::Assignable,
+            "Unassignable" => HITStatus::Unassignable,
+            "Reviewable" => HITStatus::Reviewable,
+            "Reviewing" => HITStatus::Reviewing,
+            "Disposed" => HITStatus::Disposed,
+            _ => HITStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HITStatus {
+    fn from(value: String) -> HITStatus {
+        HITStatus::from(value.as_str())
+    }
+}
+
+impl From<HITStatus> for String {
+    fn from(value: HITStatus) -> String {
+        match value {
+            HITStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HITStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HITStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HITStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HITStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HITStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListAssignmentsForHITRequest {
     /// <p>The status of the assignments to return: Submitted | Approved | Rejected</p>
     #[serde(rename = "AssignmentStatuses")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub assignment_statuses: Option<Vec<String>>,
+    pub assignment_statuses: Option<Vec<AssignmentStatus>>,
     /// <p>The ID of the HIT.</p>
     #[serde(rename = "HITId")]
     pub hit_id: String,
@@ -677,6 +2250,62 @@ pub struct ListAssignmentsForHITRequest {
     pub next_token: Option<String>,
 }
 
+impl ListAssignmentsForHITRequest {
+    /// Returns a builder for `ListAssignmentsForHITRequest`, checking its required members when built.
+    pub fn builder() -> ListAssignmentsForHITRequestBuilder {
+        ListAssignmentsForHITRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAssignmentsForHITRequest`](struct.ListAssignmentsForHITRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAssignmentsForHITRequestBuilder {
+    assignment_statuses: Option<Vec<AssignmentStatus>>,
+    hit_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAssignmentsForHITRequestBuilder {
+    /// Sets the [`assignment_statuses`](struct.ListAssignmentsForHITRequest.html#structfield.assignment_statuses) member.
+    pub fn assignment_statuses<T: Into<Vec<AssignmentStatus>>>(mut self, value: T) -> Self {
+        self.assignment_statuses = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hit_id`](struct.ListAssignmentsForHITRequest.html#structfield.hit_id) member.
+    pub fn hit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListAssignmentsForHITRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAssignmentsForHITRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAssignmentsForHITRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAssignmentsForHITRequest, ::rusoto_core::BuildError> {
+        Ok(ListAssignmentsForHITRequest {
+            assignment_statuses: self.assignment_statuses,
+            hit_id: self
+                .hit_id
+                .ok_or(::rusoto_core::BuildError::missing("hit_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAssignmentsForHITResponse {
@@ -712,14 +2341,68 @@ pub struct ListBonusPaymentsRequest {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ListBonusPaymentsResponse {
-    /// <p>A successful request to the ListBonusPayments operation returns a list of BonusPayment objects. </p>
-    #[serde(rename = "BonusPayments")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bonus_payments: Option<Vec<BonusPayment>>,
-    #[serde(rename = "NextToken")]
+impl ListBonusPaymentsRequest {
+    /// Returns a builder for `ListBonusPaymentsRequest`, checking its required members when built.
+    pub fn builder() -> ListBonusPaymentsRequestBuilder {
+        ListBonusPaymentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListBonusPaymentsRequest`](struct.ListBonusPaymentsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListBonusPaymentsRequestBuilder {
+    assignment_id: Option<String>,
+    hit_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListBonusPaymentsRequestBuilder {
+    /// Sets the [`assignment_id`](struct.ListBonusPaymentsRequest.html#structfield.assignment_id) member.
+    pub fn assignment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.assignment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hit_id`](struct.ListBonusPaymentsRequest.html#structfield.hit_id) member.
+    pub fn hit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListBonusPaymentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListBonusPaymentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListBonusPaymentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListBonusPaymentsRequest, ::rusoto_core::BuildError> {
+        Ok(ListBonusPaymentsRequest {
+            assignment_id: self.assignment_id,
+            hit_id: self.hit_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ListBonusPaymentsResponse {
+    /// <p>A successful request to the ListBonusPayments operation returns a list of BonusPayment objects. </p>
+    #[serde(rename = "BonusPayments")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bonus_payments: Option<Vec<BonusPayment>>,
+    #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_token: Option<String>,
     /// <p>The number of bonus payments on this page in the filtered results list, equivalent to the number of bonus payments being returned by this call. </p>
@@ -743,6 +2426,54 @@ pub struct ListHITsForQualificationTypeRequest {
     pub qualification_type_id: String,
 }
 
+impl ListHITsForQualificationTypeRequest {
+    /// Returns a builder for `ListHITsForQualificationTypeRequest`, checking its required members when built.
+    pub fn builder() -> ListHITsForQualificationTypeRequestBuilder {
+        ListHITsForQualificationTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListHITsForQualificationTypeRequest`](struct.ListHITsForQualificationTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct ListHITsForQualificationTypeRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    qualification_type_id: Option<String>,
+}
+
+impl ListHITsForQualificationTypeRequestBuilder {
+    /// Sets the [`max_results`](struct.ListHITsForQualificationTypeRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListHITsForQualificationTypeRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualification_type_id`](struct.ListHITsForQualificationTypeRequest.html#structfield.qualification_type_id) member.
+    pub fn qualification_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualification_type_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListHITsForQualificationTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListHITsForQualificationTypeRequest, ::rusoto_core::BuildError> {
+        Ok(ListHITsForQualificationTypeRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            qualification_type_id: self
+                .qualification_type_id
+                .ok_or(::rusoto_core::BuildError::missing("qualification_type_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListHITsForQualificationTypeResponse {
@@ -770,6 +2501,42 @@ pub struct ListHITsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListHITsRequest {
+    /// Returns a builder for `ListHITsRequest`, checking its required members when built.
+    pub fn builder() -> ListHITsRequestBuilder {
+        ListHITsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListHITsRequest`](struct.ListHITsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListHITsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListHITsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListHITsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListHITsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListHITsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListHITsRequest, ::rusoto_core::BuildError> {
+        Ok(ListHITsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListHITsResponse {
@@ -801,6 +2568,52 @@ pub struct ListQualificationRequestsRequest {
     pub qualification_type_id: Option<String>,
 }
 
+impl ListQualificationRequestsRequest {
+    /// Returns a builder for `ListQualificationRequestsRequest`, checking its required members when built.
+    pub fn builder() -> ListQualificationRequestsRequestBuilder {
+        ListQualificationRequestsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListQualificationRequestsRequest`](struct.ListQualificationRequestsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListQualificationRequestsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    qualification_type_id: Option<String>,
+}
+
+impl ListQualificationRequestsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListQualificationRequestsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListQualificationRequestsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualification_type_id`](struct.ListQualificationRequestsRequest.html#structfield.qualification_type_id) member.
+    pub fn qualification_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualification_type_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListQualificationRequestsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListQualificationRequestsRequest, ::rusoto_core::BuildError> {
+        Ok(ListQualificationRequestsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            qualification_type_id: self.qualification_type_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListQualificationRequestsResponse {
@@ -839,6 +2652,70 @@ pub struct ListQualificationTypesRequest {
     pub query: Option<String>,
 }
 
+impl ListQualificationTypesRequest {
+    /// Returns a builder for `ListQualificationTypesRequest`, checking its required members when built.
+    pub fn builder() -> ListQualificationTypesRequestBuilder {
+        ListQualificationTypesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListQualificationTypesRequest`](struct.ListQualificationTypesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListQualificationTypesRequestBuilder {
+    max_results: Option<i64>,
+    must_be_owned_by_caller: Option<bool>,
+    must_be_requestable: Option<bool>,
+    next_token: Option<String>,
+    query: Option<String>,
+}
+
+impl ListQualificationTypesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListQualificationTypesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`must_be_owned_by_caller`](struct.ListQualificationTypesRequest.html#structfield.must_be_owned_by_caller) member.
+    pub fn must_be_owned_by_caller<T: Into<bool>>(mut self, value: T) -> Self {
+        self.must_be_owned_by_caller = Some(value.into());
+        self
+    }
+
+    /// Sets the [`must_be_requestable`](struct.ListQualificationTypesRequest.html#structfield.must_be_requestable) member.
+    pub fn must_be_requestable<T: Into<bool>>(mut self, value: T) -> Self {
+        self.must_be_requestable = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListQualificationTypesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`query`](struct.ListQualificationTypesRequest.html#structfield.query) member.
+    pub fn query<T: Into<String>>(mut self, value: T) -> Self {
+        self.query = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListQualificationTypesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListQualificationTypesRequest, ::rusoto_core::BuildError> {
+        Ok(ListQualificationTypesRequest {
+            max_results: self.max_results,
+            must_be_owned_by_caller: self.must_be_owned_by_caller,
+            must_be_requestable: self
+                .must_be_requestable
+                .ok_or(::rusoto_core::BuildError::missing("must_be_requestable"))?,
+            next_token: self.next_token,
+            query: self.query,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListQualificationTypesResponse {
@@ -871,7 +2748,7 @@ pub struct ListReviewPolicyResultsForHITRequest {
     /// <p> The Policy Level(s) to retrieve review results for - HIT or Assignment. If omitted, the default behavior is to retrieve all data for both policy levels. For a list of all the described policies, see Review Policies. </p>
     #[serde(rename = "PolicyLevels")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub policy_levels: Option<Vec<String>>,
+    pub policy_levels: Option<Vec<ReviewPolicyLevel>>,
     /// <p> Specify if the operation should retrieve a list of the actions taken executing the Review Policies and their outcomes. </p>
     #[serde(rename = "RetrieveActions")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -882,6 +2759,79 @@ pub struct ListReviewPolicyResultsForHITRequest {
     pub retrieve_results: Option<bool>,
 }
 
+impl ListReviewPolicyResultsForHITRequest {
+    /// Returns a builder for `ListReviewPolicyResultsForHITRequest`, checking its required members when built.
+    pub fn builder() -> ListReviewPolicyResultsForHITRequestBuilder {
+        ListReviewPolicyResultsForHITRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListReviewPolicyResultsForHITRequest`](struct.ListReviewPolicyResultsForHITRequest.html).
+#[derive(Default, Debug)]
+pub struct ListReviewPolicyResultsForHITRequestBuilder {
+    hit_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    policy_levels: Option<Vec<ReviewPolicyLevel>>,
+    retrieve_actions: Option<bool>,
+    retrieve_results: Option<bool>,
+}
+
+impl ListReviewPolicyResultsForHITRequestBuilder {
+    /// Sets the [`hit_id`](struct.ListReviewPolicyResultsForHITRequest.html#structfield.hit_id) member.
+    pub fn hit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListReviewPolicyResultsForHITRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListReviewPolicyResultsForHITRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_levels`](struct.ListReviewPolicyResultsForHITRequest.html#structfield.policy_levels) member.
+    pub fn policy_levels<T: Into<Vec<ReviewPolicyLevel>>>(mut self, value: T) -> Self {
+        self.policy_levels = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retrieve_actions`](struct.ListReviewPolicyResultsForHITRequest.html#structfield.retrieve_actions) member.
+    pub fn retrieve_actions<T: Into<bool>>(mut self, value: T) -> Self {
+        self.retrieve_actions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retrieve_results`](struct.ListReviewPolicyResultsForHITRequest.html#structfield.retrieve_results) member.
+    pub fn retrieve_results<T: Into<bool>>(mut self, value: T) -> Self {
+        self.retrieve_results = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListReviewPolicyResultsForHITRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListReviewPolicyResultsForHITRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListReviewPolicyResultsForHITRequest {
+            hit_id: self
+                .hit_id
+                .ok_or(::rusoto_core::BuildError::missing("hit_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            policy_levels: self.policy_levels,
+            retrieve_actions: self.retrieve_actions,
+            retrieve_results: self.retrieve_results,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListReviewPolicyResultsForHITResponse {
@@ -927,7 +2877,61 @@ pub struct ListReviewableHITsRequest {
     /// <p> Can be either <code>Reviewable</code> or <code>Reviewing</code>. Reviewable is the default value. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ReviewableHITStatus>,
+}
+
+impl ListReviewableHITsRequest {
+    /// Returns a builder for `ListReviewableHITsRequest`, checking its required members when built.
+    pub fn builder() -> ListReviewableHITsRequestBuilder {
+        ListReviewableHITsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListReviewableHITsRequest`](struct.ListReviewableHITsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListReviewableHITsRequestBuilder {
+    hit_type_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    status: Option<ReviewableHITStatus>,
+}
+
+impl ListReviewableHITsRequestBuilder {
+    /// Sets the [`hit_type_id`](struct.ListReviewableHITsRequest.html#structfield.hit_type_id) member.
+    pub fn hit_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_type_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListReviewableHITsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListReviewableHITsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListReviewableHITsRequest.html#structfield.status) member.
+    pub fn status<T: Into<ReviewableHITStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListReviewableHITsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListReviewableHITsRequest, ::rusoto_core::BuildError> {
+        Ok(ListReviewableHITsRequest {
+            hit_type_id: self.hit_type_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            status: self.status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -957,6 +2961,44 @@ pub struct ListWorkerBlocksRequest {
     pub next_token: Option<String>,
 }
 
+impl ListWorkerBlocksRequest {
+    /// Returns a builder for `ListWorkerBlocksRequest`, checking its required members when built.
+    pub fn builder() -> ListWorkerBlocksRequestBuilder {
+        ListWorkerBlocksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListWorkerBlocksRequest`](struct.ListWorkerBlocksRequest.html).
+#[derive(Default, Debug)]
+pub struct ListWorkerBlocksRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListWorkerBlocksRequestBuilder {
+    /// Sets the [`max_results`](struct.ListWorkerBlocksRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListWorkerBlocksRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListWorkerBlocksRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListWorkerBlocksRequest, ::rusoto_core::BuildError> {
+        Ok(ListWorkerBlocksRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListWorkerBlocksResponse {
@@ -989,7 +3031,64 @@ pub struct ListWorkersWithQualificationTypeRequest {
     /// <p> The status of the Qualifications to return. Can be <code>Granted | Revoked</code>. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<QualificationStatus>,
+}
+
+impl ListWorkersWithQualificationTypeRequest {
+    /// Returns a builder for `ListWorkersWithQualificationTypeRequest`, checking its required members when built.
+    pub fn builder() -> ListWorkersWithQualificationTypeRequestBuilder {
+        ListWorkersWithQualificationTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListWorkersWithQualificationTypeRequest`](struct.ListWorkersWithQualificationTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct ListWorkersWithQualificationTypeRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    qualification_type_id: Option<String>,
+    status: Option<QualificationStatus>,
+}
+
+impl ListWorkersWithQualificationTypeRequestBuilder {
+    /// Sets the [`max_results`](struct.ListWorkersWithQualificationTypeRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListWorkersWithQualificationTypeRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualification_type_id`](struct.ListWorkersWithQualificationTypeRequest.html#structfield.qualification_type_id) member.
+    pub fn qualification_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualification_type_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListWorkersWithQualificationTypeRequest.html#structfield.status) member.
+    pub fn status<T: Into<QualificationStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListWorkersWithQualificationTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListWorkersWithQualificationTypeRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListWorkersWithQualificationTypeRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            qualification_type_id: self
+                .qualification_type_id
+                .ok_or(::rusoto_core::BuildError::missing("qualification_type_id"))?,
+            status: self.status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1021,22 +3120,170 @@ pub struct Locale {
 }
 
 /// <p>The NotificationSpecification data structure describes a HIT event notification for a HIT type.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct NotificationSpecification {
     /// <p><p> The target for notification messages. The Destination’s format is determined by the specified Transport: </p> <ul> <li> <p>When Transport is Email, the Destination is your email address.</p> </li> <li> <p>When Transport is SQS, the Destination is your queue URL.</p> </li> <li> <p>When Transport is SNS, the Destination is the ARN of your topic.</p> </li> </ul></p>
     #[serde(rename = "Destination")]
     pub destination: String,
     /// <p> The list of events that should cause notifications to be sent. Valid Values: AssignmentAccepted | AssignmentAbandoned | AssignmentReturned | AssignmentSubmitted | AssignmentRejected | AssignmentApproved | HITCreated | HITExtended | HITDisposed | HITReviewable | HITExpired | Ping. The Ping event is only valid for the SendTestEventNotification operation. </p>
     #[serde(rename = "EventTypes")]
-    pub event_types: Vec<String>,
+    pub event_types: Vec<EventType>,
     /// <p> The method Amazon Mechanical Turk uses to send the notification. Valid Values: Email | SQS | SNS. </p>
     #[serde(rename = "Transport")]
-    pub transport: String,
+    pub transport: NotificationTransport,
     /// <p>The version of the Notification API to use. Valid value is 2006-05-05.</p>
     #[serde(rename = "Version")]
     pub version: String,
 }
 
+/// <p> The method Amazon Mechanical Turk uses to send the notification. Valid Values: Email | SQS | SNS. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NotificationTransport {
+    /// <p><code>Email</code></p>
+    Email,
+    /// <p><code>SQS</code></p>
+    Sqs,
+    /// <p><code>SNS</code></p>
+    Sns,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NotificationTransport {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotificationTransport::Email => "Email",
+            NotificationTransport::Sqs => "SQS",
+            NotificationTransport::Sns => "SNS",
+            NotificationTransport::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NotificationTransport {
+    fn from(value: &'a str) -> NotificationTransport {
+        match value {
+            "Email" => NotificationTransport::Email,
+            "SQS" => NotificationTransport::Sqs,
+            "SNS" => NotificationTransport::Sns,
+            _ => NotificationTransport::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotificationTransport {
+    fn from(value: String) -> NotificationTransport {
+        NotificationTransport::from(value.as_str())
+    }
+}
+
+impl From<NotificationTransport> for String {
+    fn from(value: NotificationTransport) -> String {
+        match value {
+            NotificationTransport::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NotificationTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NotificationTransport {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NotificationTransport {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NotificationTransport, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NotificationTransport::from(value))
+    }
+}
+
+/// <p> Encoded value for the failure type. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NotifyWorkersFailureCode {
+    /// <p><code>SoftFailure</code></p>
+    SoftFailure,
+    /// <p><code>HardFailure</code></p>
+    HardFailure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NotifyWorkersFailureCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NotifyWorkersFailureCode::SoftFailure => "SoftFailure",
+            NotifyWorkersFailureCode::HardFailure => "HardFailure",
+            NotifyWorkersFailureCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NotifyWorkersFailureCode {
+    fn from(value: &'a str) -> NotifyWorkersFailureCode {
+        match value {
+            "SoftFailure" => NotifyWorkersFailureCode::SoftFailure,
+            "HardFailure" => NotifyWorkersFailureCode::HardFailure,
+            _ => NotifyWorkersFailureCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NotifyWorkersFailureCode {
+    fn from(value: String) -> NotifyWorkersFailureCode {
+        NotifyWorkersFailureCode::from(value.as_str())
+    }
+}
+
+impl From<NotifyWorkersFailureCode> for String {
+    fn from(value: NotifyWorkersFailureCode) -> String {
+        match value {
+            NotifyWorkersFailureCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NotifyWorkersFailureCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NotifyWorkersFailureCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NotifyWorkersFailureCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NotifyWorkersFailureCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NotifyWorkersFailureCode::from(value))
+    }
+}
+
 /// <p> When MTurk encounters an issue with notifying the Workers you specified, it returns back this object with failure details. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1044,7 +3291,7 @@ pub struct NotifyWorkersFailureStatus {
     /// <p> Encoded value for the failure type. </p>
     #[serde(rename = "NotifyWorkersFailureCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub notify_workers_failure_code: Option<String>,
+    pub notify_workers_failure_code: Option<NotifyWorkersFailureCode>,
     /// <p> A message detailing the reason the Worker could not be notified. </p>
     #[serde(rename = "NotifyWorkersFailureMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1068,6 +3315,56 @@ pub struct NotifyWorkersRequest {
     pub worker_ids: Vec<String>,
 }
 
+impl NotifyWorkersRequest {
+    /// Returns a builder for `NotifyWorkersRequest`, checking its required members when built.
+    pub fn builder() -> NotifyWorkersRequestBuilder {
+        NotifyWorkersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`NotifyWorkersRequest`](struct.NotifyWorkersRequest.html).
+#[derive(Default, Debug)]
+pub struct NotifyWorkersRequestBuilder {
+    message_text: Option<String>,
+    subject: Option<String>,
+    worker_ids: Option<Vec<String>>,
+}
+
+impl NotifyWorkersRequestBuilder {
+    /// Sets the [`message_text`](struct.NotifyWorkersRequest.html#structfield.message_text) member.
+    pub fn message_text<T: Into<String>>(mut self, value: T) -> Self {
+        self.message_text = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subject`](struct.NotifyWorkersRequest.html#structfield.subject) member.
+    pub fn subject<T: Into<String>>(mut self, value: T) -> Self {
+        self.subject = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_ids`](struct.NotifyWorkersRequest.html#structfield.worker_ids) member.
+    pub fn worker_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.worker_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `NotifyWorkersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<NotifyWorkersRequest, ::rusoto_core::BuildError> {
+        Ok(NotifyWorkersRequest {
+            message_text: self
+                .message_text
+                .ok_or(::rusoto_core::BuildError::missing("message_text"))?,
+            subject: self
+                .subject
+                .ok_or(::rusoto_core::BuildError::missing("subject"))?,
+            worker_ids: self
+                .worker_ids
+                .ok_or(::rusoto_core::BuildError::missing("worker_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct NotifyWorkersResponse {
@@ -1113,8 +3410,9 @@ pub struct PolicyParameter {
 pub struct Qualification {
     /// <p> The date and time the Qualification was granted to the Worker. If the Worker's Qualification was revoked, and then re-granted based on a new Qualification request, GrantTime is the date and time of the last call to the AcceptQualificationRequest operation.</p>
     #[serde(rename = "GrantTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub grant_time: Option<f64>,
+    pub grant_time: Option<EpochTimestamp>,
     /// <p> The value (score) of the Qualification, if the Qualification has an integer value.</p>
     #[serde(rename = "IntegerValue")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1129,7 +3427,7 @@ pub struct Qualification {
     /// <p> The status of the Qualification. Valid values are Granted | Revoked.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<QualificationStatus>,
     /// <p> The ID of the Worker who possesses the Qualification. </p>
     #[serde(rename = "WorkerId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1154,8 +3452,9 @@ pub struct QualificationRequest {
     pub qualification_type_id: Option<String>,
     /// <p>The date and time the Qualification request had a status of Submitted. This is either the time the Worker submitted answers for a Qualification test, or the time the Worker requested the Qualification if the Qualification type does not have a test. </p>
     #[serde(rename = "SubmitTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub submit_time: Option<f64>,
+    pub submit_time: Option<EpochTimestamp>,
     /// <p> The contents of the Qualification test that was presented to the Worker, if the type has a test and the Worker has submitted answers. This value is identical to the QuestionForm associated with the Qualification type at the time the Worker requests the Qualification.</p>
     #[serde(rename = "Test")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1167,15 +3466,15 @@ pub struct QualificationRequest {
 }
 
 /// <p> The QualificationRequirement data structure describes a Qualification that a Worker must have before the Worker is allowed to accept a HIT. A requirement may optionally state that a Worker must have the Qualification in order to preview the HIT, or see the HIT in search results. </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct QualificationRequirement {
     /// <p> Setting this attribute prevents Workers whose Qualifications do not meet this QualificationRequirement from taking the specified action. Valid arguments include "Accept" (Worker cannot accept the HIT, but can preview the HIT and see it in their search results), "PreviewAndAccept" (Worker cannot accept or preview the HIT, but can see the HIT in their search results), and "DiscoverPreviewAndAccept" (Worker cannot accept, preview, or see the HIT in their search results). It's possible for you to create a HIT with multiple QualificationRequirements (which can have different values for the ActionGuarded attribute). In this case, the Worker is only permitted to perform an action when they have met all QualificationRequirements guarding the action. The actions in the order of least restrictive to most restrictive are Discover, Preview and Accept. For example, if a Worker meets all QualificationRequirements that are set to DiscoverPreviewAndAccept, but do not meet all requirements that are set with PreviewAndAccept, then the Worker will be able to Discover, i.e. see the HIT in their search result, but will not be able to Preview or Accept the HIT. ActionsGuarded should not be used in combination with the <code>RequiredToPreview</code> field. </p>
     #[serde(rename = "ActionsGuarded")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub actions_guarded: Option<String>,
+    pub actions_guarded: Option<HITAccessActions>,
     /// <p>The kind of comparison to make against a Qualification's value. You can compare a Qualification's value to an IntegerValue to see if it is LessThan, LessThanOrEqualTo, GreaterThan, GreaterThanOrEqualTo, EqualTo, or NotEqualTo the IntegerValue. You can compare it to a LocaleValue to see if it is EqualTo, or NotEqualTo the LocaleValue. You can check to see if the value is In or NotIn a set of IntegerValue or LocaleValue values. Lastly, a Qualification requirement can also test if a Qualification Exists or DoesNotExist in the user's profile, regardless of its value. </p>
     #[serde(rename = "Comparator")]
-    pub comparator: String,
+    pub comparator: Comparator,
     /// <p> The integer value to compare against the Qualification's value. IntegerValue must not be present if Comparator is Exists or DoesNotExist. IntegerValue can only be used if the Qualification type has an integer value; it cannot be used with the Worker_Locale QualificationType ID. When performing a set comparison by using the In or the NotIn comparator, you can use up to 15 IntegerValue elements in a QualificationRequirement data structure. </p>
     #[serde(rename = "IntegerValues")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1189,6 +3488,78 @@ pub struct QualificationRequirement {
     pub qualification_type_id: String,
 }
 
+/// <p> The status of the Qualifications to return. Can be <code>Granted | Revoked</code>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QualificationStatus {
+    /// <p><code>Granted</code></p>
+    Granted,
+    /// <p><code>Revoked</code></p>
+    Revoked,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl QualificationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QualificationStatus::Granted => "Granted",
+            QualificationStatus::Revoked => "Revoked",
+            QualificationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for QualificationStatus {
+    fn from(value: &'a str) -> QualificationStatus {
+        match value {
+            "Granted" => QualificationStatus::Granted,
+            "Revoked" => QualificationStatus::Revoked,
+            _ => QualificationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QualificationStatus {
+    fn from(value: String) -> QualificationStatus {
+        QualificationStatus::from(value.as_str())
+    }
+}
+
+impl From<QualificationStatus> for String {
+    fn from(value: QualificationStatus) -> String {
+        match value {
+            QualificationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for QualificationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for QualificationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QualificationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<QualificationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(QualificationStatus::from(value))
+    }
+}
+
 /// <p> The QualificationType data structure represents a Qualification type, a description of a property of a Worker that must match the requirements of a HIT for the Worker to be able to accept the HIT. The type also describes how a Worker can obtain a Qualification of that type, such as through a Qualification test. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1207,8 +3578,9 @@ pub struct QualificationType {
     pub auto_granted_value: Option<i64>,
     /// <p> The date and time the Qualification type was created. </p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p> A long description for the Qualification type. </p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1232,7 +3604,7 @@ pub struct QualificationType {
     /// <p> The status of the Qualification type. A Qualification type's status determines if users can apply to receive a Qualification of this type, and if HITs can be created with requirements based on this type. Valid values are Active | Inactive. </p>
     #[serde(rename = "QualificationTypeStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub qualification_type_status: Option<String>,
+    pub qualification_type_status: Option<QualificationTypeStatus>,
     /// <p> The amount of time, in seconds, Workers must wait after taking the Qualification test before they can take it again. Workers can take a Qualification test multiple times if they were not granted the Qualification from a previous attempt, or if the test offers a gradient score and they want a better score. If not specified, retries are disabled and Workers can request a Qualification only once. </p>
     #[serde(rename = "RetryDelayInSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1247,6 +3619,78 @@ pub struct QualificationType {
     pub test_duration_in_seconds: Option<i64>,
 }
 
+/// <p>The initial status of the Qualification type.</p> <p>Constraints: Valid values are: Active | Inactive</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QualificationTypeStatus {
+    /// <p><code>Active</code></p>
+    Active,
+    /// <p><code>Inactive</code></p>
+    Inactive,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl QualificationTypeStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QualificationTypeStatus::Active => "Active",
+            QualificationTypeStatus::Inactive => "Inactive",
+            QualificationTypeStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for QualificationTypeStatus {
+    fn from(value: &'a str) -> QualificationTypeStatus {
+        match value {
+            "Active" => QualificationTypeStatus::Active,
+            "Inactive" => QualificationTypeStatus::Inactive,
+            _ => QualificationTypeStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QualificationTypeStatus {
+    fn from(value: String) -> QualificationTypeStatus {
+        QualificationTypeStatus::from(value.as_str())
+    }
+}
+
+impl From<QualificationTypeStatus> for String {
+    fn from(value: QualificationTypeStatus) -> String {
+        match value {
+            QualificationTypeStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for QualificationTypeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for QualificationTypeStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QualificationTypeStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<QualificationTypeStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(QualificationTypeStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RejectAssignmentRequest {
     /// <p> The ID of the assignment. The assignment must correspond to a HIT created by the Requester. </p>
@@ -1257,6 +3701,48 @@ pub struct RejectAssignmentRequest {
     pub requester_feedback: String,
 }
 
+impl RejectAssignmentRequest {
+    /// Returns a builder for `RejectAssignmentRequest`, checking its required members when built.
+    pub fn builder() -> RejectAssignmentRequestBuilder {
+        RejectAssignmentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RejectAssignmentRequest`](struct.RejectAssignmentRequest.html).
+#[derive(Default, Debug)]
+pub struct RejectAssignmentRequestBuilder {
+    assignment_id: Option<String>,
+    requester_feedback: Option<String>,
+}
+
+impl RejectAssignmentRequestBuilder {
+    /// Sets the [`assignment_id`](struct.RejectAssignmentRequest.html#structfield.assignment_id) member.
+    pub fn assignment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.assignment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`requester_feedback`](struct.RejectAssignmentRequest.html#structfield.requester_feedback) member.
+    pub fn requester_feedback<T: Into<String>>(mut self, value: T) -> Self {
+        self.requester_feedback = Some(value.into());
+        self
+    }
+
+    /// Builds the `RejectAssignmentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RejectAssignmentRequest, ::rusoto_core::BuildError> {
+        Ok(RejectAssignmentRequest {
+            assignment_id: self
+                .assignment_id
+                .ok_or(::rusoto_core::BuildError::missing("assignment_id"))?,
+            requester_feedback: self
+                .requester_feedback
+                .ok_or(::rusoto_core::BuildError::missing("requester_feedback"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RejectAssignmentResponse {}
@@ -1272,6 +3758,46 @@ pub struct RejectQualificationRequestRequest {
     pub reason: Option<String>,
 }
 
+impl RejectQualificationRequestRequest {
+    /// Returns a builder for `RejectQualificationRequestRequest`, checking its required members when built.
+    pub fn builder() -> RejectQualificationRequestRequestBuilder {
+        RejectQualificationRequestRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RejectQualificationRequestRequest`](struct.RejectQualificationRequestRequest.html).
+#[derive(Default, Debug)]
+pub struct RejectQualificationRequestRequestBuilder {
+    qualification_request_id: Option<String>,
+    reason: Option<String>,
+}
+
+impl RejectQualificationRequestRequestBuilder {
+    /// Sets the [`qualification_request_id`](struct.RejectQualificationRequestRequest.html#structfield.qualification_request_id) member.
+    pub fn qualification_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualification_request_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reason`](struct.RejectQualificationRequestRequest.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Builds the `RejectQualificationRequestRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RejectQualificationRequestRequest, ::rusoto_core::BuildError> {
+        Ok(RejectQualificationRequestRequest {
+            qualification_request_id: self.qualification_request_id.ok_or(
+                ::rusoto_core::BuildError::missing("qualification_request_id"),
+            )?,
+            reason: self.reason,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RejectQualificationRequestResponse {}
@@ -1290,8 +3816,9 @@ pub struct ReviewActionDetail {
     pub action_name: Option<String>,
     /// <p> The date when the action was completed.</p>
     #[serde(rename = "CompleteTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub complete_time: Option<f64>,
+    pub complete_time: Option<EpochTimestamp>,
     /// <p> Present only when the Results have a FAILED Status.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1303,7 +3830,7 @@ pub struct ReviewActionDetail {
     /// <p> The current disposition of the action: INTENDED, SUCCEEDED, FAILED, or CANCELLED. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ReviewActionStatus>,
     /// <p> The specific HITId or AssignmentID targeted by the action.</p>
     #[serde(rename = "TargetId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1314,6 +3841,86 @@ pub struct ReviewActionDetail {
     pub target_type: Option<String>,
 }
 
+/// <p> The current disposition of the action: INTENDED, SUCCEEDED, FAILED, or CANCELLED. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReviewActionStatus {
+    /// <p><code>Intended</code></p>
+    Intended,
+    /// <p><code>Succeeded</code></p>
+    Succeeded,
+    /// <p><code>Failed</code></p>
+    Failed,
+    /// <p><code>Cancelled</code></p>
+    Cancelled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReviewActionStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReviewActionStatus::Intended => "Intended",
+            ReviewActionStatus::Succeeded => "Succeeded",
+            ReviewActionStatus::Failed => "Failed",
+            ReviewActionStatus::Cancelled => "Cancelled",
+            ReviewActionStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReviewActionStatus {
+    fn from(value: &'a str) -> ReviewActionStatus {
+        match value {
+            "Intended" => ReviewActionStatus::Intended,
+            "Succeeded" => ReviewActionStatus::Succeeded,
+            "Failed" => ReviewActionStatus::Failed,
+            "Cancelled" => ReviewActionStatus::Cancelled,
+            _ => ReviewActionStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReviewActionStatus {
+    fn from(value: String) -> ReviewActionStatus {
+        ReviewActionStatus::from(value.as_str())
+    }
+}
+
+impl From<ReviewActionStatus> for String {
+    fn from(value: ReviewActionStatus) -> String {
+        match value {
+            ReviewActionStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReviewActionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReviewActionStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReviewActionStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReviewActionStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReviewActionStatus::from(value))
+    }
+}
+
 /// <p> HIT Review Policy data structures represent HIT review policies, which you specify when you create a HIT. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReviewPolicy {
@@ -1326,6 +3933,78 @@ pub struct ReviewPolicy {
     pub policy_name: String,
 }
 
+/// <p> The Policy Level(s) to retrieve review results for - HIT or Assignment. If omitted, the default behavior is to retrieve all data for both policy levels. For a list of all the described policies, see Review Policies. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReviewPolicyLevel {
+    /// <p><code>Assignment</code></p>
+    Assignment,
+    /// <p><code>HIT</code></p>
+    Hit,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReviewPolicyLevel {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReviewPolicyLevel::Assignment => "Assignment",
+            ReviewPolicyLevel::Hit => "HIT",
+            ReviewPolicyLevel::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReviewPolicyLevel {
+    fn from(value: &'a str) -> ReviewPolicyLevel {
+        match value {
+            "Assignment" => ReviewPolicyLevel::Assignment,
+            "HIT" => ReviewPolicyLevel::Hit,
+            _ => ReviewPolicyLevel::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReviewPolicyLevel {
+    fn from(value: String) -> ReviewPolicyLevel {
+        ReviewPolicyLevel::from(value.as_str())
+    }
+}
+
+impl From<ReviewPolicyLevel> for String {
+    fn from(value: ReviewPolicyLevel) -> String {
+        match value {
+            ReviewPolicyLevel::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReviewPolicyLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReviewPolicyLevel {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReviewPolicyLevel {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReviewPolicyLevel, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReviewPolicyLevel::from(value))
+    }
+}
+
 /// <p> Contains both ReviewResult and ReviewAction elements for a particular HIT. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1370,6 +4049,78 @@ pub struct ReviewResultDetail {
     pub value: Option<String>,
 }
 
+/// <p> Can be either <code>Reviewable</code> or <code>Reviewing</code>. Reviewable is the default value. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReviewableHITStatus {
+    /// <p><code>Reviewable</code></p>
+    Reviewable,
+    /// <p><code>Reviewing</code></p>
+    Reviewing,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReviewableHITStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReviewableHITStatus::Reviewable => "Reviewable",
+            ReviewableHITStatus::Reviewing => "Reviewing",
+            ReviewableHITStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReviewableHITStatus {
+    fn from(value: &'a str) -> ReviewableHITStatus {
+        match value {
+            "Reviewable" => ReviewableHITStatus::Reviewable,
+            "Reviewing" => ReviewableHITStatus::Reviewing,
+            _ => ReviewableHITStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReviewableHITStatus {
+    fn from(value: String) -> ReviewableHITStatus {
+        ReviewableHITStatus::from(value.as_str())
+    }
+}
+
+impl From<ReviewableHITStatus> for String {
+    fn from(value: ReviewableHITStatus) -> String {
+        match value {
+            ReviewableHITStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReviewableHITStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ReviewableHITStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ReviewableHITStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ReviewableHITStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ReviewableHITStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SendBonusRequest {
     /// <p>The ID of the assignment for which this bonus is paid.</p>
@@ -1390,18 +4141,128 @@ pub struct SendBonusRequest {
     pub worker_id: String,
 }
 
+impl SendBonusRequest {
+    /// Returns a builder for `SendBonusRequest`, checking its required members when built.
+    pub fn builder() -> SendBonusRequestBuilder {
+        SendBonusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendBonusRequest`](struct.SendBonusRequest.html).
+#[derive(Default, Debug)]
+pub struct SendBonusRequestBuilder {
+    assignment_id: Option<String>,
+    bonus_amount: Option<String>,
+    reason: Option<String>,
+    unique_request_token: Option<String>,
+    worker_id: Option<String>,
+}
+
+impl SendBonusRequestBuilder {
+    /// Sets the [`assignment_id`](struct.SendBonusRequest.html#structfield.assignment_id) member.
+    pub fn assignment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.assignment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`bonus_amount`](struct.SendBonusRequest.html#structfield.bonus_amount) member.
+    pub fn bonus_amount<T: Into<String>>(mut self, value: T) -> Self {
+        self.bonus_amount = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reason`](struct.SendBonusRequest.html#structfield.reason) member.
+    pub fn reason<T: Into<String>>(mut self, value: T) -> Self {
+        self.reason = Some(value.into());
+        self
+    }
+
+    /// Sets the [`unique_request_token`](struct.SendBonusRequest.html#structfield.unique_request_token) member.
+    pub fn unique_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.unique_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`worker_id`](struct.SendBonusRequest.html#structfield.worker_id) member.
+    pub fn worker_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.worker_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendBonusRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SendBonusRequest, ::rusoto_core::BuildError> {
+        Ok(SendBonusRequest {
+            assignment_id: self
+                .assignment_id
+                .ok_or(::rusoto_core::BuildError::missing("assignment_id"))?,
+            bonus_amount: self
+                .bonus_amount
+                .ok_or(::rusoto_core::BuildError::missing("bonus_amount"))?,
+            reason: self
+                .reason
+                .ok_or(::rusoto_core::BuildError::missing("reason"))?,
+            unique_request_token: self.unique_request_token,
+            worker_id: self
+                .worker_id
+                .ok_or(::rusoto_core::BuildError::missing("worker_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SendBonusResponse {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SendTestEventNotificationRequest {
     /// <p> The notification specification to test. This value is identical to the value you would provide to the UpdateNotificationSettings operation when you establish the notification specification for a HIT type. </p>
     #[serde(rename = "Notification")]
     pub notification: NotificationSpecification,
     /// <p> The event to simulate to test the notification specification. This event is included in the test message even if the notification specification does not include the event type. The notification specification does not filter out the test event. </p>
     #[serde(rename = "TestEventType")]
-    pub test_event_type: String,
+    pub test_event_type: EventType,
+}
+
+impl SendTestEventNotificationRequest {
+    /// Returns a builder for `SendTestEventNotificationRequest`, checking its required members when built.
+    pub fn builder() -> SendTestEventNotificationRequestBuilder {
+        SendTestEventNotificationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendTestEventNotificationRequest`](struct.SendTestEventNotificationRequest.html).
+#[derive(Default, Debug)]
+pub struct SendTestEventNotificationRequestBuilder {
+    notification: Option<NotificationSpecification>,
+    test_event_type: Option<EventType>,
+}
+
+impl SendTestEventNotificationRequestBuilder {
+    /// Sets the [`notification`](struct.SendTestEventNotificationRequest.html#structfield.notification) member.
+    pub fn notification<T: Into<NotificationSpecification>>(mut self, value: T) -> Self {
+        self.notification = Some(value.into());
+        self
+    }
+
+    /// Sets the [`test_event_type`](struct.SendTestEventNotificationRequest.html#structfield.test_event_type) member.
+    pub fn test_event_type<T: Into<EventType>>(mut self, value: T) -> Self {
+        self.test_event_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendTestEventNotificationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SendTestEventNotificationRequest, ::rusoto_core::BuildError> {
+        Ok(SendTestEventNotificationRequest {
+            notification: self
+                .notification
+                .ok_or(::rusoto_core::BuildError::missing("notification"))?,
+            test_event_type: self
+                .test_event_type
+                .ok_or(::rusoto_core::BuildError::missing("test_event_type"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1412,12 +4273,55 @@ pub struct SendTestEventNotificationResponse {}
 pub struct UpdateExpirationForHITRequest {
     /// <p> The date and time at which you want the HIT to expire </p>
     #[serde(rename = "ExpireAt")]
-    pub expire_at: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub expire_at: EpochTimestamp,
     /// <p> The HIT to update. </p>
     #[serde(rename = "HITId")]
     pub hit_id: String,
 }
 
+impl UpdateExpirationForHITRequest {
+    /// Returns a builder for `UpdateExpirationForHITRequest`, checking its required members when built.
+    pub fn builder() -> UpdateExpirationForHITRequestBuilder {
+        UpdateExpirationForHITRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateExpirationForHITRequest`](struct.UpdateExpirationForHITRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateExpirationForHITRequestBuilder {
+    expire_at: Option<EpochTimestamp>,
+    hit_id: Option<String>,
+}
+
+impl UpdateExpirationForHITRequestBuilder {
+    /// Sets the [`expire_at`](struct.UpdateExpirationForHITRequest.html#structfield.expire_at) member.
+    pub fn expire_at<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.expire_at = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hit_id`](struct.UpdateExpirationForHITRequest.html#structfield.hit_id) member.
+    pub fn hit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateExpirationForHITRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateExpirationForHITRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateExpirationForHITRequest {
+            expire_at: self
+                .expire_at
+                .ok_or(::rusoto_core::BuildError::missing("expire_at"))?,
+            hit_id: self
+                .hit_id
+                .ok_or(::rusoto_core::BuildError::missing("hit_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateExpirationForHITResponse {}
@@ -1433,6 +4337,46 @@ pub struct UpdateHITReviewStatusRequest {
     pub revert: Option<bool>,
 }
 
+impl UpdateHITReviewStatusRequest {
+    /// Returns a builder for `UpdateHITReviewStatusRequest`, checking its required members when built.
+    pub fn builder() -> UpdateHITReviewStatusRequestBuilder {
+        UpdateHITReviewStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateHITReviewStatusRequest`](struct.UpdateHITReviewStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateHITReviewStatusRequestBuilder {
+    hit_id: Option<String>,
+    revert: Option<bool>,
+}
+
+impl UpdateHITReviewStatusRequestBuilder {
+    /// Sets the [`hit_id`](struct.UpdateHITReviewStatusRequest.html#structfield.hit_id) member.
+    pub fn hit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revert`](struct.UpdateHITReviewStatusRequest.html#structfield.revert) member.
+    pub fn revert<T: Into<bool>>(mut self, value: T) -> Self {
+        self.revert = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateHITReviewStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateHITReviewStatusRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateHITReviewStatusRequest {
+            hit_id: self
+                .hit_id
+                .ok_or(::rusoto_core::BuildError::missing("hit_id"))?,
+            revert: self.revert,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateHITReviewStatusResponse {}
@@ -1447,6 +4391,48 @@ pub struct UpdateHITTypeOfHITRequest {
     pub hit_type_id: String,
 }
 
+impl UpdateHITTypeOfHITRequest {
+    /// Returns a builder for `UpdateHITTypeOfHITRequest`, checking its required members when built.
+    pub fn builder() -> UpdateHITTypeOfHITRequestBuilder {
+        UpdateHITTypeOfHITRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateHITTypeOfHITRequest`](struct.UpdateHITTypeOfHITRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateHITTypeOfHITRequestBuilder {
+    hit_id: Option<String>,
+    hit_type_id: Option<String>,
+}
+
+impl UpdateHITTypeOfHITRequestBuilder {
+    /// Sets the [`hit_id`](struct.UpdateHITTypeOfHITRequest.html#structfield.hit_id) member.
+    pub fn hit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hit_type_id`](struct.UpdateHITTypeOfHITRequest.html#structfield.hit_type_id) member.
+    pub fn hit_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_type_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateHITTypeOfHITRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateHITTypeOfHITRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateHITTypeOfHITRequest {
+            hit_id: self
+                .hit_id
+                .ok_or(::rusoto_core::BuildError::missing("hit_id"))?,
+            hit_type_id: self
+                .hit_type_id
+                .ok_or(::rusoto_core::BuildError::missing("hit_type_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateHITTypeOfHITResponse {}
@@ -1466,6 +4452,54 @@ pub struct UpdateNotificationSettingsRequest {
     pub notification: Option<NotificationSpecification>,
 }
 
+impl UpdateNotificationSettingsRequest {
+    /// Returns a builder for `UpdateNotificationSettingsRequest`, checking its required members when built.
+    pub fn builder() -> UpdateNotificationSettingsRequestBuilder {
+        UpdateNotificationSettingsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateNotificationSettingsRequest`](struct.UpdateNotificationSettingsRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateNotificationSettingsRequestBuilder {
+    active: Option<bool>,
+    hit_type_id: Option<String>,
+    notification: Option<NotificationSpecification>,
+}
+
+impl UpdateNotificationSettingsRequestBuilder {
+    /// Sets the [`active`](struct.UpdateNotificationSettingsRequest.html#structfield.active) member.
+    pub fn active<T: Into<bool>>(mut self, value: T) -> Self {
+        self.active = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hit_type_id`](struct.UpdateNotificationSettingsRequest.html#structfield.hit_type_id) member.
+    pub fn hit_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.hit_type_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification`](struct.UpdateNotificationSettingsRequest.html#structfield.notification) member.
+    pub fn notification<T: Into<NotificationSpecification>>(mut self, value: T) -> Self {
+        self.notification = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateNotificationSettingsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateNotificationSettingsRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateNotificationSettingsRequest {
+            active: self.active,
+            hit_type_id: self
+                .hit_type_id
+                .ok_or(::rusoto_core::BuildError::missing("hit_type_id"))?,
+            notification: self.notification,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateNotificationSettingsResponse {}
@@ -1494,7 +4528,7 @@ pub struct UpdateQualificationTypeRequest {
     /// <p>The new status of the Qualification type - Active | Inactive</p>
     #[serde(rename = "QualificationTypeStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub qualification_type_status: Option<String>,
+    pub qualification_type_status: Option<QualificationTypeStatus>,
     /// <p>The amount of time, in seconds, that Workers must wait after requesting a Qualification of the specified Qualification type before they can retry the Qualification request. It is not possible to disable retries for a Qualification type after it has been created with retries enabled. If you want to disable retries, you must dispose of the existing retry-enabled Qualification type using DisposeQualificationType and then create a new Qualification type with retries disabled using CreateQualificationType.</p>
     #[serde(rename = "RetryDelayInSeconds")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1509,6 +4543,102 @@ pub struct UpdateQualificationTypeRequest {
     pub test_duration_in_seconds: Option<i64>,
 }
 
+impl UpdateQualificationTypeRequest {
+    /// Returns a builder for `UpdateQualificationTypeRequest`, checking its required members when built.
+    pub fn builder() -> UpdateQualificationTypeRequestBuilder {
+        UpdateQualificationTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateQualificationTypeRequest`](struct.UpdateQualificationTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateQualificationTypeRequestBuilder {
+    answer_key: Option<String>,
+    auto_granted: Option<bool>,
+    auto_granted_value: Option<i64>,
+    description: Option<String>,
+    qualification_type_id: Option<String>,
+    qualification_type_status: Option<QualificationTypeStatus>,
+    retry_delay_in_seconds: Option<i64>,
+    test: Option<String>,
+    test_duration_in_seconds: Option<i64>,
+}
+
+impl UpdateQualificationTypeRequestBuilder {
+    /// Sets the [`answer_key`](struct.UpdateQualificationTypeRequest.html#structfield.answer_key) member.
+    pub fn answer_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.answer_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_granted`](struct.UpdateQualificationTypeRequest.html#structfield.auto_granted) member.
+    pub fn auto_granted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_granted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_granted_value`](struct.UpdateQualificationTypeRequest.html#structfield.auto_granted_value) member.
+    pub fn auto_granted_value<T: Into<i64>>(mut self, value: T) -> Self {
+        self.auto_granted_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateQualificationTypeRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualification_type_id`](struct.UpdateQualificationTypeRequest.html#structfield.qualification_type_id) member.
+    pub fn qualification_type_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.qualification_type_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`qualification_type_status`](struct.UpdateQualificationTypeRequest.html#structfield.qualification_type_status) member.
+    pub fn qualification_type_status<T: Into<QualificationTypeStatus>>(mut self, value: T) -> Self {
+        self.qualification_type_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retry_delay_in_seconds`](struct.UpdateQualificationTypeRequest.html#structfield.retry_delay_in_seconds) member.
+    pub fn retry_delay_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.retry_delay_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`test`](struct.UpdateQualificationTypeRequest.html#structfield.test) member.
+    pub fn test<T: Into<String>>(mut self, value: T) -> Self {
+        self.test = Some(value.into());
+        self
+    }
+
+    /// Sets the [`test_duration_in_seconds`](struct.UpdateQualificationTypeRequest.html#structfield.test_duration_in_seconds) member.
+    pub fn test_duration_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.test_duration_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateQualificationTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateQualificationTypeRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateQualificationTypeRequest {
+            answer_key: self.answer_key,
+            auto_granted: self.auto_granted,
+            auto_granted_value: self.auto_granted_value,
+            description: self.description,
+            qualification_type_id: self
+                .qualification_type_id
+                .ok_or(::rusoto_core::BuildError::missing("qualification_type_id"))?,
+            qualification_type_status: self.qualification_type_status,
+            retry_delay_in_seconds: self.retry_delay_in_seconds,
+            test: self.test,
+            test_duration_in_seconds: self.test_duration_in_seconds,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateQualificationTypeResponse {
@@ -3358,6 +6488,251 @@ pub trait MechanicalTurk {
         input: UpdateQualificationTypeRequest,
     ) -> RusotoFuture<UpdateQualificationTypeResponse, UpdateQualificationTypeError>;
 }
+impl<T: ?Sized + MechanicalTurk> MechanicalTurk for ::std::sync::Arc<T> {
+    fn accept_qualification_request(
+        &self,
+        input: AcceptQualificationRequestRequest,
+    ) -> RusotoFuture<AcceptQualificationRequestResponse, AcceptQualificationRequestError> {
+        (**self).accept_qualification_request(input)
+    }
+    fn approve_assignment(
+        &self,
+        input: ApproveAssignmentRequest,
+    ) -> RusotoFuture<ApproveAssignmentResponse, ApproveAssignmentError> {
+        (**self).approve_assignment(input)
+    }
+    fn associate_qualification_with_worker(
+        &self,
+        input: AssociateQualificationWithWorkerRequest,
+    ) -> RusotoFuture<AssociateQualificationWithWorkerResponse, AssociateQualificationWithWorkerError>
+    {
+        (**self).associate_qualification_with_worker(input)
+    }
+    fn create_additional_assignments_for_hit(
+        &self,
+        input: CreateAdditionalAssignmentsForHITRequest,
+    ) -> RusotoFuture<
+        CreateAdditionalAssignmentsForHITResponse,
+        CreateAdditionalAssignmentsForHITError,
+    > {
+        (**self).create_additional_assignments_for_hit(input)
+    }
+    fn create_hit(
+        &self,
+        input: CreateHITRequest,
+    ) -> RusotoFuture<CreateHITResponse, CreateHITError> {
+        (**self).create_hit(input)
+    }
+    fn create_hit_type(
+        &self,
+        input: CreateHITTypeRequest,
+    ) -> RusotoFuture<CreateHITTypeResponse, CreateHITTypeError> {
+        (**self).create_hit_type(input)
+    }
+    fn create_hit_with_hit_type(
+        &self,
+        input: CreateHITWithHITTypeRequest,
+    ) -> RusotoFuture<CreateHITWithHITTypeResponse, CreateHITWithHITTypeError> {
+        (**self).create_hit_with_hit_type(input)
+    }
+    fn create_qualification_type(
+        &self,
+        input: CreateQualificationTypeRequest,
+    ) -> RusotoFuture<CreateQualificationTypeResponse, CreateQualificationTypeError> {
+        (**self).create_qualification_type(input)
+    }
+    fn create_worker_block(
+        &self,
+        input: CreateWorkerBlockRequest,
+    ) -> RusotoFuture<CreateWorkerBlockResponse, CreateWorkerBlockError> {
+        (**self).create_worker_block(input)
+    }
+    fn delete_hit(
+        &self,
+        input: DeleteHITRequest,
+    ) -> RusotoFuture<DeleteHITResponse, DeleteHITError> {
+        (**self).delete_hit(input)
+    }
+    fn delete_qualification_type(
+        &self,
+        input: DeleteQualificationTypeRequest,
+    ) -> RusotoFuture<DeleteQualificationTypeResponse, DeleteQualificationTypeError> {
+        (**self).delete_qualification_type(input)
+    }
+    fn delete_worker_block(
+        &self,
+        input: DeleteWorkerBlockRequest,
+    ) -> RusotoFuture<DeleteWorkerBlockResponse, DeleteWorkerBlockError> {
+        (**self).delete_worker_block(input)
+    }
+    fn disassociate_qualification_from_worker(
+        &self,
+        input: DisassociateQualificationFromWorkerRequest,
+    ) -> RusotoFuture<
+        DisassociateQualificationFromWorkerResponse,
+        DisassociateQualificationFromWorkerError,
+    > {
+        (**self).disassociate_qualification_from_worker(input)
+    }
+    fn get_account_balance(
+        &self,
+    ) -> RusotoFuture<GetAccountBalanceResponse, GetAccountBalanceError> {
+        (**self).get_account_balance()
+    }
+    fn get_assignment(
+        &self,
+        input: GetAssignmentRequest,
+    ) -> RusotoFuture<GetAssignmentResponse, GetAssignmentError> {
+        (**self).get_assignment(input)
+    }
+    fn get_file_upload_url(
+        &self,
+        input: GetFileUploadURLRequest,
+    ) -> RusotoFuture<GetFileUploadURLResponse, GetFileUploadURLError> {
+        (**self).get_file_upload_url(input)
+    }
+    fn get_hit(&self, input: GetHITRequest) -> RusotoFuture<GetHITResponse, GetHITError> {
+        (**self).get_hit(input)
+    }
+    fn get_qualification_score(
+        &self,
+        input: GetQualificationScoreRequest,
+    ) -> RusotoFuture<GetQualificationScoreResponse, GetQualificationScoreError> {
+        (**self).get_qualification_score(input)
+    }
+    fn get_qualification_type(
+        &self,
+        input: GetQualificationTypeRequest,
+    ) -> RusotoFuture<GetQualificationTypeResponse, GetQualificationTypeError> {
+        (**self).get_qualification_type(input)
+    }
+    fn list_assignments_for_hit(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> RusotoFuture<ListAssignmentsForHITResponse, ListAssignmentsForHITError> {
+        (**self).list_assignments_for_hit(input)
+    }
+    fn list_bonus_payments(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> RusotoFuture<ListBonusPaymentsResponse, ListBonusPaymentsError> {
+        (**self).list_bonus_payments(input)
+    }
+    fn list_hi_ts(&self, input: ListHITsRequest) -> RusotoFuture<ListHITsResponse, ListHITsError> {
+        (**self).list_hi_ts(input)
+    }
+    fn list_hi_ts_for_qualification_type(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> RusotoFuture<ListHITsForQualificationTypeResponse, ListHITsForQualificationTypeError> {
+        (**self).list_hi_ts_for_qualification_type(input)
+    }
+    fn list_qualification_requests(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> RusotoFuture<ListQualificationRequestsResponse, ListQualificationRequestsError> {
+        (**self).list_qualification_requests(input)
+    }
+    fn list_qualification_types(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> RusotoFuture<ListQualificationTypesResponse, ListQualificationTypesError> {
+        (**self).list_qualification_types(input)
+    }
+    fn list_review_policy_results_for_hit(
+        &self,
+        input: ListReviewPolicyResultsForHITRequest,
+    ) -> RusotoFuture<ListReviewPolicyResultsForHITResponse, ListReviewPolicyResultsForHITError>
+    {
+        (**self).list_review_policy_results_for_hit(input)
+    }
+    fn list_reviewable_hi_ts(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> RusotoFuture<ListReviewableHITsResponse, ListReviewableHITsError> {
+        (**self).list_reviewable_hi_ts(input)
+    }
+    fn list_worker_blocks(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> RusotoFuture<ListWorkerBlocksResponse, ListWorkerBlocksError> {
+        (**self).list_worker_blocks(input)
+    }
+    fn list_workers_with_qualification_type(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> RusotoFuture<ListWorkersWithQualificationTypeResponse, ListWorkersWithQualificationTypeError>
+    {
+        (**self).list_workers_with_qualification_type(input)
+    }
+    fn notify_workers(
+        &self,
+        input: NotifyWorkersRequest,
+    ) -> RusotoFuture<NotifyWorkersResponse, NotifyWorkersError> {
+        (**self).notify_workers(input)
+    }
+    fn reject_assignment(
+        &self,
+        input: RejectAssignmentRequest,
+    ) -> RusotoFuture<RejectAssignmentResponse, RejectAssignmentError> {
+        (**self).reject_assignment(input)
+    }
+    fn reject_qualification_request(
+        &self,
+        input: RejectQualificationRequestRequest,
+    ) -> RusotoFuture<RejectQualificationRequestResponse, RejectQualificationRequestError> {
+        (**self).reject_qualification_request(input)
+    }
+    fn send_bonus(
+        &self,
+        input: SendBonusRequest,
+    ) -> RusotoFuture<SendBonusResponse, SendBonusError> {
+        (**self).send_bonus(input)
+    }
+    fn send_test_event_notification(
+        &self,
+        input: SendTestEventNotificationRequest,
+    ) -> RusotoFuture<SendTestEventNotificationResponse, SendTestEventNotificationError> {
+        (**self).send_test_event_notification(input)
+    }
+    fn update_expiration_for_hit(
+        &self,
+        input: UpdateExpirationForHITRequest,
+    ) -> RusotoFuture<UpdateExpirationForHITResponse, UpdateExpirationForHITError> {
+        (**self).update_expiration_for_hit(input)
+    }
+    fn update_hit_review_status(
+        &self,
+        input: UpdateHITReviewStatusRequest,
+    ) -> RusotoFuture<UpdateHITReviewStatusResponse, UpdateHITReviewStatusError> {
+        (**self).update_hit_review_status(input)
+    }
+    fn update_hit_type_of_hit(
+        &self,
+        input: UpdateHITTypeOfHITRequest,
+    ) -> RusotoFuture<UpdateHITTypeOfHITResponse, UpdateHITTypeOfHITError> {
+        (**self).update_hit_type_of_hit(input)
+    }
+    fn update_notification_settings(
+        &self,
+        input: UpdateNotificationSettingsRequest,
+    ) -> RusotoFuture<UpdateNotificationSettingsResponse, UpdateNotificationSettingsError> {
+        (**self).update_notification_settings(input)
+    }
+    fn update_qualification_type(
+        &self,
+        input: UpdateQualificationTypeRequest,
+    ) -> RusotoFuture<UpdateQualificationTypeResponse, UpdateQualificationTypeError> {
+        (**self).update_qualification_type(input)
+    }
+}
+/// The error codes Amazon MTurk retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon MTurk API.
 #[derive(Clone)]
 pub struct MechanicalTurkClient {
@@ -3373,6 +6748,27 @@ impl MechanicalTurkClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_MTURK`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> MechanicalTurkClient {
+        Self::new(region::Region::from_env_for_service("MTURK"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `mturk-requester`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> MechanicalTurkClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("mturk-requester", "MTURK"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3391,7 +6787,54 @@ impl MechanicalTurkClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MechanicalTurkClient {
-        MechanicalTurkClient { client, region }
+        MechanicalTurkClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "mturk-requester")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "mturk-requester",
+            "mturk-requester",
+            "MTURK",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `MechanicalTurk` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn MechanicalTurk + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for MechanicalTurkClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon MTurk"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "mturk-requester"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> MechanicalTurkClient {
+        Self::new_with_client(client, region)
     }
 }
 
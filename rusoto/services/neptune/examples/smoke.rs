@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Neptune clients.
+//!
+//! Makes a single read-only `DescribeDBClusterParameterGroups` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_neptune::{DescribeDBClusterParameterGroupsMessage, Neptune, NeptuneClient};
+
+fn main() {
+    let client = NeptuneClient::from_env();
+    match client
+        .describe_db_cluster_parameter_groups(DescribeDBClusterParameterGroupsMessage::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeDBClusterParameterGroups succeeded"),
+        Err(error) => {
+            eprintln!("DescribeDBClusterParameterGroups failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -28,6 +28,7 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -41,6 +42,48 @@ pub struct AddRoleToDBClusterMessage {
     pub role_arn: String,
 }
 
+impl AddRoleToDBClusterMessage {
+    /// Returns a builder for `AddRoleToDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> AddRoleToDBClusterMessageBuilder {
+        AddRoleToDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AddRoleToDBClusterMessage`](struct.AddRoleToDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct AddRoleToDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    role_arn: Option<String>,
+}
+
+impl AddRoleToDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.AddRoleToDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.AddRoleToDBClusterMessage.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddRoleToDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddRoleToDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(AddRoleToDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 /// Serialize `AddRoleToDBClusterMessage` contents to a `SignedRequest`.
 struct AddRoleToDBClusterMessageSerializer;
 impl AddRoleToDBClusterMessageSerializer {
@@ -66,6 +109,49 @@ pub struct AddSourceIdentifierToSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl AddSourceIdentifierToSubscriptionMessage {
+    /// Returns a builder for `AddSourceIdentifierToSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> AddSourceIdentifierToSubscriptionMessageBuilder {
+        AddSourceIdentifierToSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AddSourceIdentifierToSubscriptionMessage`](struct.AddSourceIdentifierToSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct AddSourceIdentifierToSubscriptionMessageBuilder {
+    source_identifier: Option<String>,
+    subscription_name: Option<String>,
+}
+
+impl AddSourceIdentifierToSubscriptionMessageBuilder {
+    /// Sets the [`source_identifier`](struct.AddSourceIdentifierToSubscriptionMessage.html#structfield.source_identifier) member.
+    pub fn source_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.AddSourceIdentifierToSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddSourceIdentifierToSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddSourceIdentifierToSubscriptionMessage, ::rusoto_core::BuildError>
+    {
+        Ok(AddSourceIdentifierToSubscriptionMessage {
+            source_identifier: self
+                .source_identifier
+                .ok_or(::rusoto_core::BuildError::missing("source_identifier"))?,
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+        })
+    }
+}
+
 /// Serialize `AddSourceIdentifierToSubscriptionMessage` contents to a `SignedRequest`.
 struct AddSourceIdentifierToSubscriptionMessageSerializer;
 impl AddSourceIdentifierToSubscriptionMessageSerializer {
@@ -91,10 +177,10 @@ pub struct AddSourceIdentifierToSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
 
-struct AddSourceIdentifierToSubscriptionResultDeserializer;
+pub(crate) struct AddSourceIdentifierToSubscriptionResultDeserializer;
 impl AddSourceIdentifierToSubscriptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AddSourceIdentifierToSubscriptionResult, XmlParseError> {
@@ -124,6 +210,48 @@ pub struct AddTagsToResourceMessage {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsToResourceMessage {
+    /// Returns a builder for `AddTagsToResourceMessage`, checking its required members when built.
+    pub fn builder() -> AddTagsToResourceMessageBuilder {
+        AddTagsToResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsToResourceMessage`](struct.AddTagsToResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct AddTagsToResourceMessageBuilder {
+    resource_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsToResourceMessageBuilder {
+    /// Sets the [`resource_name`](struct.AddTagsToResourceMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsToResourceMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsToResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddTagsToResourceMessage, ::rusoto_core::BuildError> {
+        Ok(AddTagsToResourceMessage {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `AddTagsToResourceMessage` contents to a `SignedRequest`.
 struct AddTagsToResourceMessageSerializer;
 impl AddTagsToResourceMessageSerializer {
@@ -138,17 +266,79 @@ impl AddTagsToResourceMessageSerializer {
     }
 }
 
-struct ApplyMethodDeserializer;
+pub(crate) struct ApplyMethodDeserializer;
 impl ApplyMethodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ApplyMethod, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>Indicates when to apply parameter updates.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApplyMethod {
+    /// <p><code>immediate</code></p>
+    Immediate,
+    /// <p><code>pending-reboot</code></p>
+    PendingReboot,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ApplyMethod {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplyMethod::Immediate => "immediate",
+            ApplyMethod::PendingReboot => "pending-reboot",
+            ApplyMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ApplyMethod {
+    fn from(value: &'a str) -> ApplyMethod {
+        match value {
+            "immediate" => ApplyMethod::Immediate,
+            "pending-reboot" => ApplyMethod::PendingReboot,
+            _ => ApplyMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplyMethod {
+    fn from(value: String) -> ApplyMethod {
+        ApplyMethod::from(value.as_str())
+    }
+}
+
+impl From<ApplyMethod> for String {
+    fn from(value: ApplyMethod) -> String {
+        match value {
+            ApplyMethod::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ApplyMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplyMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ApplyPendingMaintenanceActionMessage {
     /// <p>The pending maintenance action to apply to this resource.</p> <p>Valid values: <code>system-update</code>, <code>db-upgrade</code> </p>
@@ -159,6 +349,59 @@ pub struct ApplyPendingMaintenanceActionMessage {
     pub resource_identifier: String,
 }
 
+impl ApplyPendingMaintenanceActionMessage {
+    /// Returns a builder for `ApplyPendingMaintenanceActionMessage`, checking its required members when built.
+    pub fn builder() -> ApplyPendingMaintenanceActionMessageBuilder {
+        ApplyPendingMaintenanceActionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ApplyPendingMaintenanceActionMessage`](struct.ApplyPendingMaintenanceActionMessage.html).
+#[derive(Default, Debug)]
+pub struct ApplyPendingMaintenanceActionMessageBuilder {
+    apply_action: Option<String>,
+    opt_in_type: Option<String>,
+    resource_identifier: Option<String>,
+}
+
+impl ApplyPendingMaintenanceActionMessageBuilder {
+    /// Sets the [`apply_action`](struct.ApplyPendingMaintenanceActionMessage.html#structfield.apply_action) member.
+    pub fn apply_action<T: Into<String>>(mut self, value: T) -> Self {
+        self.apply_action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`opt_in_type`](struct.ApplyPendingMaintenanceActionMessage.html#structfield.opt_in_type) member.
+    pub fn opt_in_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.opt_in_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_identifier`](struct.ApplyPendingMaintenanceActionMessage.html#structfield.resource_identifier) member.
+    pub fn resource_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `ApplyPendingMaintenanceActionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ApplyPendingMaintenanceActionMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ApplyPendingMaintenanceActionMessage {
+            apply_action: self
+                .apply_action
+                .ok_or(::rusoto_core::BuildError::missing("apply_action"))?,
+            opt_in_type: self
+                .opt_in_type
+                .ok_or(::rusoto_core::BuildError::missing("opt_in_type"))?,
+            resource_identifier: self
+                .resource_identifier
+                .ok_or(::rusoto_core::BuildError::missing("resource_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `ApplyPendingMaintenanceActionMessage` contents to a `SignedRequest`.
 struct ApplyPendingMaintenanceActionMessageSerializer;
 impl ApplyPendingMaintenanceActionMessageSerializer {
@@ -182,10 +425,10 @@ pub struct ApplyPendingMaintenanceActionResult {
     pub resource_pending_maintenance_actions: Option<ResourcePendingMaintenanceActions>,
 }
 
-struct ApplyPendingMaintenanceActionResultDeserializer;
+pub(crate) struct ApplyPendingMaintenanceActionResultDeserializer;
 impl ApplyPendingMaintenanceActionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplyPendingMaintenanceActionResult, XmlParseError> {
@@ -208,10 +451,10 @@ impl ApplyPendingMaintenanceActionResultDeserializer {
         )
     }
 }
-struct AttributeValueListDeserializer;
+pub(crate) struct AttributeValueListDeserializer;
 impl AttributeValueListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -244,10 +487,10 @@ pub struct AvailabilityZone {
     pub name: Option<String>,
 }
 
-struct AvailabilityZoneDeserializer;
+pub(crate) struct AvailabilityZoneDeserializer;
 impl AvailabilityZoneDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AvailabilityZone, XmlParseError> {
@@ -262,10 +505,10 @@ impl AvailabilityZoneDeserializer {
         })
     }
 }
-struct AvailabilityZoneListDeserializer;
+pub(crate) struct AvailabilityZoneListDeserializer;
 impl AvailabilityZoneListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AvailabilityZone>, XmlParseError> {
@@ -282,10 +525,10 @@ impl AvailabilityZoneListDeserializer {
         })
     }
 }
-struct AvailabilityZonesDeserializer;
+pub(crate) struct AvailabilityZonesDeserializer;
 impl AvailabilityZonesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -311,10 +554,13 @@ impl AvailabilityZonesSerializer {
     }
 }
 
-struct BooleanDeserializer;
+pub(crate) struct BooleanDeserializer;
 impl BooleanDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -322,10 +568,13 @@ impl BooleanDeserializer {
         Ok(obj)
     }
 }
-struct BooleanOptionalDeserializer;
+pub(crate) struct BooleanOptionalDeserializer;
 impl BooleanOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -342,10 +591,10 @@ pub struct CharacterSet {
     pub character_set_name: Option<String>,
 }
 
-struct CharacterSetDeserializer;
+pub(crate) struct CharacterSetDeserializer;
 impl CharacterSetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CharacterSet, XmlParseError> {
@@ -414,6 +663,81 @@ pub struct CopyDBClusterParameterGroupMessage {
     pub target_db_cluster_parameter_group_identifier: String,
 }
 
+impl CopyDBClusterParameterGroupMessage {
+    /// Returns a builder for `CopyDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> CopyDBClusterParameterGroupMessageBuilder {
+        CopyDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CopyDBClusterParameterGroupMessage`](struct.CopyDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CopyDBClusterParameterGroupMessageBuilder {
+    source_db_cluster_parameter_group_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_db_cluster_parameter_group_description: Option<String>,
+    target_db_cluster_parameter_group_identifier: Option<String>,
+}
+
+impl CopyDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`source_db_cluster_parameter_group_identifier`](struct.CopyDBClusterParameterGroupMessage.html#structfield.source_db_cluster_parameter_group_identifier) member.
+    pub fn source_db_cluster_parameter_group_identifier<T: Into<String>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.source_db_cluster_parameter_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CopyDBClusterParameterGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_cluster_parameter_group_description`](struct.CopyDBClusterParameterGroupMessage.html#structfield.target_db_cluster_parameter_group_description) member.
+    pub fn target_db_cluster_parameter_group_description<T: Into<String>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.target_db_cluster_parameter_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_cluster_parameter_group_identifier`](struct.CopyDBClusterParameterGroupMessage.html#structfield.target_db_cluster_parameter_group_identifier) member.
+    pub fn target_db_cluster_parameter_group_identifier<T: Into<String>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.target_db_cluster_parameter_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CopyDBClusterParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CopyDBClusterParameterGroupMessage {
+            source_db_cluster_parameter_group_identifier: self
+                .source_db_cluster_parameter_group_identifier
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "source_db_cluster_parameter_group_identifier",
+                ))?,
+            tags: self.tags,
+            target_db_cluster_parameter_group_description: self
+                .target_db_cluster_parameter_group_description
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "target_db_cluster_parameter_group_description",
+                ))?,
+            target_db_cluster_parameter_group_identifier: self
+                .target_db_cluster_parameter_group_identifier
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "target_db_cluster_parameter_group_identifier",
+                ))?,
+        })
+    }
+}
+
 /// Serialize `CopyDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct CopyDBClusterParameterGroupMessageSerializer;
 impl CopyDBClusterParameterGroupMessageSerializer {
@@ -446,10 +770,10 @@ pub struct CopyDBClusterParameterGroupResult {
     pub db_cluster_parameter_group: Option<DBClusterParameterGroup>,
 }
 
-struct CopyDBClusterParameterGroupResultDeserializer;
+pub(crate) struct CopyDBClusterParameterGroupResultDeserializer;
 impl CopyDBClusterParameterGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CopyDBClusterParameterGroupResult, XmlParseError> {
@@ -488,6 +812,84 @@ pub struct CopyDBClusterSnapshotMessage {
     pub target_db_cluster_snapshot_identifier: String,
 }
 
+impl CopyDBClusterSnapshotMessage {
+    /// Returns a builder for `CopyDBClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> CopyDBClusterSnapshotMessageBuilder {
+        CopyDBClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CopyDBClusterSnapshotMessage`](struct.CopyDBClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct CopyDBClusterSnapshotMessageBuilder {
+    copy_tags: Option<bool>,
+    kms_key_id: Option<String>,
+    pre_signed_url: Option<String>,
+    source_db_cluster_snapshot_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_db_cluster_snapshot_identifier: Option<String>,
+}
+
+impl CopyDBClusterSnapshotMessageBuilder {
+    /// Sets the [`copy_tags`](struct.CopyDBClusterSnapshotMessage.html#structfield.copy_tags) member.
+    pub fn copy_tags<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CopyDBClusterSnapshotMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pre_signed_url`](struct.CopyDBClusterSnapshotMessage.html#structfield.pre_signed_url) member.
+    pub fn pre_signed_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.pre_signed_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_db_cluster_snapshot_identifier`](struct.CopyDBClusterSnapshotMessage.html#structfield.source_db_cluster_snapshot_identifier) member.
+    pub fn source_db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CopyDBClusterSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_cluster_snapshot_identifier`](struct.CopyDBClusterSnapshotMessage.html#structfield.target_db_cluster_snapshot_identifier) member.
+    pub fn target_db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyDBClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CopyDBClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(CopyDBClusterSnapshotMessage {
+            copy_tags: self.copy_tags,
+            kms_key_id: self.kms_key_id,
+            pre_signed_url: self.pre_signed_url,
+            source_db_cluster_snapshot_identifier: self
+                .source_db_cluster_snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "source_db_cluster_snapshot_identifier",
+                ))?,
+            tags: self.tags,
+            target_db_cluster_snapshot_identifier: self
+                .target_db_cluster_snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "target_db_cluster_snapshot_identifier",
+                ))?,
+        })
+    }
+}
+
 /// Serialize `CopyDBClusterSnapshotMessage` contents to a `SignedRequest`.
 struct CopyDBClusterSnapshotMessageSerializer;
 impl CopyDBClusterSnapshotMessageSerializer {
@@ -525,10 +927,10 @@ pub struct CopyDBClusterSnapshotResult {
     pub db_cluster_snapshot: Option<DBClusterSnapshot>,
 }
 
-struct CopyDBClusterSnapshotResultDeserializer;
+pub(crate) struct CopyDBClusterSnapshotResultDeserializer;
 impl CopyDBClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CopyDBClusterSnapshotResult, XmlParseError> {
@@ -562,6 +964,68 @@ pub struct CopyDBParameterGroupMessage {
     pub target_db_parameter_group_identifier: String,
 }
 
+impl CopyDBParameterGroupMessage {
+    /// Returns a builder for `CopyDBParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> CopyDBParameterGroupMessageBuilder {
+        CopyDBParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CopyDBParameterGroupMessage`](struct.CopyDBParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CopyDBParameterGroupMessageBuilder {
+    source_db_parameter_group_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_db_parameter_group_description: Option<String>,
+    target_db_parameter_group_identifier: Option<String>,
+}
+
+impl CopyDBParameterGroupMessageBuilder {
+    /// Sets the [`source_db_parameter_group_identifier`](struct.CopyDBParameterGroupMessage.html#structfield.source_db_parameter_group_identifier) member.
+    pub fn source_db_parameter_group_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_db_parameter_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CopyDBParameterGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_parameter_group_description`](struct.CopyDBParameterGroupMessage.html#structfield.target_db_parameter_group_description) member.
+    pub fn target_db_parameter_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_db_parameter_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_parameter_group_identifier`](struct.CopyDBParameterGroupMessage.html#structfield.target_db_parameter_group_identifier) member.
+    pub fn target_db_parameter_group_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_db_parameter_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyDBParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CopyDBParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CopyDBParameterGroupMessage {
+            source_db_parameter_group_identifier: self.source_db_parameter_group_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_db_parameter_group_identifier"),
+            )?,
+            tags: self.tags,
+            target_db_parameter_group_description: self
+                .target_db_parameter_group_description
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "target_db_parameter_group_description",
+                ))?,
+            target_db_parameter_group_identifier: self.target_db_parameter_group_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("target_db_parameter_group_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `CopyDBParameterGroupMessage` contents to a `SignedRequest`.
 struct CopyDBParameterGroupMessageSerializer;
 impl CopyDBParameterGroupMessageSerializer {
@@ -594,10 +1058,10 @@ pub struct CopyDBParameterGroupResult {
     pub db_parameter_group: Option<DBParameterGroup>,
 }
 
-struct CopyDBParameterGroupResultDeserializer;
+pub(crate) struct CopyDBParameterGroupResultDeserializer;
 impl CopyDBParameterGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CopyDBParameterGroupResult, XmlParseError> {
@@ -669,6 +1133,214 @@ pub struct CreateDBClusterMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl CreateDBClusterMessage {
+    /// Returns a builder for `CreateDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBClusterMessageBuilder {
+        CreateDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBClusterMessage`](struct.CreateDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBClusterMessageBuilder {
+    availability_zones: Option<Vec<String>>,
+    backup_retention_period: Option<i64>,
+    character_set_name: Option<String>,
+    db_cluster_identifier: Option<String>,
+    db_cluster_parameter_group_name: Option<String>,
+    db_subnet_group_name: Option<String>,
+    database_name: Option<String>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_iam_database_authentication: Option<bool>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    kms_key_id: Option<String>,
+    master_user_password: Option<String>,
+    master_username: Option<String>,
+    option_group_name: Option<String>,
+    port: Option<i64>,
+    pre_signed_url: Option<String>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    replication_source_identifier: Option<String>,
+    storage_encrypted: Option<bool>,
+    tags: Option<Vec<Tag>>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl CreateDBClusterMessageBuilder {
+    /// Sets the [`availability_zones`](struct.CreateDBClusterMessage.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_period`](struct.CreateDBClusterMessage.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`character_set_name`](struct.CreateDBClusterMessage.html#structfield.character_set_name) member.
+    pub fn character_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.character_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.CreateDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_parameter_group_name`](struct.CreateDBClusterMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.CreateDBClusterMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.CreateDBClusterMessage.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.CreateDBClusterMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.CreateDBClusterMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.CreateDBClusterMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.CreateDBClusterMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateDBClusterMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.CreateDBClusterMessage.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_username`](struct.CreateDBClusterMessage.html#structfield.master_username) member.
+    pub fn master_username<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_username = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.CreateDBClusterMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.CreateDBClusterMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pre_signed_url`](struct.CreateDBClusterMessage.html#structfield.pre_signed_url) member.
+    pub fn pre_signed_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.pre_signed_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.CreateDBClusterMessage.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateDBClusterMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_source_identifier`](struct.CreateDBClusterMessage.html#structfield.replication_source_identifier) member.
+    pub fn replication_source_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_encrypted`](struct.CreateDBClusterMessage.html#structfield.storage_encrypted) member.
+    pub fn storage_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.storage_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBClusterMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.CreateDBClusterMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBClusterMessage {
+            availability_zones: self.availability_zones,
+            backup_retention_period: self.backup_retention_period,
+            character_set_name: self.character_set_name,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            db_subnet_group_name: self.db_subnet_group_name,
+            database_name: self.database_name,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_version: self.engine_version,
+            kms_key_id: self.kms_key_id,
+            master_user_password: self.master_user_password,
+            master_username: self.master_username,
+            option_group_name: self.option_group_name,
+            port: self.port,
+            pre_signed_url: self.pre_signed_url,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            replication_source_identifier: self.replication_source_identifier,
+            storage_encrypted: self.storage_encrypted,
+            tags: self.tags,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `CreateDBClusterMessage` contents to a `SignedRequest`.
 struct CreateDBClusterMessageSerializer;
 impl CreateDBClusterMessageSerializer {
@@ -791,6 +1463,67 @@ pub struct CreateDBClusterParameterGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBClusterParameterGroupMessage {
+    /// Returns a builder for `CreateDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBClusterParameterGroupMessageBuilder {
+        CreateDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBClusterParameterGroupMessage`](struct.CreateDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBClusterParameterGroupMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    db_parameter_group_family: Option<String>,
+    description: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.CreateDBClusterParameterGroupMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_family`](struct.CreateDBClusterParameterGroupMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateDBClusterParameterGroupMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBClusterParameterGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBClusterParameterGroupMessage, ::rusoto_core::BuildError>
+    {
+        Ok(CreateDBClusterParameterGroupMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+            db_parameter_group_family: self.db_parameter_group_family.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_family"),
+            )?,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct CreateDBClusterParameterGroupMessageSerializer;
 impl CreateDBClusterParameterGroupMessageSerializer {
@@ -820,10 +1553,10 @@ pub struct CreateDBClusterParameterGroupResult {
     pub db_cluster_parameter_group: Option<DBClusterParameterGroup>,
 }
 
-struct CreateDBClusterParameterGroupResultDeserializer;
+pub(crate) struct CreateDBClusterParameterGroupResultDeserializer;
 impl CreateDBClusterParameterGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBClusterParameterGroupResult, XmlParseError> {
@@ -851,10 +1584,10 @@ pub struct CreateDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct CreateDBClusterResultDeserializer;
+pub(crate) struct CreateDBClusterResultDeserializer;
 impl CreateDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBClusterResult, XmlParseError> {
@@ -879,6 +1612,56 @@ pub struct CreateDBClusterSnapshotMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBClusterSnapshotMessage {
+    /// Returns a builder for `CreateDBClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBClusterSnapshotMessageBuilder {
+        CreateDBClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBClusterSnapshotMessage`](struct.CreateDBClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBClusterSnapshotMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    db_cluster_snapshot_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBClusterSnapshotMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.CreateDBClusterSnapshotMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.CreateDBClusterSnapshotMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBClusterSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBClusterSnapshotMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_snapshot_identifier"),
+            )?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBClusterSnapshotMessage` contents to a `SignedRequest`.
 struct CreateDBClusterSnapshotMessageSerializer;
 impl CreateDBClusterSnapshotMessageSerializer {
@@ -907,10 +1690,10 @@ pub struct CreateDBClusterSnapshotResult {
     pub db_cluster_snapshot: Option<DBClusterSnapshot>,
 }
 
-struct CreateDBClusterSnapshotResultDeserializer;
+pub(crate) struct CreateDBClusterSnapshotResultDeserializer;
 impl CreateDBClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBClusterSnapshotResult, XmlParseError> {
@@ -1018,45 +1801,401 @@ pub struct CreateDBInstanceMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
-/// Serialize `CreateDBInstanceMessage` contents to a `SignedRequest`.
-struct CreateDBInstanceMessageSerializer;
-impl CreateDBInstanceMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &CreateDBInstanceMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl CreateDBInstanceMessage {
+    /// Returns a builder for `CreateDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBInstanceMessageBuilder {
+        CreateDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBInstanceMessage`](struct.CreateDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBInstanceMessageBuilder {
+    allocated_storage: Option<i64>,
+    auto_minor_version_upgrade: Option<bool>,
+    availability_zone: Option<String>,
+    backup_retention_period: Option<i64>,
+    character_set_name: Option<String>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_cluster_identifier: Option<String>,
+    db_instance_class: Option<String>,
+    db_instance_identifier: Option<String>,
+    db_name: Option<String>,
+    db_parameter_group_name: Option<String>,
+    db_security_groups: Option<Vec<String>>,
+    db_subnet_group_name: Option<String>,
+    domain: Option<String>,
+    domain_iam_role_name: Option<String>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_iam_database_authentication: Option<bool>,
+    enable_performance_insights: Option<bool>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    iops: Option<i64>,
+    kms_key_id: Option<String>,
+    license_model: Option<String>,
+    master_user_password: Option<String>,
+    master_username: Option<String>,
+    monitoring_interval: Option<i64>,
+    monitoring_role_arn: Option<String>,
+    multi_az: Option<bool>,
+    option_group_name: Option<String>,
+    performance_insights_kms_key_id: Option<String>,
+    port: Option<i64>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    promotion_tier: Option<i64>,
+    storage_encrypted: Option<bool>,
+    storage_type: Option<String>,
+    tags: Option<Vec<Tag>>,
+    tde_credential_arn: Option<String>,
+    tde_credential_password: Option<String>,
+    timezone: Option<String>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
 
-        if let Some(ref field_value) = obj.allocated_storage {
-            params.put(&format!("{}{}", prefix, "AllocatedStorage"), &field_value);
-        }
-        if let Some(ref field_value) = obj.auto_minor_version_upgrade {
-            params.put(
-                &format!("{}{}", prefix, "AutoMinorVersionUpgrade"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.availability_zone {
-            params.put(&format!("{}{}", prefix, "AvailabilityZone"), &field_value);
-        }
-        if let Some(ref field_value) = obj.backup_retention_period {
-            params.put(
-                &format!("{}{}", prefix, "BackupRetentionPeriod"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.character_set_name {
-            params.put(&format!("{}{}", prefix, "CharacterSetName"), &field_value);
-        }
-        if let Some(ref field_value) = obj.copy_tags_to_snapshot {
-            params.put(&format!("{}{}", prefix, "CopyTagsToSnapshot"), &field_value);
-        }
-        if let Some(ref field_value) = obj.db_cluster_identifier {
-            params.put(
-                &format!("{}{}", prefix, "DBClusterIdentifier"),
-                &field_value,
-            );
-        }
+impl CreateDBInstanceMessageBuilder {
+    /// Sets the [`allocated_storage`](struct.CreateDBInstanceMessage.html#structfield.allocated_storage) member.
+    pub fn allocated_storage<T: Into<i64>>(mut self, value: T) -> Self {
+        self.allocated_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_minor_version_upgrade`](struct.CreateDBInstanceMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zone`](struct.CreateDBInstanceMessage.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_period`](struct.CreateDBInstanceMessage.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`character_set_name`](struct.CreateDBInstanceMessage.html#structfield.character_set_name) member.
+    pub fn character_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.character_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.CreateDBInstanceMessage.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.CreateDBInstanceMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_class`](struct.CreateDBInstanceMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_identifier`](struct.CreateDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_name`](struct.CreateDBInstanceMessage.html#structfield.db_name) member.
+    pub fn db_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_name`](struct.CreateDBInstanceMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_security_groups`](struct.CreateDBInstanceMessage.html#structfield.db_security_groups) member.
+    pub fn db_security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.db_security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.CreateDBInstanceMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.CreateDBInstanceMessage.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_iam_role_name`](struct.CreateDBInstanceMessage.html#structfield.domain_iam_role_name) member.
+    pub fn domain_iam_role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_iam_role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.CreateDBInstanceMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.CreateDBInstanceMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_performance_insights`](struct.CreateDBInstanceMessage.html#structfield.enable_performance_insights) member.
+    pub fn enable_performance_insights<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_performance_insights = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.CreateDBInstanceMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.CreateDBInstanceMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iops`](struct.CreateDBInstanceMessage.html#structfield.iops) member.
+    pub fn iops<T: Into<i64>>(mut self, value: T) -> Self {
+        self.iops = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateDBInstanceMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_model`](struct.CreateDBInstanceMessage.html#structfield.license_model) member.
+    pub fn license_model<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_model = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.CreateDBInstanceMessage.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_username`](struct.CreateDBInstanceMessage.html#structfield.master_username) member.
+    pub fn master_username<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_username = Some(value.into());
+        self
+    }
+
+    /// Sets the [`monitoring_interval`](struct.CreateDBInstanceMessage.html#structfield.monitoring_interval) member.
+    pub fn monitoring_interval<T: Into<i64>>(mut self, value: T) -> Self {
+        self.monitoring_interval = Some(value.into());
+        self
+    }
+
+    /// Sets the [`monitoring_role_arn`](struct.CreateDBInstanceMessage.html#structfield.monitoring_role_arn) member.
+    pub fn monitoring_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.monitoring_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.CreateDBInstanceMessage.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.CreateDBInstanceMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`performance_insights_kms_key_id`](struct.CreateDBInstanceMessage.html#structfield.performance_insights_kms_key_id) member.
+    pub fn performance_insights_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.performance_insights_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.CreateDBInstanceMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.CreateDBInstanceMessage.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateDBInstanceMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`promotion_tier`](struct.CreateDBInstanceMessage.html#structfield.promotion_tier) member.
+    pub fn promotion_tier<T: Into<i64>>(mut self, value: T) -> Self {
+        self.promotion_tier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_encrypted`](struct.CreateDBInstanceMessage.html#structfield.storage_encrypted) member.
+    pub fn storage_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.storage_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_type`](struct.CreateDBInstanceMessage.html#structfield.storage_type) member.
+    pub fn storage_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.storage_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBInstanceMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tde_credential_arn`](struct.CreateDBInstanceMessage.html#structfield.tde_credential_arn) member.
+    pub fn tde_credential_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tde_credential_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tde_credential_password`](struct.CreateDBInstanceMessage.html#structfield.tde_credential_password) member.
+    pub fn tde_credential_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.tde_credential_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timezone`](struct.CreateDBInstanceMessage.html#structfield.timezone) member.
+    pub fn timezone<T: Into<String>>(mut self, value: T) -> Self {
+        self.timezone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.CreateDBInstanceMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBInstanceMessage {
+            allocated_storage: self.allocated_storage,
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            availability_zone: self.availability_zone,
+            backup_retention_period: self.backup_retention_period,
+            character_set_name: self.character_set_name,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_cluster_identifier: self.db_cluster_identifier,
+            db_instance_class: self
+                .db_instance_class
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_class"))?,
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            db_name: self.db_name,
+            db_parameter_group_name: self.db_parameter_group_name,
+            db_security_groups: self.db_security_groups,
+            db_subnet_group_name: self.db_subnet_group_name,
+            domain: self.domain,
+            domain_iam_role_name: self.domain_iam_role_name,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            enable_performance_insights: self.enable_performance_insights,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_version: self.engine_version,
+            iops: self.iops,
+            kms_key_id: self.kms_key_id,
+            license_model: self.license_model,
+            master_user_password: self.master_user_password,
+            master_username: self.master_username,
+            monitoring_interval: self.monitoring_interval,
+            monitoring_role_arn: self.monitoring_role_arn,
+            multi_az: self.multi_az,
+            option_group_name: self.option_group_name,
+            performance_insights_kms_key_id: self.performance_insights_kms_key_id,
+            port: self.port,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            promotion_tier: self.promotion_tier,
+            storage_encrypted: self.storage_encrypted,
+            storage_type: self.storage_type,
+            tags: self.tags,
+            tde_credential_arn: self.tde_credential_arn,
+            tde_credential_password: self.tde_credential_password,
+            timezone: self.timezone,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
+/// Serialize `CreateDBInstanceMessage` contents to a `SignedRequest`.
+struct CreateDBInstanceMessageSerializer;
+impl CreateDBInstanceMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &CreateDBInstanceMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.allocated_storage {
+            params.put(&format!("{}{}", prefix, "AllocatedStorage"), &field_value);
+        }
+        if let Some(ref field_value) = obj.auto_minor_version_upgrade {
+            params.put(
+                &format!("{}{}", prefix, "AutoMinorVersionUpgrade"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.availability_zone {
+            params.put(&format!("{}{}", prefix, "AvailabilityZone"), &field_value);
+        }
+        if let Some(ref field_value) = obj.backup_retention_period {
+            params.put(
+                &format!("{}{}", prefix, "BackupRetentionPeriod"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.character_set_name {
+            params.put(&format!("{}{}", prefix, "CharacterSetName"), &field_value);
+        }
+        if let Some(ref field_value) = obj.copy_tags_to_snapshot {
+            params.put(&format!("{}{}", prefix, "CopyTagsToSnapshot"), &field_value);
+        }
+        if let Some(ref field_value) = obj.db_cluster_identifier {
+            params.put(
+                &format!("{}{}", prefix, "DBClusterIdentifier"),
+                &field_value,
+            );
+        }
         params.put(
             &format!("{}{}", prefix, "DBInstanceClass"),
             &obj.db_instance_class,
@@ -1201,10 +2340,10 @@ pub struct CreateDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct CreateDBInstanceResultDeserializer;
+pub(crate) struct CreateDBInstanceResultDeserializer;
 impl CreateDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBInstanceResult, XmlParseError> {
@@ -1232,6 +2371,66 @@ pub struct CreateDBParameterGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBParameterGroupMessage {
+    /// Returns a builder for `CreateDBParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBParameterGroupMessageBuilder {
+        CreateDBParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBParameterGroupMessage`](struct.CreateDBParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBParameterGroupMessageBuilder {
+    db_parameter_group_family: Option<String>,
+    db_parameter_group_name: Option<String>,
+    description: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBParameterGroupMessageBuilder {
+    /// Sets the [`db_parameter_group_family`](struct.CreateDBParameterGroupMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_name`](struct.CreateDBParameterGroupMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateDBParameterGroupMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBParameterGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBParameterGroupMessage {
+            db_parameter_group_family: self.db_parameter_group_family.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_family"),
+            )?,
+            db_parameter_group_name: self.db_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_name"),
+            )?,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBParameterGroupMessage` contents to a `SignedRequest`.
 struct CreateDBParameterGroupMessageSerializer;
 impl CreateDBParameterGroupMessageSerializer {
@@ -1261,10 +2460,10 @@ pub struct CreateDBParameterGroupResult {
     pub db_parameter_group: Option<DBParameterGroup>,
 }
 
-struct CreateDBParameterGroupResultDeserializer;
+pub(crate) struct CreateDBParameterGroupResultDeserializer;
 impl CreateDBParameterGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBParameterGroupResult, XmlParseError> {
@@ -1298,6 +2497,66 @@ pub struct CreateDBSubnetGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBSubnetGroupMessage {
+    /// Returns a builder for `CreateDBSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBSubnetGroupMessageBuilder {
+        CreateDBSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBSubnetGroupMessage`](struct.CreateDBSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBSubnetGroupMessageBuilder {
+    db_subnet_group_description: Option<String>,
+    db_subnet_group_name: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBSubnetGroupMessageBuilder {
+    /// Sets the [`db_subnet_group_description`](struct.CreateDBSubnetGroupMessage.html#structfield.db_subnet_group_description) member.
+    pub fn db_subnet_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.CreateDBSubnetGroupMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.CreateDBSubnetGroupMessage.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBSubnetGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBSubnetGroupMessage {
+            db_subnet_group_description: self.db_subnet_group_description.ok_or(
+                ::rusoto_core::BuildError::missing("db_subnet_group_description"),
+            )?,
+            db_subnet_group_name: self
+                .db_subnet_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_subnet_group_name"))?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBSubnetGroupMessage` contents to a `SignedRequest`.
 struct CreateDBSubnetGroupMessageSerializer;
 impl CreateDBSubnetGroupMessageSerializer {
@@ -1331,10 +2590,10 @@ pub struct CreateDBSubnetGroupResult {
     pub db_subnet_group: Option<DBSubnetGroup>,
 }
 
-struct CreateDBSubnetGroupResultDeserializer;
+pub(crate) struct CreateDBSubnetGroupResultDeserializer;
 impl CreateDBSubnetGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBSubnetGroupResult, XmlParseError> {
@@ -1374,6 +2633,88 @@ pub struct CreateEventSubscriptionMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateEventSubscriptionMessage {
+    /// Returns a builder for `CreateEventSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> CreateEventSubscriptionMessageBuilder {
+        CreateEventSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateEventSubscriptionMessage`](struct.CreateEventSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateEventSubscriptionMessageBuilder {
+    enabled: Option<bool>,
+    event_categories: Option<Vec<String>>,
+    sns_topic_arn: Option<String>,
+    source_ids: Option<Vec<String>>,
+    source_type: Option<String>,
+    subscription_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateEventSubscriptionMessageBuilder {
+    /// Sets the [`enabled`](struct.CreateEventSubscriptionMessage.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_categories`](struct.CreateEventSubscriptionMessage.html#structfield.event_categories) member.
+    pub fn event_categories<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_categories = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.CreateEventSubscriptionMessage.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_ids`](struct.CreateEventSubscriptionMessage.html#structfield.source_ids) member.
+    pub fn source_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.source_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.CreateEventSubscriptionMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.CreateEventSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateEventSubscriptionMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateEventSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateEventSubscriptionMessage, ::rusoto_core::BuildError> {
+        Ok(CreateEventSubscriptionMessage {
+            enabled: self.enabled,
+            event_categories: self.event_categories,
+            sns_topic_arn: self
+                .sns_topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("sns_topic_arn"))?,
+            source_ids: self.source_ids,
+            source_type: self.source_type,
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateEventSubscriptionMessage` contents to a `SignedRequest`.
 struct CreateEventSubscriptionMessageSerializer;
 impl CreateEventSubscriptionMessageSerializer {
@@ -1419,10 +2760,10 @@ pub struct CreateEventSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
 
-struct CreateEventSubscriptionResultDeserializer;
+pub(crate) struct CreateEventSubscriptionResultDeserializer;
 impl CreateEventSubscriptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateEventSubscriptionResult, XmlParseError> {
@@ -1460,7 +2801,7 @@ pub struct DBCluster {
     /// <p>Identifies the clone group to which the DB cluster is associated.</p>
     pub clone_group_id: Option<String>,
     /// <p>Specifies the time when the DB cluster was created, in Universal Coordinated Time (UTC).</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<IsoTimestamp>,
     /// <p>The Amazon Resource Name (ARN) for the DB cluster.</p>
     pub db_cluster_arn: Option<String>,
     /// <p>Contains a user-supplied DB cluster identifier. This identifier is the unique key that identifies a DB cluster.</p>
@@ -1478,7 +2819,7 @@ pub struct DBCluster {
     /// <p>The AWS Region-unique, immutable identifier for the DB cluster. This identifier is found in AWS CloudTrail log entries whenever the AWS KMS key for the DB cluster is accessed.</p>
     pub db_cluster_resource_id: Option<String>,
     /// <p>Specifies the earliest time to which a database can be restored with point-in-time restore.</p>
-    pub earliest_restorable_time: Option<String>,
+    pub earliest_restorable_time: Option<IsoTimestamp>,
     /// <p>A list of log types that this DB cluster is configured to export to CloudWatch Logs.</p>
     pub enabled_cloudwatch_logs_exports: Option<Vec<String>>,
     /// <p>Specifies the connection endpoint for the primary instance of the DB cluster.</p>
@@ -1494,7 +2835,7 @@ pub struct DBCluster {
     /// <p>If <code>StorageEncrypted</code> is true, the AWS KMS key identifier for the encrypted DB cluster.</p>
     pub kms_key_id: Option<String>,
     /// <p>Specifies the latest time to which a database can be restored with point-in-time restore.</p>
-    pub latest_restorable_time: Option<String>,
+    pub latest_restorable_time: Option<IsoTimestamp>,
     /// <p>Contains the master username for the DB cluster.</p>
     pub master_username: Option<String>,
     /// <p>Specifies whether the DB cluster has instances in multiple Availability Zones.</p>
@@ -1521,10 +2862,10 @@ pub struct DBCluster {
     pub vpc_security_groups: Option<Vec<VpcSecurityGroupMembership>>,
 }
 
-struct DBClusterDeserializer;
+pub(crate) struct DBClusterDeserializer;
 impl DBClusterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBCluster, XmlParseError> {
@@ -1716,10 +3057,10 @@ impl DBClusterDeserializer {
         })
     }
 }
-struct DBClusterListDeserializer;
+pub(crate) struct DBClusterListDeserializer;
 impl DBClusterListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBCluster>, XmlParseError> {
@@ -1746,10 +3087,10 @@ pub struct DBClusterMember {
     pub promotion_tier: Option<i64>,
 }
 
-struct DBClusterMemberDeserializer;
+pub(crate) struct DBClusterMemberDeserializer;
 impl DBClusterMemberDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterMember, XmlParseError> {
@@ -1783,10 +3124,10 @@ impl DBClusterMemberDeserializer {
         })
     }
 }
-struct DBClusterMemberListDeserializer;
+pub(crate) struct DBClusterMemberListDeserializer;
 impl DBClusterMemberListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterMember>, XmlParseError> {
@@ -1811,10 +3152,10 @@ pub struct DBClusterMessage {
     pub marker: Option<String>,
 }
 
-struct DBClusterMessageDeserializer;
+pub(crate) struct DBClusterMessageDeserializer;
 impl DBClusterMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterMessage, XmlParseError> {
@@ -1834,10 +3175,10 @@ impl DBClusterMessageDeserializer {
         })
     }
 }
-struct DBClusterOptionGroupMembershipsDeserializer;
+pub(crate) struct DBClusterOptionGroupMembershipsDeserializer;
 impl DBClusterOptionGroupMembershipsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterOptionGroupStatus>, XmlParseError> {
@@ -1863,10 +3204,10 @@ pub struct DBClusterOptionGroupStatus {
     pub status: Option<String>,
 }
 
-struct DBClusterOptionGroupStatusDeserializer;
+pub(crate) struct DBClusterOptionGroupStatusDeserializer;
 impl DBClusterOptionGroupStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterOptionGroupStatus, XmlParseError> {
@@ -1904,10 +3245,10 @@ pub struct DBClusterParameterGroup {
     pub description: Option<String>,
 }
 
-struct DBClusterParameterGroupDeserializer;
+pub(crate) struct DBClusterParameterGroupDeserializer;
 impl DBClusterParameterGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterParameterGroup, XmlParseError> {
@@ -1952,10 +3293,10 @@ pub struct DBClusterParameterGroupDetails {
     pub parameters: Option<Vec<Parameter>>,
 }
 
-struct DBClusterParameterGroupDetailsDeserializer;
+pub(crate) struct DBClusterParameterGroupDetailsDeserializer;
 impl DBClusterParameterGroupDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterParameterGroupDetails, XmlParseError> {
@@ -1979,10 +3320,10 @@ impl DBClusterParameterGroupDetailsDeserializer {
         )
     }
 }
-struct DBClusterParameterGroupListDeserializer;
+pub(crate) struct DBClusterParameterGroupListDeserializer;
 impl DBClusterParameterGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterParameterGroup>, XmlParseError> {
@@ -2005,10 +3346,10 @@ pub struct DBClusterParameterGroupNameMessage {
     pub db_cluster_parameter_group_name: Option<String>,
 }
 
-struct DBClusterParameterGroupNameMessageDeserializer;
+pub(crate) struct DBClusterParameterGroupNameMessageDeserializer;
 impl DBClusterParameterGroupNameMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterParameterGroupNameMessage, XmlParseError> {
@@ -2037,10 +3378,10 @@ pub struct DBClusterParameterGroupsMessage {
     pub marker: Option<String>,
 }
 
-struct DBClusterParameterGroupsMessageDeserializer;
+pub(crate) struct DBClusterParameterGroupsMessageDeserializer;
 impl DBClusterParameterGroupsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterParameterGroupsMessage, XmlParseError> {
@@ -2076,10 +3417,10 @@ pub struct DBClusterRole {
     pub status: Option<String>,
 }
 
-struct DBClusterRoleDeserializer;
+pub(crate) struct DBClusterRoleDeserializer;
 impl DBClusterRoleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterRole, XmlParseError> {
@@ -2097,10 +3438,10 @@ impl DBClusterRoleDeserializer {
         })
     }
 }
-struct DBClusterRolesDeserializer;
+pub(crate) struct DBClusterRolesDeserializer;
 impl DBClusterRolesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterRole>, XmlParseError> {
@@ -2125,7 +3466,7 @@ pub struct DBClusterSnapshot {
     /// <p>Provides the list of EC2 Availability Zones that instances in the DB cluster snapshot can be restored in.</p>
     pub availability_zones: Option<Vec<String>>,
     /// <p>Specifies the time when the DB cluster was created, in Universal Coordinated Time (UTC).</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<IsoTimestamp>,
     /// <p>Specifies the DB cluster identifier of the DB cluster that this DB cluster snapshot was created from.</p>
     pub db_cluster_identifier: Option<String>,
     /// <p>The Amazon Resource Name (ARN) for the DB cluster snapshot.</p>
@@ -2149,7 +3490,7 @@ pub struct DBClusterSnapshot {
     /// <p>Specifies the port that the DB cluster was listening on at the time of the snapshot.</p>
     pub port: Option<i64>,
     /// <p>Provides the time when the snapshot was taken, in Universal Coordinated Time (UTC).</p>
-    pub snapshot_create_time: Option<String>,
+    pub snapshot_create_time: Option<IsoTimestamp>,
     /// <p>Provides the type of the DB cluster snapshot.</p>
     pub snapshot_type: Option<String>,
     /// <p>If the DB cluster snapshot was copied from a source DB cluster snapshot, the Amazon Resource Name (ARN) for the source DB cluster snapshot, otherwise, a null value.</p>
@@ -2162,10 +3503,10 @@ pub struct DBClusterSnapshot {
     pub vpc_id: Option<String>,
 }
 
-struct DBClusterSnapshotDeserializer;
+pub(crate) struct DBClusterSnapshotDeserializer;
 impl DBClusterSnapshotDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterSnapshot, XmlParseError> {
@@ -2275,10 +3616,10 @@ pub struct DBClusterSnapshotAttribute {
     pub attribute_values: Option<Vec<String>>,
 }
 
-struct DBClusterSnapshotAttributeDeserializer;
+pub(crate) struct DBClusterSnapshotAttributeDeserializer;
 impl DBClusterSnapshotAttributeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterSnapshotAttribute, XmlParseError> {
@@ -2303,10 +3644,10 @@ impl DBClusterSnapshotAttributeDeserializer {
         )
     }
 }
-struct DBClusterSnapshotAttributeListDeserializer;
+pub(crate) struct DBClusterSnapshotAttributeListDeserializer;
 impl DBClusterSnapshotAttributeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterSnapshotAttribute>, XmlParseError> {
@@ -2332,10 +3673,10 @@ pub struct DBClusterSnapshotAttributesResult {
     pub db_cluster_snapshot_identifier: Option<String>,
 }
 
-struct DBClusterSnapshotAttributesResultDeserializer;
+pub(crate) struct DBClusterSnapshotAttributesResultDeserializer;
 impl DBClusterSnapshotAttributesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterSnapshotAttributesResult, XmlParseError> {
@@ -2365,10 +3706,10 @@ impl DBClusterSnapshotAttributesResultDeserializer {
         )
     }
 }
-struct DBClusterSnapshotListDeserializer;
+pub(crate) struct DBClusterSnapshotListDeserializer;
 impl DBClusterSnapshotListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterSnapshot>, XmlParseError> {
@@ -2393,10 +3734,10 @@ pub struct DBClusterSnapshotMessage {
     pub marker: Option<String>,
 }
 
-struct DBClusterSnapshotMessageDeserializer;
+pub(crate) struct DBClusterSnapshotMessageDeserializer;
 impl DBClusterSnapshotMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterSnapshotMessage, XmlParseError> {
@@ -2452,10 +3793,10 @@ pub struct DBEngineVersion {
     pub valid_upgrade_target: Option<Vec<UpgradeTarget>>,
 }
 
-struct DBEngineVersionDeserializer;
+pub(crate) struct DBEngineVersionDeserializer;
 impl DBEngineVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBEngineVersion, XmlParseError> {
@@ -2540,10 +3881,10 @@ impl DBEngineVersionDeserializer {
         })
     }
 }
-struct DBEngineVersionListDeserializer;
+pub(crate) struct DBEngineVersionListDeserializer;
 impl DBEngineVersionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBEngineVersion>, XmlParseError> {
@@ -2568,10 +3909,10 @@ pub struct DBEngineVersionMessage {
     pub marker: Option<String>,
 }
 
-struct DBEngineVersionMessageDeserializer;
+pub(crate) struct DBEngineVersionMessageDeserializer;
 impl DBEngineVersionMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBEngineVersionMessage, XmlParseError> {
@@ -2645,13 +3986,13 @@ pub struct DBInstance {
     /// <p>True if AWS Identity and Access Management (IAM) authentication is enabled, and otherwise false.</p>
     pub iam_database_authentication_enabled: Option<bool>,
     /// <p>Provides the date and time the DB instance was created.</p>
-    pub instance_create_time: Option<String>,
+    pub instance_create_time: Option<IsoTimestamp>,
     /// <p>Specifies the Provisioned IOPS (I/O operations per second) value.</p>
     pub iops: Option<i64>,
     /// <p> Not supported: The encryption for DB instances is managed by the DB cluster.</p>
     pub kms_key_id: Option<String>,
     /// <p>Specifies the latest time to which a database can be restored with point-in-time restore.</p>
-    pub latest_restorable_time: Option<String>,
+    pub latest_restorable_time: Option<IsoTimestamp>,
     /// <p>License model information for this DB instance.</p>
     pub license_model: Option<String>,
     /// <p>Contains the master username for the DB instance.</p>
@@ -2698,10 +4039,10 @@ pub struct DBInstance {
     pub vpc_security_groups: Option<Vec<VpcSecurityGroupMembership>>,
 }
 
-struct DBInstanceDeserializer;
+pub(crate) struct DBInstanceDeserializer;
 impl DBInstanceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBInstance, XmlParseError> {
@@ -2985,10 +4326,10 @@ impl DBInstanceDeserializer {
         })
     }
 }
-struct DBInstanceListDeserializer;
+pub(crate) struct DBInstanceListDeserializer;
 impl DBInstanceListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBInstance>, XmlParseError> {
@@ -3010,10 +4351,10 @@ pub struct DBInstanceMessage {
     pub marker: Option<String>,
 }
 
-struct DBInstanceMessageDeserializer;
+pub(crate) struct DBInstanceMessageDeserializer;
 impl DBInstanceMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBInstanceMessage, XmlParseError> {
@@ -3046,10 +4387,10 @@ pub struct DBInstanceStatusInfo {
     pub status_type: Option<String>,
 }
 
-struct DBInstanceStatusInfoDeserializer;
+pub(crate) struct DBInstanceStatusInfoDeserializer;
 impl DBInstanceStatusInfoDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBInstanceStatusInfo, XmlParseError> {
@@ -3073,10 +4414,10 @@ impl DBInstanceStatusInfoDeserializer {
         })
     }
 }
-struct DBInstanceStatusInfoListDeserializer;
+pub(crate) struct DBInstanceStatusInfoListDeserializer;
 impl DBInstanceStatusInfoListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBInstanceStatusInfo>, XmlParseError> {
@@ -3106,10 +4447,10 @@ pub struct DBParameterGroup {
     pub description: Option<String>,
 }
 
-struct DBParameterGroupDeserializer;
+pub(crate) struct DBParameterGroupDeserializer;
 impl DBParameterGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBParameterGroup, XmlParseError> {
@@ -3150,10 +4491,10 @@ pub struct DBParameterGroupDetails {
     pub parameters: Option<Vec<Parameter>>,
 }
 
-struct DBParameterGroupDetailsDeserializer;
+pub(crate) struct DBParameterGroupDetailsDeserializer;
 impl DBParameterGroupDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBParameterGroupDetails, XmlParseError> {
@@ -3177,10 +4518,10 @@ impl DBParameterGroupDetailsDeserializer {
         )
     }
 }
-struct DBParameterGroupListDeserializer;
+pub(crate) struct DBParameterGroupListDeserializer;
 impl DBParameterGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBParameterGroup>, XmlParseError> {
@@ -3203,10 +4544,10 @@ pub struct DBParameterGroupNameMessage {
     pub db_parameter_group_name: Option<String>,
 }
 
-struct DBParameterGroupNameMessageDeserializer;
+pub(crate) struct DBParameterGroupNameMessageDeserializer;
 impl DBParameterGroupNameMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBParameterGroupNameMessage, XmlParseError> {
@@ -3237,10 +4578,10 @@ pub struct DBParameterGroupStatus {
     pub parameter_apply_status: Option<String>,
 }
 
-struct DBParameterGroupStatusDeserializer;
+pub(crate) struct DBParameterGroupStatusDeserializer;
 impl DBParameterGroupStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBParameterGroupStatus, XmlParseError> {
@@ -3264,10 +4605,10 @@ impl DBParameterGroupStatusDeserializer {
         })
     }
 }
-struct DBParameterGroupStatusListDeserializer;
+pub(crate) struct DBParameterGroupStatusListDeserializer;
 impl DBParameterGroupStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBParameterGroupStatus>, XmlParseError> {
@@ -3292,10 +4633,10 @@ pub struct DBParameterGroupsMessage {
     pub marker: Option<String>,
 }
 
-struct DBParameterGroupsMessageDeserializer;
+pub(crate) struct DBParameterGroupsMessageDeserializer;
 impl DBParameterGroupsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBParameterGroupsMessage, XmlParseError> {
@@ -3331,10 +4672,10 @@ pub struct DBSecurityGroupMembership {
     pub status: Option<String>,
 }
 
-struct DBSecurityGroupMembershipDeserializer;
+pub(crate) struct DBSecurityGroupMembershipDeserializer;
 impl DBSecurityGroupMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSecurityGroupMembership, XmlParseError> {
@@ -3359,10 +4700,10 @@ impl DBSecurityGroupMembershipDeserializer {
         )
     }
 }
-struct DBSecurityGroupMembershipListDeserializer;
+pub(crate) struct DBSecurityGroupMembershipListDeserializer;
 impl DBSecurityGroupMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBSecurityGroupMembership>, XmlParseError> {
@@ -3408,10 +4749,10 @@ pub struct DBSubnetGroup {
     pub vpc_id: Option<String>,
 }
 
-struct DBSubnetGroupDeserializer;
+pub(crate) struct DBSubnetGroupDeserializer;
 impl DBSubnetGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSubnetGroup, XmlParseError> {
@@ -3457,10 +4798,10 @@ pub struct DBSubnetGroupMessage {
     pub marker: Option<String>,
 }
 
-struct DBSubnetGroupMessageDeserializer;
+pub(crate) struct DBSubnetGroupMessageDeserializer;
 impl DBSubnetGroupMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSubnetGroupMessage, XmlParseError> {
@@ -3480,10 +4821,10 @@ impl DBSubnetGroupMessageDeserializer {
         })
     }
 }
-struct DBSubnetGroupsDeserializer;
+pub(crate) struct DBSubnetGroupsDeserializer;
 impl DBSubnetGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBSubnetGroup>, XmlParseError> {
@@ -3510,6 +4851,52 @@ pub struct DeleteDBClusterMessage {
     pub skip_final_snapshot: Option<bool>,
 }
 
+impl DeleteDBClusterMessage {
+    /// Returns a builder for `DeleteDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBClusterMessageBuilder {
+        DeleteDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBClusterMessage`](struct.DeleteDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    final_db_snapshot_identifier: Option<String>,
+    skip_final_snapshot: Option<bool>,
+}
+
+impl DeleteDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.DeleteDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`final_db_snapshot_identifier`](struct.DeleteDBClusterMessage.html#structfield.final_db_snapshot_identifier) member.
+    pub fn final_db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.final_db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skip_final_snapshot`](struct.DeleteDBClusterMessage.html#structfield.skip_final_snapshot) member.
+    pub fn skip_final_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.skip_final_snapshot = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            final_db_snapshot_identifier: self.final_db_snapshot_identifier,
+            skip_final_snapshot: self.skip_final_snapshot,
+        })
+    }
+}
+
 /// Serialize `DeleteDBClusterMessage` contents to a `SignedRequest`.
 struct DeleteDBClusterMessageSerializer;
 impl DeleteDBClusterMessageSerializer {
@@ -3541,6 +4928,39 @@ pub struct DeleteDBClusterParameterGroupMessage {
     pub db_cluster_parameter_group_name: String,
 }
 
+impl DeleteDBClusterParameterGroupMessage {
+    /// Returns a builder for `DeleteDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBClusterParameterGroupMessageBuilder {
+        DeleteDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBClusterParameterGroupMessage`](struct.DeleteDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBClusterParameterGroupMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+}
+
+impl DeleteDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.DeleteDBClusterParameterGroupMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBClusterParameterGroupMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteDBClusterParameterGroupMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct DeleteDBClusterParameterGroupMessageSerializer;
 impl DeleteDBClusterParameterGroupMessageSerializer {
@@ -3562,10 +4982,10 @@ pub struct DeleteDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct DeleteDBClusterResultDeserializer;
+pub(crate) struct DeleteDBClusterResultDeserializer;
 impl DeleteDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDBClusterResult, XmlParseError> {
@@ -3586,6 +5006,38 @@ pub struct DeleteDBClusterSnapshotMessage {
     pub db_cluster_snapshot_identifier: String,
 }
 
+impl DeleteDBClusterSnapshotMessage {
+    /// Returns a builder for `DeleteDBClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBClusterSnapshotMessageBuilder {
+        DeleteDBClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBClusterSnapshotMessage`](struct.DeleteDBClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBClusterSnapshotMessageBuilder {
+    db_cluster_snapshot_identifier: Option<String>,
+}
+
+impl DeleteDBClusterSnapshotMessageBuilder {
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.DeleteDBClusterSnapshotMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBClusterSnapshotMessage {
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_snapshot_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBClusterSnapshotMessage` contents to a `SignedRequest`.
 struct DeleteDBClusterSnapshotMessageSerializer;
 impl DeleteDBClusterSnapshotMessageSerializer {
@@ -3607,10 +5059,10 @@ pub struct DeleteDBClusterSnapshotResult {
     pub db_cluster_snapshot: Option<DBClusterSnapshot>,
 }
 
-struct DeleteDBClusterSnapshotResultDeserializer;
+pub(crate) struct DeleteDBClusterSnapshotResultDeserializer;
 impl DeleteDBClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDBClusterSnapshotResult, XmlParseError> {
@@ -3642,6 +5094,54 @@ pub struct DeleteDBInstanceMessage {
     pub skip_final_snapshot: Option<bool>,
 }
 
+impl DeleteDBInstanceMessage {
+    /// Returns a builder for `DeleteDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBInstanceMessageBuilder {
+        DeleteDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBInstanceMessage`](struct.DeleteDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBInstanceMessageBuilder {
+    db_instance_identifier: Option<String>,
+    final_db_snapshot_identifier: Option<String>,
+    skip_final_snapshot: Option<bool>,
+}
+
+impl DeleteDBInstanceMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.DeleteDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`final_db_snapshot_identifier`](struct.DeleteDBInstanceMessage.html#structfield.final_db_snapshot_identifier) member.
+    pub fn final_db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.final_db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skip_final_snapshot`](struct.DeleteDBInstanceMessage.html#structfield.skip_final_snapshot) member.
+    pub fn skip_final_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.skip_final_snapshot = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBInstanceMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            final_db_snapshot_identifier: self.final_db_snapshot_identifier,
+            skip_final_snapshot: self.skip_final_snapshot,
+        })
+    }
+}
+
 /// Serialize `DeleteDBInstanceMessage` contents to a `SignedRequest`.
 struct DeleteDBInstanceMessageSerializer;
 impl DeleteDBInstanceMessageSerializer {
@@ -3672,10 +5172,10 @@ pub struct DeleteDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct DeleteDBInstanceResultDeserializer;
+pub(crate) struct DeleteDBInstanceResultDeserializer;
 impl DeleteDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDBInstanceResult, XmlParseError> {
@@ -3697,6 +5197,38 @@ pub struct DeleteDBParameterGroupMessage {
     pub db_parameter_group_name: String,
 }
 
+impl DeleteDBParameterGroupMessage {
+    /// Returns a builder for `DeleteDBParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBParameterGroupMessageBuilder {
+        DeleteDBParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBParameterGroupMessage`](struct.DeleteDBParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBParameterGroupMessageBuilder {
+    db_parameter_group_name: Option<String>,
+}
+
+impl DeleteDBParameterGroupMessageBuilder {
+    /// Sets the [`db_parameter_group_name`](struct.DeleteDBParameterGroupMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBParameterGroupMessage {
+            db_parameter_group_name: self.db_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBParameterGroupMessage` contents to a `SignedRequest`.
 struct DeleteDBParameterGroupMessageSerializer;
 impl DeleteDBParameterGroupMessageSerializer {
@@ -3719,6 +5251,38 @@ pub struct DeleteDBSubnetGroupMessage {
     pub db_subnet_group_name: String,
 }
 
+impl DeleteDBSubnetGroupMessage {
+    /// Returns a builder for `DeleteDBSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBSubnetGroupMessageBuilder {
+        DeleteDBSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBSubnetGroupMessage`](struct.DeleteDBSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBSubnetGroupMessageBuilder {
+    db_subnet_group_name: Option<String>,
+}
+
+impl DeleteDBSubnetGroupMessageBuilder {
+    /// Sets the [`db_subnet_group_name`](struct.DeleteDBSubnetGroupMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBSubnetGroupMessage {
+            db_subnet_group_name: self
+                .db_subnet_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_subnet_group_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBSubnetGroupMessage` contents to a `SignedRequest`.
 struct DeleteDBSubnetGroupMessageSerializer;
 impl DeleteDBSubnetGroupMessageSerializer {
@@ -3741,6 +5305,38 @@ pub struct DeleteEventSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl DeleteEventSubscriptionMessage {
+    /// Returns a builder for `DeleteEventSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> DeleteEventSubscriptionMessageBuilder {
+        DeleteEventSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEventSubscriptionMessage`](struct.DeleteEventSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteEventSubscriptionMessageBuilder {
+    subscription_name: Option<String>,
+}
+
+impl DeleteEventSubscriptionMessageBuilder {
+    /// Sets the [`subscription_name`](struct.DeleteEventSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEventSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteEventSubscriptionMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteEventSubscriptionMessage {
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteEventSubscriptionMessage` contents to a `SignedRequest`.
 struct DeleteEventSubscriptionMessageSerializer;
 impl DeleteEventSubscriptionMessageSerializer {
@@ -3762,10 +5358,10 @@ pub struct DeleteEventSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
 
-struct DeleteEventSubscriptionResultDeserializer;
+pub(crate) struct DeleteEventSubscriptionResultDeserializer;
 impl DeleteEventSubscriptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteEventSubscriptionResult, XmlParseError> {
@@ -3799,9 +5395,64 @@ pub struct DescribeDBClusterParameterGroupsMessage {
     pub max_records: Option<i64>,
 }
 
-/// Serialize `DescribeDBClusterParameterGroupsMessage` contents to a `SignedRequest`.
-struct DescribeDBClusterParameterGroupsMessageSerializer;
-impl DescribeDBClusterParameterGroupsMessageSerializer {
+impl DescribeDBClusterParameterGroupsMessage {
+    /// Returns a builder for `DescribeDBClusterParameterGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterParameterGroupsMessageBuilder {
+        DescribeDBClusterParameterGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterParameterGroupsMessage`](struct.DescribeDBClusterParameterGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterParameterGroupsMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBClusterParameterGroupsMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.DescribeDBClusterParameterGroupsMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClusterParameterGroupsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClusterParameterGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClusterParameterGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterParameterGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterParameterGroupsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDBClusterParameterGroupsMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
+/// Serialize `DescribeDBClusterParameterGroupsMessage` contents to a `SignedRequest`.
+struct DescribeDBClusterParameterGroupsMessageSerializer;
+impl DescribeDBClusterParameterGroupsMessageSerializer {
     fn serialize(params: &mut Params, name: &str, obj: &DescribeDBClusterParameterGroupsMessage) {
         let mut prefix = name.to_string();
         if prefix != "" {
@@ -3844,6 +5495,70 @@ pub struct DescribeDBClusterParametersMessage {
     pub source: Option<String>,
 }
 
+impl DescribeDBClusterParametersMessage {
+    /// Returns a builder for `DescribeDBClusterParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterParametersMessageBuilder {
+        DescribeDBClusterParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterParametersMessage`](struct.DescribeDBClusterParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterParametersMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    source: Option<String>,
+}
+
+impl DescribeDBClusterParametersMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.DescribeDBClusterParametersMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClusterParametersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClusterParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClusterParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.DescribeDBClusterParametersMessage.html#structfield.source) member.
+    pub fn source<T: Into<String>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterParametersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBClusterParametersMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            source: self.source,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterParametersMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterParametersMessageSerializer;
 impl DescribeDBClusterParametersMessageSerializer {
@@ -3882,6 +5597,39 @@ pub struct DescribeDBClusterSnapshotAttributesMessage {
     pub db_cluster_snapshot_identifier: String,
 }
 
+impl DescribeDBClusterSnapshotAttributesMessage {
+    /// Returns a builder for `DescribeDBClusterSnapshotAttributesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterSnapshotAttributesMessageBuilder {
+        DescribeDBClusterSnapshotAttributesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterSnapshotAttributesMessage`](struct.DescribeDBClusterSnapshotAttributesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterSnapshotAttributesMessageBuilder {
+    db_cluster_snapshot_identifier: Option<String>,
+}
+
+impl DescribeDBClusterSnapshotAttributesMessageBuilder {
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.DescribeDBClusterSnapshotAttributesMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterSnapshotAttributesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterSnapshotAttributesMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDBClusterSnapshotAttributesMessage {
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_snapshot_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterSnapshotAttributesMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterSnapshotAttributesMessageSerializer;
 impl DescribeDBClusterSnapshotAttributesMessageSerializer {
@@ -3907,10 +5655,10 @@ pub struct DescribeDBClusterSnapshotAttributesResult {
     pub db_cluster_snapshot_attributes_result: Option<DBClusterSnapshotAttributesResult>,
 }
 
-struct DescribeDBClusterSnapshotAttributesResultDeserializer;
+pub(crate) struct DescribeDBClusterSnapshotAttributesResultDeserializer;
 impl DescribeDBClusterSnapshotAttributesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeDBClusterSnapshotAttributesResult, XmlParseError> {
@@ -3953,6 +5701,92 @@ pub struct DescribeDBClusterSnapshotsMessage {
     pub snapshot_type: Option<String>,
 }
 
+impl DescribeDBClusterSnapshotsMessage {
+    /// Returns a builder for `DescribeDBClusterSnapshotsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterSnapshotsMessageBuilder {
+        DescribeDBClusterSnapshotsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterSnapshotsMessage`](struct.DescribeDBClusterSnapshotsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterSnapshotsMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    db_cluster_snapshot_identifier: Option<String>,
+    filters: Option<Vec<Filter>>,
+    include_public: Option<bool>,
+    include_shared: Option<bool>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    snapshot_type: Option<String>,
+}
+
+impl DescribeDBClusterSnapshotsMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_public`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.include_public) member.
+    pub fn include_public<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_public = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_shared`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.include_shared) member.
+    pub fn include_shared<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_shared = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_type`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.snapshot_type) member.
+    pub fn snapshot_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterSnapshotsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterSnapshotsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBClusterSnapshotsMessage {
+            db_cluster_identifier: self.db_cluster_identifier,
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier,
+            filters: self.filters,
+            include_public: self.include_public,
+            include_shared: self.include_shared,
+            marker: self.marker,
+            max_records: self.max_records,
+            snapshot_type: self.snapshot_type,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterSnapshotsMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterSnapshotsMessageSerializer;
 impl DescribeDBClusterSnapshotsMessageSerializer {
@@ -4011,6 +5845,60 @@ pub struct DescribeDBClustersMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBClustersMessage {
+    /// Returns a builder for `DescribeDBClustersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClustersMessageBuilder {
+        DescribeDBClustersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClustersMessage`](struct.DescribeDBClustersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClustersMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBClustersMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.DescribeDBClustersMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClustersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClustersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClustersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClustersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClustersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBClustersMessage {
+            db_cluster_identifier: self.db_cluster_identifier,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClustersMessage` contents to a `SignedRequest`.
 struct DescribeDBClustersMessageSerializer;
 impl DescribeDBClustersMessageSerializer {
@@ -4064,6 +5952,100 @@ pub struct DescribeDBEngineVersionsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBEngineVersionsMessage {
+    /// Returns a builder for `DescribeDBEngineVersionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBEngineVersionsMessageBuilder {
+        DescribeDBEngineVersionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBEngineVersionsMessage`](struct.DescribeDBEngineVersionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBEngineVersionsMessageBuilder {
+    db_parameter_group_family: Option<String>,
+    default_only: Option<bool>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    filters: Option<Vec<Filter>>,
+    list_supported_character_sets: Option<bool>,
+    list_supported_timezones: Option<bool>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBEngineVersionsMessageBuilder {
+    /// Sets the [`db_parameter_group_family`](struct.DescribeDBEngineVersionsMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_only`](struct.DescribeDBEngineVersionsMessage.html#structfield.default_only) member.
+    pub fn default_only<T: Into<bool>>(mut self, value: T) -> Self {
+        self.default_only = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.DescribeDBEngineVersionsMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.DescribeDBEngineVersionsMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBEngineVersionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`list_supported_character_sets`](struct.DescribeDBEngineVersionsMessage.html#structfield.list_supported_character_sets) member.
+    pub fn list_supported_character_sets<T: Into<bool>>(mut self, value: T) -> Self {
+        self.list_supported_character_sets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`list_supported_timezones`](struct.DescribeDBEngineVersionsMessage.html#structfield.list_supported_timezones) member.
+    pub fn list_supported_timezones<T: Into<bool>>(mut self, value: T) -> Self {
+        self.list_supported_timezones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBEngineVersionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBEngineVersionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBEngineVersionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBEngineVersionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBEngineVersionsMessage {
+            db_parameter_group_family: self.db_parameter_group_family,
+            default_only: self.default_only,
+            engine: self.engine,
+            engine_version: self.engine_version,
+            filters: self.filters,
+            list_supported_character_sets: self.list_supported_character_sets,
+            list_supported_timezones: self.list_supported_timezones,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBEngineVersionsMessage` contents to a `SignedRequest`.
 struct DescribeDBEngineVersionsMessageSerializer;
 impl DescribeDBEngineVersionsMessageSerializer {
@@ -4128,6 +6110,60 @@ pub struct DescribeDBInstancesMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBInstancesMessage {
+    /// Returns a builder for `DescribeDBInstancesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBInstancesMessageBuilder {
+        DescribeDBInstancesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBInstancesMessage`](struct.DescribeDBInstancesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBInstancesMessageBuilder {
+    db_instance_identifier: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBInstancesMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.DescribeDBInstancesMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBInstancesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBInstancesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBInstancesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBInstancesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBInstancesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBInstancesMessage {
+            db_instance_identifier: self.db_instance_identifier,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBInstancesMessage` contents to a `SignedRequest`.
 struct DescribeDBInstancesMessageSerializer;
 impl DescribeDBInstancesMessageSerializer {
@@ -4171,6 +6207,60 @@ pub struct DescribeDBParameterGroupsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBParameterGroupsMessage {
+    /// Returns a builder for `DescribeDBParameterGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBParameterGroupsMessageBuilder {
+        DescribeDBParameterGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBParameterGroupsMessage`](struct.DescribeDBParameterGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBParameterGroupsMessageBuilder {
+    db_parameter_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBParameterGroupsMessageBuilder {
+    /// Sets the [`db_parameter_group_name`](struct.DescribeDBParameterGroupsMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBParameterGroupsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBParameterGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBParameterGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBParameterGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBParameterGroupsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBParameterGroupsMessage {
+            db_parameter_group_name: self.db_parameter_group_name,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBParameterGroupsMessage` contents to a `SignedRequest`.
 struct DescribeDBParameterGroupsMessageSerializer;
 impl DescribeDBParameterGroupsMessageSerializer {
@@ -4216,6 +6306,70 @@ pub struct DescribeDBParametersMessage {
     pub source: Option<String>,
 }
 
+impl DescribeDBParametersMessage {
+    /// Returns a builder for `DescribeDBParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBParametersMessageBuilder {
+        DescribeDBParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBParametersMessage`](struct.DescribeDBParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBParametersMessageBuilder {
+    db_parameter_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    source: Option<String>,
+}
+
+impl DescribeDBParametersMessageBuilder {
+    /// Sets the [`db_parameter_group_name`](struct.DescribeDBParametersMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBParametersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.DescribeDBParametersMessage.html#structfield.source) member.
+    pub fn source<T: Into<String>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBParametersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBParametersMessage {
+            db_parameter_group_name: self.db_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_name"),
+            )?,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            source: self.source,
+        })
+    }
+}
+
 /// Serialize `DescribeDBParametersMessage` contents to a `SignedRequest`.
 struct DescribeDBParametersMessageSerializer;
 impl DescribeDBParametersMessageSerializer {
@@ -4260,6 +6414,60 @@ pub struct DescribeDBSubnetGroupsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBSubnetGroupsMessage {
+    /// Returns a builder for `DescribeDBSubnetGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBSubnetGroupsMessageBuilder {
+        DescribeDBSubnetGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBSubnetGroupsMessage`](struct.DescribeDBSubnetGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBSubnetGroupsMessageBuilder {
+    db_subnet_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBSubnetGroupsMessageBuilder {
+    /// Sets the [`db_subnet_group_name`](struct.DescribeDBSubnetGroupsMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBSubnetGroupsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBSubnetGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBSubnetGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBSubnetGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBSubnetGroupsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBSubnetGroupsMessage {
+            db_subnet_group_name: self.db_subnet_group_name,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBSubnetGroupsMessage` contents to a `SignedRequest`.
 struct DescribeDBSubnetGroupsMessageSerializer;
 impl DescribeDBSubnetGroupsMessageSerializer {
@@ -4300,6 +6508,65 @@ pub struct DescribeEngineDefaultClusterParametersMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeEngineDefaultClusterParametersMessage {
+    /// Returns a builder for `DescribeEngineDefaultClusterParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEngineDefaultClusterParametersMessageBuilder {
+        DescribeEngineDefaultClusterParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEngineDefaultClusterParametersMessage`](struct.DescribeEngineDefaultClusterParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEngineDefaultClusterParametersMessageBuilder {
+    db_parameter_group_family: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeEngineDefaultClusterParametersMessageBuilder {
+    /// Sets the [`db_parameter_group_family`](struct.DescribeEngineDefaultClusterParametersMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeEngineDefaultClusterParametersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEngineDefaultClusterParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEngineDefaultClusterParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEngineDefaultClusterParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeEngineDefaultClusterParametersMessage,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeEngineDefaultClusterParametersMessage {
+            db_parameter_group_family: self.db_parameter_group_family.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_family"),
+            )?,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeEngineDefaultClusterParametersMessage` contents to a `SignedRequest`.
 struct DescribeEngineDefaultClusterParametersMessageSerializer;
 impl DescribeEngineDefaultClusterParametersMessageSerializer {
@@ -4338,10 +6605,10 @@ pub struct DescribeEngineDefaultClusterParametersResult {
     pub engine_defaults: Option<EngineDefaults>,
 }
 
-struct DescribeEngineDefaultClusterParametersResultDeserializer;
+pub(crate) struct DescribeEngineDefaultClusterParametersResultDeserializer;
 impl DescribeEngineDefaultClusterParametersResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeEngineDefaultClusterParametersResult, XmlParseError> {
@@ -4375,6 +6642,63 @@ pub struct DescribeEngineDefaultParametersMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeEngineDefaultParametersMessage {
+    /// Returns a builder for `DescribeEngineDefaultParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEngineDefaultParametersMessageBuilder {
+        DescribeEngineDefaultParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEngineDefaultParametersMessage`](struct.DescribeEngineDefaultParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEngineDefaultParametersMessageBuilder {
+    db_parameter_group_family: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeEngineDefaultParametersMessageBuilder {
+    /// Sets the [`db_parameter_group_family`](struct.DescribeEngineDefaultParametersMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeEngineDefaultParametersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEngineDefaultParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEngineDefaultParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEngineDefaultParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEngineDefaultParametersMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeEngineDefaultParametersMessage {
+            db_parameter_group_family: self.db_parameter_group_family.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_family"),
+            )?,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeEngineDefaultParametersMessage` contents to a `SignedRequest`.
 struct DescribeEngineDefaultParametersMessageSerializer;
 impl DescribeEngineDefaultParametersMessageSerializer {
@@ -4409,10 +6733,10 @@ pub struct DescribeEngineDefaultParametersResult {
     pub engine_defaults: Option<EngineDefaults>,
 }
 
-struct DescribeEngineDefaultParametersResultDeserializer;
+pub(crate) struct DescribeEngineDefaultParametersResultDeserializer;
 impl DescribeEngineDefaultParametersResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeEngineDefaultParametersResult, XmlParseError> {
@@ -4442,6 +6766,44 @@ pub struct DescribeEventCategoriesMessage {
     pub source_type: Option<String>,
 }
 
+impl DescribeEventCategoriesMessage {
+    /// Returns a builder for `DescribeEventCategoriesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventCategoriesMessageBuilder {
+        DescribeEventCategoriesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventCategoriesMessage`](struct.DescribeEventCategoriesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventCategoriesMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    source_type: Option<String>,
+}
+
+impl DescribeEventCategoriesMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeEventCategoriesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.DescribeEventCategoriesMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventCategoriesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventCategoriesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventCategoriesMessage {
+            filters: self.filters,
+            source_type: self.source_type,
+        })
+    }
+}
+
 /// Serialize `DescribeEventCategoriesMessage` contents to a `SignedRequest`.
 struct DescribeEventCategoriesMessageSerializer;
 impl DescribeEventCategoriesMessageSerializer {
@@ -4476,21 +6838,75 @@ pub struct DescribeEventSubscriptionsMessage {
     pub subscription_name: Option<String>,
 }
 
-/// Serialize `DescribeEventSubscriptionsMessage` contents to a `SignedRequest`.
-struct DescribeEventSubscriptionsMessageSerializer;
-impl DescribeEventSubscriptionsMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &DescribeEventSubscriptionsMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl DescribeEventSubscriptionsMessage {
+    /// Returns a builder for `DescribeEventSubscriptionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventSubscriptionsMessageBuilder {
+        DescribeEventSubscriptionsMessageBuilder::default()
+    }
+}
 
-        if let Some(ref field_value) = obj.filters {
-            FilterListSerializer::serialize(
-                params,
-                &format!("{}{}", prefix, "Filter"),
-                field_value,
-            );
+/// Builds a [`DescribeEventSubscriptionsMessage`](struct.DescribeEventSubscriptionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventSubscriptionsMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    subscription_name: Option<String>,
+}
+
+impl DescribeEventSubscriptionsMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeEventSubscriptionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEventSubscriptionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEventSubscriptionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.DescribeEventSubscriptionsMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventSubscriptionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventSubscriptionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventSubscriptionsMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            subscription_name: self.subscription_name,
+        })
+    }
+}
+
+/// Serialize `DescribeEventSubscriptionsMessage` contents to a `SignedRequest`.
+struct DescribeEventSubscriptionsMessageSerializer;
+impl DescribeEventSubscriptionsMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &DescribeEventSubscriptionsMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.filters {
+            FilterListSerializer::serialize(
+                params,
+                &format!("{}{}", prefix, "Filter"),
+                field_value,
+            );
         }
         if let Some(ref field_value) = obj.marker {
             params.put(&format!("{}{}", prefix, "Marker"), &field_value);
@@ -4509,7 +6925,7 @@ pub struct DescribeEventsMessage {
     /// <p>The number of minutes to retrieve events for.</p> <p>Default: 60</p>
     pub duration: Option<i64>,
     /// <p> The end of the time interval for which to retrieve events, specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: 2009-07-08T18:00Z</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>A list of event categories that trigger notifications for a event notification subscription.</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>This parameter is not currently supported.</p>
@@ -4521,9 +6937,101 @@ pub struct DescribeEventsMessage {
     /// <p><p>The identifier of the event source for which events are returned. If not specified, then all sources are included in the response.</p> <p>Constraints:</p> <ul> <li> <p>If SourceIdentifier is supplied, SourceType must also be provided.</p> </li> <li> <p>If the source type is <code>DBInstance</code>, then a <code>DBInstanceIdentifier</code> must be supplied.</p> </li> <li> <p>If the source type is <code>DBSecurityGroup</code>, a <code>DBSecurityGroupName</code> must be supplied.</p> </li> <li> <p>If the source type is <code>DBParameterGroup</code>, a <code>DBParameterGroupName</code> must be supplied.</p> </li> <li> <p>If the source type is <code>DBSnapshot</code>, a <code>DBSnapshotIdentifier</code> must be supplied.</p> </li> <li> <p>Cannot end with a hyphen or contain two consecutive hyphens.</p> </li> </ul></p>
     pub source_identifier: Option<String>,
     /// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p> The beginning of the time interval to retrieve events for, specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: 2009-07-08T18:00Z</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
+}
+
+impl DescribeEventsMessage {
+    /// Returns a builder for `DescribeEventsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventsMessageBuilder {
+        DescribeEventsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventsMessage`](struct.DescribeEventsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventsMessageBuilder {
+    duration: Option<i64>,
+    end_time: Option<IsoTimestamp>,
+    event_categories: Option<Vec<String>>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    source_identifier: Option<String>,
+    source_type: Option<SourceType>,
+    start_time: Option<IsoTimestamp>,
+}
+
+impl DescribeEventsMessageBuilder {
+    /// Sets the [`duration`](struct.DescribeEventsMessage.html#structfield.duration) member.
+    pub fn duration<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.DescribeEventsMessage.html#structfield.end_time) member.
+    pub fn end_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_categories`](struct.DescribeEventsMessage.html#structfield.event_categories) member.
+    pub fn event_categories<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_categories = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeEventsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEventsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEventsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_identifier`](struct.DescribeEventsMessage.html#structfield.source_identifier) member.
+    pub fn source_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.DescribeEventsMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<SourceType>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.DescribeEventsMessage.html#structfield.start_time) member.
+    pub fn start_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventsMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeEventsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventsMessage {
+            duration: self.duration,
+            end_time: self.end_time,
+            event_categories: self.event_categories,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            source_identifier: self.source_identifier,
+            source_type: self.source_type,
+            start_time: self.start_time,
+        })
+    }
 }
 
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
@@ -4593,6 +7101,95 @@ pub struct DescribeOrderableDBInstanceOptionsMessage {
     pub vpc: Option<bool>,
 }
 
+impl DescribeOrderableDBInstanceOptionsMessage {
+    /// Returns a builder for `DescribeOrderableDBInstanceOptionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeOrderableDBInstanceOptionsMessageBuilder {
+        DescribeOrderableDBInstanceOptionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeOrderableDBInstanceOptionsMessage`](struct.DescribeOrderableDBInstanceOptionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeOrderableDBInstanceOptionsMessageBuilder {
+    db_instance_class: Option<String>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    filters: Option<Vec<Filter>>,
+    license_model: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    vpc: Option<bool>,
+}
+
+impl DescribeOrderableDBInstanceOptionsMessageBuilder {
+    /// Sets the [`db_instance_class`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_model`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.license_model) member.
+    pub fn license_model<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_model = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.vpc) member.
+    pub fn vpc<T: Into<bool>>(mut self, value: T) -> Self {
+        self.vpc = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeOrderableDBInstanceOptionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeOrderableDBInstanceOptionsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeOrderableDBInstanceOptionsMessage {
+            db_instance_class: self.db_instance_class,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_version: self.engine_version,
+            filters: self.filters,
+            license_model: self.license_model,
+            marker: self.marker,
+            max_records: self.max_records,
+            vpc: self.vpc,
+        })
+    }
+}
+
 /// Serialize `DescribeOrderableDBInstanceOptionsMessage` contents to a `SignedRequest`.
 struct DescribeOrderableDBInstanceOptionsMessageSerializer;
 impl DescribeOrderableDBInstanceOptionsMessageSerializer {
@@ -4643,6 +7240,61 @@ pub struct DescribePendingMaintenanceActionsMessage {
     pub resource_identifier: Option<String>,
 }
 
+impl DescribePendingMaintenanceActionsMessage {
+    /// Returns a builder for `DescribePendingMaintenanceActionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribePendingMaintenanceActionsMessageBuilder {
+        DescribePendingMaintenanceActionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePendingMaintenanceActionsMessage`](struct.DescribePendingMaintenanceActionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribePendingMaintenanceActionsMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    resource_identifier: Option<String>,
+}
+
+impl DescribePendingMaintenanceActionsMessageBuilder {
+    /// Sets the [`filters`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_identifier`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.resource_identifier) member.
+    pub fn resource_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePendingMaintenanceActionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePendingMaintenanceActionsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribePendingMaintenanceActionsMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            resource_identifier: self.resource_identifier,
+        })
+    }
+}
+
 /// Serialize `DescribePendingMaintenanceActionsMessage` contents to a `SignedRequest`.
 struct DescribePendingMaintenanceActionsMessageSerializer;
 impl DescribePendingMaintenanceActionsMessageSerializer {
@@ -4677,6 +7329,39 @@ pub struct DescribeValidDBInstanceModificationsMessage {
     pub db_instance_identifier: String,
 }
 
+impl DescribeValidDBInstanceModificationsMessage {
+    /// Returns a builder for `DescribeValidDBInstanceModificationsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeValidDBInstanceModificationsMessageBuilder {
+        DescribeValidDBInstanceModificationsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeValidDBInstanceModificationsMessage`](struct.DescribeValidDBInstanceModificationsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeValidDBInstanceModificationsMessageBuilder {
+    db_instance_identifier: Option<String>,
+}
+
+impl DescribeValidDBInstanceModificationsMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.DescribeValidDBInstanceModificationsMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeValidDBInstanceModificationsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeValidDBInstanceModificationsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeValidDBInstanceModificationsMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeValidDBInstanceModificationsMessage` contents to a `SignedRequest`.
 struct DescribeValidDBInstanceModificationsMessageSerializer;
 impl DescribeValidDBInstanceModificationsMessageSerializer {
@@ -4702,10 +7387,10 @@ pub struct DescribeValidDBInstanceModificationsResult {
     pub valid_db_instance_modifications_message: Option<ValidDBInstanceModificationsMessage>,
 }
 
-struct DescribeValidDBInstanceModificationsResultDeserializer;
+pub(crate) struct DescribeValidDBInstanceModificationsResultDeserializer;
 impl DescribeValidDBInstanceModificationsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeValidDBInstanceModificationsResult, XmlParseError> {
@@ -4742,10 +7427,10 @@ pub struct DomainMembership {
     pub status: Option<String>,
 }
 
-struct DomainMembershipDeserializer;
+pub(crate) struct DomainMembershipDeserializer;
 impl DomainMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DomainMembership, XmlParseError> {
@@ -4770,10 +7455,10 @@ impl DomainMembershipDeserializer {
         })
     }
 }
-struct DomainMembershipListDeserializer;
+pub(crate) struct DomainMembershipListDeserializer;
 impl DomainMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DomainMembership>, XmlParseError> {
@@ -4790,10 +7475,13 @@ impl DomainMembershipListDeserializer {
         })
     }
 }
-struct DoubleDeserializer;
+pub(crate) struct DoubleDeserializer;
 impl DoubleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4801,10 +7489,13 @@ impl DoubleDeserializer {
         Ok(obj)
     }
 }
-struct DoubleOptionalDeserializer;
+pub(crate) struct DoubleOptionalDeserializer;
 impl DoubleOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4821,10 +7512,10 @@ pub struct DoubleRange {
     pub to: Option<f64>,
 }
 
-struct DoubleRangeDeserializer;
+pub(crate) struct DoubleRangeDeserializer;
 impl DoubleRangeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DoubleRange, XmlParseError> {
@@ -4842,10 +7533,10 @@ impl DoubleRangeDeserializer {
         })
     }
 }
-struct DoubleRangeListDeserializer;
+pub(crate) struct DoubleRangeListDeserializer;
 impl DoubleRangeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DoubleRange>, XmlParseError> {
@@ -4870,10 +7561,10 @@ pub struct Endpoint {
     pub port: Option<i64>,
 }
 
-struct EndpointDeserializer;
+pub(crate) struct EndpointDeserializer;
 impl EndpointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Endpoint, XmlParseError> {
@@ -4906,10 +7597,10 @@ pub struct EngineDefaults {
     pub parameters: Option<Vec<Parameter>>,
 }
 
-struct EngineDefaultsDeserializer;
+pub(crate) struct EngineDefaultsDeserializer;
 impl EngineDefaultsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EngineDefaults, XmlParseError> {
@@ -4939,7 +7630,7 @@ impl EngineDefaultsDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Event {
     /// <p>Specifies the date and time of the event.</p>
-    pub date: Option<String>,
+    pub date: Option<IsoTimestamp>,
     /// <p>Specifies the category for the event.</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>Provides the text of this event.</p>
@@ -4949,13 +7640,16 @@ pub struct Event {
     /// <p>Provides the identifier for the source of the event.</p>
     pub source_identifier: Option<String>,
     /// <p>Specifies the source type for this event.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
-struct EventDeserializer;
+pub(crate) struct EventDeserializer;
 impl EventDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Event, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Event, XmlParseError> {
         deserialize_elements::<_, Event, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Date" => {
@@ -4986,10 +7680,10 @@ impl EventDeserializer {
         })
     }
 }
-struct EventCategoriesListDeserializer;
+pub(crate) struct EventCategoriesListDeserializer;
 impl EventCategoriesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -5024,10 +7718,10 @@ pub struct EventCategoriesMap {
     pub source_type: Option<String>,
 }
 
-struct EventCategoriesMapDeserializer;
+pub(crate) struct EventCategoriesMapDeserializer;
 impl EventCategoriesMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventCategoriesMap, XmlParseError> {
@@ -5047,10 +7741,10 @@ impl EventCategoriesMapDeserializer {
         })
     }
 }
-struct EventCategoriesMapListDeserializer;
+pub(crate) struct EventCategoriesMapListDeserializer;
 impl EventCategoriesMapListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EventCategoriesMap>, XmlParseError> {
@@ -5073,10 +7767,10 @@ pub struct EventCategoriesMessage {
     pub event_categories_map_list: Option<Vec<EventCategoriesMap>>,
 }
 
-struct EventCategoriesMessageDeserializer;
+pub(crate) struct EventCategoriesMessageDeserializer;
 impl EventCategoriesMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventCategoriesMessage, XmlParseError> {
@@ -5096,10 +7790,10 @@ impl EventCategoriesMessageDeserializer {
         })
     }
 }
-struct EventListDeserializer;
+pub(crate) struct EventListDeserializer;
 impl EventListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Event>, XmlParseError> {
@@ -5138,10 +7832,10 @@ pub struct EventSubscription {
     pub subscription_creation_time: Option<String>,
 }
 
-struct EventSubscriptionDeserializer;
+pub(crate) struct EventSubscriptionDeserializer;
 impl EventSubscriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventSubscription, XmlParseError> {
@@ -5198,10 +7892,10 @@ impl EventSubscriptionDeserializer {
         })
     }
 }
-struct EventSubscriptionsListDeserializer;
+pub(crate) struct EventSubscriptionsListDeserializer;
 impl EventSubscriptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EventSubscription>, XmlParseError> {
@@ -5226,10 +7920,10 @@ pub struct EventSubscriptionsMessage {
     pub marker: Option<String>,
 }
 
-struct EventSubscriptionsMessageDeserializer;
+pub(crate) struct EventSubscriptionsMessageDeserializer;
 impl EventSubscriptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventSubscriptionsMessage, XmlParseError> {
@@ -5264,10 +7958,10 @@ pub struct EventsMessage {
     pub marker: Option<String>,
 }
 
-struct EventsMessageDeserializer;
+pub(crate) struct EventsMessageDeserializer;
 impl EventsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventsMessage, XmlParseError> {
@@ -5295,6 +7989,44 @@ pub struct FailoverDBClusterMessage {
     pub target_db_instance_identifier: Option<String>,
 }
 
+impl FailoverDBClusterMessage {
+    /// Returns a builder for `FailoverDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> FailoverDBClusterMessageBuilder {
+        FailoverDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`FailoverDBClusterMessage`](struct.FailoverDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct FailoverDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    target_db_instance_identifier: Option<String>,
+}
+
+impl FailoverDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.FailoverDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_instance_identifier`](struct.FailoverDBClusterMessage.html#structfield.target_db_instance_identifier) member.
+    pub fn target_db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `FailoverDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<FailoverDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(FailoverDBClusterMessage {
+            db_cluster_identifier: self.db_cluster_identifier,
+            target_db_instance_identifier: self.target_db_instance_identifier,
+        })
+    }
+}
+
 /// Serialize `FailoverDBClusterMessage` contents to a `SignedRequest`.
 struct FailoverDBClusterMessageSerializer;
 impl FailoverDBClusterMessageSerializer {
@@ -5324,10 +8056,10 @@ pub struct FailoverDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct FailoverDBClusterResultDeserializer;
+pub(crate) struct FailoverDBClusterResultDeserializer;
 impl FailoverDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FailoverDBClusterResult, XmlParseError> {
@@ -5396,10 +8128,13 @@ impl FilterValueListSerializer {
     }
 }
 
-struct IntegerDeserializer;
+pub(crate) struct IntegerDeserializer;
 impl IntegerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5407,10 +8142,13 @@ impl IntegerDeserializer {
         Ok(obj)
     }
 }
-struct IntegerOptionalDeserializer;
+pub(crate) struct IntegerOptionalDeserializer;
 impl IntegerOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -5438,6 +8176,46 @@ pub struct ListTagsForResourceMessage {
     pub resource_name: String,
 }
 
+impl ListTagsForResourceMessage {
+    /// Returns a builder for `ListTagsForResourceMessage`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceMessageBuilder {
+        ListTagsForResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceMessage`](struct.ListTagsForResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    resource_name: Option<String>,
+}
+
+impl ListTagsForResourceMessageBuilder {
+    /// Sets the [`filters`](struct.ListTagsForResourceMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_name`](struct.ListTagsForResourceMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceMessage, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceMessage {
+            filters: self.filters,
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListTagsForResourceMessage` contents to a `SignedRequest`.
 struct ListTagsForResourceMessageSerializer;
 impl ListTagsForResourceMessageSerializer {
@@ -5458,10 +8236,10 @@ impl ListTagsForResourceMessageSerializer {
     }
 }
 
-struct LogTypeListDeserializer;
+pub(crate) struct LogTypeListDeserializer;
 impl LogTypeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -5519,6 +8297,143 @@ pub struct ModifyDBClusterMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl ModifyDBClusterMessage {
+    /// Returns a builder for `ModifyDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBClusterMessageBuilder {
+        ModifyDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBClusterMessage`](struct.ModifyDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBClusterMessageBuilder {
+    apply_immediately: Option<bool>,
+    backup_retention_period: Option<i64>,
+    cloudwatch_logs_export_configuration: Option<CloudwatchLogsExportConfiguration>,
+    db_cluster_identifier: Option<String>,
+    db_cluster_parameter_group_name: Option<String>,
+    enable_iam_database_authentication: Option<bool>,
+    engine_version: Option<String>,
+    master_user_password: Option<String>,
+    new_db_cluster_identifier: Option<String>,
+    option_group_name: Option<String>,
+    port: Option<i64>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl ModifyDBClusterMessageBuilder {
+    /// Sets the [`apply_immediately`](struct.ModifyDBClusterMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_period`](struct.ModifyDBClusterMessage.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cloudwatch_logs_export_configuration`](struct.ModifyDBClusterMessage.html#structfield.cloudwatch_logs_export_configuration) member.
+    pub fn cloudwatch_logs_export_configuration<T: Into<CloudwatchLogsExportConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cloudwatch_logs_export_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.ModifyDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_parameter_group_name`](struct.ModifyDBClusterMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.ModifyDBClusterMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.ModifyDBClusterMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.ModifyDBClusterMessage.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_db_cluster_identifier`](struct.ModifyDBClusterMessage.html#structfield.new_db_cluster_identifier) member.
+    pub fn new_db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.ModifyDBClusterMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.ModifyDBClusterMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.ModifyDBClusterMessage.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.ModifyDBClusterMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.ModifyDBClusterMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ModifyDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBClusterMessage {
+            apply_immediately: self.apply_immediately,
+            backup_retention_period: self.backup_retention_period,
+            cloudwatch_logs_export_configuration: self.cloudwatch_logs_export_configuration,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            engine_version: self.engine_version,
+            master_user_password: self.master_user_password,
+            new_db_cluster_identifier: self.new_db_cluster_identifier,
+            option_group_name: self.option_group_name,
+            port: self.port,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `ModifyDBClusterMessage` contents to a `SignedRequest`.
 struct ModifyDBClusterMessageSerializer;
 impl ModifyDBClusterMessageSerializer {
@@ -5608,6 +8523,49 @@ pub struct ModifyDBClusterParameterGroupMessage {
     pub parameters: Vec<Parameter>,
 }
 
+impl ModifyDBClusterParameterGroupMessage {
+    /// Returns a builder for `ModifyDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBClusterParameterGroupMessageBuilder {
+        ModifyDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBClusterParameterGroupMessage`](struct.ModifyDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBClusterParameterGroupMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+}
+
+impl ModifyDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.ModifyDBClusterParameterGroupMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ModifyDBClusterParameterGroupMessage.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBClusterParameterGroupMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ModifyDBClusterParameterGroupMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+            parameters: self
+                .parameters
+                .ok_or(::rusoto_core::BuildError::missing("parameters"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct ModifyDBClusterParameterGroupMessageSerializer;
 impl ModifyDBClusterParameterGroupMessageSerializer {
@@ -5634,10 +8592,10 @@ pub struct ModifyDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct ModifyDBClusterResultDeserializer;
+pub(crate) struct ModifyDBClusterResultDeserializer;
 impl ModifyDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBClusterResult, XmlParseError> {
@@ -5664,6 +8622,65 @@ pub struct ModifyDBClusterSnapshotAttributeMessage {
     pub values_to_remove: Option<Vec<String>>,
 }
 
+impl ModifyDBClusterSnapshotAttributeMessage {
+    /// Returns a builder for `ModifyDBClusterSnapshotAttributeMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBClusterSnapshotAttributeMessageBuilder {
+        ModifyDBClusterSnapshotAttributeMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBClusterSnapshotAttributeMessage`](struct.ModifyDBClusterSnapshotAttributeMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBClusterSnapshotAttributeMessageBuilder {
+    attribute_name: Option<String>,
+    db_cluster_snapshot_identifier: Option<String>,
+    values_to_add: Option<Vec<String>>,
+    values_to_remove: Option<Vec<String>>,
+}
+
+impl ModifyDBClusterSnapshotAttributeMessageBuilder {
+    /// Sets the [`attribute_name`](struct.ModifyDBClusterSnapshotAttributeMessage.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.ModifyDBClusterSnapshotAttributeMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`values_to_add`](struct.ModifyDBClusterSnapshotAttributeMessage.html#structfield.values_to_add) member.
+    pub fn values_to_add<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.values_to_add = Some(value.into());
+        self
+    }
+
+    /// Sets the [`values_to_remove`](struct.ModifyDBClusterSnapshotAttributeMessage.html#structfield.values_to_remove) member.
+    pub fn values_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.values_to_remove = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBClusterSnapshotAttributeMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBClusterSnapshotAttributeMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ModifyDBClusterSnapshotAttributeMessage {
+            attribute_name: self
+                .attribute_name
+                .ok_or(::rusoto_core::BuildError::missing("attribute_name"))?,
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_snapshot_identifier"),
+            )?,
+            values_to_add: self.values_to_add,
+            values_to_remove: self.values_to_remove,
+        })
+    }
+}
+
 /// Serialize `ModifyDBClusterSnapshotAttributeMessage` contents to a `SignedRequest`.
 struct ModifyDBClusterSnapshotAttributeMessageSerializer;
 impl ModifyDBClusterSnapshotAttributeMessageSerializer {
@@ -5703,10 +8720,10 @@ pub struct ModifyDBClusterSnapshotAttributeResult {
     pub db_cluster_snapshot_attributes_result: Option<DBClusterSnapshotAttributesResult>,
 }
 
-struct ModifyDBClusterSnapshotAttributeResultDeserializer;
+pub(crate) struct ModifyDBClusterSnapshotAttributeResultDeserializer;
 impl ModifyDBClusterSnapshotAttributeResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBClusterSnapshotAttributeResult, XmlParseError> {
@@ -5803,44 +8820,351 @@ pub struct ModifyDBInstanceMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
-/// Serialize `ModifyDBInstanceMessage` contents to a `SignedRequest`.
-struct ModifyDBInstanceMessageSerializer;
-impl ModifyDBInstanceMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &ModifyDBInstanceMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl ModifyDBInstanceMessage {
+    /// Returns a builder for `ModifyDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBInstanceMessageBuilder {
+        ModifyDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBInstanceMessage`](struct.ModifyDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBInstanceMessageBuilder {
+    allocated_storage: Option<i64>,
+    allow_major_version_upgrade: Option<bool>,
+    apply_immediately: Option<bool>,
+    auto_minor_version_upgrade: Option<bool>,
+    backup_retention_period: Option<i64>,
+    ca_certificate_identifier: Option<String>,
+    cloudwatch_logs_export_configuration: Option<CloudwatchLogsExportConfiguration>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_instance_class: Option<String>,
+    db_instance_identifier: Option<String>,
+    db_parameter_group_name: Option<String>,
+    db_port_number: Option<i64>,
+    db_security_groups: Option<Vec<String>>,
+    db_subnet_group_name: Option<String>,
+    domain: Option<String>,
+    domain_iam_role_name: Option<String>,
+    enable_iam_database_authentication: Option<bool>,
+    enable_performance_insights: Option<bool>,
+    engine_version: Option<String>,
+    iops: Option<i64>,
+    license_model: Option<String>,
+    master_user_password: Option<String>,
+    monitoring_interval: Option<i64>,
+    monitoring_role_arn: Option<String>,
+    multi_az: Option<bool>,
+    new_db_instance_identifier: Option<String>,
+    option_group_name: Option<String>,
+    performance_insights_kms_key_id: Option<String>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    promotion_tier: Option<i64>,
+    storage_type: Option<String>,
+    tde_credential_arn: Option<String>,
+    tde_credential_password: Option<String>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
 
-        if let Some(ref field_value) = obj.allocated_storage {
-            params.put(&format!("{}{}", prefix, "AllocatedStorage"), &field_value);
-        }
-        if let Some(ref field_value) = obj.allow_major_version_upgrade {
-            params.put(
-                &format!("{}{}", prefix, "AllowMajorVersionUpgrade"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.apply_immediately {
-            params.put(&format!("{}{}", prefix, "ApplyImmediately"), &field_value);
-        }
-        if let Some(ref field_value) = obj.auto_minor_version_upgrade {
-            params.put(
-                &format!("{}{}", prefix, "AutoMinorVersionUpgrade"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.backup_retention_period {
-            params.put(
-                &format!("{}{}", prefix, "BackupRetentionPeriod"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.ca_certificate_identifier {
-            params.put(
-                &format!("{}{}", prefix, "CACertificateIdentifier"),
-                &field_value,
-            );
+impl ModifyDBInstanceMessageBuilder {
+    /// Sets the [`allocated_storage`](struct.ModifyDBInstanceMessage.html#structfield.allocated_storage) member.
+    pub fn allocated_storage<T: Into<i64>>(mut self, value: T) -> Self {
+        self.allocated_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`allow_major_version_upgrade`](struct.ModifyDBInstanceMessage.html#structfield.allow_major_version_upgrade) member.
+    pub fn allow_major_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_major_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`apply_immediately`](struct.ModifyDBInstanceMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_minor_version_upgrade`](struct.ModifyDBInstanceMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_period`](struct.ModifyDBInstanceMessage.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ca_certificate_identifier`](struct.ModifyDBInstanceMessage.html#structfield.ca_certificate_identifier) member.
+    pub fn ca_certificate_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.ca_certificate_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cloudwatch_logs_export_configuration`](struct.ModifyDBInstanceMessage.html#structfield.cloudwatch_logs_export_configuration) member.
+    pub fn cloudwatch_logs_export_configuration<T: Into<CloudwatchLogsExportConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cloudwatch_logs_export_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.ModifyDBInstanceMessage.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_class`](struct.ModifyDBInstanceMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_identifier`](struct.ModifyDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_name`](struct.ModifyDBInstanceMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_port_number`](struct.ModifyDBInstanceMessage.html#structfield.db_port_number) member.
+    pub fn db_port_number<T: Into<i64>>(mut self, value: T) -> Self {
+        self.db_port_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_security_groups`](struct.ModifyDBInstanceMessage.html#structfield.db_security_groups) member.
+    pub fn db_security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.db_security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.ModifyDBInstanceMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.ModifyDBInstanceMessage.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_iam_role_name`](struct.ModifyDBInstanceMessage.html#structfield.domain_iam_role_name) member.
+    pub fn domain_iam_role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_iam_role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.ModifyDBInstanceMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_performance_insights`](struct.ModifyDBInstanceMessage.html#structfield.enable_performance_insights) member.
+    pub fn enable_performance_insights<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_performance_insights = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.ModifyDBInstanceMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iops`](struct.ModifyDBInstanceMessage.html#structfield.iops) member.
+    pub fn iops<T: Into<i64>>(mut self, value: T) -> Self {
+        self.iops = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_model`](struct.ModifyDBInstanceMessage.html#structfield.license_model) member.
+    pub fn license_model<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_model = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.ModifyDBInstanceMessage.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`monitoring_interval`](struct.ModifyDBInstanceMessage.html#structfield.monitoring_interval) member.
+    pub fn monitoring_interval<T: Into<i64>>(mut self, value: T) -> Self {
+        self.monitoring_interval = Some(value.into());
+        self
+    }
+
+    /// Sets the [`monitoring_role_arn`](struct.ModifyDBInstanceMessage.html#structfield.monitoring_role_arn) member.
+    pub fn monitoring_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.monitoring_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.ModifyDBInstanceMessage.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_db_instance_identifier`](struct.ModifyDBInstanceMessage.html#structfield.new_db_instance_identifier) member.
+    pub fn new_db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.ModifyDBInstanceMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`performance_insights_kms_key_id`](struct.ModifyDBInstanceMessage.html#structfield.performance_insights_kms_key_id) member.
+    pub fn performance_insights_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.performance_insights_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.ModifyDBInstanceMessage.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.ModifyDBInstanceMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`promotion_tier`](struct.ModifyDBInstanceMessage.html#structfield.promotion_tier) member.
+    pub fn promotion_tier<T: Into<i64>>(mut self, value: T) -> Self {
+        self.promotion_tier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_type`](struct.ModifyDBInstanceMessage.html#structfield.storage_type) member.
+    pub fn storage_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.storage_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tde_credential_arn`](struct.ModifyDBInstanceMessage.html#structfield.tde_credential_arn) member.
+    pub fn tde_credential_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tde_credential_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tde_credential_password`](struct.ModifyDBInstanceMessage.html#structfield.tde_credential_password) member.
+    pub fn tde_credential_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.tde_credential_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.ModifyDBInstanceMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBInstanceMessage {
+            allocated_storage: self.allocated_storage,
+            allow_major_version_upgrade: self.allow_major_version_upgrade,
+            apply_immediately: self.apply_immediately,
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            backup_retention_period: self.backup_retention_period,
+            ca_certificate_identifier: self.ca_certificate_identifier,
+            cloudwatch_logs_export_configuration: self.cloudwatch_logs_export_configuration,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_instance_class: self.db_instance_class,
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            db_parameter_group_name: self.db_parameter_group_name,
+            db_port_number: self.db_port_number,
+            db_security_groups: self.db_security_groups,
+            db_subnet_group_name: self.db_subnet_group_name,
+            domain: self.domain,
+            domain_iam_role_name: self.domain_iam_role_name,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            enable_performance_insights: self.enable_performance_insights,
+            engine_version: self.engine_version,
+            iops: self.iops,
+            license_model: self.license_model,
+            master_user_password: self.master_user_password,
+            monitoring_interval: self.monitoring_interval,
+            monitoring_role_arn: self.monitoring_role_arn,
+            multi_az: self.multi_az,
+            new_db_instance_identifier: self.new_db_instance_identifier,
+            option_group_name: self.option_group_name,
+            performance_insights_kms_key_id: self.performance_insights_kms_key_id,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            promotion_tier: self.promotion_tier,
+            storage_type: self.storage_type,
+            tde_credential_arn: self.tde_credential_arn,
+            tde_credential_password: self.tde_credential_password,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
+/// Serialize `ModifyDBInstanceMessage` contents to a `SignedRequest`.
+struct ModifyDBInstanceMessageSerializer;
+impl ModifyDBInstanceMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &ModifyDBInstanceMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.allocated_storage {
+            params.put(&format!("{}{}", prefix, "AllocatedStorage"), &field_value);
+        }
+        if let Some(ref field_value) = obj.allow_major_version_upgrade {
+            params.put(
+                &format!("{}{}", prefix, "AllowMajorVersionUpgrade"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.apply_immediately {
+            params.put(&format!("{}{}", prefix, "ApplyImmediately"), &field_value);
+        }
+        if let Some(ref field_value) = obj.auto_minor_version_upgrade {
+            params.put(
+                &format!("{}{}", prefix, "AutoMinorVersionUpgrade"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.backup_retention_period {
+            params.put(
+                &format!("{}{}", prefix, "BackupRetentionPeriod"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.ca_certificate_identifier {
+            params.put(
+                &format!("{}{}", prefix, "CACertificateIdentifier"),
+                &field_value,
+            );
         }
         if let Some(ref field_value) = obj.cloudwatch_logs_export_configuration {
             CloudwatchLogsExportConfigurationSerializer::serialize(
@@ -5975,10 +9299,10 @@ pub struct ModifyDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct ModifyDBInstanceResultDeserializer;
+pub(crate) struct ModifyDBInstanceResultDeserializer;
 impl ModifyDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBInstanceResult, XmlParseError> {
@@ -6002,6 +9326,48 @@ pub struct ModifyDBParameterGroupMessage {
     pub parameters: Vec<Parameter>,
 }
 
+impl ModifyDBParameterGroupMessage {
+    /// Returns a builder for `ModifyDBParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBParameterGroupMessageBuilder {
+        ModifyDBParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBParameterGroupMessage`](struct.ModifyDBParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBParameterGroupMessageBuilder {
+    db_parameter_group_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+}
+
+impl ModifyDBParameterGroupMessageBuilder {
+    /// Sets the [`db_parameter_group_name`](struct.ModifyDBParameterGroupMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ModifyDBParameterGroupMessage.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBParameterGroupMessage {
+            db_parameter_group_name: self.db_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_name"),
+            )?,
+            parameters: self
+                .parameters
+                .ok_or(::rusoto_core::BuildError::missing("parameters"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyDBParameterGroupMessage` contents to a `SignedRequest`.
 struct ModifyDBParameterGroupMessageSerializer;
 impl ModifyDBParameterGroupMessageSerializer {
@@ -6033,6 +9399,56 @@ pub struct ModifyDBSubnetGroupMessage {
     pub subnet_ids: Vec<String>,
 }
 
+impl ModifyDBSubnetGroupMessage {
+    /// Returns a builder for `ModifyDBSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBSubnetGroupMessageBuilder {
+        ModifyDBSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBSubnetGroupMessage`](struct.ModifyDBSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBSubnetGroupMessageBuilder {
+    db_subnet_group_description: Option<String>,
+    db_subnet_group_name: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+}
+
+impl ModifyDBSubnetGroupMessageBuilder {
+    /// Sets the [`db_subnet_group_description`](struct.ModifyDBSubnetGroupMessage.html#structfield.db_subnet_group_description) member.
+    pub fn db_subnet_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.ModifyDBSubnetGroupMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.ModifyDBSubnetGroupMessage.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBSubnetGroupMessage {
+            db_subnet_group_description: self.db_subnet_group_description,
+            db_subnet_group_name: self
+                .db_subnet_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_subnet_group_name"))?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyDBSubnetGroupMessage` contents to a `SignedRequest`.
 struct ModifyDBSubnetGroupMessageSerializer;
 impl ModifyDBSubnetGroupMessageSerializer {
@@ -6065,10 +9481,10 @@ pub struct ModifyDBSubnetGroupResult {
     pub db_subnet_group: Option<DBSubnetGroup>,
 }
 
-struct ModifyDBSubnetGroupResultDeserializer;
+pub(crate) struct ModifyDBSubnetGroupResultDeserializer;
 impl ModifyDBSubnetGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBSubnetGroupResult, XmlParseError> {
@@ -6104,6 +9520,70 @@ pub struct ModifyEventSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl ModifyEventSubscriptionMessage {
+    /// Returns a builder for `ModifyEventSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> ModifyEventSubscriptionMessageBuilder {
+        ModifyEventSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyEventSubscriptionMessage`](struct.ModifyEventSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyEventSubscriptionMessageBuilder {
+    enabled: Option<bool>,
+    event_categories: Option<Vec<String>>,
+    sns_topic_arn: Option<String>,
+    source_type: Option<String>,
+    subscription_name: Option<String>,
+}
+
+impl ModifyEventSubscriptionMessageBuilder {
+    /// Sets the [`enabled`](struct.ModifyEventSubscriptionMessage.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_categories`](struct.ModifyEventSubscriptionMessage.html#structfield.event_categories) member.
+    pub fn event_categories<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_categories = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.ModifyEventSubscriptionMessage.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.ModifyEventSubscriptionMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.ModifyEventSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyEventSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyEventSubscriptionMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyEventSubscriptionMessage {
+            enabled: self.enabled,
+            event_categories: self.event_categories,
+            sns_topic_arn: self.sns_topic_arn,
+            source_type: self.source_type,
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyEventSubscriptionMessage` contents to a `SignedRequest`.
 struct ModifyEventSubscriptionMessageSerializer;
 impl ModifyEventSubscriptionMessageSerializer {
@@ -6141,10 +9621,10 @@ pub struct ModifyEventSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
 
-struct ModifyEventSubscriptionResultDeserializer;
+pub(crate) struct ModifyEventSubscriptionResultDeserializer;
 impl ModifyEventSubscriptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyEventSubscriptionResult, XmlParseError> {
@@ -6175,10 +9655,10 @@ pub struct OptionGroupMembership {
     pub status: Option<String>,
 }
 
-struct OptionGroupMembershipDeserializer;
+pub(crate) struct OptionGroupMembershipDeserializer;
 impl OptionGroupMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OptionGroupMembership, XmlParseError> {
@@ -6197,10 +9677,10 @@ impl OptionGroupMembershipDeserializer {
         })
     }
 }
-struct OptionGroupMembershipListDeserializer;
+pub(crate) struct OptionGroupMembershipListDeserializer;
 impl OptionGroupMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OptionGroupMembership>, XmlParseError> {
@@ -6262,10 +9742,10 @@ pub struct OrderableDBInstanceOption {
     pub vpc: Option<bool>,
 }
 
-struct OrderableDBInstanceOptionDeserializer;
+pub(crate) struct OrderableDBInstanceOptionDeserializer;
 impl OrderableDBInstanceOptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OrderableDBInstanceOption, XmlParseError> {
@@ -6388,10 +9868,10 @@ impl OrderableDBInstanceOptionDeserializer {
         )
     }
 }
-struct OrderableDBInstanceOptionsListDeserializer;
+pub(crate) struct OrderableDBInstanceOptionsListDeserializer;
 impl OrderableDBInstanceOptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OrderableDBInstanceOption>, XmlParseError> {
@@ -6416,10 +9896,10 @@ pub struct OrderableDBInstanceOptionsMessage {
     pub orderable_db_instance_options: Option<Vec<OrderableDBInstanceOption>>,
 }
 
-struct OrderableDBInstanceOptionsMessageDeserializer;
+pub(crate) struct OrderableDBInstanceOptionsMessageDeserializer;
 impl OrderableDBInstanceOptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OrderableDBInstanceOptionsMessage, XmlParseError> {
@@ -6452,7 +9932,7 @@ pub struct Parameter {
     /// <p>Specifies the valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
     /// <p>Indicates when to apply parameter updates.</p>
-    pub apply_method: Option<String>,
+    pub apply_method: Option<ApplyMethod>,
     /// <p>Specifies the engine specific parameters type.</p>
     pub apply_type: Option<String>,
     /// <p>Specifies the valid data type for the parameter.</p>
@@ -6471,10 +9951,10 @@ pub struct Parameter {
     pub source: Option<String>,
 }
 
-struct ParameterDeserializer;
+pub(crate) struct ParameterDeserializer;
 impl ParameterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Parameter, XmlParseError> {
@@ -6570,10 +10050,10 @@ impl ParameterSerializer {
     }
 }
 
-struct ParametersListDeserializer;
+pub(crate) struct ParametersListDeserializer;
 impl ParametersListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Parameter>, XmlParseError> {
@@ -6608,10 +10088,10 @@ pub struct PendingCloudwatchLogsExports {
     pub log_types_to_enable: Option<Vec<String>>,
 }
 
-struct PendingCloudwatchLogsExportsDeserializer;
+pub(crate) struct PendingCloudwatchLogsExportsDeserializer;
 impl PendingCloudwatchLogsExportsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingCloudwatchLogsExports, XmlParseError> {
@@ -6643,21 +10123,21 @@ pub struct PendingMaintenanceAction {
     /// <p>The type of pending maintenance action that is available for the resource.</p>
     pub action: Option<String>,
     /// <p>The date of the maintenance window when the action is applied. The maintenance action is applied to the resource during its first maintenance window after this date. If this date is specified, any <code>next-maintenance</code> opt-in requests are ignored.</p>
-    pub auto_applied_after_date: Option<String>,
+    pub auto_applied_after_date: Option<IsoTimestamp>,
     /// <p>The effective date when the pending maintenance action is applied to the resource. This date takes into account opt-in requests received from the <a>ApplyPendingMaintenanceAction</a> API, the <code>AutoAppliedAfterDate</code>, and the <code>ForcedApplyDate</code>. This value is blank if an opt-in request has not been received and nothing has been specified as <code>AutoAppliedAfterDate</code> or <code>ForcedApplyDate</code>.</p>
-    pub current_apply_date: Option<String>,
+    pub current_apply_date: Option<IsoTimestamp>,
     /// <p>A description providing more detail about the maintenance action.</p>
     pub description: Option<String>,
     /// <p>The date when the maintenance action is automatically applied. The maintenance action is applied to the resource on this date regardless of the maintenance window for the resource. If this date is specified, any <code>immediate</code> opt-in requests are ignored.</p>
-    pub forced_apply_date: Option<String>,
+    pub forced_apply_date: Option<IsoTimestamp>,
     /// <p>Indicates the type of opt-in request that has been received for the resource.</p>
     pub opt_in_status: Option<String>,
 }
 
-struct PendingMaintenanceActionDeserializer;
+pub(crate) struct PendingMaintenanceActionDeserializer;
 impl PendingMaintenanceActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingMaintenanceAction, XmlParseError> {
@@ -6698,10 +10178,10 @@ impl PendingMaintenanceActionDeserializer {
         )
     }
 }
-struct PendingMaintenanceActionDetailsDeserializer;
+pub(crate) struct PendingMaintenanceActionDetailsDeserializer;
 impl PendingMaintenanceActionDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PendingMaintenanceAction>, XmlParseError> {
@@ -6718,10 +10198,10 @@ impl PendingMaintenanceActionDetailsDeserializer {
         })
     }
 }
-struct PendingMaintenanceActionsDeserializer;
+pub(crate) struct PendingMaintenanceActionsDeserializer;
 impl PendingMaintenanceActionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ResourcePendingMaintenanceActions>, XmlParseError> {
@@ -6746,10 +10226,10 @@ pub struct PendingMaintenanceActionsMessage {
     pub pending_maintenance_actions: Option<Vec<ResourcePendingMaintenanceActions>>,
 }
 
-struct PendingMaintenanceActionsMessageDeserializer;
+pub(crate) struct PendingMaintenanceActionsMessageDeserializer;
 impl PendingMaintenanceActionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingMaintenanceActionsMessage, XmlParseError> {
@@ -6809,10 +10289,10 @@ pub struct PendingModifiedValues {
     pub storage_type: Option<String>,
 }
 
-struct PendingModifiedValuesDeserializer;
+pub(crate) struct PendingModifiedValuesDeserializer;
 impl PendingModifiedValuesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingModifiedValues, XmlParseError> {
@@ -6896,6 +10376,38 @@ pub struct PromoteReadReplicaDBClusterMessage {
     pub db_cluster_identifier: String,
 }
 
+impl PromoteReadReplicaDBClusterMessage {
+    /// Returns a builder for `PromoteReadReplicaDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> PromoteReadReplicaDBClusterMessageBuilder {
+        PromoteReadReplicaDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`PromoteReadReplicaDBClusterMessage`](struct.PromoteReadReplicaDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct PromoteReadReplicaDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+}
+
+impl PromoteReadReplicaDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.PromoteReadReplicaDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `PromoteReadReplicaDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PromoteReadReplicaDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(PromoteReadReplicaDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `PromoteReadReplicaDBClusterMessage` contents to a `SignedRequest`.
 struct PromoteReadReplicaDBClusterMessageSerializer;
 impl PromoteReadReplicaDBClusterMessageSerializer {
@@ -6917,10 +10429,10 @@ pub struct PromoteReadReplicaDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct PromoteReadReplicaDBClusterResultDeserializer;
+pub(crate) struct PromoteReadReplicaDBClusterResultDeserializer;
 impl PromoteReadReplicaDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PromoteReadReplicaDBClusterResult, XmlParseError> {
@@ -6951,10 +10463,13 @@ pub struct Range {
     pub to: Option<i64>,
 }
 
-struct RangeDeserializer;
+pub(crate) struct RangeDeserializer;
 impl RangeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Range, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Range, XmlParseError> {
         deserialize_elements::<_, Range, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "From" => {
@@ -6972,10 +10487,10 @@ impl RangeDeserializer {
         })
     }
 }
-struct RangeListDeserializer;
+pub(crate) struct RangeListDeserializer;
 impl RangeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Range>, XmlParseError> {
@@ -6989,10 +10504,10 @@ impl RangeListDeserializer {
         })
     }
 }
-struct ReadReplicaDBClusterIdentifierListDeserializer;
+pub(crate) struct ReadReplicaDBClusterIdentifierListDeserializer;
 impl ReadReplicaDBClusterIdentifierListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -7009,10 +10524,10 @@ impl ReadReplicaDBClusterIdentifierListDeserializer {
         })
     }
 }
-struct ReadReplicaDBInstanceIdentifierListDeserializer;
+pub(crate) struct ReadReplicaDBInstanceIdentifierListDeserializer;
 impl ReadReplicaDBInstanceIdentifierListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -7029,10 +10544,10 @@ impl ReadReplicaDBInstanceIdentifierListDeserializer {
         })
     }
 }
-struct ReadReplicaIdentifierListDeserializer;
+pub(crate) struct ReadReplicaIdentifierListDeserializer;
 impl ReadReplicaIdentifierListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -7057,6 +10572,46 @@ pub struct RebootDBInstanceMessage {
     pub force_failover: Option<bool>,
 }
 
+impl RebootDBInstanceMessage {
+    /// Returns a builder for `RebootDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> RebootDBInstanceMessageBuilder {
+        RebootDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RebootDBInstanceMessage`](struct.RebootDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct RebootDBInstanceMessageBuilder {
+    db_instance_identifier: Option<String>,
+    force_failover: Option<bool>,
+}
+
+impl RebootDBInstanceMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.RebootDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_failover`](struct.RebootDBInstanceMessage.html#structfield.force_failover) member.
+    pub fn force_failover<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_failover = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebootDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RebootDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(RebootDBInstanceMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            force_failover: self.force_failover,
+        })
+    }
+}
+
 /// Serialize `RebootDBInstanceMessage` contents to a `SignedRequest`.
 struct RebootDBInstanceMessageSerializer;
 impl RebootDBInstanceMessageSerializer {
@@ -7081,10 +10636,10 @@ pub struct RebootDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct RebootDBInstanceResultDeserializer;
+pub(crate) struct RebootDBInstanceResultDeserializer;
 impl RebootDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RebootDBInstanceResult, XmlParseError> {
@@ -7108,6 +10663,48 @@ pub struct RemoveRoleFromDBClusterMessage {
     pub role_arn: String,
 }
 
+impl RemoveRoleFromDBClusterMessage {
+    /// Returns a builder for `RemoveRoleFromDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> RemoveRoleFromDBClusterMessageBuilder {
+        RemoveRoleFromDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveRoleFromDBClusterMessage`](struct.RemoveRoleFromDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct RemoveRoleFromDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    role_arn: Option<String>,
+}
+
+impl RemoveRoleFromDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.RemoveRoleFromDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.RemoveRoleFromDBClusterMessage.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveRoleFromDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveRoleFromDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(RemoveRoleFromDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveRoleFromDBClusterMessage` contents to a `SignedRequest`.
 struct RemoveRoleFromDBClusterMessageSerializer;
 impl RemoveRoleFromDBClusterMessageSerializer {
@@ -7133,6 +10730,51 @@ pub struct RemoveSourceIdentifierFromSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl RemoveSourceIdentifierFromSubscriptionMessage {
+    /// Returns a builder for `RemoveSourceIdentifierFromSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> RemoveSourceIdentifierFromSubscriptionMessageBuilder {
+        RemoveSourceIdentifierFromSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveSourceIdentifierFromSubscriptionMessage`](struct.RemoveSourceIdentifierFromSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct RemoveSourceIdentifierFromSubscriptionMessageBuilder {
+    source_identifier: Option<String>,
+    subscription_name: Option<String>,
+}
+
+impl RemoveSourceIdentifierFromSubscriptionMessageBuilder {
+    /// Sets the [`source_identifier`](struct.RemoveSourceIdentifierFromSubscriptionMessage.html#structfield.source_identifier) member.
+    pub fn source_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.RemoveSourceIdentifierFromSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveSourceIdentifierFromSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        RemoveSourceIdentifierFromSubscriptionMessage,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(RemoveSourceIdentifierFromSubscriptionMessage {
+            source_identifier: self
+                .source_identifier
+                .ok_or(::rusoto_core::BuildError::missing("source_identifier"))?,
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveSourceIdentifierFromSubscriptionMessage` contents to a `SignedRequest`.
 struct RemoveSourceIdentifierFromSubscriptionMessageSerializer;
 impl RemoveSourceIdentifierFromSubscriptionMessageSerializer {
@@ -7162,10 +10804,10 @@ pub struct RemoveSourceIdentifierFromSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
 
-struct RemoveSourceIdentifierFromSubscriptionResultDeserializer;
+pub(crate) struct RemoveSourceIdentifierFromSubscriptionResultDeserializer;
 impl RemoveSourceIdentifierFromSubscriptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RemoveSourceIdentifierFromSubscriptionResult, XmlParseError> {
@@ -7195,6 +10837,48 @@ pub struct RemoveTagsFromResourceMessage {
     pub tag_keys: Vec<String>,
 }
 
+impl RemoveTagsFromResourceMessage {
+    /// Returns a builder for `RemoveTagsFromResourceMessage`, checking its required members when built.
+    pub fn builder() -> RemoveTagsFromResourceMessageBuilder {
+        RemoveTagsFromResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsFromResourceMessage`](struct.RemoveTagsFromResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsFromResourceMessageBuilder {
+    resource_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl RemoveTagsFromResourceMessageBuilder {
+    /// Sets the [`resource_name`](struct.RemoveTagsFromResourceMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.RemoveTagsFromResourceMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsFromResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveTagsFromResourceMessage, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsFromResourceMessage {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveTagsFromResourceMessage` contents to a `SignedRequest`.
 struct RemoveTagsFromResourceMessageSerializer;
 impl RemoveTagsFromResourceMessageSerializer {
@@ -7219,6 +10903,54 @@ pub struct ResetDBClusterParameterGroupMessage {
     pub reset_all_parameters: Option<bool>,
 }
 
+impl ResetDBClusterParameterGroupMessage {
+    /// Returns a builder for `ResetDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ResetDBClusterParameterGroupMessageBuilder {
+        ResetDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ResetDBClusterParameterGroupMessage`](struct.ResetDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ResetDBClusterParameterGroupMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+    reset_all_parameters: Option<bool>,
+}
+
+impl ResetDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.ResetDBClusterParameterGroupMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ResetDBClusterParameterGroupMessage.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reset_all_parameters`](struct.ResetDBClusterParameterGroupMessage.html#structfield.reset_all_parameters) member.
+    pub fn reset_all_parameters<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reset_all_parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResetDBClusterParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ResetDBClusterParameterGroupMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+            parameters: self.parameters,
+            reset_all_parameters: self.reset_all_parameters,
+        })
+    }
+}
+
 /// Serialize `ResetDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct ResetDBClusterParameterGroupMessageSerializer;
 impl ResetDBClusterParameterGroupMessageSerializer {
@@ -7255,6 +10987,54 @@ pub struct ResetDBParameterGroupMessage {
     pub reset_all_parameters: Option<bool>,
 }
 
+impl ResetDBParameterGroupMessage {
+    /// Returns a builder for `ResetDBParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ResetDBParameterGroupMessageBuilder {
+        ResetDBParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ResetDBParameterGroupMessage`](struct.ResetDBParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ResetDBParameterGroupMessageBuilder {
+    db_parameter_group_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+    reset_all_parameters: Option<bool>,
+}
+
+impl ResetDBParameterGroupMessageBuilder {
+    /// Sets the [`db_parameter_group_name`](struct.ResetDBParameterGroupMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ResetDBParameterGroupMessage.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reset_all_parameters`](struct.ResetDBParameterGroupMessage.html#structfield.reset_all_parameters) member.
+    pub fn reset_all_parameters<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reset_all_parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetDBParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResetDBParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ResetDBParameterGroupMessage {
+            db_parameter_group_name: self.db_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_name"),
+            )?,
+            parameters: self.parameters,
+            reset_all_parameters: self.reset_all_parameters,
+        })
+    }
+}
+
 /// Serialize `ResetDBParameterGroupMessage` contents to a `SignedRequest`.
 struct ResetDBParameterGroupMessageSerializer;
 impl ResetDBParameterGroupMessageSerializer {
@@ -7290,10 +11070,10 @@ pub struct ResourcePendingMaintenanceActions {
     pub resource_identifier: Option<String>,
 }
 
-struct ResourcePendingMaintenanceActionsDeserializer;
+pub(crate) struct ResourcePendingMaintenanceActionsDeserializer;
 impl ResourcePendingMaintenanceActionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourcePendingMaintenanceActions, XmlParseError> {
@@ -7357,12 +11137,160 @@ pub struct RestoreDBClusterFromSnapshotMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
-/// Serialize `RestoreDBClusterFromSnapshotMessage` contents to a `SignedRequest`.
-struct RestoreDBClusterFromSnapshotMessageSerializer;
-impl RestoreDBClusterFromSnapshotMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &RestoreDBClusterFromSnapshotMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
+impl RestoreDBClusterFromSnapshotMessage {
+    /// Returns a builder for `RestoreDBClusterFromSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> RestoreDBClusterFromSnapshotMessageBuilder {
+        RestoreDBClusterFromSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreDBClusterFromSnapshotMessage`](struct.RestoreDBClusterFromSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct RestoreDBClusterFromSnapshotMessageBuilder {
+    availability_zones: Option<Vec<String>>,
+    db_cluster_identifier: Option<String>,
+    db_cluster_parameter_group_name: Option<String>,
+    db_subnet_group_name: Option<String>,
+    database_name: Option<String>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_iam_database_authentication: Option<bool>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    kms_key_id: Option<String>,
+    option_group_name: Option<String>,
+    port: Option<i64>,
+    snapshot_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl RestoreDBClusterFromSnapshotMessageBuilder {
+    /// Sets the [`availability_zones`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_parameter_group_name`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_identifier`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.snapshot_identifier) member.
+    pub fn snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreDBClusterFromSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreDBClusterFromSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(RestoreDBClusterFromSnapshotMessage {
+            availability_zones: self.availability_zones,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            db_subnet_group_name: self.db_subnet_group_name,
+            database_name: self.database_name,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_version: self.engine_version,
+            kms_key_id: self.kms_key_id,
+            option_group_name: self.option_group_name,
+            port: self.port,
+            snapshot_identifier: self
+                .snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_identifier"))?,
+            tags: self.tags,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
+/// Serialize `RestoreDBClusterFromSnapshotMessage` contents to a `SignedRequest`.
+struct RestoreDBClusterFromSnapshotMessageSerializer;
+impl RestoreDBClusterFromSnapshotMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &RestoreDBClusterFromSnapshotMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
             prefix.push_str(".");
         }
 
@@ -7437,10 +11365,10 @@ pub struct RestoreDBClusterFromSnapshotResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct RestoreDBClusterFromSnapshotResultDeserializer;
+pub(crate) struct RestoreDBClusterFromSnapshotResultDeserializer;
 impl RestoreDBClusterFromSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreDBClusterFromSnapshotResult, XmlParseError> {
@@ -7479,7 +11407,7 @@ pub struct RestoreDBClusterToPointInTimeMessage {
     /// <p>The port number on which the new DB cluster accepts connections.</p> <p>Constraints: Value must be <code>1150-65535</code> </p> <p>Default: The same port as the original DB cluster.</p>
     pub port: Option<i64>,
     /// <p>The date and time to restore the DB cluster to.</p> <p>Valid Values: Value must be a time in Universal Coordinated Time (UTC) format</p> <p>Constraints:</p> <ul> <li> <p>Must be before the latest restorable time for the DB instance</p> </li> <li> <p>Must be specified if <code>UseLatestRestorableTime</code> parameter is not provided</p> </li> <li> <p>Cannot be specified if <code>UseLatestRestorableTime</code> parameter is true</p> </li> <li> <p>Cannot be specified if <code>RestoreType</code> parameter is <code>copy-on-write</code> </p> </li> </ul> <p>Example: <code>2015-03-07T23:45:00Z</code> </p>
-    pub restore_to_time: Option<String>,
+    pub restore_to_time: Option<IsoTimestamp>,
     /// <p>The type of restore to be performed. You can specify one of the following values:</p> <ul> <li> <p> <code>full-copy</code> - The new DB cluster is restored as a full copy of the source DB cluster.</p> </li> <li> <p> <code>copy-on-write</code> - The new DB cluster is restored as a clone of the source DB cluster.</p> </li> </ul> <p>If you don't specify a <code>RestoreType</code> value, then the new DB cluster is restored as a full copy of the source DB cluster.</p>
     pub restore_type: Option<String>,
     /// <p><p>The identifier of the source DB cluster from which to restore.</p> <p>Constraints:</p> <ul> <li> <p>Must match the identifier of an existing DBCluster.</p> </li> </ul></p>
@@ -7492,6 +11420,145 @@ pub struct RestoreDBClusterToPointInTimeMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl RestoreDBClusterToPointInTimeMessage {
+    /// Returns a builder for `RestoreDBClusterToPointInTimeMessage`, checking its required members when built.
+    pub fn builder() -> RestoreDBClusterToPointInTimeMessageBuilder {
+        RestoreDBClusterToPointInTimeMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreDBClusterToPointInTimeMessage`](struct.RestoreDBClusterToPointInTimeMessage.html).
+#[derive(Default, Debug)]
+pub struct RestoreDBClusterToPointInTimeMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    db_cluster_parameter_group_name: Option<String>,
+    db_subnet_group_name: Option<String>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_iam_database_authentication: Option<bool>,
+    kms_key_id: Option<String>,
+    option_group_name: Option<String>,
+    port: Option<i64>,
+    restore_to_time: Option<IsoTimestamp>,
+    restore_type: Option<String>,
+    source_db_cluster_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    use_latest_restorable_time: Option<bool>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl RestoreDBClusterToPointInTimeMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_parameter_group_name`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`restore_to_time`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.restore_to_time) member.
+    pub fn restore_to_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.restore_to_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`restore_type`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.restore_type) member.
+    pub fn restore_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.restore_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_db_cluster_identifier`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.source_db_cluster_identifier) member.
+    pub fn source_db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_latest_restorable_time`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.use_latest_restorable_time) member.
+    pub fn use_latest_restorable_time<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_latest_restorable_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreDBClusterToPointInTimeMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreDBClusterToPointInTimeMessage, ::rusoto_core::BuildError>
+    {
+        Ok(RestoreDBClusterToPointInTimeMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            db_subnet_group_name: self.db_subnet_group_name,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            kms_key_id: self.kms_key_id,
+            option_group_name: self.option_group_name,
+            port: self.port,
+            restore_to_time: self.restore_to_time,
+            restore_type: self.restore_type,
+            source_db_cluster_identifier: self.source_db_cluster_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_db_cluster_identifier"),
+            )?,
+            tags: self.tags,
+            use_latest_restorable_time: self.use_latest_restorable_time,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `RestoreDBClusterToPointInTimeMessage` contents to a `SignedRequest`.
 struct RestoreDBClusterToPointInTimeMessageSerializer;
 impl RestoreDBClusterToPointInTimeMessageSerializer {
@@ -7570,10 +11637,10 @@ pub struct RestoreDBClusterToPointInTimeResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct RestoreDBClusterToPointInTimeResultDeserializer;
+pub(crate) struct RestoreDBClusterToPointInTimeResultDeserializer;
 impl RestoreDBClusterToPointInTimeResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreDBClusterToPointInTimeResult, XmlParseError> {
@@ -7593,10 +11660,10 @@ impl RestoreDBClusterToPointInTimeResultDeserializer {
         )
     }
 }
-struct SourceIdsListDeserializer;
+pub(crate) struct SourceIdsListDeserializer;
 impl SourceIdsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -7622,21 +11689,102 @@ impl SourceIdsListSerializer {
     }
 }
 
-struct SourceTypeDeserializer;
+pub(crate) struct SourceTypeDeserializer;
 impl SourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct StringDeserializer;
+/// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceType {
+    /// <p><code>db-instance</code></p>
+    DbInstance,
+    /// <p><code>db-parameter-group</code></p>
+    DbParameterGroup,
+    /// <p><code>db-security-group</code></p>
+    DbSecurityGroup,
+    /// <p><code>db-snapshot</code></p>
+    DbSnapshot,
+    /// <p><code>db-cluster</code></p>
+    DbCluster,
+    /// <p><code>db-cluster-snapshot</code></p>
+    DbClusterSnapshot,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::DbInstance => "db-instance",
+            SourceType::DbParameterGroup => "db-parameter-group",
+            SourceType::DbSecurityGroup => "db-security-group",
+            SourceType::DbSnapshot => "db-snapshot",
+            SourceType::DbCluster => "db-cluster",
+            SourceType::DbClusterSnapshot => "db-cluster-snapshot",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "db-instance" => SourceType::DbInstance,
+            "db-parameter-group" => SourceType::DbParameterGroup,
+            "db-security-group" => SourceType::DbSecurityGroup,
+            "db-snapshot" => SourceType::DbSnapshot,
+            "db-cluster" => SourceType::DbCluster,
+            "db-cluster-snapshot" => SourceType::DbClusterSnapshot,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        SourceType::from(value.as_str())
+    }
+}
+
+impl From<SourceType> for String {
+    fn from(value: SourceType) -> String {
+        match value {
+            SourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct StringDeserializer;
 impl StringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -7655,10 +11803,13 @@ pub struct Subnet {
     pub subnet_status: Option<String>,
 }
 
-struct SubnetDeserializer;
+pub(crate) struct SubnetDeserializer;
 impl SubnetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Subnet, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Subnet, XmlParseError> {
         deserialize_elements::<_, Subnet, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "SubnetAvailabilityZone" => {
@@ -7693,10 +11844,10 @@ impl SubnetIdentifierListSerializer {
     }
 }
 
-struct SubnetListDeserializer;
+pub(crate) struct SubnetListDeserializer;
 impl SubnetListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Subnet>, XmlParseError> {
@@ -7710,10 +11861,10 @@ impl SubnetListDeserializer {
         })
     }
 }
-struct SupportedCharacterSetsListDeserializer;
+pub(crate) struct SupportedCharacterSetsListDeserializer;
 impl SupportedCharacterSetsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CharacterSet>, XmlParseError> {
@@ -7730,10 +11881,10 @@ impl SupportedCharacterSetsListDeserializer {
         })
     }
 }
-struct SupportedTimezonesListDeserializer;
+pub(crate) struct SupportedTimezonesListDeserializer;
 impl SupportedTimezonesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Timezone>, XmlParseError> {
@@ -7747,12 +11898,15 @@ impl SupportedTimezonesListDeserializer {
         })
     }
 }
-struct TStampDeserializer;
+pub(crate) struct TStampDeserializer;
 impl TStampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -7767,10 +11921,13 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -7804,10 +11961,10 @@ impl TagSerializer {
     }
 }
 
-struct TagListDeserializer;
+pub(crate) struct TagListDeserializer;
 impl TagListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -7839,10 +11996,10 @@ pub struct TagListMessage {
     pub tag_list: Option<Vec<Tag>>,
 }
 
-struct TagListMessageDeserializer;
+pub(crate) struct TagListMessageDeserializer;
 impl TagListMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TagListMessage, XmlParseError> {
@@ -7866,10 +12023,10 @@ pub struct Timezone {
     pub timezone_name: Option<String>,
 }
 
-struct TimezoneDeserializer;
+pub(crate) struct TimezoneDeserializer;
 impl TimezoneDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Timezone, XmlParseError> {
@@ -7900,10 +12057,10 @@ pub struct UpgradeTarget {
     pub is_major_version_upgrade: Option<bool>,
 }
 
-struct UpgradeTargetDeserializer;
+pub(crate) struct UpgradeTargetDeserializer;
 impl UpgradeTargetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpgradeTarget, XmlParseError> {
@@ -7942,10 +12099,10 @@ pub struct ValidDBInstanceModificationsMessage {
     pub storage: Option<Vec<ValidStorageOptions>>,
 }
 
-struct ValidDBInstanceModificationsMessageDeserializer;
+pub(crate) struct ValidDBInstanceModificationsMessageDeserializer;
 impl ValidDBInstanceModificationsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ValidDBInstanceModificationsMessage, XmlParseError> {
@@ -7979,10 +12136,10 @@ pub struct ValidStorageOptions {
     pub storage_type: Option<String>,
 }
 
-struct ValidStorageOptionsDeserializer;
+pub(crate) struct ValidStorageOptionsDeserializer;
 impl ValidStorageOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ValidStorageOptions, XmlParseError> {
@@ -8012,10 +12169,10 @@ impl ValidStorageOptionsDeserializer {
         })
     }
 }
-struct ValidStorageOptionsListDeserializer;
+pub(crate) struct ValidStorageOptionsListDeserializer;
 impl ValidStorageOptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ValidStorageOptions>, XmlParseError> {
@@ -8032,10 +12189,10 @@ impl ValidStorageOptionsListDeserializer {
         })
     }
 }
-struct ValidUpgradeTargetListDeserializer;
+pub(crate) struct ValidUpgradeTargetListDeserializer;
 impl ValidUpgradeTargetListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<UpgradeTarget>, XmlParseError> {
@@ -8073,10 +12230,10 @@ pub struct VpcSecurityGroupMembership {
     pub vpc_security_group_id: Option<String>,
 }
 
-struct VpcSecurityGroupMembershipDeserializer;
+pub(crate) struct VpcSecurityGroupMembershipDeserializer;
 impl VpcSecurityGroupMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<VpcSecurityGroupMembership, XmlParseError> {
@@ -8101,10 +12258,10 @@ impl VpcSecurityGroupMembershipDeserializer {
         )
     }
 }
-struct VpcSecurityGroupMembershipListDeserializer;
+pub(crate) struct VpcSecurityGroupMembershipListDeserializer;
 impl VpcSecurityGroupMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<VpcSecurityGroupMembership>, XmlParseError> {
@@ -12750,6 +16907,374 @@ pub trait Neptune {
         input: RestoreDBClusterToPointInTimeMessage,
     ) -> RusotoFuture<RestoreDBClusterToPointInTimeResult, RestoreDBClusterToPointInTimeError>;
 }
+impl<T: ?Sized + Neptune> Neptune for ::std::sync::Arc<T> {
+    fn add_role_to_db_cluster(
+        &self,
+        input: AddRoleToDBClusterMessage,
+    ) -> RusotoFuture<(), AddRoleToDBClusterError> {
+        (**self).add_role_to_db_cluster(input)
+    }
+    fn add_source_identifier_to_subscription(
+        &self,
+        input: AddSourceIdentifierToSubscriptionMessage,
+    ) -> RusotoFuture<AddSourceIdentifierToSubscriptionResult, AddSourceIdentifierToSubscriptionError>
+    {
+        (**self).add_source_identifier_to_subscription(input)
+    }
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<(), AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> RusotoFuture<ApplyPendingMaintenanceActionResult, ApplyPendingMaintenanceActionError> {
+        (**self).apply_pending_maintenance_action(input)
+    }
+    fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CopyDBClusterParameterGroupResult, CopyDBClusterParameterGroupError> {
+        (**self).copy_db_cluster_parameter_group(input)
+    }
+    fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CopyDBClusterSnapshotResult, CopyDBClusterSnapshotError> {
+        (**self).copy_db_cluster_snapshot(input)
+    }
+    fn copy_db_parameter_group(
+        &self,
+        input: CopyDBParameterGroupMessage,
+    ) -> RusotoFuture<CopyDBParameterGroupResult, CopyDBParameterGroupError> {
+        (**self).copy_db_parameter_group(input)
+    }
+    fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> RusotoFuture<CreateDBClusterResult, CreateDBClusterError> {
+        (**self).create_db_cluster(input)
+    }
+    fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CreateDBClusterParameterGroupResult, CreateDBClusterParameterGroupError> {
+        (**self).create_db_cluster_parameter_group(input)
+    }
+    fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CreateDBClusterSnapshotResult, CreateDBClusterSnapshotError> {
+        (**self).create_db_cluster_snapshot(input)
+    }
+    fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> RusotoFuture<CreateDBInstanceResult, CreateDBInstanceError> {
+        (**self).create_db_instance(input)
+    }
+    fn create_db_parameter_group(
+        &self,
+        input: CreateDBParameterGroupMessage,
+    ) -> RusotoFuture<CreateDBParameterGroupResult, CreateDBParameterGroupError> {
+        (**self).create_db_parameter_group(input)
+    }
+    fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> RusotoFuture<CreateDBSubnetGroupResult, CreateDBSubnetGroupError> {
+        (**self).create_db_subnet_group(input)
+    }
+    fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> RusotoFuture<CreateEventSubscriptionResult, CreateEventSubscriptionError> {
+        (**self).create_event_subscription(input)
+    }
+    fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> RusotoFuture<DeleteDBClusterResult, DeleteDBClusterError> {
+        (**self).delete_db_cluster(input)
+    }
+    fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBClusterParameterGroupError> {
+        (**self).delete_db_cluster_parameter_group(input)
+    }
+    fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> RusotoFuture<DeleteDBClusterSnapshotResult, DeleteDBClusterSnapshotError> {
+        (**self).delete_db_cluster_snapshot(input)
+    }
+    fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> RusotoFuture<DeleteDBInstanceResult, DeleteDBInstanceError> {
+        (**self).delete_db_instance(input)
+    }
+    fn delete_db_parameter_group(
+        &self,
+        input: DeleteDBParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBParameterGroupError> {
+        (**self).delete_db_parameter_group(input)
+    }
+    fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBSubnetGroupError> {
+        (**self).delete_db_subnet_group(input)
+    }
+    fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> RusotoFuture<DeleteEventSubscriptionResult, DeleteEventSubscriptionError> {
+        (**self).delete_event_subscription(input)
+    }
+    fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupsMessage, DescribeDBClusterParameterGroupsError> {
+        (**self).describe_db_cluster_parameter_groups(input)
+    }
+    fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupDetails, DescribeDBClusterParametersError> {
+        (**self).describe_db_cluster_parameters(input)
+    }
+    fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> RusotoFuture<
+        DescribeDBClusterSnapshotAttributesResult,
+        DescribeDBClusterSnapshotAttributesError,
+    > {
+        (**self).describe_db_cluster_snapshot_attributes(input)
+    }
+    fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> RusotoFuture<DBClusterSnapshotMessage, DescribeDBClusterSnapshotsError> {
+        (**self).describe_db_cluster_snapshots(input)
+    }
+    fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> RusotoFuture<DBClusterMessage, DescribeDBClustersError> {
+        (**self).describe_db_clusters(input)
+    }
+    fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> RusotoFuture<DBEngineVersionMessage, DescribeDBEngineVersionsError> {
+        (**self).describe_db_engine_versions(input)
+    }
+    fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> RusotoFuture<DBInstanceMessage, DescribeDBInstancesError> {
+        (**self).describe_db_instances(input)
+    }
+    fn describe_db_parameter_groups(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> RusotoFuture<DBParameterGroupsMessage, DescribeDBParameterGroupsError> {
+        (**self).describe_db_parameter_groups(input)
+    }
+    fn describe_db_parameters(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> RusotoFuture<DBParameterGroupDetails, DescribeDBParametersError> {
+        (**self).describe_db_parameters(input)
+    }
+    fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> RusotoFuture<DBSubnetGroupMessage, DescribeDBSubnetGroupsError> {
+        (**self).describe_db_subnet_groups(input)
+    }
+    fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> RusotoFuture<
+        DescribeEngineDefaultClusterParametersResult,
+        DescribeEngineDefaultClusterParametersError,
+    > {
+        (**self).describe_engine_default_cluster_parameters(input)
+    }
+    fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> RusotoFuture<DescribeEngineDefaultParametersResult, DescribeEngineDefaultParametersError>
+    {
+        (**self).describe_engine_default_parameters(input)
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
+        (**self).describe_event_categories(input)
+    }
+    fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> RusotoFuture<EventSubscriptionsMessage, DescribeEventSubscriptionsError> {
+        (**self).describe_event_subscriptions(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> RusotoFuture<OrderableDBInstanceOptionsMessage, DescribeOrderableDBInstanceOptionsError>
+    {
+        (**self).describe_orderable_db_instance_options(input)
+    }
+    fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> RusotoFuture<PendingMaintenanceActionsMessage, DescribePendingMaintenanceActionsError>
+    {
+        (**self).describe_pending_maintenance_actions(input)
+    }
+    fn describe_valid_db_instance_modifications(
+        &self,
+        input: DescribeValidDBInstanceModificationsMessage,
+    ) -> RusotoFuture<
+        DescribeValidDBInstanceModificationsResult,
+        DescribeValidDBInstanceModificationsError,
+    > {
+        (**self).describe_valid_db_instance_modifications(input)
+    }
+    fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> RusotoFuture<FailoverDBClusterResult, FailoverDBClusterError> {
+        (**self).failover_db_cluster(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> RusotoFuture<ModifyDBClusterResult, ModifyDBClusterError> {
+        (**self).modify_db_cluster(input)
+    }
+    fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ModifyDBClusterParameterGroupError> {
+        (**self).modify_db_cluster_parameter_group(input)
+    }
+    fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> RusotoFuture<ModifyDBClusterSnapshotAttributeResult, ModifyDBClusterSnapshotAttributeError>
+    {
+        (**self).modify_db_cluster_snapshot_attribute(input)
+    }
+    fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> RusotoFuture<ModifyDBInstanceResult, ModifyDBInstanceError> {
+        (**self).modify_db_instance(input)
+    }
+    fn modify_db_parameter_group(
+        &self,
+        input: ModifyDBParameterGroupMessage,
+    ) -> RusotoFuture<DBParameterGroupNameMessage, ModifyDBParameterGroupError> {
+        (**self).modify_db_parameter_group(input)
+    }
+    fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyDBSubnetGroupResult, ModifyDBSubnetGroupError> {
+        (**self).modify_db_subnet_group(input)
+    }
+    fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> RusotoFuture<ModifyEventSubscriptionResult, ModifyEventSubscriptionError> {
+        (**self).modify_event_subscription(input)
+    }
+    fn promote_read_replica_db_cluster(
+        &self,
+        input: PromoteReadReplicaDBClusterMessage,
+    ) -> RusotoFuture<PromoteReadReplicaDBClusterResult, PromoteReadReplicaDBClusterError> {
+        (**self).promote_read_replica_db_cluster(input)
+    }
+    fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> RusotoFuture<RebootDBInstanceResult, RebootDBInstanceError> {
+        (**self).reboot_db_instance(input)
+    }
+    fn remove_role_from_db_cluster(
+        &self,
+        input: RemoveRoleFromDBClusterMessage,
+    ) -> RusotoFuture<(), RemoveRoleFromDBClusterError> {
+        (**self).remove_role_from_db_cluster(input)
+    }
+    fn remove_source_identifier_from_subscription(
+        &self,
+        input: RemoveSourceIdentifierFromSubscriptionMessage,
+    ) -> RusotoFuture<
+        RemoveSourceIdentifierFromSubscriptionResult,
+        RemoveSourceIdentifierFromSubscriptionError,
+    > {
+        (**self).remove_source_identifier_from_subscription(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<(), RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ResetDBClusterParameterGroupError> {
+        (**self).reset_db_cluster_parameter_group(input)
+    }
+    fn reset_db_parameter_group(
+        &self,
+        input: ResetDBParameterGroupMessage,
+    ) -> RusotoFuture<DBParameterGroupNameMessage, ResetDBParameterGroupError> {
+        (**self).reset_db_parameter_group(input)
+    }
+    fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> RusotoFuture<RestoreDBClusterFromSnapshotResult, RestoreDBClusterFromSnapshotError> {
+        (**self).restore_db_cluster_from_snapshot(input)
+    }
+    fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> RusotoFuture<RestoreDBClusterToPointInTimeResult, RestoreDBClusterToPointInTimeError> {
+        (**self).restore_db_cluster_to_point_in_time(input)
+    }
+}
+/// The error codes Amazon Neptune retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Neptune API.
 #[derive(Clone)]
 pub struct NeptuneClient {
@@ -12765,6 +17290,24 @@ impl NeptuneClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_NEPTUNE`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> NeptuneClient {
+        Self::new(region::Region::from_env_for_service("NEPTUNE"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `rds`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> NeptuneClient {
+        Self::new_with_client(config.client().clone(), config.region_for("rds", "NEPTUNE"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -12783,7 +17326,49 @@ impl NeptuneClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> NeptuneClient {
-        NeptuneClient { client, region }
+        NeptuneClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "rds")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "rds", "rds", "NEPTUNE")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Neptune` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Neptune + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for NeptuneClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Neptune"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "rds"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> NeptuneClient {
+        Self::new_with_client(client, region)
     }
 }
 
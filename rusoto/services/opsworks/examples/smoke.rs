@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS OpsWorks clients.
+//!
+//! Makes a single read-only `DescribeAgentVersions` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_opsworks::{DescribeAgentVersionsRequest, OpsWorks, OpsWorksClient};
+
+fn main() {
+    let client = OpsWorksClient::from_env();
+    match client
+        .describe_agent_versions(DescribeAgentVersionsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeAgentVersions succeeded"),
+        Err(error) => {
+            eprintln!("DescribeAgentVersions failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
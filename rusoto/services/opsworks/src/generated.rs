@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Describes an agent version.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -52,7 +53,7 @@ pub struct App {
     /// <p>The stack attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<AppAttributesKeys, String>>,
     /// <p>When the app was created.</p>
     #[serde(rename = "CreatedAt")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -96,7 +97,251 @@ pub struct App {
     /// <p>The app type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<AppType>,
+}
+
+/// <p>The stack attributes.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AppAttributesKeys {
+    /// <p><code>DocumentRoot</code></p>
+    DocumentRoot,
+    /// <p><code>RailsEnv</code></p>
+    RailsEnv,
+    /// <p><code>AutoBundleOnDeploy</code></p>
+    AutoBundleOnDeploy,
+    /// <p><code>AwsFlowRubySettings</code></p>
+    AwsFlowRubySettings,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AppAttributesKeys {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AppAttributesKeys::DocumentRoot => "DocumentRoot",
+            AppAttributesKeys::RailsEnv => "RailsEnv",
+            AppAttributesKeys::AutoBundleOnDeploy => "AutoBundleOnDeploy",
+            AppAttributesKeys::AwsFlowRubySettings => "AwsFlowRubySettings",
+            AppAttributesKeys::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AppAttributesKeys {
+    fn from(value: &'a str) -> AppAttributesKeys {
+        match value {
+            "DocumentRoot" => AppAttributesKeys::DocumentRoot,
+            "RailsEnv" => AppAttributesKeys::RailsEnv,
+            "AutoBundleOnDeploy" => AppAttributesKeys::AutoBundleOnDeploy,
+            "AwsFlowRubySettings" => AppAttributesKeys::AwsFlowRubySettings,
+            _ => AppAttributesKeys::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AppAttributesKeys {
+    fn from(value: String) -> AppAttributesKeys {
+        AppAttributesKeys::from(value.as_str())
+    }
+}
+
+impl From<AppAttributesKeys> for String {
+    fn from(value: AppAttributesKeys) -> String {
+        match value {
+            AppAttributesKeys::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AppAttributesKeys {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AppAttributesKeys {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AppAttributesKeys {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AppAttributesKeys, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AppAttributesKeys::from(value))
+    }
+}
+
+/// <p>The app type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AppType {
+    /// <p><code>aws-flow-ruby</code></p>
+    AwsFlowRuby,
+    /// <p><code>java</code></p>
+    Java,
+    /// <p><code>rails</code></p>
+    Rails,
+    /// <p><code>php</code></p>
+    Php,
+    /// <p><code>nodejs</code></p>
+    Nodejs,
+    /// <p><code>static</code></p>
+    Static,
+    /// <p><code>other</code></p>
+    Other,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AppType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AppType::AwsFlowRuby => "aws-flow-ruby",
+            AppType::Java => "java",
+            AppType::Rails => "rails",
+            AppType::Php => "php",
+            AppType::Nodejs => "nodejs",
+            AppType::Static => "static",
+            AppType::Other => "other",
+            AppType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AppType {
+    fn from(value: &'a str) -> AppType {
+        match value {
+            "aws-flow-ruby" => AppType::AwsFlowRuby,
+            "java" => AppType::Java,
+            "rails" => AppType::Rails,
+            "php" => AppType::Php,
+            "nodejs" => AppType::Nodejs,
+            "static" => AppType::Static,
+            "other" => AppType::Other,
+            _ => AppType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AppType {
+    fn from(value: String) -> AppType {
+        AppType::from(value.as_str())
+    }
+}
+
+impl From<AppType> for String {
+    fn from(value: AppType) -> String {
+        match value {
+            AppType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AppType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AppType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AppType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AppType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AppType::from(value))
+    }
+}
+
+/// <p>The instance architecture. The default option is <code>x86_64</code>. Instance types do not necessarily support both architectures. For a list of the architectures that are supported by the different instance types, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-types.html">Instance Families and Types</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Architecture {
+    /// <p><code>x86_64</code></p>
+    X8664,
+    /// <p><code>i386</code></p>
+    I386,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Architecture {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Architecture::X8664 => "x86_64",
+            Architecture::I386 => "i386",
+            Architecture::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Architecture {
+    fn from(value: &'a str) -> Architecture {
+        match value {
+            "x86_64" => Architecture::X8664,
+            "i386" => Architecture::I386,
+            _ => Architecture::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Architecture {
+    fn from(value: String) -> Architecture {
+        Architecture::from(value.as_str())
+    }
+}
+
+impl From<Architecture> for String {
+    fn from(value: Architecture) -> String {
+        match value {
+            Architecture::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Architecture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Architecture {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Architecture {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Architecture, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Architecture::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -109,6 +354,46 @@ pub struct AssignInstanceRequest {
     pub layer_ids: Vec<String>,
 }
 
+impl AssignInstanceRequest {
+    /// Returns a builder for `AssignInstanceRequest`, checking its required members when built.
+    pub fn builder() -> AssignInstanceRequestBuilder {
+        AssignInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssignInstanceRequest`](struct.AssignInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct AssignInstanceRequestBuilder {
+    instance_id: Option<String>,
+    layer_ids: Option<Vec<String>>,
+}
+
+impl AssignInstanceRequestBuilder {
+    /// Sets the [`instance_id`](struct.AssignInstanceRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layer_ids`](struct.AssignInstanceRequest.html#structfield.layer_ids) member.
+    pub fn layer_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.layer_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssignInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AssignInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(AssignInstanceRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            layer_ids: self
+                .layer_ids
+                .ok_or(::rusoto_core::BuildError::missing("layer_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssignVolumeRequest {
     /// <p>The instance ID.</p>
@@ -120,6 +405,44 @@ pub struct AssignVolumeRequest {
     pub volume_id: String,
 }
 
+impl AssignVolumeRequest {
+    /// Returns a builder for `AssignVolumeRequest`, checking its required members when built.
+    pub fn builder() -> AssignVolumeRequestBuilder {
+        AssignVolumeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssignVolumeRequest`](struct.AssignVolumeRequest.html).
+#[derive(Default, Debug)]
+pub struct AssignVolumeRequestBuilder {
+    instance_id: Option<String>,
+    volume_id: Option<String>,
+}
+
+impl AssignVolumeRequestBuilder {
+    /// Sets the [`instance_id`](struct.AssignVolumeRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_id`](struct.AssignVolumeRequest.html#structfield.volume_id) member.
+    pub fn volume_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssignVolumeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AssignVolumeRequest, ::rusoto_core::BuildError> {
+        Ok(AssignVolumeRequest {
+            instance_id: self.instance_id,
+            volume_id: self
+                .volume_id
+                .ok_or(::rusoto_core::BuildError::missing("volume_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AssociateElasticIpRequest {
     /// <p>The Elastic IP address.</p>
@@ -131,6 +454,46 @@ pub struct AssociateElasticIpRequest {
     pub instance_id: Option<String>,
 }
 
+impl AssociateElasticIpRequest {
+    /// Returns a builder for `AssociateElasticIpRequest`, checking its required members when built.
+    pub fn builder() -> AssociateElasticIpRequestBuilder {
+        AssociateElasticIpRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateElasticIpRequest`](struct.AssociateElasticIpRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateElasticIpRequestBuilder {
+    elastic_ip: Option<String>,
+    instance_id: Option<String>,
+}
+
+impl AssociateElasticIpRequestBuilder {
+    /// Sets the [`elastic_ip`](struct.AssociateElasticIpRequest.html#structfield.elastic_ip) member.
+    pub fn elastic_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.elastic_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.AssociateElasticIpRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateElasticIpRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateElasticIpRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateElasticIpRequest {
+            elastic_ip: self
+                .elastic_ip
+                .ok_or(::rusoto_core::BuildError::missing("elastic_ip"))?,
+            instance_id: self.instance_id,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AttachElasticLoadBalancerRequest {
     /// <p>The Elastic Load Balancing instance's name.</p>
@@ -141,6 +504,48 @@ pub struct AttachElasticLoadBalancerRequest {
     pub layer_id: String,
 }
 
+impl AttachElasticLoadBalancerRequest {
+    /// Returns a builder for `AttachElasticLoadBalancerRequest`, checking its required members when built.
+    pub fn builder() -> AttachElasticLoadBalancerRequestBuilder {
+        AttachElasticLoadBalancerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachElasticLoadBalancerRequest`](struct.AttachElasticLoadBalancerRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachElasticLoadBalancerRequestBuilder {
+    elastic_load_balancer_name: Option<String>,
+    layer_id: Option<String>,
+}
+
+impl AttachElasticLoadBalancerRequestBuilder {
+    /// Sets the [`elastic_load_balancer_name`](struct.AttachElasticLoadBalancerRequest.html#structfield.elastic_load_balancer_name) member.
+    pub fn elastic_load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.elastic_load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layer_id`](struct.AttachElasticLoadBalancerRequest.html#structfield.layer_id) member.
+    pub fn layer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachElasticLoadBalancerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AttachElasticLoadBalancerRequest, ::rusoto_core::BuildError> {
+        Ok(AttachElasticLoadBalancerRequest {
+            elastic_load_balancer_name: self.elastic_load_balancer_name.ok_or(
+                ::rusoto_core::BuildError::missing("elastic_load_balancer_name"),
+            )?,
+            layer_id: self
+                .layer_id
+                .ok_or(::rusoto_core::BuildError::missing("layer_id"))?,
+        })
+    }
+}
+
 /// <p>Describes a load-based auto scaling upscaling or downscaling threshold configuration, which specifies when AWS OpsWorks Stacks starts or stops load-based instances.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AutoScalingThresholds {
@@ -174,6 +579,78 @@ pub struct AutoScalingThresholds {
     pub thresholds_wait_time: Option<i64>,
 }
 
+/// <p>For load-based or time-based instances, the type. Windows stacks can use only time-based instances.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AutoScalingType {
+    /// <p><code>load</code></p>
+    Load,
+    /// <p><code>timer</code></p>
+    Timer,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AutoScalingType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AutoScalingType::Load => "load",
+            AutoScalingType::Timer => "timer",
+            AutoScalingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AutoScalingType {
+    fn from(value: &'a str) -> AutoScalingType {
+        match value {
+            "load" => AutoScalingType::Load,
+            "timer" => AutoScalingType::Timer,
+            _ => AutoScalingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AutoScalingType {
+    fn from(value: String) -> AutoScalingType {
+        AutoScalingType::from(value.as_str())
+    }
+}
+
+impl From<AutoScalingType> for String {
+    fn from(value: AutoScalingType) -> String {
+        match value {
+            AutoScalingType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AutoScalingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AutoScalingType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AutoScalingType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AutoScalingType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AutoScalingType::from(value))
+    }
+}
+
 /// <p>Describes a block device mapping. This data type maps directly to the Amazon EC2 <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_BlockDeviceMapping.html">BlockDeviceMapping</a> data type. </p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlockDeviceMapping {
@@ -217,7 +694,7 @@ pub struct CloneStackRequest {
     /// <p>A list of stack attributes and values as key/value pairs to be added to the cloned stack.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<StackAttributesKeys, String>>,
     /// <p>A <code>ChefConfiguration</code> object that specifies whether to enable Berkshelf and the Berkshelf version on Chef 11.10 stacks. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-creating.html">Create a New Stack</a>.</p>
     #[serde(rename = "ChefConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -257,7 +734,7 @@ pub struct CloneStackRequest {
     /// <p>The default root device type. This value is used by default for all instances in the cloned stack, but you can override it when you create an instance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
     #[serde(rename = "DefaultRootDeviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_root_device_type: Option<String>,
+    pub default_root_device_type: Option<RootDeviceType>,
     /// <p>A default Amazon EC2 key pair name. The default value is none. If you specify a key pair name, AWS OpsWorks installs the public key on the instance and you can use the private key with an SSH client to log in to the instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-ssh.html"> Using SSH to Communicate with an Instance</a> and <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/security-ssh-access.html"> Managing SSH Access</a>. You can override this setting by specifying a different key pair, or no key pair, when you <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-add.html"> create an instance</a>. </p>
     #[serde(rename = "DefaultSshKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -298,6 +775,209 @@ pub struct CloneStackRequest {
     pub vpc_id: Option<String>,
 }
 
+impl CloneStackRequest {
+    /// Returns a builder for `CloneStackRequest`, checking its required members when built.
+    pub fn builder() -> CloneStackRequestBuilder {
+        CloneStackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CloneStackRequest`](struct.CloneStackRequest.html).
+#[derive(Default, Debug)]
+pub struct CloneStackRequestBuilder {
+    agent_version: Option<String>,
+    attributes: Option<::std::collections::HashMap<StackAttributesKeys, String>>,
+    chef_configuration: Option<ChefConfiguration>,
+    clone_app_ids: Option<Vec<String>>,
+    clone_permissions: Option<bool>,
+    configuration_manager: Option<StackConfigurationManager>,
+    custom_cookbooks_source: Option<Source>,
+    custom_json: Option<String>,
+    default_availability_zone: Option<String>,
+    default_instance_profile_arn: Option<String>,
+    default_os: Option<String>,
+    default_root_device_type: Option<RootDeviceType>,
+    default_ssh_key_name: Option<String>,
+    default_subnet_id: Option<String>,
+    hostname_theme: Option<String>,
+    name: Option<String>,
+    region: Option<String>,
+    service_role_arn: Option<String>,
+    source_stack_id: Option<String>,
+    use_custom_cookbooks: Option<bool>,
+    use_opsworks_security_groups: Option<bool>,
+    vpc_id: Option<String>,
+}
+
+impl CloneStackRequestBuilder {
+    /// Sets the [`agent_version`](struct.CloneStackRequest.html#structfield.agent_version) member.
+    pub fn agent_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.agent_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attributes`](struct.CloneStackRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<StackAttributesKeys, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`chef_configuration`](struct.CloneStackRequest.html#structfield.chef_configuration) member.
+    pub fn chef_configuration<T: Into<ChefConfiguration>>(mut self, value: T) -> Self {
+        self.chef_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`clone_app_ids`](struct.CloneStackRequest.html#structfield.clone_app_ids) member.
+    pub fn clone_app_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.clone_app_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`clone_permissions`](struct.CloneStackRequest.html#structfield.clone_permissions) member.
+    pub fn clone_permissions<T: Into<bool>>(mut self, value: T) -> Self {
+        self.clone_permissions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration_manager`](struct.CloneStackRequest.html#structfield.configuration_manager) member.
+    pub fn configuration_manager<T: Into<StackConfigurationManager>>(mut self, value: T) -> Self {
+        self.configuration_manager = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_cookbooks_source`](struct.CloneStackRequest.html#structfield.custom_cookbooks_source) member.
+    pub fn custom_cookbooks_source<T: Into<Source>>(mut self, value: T) -> Self {
+        self.custom_cookbooks_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_json`](struct.CloneStackRequest.html#structfield.custom_json) member.
+    pub fn custom_json<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_json = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_availability_zone`](struct.CloneStackRequest.html#structfield.default_availability_zone) member.
+    pub fn default_availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_instance_profile_arn`](struct.CloneStackRequest.html#structfield.default_instance_profile_arn) member.
+    pub fn default_instance_profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_instance_profile_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_os`](struct.CloneStackRequest.html#structfield.default_os) member.
+    pub fn default_os<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_os = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_root_device_type`](struct.CloneStackRequest.html#structfield.default_root_device_type) member.
+    pub fn default_root_device_type<T: Into<RootDeviceType>>(mut self, value: T) -> Self {
+        self.default_root_device_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_ssh_key_name`](struct.CloneStackRequest.html#structfield.default_ssh_key_name) member.
+    pub fn default_ssh_key_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_ssh_key_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_subnet_id`](struct.CloneStackRequest.html#structfield.default_subnet_id) member.
+    pub fn default_subnet_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_subnet_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hostname_theme`](struct.CloneStackRequest.html#structfield.hostname_theme) member.
+    pub fn hostname_theme<T: Into<String>>(mut self, value: T) -> Self {
+        self.hostname_theme = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CloneStackRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`region`](struct.CloneStackRequest.html#structfield.region) member.
+    pub fn region<T: Into<String>>(mut self, value: T) -> Self {
+        self.region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role_arn`](struct.CloneStackRequest.html#structfield.service_role_arn) member.
+    pub fn service_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_stack_id`](struct.CloneStackRequest.html#structfield.source_stack_id) member.
+    pub fn source_stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_stack_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_custom_cookbooks`](struct.CloneStackRequest.html#structfield.use_custom_cookbooks) member.
+    pub fn use_custom_cookbooks<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_custom_cookbooks = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_opsworks_security_groups`](struct.CloneStackRequest.html#structfield.use_opsworks_security_groups) member.
+    pub fn use_opsworks_security_groups<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_opsworks_security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_id`](struct.CloneStackRequest.html#structfield.vpc_id) member.
+    pub fn vpc_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpc_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CloneStackRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CloneStackRequest, ::rusoto_core::BuildError> {
+        Ok(CloneStackRequest {
+            agent_version: self.agent_version,
+            attributes: self.attributes,
+            chef_configuration: self.chef_configuration,
+            clone_app_ids: self.clone_app_ids,
+            clone_permissions: self.clone_permissions,
+            configuration_manager: self.configuration_manager,
+            custom_cookbooks_source: self.custom_cookbooks_source,
+            custom_json: self.custom_json,
+            default_availability_zone: self.default_availability_zone,
+            default_instance_profile_arn: self.default_instance_profile_arn,
+            default_os: self.default_os,
+            default_root_device_type: self.default_root_device_type,
+            default_ssh_key_name: self.default_ssh_key_name,
+            default_subnet_id: self.default_subnet_id,
+            hostname_theme: self.hostname_theme,
+            name: self.name,
+            region: self.region,
+            service_role_arn: self
+                .service_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("service_role_arn"))?,
+            source_stack_id: self
+                .source_stack_id
+                .ok_or(::rusoto_core::BuildError::missing("source_stack_id"))?,
+            use_custom_cookbooks: self.use_custom_cookbooks,
+            use_opsworks_security_groups: self.use_opsworks_security_groups,
+            vpc_id: self.vpc_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>CloneStack</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -321,41 +1001,547 @@ pub struct CloudWatchLogsConfiguration {
     pub log_streams: Option<Vec<CloudWatchLogsLogStream>>,
 }
 
-/// <p>Describes the Amazon CloudWatch logs configuration for a layer. For detailed information about members of this data type, see the <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/AgentReference.html">CloudWatch Logs Agent Reference</a>.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct CloudWatchLogsLogStream {
-    /// <p>Specifies the max number of log events in a batch, up to 10000. The default value is 1000.</p>
-    #[serde(rename = "BatchCount")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub batch_count: Option<i64>,
-    /// <p>Specifies the maximum size of log events in a batch, in bytes, up to 1048576 bytes. The default value is 32768 bytes. This size is calculated as the sum of all event messages in UTF-8, plus 26 bytes for each log event.</p>
-    #[serde(rename = "BatchSize")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub batch_size: Option<i64>,
-    /// <p>Specifies the time duration for the batching of log events. The minimum value is 5000ms and default value is 5000ms.</p>
-    #[serde(rename = "BufferDuration")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub buffer_duration: Option<i64>,
-    /// <p>Specifies how the time stamp is extracted from logs. For more information, see the <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/AgentReference.html">CloudWatch Logs Agent Reference</a>.</p>
-    #[serde(rename = "DatetimeFormat")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub datetime_format: Option<String>,
-    /// <p>Specifies the encoding of the log file so that the file can be read correctly. The default is <code>utf_8</code>. Encodings supported by Python <code>codecs.decode()</code> can be used here.</p>
-    #[serde(rename = "Encoding")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub encoding: Option<String>,
-    /// <p>Specifies log files that you want to push to CloudWatch Logs.</p> <p> <code>File</code> can point to a specific file or multiple files (by using wild card characters such as <code>/var/log/system.log*</code>). Only the latest file is pushed to CloudWatch Logs, based on file modification time. We recommend that you use wild card characters to specify a series of files of the same type, such as <code>access_log.2014-06-01-01</code>, <code>access_log.2014-06-01-02</code>, and so on by using a pattern like <code>access_log.*</code>. Don't use a wildcard to match multiple file types, such as <code>access_log_80</code> and <code>access_log_443</code>. To specify multiple, different file types, add another log stream entry to the configuration file, so that each log file type is stored in a different log group.</p> <p>Zipped files are not supported.</p>
-    #[serde(rename = "File")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub file: Option<String>,
-    /// <p>Specifies the range of lines for identifying a file. The valid values are one number, or two dash-delimited numbers, such as '1', '2-5'. The default value is '1', meaning the first line is used to calculate the fingerprint. Fingerprint lines are not sent to CloudWatch Logs unless all specified lines are available.</p>
-    #[serde(rename = "FileFingerprintLines")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+/// <p>Specifies the encoding of the log file so that the file can be read correctly. The default is <code>utf_8</code>. Encodings supported by Python <code>codecs.decode()</code> can be used here.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CloudWatchLogsEncoding {
+    /// <p><code>ascii</code></p>
+    Ascii,
+    /// <p><code>big5</code></p>
+    Big5,
+    /// <p><code>big5hkscs</code></p>
+    Big5hkscs,
+    /// <p><code>cp037</code></p>
+    Cp037,
+    /// <p><code>cp424</code></p>
+    Cp424,
+    /// <p><code>cp437</code></p>
+    Cp437,
+    /// <p><code>cp500</code></p>
+    Cp500,
+    /// <p><code>cp720</code></p>
+    Cp720,
+    /// <p><code>cp737</code></p>
+    Cp737,
+    /// <p><code>cp775</code></p>
+    Cp775,
+    /// <p><code>cp850</code></p>
+    Cp850,
+    /// <p><code>cp852</code></p>
+    Cp852,
+    /// <p><code>cp855</code></p>
+    Cp855,
+    /// <p><code>cp856</code></p>
+    Cp856,
+    /// <p><code>cp857</code></p>
+    Cp857,
+    /// <p><code>cp858</code></p>
+    Cp858,
+    /// <p><code>cp860</code></p>
+    Cp860,
+    /// <p><code>cp861</code></p>
+    Cp861,
+    /// <p><code>cp862</code></p>
+    Cp862,
+    /// <p><code>cp863</code></p>
+    Cp863,
+    /// <p><code>cp864</code></p>
+    Cp864,
+    /// <p><code>cp865</code></p>
+    Cp865,
+    /// <p><code>cp866</code></p>
+    Cp866,
+    /// <p><code>cp869</code></p>
+    Cp869,
+    /// <p><code>cp874</code></p>
+    Cp874,
+    /// <p><code>cp875</code></p>
+    Cp875,
+    /// <p><code>cp932</code></p>
+    Cp932,
+    /// <p><code>cp949</code></p>
+    Cp949,
+    /// <p><code>cp950</code></p>
+    Cp950,
+    /// <p><code>cp1006</code></p>
+    Cp1006,
+    /// <p><code>cp1026</code></p>
+    Cp1026,
+    /// <p><code>cp1140</code></p>
+    Cp1140,
+    /// <p><code>cp1250</code></p>
+    Cp1250,
+    /// <p><code>cp1251</code></p>
+    Cp1251,
+    /// <p><code>cp1252</code></p>
+    Cp1252,
+    /// <p><code>cp1253</code></p>
+    Cp1253,
+    /// <p><code>cp1254</code></p>
+    Cp1254,
+    /// <p><code>cp1255</code></p>
+    Cp1255,
+    /// <p><code>cp1256</code></p>
+    Cp1256,
+    /// <p><code>cp1257</code></p>
+    Cp1257,
+    /// <p><code>cp1258</code></p>
+    Cp1258,
+    /// <p><code>euc_jp</code></p>
+    EucJp,
+    /// <p><code>euc_jis_2004</code></p>
+    EucJis2004,
+    /// <p><code>euc_jisx0213</code></p>
+    EucJisx0213,
+    /// <p><code>euc_kr</code></p>
+    EucKr,
+    /// <p><code>gb2312</code></p>
+    Gb2312,
+    /// <p><code>gbk</code></p>
+    Gbk,
+    /// <p><code>gb18030</code></p>
+    Gb18030,
+    /// <p><code>hz</code></p>
+    Hz,
+    /// <p><code>iso2022_jp</code></p>
+    Iso2022Jp,
+    /// <p><code>iso2022_jp_1</code></p>
+    Iso2022Jp1,
+    /// <p><code>iso2022_jp_2</code></p>
+    Iso2022Jp2,
+    /// <p><code>iso2022_jp_2004</code></p>
+    Iso2022Jp2004,
+    /// <p><code>iso2022_jp_3</code></p>
+    Iso2022Jp3,
+    /// <p><code>iso2022_jp_ext</code></p>
+    Iso2022JpExt,
+    /// <p><code>iso2022_kr</code></p>
+    Iso2022Kr,
+    /// <p><code>latin_1</code></p>
+    Latin1,
+    /// <p><code>iso8859_2</code></p>
+    Iso88592,
+    /// <p><code>iso8859_3</code></p>
+    Iso88593,
+    /// <p><code>iso8859_4</code></p>
+    Iso88594,
+    /// <p><code>iso8859_5</code></p>
+    Iso88595,
+    /// <p><code>iso8859_6</code></p>
+    Iso88596,
+    /// <p><code>iso8859_7</code></p>
+    Iso88597,
+    /// <p><code>iso8859_8</code></p>
+    Iso88598,
+    /// <p><code>iso8859_9</code></p>
+    Iso88599,
+    /// <p><code>iso8859_10</code></p>
+    Iso885910,
+    /// <p><code>iso8859_13</code></p>
+    Iso885913,
+    /// <p><code>iso8859_14</code></p>
+    Iso885914,
+    /// <p><code>iso8859_15</code></p>
+    Iso885915,
+    /// <p><code>iso8859_16</code></p>
+    Iso885916,
+    /// <p><code>johab</code></p>
+    Johab,
+    /// <p><code>koi8_r</code></p>
+    Koi8R,
+    /// <p><code>koi8_u</code></p>
+    Koi8U,
+    /// <p><code>mac_cyrillic</code></p>
+    MacCyrillic,
+    /// <p><code>mac_greek</code></p>
+    MacGreek,
+    /// <p><code>mac_iceland</code></p>
+    MacIceland,
+    /// <p><code>mac_latin2</code></p>
+    MacLatin2,
+    /// <p><code>mac_roman</code></p>
+    MacRoman,
+    /// <p><code>mac_turkish</code></p>
+    MacTurkish,
+    /// <p><code>ptcp154</code></p>
+    Ptcp154,
+    /// <p><code>shift_jis</code></p>
+    ShiftJis,
+    /// <p><code>shift_jis_2004</code></p>
+    ShiftJis2004,
+    /// <p><code>shift_jisx0213</code></p>
+    ShiftJisx0213,
+    /// <p><code>utf_32</code></p>
+    Utf32,
+    /// <p><code>utf_32_be</code></p>
+    Utf32Be,
+    /// <p><code>utf_32_le</code></p>
+    Utf32Le,
+    /// <p><code>utf_16</code></p>
+    Utf16,
+    /// <p><code>utf_16_be</code></p>
+    Utf16Be,
+    /// <p><code>utf_16_le</code></p>
+    Utf16Le,
+    /// <p><code>utf_7</code></p>
+    Utf7,
+    /// <p><code>utf_8</code></p>
+    Utf8,
+    /// <p><code>utf_8_sig</code></p>
+    Utf8Sig,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CloudWatchLogsEncoding {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudWatchLogsEncoding::Ascii => "ascii",
+            CloudWatchLogsEncoding::Big5 => "big5",
+            CloudWatchLogsEncoding::Big5hkscs => "big5hkscs",
+            CloudWatchLogsEncoding::Cp037 => "cp037",
+            CloudWatchLogsEncoding::Cp424 => "cp424",
+            CloudWatchLogsEncoding::Cp437 => "cp437",
+            CloudWatchLogsEncoding::Cp500 => "cp500",
+            CloudWatchLogsEncoding::Cp720 => "cp720",
+            CloudWatchLogsEncoding::Cp737 => "cp737",
+            CloudWatchLogsEncoding::Cp775 => "cp775",
+            CloudWatchLogsEncoding::Cp850 => "cp850",
+            CloudWatchLogsEncoding::Cp852 => "cp852",
+            CloudWatchLogsEncoding::Cp855 => "cp855",
+            CloudWatchLogsEncoding::Cp856 => "cp856",
+            CloudWatchLogsEncoding::Cp857 => "cp857",
+            CloudWatchLogsEncoding::Cp858 => "cp858",
+            CloudWatchLogsEncoding::Cp860 => "cp860",
+            CloudWatchLogsEncoding::Cp861 => "cp861",
+            CloudWatchLogsEncoding::Cp862 => "cp862",
+            CloudWatchLogsEncoding::Cp863 => "cp863",
+            CloudWatchLogsEncoding::Cp864 => "cp864",
+            CloudWatchLogsEncoding::Cp865 => "cp865",
+            CloudWatchLogsEncoding::Cp866 => "cp866",
+            CloudWatchLogsEncoding::Cp869 => "cp869",
+            CloudWatchLogsEncoding::Cp874 => "cp874",
+            CloudWatchLogsEncoding::Cp875 => "cp875",
+            CloudWatchLogsEncoding::Cp932 => "cp932",
+            CloudWatchLogsEncoding::Cp949 => "cp949",
+            CloudWatchLogsEncoding::Cp950 => "cp950",
+            CloudWatchLogsEncoding::Cp1006 => "cp1006",
+            CloudWatchLogsEncoding::Cp1026 => "cp1026",
+            CloudWatchLogsEncoding::Cp1140 => "cp1140",
+            CloudWatchLogsEncoding::Cp1250 => "cp1250",
+            CloudWatchLogsEncoding::Cp1251 => "cp1251",
+            CloudWatchLogsEncoding::Cp1252 => "cp1252",
+            CloudWatchLogsEncoding::Cp1253 => "cp1253",
+            CloudWatchLogsEncoding::Cp1254 => "cp1254",
+            CloudWatchLogsEncoding::Cp1255 => "cp1255",
+            CloudWatchLogsEncoding::Cp1256 => "cp1256",
+            CloudWatchLogsEncoding::Cp1257 => "cp1257",
+            CloudWatchLogsEncoding::Cp1258 => "cp1258",
+            CloudWatchLogsEncoding::EucJp => "euc_jp",
+            CloudWatchLogsEncoding::EucJis2004 => "euc_jis_2004",
+            CloudWatchLogsEncoding::EucJisx0213 => "euc_jisx0213",
+            CloudWatchLogsEncoding::EucKr => "euc_kr",
+            CloudWatchLogsEncoding::Gb2312 => "gb2312",
+            CloudWatchLogsEncoding::Gbk => "gbk",
+            CloudWatchLogsEncoding::Gb18030 => "gb18030",
+            CloudWatchLogsEncoding::Hz => "hz",
+            CloudWatchLogsEncoding::Iso2022Jp => "iso2022_jp",
+            CloudWatchLogsEncoding::Iso2022Jp1 => "iso2022_jp_1",
+            CloudWatchLogsEncoding::Iso2022Jp2 => "iso2022_jp_2",
+            CloudWatchLogsEncoding::Iso2022Jp2004 => "iso2022_jp_2004",
+            CloudWatchLogsEncoding::Iso2022Jp3 => "iso2022_jp_3",
+            CloudWatchLogsEncoding::Iso2022JpExt => "iso2022_jp_ext",
+            CloudWatchLogsEncoding::Iso2022Kr => "iso2022_kr",
+            CloudWatchLogsEncoding::Latin1 => "latin_1",
+            CloudWatchLogsEncoding::Iso88592 => "iso8859_2",
+            CloudWatchLogsEncoding::Iso88593 => "iso8859_3",
+            CloudWatchLogsEncoding::Iso88594 => "iso8859_4",
+            CloudWatchLogsEncoding::Iso88595 => "iso8859_5",
+            CloudWatchLogsEncoding::Iso88596 => "iso8859_6",
+            CloudWatchLogsEncoding::Iso88597 => "iso8859_7",
+            CloudWatchLogsEncoding::Iso88598 => "iso8859_8",
+            CloudWatchLogsEncoding::Iso88599 => "iso8859_9",
+            CloudWatchLogsEncoding::Iso885910 => "iso8859_10",
+            CloudWatchLogsEncoding::Iso885913 => "iso8859_13",
+            CloudWatchLogsEncoding::Iso885914 => "iso8859_14",
+            CloudWatchLogsEncoding::Iso885915 => "iso8859_15",
+            CloudWatchLogsEncoding::Iso885916 => "iso8859_16",
+            CloudWatchLogsEncoding::Johab => "johab",
+            CloudWatchLogsEncoding::Koi8R => "koi8_r",
+            CloudWatchLogsEncoding::Koi8U => "koi8_u",
+            CloudWatchLogsEncoding::MacCyrillic => "mac_cyrillic",
+            CloudWatchLogsEncoding::MacGreek => "mac_greek",
+            CloudWatchLogsEncoding::MacIceland => "mac_iceland",
+            CloudWatchLogsEncoding::MacLatin2 => "mac_latin2",
+            CloudWatchLogsEncoding::MacRoman => "mac_roman",
+            CloudWatchLogsEncoding::MacTurkish => "mac_turkish",
+            CloudWatchLogsEncoding::Ptcp154 => "ptcp154",
+            CloudWatchLogsEncoding::ShiftJis => "shift_jis",
+            CloudWatchLogsEncoding::ShiftJis2004 => "shift_jis_2004",
+            CloudWatchLogsEncoding::ShiftJisx0213 => "shift_jisx0213",
+            CloudWatchLogsEncoding::Utf32 => "utf_32",
+            CloudWatchLogsEncoding::Utf32Be => "utf_32_be",
+            CloudWatchLogsEncoding::Utf32Le => "utf_32_le",
+            CloudWatchLogsEncoding::Utf16 => "utf_16",
+            CloudWatchLogsEncoding::Utf16Be => "utf_16_be",
+            CloudWatchLogsEncoding::Utf16Le => "utf_16_le",
+            CloudWatchLogsEncoding::Utf7 => "utf_7",
+            CloudWatchLogsEncoding::Utf8 => "utf_8",
+            CloudWatchLogsEncoding::Utf8Sig => "utf_8_sig",
+            CloudWatchLogsEncoding::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CloudWatchLogsEncoding {
+    fn from(value: &'a str) -> CloudWatchLogsEncoding {
+        match value {
+            "ascii" => CloudWatchLogsEncoding::Ascii,
+            "big5" => CloudWatchLogsEncoding::Big5,
+            "big5hkscs" => CloudWatchLogsEncoding::Big5hkscs,
+            "cp037" => CloudWatchLogsEncoding::Cp037,
+            "cp424" => CloudWatchLogsEncoding::Cp424,
+            "cp437" => CloudWatchLogsEncoding::Cp437,
+            "cp500" => CloudWatchLogsEncoding::Cp500,
+            "cp720" => CloudWatchLogsEncoding::Cp720,
+            "cp737" => CloudWatchLogsEncoding::Cp737,
+            "cp775" => CloudWatchLogsEncoding::Cp775,
+            "cp850" => CloudWatchLogsEncoding::Cp850,
+            "cp852" => CloudWatchLogsEncoding::Cp852,
+            "cp855" => CloudWatchLogsEncoding::Cp855,
+            "cp856" => CloudWatchLogsEncoding::Cp856,
+            "cp857" => CloudWatchLogsEncoding::Cp857,
+            "cp858" => CloudWatchLogsEncoding::Cp858,
+            "cp860" => CloudWatchLogsEncoding::Cp860,
+            "cp861" => CloudWatchLogsEncoding::Cp861,
+            "cp862" => CloudWatchLogsEncoding::Cp862,
+            "cp863" => CloudWatchLogsEncoding::Cp863,
+            "cp864" => CloudWatchLogsEncoding::Cp864,
+            "cp865" => CloudWatchLogsEncoding::Cp865,
+            "cp866" => CloudWatchLogsEncoding::Cp866,
+            "cp869" => CloudWatchLogsEncoding::Cp869,
+            "cp874" => CloudWatchLogsEncoding::Cp874,
+            "cp875" => CloudWatchLogsEncoding::Cp875,
+            "cp932" => CloudWatchLogsEncoding::Cp932,
+            "cp949" => CloudWatchLogsEncoding::Cp949,
+            "cp950" => CloudWatchLogsEncoding::Cp950,
+            "cp1006" => CloudWatchLogsEncoding::Cp1006,
+            "cp1026" => CloudWatchLogsEncoding::Cp1026,
+            "cp1140" => CloudWatchLogsEncoding::Cp1140,
+            "cp1250" => CloudWatchLogsEncoding::Cp1250,
+            "cp1251" => CloudWatchLogsEncoding::Cp1251,
+            "cp1252" => CloudWatchLogsEncoding::Cp1252,
+            "cp1253" => CloudWatchLogsEncoding::Cp1253,
+            "cp1254" => CloudWatchLogsEncoding::Cp1254,
+            "cp1255" => CloudWatchLogsEncoding::Cp1255,
+            "cp1256" => CloudWatchLogsEncoding::Cp1256,
+            "cp1257" => CloudWatchLogsEncoding::Cp1257,
+            "cp1258" => CloudWatchLogsEncoding::Cp1258,
+            "euc_jp" => CloudWatchLogsEncoding::EucJp,
+            "euc_jis_2004" => CloudWatchLogsEncoding::EucJis2004,
+            "euc_jisx0213" => CloudWatchLogsEncoding::EucJisx0213,
+            "euc_kr" => CloudWatchLogsEncoding::EucKr,
+            "gb2312" => CloudWatchLogsEncoding::Gb2312,
+            "gbk" => CloudWatchLogsEncoding::Gbk,
+            "gb18030" => CloudWatchLogsEncoding::Gb18030,
+            "hz" => CloudWatchLogsEncoding::Hz,
+            "iso2022_jp" => CloudWatchLogsEncoding::Iso2022Jp,
+            "iso2022_jp_1" => CloudWatchLogsEncoding::Iso2022Jp1,
+            "iso2022_jp_2" => CloudWatchLogsEncoding::Iso2022Jp2,
+            "iso2022_jp_2004" => CloudWatchLogsEncoding::Iso2022Jp2004,
+            "iso2022_jp_3" => CloudWatchLogsEncoding::Iso2022Jp3,
+            "iso2022_jp_ext" => CloudWatchLogsEncoding::Iso2022JpExt,
+            "iso2022_kr" => CloudWatchLogsEncoding::Iso2022Kr,
+            "latin_1" => CloudWatchLogsEncoding::Latin1,
+            "iso8859_2" => CloudWatchLogsEncoding::Iso88592,
+            "iso8859_3" => CloudWatchLogsEncoding::Iso88593,
+            "iso8859_4" => CloudWatchLogsEncoding::Iso88594,
+            "iso8859_5" => CloudWatchLogsEncoding::Iso88595,
+            "iso8859_6" => CloudWatchLogsEncoding::Iso88596,
+            "iso8859_7" => CloudWatchLogsEncoding::Iso88597,
+            "iso8859_8" => CloudWatchLogsEncoding::Iso88598,
+            "iso8859_9" => CloudWatchLogsEncoding::Iso88599,
+            "iso8859_10" => CloudWatchLogsEncoding::Iso885910,
+            "iso8859_13" => CloudWatchLogsEncoding::Iso885913,
+            "iso8859_14" => CloudWatchLogsEncoding::Iso885914,
+            "iso8859_15" => CloudWatchLogsEncoding::Iso885915,
+            "iso8859_16" => CloudWatchLogsEncoding::Iso885916,
+            "johab" => CloudWatchLogsEncoding::Johab,
+            "koi8_r" => CloudWatchLogsEncoding::Koi8R,
+            "koi8_u" => CloudWatchLogsEncoding::Koi8U,
+            "mac_cyrillic" => CloudWatchLogsEncoding::MacCyrillic,
+            "mac_greek" => CloudWatchLogsEncoding::MacGreek,
+            "mac_iceland" => CloudWatchLogsEncoding::MacIceland,
+            "mac_latin2" => CloudWatchLogsEncoding::MacLatin2,
+            "mac_roman" => CloudWatchLogsEncoding::MacRoman,
+            "mac_turkish" => CloudWatchLogsEncoding::MacTurkish,
+            "ptcp154" => CloudWatchLogsEncoding::Ptcp154,
+            "shift_jis" => CloudWatchLogsEncoding::ShiftJis,
+            "shift_jis_2004" => CloudWatchLogsEncoding::ShiftJis2004,
+            "shift_jisx0213" => CloudWatchLogsEncoding::ShiftJisx0213,
+            "utf_32" => CloudWatchLogsEncoding::Utf32,
+            "utf_32_be" => CloudWatchLogsEncoding::Utf32Be,
+            "utf_32_le" => CloudWatchLogsEncoding::Utf32Le,
+            "utf_16" => CloudWatchLogsEncoding::Utf16,
+            "utf_16_be" => CloudWatchLogsEncoding::Utf16Be,
+            "utf_16_le" => CloudWatchLogsEncoding::Utf16Le,
+            "utf_7" => CloudWatchLogsEncoding::Utf7,
+            "utf_8" => CloudWatchLogsEncoding::Utf8,
+            "utf_8_sig" => CloudWatchLogsEncoding::Utf8Sig,
+            _ => CloudWatchLogsEncoding::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudWatchLogsEncoding {
+    fn from(value: String) -> CloudWatchLogsEncoding {
+        CloudWatchLogsEncoding::from(value.as_str())
+    }
+}
+
+impl From<CloudWatchLogsEncoding> for String {
+    fn from(value: CloudWatchLogsEncoding) -> String {
+        match value {
+            CloudWatchLogsEncoding::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CloudWatchLogsEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CloudWatchLogsEncoding {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CloudWatchLogsEncoding {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CloudWatchLogsEncoding, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CloudWatchLogsEncoding::from(value))
+    }
+}
+
+/// <p>Specifies where to start to read data (start_of_file or end_of_file). The default is start_of_file. It's only used if there is no state persisted for that log stream.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CloudWatchLogsInitialPosition {
+    /// <p><code>start_of_file</code></p>
+    StartOfFile,
+    /// <p><code>end_of_file</code></p>
+    EndOfFile,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CloudWatchLogsInitialPosition {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudWatchLogsInitialPosition::StartOfFile => "start_of_file",
+            CloudWatchLogsInitialPosition::EndOfFile => "end_of_file",
+            CloudWatchLogsInitialPosition::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CloudWatchLogsInitialPosition {
+    fn from(value: &'a str) -> CloudWatchLogsInitialPosition {
+        match value {
+            "start_of_file" => CloudWatchLogsInitialPosition::StartOfFile,
+            "end_of_file" => CloudWatchLogsInitialPosition::EndOfFile,
+            _ => CloudWatchLogsInitialPosition::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudWatchLogsInitialPosition {
+    fn from(value: String) -> CloudWatchLogsInitialPosition {
+        CloudWatchLogsInitialPosition::from(value.as_str())
+    }
+}
+
+impl From<CloudWatchLogsInitialPosition> for String {
+    fn from(value: CloudWatchLogsInitialPosition) -> String {
+        match value {
+            CloudWatchLogsInitialPosition::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CloudWatchLogsInitialPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CloudWatchLogsInitialPosition {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CloudWatchLogsInitialPosition {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<CloudWatchLogsInitialPosition, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CloudWatchLogsInitialPosition::from(value))
+    }
+}
+
+/// <p>Describes the Amazon CloudWatch logs configuration for a layer. For detailed information about members of this data type, see the <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/AgentReference.html">CloudWatch Logs Agent Reference</a>.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CloudWatchLogsLogStream {
+    /// <p>Specifies the max number of log events in a batch, up to 10000. The default value is 1000.</p>
+    #[serde(rename = "BatchCount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_count: Option<i64>,
+    /// <p>Specifies the maximum size of log events in a batch, in bytes, up to 1048576 bytes. The default value is 32768 bytes. This size is calculated as the sum of all event messages in UTF-8, plus 26 bytes for each log event.</p>
+    #[serde(rename = "BatchSize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_size: Option<i64>,
+    /// <p>Specifies the time duration for the batching of log events. The minimum value is 5000ms and default value is 5000ms.</p>
+    #[serde(rename = "BufferDuration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buffer_duration: Option<i64>,
+    /// <p>Specifies how the time stamp is extracted from logs. For more information, see the <a href="https://docs.aws.amazon.com/AmazonCloudWatch/latest/logs/AgentReference.html">CloudWatch Logs Agent Reference</a>.</p>
+    #[serde(rename = "DatetimeFormat")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datetime_format: Option<String>,
+    /// <p>Specifies the encoding of the log file so that the file can be read correctly. The default is <code>utf_8</code>. Encodings supported by Python <code>codecs.decode()</code> can be used here.</p>
+    #[serde(rename = "Encoding")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<CloudWatchLogsEncoding>,
+    /// <p>Specifies log files that you want to push to CloudWatch Logs.</p> <p> <code>File</code> can point to a specific file or multiple files (by using wild card characters such as <code>/var/log/system.log*</code>). Only the latest file is pushed to CloudWatch Logs, based on file modification time. We recommend that you use wild card characters to specify a series of files of the same type, such as <code>access_log.2014-06-01-01</code>, <code>access_log.2014-06-01-02</code>, and so on by using a pattern like <code>access_log.*</code>. Don't use a wildcard to match multiple file types, such as <code>access_log_80</code> and <code>access_log_443</code>. To specify multiple, different file types, add another log stream entry to the configuration file, so that each log file type is stored in a different log group.</p> <p>Zipped files are not supported.</p>
+    #[serde(rename = "File")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// <p>Specifies the range of lines for identifying a file. The valid values are one number, or two dash-delimited numbers, such as '1', '2-5'. The default value is '1', meaning the first line is used to calculate the fingerprint. Fingerprint lines are not sent to CloudWatch Logs unless all specified lines are available.</p>
+    #[serde(rename = "FileFingerprintLines")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub file_fingerprint_lines: Option<String>,
     /// <p>Specifies where to start to read data (start_of_file or end_of_file). The default is start_of_file. This setting is only used if there is no state persisted for that log stream.</p>
     #[serde(rename = "InitialPosition")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub initial_position: Option<String>,
+    pub initial_position: Option<CloudWatchLogsInitialPosition>,
     /// <p>Specifies the destination log group. A log group is created automatically if it doesn't already exist. Log group names can be between 1 and 512 characters long. Allowed characters include a-z, A-Z, 0-9, '_' (underscore), '-' (hyphen), '/' (forward slash), and '.' (period).</p>
     #[serde(rename = "LogGroupName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -367,7 +1553,79 @@ pub struct CloudWatchLogsLogStream {
     /// <p>Specifies the time zone of log event time stamps.</p>
     #[serde(rename = "TimeZone")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_zone: Option<String>,
+    pub time_zone: Option<CloudWatchLogsTimeZone>,
+}
+
+/// <p>The preferred time zone for logs streamed to CloudWatch Logs. Valid values are <code>LOCAL</code> and <code>UTC</code>, for Coordinated Universal Time.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CloudWatchLogsTimeZone {
+    /// <p><code>LOCAL</code></p>
+    Local,
+    /// <p><code>UTC</code></p>
+    Utc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CloudWatchLogsTimeZone {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CloudWatchLogsTimeZone::Local => "LOCAL",
+            CloudWatchLogsTimeZone::Utc => "UTC",
+            CloudWatchLogsTimeZone::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CloudWatchLogsTimeZone {
+    fn from(value: &'a str) -> CloudWatchLogsTimeZone {
+        match value {
+            "LOCAL" => CloudWatchLogsTimeZone::Local,
+            "UTC" => CloudWatchLogsTimeZone::Utc,
+            _ => CloudWatchLogsTimeZone::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CloudWatchLogsTimeZone {
+    fn from(value: String) -> CloudWatchLogsTimeZone {
+        CloudWatchLogsTimeZone::from(value.as_str())
+    }
+}
+
+impl From<CloudWatchLogsTimeZone> for String {
+    fn from(value: CloudWatchLogsTimeZone) -> String {
+        match value {
+            CloudWatchLogsTimeZone::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CloudWatchLogsTimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CloudWatchLogsTimeZone {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CloudWatchLogsTimeZone {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CloudWatchLogsTimeZone, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CloudWatchLogsTimeZone::from(value))
+    }
 }
 
 /// <p>Describes a command.</p>
@@ -416,7 +1674,7 @@ pub struct Command {
     pub type_: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateAppRequest {
     /// <p>A <code>Source</code> object that specifies the app repository.</p>
     #[serde(rename = "AppSource")]
@@ -425,7 +1683,7 @@ pub struct CreateAppRequest {
     /// <p>One or more user-defined key/value pairs to be added to the stack attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<AppAttributesKeys, String>>,
     /// <p>The app's data source.</p>
     #[serde(rename = "DataSources")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -462,7 +1720,132 @@ pub struct CreateAppRequest {
     pub stack_id: String,
     /// <p>The app type. Each supported type is associated with a particular layer. For example, PHP applications are associated with a PHP layer. AWS OpsWorks Stacks deploys an application to those instances that are members of the corresponding layer. If your app isn't one of the standard types, or you prefer to implement your own Deploy recipes, specify <code>other</code>.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: AppType,
+}
+
+impl CreateAppRequest {
+    /// Returns a builder for `CreateAppRequest`, checking its required members when built.
+    pub fn builder() -> CreateAppRequestBuilder {
+        CreateAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAppRequest`](struct.CreateAppRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAppRequestBuilder {
+    app_source: Option<Source>,
+    attributes: Option<::std::collections::HashMap<AppAttributesKeys, String>>,
+    data_sources: Option<Vec<DataSource>>,
+    description: Option<String>,
+    domains: Option<Vec<String>>,
+    enable_ssl: Option<bool>,
+    environment: Option<Vec<EnvironmentVariable>>,
+    name: Option<String>,
+    shortname: Option<String>,
+    ssl_configuration: Option<SslConfiguration>,
+    stack_id: Option<String>,
+    type_: Option<AppType>,
+}
+
+impl CreateAppRequestBuilder {
+    /// Sets the [`app_source`](struct.CreateAppRequest.html#structfield.app_source) member.
+    pub fn app_source<T: Into<Source>>(mut self, value: T) -> Self {
+        self.app_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attributes`](struct.CreateAppRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<AppAttributesKeys, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_sources`](struct.CreateAppRequest.html#structfield.data_sources) member.
+    pub fn data_sources<T: Into<Vec<DataSource>>>(mut self, value: T) -> Self {
+        self.data_sources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateAppRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domains`](struct.CreateAppRequest.html#structfield.domains) member.
+    pub fn domains<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.domains = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_ssl`](struct.CreateAppRequest.html#structfield.enable_ssl) member.
+    pub fn enable_ssl<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_ssl = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment`](struct.CreateAppRequest.html#structfield.environment) member.
+    pub fn environment<T: Into<Vec<EnvironmentVariable>>>(mut self, value: T) -> Self {
+        self.environment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateAppRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shortname`](struct.CreateAppRequest.html#structfield.shortname) member.
+    pub fn shortname<T: Into<String>>(mut self, value: T) -> Self {
+        self.shortname = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssl_configuration`](struct.CreateAppRequest.html#structfield.ssl_configuration) member.
+    pub fn ssl_configuration<T: Into<SslConfiguration>>(mut self, value: T) -> Self {
+        self.ssl_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.CreateAppRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.CreateAppRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<AppType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateAppRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAppRequest {
+            app_source: self.app_source,
+            attributes: self.attributes,
+            data_sources: self.data_sources,
+            description: self.description,
+            domains: self.domains,
+            enable_ssl: self.enable_ssl,
+            environment: self.environment,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            shortname: self.shortname,
+            ssl_configuration: self.ssl_configuration,
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 /// <p>Contains the response to a <code>CreateApp</code> request.</p>
@@ -475,7 +1858,7 @@ pub struct CreateAppResult {
     pub app_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateDeploymentRequest {
     /// <p>The app ID. This parameter is required for app deployments, but not for other deployment commands.</p>
     #[serde(rename = "AppId")]
@@ -505,6 +1888,88 @@ pub struct CreateDeploymentRequest {
     pub stack_id: String,
 }
 
+impl CreateDeploymentRequest {
+    /// Returns a builder for `CreateDeploymentRequest`, checking its required members when built.
+    pub fn builder() -> CreateDeploymentRequestBuilder {
+        CreateDeploymentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDeploymentRequest`](struct.CreateDeploymentRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateDeploymentRequestBuilder {
+    app_id: Option<String>,
+    command: Option<DeploymentCommand>,
+    comment: Option<String>,
+    custom_json: Option<String>,
+    instance_ids: Option<Vec<String>>,
+    layer_ids: Option<Vec<String>>,
+    stack_id: Option<String>,
+}
+
+impl CreateDeploymentRequestBuilder {
+    /// Sets the [`app_id`](struct.CreateDeploymentRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`command`](struct.CreateDeploymentRequest.html#structfield.command) member.
+    pub fn command<T: Into<DeploymentCommand>>(mut self, value: T) -> Self {
+        self.command = Some(value.into());
+        self
+    }
+
+    /// Sets the [`comment`](struct.CreateDeploymentRequest.html#structfield.comment) member.
+    pub fn comment<T: Into<String>>(mut self, value: T) -> Self {
+        self.comment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_json`](struct.CreateDeploymentRequest.html#structfield.custom_json) member.
+    pub fn custom_json<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_json = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_ids`](struct.CreateDeploymentRequest.html#structfield.instance_ids) member.
+    pub fn instance_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layer_ids`](struct.CreateDeploymentRequest.html#structfield.layer_ids) member.
+    pub fn layer_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.layer_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.CreateDeploymentRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDeploymentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDeploymentRequest, ::rusoto_core::BuildError> {
+        Ok(CreateDeploymentRequest {
+            app_id: self.app_id,
+            command: self
+                .command
+                .ok_or(::rusoto_core::BuildError::missing("command"))?,
+            comment: self.comment,
+            custom_json: self.custom_json,
+            instance_ids: self.instance_ids,
+            layer_ids: self.layer_ids,
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>CreateDeployment</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -528,11 +1993,11 @@ pub struct CreateInstanceRequest {
     /// <p>The instance architecture. The default option is <code>x86_64</code>. Instance types do not necessarily support both architectures. For a list of the architectures that are supported by the different instance types, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-types.html">Instance Families and Types</a>.</p>
     #[serde(rename = "Architecture")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub architecture: Option<String>,
+    pub architecture: Option<Architecture>,
     /// <p>For load-based or time-based instances, the type. Windows stacks can use only time-based instances.</p>
     #[serde(rename = "AutoScalingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_scaling_type: Option<String>,
+    pub auto_scaling_type: Option<AutoScalingType>,
     /// <p>The instance Availability Zone. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html">Regions and Endpoints</a>.</p>
     #[serde(rename = "AvailabilityZone")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -566,7 +2031,7 @@ pub struct CreateInstanceRequest {
     /// <p>The instance root device type. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
     #[serde(rename = "RootDeviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub root_device_type: Option<String>,
+    pub root_device_type: Option<RootDeviceType>,
     /// <p>The instance's Amazon EC2 key-pair name.</p>
     #[serde(rename = "SshKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -588,6 +2053,176 @@ pub struct CreateInstanceRequest {
     pub virtualization_type: Option<String>,
 }
 
+impl CreateInstanceRequest {
+    /// Returns a builder for `CreateInstanceRequest`, checking its required members when built.
+    pub fn builder() -> CreateInstanceRequestBuilder {
+        CreateInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateInstanceRequest`](struct.CreateInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateInstanceRequestBuilder {
+    agent_version: Option<String>,
+    ami_id: Option<String>,
+    architecture: Option<Architecture>,
+    auto_scaling_type: Option<AutoScalingType>,
+    availability_zone: Option<String>,
+    block_device_mappings: Option<Vec<BlockDeviceMapping>>,
+    ebs_optimized: Option<bool>,
+    hostname: Option<String>,
+    install_updates_on_boot: Option<bool>,
+    instance_type: Option<String>,
+    layer_ids: Option<Vec<String>>,
+    os: Option<String>,
+    root_device_type: Option<RootDeviceType>,
+    ssh_key_name: Option<String>,
+    stack_id: Option<String>,
+    subnet_id: Option<String>,
+    tenancy: Option<String>,
+    virtualization_type: Option<String>,
+}
+
+impl CreateInstanceRequestBuilder {
+    /// Sets the [`agent_version`](struct.CreateInstanceRequest.html#structfield.agent_version) member.
+    pub fn agent_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.agent_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ami_id`](struct.CreateInstanceRequest.html#structfield.ami_id) member.
+    pub fn ami_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ami_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`architecture`](struct.CreateInstanceRequest.html#structfield.architecture) member.
+    pub fn architecture<T: Into<Architecture>>(mut self, value: T) -> Self {
+        self.architecture = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_scaling_type`](struct.CreateInstanceRequest.html#structfield.auto_scaling_type) member.
+    pub fn auto_scaling_type<T: Into<AutoScalingType>>(mut self, value: T) -> Self {
+        self.auto_scaling_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zone`](struct.CreateInstanceRequest.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`block_device_mappings`](struct.CreateInstanceRequest.html#structfield.block_device_mappings) member.
+    pub fn block_device_mappings<T: Into<Vec<BlockDeviceMapping>>>(mut self, value: T) -> Self {
+        self.block_device_mappings = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ebs_optimized`](struct.CreateInstanceRequest.html#structfield.ebs_optimized) member.
+    pub fn ebs_optimized<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ebs_optimized = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hostname`](struct.CreateInstanceRequest.html#structfield.hostname) member.
+    pub fn hostname<T: Into<String>>(mut self, value: T) -> Self {
+        self.hostname = Some(value.into());
+        self
+    }
+
+    /// Sets the [`install_updates_on_boot`](struct.CreateInstanceRequest.html#structfield.install_updates_on_boot) member.
+    pub fn install_updates_on_boot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.install_updates_on_boot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_type`](struct.CreateInstanceRequest.html#structfield.instance_type) member.
+    pub fn instance_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layer_ids`](struct.CreateInstanceRequest.html#structfield.layer_ids) member.
+    pub fn layer_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.layer_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`os`](struct.CreateInstanceRequest.html#structfield.os) member.
+    pub fn os<T: Into<String>>(mut self, value: T) -> Self {
+        self.os = Some(value.into());
+        self
+    }
+
+    /// Sets the [`root_device_type`](struct.CreateInstanceRequest.html#structfield.root_device_type) member.
+    pub fn root_device_type<T: Into<RootDeviceType>>(mut self, value: T) -> Self {
+        self.root_device_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_key_name`](struct.CreateInstanceRequest.html#structfield.ssh_key_name) member.
+    pub fn ssh_key_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_key_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.CreateInstanceRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_id`](struct.CreateInstanceRequest.html#structfield.subnet_id) member.
+    pub fn subnet_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.subnet_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tenancy`](struct.CreateInstanceRequest.html#structfield.tenancy) member.
+    pub fn tenancy<T: Into<String>>(mut self, value: T) -> Self {
+        self.tenancy = Some(value.into());
+        self
+    }
+
+    /// Sets the [`virtualization_type`](struct.CreateInstanceRequest.html#structfield.virtualization_type) member.
+    pub fn virtualization_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.virtualization_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(CreateInstanceRequest {
+            agent_version: self.agent_version,
+            ami_id: self.ami_id,
+            architecture: self.architecture,
+            auto_scaling_type: self.auto_scaling_type,
+            availability_zone: self.availability_zone,
+            block_device_mappings: self.block_device_mappings,
+            ebs_optimized: self.ebs_optimized,
+            hostname: self.hostname,
+            install_updates_on_boot: self.install_updates_on_boot,
+            instance_type: self
+                .instance_type
+                .ok_or(::rusoto_core::BuildError::missing("instance_type"))?,
+            layer_ids: self
+                .layer_ids
+                .ok_or(::rusoto_core::BuildError::missing("layer_ids"))?,
+            os: self.os,
+            root_device_type: self.root_device_type,
+            ssh_key_name: self.ssh_key_name,
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+            subnet_id: self.subnet_id,
+            tenancy: self.tenancy,
+            virtualization_type: self.virtualization_type,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>CreateInstance</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -598,12 +2233,12 @@ pub struct CreateInstanceResult {
     pub instance_id: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateLayerRequest {
     /// <p>One or more user-defined key-value pairs to be added to the stack attributes.</p> <p>To create a cluster layer, set the <code>EcsClusterArn</code> attribute to the cluster's ARN.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<LayerAttributesKeys, String>>,
     /// <p>Whether to automatically assign an <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP address</a> to the layer's instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-edit.html">How to Edit a Layer</a>.</p>
     #[serde(rename = "AutoAssignElasticIps")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -659,7 +2294,7 @@ pub struct CreateLayerRequest {
     pub stack_id: String,
     /// <p>The layer type. A stack cannot have more than one built-in layer of the same type. It can have any number of custom layers. Built-in layers are not available in Chef 12 stacks.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: LayerType,
     /// <p>Whether to use Amazon EBS-optimized instances.</p>
     #[serde(rename = "UseEbsOptimizedInstances")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -670,6 +2305,187 @@ pub struct CreateLayerRequest {
     pub volume_configurations: Option<Vec<VolumeConfiguration>>,
 }
 
+impl CreateLayerRequest {
+    /// Returns a builder for `CreateLayerRequest`, checking its required members when built.
+    pub fn builder() -> CreateLayerRequestBuilder {
+        CreateLayerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLayerRequest`](struct.CreateLayerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLayerRequestBuilder {
+    attributes: Option<::std::collections::HashMap<LayerAttributesKeys, String>>,
+    auto_assign_elastic_ips: Option<bool>,
+    auto_assign_public_ips: Option<bool>,
+    cloud_watch_logs_configuration: Option<CloudWatchLogsConfiguration>,
+    custom_instance_profile_arn: Option<String>,
+    custom_json: Option<String>,
+    custom_recipes: Option<Recipes>,
+    custom_security_group_ids: Option<Vec<String>>,
+    enable_auto_healing: Option<bool>,
+    install_updates_on_boot: Option<bool>,
+    lifecycle_event_configuration: Option<LifecycleEventConfiguration>,
+    name: Option<String>,
+    packages: Option<Vec<String>>,
+    shortname: Option<String>,
+    stack_id: Option<String>,
+    type_: Option<LayerType>,
+    use_ebs_optimized_instances: Option<bool>,
+    volume_configurations: Option<Vec<VolumeConfiguration>>,
+}
+
+impl CreateLayerRequestBuilder {
+    /// Sets the [`attributes`](struct.CreateLayerRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<LayerAttributesKeys, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_assign_elastic_ips`](struct.CreateLayerRequest.html#structfield.auto_assign_elastic_ips) member.
+    pub fn auto_assign_elastic_ips<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_assign_elastic_ips = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_assign_public_ips`](struct.CreateLayerRequest.html#structfield.auto_assign_public_ips) member.
+    pub fn auto_assign_public_ips<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_assign_public_ips = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cloud_watch_logs_configuration`](struct.CreateLayerRequest.html#structfield.cloud_watch_logs_configuration) member.
+    pub fn cloud_watch_logs_configuration<T: Into<CloudWatchLogsConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cloud_watch_logs_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_instance_profile_arn`](struct.CreateLayerRequest.html#structfield.custom_instance_profile_arn) member.
+    pub fn custom_instance_profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_instance_profile_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_json`](struct.CreateLayerRequest.html#structfield.custom_json) member.
+    pub fn custom_json<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_json = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_recipes`](struct.CreateLayerRequest.html#structfield.custom_recipes) member.
+    pub fn custom_recipes<T: Into<Recipes>>(mut self, value: T) -> Self {
+        self.custom_recipes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_security_group_ids`](struct.CreateLayerRequest.html#structfield.custom_security_group_ids) member.
+    pub fn custom_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.custom_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_auto_healing`](struct.CreateLayerRequest.html#structfield.enable_auto_healing) member.
+    pub fn enable_auto_healing<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_auto_healing = Some(value.into());
+        self
+    }
+
+    /// Sets the [`install_updates_on_boot`](struct.CreateLayerRequest.html#structfield.install_updates_on_boot) member.
+    pub fn install_updates_on_boot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.install_updates_on_boot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_event_configuration`](struct.CreateLayerRequest.html#structfield.lifecycle_event_configuration) member.
+    pub fn lifecycle_event_configuration<T: Into<LifecycleEventConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.lifecycle_event_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateLayerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`packages`](struct.CreateLayerRequest.html#structfield.packages) member.
+    pub fn packages<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.packages = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shortname`](struct.CreateLayerRequest.html#structfield.shortname) member.
+    pub fn shortname<T: Into<String>>(mut self, value: T) -> Self {
+        self.shortname = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.CreateLayerRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.CreateLayerRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<LayerType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_ebs_optimized_instances`](struct.CreateLayerRequest.html#structfield.use_ebs_optimized_instances) member.
+    pub fn use_ebs_optimized_instances<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_ebs_optimized_instances = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_configurations`](struct.CreateLayerRequest.html#structfield.volume_configurations) member.
+    pub fn volume_configurations<T: Into<Vec<VolumeConfiguration>>>(mut self, value: T) -> Self {
+        self.volume_configurations = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLayerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateLayerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateLayerRequest {
+            attributes: self.attributes,
+            auto_assign_elastic_ips: self.auto_assign_elastic_ips,
+            auto_assign_public_ips: self.auto_assign_public_ips,
+            cloud_watch_logs_configuration: self.cloud_watch_logs_configuration,
+            custom_instance_profile_arn: self.custom_instance_profile_arn,
+            custom_json: self.custom_json,
+            custom_recipes: self.custom_recipes,
+            custom_security_group_ids: self.custom_security_group_ids,
+            enable_auto_healing: self.enable_auto_healing,
+            install_updates_on_boot: self.install_updates_on_boot,
+            lifecycle_event_configuration: self.lifecycle_event_configuration,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            packages: self.packages,
+            shortname: self
+                .shortname
+                .ok_or(::rusoto_core::BuildError::missing("shortname"))?,
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+            use_ebs_optimized_instances: self.use_ebs_optimized_instances,
+            volume_configurations: self.volume_configurations,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>CreateLayer</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -689,7 +2505,7 @@ pub struct CreateStackRequest {
     /// <p>One or more user-defined key-value pairs to be added to the stack attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<StackAttributesKeys, String>>,
     /// <p>A <code>ChefConfiguration</code> object that specifies whether to enable Berkshelf and the Berkshelf version on Chef 11.10 stacks. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-creating.html">Create a New Stack</a>.</p>
     #[serde(rename = "ChefConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -720,7 +2536,7 @@ pub struct CreateStackRequest {
     /// <p>The default root device type. This value is the default for all instances in the stack, but you can override it when you create an instance. The default option is <code>instance-store</code>. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
     #[serde(rename = "DefaultRootDeviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_root_device_type: Option<String>,
+    pub default_root_device_type: Option<RootDeviceType>,
     /// <p>A default Amazon EC2 key pair name. The default value is none. If you specify a key pair name, AWS OpsWorks installs the public key on the instance and you can use the private key with an SSH client to log in to the instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-ssh.html"> Using SSH to Communicate with an Instance</a> and <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/security-ssh-access.html"> Managing SSH Access</a>. You can override this setting by specifying a different key pair, or no key pair, when you <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-add.html"> create an instance</a>. </p>
     #[serde(rename = "DefaultSshKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -756,22 +2572,205 @@ pub struct CreateStackRequest {
     pub vpc_id: Option<String>,
 }
 
-/// <p>Contains the response to a <code>CreateStack</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct CreateStackResult {
-    /// <p>The stack ID, which is an opaque string that you use to identify the stack when performing actions such as <code>DescribeStacks</code>.</p>
-    #[serde(rename = "StackId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub stack_id: Option<String>,
+impl CreateStackRequest {
+    /// Returns a builder for `CreateStackRequest`, checking its required members when built.
+    pub fn builder() -> CreateStackRequestBuilder {
+        CreateStackRequestBuilder::default()
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct CreateUserProfileRequest {
-    /// <p>Whether users can specify their own SSH public key through the My Settings page. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/security-settingsshkey.html">Setting an IAM User's Public SSH Key</a>.</p>
-    #[serde(rename = "AllowSelfManagement")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub allow_self_management: Option<bool>,
+/// Builds a [`CreateStackRequest`](struct.CreateStackRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateStackRequestBuilder {
+    agent_version: Option<String>,
+    attributes: Option<::std::collections::HashMap<StackAttributesKeys, String>>,
+    chef_configuration: Option<ChefConfiguration>,
+    configuration_manager: Option<StackConfigurationManager>,
+    custom_cookbooks_source: Option<Source>,
+    custom_json: Option<String>,
+    default_availability_zone: Option<String>,
+    default_instance_profile_arn: Option<String>,
+    default_os: Option<String>,
+    default_root_device_type: Option<RootDeviceType>,
+    default_ssh_key_name: Option<String>,
+    default_subnet_id: Option<String>,
+    hostname_theme: Option<String>,
+    name: Option<String>,
+    region: Option<String>,
+    service_role_arn: Option<String>,
+    use_custom_cookbooks: Option<bool>,
+    use_opsworks_security_groups: Option<bool>,
+    vpc_id: Option<String>,
+}
+
+impl CreateStackRequestBuilder {
+    /// Sets the [`agent_version`](struct.CreateStackRequest.html#structfield.agent_version) member.
+    pub fn agent_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.agent_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attributes`](struct.CreateStackRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<StackAttributesKeys, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`chef_configuration`](struct.CreateStackRequest.html#structfield.chef_configuration) member.
+    pub fn chef_configuration<T: Into<ChefConfiguration>>(mut self, value: T) -> Self {
+        self.chef_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration_manager`](struct.CreateStackRequest.html#structfield.configuration_manager) member.
+    pub fn configuration_manager<T: Into<StackConfigurationManager>>(mut self, value: T) -> Self {
+        self.configuration_manager = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_cookbooks_source`](struct.CreateStackRequest.html#structfield.custom_cookbooks_source) member.
+    pub fn custom_cookbooks_source<T: Into<Source>>(mut self, value: T) -> Self {
+        self.custom_cookbooks_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_json`](struct.CreateStackRequest.html#structfield.custom_json) member.
+    pub fn custom_json<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_json = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_availability_zone`](struct.CreateStackRequest.html#structfield.default_availability_zone) member.
+    pub fn default_availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_instance_profile_arn`](struct.CreateStackRequest.html#structfield.default_instance_profile_arn) member.
+    pub fn default_instance_profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_instance_profile_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_os`](struct.CreateStackRequest.html#structfield.default_os) member.
+    pub fn default_os<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_os = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_root_device_type`](struct.CreateStackRequest.html#structfield.default_root_device_type) member.
+    pub fn default_root_device_type<T: Into<RootDeviceType>>(mut self, value: T) -> Self {
+        self.default_root_device_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_ssh_key_name`](struct.CreateStackRequest.html#structfield.default_ssh_key_name) member.
+    pub fn default_ssh_key_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_ssh_key_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_subnet_id`](struct.CreateStackRequest.html#structfield.default_subnet_id) member.
+    pub fn default_subnet_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_subnet_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hostname_theme`](struct.CreateStackRequest.html#structfield.hostname_theme) member.
+    pub fn hostname_theme<T: Into<String>>(mut self, value: T) -> Self {
+        self.hostname_theme = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateStackRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`region`](struct.CreateStackRequest.html#structfield.region) member.
+    pub fn region<T: Into<String>>(mut self, value: T) -> Self {
+        self.region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role_arn`](struct.CreateStackRequest.html#structfield.service_role_arn) member.
+    pub fn service_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_custom_cookbooks`](struct.CreateStackRequest.html#structfield.use_custom_cookbooks) member.
+    pub fn use_custom_cookbooks<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_custom_cookbooks = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_opsworks_security_groups`](struct.CreateStackRequest.html#structfield.use_opsworks_security_groups) member.
+    pub fn use_opsworks_security_groups<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_opsworks_security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_id`](struct.CreateStackRequest.html#structfield.vpc_id) member.
+    pub fn vpc_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.vpc_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStackRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateStackRequest, ::rusoto_core::BuildError> {
+        Ok(CreateStackRequest {
+            agent_version: self.agent_version,
+            attributes: self.attributes,
+            chef_configuration: self.chef_configuration,
+            configuration_manager: self.configuration_manager,
+            custom_cookbooks_source: self.custom_cookbooks_source,
+            custom_json: self.custom_json,
+            default_availability_zone: self.default_availability_zone,
+            default_instance_profile_arn: self.default_instance_profile_arn.ok_or(
+                ::rusoto_core::BuildError::missing("default_instance_profile_arn"),
+            )?,
+            default_os: self.default_os,
+            default_root_device_type: self.default_root_device_type,
+            default_ssh_key_name: self.default_ssh_key_name,
+            default_subnet_id: self.default_subnet_id,
+            hostname_theme: self.hostname_theme,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            region: self
+                .region
+                .ok_or(::rusoto_core::BuildError::missing("region"))?,
+            service_role_arn: self
+                .service_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("service_role_arn"))?,
+            use_custom_cookbooks: self.use_custom_cookbooks,
+            use_opsworks_security_groups: self.use_opsworks_security_groups,
+            vpc_id: self.vpc_id,
+        })
+    }
+}
+
+/// <p>Contains the response to a <code>CreateStack</code> request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct CreateStackResult {
+    /// <p>The stack ID, which is an opaque string that you use to identify the stack when performing actions such as <code>DescribeStacks</code>.</p>
+    #[serde(rename = "StackId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stack_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct CreateUserProfileRequest {
+    /// <p>Whether users can specify their own SSH public key through the My Settings page. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/security-settingsshkey.html">Setting an IAM User's Public SSH Key</a>.</p>
+    #[serde(rename = "AllowSelfManagement")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_self_management: Option<bool>,
     /// <p>The user's IAM ARN; this can also be a federated user's ARN.</p>
     #[serde(rename = "IamUserArn")]
     pub iam_user_arn: String,
@@ -785,6 +2784,62 @@ pub struct CreateUserProfileRequest {
     pub ssh_username: Option<String>,
 }
 
+impl CreateUserProfileRequest {
+    /// Returns a builder for `CreateUserProfileRequest`, checking its required members when built.
+    pub fn builder() -> CreateUserProfileRequestBuilder {
+        CreateUserProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateUserProfileRequest`](struct.CreateUserProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateUserProfileRequestBuilder {
+    allow_self_management: Option<bool>,
+    iam_user_arn: Option<String>,
+    ssh_public_key: Option<String>,
+    ssh_username: Option<String>,
+}
+
+impl CreateUserProfileRequestBuilder {
+    /// Sets the [`allow_self_management`](struct.CreateUserProfileRequest.html#structfield.allow_self_management) member.
+    pub fn allow_self_management<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_self_management = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_user_arn`](struct.CreateUserProfileRequest.html#structfield.iam_user_arn) member.
+    pub fn iam_user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_user_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_public_key`](struct.CreateUserProfileRequest.html#structfield.ssh_public_key) member.
+    pub fn ssh_public_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_username`](struct.CreateUserProfileRequest.html#structfield.ssh_username) member.
+    pub fn ssh_username<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_username = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateUserProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateUserProfileRequest, ::rusoto_core::BuildError> {
+        Ok(CreateUserProfileRequest {
+            allow_self_management: self.allow_self_management,
+            iam_user_arn: self
+                .iam_user_arn
+                .ok_or(::rusoto_core::BuildError::missing("iam_user_arn"))?,
+            ssh_public_key: self.ssh_public_key,
+            ssh_username: self.ssh_username,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>CreateUserProfile</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -819,6 +2874,36 @@ pub struct DeleteAppRequest {
     pub app_id: String,
 }
 
+impl DeleteAppRequest {
+    /// Returns a builder for `DeleteAppRequest`, checking its required members when built.
+    pub fn builder() -> DeleteAppRequestBuilder {
+        DeleteAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteAppRequest`](struct.DeleteAppRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteAppRequestBuilder {
+    app_id: Option<String>,
+}
+
+impl DeleteAppRequestBuilder {
+    /// Sets the [`app_id`](struct.DeleteAppRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteAppRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteAppRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteInstanceRequest {
     /// <p>Whether to delete the instance Elastic IP address.</p>
@@ -834,6 +2919,52 @@ pub struct DeleteInstanceRequest {
     pub instance_id: String,
 }
 
+impl DeleteInstanceRequest {
+    /// Returns a builder for `DeleteInstanceRequest`, checking its required members when built.
+    pub fn builder() -> DeleteInstanceRequestBuilder {
+        DeleteInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteInstanceRequest`](struct.DeleteInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteInstanceRequestBuilder {
+    delete_elastic_ip: Option<bool>,
+    delete_volumes: Option<bool>,
+    instance_id: Option<String>,
+}
+
+impl DeleteInstanceRequestBuilder {
+    /// Sets the [`delete_elastic_ip`](struct.DeleteInstanceRequest.html#structfield.delete_elastic_ip) member.
+    pub fn delete_elastic_ip<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_elastic_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delete_volumes`](struct.DeleteInstanceRequest.html#structfield.delete_volumes) member.
+    pub fn delete_volumes<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_volumes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.DeleteInstanceRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteInstanceRequest {
+            delete_elastic_ip: self.delete_elastic_ip,
+            delete_volumes: self.delete_volumes,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteLayerRequest {
     /// <p>The layer ID.</p>
@@ -841,6 +2972,36 @@ pub struct DeleteLayerRequest {
     pub layer_id: String,
 }
 
+impl DeleteLayerRequest {
+    /// Returns a builder for `DeleteLayerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLayerRequestBuilder {
+        DeleteLayerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLayerRequest`](struct.DeleteLayerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLayerRequestBuilder {
+    layer_id: Option<String>,
+}
+
+impl DeleteLayerRequestBuilder {
+    /// Sets the [`layer_id`](struct.DeleteLayerRequest.html#structfield.layer_id) member.
+    pub fn layer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLayerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteLayerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLayerRequest {
+            layer_id: self
+                .layer_id
+                .ok_or(::rusoto_core::BuildError::missing("layer_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteStackRequest {
     /// <p>The stack ID.</p>
@@ -848,6 +3009,36 @@ pub struct DeleteStackRequest {
     pub stack_id: String,
 }
 
+impl DeleteStackRequest {
+    /// Returns a builder for `DeleteStackRequest`, checking its required members when built.
+    pub fn builder() -> DeleteStackRequestBuilder {
+        DeleteStackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStackRequest`](struct.DeleteStackRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteStackRequestBuilder {
+    stack_id: Option<String>,
+}
+
+impl DeleteStackRequestBuilder {
+    /// Sets the [`stack_id`](struct.DeleteStackRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStackRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteStackRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteStackRequest {
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteUserProfileRequest {
     /// <p>The user's IAM ARN. This can also be a federated user's ARN.</p>
@@ -855,6 +3046,38 @@ pub struct DeleteUserProfileRequest {
     pub iam_user_arn: String,
 }
 
+impl DeleteUserProfileRequest {
+    /// Returns a builder for `DeleteUserProfileRequest`, checking its required members when built.
+    pub fn builder() -> DeleteUserProfileRequestBuilder {
+        DeleteUserProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteUserProfileRequest`](struct.DeleteUserProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteUserProfileRequestBuilder {
+    iam_user_arn: Option<String>,
+}
+
+impl DeleteUserProfileRequestBuilder {
+    /// Sets the [`iam_user_arn`](struct.DeleteUserProfileRequest.html#structfield.iam_user_arn) member.
+    pub fn iam_user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_user_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteUserProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteUserProfileRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteUserProfileRequest {
+            iam_user_arn: self
+                .iam_user_arn
+                .ok_or(::rusoto_core::BuildError::missing("iam_user_arn"))?,
+        })
+    }
+}
+
 /// <p>Describes a deployment of a stack or app.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -910,7 +3133,7 @@ pub struct Deployment {
 }
 
 /// <p>Used to specify a stack or deployment command.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeploymentCommand {
     /// <p>The arguments of those commands that take arguments. It should be set to a JSON object with the following format:</p> <p> <code>{"arg_name1" : ["value1", "value2", ...], "arg_name2" : ["value1", "value2", ...], ...}</code> </p> <p>The <code>update_dependencies</code> command takes two arguments:</p> <ul> <li> <p> <code>upgrade_os_to</code> - Specifies the desired Amazon Linux version for instances whose OS you want to upgrade, such as <code>Amazon Linux 2016.09</code>. You must also set the <code>allow_reboot</code> argument to true.</p> </li> <li> <p> <code>allow_reboot</code> - Specifies whether to allow AWS OpsWorks Stacks to reboot the instances if necessary, after installing the updates. This argument can be set to either <code>true</code> or <code>false</code>. The default value is <code>false</code>.</p> </li> </ul> <p>For example, to upgrade an instance to Amazon Linux 2016.09, set <code>Args</code> to the following.</p> <p> <code> { "upgrade_os_to":["Amazon Linux 2016.09"], "allow_reboot":["true"] } </code> </p>
     #[serde(rename = "Args")]
@@ -918,7 +3141,119 @@ pub struct DeploymentCommand {
     pub args: Option<::std::collections::HashMap<String, Vec<String>>>,
     /// <p><p>Specifies the operation. You can specify only one command.</p> <p>For stacks, the following commands are available:</p> <ul> <li> <p> <code>execute<em>recipes</code>: Execute one or more recipes. To specify the recipes, set an <code>Args</code> parameter named <code>recipes</code> to the list of recipes to be executed. For example, to execute <code>phpapp::appsetup</code>, set <code>Args</code> to <code>{&quot;recipes&quot;:[&quot;phpapp::appsetup&quot;]}</code>.</p> </li> <li> <p> <code>install</em>dependencies</code>: Install the stack&#39;s dependencies.</p> </li> <li> <p> <code>update<em>custom</em>cookbooks</code>: Update the stack&#39;s custom cookbooks.</p> </li> <li> <p> <code>update<em>dependencies</code>: Update the stack&#39;s dependencies.</p> </li> </ul> <note> <p>The update</em>dependencies and install_dependencies commands are supported only for Linux instances. You can run the commands successfully on Windows instances, but they do nothing.</p> </note> <p>For apps, the following commands are available:</p> <ul> <li> <p> <code>deploy</code>: Deploy an app. Ruby on Rails apps have an optional <code>Args</code> parameter named <code>migrate</code>. Set <code>Args</code> to {&quot;migrate&quot;:[&quot;true&quot;]} to migrate the database. The default setting is {&quot;migrate&quot;:[&quot;false&quot;]}.</p> </li> <li> <p> <code>rollback</code> Roll the app back to the previous version. When you update an app, AWS OpsWorks Stacks stores the previous version, up to a maximum of five versions. You can use this command to roll an app back as many as four versions.</p> </li> <li> <p> <code>start</code>: Start the app&#39;s web or application server.</p> </li> <li> <p> <code>stop</code>: Stop the app&#39;s web or application server.</p> </li> <li> <p> <code>restart</code>: Restart the app&#39;s web or application server.</p> </li> <li> <p> <code>undeploy</code>: Undeploy the app.</p> </li> </ul></p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: DeploymentCommandName,
+}
+
+/// <p><p>Specifies the operation. You can specify only one command.</p> <p>For stacks, the following commands are available:</p> <ul> <li> <p> <code>execute<em>recipes</code>: Execute one or more recipes. To specify the recipes, set an <code>Args</code> parameter named <code>recipes</code> to the list of recipes to be executed. For example, to execute <code>phpapp::appsetup</code>, set <code>Args</code> to <code>{&quot;recipes&quot;:[&quot;phpapp::appsetup&quot;]}</code>.</p> </li> <li> <p> <code>install</em>dependencies</code>: Install the stack&#39;s dependencies.</p> </li> <li> <p> <code>update<em>custom</em>cookbooks</code>: Update the stack&#39;s custom cookbooks.</p> </li> <li> <p> <code>update<em>dependencies</code>: Update the stack&#39;s dependencies.</p> </li> </ul> <note> <p>The update</em>dependencies and install_dependencies commands are supported only for Linux instances. You can run the commands successfully on Windows instances, but they do nothing.</p> </note> <p>For apps, the following commands are available:</p> <ul> <li> <p> <code>deploy</code>: Deploy an app. Ruby on Rails apps have an optional <code>Args</code> parameter named <code>migrate</code>. Set <code>Args</code> to {&quot;migrate&quot;:[&quot;true&quot;]} to migrate the database. The default setting is {&quot;migrate&quot;:[&quot;false&quot;]}.</p> </li> <li> <p> <code>rollback</code> Roll the app back to the previous version. When you update an app, AWS OpsWorks Stacks stores the previous version, up to a maximum of five versions. You can use this command to roll an app back as many as four versions.</p> </li> <li> <p> <code>start</code>: Start the app&#39;s web or application server.</p> </li> <li> <p> <code>stop</code>: Stop the app&#39;s web or application server.</p> </li> <li> <p> <code>restart</code>: Restart the app&#39;s web or application server.</p> </li> <li> <p> <code>undeploy</code>: Undeploy the app.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentCommandName {
+    /// <p><code>install_dependencies</code></p>
+    InstallDependencies,
+    /// <p><code>update_dependencies</code></p>
+    UpdateDependencies,
+    /// <p><code>update_custom_cookbooks</code></p>
+    UpdateCustomCookbooks,
+    /// <p><code>execute_recipes</code></p>
+    ExecuteRecipes,
+    /// <p><code>configure</code></p>
+    Configure,
+    /// <p><code>setup</code></p>
+    Setup,
+    /// <p><code>deploy</code> - Deploy an app. Ruby on Rails apps have an optional <code>Args</code> parameter named <code>migrate</code>. Set <code>Args</code> to {&quot;migrate&quot;:[&quot;true&quot;]} to migrate the database. The default setting is {&quot;migrate&quot;:[&quot;false&quot;]}.</p>
+    Deploy,
+    /// <p><code>rollback</code></p>
+    Rollback,
+    /// <p><code>start</code> - Start the app&#39;s web or application server.</p>
+    Start,
+    /// <p><code>stop</code> - Stop the app&#39;s web or application server.</p>
+    Stop,
+    /// <p><code>restart</code> - Restart the app&#39;s web or application server.</p>
+    Restart,
+    /// <p><code>undeploy</code> - Undeploy the app.</p>
+    Undeploy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl DeploymentCommandName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            DeploymentCommandName::InstallDependencies => "install_dependencies",
+            DeploymentCommandName::UpdateDependencies => "update_dependencies",
+            DeploymentCommandName::UpdateCustomCookbooks => "update_custom_cookbooks",
+            DeploymentCommandName::ExecuteRecipes => "execute_recipes",
+            DeploymentCommandName::Configure => "configure",
+            DeploymentCommandName::Setup => "setup",
+            DeploymentCommandName::Deploy => "deploy",
+            DeploymentCommandName::Rollback => "rollback",
+            DeploymentCommandName::Start => "start",
+            DeploymentCommandName::Stop => "stop",
+            DeploymentCommandName::Restart => "restart",
+            DeploymentCommandName::Undeploy => "undeploy",
+            DeploymentCommandName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for DeploymentCommandName {
+    fn from(value: &'a str) -> DeploymentCommandName {
+        match value {
+            "install_dependencies" => DeploymentCommandName::InstallDependencies,
+            "update_dependencies" => DeploymentCommandName::UpdateDependencies,
+            "update_custom_cookbooks" => DeploymentCommandName::UpdateCustomCookbooks,
+            "execute_recipes" => DeploymentCommandName::ExecuteRecipes,
+            "configure" => DeploymentCommandName::Configure,
+            "setup" => DeploymentCommandName::Setup,
+            "deploy" => DeploymentCommandName::Deploy,
+            "rollback" => DeploymentCommandName::Rollback,
+            "start" => DeploymentCommandName::Start,
+            "stop" => DeploymentCommandName::Stop,
+            "restart" => DeploymentCommandName::Restart,
+            "undeploy" => DeploymentCommandName::Undeploy,
+            _ => DeploymentCommandName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for DeploymentCommandName {
+    fn from(value: String) -> DeploymentCommandName {
+        DeploymentCommandName::from(value.as_str())
+    }
+}
+
+impl From<DeploymentCommandName> for String {
+    fn from(value: DeploymentCommandName) -> String {
+        match value {
+            DeploymentCommandName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for DeploymentCommandName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for DeploymentCommandName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for DeploymentCommandName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<DeploymentCommandName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(DeploymentCommandName::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -928,6 +3263,38 @@ pub struct DeregisterEcsClusterRequest {
     pub ecs_cluster_arn: String,
 }
 
+impl DeregisterEcsClusterRequest {
+    /// Returns a builder for `DeregisterEcsClusterRequest`, checking its required members when built.
+    pub fn builder() -> DeregisterEcsClusterRequestBuilder {
+        DeregisterEcsClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterEcsClusterRequest`](struct.DeregisterEcsClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct DeregisterEcsClusterRequestBuilder {
+    ecs_cluster_arn: Option<String>,
+}
+
+impl DeregisterEcsClusterRequestBuilder {
+    /// Sets the [`ecs_cluster_arn`](struct.DeregisterEcsClusterRequest.html#structfield.ecs_cluster_arn) member.
+    pub fn ecs_cluster_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.ecs_cluster_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterEcsClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterEcsClusterRequest, ::rusoto_core::BuildError> {
+        Ok(DeregisterEcsClusterRequest {
+            ecs_cluster_arn: self
+                .ecs_cluster_arn
+                .ok_or(::rusoto_core::BuildError::missing("ecs_cluster_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeregisterElasticIpRequest {
     /// <p>The Elastic IP address.</p>
@@ -935,6 +3302,38 @@ pub struct DeregisterElasticIpRequest {
     pub elastic_ip: String,
 }
 
+impl DeregisterElasticIpRequest {
+    /// Returns a builder for `DeregisterElasticIpRequest`, checking its required members when built.
+    pub fn builder() -> DeregisterElasticIpRequestBuilder {
+        DeregisterElasticIpRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterElasticIpRequest`](struct.DeregisterElasticIpRequest.html).
+#[derive(Default, Debug)]
+pub struct DeregisterElasticIpRequestBuilder {
+    elastic_ip: Option<String>,
+}
+
+impl DeregisterElasticIpRequestBuilder {
+    /// Sets the [`elastic_ip`](struct.DeregisterElasticIpRequest.html#structfield.elastic_ip) member.
+    pub fn elastic_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.elastic_ip = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterElasticIpRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterElasticIpRequest, ::rusoto_core::BuildError> {
+        Ok(DeregisterElasticIpRequest {
+            elastic_ip: self
+                .elastic_ip
+                .ok_or(::rusoto_core::BuildError::missing("elastic_ip"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeregisterInstanceRequest {
     /// <p>The instance ID.</p>
@@ -942,6 +3341,38 @@ pub struct DeregisterInstanceRequest {
     pub instance_id: String,
 }
 
+impl DeregisterInstanceRequest {
+    /// Returns a builder for `DeregisterInstanceRequest`, checking its required members when built.
+    pub fn builder() -> DeregisterInstanceRequestBuilder {
+        DeregisterInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterInstanceRequest`](struct.DeregisterInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeregisterInstanceRequestBuilder {
+    instance_id: Option<String>,
+}
+
+impl DeregisterInstanceRequestBuilder {
+    /// Sets the [`instance_id`](struct.DeregisterInstanceRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(DeregisterInstanceRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeregisterRdsDbInstanceRequest {
     /// <p>The Amazon RDS instance's ARN.</p>
@@ -949,6 +3380,38 @@ pub struct DeregisterRdsDbInstanceRequest {
     pub rds_db_instance_arn: String,
 }
 
+impl DeregisterRdsDbInstanceRequest {
+    /// Returns a builder for `DeregisterRdsDbInstanceRequest`, checking its required members when built.
+    pub fn builder() -> DeregisterRdsDbInstanceRequestBuilder {
+        DeregisterRdsDbInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterRdsDbInstanceRequest`](struct.DeregisterRdsDbInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct DeregisterRdsDbInstanceRequestBuilder {
+    rds_db_instance_arn: Option<String>,
+}
+
+impl DeregisterRdsDbInstanceRequestBuilder {
+    /// Sets the [`rds_db_instance_arn`](struct.DeregisterRdsDbInstanceRequest.html#structfield.rds_db_instance_arn) member.
+    pub fn rds_db_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.rds_db_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterRdsDbInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterRdsDbInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(DeregisterRdsDbInstanceRequest {
+            rds_db_instance_arn: self
+                .rds_db_instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("rds_db_instance_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeregisterVolumeRequest {
     /// <p>The AWS OpsWorks Stacks volume ID, which is the GUID that AWS OpsWorks Stacks assigned to the instance when you registered the volume with the stack, not the Amazon EC2 volume ID.</p>
@@ -956,6 +3419,38 @@ pub struct DeregisterVolumeRequest {
     pub volume_id: String,
 }
 
+impl DeregisterVolumeRequest {
+    /// Returns a builder for `DeregisterVolumeRequest`, checking its required members when built.
+    pub fn builder() -> DeregisterVolumeRequestBuilder {
+        DeregisterVolumeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeregisterVolumeRequest`](struct.DeregisterVolumeRequest.html).
+#[derive(Default, Debug)]
+pub struct DeregisterVolumeRequestBuilder {
+    volume_id: Option<String>,
+}
+
+impl DeregisterVolumeRequestBuilder {
+    /// Sets the [`volume_id`](struct.DeregisterVolumeRequest.html#structfield.volume_id) member.
+    pub fn volume_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeregisterVolumeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeregisterVolumeRequest, ::rusoto_core::BuildError> {
+        Ok(DeregisterVolumeRequest {
+            volume_id: self
+                .volume_id
+                .ok_or(::rusoto_core::BuildError::missing("volume_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeAgentVersionsRequest {
     /// <p>The configuration manager.</p>
@@ -968,6 +3463,44 @@ pub struct DescribeAgentVersionsRequest {
     pub stack_id: Option<String>,
 }
 
+impl DescribeAgentVersionsRequest {
+    /// Returns a builder for `DescribeAgentVersionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAgentVersionsRequestBuilder {
+        DescribeAgentVersionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAgentVersionsRequest`](struct.DescribeAgentVersionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAgentVersionsRequestBuilder {
+    configuration_manager: Option<StackConfigurationManager>,
+    stack_id: Option<String>,
+}
+
+impl DescribeAgentVersionsRequestBuilder {
+    /// Sets the [`configuration_manager`](struct.DescribeAgentVersionsRequest.html#structfield.configuration_manager) member.
+    pub fn configuration_manager<T: Into<StackConfigurationManager>>(mut self, value: T) -> Self {
+        self.configuration_manager = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribeAgentVersionsRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAgentVersionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAgentVersionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAgentVersionsRequest {
+            configuration_manager: self.configuration_manager,
+            stack_id: self.stack_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeAgentVersions</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -990,6 +3523,42 @@ pub struct DescribeAppsRequest {
     pub stack_id: Option<String>,
 }
 
+impl DescribeAppsRequest {
+    /// Returns a builder for `DescribeAppsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAppsRequestBuilder {
+        DescribeAppsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAppsRequest`](struct.DescribeAppsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAppsRequestBuilder {
+    app_ids: Option<Vec<String>>,
+    stack_id: Option<String>,
+}
+
+impl DescribeAppsRequestBuilder {
+    /// Sets the [`app_ids`](struct.DescribeAppsRequest.html#structfield.app_ids) member.
+    pub fn app_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.app_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribeAppsRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAppsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeAppsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAppsRequest {
+            app_ids: self.app_ids,
+            stack_id: self.stack_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeApps</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1016,6 +3585,52 @@ pub struct DescribeCommandsRequest {
     pub instance_id: Option<String>,
 }
 
+impl DescribeCommandsRequest {
+    /// Returns a builder for `DescribeCommandsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCommandsRequestBuilder {
+        DescribeCommandsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCommandsRequest`](struct.DescribeCommandsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCommandsRequestBuilder {
+    command_ids: Option<Vec<String>>,
+    deployment_id: Option<String>,
+    instance_id: Option<String>,
+}
+
+impl DescribeCommandsRequestBuilder {
+    /// Sets the [`command_ids`](struct.DescribeCommandsRequest.html#structfield.command_ids) member.
+    pub fn command_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.command_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_id`](struct.DescribeCommandsRequest.html#structfield.deployment_id) member.
+    pub fn deployment_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.deployment_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.DescribeCommandsRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCommandsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCommandsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeCommandsRequest {
+            command_ids: self.command_ids,
+            deployment_id: self.deployment_id,
+            instance_id: self.instance_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeCommands</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1042,6 +3657,52 @@ pub struct DescribeDeploymentsRequest {
     pub stack_id: Option<String>,
 }
 
+impl DescribeDeploymentsRequest {
+    /// Returns a builder for `DescribeDeploymentsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDeploymentsRequestBuilder {
+        DescribeDeploymentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDeploymentsRequest`](struct.DescribeDeploymentsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDeploymentsRequestBuilder {
+    app_id: Option<String>,
+    deployment_ids: Option<Vec<String>>,
+    stack_id: Option<String>,
+}
+
+impl DescribeDeploymentsRequestBuilder {
+    /// Sets the [`app_id`](struct.DescribeDeploymentsRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deployment_ids`](struct.DescribeDeploymentsRequest.html#structfield.deployment_ids) member.
+    pub fn deployment_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.deployment_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribeDeploymentsRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDeploymentsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDeploymentsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDeploymentsRequest {
+            app_id: self.app_id,
+            deployment_ids: self.deployment_ids,
+            stack_id: self.stack_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeDeployments</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1072,6 +3733,60 @@ pub struct DescribeEcsClustersRequest {
     pub stack_id: Option<String>,
 }
 
+impl DescribeEcsClustersRequest {
+    /// Returns a builder for `DescribeEcsClustersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEcsClustersRequestBuilder {
+        DescribeEcsClustersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEcsClustersRequest`](struct.DescribeEcsClustersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEcsClustersRequestBuilder {
+    ecs_cluster_arns: Option<Vec<String>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    stack_id: Option<String>,
+}
+
+impl DescribeEcsClustersRequestBuilder {
+    /// Sets the [`ecs_cluster_arns`](struct.DescribeEcsClustersRequest.html#structfield.ecs_cluster_arns) member.
+    pub fn ecs_cluster_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.ecs_cluster_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeEcsClustersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeEcsClustersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribeEcsClustersRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEcsClustersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEcsClustersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEcsClustersRequest {
+            ecs_cluster_arns: self.ecs_cluster_arns,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            stack_id: self.stack_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeEcsClusters</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1102,6 +3817,52 @@ pub struct DescribeElasticIpsRequest {
     pub stack_id: Option<String>,
 }
 
+impl DescribeElasticIpsRequest {
+    /// Returns a builder for `DescribeElasticIpsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeElasticIpsRequestBuilder {
+        DescribeElasticIpsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeElasticIpsRequest`](struct.DescribeElasticIpsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeElasticIpsRequestBuilder {
+    instance_id: Option<String>,
+    ips: Option<Vec<String>>,
+    stack_id: Option<String>,
+}
+
+impl DescribeElasticIpsRequestBuilder {
+    /// Sets the [`instance_id`](struct.DescribeElasticIpsRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ips`](struct.DescribeElasticIpsRequest.html#structfield.ips) member.
+    pub fn ips<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.ips = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribeElasticIpsRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeElasticIpsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeElasticIpsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeElasticIpsRequest {
+            instance_id: self.instance_id,
+            ips: self.ips,
+            stack_id: self.stack_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeElasticIps</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1124,6 +3885,44 @@ pub struct DescribeElasticLoadBalancersRequest {
     pub stack_id: Option<String>,
 }
 
+impl DescribeElasticLoadBalancersRequest {
+    /// Returns a builder for `DescribeElasticLoadBalancersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeElasticLoadBalancersRequestBuilder {
+        DescribeElasticLoadBalancersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeElasticLoadBalancersRequest`](struct.DescribeElasticLoadBalancersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeElasticLoadBalancersRequestBuilder {
+    layer_ids: Option<Vec<String>>,
+    stack_id: Option<String>,
+}
+
+impl DescribeElasticLoadBalancersRequestBuilder {
+    /// Sets the [`layer_ids`](struct.DescribeElasticLoadBalancersRequest.html#structfield.layer_ids) member.
+    pub fn layer_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.layer_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribeElasticLoadBalancersRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeElasticLoadBalancersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeElasticLoadBalancersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeElasticLoadBalancersRequest {
+            layer_ids: self.layer_ids,
+            stack_id: self.stack_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeElasticLoadBalancers</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1150,6 +3949,52 @@ pub struct DescribeInstancesRequest {
     pub stack_id: Option<String>,
 }
 
+impl DescribeInstancesRequest {
+    /// Returns a builder for `DescribeInstancesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeInstancesRequestBuilder {
+        DescribeInstancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeInstancesRequest`](struct.DescribeInstancesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeInstancesRequestBuilder {
+    instance_ids: Option<Vec<String>>,
+    layer_id: Option<String>,
+    stack_id: Option<String>,
+}
+
+impl DescribeInstancesRequestBuilder {
+    /// Sets the [`instance_ids`](struct.DescribeInstancesRequest.html#structfield.instance_ids) member.
+    pub fn instance_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layer_id`](struct.DescribeInstancesRequest.html#structfield.layer_id) member.
+    pub fn layer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribeInstancesRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeInstancesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeInstancesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeInstancesRequest {
+            instance_ids: self.instance_ids,
+            layer_id: self.layer_id,
+            stack_id: self.stack_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeInstances</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1172,6 +4017,42 @@ pub struct DescribeLayersRequest {
     pub stack_id: Option<String>,
 }
 
+impl DescribeLayersRequest {
+    /// Returns a builder for `DescribeLayersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeLayersRequestBuilder {
+        DescribeLayersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLayersRequest`](struct.DescribeLayersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeLayersRequestBuilder {
+    layer_ids: Option<Vec<String>>,
+    stack_id: Option<String>,
+}
+
+impl DescribeLayersRequestBuilder {
+    /// Sets the [`layer_ids`](struct.DescribeLayersRequest.html#structfield.layer_ids) member.
+    pub fn layer_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.layer_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribeLayersRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLayersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeLayersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeLayersRequest {
+            layer_ids: self.layer_ids,
+            stack_id: self.stack_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeLayers</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1189,6 +4070,38 @@ pub struct DescribeLoadBasedAutoScalingRequest {
     pub layer_ids: Vec<String>,
 }
 
+impl DescribeLoadBasedAutoScalingRequest {
+    /// Returns a builder for `DescribeLoadBasedAutoScalingRequest`, checking its required members when built.
+    pub fn builder() -> DescribeLoadBasedAutoScalingRequestBuilder {
+        DescribeLoadBasedAutoScalingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLoadBasedAutoScalingRequest`](struct.DescribeLoadBasedAutoScalingRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeLoadBasedAutoScalingRequestBuilder {
+    layer_ids: Option<Vec<String>>,
+}
+
+impl DescribeLoadBasedAutoScalingRequestBuilder {
+    /// Sets the [`layer_ids`](struct.DescribeLoadBasedAutoScalingRequest.html#structfield.layer_ids) member.
+    pub fn layer_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.layer_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLoadBasedAutoScalingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLoadBasedAutoScalingRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeLoadBasedAutoScalingRequest {
+            layer_ids: self
+                .layer_ids
+                .ok_or(::rusoto_core::BuildError::missing("layer_ids"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeLoadBasedAutoScaling</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1231,6 +4144,44 @@ pub struct DescribePermissionsRequest {
     pub stack_id: Option<String>,
 }
 
+impl DescribePermissionsRequest {
+    /// Returns a builder for `DescribePermissionsRequest`, checking its required members when built.
+    pub fn builder() -> DescribePermissionsRequestBuilder {
+        DescribePermissionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePermissionsRequest`](struct.DescribePermissionsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribePermissionsRequestBuilder {
+    iam_user_arn: Option<String>,
+    stack_id: Option<String>,
+}
+
+impl DescribePermissionsRequestBuilder {
+    /// Sets the [`iam_user_arn`](struct.DescribePermissionsRequest.html#structfield.iam_user_arn) member.
+    pub fn iam_user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_user_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribePermissionsRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePermissionsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePermissionsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribePermissionsRequest {
+            iam_user_arn: self.iam_user_arn,
+            stack_id: self.stack_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribePermissions</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1257,6 +4208,52 @@ pub struct DescribeRaidArraysRequest {
     pub stack_id: Option<String>,
 }
 
+impl DescribeRaidArraysRequest {
+    /// Returns a builder for `DescribeRaidArraysRequest`, checking its required members when built.
+    pub fn builder() -> DescribeRaidArraysRequestBuilder {
+        DescribeRaidArraysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRaidArraysRequest`](struct.DescribeRaidArraysRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeRaidArraysRequestBuilder {
+    instance_id: Option<String>,
+    raid_array_ids: Option<Vec<String>>,
+    stack_id: Option<String>,
+}
+
+impl DescribeRaidArraysRequestBuilder {
+    /// Sets the [`instance_id`](struct.DescribeRaidArraysRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`raid_array_ids`](struct.DescribeRaidArraysRequest.html#structfield.raid_array_ids) member.
+    pub fn raid_array_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.raid_array_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribeRaidArraysRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRaidArraysRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeRaidArraysRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeRaidArraysRequest {
+            instance_id: self.instance_id,
+            raid_array_ids: self.raid_array_ids,
+            stack_id: self.stack_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeRaidArrays</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1278,6 +4275,46 @@ pub struct DescribeRdsDbInstancesRequest {
     pub stack_id: String,
 }
 
+impl DescribeRdsDbInstancesRequest {
+    /// Returns a builder for `DescribeRdsDbInstancesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeRdsDbInstancesRequestBuilder {
+        DescribeRdsDbInstancesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeRdsDbInstancesRequest`](struct.DescribeRdsDbInstancesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeRdsDbInstancesRequestBuilder {
+    rds_db_instance_arns: Option<Vec<String>>,
+    stack_id: Option<String>,
+}
+
+impl DescribeRdsDbInstancesRequestBuilder {
+    /// Sets the [`rds_db_instance_arns`](struct.DescribeRdsDbInstancesRequest.html#structfield.rds_db_instance_arns) member.
+    pub fn rds_db_instance_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.rds_db_instance_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribeRdsDbInstancesRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeRdsDbInstancesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeRdsDbInstancesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeRdsDbInstancesRequest {
+            rds_db_instance_arns: self.rds_db_instance_arns,
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeRdsDbInstances</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1304,6 +4341,52 @@ pub struct DescribeServiceErrorsRequest {
     pub stack_id: Option<String>,
 }
 
+impl DescribeServiceErrorsRequest {
+    /// Returns a builder for `DescribeServiceErrorsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeServiceErrorsRequestBuilder {
+        DescribeServiceErrorsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeServiceErrorsRequest`](struct.DescribeServiceErrorsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeServiceErrorsRequestBuilder {
+    instance_id: Option<String>,
+    service_error_ids: Option<Vec<String>>,
+    stack_id: Option<String>,
+}
+
+impl DescribeServiceErrorsRequestBuilder {
+    /// Sets the [`instance_id`](struct.DescribeServiceErrorsRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_error_ids`](struct.DescribeServiceErrorsRequest.html#structfield.service_error_ids) member.
+    pub fn service_error_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.service_error_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribeServiceErrorsRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeServiceErrorsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeServiceErrorsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeServiceErrorsRequest {
+            instance_id: self.instance_id,
+            service_error_ids: self.service_error_ids,
+            stack_id: self.stack_id,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeServiceErrors</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1321,6 +4404,39 @@ pub struct DescribeStackProvisioningParametersRequest {
     pub stack_id: String,
 }
 
+impl DescribeStackProvisioningParametersRequest {
+    /// Returns a builder for `DescribeStackProvisioningParametersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeStackProvisioningParametersRequestBuilder {
+        DescribeStackProvisioningParametersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStackProvisioningParametersRequest`](struct.DescribeStackProvisioningParametersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeStackProvisioningParametersRequestBuilder {
+    stack_id: Option<String>,
+}
+
+impl DescribeStackProvisioningParametersRequestBuilder {
+    /// Sets the [`stack_id`](struct.DescribeStackProvisioningParametersRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStackProvisioningParametersRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStackProvisioningParametersRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeStackProvisioningParametersRequest {
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeStackProvisioningParameters</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1342,6 +4458,38 @@ pub struct DescribeStackSummaryRequest {
     pub stack_id: String,
 }
 
+impl DescribeStackSummaryRequest {
+    /// Returns a builder for `DescribeStackSummaryRequest`, checking its required members when built.
+    pub fn builder() -> DescribeStackSummaryRequestBuilder {
+        DescribeStackSummaryRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStackSummaryRequest`](struct.DescribeStackSummaryRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeStackSummaryRequestBuilder {
+    stack_id: Option<String>,
+}
+
+impl DescribeStackSummaryRequestBuilder {
+    /// Sets the [`stack_id`](struct.DescribeStackSummaryRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStackSummaryRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStackSummaryRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeStackSummaryRequest {
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeStackSummary</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1360,6 +4508,34 @@ pub struct DescribeStacksRequest {
     pub stack_ids: Option<Vec<String>>,
 }
 
+impl DescribeStacksRequest {
+    /// Returns a builder for `DescribeStacksRequest`, checking its required members when built.
+    pub fn builder() -> DescribeStacksRequestBuilder {
+        DescribeStacksRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStacksRequest`](struct.DescribeStacksRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeStacksRequestBuilder {
+    stack_ids: Option<Vec<String>>,
+}
+
+impl DescribeStacksRequestBuilder {
+    /// Sets the [`stack_ids`](struct.DescribeStacksRequest.html#structfield.stack_ids) member.
+    pub fn stack_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.stack_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStacksRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeStacksRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeStacksRequest {
+            stack_ids: self.stack_ids,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeStacks</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1377,6 +4553,38 @@ pub struct DescribeTimeBasedAutoScalingRequest {
     pub instance_ids: Vec<String>,
 }
 
+impl DescribeTimeBasedAutoScalingRequest {
+    /// Returns a builder for `DescribeTimeBasedAutoScalingRequest`, checking its required members when built.
+    pub fn builder() -> DescribeTimeBasedAutoScalingRequestBuilder {
+        DescribeTimeBasedAutoScalingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTimeBasedAutoScalingRequest`](struct.DescribeTimeBasedAutoScalingRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeTimeBasedAutoScalingRequestBuilder {
+    instance_ids: Option<Vec<String>>,
+}
+
+impl DescribeTimeBasedAutoScalingRequestBuilder {
+    /// Sets the [`instance_ids`](struct.DescribeTimeBasedAutoScalingRequest.html#structfield.instance_ids) member.
+    pub fn instance_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.instance_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTimeBasedAutoScalingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTimeBasedAutoScalingRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeTimeBasedAutoScalingRequest {
+            instance_ids: self
+                .instance_ids
+                .ok_or(::rusoto_core::BuildError::missing("instance_ids"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeTimeBasedAutoScaling</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1395,6 +4603,36 @@ pub struct DescribeUserProfilesRequest {
     pub iam_user_arns: Option<Vec<String>>,
 }
 
+impl DescribeUserProfilesRequest {
+    /// Returns a builder for `DescribeUserProfilesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeUserProfilesRequestBuilder {
+        DescribeUserProfilesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeUserProfilesRequest`](struct.DescribeUserProfilesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeUserProfilesRequestBuilder {
+    iam_user_arns: Option<Vec<String>>,
+}
+
+impl DescribeUserProfilesRequestBuilder {
+    /// Sets the [`iam_user_arns`](struct.DescribeUserProfilesRequest.html#structfield.iam_user_arns) member.
+    pub fn iam_user_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.iam_user_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeUserProfilesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeUserProfilesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeUserProfilesRequest {
+            iam_user_arns: self.iam_user_arns,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeUserProfiles</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1425,6 +4663,58 @@ pub struct DescribeVolumesRequest {
     pub volume_ids: Option<Vec<String>>,
 }
 
+impl DescribeVolumesRequest {
+    /// Returns a builder for `DescribeVolumesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeVolumesRequestBuilder {
+        DescribeVolumesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeVolumesRequest`](struct.DescribeVolumesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeVolumesRequestBuilder {
+    instance_id: Option<String>,
+    raid_array_id: Option<String>,
+    stack_id: Option<String>,
+    volume_ids: Option<Vec<String>>,
+}
+
+impl DescribeVolumesRequestBuilder {
+    /// Sets the [`instance_id`](struct.DescribeVolumesRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`raid_array_id`](struct.DescribeVolumesRequest.html#structfield.raid_array_id) member.
+    pub fn raid_array_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.raid_array_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.DescribeVolumesRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_ids`](struct.DescribeVolumesRequest.html#structfield.volume_ids) member.
+    pub fn volume_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.volume_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeVolumesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeVolumesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeVolumesRequest {
+            instance_id: self.instance_id,
+            raid_array_id: self.raid_array_id,
+            stack_id: self.stack_id,
+            volume_ids: self.volume_ids,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>DescribeVolumes</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1445,6 +4735,48 @@ pub struct DetachElasticLoadBalancerRequest {
     pub layer_id: String,
 }
 
+impl DetachElasticLoadBalancerRequest {
+    /// Returns a builder for `DetachElasticLoadBalancerRequest`, checking its required members when built.
+    pub fn builder() -> DetachElasticLoadBalancerRequestBuilder {
+        DetachElasticLoadBalancerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachElasticLoadBalancerRequest`](struct.DetachElasticLoadBalancerRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachElasticLoadBalancerRequestBuilder {
+    elastic_load_balancer_name: Option<String>,
+    layer_id: Option<String>,
+}
+
+impl DetachElasticLoadBalancerRequestBuilder {
+    /// Sets the [`elastic_load_balancer_name`](struct.DetachElasticLoadBalancerRequest.html#structfield.elastic_load_balancer_name) member.
+    pub fn elastic_load_balancer_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.elastic_load_balancer_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layer_id`](struct.DetachElasticLoadBalancerRequest.html#structfield.layer_id) member.
+    pub fn layer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachElasticLoadBalancerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetachElasticLoadBalancerRequest, ::rusoto_core::BuildError> {
+        Ok(DetachElasticLoadBalancerRequest {
+            elastic_load_balancer_name: self.elastic_load_balancer_name.ok_or(
+                ::rusoto_core::BuildError::missing("elastic_load_balancer_name"),
+            )?,
+            layer_id: self
+                .layer_id
+                .ok_or(::rusoto_core::BuildError::missing("layer_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DisassociateElasticIpRequest {
     /// <p>The Elastic IP address.</p>
@@ -1452,6 +4784,38 @@ pub struct DisassociateElasticIpRequest {
     pub elastic_ip: String,
 }
 
+impl DisassociateElasticIpRequest {
+    /// Returns a builder for `DisassociateElasticIpRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateElasticIpRequestBuilder {
+        DisassociateElasticIpRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateElasticIpRequest`](struct.DisassociateElasticIpRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateElasticIpRequestBuilder {
+    elastic_ip: Option<String>,
+}
+
+impl DisassociateElasticIpRequestBuilder {
+    /// Sets the [`elastic_ip`](struct.DisassociateElasticIpRequest.html#structfield.elastic_ip) member.
+    pub fn elastic_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.elastic_ip = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateElasticIpRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateElasticIpRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateElasticIpRequest {
+            elastic_ip: self
+                .elastic_ip
+                .ok_or(::rusoto_core::BuildError::missing("elastic_ip"))?,
+        })
+    }
+}
+
 /// <p>Describes an Amazon EBS volume. This data type maps directly to the Amazon EC2 <a href="https://docs.aws.amazon.com/AWSEC2/latest/APIReference/API_EbsBlockDevice.html">EbsBlockDevice</a> data type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EbsBlockDevice {
@@ -1474,7 +4838,7 @@ pub struct EbsBlockDevice {
     /// <p>The volume type. <code>gp2</code> for General Purpose (SSD) volumes, <code>io1</code> for Provisioned IOPS (SSD) volumes, <code>st1</code> for Throughput Optimized hard disk drives (HDD), <code>sc1</code> for Cold HDD,and <code>standard</code> for Magnetic volumes.</p> <p>If you specify the <code>io1</code> volume type, you must also specify a value for the <code>Iops</code> attribute. The maximum ratio of provisioned IOPS to requested volume size (in GiB) is 50:1. AWS uses the default volume size (in GiB) specified in the AMI attributes to set IOPS to 50 x (volume size).</p>
     #[serde(rename = "VolumeType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub volume_type: Option<String>,
+    pub volume_type: Option<VolumeType>,
 }
 
 /// <p>Describes a registered Amazon ECS cluster.</p>
@@ -1589,9 +4953,41 @@ pub struct GetHostnameSuggestionRequest {
     pub layer_id: String,
 }
 
-/// <p>Contains the response to a <code>GetHostnameSuggestion</code> request.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
+impl GetHostnameSuggestionRequest {
+    /// Returns a builder for `GetHostnameSuggestionRequest`, checking its required members when built.
+    pub fn builder() -> GetHostnameSuggestionRequestBuilder {
+        GetHostnameSuggestionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetHostnameSuggestionRequest`](struct.GetHostnameSuggestionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetHostnameSuggestionRequestBuilder {
+    layer_id: Option<String>,
+}
+
+impl GetHostnameSuggestionRequestBuilder {
+    /// Sets the [`layer_id`](struct.GetHostnameSuggestionRequest.html#structfield.layer_id) member.
+    pub fn layer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetHostnameSuggestionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetHostnameSuggestionRequest, ::rusoto_core::BuildError> {
+        Ok(GetHostnameSuggestionRequest {
+            layer_id: self
+                .layer_id
+                .ok_or(::rusoto_core::BuildError::missing("layer_id"))?,
+        })
+    }
+}
+
+/// <p>Contains the response to a <code>GetHostnameSuggestion</code> request.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
 pub struct GetHostnameSuggestionResult {
     /// <p>The generated host name.</p>
     #[serde(rename = "Hostname")]
@@ -1614,6 +5010,44 @@ pub struct GrantAccessRequest {
     pub valid_for_in_minutes: Option<i64>,
 }
 
+impl GrantAccessRequest {
+    /// Returns a builder for `GrantAccessRequest`, checking its required members when built.
+    pub fn builder() -> GrantAccessRequestBuilder {
+        GrantAccessRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GrantAccessRequest`](struct.GrantAccessRequest.html).
+#[derive(Default, Debug)]
+pub struct GrantAccessRequestBuilder {
+    instance_id: Option<String>,
+    valid_for_in_minutes: Option<i64>,
+}
+
+impl GrantAccessRequestBuilder {
+    /// Sets the [`instance_id`](struct.GrantAccessRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`valid_for_in_minutes`](struct.GrantAccessRequest.html#structfield.valid_for_in_minutes) member.
+    pub fn valid_for_in_minutes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.valid_for_in_minutes = Some(value.into());
+        self
+    }
+
+    /// Builds the `GrantAccessRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GrantAccessRequest, ::rusoto_core::BuildError> {
+        Ok(GrantAccessRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            valid_for_in_minutes: self.valid_for_in_minutes,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>GrantAccess</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1639,7 +5073,7 @@ pub struct Instance {
     /// <p>The instance architecture: "i386" or "x86_64".</p>
     #[serde(rename = "Architecture")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub architecture: Option<String>,
+    pub architecture: Option<Architecture>,
     /// <p>The instance's Amazon Resource Number (ARN).</p>
     #[serde(rename = "Arn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1647,7 +5081,7 @@ pub struct Instance {
     /// <p>For load-based or time-based instances, the type.</p>
     #[serde(rename = "AutoScalingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_scaling_type: Option<String>,
+    pub auto_scaling_type: Option<AutoScalingType>,
     /// <p>The instance Availability Zone. For more information, see <a href="https://docs.aws.amazon.com/general/latest/gr/rande.html">Regions and Endpoints</a>.</p>
     #[serde(rename = "AvailabilityZone")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1751,7 +5185,7 @@ pub struct Instance {
     /// <p>The instance's root device type. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
     #[serde(rename = "RootDeviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub root_device_type: Option<String>,
+    pub root_device_type: Option<RootDeviceType>,
     /// <p>The root device volume ID.</p>
     #[serde(rename = "RootDeviceVolumeId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1791,7 +5225,7 @@ pub struct Instance {
     /// <p>The instance's virtualization type: <code>paravirtual</code> or <code>hvm</code>.</p>
     #[serde(rename = "VirtualizationType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub virtualization_type: Option<String>,
+    pub virtualization_type: Option<VirtualizationType>,
 }
 
 /// <p>Contains a description of an Amazon EC2 instance from the Amazon EC2 metadata service. For more information, see <a href="https://docs.aws.amazon.com/sdkfornet/latest/apidocs/Index.html">Instance Metadata and User Data</a>.</p>
@@ -1904,7 +5338,7 @@ pub struct Layer {
     /// <p>The layer attributes.</p> <p>For the <code>HaproxyStatsPassword</code>, <code>MysqlRootPassword</code>, and <code>GangliaPassword</code> attributes, AWS OpsWorks Stacks returns <code>*****FILTERED*****</code> instead of the actual value</p> <p>For an ECS Cluster layer, AWS OpsWorks Stacks the <code>EcsClusterArn</code> attribute is set to the cluster's ARN.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<LayerAttributesKeys, String>>,
     /// <p>Whether to automatically assign an <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP address</a> to the layer's instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-edit.html">How to Edit a Layer</a>.</p>
     #[serde(rename = "AutoAssignElasticIps")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1980,7 +5414,7 @@ pub struct Layer {
     /// <p>The layer type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<LayerType>,
     /// <p>Whether the layer uses Amazon EBS-optimized instances.</p>
     #[serde(rename = "UseEbsOptimizedInstances")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1991,6 +5425,282 @@ pub struct Layer {
     pub volume_configurations: Option<Vec<VolumeConfiguration>>,
 }
 
+/// <p>One or more user-defined key-value pairs to be added to the stack attributes.</p> <p>To create a cluster layer, set the <code>EcsClusterArn</code> attribute to the cluster's ARN.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LayerAttributesKeys {
+    /// <p><code>EcsClusterArn</code></p>
+    EcsClusterArn,
+    /// <p><code>EnableHaproxyStats</code></p>
+    EnableHaproxyStats,
+    /// <p><code>HaproxyStatsUrl</code></p>
+    HaproxyStatsUrl,
+    /// <p><code>HaproxyStatsUser</code></p>
+    HaproxyStatsUser,
+    /// <p><code>HaproxyStatsPassword</code></p>
+    HaproxyStatsPassword,
+    /// <p><code>HaproxyHealthCheckUrl</code></p>
+    HaproxyHealthCheckUrl,
+    /// <p><code>HaproxyHealthCheckMethod</code></p>
+    HaproxyHealthCheckMethod,
+    /// <p><code>MysqlRootPassword</code></p>
+    MysqlRootPassword,
+    /// <p><code>MysqlRootPasswordUbiquitous</code></p>
+    MysqlRootPasswordUbiquitous,
+    /// <p><code>GangliaUrl</code></p>
+    GangliaUrl,
+    /// <p><code>GangliaUser</code></p>
+    GangliaUser,
+    /// <p><code>GangliaPassword</code></p>
+    GangliaPassword,
+    /// <p><code>MemcachedMemory</code></p>
+    MemcachedMemory,
+    /// <p><code>NodejsVersion</code></p>
+    NodejsVersion,
+    /// <p><code>RubyVersion</code></p>
+    RubyVersion,
+    /// <p><code>RubygemsVersion</code></p>
+    RubygemsVersion,
+    /// <p><code>ManageBundler</code></p>
+    ManageBundler,
+    /// <p><code>BundlerVersion</code></p>
+    BundlerVersion,
+    /// <p><code>RailsStack</code></p>
+    RailsStack,
+    /// <p><code>PassengerVersion</code></p>
+    PassengerVersion,
+    /// <p><code>Jvm</code></p>
+    Jvm,
+    /// <p><code>JvmVersion</code></p>
+    JvmVersion,
+    /// <p><code>JvmOptions</code></p>
+    JvmOptions,
+    /// <p><code>JavaAppServer</code></p>
+    JavaAppServer,
+    /// <p><code>JavaAppServerVersion</code></p>
+    JavaAppServerVersion,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LayerAttributesKeys {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LayerAttributesKeys::EcsClusterArn => "EcsClusterArn",
+            LayerAttributesKeys::EnableHaproxyStats => "EnableHaproxyStats",
+            LayerAttributesKeys::HaproxyStatsUrl => "HaproxyStatsUrl",
+            LayerAttributesKeys::HaproxyStatsUser => "HaproxyStatsUser",
+            LayerAttributesKeys::HaproxyStatsPassword => "HaproxyStatsPassword",
+            LayerAttributesKeys::HaproxyHealthCheckUrl => "HaproxyHealthCheckUrl",
+            LayerAttributesKeys::HaproxyHealthCheckMethod => "HaproxyHealthCheckMethod",
+            LayerAttributesKeys::MysqlRootPassword => "MysqlRootPassword",
+            LayerAttributesKeys::MysqlRootPasswordUbiquitous => "MysqlRootPasswordUbiquitous",
+            LayerAttributesKeys::GangliaUrl => "GangliaUrl",
+            LayerAttributesKeys::GangliaUser => "GangliaUser",
+            LayerAttributesKeys::GangliaPassword => "GangliaPassword",
+            LayerAttributesKeys::MemcachedMemory => "MemcachedMemory",
+            LayerAttributesKeys::NodejsVersion => "NodejsVersion",
+            LayerAttributesKeys::RubyVersion => "RubyVersion",
+            LayerAttributesKeys::RubygemsVersion => "RubygemsVersion",
+            LayerAttributesKeys::ManageBundler => "ManageBundler",
+            LayerAttributesKeys::BundlerVersion => "BundlerVersion",
+            LayerAttributesKeys::RailsStack => "RailsStack",
+            LayerAttributesKeys::PassengerVersion => "PassengerVersion",
+            LayerAttributesKeys::Jvm => "Jvm",
+            LayerAttributesKeys::JvmVersion => "JvmVersion",
+            LayerAttributesKeys::JvmOptions => "JvmOptions",
+            LayerAttributesKeys::JavaAppServer => "JavaAppServer",
+            LayerAttributesKeys::JavaAppServerVersion => "JavaAppServerVersion",
+            LayerAttributesKeys::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LayerAttributesKeys {
+    fn from(value: &'a str) -> LayerAttributesKeys {
+        match value {
+            "EcsClusterArn" => LayerAttributesKeys::EcsClusterArn,
+            "EnableHaproxyStats" => LayerAttributesKeys::EnableHaproxyStats,
+            "HaproxyStatsUrl" => LayerAttributesKeys::HaproxyStatsUrl,
+            "HaproxyStatsUser" => LayerAttributesKeys::HaproxyStatsUser,
+            "HaproxyStatsPassword" => LayerAttributesKeys::HaproxyStatsPassword,
+            "HaproxyHealthCheckUrl" => LayerAttributesKeys::HaproxyHealthCheckUrl,
+            "HaproxyHealthCheckMethod" => LayerAttributesKeys::HaproxyHealthCheckMethod,
+            "MysqlRootPassword" => LayerAttributesKeys::MysqlRootPassword,
+            "MysqlRootPasswordUbiquitous" => LayerAttributesKeys::MysqlRootPasswordUbiquitous,
+            "GangliaUrl" => LayerAttributesKeys::GangliaUrl,
+            "GangliaUser" => LayerAttributesKeys::GangliaUser,
+            "GangliaPassword" => LayerAttributesKeys::GangliaPassword,
+            "MemcachedMemory" => LayerAttributesKeys::MemcachedMemory,
+            "NodejsVersion" => LayerAttributesKeys::NodejsVersion,
+            "RubyVersion" => LayerAttributesKeys::RubyVersion,
+            "RubygemsVersion" => LayerAttributesKeys::RubygemsVersion,
+            "ManageBundler" => LayerAttributesKeys::ManageBundler,
+            "BundlerVersion" => LayerAttributesKeys::BundlerVersion,
+            "RailsStack" => LayerAttributesKeys::RailsStack,
+            "PassengerVersion" => LayerAttributesKeys::PassengerVersion,
+            "Jvm" => LayerAttributesKeys::Jvm,
+            "JvmVersion" => LayerAttributesKeys::JvmVersion,
+            "JvmOptions" => LayerAttributesKeys::JvmOptions,
+            "JavaAppServer" => LayerAttributesKeys::JavaAppServer,
+            "JavaAppServerVersion" => LayerAttributesKeys::JavaAppServerVersion,
+            _ => LayerAttributesKeys::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LayerAttributesKeys {
+    fn from(value: String) -> LayerAttributesKeys {
+        LayerAttributesKeys::from(value.as_str())
+    }
+}
+
+impl From<LayerAttributesKeys> for String {
+    fn from(value: LayerAttributesKeys) -> String {
+        match value {
+            LayerAttributesKeys::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LayerAttributesKeys {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LayerAttributesKeys {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LayerAttributesKeys {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LayerAttributesKeys, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LayerAttributesKeys::from(value))
+    }
+}
+
+/// <p>The layer type. A stack cannot have more than one built-in layer of the same type. It can have any number of custom layers. Built-in layers are not available in Chef 12 stacks.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LayerType {
+    /// <p><code>aws-flow-ruby</code></p>
+    AwsFlowRuby,
+    /// <p><code>ecs-cluster</code></p>
+    EcsCluster,
+    /// <p><code>java-app</code></p>
+    JavaApp,
+    /// <p><code>lb</code></p>
+    Lb,
+    /// <p><code>web</code></p>
+    Web,
+    /// <p><code>php-app</code></p>
+    PhpApp,
+    /// <p><code>rails-app</code></p>
+    RailsApp,
+    /// <p><code>nodejs-app</code></p>
+    NodejsApp,
+    /// <p><code>memcached</code></p>
+    Memcached,
+    /// <p><code>db-master</code></p>
+    DbMaster,
+    /// <p><code>monitoring-master</code></p>
+    MonitoringMaster,
+    /// <p><code>custom</code></p>
+    Custom,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LayerType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LayerType::AwsFlowRuby => "aws-flow-ruby",
+            LayerType::EcsCluster => "ecs-cluster",
+            LayerType::JavaApp => "java-app",
+            LayerType::Lb => "lb",
+            LayerType::Web => "web",
+            LayerType::PhpApp => "php-app",
+            LayerType::RailsApp => "rails-app",
+            LayerType::NodejsApp => "nodejs-app",
+            LayerType::Memcached => "memcached",
+            LayerType::DbMaster => "db-master",
+            LayerType::MonitoringMaster => "monitoring-master",
+            LayerType::Custom => "custom",
+            LayerType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LayerType {
+    fn from(value: &'a str) -> LayerType {
+        match value {
+            "aws-flow-ruby" => LayerType::AwsFlowRuby,
+            "ecs-cluster" => LayerType::EcsCluster,
+            "java-app" => LayerType::JavaApp,
+            "lb" => LayerType::Lb,
+            "web" => LayerType::Web,
+            "php-app" => LayerType::PhpApp,
+            "rails-app" => LayerType::RailsApp,
+            "nodejs-app" => LayerType::NodejsApp,
+            "memcached" => LayerType::Memcached,
+            "db-master" => LayerType::DbMaster,
+            "monitoring-master" => LayerType::MonitoringMaster,
+            "custom" => LayerType::Custom,
+            _ => LayerType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LayerType {
+    fn from(value: String) -> LayerType {
+        LayerType::from(value.as_str())
+    }
+}
+
+impl From<LayerType> for String {
+    fn from(value: LayerType) -> String {
+        match value {
+            LayerType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LayerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LayerType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LayerType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LayerType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LayerType::from(value))
+    }
+}
+
 /// <p>Specifies the lifecycle event configuration</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LifecycleEventConfiguration {
@@ -2015,6 +5725,52 @@ pub struct ListTagsRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsRequest {
+    /// Returns a builder for `ListTagsRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsRequestBuilder {
+        ListTagsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsRequest`](struct.ListTagsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_arn: Option<String>,
+}
+
+impl ListTagsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListTagsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTagsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListTagsRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListTagsRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>ListTags</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2232,6 +5988,36 @@ pub struct RebootInstanceRequest {
     pub instance_id: String,
 }
 
+impl RebootInstanceRequest {
+    /// Returns a builder for `RebootInstanceRequest`, checking its required members when built.
+    pub fn builder() -> RebootInstanceRequestBuilder {
+        RebootInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RebootInstanceRequest`](struct.RebootInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct RebootInstanceRequestBuilder {
+    instance_id: Option<String>,
+}
+
+impl RebootInstanceRequestBuilder {
+    /// Sets the [`instance_id`](struct.RebootInstanceRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebootInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RebootInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(RebootInstanceRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
 /// <p>AWS OpsWorks Stacks supports five lifecycle events: <b>setup</b>, <b>configuration</b>, <b>deploy</b>, <b>undeploy</b>, and <b>shutdown</b>. For each layer, AWS OpsWorks Stacks runs a set of standard recipes for each event. In addition, you can provide custom recipes for any or all layers and events. AWS OpsWorks Stacks runs custom event recipes after the standard recipes. <code>LayerCustomRecipes</code> specifies the custom recipes for a particular layer to be run in response to each of the five events. </p> <p>To specify a recipe, use the cookbook's directory name in the repository followed by two colons and the recipe name, which is the recipe's file name without the .rb extension. For example: phpapp2::dbsetup specifies the dbsetup.rb recipe in the repository's phpapp2 folder.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Recipes {
@@ -2267,6 +6053,48 @@ pub struct RegisterEcsClusterRequest {
     pub stack_id: String,
 }
 
+impl RegisterEcsClusterRequest {
+    /// Returns a builder for `RegisterEcsClusterRequest`, checking its required members when built.
+    pub fn builder() -> RegisterEcsClusterRequestBuilder {
+        RegisterEcsClusterRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterEcsClusterRequest`](struct.RegisterEcsClusterRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterEcsClusterRequestBuilder {
+    ecs_cluster_arn: Option<String>,
+    stack_id: Option<String>,
+}
+
+impl RegisterEcsClusterRequestBuilder {
+    /// Sets the [`ecs_cluster_arn`](struct.RegisterEcsClusterRequest.html#structfield.ecs_cluster_arn) member.
+    pub fn ecs_cluster_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.ecs_cluster_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.RegisterEcsClusterRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterEcsClusterRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterEcsClusterRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterEcsClusterRequest {
+            ecs_cluster_arn: self
+                .ecs_cluster_arn
+                .ok_or(::rusoto_core::BuildError::missing("ecs_cluster_arn"))?,
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>RegisterEcsCluster</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2287,6 +6115,48 @@ pub struct RegisterElasticIpRequest {
     pub stack_id: String,
 }
 
+impl RegisterElasticIpRequest {
+    /// Returns a builder for `RegisterElasticIpRequest`, checking its required members when built.
+    pub fn builder() -> RegisterElasticIpRequestBuilder {
+        RegisterElasticIpRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterElasticIpRequest`](struct.RegisterElasticIpRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterElasticIpRequestBuilder {
+    elastic_ip: Option<String>,
+    stack_id: Option<String>,
+}
+
+impl RegisterElasticIpRequestBuilder {
+    /// Sets the [`elastic_ip`](struct.RegisterElasticIpRequest.html#structfield.elastic_ip) member.
+    pub fn elastic_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.elastic_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.RegisterElasticIpRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterElasticIpRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterElasticIpRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterElasticIpRequest {
+            elastic_ip: self
+                .elastic_ip
+                .ok_or(::rusoto_core::BuildError::missing("elastic_ip"))?,
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>RegisterElasticIp</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2328,6 +6198,86 @@ pub struct RegisterInstanceRequest {
     pub stack_id: String,
 }
 
+impl RegisterInstanceRequest {
+    /// Returns a builder for `RegisterInstanceRequest`, checking its required members when built.
+    pub fn builder() -> RegisterInstanceRequestBuilder {
+        RegisterInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterInstanceRequest`](struct.RegisterInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterInstanceRequestBuilder {
+    hostname: Option<String>,
+    instance_identity: Option<InstanceIdentity>,
+    private_ip: Option<String>,
+    public_ip: Option<String>,
+    rsa_public_key: Option<String>,
+    rsa_public_key_fingerprint: Option<String>,
+    stack_id: Option<String>,
+}
+
+impl RegisterInstanceRequestBuilder {
+    /// Sets the [`hostname`](struct.RegisterInstanceRequest.html#structfield.hostname) member.
+    pub fn hostname<T: Into<String>>(mut self, value: T) -> Self {
+        self.hostname = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_identity`](struct.RegisterInstanceRequest.html#structfield.instance_identity) member.
+    pub fn instance_identity<T: Into<InstanceIdentity>>(mut self, value: T) -> Self {
+        self.instance_identity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`private_ip`](struct.RegisterInstanceRequest.html#structfield.private_ip) member.
+    pub fn private_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.private_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`public_ip`](struct.RegisterInstanceRequest.html#structfield.public_ip) member.
+    pub fn public_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.public_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rsa_public_key`](struct.RegisterInstanceRequest.html#structfield.rsa_public_key) member.
+    pub fn rsa_public_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.rsa_public_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rsa_public_key_fingerprint`](struct.RegisterInstanceRequest.html#structfield.rsa_public_key_fingerprint) member.
+    pub fn rsa_public_key_fingerprint<T: Into<String>>(mut self, value: T) -> Self {
+        self.rsa_public_key_fingerprint = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.RegisterInstanceRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterInstanceRequest {
+            hostname: self.hostname,
+            instance_identity: self.instance_identity,
+            private_ip: self.private_ip,
+            public_ip: self.public_ip,
+            rsa_public_key: self.rsa_public_key,
+            rsa_public_key_fingerprint: self.rsa_public_key_fingerprint,
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>RegisterInstanceResult</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2354,6 +6304,68 @@ pub struct RegisterRdsDbInstanceRequest {
     pub stack_id: String,
 }
 
+impl RegisterRdsDbInstanceRequest {
+    /// Returns a builder for `RegisterRdsDbInstanceRequest`, checking its required members when built.
+    pub fn builder() -> RegisterRdsDbInstanceRequestBuilder {
+        RegisterRdsDbInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterRdsDbInstanceRequest`](struct.RegisterRdsDbInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterRdsDbInstanceRequestBuilder {
+    db_password: Option<String>,
+    db_user: Option<String>,
+    rds_db_instance_arn: Option<String>,
+    stack_id: Option<String>,
+}
+
+impl RegisterRdsDbInstanceRequestBuilder {
+    /// Sets the [`db_password`](struct.RegisterRdsDbInstanceRequest.html#structfield.db_password) member.
+    pub fn db_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_user`](struct.RegisterRdsDbInstanceRequest.html#structfield.db_user) member.
+    pub fn db_user<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_user = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rds_db_instance_arn`](struct.RegisterRdsDbInstanceRequest.html#structfield.rds_db_instance_arn) member.
+    pub fn rds_db_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.rds_db_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.RegisterRdsDbInstanceRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterRdsDbInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RegisterRdsDbInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterRdsDbInstanceRequest {
+            db_password: self
+                .db_password
+                .ok_or(::rusoto_core::BuildError::missing("db_password"))?,
+            db_user: self
+                .db_user
+                .ok_or(::rusoto_core::BuildError::missing("db_user"))?,
+            rds_db_instance_arn: self
+                .rds_db_instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("rds_db_instance_arn"))?,
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RegisterVolumeRequest {
     /// <p>The Amazon EBS volume ID.</p>
@@ -2365,6 +6377,44 @@ pub struct RegisterVolumeRequest {
     pub stack_id: String,
 }
 
+impl RegisterVolumeRequest {
+    /// Returns a builder for `RegisterVolumeRequest`, checking its required members when built.
+    pub fn builder() -> RegisterVolumeRequestBuilder {
+        RegisterVolumeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RegisterVolumeRequest`](struct.RegisterVolumeRequest.html).
+#[derive(Default, Debug)]
+pub struct RegisterVolumeRequestBuilder {
+    ec_2_volume_id: Option<String>,
+    stack_id: Option<String>,
+}
+
+impl RegisterVolumeRequestBuilder {
+    /// Sets the [`ec_2_volume_id`](struct.RegisterVolumeRequest.html#structfield.ec_2_volume_id) member.
+    pub fn ec_2_volume_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec_2_volume_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.RegisterVolumeRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RegisterVolumeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RegisterVolumeRequest, ::rusoto_core::BuildError> {
+        Ok(RegisterVolumeRequest {
+            ec_2_volume_id: self.ec_2_volume_id,
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 /// <p>Contains the response to a <code>RegisterVolume</code> request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2393,6 +6443,78 @@ pub struct ReportedOs {
     pub version: Option<String>,
 }
 
+/// <p>The default root device type. This value is used by default for all instances in the cloned stack, but you can override it when you create an instance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RootDeviceType {
+    /// <p><code>ebs</code></p>
+    Ebs,
+    /// <p><code>instance-store</code></p>
+    InstanceStore,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl RootDeviceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RootDeviceType::Ebs => "ebs",
+            RootDeviceType::InstanceStore => "instance-store",
+            RootDeviceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for RootDeviceType {
+    fn from(value: &'a str) -> RootDeviceType {
+        match value {
+            "ebs" => RootDeviceType::Ebs,
+            "instance-store" => RootDeviceType::InstanceStore,
+            _ => RootDeviceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for RootDeviceType {
+    fn from(value: String) -> RootDeviceType {
+        RootDeviceType::from(value.as_str())
+    }
+}
+
+impl From<RootDeviceType> for String {
+    fn from(value: RootDeviceType) -> String {
+        match value {
+            RootDeviceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for RootDeviceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for RootDeviceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RootDeviceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<RootDeviceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(RootDeviceType::from(value))
+    }
+}
+
 /// <p>Describes a user's SSH information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2464,6 +6586,62 @@ pub struct SetLoadBasedAutoScalingRequest {
     pub up_scaling: Option<AutoScalingThresholds>,
 }
 
+impl SetLoadBasedAutoScalingRequest {
+    /// Returns a builder for `SetLoadBasedAutoScalingRequest`, checking its required members when built.
+    pub fn builder() -> SetLoadBasedAutoScalingRequestBuilder {
+        SetLoadBasedAutoScalingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetLoadBasedAutoScalingRequest`](struct.SetLoadBasedAutoScalingRequest.html).
+#[derive(Default, Debug)]
+pub struct SetLoadBasedAutoScalingRequestBuilder {
+    down_scaling: Option<AutoScalingThresholds>,
+    enable: Option<bool>,
+    layer_id: Option<String>,
+    up_scaling: Option<AutoScalingThresholds>,
+}
+
+impl SetLoadBasedAutoScalingRequestBuilder {
+    /// Sets the [`down_scaling`](struct.SetLoadBasedAutoScalingRequest.html#structfield.down_scaling) member.
+    pub fn down_scaling<T: Into<AutoScalingThresholds>>(mut self, value: T) -> Self {
+        self.down_scaling = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable`](struct.SetLoadBasedAutoScalingRequest.html#structfield.enable) member.
+    pub fn enable<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layer_id`](struct.SetLoadBasedAutoScalingRequest.html#structfield.layer_id) member.
+    pub fn layer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`up_scaling`](struct.SetLoadBasedAutoScalingRequest.html#structfield.up_scaling) member.
+    pub fn up_scaling<T: Into<AutoScalingThresholds>>(mut self, value: T) -> Self {
+        self.up_scaling = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetLoadBasedAutoScalingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetLoadBasedAutoScalingRequest, ::rusoto_core::BuildError> {
+        Ok(SetLoadBasedAutoScalingRequest {
+            down_scaling: self.down_scaling,
+            enable: self.enable,
+            layer_id: self
+                .layer_id
+                .ok_or(::rusoto_core::BuildError::missing("layer_id"))?,
+            up_scaling: self.up_scaling,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SetPermissionRequest {
     /// <p>The user is allowed to use SSH to communicate with the instance.</p>
@@ -2486,6 +6664,70 @@ pub struct SetPermissionRequest {
     pub stack_id: String,
 }
 
+impl SetPermissionRequest {
+    /// Returns a builder for `SetPermissionRequest`, checking its required members when built.
+    pub fn builder() -> SetPermissionRequestBuilder {
+        SetPermissionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetPermissionRequest`](struct.SetPermissionRequest.html).
+#[derive(Default, Debug)]
+pub struct SetPermissionRequestBuilder {
+    allow_ssh: Option<bool>,
+    allow_sudo: Option<bool>,
+    iam_user_arn: Option<String>,
+    level: Option<String>,
+    stack_id: Option<String>,
+}
+
+impl SetPermissionRequestBuilder {
+    /// Sets the [`allow_ssh`](struct.SetPermissionRequest.html#structfield.allow_ssh) member.
+    pub fn allow_ssh<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_ssh = Some(value.into());
+        self
+    }
+
+    /// Sets the [`allow_sudo`](struct.SetPermissionRequest.html#structfield.allow_sudo) member.
+    pub fn allow_sudo<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_sudo = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_user_arn`](struct.SetPermissionRequest.html#structfield.iam_user_arn) member.
+    pub fn iam_user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_user_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`level`](struct.SetPermissionRequest.html#structfield.level) member.
+    pub fn level<T: Into<String>>(mut self, value: T) -> Self {
+        self.level = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.SetPermissionRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetPermissionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SetPermissionRequest, ::rusoto_core::BuildError> {
+        Ok(SetPermissionRequest {
+            allow_ssh: self.allow_ssh,
+            allow_sudo: self.allow_sudo,
+            iam_user_arn: self
+                .iam_user_arn
+                .ok_or(::rusoto_core::BuildError::missing("iam_user_arn"))?,
+            level: self.level,
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct SetTimeBasedAutoScalingRequest {
     /// <p>An <code>AutoScalingSchedule</code> with the instance schedule.</p>
@@ -2497,17 +6739,57 @@ pub struct SetTimeBasedAutoScalingRequest {
     pub instance_id: String,
 }
 
-/// <p>The Shutdown event configuration.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ShutdownEventConfiguration {
-    /// <p>Whether to enable Elastic Load Balancing connection draining. For more information, see <a href="https://docs.aws.amazon.com/ElasticLoadBalancing/latest/DeveloperGuide/TerminologyandKeyConcepts.html#conn-drain">Connection Draining</a> </p>
-    #[serde(rename = "DelayUntilElbConnectionsDrained")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub delay_until_elb_connections_drained: Option<bool>,
-    /// <p>The time, in seconds, that AWS OpsWorks Stacks will wait after triggering a Shutdown event before shutting down an instance.</p>
-    #[serde(rename = "ExecutionTimeout")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub execution_timeout: Option<i64>,
+impl SetTimeBasedAutoScalingRequest {
+    /// Returns a builder for `SetTimeBasedAutoScalingRequest`, checking its required members when built.
+    pub fn builder() -> SetTimeBasedAutoScalingRequestBuilder {
+        SetTimeBasedAutoScalingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SetTimeBasedAutoScalingRequest`](struct.SetTimeBasedAutoScalingRequest.html).
+#[derive(Default, Debug)]
+pub struct SetTimeBasedAutoScalingRequestBuilder {
+    auto_scaling_schedule: Option<WeeklyAutoScalingSchedule>,
+    instance_id: Option<String>,
+}
+
+impl SetTimeBasedAutoScalingRequestBuilder {
+    /// Sets the [`auto_scaling_schedule`](struct.SetTimeBasedAutoScalingRequest.html#structfield.auto_scaling_schedule) member.
+    pub fn auto_scaling_schedule<T: Into<WeeklyAutoScalingSchedule>>(mut self, value: T) -> Self {
+        self.auto_scaling_schedule = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.SetTimeBasedAutoScalingRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SetTimeBasedAutoScalingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SetTimeBasedAutoScalingRequest, ::rusoto_core::BuildError> {
+        Ok(SetTimeBasedAutoScalingRequest {
+            auto_scaling_schedule: self.auto_scaling_schedule,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
+/// <p>The Shutdown event configuration.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShutdownEventConfiguration {
+    /// <p>Whether to enable Elastic Load Balancing connection draining. For more information, see <a href="https://docs.aws.amazon.com/ElasticLoadBalancing/latest/DeveloperGuide/TerminologyandKeyConcepts.html#conn-drain">Connection Draining</a> </p>
+    #[serde(rename = "DelayUntilElbConnectionsDrained")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay_until_elb_connections_drained: Option<bool>,
+    /// <p>The time, in seconds, that AWS OpsWorks Stacks will wait after triggering a Shutdown event before shutting down an instance.</p>
+    #[serde(rename = "ExecutionTimeout")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution_timeout: Option<i64>,
 }
 
 /// <p>Contains the information required to retrieve an app or cookbook from a repository. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingapps-creating.html">Creating Apps</a> or <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingcookbook.html">Custom Recipes and Cookbooks</a>.</p>
@@ -2528,7 +6810,7 @@ pub struct Source {
     /// <p>The repository type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<SourceType>,
     /// <p>The source URL. The following is an example of an Amazon S3 source URL: <code>https://s3.amazonaws.com/opsworks-demo-bucket/opsworks_cookbook_demo.tar.gz</code>.</p>
     #[serde(rename = "Url")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2539,6 +6821,86 @@ pub struct Source {
     pub username: Option<String>,
 }
 
+/// <p>The repository type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceType {
+    /// <p><code>git</code></p>
+    Git,
+    /// <p><code>svn</code></p>
+    Svn,
+    /// <p><code>archive</code></p>
+    Archive,
+    /// <p><code>s3</code></p>
+    S3,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::Git => "git",
+            SourceType::Svn => "svn",
+            SourceType::Archive => "archive",
+            SourceType::S3 => "s3",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "git" => SourceType::Git,
+            "svn" => SourceType::Svn,
+            "archive" => SourceType::Archive,
+            "s3" => SourceType::S3,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        SourceType::from(value.as_str())
+    }
+}
+
+impl From<SourceType> for String {
+    fn from(value: SourceType) -> String {
+        match value {
+            SourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for SourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SourceType::from(value))
+    }
+}
+
 /// <p>Describes an app's SSL configuration.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SslConfiguration {
@@ -2569,7 +6931,7 @@ pub struct Stack {
     /// <p>The stack's attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<StackAttributesKeys, String>>,
     /// <p>A <code>ChefConfiguration</code> object that specifies whether to enable Berkshelf and the Berkshelf version. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-creating.html">Create a New Stack</a>.</p>
     #[serde(rename = "ChefConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2605,7 +6967,7 @@ pub struct Stack {
     /// <p>The default root device type. This value is used by default for all instances in the stack, but you can override it when you create an instance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
     #[serde(rename = "DefaultRootDeviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_root_device_type: Option<String>,
+    pub default_root_device_type: Option<RootDeviceType>,
     /// <p>A default Amazon EC2 key pair for the stack's instances. You can override this value when you create or update an instance.</p>
     #[serde(rename = "DefaultSshKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2648,6 +7010,74 @@ pub struct Stack {
     pub vpc_id: Option<String>,
 }
 
+/// <p>A list of stack attributes and values as key/value pairs to be added to the cloned stack.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StackAttributesKeys {
+    /// <p><code>Color</code></p>
+    Color,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StackAttributesKeys {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StackAttributesKeys::Color => "Color",
+            StackAttributesKeys::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StackAttributesKeys {
+    fn from(value: &'a str) -> StackAttributesKeys {
+        match value {
+            "Color" => StackAttributesKeys::Color,
+            _ => StackAttributesKeys::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StackAttributesKeys {
+    fn from(value: String) -> StackAttributesKeys {
+        StackAttributesKeys::from(value.as_str())
+    }
+}
+
+impl From<StackAttributesKeys> for String {
+    fn from(value: StackAttributesKeys) -> String {
+        match value {
+            StackAttributesKeys::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StackAttributesKeys {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StackAttributesKeys {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StackAttributesKeys {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StackAttributesKeys, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StackAttributesKeys::from(value))
+    }
+}
+
 /// <p>Describes the configuration manager.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StackConfigurationManager {
@@ -2698,6 +7128,36 @@ pub struct StartInstanceRequest {
     pub instance_id: String,
 }
 
+impl StartInstanceRequest {
+    /// Returns a builder for `StartInstanceRequest`, checking its required members when built.
+    pub fn builder() -> StartInstanceRequestBuilder {
+        StartInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartInstanceRequest`](struct.StartInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct StartInstanceRequestBuilder {
+    instance_id: Option<String>,
+}
+
+impl StartInstanceRequestBuilder {
+    /// Sets the [`instance_id`](struct.StartInstanceRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(StartInstanceRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartStackRequest {
     /// <p>The stack ID.</p>
@@ -2705,6 +7165,36 @@ pub struct StartStackRequest {
     pub stack_id: String,
 }
 
+impl StartStackRequest {
+    /// Returns a builder for `StartStackRequest`, checking its required members when built.
+    pub fn builder() -> StartStackRequestBuilder {
+        StartStackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartStackRequest`](struct.StartStackRequest.html).
+#[derive(Default, Debug)]
+pub struct StartStackRequestBuilder {
+    stack_id: Option<String>,
+}
+
+impl StartStackRequestBuilder {
+    /// Sets the [`stack_id`](struct.StartStackRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartStackRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartStackRequest, ::rusoto_core::BuildError> {
+        Ok(StartStackRequest {
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StopInstanceRequest {
     /// <p>Specifies whether to force an instance to stop. If the instance's root device type is <code>ebs</code>, or EBS-backed, adding the <code>Force</code> parameter to the <code>StopInstances</code> API call disassociates the AWS OpsWorks Stacks instance from EC2, and forces deletion of <i>only</i> the OpsWorks Stacks instance. You must also delete the formerly-associated instance in EC2 after troubleshooting and replacing the AWS OpsWorks Stacks instance with a new one.</p>
@@ -2716,6 +7206,44 @@ pub struct StopInstanceRequest {
     pub instance_id: String,
 }
 
+impl StopInstanceRequest {
+    /// Returns a builder for `StopInstanceRequest`, checking its required members when built.
+    pub fn builder() -> StopInstanceRequestBuilder {
+        StopInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopInstanceRequest`](struct.StopInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct StopInstanceRequestBuilder {
+    force: Option<bool>,
+    instance_id: Option<String>,
+}
+
+impl StopInstanceRequestBuilder {
+    /// Sets the [`force`](struct.StopInstanceRequest.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.StopInstanceRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(StopInstanceRequest {
+            force: self.force,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StopStackRequest {
     /// <p>The stack ID.</p>
@@ -2723,6 +7251,36 @@ pub struct StopStackRequest {
     pub stack_id: String,
 }
 
+impl StopStackRequest {
+    /// Returns a builder for `StopStackRequest`, checking its required members when built.
+    pub fn builder() -> StopStackRequestBuilder {
+        StopStackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopStackRequest`](struct.StopStackRequest.html).
+#[derive(Default, Debug)]
+pub struct StopStackRequestBuilder {
+    stack_id: Option<String>,
+}
+
+impl StopStackRequestBuilder {
+    /// Sets the [`stack_id`](struct.StopStackRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopStackRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopStackRequest, ::rusoto_core::BuildError> {
+        Ok(StopStackRequest {
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TagResourceRequest {
     /// <p>The stack or layer's Amazon Resource Number (ARN).</p>
@@ -2733,6 +7291,46 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// <p>Contains the data needed by RDP clients such as the Microsoft Remote Desktop Connection to log in to the instance.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -2776,6 +7374,38 @@ pub struct UnassignInstanceRequest {
     pub instance_id: String,
 }
 
+impl UnassignInstanceRequest {
+    /// Returns a builder for `UnassignInstanceRequest`, checking its required members when built.
+    pub fn builder() -> UnassignInstanceRequestBuilder {
+        UnassignInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UnassignInstanceRequest`](struct.UnassignInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct UnassignInstanceRequestBuilder {
+    instance_id: Option<String>,
+}
+
+impl UnassignInstanceRequestBuilder {
+    /// Sets the [`instance_id`](struct.UnassignInstanceRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UnassignInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UnassignInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(UnassignInstanceRequest {
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UnassignVolumeRequest {
     /// <p>The volume ID.</p>
@@ -2783,6 +7413,36 @@ pub struct UnassignVolumeRequest {
     pub volume_id: String,
 }
 
+impl UnassignVolumeRequest {
+    /// Returns a builder for `UnassignVolumeRequest`, checking its required members when built.
+    pub fn builder() -> UnassignVolumeRequestBuilder {
+        UnassignVolumeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UnassignVolumeRequest`](struct.UnassignVolumeRequest.html).
+#[derive(Default, Debug)]
+pub struct UnassignVolumeRequestBuilder {
+    volume_id: Option<String>,
+}
+
+impl UnassignVolumeRequestBuilder {
+    /// Sets the [`volume_id`](struct.UnassignVolumeRequest.html#structfield.volume_id) member.
+    pub fn volume_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UnassignVolumeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UnassignVolumeRequest, ::rusoto_core::BuildError> {
+        Ok(UnassignVolumeRequest {
+            volume_id: self
+                .volume_id
+                .ok_or(::rusoto_core::BuildError::missing("volume_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
     /// <p>The stack or layer's Amazon Resource Number (ARN).</p>
@@ -2793,6 +7453,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateAppRequest {
     /// <p>The app ID.</p>
@@ -2805,7 +7505,7 @@ pub struct UpdateAppRequest {
     /// <p>One or more user-defined key/value pairs to be added to the stack attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<AppAttributesKeys, String>>,
     /// <p>The app's data sources.</p>
     #[serde(rename = "DataSources")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2837,7 +7537,120 @@ pub struct UpdateAppRequest {
     /// <p>The app type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<AppType>,
+}
+
+impl UpdateAppRequest {
+    /// Returns a builder for `UpdateAppRequest`, checking its required members when built.
+    pub fn builder() -> UpdateAppRequestBuilder {
+        UpdateAppRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateAppRequest`](struct.UpdateAppRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateAppRequestBuilder {
+    app_id: Option<String>,
+    app_source: Option<Source>,
+    attributes: Option<::std::collections::HashMap<AppAttributesKeys, String>>,
+    data_sources: Option<Vec<DataSource>>,
+    description: Option<String>,
+    domains: Option<Vec<String>>,
+    enable_ssl: Option<bool>,
+    environment: Option<Vec<EnvironmentVariable>>,
+    name: Option<String>,
+    ssl_configuration: Option<SslConfiguration>,
+    type_: Option<AppType>,
+}
+
+impl UpdateAppRequestBuilder {
+    /// Sets the [`app_id`](struct.UpdateAppRequest.html#structfield.app_id) member.
+    pub fn app_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.app_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`app_source`](struct.UpdateAppRequest.html#structfield.app_source) member.
+    pub fn app_source<T: Into<Source>>(mut self, value: T) -> Self {
+        self.app_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attributes`](struct.UpdateAppRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<AppAttributesKeys, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`data_sources`](struct.UpdateAppRequest.html#structfield.data_sources) member.
+    pub fn data_sources<T: Into<Vec<DataSource>>>(mut self, value: T) -> Self {
+        self.data_sources = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdateAppRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domains`](struct.UpdateAppRequest.html#structfield.domains) member.
+    pub fn domains<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.domains = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_ssl`](struct.UpdateAppRequest.html#structfield.enable_ssl) member.
+    pub fn enable_ssl<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_ssl = Some(value.into());
+        self
+    }
+
+    /// Sets the [`environment`](struct.UpdateAppRequest.html#structfield.environment) member.
+    pub fn environment<T: Into<Vec<EnvironmentVariable>>>(mut self, value: T) -> Self {
+        self.environment = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateAppRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssl_configuration`](struct.UpdateAppRequest.html#structfield.ssl_configuration) member.
+    pub fn ssl_configuration<T: Into<SslConfiguration>>(mut self, value: T) -> Self {
+        self.ssl_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.UpdateAppRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<AppType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateAppRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateAppRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateAppRequest {
+            app_id: self
+                .app_id
+                .ok_or(::rusoto_core::BuildError::missing("app_id"))?,
+            app_source: self.app_source,
+            attributes: self.attributes,
+            data_sources: self.data_sources,
+            description: self.description,
+            domains: self.domains,
+            enable_ssl: self.enable_ssl,
+            environment: self.environment,
+            name: self.name,
+            ssl_configuration: self.ssl_configuration,
+            type_: self.type_,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -2851,6 +7664,44 @@ pub struct UpdateElasticIpRequest {
     pub name: Option<String>,
 }
 
+impl UpdateElasticIpRequest {
+    /// Returns a builder for `UpdateElasticIpRequest`, checking its required members when built.
+    pub fn builder() -> UpdateElasticIpRequestBuilder {
+        UpdateElasticIpRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateElasticIpRequest`](struct.UpdateElasticIpRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateElasticIpRequestBuilder {
+    elastic_ip: Option<String>,
+    name: Option<String>,
+}
+
+impl UpdateElasticIpRequestBuilder {
+    /// Sets the [`elastic_ip`](struct.UpdateElasticIpRequest.html#structfield.elastic_ip) member.
+    pub fn elastic_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.elastic_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateElasticIpRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateElasticIpRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateElasticIpRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateElasticIpRequest {
+            elastic_ip: self
+                .elastic_ip
+                .ok_or(::rusoto_core::BuildError::missing("elastic_ip"))?,
+            name: self.name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateInstanceRequest {
     /// <p>The default AWS OpsWorks Stacks agent version. You have the following options:</p> <ul> <li> <p> <code>INHERIT</code> - Use the stack's default agent version setting.</p> </li> <li> <p> <i>version_number</i> - Use the specified agent version. This value overrides the stack's default setting. To update the agent version, you must edit the instance configuration and specify a new version. AWS OpsWorks Stacks then automatically installs that version on the instance.</p> </li> </ul> <p>The default setting is <code>INHERIT</code>. To specify an agent version, you must use the complete version number, not the abbreviated number shown on the console. For a list of available agent version numbers, call <a>DescribeAgentVersions</a>.</p> <p>AgentVersion cannot be set to Chef 12.2.</p>
@@ -2864,11 +7715,11 @@ pub struct UpdateInstanceRequest {
     /// <p>The instance architecture. Instance types do not necessarily support both architectures. For a list of the architectures that are supported by the different instance types, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/instance-types.html">Instance Families and Types</a>.</p>
     #[serde(rename = "Architecture")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub architecture: Option<String>,
+    pub architecture: Option<Architecture>,
     /// <p>For load-based or time-based instances, the type. Windows stacks can use only time-based instances.</p>
     #[serde(rename = "AutoScalingType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_scaling_type: Option<String>,
+    pub auto_scaling_type: Option<AutoScalingType>,
     /// <p>This property cannot be updated.</p>
     #[serde(rename = "EbsOptimized")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2902,38 +7753,156 @@ pub struct UpdateInstanceRequest {
     pub ssh_key_name: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct UpdateLayerRequest {
-    /// <p>One or more user-defined key/value pairs to be added to the stack attributes.</p>
-    #[serde(rename = "Attributes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
-    /// <p>Whether to automatically assign an <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP address</a> to the layer's instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-edit.html">How to Edit a Layer</a>.</p>
-    #[serde(rename = "AutoAssignElasticIps")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_assign_elastic_ips: Option<bool>,
-    /// <p>For stacks that are running in a VPC, whether to automatically assign a public IP address to the layer's instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-edit.html">How to Edit a Layer</a>.</p>
-    #[serde(rename = "AutoAssignPublicIps")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_assign_public_ips: Option<bool>,
-    /// <p>Specifies CloudWatch Logs configuration options for the layer. For more information, see <a>CloudWatchLogsLogStream</a>.</p>
-    #[serde(rename = "CloudWatchLogsConfiguration")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cloud_watch_logs_configuration: Option<CloudWatchLogsConfiguration>,
-    /// <p>The ARN of an IAM profile to be used for all of the layer's EC2 instances. For more information about IAM ARNs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">Using Identifiers</a>.</p>
-    #[serde(rename = "CustomInstanceProfileArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_instance_profile_arn: Option<String>,
-    /// <p>A JSON-formatted string containing custom stack configuration and deployment attributes to be installed on the layer's instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingcookbook-json-override.html"> Using Custom JSON</a>. </p>
-    #[serde(rename = "CustomJson")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_json: Option<String>,
-    /// <p>A <code>LayerCustomRecipes</code> object that specifies the layer's custom recipes.</p>
-    #[serde(rename = "CustomRecipes")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub custom_recipes: Option<Recipes>,
-    /// <p>An array containing the layer's custom security group IDs.</p>
-    #[serde(rename = "CustomSecurityGroupIds")]
+impl UpdateInstanceRequest {
+    /// Returns a builder for `UpdateInstanceRequest`, checking its required members when built.
+    pub fn builder() -> UpdateInstanceRequestBuilder {
+        UpdateInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateInstanceRequest`](struct.UpdateInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateInstanceRequestBuilder {
+    agent_version: Option<String>,
+    ami_id: Option<String>,
+    architecture: Option<Architecture>,
+    auto_scaling_type: Option<AutoScalingType>,
+    ebs_optimized: Option<bool>,
+    hostname: Option<String>,
+    install_updates_on_boot: Option<bool>,
+    instance_id: Option<String>,
+    instance_type: Option<String>,
+    layer_ids: Option<Vec<String>>,
+    os: Option<String>,
+    ssh_key_name: Option<String>,
+}
+
+impl UpdateInstanceRequestBuilder {
+    /// Sets the [`agent_version`](struct.UpdateInstanceRequest.html#structfield.agent_version) member.
+    pub fn agent_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.agent_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ami_id`](struct.UpdateInstanceRequest.html#structfield.ami_id) member.
+    pub fn ami_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ami_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`architecture`](struct.UpdateInstanceRequest.html#structfield.architecture) member.
+    pub fn architecture<T: Into<Architecture>>(mut self, value: T) -> Self {
+        self.architecture = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_scaling_type`](struct.UpdateInstanceRequest.html#structfield.auto_scaling_type) member.
+    pub fn auto_scaling_type<T: Into<AutoScalingType>>(mut self, value: T) -> Self {
+        self.auto_scaling_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ebs_optimized`](struct.UpdateInstanceRequest.html#structfield.ebs_optimized) member.
+    pub fn ebs_optimized<T: Into<bool>>(mut self, value: T) -> Self {
+        self.ebs_optimized = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hostname`](struct.UpdateInstanceRequest.html#structfield.hostname) member.
+    pub fn hostname<T: Into<String>>(mut self, value: T) -> Self {
+        self.hostname = Some(value.into());
+        self
+    }
+
+    /// Sets the [`install_updates_on_boot`](struct.UpdateInstanceRequest.html#structfield.install_updates_on_boot) member.
+    pub fn install_updates_on_boot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.install_updates_on_boot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_id`](struct.UpdateInstanceRequest.html#structfield.instance_id) member.
+    pub fn instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_type`](struct.UpdateInstanceRequest.html#structfield.instance_type) member.
+    pub fn instance_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layer_ids`](struct.UpdateInstanceRequest.html#structfield.layer_ids) member.
+    pub fn layer_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.layer_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`os`](struct.UpdateInstanceRequest.html#structfield.os) member.
+    pub fn os<T: Into<String>>(mut self, value: T) -> Self {
+        self.os = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_key_name`](struct.UpdateInstanceRequest.html#structfield.ssh_key_name) member.
+    pub fn ssh_key_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_key_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateInstanceRequest {
+            agent_version: self.agent_version,
+            ami_id: self.ami_id,
+            architecture: self.architecture,
+            auto_scaling_type: self.auto_scaling_type,
+            ebs_optimized: self.ebs_optimized,
+            hostname: self.hostname,
+            install_updates_on_boot: self.install_updates_on_boot,
+            instance_id: self
+                .instance_id
+                .ok_or(::rusoto_core::BuildError::missing("instance_id"))?,
+            instance_type: self.instance_type,
+            layer_ids: self.layer_ids,
+            os: self.os,
+            ssh_key_name: self.ssh_key_name,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UpdateLayerRequest {
+    /// <p>One or more user-defined key/value pairs to be added to the stack attributes.</p>
+    #[serde(rename = "Attributes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<::std::collections::HashMap<LayerAttributesKeys, String>>,
+    /// <p>Whether to automatically assign an <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/elastic-ip-addresses-eip.html">Elastic IP address</a> to the layer's instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-edit.html">How to Edit a Layer</a>.</p>
+    #[serde(rename = "AutoAssignElasticIps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_assign_elastic_ips: Option<bool>,
+    /// <p>For stacks that are running in a VPC, whether to automatically assign a public IP address to the layer's instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinglayers-basics-edit.html">How to Edit a Layer</a>.</p>
+    #[serde(rename = "AutoAssignPublicIps")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_assign_public_ips: Option<bool>,
+    /// <p>Specifies CloudWatch Logs configuration options for the layer. For more information, see <a>CloudWatchLogsLogStream</a>.</p>
+    #[serde(rename = "CloudWatchLogsConfiguration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud_watch_logs_configuration: Option<CloudWatchLogsConfiguration>,
+    /// <p>The ARN of an IAM profile to be used for all of the layer's EC2 instances. For more information about IAM ARNs, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/Using_Identifiers.html">Using Identifiers</a>.</p>
+    #[serde(rename = "CustomInstanceProfileArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_instance_profile_arn: Option<String>,
+    /// <p>A JSON-formatted string containing custom stack configuration and deployment attributes to be installed on the layer's instances. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingcookbook-json-override.html"> Using Custom JSON</a>. </p>
+    #[serde(rename = "CustomJson")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_json: Option<String>,
+    /// <p>A <code>LayerCustomRecipes</code> object that specifies the layer's custom recipes.</p>
+    #[serde(rename = "CustomRecipes")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_recipes: Option<Recipes>,
+    /// <p>An array containing the layer's custom security group IDs.</p>
+    #[serde(rename = "CustomSecurityGroupIds")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_security_group_ids: Option<Vec<String>>,
     /// <p>Whether to disable auto healing for the layer.</p>
@@ -2973,6 +7942,173 @@ pub struct UpdateLayerRequest {
     pub volume_configurations: Option<Vec<VolumeConfiguration>>,
 }
 
+impl UpdateLayerRequest {
+    /// Returns a builder for `UpdateLayerRequest`, checking its required members when built.
+    pub fn builder() -> UpdateLayerRequestBuilder {
+        UpdateLayerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateLayerRequest`](struct.UpdateLayerRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateLayerRequestBuilder {
+    attributes: Option<::std::collections::HashMap<LayerAttributesKeys, String>>,
+    auto_assign_elastic_ips: Option<bool>,
+    auto_assign_public_ips: Option<bool>,
+    cloud_watch_logs_configuration: Option<CloudWatchLogsConfiguration>,
+    custom_instance_profile_arn: Option<String>,
+    custom_json: Option<String>,
+    custom_recipes: Option<Recipes>,
+    custom_security_group_ids: Option<Vec<String>>,
+    enable_auto_healing: Option<bool>,
+    install_updates_on_boot: Option<bool>,
+    layer_id: Option<String>,
+    lifecycle_event_configuration: Option<LifecycleEventConfiguration>,
+    name: Option<String>,
+    packages: Option<Vec<String>>,
+    shortname: Option<String>,
+    use_ebs_optimized_instances: Option<bool>,
+    volume_configurations: Option<Vec<VolumeConfiguration>>,
+}
+
+impl UpdateLayerRequestBuilder {
+    /// Sets the [`attributes`](struct.UpdateLayerRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<LayerAttributesKeys, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_assign_elastic_ips`](struct.UpdateLayerRequest.html#structfield.auto_assign_elastic_ips) member.
+    pub fn auto_assign_elastic_ips<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_assign_elastic_ips = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_assign_public_ips`](struct.UpdateLayerRequest.html#structfield.auto_assign_public_ips) member.
+    pub fn auto_assign_public_ips<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_assign_public_ips = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cloud_watch_logs_configuration`](struct.UpdateLayerRequest.html#structfield.cloud_watch_logs_configuration) member.
+    pub fn cloud_watch_logs_configuration<T: Into<CloudWatchLogsConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cloud_watch_logs_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_instance_profile_arn`](struct.UpdateLayerRequest.html#structfield.custom_instance_profile_arn) member.
+    pub fn custom_instance_profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_instance_profile_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_json`](struct.UpdateLayerRequest.html#structfield.custom_json) member.
+    pub fn custom_json<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_json = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_recipes`](struct.UpdateLayerRequest.html#structfield.custom_recipes) member.
+    pub fn custom_recipes<T: Into<Recipes>>(mut self, value: T) -> Self {
+        self.custom_recipes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_security_group_ids`](struct.UpdateLayerRequest.html#structfield.custom_security_group_ids) member.
+    pub fn custom_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.custom_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_auto_healing`](struct.UpdateLayerRequest.html#structfield.enable_auto_healing) member.
+    pub fn enable_auto_healing<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_auto_healing = Some(value.into());
+        self
+    }
+
+    /// Sets the [`install_updates_on_boot`](struct.UpdateLayerRequest.html#structfield.install_updates_on_boot) member.
+    pub fn install_updates_on_boot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.install_updates_on_boot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`layer_id`](struct.UpdateLayerRequest.html#structfield.layer_id) member.
+    pub fn layer_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.layer_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lifecycle_event_configuration`](struct.UpdateLayerRequest.html#structfield.lifecycle_event_configuration) member.
+    pub fn lifecycle_event_configuration<T: Into<LifecycleEventConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.lifecycle_event_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateLayerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`packages`](struct.UpdateLayerRequest.html#structfield.packages) member.
+    pub fn packages<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.packages = Some(value.into());
+        self
+    }
+
+    /// Sets the [`shortname`](struct.UpdateLayerRequest.html#structfield.shortname) member.
+    pub fn shortname<T: Into<String>>(mut self, value: T) -> Self {
+        self.shortname = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_ebs_optimized_instances`](struct.UpdateLayerRequest.html#structfield.use_ebs_optimized_instances) member.
+    pub fn use_ebs_optimized_instances<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_ebs_optimized_instances = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_configurations`](struct.UpdateLayerRequest.html#structfield.volume_configurations) member.
+    pub fn volume_configurations<T: Into<Vec<VolumeConfiguration>>>(mut self, value: T) -> Self {
+        self.volume_configurations = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateLayerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateLayerRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateLayerRequest {
+            attributes: self.attributes,
+            auto_assign_elastic_ips: self.auto_assign_elastic_ips,
+            auto_assign_public_ips: self.auto_assign_public_ips,
+            cloud_watch_logs_configuration: self.cloud_watch_logs_configuration,
+            custom_instance_profile_arn: self.custom_instance_profile_arn,
+            custom_json: self.custom_json,
+            custom_recipes: self.custom_recipes,
+            custom_security_group_ids: self.custom_security_group_ids,
+            enable_auto_healing: self.enable_auto_healing,
+            install_updates_on_boot: self.install_updates_on_boot,
+            layer_id: self
+                .layer_id
+                .ok_or(::rusoto_core::BuildError::missing("layer_id"))?,
+            lifecycle_event_configuration: self.lifecycle_event_configuration,
+            name: self.name,
+            packages: self.packages,
+            shortname: self.shortname,
+            use_ebs_optimized_instances: self.use_ebs_optimized_instances,
+            volume_configurations: self.volume_configurations,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateMyUserProfileRequest {
     /// <p>The user's SSH public key.</p>
@@ -2981,6 +8117,36 @@ pub struct UpdateMyUserProfileRequest {
     pub ssh_public_key: Option<String>,
 }
 
+impl UpdateMyUserProfileRequest {
+    /// Returns a builder for `UpdateMyUserProfileRequest`, checking its required members when built.
+    pub fn builder() -> UpdateMyUserProfileRequestBuilder {
+        UpdateMyUserProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateMyUserProfileRequest`](struct.UpdateMyUserProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateMyUserProfileRequestBuilder {
+    ssh_public_key: Option<String>,
+}
+
+impl UpdateMyUserProfileRequestBuilder {
+    /// Sets the [`ssh_public_key`](struct.UpdateMyUserProfileRequest.html#structfield.ssh_public_key) member.
+    pub fn ssh_public_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateMyUserProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateMyUserProfileRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateMyUserProfileRequest {
+            ssh_public_key: self.ssh_public_key,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateRdsDbInstanceRequest {
     /// <p>The database password.</p>
@@ -2996,6 +8162,54 @@ pub struct UpdateRdsDbInstanceRequest {
     pub rds_db_instance_arn: String,
 }
 
+impl UpdateRdsDbInstanceRequest {
+    /// Returns a builder for `UpdateRdsDbInstanceRequest`, checking its required members when built.
+    pub fn builder() -> UpdateRdsDbInstanceRequestBuilder {
+        UpdateRdsDbInstanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateRdsDbInstanceRequest`](struct.UpdateRdsDbInstanceRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateRdsDbInstanceRequestBuilder {
+    db_password: Option<String>,
+    db_user: Option<String>,
+    rds_db_instance_arn: Option<String>,
+}
+
+impl UpdateRdsDbInstanceRequestBuilder {
+    /// Sets the [`db_password`](struct.UpdateRdsDbInstanceRequest.html#structfield.db_password) member.
+    pub fn db_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_user`](struct.UpdateRdsDbInstanceRequest.html#structfield.db_user) member.
+    pub fn db_user<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_user = Some(value.into());
+        self
+    }
+
+    /// Sets the [`rds_db_instance_arn`](struct.UpdateRdsDbInstanceRequest.html#structfield.rds_db_instance_arn) member.
+    pub fn rds_db_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.rds_db_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateRdsDbInstanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateRdsDbInstanceRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateRdsDbInstanceRequest {
+            db_password: self.db_password,
+            db_user: self.db_user,
+            rds_db_instance_arn: self
+                .rds_db_instance_arn
+                .ok_or(::rusoto_core::BuildError::missing("rds_db_instance_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateStackRequest {
     /// <p><p>The default AWS OpsWorks Stacks agent version. You have the following options:</p> <ul> <li> <p>Auto-update - Set this parameter to <code>LATEST</code>. AWS OpsWorks Stacks automatically installs new agent versions on the stack&#39;s instances as soon as they are available.</p> </li> <li> <p>Fixed version - Set this parameter to your preferred agent version. To update the agent version, you must edit the stack configuration and specify a new version. AWS OpsWorks Stacks then automatically installs that version on the stack&#39;s instances.</p> </li> </ul> <p>The default setting is <code>LATEST</code>. To specify an agent version, you must use the complete version number, not the abbreviated number shown on the console. For a list of available agent version numbers, call <a>DescribeAgentVersions</a>. AgentVersion cannot be set to Chef 12.2.</p> <note> <p>You can also specify an agent version when you create or update an instance, which overrides the stack&#39;s default setting.</p> </note></p>
@@ -3005,7 +8219,7 @@ pub struct UpdateStackRequest {
     /// <p>One or more user-defined key-value pairs to be added to the stack attributes.</p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<::std::collections::HashMap<String, String>>,
+    pub attributes: Option<::std::collections::HashMap<StackAttributesKeys, String>>,
     /// <p>A <code>ChefConfiguration</code> object that specifies whether to enable Berkshelf and the Berkshelf version on Chef 11.10 stacks. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workingstacks-creating.html">Create a New Stack</a>.</p>
     #[serde(rename = "ChefConfiguration")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3037,7 +8251,7 @@ pub struct UpdateStackRequest {
     /// <p>The default root device type. This value is used by default for all instances in the stack, but you can override it when you create an instance. For more information, see <a href="https://docs.aws.amazon.com/AWSEC2/latest/UserGuide/ComponentsAMIs.html#storage-for-the-root-device">Storage for the Root Device</a>.</p>
     #[serde(rename = "DefaultRootDeviceType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_root_device_type: Option<String>,
+    pub default_root_device_type: Option<RootDeviceType>,
     /// <p>A default Amazon EC2 key-pair name. The default value is <code>none</code>. If you specify a key-pair name, AWS OpsWorks Stacks installs the public key on the instance and you can use the private key with an SSH client to log in to the instance. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-ssh.html"> Using SSH to Communicate with an Instance</a> and <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/security-ssh-access.html"> Managing SSH Access</a>. You can override this setting by specifying a different key pair, or no key pair, when you <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/workinginstances-add.html"> create an instance</a>. </p>
     #[serde(rename = "DefaultSshKeyName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3071,6 +8285,175 @@ pub struct UpdateStackRequest {
     pub use_opsworks_security_groups: Option<bool>,
 }
 
+impl UpdateStackRequest {
+    /// Returns a builder for `UpdateStackRequest`, checking its required members when built.
+    pub fn builder() -> UpdateStackRequestBuilder {
+        UpdateStackRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateStackRequest`](struct.UpdateStackRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateStackRequestBuilder {
+    agent_version: Option<String>,
+    attributes: Option<::std::collections::HashMap<StackAttributesKeys, String>>,
+    chef_configuration: Option<ChefConfiguration>,
+    configuration_manager: Option<StackConfigurationManager>,
+    custom_cookbooks_source: Option<Source>,
+    custom_json: Option<String>,
+    default_availability_zone: Option<String>,
+    default_instance_profile_arn: Option<String>,
+    default_os: Option<String>,
+    default_root_device_type: Option<RootDeviceType>,
+    default_ssh_key_name: Option<String>,
+    default_subnet_id: Option<String>,
+    hostname_theme: Option<String>,
+    name: Option<String>,
+    service_role_arn: Option<String>,
+    stack_id: Option<String>,
+    use_custom_cookbooks: Option<bool>,
+    use_opsworks_security_groups: Option<bool>,
+}
+
+impl UpdateStackRequestBuilder {
+    /// Sets the [`agent_version`](struct.UpdateStackRequest.html#structfield.agent_version) member.
+    pub fn agent_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.agent_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attributes`](struct.UpdateStackRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<::std::collections::HashMap<StackAttributesKeys, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`chef_configuration`](struct.UpdateStackRequest.html#structfield.chef_configuration) member.
+    pub fn chef_configuration<T: Into<ChefConfiguration>>(mut self, value: T) -> Self {
+        self.chef_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`configuration_manager`](struct.UpdateStackRequest.html#structfield.configuration_manager) member.
+    pub fn configuration_manager<T: Into<StackConfigurationManager>>(mut self, value: T) -> Self {
+        self.configuration_manager = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_cookbooks_source`](struct.UpdateStackRequest.html#structfield.custom_cookbooks_source) member.
+    pub fn custom_cookbooks_source<T: Into<Source>>(mut self, value: T) -> Self {
+        self.custom_cookbooks_source = Some(value.into());
+        self
+    }
+
+    /// Sets the [`custom_json`](struct.UpdateStackRequest.html#structfield.custom_json) member.
+    pub fn custom_json<T: Into<String>>(mut self, value: T) -> Self {
+        self.custom_json = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_availability_zone`](struct.UpdateStackRequest.html#structfield.default_availability_zone) member.
+    pub fn default_availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_instance_profile_arn`](struct.UpdateStackRequest.html#structfield.default_instance_profile_arn) member.
+    pub fn default_instance_profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_instance_profile_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_os`](struct.UpdateStackRequest.html#structfield.default_os) member.
+    pub fn default_os<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_os = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_root_device_type`](struct.UpdateStackRequest.html#structfield.default_root_device_type) member.
+    pub fn default_root_device_type<T: Into<RootDeviceType>>(mut self, value: T) -> Self {
+        self.default_root_device_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_ssh_key_name`](struct.UpdateStackRequest.html#structfield.default_ssh_key_name) member.
+    pub fn default_ssh_key_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_ssh_key_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_subnet_id`](struct.UpdateStackRequest.html#structfield.default_subnet_id) member.
+    pub fn default_subnet_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.default_subnet_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hostname_theme`](struct.UpdateStackRequest.html#structfield.hostname_theme) member.
+    pub fn hostname_theme<T: Into<String>>(mut self, value: T) -> Self {
+        self.hostname_theme = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateStackRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role_arn`](struct.UpdateStackRequest.html#structfield.service_role_arn) member.
+    pub fn service_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`stack_id`](struct.UpdateStackRequest.html#structfield.stack_id) member.
+    pub fn stack_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.stack_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_custom_cookbooks`](struct.UpdateStackRequest.html#structfield.use_custom_cookbooks) member.
+    pub fn use_custom_cookbooks<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_custom_cookbooks = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_opsworks_security_groups`](struct.UpdateStackRequest.html#structfield.use_opsworks_security_groups) member.
+    pub fn use_opsworks_security_groups<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_opsworks_security_groups = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateStackRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateStackRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateStackRequest {
+            agent_version: self.agent_version,
+            attributes: self.attributes,
+            chef_configuration: self.chef_configuration,
+            configuration_manager: self.configuration_manager,
+            custom_cookbooks_source: self.custom_cookbooks_source,
+            custom_json: self.custom_json,
+            default_availability_zone: self.default_availability_zone,
+            default_instance_profile_arn: self.default_instance_profile_arn,
+            default_os: self.default_os,
+            default_root_device_type: self.default_root_device_type,
+            default_ssh_key_name: self.default_ssh_key_name,
+            default_subnet_id: self.default_subnet_id,
+            hostname_theme: self.hostname_theme,
+            name: self.name,
+            service_role_arn: self.service_role_arn,
+            stack_id: self
+                .stack_id
+                .ok_or(::rusoto_core::BuildError::missing("stack_id"))?,
+            use_custom_cookbooks: self.use_custom_cookbooks,
+            use_opsworks_security_groups: self.use_opsworks_security_groups,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateUserProfileRequest {
     /// <p>Whether users can specify their own SSH public key through the My Settings page. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/security-settingsshkey.html">Managing User Permissions</a>.</p>
@@ -3090,6 +8473,62 @@ pub struct UpdateUserProfileRequest {
     pub ssh_username: Option<String>,
 }
 
+impl UpdateUserProfileRequest {
+    /// Returns a builder for `UpdateUserProfileRequest`, checking its required members when built.
+    pub fn builder() -> UpdateUserProfileRequestBuilder {
+        UpdateUserProfileRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateUserProfileRequest`](struct.UpdateUserProfileRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateUserProfileRequestBuilder {
+    allow_self_management: Option<bool>,
+    iam_user_arn: Option<String>,
+    ssh_public_key: Option<String>,
+    ssh_username: Option<String>,
+}
+
+impl UpdateUserProfileRequestBuilder {
+    /// Sets the [`allow_self_management`](struct.UpdateUserProfileRequest.html#structfield.allow_self_management) member.
+    pub fn allow_self_management<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_self_management = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_user_arn`](struct.UpdateUserProfileRequest.html#structfield.iam_user_arn) member.
+    pub fn iam_user_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.iam_user_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_public_key`](struct.UpdateUserProfileRequest.html#structfield.ssh_public_key) member.
+    pub fn ssh_public_key<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_public_key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ssh_username`](struct.UpdateUserProfileRequest.html#structfield.ssh_username) member.
+    pub fn ssh_username<T: Into<String>>(mut self, value: T) -> Self {
+        self.ssh_username = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateUserProfileRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateUserProfileRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateUserProfileRequest {
+            allow_self_management: self.allow_self_management,
+            iam_user_arn: self
+                .iam_user_arn
+                .ok_or(::rusoto_core::BuildError::missing("iam_user_arn"))?,
+            ssh_public_key: self.ssh_public_key,
+            ssh_username: self.ssh_username,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateVolumeRequest {
     /// <p>The new mount point.</p>
@@ -3105,6 +8544,52 @@ pub struct UpdateVolumeRequest {
     pub volume_id: String,
 }
 
+impl UpdateVolumeRequest {
+    /// Returns a builder for `UpdateVolumeRequest`, checking its required members when built.
+    pub fn builder() -> UpdateVolumeRequestBuilder {
+        UpdateVolumeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateVolumeRequest`](struct.UpdateVolumeRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateVolumeRequestBuilder {
+    mount_point: Option<String>,
+    name: Option<String>,
+    volume_id: Option<String>,
+}
+
+impl UpdateVolumeRequestBuilder {
+    /// Sets the [`mount_point`](struct.UpdateVolumeRequest.html#structfield.mount_point) member.
+    pub fn mount_point<T: Into<String>>(mut self, value: T) -> Self {
+        self.mount_point = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateVolumeRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`volume_id`](struct.UpdateVolumeRequest.html#structfield.volume_id) member.
+    pub fn volume_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.volume_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateVolumeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateVolumeRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateVolumeRequest {
+            mount_point: self.mount_point,
+            name: self.name,
+            volume_id: self
+                .volume_id
+                .ok_or(::rusoto_core::BuildError::missing("volume_id"))?,
+        })
+    }
+}
+
 /// <p>Describes a user's SSH information.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3131,6 +8616,78 @@ pub struct UserProfile {
     pub ssh_username: Option<String>,
 }
 
+/// <p>The instance's virtualization type: <code>paravirtual</code> or <code>hvm</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VirtualizationType {
+    /// <p><code>paravirtual</code></p>
+    Paravirtual,
+    /// <p><code>hvm</code></p>
+    Hvm,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VirtualizationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VirtualizationType::Paravirtual => "paravirtual",
+            VirtualizationType::Hvm => "hvm",
+            VirtualizationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VirtualizationType {
+    fn from(value: &'a str) -> VirtualizationType {
+        match value {
+            "paravirtual" => VirtualizationType::Paravirtual,
+            "hvm" => VirtualizationType::Hvm,
+            _ => VirtualizationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VirtualizationType {
+    fn from(value: String) -> VirtualizationType {
+        VirtualizationType::from(value.as_str())
+    }
+}
+
+impl From<VirtualizationType> for String {
+    fn from(value: VirtualizationType) -> String {
+        match value {
+            VirtualizationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VirtualizationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for VirtualizationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VirtualizationType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VirtualizationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VirtualizationType::from(value))
+    }
+}
+
 /// <p>Describes an instance's Amazon EBS volume.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -3223,25 +8780,101 @@ pub struct VolumeConfiguration {
     pub volume_type: Option<String>,
 }
 
-/// <p>Describes a time-based instance's auto scaling schedule. The schedule consists of a set of key-value pairs.</p> <ul> <li> <p>The key is the time period (a UTC hour) and must be an integer from 0 - 23.</p> </li> <li> <p>The value indicates whether the instance should be online or offline for the specified period, and must be set to "on" or "off"</p> </li> </ul> <p>The default setting for all time periods is off, so you use the following parameters primarily to specify the online periods. You don't have to explicitly specify offline periods unless you want to change an online period to an offline period.</p> <p>The following example specifies that the instance should be online for four hours, from UTC 1200 - 1600. It will be off for the remainder of the day.</p> <p> <code> { "12":"on", "13":"on", "14":"on", "15":"on" } </code> </p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct WeeklyAutoScalingSchedule {
-    /// <p>The schedule for Friday.</p>
-    #[serde(rename = "Friday")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub friday: Option<::std::collections::HashMap<String, String>>,
-    /// <p>The schedule for Monday.</p>
-    #[serde(rename = "Monday")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub monday: Option<::std::collections::HashMap<String, String>>,
-    /// <p>The schedule for Saturday.</p>
-    #[serde(rename = "Saturday")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub saturday: Option<::std::collections::HashMap<String, String>>,
-    /// <p>The schedule for Sunday.</p>
-    #[serde(rename = "Sunday")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sunday: Option<::std::collections::HashMap<String, String>>,
+/// <p>The volume type. <code>gp2</code> for General Purpose (SSD) volumes, <code>io1</code> for Provisioned IOPS (SSD) volumes, <code>st1</code> for Throughput Optimized hard disk drives (HDD), <code>sc1</code> for Cold HDD,and <code>standard</code> for Magnetic volumes.</p> <p>If you specify the <code>io1</code> volume type, you must also specify a value for the <code>Iops</code> attribute. The maximum ratio of provisioned IOPS to requested volume size (in GiB) is 50:1. AWS uses the default volume size (in GiB) specified in the AMI attributes to set IOPS to 50 x (volume size).</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VolumeType {
+    /// <p><code>gp2</code></p>
+    Gp2,
+    /// <p><code>io1</code></p>
+    Io1,
+    /// <p><code>standard</code></p>
+    Standard,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VolumeType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VolumeType::Gp2 => "gp2",
+            VolumeType::Io1 => "io1",
+            VolumeType::Standard => "standard",
+            VolumeType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VolumeType {
+    fn from(value: &'a str) -> VolumeType {
+        match value {
+            "gp2" => VolumeType::Gp2,
+            "io1" => VolumeType::Io1,
+            "standard" => VolumeType::Standard,
+            _ => VolumeType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VolumeType {
+    fn from(value: String) -> VolumeType {
+        VolumeType::from(value.as_str())
+    }
+}
+
+impl From<VolumeType> for String {
+    fn from(value: VolumeType) -> String {
+        match value {
+            VolumeType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VolumeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for VolumeType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VolumeType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VolumeType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VolumeType::from(value))
+    }
+}
+
+/// <p>Describes a time-based instance's auto scaling schedule. The schedule consists of a set of key-value pairs.</p> <ul> <li> <p>The key is the time period (a UTC hour) and must be an integer from 0 - 23.</p> </li> <li> <p>The value indicates whether the instance should be online or offline for the specified period, and must be set to "on" or "off"</p> </li> </ul> <p>The default setting for all time periods is off, so you use the following parameters primarily to specify the online periods. You don't have to explicitly specify offline periods unless you want to change an online period to an offline period.</p> <p>The following example specifies that the instance should be online for four hours, from UTC 1200 - 1600. It will be off for the remainder of the day.</p> <p> <code> { "12":"on", "13":"on", "14":"on", "15":"on" } </code> </p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeeklyAutoScalingSchedule {
+    /// <p>The schedule for Friday.</p>
+    #[serde(rename = "Friday")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friday: Option<::std::collections::HashMap<String, String>>,
+    /// <p>The schedule for Monday.</p>
+    #[serde(rename = "Monday")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monday: Option<::std::collections::HashMap<String, String>>,
+    /// <p>The schedule for Saturday.</p>
+    #[serde(rename = "Saturday")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub saturday: Option<::std::collections::HashMap<String, String>>,
+    /// <p>The schedule for Sunday.</p>
+    #[serde(rename = "Sunday")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunday: Option<::std::collections::HashMap<String, String>>,
     /// <p>The schedule for Thursday.</p>
     #[serde(rename = "Thursday")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -6108,6 +11741,409 @@ pub trait OpsWorks {
     /// <p>Updates an Amazon EBS volume's name or mount point. For more information, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/resources.html">Resource Management</a>.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     fn update_volume(&self, input: UpdateVolumeRequest) -> RusotoFuture<(), UpdateVolumeError>;
 }
+impl<T: ?Sized + OpsWorks> OpsWorks for ::std::sync::Arc<T> {
+    fn assign_instance(
+        &self,
+        input: AssignInstanceRequest,
+    ) -> RusotoFuture<(), AssignInstanceError> {
+        (**self).assign_instance(input)
+    }
+    fn assign_volume(&self, input: AssignVolumeRequest) -> RusotoFuture<(), AssignVolumeError> {
+        (**self).assign_volume(input)
+    }
+    fn associate_elastic_ip(
+        &self,
+        input: AssociateElasticIpRequest,
+    ) -> RusotoFuture<(), AssociateElasticIpError> {
+        (**self).associate_elastic_ip(input)
+    }
+    fn attach_elastic_load_balancer(
+        &self,
+        input: AttachElasticLoadBalancerRequest,
+    ) -> RusotoFuture<(), AttachElasticLoadBalancerError> {
+        (**self).attach_elastic_load_balancer(input)
+    }
+    fn clone_stack(
+        &self,
+        input: CloneStackRequest,
+    ) -> RusotoFuture<CloneStackResult, CloneStackError> {
+        (**self).clone_stack(input)
+    }
+    fn create_app(&self, input: CreateAppRequest) -> RusotoFuture<CreateAppResult, CreateAppError> {
+        (**self).create_app(input)
+    }
+    fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> RusotoFuture<CreateDeploymentResult, CreateDeploymentError> {
+        (**self).create_deployment(input)
+    }
+    fn create_instance(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> RusotoFuture<CreateInstanceResult, CreateInstanceError> {
+        (**self).create_instance(input)
+    }
+    fn create_layer(
+        &self,
+        input: CreateLayerRequest,
+    ) -> RusotoFuture<CreateLayerResult, CreateLayerError> {
+        (**self).create_layer(input)
+    }
+    fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> RusotoFuture<CreateStackResult, CreateStackError> {
+        (**self).create_stack(input)
+    }
+    fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> RusotoFuture<CreateUserProfileResult, CreateUserProfileError> {
+        (**self).create_user_profile(input)
+    }
+    fn delete_app(&self, input: DeleteAppRequest) -> RusotoFuture<(), DeleteAppError> {
+        (**self).delete_app(input)
+    }
+    fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> RusotoFuture<(), DeleteInstanceError> {
+        (**self).delete_instance(input)
+    }
+    fn delete_layer(&self, input: DeleteLayerRequest) -> RusotoFuture<(), DeleteLayerError> {
+        (**self).delete_layer(input)
+    }
+    fn delete_stack(&self, input: DeleteStackRequest) -> RusotoFuture<(), DeleteStackError> {
+        (**self).delete_stack(input)
+    }
+    fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> RusotoFuture<(), DeleteUserProfileError> {
+        (**self).delete_user_profile(input)
+    }
+    fn deregister_ecs_cluster(
+        &self,
+        input: DeregisterEcsClusterRequest,
+    ) -> RusotoFuture<(), DeregisterEcsClusterError> {
+        (**self).deregister_ecs_cluster(input)
+    }
+    fn deregister_elastic_ip(
+        &self,
+        input: DeregisterElasticIpRequest,
+    ) -> RusotoFuture<(), DeregisterElasticIpError> {
+        (**self).deregister_elastic_ip(input)
+    }
+    fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> RusotoFuture<(), DeregisterInstanceError> {
+        (**self).deregister_instance(input)
+    }
+    fn deregister_rds_db_instance(
+        &self,
+        input: DeregisterRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), DeregisterRdsDbInstanceError> {
+        (**self).deregister_rds_db_instance(input)
+    }
+    fn deregister_volume(
+        &self,
+        input: DeregisterVolumeRequest,
+    ) -> RusotoFuture<(), DeregisterVolumeError> {
+        (**self).deregister_volume(input)
+    }
+    fn describe_agent_versions(
+        &self,
+        input: DescribeAgentVersionsRequest,
+    ) -> RusotoFuture<DescribeAgentVersionsResult, DescribeAgentVersionsError> {
+        (**self).describe_agent_versions(input)
+    }
+    fn describe_apps(
+        &self,
+        input: DescribeAppsRequest,
+    ) -> RusotoFuture<DescribeAppsResult, DescribeAppsError> {
+        (**self).describe_apps(input)
+    }
+    fn describe_commands(
+        &self,
+        input: DescribeCommandsRequest,
+    ) -> RusotoFuture<DescribeCommandsResult, DescribeCommandsError> {
+        (**self).describe_commands(input)
+    }
+    fn describe_deployments(
+        &self,
+        input: DescribeDeploymentsRequest,
+    ) -> RusotoFuture<DescribeDeploymentsResult, DescribeDeploymentsError> {
+        (**self).describe_deployments(input)
+    }
+    fn describe_ecs_clusters(
+        &self,
+        input: DescribeEcsClustersRequest,
+    ) -> RusotoFuture<DescribeEcsClustersResult, DescribeEcsClustersError> {
+        (**self).describe_ecs_clusters(input)
+    }
+    fn describe_elastic_ips(
+        &self,
+        input: DescribeElasticIpsRequest,
+    ) -> RusotoFuture<DescribeElasticIpsResult, DescribeElasticIpsError> {
+        (**self).describe_elastic_ips(input)
+    }
+    fn describe_elastic_load_balancers(
+        &self,
+        input: DescribeElasticLoadBalancersRequest,
+    ) -> RusotoFuture<DescribeElasticLoadBalancersResult, DescribeElasticLoadBalancersError> {
+        (**self).describe_elastic_load_balancers(input)
+    }
+    fn describe_instances(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> RusotoFuture<DescribeInstancesResult, DescribeInstancesError> {
+        (**self).describe_instances(input)
+    }
+    fn describe_layers(
+        &self,
+        input: DescribeLayersRequest,
+    ) -> RusotoFuture<DescribeLayersResult, DescribeLayersError> {
+        (**self).describe_layers(input)
+    }
+    fn describe_load_based_auto_scaling(
+        &self,
+        input: DescribeLoadBasedAutoScalingRequest,
+    ) -> RusotoFuture<DescribeLoadBasedAutoScalingResult, DescribeLoadBasedAutoScalingError> {
+        (**self).describe_load_based_auto_scaling(input)
+    }
+    fn describe_my_user_profile(
+        &self,
+    ) -> RusotoFuture<DescribeMyUserProfileResult, DescribeMyUserProfileError> {
+        (**self).describe_my_user_profile()
+    }
+    fn describe_operating_systems(
+        &self,
+    ) -> RusotoFuture<DescribeOperatingSystemsResponse, DescribeOperatingSystemsError> {
+        (**self).describe_operating_systems()
+    }
+    fn describe_permissions(
+        &self,
+        input: DescribePermissionsRequest,
+    ) -> RusotoFuture<DescribePermissionsResult, DescribePermissionsError> {
+        (**self).describe_permissions(input)
+    }
+    fn describe_raid_arrays(
+        &self,
+        input: DescribeRaidArraysRequest,
+    ) -> RusotoFuture<DescribeRaidArraysResult, DescribeRaidArraysError> {
+        (**self).describe_raid_arrays(input)
+    }
+    fn describe_rds_db_instances(
+        &self,
+        input: DescribeRdsDbInstancesRequest,
+    ) -> RusotoFuture<DescribeRdsDbInstancesResult, DescribeRdsDbInstancesError> {
+        (**self).describe_rds_db_instances(input)
+    }
+    fn describe_service_errors(
+        &self,
+        input: DescribeServiceErrorsRequest,
+    ) -> RusotoFuture<DescribeServiceErrorsResult, DescribeServiceErrorsError> {
+        (**self).describe_service_errors(input)
+    }
+    fn describe_stack_provisioning_parameters(
+        &self,
+        input: DescribeStackProvisioningParametersRequest,
+    ) -> RusotoFuture<
+        DescribeStackProvisioningParametersResult,
+        DescribeStackProvisioningParametersError,
+    > {
+        (**self).describe_stack_provisioning_parameters(input)
+    }
+    fn describe_stack_summary(
+        &self,
+        input: DescribeStackSummaryRequest,
+    ) -> RusotoFuture<DescribeStackSummaryResult, DescribeStackSummaryError> {
+        (**self).describe_stack_summary(input)
+    }
+    fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> RusotoFuture<DescribeStacksResult, DescribeStacksError> {
+        (**self).describe_stacks(input)
+    }
+    fn describe_time_based_auto_scaling(
+        &self,
+        input: DescribeTimeBasedAutoScalingRequest,
+    ) -> RusotoFuture<DescribeTimeBasedAutoScalingResult, DescribeTimeBasedAutoScalingError> {
+        (**self).describe_time_based_auto_scaling(input)
+    }
+    fn describe_user_profiles(
+        &self,
+        input: DescribeUserProfilesRequest,
+    ) -> RusotoFuture<DescribeUserProfilesResult, DescribeUserProfilesError> {
+        (**self).describe_user_profiles(input)
+    }
+    fn describe_volumes(
+        &self,
+        input: DescribeVolumesRequest,
+    ) -> RusotoFuture<DescribeVolumesResult, DescribeVolumesError> {
+        (**self).describe_volumes(input)
+    }
+    fn detach_elastic_load_balancer(
+        &self,
+        input: DetachElasticLoadBalancerRequest,
+    ) -> RusotoFuture<(), DetachElasticLoadBalancerError> {
+        (**self).detach_elastic_load_balancer(input)
+    }
+    fn disassociate_elastic_ip(
+        &self,
+        input: DisassociateElasticIpRequest,
+    ) -> RusotoFuture<(), DisassociateElasticIpError> {
+        (**self).disassociate_elastic_ip(input)
+    }
+    fn get_hostname_suggestion(
+        &self,
+        input: GetHostnameSuggestionRequest,
+    ) -> RusotoFuture<GetHostnameSuggestionResult, GetHostnameSuggestionError> {
+        (**self).get_hostname_suggestion(input)
+    }
+    fn grant_access(
+        &self,
+        input: GrantAccessRequest,
+    ) -> RusotoFuture<GrantAccessResult, GrantAccessError> {
+        (**self).grant_access(input)
+    }
+    fn list_tags(&self, input: ListTagsRequest) -> RusotoFuture<ListTagsResult, ListTagsError> {
+        (**self).list_tags(input)
+    }
+    fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> RusotoFuture<(), RebootInstanceError> {
+        (**self).reboot_instance(input)
+    }
+    fn register_ecs_cluster(
+        &self,
+        input: RegisterEcsClusterRequest,
+    ) -> RusotoFuture<RegisterEcsClusterResult, RegisterEcsClusterError> {
+        (**self).register_ecs_cluster(input)
+    }
+    fn register_elastic_ip(
+        &self,
+        input: RegisterElasticIpRequest,
+    ) -> RusotoFuture<RegisterElasticIpResult, RegisterElasticIpError> {
+        (**self).register_elastic_ip(input)
+    }
+    fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> RusotoFuture<RegisterInstanceResult, RegisterInstanceError> {
+        (**self).register_instance(input)
+    }
+    fn register_rds_db_instance(
+        &self,
+        input: RegisterRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), RegisterRdsDbInstanceError> {
+        (**self).register_rds_db_instance(input)
+    }
+    fn register_volume(
+        &self,
+        input: RegisterVolumeRequest,
+    ) -> RusotoFuture<RegisterVolumeResult, RegisterVolumeError> {
+        (**self).register_volume(input)
+    }
+    fn set_load_based_auto_scaling(
+        &self,
+        input: SetLoadBasedAutoScalingRequest,
+    ) -> RusotoFuture<(), SetLoadBasedAutoScalingError> {
+        (**self).set_load_based_auto_scaling(input)
+    }
+    fn set_permission(&self, input: SetPermissionRequest) -> RusotoFuture<(), SetPermissionError> {
+        (**self).set_permission(input)
+    }
+    fn set_time_based_auto_scaling(
+        &self,
+        input: SetTimeBasedAutoScalingRequest,
+    ) -> RusotoFuture<(), SetTimeBasedAutoScalingError> {
+        (**self).set_time_based_auto_scaling(input)
+    }
+    fn start_instance(&self, input: StartInstanceRequest) -> RusotoFuture<(), StartInstanceError> {
+        (**self).start_instance(input)
+    }
+    fn start_stack(&self, input: StartStackRequest) -> RusotoFuture<(), StartStackError> {
+        (**self).start_stack(input)
+    }
+    fn stop_instance(&self, input: StopInstanceRequest) -> RusotoFuture<(), StopInstanceError> {
+        (**self).stop_instance(input)
+    }
+    fn stop_stack(&self, input: StopStackRequest) -> RusotoFuture<(), StopStackError> {
+        (**self).stop_stack(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn unassign_instance(
+        &self,
+        input: UnassignInstanceRequest,
+    ) -> RusotoFuture<(), UnassignInstanceError> {
+        (**self).unassign_instance(input)
+    }
+    fn unassign_volume(
+        &self,
+        input: UnassignVolumeRequest,
+    ) -> RusotoFuture<(), UnassignVolumeError> {
+        (**self).unassign_volume(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_app(&self, input: UpdateAppRequest) -> RusotoFuture<(), UpdateAppError> {
+        (**self).update_app(input)
+    }
+    fn update_elastic_ip(
+        &self,
+        input: UpdateElasticIpRequest,
+    ) -> RusotoFuture<(), UpdateElasticIpError> {
+        (**self).update_elastic_ip(input)
+    }
+    fn update_instance(
+        &self,
+        input: UpdateInstanceRequest,
+    ) -> RusotoFuture<(), UpdateInstanceError> {
+        (**self).update_instance(input)
+    }
+    fn update_layer(&self, input: UpdateLayerRequest) -> RusotoFuture<(), UpdateLayerError> {
+        (**self).update_layer(input)
+    }
+    fn update_my_user_profile(
+        &self,
+        input: UpdateMyUserProfileRequest,
+    ) -> RusotoFuture<(), UpdateMyUserProfileError> {
+        (**self).update_my_user_profile(input)
+    }
+    fn update_rds_db_instance(
+        &self,
+        input: UpdateRdsDbInstanceRequest,
+    ) -> RusotoFuture<(), UpdateRdsDbInstanceError> {
+        (**self).update_rds_db_instance(input)
+    }
+    fn update_stack(&self, input: UpdateStackRequest) -> RusotoFuture<(), UpdateStackError> {
+        (**self).update_stack(input)
+    }
+    fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> RusotoFuture<(), UpdateUserProfileError> {
+        (**self).update_user_profile(input)
+    }
+    fn update_volume(&self, input: UpdateVolumeRequest) -> RusotoFuture<(), UpdateVolumeError> {
+        (**self).update_volume(input)
+    }
+}
+/// The error codes AWS OpsWorks retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS OpsWorks API.
 #[derive(Clone)]
 pub struct OpsWorksClient {
@@ -6123,6 +12159,27 @@ impl OpsWorksClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_OPSWORKS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> OpsWorksClient {
+        Self::new(region::Region::from_env_for_service("OPSWORKS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `opsworks`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> OpsWorksClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("opsworks", "OPSWORKS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -6141,7 +12198,54 @@ impl OpsWorksClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksClient {
-        OpsWorksClient { client, region }
+        OpsWorksClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "opsworks")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "opsworks",
+            "opsworks",
+            "OPSWORKS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `OpsWorks` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn OpsWorks + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for OpsWorksClient {
+    fn service_name(&self) -> &'static str {
+        "AWS OpsWorks"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "opsworks"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> OpsWorksClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS OpsWorks CM clients.
+//!
+//! Makes a single read-only `DescribeAccountAttributes` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_opsworkscm::{OpsWorksCM, OpsWorksCMClient};
+
+fn main() {
+    let client = OpsWorksCMClient::from_env();
+    match client.describe_account_attributes().sync() {
+        Ok(_) => println!("DescribeAccountAttributes succeeded"),
+        Err(error) => {
+            eprintln!("DescribeAccountAttributes failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Stores account attributes. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -54,6 +55,56 @@ pub struct AssociateNodeRequest {
     pub server_name: String,
 }
 
+impl AssociateNodeRequest {
+    /// Returns a builder for `AssociateNodeRequest`, checking its required members when built.
+    pub fn builder() -> AssociateNodeRequestBuilder {
+        AssociateNodeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateNodeRequest`](struct.AssociateNodeRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateNodeRequestBuilder {
+    engine_attributes: Option<Vec<EngineAttribute>>,
+    node_name: Option<String>,
+    server_name: Option<String>,
+}
+
+impl AssociateNodeRequestBuilder {
+    /// Sets the [`engine_attributes`](struct.AssociateNodeRequest.html#structfield.engine_attributes) member.
+    pub fn engine_attributes<T: Into<Vec<EngineAttribute>>>(mut self, value: T) -> Self {
+        self.engine_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_name`](struct.AssociateNodeRequest.html#structfield.node_name) member.
+    pub fn node_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.node_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.AssociateNodeRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateNodeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AssociateNodeRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateNodeRequest {
+            engine_attributes: self
+                .engine_attributes
+                .ok_or(::rusoto_core::BuildError::missing("engine_attributes"))?,
+            node_name: self
+                .node_name
+                .ok_or(::rusoto_core::BuildError::missing("node_name"))?,
+            server_name: self
+                .server_name
+                .ok_or(::rusoto_core::BuildError::missing("server_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateNodeResponse {
@@ -78,11 +129,12 @@ pub struct Backup {
     /// <p> The backup type. Valid values are <code>automated</code> or <code>manual</code>. </p>
     #[serde(rename = "BackupType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub backup_type: Option<String>,
+    pub backup_type: Option<BackupType>,
     /// <p> The time stamp when the backup was created in the database. Example: <code>2016-07-29T13:38:47.520Z</code> </p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p> A user-provided description for a manual backup. This field is empty for automated backups. </p>
     #[serde(rename = "Description")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -138,7 +190,7 @@ pub struct Backup {
     /// <p>The status of a backup while in progress. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<BackupStatus>,
     /// <p> An informational message about backup status. </p>
     #[serde(rename = "StatusDescription")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -157,6 +209,158 @@ pub struct Backup {
     pub user_arn: Option<String>,
 }
 
+/// <p>The status of a backup while in progress. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BackupStatus {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>OK</code></p>
+    Ok,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BackupStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupStatus::InProgress => "IN_PROGRESS",
+            BackupStatus::Ok => "OK",
+            BackupStatus::Failed => "FAILED",
+            BackupStatus::Deleting => "DELETING",
+            BackupStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BackupStatus {
+    fn from(value: &'a str) -> BackupStatus {
+        match value {
+            "IN_PROGRESS" => BackupStatus::InProgress,
+            "OK" => BackupStatus::Ok,
+            "FAILED" => BackupStatus::Failed,
+            "DELETING" => BackupStatus::Deleting,
+            _ => BackupStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupStatus {
+    fn from(value: String) -> BackupStatus {
+        BackupStatus::from(value.as_str())
+    }
+}
+
+impl From<BackupStatus> for String {
+    fn from(value: BackupStatus) -> String {
+        match value {
+            BackupStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BackupStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BackupStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BackupStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BackupStatus::from(value))
+    }
+}
+
+/// <p> The backup type. Valid values are <code>automated</code> or <code>manual</code>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BackupType {
+    /// <p><code>AUTOMATED</code></p>
+    Automated,
+    /// <p><code>MANUAL</code></p>
+    Manual,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl BackupType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            BackupType::Automated => "AUTOMATED",
+            BackupType::Manual => "MANUAL",
+            BackupType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for BackupType {
+    fn from(value: &'a str) -> BackupType {
+        match value {
+            "AUTOMATED" => BackupType::Automated,
+            "MANUAL" => BackupType::Manual,
+            _ => BackupType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for BackupType {
+    fn from(value: String) -> BackupType {
+        BackupType::from(value.as_str())
+    }
+}
+
+impl From<BackupType> for String {
+    fn from(value: BackupType) -> String {
+        match value {
+            BackupType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for BackupType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for BackupType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for BackupType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<BackupType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(BackupType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateBackupRequest {
     /// <p> A user-defined description of the backup. </p>
@@ -168,6 +372,44 @@ pub struct CreateBackupRequest {
     pub server_name: String,
 }
 
+impl CreateBackupRequest {
+    /// Returns a builder for `CreateBackupRequest`, checking its required members when built.
+    pub fn builder() -> CreateBackupRequestBuilder {
+        CreateBackupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateBackupRequest`](struct.CreateBackupRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateBackupRequestBuilder {
+    description: Option<String>,
+    server_name: Option<String>,
+}
+
+impl CreateBackupRequestBuilder {
+    /// Sets the [`description`](struct.CreateBackupRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.CreateBackupRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateBackupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateBackupRequest, ::rusoto_core::BuildError> {
+        Ok(CreateBackupRequest {
+            description: self.description,
+            server_name: self
+                .server_name
+                .ok_or(::rusoto_core::BuildError::missing("server_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateBackupResponse {
@@ -245,6 +487,170 @@ pub struct CreateServerRequest {
     pub subnet_ids: Option<Vec<String>>,
 }
 
+impl CreateServerRequest {
+    /// Returns a builder for `CreateServerRequest`, checking its required members when built.
+    pub fn builder() -> CreateServerRequestBuilder {
+        CreateServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateServerRequest`](struct.CreateServerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateServerRequestBuilder {
+    associate_public_ip_address: Option<bool>,
+    backup_id: Option<String>,
+    backup_retention_count: Option<i64>,
+    disable_automated_backup: Option<bool>,
+    engine: Option<String>,
+    engine_attributes: Option<Vec<EngineAttribute>>,
+    engine_model: Option<String>,
+    engine_version: Option<String>,
+    instance_profile_arn: Option<String>,
+    instance_type: Option<String>,
+    key_pair: Option<String>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    security_group_ids: Option<Vec<String>>,
+    server_name: Option<String>,
+    service_role_arn: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+}
+
+impl CreateServerRequestBuilder {
+    /// Sets the [`associate_public_ip_address`](struct.CreateServerRequest.html#structfield.associate_public_ip_address) member.
+    pub fn associate_public_ip_address<T: Into<bool>>(mut self, value: T) -> Self {
+        self.associate_public_ip_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_id`](struct.CreateServerRequest.html#structfield.backup_id) member.
+    pub fn backup_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_count`](struct.CreateServerRequest.html#structfield.backup_retention_count) member.
+    pub fn backup_retention_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disable_automated_backup`](struct.CreateServerRequest.html#structfield.disable_automated_backup) member.
+    pub fn disable_automated_backup<T: Into<bool>>(mut self, value: T) -> Self {
+        self.disable_automated_backup = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.CreateServerRequest.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_attributes`](struct.CreateServerRequest.html#structfield.engine_attributes) member.
+    pub fn engine_attributes<T: Into<Vec<EngineAttribute>>>(mut self, value: T) -> Self {
+        self.engine_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_model`](struct.CreateServerRequest.html#structfield.engine_model) member.
+    pub fn engine_model<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_model = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.CreateServerRequest.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_profile_arn`](struct.CreateServerRequest.html#structfield.instance_profile_arn) member.
+    pub fn instance_profile_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_profile_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_type`](struct.CreateServerRequest.html#structfield.instance_type) member.
+    pub fn instance_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_pair`](struct.CreateServerRequest.html#structfield.key_pair) member.
+    pub fn key_pair<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_pair = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.CreateServerRequest.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateServerRequest.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`security_group_ids`](struct.CreateServerRequest.html#structfield.security_group_ids) member.
+    pub fn security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.CreateServerRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_role_arn`](struct.CreateServerRequest.html#structfield.service_role_arn) member.
+    pub fn service_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.CreateServerRequest.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateServerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateServerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateServerRequest {
+            associate_public_ip_address: self.associate_public_ip_address,
+            backup_id: self.backup_id,
+            backup_retention_count: self.backup_retention_count,
+            disable_automated_backup: self.disable_automated_backup,
+            engine: self.engine,
+            engine_attributes: self.engine_attributes,
+            engine_model: self.engine_model,
+            engine_version: self.engine_version,
+            instance_profile_arn: self
+                .instance_profile_arn
+                .ok_or(::rusoto_core::BuildError::missing("instance_profile_arn"))?,
+            instance_type: self
+                .instance_type
+                .ok_or(::rusoto_core::BuildError::missing("instance_type"))?,
+            key_pair: self.key_pair,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            security_group_ids: self.security_group_ids,
+            server_name: self
+                .server_name
+                .ok_or(::rusoto_core::BuildError::missing("server_name"))?,
+            service_role_arn: self
+                .service_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("service_role_arn"))?,
+            subnet_ids: self.subnet_ids,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateServerResponse {
@@ -261,6 +667,36 @@ pub struct DeleteBackupRequest {
     pub backup_id: String,
 }
 
+impl DeleteBackupRequest {
+    /// Returns a builder for `DeleteBackupRequest`, checking its required members when built.
+    pub fn builder() -> DeleteBackupRequestBuilder {
+        DeleteBackupRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteBackupRequest`](struct.DeleteBackupRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteBackupRequestBuilder {
+    backup_id: Option<String>,
+}
+
+impl DeleteBackupRequestBuilder {
+    /// Sets the [`backup_id`](struct.DeleteBackupRequest.html#structfield.backup_id) member.
+    pub fn backup_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteBackupRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteBackupRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteBackupRequest {
+            backup_id: self
+                .backup_id
+                .ok_or(::rusoto_core::BuildError::missing("backup_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteBackupResponse {}
@@ -272,6 +708,36 @@ pub struct DeleteServerRequest {
     pub server_name: String,
 }
 
+impl DeleteServerRequest {
+    /// Returns a builder for `DeleteServerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteServerRequestBuilder {
+        DeleteServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteServerRequest`](struct.DeleteServerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteServerRequestBuilder {
+    server_name: Option<String>,
+}
+
+impl DeleteServerRequestBuilder {
+    /// Sets the [`server_name`](struct.DeleteServerRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteServerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteServerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteServerRequest {
+            server_name: self
+                .server_name
+                .ok_or(::rusoto_core::BuildError::missing("server_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteServerResponse {}
@@ -308,6 +774,58 @@ pub struct DescribeBackupsRequest {
     pub server_name: Option<String>,
 }
 
+impl DescribeBackupsRequest {
+    /// Returns a builder for `DescribeBackupsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeBackupsRequestBuilder {
+        DescribeBackupsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeBackupsRequest`](struct.DescribeBackupsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeBackupsRequestBuilder {
+    backup_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    server_name: Option<String>,
+}
+
+impl DescribeBackupsRequestBuilder {
+    /// Sets the [`backup_id`](struct.DescribeBackupsRequest.html#structfield.backup_id) member.
+    pub fn backup_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeBackupsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeBackupsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.DescribeBackupsRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeBackupsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeBackupsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeBackupsRequest {
+            backup_id: self.backup_id,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            server_name: self.server_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeBackupsResponse {
@@ -336,6 +854,52 @@ pub struct DescribeEventsRequest {
     pub server_name: String,
 }
 
+impl DescribeEventsRequest {
+    /// Returns a builder for `DescribeEventsRequest`, checking its required members when built.
+    pub fn builder() -> DescribeEventsRequestBuilder {
+        DescribeEventsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventsRequest`](struct.DescribeEventsRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    server_name: Option<String>,
+}
+
+impl DescribeEventsRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeEventsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeEventsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.DescribeEventsRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeEventsRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeEventsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            server_name: self
+                .server_name
+                .ok_or(::rusoto_core::BuildError::missing("server_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeEventsResponse {
@@ -359,6 +923,49 @@ pub struct DescribeNodeAssociationStatusRequest {
     pub server_name: String,
 }
 
+impl DescribeNodeAssociationStatusRequest {
+    /// Returns a builder for `DescribeNodeAssociationStatusRequest`, checking its required members when built.
+    pub fn builder() -> DescribeNodeAssociationStatusRequestBuilder {
+        DescribeNodeAssociationStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeNodeAssociationStatusRequest`](struct.DescribeNodeAssociationStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeNodeAssociationStatusRequestBuilder {
+    node_association_status_token: Option<String>,
+    server_name: Option<String>,
+}
+
+impl DescribeNodeAssociationStatusRequestBuilder {
+    /// Sets the [`node_association_status_token`](struct.DescribeNodeAssociationStatusRequest.html#structfield.node_association_status_token) member.
+    pub fn node_association_status_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.node_association_status_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.DescribeNodeAssociationStatusRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeNodeAssociationStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeNodeAssociationStatusRequest, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeNodeAssociationStatusRequest {
+            node_association_status_token: self.node_association_status_token.ok_or(
+                ::rusoto_core::BuildError::missing("node_association_status_token"),
+            )?,
+            server_name: self
+                .server_name
+                .ok_or(::rusoto_core::BuildError::missing("server_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeNodeAssociationStatusResponse {
@@ -369,7 +976,7 @@ pub struct DescribeNodeAssociationStatusResponse {
     /// <p><p>The status of the association or disassociation request. </p> <p class="title"> <b>Possible values:</b> </p> <ul> <li> <p> <code>SUCCESS</code>: The association or disassociation succeeded. </p> </li> <li> <p> <code>FAILED</code>: The association or disassociation failed. </p> </li> <li> <p> <code>IN_PROGRESS</code>: The association or disassociation is still in progress. </p> </li> </ul></p>
     #[serde(rename = "NodeAssociationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_association_status: Option<String>,
+    pub node_association_status: Option<NodeAssociationStatus>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -388,6 +995,50 @@ pub struct DescribeServersRequest {
     pub server_name: Option<String>,
 }
 
+impl DescribeServersRequest {
+    /// Returns a builder for `DescribeServersRequest`, checking its required members when built.
+    pub fn builder() -> DescribeServersRequestBuilder {
+        DescribeServersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeServersRequest`](struct.DescribeServersRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeServersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    server_name: Option<String>,
+}
+
+impl DescribeServersRequestBuilder {
+    /// Sets the [`max_results`](struct.DescribeServersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeServersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.DescribeServersRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeServersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeServersRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeServersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            server_name: self.server_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeServersResponse {
@@ -415,6 +1066,56 @@ pub struct DisassociateNodeRequest {
     pub server_name: String,
 }
 
+impl DisassociateNodeRequest {
+    /// Returns a builder for `DisassociateNodeRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateNodeRequestBuilder {
+        DisassociateNodeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateNodeRequest`](struct.DisassociateNodeRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateNodeRequestBuilder {
+    engine_attributes: Option<Vec<EngineAttribute>>,
+    node_name: Option<String>,
+    server_name: Option<String>,
+}
+
+impl DisassociateNodeRequestBuilder {
+    /// Sets the [`engine_attributes`](struct.DisassociateNodeRequest.html#structfield.engine_attributes) member.
+    pub fn engine_attributes<T: Into<Vec<EngineAttribute>>>(mut self, value: T) -> Self {
+        self.engine_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_name`](struct.DisassociateNodeRequest.html#structfield.node_name) member.
+    pub fn node_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.node_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.DisassociateNodeRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateNodeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateNodeRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateNodeRequest {
+            engine_attributes: self.engine_attributes,
+            node_name: self
+                .node_name
+                .ok_or(::rusoto_core::BuildError::missing("node_name"))?,
+            server_name: self
+                .server_name
+                .ok_or(::rusoto_core::BuildError::missing("server_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateNodeResponse {
@@ -451,6 +1152,56 @@ pub struct ExportServerEngineAttributeRequest {
     pub server_name: String,
 }
 
+impl ExportServerEngineAttributeRequest {
+    /// Returns a builder for `ExportServerEngineAttributeRequest`, checking its required members when built.
+    pub fn builder() -> ExportServerEngineAttributeRequestBuilder {
+        ExportServerEngineAttributeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ExportServerEngineAttributeRequest`](struct.ExportServerEngineAttributeRequest.html).
+#[derive(Default, Debug)]
+pub struct ExportServerEngineAttributeRequestBuilder {
+    export_attribute_name: Option<String>,
+    input_attributes: Option<Vec<EngineAttribute>>,
+    server_name: Option<String>,
+}
+
+impl ExportServerEngineAttributeRequestBuilder {
+    /// Sets the [`export_attribute_name`](struct.ExportServerEngineAttributeRequest.html#structfield.export_attribute_name) member.
+    pub fn export_attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.export_attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`input_attributes`](struct.ExportServerEngineAttributeRequest.html#structfield.input_attributes) member.
+    pub fn input_attributes<T: Into<Vec<EngineAttribute>>>(mut self, value: T) -> Self {
+        self.input_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.ExportServerEngineAttributeRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExportServerEngineAttributeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ExportServerEngineAttributeRequest, ::rusoto_core::BuildError> {
+        Ok(ExportServerEngineAttributeRequest {
+            export_attribute_name: self
+                .export_attribute_name
+                .ok_or(::rusoto_core::BuildError::missing("export_attribute_name"))?,
+            input_attributes: self.input_attributes,
+            server_name: self
+                .server_name
+                .ok_or(::rusoto_core::BuildError::missing("server_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ExportServerEngineAttributeResponse {
@@ -464,6 +1215,154 @@ pub struct ExportServerEngineAttributeResponse {
     pub server_name: Option<String>,
 }
 
+/// <p>The status of the most recent server maintenance run. Shows <code>SUCCESS</code> or <code>FAILED</code>. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MaintenanceStatus {
+    /// <p><code>SUCCESS</code></p>
+    Success,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl MaintenanceStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            MaintenanceStatus::Success => "SUCCESS",
+            MaintenanceStatus::Failed => "FAILED",
+            MaintenanceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for MaintenanceStatus {
+    fn from(value: &'a str) -> MaintenanceStatus {
+        match value {
+            "SUCCESS" => MaintenanceStatus::Success,
+            "FAILED" => MaintenanceStatus::Failed,
+            _ => MaintenanceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for MaintenanceStatus {
+    fn from(value: String) -> MaintenanceStatus {
+        MaintenanceStatus::from(value.as_str())
+    }
+}
+
+impl From<MaintenanceStatus> for String {
+    fn from(value: MaintenanceStatus) -> String {
+        match value {
+            MaintenanceStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MaintenanceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for MaintenanceStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for MaintenanceStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<MaintenanceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(MaintenanceStatus::from(value))
+    }
+}
+
+/// <p>The status of the association or disassociation request. </p> <p class="title"> <b>Possible values:</b> </p> <ul> <li> <p> <code>SUCCESS</code>: The association or disassociation succeeded. </p> </li> <li> <p> <code>FAILED</code>: The association or disassociation failed. </p> </li> <li> <p> <code>IN_PROGRESS</code>: The association or disassociation is still in progress. </p> </li> </ul>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NodeAssociationStatus {
+    /// <p><code>SUCCESS</code> - The association or disassociation succeeded.</p>
+    Success,
+    /// <p><code>FAILED</code> - The association or disassociation failed.</p>
+    Failed,
+    /// <p><code>IN_PROGRESS</code> - The association or disassociation is still in progress.</p>
+    InProgress,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl NodeAssociationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            NodeAssociationStatus::Success => "SUCCESS",
+            NodeAssociationStatus::Failed => "FAILED",
+            NodeAssociationStatus::InProgress => "IN_PROGRESS",
+            NodeAssociationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for NodeAssociationStatus {
+    fn from(value: &'a str) -> NodeAssociationStatus {
+        match value {
+            "SUCCESS" => NodeAssociationStatus::Success,
+            "FAILED" => NodeAssociationStatus::Failed,
+            "IN_PROGRESS" => NodeAssociationStatus::InProgress,
+            _ => NodeAssociationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for NodeAssociationStatus {
+    fn from(value: String) -> NodeAssociationStatus {
+        NodeAssociationStatus::from(value.as_str())
+    }
+}
+
+impl From<NodeAssociationStatus> for String {
+    fn from(value: NodeAssociationStatus) -> String {
+        match value {
+            NodeAssociationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for NodeAssociationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for NodeAssociationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for NodeAssociationStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<NodeAssociationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(NodeAssociationStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RestoreServerRequest {
     /// <p> The ID of the backup that you want to use to restore a server. </p>
@@ -482,6 +1381,62 @@ pub struct RestoreServerRequest {
     pub server_name: String,
 }
 
+impl RestoreServerRequest {
+    /// Returns a builder for `RestoreServerRequest`, checking its required members when built.
+    pub fn builder() -> RestoreServerRequestBuilder {
+        RestoreServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreServerRequest`](struct.RestoreServerRequest.html).
+#[derive(Default, Debug)]
+pub struct RestoreServerRequestBuilder {
+    backup_id: Option<String>,
+    instance_type: Option<String>,
+    key_pair: Option<String>,
+    server_name: Option<String>,
+}
+
+impl RestoreServerRequestBuilder {
+    /// Sets the [`backup_id`](struct.RestoreServerRequest.html#structfield.backup_id) member.
+    pub fn backup_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.backup_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`instance_type`](struct.RestoreServerRequest.html#structfield.instance_type) member.
+    pub fn instance_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.instance_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`key_pair`](struct.RestoreServerRequest.html#structfield.key_pair) member.
+    pub fn key_pair<T: Into<String>>(mut self, value: T) -> Self {
+        self.key_pair = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.RestoreServerRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreServerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RestoreServerRequest, ::rusoto_core::BuildError> {
+        Ok(RestoreServerRequest {
+            backup_id: self
+                .backup_id
+                .ok_or(::rusoto_core::BuildError::missing("backup_id"))?,
+            instance_type: self.instance_type,
+            key_pair: self.key_pair,
+            server_name: self
+                .server_name
+                .ok_or(::rusoto_core::BuildError::missing("server_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RestoreServerResponse {}
@@ -504,8 +1459,9 @@ pub struct Server {
     pub cloud_formation_stack_arn: Option<String>,
     /// <p>Time stamp of server creation. Example <code>2016-07-29T13:38:47.520Z</code> </p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>Disables automated backups. The number of stored backups is dependent on the value of PreferredBackupCount. </p>
     #[serde(rename = "DisableAutomatedBackup")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -545,7 +1501,7 @@ pub struct Server {
     /// <p>The status of the most recent server maintenance run. Shows <code>SUCCESS</code> or <code>FAILED</code>. </p>
     #[serde(rename = "MaintenanceStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maintenance_status: Option<String>,
+    pub maintenance_status: Option<MaintenanceStatus>,
     /// <p>The preferred backup period specified for the server. </p>
     #[serde(rename = "PreferredBackupWindow")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -573,7 +1529,7 @@ pub struct Server {
     /// <p> The server's status. This field displays the states of actions in progress, such as creating, running, or backing up the server, as well as the server's health state. </p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ServerStatus>,
     /// <p> Depending on the server status, this field has either a human-readable message (such as a create or backup error), or an escaped block of JSON (used for health check results). </p>
     #[serde(rename = "StatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -590,8 +1546,9 @@ pub struct Server {
 pub struct ServerEvent {
     /// <p>The time when the event occurred. </p>
     #[serde(rename = "CreatedAt")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<f64>,
+    pub created_at: Option<EpochTimestamp>,
     /// <p>The Amazon S3 URL of the event's log file.</p>
     #[serde(rename = "LogUrl")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -606,6 +1563,122 @@ pub struct ServerEvent {
     pub server_name: Option<String>,
 }
 
+/// <p> The server's status. This field displays the states of actions in progress, such as creating, running, or backing up the server, as well as the server's health state. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServerStatus {
+    /// <p><code>BACKING_UP</code></p>
+    BackingUp,
+    /// <p><code>CONNECTION_LOST</code></p>
+    ConnectionLost,
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>MODIFYING</code></p>
+    Modifying,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>HEALTHY</code></p>
+    Healthy,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>RESTORING</code></p>
+    Restoring,
+    /// <p><code>SETUP</code></p>
+    Setup,
+    /// <p><code>UNDER_MAINTENANCE</code></p>
+    UnderMaintenance,
+    /// <p><code>UNHEALTHY</code></p>
+    Unhealthy,
+    /// <p><code>TERMINATED</code></p>
+    Terminated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServerStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServerStatus::BackingUp => "BACKING_UP",
+            ServerStatus::ConnectionLost => "CONNECTION_LOST",
+            ServerStatus::Creating => "CREATING",
+            ServerStatus::Deleting => "DELETING",
+            ServerStatus::Modifying => "MODIFYING",
+            ServerStatus::Failed => "FAILED",
+            ServerStatus::Healthy => "HEALTHY",
+            ServerStatus::Running => "RUNNING",
+            ServerStatus::Restoring => "RESTORING",
+            ServerStatus::Setup => "SETUP",
+            ServerStatus::UnderMaintenance => "UNDER_MAINTENANCE",
+            ServerStatus::Unhealthy => "UNHEALTHY",
+            ServerStatus::Terminated => "TERMINATED",
+            ServerStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServerStatus {
+    fn from(value: &'a str) -> ServerStatus {
+        match value {
+            "BACKING_UP" => ServerStatus::BackingUp,
+            "CONNECTION_LOST" => ServerStatus::ConnectionLost,
+            "CREATING" => ServerStatus::Creating,
+            "DELETING" => ServerStatus::Deleting,
+            "MODIFYING" => ServerStatus::Modifying,
+            "FAILED" => ServerStatus::Failed,
+            "HEALTHY" => ServerStatus::Healthy,
+            "RUNNING" => ServerStatus::Running,
+            "RESTORING" => ServerStatus::Restoring,
+            "SETUP" => ServerStatus::Setup,
+            "UNDER_MAINTENANCE" => ServerStatus::UnderMaintenance,
+            "UNHEALTHY" => ServerStatus::Unhealthy,
+            "TERMINATED" => ServerStatus::Terminated,
+            _ => ServerStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServerStatus {
+    fn from(value: String) -> ServerStatus {
+        ServerStatus::from(value.as_str())
+    }
+}
+
+impl From<ServerStatus> for String {
+    fn from(value: ServerStatus) -> String {
+        match value {
+            ServerStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ServerStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServerStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ServerStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ServerStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct StartMaintenanceRequest {
     /// <p>Engine attributes that are specific to the server on which you want to run maintenance. </p>
@@ -617,6 +1690,46 @@ pub struct StartMaintenanceRequest {
     pub server_name: String,
 }
 
+impl StartMaintenanceRequest {
+    /// Returns a builder for `StartMaintenanceRequest`, checking its required members when built.
+    pub fn builder() -> StartMaintenanceRequestBuilder {
+        StartMaintenanceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartMaintenanceRequest`](struct.StartMaintenanceRequest.html).
+#[derive(Default, Debug)]
+pub struct StartMaintenanceRequestBuilder {
+    engine_attributes: Option<Vec<EngineAttribute>>,
+    server_name: Option<String>,
+}
+
+impl StartMaintenanceRequestBuilder {
+    /// Sets the [`engine_attributes`](struct.StartMaintenanceRequest.html#structfield.engine_attributes) member.
+    pub fn engine_attributes<T: Into<Vec<EngineAttribute>>>(mut self, value: T) -> Self {
+        self.engine_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.StartMaintenanceRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartMaintenanceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartMaintenanceRequest, ::rusoto_core::BuildError> {
+        Ok(StartMaintenanceRequest {
+            engine_attributes: self.engine_attributes,
+            server_name: self
+                .server_name
+                .ok_or(::rusoto_core::BuildError::missing("server_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartMaintenanceResponse {
@@ -640,6 +1753,56 @@ pub struct UpdateServerEngineAttributesRequest {
     pub server_name: String,
 }
 
+impl UpdateServerEngineAttributesRequest {
+    /// Returns a builder for `UpdateServerEngineAttributesRequest`, checking its required members when built.
+    pub fn builder() -> UpdateServerEngineAttributesRequestBuilder {
+        UpdateServerEngineAttributesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateServerEngineAttributesRequest`](struct.UpdateServerEngineAttributesRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateServerEngineAttributesRequestBuilder {
+    attribute_name: Option<String>,
+    attribute_value: Option<String>,
+    server_name: Option<String>,
+}
+
+impl UpdateServerEngineAttributesRequestBuilder {
+    /// Sets the [`attribute_name`](struct.UpdateServerEngineAttributesRequest.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`attribute_value`](struct.UpdateServerEngineAttributesRequest.html#structfield.attribute_value) member.
+    pub fn attribute_value<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_value = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.UpdateServerEngineAttributesRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateServerEngineAttributesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateServerEngineAttributesRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateServerEngineAttributesRequest {
+            attribute_name: self
+                .attribute_name
+                .ok_or(::rusoto_core::BuildError::missing("attribute_name"))?,
+            attribute_value: self.attribute_value,
+            server_name: self
+                .server_name
+                .ok_or(::rusoto_core::BuildError::missing("server_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateServerEngineAttributesResponse {
@@ -670,6 +1833,68 @@ pub struct UpdateServerRequest {
     pub server_name: String,
 }
 
+impl UpdateServerRequest {
+    /// Returns a builder for `UpdateServerRequest`, checking its required members when built.
+    pub fn builder() -> UpdateServerRequestBuilder {
+        UpdateServerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateServerRequest`](struct.UpdateServerRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateServerRequestBuilder {
+    backup_retention_count: Option<i64>,
+    disable_automated_backup: Option<bool>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    server_name: Option<String>,
+}
+
+impl UpdateServerRequestBuilder {
+    /// Sets the [`backup_retention_count`](struct.UpdateServerRequest.html#structfield.backup_retention_count) member.
+    pub fn backup_retention_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disable_automated_backup`](struct.UpdateServerRequest.html#structfield.disable_automated_backup) member.
+    pub fn disable_automated_backup<T: Into<bool>>(mut self, value: T) -> Self {
+        self.disable_automated_backup = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.UpdateServerRequest.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.UpdateServerRequest.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`server_name`](struct.UpdateServerRequest.html#structfield.server_name) member.
+    pub fn server_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.server_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateServerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateServerRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateServerRequest {
+            backup_retention_count: self.backup_retention_count,
+            disable_automated_backup: self.disable_automated_backup,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            server_name: self
+                .server_name
+                .ok_or(::rusoto_core::BuildError::missing("server_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateServerResponse {
@@ -1408,6 +2633,111 @@ pub trait OpsWorksCM {
         input: UpdateServerEngineAttributesRequest,
     ) -> RusotoFuture<UpdateServerEngineAttributesResponse, UpdateServerEngineAttributesError>;
 }
+impl<T: ?Sized + OpsWorksCM> OpsWorksCM for ::std::sync::Arc<T> {
+    fn associate_node(
+        &self,
+        input: AssociateNodeRequest,
+    ) -> RusotoFuture<AssociateNodeResponse, AssociateNodeError> {
+        (**self).associate_node(input)
+    }
+    fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> RusotoFuture<CreateBackupResponse, CreateBackupError> {
+        (**self).create_backup(input)
+    }
+    fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> RusotoFuture<CreateServerResponse, CreateServerError> {
+        (**self).create_server(input)
+    }
+    fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> RusotoFuture<DeleteBackupResponse, DeleteBackupError> {
+        (**self).delete_backup(input)
+    }
+    fn delete_server(
+        &self,
+        input: DeleteServerRequest,
+    ) -> RusotoFuture<DeleteServerResponse, DeleteServerError> {
+        (**self).delete_server(input)
+    }
+    fn describe_account_attributes(
+        &self,
+    ) -> RusotoFuture<DescribeAccountAttributesResponse, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes()
+    }
+    fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> RusotoFuture<DescribeBackupsResponse, DescribeBackupsError> {
+        (**self).describe_backups(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> RusotoFuture<DescribeEventsResponse, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_node_association_status(
+        &self,
+        input: DescribeNodeAssociationStatusRequest,
+    ) -> RusotoFuture<DescribeNodeAssociationStatusResponse, DescribeNodeAssociationStatusError>
+    {
+        (**self).describe_node_association_status(input)
+    }
+    fn describe_servers(
+        &self,
+        input: DescribeServersRequest,
+    ) -> RusotoFuture<DescribeServersResponse, DescribeServersError> {
+        (**self).describe_servers(input)
+    }
+    fn disassociate_node(
+        &self,
+        input: DisassociateNodeRequest,
+    ) -> RusotoFuture<DisassociateNodeResponse, DisassociateNodeError> {
+        (**self).disassociate_node(input)
+    }
+    fn export_server_engine_attribute(
+        &self,
+        input: ExportServerEngineAttributeRequest,
+    ) -> RusotoFuture<ExportServerEngineAttributeResponse, ExportServerEngineAttributeError> {
+        (**self).export_server_engine_attribute(input)
+    }
+    fn restore_server(
+        &self,
+        input: RestoreServerRequest,
+    ) -> RusotoFuture<RestoreServerResponse, RestoreServerError> {
+        (**self).restore_server(input)
+    }
+    fn start_maintenance(
+        &self,
+        input: StartMaintenanceRequest,
+    ) -> RusotoFuture<StartMaintenanceResponse, StartMaintenanceError> {
+        (**self).start_maintenance(input)
+    }
+    fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> RusotoFuture<UpdateServerResponse, UpdateServerError> {
+        (**self).update_server(input)
+    }
+    fn update_server_engine_attributes(
+        &self,
+        input: UpdateServerEngineAttributesRequest,
+    ) -> RusotoFuture<UpdateServerEngineAttributesResponse, UpdateServerEngineAttributesError> {
+        (**self).update_server_engine_attributes(input)
+    }
+}
+/// The error codes OpsWorksCM retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the OpsWorksCM API.
 #[derive(Clone)]
 pub struct OpsWorksCMClient {
@@ -1423,6 +2753,27 @@ impl OpsWorksCMClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_OPSWORKSCM`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> OpsWorksCMClient {
+        Self::new(region::Region::from_env_for_service("OPSWORKSCM"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `opsworks-cm`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> OpsWorksCMClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("opsworks-cm", "OPSWORKSCM"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1441,7 +2792,54 @@ impl OpsWorksCMClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksCMClient {
-        OpsWorksCMClient { client, region }
+        OpsWorksCMClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "opsworks-cm")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "opsworks-cm",
+            "opsworks-cm",
+            "OPSWORKSCM",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `OpsWorksCM` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn OpsWorksCM + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for OpsWorksCMClient {
+    fn service_name(&self) -> &'static str {
+        "OpsWorksCM"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "opsworks-cm"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> OpsWorksCMClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,42 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Organizations clients.
+//!
+//! Makes a single read-only `ListAwsServiceAccessForOrganization` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_organizations::{
+    ListAWSServiceAccessForOrganizationRequest, Organizations, OrganizationsClient,
+};
+
+fn main() {
+    let client = OrganizationsClient::from_env();
+    match client
+        .list_aws_service_access_for_organization(
+            ListAWSServiceAccessForOrganizationRequest::default(),
+        )
+        .sync()
+    {
+        Ok(_) => println!("ListAwsServiceAccessForOrganization succeeded"),
+        Err(error) => {
+            eprintln!("ListAwsServiceAccessForOrganization failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AcceptHandshakeRequest {
@@ -30,6 +31,36 @@ pub struct AcceptHandshakeRequest {
     pub handshake_id: String,
 }
 
+impl AcceptHandshakeRequest {
+    /// Returns a builder for `AcceptHandshakeRequest`, checking its required members when built.
+    pub fn builder() -> AcceptHandshakeRequestBuilder {
+        AcceptHandshakeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AcceptHandshakeRequest`](struct.AcceptHandshakeRequest.html).
+#[derive(Default, Debug)]
+pub struct AcceptHandshakeRequestBuilder {
+    handshake_id: Option<String>,
+}
+
+impl AcceptHandshakeRequestBuilder {
+    /// Sets the [`handshake_id`](struct.AcceptHandshakeRequest.html#structfield.handshake_id) member.
+    pub fn handshake_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.handshake_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AcceptHandshakeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AcceptHandshakeRequest, ::rusoto_core::BuildError> {
+        Ok(AcceptHandshakeRequest {
+            handshake_id: self
+                .handshake_id
+                .ok_or(::rusoto_core::BuildError::missing("handshake_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AcceptHandshakeResponse {
@@ -58,11 +89,12 @@ pub struct Account {
     /// <p>The method by which the account joined the organization.</p>
     #[serde(rename = "JoinedMethod")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub joined_method: Option<String>,
+    pub joined_method: Option<AccountJoinedMethod>,
     /// <p>The date the account became a part of the organization.</p>
     #[serde(rename = "JoinedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub joined_timestamp: Option<f64>,
+    pub joined_timestamp: Option<EpochTimestamp>,
     /// <p>The friendly name of the account.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> that is used to validate this parameter is a string of any of the characters in the ASCII character range.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -70,7 +102,239 @@ pub struct Account {
     /// <p>The status of the account in the organization.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<AccountStatus>,
+}
+
+/// <p>The method by which the account joined the organization.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccountJoinedMethod {
+    /// <p><code>INVITED</code></p>
+    Invited,
+    /// <p><code>CREATED</code></p>
+    Created,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AccountJoinedMethod {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountJoinedMethod::Invited => "INVITED",
+            AccountJoinedMethod::Created => "CREATED",
+            AccountJoinedMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AccountJoinedMethod {
+    fn from(value: &'a str) -> AccountJoinedMethod {
+        match value {
+            "INVITED" => AccountJoinedMethod::Invited,
+            "CREATED" => AccountJoinedMethod::Created,
+            _ => AccountJoinedMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountJoinedMethod {
+    fn from(value: String) -> AccountJoinedMethod {
+        AccountJoinedMethod::from(value.as_str())
+    }
+}
+
+impl From<AccountJoinedMethod> for String {
+    fn from(value: AccountJoinedMethod) -> String {
+        match value {
+            AccountJoinedMethod::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AccountJoinedMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AccountJoinedMethod {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccountJoinedMethod {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AccountJoinedMethod, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AccountJoinedMethod::from(value))
+    }
+}
+
+/// <p>The status of the account in the organization.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AccountStatus {
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>SUSPENDED</code></p>
+    Suspended,
+    /// <p><code>PENDING_CLOSURE</code></p>
+    PendingClosure,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl AccountStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            AccountStatus::Active => "ACTIVE",
+            AccountStatus::Suspended => "SUSPENDED",
+            AccountStatus::PendingClosure => "PENDING_CLOSURE",
+            AccountStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for AccountStatus {
+    fn from(value: &'a str) -> AccountStatus {
+        match value {
+            "ACTIVE" => AccountStatus::Active,
+            "SUSPENDED" => AccountStatus::Suspended,
+            "PENDING_CLOSURE" => AccountStatus::PendingClosure,
+            _ => AccountStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for AccountStatus {
+    fn from(value: String) -> AccountStatus {
+        AccountStatus::from(value.as_str())
+    }
+}
+
+impl From<AccountStatus> for String {
+    fn from(value: AccountStatus) -> String {
+        match value {
+            AccountStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AccountStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for AccountStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for AccountStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<AccountStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(AccountStatus::from(value))
+    }
+}
+
+/// <p><p>The type of handshake, indicating what action occurs when the recipient accepts the handshake. The following handshake types are supported:</p> <ul> <li> <p> <b>INVITE</b>: This type of handshake represents a request to join an organization. It is always sent from the master account to only non-member accounts.</p> </li> <li> <p> <b>ENABLE<em>ALL</em>FEATURES</b>: This type of handshake represents a request to enable all features in an organization. It is always sent from the master account to only <i>invited</i> member accounts. Created accounts do not receive this because those accounts were created by the organization&#39;s master account and approval is inferred.</p> </li> <li> <p> <b>APPROVE<em>ALL</em>FEATURES</b>: This type of handshake is sent from the Organizations service when all member accounts have approved the <code>ENABLE<em>ALL</em>FEATURES</code> invitation. It is sent only to the master account and signals the master that it can finalize the process to enable all features.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActionType {
+    /// <p><code>INVITE</code></p>
+    Invite,
+    /// <p><code>ENABLE_ALL_FEATURES</code></p>
+    EnableAllFeatures,
+    /// <p><code>APPROVE_ALL_FEATURES</code></p>
+    ApproveAllFeatures,
+    /// <p><code>ADD_ORGANIZATIONS_SERVICE_LINKED_ROLE</code></p>
+    AddOrganizationsServiceLinkedRole,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActionType::Invite => "INVITE",
+            ActionType::EnableAllFeatures => "ENABLE_ALL_FEATURES",
+            ActionType::ApproveAllFeatures => "APPROVE_ALL_FEATURES",
+            ActionType::AddOrganizationsServiceLinkedRole => {
+                "ADD_ORGANIZATIONS_SERVICE_LINKED_ROLE"
+            }
+            ActionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActionType {
+    fn from(value: &'a str) -> ActionType {
+        match value {
+            "INVITE" => ActionType::Invite,
+            "ENABLE_ALL_FEATURES" => ActionType::EnableAllFeatures,
+            "APPROVE_ALL_FEATURES" => ActionType::ApproveAllFeatures,
+            "ADD_ORGANIZATIONS_SERVICE_LINKED_ROLE" => {
+                ActionType::AddOrganizationsServiceLinkedRole
+            }
+            _ => ActionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActionType {
+    fn from(value: String) -> ActionType {
+        ActionType::from(value.as_str())
+    }
+}
+
+impl From<ActionType> for String {
+    fn from(value: ActionType) -> String {
+        match value {
+            ActionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ActionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ActionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ActionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ActionType::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -83,6 +347,46 @@ pub struct AttachPolicyRequest {
     pub target_id: String,
 }
 
+impl AttachPolicyRequest {
+    /// Returns a builder for `AttachPolicyRequest`, checking its required members when built.
+    pub fn builder() -> AttachPolicyRequestBuilder {
+        AttachPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AttachPolicyRequest`](struct.AttachPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct AttachPolicyRequestBuilder {
+    policy_id: Option<String>,
+    target_id: Option<String>,
+}
+
+impl AttachPolicyRequestBuilder {
+    /// Sets the [`policy_id`](struct.AttachPolicyRequest.html#structfield.policy_id) member.
+    pub fn policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_id`](struct.AttachPolicyRequest.html#structfield.target_id) member.
+    pub fn target_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AttachPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<AttachPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(AttachPolicyRequest {
+            policy_id: self
+                .policy_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_id"))?,
+            target_id: self
+                .target_id
+                .ok_or(::rusoto_core::BuildError::missing("target_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CancelHandshakeRequest {
     /// <p>The unique identifier (ID) of the handshake that you want to cancel. You can get the ID from the <a>ListHandshakesForOrganization</a> operation.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
@@ -90,6 +394,36 @@ pub struct CancelHandshakeRequest {
     pub handshake_id: String,
 }
 
+impl CancelHandshakeRequest {
+    /// Returns a builder for `CancelHandshakeRequest`, checking its required members when built.
+    pub fn builder() -> CancelHandshakeRequestBuilder {
+        CancelHandshakeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CancelHandshakeRequest`](struct.CancelHandshakeRequest.html).
+#[derive(Default, Debug)]
+pub struct CancelHandshakeRequestBuilder {
+    handshake_id: Option<String>,
+}
+
+impl CancelHandshakeRequestBuilder {
+    /// Sets the [`handshake_id`](struct.CancelHandshakeRequest.html#structfield.handshake_id) member.
+    pub fn handshake_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.handshake_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelHandshakeRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelHandshakeRequest, ::rusoto_core::BuildError> {
+        Ok(CancelHandshakeRequest {
+            handshake_id: self
+                .handshake_id
+                .ok_or(::rusoto_core::BuildError::missing("handshake_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CancelHandshakeResponse {
@@ -110,7 +444,221 @@ pub struct Child {
     /// <p>The type of this child entity.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ChildType>,
+}
+
+/// <p>The type of this child entity.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChildType {
+    /// <p><code>ACCOUNT</code></p>
+    Account,
+    /// <p><code>ORGANIZATIONAL_UNIT</code></p>
+    OrganizationalUnit,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ChildType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChildType::Account => "ACCOUNT",
+            ChildType::OrganizationalUnit => "ORGANIZATIONAL_UNIT",
+            ChildType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ChildType {
+    fn from(value: &'a str) -> ChildType {
+        match value {
+            "ACCOUNT" => ChildType::Account,
+            "ORGANIZATIONAL_UNIT" => ChildType::OrganizationalUnit,
+            _ => ChildType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ChildType {
+    fn from(value: String) -> ChildType {
+        ChildType::from(value.as_str())
+    }
+}
+
+impl From<ChildType> for String {
+    fn from(value: ChildType) -> String {
+        match value {
+            ChildType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ChildType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ChildType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ChildType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ChildType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ChildType::from(value))
+    }
+}
+
+/// <p><p>If the request failed, a description of the reason for the failure.</p> <ul> <li> <p>ACCOUNT<em>LIMIT</em>EXCEEDED: The account could not be created because you have reached the limit on the number of accounts in your organization.</p> </li> <li> <p>EMAIL<em>ALREADY</em>EXISTS: The account could not be created because another AWS account with that email address already exists.</p> </li> <li> <p>INVALID<em>ADDRESS: The account could not be created because the address you provided is not valid.</p> </li> <li> <p>INVALID</em>EMAIL: The account could not be created because the email address you provided is not valid.</p> </li> <li> <p>INTERNAL_FAILURE: The account could not be created because of an internal failure. Try again later. If the problem persists, contact Customer Support.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CreateAccountFailureReason {
+    /// <p><code>ACCOUNT_LIMIT_EXCEEDED</code></p>
+    AccountLimitExceeded,
+    /// <p><code>EMAIL_ALREADY_EXISTS</code></p>
+    EmailAlreadyExists,
+    /// <p><code>INVALID_ADDRESS</code></p>
+    InvalidAddress,
+    /// <p><code>INVALID_EMAIL</code></p>
+    InvalidEmail,
+    /// <p><code>CONCURRENT_ACCOUNT_MODIFICATION</code></p>
+    ConcurrentAccountModification,
+    /// <p><code>INTERNAL_FAILURE</code></p>
+    InternalFailure,
+    /// <p><code>GOVCLOUD_ACCOUNT_ALREADY_EXISTS</code></p>
+    GovcloudAccountAlreadyExists,
+    /// <p><code>MISSING_BUSINESS_VALIDATION</code></p>
+    MissingBusinessValidation,
+    /// <p><code>FAILED_BUSINESS_VALIDATION</code></p>
+    FailedBusinessValidation,
+    /// <p><code>PENDING_BUSINESS_VALIDATION</code></p>
+    PendingBusinessValidation,
+    /// <p><code>INVALID_IDENTITY_FOR_BUSINESS_VALIDATION</code></p>
+    InvalidIdentityForBusinessValidation,
+    /// <p><code>UNKNOWN_BUSINESS_VALIDATION</code></p>
+    UnknownBusinessValidation,
+    /// <p><code>MISSING_PAYMENT_INSTRUMENT</code></p>
+    MissingPaymentInstrument,
+    /// <p><code>INVALID_PAYMENT_INSTRUMENT</code></p>
+    InvalidPaymentInstrument,
+    /// <p><code>UPDATE_EXISTING_RESOURCE_POLICY_WITH_TAGS_NOT_SUPPORTED</code></p>
+    UpdateExistingResourcePolicyWithTagsNotSupported,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CreateAccountFailureReason {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CreateAccountFailureReason::AccountLimitExceeded => "ACCOUNT_LIMIT_EXCEEDED",
+            CreateAccountFailureReason::EmailAlreadyExists => "EMAIL_ALREADY_EXISTS",
+            CreateAccountFailureReason::InvalidAddress => "INVALID_ADDRESS",
+            CreateAccountFailureReason::InvalidEmail => "INVALID_EMAIL",
+            CreateAccountFailureReason::ConcurrentAccountModification => {
+                "CONCURRENT_ACCOUNT_MODIFICATION"
+            }
+            CreateAccountFailureReason::InternalFailure => "INTERNAL_FAILURE",
+            CreateAccountFailureReason::GovcloudAccountAlreadyExists => {
+                "GOVCLOUD_ACCOUNT_ALREADY_EXISTS"
+            }
+            CreateAccountFailureReason::MissingBusinessValidation => "MISSING_BUSINESS_VALIDATION",
+            CreateAccountFailureReason::FailedBusinessValidation => "FAILED_BUSINESS_VALIDATION",
+            CreateAccountFailureReason::PendingBusinessValidation => "PENDING_BUSINESS_VALIDATION",
+            CreateAccountFailureReason::InvalidIdentityForBusinessValidation => {
+                "INVALID_IDENTITY_FOR_BUSINESS_VALIDATION"
+            }
+            CreateAccountFailureReason::UnknownBusinessValidation => "UNKNOWN_BUSINESS_VALIDATION",
+            CreateAccountFailureReason::MissingPaymentInstrument => "MISSING_PAYMENT_INSTRUMENT",
+            CreateAccountFailureReason::InvalidPaymentInstrument => "INVALID_PAYMENT_INSTRUMENT",
+            CreateAccountFailureReason::UpdateExistingResourcePolicyWithTagsNotSupported => {
+                "UPDATE_EXISTING_RESOURCE_POLICY_WITH_TAGS_NOT_SUPPORTED"
+            }
+            CreateAccountFailureReason::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CreateAccountFailureReason {
+    fn from(value: &'a str) -> CreateAccountFailureReason {
+        match value {
+            "ACCOUNT_LIMIT_EXCEEDED" => CreateAccountFailureReason::AccountLimitExceeded,
+            "EMAIL_ALREADY_EXISTS" => CreateAccountFailureReason::EmailAlreadyExists,
+            "INVALID_ADDRESS" => CreateAccountFailureReason::InvalidAddress,
+            "INVALID_EMAIL" => CreateAccountFailureReason::InvalidEmail,
+            "CONCURRENT_ACCOUNT_MODIFICATION" => {
+                CreateAccountFailureReason::ConcurrentAccountModification
+            }
+            "INTERNAL_FAILURE" => CreateAccountFailureReason::InternalFailure,
+            "GOVCLOUD_ACCOUNT_ALREADY_EXISTS" => {
+                CreateAccountFailureReason::GovcloudAccountAlreadyExists
+            }
+            "MISSING_BUSINESS_VALIDATION" => CreateAccountFailureReason::MissingBusinessValidation,
+            "FAILED_BUSINESS_VALIDATION" => CreateAccountFailureReason::FailedBusinessValidation,
+            "PENDING_BUSINESS_VALIDATION" => CreateAccountFailureReason::PendingBusinessValidation,
+            "INVALID_IDENTITY_FOR_BUSINESS_VALIDATION" => {
+                CreateAccountFailureReason::InvalidIdentityForBusinessValidation
+            }
+            "UNKNOWN_BUSINESS_VALIDATION" => CreateAccountFailureReason::UnknownBusinessValidation,
+            "MISSING_PAYMENT_INSTRUMENT" => CreateAccountFailureReason::MissingPaymentInstrument,
+            "INVALID_PAYMENT_INSTRUMENT" => CreateAccountFailureReason::InvalidPaymentInstrument,
+            "UPDATE_EXISTING_RESOURCE_POLICY_WITH_TAGS_NOT_SUPPORTED" => {
+                CreateAccountFailureReason::UpdateExistingResourcePolicyWithTagsNotSupported
+            }
+            _ => CreateAccountFailureReason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CreateAccountFailureReason {
+    fn from(value: String) -> CreateAccountFailureReason {
+        CreateAccountFailureReason::from(value.as_str())
+    }
+}
+
+impl From<CreateAccountFailureReason> for String {
+    fn from(value: CreateAccountFailureReason) -> String {
+        match value {
+            CreateAccountFailureReason::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CreateAccountFailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CreateAccountFailureReason {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CreateAccountFailureReason {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<CreateAccountFailureReason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CreateAccountFailureReason::from(value))
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -124,13 +672,69 @@ pub struct CreateAccountRequest {
     /// <p>If set to <code>ALLOW</code>, the new account enables IAM users to access account billing information <i>if</i> they have the required permissions. If set to <code>DENY</code>, only the root user of the new account can access account billing information. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>If you don't specify this parameter, the value defaults to <code>ALLOW</code>, and IAM users and roles with the required permissions can access billing information for the new account.</p>
     #[serde(rename = "IamUserAccessToBilling")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub iam_user_access_to_billing: Option<String>,
+    pub iam_user_access_to_billing: Option<IAMUserAccessToBilling>,
     /// <p>(Optional)</p> <p>The name of an IAM role that AWS Organizations automatically preconfigures in the new member account. This role trusts the master account, allowing users in the master account to assume the role, as permitted by the master account administrator. The role has administrator permissions in the new member account.</p> <p>If you don't specify this parameter, the role name defaults to <code>OrganizationAccountAccessRole</code>.</p> <p>For more information about how to use this role to access the member account, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_access.html#orgs_manage_accounts_create-cross-account-role">Accessing and Administering the Member Accounts in Your Organization</a> in the <i>AWS Organizations User Guide</i>, and steps 2 and 3 in <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/tutorial_cross-account-with-roles.html">Tutorial: Delegate Access Across AWS Accounts Using IAM Roles</a> in the <i>IAM User Guide.</i> </p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> that is used to validate this parameter is a string of characters that can consist of uppercase letters, lowercase letters, digits with no spaces, and any of the following characters: =,.@-</p>
     #[serde(rename = "RoleName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role_name: Option<String>,
 }
 
+impl CreateAccountRequest {
+    /// Returns a builder for `CreateAccountRequest`, checking its required members when built.
+    pub fn builder() -> CreateAccountRequestBuilder {
+        CreateAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateAccountRequest`](struct.CreateAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateAccountRequestBuilder {
+    account_name: Option<String>,
+    email: Option<String>,
+    iam_user_access_to_billing: Option<IAMUserAccessToBilling>,
+    role_name: Option<String>,
+}
+
+impl CreateAccountRequestBuilder {
+    /// Sets the [`account_name`](struct.CreateAccountRequest.html#structfield.account_name) member.
+    pub fn account_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email`](struct.CreateAccountRequest.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_user_access_to_billing`](struct.CreateAccountRequest.html#structfield.iam_user_access_to_billing) member.
+    pub fn iam_user_access_to_billing<T: Into<IAMUserAccessToBilling>>(mut self, value: T) -> Self {
+        self.iam_user_access_to_billing = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.CreateAccountRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateAccountRequest, ::rusoto_core::BuildError> {
+        Ok(CreateAccountRequest {
+            account_name: self
+                .account_name
+                .ok_or(::rusoto_core::BuildError::missing("account_name"))?,
+            email: self
+                .email
+                .ok_or(::rusoto_core::BuildError::missing("email"))?,
+            iam_user_access_to_billing: self.iam_user_access_to_billing,
+            role_name: self.role_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateAccountResponse {
@@ -140,6 +744,82 @@ pub struct CreateAccountResponse {
     pub create_account_status: Option<CreateAccountStatus>,
 }
 
+/// <p>The status of the request.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CreateAccountState {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CreateAccountState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CreateAccountState::InProgress => "IN_PROGRESS",
+            CreateAccountState::Succeeded => "SUCCEEDED",
+            CreateAccountState::Failed => "FAILED",
+            CreateAccountState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CreateAccountState {
+    fn from(value: &'a str) -> CreateAccountState {
+        match value {
+            "IN_PROGRESS" => CreateAccountState::InProgress,
+            "SUCCEEDED" => CreateAccountState::Succeeded,
+            "FAILED" => CreateAccountState::Failed,
+            _ => CreateAccountState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CreateAccountState {
+    fn from(value: String) -> CreateAccountState {
+        CreateAccountState::from(value.as_str())
+    }
+}
+
+impl From<CreateAccountState> for String {
+    fn from(value: CreateAccountState) -> String {
+        match value {
+            CreateAccountState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CreateAccountState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CreateAccountState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CreateAccountState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<CreateAccountState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CreateAccountState::from(value))
+    }
+}
+
 /// <p>Contains the status about a <a>CreateAccount</a> or <a>CreateGovCloudAccount</a> request to create an AWS account or an AWS GovCloud (US) account in an organization.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -154,12 +834,13 @@ pub struct CreateAccountStatus {
     pub account_name: Option<String>,
     /// <p>The date and time that the account was created and the request completed.</p>
     #[serde(rename = "CompletedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_timestamp: Option<f64>,
+    pub completed_timestamp: Option<EpochTimestamp>,
     /// <p><p>If the request failed, a description of the reason for the failure.</p> <ul> <li> <p>ACCOUNT<em>LIMIT</em>EXCEEDED: The account could not be created because you have reached the limit on the number of accounts in your organization.</p> </li> <li> <p>EMAIL<em>ALREADY</em>EXISTS: The account could not be created because another AWS account with that email address already exists.</p> </li> <li> <p>INVALID<em>ADDRESS: The account could not be created because the address you provided is not valid.</p> </li> <li> <p>INVALID</em>EMAIL: The account could not be created because the email address you provided is not valid.</p> </li> <li> <p>INTERNAL_FAILURE: The account could not be created because of an internal failure. Try again later. If the problem persists, contact Customer Support.</p> </li> </ul></p>
     #[serde(rename = "FailureReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub failure_reason: Option<String>,
+    pub failure_reason: Option<CreateAccountFailureReason>,
     /// <p>If the account was created successfully, the unique identifier (ID) of the new account in the AWS GovCloud (US) Region.</p>
     #[serde(rename = "GovCloudAccountId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -170,12 +851,13 @@ pub struct CreateAccountStatus {
     pub id: Option<String>,
     /// <p>The date and time that the request was made for the account creation.</p>
     #[serde(rename = "RequestedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requested_timestamp: Option<f64>,
+    pub requested_timestamp: Option<EpochTimestamp>,
     /// <p>The status of the request.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<CreateAccountState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -189,13 +871,71 @@ pub struct CreateGovCloudAccountRequest {
     /// <p>If set to <code>ALLOW</code>, the new linked account in the commercial Region enables IAM users to access account billing information <i>if</i> they have the required permissions. If set to <code>DENY</code>, only the root user of the new account can access account billing information. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide.</i> </p> <p>If you don't specify this parameter, the value defaults to <code>ALLOW</code>, and IAM users and roles with the required permissions can access billing information for the new account.</p>
     #[serde(rename = "IamUserAccessToBilling")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub iam_user_access_to_billing: Option<String>,
+    pub iam_user_access_to_billing: Option<IAMUserAccessToBilling>,
     /// <p>(Optional)</p> <p>The name of an IAM role that AWS Organizations automatically preconfigures in the new member accounts in both the AWS GovCloud (US) Region and in the commercial Region. This role trusts the master account, allowing users in the master account to assume the role, as permitted by the master account administrator. The role has administrator permissions in the new member account.</p> <p>If you don't specify this parameter, the role name defaults to <code>OrganizationAccountAccessRole</code>.</p> <p>For more information about how to use this role to access the member account, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_access.html#orgs_manage_accounts_create-cross-account-role">Accessing and Administering the Member Accounts in Your Organization</a> in the <i>AWS Organizations User Guide</i> and steps 2 and 3 in <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/tutorial_cross-account-with-roles.html">Tutorial: Delegate Access Across AWS Accounts Using IAM Roles</a> in the <i>IAM User Guide.</i> </p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> that is used to validate this parameter is a string of characters that can consist of uppercase letters, lowercase letters, digits with no spaces, and any of the following characters: =,.@-</p>
     #[serde(rename = "RoleName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role_name: Option<String>,
 }
 
+impl CreateGovCloudAccountRequest {
+    /// Returns a builder for `CreateGovCloudAccountRequest`, checking its required members when built.
+    pub fn builder() -> CreateGovCloudAccountRequestBuilder {
+        CreateGovCloudAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGovCloudAccountRequest`](struct.CreateGovCloudAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateGovCloudAccountRequestBuilder {
+    account_name: Option<String>,
+    email: Option<String>,
+    iam_user_access_to_billing: Option<IAMUserAccessToBilling>,
+    role_name: Option<String>,
+}
+
+impl CreateGovCloudAccountRequestBuilder {
+    /// Sets the [`account_name`](struct.CreateGovCloudAccountRequest.html#structfield.account_name) member.
+    pub fn account_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`email`](struct.CreateGovCloudAccountRequest.html#structfield.email) member.
+    pub fn email<T: Into<String>>(mut self, value: T) -> Self {
+        self.email = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_user_access_to_billing`](struct.CreateGovCloudAccountRequest.html#structfield.iam_user_access_to_billing) member.
+    pub fn iam_user_access_to_billing<T: Into<IAMUserAccessToBilling>>(mut self, value: T) -> Self {
+        self.iam_user_access_to_billing = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_name`](struct.CreateGovCloudAccountRequest.html#structfield.role_name) member.
+    pub fn role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGovCloudAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateGovCloudAccountRequest, ::rusoto_core::BuildError> {
+        Ok(CreateGovCloudAccountRequest {
+            account_name: self
+                .account_name
+                .ok_or(::rusoto_core::BuildError::missing("account_name"))?,
+            email: self
+                .email
+                .ok_or(::rusoto_core::BuildError::missing("email"))?,
+            iam_user_access_to_billing: self.iam_user_access_to_billing,
+            role_name: self.role_name,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateGovCloudAccountResponse {
@@ -209,7 +949,37 @@ pub struct CreateOrganizationRequest {
     /// <p><p>Specifies the feature set supported by the new organization. Each feature set supports different levels of functionality.</p> <ul> <li> <p> <code>CONSOLIDATED<em>BILLING</code>: All member accounts have their bills consolidated to and paid by the master account. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/organizations/latest/userguide/orgs</em>getting-started<em>concepts.html#feature-set-cb-only&quot;&gt;Consolidated billing</a> in the <i>AWS Organizations User Guide.</i> </p> <p> The consolidated billing feature subset isn&#39;t available for organizations in the AWS GovCloud (US) Region.</p> </li> <li> <p> <code>ALL</code>: In addition to all the features supported by the consolidated billing feature set, the master account can also apply any policy type to any member account in the organization. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/organizations/latest/userguide/orgs</em>getting-started_concepts.html#feature-set-all&quot;&gt;All features</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul></p>
     #[serde(rename = "FeatureSet")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub feature_set: Option<String>,
+    pub feature_set: Option<OrganizationFeatureSet>,
+}
+
+impl CreateOrganizationRequest {
+    /// Returns a builder for `CreateOrganizationRequest`, checking its required members when built.
+    pub fn builder() -> CreateOrganizationRequestBuilder {
+        CreateOrganizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateOrganizationRequest`](struct.CreateOrganizationRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateOrganizationRequestBuilder {
+    feature_set: Option<OrganizationFeatureSet>,
+}
+
+impl CreateOrganizationRequestBuilder {
+    /// Sets the [`feature_set`](struct.CreateOrganizationRequest.html#structfield.feature_set) member.
+    pub fn feature_set<T: Into<OrganizationFeatureSet>>(mut self, value: T) -> Self {
+        self.feature_set = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateOrganizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateOrganizationRequest, ::rusoto_core::BuildError> {
+        Ok(CreateOrganizationRequest {
+            feature_set: self.feature_set,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -231,6 +1001,48 @@ pub struct CreateOrganizationalUnitRequest {
     pub parent_id: String,
 }
 
+impl CreateOrganizationalUnitRequest {
+    /// Returns a builder for `CreateOrganizationalUnitRequest`, checking its required members when built.
+    pub fn builder() -> CreateOrganizationalUnitRequestBuilder {
+        CreateOrganizationalUnitRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateOrganizationalUnitRequest`](struct.CreateOrganizationalUnitRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateOrganizationalUnitRequestBuilder {
+    name: Option<String>,
+    parent_id: Option<String>,
+}
+
+impl CreateOrganizationalUnitRequestBuilder {
+    /// Sets the [`name`](struct.CreateOrganizationalUnitRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_id`](struct.CreateOrganizationalUnitRequest.html#structfield.parent_id) member.
+    pub fn parent_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateOrganizationalUnitRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateOrganizationalUnitRequest, ::rusoto_core::BuildError> {
+        Ok(CreateOrganizationalUnitRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            parent_id: self
+                .parent_id
+                .ok_or(::rusoto_core::BuildError::missing("parent_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateOrganizationalUnitResponse {
@@ -240,7 +1052,7 @@ pub struct CreateOrganizationalUnitResponse {
     pub organizational_unit: Option<OrganizationalUnit>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreatePolicyRequest {
     /// <p>The policy content to add to the new policy. For example, if you create a <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_policies_scp.html">service control policy</a> (SCP), this string must be JSON text that specifies the permissions that admins in attached accounts can delegate to their users, groups, and roles. For more information about the SCP syntax, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_reference_scp-syntax.html">Service Control Policy Syntax</a> in the <i>AWS Organizations User Guide.</i> </p>
     #[serde(rename = "Content")]
@@ -253,7 +1065,67 @@ pub struct CreatePolicyRequest {
     pub name: String,
     /// <p><p>The type of policy to create.</p> <note> <p>In the current release, the only type of policy that you can create is a service control policy (SCP).</p> </note></p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: PolicyType,
+}
+
+impl CreatePolicyRequest {
+    /// Returns a builder for `CreatePolicyRequest`, checking its required members when built.
+    pub fn builder() -> CreatePolicyRequestBuilder {
+        CreatePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreatePolicyRequest`](struct.CreatePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct CreatePolicyRequestBuilder {
+    content: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+    type_: Option<PolicyType>,
+}
+
+impl CreatePolicyRequestBuilder {
+    /// Sets the [`content`](struct.CreatePolicyRequest.html#structfield.content) member.
+    pub fn content<T: Into<String>>(mut self, value: T) -> Self {
+        self.content = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreatePolicyRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreatePolicyRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`type_`](struct.CreatePolicyRequest.html#structfield.type_) member.
+    pub fn type_<T: Into<PolicyType>>(mut self, value: T) -> Self {
+        self.type_ = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreatePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreatePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(CreatePolicyRequest {
+            content: self
+                .content
+                .ok_or(::rusoto_core::BuildError::missing("content"))?,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            type_: self
+                .type_
+                .ok_or(::rusoto_core::BuildError::missing("type_"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -272,6 +1144,38 @@ pub struct DeclineHandshakeRequest {
     pub handshake_id: String,
 }
 
+impl DeclineHandshakeRequest {
+    /// Returns a builder for `DeclineHandshakeRequest`, checking its required members when built.
+    pub fn builder() -> DeclineHandshakeRequestBuilder {
+        DeclineHandshakeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeclineHandshakeRequest`](struct.DeclineHandshakeRequest.html).
+#[derive(Default, Debug)]
+pub struct DeclineHandshakeRequestBuilder {
+    handshake_id: Option<String>,
+}
+
+impl DeclineHandshakeRequestBuilder {
+    /// Sets the [`handshake_id`](struct.DeclineHandshakeRequest.html#structfield.handshake_id) member.
+    pub fn handshake_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.handshake_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeclineHandshakeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeclineHandshakeRequest, ::rusoto_core::BuildError> {
+        Ok(DeclineHandshakeRequest {
+            handshake_id: self
+                .handshake_id
+                .ok_or(::rusoto_core::BuildError::missing("handshake_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeclineHandshakeResponse {
@@ -288,6 +1192,38 @@ pub struct DeleteOrganizationalUnitRequest {
     pub organizational_unit_id: String,
 }
 
+impl DeleteOrganizationalUnitRequest {
+    /// Returns a builder for `DeleteOrganizationalUnitRequest`, checking its required members when built.
+    pub fn builder() -> DeleteOrganizationalUnitRequestBuilder {
+        DeleteOrganizationalUnitRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteOrganizationalUnitRequest`](struct.DeleteOrganizationalUnitRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteOrganizationalUnitRequestBuilder {
+    organizational_unit_id: Option<String>,
+}
+
+impl DeleteOrganizationalUnitRequestBuilder {
+    /// Sets the [`organizational_unit_id`](struct.DeleteOrganizationalUnitRequest.html#structfield.organizational_unit_id) member.
+    pub fn organizational_unit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organizational_unit_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteOrganizationalUnitRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteOrganizationalUnitRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteOrganizationalUnitRequest {
+            organizational_unit_id: self
+                .organizational_unit_id
+                .ok_or(::rusoto_core::BuildError::missing("organizational_unit_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeletePolicyRequest {
     /// <p>The unique identifier (ID) of the policy that you want to delete. You can get the ID from the <a>ListPolicies</a> or <a>ListPoliciesForTarget</a> operations.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a policy ID string requires "p-" followed by from 8 to 128 lower-case letters or digits.</p>
@@ -295,6 +1231,36 @@ pub struct DeletePolicyRequest {
     pub policy_id: String,
 }
 
+impl DeletePolicyRequest {
+    /// Returns a builder for `DeletePolicyRequest`, checking its required members when built.
+    pub fn builder() -> DeletePolicyRequestBuilder {
+        DeletePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeletePolicyRequest`](struct.DeletePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DeletePolicyRequestBuilder {
+    policy_id: Option<String>,
+}
+
+impl DeletePolicyRequestBuilder {
+    /// Sets the [`policy_id`](struct.DeletePolicyRequest.html#structfield.policy_id) member.
+    pub fn policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeletePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeletePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DeletePolicyRequest {
+            policy_id: self
+                .policy_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeAccountRequest {
     /// <p>The unique identifier (ID) of the AWS account that you want information about. You can get the ID from the <a>ListAccounts</a> or <a>ListAccountsForParent</a> operations.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an account ID string requires exactly 12 digits.</p>
@@ -302,6 +1268,36 @@ pub struct DescribeAccountRequest {
     pub account_id: String,
 }
 
+impl DescribeAccountRequest {
+    /// Returns a builder for `DescribeAccountRequest`, checking its required members when built.
+    pub fn builder() -> DescribeAccountRequestBuilder {
+        DescribeAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAccountRequest`](struct.DescribeAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeAccountRequestBuilder {
+    account_id: Option<String>,
+}
+
+impl DescribeAccountRequestBuilder {
+    /// Sets the [`account_id`](struct.DescribeAccountRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeAccountRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeAccountRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeAccountResponse {
@@ -318,6 +1314,38 @@ pub struct DescribeCreateAccountStatusRequest {
     pub create_account_request_id: String,
 }
 
+impl DescribeCreateAccountStatusRequest {
+    /// Returns a builder for `DescribeCreateAccountStatusRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCreateAccountStatusRequestBuilder {
+        DescribeCreateAccountStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCreateAccountStatusRequest`](struct.DescribeCreateAccountStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCreateAccountStatusRequestBuilder {
+    create_account_request_id: Option<String>,
+}
+
+impl DescribeCreateAccountStatusRequestBuilder {
+    /// Sets the [`create_account_request_id`](struct.DescribeCreateAccountStatusRequest.html#structfield.create_account_request_id) member.
+    pub fn create_account_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.create_account_request_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCreateAccountStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCreateAccountStatusRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeCreateAccountStatusRequest {
+            create_account_request_id: self.create_account_request_id.ok_or(
+                ::rusoto_core::BuildError::missing("create_account_request_id"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeCreateAccountStatusResponse {
@@ -334,6 +1362,38 @@ pub struct DescribeHandshakeRequest {
     pub handshake_id: String,
 }
 
+impl DescribeHandshakeRequest {
+    /// Returns a builder for `DescribeHandshakeRequest`, checking its required members when built.
+    pub fn builder() -> DescribeHandshakeRequestBuilder {
+        DescribeHandshakeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeHandshakeRequest`](struct.DescribeHandshakeRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeHandshakeRequestBuilder {
+    handshake_id: Option<String>,
+}
+
+impl DescribeHandshakeRequestBuilder {
+    /// Sets the [`handshake_id`](struct.DescribeHandshakeRequest.html#structfield.handshake_id) member.
+    pub fn handshake_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.handshake_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeHandshakeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeHandshakeRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeHandshakeRequest {
+            handshake_id: self
+                .handshake_id
+                .ok_or(::rusoto_core::BuildError::missing("handshake_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeHandshakeResponse {
@@ -359,6 +1419,38 @@ pub struct DescribeOrganizationalUnitRequest {
     pub organizational_unit_id: String,
 }
 
+impl DescribeOrganizationalUnitRequest {
+    /// Returns a builder for `DescribeOrganizationalUnitRequest`, checking its required members when built.
+    pub fn builder() -> DescribeOrganizationalUnitRequestBuilder {
+        DescribeOrganizationalUnitRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeOrganizationalUnitRequest`](struct.DescribeOrganizationalUnitRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeOrganizationalUnitRequestBuilder {
+    organizational_unit_id: Option<String>,
+}
+
+impl DescribeOrganizationalUnitRequestBuilder {
+    /// Sets the [`organizational_unit_id`](struct.DescribeOrganizationalUnitRequest.html#structfield.organizational_unit_id) member.
+    pub fn organizational_unit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organizational_unit_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeOrganizationalUnitRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeOrganizationalUnitRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeOrganizationalUnitRequest {
+            organizational_unit_id: self
+                .organizational_unit_id
+                .ok_or(::rusoto_core::BuildError::missing("organizational_unit_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeOrganizationalUnitResponse {
@@ -375,6 +1467,36 @@ pub struct DescribePolicyRequest {
     pub policy_id: String,
 }
 
+impl DescribePolicyRequest {
+    /// Returns a builder for `DescribePolicyRequest`, checking its required members when built.
+    pub fn builder() -> DescribePolicyRequestBuilder {
+        DescribePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePolicyRequest`](struct.DescribePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribePolicyRequestBuilder {
+    policy_id: Option<String>,
+}
+
+impl DescribePolicyRequestBuilder {
+    /// Sets the [`policy_id`](struct.DescribePolicyRequest.html#structfield.policy_id) member.
+    pub fn policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DescribePolicyRequest {
+            policy_id: self
+                .policy_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribePolicyResponse {
@@ -394,6 +1516,46 @@ pub struct DetachPolicyRequest {
     pub target_id: String,
 }
 
+impl DetachPolicyRequest {
+    /// Returns a builder for `DetachPolicyRequest`, checking its required members when built.
+    pub fn builder() -> DetachPolicyRequestBuilder {
+        DetachPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetachPolicyRequest`](struct.DetachPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct DetachPolicyRequestBuilder {
+    policy_id: Option<String>,
+    target_id: Option<String>,
+}
+
+impl DetachPolicyRequestBuilder {
+    /// Sets the [`policy_id`](struct.DetachPolicyRequest.html#structfield.policy_id) member.
+    pub fn policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_id`](struct.DetachPolicyRequest.html#structfield.target_id) member.
+    pub fn target_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetachPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetachPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(DetachPolicyRequest {
+            policy_id: self
+                .policy_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_id"))?,
+            target_id: self
+                .target_id
+                .ok_or(::rusoto_core::BuildError::missing("target_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DisableAWSServiceAccessRequest {
     /// <p>The service principal name of the AWS service for which you want to disable integration with your organization. This is typically in the form of a URL, such as <code> <i>service-abbreviation</i>.amazonaws.com</code>.</p>
@@ -401,16 +1563,90 @@ pub struct DisableAWSServiceAccessRequest {
     pub service_principal: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl DisableAWSServiceAccessRequest {
+    /// Returns a builder for `DisableAWSServiceAccessRequest`, checking its required members when built.
+    pub fn builder() -> DisableAWSServiceAccessRequestBuilder {
+        DisableAWSServiceAccessRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisableAWSServiceAccessRequest`](struct.DisableAWSServiceAccessRequest.html).
+#[derive(Default, Debug)]
+pub struct DisableAWSServiceAccessRequestBuilder {
+    service_principal: Option<String>,
+}
+
+impl DisableAWSServiceAccessRequestBuilder {
+    /// Sets the [`service_principal`](struct.DisableAWSServiceAccessRequest.html#structfield.service_principal) member.
+    pub fn service_principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_principal = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableAWSServiceAccessRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisableAWSServiceAccessRequest, ::rusoto_core::BuildError> {
+        Ok(DisableAWSServiceAccessRequest {
+            service_principal: self
+                .service_principal
+                .ok_or(::rusoto_core::BuildError::missing("service_principal"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DisablePolicyTypeRequest {
     /// <p>The policy type that you want to disable in this root.</p>
     #[serde(rename = "PolicyType")]
-    pub policy_type: String,
+    pub policy_type: PolicyType,
     /// <p>The unique identifier (ID) of the root in which you want to disable a policy type. You can get the ID from the <a>ListRoots</a> operation.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a root ID string requires "r-" followed by from 4 to 32 lower-case letters or digits.</p>
     #[serde(rename = "RootId")]
     pub root_id: String,
 }
 
+impl DisablePolicyTypeRequest {
+    /// Returns a builder for `DisablePolicyTypeRequest`, checking its required members when built.
+    pub fn builder() -> DisablePolicyTypeRequestBuilder {
+        DisablePolicyTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisablePolicyTypeRequest`](struct.DisablePolicyTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct DisablePolicyTypeRequestBuilder {
+    policy_type: Option<PolicyType>,
+    root_id: Option<String>,
+}
+
+impl DisablePolicyTypeRequestBuilder {
+    /// Sets the [`policy_type`](struct.DisablePolicyTypeRequest.html#structfield.policy_type) member.
+    pub fn policy_type<T: Into<PolicyType>>(mut self, value: T) -> Self {
+        self.policy_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`root_id`](struct.DisablePolicyTypeRequest.html#structfield.root_id) member.
+    pub fn root_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.root_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisablePolicyTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisablePolicyTypeRequest, ::rusoto_core::BuildError> {
+        Ok(DisablePolicyTypeRequest {
+            policy_type: self
+                .policy_type
+                .ok_or(::rusoto_core::BuildError::missing("policy_type"))?,
+            root_id: self
+                .root_id
+                .ok_or(::rusoto_core::BuildError::missing("root_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisablePolicyTypeResponse {
@@ -427,6 +1663,38 @@ pub struct EnableAWSServiceAccessRequest {
     pub service_principal: String,
 }
 
+impl EnableAWSServiceAccessRequest {
+    /// Returns a builder for `EnableAWSServiceAccessRequest`, checking its required members when built.
+    pub fn builder() -> EnableAWSServiceAccessRequestBuilder {
+        EnableAWSServiceAccessRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnableAWSServiceAccessRequest`](struct.EnableAWSServiceAccessRequest.html).
+#[derive(Default, Debug)]
+pub struct EnableAWSServiceAccessRequestBuilder {
+    service_principal: Option<String>,
+}
+
+impl EnableAWSServiceAccessRequestBuilder {
+    /// Sets the [`service_principal`](struct.EnableAWSServiceAccessRequest.html#structfield.service_principal) member.
+    pub fn service_principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_principal = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableAWSServiceAccessRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EnableAWSServiceAccessRequest, ::rusoto_core::BuildError> {
+        Ok(EnableAWSServiceAccessRequest {
+            service_principal: self
+                .service_principal
+                .ok_or(::rusoto_core::BuildError::missing("service_principal"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct EnableAllFeaturesRequest {}
 
@@ -439,16 +1707,58 @@ pub struct EnableAllFeaturesResponse {
     pub handshake: Option<Handshake>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct EnablePolicyTypeRequest {
     /// <p>The policy type that you want to enable.</p>
     #[serde(rename = "PolicyType")]
-    pub policy_type: String,
+    pub policy_type: PolicyType,
     /// <p>The unique identifier (ID) of the root in which you want to enable a policy type. You can get the ID from the <a>ListRoots</a> operation.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for a root ID string requires "r-" followed by from 4 to 32 lower-case letters or digits.</p>
     #[serde(rename = "RootId")]
     pub root_id: String,
 }
 
+impl EnablePolicyTypeRequest {
+    /// Returns a builder for `EnablePolicyTypeRequest`, checking its required members when built.
+    pub fn builder() -> EnablePolicyTypeRequestBuilder {
+        EnablePolicyTypeRequestBuilder::default()
+    }
+}
+
+/// Builds a [`EnablePolicyTypeRequest`](struct.EnablePolicyTypeRequest.html).
+#[derive(Default, Debug)]
+pub struct EnablePolicyTypeRequestBuilder {
+    policy_type: Option<PolicyType>,
+    root_id: Option<String>,
+}
+
+impl EnablePolicyTypeRequestBuilder {
+    /// Sets the [`policy_type`](struct.EnablePolicyTypeRequest.html#structfield.policy_type) member.
+    pub fn policy_type<T: Into<PolicyType>>(mut self, value: T) -> Self {
+        self.policy_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`root_id`](struct.EnablePolicyTypeRequest.html#structfield.root_id) member.
+    pub fn root_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.root_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnablePolicyTypeRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EnablePolicyTypeRequest, ::rusoto_core::BuildError> {
+        Ok(EnablePolicyTypeRequest {
+            policy_type: self
+                .policy_type
+                .ok_or(::rusoto_core::BuildError::missing("policy_type"))?,
+            root_id: self
+                .root_id
+                .ok_or(::rusoto_core::BuildError::missing("root_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct EnablePolicyTypeResponse {
@@ -464,8 +1774,9 @@ pub struct EnablePolicyTypeResponse {
 pub struct EnabledServicePrincipal {
     /// <p>The date that the service principal was enabled for integration with AWS Organizations.</p>
     #[serde(rename = "DateEnabled")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date_enabled: Option<f64>,
+    pub date_enabled: Option<EpochTimestamp>,
     /// <p>The name of the service principal. This is typically in the form of a URL, such as: <code> <i>servicename</i>.amazonaws.com</code>.</p>
     #[serde(rename = "ServicePrincipal")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -479,15 +1790,16 @@ pub struct Handshake {
     /// <p><p>The type of handshake, indicating what action occurs when the recipient accepts the handshake. The following handshake types are supported:</p> <ul> <li> <p> <b>INVITE</b>: This type of handshake represents a request to join an organization. It is always sent from the master account to only non-member accounts.</p> </li> <li> <p> <b>ENABLE<em>ALL</em>FEATURES</b>: This type of handshake represents a request to enable all features in an organization. It is always sent from the master account to only <i>invited</i> member accounts. Created accounts do not receive this because those accounts were created by the organization&#39;s master account and approval is inferred.</p> </li> <li> <p> <b>APPROVE<em>ALL</em>FEATURES</b>: This type of handshake is sent from the Organizations service when all member accounts have approved the <code>ENABLE<em>ALL</em>FEATURES</code> invitation. It is sent only to the master account and signals the master that it can finalize the process to enable all features.</p> </li> </ul></p>
     #[serde(rename = "Action")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub action: Option<String>,
+    pub action: Option<ActionType>,
     /// <p>The Amazon Resource Name (ARN) of a handshake.</p> <p>For more information about ARNs in Organizations, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_permissions.html#orgs-permissions-arns">ARN Formats Supported by Organizations</a> in the <i>AWS Organizations User Guide</i>.</p>
     #[serde(rename = "Arn")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arn: Option<String>,
     /// <p>The date and time that the handshake expires. If the recipient of the handshake request fails to respond before the specified date and time, the handshake becomes inactive and is no longer valid.</p>
     #[serde(rename = "ExpirationTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration_timestamp: Option<f64>,
+    pub expiration_timestamp: Option<EpochTimestamp>,
     /// <p>The unique identifier (ID) of a handshake. The originating account creates the ID when it initiates the handshake.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -498,8 +1810,9 @@ pub struct Handshake {
     pub parties: Option<Vec<HandshakeParty>>,
     /// <p>The date and time that the handshake request was made.</p>
     #[serde(rename = "RequestedTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub requested_timestamp: Option<f64>,
+    pub requested_timestamp: Option<EpochTimestamp>,
     /// <p>Additional information that is needed to process the handshake.</p>
     #[serde(rename = "Resources")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -507,52 +1820,384 @@ pub struct Handshake {
     /// <p><p>The current state of the handshake. Use the state to trace the flow of the handshake through the process from its creation to its acceptance. The meaning of each of the valid values is as follows:</p> <ul> <li> <p> <b>REQUESTED</b>: This handshake was sent to multiple recipients (applicable to only some handshake types) and not all recipients have responded yet. The request stays in this state until all recipients respond.</p> </li> <li> <p> <b>OPEN</b>: This handshake was sent to multiple recipients (applicable to only some policy types) and all recipients have responded, allowing the originator to complete the handshake action.</p> </li> <li> <p> <b>CANCELED</b>: This handshake is no longer active because it was canceled by the originating account.</p> </li> <li> <p> <b>ACCEPTED</b>: This handshake is complete because it has been accepted by the recipient.</p> </li> <li> <p> <b>DECLINED</b>: This handshake is no longer active because it was declined by the recipient account.</p> </li> <li> <p> <b>EXPIRED</b>: This handshake is no longer active because the originator did not receive a response of any kind from the recipient before the expiration time (15 days).</p> </li> </ul></p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<HandshakeState>,
+}
+
+/// <p>Specifies the criteria that are used to select the handshakes for the operation.</p>
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct HandshakeFilter {
+    /// <p>Specifies the type of handshake action.</p> <p>If you specify <code>ActionType</code>, you cannot also specify <code>ParentHandshakeId</code>.</p>
+    #[serde(rename = "ActionType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<ActionType>,
+    /// <p>Specifies the parent handshake. Only used for handshake types that are a child of another type.</p> <p>If you specify <code>ParentHandshakeId</code>, you cannot also specify <code>ActionType</code>.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
+    #[serde(rename = "ParentHandshakeId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_handshake_id: Option<String>,
+}
+
+/// <p>Identifies a participant in a handshake.</p>
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HandshakeParty {
+    /// <p>The unique identifier (ID) for the party.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
+    #[serde(rename = "Id")]
+    pub id: String,
+    /// <p>The type of party.</p>
+    #[serde(rename = "Type")]
+    pub type_: HandshakePartyType,
+}
+
+/// <p>The type of party.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HandshakePartyType {
+    /// <p><code>ACCOUNT</code></p>
+    Account,
+    /// <p><code>ORGANIZATION</code></p>
+    Organization,
+    /// <p><code>EMAIL</code></p>
+    Email,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HandshakePartyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HandshakePartyType::Account => "ACCOUNT",
+            HandshakePartyType::Organization => "ORGANIZATION",
+            HandshakePartyType::Email => "EMAIL",
+            HandshakePartyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HandshakePartyType {
+    fn from(value: &'a str) -> HandshakePartyType {
+        match value {
+            "ACCOUNT" => HandshakePartyType::Account,
+            "ORGANIZATION" => HandshakePartyType::Organization,
+            "EMAIL" => HandshakePartyType::Email,
+            _ => HandshakePartyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HandshakePartyType {
+    fn from(value: String) -> HandshakePartyType {
+        HandshakePartyType::from(value.as_str())
+    }
+}
+
+impl From<HandshakePartyType> for String {
+    fn from(value: HandshakePartyType) -> String {
+        match value {
+            HandshakePartyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HandshakePartyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HandshakePartyType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HandshakePartyType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HandshakePartyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HandshakePartyType::from(value))
+    }
+}
+
+/// <p>Contains additional data that is needed to process a handshake.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct HandshakeResource {
+    /// <p>When needed, contains an additional array of <code>HandshakeResource</code> objects.</p>
+    #[serde(rename = "Resources")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<HandshakeResource>>,
+    /// <p><p>The type of information being passed, specifying how the value is to be interpreted by the other party:</p> <ul> <li> <p> <code>ACCOUNT</code> - Specifies an AWS account ID number.</p> </li> <li> <p> <code>ORGANIZATION</code> - Specifies an organization ID number.</p> </li> <li> <p> <code>EMAIL</code> - Specifies the email address that is associated with the account that receives the handshake. </p> </li> <li> <p> <code>OWNER<em>EMAIL</code> - Specifies the email address associated with the master account. Included as information about an organization. </p> </li> <li> <p> <code>OWNER</em>NAME</code> - Specifies the name associated with the master account. Included as information about an organization. </p> </li> <li> <p> <code>NOTES</code> - Additional text provided by the handshake initiator and intended for the recipient to read.</p> </li> </ul></p>
+    #[serde(rename = "Type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_: Option<HandshakeResourceType>,
+    /// <p>The information that is passed to the other party in the handshake. The format of the value string must match the requirements of the specified type.</p>
+    #[serde(rename = "Value")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// <p><p>The type of information being passed, specifying how the value is to be interpreted by the other party:</p> <ul> <li> <p> <code>ACCOUNT</code> - Specifies an AWS account ID number.</p> </li> <li> <p> <code>ORGANIZATION</code> - Specifies an organization ID number.</p> </li> <li> <p> <code>EMAIL</code> - Specifies the email address that is associated with the account that receives the handshake. </p> </li> <li> <p> <code>OWNER<em>EMAIL</code> - Specifies the email address associated with the master account. Included as information about an organization. </p> </li> <li> <p> <code>OWNER</em>NAME</code> - Specifies the name associated with the master account. Included as information about an organization. </p> </li> <li> <p> <code>NOTES</code> - Additional text provided by the handshake initiator and intended for the recipient to read.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HandshakeResourceType {
+    /// <p><code>ACCOUNT</code> - Specifies an AWS account ID number.</p>
+    Account,
+    /// <p><code>ORGANIZATION</code> - Specifies an organization ID number.</p>
+    Organization,
+    /// <p><code>ORGANIZATION_FEATURE_SET</code></p>
+    OrganizationFeatureSet,
+    /// <p><code>EMAIL</code> - Specifies the email address that is associated with the account that receives the handshake.</p>
+    Email,
+    /// <p><code>MASTER_EMAIL</code></p>
+    MasterEmail,
+    /// <p><code>MASTER_NAME</code></p>
+    MasterName,
+    /// <p><code>NOTES</code> - Additional text provided by the handshake initiator and intended for the recipient to read.</p>
+    Notes,
+    /// <p><code>PARENT_HANDSHAKE</code></p>
+    ParentHandshake,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HandshakeResourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HandshakeResourceType::Account => "ACCOUNT",
+            HandshakeResourceType::Organization => "ORGANIZATION",
+            HandshakeResourceType::OrganizationFeatureSet => "ORGANIZATION_FEATURE_SET",
+            HandshakeResourceType::Email => "EMAIL",
+            HandshakeResourceType::MasterEmail => "MASTER_EMAIL",
+            HandshakeResourceType::MasterName => "MASTER_NAME",
+            HandshakeResourceType::Notes => "NOTES",
+            HandshakeResourceType::ParentHandshake => "PARENT_HANDSHAKE",
+            HandshakeResourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HandshakeResourceType {
+    fn from(value: &'a str) -> HandshakeResourceType {
+        match value {
+            "ACCOUNT" => HandshakeResourceType::Account,
+            "ORGANIZATION" => HandshakeResourceType::Organization,
+            "ORGANIZATION_FEATURE_SET" => HandshakeResourceType::OrganizationFeatureSet,
+            "EMAIL" => HandshakeResourceType::Email,
+            "MASTER_EMAIL" => HandshakeResourceType::MasterEmail,
+            "MASTER_NAME" => HandshakeResourceType::MasterName,
+            "NOTES" => HandshakeResourceType::Notes,
+            "PARENT_HANDSHAKE" => HandshakeResourceType::ParentHandshake,
+            _ => HandshakeResourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HandshakeResourceType {
+    fn from(value: String) -> HandshakeResourceType {
+        HandshakeResourceType::from(value.as_str())
+    }
+}
+
+impl From<HandshakeResourceType> for String {
+    fn from(value: HandshakeResourceType) -> String {
+        match value {
+            HandshakeResourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HandshakeResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HandshakeResourceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HandshakeResourceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HandshakeResourceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HandshakeResourceType::from(value))
+    }
+}
+
+/// <p><p>The current state of the handshake. Use the state to trace the flow of the handshake through the process from its creation to its acceptance. The meaning of each of the valid values is as follows:</p> <ul> <li> <p> <b>REQUESTED</b>: This handshake was sent to multiple recipients (applicable to only some handshake types) and not all recipients have responded yet. The request stays in this state until all recipients respond.</p> </li> <li> <p> <b>OPEN</b>: This handshake was sent to multiple recipients (applicable to only some policy types) and all recipients have responded, allowing the originator to complete the handshake action.</p> </li> <li> <p> <b>CANCELED</b>: This handshake is no longer active because it was canceled by the originating account.</p> </li> <li> <p> <b>ACCEPTED</b>: This handshake is complete because it has been accepted by the recipient.</p> </li> <li> <p> <b>DECLINED</b>: This handshake is no longer active because it was declined by the recipient account.</p> </li> <li> <p> <b>EXPIRED</b>: This handshake is no longer active because the originator did not receive a response of any kind from the recipient before the expiration time (15 days).</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HandshakeState {
+    /// <p><code>REQUESTED</code></p>
+    Requested,
+    /// <p><code>OPEN</code></p>
+    Open,
+    /// <p><code>CANCELED</code></p>
+    Canceled,
+    /// <p><code>ACCEPTED</code></p>
+    Accepted,
+    /// <p><code>DECLINED</code></p>
+    Declined,
+    /// <p><code>EXPIRED</code></p>
+    Expired,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl HandshakeState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            HandshakeState::Requested => "REQUESTED",
+            HandshakeState::Open => "OPEN",
+            HandshakeState::Canceled => "CANCELED",
+            HandshakeState::Accepted => "ACCEPTED",
+            HandshakeState::Declined => "DECLINED",
+            HandshakeState::Expired => "EXPIRED",
+            HandshakeState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for HandshakeState {
+    fn from(value: &'a str) -> HandshakeState {
+        match value {
+            "REQUESTED" => HandshakeState::Requested,
+            "OPEN" => HandshakeState::Open,
+            "CANCELED" => HandshakeState::Canceled,
+            "ACCEPTED" => HandshakeState::Accepted,
+            "DECLINED" => HandshakeState::Declined,
+            "EXPIRED" => HandshakeState::Expired,
+            _ => HandshakeState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for HandshakeState {
+    fn from(value: String) -> HandshakeState {
+        HandshakeState::from(value.as_str())
+    }
+}
+
+impl From<HandshakeState> for String {
+    fn from(value: HandshakeState) -> String {
+        match value {
+            HandshakeState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for HandshakeState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for HandshakeState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for HandshakeState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<HandshakeState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(HandshakeState::from(value))
+    }
 }
 
-/// <p>Specifies the criteria that are used to select the handshakes for the operation.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
-pub struct HandshakeFilter {
-    /// <p>Specifies the type of handshake action.</p> <p>If you specify <code>ActionType</code>, you cannot also specify <code>ParentHandshakeId</code>.</p>
-    #[serde(rename = "ActionType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub action_type: Option<String>,
-    /// <p>Specifies the parent handshake. Only used for handshake types that are a child of another type.</p> <p>If you specify <code>ParentHandshakeId</code>, you cannot also specify <code>ActionType</code>.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
-    #[serde(rename = "ParentHandshakeId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parent_handshake_id: Option<String>,
+/// <p>If set to <code>ALLOW</code>, the new account enables IAM users to access account billing information <i>if</i> they have the required permissions. If set to <code>DENY</code>, only the root user of the new account can access account billing information. For more information, see <a href="https://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide</i>.</p> <p>If you don't specify this parameter, the value defaults to <code>ALLOW</code>, and IAM users and roles with the required permissions can access billing information for the new account.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IAMUserAccessToBilling {
+    /// <p><code>ALLOW</code></p>
+    Allow,
+    /// <p><code>DENY</code></p>
+    Deny,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
 }
 
-/// <p>Identifies a participant in a handshake.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct HandshakeParty {
-    /// <p>The unique identifier (ID) for the party.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for handshake ID string requires "h-" followed by from 8 to 32 lower-case letters or digits.</p>
-    #[serde(rename = "Id")]
-    pub id: String,
-    /// <p>The type of party.</p>
-    #[serde(rename = "Type")]
-    pub type_: String,
+impl IAMUserAccessToBilling {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            IAMUserAccessToBilling::Allow => "ALLOW",
+            IAMUserAccessToBilling::Deny => "DENY",
+            IAMUserAccessToBilling::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Contains additional data that is needed to process a handshake.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct HandshakeResource {
-    /// <p>When needed, contains an additional array of <code>HandshakeResource</code> objects.</p>
-    #[serde(rename = "Resources")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resources: Option<Vec<HandshakeResource>>,
-    /// <p><p>The type of information being passed, specifying how the value is to be interpreted by the other party:</p> <ul> <li> <p> <code>ACCOUNT</code> - Specifies an AWS account ID number.</p> </li> <li> <p> <code>ORGANIZATION</code> - Specifies an organization ID number.</p> </li> <li> <p> <code>EMAIL</code> - Specifies the email address that is associated with the account that receives the handshake. </p> </li> <li> <p> <code>OWNER<em>EMAIL</code> - Specifies the email address associated with the master account. Included as information about an organization. </p> </li> <li> <p> <code>OWNER</em>NAME</code> - Specifies the name associated with the master account. Included as information about an organization. </p> </li> <li> <p> <code>NOTES</code> - Additional text provided by the handshake initiator and intended for the recipient to read.</p> </li> </ul></p>
-    #[serde(rename = "Type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
-    /// <p>The information that is passed to the other party in the handshake. The format of the value string must match the requirements of the specified type.</p>
-    #[serde(rename = "Value")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
+impl<'a> From<&'a str> for IAMUserAccessToBilling {
+    fn from(value: &'a str) -> IAMUserAccessToBilling {
+        match value {
+            "ALLOW" => IAMUserAccessToBilling::Allow,
+            "DENY" => IAMUserAccessToBilling::Deny,
+            _ => IAMUserAccessToBilling::Unknown(value.to_owned()),
+        }
+    }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+impl From<String> for IAMUserAccessToBilling {
+    fn from(value: String) -> IAMUserAccessToBilling {
+        IAMUserAccessToBilling::from(value.as_str())
+    }
+}
+
+impl From<IAMUserAccessToBilling> for String {
+    fn from(value: IAMUserAccessToBilling) -> String {
+        match value {
+            IAMUserAccessToBilling::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for IAMUserAccessToBilling {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for IAMUserAccessToBilling {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for IAMUserAccessToBilling {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<IAMUserAccessToBilling, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(IAMUserAccessToBilling::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct InviteAccountToOrganizationRequest {
     /// <p>Additional information that you want to include in the generated email to the recipient account owner.</p>
     #[serde(rename = "Notes")]
@@ -563,6 +2208,46 @@ pub struct InviteAccountToOrganizationRequest {
     pub target: HandshakeParty,
 }
 
+impl InviteAccountToOrganizationRequest {
+    /// Returns a builder for `InviteAccountToOrganizationRequest`, checking its required members when built.
+    pub fn builder() -> InviteAccountToOrganizationRequestBuilder {
+        InviteAccountToOrganizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`InviteAccountToOrganizationRequest`](struct.InviteAccountToOrganizationRequest.html).
+#[derive(Default, Debug)]
+pub struct InviteAccountToOrganizationRequestBuilder {
+    notes: Option<String>,
+    target: Option<HandshakeParty>,
+}
+
+impl InviteAccountToOrganizationRequestBuilder {
+    /// Sets the [`notes`](struct.InviteAccountToOrganizationRequest.html#structfield.notes) member.
+    pub fn notes<T: Into<String>>(mut self, value: T) -> Self {
+        self.notes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target`](struct.InviteAccountToOrganizationRequest.html#structfield.target) member.
+    pub fn target<T: Into<HandshakeParty>>(mut self, value: T) -> Self {
+        self.target = Some(value.into());
+        self
+    }
+
+    /// Builds the `InviteAccountToOrganizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<InviteAccountToOrganizationRequest, ::rusoto_core::BuildError> {
+        Ok(InviteAccountToOrganizationRequest {
+            notes: self.notes,
+            target: self
+                .target
+                .ok_or(::rusoto_core::BuildError::missing("target"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct InviteAccountToOrganizationResponse {
@@ -584,6 +2269,45 @@ pub struct ListAWSServiceAccessForOrganizationRequest {
     pub next_token: Option<String>,
 }
 
+impl ListAWSServiceAccessForOrganizationRequest {
+    /// Returns a builder for `ListAWSServiceAccessForOrganizationRequest`, checking its required members when built.
+    pub fn builder() -> ListAWSServiceAccessForOrganizationRequestBuilder {
+        ListAWSServiceAccessForOrganizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAWSServiceAccessForOrganizationRequest`](struct.ListAWSServiceAccessForOrganizationRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAWSServiceAccessForOrganizationRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAWSServiceAccessForOrganizationRequestBuilder {
+    /// Sets the [`max_results`](struct.ListAWSServiceAccessForOrganizationRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAWSServiceAccessForOrganizationRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAWSServiceAccessForOrganizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAWSServiceAccessForOrganizationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListAWSServiceAccessForOrganizationRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAWSServiceAccessForOrganizationResponse {
@@ -612,6 +2336,54 @@ pub struct ListAccountsForParentRequest {
     pub parent_id: String,
 }
 
+impl ListAccountsForParentRequest {
+    /// Returns a builder for `ListAccountsForParentRequest`, checking its required members when built.
+    pub fn builder() -> ListAccountsForParentRequestBuilder {
+        ListAccountsForParentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAccountsForParentRequest`](struct.ListAccountsForParentRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAccountsForParentRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    parent_id: Option<String>,
+}
+
+impl ListAccountsForParentRequestBuilder {
+    /// Sets the [`max_results`](struct.ListAccountsForParentRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAccountsForParentRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_id`](struct.ListAccountsForParentRequest.html#structfield.parent_id) member.
+    pub fn parent_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAccountsForParentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListAccountsForParentRequest, ::rusoto_core::BuildError> {
+        Ok(ListAccountsForParentRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            parent_id: self
+                .parent_id
+                .ok_or(::rusoto_core::BuildError::missing("parent_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAccountsForParentResponse {
@@ -637,6 +2409,42 @@ pub struct ListAccountsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListAccountsRequest {
+    /// Returns a builder for `ListAccountsRequest`, checking its required members when built.
+    pub fn builder() -> ListAccountsRequestBuilder {
+        ListAccountsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListAccountsRequest`](struct.ListAccountsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListAccountsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListAccountsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListAccountsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListAccountsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListAccountsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListAccountsRequest, ::rusoto_core::BuildError> {
+        Ok(ListAccountsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListAccountsResponse {
@@ -650,11 +2458,11 @@ pub struct ListAccountsResponse {
     pub next_token: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListChildrenRequest {
     /// <p>Filters the output to include only the specified child type.</p>
     #[serde(rename = "ChildType")]
-    pub child_type: String,
+    pub child_type: ChildType,
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -668,6 +2476,62 @@ pub struct ListChildrenRequest {
     pub parent_id: String,
 }
 
+impl ListChildrenRequest {
+    /// Returns a builder for `ListChildrenRequest`, checking its required members when built.
+    pub fn builder() -> ListChildrenRequestBuilder {
+        ListChildrenRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListChildrenRequest`](struct.ListChildrenRequest.html).
+#[derive(Default, Debug)]
+pub struct ListChildrenRequestBuilder {
+    child_type: Option<ChildType>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    parent_id: Option<String>,
+}
+
+impl ListChildrenRequestBuilder {
+    /// Sets the [`child_type`](struct.ListChildrenRequest.html#structfield.child_type) member.
+    pub fn child_type<T: Into<ChildType>>(mut self, value: T) -> Self {
+        self.child_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListChildrenRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListChildrenRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_id`](struct.ListChildrenRequest.html#structfield.parent_id) member.
+    pub fn parent_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListChildrenRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListChildrenRequest, ::rusoto_core::BuildError> {
+        Ok(ListChildrenRequest {
+            child_type: self
+                .child_type
+                .ok_or(::rusoto_core::BuildError::missing("child_type"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            parent_id: self
+                .parent_id
+                .ok_or(::rusoto_core::BuildError::missing("parent_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListChildrenResponse {
@@ -694,7 +2558,53 @@ pub struct ListCreateAccountStatusRequest {
     /// <p>A list of one or more states that you want included in the response. If this parameter isn't present, all requests are included in the response.</p>
     #[serde(rename = "States")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub states: Option<Vec<String>>,
+    pub states: Option<Vec<CreateAccountState>>,
+}
+
+impl ListCreateAccountStatusRequest {
+    /// Returns a builder for `ListCreateAccountStatusRequest`, checking its required members when built.
+    pub fn builder() -> ListCreateAccountStatusRequestBuilder {
+        ListCreateAccountStatusRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCreateAccountStatusRequest`](struct.ListCreateAccountStatusRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCreateAccountStatusRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    states: Option<Vec<CreateAccountState>>,
+}
+
+impl ListCreateAccountStatusRequestBuilder {
+    /// Sets the [`max_results`](struct.ListCreateAccountStatusRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListCreateAccountStatusRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`states`](struct.ListCreateAccountStatusRequest.html#structfield.states) member.
+    pub fn states<T: Into<Vec<CreateAccountState>>>(mut self, value: T) -> Self {
+        self.states = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCreateAccountStatusRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListCreateAccountStatusRequest, ::rusoto_core::BuildError> {
+        Ok(ListCreateAccountStatusRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            states: self.states,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -726,6 +2636,52 @@ pub struct ListHandshakesForAccountRequest {
     pub next_token: Option<String>,
 }
 
+impl ListHandshakesForAccountRequest {
+    /// Returns a builder for `ListHandshakesForAccountRequest`, checking its required members when built.
+    pub fn builder() -> ListHandshakesForAccountRequestBuilder {
+        ListHandshakesForAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListHandshakesForAccountRequest`](struct.ListHandshakesForAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct ListHandshakesForAccountRequestBuilder {
+    filter: Option<HandshakeFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListHandshakesForAccountRequestBuilder {
+    /// Sets the [`filter`](struct.ListHandshakesForAccountRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<HandshakeFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListHandshakesForAccountRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListHandshakesForAccountRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListHandshakesForAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListHandshakesForAccountRequest, ::rusoto_core::BuildError> {
+        Ok(ListHandshakesForAccountRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListHandshakesForAccountResponse {
@@ -755,6 +2711,53 @@ pub struct ListHandshakesForOrganizationRequest {
     pub next_token: Option<String>,
 }
 
+impl ListHandshakesForOrganizationRequest {
+    /// Returns a builder for `ListHandshakesForOrganizationRequest`, checking its required members when built.
+    pub fn builder() -> ListHandshakesForOrganizationRequestBuilder {
+        ListHandshakesForOrganizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListHandshakesForOrganizationRequest`](struct.ListHandshakesForOrganizationRequest.html).
+#[derive(Default, Debug)]
+pub struct ListHandshakesForOrganizationRequestBuilder {
+    filter: Option<HandshakeFilter>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListHandshakesForOrganizationRequestBuilder {
+    /// Sets the [`filter`](struct.ListHandshakesForOrganizationRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<HandshakeFilter>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListHandshakesForOrganizationRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListHandshakesForOrganizationRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListHandshakesForOrganizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListHandshakesForOrganizationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListHandshakesForOrganizationRequest {
+            filter: self.filter,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListHandshakesForOrganizationResponse {
@@ -783,6 +2786,55 @@ pub struct ListOrganizationalUnitsForParentRequest {
     pub parent_id: String,
 }
 
+impl ListOrganizationalUnitsForParentRequest {
+    /// Returns a builder for `ListOrganizationalUnitsForParentRequest`, checking its required members when built.
+    pub fn builder() -> ListOrganizationalUnitsForParentRequestBuilder {
+        ListOrganizationalUnitsForParentRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListOrganizationalUnitsForParentRequest`](struct.ListOrganizationalUnitsForParentRequest.html).
+#[derive(Default, Debug)]
+pub struct ListOrganizationalUnitsForParentRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    parent_id: Option<String>,
+}
+
+impl ListOrganizationalUnitsForParentRequestBuilder {
+    /// Sets the [`max_results`](struct.ListOrganizationalUnitsForParentRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListOrganizationalUnitsForParentRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parent_id`](struct.ListOrganizationalUnitsForParentRequest.html#structfield.parent_id) member.
+    pub fn parent_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.parent_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListOrganizationalUnitsForParentRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListOrganizationalUnitsForParentRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListOrganizationalUnitsForParentRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            parent_id: self
+                .parent_id
+                .ok_or(::rusoto_core::BuildError::missing("parent_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListOrganizationalUnitsForParentResponse {
@@ -811,6 +2863,52 @@ pub struct ListParentsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListParentsRequest {
+    /// Returns a builder for `ListParentsRequest`, checking its required members when built.
+    pub fn builder() -> ListParentsRequestBuilder {
+        ListParentsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListParentsRequest`](struct.ListParentsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListParentsRequestBuilder {
+    child_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListParentsRequestBuilder {
+    /// Sets the [`child_id`](struct.ListParentsRequest.html#structfield.child_id) member.
+    pub fn child_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.child_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListParentsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListParentsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListParentsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListParentsRequest, ::rusoto_core::BuildError> {
+        Ok(ListParentsRequest {
+            child_id: self
+                .child_id
+                .ok_or(::rusoto_core::BuildError::missing("child_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListParentsResponse {
@@ -824,11 +2922,11 @@ pub struct ListParentsResponse {
     pub parents: Option<Vec<Parent>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListPoliciesForTargetRequest {
     /// <p>The type of policy that you want to include in the returned list.</p>
     #[serde(rename = "Filter")]
-    pub filter: String,
+    pub filter: PolicyType,
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -842,6 +2940,64 @@ pub struct ListPoliciesForTargetRequest {
     pub target_id: String,
 }
 
+impl ListPoliciesForTargetRequest {
+    /// Returns a builder for `ListPoliciesForTargetRequest`, checking its required members when built.
+    pub fn builder() -> ListPoliciesForTargetRequestBuilder {
+        ListPoliciesForTargetRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPoliciesForTargetRequest`](struct.ListPoliciesForTargetRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPoliciesForTargetRequestBuilder {
+    filter: Option<PolicyType>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    target_id: Option<String>,
+}
+
+impl ListPoliciesForTargetRequestBuilder {
+    /// Sets the [`filter`](struct.ListPoliciesForTargetRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<PolicyType>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListPoliciesForTargetRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPoliciesForTargetRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_id`](struct.ListPoliciesForTargetRequest.html#structfield.target_id) member.
+    pub fn target_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPoliciesForTargetRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListPoliciesForTargetRequest, ::rusoto_core::BuildError> {
+        Ok(ListPoliciesForTargetRequest {
+            filter: self
+                .filter
+                .ok_or(::rusoto_core::BuildError::missing("filter"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            target_id: self
+                .target_id
+                .ok_or(::rusoto_core::BuildError::missing("target_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPoliciesForTargetResponse {
@@ -855,11 +3011,11 @@ pub struct ListPoliciesForTargetResponse {
     pub policies: Option<Vec<PolicySummary>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListPoliciesRequest {
     /// <p>Specifies the type of policy that you want to include in the response.</p>
     #[serde(rename = "Filter")]
-    pub filter: String,
+    pub filter: PolicyType,
     /// <p>(Optional) Use this to limit the number of results you want included per page in the response. If you do not include this parameter, it defaults to a value that is specific to the operation. If additional items exist beyond the maximum you specify, the <code>NextToken</code> response element is present and has a value (is not null). Include that value as the <code>NextToken</code> request parameter in the next call to the operation to get the next part of the results. Note that Organizations might return fewer results than the maximum even when there are more results available. You should check <code>NextToken</code> after every operation to ensure that you receive all of the results.</p>
     #[serde(rename = "MaxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -870,6 +3026,52 @@ pub struct ListPoliciesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListPoliciesRequest {
+    /// Returns a builder for `ListPoliciesRequest`, checking its required members when built.
+    pub fn builder() -> ListPoliciesRequestBuilder {
+        ListPoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPoliciesRequest`](struct.ListPoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPoliciesRequestBuilder {
+    filter: Option<PolicyType>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListPoliciesRequestBuilder {
+    /// Sets the [`filter`](struct.ListPoliciesRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<PolicyType>>(mut self, value: T) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListPoliciesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPoliciesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(ListPoliciesRequest {
+            filter: self
+                .filter
+                .ok_or(::rusoto_core::BuildError::missing("filter"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPoliciesResponse {
@@ -895,6 +3097,42 @@ pub struct ListRootsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListRootsRequest {
+    /// Returns a builder for `ListRootsRequest`, checking its required members when built.
+    pub fn builder() -> ListRootsRequestBuilder {
+        ListRootsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListRootsRequest`](struct.ListRootsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListRootsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListRootsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListRootsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListRootsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListRootsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListRootsRequest, ::rusoto_core::BuildError> {
+        Ok(ListRootsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListRootsResponse {
@@ -919,6 +3157,46 @@ pub struct ListTagsForResourceRequest {
     pub resource_id: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    next_token: Option<String>,
+    resource_id: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`next_token`](struct.ListTagsForResourceRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_id`](struct.ListTagsForResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            next_token: self.next_token,
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -947,6 +3225,54 @@ pub struct ListTargetsForPolicyRequest {
     pub policy_id: String,
 }
 
+impl ListTargetsForPolicyRequest {
+    /// Returns a builder for `ListTargetsForPolicyRequest`, checking its required members when built.
+    pub fn builder() -> ListTargetsForPolicyRequestBuilder {
+        ListTargetsForPolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTargetsForPolicyRequest`](struct.ListTargetsForPolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTargetsForPolicyRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    policy_id: Option<String>,
+}
+
+impl ListTargetsForPolicyRequestBuilder {
+    /// Sets the [`max_results`](struct.ListTargetsForPolicyRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListTargetsForPolicyRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_id`](struct.ListTargetsForPolicyRequest.html#structfield.policy_id) member.
+    pub fn policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTargetsForPolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTargetsForPolicyRequest, ::rusoto_core::BuildError> {
+        Ok(ListTargetsForPolicyRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            policy_id: self
+                .policy_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTargetsForPolicyResponse {
@@ -973,6 +3299,56 @@ pub struct MoveAccountRequest {
     pub source_parent_id: String,
 }
 
+impl MoveAccountRequest {
+    /// Returns a builder for `MoveAccountRequest`, checking its required members when built.
+    pub fn builder() -> MoveAccountRequestBuilder {
+        MoveAccountRequestBuilder::default()
+    }
+}
+
+/// Builds a [`MoveAccountRequest`](struct.MoveAccountRequest.html).
+#[derive(Default, Debug)]
+pub struct MoveAccountRequestBuilder {
+    account_id: Option<String>,
+    destination_parent_id: Option<String>,
+    source_parent_id: Option<String>,
+}
+
+impl MoveAccountRequestBuilder {
+    /// Sets the [`account_id`](struct.MoveAccountRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_parent_id`](struct.MoveAccountRequest.html#structfield.destination_parent_id) member.
+    pub fn destination_parent_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_parent_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_parent_id`](struct.MoveAccountRequest.html#structfield.source_parent_id) member.
+    pub fn source_parent_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_parent_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `MoveAccountRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<MoveAccountRequest, ::rusoto_core::BuildError> {
+        Ok(MoveAccountRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+            destination_parent_id: self
+                .destination_parent_id
+                .ok_or(::rusoto_core::BuildError::missing("destination_parent_id"))?,
+            source_parent_id: self
+                .source_parent_id
+                .ok_or(::rusoto_core::BuildError::missing("source_parent_id"))?,
+        })
+    }
+}
+
 /// <p>Contains details about an organization. An organization is a collection of accounts that are centrally managed together using consolidated billing, organized hierarchically with organizational units (OUs), and controlled with policies .</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -988,7 +3364,7 @@ pub struct Organization {
     /// <p>Specifies the functionality that currently is available to the organization. If set to "ALL", then all features are enabled and policies can be applied to accounts in the organization. If set to "CONSOLIDATED_BILLING", then only consolidated billing functionality is available. For more information, see <a href="https://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_org_support-all-features.html">Enabling All Features in Your Organization</a> in the <i>AWS Organizations User Guide</i>.</p>
     #[serde(rename = "FeatureSet")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub feature_set: Option<String>,
+    pub feature_set: Option<OrganizationFeatureSet>,
     /// <p>The unique identifier (ID) of an organization.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> for an organization ID string requires "o-" followed by from 10 to 32 lower-case letters or digits.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1007,6 +3383,78 @@ pub struct Organization {
     pub master_account_id: Option<String>,
 }
 
+/// <p><p>Specifies the feature set supported by the new organization. Each feature set supports different levels of functionality.</p> <ul> <li> <p> <code>CONSOLIDATED<em>BILLING</code>: All member accounts have their bills consolidated to and paid by the master account. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/organizations/latest/userguide/orgs</em>getting-started<em>concepts.html#feature-set-cb-only&quot;&gt;Consolidated billing</a> in the <i>AWS Organizations User Guide.</i> </p> <p> The consolidated billing feature subset isn&#39;t available for organizations in the AWS GovCloud (US) Region.</p> </li> <li> <p> <code>ALL</code>: In addition to all the features supported by the consolidated billing feature set, the master account can also apply any policy type to any member account in the organization. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/organizations/latest/userguide/orgs</em>getting-started_concepts.html#feature-set-all&quot;&gt;All features</a> in the <i>AWS Organizations User Guide.</i> </p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrganizationFeatureSet {
+    /// <p><code>ALL</code> - In addition to all the features supported by the consolidated billing feature set, the master account can also apply any policy type to any member account in the organization. For more information, see &lt;a href=&quot;https://docs.aws.amazon.com/organizations/latest/userguide/orgs</em>getting-started_concepts.html#feature-set-all&quot;&gt;All features</a> in the <i>AWS Organizations User Guide.</i></p>
+    All,
+    /// <p><code>CONSOLIDATED_BILLING</code></p>
+    ConsolidatedBilling,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OrganizationFeatureSet {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrganizationFeatureSet::All => "ALL",
+            OrganizationFeatureSet::ConsolidatedBilling => "CONSOLIDATED_BILLING",
+            OrganizationFeatureSet::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OrganizationFeatureSet {
+    fn from(value: &'a str) -> OrganizationFeatureSet {
+        match value {
+            "ALL" => OrganizationFeatureSet::All,
+            "CONSOLIDATED_BILLING" => OrganizationFeatureSet::ConsolidatedBilling,
+            _ => OrganizationFeatureSet::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrganizationFeatureSet {
+    fn from(value: String) -> OrganizationFeatureSet {
+        OrganizationFeatureSet::from(value.as_str())
+    }
+}
+
+impl From<OrganizationFeatureSet> for String {
+    fn from(value: OrganizationFeatureSet) -> String {
+        match value {
+            OrganizationFeatureSet::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OrganizationFeatureSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OrganizationFeatureSet {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrganizationFeatureSet {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OrganizationFeatureSet, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrganizationFeatureSet::from(value))
+    }
+}
+
 /// <p>Contains details about an organizational unit (OU). An OU is a container of AWS accounts within a root of an organization. Policies that are attached to an OU apply to all accounts contained in that OU and in any child OUs.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1036,7 +3484,79 @@ pub struct Parent {
     /// <p>The type of the parent entity.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<ParentType>,
+}
+
+/// <p>The type of the parent entity.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParentType {
+    /// <p><code>ROOT</code></p>
+    Root,
+    /// <p><code>ORGANIZATIONAL_UNIT</code></p>
+    OrganizationalUnit,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ParentType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParentType::Root => "ROOT",
+            ParentType::OrganizationalUnit => "ORGANIZATIONAL_UNIT",
+            ParentType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ParentType {
+    fn from(value: &'a str) -> ParentType {
+        match value {
+            "ROOT" => ParentType::Root,
+            "ORGANIZATIONAL_UNIT" => ParentType::OrganizationalUnit,
+            _ => ParentType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParentType {
+    fn from(value: String) -> ParentType {
+        ParentType::from(value.as_str())
+    }
+}
+
+impl From<ParentType> for String {
+    fn from(value: ParentType) -> String {
+        match value {
+            ParentType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ParentType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ParentType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ParentType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ParentType::from(value))
+    }
 }
 
 /// <p>Contains rules to be applied to the affected accounts. Policies can be attached directly to accounts, or to roots and OUs to affect all accounts in those hierarchies.</p>
@@ -1080,7 +3600,7 @@ pub struct PolicySummary {
     /// <p>The type of policy.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<PolicyType>,
 }
 
 /// <p>Contains information about a root, OU, or account that a policy is attached to.</p>
@@ -1102,7 +3622,163 @@ pub struct PolicyTargetSummary {
     /// <p>The type of the policy target.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TargetType>,
+}
+
+/// <p><p>The type of policy to create.</p> <note> <p>In the current release, the only type of policy that you can create is a service control policy (SCP).</p> </note></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyType {
+    /// <p><code>SERVICE_CONTROL_POLICY</code></p>
+    ServiceControlPolicy,
+    /// <p><code>TAG_POLICY</code></p>
+    TagPolicy,
+    /// <p><code>BACKUP_POLICY</code></p>
+    BackupPolicy,
+    /// <p><code>AISERVICES_OPT_OUT_POLICY</code></p>
+    AiservicesOptOutPolicy,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PolicyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyType::ServiceControlPolicy => "SERVICE_CONTROL_POLICY",
+            PolicyType::TagPolicy => "TAG_POLICY",
+            PolicyType::BackupPolicy => "BACKUP_POLICY",
+            PolicyType::AiservicesOptOutPolicy => "AISERVICES_OPT_OUT_POLICY",
+            PolicyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PolicyType {
+    fn from(value: &'a str) -> PolicyType {
+        match value {
+            "SERVICE_CONTROL_POLICY" => PolicyType::ServiceControlPolicy,
+            "TAG_POLICY" => PolicyType::TagPolicy,
+            "BACKUP_POLICY" => PolicyType::BackupPolicy,
+            "AISERVICES_OPT_OUT_POLICY" => PolicyType::AiservicesOptOutPolicy,
+            _ => PolicyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyType {
+    fn from(value: String) -> PolicyType {
+        PolicyType::from(value.as_str())
+    }
+}
+
+impl From<PolicyType> for String {
+    fn from(value: PolicyType) -> String {
+        match value {
+            PolicyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PolicyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PolicyType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PolicyType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PolicyType::from(value))
+    }
+}
+
+/// <p>The status of the policy type as it relates to the associated root. To attach a policy of the specified type to a root or to an OU or account in that root, it must be available in the organization and enabled for that root.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyTypeStatus {
+    /// <p><code>ENABLED</code></p>
+    Enabled,
+    /// <p><code>PENDING_ENABLE</code></p>
+    PendingEnable,
+    /// <p><code>PENDING_DISABLE</code></p>
+    PendingDisable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PolicyTypeStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PolicyTypeStatus::Enabled => "ENABLED",
+            PolicyTypeStatus::PendingEnable => "PENDING_ENABLE",
+            PolicyTypeStatus::PendingDisable => "PENDING_DISABLE",
+            PolicyTypeStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PolicyTypeStatus {
+    fn from(value: &'a str) -> PolicyTypeStatus {
+        match value {
+            "ENABLED" => PolicyTypeStatus::Enabled,
+            "PENDING_ENABLE" => PolicyTypeStatus::PendingEnable,
+            "PENDING_DISABLE" => PolicyTypeStatus::PendingDisable,
+            _ => PolicyTypeStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PolicyTypeStatus {
+    fn from(value: String) -> PolicyTypeStatus {
+        PolicyTypeStatus::from(value.as_str())
+    }
+}
+
+impl From<PolicyTypeStatus> for String {
+    fn from(value: PolicyTypeStatus) -> String {
+        match value {
+            PolicyTypeStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PolicyTypeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PolicyTypeStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PolicyTypeStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PolicyTypeStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PolicyTypeStatus::from(value))
+    }
 }
 
 /// <p>Contains information about a policy type and its status in the associated root.</p>
@@ -1112,11 +3788,11 @@ pub struct PolicyTypeSummary {
     /// <p>The status of the policy type as it relates to the associated root. To attach a policy of the specified type to a root or to an OU or account in that root, it must be available in the organization and enabled for that root.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<PolicyTypeStatus>,
     /// <p>The name of the policy type.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<PolicyType>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -1126,6 +3802,39 @@ pub struct RemoveAccountFromOrganizationRequest {
     pub account_id: String,
 }
 
+impl RemoveAccountFromOrganizationRequest {
+    /// Returns a builder for `RemoveAccountFromOrganizationRequest`, checking its required members when built.
+    pub fn builder() -> RemoveAccountFromOrganizationRequestBuilder {
+        RemoveAccountFromOrganizationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveAccountFromOrganizationRequest`](struct.RemoveAccountFromOrganizationRequest.html).
+#[derive(Default, Debug)]
+pub struct RemoveAccountFromOrganizationRequestBuilder {
+    account_id: Option<String>,
+}
+
+impl RemoveAccountFromOrganizationRequestBuilder {
+    /// Sets the [`account_id`](struct.RemoveAccountFromOrganizationRequest.html#structfield.account_id) member.
+    pub fn account_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveAccountFromOrganizationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveAccountFromOrganizationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(RemoveAccountFromOrganizationRequest {
+            account_id: self
+                .account_id
+                .ok_or(::rusoto_core::BuildError::missing("account_id"))?,
+        })
+    }
+}
+
 /// <p>Contains details about a root. A root is a top-level parent node in the hierarchy of an organization that can contain organizational units (OUs) and accounts. Every root contains every AWS account in the organization. Each root enables the accounts to be organized in a different way and to have different policy types enabled for use in that root.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1169,6 +3878,122 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_id`](struct.TagResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
+/// <p>The type of the policy target.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TargetType {
+    /// <p><code>ACCOUNT</code></p>
+    Account,
+    /// <p><code>ORGANIZATIONAL_UNIT</code></p>
+    OrganizationalUnit,
+    /// <p><code>ROOT</code></p>
+    Root,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TargetType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TargetType::Account => "ACCOUNT",
+            TargetType::OrganizationalUnit => "ORGANIZATIONAL_UNIT",
+            TargetType::Root => "ROOT",
+            TargetType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TargetType {
+    fn from(value: &'a str) -> TargetType {
+        match value {
+            "ACCOUNT" => TargetType::Account,
+            "ORGANIZATIONAL_UNIT" => TargetType::OrganizationalUnit,
+            "ROOT" => TargetType::Root,
+            _ => TargetType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TargetType {
+    fn from(value: String) -> TargetType {
+        TargetType::from(value.as_str())
+    }
+}
+
+impl From<TargetType> for String {
+    fn from(value: TargetType) -> String {
+        match value {
+            TargetType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TargetType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TargetType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TargetType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TargetType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TargetType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UntagResourceRequest {
     /// <p>The ID of the resource to remove the tag from.</p>
@@ -1179,6 +4004,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_id: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_id`](struct.UntagResourceRequest.html#structfield.resource_id) member.
+    pub fn resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_id: self
+                .resource_id
+                .ok_or(::rusoto_core::BuildError::missing("resource_id"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateOrganizationalUnitRequest {
     /// <p>The new name that you want to assign to the OU.</p> <p>The <a href="http://wikipedia.org/wiki/regex">regex pattern</a> that is used to validate this parameter is a string of any of the characters in the ASCII character range.</p>
@@ -1190,6 +4055,46 @@ pub struct UpdateOrganizationalUnitRequest {
     pub organizational_unit_id: String,
 }
 
+impl UpdateOrganizationalUnitRequest {
+    /// Returns a builder for `UpdateOrganizationalUnitRequest`, checking its required members when built.
+    pub fn builder() -> UpdateOrganizationalUnitRequestBuilder {
+        UpdateOrganizationalUnitRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateOrganizationalUnitRequest`](struct.UpdateOrganizationalUnitRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateOrganizationalUnitRequestBuilder {
+    name: Option<String>,
+    organizational_unit_id: Option<String>,
+}
+
+impl UpdateOrganizationalUnitRequestBuilder {
+    /// Sets the [`name`](struct.UpdateOrganizationalUnitRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`organizational_unit_id`](struct.UpdateOrganizationalUnitRequest.html#structfield.organizational_unit_id) member.
+    pub fn organizational_unit_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.organizational_unit_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateOrganizationalUnitRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateOrganizationalUnitRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateOrganizationalUnitRequest {
+            name: self.name,
+            organizational_unit_id: self
+                .organizational_unit_id
+                .ok_or(::rusoto_core::BuildError::missing("organizational_unit_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateOrganizationalUnitResponse {
@@ -1218,6 +4123,60 @@ pub struct UpdatePolicyRequest {
     pub policy_id: String,
 }
 
+impl UpdatePolicyRequest {
+    /// Returns a builder for `UpdatePolicyRequest`, checking its required members when built.
+    pub fn builder() -> UpdatePolicyRequestBuilder {
+        UpdatePolicyRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdatePolicyRequest`](struct.UpdatePolicyRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdatePolicyRequestBuilder {
+    content: Option<String>,
+    description: Option<String>,
+    name: Option<String>,
+    policy_id: Option<String>,
+}
+
+impl UpdatePolicyRequestBuilder {
+    /// Sets the [`content`](struct.UpdatePolicyRequest.html#structfield.content) member.
+    pub fn content<T: Into<String>>(mut self, value: T) -> Self {
+        self.content = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.UpdatePolicyRequest.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdatePolicyRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`policy_id`](struct.UpdatePolicyRequest.html#structfield.policy_id) member.
+    pub fn policy_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.policy_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdatePolicyRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdatePolicyRequest, ::rusoto_core::BuildError> {
+        Ok(UpdatePolicyRequest {
+            content: self.content,
+            description: self.description,
+            name: self.name,
+            policy_id: self
+                .policy_id
+                .ok_or(::rusoto_core::BuildError::missing("policy_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdatePolicyResponse {
@@ -5134,6 +8093,270 @@ pub trait Organizations {
         input: UpdatePolicyRequest,
     ) -> RusotoFuture<UpdatePolicyResponse, UpdatePolicyError>;
 }
+impl<T: ?Sized + Organizations> Organizations for ::std::sync::Arc<T> {
+    fn accept_handshake(
+        &self,
+        input: AcceptHandshakeRequest,
+    ) -> RusotoFuture<AcceptHandshakeResponse, AcceptHandshakeError> {
+        (**self).accept_handshake(input)
+    }
+    fn attach_policy(&self, input: AttachPolicyRequest) -> RusotoFuture<(), AttachPolicyError> {
+        (**self).attach_policy(input)
+    }
+    fn cancel_handshake(
+        &self,
+        input: CancelHandshakeRequest,
+    ) -> RusotoFuture<CancelHandshakeResponse, CancelHandshakeError> {
+        (**self).cancel_handshake(input)
+    }
+    fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> RusotoFuture<CreateAccountResponse, CreateAccountError> {
+        (**self).create_account(input)
+    }
+    fn create_gov_cloud_account(
+        &self,
+        input: CreateGovCloudAccountRequest,
+    ) -> RusotoFuture<CreateGovCloudAccountResponse, CreateGovCloudAccountError> {
+        (**self).create_gov_cloud_account(input)
+    }
+    fn create_organization(
+        &self,
+        input: CreateOrganizationRequest,
+    ) -> RusotoFuture<CreateOrganizationResponse, CreateOrganizationError> {
+        (**self).create_organization(input)
+    }
+    fn create_organizational_unit(
+        &self,
+        input: CreateOrganizationalUnitRequest,
+    ) -> RusotoFuture<CreateOrganizationalUnitResponse, CreateOrganizationalUnitError> {
+        (**self).create_organizational_unit(input)
+    }
+    fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> RusotoFuture<CreatePolicyResponse, CreatePolicyError> {
+        (**self).create_policy(input)
+    }
+    fn decline_handshake(
+        &self,
+        input: DeclineHandshakeRequest,
+    ) -> RusotoFuture<DeclineHandshakeResponse, DeclineHandshakeError> {
+        (**self).decline_handshake(input)
+    }
+    fn delete_organization(&self) -> RusotoFuture<(), DeleteOrganizationError> {
+        (**self).delete_organization()
+    }
+    fn delete_organizational_unit(
+        &self,
+        input: DeleteOrganizationalUnitRequest,
+    ) -> RusotoFuture<(), DeleteOrganizationalUnitError> {
+        (**self).delete_organizational_unit(input)
+    }
+    fn delete_policy(&self, input: DeletePolicyRequest) -> RusotoFuture<(), DeletePolicyError> {
+        (**self).delete_policy(input)
+    }
+    fn describe_account(
+        &self,
+        input: DescribeAccountRequest,
+    ) -> RusotoFuture<DescribeAccountResponse, DescribeAccountError> {
+        (**self).describe_account(input)
+    }
+    fn describe_create_account_status(
+        &self,
+        input: DescribeCreateAccountStatusRequest,
+    ) -> RusotoFuture<DescribeCreateAccountStatusResponse, DescribeCreateAccountStatusError> {
+        (**self).describe_create_account_status(input)
+    }
+    fn describe_handshake(
+        &self,
+        input: DescribeHandshakeRequest,
+    ) -> RusotoFuture<DescribeHandshakeResponse, DescribeHandshakeError> {
+        (**self).describe_handshake(input)
+    }
+    fn describe_organization(
+        &self,
+    ) -> RusotoFuture<DescribeOrganizationResponse, DescribeOrganizationError> {
+        (**self).describe_organization()
+    }
+    fn describe_organizational_unit(
+        &self,
+        input: DescribeOrganizationalUnitRequest,
+    ) -> RusotoFuture<DescribeOrganizationalUnitResponse, DescribeOrganizationalUnitError> {
+        (**self).describe_organizational_unit(input)
+    }
+    fn describe_policy(
+        &self,
+        input: DescribePolicyRequest,
+    ) -> RusotoFuture<DescribePolicyResponse, DescribePolicyError> {
+        (**self).describe_policy(input)
+    }
+    fn detach_policy(&self, input: DetachPolicyRequest) -> RusotoFuture<(), DetachPolicyError> {
+        (**self).detach_policy(input)
+    }
+    fn disable_aws_service_access(
+        &self,
+        input: DisableAWSServiceAccessRequest,
+    ) -> RusotoFuture<(), DisableAWSServiceAccessError> {
+        (**self).disable_aws_service_access(input)
+    }
+    fn disable_policy_type(
+        &self,
+        input: DisablePolicyTypeRequest,
+    ) -> RusotoFuture<DisablePolicyTypeResponse, DisablePolicyTypeError> {
+        (**self).disable_policy_type(input)
+    }
+    fn enable_aws_service_access(
+        &self,
+        input: EnableAWSServiceAccessRequest,
+    ) -> RusotoFuture<(), EnableAWSServiceAccessError> {
+        (**self).enable_aws_service_access(input)
+    }
+    fn enable_all_features(
+        &self,
+    ) -> RusotoFuture<EnableAllFeaturesResponse, EnableAllFeaturesError> {
+        (**self).enable_all_features()
+    }
+    fn enable_policy_type(
+        &self,
+        input: EnablePolicyTypeRequest,
+    ) -> RusotoFuture<EnablePolicyTypeResponse, EnablePolicyTypeError> {
+        (**self).enable_policy_type(input)
+    }
+    fn invite_account_to_organization(
+        &self,
+        input: InviteAccountToOrganizationRequest,
+    ) -> RusotoFuture<InviteAccountToOrganizationResponse, InviteAccountToOrganizationError> {
+        (**self).invite_account_to_organization(input)
+    }
+    fn leave_organization(&self) -> RusotoFuture<(), LeaveOrganizationError> {
+        (**self).leave_organization()
+    }
+    fn list_aws_service_access_for_organization(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> RusotoFuture<
+        ListAWSServiceAccessForOrganizationResponse,
+        ListAWSServiceAccessForOrganizationError,
+    > {
+        (**self).list_aws_service_access_for_organization(input)
+    }
+    fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> RusotoFuture<ListAccountsResponse, ListAccountsError> {
+        (**self).list_accounts(input)
+    }
+    fn list_accounts_for_parent(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> RusotoFuture<ListAccountsForParentResponse, ListAccountsForParentError> {
+        (**self).list_accounts_for_parent(input)
+    }
+    fn list_children(
+        &self,
+        input: ListChildrenRequest,
+    ) -> RusotoFuture<ListChildrenResponse, ListChildrenError> {
+        (**self).list_children(input)
+    }
+    fn list_create_account_status(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> RusotoFuture<ListCreateAccountStatusResponse, ListCreateAccountStatusError> {
+        (**self).list_create_account_status(input)
+    }
+    fn list_handshakes_for_account(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> RusotoFuture<ListHandshakesForAccountResponse, ListHandshakesForAccountError> {
+        (**self).list_handshakes_for_account(input)
+    }
+    fn list_handshakes_for_organization(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> RusotoFuture<ListHandshakesForOrganizationResponse, ListHandshakesForOrganizationError>
+    {
+        (**self).list_handshakes_for_organization(input)
+    }
+    fn list_organizational_units_for_parent(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> RusotoFuture<ListOrganizationalUnitsForParentResponse, ListOrganizationalUnitsForParentError>
+    {
+        (**self).list_organizational_units_for_parent(input)
+    }
+    fn list_parents(
+        &self,
+        input: ListParentsRequest,
+    ) -> RusotoFuture<ListParentsResponse, ListParentsError> {
+        (**self).list_parents(input)
+    }
+    fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> RusotoFuture<ListPoliciesResponse, ListPoliciesError> {
+        (**self).list_policies(input)
+    }
+    fn list_policies_for_target(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> RusotoFuture<ListPoliciesForTargetResponse, ListPoliciesForTargetError> {
+        (**self).list_policies_for_target(input)
+    }
+    fn list_roots(
+        &self,
+        input: ListRootsRequest,
+    ) -> RusotoFuture<ListRootsResponse, ListRootsError> {
+        (**self).list_roots(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn list_targets_for_policy(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> RusotoFuture<ListTargetsForPolicyResponse, ListTargetsForPolicyError> {
+        (**self).list_targets_for_policy(input)
+    }
+    fn move_account(&self, input: MoveAccountRequest) -> RusotoFuture<(), MoveAccountError> {
+        (**self).move_account(input)
+    }
+    fn remove_account_from_organization(
+        &self,
+        input: RemoveAccountFromOrganizationRequest,
+    ) -> RusotoFuture<(), RemoveAccountFromOrganizationError> {
+        (**self).remove_account_from_organization(input)
+    }
+    fn tag_resource(&self, input: TagResourceRequest) -> RusotoFuture<(), TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(&self, input: UntagResourceRequest) -> RusotoFuture<(), UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_organizational_unit(
+        &self,
+        input: UpdateOrganizationalUnitRequest,
+    ) -> RusotoFuture<UpdateOrganizationalUnitResponse, UpdateOrganizationalUnitError> {
+        (**self).update_organizational_unit(input)
+    }
+    fn update_policy(
+        &self,
+        input: UpdatePolicyRequest,
+    ) -> RusotoFuture<UpdatePolicyResponse, UpdatePolicyError> {
+        (**self).update_policy(input)
+    }
+}
+/// The error codes Organizations retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the Organizations API.
 #[derive(Clone)]
 pub struct OrganizationsClient {
@@ -5149,6 +8372,27 @@ impl OrganizationsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_ORGANIZATIONS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> OrganizationsClient {
+        Self::new(region::Region::from_env_for_service("ORGANIZATIONS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `organizations`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> OrganizationsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("organizations", "ORGANIZATIONS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -5167,7 +8411,54 @@ impl OrganizationsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> OrganizationsClient {
-        OrganizationsClient { client, region }
+        OrganizationsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "organizations")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "organizations",
+            "organizations",
+            "ORGANIZATIONS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Organizations` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Organizations + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for OrganizationsClient {
+    fn service_name(&self) -> &'static str {
+        "Organizations"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "organizations"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> OrganizationsClient {
+        Self::new_with_client(client, region)
     }
 }
 
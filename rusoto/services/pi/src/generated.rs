@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>A timestamp, and a single numerical value, which together represent a measurement at a particular point in time.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -29,17 +30,19 @@ use serde_json;
 pub struct DataPoint {
     /// <p>The time, in epoch format, associated with a particular <code>Value</code>.</p>
     #[serde(rename = "Timestamp")]
-    pub timestamp: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub timestamp: EpochTimestamp,
     /// <p>The actual value associated with a particular <code>Timestamp</code>.</p>
     #[serde(rename = "Value")]
     pub value: f64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeDimensionKeysRequest {
     /// <p>The date and time specifying the end of the requested time series data. The value specified is <i>exclusive</i> - data points less than (but not equal to) <code>EndTime</code> will be returned.</p> <p>The value for <code>EndTime</code> must be later than the value for <code>StartTime</code>.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p><p>One or more filters to apply in the request. Restrictions:</p> <ul> <li> <p>Any number of filters by the same dimension, as specified in the <code>GroupBy</code> or <code>Partition</code> parameters.</p> </li> <li> <p>A single filter for any other dimension in this dimension group.</p> </li> </ul></p>
     #[serde(rename = "Filter")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -71,10 +74,136 @@ pub struct DescribeDimensionKeysRequest {
     pub period_in_seconds: Option<i64>,
     /// <p>The AWS service for which Performance Insights will return metrics. The only valid value for <i>ServiceType</i> is: <code>RDS</code> </p>
     #[serde(rename = "ServiceType")]
-    pub service_type: String,
+    pub service_type: ServiceType,
     /// <p>The date and time specifying the beginning of the requested time series data. You can't specify a <code>StartTime</code> that's earlier than 7 days ago. The value specified is <i>inclusive</i> - data points equal to or greater than <code>StartTime</code> will be returned.</p> <p>The value for <code>StartTime</code> must be earlier than the value for <code>EndTime</code>.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
+}
+
+impl DescribeDimensionKeysRequest {
+    /// Returns a builder for `DescribeDimensionKeysRequest`, checking its required members when built.
+    pub fn builder() -> DescribeDimensionKeysRequestBuilder {
+        DescribeDimensionKeysRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDimensionKeysRequest`](struct.DescribeDimensionKeysRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeDimensionKeysRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    filter: Option<::std::collections::HashMap<String, String>>,
+    group_by: Option<DimensionGroup>,
+    identifier: Option<String>,
+    max_results: Option<i64>,
+    metric: Option<String>,
+    next_token: Option<String>,
+    partition_by: Option<DimensionGroup>,
+    period_in_seconds: Option<i64>,
+    service_type: Option<ServiceType>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl DescribeDimensionKeysRequestBuilder {
+    /// Sets the [`end_time`](struct.DescribeDimensionKeysRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filter`](struct.DescribeDimensionKeysRequest.html#structfield.filter) member.
+    pub fn filter<T: Into<::std::collections::HashMap<String, String>>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.filter = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_by`](struct.DescribeDimensionKeysRequest.html#structfield.group_by) member.
+    pub fn group_by<T: Into<DimensionGroup>>(mut self, value: T) -> Self {
+        self.group_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identifier`](struct.DescribeDimensionKeysRequest.html#structfield.identifier) member.
+    pub fn identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeDimensionKeysRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric`](struct.DescribeDimensionKeysRequest.html#structfield.metric) member.
+    pub fn metric<T: Into<String>>(mut self, value: T) -> Self {
+        self.metric = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeDimensionKeysRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`partition_by`](struct.DescribeDimensionKeysRequest.html#structfield.partition_by) member.
+    pub fn partition_by<T: Into<DimensionGroup>>(mut self, value: T) -> Self {
+        self.partition_by = Some(value.into());
+        self
+    }
+
+    /// Sets the [`period_in_seconds`](struct.DescribeDimensionKeysRequest.html#structfield.period_in_seconds) member.
+    pub fn period_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.period_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_type`](struct.DescribeDimensionKeysRequest.html#structfield.service_type) member.
+    pub fn service_type<T: Into<ServiceType>>(mut self, value: T) -> Self {
+        self.service_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.DescribeDimensionKeysRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDimensionKeysRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDimensionKeysRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeDimensionKeysRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            filter: self.filter,
+            group_by: self
+                .group_by
+                .ok_or(::rusoto_core::BuildError::missing("group_by"))?,
+            identifier: self
+                .identifier
+                .ok_or(::rusoto_core::BuildError::missing("identifier"))?,
+            max_results: self.max_results,
+            metric: self
+                .metric
+                .ok_or(::rusoto_core::BuildError::missing("metric"))?,
+            next_token: self.next_token,
+            partition_by: self.partition_by,
+            period_in_seconds: self.period_in_seconds,
+            service_type: self
+                .service_type
+                .ok_or(::rusoto_core::BuildError::missing("service_type"))?,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -82,12 +211,14 @@ pub struct DescribeDimensionKeysRequest {
 pub struct DescribeDimensionKeysResponse {
     /// <p>The end time for the returned dimension keys, after alignment to a granular boundary (as specified by <code>PeriodInSeconds</code>). <code>AlignedEndTime</code> will be greater than or equal to the value of the user-specified <code>Endtime</code>.</p>
     #[serde(rename = "AlignedEndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aligned_end_time: Option<f64>,
+    pub aligned_end_time: Option<EpochTimestamp>,
     /// <p>The start time for the returned dimension keys, after alignment to a granular boundary (as specified by <code>PeriodInSeconds</code>). <code>AlignedStartTime</code> will be less than or equal to the value of the user-specified <code>StartTime</code>.</p>
     #[serde(rename = "AlignedStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aligned_start_time: Option<f64>,
+    pub aligned_start_time: Option<EpochTimestamp>,
     /// <p>The dimension keys that were requested.</p>
     #[serde(rename = "Keys")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -136,11 +267,12 @@ pub struct DimensionKeyDescription {
     pub total: Option<f64>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetResourceMetricsRequest {
     /// <p>The date and time specifiying the end of the requested time series data. The value specified is <i>exclusive</i> - data points less than (but not equal to) <code>EndTime</code> will be returned.</p> <p>The value for <code>EndTime</code> must be later than the value for <code>StartTime</code>.</p>
     #[serde(rename = "EndTime")]
-    pub end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub end_time: EpochTimestamp,
     /// <p>An immutable, AWS Region-unique identifier for a data source. Performance Insights gathers metrics from this data source.</p> <p>To use an Amazon RDS instance as a data source, you specify its <code>DbiResourceId</code> value - for example: <code>db-FAIHNTYBKTGAUSUZQYPDS2GW4A</code> </p>
     #[serde(rename = "Identifier")]
     pub identifier: String,
@@ -161,10 +293,107 @@ pub struct GetResourceMetricsRequest {
     pub period_in_seconds: Option<i64>,
     /// <p>The AWS service for which Performance Insights will return metrics. The only valid value for <i>ServiceType</i> is: <code>RDS</code> </p>
     #[serde(rename = "ServiceType")]
-    pub service_type: String,
+    pub service_type: ServiceType,
     /// <p>The date and time specifying the beginning of the requested time series data. You can't specify a <code>StartTime</code> that's earlier than 7 days ago. The value specified is <i>inclusive</i> - data points equal to or greater than <code>StartTime</code> will be returned.</p> <p>The value for <code>StartTime</code> must be earlier than the value for <code>EndTime</code>.</p>
     #[serde(rename = "StartTime")]
-    pub start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub start_time: EpochTimestamp,
+}
+
+impl GetResourceMetricsRequest {
+    /// Returns a builder for `GetResourceMetricsRequest`, checking its required members when built.
+    pub fn builder() -> GetResourceMetricsRequestBuilder {
+        GetResourceMetricsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourceMetricsRequest`](struct.GetResourceMetricsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResourceMetricsRequestBuilder {
+    end_time: Option<EpochTimestamp>,
+    identifier: Option<String>,
+    max_results: Option<i64>,
+    metric_queries: Option<Vec<MetricQuery>>,
+    next_token: Option<String>,
+    period_in_seconds: Option<i64>,
+    service_type: Option<ServiceType>,
+    start_time: Option<EpochTimestamp>,
+}
+
+impl GetResourceMetricsRequestBuilder {
+    /// Sets the [`end_time`](struct.GetResourceMetricsRequest.html#structfield.end_time) member.
+    pub fn end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`identifier`](struct.GetResourceMetricsRequest.html#structfield.identifier) member.
+    pub fn identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetResourceMetricsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`metric_queries`](struct.GetResourceMetricsRequest.html#structfield.metric_queries) member.
+    pub fn metric_queries<T: Into<Vec<MetricQuery>>>(mut self, value: T) -> Self {
+        self.metric_queries = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetResourceMetricsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`period_in_seconds`](struct.GetResourceMetricsRequest.html#structfield.period_in_seconds) member.
+    pub fn period_in_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.period_in_seconds = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_type`](struct.GetResourceMetricsRequest.html#structfield.service_type) member.
+    pub fn service_type<T: Into<ServiceType>>(mut self, value: T) -> Self {
+        self.service_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.GetResourceMetricsRequest.html#structfield.start_time) member.
+    pub fn start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourceMetricsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetResourceMetricsRequest, ::rusoto_core::BuildError> {
+        Ok(GetResourceMetricsRequest {
+            end_time: self
+                .end_time
+                .ok_or(::rusoto_core::BuildError::missing("end_time"))?,
+            identifier: self
+                .identifier
+                .ok_or(::rusoto_core::BuildError::missing("identifier"))?,
+            max_results: self.max_results,
+            metric_queries: self
+                .metric_queries
+                .ok_or(::rusoto_core::BuildError::missing("metric_queries"))?,
+            next_token: self.next_token,
+            period_in_seconds: self.period_in_seconds,
+            service_type: self
+                .service_type
+                .ok_or(::rusoto_core::BuildError::missing("service_type"))?,
+            start_time: self
+                .start_time
+                .ok_or(::rusoto_core::BuildError::missing("start_time"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -172,12 +401,14 @@ pub struct GetResourceMetricsRequest {
 pub struct GetResourceMetricsResponse {
     /// <p>The end time for the returned metrics, after alignment to a granular boundary (as specified by <code>PeriodInSeconds</code>). <code>AlignedEndTime</code> will be greater than or equal to the value of the user-specified <code>Endtime</code>.</p>
     #[serde(rename = "AlignedEndTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aligned_end_time: Option<f64>,
+    pub aligned_end_time: Option<EpochTimestamp>,
     /// <p>The start time for the returned metrics, after alignment to a granular boundary (as specified by <code>PeriodInSeconds</code>). <code>AlignedStartTime</code> will be less than or equal to the value of the user-specified <code>StartTime</code>.</p>
     #[serde(rename = "AlignedStartTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub aligned_start_time: Option<f64>,
+    pub aligned_start_time: Option<EpochTimestamp>,
     /// <p>An immutable, AWS Region-unique identifier for a data source. Performance Insights gathers metrics from this data source.</p> <p>To use an Amazon RDS instance as a data source, you specify its <code>DbiResourceId</code> value - for example: <code>db-FAIHNTYBKTGAUSUZQYPDS2GW4A</code> </p>
     #[serde(rename = "Identifier")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -244,6 +475,78 @@ pub struct ResponseResourceMetricKey {
     pub metric: String,
 }
 
+/// <p>The AWS service for which Performance Insights will return metrics. The only valid value for <i>ServiceType</i> is: <code>RDS</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ServiceType {
+    /// <p><code>RDS</code></p>
+    Rds,
+    /// <p><code>DOCDB</code></p>
+    Docdb,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ServiceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ServiceType::Rds => "RDS",
+            ServiceType::Docdb => "DOCDB",
+            ServiceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ServiceType {
+    fn from(value: &'a str) -> ServiceType {
+        match value {
+            "RDS" => ServiceType::Rds,
+            "DOCDB" => ServiceType::Docdb,
+            _ => ServiceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ServiceType {
+    fn from(value: String) -> ServiceType {
+        ServiceType::from(value.as_str())
+    }
+}
+
+impl From<ServiceType> for String {
+    fn from(value: ServiceType) -> String {
+        match value {
+            ServiceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ServiceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ServiceType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ServiceType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ServiceType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ServiceType::from(value))
+    }
+}
+
 /// Errors returned by DescribeDimensionKeys
 #[derive(Debug, PartialEq)]
 pub enum DescribeDimensionKeysError {
@@ -354,6 +657,27 @@ pub trait PerformanceInsights {
         input: GetResourceMetricsRequest,
     ) -> RusotoFuture<GetResourceMetricsResponse, GetResourceMetricsError>;
 }
+impl<T: ?Sized + PerformanceInsights> PerformanceInsights for ::std::sync::Arc<T> {
+    fn describe_dimension_keys(
+        &self,
+        input: DescribeDimensionKeysRequest,
+    ) -> RusotoFuture<DescribeDimensionKeysResponse, DescribeDimensionKeysError> {
+        (**self).describe_dimension_keys(input)
+    }
+    fn get_resource_metrics(
+        &self,
+        input: GetResourceMetricsRequest,
+    ) -> RusotoFuture<GetResourceMetricsResponse, GetResourceMetricsError> {
+        (**self).get_resource_metrics(input)
+    }
+}
+/// The error codes AWS PI retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS PI API.
 #[derive(Clone)]
 pub struct PerformanceInsightsClient {
@@ -369,6 +693,24 @@ impl PerformanceInsightsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_PI`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> PerformanceInsightsClient {
+        Self::new(region::Region::from_env_for_service("PI"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `pi`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> PerformanceInsightsClient {
+        Self::new_with_client(config.client().clone(), config.region_for("pi", "PI"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -387,7 +729,49 @@ impl PerformanceInsightsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> PerformanceInsightsClient {
-        PerformanceInsightsClient { client, region }
+        PerformanceInsightsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "pi")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "pi", "pi", "PI")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `PerformanceInsights` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn PerformanceInsights + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for PerformanceInsightsClient {
+    fn service_name(&self) -> &'static str {
+        "AWS PI"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "pi"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> PerformanceInsightsClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Polly clients.
+//!
+//! Makes a single read-only `ListLexicons` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_polly::{ListLexiconsInput, Polly, PollyClient};
+
+fn main() {
+    let client = PollyClient::from_env();
+    match client.list_lexicons(ListLexiconsInput::default()).sync() {
+        Ok(_) => println!("ListLexicons succeeded"),
+        Err(error) => {
+            eprintln!("ListLexicons failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DeleteLexiconInput {
@@ -31,6 +32,36 @@ pub struct DeleteLexiconInput {
     pub name: String,
 }
 
+impl DeleteLexiconInput {
+    /// Returns a builder for `DeleteLexiconInput`, checking its required members when built.
+    pub fn builder() -> DeleteLexiconInputBuilder {
+        DeleteLexiconInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLexiconInput`](struct.DeleteLexiconInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteLexiconInputBuilder {
+    name: Option<String>,
+}
+
+impl DeleteLexiconInputBuilder {
+    /// Sets the [`name`](struct.DeleteLexiconInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLexiconInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteLexiconInput, ::rusoto_core::BuildError> {
+        Ok(DeleteLexiconInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteLexiconOutput {}
@@ -40,7 +71,7 @@ pub struct DescribeVoicesInput {
     /// <p>Specifies the engine (<code>standard</code> or <code>neural</code>) used by Amazon Polly when processing input text for speech synthesis. </p>
     #[serde(rename = "Engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine: Option<String>,
+    pub engine: Option<Engine>,
     /// <p>Boolean value indicating whether to return any bilingual voices that use the specified language as an additional language. For instance, if you request all languages that use US English (es-US), and there is an Italian voice that speaks both Italian (it-IT) and US English, that voice will be included if you specify <code>yes</code> but not if you specify <code>no</code>.</p>
     #[serde(rename = "IncludeAdditionalLanguageCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -48,13 +79,65 @@ pub struct DescribeVoicesInput {
     /// <p> The language identification tag (ISO 639 code for the language name-ISO 3166 country code) for filtering the list of voices returned. If you don't specify this optional parameter, all available voices are returned. </p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>An opaque pagination token returned from the previous <code>DescribeVoices</code> operation. If present, this indicates where to continue the listing.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_token: Option<String>,
 }
 
+impl DescribeVoicesInput {
+    /// Returns a builder for `DescribeVoicesInput`, checking its required members when built.
+    pub fn builder() -> DescribeVoicesInputBuilder {
+        DescribeVoicesInputBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeVoicesInput`](struct.DescribeVoicesInput.html).
+#[derive(Default, Debug)]
+pub struct DescribeVoicesInputBuilder {
+    engine: Option<Engine>,
+    include_additional_language_codes: Option<bool>,
+    language_code: Option<LanguageCode>,
+    next_token: Option<String>,
+}
+
+impl DescribeVoicesInputBuilder {
+    /// Sets the [`engine`](struct.DescribeVoicesInput.html#structfield.engine) member.
+    pub fn engine<T: Into<Engine>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_additional_language_codes`](struct.DescribeVoicesInput.html#structfield.include_additional_language_codes) member.
+    pub fn include_additional_language_codes<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_additional_language_codes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`language_code`](struct.DescribeVoicesInput.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeVoicesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeVoicesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeVoicesInput, ::rusoto_core::BuildError> {
+        Ok(DescribeVoicesInput {
+            engine: self.engine,
+            include_additional_language_codes: self.include_additional_language_codes,
+            language_code: self.language_code,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeVoicesOutput {
@@ -68,6 +151,166 @@ pub struct DescribeVoicesOutput {
     pub voices: Option<Vec<Voice>>,
 }
 
+/// <p>Specifies the engine (<code>standard</code> or <code>neural</code>) used by Amazon Polly when processing input text for speech synthesis. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Engine {
+    /// <p><code>standard</code></p>
+    Standard,
+    /// <p><code>neural</code></p>
+    Neural,
+    /// <p><code>long-form</code></p>
+    LongForm,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Engine {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Engine::Standard => "standard",
+            Engine::Neural => "neural",
+            Engine::LongForm => "long-form",
+            Engine::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Engine {
+    fn from(value: &'a str) -> Engine {
+        match value {
+            "standard" => Engine::Standard,
+            "neural" => Engine::Neural,
+            "long-form" => Engine::LongForm,
+            _ => Engine::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Engine {
+    fn from(value: String) -> Engine {
+        Engine::from(value.as_str())
+    }
+}
+
+impl From<Engine> for String {
+    fn from(value: Engine) -> String {
+        match value {
+            Engine::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Engine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Engine {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Engine {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Engine {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Engine, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Engine::from(value))
+    }
+}
+
+/// <p>Gender of the voice.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Gender {
+    /// <p><code>Female</code></p>
+    Female,
+    /// <p><code>Male</code></p>
+    Male,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Gender {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Gender::Female => "Female",
+            Gender::Male => "Male",
+            Gender::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Gender {
+    fn from(value: &'a str) -> Gender {
+        match value {
+            "Female" => Gender::Female,
+            "Male" => Gender::Male,
+            _ => Gender::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Gender {
+    fn from(value: String) -> Gender {
+        Gender::from(value.as_str())
+    }
+}
+
+impl From<Gender> for String {
+    fn from(value: Gender) -> String {
+        match value {
+            Gender::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Gender {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for Gender {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for Gender {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Gender {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Gender, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Gender::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetLexiconInput {
     /// <p>Name of the lexicon.</p>
@@ -75,6 +318,36 @@ pub struct GetLexiconInput {
     pub name: String,
 }
 
+impl GetLexiconInput {
+    /// Returns a builder for `GetLexiconInput`, checking its required members when built.
+    pub fn builder() -> GetLexiconInputBuilder {
+        GetLexiconInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetLexiconInput`](struct.GetLexiconInput.html).
+#[derive(Default, Debug)]
+pub struct GetLexiconInputBuilder {
+    name: Option<String>,
+}
+
+impl GetLexiconInputBuilder {
+    /// Sets the [`name`](struct.GetLexiconInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLexiconInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetLexiconInput, ::rusoto_core::BuildError> {
+        Ok(GetLexiconInput {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetLexiconOutput {
@@ -95,6 +368,38 @@ pub struct GetSpeechSynthesisTaskInput {
     pub task_id: String,
 }
 
+impl GetSpeechSynthesisTaskInput {
+    /// Returns a builder for `GetSpeechSynthesisTaskInput`, checking its required members when built.
+    pub fn builder() -> GetSpeechSynthesisTaskInputBuilder {
+        GetSpeechSynthesisTaskInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetSpeechSynthesisTaskInput`](struct.GetSpeechSynthesisTaskInput.html).
+#[derive(Default, Debug)]
+pub struct GetSpeechSynthesisTaskInputBuilder {
+    task_id: Option<String>,
+}
+
+impl GetSpeechSynthesisTaskInputBuilder {
+    /// Sets the [`task_id`](struct.GetSpeechSynthesisTaskInput.html#structfield.task_id) member.
+    pub fn task_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.task_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetSpeechSynthesisTaskInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetSpeechSynthesisTaskInput, ::rusoto_core::BuildError> {
+        Ok(GetSpeechSynthesisTaskInput {
+            task_id: self
+                .task_id
+                .ok_or(::rusoto_core::BuildError::missing("task_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetSpeechSynthesisTaskOutput {
@@ -104,6 +409,232 @@ pub struct GetSpeechSynthesisTaskOutput {
     pub synthesis_task: Option<SynthesisTask>,
 }
 
+/// <p> The language identification tag (ISO 639 code for the language name-ISO 3166 country code) for filtering the list of voices returned. If you don't specify this optional parameter, all available voices are returned. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LanguageCode {
+    /// <p><code>arb</code></p>
+    Arb,
+    /// <p><code>cmn-CN</code></p>
+    CmnCn,
+    /// <p><code>cy-GB</code></p>
+    CyGb,
+    /// <p><code>da-DK</code></p>
+    DaDk,
+    /// <p><code>de-DE</code></p>
+    DeDe,
+    /// <p><code>en-AU</code></p>
+    EnAu,
+    /// <p><code>en-GB</code></p>
+    EnGb,
+    /// <p><code>en-GB-WLS</code></p>
+    EnGbWls,
+    /// <p><code>en-IN</code></p>
+    EnIn,
+    /// <p><code>en-US</code></p>
+    EnUs,
+    /// <p><code>es-ES</code></p>
+    EsEs,
+    /// <p><code>es-MX</code></p>
+    EsMx,
+    /// <p><code>es-US</code></p>
+    EsUs,
+    /// <p><code>fr-CA</code></p>
+    FrCa,
+    /// <p><code>fr-FR</code></p>
+    FrFr,
+    /// <p><code>is-IS</code></p>
+    IsIs,
+    /// <p><code>it-IT</code></p>
+    ItIt,
+    /// <p><code>ja-JP</code></p>
+    JaJp,
+    /// <p><code>hi-IN</code></p>
+    HiIn,
+    /// <p><code>ko-KR</code></p>
+    KoKr,
+    /// <p><code>nb-NO</code></p>
+    NbNo,
+    /// <p><code>nl-NL</code></p>
+    NlNl,
+    /// <p><code>pl-PL</code></p>
+    PlPl,
+    /// <p><code>pt-BR</code></p>
+    PtBr,
+    /// <p><code>pt-PT</code></p>
+    PtPt,
+    /// <p><code>ro-RO</code></p>
+    RoRo,
+    /// <p><code>ru-RU</code></p>
+    RuRu,
+    /// <p><code>sv-SE</code></p>
+    SvSe,
+    /// <p><code>tr-TR</code></p>
+    TrTr,
+    /// <p><code>en-NZ</code></p>
+    EnNz,
+    /// <p><code>en-ZA</code></p>
+    EnZa,
+    /// <p><code>ca-ES</code></p>
+    CaEs,
+    /// <p><code>de-AT</code></p>
+    DeAt,
+    /// <p><code>yue-CN</code></p>
+    YueCn,
+    /// <p><code>ar-AE</code></p>
+    ArAe,
+    /// <p><code>fi-FI</code></p>
+    FiFi,
+    /// <p><code>en-IE</code></p>
+    EnIe,
+    /// <p><code>nl-BE</code></p>
+    NlBe,
+    /// <p><code>fr-BE</code></p>
+    FrBe,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LanguageCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LanguageCode::Arb => "arb",
+            LanguageCode::CmnCn => "cmn-CN",
+            LanguageCode::CyGb => "cy-GB",
+            LanguageCode::DaDk => "da-DK",
+            LanguageCode::DeDe => "de-DE",
+            LanguageCode::EnAu => "en-AU",
+            LanguageCode::EnGb => "en-GB",
+            LanguageCode::EnGbWls => "en-GB-WLS",
+            LanguageCode::EnIn => "en-IN",
+            LanguageCode::EnUs => "en-US",
+            LanguageCode::EsEs => "es-ES",
+            LanguageCode::EsMx => "es-MX",
+            LanguageCode::EsUs => "es-US",
+            LanguageCode::FrCa => "fr-CA",
+            LanguageCode::FrFr => "fr-FR",
+            LanguageCode::IsIs => "is-IS",
+            LanguageCode::ItIt => "it-IT",
+            LanguageCode::JaJp => "ja-JP",
+            LanguageCode::HiIn => "hi-IN",
+            LanguageCode::KoKr => "ko-KR",
+            LanguageCode::NbNo => "nb-NO",
+            LanguageCode::NlNl => "nl-NL",
+            LanguageCode::PlPl => "pl-PL",
+            LanguageCode::PtBr => "pt-BR",
+            LanguageCode::PtPt => "pt-PT",
+            LanguageCode::RoRo => "ro-RO",
+            LanguageCode::RuRu => "ru-RU",
+            LanguageCode::SvSe => "sv-SE",
+            LanguageCode::TrTr => "tr-TR",
+            LanguageCode::EnNz => "en-NZ",
+            LanguageCode::EnZa => "en-ZA",
+            LanguageCode::CaEs => "ca-ES",
+            LanguageCode::DeAt => "de-AT",
+            LanguageCode::YueCn => "yue-CN",
+            LanguageCode::ArAe => "ar-AE",
+            LanguageCode::FiFi => "fi-FI",
+            LanguageCode::EnIe => "en-IE",
+            LanguageCode::NlBe => "nl-BE",
+            LanguageCode::FrBe => "fr-BE",
+            LanguageCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LanguageCode {
+    fn from(value: &'a str) -> LanguageCode {
+        match value {
+            "arb" => LanguageCode::Arb,
+            "cmn-CN" => LanguageCode::CmnCn,
+            "cy-GB" => LanguageCode::CyGb,
+            "da-DK" => LanguageCode::DaDk,
+            "de-DE" => LanguageCode::DeDe,
+            "en-AU" => LanguageCode::EnAu,
+            "en-GB" => LanguageCode::EnGb,
+            "en-GB-WLS" => LanguageCode::EnGbWls,
+            "en-IN" => LanguageCode::EnIn,
+            "en-US" => LanguageCode::EnUs,
+            "es-ES" => LanguageCode::EsEs,
+            "es-MX" => LanguageCode::EsMx,
+            "es-US" => LanguageCode::EsUs,
+            "fr-CA" => LanguageCode::FrCa,
+            "fr-FR" => LanguageCode::FrFr,
+            "is-IS" => LanguageCode::IsIs,
+            "it-IT" => LanguageCode::ItIt,
+            "ja-JP" => LanguageCode::JaJp,
+            "hi-IN" => LanguageCode::HiIn,
+            "ko-KR" => LanguageCode::KoKr,
+            "nb-NO" => LanguageCode::NbNo,
+            "nl-NL" => LanguageCode::NlNl,
+            "pl-PL" => LanguageCode::PlPl,
+            "pt-BR" => LanguageCode::PtBr,
+            "pt-PT" => LanguageCode::PtPt,
+            "ro-RO" => LanguageCode::RoRo,
+            "ru-RU" => LanguageCode::RuRu,
+            "sv-SE" => LanguageCode::SvSe,
+            "tr-TR" => LanguageCode::TrTr,
+            "en-NZ" => LanguageCode::EnNz,
+            "en-ZA" => LanguageCode::EnZa,
+            "ca-ES" => LanguageCode::CaEs,
+            "de-AT" => LanguageCode::DeAt,
+            "yue-CN" => LanguageCode::YueCn,
+            "ar-AE" => LanguageCode::ArAe,
+            "fi-FI" => LanguageCode::FiFi,
+            "en-IE" => LanguageCode::EnIe,
+            "nl-BE" => LanguageCode::NlBe,
+            "fr-BE" => LanguageCode::FrBe,
+            _ => LanguageCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LanguageCode {
+    fn from(value: String) -> LanguageCode {
+        LanguageCode::from(value.as_str())
+    }
+}
+
+impl From<LanguageCode> for String {
+    fn from(value: LanguageCode) -> String {
+        match value {
+            LanguageCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LanguageCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LanguageCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LanguageCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LanguageCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LanguageCode::from(value))
+    }
+}
+
 /// <p>Provides lexicon name and lexicon content in string format. For more information, see <a href="https://www.w3.org/TR/pronunciation-lexicon/">Pronunciation Lexicon Specification (PLS) Version 1.0</a>.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -129,11 +660,12 @@ pub struct LexiconAttributes {
     /// <p>Language code that the lexicon applies to. A lexicon with a language code such as "en" would be applied to all English languages (en-GB, en-US, en-AUS, en-WLS, and so on.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>Date lexicon was last modified (a timestamp value).</p>
     #[serde(rename = "LastModified")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_modified: Option<f64>,
+    pub last_modified: Option<EpochTimestamp>,
     /// <p>Number of lexemes in the lexicon.</p>
     #[serde(rename = "LexemesCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -170,6 +702,34 @@ pub struct ListLexiconsInput {
     pub next_token: Option<String>,
 }
 
+impl ListLexiconsInput {
+    /// Returns a builder for `ListLexiconsInput`, checking its required members when built.
+    pub fn builder() -> ListLexiconsInputBuilder {
+        ListLexiconsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListLexiconsInput`](struct.ListLexiconsInput.html).
+#[derive(Default, Debug)]
+pub struct ListLexiconsInputBuilder {
+    next_token: Option<String>,
+}
+
+impl ListLexiconsInputBuilder {
+    /// Sets the [`next_token`](struct.ListLexiconsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLexiconsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListLexiconsInput, ::rusoto_core::BuildError> {
+        Ok(ListLexiconsInput {
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLexiconsOutput {
@@ -196,7 +756,53 @@ pub struct ListSpeechSynthesisTasksInput {
     /// <p>Status of the speech synthesis tasks returned in a List operation</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<TaskStatus>,
+}
+
+impl ListSpeechSynthesisTasksInput {
+    /// Returns a builder for `ListSpeechSynthesisTasksInput`, checking its required members when built.
+    pub fn builder() -> ListSpeechSynthesisTasksInputBuilder {
+        ListSpeechSynthesisTasksInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListSpeechSynthesisTasksInput`](struct.ListSpeechSynthesisTasksInput.html).
+#[derive(Default, Debug)]
+pub struct ListSpeechSynthesisTasksInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    status: Option<TaskStatus>,
+}
+
+impl ListSpeechSynthesisTasksInputBuilder {
+    /// Sets the [`max_results`](struct.ListSpeechSynthesisTasksInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListSpeechSynthesisTasksInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`status`](struct.ListSpeechSynthesisTasksInput.html#structfield.status) member.
+    pub fn status<T: Into<TaskStatus>>(mut self, value: T) -> Self {
+        self.status = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListSpeechSynthesisTasksInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListSpeechSynthesisTasksInput, ::rusoto_core::BuildError> {
+        Ok(ListSpeechSynthesisTasksInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            status: self.status,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -212,6 +818,92 @@ pub struct ListSpeechSynthesisTasksOutput {
     pub synthesis_tasks: Option<Vec<SynthesisTask>>,
 }
 
+/// <p>The format in which the returned output will be encoded. For audio stream, this will be mp3, ogg_vorbis, or pcm. For speech marks, this will be json. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OutputFormat {
+    /// <p><code>json</code></p>
+    Json,
+    /// <p><code>mp3</code></p>
+    Mp3,
+    /// <p><code>ogg_vorbis</code></p>
+    OggVorbis,
+    /// <p><code>pcm</code></p>
+    Pcm,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OutputFormat {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OutputFormat::Json => "json",
+            OutputFormat::Mp3 => "mp3",
+            OutputFormat::OggVorbis => "ogg_vorbis",
+            OutputFormat::Pcm => "pcm",
+            OutputFormat::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OutputFormat {
+    fn from(value: &'a str) -> OutputFormat {
+        match value {
+            "json" => OutputFormat::Json,
+            "mp3" => OutputFormat::Mp3,
+            "ogg_vorbis" => OutputFormat::OggVorbis,
+            "pcm" => OutputFormat::Pcm,
+            _ => OutputFormat::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OutputFormat {
+    fn from(value: String) -> OutputFormat {
+        OutputFormat::from(value.as_str())
+    }
+}
+
+impl From<OutputFormat> for String {
+    fn from(value: OutputFormat) -> String {
+        match value {
+            OutputFormat::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for OutputFormat {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for OutputFormat {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OutputFormat {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OutputFormat, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OutputFormat::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct PutLexiconInput {
     /// <p>Content of the PLS lexicon as string data.</p>
@@ -222,27 +914,153 @@ pub struct PutLexiconInput {
     pub name: String,
 }
 
+impl PutLexiconInput {
+    /// Returns a builder for `PutLexiconInput`, checking its required members when built.
+    pub fn builder() -> PutLexiconInputBuilder {
+        PutLexiconInputBuilder::default()
+    }
+}
+
+/// Builds a [`PutLexiconInput`](struct.PutLexiconInput.html).
+#[derive(Default, Debug)]
+pub struct PutLexiconInputBuilder {
+    content: Option<String>,
+    name: Option<String>,
+}
+
+impl PutLexiconInputBuilder {
+    /// Sets the [`content`](struct.PutLexiconInput.html#structfield.content) member.
+    pub fn content<T: Into<String>>(mut self, value: T) -> Self {
+        self.content = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.PutLexiconInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `PutLexiconInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<PutLexiconInput, ::rusoto_core::BuildError> {
+        Ok(PutLexiconInput {
+            content: self
+                .content
+                .ok_or(::rusoto_core::BuildError::missing("content"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct PutLexiconOutput {}
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+/// <p>The type of speech marks returned for the input text.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SpeechMarkType {
+    /// <p><code>sentence</code></p>
+    Sentence,
+    /// <p><code>ssml</code></p>
+    Ssml,
+    /// <p><code>viseme</code></p>
+    Viseme,
+    /// <p><code>word</code></p>
+    Word,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SpeechMarkType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SpeechMarkType::Sentence => "sentence",
+            SpeechMarkType::Ssml => "ssml",
+            SpeechMarkType::Viseme => "viseme",
+            SpeechMarkType::Word => "word",
+            SpeechMarkType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SpeechMarkType {
+    fn from(value: &'a str) -> SpeechMarkType {
+        match value {
+            "sentence" => SpeechMarkType::Sentence,
+            "ssml" => SpeechMarkType::Ssml,
+            "viseme" => SpeechMarkType::Viseme,
+            "word" => SpeechMarkType::Word,
+            _ => SpeechMarkType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SpeechMarkType {
+    fn from(value: String) -> SpeechMarkType {
+        SpeechMarkType::from(value.as_str())
+    }
+}
+
+impl From<SpeechMarkType> for String {
+    fn from(value: SpeechMarkType) -> String {
+        match value {
+            SpeechMarkType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SpeechMarkType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SpeechMarkType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for SpeechMarkType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for SpeechMarkType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<SpeechMarkType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(SpeechMarkType::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct StartSpeechSynthesisTaskInput {
     /// <p>Specifies the engine (<code>standard</code> or <code>neural</code>) for Amazon Polly to use when processing input text for speech synthesis. Using a voice that is not supported for the engine selected will result in an error.</p>
     #[serde(rename = "Engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine: Option<String>,
+    pub engine: Option<Engine>,
     /// <p>Optional language code for the Speech Synthesis request. This is only necessary if using a bilingual voice, such as Aditi, which can be used for either Indian English (en-IN) or Hindi (hi-IN). </p> <p>If a bilingual voice is used and no language code is specified, Amazon Polly will use the default language of the bilingual voice. The default language for any voice is the one returned by the <a href="https://docs.aws.amazon.com/polly/latest/dg/API_DescribeVoices.html">DescribeVoices</a> operation for the <code>LanguageCode</code> parameter. For example, if no language code is specified, Aditi will use Indian English rather than Hindi.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>List of one or more pronunciation lexicon names you want the service to apply during synthesis. Lexicons are applied only if the language of the lexicon is the same as the language of the voice. </p>
     #[serde(rename = "LexiconNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lexicon_names: Option<Vec<String>>,
     /// <p>The format in which the returned output will be encoded. For audio stream, this will be mp3, ogg_vorbis, or pcm. For speech marks, this will be json. </p>
     #[serde(rename = "OutputFormat")]
-    pub output_format: String,
+    pub output_format: OutputFormat,
     /// <p>Amazon S3 bucket name to which the output file will be saved.</p>
     #[serde(rename = "OutputS3BucketName")]
     pub output_s3_bucket_name: String,
@@ -261,17 +1079,143 @@ pub struct StartSpeechSynthesisTaskInput {
     /// <p>The type of speech marks returned for the input text.</p>
     #[serde(rename = "SpeechMarkTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub speech_mark_types: Option<Vec<String>>,
+    pub speech_mark_types: Option<Vec<SpeechMarkType>>,
     /// <p>The input text to synthesize. If you specify ssml as the TextType, follow the SSML format for the input text. </p>
     #[serde(rename = "Text")]
     pub text: String,
     /// <p>Specifies whether the input text is plain text or SSML. The default value is plain text. </p>
     #[serde(rename = "TextType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub text_type: Option<String>,
+    pub text_type: Option<TextType>,
     /// <p>Voice ID to use for the synthesis. </p>
     #[serde(rename = "VoiceId")]
-    pub voice_id: String,
+    pub voice_id: VoiceId,
+}
+
+impl StartSpeechSynthesisTaskInput {
+    /// Returns a builder for `StartSpeechSynthesisTaskInput`, checking its required members when built.
+    pub fn builder() -> StartSpeechSynthesisTaskInputBuilder {
+        StartSpeechSynthesisTaskInputBuilder::default()
+    }
+}
+
+/// Builds a [`StartSpeechSynthesisTaskInput`](struct.StartSpeechSynthesisTaskInput.html).
+#[derive(Default, Debug)]
+pub struct StartSpeechSynthesisTaskInputBuilder {
+    engine: Option<Engine>,
+    language_code: Option<LanguageCode>,
+    lexicon_names: Option<Vec<String>>,
+    output_format: Option<OutputFormat>,
+    output_s3_bucket_name: Option<String>,
+    output_s3_key_prefix: Option<String>,
+    sample_rate: Option<String>,
+    sns_topic_arn: Option<String>,
+    speech_mark_types: Option<Vec<SpeechMarkType>>,
+    text: Option<String>,
+    text_type: Option<TextType>,
+    voice_id: Option<VoiceId>,
+}
+
+impl StartSpeechSynthesisTaskInputBuilder {
+    /// Sets the [`engine`](struct.StartSpeechSynthesisTaskInput.html#structfield.engine) member.
+    pub fn engine<T: Into<Engine>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`language_code`](struct.StartSpeechSynthesisTaskInput.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lexicon_names`](struct.StartSpeechSynthesisTaskInput.html#structfield.lexicon_names) member.
+    pub fn lexicon_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.lexicon_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_format`](struct.StartSpeechSynthesisTaskInput.html#structfield.output_format) member.
+    pub fn output_format<T: Into<OutputFormat>>(mut self, value: T) -> Self {
+        self.output_format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_s3_bucket_name`](struct.StartSpeechSynthesisTaskInput.html#structfield.output_s3_bucket_name) member.
+    pub fn output_s3_bucket_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.output_s3_bucket_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_s3_key_prefix`](struct.StartSpeechSynthesisTaskInput.html#structfield.output_s3_key_prefix) member.
+    pub fn output_s3_key_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.output_s3_key_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sample_rate`](struct.StartSpeechSynthesisTaskInput.html#structfield.sample_rate) member.
+    pub fn sample_rate<T: Into<String>>(mut self, value: T) -> Self {
+        self.sample_rate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.StartSpeechSynthesisTaskInput.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`speech_mark_types`](struct.StartSpeechSynthesisTaskInput.html#structfield.speech_mark_types) member.
+    pub fn speech_mark_types<T: Into<Vec<SpeechMarkType>>>(mut self, value: T) -> Self {
+        self.speech_mark_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text`](struct.StartSpeechSynthesisTaskInput.html#structfield.text) member.
+    pub fn text<T: Into<String>>(mut self, value: T) -> Self {
+        self.text = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text_type`](struct.StartSpeechSynthesisTaskInput.html#structfield.text_type) member.
+    pub fn text_type<T: Into<TextType>>(mut self, value: T) -> Self {
+        self.text_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`voice_id`](struct.StartSpeechSynthesisTaskInput.html#structfield.voice_id) member.
+    pub fn voice_id<T: Into<VoiceId>>(mut self, value: T) -> Self {
+        self.voice_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartSpeechSynthesisTaskInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartSpeechSynthesisTaskInput, ::rusoto_core::BuildError> {
+        Ok(StartSpeechSynthesisTaskInput {
+            engine: self.engine,
+            language_code: self.language_code,
+            lexicon_names: self.lexicon_names,
+            output_format: self
+                .output_format
+                .ok_or(::rusoto_core::BuildError::missing("output_format"))?,
+            output_s3_bucket_name: self
+                .output_s3_bucket_name
+                .ok_or(::rusoto_core::BuildError::missing("output_s3_bucket_name"))?,
+            output_s3_key_prefix: self.output_s3_key_prefix,
+            sample_rate: self.sample_rate,
+            sns_topic_arn: self.sns_topic_arn,
+            speech_mark_types: self.speech_mark_types,
+            text: self
+                .text
+                .ok_or(::rusoto_core::BuildError::missing("text"))?,
+            text_type: self.text_type,
+            voice_id: self
+                .voice_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_id"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -289,16 +1233,17 @@ pub struct StartSpeechSynthesisTaskOutput {
 pub struct SynthesisTask {
     /// <p>Timestamp for the time the synthesis task was started.</p>
     #[serde(rename = "CreationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>Specifies the engine (<code>standard</code> or <code>neural</code>) for Amazon Polly to use when processing input text for speech synthesis. Using a voice that is not supported for the engine selected will result in an error.</p>
     #[serde(rename = "Engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine: Option<String>,
+    pub engine: Option<Engine>,
     /// <p>Optional language code for a synthesis task. This is only necessary if using a bilingual voice, such as Aditi, which can be used for either Indian English (en-IN) or Hindi (hi-IN). </p> <p>If a bilingual voice is used and no language code is specified, Amazon Polly will use the default language of the bilingual voice. The default language for any voice is the one returned by the <a href="https://docs.aws.amazon.com/polly/latest/dg/API_DescribeVoices.html">DescribeVoices</a> operation for the <code>LanguageCode</code> parameter. For example, if no language code is specified, Aditi will use Indian English rather than Hindi.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>List of one or more pronunciation lexicon names you want the service to apply during synthesis. Lexicons are applied only if the language of the lexicon is the same as the language of the voice. </p>
     #[serde(rename = "LexiconNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -306,7 +1251,7 @@ pub struct SynthesisTask {
     /// <p>The format in which the returned output will be encoded. For audio stream, this will be mp3, ogg_vorbis, or pcm. For speech marks, this will be json. </p>
     #[serde(rename = "OutputFormat")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub output_format: Option<String>,
+    pub output_format: Option<OutputFormat>,
     /// <p>Pathway for the output speech file.</p>
     #[serde(rename = "OutputUri")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -326,7 +1271,7 @@ pub struct SynthesisTask {
     /// <p>The type of speech marks returned for the input text.</p>
     #[serde(rename = "SpeechMarkTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub speech_mark_types: Option<Vec<String>>,
+    pub speech_mark_types: Option<Vec<SpeechMarkType>>,
     /// <p>The Amazon Polly generated identifier for a speech synthesis task.</p>
     #[serde(rename = "TaskId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -334,7 +1279,7 @@ pub struct SynthesisTask {
     /// <p>Current status of the individual speech synthesis task.</p>
     #[serde(rename = "TaskStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub task_status: Option<String>,
+    pub task_status: Option<TaskStatus>,
     /// <p>Reason for the current status of a specific speech synthesis task, including errors if the task has failed.</p>
     #[serde(rename = "TaskStatusReason")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -342,30 +1287,30 @@ pub struct SynthesisTask {
     /// <p>Specifies whether the input text is plain text or SSML. The default value is plain text. </p>
     #[serde(rename = "TextType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub text_type: Option<String>,
+    pub text_type: Option<TextType>,
     /// <p>Voice ID to use for the synthesis. </p>
     #[serde(rename = "VoiceId")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub voice_id: Option<String>,
+    pub voice_id: Option<VoiceId>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SynthesizeSpeechInput {
     /// <p>Specifies the engine (<code>standard</code> or <code>neural</code>) for Amazon Polly to use when processing input text for speech synthesis. Using a voice that is not supported for the engine selected will result in an error.</p>
     #[serde(rename = "Engine")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub engine: Option<String>,
+    pub engine: Option<Engine>,
     /// <p>Optional language code for the Synthesize Speech request. This is only necessary if using a bilingual voice, such as Aditi, which can be used for either Indian English (en-IN) or Hindi (hi-IN). </p> <p>If a bilingual voice is used and no language code is specified, Amazon Polly will use the default language of the bilingual voice. The default language for any voice is the one returned by the <a href="https://docs.aws.amazon.com/polly/latest/dg/API_DescribeVoices.html">DescribeVoices</a> operation for the <code>LanguageCode</code> parameter. For example, if no language code is specified, Aditi will use Indian English rather than Hindi.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>List of one or more pronunciation lexicon names you want the service to apply during synthesis. Lexicons are applied only if the language of the lexicon is the same as the language of the voice. For information about storing lexicons, see <a href="https://docs.aws.amazon.com/polly/latest/dg/API_PutLexicon.html">PutLexicon</a>.</p>
     #[serde(rename = "LexiconNames")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lexicon_names: Option<Vec<String>>,
     /// <p> The format in which the returned output will be encoded. For audio stream, this will be mp3, ogg_vorbis, or pcm. For speech marks, this will be json. </p> <p>When pcm is used, the content returned is audio/pcm in a signed 16-bit, 1 channel (mono), little-endian format. </p>
     #[serde(rename = "OutputFormat")]
-    pub output_format: String,
+    pub output_format: OutputFormat,
     /// <p>The audio frequency specified in Hz.</p> <p>The valid values for mp3 and ogg_vorbis are "8000", "16000", "22050", and "24000". The default value for standard voices is "22050". The default value for neural voices is "24000".</p> <p>Valid values for pcm are "8000" and "16000" The default value is "16000". </p>
     #[serde(rename = "SampleRate")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -373,17 +1318,115 @@ pub struct SynthesizeSpeechInput {
     /// <p>The type of speech marks returned for the input text.</p>
     #[serde(rename = "SpeechMarkTypes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub speech_mark_types: Option<Vec<String>>,
+    pub speech_mark_types: Option<Vec<SpeechMarkType>>,
     /// <p> Input text to synthesize. If you specify <code>ssml</code> as the <code>TextType</code>, follow the SSML format for the input text. </p>
     #[serde(rename = "Text")]
     pub text: String,
     /// <p> Specifies whether the input text is plain text or SSML. The default value is plain text. For more information, see <a href="https://docs.aws.amazon.com/polly/latest/dg/ssml.html">Using SSML</a>.</p>
     #[serde(rename = "TextType")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub text_type: Option<String>,
+    pub text_type: Option<TextType>,
     /// <p> Voice ID to use for the synthesis. You can get a list of available voice IDs by calling the <a href="https://docs.aws.amazon.com/polly/latest/dg/API_DescribeVoices.html">DescribeVoices</a> operation. </p>
     #[serde(rename = "VoiceId")]
-    pub voice_id: String,
+    pub voice_id: VoiceId,
+}
+
+impl SynthesizeSpeechInput {
+    /// Returns a builder for `SynthesizeSpeechInput`, checking its required members when built.
+    pub fn builder() -> SynthesizeSpeechInputBuilder {
+        SynthesizeSpeechInputBuilder::default()
+    }
+}
+
+/// Builds a [`SynthesizeSpeechInput`](struct.SynthesizeSpeechInput.html).
+#[derive(Default, Debug)]
+pub struct SynthesizeSpeechInputBuilder {
+    engine: Option<Engine>,
+    language_code: Option<LanguageCode>,
+    lexicon_names: Option<Vec<String>>,
+    output_format: Option<OutputFormat>,
+    sample_rate: Option<String>,
+    speech_mark_types: Option<Vec<SpeechMarkType>>,
+    text: Option<String>,
+    text_type: Option<TextType>,
+    voice_id: Option<VoiceId>,
+}
+
+impl SynthesizeSpeechInputBuilder {
+    /// Sets the [`engine`](struct.SynthesizeSpeechInput.html#structfield.engine) member.
+    pub fn engine<T: Into<Engine>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`language_code`](struct.SynthesizeSpeechInput.html#structfield.language_code) member.
+    pub fn language_code<T: Into<LanguageCode>>(mut self, value: T) -> Self {
+        self.language_code = Some(value.into());
+        self
+    }
+
+    /// Sets the [`lexicon_names`](struct.SynthesizeSpeechInput.html#structfield.lexicon_names) member.
+    pub fn lexicon_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.lexicon_names = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output_format`](struct.SynthesizeSpeechInput.html#structfield.output_format) member.
+    pub fn output_format<T: Into<OutputFormat>>(mut self, value: T) -> Self {
+        self.output_format = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sample_rate`](struct.SynthesizeSpeechInput.html#structfield.sample_rate) member.
+    pub fn sample_rate<T: Into<String>>(mut self, value: T) -> Self {
+        self.sample_rate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`speech_mark_types`](struct.SynthesizeSpeechInput.html#structfield.speech_mark_types) member.
+    pub fn speech_mark_types<T: Into<Vec<SpeechMarkType>>>(mut self, value: T) -> Self {
+        self.speech_mark_types = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text`](struct.SynthesizeSpeechInput.html#structfield.text) member.
+    pub fn text<T: Into<String>>(mut self, value: T) -> Self {
+        self.text = Some(value.into());
+        self
+    }
+
+    /// Sets the [`text_type`](struct.SynthesizeSpeechInput.html#structfield.text_type) member.
+    pub fn text_type<T: Into<TextType>>(mut self, value: T) -> Self {
+        self.text_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`voice_id`](struct.SynthesizeSpeechInput.html#structfield.voice_id) member.
+    pub fn voice_id<T: Into<VoiceId>>(mut self, value: T) -> Self {
+        self.voice_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `SynthesizeSpeechInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SynthesizeSpeechInput, ::rusoto_core::BuildError> {
+        Ok(SynthesizeSpeechInput {
+            engine: self.engine,
+            language_code: self.language_code,
+            lexicon_names: self.lexicon_names,
+            output_format: self
+                .output_format
+                .ok_or(::rusoto_core::BuildError::missing("output_format"))?,
+            sample_rate: self.sample_rate,
+            speech_mark_types: self.speech_mark_types,
+            text: self
+                .text
+                .ok_or(::rusoto_core::BuildError::missing("text"))?,
+            text_type: self.text_type,
+            voice_id: self
+                .voice_id
+                .ok_or(::rusoto_core::BuildError::missing("voice_id"))?,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
@@ -396,6 +1439,170 @@ pub struct SynthesizeSpeechOutput {
     pub request_characters: Option<i64>,
 }
 
+/// <p>Status of the speech synthesis tasks returned in a List operation</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TaskStatus {
+    /// <p><code>scheduled</code></p>
+    Scheduled,
+    /// <p><code>inProgress</code></p>
+    InProgress,
+    /// <p><code>completed</code></p>
+    Completed,
+    /// <p><code>failed</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TaskStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TaskStatus::Scheduled => "scheduled",
+            TaskStatus::InProgress => "inProgress",
+            TaskStatus::Completed => "completed",
+            TaskStatus::Failed => "failed",
+            TaskStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TaskStatus {
+    fn from(value: &'a str) -> TaskStatus {
+        match value {
+            "scheduled" => TaskStatus::Scheduled,
+            "inProgress" => TaskStatus::InProgress,
+            "completed" => TaskStatus::Completed,
+            "failed" => TaskStatus::Failed,
+            _ => TaskStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TaskStatus {
+    fn from(value: String) -> TaskStatus {
+        TaskStatus::from(value.as_str())
+    }
+}
+
+impl From<TaskStatus> for String {
+    fn from(value: TaskStatus) -> String {
+        match value {
+            TaskStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TaskStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TaskStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TaskStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TaskStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TaskStatus::from(value))
+    }
+}
+
+/// <p>Specifies whether the input text is plain text or SSML. The default value is plain text. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TextType {
+    /// <p><code>ssml</code></p>
+    Ssml,
+    /// <p><code>text</code></p>
+    Text,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TextType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TextType::Ssml => "ssml",
+            TextType::Text => "text",
+            TextType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TextType {
+    fn from(value: &'a str) -> TextType {
+        match value {
+            "ssml" => TextType::Ssml,
+            "text" => TextType::Text,
+            _ => TextType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TextType {
+    fn from(value: String) -> TextType {
+        TextType::from(value.as_str())
+    }
+}
+
+impl From<TextType> for String {
+    fn from(value: TextType) -> String {
+        match value {
+            TextType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TextType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TextType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for TextType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TextType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TextType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TextType::from(value))
+    }
+}
+
 /// <p>Description of the voice.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -403,19 +1610,19 @@ pub struct Voice {
     /// <p>Additional codes for languages available for the specified voice in addition to its default language. </p> <p>For example, the default language for Aditi is Indian English (en-IN) because it was first used for that language. Since Aditi is bilingual and fluent in both Indian English and Hindi, this parameter would show the code <code>hi-IN</code>.</p>
     #[serde(rename = "AdditionalLanguageCodes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub additional_language_codes: Option<Vec<String>>,
+    pub additional_language_codes: Option<Vec<LanguageCode>>,
     /// <p>Gender of the voice.</p>
     #[serde(rename = "Gender")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub gender: Option<String>,
+    pub gender: Option<Gender>,
     /// <p>Amazon Polly assigned voice ID. This is the ID that you specify when calling the <code>SynthesizeSpeech</code> operation.</p>
     #[serde(rename = "Id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    pub id: Option<VoiceId>,
     /// <p>Language code of the voice.</p>
     #[serde(rename = "LanguageCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language_code: Option<String>,
+    pub language_code: Option<LanguageCode>,
     /// <p>Human readable name of the language in English.</p>
     #[serde(rename = "LanguageName")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -427,7 +1634,457 @@ pub struct Voice {
     /// <p>Specifies which engines (<code>standard</code> or <code>neural</code>) that are supported by a given voice.</p>
     #[serde(rename = "SupportedEngines")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub supported_engines: Option<Vec<String>>,
+    pub supported_engines: Option<Vec<Engine>>,
+}
+
+/// <p>Voice ID to use for the synthesis. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VoiceId {
+    /// <p><code>Aditi</code></p>
+    Aditi,
+    /// <p><code>Amy</code></p>
+    Amy,
+    /// <p><code>Astrid</code></p>
+    Astrid,
+    /// <p><code>Bianca</code></p>
+    Bianca,
+    /// <p><code>Brian</code></p>
+    Brian,
+    /// <p><code>Camila</code></p>
+    Camila,
+    /// <p><code>Carla</code></p>
+    Carla,
+    /// <p><code>Carmen</code></p>
+    Carmen,
+    /// <p><code>Celine</code></p>
+    Celine,
+    /// <p><code>Chantal</code></p>
+    Chantal,
+    /// <p><code>Conchita</code></p>
+    Conchita,
+    /// <p><code>Cristiano</code></p>
+    Cristiano,
+    /// <p><code>Dora</code></p>
+    Dora,
+    /// <p><code>Emma</code></p>
+    Emma,
+    /// <p><code>Enrique</code></p>
+    Enrique,
+    /// <p><code>Ewa</code></p>
+    Ewa,
+    /// <p><code>Filiz</code></p>
+    Filiz,
+    /// <p><code>Gabrielle</code></p>
+    Gabrielle,
+    /// <p><code>Geraint</code></p>
+    Geraint,
+    /// <p><code>Giorgio</code></p>
+    Giorgio,
+    /// <p><code>Gwyneth</code></p>
+    Gwyneth,
+    /// <p><code>Hans</code></p>
+    Hans,
+    /// <p><code>Ines</code></p>
+    Ines,
+    /// <p><code>Ivy</code></p>
+    Ivy,
+    /// <p><code>Jacek</code></p>
+    Jacek,
+    /// <p><code>Jan</code></p>
+    Jan,
+    /// <p><code>Joanna</code></p>
+    Joanna,
+    /// <p><code>Joey</code></p>
+    Joey,
+    /// <p><code>Justin</code></p>
+    Justin,
+    /// <p><code>Karl</code></p>
+    Karl,
+    /// <p><code>Kendra</code></p>
+    Kendra,
+    /// <p><code>Kevin</code></p>
+    Kevin,
+    /// <p><code>Kimberly</code></p>
+    Kimberly,
+    /// <p><code>Lea</code></p>
+    Lea,
+    /// <p><code>Liv</code></p>
+    Liv,
+    /// <p><code>Lotte</code></p>
+    Lotte,
+    /// <p><code>Lucia</code></p>
+    Lucia,
+    /// <p><code>Lupe</code></p>
+    Lupe,
+    /// <p><code>Mads</code></p>
+    Mads,
+    /// <p><code>Maja</code></p>
+    Maja,
+    /// <p><code>Marlene</code></p>
+    Marlene,
+    /// <p><code>Mathieu</code></p>
+    Mathieu,
+    /// <p><code>Matthew</code></p>
+    Matthew,
+    /// <p><code>Maxim</code></p>
+    Maxim,
+    /// <p><code>Mia</code></p>
+    Mia,
+    /// <p><code>Miguel</code></p>
+    Miguel,
+    /// <p><code>Mizuki</code></p>
+    Mizuki,
+    /// <p><code>Naja</code></p>
+    Naja,
+    /// <p><code>Nicole</code></p>
+    Nicole,
+    /// <p><code>Olivia</code></p>
+    Olivia,
+    /// <p><code>Penelope</code></p>
+    Penelope,
+    /// <p><code>Raveena</code></p>
+    Raveena,
+    /// <p><code>Ricardo</code></p>
+    Ricardo,
+    /// <p><code>Ruben</code></p>
+    Ruben,
+    /// <p><code>Russell</code></p>
+    Russell,
+    /// <p><code>Salli</code></p>
+    Salli,
+    /// <p><code>Seoyeon</code></p>
+    Seoyeon,
+    /// <p><code>Takumi</code></p>
+    Takumi,
+    /// <p><code>Tatyana</code></p>
+    Tatyana,
+    /// <p><code>Vicki</code></p>
+    Vicki,
+    /// <p><code>Vitoria</code></p>
+    Vitoria,
+    /// <p><code>Zeina</code></p>
+    Zeina,
+    /// <p><code>Zhiyu</code></p>
+    Zhiyu,
+    /// <p><code>Aria</code></p>
+    Aria,
+    /// <p><code>Ayanda</code></p>
+    Ayanda,
+    /// <p><code>Arlet</code></p>
+    Arlet,
+    /// <p><code>Hannah</code></p>
+    Hannah,
+    /// <p><code>Arthur</code></p>
+    Arthur,
+    /// <p><code>Daniel</code></p>
+    Daniel,
+    /// <p><code>Liam</code></p>
+    Liam,
+    /// <p><code>Pedro</code></p>
+    Pedro,
+    /// <p><code>Kajal</code></p>
+    Kajal,
+    /// <p><code>Hiujin</code></p>
+    Hiujin,
+    /// <p><code>Laura</code></p>
+    Laura,
+    /// <p><code>Elin</code></p>
+    Elin,
+    /// <p><code>Ida</code></p>
+    Ida,
+    /// <p><code>Suvi</code></p>
+    Suvi,
+    /// <p><code>Ola</code></p>
+    Ola,
+    /// <p><code>Hala</code></p>
+    Hala,
+    /// <p><code>Andres</code></p>
+    Andres,
+    /// <p><code>Sergio</code></p>
+    Sergio,
+    /// <p><code>Remi</code></p>
+    Remi,
+    /// <p><code>Adriano</code></p>
+    Adriano,
+    /// <p><code>Thiago</code></p>
+    Thiago,
+    /// <p><code>Ruth</code></p>
+    Ruth,
+    /// <p><code>Stephen</code></p>
+    Stephen,
+    /// <p><code>Kazuha</code></p>
+    Kazuha,
+    /// <p><code>Tomoko</code></p>
+    Tomoko,
+    /// <p><code>Niamh</code></p>
+    Niamh,
+    /// <p><code>Sofie</code></p>
+    Sofie,
+    /// <p><code>Lisa</code></p>
+    Lisa,
+    /// <p><code>Isabelle</code></p>
+    Isabelle,
+    /// <p><code>Zayd</code></p>
+    Zayd,
+    /// <p><code>Danielle</code></p>
+    Danielle,
+    /// <p><code>Gregory</code></p>
+    Gregory,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VoiceId {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VoiceId::Aditi => "Aditi",
+            VoiceId::Amy => "Amy",
+            VoiceId::Astrid => "Astrid",
+            VoiceId::Bianca => "Bianca",
+            VoiceId::Brian => "Brian",
+            VoiceId::Camila => "Camila",
+            VoiceId::Carla => "Carla",
+            VoiceId::Carmen => "Carmen",
+            VoiceId::Celine => "Celine",
+            VoiceId::Chantal => "Chantal",
+            VoiceId::Conchita => "Conchita",
+            VoiceId::Cristiano => "Cristiano",
+            VoiceId::Dora => "Dora",
+            VoiceId::Emma => "Emma",
+            VoiceId::Enrique => "Enrique",
+            VoiceId::Ewa => "Ewa",
+            VoiceId::Filiz => "Filiz",
+            VoiceId::Gabrielle => "Gabrielle",
+            VoiceId::Geraint => "Geraint",
+            VoiceId::Giorgio => "Giorgio",
+            VoiceId::Gwyneth => "Gwyneth",
+            VoiceId::Hans => "Hans",
+            VoiceId::Ines => "Ines",
+            VoiceId::Ivy => "Ivy",
+            VoiceId::Jacek => "Jacek",
+            VoiceId::Jan => "Jan",
+            VoiceId::Joanna => "Joanna",
+            VoiceId::Joey => "Joey",
+            VoiceId::Justin => "Justin",
+            VoiceId::Karl => "Karl",
+            VoiceId::Kendra => "Kendra",
+            VoiceId::Kevin => "Kevin",
+            VoiceId::Kimberly => "Kimberly",
+            VoiceId::Lea => "Lea",
+            VoiceId::Liv => "Liv",
+            VoiceId::Lotte => "Lotte",
+            VoiceId::Lucia => "Lucia",
+            VoiceId::Lupe => "Lupe",
+            VoiceId::Mads => "Mads",
+            VoiceId::Maja => "Maja",
+            VoiceId::Marlene => "Marlene",
+            VoiceId::Mathieu => "Mathieu",
+            VoiceId::Matthew => "Matthew",
+            VoiceId::Maxim => "Maxim",
+            VoiceId::Mia => "Mia",
+            VoiceId::Miguel => "Miguel",
+            VoiceId::Mizuki => "Mizuki",
+            VoiceId::Naja => "Naja",
+            VoiceId::Nicole => "Nicole",
+            VoiceId::Olivia => "Olivia",
+            VoiceId::Penelope => "Penelope",
+            VoiceId::Raveena => "Raveena",
+            VoiceId::Ricardo => "Ricardo",
+            VoiceId::Ruben => "Ruben",
+            VoiceId::Russell => "Russell",
+            VoiceId::Salli => "Salli",
+            VoiceId::Seoyeon => "Seoyeon",
+            VoiceId::Takumi => "Takumi",
+            VoiceId::Tatyana => "Tatyana",
+            VoiceId::Vicki => "Vicki",
+            VoiceId::Vitoria => "Vitoria",
+            VoiceId::Zeina => "Zeina",
+            VoiceId::Zhiyu => "Zhiyu",
+            VoiceId::Aria => "Aria",
+            VoiceId::Ayanda => "Ayanda",
+            VoiceId::Arlet => "Arlet",
+            VoiceId::Hannah => "Hannah",
+            VoiceId::Arthur => "Arthur",
+            VoiceId::Daniel => "Daniel",
+            VoiceId::Liam => "Liam",
+            VoiceId::Pedro => "Pedro",
+            VoiceId::Kajal => "Kajal",
+            VoiceId::Hiujin => "Hiujin",
+            VoiceId::Laura => "Laura",
+            VoiceId::Elin => "Elin",
+            VoiceId::Ida => "Ida",
+            VoiceId::Suvi => "Suvi",
+            VoiceId::Ola => "Ola",
+            VoiceId::Hala => "Hala",
+            VoiceId::Andres => "Andres",
+            VoiceId::Sergio => "Sergio",
+            VoiceId::Remi => "Remi",
+            VoiceId::Adriano => "Adriano",
+            VoiceId::Thiago => "Thiago",
+            VoiceId::Ruth => "Ruth",
+            VoiceId::Stephen => "Stephen",
+            VoiceId::Kazuha => "Kazuha",
+            VoiceId::Tomoko => "Tomoko",
+            VoiceId::Niamh => "Niamh",
+            VoiceId::Sofie => "Sofie",
+            VoiceId::Lisa => "Lisa",
+            VoiceId::Isabelle => "Isabelle",
+            VoiceId::Zayd => "Zayd",
+            VoiceId::Danielle => "Danielle",
+            VoiceId::Gregory => "Gregory",
+            VoiceId::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VoiceId {
+    fn from(value: &'a str) -> VoiceId {
+        match value {
+            "Aditi" => VoiceId::Aditi,
+            "Amy" => VoiceId::Amy,
+            "Astrid" => VoiceId::Astrid,
+            "Bianca" => VoiceId::Bianca,
+            "Brian" => VoiceId::Brian,
+            "Camila" => VoiceId::Camila,
+            "Carla" => VoiceId::Carla,
+            "Carmen" => VoiceId::Carmen,
+            "Celine" => VoiceId::Celine,
+            "Chantal" => VoiceId::Chantal,
+            "Conchita" => VoiceId::Conchita,
+            "Cristiano" => VoiceId::Cristiano,
+            "Dora" => VoiceId::Dora,
+            "Emma" => VoiceId::Emma,
+            "Enrique" => VoiceId::Enrique,
+            "Ewa" => VoiceId::Ewa,
+            "Filiz" => VoiceId::Filiz,
+            "Gabrielle" => VoiceId::Gabrielle,
+            "Geraint" => VoiceId::Geraint,
+            "Giorgio" => VoiceId::Giorgio,
+            "Gwyneth" => VoiceId::Gwyneth,
+            "Hans" => VoiceId::Hans,
+            "Ines" => VoiceId::Ines,
+            "Ivy" => VoiceId::Ivy,
+            "Jacek" => VoiceId::Jacek,
+            "Jan" => VoiceId::Jan,
+            "Joanna" => VoiceId::Joanna,
+            "Joey" => VoiceId::Joey,
+            "Justin" => VoiceId::Justin,
+            "Karl" => VoiceId::Karl,
+            "Kendra" => VoiceId::Kendra,
+            "Kevin" => VoiceId::Kevin,
+            "Kimberly" => VoiceId::Kimberly,
+            "Lea" => VoiceId::Lea,
+            "Liv" => VoiceId::Liv,
+            "Lotte" => VoiceId::Lotte,
+            "Lucia" => VoiceId::Lucia,
+            "Lupe" => VoiceId::Lupe,
+            "Mads" => VoiceId::Mads,
+            "Maja" => VoiceId::Maja,
+            "Marlene" => VoiceId::Marlene,
+            "Mathieu" => VoiceId::Mathieu,
+            "Matthew" => VoiceId::Matthew,
+            "Maxim" => VoiceId::Maxim,
+            "Mia" => VoiceId::Mia,
+            "Miguel" => VoiceId::Miguel,
+            "Mizuki" => VoiceId::Mizuki,
+            "Naja" => VoiceId::Naja,
+            "Nicole" => VoiceId::Nicole,
+            "Olivia" => VoiceId::Olivia,
+            "Penelope" => VoiceId::Penelope,
+            "Raveena" => VoiceId::Raveena,
+            "Ricardo" => VoiceId::Ricardo,
+            "Ruben" => VoiceId::Ruben,
+            "Russell" => VoiceId::Russell,
+            "Salli" => VoiceId::Salli,
+            "Seoyeon" => VoiceId::Seoyeon,
+            "Takumi" => VoiceId::Takumi,
+            "Tatyana" => VoiceId::Tatyana,
+            "Vicki" => VoiceId::Vicki,
+            "Vitoria" => VoiceId::Vitoria,
+            "Zeina" => VoiceId::Zeina,
+            "Zhiyu" => VoiceId::Zhiyu,
+            "Aria" => VoiceId::Aria,
+            "Ayanda" => VoiceId::Ayanda,
+            "Arlet" => VoiceId::Arlet,
+            "Hannah" => VoiceId::Hannah,
+            "Arthur" => VoiceId::Arthur,
+            "Daniel" => VoiceId::Daniel,
+            "Liam" => VoiceId::Liam,
+            "Pedro" => VoiceId::Pedro,
+            "Kajal" => VoiceId::Kajal,
+            "Hiujin" => VoiceId::Hiujin,
+            "Laura" => VoiceId::Laura,
+            "Elin" => VoiceId::Elin,
+            "Ida" => VoiceId::Ida,
+            "Suvi" => VoiceId::Suvi,
+            "Ola" => VoiceId::Ola,
+            "Hala" => VoiceId::Hala,
+            "Andres" => VoiceId::Andres,
+            "Sergio" => VoiceId::Sergio,
+            "Remi" => VoiceId::Remi,
+            "Adriano" => VoiceId::Adriano,
+            "Thiago" => VoiceId::Thiago,
+            "Ruth" => VoiceId::Ruth,
+            "Stephen" => VoiceId::Stephen,
+            "Kazuha" => VoiceId::Kazuha,
+            "Tomoko" => VoiceId::Tomoko,
+            "Niamh" => VoiceId::Niamh,
+            "Sofie" => VoiceId::Sofie,
+            "Lisa" => VoiceId::Lisa,
+            "Isabelle" => VoiceId::Isabelle,
+            "Zayd" => VoiceId::Zayd,
+            "Danielle" => VoiceId::Danielle,
+            "Gregory" => VoiceId::Gregory,
+            _ => VoiceId::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VoiceId {
+    fn from(value: String) -> VoiceId {
+        VoiceId::from(value.as_str())
+    }
+}
+
+impl From<VoiceId> for String {
+    fn from(value: VoiceId) -> String {
+        match value {
+            VoiceId::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VoiceId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for VoiceId {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for VoiceId {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VoiceId {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VoiceId, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VoiceId::from(value))
+    }
 }
 
 /// Errors returned by DeleteLexicon
@@ -1017,6 +2674,69 @@ pub trait Polly {
         input: SynthesizeSpeechInput,
     ) -> RusotoFuture<SynthesizeSpeechOutput, SynthesizeSpeechError>;
 }
+impl<T: ?Sized + Polly> Polly for ::std::sync::Arc<T> {
+    fn delete_lexicon(
+        &self,
+        input: DeleteLexiconInput,
+    ) -> RusotoFuture<DeleteLexiconOutput, DeleteLexiconError> {
+        (**self).delete_lexicon(input)
+    }
+    fn describe_voices(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> RusotoFuture<DescribeVoicesOutput, DescribeVoicesError> {
+        (**self).describe_voices(input)
+    }
+    fn get_lexicon(
+        &self,
+        input: GetLexiconInput,
+    ) -> RusotoFuture<GetLexiconOutput, GetLexiconError> {
+        (**self).get_lexicon(input)
+    }
+    fn get_speech_synthesis_task(
+        &self,
+        input: GetSpeechSynthesisTaskInput,
+    ) -> RusotoFuture<GetSpeechSynthesisTaskOutput, GetSpeechSynthesisTaskError> {
+        (**self).get_speech_synthesis_task(input)
+    }
+    fn list_lexicons(
+        &self,
+        input: ListLexiconsInput,
+    ) -> RusotoFuture<ListLexiconsOutput, ListLexiconsError> {
+        (**self).list_lexicons(input)
+    }
+    fn list_speech_synthesis_tasks(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> RusotoFuture<ListSpeechSynthesisTasksOutput, ListSpeechSynthesisTasksError> {
+        (**self).list_speech_synthesis_tasks(input)
+    }
+    fn put_lexicon(
+        &self,
+        input: PutLexiconInput,
+    ) -> RusotoFuture<PutLexiconOutput, PutLexiconError> {
+        (**self).put_lexicon(input)
+    }
+    fn start_speech_synthesis_task(
+        &self,
+        input: StartSpeechSynthesisTaskInput,
+    ) -> RusotoFuture<StartSpeechSynthesisTaskOutput, StartSpeechSynthesisTaskError> {
+        (**self).start_speech_synthesis_task(input)
+    }
+    fn synthesize_speech(
+        &self,
+        input: SynthesizeSpeechInput,
+    ) -> RusotoFuture<SynthesizeSpeechOutput, SynthesizeSpeechError> {
+        (**self).synthesize_speech(input)
+    }
+}
+/// The error codes Amazon Polly retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Polly API.
 #[derive(Clone)]
 pub struct PollyClient {
@@ -1032,6 +2752,24 @@ impl PollyClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_POLLY`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> PollyClient {
+        Self::new(region::Region::from_env_for_service("POLLY"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `polly`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> PollyClient {
+        Self::new_with_client(config.client().clone(), config.region_for("polly", "POLLY"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1050,7 +2788,49 @@ impl PollyClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> PollyClient {
-        PollyClient { client, region }
+        PollyClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "polly")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "polly", "polly", "POLLY")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Polly` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Polly + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for PollyClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Polly"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "polly"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> PollyClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Price List Service clients.
+//!
+//! Makes a single read-only `DescribeServices` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_pricing::{DescribeServicesRequest, Pricing, PricingClient};
+
+fn main() {
+    let client = PricingClient::from_env();
+    match client
+        .describe_services(DescribeServicesRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("DescribeServices succeeded"),
+        Err(error) => {
+            eprintln!("DescribeServices failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
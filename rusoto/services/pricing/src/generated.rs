@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>The values of a given attribute, such as <code>Throughput Optimized HDD</code> or <code>Provisioned IOPS</code> for the <code>Amazon EC2</code> <code>volumeType</code> attribute.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -53,6 +54,60 @@ pub struct DescribeServicesRequest {
     pub service_code: Option<String>,
 }
 
+impl DescribeServicesRequest {
+    /// Returns a builder for `DescribeServicesRequest`, checking its required members when built.
+    pub fn builder() -> DescribeServicesRequestBuilder {
+        DescribeServicesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeServicesRequest`](struct.DescribeServicesRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeServicesRequestBuilder {
+    format_version: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    service_code: Option<String>,
+}
+
+impl DescribeServicesRequestBuilder {
+    /// Sets the [`format_version`](struct.DescribeServicesRequest.html#structfield.format_version) member.
+    pub fn format_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.format_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.DescribeServicesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.DescribeServicesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_code`](struct.DescribeServicesRequest.html#structfield.service_code) member.
+    pub fn service_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeServicesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeServicesRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeServicesRequest {
+            format_version: self.format_version,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            service_code: self.service_code,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeServicesResponse {
@@ -71,19 +126,87 @@ pub struct DescribeServicesResponse {
 }
 
 /// <p>The constraints that you want all returned products to match.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Filter {
     /// <p>The product metadata field that you want to filter on. You can filter by just the service code to see all products for a specific service, filter by just the attribute name to see a specific attribute for multiple services, or use both a service code and an attribute name to retrieve only products that match both fields.</p> <p>Valid values include: <code>ServiceCode</code>, and all attribute names</p> <p>For example, you can filter by the <code>AmazonEC2</code> service code and the <code>volumeType</code> attribute name to get the prices for only Amazon EC2 volumes.</p>
     #[serde(rename = "Field")]
     pub field: String,
     /// <p>The type of filter that you want to use.</p> <p>Valid values are: <code>TERM_MATCH</code>. <code>TERM_MATCH</code> returns only products that match both the given filter field and the given value.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: FilterType,
     /// <p>The service code or attribute value that you want to filter by. If you are filtering by service code this is the actual service code, such as <code>AmazonEC2</code>. If you are filtering by attribute name, this is the attribute value that you want the returned products to match, such as a <code>Provisioned IOPS</code> volume.</p>
     #[serde(rename = "Value")]
     pub value: String,
 }
 
+/// <p>The type of filter that you want to use.</p> <p>Valid values are: <code>TERM_MATCH</code>. <code>TERM_MATCH</code> returns only products that match both the given filter field and the given value.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FilterType {
+    /// <p><code>TERM_MATCH</code></p>
+    TermMatch,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FilterType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FilterType::TermMatch => "TERM_MATCH",
+            FilterType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FilterType {
+    fn from(value: &'a str) -> FilterType {
+        match value {
+            "TERM_MATCH" => FilterType::TermMatch,
+            _ => FilterType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FilterType {
+    fn from(value: String) -> FilterType {
+        FilterType::from(value.as_str())
+    }
+}
+
+impl From<FilterType> for String {
+    fn from(value: FilterType) -> String {
+        match value {
+            FilterType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FilterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FilterType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FilterType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FilterType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FilterType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetAttributeValuesRequest {
     /// <p>The name of the attribute that you want to retrieve the values for, such as <code>volumeType</code>.</p>
@@ -102,6 +225,64 @@ pub struct GetAttributeValuesRequest {
     pub service_code: String,
 }
 
+impl GetAttributeValuesRequest {
+    /// Returns a builder for `GetAttributeValuesRequest`, checking its required members when built.
+    pub fn builder() -> GetAttributeValuesRequestBuilder {
+        GetAttributeValuesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetAttributeValuesRequest`](struct.GetAttributeValuesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetAttributeValuesRequestBuilder {
+    attribute_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    service_code: Option<String>,
+}
+
+impl GetAttributeValuesRequestBuilder {
+    /// Sets the [`attribute_name`](struct.GetAttributeValuesRequest.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetAttributeValuesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetAttributeValuesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_code`](struct.GetAttributeValuesRequest.html#structfield.service_code) member.
+    pub fn service_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetAttributeValuesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetAttributeValuesRequest, ::rusoto_core::BuildError> {
+        Ok(GetAttributeValuesRequest {
+            attribute_name: self
+                .attribute_name
+                .ok_or(::rusoto_core::BuildError::missing("attribute_name"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            service_code: self
+                .service_code
+                .ok_or(::rusoto_core::BuildError::missing("service_code"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetAttributeValuesResponse {
@@ -139,6 +320,66 @@ pub struct GetProductsRequest {
     pub service_code: Option<String>,
 }
 
+impl GetProductsRequest {
+    /// Returns a builder for `GetProductsRequest`, checking its required members when built.
+    pub fn builder() -> GetProductsRequestBuilder {
+        GetProductsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetProductsRequest`](struct.GetProductsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetProductsRequestBuilder {
+    filters: Option<Vec<Filter>>,
+    format_version: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    service_code: Option<String>,
+}
+
+impl GetProductsRequestBuilder {
+    /// Sets the [`filters`](struct.GetProductsRequest.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`format_version`](struct.GetProductsRequest.html#structfield.format_version) member.
+    pub fn format_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.format_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetProductsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetProductsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`service_code`](struct.GetProductsRequest.html#structfield.service_code) member.
+    pub fn service_code<T: Into<String>>(mut self, value: T) -> Self {
+        self.service_code = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetProductsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetProductsRequest, ::rusoto_core::BuildError> {
+        Ok(GetProductsRequest {
+            filters: self.filters,
+            format_version: self.format_version,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            service_code: self.service_code,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetProductsResponse {
@@ -361,6 +602,33 @@ pub trait Pricing {
         input: GetProductsRequest,
     ) -> RusotoFuture<GetProductsResponse, GetProductsError>;
 }
+impl<T: ?Sized + Pricing> Pricing for ::std::sync::Arc<T> {
+    fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> RusotoFuture<DescribeServicesResponse, DescribeServicesError> {
+        (**self).describe_services(input)
+    }
+    fn get_attribute_values(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> RusotoFuture<GetAttributeValuesResponse, GetAttributeValuesError> {
+        (**self).get_attribute_values(input)
+    }
+    fn get_products(
+        &self,
+        input: GetProductsRequest,
+    ) -> RusotoFuture<GetProductsResponse, GetProductsError> {
+        (**self).get_products(input)
+    }
+}
+/// The error codes AWS Pricing retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Pricing API.
 #[derive(Clone)]
 pub struct PricingClient {
@@ -376,6 +644,27 @@ impl PricingClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_PRICING`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> PricingClient {
+        Self::new(region::Region::from_env_for_service("PRICING"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `api.pricing`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> PricingClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("api.pricing", "PRICING"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -394,7 +683,54 @@ impl PricingClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> PricingClient {
-        PricingClient { client, region }
+        PricingClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "api.pricing")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "api.pricing",
+            "pricing",
+            "PRICING",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Pricing` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Pricing + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for PricingClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Pricing"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "api.pricing"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> PricingClient {
+        Self::new_with_client(client, region)
     }
 }
 
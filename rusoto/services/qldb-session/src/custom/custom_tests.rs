@@ -0,0 +1,133 @@
+extern crate rusoto_mock;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::custom::driver::{dot, statement_hash, DriverConfig, DriverError, QldbDriver};
+use crate::custom::ion::{base64, IonValue};
+use crate::generated::{QldbSessionClient, SendCommandError};
+
+use self::rusoto_mock::*;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::{Region, RusotoError};
+
+/// Records the command sent with every request.
+fn command_recorder() -> (
+    Arc<Mutex<Vec<String>>>,
+    impl Fn(&SignedRequest) + Send + Sync + 'static,
+) {
+    let commands = Arc::new(Mutex::new(Vec::new()));
+    let recorded = commands.clone();
+    let checker = move |request: &SignedRequest| {
+        let body: Value = match request.payload {
+            Some(SignedRequestPayload::Buffer(ref buffer)) => {
+                serde_json::from_slice(buffer).unwrap()
+            }
+            _ => panic!("expected a buffered payload"),
+        };
+        let command = body
+            .as_object()
+            .unwrap()
+            .keys()
+            .find(|key| *key != "SessionToken")
+            .unwrap()
+            .clone();
+        recorded.lock().unwrap().push(command);
+    };
+    (commands, checker)
+}
+
+fn transaction_response(commit_digest: &[u8]) -> String {
+    // the Ion binary value 42
+    let value = [0xE0, 0x01, 0x00, 0xEA, 0x21, 0x2A];
+    format!(
+        r#"{{
+            "StartSession": {{"SessionToken": "session-1"}},
+            "StartTransaction": {{"TransactionId": "txn-1"}},
+            "ExecuteStatement": {{"FirstPage": {{"Values": [{{"IonBinary": "{}"}}]}}}},
+            "CommitTransaction": {{"TransactionId": "txn-1", "CommitDigest": "{}"}}
+        }}"#,
+        base64(&value),
+        base64(commit_digest)
+    )
+}
+
+#[test]
+fn should_execute_and_commit_transactions() {
+    let statement = "SELECT COUNT(*) FROM Person";
+    let digest = dot(
+        &IonValue::String("txn-1".to_owned()).ion_hash(),
+        &statement_hash(statement, &[]),
+    );
+    let (commands, checker) = command_recorder();
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(&transaction_response(&digest))
+        .with_request_checker(checker);
+    let client = QldbSessionClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let driver = QldbDriver::new(client, "ledger");
+
+    for _ in 0..2 {
+        let values = driver
+            .execute(move |txn| txn.execute(statement, Vec::new()))
+            .sync()
+            .unwrap();
+        assert_eq!(values, vec![IonValue::Int(42)]);
+    }
+
+    // the session is reused by the second transaction
+    assert_eq!(
+        *commands.lock().unwrap(),
+        vec![
+            "StartSession",
+            "StartTransaction",
+            "ExecuteStatement",
+            "CommitTransaction",
+            "StartTransaction",
+            "ExecuteStatement",
+            "CommitTransaction",
+        ]
+    );
+}
+
+#[test]
+fn should_fail_on_digest_mismatch() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(&transaction_response(b"digest"));
+    let client = QldbSessionClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let driver = QldbDriver::new(client, "ledger");
+
+    let result = driver
+        .execute(|txn| txn.execute("SELECT * FROM Person", Vec::new()))
+        .sync();
+    assert_eq!(
+        result,
+        Err(RusotoError::Service(DriverError::DigestMismatch))
+    );
+}
+
+#[test]
+fn should_retry_occ_conflicts() {
+    let (commands, checker) = command_recorder();
+    let mock = MockRequestDispatcher::with_status(400)
+        .with_body(r#"{"__type": "OccConflictException", "Message": "conflict"}"#)
+        .with_request_checker(checker);
+    let client = QldbSessionClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let config = DriverConfig {
+        max_retries: 2,
+        base_delay: Duration::from_millis(1),
+        ..Default::default()
+    };
+    let driver = QldbDriver::with_config(client, "ledger", config);
+
+    let result = driver
+        .execute(|txn| txn.execute("SELECT * FROM Person", Vec::new()))
+        .sync();
+    assert_eq!(
+        result,
+        Err(RusotoError::Service(DriverError::SendCommand(
+            SendCommandError::OccConflict("conflict".to_owned())
+        )))
+    );
+    assert_eq!(commands.lock().unwrap().len(), 3);
+}
@@ -0,0 +1,505 @@
+//! A driver running transactions against a QLDB ledger.
+//!
+//! The QLDB session API is a single `SendCommand` operation: a session is started, transactions
+//! are started in the session, statements are executed and results fetched page by page, and
+//! transactions are committed with a digest of their statements. `QldbDriver` takes care of
+//! these steps. It pools sessions, verifies the commit digest and retries transactions which
+//! fail because of an optimistic concurrency control (OCC) conflict.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use rusoto_core::Region;
+//! use rusoto_qldb_session::driver::QldbDriver;
+//! use rusoto_qldb_session::ion::IonValue;
+//! use rusoto_qldb_session::QldbSessionClient;
+//!
+//! let client = QldbSessionClient::new(Region::UsEast1);
+//! let driver = QldbDriver::new(client, "vehicle-registration");
+//!
+//! let owners = driver
+//!     .execute(|txn| {
+//!         txn.execute(
+//!             "SELECT * FROM Person WHERE GovId = ?",
+//!             vec![IonValue::String("LEWISR261LL".to_owned())],
+//!         )
+//!     })
+//!     .sync()
+//!     .expect("transaction failed");
+//! ```
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::future::{self, Either, Loop};
+use futures::{Future, IntoFuture};
+use rusoto_core::waiter::sleep;
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::custom::ion::{IonError, IonValue};
+use crate::generated::{
+    AbortTransactionRequest, CommitTransactionRequest, EndSessionRequest, ExecuteStatementRequest,
+    FetchPageRequest, Page, QldbSession, QldbSessionClient, SendCommandError, SendCommandRequest,
+    SendCommandResult, StartSessionRequest, StartTransactionRequest,
+};
+
+/// Controls how `QldbDriver` pools sessions and retries transactions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DriverConfig {
+    /// Number of times a transaction is retried after an OCC conflict or an expired session
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for every subsequent retry
+    pub base_delay: Duration,
+    /// Maximum number of idle sessions kept for later transactions
+    pub max_idle_sessions: usize,
+}
+
+impl Default for DriverConfig {
+    fn default() -> DriverConfig {
+        DriverConfig {
+            max_retries: 4,
+            base_delay: Duration::from_millis(10),
+            max_idle_sessions: 10,
+        }
+    }
+}
+
+/// Errors returned by `QldbDriver` and `Transaction`.
+#[derive(Debug, PartialEq)]
+pub enum DriverError {
+    /// A command sent to the ledger failed
+    SendCommand(SendCommandError),
+    /// The digest of the committed transaction doesn't match the statements it executed
+    DigestMismatch,
+    /// A statement returned a value which can't be decoded
+    Ion(IonError),
+}
+
+impl fmt::Display for DriverError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for DriverError {
+    fn description(&self) -> &str {
+        match *self {
+            DriverError::SendCommand(ref cause) => cause.description(),
+            DriverError::DigestMismatch => "Commit digest doesn't match the executed statements",
+            DriverError::Ion(ref cause) => cause.description(),
+        }
+    }
+}
+
+type DriverFuture<T> = Box<dyn Future<Item = T, Error = RusotoError<DriverError>> + Send>;
+
+fn send_command(
+    client: &QldbSessionClient,
+    request: SendCommandRequest,
+) -> impl Future<Item = SendCommandResult, Error = RusotoError<DriverError>> + Send {
+    client
+        .send_command(request)
+        .map_err(|e| e.map_service(DriverError::SendCommand))
+}
+
+fn missing(field: &str) -> RusotoError<DriverError> {
+    RusotoError::ParseError(format!("SendCommand result without {}", field))
+}
+
+/// Combines two QLDB hashes, ordering them by comparing their bytes as signed integers from the
+/// last byte to the first.
+pub(crate) fn dot(left: &[u8], right: &[u8]) -> Vec<u8> {
+    if left.is_empty() {
+        return right.to_vec();
+    }
+    if right.is_empty() {
+        return left.to_vec();
+    }
+    let ordering = left
+        .iter()
+        .rev()
+        .zip(right.iter().rev())
+        .map(|(&l, &r)| (l as i8).cmp(&(r as i8)))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or(Ordering::Equal);
+    let mut concatenated = Vec::with_capacity(left.len() + right.len());
+    if ordering == Ordering::Less {
+        concatenated.extend_from_slice(left);
+        concatenated.extend_from_slice(right);
+    } else {
+        concatenated.extend_from_slice(right);
+        concatenated.extend_from_slice(left);
+    }
+    crate::custom::ion::sha256(&concatenated)
+}
+
+/// The hash of a statement and its parameters, as included in the commit digest.
+pub(crate) fn statement_hash(statement: &str, parameters: &[IonValue]) -> Vec<u8> {
+    parameters.iter().fold(
+        IonValue::String(statement.to_owned()).ion_hash(),
+        |hash, parameter| dot(&hash, &parameter.ion_hash()),
+    )
+}
+
+/// An open transaction, passed to the function given to `QldbDriver::execute`.
+#[derive(Clone)]
+pub struct Transaction {
+    client: QldbSessionClient,
+    session_token: String,
+    transaction_id: String,
+    digest: Arc<Mutex<Vec<u8>>>,
+}
+
+impl Transaction {
+    fn new(client: QldbSessionClient, session_token: String, transaction_id: String) -> Self {
+        let digest = IonValue::String(transaction_id.clone()).ion_hash();
+        Transaction {
+            client,
+            session_token,
+            transaction_id,
+            digest: Arc::new(Mutex::new(digest)),
+        }
+    }
+
+    /// The ID of the transaction.
+    pub fn id(&self) -> &str {
+        &self.transaction_id
+    }
+
+    /// Executes a PartiQL statement, substituting `?` placeholders with `parameters`, and
+    /// returns all the values it produced.
+    pub fn execute(
+        &self,
+        statement: &str,
+        parameters: Vec<IonValue>,
+    ) -> RusotoFuture<Vec<IonValue>, DriverError> {
+        {
+            let mut digest = self.digest.lock().unwrap();
+            *digest = dot(&digest, &statement_hash(statement, &parameters));
+        }
+
+        let request = SendCommandRequest {
+            session_token: Some(self.session_token.clone()),
+            execute_statement: Some(ExecuteStatementRequest {
+                statement: statement.to_owned(),
+                parameters: if parameters.is_empty() {
+                    None
+                } else {
+                    Some(parameters.iter().map(IonValue::to_value_holder).collect())
+                },
+                transaction_id: self.transaction_id.clone(),
+            }),
+            ..Default::default()
+        };
+
+        let txn = self.clone();
+        let future = send_command(&self.client, request).and_then(move |result| {
+            let page = result
+                .execute_statement
+                .and_then(|result| result.first_page)
+                .ok_or_else(|| missing("ExecuteStatement"))?;
+            Ok(txn.fetch_remaining(page))
+        });
+
+        RusotoFuture::from_future(future.flatten())
+    }
+
+    fn fetch_remaining(
+        self,
+        first_page: Page,
+    ) -> impl Future<Item = Vec<IonValue>, Error = RusotoError<DriverError>> + Send {
+        future::loop_fn((Vec::new(), first_page), move |(mut values, page)| {
+            for holder in page.values.unwrap_or_default() {
+                match IonValue::from_value_holder(&holder) {
+                    Ok(value) => values.push(value),
+                    Err(e) => {
+                        return Either::A(future::err(RusotoError::Service(DriverError::Ion(e))))
+                    }
+                }
+            }
+            let next_page_token = match page.next_page_token {
+                Some(token) => token,
+                None => return Either::A(future::ok(Loop::Break(values))),
+            };
+            let request = SendCommandRequest {
+                session_token: Some(self.session_token.clone()),
+                fetch_page: Some(FetchPageRequest {
+                    next_page_token,
+                    transaction_id: self.transaction_id.clone(),
+                }),
+                ..Default::default()
+            };
+            Either::B(send_command(&self.client, request).and_then(|result| {
+                let page = result
+                    .fetch_page
+                    .and_then(|result| result.page)
+                    .ok_or_else(|| missing("FetchPage"))?;
+                Ok(Loop::Continue((values, page)))
+            }))
+        })
+    }
+
+    fn commit(self) -> impl Future<Item = (), Error = RusotoError<DriverError>> + Send {
+        let digest = self.digest.lock().unwrap().clone();
+        let request = SendCommandRequest {
+            session_token: Some(self.session_token.clone()),
+            commit_transaction: Some(CommitTransactionRequest {
+                commit_digest: Bytes::from(digest.clone()),
+                transaction_id: self.transaction_id.clone(),
+            }),
+            ..Default::default()
+        };
+
+        send_command(&self.client, request).and_then(move |result| {
+            let committed = result
+                .commit_transaction
+                .and_then(|result| result.commit_digest)
+                .ok_or_else(|| missing("CommitTransaction"))?;
+            if committed.as_ref() == digest.as_slice() {
+                Ok(())
+            } else {
+                Err(RusotoError::Service(DriverError::DigestMismatch))
+            }
+        })
+    }
+
+    fn abort(self) -> impl Future<Item = (), Error = RusotoError<DriverError>> + Send {
+        let request = SendCommandRequest {
+            session_token: Some(self.session_token.clone()),
+            abort_transaction: Some(AbortTransactionRequest {}),
+            ..Default::default()
+        };
+
+        send_command(&self.client, request).map(|_| ())
+    }
+}
+
+/// Runs transactions against a ledger, see the module documentation.
+#[derive(Clone)]
+pub struct QldbDriver {
+    client: QldbSessionClient,
+    ledger_name: String,
+    config: DriverConfig,
+    sessions: Arc<Mutex<Vec<String>>>,
+}
+
+impl QldbDriver {
+    /// Creates a driver for the ledger `ledger_name`, with the default configuration.
+    pub fn new<S>(client: QldbSessionClient, ledger_name: S) -> QldbDriver
+    where
+        S: Into<String>,
+    {
+        QldbDriver::with_config(client, ledger_name, DriverConfig::default())
+    }
+
+    /// Creates a driver for the ledger `ledger_name`.
+    pub fn with_config<S>(
+        client: QldbSessionClient,
+        ledger_name: S,
+        config: DriverConfig,
+    ) -> QldbDriver
+    where
+        S: Into<String>,
+    {
+        QldbDriver {
+            client,
+            ledger_name: ledger_name.into(),
+            config,
+            sessions: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Runs `transaction` in a new transaction and commits it.
+    ///
+    /// The function is called again, in a new transaction, if the commit fails because of an
+    /// OCC conflict or the session expired, so it should not have side effects outside of the
+    /// ledger. If the function fails, the transaction is aborted and the error is returned.
+    pub fn execute<F, R, T>(&self, transaction: F) -> RusotoFuture<T, DriverError>
+    where
+        F: Fn(Transaction) -> R + Send + Sync + 'static,
+        R: IntoFuture<Item = T, Error = RusotoError<DriverError>>,
+        R::Future: Send + 'static,
+        T: Send + 'static,
+    {
+        let driver = self.clone();
+        let transaction = Arc::new(transaction);
+
+        let future = future::loop_fn(0, move |retries| {
+            let driver = driver.clone();
+            let transaction = transaction.clone();
+
+            driver
+                .session()
+                .then(move |session| match session {
+                    Ok(session) => Either::A(
+                        driver
+                            .run(session.clone(), transaction)
+                            .then(move |result| Ok((driver, Some(session), result))),
+                    ),
+                    Err(err) => Either::B(future::ok((driver, None, Err(err)))),
+                })
+                .and_then(move |(driver, session, result)| {
+                    let err = match result {
+                        Ok(value) => {
+                            driver.release(session);
+                            return Either::A(future::ok(Loop::Break(value)));
+                        }
+                        Err(err) => err,
+                    };
+                    let retry = retries < driver.config.max_retries
+                        && match err {
+                            RusotoError::Service(DriverError::SendCommand(ref cause)) => {
+                                match *cause {
+                                    SendCommandError::InvalidSession(_)
+                                    | SendCommandError::OccConflict(_)
+                                    | SendCommandError::RateExceeded(_) => true,
+                                    _ => false,
+                                }
+                            }
+                            _ => false,
+                        };
+                    // expired sessions are not reused
+                    if !is_invalid_session(&err) {
+                        driver.release(session);
+                    }
+                    if !retry {
+                        return Either::A(future::err(err));
+                    }
+                    let delay = backoff(driver.config.base_delay, retries);
+                    Either::B(sleep(delay).map(move |_| Loop::Continue(retries + 1)))
+                })
+        });
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Ends the idle sessions of the driver.
+    pub fn close(&self) -> RusotoFuture<(), DriverError> {
+        let sessions = self.sessions.lock().unwrap().drain(..).collect::<Vec<_>>();
+        let client = self.client.clone();
+        let ended = sessions.into_iter().map(move |session| {
+            let request = SendCommandRequest {
+                session_token: Some(session),
+                end_session: Some(EndSessionRequest {}),
+                ..Default::default()
+            };
+            send_command(&client, request)
+        });
+
+        RusotoFuture::from_future(future::join_all(ended).map(|_| ()))
+    }
+
+    /// Takes an idle session, or starts a new one.
+    fn session(&self) -> DriverFuture<String> {
+        if let Some(session) = self.sessions.lock().unwrap().pop() {
+            return Box::new(future::ok(session));
+        }
+        let request = SendCommandRequest {
+            start_session: Some(StartSessionRequest {
+                ledger_name: self.ledger_name.clone(),
+            }),
+            ..Default::default()
+        };
+
+        Box::new(send_command(&self.client, request).and_then(|result| {
+            result
+                .start_session
+                .and_then(|result| result.session_token)
+                .ok_or_else(|| missing("StartSession"))
+        }))
+    }
+
+    /// Keeps a session for later transactions, unless enough sessions are idle already.
+    fn release(&self, session: Option<String>) {
+        let mut sessions = self.sessions.lock().unwrap();
+        if let Some(session) = session {
+            if sessions.len() < self.config.max_idle_sessions {
+                sessions.push(session);
+            }
+        }
+    }
+
+    /// Runs one attempt of a transaction in `session`.
+    fn run<F, R, T>(&self, session: String, transaction: Arc<F>) -> DriverFuture<T>
+    where
+        F: Fn(Transaction) -> R + Send + Sync + 'static,
+        R: IntoFuture<Item = T, Error = RusotoError<DriverError>>,
+        R::Future: Send + 'static,
+        T: Send + 'static,
+    {
+        let client = self.client.clone();
+        let request = SendCommandRequest {
+            session_token: Some(session.clone()),
+            start_transaction: Some(StartTransactionRequest {}),
+            ..Default::default()
+        };
+
+        Box::new(
+            send_command(&self.client, request)
+                .and_then(move |result| {
+                    result
+                        .start_transaction
+                        .and_then(|result| result.transaction_id)
+                        .map(|transaction_id| Transaction::new(client, session, transaction_id))
+                        .ok_or_else(|| missing("StartTransaction"))
+                })
+                .and_then(move |txn| {
+                    transaction(txn.clone())
+                        .into_future()
+                        .then(move |result| match result {
+                            Ok(value) => Either::A(txn.commit().map(move |_| value)),
+                            // the transaction is aborted on a best effort basis
+                            Err(err) => Either::B(txn.abort().then(move |_| Err(err))),
+                        })
+                }),
+        )
+    }
+}
+
+fn is_invalid_session(err: &RusotoError<DriverError>) -> bool {
+    match *err {
+        RusotoError::Service(DriverError::SendCommand(SendCommandError::InvalidSession(_))) => true,
+        _ => false,
+    }
+}
+
+fn backoff(base_delay: Duration, retries: u32) -> Duration {
+    let factor = 1u32.checked_shl(retries).unwrap_or(u32::max_value());
+    base_delay
+        .checked_mul(factor)
+        .map_or(Duration::from_secs(5), |delay| {
+            delay.min(Duration::from_secs(5))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orders_hashes_by_signed_bytes_from_the_end() {
+        // 0x80 is negative as a signed byte, so `right` sorts first
+        let left = [0x01, 0x01];
+        let right = [0x00, 0x80];
+        assert_eq!(
+            dot(&left, &right),
+            crate::custom::ion::sha256(&[0x00, 0x80, 0x01, 0x01])
+        );
+        assert_eq!(dot(&right, &left), dot(&left, &right));
+        assert_eq!(dot(&[], &left), left.to_vec());
+    }
+
+    #[test]
+    fn hashes_statements_with_parameters() {
+        let statement = IonValue::String("SELECT * FROM t WHERE a = ?".to_owned()).ion_hash();
+        let parameter = IonValue::Int(1).ion_hash();
+        assert_eq!(
+            statement_hash("SELECT * FROM t WHERE a = ?", &[IonValue::Int(1)]),
+            dot(&statement, &parameter)
+        );
+    }
+}
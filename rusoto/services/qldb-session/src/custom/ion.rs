@@ -0,0 +1,714 @@
+//! A subset of Amazon Ion for statement parameters and results.
+//!
+//! QLDB returns every value as an Ion binary document and accepts parameters as Ion text.
+//! `IonValue` covers the types most documents use: nulls, booleans, integers, floats, decimals,
+//! strings, symbols, blobs, lists and structs. Timestamps and type annotations are not
+//! supported; decoding a value containing a timestamp fails with `IonError::Unsupported`.
+//!
+//! `IonValue::ion_hash` computes the Ion hash with SHA-256 that QLDB uses to verify the
+//! statements and parameters of a transaction.
+
+use std::error::Error;
+use std::fmt;
+
+use rusoto_core::signature::ChecksumAlgorithm;
+
+use crate::generated::ValueHolder;
+
+/// An Amazon Ion value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IonValue {
+    /// A null value of any type
+    Null,
+    /// A boolean
+    Bool(bool),
+    /// An integer
+    Int(i64),
+    /// A 64 bit floating point number
+    Float(f64),
+    /// A decimal number, `coefficient * 10^exponent`
+    Decimal {
+        /// The digits of the number
+        coefficient: i64,
+        /// The power of ten the coefficient is multiplied with
+        exponent: i64,
+    },
+    /// A string
+    String(String),
+    /// A symbol
+    Symbol(String),
+    /// Binary data, decoded from blobs and clobs
+    Blob(Vec<u8>),
+    /// A list, also decoded from s-expressions
+    List(Vec<IonValue>),
+    /// A struct, in the order its fields were encoded
+    Struct(Vec<(String, IonValue)>),
+}
+
+/// Errors decoding an Ion binary value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IonError {
+    /// The data ended in the middle of a value
+    UnexpectedEnd,
+    /// The data is not valid Ion binary
+    Invalid(String),
+    /// The value has a type this module doesn't support
+    Unsupported(String),
+}
+
+impl fmt::Display for IonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IonError::UnexpectedEnd => write!(f, "{}", self.description()),
+            IonError::Invalid(ref cause) | IonError::Unsupported(ref cause) => {
+                write!(f, "{}: {}", self.description(), cause)
+            }
+        }
+    }
+}
+
+impl Error for IonError {
+    fn description(&self) -> &str {
+        match *self {
+            IonError::UnexpectedEnd => "Unexpected end of Ion data",
+            IonError::Invalid(_) => "Invalid Ion data",
+            IonError::Unsupported(_) => "Unsupported Ion value",
+        }
+    }
+}
+
+const SYSTEM_SYMBOLS: [&str; 10] = [
+    "",
+    "$ion",
+    "$ion_1_0",
+    "$ion_symbol_table",
+    "name",
+    "version",
+    "imports",
+    "symbols",
+    "max_id",
+    "$ion_shared_symbol_table",
+];
+
+const VERSION_MARKER: [u8; 4] = [0xE0, 0x01, 0x00, 0xEA];
+
+impl IonValue {
+    /// Decodes the first value of an Ion binary document, such as the values QLDB returns.
+    pub fn from_binary(data: &[u8]) -> Result<IonValue, IonError> {
+        let mut reader = Reader {
+            data,
+            position: 0,
+            symbols: system_symbols(),
+        };
+        loop {
+            if reader.data[reader.position..].starts_with(&VERSION_MARKER) {
+                reader.position += VERSION_MARKER.len();
+                reader.symbols = system_symbols();
+                continue;
+            }
+            if reader.position >= reader.data.len() {
+                return Err(IonError::UnexpectedEnd);
+            }
+            if let Some(value) = reader.top_level_value()? {
+                return Ok(value);
+            }
+        }
+    }
+
+    /// Decodes the value of a `ValueHolder` returned by QLDB.
+    pub fn from_value_holder(holder: &ValueHolder) -> Result<IonValue, IonError> {
+        match holder.ion_binary {
+            Some(ref binary) => IonValue::from_binary(binary),
+            None => Err(IonError::Unsupported("Ion text values".to_owned())),
+        }
+    }
+
+    /// Wraps the value in a `ValueHolder`, e.g. to pass it as a statement parameter.
+    pub fn to_value_holder(&self) -> ValueHolder {
+        ValueHolder {
+            ion_binary: None,
+            ion_text: Some(self.to_text()),
+        }
+    }
+
+    /// Encodes the value as Ion text.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        self.write_text(&mut text);
+        text
+    }
+
+    /// The Ion hash of the value, computed with SHA-256.
+    pub fn ion_hash(&self) -> Vec<u8> {
+        let mut serialized = Vec::new();
+        self.serialize_for_hash(&mut serialized);
+        sha256(&serialized)
+    }
+
+    fn write_text(&self, out: &mut String) {
+        match *self {
+            IonValue::Null => out.push_str("null"),
+            IonValue::Bool(value) => out.push_str(if value { "true" } else { "false" }),
+            IonValue::Int(value) => out.push_str(&value.to_string()),
+            IonValue::Float(value) if value.is_nan() => out.push_str("nan"),
+            IonValue::Float(value) if value.is_infinite() => {
+                out.push_str(if value > 0.0 { "+inf" } else { "-inf" })
+            }
+            IonValue::Float(value) => out.push_str(&format!("{:e}", value)),
+            IonValue::Decimal {
+                coefficient,
+                exponent,
+            } => out.push_str(&format!("{}d{}", coefficient, exponent)),
+            IonValue::String(ref value) => write_quoted(out, value, '"'),
+            IonValue::Symbol(ref value) => write_quoted(out, value, '\''),
+            IonValue::Blob(ref value) => {
+                out.push_str("{{");
+                out.push_str(&base64(value));
+                out.push_str("}}");
+            }
+            IonValue::List(ref values) => {
+                out.push('[');
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        out.push_str(", ");
+                    }
+                    value.write_text(out);
+                }
+                out.push(']');
+            }
+            IonValue::Struct(ref fields) => {
+                out.push('{');
+                for (index, &(ref name, ref value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        out.push_str(", ");
+                    }
+                    write_quoted(out, name, '\'');
+                    out.push_str(": ");
+                    value.write_text(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+
+    /// Appends `s(value)` as defined by the Ion hash specification.
+    fn serialize_for_hash(&self, out: &mut Vec<u8>) {
+        match *self {
+            IonValue::List(ref values) => {
+                out.push(HASH_BEGIN);
+                out.push(0xB0);
+                for value in values {
+                    value.serialize_for_hash(out);
+                }
+                out.push(HASH_END);
+            }
+            IonValue::Struct(ref fields) => {
+                let mut hashes = fields
+                    .iter()
+                    .map(|&(ref name, ref value)| {
+                        let mut field = Vec::new();
+                        IonValue::Symbol(name.clone()).serialize_for_hash(&mut field);
+                        value.serialize_for_hash(&mut field);
+                        sha256(&field)
+                    })
+                    .collect::<Vec<_>>();
+                hashes.sort();
+                out.push(HASH_BEGIN);
+                out.push(0xD0);
+                for hash in hashes {
+                    escape_into(out, &hash);
+                }
+                out.push(HASH_END);
+            }
+            _ => {
+                let (type_qualifier, representation) = self.scalar_representation();
+                out.push(HASH_BEGIN);
+                escape_into(out, &[type_qualifier]);
+                escape_into(out, &representation);
+                out.push(HASH_END);
+            }
+        }
+    }
+
+    /// The type/qualifier byte and binary representation of a scalar.
+    fn scalar_representation(&self) -> (u8, Vec<u8>) {
+        match *self {
+            IonValue::Null => (0x0F, Vec::new()),
+            IonValue::Bool(value) => (0x10 | value as u8, Vec::new()),
+            IonValue::Int(value) if value < 0 => (0x30, magnitude(value.wrapping_abs() as u64)),
+            IonValue::Int(value) => (0x20, magnitude(value as u64)),
+            IonValue::Float(value) if value == 0.0 && value.is_sign_positive() => {
+                (0x40, Vec::new())
+            }
+            IonValue::Float(value) => (0x40, value.to_bits().to_be_bytes().to_vec()),
+            IonValue::Decimal {
+                coefficient,
+                exponent,
+            } => {
+                if coefficient == 0 && exponent == 0 {
+                    return (0x50, Vec::new());
+                }
+                let mut representation = var_int(exponent);
+                if coefficient != 0 {
+                    representation.extend(signed_int(coefficient));
+                }
+                (0x50, representation)
+            }
+            IonValue::Symbol(ref value) => (0x70, value.as_bytes().to_vec()),
+            IonValue::String(ref value) => (0x80, value.as_bytes().to_vec()),
+            IonValue::Blob(ref value) => (0xA0, value.clone()),
+            IonValue::List(_) | IonValue::Struct(_) => unreachable!("not a scalar"),
+        }
+    }
+}
+
+const HASH_BEGIN: u8 = 0x0B;
+const HASH_END: u8 = 0x0E;
+const HASH_ESCAPE: u8 = 0x0C;
+
+fn escape_into(out: &mut Vec<u8>, bytes: &[u8]) {
+    for &byte in bytes {
+        if byte == HASH_BEGIN || byte == HASH_END || byte == HASH_ESCAPE {
+            out.push(HASH_ESCAPE);
+        }
+        out.push(byte);
+    }
+}
+
+pub(crate) fn sha256(data: &[u8]) -> Vec<u8> {
+    ChecksumAlgorithm::Sha256.digest(data)
+}
+
+fn magnitude(value: u64) -> Vec<u8> {
+    value
+        .to_be_bytes()
+        .iter()
+        .skip_while(|&&byte| byte == 0)
+        .cloned()
+        .collect()
+}
+
+/// A fixed size Int: the sign in the highest bit, followed by the magnitude.
+fn signed_int(value: i64) -> Vec<u8> {
+    let mut bytes = magnitude(value.wrapping_abs() as u64);
+    if bytes[0] & 0x80 != 0 {
+        bytes.insert(0, 0);
+    }
+    if value < 0 {
+        bytes[0] |= 0x80;
+    }
+    bytes
+}
+
+/// A VarInt: 7 bits per byte, the sign in the first byte and the end flag in the last byte.
+fn var_int(value: i64) -> Vec<u8> {
+    let mut magnitude = value.wrapping_abs() as u64;
+    let mut bytes = vec![(magnitude & 0x7F) as u8 | 0x80];
+    magnitude >>= 7;
+    while magnitude > 0 {
+        bytes.insert(0, (magnitude & 0x7F) as u8);
+        magnitude >>= 7;
+    }
+    if bytes[0] & 0x40 != 0 {
+        bytes.insert(0, 0);
+    }
+    if value < 0 {
+        bytes[0] |= 0x40;
+    }
+    bytes
+}
+
+fn write_quoted(out: &mut String, value: &str, quote: char) {
+    out.push(quote);
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c == quote => {
+                out.push('\\');
+                out.push(c);
+            }
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\x{:02x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push(quote);
+}
+
+pub(crate) fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let group = (u32::from(bytes[0]) << 16) | (u32::from(bytes[1]) << 8) | u32::from(bytes[2]);
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[((group >> (18 - 6 * index)) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+fn system_symbols() -> Vec<String> {
+    SYSTEM_SYMBOLS
+        .iter()
+        .map(|&symbol| symbol.to_owned())
+        .collect()
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+    symbols: Vec<String>,
+}
+
+impl<'a> Reader<'a> {
+    fn byte(&mut self) -> Result<u8, IonError> {
+        let byte = *self
+            .data
+            .get(self.position)
+            .ok_or(IonError::UnexpectedEnd)?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8], IonError> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|&end| end <= self.data.len())
+            .ok_or(IonError::UnexpectedEnd)?;
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn var_uint(&mut self) -> Result<u64, IonError> {
+        let mut value = 0u64;
+        loop {
+            let byte = self.byte()?;
+            if value.leading_zeros() < 7 {
+                return Err(IonError::Unsupported("VarUInt overflow".to_owned()));
+            }
+            value = (value << 7) | u64::from(byte & 0x7F);
+            if byte & 0x80 != 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn var_int(&mut self) -> Result<i64, IonError> {
+        let first = self.byte()?;
+        let negative = first & 0x40 != 0;
+        let mut value = u64::from(first & 0x3F);
+        let mut last = first;
+        while last & 0x80 == 0 {
+            last = self.byte()?;
+            if value.leading_zeros() < 7 {
+                return Err(IonError::Unsupported("VarInt overflow".to_owned()));
+            }
+            value = (value << 7) | u64::from(last & 0x7F);
+        }
+        to_i64(value, negative)
+    }
+
+    fn symbol(&self, sid: u64) -> Result<String, IonError> {
+        self.symbols
+            .get(sid as usize)
+            .cloned()
+            .ok_or_else(|| IonError::Invalid(format!("unknown symbol ${}", sid)))
+    }
+
+    /// Reads a top level value, returning `None` for system values such as symbol tables.
+    fn top_level_value(&mut self) -> Result<Option<IonValue>, IonError> {
+        let descriptor = self.data[self.position];
+        if descriptor >> 4 == 0xE {
+            let start = self.position;
+            self.position += 1;
+            let length = self.length(descriptor)?;
+            let end = self.position + length;
+            let annotations_length = self.var_uint()? as usize;
+            let annotations_end = self.position + annotations_length;
+            let first_annotation = self.var_uint()?;
+            self.position = annotations_end;
+            if first_annotation == 3 {
+                let table = self.value()?;
+                self.position = end;
+                self.load_symbol_table(table)?;
+                return Ok(None);
+            }
+            self.position = start;
+        }
+        match self.value()? {
+            // NOP padding
+            None => Ok(None),
+            Some(value) => Ok(Some(value)),
+        }
+    }
+
+    fn load_symbol_table(&mut self, table: Option<IonValue>) -> Result<(), IonError> {
+        let fields = match table {
+            Some(IonValue::Struct(fields)) => fields,
+            _ => return Ok(()),
+        };
+        let appends = fields.iter().any(|&(ref name, ref value)| {
+            name == "imports" && *value == IonValue::Symbol("$ion_symbol_table".to_owned())
+        });
+        if !appends {
+            self.symbols = system_symbols();
+        }
+        for (name, value) in fields {
+            if name != "symbols" {
+                continue;
+            }
+            if let IonValue::List(symbols) = value {
+                for symbol in symbols {
+                    self.symbols.push(match symbol {
+                        IonValue::String(text) => text,
+                        _ => String::new(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn length(&mut self, descriptor: u8) -> Result<usize, IonError> {
+        match descriptor & 0x0F {
+            14 => Ok(self.var_uint()? as usize),
+            length => Ok(length as usize),
+        }
+    }
+
+    /// Reads a value, returning `None` for NOP padding.
+    fn value(&mut self) -> Result<Option<IonValue>, IonError> {
+        let descriptor = self.byte()?;
+        let (type_code, length_code) = (descriptor >> 4, descriptor & 0x0F);
+        if length_code == 15 {
+            return match type_code {
+                0x0..=0xD => Ok(Some(IonValue::Null)),
+                _ => Err(IonError::Invalid(format!("descriptor {:#x}", descriptor))),
+            };
+        }
+        let length = match (type_code, length_code) {
+            (0x1, _) => 0,
+            (0xD, 1) => self.var_uint()? as usize,
+            _ => self.length(descriptor)?,
+        };
+        let start = self.position;
+        let body = self.bytes(length)?;
+
+        let value = match type_code {
+            0x0 => return Ok(None),
+            0x1 => IonValue::Bool(length_code == 1),
+            0x2 | 0x3 => {
+                if body.len() > 8 {
+                    return Err(IonError::Unsupported("integer overflow".to_owned()));
+                }
+                let magnitude = body
+                    .iter()
+                    .fold(0u64, |value, &byte| (value << 8) | u64::from(byte));
+                IonValue::Int(to_i64(magnitude, type_code == 0x3)?)
+            }
+            0x4 => match length {
+                0 => IonValue::Float(0.0),
+                4 => IonValue::Float(f64::from(f32::from_bits(u32::from_be_bytes([
+                    body[0], body[1], body[2], body[3],
+                ])))),
+                8 => {
+                    let mut bits = [0u8; 8];
+                    bits.copy_from_slice(body);
+                    IonValue::Float(f64::from_bits(u64::from_be_bytes(bits)))
+                }
+                _ => return Err(IonError::Invalid(format!("float of {} bytes", length))),
+            },
+            0x5 => {
+                if length == 0 {
+                    IonValue::Decimal {
+                        coefficient: 0,
+                        exponent: 0,
+                    }
+                } else {
+                    self.position = start;
+                    let exponent = self.var_int()?;
+                    let coefficient = self.bytes(start + length - self.position)?;
+                    IonValue::Decimal {
+                        coefficient: read_signed_int(coefficient)?,
+                        exponent,
+                    }
+                }
+            }
+            0x6 => return Err(IonError::Unsupported("timestamp".to_owned())),
+            0x7 => {
+                let sid = body
+                    .iter()
+                    .fold(0u64, |value, &byte| (value << 8) | u64::from(byte));
+                IonValue::Symbol(self.symbol(sid)?)
+            }
+            0x8 => IonValue::String(
+                String::from_utf8(body.to_vec())
+                    .map_err(|_| IonError::Invalid("string is not UTF-8".to_owned()))?,
+            ),
+            0x9 | 0xA => IonValue::Blob(body.to_vec()),
+            0xB | 0xC => {
+                self.position = start;
+                let mut values = Vec::new();
+                while self.position < start + length {
+                    if let Some(value) = self.value()? {
+                        values.push(value);
+                    }
+                }
+                IonValue::List(values)
+            }
+            0xD => {
+                self.position = start;
+                let mut fields = Vec::new();
+                while self.position < start + length {
+                    let name = self.var_uint()?;
+                    if let Some(value) = self.value()? {
+                        fields.push((self.symbol(name)?, value));
+                    }
+                }
+                IonValue::Struct(fields)
+            }
+            0xE => {
+                // annotations are dropped
+                self.position = start;
+                let annotations_length = self.var_uint()? as usize;
+                self.position += annotations_length;
+                let value = self.value()?;
+                self.position = start + length;
+                return Ok(value);
+            }
+            _ => return Err(IonError::Invalid(format!("descriptor {:#x}", descriptor))),
+        };
+        if self.position != start + length {
+            return Err(IonError::Invalid("value length mismatch".to_owned()));
+        }
+        Ok(Some(value))
+    }
+}
+
+fn to_i64(magnitude: u64, negative: bool) -> Result<i64, IonError> {
+    if negative {
+        if magnitude <= i64::max_value() as u64 + 1 {
+            Ok((magnitude as i64).wrapping_neg())
+        } else {
+            Err(IonError::Unsupported("integer overflow".to_owned()))
+        }
+    } else if magnitude <= i64::max_value() as u64 {
+        Ok(magnitude as i64)
+    } else {
+        Err(IonError::Unsupported("integer overflow".to_owned()))
+    }
+}
+
+fn read_signed_int(bytes: &[u8]) -> Result<i64, IonError> {
+    if bytes.is_empty() {
+        return Ok(0);
+    }
+    if bytes.len() > 8 {
+        return Err(IonError::Unsupported("decimal overflow".to_owned()));
+    }
+    let negative = bytes[0] & 0x80 != 0;
+    let magnitude = bytes
+        .iter()
+        .enumerate()
+        .fold(0u64, |value, (index, &byte)| {
+            let byte = if index == 0 { byte & 0x7F } else { byte };
+            (value << 8) | u64::from(byte)
+        });
+    to_i64(magnitude, negative)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_values_with_local_symbols() {
+        let data = [
+            0xE0, 0x01, 0x00, 0xEA, // version marker
+            0xEA, 0x81, 0x83, 0xD7, 0x87, 0xB5, 0x84, b'n', b'a', b'm', b'e', // symbols
+            0xD9, 0x8A, 0x81, b'x', 0x8A, 0x31, 0x05, 0x8A, 0xB1, 0x11, // {name: "x", ...}
+        ];
+        assert_eq!(
+            IonValue::from_binary(&data),
+            Ok(IonValue::Struct(vec![
+                ("name".to_owned(), IonValue::String("x".to_owned())),
+                ("name".to_owned(), IonValue::Int(-5)),
+                (
+                    "name".to_owned(),
+                    IonValue::List(vec![IonValue::Bool(true)])
+                ),
+            ]))
+        );
+        assert_eq!(
+            IonValue::from_binary(&[0xE0, 0x01, 0x00, 0xEA, 0x61, 0x80]),
+            Err(IonError::Unsupported("timestamp".to_owned()))
+        );
+    }
+
+    #[test]
+    fn decodes_numbers() {
+        let decimal = [0xE0, 0x01, 0x00, 0xEA, 0x52, 0xC2, 0x8F];
+        assert_eq!(
+            IonValue::from_binary(&decimal),
+            Ok(IonValue::Decimal {
+                coefficient: -15,
+                exponent: -2,
+            })
+        );
+        let float = [0xE0, 0x01, 0x00, 0xEA, 0x44, 0x3F, 0xC0, 0x00, 0x00];
+        assert_eq!(IonValue::from_binary(&float), Ok(IonValue::Float(1.5)));
+    }
+
+    #[test]
+    fn encodes_text() {
+        let value = IonValue::Struct(vec![
+            ("id".to_owned(), IonValue::Int(42)),
+            ("it's".to_owned(), IonValue::String("a \"b\"\n".to_owned())),
+            (
+                "tags".to_owned(),
+                IonValue::List(vec![IonValue::Float(1.5), IonValue::Blob(b"ab".to_vec())]),
+            ),
+        ]);
+        assert_eq!(
+            value.to_text(),
+            r#"{'id': 42, 'it\'s': "a \"b\"\n", 'tags': [1.5e0, {{YWI=}}]}"#
+        );
+    }
+
+    #[test]
+    fn serializes_values_for_hashing() {
+        let mut serialized = Vec::new();
+        IonValue::String("hello".to_owned()).serialize_for_hash(&mut serialized);
+        assert_eq!(serialized, b"\x0b\x80hello\x0e".to_vec());
+
+        let mut serialized = Vec::new();
+        IonValue::Int(-14).serialize_for_hash(&mut serialized);
+        assert_eq!(serialized, vec![0x0B, 0x30, 0x0C, 0x0E, 0x0E]);
+
+        let mut serialized = Vec::new();
+        IonValue::List(vec![IonValue::Bool(true)]).serialize_for_hash(&mut serialized);
+        assert_eq!(serialized, vec![0x0B, 0xB0, 0x0B, 0x11, 0x0E, 0x0E]);
+    }
+
+    #[test]
+    fn encodes_decimal_representation() {
+        let decimal = IonValue::Decimal {
+            coefficient: -15,
+            exponent: -2,
+        };
+        assert_eq!(decimal.scalar_representation(), (0x50, vec![0xC2, 0x8F]));
+    }
+}
@@ -0,0 +1,8 @@
+/// Running transactions with session pooling, commit digests and OCC retries
+pub mod driver;
+
+/// Amazon Ion values for statement parameters and results
+pub mod ion;
+
+#[cfg(test)]
+mod custom_tests;
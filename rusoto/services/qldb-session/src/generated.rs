@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Contains the details of the transaction to abort.</p>
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -172,6 +173,90 @@ pub struct SendCommandRequest {
     pub start_transaction: Option<StartTransactionRequest>,
 }
 
+impl SendCommandRequest {
+    /// Returns a builder for `SendCommandRequest`, checking its required members when built.
+    pub fn builder() -> SendCommandRequestBuilder {
+        SendCommandRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SendCommandRequest`](struct.SendCommandRequest.html).
+#[derive(Default, Debug)]
+pub struct SendCommandRequestBuilder {
+    abort_transaction: Option<AbortTransactionRequest>,
+    commit_transaction: Option<CommitTransactionRequest>,
+    end_session: Option<EndSessionRequest>,
+    execute_statement: Option<ExecuteStatementRequest>,
+    fetch_page: Option<FetchPageRequest>,
+    session_token: Option<String>,
+    start_session: Option<StartSessionRequest>,
+    start_transaction: Option<StartTransactionRequest>,
+}
+
+impl SendCommandRequestBuilder {
+    /// Sets the [`abort_transaction`](struct.SendCommandRequest.html#structfield.abort_transaction) member.
+    pub fn abort_transaction<T: Into<AbortTransactionRequest>>(mut self, value: T) -> Self {
+        self.abort_transaction = Some(value.into());
+        self
+    }
+
+    /// Sets the [`commit_transaction`](struct.SendCommandRequest.html#structfield.commit_transaction) member.
+    pub fn commit_transaction<T: Into<CommitTransactionRequest>>(mut self, value: T) -> Self {
+        self.commit_transaction = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_session`](struct.SendCommandRequest.html#structfield.end_session) member.
+    pub fn end_session<T: Into<EndSessionRequest>>(mut self, value: T) -> Self {
+        self.end_session = Some(value.into());
+        self
+    }
+
+    /// Sets the [`execute_statement`](struct.SendCommandRequest.html#structfield.execute_statement) member.
+    pub fn execute_statement<T: Into<ExecuteStatementRequest>>(mut self, value: T) -> Self {
+        self.execute_statement = Some(value.into());
+        self
+    }
+
+    /// Sets the [`fetch_page`](struct.SendCommandRequest.html#structfield.fetch_page) member.
+    pub fn fetch_page<T: Into<FetchPageRequest>>(mut self, value: T) -> Self {
+        self.fetch_page = Some(value.into());
+        self
+    }
+
+    /// Sets the [`session_token`](struct.SendCommandRequest.html#structfield.session_token) member.
+    pub fn session_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.session_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_session`](struct.SendCommandRequest.html#structfield.start_session) member.
+    pub fn start_session<T: Into<StartSessionRequest>>(mut self, value: T) -> Self {
+        self.start_session = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_transaction`](struct.SendCommandRequest.html#structfield.start_transaction) member.
+    pub fn start_transaction<T: Into<StartTransactionRequest>>(mut self, value: T) -> Self {
+        self.start_transaction = Some(value.into());
+        self
+    }
+
+    /// Builds the `SendCommandRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SendCommandRequest, ::rusoto_core::BuildError> {
+        Ok(SendCommandRequest {
+            abort_transaction: self.abort_transaction,
+            commit_transaction: self.commit_transaction,
+            end_session: self.end_session,
+            execute_statement: self.execute_statement,
+            fetch_page: self.fetch_page,
+            session_token: self.session_token,
+            start_session: self.start_session,
+            start_transaction: self.start_transaction,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SendCommandResult {
@@ -320,6 +405,21 @@ pub trait QldbSession {
         input: SendCommandRequest,
     ) -> RusotoFuture<SendCommandResult, SendCommandError>;
 }
+impl<T: ?Sized + QldbSession> QldbSession for ::std::sync::Arc<T> {
+    fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> RusotoFuture<SendCommandResult, SendCommandError> {
+        (**self).send_command(input)
+    }
+}
+/// The error codes QLDB Session retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the QLDB Session API.
 #[derive(Clone)]
 pub struct QldbSessionClient {
@@ -335,6 +435,27 @@ impl QldbSessionClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_QLDB_SESSION`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> QldbSessionClient {
+        Self::new(region::Region::from_env_for_service("QLDB_SESSION"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `session.qldb`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> QldbSessionClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("session.qldb", "QLDB_SESSION"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -353,7 +474,54 @@ impl QldbSessionClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> QldbSessionClient {
-        QldbSessionClient { client, region }
+        QldbSessionClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "session.qldb")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "session.qldb",
+            "qldb",
+            "QLDB_SESSION",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `QldbSession` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn QldbSession + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for QldbSessionClient {
+    fn service_name(&self) -> &'static str {
+        "QLDB Session"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "session.qldb"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> QldbSessionClient {
+        Self::new_with_client(client, region)
     }
 }
 
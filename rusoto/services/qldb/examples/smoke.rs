@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon QLDB clients.
+//!
+//! Makes a single read-only `ListJournalS3Exports` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_qldb::{ListJournalS3ExportsRequest, Qldb, QldbClient};
+
+fn main() {
+    let client = QldbClient::from_env();
+    match client
+        .list_journal_s3_exports(ListJournalS3ExportsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListJournalS3Exports succeeded"),
+        Err(error) => {
+            eprintln!("ListJournalS3Exports failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -23,8 +23,9 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateLedgerRequest {
     /// <p>The flag that prevents a ledger from being deleted by any user. If not provided on ledger creation, this feature is enabled (<code>true</code>) by default.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
     #[serde(rename = "DeletionProtection")]
@@ -35,13 +36,69 @@ pub struct CreateLedgerRequest {
     pub name: String,
     /// <p>The permissions mode to assign to the ledger that you want to create.</p>
     #[serde(rename = "PermissionsMode")]
-    pub permissions_mode: String,
+    pub permissions_mode: PermissionsMode,
     /// <p>The key-value pairs to add as tags to the ledger that you want to create. Tag keys are case sensitive. Tag values are case sensitive and can be null.</p>
     #[serde(rename = "Tags")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateLedgerRequest {
+    /// Returns a builder for `CreateLedgerRequest`, checking its required members when built.
+    pub fn builder() -> CreateLedgerRequestBuilder {
+        CreateLedgerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateLedgerRequest`](struct.CreateLedgerRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateLedgerRequestBuilder {
+    deletion_protection: Option<bool>,
+    name: Option<String>,
+    permissions_mode: Option<PermissionsMode>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateLedgerRequestBuilder {
+    /// Sets the [`deletion_protection`](struct.CreateLedgerRequest.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateLedgerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`permissions_mode`](struct.CreateLedgerRequest.html#structfield.permissions_mode) member.
+    pub fn permissions_mode<T: Into<PermissionsMode>>(mut self, value: T) -> Self {
+        self.permissions_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateLedgerRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateLedgerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateLedgerRequest, ::rusoto_core::BuildError> {
+        Ok(CreateLedgerRequest {
+            deletion_protection: self.deletion_protection,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            permissions_mode: self
+                .permissions_mode
+                .ok_or(::rusoto_core::BuildError::missing("permissions_mode"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateLedgerResponse {
@@ -51,8 +108,9 @@ pub struct CreateLedgerResponse {
     pub arn: Option<String>,
     /// <p>The date and time, in epoch time format, when the ledger was created. (Epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC.)</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The flag that prevents a ledger from being deleted by any user. If not provided on ledger creation, this feature is enabled (<code>true</code>) by default.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
     #[serde(rename = "DeletionProtection")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -64,7 +122,7 @@ pub struct CreateLedgerResponse {
     /// <p>The current status of the ledger.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<LedgerState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -74,6 +132,36 @@ pub struct DeleteLedgerRequest {
     pub name: String,
 }
 
+impl DeleteLedgerRequest {
+    /// Returns a builder for `DeleteLedgerRequest`, checking its required members when built.
+    pub fn builder() -> DeleteLedgerRequestBuilder {
+        DeleteLedgerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteLedgerRequest`](struct.DeleteLedgerRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteLedgerRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteLedgerRequestBuilder {
+    /// Sets the [`name`](struct.DeleteLedgerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteLedgerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteLedgerRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteLedgerRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct DescribeJournalS3ExportRequest {
     /// <p>The unique ID of the journal export job that you want to describe.</p>
@@ -84,7 +172,49 @@ pub struct DescribeJournalS3ExportRequest {
     pub name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+impl DescribeJournalS3ExportRequest {
+    /// Returns a builder for `DescribeJournalS3ExportRequest`, checking its required members when built.
+    pub fn builder() -> DescribeJournalS3ExportRequestBuilder {
+        DescribeJournalS3ExportRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeJournalS3ExportRequest`](struct.DescribeJournalS3ExportRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeJournalS3ExportRequestBuilder {
+    export_id: Option<String>,
+    name: Option<String>,
+}
+
+impl DescribeJournalS3ExportRequestBuilder {
+    /// Sets the [`export_id`](struct.DescribeJournalS3ExportRequest.html#structfield.export_id) member.
+    pub fn export_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.export_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.DescribeJournalS3ExportRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeJournalS3ExportRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeJournalS3ExportRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeJournalS3ExportRequest {
+            export_id: self
+                .export_id
+                .ok_or(::rusoto_core::BuildError::missing("export_id"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeJournalS3ExportResponse {
     /// <p>Information about the journal export job returned by a <code>DescribeJournalS3Export</code> request.</p>
@@ -99,6 +229,36 @@ pub struct DescribeLedgerRequest {
     pub name: String,
 }
 
+impl DescribeLedgerRequest {
+    /// Returns a builder for `DescribeLedgerRequest`, checking its required members when built.
+    pub fn builder() -> DescribeLedgerRequestBuilder {
+        DescribeLedgerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLedgerRequest`](struct.DescribeLedgerRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeLedgerRequestBuilder {
+    name: Option<String>,
+}
+
+impl DescribeLedgerRequestBuilder {
+    /// Sets the [`name`](struct.DescribeLedgerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLedgerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeLedgerRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeLedgerRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeLedgerResponse {
@@ -108,8 +268,9 @@ pub struct DescribeLedgerResponse {
     pub arn: Option<String>,
     /// <p>The date and time, in epoch time format, when the ledger was created. (Epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC.)</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The flag that prevents a ledger from being deleted by any user. If not provided on ledger creation, this feature is enabled (<code>true</code>) by default.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
     #[serde(rename = "DeletionProtection")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -121,17 +282,19 @@ pub struct DescribeLedgerResponse {
     /// <p>The current status of the ledger.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<LedgerState>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ExportJournalToS3Request {
     /// <p>The exclusive end date and time for the range of journal contents that you want to export.</p> <p>The <code>ExclusiveEndTime</code> must be in <code>ISO 8601</code> date and time format and in Universal Coordinated Time (UTC). For example: <code>2019-06-13T21:36:34Z</code> </p> <p>The <code>ExclusiveEndTime</code> must be less than or equal to the current UTC date and time.</p>
     #[serde(rename = "ExclusiveEndTime")]
-    pub exclusive_end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub exclusive_end_time: EpochTimestamp,
     /// <p>The inclusive start date and time for the range of journal contents that you want to export.</p> <p>The <code>InclusiveStartTime</code> must be in <code>ISO 8601</code> date and time format and in Universal Coordinated Time (UTC). For example: <code>2019-06-13T21:36:34Z</code> </p> <p>The <code>InclusiveStartTime</code> must be before <code>ExclusiveEndTime</code>.</p> <p>If you provide an <code>InclusiveStartTime</code> that is before the ledger's <code>CreationDateTime</code>, Amazon QLDB defaults it to the ledger's <code>CreationDateTime</code>.</p>
     #[serde(rename = "InclusiveStartTime")]
-    pub inclusive_start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub inclusive_start_time: EpochTimestamp,
     /// <p>The name of the ledger.</p>
     #[serde(rename = "Name")]
     pub name: String,
@@ -143,6 +306,78 @@ pub struct ExportJournalToS3Request {
     pub s3_export_configuration: S3ExportConfiguration,
 }
 
+impl ExportJournalToS3Request {
+    /// Returns a builder for `ExportJournalToS3Request`, checking its required members when built.
+    pub fn builder() -> ExportJournalToS3RequestBuilder {
+        ExportJournalToS3RequestBuilder::default()
+    }
+}
+
+/// Builds a [`ExportJournalToS3Request`](struct.ExportJournalToS3Request.html).
+#[derive(Default, Debug)]
+pub struct ExportJournalToS3RequestBuilder {
+    exclusive_end_time: Option<EpochTimestamp>,
+    inclusive_start_time: Option<EpochTimestamp>,
+    name: Option<String>,
+    role_arn: Option<String>,
+    s3_export_configuration: Option<S3ExportConfiguration>,
+}
+
+impl ExportJournalToS3RequestBuilder {
+    /// Sets the [`exclusive_end_time`](struct.ExportJournalToS3Request.html#structfield.exclusive_end_time) member.
+    pub fn exclusive_end_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.exclusive_end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`inclusive_start_time`](struct.ExportJournalToS3Request.html#structfield.inclusive_start_time) member.
+    pub fn inclusive_start_time<T: Into<EpochTimestamp>>(mut self, value: T) -> Self {
+        self.inclusive_start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.ExportJournalToS3Request.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.ExportJournalToS3Request.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_export_configuration`](struct.ExportJournalToS3Request.html#structfield.s3_export_configuration) member.
+    pub fn s3_export_configuration<T: Into<S3ExportConfiguration>>(mut self, value: T) -> Self {
+        self.s3_export_configuration = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExportJournalToS3Request`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ExportJournalToS3Request, ::rusoto_core::BuildError> {
+        Ok(ExportJournalToS3Request {
+            exclusive_end_time: self
+                .exclusive_end_time
+                .ok_or(::rusoto_core::BuildError::missing("exclusive_end_time"))?,
+            inclusive_start_time: self
+                .inclusive_start_time
+                .ok_or(::rusoto_core::BuildError::missing("inclusive_start_time"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            s3_export_configuration: self.s3_export_configuration.ok_or(
+                ::rusoto_core::BuildError::missing("s3_export_configuration"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ExportJournalToS3Response {
@@ -151,6 +386,88 @@ pub struct ExportJournalToS3Response {
     pub export_id: String,
 }
 
+/// <p>The current state of the journal export job.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExportStatus {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>COMPLETED</code></p>
+    Completed,
+    /// <p><code>CANCELLED</code></p>
+    Cancelled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ExportStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ExportStatus::InProgress => "IN_PROGRESS",
+            ExportStatus::Completed => "COMPLETED",
+            ExportStatus::Cancelled => "CANCELLED",
+            ExportStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ExportStatus {
+    fn from(value: &'a str) -> ExportStatus {
+        match value {
+            "IN_PROGRESS" => ExportStatus::InProgress,
+            "COMPLETED" => ExportStatus::Completed,
+            "CANCELLED" => ExportStatus::Cancelled,
+            _ => ExportStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ExportStatus {
+    fn from(value: String) -> ExportStatus {
+        ExportStatus::from(value.as_str())
+    }
+}
+
+impl From<ExportStatus> for String {
+    fn from(value: ExportStatus) -> String {
+        match value {
+            ExportStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ExportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ExportStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ExportStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ExportStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ExportStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ExportStatus::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct GetBlockRequest {
     /// <p>The location of the block that you want to request. An address is an Amazon Ion structure that has two fields: <code>strandId</code> and <code>sequenceNo</code>.</p> <p>For example: <code>{strandId:"BlFTjlSXze9BIh1KOszcE3",sequenceNo:14}</code> </p>
@@ -165,6 +482,54 @@ pub struct GetBlockRequest {
     pub name: String,
 }
 
+impl GetBlockRequest {
+    /// Returns a builder for `GetBlockRequest`, checking its required members when built.
+    pub fn builder() -> GetBlockRequestBuilder {
+        GetBlockRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetBlockRequest`](struct.GetBlockRequest.html).
+#[derive(Default, Debug)]
+pub struct GetBlockRequestBuilder {
+    block_address: Option<ValueHolder>,
+    digest_tip_address: Option<ValueHolder>,
+    name: Option<String>,
+}
+
+impl GetBlockRequestBuilder {
+    /// Sets the [`block_address`](struct.GetBlockRequest.html#structfield.block_address) member.
+    pub fn block_address<T: Into<ValueHolder>>(mut self, value: T) -> Self {
+        self.block_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`digest_tip_address`](struct.GetBlockRequest.html#structfield.digest_tip_address) member.
+    pub fn digest_tip_address<T: Into<ValueHolder>>(mut self, value: T) -> Self {
+        self.digest_tip_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetBlockRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetBlockRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetBlockRequest, ::rusoto_core::BuildError> {
+        Ok(GetBlockRequest {
+            block_address: self
+                .block_address
+                .ok_or(::rusoto_core::BuildError::missing("block_address"))?,
+            digest_tip_address: self.digest_tip_address,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetBlockResponse {
@@ -184,6 +549,36 @@ pub struct GetDigestRequest {
     pub name: String,
 }
 
+impl GetDigestRequest {
+    /// Returns a builder for `GetDigestRequest`, checking its required members when built.
+    pub fn builder() -> GetDigestRequestBuilder {
+        GetDigestRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetDigestRequest`](struct.GetDigestRequest.html).
+#[derive(Default, Debug)]
+pub struct GetDigestRequestBuilder {
+    name: Option<String>,
+}
+
+impl GetDigestRequestBuilder {
+    /// Sets the [`name`](struct.GetDigestRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetDigestRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetDigestRequest, ::rusoto_core::BuildError> {
+        Ok(GetDigestRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetDigestResponse {
@@ -217,6 +612,64 @@ pub struct GetRevisionRequest {
     pub name: String,
 }
 
+impl GetRevisionRequest {
+    /// Returns a builder for `GetRevisionRequest`, checking its required members when built.
+    pub fn builder() -> GetRevisionRequestBuilder {
+        GetRevisionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetRevisionRequest`](struct.GetRevisionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetRevisionRequestBuilder {
+    block_address: Option<ValueHolder>,
+    digest_tip_address: Option<ValueHolder>,
+    document_id: Option<String>,
+    name: Option<String>,
+}
+
+impl GetRevisionRequestBuilder {
+    /// Sets the [`block_address`](struct.GetRevisionRequest.html#structfield.block_address) member.
+    pub fn block_address<T: Into<ValueHolder>>(mut self, value: T) -> Self {
+        self.block_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`digest_tip_address`](struct.GetRevisionRequest.html#structfield.digest_tip_address) member.
+    pub fn digest_tip_address<T: Into<ValueHolder>>(mut self, value: T) -> Self {
+        self.digest_tip_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`document_id`](struct.GetRevisionRequest.html#structfield.document_id) member.
+    pub fn document_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.document_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetRevisionRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetRevisionRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetRevisionRequest, ::rusoto_core::BuildError> {
+        Ok(GetRevisionRequest {
+            block_address: self
+                .block_address
+                .ok_or(::rusoto_core::BuildError::missing("block_address"))?,
+            digest_tip_address: self.digest_tip_address,
+            document_id: self
+                .document_id
+                .ok_or(::rusoto_core::BuildError::missing("document_id"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetRevisionResponse {
@@ -230,21 +683,24 @@ pub struct GetRevisionResponse {
 }
 
 /// <p>The information about a journal export job, including the ledger name, export ID, when it was created, current status, and its start and end time export parameters.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct JournalS3ExportDescription {
     /// <p>The exclusive end date and time for the range of journal contents that are specified in the original export request.</p>
     #[serde(rename = "ExclusiveEndTime")]
-    pub exclusive_end_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub exclusive_end_time: EpochTimestamp,
     /// <p>The date and time, in epoch time format, when the export job was created. (Epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC.)</p>
     #[serde(rename = "ExportCreationTime")]
-    pub export_creation_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub export_creation_time: EpochTimestamp,
     /// <p>The unique ID of the journal export job.</p>
     #[serde(rename = "ExportId")]
     pub export_id: String,
     /// <p>The inclusive start date and time for the range of journal contents that are specified in the original export request.</p>
     #[serde(rename = "InclusiveStartTime")]
-    pub inclusive_start_time: f64,
+    #[serde(with = "::rusoto_core::timestamp::epoch_seconds")]
+    pub inclusive_start_time: EpochTimestamp,
     /// <p>The name of the ledger.</p>
     #[serde(rename = "LedgerName")]
     pub ledger_name: String,
@@ -255,7 +711,93 @@ pub struct JournalS3ExportDescription {
     pub s3_export_configuration: S3ExportConfiguration,
     /// <p>The current state of the journal export job.</p>
     #[serde(rename = "Status")]
-    pub status: String,
+    pub status: ExportStatus,
+}
+
+/// <p>The current status of the ledger.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LedgerState {
+    /// <p><code>CREATING</code></p>
+    Creating,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LedgerState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LedgerState::Creating => "CREATING",
+            LedgerState::Active => "ACTIVE",
+            LedgerState::Deleting => "DELETING",
+            LedgerState::Deleted => "DELETED",
+            LedgerState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LedgerState {
+    fn from(value: &'a str) -> LedgerState {
+        match value {
+            "CREATING" => LedgerState::Creating,
+            "ACTIVE" => LedgerState::Active,
+            "DELETING" => LedgerState::Deleting,
+            "DELETED" => LedgerState::Deleted,
+            _ => LedgerState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LedgerState {
+    fn from(value: String) -> LedgerState {
+        LedgerState::from(value.as_str())
+    }
+}
+
+impl From<LedgerState> for String {
+    fn from(value: LedgerState) -> String {
+        match value {
+            LedgerState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LedgerState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for LedgerState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for LedgerState {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LedgerState {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LedgerState, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LedgerState::from(value))
+    }
 }
 
 /// <p>Information about a ledger, including its name, state, and when it was created.</p>
@@ -264,8 +806,9 @@ pub struct JournalS3ExportDescription {
 pub struct LedgerSummary {
     /// <p>The date and time, in epoch time format, when the ledger was created. (Epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC.)</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The name of the ledger.</p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -273,7 +816,7 @@ pub struct LedgerSummary {
     /// <p>The current status of the ledger.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<LedgerState>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -291,6 +834,55 @@ pub struct ListJournalS3ExportsForLedgerRequest {
     pub next_token: Option<String>,
 }
 
+impl ListJournalS3ExportsForLedgerRequest {
+    /// Returns a builder for `ListJournalS3ExportsForLedgerRequest`, checking its required members when built.
+    pub fn builder() -> ListJournalS3ExportsForLedgerRequestBuilder {
+        ListJournalS3ExportsForLedgerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListJournalS3ExportsForLedgerRequest`](struct.ListJournalS3ExportsForLedgerRequest.html).
+#[derive(Default, Debug)]
+pub struct ListJournalS3ExportsForLedgerRequestBuilder {
+    max_results: Option<i64>,
+    name: Option<String>,
+    next_token: Option<String>,
+}
+
+impl ListJournalS3ExportsForLedgerRequestBuilder {
+    /// Sets the [`max_results`](struct.ListJournalS3ExportsForLedgerRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.ListJournalS3ExportsForLedgerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListJournalS3ExportsForLedgerRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJournalS3ExportsForLedgerRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListJournalS3ExportsForLedgerRequest, ::rusoto_core::BuildError>
+    {
+        Ok(ListJournalS3ExportsForLedgerRequest {
+            max_results: self.max_results,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListJournalS3ExportsForLedgerResponse {
@@ -316,6 +908,44 @@ pub struct ListJournalS3ExportsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListJournalS3ExportsRequest {
+    /// Returns a builder for `ListJournalS3ExportsRequest`, checking its required members when built.
+    pub fn builder() -> ListJournalS3ExportsRequestBuilder {
+        ListJournalS3ExportsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListJournalS3ExportsRequest`](struct.ListJournalS3ExportsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListJournalS3ExportsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListJournalS3ExportsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListJournalS3ExportsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListJournalS3ExportsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListJournalS3ExportsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListJournalS3ExportsRequest, ::rusoto_core::BuildError> {
+        Ok(ListJournalS3ExportsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListJournalS3ExportsResponse {
@@ -341,6 +971,42 @@ pub struct ListLedgersRequest {
     pub next_token: Option<String>,
 }
 
+impl ListLedgersRequest {
+    /// Returns a builder for `ListLedgersRequest`, checking its required members when built.
+    pub fn builder() -> ListLedgersRequestBuilder {
+        ListLedgersRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListLedgersRequest`](struct.ListLedgersRequest.html).
+#[derive(Default, Debug)]
+pub struct ListLedgersRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListLedgersRequestBuilder {
+    /// Sets the [`max_results`](struct.ListLedgersRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListLedgersRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListLedgersRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListLedgersRequest, ::rusoto_core::BuildError> {
+        Ok(ListLedgersRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListLedgersResponse {
@@ -361,6 +1027,38 @@ pub struct ListTagsForResourceRequest {
     pub resource_arn: String,
 }
 
+impl ListTagsForResourceRequest {
+    /// Returns a builder for `ListTagsForResourceRequest`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceRequestBuilder {
+        ListTagsForResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceRequest`](struct.ListTagsForResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceRequestBuilder {
+    resource_arn: Option<String>,
+}
+
+impl ListTagsForResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.ListTagsForResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceRequest, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListTagsForResourceResponse {
@@ -370,8 +1068,86 @@ pub struct ListTagsForResourceResponse {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+/// <p>The permissions mode to assign to the ledger that you want to create.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PermissionsMode {
+    /// <p><code>ALLOW_ALL</code></p>
+    AllowAll,
+    /// <p><code>STANDARD</code></p>
+    Standard,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PermissionsMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PermissionsMode::AllowAll => "ALLOW_ALL",
+            PermissionsMode::Standard => "STANDARD",
+            PermissionsMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PermissionsMode {
+    fn from(value: &'a str) -> PermissionsMode {
+        match value {
+            "ALLOW_ALL" => PermissionsMode::AllowAll,
+            "STANDARD" => PermissionsMode::Standard,
+            _ => PermissionsMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PermissionsMode {
+    fn from(value: String) -> PermissionsMode {
+        PermissionsMode::from(value.as_str())
+    }
+}
+
+impl From<PermissionsMode> for String {
+    fn from(value: PermissionsMode) -> String {
+        match value {
+            PermissionsMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PermissionsMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for PermissionsMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for PermissionsMode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PermissionsMode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PermissionsMode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PermissionsMode::from(value))
+    }
+}
+
 /// <p>The encryption settings that are used by a journal export job to write data in an Amazon Simple Storage Service (Amazon S3) bucket.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3EncryptionConfiguration {
     /// <p>The Amazon Resource Name (ARN) for a customer master key (CMK) in AWS Key Management Service (AWS KMS).</p> <p>You must provide a <code>KmsKeyArn</code> if you specify <code>SSE_KMS</code> as the <code>ObjectEncryptionType</code>.</p> <p> <code>KmsKeyArn</code> is not required if you specify <code>SSE_S3</code> as the <code>ObjectEncryptionType</code>.</p>
     #[serde(rename = "KmsKeyArn")]
@@ -379,11 +1155,11 @@ pub struct S3EncryptionConfiguration {
     pub kms_key_arn: Option<String>,
     /// <p>The Amazon S3 object encryption type.</p> <p>To learn more about server-side encryption options in Amazon S3, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/serv-side-encryption.html">Protecting Data Using Server-Side Encryption</a> in the <i>Amazon S3 Developer Guide</i>.</p>
     #[serde(rename = "ObjectEncryptionType")]
-    pub object_encryption_type: String,
+    pub object_encryption_type: S3ObjectEncryptionType,
 }
 
 /// <p>The Amazon Simple Storage Service (Amazon S3) bucket location in which a journal export job writes the journal contents.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct S3ExportConfiguration {
     /// <p>The Amazon S3 bucket name in which a journal export job writes the journal contents.</p> <p>The bucket name must comply with the Amazon S3 bucket naming conventions. For more information, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/BucketRestrictions.html">Bucket Restrictions and Limitations</a> in the <i>Amazon S3 Developer Guide</i>.</p>
     #[serde(rename = "Bucket")]
@@ -396,6 +1172,88 @@ pub struct S3ExportConfiguration {
     pub prefix: String,
 }
 
+/// <p>The Amazon S3 object encryption type.</p> <p>To learn more about server-side encryption options in Amazon S3, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/dev/serv-side-encryption.html">Protecting Data Using Server-Side Encryption</a> in the <i>Amazon S3 Developer Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum S3ObjectEncryptionType {
+    /// <p><code>SSE_KMS</code></p>
+    SseKms,
+    /// <p><code>SSE_S3</code></p>
+    SseS3,
+    /// <p><code>NO_ENCRYPTION</code></p>
+    NoEncryption,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl S3ObjectEncryptionType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            S3ObjectEncryptionType::SseKms => "SSE_KMS",
+            S3ObjectEncryptionType::SseS3 => "SSE_S3",
+            S3ObjectEncryptionType::NoEncryption => "NO_ENCRYPTION",
+            S3ObjectEncryptionType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for S3ObjectEncryptionType {
+    fn from(value: &'a str) -> S3ObjectEncryptionType {
+        match value {
+            "SSE_KMS" => S3ObjectEncryptionType::SseKms,
+            "SSE_S3" => S3ObjectEncryptionType::SseS3,
+            "NO_ENCRYPTION" => S3ObjectEncryptionType::NoEncryption,
+            _ => S3ObjectEncryptionType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for S3ObjectEncryptionType {
+    fn from(value: String) -> S3ObjectEncryptionType {
+        S3ObjectEncryptionType::from(value.as_str())
+    }
+}
+
+impl From<S3ObjectEncryptionType> for String {
+    fn from(value: S3ObjectEncryptionType) -> String {
+        match value {
+            S3ObjectEncryptionType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for S3ObjectEncryptionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for S3ObjectEncryptionType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for S3ObjectEncryptionType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for S3ObjectEncryptionType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<S3ObjectEncryptionType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(S3ObjectEncryptionType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TagResourceRequest {
     /// <p>The Amazon Resource Name (ARN) to which you want to add the tags. For example:</p> <p> <code>arn:aws:qldb:us-east-1:123456789012:ledger/exampleLedger</code> </p>
@@ -406,6 +1264,46 @@ pub struct TagResourceRequest {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.TagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -420,6 +1318,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_arn`](struct.UntagResourceRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -435,6 +1373,44 @@ pub struct UpdateLedgerRequest {
     pub name: String,
 }
 
+impl UpdateLedgerRequest {
+    /// Returns a builder for `UpdateLedgerRequest`, checking its required members when built.
+    pub fn builder() -> UpdateLedgerRequestBuilder {
+        UpdateLedgerRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateLedgerRequest`](struct.UpdateLedgerRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateLedgerRequestBuilder {
+    deletion_protection: Option<bool>,
+    name: Option<String>,
+}
+
+impl UpdateLedgerRequestBuilder {
+    /// Sets the [`deletion_protection`](struct.UpdateLedgerRequest.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateLedgerRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateLedgerRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateLedgerRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateLedgerRequest {
+            deletion_protection: self.deletion_protection,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateLedgerResponse {
@@ -444,8 +1420,9 @@ pub struct UpdateLedgerResponse {
     pub arn: Option<String>,
     /// <p>The date and time, in epoch time format, when the ledger was created. (Epoch time format is the number of seconds elapsed since 12:00:00 AM January 1, 1970 UTC.)</p>
     #[serde(rename = "CreationDateTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_date_time: Option<f64>,
+    pub creation_date_time: Option<EpochTimestamp>,
     /// <p>The flag that prevents a ledger from being deleted by any user. If not provided on ledger creation, this feature is enabled (<code>true</code>) by default.</p> <p>If deletion protection is enabled, you must first disable it before you can delete the ledger using the QLDB API or the AWS Command Line Interface (AWS CLI). You can disable it by calling the <code>UpdateLedger</code> operation to set the flag to <code>false</code>. The QLDB console disables deletion protection for you when you use it to delete a ledger.</p>
     #[serde(rename = "DeletionProtection")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -457,7 +1434,7 @@ pub struct UpdateLedgerResponse {
     /// <p>The current status of the ledger.</p>
     #[serde(rename = "State")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub state: Option<String>,
+    pub state: Option<LedgerState>,
 }
 
 /// <p>A structure that can contain an Amazon Ion value in multiple encoding formats.</p>
@@ -1150,6 +2127,100 @@ pub trait Qldb {
         input: UpdateLedgerRequest,
     ) -> RusotoFuture<UpdateLedgerResponse, UpdateLedgerError>;
 }
+impl<T: ?Sized + Qldb> Qldb for ::std::sync::Arc<T> {
+    fn create_ledger(
+        &self,
+        input: CreateLedgerRequest,
+    ) -> RusotoFuture<CreateLedgerResponse, CreateLedgerError> {
+        (**self).create_ledger(input)
+    }
+    fn delete_ledger(&self, input: DeleteLedgerRequest) -> RusotoFuture<(), DeleteLedgerError> {
+        (**self).delete_ledger(input)
+    }
+    fn describe_journal_s3_export(
+        &self,
+        input: DescribeJournalS3ExportRequest,
+    ) -> RusotoFuture<DescribeJournalS3ExportResponse, DescribeJournalS3ExportError> {
+        (**self).describe_journal_s3_export(input)
+    }
+    fn describe_ledger(
+        &self,
+        input: DescribeLedgerRequest,
+    ) -> RusotoFuture<DescribeLedgerResponse, DescribeLedgerError> {
+        (**self).describe_ledger(input)
+    }
+    fn export_journal_to_s3(
+        &self,
+        input: ExportJournalToS3Request,
+    ) -> RusotoFuture<ExportJournalToS3Response, ExportJournalToS3Error> {
+        (**self).export_journal_to_s3(input)
+    }
+    fn get_block(&self, input: GetBlockRequest) -> RusotoFuture<GetBlockResponse, GetBlockError> {
+        (**self).get_block(input)
+    }
+    fn get_digest(
+        &self,
+        input: GetDigestRequest,
+    ) -> RusotoFuture<GetDigestResponse, GetDigestError> {
+        (**self).get_digest(input)
+    }
+    fn get_revision(
+        &self,
+        input: GetRevisionRequest,
+    ) -> RusotoFuture<GetRevisionResponse, GetRevisionError> {
+        (**self).get_revision(input)
+    }
+    fn list_journal_s3_exports(
+        &self,
+        input: ListJournalS3ExportsRequest,
+    ) -> RusotoFuture<ListJournalS3ExportsResponse, ListJournalS3ExportsError> {
+        (**self).list_journal_s3_exports(input)
+    }
+    fn list_journal_s3_exports_for_ledger(
+        &self,
+        input: ListJournalS3ExportsForLedgerRequest,
+    ) -> RusotoFuture<ListJournalS3ExportsForLedgerResponse, ListJournalS3ExportsForLedgerError>
+    {
+        (**self).list_journal_s3_exports_for_ledger(input)
+    }
+    fn list_ledgers(
+        &self,
+        input: ListLedgersRequest,
+    ) -> RusotoFuture<ListLedgersResponse, ListLedgersError> {
+        (**self).list_ledgers(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> RusotoFuture<ListTagsForResourceResponse, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_ledger(
+        &self,
+        input: UpdateLedgerRequest,
+    ) -> RusotoFuture<UpdateLedgerResponse, UpdateLedgerError> {
+        (**self).update_ledger(input)
+    }
+}
+/// The error codes QLDB retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the QLDB API.
 #[derive(Clone)]
 pub struct QldbClient {
@@ -1165,6 +2236,24 @@ impl QldbClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_QLDB`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> QldbClient {
+        Self::new(region::Region::from_env_for_service("QLDB"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `qldb`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> QldbClient {
+        Self::new_with_client(config.client().clone(), config.region_for("qldb", "QLDB"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1183,7 +2272,49 @@ impl QldbClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> QldbClient {
-        QldbClient { client, region }
+        QldbClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "qldb")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "qldb", "qldb", "QLDB")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Qldb` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Qldb + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for QldbClient {
+    fn service_name(&self) -> &'static str {
+        "QLDB"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "qldb"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> QldbClient {
+        Self::new_with_client(client, region)
     }
 }
 
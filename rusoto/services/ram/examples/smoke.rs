@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Resource Access Manager clients.
+//!
+//! Makes a single read-only `GetResourceShareInvitations` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_ram::{GetResourceShareInvitationsRequest, Ram, RamClient};
+
+fn main() {
+    let client = RamClient::from_env();
+    match client
+        .get_resource_share_invitations(GetResourceShareInvitationsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("GetResourceShareInvitations succeeded"),
+        Err(error) => {
+            eprintln!("GetResourceShareInvitations failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,107 @@
+extern crate rusoto_mock;
+
+use crate::custom::waiters::{AssociationWaitError, AssociationWaiterExt};
+use crate::generated::{
+    AssociateResourceShareRequest, RamClient, ResourceShareAssociationStatus,
+    ResourceShareAssociationType,
+};
+
+use self::rusoto_mock::*;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::waiter::WaiterConfig;
+use rusoto_core::{Region, RusotoError};
+use std::time::Duration;
+
+const SHARE_ARN: &str = "arn:aws:ram:us-east-1:123456789012:resource-share/share";
+
+fn associations_body(status: &str) -> String {
+    format!(
+        r#"{{
+            "resourceShareAssociations": [{{
+                "associatedEntity": "210987654321",
+                "associationType": "PRINCIPAL",
+                "resourceShareArn": "{}",
+                "status": "{}",
+                "statusMessage": "Account is not part of the organization"
+            }}]
+        }}"#,
+        SHARE_ARN, status
+    )
+}
+
+#[test]
+fn associate_resource_share_and_wait_returns_settled_associations() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(&associations_body("ASSOCIATED"))
+        .with_request_checker(|request: &SignedRequest| {
+            if request.path != "/getresourceshareassociations" {
+                return;
+            }
+            let body = match request.payload {
+                Some(SignedRequestPayload::Buffer(ref body)) => body,
+                _ => panic!("expected a buffered payload"),
+            };
+            let input: serde_json::Value = serde_json::from_slice(body).unwrap();
+            assert_eq!(input["associationType"], "PRINCIPAL");
+            assert_eq!(input["resourceShareArns"][0], SHARE_ARN);
+        });
+    let client = RamClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let associations = client
+        .associate_resource_share_and_wait(
+            AssociateResourceShareRequest {
+                resource_share_arn: SHARE_ARN.to_owned(),
+                principals: Some(vec!["210987654321".to_owned()]),
+                ..Default::default()
+            },
+            WaiterConfig::new(Duration::from_millis(1), 3),
+        )
+        .sync()
+        .unwrap();
+
+    assert_eq!(associations.len(), 1);
+    assert_eq!(
+        associations[0].status,
+        Some(ResourceShareAssociationStatus::Associated)
+    );
+}
+
+#[test]
+fn wait_for_associations_reports_failed_associations() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(&associations_body("FAILED"));
+    let client = RamClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let result = client
+        .wait_for_associations(
+            SHARE_ARN,
+            ResourceShareAssociationType::Principal,
+            WaiterConfig::new(Duration::from_millis(1), 3),
+        )
+        .sync();
+
+    match result {
+        Err(RusotoError::Service(AssociationWaitError::Failed(message))) => {
+            assert!(message.contains("not part of the organization"));
+        }
+        other => panic!("expected a failed association, got {:?}", other),
+    }
+}
+
+#[test]
+fn wait_for_associations_times_out_while_associating() {
+    let mock = MockRequestDispatcher::with_status(200).with_body(&associations_body("ASSOCIATING"));
+    let client = RamClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+
+    let result = client
+        .wait_for_associations(
+            SHARE_ARN,
+            ResourceShareAssociationType::Principal,
+            WaiterConfig::new(Duration::from_millis(1), 2),
+        )
+        .sync();
+
+    match result {
+        Err(RusotoError::Service(AssociationWaitError::TimedOut(_))) => {}
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+}
@@ -1 +1,5 @@
+/// Waiting for resource share associations to complete
+pub mod waiters;
 
+#[cfg(test)]
+mod custom_tests;
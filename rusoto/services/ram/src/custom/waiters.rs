@@ -0,0 +1,200 @@
+//! Helpers for resource share associations, which complete asynchronously.
+//!
+//! `AssociateResourceShare` returns as soon as the request is accepted, while the new
+//! associations stay `ASSOCIATING` until the resources or principals can actually use the share.
+//! The methods of `AssociationWaiterExt` poll `GetResourceShareAssociations` until every
+//! association of a share has settled.
+
+use std::error::Error;
+use std::fmt;
+
+use futures::future::{self, Loop};
+use futures::Future;
+use rusoto_core::waiter::{poll_until, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    AssociateResourceShareError, AssociateResourceShareRequest, GetResourceShareAssociationsError,
+    GetResourceShareAssociationsRequest, Ram, ResourceShareAssociation,
+    ResourceShareAssociationStatus, ResourceShareAssociationType,
+};
+
+/// Errors returned while associating a resource share and waiting for the associations.
+#[derive(Debug, PartialEq)]
+pub enum AssociationWaitError {
+    /// Associating the resource share failed.
+    AssociateResourceShare(AssociateResourceShareError),
+    /// Listing the associations while waiting failed.
+    GetResourceShareAssociations(GetResourceShareAssociationsError),
+    /// An association ended up `FAILED`.
+    Failed(String),
+    /// The associations did not settle within the configured number of attempts.
+    TimedOut(String),
+}
+
+impl fmt::Display for AssociationWaitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl Error for AssociationWaitError {
+    fn description(&self) -> &str {
+        match *self {
+            AssociationWaitError::AssociateResourceShare(ref cause) => cause.description(),
+            AssociationWaitError::GetResourceShareAssociations(ref cause) => cause.description(),
+            AssociationWaitError::Failed(ref cause) => cause,
+            AssociationWaitError::TimedOut(ref cause) => cause,
+        }
+    }
+}
+
+/// Returns true once the association is no longer `ASSOCIATING` or `DISASSOCIATING`.
+pub fn is_association_settled(association: &ResourceShareAssociation) -> bool {
+    match association.status {
+        Some(ResourceShareAssociationStatus::Associating)
+        | Some(ResourceShareAssociationStatus::Disassociating) => false,
+        _ => true,
+    }
+}
+
+fn failure(association: &ResourceShareAssociation) -> Option<AssociationWaitError> {
+    if association.status != Some(ResourceShareAssociationStatus::Failed) {
+        return None;
+    }
+    let field = |value: &Option<String>| value.clone().unwrap_or_default();
+    Some(AssociationWaitError::Failed(format!(
+        "Association of {} with {} failed: {}",
+        field(&association.associated_entity),
+        field(&association.resource_share_arn),
+        field(&association.status_message),
+    )))
+}
+
+/// Extension methods which wait for resource share associations to settle.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use rusoto_core::waiter::WaiterConfig;
+/// use rusoto_core::Region;
+/// use rusoto_ram::waiters::AssociationWaiterExt;
+/// use rusoto_ram::{RamClient, ResourceShareAssociationType};
+///
+/// let client = RamClient::new(Region::UsEast1);
+/// let associations = client
+///     .wait_for_associations(
+///         "arn:aws:ram:us-east-1:123456789012:resource-share/share",
+///         ResourceShareAssociationType::Principal,
+///         WaiterConfig::default(),
+///     )
+///     .sync()
+///     .unwrap();
+/// ```
+pub trait AssociationWaiterExt: Ram + Clone + Send + Sized + 'static {
+    /// Polls `GetResourceShareAssociations` until no association of the given type is
+    /// `ASSOCIATING` or `DISASSOCIATING`, and returns all of them.
+    ///
+    /// Fails with `AssociationWaitError::Failed` as soon as an association is `FAILED`.
+    fn wait_for_associations(
+        &self,
+        resource_share_arn: &str,
+        association_type: ResourceShareAssociationType,
+        config: WaiterConfig,
+    ) -> RusotoFuture<Vec<ResourceShareAssociation>, AssociationWaitError> {
+        let client = self.clone();
+        let resource_share_arn = resource_share_arn.to_owned();
+        let timeout = RusotoError::Service(AssociationWaitError::TimedOut(format!(
+            "Associations of resource share {} did not settle",
+            resource_share_arn
+        )));
+
+        let future = poll_until(
+            config,
+            move || {
+                list_associations(&client, &resource_share_arn, association_type.clone()).and_then(
+                    |associations| {
+                        if let Some(error) = associations.iter().filter_map(failure).next() {
+                            return Err(RusotoError::Service(error));
+                        }
+                        if associations.iter().all(is_association_settled) {
+                            Ok(Some(associations))
+                        } else {
+                            Ok(None)
+                        }
+                    },
+                )
+            },
+            timeout,
+        );
+
+        RusotoFuture::from_future(future)
+    }
+
+    /// Associates principals or resources with a resource share, then waits until all of the
+    /// share's associations of that type have settled.
+    ///
+    /// Principals and resources should be associated with separate calls, as only one type of
+    /// association is waited for.
+    fn associate_resource_share_and_wait(
+        &self,
+        input: AssociateResourceShareRequest,
+        config: WaiterConfig,
+    ) -> RusotoFuture<Vec<ResourceShareAssociation>, AssociationWaitError> {
+        let client = self.clone();
+        let resource_share_arn = input.resource_share_arn.clone();
+        let association_type = if input.resource_arns.is_some() {
+            ResourceShareAssociationType::Resource
+        } else {
+            ResourceShareAssociationType::Principal
+        };
+
+        let future = self
+            .associate_resource_share(input)
+            .map_err(|e| e.map_service(AssociationWaitError::AssociateResourceShare))
+            .and_then(move |_| {
+                client.wait_for_associations(&resource_share_arn, association_type, config)
+            });
+
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: Ram + Clone + Send + 'static> AssociationWaiterExt for T {}
+
+/// Fetches every page of associations of the given type for a resource share.
+fn list_associations<C: Ram + Clone + Send + 'static>(
+    client: &C,
+    resource_share_arn: &str,
+    association_type: ResourceShareAssociationType,
+) -> impl Future<Item = Vec<ResourceShareAssociation>, Error = RusotoError<AssociationWaitError>> {
+    let client = client.clone();
+    let request = GetResourceShareAssociationsRequest {
+        association_status: None,
+        association_type,
+        max_results: None,
+        next_token: None,
+        principal: None,
+        resource_arn: None,
+        resource_share_arns: Some(vec![resource_share_arn.to_owned()]),
+    };
+
+    future::loop_fn(
+        (request, Vec::new()),
+        move |(mut request, mut associations)| {
+            client
+                .get_resource_share_associations(request.clone())
+                .map_err(|e| e.map_service(AssociationWaitError::GetResourceShareAssociations))
+                .map(move |output| {
+                    associations.extend(output.resource_share_associations.unwrap_or_default());
+                    match output.next_token {
+                        Some(token) => {
+                            request.next_token = Some(token);
+                            Loop::Continue((request, associations))
+                        }
+                        None => Loop::Break(associations),
+                    }
+                })
+        },
+    )
+}
@@ -23,6 +23,7 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AcceptResourceShareInvitationRequest {
@@ -35,6 +36,47 @@ pub struct AcceptResourceShareInvitationRequest {
     pub resource_share_invitation_arn: String,
 }
 
+impl AcceptResourceShareInvitationRequest {
+    /// Returns a builder for `AcceptResourceShareInvitationRequest`, checking its required members when built.
+    pub fn builder() -> AcceptResourceShareInvitationRequestBuilder {
+        AcceptResourceShareInvitationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AcceptResourceShareInvitationRequest`](struct.AcceptResourceShareInvitationRequest.html).
+#[derive(Default, Debug)]
+pub struct AcceptResourceShareInvitationRequestBuilder {
+    client_token: Option<String>,
+    resource_share_invitation_arn: Option<String>,
+}
+
+impl AcceptResourceShareInvitationRequestBuilder {
+    /// Sets the [`client_token`](struct.AcceptResourceShareInvitationRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_invitation_arn`](struct.AcceptResourceShareInvitationRequest.html#structfield.resource_share_invitation_arn) member.
+    pub fn resource_share_invitation_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_share_invitation_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AcceptResourceShareInvitationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AcceptResourceShareInvitationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(AcceptResourceShareInvitationRequest {
+            client_token: self.client_token,
+            resource_share_invitation_arn: self.resource_share_invitation_arn.ok_or(
+                ::rusoto_core::BuildError::missing("resource_share_invitation_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AcceptResourceShareInvitationResponse {
@@ -67,6 +109,62 @@ pub struct AssociateResourceShareRequest {
     pub resource_share_arn: String,
 }
 
+impl AssociateResourceShareRequest {
+    /// Returns a builder for `AssociateResourceShareRequest`, checking its required members when built.
+    pub fn builder() -> AssociateResourceShareRequestBuilder {
+        AssociateResourceShareRequestBuilder::default()
+    }
+}
+
+/// Builds a [`AssociateResourceShareRequest`](struct.AssociateResourceShareRequest.html).
+#[derive(Default, Debug)]
+pub struct AssociateResourceShareRequestBuilder {
+    client_token: Option<String>,
+    principals: Option<Vec<String>>,
+    resource_arns: Option<Vec<String>>,
+    resource_share_arn: Option<String>,
+}
+
+impl AssociateResourceShareRequestBuilder {
+    /// Sets the [`client_token`](struct.AssociateResourceShareRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principals`](struct.AssociateResourceShareRequest.html#structfield.principals) member.
+    pub fn principals<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.principals = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arns`](struct.AssociateResourceShareRequest.html#structfield.resource_arns) member.
+    pub fn resource_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_arn`](struct.AssociateResourceShareRequest.html#structfield.resource_share_arn) member.
+    pub fn resource_share_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_share_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AssociateResourceShareRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AssociateResourceShareRequest, ::rusoto_core::BuildError> {
+        Ok(AssociateResourceShareRequest {
+            client_token: self.client_token,
+            principals: self.principals,
+            resource_arns: self.resource_arns,
+            resource_share_arn: self
+                .resource_share_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_share_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct AssociateResourceShareResponse {
@@ -107,6 +205,78 @@ pub struct CreateResourceShareRequest {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateResourceShareRequest {
+    /// Returns a builder for `CreateResourceShareRequest`, checking its required members when built.
+    pub fn builder() -> CreateResourceShareRequestBuilder {
+        CreateResourceShareRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateResourceShareRequest`](struct.CreateResourceShareRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateResourceShareRequestBuilder {
+    allow_external_principals: Option<bool>,
+    client_token: Option<String>,
+    name: Option<String>,
+    principals: Option<Vec<String>>,
+    resource_arns: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateResourceShareRequestBuilder {
+    /// Sets the [`allow_external_principals`](struct.CreateResourceShareRequest.html#structfield.allow_external_principals) member.
+    pub fn allow_external_principals<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_external_principals = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_token`](struct.CreateResourceShareRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateResourceShareRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principals`](struct.CreateResourceShareRequest.html#structfield.principals) member.
+    pub fn principals<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.principals = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arns`](struct.CreateResourceShareRequest.html#structfield.resource_arns) member.
+    pub fn resource_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateResourceShareRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateResourceShareRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateResourceShareRequest, ::rusoto_core::BuildError> {
+        Ok(CreateResourceShareRequest {
+            allow_external_principals: self.allow_external_principals,
+            client_token: self.client_token,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            principals: self.principals,
+            resource_arns: self.resource_arns,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateResourceShareResponse {
@@ -131,6 +301,46 @@ pub struct DeleteResourceShareRequest {
     pub resource_share_arn: String,
 }
 
+impl DeleteResourceShareRequest {
+    /// Returns a builder for `DeleteResourceShareRequest`, checking its required members when built.
+    pub fn builder() -> DeleteResourceShareRequestBuilder {
+        DeleteResourceShareRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteResourceShareRequest`](struct.DeleteResourceShareRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteResourceShareRequestBuilder {
+    client_token: Option<String>,
+    resource_share_arn: Option<String>,
+}
+
+impl DeleteResourceShareRequestBuilder {
+    /// Sets the [`client_token`](struct.DeleteResourceShareRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_arn`](struct.DeleteResourceShareRequest.html#structfield.resource_share_arn) member.
+    pub fn resource_share_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_share_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteResourceShareRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteResourceShareRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteResourceShareRequest {
+            client_token: self.client_token,
+            resource_share_arn: self
+                .resource_share_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_share_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteResourceShareResponse {
@@ -163,6 +373,62 @@ pub struct DisassociateResourceShareRequest {
     pub resource_share_arn: String,
 }
 
+impl DisassociateResourceShareRequest {
+    /// Returns a builder for `DisassociateResourceShareRequest`, checking its required members when built.
+    pub fn builder() -> DisassociateResourceShareRequestBuilder {
+        DisassociateResourceShareRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DisassociateResourceShareRequest`](struct.DisassociateResourceShareRequest.html).
+#[derive(Default, Debug)]
+pub struct DisassociateResourceShareRequestBuilder {
+    client_token: Option<String>,
+    principals: Option<Vec<String>>,
+    resource_arns: Option<Vec<String>>,
+    resource_share_arn: Option<String>,
+}
+
+impl DisassociateResourceShareRequestBuilder {
+    /// Sets the [`client_token`](struct.DisassociateResourceShareRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principals`](struct.DisassociateResourceShareRequest.html#structfield.principals) member.
+    pub fn principals<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.principals = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arns`](struct.DisassociateResourceShareRequest.html#structfield.resource_arns) member.
+    pub fn resource_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_arn`](struct.DisassociateResourceShareRequest.html#structfield.resource_share_arn) member.
+    pub fn resource_share_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_share_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisassociateResourceShareRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisassociateResourceShareRequest, ::rusoto_core::BuildError> {
+        Ok(DisassociateResourceShareRequest {
+            client_token: self.client_token,
+            principals: self.principals,
+            resource_arns: self.resource_arns,
+            resource_share_arn: self
+                .resource_share_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_share_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DisassociateResourceShareResponse {
@@ -207,6 +473,62 @@ pub struct GetResourcePoliciesRequest {
     pub resource_arns: Vec<String>,
 }
 
+impl GetResourcePoliciesRequest {
+    /// Returns a builder for `GetResourcePoliciesRequest`, checking its required members when built.
+    pub fn builder() -> GetResourcePoliciesRequestBuilder {
+        GetResourcePoliciesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourcePoliciesRequest`](struct.GetResourcePoliciesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResourcePoliciesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    principal: Option<String>,
+    resource_arns: Option<Vec<String>>,
+}
+
+impl GetResourcePoliciesRequestBuilder {
+    /// Sets the [`max_results`](struct.GetResourcePoliciesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetResourcePoliciesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.GetResourcePoliciesRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arns`](struct.GetResourcePoliciesRequest.html#structfield.resource_arns) member.
+    pub fn resource_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourcePoliciesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetResourcePoliciesRequest, ::rusoto_core::BuildError> {
+        Ok(GetResourcePoliciesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            principal: self.principal,
+            resource_arns: self
+                .resource_arns
+                .ok_or(::rusoto_core::BuildError::missing("resource_arns"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetResourcePoliciesResponse {
@@ -220,15 +542,15 @@ pub struct GetResourcePoliciesResponse {
     pub policies: Option<Vec<String>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetResourceShareAssociationsRequest {
     /// <p>The status of the association.</p>
     #[serde(rename = "associationStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_status: Option<String>,
+    pub association_status: Option<ResourceShareAssociationStatus>,
     /// <p>The association type.</p>
     #[serde(rename = "associationType")]
-    pub association_type: String,
+    pub association_type: ResourceShareAssociationType,
     /// <p>The maximum number of results to return with a single call. To retrieve the remaining results, make another call with the returned <code>nextToken</code> value.</p>
     #[serde(rename = "maxResults")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -251,6 +573,86 @@ pub struct GetResourceShareAssociationsRequest {
     pub resource_share_arns: Option<Vec<String>>,
 }
 
+impl GetResourceShareAssociationsRequest {
+    /// Returns a builder for `GetResourceShareAssociationsRequest`, checking its required members when built.
+    pub fn builder() -> GetResourceShareAssociationsRequestBuilder {
+        GetResourceShareAssociationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourceShareAssociationsRequest`](struct.GetResourceShareAssociationsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResourceShareAssociationsRequestBuilder {
+    association_status: Option<ResourceShareAssociationStatus>,
+    association_type: Option<ResourceShareAssociationType>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    principal: Option<String>,
+    resource_arn: Option<String>,
+    resource_share_arns: Option<Vec<String>>,
+}
+
+impl GetResourceShareAssociationsRequestBuilder {
+    /// Sets the [`association_status`](struct.GetResourceShareAssociationsRequest.html#structfield.association_status) member.
+    pub fn association_status<T: Into<ResourceShareAssociationStatus>>(mut self, value: T) -> Self {
+        self.association_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`association_type`](struct.GetResourceShareAssociationsRequest.html#structfield.association_type) member.
+    pub fn association_type<T: Into<ResourceShareAssociationType>>(mut self, value: T) -> Self {
+        self.association_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetResourceShareAssociationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetResourceShareAssociationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.GetResourceShareAssociationsRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.GetResourceShareAssociationsRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_arns`](struct.GetResourceShareAssociationsRequest.html#structfield.resource_share_arns) member.
+    pub fn resource_share_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_share_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourceShareAssociationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetResourceShareAssociationsRequest, ::rusoto_core::BuildError> {
+        Ok(GetResourceShareAssociationsRequest {
+            association_status: self.association_status,
+            association_type: self
+                .association_type
+                .ok_or(::rusoto_core::BuildError::missing("association_type"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            principal: self.principal,
+            resource_arn: self.resource_arn,
+            resource_share_arns: self.resource_share_arns,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetResourceShareAssociationsResponse {
@@ -284,6 +686,60 @@ pub struct GetResourceShareInvitationsRequest {
     pub resource_share_invitation_arns: Option<Vec<String>>,
 }
 
+impl GetResourceShareInvitationsRequest {
+    /// Returns a builder for `GetResourceShareInvitationsRequest`, checking its required members when built.
+    pub fn builder() -> GetResourceShareInvitationsRequestBuilder {
+        GetResourceShareInvitationsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourceShareInvitationsRequest`](struct.GetResourceShareInvitationsRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResourceShareInvitationsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_share_arns: Option<Vec<String>>,
+    resource_share_invitation_arns: Option<Vec<String>>,
+}
+
+impl GetResourceShareInvitationsRequestBuilder {
+    /// Sets the [`max_results`](struct.GetResourceShareInvitationsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetResourceShareInvitationsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_arns`](struct.GetResourceShareInvitationsRequest.html#structfield.resource_share_arns) member.
+    pub fn resource_share_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_share_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_invitation_arns`](struct.GetResourceShareInvitationsRequest.html#structfield.resource_share_invitation_arns) member.
+    pub fn resource_share_invitation_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_share_invitation_arns = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourceShareInvitationsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetResourceShareInvitationsRequest, ::rusoto_core::BuildError> {
+        Ok(GetResourceShareInvitationsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_share_arns: self.resource_share_arns,
+            resource_share_invitation_arns: self.resource_share_invitation_arns,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetResourceShareInvitationsResponse {
@@ -297,7 +753,7 @@ pub struct GetResourceShareInvitationsResponse {
     pub resource_share_invitations: Option<Vec<ResourceShareInvitation>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GetResourceSharesRequest {
     /// <p>The maximum number of results to return with a single call. To retrieve the remaining results, make another call with the returned <code>nextToken</code> value.</p>
     #[serde(rename = "maxResults")]
@@ -313,7 +769,7 @@ pub struct GetResourceSharesRequest {
     pub next_token: Option<String>,
     /// <p>The type of owner.</p>
     #[serde(rename = "resourceOwner")]
-    pub resource_owner: String,
+    pub resource_owner: ResourceOwner,
     /// <p>The Amazon Resource Names (ARN) of the resource shares.</p>
     #[serde(rename = "resourceShareArns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -321,13 +777,93 @@ pub struct GetResourceSharesRequest {
     /// <p>The status of the resource share.</p>
     #[serde(rename = "resourceShareStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_share_status: Option<String>,
+    pub resource_share_status: Option<ResourceShareStatus>,
     /// <p>One or more tag filters.</p>
     #[serde(rename = "tagFilters")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag_filters: Option<Vec<TagFilter>>,
 }
 
+impl GetResourceSharesRequest {
+    /// Returns a builder for `GetResourceSharesRequest`, checking its required members when built.
+    pub fn builder() -> GetResourceSharesRequestBuilder {
+        GetResourceSharesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourceSharesRequest`](struct.GetResourceSharesRequest.html).
+#[derive(Default, Debug)]
+pub struct GetResourceSharesRequestBuilder {
+    max_results: Option<i64>,
+    name: Option<String>,
+    next_token: Option<String>,
+    resource_owner: Option<ResourceOwner>,
+    resource_share_arns: Option<Vec<String>>,
+    resource_share_status: Option<ResourceShareStatus>,
+    tag_filters: Option<Vec<TagFilter>>,
+}
+
+impl GetResourceSharesRequestBuilder {
+    /// Sets the [`max_results`](struct.GetResourceSharesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.GetResourceSharesRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetResourceSharesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_owner`](struct.GetResourceSharesRequest.html#structfield.resource_owner) member.
+    pub fn resource_owner<T: Into<ResourceOwner>>(mut self, value: T) -> Self {
+        self.resource_owner = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_arns`](struct.GetResourceSharesRequest.html#structfield.resource_share_arns) member.
+    pub fn resource_share_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_share_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_status`](struct.GetResourceSharesRequest.html#structfield.resource_share_status) member.
+    pub fn resource_share_status<T: Into<ResourceShareStatus>>(mut self, value: T) -> Self {
+        self.resource_share_status = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_filters`](struct.GetResourceSharesRequest.html#structfield.tag_filters) member.
+    pub fn tag_filters<T: Into<Vec<TagFilter>>>(mut self, value: T) -> Self {
+        self.tag_filters = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourceSharesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetResourceSharesRequest, ::rusoto_core::BuildError> {
+        Ok(GetResourceSharesRequest {
+            max_results: self.max_results,
+            name: self.name,
+            next_token: self.next_token,
+            resource_owner: self
+                .resource_owner
+                .ok_or(::rusoto_core::BuildError::missing("resource_owner"))?,
+            resource_share_arns: self.resource_share_arns,
+            resource_share_status: self.resource_share_status,
+            tag_filters: self.tag_filters,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetResourceSharesResponse {
@@ -341,7 +877,7 @@ pub struct GetResourceSharesResponse {
     pub resource_shares: Option<Vec<ResourceShare>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListPrincipalsRequest {
     /// <p>The maximum number of results to return with a single call. To retrieve the remaining results, make another call with the returned <code>nextToken</code> value.</p>
     #[serde(rename = "maxResults")]
@@ -361,7 +897,7 @@ pub struct ListPrincipalsRequest {
     pub resource_arn: Option<String>,
     /// <p>The type of owner.</p>
     #[serde(rename = "resourceOwner")]
-    pub resource_owner: String,
+    pub resource_owner: ResourceOwner,
     /// <p>The Amazon Resource Names (ARN) of the resource shares.</p>
     #[serde(rename = "resourceShareArns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -372,6 +908,84 @@ pub struct ListPrincipalsRequest {
     pub resource_type: Option<String>,
 }
 
+impl ListPrincipalsRequest {
+    /// Returns a builder for `ListPrincipalsRequest`, checking its required members when built.
+    pub fn builder() -> ListPrincipalsRequestBuilder {
+        ListPrincipalsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListPrincipalsRequest`](struct.ListPrincipalsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListPrincipalsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    principals: Option<Vec<String>>,
+    resource_arn: Option<String>,
+    resource_owner: Option<ResourceOwner>,
+    resource_share_arns: Option<Vec<String>>,
+    resource_type: Option<String>,
+}
+
+impl ListPrincipalsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListPrincipalsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListPrincipalsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principals`](struct.ListPrincipalsRequest.html#structfield.principals) member.
+    pub fn principals<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.principals = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ListPrincipalsRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_owner`](struct.ListPrincipalsRequest.html#structfield.resource_owner) member.
+    pub fn resource_owner<T: Into<ResourceOwner>>(mut self, value: T) -> Self {
+        self.resource_owner = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_arns`](struct.ListPrincipalsRequest.html#structfield.resource_share_arns) member.
+    pub fn resource_share_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_share_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.ListPrincipalsRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListPrincipalsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListPrincipalsRequest, ::rusoto_core::BuildError> {
+        Ok(ListPrincipalsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            principals: self.principals,
+            resource_arn: self.resource_arn,
+            resource_owner: self
+                .resource_owner
+                .ok_or(::rusoto_core::BuildError::missing("resource_owner"))?,
+            resource_share_arns: self.resource_share_arns,
+            resource_type: self.resource_type,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListPrincipalsResponse {
@@ -385,7 +999,7 @@ pub struct ListPrincipalsResponse {
     pub principals: Option<Vec<Principal>>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ListResourcesRequest {
     /// <p>The maximum number of results to return with a single call. To retrieve the remaining results, make another call with the returned <code>nextToken</code> value.</p>
     #[serde(rename = "maxResults")]
@@ -405,7 +1019,7 @@ pub struct ListResourcesRequest {
     pub resource_arns: Option<Vec<String>>,
     /// <p>The type of owner.</p>
     #[serde(rename = "resourceOwner")]
-    pub resource_owner: String,
+    pub resource_owner: ResourceOwner,
     /// <p>The Amazon Resource Names (ARN) of the resource shares.</p>
     #[serde(rename = "resourceShareArns")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -416,6 +1030,84 @@ pub struct ListResourcesRequest {
     pub resource_type: Option<String>,
 }
 
+impl ListResourcesRequest {
+    /// Returns a builder for `ListResourcesRequest`, checking its required members when built.
+    pub fn builder() -> ListResourcesRequestBuilder {
+        ListResourcesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListResourcesRequest`](struct.ListResourcesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListResourcesRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    principal: Option<String>,
+    resource_arns: Option<Vec<String>>,
+    resource_owner: Option<ResourceOwner>,
+    resource_share_arns: Option<Vec<String>>,
+    resource_type: Option<String>,
+}
+
+impl ListResourcesRequestBuilder {
+    /// Sets the [`max_results`](struct.ListResourcesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListResourcesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`principal`](struct.ListResourcesRequest.html#structfield.principal) member.
+    pub fn principal<T: Into<String>>(mut self, value: T) -> Self {
+        self.principal = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arns`](struct.ListResourcesRequest.html#structfield.resource_arns) member.
+    pub fn resource_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_owner`](struct.ListResourcesRequest.html#structfield.resource_owner) member.
+    pub fn resource_owner<T: Into<ResourceOwner>>(mut self, value: T) -> Self {
+        self.resource_owner = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_arns`](struct.ListResourcesRequest.html#structfield.resource_share_arns) member.
+    pub fn resource_share_arns<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_share_arns = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.ListResourcesRequest.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListResourcesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListResourcesRequest, ::rusoto_core::BuildError> {
+        Ok(ListResourcesRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            principal: self.principal,
+            resource_arns: self.resource_arns,
+            resource_owner: self
+                .resource_owner
+                .ok_or(::rusoto_core::BuildError::missing("resource_owner"))?,
+            resource_share_arns: self.resource_share_arns,
+            resource_type: self.resource_type,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListResourcesResponse {
@@ -435,8 +1127,9 @@ pub struct ListResourcesResponse {
 pub struct Principal {
     /// <p>The time when the principal was associated with the resource share.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>Indicates whether the principal belongs to the same organization as the AWS account that owns the resource share.</p>
     #[serde(rename = "external")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -447,8 +1140,9 @@ pub struct Principal {
     pub id: Option<String>,
     /// <p>The time when the association was last updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<EpochTimestamp>,
     /// <p>The Amazon Resource Name (ARN) of the resource share.</p>
     #[serde(rename = "resourceShareArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -466,6 +1160,47 @@ pub struct RejectResourceShareInvitationRequest {
     pub resource_share_invitation_arn: String,
 }
 
+impl RejectResourceShareInvitationRequest {
+    /// Returns a builder for `RejectResourceShareInvitationRequest`, checking its required members when built.
+    pub fn builder() -> RejectResourceShareInvitationRequestBuilder {
+        RejectResourceShareInvitationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RejectResourceShareInvitationRequest`](struct.RejectResourceShareInvitationRequest.html).
+#[derive(Default, Debug)]
+pub struct RejectResourceShareInvitationRequestBuilder {
+    client_token: Option<String>,
+    resource_share_invitation_arn: Option<String>,
+}
+
+impl RejectResourceShareInvitationRequestBuilder {
+    /// Sets the [`client_token`](struct.RejectResourceShareInvitationRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_invitation_arn`](struct.RejectResourceShareInvitationRequest.html#structfield.resource_share_invitation_arn) member.
+    pub fn resource_share_invitation_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_share_invitation_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RejectResourceShareInvitationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RejectResourceShareInvitationRequest, ::rusoto_core::BuildError>
+    {
+        Ok(RejectResourceShareInvitationRequest {
+            client_token: self.client_token,
+            resource_share_invitation_arn: self.resource_share_invitation_arn.ok_or(
+                ::rusoto_core::BuildError::missing("resource_share_invitation_arn"),
+            )?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RejectResourceShareInvitationResponse {
@@ -489,12 +1224,14 @@ pub struct Resource {
     pub arn: Option<String>,
     /// <p>The time when the resource was associated with the resource share.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The time when the association was last updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<EpochTimestamp>,
     /// <p>The Amazon Resource Name (ARN) of the resource share.</p>
     #[serde(rename = "resourceShareArn")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -502,7 +1239,7 @@ pub struct Resource {
     /// <p>The status of the resource.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResourceStatus>,
     /// <p>A message about the status of the resource.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -513,6 +1250,84 @@ pub struct Resource {
     pub type_: Option<String>,
 }
 
+/// <p>The type of owner.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceOwner {
+    /// <p><code>SELF</code></p>
+    SelfValue,
+    /// <p><code>OTHER-ACCOUNTS</code></p>
+    OtherAccounts,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceOwner {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceOwner::SelfValue => "SELF",
+            ResourceOwner::OtherAccounts => "OTHER-ACCOUNTS",
+            ResourceOwner::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceOwner {
+    fn from(value: &'a str) -> ResourceOwner {
+        match value {
+            "SELF" => ResourceOwner::SelfValue,
+            "OTHER-ACCOUNTS" => ResourceOwner::OtherAccounts,
+            _ => ResourceOwner::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceOwner {
+    fn from(value: String) -> ResourceOwner {
+        ResourceOwner::from(value.as_str())
+    }
+}
+
+impl From<ResourceOwner> for String {
+    fn from(value: ResourceOwner) -> String {
+        match value {
+            ResourceOwner::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceOwner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceOwner {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceOwner {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceOwner {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceOwner, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceOwner::from(value))
+    }
+}
+
 /// <p>Describes a resource share.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -523,12 +1338,14 @@ pub struct ResourceShare {
     pub allow_external_principals: Option<bool>,
     /// <p>The time when the resource share was created.</p>
     #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
+    pub creation_time: Option<EpochTimestamp>,
     /// <p>The time when the resource share was last updated.</p>
     #[serde(rename = "lastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
+    pub last_updated_time: Option<EpochTimestamp>,
     /// <p>The name of the resource share.</p>
     #[serde(rename = "name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -544,7 +1361,7 @@ pub struct ResourceShare {
     /// <p>The status of the resource share.</p>
     #[serde(rename = "status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<ResourceShareStatus>,
     /// <p>A message about the status of the resource share.</p>
     #[serde(rename = "statusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -555,80 +1372,523 @@ pub struct ResourceShare {
     pub tags: Option<Vec<Tag>>,
 }
 
-/// <p>Describes an association with a resource share.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ResourceShareAssociation {
-    /// <p>The associated entity. For resource associations, this is the ARN of the resource. For principal associations, this is the ID of an AWS account or the ARN of an OU or organization from AWS Organizations.</p>
-    #[serde(rename = "associatedEntity")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub associated_entity: Option<String>,
-    /// <p>The association type.</p>
-    #[serde(rename = "associationType")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_type: Option<String>,
-    /// <p>The time when the association was created.</p>
-    #[serde(rename = "creationTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_time: Option<f64>,
-    /// <p>Indicates whether the principal belongs to the same organization as the AWS account that owns the resource share.</p>
-    #[serde(rename = "external")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub external: Option<bool>,
-    /// <p>The time when the association was last updated.</p>
-    #[serde(rename = "lastUpdatedTime")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_updated_time: Option<f64>,
-    /// <p>The Amazon Resource Name (ARN) of the resource share.</p>
-    #[serde(rename = "resourceShareArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_share_arn: Option<String>,
-    /// <p>The status of the association.</p>
-    #[serde(rename = "status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
-    /// <p>A message about the status of the association.</p>
-    #[serde(rename = "statusMessage")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status_message: Option<String>,
+/// <p>Describes an association with a resource share.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ResourceShareAssociation {
+    /// <p>The associated entity. For resource associations, this is the ARN of the resource. For principal associations, this is the ID of an AWS account or the ARN of an OU or organization from AWS Organizations.</p>
+    #[serde(rename = "associatedEntity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub associated_entity: Option<String>,
+    /// <p>The association type.</p>
+    #[serde(rename = "associationType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub association_type: Option<ResourceShareAssociationType>,
+    /// <p>The time when the association was created.</p>
+    #[serde(rename = "creationTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creation_time: Option<EpochTimestamp>,
+    /// <p>Indicates whether the principal belongs to the same organization as the AWS account that owns the resource share.</p>
+    #[serde(rename = "external")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external: Option<bool>,
+    /// <p>The time when the association was last updated.</p>
+    #[serde(rename = "lastUpdatedTime")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_updated_time: Option<EpochTimestamp>,
+    /// <p>The Amazon Resource Name (ARN) of the resource share.</p>
+    #[serde(rename = "resourceShareArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_share_arn: Option<String>,
+    /// <p>The status of the association.</p>
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ResourceShareAssociationStatus>,
+    /// <p>A message about the status of the association.</p>
+    #[serde(rename = "statusMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_message: Option<String>,
+}
+
+/// <p>The status of the association.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceShareAssociationStatus {
+    /// <p><code>ASSOCIATING</code></p>
+    Associating,
+    /// <p><code>ASSOCIATED</code></p>
+    Associated,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>DISASSOCIATING</code></p>
+    Disassociating,
+    /// <p><code>DISASSOCIATED</code></p>
+    Disassociated,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceShareAssociationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceShareAssociationStatus::Associating => "ASSOCIATING",
+            ResourceShareAssociationStatus::Associated => "ASSOCIATED",
+            ResourceShareAssociationStatus::Failed => "FAILED",
+            ResourceShareAssociationStatus::Disassociating => "DISASSOCIATING",
+            ResourceShareAssociationStatus::Disassociated => "DISASSOCIATED",
+            ResourceShareAssociationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceShareAssociationStatus {
+    fn from(value: &'a str) -> ResourceShareAssociationStatus {
+        match value {
+            "ASSOCIATING" => ResourceShareAssociationStatus::Associating,
+            "ASSOCIATED" => ResourceShareAssociationStatus::Associated,
+            "FAILED" => ResourceShareAssociationStatus::Failed,
+            "DISASSOCIATING" => ResourceShareAssociationStatus::Disassociating,
+            "DISASSOCIATED" => ResourceShareAssociationStatus::Disassociated,
+            _ => ResourceShareAssociationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceShareAssociationStatus {
+    fn from(value: String) -> ResourceShareAssociationStatus {
+        ResourceShareAssociationStatus::from(value.as_str())
+    }
+}
+
+impl From<ResourceShareAssociationStatus> for String {
+    fn from(value: ResourceShareAssociationStatus) -> String {
+        match value {
+            ResourceShareAssociationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceShareAssociationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceShareAssociationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceShareAssociationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceShareAssociationStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ResourceShareAssociationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceShareAssociationStatus::from(value))
+    }
+}
+
+/// <p>The association type.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceShareAssociationType {
+    /// <p><code>PRINCIPAL</code></p>
+    Principal,
+    /// <p><code>RESOURCE</code></p>
+    Resource,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceShareAssociationType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceShareAssociationType::Principal => "PRINCIPAL",
+            ResourceShareAssociationType::Resource => "RESOURCE",
+            ResourceShareAssociationType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceShareAssociationType {
+    fn from(value: &'a str) -> ResourceShareAssociationType {
+        match value {
+            "PRINCIPAL" => ResourceShareAssociationType::Principal,
+            "RESOURCE" => ResourceShareAssociationType::Resource,
+            _ => ResourceShareAssociationType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceShareAssociationType {
+    fn from(value: String) -> ResourceShareAssociationType {
+        ResourceShareAssociationType::from(value.as_str())
+    }
+}
+
+impl From<ResourceShareAssociationType> for String {
+    fn from(value: ResourceShareAssociationType) -> String {
+        match value {
+            ResourceShareAssociationType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceShareAssociationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceShareAssociationType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceShareAssociationType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceShareAssociationType {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ResourceShareAssociationType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceShareAssociationType::from(value))
+    }
+}
+
+/// <p>Describes an invitation to join a resource share.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct ResourceShareInvitation {
+    /// <p>The date and time when the invitation was sent.</p>
+    #[serde(rename = "invitationTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitation_timestamp: Option<EpochTimestamp>,
+    /// <p>The ID of the AWS account that received the invitation.</p>
+    #[serde(rename = "receiverAccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver_account_id: Option<String>,
+    /// <p>The Amazon Resource Name (ARN) of the resource share.</p>
+    #[serde(rename = "resourceShareArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_share_arn: Option<String>,
+    /// <p>The resources associated with the resource share.</p>
+    #[serde(rename = "resourceShareAssociations")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_share_associations: Option<Vec<ResourceShareAssociation>>,
+    /// <p>The Amazon Resource Name (ARN) of the invitation.</p>
+    #[serde(rename = "resourceShareInvitationArn")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_share_invitation_arn: Option<String>,
+    /// <p>The name of the resource share.</p>
+    #[serde(rename = "resourceShareName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_share_name: Option<String>,
+    /// <p>The ID of the AWS account that sent the invitation.</p>
+    #[serde(rename = "senderAccountId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender_account_id: Option<String>,
+    /// <p>The status of the invitation.</p>
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ResourceShareInvitationStatus>,
+}
+
+/// <p>The status of the invitation.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceShareInvitationStatus {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>ACCEPTED</code></p>
+    Accepted,
+    /// <p><code>REJECTED</code></p>
+    Rejected,
+    /// <p><code>EXPIRED</code></p>
+    Expired,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceShareInvitationStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceShareInvitationStatus::Pending => "PENDING",
+            ResourceShareInvitationStatus::Accepted => "ACCEPTED",
+            ResourceShareInvitationStatus::Rejected => "REJECTED",
+            ResourceShareInvitationStatus::Expired => "EXPIRED",
+            ResourceShareInvitationStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceShareInvitationStatus {
+    fn from(value: &'a str) -> ResourceShareInvitationStatus {
+        match value {
+            "PENDING" => ResourceShareInvitationStatus::Pending,
+            "ACCEPTED" => ResourceShareInvitationStatus::Accepted,
+            "REJECTED" => ResourceShareInvitationStatus::Rejected,
+            "EXPIRED" => ResourceShareInvitationStatus::Expired,
+            _ => ResourceShareInvitationStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceShareInvitationStatus {
+    fn from(value: String) -> ResourceShareInvitationStatus {
+        ResourceShareInvitationStatus::from(value.as_str())
+    }
+}
+
+impl From<ResourceShareInvitationStatus> for String {
+    fn from(value: ResourceShareInvitationStatus) -> String {
+        match value {
+            ResourceShareInvitationStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceShareInvitationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceShareInvitationStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceShareInvitationStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceShareInvitationStatus {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<ResourceShareInvitationStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceShareInvitationStatus::from(value))
+    }
+}
+
+/// <p>The status of the resource share.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceShareStatus {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>DELETING</code></p>
+    Deleting,
+    /// <p><code>DELETED</code></p>
+    Deleted,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceShareStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceShareStatus::Pending => "PENDING",
+            ResourceShareStatus::Active => "ACTIVE",
+            ResourceShareStatus::Failed => "FAILED",
+            ResourceShareStatus::Deleting => "DELETING",
+            ResourceShareStatus::Deleted => "DELETED",
+            ResourceShareStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceShareStatus {
+    fn from(value: &'a str) -> ResourceShareStatus {
+        match value {
+            "PENDING" => ResourceShareStatus::Pending,
+            "ACTIVE" => ResourceShareStatus::Active,
+            "FAILED" => ResourceShareStatus::Failed,
+            "DELETING" => ResourceShareStatus::Deleting,
+            "DELETED" => ResourceShareStatus::Deleted,
+            _ => ResourceShareStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceShareStatus {
+    fn from(value: String) -> ResourceShareStatus {
+        ResourceShareStatus::from(value.as_str())
+    }
+}
+
+impl From<ResourceShareStatus> for String {
+    fn from(value: ResourceShareStatus) -> String {
+        match value {
+            ResourceShareStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceShareStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceShareStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceShareStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceShareStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceShareStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceShareStatus::from(value))
+    }
 }
 
-/// <p>Describes an invitation to join a resource share.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct ResourceShareInvitation {
-    /// <p>The date and time when the invitation was sent.</p>
-    #[serde(rename = "invitationTimestamp")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub invitation_timestamp: Option<f64>,
-    /// <p>The ID of the AWS account that received the invitation.</p>
-    #[serde(rename = "receiverAccountId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub receiver_account_id: Option<String>,
-    /// <p>The Amazon Resource Name (ARN) of the resource share.</p>
-    #[serde(rename = "resourceShareArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_share_arn: Option<String>,
-    /// <p>The resources associated with the resource share.</p>
-    #[serde(rename = "resourceShareAssociations")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_share_associations: Option<Vec<ResourceShareAssociation>>,
-    /// <p>The Amazon Resource Name (ARN) of the invitation.</p>
-    #[serde(rename = "resourceShareInvitationArn")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_share_invitation_arn: Option<String>,
-    /// <p>The name of the resource share.</p>
-    #[serde(rename = "resourceShareName")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub resource_share_name: Option<String>,
-    /// <p>The ID of the AWS account that sent the invitation.</p>
-    #[serde(rename = "senderAccountId")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sender_account_id: Option<String>,
-    /// <p>The status of the invitation.</p>
-    #[serde(rename = "status")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+/// <p>The status of the resource.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceStatus {
+    /// <p><code>AVAILABLE</code></p>
+    Available,
+    /// <p><code>ZONAL_RESOURCE_INACCESSIBLE</code></p>
+    ZonalResourceInaccessible,
+    /// <p><code>LIMIT_EXCEEDED</code></p>
+    LimitExceeded,
+    /// <p><code>UNAVAILABLE</code></p>
+    Unavailable,
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceStatus::Available => "AVAILABLE",
+            ResourceStatus::ZonalResourceInaccessible => "ZONAL_RESOURCE_INACCESSIBLE",
+            ResourceStatus::LimitExceeded => "LIMIT_EXCEEDED",
+            ResourceStatus::Unavailable => "UNAVAILABLE",
+            ResourceStatus::Pending => "PENDING",
+            ResourceStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceStatus {
+    fn from(value: &'a str) -> ResourceStatus {
+        match value {
+            "AVAILABLE" => ResourceStatus::Available,
+            "ZONAL_RESOURCE_INACCESSIBLE" => ResourceStatus::ZonalResourceInaccessible,
+            "LIMIT_EXCEEDED" => ResourceStatus::LimitExceeded,
+            "UNAVAILABLE" => ResourceStatus::Unavailable,
+            "PENDING" => ResourceStatus::Pending,
+            _ => ResourceStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceStatus {
+    fn from(value: String) -> ResourceStatus {
+        ResourceStatus::from(value.as_str())
+    }
+}
+
+impl From<ResourceStatus> for String {
+    fn from(value: ResourceStatus) -> String {
+        match value {
+            ResourceStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceStatus::from(value))
+    }
 }
 
 /// <p>Information about a tag.</p>
@@ -667,6 +1927,46 @@ pub struct TagResourceRequest {
     pub tags: Vec<Tag>,
 }
 
+impl TagResourceRequest {
+    /// Returns a builder for `TagResourceRequest`, checking its required members when built.
+    pub fn builder() -> TagResourceRequestBuilder {
+        TagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourceRequest`](struct.TagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct TagResourceRequestBuilder {
+    resource_share_arn: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl TagResourceRequestBuilder {
+    /// Sets the [`resource_share_arn`](struct.TagResourceRequest.html#structfield.resource_share_arn) member.
+    pub fn resource_share_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_share_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourceRequest.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(TagResourceRequest {
+            resource_share_arn: self
+                .resource_share_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_share_arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourceResponse {}
@@ -681,6 +1981,46 @@ pub struct UntagResourceRequest {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourceRequest {
+    /// Returns a builder for `UntagResourceRequest`, checking its required members when built.
+    pub fn builder() -> UntagResourceRequestBuilder {
+        UntagResourceRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourceRequest`](struct.UntagResourceRequest.html).
+#[derive(Default, Debug)]
+pub struct UntagResourceRequestBuilder {
+    resource_share_arn: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourceRequestBuilder {
+    /// Sets the [`resource_share_arn`](struct.UntagResourceRequest.html#structfield.resource_share_arn) member.
+    pub fn resource_share_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_share_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourceRequest.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourceRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourceRequest, ::rusoto_core::BuildError> {
+        Ok(UntagResourceRequest {
+            resource_share_arn: self
+                .resource_share_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_share_arn"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourceResponse {}
@@ -704,6 +2044,62 @@ pub struct UpdateResourceShareRequest {
     pub resource_share_arn: String,
 }
 
+impl UpdateResourceShareRequest {
+    /// Returns a builder for `UpdateResourceShareRequest`, checking its required members when built.
+    pub fn builder() -> UpdateResourceShareRequestBuilder {
+        UpdateResourceShareRequestBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateResourceShareRequest`](struct.UpdateResourceShareRequest.html).
+#[derive(Default, Debug)]
+pub struct UpdateResourceShareRequestBuilder {
+    allow_external_principals: Option<bool>,
+    client_token: Option<String>,
+    name: Option<String>,
+    resource_share_arn: Option<String>,
+}
+
+impl UpdateResourceShareRequestBuilder {
+    /// Sets the [`allow_external_principals`](struct.UpdateResourceShareRequest.html#structfield.allow_external_principals) member.
+    pub fn allow_external_principals<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_external_principals = Some(value.into());
+        self
+    }
+
+    /// Sets the [`client_token`](struct.UpdateResourceShareRequest.html#structfield.client_token) member.
+    pub fn client_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.UpdateResourceShareRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_share_arn`](struct.UpdateResourceShareRequest.html#structfield.resource_share_arn) member.
+    pub fn resource_share_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_share_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateResourceShareRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<UpdateResourceShareRequest, ::rusoto_core::BuildError> {
+        Ok(UpdateResourceShareRequest {
+            allow_external_principals: self.allow_external_principals,
+            client_token: self.client_token,
+            name: self.name,
+            resource_share_arn: self
+                .resource_share_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_share_arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateResourceShareResponse {
@@ -2118,6 +3514,113 @@ pub trait Ram {
         input: UpdateResourceShareRequest,
     ) -> RusotoFuture<UpdateResourceShareResponse, UpdateResourceShareError>;
 }
+impl<T: ?Sized + Ram> Ram for ::std::sync::Arc<T> {
+    fn accept_resource_share_invitation(
+        &self,
+        input: AcceptResourceShareInvitationRequest,
+    ) -> RusotoFuture<AcceptResourceShareInvitationResponse, AcceptResourceShareInvitationError>
+    {
+        (**self).accept_resource_share_invitation(input)
+    }
+    fn associate_resource_share(
+        &self,
+        input: AssociateResourceShareRequest,
+    ) -> RusotoFuture<AssociateResourceShareResponse, AssociateResourceShareError> {
+        (**self).associate_resource_share(input)
+    }
+    fn create_resource_share(
+        &self,
+        input: CreateResourceShareRequest,
+    ) -> RusotoFuture<CreateResourceShareResponse, CreateResourceShareError> {
+        (**self).create_resource_share(input)
+    }
+    fn delete_resource_share(
+        &self,
+        input: DeleteResourceShareRequest,
+    ) -> RusotoFuture<DeleteResourceShareResponse, DeleteResourceShareError> {
+        (**self).delete_resource_share(input)
+    }
+    fn disassociate_resource_share(
+        &self,
+        input: DisassociateResourceShareRequest,
+    ) -> RusotoFuture<DisassociateResourceShareResponse, DisassociateResourceShareError> {
+        (**self).disassociate_resource_share(input)
+    }
+    fn enable_sharing_with_aws_organization(
+        &self,
+    ) -> RusotoFuture<EnableSharingWithAwsOrganizationResponse, EnableSharingWithAwsOrganizationError>
+    {
+        (**self).enable_sharing_with_aws_organization()
+    }
+    fn get_resource_policies(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> RusotoFuture<GetResourcePoliciesResponse, GetResourcePoliciesError> {
+        (**self).get_resource_policies(input)
+    }
+    fn get_resource_share_associations(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> RusotoFuture<GetResourceShareAssociationsResponse, GetResourceShareAssociationsError> {
+        (**self).get_resource_share_associations(input)
+    }
+    fn get_resource_share_invitations(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> RusotoFuture<GetResourceShareInvitationsResponse, GetResourceShareInvitationsError> {
+        (**self).get_resource_share_invitations(input)
+    }
+    fn get_resource_shares(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> RusotoFuture<GetResourceSharesResponse, GetResourceSharesError> {
+        (**self).get_resource_shares(input)
+    }
+    fn list_principals(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> RusotoFuture<ListPrincipalsResponse, ListPrincipalsError> {
+        (**self).list_principals(input)
+    }
+    fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> RusotoFuture<ListResourcesResponse, ListResourcesError> {
+        (**self).list_resources(input)
+    }
+    fn reject_resource_share_invitation(
+        &self,
+        input: RejectResourceShareInvitationRequest,
+    ) -> RusotoFuture<RejectResourceShareInvitationResponse, RejectResourceShareInvitationError>
+    {
+        (**self).reject_resource_share_invitation(input)
+    }
+    fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> RusotoFuture<TagResourceResponse, TagResourceError> {
+        (**self).tag_resource(input)
+    }
+    fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> RusotoFuture<UntagResourceResponse, UntagResourceError> {
+        (**self).untag_resource(input)
+    }
+    fn update_resource_share(
+        &self,
+        input: UpdateResourceShareRequest,
+    ) -> RusotoFuture<UpdateResourceShareResponse, UpdateResourceShareError> {
+        (**self).update_resource_share(input)
+    }
+}
+/// The error codes RAM retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the RAM API.
 #[derive(Clone)]
 pub struct RamClient {
@@ -2133,6 +3636,24 @@ impl RamClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_RAM`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> RamClient {
+        Self::new(region::Region::from_env_for_service("RAM"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `ram`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> RamClient {
+        Self::new_with_client(config.client().clone(), config.region_for("ram", "RAM"))
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2151,7 +3672,49 @@ impl RamClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> RamClient {
-        RamClient { client, region }
+        RamClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "ram")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "ram", "ram", "RAM")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Ram` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Ram + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for RamClient {
+    fn service_name(&self) -> &'static str {
+        "RAM"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "ram"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> RamClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>The request parameters represent the input of a SQL statement over an array of
 /// data.</p>
@@ -60,6 +61,88 @@ pub struct BatchExecuteStatementRequest {
     pub transaction_id: Option<String>,
 }
 
+impl BatchExecuteStatementRequest {
+    /// Returns a builder for `BatchExecuteStatementRequest`, checking its required members when built.
+    pub fn builder() -> BatchExecuteStatementRequestBuilder {
+        BatchExecuteStatementRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchExecuteStatementRequest`](struct.BatchExecuteStatementRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchExecuteStatementRequestBuilder {
+    database: Option<String>,
+    parameter_sets: Option<Vec<Vec<SqlParameter>>>,
+    resource_arn: Option<String>,
+    schema: Option<String>,
+    secret_arn: Option<String>,
+    sql: Option<String>,
+    transaction_id: Option<String>,
+}
+
+impl BatchExecuteStatementRequestBuilder {
+    /// Sets the [`database`](struct.BatchExecuteStatementRequest.html#structfield.database) member.
+    pub fn database<T: Into<String>>(mut self, value: T) -> Self {
+        self.database = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_sets`](struct.BatchExecuteStatementRequest.html#structfield.parameter_sets) member.
+    pub fn parameter_sets<T: Into<Vec<Vec<SqlParameter>>>>(mut self, value: T) -> Self {
+        self.parameter_sets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.BatchExecuteStatementRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema`](struct.BatchExecuteStatementRequest.html#structfield.schema) member.
+    pub fn schema<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_arn`](struct.BatchExecuteStatementRequest.html#structfield.secret_arn) member.
+    pub fn secret_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sql`](struct.BatchExecuteStatementRequest.html#structfield.sql) member.
+    pub fn sql<T: Into<String>>(mut self, value: T) -> Self {
+        self.sql = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transaction_id`](struct.BatchExecuteStatementRequest.html#structfield.transaction_id) member.
+    pub fn transaction_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transaction_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchExecuteStatementRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchExecuteStatementRequest, ::rusoto_core::BuildError> {
+        Ok(BatchExecuteStatementRequest {
+            database: self.database,
+            parameter_sets: self.parameter_sets,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            schema: self.schema,
+            secret_arn: self
+                .secret_arn
+                .ok_or(::rusoto_core::BuildError::missing("secret_arn"))?,
+            sql: self.sql.ok_or(::rusoto_core::BuildError::missing("sql"))?,
+            transaction_id: self.transaction_id,
+        })
+    }
+}
+
 /// <p>The response elements represent the output of a SQL statement over an array of
 /// data.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -91,6 +174,64 @@ pub struct BeginTransactionRequest {
     pub secret_arn: String,
 }
 
+impl BeginTransactionRequest {
+    /// Returns a builder for `BeginTransactionRequest`, checking its required members when built.
+    pub fn builder() -> BeginTransactionRequestBuilder {
+        BeginTransactionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BeginTransactionRequest`](struct.BeginTransactionRequest.html).
+#[derive(Default, Debug)]
+pub struct BeginTransactionRequestBuilder {
+    database: Option<String>,
+    resource_arn: Option<String>,
+    schema: Option<String>,
+    secret_arn: Option<String>,
+}
+
+impl BeginTransactionRequestBuilder {
+    /// Sets the [`database`](struct.BeginTransactionRequest.html#structfield.database) member.
+    pub fn database<T: Into<String>>(mut self, value: T) -> Self {
+        self.database = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.BeginTransactionRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema`](struct.BeginTransactionRequest.html#structfield.schema) member.
+    pub fn schema<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_arn`](struct.BeginTransactionRequest.html#structfield.secret_arn) member.
+    pub fn secret_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `BeginTransactionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BeginTransactionRequest, ::rusoto_core::BuildError> {
+        Ok(BeginTransactionRequest {
+            database: self.database,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            schema: self.schema,
+            secret_arn: self
+                .secret_arn
+                .ok_or(::rusoto_core::BuildError::missing("secret_arn"))?,
+        })
+    }
+}
+
 /// <p>The response elements represent the output of a request to start a SQL
 /// transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -178,6 +319,58 @@ pub struct CommitTransactionRequest {
     pub transaction_id: String,
 }
 
+impl CommitTransactionRequest {
+    /// Returns a builder for `CommitTransactionRequest`, checking its required members when built.
+    pub fn builder() -> CommitTransactionRequestBuilder {
+        CommitTransactionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CommitTransactionRequest`](struct.CommitTransactionRequest.html).
+#[derive(Default, Debug)]
+pub struct CommitTransactionRequestBuilder {
+    resource_arn: Option<String>,
+    secret_arn: Option<String>,
+    transaction_id: Option<String>,
+}
+
+impl CommitTransactionRequestBuilder {
+    /// Sets the [`resource_arn`](struct.CommitTransactionRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_arn`](struct.CommitTransactionRequest.html#structfield.secret_arn) member.
+    pub fn secret_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transaction_id`](struct.CommitTransactionRequest.html#structfield.transaction_id) member.
+    pub fn transaction_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transaction_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CommitTransactionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CommitTransactionRequest, ::rusoto_core::BuildError> {
+        Ok(CommitTransactionRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            secret_arn: self
+                .secret_arn
+                .ok_or(::rusoto_core::BuildError::missing("secret_arn"))?,
+            transaction_id: self
+                .transaction_id
+                .ok_or(::rusoto_core::BuildError::missing("transaction_id"))?,
+        })
+    }
+}
+
 /// <p>The response elements represent the output of a commit transaction request.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -216,6 +409,72 @@ pub struct ExecuteSqlRequest {
     pub sql_statements: String,
 }
 
+impl ExecuteSqlRequest {
+    /// Returns a builder for `ExecuteSqlRequest`, checking its required members when built.
+    pub fn builder() -> ExecuteSqlRequestBuilder {
+        ExecuteSqlRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ExecuteSqlRequest`](struct.ExecuteSqlRequest.html).
+#[derive(Default, Debug)]
+pub struct ExecuteSqlRequestBuilder {
+    aws_secret_store_arn: Option<String>,
+    database: Option<String>,
+    db_cluster_or_instance_arn: Option<String>,
+    schema: Option<String>,
+    sql_statements: Option<String>,
+}
+
+impl ExecuteSqlRequestBuilder {
+    /// Sets the [`aws_secret_store_arn`](struct.ExecuteSqlRequest.html#structfield.aws_secret_store_arn) member.
+    pub fn aws_secret_store_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.aws_secret_store_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database`](struct.ExecuteSqlRequest.html#structfield.database) member.
+    pub fn database<T: Into<String>>(mut self, value: T) -> Self {
+        self.database = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_or_instance_arn`](struct.ExecuteSqlRequest.html#structfield.db_cluster_or_instance_arn) member.
+    pub fn db_cluster_or_instance_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_or_instance_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema`](struct.ExecuteSqlRequest.html#structfield.schema) member.
+    pub fn schema<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sql_statements`](struct.ExecuteSqlRequest.html#structfield.sql_statements) member.
+    pub fn sql_statements<T: Into<String>>(mut self, value: T) -> Self {
+        self.sql_statements = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExecuteSqlRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ExecuteSqlRequest, ::rusoto_core::BuildError> {
+        Ok(ExecuteSqlRequest {
+            aws_secret_store_arn: self
+                .aws_secret_store_arn
+                .ok_or(::rusoto_core::BuildError::missing("aws_secret_store_arn"))?,
+            database: self.database,
+            db_cluster_or_instance_arn: self.db_cluster_or_instance_arn.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_or_instance_arn"),
+            )?,
+            schema: self.schema,
+            sql_statements: self
+                .sql_statements
+                .ok_or(::rusoto_core::BuildError::missing("sql_statements"))?,
+        })
+    }
+}
+
 /// <p>The response elements represent the output of a request to run one or more SQL
 /// statements.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -280,6 +539,104 @@ pub struct ExecuteStatementRequest {
     pub transaction_id: Option<String>,
 }
 
+impl ExecuteStatementRequest {
+    /// Returns a builder for `ExecuteStatementRequest`, checking its required members when built.
+    pub fn builder() -> ExecuteStatementRequestBuilder {
+        ExecuteStatementRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ExecuteStatementRequest`](struct.ExecuteStatementRequest.html).
+#[derive(Default, Debug)]
+pub struct ExecuteStatementRequestBuilder {
+    continue_after_timeout: Option<bool>,
+    database: Option<String>,
+    include_result_metadata: Option<bool>,
+    parameters: Option<Vec<SqlParameter>>,
+    resource_arn: Option<String>,
+    schema: Option<String>,
+    secret_arn: Option<String>,
+    sql: Option<String>,
+    transaction_id: Option<String>,
+}
+
+impl ExecuteStatementRequestBuilder {
+    /// Sets the [`continue_after_timeout`](struct.ExecuteStatementRequest.html#structfield.continue_after_timeout) member.
+    pub fn continue_after_timeout<T: Into<bool>>(mut self, value: T) -> Self {
+        self.continue_after_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database`](struct.ExecuteStatementRequest.html#structfield.database) member.
+    pub fn database<T: Into<String>>(mut self, value: T) -> Self {
+        self.database = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_result_metadata`](struct.ExecuteStatementRequest.html#structfield.include_result_metadata) member.
+    pub fn include_result_metadata<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_result_metadata = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ExecuteStatementRequest.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<SqlParameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.ExecuteStatementRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schema`](struct.ExecuteStatementRequest.html#structfield.schema) member.
+    pub fn schema<T: Into<String>>(mut self, value: T) -> Self {
+        self.schema = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_arn`](struct.ExecuteStatementRequest.html#structfield.secret_arn) member.
+    pub fn secret_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sql`](struct.ExecuteStatementRequest.html#structfield.sql) member.
+    pub fn sql<T: Into<String>>(mut self, value: T) -> Self {
+        self.sql = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transaction_id`](struct.ExecuteStatementRequest.html#structfield.transaction_id) member.
+    pub fn transaction_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transaction_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `ExecuteStatementRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ExecuteStatementRequest, ::rusoto_core::BuildError> {
+        Ok(ExecuteStatementRequest {
+            continue_after_timeout: self.continue_after_timeout,
+            database: self.database,
+            include_result_metadata: self.include_result_metadata,
+            parameters: self.parameters,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            schema: self.schema,
+            secret_arn: self
+                .secret_arn
+                .ok_or(::rusoto_core::BuildError::missing("secret_arn"))?,
+            sql: self.sql.ok_or(::rusoto_core::BuildError::missing("sql"))?,
+            transaction_id: self.transaction_id,
+        })
+    }
+}
+
 /// <p>The response elements represent the output of a request to run a SQL statement against
 /// a database.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -390,6 +747,58 @@ pub struct RollbackTransactionRequest {
     pub transaction_id: String,
 }
 
+impl RollbackTransactionRequest {
+    /// Returns a builder for `RollbackTransactionRequest`, checking its required members when built.
+    pub fn builder() -> RollbackTransactionRequestBuilder {
+        RollbackTransactionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RollbackTransactionRequest`](struct.RollbackTransactionRequest.html).
+#[derive(Default, Debug)]
+pub struct RollbackTransactionRequestBuilder {
+    resource_arn: Option<String>,
+    secret_arn: Option<String>,
+    transaction_id: Option<String>,
+}
+
+impl RollbackTransactionRequestBuilder {
+    /// Sets the [`resource_arn`](struct.RollbackTransactionRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`secret_arn`](struct.RollbackTransactionRequest.html#structfield.secret_arn) member.
+    pub fn secret_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.secret_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`transaction_id`](struct.RollbackTransactionRequest.html#structfield.transaction_id) member.
+    pub fn transaction_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.transaction_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RollbackTransactionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RollbackTransactionRequest, ::rusoto_core::BuildError> {
+        Ok(RollbackTransactionRequest {
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+            secret_arn: self
+                .secret_arn
+                .ok_or(::rusoto_core::BuildError::missing("secret_arn"))?,
+            transaction_id: self
+                .transaction_id
+                .ok_or(::rusoto_core::BuildError::missing("transaction_id"))?,
+        })
+    }
+}
+
 /// <p>The response elements represent the output of a request to perform a rollback of a
 /// transaction.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -938,6 +1347,51 @@ pub trait RdsData {
         input: RollbackTransactionRequest,
     ) -> RusotoFuture<RollbackTransactionResponse, RollbackTransactionError>;
 }
+impl<T: ?Sized + RdsData> RdsData for ::std::sync::Arc<T> {
+    fn batch_execute_statement(
+        &self,
+        input: BatchExecuteStatementRequest,
+    ) -> RusotoFuture<BatchExecuteStatementResponse, BatchExecuteStatementError> {
+        (**self).batch_execute_statement(input)
+    }
+    fn begin_transaction(
+        &self,
+        input: BeginTransactionRequest,
+    ) -> RusotoFuture<BeginTransactionResponse, BeginTransactionError> {
+        (**self).begin_transaction(input)
+    }
+    fn commit_transaction(
+        &self,
+        input: CommitTransactionRequest,
+    ) -> RusotoFuture<CommitTransactionResponse, CommitTransactionError> {
+        (**self).commit_transaction(input)
+    }
+    fn execute_sql(
+        &self,
+        input: ExecuteSqlRequest,
+    ) -> RusotoFuture<ExecuteSqlResponse, ExecuteSqlError> {
+        (**self).execute_sql(input)
+    }
+    fn execute_statement(
+        &self,
+        input: ExecuteStatementRequest,
+    ) -> RusotoFuture<ExecuteStatementResponse, ExecuteStatementError> {
+        (**self).execute_statement(input)
+    }
+    fn rollback_transaction(
+        &self,
+        input: RollbackTransactionRequest,
+    ) -> RusotoFuture<RollbackTransactionResponse, RollbackTransactionError> {
+        (**self).rollback_transaction(input)
+    }
+}
+/// The error codes AWS RDS DataService retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS RDS DataService API.
 #[derive(Clone)]
 pub struct RdsDataClient {
@@ -953,6 +1407,27 @@ impl RdsDataClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_RDS_DATA`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> RdsDataClient {
+        Self::new(region::Region::from_env_for_service("RDS_DATA"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `rds-data`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> RdsDataClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("rds-data", "RDS_DATA"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -971,7 +1446,54 @@ impl RdsDataClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> RdsDataClient {
-        RdsDataClient { client, region }
+        RdsDataClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "rds-data")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "rds-data",
+            "rds-data",
+            "RDS_DATA",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `RdsData` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn RdsData + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for RdsDataClient {
+    fn service_name(&self) -> &'static str {
+        "AWS RDS DataService"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "rds-data"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> RdsDataClient {
+        Self::new_with_client(client, region)
     }
 }
 
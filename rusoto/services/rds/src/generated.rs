@@ -28,6 +28,7 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -40,10 +41,10 @@ pub struct AccountAttributesMessage {
     pub account_quotas: Option<Vec<AccountQuota>>,
 }
 
-struct AccountAttributesMessageDeserializer;
+pub(crate) struct AccountAttributesMessageDeserializer;
 impl AccountAttributesMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccountAttributesMessage, XmlParseError> {
@@ -75,10 +76,10 @@ pub struct AccountQuota {
     pub used: Option<i64>,
 }
 
-struct AccountQuotaDeserializer;
+pub(crate) struct AccountQuotaDeserializer;
 impl AccountQuotaDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccountQuota, XmlParseError> {
@@ -100,10 +101,10 @@ impl AccountQuotaDeserializer {
         })
     }
 }
-struct AccountQuotaListDeserializer;
+pub(crate) struct AccountQuotaListDeserializer;
 impl AccountQuotaListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AccountQuota>, XmlParseError> {
@@ -120,28 +121,160 @@ impl AccountQuotaListDeserializer {
         })
     }
 }
-struct ActivityStreamModeDeserializer;
+pub(crate) struct ActivityStreamModeDeserializer;
 impl ActivityStreamModeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ActivityStreamMode, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ActivityStreamStatusDeserializer;
+/// <p>The mode of the database activity stream. Database events such as a change or access generate an activity stream event. The database session can handle these events either synchronously or asynchronously. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActivityStreamMode {
+    /// <p><code>sync</code></p>
+    Sync,
+    /// <p><code>async</code></p>
+    Async,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActivityStreamMode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActivityStreamMode::Sync => "sync",
+            ActivityStreamMode::Async => "async",
+            ActivityStreamMode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActivityStreamMode {
+    fn from(value: &'a str) -> ActivityStreamMode {
+        match value {
+            "sync" => ActivityStreamMode::Sync,
+            "async" => ActivityStreamMode::Async,
+            _ => ActivityStreamMode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActivityStreamMode {
+    fn from(value: String) -> ActivityStreamMode {
+        ActivityStreamMode::from(value.as_str())
+    }
+}
+
+impl From<ActivityStreamMode> for String {
+    fn from(value: ActivityStreamMode) -> String {
+        match value {
+            ActivityStreamMode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActivityStreamMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActivityStreamMode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ActivityStreamStatusDeserializer;
 impl ActivityStreamStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ActivityStreamStatus, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>The status of the database activity stream.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ActivityStreamStatus {
+    /// <p><code>stopped</code></p>
+    Stopped,
+    /// <p><code>starting</code></p>
+    Starting,
+    /// <p><code>started</code></p>
+    Started,
+    /// <p><code>stopping</code></p>
+    Stopping,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ActivityStreamStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ActivityStreamStatus::Stopped => "stopped",
+            ActivityStreamStatus::Starting => "starting",
+            ActivityStreamStatus::Started => "started",
+            ActivityStreamStatus::Stopping => "stopping",
+            ActivityStreamStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ActivityStreamStatus {
+    fn from(value: &'a str) -> ActivityStreamStatus {
+        match value {
+            "stopped" => ActivityStreamStatus::Stopped,
+            "starting" => ActivityStreamStatus::Starting,
+            "started" => ActivityStreamStatus::Started,
+            "stopping" => ActivityStreamStatus::Stopping,
+            _ => ActivityStreamStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ActivityStreamStatus {
+    fn from(value: String) -> ActivityStreamStatus {
+        ActivityStreamStatus::from(value.as_str())
+    }
+}
+
+impl From<ActivityStreamStatus> for String {
+    fn from(value: ActivityStreamStatus) -> String {
+        match value {
+            ActivityStreamStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ActivityStreamStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ActivityStreamStatus {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct AddRoleToDBClusterMessage {
     /// <p>The name of the DB cluster to associate the IAM role with.</p>
@@ -152,6 +285,56 @@ pub struct AddRoleToDBClusterMessage {
     pub role_arn: String,
 }
 
+impl AddRoleToDBClusterMessage {
+    /// Returns a builder for `AddRoleToDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> AddRoleToDBClusterMessageBuilder {
+        AddRoleToDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AddRoleToDBClusterMessage`](struct.AddRoleToDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct AddRoleToDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    feature_name: Option<String>,
+    role_arn: Option<String>,
+}
+
+impl AddRoleToDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.AddRoleToDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`feature_name`](struct.AddRoleToDBClusterMessage.html#structfield.feature_name) member.
+    pub fn feature_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.feature_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.AddRoleToDBClusterMessage.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddRoleToDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddRoleToDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(AddRoleToDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            feature_name: self.feature_name,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 /// Serialize `AddRoleToDBClusterMessage` contents to a `SignedRequest`.
 struct AddRoleToDBClusterMessageSerializer;
 impl AddRoleToDBClusterMessageSerializer {
@@ -182,6 +365,58 @@ pub struct AddRoleToDBInstanceMessage {
     pub role_arn: String,
 }
 
+impl AddRoleToDBInstanceMessage {
+    /// Returns a builder for `AddRoleToDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> AddRoleToDBInstanceMessageBuilder {
+        AddRoleToDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AddRoleToDBInstanceMessage`](struct.AddRoleToDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct AddRoleToDBInstanceMessageBuilder {
+    db_instance_identifier: Option<String>,
+    feature_name: Option<String>,
+    role_arn: Option<String>,
+}
+
+impl AddRoleToDBInstanceMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.AddRoleToDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`feature_name`](struct.AddRoleToDBInstanceMessage.html#structfield.feature_name) member.
+    pub fn feature_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.feature_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.AddRoleToDBInstanceMessage.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddRoleToDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddRoleToDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(AddRoleToDBInstanceMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            feature_name: self
+                .feature_name
+                .ok_or(::rusoto_core::BuildError::missing("feature_name"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 /// Serialize `AddRoleToDBInstanceMessage` contents to a `SignedRequest`.
 struct AddRoleToDBInstanceMessageSerializer;
 impl AddRoleToDBInstanceMessageSerializer {
@@ -209,6 +444,49 @@ pub struct AddSourceIdentifierToSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl AddSourceIdentifierToSubscriptionMessage {
+    /// Returns a builder for `AddSourceIdentifierToSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> AddSourceIdentifierToSubscriptionMessageBuilder {
+        AddSourceIdentifierToSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AddSourceIdentifierToSubscriptionMessage`](struct.AddSourceIdentifierToSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct AddSourceIdentifierToSubscriptionMessageBuilder {
+    source_identifier: Option<String>,
+    subscription_name: Option<String>,
+}
+
+impl AddSourceIdentifierToSubscriptionMessageBuilder {
+    /// Sets the [`source_identifier`](struct.AddSourceIdentifierToSubscriptionMessage.html#structfield.source_identifier) member.
+    pub fn source_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.AddSourceIdentifierToSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddSourceIdentifierToSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddSourceIdentifierToSubscriptionMessage, ::rusoto_core::BuildError>
+    {
+        Ok(AddSourceIdentifierToSubscriptionMessage {
+            source_identifier: self
+                .source_identifier
+                .ok_or(::rusoto_core::BuildError::missing("source_identifier"))?,
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+        })
+    }
+}
+
 /// Serialize `AddSourceIdentifierToSubscriptionMessage` contents to a `SignedRequest`.
 struct AddSourceIdentifierToSubscriptionMessageSerializer;
 impl AddSourceIdentifierToSubscriptionMessageSerializer {
@@ -234,10 +512,10 @@ pub struct AddSourceIdentifierToSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
 
-struct AddSourceIdentifierToSubscriptionResultDeserializer;
+pub(crate) struct AddSourceIdentifierToSubscriptionResultDeserializer;
 impl AddSourceIdentifierToSubscriptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AddSourceIdentifierToSubscriptionResult, XmlParseError> {
@@ -268,6 +546,48 @@ pub struct AddTagsToResourceMessage {
     pub tags: Vec<Tag>,
 }
 
+impl AddTagsToResourceMessage {
+    /// Returns a builder for `AddTagsToResourceMessage`, checking its required members when built.
+    pub fn builder() -> AddTagsToResourceMessageBuilder {
+        AddTagsToResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AddTagsToResourceMessage`](struct.AddTagsToResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct AddTagsToResourceMessageBuilder {
+    resource_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl AddTagsToResourceMessageBuilder {
+    /// Sets the [`resource_name`](struct.AddTagsToResourceMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.AddTagsToResourceMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `AddTagsToResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AddTagsToResourceMessage, ::rusoto_core::BuildError> {
+        Ok(AddTagsToResourceMessage {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `AddTagsToResourceMessage` contents to a `SignedRequest`.
 struct AddTagsToResourceMessageSerializer;
 impl AddTagsToResourceMessageSerializer {
@@ -282,17 +602,79 @@ impl AddTagsToResourceMessageSerializer {
     }
 }
 
-struct ApplyMethodDeserializer;
+pub(crate) struct ApplyMethodDeserializer;
 impl ApplyMethodDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ApplyMethod, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>Indicates when to apply parameter updates.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApplyMethod {
+    /// <p><code>immediate</code></p>
+    Immediate,
+    /// <p><code>pending-reboot</code></p>
+    PendingReboot,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ApplyMethod {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ApplyMethod::Immediate => "immediate",
+            ApplyMethod::PendingReboot => "pending-reboot",
+            ApplyMethod::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ApplyMethod {
+    fn from(value: &'a str) -> ApplyMethod {
+        match value {
+            "immediate" => ApplyMethod::Immediate,
+            "pending-reboot" => ApplyMethod::PendingReboot,
+            _ => ApplyMethod::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ApplyMethod {
+    fn from(value: String) -> ApplyMethod {
+        ApplyMethod::from(value.as_str())
+    }
+}
+
+impl From<ApplyMethod> for String {
+    fn from(value: ApplyMethod) -> String {
+        match value {
+            ApplyMethod::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ApplyMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ApplyMethod {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ApplyPendingMaintenanceActionMessage {
@@ -304,6 +686,59 @@ pub struct ApplyPendingMaintenanceActionMessage {
     pub resource_identifier: String,
 }
 
+impl ApplyPendingMaintenanceActionMessage {
+    /// Returns a builder for `ApplyPendingMaintenanceActionMessage`, checking its required members when built.
+    pub fn builder() -> ApplyPendingMaintenanceActionMessageBuilder {
+        ApplyPendingMaintenanceActionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ApplyPendingMaintenanceActionMessage`](struct.ApplyPendingMaintenanceActionMessage.html).
+#[derive(Default, Debug)]
+pub struct ApplyPendingMaintenanceActionMessageBuilder {
+    apply_action: Option<String>,
+    opt_in_type: Option<String>,
+    resource_identifier: Option<String>,
+}
+
+impl ApplyPendingMaintenanceActionMessageBuilder {
+    /// Sets the [`apply_action`](struct.ApplyPendingMaintenanceActionMessage.html#structfield.apply_action) member.
+    pub fn apply_action<T: Into<String>>(mut self, value: T) -> Self {
+        self.apply_action = Some(value.into());
+        self
+    }
+
+    /// Sets the [`opt_in_type`](struct.ApplyPendingMaintenanceActionMessage.html#structfield.opt_in_type) member.
+    pub fn opt_in_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.opt_in_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_identifier`](struct.ApplyPendingMaintenanceActionMessage.html#structfield.resource_identifier) member.
+    pub fn resource_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `ApplyPendingMaintenanceActionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ApplyPendingMaintenanceActionMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ApplyPendingMaintenanceActionMessage {
+            apply_action: self
+                .apply_action
+                .ok_or(::rusoto_core::BuildError::missing("apply_action"))?,
+            opt_in_type: self
+                .opt_in_type
+                .ok_or(::rusoto_core::BuildError::missing("opt_in_type"))?,
+            resource_identifier: self
+                .resource_identifier
+                .ok_or(::rusoto_core::BuildError::missing("resource_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `ApplyPendingMaintenanceActionMessage` contents to a `SignedRequest`.
 struct ApplyPendingMaintenanceActionMessageSerializer;
 impl ApplyPendingMaintenanceActionMessageSerializer {
@@ -327,10 +762,10 @@ pub struct ApplyPendingMaintenanceActionResult {
     pub resource_pending_maintenance_actions: Option<ResourcePendingMaintenanceActions>,
 }
 
-struct ApplyPendingMaintenanceActionResultDeserializer;
+pub(crate) struct ApplyPendingMaintenanceActionResultDeserializer;
 impl ApplyPendingMaintenanceActionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ApplyPendingMaintenanceActionResult, XmlParseError> {
@@ -353,10 +788,10 @@ impl ApplyPendingMaintenanceActionResultDeserializer {
         )
     }
 }
-struct AttributeValueListDeserializer;
+pub(crate) struct AttributeValueListDeserializer;
 impl AttributeValueListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -397,6 +832,71 @@ pub struct AuthorizeDBSecurityGroupIngressMessage {
     pub ec2_security_group_owner_id: Option<String>,
 }
 
+impl AuthorizeDBSecurityGroupIngressMessage {
+    /// Returns a builder for `AuthorizeDBSecurityGroupIngressMessage`, checking its required members when built.
+    pub fn builder() -> AuthorizeDBSecurityGroupIngressMessageBuilder {
+        AuthorizeDBSecurityGroupIngressMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AuthorizeDBSecurityGroupIngressMessage`](struct.AuthorizeDBSecurityGroupIngressMessage.html).
+#[derive(Default, Debug)]
+pub struct AuthorizeDBSecurityGroupIngressMessageBuilder {
+    cidrip: Option<String>,
+    db_security_group_name: Option<String>,
+    ec2_security_group_id: Option<String>,
+    ec2_security_group_name: Option<String>,
+    ec2_security_group_owner_id: Option<String>,
+}
+
+impl AuthorizeDBSecurityGroupIngressMessageBuilder {
+    /// Sets the [`cidrip`](struct.AuthorizeDBSecurityGroupIngressMessage.html#structfield.cidrip) member.
+    pub fn cidrip<T: Into<String>>(mut self, value: T) -> Self {
+        self.cidrip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_security_group_name`](struct.AuthorizeDBSecurityGroupIngressMessage.html#structfield.db_security_group_name) member.
+    pub fn db_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_id`](struct.AuthorizeDBSecurityGroupIngressMessage.html#structfield.ec2_security_group_id) member.
+    pub fn ec2_security_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_name`](struct.AuthorizeDBSecurityGroupIngressMessage.html#structfield.ec2_security_group_name) member.
+    pub fn ec2_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_owner_id`](struct.AuthorizeDBSecurityGroupIngressMessage.html#structfield.ec2_security_group_owner_id) member.
+    pub fn ec2_security_group_owner_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_owner_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AuthorizeDBSecurityGroupIngressMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AuthorizeDBSecurityGroupIngressMessage, ::rusoto_core::BuildError>
+    {
+        Ok(AuthorizeDBSecurityGroupIngressMessage {
+            cidrip: self.cidrip,
+            db_security_group_name: self
+                .db_security_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_security_group_name"))?,
+            ec2_security_group_id: self.ec2_security_group_id,
+            ec2_security_group_name: self.ec2_security_group_name,
+            ec2_security_group_owner_id: self.ec2_security_group_owner_id,
+        })
+    }
+}
+
 /// Serialize `AuthorizeDBSecurityGroupIngressMessage` contents to a `SignedRequest`.
 struct AuthorizeDBSecurityGroupIngressMessageSerializer;
 impl AuthorizeDBSecurityGroupIngressMessageSerializer {
@@ -436,10 +936,10 @@ pub struct AuthorizeDBSecurityGroupIngressResult {
     pub db_security_group: Option<DBSecurityGroup>,
 }
 
-struct AuthorizeDBSecurityGroupIngressResultDeserializer;
+pub(crate) struct AuthorizeDBSecurityGroupIngressResultDeserializer;
 impl AuthorizeDBSecurityGroupIngressResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AuthorizeDBSecurityGroupIngressResult, XmlParseError> {
@@ -468,10 +968,10 @@ pub struct AvailabilityZone {
     pub name: Option<String>,
 }
 
-struct AvailabilityZoneDeserializer;
+pub(crate) struct AvailabilityZoneDeserializer;
 impl AvailabilityZoneDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AvailabilityZone, XmlParseError> {
@@ -486,10 +986,10 @@ impl AvailabilityZoneDeserializer {
         })
     }
 }
-struct AvailabilityZoneListDeserializer;
+pub(crate) struct AvailabilityZoneListDeserializer;
 impl AvailabilityZoneListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AvailabilityZone>, XmlParseError> {
@@ -506,10 +1006,10 @@ impl AvailabilityZoneListDeserializer {
         })
     }
 }
-struct AvailabilityZonesDeserializer;
+pub(crate) struct AvailabilityZonesDeserializer;
 impl AvailabilityZonesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -546,10 +1046,10 @@ pub struct AvailableProcessorFeature {
     pub name: Option<String>,
 }
 
-struct AvailableProcessorFeatureDeserializer;
+pub(crate) struct AvailableProcessorFeatureDeserializer;
 impl AvailableProcessorFeatureDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AvailableProcessorFeature, XmlParseError> {
@@ -576,10 +1076,10 @@ impl AvailableProcessorFeatureDeserializer {
         )
     }
 }
-struct AvailableProcessorFeatureListDeserializer;
+pub(crate) struct AvailableProcessorFeatureListDeserializer;
 impl AvailableProcessorFeatureListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AvailableProcessorFeature>, XmlParseError> {
@@ -600,7 +1100,7 @@ impl AvailableProcessorFeatureListDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct BacktrackDBClusterMessage {
     /// <p>The timestamp of the time to backtrack the DB cluster to, specified in ISO 8601 format. For more information about ISO 8601, see the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <note> <p>If the specified time is not a consistent time for the DB cluster, Aurora automatically chooses the nearest possible consistent time for the DB cluster.</p> </note> <p>Constraints:</p> <ul> <li> <p>Must contain a valid ISO 8601 timestamp.</p> </li> <li> <p>Can't contain a timestamp set in the future.</p> </li> </ul> <p>Example: <code>2017-07-08T18:00Z</code> </p>
-    pub backtrack_to: String,
+    pub backtrack_to: IsoTimestamp,
     /// <p>The DB cluster identifier of the DB cluster to be backtracked. This parameter is stored as a lowercase string.</p> <p>Constraints:</p> <ul> <li> <p>Must contain from 1 to 63 alphanumeric characters or hyphens.</p> </li> <li> <p>First character must be a letter.</p> </li> <li> <p>Can't end with a hyphen or contain two consecutive hyphens.</p> </li> </ul> <p>Example: <code>my-cluster1</code> </p>
     pub db_cluster_identifier: String,
     /// <p>A value that indicates whether to force the DB cluster to backtrack when binary logging is enabled. Otherwise, an error occurs when binary logging is enabled.</p>
@@ -609,6 +1109,68 @@ pub struct BacktrackDBClusterMessage {
     pub use_earliest_time_on_point_in_time_unavailable: Option<bool>,
 }
 
+impl BacktrackDBClusterMessage {
+    /// Returns a builder for `BacktrackDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> BacktrackDBClusterMessageBuilder {
+        BacktrackDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`BacktrackDBClusterMessage`](struct.BacktrackDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct BacktrackDBClusterMessageBuilder {
+    backtrack_to: Option<IsoTimestamp>,
+    db_cluster_identifier: Option<String>,
+    force: Option<bool>,
+    use_earliest_time_on_point_in_time_unavailable: Option<bool>,
+}
+
+impl BacktrackDBClusterMessageBuilder {
+    /// Sets the [`backtrack_to`](struct.BacktrackDBClusterMessage.html#structfield.backtrack_to) member.
+    pub fn backtrack_to<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.backtrack_to = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.BacktrackDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force`](struct.BacktrackDBClusterMessage.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_earliest_time_on_point_in_time_unavailable`](struct.BacktrackDBClusterMessage.html#structfield.use_earliest_time_on_point_in_time_unavailable) member.
+    pub fn use_earliest_time_on_point_in_time_unavailable<T: Into<bool>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.use_earliest_time_on_point_in_time_unavailable = Some(value.into());
+        self
+    }
+
+    /// Builds the `BacktrackDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BacktrackDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(BacktrackDBClusterMessage {
+            backtrack_to: self
+                .backtrack_to
+                .ok_or(::rusoto_core::BuildError::missing("backtrack_to"))?,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            force: self.force,
+            use_earliest_time_on_point_in_time_unavailable: self
+                .use_earliest_time_on_point_in_time_unavailable,
+        })
+    }
+}
+
 /// Serialize `BacktrackDBClusterMessage` contents to a `SignedRequest`.
 struct BacktrackDBClusterMessageSerializer;
 impl BacktrackDBClusterMessageSerializer {
@@ -635,10 +1197,13 @@ impl BacktrackDBClusterMessageSerializer {
     }
 }
 
-struct BooleanDeserializer;
+pub(crate) struct BooleanDeserializer;
 impl BooleanDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -646,10 +1211,13 @@ impl BooleanDeserializer {
         Ok(obj)
     }
 }
-struct BooleanOptionalDeserializer;
+pub(crate) struct BooleanOptionalDeserializer;
 impl BooleanOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -669,15 +1237,15 @@ pub struct Certificate {
     /// <p>The thumbprint of the certificate.</p>
     pub thumbprint: Option<String>,
     /// <p>The starting date from which the certificate is valid.</p>
-    pub valid_from: Option<String>,
+    pub valid_from: Option<IsoTimestamp>,
     /// <p>The final date that the certificate continues to be valid.</p>
-    pub valid_till: Option<String>,
+    pub valid_till: Option<IsoTimestamp>,
 }
 
-struct CertificateDeserializer;
+pub(crate) struct CertificateDeserializer;
 impl CertificateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Certificate, XmlParseError> {
@@ -712,10 +1280,10 @@ impl CertificateDeserializer {
         })
     }
 }
-struct CertificateListDeserializer;
+pub(crate) struct CertificateListDeserializer;
 impl CertificateListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Certificate>, XmlParseError> {
@@ -738,10 +1306,10 @@ pub struct CertificateMessage {
     pub marker: Option<String>,
 }
 
-struct CertificateMessageDeserializer;
+pub(crate) struct CertificateMessageDeserializer;
 impl CertificateMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CertificateMessage, XmlParseError> {
@@ -770,10 +1338,10 @@ pub struct CharacterSet {
     pub character_set_name: Option<String>,
 }
 
-struct CharacterSetDeserializer;
+pub(crate) struct CharacterSetDeserializer;
 impl CharacterSetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CharacterSet, XmlParseError> {
@@ -841,6 +1409,81 @@ pub struct CopyDBClusterParameterGroupMessage {
     pub target_db_cluster_parameter_group_identifier: String,
 }
 
+impl CopyDBClusterParameterGroupMessage {
+    /// Returns a builder for `CopyDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> CopyDBClusterParameterGroupMessageBuilder {
+        CopyDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CopyDBClusterParameterGroupMessage`](struct.CopyDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CopyDBClusterParameterGroupMessageBuilder {
+    source_db_cluster_parameter_group_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_db_cluster_parameter_group_description: Option<String>,
+    target_db_cluster_parameter_group_identifier: Option<String>,
+}
+
+impl CopyDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`source_db_cluster_parameter_group_identifier`](struct.CopyDBClusterParameterGroupMessage.html#structfield.source_db_cluster_parameter_group_identifier) member.
+    pub fn source_db_cluster_parameter_group_identifier<T: Into<String>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.source_db_cluster_parameter_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CopyDBClusterParameterGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_cluster_parameter_group_description`](struct.CopyDBClusterParameterGroupMessage.html#structfield.target_db_cluster_parameter_group_description) member.
+    pub fn target_db_cluster_parameter_group_description<T: Into<String>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.target_db_cluster_parameter_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_cluster_parameter_group_identifier`](struct.CopyDBClusterParameterGroupMessage.html#structfield.target_db_cluster_parameter_group_identifier) member.
+    pub fn target_db_cluster_parameter_group_identifier<T: Into<String>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.target_db_cluster_parameter_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CopyDBClusterParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CopyDBClusterParameterGroupMessage {
+            source_db_cluster_parameter_group_identifier: self
+                .source_db_cluster_parameter_group_identifier
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "source_db_cluster_parameter_group_identifier",
+                ))?,
+            tags: self.tags,
+            target_db_cluster_parameter_group_description: self
+                .target_db_cluster_parameter_group_description
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "target_db_cluster_parameter_group_description",
+                ))?,
+            target_db_cluster_parameter_group_identifier: self
+                .target_db_cluster_parameter_group_identifier
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "target_db_cluster_parameter_group_identifier",
+                ))?,
+        })
+    }
+}
+
 /// Serialize `CopyDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct CopyDBClusterParameterGroupMessageSerializer;
 impl CopyDBClusterParameterGroupMessageSerializer {
@@ -873,10 +1516,10 @@ pub struct CopyDBClusterParameterGroupResult {
     pub db_cluster_parameter_group: Option<DBClusterParameterGroup>,
 }
 
-struct CopyDBClusterParameterGroupResultDeserializer;
+pub(crate) struct CopyDBClusterParameterGroupResultDeserializer;
 impl CopyDBClusterParameterGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CopyDBClusterParameterGroupResult, XmlParseError> {
@@ -915,6 +1558,84 @@ pub struct CopyDBClusterSnapshotMessage {
     pub target_db_cluster_snapshot_identifier: String,
 }
 
+impl CopyDBClusterSnapshotMessage {
+    /// Returns a builder for `CopyDBClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> CopyDBClusterSnapshotMessageBuilder {
+        CopyDBClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CopyDBClusterSnapshotMessage`](struct.CopyDBClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct CopyDBClusterSnapshotMessageBuilder {
+    copy_tags: Option<bool>,
+    kms_key_id: Option<String>,
+    pre_signed_url: Option<String>,
+    source_db_cluster_snapshot_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_db_cluster_snapshot_identifier: Option<String>,
+}
+
+impl CopyDBClusterSnapshotMessageBuilder {
+    /// Sets the [`copy_tags`](struct.CopyDBClusterSnapshotMessage.html#structfield.copy_tags) member.
+    pub fn copy_tags<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CopyDBClusterSnapshotMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pre_signed_url`](struct.CopyDBClusterSnapshotMessage.html#structfield.pre_signed_url) member.
+    pub fn pre_signed_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.pre_signed_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_db_cluster_snapshot_identifier`](struct.CopyDBClusterSnapshotMessage.html#structfield.source_db_cluster_snapshot_identifier) member.
+    pub fn source_db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CopyDBClusterSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_cluster_snapshot_identifier`](struct.CopyDBClusterSnapshotMessage.html#structfield.target_db_cluster_snapshot_identifier) member.
+    pub fn target_db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyDBClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CopyDBClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(CopyDBClusterSnapshotMessage {
+            copy_tags: self.copy_tags,
+            kms_key_id: self.kms_key_id,
+            pre_signed_url: self.pre_signed_url,
+            source_db_cluster_snapshot_identifier: self
+                .source_db_cluster_snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "source_db_cluster_snapshot_identifier",
+                ))?,
+            tags: self.tags,
+            target_db_cluster_snapshot_identifier: self
+                .target_db_cluster_snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "target_db_cluster_snapshot_identifier",
+                ))?,
+        })
+    }
+}
+
 /// Serialize `CopyDBClusterSnapshotMessage` contents to a `SignedRequest`.
 struct CopyDBClusterSnapshotMessageSerializer;
 impl CopyDBClusterSnapshotMessageSerializer {
@@ -952,10 +1673,10 @@ pub struct CopyDBClusterSnapshotResult {
     pub db_cluster_snapshot: Option<DBClusterSnapshot>,
 }
 
-struct CopyDBClusterSnapshotResultDeserializer;
+pub(crate) struct CopyDBClusterSnapshotResultDeserializer;
 impl CopyDBClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CopyDBClusterSnapshotResult, XmlParseError> {
@@ -989,6 +1710,68 @@ pub struct CopyDBParameterGroupMessage {
     pub target_db_parameter_group_identifier: String,
 }
 
+impl CopyDBParameterGroupMessage {
+    /// Returns a builder for `CopyDBParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> CopyDBParameterGroupMessageBuilder {
+        CopyDBParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CopyDBParameterGroupMessage`](struct.CopyDBParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CopyDBParameterGroupMessageBuilder {
+    source_db_parameter_group_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_db_parameter_group_description: Option<String>,
+    target_db_parameter_group_identifier: Option<String>,
+}
+
+impl CopyDBParameterGroupMessageBuilder {
+    /// Sets the [`source_db_parameter_group_identifier`](struct.CopyDBParameterGroupMessage.html#structfield.source_db_parameter_group_identifier) member.
+    pub fn source_db_parameter_group_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_db_parameter_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CopyDBParameterGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_parameter_group_description`](struct.CopyDBParameterGroupMessage.html#structfield.target_db_parameter_group_description) member.
+    pub fn target_db_parameter_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_db_parameter_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_parameter_group_identifier`](struct.CopyDBParameterGroupMessage.html#structfield.target_db_parameter_group_identifier) member.
+    pub fn target_db_parameter_group_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_db_parameter_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyDBParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CopyDBParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CopyDBParameterGroupMessage {
+            source_db_parameter_group_identifier: self.source_db_parameter_group_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_db_parameter_group_identifier"),
+            )?,
+            tags: self.tags,
+            target_db_parameter_group_description: self
+                .target_db_parameter_group_description
+                .ok_or(::rusoto_core::BuildError::missing(
+                    "target_db_parameter_group_description",
+                ))?,
+            target_db_parameter_group_identifier: self.target_db_parameter_group_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("target_db_parameter_group_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `CopyDBParameterGroupMessage` contents to a `SignedRequest`.
 struct CopyDBParameterGroupMessageSerializer;
 impl CopyDBParameterGroupMessageSerializer {
@@ -1021,10 +1804,10 @@ pub struct CopyDBParameterGroupResult {
     pub db_parameter_group: Option<DBParameterGroup>,
 }
 
-struct CopyDBParameterGroupResultDeserializer;
+pub(crate) struct CopyDBParameterGroupResultDeserializer;
 impl CopyDBParameterGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CopyDBParameterGroupResult, XmlParseError> {
@@ -1064,11 +1847,91 @@ pub struct CopyDBSnapshotMessage {
     pub target_db_snapshot_identifier: String,
 }
 
-/// Serialize `CopyDBSnapshotMessage` contents to a `SignedRequest`.
-struct CopyDBSnapshotMessageSerializer;
-impl CopyDBSnapshotMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &CopyDBSnapshotMessage) {
-        let mut prefix = name.to_string();
+impl CopyDBSnapshotMessage {
+    /// Returns a builder for `CopyDBSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> CopyDBSnapshotMessageBuilder {
+        CopyDBSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CopyDBSnapshotMessage`](struct.CopyDBSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct CopyDBSnapshotMessageBuilder {
+    copy_tags: Option<bool>,
+    kms_key_id: Option<String>,
+    option_group_name: Option<String>,
+    pre_signed_url: Option<String>,
+    source_db_snapshot_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_db_snapshot_identifier: Option<String>,
+}
+
+impl CopyDBSnapshotMessageBuilder {
+    /// Sets the [`copy_tags`](struct.CopyDBSnapshotMessage.html#structfield.copy_tags) member.
+    pub fn copy_tags<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CopyDBSnapshotMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.CopyDBSnapshotMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pre_signed_url`](struct.CopyDBSnapshotMessage.html#structfield.pre_signed_url) member.
+    pub fn pre_signed_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.pre_signed_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_db_snapshot_identifier`](struct.CopyDBSnapshotMessage.html#structfield.source_db_snapshot_identifier) member.
+    pub fn source_db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CopyDBSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_snapshot_identifier`](struct.CopyDBSnapshotMessage.html#structfield.target_db_snapshot_identifier) member.
+    pub fn target_db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyDBSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CopyDBSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(CopyDBSnapshotMessage {
+            copy_tags: self.copy_tags,
+            kms_key_id: self.kms_key_id,
+            option_group_name: self.option_group_name,
+            pre_signed_url: self.pre_signed_url,
+            source_db_snapshot_identifier: self.source_db_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_db_snapshot_identifier"),
+            )?,
+            tags: self.tags,
+            target_db_snapshot_identifier: self.target_db_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("target_db_snapshot_identifier"),
+            )?,
+        })
+    }
+}
+
+/// Serialize `CopyDBSnapshotMessage` contents to a `SignedRequest`.
+struct CopyDBSnapshotMessageSerializer;
+impl CopyDBSnapshotMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &CopyDBSnapshotMessage) {
+        let mut prefix = name.to_string();
         if prefix != "" {
             prefix.push_str(".");
         }
@@ -1104,10 +1967,10 @@ pub struct CopyDBSnapshotResult {
     pub db_snapshot: Option<DBSnapshot>,
 }
 
-struct CopyDBSnapshotResultDeserializer;
+pub(crate) struct CopyDBSnapshotResultDeserializer;
 impl CopyDBSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CopyDBSnapshotResult, XmlParseError> {
@@ -1135,6 +1998,64 @@ pub struct CopyOptionGroupMessage {
     pub target_option_group_identifier: String,
 }
 
+impl CopyOptionGroupMessage {
+    /// Returns a builder for `CopyOptionGroupMessage`, checking its required members when built.
+    pub fn builder() -> CopyOptionGroupMessageBuilder {
+        CopyOptionGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CopyOptionGroupMessage`](struct.CopyOptionGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CopyOptionGroupMessageBuilder {
+    source_option_group_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_option_group_description: Option<String>,
+    target_option_group_identifier: Option<String>,
+}
+
+impl CopyOptionGroupMessageBuilder {
+    /// Sets the [`source_option_group_identifier`](struct.CopyOptionGroupMessage.html#structfield.source_option_group_identifier) member.
+    pub fn source_option_group_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_option_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CopyOptionGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_option_group_description`](struct.CopyOptionGroupMessage.html#structfield.target_option_group_description) member.
+    pub fn target_option_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_option_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_option_group_identifier`](struct.CopyOptionGroupMessage.html#structfield.target_option_group_identifier) member.
+    pub fn target_option_group_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_option_group_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyOptionGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CopyOptionGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CopyOptionGroupMessage {
+            source_option_group_identifier: self.source_option_group_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_option_group_identifier"),
+            )?,
+            tags: self.tags,
+            target_option_group_description: self.target_option_group_description.ok_or(
+                ::rusoto_core::BuildError::missing("target_option_group_description"),
+            )?,
+            target_option_group_identifier: self.target_option_group_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("target_option_group_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `CopyOptionGroupMessage` contents to a `SignedRequest`.
 struct CopyOptionGroupMessageSerializer;
 impl CopyOptionGroupMessageSerializer {
@@ -1167,10 +2088,10 @@ pub struct CopyOptionGroupResult {
     pub option_group: Option<OptionGroup>,
 }
 
-struct CopyOptionGroupResultDeserializer;
+pub(crate) struct CopyOptionGroupResultDeserializer;
 impl CopyOptionGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CopyOptionGroupResult, XmlParseError> {
@@ -1200,6 +2121,74 @@ pub struct CreateDBClusterEndpointMessage {
     pub static_members: Option<Vec<String>>,
 }
 
+impl CreateDBClusterEndpointMessage {
+    /// Returns a builder for `CreateDBClusterEndpointMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBClusterEndpointMessageBuilder {
+        CreateDBClusterEndpointMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBClusterEndpointMessage`](struct.CreateDBClusterEndpointMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBClusterEndpointMessageBuilder {
+    db_cluster_endpoint_identifier: Option<String>,
+    db_cluster_identifier: Option<String>,
+    endpoint_type: Option<String>,
+    excluded_members: Option<Vec<String>>,
+    static_members: Option<Vec<String>>,
+}
+
+impl CreateDBClusterEndpointMessageBuilder {
+    /// Sets the [`db_cluster_endpoint_identifier`](struct.CreateDBClusterEndpointMessage.html#structfield.db_cluster_endpoint_identifier) member.
+    pub fn db_cluster_endpoint_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_endpoint_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.CreateDBClusterEndpointMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_type`](struct.CreateDBClusterEndpointMessage.html#structfield.endpoint_type) member.
+    pub fn endpoint_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`excluded_members`](struct.CreateDBClusterEndpointMessage.html#structfield.excluded_members) member.
+    pub fn excluded_members<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.excluded_members = Some(value.into());
+        self
+    }
+
+    /// Sets the [`static_members`](struct.CreateDBClusterEndpointMessage.html#structfield.static_members) member.
+    pub fn static_members<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.static_members = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBClusterEndpointMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBClusterEndpointMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBClusterEndpointMessage {
+            db_cluster_endpoint_identifier: self.db_cluster_endpoint_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_endpoint_identifier"),
+            )?,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            endpoint_type: self
+                .endpoint_type
+                .ok_or(::rusoto_core::BuildError::missing("endpoint_type"))?,
+            excluded_members: self.excluded_members,
+            static_members: self.static_members,
+        })
+    }
+}
+
 /// Serialize `CreateDBClusterEndpointMessage` contents to a `SignedRequest`.
 struct CreateDBClusterEndpointMessageSerializer;
 impl CreateDBClusterEndpointMessageSerializer {
@@ -1300,6 +2289,270 @@ pub struct CreateDBClusterMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl CreateDBClusterMessage {
+    /// Returns a builder for `CreateDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBClusterMessageBuilder {
+        CreateDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBClusterMessage`](struct.CreateDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBClusterMessageBuilder {
+    availability_zones: Option<Vec<String>>,
+    backtrack_window: Option<i64>,
+    backup_retention_period: Option<i64>,
+    character_set_name: Option<String>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_cluster_identifier: Option<String>,
+    db_cluster_parameter_group_name: Option<String>,
+    db_subnet_group_name: Option<String>,
+    database_name: Option<String>,
+    deletion_protection: Option<bool>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_http_endpoint: Option<bool>,
+    enable_iam_database_authentication: Option<bool>,
+    engine: Option<String>,
+    engine_mode: Option<String>,
+    engine_version: Option<String>,
+    global_cluster_identifier: Option<String>,
+    kms_key_id: Option<String>,
+    master_user_password: Option<String>,
+    master_username: Option<String>,
+    option_group_name: Option<String>,
+    port: Option<i64>,
+    pre_signed_url: Option<String>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    replication_source_identifier: Option<String>,
+    scaling_configuration: Option<ScalingConfiguration>,
+    storage_encrypted: Option<bool>,
+    tags: Option<Vec<Tag>>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl CreateDBClusterMessageBuilder {
+    /// Sets the [`availability_zones`](struct.CreateDBClusterMessage.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backtrack_window`](struct.CreateDBClusterMessage.html#structfield.backtrack_window) member.
+    pub fn backtrack_window<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backtrack_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_period`](struct.CreateDBClusterMessage.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`character_set_name`](struct.CreateDBClusterMessage.html#structfield.character_set_name) member.
+    pub fn character_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.character_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.CreateDBClusterMessage.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.CreateDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_parameter_group_name`](struct.CreateDBClusterMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.CreateDBClusterMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.CreateDBClusterMessage.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.CreateDBClusterMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.CreateDBClusterMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_http_endpoint`](struct.CreateDBClusterMessage.html#structfield.enable_http_endpoint) member.
+    pub fn enable_http_endpoint<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_http_endpoint = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.CreateDBClusterMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.CreateDBClusterMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_mode`](struct.CreateDBClusterMessage.html#structfield.engine_mode) member.
+    pub fn engine_mode<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.CreateDBClusterMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`global_cluster_identifier`](struct.CreateDBClusterMessage.html#structfield.global_cluster_identifier) member.
+    pub fn global_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.global_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateDBClusterMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.CreateDBClusterMessage.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_username`](struct.CreateDBClusterMessage.html#structfield.master_username) member.
+    pub fn master_username<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_username = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.CreateDBClusterMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.CreateDBClusterMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pre_signed_url`](struct.CreateDBClusterMessage.html#structfield.pre_signed_url) member.
+    pub fn pre_signed_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.pre_signed_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.CreateDBClusterMessage.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateDBClusterMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`replication_source_identifier`](struct.CreateDBClusterMessage.html#structfield.replication_source_identifier) member.
+    pub fn replication_source_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.replication_source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_configuration`](struct.CreateDBClusterMessage.html#structfield.scaling_configuration) member.
+    pub fn scaling_configuration<T: Into<ScalingConfiguration>>(mut self, value: T) -> Self {
+        self.scaling_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_encrypted`](struct.CreateDBClusterMessage.html#structfield.storage_encrypted) member.
+    pub fn storage_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.storage_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBClusterMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.CreateDBClusterMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBClusterMessage {
+            availability_zones: self.availability_zones,
+            backtrack_window: self.backtrack_window,
+            backup_retention_period: self.backup_retention_period,
+            character_set_name: self.character_set_name,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            db_subnet_group_name: self.db_subnet_group_name,
+            database_name: self.database_name,
+            deletion_protection: self.deletion_protection,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_http_endpoint: self.enable_http_endpoint,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_mode: self.engine_mode,
+            engine_version: self.engine_version,
+            global_cluster_identifier: self.global_cluster_identifier,
+            kms_key_id: self.kms_key_id,
+            master_user_password: self.master_user_password,
+            master_username: self.master_username,
+            option_group_name: self.option_group_name,
+            port: self.port,
+            pre_signed_url: self.pre_signed_url,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            replication_source_identifier: self.replication_source_identifier,
+            scaling_configuration: self.scaling_configuration,
+            storage_encrypted: self.storage_encrypted,
+            tags: self.tags,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `CreateDBClusterMessage` contents to a `SignedRequest`.
 struct CreateDBClusterMessageSerializer;
 impl CreateDBClusterMessageSerializer {
@@ -1451,6 +2704,67 @@ pub struct CreateDBClusterParameterGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBClusterParameterGroupMessage {
+    /// Returns a builder for `CreateDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBClusterParameterGroupMessageBuilder {
+        CreateDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBClusterParameterGroupMessage`](struct.CreateDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBClusterParameterGroupMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    db_parameter_group_family: Option<String>,
+    description: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.CreateDBClusterParameterGroupMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_family`](struct.CreateDBClusterParameterGroupMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateDBClusterParameterGroupMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBClusterParameterGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBClusterParameterGroupMessage, ::rusoto_core::BuildError>
+    {
+        Ok(CreateDBClusterParameterGroupMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+            db_parameter_group_family: self.db_parameter_group_family.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_family"),
+            )?,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct CreateDBClusterParameterGroupMessageSerializer;
 impl CreateDBClusterParameterGroupMessageSerializer {
@@ -1480,10 +2794,10 @@ pub struct CreateDBClusterParameterGroupResult {
     pub db_cluster_parameter_group: Option<DBClusterParameterGroup>,
 }
 
-struct CreateDBClusterParameterGroupResultDeserializer;
+pub(crate) struct CreateDBClusterParameterGroupResultDeserializer;
 impl CreateDBClusterParameterGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBClusterParameterGroupResult, XmlParseError> {
@@ -1511,10 +2825,10 @@ pub struct CreateDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct CreateDBClusterResultDeserializer;
+pub(crate) struct CreateDBClusterResultDeserializer;
 impl CreateDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBClusterResult, XmlParseError> {
@@ -1540,6 +2854,56 @@ pub struct CreateDBClusterSnapshotMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBClusterSnapshotMessage {
+    /// Returns a builder for `CreateDBClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBClusterSnapshotMessageBuilder {
+        CreateDBClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBClusterSnapshotMessage`](struct.CreateDBClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBClusterSnapshotMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    db_cluster_snapshot_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBClusterSnapshotMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.CreateDBClusterSnapshotMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.CreateDBClusterSnapshotMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBClusterSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBClusterSnapshotMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_snapshot_identifier"),
+            )?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBClusterSnapshotMessage` contents to a `SignedRequest`.
 struct CreateDBClusterSnapshotMessageSerializer;
 impl CreateDBClusterSnapshotMessageSerializer {
@@ -1568,10 +2932,10 @@ pub struct CreateDBClusterSnapshotResult {
     pub db_cluster_snapshot: Option<DBClusterSnapshot>,
 }
 
-struct CreateDBClusterSnapshotResultDeserializer;
+pub(crate) struct CreateDBClusterSnapshotResultDeserializer;
 impl CreateDBClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBClusterSnapshotResult, XmlParseError> {
@@ -1690,6 +3054,402 @@ pub struct CreateDBInstanceMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl CreateDBInstanceMessage {
+    /// Returns a builder for `CreateDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBInstanceMessageBuilder {
+        CreateDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBInstanceMessage`](struct.CreateDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBInstanceMessageBuilder {
+    allocated_storage: Option<i64>,
+    auto_minor_version_upgrade: Option<bool>,
+    availability_zone: Option<String>,
+    backup_retention_period: Option<i64>,
+    character_set_name: Option<String>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_cluster_identifier: Option<String>,
+    db_instance_class: Option<String>,
+    db_instance_identifier: Option<String>,
+    db_name: Option<String>,
+    db_parameter_group_name: Option<String>,
+    db_security_groups: Option<Vec<String>>,
+    db_subnet_group_name: Option<String>,
+    deletion_protection: Option<bool>,
+    domain: Option<String>,
+    domain_iam_role_name: Option<String>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_iam_database_authentication: Option<bool>,
+    enable_performance_insights: Option<bool>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    iops: Option<i64>,
+    kms_key_id: Option<String>,
+    license_model: Option<String>,
+    master_user_password: Option<String>,
+    master_username: Option<String>,
+    max_allocated_storage: Option<i64>,
+    monitoring_interval: Option<i64>,
+    monitoring_role_arn: Option<String>,
+    multi_az: Option<bool>,
+    option_group_name: Option<String>,
+    performance_insights_kms_key_id: Option<String>,
+    performance_insights_retention_period: Option<i64>,
+    port: Option<i64>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    processor_features: Option<Vec<ProcessorFeature>>,
+    promotion_tier: Option<i64>,
+    publicly_accessible: Option<bool>,
+    storage_encrypted: Option<bool>,
+    storage_type: Option<String>,
+    tags: Option<Vec<Tag>>,
+    tde_credential_arn: Option<String>,
+    tde_credential_password: Option<String>,
+    timezone: Option<String>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl CreateDBInstanceMessageBuilder {
+    /// Sets the [`allocated_storage`](struct.CreateDBInstanceMessage.html#structfield.allocated_storage) member.
+    pub fn allocated_storage<T: Into<i64>>(mut self, value: T) -> Self {
+        self.allocated_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_minor_version_upgrade`](struct.CreateDBInstanceMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zone`](struct.CreateDBInstanceMessage.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_period`](struct.CreateDBInstanceMessage.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`character_set_name`](struct.CreateDBInstanceMessage.html#structfield.character_set_name) member.
+    pub fn character_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.character_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.CreateDBInstanceMessage.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.CreateDBInstanceMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_class`](struct.CreateDBInstanceMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_identifier`](struct.CreateDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_name`](struct.CreateDBInstanceMessage.html#structfield.db_name) member.
+    pub fn db_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_name`](struct.CreateDBInstanceMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_security_groups`](struct.CreateDBInstanceMessage.html#structfield.db_security_groups) member.
+    pub fn db_security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.db_security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.CreateDBInstanceMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.CreateDBInstanceMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.CreateDBInstanceMessage.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_iam_role_name`](struct.CreateDBInstanceMessage.html#structfield.domain_iam_role_name) member.
+    pub fn domain_iam_role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_iam_role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.CreateDBInstanceMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.CreateDBInstanceMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_performance_insights`](struct.CreateDBInstanceMessage.html#structfield.enable_performance_insights) member.
+    pub fn enable_performance_insights<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_performance_insights = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.CreateDBInstanceMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.CreateDBInstanceMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iops`](struct.CreateDBInstanceMessage.html#structfield.iops) member.
+    pub fn iops<T: Into<i64>>(mut self, value: T) -> Self {
+        self.iops = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateDBInstanceMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_model`](struct.CreateDBInstanceMessage.html#structfield.license_model) member.
+    pub fn license_model<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_model = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.CreateDBInstanceMessage.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_username`](struct.CreateDBInstanceMessage.html#structfield.master_username) member.
+    pub fn master_username<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_username = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_allocated_storage`](struct.CreateDBInstanceMessage.html#structfield.max_allocated_storage) member.
+    pub fn max_allocated_storage<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_allocated_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`monitoring_interval`](struct.CreateDBInstanceMessage.html#structfield.monitoring_interval) member.
+    pub fn monitoring_interval<T: Into<i64>>(mut self, value: T) -> Self {
+        self.monitoring_interval = Some(value.into());
+        self
+    }
+
+    /// Sets the [`monitoring_role_arn`](struct.CreateDBInstanceMessage.html#structfield.monitoring_role_arn) member.
+    pub fn monitoring_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.monitoring_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.CreateDBInstanceMessage.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.CreateDBInstanceMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`performance_insights_kms_key_id`](struct.CreateDBInstanceMessage.html#structfield.performance_insights_kms_key_id) member.
+    pub fn performance_insights_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.performance_insights_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`performance_insights_retention_period`](struct.CreateDBInstanceMessage.html#structfield.performance_insights_retention_period) member.
+    pub fn performance_insights_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.performance_insights_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.CreateDBInstanceMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.CreateDBInstanceMessage.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateDBInstanceMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`processor_features`](struct.CreateDBInstanceMessage.html#structfield.processor_features) member.
+    pub fn processor_features<T: Into<Vec<ProcessorFeature>>>(mut self, value: T) -> Self {
+        self.processor_features = Some(value.into());
+        self
+    }
+
+    /// Sets the [`promotion_tier`](struct.CreateDBInstanceMessage.html#structfield.promotion_tier) member.
+    pub fn promotion_tier<T: Into<i64>>(mut self, value: T) -> Self {
+        self.promotion_tier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.CreateDBInstanceMessage.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_encrypted`](struct.CreateDBInstanceMessage.html#structfield.storage_encrypted) member.
+    pub fn storage_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.storage_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_type`](struct.CreateDBInstanceMessage.html#structfield.storage_type) member.
+    pub fn storage_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.storage_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBInstanceMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tde_credential_arn`](struct.CreateDBInstanceMessage.html#structfield.tde_credential_arn) member.
+    pub fn tde_credential_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tde_credential_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tde_credential_password`](struct.CreateDBInstanceMessage.html#structfield.tde_credential_password) member.
+    pub fn tde_credential_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.tde_credential_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timezone`](struct.CreateDBInstanceMessage.html#structfield.timezone) member.
+    pub fn timezone<T: Into<String>>(mut self, value: T) -> Self {
+        self.timezone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.CreateDBInstanceMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBInstanceMessage {
+            allocated_storage: self.allocated_storage,
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            availability_zone: self.availability_zone,
+            backup_retention_period: self.backup_retention_period,
+            character_set_name: self.character_set_name,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_cluster_identifier: self.db_cluster_identifier,
+            db_instance_class: self
+                .db_instance_class
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_class"))?,
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            db_name: self.db_name,
+            db_parameter_group_name: self.db_parameter_group_name,
+            db_security_groups: self.db_security_groups,
+            db_subnet_group_name: self.db_subnet_group_name,
+            deletion_protection: self.deletion_protection,
+            domain: self.domain,
+            domain_iam_role_name: self.domain_iam_role_name,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            enable_performance_insights: self.enable_performance_insights,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_version: self.engine_version,
+            iops: self.iops,
+            kms_key_id: self.kms_key_id,
+            license_model: self.license_model,
+            master_user_password: self.master_user_password,
+            master_username: self.master_username,
+            max_allocated_storage: self.max_allocated_storage,
+            monitoring_interval: self.monitoring_interval,
+            monitoring_role_arn: self.monitoring_role_arn,
+            multi_az: self.multi_az,
+            option_group_name: self.option_group_name,
+            performance_insights_kms_key_id: self.performance_insights_kms_key_id,
+            performance_insights_retention_period: self.performance_insights_retention_period,
+            port: self.port,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            processor_features: self.processor_features,
+            promotion_tier: self.promotion_tier,
+            publicly_accessible: self.publicly_accessible,
+            storage_encrypted: self.storage_encrypted,
+            storage_type: self.storage_type,
+            tags: self.tags,
+            tde_credential_arn: self.tde_credential_arn,
+            tde_credential_password: self.tde_credential_password,
+            timezone: self.timezone,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `CreateDBInstanceMessage` contents to a `SignedRequest`.
 struct CreateDBInstanceMessageSerializer;
 impl CreateDBInstanceMessageSerializer {
@@ -1951,9 +3711,259 @@ pub struct CreateDBInstanceReadReplicaMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
-/// Serialize `CreateDBInstanceReadReplicaMessage` contents to a `SignedRequest`.
-struct CreateDBInstanceReadReplicaMessageSerializer;
-impl CreateDBInstanceReadReplicaMessageSerializer {
+impl CreateDBInstanceReadReplicaMessage {
+    /// Returns a builder for `CreateDBInstanceReadReplicaMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBInstanceReadReplicaMessageBuilder {
+        CreateDBInstanceReadReplicaMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBInstanceReadReplicaMessage`](struct.CreateDBInstanceReadReplicaMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBInstanceReadReplicaMessageBuilder {
+    auto_minor_version_upgrade: Option<bool>,
+    availability_zone: Option<String>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_instance_class: Option<String>,
+    db_instance_identifier: Option<String>,
+    db_parameter_group_name: Option<String>,
+    db_subnet_group_name: Option<String>,
+    deletion_protection: Option<bool>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_iam_database_authentication: Option<bool>,
+    enable_performance_insights: Option<bool>,
+    iops: Option<i64>,
+    kms_key_id: Option<String>,
+    monitoring_interval: Option<i64>,
+    monitoring_role_arn: Option<String>,
+    multi_az: Option<bool>,
+    option_group_name: Option<String>,
+    performance_insights_kms_key_id: Option<String>,
+    performance_insights_retention_period: Option<i64>,
+    port: Option<i64>,
+    pre_signed_url: Option<String>,
+    processor_features: Option<Vec<ProcessorFeature>>,
+    publicly_accessible: Option<bool>,
+    source_db_instance_identifier: Option<String>,
+    storage_type: Option<String>,
+    tags: Option<Vec<Tag>>,
+    use_default_processor_features: Option<bool>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl CreateDBInstanceReadReplicaMessageBuilder {
+    /// Sets the [`auto_minor_version_upgrade`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zone`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_class`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_identifier`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_name`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_performance_insights`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.enable_performance_insights) member.
+    pub fn enable_performance_insights<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_performance_insights = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iops`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.iops) member.
+    pub fn iops<T: Into<i64>>(mut self, value: T) -> Self {
+        self.iops = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`monitoring_interval`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.monitoring_interval) member.
+    pub fn monitoring_interval<T: Into<i64>>(mut self, value: T) -> Self {
+        self.monitoring_interval = Some(value.into());
+        self
+    }
+
+    /// Sets the [`monitoring_role_arn`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.monitoring_role_arn) member.
+    pub fn monitoring_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.monitoring_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`performance_insights_kms_key_id`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.performance_insights_kms_key_id) member.
+    pub fn performance_insights_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.performance_insights_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`performance_insights_retention_period`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.performance_insights_retention_period) member.
+    pub fn performance_insights_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.performance_insights_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pre_signed_url`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.pre_signed_url) member.
+    pub fn pre_signed_url<T: Into<String>>(mut self, value: T) -> Self {
+        self.pre_signed_url = Some(value.into());
+        self
+    }
+
+    /// Sets the [`processor_features`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.processor_features) member.
+    pub fn processor_features<T: Into<Vec<ProcessorFeature>>>(mut self, value: T) -> Self {
+        self.processor_features = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_db_instance_identifier`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.source_db_instance_identifier) member.
+    pub fn source_db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_type`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.storage_type) member.
+    pub fn storage_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.storage_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_default_processor_features`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.use_default_processor_features) member.
+    pub fn use_default_processor_features<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_default_processor_features = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.CreateDBInstanceReadReplicaMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBInstanceReadReplicaMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBInstanceReadReplicaMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBInstanceReadReplicaMessage {
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            availability_zone: self.availability_zone,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_instance_class: self.db_instance_class,
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            db_parameter_group_name: self.db_parameter_group_name,
+            db_subnet_group_name: self.db_subnet_group_name,
+            deletion_protection: self.deletion_protection,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            enable_performance_insights: self.enable_performance_insights,
+            iops: self.iops,
+            kms_key_id: self.kms_key_id,
+            monitoring_interval: self.monitoring_interval,
+            monitoring_role_arn: self.monitoring_role_arn,
+            multi_az: self.multi_az,
+            option_group_name: self.option_group_name,
+            performance_insights_kms_key_id: self.performance_insights_kms_key_id,
+            performance_insights_retention_period: self.performance_insights_retention_period,
+            port: self.port,
+            pre_signed_url: self.pre_signed_url,
+            processor_features: self.processor_features,
+            publicly_accessible: self.publicly_accessible,
+            source_db_instance_identifier: self.source_db_instance_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_db_instance_identifier"),
+            )?,
+            storage_type: self.storage_type,
+            tags: self.tags,
+            use_default_processor_features: self.use_default_processor_features,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
+/// Serialize `CreateDBInstanceReadReplicaMessage` contents to a `SignedRequest`.
+struct CreateDBInstanceReadReplicaMessageSerializer;
+impl CreateDBInstanceReadReplicaMessageSerializer {
     fn serialize(params: &mut Params, name: &str, obj: &CreateDBInstanceReadReplicaMessage) {
         let mut prefix = name.to_string();
         if prefix != "" {
@@ -2087,10 +4097,10 @@ pub struct CreateDBInstanceReadReplicaResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct CreateDBInstanceReadReplicaResultDeserializer;
+pub(crate) struct CreateDBInstanceReadReplicaResultDeserializer;
 impl CreateDBInstanceReadReplicaResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBInstanceReadReplicaResult, XmlParseError> {
@@ -2115,10 +4125,10 @@ pub struct CreateDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct CreateDBInstanceResultDeserializer;
+pub(crate) struct CreateDBInstanceResultDeserializer;
 impl CreateDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBInstanceResult, XmlParseError> {
@@ -2147,6 +4157,66 @@ pub struct CreateDBParameterGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBParameterGroupMessage {
+    /// Returns a builder for `CreateDBParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBParameterGroupMessageBuilder {
+        CreateDBParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBParameterGroupMessage`](struct.CreateDBParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBParameterGroupMessageBuilder {
+    db_parameter_group_family: Option<String>,
+    db_parameter_group_name: Option<String>,
+    description: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBParameterGroupMessageBuilder {
+    /// Sets the [`db_parameter_group_family`](struct.CreateDBParameterGroupMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_name`](struct.CreateDBParameterGroupMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateDBParameterGroupMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBParameterGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBParameterGroupMessage {
+            db_parameter_group_family: self.db_parameter_group_family.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_family"),
+            )?,
+            db_parameter_group_name: self.db_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_name"),
+            )?,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBParameterGroupMessage` contents to a `SignedRequest`.
 struct CreateDBParameterGroupMessageSerializer;
 impl CreateDBParameterGroupMessageSerializer {
@@ -2176,10 +4246,10 @@ pub struct CreateDBParameterGroupResult {
     pub db_parameter_group: Option<DBParameterGroup>,
 }
 
-struct CreateDBParameterGroupResultDeserializer;
+pub(crate) struct CreateDBParameterGroupResultDeserializer;
 impl CreateDBParameterGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBParameterGroupResult, XmlParseError> {
@@ -2212,6 +4282,56 @@ pub struct CreateDBSecurityGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBSecurityGroupMessage {
+    /// Returns a builder for `CreateDBSecurityGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBSecurityGroupMessageBuilder {
+        CreateDBSecurityGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBSecurityGroupMessage`](struct.CreateDBSecurityGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBSecurityGroupMessageBuilder {
+    db_security_group_description: Option<String>,
+    db_security_group_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBSecurityGroupMessageBuilder {
+    /// Sets the [`db_security_group_description`](struct.CreateDBSecurityGroupMessage.html#structfield.db_security_group_description) member.
+    pub fn db_security_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_security_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_security_group_name`](struct.CreateDBSecurityGroupMessage.html#structfield.db_security_group_name) member.
+    pub fn db_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBSecurityGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBSecurityGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBSecurityGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBSecurityGroupMessage {
+            db_security_group_description: self.db_security_group_description.ok_or(
+                ::rusoto_core::BuildError::missing("db_security_group_description"),
+            )?,
+            db_security_group_name: self
+                .db_security_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_security_group_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBSecurityGroupMessage` contents to a `SignedRequest`.
 struct CreateDBSecurityGroupMessageSerializer;
 impl CreateDBSecurityGroupMessageSerializer {
@@ -2240,10 +4360,10 @@ pub struct CreateDBSecurityGroupResult {
     pub db_security_group: Option<DBSecurityGroup>,
 }
 
-struct CreateDBSecurityGroupResultDeserializer;
+pub(crate) struct CreateDBSecurityGroupResultDeserializer;
 impl CreateDBSecurityGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBSecurityGroupResult, XmlParseError> {
@@ -2275,6 +4395,56 @@ pub struct CreateDBSnapshotMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBSnapshotMessage {
+    /// Returns a builder for `CreateDBSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBSnapshotMessageBuilder {
+        CreateDBSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBSnapshotMessage`](struct.CreateDBSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBSnapshotMessageBuilder {
+    db_instance_identifier: Option<String>,
+    db_snapshot_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBSnapshotMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.CreateDBSnapshotMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_snapshot_identifier`](struct.CreateDBSnapshotMessage.html#structfield.db_snapshot_identifier) member.
+    pub fn db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBSnapshotMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            db_snapshot_identifier: self
+                .db_snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_snapshot_identifier"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBSnapshotMessage` contents to a `SignedRequest`.
 struct CreateDBSnapshotMessageSerializer;
 impl CreateDBSnapshotMessageSerializer {
@@ -2303,10 +4473,10 @@ pub struct CreateDBSnapshotResult {
     pub db_snapshot: Option<DBSnapshot>,
 }
 
-struct CreateDBSnapshotResultDeserializer;
+pub(crate) struct CreateDBSnapshotResultDeserializer;
 impl CreateDBSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBSnapshotResult, XmlParseError> {
@@ -2335,6 +4505,66 @@ pub struct CreateDBSubnetGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateDBSubnetGroupMessage {
+    /// Returns a builder for `CreateDBSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateDBSubnetGroupMessageBuilder {
+        CreateDBSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateDBSubnetGroupMessage`](struct.CreateDBSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateDBSubnetGroupMessageBuilder {
+    db_subnet_group_description: Option<String>,
+    db_subnet_group_name: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateDBSubnetGroupMessageBuilder {
+    /// Sets the [`db_subnet_group_description`](struct.CreateDBSubnetGroupMessage.html#structfield.db_subnet_group_description) member.
+    pub fn db_subnet_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.CreateDBSubnetGroupMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.CreateDBSubnetGroupMessage.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateDBSubnetGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateDBSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateDBSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateDBSubnetGroupMessage {
+            db_subnet_group_description: self.db_subnet_group_description.ok_or(
+                ::rusoto_core::BuildError::missing("db_subnet_group_description"),
+            )?,
+            db_subnet_group_name: self
+                .db_subnet_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_subnet_group_name"))?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateDBSubnetGroupMessage` contents to a `SignedRequest`.
 struct CreateDBSubnetGroupMessageSerializer;
 impl CreateDBSubnetGroupMessageSerializer {
@@ -2368,10 +4598,10 @@ pub struct CreateDBSubnetGroupResult {
     pub db_subnet_group: Option<DBSubnetGroup>,
 }
 
-struct CreateDBSubnetGroupResultDeserializer;
+pub(crate) struct CreateDBSubnetGroupResultDeserializer;
 impl CreateDBSubnetGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateDBSubnetGroupResult, XmlParseError> {
@@ -2411,6 +4641,88 @@ pub struct CreateEventSubscriptionMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateEventSubscriptionMessage {
+    /// Returns a builder for `CreateEventSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> CreateEventSubscriptionMessageBuilder {
+        CreateEventSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateEventSubscriptionMessage`](struct.CreateEventSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateEventSubscriptionMessageBuilder {
+    enabled: Option<bool>,
+    event_categories: Option<Vec<String>>,
+    sns_topic_arn: Option<String>,
+    source_ids: Option<Vec<String>>,
+    source_type: Option<String>,
+    subscription_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateEventSubscriptionMessageBuilder {
+    /// Sets the [`enabled`](struct.CreateEventSubscriptionMessage.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_categories`](struct.CreateEventSubscriptionMessage.html#structfield.event_categories) member.
+    pub fn event_categories<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_categories = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.CreateEventSubscriptionMessage.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_ids`](struct.CreateEventSubscriptionMessage.html#structfield.source_ids) member.
+    pub fn source_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.source_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.CreateEventSubscriptionMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.CreateEventSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateEventSubscriptionMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateEventSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateEventSubscriptionMessage, ::rusoto_core::BuildError> {
+        Ok(CreateEventSubscriptionMessage {
+            enabled: self.enabled,
+            event_categories: self.event_categories,
+            sns_topic_arn: self
+                .sns_topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("sns_topic_arn"))?,
+            source_ids: self.source_ids,
+            source_type: self.source_type,
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateEventSubscriptionMessage` contents to a `SignedRequest`.
 struct CreateEventSubscriptionMessageSerializer;
 impl CreateEventSubscriptionMessageSerializer {
@@ -2456,10 +4768,10 @@ pub struct CreateEventSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
 
-struct CreateEventSubscriptionResultDeserializer;
+pub(crate) struct CreateEventSubscriptionResultDeserializer;
 impl CreateEventSubscriptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateEventSubscriptionResult, XmlParseError> {
@@ -2499,6 +4811,84 @@ pub struct CreateGlobalClusterMessage {
     pub storage_encrypted: Option<bool>,
 }
 
+impl CreateGlobalClusterMessage {
+    /// Returns a builder for `CreateGlobalClusterMessage`, checking its required members when built.
+    pub fn builder() -> CreateGlobalClusterMessageBuilder {
+        CreateGlobalClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGlobalClusterMessage`](struct.CreateGlobalClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateGlobalClusterMessageBuilder {
+    database_name: Option<String>,
+    deletion_protection: Option<bool>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    global_cluster_identifier: Option<String>,
+    source_db_cluster_identifier: Option<String>,
+    storage_encrypted: Option<bool>,
+}
+
+impl CreateGlobalClusterMessageBuilder {
+    /// Sets the [`database_name`](struct.CreateGlobalClusterMessage.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.CreateGlobalClusterMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.CreateGlobalClusterMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.CreateGlobalClusterMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`global_cluster_identifier`](struct.CreateGlobalClusterMessage.html#structfield.global_cluster_identifier) member.
+    pub fn global_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.global_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_db_cluster_identifier`](struct.CreateGlobalClusterMessage.html#structfield.source_db_cluster_identifier) member.
+    pub fn source_db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_encrypted`](struct.CreateGlobalClusterMessage.html#structfield.storage_encrypted) member.
+    pub fn storage_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.storage_encrypted = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGlobalClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateGlobalClusterMessage, ::rusoto_core::BuildError> {
+        Ok(CreateGlobalClusterMessage {
+            database_name: self.database_name,
+            deletion_protection: self.deletion_protection,
+            engine: self.engine,
+            engine_version: self.engine_version,
+            global_cluster_identifier: self.global_cluster_identifier,
+            source_db_cluster_identifier: self.source_db_cluster_identifier,
+            storage_encrypted: self.storage_encrypted,
+        })
+    }
+}
+
 /// Serialize `CreateGlobalClusterMessage` contents to a `SignedRequest`.
 struct CreateGlobalClusterMessageSerializer;
 impl CreateGlobalClusterMessageSerializer {
@@ -2543,10 +4933,10 @@ pub struct CreateGlobalClusterResult {
     pub global_cluster: Option<GlobalCluster>,
 }
 
-struct CreateGlobalClusterResultDeserializer;
+pub(crate) struct CreateGlobalClusterResultDeserializer;
 impl CreateGlobalClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateGlobalClusterResult, XmlParseError> {
@@ -2583,6 +4973,76 @@ pub struct CreateOptionGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateOptionGroupMessage {
+    /// Returns a builder for `CreateOptionGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateOptionGroupMessageBuilder {
+        CreateOptionGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateOptionGroupMessage`](struct.CreateOptionGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateOptionGroupMessageBuilder {
+    engine_name: Option<String>,
+    major_engine_version: Option<String>,
+    option_group_description: Option<String>,
+    option_group_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateOptionGroupMessageBuilder {
+    /// Sets the [`engine_name`](struct.CreateOptionGroupMessage.html#structfield.engine_name) member.
+    pub fn engine_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`major_engine_version`](struct.CreateOptionGroupMessage.html#structfield.major_engine_version) member.
+    pub fn major_engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.major_engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_description`](struct.CreateOptionGroupMessage.html#structfield.option_group_description) member.
+    pub fn option_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.CreateOptionGroupMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateOptionGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateOptionGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateOptionGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateOptionGroupMessage {
+            engine_name: self
+                .engine_name
+                .ok_or(::rusoto_core::BuildError::missing("engine_name"))?,
+            major_engine_version: self
+                .major_engine_version
+                .ok_or(::rusoto_core::BuildError::missing("major_engine_version"))?,
+            option_group_description: self.option_group_description.ok_or(
+                ::rusoto_core::BuildError::missing("option_group_description"),
+            )?,
+            option_group_name: self
+                .option_group_name
+                .ok_or(::rusoto_core::BuildError::missing("option_group_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateOptionGroupMessage` contents to a `SignedRequest`.
 struct CreateOptionGroupMessageSerializer;
 impl CreateOptionGroupMessageSerializer {
@@ -2616,10 +5076,10 @@ pub struct CreateOptionGroupResult {
     pub option_group: Option<OptionGroup>,
 }
 
-struct CreateOptionGroupResultDeserializer;
+pub(crate) struct CreateOptionGroupResultDeserializer;
 impl CreateOptionGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateOptionGroupResult, XmlParseError> {
@@ -2647,9 +5107,9 @@ pub struct DBCluster {
     /// <p>The AWS KMS key identifier used for encrypting messages in the database activity stream.</p>
     pub activity_stream_kms_key_id: Option<String>,
     /// <p>The mode of the database activity stream. Database events such as a change or access generate an activity stream event. The database session can handle these events either synchronously or asynchronously. </p>
-    pub activity_stream_mode: Option<String>,
+    pub activity_stream_mode: Option<ActivityStreamMode>,
     /// <p>The status of the database activity stream.</p>
-    pub activity_stream_status: Option<String>,
+    pub activity_stream_status: Option<ActivityStreamStatus>,
     /// <p>For all database engines except Amazon Aurora, <code>AllocatedStorage</code> specifies the allocated storage size in gibibytes (GiB). For Aurora, <code>AllocatedStorage</code> always returns 1, because Aurora DB cluster storage size is not fixed, but instead automatically adjusts as needed.</p>
     pub allocated_storage: Option<i64>,
     /// <p>Provides a list of the AWS Identity and Access Management (IAM) roles that are associated with the DB cluster. IAM roles that are associated with a DB cluster grant permission for the DB cluster to access other AWS services on your behalf.</p>
@@ -2669,7 +5129,7 @@ pub struct DBCluster {
     /// <p>Identifies the clone group to which the DB cluster is associated.</p>
     pub clone_group_id: Option<String>,
     /// <p>Specifies the time when the DB cluster was created, in Universal Coordinated Time (UTC).</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<IsoTimestamp>,
     /// <p>Specifies whether tags are copied from the DB cluster to snapshots of the DB cluster.</p>
     pub copy_tags_to_snapshot: Option<bool>,
     /// <p>Specifies whether the DB cluster is a clone of a DB cluster owned by a different AWS account.</p>
@@ -2695,9 +5155,9 @@ pub struct DBCluster {
     /// <p>Indicates if the DB cluster has deletion protection enabled. The database can't be deleted when deletion protection is enabled. </p>
     pub deletion_protection: Option<bool>,
     /// <p>The earliest time to which a DB cluster can be backtracked.</p>
-    pub earliest_backtrack_time: Option<String>,
+    pub earliest_backtrack_time: Option<IsoTimestamp>,
     /// <p>The earliest time to which a database can be restored with point-in-time restore.</p>
-    pub earliest_restorable_time: Option<String>,
+    pub earliest_restorable_time: Option<IsoTimestamp>,
     /// <p>A list of log types that this DB cluster is configured to export to CloudWatch Logs.</p> <p>Log types vary by DB engine. For information about the log types for each DB engine, see <a href="https://docs.aws.amazon.com/AmazonRDS/latest/AuroraUserGuide/USER_LogAccess.html">Amazon RDS Database Log Files</a> in the <i>Amazon Aurora User Guide.</i> </p>
     pub enabled_cloudwatch_logs_exports: Option<Vec<String>>,
     /// <p>Specifies the connection endpoint for the primary instance of the DB cluster.</p>
@@ -2717,7 +5177,7 @@ pub struct DBCluster {
     /// <p>If <code>StorageEncrypted</code> is enabled, the AWS KMS key identifier for the encrypted DB cluster.</p>
     pub kms_key_id: Option<String>,
     /// <p>Specifies the latest time to which a database can be restored with point-in-time restore.</p>
-    pub latest_restorable_time: Option<String>,
+    pub latest_restorable_time: Option<IsoTimestamp>,
     /// <p>Contains the master username for the DB cluster.</p>
     pub master_username: Option<String>,
     /// <p>Specifies whether the DB cluster has instances in multiple Availability Zones.</p>
@@ -2745,10 +5205,10 @@ pub struct DBCluster {
     pub vpc_security_groups: Option<Vec<VpcSecurityGroupMembership>>,
 }
 
-struct DBClusterDeserializer;
+pub(crate) struct DBClusterDeserializer;
 impl DBClusterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBCluster, XmlParseError> {
@@ -3033,21 +5493,21 @@ pub struct DBClusterBacktrack {
     /// <p>Contains the backtrack identifier.</p>
     pub backtrack_identifier: Option<String>,
     /// <p>The timestamp of the time at which the backtrack was requested.</p>
-    pub backtrack_request_creation_time: Option<String>,
+    pub backtrack_request_creation_time: Option<IsoTimestamp>,
     /// <p>The timestamp of the time to which the DB cluster was backtracked.</p>
-    pub backtrack_to: Option<String>,
+    pub backtrack_to: Option<IsoTimestamp>,
     /// <p>The timestamp of the time from which the DB cluster was backtracked.</p>
-    pub backtracked_from: Option<String>,
+    pub backtracked_from: Option<IsoTimestamp>,
     /// <p>Contains a user-supplied DB cluster identifier. This identifier is the unique key that identifies a DB cluster.</p>
     pub db_cluster_identifier: Option<String>,
     /// <p><p>The status of the backtrack. This property returns one of the following values:</p> <ul> <li> <p> <code>applying</code> - The backtrack is currently being applied to or rolled back from the DB cluster.</p> </li> <li> <p> <code>completed</code> - The backtrack has successfully been applied to or rolled back from the DB cluster.</p> </li> <li> <p> <code>failed</code> - An error occurred while the backtrack was applied to or rolled back from the DB cluster.</p> </li> <li> <p> <code>pending</code> - The backtrack is currently pending application to or rollback from the DB cluster.</p> </li> </ul></p>
     pub status: Option<String>,
 }
 
-struct DBClusterBacktrackDeserializer;
+pub(crate) struct DBClusterBacktrackDeserializer;
 impl DBClusterBacktrackDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterBacktrack, XmlParseError> {
@@ -3087,10 +5547,10 @@ impl DBClusterBacktrackDeserializer {
         })
     }
 }
-struct DBClusterBacktrackListDeserializer;
+pub(crate) struct DBClusterBacktrackListDeserializer;
 impl DBClusterBacktrackListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterBacktrack>, XmlParseError> {
@@ -3116,10 +5576,10 @@ pub struct DBClusterBacktrackMessage {
     pub marker: Option<String>,
 }
 
-struct DBClusterBacktrackMessageDeserializer;
+pub(crate) struct DBClusterBacktrackMessageDeserializer;
 impl DBClusterBacktrackMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterBacktrackMessage, XmlParseError> {
@@ -3160,10 +5620,10 @@ pub struct DBClusterCapacityInfo {
     pub timeout_action: Option<String>,
 }
 
-struct DBClusterCapacityInfoDeserializer;
+pub(crate) struct DBClusterCapacityInfoDeserializer;
 impl DBClusterCapacityInfoDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterCapacityInfo, XmlParseError> {
@@ -3228,10 +5688,10 @@ pub struct DBClusterEndpoint {
     pub status: Option<String>,
 }
 
-struct DBClusterEndpointDeserializer;
+pub(crate) struct DBClusterEndpointDeserializer;
 impl DBClusterEndpointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterEndpoint, XmlParseError> {
@@ -3294,10 +5754,10 @@ impl DBClusterEndpointDeserializer {
         })
     }
 }
-struct DBClusterEndpointListDeserializer;
+pub(crate) struct DBClusterEndpointListDeserializer;
 impl DBClusterEndpointListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterEndpoint>, XmlParseError> {
@@ -3322,10 +5782,10 @@ pub struct DBClusterEndpointMessage {
     pub marker: Option<String>,
 }
 
-struct DBClusterEndpointMessageDeserializer;
+pub(crate) struct DBClusterEndpointMessageDeserializer;
 impl DBClusterEndpointMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterEndpointMessage, XmlParseError> {
@@ -3352,10 +5812,10 @@ impl DBClusterEndpointMessageDeserializer {
         )
     }
 }
-struct DBClusterListDeserializer;
+pub(crate) struct DBClusterListDeserializer;
 impl DBClusterListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBCluster>, XmlParseError> {
@@ -3382,10 +5842,10 @@ pub struct DBClusterMember {
     pub promotion_tier: Option<i64>,
 }
 
-struct DBClusterMemberDeserializer;
+pub(crate) struct DBClusterMemberDeserializer;
 impl DBClusterMemberDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterMember, XmlParseError> {
@@ -3419,10 +5879,10 @@ impl DBClusterMemberDeserializer {
         })
     }
 }
-struct DBClusterMemberListDeserializer;
+pub(crate) struct DBClusterMemberListDeserializer;
 impl DBClusterMemberListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterMember>, XmlParseError> {
@@ -3448,10 +5908,10 @@ pub struct DBClusterMessage {
     pub marker: Option<String>,
 }
 
-struct DBClusterMessageDeserializer;
+pub(crate) struct DBClusterMessageDeserializer;
 impl DBClusterMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterMessage, XmlParseError> {
@@ -3471,10 +5931,10 @@ impl DBClusterMessageDeserializer {
         })
     }
 }
-struct DBClusterOptionGroupMembershipsDeserializer;
+pub(crate) struct DBClusterOptionGroupMembershipsDeserializer;
 impl DBClusterOptionGroupMembershipsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterOptionGroupStatus>, XmlParseError> {
@@ -3500,10 +5960,10 @@ pub struct DBClusterOptionGroupStatus {
     pub status: Option<String>,
 }
 
-struct DBClusterOptionGroupStatusDeserializer;
+pub(crate) struct DBClusterOptionGroupStatusDeserializer;
 impl DBClusterOptionGroupStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterOptionGroupStatus, XmlParseError> {
@@ -3541,10 +6001,10 @@ pub struct DBClusterParameterGroup {
     pub description: Option<String>,
 }
 
-struct DBClusterParameterGroupDeserializer;
+pub(crate) struct DBClusterParameterGroupDeserializer;
 impl DBClusterParameterGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterParameterGroup, XmlParseError> {
@@ -3590,10 +6050,10 @@ pub struct DBClusterParameterGroupDetails {
     pub parameters: Option<Vec<Parameter>>,
 }
 
-struct DBClusterParameterGroupDetailsDeserializer;
+pub(crate) struct DBClusterParameterGroupDetailsDeserializer;
 impl DBClusterParameterGroupDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterParameterGroupDetails, XmlParseError> {
@@ -3617,10 +6077,10 @@ impl DBClusterParameterGroupDetailsDeserializer {
         )
     }
 }
-struct DBClusterParameterGroupListDeserializer;
+pub(crate) struct DBClusterParameterGroupListDeserializer;
 impl DBClusterParameterGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterParameterGroup>, XmlParseError> {
@@ -3644,10 +6104,10 @@ pub struct DBClusterParameterGroupNameMessage {
     pub db_cluster_parameter_group_name: Option<String>,
 }
 
-struct DBClusterParameterGroupNameMessageDeserializer;
+pub(crate) struct DBClusterParameterGroupNameMessageDeserializer;
 impl DBClusterParameterGroupNameMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterParameterGroupNameMessage, XmlParseError> {
@@ -3677,10 +6137,10 @@ pub struct DBClusterParameterGroupsMessage {
     pub marker: Option<String>,
 }
 
-struct DBClusterParameterGroupsMessageDeserializer;
+pub(crate) struct DBClusterParameterGroupsMessageDeserializer;
 impl DBClusterParameterGroupsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterParameterGroupsMessage, XmlParseError> {
@@ -3718,10 +6178,10 @@ pub struct DBClusterRole {
     pub status: Option<String>,
 }
 
-struct DBClusterRoleDeserializer;
+pub(crate) struct DBClusterRoleDeserializer;
 impl DBClusterRoleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterRole, XmlParseError> {
@@ -3742,10 +6202,10 @@ impl DBClusterRoleDeserializer {
         })
     }
 }
-struct DBClusterRolesDeserializer;
+pub(crate) struct DBClusterRolesDeserializer;
 impl DBClusterRolesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterRole>, XmlParseError> {
@@ -3770,7 +6230,7 @@ pub struct DBClusterSnapshot {
     /// <p>Provides the list of Availability Zones (AZs) where instances in the DB cluster snapshot can be restored.</p>
     pub availability_zones: Option<Vec<String>>,
     /// <p>Specifies the time when the DB cluster was created, in Universal Coordinated Time (UTC).</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<IsoTimestamp>,
     /// <p>Specifies the DB cluster identifier of the DB cluster that this DB cluster snapshot was created from.</p>
     pub db_cluster_identifier: Option<String>,
     /// <p>The Amazon Resource Name (ARN) for the DB cluster snapshot.</p>
@@ -3794,7 +6254,7 @@ pub struct DBClusterSnapshot {
     /// <p>Specifies the port that the DB cluster was listening on at the time of the snapshot.</p>
     pub port: Option<i64>,
     /// <p>Provides the time when the snapshot was taken, in Universal Coordinated Time (UTC).</p>
-    pub snapshot_create_time: Option<String>,
+    pub snapshot_create_time: Option<IsoTimestamp>,
     /// <p>Provides the type of the DB cluster snapshot.</p>
     pub snapshot_type: Option<String>,
     /// <p>If the DB cluster snapshot was copied from a source DB cluster snapshot, the Amazon Resource Name (ARN) for the source DB cluster snapshot, otherwise, a null value.</p>
@@ -3807,10 +6267,10 @@ pub struct DBClusterSnapshot {
     pub vpc_id: Option<String>,
 }
 
-struct DBClusterSnapshotDeserializer;
+pub(crate) struct DBClusterSnapshotDeserializer;
 impl DBClusterSnapshotDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterSnapshot, XmlParseError> {
@@ -3920,10 +6380,10 @@ pub struct DBClusterSnapshotAttribute {
     pub attribute_values: Option<Vec<String>>,
 }
 
-struct DBClusterSnapshotAttributeDeserializer;
+pub(crate) struct DBClusterSnapshotAttributeDeserializer;
 impl DBClusterSnapshotAttributeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterSnapshotAttribute, XmlParseError> {
@@ -3948,10 +6408,10 @@ impl DBClusterSnapshotAttributeDeserializer {
         )
     }
 }
-struct DBClusterSnapshotAttributeListDeserializer;
+pub(crate) struct DBClusterSnapshotAttributeListDeserializer;
 impl DBClusterSnapshotAttributeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterSnapshotAttribute>, XmlParseError> {
@@ -3977,10 +6437,10 @@ pub struct DBClusterSnapshotAttributesResult {
     pub db_cluster_snapshot_identifier: Option<String>,
 }
 
-struct DBClusterSnapshotAttributesResultDeserializer;
+pub(crate) struct DBClusterSnapshotAttributesResultDeserializer;
 impl DBClusterSnapshotAttributesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterSnapshotAttributesResult, XmlParseError> {
@@ -4010,10 +6470,10 @@ impl DBClusterSnapshotAttributesResultDeserializer {
         )
     }
 }
-struct DBClusterSnapshotListDeserializer;
+pub(crate) struct DBClusterSnapshotListDeserializer;
 impl DBClusterSnapshotListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBClusterSnapshot>, XmlParseError> {
@@ -4039,10 +6499,10 @@ pub struct DBClusterSnapshotMessage {
     pub marker: Option<String>,
 }
 
-struct DBClusterSnapshotMessageDeserializer;
+pub(crate) struct DBClusterSnapshotMessageDeserializer;
 impl DBClusterSnapshotMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBClusterSnapshotMessage, XmlParseError> {
@@ -4104,10 +6564,10 @@ pub struct DBEngineVersion {
     pub valid_upgrade_target: Option<Vec<UpgradeTarget>>,
 }
 
-struct DBEngineVersionDeserializer;
+pub(crate) struct DBEngineVersionDeserializer;
 impl DBEngineVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBEngineVersion, XmlParseError> {
@@ -4205,10 +6665,10 @@ impl DBEngineVersionDeserializer {
         })
     }
 }
-struct DBEngineVersionListDeserializer;
+pub(crate) struct DBEngineVersionListDeserializer;
 impl DBEngineVersionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBEngineVersion>, XmlParseError> {
@@ -4234,10 +6694,10 @@ pub struct DBEngineVersionMessage {
     pub marker: Option<String>,
 }
 
-struct DBEngineVersionMessageDeserializer;
+pub(crate) struct DBEngineVersionMessageDeserializer;
 impl DBEngineVersionMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBEngineVersionMessage, XmlParseError> {
@@ -4315,13 +6775,13 @@ pub struct DBInstance {
     /// <p><p>True if mapping of AWS Identity and Access Management (IAM) accounts to database accounts is enabled, and otherwise false.</p> <p>IAM database authentication can be enabled for the following database engines</p> <ul> <li> <p>For MySQL 5.6, minor version 5.6.34 or higher</p> </li> <li> <p>For MySQL 5.7, minor version 5.7.16 or higher</p> </li> <li> <p>Aurora 5.6 or higher. To enable IAM database authentication for Aurora, see DBCluster Type.</p> </li> </ul></p>
     pub iam_database_authentication_enabled: Option<bool>,
     /// <p>Provides the date and time the DB instance was created.</p>
-    pub instance_create_time: Option<String>,
+    pub instance_create_time: Option<IsoTimestamp>,
     /// <p>Specifies the Provisioned IOPS (I/O operations per second) value.</p>
     pub iops: Option<i64>,
     /// <p> If <code>StorageEncrypted</code> is true, the AWS KMS key identifier for the encrypted DB instance. </p>
     pub kms_key_id: Option<String>,
     /// <p>Specifies the latest time to which a database can be restored with point-in-time restore.</p>
-    pub latest_restorable_time: Option<String>,
+    pub latest_restorable_time: Option<IsoTimestamp>,
     /// <p>License model information for this DB instance.</p>
     pub license_model: Option<String>,
     /// <p>Specifies the listener connection endpoint for SQL Server Always On.</p>
@@ -4378,10 +6838,10 @@ pub struct DBInstance {
     pub vpc_security_groups: Option<Vec<VpcSecurityGroupMembership>>,
 }
 
-struct DBInstanceDeserializer;
+pub(crate) struct DBInstanceDeserializer;
 impl DBInstanceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBInstance, XmlParseError> {
@@ -4728,7 +7188,7 @@ pub struct DBInstanceAutomatedBackup {
     /// <p>True if mapping of AWS Identity and Access Management (IAM) accounts to database accounts is enabled, and otherwise false.</p>
     pub iam_database_authentication_enabled: Option<bool>,
     /// <p>Provides the date and time that the DB instance was created. </p>
-    pub instance_create_time: Option<String>,
+    pub instance_create_time: Option<IsoTimestamp>,
     /// <p>The IOPS (I/O operations per second) value for the automated backup. </p>
     pub iops: Option<i64>,
     /// <p>The AWS KMS key ID for an automated backup. The KMS key ID is the Amazon Resource Name (ARN), KMS key identifier, or the KMS key alias for the KMS encryption key. </p>
@@ -4757,10 +7217,10 @@ pub struct DBInstanceAutomatedBackup {
     pub vpc_id: Option<String>,
 }
 
-struct DBInstanceAutomatedBackupDeserializer;
+pub(crate) struct DBInstanceAutomatedBackupDeserializer;
 impl DBInstanceAutomatedBackupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBInstanceAutomatedBackup, XmlParseError> {
@@ -4868,10 +7328,10 @@ impl DBInstanceAutomatedBackupDeserializer {
         )
     }
 }
-struct DBInstanceAutomatedBackupListDeserializer;
+pub(crate) struct DBInstanceAutomatedBackupListDeserializer;
 impl DBInstanceAutomatedBackupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBInstanceAutomatedBackup>, XmlParseError> {
@@ -4897,10 +7357,10 @@ pub struct DBInstanceAutomatedBackupMessage {
     pub marker: Option<String>,
 }
 
-struct DBInstanceAutomatedBackupMessageDeserializer;
+pub(crate) struct DBInstanceAutomatedBackupMessageDeserializer;
 impl DBInstanceAutomatedBackupMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBInstanceAutomatedBackupMessage, XmlParseError> {
@@ -4927,10 +7387,10 @@ impl DBInstanceAutomatedBackupMessageDeserializer {
         )
     }
 }
-struct DBInstanceListDeserializer;
+pub(crate) struct DBInstanceListDeserializer;
 impl DBInstanceListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBInstance>, XmlParseError> {
@@ -4953,10 +7413,10 @@ pub struct DBInstanceMessage {
     pub marker: Option<String>,
 }
 
-struct DBInstanceMessageDeserializer;
+pub(crate) struct DBInstanceMessageDeserializer;
 impl DBInstanceMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBInstanceMessage, XmlParseError> {
@@ -4987,10 +7447,10 @@ pub struct DBInstanceRole {
     pub status: Option<String>,
 }
 
-struct DBInstanceRoleDeserializer;
+pub(crate) struct DBInstanceRoleDeserializer;
 impl DBInstanceRoleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBInstanceRole, XmlParseError> {
@@ -5011,10 +7471,10 @@ impl DBInstanceRoleDeserializer {
         })
     }
 }
-struct DBInstanceRolesDeserializer;
+pub(crate) struct DBInstanceRolesDeserializer;
 impl DBInstanceRolesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBInstanceRole>, XmlParseError> {
@@ -5044,10 +7504,10 @@ pub struct DBInstanceStatusInfo {
     pub status_type: Option<String>,
 }
 
-struct DBInstanceStatusInfoDeserializer;
+pub(crate) struct DBInstanceStatusInfoDeserializer;
 impl DBInstanceStatusInfoDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBInstanceStatusInfo, XmlParseError> {
@@ -5071,10 +7531,10 @@ impl DBInstanceStatusInfoDeserializer {
         })
     }
 }
-struct DBInstanceStatusInfoListDeserializer;
+pub(crate) struct DBInstanceStatusInfoListDeserializer;
 impl DBInstanceStatusInfoListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBInstanceStatusInfo>, XmlParseError> {
@@ -5104,10 +7564,10 @@ pub struct DBParameterGroup {
     pub description: Option<String>,
 }
 
-struct DBParameterGroupDeserializer;
+pub(crate) struct DBParameterGroupDeserializer;
 impl DBParameterGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBParameterGroup, XmlParseError> {
@@ -5149,10 +7609,10 @@ pub struct DBParameterGroupDetails {
     pub parameters: Option<Vec<Parameter>>,
 }
 
-struct DBParameterGroupDetailsDeserializer;
+pub(crate) struct DBParameterGroupDetailsDeserializer;
 impl DBParameterGroupDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBParameterGroupDetails, XmlParseError> {
@@ -5176,10 +7636,10 @@ impl DBParameterGroupDetailsDeserializer {
         )
     }
 }
-struct DBParameterGroupListDeserializer;
+pub(crate) struct DBParameterGroupListDeserializer;
 impl DBParameterGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBParameterGroup>, XmlParseError> {
@@ -5203,10 +7663,10 @@ pub struct DBParameterGroupNameMessage {
     pub db_parameter_group_name: Option<String>,
 }
 
-struct DBParameterGroupNameMessageDeserializer;
+pub(crate) struct DBParameterGroupNameMessageDeserializer;
 impl DBParameterGroupNameMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBParameterGroupNameMessage, XmlParseError> {
@@ -5237,10 +7697,10 @@ pub struct DBParameterGroupStatus {
     pub parameter_apply_status: Option<String>,
 }
 
-struct DBParameterGroupStatusDeserializer;
+pub(crate) struct DBParameterGroupStatusDeserializer;
 impl DBParameterGroupStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBParameterGroupStatus, XmlParseError> {
@@ -5264,10 +7724,10 @@ impl DBParameterGroupStatusDeserializer {
         })
     }
 }
-struct DBParameterGroupStatusListDeserializer;
+pub(crate) struct DBParameterGroupStatusListDeserializer;
 impl DBParameterGroupStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBParameterGroupStatus>, XmlParseError> {
@@ -5293,10 +7753,10 @@ pub struct DBParameterGroupsMessage {
     pub marker: Option<String>,
 }
 
-struct DBParameterGroupsMessageDeserializer;
+pub(crate) struct DBParameterGroupsMessageDeserializer;
 impl DBParameterGroupsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBParameterGroupsMessage, XmlParseError> {
@@ -5342,10 +7802,10 @@ pub struct DBSecurityGroup {
     pub vpc_id: Option<String>,
 }
 
-struct DBSecurityGroupDeserializer;
+pub(crate) struct DBSecurityGroupDeserializer;
 impl DBSecurityGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSecurityGroup, XmlParseError> {
@@ -5400,10 +7860,10 @@ pub struct DBSecurityGroupMembership {
     pub status: Option<String>,
 }
 
-struct DBSecurityGroupMembershipDeserializer;
+pub(crate) struct DBSecurityGroupMembershipDeserializer;
 impl DBSecurityGroupMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSecurityGroupMembership, XmlParseError> {
@@ -5428,10 +7888,10 @@ impl DBSecurityGroupMembershipDeserializer {
         )
     }
 }
-struct DBSecurityGroupMembershipListDeserializer;
+pub(crate) struct DBSecurityGroupMembershipListDeserializer;
 impl DBSecurityGroupMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBSecurityGroupMembership>, XmlParseError> {
@@ -5457,10 +7917,10 @@ pub struct DBSecurityGroupMessage {
     pub marker: Option<String>,
 }
 
-struct DBSecurityGroupMessageDeserializer;
+pub(crate) struct DBSecurityGroupMessageDeserializer;
 impl DBSecurityGroupMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSecurityGroupMessage, XmlParseError> {
@@ -5492,10 +7952,10 @@ impl DBSecurityGroupNameListSerializer {
     }
 }
 
-struct DBSecurityGroupsDeserializer;
+pub(crate) struct DBSecurityGroupsDeserializer;
 impl DBSecurityGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBSecurityGroup>, XmlParseError> {
@@ -5536,7 +7996,7 @@ pub struct DBSnapshot {
     /// <p>True if mapping of AWS Identity and Access Management (IAM) accounts to database accounts is enabled, and otherwise false.</p>
     pub iam_database_authentication_enabled: Option<bool>,
     /// <p>Specifies the time when the snapshot was taken, in Universal Coordinated Time (UTC).</p>
-    pub instance_create_time: Option<String>,
+    pub instance_create_time: Option<IsoTimestamp>,
     /// <p>Specifies the Provisioned IOPS (I/O operations per second) value of the DB instance at the time of the snapshot.</p>
     pub iops: Option<i64>,
     /// <p> If <code>Encrypted</code> is true, the AWS KMS key identifier for the encrypted DB snapshot. </p>
@@ -5554,7 +8014,7 @@ pub struct DBSnapshot {
     /// <p>The number of CPU cores and the number of threads per core for the DB instance class of the DB instance when the DB snapshot was created.</p>
     pub processor_features: Option<Vec<ProcessorFeature>>,
     /// <p>Provides the time when the snapshot was taken, in Universal Coordinated Time (UTC).</p>
-    pub snapshot_create_time: Option<String>,
+    pub snapshot_create_time: Option<IsoTimestamp>,
     /// <p>Provides the type of the DB snapshot.</p>
     pub snapshot_type: Option<String>,
     /// <p>The DB snapshot Amazon Resource Name (ARN) that the DB snapshot was copied from. It only has value in case of cross-customer or cross-region copy.</p>
@@ -5573,10 +8033,10 @@ pub struct DBSnapshot {
     pub vpc_id: Option<String>,
 }
 
-struct DBSnapshotDeserializer;
+pub(crate) struct DBSnapshotDeserializer;
 impl DBSnapshotDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSnapshot, XmlParseError> {
@@ -5714,10 +8174,10 @@ pub struct DBSnapshotAttribute {
     pub attribute_values: Option<Vec<String>>,
 }
 
-struct DBSnapshotAttributeDeserializer;
+pub(crate) struct DBSnapshotAttributeDeserializer;
 impl DBSnapshotAttributeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSnapshotAttribute, XmlParseError> {
@@ -5738,10 +8198,10 @@ impl DBSnapshotAttributeDeserializer {
         })
     }
 }
-struct DBSnapshotAttributeListDeserializer;
+pub(crate) struct DBSnapshotAttributeListDeserializer;
 impl DBSnapshotAttributeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBSnapshotAttribute>, XmlParseError> {
@@ -5767,10 +8227,10 @@ pub struct DBSnapshotAttributesResult {
     pub db_snapshot_identifier: Option<String>,
 }
 
-struct DBSnapshotAttributesResultDeserializer;
+pub(crate) struct DBSnapshotAttributesResultDeserializer;
 impl DBSnapshotAttributesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSnapshotAttributesResult, XmlParseError> {
@@ -5800,10 +8260,10 @@ impl DBSnapshotAttributesResultDeserializer {
         )
     }
 }
-struct DBSnapshotListDeserializer;
+pub(crate) struct DBSnapshotListDeserializer;
 impl DBSnapshotListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBSnapshot>, XmlParseError> {
@@ -5826,10 +8286,10 @@ pub struct DBSnapshotMessage {
     pub marker: Option<String>,
 }
 
-struct DBSnapshotMessageDeserializer;
+pub(crate) struct DBSnapshotMessageDeserializer;
 impl DBSnapshotMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSnapshotMessage, XmlParseError> {
@@ -5866,10 +8326,10 @@ pub struct DBSubnetGroup {
     pub vpc_id: Option<String>,
 }
 
-struct DBSubnetGroupDeserializer;
+pub(crate) struct DBSubnetGroupDeserializer;
 impl DBSubnetGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSubnetGroup, XmlParseError> {
@@ -5916,10 +8376,10 @@ pub struct DBSubnetGroupMessage {
     pub marker: Option<String>,
 }
 
-struct DBSubnetGroupMessageDeserializer;
+pub(crate) struct DBSubnetGroupMessageDeserializer;
 impl DBSubnetGroupMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DBSubnetGroupMessage, XmlParseError> {
@@ -5939,10 +8399,10 @@ impl DBSubnetGroupMessageDeserializer {
         })
     }
 }
-struct DBSubnetGroupsDeserializer;
+pub(crate) struct DBSubnetGroupsDeserializer;
 impl DBSubnetGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DBSubnetGroup>, XmlParseError> {
@@ -5965,6 +8425,38 @@ pub struct DeleteDBClusterEndpointMessage {
     pub db_cluster_endpoint_identifier: String,
 }
 
+impl DeleteDBClusterEndpointMessage {
+    /// Returns a builder for `DeleteDBClusterEndpointMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBClusterEndpointMessageBuilder {
+        DeleteDBClusterEndpointMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBClusterEndpointMessage`](struct.DeleteDBClusterEndpointMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBClusterEndpointMessageBuilder {
+    db_cluster_endpoint_identifier: Option<String>,
+}
+
+impl DeleteDBClusterEndpointMessageBuilder {
+    /// Sets the [`db_cluster_endpoint_identifier`](struct.DeleteDBClusterEndpointMessage.html#structfield.db_cluster_endpoint_identifier) member.
+    pub fn db_cluster_endpoint_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_endpoint_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBClusterEndpointMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBClusterEndpointMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBClusterEndpointMessage {
+            db_cluster_endpoint_identifier: self.db_cluster_endpoint_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_endpoint_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBClusterEndpointMessage` contents to a `SignedRequest`.
 struct DeleteDBClusterEndpointMessageSerializer;
 impl DeleteDBClusterEndpointMessageSerializer {
@@ -5992,6 +8484,52 @@ pub struct DeleteDBClusterMessage {
     pub skip_final_snapshot: Option<bool>,
 }
 
+impl DeleteDBClusterMessage {
+    /// Returns a builder for `DeleteDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBClusterMessageBuilder {
+        DeleteDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBClusterMessage`](struct.DeleteDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    final_db_snapshot_identifier: Option<String>,
+    skip_final_snapshot: Option<bool>,
+}
+
+impl DeleteDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.DeleteDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`final_db_snapshot_identifier`](struct.DeleteDBClusterMessage.html#structfield.final_db_snapshot_identifier) member.
+    pub fn final_db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.final_db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skip_final_snapshot`](struct.DeleteDBClusterMessage.html#structfield.skip_final_snapshot) member.
+    pub fn skip_final_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.skip_final_snapshot = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            final_db_snapshot_identifier: self.final_db_snapshot_identifier,
+            skip_final_snapshot: self.skip_final_snapshot,
+        })
+    }
+}
+
 /// Serialize `DeleteDBClusterMessage` contents to a `SignedRequest`.
 struct DeleteDBClusterMessageSerializer;
 impl DeleteDBClusterMessageSerializer {
@@ -6024,6 +8562,39 @@ pub struct DeleteDBClusterParameterGroupMessage {
     pub db_cluster_parameter_group_name: String,
 }
 
+impl DeleteDBClusterParameterGroupMessage {
+    /// Returns a builder for `DeleteDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBClusterParameterGroupMessageBuilder {
+        DeleteDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBClusterParameterGroupMessage`](struct.DeleteDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBClusterParameterGroupMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+}
+
+impl DeleteDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.DeleteDBClusterParameterGroupMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBClusterParameterGroupMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteDBClusterParameterGroupMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct DeleteDBClusterParameterGroupMessageSerializer;
 impl DeleteDBClusterParameterGroupMessageSerializer {
@@ -6045,10 +8616,10 @@ pub struct DeleteDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct DeleteDBClusterResultDeserializer;
+pub(crate) struct DeleteDBClusterResultDeserializer;
 impl DeleteDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDBClusterResult, XmlParseError> {
@@ -6070,6 +8641,38 @@ pub struct DeleteDBClusterSnapshotMessage {
     pub db_cluster_snapshot_identifier: String,
 }
 
+impl DeleteDBClusterSnapshotMessage {
+    /// Returns a builder for `DeleteDBClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBClusterSnapshotMessageBuilder {
+        DeleteDBClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBClusterSnapshotMessage`](struct.DeleteDBClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBClusterSnapshotMessageBuilder {
+    db_cluster_snapshot_identifier: Option<String>,
+}
+
+impl DeleteDBClusterSnapshotMessageBuilder {
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.DeleteDBClusterSnapshotMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBClusterSnapshotMessage {
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_snapshot_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBClusterSnapshotMessage` contents to a `SignedRequest`.
 struct DeleteDBClusterSnapshotMessageSerializer;
 impl DeleteDBClusterSnapshotMessageSerializer {
@@ -6091,10 +8694,10 @@ pub struct DeleteDBClusterSnapshotResult {
     pub db_cluster_snapshot: Option<DBClusterSnapshot>,
 }
 
-struct DeleteDBClusterSnapshotResultDeserializer;
+pub(crate) struct DeleteDBClusterSnapshotResultDeserializer;
 impl DeleteDBClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDBClusterSnapshotResult, XmlParseError> {
@@ -6123,14 +8726,47 @@ pub struct DeleteDBInstanceAutomatedBackupMessage {
     pub dbi_resource_id: String,
 }
 
-/// Serialize `DeleteDBInstanceAutomatedBackupMessage` contents to a `SignedRequest`.
-struct DeleteDBInstanceAutomatedBackupMessageSerializer;
-impl DeleteDBInstanceAutomatedBackupMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &DeleteDBInstanceAutomatedBackupMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl DeleteDBInstanceAutomatedBackupMessage {
+    /// Returns a builder for `DeleteDBInstanceAutomatedBackupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBInstanceAutomatedBackupMessageBuilder {
+        DeleteDBInstanceAutomatedBackupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBInstanceAutomatedBackupMessage`](struct.DeleteDBInstanceAutomatedBackupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBInstanceAutomatedBackupMessageBuilder {
+    dbi_resource_id: Option<String>,
+}
+
+impl DeleteDBInstanceAutomatedBackupMessageBuilder {
+    /// Sets the [`dbi_resource_id`](struct.DeleteDBInstanceAutomatedBackupMessage.html#structfield.dbi_resource_id) member.
+    pub fn dbi_resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.dbi_resource_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBInstanceAutomatedBackupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBInstanceAutomatedBackupMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DeleteDBInstanceAutomatedBackupMessage {
+            dbi_resource_id: self
+                .dbi_resource_id
+                .ok_or(::rusoto_core::BuildError::missing("dbi_resource_id"))?,
+        })
+    }
+}
+
+/// Serialize `DeleteDBInstanceAutomatedBackupMessage` contents to a `SignedRequest`.
+struct DeleteDBInstanceAutomatedBackupMessageSerializer;
+impl DeleteDBInstanceAutomatedBackupMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &DeleteDBInstanceAutomatedBackupMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
 
         params.put(
             &format!("{}{}", prefix, "DbiResourceId"),
@@ -6144,10 +8780,10 @@ pub struct DeleteDBInstanceAutomatedBackupResult {
     pub db_instance_automated_backup: Option<DBInstanceAutomatedBackup>,
 }
 
-struct DeleteDBInstanceAutomatedBackupResultDeserializer;
+pub(crate) struct DeleteDBInstanceAutomatedBackupResultDeserializer;
 impl DeleteDBInstanceAutomatedBackupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDBInstanceAutomatedBackupResult, XmlParseError> {
@@ -6183,6 +8819,62 @@ pub struct DeleteDBInstanceMessage {
     pub skip_final_snapshot: Option<bool>,
 }
 
+impl DeleteDBInstanceMessage {
+    /// Returns a builder for `DeleteDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBInstanceMessageBuilder {
+        DeleteDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBInstanceMessage`](struct.DeleteDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBInstanceMessageBuilder {
+    db_instance_identifier: Option<String>,
+    delete_automated_backups: Option<bool>,
+    final_db_snapshot_identifier: Option<String>,
+    skip_final_snapshot: Option<bool>,
+}
+
+impl DeleteDBInstanceMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.DeleteDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`delete_automated_backups`](struct.DeleteDBInstanceMessage.html#structfield.delete_automated_backups) member.
+    pub fn delete_automated_backups<T: Into<bool>>(mut self, value: T) -> Self {
+        self.delete_automated_backups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`final_db_snapshot_identifier`](struct.DeleteDBInstanceMessage.html#structfield.final_db_snapshot_identifier) member.
+    pub fn final_db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.final_db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skip_final_snapshot`](struct.DeleteDBInstanceMessage.html#structfield.skip_final_snapshot) member.
+    pub fn skip_final_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.skip_final_snapshot = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBInstanceMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            delete_automated_backups: self.delete_automated_backups,
+            final_db_snapshot_identifier: self.final_db_snapshot_identifier,
+            skip_final_snapshot: self.skip_final_snapshot,
+        })
+    }
+}
+
 /// Serialize `DeleteDBInstanceMessage` contents to a `SignedRequest`.
 struct DeleteDBInstanceMessageSerializer;
 impl DeleteDBInstanceMessageSerializer {
@@ -6219,10 +8911,10 @@ pub struct DeleteDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct DeleteDBInstanceResultDeserializer;
+pub(crate) struct DeleteDBInstanceResultDeserializer;
 impl DeleteDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDBInstanceResult, XmlParseError> {
@@ -6245,6 +8937,38 @@ pub struct DeleteDBParameterGroupMessage {
     pub db_parameter_group_name: String,
 }
 
+impl DeleteDBParameterGroupMessage {
+    /// Returns a builder for `DeleteDBParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBParameterGroupMessageBuilder {
+        DeleteDBParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBParameterGroupMessage`](struct.DeleteDBParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBParameterGroupMessageBuilder {
+    db_parameter_group_name: Option<String>,
+}
+
+impl DeleteDBParameterGroupMessageBuilder {
+    /// Sets the [`db_parameter_group_name`](struct.DeleteDBParameterGroupMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBParameterGroupMessage {
+            db_parameter_group_name: self.db_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBParameterGroupMessage` contents to a `SignedRequest`.
 struct DeleteDBParameterGroupMessageSerializer;
 impl DeleteDBParameterGroupMessageSerializer {
@@ -6268,6 +8992,38 @@ pub struct DeleteDBSecurityGroupMessage {
     pub db_security_group_name: String,
 }
 
+impl DeleteDBSecurityGroupMessage {
+    /// Returns a builder for `DeleteDBSecurityGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBSecurityGroupMessageBuilder {
+        DeleteDBSecurityGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBSecurityGroupMessage`](struct.DeleteDBSecurityGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBSecurityGroupMessageBuilder {
+    db_security_group_name: Option<String>,
+}
+
+impl DeleteDBSecurityGroupMessageBuilder {
+    /// Sets the [`db_security_group_name`](struct.DeleteDBSecurityGroupMessage.html#structfield.db_security_group_name) member.
+    pub fn db_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBSecurityGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBSecurityGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBSecurityGroupMessage {
+            db_security_group_name: self
+                .db_security_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_security_group_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBSecurityGroupMessage` contents to a `SignedRequest`.
 struct DeleteDBSecurityGroupMessageSerializer;
 impl DeleteDBSecurityGroupMessageSerializer {
@@ -6291,6 +9047,38 @@ pub struct DeleteDBSnapshotMessage {
     pub db_snapshot_identifier: String,
 }
 
+impl DeleteDBSnapshotMessage {
+    /// Returns a builder for `DeleteDBSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBSnapshotMessageBuilder {
+        DeleteDBSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBSnapshotMessage`](struct.DeleteDBSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBSnapshotMessageBuilder {
+    db_snapshot_identifier: Option<String>,
+}
+
+impl DeleteDBSnapshotMessageBuilder {
+    /// Sets the [`db_snapshot_identifier`](struct.DeleteDBSnapshotMessage.html#structfield.db_snapshot_identifier) member.
+    pub fn db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBSnapshotMessage {
+            db_snapshot_identifier: self
+                .db_snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_snapshot_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBSnapshotMessage` contents to a `SignedRequest`.
 struct DeleteDBSnapshotMessageSerializer;
 impl DeleteDBSnapshotMessageSerializer {
@@ -6312,10 +9100,10 @@ pub struct DeleteDBSnapshotResult {
     pub db_snapshot: Option<DBSnapshot>,
 }
 
-struct DeleteDBSnapshotResultDeserializer;
+pub(crate) struct DeleteDBSnapshotResultDeserializer;
 impl DeleteDBSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteDBSnapshotResult, XmlParseError> {
@@ -6338,6 +9126,38 @@ pub struct DeleteDBSubnetGroupMessage {
     pub db_subnet_group_name: String,
 }
 
+impl DeleteDBSubnetGroupMessage {
+    /// Returns a builder for `DeleteDBSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteDBSubnetGroupMessageBuilder {
+        DeleteDBSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteDBSubnetGroupMessage`](struct.DeleteDBSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteDBSubnetGroupMessageBuilder {
+    db_subnet_group_name: Option<String>,
+}
+
+impl DeleteDBSubnetGroupMessageBuilder {
+    /// Sets the [`db_subnet_group_name`](struct.DeleteDBSubnetGroupMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteDBSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteDBSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteDBSubnetGroupMessage {
+            db_subnet_group_name: self
+                .db_subnet_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_subnet_group_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteDBSubnetGroupMessage` contents to a `SignedRequest`.
 struct DeleteDBSubnetGroupMessageSerializer;
 impl DeleteDBSubnetGroupMessageSerializer {
@@ -6361,6 +9181,38 @@ pub struct DeleteEventSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl DeleteEventSubscriptionMessage {
+    /// Returns a builder for `DeleteEventSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> DeleteEventSubscriptionMessageBuilder {
+        DeleteEventSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEventSubscriptionMessage`](struct.DeleteEventSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteEventSubscriptionMessageBuilder {
+    subscription_name: Option<String>,
+}
+
+impl DeleteEventSubscriptionMessageBuilder {
+    /// Sets the [`subscription_name`](struct.DeleteEventSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEventSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteEventSubscriptionMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteEventSubscriptionMessage {
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteEventSubscriptionMessage` contents to a `SignedRequest`.
 struct DeleteEventSubscriptionMessageSerializer;
 impl DeleteEventSubscriptionMessageSerializer {
@@ -6382,10 +9234,10 @@ pub struct DeleteEventSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
 
-struct DeleteEventSubscriptionResultDeserializer;
+pub(crate) struct DeleteEventSubscriptionResultDeserializer;
 impl DeleteEventSubscriptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteEventSubscriptionResult, XmlParseError> {
@@ -6413,6 +9265,38 @@ pub struct DeleteGlobalClusterMessage {
     pub global_cluster_identifier: String,
 }
 
+impl DeleteGlobalClusterMessage {
+    /// Returns a builder for `DeleteGlobalClusterMessage`, checking its required members when built.
+    pub fn builder() -> DeleteGlobalClusterMessageBuilder {
+        DeleteGlobalClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGlobalClusterMessage`](struct.DeleteGlobalClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteGlobalClusterMessageBuilder {
+    global_cluster_identifier: Option<String>,
+}
+
+impl DeleteGlobalClusterMessageBuilder {
+    /// Sets the [`global_cluster_identifier`](struct.DeleteGlobalClusterMessage.html#structfield.global_cluster_identifier) member.
+    pub fn global_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.global_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGlobalClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteGlobalClusterMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteGlobalClusterMessage {
+            global_cluster_identifier: self.global_cluster_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("global_cluster_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteGlobalClusterMessage` contents to a `SignedRequest`.
 struct DeleteGlobalClusterMessageSerializer;
 impl DeleteGlobalClusterMessageSerializer {
@@ -6434,10 +9318,10 @@ pub struct DeleteGlobalClusterResult {
     pub global_cluster: Option<GlobalCluster>,
 }
 
-struct DeleteGlobalClusterResultDeserializer;
+pub(crate) struct DeleteGlobalClusterResultDeserializer;
 impl DeleteGlobalClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteGlobalClusterResult, XmlParseError> {
@@ -6466,6 +9350,38 @@ pub struct DeleteOptionGroupMessage {
     pub option_group_name: String,
 }
 
+impl DeleteOptionGroupMessage {
+    /// Returns a builder for `DeleteOptionGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteOptionGroupMessageBuilder {
+        DeleteOptionGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteOptionGroupMessage`](struct.DeleteOptionGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteOptionGroupMessageBuilder {
+    option_group_name: Option<String>,
+}
+
+impl DeleteOptionGroupMessageBuilder {
+    /// Sets the [`option_group_name`](struct.DeleteOptionGroupMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteOptionGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteOptionGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteOptionGroupMessage {
+            option_group_name: self
+                .option_group_name
+                .ok_or(::rusoto_core::BuildError::missing("option_group_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteOptionGroupMessage` contents to a `SignedRequest`.
 struct DeleteOptionGroupMessageSerializer;
 impl DeleteOptionGroupMessageSerializer {
@@ -6486,6 +9402,26 @@ impl DeleteOptionGroupMessageSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DescribeAccountAttributesMessage {}
 
+impl DescribeAccountAttributesMessage {
+    /// Returns a builder for `DescribeAccountAttributesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeAccountAttributesMessageBuilder {
+        DescribeAccountAttributesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAccountAttributesMessage`](struct.DescribeAccountAttributesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeAccountAttributesMessageBuilder {}
+
+impl DescribeAccountAttributesMessageBuilder {
+    /// Builds the `DescribeAccountAttributesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAccountAttributesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeAccountAttributesMessage {})
+    }
+}
+
 /// Serialize `DescribeAccountAttributesMessage` contents to a `SignedRequest`.
 struct DescribeAccountAttributesMessageSerializer;
 impl DescribeAccountAttributesMessageSerializer {
@@ -6510,6 +9446,60 @@ pub struct DescribeCertificatesMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeCertificatesMessage {
+    /// Returns a builder for `DescribeCertificatesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeCertificatesMessageBuilder {
+        DescribeCertificatesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCertificatesMessage`](struct.DescribeCertificatesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeCertificatesMessageBuilder {
+    certificate_identifier: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeCertificatesMessageBuilder {
+    /// Sets the [`certificate_identifier`](struct.DescribeCertificatesMessage.html#structfield.certificate_identifier) member.
+    pub fn certificate_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.certificate_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeCertificatesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeCertificatesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeCertificatesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCertificatesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCertificatesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeCertificatesMessage {
+            certificate_identifier: self.certificate_identifier,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeCertificatesMessage` contents to a `SignedRequest`.
 struct DescribeCertificatesMessageSerializer;
 impl DescribeCertificatesMessageSerializer {
@@ -6556,6 +9546,70 @@ pub struct DescribeDBClusterBacktracksMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBClusterBacktracksMessage {
+    /// Returns a builder for `DescribeDBClusterBacktracksMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterBacktracksMessageBuilder {
+        DescribeDBClusterBacktracksMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterBacktracksMessage`](struct.DescribeDBClusterBacktracksMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterBacktracksMessageBuilder {
+    backtrack_identifier: Option<String>,
+    db_cluster_identifier: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBClusterBacktracksMessageBuilder {
+    /// Sets the [`backtrack_identifier`](struct.DescribeDBClusterBacktracksMessage.html#structfield.backtrack_identifier) member.
+    pub fn backtrack_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.backtrack_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.DescribeDBClusterBacktracksMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClusterBacktracksMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClusterBacktracksMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClusterBacktracksMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterBacktracksMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterBacktracksMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBClusterBacktracksMessage {
+            backtrack_identifier: self.backtrack_identifier,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterBacktracksMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterBacktracksMessageSerializer;
 impl DescribeDBClusterBacktracksMessageSerializer {
@@ -6605,6 +9659,68 @@ pub struct DescribeDBClusterEndpointsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBClusterEndpointsMessage {
+    /// Returns a builder for `DescribeDBClusterEndpointsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterEndpointsMessageBuilder {
+        DescribeDBClusterEndpointsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterEndpointsMessage`](struct.DescribeDBClusterEndpointsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterEndpointsMessageBuilder {
+    db_cluster_endpoint_identifier: Option<String>,
+    db_cluster_identifier: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBClusterEndpointsMessageBuilder {
+    /// Sets the [`db_cluster_endpoint_identifier`](struct.DescribeDBClusterEndpointsMessage.html#structfield.db_cluster_endpoint_identifier) member.
+    pub fn db_cluster_endpoint_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_endpoint_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.DescribeDBClusterEndpointsMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClusterEndpointsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClusterEndpointsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClusterEndpointsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterEndpointsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterEndpointsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBClusterEndpointsMessage {
+            db_cluster_endpoint_identifier: self.db_cluster_endpoint_identifier,
+            db_cluster_identifier: self.db_cluster_identifier,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterEndpointsMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterEndpointsMessageSerializer;
 impl DescribeDBClusterEndpointsMessageSerializer {
@@ -6655,6 +9771,61 @@ pub struct DescribeDBClusterParameterGroupsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBClusterParameterGroupsMessage {
+    /// Returns a builder for `DescribeDBClusterParameterGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterParameterGroupsMessageBuilder {
+        DescribeDBClusterParameterGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterParameterGroupsMessage`](struct.DescribeDBClusterParameterGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterParameterGroupsMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBClusterParameterGroupsMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.DescribeDBClusterParameterGroupsMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClusterParameterGroupsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClusterParameterGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClusterParameterGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterParameterGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterParameterGroupsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDBClusterParameterGroupsMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterParameterGroupsMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterParameterGroupsMessageSerializer;
 impl DescribeDBClusterParameterGroupsMessageSerializer {
@@ -6701,6 +9872,70 @@ pub struct DescribeDBClusterParametersMessage {
     pub source: Option<String>,
 }
 
+impl DescribeDBClusterParametersMessage {
+    /// Returns a builder for `DescribeDBClusterParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterParametersMessageBuilder {
+        DescribeDBClusterParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterParametersMessage`](struct.DescribeDBClusterParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterParametersMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    source: Option<String>,
+}
+
+impl DescribeDBClusterParametersMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.DescribeDBClusterParametersMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClusterParametersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClusterParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClusterParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.DescribeDBClusterParametersMessage.html#structfield.source) member.
+    pub fn source<T: Into<String>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterParametersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBClusterParametersMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            source: self.source,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterParametersMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterParametersMessageSerializer;
 impl DescribeDBClusterParametersMessageSerializer {
@@ -6740,6 +9975,39 @@ pub struct DescribeDBClusterSnapshotAttributesMessage {
     pub db_cluster_snapshot_identifier: String,
 }
 
+impl DescribeDBClusterSnapshotAttributesMessage {
+    /// Returns a builder for `DescribeDBClusterSnapshotAttributesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterSnapshotAttributesMessageBuilder {
+        DescribeDBClusterSnapshotAttributesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterSnapshotAttributesMessage`](struct.DescribeDBClusterSnapshotAttributesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterSnapshotAttributesMessageBuilder {
+    db_cluster_snapshot_identifier: Option<String>,
+}
+
+impl DescribeDBClusterSnapshotAttributesMessageBuilder {
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.DescribeDBClusterSnapshotAttributesMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterSnapshotAttributesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterSnapshotAttributesMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDBClusterSnapshotAttributesMessage {
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_snapshot_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterSnapshotAttributesMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterSnapshotAttributesMessageSerializer;
 impl DescribeDBClusterSnapshotAttributesMessageSerializer {
@@ -6765,10 +10033,10 @@ pub struct DescribeDBClusterSnapshotAttributesResult {
     pub db_cluster_snapshot_attributes_result: Option<DBClusterSnapshotAttributesResult>,
 }
 
-struct DescribeDBClusterSnapshotAttributesResultDeserializer;
+pub(crate) struct DescribeDBClusterSnapshotAttributesResultDeserializer;
 impl DescribeDBClusterSnapshotAttributesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeDBClusterSnapshotAttributesResult, XmlParseError> {
@@ -6812,6 +10080,92 @@ pub struct DescribeDBClusterSnapshotsMessage {
     pub snapshot_type: Option<String>,
 }
 
+impl DescribeDBClusterSnapshotsMessage {
+    /// Returns a builder for `DescribeDBClusterSnapshotsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClusterSnapshotsMessageBuilder {
+        DescribeDBClusterSnapshotsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClusterSnapshotsMessage`](struct.DescribeDBClusterSnapshotsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClusterSnapshotsMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    db_cluster_snapshot_identifier: Option<String>,
+    filters: Option<Vec<Filter>>,
+    include_public: Option<bool>,
+    include_shared: Option<bool>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    snapshot_type: Option<String>,
+}
+
+impl DescribeDBClusterSnapshotsMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_public`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.include_public) member.
+    pub fn include_public<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_public = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_shared`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.include_shared) member.
+    pub fn include_shared<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_shared = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_type`](struct.DescribeDBClusterSnapshotsMessage.html#structfield.snapshot_type) member.
+    pub fn snapshot_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClusterSnapshotsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClusterSnapshotsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBClusterSnapshotsMessage {
+            db_cluster_identifier: self.db_cluster_identifier,
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier,
+            filters: self.filters,
+            include_public: self.include_public,
+            include_shared: self.include_shared,
+            marker: self.marker,
+            max_records: self.max_records,
+            snapshot_type: self.snapshot_type,
+        })
+    }
+}
+
 /// Serialize `DescribeDBClusterSnapshotsMessage` contents to a `SignedRequest`.
 struct DescribeDBClusterSnapshotsMessageSerializer;
 impl DescribeDBClusterSnapshotsMessageSerializer {
@@ -6873,14 +10227,76 @@ pub struct DescribeDBClustersMessage {
     pub max_records: Option<i64>,
 }
 
-/// Serialize `DescribeDBClustersMessage` contents to a `SignedRequest`.
-struct DescribeDBClustersMessageSerializer;
-impl DescribeDBClustersMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &DescribeDBClustersMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl DescribeDBClustersMessage {
+    /// Returns a builder for `DescribeDBClustersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBClustersMessageBuilder {
+        DescribeDBClustersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBClustersMessage`](struct.DescribeDBClustersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBClustersMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    filters: Option<Vec<Filter>>,
+    include_shared: Option<bool>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBClustersMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.DescribeDBClustersMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBClustersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_shared`](struct.DescribeDBClustersMessage.html#structfield.include_shared) member.
+    pub fn include_shared<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_shared = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBClustersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBClustersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBClustersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBClustersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBClustersMessage {
+            db_cluster_identifier: self.db_cluster_identifier,
+            filters: self.filters,
+            include_shared: self.include_shared,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
+/// Serialize `DescribeDBClustersMessage` contents to a `SignedRequest`.
+struct DescribeDBClustersMessageSerializer;
+impl DescribeDBClustersMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &DescribeDBClustersMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
 
         if let Some(ref field_value) = obj.db_cluster_identifier {
             params.put(
@@ -6931,6 +10347,108 @@ pub struct DescribeDBEngineVersionsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBEngineVersionsMessage {
+    /// Returns a builder for `DescribeDBEngineVersionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBEngineVersionsMessageBuilder {
+        DescribeDBEngineVersionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBEngineVersionsMessage`](struct.DescribeDBEngineVersionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBEngineVersionsMessageBuilder {
+    db_parameter_group_family: Option<String>,
+    default_only: Option<bool>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    filters: Option<Vec<Filter>>,
+    include_all: Option<bool>,
+    list_supported_character_sets: Option<bool>,
+    list_supported_timezones: Option<bool>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBEngineVersionsMessageBuilder {
+    /// Sets the [`db_parameter_group_family`](struct.DescribeDBEngineVersionsMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`default_only`](struct.DescribeDBEngineVersionsMessage.html#structfield.default_only) member.
+    pub fn default_only<T: Into<bool>>(mut self, value: T) -> Self {
+        self.default_only = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.DescribeDBEngineVersionsMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.DescribeDBEngineVersionsMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBEngineVersionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_all`](struct.DescribeDBEngineVersionsMessage.html#structfield.include_all) member.
+    pub fn include_all<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_all = Some(value.into());
+        self
+    }
+
+    /// Sets the [`list_supported_character_sets`](struct.DescribeDBEngineVersionsMessage.html#structfield.list_supported_character_sets) member.
+    pub fn list_supported_character_sets<T: Into<bool>>(mut self, value: T) -> Self {
+        self.list_supported_character_sets = Some(value.into());
+        self
+    }
+
+    /// Sets the [`list_supported_timezones`](struct.DescribeDBEngineVersionsMessage.html#structfield.list_supported_timezones) member.
+    pub fn list_supported_timezones<T: Into<bool>>(mut self, value: T) -> Self {
+        self.list_supported_timezones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBEngineVersionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBEngineVersionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBEngineVersionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBEngineVersionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBEngineVersionsMessage {
+            db_parameter_group_family: self.db_parameter_group_family,
+            default_only: self.default_only,
+            engine: self.engine,
+            engine_version: self.engine_version,
+            filters: self.filters,
+            include_all: self.include_all,
+            list_supported_character_sets: self.list_supported_character_sets,
+            list_supported_timezones: self.list_supported_timezones,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBEngineVersionsMessage` contents to a `SignedRequest`.
 struct DescribeDBEngineVersionsMessageSerializer;
 impl DescribeDBEngineVersionsMessageSerializer {
@@ -7001,6 +10519,69 @@ pub struct DescribeDBInstanceAutomatedBackupsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBInstanceAutomatedBackupsMessage {
+    /// Returns a builder for `DescribeDBInstanceAutomatedBackupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBInstanceAutomatedBackupsMessageBuilder {
+        DescribeDBInstanceAutomatedBackupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBInstanceAutomatedBackupsMessage`](struct.DescribeDBInstanceAutomatedBackupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBInstanceAutomatedBackupsMessageBuilder {
+    db_instance_identifier: Option<String>,
+    dbi_resource_id: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBInstanceAutomatedBackupsMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.DescribeDBInstanceAutomatedBackupsMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dbi_resource_id`](struct.DescribeDBInstanceAutomatedBackupsMessage.html#structfield.dbi_resource_id) member.
+    pub fn dbi_resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.dbi_resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBInstanceAutomatedBackupsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBInstanceAutomatedBackupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBInstanceAutomatedBackupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBInstanceAutomatedBackupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBInstanceAutomatedBackupsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDBInstanceAutomatedBackupsMessage {
+            db_instance_identifier: self.db_instance_identifier,
+            dbi_resource_id: self.dbi_resource_id,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBInstanceAutomatedBackupsMessage` contents to a `SignedRequest`.
 struct DescribeDBInstanceAutomatedBackupsMessageSerializer;
 impl DescribeDBInstanceAutomatedBackupsMessageSerializer {
@@ -7048,6 +10629,60 @@ pub struct DescribeDBInstancesMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBInstancesMessage {
+    /// Returns a builder for `DescribeDBInstancesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBInstancesMessageBuilder {
+        DescribeDBInstancesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBInstancesMessage`](struct.DescribeDBInstancesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBInstancesMessageBuilder {
+    db_instance_identifier: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBInstancesMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.DescribeDBInstancesMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBInstancesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBInstancesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBInstancesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBInstancesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBInstancesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBInstancesMessage {
+            db_instance_identifier: self.db_instance_identifier,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBInstancesMessage` contents to a `SignedRequest`.
 struct DescribeDBInstancesMessageSerializer;
 impl DescribeDBInstancesMessageSerializer {
@@ -7090,10 +10725,10 @@ pub struct DescribeDBLogFilesDetails {
     pub size: Option<i64>,
 }
 
-struct DescribeDBLogFilesDetailsDeserializer;
+pub(crate) struct DescribeDBLogFilesDetailsDeserializer;
 impl DescribeDBLogFilesDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeDBLogFilesDetails, XmlParseError> {
@@ -7120,10 +10755,10 @@ impl DescribeDBLogFilesDetailsDeserializer {
         )
     }
 }
-struct DescribeDBLogFilesListDeserializer;
+pub(crate) struct DescribeDBLogFilesListDeserializer;
 impl DescribeDBLogFilesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DescribeDBLogFilesDetails>, XmlParseError> {
@@ -7159,6 +10794,86 @@ pub struct DescribeDBLogFilesMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBLogFilesMessage {
+    /// Returns a builder for `DescribeDBLogFilesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBLogFilesMessageBuilder {
+        DescribeDBLogFilesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBLogFilesMessage`](struct.DescribeDBLogFilesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBLogFilesMessageBuilder {
+    db_instance_identifier: Option<String>,
+    file_last_written: Option<i64>,
+    file_size: Option<i64>,
+    filename_contains: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBLogFilesMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.DescribeDBLogFilesMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_last_written`](struct.DescribeDBLogFilesMessage.html#structfield.file_last_written) member.
+    pub fn file_last_written<T: Into<i64>>(mut self, value: T) -> Self {
+        self.file_last_written = Some(value.into());
+        self
+    }
+
+    /// Sets the [`file_size`](struct.DescribeDBLogFilesMessage.html#structfield.file_size) member.
+    pub fn file_size<T: Into<i64>>(mut self, value: T) -> Self {
+        self.file_size = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filename_contains`](struct.DescribeDBLogFilesMessage.html#structfield.filename_contains) member.
+    pub fn filename_contains<T: Into<String>>(mut self, value: T) -> Self {
+        self.filename_contains = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBLogFilesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBLogFilesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBLogFilesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBLogFilesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBLogFilesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBLogFilesMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            file_last_written: self.file_last_written,
+            file_size: self.file_size,
+            filename_contains: self.filename_contains,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBLogFilesMessage` contents to a `SignedRequest`.
 struct DescribeDBLogFilesMessageSerializer;
 impl DescribeDBLogFilesMessageSerializer {
@@ -7206,10 +10921,10 @@ pub struct DescribeDBLogFilesResponse {
     pub marker: Option<String>,
 }
 
-struct DescribeDBLogFilesResponseDeserializer;
+pub(crate) struct DescribeDBLogFilesResponseDeserializer;
 impl DescribeDBLogFilesResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeDBLogFilesResponse, XmlParseError> {
@@ -7249,6 +10964,60 @@ pub struct DescribeDBParameterGroupsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBParameterGroupsMessage {
+    /// Returns a builder for `DescribeDBParameterGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBParameterGroupsMessageBuilder {
+        DescribeDBParameterGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBParameterGroupsMessage`](struct.DescribeDBParameterGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBParameterGroupsMessageBuilder {
+    db_parameter_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBParameterGroupsMessageBuilder {
+    /// Sets the [`db_parameter_group_name`](struct.DescribeDBParameterGroupsMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBParameterGroupsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBParameterGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBParameterGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBParameterGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBParameterGroupsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBParameterGroupsMessage {
+            db_parameter_group_name: self.db_parameter_group_name,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBParameterGroupsMessage` contents to a `SignedRequest`.
 struct DescribeDBParameterGroupsMessageSerializer;
 impl DescribeDBParameterGroupsMessageSerializer {
@@ -7294,6 +11063,70 @@ pub struct DescribeDBParametersMessage {
     pub source: Option<String>,
 }
 
+impl DescribeDBParametersMessage {
+    /// Returns a builder for `DescribeDBParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBParametersMessageBuilder {
+        DescribeDBParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBParametersMessage`](struct.DescribeDBParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBParametersMessageBuilder {
+    db_parameter_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    source: Option<String>,
+}
+
+impl DescribeDBParametersMessageBuilder {
+    /// Sets the [`db_parameter_group_name`](struct.DescribeDBParametersMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBParametersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.DescribeDBParametersMessage.html#structfield.source) member.
+    pub fn source<T: Into<String>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBParametersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBParametersMessage {
+            db_parameter_group_name: self.db_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_name"),
+            )?,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            source: self.source,
+        })
+    }
+}
+
 /// Serialize `DescribeDBParametersMessage` contents to a `SignedRequest`.
 struct DescribeDBParametersMessageSerializer;
 impl DescribeDBParametersMessageSerializer {
@@ -7339,6 +11172,60 @@ pub struct DescribeDBSecurityGroupsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBSecurityGroupsMessage {
+    /// Returns a builder for `DescribeDBSecurityGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBSecurityGroupsMessageBuilder {
+        DescribeDBSecurityGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBSecurityGroupsMessage`](struct.DescribeDBSecurityGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBSecurityGroupsMessageBuilder {
+    db_security_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBSecurityGroupsMessageBuilder {
+    /// Sets the [`db_security_group_name`](struct.DescribeDBSecurityGroupsMessage.html#structfield.db_security_group_name) member.
+    pub fn db_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBSecurityGroupsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBSecurityGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBSecurityGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBSecurityGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBSecurityGroupsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBSecurityGroupsMessage {
+            db_security_group_name: self.db_security_group_name,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBSecurityGroupsMessage` contents to a `SignedRequest`.
 struct DescribeDBSecurityGroupsMessageSerializer;
 impl DescribeDBSecurityGroupsMessageSerializer {
@@ -7377,6 +11264,38 @@ pub struct DescribeDBSnapshotAttributesMessage {
     pub db_snapshot_identifier: String,
 }
 
+impl DescribeDBSnapshotAttributesMessage {
+    /// Returns a builder for `DescribeDBSnapshotAttributesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBSnapshotAttributesMessageBuilder {
+        DescribeDBSnapshotAttributesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBSnapshotAttributesMessage`](struct.DescribeDBSnapshotAttributesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBSnapshotAttributesMessageBuilder {
+    db_snapshot_identifier: Option<String>,
+}
+
+impl DescribeDBSnapshotAttributesMessageBuilder {
+    /// Sets the [`db_snapshot_identifier`](struct.DescribeDBSnapshotAttributesMessage.html#structfield.db_snapshot_identifier) member.
+    pub fn db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBSnapshotAttributesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBSnapshotAttributesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBSnapshotAttributesMessage {
+            db_snapshot_identifier: self
+                .db_snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_snapshot_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeDBSnapshotAttributesMessage` contents to a `SignedRequest`.
 struct DescribeDBSnapshotAttributesMessageSerializer;
 impl DescribeDBSnapshotAttributesMessageSerializer {
@@ -7398,10 +11317,10 @@ pub struct DescribeDBSnapshotAttributesResult {
     pub db_snapshot_attributes_result: Option<DBSnapshotAttributesResult>,
 }
 
-struct DescribeDBSnapshotAttributesResultDeserializer;
+pub(crate) struct DescribeDBSnapshotAttributesResultDeserializer;
 impl DescribeDBSnapshotAttributesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeDBSnapshotAttributesResult, XmlParseError> {
@@ -7447,6 +11366,100 @@ pub struct DescribeDBSnapshotsMessage {
     pub snapshot_type: Option<String>,
 }
 
+impl DescribeDBSnapshotsMessage {
+    /// Returns a builder for `DescribeDBSnapshotsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBSnapshotsMessageBuilder {
+        DescribeDBSnapshotsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBSnapshotsMessage`](struct.DescribeDBSnapshotsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBSnapshotsMessageBuilder {
+    db_instance_identifier: Option<String>,
+    db_snapshot_identifier: Option<String>,
+    dbi_resource_id: Option<String>,
+    filters: Option<Vec<Filter>>,
+    include_public: Option<bool>,
+    include_shared: Option<bool>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    snapshot_type: Option<String>,
+}
+
+impl DescribeDBSnapshotsMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.DescribeDBSnapshotsMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_snapshot_identifier`](struct.DescribeDBSnapshotsMessage.html#structfield.db_snapshot_identifier) member.
+    pub fn db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`dbi_resource_id`](struct.DescribeDBSnapshotsMessage.html#structfield.dbi_resource_id) member.
+    pub fn dbi_resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.dbi_resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBSnapshotsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_public`](struct.DescribeDBSnapshotsMessage.html#structfield.include_public) member.
+    pub fn include_public<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_public = Some(value.into());
+        self
+    }
+
+    /// Sets the [`include_shared`](struct.DescribeDBSnapshotsMessage.html#structfield.include_shared) member.
+    pub fn include_shared<T: Into<bool>>(mut self, value: T) -> Self {
+        self.include_shared = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBSnapshotsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBSnapshotsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_type`](struct.DescribeDBSnapshotsMessage.html#structfield.snapshot_type) member.
+    pub fn snapshot_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBSnapshotsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBSnapshotsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBSnapshotsMessage {
+            db_instance_identifier: self.db_instance_identifier,
+            db_snapshot_identifier: self.db_snapshot_identifier,
+            dbi_resource_id: self.dbi_resource_id,
+            filters: self.filters,
+            include_public: self.include_public,
+            include_shared: self.include_shared,
+            marker: self.marker,
+            max_records: self.max_records,
+            snapshot_type: self.snapshot_type,
+        })
+    }
+}
+
 /// Serialize `DescribeDBSnapshotsMessage` contents to a `SignedRequest`.
 struct DescribeDBSnapshotsMessageSerializer;
 impl DescribeDBSnapshotsMessageSerializer {
@@ -7509,6 +11522,60 @@ pub struct DescribeDBSubnetGroupsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeDBSubnetGroupsMessage {
+    /// Returns a builder for `DescribeDBSubnetGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDBSubnetGroupsMessageBuilder {
+        DescribeDBSubnetGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDBSubnetGroupsMessage`](struct.DescribeDBSubnetGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDBSubnetGroupsMessageBuilder {
+    db_subnet_group_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeDBSubnetGroupsMessageBuilder {
+    /// Sets the [`db_subnet_group_name`](struct.DescribeDBSubnetGroupsMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeDBSubnetGroupsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeDBSubnetGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDBSubnetGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDBSubnetGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDBSubnetGroupsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeDBSubnetGroupsMessage {
+            db_subnet_group_name: self.db_subnet_group_name,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeDBSubnetGroupsMessage` contents to a `SignedRequest`.
 struct DescribeDBSubnetGroupsMessageSerializer;
 impl DescribeDBSubnetGroupsMessageSerializer {
@@ -7550,6 +11617,65 @@ pub struct DescribeEngineDefaultClusterParametersMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeEngineDefaultClusterParametersMessage {
+    /// Returns a builder for `DescribeEngineDefaultClusterParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEngineDefaultClusterParametersMessageBuilder {
+        DescribeEngineDefaultClusterParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEngineDefaultClusterParametersMessage`](struct.DescribeEngineDefaultClusterParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEngineDefaultClusterParametersMessageBuilder {
+    db_parameter_group_family: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeEngineDefaultClusterParametersMessageBuilder {
+    /// Sets the [`db_parameter_group_family`](struct.DescribeEngineDefaultClusterParametersMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeEngineDefaultClusterParametersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEngineDefaultClusterParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEngineDefaultClusterParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEngineDefaultClusterParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        DescribeEngineDefaultClusterParametersMessage,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(DescribeEngineDefaultClusterParametersMessage {
+            db_parameter_group_family: self.db_parameter_group_family.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_family"),
+            )?,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeEngineDefaultClusterParametersMessage` contents to a `SignedRequest`.
 struct DescribeEngineDefaultClusterParametersMessageSerializer;
 impl DescribeEngineDefaultClusterParametersMessageSerializer {
@@ -7588,10 +11714,10 @@ pub struct DescribeEngineDefaultClusterParametersResult {
     pub engine_defaults: Option<EngineDefaults>,
 }
 
-struct DescribeEngineDefaultClusterParametersResultDeserializer;
+pub(crate) struct DescribeEngineDefaultClusterParametersResultDeserializer;
 impl DescribeEngineDefaultClusterParametersResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeEngineDefaultClusterParametersResult, XmlParseError> {
@@ -7626,19 +11752,76 @@ pub struct DescribeEngineDefaultParametersMessage {
     pub max_records: Option<i64>,
 }
 
-/// Serialize `DescribeEngineDefaultParametersMessage` contents to a `SignedRequest`.
-struct DescribeEngineDefaultParametersMessageSerializer;
-impl DescribeEngineDefaultParametersMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &DescribeEngineDefaultParametersMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl DescribeEngineDefaultParametersMessage {
+    /// Returns a builder for `DescribeEngineDefaultParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEngineDefaultParametersMessageBuilder {
+        DescribeEngineDefaultParametersMessageBuilder::default()
+    }
+}
 
-        params.put(
-            &format!("{}{}", prefix, "DBParameterGroupFamily"),
-            &obj.db_parameter_group_family,
-        );
+/// Builds a [`DescribeEngineDefaultParametersMessage`](struct.DescribeEngineDefaultParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEngineDefaultParametersMessageBuilder {
+    db_parameter_group_family: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeEngineDefaultParametersMessageBuilder {
+    /// Sets the [`db_parameter_group_family`](struct.DescribeEngineDefaultParametersMessage.html#structfield.db_parameter_group_family) member.
+    pub fn db_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeEngineDefaultParametersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEngineDefaultParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEngineDefaultParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEngineDefaultParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEngineDefaultParametersMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeEngineDefaultParametersMessage {
+            db_parameter_group_family: self.db_parameter_group_family.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_family"),
+            )?,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
+/// Serialize `DescribeEngineDefaultParametersMessage` contents to a `SignedRequest`.
+struct DescribeEngineDefaultParametersMessageSerializer;
+impl DescribeEngineDefaultParametersMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &DescribeEngineDefaultParametersMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        params.put(
+            &format!("{}{}", prefix, "DBParameterGroupFamily"),
+            &obj.db_parameter_group_family,
+        );
         if let Some(ref field_value) = obj.filters {
             FilterListSerializer::serialize(
                 params,
@@ -7660,10 +11843,10 @@ pub struct DescribeEngineDefaultParametersResult {
     pub engine_defaults: Option<EngineDefaults>,
 }
 
-struct DescribeEngineDefaultParametersResultDeserializer;
+pub(crate) struct DescribeEngineDefaultParametersResultDeserializer;
 impl DescribeEngineDefaultParametersResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeEngineDefaultParametersResult, XmlParseError> {
@@ -7694,6 +11877,44 @@ pub struct DescribeEventCategoriesMessage {
     pub source_type: Option<String>,
 }
 
+impl DescribeEventCategoriesMessage {
+    /// Returns a builder for `DescribeEventCategoriesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventCategoriesMessageBuilder {
+        DescribeEventCategoriesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventCategoriesMessage`](struct.DescribeEventCategoriesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventCategoriesMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    source_type: Option<String>,
+}
+
+impl DescribeEventCategoriesMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeEventCategoriesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.DescribeEventCategoriesMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventCategoriesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventCategoriesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventCategoriesMessage {
+            filters: self.filters,
+            source_type: self.source_type,
+        })
+    }
+}
+
 /// Serialize `DescribeEventCategoriesMessage` contents to a `SignedRequest`.
 struct DescribeEventCategoriesMessageSerializer;
 impl DescribeEventCategoriesMessageSerializer {
@@ -7729,6 +11950,60 @@ pub struct DescribeEventSubscriptionsMessage {
     pub subscription_name: Option<String>,
 }
 
+impl DescribeEventSubscriptionsMessage {
+    /// Returns a builder for `DescribeEventSubscriptionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventSubscriptionsMessageBuilder {
+        DescribeEventSubscriptionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventSubscriptionsMessage`](struct.DescribeEventSubscriptionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventSubscriptionsMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    subscription_name: Option<String>,
+}
+
+impl DescribeEventSubscriptionsMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeEventSubscriptionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEventSubscriptionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEventSubscriptionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.DescribeEventSubscriptionsMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventSubscriptionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventSubscriptionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventSubscriptionsMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            subscription_name: self.subscription_name,
+        })
+    }
+}
+
 /// Serialize `DescribeEventSubscriptionsMessage` contents to a `SignedRequest`.
 struct DescribeEventSubscriptionsMessageSerializer;
 impl DescribeEventSubscriptionsMessageSerializer {
@@ -7763,7 +12038,7 @@ pub struct DescribeEventsMessage {
     /// <p>The number of minutes to retrieve events for.</p> <p>Default: 60</p>
     pub duration: Option<i64>,
     /// <p> The end of the time interval for which to retrieve events, specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: 2009-07-08T18:00Z</p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>A list of event categories that trigger notifications for a event notification subscription.</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>This parameter is not currently supported.</p>
@@ -7775,9 +12050,101 @@ pub struct DescribeEventsMessage {
     /// <p><p>The identifier of the event source for which events are returned. If not specified, then all sources are included in the response.</p> <p>Constraints:</p> <ul> <li> <p>If SourceIdentifier is supplied, SourceType must also be provided.</p> </li> <li> <p>If the source type is <code>DBInstance</code>, then a <code>DBInstanceIdentifier</code> must be supplied.</p> </li> <li> <p>If the source type is <code>DBSecurityGroup</code>, a <code>DBSecurityGroupName</code> must be supplied.</p> </li> <li> <p>If the source type is <code>DBParameterGroup</code>, a <code>DBParameterGroupName</code> must be supplied.</p> </li> <li> <p>If the source type is <code>DBSnapshot</code>, a <code>DBSnapshotIdentifier</code> must be supplied.</p> </li> <li> <p>Can&#39;t end with a hyphen or contain two consecutive hyphens.</p> </li> </ul></p>
     pub source_identifier: Option<String>,
     /// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p> The beginning of the time interval to retrieve events for, specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: 2009-07-08T18:00Z</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
+}
+
+impl DescribeEventsMessage {
+    /// Returns a builder for `DescribeEventsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventsMessageBuilder {
+        DescribeEventsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventsMessage`](struct.DescribeEventsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventsMessageBuilder {
+    duration: Option<i64>,
+    end_time: Option<IsoTimestamp>,
+    event_categories: Option<Vec<String>>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    source_identifier: Option<String>,
+    source_type: Option<SourceType>,
+    start_time: Option<IsoTimestamp>,
+}
+
+impl DescribeEventsMessageBuilder {
+    /// Sets the [`duration`](struct.DescribeEventsMessage.html#structfield.duration) member.
+    pub fn duration<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.DescribeEventsMessage.html#structfield.end_time) member.
+    pub fn end_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_categories`](struct.DescribeEventsMessage.html#structfield.event_categories) member.
+    pub fn event_categories<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_categories = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeEventsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEventsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEventsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_identifier`](struct.DescribeEventsMessage.html#structfield.source_identifier) member.
+    pub fn source_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.DescribeEventsMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<SourceType>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.DescribeEventsMessage.html#structfield.start_time) member.
+    pub fn start_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventsMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeEventsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventsMessage {
+            duration: self.duration,
+            end_time: self.end_time,
+            event_categories: self.event_categories,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            source_identifier: self.source_identifier,
+            source_type: self.source_type,
+            start_time: self.start_time,
+        })
+    }
 }
 
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
@@ -7839,6 +12206,60 @@ pub struct DescribeGlobalClustersMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeGlobalClustersMessage {
+    /// Returns a builder for `DescribeGlobalClustersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeGlobalClustersMessageBuilder {
+        DescribeGlobalClustersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeGlobalClustersMessage`](struct.DescribeGlobalClustersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeGlobalClustersMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    global_cluster_identifier: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeGlobalClustersMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeGlobalClustersMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`global_cluster_identifier`](struct.DescribeGlobalClustersMessage.html#structfield.global_cluster_identifier) member.
+    pub fn global_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.global_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeGlobalClustersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeGlobalClustersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeGlobalClustersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeGlobalClustersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeGlobalClustersMessage {
+            filters: self.filters,
+            global_cluster_identifier: self.global_cluster_identifier,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeGlobalClustersMessage` contents to a `SignedRequest`.
 struct DescribeGlobalClustersMessageSerializer;
 impl DescribeGlobalClustersMessageSerializer {
@@ -7885,6 +12306,70 @@ pub struct DescribeOptionGroupOptionsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeOptionGroupOptionsMessage {
+    /// Returns a builder for `DescribeOptionGroupOptionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeOptionGroupOptionsMessageBuilder {
+        DescribeOptionGroupOptionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeOptionGroupOptionsMessage`](struct.DescribeOptionGroupOptionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeOptionGroupOptionsMessageBuilder {
+    engine_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    major_engine_version: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeOptionGroupOptionsMessageBuilder {
+    /// Sets the [`engine_name`](struct.DescribeOptionGroupOptionsMessage.html#structfield.engine_name) member.
+    pub fn engine_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeOptionGroupOptionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`major_engine_version`](struct.DescribeOptionGroupOptionsMessage.html#structfield.major_engine_version) member.
+    pub fn major_engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.major_engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeOptionGroupOptionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeOptionGroupOptionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeOptionGroupOptionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeOptionGroupOptionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeOptionGroupOptionsMessage {
+            engine_name: self
+                .engine_name
+                .ok_or(::rusoto_core::BuildError::missing("engine_name"))?,
+            filters: self.filters,
+            major_engine_version: self.major_engine_version,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeOptionGroupOptionsMessage` contents to a `SignedRequest`.
 struct DescribeOptionGroupOptionsMessageSerializer;
 impl DescribeOptionGroupOptionsMessageSerializer {
@@ -7931,6 +12416,76 @@ pub struct DescribeOptionGroupsMessage {
     pub option_group_name: Option<String>,
 }
 
+impl DescribeOptionGroupsMessage {
+    /// Returns a builder for `DescribeOptionGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeOptionGroupsMessageBuilder {
+        DescribeOptionGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeOptionGroupsMessage`](struct.DescribeOptionGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeOptionGroupsMessageBuilder {
+    engine_name: Option<String>,
+    filters: Option<Vec<Filter>>,
+    major_engine_version: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    option_group_name: Option<String>,
+}
+
+impl DescribeOptionGroupsMessageBuilder {
+    /// Sets the [`engine_name`](struct.DescribeOptionGroupsMessage.html#structfield.engine_name) member.
+    pub fn engine_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeOptionGroupsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`major_engine_version`](struct.DescribeOptionGroupsMessage.html#structfield.major_engine_version) member.
+    pub fn major_engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.major_engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeOptionGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeOptionGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.DescribeOptionGroupsMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeOptionGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeOptionGroupsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeOptionGroupsMessage {
+            engine_name: self.engine_name,
+            filters: self.filters,
+            major_engine_version: self.major_engine_version,
+            marker: self.marker,
+            max_records: self.max_records,
+            option_group_name: self.option_group_name,
+        })
+    }
+}
+
 /// Serialize `DescribeOptionGroupsMessage` contents to a `SignedRequest`.
 struct DescribeOptionGroupsMessageSerializer;
 impl DescribeOptionGroupsMessageSerializer {
@@ -7986,6 +12541,95 @@ pub struct DescribeOrderableDBInstanceOptionsMessage {
     pub vpc: Option<bool>,
 }
 
+impl DescribeOrderableDBInstanceOptionsMessage {
+    /// Returns a builder for `DescribeOrderableDBInstanceOptionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeOrderableDBInstanceOptionsMessageBuilder {
+        DescribeOrderableDBInstanceOptionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeOrderableDBInstanceOptionsMessage`](struct.DescribeOrderableDBInstanceOptionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeOrderableDBInstanceOptionsMessageBuilder {
+    db_instance_class: Option<String>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    filters: Option<Vec<Filter>>,
+    license_model: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    vpc: Option<bool>,
+}
+
+impl DescribeOrderableDBInstanceOptionsMessageBuilder {
+    /// Sets the [`db_instance_class`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_model`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.license_model) member.
+    pub fn license_model<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_model = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc`](struct.DescribeOrderableDBInstanceOptionsMessage.html#structfield.vpc) member.
+    pub fn vpc<T: Into<bool>>(mut self, value: T) -> Self {
+        self.vpc = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeOrderableDBInstanceOptionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeOrderableDBInstanceOptionsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeOrderableDBInstanceOptionsMessage {
+            db_instance_class: self.db_instance_class,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_version: self.engine_version,
+            filters: self.filters,
+            license_model: self.license_model,
+            marker: self.marker,
+            max_records: self.max_records,
+            vpc: self.vpc,
+        })
+    }
+}
+
 /// Serialize `DescribeOrderableDBInstanceOptionsMessage` contents to a `SignedRequest`.
 struct DescribeOrderableDBInstanceOptionsMessageSerializer;
 impl DescribeOrderableDBInstanceOptionsMessageSerializer {
@@ -8037,6 +12681,61 @@ pub struct DescribePendingMaintenanceActionsMessage {
     pub resource_identifier: Option<String>,
 }
 
+impl DescribePendingMaintenanceActionsMessage {
+    /// Returns a builder for `DescribePendingMaintenanceActionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribePendingMaintenanceActionsMessageBuilder {
+        DescribePendingMaintenanceActionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribePendingMaintenanceActionsMessage`](struct.DescribePendingMaintenanceActionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribePendingMaintenanceActionsMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    resource_identifier: Option<String>,
+}
+
+impl DescribePendingMaintenanceActionsMessageBuilder {
+    /// Sets the [`filters`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_identifier`](struct.DescribePendingMaintenanceActionsMessage.html#structfield.resource_identifier) member.
+    pub fn resource_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribePendingMaintenanceActionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribePendingMaintenanceActionsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribePendingMaintenanceActionsMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            resource_identifier: self.resource_identifier,
+        })
+    }
+}
+
 /// Serialize `DescribePendingMaintenanceActionsMessage` contents to a `SignedRequest`.
 struct DescribePendingMaintenanceActionsMessageSerializer;
 impl DescribePendingMaintenanceActionsMessageSerializer {
@@ -8090,6 +12789,108 @@ pub struct DescribeReservedDBInstancesMessage {
     pub reserved_db_instances_offering_id: Option<String>,
 }
 
+impl DescribeReservedDBInstancesMessage {
+    /// Returns a builder for `DescribeReservedDBInstancesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeReservedDBInstancesMessageBuilder {
+        DescribeReservedDBInstancesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReservedDBInstancesMessage`](struct.DescribeReservedDBInstancesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeReservedDBInstancesMessageBuilder {
+    db_instance_class: Option<String>,
+    duration: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    multi_az: Option<bool>,
+    offering_type: Option<String>,
+    product_description: Option<String>,
+    reserved_db_instance_id: Option<String>,
+    reserved_db_instances_offering_id: Option<String>,
+}
+
+impl DescribeReservedDBInstancesMessageBuilder {
+    /// Sets the [`db_instance_class`](struct.DescribeReservedDBInstancesMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`duration`](struct.DescribeReservedDBInstancesMessage.html#structfield.duration) member.
+    pub fn duration<T: Into<String>>(mut self, value: T) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeReservedDBInstancesMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeReservedDBInstancesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeReservedDBInstancesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.DescribeReservedDBInstancesMessage.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Sets the [`offering_type`](struct.DescribeReservedDBInstancesMessage.html#structfield.offering_type) member.
+    pub fn offering_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.offering_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_description`](struct.DescribeReservedDBInstancesMessage.html#structfield.product_description) member.
+    pub fn product_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_db_instance_id`](struct.DescribeReservedDBInstancesMessage.html#structfield.reserved_db_instance_id) member.
+    pub fn reserved_db_instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_db_instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_db_instances_offering_id`](struct.DescribeReservedDBInstancesMessage.html#structfield.reserved_db_instances_offering_id) member.
+    pub fn reserved_db_instances_offering_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_db_instances_offering_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReservedDBInstancesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReservedDBInstancesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeReservedDBInstancesMessage {
+            db_instance_class: self.db_instance_class,
+            duration: self.duration,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            multi_az: self.multi_az,
+            offering_type: self.offering_type,
+            product_description: self.product_description,
+            reserved_db_instance_id: self.reserved_db_instance_id,
+            reserved_db_instances_offering_id: self.reserved_db_instances_offering_id,
+        })
+    }
+}
+
 /// Serialize `DescribeReservedDBInstancesMessage` contents to a `SignedRequest`.
 struct DescribeReservedDBInstancesMessageSerializer;
 impl DescribeReservedDBInstancesMessageSerializer {
@@ -8165,6 +12966,101 @@ pub struct DescribeReservedDBInstancesOfferingsMessage {
     pub reserved_db_instances_offering_id: Option<String>,
 }
 
+impl DescribeReservedDBInstancesOfferingsMessage {
+    /// Returns a builder for `DescribeReservedDBInstancesOfferingsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeReservedDBInstancesOfferingsMessageBuilder {
+        DescribeReservedDBInstancesOfferingsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReservedDBInstancesOfferingsMessage`](struct.DescribeReservedDBInstancesOfferingsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeReservedDBInstancesOfferingsMessageBuilder {
+    db_instance_class: Option<String>,
+    duration: Option<String>,
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    multi_az: Option<bool>,
+    offering_type: Option<String>,
+    product_description: Option<String>,
+    reserved_db_instances_offering_id: Option<String>,
+}
+
+impl DescribeReservedDBInstancesOfferingsMessageBuilder {
+    /// Sets the [`db_instance_class`](struct.DescribeReservedDBInstancesOfferingsMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`duration`](struct.DescribeReservedDBInstancesOfferingsMessage.html#structfield.duration) member.
+    pub fn duration<T: Into<String>>(mut self, value: T) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`filters`](struct.DescribeReservedDBInstancesOfferingsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeReservedDBInstancesOfferingsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeReservedDBInstancesOfferingsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.DescribeReservedDBInstancesOfferingsMessage.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Sets the [`offering_type`](struct.DescribeReservedDBInstancesOfferingsMessage.html#structfield.offering_type) member.
+    pub fn offering_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.offering_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`product_description`](struct.DescribeReservedDBInstancesOfferingsMessage.html#structfield.product_description) member.
+    pub fn product_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.product_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_db_instances_offering_id`](struct.DescribeReservedDBInstancesOfferingsMessage.html#structfield.reserved_db_instances_offering_id) member.
+    pub fn reserved_db_instances_offering_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_db_instances_offering_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReservedDBInstancesOfferingsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReservedDBInstancesOfferingsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeReservedDBInstancesOfferingsMessage {
+            db_instance_class: self.db_instance_class,
+            duration: self.duration,
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            multi_az: self.multi_az,
+            offering_type: self.offering_type,
+            product_description: self.product_description,
+            reserved_db_instances_offering_id: self.reserved_db_instances_offering_id,
+        })
+    }
+}
+
 /// Serialize `DescribeReservedDBInstancesOfferingsMessage` contents to a `SignedRequest`.
 struct DescribeReservedDBInstancesOfferingsMessageSerializer;
 impl DescribeReservedDBInstancesOfferingsMessageSerializer {
@@ -8228,17 +13124,71 @@ pub struct DescribeSourceRegionsMessage {
     pub region_name: Option<String>,
 }
 
-/// Serialize `DescribeSourceRegionsMessage` contents to a `SignedRequest`.
-struct DescribeSourceRegionsMessageSerializer;
-impl DescribeSourceRegionsMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &DescribeSourceRegionsMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl DescribeSourceRegionsMessage {
+    /// Returns a builder for `DescribeSourceRegionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeSourceRegionsMessageBuilder {
+        DescribeSourceRegionsMessageBuilder::default()
+    }
+}
 
-        if let Some(ref field_value) = obj.filters {
-            FilterListSerializer::serialize(
+/// Builds a [`DescribeSourceRegionsMessage`](struct.DescribeSourceRegionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeSourceRegionsMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    region_name: Option<String>,
+}
+
+impl DescribeSourceRegionsMessageBuilder {
+    /// Sets the [`filters`](struct.DescribeSourceRegionsMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeSourceRegionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeSourceRegionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`region_name`](struct.DescribeSourceRegionsMessage.html#structfield.region_name) member.
+    pub fn region_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.region_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSourceRegionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSourceRegionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeSourceRegionsMessage {
+            filters: self.filters,
+            marker: self.marker,
+            max_records: self.max_records,
+            region_name: self.region_name,
+        })
+    }
+}
+
+/// Serialize `DescribeSourceRegionsMessage` contents to a `SignedRequest`.
+struct DescribeSourceRegionsMessageSerializer;
+impl DescribeSourceRegionsMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &DescribeSourceRegionsMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.filters {
+            FilterListSerializer::serialize(
                 params,
                 &format!("{}{}", prefix, "Filter"),
                 field_value,
@@ -8263,6 +13213,39 @@ pub struct DescribeValidDBInstanceModificationsMessage {
     pub db_instance_identifier: String,
 }
 
+impl DescribeValidDBInstanceModificationsMessage {
+    /// Returns a builder for `DescribeValidDBInstanceModificationsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeValidDBInstanceModificationsMessageBuilder {
+        DescribeValidDBInstanceModificationsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeValidDBInstanceModificationsMessage`](struct.DescribeValidDBInstanceModificationsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeValidDBInstanceModificationsMessageBuilder {
+    db_instance_identifier: Option<String>,
+}
+
+impl DescribeValidDBInstanceModificationsMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.DescribeValidDBInstanceModificationsMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeValidDBInstanceModificationsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeValidDBInstanceModificationsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeValidDBInstanceModificationsMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeValidDBInstanceModificationsMessage` contents to a `SignedRequest`.
 struct DescribeValidDBInstanceModificationsMessageSerializer;
 impl DescribeValidDBInstanceModificationsMessageSerializer {
@@ -8288,10 +13271,10 @@ pub struct DescribeValidDBInstanceModificationsResult {
     pub valid_db_instance_modifications_message: Option<ValidDBInstanceModificationsMessage>,
 }
 
-struct DescribeValidDBInstanceModificationsResultDeserializer;
+pub(crate) struct DescribeValidDBInstanceModificationsResultDeserializer;
 impl DescribeValidDBInstanceModificationsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeValidDBInstanceModificationsResult, XmlParseError> {
@@ -8328,10 +13311,10 @@ pub struct DomainMembership {
     pub status: Option<String>,
 }
 
-struct DomainMembershipDeserializer;
+pub(crate) struct DomainMembershipDeserializer;
 impl DomainMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DomainMembership, XmlParseError> {
@@ -8356,10 +13339,10 @@ impl DomainMembershipDeserializer {
         })
     }
 }
-struct DomainMembershipListDeserializer;
+pub(crate) struct DomainMembershipListDeserializer;
 impl DomainMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DomainMembership>, XmlParseError> {
@@ -8376,10 +13359,13 @@ impl DomainMembershipListDeserializer {
         })
     }
 }
-struct DoubleDeserializer;
+pub(crate) struct DoubleDeserializer;
 impl DoubleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -8387,10 +13373,13 @@ impl DoubleDeserializer {
         Ok(obj)
     }
 }
-struct DoubleOptionalDeserializer;
+pub(crate) struct DoubleOptionalDeserializer;
 impl DoubleOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -8407,10 +13396,10 @@ pub struct DoubleRange {
     pub to: Option<f64>,
 }
 
-struct DoubleRangeDeserializer;
+pub(crate) struct DoubleRangeDeserializer;
 impl DoubleRangeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DoubleRange, XmlParseError> {
@@ -8428,10 +13417,10 @@ impl DoubleRangeDeserializer {
         })
     }
 }
-struct DoubleRangeListDeserializer;
+pub(crate) struct DoubleRangeListDeserializer;
 impl DoubleRangeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DoubleRange>, XmlParseError> {
@@ -8456,10 +13445,10 @@ pub struct DownloadDBLogFilePortionDetails {
     pub marker: Option<String>,
 }
 
-struct DownloadDBLogFilePortionDetailsDeserializer;
+pub(crate) struct DownloadDBLogFilePortionDetailsDeserializer;
 impl DownloadDBLogFilePortionDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DownloadDBLogFilePortionDetails, XmlParseError> {
@@ -8501,6 +13490,64 @@ pub struct DownloadDBLogFilePortionMessage {
     pub number_of_lines: Option<i64>,
 }
 
+impl DownloadDBLogFilePortionMessage {
+    /// Returns a builder for `DownloadDBLogFilePortionMessage`, checking its required members when built.
+    pub fn builder() -> DownloadDBLogFilePortionMessageBuilder {
+        DownloadDBLogFilePortionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DownloadDBLogFilePortionMessage`](struct.DownloadDBLogFilePortionMessage.html).
+#[derive(Default, Debug)]
+pub struct DownloadDBLogFilePortionMessageBuilder {
+    db_instance_identifier: Option<String>,
+    log_file_name: Option<String>,
+    marker: Option<String>,
+    number_of_lines: Option<i64>,
+}
+
+impl DownloadDBLogFilePortionMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.DownloadDBLogFilePortionMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`log_file_name`](struct.DownloadDBLogFilePortionMessage.html#structfield.log_file_name) member.
+    pub fn log_file_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.log_file_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DownloadDBLogFilePortionMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_lines`](struct.DownloadDBLogFilePortionMessage.html#structfield.number_of_lines) member.
+    pub fn number_of_lines<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_lines = Some(value.into());
+        self
+    }
+
+    /// Builds the `DownloadDBLogFilePortionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DownloadDBLogFilePortionMessage, ::rusoto_core::BuildError> {
+        Ok(DownloadDBLogFilePortionMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            log_file_name: self
+                .log_file_name
+                .ok_or(::rusoto_core::BuildError::missing("log_file_name"))?,
+            marker: self.marker,
+            number_of_lines: self.number_of_lines,
+        })
+    }
+}
+
 /// Serialize `DownloadDBLogFilePortionMessage` contents to a `SignedRequest`.
 struct DownloadDBLogFilePortionMessageSerializer;
 impl DownloadDBLogFilePortionMessageSerializer {
@@ -8537,10 +13584,10 @@ pub struct EC2SecurityGroup {
     pub status: Option<String>,
 }
 
-struct EC2SecurityGroupDeserializer;
+pub(crate) struct EC2SecurityGroupDeserializer;
 impl EC2SecurityGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EC2SecurityGroup, XmlParseError> {
@@ -8573,10 +13620,10 @@ impl EC2SecurityGroupDeserializer {
         })
     }
 }
-struct EC2SecurityGroupListDeserializer;
+pub(crate) struct EC2SecurityGroupListDeserializer;
 impl EC2SecurityGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EC2SecurityGroup>, XmlParseError> {
@@ -8604,10 +13651,10 @@ pub struct Endpoint {
     pub port: Option<i64>,
 }
 
-struct EndpointDeserializer;
+pub(crate) struct EndpointDeserializer;
 impl EndpointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Endpoint, XmlParseError> {
@@ -8640,10 +13687,10 @@ pub struct EngineDefaults {
     pub parameters: Option<Vec<Parameter>>,
 }
 
-struct EngineDefaultsDeserializer;
+pub(crate) struct EngineDefaultsDeserializer;
 impl EngineDefaultsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EngineDefaults, XmlParseError> {
@@ -8669,10 +13716,10 @@ impl EngineDefaultsDeserializer {
         })
     }
 }
-struct EngineModeListDeserializer;
+pub(crate) struct EngineModeListDeserializer;
 impl EngineModeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -8702,7 +13749,7 @@ impl EngineModeListSerializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Event {
     /// <p>Specifies the date and time of the event.</p>
-    pub date: Option<String>,
+    pub date: Option<IsoTimestamp>,
     /// <p>Specifies the category for the event.</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>Provides the text of this event.</p>
@@ -8712,13 +13759,16 @@ pub struct Event {
     /// <p>Provides the identifier for the source of the event.</p>
     pub source_identifier: Option<String>,
     /// <p>Specifies the source type for this event.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
-struct EventDeserializer;
+pub(crate) struct EventDeserializer;
 impl EventDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Event, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Event, XmlParseError> {
         deserialize_elements::<_, Event, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Date" => {
@@ -8749,10 +13799,10 @@ impl EventDeserializer {
         })
     }
 }
-struct EventCategoriesListDeserializer;
+pub(crate) struct EventCategoriesListDeserializer;
 impl EventCategoriesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -8787,10 +13837,10 @@ pub struct EventCategoriesMap {
     pub source_type: Option<String>,
 }
 
-struct EventCategoriesMapDeserializer;
+pub(crate) struct EventCategoriesMapDeserializer;
 impl EventCategoriesMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventCategoriesMap, XmlParseError> {
@@ -8810,10 +13860,10 @@ impl EventCategoriesMapDeserializer {
         })
     }
 }
-struct EventCategoriesMapListDeserializer;
+pub(crate) struct EventCategoriesMapListDeserializer;
 impl EventCategoriesMapListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EventCategoriesMap>, XmlParseError> {
@@ -8837,10 +13887,10 @@ pub struct EventCategoriesMessage {
     pub event_categories_map_list: Option<Vec<EventCategoriesMap>>,
 }
 
-struct EventCategoriesMessageDeserializer;
+pub(crate) struct EventCategoriesMessageDeserializer;
 impl EventCategoriesMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventCategoriesMessage, XmlParseError> {
@@ -8860,10 +13910,10 @@ impl EventCategoriesMessageDeserializer {
         })
     }
 }
-struct EventListDeserializer;
+pub(crate) struct EventListDeserializer;
 impl EventListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Event>, XmlParseError> {
@@ -8902,10 +13952,10 @@ pub struct EventSubscription {
     pub subscription_creation_time: Option<String>,
 }
 
-struct EventSubscriptionDeserializer;
+pub(crate) struct EventSubscriptionDeserializer;
 impl EventSubscriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventSubscription, XmlParseError> {
@@ -8962,10 +14012,10 @@ impl EventSubscriptionDeserializer {
         })
     }
 }
-struct EventSubscriptionsListDeserializer;
+pub(crate) struct EventSubscriptionsListDeserializer;
 impl EventSubscriptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EventSubscription>, XmlParseError> {
@@ -8991,10 +14041,10 @@ pub struct EventSubscriptionsMessage {
     pub marker: Option<String>,
 }
 
-struct EventSubscriptionsMessageDeserializer;
+pub(crate) struct EventSubscriptionsMessageDeserializer;
 impl EventSubscriptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventSubscriptionsMessage, XmlParseError> {
@@ -9030,10 +14080,10 @@ pub struct EventsMessage {
     pub marker: Option<String>,
 }
 
-struct EventsMessageDeserializer;
+pub(crate) struct EventsMessageDeserializer;
 impl EventsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventsMessage, XmlParseError> {
@@ -9062,6 +14112,46 @@ pub struct FailoverDBClusterMessage {
     pub target_db_instance_identifier: Option<String>,
 }
 
+impl FailoverDBClusterMessage {
+    /// Returns a builder for `FailoverDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> FailoverDBClusterMessageBuilder {
+        FailoverDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`FailoverDBClusterMessage`](struct.FailoverDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct FailoverDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    target_db_instance_identifier: Option<String>,
+}
+
+impl FailoverDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.FailoverDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_instance_identifier`](struct.FailoverDBClusterMessage.html#structfield.target_db_instance_identifier) member.
+    pub fn target_db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `FailoverDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<FailoverDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(FailoverDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            target_db_instance_identifier: self.target_db_instance_identifier,
+        })
+    }
+}
+
 /// Serialize `FailoverDBClusterMessage` contents to a `SignedRequest`.
 struct FailoverDBClusterMessageSerializer;
 impl FailoverDBClusterMessageSerializer {
@@ -9089,10 +14179,10 @@ pub struct FailoverDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct FailoverDBClusterResultDeserializer;
+pub(crate) struct FailoverDBClusterResultDeserializer;
 impl FailoverDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<FailoverDBClusterResult, XmlParseError> {
@@ -9112,10 +14202,10 @@ impl FailoverDBClusterResultDeserializer {
         )
     }
 }
-struct FeatureNameListDeserializer;
+pub(crate) struct FeatureNameListDeserializer;
 impl FeatureNameListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -9203,10 +14293,10 @@ pub struct GlobalCluster {
     pub storage_encrypted: Option<bool>,
 }
 
-struct GlobalClusterDeserializer;
+pub(crate) struct GlobalClusterDeserializer;
 impl GlobalClusterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GlobalCluster, XmlParseError> {
@@ -9268,10 +14358,10 @@ impl GlobalClusterDeserializer {
         })
     }
 }
-struct GlobalClusterListDeserializer;
+pub(crate) struct GlobalClusterListDeserializer;
 impl GlobalClusterListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<GlobalCluster>, XmlParseError> {
@@ -9299,10 +14389,10 @@ pub struct GlobalClusterMember {
     pub readers: Option<Vec<String>>,
 }
 
-struct GlobalClusterMemberDeserializer;
+pub(crate) struct GlobalClusterMemberDeserializer;
 impl GlobalClusterMemberDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GlobalClusterMember, XmlParseError> {
@@ -9326,10 +14416,10 @@ impl GlobalClusterMemberDeserializer {
         })
     }
 }
-struct GlobalClusterMemberListDeserializer;
+pub(crate) struct GlobalClusterMemberListDeserializer;
 impl GlobalClusterMemberListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<GlobalClusterMember>, XmlParseError> {
@@ -9354,10 +14444,10 @@ pub struct GlobalClustersMessage {
     pub marker: Option<String>,
 }
 
-struct GlobalClustersMessageDeserializer;
+pub(crate) struct GlobalClustersMessageDeserializer;
 impl GlobalClustersMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GlobalClustersMessage, XmlParseError> {
@@ -9386,10 +14476,10 @@ pub struct IPRange {
     pub status: Option<String>,
 }
 
-struct IPRangeDeserializer;
+pub(crate) struct IPRangeDeserializer;
 impl IPRangeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<IPRange, XmlParseError> {
@@ -9407,10 +14497,10 @@ impl IPRangeDeserializer {
         })
     }
 }
-struct IPRangeListDeserializer;
+pub(crate) struct IPRangeListDeserializer;
 impl IPRangeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<IPRange>, XmlParseError> {
@@ -9424,10 +14514,13 @@ impl IPRangeListDeserializer {
         })
     }
 }
-struct IntegerDeserializer;
+pub(crate) struct IntegerDeserializer;
 impl IntegerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -9435,10 +14528,13 @@ impl IntegerDeserializer {
         Ok(obj)
     }
 }
-struct IntegerOptionalDeserializer;
+pub(crate) struct IntegerOptionalDeserializer;
 impl IntegerOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -9467,6 +14563,46 @@ pub struct ListTagsForResourceMessage {
     pub resource_name: String,
 }
 
+impl ListTagsForResourceMessage {
+    /// Returns a builder for `ListTagsForResourceMessage`, checking its required members when built.
+    pub fn builder() -> ListTagsForResourceMessageBuilder {
+        ListTagsForResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ListTagsForResourceMessage`](struct.ListTagsForResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct ListTagsForResourceMessageBuilder {
+    filters: Option<Vec<Filter>>,
+    resource_name: Option<String>,
+}
+
+impl ListTagsForResourceMessageBuilder {
+    /// Sets the [`filters`](struct.ListTagsForResourceMessage.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<Filter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_name`](struct.ListTagsForResourceMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListTagsForResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListTagsForResourceMessage, ::rusoto_core::BuildError> {
+        Ok(ListTagsForResourceMessage {
+            filters: self.filters,
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+        })
+    }
+}
+
 /// Serialize `ListTagsForResourceMessage` contents to a `SignedRequest`.
 struct ListTagsForResourceMessageSerializer;
 impl ListTagsForResourceMessageSerializer {
@@ -9487,10 +14623,10 @@ impl ListTagsForResourceMessageSerializer {
     }
 }
 
-struct LogTypeListDeserializer;
+pub(crate) struct LogTypeListDeserializer;
 impl LogTypeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -9516,10 +14652,13 @@ impl LogTypeListSerializer {
     }
 }
 
-struct LongDeserializer;
+pub(crate) struct LongDeserializer;
 impl LongDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -9527,10 +14666,13 @@ impl LongDeserializer {
         Ok(obj)
     }
 }
-struct LongOptionalDeserializer;
+pub(crate) struct LongOptionalDeserializer;
 impl LongOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -9547,10 +14689,10 @@ pub struct MinimumEngineVersionPerAllowedValue {
     pub minimum_engine_version: Option<String>,
 }
 
-struct MinimumEngineVersionPerAllowedValueDeserializer;
+pub(crate) struct MinimumEngineVersionPerAllowedValueDeserializer;
 impl MinimumEngineVersionPerAllowedValueDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MinimumEngineVersionPerAllowedValue, XmlParseError> {
@@ -9576,10 +14718,10 @@ impl MinimumEngineVersionPerAllowedValueDeserializer {
         )
     }
 }
-struct MinimumEngineVersionPerAllowedValueListDeserializer;
+pub(crate) struct MinimumEngineVersionPerAllowedValueListDeserializer;
 impl MinimumEngineVersionPerAllowedValueListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<MinimumEngineVersionPerAllowedValue>, XmlParseError> {
@@ -9610,6 +14752,63 @@ pub struct ModifyCurrentDBClusterCapacityMessage {
     pub timeout_action: Option<String>,
 }
 
+impl ModifyCurrentDBClusterCapacityMessage {
+    /// Returns a builder for `ModifyCurrentDBClusterCapacityMessage`, checking its required members when built.
+    pub fn builder() -> ModifyCurrentDBClusterCapacityMessageBuilder {
+        ModifyCurrentDBClusterCapacityMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyCurrentDBClusterCapacityMessage`](struct.ModifyCurrentDBClusterCapacityMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyCurrentDBClusterCapacityMessageBuilder {
+    capacity: Option<i64>,
+    db_cluster_identifier: Option<String>,
+    seconds_before_timeout: Option<i64>,
+    timeout_action: Option<String>,
+}
+
+impl ModifyCurrentDBClusterCapacityMessageBuilder {
+    /// Sets the [`capacity`](struct.ModifyCurrentDBClusterCapacityMessage.html#structfield.capacity) member.
+    pub fn capacity<T: Into<i64>>(mut self, value: T) -> Self {
+        self.capacity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.ModifyCurrentDBClusterCapacityMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`seconds_before_timeout`](struct.ModifyCurrentDBClusterCapacityMessage.html#structfield.seconds_before_timeout) member.
+    pub fn seconds_before_timeout<T: Into<i64>>(mut self, value: T) -> Self {
+        self.seconds_before_timeout = Some(value.into());
+        self
+    }
+
+    /// Sets the [`timeout_action`](struct.ModifyCurrentDBClusterCapacityMessage.html#structfield.timeout_action) member.
+    pub fn timeout_action<T: Into<String>>(mut self, value: T) -> Self {
+        self.timeout_action = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyCurrentDBClusterCapacityMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyCurrentDBClusterCapacityMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ModifyCurrentDBClusterCapacityMessage {
+            capacity: self.capacity,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            seconds_before_timeout: self.seconds_before_timeout,
+            timeout_action: self.timeout_action,
+        })
+    }
+}
+
 /// Serialize `ModifyCurrentDBClusterCapacityMessage` contents to a `SignedRequest`.
 struct ModifyCurrentDBClusterCapacityMessageSerializer;
 impl ModifyCurrentDBClusterCapacityMessageSerializer {
@@ -9650,6 +14849,62 @@ pub struct ModifyDBClusterEndpointMessage {
     pub static_members: Option<Vec<String>>,
 }
 
+impl ModifyDBClusterEndpointMessage {
+    /// Returns a builder for `ModifyDBClusterEndpointMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBClusterEndpointMessageBuilder {
+        ModifyDBClusterEndpointMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBClusterEndpointMessage`](struct.ModifyDBClusterEndpointMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBClusterEndpointMessageBuilder {
+    db_cluster_endpoint_identifier: Option<String>,
+    endpoint_type: Option<String>,
+    excluded_members: Option<Vec<String>>,
+    static_members: Option<Vec<String>>,
+}
+
+impl ModifyDBClusterEndpointMessageBuilder {
+    /// Sets the [`db_cluster_endpoint_identifier`](struct.ModifyDBClusterEndpointMessage.html#structfield.db_cluster_endpoint_identifier) member.
+    pub fn db_cluster_endpoint_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_endpoint_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`endpoint_type`](struct.ModifyDBClusterEndpointMessage.html#structfield.endpoint_type) member.
+    pub fn endpoint_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.endpoint_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`excluded_members`](struct.ModifyDBClusterEndpointMessage.html#structfield.excluded_members) member.
+    pub fn excluded_members<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.excluded_members = Some(value.into());
+        self
+    }
+
+    /// Sets the [`static_members`](struct.ModifyDBClusterEndpointMessage.html#structfield.static_members) member.
+    pub fn static_members<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.static_members = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBClusterEndpointMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBClusterEndpointMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBClusterEndpointMessage {
+            db_cluster_endpoint_identifier: self.db_cluster_endpoint_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_endpoint_identifier"),
+            )?,
+            endpoint_type: self.endpoint_type,
+            excluded_members: self.excluded_members,
+            static_members: self.static_members,
+        })
+    }
+}
+
 /// Serialize `ModifyDBClusterEndpointMessage` contents to a `SignedRequest`.
 struct ModifyDBClusterEndpointMessageSerializer;
 impl ModifyDBClusterEndpointMessageSerializer {
@@ -9730,6 +14985,199 @@ pub struct ModifyDBClusterMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl ModifyDBClusterMessage {
+    /// Returns a builder for `ModifyDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBClusterMessageBuilder {
+        ModifyDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBClusterMessage`](struct.ModifyDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBClusterMessageBuilder {
+    allow_major_version_upgrade: Option<bool>,
+    apply_immediately: Option<bool>,
+    backtrack_window: Option<i64>,
+    backup_retention_period: Option<i64>,
+    cloudwatch_logs_export_configuration: Option<CloudwatchLogsExportConfiguration>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_cluster_identifier: Option<String>,
+    db_cluster_parameter_group_name: Option<String>,
+    db_instance_parameter_group_name: Option<String>,
+    deletion_protection: Option<bool>,
+    enable_http_endpoint: Option<bool>,
+    enable_iam_database_authentication: Option<bool>,
+    engine_version: Option<String>,
+    master_user_password: Option<String>,
+    new_db_cluster_identifier: Option<String>,
+    option_group_name: Option<String>,
+    port: Option<i64>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    scaling_configuration: Option<ScalingConfiguration>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl ModifyDBClusterMessageBuilder {
+    /// Sets the [`allow_major_version_upgrade`](struct.ModifyDBClusterMessage.html#structfield.allow_major_version_upgrade) member.
+    pub fn allow_major_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_major_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`apply_immediately`](struct.ModifyDBClusterMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backtrack_window`](struct.ModifyDBClusterMessage.html#structfield.backtrack_window) member.
+    pub fn backtrack_window<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backtrack_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_period`](struct.ModifyDBClusterMessage.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cloudwatch_logs_export_configuration`](struct.ModifyDBClusterMessage.html#structfield.cloudwatch_logs_export_configuration) member.
+    pub fn cloudwatch_logs_export_configuration<T: Into<CloudwatchLogsExportConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cloudwatch_logs_export_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.ModifyDBClusterMessage.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.ModifyDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_parameter_group_name`](struct.ModifyDBClusterMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_parameter_group_name`](struct.ModifyDBClusterMessage.html#structfield.db_instance_parameter_group_name) member.
+    pub fn db_instance_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.ModifyDBClusterMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_http_endpoint`](struct.ModifyDBClusterMessage.html#structfield.enable_http_endpoint) member.
+    pub fn enable_http_endpoint<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_http_endpoint = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.ModifyDBClusterMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.ModifyDBClusterMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.ModifyDBClusterMessage.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_db_cluster_identifier`](struct.ModifyDBClusterMessage.html#structfield.new_db_cluster_identifier) member.
+    pub fn new_db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.ModifyDBClusterMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.ModifyDBClusterMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.ModifyDBClusterMessage.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.ModifyDBClusterMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_configuration`](struct.ModifyDBClusterMessage.html#structfield.scaling_configuration) member.
+    pub fn scaling_configuration<T: Into<ScalingConfiguration>>(mut self, value: T) -> Self {
+        self.scaling_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.ModifyDBClusterMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ModifyDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBClusterMessage {
+            allow_major_version_upgrade: self.allow_major_version_upgrade,
+            apply_immediately: self.apply_immediately,
+            backtrack_window: self.backtrack_window,
+            backup_retention_period: self.backup_retention_period,
+            cloudwatch_logs_export_configuration: self.cloudwatch_logs_export_configuration,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            db_instance_parameter_group_name: self.db_instance_parameter_group_name,
+            deletion_protection: self.deletion_protection,
+            enable_http_endpoint: self.enable_http_endpoint,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            engine_version: self.engine_version,
+            master_user_password: self.master_user_password,
+            new_db_cluster_identifier: self.new_db_cluster_identifier,
+            option_group_name: self.option_group_name,
+            port: self.port,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            scaling_configuration: self.scaling_configuration,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `ModifyDBClusterMessage` contents to a `SignedRequest`.
 struct ModifyDBClusterMessageSerializer;
 impl ModifyDBClusterMessageSerializer {
@@ -9851,6 +15299,49 @@ pub struct ModifyDBClusterParameterGroupMessage {
     pub parameters: Vec<Parameter>,
 }
 
+impl ModifyDBClusterParameterGroupMessage {
+    /// Returns a builder for `ModifyDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBClusterParameterGroupMessageBuilder {
+        ModifyDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBClusterParameterGroupMessage`](struct.ModifyDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBClusterParameterGroupMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+}
+
+impl ModifyDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.ModifyDBClusterParameterGroupMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ModifyDBClusterParameterGroupMessage.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBClusterParameterGroupMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ModifyDBClusterParameterGroupMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+            parameters: self
+                .parameters
+                .ok_or(::rusoto_core::BuildError::missing("parameters"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct ModifyDBClusterParameterGroupMessageSerializer;
 impl ModifyDBClusterParameterGroupMessageSerializer {
@@ -9877,10 +15368,10 @@ pub struct ModifyDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct ModifyDBClusterResultDeserializer;
+pub(crate) struct ModifyDBClusterResultDeserializer;
 impl ModifyDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBClusterResult, XmlParseError> {
@@ -9908,6 +15399,65 @@ pub struct ModifyDBClusterSnapshotAttributeMessage {
     pub values_to_remove: Option<Vec<String>>,
 }
 
+impl ModifyDBClusterSnapshotAttributeMessage {
+    /// Returns a builder for `ModifyDBClusterSnapshotAttributeMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBClusterSnapshotAttributeMessageBuilder {
+        ModifyDBClusterSnapshotAttributeMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBClusterSnapshotAttributeMessage`](struct.ModifyDBClusterSnapshotAttributeMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBClusterSnapshotAttributeMessageBuilder {
+    attribute_name: Option<String>,
+    db_cluster_snapshot_identifier: Option<String>,
+    values_to_add: Option<Vec<String>>,
+    values_to_remove: Option<Vec<String>>,
+}
+
+impl ModifyDBClusterSnapshotAttributeMessageBuilder {
+    /// Sets the [`attribute_name`](struct.ModifyDBClusterSnapshotAttributeMessage.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_snapshot_identifier`](struct.ModifyDBClusterSnapshotAttributeMessage.html#structfield.db_cluster_snapshot_identifier) member.
+    pub fn db_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`values_to_add`](struct.ModifyDBClusterSnapshotAttributeMessage.html#structfield.values_to_add) member.
+    pub fn values_to_add<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.values_to_add = Some(value.into());
+        self
+    }
+
+    /// Sets the [`values_to_remove`](struct.ModifyDBClusterSnapshotAttributeMessage.html#structfield.values_to_remove) member.
+    pub fn values_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.values_to_remove = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBClusterSnapshotAttributeMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBClusterSnapshotAttributeMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ModifyDBClusterSnapshotAttributeMessage {
+            attribute_name: self
+                .attribute_name
+                .ok_or(::rusoto_core::BuildError::missing("attribute_name"))?,
+            db_cluster_snapshot_identifier: self.db_cluster_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_snapshot_identifier"),
+            )?,
+            values_to_add: self.values_to_add,
+            values_to_remove: self.values_to_remove,
+        })
+    }
+}
+
 /// Serialize `ModifyDBClusterSnapshotAttributeMessage` contents to a `SignedRequest`.
 struct ModifyDBClusterSnapshotAttributeMessageSerializer;
 impl ModifyDBClusterSnapshotAttributeMessageSerializer {
@@ -9947,10 +15497,10 @@ pub struct ModifyDBClusterSnapshotAttributeResult {
     pub db_cluster_snapshot_attributes_result: Option<DBClusterSnapshotAttributesResult>,
 }
 
-struct ModifyDBClusterSnapshotAttributeResultDeserializer;
+pub(crate) struct ModifyDBClusterSnapshotAttributeResultDeserializer;
 impl ModifyDBClusterSnapshotAttributeResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBClusterSnapshotAttributeResult, XmlParseError> {
@@ -10060,49 +15610,404 @@ pub struct ModifyDBInstanceMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
-/// Serialize `ModifyDBInstanceMessage` contents to a `SignedRequest`.
-struct ModifyDBInstanceMessageSerializer;
-impl ModifyDBInstanceMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &ModifyDBInstanceMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl ModifyDBInstanceMessage {
+    /// Returns a builder for `ModifyDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBInstanceMessageBuilder {
+        ModifyDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBInstanceMessage`](struct.ModifyDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBInstanceMessageBuilder {
+    allocated_storage: Option<i64>,
+    allow_major_version_upgrade: Option<bool>,
+    apply_immediately: Option<bool>,
+    auto_minor_version_upgrade: Option<bool>,
+    backup_retention_period: Option<i64>,
+    ca_certificate_identifier: Option<String>,
+    cloudwatch_logs_export_configuration: Option<CloudwatchLogsExportConfiguration>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_instance_class: Option<String>,
+    db_instance_identifier: Option<String>,
+    db_parameter_group_name: Option<String>,
+    db_port_number: Option<i64>,
+    db_security_groups: Option<Vec<String>>,
+    db_subnet_group_name: Option<String>,
+    deletion_protection: Option<bool>,
+    domain: Option<String>,
+    domain_iam_role_name: Option<String>,
+    enable_iam_database_authentication: Option<bool>,
+    enable_performance_insights: Option<bool>,
+    engine_version: Option<String>,
+    iops: Option<i64>,
+    license_model: Option<String>,
+    master_user_password: Option<String>,
+    max_allocated_storage: Option<i64>,
+    monitoring_interval: Option<i64>,
+    monitoring_role_arn: Option<String>,
+    multi_az: Option<bool>,
+    new_db_instance_identifier: Option<String>,
+    option_group_name: Option<String>,
+    performance_insights_kms_key_id: Option<String>,
+    performance_insights_retention_period: Option<i64>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    processor_features: Option<Vec<ProcessorFeature>>,
+    promotion_tier: Option<i64>,
+    publicly_accessible: Option<bool>,
+    storage_type: Option<String>,
+    tde_credential_arn: Option<String>,
+    tde_credential_password: Option<String>,
+    use_default_processor_features: Option<bool>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
 
-        if let Some(ref field_value) = obj.allocated_storage {
-            params.put(&format!("{}{}", prefix, "AllocatedStorage"), &field_value);
-        }
-        if let Some(ref field_value) = obj.allow_major_version_upgrade {
-            params.put(
-                &format!("{}{}", prefix, "AllowMajorVersionUpgrade"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.apply_immediately {
-            params.put(&format!("{}{}", prefix, "ApplyImmediately"), &field_value);
-        }
-        if let Some(ref field_value) = obj.auto_minor_version_upgrade {
-            params.put(
-                &format!("{}{}", prefix, "AutoMinorVersionUpgrade"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.backup_retention_period {
-            params.put(
-                &format!("{}{}", prefix, "BackupRetentionPeriod"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.ca_certificate_identifier {
-            params.put(
-                &format!("{}{}", prefix, "CACertificateIdentifier"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.cloudwatch_logs_export_configuration {
-            CloudwatchLogsExportConfigurationSerializer::serialize(
-                params,
-                &format!("{}{}", prefix, "CloudwatchLogsExportConfiguration"),
+impl ModifyDBInstanceMessageBuilder {
+    /// Sets the [`allocated_storage`](struct.ModifyDBInstanceMessage.html#structfield.allocated_storage) member.
+    pub fn allocated_storage<T: Into<i64>>(mut self, value: T) -> Self {
+        self.allocated_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`allow_major_version_upgrade`](struct.ModifyDBInstanceMessage.html#structfield.allow_major_version_upgrade) member.
+    pub fn allow_major_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_major_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`apply_immediately`](struct.ModifyDBInstanceMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_minor_version_upgrade`](struct.ModifyDBInstanceMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_period`](struct.ModifyDBInstanceMessage.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ca_certificate_identifier`](struct.ModifyDBInstanceMessage.html#structfield.ca_certificate_identifier) member.
+    pub fn ca_certificate_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.ca_certificate_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cloudwatch_logs_export_configuration`](struct.ModifyDBInstanceMessage.html#structfield.cloudwatch_logs_export_configuration) member.
+    pub fn cloudwatch_logs_export_configuration<T: Into<CloudwatchLogsExportConfiguration>>(
+        mut self,
+        value: T,
+    ) -> Self {
+        self.cloudwatch_logs_export_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.ModifyDBInstanceMessage.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_class`](struct.ModifyDBInstanceMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_identifier`](struct.ModifyDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_name`](struct.ModifyDBInstanceMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_port_number`](struct.ModifyDBInstanceMessage.html#structfield.db_port_number) member.
+    pub fn db_port_number<T: Into<i64>>(mut self, value: T) -> Self {
+        self.db_port_number = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_security_groups`](struct.ModifyDBInstanceMessage.html#structfield.db_security_groups) member.
+    pub fn db_security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.db_security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.ModifyDBInstanceMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.ModifyDBInstanceMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.ModifyDBInstanceMessage.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_iam_role_name`](struct.ModifyDBInstanceMessage.html#structfield.domain_iam_role_name) member.
+    pub fn domain_iam_role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_iam_role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.ModifyDBInstanceMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_performance_insights`](struct.ModifyDBInstanceMessage.html#structfield.enable_performance_insights) member.
+    pub fn enable_performance_insights<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_performance_insights = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.ModifyDBInstanceMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iops`](struct.ModifyDBInstanceMessage.html#structfield.iops) member.
+    pub fn iops<T: Into<i64>>(mut self, value: T) -> Self {
+        self.iops = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_model`](struct.ModifyDBInstanceMessage.html#structfield.license_model) member.
+    pub fn license_model<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_model = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.ModifyDBInstanceMessage.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_allocated_storage`](struct.ModifyDBInstanceMessage.html#structfield.max_allocated_storage) member.
+    pub fn max_allocated_storage<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_allocated_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`monitoring_interval`](struct.ModifyDBInstanceMessage.html#structfield.monitoring_interval) member.
+    pub fn monitoring_interval<T: Into<i64>>(mut self, value: T) -> Self {
+        self.monitoring_interval = Some(value.into());
+        self
+    }
+
+    /// Sets the [`monitoring_role_arn`](struct.ModifyDBInstanceMessage.html#structfield.monitoring_role_arn) member.
+    pub fn monitoring_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.monitoring_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.ModifyDBInstanceMessage.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_db_instance_identifier`](struct.ModifyDBInstanceMessage.html#structfield.new_db_instance_identifier) member.
+    pub fn new_db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.ModifyDBInstanceMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`performance_insights_kms_key_id`](struct.ModifyDBInstanceMessage.html#structfield.performance_insights_kms_key_id) member.
+    pub fn performance_insights_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.performance_insights_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`performance_insights_retention_period`](struct.ModifyDBInstanceMessage.html#structfield.performance_insights_retention_period) member.
+    pub fn performance_insights_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.performance_insights_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.ModifyDBInstanceMessage.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.ModifyDBInstanceMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`processor_features`](struct.ModifyDBInstanceMessage.html#structfield.processor_features) member.
+    pub fn processor_features<T: Into<Vec<ProcessorFeature>>>(mut self, value: T) -> Self {
+        self.processor_features = Some(value.into());
+        self
+    }
+
+    /// Sets the [`promotion_tier`](struct.ModifyDBInstanceMessage.html#structfield.promotion_tier) member.
+    pub fn promotion_tier<T: Into<i64>>(mut self, value: T) -> Self {
+        self.promotion_tier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.ModifyDBInstanceMessage.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_type`](struct.ModifyDBInstanceMessage.html#structfield.storage_type) member.
+    pub fn storage_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.storage_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tde_credential_arn`](struct.ModifyDBInstanceMessage.html#structfield.tde_credential_arn) member.
+    pub fn tde_credential_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tde_credential_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tde_credential_password`](struct.ModifyDBInstanceMessage.html#structfield.tde_credential_password) member.
+    pub fn tde_credential_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.tde_credential_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_default_processor_features`](struct.ModifyDBInstanceMessage.html#structfield.use_default_processor_features) member.
+    pub fn use_default_processor_features<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_default_processor_features = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.ModifyDBInstanceMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBInstanceMessage {
+            allocated_storage: self.allocated_storage,
+            allow_major_version_upgrade: self.allow_major_version_upgrade,
+            apply_immediately: self.apply_immediately,
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            backup_retention_period: self.backup_retention_period,
+            ca_certificate_identifier: self.ca_certificate_identifier,
+            cloudwatch_logs_export_configuration: self.cloudwatch_logs_export_configuration,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_instance_class: self.db_instance_class,
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            db_parameter_group_name: self.db_parameter_group_name,
+            db_port_number: self.db_port_number,
+            db_security_groups: self.db_security_groups,
+            db_subnet_group_name: self.db_subnet_group_name,
+            deletion_protection: self.deletion_protection,
+            domain: self.domain,
+            domain_iam_role_name: self.domain_iam_role_name,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            enable_performance_insights: self.enable_performance_insights,
+            engine_version: self.engine_version,
+            iops: self.iops,
+            license_model: self.license_model,
+            master_user_password: self.master_user_password,
+            max_allocated_storage: self.max_allocated_storage,
+            monitoring_interval: self.monitoring_interval,
+            monitoring_role_arn: self.monitoring_role_arn,
+            multi_az: self.multi_az,
+            new_db_instance_identifier: self.new_db_instance_identifier,
+            option_group_name: self.option_group_name,
+            performance_insights_kms_key_id: self.performance_insights_kms_key_id,
+            performance_insights_retention_period: self.performance_insights_retention_period,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            processor_features: self.processor_features,
+            promotion_tier: self.promotion_tier,
+            publicly_accessible: self.publicly_accessible,
+            storage_type: self.storage_type,
+            tde_credential_arn: self.tde_credential_arn,
+            tde_credential_password: self.tde_credential_password,
+            use_default_processor_features: self.use_default_processor_features,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
+/// Serialize `ModifyDBInstanceMessage` contents to a `SignedRequest`.
+struct ModifyDBInstanceMessageSerializer;
+impl ModifyDBInstanceMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &ModifyDBInstanceMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.allocated_storage {
+            params.put(&format!("{}{}", prefix, "AllocatedStorage"), &field_value);
+        }
+        if let Some(ref field_value) = obj.allow_major_version_upgrade {
+            params.put(
+                &format!("{}{}", prefix, "AllowMajorVersionUpgrade"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.apply_immediately {
+            params.put(&format!("{}{}", prefix, "ApplyImmediately"), &field_value);
+        }
+        if let Some(ref field_value) = obj.auto_minor_version_upgrade {
+            params.put(
+                &format!("{}{}", prefix, "AutoMinorVersionUpgrade"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.backup_retention_period {
+            params.put(
+                &format!("{}{}", prefix, "BackupRetentionPeriod"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.ca_certificate_identifier {
+            params.put(
+                &format!("{}{}", prefix, "CACertificateIdentifier"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.cloudwatch_logs_export_configuration {
+            CloudwatchLogsExportConfigurationSerializer::serialize(
+                params,
+                &format!("{}{}", prefix, "CloudwatchLogsExportConfiguration"),
                 field_value,
             );
         }
@@ -10262,10 +16167,10 @@ pub struct ModifyDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct ModifyDBInstanceResultDeserializer;
+pub(crate) struct ModifyDBInstanceResultDeserializer;
 impl ModifyDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBInstanceResult, XmlParseError> {
@@ -10290,6 +16195,48 @@ pub struct ModifyDBParameterGroupMessage {
     pub parameters: Vec<Parameter>,
 }
 
+impl ModifyDBParameterGroupMessage {
+    /// Returns a builder for `ModifyDBParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBParameterGroupMessageBuilder {
+        ModifyDBParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBParameterGroupMessage`](struct.ModifyDBParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBParameterGroupMessageBuilder {
+    db_parameter_group_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+}
+
+impl ModifyDBParameterGroupMessageBuilder {
+    /// Sets the [`db_parameter_group_name`](struct.ModifyDBParameterGroupMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ModifyDBParameterGroupMessage.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBParameterGroupMessage {
+            db_parameter_group_name: self.db_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_name"),
+            )?,
+            parameters: self
+                .parameters
+                .ok_or(::rusoto_core::BuildError::missing("parameters"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyDBParameterGroupMessage` contents to a `SignedRequest`.
 struct ModifyDBParameterGroupMessageSerializer;
 impl ModifyDBParameterGroupMessageSerializer {
@@ -10324,6 +16271,64 @@ pub struct ModifyDBSnapshotAttributeMessage {
     pub values_to_remove: Option<Vec<String>>,
 }
 
+impl ModifyDBSnapshotAttributeMessage {
+    /// Returns a builder for `ModifyDBSnapshotAttributeMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBSnapshotAttributeMessageBuilder {
+        ModifyDBSnapshotAttributeMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBSnapshotAttributeMessage`](struct.ModifyDBSnapshotAttributeMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBSnapshotAttributeMessageBuilder {
+    attribute_name: Option<String>,
+    db_snapshot_identifier: Option<String>,
+    values_to_add: Option<Vec<String>>,
+    values_to_remove: Option<Vec<String>>,
+}
+
+impl ModifyDBSnapshotAttributeMessageBuilder {
+    /// Sets the [`attribute_name`](struct.ModifyDBSnapshotAttributeMessage.html#structfield.attribute_name) member.
+    pub fn attribute_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.attribute_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_snapshot_identifier`](struct.ModifyDBSnapshotAttributeMessage.html#structfield.db_snapshot_identifier) member.
+    pub fn db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`values_to_add`](struct.ModifyDBSnapshotAttributeMessage.html#structfield.values_to_add) member.
+    pub fn values_to_add<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.values_to_add = Some(value.into());
+        self
+    }
+
+    /// Sets the [`values_to_remove`](struct.ModifyDBSnapshotAttributeMessage.html#structfield.values_to_remove) member.
+    pub fn values_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.values_to_remove = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBSnapshotAttributeMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBSnapshotAttributeMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBSnapshotAttributeMessage {
+            attribute_name: self
+                .attribute_name
+                .ok_or(::rusoto_core::BuildError::missing("attribute_name"))?,
+            db_snapshot_identifier: self
+                .db_snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_snapshot_identifier"))?,
+            values_to_add: self.values_to_add,
+            values_to_remove: self.values_to_remove,
+        })
+    }
+}
+
 /// Serialize `ModifyDBSnapshotAttributeMessage` contents to a `SignedRequest`.
 struct ModifyDBSnapshotAttributeMessageSerializer;
 impl ModifyDBSnapshotAttributeMessageSerializer {
@@ -10363,10 +16368,10 @@ pub struct ModifyDBSnapshotAttributeResult {
     pub db_snapshot_attributes_result: Option<DBSnapshotAttributesResult>,
 }
 
-struct ModifyDBSnapshotAttributeResultDeserializer;
+pub(crate) struct ModifyDBSnapshotAttributeResultDeserializer;
 impl ModifyDBSnapshotAttributeResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBSnapshotAttributeResult, XmlParseError> {
@@ -10399,6 +16404,54 @@ pub struct ModifyDBSnapshotMessage {
     pub option_group_name: Option<String>,
 }
 
+impl ModifyDBSnapshotMessage {
+    /// Returns a builder for `ModifyDBSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBSnapshotMessageBuilder {
+        ModifyDBSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBSnapshotMessage`](struct.ModifyDBSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBSnapshotMessageBuilder {
+    db_snapshot_identifier: Option<String>,
+    engine_version: Option<String>,
+    option_group_name: Option<String>,
+}
+
+impl ModifyDBSnapshotMessageBuilder {
+    /// Sets the [`db_snapshot_identifier`](struct.ModifyDBSnapshotMessage.html#structfield.db_snapshot_identifier) member.
+    pub fn db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.ModifyDBSnapshotMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.ModifyDBSnapshotMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBSnapshotMessage {
+            db_snapshot_identifier: self
+                .db_snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_snapshot_identifier"))?,
+            engine_version: self.engine_version,
+            option_group_name: self.option_group_name,
+        })
+    }
+}
+
 /// Serialize `ModifyDBSnapshotMessage` contents to a `SignedRequest`.
 struct ModifyDBSnapshotMessageSerializer;
 impl ModifyDBSnapshotMessageSerializer {
@@ -10426,10 +16479,10 @@ pub struct ModifyDBSnapshotResult {
     pub db_snapshot: Option<DBSnapshot>,
 }
 
-struct ModifyDBSnapshotResultDeserializer;
+pub(crate) struct ModifyDBSnapshotResultDeserializer;
 impl ModifyDBSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBSnapshotResult, XmlParseError> {
@@ -10456,6 +16509,56 @@ pub struct ModifyDBSubnetGroupMessage {
     pub subnet_ids: Vec<String>,
 }
 
+impl ModifyDBSubnetGroupMessage {
+    /// Returns a builder for `ModifyDBSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyDBSubnetGroupMessageBuilder {
+        ModifyDBSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyDBSubnetGroupMessage`](struct.ModifyDBSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyDBSubnetGroupMessageBuilder {
+    db_subnet_group_description: Option<String>,
+    db_subnet_group_name: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+}
+
+impl ModifyDBSubnetGroupMessageBuilder {
+    /// Sets the [`db_subnet_group_description`](struct.ModifyDBSubnetGroupMessage.html#structfield.db_subnet_group_description) member.
+    pub fn db_subnet_group_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.ModifyDBSubnetGroupMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.ModifyDBSubnetGroupMessage.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyDBSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyDBSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyDBSubnetGroupMessage {
+            db_subnet_group_description: self.db_subnet_group_description,
+            db_subnet_group_name: self
+                .db_subnet_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_subnet_group_name"))?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyDBSubnetGroupMessage` contents to a `SignedRequest`.
 struct ModifyDBSubnetGroupMessageSerializer;
 impl ModifyDBSubnetGroupMessageSerializer {
@@ -10488,10 +16591,10 @@ pub struct ModifyDBSubnetGroupResult {
     pub db_subnet_group: Option<DBSubnetGroup>,
 }
 
-struct ModifyDBSubnetGroupResultDeserializer;
+pub(crate) struct ModifyDBSubnetGroupResultDeserializer;
 impl ModifyDBSubnetGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyDBSubnetGroupResult, XmlParseError> {
@@ -10528,6 +16631,70 @@ pub struct ModifyEventSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl ModifyEventSubscriptionMessage {
+    /// Returns a builder for `ModifyEventSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> ModifyEventSubscriptionMessageBuilder {
+        ModifyEventSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyEventSubscriptionMessage`](struct.ModifyEventSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyEventSubscriptionMessageBuilder {
+    enabled: Option<bool>,
+    event_categories: Option<Vec<String>>,
+    sns_topic_arn: Option<String>,
+    source_type: Option<String>,
+    subscription_name: Option<String>,
+}
+
+impl ModifyEventSubscriptionMessageBuilder {
+    /// Sets the [`enabled`](struct.ModifyEventSubscriptionMessage.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_categories`](struct.ModifyEventSubscriptionMessage.html#structfield.event_categories) member.
+    pub fn event_categories<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_categories = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.ModifyEventSubscriptionMessage.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.ModifyEventSubscriptionMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.ModifyEventSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyEventSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyEventSubscriptionMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyEventSubscriptionMessage {
+            enabled: self.enabled,
+            event_categories: self.event_categories,
+            sns_topic_arn: self.sns_topic_arn,
+            source_type: self.source_type,
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyEventSubscriptionMessage` contents to a `SignedRequest`.
 struct ModifyEventSubscriptionMessageSerializer;
 impl ModifyEventSubscriptionMessageSerializer {
@@ -10565,10 +16732,10 @@ pub struct ModifyEventSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
 
-struct ModifyEventSubscriptionResultDeserializer;
+pub(crate) struct ModifyEventSubscriptionResultDeserializer;
 impl ModifyEventSubscriptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyEventSubscriptionResult, XmlParseError> {
@@ -10600,6 +16767,52 @@ pub struct ModifyGlobalClusterMessage {
     pub new_global_cluster_identifier: Option<String>,
 }
 
+impl ModifyGlobalClusterMessage {
+    /// Returns a builder for `ModifyGlobalClusterMessage`, checking its required members when built.
+    pub fn builder() -> ModifyGlobalClusterMessageBuilder {
+        ModifyGlobalClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyGlobalClusterMessage`](struct.ModifyGlobalClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyGlobalClusterMessageBuilder {
+    deletion_protection: Option<bool>,
+    global_cluster_identifier: Option<String>,
+    new_global_cluster_identifier: Option<String>,
+}
+
+impl ModifyGlobalClusterMessageBuilder {
+    /// Sets the [`deletion_protection`](struct.ModifyGlobalClusterMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`global_cluster_identifier`](struct.ModifyGlobalClusterMessage.html#structfield.global_cluster_identifier) member.
+    pub fn global_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.global_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_global_cluster_identifier`](struct.ModifyGlobalClusterMessage.html#structfield.new_global_cluster_identifier) member.
+    pub fn new_global_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_global_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyGlobalClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyGlobalClusterMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyGlobalClusterMessage {
+            deletion_protection: self.deletion_protection,
+            global_cluster_identifier: self.global_cluster_identifier,
+            new_global_cluster_identifier: self.new_global_cluster_identifier,
+        })
+    }
+}
+
 /// Serialize `ModifyGlobalClusterMessage` contents to a `SignedRequest`.
 struct ModifyGlobalClusterMessageSerializer;
 impl ModifyGlobalClusterMessageSerializer {
@@ -10632,10 +16845,10 @@ pub struct ModifyGlobalClusterResult {
     pub global_cluster: Option<GlobalCluster>,
 }
 
-struct ModifyGlobalClusterResultDeserializer;
+pub(crate) struct ModifyGlobalClusterResultDeserializer;
 impl ModifyGlobalClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyGlobalClusterResult, XmlParseError> {
@@ -10670,6 +16883,62 @@ pub struct ModifyOptionGroupMessage {
     pub options_to_remove: Option<Vec<String>>,
 }
 
+impl ModifyOptionGroupMessage {
+    /// Returns a builder for `ModifyOptionGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyOptionGroupMessageBuilder {
+        ModifyOptionGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyOptionGroupMessage`](struct.ModifyOptionGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyOptionGroupMessageBuilder {
+    apply_immediately: Option<bool>,
+    option_group_name: Option<String>,
+    options_to_include: Option<Vec<OptionConfiguration>>,
+    options_to_remove: Option<Vec<String>>,
+}
+
+impl ModifyOptionGroupMessageBuilder {
+    /// Sets the [`apply_immediately`](struct.ModifyOptionGroupMessage.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.ModifyOptionGroupMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`options_to_include`](struct.ModifyOptionGroupMessage.html#structfield.options_to_include) member.
+    pub fn options_to_include<T: Into<Vec<OptionConfiguration>>>(mut self, value: T) -> Self {
+        self.options_to_include = Some(value.into());
+        self
+    }
+
+    /// Sets the [`options_to_remove`](struct.ModifyOptionGroupMessage.html#structfield.options_to_remove) member.
+    pub fn options_to_remove<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.options_to_remove = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyOptionGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyOptionGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyOptionGroupMessage {
+            apply_immediately: self.apply_immediately,
+            option_group_name: self
+                .option_group_name
+                .ok_or(::rusoto_core::BuildError::missing("option_group_name"))?,
+            options_to_include: self.options_to_include,
+            options_to_remove: self.options_to_remove,
+        })
+    }
+}
+
 /// Serialize `ModifyOptionGroupMessage` contents to a `SignedRequest`.
 struct ModifyOptionGroupMessageSerializer;
 impl ModifyOptionGroupMessageSerializer {
@@ -10708,10 +16977,10 @@ pub struct ModifyOptionGroupResult {
     pub option_group: Option<OptionGroup>,
 }
 
-struct ModifyOptionGroupResultDeserializer;
+pub(crate) struct ModifyOptionGroupResultDeserializer;
 impl ModifyOptionGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyOptionGroupResult, XmlParseError> {
@@ -10754,10 +17023,10 @@ pub struct RDSOption {
     pub vpc_security_group_memberships: Option<Vec<VpcSecurityGroupMembership>>,
 }
 
-struct RDSOptionDeserializer;
+pub(crate) struct RDSOptionDeserializer;
 impl RDSOptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RDSOption, XmlParseError> {
@@ -10902,10 +17171,10 @@ pub struct OptionGroup {
     pub vpc_id: Option<String>,
 }
 
-struct OptionGroupDeserializer;
+pub(crate) struct OptionGroupDeserializer;
 impl OptionGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OptionGroup, XmlParseError> {
@@ -10964,10 +17233,10 @@ pub struct OptionGroupMembership {
     pub status: Option<String>,
 }
 
-struct OptionGroupMembershipDeserializer;
+pub(crate) struct OptionGroupMembershipDeserializer;
 impl OptionGroupMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OptionGroupMembership, XmlParseError> {
@@ -10986,10 +17255,10 @@ impl OptionGroupMembershipDeserializer {
         })
     }
 }
-struct OptionGroupMembershipListDeserializer;
+pub(crate) struct OptionGroupMembershipListDeserializer;
 impl OptionGroupMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OptionGroupMembership>, XmlParseError> {
@@ -11043,10 +17312,10 @@ pub struct OptionGroupOption {
     pub vpc_only: Option<bool>,
 }
 
-struct OptionGroupOptionDeserializer;
+pub(crate) struct OptionGroupOptionDeserializer;
 impl OptionGroupOptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OptionGroupOption, XmlParseError> {
@@ -11163,10 +17432,10 @@ pub struct OptionGroupOptionSetting {
     pub setting_name: Option<String>,
 }
 
-struct OptionGroupOptionSettingDeserializer;
+pub(crate) struct OptionGroupOptionSettingDeserializer;
 impl OptionGroupOptionSettingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OptionGroupOptionSetting, XmlParseError> {
@@ -11221,10 +17490,10 @@ impl OptionGroupOptionSettingDeserializer {
         )
     }
 }
-struct OptionGroupOptionSettingsListDeserializer;
+pub(crate) struct OptionGroupOptionSettingsListDeserializer;
 impl OptionGroupOptionSettingsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OptionGroupOptionSetting>, XmlParseError> {
@@ -11241,10 +17510,10 @@ impl OptionGroupOptionSettingsListDeserializer {
         })
     }
 }
-struct OptionGroupOptionVersionsListDeserializer;
+pub(crate) struct OptionGroupOptionVersionsListDeserializer;
 impl OptionGroupOptionVersionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OptionVersion>, XmlParseError> {
@@ -11261,10 +17530,10 @@ impl OptionGroupOptionVersionsListDeserializer {
         })
     }
 }
-struct OptionGroupOptionsListDeserializer;
+pub(crate) struct OptionGroupOptionsListDeserializer;
 impl OptionGroupOptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OptionGroupOption>, XmlParseError> {
@@ -11289,10 +17558,10 @@ pub struct OptionGroupOptionsMessage {
     pub option_group_options: Option<Vec<OptionGroupOption>>,
 }
 
-struct OptionGroupOptionsMessageDeserializer;
+pub(crate) struct OptionGroupOptionsMessageDeserializer;
 impl OptionGroupOptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OptionGroupOptionsMessage, XmlParseError> {
@@ -11328,10 +17597,10 @@ pub struct OptionGroups {
     pub option_groups_list: Option<Vec<OptionGroup>>,
 }
 
-struct OptionGroupsDeserializer;
+pub(crate) struct OptionGroupsDeserializer;
 impl OptionGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OptionGroups, XmlParseError> {
@@ -11351,10 +17620,10 @@ impl OptionGroupsDeserializer {
         })
     }
 }
-struct OptionGroupsListDeserializer;
+pub(crate) struct OptionGroupsListDeserializer;
 impl OptionGroupsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OptionGroup>, XmlParseError> {
@@ -11403,10 +17672,10 @@ pub struct OptionSetting {
     pub value: Option<String>,
 }
 
-struct OptionSettingDeserializer;
+pub(crate) struct OptionSettingDeserializer;
 impl OptionSettingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OptionSetting, XmlParseError> {
@@ -11489,10 +17758,10 @@ impl OptionSettingSerializer {
     }
 }
 
-struct OptionSettingConfigurationListDeserializer;
+pub(crate) struct OptionSettingConfigurationListDeserializer;
 impl OptionSettingConfigurationListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OptionSetting>, XmlParseError> {
@@ -11530,10 +17799,10 @@ pub struct OptionVersion {
     pub version: Option<String>,
 }
 
-struct OptionVersionDeserializer;
+pub(crate) struct OptionVersionDeserializer;
 impl OptionVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OptionVersion, XmlParseError> {
@@ -11551,10 +17820,10 @@ impl OptionVersionDeserializer {
         })
     }
 }
-struct OptionsConflictsWithDeserializer;
+pub(crate) struct OptionsConflictsWithDeserializer;
 impl OptionsConflictsWithDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -11571,10 +17840,10 @@ impl OptionsConflictsWithDeserializer {
         })
     }
 }
-struct OptionsDependedOnDeserializer;
+pub(crate) struct OptionsDependedOnDeserializer;
 impl OptionsDependedOnDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -11588,10 +17857,10 @@ impl OptionsDependedOnDeserializer {
         })
     }
 }
-struct OptionsListDeserializer;
+pub(crate) struct OptionsListDeserializer;
 impl OptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<RDSOption>, XmlParseError> {
@@ -11656,10 +17925,10 @@ pub struct OrderableDBInstanceOption {
     pub vpc: Option<bool>,
 }
 
-struct OrderableDBInstanceOptionDeserializer;
+pub(crate) struct OrderableDBInstanceOptionDeserializer;
 impl OrderableDBInstanceOptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OrderableDBInstanceOption, XmlParseError> {
@@ -11802,10 +18071,10 @@ impl OrderableDBInstanceOptionDeserializer {
         )
     }
 }
-struct OrderableDBInstanceOptionsListDeserializer;
+pub(crate) struct OrderableDBInstanceOptionsListDeserializer;
 impl OrderableDBInstanceOptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OrderableDBInstanceOption>, XmlParseError> {
@@ -11831,10 +18100,10 @@ pub struct OrderableDBInstanceOptionsMessage {
     pub orderable_db_instance_options: Option<Vec<OrderableDBInstanceOption>>,
 }
 
-struct OrderableDBInstanceOptionsMessageDeserializer;
+pub(crate) struct OrderableDBInstanceOptionsMessageDeserializer;
 impl OrderableDBInstanceOptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OrderableDBInstanceOptionsMessage, XmlParseError> {
@@ -11867,7 +18136,7 @@ pub struct Parameter {
     /// <p>Specifies the valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
     /// <p>Indicates when to apply parameter updates.</p>
-    pub apply_method: Option<String>,
+    pub apply_method: Option<ApplyMethod>,
     /// <p>Specifies the engine specific parameters type.</p>
     pub apply_type: Option<String>,
     /// <p>Specifies the valid data type for the parameter.</p>
@@ -11888,10 +18157,10 @@ pub struct Parameter {
     pub supported_engine_modes: Option<Vec<String>>,
 }
 
-struct ParameterDeserializer;
+pub(crate) struct ParameterDeserializer;
 impl ParameterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Parameter, XmlParseError> {
@@ -11999,10 +18268,10 @@ impl ParameterSerializer {
     }
 }
 
-struct ParametersListDeserializer;
+pub(crate) struct ParametersListDeserializer;
 impl ParametersListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Parameter>, XmlParseError> {
@@ -12037,10 +18306,10 @@ pub struct PendingCloudwatchLogsExports {
     pub log_types_to_enable: Option<Vec<String>>,
 }
 
-struct PendingCloudwatchLogsExportsDeserializer;
+pub(crate) struct PendingCloudwatchLogsExportsDeserializer;
 impl PendingCloudwatchLogsExportsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingCloudwatchLogsExports, XmlParseError> {
@@ -12072,21 +18341,21 @@ pub struct PendingMaintenanceAction {
     /// <p>The type of pending maintenance action that is available for the resource. Valid actions are <code>system-update</code>, <code>db-upgrade</code>, and <code>hardware-maintenance</code>.</p>
     pub action: Option<String>,
     /// <p>The date of the maintenance window when the action is applied. The maintenance action is applied to the resource during its first maintenance window after this date.</p>
-    pub auto_applied_after_date: Option<String>,
+    pub auto_applied_after_date: Option<IsoTimestamp>,
     /// <p>The effective date when the pending maintenance action is applied to the resource. This date takes into account opt-in requests received from the <code>ApplyPendingMaintenanceAction</code> API, the <code>AutoAppliedAfterDate</code>, and the <code>ForcedApplyDate</code>. This value is blank if an opt-in request has not been received and nothing has been specified as <code>AutoAppliedAfterDate</code> or <code>ForcedApplyDate</code>.</p>
-    pub current_apply_date: Option<String>,
+    pub current_apply_date: Option<IsoTimestamp>,
     /// <p>A description providing more detail about the maintenance action.</p>
     pub description: Option<String>,
     /// <p>The date when the maintenance action is automatically applied. The maintenance action is applied to the resource on this date regardless of the maintenance window for the resource.</p>
-    pub forced_apply_date: Option<String>,
+    pub forced_apply_date: Option<IsoTimestamp>,
     /// <p>Indicates the type of opt-in request that has been received for the resource.</p>
     pub opt_in_status: Option<String>,
 }
 
-struct PendingMaintenanceActionDeserializer;
+pub(crate) struct PendingMaintenanceActionDeserializer;
 impl PendingMaintenanceActionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingMaintenanceAction, XmlParseError> {
@@ -12127,10 +18396,10 @@ impl PendingMaintenanceActionDeserializer {
         )
     }
 }
-struct PendingMaintenanceActionDetailsDeserializer;
+pub(crate) struct PendingMaintenanceActionDetailsDeserializer;
 impl PendingMaintenanceActionDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<PendingMaintenanceAction>, XmlParseError> {
@@ -12147,10 +18416,10 @@ impl PendingMaintenanceActionDetailsDeserializer {
         })
     }
 }
-struct PendingMaintenanceActionsDeserializer;
+pub(crate) struct PendingMaintenanceActionsDeserializer;
 impl PendingMaintenanceActionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ResourcePendingMaintenanceActions>, XmlParseError> {
@@ -12176,10 +18445,10 @@ pub struct PendingMaintenanceActionsMessage {
     pub pending_maintenance_actions: Option<Vec<ResourcePendingMaintenanceActions>>,
 }
 
-struct PendingMaintenanceActionsMessageDeserializer;
+pub(crate) struct PendingMaintenanceActionsMessageDeserializer;
 impl PendingMaintenanceActionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingMaintenanceActionsMessage, XmlParseError> {
@@ -12240,10 +18509,10 @@ pub struct PendingModifiedValues {
     pub storage_type: Option<String>,
 }
 
-struct PendingModifiedValuesDeserializer;
+pub(crate) struct PendingModifiedValuesDeserializer;
 impl PendingModifiedValuesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingModifiedValues, XmlParseError> {
@@ -12335,10 +18604,10 @@ pub struct ProcessorFeature {
     pub value: Option<String>,
 }
 
-struct ProcessorFeatureDeserializer;
+pub(crate) struct ProcessorFeatureDeserializer;
 impl ProcessorFeatureDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ProcessorFeature, XmlParseError> {
@@ -12375,10 +18644,10 @@ impl ProcessorFeatureSerializer {
     }
 }
 
-struct ProcessorFeatureListDeserializer;
+pub(crate) struct ProcessorFeatureListDeserializer;
 impl ProcessorFeatureListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ProcessorFeature>, XmlParseError> {
@@ -12414,6 +18683,38 @@ pub struct PromoteReadReplicaDBClusterMessage {
     pub db_cluster_identifier: String,
 }
 
+impl PromoteReadReplicaDBClusterMessage {
+    /// Returns a builder for `PromoteReadReplicaDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> PromoteReadReplicaDBClusterMessageBuilder {
+        PromoteReadReplicaDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`PromoteReadReplicaDBClusterMessage`](struct.PromoteReadReplicaDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct PromoteReadReplicaDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+}
+
+impl PromoteReadReplicaDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.PromoteReadReplicaDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `PromoteReadReplicaDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PromoteReadReplicaDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(PromoteReadReplicaDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `PromoteReadReplicaDBClusterMessage` contents to a `SignedRequest`.
 struct PromoteReadReplicaDBClusterMessageSerializer;
 impl PromoteReadReplicaDBClusterMessageSerializer {
@@ -12435,10 +18736,10 @@ pub struct PromoteReadReplicaDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct PromoteReadReplicaDBClusterResultDeserializer;
+pub(crate) struct PromoteReadReplicaDBClusterResultDeserializer;
 impl PromoteReadReplicaDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PromoteReadReplicaDBClusterResult, XmlParseError> {
@@ -12469,11 +18770,59 @@ pub struct PromoteReadReplicaMessage {
     pub preferred_backup_window: Option<String>,
 }
 
-/// Serialize `PromoteReadReplicaMessage` contents to a `SignedRequest`.
-struct PromoteReadReplicaMessageSerializer;
-impl PromoteReadReplicaMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &PromoteReadReplicaMessage) {
-        let mut prefix = name.to_string();
+impl PromoteReadReplicaMessage {
+    /// Returns a builder for `PromoteReadReplicaMessage`, checking its required members when built.
+    pub fn builder() -> PromoteReadReplicaMessageBuilder {
+        PromoteReadReplicaMessageBuilder::default()
+    }
+}
+
+/// Builds a [`PromoteReadReplicaMessage`](struct.PromoteReadReplicaMessage.html).
+#[derive(Default, Debug)]
+pub struct PromoteReadReplicaMessageBuilder {
+    backup_retention_period: Option<i64>,
+    db_instance_identifier: Option<String>,
+    preferred_backup_window: Option<String>,
+}
+
+impl PromoteReadReplicaMessageBuilder {
+    /// Sets the [`backup_retention_period`](struct.PromoteReadReplicaMessage.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_identifier`](struct.PromoteReadReplicaMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.PromoteReadReplicaMessage.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Builds the `PromoteReadReplicaMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PromoteReadReplicaMessage, ::rusoto_core::BuildError> {
+        Ok(PromoteReadReplicaMessage {
+            backup_retention_period: self.backup_retention_period,
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            preferred_backup_window: self.preferred_backup_window,
+        })
+    }
+}
+
+/// Serialize `PromoteReadReplicaMessage` contents to a `SignedRequest`.
+struct PromoteReadReplicaMessageSerializer;
+impl PromoteReadReplicaMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &PromoteReadReplicaMessage) {
+        let mut prefix = name.to_string();
         if prefix != "" {
             prefix.push_str(".");
         }
@@ -12502,10 +18851,10 @@ pub struct PromoteReadReplicaResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct PromoteReadReplicaResultDeserializer;
+pub(crate) struct PromoteReadReplicaResultDeserializer;
 impl PromoteReadReplicaResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PromoteReadReplicaResult, XmlParseError> {
@@ -12537,6 +18886,63 @@ pub struct PurchaseReservedDBInstancesOfferingMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl PurchaseReservedDBInstancesOfferingMessage {
+    /// Returns a builder for `PurchaseReservedDBInstancesOfferingMessage`, checking its required members when built.
+    pub fn builder() -> PurchaseReservedDBInstancesOfferingMessageBuilder {
+        PurchaseReservedDBInstancesOfferingMessageBuilder::default()
+    }
+}
+
+/// Builds a [`PurchaseReservedDBInstancesOfferingMessage`](struct.PurchaseReservedDBInstancesOfferingMessage.html).
+#[derive(Default, Debug)]
+pub struct PurchaseReservedDBInstancesOfferingMessageBuilder {
+    db_instance_count: Option<i64>,
+    reserved_db_instance_id: Option<String>,
+    reserved_db_instances_offering_id: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl PurchaseReservedDBInstancesOfferingMessageBuilder {
+    /// Sets the [`db_instance_count`](struct.PurchaseReservedDBInstancesOfferingMessage.html#structfield.db_instance_count) member.
+    pub fn db_instance_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.db_instance_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_db_instance_id`](struct.PurchaseReservedDBInstancesOfferingMessage.html#structfield.reserved_db_instance_id) member.
+    pub fn reserved_db_instance_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_db_instance_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_db_instances_offering_id`](struct.PurchaseReservedDBInstancesOfferingMessage.html#structfield.reserved_db_instances_offering_id) member.
+    pub fn reserved_db_instances_offering_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_db_instances_offering_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.PurchaseReservedDBInstancesOfferingMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `PurchaseReservedDBInstancesOfferingMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PurchaseReservedDBInstancesOfferingMessage, ::rusoto_core::BuildError>
+    {
+        Ok(PurchaseReservedDBInstancesOfferingMessage {
+            db_instance_count: self.db_instance_count,
+            reserved_db_instance_id: self.reserved_db_instance_id,
+            reserved_db_instances_offering_id: self.reserved_db_instances_offering_id.ok_or(
+                ::rusoto_core::BuildError::missing("reserved_db_instances_offering_id"),
+            )?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `PurchaseReservedDBInstancesOfferingMessage` contents to a `SignedRequest`.
 struct PurchaseReservedDBInstancesOfferingMessageSerializer;
 impl PurchaseReservedDBInstancesOfferingMessageSerializer {
@@ -12574,10 +18980,10 @@ pub struct PurchaseReservedDBInstancesOfferingResult {
     pub reserved_db_instance: Option<ReservedDBInstance>,
 }
 
-struct PurchaseReservedDBInstancesOfferingResultDeserializer;
+pub(crate) struct PurchaseReservedDBInstancesOfferingResultDeserializer;
 impl PurchaseReservedDBInstancesOfferingResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PurchaseReservedDBInstancesOfferingResult, XmlParseError> {
@@ -12611,10 +19017,13 @@ pub struct Range {
     pub to: Option<i64>,
 }
 
-struct RangeDeserializer;
+pub(crate) struct RangeDeserializer;
 impl RangeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Range, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Range, XmlParseError> {
         deserialize_elements::<_, Range, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "From" => {
@@ -12632,10 +19041,10 @@ impl RangeDeserializer {
         })
     }
 }
-struct RangeListDeserializer;
+pub(crate) struct RangeListDeserializer;
 impl RangeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Range>, XmlParseError> {
@@ -12649,10 +19058,10 @@ impl RangeListDeserializer {
         })
     }
 }
-struct ReadReplicaDBClusterIdentifierListDeserializer;
+pub(crate) struct ReadReplicaDBClusterIdentifierListDeserializer;
 impl ReadReplicaDBClusterIdentifierListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -12669,10 +19078,10 @@ impl ReadReplicaDBClusterIdentifierListDeserializer {
         })
     }
 }
-struct ReadReplicaDBInstanceIdentifierListDeserializer;
+pub(crate) struct ReadReplicaDBInstanceIdentifierListDeserializer;
 impl ReadReplicaDBInstanceIdentifierListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -12689,10 +19098,10 @@ impl ReadReplicaDBInstanceIdentifierListDeserializer {
         })
     }
 }
-struct ReadReplicaIdentifierListDeserializer;
+pub(crate) struct ReadReplicaIdentifierListDeserializer;
 impl ReadReplicaIdentifierListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -12709,10 +19118,10 @@ impl ReadReplicaIdentifierListDeserializer {
         })
     }
 }
-struct ReadersArnListDeserializer;
+pub(crate) struct ReadersArnListDeserializer;
 impl ReadersArnListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -12735,6 +19144,46 @@ pub struct RebootDBInstanceMessage {
     pub force_failover: Option<bool>,
 }
 
+impl RebootDBInstanceMessage {
+    /// Returns a builder for `RebootDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> RebootDBInstanceMessageBuilder {
+        RebootDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RebootDBInstanceMessage`](struct.RebootDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct RebootDBInstanceMessageBuilder {
+    db_instance_identifier: Option<String>,
+    force_failover: Option<bool>,
+}
+
+impl RebootDBInstanceMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.RebootDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`force_failover`](struct.RebootDBInstanceMessage.html#structfield.force_failover) member.
+    pub fn force_failover<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force_failover = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebootDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RebootDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(RebootDBInstanceMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            force_failover: self.force_failover,
+        })
+    }
+}
+
 /// Serialize `RebootDBInstanceMessage` contents to a `SignedRequest`.
 struct RebootDBInstanceMessageSerializer;
 impl RebootDBInstanceMessageSerializer {
@@ -12759,10 +19208,10 @@ pub struct RebootDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct RebootDBInstanceResultDeserializer;
+pub(crate) struct RebootDBInstanceResultDeserializer;
 impl RebootDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RebootDBInstanceResult, XmlParseError> {
@@ -12787,10 +19236,10 @@ pub struct RecurringCharge {
     pub recurring_charge_frequency: Option<String>,
 }
 
-struct RecurringChargeDeserializer;
+pub(crate) struct RecurringChargeDeserializer;
 impl RecurringChargeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RecurringCharge, XmlParseError> {
@@ -12814,10 +19263,10 @@ impl RecurringChargeDeserializer {
         })
     }
 }
-struct RecurringChargeListDeserializer;
+pub(crate) struct RecurringChargeListDeserializer;
 impl RecurringChargeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<RecurringCharge>, XmlParseError> {
@@ -12842,6 +19291,44 @@ pub struct RemoveFromGlobalClusterMessage {
     pub global_cluster_identifier: Option<String>,
 }
 
+impl RemoveFromGlobalClusterMessage {
+    /// Returns a builder for `RemoveFromGlobalClusterMessage`, checking its required members when built.
+    pub fn builder() -> RemoveFromGlobalClusterMessageBuilder {
+        RemoveFromGlobalClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveFromGlobalClusterMessage`](struct.RemoveFromGlobalClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct RemoveFromGlobalClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    global_cluster_identifier: Option<String>,
+}
+
+impl RemoveFromGlobalClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.RemoveFromGlobalClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`global_cluster_identifier`](struct.RemoveFromGlobalClusterMessage.html#structfield.global_cluster_identifier) member.
+    pub fn global_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.global_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveFromGlobalClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveFromGlobalClusterMessage, ::rusoto_core::BuildError> {
+        Ok(RemoveFromGlobalClusterMessage {
+            db_cluster_identifier: self.db_cluster_identifier,
+            global_cluster_identifier: self.global_cluster_identifier,
+        })
+    }
+}
+
 /// Serialize `RemoveFromGlobalClusterMessage` contents to a `SignedRequest`.
 struct RemoveFromGlobalClusterMessageSerializer;
 impl RemoveFromGlobalClusterMessageSerializer {
@@ -12871,10 +19358,10 @@ pub struct RemoveFromGlobalClusterResult {
     pub global_cluster: Option<GlobalCluster>,
 }
 
-struct RemoveFromGlobalClusterResultDeserializer;
+pub(crate) struct RemoveFromGlobalClusterResultDeserializer;
 impl RemoveFromGlobalClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RemoveFromGlobalClusterResult, XmlParseError> {
@@ -12906,6 +19393,56 @@ pub struct RemoveRoleFromDBClusterMessage {
     pub role_arn: String,
 }
 
+impl RemoveRoleFromDBClusterMessage {
+    /// Returns a builder for `RemoveRoleFromDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> RemoveRoleFromDBClusterMessageBuilder {
+        RemoveRoleFromDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveRoleFromDBClusterMessage`](struct.RemoveRoleFromDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct RemoveRoleFromDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+    feature_name: Option<String>,
+    role_arn: Option<String>,
+}
+
+impl RemoveRoleFromDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.RemoveRoleFromDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`feature_name`](struct.RemoveRoleFromDBClusterMessage.html#structfield.feature_name) member.
+    pub fn feature_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.feature_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.RemoveRoleFromDBClusterMessage.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveRoleFromDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveRoleFromDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(RemoveRoleFromDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            feature_name: self.feature_name,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveRoleFromDBClusterMessage` contents to a `SignedRequest`.
 struct RemoveRoleFromDBClusterMessageSerializer;
 impl RemoveRoleFromDBClusterMessageSerializer {
@@ -12936,6 +19473,58 @@ pub struct RemoveRoleFromDBInstanceMessage {
     pub role_arn: String,
 }
 
+impl RemoveRoleFromDBInstanceMessage {
+    /// Returns a builder for `RemoveRoleFromDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> RemoveRoleFromDBInstanceMessageBuilder {
+        RemoveRoleFromDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveRoleFromDBInstanceMessage`](struct.RemoveRoleFromDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct RemoveRoleFromDBInstanceMessageBuilder {
+    db_instance_identifier: Option<String>,
+    feature_name: Option<String>,
+    role_arn: Option<String>,
+}
+
+impl RemoveRoleFromDBInstanceMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.RemoveRoleFromDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`feature_name`](struct.RemoveRoleFromDBInstanceMessage.html#structfield.feature_name) member.
+    pub fn feature_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.feature_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.RemoveRoleFromDBInstanceMessage.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveRoleFromDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveRoleFromDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(RemoveRoleFromDBInstanceMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            feature_name: self
+                .feature_name
+                .ok_or(::rusoto_core::BuildError::missing("feature_name"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveRoleFromDBInstanceMessage` contents to a `SignedRequest`.
 struct RemoveRoleFromDBInstanceMessageSerializer;
 impl RemoveRoleFromDBInstanceMessageSerializer {
@@ -12963,6 +19552,51 @@ pub struct RemoveSourceIdentifierFromSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl RemoveSourceIdentifierFromSubscriptionMessage {
+    /// Returns a builder for `RemoveSourceIdentifierFromSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> RemoveSourceIdentifierFromSubscriptionMessageBuilder {
+        RemoveSourceIdentifierFromSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveSourceIdentifierFromSubscriptionMessage`](struct.RemoveSourceIdentifierFromSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct RemoveSourceIdentifierFromSubscriptionMessageBuilder {
+    source_identifier: Option<String>,
+    subscription_name: Option<String>,
+}
+
+impl RemoveSourceIdentifierFromSubscriptionMessageBuilder {
+    /// Sets the [`source_identifier`](struct.RemoveSourceIdentifierFromSubscriptionMessage.html#structfield.source_identifier) member.
+    pub fn source_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.RemoveSourceIdentifierFromSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveSourceIdentifierFromSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        RemoveSourceIdentifierFromSubscriptionMessage,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(RemoveSourceIdentifierFromSubscriptionMessage {
+            source_identifier: self
+                .source_identifier
+                .ok_or(::rusoto_core::BuildError::missing("source_identifier"))?,
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveSourceIdentifierFromSubscriptionMessage` contents to a `SignedRequest`.
 struct RemoveSourceIdentifierFromSubscriptionMessageSerializer;
 impl RemoveSourceIdentifierFromSubscriptionMessageSerializer {
@@ -12992,10 +19626,10 @@ pub struct RemoveSourceIdentifierFromSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
 
-struct RemoveSourceIdentifierFromSubscriptionResultDeserializer;
+pub(crate) struct RemoveSourceIdentifierFromSubscriptionResultDeserializer;
 impl RemoveSourceIdentifierFromSubscriptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RemoveSourceIdentifierFromSubscriptionResult, XmlParseError> {
@@ -13026,6 +19660,48 @@ pub struct RemoveTagsFromResourceMessage {
     pub tag_keys: Vec<String>,
 }
 
+impl RemoveTagsFromResourceMessage {
+    /// Returns a builder for `RemoveTagsFromResourceMessage`, checking its required members when built.
+    pub fn builder() -> RemoveTagsFromResourceMessageBuilder {
+        RemoveTagsFromResourceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RemoveTagsFromResourceMessage`](struct.RemoveTagsFromResourceMessage.html).
+#[derive(Default, Debug)]
+pub struct RemoveTagsFromResourceMessageBuilder {
+    resource_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl RemoveTagsFromResourceMessageBuilder {
+    /// Sets the [`resource_name`](struct.RemoveTagsFromResourceMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.RemoveTagsFromResourceMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `RemoveTagsFromResourceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RemoveTagsFromResourceMessage, ::rusoto_core::BuildError> {
+        Ok(RemoveTagsFromResourceMessage {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// Serialize `RemoveTagsFromResourceMessage` contents to a `SignedRequest`.
 struct RemoveTagsFromResourceMessageSerializer;
 impl RemoveTagsFromResourceMessageSerializer {
@@ -13068,17 +19744,17 @@ pub struct ReservedDBInstance {
     /// <p>The offering identifier.</p>
     pub reserved_db_instances_offering_id: Option<String>,
     /// <p>The time the reservation started.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
     /// <p>The state of the reserved DB instance.</p>
     pub state: Option<String>,
     /// <p>The hourly price charged for this reserved DB instance.</p>
     pub usage_price: Option<f64>,
 }
 
-struct ReservedDBInstanceDeserializer;
+pub(crate) struct ReservedDBInstanceDeserializer;
 impl ReservedDBInstanceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReservedDBInstance, XmlParseError> {
@@ -13153,10 +19829,10 @@ impl ReservedDBInstanceDeserializer {
         })
     }
 }
-struct ReservedDBInstanceListDeserializer;
+pub(crate) struct ReservedDBInstanceListDeserializer;
 impl ReservedDBInstanceListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ReservedDBInstance>, XmlParseError> {
@@ -13182,10 +19858,10 @@ pub struct ReservedDBInstanceMessage {
     pub reserved_db_instances: Option<Vec<ReservedDBInstance>>,
 }
 
-struct ReservedDBInstanceMessageDeserializer;
+pub(crate) struct ReservedDBInstanceMessageDeserializer;
 impl ReservedDBInstanceMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReservedDBInstanceMessage, XmlParseError> {
@@ -13237,10 +19913,10 @@ pub struct ReservedDBInstancesOffering {
     pub usage_price: Option<f64>,
 }
 
-struct ReservedDBInstancesOfferingDeserializer;
+pub(crate) struct ReservedDBInstancesOfferingDeserializer;
 impl ReservedDBInstancesOfferingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReservedDBInstancesOffering, XmlParseError> {
@@ -13303,10 +19979,10 @@ impl ReservedDBInstancesOfferingDeserializer {
         )
     }
 }
-struct ReservedDBInstancesOfferingListDeserializer;
+pub(crate) struct ReservedDBInstancesOfferingListDeserializer;
 impl ReservedDBInstancesOfferingListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ReservedDBInstancesOffering>, XmlParseError> {
@@ -13332,10 +20008,10 @@ pub struct ReservedDBInstancesOfferingMessage {
     pub reserved_db_instances_offerings: Option<Vec<ReservedDBInstancesOffering>>,
 }
 
-struct ReservedDBInstancesOfferingMessageDeserializer;
+pub(crate) struct ReservedDBInstancesOfferingMessageDeserializer;
 impl ReservedDBInstancesOfferingMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReservedDBInstancesOfferingMessage, XmlParseError> {
@@ -13373,6 +20049,54 @@ pub struct ResetDBClusterParameterGroupMessage {
     pub reset_all_parameters: Option<bool>,
 }
 
+impl ResetDBClusterParameterGroupMessage {
+    /// Returns a builder for `ResetDBClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ResetDBClusterParameterGroupMessageBuilder {
+        ResetDBClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ResetDBClusterParameterGroupMessage`](struct.ResetDBClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ResetDBClusterParameterGroupMessageBuilder {
+    db_cluster_parameter_group_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+    reset_all_parameters: Option<bool>,
+}
+
+impl ResetDBClusterParameterGroupMessageBuilder {
+    /// Sets the [`db_cluster_parameter_group_name`](struct.ResetDBClusterParameterGroupMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ResetDBClusterParameterGroupMessage.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reset_all_parameters`](struct.ResetDBClusterParameterGroupMessage.html#structfield.reset_all_parameters) member.
+    pub fn reset_all_parameters<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reset_all_parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetDBClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResetDBClusterParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ResetDBClusterParameterGroupMessage {
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_cluster_parameter_group_name"),
+            )?,
+            parameters: self.parameters,
+            reset_all_parameters: self.reset_all_parameters,
+        })
+    }
+}
+
 /// Serialize `ResetDBClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct ResetDBClusterParameterGroupMessageSerializer;
 impl ResetDBClusterParameterGroupMessageSerializer {
@@ -13410,6 +20134,54 @@ pub struct ResetDBParameterGroupMessage {
     pub reset_all_parameters: Option<bool>,
 }
 
+impl ResetDBParameterGroupMessage {
+    /// Returns a builder for `ResetDBParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ResetDBParameterGroupMessageBuilder {
+        ResetDBParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ResetDBParameterGroupMessage`](struct.ResetDBParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ResetDBParameterGroupMessageBuilder {
+    db_parameter_group_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+    reset_all_parameters: Option<bool>,
+}
+
+impl ResetDBParameterGroupMessageBuilder {
+    /// Sets the [`db_parameter_group_name`](struct.ResetDBParameterGroupMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ResetDBParameterGroupMessage.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reset_all_parameters`](struct.ResetDBParameterGroupMessage.html#structfield.reset_all_parameters) member.
+    pub fn reset_all_parameters<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reset_all_parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetDBParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResetDBParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ResetDBParameterGroupMessage {
+            db_parameter_group_name: self.db_parameter_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("db_parameter_group_name"),
+            )?,
+            parameters: self.parameters,
+            reset_all_parameters: self.reset_all_parameters,
+        })
+    }
+}
+
 /// Serialize `ResetDBParameterGroupMessage` contents to a `SignedRequest`.
 struct ResetDBParameterGroupMessageSerializer;
 impl ResetDBParameterGroupMessageSerializer {
@@ -13445,10 +20217,10 @@ pub struct ResourcePendingMaintenanceActions {
     pub resource_identifier: Option<String>,
 }
 
-struct ResourcePendingMaintenanceActionsDeserializer;
+pub(crate) struct ResourcePendingMaintenanceActionsDeserializer;
 impl ResourcePendingMaintenanceActionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResourcePendingMaintenanceActions, XmlParseError> {
@@ -13539,6 +20311,276 @@ pub struct RestoreDBClusterFromS3Message {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl RestoreDBClusterFromS3Message {
+    /// Returns a builder for `RestoreDBClusterFromS3Message`, checking its required members when built.
+    pub fn builder() -> RestoreDBClusterFromS3MessageBuilder {
+        RestoreDBClusterFromS3MessageBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreDBClusterFromS3Message`](struct.RestoreDBClusterFromS3Message.html).
+#[derive(Default, Debug)]
+pub struct RestoreDBClusterFromS3MessageBuilder {
+    availability_zones: Option<Vec<String>>,
+    backtrack_window: Option<i64>,
+    backup_retention_period: Option<i64>,
+    character_set_name: Option<String>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_cluster_identifier: Option<String>,
+    db_cluster_parameter_group_name: Option<String>,
+    db_subnet_group_name: Option<String>,
+    database_name: Option<String>,
+    deletion_protection: Option<bool>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_iam_database_authentication: Option<bool>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    kms_key_id: Option<String>,
+    master_user_password: Option<String>,
+    master_username: Option<String>,
+    option_group_name: Option<String>,
+    port: Option<i64>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    s3_bucket_name: Option<String>,
+    s3_ingestion_role_arn: Option<String>,
+    s3_prefix: Option<String>,
+    source_engine: Option<String>,
+    source_engine_version: Option<String>,
+    storage_encrypted: Option<bool>,
+    tags: Option<Vec<Tag>>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl RestoreDBClusterFromS3MessageBuilder {
+    /// Sets the [`availability_zones`](struct.RestoreDBClusterFromS3Message.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backtrack_window`](struct.RestoreDBClusterFromS3Message.html#structfield.backtrack_window) member.
+    pub fn backtrack_window<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backtrack_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_period`](struct.RestoreDBClusterFromS3Message.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`character_set_name`](struct.RestoreDBClusterFromS3Message.html#structfield.character_set_name) member.
+    pub fn character_set_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.character_set_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.RestoreDBClusterFromS3Message.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.RestoreDBClusterFromS3Message.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_parameter_group_name`](struct.RestoreDBClusterFromS3Message.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.RestoreDBClusterFromS3Message.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.RestoreDBClusterFromS3Message.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.RestoreDBClusterFromS3Message.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.RestoreDBClusterFromS3Message.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.RestoreDBClusterFromS3Message.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.RestoreDBClusterFromS3Message.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.RestoreDBClusterFromS3Message.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.RestoreDBClusterFromS3Message.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.RestoreDBClusterFromS3Message.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_username`](struct.RestoreDBClusterFromS3Message.html#structfield.master_username) member.
+    pub fn master_username<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_username = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.RestoreDBClusterFromS3Message.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.RestoreDBClusterFromS3Message.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.RestoreDBClusterFromS3Message.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.RestoreDBClusterFromS3Message.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_bucket_name`](struct.RestoreDBClusterFromS3Message.html#structfield.s3_bucket_name) member.
+    pub fn s3_bucket_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_bucket_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_ingestion_role_arn`](struct.RestoreDBClusterFromS3Message.html#structfield.s3_ingestion_role_arn) member.
+    pub fn s3_ingestion_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_ingestion_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_prefix`](struct.RestoreDBClusterFromS3Message.html#structfield.s3_prefix) member.
+    pub fn s3_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_engine`](struct.RestoreDBClusterFromS3Message.html#structfield.source_engine) member.
+    pub fn source_engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_engine_version`](struct.RestoreDBClusterFromS3Message.html#structfield.source_engine_version) member.
+    pub fn source_engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_encrypted`](struct.RestoreDBClusterFromS3Message.html#structfield.storage_encrypted) member.
+    pub fn storage_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.storage_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RestoreDBClusterFromS3Message.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.RestoreDBClusterFromS3Message.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreDBClusterFromS3Message`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreDBClusterFromS3Message, ::rusoto_core::BuildError> {
+        Ok(RestoreDBClusterFromS3Message {
+            availability_zones: self.availability_zones,
+            backtrack_window: self.backtrack_window,
+            backup_retention_period: self.backup_retention_period,
+            character_set_name: self.character_set_name,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            db_subnet_group_name: self.db_subnet_group_name,
+            database_name: self.database_name,
+            deletion_protection: self.deletion_protection,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_version: self.engine_version,
+            kms_key_id: self.kms_key_id,
+            master_user_password: self
+                .master_user_password
+                .ok_or(::rusoto_core::BuildError::missing("master_user_password"))?,
+            master_username: self
+                .master_username
+                .ok_or(::rusoto_core::BuildError::missing("master_username"))?,
+            option_group_name: self.option_group_name,
+            port: self.port,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            s3_bucket_name: self
+                .s3_bucket_name
+                .ok_or(::rusoto_core::BuildError::missing("s3_bucket_name"))?,
+            s3_ingestion_role_arn: self
+                .s3_ingestion_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("s3_ingestion_role_arn"))?,
+            s3_prefix: self.s3_prefix,
+            source_engine: self
+                .source_engine
+                .ok_or(::rusoto_core::BuildError::missing("source_engine"))?,
+            source_engine_version: self
+                .source_engine_version
+                .ok_or(::rusoto_core::BuildError::missing("source_engine_version"))?,
+            storage_encrypted: self.storage_encrypted,
+            tags: self.tags,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `RestoreDBClusterFromS3Message` contents to a `SignedRequest`.
 struct RestoreDBClusterFromS3MessageSerializer;
 impl RestoreDBClusterFromS3MessageSerializer {
@@ -13672,10 +20714,10 @@ pub struct RestoreDBClusterFromS3Result {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct RestoreDBClusterFromS3ResultDeserializer;
+pub(crate) struct RestoreDBClusterFromS3ResultDeserializer;
 impl RestoreDBClusterFromS3ResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreDBClusterFromS3Result, XmlParseError> {
@@ -13740,42 +20782,230 @@ pub struct RestoreDBClusterFromSnapshotMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
-/// Serialize `RestoreDBClusterFromSnapshotMessage` contents to a `SignedRequest`.
-struct RestoreDBClusterFromSnapshotMessageSerializer;
-impl RestoreDBClusterFromSnapshotMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &RestoreDBClusterFromSnapshotMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl RestoreDBClusterFromSnapshotMessage {
+    /// Returns a builder for `RestoreDBClusterFromSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> RestoreDBClusterFromSnapshotMessageBuilder {
+        RestoreDBClusterFromSnapshotMessageBuilder::default()
+    }
+}
 
-        if let Some(ref field_value) = obj.availability_zones {
-            AvailabilityZonesSerializer::serialize(
-                params,
-                &format!("{}{}", prefix, "AvailabilityZone"),
-                field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.backtrack_window {
-            params.put(&format!("{}{}", prefix, "BacktrackWindow"), &field_value);
-        }
-        if let Some(ref field_value) = obj.copy_tags_to_snapshot {
-            params.put(&format!("{}{}", prefix, "CopyTagsToSnapshot"), &field_value);
-        }
-        params.put(
-            &format!("{}{}", prefix, "DBClusterIdentifier"),
-            &obj.db_cluster_identifier,
-        );
-        if let Some(ref field_value) = obj.db_cluster_parameter_group_name {
-            params.put(
-                &format!("{}{}", prefix, "DBClusterParameterGroupName"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.db_subnet_group_name {
-            params.put(&format!("{}{}", prefix, "DBSubnetGroupName"), &field_value);
-        }
-        if let Some(ref field_value) = obj.database_name {
+/// Builds a [`RestoreDBClusterFromSnapshotMessage`](struct.RestoreDBClusterFromSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct RestoreDBClusterFromSnapshotMessageBuilder {
+    availability_zones: Option<Vec<String>>,
+    backtrack_window: Option<i64>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_cluster_identifier: Option<String>,
+    db_cluster_parameter_group_name: Option<String>,
+    db_subnet_group_name: Option<String>,
+    database_name: Option<String>,
+    deletion_protection: Option<bool>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_iam_database_authentication: Option<bool>,
+    engine: Option<String>,
+    engine_mode: Option<String>,
+    engine_version: Option<String>,
+    kms_key_id: Option<String>,
+    option_group_name: Option<String>,
+    port: Option<i64>,
+    scaling_configuration: Option<ScalingConfiguration>,
+    snapshot_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl RestoreDBClusterFromSnapshotMessageBuilder {
+    /// Sets the [`availability_zones`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.availability_zones) member.
+    pub fn availability_zones<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.availability_zones = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backtrack_window`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.backtrack_window) member.
+    pub fn backtrack_window<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backtrack_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_parameter_group_name`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`database_name`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.database_name) member.
+    pub fn database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_mode`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.engine_mode) member.
+    pub fn engine_mode<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`scaling_configuration`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.scaling_configuration) member.
+    pub fn scaling_configuration<T: Into<ScalingConfiguration>>(mut self, value: T) -> Self {
+        self.scaling_configuration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_identifier`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.snapshot_identifier) member.
+    pub fn snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.RestoreDBClusterFromSnapshotMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreDBClusterFromSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreDBClusterFromSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(RestoreDBClusterFromSnapshotMessage {
+            availability_zones: self.availability_zones,
+            backtrack_window: self.backtrack_window,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            db_subnet_group_name: self.db_subnet_group_name,
+            database_name: self.database_name,
+            deletion_protection: self.deletion_protection,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_mode: self.engine_mode,
+            engine_version: self.engine_version,
+            kms_key_id: self.kms_key_id,
+            option_group_name: self.option_group_name,
+            port: self.port,
+            scaling_configuration: self.scaling_configuration,
+            snapshot_identifier: self
+                .snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_identifier"))?,
+            tags: self.tags,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
+/// Serialize `RestoreDBClusterFromSnapshotMessage` contents to a `SignedRequest`.
+struct RestoreDBClusterFromSnapshotMessageSerializer;
+impl RestoreDBClusterFromSnapshotMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &RestoreDBClusterFromSnapshotMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.availability_zones {
+            AvailabilityZonesSerializer::serialize(
+                params,
+                &format!("{}{}", prefix, "AvailabilityZone"),
+                field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.backtrack_window {
+            params.put(&format!("{}{}", prefix, "BacktrackWindow"), &field_value);
+        }
+        if let Some(ref field_value) = obj.copy_tags_to_snapshot {
+            params.put(&format!("{}{}", prefix, "CopyTagsToSnapshot"), &field_value);
+        }
+        params.put(
+            &format!("{}{}", prefix, "DBClusterIdentifier"),
+            &obj.db_cluster_identifier,
+        );
+        if let Some(ref field_value) = obj.db_cluster_parameter_group_name {
+            params.put(
+                &format!("{}{}", prefix, "DBClusterParameterGroupName"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.db_subnet_group_name {
+            params.put(&format!("{}{}", prefix, "DBSubnetGroupName"), &field_value);
+        }
+        if let Some(ref field_value) = obj.database_name {
             params.put(&format!("{}{}", prefix, "DatabaseName"), &field_value);
         }
         if let Some(ref field_value) = obj.deletion_protection {
@@ -13839,10 +21069,10 @@ pub struct RestoreDBClusterFromSnapshotResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct RestoreDBClusterFromSnapshotResultDeserializer;
+pub(crate) struct RestoreDBClusterFromSnapshotResultDeserializer;
 impl RestoreDBClusterFromSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreDBClusterFromSnapshotResult, XmlParseError> {
@@ -13888,7 +21118,7 @@ pub struct RestoreDBClusterToPointInTimeMessage {
     /// <p>The port number on which the new DB cluster accepts connections.</p> <p>Constraints: A value from <code>1150-65535</code>. </p> <p>Default: The default port for the engine.</p>
     pub port: Option<i64>,
     /// <p>The date and time to restore the DB cluster to.</p> <p>Valid Values: Value must be a time in Universal Coordinated Time (UTC) format</p> <p>Constraints:</p> <ul> <li> <p>Must be before the latest restorable time for the DB instance</p> </li> <li> <p>Must be specified if <code>UseLatestRestorableTime</code> parameter is not provided</p> </li> <li> <p>Can't be specified if the <code>UseLatestRestorableTime</code> parameter is enabled</p> </li> <li> <p>Can't be specified if the <code>RestoreType</code> parameter is <code>copy-on-write</code> </p> </li> </ul> <p>Example: <code>2015-03-07T23:45:00Z</code> </p>
-    pub restore_to_time: Option<String>,
+    pub restore_to_time: Option<IsoTimestamp>,
     /// <p>The type of restore to be performed. You can specify one of the following values:</p> <ul> <li> <p> <code>full-copy</code> - The new DB cluster is restored as a full copy of the source DB cluster.</p> </li> <li> <p> <code>copy-on-write</code> - The new DB cluster is restored as a clone of the source DB cluster.</p> </li> </ul> <p>Constraints: You can't specify <code>copy-on-write</code> if the engine version of the source DB cluster is earlier than 1.11.</p> <p>If you don't specify a <code>RestoreType</code> value, then the new DB cluster is restored as a full copy of the source DB cluster.</p>
     pub restore_type: Option<String>,
     /// <p><p>The identifier of the source DB cluster from which to restore.</p> <p>Constraints:</p> <ul> <li> <p>Must match the identifier of an existing DBCluster.</p> </li> </ul></p>
@@ -13900,6 +21130,169 @@ pub struct RestoreDBClusterToPointInTimeMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl RestoreDBClusterToPointInTimeMessage {
+    /// Returns a builder for `RestoreDBClusterToPointInTimeMessage`, checking its required members when built.
+    pub fn builder() -> RestoreDBClusterToPointInTimeMessageBuilder {
+        RestoreDBClusterToPointInTimeMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreDBClusterToPointInTimeMessage`](struct.RestoreDBClusterToPointInTimeMessage.html).
+#[derive(Default, Debug)]
+pub struct RestoreDBClusterToPointInTimeMessageBuilder {
+    backtrack_window: Option<i64>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_cluster_identifier: Option<String>,
+    db_cluster_parameter_group_name: Option<String>,
+    db_subnet_group_name: Option<String>,
+    deletion_protection: Option<bool>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_iam_database_authentication: Option<bool>,
+    kms_key_id: Option<String>,
+    option_group_name: Option<String>,
+    port: Option<i64>,
+    restore_to_time: Option<IsoTimestamp>,
+    restore_type: Option<String>,
+    source_db_cluster_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    use_latest_restorable_time: Option<bool>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl RestoreDBClusterToPointInTimeMessageBuilder {
+    /// Sets the [`backtrack_window`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.backtrack_window) member.
+    pub fn backtrack_window<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backtrack_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_identifier`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_cluster_parameter_group_name`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.db_cluster_parameter_group_name) member.
+    pub fn db_cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`restore_to_time`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.restore_to_time) member.
+    pub fn restore_to_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.restore_to_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`restore_type`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.restore_type) member.
+    pub fn restore_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.restore_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_db_cluster_identifier`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.source_db_cluster_identifier) member.
+    pub fn source_db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_latest_restorable_time`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.use_latest_restorable_time) member.
+    pub fn use_latest_restorable_time<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_latest_restorable_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.RestoreDBClusterToPointInTimeMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreDBClusterToPointInTimeMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreDBClusterToPointInTimeMessage, ::rusoto_core::BuildError>
+    {
+        Ok(RestoreDBClusterToPointInTimeMessage {
+            backtrack_window: self.backtrack_window,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+            db_cluster_parameter_group_name: self.db_cluster_parameter_group_name,
+            db_subnet_group_name: self.db_subnet_group_name,
+            deletion_protection: self.deletion_protection,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            kms_key_id: self.kms_key_id,
+            option_group_name: self.option_group_name,
+            port: self.port,
+            restore_to_time: self.restore_to_time,
+            restore_type: self.restore_type,
+            source_db_cluster_identifier: self.source_db_cluster_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_db_cluster_identifier"),
+            )?,
+            tags: self.tags,
+            use_latest_restorable_time: self.use_latest_restorable_time,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `RestoreDBClusterToPointInTimeMessage` contents to a `SignedRequest`.
 struct RestoreDBClusterToPointInTimeMessageSerializer;
 impl RestoreDBClusterToPointInTimeMessageSerializer {
@@ -13987,10 +21380,10 @@ pub struct RestoreDBClusterToPointInTimeResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct RestoreDBClusterToPointInTimeResultDeserializer;
+pub(crate) struct RestoreDBClusterToPointInTimeResultDeserializer;
 impl RestoreDBClusterToPointInTimeResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreDBClusterToPointInTimeResult, XmlParseError> {
@@ -14070,6 +21463,257 @@ pub struct RestoreDBInstanceFromDBSnapshotMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl RestoreDBInstanceFromDBSnapshotMessage {
+    /// Returns a builder for `RestoreDBInstanceFromDBSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> RestoreDBInstanceFromDBSnapshotMessageBuilder {
+        RestoreDBInstanceFromDBSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreDBInstanceFromDBSnapshotMessage`](struct.RestoreDBInstanceFromDBSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct RestoreDBInstanceFromDBSnapshotMessageBuilder {
+    auto_minor_version_upgrade: Option<bool>,
+    availability_zone: Option<String>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_instance_class: Option<String>,
+    db_instance_identifier: Option<String>,
+    db_name: Option<String>,
+    db_parameter_group_name: Option<String>,
+    db_snapshot_identifier: Option<String>,
+    db_subnet_group_name: Option<String>,
+    deletion_protection: Option<bool>,
+    domain: Option<String>,
+    domain_iam_role_name: Option<String>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_iam_database_authentication: Option<bool>,
+    engine: Option<String>,
+    iops: Option<i64>,
+    license_model: Option<String>,
+    multi_az: Option<bool>,
+    option_group_name: Option<String>,
+    port: Option<i64>,
+    processor_features: Option<Vec<ProcessorFeature>>,
+    publicly_accessible: Option<bool>,
+    storage_type: Option<String>,
+    tags: Option<Vec<Tag>>,
+    tde_credential_arn: Option<String>,
+    tde_credential_password: Option<String>,
+    use_default_processor_features: Option<bool>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl RestoreDBInstanceFromDBSnapshotMessageBuilder {
+    /// Sets the [`auto_minor_version_upgrade`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zone`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_class`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_identifier`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_name`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.db_name) member.
+    pub fn db_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_name`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_snapshot_identifier`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.db_snapshot_identifier) member.
+    pub fn db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_iam_role_name`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.domain_iam_role_name) member.
+    pub fn domain_iam_role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_iam_role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iops`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.iops) member.
+    pub fn iops<T: Into<i64>>(mut self, value: T) -> Self {
+        self.iops = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_model`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.license_model) member.
+    pub fn license_model<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_model = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`processor_features`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.processor_features) member.
+    pub fn processor_features<T: Into<Vec<ProcessorFeature>>>(mut self, value: T) -> Self {
+        self.processor_features = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_type`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.storage_type) member.
+    pub fn storage_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.storage_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tde_credential_arn`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.tde_credential_arn) member.
+    pub fn tde_credential_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tde_credential_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tde_credential_password`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.tde_credential_password) member.
+    pub fn tde_credential_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.tde_credential_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_default_processor_features`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.use_default_processor_features) member.
+    pub fn use_default_processor_features<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_default_processor_features = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.RestoreDBInstanceFromDBSnapshotMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreDBInstanceFromDBSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreDBInstanceFromDBSnapshotMessage, ::rusoto_core::BuildError>
+    {
+        Ok(RestoreDBInstanceFromDBSnapshotMessage {
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            availability_zone: self.availability_zone,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_instance_class: self.db_instance_class,
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            db_name: self.db_name,
+            db_parameter_group_name: self.db_parameter_group_name,
+            db_snapshot_identifier: self
+                .db_snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_snapshot_identifier"))?,
+            db_subnet_group_name: self.db_subnet_group_name,
+            deletion_protection: self.deletion_protection,
+            domain: self.domain,
+            domain_iam_role_name: self.domain_iam_role_name,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            engine: self.engine,
+            iops: self.iops,
+            license_model: self.license_model,
+            multi_az: self.multi_az,
+            option_group_name: self.option_group_name,
+            port: self.port,
+            processor_features: self.processor_features,
+            publicly_accessible: self.publicly_accessible,
+            storage_type: self.storage_type,
+            tags: self.tags,
+            tde_credential_arn: self.tde_credential_arn,
+            tde_credential_password: self.tde_credential_password,
+            use_default_processor_features: self.use_default_processor_features,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `RestoreDBInstanceFromDBSnapshotMessage` contents to a `SignedRequest`.
 struct RestoreDBInstanceFromDBSnapshotMessageSerializer;
 impl RestoreDBInstanceFromDBSnapshotMessageSerializer {
@@ -14200,10 +21844,10 @@ pub struct RestoreDBInstanceFromDBSnapshotResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct RestoreDBInstanceFromDBSnapshotResultDeserializer;
+pub(crate) struct RestoreDBInstanceFromDBSnapshotResultDeserializer;
 impl RestoreDBInstanceFromDBSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreDBInstanceFromDBSnapshotResult, XmlParseError> {
@@ -14313,70 +21957,450 @@ pub struct RestoreDBInstanceFromS3Message {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
-/// Serialize `RestoreDBInstanceFromS3Message` contents to a `SignedRequest`.
-struct RestoreDBInstanceFromS3MessageSerializer;
-impl RestoreDBInstanceFromS3MessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &RestoreDBInstanceFromS3Message) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl RestoreDBInstanceFromS3Message {
+    /// Returns a builder for `RestoreDBInstanceFromS3Message`, checking its required members when built.
+    pub fn builder() -> RestoreDBInstanceFromS3MessageBuilder {
+        RestoreDBInstanceFromS3MessageBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreDBInstanceFromS3Message`](struct.RestoreDBInstanceFromS3Message.html).
+#[derive(Default, Debug)]
+pub struct RestoreDBInstanceFromS3MessageBuilder {
+    allocated_storage: Option<i64>,
+    auto_minor_version_upgrade: Option<bool>,
+    availability_zone: Option<String>,
+    backup_retention_period: Option<i64>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_instance_class: Option<String>,
+    db_instance_identifier: Option<String>,
+    db_name: Option<String>,
+    db_parameter_group_name: Option<String>,
+    db_security_groups: Option<Vec<String>>,
+    db_subnet_group_name: Option<String>,
+    deletion_protection: Option<bool>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_iam_database_authentication: Option<bool>,
+    enable_performance_insights: Option<bool>,
+    engine: Option<String>,
+    engine_version: Option<String>,
+    iops: Option<i64>,
+    kms_key_id: Option<String>,
+    license_model: Option<String>,
+    master_user_password: Option<String>,
+    master_username: Option<String>,
+    monitoring_interval: Option<i64>,
+    monitoring_role_arn: Option<String>,
+    multi_az: Option<bool>,
+    option_group_name: Option<String>,
+    performance_insights_kms_key_id: Option<String>,
+    performance_insights_retention_period: Option<i64>,
+    port: Option<i64>,
+    preferred_backup_window: Option<String>,
+    preferred_maintenance_window: Option<String>,
+    processor_features: Option<Vec<ProcessorFeature>>,
+    publicly_accessible: Option<bool>,
+    s3_bucket_name: Option<String>,
+    s3_ingestion_role_arn: Option<String>,
+    s3_prefix: Option<String>,
+    source_engine: Option<String>,
+    source_engine_version: Option<String>,
+    storage_encrypted: Option<bool>,
+    storage_type: Option<String>,
+    tags: Option<Vec<Tag>>,
+    use_default_processor_features: Option<bool>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
 
-        if let Some(ref field_value) = obj.allocated_storage {
-            params.put(&format!("{}{}", prefix, "AllocatedStorage"), &field_value);
-        }
-        if let Some(ref field_value) = obj.auto_minor_version_upgrade {
-            params.put(
-                &format!("{}{}", prefix, "AutoMinorVersionUpgrade"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.availability_zone {
-            params.put(&format!("{}{}", prefix, "AvailabilityZone"), &field_value);
-        }
-        if let Some(ref field_value) = obj.backup_retention_period {
-            params.put(
-                &format!("{}{}", prefix, "BackupRetentionPeriod"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.copy_tags_to_snapshot {
-            params.put(&format!("{}{}", prefix, "CopyTagsToSnapshot"), &field_value);
-        }
-        params.put(
-            &format!("{}{}", prefix, "DBInstanceClass"),
-            &obj.db_instance_class,
-        );
-        params.put(
-            &format!("{}{}", prefix, "DBInstanceIdentifier"),
-            &obj.db_instance_identifier,
-        );
-        if let Some(ref field_value) = obj.db_name {
-            params.put(&format!("{}{}", prefix, "DBName"), &field_value);
-        }
-        if let Some(ref field_value) = obj.db_parameter_group_name {
-            params.put(
-                &format!("{}{}", prefix, "DBParameterGroupName"),
-                &field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.db_security_groups {
-            DBSecurityGroupNameListSerializer::serialize(
-                params,
-                &format!("{}{}", prefix, "DBSecurityGroupName"),
-                field_value,
-            );
-        }
-        if let Some(ref field_value) = obj.db_subnet_group_name {
-            params.put(&format!("{}{}", prefix, "DBSubnetGroupName"), &field_value);
-        }
-        if let Some(ref field_value) = obj.deletion_protection {
-            params.put(&format!("{}{}", prefix, "DeletionProtection"), &field_value);
-        }
-        if let Some(ref field_value) = obj.enable_cloudwatch_logs_exports {
-            LogTypeListSerializer::serialize(
-                params,
-                &format!("{}{}", prefix, "EnableCloudwatchLogsExports"),
+impl RestoreDBInstanceFromS3MessageBuilder {
+    /// Sets the [`allocated_storage`](struct.RestoreDBInstanceFromS3Message.html#structfield.allocated_storage) member.
+    pub fn allocated_storage<T: Into<i64>>(mut self, value: T) -> Self {
+        self.allocated_storage = Some(value.into());
+        self
+    }
+
+    /// Sets the [`auto_minor_version_upgrade`](struct.RestoreDBInstanceFromS3Message.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zone`](struct.RestoreDBInstanceFromS3Message.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`backup_retention_period`](struct.RestoreDBInstanceFromS3Message.html#structfield.backup_retention_period) member.
+    pub fn backup_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.backup_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.RestoreDBInstanceFromS3Message.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_class`](struct.RestoreDBInstanceFromS3Message.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_identifier`](struct.RestoreDBInstanceFromS3Message.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_name`](struct.RestoreDBInstanceFromS3Message.html#structfield.db_name) member.
+    pub fn db_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_name`](struct.RestoreDBInstanceFromS3Message.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_security_groups`](struct.RestoreDBInstanceFromS3Message.html#structfield.db_security_groups) member.
+    pub fn db_security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.db_security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.RestoreDBInstanceFromS3Message.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.RestoreDBInstanceFromS3Message.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.RestoreDBInstanceFromS3Message.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.RestoreDBInstanceFromS3Message.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_performance_insights`](struct.RestoreDBInstanceFromS3Message.html#structfield.enable_performance_insights) member.
+    pub fn enable_performance_insights<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_performance_insights = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.RestoreDBInstanceFromS3Message.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine_version`](struct.RestoreDBInstanceFromS3Message.html#structfield.engine_version) member.
+    pub fn engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iops`](struct.RestoreDBInstanceFromS3Message.html#structfield.iops) member.
+    pub fn iops<T: Into<i64>>(mut self, value: T) -> Self {
+        self.iops = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.RestoreDBInstanceFromS3Message.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_model`](struct.RestoreDBInstanceFromS3Message.html#structfield.license_model) member.
+    pub fn license_model<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_model = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.RestoreDBInstanceFromS3Message.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_username`](struct.RestoreDBInstanceFromS3Message.html#structfield.master_username) member.
+    pub fn master_username<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_username = Some(value.into());
+        self
+    }
+
+    /// Sets the [`monitoring_interval`](struct.RestoreDBInstanceFromS3Message.html#structfield.monitoring_interval) member.
+    pub fn monitoring_interval<T: Into<i64>>(mut self, value: T) -> Self {
+        self.monitoring_interval = Some(value.into());
+        self
+    }
+
+    /// Sets the [`monitoring_role_arn`](struct.RestoreDBInstanceFromS3Message.html#structfield.monitoring_role_arn) member.
+    pub fn monitoring_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.monitoring_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.RestoreDBInstanceFromS3Message.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.RestoreDBInstanceFromS3Message.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`performance_insights_kms_key_id`](struct.RestoreDBInstanceFromS3Message.html#structfield.performance_insights_kms_key_id) member.
+    pub fn performance_insights_kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.performance_insights_kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`performance_insights_retention_period`](struct.RestoreDBInstanceFromS3Message.html#structfield.performance_insights_retention_period) member.
+    pub fn performance_insights_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.performance_insights_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.RestoreDBInstanceFromS3Message.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_backup_window`](struct.RestoreDBInstanceFromS3Message.html#structfield.preferred_backup_window) member.
+    pub fn preferred_backup_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_backup_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.RestoreDBInstanceFromS3Message.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`processor_features`](struct.RestoreDBInstanceFromS3Message.html#structfield.processor_features) member.
+    pub fn processor_features<T: Into<Vec<ProcessorFeature>>>(mut self, value: T) -> Self {
+        self.processor_features = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.RestoreDBInstanceFromS3Message.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_bucket_name`](struct.RestoreDBInstanceFromS3Message.html#structfield.s3_bucket_name) member.
+    pub fn s3_bucket_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_bucket_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_ingestion_role_arn`](struct.RestoreDBInstanceFromS3Message.html#structfield.s3_ingestion_role_arn) member.
+    pub fn s3_ingestion_role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_ingestion_role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_prefix`](struct.RestoreDBInstanceFromS3Message.html#structfield.s3_prefix) member.
+    pub fn s3_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_prefix = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_engine`](struct.RestoreDBInstanceFromS3Message.html#structfield.source_engine) member.
+    pub fn source_engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_engine_version`](struct.RestoreDBInstanceFromS3Message.html#structfield.source_engine_version) member.
+    pub fn source_engine_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_engine_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_encrypted`](struct.RestoreDBInstanceFromS3Message.html#structfield.storage_encrypted) member.
+    pub fn storage_encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.storage_encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_type`](struct.RestoreDBInstanceFromS3Message.html#structfield.storage_type) member.
+    pub fn storage_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.storage_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RestoreDBInstanceFromS3Message.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_default_processor_features`](struct.RestoreDBInstanceFromS3Message.html#structfield.use_default_processor_features) member.
+    pub fn use_default_processor_features<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_default_processor_features = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.RestoreDBInstanceFromS3Message.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreDBInstanceFromS3Message`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreDBInstanceFromS3Message, ::rusoto_core::BuildError> {
+        Ok(RestoreDBInstanceFromS3Message {
+            allocated_storage: self.allocated_storage,
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            availability_zone: self.availability_zone,
+            backup_retention_period: self.backup_retention_period,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_instance_class: self
+                .db_instance_class
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_class"))?,
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            db_name: self.db_name,
+            db_parameter_group_name: self.db_parameter_group_name,
+            db_security_groups: self.db_security_groups,
+            db_subnet_group_name: self.db_subnet_group_name,
+            deletion_protection: self.deletion_protection,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            enable_performance_insights: self.enable_performance_insights,
+            engine: self
+                .engine
+                .ok_or(::rusoto_core::BuildError::missing("engine"))?,
+            engine_version: self.engine_version,
+            iops: self.iops,
+            kms_key_id: self.kms_key_id,
+            license_model: self.license_model,
+            master_user_password: self.master_user_password,
+            master_username: self.master_username,
+            monitoring_interval: self.monitoring_interval,
+            monitoring_role_arn: self.monitoring_role_arn,
+            multi_az: self.multi_az,
+            option_group_name: self.option_group_name,
+            performance_insights_kms_key_id: self.performance_insights_kms_key_id,
+            performance_insights_retention_period: self.performance_insights_retention_period,
+            port: self.port,
+            preferred_backup_window: self.preferred_backup_window,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            processor_features: self.processor_features,
+            publicly_accessible: self.publicly_accessible,
+            s3_bucket_name: self
+                .s3_bucket_name
+                .ok_or(::rusoto_core::BuildError::missing("s3_bucket_name"))?,
+            s3_ingestion_role_arn: self
+                .s3_ingestion_role_arn
+                .ok_or(::rusoto_core::BuildError::missing("s3_ingestion_role_arn"))?,
+            s3_prefix: self.s3_prefix,
+            source_engine: self
+                .source_engine
+                .ok_or(::rusoto_core::BuildError::missing("source_engine"))?,
+            source_engine_version: self
+                .source_engine_version
+                .ok_or(::rusoto_core::BuildError::missing("source_engine_version"))?,
+            storage_encrypted: self.storage_encrypted,
+            storage_type: self.storage_type,
+            tags: self.tags,
+            use_default_processor_features: self.use_default_processor_features,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
+/// Serialize `RestoreDBInstanceFromS3Message` contents to a `SignedRequest`.
+struct RestoreDBInstanceFromS3MessageSerializer;
+impl RestoreDBInstanceFromS3MessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &RestoreDBInstanceFromS3Message) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.allocated_storage {
+            params.put(&format!("{}{}", prefix, "AllocatedStorage"), &field_value);
+        }
+        if let Some(ref field_value) = obj.auto_minor_version_upgrade {
+            params.put(
+                &format!("{}{}", prefix, "AutoMinorVersionUpgrade"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.availability_zone {
+            params.put(&format!("{}{}", prefix, "AvailabilityZone"), &field_value);
+        }
+        if let Some(ref field_value) = obj.backup_retention_period {
+            params.put(
+                &format!("{}{}", prefix, "BackupRetentionPeriod"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.copy_tags_to_snapshot {
+            params.put(&format!("{}{}", prefix, "CopyTagsToSnapshot"), &field_value);
+        }
+        params.put(
+            &format!("{}{}", prefix, "DBInstanceClass"),
+            &obj.db_instance_class,
+        );
+        params.put(
+            &format!("{}{}", prefix, "DBInstanceIdentifier"),
+            &obj.db_instance_identifier,
+        );
+        if let Some(ref field_value) = obj.db_name {
+            params.put(&format!("{}{}", prefix, "DBName"), &field_value);
+        }
+        if let Some(ref field_value) = obj.db_parameter_group_name {
+            params.put(
+                &format!("{}{}", prefix, "DBParameterGroupName"),
+                &field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.db_security_groups {
+            DBSecurityGroupNameListSerializer::serialize(
+                params,
+                &format!("{}{}", prefix, "DBSecurityGroupName"),
+                field_value,
+            );
+        }
+        if let Some(ref field_value) = obj.db_subnet_group_name {
+            params.put(&format!("{}{}", prefix, "DBSubnetGroupName"), &field_value);
+        }
+        if let Some(ref field_value) = obj.deletion_protection {
+            params.put(&format!("{}{}", prefix, "DeletionProtection"), &field_value);
+        }
+        if let Some(ref field_value) = obj.enable_cloudwatch_logs_exports {
+            LogTypeListSerializer::serialize(
+                params,
+                &format!("{}{}", prefix, "EnableCloudwatchLogsExports"),
                 field_value,
             );
         }
@@ -14506,10 +22530,10 @@ pub struct RestoreDBInstanceFromS3Result {
     pub db_instance: Option<DBInstance>,
 }
 
-struct RestoreDBInstanceFromS3ResultDeserializer;
+pub(crate) struct RestoreDBInstanceFromS3ResultDeserializer;
 impl RestoreDBInstanceFromS3ResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreDBInstanceFromS3Result, XmlParseError> {
@@ -14573,7 +22597,7 @@ pub struct RestoreDBInstanceToPointInTimeMessage {
     /// <p>A value that indicates whether the DB instance is publicly accessible. When the DB instance is publicly accessible, it is an Internet-facing instance with a publicly resolvable DNS name, which resolves to a public IP address. When the DB instance is not publicly accessible, it is an internal instance with a DNS name that resolves to a private IP address. For more information, see <a>CreateDBInstance</a>.</p>
     pub publicly_accessible: Option<bool>,
     /// <p>The date and time to restore from.</p> <p>Valid Values: Value must be a time in Universal Coordinated Time (UTC) format</p> <p>Constraints:</p> <ul> <li> <p>Must be before the latest restorable time for the DB instance</p> </li> <li> <p>Can't be specified if the <code>UseLatestRestorableTime</code> parameter is enabled</p> </li> </ul> <p>Example: <code>2009-09-07T23:45:00Z</code> </p>
-    pub restore_time: Option<String>,
+    pub restore_time: Option<IsoTimestamp>,
     /// <p><p>The identifier of the source DB instance from which to restore.</p> <p>Constraints:</p> <ul> <li> <p>Must match the identifier of an existing DB instance.</p> </li> </ul></p>
     pub source_db_instance_identifier: Option<String>,
     /// <p>The resource ID of the source DB instance from which to restore.</p>
@@ -14595,6 +22619,279 @@ pub struct RestoreDBInstanceToPointInTimeMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl RestoreDBInstanceToPointInTimeMessage {
+    /// Returns a builder for `RestoreDBInstanceToPointInTimeMessage`, checking its required members when built.
+    pub fn builder() -> RestoreDBInstanceToPointInTimeMessageBuilder {
+        RestoreDBInstanceToPointInTimeMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreDBInstanceToPointInTimeMessage`](struct.RestoreDBInstanceToPointInTimeMessage.html).
+#[derive(Default, Debug)]
+pub struct RestoreDBInstanceToPointInTimeMessageBuilder {
+    auto_minor_version_upgrade: Option<bool>,
+    availability_zone: Option<String>,
+    copy_tags_to_snapshot: Option<bool>,
+    db_instance_class: Option<String>,
+    db_name: Option<String>,
+    db_parameter_group_name: Option<String>,
+    db_subnet_group_name: Option<String>,
+    deletion_protection: Option<bool>,
+    domain: Option<String>,
+    domain_iam_role_name: Option<String>,
+    enable_cloudwatch_logs_exports: Option<Vec<String>>,
+    enable_iam_database_authentication: Option<bool>,
+    engine: Option<String>,
+    iops: Option<i64>,
+    license_model: Option<String>,
+    multi_az: Option<bool>,
+    option_group_name: Option<String>,
+    port: Option<i64>,
+    processor_features: Option<Vec<ProcessorFeature>>,
+    publicly_accessible: Option<bool>,
+    restore_time: Option<IsoTimestamp>,
+    source_db_instance_identifier: Option<String>,
+    source_dbi_resource_id: Option<String>,
+    storage_type: Option<String>,
+    tags: Option<Vec<Tag>>,
+    target_db_instance_identifier: Option<String>,
+    tde_credential_arn: Option<String>,
+    tde_credential_password: Option<String>,
+    use_default_processor_features: Option<bool>,
+    use_latest_restorable_time: Option<bool>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl RestoreDBInstanceToPointInTimeMessageBuilder {
+    /// Sets the [`auto_minor_version_upgrade`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.auto_minor_version_upgrade) member.
+    pub fn auto_minor_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_minor_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zone`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`copy_tags_to_snapshot`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.copy_tags_to_snapshot) member.
+    pub fn copy_tags_to_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.copy_tags_to_snapshot = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_instance_class`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.db_instance_class) member.
+    pub fn db_instance_class<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_class = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_name`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.db_name) member.
+    pub fn db_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_parameter_group_name`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.db_parameter_group_name) member.
+    pub fn db_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_subnet_group_name`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.db_subnet_group_name) member.
+    pub fn db_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`deletion_protection`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.deletion_protection) member.
+    pub fn deletion_protection<T: Into<bool>>(mut self, value: T) -> Self {
+        self.deletion_protection = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.domain) member.
+    pub fn domain<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain = Some(value.into());
+        self
+    }
+
+    /// Sets the [`domain_iam_role_name`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.domain_iam_role_name) member.
+    pub fn domain_iam_role_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.domain_iam_role_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_cloudwatch_logs_exports`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.enable_cloudwatch_logs_exports) member.
+    pub fn enable_cloudwatch_logs_exports<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.enable_cloudwatch_logs_exports = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enable_iam_database_authentication`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.enable_iam_database_authentication) member.
+    pub fn enable_iam_database_authentication<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enable_iam_database_authentication = Some(value.into());
+        self
+    }
+
+    /// Sets the [`engine`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.engine) member.
+    pub fn engine<T: Into<String>>(mut self, value: T) -> Self {
+        self.engine = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iops`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.iops) member.
+    pub fn iops<T: Into<i64>>(mut self, value: T) -> Self {
+        self.iops = Some(value.into());
+        self
+    }
+
+    /// Sets the [`license_model`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.license_model) member.
+    pub fn license_model<T: Into<String>>(mut self, value: T) -> Self {
+        self.license_model = Some(value.into());
+        self
+    }
+
+    /// Sets the [`multi_az`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.multi_az) member.
+    pub fn multi_az<T: Into<bool>>(mut self, value: T) -> Self {
+        self.multi_az = Some(value.into());
+        self
+    }
+
+    /// Sets the [`option_group_name`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.option_group_name) member.
+    pub fn option_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.option_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`processor_features`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.processor_features) member.
+    pub fn processor_features<T: Into<Vec<ProcessorFeature>>>(mut self, value: T) -> Self {
+        self.processor_features = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`restore_time`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.restore_time) member.
+    pub fn restore_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.restore_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_db_instance_identifier`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.source_db_instance_identifier) member.
+    pub fn source_db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_dbi_resource_id`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.source_dbi_resource_id) member.
+    pub fn source_dbi_resource_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_dbi_resource_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`storage_type`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.storage_type) member.
+    pub fn storage_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.storage_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_db_instance_identifier`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.target_db_instance_identifier) member.
+    pub fn target_db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tde_credential_arn`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.tde_credential_arn) member.
+    pub fn tde_credential_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.tde_credential_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tde_credential_password`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.tde_credential_password) member.
+    pub fn tde_credential_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.tde_credential_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_default_processor_features`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.use_default_processor_features) member.
+    pub fn use_default_processor_features<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_default_processor_features = Some(value.into());
+        self
+    }
+
+    /// Sets the [`use_latest_restorable_time`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.use_latest_restorable_time) member.
+    pub fn use_latest_restorable_time<T: Into<bool>>(mut self, value: T) -> Self {
+        self.use_latest_restorable_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.RestoreDBInstanceToPointInTimeMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreDBInstanceToPointInTimeMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreDBInstanceToPointInTimeMessage, ::rusoto_core::BuildError>
+    {
+        Ok(RestoreDBInstanceToPointInTimeMessage {
+            auto_minor_version_upgrade: self.auto_minor_version_upgrade,
+            availability_zone: self.availability_zone,
+            copy_tags_to_snapshot: self.copy_tags_to_snapshot,
+            db_instance_class: self.db_instance_class,
+            db_name: self.db_name,
+            db_parameter_group_name: self.db_parameter_group_name,
+            db_subnet_group_name: self.db_subnet_group_name,
+            deletion_protection: self.deletion_protection,
+            domain: self.domain,
+            domain_iam_role_name: self.domain_iam_role_name,
+            enable_cloudwatch_logs_exports: self.enable_cloudwatch_logs_exports,
+            enable_iam_database_authentication: self.enable_iam_database_authentication,
+            engine: self.engine,
+            iops: self.iops,
+            license_model: self.license_model,
+            multi_az: self.multi_az,
+            option_group_name: self.option_group_name,
+            port: self.port,
+            processor_features: self.processor_features,
+            publicly_accessible: self.publicly_accessible,
+            restore_time: self.restore_time,
+            source_db_instance_identifier: self.source_db_instance_identifier,
+            source_dbi_resource_id: self.source_dbi_resource_id,
+            storage_type: self.storage_type,
+            tags: self.tags,
+            target_db_instance_identifier: self.target_db_instance_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("target_db_instance_identifier"),
+            )?,
+            tde_credential_arn: self.tde_credential_arn,
+            tde_credential_password: self.tde_credential_password,
+            use_default_processor_features: self.use_default_processor_features,
+            use_latest_restorable_time: self.use_latest_restorable_time,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `RestoreDBInstanceToPointInTimeMessage` contents to a `SignedRequest`.
 struct RestoreDBInstanceToPointInTimeMessageSerializer;
 impl RestoreDBInstanceToPointInTimeMessageSerializer {
@@ -14742,10 +23039,10 @@ pub struct RestoreDBInstanceToPointInTimeResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct RestoreDBInstanceToPointInTimeResultDeserializer;
+pub(crate) struct RestoreDBInstanceToPointInTimeResultDeserializer;
 impl RestoreDBInstanceToPointInTimeResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreDBInstanceToPointInTimeResult, XmlParseError> {
@@ -14769,15 +23066,15 @@ impl RestoreDBInstanceToPointInTimeResultDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct RestoreWindow {
     /// <p>The earliest time you can restore an instance to.</p>
-    pub earliest_time: Option<String>,
+    pub earliest_time: Option<IsoTimestamp>,
     /// <p>The latest time you can restore an instance to.</p>
-    pub latest_time: Option<String>,
+    pub latest_time: Option<IsoTimestamp>,
 }
 
-struct RestoreWindowDeserializer;
+pub(crate) struct RestoreWindowDeserializer;
 impl RestoreWindowDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreWindow, XmlParseError> {
@@ -14811,6 +23108,70 @@ pub struct RevokeDBSecurityGroupIngressMessage {
     pub ec2_security_group_owner_id: Option<String>,
 }
 
+impl RevokeDBSecurityGroupIngressMessage {
+    /// Returns a builder for `RevokeDBSecurityGroupIngressMessage`, checking its required members when built.
+    pub fn builder() -> RevokeDBSecurityGroupIngressMessageBuilder {
+        RevokeDBSecurityGroupIngressMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RevokeDBSecurityGroupIngressMessage`](struct.RevokeDBSecurityGroupIngressMessage.html).
+#[derive(Default, Debug)]
+pub struct RevokeDBSecurityGroupIngressMessageBuilder {
+    cidrip: Option<String>,
+    db_security_group_name: Option<String>,
+    ec2_security_group_id: Option<String>,
+    ec2_security_group_name: Option<String>,
+    ec2_security_group_owner_id: Option<String>,
+}
+
+impl RevokeDBSecurityGroupIngressMessageBuilder {
+    /// Sets the [`cidrip`](struct.RevokeDBSecurityGroupIngressMessage.html#structfield.cidrip) member.
+    pub fn cidrip<T: Into<String>>(mut self, value: T) -> Self {
+        self.cidrip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_security_group_name`](struct.RevokeDBSecurityGroupIngressMessage.html#structfield.db_security_group_name) member.
+    pub fn db_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_id`](struct.RevokeDBSecurityGroupIngressMessage.html#structfield.ec2_security_group_id) member.
+    pub fn ec2_security_group_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_name`](struct.RevokeDBSecurityGroupIngressMessage.html#structfield.ec2_security_group_name) member.
+    pub fn ec2_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_owner_id`](struct.RevokeDBSecurityGroupIngressMessage.html#structfield.ec2_security_group_owner_id) member.
+    pub fn ec2_security_group_owner_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_owner_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RevokeDBSecurityGroupIngressMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RevokeDBSecurityGroupIngressMessage, ::rusoto_core::BuildError> {
+        Ok(RevokeDBSecurityGroupIngressMessage {
+            cidrip: self.cidrip,
+            db_security_group_name: self
+                .db_security_group_name
+                .ok_or(::rusoto_core::BuildError::missing("db_security_group_name"))?,
+            ec2_security_group_id: self.ec2_security_group_id,
+            ec2_security_group_name: self.ec2_security_group_name,
+            ec2_security_group_owner_id: self.ec2_security_group_owner_id,
+        })
+    }
+}
+
 /// Serialize `RevokeDBSecurityGroupIngressMessage` contents to a `SignedRequest`.
 struct RevokeDBSecurityGroupIngressMessageSerializer;
 impl RevokeDBSecurityGroupIngressMessageSerializer {
@@ -14850,10 +23211,10 @@ pub struct RevokeDBSecurityGroupIngressResult {
     pub db_security_group: Option<DBSecurityGroup>,
 }
 
-struct RevokeDBSecurityGroupIngressResultDeserializer;
+pub(crate) struct RevokeDBSecurityGroupIngressResultDeserializer;
 impl RevokeDBSecurityGroupIngressResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RevokeDBSecurityGroupIngressResult, XmlParseError> {
@@ -14935,10 +23296,10 @@ pub struct ScalingConfigurationInfo {
     pub timeout_action: Option<String>,
 }
 
-struct ScalingConfigurationInfoDeserializer;
+pub(crate) struct ScalingConfigurationInfoDeserializer;
 impl ScalingConfigurationInfoDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ScalingConfigurationInfo, XmlParseError> {
@@ -14983,10 +23344,10 @@ impl ScalingConfigurationInfoDeserializer {
         )
     }
 }
-struct SourceIdsListDeserializer;
+pub(crate) struct SourceIdsListDeserializer;
 impl SourceIdsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -15023,10 +23384,10 @@ pub struct SourceRegion {
     pub status: Option<String>,
 }
 
-struct SourceRegionDeserializer;
+pub(crate) struct SourceRegionDeserializer;
 impl SourceRegionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SourceRegion, XmlParseError> {
@@ -15047,10 +23408,10 @@ impl SourceRegionDeserializer {
         })
     }
 }
-struct SourceRegionListDeserializer;
+pub(crate) struct SourceRegionListDeserializer;
 impl SourceRegionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SourceRegion>, XmlParseError> {
@@ -15076,10 +23437,10 @@ pub struct SourceRegionMessage {
     pub source_regions: Option<Vec<SourceRegion>>,
 }
 
-struct SourceRegionMessageDeserializer;
+pub(crate) struct SourceRegionMessageDeserializer;
 impl SourceRegionMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SourceRegionMessage, XmlParseError> {
@@ -15099,29 +23460,179 @@ impl SourceRegionMessageDeserializer {
         })
     }
 }
-struct SourceTypeDeserializer;
+pub(crate) struct SourceTypeDeserializer;
 impl SourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-#[derive(Default, Debug, Clone, PartialEq)]
+/// <p>The event source to retrieve events for. If no value is specified, all events are returned.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceType {
+    /// <p><code>db-instance</code></p>
+    DbInstance,
+    /// <p><code>db-parameter-group</code></p>
+    DbParameterGroup,
+    /// <p><code>db-security-group</code></p>
+    DbSecurityGroup,
+    /// <p><code>db-snapshot</code></p>
+    DbSnapshot,
+    /// <p><code>db-cluster</code></p>
+    DbCluster,
+    /// <p><code>db-cluster-snapshot</code></p>
+    DbClusterSnapshot,
+    /// <p><code>custom-engine-version</code></p>
+    CustomEngineVersion,
+    /// <p><code>db-proxy</code></p>
+    DbProxy,
+    /// <p><code>blue-green-deployment</code></p>
+    BlueGreenDeployment,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::DbInstance => "db-instance",
+            SourceType::DbParameterGroup => "db-parameter-group",
+            SourceType::DbSecurityGroup => "db-security-group",
+            SourceType::DbSnapshot => "db-snapshot",
+            SourceType::DbCluster => "db-cluster",
+            SourceType::DbClusterSnapshot => "db-cluster-snapshot",
+            SourceType::CustomEngineVersion => "custom-engine-version",
+            SourceType::DbProxy => "db-proxy",
+            SourceType::BlueGreenDeployment => "blue-green-deployment",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "db-instance" => SourceType::DbInstance,
+            "db-parameter-group" => SourceType::DbParameterGroup,
+            "db-security-group" => SourceType::DbSecurityGroup,
+            "db-snapshot" => SourceType::DbSnapshot,
+            "db-cluster" => SourceType::DbCluster,
+            "db-cluster-snapshot" => SourceType::DbClusterSnapshot,
+            "custom-engine-version" => SourceType::CustomEngineVersion,
+            "db-proxy" => SourceType::DbProxy,
+            "blue-green-deployment" => SourceType::BlueGreenDeployment,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        SourceType::from(value.as_str())
+    }
+}
+
+impl From<SourceType> for String {
+    fn from(value: SourceType) -> String {
+        match value {
+            SourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct StartActivityStreamRequest {
     /// <p>Specifies whether or not the database activity stream is to start as soon as possible, regardless of the maintenance window for the database.</p>
     pub apply_immediately: Option<bool>,
     /// <p>The AWS KMS key identifier for encrypting messages in the database activity stream. The key identifier can be either a key ID, a key ARN, or a key alias.</p>
     pub kms_key_id: String,
     /// <p>Specifies the mode of the database activity stream. Database events such as a change or access generate an activity stream event. The database session can handle these events either synchronously or asynchronously. </p>
-    pub mode: String,
+    pub mode: ActivityStreamMode,
     /// <p>The Amazon Resource Name (ARN) of the DB cluster, for example <code>arn:aws:rds:us-east-1:12345667890:cluster:das-cluster</code>.</p>
     pub resource_arn: String,
 }
 
+impl StartActivityStreamRequest {
+    /// Returns a builder for `StartActivityStreamRequest`, checking its required members when built.
+    pub fn builder() -> StartActivityStreamRequestBuilder {
+        StartActivityStreamRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartActivityStreamRequest`](struct.StartActivityStreamRequest.html).
+#[derive(Default, Debug)]
+pub struct StartActivityStreamRequestBuilder {
+    apply_immediately: Option<bool>,
+    kms_key_id: Option<String>,
+    mode: Option<ActivityStreamMode>,
+    resource_arn: Option<String>,
+}
+
+impl StartActivityStreamRequestBuilder {
+    /// Sets the [`apply_immediately`](struct.StartActivityStreamRequest.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.StartActivityStreamRequest.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`mode`](struct.StartActivityStreamRequest.html#structfield.mode) member.
+    pub fn mode<T: Into<ActivityStreamMode>>(mut self, value: T) -> Self {
+        self.mode = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.StartActivityStreamRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartActivityStreamRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartActivityStreamRequest, ::rusoto_core::BuildError> {
+        Ok(StartActivityStreamRequest {
+            apply_immediately: self.apply_immediately,
+            kms_key_id: self
+                .kms_key_id
+                .ok_or(::rusoto_core::BuildError::missing("kms_key_id"))?,
+            mode: self
+                .mode
+                .ok_or(::rusoto_core::BuildError::missing("mode"))?,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 /// Serialize `StartActivityStreamRequest` contents to a `SignedRequest`.
 struct StartActivityStreamRequestSerializer;
 impl StartActivityStreamRequestSerializer {
@@ -15149,15 +23660,15 @@ pub struct StartActivityStreamResponse {
     /// <p>The AWS KMS key identifier for encryption of messages in the database activity stream.</p>
     pub kms_key_id: Option<String>,
     /// <p>The mode of the database activity stream.</p>
-    pub mode: Option<String>,
+    pub mode: Option<ActivityStreamMode>,
     /// <p>The status of the database activity stream.</p>
-    pub status: Option<String>,
+    pub status: Option<ActivityStreamStatus>,
 }
 
-struct StartActivityStreamResponseDeserializer;
+pub(crate) struct StartActivityStreamResponseDeserializer;
 impl StartActivityStreamResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StartActivityStreamResponse, XmlParseError> {
@@ -15199,6 +23710,36 @@ pub struct StartDBClusterMessage {
     pub db_cluster_identifier: String,
 }
 
+impl StartDBClusterMessage {
+    /// Returns a builder for `StartDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> StartDBClusterMessageBuilder {
+        StartDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`StartDBClusterMessage`](struct.StartDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct StartDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+}
+
+impl StartDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.StartDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(StartDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `StartDBClusterMessage` contents to a `SignedRequest`.
 struct StartDBClusterMessageSerializer;
 impl StartDBClusterMessageSerializer {
@@ -15220,10 +23761,10 @@ pub struct StartDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct StartDBClusterResultDeserializer;
+pub(crate) struct StartDBClusterResultDeserializer;
 impl StartDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StartDBClusterResult, XmlParseError> {
@@ -15244,6 +23785,36 @@ pub struct StartDBInstanceMessage {
     pub db_instance_identifier: String,
 }
 
+impl StartDBInstanceMessage {
+    /// Returns a builder for `StartDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> StartDBInstanceMessageBuilder {
+        StartDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`StartDBInstanceMessage`](struct.StartDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct StartDBInstanceMessageBuilder {
+    db_instance_identifier: Option<String>,
+}
+
+impl StartDBInstanceMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.StartDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(StartDBInstanceMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `StartDBInstanceMessage` contents to a `SignedRequest`.
 struct StartDBInstanceMessageSerializer;
 impl StartDBInstanceMessageSerializer {
@@ -15265,10 +23836,10 @@ pub struct StartDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct StartDBInstanceResultDeserializer;
+pub(crate) struct StartDBInstanceResultDeserializer;
 impl StartDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StartDBInstanceResult, XmlParseError> {
@@ -15292,6 +23863,46 @@ pub struct StopActivityStreamRequest {
     pub resource_arn: String,
 }
 
+impl StopActivityStreamRequest {
+    /// Returns a builder for `StopActivityStreamRequest`, checking its required members when built.
+    pub fn builder() -> StopActivityStreamRequestBuilder {
+        StopActivityStreamRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopActivityStreamRequest`](struct.StopActivityStreamRequest.html).
+#[derive(Default, Debug)]
+pub struct StopActivityStreamRequestBuilder {
+    apply_immediately: Option<bool>,
+    resource_arn: Option<String>,
+}
+
+impl StopActivityStreamRequestBuilder {
+    /// Sets the [`apply_immediately`](struct.StopActivityStreamRequest.html#structfield.apply_immediately) member.
+    pub fn apply_immediately<T: Into<bool>>(mut self, value: T) -> Self {
+        self.apply_immediately = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_arn`](struct.StopActivityStreamRequest.html#structfield.resource_arn) member.
+    pub fn resource_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopActivityStreamRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopActivityStreamRequest, ::rusoto_core::BuildError> {
+        Ok(StopActivityStreamRequest {
+            apply_immediately: self.apply_immediately,
+            resource_arn: self
+                .resource_arn
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn"))?,
+        })
+    }
+}
+
 /// Serialize `StopActivityStreamRequest` contents to a `SignedRequest`.
 struct StopActivityStreamRequestSerializer;
 impl StopActivityStreamRequestSerializer {
@@ -15315,13 +23926,13 @@ pub struct StopActivityStreamResponse {
     /// <p>The AWS KMS key identifier used for encrypting messages in the database activity stream.</p>
     pub kms_key_id: Option<String>,
     /// <p>The status of the database activity stream.</p>
-    pub status: Option<String>,
+    pub status: Option<ActivityStreamStatus>,
 }
 
-struct StopActivityStreamResponseDeserializer;
+pub(crate) struct StopActivityStreamResponseDeserializer;
 impl StopActivityStreamResponseDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StopActivityStreamResponse, XmlParseError> {
@@ -15355,6 +23966,36 @@ pub struct StopDBClusterMessage {
     pub db_cluster_identifier: String,
 }
 
+impl StopDBClusterMessage {
+    /// Returns a builder for `StopDBClusterMessage`, checking its required members when built.
+    pub fn builder() -> StopDBClusterMessageBuilder {
+        StopDBClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`StopDBClusterMessage`](struct.StopDBClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct StopDBClusterMessageBuilder {
+    db_cluster_identifier: Option<String>,
+}
+
+impl StopDBClusterMessageBuilder {
+    /// Sets the [`db_cluster_identifier`](struct.StopDBClusterMessage.html#structfield.db_cluster_identifier) member.
+    pub fn db_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopDBClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopDBClusterMessage, ::rusoto_core::BuildError> {
+        Ok(StopDBClusterMessage {
+            db_cluster_identifier: self
+                .db_cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_cluster_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `StopDBClusterMessage` contents to a `SignedRequest`.
 struct StopDBClusterMessageSerializer;
 impl StopDBClusterMessageSerializer {
@@ -15376,10 +24017,10 @@ pub struct StopDBClusterResult {
     pub db_cluster: Option<DBCluster>,
 }
 
-struct StopDBClusterResultDeserializer;
+pub(crate) struct StopDBClusterResultDeserializer;
 impl StopDBClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StopDBClusterResult, XmlParseError> {
@@ -15402,6 +24043,44 @@ pub struct StopDBInstanceMessage {
     pub db_snapshot_identifier: Option<String>,
 }
 
+impl StopDBInstanceMessage {
+    /// Returns a builder for `StopDBInstanceMessage`, checking its required members when built.
+    pub fn builder() -> StopDBInstanceMessageBuilder {
+        StopDBInstanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`StopDBInstanceMessage`](struct.StopDBInstanceMessage.html).
+#[derive(Default, Debug)]
+pub struct StopDBInstanceMessageBuilder {
+    db_instance_identifier: Option<String>,
+    db_snapshot_identifier: Option<String>,
+}
+
+impl StopDBInstanceMessageBuilder {
+    /// Sets the [`db_instance_identifier`](struct.StopDBInstanceMessage.html#structfield.db_instance_identifier) member.
+    pub fn db_instance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_instance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_snapshot_identifier`](struct.StopDBInstanceMessage.html#structfield.db_snapshot_identifier) member.
+    pub fn db_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopDBInstanceMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StopDBInstanceMessage, ::rusoto_core::BuildError> {
+        Ok(StopDBInstanceMessage {
+            db_instance_identifier: self
+                .db_instance_identifier
+                .ok_or(::rusoto_core::BuildError::missing("db_instance_identifier"))?,
+            db_snapshot_identifier: self.db_snapshot_identifier,
+        })
+    }
+}
+
 /// Serialize `StopDBInstanceMessage` contents to a `SignedRequest`.
 struct StopDBInstanceMessageSerializer;
 impl StopDBInstanceMessageSerializer {
@@ -15429,10 +24108,10 @@ pub struct StopDBInstanceResult {
     pub db_instance: Option<DBInstance>,
 }
 
-struct StopDBInstanceResultDeserializer;
+pub(crate) struct StopDBInstanceResultDeserializer;
 impl StopDBInstanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<StopDBInstanceResult, XmlParseError> {
@@ -15448,10 +24127,13 @@ impl StopDBInstanceResultDeserializer {
         })
     }
 }
-struct StringDeserializer;
+pub(crate) struct StringDeserializer;
 impl StringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -15459,10 +24141,10 @@ impl StringDeserializer {
         Ok(obj)
     }
 }
-struct StringListDeserializer;
+pub(crate) struct StringListDeserializer;
 impl StringListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -15498,10 +24180,13 @@ pub struct Subnet {
     pub subnet_status: Option<String>,
 }
 
-struct SubnetDeserializer;
+pub(crate) struct SubnetDeserializer;
 impl SubnetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Subnet, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Subnet, XmlParseError> {
         deserialize_elements::<_, Subnet, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "SubnetAvailabilityZone" => {
@@ -15536,10 +24221,10 @@ impl SubnetIdentifierListSerializer {
     }
 }
 
-struct SubnetListDeserializer;
+pub(crate) struct SubnetListDeserializer;
 impl SubnetListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Subnet>, XmlParseError> {
@@ -15553,10 +24238,10 @@ impl SubnetListDeserializer {
         })
     }
 }
-struct SupportedCharacterSetsListDeserializer;
+pub(crate) struct SupportedCharacterSetsListDeserializer;
 impl SupportedCharacterSetsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<CharacterSet>, XmlParseError> {
@@ -15573,10 +24258,10 @@ impl SupportedCharacterSetsListDeserializer {
         })
     }
 }
-struct SupportedTimezonesListDeserializer;
+pub(crate) struct SupportedTimezonesListDeserializer;
 impl SupportedTimezonesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Timezone>, XmlParseError> {
@@ -15590,12 +24275,15 @@ impl SupportedTimezonesListDeserializer {
         })
     }
 }
-struct TStampDeserializer;
+pub(crate) struct TStampDeserializer;
 impl TStampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -15610,10 +24298,13 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -15647,10 +24338,10 @@ impl TagSerializer {
     }
 }
 
-struct TagListDeserializer;
+pub(crate) struct TagListDeserializer;
 impl TagListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -15683,10 +24374,10 @@ pub struct TagListMessage {
     pub tag_list: Option<Vec<Tag>>,
 }
 
-struct TagListMessageDeserializer;
+pub(crate) struct TagListMessageDeserializer;
 impl TagListMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TagListMessage, XmlParseError> {
@@ -15710,10 +24401,10 @@ pub struct Timezone {
     pub timezone_name: Option<String>,
 }
 
-struct TimezoneDeserializer;
+pub(crate) struct TimezoneDeserializer;
 impl TimezoneDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Timezone, XmlParseError> {
@@ -15744,10 +24435,10 @@ pub struct UpgradeTarget {
     pub is_major_version_upgrade: Option<bool>,
 }
 
-struct UpgradeTargetDeserializer;
+pub(crate) struct UpgradeTargetDeserializer;
 impl UpgradeTargetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpgradeTarget, XmlParseError> {
@@ -15788,10 +24479,10 @@ pub struct ValidDBInstanceModificationsMessage {
     pub valid_processor_features: Option<Vec<AvailableProcessorFeature>>,
 }
 
-struct ValidDBInstanceModificationsMessageDeserializer;
+pub(crate) struct ValidDBInstanceModificationsMessageDeserializer;
 impl ValidDBInstanceModificationsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ValidDBInstanceModificationsMessage, XmlParseError> {
@@ -15835,10 +24526,10 @@ pub struct ValidStorageOptions {
     pub supports_storage_autoscaling: Option<bool>,
 }
 
-struct ValidStorageOptionsDeserializer;
+pub(crate) struct ValidStorageOptionsDeserializer;
 impl ValidStorageOptionsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ValidStorageOptions, XmlParseError> {
@@ -15874,10 +24565,10 @@ impl ValidStorageOptionsDeserializer {
         })
     }
 }
-struct ValidStorageOptionsListDeserializer;
+pub(crate) struct ValidStorageOptionsListDeserializer;
 impl ValidStorageOptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ValidStorageOptions>, XmlParseError> {
@@ -15894,10 +24585,10 @@ impl ValidStorageOptionsListDeserializer {
         })
     }
 }
-struct ValidUpgradeTargetListDeserializer;
+pub(crate) struct ValidUpgradeTargetListDeserializer;
 impl ValidUpgradeTargetListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<UpgradeTarget>, XmlParseError> {
@@ -15935,10 +24626,10 @@ pub struct VpcSecurityGroupMembership {
     pub vpc_security_group_id: Option<String>,
 }
 
-struct VpcSecurityGroupMembershipDeserializer;
+pub(crate) struct VpcSecurityGroupMembershipDeserializer;
 impl VpcSecurityGroupMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<VpcSecurityGroupMembership, XmlParseError> {
@@ -15963,10 +24654,10 @@ impl VpcSecurityGroupMembershipDeserializer {
         )
     }
 }
-struct VpcSecurityGroupMembershipListDeserializer;
+pub(crate) struct VpcSecurityGroupMembershipListDeserializer;
 impl VpcSecurityGroupMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<VpcSecurityGroupMembership>, XmlParseError> {
@@ -25200,40 +33891,801 @@ pub trait Rds {
         input: StopDBInstanceMessage,
     ) -> RusotoFuture<StopDBInstanceResult, StopDBInstanceError>;
 }
-/// A client for the Amazon RDS API.
-#[derive(Clone)]
-pub struct RdsClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl RdsClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> RdsClient {
-        Self::new_with_client(Client::shared(), region)
+impl<T: ?Sized + Rds> Rds for ::std::sync::Arc<T> {
+    fn add_role_to_db_cluster(
+        &self,
+        input: AddRoleToDBClusterMessage,
+    ) -> RusotoFuture<(), AddRoleToDBClusterError> {
+        (**self).add_role_to_db_cluster(input)
     }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> RdsClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
+    fn add_role_to_db_instance(
+        &self,
+        input: AddRoleToDBInstanceMessage,
+    ) -> RusotoFuture<(), AddRoleToDBInstanceError> {
+        (**self).add_role_to_db_instance(input)
+    }
+    fn add_source_identifier_to_subscription(
+        &self,
+        input: AddSourceIdentifierToSubscriptionMessage,
+    ) -> RusotoFuture<AddSourceIdentifierToSubscriptionResult, AddSourceIdentifierToSubscriptionError>
     {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
+        (**self).add_source_identifier_to_subscription(input)
     }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> RdsClient {
-        RdsClient { client, region }
+    fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> RusotoFuture<(), AddTagsToResourceError> {
+        (**self).add_tags_to_resource(input)
+    }
+    fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> RusotoFuture<ApplyPendingMaintenanceActionResult, ApplyPendingMaintenanceActionError> {
+        (**self).apply_pending_maintenance_action(input)
+    }
+    fn authorize_db_security_group_ingress(
+        &self,
+        input: AuthorizeDBSecurityGroupIngressMessage,
+    ) -> RusotoFuture<AuthorizeDBSecurityGroupIngressResult, AuthorizeDBSecurityGroupIngressError>
+    {
+        (**self).authorize_db_security_group_ingress(input)
+    }
+    fn backtrack_db_cluster(
+        &self,
+        input: BacktrackDBClusterMessage,
+    ) -> RusotoFuture<DBClusterBacktrack, BacktrackDBClusterError> {
+        (**self).backtrack_db_cluster(input)
+    }
+    fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CopyDBClusterParameterGroupResult, CopyDBClusterParameterGroupError> {
+        (**self).copy_db_cluster_parameter_group(input)
+    }
+    fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CopyDBClusterSnapshotResult, CopyDBClusterSnapshotError> {
+        (**self).copy_db_cluster_snapshot(input)
+    }
+    fn copy_db_parameter_group(
+        &self,
+        input: CopyDBParameterGroupMessage,
+    ) -> RusotoFuture<CopyDBParameterGroupResult, CopyDBParameterGroupError> {
+        (**self).copy_db_parameter_group(input)
+    }
+    fn copy_db_snapshot(
+        &self,
+        input: CopyDBSnapshotMessage,
+    ) -> RusotoFuture<CopyDBSnapshotResult, CopyDBSnapshotError> {
+        (**self).copy_db_snapshot(input)
+    }
+    fn copy_option_group(
+        &self,
+        input: CopyOptionGroupMessage,
+    ) -> RusotoFuture<CopyOptionGroupResult, CopyOptionGroupError> {
+        (**self).copy_option_group(input)
+    }
+    fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> RusotoFuture<CreateDBClusterResult, CreateDBClusterError> {
+        (**self).create_db_cluster(input)
+    }
+    fn create_db_cluster_endpoint(
+        &self,
+        input: CreateDBClusterEndpointMessage,
+    ) -> RusotoFuture<DBClusterEndpoint, CreateDBClusterEndpointError> {
+        (**self).create_db_cluster_endpoint(input)
+    }
+    fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<CreateDBClusterParameterGroupResult, CreateDBClusterParameterGroupError> {
+        (**self).create_db_cluster_parameter_group(input)
+    }
+    fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> RusotoFuture<CreateDBClusterSnapshotResult, CreateDBClusterSnapshotError> {
+        (**self).create_db_cluster_snapshot(input)
+    }
+    fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> RusotoFuture<CreateDBInstanceResult, CreateDBInstanceError> {
+        (**self).create_db_instance(input)
+    }
+    fn create_db_instance_read_replica(
+        &self,
+        input: CreateDBInstanceReadReplicaMessage,
+    ) -> RusotoFuture<CreateDBInstanceReadReplicaResult, CreateDBInstanceReadReplicaError> {
+        (**self).create_db_instance_read_replica(input)
+    }
+    fn create_db_parameter_group(
+        &self,
+        input: CreateDBParameterGroupMessage,
+    ) -> RusotoFuture<CreateDBParameterGroupResult, CreateDBParameterGroupError> {
+        (**self).create_db_parameter_group(input)
+    }
+    fn create_db_security_group(
+        &self,
+        input: CreateDBSecurityGroupMessage,
+    ) -> RusotoFuture<CreateDBSecurityGroupResult, CreateDBSecurityGroupError> {
+        (**self).create_db_security_group(input)
+    }
+    fn create_db_snapshot(
+        &self,
+        input: CreateDBSnapshotMessage,
+    ) -> RusotoFuture<CreateDBSnapshotResult, CreateDBSnapshotError> {
+        (**self).create_db_snapshot(input)
+    }
+    fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> RusotoFuture<CreateDBSubnetGroupResult, CreateDBSubnetGroupError> {
+        (**self).create_db_subnet_group(input)
+    }
+    fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> RusotoFuture<CreateEventSubscriptionResult, CreateEventSubscriptionError> {
+        (**self).create_event_subscription(input)
+    }
+    fn create_global_cluster(
+        &self,
+        input: CreateGlobalClusterMessage,
+    ) -> RusotoFuture<CreateGlobalClusterResult, CreateGlobalClusterError> {
+        (**self).create_global_cluster(input)
+    }
+    fn create_option_group(
+        &self,
+        input: CreateOptionGroupMessage,
+    ) -> RusotoFuture<CreateOptionGroupResult, CreateOptionGroupError> {
+        (**self).create_option_group(input)
+    }
+    fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> RusotoFuture<DeleteDBClusterResult, DeleteDBClusterError> {
+        (**self).delete_db_cluster(input)
+    }
+    fn delete_db_cluster_endpoint(
+        &self,
+        input: DeleteDBClusterEndpointMessage,
+    ) -> RusotoFuture<DBClusterEndpoint, DeleteDBClusterEndpointError> {
+        (**self).delete_db_cluster_endpoint(input)
+    }
+    fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBClusterParameterGroupError> {
+        (**self).delete_db_cluster_parameter_group(input)
+    }
+    fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> RusotoFuture<DeleteDBClusterSnapshotResult, DeleteDBClusterSnapshotError> {
+        (**self).delete_db_cluster_snapshot(input)
+    }
+    fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> RusotoFuture<DeleteDBInstanceResult, DeleteDBInstanceError> {
+        (**self).delete_db_instance(input)
+    }
+    fn delete_db_instance_automated_backup(
+        &self,
+        input: DeleteDBInstanceAutomatedBackupMessage,
+    ) -> RusotoFuture<DeleteDBInstanceAutomatedBackupResult, DeleteDBInstanceAutomatedBackupError>
+    {
+        (**self).delete_db_instance_automated_backup(input)
+    }
+    fn delete_db_parameter_group(
+        &self,
+        input: DeleteDBParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBParameterGroupError> {
+        (**self).delete_db_parameter_group(input)
+    }
+    fn delete_db_security_group(
+        &self,
+        input: DeleteDBSecurityGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBSecurityGroupError> {
+        (**self).delete_db_security_group(input)
+    }
+    fn delete_db_snapshot(
+        &self,
+        input: DeleteDBSnapshotMessage,
+    ) -> RusotoFuture<DeleteDBSnapshotResult, DeleteDBSnapshotError> {
+        (**self).delete_db_snapshot(input)
+    }
+    fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteDBSubnetGroupError> {
+        (**self).delete_db_subnet_group(input)
+    }
+    fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> RusotoFuture<DeleteEventSubscriptionResult, DeleteEventSubscriptionError> {
+        (**self).delete_event_subscription(input)
+    }
+    fn delete_global_cluster(
+        &self,
+        input: DeleteGlobalClusterMessage,
+    ) -> RusotoFuture<DeleteGlobalClusterResult, DeleteGlobalClusterError> {
+        (**self).delete_global_cluster(input)
+    }
+    fn delete_option_group(
+        &self,
+        input: DeleteOptionGroupMessage,
+    ) -> RusotoFuture<(), DeleteOptionGroupError> {
+        (**self).delete_option_group(input)
+    }
+    fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> RusotoFuture<AccountAttributesMessage, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes(input)
+    }
+    fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> RusotoFuture<CertificateMessage, DescribeCertificatesError> {
+        (**self).describe_certificates(input)
+    }
+    fn describe_db_cluster_backtracks(
+        &self,
+        input: DescribeDBClusterBacktracksMessage,
+    ) -> RusotoFuture<DBClusterBacktrackMessage, DescribeDBClusterBacktracksError> {
+        (**self).describe_db_cluster_backtracks(input)
+    }
+    fn describe_db_cluster_endpoints(
+        &self,
+        input: DescribeDBClusterEndpointsMessage,
+    ) -> RusotoFuture<DBClusterEndpointMessage, DescribeDBClusterEndpointsError> {
+        (**self).describe_db_cluster_endpoints(input)
+    }
+    fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupsMessage, DescribeDBClusterParameterGroupsError> {
+        (**self).describe_db_cluster_parameter_groups(input)
+    }
+    fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupDetails, DescribeDBClusterParametersError> {
+        (**self).describe_db_cluster_parameters(input)
+    }
+    fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> RusotoFuture<
+        DescribeDBClusterSnapshotAttributesResult,
+        DescribeDBClusterSnapshotAttributesError,
+    > {
+        (**self).describe_db_cluster_snapshot_attributes(input)
+    }
+    fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> RusotoFuture<DBClusterSnapshotMessage, DescribeDBClusterSnapshotsError> {
+        (**self).describe_db_cluster_snapshots(input)
+    }
+    fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> RusotoFuture<DBClusterMessage, DescribeDBClustersError> {
+        (**self).describe_db_clusters(input)
+    }
+    fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> RusotoFuture<DBEngineVersionMessage, DescribeDBEngineVersionsError> {
+        (**self).describe_db_engine_versions(input)
+    }
+    fn describe_db_instance_automated_backups(
+        &self,
+        input: DescribeDBInstanceAutomatedBackupsMessage,
+    ) -> RusotoFuture<DBInstanceAutomatedBackupMessage, DescribeDBInstanceAutomatedBackupsError>
+    {
+        (**self).describe_db_instance_automated_backups(input)
+    }
+    fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> RusotoFuture<DBInstanceMessage, DescribeDBInstancesError> {
+        (**self).describe_db_instances(input)
+    }
+    fn describe_db_log_files(
+        &self,
+        input: DescribeDBLogFilesMessage,
+    ) -> RusotoFuture<DescribeDBLogFilesResponse, DescribeDBLogFilesError> {
+        (**self).describe_db_log_files(input)
+    }
+    fn describe_db_parameter_groups(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> RusotoFuture<DBParameterGroupsMessage, DescribeDBParameterGroupsError> {
+        (**self).describe_db_parameter_groups(input)
+    }
+    fn describe_db_parameters(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> RusotoFuture<DBParameterGroupDetails, DescribeDBParametersError> {
+        (**self).describe_db_parameters(input)
+    }
+    fn describe_db_security_groups(
+        &self,
+        input: DescribeDBSecurityGroupsMessage,
+    ) -> RusotoFuture<DBSecurityGroupMessage, DescribeDBSecurityGroupsError> {
+        (**self).describe_db_security_groups(input)
+    }
+    fn describe_db_snapshot_attributes(
+        &self,
+        input: DescribeDBSnapshotAttributesMessage,
+    ) -> RusotoFuture<DescribeDBSnapshotAttributesResult, DescribeDBSnapshotAttributesError> {
+        (**self).describe_db_snapshot_attributes(input)
+    }
+    fn describe_db_snapshots(
+        &self,
+        input: DescribeDBSnapshotsMessage,
+    ) -> RusotoFuture<DBSnapshotMessage, DescribeDBSnapshotsError> {
+        (**self).describe_db_snapshots(input)
+    }
+    fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> RusotoFuture<DBSubnetGroupMessage, DescribeDBSubnetGroupsError> {
+        (**self).describe_db_subnet_groups(input)
+    }
+    fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> RusotoFuture<
+        DescribeEngineDefaultClusterParametersResult,
+        DescribeEngineDefaultClusterParametersError,
+    > {
+        (**self).describe_engine_default_cluster_parameters(input)
+    }
+    fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> RusotoFuture<DescribeEngineDefaultParametersResult, DescribeEngineDefaultParametersError>
+    {
+        (**self).describe_engine_default_parameters(input)
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
+        (**self).describe_event_categories(input)
+    }
+    fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> RusotoFuture<EventSubscriptionsMessage, DescribeEventSubscriptionsError> {
+        (**self).describe_event_subscriptions(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_global_clusters(
+        &self,
+        input: DescribeGlobalClustersMessage,
+    ) -> RusotoFuture<GlobalClustersMessage, DescribeGlobalClustersError> {
+        (**self).describe_global_clusters(input)
+    }
+    fn describe_option_group_options(
+        &self,
+        input: DescribeOptionGroupOptionsMessage,
+    ) -> RusotoFuture<OptionGroupOptionsMessage, DescribeOptionGroupOptionsError> {
+        (**self).describe_option_group_options(input)
+    }
+    fn describe_option_groups(
+        &self,
+        input: DescribeOptionGroupsMessage,
+    ) -> RusotoFuture<OptionGroups, DescribeOptionGroupsError> {
+        (**self).describe_option_groups(input)
+    }
+    fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> RusotoFuture<OrderableDBInstanceOptionsMessage, DescribeOrderableDBInstanceOptionsError>
+    {
+        (**self).describe_orderable_db_instance_options(input)
+    }
+    fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> RusotoFuture<PendingMaintenanceActionsMessage, DescribePendingMaintenanceActionsError>
+    {
+        (**self).describe_pending_maintenance_actions(input)
+    }
+    fn describe_reserved_db_instances(
+        &self,
+        input: DescribeReservedDBInstancesMessage,
+    ) -> RusotoFuture<ReservedDBInstanceMessage, DescribeReservedDBInstancesError> {
+        (**self).describe_reserved_db_instances(input)
+    }
+    fn describe_reserved_db_instances_offerings(
+        &self,
+        input: DescribeReservedDBInstancesOfferingsMessage,
+    ) -> RusotoFuture<ReservedDBInstancesOfferingMessage, DescribeReservedDBInstancesOfferingsError>
+    {
+        (**self).describe_reserved_db_instances_offerings(input)
+    }
+    fn describe_source_regions(
+        &self,
+        input: DescribeSourceRegionsMessage,
+    ) -> RusotoFuture<SourceRegionMessage, DescribeSourceRegionsError> {
+        (**self).describe_source_regions(input)
+    }
+    fn describe_valid_db_instance_modifications(
+        &self,
+        input: DescribeValidDBInstanceModificationsMessage,
+    ) -> RusotoFuture<
+        DescribeValidDBInstanceModificationsResult,
+        DescribeValidDBInstanceModificationsError,
+    > {
+        (**self).describe_valid_db_instance_modifications(input)
+    }
+    fn download_db_log_file_portion(
+        &self,
+        input: DownloadDBLogFilePortionMessage,
+    ) -> RusotoFuture<DownloadDBLogFilePortionDetails, DownloadDBLogFilePortionError> {
+        (**self).download_db_log_file_portion(input)
+    }
+    fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> RusotoFuture<FailoverDBClusterResult, FailoverDBClusterError> {
+        (**self).failover_db_cluster(input)
+    }
+    fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> RusotoFuture<TagListMessage, ListTagsForResourceError> {
+        (**self).list_tags_for_resource(input)
+    }
+    fn modify_current_db_cluster_capacity(
+        &self,
+        input: ModifyCurrentDBClusterCapacityMessage,
+    ) -> RusotoFuture<DBClusterCapacityInfo, ModifyCurrentDBClusterCapacityError> {
+        (**self).modify_current_db_cluster_capacity(input)
+    }
+    fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> RusotoFuture<ModifyDBClusterResult, ModifyDBClusterError> {
+        (**self).modify_db_cluster(input)
+    }
+    fn modify_db_cluster_endpoint(
+        &self,
+        input: ModifyDBClusterEndpointMessage,
+    ) -> RusotoFuture<DBClusterEndpoint, ModifyDBClusterEndpointError> {
+        (**self).modify_db_cluster_endpoint(input)
+    }
+    fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ModifyDBClusterParameterGroupError> {
+        (**self).modify_db_cluster_parameter_group(input)
+    }
+    fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> RusotoFuture<ModifyDBClusterSnapshotAttributeResult, ModifyDBClusterSnapshotAttributeError>
+    {
+        (**self).modify_db_cluster_snapshot_attribute(input)
+    }
+    fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> RusotoFuture<ModifyDBInstanceResult, ModifyDBInstanceError> {
+        (**self).modify_db_instance(input)
+    }
+    fn modify_db_parameter_group(
+        &self,
+        input: ModifyDBParameterGroupMessage,
+    ) -> RusotoFuture<DBParameterGroupNameMessage, ModifyDBParameterGroupError> {
+        (**self).modify_db_parameter_group(input)
+    }
+    fn modify_db_snapshot(
+        &self,
+        input: ModifyDBSnapshotMessage,
+    ) -> RusotoFuture<ModifyDBSnapshotResult, ModifyDBSnapshotError> {
+        (**self).modify_db_snapshot(input)
+    }
+    fn modify_db_snapshot_attribute(
+        &self,
+        input: ModifyDBSnapshotAttributeMessage,
+    ) -> RusotoFuture<ModifyDBSnapshotAttributeResult, ModifyDBSnapshotAttributeError> {
+        (**self).modify_db_snapshot_attribute(input)
+    }
+    fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyDBSubnetGroupResult, ModifyDBSubnetGroupError> {
+        (**self).modify_db_subnet_group(input)
+    }
+    fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> RusotoFuture<ModifyEventSubscriptionResult, ModifyEventSubscriptionError> {
+        (**self).modify_event_subscription(input)
+    }
+    fn modify_global_cluster(
+        &self,
+        input: ModifyGlobalClusterMessage,
+    ) -> RusotoFuture<ModifyGlobalClusterResult, ModifyGlobalClusterError> {
+        (**self).modify_global_cluster(input)
+    }
+    fn modify_option_group(
+        &self,
+        input: ModifyOptionGroupMessage,
+    ) -> RusotoFuture<ModifyOptionGroupResult, ModifyOptionGroupError> {
+        (**self).modify_option_group(input)
+    }
+    fn promote_read_replica(
+        &self,
+        input: PromoteReadReplicaMessage,
+    ) -> RusotoFuture<PromoteReadReplicaResult, PromoteReadReplicaError> {
+        (**self).promote_read_replica(input)
+    }
+    fn promote_read_replica_db_cluster(
+        &self,
+        input: PromoteReadReplicaDBClusterMessage,
+    ) -> RusotoFuture<PromoteReadReplicaDBClusterResult, PromoteReadReplicaDBClusterError> {
+        (**self).promote_read_replica_db_cluster(input)
+    }
+    fn purchase_reserved_db_instances_offering(
+        &self,
+        input: PurchaseReservedDBInstancesOfferingMessage,
+    ) -> RusotoFuture<
+        PurchaseReservedDBInstancesOfferingResult,
+        PurchaseReservedDBInstancesOfferingError,
+    > {
+        (**self).purchase_reserved_db_instances_offering(input)
+    }
+    fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> RusotoFuture<RebootDBInstanceResult, RebootDBInstanceError> {
+        (**self).reboot_db_instance(input)
+    }
+    fn remove_from_global_cluster(
+        &self,
+        input: RemoveFromGlobalClusterMessage,
+    ) -> RusotoFuture<RemoveFromGlobalClusterResult, RemoveFromGlobalClusterError> {
+        (**self).remove_from_global_cluster(input)
+    }
+    fn remove_role_from_db_cluster(
+        &self,
+        input: RemoveRoleFromDBClusterMessage,
+    ) -> RusotoFuture<(), RemoveRoleFromDBClusterError> {
+        (**self).remove_role_from_db_cluster(input)
+    }
+    fn remove_role_from_db_instance(
+        &self,
+        input: RemoveRoleFromDBInstanceMessage,
+    ) -> RusotoFuture<(), RemoveRoleFromDBInstanceError> {
+        (**self).remove_role_from_db_instance(input)
+    }
+    fn remove_source_identifier_from_subscription(
+        &self,
+        input: RemoveSourceIdentifierFromSubscriptionMessage,
+    ) -> RusotoFuture<
+        RemoveSourceIdentifierFromSubscriptionResult,
+        RemoveSourceIdentifierFromSubscriptionError,
+    > {
+        (**self).remove_source_identifier_from_subscription(input)
+    }
+    fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> RusotoFuture<(), RemoveTagsFromResourceError> {
+        (**self).remove_tags_from_resource(input)
+    }
+    fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> RusotoFuture<DBClusterParameterGroupNameMessage, ResetDBClusterParameterGroupError> {
+        (**self).reset_db_cluster_parameter_group(input)
+    }
+    fn reset_db_parameter_group(
+        &self,
+        input: ResetDBParameterGroupMessage,
+    ) -> RusotoFuture<DBParameterGroupNameMessage, ResetDBParameterGroupError> {
+        (**self).reset_db_parameter_group(input)
+    }
+    fn restore_db_cluster_from_s3(
+        &self,
+        input: RestoreDBClusterFromS3Message,
+    ) -> RusotoFuture<RestoreDBClusterFromS3Result, RestoreDBClusterFromS3Error> {
+        (**self).restore_db_cluster_from_s3(input)
+    }
+    fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> RusotoFuture<RestoreDBClusterFromSnapshotResult, RestoreDBClusterFromSnapshotError> {
+        (**self).restore_db_cluster_from_snapshot(input)
+    }
+    fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> RusotoFuture<RestoreDBClusterToPointInTimeResult, RestoreDBClusterToPointInTimeError> {
+        (**self).restore_db_cluster_to_point_in_time(input)
+    }
+    fn restore_db_instance_from_db_snapshot(
+        &self,
+        input: RestoreDBInstanceFromDBSnapshotMessage,
+    ) -> RusotoFuture<RestoreDBInstanceFromDBSnapshotResult, RestoreDBInstanceFromDBSnapshotError>
+    {
+        (**self).restore_db_instance_from_db_snapshot(input)
+    }
+    fn restore_db_instance_from_s3(
+        &self,
+        input: RestoreDBInstanceFromS3Message,
+    ) -> RusotoFuture<RestoreDBInstanceFromS3Result, RestoreDBInstanceFromS3Error> {
+        (**self).restore_db_instance_from_s3(input)
+    }
+    fn restore_db_instance_to_point_in_time(
+        &self,
+        input: RestoreDBInstanceToPointInTimeMessage,
+    ) -> RusotoFuture<RestoreDBInstanceToPointInTimeResult, RestoreDBInstanceToPointInTimeError>
+    {
+        (**self).restore_db_instance_to_point_in_time(input)
+    }
+    fn revoke_db_security_group_ingress(
+        &self,
+        input: RevokeDBSecurityGroupIngressMessage,
+    ) -> RusotoFuture<RevokeDBSecurityGroupIngressResult, RevokeDBSecurityGroupIngressError> {
+        (**self).revoke_db_security_group_ingress(input)
+    }
+    fn start_activity_stream(
+        &self,
+        input: StartActivityStreamRequest,
+    ) -> RusotoFuture<StartActivityStreamResponse, StartActivityStreamError> {
+        (**self).start_activity_stream(input)
+    }
+    fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> RusotoFuture<StartDBClusterResult, StartDBClusterError> {
+        (**self).start_db_cluster(input)
+    }
+    fn start_db_instance(
+        &self,
+        input: StartDBInstanceMessage,
+    ) -> RusotoFuture<StartDBInstanceResult, StartDBInstanceError> {
+        (**self).start_db_instance(input)
+    }
+    fn stop_activity_stream(
+        &self,
+        input: StopActivityStreamRequest,
+    ) -> RusotoFuture<StopActivityStreamResponse, StopActivityStreamError> {
+        (**self).stop_activity_stream(input)
+    }
+    fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> RusotoFuture<StopDBClusterResult, StopDBClusterError> {
+        (**self).stop_db_cluster(input)
+    }
+    fn stop_db_instance(
+        &self,
+        input: StopDBInstanceMessage,
+    ) -> RusotoFuture<StopDBInstanceResult, StopDBInstanceError> {
+        (**self).stop_db_instance(input)
+    }
+}
+/// The error codes Amazon RDS retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
+/// A client for the Amazon RDS API.
+#[derive(Clone)]
+pub struct RdsClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl RdsClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> RdsClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_RDS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> RdsClient {
+        Self::new(region::Region::from_env_for_service("RDS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `rds`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> RdsClient {
+        Self::new_with_client(config.client().clone(), config.region_for("rds", "RDS"))
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RdsClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> RdsClient {
+        RdsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "rds")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(&self.region, "rds", "rds", "RDS")
+            .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Rds` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Rds + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for RdsClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon RDS"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "rds"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> RdsClient {
+        Self::new_with_client(client, region)
     }
 }
 
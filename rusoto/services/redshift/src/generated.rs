@@ -28,6 +28,7 @@ use rusoto_core::proto::xml::util::{
 };
 use rusoto_core::proto::xml::util::{Next, Peek, XmlParseError, XmlResponse};
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::IsoTimestamp;
 use serde_urlencoded;
 use std::str::FromStr;
 use xml::reader::ParserConfig;
@@ -41,6 +42,49 @@ pub struct AcceptReservedNodeExchangeInputMessage {
     pub target_reserved_node_offering_id: String,
 }
 
+impl AcceptReservedNodeExchangeInputMessage {
+    /// Returns a builder for `AcceptReservedNodeExchangeInputMessage`, checking its required members when built.
+    pub fn builder() -> AcceptReservedNodeExchangeInputMessageBuilder {
+        AcceptReservedNodeExchangeInputMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AcceptReservedNodeExchangeInputMessage`](struct.AcceptReservedNodeExchangeInputMessage.html).
+#[derive(Default, Debug)]
+pub struct AcceptReservedNodeExchangeInputMessageBuilder {
+    reserved_node_id: Option<String>,
+    target_reserved_node_offering_id: Option<String>,
+}
+
+impl AcceptReservedNodeExchangeInputMessageBuilder {
+    /// Sets the [`reserved_node_id`](struct.AcceptReservedNodeExchangeInputMessage.html#structfield.reserved_node_id) member.
+    pub fn reserved_node_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_node_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_reserved_node_offering_id`](struct.AcceptReservedNodeExchangeInputMessage.html#structfield.target_reserved_node_offering_id) member.
+    pub fn target_reserved_node_offering_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_reserved_node_offering_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AcceptReservedNodeExchangeInputMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AcceptReservedNodeExchangeInputMessage, ::rusoto_core::BuildError>
+    {
+        Ok(AcceptReservedNodeExchangeInputMessage {
+            reserved_node_id: self
+                .reserved_node_id
+                .ok_or(::rusoto_core::BuildError::missing("reserved_node_id"))?,
+            target_reserved_node_offering_id: self.target_reserved_node_offering_id.ok_or(
+                ::rusoto_core::BuildError::missing("target_reserved_node_offering_id"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `AcceptReservedNodeExchangeInputMessage` contents to a `SignedRequest`.
 struct AcceptReservedNodeExchangeInputMessageSerializer;
 impl AcceptReservedNodeExchangeInputMessageSerializer {
@@ -67,10 +111,10 @@ pub struct AcceptReservedNodeExchangeOutputMessage {
     pub exchanged_reserved_node: Option<ReservedNode>,
 }
 
-struct AcceptReservedNodeExchangeOutputMessageDeserializer;
+pub(crate) struct AcceptReservedNodeExchangeOutputMessageDeserializer;
 impl AcceptReservedNodeExchangeOutputMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AcceptReservedNodeExchangeOutputMessage, XmlParseError> {
@@ -101,10 +145,10 @@ pub struct AccountAttribute {
     pub attribute_values: Option<Vec<AttributeValueTarget>>,
 }
 
-struct AccountAttributeDeserializer;
+pub(crate) struct AccountAttributeDeserializer;
 impl AccountAttributeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccountAttribute, XmlParseError> {
@@ -131,10 +175,10 @@ pub struct AccountAttributeList {
     pub account_attributes: Option<Vec<AccountAttribute>>,
 }
 
-struct AccountAttributeListDeserializer;
+pub(crate) struct AccountAttributeListDeserializer;
 impl AccountAttributeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccountAttributeList, XmlParseError> {
@@ -160,10 +204,10 @@ pub struct AccountWithRestoreAccess {
     pub account_id: Option<String>,
 }
 
-struct AccountWithRestoreAccessDeserializer;
+pub(crate) struct AccountWithRestoreAccessDeserializer;
 impl AccountWithRestoreAccessDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AccountWithRestoreAccess, XmlParseError> {
@@ -186,10 +230,10 @@ impl AccountWithRestoreAccessDeserializer {
         )
     }
 }
-struct AccountsWithRestoreAccessListDeserializer;
+pub(crate) struct AccountsWithRestoreAccessListDeserializer;
 impl AccountsWithRestoreAccessListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AccountWithRestoreAccess>, XmlParseError> {
@@ -206,10 +250,10 @@ impl AccountsWithRestoreAccessListDeserializer {
         })
     }
 }
-struct AssociatedClusterListDeserializer;
+pub(crate) struct AssociatedClusterListDeserializer;
 impl AssociatedClusterListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ClusterAssociatedToSchedule>, XmlParseError> {
@@ -226,10 +270,10 @@ impl AssociatedClusterListDeserializer {
         })
     }
 }
-struct AttributeListDeserializer;
+pub(crate) struct AttributeListDeserializer;
 impl AttributeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AccountAttribute>, XmlParseError> {
@@ -258,10 +302,10 @@ impl AttributeNameListSerializer {
     }
 }
 
-struct AttributeValueListDeserializer;
+pub(crate) struct AttributeValueListDeserializer;
 impl AttributeValueListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AttributeValueTarget>, XmlParseError> {
@@ -285,10 +329,10 @@ pub struct AttributeValueTarget {
     pub attribute_value: Option<String>,
 }
 
-struct AttributeValueTargetDeserializer;
+pub(crate) struct AttributeValueTargetDeserializer;
 impl AttributeValueTargetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AttributeValueTarget, XmlParseError> {
@@ -317,6 +361,63 @@ pub struct AuthorizeClusterSecurityGroupIngressMessage {
     pub ec2_security_group_owner_id: Option<String>,
 }
 
+impl AuthorizeClusterSecurityGroupIngressMessage {
+    /// Returns a builder for `AuthorizeClusterSecurityGroupIngressMessage`, checking its required members when built.
+    pub fn builder() -> AuthorizeClusterSecurityGroupIngressMessageBuilder {
+        AuthorizeClusterSecurityGroupIngressMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AuthorizeClusterSecurityGroupIngressMessage`](struct.AuthorizeClusterSecurityGroupIngressMessage.html).
+#[derive(Default, Debug)]
+pub struct AuthorizeClusterSecurityGroupIngressMessageBuilder {
+    cidrip: Option<String>,
+    cluster_security_group_name: Option<String>,
+    ec2_security_group_name: Option<String>,
+    ec2_security_group_owner_id: Option<String>,
+}
+
+impl AuthorizeClusterSecurityGroupIngressMessageBuilder {
+    /// Sets the [`cidrip`](struct.AuthorizeClusterSecurityGroupIngressMessage.html#structfield.cidrip) member.
+    pub fn cidrip<T: Into<String>>(mut self, value: T) -> Self {
+        self.cidrip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_security_group_name`](struct.AuthorizeClusterSecurityGroupIngressMessage.html#structfield.cluster_security_group_name) member.
+    pub fn cluster_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_name`](struct.AuthorizeClusterSecurityGroupIngressMessage.html#structfield.ec2_security_group_name) member.
+    pub fn ec2_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_owner_id`](struct.AuthorizeClusterSecurityGroupIngressMessage.html#structfield.ec2_security_group_owner_id) member.
+    pub fn ec2_security_group_owner_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_owner_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `AuthorizeClusterSecurityGroupIngressMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AuthorizeClusterSecurityGroupIngressMessage, ::rusoto_core::BuildError>
+    {
+        Ok(AuthorizeClusterSecurityGroupIngressMessage {
+            cidrip: self.cidrip,
+            cluster_security_group_name: self.cluster_security_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cluster_security_group_name"),
+            )?,
+            ec2_security_group_name: self.ec2_security_group_name,
+            ec2_security_group_owner_id: self.ec2_security_group_owner_id,
+        })
+    }
+}
+
 /// Serialize `AuthorizeClusterSecurityGroupIngressMessage` contents to a `SignedRequest`.
 struct AuthorizeClusterSecurityGroupIngressMessageSerializer;
 impl AuthorizeClusterSecurityGroupIngressMessageSerializer {
@@ -357,10 +458,10 @@ pub struct AuthorizeClusterSecurityGroupIngressResult {
     pub cluster_security_group: Option<ClusterSecurityGroup>,
 }
 
-struct AuthorizeClusterSecurityGroupIngressResultDeserializer;
+pub(crate) struct AuthorizeClusterSecurityGroupIngressResultDeserializer;
 impl AuthorizeClusterSecurityGroupIngressResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AuthorizeClusterSecurityGroupIngressResult, XmlParseError> {
@@ -394,6 +495,56 @@ pub struct AuthorizeSnapshotAccessMessage {
     pub snapshot_identifier: String,
 }
 
+impl AuthorizeSnapshotAccessMessage {
+    /// Returns a builder for `AuthorizeSnapshotAccessMessage`, checking its required members when built.
+    pub fn builder() -> AuthorizeSnapshotAccessMessageBuilder {
+        AuthorizeSnapshotAccessMessageBuilder::default()
+    }
+}
+
+/// Builds a [`AuthorizeSnapshotAccessMessage`](struct.AuthorizeSnapshotAccessMessage.html).
+#[derive(Default, Debug)]
+pub struct AuthorizeSnapshotAccessMessageBuilder {
+    account_with_restore_access: Option<String>,
+    snapshot_cluster_identifier: Option<String>,
+    snapshot_identifier: Option<String>,
+}
+
+impl AuthorizeSnapshotAccessMessageBuilder {
+    /// Sets the [`account_with_restore_access`](struct.AuthorizeSnapshotAccessMessage.html#structfield.account_with_restore_access) member.
+    pub fn account_with_restore_access<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_with_restore_access = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_cluster_identifier`](struct.AuthorizeSnapshotAccessMessage.html#structfield.snapshot_cluster_identifier) member.
+    pub fn snapshot_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_identifier`](struct.AuthorizeSnapshotAccessMessage.html#structfield.snapshot_identifier) member.
+    pub fn snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `AuthorizeSnapshotAccessMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<AuthorizeSnapshotAccessMessage, ::rusoto_core::BuildError> {
+        Ok(AuthorizeSnapshotAccessMessage {
+            account_with_restore_access: self.account_with_restore_access.ok_or(
+                ::rusoto_core::BuildError::missing("account_with_restore_access"),
+            )?,
+            snapshot_cluster_identifier: self.snapshot_cluster_identifier,
+            snapshot_identifier: self
+                .snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `AuthorizeSnapshotAccessMessage` contents to a `SignedRequest`.
 struct AuthorizeSnapshotAccessMessageSerializer;
 impl AuthorizeSnapshotAccessMessageSerializer {
@@ -425,10 +576,10 @@ pub struct AuthorizeSnapshotAccessResult {
     pub snapshot: Option<Snapshot>,
 }
 
-struct AuthorizeSnapshotAccessResultDeserializer;
+pub(crate) struct AuthorizeSnapshotAccessResultDeserializer;
 impl AuthorizeSnapshotAccessResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AuthorizeSnapshotAccessResult, XmlParseError> {
@@ -456,10 +607,10 @@ pub struct AvailabilityZone {
     pub supported_platforms: Option<Vec<SupportedPlatform>>,
 }
 
-struct AvailabilityZoneDeserializer;
+pub(crate) struct AvailabilityZoneDeserializer;
 impl AvailabilityZoneDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<AvailabilityZone, XmlParseError> {
@@ -482,10 +633,10 @@ impl AvailabilityZoneDeserializer {
         })
     }
 }
-struct AvailabilityZoneListDeserializer;
+pub(crate) struct AvailabilityZoneListDeserializer;
 impl AvailabilityZoneListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<AvailabilityZone>, XmlParseError> {
@@ -508,6 +659,38 @@ pub struct BatchDeleteClusterSnapshotsRequest {
     pub identifiers: Vec<DeleteClusterSnapshotMessage>,
 }
 
+impl BatchDeleteClusterSnapshotsRequest {
+    /// Returns a builder for `BatchDeleteClusterSnapshotsRequest`, checking its required members when built.
+    pub fn builder() -> BatchDeleteClusterSnapshotsRequestBuilder {
+        BatchDeleteClusterSnapshotsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`BatchDeleteClusterSnapshotsRequest`](struct.BatchDeleteClusterSnapshotsRequest.html).
+#[derive(Default, Debug)]
+pub struct BatchDeleteClusterSnapshotsRequestBuilder {
+    identifiers: Option<Vec<DeleteClusterSnapshotMessage>>,
+}
+
+impl BatchDeleteClusterSnapshotsRequestBuilder {
+    /// Sets the [`identifiers`](struct.BatchDeleteClusterSnapshotsRequest.html#structfield.identifiers) member.
+    pub fn identifiers<T: Into<Vec<DeleteClusterSnapshotMessage>>>(mut self, value: T) -> Self {
+        self.identifiers = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchDeleteClusterSnapshotsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchDeleteClusterSnapshotsRequest, ::rusoto_core::BuildError> {
+        Ok(BatchDeleteClusterSnapshotsRequest {
+            identifiers: self
+                .identifiers
+                .ok_or(::rusoto_core::BuildError::missing("identifiers"))?,
+        })
+    }
+}
+
 /// Serialize `BatchDeleteClusterSnapshotsRequest` contents to a `SignedRequest`.
 struct BatchDeleteClusterSnapshotsRequestSerializer;
 impl BatchDeleteClusterSnapshotsRequestSerializer {
@@ -533,10 +716,10 @@ pub struct BatchDeleteClusterSnapshotsResult {
     pub resources: Option<Vec<String>>,
 }
 
-struct BatchDeleteClusterSnapshotsResultDeserializer;
+pub(crate) struct BatchDeleteClusterSnapshotsResultDeserializer;
 impl BatchDeleteClusterSnapshotsResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<BatchDeleteClusterSnapshotsResult, XmlParseError> {
@@ -574,6 +757,54 @@ pub struct BatchModifyClusterSnapshotsMessage {
     pub snapshot_identifier_list: Vec<String>,
 }
 
+impl BatchModifyClusterSnapshotsMessage {
+    /// Returns a builder for `BatchModifyClusterSnapshotsMessage`, checking its required members when built.
+    pub fn builder() -> BatchModifyClusterSnapshotsMessageBuilder {
+        BatchModifyClusterSnapshotsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`BatchModifyClusterSnapshotsMessage`](struct.BatchModifyClusterSnapshotsMessage.html).
+#[derive(Default, Debug)]
+pub struct BatchModifyClusterSnapshotsMessageBuilder {
+    force: Option<bool>,
+    manual_snapshot_retention_period: Option<i64>,
+    snapshot_identifier_list: Option<Vec<String>>,
+}
+
+impl BatchModifyClusterSnapshotsMessageBuilder {
+    /// Sets the [`force`](struct.BatchModifyClusterSnapshotsMessage.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manual_snapshot_retention_period`](struct.BatchModifyClusterSnapshotsMessage.html#structfield.manual_snapshot_retention_period) member.
+    pub fn manual_snapshot_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.manual_snapshot_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_identifier_list`](struct.BatchModifyClusterSnapshotsMessage.html#structfield.snapshot_identifier_list) member.
+    pub fn snapshot_identifier_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.snapshot_identifier_list = Some(value.into());
+        self
+    }
+
+    /// Builds the `BatchModifyClusterSnapshotsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<BatchModifyClusterSnapshotsMessage, ::rusoto_core::BuildError> {
+        Ok(BatchModifyClusterSnapshotsMessage {
+            force: self.force,
+            manual_snapshot_retention_period: self.manual_snapshot_retention_period,
+            snapshot_identifier_list: self.snapshot_identifier_list.ok_or(
+                ::rusoto_core::BuildError::missing("snapshot_identifier_list"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `BatchModifyClusterSnapshotsMessage` contents to a `SignedRequest`.
 struct BatchModifyClusterSnapshotsMessageSerializer;
 impl BatchModifyClusterSnapshotsMessageSerializer {
@@ -608,10 +839,10 @@ pub struct BatchModifyClusterSnapshotsOutputMessage {
     pub resources: Option<Vec<String>>,
 }
 
-struct BatchModifyClusterSnapshotsOutputMessageDeserializer;
+pub(crate) struct BatchModifyClusterSnapshotsOutputMessageDeserializer;
 impl BatchModifyClusterSnapshotsOutputMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<BatchModifyClusterSnapshotsOutputMessage, XmlParseError> {
@@ -637,10 +868,10 @@ impl BatchModifyClusterSnapshotsOutputMessageDeserializer {
         )
     }
 }
-struct BatchSnapshotOperationErrorListDeserializer;
+pub(crate) struct BatchSnapshotOperationErrorListDeserializer;
 impl BatchSnapshotOperationErrorListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SnapshotErrorMessage>, XmlParseError> {
@@ -657,10 +888,10 @@ impl BatchSnapshotOperationErrorListDeserializer {
         })
     }
 }
-struct BatchSnapshotOperationErrorsDeserializer;
+pub(crate) struct BatchSnapshotOperationErrorsDeserializer;
 impl BatchSnapshotOperationErrorsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SnapshotErrorMessage>, XmlParseError> {
@@ -677,10 +908,13 @@ impl BatchSnapshotOperationErrorsDeserializer {
         })
     }
 }
-struct BooleanDeserializer;
+pub(crate) struct BooleanDeserializer;
 impl BooleanDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -688,10 +922,13 @@ impl BooleanDeserializer {
         Ok(obj)
     }
 }
-struct BooleanOptionalDeserializer;
+pub(crate) struct BooleanOptionalDeserializer;
 impl BooleanOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<bool, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<bool, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = bool::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -705,6 +942,36 @@ pub struct CancelResizeMessage {
     pub cluster_identifier: String,
 }
 
+impl CancelResizeMessage {
+    /// Returns a builder for `CancelResizeMessage`, checking its required members when built.
+    pub fn builder() -> CancelResizeMessageBuilder {
+        CancelResizeMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CancelResizeMessage`](struct.CancelResizeMessage.html).
+#[derive(Default, Debug)]
+pub struct CancelResizeMessageBuilder {
+    cluster_identifier: Option<String>,
+}
+
+impl CancelResizeMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.CancelResizeMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CancelResizeMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CancelResizeMessage, ::rusoto_core::BuildError> {
+        Ok(CancelResizeMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `CancelResizeMessage` contents to a `SignedRequest`.
 struct CancelResizeMessageSerializer;
 impl CancelResizeMessageSerializer {
@@ -733,7 +1000,7 @@ pub struct Cluster {
     /// <p><p>The availability status of the cluster for queries. Possible values are the following:</p> <ul> <li> <p>Available - The cluster is available for queries. </p> </li> <li> <p>Unavailable - The cluster is not available for queries.</p> </li> <li> <p>Maintenance - The cluster is intermittently available for queries due to maintenance activities.</p> </li> <li> <p>Modifying - The cluster is intermittently available for queries due to changes that modify the cluster.</p> </li> <li> <p>Failed - The cluster failed and is not available for queries.</p> </li> </ul></p>
     pub cluster_availability_status: Option<String>,
     /// <p>The date and time that the cluster was created.</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<IsoTimestamp>,
     /// <p>The unique identifier of the cluster.</p>
     pub cluster_identifier: Option<String>,
     /// <p>The nodes in the cluster.</p>
@@ -771,7 +1038,7 @@ pub struct Cluster {
     /// <p>An option that specifies whether to create the cluster with enhanced VPC routing enabled. To create a cluster that uses enhanced VPC routing, the cluster must be in a VPC. For more information, see <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/enhanced-vpc-routing.html">Enhanced VPC Routing</a> in the Amazon Redshift Cluster Management Guide.</p> <p>If this option is <code>true</code>, enhanced VPC routing is enabled. </p> <p>Default: false</p>
     pub enhanced_vpc_routing: Option<bool>,
     /// <p>The date and time when the next snapshot is expected to be taken for clusters with a valid snapshot schedule and backups enabled. </p>
-    pub expected_next_snapshot_schedule_time: Option<String>,
+    pub expected_next_snapshot_schedule_time: Option<IsoTimestamp>,
     /// <p><p> The status of next expected snapshot for clusters having a valid snapshot schedule and backups enabled. Possible values are the following:</p> <ul> <li> <p>OnTrack - The next snapshot is expected to be taken on time. </p> </li> <li> <p>Pending - The next snapshot is pending to be taken. </p> </li> </ul></p>
     pub expected_next_snapshot_schedule_time_status: Option<String>,
     /// <p>A value that reports whether the Amazon Redshift cluster has finished applying any hardware security module (HSM) settings changes specified in a modify cluster command.</p> <p>Values: active, applying</p>
@@ -807,7 +1074,7 @@ pub struct Cluster {
     /// <p>A unique identifier for the cluster snapshot schedule.</p>
     pub snapshot_schedule_identifier: Option<String>,
     /// <p>The current state of the cluster snapshot schedule.</p>
-    pub snapshot_schedule_state: Option<String>,
+    pub snapshot_schedule_state: Option<ScheduleState>,
     /// <p>The list of tags for the cluster.</p>
     pub tags: Option<Vec<Tag>>,
     /// <p>The identifier of the VPC the cluster is in, if the cluster is in a VPC.</p>
@@ -816,10 +1083,10 @@ pub struct Cluster {
     pub vpc_security_groups: Option<Vec<VpcSecurityGroupMembership>>,
 }
 
-struct ClusterDeserializer;
+pub(crate) struct ClusterDeserializer;
 impl ClusterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Cluster, XmlParseError> {
@@ -1073,13 +1340,13 @@ pub struct ClusterAssociatedToSchedule {
     /// <p><p/></p>
     pub cluster_identifier: Option<String>,
     /// <p><p/></p>
-    pub schedule_association_state: Option<String>,
+    pub schedule_association_state: Option<ScheduleState>,
 }
 
-struct ClusterAssociatedToScheduleDeserializer;
+pub(crate) struct ClusterAssociatedToScheduleDeserializer;
 impl ClusterAssociatedToScheduleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterAssociatedToSchedule, XmlParseError> {
@@ -1114,13 +1381,13 @@ pub struct ClusterCredentials {
     /// <p>A database user name that is authorized to log on to the database <code>DbName</code> using the password <code>DbPassword</code>. If the specified DbUser exists in the database, the new user name has the same database privileges as the the user named in DbUser. By default, the user is added to PUBLIC. If the <code>DbGroups</code> parameter is specifed, <code>DbUser</code> is added to the listed groups for any sessions created using these credentials.</p>
     pub db_user: Option<String>,
     /// <p>The date and time the password in <code>DbPassword</code> expires.</p>
-    pub expiration: Option<String>,
+    pub expiration: Option<IsoTimestamp>,
 }
 
-struct ClusterCredentialsDeserializer;
+pub(crate) struct ClusterCredentialsDeserializer;
 impl ClusterCredentialsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterCredentials, XmlParseError> {
@@ -1152,15 +1419,15 @@ pub struct ClusterDbRevision {
     /// <p>A string representing the current cluster version.</p>
     pub current_database_revision: Option<String>,
     /// <p>The date on which the database revision was released.</p>
-    pub database_revision_release_date: Option<String>,
+    pub database_revision_release_date: Option<IsoTimestamp>,
     /// <p>A list of <code>RevisionTarget</code> objects, where each object describes the database revision that a cluster can be updated to.</p>
     pub revision_targets: Option<Vec<RevisionTarget>>,
 }
 
-struct ClusterDbRevisionDeserializer;
+pub(crate) struct ClusterDbRevisionDeserializer;
 impl ClusterDbRevisionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterDbRevision, XmlParseError> {
@@ -1193,10 +1460,10 @@ impl ClusterDbRevisionDeserializer {
         })
     }
 }
-struct ClusterDbRevisionsListDeserializer;
+pub(crate) struct ClusterDbRevisionsListDeserializer;
 impl ClusterDbRevisionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ClusterDbRevision>, XmlParseError> {
@@ -1221,10 +1488,10 @@ pub struct ClusterDbRevisionsMessage {
     pub marker: Option<String>,
 }
 
-struct ClusterDbRevisionsMessageDeserializer;
+pub(crate) struct ClusterDbRevisionsMessageDeserializer;
 impl ClusterDbRevisionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterDbRevisionsMessage, XmlParseError> {
@@ -1260,10 +1527,10 @@ pub struct ClusterIamRole {
     pub iam_role_arn: Option<String>,
 }
 
-struct ClusterIamRoleDeserializer;
+pub(crate) struct ClusterIamRoleDeserializer;
 impl ClusterIamRoleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterIamRole, XmlParseError> {
@@ -1281,10 +1548,10 @@ impl ClusterIamRoleDeserializer {
         })
     }
 }
-struct ClusterIamRoleListDeserializer;
+pub(crate) struct ClusterIamRoleListDeserializer;
 impl ClusterIamRoleListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ClusterIamRole>, XmlParseError> {
@@ -1301,10 +1568,10 @@ impl ClusterIamRoleListDeserializer {
         })
     }
 }
-struct ClusterListDeserializer;
+pub(crate) struct ClusterListDeserializer;
 impl ClusterListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Cluster>, XmlParseError> {
@@ -1329,10 +1596,10 @@ pub struct ClusterNode {
     pub public_ip_address: Option<String>,
 }
 
-struct ClusterNodeDeserializer;
+pub(crate) struct ClusterNodeDeserializer;
 impl ClusterNodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterNode, XmlParseError> {
@@ -1355,10 +1622,10 @@ impl ClusterNodeDeserializer {
         })
     }
 }
-struct ClusterNodesListDeserializer;
+pub(crate) struct ClusterNodesListDeserializer;
 impl ClusterNodesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ClusterNode>, XmlParseError> {
@@ -1385,10 +1652,10 @@ pub struct ClusterParameterGroup {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct ClusterParameterGroupDeserializer;
+pub(crate) struct ClusterParameterGroupDeserializer;
 impl ClusterParameterGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterParameterGroup, XmlParseError> {
@@ -1429,10 +1696,10 @@ pub struct ClusterParameterGroupDetails {
     pub parameters: Option<Vec<Parameter>>,
 }
 
-struct ClusterParameterGroupDetailsDeserializer;
+pub(crate) struct ClusterParameterGroupDetailsDeserializer;
 impl ClusterParameterGroupDetailsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterParameterGroupDetails, XmlParseError> {
@@ -1465,10 +1732,10 @@ pub struct ClusterParameterGroupNameMessage {
     pub parameter_group_status: Option<String>,
 }
 
-struct ClusterParameterGroupNameMessageDeserializer;
+pub(crate) struct ClusterParameterGroupNameMessageDeserializer;
 impl ClusterParameterGroupNameMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterParameterGroupNameMessage, XmlParseError> {
@@ -1507,10 +1774,10 @@ pub struct ClusterParameterGroupStatus {
     pub parameter_group_name: Option<String>,
 }
 
-struct ClusterParameterGroupStatusDeserializer;
+pub(crate) struct ClusterParameterGroupStatusDeserializer;
 impl ClusterParameterGroupStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterParameterGroupStatus, XmlParseError> {
@@ -1546,10 +1813,10 @@ impl ClusterParameterGroupStatusDeserializer {
         )
     }
 }
-struct ClusterParameterGroupStatusListDeserializer;
+pub(crate) struct ClusterParameterGroupStatusListDeserializer;
 impl ClusterParameterGroupStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ClusterParameterGroupStatus>, XmlParseError> {
@@ -1575,10 +1842,10 @@ pub struct ClusterParameterGroupsMessage {
     pub parameter_groups: Option<Vec<ClusterParameterGroup>>,
 }
 
-struct ClusterParameterGroupsMessageDeserializer;
+pub(crate) struct ClusterParameterGroupsMessageDeserializer;
 impl ClusterParameterGroupsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterParameterGroupsMessage, XmlParseError> {
@@ -1613,10 +1880,10 @@ pub struct ClusterParameterStatus {
     pub parameter_name: Option<String>,
 }
 
-struct ClusterParameterStatusDeserializer;
+pub(crate) struct ClusterParameterStatusDeserializer;
 impl ClusterParameterStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterParameterStatus, XmlParseError> {
@@ -1644,10 +1911,10 @@ impl ClusterParameterStatusDeserializer {
         })
     }
 }
-struct ClusterParameterStatusListDeserializer;
+pub(crate) struct ClusterParameterStatusListDeserializer;
 impl ClusterParameterStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ClusterParameterStatus>, XmlParseError> {
@@ -1678,10 +1945,10 @@ pub struct ClusterSecurityGroup {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct ClusterSecurityGroupDeserializer;
+pub(crate) struct ClusterSecurityGroupDeserializer;
 impl ClusterSecurityGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterSecurityGroup, XmlParseError> {
@@ -1726,10 +1993,10 @@ pub struct ClusterSecurityGroupMembership {
     pub status: Option<String>,
 }
 
-struct ClusterSecurityGroupMembershipDeserializer;
+pub(crate) struct ClusterSecurityGroupMembershipDeserializer;
 impl ClusterSecurityGroupMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterSecurityGroupMembership, XmlParseError> {
@@ -1754,10 +2021,10 @@ impl ClusterSecurityGroupMembershipDeserializer {
         )
     }
 }
-struct ClusterSecurityGroupMembershipListDeserializer;
+pub(crate) struct ClusterSecurityGroupMembershipListDeserializer;
 impl ClusterSecurityGroupMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ClusterSecurityGroupMembership>, XmlParseError> {
@@ -1783,10 +2050,10 @@ pub struct ClusterSecurityGroupMessage {
     pub marker: Option<String>,
 }
 
-struct ClusterSecurityGroupMessageDeserializer;
+pub(crate) struct ClusterSecurityGroupMessageDeserializer;
 impl ClusterSecurityGroupMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterSecurityGroupMessage, XmlParseError> {
@@ -1825,10 +2092,10 @@ impl ClusterSecurityGroupNameListSerializer {
     }
 }
 
-struct ClusterSecurityGroupsDeserializer;
+pub(crate) struct ClusterSecurityGroupsDeserializer;
 impl ClusterSecurityGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ClusterSecurityGroup>, XmlParseError> {
@@ -1858,10 +2125,10 @@ pub struct ClusterSnapshotCopyStatus {
     pub snapshot_copy_grant_name: Option<String>,
 }
 
-struct ClusterSnapshotCopyStatusDeserializer;
+pub(crate) struct ClusterSnapshotCopyStatusDeserializer;
 impl ClusterSnapshotCopyStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterSnapshotCopyStatus, XmlParseError> {
@@ -1915,10 +2182,10 @@ pub struct ClusterSubnetGroup {
     pub vpc_id: Option<String>,
 }
 
-struct ClusterSubnetGroupDeserializer;
+pub(crate) struct ClusterSubnetGroupDeserializer;
 impl ClusterSubnetGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterSubnetGroup, XmlParseError> {
@@ -1965,10 +2232,10 @@ pub struct ClusterSubnetGroupMessage {
     pub marker: Option<String>,
 }
 
-struct ClusterSubnetGroupMessageDeserializer;
+pub(crate) struct ClusterSubnetGroupMessageDeserializer;
 impl ClusterSubnetGroupMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterSubnetGroupMessage, XmlParseError> {
@@ -1995,10 +2262,10 @@ impl ClusterSubnetGroupMessageDeserializer {
         )
     }
 }
-struct ClusterSubnetGroupsDeserializer;
+pub(crate) struct ClusterSubnetGroupsDeserializer;
 impl ClusterSubnetGroupsDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ClusterSubnetGroup>, XmlParseError> {
@@ -2026,10 +2293,10 @@ pub struct ClusterVersion {
     pub description: Option<String>,
 }
 
-struct ClusterVersionDeserializer;
+pub(crate) struct ClusterVersionDeserializer;
 impl ClusterVersionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterVersion, XmlParseError> {
@@ -2054,10 +2321,10 @@ impl ClusterVersionDeserializer {
         })
     }
 }
-struct ClusterVersionListDeserializer;
+pub(crate) struct ClusterVersionListDeserializer;
 impl ClusterVersionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ClusterVersion>, XmlParseError> {
@@ -2083,10 +2350,10 @@ pub struct ClusterVersionsMessage {
     pub marker: Option<String>,
 }
 
-struct ClusterVersionsMessageDeserializer;
+pub(crate) struct ClusterVersionsMessageDeserializer;
 impl ClusterVersionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClusterVersionsMessage, XmlParseError> {
@@ -2115,10 +2382,10 @@ pub struct ClustersMessage {
     pub marker: Option<String>,
 }
 
-struct ClustersMessageDeserializer;
+pub(crate) struct ClustersMessageDeserializer;
 impl ClustersMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ClustersMessage, XmlParseError> {
@@ -2151,6 +2418,64 @@ pub struct CopyClusterSnapshotMessage {
     pub target_snapshot_identifier: String,
 }
 
+impl CopyClusterSnapshotMessage {
+    /// Returns a builder for `CopyClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> CopyClusterSnapshotMessageBuilder {
+        CopyClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CopyClusterSnapshotMessage`](struct.CopyClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct CopyClusterSnapshotMessageBuilder {
+    manual_snapshot_retention_period: Option<i64>,
+    source_snapshot_cluster_identifier: Option<String>,
+    source_snapshot_identifier: Option<String>,
+    target_snapshot_identifier: Option<String>,
+}
+
+impl CopyClusterSnapshotMessageBuilder {
+    /// Sets the [`manual_snapshot_retention_period`](struct.CopyClusterSnapshotMessage.html#structfield.manual_snapshot_retention_period) member.
+    pub fn manual_snapshot_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.manual_snapshot_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_snapshot_cluster_identifier`](struct.CopyClusterSnapshotMessage.html#structfield.source_snapshot_cluster_identifier) member.
+    pub fn source_snapshot_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_snapshot_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_snapshot_identifier`](struct.CopyClusterSnapshotMessage.html#structfield.source_snapshot_identifier) member.
+    pub fn source_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_snapshot_identifier`](struct.CopyClusterSnapshotMessage.html#structfield.target_snapshot_identifier) member.
+    pub fn target_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `CopyClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CopyClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(CopyClusterSnapshotMessage {
+            manual_snapshot_retention_period: self.manual_snapshot_retention_period,
+            source_snapshot_cluster_identifier: self.source_snapshot_cluster_identifier,
+            source_snapshot_identifier: self.source_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("source_snapshot_identifier"),
+            )?,
+            target_snapshot_identifier: self.target_snapshot_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("target_snapshot_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `CopyClusterSnapshotMessage` contents to a `SignedRequest`.
 struct CopyClusterSnapshotMessageSerializer;
 impl CopyClusterSnapshotMessageSerializer {
@@ -2188,10 +2513,10 @@ pub struct CopyClusterSnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
 
-struct CopyClusterSnapshotResultDeserializer;
+pub(crate) struct CopyClusterSnapshotResultDeserializer;
 impl CopyClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CopyClusterSnapshotResult, XmlParseError> {
@@ -2275,6 +2600,274 @@ pub struct CreateClusterMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl CreateClusterMessage {
+    /// Returns a builder for `CreateClusterMessage`, checking its required members when built.
+    pub fn builder() -> CreateClusterMessageBuilder {
+        CreateClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateClusterMessage`](struct.CreateClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateClusterMessageBuilder {
+    additional_info: Option<String>,
+    allow_version_upgrade: Option<bool>,
+    automated_snapshot_retention_period: Option<i64>,
+    availability_zone: Option<String>,
+    cluster_identifier: Option<String>,
+    cluster_parameter_group_name: Option<String>,
+    cluster_security_groups: Option<Vec<String>>,
+    cluster_subnet_group_name: Option<String>,
+    cluster_type: Option<String>,
+    cluster_version: Option<String>,
+    db_name: Option<String>,
+    elastic_ip: Option<String>,
+    encrypted: Option<bool>,
+    enhanced_vpc_routing: Option<bool>,
+    hsm_client_certificate_identifier: Option<String>,
+    hsm_configuration_identifier: Option<String>,
+    iam_roles: Option<Vec<String>>,
+    kms_key_id: Option<String>,
+    maintenance_track_name: Option<String>,
+    manual_snapshot_retention_period: Option<i64>,
+    master_user_password: Option<String>,
+    master_username: Option<String>,
+    node_type: Option<String>,
+    number_of_nodes: Option<i64>,
+    port: Option<i64>,
+    preferred_maintenance_window: Option<String>,
+    publicly_accessible: Option<bool>,
+    snapshot_schedule_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl CreateClusterMessageBuilder {
+    /// Sets the [`additional_info`](struct.CreateClusterMessage.html#structfield.additional_info) member.
+    pub fn additional_info<T: Into<String>>(mut self, value: T) -> Self {
+        self.additional_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`allow_version_upgrade`](struct.CreateClusterMessage.html#structfield.allow_version_upgrade) member.
+    pub fn allow_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`automated_snapshot_retention_period`](struct.CreateClusterMessage.html#structfield.automated_snapshot_retention_period) member.
+    pub fn automated_snapshot_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.automated_snapshot_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zone`](struct.CreateClusterMessage.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_identifier`](struct.CreateClusterMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_parameter_group_name`](struct.CreateClusterMessage.html#structfield.cluster_parameter_group_name) member.
+    pub fn cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_security_groups`](struct.CreateClusterMessage.html#structfield.cluster_security_groups) member.
+    pub fn cluster_security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cluster_security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_subnet_group_name`](struct.CreateClusterMessage.html#structfield.cluster_subnet_group_name) member.
+    pub fn cluster_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_type`](struct.CreateClusterMessage.html#structfield.cluster_type) member.
+    pub fn cluster_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_version`](struct.CreateClusterMessage.html#structfield.cluster_version) member.
+    pub fn cluster_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_name`](struct.CreateClusterMessage.html#structfield.db_name) member.
+    pub fn db_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`elastic_ip`](struct.CreateClusterMessage.html#structfield.elastic_ip) member.
+    pub fn elastic_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.elastic_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`encrypted`](struct.CreateClusterMessage.html#structfield.encrypted) member.
+    pub fn encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enhanced_vpc_routing`](struct.CreateClusterMessage.html#structfield.enhanced_vpc_routing) member.
+    pub fn enhanced_vpc_routing<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enhanced_vpc_routing = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_client_certificate_identifier`](struct.CreateClusterMessage.html#structfield.hsm_client_certificate_identifier) member.
+    pub fn hsm_client_certificate_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_client_certificate_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_configuration_identifier`](struct.CreateClusterMessage.html#structfield.hsm_configuration_identifier) member.
+    pub fn hsm_configuration_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_configuration_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_roles`](struct.CreateClusterMessage.html#structfield.iam_roles) member.
+    pub fn iam_roles<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.iam_roles = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.CreateClusterMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`maintenance_track_name`](struct.CreateClusterMessage.html#structfield.maintenance_track_name) member.
+    pub fn maintenance_track_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.maintenance_track_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manual_snapshot_retention_period`](struct.CreateClusterMessage.html#structfield.manual_snapshot_retention_period) member.
+    pub fn manual_snapshot_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.manual_snapshot_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.CreateClusterMessage.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_username`](struct.CreateClusterMessage.html#structfield.master_username) member.
+    pub fn master_username<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_username = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_type`](struct.CreateClusterMessage.html#structfield.node_type) member.
+    pub fn node_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.node_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_nodes`](struct.CreateClusterMessage.html#structfield.number_of_nodes) member.
+    pub fn number_of_nodes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_nodes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.CreateClusterMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.CreateClusterMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.CreateClusterMessage.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_schedule_identifier`](struct.CreateClusterMessage.html#structfield.snapshot_schedule_identifier) member.
+    pub fn snapshot_schedule_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_schedule_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateClusterMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.CreateClusterMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateClusterMessage, ::rusoto_core::BuildError> {
+        Ok(CreateClusterMessage {
+            additional_info: self.additional_info,
+            allow_version_upgrade: self.allow_version_upgrade,
+            automated_snapshot_retention_period: self.automated_snapshot_retention_period,
+            availability_zone: self.availability_zone,
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            cluster_parameter_group_name: self.cluster_parameter_group_name,
+            cluster_security_groups: self.cluster_security_groups,
+            cluster_subnet_group_name: self.cluster_subnet_group_name,
+            cluster_type: self.cluster_type,
+            cluster_version: self.cluster_version,
+            db_name: self.db_name,
+            elastic_ip: self.elastic_ip,
+            encrypted: self.encrypted,
+            enhanced_vpc_routing: self.enhanced_vpc_routing,
+            hsm_client_certificate_identifier: self.hsm_client_certificate_identifier,
+            hsm_configuration_identifier: self.hsm_configuration_identifier,
+            iam_roles: self.iam_roles,
+            kms_key_id: self.kms_key_id,
+            maintenance_track_name: self.maintenance_track_name,
+            manual_snapshot_retention_period: self.manual_snapshot_retention_period,
+            master_user_password: self
+                .master_user_password
+                .ok_or(::rusoto_core::BuildError::missing("master_user_password"))?,
+            master_username: self
+                .master_username
+                .ok_or(::rusoto_core::BuildError::missing("master_username"))?,
+            node_type: self
+                .node_type
+                .ok_or(::rusoto_core::BuildError::missing("node_type"))?,
+            number_of_nodes: self.number_of_nodes,
+            port: self.port,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            publicly_accessible: self.publicly_accessible,
+            snapshot_schedule_identifier: self.snapshot_schedule_identifier,
+            tags: self.tags,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `CreateClusterMessage` contents to a `SignedRequest`.
 struct CreateClusterMessageSerializer;
 impl CreateClusterMessageSerializer {
@@ -2433,6 +3026,66 @@ pub struct CreateClusterParameterGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateClusterParameterGroupMessage {
+    /// Returns a builder for `CreateClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateClusterParameterGroupMessageBuilder {
+        CreateClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateClusterParameterGroupMessage`](struct.CreateClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateClusterParameterGroupMessageBuilder {
+    description: Option<String>,
+    parameter_group_family: Option<String>,
+    parameter_group_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateClusterParameterGroupMessageBuilder {
+    /// Sets the [`description`](struct.CreateClusterParameterGroupMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_group_family`](struct.CreateClusterParameterGroupMessage.html#structfield.parameter_group_family) member.
+    pub fn parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_group_name`](struct.CreateClusterParameterGroupMessage.html#structfield.parameter_group_name) member.
+    pub fn parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateClusterParameterGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateClusterParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateClusterParameterGroupMessage {
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            parameter_group_family: self
+                .parameter_group_family
+                .ok_or(::rusoto_core::BuildError::missing("parameter_group_family"))?,
+            parameter_group_name: self
+                .parameter_group_name
+                .ok_or(::rusoto_core::BuildError::missing("parameter_group_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct CreateClusterParameterGroupMessageSerializer;
 impl CreateClusterParameterGroupMessageSerializer {
@@ -2462,10 +3115,10 @@ pub struct CreateClusterParameterGroupResult {
     pub cluster_parameter_group: Option<ClusterParameterGroup>,
 }
 
-struct CreateClusterParameterGroupResultDeserializer;
+pub(crate) struct CreateClusterParameterGroupResultDeserializer;
 impl CreateClusterParameterGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateClusterParameterGroupResult, XmlParseError> {
@@ -2493,10 +3146,10 @@ pub struct CreateClusterResult {
     pub cluster: Option<Cluster>,
 }
 
-struct CreateClusterResultDeserializer;
+pub(crate) struct CreateClusterResultDeserializer;
 impl CreateClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateClusterResult, XmlParseError> {
@@ -2522,6 +3175,56 @@ pub struct CreateClusterSecurityGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateClusterSecurityGroupMessage {
+    /// Returns a builder for `CreateClusterSecurityGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateClusterSecurityGroupMessageBuilder {
+        CreateClusterSecurityGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateClusterSecurityGroupMessage`](struct.CreateClusterSecurityGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateClusterSecurityGroupMessageBuilder {
+    cluster_security_group_name: Option<String>,
+    description: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateClusterSecurityGroupMessageBuilder {
+    /// Sets the [`cluster_security_group_name`](struct.CreateClusterSecurityGroupMessage.html#structfield.cluster_security_group_name) member.
+    pub fn cluster_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateClusterSecurityGroupMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateClusterSecurityGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateClusterSecurityGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateClusterSecurityGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateClusterSecurityGroupMessage {
+            cluster_security_group_name: self.cluster_security_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cluster_security_group_name"),
+            )?,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateClusterSecurityGroupMessage` contents to a `SignedRequest`.
 struct CreateClusterSecurityGroupMessageSerializer;
 impl CreateClusterSecurityGroupMessageSerializer {
@@ -2547,10 +3250,10 @@ pub struct CreateClusterSecurityGroupResult {
     pub cluster_security_group: Option<ClusterSecurityGroup>,
 }
 
-struct CreateClusterSecurityGroupResultDeserializer;
+pub(crate) struct CreateClusterSecurityGroupResultDeserializer;
 impl CreateClusterSecurityGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateClusterSecurityGroupResult, XmlParseError> {
@@ -2586,6 +3289,64 @@ pub struct CreateClusterSnapshotMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateClusterSnapshotMessage {
+    /// Returns a builder for `CreateClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> CreateClusterSnapshotMessageBuilder {
+        CreateClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateClusterSnapshotMessage`](struct.CreateClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateClusterSnapshotMessageBuilder {
+    cluster_identifier: Option<String>,
+    manual_snapshot_retention_period: Option<i64>,
+    snapshot_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateClusterSnapshotMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.CreateClusterSnapshotMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manual_snapshot_retention_period`](struct.CreateClusterSnapshotMessage.html#structfield.manual_snapshot_retention_period) member.
+    pub fn manual_snapshot_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.manual_snapshot_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_identifier`](struct.CreateClusterSnapshotMessage.html#structfield.snapshot_identifier) member.
+    pub fn snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateClusterSnapshotMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(CreateClusterSnapshotMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            manual_snapshot_retention_period: self.manual_snapshot_retention_period,
+            snapshot_identifier: self
+                .snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_identifier"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateClusterSnapshotMessage` contents to a `SignedRequest`.
 struct CreateClusterSnapshotMessageSerializer;
 impl CreateClusterSnapshotMessageSerializer {
@@ -2620,10 +3381,10 @@ pub struct CreateClusterSnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
 
-struct CreateClusterSnapshotResultDeserializer;
+pub(crate) struct CreateClusterSnapshotResultDeserializer;
 impl CreateClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateClusterSnapshotResult, XmlParseError> {
@@ -2655,6 +3416,66 @@ pub struct CreateClusterSubnetGroupMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateClusterSubnetGroupMessage {
+    /// Returns a builder for `CreateClusterSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> CreateClusterSubnetGroupMessageBuilder {
+        CreateClusterSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateClusterSubnetGroupMessage`](struct.CreateClusterSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateClusterSubnetGroupMessageBuilder {
+    cluster_subnet_group_name: Option<String>,
+    description: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateClusterSubnetGroupMessageBuilder {
+    /// Sets the [`cluster_subnet_group_name`](struct.CreateClusterSubnetGroupMessage.html#structfield.cluster_subnet_group_name) member.
+    pub fn cluster_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.CreateClusterSubnetGroupMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.CreateClusterSubnetGroupMessage.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateClusterSubnetGroupMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateClusterSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateClusterSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(CreateClusterSubnetGroupMessage {
+            cluster_subnet_group_name: self.cluster_subnet_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cluster_subnet_group_name"),
+            )?,
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateClusterSubnetGroupMessage` contents to a `SignedRequest`.
 struct CreateClusterSubnetGroupMessageSerializer;
 impl CreateClusterSubnetGroupMessageSerializer {
@@ -2685,10 +3506,10 @@ pub struct CreateClusterSubnetGroupResult {
     pub cluster_subnet_group: Option<ClusterSubnetGroup>,
 }
 
-struct CreateClusterSubnetGroupResultDeserializer;
+pub(crate) struct CreateClusterSubnetGroupResultDeserializer;
 impl CreateClusterSubnetGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateClusterSubnetGroupResult, XmlParseError> {
@@ -2732,25 +3553,115 @@ pub struct CreateEventSubscriptionMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
-/// Serialize `CreateEventSubscriptionMessage` contents to a `SignedRequest`.
-struct CreateEventSubscriptionMessageSerializer;
-impl CreateEventSubscriptionMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &CreateEventSubscriptionMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl CreateEventSubscriptionMessage {
+    /// Returns a builder for `CreateEventSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> CreateEventSubscriptionMessageBuilder {
+        CreateEventSubscriptionMessageBuilder::default()
+    }
+}
 
-        if let Some(ref field_value) = obj.enabled {
-            params.put(&format!("{}{}", prefix, "Enabled"), &field_value);
-        }
-        if let Some(ref field_value) = obj.event_categories {
-            EventCategoriesListSerializer::serialize(
-                params,
-                &format!("{}{}", prefix, "EventCategory"),
-                field_value,
-            );
-        }
+/// Builds a [`CreateEventSubscriptionMessage`](struct.CreateEventSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateEventSubscriptionMessageBuilder {
+    enabled: Option<bool>,
+    event_categories: Option<Vec<String>>,
+    severity: Option<String>,
+    sns_topic_arn: Option<String>,
+    source_ids: Option<Vec<String>>,
+    source_type: Option<String>,
+    subscription_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateEventSubscriptionMessageBuilder {
+    /// Sets the [`enabled`](struct.CreateEventSubscriptionMessage.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_categories`](struct.CreateEventSubscriptionMessage.html#structfield.event_categories) member.
+    pub fn event_categories<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_categories = Some(value.into());
+        self
+    }
+
+    /// Sets the [`severity`](struct.CreateEventSubscriptionMessage.html#structfield.severity) member.
+    pub fn severity<T: Into<String>>(mut self, value: T) -> Self {
+        self.severity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.CreateEventSubscriptionMessage.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_ids`](struct.CreateEventSubscriptionMessage.html#structfield.source_ids) member.
+    pub fn source_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.source_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.CreateEventSubscriptionMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.CreateEventSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateEventSubscriptionMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateEventSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateEventSubscriptionMessage, ::rusoto_core::BuildError> {
+        Ok(CreateEventSubscriptionMessage {
+            enabled: self.enabled,
+            event_categories: self.event_categories,
+            severity: self.severity,
+            sns_topic_arn: self
+                .sns_topic_arn
+                .ok_or(::rusoto_core::BuildError::missing("sns_topic_arn"))?,
+            source_ids: self.source_ids,
+            source_type: self.source_type,
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+            tags: self.tags,
+        })
+    }
+}
+
+/// Serialize `CreateEventSubscriptionMessage` contents to a `SignedRequest`.
+struct CreateEventSubscriptionMessageSerializer;
+impl CreateEventSubscriptionMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &CreateEventSubscriptionMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
+
+        if let Some(ref field_value) = obj.enabled {
+            params.put(&format!("{}{}", prefix, "Enabled"), &field_value);
+        }
+        if let Some(ref field_value) = obj.event_categories {
+            EventCategoriesListSerializer::serialize(
+                params,
+                &format!("{}{}", prefix, "EventCategory"),
+                field_value,
+            );
+        }
         if let Some(ref field_value) = obj.severity {
             params.put(&format!("{}{}", prefix, "Severity"), &field_value);
         }
@@ -2780,10 +3691,10 @@ pub struct CreateEventSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
 
-struct CreateEventSubscriptionResultDeserializer;
+pub(crate) struct CreateEventSubscriptionResultDeserializer;
 impl CreateEventSubscriptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateEventSubscriptionResult, XmlParseError> {
@@ -2814,6 +3725,46 @@ pub struct CreateHsmClientCertificateMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateHsmClientCertificateMessage {
+    /// Returns a builder for `CreateHsmClientCertificateMessage`, checking its required members when built.
+    pub fn builder() -> CreateHsmClientCertificateMessageBuilder {
+        CreateHsmClientCertificateMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateHsmClientCertificateMessage`](struct.CreateHsmClientCertificateMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateHsmClientCertificateMessageBuilder {
+    hsm_client_certificate_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateHsmClientCertificateMessageBuilder {
+    /// Sets the [`hsm_client_certificate_identifier`](struct.CreateHsmClientCertificateMessage.html#structfield.hsm_client_certificate_identifier) member.
+    pub fn hsm_client_certificate_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_client_certificate_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateHsmClientCertificateMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateHsmClientCertificateMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateHsmClientCertificateMessage, ::rusoto_core::BuildError> {
+        Ok(CreateHsmClientCertificateMessage {
+            hsm_client_certificate_identifier: self.hsm_client_certificate_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("hsm_client_certificate_identifier"),
+            )?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateHsmClientCertificateMessage` contents to a `SignedRequest`.
 struct CreateHsmClientCertificateMessageSerializer;
 impl CreateHsmClientCertificateMessageSerializer {
@@ -2838,10 +3789,10 @@ pub struct CreateHsmClientCertificateResult {
     pub hsm_client_certificate: Option<HsmClientCertificate>,
 }
 
-struct CreateHsmClientCertificateResultDeserializer;
+pub(crate) struct CreateHsmClientCertificateResultDeserializer;
 impl CreateHsmClientCertificateResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateHsmClientCertificateResult, XmlParseError> {
@@ -2883,6 +3834,96 @@ pub struct CreateHsmConfigurationMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateHsmConfigurationMessage {
+    /// Returns a builder for `CreateHsmConfigurationMessage`, checking its required members when built.
+    pub fn builder() -> CreateHsmConfigurationMessageBuilder {
+        CreateHsmConfigurationMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateHsmConfigurationMessage`](struct.CreateHsmConfigurationMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateHsmConfigurationMessageBuilder {
+    description: Option<String>,
+    hsm_configuration_identifier: Option<String>,
+    hsm_ip_address: Option<String>,
+    hsm_partition_name: Option<String>,
+    hsm_partition_password: Option<String>,
+    hsm_server_public_certificate: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateHsmConfigurationMessageBuilder {
+    /// Sets the [`description`](struct.CreateHsmConfigurationMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_configuration_identifier`](struct.CreateHsmConfigurationMessage.html#structfield.hsm_configuration_identifier) member.
+    pub fn hsm_configuration_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_configuration_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_ip_address`](struct.CreateHsmConfigurationMessage.html#structfield.hsm_ip_address) member.
+    pub fn hsm_ip_address<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_ip_address = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_partition_name`](struct.CreateHsmConfigurationMessage.html#structfield.hsm_partition_name) member.
+    pub fn hsm_partition_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_partition_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_partition_password`](struct.CreateHsmConfigurationMessage.html#structfield.hsm_partition_password) member.
+    pub fn hsm_partition_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_partition_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_server_public_certificate`](struct.CreateHsmConfigurationMessage.html#structfield.hsm_server_public_certificate) member.
+    pub fn hsm_server_public_certificate<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_server_public_certificate = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateHsmConfigurationMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateHsmConfigurationMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateHsmConfigurationMessage, ::rusoto_core::BuildError> {
+        Ok(CreateHsmConfigurationMessage {
+            description: self
+                .description
+                .ok_or(::rusoto_core::BuildError::missing("description"))?,
+            hsm_configuration_identifier: self.hsm_configuration_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("hsm_configuration_identifier"),
+            )?,
+            hsm_ip_address: self
+                .hsm_ip_address
+                .ok_or(::rusoto_core::BuildError::missing("hsm_ip_address"))?,
+            hsm_partition_name: self
+                .hsm_partition_name
+                .ok_or(::rusoto_core::BuildError::missing("hsm_partition_name"))?,
+            hsm_partition_password: self
+                .hsm_partition_password
+                .ok_or(::rusoto_core::BuildError::missing("hsm_partition_password"))?,
+            hsm_server_public_certificate: self.hsm_server_public_certificate.ok_or(
+                ::rusoto_core::BuildError::missing("hsm_server_public_certificate"),
+            )?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateHsmConfigurationMessage` contents to a `SignedRequest`.
 struct CreateHsmConfigurationMessageSerializer;
 impl CreateHsmConfigurationMessageSerializer {
@@ -2924,10 +3965,10 @@ pub struct CreateHsmConfigurationResult {
     pub hsm_configuration: Option<HsmConfiguration>,
 }
 
-struct CreateHsmConfigurationResultDeserializer;
+pub(crate) struct CreateHsmConfigurationResultDeserializer;
 impl CreateHsmConfigurationResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateHsmConfigurationResult, XmlParseError> {
@@ -2960,6 +4001,54 @@ pub struct CreateSnapshotCopyGrantMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateSnapshotCopyGrantMessage {
+    /// Returns a builder for `CreateSnapshotCopyGrantMessage`, checking its required members when built.
+    pub fn builder() -> CreateSnapshotCopyGrantMessageBuilder {
+        CreateSnapshotCopyGrantMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSnapshotCopyGrantMessage`](struct.CreateSnapshotCopyGrantMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateSnapshotCopyGrantMessageBuilder {
+    kms_key_id: Option<String>,
+    snapshot_copy_grant_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateSnapshotCopyGrantMessageBuilder {
+    /// Sets the [`kms_key_id`](struct.CreateSnapshotCopyGrantMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_copy_grant_name`](struct.CreateSnapshotCopyGrantMessage.html#structfield.snapshot_copy_grant_name) member.
+    pub fn snapshot_copy_grant_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_copy_grant_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateSnapshotCopyGrantMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSnapshotCopyGrantMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSnapshotCopyGrantMessage, ::rusoto_core::BuildError> {
+        Ok(CreateSnapshotCopyGrantMessage {
+            kms_key_id: self.kms_key_id,
+            snapshot_copy_grant_name: self.snapshot_copy_grant_name.ok_or(
+                ::rusoto_core::BuildError::missing("snapshot_copy_grant_name"),
+            )?,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateSnapshotCopyGrantMessage` contents to a `SignedRequest`.
 struct CreateSnapshotCopyGrantMessageSerializer;
 impl CreateSnapshotCopyGrantMessageSerializer {
@@ -2987,10 +4076,10 @@ pub struct CreateSnapshotCopyGrantResult {
     pub snapshot_copy_grant: Option<SnapshotCopyGrant>,
 }
 
-struct CreateSnapshotCopyGrantResultDeserializer;
+pub(crate) struct CreateSnapshotCopyGrantResultDeserializer;
 impl CreateSnapshotCopyGrantResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CreateSnapshotCopyGrantResult, XmlParseError> {
@@ -3028,6 +4117,76 @@ pub struct CreateSnapshotScheduleMessage {
     pub tags: Option<Vec<Tag>>,
 }
 
+impl CreateSnapshotScheduleMessage {
+    /// Returns a builder for `CreateSnapshotScheduleMessage`, checking its required members when built.
+    pub fn builder() -> CreateSnapshotScheduleMessageBuilder {
+        CreateSnapshotScheduleMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateSnapshotScheduleMessage`](struct.CreateSnapshotScheduleMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateSnapshotScheduleMessageBuilder {
+    dry_run: Option<bool>,
+    next_invocations: Option<i64>,
+    schedule_definitions: Option<Vec<String>>,
+    schedule_description: Option<String>,
+    schedule_identifier: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateSnapshotScheduleMessageBuilder {
+    /// Sets the [`dry_run`](struct.CreateSnapshotScheduleMessage.html#structfield.dry_run) member.
+    pub fn dry_run<T: Into<bool>>(mut self, value: T) -> Self {
+        self.dry_run = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_invocations`](struct.CreateSnapshotScheduleMessage.html#structfield.next_invocations) member.
+    pub fn next_invocations<T: Into<i64>>(mut self, value: T) -> Self {
+        self.next_invocations = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule_definitions`](struct.CreateSnapshotScheduleMessage.html#structfield.schedule_definitions) member.
+    pub fn schedule_definitions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.schedule_definitions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule_description`](struct.CreateSnapshotScheduleMessage.html#structfield.schedule_description) member.
+    pub fn schedule_description<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule_description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule_identifier`](struct.CreateSnapshotScheduleMessage.html#structfield.schedule_identifier) member.
+    pub fn schedule_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateSnapshotScheduleMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateSnapshotScheduleMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateSnapshotScheduleMessage, ::rusoto_core::BuildError> {
+        Ok(CreateSnapshotScheduleMessage {
+            dry_run: self.dry_run,
+            next_invocations: self.next_invocations,
+            schedule_definitions: self.schedule_definitions,
+            schedule_description: self.schedule_description,
+            schedule_identifier: self.schedule_identifier,
+            tags: self.tags,
+        })
+    }
+}
+
 /// Serialize `CreateSnapshotScheduleMessage` contents to a `SignedRequest`.
 struct CreateSnapshotScheduleMessageSerializer;
 impl CreateSnapshotScheduleMessageSerializer {
@@ -3074,6 +4233,46 @@ pub struct CreateTagsMessage {
     pub tags: Vec<Tag>,
 }
 
+impl CreateTagsMessage {
+    /// Returns a builder for `CreateTagsMessage`, checking its required members when built.
+    pub fn builder() -> CreateTagsMessageBuilder {
+        CreateTagsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`CreateTagsMessage`](struct.CreateTagsMessage.html).
+#[derive(Default, Debug)]
+pub struct CreateTagsMessageBuilder {
+    resource_name: Option<String>,
+    tags: Option<Vec<Tag>>,
+}
+
+impl CreateTagsMessageBuilder {
+    /// Sets the [`resource_name`](struct.CreateTagsMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateTagsMessage.html#structfield.tags) member.
+    pub fn tags<T: Into<Vec<Tag>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateTagsMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateTagsMessage, ::rusoto_core::BuildError> {
+        Ok(CreateTagsMessage {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 /// Serialize `CreateTagsMessage` contents to a `SignedRequest`.
 struct CreateTagsMessageSerializer;
 impl CreateTagsMessageSerializer {
@@ -3096,10 +4295,10 @@ pub struct CustomerStorageMessage {
     pub total_provisioned_storage_in_mega_bytes: Option<f64>,
 }
 
-struct CustomerStorageMessageDeserializer;
+pub(crate) struct CustomerStorageMessageDeserializer;
 impl CustomerStorageMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<CustomerStorageMessage, XmlParseError> {
@@ -3141,10 +4340,10 @@ pub struct DataTransferProgress {
     pub total_data_in_mega_bytes: Option<i64>,
 }
 
-struct DataTransferProgressDeserializer;
+pub(crate) struct DataTransferProgressDeserializer;
 impl DataTransferProgressDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DataTransferProgress, XmlParseError> {
@@ -3214,10 +4413,10 @@ pub struct DefaultClusterParameters {
     pub parameters: Option<Vec<Parameter>>,
 }
 
-struct DefaultClusterParametersDeserializer;
+pub(crate) struct DefaultClusterParametersDeserializer;
 impl DefaultClusterParametersDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DefaultClusterParameters, XmlParseError> {
@@ -3251,17 +4450,17 @@ impl DefaultClusterParametersDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct DeferredMaintenanceWindow {
     /// <p> A timestamp for the end of the time period when we defer maintenance.</p>
-    pub defer_maintenance_end_time: Option<String>,
+    pub defer_maintenance_end_time: Option<IsoTimestamp>,
     /// <p>A unique identifier for the maintenance window.</p>
     pub defer_maintenance_identifier: Option<String>,
     /// <p> A timestamp for the beginning of the time period when we defer maintenance.</p>
-    pub defer_maintenance_start_time: Option<String>,
+    pub defer_maintenance_start_time: Option<IsoTimestamp>,
 }
 
-struct DeferredMaintenanceWindowDeserializer;
+pub(crate) struct DeferredMaintenanceWindowDeserializer;
 impl DeferredMaintenanceWindowDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeferredMaintenanceWindow, XmlParseError> {
@@ -3295,10 +4494,10 @@ impl DeferredMaintenanceWindowDeserializer {
         )
     }
 }
-struct DeferredMaintenanceWindowsListDeserializer;
+pub(crate) struct DeferredMaintenanceWindowsListDeserializer;
 impl DeferredMaintenanceWindowsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<DeferredMaintenanceWindow>, XmlParseError> {
@@ -3328,6 +4527,60 @@ pub struct DeleteClusterMessage {
     pub skip_final_cluster_snapshot: Option<bool>,
 }
 
+impl DeleteClusterMessage {
+    /// Returns a builder for `DeleteClusterMessage`, checking its required members when built.
+    pub fn builder() -> DeleteClusterMessageBuilder {
+        DeleteClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteClusterMessage`](struct.DeleteClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteClusterMessageBuilder {
+    cluster_identifier: Option<String>,
+    final_cluster_snapshot_identifier: Option<String>,
+    final_cluster_snapshot_retention_period: Option<i64>,
+    skip_final_cluster_snapshot: Option<bool>,
+}
+
+impl DeleteClusterMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.DeleteClusterMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`final_cluster_snapshot_identifier`](struct.DeleteClusterMessage.html#structfield.final_cluster_snapshot_identifier) member.
+    pub fn final_cluster_snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.final_cluster_snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`final_cluster_snapshot_retention_period`](struct.DeleteClusterMessage.html#structfield.final_cluster_snapshot_retention_period) member.
+    pub fn final_cluster_snapshot_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.final_cluster_snapshot_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`skip_final_cluster_snapshot`](struct.DeleteClusterMessage.html#structfield.skip_final_cluster_snapshot) member.
+    pub fn skip_final_cluster_snapshot<T: Into<bool>>(mut self, value: T) -> Self {
+        self.skip_final_cluster_snapshot = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteClusterMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteClusterMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            final_cluster_snapshot_identifier: self.final_cluster_snapshot_identifier,
+            final_cluster_snapshot_retention_period: self.final_cluster_snapshot_retention_period,
+            skip_final_cluster_snapshot: self.skip_final_cluster_snapshot,
+        })
+    }
+}
+
 /// Serialize `DeleteClusterMessage` contents to a `SignedRequest`.
 struct DeleteClusterMessageSerializer;
 impl DeleteClusterMessageSerializer {
@@ -3369,6 +4622,38 @@ pub struct DeleteClusterParameterGroupMessage {
     pub parameter_group_name: String,
 }
 
+impl DeleteClusterParameterGroupMessage {
+    /// Returns a builder for `DeleteClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteClusterParameterGroupMessageBuilder {
+        DeleteClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteClusterParameterGroupMessage`](struct.DeleteClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteClusterParameterGroupMessageBuilder {
+    parameter_group_name: Option<String>,
+}
+
+impl DeleteClusterParameterGroupMessageBuilder {
+    /// Sets the [`parameter_group_name`](struct.DeleteClusterParameterGroupMessage.html#structfield.parameter_group_name) member.
+    pub fn parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteClusterParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteClusterParameterGroupMessage {
+            parameter_group_name: self
+                .parameter_group_name
+                .ok_or(::rusoto_core::BuildError::missing("parameter_group_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct DeleteClusterParameterGroupMessageSerializer;
 impl DeleteClusterParameterGroupMessageSerializer {
@@ -3390,10 +4675,10 @@ pub struct DeleteClusterResult {
     pub cluster: Option<Cluster>,
 }
 
-struct DeleteClusterResultDeserializer;
+pub(crate) struct DeleteClusterResultDeserializer;
 impl DeleteClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteClusterResult, XmlParseError> {
@@ -3415,6 +4700,38 @@ pub struct DeleteClusterSecurityGroupMessage {
     pub cluster_security_group_name: String,
 }
 
+impl DeleteClusterSecurityGroupMessage {
+    /// Returns a builder for `DeleteClusterSecurityGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteClusterSecurityGroupMessageBuilder {
+        DeleteClusterSecurityGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteClusterSecurityGroupMessage`](struct.DeleteClusterSecurityGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteClusterSecurityGroupMessageBuilder {
+    cluster_security_group_name: Option<String>,
+}
+
+impl DeleteClusterSecurityGroupMessageBuilder {
+    /// Sets the [`cluster_security_group_name`](struct.DeleteClusterSecurityGroupMessage.html#structfield.cluster_security_group_name) member.
+    pub fn cluster_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteClusterSecurityGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteClusterSecurityGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteClusterSecurityGroupMessage {
+            cluster_security_group_name: self.cluster_security_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cluster_security_group_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteClusterSecurityGroupMessage` contents to a `SignedRequest`.
 struct DeleteClusterSecurityGroupMessageSerializer;
 impl DeleteClusterSecurityGroupMessageSerializer {
@@ -3440,6 +4757,46 @@ pub struct DeleteClusterSnapshotMessage {
     pub snapshot_identifier: String,
 }
 
+impl DeleteClusterSnapshotMessage {
+    /// Returns a builder for `DeleteClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> DeleteClusterSnapshotMessageBuilder {
+        DeleteClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteClusterSnapshotMessage`](struct.DeleteClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteClusterSnapshotMessageBuilder {
+    snapshot_cluster_identifier: Option<String>,
+    snapshot_identifier: Option<String>,
+}
+
+impl DeleteClusterSnapshotMessageBuilder {
+    /// Sets the [`snapshot_cluster_identifier`](struct.DeleteClusterSnapshotMessage.html#structfield.snapshot_cluster_identifier) member.
+    pub fn snapshot_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_identifier`](struct.DeleteClusterSnapshotMessage.html#structfield.snapshot_identifier) member.
+    pub fn snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteClusterSnapshotMessage {
+            snapshot_cluster_identifier: self.snapshot_cluster_identifier,
+            snapshot_identifier: self
+                .snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteClusterSnapshotMessage` contents to a `SignedRequest`.
 struct DeleteClusterSnapshotMessageSerializer;
 impl DeleteClusterSnapshotMessageSerializer {
@@ -3478,10 +4835,10 @@ pub struct DeleteClusterSnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
 
-struct DeleteClusterSnapshotResultDeserializer;
+pub(crate) struct DeleteClusterSnapshotResultDeserializer;
 impl DeleteClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DeleteClusterSnapshotResult, XmlParseError> {
@@ -3507,6 +4864,38 @@ pub struct DeleteClusterSubnetGroupMessage {
     pub cluster_subnet_group_name: String,
 }
 
+impl DeleteClusterSubnetGroupMessage {
+    /// Returns a builder for `DeleteClusterSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> DeleteClusterSubnetGroupMessageBuilder {
+        DeleteClusterSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteClusterSubnetGroupMessage`](struct.DeleteClusterSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteClusterSubnetGroupMessageBuilder {
+    cluster_subnet_group_name: Option<String>,
+}
+
+impl DeleteClusterSubnetGroupMessageBuilder {
+    /// Sets the [`cluster_subnet_group_name`](struct.DeleteClusterSubnetGroupMessage.html#structfield.cluster_subnet_group_name) member.
+    pub fn cluster_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteClusterSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteClusterSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteClusterSubnetGroupMessage {
+            cluster_subnet_group_name: self.cluster_subnet_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cluster_subnet_group_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteClusterSubnetGroupMessage` contents to a `SignedRequest`.
 struct DeleteClusterSubnetGroupMessageSerializer;
 impl DeleteClusterSubnetGroupMessageSerializer {
@@ -3530,6 +4919,38 @@ pub struct DeleteEventSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl DeleteEventSubscriptionMessage {
+    /// Returns a builder for `DeleteEventSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> DeleteEventSubscriptionMessageBuilder {
+        DeleteEventSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteEventSubscriptionMessage`](struct.DeleteEventSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteEventSubscriptionMessageBuilder {
+    subscription_name: Option<String>,
+}
+
+impl DeleteEventSubscriptionMessageBuilder {
+    /// Sets the [`subscription_name`](struct.DeleteEventSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteEventSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteEventSubscriptionMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteEventSubscriptionMessage {
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteEventSubscriptionMessage` contents to a `SignedRequest`.
 struct DeleteEventSubscriptionMessageSerializer;
 impl DeleteEventSubscriptionMessageSerializer {
@@ -3553,6 +4974,38 @@ pub struct DeleteHsmClientCertificateMessage {
     pub hsm_client_certificate_identifier: String,
 }
 
+impl DeleteHsmClientCertificateMessage {
+    /// Returns a builder for `DeleteHsmClientCertificateMessage`, checking its required members when built.
+    pub fn builder() -> DeleteHsmClientCertificateMessageBuilder {
+        DeleteHsmClientCertificateMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteHsmClientCertificateMessage`](struct.DeleteHsmClientCertificateMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteHsmClientCertificateMessageBuilder {
+    hsm_client_certificate_identifier: Option<String>,
+}
+
+impl DeleteHsmClientCertificateMessageBuilder {
+    /// Sets the [`hsm_client_certificate_identifier`](struct.DeleteHsmClientCertificateMessage.html#structfield.hsm_client_certificate_identifier) member.
+    pub fn hsm_client_certificate_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_client_certificate_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteHsmClientCertificateMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteHsmClientCertificateMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteHsmClientCertificateMessage {
+            hsm_client_certificate_identifier: self.hsm_client_certificate_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("hsm_client_certificate_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteHsmClientCertificateMessage` contents to a `SignedRequest`.
 struct DeleteHsmClientCertificateMessageSerializer;
 impl DeleteHsmClientCertificateMessageSerializer {
@@ -3576,6 +5029,38 @@ pub struct DeleteHsmConfigurationMessage {
     pub hsm_configuration_identifier: String,
 }
 
+impl DeleteHsmConfigurationMessage {
+    /// Returns a builder for `DeleteHsmConfigurationMessage`, checking its required members when built.
+    pub fn builder() -> DeleteHsmConfigurationMessageBuilder {
+        DeleteHsmConfigurationMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteHsmConfigurationMessage`](struct.DeleteHsmConfigurationMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteHsmConfigurationMessageBuilder {
+    hsm_configuration_identifier: Option<String>,
+}
+
+impl DeleteHsmConfigurationMessageBuilder {
+    /// Sets the [`hsm_configuration_identifier`](struct.DeleteHsmConfigurationMessage.html#structfield.hsm_configuration_identifier) member.
+    pub fn hsm_configuration_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_configuration_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteHsmConfigurationMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteHsmConfigurationMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteHsmConfigurationMessage {
+            hsm_configuration_identifier: self.hsm_configuration_identifier.ok_or(
+                ::rusoto_core::BuildError::missing("hsm_configuration_identifier"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteHsmConfigurationMessage` contents to a `SignedRequest`.
 struct DeleteHsmConfigurationMessageSerializer;
 impl DeleteHsmConfigurationMessageSerializer {
@@ -3599,6 +5084,38 @@ pub struct DeleteSnapshotCopyGrantMessage {
     pub snapshot_copy_grant_name: String,
 }
 
+impl DeleteSnapshotCopyGrantMessage {
+    /// Returns a builder for `DeleteSnapshotCopyGrantMessage`, checking its required members when built.
+    pub fn builder() -> DeleteSnapshotCopyGrantMessageBuilder {
+        DeleteSnapshotCopyGrantMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSnapshotCopyGrantMessage`](struct.DeleteSnapshotCopyGrantMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteSnapshotCopyGrantMessageBuilder {
+    snapshot_copy_grant_name: Option<String>,
+}
+
+impl DeleteSnapshotCopyGrantMessageBuilder {
+    /// Sets the [`snapshot_copy_grant_name`](struct.DeleteSnapshotCopyGrantMessage.html#structfield.snapshot_copy_grant_name) member.
+    pub fn snapshot_copy_grant_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_copy_grant_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSnapshotCopyGrantMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSnapshotCopyGrantMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteSnapshotCopyGrantMessage {
+            snapshot_copy_grant_name: self.snapshot_copy_grant_name.ok_or(
+                ::rusoto_core::BuildError::missing("snapshot_copy_grant_name"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `DeleteSnapshotCopyGrantMessage` contents to a `SignedRequest`.
 struct DeleteSnapshotCopyGrantMessageSerializer;
 impl DeleteSnapshotCopyGrantMessageSerializer {
@@ -3621,6 +5138,38 @@ pub struct DeleteSnapshotScheduleMessage {
     pub schedule_identifier: String,
 }
 
+impl DeleteSnapshotScheduleMessage {
+    /// Returns a builder for `DeleteSnapshotScheduleMessage`, checking its required members when built.
+    pub fn builder() -> DeleteSnapshotScheduleMessageBuilder {
+        DeleteSnapshotScheduleMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteSnapshotScheduleMessage`](struct.DeleteSnapshotScheduleMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteSnapshotScheduleMessageBuilder {
+    schedule_identifier: Option<String>,
+}
+
+impl DeleteSnapshotScheduleMessageBuilder {
+    /// Sets the [`schedule_identifier`](struct.DeleteSnapshotScheduleMessage.html#structfield.schedule_identifier) member.
+    pub fn schedule_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteSnapshotScheduleMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteSnapshotScheduleMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteSnapshotScheduleMessage {
+            schedule_identifier: self
+                .schedule_identifier
+                .ok_or(::rusoto_core::BuildError::missing("schedule_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteSnapshotScheduleMessage` contents to a `SignedRequest`.
 struct DeleteSnapshotScheduleMessageSerializer;
 impl DeleteSnapshotScheduleMessageSerializer {
@@ -3646,6 +5195,46 @@ pub struct DeleteTagsMessage {
     pub tag_keys: Vec<String>,
 }
 
+impl DeleteTagsMessage {
+    /// Returns a builder for `DeleteTagsMessage`, checking its required members when built.
+    pub fn builder() -> DeleteTagsMessageBuilder {
+        DeleteTagsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteTagsMessage`](struct.DeleteTagsMessage.html).
+#[derive(Default, Debug)]
+pub struct DeleteTagsMessageBuilder {
+    resource_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl DeleteTagsMessageBuilder {
+    /// Sets the [`resource_name`](struct.DeleteTagsMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DeleteTagsMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteTagsMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteTagsMessage, ::rusoto_core::BuildError> {
+        Ok(DeleteTagsMessage {
+            resource_name: self
+                .resource_name
+                .ok_or(::rusoto_core::BuildError::missing("resource_name"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 /// Serialize `DeleteTagsMessage` contents to a `SignedRequest`.
 struct DeleteTagsMessageSerializer;
 impl DeleteTagsMessageSerializer {
@@ -3666,6 +5255,36 @@ pub struct DescribeAccountAttributesMessage {
     pub attribute_names: Option<Vec<String>>,
 }
 
+impl DescribeAccountAttributesMessage {
+    /// Returns a builder for `DescribeAccountAttributesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeAccountAttributesMessageBuilder {
+        DescribeAccountAttributesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeAccountAttributesMessage`](struct.DescribeAccountAttributesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeAccountAttributesMessageBuilder {
+    attribute_names: Option<Vec<String>>,
+}
+
+impl DescribeAccountAttributesMessageBuilder {
+    /// Sets the [`attribute_names`](struct.DescribeAccountAttributesMessage.html#structfield.attribute_names) member.
+    pub fn attribute_names<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.attribute_names = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeAccountAttributesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeAccountAttributesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeAccountAttributesMessage {
+            attribute_names: self.attribute_names,
+        })
+    }
+}
+
 /// Serialize `DescribeAccountAttributesMessage` contents to a `SignedRequest`.
 struct DescribeAccountAttributesMessageSerializer;
 impl DescribeAccountAttributesMessageSerializer {
@@ -3695,13 +5314,59 @@ pub struct DescribeClusterDbRevisionsMessage {
     pub max_records: Option<i64>,
 }
 
-/// Serialize `DescribeClusterDbRevisionsMessage` contents to a `SignedRequest`.
-struct DescribeClusterDbRevisionsMessageSerializer;
-impl DescribeClusterDbRevisionsMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &DescribeClusterDbRevisionsMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
+impl DescribeClusterDbRevisionsMessage {
+    /// Returns a builder for `DescribeClusterDbRevisionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeClusterDbRevisionsMessageBuilder {
+        DescribeClusterDbRevisionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterDbRevisionsMessage`](struct.DescribeClusterDbRevisionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterDbRevisionsMessageBuilder {
+    cluster_identifier: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeClusterDbRevisionsMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.DescribeClusterDbRevisionsMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeClusterDbRevisionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeClusterDbRevisionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterDbRevisionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClusterDbRevisionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeClusterDbRevisionsMessage {
+            cluster_identifier: self.cluster_identifier,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
+/// Serialize `DescribeClusterDbRevisionsMessage` contents to a `SignedRequest`.
+struct DescribeClusterDbRevisionsMessageSerializer;
+impl DescribeClusterDbRevisionsMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &DescribeClusterDbRevisionsMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
         }
 
         if let Some(ref field_value) = obj.cluster_identifier {
@@ -3731,6 +5396,69 @@ pub struct DescribeClusterParameterGroupsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl DescribeClusterParameterGroupsMessage {
+    /// Returns a builder for `DescribeClusterParameterGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeClusterParameterGroupsMessageBuilder {
+        DescribeClusterParameterGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterParameterGroupsMessage`](struct.DescribeClusterParameterGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterParameterGroupsMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    parameter_group_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+    tag_values: Option<Vec<String>>,
+}
+
+impl DescribeClusterParameterGroupsMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeClusterParameterGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeClusterParameterGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_group_name`](struct.DescribeClusterParameterGroupsMessage.html#structfield.parameter_group_name) member.
+    pub fn parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DescribeClusterParameterGroupsMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_values`](struct.DescribeClusterParameterGroupsMessage.html#structfield.tag_values) member.
+    pub fn tag_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterParameterGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClusterParameterGroupsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeClusterParameterGroupsMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            parameter_group_name: self.parameter_group_name,
+            tag_keys: self.tag_keys,
+            tag_values: self.tag_values,
+        })
+    }
+}
+
 /// Serialize `DescribeClusterParameterGroupsMessage` contents to a `SignedRequest`.
 struct DescribeClusterParameterGroupsMessageSerializer;
 impl DescribeClusterParameterGroupsMessageSerializer {
@@ -3779,6 +5507,62 @@ pub struct DescribeClusterParametersMessage {
     pub source: Option<String>,
 }
 
+impl DescribeClusterParametersMessage {
+    /// Returns a builder for `DescribeClusterParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeClusterParametersMessageBuilder {
+        DescribeClusterParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterParametersMessage`](struct.DescribeClusterParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterParametersMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    parameter_group_name: Option<String>,
+    source: Option<String>,
+}
+
+impl DescribeClusterParametersMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeClusterParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeClusterParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_group_name`](struct.DescribeClusterParametersMessage.html#structfield.parameter_group_name) member.
+    pub fn parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source`](struct.DescribeClusterParametersMessage.html#structfield.source) member.
+    pub fn source<T: Into<String>>(mut self, value: T) -> Self {
+        self.source = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClusterParametersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeClusterParametersMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            parameter_group_name: self
+                .parameter_group_name
+                .ok_or(::rusoto_core::BuildError::missing("parameter_group_name"))?,
+            source: self.source,
+        })
+    }
+}
+
 /// Serialize `DescribeClusterParametersMessage` contents to a `SignedRequest`.
 struct DescribeClusterParametersMessageSerializer;
 impl DescribeClusterParametersMessageSerializer {
@@ -3819,6 +5603,69 @@ pub struct DescribeClusterSecurityGroupsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl DescribeClusterSecurityGroupsMessage {
+    /// Returns a builder for `DescribeClusterSecurityGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeClusterSecurityGroupsMessageBuilder {
+        DescribeClusterSecurityGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterSecurityGroupsMessage`](struct.DescribeClusterSecurityGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterSecurityGroupsMessageBuilder {
+    cluster_security_group_name: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    tag_keys: Option<Vec<String>>,
+    tag_values: Option<Vec<String>>,
+}
+
+impl DescribeClusterSecurityGroupsMessageBuilder {
+    /// Sets the [`cluster_security_group_name`](struct.DescribeClusterSecurityGroupsMessage.html#structfield.cluster_security_group_name) member.
+    pub fn cluster_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeClusterSecurityGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeClusterSecurityGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DescribeClusterSecurityGroupsMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_values`](struct.DescribeClusterSecurityGroupsMessage.html#structfield.tag_values) member.
+    pub fn tag_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterSecurityGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClusterSecurityGroupsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeClusterSecurityGroupsMessage {
+            cluster_security_group_name: self.cluster_security_group_name,
+            marker: self.marker,
+            max_records: self.max_records,
+            tag_keys: self.tag_keys,
+            tag_values: self.tag_values,
+        })
+    }
+}
+
 /// Serialize `DescribeClusterSecurityGroupsMessage` contents to a `SignedRequest`.
 struct DescribeClusterSecurityGroupsMessageSerializer;
 impl DescribeClusterSecurityGroupsMessageSerializer {
@@ -3865,7 +5712,7 @@ pub struct DescribeClusterSnapshotsMessage {
     /// <p>The identifier of the cluster which generated the requested snapshots.</p>
     pub cluster_identifier: Option<String>,
     /// <p>A time value that requests only snapshots created at or before the specified time. The time value is specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: <code>2012-07-16T18:00:00Z</code> </p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a <a>DescribeClusterSnapshots</a> request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p>
     pub marker: Option<String>,
     /// <p>The maximum number of response records to return in each call. If the number of remaining response records exceeds the specified <code>MaxRecords</code> value, a value is returned in a <code>marker</code> field of the response. You can retrieve the next set of records by retrying the command with the returned marker value. </p> <p>Default: <code>100</code> </p> <p>Constraints: minimum 20, maximum 100.</p>
@@ -3879,13 +5726,131 @@ pub struct DescribeClusterSnapshotsMessage {
     /// <p><p/></p>
     pub sorting_entities: Option<Vec<SnapshotSortingEntity>>,
     /// <p>A value that requests only snapshots created at or after the specified time. The time value is specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: <code>2012-07-16T18:00:00Z</code> </p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
     /// <p>A tag key or keys for which you want to return all matching cluster snapshots that are associated with the specified key or keys. For example, suppose that you have snapshots that are tagged with keys called <code>owner</code> and <code>environment</code>. If you specify both of these tag keys in the request, Amazon Redshift returns a response with the snapshots that have either or both of these tag keys associated with them.</p>
     pub tag_keys: Option<Vec<String>>,
     /// <p>A tag value or values for which you want to return all matching cluster snapshots that are associated with the specified tag value or values. For example, suppose that you have snapshots that are tagged with values called <code>admin</code> and <code>test</code>. If you specify both of these tag values in the request, Amazon Redshift returns a response with the snapshots that have either or both of these tag values associated with them.</p>
     pub tag_values: Option<Vec<String>>,
 }
 
+impl DescribeClusterSnapshotsMessage {
+    /// Returns a builder for `DescribeClusterSnapshotsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeClusterSnapshotsMessageBuilder {
+        DescribeClusterSnapshotsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterSnapshotsMessage`](struct.DescribeClusterSnapshotsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterSnapshotsMessageBuilder {
+    cluster_exists: Option<bool>,
+    cluster_identifier: Option<String>,
+    end_time: Option<IsoTimestamp>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    owner_account: Option<String>,
+    snapshot_identifier: Option<String>,
+    snapshot_type: Option<String>,
+    sorting_entities: Option<Vec<SnapshotSortingEntity>>,
+    start_time: Option<IsoTimestamp>,
+    tag_keys: Option<Vec<String>>,
+    tag_values: Option<Vec<String>>,
+}
+
+impl DescribeClusterSnapshotsMessageBuilder {
+    /// Sets the [`cluster_exists`](struct.DescribeClusterSnapshotsMessage.html#structfield.cluster_exists) member.
+    pub fn cluster_exists<T: Into<bool>>(mut self, value: T) -> Self {
+        self.cluster_exists = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_identifier`](struct.DescribeClusterSnapshotsMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.DescribeClusterSnapshotsMessage.html#structfield.end_time) member.
+    pub fn end_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeClusterSnapshotsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeClusterSnapshotsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`owner_account`](struct.DescribeClusterSnapshotsMessage.html#structfield.owner_account) member.
+    pub fn owner_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.owner_account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_identifier`](struct.DescribeClusterSnapshotsMessage.html#structfield.snapshot_identifier) member.
+    pub fn snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_type`](struct.DescribeClusterSnapshotsMessage.html#structfield.snapshot_type) member.
+    pub fn snapshot_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sorting_entities`](struct.DescribeClusterSnapshotsMessage.html#structfield.sorting_entities) member.
+    pub fn sorting_entities<T: Into<Vec<SnapshotSortingEntity>>>(mut self, value: T) -> Self {
+        self.sorting_entities = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.DescribeClusterSnapshotsMessage.html#structfield.start_time) member.
+    pub fn start_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DescribeClusterSnapshotsMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_values`](struct.DescribeClusterSnapshotsMessage.html#structfield.tag_values) member.
+    pub fn tag_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterSnapshotsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClusterSnapshotsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeClusterSnapshotsMessage {
+            cluster_exists: self.cluster_exists,
+            cluster_identifier: self.cluster_identifier,
+            end_time: self.end_time,
+            marker: self.marker,
+            max_records: self.max_records,
+            owner_account: self.owner_account,
+            snapshot_identifier: self.snapshot_identifier,
+            snapshot_type: self.snapshot_type,
+            sorting_entities: self.sorting_entities,
+            start_time: self.start_time,
+            tag_keys: self.tag_keys,
+            tag_values: self.tag_values,
+        })
+    }
+}
+
 /// Serialize `DescribeClusterSnapshotsMessage` contents to a `SignedRequest`.
 struct DescribeClusterSnapshotsMessageSerializer;
 impl DescribeClusterSnapshotsMessageSerializer {
@@ -3961,6 +5926,68 @@ pub struct DescribeClusterSubnetGroupsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl DescribeClusterSubnetGroupsMessage {
+    /// Returns a builder for `DescribeClusterSubnetGroupsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeClusterSubnetGroupsMessageBuilder {
+        DescribeClusterSubnetGroupsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterSubnetGroupsMessage`](struct.DescribeClusterSubnetGroupsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterSubnetGroupsMessageBuilder {
+    cluster_subnet_group_name: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    tag_keys: Option<Vec<String>>,
+    tag_values: Option<Vec<String>>,
+}
+
+impl DescribeClusterSubnetGroupsMessageBuilder {
+    /// Sets the [`cluster_subnet_group_name`](struct.DescribeClusterSubnetGroupsMessage.html#structfield.cluster_subnet_group_name) member.
+    pub fn cluster_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeClusterSubnetGroupsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeClusterSubnetGroupsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DescribeClusterSubnetGroupsMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_values`](struct.DescribeClusterSubnetGroupsMessage.html#structfield.tag_values) member.
+    pub fn tag_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterSubnetGroupsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClusterSubnetGroupsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeClusterSubnetGroupsMessage {
+            cluster_subnet_group_name: self.cluster_subnet_group_name,
+            marker: self.marker,
+            max_records: self.max_records,
+            tag_keys: self.tag_keys,
+            tag_values: self.tag_values,
+        })
+    }
+}
+
 /// Serialize `DescribeClusterSubnetGroupsMessage` contents to a `SignedRequest`.
 struct DescribeClusterSubnetGroupsMessageSerializer;
 impl DescribeClusterSubnetGroupsMessageSerializer {
@@ -4009,6 +6036,52 @@ pub struct DescribeClusterTracksMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeClusterTracksMessage {
+    /// Returns a builder for `DescribeClusterTracksMessage`, checking its required members when built.
+    pub fn builder() -> DescribeClusterTracksMessageBuilder {
+        DescribeClusterTracksMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterTracksMessage`](struct.DescribeClusterTracksMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterTracksMessageBuilder {
+    maintenance_track_name: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeClusterTracksMessageBuilder {
+    /// Sets the [`maintenance_track_name`](struct.DescribeClusterTracksMessage.html#structfield.maintenance_track_name) member.
+    pub fn maintenance_track_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.maintenance_track_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeClusterTracksMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeClusterTracksMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterTracksMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClusterTracksMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeClusterTracksMessage {
+            maintenance_track_name: self.maintenance_track_name,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeClusterTracksMessage` contents to a `SignedRequest`.
 struct DescribeClusterTracksMessageSerializer;
 impl DescribeClusterTracksMessageSerializer {
@@ -4046,6 +6119,60 @@ pub struct DescribeClusterVersionsMessage {
     pub max_records: Option<i64>,
 }
 
+impl DescribeClusterVersionsMessage {
+    /// Returns a builder for `DescribeClusterVersionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeClusterVersionsMessageBuilder {
+        DescribeClusterVersionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClusterVersionsMessage`](struct.DescribeClusterVersionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeClusterVersionsMessageBuilder {
+    cluster_parameter_group_family: Option<String>,
+    cluster_version: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+}
+
+impl DescribeClusterVersionsMessageBuilder {
+    /// Sets the [`cluster_parameter_group_family`](struct.DescribeClusterVersionsMessage.html#structfield.cluster_parameter_group_family) member.
+    pub fn cluster_parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_version`](struct.DescribeClusterVersionsMessage.html#structfield.cluster_version) member.
+    pub fn cluster_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeClusterVersionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeClusterVersionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClusterVersionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClusterVersionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeClusterVersionsMessage {
+            cluster_parameter_group_family: self.cluster_parameter_group_family,
+            cluster_version: self.cluster_version,
+            marker: self.marker,
+            max_records: self.max_records,
+        })
+    }
+}
+
 /// Serialize `DescribeClusterVersionsMessage` contents to a `SignedRequest`.
 struct DescribeClusterVersionsMessageSerializer;
 impl DescribeClusterVersionsMessageSerializer {
@@ -4088,6 +6215,68 @@ pub struct DescribeClustersMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl DescribeClustersMessage {
+    /// Returns a builder for `DescribeClustersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeClustersMessageBuilder {
+        DescribeClustersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeClustersMessage`](struct.DescribeClustersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeClustersMessageBuilder {
+    cluster_identifier: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    tag_keys: Option<Vec<String>>,
+    tag_values: Option<Vec<String>>,
+}
+
+impl DescribeClustersMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.DescribeClustersMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeClustersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeClustersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DescribeClustersMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_values`](struct.DescribeClustersMessage.html#structfield.tag_values) member.
+    pub fn tag_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeClustersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeClustersMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeClustersMessage {
+            cluster_identifier: self.cluster_identifier,
+            marker: self.marker,
+            max_records: self.max_records,
+            tag_keys: self.tag_keys,
+            tag_values: self.tag_values,
+        })
+    }
+}
+
 /// Serialize `DescribeClustersMessage` contents to a `SignedRequest`.
 struct DescribeClustersMessageSerializer;
 impl DescribeClustersMessageSerializer {
@@ -4134,6 +6323,55 @@ pub struct DescribeDefaultClusterParametersMessage {
     pub parameter_group_family: String,
 }
 
+impl DescribeDefaultClusterParametersMessage {
+    /// Returns a builder for `DescribeDefaultClusterParametersMessage`, checking its required members when built.
+    pub fn builder() -> DescribeDefaultClusterParametersMessageBuilder {
+        DescribeDefaultClusterParametersMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeDefaultClusterParametersMessage`](struct.DescribeDefaultClusterParametersMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeDefaultClusterParametersMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    parameter_group_family: Option<String>,
+}
+
+impl DescribeDefaultClusterParametersMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeDefaultClusterParametersMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeDefaultClusterParametersMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameter_group_family`](struct.DescribeDefaultClusterParametersMessage.html#structfield.parameter_group_family) member.
+    pub fn parameter_group_family<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_family = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeDefaultClusterParametersMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeDefaultClusterParametersMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeDefaultClusterParametersMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            parameter_group_family: self
+                .parameter_group_family
+                .ok_or(::rusoto_core::BuildError::missing("parameter_group_family"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeDefaultClusterParametersMessage` contents to a `SignedRequest`.
 struct DescribeDefaultClusterParametersMessageSerializer;
 impl DescribeDefaultClusterParametersMessageSerializer {
@@ -4161,10 +6399,10 @@ pub struct DescribeDefaultClusterParametersResult {
     pub default_cluster_parameters: Option<DefaultClusterParameters>,
 }
 
-struct DescribeDefaultClusterParametersResultDeserializer;
+pub(crate) struct DescribeDefaultClusterParametersResultDeserializer;
 impl DescribeDefaultClusterParametersResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeDefaultClusterParametersResult, XmlParseError> {
@@ -4194,6 +6432,36 @@ pub struct DescribeEventCategoriesMessage {
     pub source_type: Option<String>,
 }
 
+impl DescribeEventCategoriesMessage {
+    /// Returns a builder for `DescribeEventCategoriesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventCategoriesMessageBuilder {
+        DescribeEventCategoriesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventCategoriesMessage`](struct.DescribeEventCategoriesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventCategoriesMessageBuilder {
+    source_type: Option<String>,
+}
+
+impl DescribeEventCategoriesMessageBuilder {
+    /// Sets the [`source_type`](struct.DescribeEventCategoriesMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventCategoriesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventCategoriesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventCategoriesMessage {
+            source_type: self.source_type,
+        })
+    }
+}
+
 /// Serialize `DescribeEventCategoriesMessage` contents to a `SignedRequest`.
 struct DescribeEventCategoriesMessageSerializer;
 impl DescribeEventCategoriesMessageSerializer {
@@ -4224,6 +6492,68 @@ pub struct DescribeEventSubscriptionsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl DescribeEventSubscriptionsMessage {
+    /// Returns a builder for `DescribeEventSubscriptionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventSubscriptionsMessageBuilder {
+        DescribeEventSubscriptionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventSubscriptionsMessage`](struct.DescribeEventSubscriptionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventSubscriptionsMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    subscription_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+    tag_values: Option<Vec<String>>,
+}
+
+impl DescribeEventSubscriptionsMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeEventSubscriptionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEventSubscriptionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.DescribeEventSubscriptionsMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DescribeEventSubscriptionsMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_values`](struct.DescribeEventSubscriptionsMessage.html#structfield.tag_values) member.
+    pub fn tag_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventSubscriptionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeEventSubscriptionsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventSubscriptionsMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            subscription_name: self.subscription_name,
+            tag_keys: self.tag_keys,
+            tag_values: self.tag_values,
+        })
+    }
+}
+
 /// Serialize `DescribeEventSubscriptionsMessage` contents to a `SignedRequest`.
 struct DescribeEventSubscriptionsMessageSerializer;
 impl DescribeEventSubscriptionsMessageSerializer {
@@ -4265,7 +6595,7 @@ pub struct DescribeEventsMessage {
     /// <p>The number of minutes prior to the time of the request for which to retrieve events. For example, if the request is sent at 18:00 and you specify a duration of 60, then only events which have occurred after 17:00 will be returned.</p> <p>Default: <code>60</code> </p>
     pub duration: Option<i64>,
     /// <p>The end of the time interval for which to retrieve events, specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: <code>2009-07-08T18:00Z</code> </p>
-    pub end_time: Option<String>,
+    pub end_time: Option<IsoTimestamp>,
     /// <p>An optional parameter that specifies the starting point to return a set of response records. When the results of a <a>DescribeEvents</a> request exceed the value specified in <code>MaxRecords</code>, AWS returns a value in the <code>Marker</code> field of the response. You can retrieve the next set of response records by providing the returned marker value in the <code>Marker</code> parameter and retrying the request. </p>
     pub marker: Option<String>,
     /// <p>The maximum number of response records to return in each call. If the number of remaining response records exceeds the specified <code>MaxRecords</code> value, a value is returned in a <code>marker</code> field of the response. You can retrieve the next set of records by retrying the command with the returned marker value. </p> <p>Default: <code>100</code> </p> <p>Constraints: minimum 20, maximum 100.</p>
@@ -4273,9 +6603,85 @@ pub struct DescribeEventsMessage {
     /// <p><p>The identifier of the event source for which events will be returned. If this parameter is not specified, then all sources are included in the response.</p> <p>Constraints:</p> <p>If <i>SourceIdentifier</i> is supplied, <i>SourceType</i> must also be provided.</p> <ul> <li> <p>Specify a cluster identifier when <i>SourceType</i> is <code>cluster</code>.</p> </li> <li> <p>Specify a cluster security group name when <i>SourceType</i> is <code>cluster-security-group</code>.</p> </li> <li> <p>Specify a cluster parameter group name when <i>SourceType</i> is <code>cluster-parameter-group</code>.</p> </li> <li> <p>Specify a cluster snapshot identifier when <i>SourceType</i> is <code>cluster-snapshot</code>.</p> </li> </ul></p>
     pub source_identifier: Option<String>,
     /// <p><p>The event source to retrieve events for. If no value is specified, all events are returned.</p> <p>Constraints:</p> <p>If <i>SourceType</i> is supplied, <i>SourceIdentifier</i> must also be provided.</p> <ul> <li> <p>Specify <code>cluster</code> when <i>SourceIdentifier</i> is a cluster identifier.</p> </li> <li> <p>Specify <code>cluster-security-group</code> when <i>SourceIdentifier</i> is a cluster security group name.</p> </li> <li> <p>Specify <code>cluster-parameter-group</code> when <i>SourceIdentifier</i> is a cluster parameter group name.</p> </li> <li> <p>Specify <code>cluster-snapshot</code> when <i>SourceIdentifier</i> is a cluster snapshot identifier.</p> </li> </ul></p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
     /// <p>The beginning of the time interval to retrieve events for, specified in ISO 8601 format. For more information about ISO 8601, go to the <a href="http://en.wikipedia.org/wiki/ISO_8601">ISO8601 Wikipedia page.</a> </p> <p>Example: <code>2009-07-08T18:00Z</code> </p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
+}
+
+impl DescribeEventsMessage {
+    /// Returns a builder for `DescribeEventsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeEventsMessageBuilder {
+        DescribeEventsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeEventsMessage`](struct.DescribeEventsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeEventsMessageBuilder {
+    duration: Option<i64>,
+    end_time: Option<IsoTimestamp>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    source_identifier: Option<String>,
+    source_type: Option<SourceType>,
+    start_time: Option<IsoTimestamp>,
+}
+
+impl DescribeEventsMessageBuilder {
+    /// Sets the [`duration`](struct.DescribeEventsMessage.html#structfield.duration) member.
+    pub fn duration<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`end_time`](struct.DescribeEventsMessage.html#structfield.end_time) member.
+    pub fn end_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeEventsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeEventsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_identifier`](struct.DescribeEventsMessage.html#structfield.source_identifier) member.
+    pub fn source_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.DescribeEventsMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<SourceType>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`start_time`](struct.DescribeEventsMessage.html#structfield.start_time) member.
+    pub fn start_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeEventsMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeEventsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeEventsMessage {
+            duration: self.duration,
+            end_time: self.end_time,
+            marker: self.marker,
+            max_records: self.max_records,
+            source_identifier: self.source_identifier,
+            source_type: self.source_type,
+            start_time: self.start_time,
+        })
+    }
 }
 
 /// Serialize `DescribeEventsMessage` contents to a `SignedRequest`.
@@ -4326,10 +6732,73 @@ pub struct DescribeHsmClientCertificatesMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
-/// Serialize `DescribeHsmClientCertificatesMessage` contents to a `SignedRequest`.
-struct DescribeHsmClientCertificatesMessageSerializer;
-impl DescribeHsmClientCertificatesMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &DescribeHsmClientCertificatesMessage) {
+impl DescribeHsmClientCertificatesMessage {
+    /// Returns a builder for `DescribeHsmClientCertificatesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeHsmClientCertificatesMessageBuilder {
+        DescribeHsmClientCertificatesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeHsmClientCertificatesMessage`](struct.DescribeHsmClientCertificatesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeHsmClientCertificatesMessageBuilder {
+    hsm_client_certificate_identifier: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    tag_keys: Option<Vec<String>>,
+    tag_values: Option<Vec<String>>,
+}
+
+impl DescribeHsmClientCertificatesMessageBuilder {
+    /// Sets the [`hsm_client_certificate_identifier`](struct.DescribeHsmClientCertificatesMessage.html#structfield.hsm_client_certificate_identifier) member.
+    pub fn hsm_client_certificate_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_client_certificate_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeHsmClientCertificatesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeHsmClientCertificatesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DescribeHsmClientCertificatesMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_values`](struct.DescribeHsmClientCertificatesMessage.html#structfield.tag_values) member.
+    pub fn tag_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeHsmClientCertificatesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeHsmClientCertificatesMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeHsmClientCertificatesMessage {
+            hsm_client_certificate_identifier: self.hsm_client_certificate_identifier,
+            marker: self.marker,
+            max_records: self.max_records,
+            tag_keys: self.tag_keys,
+            tag_values: self.tag_values,
+        })
+    }
+}
+
+/// Serialize `DescribeHsmClientCertificatesMessage` contents to a `SignedRequest`.
+struct DescribeHsmClientCertificatesMessageSerializer;
+impl DescribeHsmClientCertificatesMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &DescribeHsmClientCertificatesMessage) {
         let mut prefix = name.to_string();
         if prefix != "" {
             prefix.push_str(".");
@@ -4379,6 +6848,68 @@ pub struct DescribeHsmConfigurationsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl DescribeHsmConfigurationsMessage {
+    /// Returns a builder for `DescribeHsmConfigurationsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeHsmConfigurationsMessageBuilder {
+        DescribeHsmConfigurationsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeHsmConfigurationsMessage`](struct.DescribeHsmConfigurationsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeHsmConfigurationsMessageBuilder {
+    hsm_configuration_identifier: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    tag_keys: Option<Vec<String>>,
+    tag_values: Option<Vec<String>>,
+}
+
+impl DescribeHsmConfigurationsMessageBuilder {
+    /// Sets the [`hsm_configuration_identifier`](struct.DescribeHsmConfigurationsMessage.html#structfield.hsm_configuration_identifier) member.
+    pub fn hsm_configuration_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_configuration_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeHsmConfigurationsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeHsmConfigurationsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DescribeHsmConfigurationsMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_values`](struct.DescribeHsmConfigurationsMessage.html#structfield.tag_values) member.
+    pub fn tag_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeHsmConfigurationsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeHsmConfigurationsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeHsmConfigurationsMessage {
+            hsm_configuration_identifier: self.hsm_configuration_identifier,
+            marker: self.marker,
+            max_records: self.max_records,
+            tag_keys: self.tag_keys,
+            tag_values: self.tag_values,
+        })
+    }
+}
+
 /// Serialize `DescribeHsmConfigurationsMessage` contents to a `SignedRequest`.
 struct DescribeHsmConfigurationsMessageSerializer;
 impl DescribeHsmConfigurationsMessageSerializer {
@@ -4424,6 +6955,38 @@ pub struct DescribeLoggingStatusMessage {
     pub cluster_identifier: String,
 }
 
+impl DescribeLoggingStatusMessage {
+    /// Returns a builder for `DescribeLoggingStatusMessage`, checking its required members when built.
+    pub fn builder() -> DescribeLoggingStatusMessageBuilder {
+        DescribeLoggingStatusMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeLoggingStatusMessage`](struct.DescribeLoggingStatusMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeLoggingStatusMessageBuilder {
+    cluster_identifier: Option<String>,
+}
+
+impl DescribeLoggingStatusMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.DescribeLoggingStatusMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeLoggingStatusMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeLoggingStatusMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeLoggingStatusMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeLoggingStatusMessage` contents to a `SignedRequest`.
 struct DescribeLoggingStatusMessageSerializer;
 impl DescribeLoggingStatusMessageSerializer {
@@ -4453,6 +7016,61 @@ pub struct DescribeOrderableClusterOptionsMessage {
     pub node_type: Option<String>,
 }
 
+impl DescribeOrderableClusterOptionsMessage {
+    /// Returns a builder for `DescribeOrderableClusterOptionsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeOrderableClusterOptionsMessageBuilder {
+        DescribeOrderableClusterOptionsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeOrderableClusterOptionsMessage`](struct.DescribeOrderableClusterOptionsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeOrderableClusterOptionsMessageBuilder {
+    cluster_version: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    node_type: Option<String>,
+}
+
+impl DescribeOrderableClusterOptionsMessageBuilder {
+    /// Sets the [`cluster_version`](struct.DescribeOrderableClusterOptionsMessage.html#structfield.cluster_version) member.
+    pub fn cluster_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeOrderableClusterOptionsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeOrderableClusterOptionsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_type`](struct.DescribeOrderableClusterOptionsMessage.html#structfield.node_type) member.
+    pub fn node_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.node_type = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeOrderableClusterOptionsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeOrderableClusterOptionsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeOrderableClusterOptionsMessage {
+            cluster_version: self.cluster_version,
+            marker: self.marker,
+            max_records: self.max_records,
+            node_type: self.node_type,
+        })
+    }
+}
+
 /// Serialize `DescribeOrderableClusterOptionsMessage` contents to a `SignedRequest`.
 struct DescribeOrderableClusterOptionsMessageSerializer;
 impl DescribeOrderableClusterOptionsMessageSerializer {
@@ -4488,6 +7106,53 @@ pub struct DescribeReservedNodeOfferingsMessage {
     pub reserved_node_offering_id: Option<String>,
 }
 
+impl DescribeReservedNodeOfferingsMessage {
+    /// Returns a builder for `DescribeReservedNodeOfferingsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeReservedNodeOfferingsMessageBuilder {
+        DescribeReservedNodeOfferingsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReservedNodeOfferingsMessage`](struct.DescribeReservedNodeOfferingsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeReservedNodeOfferingsMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    reserved_node_offering_id: Option<String>,
+}
+
+impl DescribeReservedNodeOfferingsMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeReservedNodeOfferingsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeReservedNodeOfferingsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_node_offering_id`](struct.DescribeReservedNodeOfferingsMessage.html#structfield.reserved_node_offering_id) member.
+    pub fn reserved_node_offering_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_node_offering_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReservedNodeOfferingsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReservedNodeOfferingsMessage, ::rusoto_core::BuildError>
+    {
+        Ok(DescribeReservedNodeOfferingsMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            reserved_node_offering_id: self.reserved_node_offering_id,
+        })
+    }
+}
+
 /// Serialize `DescribeReservedNodeOfferingsMessage` contents to a `SignedRequest`.
 struct DescribeReservedNodeOfferingsMessageSerializer;
 impl DescribeReservedNodeOfferingsMessageSerializer {
@@ -4523,6 +7188,52 @@ pub struct DescribeReservedNodesMessage {
     pub reserved_node_id: Option<String>,
 }
 
+impl DescribeReservedNodesMessage {
+    /// Returns a builder for `DescribeReservedNodesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeReservedNodesMessageBuilder {
+        DescribeReservedNodesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeReservedNodesMessage`](struct.DescribeReservedNodesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeReservedNodesMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    reserved_node_id: Option<String>,
+}
+
+impl DescribeReservedNodesMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeReservedNodesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeReservedNodesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_node_id`](struct.DescribeReservedNodesMessage.html#structfield.reserved_node_id) member.
+    pub fn reserved_node_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_node_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeReservedNodesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeReservedNodesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeReservedNodesMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            reserved_node_id: self.reserved_node_id,
+        })
+    }
+}
+
 /// Serialize `DescribeReservedNodesMessage` contents to a `SignedRequest`.
 struct DescribeReservedNodesMessageSerializer;
 impl DescribeReservedNodesMessageSerializer {
@@ -4551,6 +7262,36 @@ pub struct DescribeResizeMessage {
     pub cluster_identifier: String,
 }
 
+impl DescribeResizeMessage {
+    /// Returns a builder for `DescribeResizeMessage`, checking its required members when built.
+    pub fn builder() -> DescribeResizeMessageBuilder {
+        DescribeResizeMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeResizeMessage`](struct.DescribeResizeMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeResizeMessageBuilder {
+    cluster_identifier: Option<String>,
+}
+
+impl DescribeResizeMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.DescribeResizeMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeResizeMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeResizeMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeResizeMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `DescribeResizeMessage` contents to a `SignedRequest`.
 struct DescribeResizeMessageSerializer;
 impl DescribeResizeMessageSerializer {
@@ -4582,6 +7323,68 @@ pub struct DescribeSnapshotCopyGrantsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl DescribeSnapshotCopyGrantsMessage {
+    /// Returns a builder for `DescribeSnapshotCopyGrantsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeSnapshotCopyGrantsMessageBuilder {
+        DescribeSnapshotCopyGrantsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSnapshotCopyGrantsMessage`](struct.DescribeSnapshotCopyGrantsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeSnapshotCopyGrantsMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    snapshot_copy_grant_name: Option<String>,
+    tag_keys: Option<Vec<String>>,
+    tag_values: Option<Vec<String>>,
+}
+
+impl DescribeSnapshotCopyGrantsMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeSnapshotCopyGrantsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeSnapshotCopyGrantsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_copy_grant_name`](struct.DescribeSnapshotCopyGrantsMessage.html#structfield.snapshot_copy_grant_name) member.
+    pub fn snapshot_copy_grant_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_copy_grant_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DescribeSnapshotCopyGrantsMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_values`](struct.DescribeSnapshotCopyGrantsMessage.html#structfield.tag_values) member.
+    pub fn tag_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSnapshotCopyGrantsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSnapshotCopyGrantsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeSnapshotCopyGrantsMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            snapshot_copy_grant_name: self.snapshot_copy_grant_name,
+            tag_keys: self.tag_keys,
+            tag_values: self.tag_values,
+        })
+    }
+}
+
 /// Serialize `DescribeSnapshotCopyGrantsMessage` contents to a `SignedRequest`.
 struct DescribeSnapshotCopyGrantsMessageSerializer;
 impl DescribeSnapshotCopyGrantsMessageSerializer {
@@ -4636,6 +7439,76 @@ pub struct DescribeSnapshotSchedulesMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl DescribeSnapshotSchedulesMessage {
+    /// Returns a builder for `DescribeSnapshotSchedulesMessage`, checking its required members when built.
+    pub fn builder() -> DescribeSnapshotSchedulesMessageBuilder {
+        DescribeSnapshotSchedulesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeSnapshotSchedulesMessage`](struct.DescribeSnapshotSchedulesMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeSnapshotSchedulesMessageBuilder {
+    cluster_identifier: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    schedule_identifier: Option<String>,
+    tag_keys: Option<Vec<String>>,
+    tag_values: Option<Vec<String>>,
+}
+
+impl DescribeSnapshotSchedulesMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.DescribeSnapshotSchedulesMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeSnapshotSchedulesMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeSnapshotSchedulesMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule_identifier`](struct.DescribeSnapshotSchedulesMessage.html#structfield.schedule_identifier) member.
+    pub fn schedule_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DescribeSnapshotSchedulesMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_values`](struct.DescribeSnapshotSchedulesMessage.html#structfield.tag_values) member.
+    pub fn tag_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeSnapshotSchedulesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeSnapshotSchedulesMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeSnapshotSchedulesMessage {
+            cluster_identifier: self.cluster_identifier,
+            marker: self.marker,
+            max_records: self.max_records,
+            schedule_identifier: self.schedule_identifier,
+            tag_keys: self.tag_keys,
+            tag_values: self.tag_values,
+        })
+    }
+}
+
 /// Serialize `DescribeSnapshotSchedulesMessage` contents to a `SignedRequest`.
 struct DescribeSnapshotSchedulesMessageSerializer;
 impl DescribeSnapshotSchedulesMessageSerializer {
@@ -4682,10 +7555,10 @@ pub struct DescribeSnapshotSchedulesOutputMessage {
     pub snapshot_schedules: Option<Vec<SnapshotSchedule>>,
 }
 
-struct DescribeSnapshotSchedulesOutputMessageDeserializer;
+pub(crate) struct DescribeSnapshotSchedulesOutputMessageDeserializer;
 impl DescribeSnapshotSchedulesOutputMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DescribeSnapshotSchedulesOutputMessage, XmlParseError> {
@@ -4725,6 +7598,60 @@ pub struct DescribeTableRestoreStatusMessage {
     pub table_restore_request_id: Option<String>,
 }
 
+impl DescribeTableRestoreStatusMessage {
+    /// Returns a builder for `DescribeTableRestoreStatusMessage`, checking its required members when built.
+    pub fn builder() -> DescribeTableRestoreStatusMessageBuilder {
+        DescribeTableRestoreStatusMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTableRestoreStatusMessage`](struct.DescribeTableRestoreStatusMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeTableRestoreStatusMessageBuilder {
+    cluster_identifier: Option<String>,
+    marker: Option<String>,
+    max_records: Option<i64>,
+    table_restore_request_id: Option<String>,
+}
+
+impl DescribeTableRestoreStatusMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.DescribeTableRestoreStatusMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`marker`](struct.DescribeTableRestoreStatusMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeTableRestoreStatusMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`table_restore_request_id`](struct.DescribeTableRestoreStatusMessage.html#structfield.table_restore_request_id) member.
+    pub fn table_restore_request_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.table_restore_request_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTableRestoreStatusMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeTableRestoreStatusMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeTableRestoreStatusMessage {
+            cluster_identifier: self.cluster_identifier,
+            marker: self.marker,
+            max_records: self.max_records,
+            table_restore_request_id: self.table_restore_request_id,
+        })
+    }
+}
+
 /// Serialize `DescribeTableRestoreStatusMessage` contents to a `SignedRequest`.
 struct DescribeTableRestoreStatusMessageSerializer;
 impl DescribeTableRestoreStatusMessageSerializer {
@@ -4769,6 +7696,74 @@ pub struct DescribeTagsMessage {
     pub tag_values: Option<Vec<String>>,
 }
 
+impl DescribeTagsMessage {
+    /// Returns a builder for `DescribeTagsMessage`, checking its required members when built.
+    pub fn builder() -> DescribeTagsMessageBuilder {
+        DescribeTagsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeTagsMessage`](struct.DescribeTagsMessage.html).
+#[derive(Default, Debug)]
+pub struct DescribeTagsMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    resource_name: Option<String>,
+    resource_type: Option<String>,
+    tag_keys: Option<Vec<String>>,
+    tag_values: Option<Vec<String>>,
+}
+
+impl DescribeTagsMessageBuilder {
+    /// Sets the [`marker`](struct.DescribeTagsMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.DescribeTagsMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_name`](struct.DescribeTagsMessage.html#structfield.resource_name) member.
+    pub fn resource_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type`](struct.DescribeTagsMessage.html#structfield.resource_type) member.
+    pub fn resource_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.resource_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.DescribeTagsMessage.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_values`](struct.DescribeTagsMessage.html#structfield.tag_values) member.
+    pub fn tag_values<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_values = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeTagsMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DescribeTagsMessage, ::rusoto_core::BuildError> {
+        Ok(DescribeTagsMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            resource_name: self.resource_name,
+            resource_type: self.resource_type,
+            tag_keys: self.tag_keys,
+            tag_values: self.tag_values,
+        })
+    }
+}
+
 /// Serialize `DescribeTagsMessage` contents to a `SignedRequest`.
 struct DescribeTagsMessageSerializer;
 impl DescribeTagsMessageSerializer {
@@ -4814,6 +7809,36 @@ pub struct DisableLoggingMessage {
     pub cluster_identifier: String,
 }
 
+impl DisableLoggingMessage {
+    /// Returns a builder for `DisableLoggingMessage`, checking its required members when built.
+    pub fn builder() -> DisableLoggingMessageBuilder {
+        DisableLoggingMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DisableLoggingMessage`](struct.DisableLoggingMessage.html).
+#[derive(Default, Debug)]
+pub struct DisableLoggingMessageBuilder {
+    cluster_identifier: Option<String>,
+}
+
+impl DisableLoggingMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.DisableLoggingMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableLoggingMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DisableLoggingMessage, ::rusoto_core::BuildError> {
+        Ok(DisableLoggingMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `DisableLoggingMessage` contents to a `SignedRequest`.
 struct DisableLoggingMessageSerializer;
 impl DisableLoggingMessageSerializer {
@@ -4837,6 +7862,38 @@ pub struct DisableSnapshotCopyMessage {
     pub cluster_identifier: String,
 }
 
+impl DisableSnapshotCopyMessage {
+    /// Returns a builder for `DisableSnapshotCopyMessage`, checking its required members when built.
+    pub fn builder() -> DisableSnapshotCopyMessageBuilder {
+        DisableSnapshotCopyMessageBuilder::default()
+    }
+}
+
+/// Builds a [`DisableSnapshotCopyMessage`](struct.DisableSnapshotCopyMessage.html).
+#[derive(Default, Debug)]
+pub struct DisableSnapshotCopyMessageBuilder {
+    cluster_identifier: Option<String>,
+}
+
+impl DisableSnapshotCopyMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.DisableSnapshotCopyMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `DisableSnapshotCopyMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DisableSnapshotCopyMessage, ::rusoto_core::BuildError> {
+        Ok(DisableSnapshotCopyMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `DisableSnapshotCopyMessage` contents to a `SignedRequest`.
 struct DisableSnapshotCopyMessageSerializer;
 impl DisableSnapshotCopyMessageSerializer {
@@ -4858,10 +7915,10 @@ pub struct DisableSnapshotCopyResult {
     pub cluster: Option<Cluster>,
 }
 
-struct DisableSnapshotCopyResultDeserializer;
+pub(crate) struct DisableSnapshotCopyResultDeserializer;
 impl DisableSnapshotCopyResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<DisableSnapshotCopyResult, XmlParseError> {
@@ -4880,10 +7937,13 @@ impl DisableSnapshotCopyResultDeserializer {
         )
     }
 }
-struct DoubleDeserializer;
+pub(crate) struct DoubleDeserializer;
 impl DoubleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4891,10 +7951,13 @@ impl DoubleDeserializer {
         Ok(obj)
     }
 }
-struct DoubleOptionalDeserializer;
+pub(crate) struct DoubleOptionalDeserializer;
 impl DoubleOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<f64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<f64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = f64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -4915,10 +7978,10 @@ pub struct EC2SecurityGroup {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct EC2SecurityGroupDeserializer;
+pub(crate) struct EC2SecurityGroupDeserializer;
 impl EC2SecurityGroupDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EC2SecurityGroup, XmlParseError> {
@@ -4950,10 +8013,10 @@ impl EC2SecurityGroupDeserializer {
         })
     }
 }
-struct EC2SecurityGroupListDeserializer;
+pub(crate) struct EC2SecurityGroupListDeserializer;
 impl EC2SecurityGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EC2SecurityGroup>, XmlParseError> {
@@ -4979,10 +8042,10 @@ pub struct ElasticIpStatus {
     pub status: Option<String>,
 }
 
-struct ElasticIpStatusDeserializer;
+pub(crate) struct ElasticIpStatusDeserializer;
 impl ElasticIpStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ElasticIpStatus, XmlParseError> {
@@ -5000,10 +8063,10 @@ impl ElasticIpStatusDeserializer {
         })
     }
 }
-struct EligibleTracksToUpdateListDeserializer;
+pub(crate) struct EligibleTracksToUpdateListDeserializer;
 impl EligibleTracksToUpdateListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<UpdateTarget>, XmlParseError> {
@@ -5031,6 +8094,54 @@ pub struct EnableLoggingMessage {
     pub s3_key_prefix: Option<String>,
 }
 
+impl EnableLoggingMessage {
+    /// Returns a builder for `EnableLoggingMessage`, checking its required members when built.
+    pub fn builder() -> EnableLoggingMessageBuilder {
+        EnableLoggingMessageBuilder::default()
+    }
+}
+
+/// Builds a [`EnableLoggingMessage`](struct.EnableLoggingMessage.html).
+#[derive(Default, Debug)]
+pub struct EnableLoggingMessageBuilder {
+    bucket_name: Option<String>,
+    cluster_identifier: Option<String>,
+    s3_key_prefix: Option<String>,
+}
+
+impl EnableLoggingMessageBuilder {
+    /// Sets the [`bucket_name`](struct.EnableLoggingMessage.html#structfield.bucket_name) member.
+    pub fn bucket_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.bucket_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_identifier`](struct.EnableLoggingMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`s3_key_prefix`](struct.EnableLoggingMessage.html#structfield.s3_key_prefix) member.
+    pub fn s3_key_prefix<T: Into<String>>(mut self, value: T) -> Self {
+        self.s3_key_prefix = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableLoggingMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<EnableLoggingMessage, ::rusoto_core::BuildError> {
+        Ok(EnableLoggingMessage {
+            bucket_name: self
+                .bucket_name
+                .ok_or(::rusoto_core::BuildError::missing("bucket_name"))?,
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            s3_key_prefix: self.s3_key_prefix,
+        })
+    }
+}
+
 /// Serialize `EnableLoggingMessage` contents to a `SignedRequest`.
 struct EnableLoggingMessageSerializer;
 impl EnableLoggingMessageSerializer {
@@ -5066,6 +8177,72 @@ pub struct EnableSnapshotCopyMessage {
     pub snapshot_copy_grant_name: Option<String>,
 }
 
+impl EnableSnapshotCopyMessage {
+    /// Returns a builder for `EnableSnapshotCopyMessage`, checking its required members when built.
+    pub fn builder() -> EnableSnapshotCopyMessageBuilder {
+        EnableSnapshotCopyMessageBuilder::default()
+    }
+}
+
+/// Builds a [`EnableSnapshotCopyMessage`](struct.EnableSnapshotCopyMessage.html).
+#[derive(Default, Debug)]
+pub struct EnableSnapshotCopyMessageBuilder {
+    cluster_identifier: Option<String>,
+    destination_region: Option<String>,
+    manual_snapshot_retention_period: Option<i64>,
+    retention_period: Option<i64>,
+    snapshot_copy_grant_name: Option<String>,
+}
+
+impl EnableSnapshotCopyMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.EnableSnapshotCopyMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`destination_region`](struct.EnableSnapshotCopyMessage.html#structfield.destination_region) member.
+    pub fn destination_region<T: Into<String>>(mut self, value: T) -> Self {
+        self.destination_region = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manual_snapshot_retention_period`](struct.EnableSnapshotCopyMessage.html#structfield.manual_snapshot_retention_period) member.
+    pub fn manual_snapshot_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.manual_snapshot_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retention_period`](struct.EnableSnapshotCopyMessage.html#structfield.retention_period) member.
+    pub fn retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_copy_grant_name`](struct.EnableSnapshotCopyMessage.html#structfield.snapshot_copy_grant_name) member.
+    pub fn snapshot_copy_grant_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_copy_grant_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `EnableSnapshotCopyMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<EnableSnapshotCopyMessage, ::rusoto_core::BuildError> {
+        Ok(EnableSnapshotCopyMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            destination_region: self
+                .destination_region
+                .ok_or(::rusoto_core::BuildError::missing("destination_region"))?,
+            manual_snapshot_retention_period: self.manual_snapshot_retention_period,
+            retention_period: self.retention_period,
+            snapshot_copy_grant_name: self.snapshot_copy_grant_name,
+        })
+    }
+}
+
 /// Serialize `EnableSnapshotCopyMessage` contents to a `SignedRequest`.
 struct EnableSnapshotCopyMessageSerializer;
 impl EnableSnapshotCopyMessageSerializer {
@@ -5106,10 +8283,10 @@ pub struct EnableSnapshotCopyResult {
     pub cluster: Option<Cluster>,
 }
 
-struct EnableSnapshotCopyResultDeserializer;
+pub(crate) struct EnableSnapshotCopyResultDeserializer;
 impl EnableSnapshotCopyResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EnableSnapshotCopyResult, XmlParseError> {
@@ -5137,10 +8314,10 @@ pub struct Endpoint {
     pub port: Option<i64>,
 }
 
-struct EndpointDeserializer;
+pub(crate) struct EndpointDeserializer;
 impl EndpointDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Endpoint, XmlParseError> {
@@ -5162,7 +8339,7 @@ impl EndpointDeserializer {
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Event {
     /// <p>The date and time of the event.</p>
-    pub date: Option<String>,
+    pub date: Option<IsoTimestamp>,
     /// <p>A list of the event categories.</p> <p>Values: Configuration, Management, Monitoring, Security</p>
     pub event_categories: Option<Vec<String>>,
     /// <p>The identifier of the event.</p>
@@ -5174,13 +8351,16 @@ pub struct Event {
     /// <p>The identifier for the source of the event.</p>
     pub source_identifier: Option<String>,
     /// <p>The source type for this event.</p>
-    pub source_type: Option<String>,
+    pub source_type: Option<SourceType>,
 }
 
-struct EventDeserializer;
+pub(crate) struct EventDeserializer;
 impl EventDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Event, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Event, XmlParseError> {
         deserialize_elements::<_, Event, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Date" => {
@@ -5214,10 +8394,10 @@ impl EventDeserializer {
         })
     }
 }
-struct EventCategoriesListDeserializer;
+pub(crate) struct EventCategoriesListDeserializer;
 impl EventCategoriesListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -5252,10 +8432,10 @@ pub struct EventCategoriesMap {
     pub source_type: Option<String>,
 }
 
-struct EventCategoriesMapDeserializer;
+pub(crate) struct EventCategoriesMapDeserializer;
 impl EventCategoriesMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventCategoriesMap, XmlParseError> {
@@ -5275,10 +8455,10 @@ impl EventCategoriesMapDeserializer {
         })
     }
 }
-struct EventCategoriesMapListDeserializer;
+pub(crate) struct EventCategoriesMapListDeserializer;
 impl EventCategoriesMapListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EventCategoriesMap>, XmlParseError> {
@@ -5302,10 +8482,10 @@ pub struct EventCategoriesMessage {
     pub event_categories_map_list: Option<Vec<EventCategoriesMap>>,
 }
 
-struct EventCategoriesMessageDeserializer;
+pub(crate) struct EventCategoriesMessageDeserializer;
 impl EventCategoriesMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventCategoriesMessage, XmlParseError> {
@@ -5338,10 +8518,10 @@ pub struct EventInfoMap {
     pub severity: Option<String>,
 }
 
-struct EventInfoMapDeserializer;
+pub(crate) struct EventInfoMapDeserializer;
 impl EventInfoMapDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventInfoMap, XmlParseError> {
@@ -5368,10 +8548,10 @@ impl EventInfoMapDeserializer {
         })
     }
 }
-struct EventInfoMapListDeserializer;
+pub(crate) struct EventInfoMapListDeserializer;
 impl EventInfoMapListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EventInfoMap>, XmlParseError> {
@@ -5388,10 +8568,10 @@ impl EventInfoMapListDeserializer {
         })
     }
 }
-struct EventListDeserializer;
+pub(crate) struct EventListDeserializer;
 impl EventListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Event>, XmlParseError> {
@@ -5427,15 +8607,15 @@ pub struct EventSubscription {
     /// <p><p>The status of the Amazon Redshift event notification subscription.</p> <p>Constraints:</p> <ul> <li> <p>Can be one of the following: active | no-permission | topic-not-exist</p> </li> <li> <p>The status &quot;no-permission&quot; indicates that Amazon Redshift no longer has permission to post to the Amazon SNS topic. The status &quot;topic-not-exist&quot; indicates that the topic was deleted after the subscription was created.</p> </li> </ul></p>
     pub status: Option<String>,
     /// <p>The date and time the Amazon Redshift event notification subscription was created.</p>
-    pub subscription_creation_time: Option<String>,
+    pub subscription_creation_time: Option<IsoTimestamp>,
     /// <p>The list of tags for the event subscription.</p>
     pub tags: Option<Vec<Tag>>,
 }
 
-struct EventSubscriptionDeserializer;
+pub(crate) struct EventSubscriptionDeserializer;
 impl EventSubscriptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventSubscription, XmlParseError> {
@@ -5494,10 +8674,10 @@ impl EventSubscriptionDeserializer {
         })
     }
 }
-struct EventSubscriptionsListDeserializer;
+pub(crate) struct EventSubscriptionsListDeserializer;
 impl EventSubscriptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<EventSubscription>, XmlParseError> {
@@ -5523,10 +8703,10 @@ pub struct EventSubscriptionsMessage {
     pub marker: Option<String>,
 }
 
-struct EventSubscriptionsMessageDeserializer;
+pub(crate) struct EventSubscriptionsMessageDeserializer;
 impl EventSubscriptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventSubscriptionsMessage, XmlParseError> {
@@ -5562,10 +8742,10 @@ pub struct EventsMessage {
     pub marker: Option<String>,
 }
 
-struct EventsMessageDeserializer;
+pub(crate) struct EventsMessageDeserializer;
 impl EventsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<EventsMessage, XmlParseError> {
@@ -5602,7 +8782,81 @@ pub struct GetClusterCredentialsMessage {
     pub duration_seconds: Option<i64>,
 }
 
-/// Serialize `GetClusterCredentialsMessage` contents to a `SignedRequest`.
+impl GetClusterCredentialsMessage {
+    /// Returns a builder for `GetClusterCredentialsMessage`, checking its required members when built.
+    pub fn builder() -> GetClusterCredentialsMessageBuilder {
+        GetClusterCredentialsMessageBuilder::default()
+    }
+}
+
+/// Builds a [`GetClusterCredentialsMessage`](struct.GetClusterCredentialsMessage.html).
+#[derive(Default, Debug)]
+pub struct GetClusterCredentialsMessageBuilder {
+    auto_create: Option<bool>,
+    cluster_identifier: Option<String>,
+    db_groups: Option<Vec<String>>,
+    db_name: Option<String>,
+    db_user: Option<String>,
+    duration_seconds: Option<i64>,
+}
+
+impl GetClusterCredentialsMessageBuilder {
+    /// Sets the [`auto_create`](struct.GetClusterCredentialsMessage.html#structfield.auto_create) member.
+    pub fn auto_create<T: Into<bool>>(mut self, value: T) -> Self {
+        self.auto_create = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_identifier`](struct.GetClusterCredentialsMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_groups`](struct.GetClusterCredentialsMessage.html#structfield.db_groups) member.
+    pub fn db_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.db_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_name`](struct.GetClusterCredentialsMessage.html#structfield.db_name) member.
+    pub fn db_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`db_user`](struct.GetClusterCredentialsMessage.html#structfield.db_user) member.
+    pub fn db_user<T: Into<String>>(mut self, value: T) -> Self {
+        self.db_user = Some(value.into());
+        self
+    }
+
+    /// Sets the [`duration_seconds`](struct.GetClusterCredentialsMessage.html#structfield.duration_seconds) member.
+    pub fn duration_seconds<T: Into<i64>>(mut self, value: T) -> Self {
+        self.duration_seconds = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetClusterCredentialsMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetClusterCredentialsMessage, ::rusoto_core::BuildError> {
+        Ok(GetClusterCredentialsMessage {
+            auto_create: self.auto_create,
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            db_groups: self.db_groups,
+            db_name: self.db_name,
+            db_user: self
+                .db_user
+                .ok_or(::rusoto_core::BuildError::missing("db_user"))?,
+            duration_seconds: self.duration_seconds,
+        })
+    }
+}
+
+/// Serialize `GetClusterCredentialsMessage` contents to a `SignedRequest`.
 struct GetClusterCredentialsMessageSerializer;
 impl GetClusterCredentialsMessageSerializer {
     fn serialize(params: &mut Params, name: &str, obj: &GetClusterCredentialsMessage) {
@@ -5646,6 +8900,57 @@ pub struct GetReservedNodeExchangeOfferingsInputMessage {
     pub reserved_node_id: String,
 }
 
+impl GetReservedNodeExchangeOfferingsInputMessage {
+    /// Returns a builder for `GetReservedNodeExchangeOfferingsInputMessage`, checking its required members when built.
+    pub fn builder() -> GetReservedNodeExchangeOfferingsInputMessageBuilder {
+        GetReservedNodeExchangeOfferingsInputMessageBuilder::default()
+    }
+}
+
+/// Builds a [`GetReservedNodeExchangeOfferingsInputMessage`](struct.GetReservedNodeExchangeOfferingsInputMessage.html).
+#[derive(Default, Debug)]
+pub struct GetReservedNodeExchangeOfferingsInputMessageBuilder {
+    marker: Option<String>,
+    max_records: Option<i64>,
+    reserved_node_id: Option<String>,
+}
+
+impl GetReservedNodeExchangeOfferingsInputMessageBuilder {
+    /// Sets the [`marker`](struct.GetReservedNodeExchangeOfferingsInputMessage.html#structfield.marker) member.
+    pub fn marker<T: Into<String>>(mut self, value: T) -> Self {
+        self.marker = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_records`](struct.GetReservedNodeExchangeOfferingsInputMessage.html#structfield.max_records) member.
+    pub fn max_records<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_records = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_node_id`](struct.GetReservedNodeExchangeOfferingsInputMessage.html#structfield.reserved_node_id) member.
+    pub fn reserved_node_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_node_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetReservedNodeExchangeOfferingsInputMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<
+        GetReservedNodeExchangeOfferingsInputMessage,
+        ::rusoto_core::BuildError,
+    > {
+        Ok(GetReservedNodeExchangeOfferingsInputMessage {
+            marker: self.marker,
+            max_records: self.max_records,
+            reserved_node_id: self
+                .reserved_node_id
+                .ok_or(::rusoto_core::BuildError::missing("reserved_node_id"))?,
+        })
+    }
+}
+
 /// Serialize `GetReservedNodeExchangeOfferingsInputMessage` contents to a `SignedRequest`.
 struct GetReservedNodeExchangeOfferingsInputMessageSerializer;
 impl GetReservedNodeExchangeOfferingsInputMessageSerializer {
@@ -5680,10 +8985,10 @@ pub struct GetReservedNodeExchangeOfferingsOutputMessage {
     pub reserved_node_offerings: Option<Vec<ReservedNodeOffering>>,
 }
 
-struct GetReservedNodeExchangeOfferingsOutputMessageDeserializer;
+pub(crate) struct GetReservedNodeExchangeOfferingsOutputMessageDeserializer;
 impl GetReservedNodeExchangeOfferingsOutputMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<GetReservedNodeExchangeOfferingsOutputMessage, XmlParseError> {
@@ -5721,10 +9026,10 @@ pub struct HsmClientCertificate {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct HsmClientCertificateDeserializer;
+pub(crate) struct HsmClientCertificateDeserializer;
 impl HsmClientCertificateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HsmClientCertificate, XmlParseError> {
@@ -5753,10 +9058,10 @@ impl HsmClientCertificateDeserializer {
         })
     }
 }
-struct HsmClientCertificateListDeserializer;
+pub(crate) struct HsmClientCertificateListDeserializer;
 impl HsmClientCertificateListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<HsmClientCertificate>, XmlParseError> {
@@ -5782,10 +9087,10 @@ pub struct HsmClientCertificateMessage {
     pub marker: Option<String>,
 }
 
-struct HsmClientCertificateMessageDeserializer;
+pub(crate) struct HsmClientCertificateMessageDeserializer;
 impl HsmClientCertificateMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HsmClientCertificateMessage, XmlParseError> {
@@ -5827,10 +9132,10 @@ pub struct HsmConfiguration {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct HsmConfigurationDeserializer;
+pub(crate) struct HsmConfigurationDeserializer;
 impl HsmConfigurationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HsmConfiguration, XmlParseError> {
@@ -5864,10 +9169,10 @@ impl HsmConfigurationDeserializer {
         })
     }
 }
-struct HsmConfigurationListDeserializer;
+pub(crate) struct HsmConfigurationListDeserializer;
 impl HsmConfigurationListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<HsmConfiguration>, XmlParseError> {
@@ -5893,10 +9198,10 @@ pub struct HsmConfigurationMessage {
     pub marker: Option<String>,
 }
 
-struct HsmConfigurationMessageDeserializer;
+pub(crate) struct HsmConfigurationMessageDeserializer;
 impl HsmConfigurationMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HsmConfigurationMessage, XmlParseError> {
@@ -5934,10 +9239,10 @@ pub struct HsmStatus {
     pub status: Option<String>,
 }
 
-struct HsmStatusDeserializer;
+pub(crate) struct HsmStatusDeserializer;
 impl HsmStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<HsmStatus, XmlParseError> {
@@ -5975,10 +9280,10 @@ pub struct IPRange {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct IPRangeDeserializer;
+pub(crate) struct IPRangeDeserializer;
 impl IPRangeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<IPRange, XmlParseError> {
@@ -6001,10 +9306,10 @@ impl IPRangeDeserializer {
         })
     }
 }
-struct IPRangeListDeserializer;
+pub(crate) struct IPRangeListDeserializer;
 impl IPRangeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<IPRange>, XmlParseError> {
@@ -6030,10 +9335,10 @@ impl IamRoleArnListSerializer {
     }
 }
 
-struct ImportTablesCompletedDeserializer;
+pub(crate) struct ImportTablesCompletedDeserializer;
 impl ImportTablesCompletedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6047,10 +9352,10 @@ impl ImportTablesCompletedDeserializer {
         })
     }
 }
-struct ImportTablesInProgressDeserializer;
+pub(crate) struct ImportTablesInProgressDeserializer;
 impl ImportTablesInProgressDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6064,10 +9369,10 @@ impl ImportTablesInProgressDeserializer {
         })
     }
 }
-struct ImportTablesNotStartedDeserializer;
+pub(crate) struct ImportTablesNotStartedDeserializer;
 impl ImportTablesNotStartedDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -6081,10 +9386,13 @@ impl ImportTablesNotStartedDeserializer {
         })
     }
 }
-struct IntegerDeserializer;
+pub(crate) struct IntegerDeserializer;
 impl IntegerDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -6092,10 +9400,13 @@ impl IntegerDeserializer {
         Ok(obj)
     }
 }
-struct IntegerOptionalDeserializer;
+pub(crate) struct IntegerOptionalDeserializer;
 impl IntegerOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -6111,19 +9422,19 @@ pub struct LoggingStatus {
     /// <p>The message indicating that logs failed to be delivered.</p>
     pub last_failure_message: Option<String>,
     /// <p>The last time when logs failed to be delivered.</p>
-    pub last_failure_time: Option<String>,
+    pub last_failure_time: Option<IsoTimestamp>,
     /// <p>The last time that logs were delivered.</p>
-    pub last_successful_delivery_time: Option<String>,
+    pub last_successful_delivery_time: Option<IsoTimestamp>,
     /// <p> <code>true</code> if logging is on, <code>false</code> if logging is off.</p>
     pub logging_enabled: Option<bool>,
     /// <p>The prefix applied to the log file names.</p>
     pub s3_key_prefix: Option<String>,
 }
 
-struct LoggingStatusDeserializer;
+pub(crate) struct LoggingStatusDeserializer;
 impl LoggingStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<LoggingStatus, XmlParseError> {
@@ -6162,10 +9473,13 @@ impl LoggingStatusDeserializer {
         })
     }
 }
-struct LongDeserializer;
+pub(crate) struct LongDeserializer;
 impl LongDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -6173,10 +9487,13 @@ impl LongDeserializer {
         Ok(obj)
     }
 }
-struct LongOptionalDeserializer;
+pub(crate) struct LongOptionalDeserializer;
 impl LongOptionalDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<i64, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<i64, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = i64::from_str(characters(stack)?.as_ref()).unwrap();
         end_element(tag_name, stack)?;
@@ -6195,10 +9512,10 @@ pub struct MaintenanceTrack {
     pub update_targets: Option<Vec<UpdateTarget>>,
 }
 
-struct MaintenanceTrackDeserializer;
+pub(crate) struct MaintenanceTrackDeserializer;
 impl MaintenanceTrackDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<MaintenanceTrack, XmlParseError> {
@@ -6236,6 +9553,48 @@ pub struct ModifyClusterDbRevisionMessage {
     pub revision_target: String,
 }
 
+impl ModifyClusterDbRevisionMessage {
+    /// Returns a builder for `ModifyClusterDbRevisionMessage`, checking its required members when built.
+    pub fn builder() -> ModifyClusterDbRevisionMessageBuilder {
+        ModifyClusterDbRevisionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyClusterDbRevisionMessage`](struct.ModifyClusterDbRevisionMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyClusterDbRevisionMessageBuilder {
+    cluster_identifier: Option<String>,
+    revision_target: Option<String>,
+}
+
+impl ModifyClusterDbRevisionMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.ModifyClusterDbRevisionMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`revision_target`](struct.ModifyClusterDbRevisionMessage.html#structfield.revision_target) member.
+    pub fn revision_target<T: Into<String>>(mut self, value: T) -> Self {
+        self.revision_target = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyClusterDbRevisionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyClusterDbRevisionMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyClusterDbRevisionMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            revision_target: self
+                .revision_target
+                .ok_or(::rusoto_core::BuildError::missing("revision_target"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyClusterDbRevisionMessage` contents to a `SignedRequest`.
 struct ModifyClusterDbRevisionMessageSerializer;
 impl ModifyClusterDbRevisionMessageSerializer {
@@ -6261,10 +9620,10 @@ pub struct ModifyClusterDbRevisionResult {
     pub cluster: Option<Cluster>,
 }
 
-struct ModifyClusterDbRevisionResultDeserializer;
+pub(crate) struct ModifyClusterDbRevisionResultDeserializer;
 impl ModifyClusterDbRevisionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyClusterDbRevisionResult, XmlParseError> {
@@ -6294,6 +9653,54 @@ pub struct ModifyClusterIamRolesMessage {
     pub remove_iam_roles: Option<Vec<String>>,
 }
 
+impl ModifyClusterIamRolesMessage {
+    /// Returns a builder for `ModifyClusterIamRolesMessage`, checking its required members when built.
+    pub fn builder() -> ModifyClusterIamRolesMessageBuilder {
+        ModifyClusterIamRolesMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyClusterIamRolesMessage`](struct.ModifyClusterIamRolesMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyClusterIamRolesMessageBuilder {
+    add_iam_roles: Option<Vec<String>>,
+    cluster_identifier: Option<String>,
+    remove_iam_roles: Option<Vec<String>>,
+}
+
+impl ModifyClusterIamRolesMessageBuilder {
+    /// Sets the [`add_iam_roles`](struct.ModifyClusterIamRolesMessage.html#structfield.add_iam_roles) member.
+    pub fn add_iam_roles<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.add_iam_roles = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_identifier`](struct.ModifyClusterIamRolesMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`remove_iam_roles`](struct.ModifyClusterIamRolesMessage.html#structfield.remove_iam_roles) member.
+    pub fn remove_iam_roles<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.remove_iam_roles = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyClusterIamRolesMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyClusterIamRolesMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyClusterIamRolesMessage {
+            add_iam_roles: self.add_iam_roles,
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            remove_iam_roles: self.remove_iam_roles,
+        })
+    }
+}
+
 /// Serialize `ModifyClusterIamRolesMessage` contents to a `SignedRequest`.
 struct ModifyClusterIamRolesMessageSerializer;
 impl ModifyClusterIamRolesMessageSerializer {
@@ -6329,10 +9736,10 @@ pub struct ModifyClusterIamRolesResult {
     pub cluster: Option<Cluster>,
 }
 
-struct ModifyClusterIamRolesResultDeserializer;
+pub(crate) struct ModifyClusterIamRolesResultDeserializer;
 impl ModifyClusterIamRolesResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyClusterIamRolesResult, XmlParseError> {
@@ -6360,11 +9767,83 @@ pub struct ModifyClusterMaintenanceMessage {
     /// <p>An integer indicating the duration of the maintenance window in days. If you specify a duration, you can't specify an end time. The duration must be 45 days or less.</p>
     pub defer_maintenance_duration: Option<i64>,
     /// <p>A timestamp indicating end time for the deferred maintenance window. If you specify an end time, you can't specify a duration.</p>
-    pub defer_maintenance_end_time: Option<String>,
+    pub defer_maintenance_end_time: Option<IsoTimestamp>,
     /// <p>A unique identifier for the deferred maintenance window.</p>
     pub defer_maintenance_identifier: Option<String>,
     /// <p>A timestamp indicating the start time for the deferred maintenance window.</p>
-    pub defer_maintenance_start_time: Option<String>,
+    pub defer_maintenance_start_time: Option<IsoTimestamp>,
+}
+
+impl ModifyClusterMaintenanceMessage {
+    /// Returns a builder for `ModifyClusterMaintenanceMessage`, checking its required members when built.
+    pub fn builder() -> ModifyClusterMaintenanceMessageBuilder {
+        ModifyClusterMaintenanceMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyClusterMaintenanceMessage`](struct.ModifyClusterMaintenanceMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyClusterMaintenanceMessageBuilder {
+    cluster_identifier: Option<String>,
+    defer_maintenance: Option<bool>,
+    defer_maintenance_duration: Option<i64>,
+    defer_maintenance_end_time: Option<IsoTimestamp>,
+    defer_maintenance_identifier: Option<String>,
+    defer_maintenance_start_time: Option<IsoTimestamp>,
+}
+
+impl ModifyClusterMaintenanceMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.ModifyClusterMaintenanceMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`defer_maintenance`](struct.ModifyClusterMaintenanceMessage.html#structfield.defer_maintenance) member.
+    pub fn defer_maintenance<T: Into<bool>>(mut self, value: T) -> Self {
+        self.defer_maintenance = Some(value.into());
+        self
+    }
+
+    /// Sets the [`defer_maintenance_duration`](struct.ModifyClusterMaintenanceMessage.html#structfield.defer_maintenance_duration) member.
+    pub fn defer_maintenance_duration<T: Into<i64>>(mut self, value: T) -> Self {
+        self.defer_maintenance_duration = Some(value.into());
+        self
+    }
+
+    /// Sets the [`defer_maintenance_end_time`](struct.ModifyClusterMaintenanceMessage.html#structfield.defer_maintenance_end_time) member.
+    pub fn defer_maintenance_end_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.defer_maintenance_end_time = Some(value.into());
+        self
+    }
+
+    /// Sets the [`defer_maintenance_identifier`](struct.ModifyClusterMaintenanceMessage.html#structfield.defer_maintenance_identifier) member.
+    pub fn defer_maintenance_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.defer_maintenance_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`defer_maintenance_start_time`](struct.ModifyClusterMaintenanceMessage.html#structfield.defer_maintenance_start_time) member.
+    pub fn defer_maintenance_start_time<T: Into<IsoTimestamp>>(mut self, value: T) -> Self {
+        self.defer_maintenance_start_time = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyClusterMaintenanceMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyClusterMaintenanceMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyClusterMaintenanceMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            defer_maintenance: self.defer_maintenance,
+            defer_maintenance_duration: self.defer_maintenance_duration,
+            defer_maintenance_end_time: self.defer_maintenance_end_time,
+            defer_maintenance_identifier: self.defer_maintenance_identifier,
+            defer_maintenance_start_time: self.defer_maintenance_start_time,
+        })
+    }
 }
 
 /// Serialize `ModifyClusterMaintenanceMessage` contents to a `SignedRequest`.
@@ -6415,10 +9894,10 @@ pub struct ModifyClusterMaintenanceResult {
     pub cluster: Option<Cluster>,
 }
 
-struct ModifyClusterMaintenanceResultDeserializer;
+pub(crate) struct ModifyClusterMaintenanceResultDeserializer;
 impl ModifyClusterMaintenanceResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyClusterMaintenanceResult, XmlParseError> {
@@ -6486,6 +9965,204 @@ pub struct ModifyClusterMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl ModifyClusterMessage {
+    /// Returns a builder for `ModifyClusterMessage`, checking its required members when built.
+    pub fn builder() -> ModifyClusterMessageBuilder {
+        ModifyClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyClusterMessage`](struct.ModifyClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyClusterMessageBuilder {
+    allow_version_upgrade: Option<bool>,
+    automated_snapshot_retention_period: Option<i64>,
+    cluster_identifier: Option<String>,
+    cluster_parameter_group_name: Option<String>,
+    cluster_security_groups: Option<Vec<String>>,
+    cluster_type: Option<String>,
+    cluster_version: Option<String>,
+    elastic_ip: Option<String>,
+    encrypted: Option<bool>,
+    enhanced_vpc_routing: Option<bool>,
+    hsm_client_certificate_identifier: Option<String>,
+    hsm_configuration_identifier: Option<String>,
+    kms_key_id: Option<String>,
+    maintenance_track_name: Option<String>,
+    manual_snapshot_retention_period: Option<i64>,
+    master_user_password: Option<String>,
+    new_cluster_identifier: Option<String>,
+    node_type: Option<String>,
+    number_of_nodes: Option<i64>,
+    preferred_maintenance_window: Option<String>,
+    publicly_accessible: Option<bool>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl ModifyClusterMessageBuilder {
+    /// Sets the [`allow_version_upgrade`](struct.ModifyClusterMessage.html#structfield.allow_version_upgrade) member.
+    pub fn allow_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`automated_snapshot_retention_period`](struct.ModifyClusterMessage.html#structfield.automated_snapshot_retention_period) member.
+    pub fn automated_snapshot_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.automated_snapshot_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_identifier`](struct.ModifyClusterMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_parameter_group_name`](struct.ModifyClusterMessage.html#structfield.cluster_parameter_group_name) member.
+    pub fn cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_security_groups`](struct.ModifyClusterMessage.html#structfield.cluster_security_groups) member.
+    pub fn cluster_security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cluster_security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_type`](struct.ModifyClusterMessage.html#structfield.cluster_type) member.
+    pub fn cluster_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_version`](struct.ModifyClusterMessage.html#structfield.cluster_version) member.
+    pub fn cluster_version<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_version = Some(value.into());
+        self
+    }
+
+    /// Sets the [`elastic_ip`](struct.ModifyClusterMessage.html#structfield.elastic_ip) member.
+    pub fn elastic_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.elastic_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`encrypted`](struct.ModifyClusterMessage.html#structfield.encrypted) member.
+    pub fn encrypted<T: Into<bool>>(mut self, value: T) -> Self {
+        self.encrypted = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enhanced_vpc_routing`](struct.ModifyClusterMessage.html#structfield.enhanced_vpc_routing) member.
+    pub fn enhanced_vpc_routing<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enhanced_vpc_routing = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_client_certificate_identifier`](struct.ModifyClusterMessage.html#structfield.hsm_client_certificate_identifier) member.
+    pub fn hsm_client_certificate_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_client_certificate_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_configuration_identifier`](struct.ModifyClusterMessage.html#structfield.hsm_configuration_identifier) member.
+    pub fn hsm_configuration_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_configuration_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.ModifyClusterMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`maintenance_track_name`](struct.ModifyClusterMessage.html#structfield.maintenance_track_name) member.
+    pub fn maintenance_track_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.maintenance_track_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manual_snapshot_retention_period`](struct.ModifyClusterMessage.html#structfield.manual_snapshot_retention_period) member.
+    pub fn manual_snapshot_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.manual_snapshot_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`master_user_password`](struct.ModifyClusterMessage.html#structfield.master_user_password) member.
+    pub fn master_user_password<T: Into<String>>(mut self, value: T) -> Self {
+        self.master_user_password = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_cluster_identifier`](struct.ModifyClusterMessage.html#structfield.new_cluster_identifier) member.
+    pub fn new_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_type`](struct.ModifyClusterMessage.html#structfield.node_type) member.
+    pub fn node_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.node_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_nodes`](struct.ModifyClusterMessage.html#structfield.number_of_nodes) member.
+    pub fn number_of_nodes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_nodes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.ModifyClusterMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.ModifyClusterMessage.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.ModifyClusterMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ModifyClusterMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyClusterMessage {
+            allow_version_upgrade: self.allow_version_upgrade,
+            automated_snapshot_retention_period: self.automated_snapshot_retention_period,
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            cluster_parameter_group_name: self.cluster_parameter_group_name,
+            cluster_security_groups: self.cluster_security_groups,
+            cluster_type: self.cluster_type,
+            cluster_version: self.cluster_version,
+            elastic_ip: self.elastic_ip,
+            encrypted: self.encrypted,
+            enhanced_vpc_routing: self.enhanced_vpc_routing,
+            hsm_client_certificate_identifier: self.hsm_client_certificate_identifier,
+            hsm_configuration_identifier: self.hsm_configuration_identifier,
+            kms_key_id: self.kms_key_id,
+            maintenance_track_name: self.maintenance_track_name,
+            manual_snapshot_retention_period: self.manual_snapshot_retention_period,
+            master_user_password: self.master_user_password,
+            new_cluster_identifier: self.new_cluster_identifier,
+            node_type: self.node_type,
+            number_of_nodes: self.number_of_nodes,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            publicly_accessible: self.publicly_accessible,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `ModifyClusterMessage` contents to a `SignedRequest`.
 struct ModifyClusterMessageSerializer;
 impl ModifyClusterMessageSerializer {
@@ -6609,6 +10286,48 @@ pub struct ModifyClusterParameterGroupMessage {
     pub parameters: Vec<Parameter>,
 }
 
+impl ModifyClusterParameterGroupMessage {
+    /// Returns a builder for `ModifyClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyClusterParameterGroupMessageBuilder {
+        ModifyClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyClusterParameterGroupMessage`](struct.ModifyClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyClusterParameterGroupMessageBuilder {
+    parameter_group_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+}
+
+impl ModifyClusterParameterGroupMessageBuilder {
+    /// Sets the [`parameter_group_name`](struct.ModifyClusterParameterGroupMessage.html#structfield.parameter_group_name) member.
+    pub fn parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ModifyClusterParameterGroupMessage.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyClusterParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyClusterParameterGroupMessage {
+            parameter_group_name: self
+                .parameter_group_name
+                .ok_or(::rusoto_core::BuildError::missing("parameter_group_name"))?,
+            parameters: self
+                .parameters
+                .ok_or(::rusoto_core::BuildError::missing("parameters"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct ModifyClusterParameterGroupMessageSerializer;
 impl ModifyClusterParameterGroupMessageSerializer {
@@ -6635,10 +10354,10 @@ pub struct ModifyClusterResult {
     pub cluster: Option<Cluster>,
 }
 
-struct ModifyClusterResultDeserializer;
+pub(crate) struct ModifyClusterResultDeserializer;
 impl ModifyClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyClusterResult, XmlParseError> {
@@ -6663,6 +10382,54 @@ pub struct ModifyClusterSnapshotMessage {
     pub snapshot_identifier: String,
 }
 
+impl ModifyClusterSnapshotMessage {
+    /// Returns a builder for `ModifyClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> ModifyClusterSnapshotMessageBuilder {
+        ModifyClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyClusterSnapshotMessage`](struct.ModifyClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyClusterSnapshotMessageBuilder {
+    force: Option<bool>,
+    manual_snapshot_retention_period: Option<i64>,
+    snapshot_identifier: Option<String>,
+}
+
+impl ModifyClusterSnapshotMessageBuilder {
+    /// Sets the [`force`](struct.ModifyClusterSnapshotMessage.html#structfield.force) member.
+    pub fn force<T: Into<bool>>(mut self, value: T) -> Self {
+        self.force = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manual_snapshot_retention_period`](struct.ModifyClusterSnapshotMessage.html#structfield.manual_snapshot_retention_period) member.
+    pub fn manual_snapshot_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.manual_snapshot_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_identifier`](struct.ModifyClusterSnapshotMessage.html#structfield.snapshot_identifier) member.
+    pub fn snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyClusterSnapshotMessage {
+            force: self.force,
+            manual_snapshot_retention_period: self.manual_snapshot_retention_period,
+            snapshot_identifier: self
+                .snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyClusterSnapshotMessage` contents to a `SignedRequest`.
 struct ModifyClusterSnapshotMessageSerializer;
 impl ModifyClusterSnapshotMessageSerializer {
@@ -6693,10 +10460,10 @@ pub struct ModifyClusterSnapshotResult {
     pub snapshot: Option<Snapshot>,
 }
 
-struct ModifyClusterSnapshotResultDeserializer;
+pub(crate) struct ModifyClusterSnapshotResultDeserializer;
 impl ModifyClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyClusterSnapshotResult, XmlParseError> {
@@ -6725,6 +10492,55 @@ pub struct ModifyClusterSnapshotScheduleMessage {
     pub schedule_identifier: Option<String>,
 }
 
+impl ModifyClusterSnapshotScheduleMessage {
+    /// Returns a builder for `ModifyClusterSnapshotScheduleMessage`, checking its required members when built.
+    pub fn builder() -> ModifyClusterSnapshotScheduleMessageBuilder {
+        ModifyClusterSnapshotScheduleMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyClusterSnapshotScheduleMessage`](struct.ModifyClusterSnapshotScheduleMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyClusterSnapshotScheduleMessageBuilder {
+    cluster_identifier: Option<String>,
+    disassociate_schedule: Option<bool>,
+    schedule_identifier: Option<String>,
+}
+
+impl ModifyClusterSnapshotScheduleMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.ModifyClusterSnapshotScheduleMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`disassociate_schedule`](struct.ModifyClusterSnapshotScheduleMessage.html#structfield.disassociate_schedule) member.
+    pub fn disassociate_schedule<T: Into<bool>>(mut self, value: T) -> Self {
+        self.disassociate_schedule = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule_identifier`](struct.ModifyClusterSnapshotScheduleMessage.html#structfield.schedule_identifier) member.
+    pub fn schedule_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyClusterSnapshotScheduleMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyClusterSnapshotScheduleMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ModifyClusterSnapshotScheduleMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            disassociate_schedule: self.disassociate_schedule,
+            schedule_identifier: self.schedule_identifier,
+        })
+    }
+}
+
 /// Serialize `ModifyClusterSnapshotScheduleMessage` contents to a `SignedRequest`.
 struct ModifyClusterSnapshotScheduleMessageSerializer;
 impl ModifyClusterSnapshotScheduleMessageSerializer {
@@ -6761,6 +10577,56 @@ pub struct ModifyClusterSubnetGroupMessage {
     pub subnet_ids: Vec<String>,
 }
 
+impl ModifyClusterSubnetGroupMessage {
+    /// Returns a builder for `ModifyClusterSubnetGroupMessage`, checking its required members when built.
+    pub fn builder() -> ModifyClusterSubnetGroupMessageBuilder {
+        ModifyClusterSubnetGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyClusterSubnetGroupMessage`](struct.ModifyClusterSubnetGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyClusterSubnetGroupMessageBuilder {
+    cluster_subnet_group_name: Option<String>,
+    description: Option<String>,
+    subnet_ids: Option<Vec<String>>,
+}
+
+impl ModifyClusterSubnetGroupMessageBuilder {
+    /// Sets the [`cluster_subnet_group_name`](struct.ModifyClusterSubnetGroupMessage.html#structfield.cluster_subnet_group_name) member.
+    pub fn cluster_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`description`](struct.ModifyClusterSubnetGroupMessage.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subnet_ids`](struct.ModifyClusterSubnetGroupMessage.html#structfield.subnet_ids) member.
+    pub fn subnet_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.subnet_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyClusterSubnetGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyClusterSubnetGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyClusterSubnetGroupMessage {
+            cluster_subnet_group_name: self.cluster_subnet_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cluster_subnet_group_name"),
+            )?,
+            description: self.description,
+            subnet_ids: self
+                .subnet_ids
+                .ok_or(::rusoto_core::BuildError::missing("subnet_ids"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyClusterSubnetGroupMessage` contents to a `SignedRequest`.
 struct ModifyClusterSubnetGroupMessageSerializer;
 impl ModifyClusterSubnetGroupMessageSerializer {
@@ -6790,10 +10656,10 @@ pub struct ModifyClusterSubnetGroupResult {
     pub cluster_subnet_group: Option<ClusterSubnetGroup>,
 }
 
-struct ModifyClusterSubnetGroupResultDeserializer;
+pub(crate) struct ModifyClusterSubnetGroupResultDeserializer;
 impl ModifyClusterSubnetGroupResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyClusterSubnetGroupResult, XmlParseError> {
@@ -6835,6 +10701,86 @@ pub struct ModifyEventSubscriptionMessage {
     pub subscription_name: String,
 }
 
+impl ModifyEventSubscriptionMessage {
+    /// Returns a builder for `ModifyEventSubscriptionMessage`, checking its required members when built.
+    pub fn builder() -> ModifyEventSubscriptionMessageBuilder {
+        ModifyEventSubscriptionMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifyEventSubscriptionMessage`](struct.ModifyEventSubscriptionMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifyEventSubscriptionMessageBuilder {
+    enabled: Option<bool>,
+    event_categories: Option<Vec<String>>,
+    severity: Option<String>,
+    sns_topic_arn: Option<String>,
+    source_ids: Option<Vec<String>>,
+    source_type: Option<String>,
+    subscription_name: Option<String>,
+}
+
+impl ModifyEventSubscriptionMessageBuilder {
+    /// Sets the [`enabled`](struct.ModifyEventSubscriptionMessage.html#structfield.enabled) member.
+    pub fn enabled<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enabled = Some(value.into());
+        self
+    }
+
+    /// Sets the [`event_categories`](struct.ModifyEventSubscriptionMessage.html#structfield.event_categories) member.
+    pub fn event_categories<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.event_categories = Some(value.into());
+        self
+    }
+
+    /// Sets the [`severity`](struct.ModifyEventSubscriptionMessage.html#structfield.severity) member.
+    pub fn severity<T: Into<String>>(mut self, value: T) -> Self {
+        self.severity = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sns_topic_arn`](struct.ModifyEventSubscriptionMessage.html#structfield.sns_topic_arn) member.
+    pub fn sns_topic_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.sns_topic_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_ids`](struct.ModifyEventSubscriptionMessage.html#structfield.source_ids) member.
+    pub fn source_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.source_ids = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_type`](struct.ModifyEventSubscriptionMessage.html#structfield.source_type) member.
+    pub fn source_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`subscription_name`](struct.ModifyEventSubscriptionMessage.html#structfield.subscription_name) member.
+    pub fn subscription_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.subscription_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifyEventSubscriptionMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifyEventSubscriptionMessage, ::rusoto_core::BuildError> {
+        Ok(ModifyEventSubscriptionMessage {
+            enabled: self.enabled,
+            event_categories: self.event_categories,
+            severity: self.severity,
+            sns_topic_arn: self.sns_topic_arn,
+            source_ids: self.source_ids,
+            source_type: self.source_type,
+            subscription_name: self
+                .subscription_name
+                .ok_or(::rusoto_core::BuildError::missing("subscription_name"))?,
+        })
+    }
+}
+
 /// Serialize `ModifyEventSubscriptionMessage` contents to a `SignedRequest`.
 struct ModifyEventSubscriptionMessageSerializer;
 impl ModifyEventSubscriptionMessageSerializer {
@@ -6882,10 +10828,10 @@ pub struct ModifyEventSubscriptionResult {
     pub event_subscription: Option<EventSubscription>,
 }
 
-struct ModifyEventSubscriptionResultDeserializer;
+pub(crate) struct ModifyEventSubscriptionResultDeserializer;
 impl ModifyEventSubscriptionResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifyEventSubscriptionResult, XmlParseError> {
@@ -6918,6 +10864,57 @@ pub struct ModifySnapshotCopyRetentionPeriodMessage {
     pub retention_period: i64,
 }
 
+impl ModifySnapshotCopyRetentionPeriodMessage {
+    /// Returns a builder for `ModifySnapshotCopyRetentionPeriodMessage`, checking its required members when built.
+    pub fn builder() -> ModifySnapshotCopyRetentionPeriodMessageBuilder {
+        ModifySnapshotCopyRetentionPeriodMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifySnapshotCopyRetentionPeriodMessage`](struct.ModifySnapshotCopyRetentionPeriodMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifySnapshotCopyRetentionPeriodMessageBuilder {
+    cluster_identifier: Option<String>,
+    manual: Option<bool>,
+    retention_period: Option<i64>,
+}
+
+impl ModifySnapshotCopyRetentionPeriodMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.ModifySnapshotCopyRetentionPeriodMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manual`](struct.ModifySnapshotCopyRetentionPeriodMessage.html#structfield.manual) member.
+    pub fn manual<T: Into<bool>>(mut self, value: T) -> Self {
+        self.manual = Some(value.into());
+        self
+    }
+
+    /// Sets the [`retention_period`](struct.ModifySnapshotCopyRetentionPeriodMessage.html#structfield.retention_period) member.
+    pub fn retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.retention_period = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifySnapshotCopyRetentionPeriodMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifySnapshotCopyRetentionPeriodMessage, ::rusoto_core::BuildError>
+    {
+        Ok(ModifySnapshotCopyRetentionPeriodMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            manual: self.manual,
+            retention_period: self
+                .retention_period
+                .ok_or(::rusoto_core::BuildError::missing("retention_period"))?,
+        })
+    }
+}
+
 /// Serialize `ModifySnapshotCopyRetentionPeriodMessage` contents to a `SignedRequest`.
 struct ModifySnapshotCopyRetentionPeriodMessageSerializer;
 impl ModifySnapshotCopyRetentionPeriodMessageSerializer {
@@ -6946,10 +10943,10 @@ pub struct ModifySnapshotCopyRetentionPeriodResult {
     pub cluster: Option<Cluster>,
 }
 
-struct ModifySnapshotCopyRetentionPeriodResultDeserializer;
+pub(crate) struct ModifySnapshotCopyRetentionPeriodResultDeserializer;
 impl ModifySnapshotCopyRetentionPeriodResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ModifySnapshotCopyRetentionPeriodResult, XmlParseError> {
@@ -6976,14 +10973,56 @@ pub struct ModifySnapshotScheduleMessage {
     pub schedule_identifier: String,
 }
 
-/// Serialize `ModifySnapshotScheduleMessage` contents to a `SignedRequest`.
-struct ModifySnapshotScheduleMessageSerializer;
-impl ModifySnapshotScheduleMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &ModifySnapshotScheduleMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
-            prefix.push_str(".");
-        }
+impl ModifySnapshotScheduleMessage {
+    /// Returns a builder for `ModifySnapshotScheduleMessage`, checking its required members when built.
+    pub fn builder() -> ModifySnapshotScheduleMessageBuilder {
+        ModifySnapshotScheduleMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ModifySnapshotScheduleMessage`](struct.ModifySnapshotScheduleMessage.html).
+#[derive(Default, Debug)]
+pub struct ModifySnapshotScheduleMessageBuilder {
+    schedule_definitions: Option<Vec<String>>,
+    schedule_identifier: Option<String>,
+}
+
+impl ModifySnapshotScheduleMessageBuilder {
+    /// Sets the [`schedule_definitions`](struct.ModifySnapshotScheduleMessage.html#structfield.schedule_definitions) member.
+    pub fn schedule_definitions<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.schedule_definitions = Some(value.into());
+        self
+    }
+
+    /// Sets the [`schedule_identifier`](struct.ModifySnapshotScheduleMessage.html#structfield.schedule_identifier) member.
+    pub fn schedule_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.schedule_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `ModifySnapshotScheduleMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ModifySnapshotScheduleMessage, ::rusoto_core::BuildError> {
+        Ok(ModifySnapshotScheduleMessage {
+            schedule_definitions: self
+                .schedule_definitions
+                .ok_or(::rusoto_core::BuildError::missing("schedule_definitions"))?,
+            schedule_identifier: self
+                .schedule_identifier
+                .ok_or(::rusoto_core::BuildError::missing("schedule_identifier"))?,
+        })
+    }
+}
+
+/// Serialize `ModifySnapshotScheduleMessage` contents to a `SignedRequest`.
+struct ModifySnapshotScheduleMessageSerializer;
+impl ModifySnapshotScheduleMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &ModifySnapshotScheduleMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
+            prefix.push_str(".");
+        }
 
         ScheduleDefinitionListSerializer::serialize(
             params,
@@ -7010,10 +11049,10 @@ pub struct OrderableClusterOption {
     pub node_type: Option<String>,
 }
 
-struct OrderableClusterOptionDeserializer;
+pub(crate) struct OrderableClusterOptionDeserializer;
 impl OrderableClusterOptionDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OrderableClusterOption, XmlParseError> {
@@ -7040,10 +11079,10 @@ impl OrderableClusterOptionDeserializer {
         })
     }
 }
-struct OrderableClusterOptionsListDeserializer;
+pub(crate) struct OrderableClusterOptionsListDeserializer;
 impl OrderableClusterOptionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<OrderableClusterOption>, XmlParseError> {
@@ -7069,10 +11108,10 @@ pub struct OrderableClusterOptionsMessage {
     pub orderable_cluster_options: Option<Vec<OrderableClusterOption>>,
 }
 
-struct OrderableClusterOptionsMessageDeserializer;
+pub(crate) struct OrderableClusterOptionsMessageDeserializer;
 impl OrderableClusterOptionsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<OrderableClusterOptionsMessage, XmlParseError> {
@@ -7105,7 +11144,7 @@ pub struct Parameter {
     /// <p>The valid range of values for the parameter.</p>
     pub allowed_values: Option<String>,
     /// <p>Specifies how to apply the WLM configuration parameter. Some properties can be applied dynamically, while other properties require that any associated clusters be rebooted for the configuration changes to be applied. For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
-    pub apply_type: Option<String>,
+    pub apply_type: Option<ParameterApplyType>,
     /// <p>The data type of the parameter.</p>
     pub data_type: Option<String>,
     /// <p>A description of the parameter.</p>
@@ -7122,10 +11161,10 @@ pub struct Parameter {
     pub source: Option<String>,
 }
 
-struct ParameterDeserializer;
+pub(crate) struct ParameterDeserializer;
 impl ParameterDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Parameter, XmlParseError> {
@@ -7217,21 +11256,83 @@ impl ParameterSerializer {
     }
 }
 
-struct ParameterApplyTypeDeserializer;
+pub(crate) struct ParameterApplyTypeDeserializer;
 impl ParameterApplyTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ParameterApplyType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ParameterGroupListDeserializer;
+/// <p>Specifies how to apply the WLM configuration parameter. Some properties can be applied dynamically, while other properties require that any associated clusters be rebooted for the configuration changes to be applied. For more information about parameters and parameter groups, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-parameter-groups.html">Amazon Redshift Parameter Groups</a> in the <i>Amazon Redshift Cluster Management Guide</i>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParameterApplyType {
+    /// <p><code>static</code></p>
+    Static,
+    /// <p><code>dynamic</code></p>
+    Dynamic,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ParameterApplyType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ParameterApplyType::Static => "static",
+            ParameterApplyType::Dynamic => "dynamic",
+            ParameterApplyType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ParameterApplyType {
+    fn from(value: &'a str) -> ParameterApplyType {
+        match value {
+            "static" => ParameterApplyType::Static,
+            "dynamic" => ParameterApplyType::Dynamic,
+            _ => ParameterApplyType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ParameterApplyType {
+    fn from(value: String) -> ParameterApplyType {
+        ParameterApplyType::from(value.as_str())
+    }
+}
+
+impl From<ParameterApplyType> for String {
+    fn from(value: ParameterApplyType) -> String {
+        match value {
+            ParameterApplyType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParameterApplyType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ParameterApplyType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ParameterGroupListDeserializer;
 impl ParameterGroupListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ClusterParameterGroup>, XmlParseError> {
@@ -7248,10 +11349,10 @@ impl ParameterGroupListDeserializer {
         })
     }
 }
-struct ParametersListDeserializer;
+pub(crate) struct ParametersListDeserializer;
 impl ParametersListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Parameter>, XmlParseError> {
@@ -7277,10 +11378,10 @@ impl ParametersListSerializer {
     }
 }
 
-struct PendingActionsListDeserializer;
+pub(crate) struct PendingActionsListDeserializer;
 impl PendingActionsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -7321,10 +11422,10 @@ pub struct PendingModifiedValues {
     pub publicly_accessible: Option<bool>,
 }
 
-struct PendingModifiedValuesDeserializer;
+pub(crate) struct PendingModifiedValuesDeserializer;
 impl PendingModifiedValuesDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PendingModifiedValues, XmlParseError> {
@@ -7400,6 +11501,46 @@ pub struct PurchaseReservedNodeOfferingMessage {
     pub reserved_node_offering_id: String,
 }
 
+impl PurchaseReservedNodeOfferingMessage {
+    /// Returns a builder for `PurchaseReservedNodeOfferingMessage`, checking its required members when built.
+    pub fn builder() -> PurchaseReservedNodeOfferingMessageBuilder {
+        PurchaseReservedNodeOfferingMessageBuilder::default()
+    }
+}
+
+/// Builds a [`PurchaseReservedNodeOfferingMessage`](struct.PurchaseReservedNodeOfferingMessage.html).
+#[derive(Default, Debug)]
+pub struct PurchaseReservedNodeOfferingMessageBuilder {
+    node_count: Option<i64>,
+    reserved_node_offering_id: Option<String>,
+}
+
+impl PurchaseReservedNodeOfferingMessageBuilder {
+    /// Sets the [`node_count`](struct.PurchaseReservedNodeOfferingMessage.html#structfield.node_count) member.
+    pub fn node_count<T: Into<i64>>(mut self, value: T) -> Self {
+        self.node_count = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reserved_node_offering_id`](struct.PurchaseReservedNodeOfferingMessage.html#structfield.reserved_node_offering_id) member.
+    pub fn reserved_node_offering_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.reserved_node_offering_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `PurchaseReservedNodeOfferingMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<PurchaseReservedNodeOfferingMessage, ::rusoto_core::BuildError> {
+        Ok(PurchaseReservedNodeOfferingMessage {
+            node_count: self.node_count,
+            reserved_node_offering_id: self.reserved_node_offering_id.ok_or(
+                ::rusoto_core::BuildError::missing("reserved_node_offering_id"),
+            )?,
+        })
+    }
+}
+
 /// Serialize `PurchaseReservedNodeOfferingMessage` contents to a `SignedRequest`.
 struct PurchaseReservedNodeOfferingMessageSerializer;
 impl PurchaseReservedNodeOfferingMessageSerializer {
@@ -7424,10 +11565,10 @@ pub struct PurchaseReservedNodeOfferingResult {
     pub reserved_node: Option<ReservedNode>,
 }
 
-struct PurchaseReservedNodeOfferingResultDeserializer;
+pub(crate) struct PurchaseReservedNodeOfferingResultDeserializer;
 impl PurchaseReservedNodeOfferingResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<PurchaseReservedNodeOfferingResult, XmlParseError> {
@@ -7456,6 +11597,36 @@ pub struct RebootClusterMessage {
     pub cluster_identifier: String,
 }
 
+impl RebootClusterMessage {
+    /// Returns a builder for `RebootClusterMessage`, checking its required members when built.
+    pub fn builder() -> RebootClusterMessageBuilder {
+        RebootClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RebootClusterMessage`](struct.RebootClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct RebootClusterMessageBuilder {
+    cluster_identifier: Option<String>,
+}
+
+impl RebootClusterMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.RebootClusterMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `RebootClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<RebootClusterMessage, ::rusoto_core::BuildError> {
+        Ok(RebootClusterMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `RebootClusterMessage` contents to a `SignedRequest`.
 struct RebootClusterMessageSerializer;
 impl RebootClusterMessageSerializer {
@@ -7477,10 +11648,10 @@ pub struct RebootClusterResult {
     pub cluster: Option<Cluster>,
 }
 
-struct RebootClusterResultDeserializer;
+pub(crate) struct RebootClusterResultDeserializer;
 impl RebootClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RebootClusterResult, XmlParseError> {
@@ -7504,10 +11675,10 @@ pub struct RecurringCharge {
     pub recurring_charge_frequency: Option<String>,
 }
 
-struct RecurringChargeDeserializer;
+pub(crate) struct RecurringChargeDeserializer;
 impl RecurringChargeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RecurringCharge, XmlParseError> {
@@ -7531,10 +11702,10 @@ impl RecurringChargeDeserializer {
         })
     }
 }
-struct RecurringChargeListDeserializer;
+pub(crate) struct RecurringChargeListDeserializer;
 impl RecurringChargeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<RecurringCharge>, XmlParseError> {
@@ -7573,19 +11744,19 @@ pub struct ReservedNode {
     /// <p>The identifier for the reserved node offering.</p>
     pub reserved_node_offering_id: Option<String>,
     /// <p><p/></p>
-    pub reserved_node_offering_type: Option<String>,
+    pub reserved_node_offering_type: Option<ReservedNodeOfferingType>,
     /// <p>The time the reservation started. You purchase a reserved node offering for a duration. This is the start time of that duration.</p>
-    pub start_time: Option<String>,
+    pub start_time: Option<IsoTimestamp>,
     /// <p><p>The state of the reserved compute node.</p> <p>Possible Values:</p> <ul> <li> <p>pending-payment-This reserved node has recently been purchased, and the sale has been approved, but payment has not yet been confirmed.</p> </li> <li> <p>active-This reserved node is owned by the caller and is available for use.</p> </li> <li> <p>payment-failed-Payment failed for the purchase attempt.</p> </li> <li> <p>retired-The reserved node is no longer available. </p> </li> <li> <p>exchanging-The owner is exchanging the reserved node for another reserved node.</p> </li> </ul></p>
     pub state: Option<String>,
     /// <p>The hourly rate Amazon Redshift charges you for this reserved node.</p>
     pub usage_price: Option<f64>,
 }
 
-struct ReservedNodeDeserializer;
+pub(crate) struct ReservedNodeDeserializer;
 impl ReservedNodeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReservedNode, XmlParseError> {
@@ -7648,10 +11819,10 @@ impl ReservedNodeDeserializer {
         })
     }
 }
-struct ReservedNodeListDeserializer;
+pub(crate) struct ReservedNodeListDeserializer;
 impl ReservedNodeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ReservedNode>, XmlParseError> {
@@ -7686,15 +11857,15 @@ pub struct ReservedNodeOffering {
     /// <p>The offering identifier.</p>
     pub reserved_node_offering_id: Option<String>,
     /// <p><p/></p>
-    pub reserved_node_offering_type: Option<String>,
+    pub reserved_node_offering_type: Option<ReservedNodeOfferingType>,
     /// <p>The rate you are charged for each hour the cluster that is using the offering is running.</p>
     pub usage_price: Option<f64>,
 }
 
-struct ReservedNodeOfferingDeserializer;
+pub(crate) struct ReservedNodeOfferingDeserializer;
 impl ReservedNodeOfferingDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReservedNodeOffering, XmlParseError> {
@@ -7744,10 +11915,10 @@ impl ReservedNodeOfferingDeserializer {
         })
     }
 }
-struct ReservedNodeOfferingListDeserializer;
+pub(crate) struct ReservedNodeOfferingListDeserializer;
 impl ReservedNodeOfferingListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<ReservedNodeOffering>, XmlParseError> {
@@ -7764,17 +11935,79 @@ impl ReservedNodeOfferingListDeserializer {
         })
     }
 }
-struct ReservedNodeOfferingTypeDeserializer;
+pub(crate) struct ReservedNodeOfferingTypeDeserializer;
 impl ReservedNodeOfferingTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ReservedNodeOfferingType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p><p/></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ReservedNodeOfferingType {
+    /// <p><code>Regular</code></p>
+    Regular,
+    /// <p><code>Upgradable</code></p>
+    Upgradable,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ReservedNodeOfferingType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ReservedNodeOfferingType::Regular => "Regular",
+            ReservedNodeOfferingType::Upgradable => "Upgradable",
+            ReservedNodeOfferingType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ReservedNodeOfferingType {
+    fn from(value: &'a str) -> ReservedNodeOfferingType {
+        match value {
+            "Regular" => ReservedNodeOfferingType::Regular,
+            "Upgradable" => ReservedNodeOfferingType::Upgradable,
+            _ => ReservedNodeOfferingType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ReservedNodeOfferingType {
+    fn from(value: String) -> ReservedNodeOfferingType {
+        ReservedNodeOfferingType::from(value.as_str())
+    }
+}
+
+impl From<ReservedNodeOfferingType> for String {
+    fn from(value: ReservedNodeOfferingType) -> String {
+        match value {
+            ReservedNodeOfferingType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ReservedNodeOfferingType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ReservedNodeOfferingType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p><p/></p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct ReservedNodeOfferingsMessage {
@@ -7784,10 +12017,10 @@ pub struct ReservedNodeOfferingsMessage {
     pub reserved_node_offerings: Option<Vec<ReservedNodeOffering>>,
 }
 
-struct ReservedNodeOfferingsMessageDeserializer;
+pub(crate) struct ReservedNodeOfferingsMessageDeserializer;
 impl ReservedNodeOfferingsMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReservedNodeOfferingsMessage, XmlParseError> {
@@ -7823,10 +12056,10 @@ pub struct ReservedNodesMessage {
     pub reserved_nodes: Option<Vec<ReservedNode>>,
 }
 
-struct ReservedNodesMessageDeserializer;
+pub(crate) struct ReservedNodesMessageDeserializer;
 impl ReservedNodesMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ReservedNodesMessage, XmlParseError> {
@@ -7857,6 +12090,54 @@ pub struct ResetClusterParameterGroupMessage {
     pub reset_all_parameters: Option<bool>,
 }
 
+impl ResetClusterParameterGroupMessage {
+    /// Returns a builder for `ResetClusterParameterGroupMessage`, checking its required members when built.
+    pub fn builder() -> ResetClusterParameterGroupMessageBuilder {
+        ResetClusterParameterGroupMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ResetClusterParameterGroupMessage`](struct.ResetClusterParameterGroupMessage.html).
+#[derive(Default, Debug)]
+pub struct ResetClusterParameterGroupMessageBuilder {
+    parameter_group_name: Option<String>,
+    parameters: Option<Vec<Parameter>>,
+    reset_all_parameters: Option<bool>,
+}
+
+impl ResetClusterParameterGroupMessageBuilder {
+    /// Sets the [`parameter_group_name`](struct.ResetClusterParameterGroupMessage.html#structfield.parameter_group_name) member.
+    pub fn parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`parameters`](struct.ResetClusterParameterGroupMessage.html#structfield.parameters) member.
+    pub fn parameters<T: Into<Vec<Parameter>>>(mut self, value: T) -> Self {
+        self.parameters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`reset_all_parameters`](struct.ResetClusterParameterGroupMessage.html#structfield.reset_all_parameters) member.
+    pub fn reset_all_parameters<T: Into<bool>>(mut self, value: T) -> Self {
+        self.reset_all_parameters = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResetClusterParameterGroupMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ResetClusterParameterGroupMessage, ::rusoto_core::BuildError> {
+        Ok(ResetClusterParameterGroupMessage {
+            parameter_group_name: self
+                .parameter_group_name
+                .ok_or(::rusoto_core::BuildError::missing("parameter_group_name"))?,
+            parameters: self.parameters,
+            reset_all_parameters: self.reset_all_parameters,
+        })
+    }
+}
+
 /// Serialize `ResetClusterParameterGroupMessage` contents to a `SignedRequest`.
 struct ResetClusterParameterGroupMessageSerializer;
 impl ResetClusterParameterGroupMessageSerializer {
@@ -7897,6 +12178,70 @@ pub struct ResizeClusterMessage {
     pub number_of_nodes: i64,
 }
 
+impl ResizeClusterMessage {
+    /// Returns a builder for `ResizeClusterMessage`, checking its required members when built.
+    pub fn builder() -> ResizeClusterMessageBuilder {
+        ResizeClusterMessageBuilder::default()
+    }
+}
+
+/// Builds a [`ResizeClusterMessage`](struct.ResizeClusterMessage.html).
+#[derive(Default, Debug)]
+pub struct ResizeClusterMessageBuilder {
+    classic: Option<bool>,
+    cluster_identifier: Option<String>,
+    cluster_type: Option<String>,
+    node_type: Option<String>,
+    number_of_nodes: Option<i64>,
+}
+
+impl ResizeClusterMessageBuilder {
+    /// Sets the [`classic`](struct.ResizeClusterMessage.html#structfield.classic) member.
+    pub fn classic<T: Into<bool>>(mut self, value: T) -> Self {
+        self.classic = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_identifier`](struct.ResizeClusterMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_type`](struct.ResizeClusterMessage.html#structfield.cluster_type) member.
+    pub fn cluster_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_type`](struct.ResizeClusterMessage.html#structfield.node_type) member.
+    pub fn node_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.node_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`number_of_nodes`](struct.ResizeClusterMessage.html#structfield.number_of_nodes) member.
+    pub fn number_of_nodes<T: Into<i64>>(mut self, value: T) -> Self {
+        self.number_of_nodes = Some(value.into());
+        self
+    }
+
+    /// Builds the `ResizeClusterMessage`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ResizeClusterMessage, ::rusoto_core::BuildError> {
+        Ok(ResizeClusterMessage {
+            classic: self.classic,
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            cluster_type: self.cluster_type,
+            node_type: self.node_type,
+            number_of_nodes: self
+                .number_of_nodes
+                .ok_or(::rusoto_core::BuildError::missing("number_of_nodes"))?,
+        })
+    }
+}
+
 /// Serialize `ResizeClusterMessage` contents to a `SignedRequest`.
 struct ResizeClusterMessageSerializer;
 impl ResizeClusterMessageSerializer {
@@ -7931,10 +12276,10 @@ pub struct ResizeClusterResult {
     pub cluster: Option<Cluster>,
 }
 
-struct ResizeClusterResultDeserializer;
+pub(crate) struct ResizeClusterResultDeserializer;
 impl ResizeClusterResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResizeClusterResult, XmlParseError> {
@@ -7958,10 +12303,10 @@ pub struct ResizeInfo {
     pub resize_type: Option<String>,
 }
 
-struct ResizeInfoDeserializer;
+pub(crate) struct ResizeInfoDeserializer;
 impl ResizeInfoDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResizeInfo, XmlParseError> {
@@ -8019,10 +12364,10 @@ pub struct ResizeProgressMessage {
     pub total_resize_data_in_mega_bytes: Option<i64>,
 }
 
-struct ResizeProgressMessageDeserializer;
+pub(crate) struct ResizeProgressMessageDeserializer;
 impl ResizeProgressMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<ResizeProgressMessage, XmlParseError> {
@@ -8125,10 +12470,10 @@ impl ResizeProgressMessageDeserializer {
         })
     }
 }
-struct RestorableNodeTypeListDeserializer;
+pub(crate) struct RestorableNodeTypeListDeserializer;
 impl RestorableNodeTypeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -8197,6 +12542,232 @@ pub struct RestoreFromClusterSnapshotMessage {
     pub vpc_security_group_ids: Option<Vec<String>>,
 }
 
+impl RestoreFromClusterSnapshotMessage {
+    /// Returns a builder for `RestoreFromClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> RestoreFromClusterSnapshotMessageBuilder {
+        RestoreFromClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreFromClusterSnapshotMessage`](struct.RestoreFromClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct RestoreFromClusterSnapshotMessageBuilder {
+    additional_info: Option<String>,
+    allow_version_upgrade: Option<bool>,
+    automated_snapshot_retention_period: Option<i64>,
+    availability_zone: Option<String>,
+    cluster_identifier: Option<String>,
+    cluster_parameter_group_name: Option<String>,
+    cluster_security_groups: Option<Vec<String>>,
+    cluster_subnet_group_name: Option<String>,
+    elastic_ip: Option<String>,
+    enhanced_vpc_routing: Option<bool>,
+    hsm_client_certificate_identifier: Option<String>,
+    hsm_configuration_identifier: Option<String>,
+    iam_roles: Option<Vec<String>>,
+    kms_key_id: Option<String>,
+    maintenance_track_name: Option<String>,
+    manual_snapshot_retention_period: Option<i64>,
+    node_type: Option<String>,
+    owner_account: Option<String>,
+    port: Option<i64>,
+    preferred_maintenance_window: Option<String>,
+    publicly_accessible: Option<bool>,
+    snapshot_cluster_identifier: Option<String>,
+    snapshot_identifier: Option<String>,
+    snapshot_schedule_identifier: Option<String>,
+    vpc_security_group_ids: Option<Vec<String>>,
+}
+
+impl RestoreFromClusterSnapshotMessageBuilder {
+    /// Sets the [`additional_info`](struct.RestoreFromClusterSnapshotMessage.html#structfield.additional_info) member.
+    pub fn additional_info<T: Into<String>>(mut self, value: T) -> Self {
+        self.additional_info = Some(value.into());
+        self
+    }
+
+    /// Sets the [`allow_version_upgrade`](struct.RestoreFromClusterSnapshotMessage.html#structfield.allow_version_upgrade) member.
+    pub fn allow_version_upgrade<T: Into<bool>>(mut self, value: T) -> Self {
+        self.allow_version_upgrade = Some(value.into());
+        self
+    }
+
+    /// Sets the [`automated_snapshot_retention_period`](struct.RestoreFromClusterSnapshotMessage.html#structfield.automated_snapshot_retention_period) member.
+    pub fn automated_snapshot_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.automated_snapshot_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`availability_zone`](struct.RestoreFromClusterSnapshotMessage.html#structfield.availability_zone) member.
+    pub fn availability_zone<T: Into<String>>(mut self, value: T) -> Self {
+        self.availability_zone = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_identifier`](struct.RestoreFromClusterSnapshotMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_parameter_group_name`](struct.RestoreFromClusterSnapshotMessage.html#structfield.cluster_parameter_group_name) member.
+    pub fn cluster_parameter_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_parameter_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_security_groups`](struct.RestoreFromClusterSnapshotMessage.html#structfield.cluster_security_groups) member.
+    pub fn cluster_security_groups<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.cluster_security_groups = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_subnet_group_name`](struct.RestoreFromClusterSnapshotMessage.html#structfield.cluster_subnet_group_name) member.
+    pub fn cluster_subnet_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_subnet_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`elastic_ip`](struct.RestoreFromClusterSnapshotMessage.html#structfield.elastic_ip) member.
+    pub fn elastic_ip<T: Into<String>>(mut self, value: T) -> Self {
+        self.elastic_ip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`enhanced_vpc_routing`](struct.RestoreFromClusterSnapshotMessage.html#structfield.enhanced_vpc_routing) member.
+    pub fn enhanced_vpc_routing<T: Into<bool>>(mut self, value: T) -> Self {
+        self.enhanced_vpc_routing = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_client_certificate_identifier`](struct.RestoreFromClusterSnapshotMessage.html#structfield.hsm_client_certificate_identifier) member.
+    pub fn hsm_client_certificate_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_client_certificate_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`hsm_configuration_identifier`](struct.RestoreFromClusterSnapshotMessage.html#structfield.hsm_configuration_identifier) member.
+    pub fn hsm_configuration_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.hsm_configuration_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`iam_roles`](struct.RestoreFromClusterSnapshotMessage.html#structfield.iam_roles) member.
+    pub fn iam_roles<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.iam_roles = Some(value.into());
+        self
+    }
+
+    /// Sets the [`kms_key_id`](struct.RestoreFromClusterSnapshotMessage.html#structfield.kms_key_id) member.
+    pub fn kms_key_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.kms_key_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`maintenance_track_name`](struct.RestoreFromClusterSnapshotMessage.html#structfield.maintenance_track_name) member.
+    pub fn maintenance_track_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.maintenance_track_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`manual_snapshot_retention_period`](struct.RestoreFromClusterSnapshotMessage.html#structfield.manual_snapshot_retention_period) member.
+    pub fn manual_snapshot_retention_period<T: Into<i64>>(mut self, value: T) -> Self {
+        self.manual_snapshot_retention_period = Some(value.into());
+        self
+    }
+
+    /// Sets the [`node_type`](struct.RestoreFromClusterSnapshotMessage.html#structfield.node_type) member.
+    pub fn node_type<T: Into<String>>(mut self, value: T) -> Self {
+        self.node_type = Some(value.into());
+        self
+    }
+
+    /// Sets the [`owner_account`](struct.RestoreFromClusterSnapshotMessage.html#structfield.owner_account) member.
+    pub fn owner_account<T: Into<String>>(mut self, value: T) -> Self {
+        self.owner_account = Some(value.into());
+        self
+    }
+
+    /// Sets the [`port`](struct.RestoreFromClusterSnapshotMessage.html#structfield.port) member.
+    pub fn port<T: Into<i64>>(mut self, value: T) -> Self {
+        self.port = Some(value.into());
+        self
+    }
+
+    /// Sets the [`preferred_maintenance_window`](struct.RestoreFromClusterSnapshotMessage.html#structfield.preferred_maintenance_window) member.
+    pub fn preferred_maintenance_window<T: Into<String>>(mut self, value: T) -> Self {
+        self.preferred_maintenance_window = Some(value.into());
+        self
+    }
+
+    /// Sets the [`publicly_accessible`](struct.RestoreFromClusterSnapshotMessage.html#structfield.publicly_accessible) member.
+    pub fn publicly_accessible<T: Into<bool>>(mut self, value: T) -> Self {
+        self.publicly_accessible = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_cluster_identifier`](struct.RestoreFromClusterSnapshotMessage.html#structfield.snapshot_cluster_identifier) member.
+    pub fn snapshot_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_identifier`](struct.RestoreFromClusterSnapshotMessage.html#structfield.snapshot_identifier) member.
+    pub fn snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_schedule_identifier`](struct.RestoreFromClusterSnapshotMessage.html#structfield.snapshot_schedule_identifier) member.
+    pub fn snapshot_schedule_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_schedule_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`vpc_security_group_ids`](struct.RestoreFromClusterSnapshotMessage.html#structfield.vpc_security_group_ids) member.
+    pub fn vpc_security_group_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.vpc_security_group_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreFromClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreFromClusterSnapshotMessage, ::rusoto_core::BuildError> {
+        Ok(RestoreFromClusterSnapshotMessage {
+            additional_info: self.additional_info,
+            allow_version_upgrade: self.allow_version_upgrade,
+            automated_snapshot_retention_period: self.automated_snapshot_retention_period,
+            availability_zone: self.availability_zone,
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            cluster_parameter_group_name: self.cluster_parameter_group_name,
+            cluster_security_groups: self.cluster_security_groups,
+            cluster_subnet_group_name: self.cluster_subnet_group_name,
+            elastic_ip: self.elastic_ip,
+            enhanced_vpc_routing: self.enhanced_vpc_routing,
+            hsm_client_certificate_identifier: self.hsm_client_certificate_identifier,
+            hsm_configuration_identifier: self.hsm_configuration_identifier,
+            iam_roles: self.iam_roles,
+            kms_key_id: self.kms_key_id,
+            maintenance_track_name: self.maintenance_track_name,
+            manual_snapshot_retention_period: self.manual_snapshot_retention_period,
+            node_type: self.node_type,
+            owner_account: self.owner_account,
+            port: self.port,
+            preferred_maintenance_window: self.preferred_maintenance_window,
+            publicly_accessible: self.publicly_accessible,
+            snapshot_cluster_identifier: self.snapshot_cluster_identifier,
+            snapshot_identifier: self
+                .snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_identifier"))?,
+            snapshot_schedule_identifier: self.snapshot_schedule_identifier,
+            vpc_security_group_ids: self.vpc_security_group_ids,
+        })
+    }
+}
+
 /// Serialize `RestoreFromClusterSnapshotMessage` contents to a `SignedRequest`.
 struct RestoreFromClusterSnapshotMessageSerializer;
 impl RestoreFromClusterSnapshotMessageSerializer {
@@ -8336,10 +12907,10 @@ pub struct RestoreFromClusterSnapshotResult {
     pub cluster: Option<Cluster>,
 }
 
-struct RestoreFromClusterSnapshotResultDeserializer;
+pub(crate) struct RestoreFromClusterSnapshotResultDeserializer;
 impl RestoreFromClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreFromClusterSnapshotResult, XmlParseError> {
@@ -8375,10 +12946,10 @@ pub struct RestoreStatus {
     pub status: Option<String>,
 }
 
-struct RestoreStatusDeserializer;
+pub(crate) struct RestoreStatusDeserializer;
 impl RestoreStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreStatus, XmlParseError> {
@@ -8442,6 +13013,103 @@ pub struct RestoreTableFromClusterSnapshotMessage {
     pub target_schema_name: Option<String>,
 }
 
+impl RestoreTableFromClusterSnapshotMessage {
+    /// Returns a builder for `RestoreTableFromClusterSnapshotMessage`, checking its required members when built.
+    pub fn builder() -> RestoreTableFromClusterSnapshotMessageBuilder {
+        RestoreTableFromClusterSnapshotMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RestoreTableFromClusterSnapshotMessage`](struct.RestoreTableFromClusterSnapshotMessage.html).
+#[derive(Default, Debug)]
+pub struct RestoreTableFromClusterSnapshotMessageBuilder {
+    cluster_identifier: Option<String>,
+    new_table_name: Option<String>,
+    snapshot_identifier: Option<String>,
+    source_database_name: Option<String>,
+    source_schema_name: Option<String>,
+    source_table_name: Option<String>,
+    target_database_name: Option<String>,
+    target_schema_name: Option<String>,
+}
+
+impl RestoreTableFromClusterSnapshotMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.RestoreTableFromClusterSnapshotMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`new_table_name`](struct.RestoreTableFromClusterSnapshotMessage.html#structfield.new_table_name) member.
+    pub fn new_table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.new_table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_identifier`](struct.RestoreTableFromClusterSnapshotMessage.html#structfield.snapshot_identifier) member.
+    pub fn snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_database_name`](struct.RestoreTableFromClusterSnapshotMessage.html#structfield.source_database_name) member.
+    pub fn source_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_schema_name`](struct.RestoreTableFromClusterSnapshotMessage.html#structfield.source_schema_name) member.
+    pub fn source_schema_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_schema_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_table_name`](struct.RestoreTableFromClusterSnapshotMessage.html#structfield.source_table_name) member.
+    pub fn source_table_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.source_table_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_database_name`](struct.RestoreTableFromClusterSnapshotMessage.html#structfield.target_database_name) member.
+    pub fn target_database_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_database_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_schema_name`](struct.RestoreTableFromClusterSnapshotMessage.html#structfield.target_schema_name) member.
+    pub fn target_schema_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.target_schema_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `RestoreTableFromClusterSnapshotMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RestoreTableFromClusterSnapshotMessage, ::rusoto_core::BuildError>
+    {
+        Ok(RestoreTableFromClusterSnapshotMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+            new_table_name: self
+                .new_table_name
+                .ok_or(::rusoto_core::BuildError::missing("new_table_name"))?,
+            snapshot_identifier: self
+                .snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_identifier"))?,
+            source_database_name: self
+                .source_database_name
+                .ok_or(::rusoto_core::BuildError::missing("source_database_name"))?,
+            source_schema_name: self.source_schema_name,
+            source_table_name: self
+                .source_table_name
+                .ok_or(::rusoto_core::BuildError::missing("source_table_name"))?,
+            target_database_name: self.target_database_name,
+            target_schema_name: self.target_schema_name,
+        })
+    }
+}
+
 /// Serialize `RestoreTableFromClusterSnapshotMessage` contents to a `SignedRequest`.
 struct RestoreTableFromClusterSnapshotMessageSerializer;
 impl RestoreTableFromClusterSnapshotMessageSerializer {
@@ -8488,10 +13156,10 @@ pub struct RestoreTableFromClusterSnapshotResult {
     pub table_restore_status: Option<TableRestoreStatus>,
 }
 
-struct RestoreTableFromClusterSnapshotResultDeserializer;
+pub(crate) struct RestoreTableFromClusterSnapshotResultDeserializer;
 impl RestoreTableFromClusterSnapshotResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RestoreTableFromClusterSnapshotResult, XmlParseError> {
@@ -8520,15 +13188,15 @@ pub struct RevisionTarget {
     /// <p>A unique string that identifies the version to update the cluster to. You can use this value in <a>ModifyClusterDbRevision</a>.</p>
     pub database_revision: Option<String>,
     /// <p>The date on which the database revision was released.</p>
-    pub database_revision_release_date: Option<String>,
+    pub database_revision_release_date: Option<IsoTimestamp>,
     /// <p>A string that describes the changes and features that will be applied to the cluster when it is updated to the corresponding <a>ClusterDbRevision</a>.</p>
     pub description: Option<String>,
 }
 
-struct RevisionTargetDeserializer;
+pub(crate) struct RevisionTargetDeserializer;
 impl RevisionTargetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RevisionTarget, XmlParseError> {
@@ -8553,10 +13221,10 @@ impl RevisionTargetDeserializer {
         })
     }
 }
-struct RevisionTargetsListDeserializer;
+pub(crate) struct RevisionTargetsListDeserializer;
 impl RevisionTargetsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<RevisionTarget>, XmlParseError> {
@@ -8586,6 +13254,63 @@ pub struct RevokeClusterSecurityGroupIngressMessage {
     pub ec2_security_group_owner_id: Option<String>,
 }
 
+impl RevokeClusterSecurityGroupIngressMessage {
+    /// Returns a builder for `RevokeClusterSecurityGroupIngressMessage`, checking its required members when built.
+    pub fn builder() -> RevokeClusterSecurityGroupIngressMessageBuilder {
+        RevokeClusterSecurityGroupIngressMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RevokeClusterSecurityGroupIngressMessage`](struct.RevokeClusterSecurityGroupIngressMessage.html).
+#[derive(Default, Debug)]
+pub struct RevokeClusterSecurityGroupIngressMessageBuilder {
+    cidrip: Option<String>,
+    cluster_security_group_name: Option<String>,
+    ec2_security_group_name: Option<String>,
+    ec2_security_group_owner_id: Option<String>,
+}
+
+impl RevokeClusterSecurityGroupIngressMessageBuilder {
+    /// Sets the [`cidrip`](struct.RevokeClusterSecurityGroupIngressMessage.html#structfield.cidrip) member.
+    pub fn cidrip<T: Into<String>>(mut self, value: T) -> Self {
+        self.cidrip = Some(value.into());
+        self
+    }
+
+    /// Sets the [`cluster_security_group_name`](struct.RevokeClusterSecurityGroupIngressMessage.html#structfield.cluster_security_group_name) member.
+    pub fn cluster_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_name`](struct.RevokeClusterSecurityGroupIngressMessage.html#structfield.ec2_security_group_name) member.
+    pub fn ec2_security_group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`ec2_security_group_owner_id`](struct.RevokeClusterSecurityGroupIngressMessage.html#structfield.ec2_security_group_owner_id) member.
+    pub fn ec2_security_group_owner_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.ec2_security_group_owner_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `RevokeClusterSecurityGroupIngressMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RevokeClusterSecurityGroupIngressMessage, ::rusoto_core::BuildError>
+    {
+        Ok(RevokeClusterSecurityGroupIngressMessage {
+            cidrip: self.cidrip,
+            cluster_security_group_name: self.cluster_security_group_name.ok_or(
+                ::rusoto_core::BuildError::missing("cluster_security_group_name"),
+            )?,
+            ec2_security_group_name: self.ec2_security_group_name,
+            ec2_security_group_owner_id: self.ec2_security_group_owner_id,
+        })
+    }
+}
+
 /// Serialize `RevokeClusterSecurityGroupIngressMessage` contents to a `SignedRequest`.
 struct RevokeClusterSecurityGroupIngressMessageSerializer;
 impl RevokeClusterSecurityGroupIngressMessageSerializer {
@@ -8622,10 +13347,10 @@ pub struct RevokeClusterSecurityGroupIngressResult {
     pub cluster_security_group: Option<ClusterSecurityGroup>,
 }
 
-struct RevokeClusterSecurityGroupIngressResultDeserializer;
+pub(crate) struct RevokeClusterSecurityGroupIngressResultDeserializer;
 impl RevokeClusterSecurityGroupIngressResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RevokeClusterSecurityGroupIngressResult, XmlParseError> {
@@ -8659,6 +13384,56 @@ pub struct RevokeSnapshotAccessMessage {
     pub snapshot_identifier: String,
 }
 
+impl RevokeSnapshotAccessMessage {
+    /// Returns a builder for `RevokeSnapshotAccessMessage`, checking its required members when built.
+    pub fn builder() -> RevokeSnapshotAccessMessageBuilder {
+        RevokeSnapshotAccessMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RevokeSnapshotAccessMessage`](struct.RevokeSnapshotAccessMessage.html).
+#[derive(Default, Debug)]
+pub struct RevokeSnapshotAccessMessageBuilder {
+    account_with_restore_access: Option<String>,
+    snapshot_cluster_identifier: Option<String>,
+    snapshot_identifier: Option<String>,
+}
+
+impl RevokeSnapshotAccessMessageBuilder {
+    /// Sets the [`account_with_restore_access`](struct.RevokeSnapshotAccessMessage.html#structfield.account_with_restore_access) member.
+    pub fn account_with_restore_access<T: Into<String>>(mut self, value: T) -> Self {
+        self.account_with_restore_access = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_cluster_identifier`](struct.RevokeSnapshotAccessMessage.html#structfield.snapshot_cluster_identifier) member.
+    pub fn snapshot_cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Sets the [`snapshot_identifier`](struct.RevokeSnapshotAccessMessage.html#structfield.snapshot_identifier) member.
+    pub fn snapshot_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.snapshot_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `RevokeSnapshotAccessMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RevokeSnapshotAccessMessage, ::rusoto_core::BuildError> {
+        Ok(RevokeSnapshotAccessMessage {
+            account_with_restore_access: self.account_with_restore_access.ok_or(
+                ::rusoto_core::BuildError::missing("account_with_restore_access"),
+            )?,
+            snapshot_cluster_identifier: self.snapshot_cluster_identifier,
+            snapshot_identifier: self
+                .snapshot_identifier
+                .ok_or(::rusoto_core::BuildError::missing("snapshot_identifier"))?,
+        })
+    }
+}
+
 /// Serialize `RevokeSnapshotAccessMessage` contents to a `SignedRequest`.
 struct RevokeSnapshotAccessMessageSerializer;
 impl RevokeSnapshotAccessMessageSerializer {
@@ -8690,10 +13465,10 @@ pub struct RevokeSnapshotAccessResult {
     pub snapshot: Option<Snapshot>,
 }
 
-struct RevokeSnapshotAccessResultDeserializer;
+pub(crate) struct RevokeSnapshotAccessResultDeserializer;
 impl RevokeSnapshotAccessResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RevokeSnapshotAccessResult, XmlParseError> {
@@ -8719,12 +13494,44 @@ pub struct RotateEncryptionKeyMessage {
     pub cluster_identifier: String,
 }
 
-/// Serialize `RotateEncryptionKeyMessage` contents to a `SignedRequest`.
-struct RotateEncryptionKeyMessageSerializer;
-impl RotateEncryptionKeyMessageSerializer {
-    fn serialize(params: &mut Params, name: &str, obj: &RotateEncryptionKeyMessage) {
-        let mut prefix = name.to_string();
-        if prefix != "" {
+impl RotateEncryptionKeyMessage {
+    /// Returns a builder for `RotateEncryptionKeyMessage`, checking its required members when built.
+    pub fn builder() -> RotateEncryptionKeyMessageBuilder {
+        RotateEncryptionKeyMessageBuilder::default()
+    }
+}
+
+/// Builds a [`RotateEncryptionKeyMessage`](struct.RotateEncryptionKeyMessage.html).
+#[derive(Default, Debug)]
+pub struct RotateEncryptionKeyMessageBuilder {
+    cluster_identifier: Option<String>,
+}
+
+impl RotateEncryptionKeyMessageBuilder {
+    /// Sets the [`cluster_identifier`](struct.RotateEncryptionKeyMessage.html#structfield.cluster_identifier) member.
+    pub fn cluster_identifier<T: Into<String>>(mut self, value: T) -> Self {
+        self.cluster_identifier = Some(value.into());
+        self
+    }
+
+    /// Builds the `RotateEncryptionKeyMessage`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RotateEncryptionKeyMessage, ::rusoto_core::BuildError> {
+        Ok(RotateEncryptionKeyMessage {
+            cluster_identifier: self
+                .cluster_identifier
+                .ok_or(::rusoto_core::BuildError::missing("cluster_identifier"))?,
+        })
+    }
+}
+
+/// Serialize `RotateEncryptionKeyMessage` contents to a `SignedRequest`.
+struct RotateEncryptionKeyMessageSerializer;
+impl RotateEncryptionKeyMessageSerializer {
+    fn serialize(params: &mut Params, name: &str, obj: &RotateEncryptionKeyMessage) {
+        let mut prefix = name.to_string();
+        if prefix != "" {
             prefix.push_str(".");
         }
 
@@ -8740,10 +13547,10 @@ pub struct RotateEncryptionKeyResult {
     pub cluster: Option<Cluster>,
 }
 
-struct RotateEncryptionKeyResultDeserializer;
+pub(crate) struct RotateEncryptionKeyResultDeserializer;
 impl RotateEncryptionKeyResultDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<RotateEncryptionKeyResult, XmlParseError> {
@@ -8762,10 +13569,10 @@ impl RotateEncryptionKeyResultDeserializer {
         )
     }
 }
-struct ScheduleDefinitionListDeserializer;
+pub(crate) struct ScheduleDefinitionListDeserializer;
 impl ScheduleDefinitionListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -8794,24 +13601,90 @@ impl ScheduleDefinitionListSerializer {
     }
 }
 
-struct ScheduleStateDeserializer;
+pub(crate) struct ScheduleStateDeserializer;
 impl ScheduleStateDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<ScheduleState, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct ScheduledSnapshotTimeListDeserializer;
+/// <p>The current state of the cluster snapshot schedule.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ScheduleState {
+    /// <p><code>MODIFYING</code></p>
+    Modifying,
+    /// <p><code>ACTIVE</code></p>
+    Active,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ScheduleState {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ScheduleState::Modifying => "MODIFYING",
+            ScheduleState::Active => "ACTIVE",
+            ScheduleState::Failed => "FAILED",
+            ScheduleState::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ScheduleState {
+    fn from(value: &'a str) -> ScheduleState {
+        match value {
+            "MODIFYING" => ScheduleState::Modifying,
+            "ACTIVE" => ScheduleState::Active,
+            "FAILED" => ScheduleState::Failed,
+            _ => ScheduleState::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ScheduleState {
+    fn from(value: String) -> ScheduleState {
+        ScheduleState::from(value.as_str())
+    }
+}
+
+impl From<ScheduleState> for String {
+    fn from(value: ScheduleState) -> String {
+        match value {
+            ScheduleState::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ScheduleState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ScheduleState {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct ScheduledSnapshotTimeListDeserializer;
 impl ScheduledSnapshotTimeListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
-    ) -> Result<Vec<String>, XmlParseError> {
+    ) -> Result<Vec<IsoTimestamp>, XmlParseError> {
         deserialize_elements::<_, Vec<_>, _>(tag_name, stack, |name, stack, obj| {
             if name == "SnapshotTime" {
                 obj.push(TStampDeserializer::deserialize("SnapshotTime", stack)?);
@@ -8822,10 +13695,13 @@ impl ScheduledSnapshotTimeListDeserializer {
         })
     }
 }
-struct SensitiveStringDeserializer;
+pub(crate) struct SensitiveStringDeserializer;
 impl SensitiveStringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -8845,7 +13721,7 @@ pub struct Snapshot {
     /// <p>The number of megabytes that have been transferred to the snapshot backup.</p>
     pub backup_progress_in_mega_bytes: Option<f64>,
     /// <p>The time (UTC) when the cluster was originally created.</p>
-    pub cluster_create_time: Option<String>,
+    pub cluster_create_time: Option<IsoTimestamp>,
     /// <p>The identifier of the cluster for which the snapshot was taken.</p>
     pub cluster_identifier: Option<String>,
     /// <p>The version ID of the Amazon Redshift engine that is running on the cluster.</p>
@@ -8885,11 +13761,11 @@ pub struct Snapshot {
     /// <p>The list of node types that this cluster snapshot is able to restore into.</p>
     pub restorable_node_types: Option<Vec<String>>,
     /// <p>The time (in UTC format) when Amazon Redshift began the snapshot. A snapshot contains a copy of the cluster data as of this exact time.</p>
-    pub snapshot_create_time: Option<String>,
+    pub snapshot_create_time: Option<IsoTimestamp>,
     /// <p>The snapshot identifier that is provided in the request.</p>
     pub snapshot_identifier: Option<String>,
     /// <p>A timestamp representing the start of the retention period for the snapshot.</p>
-    pub snapshot_retention_start_time: Option<String>,
+    pub snapshot_retention_start_time: Option<IsoTimestamp>,
     /// <p>The snapshot type. Snapshots created using <a>CreateClusterSnapshot</a> and <a>CopyClusterSnapshot</a> are of type "manual". </p>
     pub snapshot_type: Option<String>,
     /// <p>The source region from which the snapshot was copied.</p>
@@ -8904,10 +13780,10 @@ pub struct Snapshot {
     pub vpc_id: Option<String>,
 }
 
-struct SnapshotDeserializer;
+pub(crate) struct SnapshotDeserializer;
 impl SnapshotDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Snapshot, XmlParseError> {
@@ -9083,6 +13959,69 @@ impl SnapshotDeserializer {
         })
     }
 }
+/// <p>The category for sorting the snapshots.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SnapshotAttributeToSortBy {
+    /// <p><code>SOURCE_TYPE</code></p>
+    SourceType,
+    /// <p><code>TOTAL_SIZE</code></p>
+    TotalSize,
+    /// <p><code>CREATE_TIME</code></p>
+    CreateTime,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SnapshotAttributeToSortBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SnapshotAttributeToSortBy::SourceType => "SOURCE_TYPE",
+            SnapshotAttributeToSortBy::TotalSize => "TOTAL_SIZE",
+            SnapshotAttributeToSortBy::CreateTime => "CREATE_TIME",
+            SnapshotAttributeToSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SnapshotAttributeToSortBy {
+    fn from(value: &'a str) -> SnapshotAttributeToSortBy {
+        match value {
+            "SOURCE_TYPE" => SnapshotAttributeToSortBy::SourceType,
+            "TOTAL_SIZE" => SnapshotAttributeToSortBy::TotalSize,
+            "CREATE_TIME" => SnapshotAttributeToSortBy::CreateTime,
+            _ => SnapshotAttributeToSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SnapshotAttributeToSortBy {
+    fn from(value: String) -> SnapshotAttributeToSortBy {
+        SnapshotAttributeToSortBy::from(value.as_str())
+    }
+}
+
+impl From<SnapshotAttributeToSortBy> for String {
+    fn from(value: SnapshotAttributeToSortBy) -> String {
+        match value {
+            SnapshotAttributeToSortBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SnapshotAttributeToSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SnapshotAttributeToSortBy {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>The snapshot copy grant that grants Amazon Redshift permission to encrypt copied snapshots with the specified customer master key (CMK) from AWS KMS in the destination region.</p> <p> For more information about managing snapshot copy grants, go to <a href="https://docs.aws.amazon.com/redshift/latest/mgmt/working-with-db-encryption.html">Amazon Redshift Database Encryption</a> in the <i>Amazon Redshift Cluster Management Guide</i>. </p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SnapshotCopyGrant {
@@ -9094,10 +14033,10 @@ pub struct SnapshotCopyGrant {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct SnapshotCopyGrantDeserializer;
+pub(crate) struct SnapshotCopyGrantDeserializer;
 impl SnapshotCopyGrantDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SnapshotCopyGrant, XmlParseError> {
@@ -9123,10 +14062,10 @@ impl SnapshotCopyGrantDeserializer {
         })
     }
 }
-struct SnapshotCopyGrantListDeserializer;
+pub(crate) struct SnapshotCopyGrantListDeserializer;
 impl SnapshotCopyGrantListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SnapshotCopyGrant>, XmlParseError> {
@@ -9152,10 +14091,10 @@ pub struct SnapshotCopyGrantMessage {
     pub snapshot_copy_grants: Option<Vec<SnapshotCopyGrant>>,
 }
 
-struct SnapshotCopyGrantMessageDeserializer;
+pub(crate) struct SnapshotCopyGrantMessageDeserializer;
 impl SnapshotCopyGrantMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SnapshotCopyGrantMessage, XmlParseError> {
@@ -9195,10 +14134,10 @@ pub struct SnapshotErrorMessage {
     pub snapshot_identifier: Option<String>,
 }
 
-struct SnapshotErrorMessageDeserializer;
+pub(crate) struct SnapshotErrorMessageDeserializer;
 impl SnapshotErrorMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SnapshotErrorMessage, XmlParseError> {
@@ -9229,10 +14168,10 @@ impl SnapshotErrorMessageDeserializer {
         })
     }
 }
-struct SnapshotIdentifierListDeserializer;
+pub(crate) struct SnapshotIdentifierListDeserializer;
 impl SnapshotIdentifierListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -9258,10 +14197,10 @@ impl SnapshotIdentifierListSerializer {
     }
 }
 
-struct SnapshotListDeserializer;
+pub(crate) struct SnapshotListDeserializer;
 impl SnapshotListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Snapshot>, XmlParseError> {
@@ -9284,10 +14223,10 @@ pub struct SnapshotMessage {
     pub snapshots: Option<Vec<Snapshot>>,
 }
 
-struct SnapshotMessageDeserializer;
+pub(crate) struct SnapshotMessageDeserializer;
 impl SnapshotMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SnapshotMessage, XmlParseError> {
@@ -9315,7 +14254,7 @@ pub struct SnapshotSchedule {
     /// <p>A list of clusters associated with the schedule. A maximum of 100 clusters is returned.</p>
     pub associated_clusters: Option<Vec<ClusterAssociatedToSchedule>>,
     /// <p><p/></p>
-    pub next_invocations: Option<Vec<String>>,
+    pub next_invocations: Option<Vec<IsoTimestamp>>,
     /// <p>A list of ScheduleDefinitions.</p>
     pub schedule_definitions: Option<Vec<String>>,
     /// <p>The description of the schedule.</p>
@@ -9326,10 +14265,10 @@ pub struct SnapshotSchedule {
     pub tags: Option<Vec<Tag>>,
 }
 
-struct SnapshotScheduleDeserializer;
+pub(crate) struct SnapshotScheduleDeserializer;
 impl SnapshotScheduleDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SnapshotSchedule, XmlParseError> {
@@ -9388,10 +14327,10 @@ impl SnapshotScheduleDeserializer {
         })
     }
 }
-struct SnapshotScheduleListDeserializer;
+pub(crate) struct SnapshotScheduleListDeserializer;
 impl SnapshotScheduleListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SnapshotSchedule>, XmlParseError> {
@@ -9409,12 +14348,12 @@ impl SnapshotScheduleListDeserializer {
     }
 }
 /// <p>Describes a sorting entity</p>
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SnapshotSortingEntity {
     /// <p>The category for sorting the snapshots.</p>
-    pub attribute: String,
+    pub attribute: SnapshotAttributeToSortBy,
     /// <p>The order for listing the attributes.</p>
-    pub sort_order: Option<String>,
+    pub sort_order: Option<SortByOrder>,
 }
 
 /// Serialize `SnapshotSortingEntity` contents to a `SignedRequest`.
@@ -9444,10 +14383,69 @@ impl SnapshotSortingEntityListSerializer {
     }
 }
 
-struct SourceIdsListDeserializer;
+/// <p>The order for listing the attributes.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SortByOrder {
+    /// <p><code>ASC</code></p>
+    Asc,
+    /// <p><code>DESC</code></p>
+    Desc,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SortByOrder {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SortByOrder::Asc => "ASC",
+            SortByOrder::Desc => "DESC",
+            SortByOrder::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SortByOrder {
+    fn from(value: &'a str) -> SortByOrder {
+        match value {
+            "ASC" => SortByOrder::Asc,
+            "DESC" => SortByOrder::Desc,
+            _ => SortByOrder::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SortByOrder {
+    fn from(value: String) -> SortByOrder {
+        SortByOrder::from(value.as_str())
+    }
+}
+
+impl From<SortByOrder> for String {
+    fn from(value: SortByOrder) -> String {
+        match value {
+            SortByOrder::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SortByOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SortByOrder {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct SourceIdsListDeserializer;
 impl SourceIdsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<String>, XmlParseError> {
@@ -9473,21 +14471,98 @@ impl SourceIdsListSerializer {
     }
 }
 
-struct SourceTypeDeserializer;
+pub(crate) struct SourceTypeDeserializer;
 impl SourceTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<SourceType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
-struct StringDeserializer;
+/// <p><p>The event source to retrieve events for. If no value is specified, all events are returned.</p> <p>Constraints:</p> <p>If <i>SourceType</i> is supplied, <i>SourceIdentifier</i> must also be provided.</p> <ul> <li> <p>Specify <code>cluster</code> when <i>SourceIdentifier</i> is a cluster identifier.</p> </li> <li> <p>Specify <code>cluster-security-group</code> when <i>SourceIdentifier</i> is a cluster security group name.</p> </li> <li> <p>Specify <code>cluster-parameter-group</code> when <i>SourceIdentifier</i> is a cluster parameter group name.</p> </li> <li> <p>Specify <code>cluster-snapshot</code> when <i>SourceIdentifier</i> is a cluster snapshot identifier.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SourceType {
+    /// <p><code>cluster</code></p>
+    Cluster,
+    /// <p><code>cluster-parameter-group</code></p>
+    ClusterParameterGroup,
+    /// <p><code>cluster-security-group</code></p>
+    ClusterSecurityGroup,
+    /// <p><code>cluster-snapshot</code></p>
+    ClusterSnapshot,
+    /// <p><code>scheduled-action</code></p>
+    ScheduledAction,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl SourceType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            SourceType::Cluster => "cluster",
+            SourceType::ClusterParameterGroup => "cluster-parameter-group",
+            SourceType::ClusterSecurityGroup => "cluster-security-group",
+            SourceType::ClusterSnapshot => "cluster-snapshot",
+            SourceType::ScheduledAction => "scheduled-action",
+            SourceType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for SourceType {
+    fn from(value: &'a str) -> SourceType {
+        match value {
+            "cluster" => SourceType::Cluster,
+            "cluster-parameter-group" => SourceType::ClusterParameterGroup,
+            "cluster-security-group" => SourceType::ClusterSecurityGroup,
+            "cluster-snapshot" => SourceType::ClusterSnapshot,
+            "scheduled-action" => SourceType::ScheduledAction,
+            _ => SourceType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(value: String) -> SourceType {
+        SourceType::from(value.as_str())
+    }
+}
+
+impl From<SourceType> for String {
+    fn from(value: SourceType) -> String {
+        match value {
+            SourceType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for SourceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for SourceType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+pub(crate) struct StringDeserializer;
 impl StringDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<String, XmlParseError> {
         start_element(tag_name, stack)?;
         let obj = characters(stack)?;
         end_element(tag_name, stack)?;
@@ -9506,10 +14581,13 @@ pub struct Subnet {
     pub subnet_status: Option<String>,
 }
 
-struct SubnetDeserializer;
+pub(crate) struct SubnetDeserializer;
 impl SubnetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Subnet, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Subnet, XmlParseError> {
         deserialize_elements::<_, Subnet, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "SubnetAvailabilityZone" => {
@@ -9544,10 +14622,10 @@ impl SubnetIdentifierListSerializer {
     }
 }
 
-struct SubnetListDeserializer;
+pub(crate) struct SubnetListDeserializer;
 impl SubnetListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Subnet>, XmlParseError> {
@@ -9568,10 +14646,10 @@ pub struct SupportedOperation {
     pub operation_name: Option<String>,
 }
 
-struct SupportedOperationDeserializer;
+pub(crate) struct SupportedOperationDeserializer;
 impl SupportedOperationDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SupportedOperation, XmlParseError> {
@@ -9587,10 +14665,10 @@ impl SupportedOperationDeserializer {
         })
     }
 }
-struct SupportedOperationListDeserializer;
+pub(crate) struct SupportedOperationListDeserializer;
 impl SupportedOperationListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SupportedOperation>, XmlParseError> {
@@ -9614,10 +14692,10 @@ pub struct SupportedPlatform {
     pub name: Option<String>,
 }
 
-struct SupportedPlatformDeserializer;
+pub(crate) struct SupportedPlatformDeserializer;
 impl SupportedPlatformDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<SupportedPlatform, XmlParseError> {
@@ -9632,10 +14710,10 @@ impl SupportedPlatformDeserializer {
         })
     }
 }
-struct SupportedPlatformsListDeserializer;
+pub(crate) struct SupportedPlatformsListDeserializer;
 impl SupportedPlatformsListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<SupportedPlatform>, XmlParseError> {
@@ -9652,12 +14730,15 @@ impl SupportedPlatformsListDeserializer {
         })
     }
 }
-struct TStampDeserializer;
+pub(crate) struct TStampDeserializer;
 impl TStampDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<IsoTimestamp, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = rusoto_core::timestamp::from_iso8601(characters(stack)?)?;
         end_element(tag_name, stack)?;
 
         Ok(obj)
@@ -9675,7 +14756,7 @@ pub struct TableRestoreStatus {
     /// <p>The amount of data restored to the new table so far, in megabytes (MB).</p>
     pub progress_in_mega_bytes: Option<i64>,
     /// <p>The time that the table restore request was made, in Universal Coordinated Time (UTC).</p>
-    pub request_time: Option<String>,
+    pub request_time: Option<IsoTimestamp>,
     /// <p>The identifier of the snapshot that the table is being restored from.</p>
     pub snapshot_identifier: Option<String>,
     /// <p>The name of the source database that contains the table being restored.</p>
@@ -9685,7 +14766,7 @@ pub struct TableRestoreStatus {
     /// <p>The name of the source table being restored.</p>
     pub source_table_name: Option<String>,
     /// <p>A value that describes the current state of the table restore request.</p> <p>Valid Values: <code>SUCCEEDED</code>, <code>FAILED</code>, <code>CANCELED</code>, <code>PENDING</code>, <code>IN_PROGRESS</code> </p>
-    pub status: Option<String>,
+    pub status: Option<TableRestoreStatusType>,
     /// <p>The unique identifier for the table restore request.</p>
     pub table_restore_request_id: Option<String>,
     /// <p>The name of the database to restore the table to.</p>
@@ -9696,10 +14777,10 @@ pub struct TableRestoreStatus {
     pub total_data_in_mega_bytes: Option<i64>,
 }
 
-struct TableRestoreStatusDeserializer;
+pub(crate) struct TableRestoreStatusDeserializer;
 impl TableRestoreStatusDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TableRestoreStatus, XmlParseError> {
@@ -9778,10 +14859,10 @@ impl TableRestoreStatusDeserializer {
         })
     }
 }
-struct TableRestoreStatusListDeserializer;
+pub(crate) struct TableRestoreStatusListDeserializer;
 impl TableRestoreStatusListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TableRestoreStatus>, XmlParseError> {
@@ -9807,10 +14888,10 @@ pub struct TableRestoreStatusMessage {
     pub table_restore_status_details: Option<Vec<TableRestoreStatus>>,
 }
 
-struct TableRestoreStatusMessageDeserializer;
+pub(crate) struct TableRestoreStatusMessageDeserializer;
 impl TableRestoreStatusMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TableRestoreStatusMessage, XmlParseError> {
@@ -9837,17 +14918,91 @@ impl TableRestoreStatusMessageDeserializer {
         )
     }
 }
-struct TableRestoreStatusTypeDeserializer;
+pub(crate) struct TableRestoreStatusTypeDeserializer;
 impl TableRestoreStatusTypeDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<String, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<TableRestoreStatusType, XmlParseError> {
         start_element(tag_name, stack)?;
-        let obj = characters(stack)?;
+        let obj = characters(stack)?.into();
         end_element(tag_name, stack)?;
 
         Ok(obj)
     }
 }
+/// <p>A value that describes the current state of the table restore request.</p> <p>Valid Values: <code>SUCCEEDED</code>, <code>FAILED</code>, <code>CANCELED</code>, <code>PENDING</code>, <code>IN_PROGRESS</code> </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TableRestoreStatusType {
+    /// <p><code>PENDING</code></p>
+    Pending,
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>CANCELED</code></p>
+    Canceled,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TableRestoreStatusType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TableRestoreStatusType::Pending => "PENDING",
+            TableRestoreStatusType::InProgress => "IN_PROGRESS",
+            TableRestoreStatusType::Succeeded => "SUCCEEDED",
+            TableRestoreStatusType::Failed => "FAILED",
+            TableRestoreStatusType::Canceled => "CANCELED",
+            TableRestoreStatusType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TableRestoreStatusType {
+    fn from(value: &'a str) -> TableRestoreStatusType {
+        match value {
+            "PENDING" => TableRestoreStatusType::Pending,
+            "IN_PROGRESS" => TableRestoreStatusType::InProgress,
+            "SUCCEEDED" => TableRestoreStatusType::Succeeded,
+            "FAILED" => TableRestoreStatusType::Failed,
+            "CANCELED" => TableRestoreStatusType::Canceled,
+            _ => TableRestoreStatusType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TableRestoreStatusType {
+    fn from(value: String) -> TableRestoreStatusType {
+        TableRestoreStatusType::from(value.as_str())
+    }
+}
+
+impl From<TableRestoreStatusType> for String {
+    fn from(value: TableRestoreStatusType) -> String {
+        match value {
+            TableRestoreStatusType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TableRestoreStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for TableRestoreStatusType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
 /// <p>A tag consisting of a name/value pair for a resource.</p>
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct Tag {
@@ -9857,10 +15012,13 @@ pub struct Tag {
     pub value: Option<String>,
 }
 
-struct TagDeserializer;
+pub(crate) struct TagDeserializer;
 impl TagDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(tag_name: &str, stack: &mut T) -> Result<Tag, XmlParseError> {
+    pub(crate) fn deserialize<T: Peek + Next>(
+        tag_name: &str,
+        stack: &mut T,
+    ) -> Result<Tag, XmlParseError> {
         deserialize_elements::<_, Tag, _>(tag_name, stack, |name, stack, obj| {
             match name {
                 "Key" => {
@@ -9905,10 +15063,10 @@ impl TagKeyListSerializer {
     }
 }
 
-struct TagListDeserializer;
+pub(crate) struct TagListDeserializer;
 impl TagListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<Tag>, XmlParseError> {
@@ -9956,10 +15114,10 @@ pub struct TaggedResource {
     pub tag: Option<Tag>,
 }
 
-struct TaggedResourceDeserializer;
+pub(crate) struct TaggedResourceDeserializer;
 impl TaggedResourceDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TaggedResource, XmlParseError> {
@@ -9982,10 +15140,10 @@ impl TaggedResourceDeserializer {
         })
     }
 }
-struct TaggedResourceListDeserializer;
+pub(crate) struct TaggedResourceListDeserializer;
 impl TaggedResourceListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<TaggedResource>, XmlParseError> {
@@ -10011,10 +15169,10 @@ pub struct TaggedResourceListMessage {
     pub tagged_resources: Option<Vec<TaggedResource>>,
 }
 
-struct TaggedResourceListMessageDeserializer;
+pub(crate) struct TaggedResourceListMessageDeserializer;
 impl TaggedResourceListMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TaggedResourceListMessage, XmlParseError> {
@@ -10038,10 +15196,10 @@ impl TaggedResourceListMessageDeserializer {
         )
     }
 }
-struct TrackListDeserializer;
+pub(crate) struct TrackListDeserializer;
 impl TrackListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<MaintenanceTrack>, XmlParseError> {
@@ -10066,10 +15224,10 @@ pub struct TrackListMessage {
     pub marker: Option<String>,
 }
 
-struct TrackListMessageDeserializer;
+pub(crate) struct TrackListMessageDeserializer;
 impl TrackListMessageDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<TrackListMessage, XmlParseError> {
@@ -10100,10 +15258,10 @@ pub struct UpdateTarget {
     pub supported_operations: Option<Vec<SupportedOperation>>,
 }
 
-struct UpdateTargetDeserializer;
+pub(crate) struct UpdateTargetDeserializer;
 impl UpdateTargetDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<UpdateTarget, XmlParseError> {
@@ -10154,10 +15312,10 @@ pub struct VpcSecurityGroupMembership {
     pub vpc_security_group_id: Option<String>,
 }
 
-struct VpcSecurityGroupMembershipDeserializer;
+pub(crate) struct VpcSecurityGroupMembershipDeserializer;
 impl VpcSecurityGroupMembershipDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<VpcSecurityGroupMembership, XmlParseError> {
@@ -10182,10 +15340,10 @@ impl VpcSecurityGroupMembershipDeserializer {
         )
     }
 }
-struct VpcSecurityGroupMembershipListDeserializer;
+pub(crate) struct VpcSecurityGroupMembershipListDeserializer;
 impl VpcSecurityGroupMembershipListDeserializer {
     #[allow(unused_variables)]
-    fn deserialize<T: Peek + Next>(
+    pub(crate) fn deserialize<T: Peek + Next>(
         tag_name: &str,
         stack: &mut T,
     ) -> Result<Vec<VpcSecurityGroupMembership>, XmlParseError> {
@@ -16767,40 +21925,606 @@ pub trait Redshift {
         input: RotateEncryptionKeyMessage,
     ) -> RusotoFuture<RotateEncryptionKeyResult, RotateEncryptionKeyError>;
 }
-/// A client for the Amazon Redshift API.
-#[derive(Clone)]
-pub struct RedshiftClient {
-    client: Client,
-    region: region::Region,
-}
-
-impl RedshiftClient {
-    /// Creates a client backed by the default tokio event loop.
-    ///
-    /// The client will use the default credentials provider and tls client.
-    pub fn new(region: region::Region) -> RedshiftClient {
-        Self::new_with_client(Client::shared(), region)
+impl<T: ?Sized + Redshift> Redshift for ::std::sync::Arc<T> {
+    fn accept_reserved_node_exchange(
+        &self,
+        input: AcceptReservedNodeExchangeInputMessage,
+    ) -> RusotoFuture<AcceptReservedNodeExchangeOutputMessage, AcceptReservedNodeExchangeError>
+    {
+        (**self).accept_reserved_node_exchange(input)
     }
-
-    pub fn new_with<P, D>(
-        request_dispatcher: D,
-        credentials_provider: P,
-        region: region::Region,
-    ) -> RedshiftClient
-    where
-        P: ProvideAwsCredentials + Send + Sync + 'static,
-        P::Future: Send,
-        D: DispatchSignedRequest + Send + Sync + 'static,
-        D::Future: Send,
+    fn authorize_cluster_security_group_ingress(
+        &self,
+        input: AuthorizeClusterSecurityGroupIngressMessage,
+    ) -> RusotoFuture<
+        AuthorizeClusterSecurityGroupIngressResult,
+        AuthorizeClusterSecurityGroupIngressError,
+    > {
+        (**self).authorize_cluster_security_group_ingress(input)
+    }
+    fn authorize_snapshot_access(
+        &self,
+        input: AuthorizeSnapshotAccessMessage,
+    ) -> RusotoFuture<AuthorizeSnapshotAccessResult, AuthorizeSnapshotAccessError> {
+        (**self).authorize_snapshot_access(input)
+    }
+    fn batch_delete_cluster_snapshots(
+        &self,
+        input: BatchDeleteClusterSnapshotsRequest,
+    ) -> RusotoFuture<BatchDeleteClusterSnapshotsResult, BatchDeleteClusterSnapshotsError> {
+        (**self).batch_delete_cluster_snapshots(input)
+    }
+    fn batch_modify_cluster_snapshots(
+        &self,
+        input: BatchModifyClusterSnapshotsMessage,
+    ) -> RusotoFuture<BatchModifyClusterSnapshotsOutputMessage, BatchModifyClusterSnapshotsError>
     {
-        Self::new_with_client(
-            Client::new_with(credentials_provider, request_dispatcher),
-            region,
-        )
+        (**self).batch_modify_cluster_snapshots(input)
     }
-
-    pub fn new_with_client(client: Client, region: region::Region) -> RedshiftClient {
-        RedshiftClient { client, region }
+    fn cancel_resize(
+        &self,
+        input: CancelResizeMessage,
+    ) -> RusotoFuture<ResizeProgressMessage, CancelResizeError> {
+        (**self).cancel_resize(input)
+    }
+    fn copy_cluster_snapshot(
+        &self,
+        input: CopyClusterSnapshotMessage,
+    ) -> RusotoFuture<CopyClusterSnapshotResult, CopyClusterSnapshotError> {
+        (**self).copy_cluster_snapshot(input)
+    }
+    fn create_cluster(
+        &self,
+        input: CreateClusterMessage,
+    ) -> RusotoFuture<CreateClusterResult, CreateClusterError> {
+        (**self).create_cluster(input)
+    }
+    fn create_cluster_parameter_group(
+        &self,
+        input: CreateClusterParameterGroupMessage,
+    ) -> RusotoFuture<CreateClusterParameterGroupResult, CreateClusterParameterGroupError> {
+        (**self).create_cluster_parameter_group(input)
+    }
+    fn create_cluster_security_group(
+        &self,
+        input: CreateClusterSecurityGroupMessage,
+    ) -> RusotoFuture<CreateClusterSecurityGroupResult, CreateClusterSecurityGroupError> {
+        (**self).create_cluster_security_group(input)
+    }
+    fn create_cluster_snapshot(
+        &self,
+        input: CreateClusterSnapshotMessage,
+    ) -> RusotoFuture<CreateClusterSnapshotResult, CreateClusterSnapshotError> {
+        (**self).create_cluster_snapshot(input)
+    }
+    fn create_cluster_subnet_group(
+        &self,
+        input: CreateClusterSubnetGroupMessage,
+    ) -> RusotoFuture<CreateClusterSubnetGroupResult, CreateClusterSubnetGroupError> {
+        (**self).create_cluster_subnet_group(input)
+    }
+    fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> RusotoFuture<CreateEventSubscriptionResult, CreateEventSubscriptionError> {
+        (**self).create_event_subscription(input)
+    }
+    fn create_hsm_client_certificate(
+        &self,
+        input: CreateHsmClientCertificateMessage,
+    ) -> RusotoFuture<CreateHsmClientCertificateResult, CreateHsmClientCertificateError> {
+        (**self).create_hsm_client_certificate(input)
+    }
+    fn create_hsm_configuration(
+        &self,
+        input: CreateHsmConfigurationMessage,
+    ) -> RusotoFuture<CreateHsmConfigurationResult, CreateHsmConfigurationError> {
+        (**self).create_hsm_configuration(input)
+    }
+    fn create_snapshot_copy_grant(
+        &self,
+        input: CreateSnapshotCopyGrantMessage,
+    ) -> RusotoFuture<CreateSnapshotCopyGrantResult, CreateSnapshotCopyGrantError> {
+        (**self).create_snapshot_copy_grant(input)
+    }
+    fn create_snapshot_schedule(
+        &self,
+        input: CreateSnapshotScheduleMessage,
+    ) -> RusotoFuture<SnapshotSchedule, CreateSnapshotScheduleError> {
+        (**self).create_snapshot_schedule(input)
+    }
+    fn create_tags(&self, input: CreateTagsMessage) -> RusotoFuture<(), CreateTagsError> {
+        (**self).create_tags(input)
+    }
+    fn delete_cluster(
+        &self,
+        input: DeleteClusterMessage,
+    ) -> RusotoFuture<DeleteClusterResult, DeleteClusterError> {
+        (**self).delete_cluster(input)
+    }
+    fn delete_cluster_parameter_group(
+        &self,
+        input: DeleteClusterParameterGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterParameterGroupError> {
+        (**self).delete_cluster_parameter_group(input)
+    }
+    fn delete_cluster_security_group(
+        &self,
+        input: DeleteClusterSecurityGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterSecurityGroupError> {
+        (**self).delete_cluster_security_group(input)
+    }
+    fn delete_cluster_snapshot(
+        &self,
+        input: DeleteClusterSnapshotMessage,
+    ) -> RusotoFuture<DeleteClusterSnapshotResult, DeleteClusterSnapshotError> {
+        (**self).delete_cluster_snapshot(input)
+    }
+    fn delete_cluster_subnet_group(
+        &self,
+        input: DeleteClusterSubnetGroupMessage,
+    ) -> RusotoFuture<(), DeleteClusterSubnetGroupError> {
+        (**self).delete_cluster_subnet_group(input)
+    }
+    fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> RusotoFuture<(), DeleteEventSubscriptionError> {
+        (**self).delete_event_subscription(input)
+    }
+    fn delete_hsm_client_certificate(
+        &self,
+        input: DeleteHsmClientCertificateMessage,
+    ) -> RusotoFuture<(), DeleteHsmClientCertificateError> {
+        (**self).delete_hsm_client_certificate(input)
+    }
+    fn delete_hsm_configuration(
+        &self,
+        input: DeleteHsmConfigurationMessage,
+    ) -> RusotoFuture<(), DeleteHsmConfigurationError> {
+        (**self).delete_hsm_configuration(input)
+    }
+    fn delete_snapshot_copy_grant(
+        &self,
+        input: DeleteSnapshotCopyGrantMessage,
+    ) -> RusotoFuture<(), DeleteSnapshotCopyGrantError> {
+        (**self).delete_snapshot_copy_grant(input)
+    }
+    fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleMessage,
+    ) -> RusotoFuture<(), DeleteSnapshotScheduleError> {
+        (**self).delete_snapshot_schedule(input)
+    }
+    fn delete_tags(&self, input: DeleteTagsMessage) -> RusotoFuture<(), DeleteTagsError> {
+        (**self).delete_tags(input)
+    }
+    fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> RusotoFuture<AccountAttributeList, DescribeAccountAttributesError> {
+        (**self).describe_account_attributes(input)
+    }
+    fn describe_cluster_db_revisions(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> RusotoFuture<ClusterDbRevisionsMessage, DescribeClusterDbRevisionsError> {
+        (**self).describe_cluster_db_revisions(input)
+    }
+    fn describe_cluster_parameter_groups(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> RusotoFuture<ClusterParameterGroupsMessage, DescribeClusterParameterGroupsError> {
+        (**self).describe_cluster_parameter_groups(input)
+    }
+    fn describe_cluster_parameters(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> RusotoFuture<ClusterParameterGroupDetails, DescribeClusterParametersError> {
+        (**self).describe_cluster_parameters(input)
+    }
+    fn describe_cluster_security_groups(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> RusotoFuture<ClusterSecurityGroupMessage, DescribeClusterSecurityGroupsError> {
+        (**self).describe_cluster_security_groups(input)
+    }
+    fn describe_cluster_snapshots(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> RusotoFuture<SnapshotMessage, DescribeClusterSnapshotsError> {
+        (**self).describe_cluster_snapshots(input)
+    }
+    fn describe_cluster_subnet_groups(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> RusotoFuture<ClusterSubnetGroupMessage, DescribeClusterSubnetGroupsError> {
+        (**self).describe_cluster_subnet_groups(input)
+    }
+    fn describe_cluster_tracks(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> RusotoFuture<TrackListMessage, DescribeClusterTracksError> {
+        (**self).describe_cluster_tracks(input)
+    }
+    fn describe_cluster_versions(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> RusotoFuture<ClusterVersionsMessage, DescribeClusterVersionsError> {
+        (**self).describe_cluster_versions(input)
+    }
+    fn describe_clusters(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> RusotoFuture<ClustersMessage, DescribeClustersError> {
+        (**self).describe_clusters(input)
+    }
+    fn describe_default_cluster_parameters(
+        &self,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> RusotoFuture<DescribeDefaultClusterParametersResult, DescribeDefaultClusterParametersError>
+    {
+        (**self).describe_default_cluster_parameters(input)
+    }
+    fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> RusotoFuture<EventCategoriesMessage, DescribeEventCategoriesError> {
+        (**self).describe_event_categories(input)
+    }
+    fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> RusotoFuture<EventSubscriptionsMessage, DescribeEventSubscriptionsError> {
+        (**self).describe_event_subscriptions(input)
+    }
+    fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> RusotoFuture<EventsMessage, DescribeEventsError> {
+        (**self).describe_events(input)
+    }
+    fn describe_hsm_client_certificates(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> RusotoFuture<HsmClientCertificateMessage, DescribeHsmClientCertificatesError> {
+        (**self).describe_hsm_client_certificates(input)
+    }
+    fn describe_hsm_configurations(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> RusotoFuture<HsmConfigurationMessage, DescribeHsmConfigurationsError> {
+        (**self).describe_hsm_configurations(input)
+    }
+    fn describe_logging_status(
+        &self,
+        input: DescribeLoggingStatusMessage,
+    ) -> RusotoFuture<LoggingStatus, DescribeLoggingStatusError> {
+        (**self).describe_logging_status(input)
+    }
+    fn describe_orderable_cluster_options(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> RusotoFuture<OrderableClusterOptionsMessage, DescribeOrderableClusterOptionsError> {
+        (**self).describe_orderable_cluster_options(input)
+    }
+    fn describe_reserved_node_offerings(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> RusotoFuture<ReservedNodeOfferingsMessage, DescribeReservedNodeOfferingsError> {
+        (**self).describe_reserved_node_offerings(input)
+    }
+    fn describe_reserved_nodes(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> RusotoFuture<ReservedNodesMessage, DescribeReservedNodesError> {
+        (**self).describe_reserved_nodes(input)
+    }
+    fn describe_resize(
+        &self,
+        input: DescribeResizeMessage,
+    ) -> RusotoFuture<ResizeProgressMessage, DescribeResizeError> {
+        (**self).describe_resize(input)
+    }
+    fn describe_snapshot_copy_grants(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> RusotoFuture<SnapshotCopyGrantMessage, DescribeSnapshotCopyGrantsError> {
+        (**self).describe_snapshot_copy_grants(input)
+    }
+    fn describe_snapshot_schedules(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> RusotoFuture<DescribeSnapshotSchedulesOutputMessage, DescribeSnapshotSchedulesError> {
+        (**self).describe_snapshot_schedules(input)
+    }
+    fn describe_storage(&self) -> RusotoFuture<CustomerStorageMessage, DescribeStorageError> {
+        (**self).describe_storage()
+    }
+    fn describe_table_restore_status(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> RusotoFuture<TableRestoreStatusMessage, DescribeTableRestoreStatusError> {
+        (**self).describe_table_restore_status(input)
+    }
+    fn describe_tags(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> RusotoFuture<TaggedResourceListMessage, DescribeTagsError> {
+        (**self).describe_tags(input)
+    }
+    fn disable_logging(
+        &self,
+        input: DisableLoggingMessage,
+    ) -> RusotoFuture<LoggingStatus, DisableLoggingError> {
+        (**self).disable_logging(input)
+    }
+    fn disable_snapshot_copy(
+        &self,
+        input: DisableSnapshotCopyMessage,
+    ) -> RusotoFuture<DisableSnapshotCopyResult, DisableSnapshotCopyError> {
+        (**self).disable_snapshot_copy(input)
+    }
+    fn enable_logging(
+        &self,
+        input: EnableLoggingMessage,
+    ) -> RusotoFuture<LoggingStatus, EnableLoggingError> {
+        (**self).enable_logging(input)
+    }
+    fn enable_snapshot_copy(
+        &self,
+        input: EnableSnapshotCopyMessage,
+    ) -> RusotoFuture<EnableSnapshotCopyResult, EnableSnapshotCopyError> {
+        (**self).enable_snapshot_copy(input)
+    }
+    fn get_cluster_credentials(
+        &self,
+        input: GetClusterCredentialsMessage,
+    ) -> RusotoFuture<ClusterCredentials, GetClusterCredentialsError> {
+        (**self).get_cluster_credentials(input)
+    }
+    fn get_reserved_node_exchange_offerings(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> RusotoFuture<
+        GetReservedNodeExchangeOfferingsOutputMessage,
+        GetReservedNodeExchangeOfferingsError,
+    > {
+        (**self).get_reserved_node_exchange_offerings(input)
+    }
+    fn modify_cluster(
+        &self,
+        input: ModifyClusterMessage,
+    ) -> RusotoFuture<ModifyClusterResult, ModifyClusterError> {
+        (**self).modify_cluster(input)
+    }
+    fn modify_cluster_db_revision(
+        &self,
+        input: ModifyClusterDbRevisionMessage,
+    ) -> RusotoFuture<ModifyClusterDbRevisionResult, ModifyClusterDbRevisionError> {
+        (**self).modify_cluster_db_revision(input)
+    }
+    fn modify_cluster_iam_roles(
+        &self,
+        input: ModifyClusterIamRolesMessage,
+    ) -> RusotoFuture<ModifyClusterIamRolesResult, ModifyClusterIamRolesError> {
+        (**self).modify_cluster_iam_roles(input)
+    }
+    fn modify_cluster_maintenance(
+        &self,
+        input: ModifyClusterMaintenanceMessage,
+    ) -> RusotoFuture<ModifyClusterMaintenanceResult, ModifyClusterMaintenanceError> {
+        (**self).modify_cluster_maintenance(input)
+    }
+    fn modify_cluster_parameter_group(
+        &self,
+        input: ModifyClusterParameterGroupMessage,
+    ) -> RusotoFuture<ClusterParameterGroupNameMessage, ModifyClusterParameterGroupError> {
+        (**self).modify_cluster_parameter_group(input)
+    }
+    fn modify_cluster_snapshot(
+        &self,
+        input: ModifyClusterSnapshotMessage,
+    ) -> RusotoFuture<ModifyClusterSnapshotResult, ModifyClusterSnapshotError> {
+        (**self).modify_cluster_snapshot(input)
+    }
+    fn modify_cluster_snapshot_schedule(
+        &self,
+        input: ModifyClusterSnapshotScheduleMessage,
+    ) -> RusotoFuture<(), ModifyClusterSnapshotScheduleError> {
+        (**self).modify_cluster_snapshot_schedule(input)
+    }
+    fn modify_cluster_subnet_group(
+        &self,
+        input: ModifyClusterSubnetGroupMessage,
+    ) -> RusotoFuture<ModifyClusterSubnetGroupResult, ModifyClusterSubnetGroupError> {
+        (**self).modify_cluster_subnet_group(input)
+    }
+    fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> RusotoFuture<ModifyEventSubscriptionResult, ModifyEventSubscriptionError> {
+        (**self).modify_event_subscription(input)
+    }
+    fn modify_snapshot_copy_retention_period(
+        &self,
+        input: ModifySnapshotCopyRetentionPeriodMessage,
+    ) -> RusotoFuture<ModifySnapshotCopyRetentionPeriodResult, ModifySnapshotCopyRetentionPeriodError>
+    {
+        (**self).modify_snapshot_copy_retention_period(input)
+    }
+    fn modify_snapshot_schedule(
+        &self,
+        input: ModifySnapshotScheduleMessage,
+    ) -> RusotoFuture<SnapshotSchedule, ModifySnapshotScheduleError> {
+        (**self).modify_snapshot_schedule(input)
+    }
+    fn purchase_reserved_node_offering(
+        &self,
+        input: PurchaseReservedNodeOfferingMessage,
+    ) -> RusotoFuture<PurchaseReservedNodeOfferingResult, PurchaseReservedNodeOfferingError> {
+        (**self).purchase_reserved_node_offering(input)
+    }
+    fn reboot_cluster(
+        &self,
+        input: RebootClusterMessage,
+    ) -> RusotoFuture<RebootClusterResult, RebootClusterError> {
+        (**self).reboot_cluster(input)
+    }
+    fn reset_cluster_parameter_group(
+        &self,
+        input: ResetClusterParameterGroupMessage,
+    ) -> RusotoFuture<ClusterParameterGroupNameMessage, ResetClusterParameterGroupError> {
+        (**self).reset_cluster_parameter_group(input)
+    }
+    fn resize_cluster(
+        &self,
+        input: ResizeClusterMessage,
+    ) -> RusotoFuture<ResizeClusterResult, ResizeClusterError> {
+        (**self).resize_cluster(input)
+    }
+    fn restore_from_cluster_snapshot(
+        &self,
+        input: RestoreFromClusterSnapshotMessage,
+    ) -> RusotoFuture<RestoreFromClusterSnapshotResult, RestoreFromClusterSnapshotError> {
+        (**self).restore_from_cluster_snapshot(input)
+    }
+    fn restore_table_from_cluster_snapshot(
+        &self,
+        input: RestoreTableFromClusterSnapshotMessage,
+    ) -> RusotoFuture<RestoreTableFromClusterSnapshotResult, RestoreTableFromClusterSnapshotError>
+    {
+        (**self).restore_table_from_cluster_snapshot(input)
+    }
+    fn revoke_cluster_security_group_ingress(
+        &self,
+        input: RevokeClusterSecurityGroupIngressMessage,
+    ) -> RusotoFuture<RevokeClusterSecurityGroupIngressResult, RevokeClusterSecurityGroupIngressError>
+    {
+        (**self).revoke_cluster_security_group_ingress(input)
+    }
+    fn revoke_snapshot_access(
+        &self,
+        input: RevokeSnapshotAccessMessage,
+    ) -> RusotoFuture<RevokeSnapshotAccessResult, RevokeSnapshotAccessError> {
+        (**self).revoke_snapshot_access(input)
+    }
+    fn rotate_encryption_key(
+        &self,
+        input: RotateEncryptionKeyMessage,
+    ) -> RusotoFuture<RotateEncryptionKeyResult, RotateEncryptionKeyError> {
+        (**self).rotate_encryption_key(input)
+    }
+}
+/// The error codes Amazon Redshift retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
+/// A client for the Amazon Redshift API.
+#[derive(Clone)]
+pub struct RedshiftClient {
+    client: Client,
+    region: region::Region,
+}
+
+impl RedshiftClient {
+    /// Creates a client backed by the default tokio event loop.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> RedshiftClient {
+        Self::new_with_client(Client::shared(), region)
+    }
+
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_REDSHIFT`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> RedshiftClient {
+        Self::new(region::Region::from_env_for_service("REDSHIFT"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `redshift`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> RedshiftClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("redshift", "REDSHIFT"),
+        )
+    }
+
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RedshiftClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        P::Future: Send,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+        D::Future: Send,
+    {
+        Self::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    pub fn new_with_client(client: Client, region: region::Region) -> RedshiftClient {
+        RedshiftClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "redshift")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "redshift",
+            "redshift",
+            "REDSHIFT",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Redshift` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Redshift + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for RedshiftClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Redshift"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "redshift"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> RedshiftClient {
+        Self::new_with_client(client, region)
     }
 }
 
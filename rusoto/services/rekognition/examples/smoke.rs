@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Rekognition clients.
+//!
+//! Makes a single read-only `ListCollections` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_rekognition::{ListCollectionsRequest, Rekognition, RekognitionClient};
+
+fn main() {
+    let client = RekognitionClient::from_env();
+    match client
+        .list_collections(ListCollectionsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListCollections succeeded"),
+        Err(error) => {
+            eprintln!("ListCollections failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -1 +1,5 @@
-
+/// Waiting for video analysis jobs to finish with SQS
+#[cfg(feature = "notifications")]
+pub mod notifications;
+/// Running video analysis jobs and reading every page of their results
+pub mod video;
@@ -0,0 +1,258 @@
+//! Waiting for jobs to finish with SQS instead of polling.
+//!
+//! Jobs started with a `notification_channel` publish a notification to its SNS topic when they
+//! finish. `wait_for_job` long polls an SQS queue subscribed to the topic until the notification
+//! for a job arrives, deleting it from the queue. Notifications for other jobs are left in the
+//! queue, and become visible to other receivers again after the queue's visibility timeout.
+//!
+//! This module is only available with the `notifications` feature enabled.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use rusoto_core::waiter::WaiterConfig;
+//! use rusoto_core::Region;
+//! use rusoto_rekognition::notifications::wait_for_job;
+//! use rusoto_rekognition::video::{VideoJobConfig, VideoJobExt};
+//! use rusoto_rekognition::{
+//!     NotificationChannel, Rekognition, RekognitionClient, S3Object,
+//!     StartContentModerationRequest, Video,
+//! };
+//! use rusoto_sqs::SqsClient;
+//!
+//! let rekognition = RekognitionClient::new(Region::UsEast1);
+//! let sqs = SqsClient::new(Region::UsEast1);
+//! let job_id = rekognition
+//!     .start_content_moderation(StartContentModerationRequest {
+//!         video: Video {
+//!             s3_object: Some(S3Object {
+//!                 bucket: Some("my-uploads".to_owned()),
+//!                 name: Some("clip.mp4".to_owned()),
+//!                 ..Default::default()
+//!             }),
+//!         },
+//!         notification_channel: Some(NotificationChannel {
+//!             role_arn: "arn:aws:iam::123456789012:role/RekognitionPublish".to_owned(),
+//!             sns_topic_arn: "arn:aws:sns:us-east-1:123456789012:AmazonRekognition".to_owned(),
+//!         }),
+//!         ..Default::default()
+//!     })
+//!     .sync()
+//!     .unwrap()
+//!     .job_id
+//!     .unwrap();
+//! let queue_url = "https://sqs.us-east-1.amazonaws.com/123456789012/rekognition";
+//! let waiter = WaiterConfig::new(Duration::from_secs(0), 360);
+//! let notification = wait_for_job(&sqs, queue_url, &job_id, waiter).sync().unwrap();
+//! if notification.succeeded() {
+//!     let results = rekognition
+//!         .wait_for_content_moderation(&job_id, VideoJobConfig::default())
+//!         .sync()
+//!         .unwrap();
+//!     println!("{:?}", results.moderation_labels);
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use futures::{future, Future};
+use rusoto_core::waiter::{poll_until, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+use rusoto_sqs::{
+    DeleteMessageError, DeleteMessageRequest, Message, ReceiveMessageError, ReceiveMessageRequest,
+    Sqs,
+};
+use serde_json::Value;
+
+/// The longest SQS lets a receive wait for messages, in seconds.
+const MAX_WAIT_TIME_SECONDS: i64 = 20;
+
+/// The notification a job publishes when it finishes.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct JobNotification {
+    /// The ID of the job
+    #[serde(rename = "JobId")]
+    pub job_id: String,
+    /// The status of the job, e.g. `SUCCEEDED`
+    #[serde(rename = "Status")]
+    pub status: String,
+    /// The operation which started the job, e.g. `StartLabelDetection`
+    #[serde(rename = "API")]
+    pub api: Option<String>,
+    /// The tag the job was started with
+    #[serde(rename = "JobTag")]
+    pub job_tag: Option<String>,
+    /// When the job finished, in milliseconds since the Unix epoch
+    #[serde(rename = "Timestamp")]
+    pub timestamp: Option<i64>,
+}
+
+impl JobNotification {
+    /// Parses the body of an SQS message, either an SNS notification wrapping the job's
+    /// notification or, with raw message delivery, the job's notification itself.
+    pub fn from_message_body(body: &str) -> Result<JobNotification, String> {
+        let value: Value = serde_json::from_str(body).map_err(|e| e.to_string())?;
+        let notification = match value.get("Message").and_then(Value::as_str) {
+            Some(message) => serde_json::from_str(message),
+            None => serde_json::from_value(value),
+        };
+        notification.map_err(|e| e.to_string())
+    }
+
+    /// Whether the job succeeded.
+    pub fn succeeded(&self) -> bool {
+        self.status == "SUCCEEDED"
+    }
+}
+
+/// Errors returned by `wait_for_job`.
+#[derive(Debug, PartialEq)]
+pub enum JobNotificationError {
+    /// Receiving messages from the queue failed.
+    ReceiveMessage(ReceiveMessageError),
+    /// Deleting the job's notification from the queue failed.
+    DeleteMessage(DeleteMessageError),
+    /// The job's notification hadn't arrived when the waiter gave up.
+    Timeout(String),
+}
+
+impl fmt::Display for JobNotificationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JobNotificationError::Timeout(ref job_id) => {
+                write!(f, "{}: {}", self.description(), job_id)
+            }
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for JobNotificationError {
+    fn description(&self) -> &str {
+        match *self {
+            JobNotificationError::ReceiveMessage(ref cause) => cause.description(),
+            JobNotificationError::DeleteMessage(ref cause) => cause.description(),
+            JobNotificationError::Timeout(_) => "No notification received for job",
+        }
+    }
+}
+
+/// Receives messages from the queue at `queue_url` until the notification for the job `job_id`
+/// arrives, and deletes it from the queue.
+///
+/// Every attempt of `waiter` waits up to 20 seconds for messages, so its delay can be zero.
+pub fn wait_for_job<Q>(
+    sqs: &Q,
+    queue_url: &str,
+    job_id: &str,
+    waiter: WaiterConfig,
+) -> RusotoFuture<JobNotification, JobNotificationError>
+where
+    Q: Sqs + Clone + Send + 'static,
+{
+    let sqs = sqs.clone();
+    let queue_url = queue_url.to_owned();
+    let job_id = job_id.to_owned();
+    let timeout = RusotoError::Service(JobNotificationError::Timeout(job_id.clone()));
+    let future = poll_until(
+        waiter,
+        move || {
+            let sqs = sqs.clone();
+            let queue_url = queue_url.clone();
+            let job_id = job_id.clone();
+            sqs.receive_message(ReceiveMessageRequest {
+                queue_url: queue_url.clone(),
+                max_number_of_messages: Some(10),
+                wait_time_seconds: Some(MAX_WAIT_TIME_SECONDS),
+                ..Default::default()
+            })
+            .map_err(|e| e.map_service(JobNotificationError::ReceiveMessage))
+            .and_then(
+                move |output| -> Box<dyn Future<Item = _, Error = _> + Send> {
+                    let messages = output.messages.unwrap_or_default();
+                    match find_notification(messages, &job_id) {
+                        Some((notification, receipt_handle)) => Box::new(
+                            sqs.delete_message(DeleteMessageRequest {
+                                queue_url,
+                                receipt_handle,
+                            })
+                            .map_err(|e| e.map_service(JobNotificationError::DeleteMessage))
+                            .map(move |_| Some(notification)),
+                        ),
+                        None => Box::new(future::ok(None)),
+                    }
+                },
+            )
+        },
+        timeout,
+    );
+    RusotoFuture::from_future(future)
+}
+
+/// The notification for the job `job_id` among `messages`, and the receipt handle of its
+/// message. Messages which aren't job notifications are ignored.
+fn find_notification(messages: Vec<Message>, job_id: &str) -> Option<(JobNotification, String)> {
+    messages.into_iter().find_map(|message| {
+        let notification = JobNotification::from_message_body(message.body.as_ref()?).ok()?;
+        if notification.job_id == job_id {
+            Some((notification, message.receipt_handle?))
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTIFICATION: &str = r#"{"JobId":"1a2b3c","Status":"SUCCEEDED","API":"StartLabelDetection","JobTag":"driveway","Timestamp":1567017436153,"Video":{"S3ObjectName":"driveway.mp4","S3Bucket":"my-videos"}}"#;
+
+    fn message(body: &str, receipt_handle: &str) -> Message {
+        Message {
+            body: Some(body.to_owned()),
+            receipt_handle: Some(receipt_handle.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parses_raw_and_sns_wrapped_notifications() {
+        let raw = JobNotification::from_message_body(NOTIFICATION).unwrap();
+        assert_eq!(raw.job_id, "1a2b3c");
+        assert!(raw.succeeded());
+        assert_eq!(raw.api, Some("StartLabelDetection".to_owned()));
+        assert_eq!(raw.timestamp, Some(1_567_017_436_153));
+
+        let envelope = serde_json::json!({
+            "Type": "Notification",
+            "TopicArn": "arn:aws:sns:us-east-1:123456789012:AmazonRekognition",
+            "Message": NOTIFICATION,
+        });
+        let wrapped = JobNotification::from_message_body(&envelope.to_string()).unwrap();
+        assert_eq!(wrapped, raw);
+
+        assert!(JobNotification::from_message_body("not json").is_err());
+    }
+
+    #[test]
+    fn finds_the_notification_for_the_job() {
+        let other = NOTIFICATION.replace("1a2b3c", "4d5e6f");
+        let messages = vec![
+            message("garbage", "handle-0"),
+            message(&other, "handle-1"),
+            message(NOTIFICATION, "handle-2"),
+        ];
+        let (notification, receipt_handle) = find_notification(messages, "1a2b3c").unwrap();
+        assert_eq!(notification.job_id, "1a2b3c");
+        assert_eq!(receipt_handle, "handle-2");
+
+        assert_eq!(
+            find_notification(vec![message(&other, "handle-1")], "1a2b3c"),
+            None
+        );
+    }
+}
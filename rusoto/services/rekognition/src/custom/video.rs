@@ -0,0 +1,578 @@
+//! Running video analysis jobs and reading every page of their results.
+//!
+//! Stored videos are analysed asynchronously: operations such as `StartLabelDetection` only
+//! queue a job, whose results are read a page at a time with the matching `Get` operation once
+//! it succeeds. `VideoJobExt` starts jobs, waits for them by polling the `Get` operation, and
+//! reads every page of their results, merged into a single response.
+//!
+//! Instead of polling, jobs can publish to an SNS topic when they finish: set the
+//! `notification_channel` of the request, and with the `notifications` feature enabled wait for
+//! the SQS queue subscribed to the topic to receive the notification with
+//! `notifications::wait_for_job`. The `wait_for_*` methods then read the results without waiting.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use rusoto_core::Region;
+//! use rusoto_rekognition::video::{VideoJobConfig, VideoJobExt};
+//! use rusoto_rekognition::{RekognitionClient, S3Object, StartLabelDetectionRequest, Video};
+//!
+//! let client = RekognitionClient::new(Region::UsEast1);
+//! let request = StartLabelDetectionRequest {
+//!     video: Video {
+//!         s3_object: Some(S3Object {
+//!             bucket: Some("my-videos".to_owned()),
+//!             name: Some("driveway.mp4".to_owned()),
+//!             ..Default::default()
+//!         }),
+//!     },
+//!     min_confidence: Some(80.0),
+//!     ..Default::default()
+//! };
+//! let results = client
+//!     .run_label_detection(request, VideoJobConfig::default())
+//!     .sync()
+//!     .unwrap();
+//! for detection in results.labels.unwrap_or_default() {
+//!     println!("{:?} {:?}", detection.timestamp, detection.label);
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use futures::future::{self, Loop};
+use futures::Future;
+use rusoto_core::waiter::{poll_until, WaiterConfig};
+use rusoto_core::{RusotoError, RusotoFuture};
+
+use crate::generated::{
+    GetCelebrityRecognitionError, GetCelebrityRecognitionRequest, GetCelebrityRecognitionResponse,
+    GetContentModerationError, GetContentModerationRequest, GetContentModerationResponse,
+    GetFaceDetectionError, GetFaceDetectionRequest, GetFaceDetectionResponse, GetFaceSearchError,
+    GetFaceSearchRequest, GetFaceSearchResponse, GetLabelDetectionError, GetLabelDetectionRequest,
+    GetLabelDetectionResponse, GetPersonTrackingError, GetPersonTrackingRequest,
+    GetPersonTrackingResponse, Rekognition, StartCelebrityRecognitionError,
+    StartCelebrityRecognitionRequest, StartContentModerationError, StartContentModerationRequest,
+    StartFaceDetectionError, StartFaceDetectionRequest, StartFaceSearchError,
+    StartFaceSearchRequest, StartLabelDetectionError, StartLabelDetectionRequest,
+    StartPersonTrackingError, StartPersonTrackingRequest, VideoJobStatus,
+};
+
+/// The most results the `Get` operations return per page.
+const MAX_PAGE_SIZE: i64 = 1000;
+
+/// Errors returned by `VideoJobExt`.
+#[derive(Debug, PartialEq)]
+pub enum VideoJobError {
+    /// Starting a celebrity recognition job failed.
+    StartCelebrityRecognition(StartCelebrityRecognitionError),
+    /// Starting a content moderation job failed.
+    StartContentModeration(StartContentModerationError),
+    /// Starting a face detection job failed.
+    StartFaceDetection(StartFaceDetectionError),
+    /// Starting a face search job failed.
+    StartFaceSearch(StartFaceSearchError),
+    /// Starting a label detection job failed.
+    StartLabelDetection(StartLabelDetectionError),
+    /// Starting a person tracking job failed.
+    StartPersonTracking(StartPersonTrackingError),
+    /// Reading the status or results of a celebrity recognition job failed.
+    GetCelebrityRecognition(GetCelebrityRecognitionError),
+    /// Reading the status or results of a content moderation job failed.
+    GetContentModeration(GetContentModerationError),
+    /// Reading the status or results of a face detection job failed.
+    GetFaceDetection(GetFaceDetectionError),
+    /// Reading the status or results of a face search job failed.
+    GetFaceSearch(GetFaceSearchError),
+    /// Reading the status or results of a label detection job failed.
+    GetLabelDetection(GetLabelDetectionError),
+    /// Reading the status or results of a person tracking job failed.
+    GetPersonTracking(GetPersonTrackingError),
+    /// The job failed.
+    JobFailed {
+        /// The ID of the job
+        job_id: String,
+        /// Why the job failed
+        status_message: Option<String>,
+    },
+    /// The job was still in progress when the waiter gave up.
+    Timeout(String),
+}
+
+impl fmt::Display for VideoJobError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VideoJobError::JobFailed {
+                ref job_id,
+                ref status_message,
+            } => write!(
+                f,
+                "Job {} failed: {}",
+                job_id,
+                status_message
+                    .as_ref()
+                    .map_or("no reason given", String::as_str)
+            ),
+            VideoJobError::Timeout(ref job_id) => write!(f, "{}: {}", self.description(), job_id),
+            _ => write!(f, "{}", self.description()),
+        }
+    }
+}
+
+impl Error for VideoJobError {
+    fn description(&self) -> &str {
+        match *self {
+            VideoJobError::StartCelebrityRecognition(ref cause) => cause.description(),
+            VideoJobError::StartContentModeration(ref cause) => cause.description(),
+            VideoJobError::StartFaceDetection(ref cause) => cause.description(),
+            VideoJobError::StartFaceSearch(ref cause) => cause.description(),
+            VideoJobError::StartLabelDetection(ref cause) => cause.description(),
+            VideoJobError::StartPersonTracking(ref cause) => cause.description(),
+            VideoJobError::GetCelebrityRecognition(ref cause) => cause.description(),
+            VideoJobError::GetContentModeration(ref cause) => cause.description(),
+            VideoJobError::GetFaceDetection(ref cause) => cause.description(),
+            VideoJobError::GetFaceSearch(ref cause) => cause.description(),
+            VideoJobError::GetLabelDetection(ref cause) => cause.description(),
+            VideoJobError::GetPersonTracking(ref cause) => cause.description(),
+            VideoJobError::JobFailed { .. } => "Job failed",
+            VideoJobError::Timeout(_) => "Job still in progress",
+        }
+    }
+}
+
+/// How `VideoJobExt` waits for jobs and reads their results.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VideoJobConfig {
+    /// How often, and for how long, jobs are polled. Defaults to every 10 seconds for up to two
+    /// hours.
+    pub waiter: WaiterConfig,
+    /// How many results to read per page. Defaults to the maximum, 1000.
+    pub page_size: i64,
+    /// How results are sorted, `TIMESTAMP` or the operation's other key, e.g. `NAME` for labels
+    /// or `ID` for people. Ignored by face detection, which is always sorted by timestamp.
+    pub sort_by: Option<String>,
+}
+
+impl Default for VideoJobConfig {
+    fn default() -> VideoJobConfig {
+        VideoJobConfig {
+            waiter: WaiterConfig::new(Duration::from_secs(10), 720),
+            page_size: MAX_PAGE_SIZE,
+            sort_by: None,
+        }
+    }
+}
+
+/// A page of the results of a video analysis job.
+pub trait VideoResults: Sized {
+    /// `IN_PROGRESS`, `SUCCEEDED` or `FAILED`.
+    fn job_status(&self) -> Option<&str>;
+    /// Why the job failed.
+    fn status_message(&self) -> Option<&str>;
+    /// The token of the next page, if there is one.
+    fn next_token(&self) -> Option<&str>;
+    /// Appends the results of the following page, taking its next token.
+    fn merge(&mut self, page: Self);
+}
+
+fn merge_vec<T>(results: &mut Option<Vec<T>>, page: Option<Vec<T>>) {
+    match (results.as_mut(), page) {
+        (Some(results), Some(page)) => results.extend(page),
+        (None, page) => *results = page,
+        (_, None) => {}
+    }
+}
+
+macro_rules! video_results {
+    ($response:ident, $results:ident) => {
+        impl VideoResults for $response {
+            fn job_status(&self) -> Option<&str> {
+                self.job_status.as_ref().map(VideoJobStatus::as_str)
+            }
+
+            fn status_message(&self) -> Option<&str> {
+                self.status_message.as_ref().map(String::as_str)
+            }
+
+            fn next_token(&self) -> Option<&str> {
+                self.next_token.as_ref().map(String::as_str)
+            }
+
+            fn merge(&mut self, page: Self) {
+                merge_vec(&mut self.$results, page.$results);
+                self.next_token = page.next_token;
+            }
+        }
+    };
+}
+
+video_results!(GetCelebrityRecognitionResponse, celebrities);
+video_results!(GetContentModerationResponse, moderation_labels);
+video_results!(GetFaceDetectionResponse, faces);
+video_results!(GetFaceSearchResponse, persons);
+video_results!(GetLabelDetectionResponse, labels);
+video_results!(GetPersonTrackingResponse, persons);
+
+type JobFuture<T> = Box<dyn Future<Item = T, Error = RusotoError<VideoJobError>> + Send>;
+
+/// Starts Rekognition video analysis jobs, waits for them and reads their results.
+pub trait VideoJobExt: Rekognition + Clone + Send + Sized + 'static {
+    /// Polls the celebrity recognition job `job_id` until it succeeds, then reads every page of
+    /// its results.
+    fn wait_for_celebrity_recognition(
+        &self,
+        job_id: &str,
+        config: VideoJobConfig,
+    ) -> RusotoFuture<GetCelebrityRecognitionResponse, VideoJobError> {
+        let client = self.clone();
+        let (page_size, sort_by) = (config.page_size, config.sort_by.clone());
+        let get = move |job_id: String, next_token| -> JobFuture<_> {
+            let request = GetCelebrityRecognitionRequest {
+                job_id,
+                max_results: Some(page_size),
+                next_token,
+                sort_by: sort_by.clone().map(Into::into),
+            };
+            Box::new(
+                client
+                    .get_celebrity_recognition(request)
+                    .map_err(|e| e.map_service(VideoJobError::GetCelebrityRecognition)),
+            )
+        };
+        RusotoFuture::from_future(wait_for_job(job_id.to_owned(), config.waiter, get))
+    }
+
+    /// Polls the content moderation job `job_id` until it succeeds, then reads every page of its
+    /// results.
+    fn wait_for_content_moderation(
+        &self,
+        job_id: &str,
+        config: VideoJobConfig,
+    ) -> RusotoFuture<GetContentModerationResponse, VideoJobError> {
+        let client = self.clone();
+        let (page_size, sort_by) = (config.page_size, config.sort_by.clone());
+        let get = move |job_id: String, next_token| -> JobFuture<_> {
+            let request = GetContentModerationRequest {
+                job_id,
+                max_results: Some(page_size),
+                next_token,
+                sort_by: sort_by.clone().map(Into::into),
+            };
+            Box::new(
+                client
+                    .get_content_moderation(request)
+                    .map_err(|e| e.map_service(VideoJobError::GetContentModeration)),
+            )
+        };
+        RusotoFuture::from_future(wait_for_job(job_id.to_owned(), config.waiter, get))
+    }
+
+    /// Polls the face detection job `job_id` until it succeeds, then reads every page of its
+    /// results.
+    fn wait_for_face_detection(
+        &self,
+        job_id: &str,
+        config: VideoJobConfig,
+    ) -> RusotoFuture<GetFaceDetectionResponse, VideoJobError> {
+        let client = self.clone();
+        let page_size = config.page_size;
+        let get = move |job_id: String, next_token| -> JobFuture<_> {
+            let request = GetFaceDetectionRequest {
+                job_id,
+                max_results: Some(page_size),
+                next_token,
+            };
+            Box::new(
+                client
+                    .get_face_detection(request)
+                    .map_err(|e| e.map_service(VideoJobError::GetFaceDetection)),
+            )
+        };
+        RusotoFuture::from_future(wait_for_job(job_id.to_owned(), config.waiter, get))
+    }
+
+    /// Polls the face search job `job_id` until it succeeds, then reads every page of its
+    /// results.
+    fn wait_for_face_search(
+        &self,
+        job_id: &str,
+        config: VideoJobConfig,
+    ) -> RusotoFuture<GetFaceSearchResponse, VideoJobError> {
+        let client = self.clone();
+        let (page_size, sort_by) = (config.page_size, config.sort_by.clone());
+        let get = move |job_id: String, next_token| -> JobFuture<_> {
+            let request = GetFaceSearchRequest {
+                job_id,
+                max_results: Some(page_size),
+                next_token,
+                sort_by: sort_by.clone().map(Into::into),
+            };
+            Box::new(
+                client
+                    .get_face_search(request)
+                    .map_err(|e| e.map_service(VideoJobError::GetFaceSearch)),
+            )
+        };
+        RusotoFuture::from_future(wait_for_job(job_id.to_owned(), config.waiter, get))
+    }
+
+    /// Polls the label detection job `job_id` until it succeeds, then reads every page of its
+    /// results.
+    fn wait_for_label_detection(
+        &self,
+        job_id: &str,
+        config: VideoJobConfig,
+    ) -> RusotoFuture<GetLabelDetectionResponse, VideoJobError> {
+        let client = self.clone();
+        let (page_size, sort_by) = (config.page_size, config.sort_by.clone());
+        let get = move |job_id: String, next_token| -> JobFuture<_> {
+            let request = GetLabelDetectionRequest {
+                job_id,
+                max_results: Some(page_size),
+                next_token,
+                sort_by: sort_by.clone().map(Into::into),
+            };
+            Box::new(
+                client
+                    .get_label_detection(request)
+                    .map_err(|e| e.map_service(VideoJobError::GetLabelDetection)),
+            )
+        };
+        RusotoFuture::from_future(wait_for_job(job_id.to_owned(), config.waiter, get))
+    }
+
+    /// Polls the person tracking job `job_id` until it succeeds, then reads every page of its
+    /// results.
+    fn wait_for_person_tracking(
+        &self,
+        job_id: &str,
+        config: VideoJobConfig,
+    ) -> RusotoFuture<GetPersonTrackingResponse, VideoJobError> {
+        let client = self.clone();
+        let (page_size, sort_by) = (config.page_size, config.sort_by.clone());
+        let get = move |job_id: String, next_token| -> JobFuture<_> {
+            let request = GetPersonTrackingRequest {
+                job_id,
+                max_results: Some(page_size),
+                next_token,
+                sort_by: sort_by.clone().map(Into::into),
+            };
+            Box::new(
+                client
+                    .get_person_tracking(request)
+                    .map_err(|e| e.map_service(VideoJobError::GetPersonTracking)),
+            )
+        };
+        RusotoFuture::from_future(wait_for_job(job_id.to_owned(), config.waiter, get))
+    }
+
+    /// Starts a celebrity recognition job, waits for it to succeed and reads its results.
+    fn run_celebrity_recognition(
+        &self,
+        input: StartCelebrityRecognitionRequest,
+        config: VideoJobConfig,
+    ) -> RusotoFuture<GetCelebrityRecognitionResponse, VideoJobError> {
+        let client = self.clone();
+        let future = self
+            .start_celebrity_recognition(input)
+            .map_err(|e| e.map_service(VideoJobError::StartCelebrityRecognition))
+            .and_then(|output| started(output.job_id))
+            .and_then(move |job_id| client.wait_for_celebrity_recognition(&job_id, config));
+        RusotoFuture::from_future(future)
+    }
+
+    /// Starts a content moderation job, waits for it to succeed and reads its results.
+    fn run_content_moderation(
+        &self,
+        input: StartContentModerationRequest,
+        config: VideoJobConfig,
+    ) -> RusotoFuture<GetContentModerationResponse, VideoJobError> {
+        let client = self.clone();
+        let future = self
+            .start_content_moderation(input)
+            .map_err(|e| e.map_service(VideoJobError::StartContentModeration))
+            .and_then(|output| started(output.job_id))
+            .and_then(move |job_id| client.wait_for_content_moderation(&job_id, config));
+        RusotoFuture::from_future(future)
+    }
+
+    /// Starts a face detection job, waits for it to succeed and reads its results.
+    fn run_face_detection(
+        &self,
+        input: StartFaceDetectionRequest,
+        config: VideoJobConfig,
+    ) -> RusotoFuture<GetFaceDetectionResponse, VideoJobError> {
+        let client = self.clone();
+        let future = self
+            .start_face_detection(input)
+            .map_err(|e| e.map_service(VideoJobError::StartFaceDetection))
+            .and_then(|output| started(output.job_id))
+            .and_then(move |job_id| client.wait_for_face_detection(&job_id, config));
+        RusotoFuture::from_future(future)
+    }
+
+    /// Starts a face search job, waits for it to succeed and reads its results.
+    fn run_face_search(
+        &self,
+        input: StartFaceSearchRequest,
+        config: VideoJobConfig,
+    ) -> RusotoFuture<GetFaceSearchResponse, VideoJobError> {
+        let client = self.clone();
+        let future = self
+            .start_face_search(input)
+            .map_err(|e| e.map_service(VideoJobError::StartFaceSearch))
+            .and_then(|output| started(output.job_id))
+            .and_then(move |job_id| client.wait_for_face_search(&job_id, config));
+        RusotoFuture::from_future(future)
+    }
+
+    /// Starts a label detection job, waits for it to succeed and reads its results.
+    fn run_label_detection(
+        &self,
+        input: StartLabelDetectionRequest,
+        config: VideoJobConfig,
+    ) -> RusotoFuture<GetLabelDetectionResponse, VideoJobError> {
+        let client = self.clone();
+        let future = self
+            .start_label_detection(input)
+            .map_err(|e| e.map_service(VideoJobError::StartLabelDetection))
+            .and_then(|output| started(output.job_id))
+            .and_then(move |job_id| client.wait_for_label_detection(&job_id, config));
+        RusotoFuture::from_future(future)
+    }
+
+    /// Starts a person tracking job, waits for it to succeed and reads its results.
+    fn run_person_tracking(
+        &self,
+        input: StartPersonTrackingRequest,
+        config: VideoJobConfig,
+    ) -> RusotoFuture<GetPersonTrackingResponse, VideoJobError> {
+        let client = self.clone();
+        let future = self
+            .start_person_tracking(input)
+            .map_err(|e| e.map_service(VideoJobError::StartPersonTracking))
+            .and_then(|output| started(output.job_id))
+            .and_then(move |job_id| client.wait_for_person_tracking(&job_id, config));
+        RusotoFuture::from_future(future)
+    }
+}
+
+impl<T: Rekognition + Clone + Send + Sized + 'static> VideoJobExt for T {}
+
+fn started(job_id: Option<String>) -> Result<String, RusotoError<VideoJobError>> {
+    job_id.ok_or_else(|| RusotoError::ParseError("Missing JobId".to_owned()))
+}
+
+/// Whether the job a page of results is for has succeeded, failing if the job failed.
+fn succeeded<R: VideoResults>(job_id: &str, page: &R) -> Result<bool, RusotoError<VideoJobError>> {
+    match page.job_status() {
+        Some("SUCCEEDED") => Ok(true),
+        Some("IN_PROGRESS") => Ok(false),
+        Some("FAILED") => Err(RusotoError::Service(VideoJobError::JobFailed {
+            job_id: job_id.to_owned(),
+            status_message: page.status_message().map(str::to_owned),
+        })),
+        status => Err(RusotoError::ParseError(format!(
+            "Unknown job status: {:?}",
+            status
+        ))),
+    }
+}
+
+/// Polls the job with `get` until it succeeds, then reads the pages following the first one and
+/// merges them into it.
+fn wait_for_job<R, F>(job_id: String, waiter: WaiterConfig, get: F) -> JobFuture<R>
+where
+    R: VideoResults + Send + 'static,
+    F: Fn(String, Option<String>) -> JobFuture<R> + Clone + Send + 'static,
+{
+    let timeout = RusotoError::Service(VideoJobError::Timeout(job_id.clone()));
+    let poll_get = get.clone();
+    let poll_job_id = job_id.clone();
+    let first_page = poll_until(
+        waiter,
+        move || {
+            let job_id = poll_job_id.clone();
+            poll_get(job_id.clone(), None).and_then(move |page| {
+                if succeeded(&job_id, &page)? {
+                    Ok(Some(page))
+                } else {
+                    Ok(None)
+                }
+            })
+        },
+        timeout,
+    );
+    let results = first_page.and_then(move |first_page| {
+        future::loop_fn(first_page, move |mut results| {
+            let next_token = match results.next_token() {
+                Some(next_token) => next_token.to_owned(),
+                None => return future::Either::A(future::ok(Loop::Break(results))),
+            };
+            future::Either::B(get(job_id.clone(), Some(next_token)).map(move |page| {
+                results.merge(page);
+                Loop::Continue(results)
+            }))
+        })
+    });
+    Box::new(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::{Label, LabelDetection};
+
+    fn labels(names: &[&str], next_token: Option<&str>) -> GetLabelDetectionResponse {
+        GetLabelDetectionResponse {
+            job_status: Some(VideoJobStatus::Succeeded),
+            labels: Some(
+                names
+                    .iter()
+                    .map(|&name| LabelDetection {
+                        label: Some(Label {
+                            name: Some(name.to_owned()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            next_token: next_token.map(str::to_owned),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn merges_pages_of_results() {
+        let mut results = labels(&["Car", "Dog"], Some("page-2"));
+        results.merge(labels(&["Person"], None));
+        let names = results
+            .labels
+            .unwrap()
+            .into_iter()
+            .map(|detection| detection.label.unwrap().name.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["Car", "Dog", "Person"]);
+        assert_eq!(results.next_token, None);
+    }
+
+    #[test]
+    fn failed_jobs_are_errors() {
+        let mut page = labels(&[], None);
+        assert!(succeeded("job", &page).unwrap());
+        page.job_status = Some(VideoJobStatus::InProgress);
+        assert!(!succeeded("job", &page).unwrap());
+        page.job_status = Some(VideoJobStatus::Failed);
+        page.status_message = Some("Unsupported codec".to_owned());
+        assert_eq!(
+            succeeded("job", &page).unwrap_err(),
+            RusotoError::Service(VideoJobError::JobFailed {
+                job_id: "job".to_owned(),
+                status_message: Some("Unsupported codec".to_owned()),
+            })
+        );
+    }
+}
@@ -22,6 +22,7 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
 /// <p>Structure containing the estimated age range, in years, for a face.</p> <p>Amazon Rekognition estimates an age range for faces detected in the input image. Estimated age ranges can overlap. A face of a 5-year-old might have an estimated range of 4-6, while the face of a 6-year-old might have an estimated range of 4-8.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -37,6 +38,126 @@ pub struct AgeRange {
     pub low: Option<i64>,
 }
 
+/// <p>An array of facial attributes you want to be returned. This can be the default list of attributes or all attributes. If you don't specify a value for <code>Attributes</code> or if you specify <code>["DEFAULT"]</code>, the API returns the following subset of facial attributes: <code>BoundingBox</code>, <code>Confidence</code>, <code>Pose</code>, <code>Quality</code>, and <code>Landmarks</code>. If you provide <code>["ALL"]</code>, all facial attributes are returned, but the operation takes longer to complete.</p> <p>If you provide both, <code>["ALL", "DEFAULT"]</code>, the service uses a logical AND operator to determine which attributes to return (in this case, all attributes). </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Attribute {
+    /// <p><code>DEFAULT</code></p>
+    Default,
+    /// <p><code>ALL</code></p>
+    All,
+    /// <p><code>AGE_RANGE</code></p>
+    AgeRange,
+    /// <p><code>BEARD</code></p>
+    Beard,
+    /// <p><code>EMOTIONS</code></p>
+    Emotions,
+    /// <p><code>EYE_DIRECTION</code></p>
+    EyeDirection,
+    /// <p><code>EYEGLASSES</code></p>
+    Eyeglasses,
+    /// <p><code>EYES_OPEN</code></p>
+    EyesOpen,
+    /// <p><code>GENDER</code></p>
+    Gender,
+    /// <p><code>MOUTH_OPEN</code></p>
+    MouthOpen,
+    /// <p><code>MUSTACHE</code></p>
+    Mustache,
+    /// <p><code>FACE_OCCLUDED</code></p>
+    FaceOccluded,
+    /// <p><code>SMILE</code></p>
+    Smile,
+    /// <p><code>SUNGLASSES</code></p>
+    Sunglasses,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Attribute {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Attribute::Default => "DEFAULT",
+            Attribute::All => "ALL",
+            Attribute::AgeRange => "AGE_RANGE",
+            Attribute::Beard => "BEARD",
+            Attribute::Emotions => "EMOTIONS",
+            Attribute::EyeDirection => "EYE_DIRECTION",
+            Attribute::Eyeglasses => "EYEGLASSES",
+            Attribute::EyesOpen => "EYES_OPEN",
+            Attribute::Gender => "GENDER",
+            Attribute::MouthOpen => "MOUTH_OPEN",
+            Attribute::Mustache => "MUSTACHE",
+            Attribute::FaceOccluded => "FACE_OCCLUDED",
+            Attribute::Smile => "SMILE",
+            Attribute::Sunglasses => "SUNGLASSES",
+            Attribute::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Attribute {
+    fn from(value: &'a str) -> Attribute {
+        match value {
+            "DEFAULT" => Attribute::Default,
+            "ALL" => Attribute::All,
+            "AGE_RANGE" => Attribute::AgeRange,
+            "BEARD" => Attribute::Beard,
+            "EMOTIONS" => Attribute::Emotions,
+            "EYE_DIRECTION" => Attribute::EyeDirection,
+            "EYEGLASSES" => Attribute::Eyeglasses,
+            "EYES_OPEN" => Attribute::EyesOpen,
+            "GENDER" => Attribute::Gender,
+            "MOUTH_OPEN" => Attribute::MouthOpen,
+            "MUSTACHE" => Attribute::Mustache,
+            "FACE_OCCLUDED" => Attribute::FaceOccluded,
+            "SMILE" => Attribute::Smile,
+            "SUNGLASSES" => Attribute::Sunglasses,
+            _ => Attribute::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Attribute {
+    fn from(value: String) -> Attribute {
+        Attribute::from(value.as_str())
+    }
+}
+
+impl From<Attribute> for String {
+    fn from(value: Attribute) -> String {
+        match value {
+            Attribute::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Attribute {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Attribute {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Attribute, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Attribute::from(value))
+    }
+}
+
 /// <p>Indicates whether or not the face has a beard, and the confidence level in the determination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -143,6 +264,80 @@ pub struct CelebrityRecognition {
     pub timestamp: Option<i64>,
 }
 
+/// <p>Sort to use for celebrities returned in <code>Celebrities</code> field. Specify <code>ID</code> to sort by the celebrity identifier, specify <code>TIMESTAMP</code> to sort by the time the celebrity was recognized.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CelebrityRecognitionSortBy {
+    /// <p><code>ID</code></p>
+    Id,
+    /// <p><code>TIMESTAMP</code></p>
+    Timestamp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl CelebrityRecognitionSortBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            CelebrityRecognitionSortBy::Id => "ID",
+            CelebrityRecognitionSortBy::Timestamp => "TIMESTAMP",
+            CelebrityRecognitionSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for CelebrityRecognitionSortBy {
+    fn from(value: &'a str) -> CelebrityRecognitionSortBy {
+        match value {
+            "ID" => CelebrityRecognitionSortBy::Id,
+            "TIMESTAMP" => CelebrityRecognitionSortBy::Timestamp,
+            _ => CelebrityRecognitionSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for CelebrityRecognitionSortBy {
+    fn from(value: String) -> CelebrityRecognitionSortBy {
+        CelebrityRecognitionSortBy::from(value.as_str())
+    }
+}
+
+impl From<CelebrityRecognitionSortBy> for String {
+    fn from(value: CelebrityRecognitionSortBy) -> String {
+        match value {
+            CelebrityRecognitionSortBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for CelebrityRecognitionSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for CelebrityRecognitionSortBy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for CelebrityRecognitionSortBy {
+    fn deserialize<D>(
+        deserializer: D,
+    ) -> ::std::result::Result<CelebrityRecognitionSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(CelebrityRecognitionSortBy::from(value))
+    }
+}
+
 /// <p>Provides information about a face in a target image that matches the source image face analyzed by <code>CompareFaces</code>. The <code>Face</code> property contains the bounding box of the face in the target image. The <code>Similarity</code> property is the confidence that the source image face matches the face in the bounding box.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -171,6 +366,54 @@ pub struct CompareFacesRequest {
     pub target_image: Image,
 }
 
+impl CompareFacesRequest {
+    /// Returns a builder for `CompareFacesRequest`, checking its required members when built.
+    pub fn builder() -> CompareFacesRequestBuilder {
+        CompareFacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CompareFacesRequest`](struct.CompareFacesRequest.html).
+#[derive(Default, Debug)]
+pub struct CompareFacesRequestBuilder {
+    similarity_threshold: Option<f32>,
+    source_image: Option<Image>,
+    target_image: Option<Image>,
+}
+
+impl CompareFacesRequestBuilder {
+    /// Sets the [`similarity_threshold`](struct.CompareFacesRequest.html#structfield.similarity_threshold) member.
+    pub fn similarity_threshold<T: Into<f32>>(mut self, value: T) -> Self {
+        self.similarity_threshold = Some(value.into());
+        self
+    }
+
+    /// Sets the [`source_image`](struct.CompareFacesRequest.html#structfield.source_image) member.
+    pub fn source_image<T: Into<Image>>(mut self, value: T) -> Self {
+        self.source_image = Some(value.into());
+        self
+    }
+
+    /// Sets the [`target_image`](struct.CompareFacesRequest.html#structfield.target_image) member.
+    pub fn target_image<T: Into<Image>>(mut self, value: T) -> Self {
+        self.target_image = Some(value.into());
+        self
+    }
+
+    /// Builds the `CompareFacesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CompareFacesRequest, ::rusoto_core::BuildError> {
+        Ok(CompareFacesRequest {
+            similarity_threshold: self.similarity_threshold,
+            source_image: self
+                .source_image
+                .ok_or(::rusoto_core::BuildError::missing("source_image"))?,
+            target_image: self
+                .target_image
+                .ok_or(::rusoto_core::BuildError::missing("target_image"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CompareFacesResponse {
@@ -185,11 +428,11 @@ pub struct CompareFacesResponse {
     /// <p>The value of <code>SourceImageOrientationCorrection</code> is always null.</p> <p>If the input image is in .jpeg format, it might contain exchangeable image file format (Exif) metadata that includes the image's orientation. Amazon Rekognition uses this orientation information to perform image correction. The bounding box coordinates are translated to represent object locations after the orientation information in the Exif metadata is used to correct the image orientation. Images in .png format don't contain Exif metadata.</p> <p>Amazon Rekognition doesn’t perform image correction for images in .png format and .jpeg images without orientation information in the image Exif metadata. The bounding box coordinates aren't translated and represent the object locations before the image is rotated. </p>
     #[serde(rename = "SourceImageOrientationCorrection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_image_orientation_correction: Option<String>,
+    pub source_image_orientation_correction: Option<OrientationCorrection>,
     /// <p>The value of <code>TargetImageOrientationCorrection</code> is always null.</p> <p>If the input image is in .jpeg format, it might contain exchangeable image file format (Exif) metadata that includes the image's orientation. Amazon Rekognition uses this orientation information to perform image correction. The bounding box coordinates are translated to represent object locations after the orientation information in the Exif metadata is used to correct the image orientation. Images in .png format don't contain Exif metadata.</p> <p>Amazon Rekognition doesn’t perform image correction for images in .png format and .jpeg images without orientation information in the image Exif metadata. The bounding box coordinates aren't translated and represent the object locations before the image is rotated. </p>
     #[serde(rename = "TargetImageOrientationCorrection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_image_orientation_correction: Option<String>,
+    pub target_image_orientation_correction: Option<OrientationCorrection>,
     /// <p>An array of faces in the target image that did not match the source image face.</p>
     #[serde(rename = "UnmatchedFaces")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -250,6 +493,78 @@ pub struct ContentModerationDetection {
     pub timestamp: Option<i64>,
 }
 
+/// <p>Sort to use for elements in the <code>ModerationLabelDetections</code> array. Use <code>TIMESTAMP</code> to sort array elements by the time labels are detected. Use <code>NAME</code> to alphabetically group elements for a label together. Within each label group, the array element are sorted by detection confidence. The default sort is by <code>TIMESTAMP</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContentModerationSortBy {
+    /// <p><code>NAME</code></p>
+    Name,
+    /// <p><code>TIMESTAMP</code></p>
+    Timestamp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ContentModerationSortBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ContentModerationSortBy::Name => "NAME",
+            ContentModerationSortBy::Timestamp => "TIMESTAMP",
+            ContentModerationSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ContentModerationSortBy {
+    fn from(value: &'a str) -> ContentModerationSortBy {
+        match value {
+            "NAME" => ContentModerationSortBy::Name,
+            "TIMESTAMP" => ContentModerationSortBy::Timestamp,
+            _ => ContentModerationSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ContentModerationSortBy {
+    fn from(value: String) -> ContentModerationSortBy {
+        ContentModerationSortBy::from(value.as_str())
+    }
+}
+
+impl From<ContentModerationSortBy> for String {
+    fn from(value: ContentModerationSortBy) -> String {
+        match value {
+            ContentModerationSortBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ContentModerationSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ContentModerationSortBy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ContentModerationSortBy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ContentModerationSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ContentModerationSortBy::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct CreateCollectionRequest {
     /// <p>ID for the collection that you are creating.</p>
@@ -257,6 +572,38 @@ pub struct CreateCollectionRequest {
     pub collection_id: String,
 }
 
+impl CreateCollectionRequest {
+    /// Returns a builder for `CreateCollectionRequest`, checking its required members when built.
+    pub fn builder() -> CreateCollectionRequestBuilder {
+        CreateCollectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateCollectionRequest`](struct.CreateCollectionRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateCollectionRequestBuilder {
+    collection_id: Option<String>,
+}
+
+impl CreateCollectionRequestBuilder {
+    /// Sets the [`collection_id`](struct.CreateCollectionRequest.html#structfield.collection_id) member.
+    pub fn collection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.collection_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateCollectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateCollectionRequest, ::rusoto_core::BuildError> {
+        Ok(CreateCollectionRequest {
+            collection_id: self
+                .collection_id
+                .ok_or(::rusoto_core::BuildError::missing("collection_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateCollectionResponse {
@@ -293,6 +640,78 @@ pub struct CreateStreamProcessorRequest {
     pub settings: StreamProcessorSettings,
 }
 
+impl CreateStreamProcessorRequest {
+    /// Returns a builder for `CreateStreamProcessorRequest`, checking its required members when built.
+    pub fn builder() -> CreateStreamProcessorRequestBuilder {
+        CreateStreamProcessorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`CreateStreamProcessorRequest`](struct.CreateStreamProcessorRequest.html).
+#[derive(Default, Debug)]
+pub struct CreateStreamProcessorRequestBuilder {
+    input: Option<StreamProcessorInput>,
+    name: Option<String>,
+    output: Option<StreamProcessorOutput>,
+    role_arn: Option<String>,
+    settings: Option<StreamProcessorSettings>,
+}
+
+impl CreateStreamProcessorRequestBuilder {
+    /// Sets the [`input`](struct.CreateStreamProcessorRequest.html#structfield.input) member.
+    pub fn input<T: Into<StreamProcessorInput>>(mut self, value: T) -> Self {
+        self.input = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateStreamProcessorRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`output`](struct.CreateStreamProcessorRequest.html#structfield.output) member.
+    pub fn output<T: Into<StreamProcessorOutput>>(mut self, value: T) -> Self {
+        self.output = Some(value.into());
+        self
+    }
+
+    /// Sets the [`role_arn`](struct.CreateStreamProcessorRequest.html#structfield.role_arn) member.
+    pub fn role_arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.role_arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`settings`](struct.CreateStreamProcessorRequest.html#structfield.settings) member.
+    pub fn settings<T: Into<StreamProcessorSettings>>(mut self, value: T) -> Self {
+        self.settings = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateStreamProcessorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<CreateStreamProcessorRequest, ::rusoto_core::BuildError> {
+        Ok(CreateStreamProcessorRequest {
+            input: self
+                .input
+                .ok_or(::rusoto_core::BuildError::missing("input"))?,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            output: self
+                .output
+                .ok_or(::rusoto_core::BuildError::missing("output"))?,
+            role_arn: self
+                .role_arn
+                .ok_or(::rusoto_core::BuildError::missing("role_arn"))?,
+            settings: self
+                .settings
+                .ok_or(::rusoto_core::BuildError::missing("settings"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateStreamProcessorResponse {
@@ -309,6 +728,38 @@ pub struct DeleteCollectionRequest {
     pub collection_id: String,
 }
 
+impl DeleteCollectionRequest {
+    /// Returns a builder for `DeleteCollectionRequest`, checking its required members when built.
+    pub fn builder() -> DeleteCollectionRequestBuilder {
+        DeleteCollectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteCollectionRequest`](struct.DeleteCollectionRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteCollectionRequestBuilder {
+    collection_id: Option<String>,
+}
+
+impl DeleteCollectionRequestBuilder {
+    /// Sets the [`collection_id`](struct.DeleteCollectionRequest.html#structfield.collection_id) member.
+    pub fn collection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.collection_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteCollectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteCollectionRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteCollectionRequest {
+            collection_id: self
+                .collection_id
+                .ok_or(::rusoto_core::BuildError::missing("collection_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteCollectionResponse {
@@ -328,6 +779,46 @@ pub struct DeleteFacesRequest {
     pub face_ids: Vec<String>,
 }
 
+impl DeleteFacesRequest {
+    /// Returns a builder for `DeleteFacesRequest`, checking its required members when built.
+    pub fn builder() -> DeleteFacesRequestBuilder {
+        DeleteFacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteFacesRequest`](struct.DeleteFacesRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteFacesRequestBuilder {
+    collection_id: Option<String>,
+    face_ids: Option<Vec<String>>,
+}
+
+impl DeleteFacesRequestBuilder {
+    /// Sets the [`collection_id`](struct.DeleteFacesRequest.html#structfield.collection_id) member.
+    pub fn collection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.collection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`face_ids`](struct.DeleteFacesRequest.html#structfield.face_ids) member.
+    pub fn face_ids<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.face_ids = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteFacesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteFacesRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteFacesRequest {
+            collection_id: self
+                .collection_id
+                .ok_or(::rusoto_core::BuildError::missing("collection_id"))?,
+            face_ids: self
+                .face_ids
+                .ok_or(::rusoto_core::BuildError::missing("face_ids"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteFacesResponse {
@@ -344,6 +835,38 @@ pub struct DeleteStreamProcessorRequest {
     pub name: String,
 }
 
+impl DeleteStreamProcessorRequest {
+    /// Returns a builder for `DeleteStreamProcessorRequest`, checking its required members when built.
+    pub fn builder() -> DeleteStreamProcessorRequestBuilder {
+        DeleteStreamProcessorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteStreamProcessorRequest`](struct.DeleteStreamProcessorRequest.html).
+#[derive(Default, Debug)]
+pub struct DeleteStreamProcessorRequestBuilder {
+    name: Option<String>,
+}
+
+impl DeleteStreamProcessorRequestBuilder {
+    /// Sets the [`name`](struct.DeleteStreamProcessorRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteStreamProcessorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DeleteStreamProcessorRequest, ::rusoto_core::BuildError> {
+        Ok(DeleteStreamProcessorRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteStreamProcessorResponse {}
@@ -355,6 +878,38 @@ pub struct DescribeCollectionRequest {
     pub collection_id: String,
 }
 
+impl DescribeCollectionRequest {
+    /// Returns a builder for `DescribeCollectionRequest`, checking its required members when built.
+    pub fn builder() -> DescribeCollectionRequestBuilder {
+        DescribeCollectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeCollectionRequest`](struct.DescribeCollectionRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeCollectionRequestBuilder {
+    collection_id: Option<String>,
+}
+
+impl DescribeCollectionRequestBuilder {
+    /// Sets the [`collection_id`](struct.DescribeCollectionRequest.html#structfield.collection_id) member.
+    pub fn collection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.collection_id = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeCollectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeCollectionRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeCollectionRequest {
+            collection_id: self
+                .collection_id
+                .ok_or(::rusoto_core::BuildError::missing("collection_id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeCollectionResponse {
@@ -364,8 +919,9 @@ pub struct DescribeCollectionResponse {
     pub collection_arn: Option<String>,
     /// <p>The number of milliseconds since the Unix epoch time until the creation of the collection. The Unix epoch time is 00:00:00 Coordinated Universal Time (UTC), Thursday, 1 January 1970.</p>
     #[serde(rename = "CreationTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_timestamp: Option<f64>,
+    pub creation_timestamp: Option<EpochTimestamp>,
     /// <p>The number of faces that are indexed into the collection. To index faces into a collection, use <a>IndexFaces</a>.</p>
     #[serde(rename = "FaceCount")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -383,21 +939,55 @@ pub struct DescribeStreamProcessorRequest {
     pub name: String,
 }
 
+impl DescribeStreamProcessorRequest {
+    /// Returns a builder for `DescribeStreamProcessorRequest`, checking its required members when built.
+    pub fn builder() -> DescribeStreamProcessorRequestBuilder {
+        DescribeStreamProcessorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DescribeStreamProcessorRequest`](struct.DescribeStreamProcessorRequest.html).
+#[derive(Default, Debug)]
+pub struct DescribeStreamProcessorRequestBuilder {
+    name: Option<String>,
+}
+
+impl DescribeStreamProcessorRequestBuilder {
+    /// Sets the [`name`](struct.DescribeStreamProcessorRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DescribeStreamProcessorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DescribeStreamProcessorRequest, ::rusoto_core::BuildError> {
+        Ok(DescribeStreamProcessorRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DescribeStreamProcessorResponse {
     /// <p>Date and time the stream processor was created</p>
     #[serde(rename = "CreationTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub creation_timestamp: Option<f64>,
+    pub creation_timestamp: Option<EpochTimestamp>,
     /// <p>Kinesis video stream that provides the source streaming video.</p>
     #[serde(rename = "Input")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub input: Option<StreamProcessorInput>,
     /// <p>The time, in Unix format, the stream processor was last updated. For example, when the stream processor moves from a running state to a failed state, or when the user starts or stops the stream processor.</p>
     #[serde(rename = "LastUpdateTimestamp")]
+    #[serde(default, with = "::rusoto_core::timestamp::epoch_seconds::option")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_update_timestamp: Option<f64>,
+    pub last_update_timestamp: Option<EpochTimestamp>,
     /// <p>Name of the stream processor. </p>
     #[serde(rename = "Name")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -417,7 +1007,7 @@ pub struct DescribeStreamProcessorResponse {
     /// <p>Current status of the stream processor.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<StreamProcessorStatus>,
     /// <p>Detailed status message about the stream processor.</p>
     #[serde(rename = "StatusMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -433,12 +1023,50 @@ pub struct DetectFacesRequest {
     /// <p>An array of facial attributes you want to be returned. This can be the default list of attributes or all attributes. If you don't specify a value for <code>Attributes</code> or if you specify <code>["DEFAULT"]</code>, the API returns the following subset of facial attributes: <code>BoundingBox</code>, <code>Confidence</code>, <code>Pose</code>, <code>Quality</code>, and <code>Landmarks</code>. If you provide <code>["ALL"]</code>, all facial attributes are returned, but the operation takes longer to complete.</p> <p>If you provide both, <code>["ALL", "DEFAULT"]</code>, the service uses a logical AND operator to determine which attributes to return (in this case, all attributes). </p>
     #[serde(rename = "Attributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub attributes: Option<Vec<String>>,
+    pub attributes: Option<Vec<Attribute>>,
     /// <p>The input image as base64-encoded bytes or an S3 object. If you use the AWS CLI to call Amazon Rekognition operations, passing base64-encoded image bytes is not supported. </p> <p>If you are using an AWS SDK to call Amazon Rekognition, you might not need to base64-encode image bytes passed using the <code>Bytes</code> field. For more information, see Images in the Amazon Rekognition developer guide.</p>
     #[serde(rename = "Image")]
     pub image: Image,
 }
 
+impl DetectFacesRequest {
+    /// Returns a builder for `DetectFacesRequest`, checking its required members when built.
+    pub fn builder() -> DetectFacesRequestBuilder {
+        DetectFacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetectFacesRequest`](struct.DetectFacesRequest.html).
+#[derive(Default, Debug)]
+pub struct DetectFacesRequestBuilder {
+    attributes: Option<Vec<Attribute>>,
+    image: Option<Image>,
+}
+
+impl DetectFacesRequestBuilder {
+    /// Sets the [`attributes`](struct.DetectFacesRequest.html#structfield.attributes) member.
+    pub fn attributes<T: Into<Vec<Attribute>>>(mut self, value: T) -> Self {
+        self.attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image`](struct.DetectFacesRequest.html#structfield.image) member.
+    pub fn image<T: Into<Image>>(mut self, value: T) -> Self {
+        self.image = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectFacesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetectFacesRequest, ::rusoto_core::BuildError> {
+        Ok(DetectFacesRequest {
+            attributes: self.attributes,
+            image: self
+                .image
+                .ok_or(::rusoto_core::BuildError::missing("image"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetectFacesResponse {
@@ -449,7 +1077,7 @@ pub struct DetectFacesResponse {
     /// <p>The value of <code>OrientationCorrection</code> is always null.</p> <p>If the input image is in .jpeg format, it might contain exchangeable image file format (Exif) metadata that includes the image's orientation. Amazon Rekognition uses this orientation information to perform image correction. The bounding box coordinates are translated to represent object locations after the orientation information in the Exif metadata is used to correct the image orientation. Images in .png format don't contain Exif metadata.</p> <p>Amazon Rekognition doesn’t perform image correction for images in .png format and .jpeg images without orientation information in the image Exif metadata. The bounding box coordinates aren't translated and represent the object locations before the image is rotated. </p>
     #[serde(rename = "OrientationCorrection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub orientation_correction: Option<String>,
+    pub orientation_correction: Option<OrientationCorrection>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -467,6 +1095,52 @@ pub struct DetectLabelsRequest {
     pub min_confidence: Option<f32>,
 }
 
+impl DetectLabelsRequest {
+    /// Returns a builder for `DetectLabelsRequest`, checking its required members when built.
+    pub fn builder() -> DetectLabelsRequestBuilder {
+        DetectLabelsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetectLabelsRequest`](struct.DetectLabelsRequest.html).
+#[derive(Default, Debug)]
+pub struct DetectLabelsRequestBuilder {
+    image: Option<Image>,
+    max_labels: Option<i64>,
+    min_confidence: Option<f32>,
+}
+
+impl DetectLabelsRequestBuilder {
+    /// Sets the [`image`](struct.DetectLabelsRequest.html#structfield.image) member.
+    pub fn image<T: Into<Image>>(mut self, value: T) -> Self {
+        self.image = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_labels`](struct.DetectLabelsRequest.html#structfield.max_labels) member.
+    pub fn max_labels<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_labels = Some(value.into());
+        self
+    }
+
+    /// Sets the [`min_confidence`](struct.DetectLabelsRequest.html#structfield.min_confidence) member.
+    pub fn min_confidence<T: Into<f32>>(mut self, value: T) -> Self {
+        self.min_confidence = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectLabelsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetectLabelsRequest, ::rusoto_core::BuildError> {
+        Ok(DetectLabelsRequest {
+            image: self
+                .image
+                .ok_or(::rusoto_core::BuildError::missing("image"))?,
+            max_labels: self.max_labels,
+            min_confidence: self.min_confidence,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetectLabelsResponse {
@@ -481,7 +1155,7 @@ pub struct DetectLabelsResponse {
     /// <p>The value of <code>OrientationCorrection</code> is always null.</p> <p>If the input image is in .jpeg format, it might contain exchangeable image file format (Exif) metadata that includes the image's orientation. Amazon Rekognition uses this orientation information to perform image correction. The bounding box coordinates are translated to represent object locations after the orientation information in the Exif metadata is used to correct the image orientation. Images in .png format don't contain Exif metadata.</p> <p>Amazon Rekognition doesn’t perform image correction for images in .png format and .jpeg images without orientation information in the image Exif metadata. The bounding box coordinates aren't translated and represent the object locations before the image is rotated. </p>
     #[serde(rename = "OrientationCorrection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub orientation_correction: Option<String>,
+    pub orientation_correction: Option<OrientationCorrection>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
@@ -495,6 +1169,46 @@ pub struct DetectModerationLabelsRequest {
     pub min_confidence: Option<f32>,
 }
 
+impl DetectModerationLabelsRequest {
+    /// Returns a builder for `DetectModerationLabelsRequest`, checking its required members when built.
+    pub fn builder() -> DetectModerationLabelsRequestBuilder {
+        DetectModerationLabelsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetectModerationLabelsRequest`](struct.DetectModerationLabelsRequest.html).
+#[derive(Default, Debug)]
+pub struct DetectModerationLabelsRequestBuilder {
+    image: Option<Image>,
+    min_confidence: Option<f32>,
+}
+
+impl DetectModerationLabelsRequestBuilder {
+    /// Sets the [`image`](struct.DetectModerationLabelsRequest.html#structfield.image) member.
+    pub fn image<T: Into<Image>>(mut self, value: T) -> Self {
+        self.image = Some(value.into());
+        self
+    }
+
+    /// Sets the [`min_confidence`](struct.DetectModerationLabelsRequest.html#structfield.min_confidence) member.
+    pub fn min_confidence<T: Into<f32>>(mut self, value: T) -> Self {
+        self.min_confidence = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectModerationLabelsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<DetectModerationLabelsRequest, ::rusoto_core::BuildError> {
+        Ok(DetectModerationLabelsRequest {
+            image: self
+                .image
+                .ok_or(::rusoto_core::BuildError::missing("image"))?,
+            min_confidence: self.min_confidence,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetectModerationLabelsResponse {
@@ -515,6 +1229,36 @@ pub struct DetectTextRequest {
     pub image: Image,
 }
 
+impl DetectTextRequest {
+    /// Returns a builder for `DetectTextRequest`, checking its required members when built.
+    pub fn builder() -> DetectTextRequestBuilder {
+        DetectTextRequestBuilder::default()
+    }
+}
+
+/// Builds a [`DetectTextRequest`](struct.DetectTextRequest.html).
+#[derive(Default, Debug)]
+pub struct DetectTextRequestBuilder {
+    image: Option<Image>,
+}
+
+impl DetectTextRequestBuilder {
+    /// Sets the [`image`](struct.DetectTextRequest.html#structfield.image) member.
+    pub fn image<T: Into<Image>>(mut self, value: T) -> Self {
+        self.image = Some(value.into());
+        self
+    }
+
+    /// Builds the `DetectTextRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DetectTextRequest, ::rusoto_core::BuildError> {
+        Ok(DetectTextRequest {
+            image: self
+                .image
+                .ok_or(::rusoto_core::BuildError::missing("image"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DetectTextResponse {
@@ -535,14 +1279,114 @@ pub struct Emotion {
     /// <p>Type of emotion detected.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<EmotionName>,
+}
+
+/// <p>Type of emotion detected.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EmotionName {
+    /// <p><code>HAPPY</code></p>
+    Happy,
+    /// <p><code>SAD</code></p>
+    Sad,
+    /// <p><code>ANGRY</code></p>
+    Angry,
+    /// <p><code>CONFUSED</code></p>
+    Confused,
+    /// <p><code>DISGUSTED</code></p>
+    Disgusted,
+    /// <p><code>SURPRISED</code></p>
+    Surprised,
+    /// <p><code>CALM</code></p>
+    Calm,
+    /// <p><code>UNKNOWN</code></p>
+    UnknownValue,
+    /// <p><code>FEAR</code></p>
+    Fear,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl EmotionName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            EmotionName::Happy => "HAPPY",
+            EmotionName::Sad => "SAD",
+            EmotionName::Angry => "ANGRY",
+            EmotionName::Confused => "CONFUSED",
+            EmotionName::Disgusted => "DISGUSTED",
+            EmotionName::Surprised => "SURPRISED",
+            EmotionName::Calm => "CALM",
+            EmotionName::UnknownValue => "UNKNOWN",
+            EmotionName::Fear => "FEAR",
+            EmotionName::Unknown(ref value) => value,
+        }
+    }
 }
 
-/// <p>Indicates whether or not the eyes on the face are open, and the confidence level in the determination.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct EyeOpen {
-    /// <p>Level of confidence in the determination.</p>
+impl<'a> From<&'a str> for EmotionName {
+    fn from(value: &'a str) -> EmotionName {
+        match value {
+            "HAPPY" => EmotionName::Happy,
+            "SAD" => EmotionName::Sad,
+            "ANGRY" => EmotionName::Angry,
+            "CONFUSED" => EmotionName::Confused,
+            "DISGUSTED" => EmotionName::Disgusted,
+            "SURPRISED" => EmotionName::Surprised,
+            "CALM" => EmotionName::Calm,
+            "UNKNOWN" => EmotionName::UnknownValue,
+            "FEAR" => EmotionName::Fear,
+            _ => EmotionName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for EmotionName {
+    fn from(value: String) -> EmotionName {
+        EmotionName::from(value.as_str())
+    }
+}
+
+impl From<EmotionName> for String {
+    fn from(value: EmotionName) -> String {
+        match value {
+            EmotionName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for EmotionName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for EmotionName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for EmotionName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<EmotionName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(EmotionName::from(value))
+    }
+}
+
+/// <p>Indicates whether or not the eyes on the face are open, and the confidence level in the determination.</p>
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct EyeOpen {
+    /// <p>Level of confidence in the determination.</p>
     #[serde(rename = "Confidence")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub confidence: Option<f32>,
@@ -592,6 +1436,78 @@ pub struct Face {
     pub image_id: Option<String>,
 }
 
+/// <p>The face attributes you want returned.</p> <p> <code>DEFAULT</code> - The following subset of facial attributes are returned: BoundingBox, Confidence, Pose, Quality and Landmarks. </p> <p> <code>ALL</code> - All facial attributes are returned.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FaceAttributes {
+    /// <p><code>DEFAULT</code> - The following subset of facial attributes are returned: BoundingBox, Confidence, Pose, Quality and Landmarks.</p>
+    Default,
+    /// <p><code>ALL</code> - All facial attributes are returned.</p>
+    All,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FaceAttributes {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FaceAttributes::Default => "DEFAULT",
+            FaceAttributes::All => "ALL",
+            FaceAttributes::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FaceAttributes {
+    fn from(value: &'a str) -> FaceAttributes {
+        match value {
+            "DEFAULT" => FaceAttributes::Default,
+            "ALL" => FaceAttributes::All,
+            _ => FaceAttributes::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FaceAttributes {
+    fn from(value: String) -> FaceAttributes {
+        FaceAttributes::from(value.as_str())
+    }
+}
+
+impl From<FaceAttributes> for String {
+    fn from(value: FaceAttributes) -> String {
+        match value {
+            FaceAttributes::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FaceAttributes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FaceAttributes {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FaceAttributes {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FaceAttributes, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FaceAttributes::from(value))
+    }
+}
+
 /// <p>Structure containing attributes of the face that the algorithm detected.</p> <p>A <code>FaceDetail</code> object contains either the default facial attributes or all facial attributes. The default attributes are <code>BoundingBox</code>, <code>Confidence</code>, <code>Landmarks</code>, <code>Pose</code>, and <code>Quality</code>.</p> <p> <a>GetFaceDetection</a> is the only Amazon Rekognition Video stored video operation that can return a <code>FaceDetail</code> object with all attributes. To specify which attributes to return, use the <code>FaceAttributes</code> input parameter for <a>StartFaceDetection</a>. The following Amazon Rekognition Video operations return only the default attributes. The corresponding Start operations don't have a <code>FaceAttributes</code> input parameter.</p> <ul> <li> <p>GetCelebrityRecognition</p> </li> <li> <p>GetPersonTracking</p> </li> <li> <p>GetFaceSearch</p> </li> </ul> <p>The Amazon Rekognition Image <a>DetectFaces</a> and <a>IndexFaces</a> operations can return all facial attributes. To specify which attributes to return, use the <code>Attributes</code> input parameter for <code>DetectFaces</code>. For <code>IndexFaces</code>, use the <code>DetectAttributes</code> input parameter.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -713,6 +1629,78 @@ pub struct FaceSearchSettings {
     pub face_match_threshold: Option<f32>,
 }
 
+/// <p>Sort to use for grouping faces in the response. Use <code>TIMESTAMP</code> to group faces by the time that they are recognized. Use <code>INDEX</code> to sort by recognized faces. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FaceSearchSortBy {
+    /// <p><code>INDEX</code></p>
+    Index,
+    /// <p><code>TIMESTAMP</code></p>
+    Timestamp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl FaceSearchSortBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            FaceSearchSortBy::Index => "INDEX",
+            FaceSearchSortBy::Timestamp => "TIMESTAMP",
+            FaceSearchSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for FaceSearchSortBy {
+    fn from(value: &'a str) -> FaceSearchSortBy {
+        match value {
+            "INDEX" => FaceSearchSortBy::Index,
+            "TIMESTAMP" => FaceSearchSortBy::Timestamp,
+            _ => FaceSearchSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for FaceSearchSortBy {
+    fn from(value: String) -> FaceSearchSortBy {
+        FaceSearchSortBy::from(value.as_str())
+    }
+}
+
+impl From<FaceSearchSortBy> for String {
+    fn from(value: FaceSearchSortBy) -> String {
+        match value {
+            FaceSearchSortBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for FaceSearchSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for FaceSearchSortBy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for FaceSearchSortBy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<FaceSearchSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(FaceSearchSortBy::from(value))
+    }
+}
+
 /// <p>Gender of the face and the confidence level in the determination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -724,7 +1712,79 @@ pub struct Gender {
     /// <p>Gender of the face.</p>
     #[serde(rename = "Value")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<String>,
+    pub value: Option<GenderType>,
+}
+
+/// <p>Gender of the face.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GenderType {
+    /// <p><code>Male</code></p>
+    Male,
+    /// <p><code>Female</code></p>
+    Female,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl GenderType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GenderType::Male => "Male",
+            GenderType::Female => "Female",
+            GenderType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GenderType {
+    fn from(value: &'a str) -> GenderType {
+        match value {
+            "Male" => GenderType::Male,
+            "Female" => GenderType::Female,
+            _ => GenderType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GenderType {
+    fn from(value: String) -> GenderType {
+        GenderType::from(value.as_str())
+    }
+}
+
+impl From<GenderType> for String {
+    fn from(value: GenderType) -> String {
+        match value {
+            GenderType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GenderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for GenderType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GenderType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<GenderType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(GenderType::from(value))
+    }
 }
 
 /// <p>Information about where the text detected by <a>DetectText</a> is located on an image.</p>
@@ -748,6 +1808,36 @@ pub struct GetCelebrityInfoRequest {
     pub id: String,
 }
 
+impl GetCelebrityInfoRequest {
+    /// Returns a builder for `GetCelebrityInfoRequest`, checking its required members when built.
+    pub fn builder() -> GetCelebrityInfoRequestBuilder {
+        GetCelebrityInfoRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCelebrityInfoRequest`](struct.GetCelebrityInfoRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCelebrityInfoRequestBuilder {
+    id: Option<String>,
+}
+
+impl GetCelebrityInfoRequestBuilder {
+    /// Sets the [`id`](struct.GetCelebrityInfoRequest.html#structfield.id) member.
+    pub fn id<T: Into<String>>(mut self, value: T) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCelebrityInfoRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCelebrityInfoRequest, ::rusoto_core::BuildError> {
+        Ok(GetCelebrityInfoRequest {
+            id: self.id.ok_or(::rusoto_core::BuildError::missing("id"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetCelebrityInfoResponse {
@@ -777,7 +1867,63 @@ pub struct GetCelebrityRecognitionRequest {
     /// <p>Sort to use for celebrities returned in <code>Celebrities</code> field. Specify <code>ID</code> to sort by the celebrity identifier, specify <code>TIMESTAMP</code> to sort by the time the celebrity was recognized.</p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<CelebrityRecognitionSortBy>,
+}
+
+impl GetCelebrityRecognitionRequest {
+    /// Returns a builder for `GetCelebrityRecognitionRequest`, checking its required members when built.
+    pub fn builder() -> GetCelebrityRecognitionRequestBuilder {
+        GetCelebrityRecognitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetCelebrityRecognitionRequest`](struct.GetCelebrityRecognitionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetCelebrityRecognitionRequestBuilder {
+    job_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_by: Option<CelebrityRecognitionSortBy>,
+}
+
+impl GetCelebrityRecognitionRequestBuilder {
+    /// Sets the [`job_id`](struct.GetCelebrityRecognitionRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetCelebrityRecognitionRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetCelebrityRecognitionRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_by`](struct.GetCelebrityRecognitionRequest.html#structfield.sort_by) member.
+    pub fn sort_by<T: Into<CelebrityRecognitionSortBy>>(mut self, value: T) -> Self {
+        self.sort_by = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetCelebrityRecognitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetCelebrityRecognitionRequest, ::rusoto_core::BuildError> {
+        Ok(GetCelebrityRecognitionRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_by: self.sort_by,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -790,7 +1936,7 @@ pub struct GetCelebrityRecognitionResponse {
     /// <p>The current status of the celebrity recognition job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<VideoJobStatus>,
     /// <p>If the response is truncated, Amazon Rekognition Video returns this token that you can use in the subsequent request to retrieve the next set of celebrities.</p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -821,7 +1967,63 @@ pub struct GetContentModerationRequest {
     /// <p>Sort to use for elements in the <code>ModerationLabelDetections</code> array. Use <code>TIMESTAMP</code> to sort array elements by the time labels are detected. Use <code>NAME</code> to alphabetically group elements for a label together. Within each label group, the array element are sorted by detection confidence. The default sort is by <code>TIMESTAMP</code>.</p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<ContentModerationSortBy>,
+}
+
+impl GetContentModerationRequest {
+    /// Returns a builder for `GetContentModerationRequest`, checking its required members when built.
+    pub fn builder() -> GetContentModerationRequestBuilder {
+        GetContentModerationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetContentModerationRequest`](struct.GetContentModerationRequest.html).
+#[derive(Default, Debug)]
+pub struct GetContentModerationRequestBuilder {
+    job_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_by: Option<ContentModerationSortBy>,
+}
+
+impl GetContentModerationRequestBuilder {
+    /// Sets the [`job_id`](struct.GetContentModerationRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetContentModerationRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetContentModerationRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_by`](struct.GetContentModerationRequest.html#structfield.sort_by) member.
+    pub fn sort_by<T: Into<ContentModerationSortBy>>(mut self, value: T) -> Self {
+        self.sort_by = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetContentModerationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetContentModerationRequest, ::rusoto_core::BuildError> {
+        Ok(GetContentModerationRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_by: self.sort_by,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -830,7 +2032,7 @@ pub struct GetContentModerationResponse {
     /// <p>The current status of the unsafe content analysis job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<VideoJobStatus>,
     /// <p>The detected unsafe content labels and the time(s) they were detected.</p>
     #[serde(rename = "ModerationLabels")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -868,6 +2070,54 @@ pub struct GetFaceDetectionRequest {
     pub next_token: Option<String>,
 }
 
+impl GetFaceDetectionRequest {
+    /// Returns a builder for `GetFaceDetectionRequest`, checking its required members when built.
+    pub fn builder() -> GetFaceDetectionRequestBuilder {
+        GetFaceDetectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFaceDetectionRequest`](struct.GetFaceDetectionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFaceDetectionRequestBuilder {
+    job_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl GetFaceDetectionRequestBuilder {
+    /// Sets the [`job_id`](struct.GetFaceDetectionRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetFaceDetectionRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetFaceDetectionRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFaceDetectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetFaceDetectionRequest, ::rusoto_core::BuildError> {
+        Ok(GetFaceDetectionRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetFaceDetectionResponse {
@@ -878,7 +2128,7 @@ pub struct GetFaceDetectionResponse {
     /// <p>The current status of the face detection job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<VideoJobStatus>,
     /// <p>If the response is truncated, Amazon Rekognition returns this token that you can use in the subsequent request to retrieve the next set of faces. </p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -909,7 +2159,61 @@ pub struct GetFaceSearchRequest {
     /// <p>Sort to use for grouping faces in the response. Use <code>TIMESTAMP</code> to group faces by the time that they are recognized. Use <code>INDEX</code> to sort by recognized faces. </p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<FaceSearchSortBy>,
+}
+
+impl GetFaceSearchRequest {
+    /// Returns a builder for `GetFaceSearchRequest`, checking its required members when built.
+    pub fn builder() -> GetFaceSearchRequestBuilder {
+        GetFaceSearchRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetFaceSearchRequest`](struct.GetFaceSearchRequest.html).
+#[derive(Default, Debug)]
+pub struct GetFaceSearchRequestBuilder {
+    job_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_by: Option<FaceSearchSortBy>,
+}
+
+impl GetFaceSearchRequestBuilder {
+    /// Sets the [`job_id`](struct.GetFaceSearchRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetFaceSearchRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetFaceSearchRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_by`](struct.GetFaceSearchRequest.html#structfield.sort_by) member.
+    pub fn sort_by<T: Into<FaceSearchSortBy>>(mut self, value: T) -> Self {
+        self.sort_by = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetFaceSearchRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetFaceSearchRequest, ::rusoto_core::BuildError> {
+        Ok(GetFaceSearchRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_by: self.sort_by,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -918,7 +2222,7 @@ pub struct GetFaceSearchResponse {
     /// <p>The current status of the face search job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<VideoJobStatus>,
     /// <p>If the response is truncated, Amazon Rekognition Video returns this token that you can use in the subsequent request to retrieve the next set of search results. </p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -953,7 +2257,63 @@ pub struct GetLabelDetectionRequest {
     /// <p>Sort to use for elements in the <code>Labels</code> array. Use <code>TIMESTAMP</code> to sort array elements by the time labels are detected. Use <code>NAME</code> to alphabetically group elements for a label together. Within each label group, the array element are sorted by detection confidence. The default sort is by <code>TIMESTAMP</code>.</p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<LabelDetectionSortBy>,
+}
+
+impl GetLabelDetectionRequest {
+    /// Returns a builder for `GetLabelDetectionRequest`, checking its required members when built.
+    pub fn builder() -> GetLabelDetectionRequestBuilder {
+        GetLabelDetectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetLabelDetectionRequest`](struct.GetLabelDetectionRequest.html).
+#[derive(Default, Debug)]
+pub struct GetLabelDetectionRequestBuilder {
+    job_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_by: Option<LabelDetectionSortBy>,
+}
+
+impl GetLabelDetectionRequestBuilder {
+    /// Sets the [`job_id`](struct.GetLabelDetectionRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetLabelDetectionRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetLabelDetectionRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_by`](struct.GetLabelDetectionRequest.html#structfield.sort_by) member.
+    pub fn sort_by<T: Into<LabelDetectionSortBy>>(mut self, value: T) -> Self {
+        self.sort_by = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetLabelDetectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetLabelDetectionRequest, ::rusoto_core::BuildError> {
+        Ok(GetLabelDetectionRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_by: self.sort_by,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -962,7 +2322,7 @@ pub struct GetLabelDetectionResponse {
     /// <p>The current status of the label detection job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<VideoJobStatus>,
     /// <p>Version number of the label detection model that was used to detect labels.</p>
     #[serde(rename = "LabelModelVersion")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1001,7 +2361,63 @@ pub struct GetPersonTrackingRequest {
     /// <p>Sort to use for elements in the <code>Persons</code> array. Use <code>TIMESTAMP</code> to sort array elements by the time persons are detected. Use <code>INDEX</code> to sort by the tracked persons. If you sort by <code>INDEX</code>, the array elements for each person are sorted by detection confidence. The default sort is by <code>TIMESTAMP</code>.</p>
     #[serde(rename = "SortBy")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort_by: Option<String>,
+    pub sort_by: Option<PersonTrackingSortBy>,
+}
+
+impl GetPersonTrackingRequest {
+    /// Returns a builder for `GetPersonTrackingRequest`, checking its required members when built.
+    pub fn builder() -> GetPersonTrackingRequestBuilder {
+        GetPersonTrackingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`GetPersonTrackingRequest`](struct.GetPersonTrackingRequest.html).
+#[derive(Default, Debug)]
+pub struct GetPersonTrackingRequestBuilder {
+    job_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    sort_by: Option<PersonTrackingSortBy>,
+}
+
+impl GetPersonTrackingRequestBuilder {
+    /// Sets the [`job_id`](struct.GetPersonTrackingRequest.html#structfield.job_id) member.
+    pub fn job_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.GetPersonTrackingRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.GetPersonTrackingRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`sort_by`](struct.GetPersonTrackingRequest.html#structfield.sort_by) member.
+    pub fn sort_by<T: Into<PersonTrackingSortBy>>(mut self, value: T) -> Self {
+        self.sort_by = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetPersonTrackingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<GetPersonTrackingRequest, ::rusoto_core::BuildError> {
+        Ok(GetPersonTrackingRequest {
+            job_id: self
+                .job_id
+                .ok_or(::rusoto_core::BuildError::missing("job_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+            sort_by: self.sort_by,
+        })
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -1010,7 +2426,7 @@ pub struct GetPersonTrackingResponse {
     /// <p>The current status of the person tracking job.</p>
     #[serde(rename = "JobStatus")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub job_status: Option<String>,
+    pub job_status: Option<VideoJobStatus>,
     /// <p>If the response is truncated, Amazon Rekognition Video returns this token that you can use in the subsequent request to retrieve the next set of persons. </p>
     #[serde(rename = "NextToken")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1069,7 +2485,7 @@ pub struct IndexFacesRequest {
     /// <p>An array of facial attributes that you want to be returned. This can be the default list of attributes or all attributes. If you don't specify a value for <code>Attributes</code> or if you specify <code>["DEFAULT"]</code>, the API returns the following subset of facial attributes: <code>BoundingBox</code>, <code>Confidence</code>, <code>Pose</code>, <code>Quality</code>, and <code>Landmarks</code>. If you provide <code>["ALL"]</code>, all facial attributes are returned, but the operation takes longer to complete.</p> <p>If you provide both, <code>["ALL", "DEFAULT"]</code>, the service uses a logical AND operator to determine which attributes to return (in this case, all attributes). </p>
     #[serde(rename = "DetectionAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub detection_attributes: Option<Vec<String>>,
+    pub detection_attributes: Option<Vec<Attribute>>,
     /// <p>The ID you want to assign to all the faces detected in the image.</p>
     #[serde(rename = "ExternalImageId")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1084,28 +2500,100 @@ pub struct IndexFacesRequest {
     /// <p>A filter that specifies how much filtering is done to identify faces that are detected with low quality. Filtered faces aren't indexed. If you specify <code>AUTO</code>, filtering prioritizes the identification of faces that don’t meet the required quality bar chosen by Amazon Rekognition. The quality bar is based on a variety of common use cases. Low-quality detections can occur for a number of reasons. Some examples are an object that's misidentified as a face, a face that's too blurry, or a face with a pose that's too extreme to use. If you specify <code>NONE</code>, no filtering is performed. The default value is AUTO.</p> <p>To use quality filtering, the collection you are using must be associated with version 3 of the face model.</p>
     #[serde(rename = "QualityFilter")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub quality_filter: Option<String>,
+    pub quality_filter: Option<QualityFilter>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[cfg_attr(test, derive(Serialize))]
-pub struct IndexFacesResponse {
-    /// <p>The version number of the face detection model that's associated with the input collection (<code>CollectionId</code>).</p>
-    #[serde(rename = "FaceModelVersion")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub face_model_version: Option<String>,
-    /// <p>An array of faces detected and added to the collection. For more information, see Searching Faces in a Collection in the Amazon Rekognition Developer Guide. </p>
-    #[serde(rename = "FaceRecords")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub face_records: Option<Vec<FaceRecord>>,
-    /// <p>If your collection is associated with a face detection model that's later than version 3.0, the value of <code>OrientationCorrection</code> is always null and no orientation information is returned.</p> <p>If your collection is associated with a face detection model that's version 3.0 or earlier, the following applies:</p> <ul> <li> <p>If the input image is in .jpeg format, it might contain exchangeable image file format (Exif) metadata that includes the image's orientation. Amazon Rekognition uses this orientation information to perform image correction - the bounding box coordinates are translated to represent object locations after the orientation information in the Exif metadata is used to correct the image orientation. Images in .png format don't contain Exif metadata. The value of <code>OrientationCorrection</code> is null.</p> </li> <li> <p>If the image doesn't contain orientation information in its Exif metadata, Amazon Rekognition returns an estimated orientation (ROTATE_0, ROTATE_90, ROTATE_180, ROTATE_270). Amazon Rekognition doesn’t perform image correction for images. The bounding box coordinates aren't translated and represent the object locations before the image is rotated.</p> </li> </ul> <p>Bounding box information is returned in the <code>FaceRecords</code> array. You can get the version of the face detection model by calling <a>DescribeCollection</a>. </p>
-    #[serde(rename = "OrientationCorrection")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub orientation_correction: Option<String>,
-    /// <p>An array of faces that were detected in the image but weren't indexed. They weren't indexed because the quality filter identified them as low quality, or the <code>MaxFaces</code> request parameter filtered them out. To use the quality filter, you specify the <code>QualityFilter</code> request parameter.</p>
-    #[serde(rename = "UnindexedFaces")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub unindexed_faces: Option<Vec<UnindexedFace>>,
+impl IndexFacesRequest {
+    /// Returns a builder for `IndexFacesRequest`, checking its required members when built.
+    pub fn builder() -> IndexFacesRequestBuilder {
+        IndexFacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`IndexFacesRequest`](struct.IndexFacesRequest.html).
+#[derive(Default, Debug)]
+pub struct IndexFacesRequestBuilder {
+    collection_id: Option<String>,
+    detection_attributes: Option<Vec<Attribute>>,
+    external_image_id: Option<String>,
+    image: Option<Image>,
+    max_faces: Option<i64>,
+    quality_filter: Option<QualityFilter>,
+}
+
+impl IndexFacesRequestBuilder {
+    /// Sets the [`collection_id`](struct.IndexFacesRequest.html#structfield.collection_id) member.
+    pub fn collection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.collection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`detection_attributes`](struct.IndexFacesRequest.html#structfield.detection_attributes) member.
+    pub fn detection_attributes<T: Into<Vec<Attribute>>>(mut self, value: T) -> Self {
+        self.detection_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`external_image_id`](struct.IndexFacesRequest.html#structfield.external_image_id) member.
+    pub fn external_image_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.external_image_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image`](struct.IndexFacesRequest.html#structfield.image) member.
+    pub fn image<T: Into<Image>>(mut self, value: T) -> Self {
+        self.image = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_faces`](struct.IndexFacesRequest.html#structfield.max_faces) member.
+    pub fn max_faces<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_faces = Some(value.into());
+        self
+    }
+
+    /// Sets the [`quality_filter`](struct.IndexFacesRequest.html#structfield.quality_filter) member.
+    pub fn quality_filter<T: Into<QualityFilter>>(mut self, value: T) -> Self {
+        self.quality_filter = Some(value.into());
+        self
+    }
+
+    /// Builds the `IndexFacesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<IndexFacesRequest, ::rusoto_core::BuildError> {
+        Ok(IndexFacesRequest {
+            collection_id: self
+                .collection_id
+                .ok_or(::rusoto_core::BuildError::missing("collection_id"))?,
+            detection_attributes: self.detection_attributes,
+            external_image_id: self.external_image_id,
+            image: self
+                .image
+                .ok_or(::rusoto_core::BuildError::missing("image"))?,
+            max_faces: self.max_faces,
+            quality_filter: self.quality_filter,
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct IndexFacesResponse {
+    /// <p>The version number of the face detection model that's associated with the input collection (<code>CollectionId</code>).</p>
+    #[serde(rename = "FaceModelVersion")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub face_model_version: Option<String>,
+    /// <p>An array of faces detected and added to the collection. For more information, see Searching Faces in a Collection in the Amazon Rekognition Developer Guide. </p>
+    #[serde(rename = "FaceRecords")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub face_records: Option<Vec<FaceRecord>>,
+    /// <p>If your collection is associated with a face detection model that's later than version 3.0, the value of <code>OrientationCorrection</code> is always null and no orientation information is returned.</p> <p>If your collection is associated with a face detection model that's version 3.0 or earlier, the following applies:</p> <ul> <li> <p>If the input image is in .jpeg format, it might contain exchangeable image file format (Exif) metadata that includes the image's orientation. Amazon Rekognition uses this orientation information to perform image correction - the bounding box coordinates are translated to represent object locations after the orientation information in the Exif metadata is used to correct the image orientation. Images in .png format don't contain Exif metadata. The value of <code>OrientationCorrection</code> is null.</p> </li> <li> <p>If the image doesn't contain orientation information in its Exif metadata, Amazon Rekognition returns an estimated orientation (ROTATE_0, ROTATE_90, ROTATE_180, ROTATE_270). Amazon Rekognition doesn’t perform image correction for images. The bounding box coordinates aren't translated and represent the object locations before the image is rotated.</p> </li> </ul> <p>Bounding box information is returned in the <code>FaceRecords</code> array. You can get the version of the face detection model by calling <a>DescribeCollection</a>. </p>
+    #[serde(rename = "OrientationCorrection")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orientation_correction: Option<OrientationCorrection>,
+    /// <p>An array of faces that were detected in the image but weren't indexed. They weren't indexed because the quality filter identified them as low quality, or the <code>MaxFaces</code> request parameter filtered them out. To use the quality filter, you specify the <code>QualityFilter</code> request parameter.</p>
+    #[serde(rename = "UnindexedFaces")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unindexed_faces: Option<Vec<UnindexedFace>>,
 }
 
 /// <p>An instance of a label returned by Amazon Rekognition Image (<a>DetectLabels</a>) or by Amazon Rekognition Video (<a>GetLabelDetection</a>).</p>
@@ -1176,6 +2664,78 @@ pub struct LabelDetection {
     pub timestamp: Option<i64>,
 }
 
+/// <p>Sort to use for elements in the <code>Labels</code> array. Use <code>TIMESTAMP</code> to sort array elements by the time labels are detected. Use <code>NAME</code> to alphabetically group elements for a label together. Within each label group, the array element are sorted by detection confidence. The default sort is by <code>TIMESTAMP</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LabelDetectionSortBy {
+    /// <p><code>NAME</code></p>
+    Name,
+    /// <p><code>TIMESTAMP</code></p>
+    Timestamp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LabelDetectionSortBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LabelDetectionSortBy::Name => "NAME",
+            LabelDetectionSortBy::Timestamp => "TIMESTAMP",
+            LabelDetectionSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LabelDetectionSortBy {
+    fn from(value: &'a str) -> LabelDetectionSortBy {
+        match value {
+            "NAME" => LabelDetectionSortBy::Name,
+            "TIMESTAMP" => LabelDetectionSortBy::Timestamp,
+            _ => LabelDetectionSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LabelDetectionSortBy {
+    fn from(value: String) -> LabelDetectionSortBy {
+        LabelDetectionSortBy::from(value.as_str())
+    }
+}
+
+impl From<LabelDetectionSortBy> for String {
+    fn from(value: LabelDetectionSortBy) -> String {
+        match value {
+            LabelDetectionSortBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LabelDetectionSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LabelDetectionSortBy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LabelDetectionSortBy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LabelDetectionSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LabelDetectionSortBy::from(value))
+    }
+}
+
 /// <p>Indicates the location of the landmark on the face.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1183,7 +2743,7 @@ pub struct Landmark {
     /// <p>Type of landmark.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<LandmarkType>,
     /// <p>The x-coordinate from the top left of the landmark expressed as the ratio of the width of the image. For example, if the image is 700 x 200 and the x-coordinate of the landmark is at 350 pixels, this value is 0.5. </p>
     #[serde(rename = "X")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1194,6 +2754,190 @@ pub struct Landmark {
     pub y: Option<f32>,
 }
 
+/// <p>Type of landmark.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LandmarkType {
+    /// <p><code>eyeLeft</code></p>
+    EyeLeft,
+    /// <p><code>eyeRight</code></p>
+    EyeRight,
+    /// <p><code>nose</code></p>
+    Nose,
+    /// <p><code>mouthLeft</code></p>
+    MouthLeft,
+    /// <p><code>mouthRight</code></p>
+    MouthRight,
+    /// <p><code>leftEyeBrowLeft</code></p>
+    LeftEyeBrowLeft,
+    /// <p><code>leftEyeBrowRight</code></p>
+    LeftEyeBrowRight,
+    /// <p><code>leftEyeBrowUp</code></p>
+    LeftEyeBrowUp,
+    /// <p><code>rightEyeBrowLeft</code></p>
+    RightEyeBrowLeft,
+    /// <p><code>rightEyeBrowRight</code></p>
+    RightEyeBrowRight,
+    /// <p><code>rightEyeBrowUp</code></p>
+    RightEyeBrowUp,
+    /// <p><code>leftEyeLeft</code></p>
+    LeftEyeLeft,
+    /// <p><code>leftEyeRight</code></p>
+    LeftEyeRight,
+    /// <p><code>leftEyeUp</code></p>
+    LeftEyeUp,
+    /// <p><code>leftEyeDown</code></p>
+    LeftEyeDown,
+    /// <p><code>rightEyeLeft</code></p>
+    RightEyeLeft,
+    /// <p><code>rightEyeRight</code></p>
+    RightEyeRight,
+    /// <p><code>rightEyeUp</code></p>
+    RightEyeUp,
+    /// <p><code>rightEyeDown</code></p>
+    RightEyeDown,
+    /// <p><code>noseLeft</code></p>
+    NoseLeft,
+    /// <p><code>noseRight</code></p>
+    NoseRight,
+    /// <p><code>mouthUp</code></p>
+    MouthUp,
+    /// <p><code>mouthDown</code></p>
+    MouthDown,
+    /// <p><code>leftPupil</code></p>
+    LeftPupil,
+    /// <p><code>rightPupil</code></p>
+    RightPupil,
+    /// <p><code>upperJawlineLeft</code></p>
+    UpperJawlineLeft,
+    /// <p><code>midJawlineLeft</code></p>
+    MidJawlineLeft,
+    /// <p><code>chinBottom</code></p>
+    ChinBottom,
+    /// <p><code>midJawlineRight</code></p>
+    MidJawlineRight,
+    /// <p><code>upperJawlineRight</code></p>
+    UpperJawlineRight,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl LandmarkType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            LandmarkType::EyeLeft => "eyeLeft",
+            LandmarkType::EyeRight => "eyeRight",
+            LandmarkType::Nose => "nose",
+            LandmarkType::MouthLeft => "mouthLeft",
+            LandmarkType::MouthRight => "mouthRight",
+            LandmarkType::LeftEyeBrowLeft => "leftEyeBrowLeft",
+            LandmarkType::LeftEyeBrowRight => "leftEyeBrowRight",
+            LandmarkType::LeftEyeBrowUp => "leftEyeBrowUp",
+            LandmarkType::RightEyeBrowLeft => "rightEyeBrowLeft",
+            LandmarkType::RightEyeBrowRight => "rightEyeBrowRight",
+            LandmarkType::RightEyeBrowUp => "rightEyeBrowUp",
+            LandmarkType::LeftEyeLeft => "leftEyeLeft",
+            LandmarkType::LeftEyeRight => "leftEyeRight",
+            LandmarkType::LeftEyeUp => "leftEyeUp",
+            LandmarkType::LeftEyeDown => "leftEyeDown",
+            LandmarkType::RightEyeLeft => "rightEyeLeft",
+            LandmarkType::RightEyeRight => "rightEyeRight",
+            LandmarkType::RightEyeUp => "rightEyeUp",
+            LandmarkType::RightEyeDown => "rightEyeDown",
+            LandmarkType::NoseLeft => "noseLeft",
+            LandmarkType::NoseRight => "noseRight",
+            LandmarkType::MouthUp => "mouthUp",
+            LandmarkType::MouthDown => "mouthDown",
+            LandmarkType::LeftPupil => "leftPupil",
+            LandmarkType::RightPupil => "rightPupil",
+            LandmarkType::UpperJawlineLeft => "upperJawlineLeft",
+            LandmarkType::MidJawlineLeft => "midJawlineLeft",
+            LandmarkType::ChinBottom => "chinBottom",
+            LandmarkType::MidJawlineRight => "midJawlineRight",
+            LandmarkType::UpperJawlineRight => "upperJawlineRight",
+            LandmarkType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for LandmarkType {
+    fn from(value: &'a str) -> LandmarkType {
+        match value {
+            "eyeLeft" => LandmarkType::EyeLeft,
+            "eyeRight" => LandmarkType::EyeRight,
+            "nose" => LandmarkType::Nose,
+            "mouthLeft" => LandmarkType::MouthLeft,
+            "mouthRight" => LandmarkType::MouthRight,
+            "leftEyeBrowLeft" => LandmarkType::LeftEyeBrowLeft,
+            "leftEyeBrowRight" => LandmarkType::LeftEyeBrowRight,
+            "leftEyeBrowUp" => LandmarkType::LeftEyeBrowUp,
+            "rightEyeBrowLeft" => LandmarkType::RightEyeBrowLeft,
+            "rightEyeBrowRight" => LandmarkType::RightEyeBrowRight,
+            "rightEyeBrowUp" => LandmarkType::RightEyeBrowUp,
+            "leftEyeLeft" => LandmarkType::LeftEyeLeft,
+            "leftEyeRight" => LandmarkType::LeftEyeRight,
+            "leftEyeUp" => LandmarkType::LeftEyeUp,
+            "leftEyeDown" => LandmarkType::LeftEyeDown,
+            "rightEyeLeft" => LandmarkType::RightEyeLeft,
+            "rightEyeRight" => LandmarkType::RightEyeRight,
+            "rightEyeUp" => LandmarkType::RightEyeUp,
+            "rightEyeDown" => LandmarkType::RightEyeDown,
+            "noseLeft" => LandmarkType::NoseLeft,
+            "noseRight" => LandmarkType::NoseRight,
+            "mouthUp" => LandmarkType::MouthUp,
+            "mouthDown" => LandmarkType::MouthDown,
+            "leftPupil" => LandmarkType::LeftPupil,
+            "rightPupil" => LandmarkType::RightPupil,
+            "upperJawlineLeft" => LandmarkType::UpperJawlineLeft,
+            "midJawlineLeft" => LandmarkType::MidJawlineLeft,
+            "chinBottom" => LandmarkType::ChinBottom,
+            "midJawlineRight" => LandmarkType::MidJawlineRight,
+            "upperJawlineRight" => LandmarkType::UpperJawlineRight,
+            _ => LandmarkType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for LandmarkType {
+    fn from(value: String) -> LandmarkType {
+        LandmarkType::from(value.as_str())
+    }
+}
+
+impl From<LandmarkType> for String {
+    fn from(value: LandmarkType) -> String {
+        match value {
+            LandmarkType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LandmarkType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for LandmarkType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for LandmarkType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<LandmarkType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(LandmarkType::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ListCollectionsRequest {
     /// <p>Maximum number of collection IDs to return. </p>
@@ -1206,6 +2950,42 @@ pub struct ListCollectionsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListCollectionsRequest {
+    /// Returns a builder for `ListCollectionsRequest`, checking its required members when built.
+    pub fn builder() -> ListCollectionsRequestBuilder {
+        ListCollectionsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListCollectionsRequest`](struct.ListCollectionsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListCollectionsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListCollectionsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListCollectionsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListCollectionsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListCollectionsRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListCollectionsRequest, ::rusoto_core::BuildError> {
+        Ok(ListCollectionsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListCollectionsResponse {
@@ -1238,6 +3018,52 @@ pub struct ListFacesRequest {
     pub next_token: Option<String>,
 }
 
+impl ListFacesRequest {
+    /// Returns a builder for `ListFacesRequest`, checking its required members when built.
+    pub fn builder() -> ListFacesRequestBuilder {
+        ListFacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListFacesRequest`](struct.ListFacesRequest.html).
+#[derive(Default, Debug)]
+pub struct ListFacesRequestBuilder {
+    collection_id: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListFacesRequestBuilder {
+    /// Sets the [`collection_id`](struct.ListFacesRequest.html#structfield.collection_id) member.
+    pub fn collection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.collection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListFacesRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListFacesRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListFacesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListFacesRequest, ::rusoto_core::BuildError> {
+        Ok(ListFacesRequest {
+            collection_id: self
+                .collection_id
+                .ok_or(::rusoto_core::BuildError::missing("collection_id"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListFacesResponse {
@@ -1267,6 +3093,44 @@ pub struct ListStreamProcessorsRequest {
     pub next_token: Option<String>,
 }
 
+impl ListStreamProcessorsRequest {
+    /// Returns a builder for `ListStreamProcessorsRequest`, checking its required members when built.
+    pub fn builder() -> ListStreamProcessorsRequestBuilder {
+        ListStreamProcessorsRequestBuilder::default()
+    }
+}
+
+/// Builds a [`ListStreamProcessorsRequest`](struct.ListStreamProcessorsRequest.html).
+#[derive(Default, Debug)]
+pub struct ListStreamProcessorsRequestBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListStreamProcessorsRequestBuilder {
+    /// Sets the [`max_results`](struct.ListStreamProcessorsRequest.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListStreamProcessorsRequest.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListStreamProcessorsRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListStreamProcessorsRequest, ::rusoto_core::BuildError> {
+        Ok(ListStreamProcessorsRequest {
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListStreamProcessorsResponse {
@@ -1337,6 +3201,86 @@ pub struct NotificationChannel {
     pub sns_topic_arn: String,
 }
 
+/// <p>The value of <code>SourceImageOrientationCorrection</code> is always null.</p> <p>If the input image is in .jpeg format, it might contain exchangeable image file format (Exif) metadata that includes the image's orientation. Amazon Rekognition uses this orientation information to perform image correction. The bounding box coordinates are translated to represent object locations after the orientation information in the Exif metadata is used to correct the image orientation. Images in .png format don't contain Exif metadata.</p> <p>Amazon Rekognition doesn’t perform image correction for images in .png format and .jpeg images without orientation information in the image Exif metadata. The bounding box coordinates aren't translated and represent the object locations before the image is rotated. </p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrientationCorrection {
+    /// <p><code>ROTATE_0</code></p>
+    Rotate0,
+    /// <p><code>ROTATE_90</code></p>
+    Rotate90,
+    /// <p><code>ROTATE_180</code></p>
+    Rotate180,
+    /// <p><code>ROTATE_270</code></p>
+    Rotate270,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl OrientationCorrection {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            OrientationCorrection::Rotate0 => "ROTATE_0",
+            OrientationCorrection::Rotate90 => "ROTATE_90",
+            OrientationCorrection::Rotate180 => "ROTATE_180",
+            OrientationCorrection::Rotate270 => "ROTATE_270",
+            OrientationCorrection::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for OrientationCorrection {
+    fn from(value: &'a str) -> OrientationCorrection {
+        match value {
+            "ROTATE_0" => OrientationCorrection::Rotate0,
+            "ROTATE_90" => OrientationCorrection::Rotate90,
+            "ROTATE_180" => OrientationCorrection::Rotate180,
+            "ROTATE_270" => OrientationCorrection::Rotate270,
+            _ => OrientationCorrection::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for OrientationCorrection {
+    fn from(value: String) -> OrientationCorrection {
+        OrientationCorrection::from(value.as_str())
+    }
+}
+
+impl From<OrientationCorrection> for String {
+    fn from(value: OrientationCorrection) -> String {
+        match value {
+            OrientationCorrection::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for OrientationCorrection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for OrientationCorrection {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for OrientationCorrection {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<OrientationCorrection, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(OrientationCorrection::from(value))
+    }
+}
+
 /// <p>A parent label for a label. A label can have 0, 1, or more parents. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1397,6 +3341,78 @@ pub struct PersonMatch {
     pub timestamp: Option<i64>,
 }
 
+/// <p>Sort to use for elements in the <code>Persons</code> array. Use <code>TIMESTAMP</code> to sort array elements by the time persons are detected. Use <code>INDEX</code> to sort by the tracked persons. If you sort by <code>INDEX</code>, the array elements for each person are sorted by detection confidence. The default sort is by <code>TIMESTAMP</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PersonTrackingSortBy {
+    /// <p><code>INDEX</code></p>
+    Index,
+    /// <p><code>TIMESTAMP</code></p>
+    Timestamp,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl PersonTrackingSortBy {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            PersonTrackingSortBy::Index => "INDEX",
+            PersonTrackingSortBy::Timestamp => "TIMESTAMP",
+            PersonTrackingSortBy::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for PersonTrackingSortBy {
+    fn from(value: &'a str) -> PersonTrackingSortBy {
+        match value {
+            "INDEX" => PersonTrackingSortBy::Index,
+            "TIMESTAMP" => PersonTrackingSortBy::Timestamp,
+            _ => PersonTrackingSortBy::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for PersonTrackingSortBy {
+    fn from(value: String) -> PersonTrackingSortBy {
+        PersonTrackingSortBy::from(value.as_str())
+    }
+}
+
+impl From<PersonTrackingSortBy> for String {
+    fn from(value: PersonTrackingSortBy) -> String {
+        match value {
+            PersonTrackingSortBy::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for PersonTrackingSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for PersonTrackingSortBy {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for PersonTrackingSortBy {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<PersonTrackingSortBy, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(PersonTrackingSortBy::from(value))
+    }
+}
+
 /// <p>The X and Y coordinates of a point on an image. The X and Y values returned are ratios of the overall image size. For example, if the input image is 700x200 and the operation returns X=0.5 and Y=0.25, then the point is at the (350,50) pixel coordinate on the image.</p> <p>An array of <code>Point</code> objects, <code>Polygon</code>, is returned by <a>DetectText</a>. <code>Polygon</code> represents a fine-grained polygon around detected text. For more information, see Geometry in the Amazon Rekognition Developer Guide. </p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1429,6 +3445,182 @@ pub struct Pose {
     pub yaw: Option<f32>,
 }
 
+/// <p>A filter that specifies how much filtering is done to identify faces that are detected with low quality. Filtered faces aren't indexed. If you specify <code>AUTO</code>, filtering prioritizes the identification of faces that don’t meet the required quality bar chosen by Amazon Rekognition. The quality bar is based on a variety of common use cases. Low-quality detections can occur for a number of reasons. Some examples are an object that's misidentified as a face, a face that's too blurry, or a face with a pose that's too extreme to use. If you specify <code>NONE</code>, no filtering is performed. The default value is AUTO.</p> <p>To use quality filtering, the collection you are using must be associated with version 3 of the face model.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QualityFilter {
+    /// <p><code>NONE</code></p>
+    None,
+    /// <p><code>AUTO</code></p>
+    Auto,
+    /// <p><code>LOW</code></p>
+    Low,
+    /// <p><code>MEDIUM</code></p>
+    Medium,
+    /// <p><code>HIGH</code></p>
+    High,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl QualityFilter {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QualityFilter::None => "NONE",
+            QualityFilter::Auto => "AUTO",
+            QualityFilter::Low => "LOW",
+            QualityFilter::Medium => "MEDIUM",
+            QualityFilter::High => "HIGH",
+            QualityFilter::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for QualityFilter {
+    fn from(value: &'a str) -> QualityFilter {
+        match value {
+            "NONE" => QualityFilter::None,
+            "AUTO" => QualityFilter::Auto,
+            "LOW" => QualityFilter::Low,
+            "MEDIUM" => QualityFilter::Medium,
+            "HIGH" => QualityFilter::High,
+            _ => QualityFilter::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QualityFilter {
+    fn from(value: String) -> QualityFilter {
+        QualityFilter::from(value.as_str())
+    }
+}
+
+impl From<QualityFilter> for String {
+    fn from(value: QualityFilter) -> String {
+        match value {
+            QualityFilter::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for QualityFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for QualityFilter {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QualityFilter {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<QualityFilter, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(QualityFilter::from(value))
+    }
+}
+
+/// <p><p>An array of reasons that specify why a face wasn&#39;t indexed. </p> <ul> <li> <p>EXTREME<em>POSE - The face is at a pose that can&#39;t be detected. For example, the head is turned too far away from the camera.</p> </li> <li> <p>EXCEEDS</em>MAX<em>FACES - The number of faces detected is already higher than that specified by the <code>MaxFaces</code> input parameter for <code>IndexFaces</code>.</p> </li> <li> <p>LOW</em>BRIGHTNESS - The image is too dark.</p> </li> <li> <p>LOW<em>SHARPNESS - The image is too blurry.</p> </li> <li> <p>LOW</em>CONFIDENCE - The face was detected with a low confidence.</p> </li> <li> <p>SMALL<em>BOUNDING</em>BOX - The bounding box around the face is too small.</p> </li> </ul></p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Reason {
+    /// <p><code>EXCEEDS_MAX_FACES</code></p>
+    ExceedsMaxFaces,
+    /// <p><code>EXTREME_POSE</code></p>
+    ExtremePose,
+    /// <p><code>LOW_BRIGHTNESS</code></p>
+    LowBrightness,
+    /// <p><code>LOW_SHARPNESS</code></p>
+    LowSharpness,
+    /// <p><code>LOW_CONFIDENCE</code></p>
+    LowConfidence,
+    /// <p><code>SMALL_BOUNDING_BOX</code></p>
+    SmallBoundingBox,
+    /// <p><code>LOW_FACE_QUALITY</code></p>
+    LowFaceQuality,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl Reason {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            Reason::ExceedsMaxFaces => "EXCEEDS_MAX_FACES",
+            Reason::ExtremePose => "EXTREME_POSE",
+            Reason::LowBrightness => "LOW_BRIGHTNESS",
+            Reason::LowSharpness => "LOW_SHARPNESS",
+            Reason::LowConfidence => "LOW_CONFIDENCE",
+            Reason::SmallBoundingBox => "SMALL_BOUNDING_BOX",
+            Reason::LowFaceQuality => "LOW_FACE_QUALITY",
+            Reason::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Reason {
+    fn from(value: &'a str) -> Reason {
+        match value {
+            "EXCEEDS_MAX_FACES" => Reason::ExceedsMaxFaces,
+            "EXTREME_POSE" => Reason::ExtremePose,
+            "LOW_BRIGHTNESS" => Reason::LowBrightness,
+            "LOW_SHARPNESS" => Reason::LowSharpness,
+            "LOW_CONFIDENCE" => Reason::LowConfidence,
+            "SMALL_BOUNDING_BOX" => Reason::SmallBoundingBox,
+            "LOW_FACE_QUALITY" => Reason::LowFaceQuality,
+            _ => Reason::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for Reason {
+    fn from(value: String) -> Reason {
+        Reason::from(value.as_str())
+    }
+}
+
+impl From<Reason> for String {
+    fn from(value: Reason) -> String {
+        match value {
+            Reason::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for Reason {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Reason {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<Reason, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Reason::from(value))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct RecognizeCelebritiesRequest {
     /// <p>The input image as base64-encoded bytes or an S3 object. If you use the AWS CLI to call Amazon Rekognition operations, passing base64-encoded image bytes is not supported. </p> <p>If you are using an AWS SDK to call Amazon Rekognition, you might not need to base64-encode image bytes passed using the <code>Bytes</code> field. For more information, see Images in the Amazon Rekognition developer guide.</p>
@@ -1436,6 +3628,38 @@ pub struct RecognizeCelebritiesRequest {
     pub image: Image,
 }
 
+impl RecognizeCelebritiesRequest {
+    /// Returns a builder for `RecognizeCelebritiesRequest`, checking its required members when built.
+    pub fn builder() -> RecognizeCelebritiesRequestBuilder {
+        RecognizeCelebritiesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`RecognizeCelebritiesRequest`](struct.RecognizeCelebritiesRequest.html).
+#[derive(Default, Debug)]
+pub struct RecognizeCelebritiesRequestBuilder {
+    image: Option<Image>,
+}
+
+impl RecognizeCelebritiesRequestBuilder {
+    /// Sets the [`image`](struct.RecognizeCelebritiesRequest.html#structfield.image) member.
+    pub fn image<T: Into<Image>>(mut self, value: T) -> Self {
+        self.image = Some(value.into());
+        self
+    }
+
+    /// Builds the `RecognizeCelebritiesRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<RecognizeCelebritiesRequest, ::rusoto_core::BuildError> {
+        Ok(RecognizeCelebritiesRequest {
+            image: self
+                .image
+                .ok_or(::rusoto_core::BuildError::missing("image"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct RecognizeCelebritiesResponse {
@@ -1446,7 +3670,7 @@ pub struct RecognizeCelebritiesResponse {
     /// <p><p>The orientation of the input image (counterclockwise direction). If your application displays the image, you can use this value to correct the orientation. The bounding box coordinates returned in <code>CelebrityFaces</code> and <code>UnrecognizedFaces</code> represent face locations before the image orientation is corrected. </p> <note> <p>If the input image is in .jpeg format, it might contain exchangeable image (Exif) metadata that includes the image&#39;s orientation. If so, and the Exif metadata for the input image populates the orientation field, the value of <code>OrientationCorrection</code> is null. The <code>CelebrityFaces</code> and <code>UnrecognizedFaces</code> bounding box coordinates represent face locations after Exif metadata is used to correct the image orientation. Images in .png format don&#39;t contain Exif metadata. </p> </note></p>
     #[serde(rename = "OrientationCorrection")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub orientation_correction: Option<String>,
+    pub orientation_correction: Option<OrientationCorrection>,
     /// <p>Details about each unrecognized face in the image.</p>
     #[serde(rename = "UnrecognizedFaces")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1488,6 +3712,64 @@ pub struct SearchFacesByImageRequest {
     pub max_faces: Option<i64>,
 }
 
+impl SearchFacesByImageRequest {
+    /// Returns a builder for `SearchFacesByImageRequest`, checking its required members when built.
+    pub fn builder() -> SearchFacesByImageRequestBuilder {
+        SearchFacesByImageRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchFacesByImageRequest`](struct.SearchFacesByImageRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchFacesByImageRequestBuilder {
+    collection_id: Option<String>,
+    face_match_threshold: Option<f32>,
+    image: Option<Image>,
+    max_faces: Option<i64>,
+}
+
+impl SearchFacesByImageRequestBuilder {
+    /// Sets the [`collection_id`](struct.SearchFacesByImageRequest.html#structfield.collection_id) member.
+    pub fn collection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.collection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`face_match_threshold`](struct.SearchFacesByImageRequest.html#structfield.face_match_threshold) member.
+    pub fn face_match_threshold<T: Into<f32>>(mut self, value: T) -> Self {
+        self.face_match_threshold = Some(value.into());
+        self
+    }
+
+    /// Sets the [`image`](struct.SearchFacesByImageRequest.html#structfield.image) member.
+    pub fn image<T: Into<Image>>(mut self, value: T) -> Self {
+        self.image = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_faces`](struct.SearchFacesByImageRequest.html#structfield.max_faces) member.
+    pub fn max_faces<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_faces = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchFacesByImageRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<SearchFacesByImageRequest, ::rusoto_core::BuildError> {
+        Ok(SearchFacesByImageRequest {
+            collection_id: self
+                .collection_id
+                .ok_or(::rusoto_core::BuildError::missing("collection_id"))?,
+            face_match_threshold: self.face_match_threshold,
+            image: self
+                .image
+                .ok_or(::rusoto_core::BuildError::missing("image"))?,
+            max_faces: self.max_faces,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SearchFacesByImageResponse {
@@ -1527,6 +3809,62 @@ pub struct SearchFacesRequest {
     pub max_faces: Option<i64>,
 }
 
+impl SearchFacesRequest {
+    /// Returns a builder for `SearchFacesRequest`, checking its required members when built.
+    pub fn builder() -> SearchFacesRequestBuilder {
+        SearchFacesRequestBuilder::default()
+    }
+}
+
+/// Builds a [`SearchFacesRequest`](struct.SearchFacesRequest.html).
+#[derive(Default, Debug)]
+pub struct SearchFacesRequestBuilder {
+    collection_id: Option<String>,
+    face_id: Option<String>,
+    face_match_threshold: Option<f32>,
+    max_faces: Option<i64>,
+}
+
+impl SearchFacesRequestBuilder {
+    /// Sets the [`collection_id`](struct.SearchFacesRequest.html#structfield.collection_id) member.
+    pub fn collection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.collection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`face_id`](struct.SearchFacesRequest.html#structfield.face_id) member.
+    pub fn face_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.face_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`face_match_threshold`](struct.SearchFacesRequest.html#structfield.face_match_threshold) member.
+    pub fn face_match_threshold<T: Into<f32>>(mut self, value: T) -> Self {
+        self.face_match_threshold = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_faces`](struct.SearchFacesRequest.html#structfield.max_faces) member.
+    pub fn max_faces<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_faces = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchFacesRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SearchFacesRequest, ::rusoto_core::BuildError> {
+        Ok(SearchFacesRequest {
+            collection_id: self
+                .collection_id
+                .ok_or(::rusoto_core::BuildError::missing("collection_id"))?,
+            face_id: self
+                .face_id
+                .ok_or(::rusoto_core::BuildError::missing("face_id"))?,
+            face_match_threshold: self.face_match_threshold,
+            max_faces: self.max_faces,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SearchFacesResponse {
@@ -1577,6 +3915,62 @@ pub struct StartCelebrityRecognitionRequest {
     pub video: Video,
 }
 
+impl StartCelebrityRecognitionRequest {
+    /// Returns a builder for `StartCelebrityRecognitionRequest`, checking its required members when built.
+    pub fn builder() -> StartCelebrityRecognitionRequestBuilder {
+        StartCelebrityRecognitionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartCelebrityRecognitionRequest`](struct.StartCelebrityRecognitionRequest.html).
+#[derive(Default, Debug)]
+pub struct StartCelebrityRecognitionRequestBuilder {
+    client_request_token: Option<String>,
+    job_tag: Option<String>,
+    notification_channel: Option<NotificationChannel>,
+    video: Option<Video>,
+}
+
+impl StartCelebrityRecognitionRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartCelebrityRecognitionRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_tag`](struct.StartCelebrityRecognitionRequest.html#structfield.job_tag) member.
+    pub fn job_tag<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_tag = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_channel`](struct.StartCelebrityRecognitionRequest.html#structfield.notification_channel) member.
+    pub fn notification_channel<T: Into<NotificationChannel>>(mut self, value: T) -> Self {
+        self.notification_channel = Some(value.into());
+        self
+    }
+
+    /// Sets the [`video`](struct.StartCelebrityRecognitionRequest.html#structfield.video) member.
+    pub fn video<T: Into<Video>>(mut self, value: T) -> Self {
+        self.video = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartCelebrityRecognitionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartCelebrityRecognitionRequest, ::rusoto_core::BuildError> {
+        Ok(StartCelebrityRecognitionRequest {
+            client_request_token: self.client_request_token,
+            job_tag: self.job_tag,
+            notification_channel: self.notification_channel,
+            video: self
+                .video
+                .ok_or(::rusoto_core::BuildError::missing("video"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartCelebrityRecognitionResponse {
@@ -1609,6 +4003,70 @@ pub struct StartContentModerationRequest {
     pub video: Video,
 }
 
+impl StartContentModerationRequest {
+    /// Returns a builder for `StartContentModerationRequest`, checking its required members when built.
+    pub fn builder() -> StartContentModerationRequestBuilder {
+        StartContentModerationRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartContentModerationRequest`](struct.StartContentModerationRequest.html).
+#[derive(Default, Debug)]
+pub struct StartContentModerationRequestBuilder {
+    client_request_token: Option<String>,
+    job_tag: Option<String>,
+    min_confidence: Option<f32>,
+    notification_channel: Option<NotificationChannel>,
+    video: Option<Video>,
+}
+
+impl StartContentModerationRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartContentModerationRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_tag`](struct.StartContentModerationRequest.html#structfield.job_tag) member.
+    pub fn job_tag<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_tag = Some(value.into());
+        self
+    }
+
+    /// Sets the [`min_confidence`](struct.StartContentModerationRequest.html#structfield.min_confidence) member.
+    pub fn min_confidence<T: Into<f32>>(mut self, value: T) -> Self {
+        self.min_confidence = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_channel`](struct.StartContentModerationRequest.html#structfield.notification_channel) member.
+    pub fn notification_channel<T: Into<NotificationChannel>>(mut self, value: T) -> Self {
+        self.notification_channel = Some(value.into());
+        self
+    }
+
+    /// Sets the [`video`](struct.StartContentModerationRequest.html#structfield.video) member.
+    pub fn video<T: Into<Video>>(mut self, value: T) -> Self {
+        self.video = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartContentModerationRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartContentModerationRequest, ::rusoto_core::BuildError> {
+        Ok(StartContentModerationRequest {
+            client_request_token: self.client_request_token,
+            job_tag: self.job_tag,
+            min_confidence: self.min_confidence,
+            notification_channel: self.notification_channel,
+            video: self
+                .video
+                .ok_or(::rusoto_core::BuildError::missing("video"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartContentModerationResponse {
@@ -1627,7 +4085,7 @@ pub struct StartFaceDetectionRequest {
     /// <p>The face attributes you want returned.</p> <p> <code>DEFAULT</code> - The following subset of facial attributes are returned: BoundingBox, Confidence, Pose, Quality and Landmarks. </p> <p> <code>ALL</code> - All facial attributes are returned.</p>
     #[serde(rename = "FaceAttributes")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub face_attributes: Option<String>,
+    pub face_attributes: Option<FaceAttributes>,
     /// <p>An identifier you specify that's returned in the completion notification that's published to your Amazon Simple Notification Service topic. For example, you can use <code>JobTag</code> to group related jobs and identify them in the completion notification.</p>
     #[serde(rename = "JobTag")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1641,6 +4099,70 @@ pub struct StartFaceDetectionRequest {
     pub video: Video,
 }
 
+impl StartFaceDetectionRequest {
+    /// Returns a builder for `StartFaceDetectionRequest`, checking its required members when built.
+    pub fn builder() -> StartFaceDetectionRequestBuilder {
+        StartFaceDetectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartFaceDetectionRequest`](struct.StartFaceDetectionRequest.html).
+#[derive(Default, Debug)]
+pub struct StartFaceDetectionRequestBuilder {
+    client_request_token: Option<String>,
+    face_attributes: Option<FaceAttributes>,
+    job_tag: Option<String>,
+    notification_channel: Option<NotificationChannel>,
+    video: Option<Video>,
+}
+
+impl StartFaceDetectionRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartFaceDetectionRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`face_attributes`](struct.StartFaceDetectionRequest.html#structfield.face_attributes) member.
+    pub fn face_attributes<T: Into<FaceAttributes>>(mut self, value: T) -> Self {
+        self.face_attributes = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_tag`](struct.StartFaceDetectionRequest.html#structfield.job_tag) member.
+    pub fn job_tag<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_tag = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_channel`](struct.StartFaceDetectionRequest.html#structfield.notification_channel) member.
+    pub fn notification_channel<T: Into<NotificationChannel>>(mut self, value: T) -> Self {
+        self.notification_channel = Some(value.into());
+        self
+    }
+
+    /// Sets the [`video`](struct.StartFaceDetectionRequest.html#structfield.video) member.
+    pub fn video<T: Into<Video>>(mut self, value: T) -> Self {
+        self.video = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartFaceDetectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartFaceDetectionRequest, ::rusoto_core::BuildError> {
+        Ok(StartFaceDetectionRequest {
+            client_request_token: self.client_request_token,
+            face_attributes: self.face_attributes,
+            job_tag: self.job_tag,
+            notification_channel: self.notification_channel,
+            video: self
+                .video
+                .ok_or(::rusoto_core::BuildError::missing("video"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartFaceDetectionResponse {
@@ -1676,6 +4198,78 @@ pub struct StartFaceSearchRequest {
     pub video: Video,
 }
 
+impl StartFaceSearchRequest {
+    /// Returns a builder for `StartFaceSearchRequest`, checking its required members when built.
+    pub fn builder() -> StartFaceSearchRequestBuilder {
+        StartFaceSearchRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartFaceSearchRequest`](struct.StartFaceSearchRequest.html).
+#[derive(Default, Debug)]
+pub struct StartFaceSearchRequestBuilder {
+    client_request_token: Option<String>,
+    collection_id: Option<String>,
+    face_match_threshold: Option<f32>,
+    job_tag: Option<String>,
+    notification_channel: Option<NotificationChannel>,
+    video: Option<Video>,
+}
+
+impl StartFaceSearchRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartFaceSearchRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`collection_id`](struct.StartFaceSearchRequest.html#structfield.collection_id) member.
+    pub fn collection_id<T: Into<String>>(mut self, value: T) -> Self {
+        self.collection_id = Some(value.into());
+        self
+    }
+
+    /// Sets the [`face_match_threshold`](struct.StartFaceSearchRequest.html#structfield.face_match_threshold) member.
+    pub fn face_match_threshold<T: Into<f32>>(mut self, value: T) -> Self {
+        self.face_match_threshold = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_tag`](struct.StartFaceSearchRequest.html#structfield.job_tag) member.
+    pub fn job_tag<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_tag = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_channel`](struct.StartFaceSearchRequest.html#structfield.notification_channel) member.
+    pub fn notification_channel<T: Into<NotificationChannel>>(mut self, value: T) -> Self {
+        self.notification_channel = Some(value.into());
+        self
+    }
+
+    /// Sets the [`video`](struct.StartFaceSearchRequest.html#structfield.video) member.
+    pub fn video<T: Into<Video>>(mut self, value: T) -> Self {
+        self.video = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartFaceSearchRequest`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<StartFaceSearchRequest, ::rusoto_core::BuildError> {
+        Ok(StartFaceSearchRequest {
+            client_request_token: self.client_request_token,
+            collection_id: self
+                .collection_id
+                .ok_or(::rusoto_core::BuildError::missing("collection_id"))?,
+            face_match_threshold: self.face_match_threshold,
+            job_tag: self.job_tag,
+            notification_channel: self.notification_channel,
+            video: self
+                .video
+                .ok_or(::rusoto_core::BuildError::missing("video"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartFaceSearchResponse {
@@ -1708,6 +4302,70 @@ pub struct StartLabelDetectionRequest {
     pub video: Video,
 }
 
+impl StartLabelDetectionRequest {
+    /// Returns a builder for `StartLabelDetectionRequest`, checking its required members when built.
+    pub fn builder() -> StartLabelDetectionRequestBuilder {
+        StartLabelDetectionRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartLabelDetectionRequest`](struct.StartLabelDetectionRequest.html).
+#[derive(Default, Debug)]
+pub struct StartLabelDetectionRequestBuilder {
+    client_request_token: Option<String>,
+    job_tag: Option<String>,
+    min_confidence: Option<f32>,
+    notification_channel: Option<NotificationChannel>,
+    video: Option<Video>,
+}
+
+impl StartLabelDetectionRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartLabelDetectionRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_tag`](struct.StartLabelDetectionRequest.html#structfield.job_tag) member.
+    pub fn job_tag<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_tag = Some(value.into());
+        self
+    }
+
+    /// Sets the [`min_confidence`](struct.StartLabelDetectionRequest.html#structfield.min_confidence) member.
+    pub fn min_confidence<T: Into<f32>>(mut self, value: T) -> Self {
+        self.min_confidence = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_channel`](struct.StartLabelDetectionRequest.html#structfield.notification_channel) member.
+    pub fn notification_channel<T: Into<NotificationChannel>>(mut self, value: T) -> Self {
+        self.notification_channel = Some(value.into());
+        self
+    }
+
+    /// Sets the [`video`](struct.StartLabelDetectionRequest.html#structfield.video) member.
+    pub fn video<T: Into<Video>>(mut self, value: T) -> Self {
+        self.video = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartLabelDetectionRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartLabelDetectionRequest, ::rusoto_core::BuildError> {
+        Ok(StartLabelDetectionRequest {
+            client_request_token: self.client_request_token,
+            job_tag: self.job_tag,
+            min_confidence: self.min_confidence,
+            notification_channel: self.notification_channel,
+            video: self
+                .video
+                .ok_or(::rusoto_core::BuildError::missing("video"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartLabelDetectionResponse {
@@ -1736,6 +4394,62 @@ pub struct StartPersonTrackingRequest {
     pub video: Video,
 }
 
+impl StartPersonTrackingRequest {
+    /// Returns a builder for `StartPersonTrackingRequest`, checking its required members when built.
+    pub fn builder() -> StartPersonTrackingRequestBuilder {
+        StartPersonTrackingRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartPersonTrackingRequest`](struct.StartPersonTrackingRequest.html).
+#[derive(Default, Debug)]
+pub struct StartPersonTrackingRequestBuilder {
+    client_request_token: Option<String>,
+    job_tag: Option<String>,
+    notification_channel: Option<NotificationChannel>,
+    video: Option<Video>,
+}
+
+impl StartPersonTrackingRequestBuilder {
+    /// Sets the [`client_request_token`](struct.StartPersonTrackingRequest.html#structfield.client_request_token) member.
+    pub fn client_request_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.client_request_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`job_tag`](struct.StartPersonTrackingRequest.html#structfield.job_tag) member.
+    pub fn job_tag<T: Into<String>>(mut self, value: T) -> Self {
+        self.job_tag = Some(value.into());
+        self
+    }
+
+    /// Sets the [`notification_channel`](struct.StartPersonTrackingRequest.html#structfield.notification_channel) member.
+    pub fn notification_channel<T: Into<NotificationChannel>>(mut self, value: T) -> Self {
+        self.notification_channel = Some(value.into());
+        self
+    }
+
+    /// Sets the [`video`](struct.StartPersonTrackingRequest.html#structfield.video) member.
+    pub fn video<T: Into<Video>>(mut self, value: T) -> Self {
+        self.video = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartPersonTrackingRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartPersonTrackingRequest, ::rusoto_core::BuildError> {
+        Ok(StartPersonTrackingRequest {
+            client_request_token: self.client_request_token,
+            job_tag: self.job_tag,
+            notification_channel: self.notification_channel,
+            video: self
+                .video
+                .ok_or(::rusoto_core::BuildError::missing("video"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartPersonTrackingResponse {
@@ -1752,6 +4466,38 @@ pub struct StartStreamProcessorRequest {
     pub name: String,
 }
 
+impl StartStreamProcessorRequest {
+    /// Returns a builder for `StartStreamProcessorRequest`, checking its required members when built.
+    pub fn builder() -> StartStreamProcessorRequestBuilder {
+        StartStreamProcessorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StartStreamProcessorRequest`](struct.StartStreamProcessorRequest.html).
+#[derive(Default, Debug)]
+pub struct StartStreamProcessorRequestBuilder {
+    name: Option<String>,
+}
+
+impl StartStreamProcessorRequestBuilder {
+    /// Sets the [`name`](struct.StartStreamProcessorRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StartStreamProcessorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StartStreamProcessorRequest, ::rusoto_core::BuildError> {
+        Ok(StartStreamProcessorRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StartStreamProcessorResponse {}
@@ -1763,6 +4509,38 @@ pub struct StopStreamProcessorRequest {
     pub name: String,
 }
 
+impl StopStreamProcessorRequest {
+    /// Returns a builder for `StopStreamProcessorRequest`, checking its required members when built.
+    pub fn builder() -> StopStreamProcessorRequestBuilder {
+        StopStreamProcessorRequestBuilder::default()
+    }
+}
+
+/// Builds a [`StopStreamProcessorRequest`](struct.StopStreamProcessorRequest.html).
+#[derive(Default, Debug)]
+pub struct StopStreamProcessorRequestBuilder {
+    name: Option<String>,
+}
+
+impl StopStreamProcessorRequestBuilder {
+    /// Sets the [`name`](struct.StopStreamProcessorRequest.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Builds the `StopStreamProcessorRequest`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<StopStreamProcessorRequest, ::rusoto_core::BuildError> {
+        Ok(StopStreamProcessorRequest {
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct StopStreamProcessorResponse {}
@@ -1778,7 +4556,7 @@ pub struct StreamProcessor {
     /// <p>Current status of the Amazon Rekognition stream processor.</p>
     #[serde(rename = "Status")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<String>,
+    pub status: Option<StreamProcessorStatus>,
 }
 
 /// <p>Information about the source streaming video. </p>
@@ -1808,6 +4586,94 @@ pub struct StreamProcessorSettings {
     pub face_search: Option<FaceSearchSettings>,
 }
 
+/// <p>Current status of the stream processor.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StreamProcessorStatus {
+    /// <p><code>STOPPED</code></p>
+    Stopped,
+    /// <p><code>STARTING</code></p>
+    Starting,
+    /// <p><code>RUNNING</code></p>
+    Running,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// <p><code>STOPPING</code></p>
+    Stopping,
+    /// <p><code>UPDATING</code></p>
+    Updating,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl StreamProcessorStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            StreamProcessorStatus::Stopped => "STOPPED",
+            StreamProcessorStatus::Starting => "STARTING",
+            StreamProcessorStatus::Running => "RUNNING",
+            StreamProcessorStatus::Failed => "FAILED",
+            StreamProcessorStatus::Stopping => "STOPPING",
+            StreamProcessorStatus::Updating => "UPDATING",
+            StreamProcessorStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StreamProcessorStatus {
+    fn from(value: &'a str) -> StreamProcessorStatus {
+        match value {
+            "STOPPED" => StreamProcessorStatus::Stopped,
+            "STARTING" => StreamProcessorStatus::Starting,
+            "RUNNING" => StreamProcessorStatus::Running,
+            "FAILED" => StreamProcessorStatus::Failed,
+            "STOPPING" => StreamProcessorStatus::Stopping,
+            "UPDATING" => StreamProcessorStatus::Updating,
+            _ => StreamProcessorStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for StreamProcessorStatus {
+    fn from(value: String) -> StreamProcessorStatus {
+        StreamProcessorStatus::from(value.as_str())
+    }
+}
+
+impl From<StreamProcessorStatus> for String {
+    fn from(value: StreamProcessorStatus) -> String {
+        match value {
+            StreamProcessorStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for StreamProcessorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for StreamProcessorStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for StreamProcessorStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<StreamProcessorStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(StreamProcessorStatus::from(value))
+    }
+}
+
 /// <p>Indicates whether or not the face is wearing sunglasses, and the confidence level in the determination.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -1849,7 +4715,79 @@ pub struct TextDetection {
     /// <p>The type of text that was detected.</p>
     #[serde(rename = "Type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    pub type_: Option<TextTypes>,
+}
+
+/// <p>The type of text that was detected.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TextTypes {
+    /// <p><code>LINE</code></p>
+    Line,
+    /// <p><code>WORD</code></p>
+    Word,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl TextTypes {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            TextTypes::Line => "LINE",
+            TextTypes::Word => "WORD",
+            TextTypes::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for TextTypes {
+    fn from(value: &'a str) -> TextTypes {
+        match value {
+            "LINE" => TextTypes::Line,
+            "WORD" => TextTypes::Word,
+            _ => TextTypes::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for TextTypes {
+    fn from(value: String) -> TextTypes {
+        TextTypes::from(value.as_str())
+    }
+}
+
+impl From<TextTypes> for String {
+    fn from(value: TextTypes) -> String {
+        match value {
+            TextTypes::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for TextTypes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for TextTypes {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for TextTypes {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<TextTypes, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(TextTypes::from(value))
+    }
 }
 
 /// <p>A face that <a>IndexFaces</a> detected, but didn't index. Use the <code>Reasons</code> response attribute to determine why a face wasn't indexed.</p>
@@ -1863,7 +4801,7 @@ pub struct UnindexedFace {
     /// <p><p>An array of reasons that specify why a face wasn&#39;t indexed. </p> <ul> <li> <p>EXTREME<em>POSE - The face is at a pose that can&#39;t be detected. For example, the head is turned too far away from the camera.</p> </li> <li> <p>EXCEEDS</em>MAX<em>FACES - The number of faces detected is already higher than that specified by the <code>MaxFaces</code> input parameter for <code>IndexFaces</code>.</p> </li> <li> <p>LOW</em>BRIGHTNESS - The image is too dark.</p> </li> <li> <p>LOW<em>SHARPNESS - The image is too blurry.</p> </li> <li> <p>LOW</em>CONFIDENCE - The face was detected with a low confidence.</p> </li> <li> <p>SMALL<em>BOUNDING</em>BOX - The bounding box around the face is too small.</p> </li> </ul></p>
     #[serde(rename = "Reasons")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reasons: Option<Vec<String>>,
+    pub reasons: Option<Vec<Reason>>,
 }
 
 /// <p>Video file stored in an Amazon S3 bucket. Amazon Rekognition video start operations such as <a>StartLabelDetection</a> use <code>Video</code> to specify a video for analysis. The supported file formats are .mp4, .mov and .avi.</p>
@@ -1875,6 +4813,82 @@ pub struct Video {
     pub s3_object: Option<S3Object>,
 }
 
+/// <p>The current status of the celebrity recognition job.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VideoJobStatus {
+    /// <p><code>IN_PROGRESS</code></p>
+    InProgress,
+    /// <p><code>SUCCEEDED</code></p>
+    Succeeded,
+    /// <p><code>FAILED</code></p>
+    Failed,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl VideoJobStatus {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            VideoJobStatus::InProgress => "IN_PROGRESS",
+            VideoJobStatus::Succeeded => "SUCCEEDED",
+            VideoJobStatus::Failed => "FAILED",
+            VideoJobStatus::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for VideoJobStatus {
+    fn from(value: &'a str) -> VideoJobStatus {
+        match value {
+            "IN_PROGRESS" => VideoJobStatus::InProgress,
+            "SUCCEEDED" => VideoJobStatus::Succeeded,
+            "FAILED" => VideoJobStatus::Failed,
+            _ => VideoJobStatus::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for VideoJobStatus {
+    fn from(value: String) -> VideoJobStatus {
+        VideoJobStatus::from(value.as_str())
+    }
+}
+
+impl From<VideoJobStatus> for String {
+    fn from(value: VideoJobStatus) -> String {
+        match value {
+            VideoJobStatus::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for VideoJobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for VideoJobStatus {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for VideoJobStatus {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<VideoJobStatus, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(VideoJobStatus::from(value))
+    }
+}
+
 /// <p>Information about a video that Amazon Rekognition analyzed. <code>Videometadata</code> is returned in every page of paginated responses from a Amazon Rekognition video operation.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -4763,6 +7777,219 @@ pub trait Rekognition {
         input: StopStreamProcessorRequest,
     ) -> RusotoFuture<StopStreamProcessorResponse, StopStreamProcessorError>;
 }
+impl<T: ?Sized + Rekognition> Rekognition for ::std::sync::Arc<T> {
+    fn compare_faces(
+        &self,
+        input: CompareFacesRequest,
+    ) -> RusotoFuture<CompareFacesResponse, CompareFacesError> {
+        (**self).compare_faces(input)
+    }
+    fn create_collection(
+        &self,
+        input: CreateCollectionRequest,
+    ) -> RusotoFuture<CreateCollectionResponse, CreateCollectionError> {
+        (**self).create_collection(input)
+    }
+    fn create_stream_processor(
+        &self,
+        input: CreateStreamProcessorRequest,
+    ) -> RusotoFuture<CreateStreamProcessorResponse, CreateStreamProcessorError> {
+        (**self).create_stream_processor(input)
+    }
+    fn delete_collection(
+        &self,
+        input: DeleteCollectionRequest,
+    ) -> RusotoFuture<DeleteCollectionResponse, DeleteCollectionError> {
+        (**self).delete_collection(input)
+    }
+    fn delete_faces(
+        &self,
+        input: DeleteFacesRequest,
+    ) -> RusotoFuture<DeleteFacesResponse, DeleteFacesError> {
+        (**self).delete_faces(input)
+    }
+    fn delete_stream_processor(
+        &self,
+        input: DeleteStreamProcessorRequest,
+    ) -> RusotoFuture<DeleteStreamProcessorResponse, DeleteStreamProcessorError> {
+        (**self).delete_stream_processor(input)
+    }
+    fn describe_collection(
+        &self,
+        input: DescribeCollectionRequest,
+    ) -> RusotoFuture<DescribeCollectionResponse, DescribeCollectionError> {
+        (**self).describe_collection(input)
+    }
+    fn describe_stream_processor(
+        &self,
+        input: DescribeStreamProcessorRequest,
+    ) -> RusotoFuture<DescribeStreamProcessorResponse, DescribeStreamProcessorError> {
+        (**self).describe_stream_processor(input)
+    }
+    fn detect_faces(
+        &self,
+        input: DetectFacesRequest,
+    ) -> RusotoFuture<DetectFacesResponse, DetectFacesError> {
+        (**self).detect_faces(input)
+    }
+    fn detect_labels(
+        &self,
+        input: DetectLabelsRequest,
+    ) -> RusotoFuture<DetectLabelsResponse, DetectLabelsError> {
+        (**self).detect_labels(input)
+    }
+    fn detect_moderation_labels(
+        &self,
+        input: DetectModerationLabelsRequest,
+    ) -> RusotoFuture<DetectModerationLabelsResponse, DetectModerationLabelsError> {
+        (**self).detect_moderation_labels(input)
+    }
+    fn detect_text(
+        &self,
+        input: DetectTextRequest,
+    ) -> RusotoFuture<DetectTextResponse, DetectTextError> {
+        (**self).detect_text(input)
+    }
+    fn get_celebrity_info(
+        &self,
+        input: GetCelebrityInfoRequest,
+    ) -> RusotoFuture<GetCelebrityInfoResponse, GetCelebrityInfoError> {
+        (**self).get_celebrity_info(input)
+    }
+    fn get_celebrity_recognition(
+        &self,
+        input: GetCelebrityRecognitionRequest,
+    ) -> RusotoFuture<GetCelebrityRecognitionResponse, GetCelebrityRecognitionError> {
+        (**self).get_celebrity_recognition(input)
+    }
+    fn get_content_moderation(
+        &self,
+        input: GetContentModerationRequest,
+    ) -> RusotoFuture<GetContentModerationResponse, GetContentModerationError> {
+        (**self).get_content_moderation(input)
+    }
+    fn get_face_detection(
+        &self,
+        input: GetFaceDetectionRequest,
+    ) -> RusotoFuture<GetFaceDetectionResponse, GetFaceDetectionError> {
+        (**self).get_face_detection(input)
+    }
+    fn get_face_search(
+        &self,
+        input: GetFaceSearchRequest,
+    ) -> RusotoFuture<GetFaceSearchResponse, GetFaceSearchError> {
+        (**self).get_face_search(input)
+    }
+    fn get_label_detection(
+        &self,
+        input: GetLabelDetectionRequest,
+    ) -> RusotoFuture<GetLabelDetectionResponse, GetLabelDetectionError> {
+        (**self).get_label_detection(input)
+    }
+    fn get_person_tracking(
+        &self,
+        input: GetPersonTrackingRequest,
+    ) -> RusotoFuture<GetPersonTrackingResponse, GetPersonTrackingError> {
+        (**self).get_person_tracking(input)
+    }
+    fn index_faces(
+        &self,
+        input: IndexFacesRequest,
+    ) -> RusotoFuture<IndexFacesResponse, IndexFacesError> {
+        (**self).index_faces(input)
+    }
+    fn list_collections(
+        &self,
+        input: ListCollectionsRequest,
+    ) -> RusotoFuture<ListCollectionsResponse, ListCollectionsError> {
+        (**self).list_collections(input)
+    }
+    fn list_faces(
+        &self,
+        input: ListFacesRequest,
+    ) -> RusotoFuture<ListFacesResponse, ListFacesError> {
+        (**self).list_faces(input)
+    }
+    fn list_stream_processors(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> RusotoFuture<ListStreamProcessorsResponse, ListStreamProcessorsError> {
+        (**self).list_stream_processors(input)
+    }
+    fn recognize_celebrities(
+        &self,
+        input: RecognizeCelebritiesRequest,
+    ) -> RusotoFuture<RecognizeCelebritiesResponse, RecognizeCelebritiesError> {
+        (**self).recognize_celebrities(input)
+    }
+    fn search_faces(
+        &self,
+        input: SearchFacesRequest,
+    ) -> RusotoFuture<SearchFacesResponse, SearchFacesError> {
+        (**self).search_faces(input)
+    }
+    fn search_faces_by_image(
+        &self,
+        input: SearchFacesByImageRequest,
+    ) -> RusotoFuture<SearchFacesByImageResponse, SearchFacesByImageError> {
+        (**self).search_faces_by_image(input)
+    }
+    fn start_celebrity_recognition(
+        &self,
+        input: StartCelebrityRecognitionRequest,
+    ) -> RusotoFuture<StartCelebrityRecognitionResponse, StartCelebrityRecognitionError> {
+        (**self).start_celebrity_recognition(input)
+    }
+    fn start_content_moderation(
+        &self,
+        input: StartContentModerationRequest,
+    ) -> RusotoFuture<StartContentModerationResponse, StartContentModerationError> {
+        (**self).start_content_moderation(input)
+    }
+    fn start_face_detection(
+        &self,
+        input: StartFaceDetectionRequest,
+    ) -> RusotoFuture<StartFaceDetectionResponse, StartFaceDetectionError> {
+        (**self).start_face_detection(input)
+    }
+    fn start_face_search(
+        &self,
+        input: StartFaceSearchRequest,
+    ) -> RusotoFuture<StartFaceSearchResponse, StartFaceSearchError> {
+        (**self).start_face_search(input)
+    }
+    fn start_label_detection(
+        &self,
+        input: StartLabelDetectionRequest,
+    ) -> RusotoFuture<StartLabelDetectionResponse, StartLabelDetectionError> {
+        (**self).start_label_detection(input)
+    }
+    fn start_person_tracking(
+        &self,
+        input: StartPersonTrackingRequest,
+    ) -> RusotoFuture<StartPersonTrackingResponse, StartPersonTrackingError> {
+        (**self).start_person_tracking(input)
+    }
+    fn start_stream_processor(
+        &self,
+        input: StartStreamProcessorRequest,
+    ) -> RusotoFuture<StartStreamProcessorResponse, StartStreamProcessorError> {
+        (**self).start_stream_processor(input)
+    }
+    fn stop_stream_processor(
+        &self,
+        input: StopStreamProcessorRequest,
+    ) -> RusotoFuture<StopStreamProcessorResponse, StopStreamProcessorError> {
+        (**self).stop_stream_processor(input)
+    }
+}
+/// The error codes Amazon Rekognition retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the Amazon Rekognition API.
 #[derive(Clone)]
 pub struct RekognitionClient {
@@ -4778,6 +8005,27 @@ impl RekognitionClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_REKOGNITION`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> RekognitionClient {
+        Self::new(region::Region::from_env_for_service("REKOGNITION"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `rekognition`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> RekognitionClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("rekognition", "REKOGNITION"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4796,7 +8044,54 @@ impl RekognitionClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> RekognitionClient {
-        RekognitionClient { client, region }
+        RekognitionClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "rekognition")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "rekognition",
+            "rekognition",
+            "REKOGNITION",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `Rekognition` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn Rekognition + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for RekognitionClient {
+    fn service_name(&self) -> &'static str {
+        "Amazon Rekognition"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "rekognition"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> RekognitionClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,35 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Resource Groups clients.
+//!
+//! Makes a single read-only `ListGroups` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_resource_groups::{ListGroupsInput, ResourceGroups, ResourceGroupsClient};
+
+fn main() {
+    let client = ResourceGroupsClient::from_env();
+    match client.list_groups(ListGroupsInput::default()).sync() {
+        Ok(_) => println!("ListGroups succeeded"),
+        Err(error) => {
+            eprintln!("ListGroups failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
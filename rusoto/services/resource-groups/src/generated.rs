@@ -23,8 +23,9 @@ use std::fmt;
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateGroupInput {
     /// <p>The description of the resource group. Descriptions can have a maximum of 511 characters, including letters, numbers, hyphens, underscores, punctuation, and spaces.</p>
     #[serde(rename = "Description")]
@@ -42,6 +43,62 @@ pub struct CreateGroupInput {
     pub tags: Option<::std::collections::HashMap<String, String>>,
 }
 
+impl CreateGroupInput {
+    /// Returns a builder for `CreateGroupInput`, checking its required members when built.
+    pub fn builder() -> CreateGroupInputBuilder {
+        CreateGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`CreateGroupInput`](struct.CreateGroupInput.html).
+#[derive(Default, Debug)]
+pub struct CreateGroupInputBuilder {
+    description: Option<String>,
+    name: Option<String>,
+    resource_query: Option<ResourceQuery>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl CreateGroupInputBuilder {
+    /// Sets the [`description`](struct.CreateGroupInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`name`](struct.CreateGroupInput.html#structfield.name) member.
+    pub fn name<T: Into<String>>(mut self, value: T) -> Self {
+        self.name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_query`](struct.CreateGroupInput.html#structfield.resource_query) member.
+    pub fn resource_query<T: Into<ResourceQuery>>(mut self, value: T) -> Self {
+        self.resource_query = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.CreateGroupInput.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `CreateGroupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<CreateGroupInput, ::rusoto_core::BuildError> {
+        Ok(CreateGroupInput {
+            description: self.description,
+            name: self
+                .name
+                .ok_or(::rusoto_core::BuildError::missing("name"))?,
+            resource_query: self
+                .resource_query
+                .ok_or(::rusoto_core::BuildError::missing("resource_query"))?,
+            tags: self.tags,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct CreateGroupOutput {
@@ -66,6 +123,36 @@ pub struct DeleteGroupInput {
     pub group_name: String,
 }
 
+impl DeleteGroupInput {
+    /// Returns a builder for `DeleteGroupInput`, checking its required members when built.
+    pub fn builder() -> DeleteGroupInputBuilder {
+        DeleteGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`DeleteGroupInput`](struct.DeleteGroupInput.html).
+#[derive(Default, Debug)]
+pub struct DeleteGroupInputBuilder {
+    group_name: Option<String>,
+}
+
+impl DeleteGroupInputBuilder {
+    /// Sets the [`group_name`](struct.DeleteGroupInput.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `DeleteGroupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<DeleteGroupInput, ::rusoto_core::BuildError> {
+        Ok(DeleteGroupInput {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct DeleteGroupOutput {
@@ -82,6 +169,36 @@ pub struct GetGroupInput {
     pub group_name: String,
 }
 
+impl GetGroupInput {
+    /// Returns a builder for `GetGroupInput`, checking its required members when built.
+    pub fn builder() -> GetGroupInputBuilder {
+        GetGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetGroupInput`](struct.GetGroupInput.html).
+#[derive(Default, Debug)]
+pub struct GetGroupInputBuilder {
+    group_name: Option<String>,
+}
+
+impl GetGroupInputBuilder {
+    /// Sets the [`group_name`](struct.GetGroupInput.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGroupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGroupInput, ::rusoto_core::BuildError> {
+        Ok(GetGroupInput {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetGroupOutput {
@@ -98,6 +215,36 @@ pub struct GetGroupQueryInput {
     pub group_name: String,
 }
 
+impl GetGroupQueryInput {
+    /// Returns a builder for `GetGroupQueryInput`, checking its required members when built.
+    pub fn builder() -> GetGroupQueryInputBuilder {
+        GetGroupQueryInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetGroupQueryInput`](struct.GetGroupQueryInput.html).
+#[derive(Default, Debug)]
+pub struct GetGroupQueryInputBuilder {
+    group_name: Option<String>,
+}
+
+impl GetGroupQueryInputBuilder {
+    /// Sets the [`group_name`](struct.GetGroupQueryInput.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetGroupQueryInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetGroupQueryInput, ::rusoto_core::BuildError> {
+        Ok(GetGroupQueryInput {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetGroupQueryOutput {
@@ -114,6 +261,34 @@ pub struct GetTagsInput {
     pub arn: String,
 }
 
+impl GetTagsInput {
+    /// Returns a builder for `GetTagsInput`, checking its required members when built.
+    pub fn builder() -> GetTagsInputBuilder {
+        GetTagsInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetTagsInput`](struct.GetTagsInput.html).
+#[derive(Default, Debug)]
+pub struct GetTagsInputBuilder {
+    arn: Option<String>,
+}
+
+impl GetTagsInputBuilder {
+    /// Sets the [`arn`](struct.GetTagsInput.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTagsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTagsInput, ::rusoto_core::BuildError> {
+        Ok(GetTagsInput {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTagsOutput {
@@ -144,16 +319,94 @@ pub struct Group {
 }
 
 /// <p>A filter name and value pair that is used to obtain more specific results from a list of groups.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct GroupFilter {
     /// <p>The name of the filter. Filter names are case-sensitive.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: GroupFilterName,
     /// <p>One or more filter values. Allowed filter values vary by group filter name, and are case-sensitive.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+/// <p>The name of the filter. Filter names are case-sensitive.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GroupFilterName {
+    /// <p><code>resource-type</code></p>
+    ResourceType,
+    /// <p><code>configuration-type</code></p>
+    ConfigurationType,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl GroupFilterName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            GroupFilterName::ResourceType => "resource-type",
+            GroupFilterName::ConfigurationType => "configuration-type",
+            GroupFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for GroupFilterName {
+    fn from(value: &'a str) -> GroupFilterName {
+        match value {
+            "resource-type" => GroupFilterName::ResourceType,
+            "configuration-type" => GroupFilterName::ConfigurationType,
+            _ => GroupFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for GroupFilterName {
+    fn from(value: String) -> GroupFilterName {
+        GroupFilterName::from(value.as_str())
+    }
+}
+
+impl From<GroupFilterName> for String {
+    fn from(value: GroupFilterName) -> String {
+        match value {
+            GroupFilterName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GroupFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for GroupFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for GroupFilterName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for GroupFilterName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<GroupFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(GroupFilterName::from(value))
+    }
+}
+
 /// <p>The ARN and group name of a group.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -169,7 +422,7 @@ pub struct GroupIdentifier {
 }
 
 /// <p>The underlying resource query of a resource group. Resources that match query results are part of the group.</p>
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GroupQuery {
     /// <p>The name of a resource group that is associated with a specific resource query.</p>
@@ -199,6 +452,62 @@ pub struct ListGroupResourcesInput {
     pub next_token: Option<String>,
 }
 
+impl ListGroupResourcesInput {
+    /// Returns a builder for `ListGroupResourcesInput`, checking its required members when built.
+    pub fn builder() -> ListGroupResourcesInputBuilder {
+        ListGroupResourcesInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListGroupResourcesInput`](struct.ListGroupResourcesInput.html).
+#[derive(Default, Debug)]
+pub struct ListGroupResourcesInputBuilder {
+    filters: Option<Vec<ResourceFilter>>,
+    group_name: Option<String>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListGroupResourcesInputBuilder {
+    /// Sets the [`filters`](struct.ListGroupResourcesInput.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<ResourceFilter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.ListGroupResourcesInput.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListGroupResourcesInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListGroupResourcesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGroupResourcesInput`, failing if a required member hasn't been set.
+    pub fn build(
+        self,
+    ) -> ::std::result::Result<ListGroupResourcesInput, ::rusoto_core::BuildError> {
+        Ok(ListGroupResourcesInput {
+            filters: self.filters,
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGroupResourcesOutput {
@@ -232,6 +541,50 @@ pub struct ListGroupsInput {
     pub next_token: Option<String>,
 }
 
+impl ListGroupsInput {
+    /// Returns a builder for `ListGroupsInput`, checking its required members when built.
+    pub fn builder() -> ListGroupsInputBuilder {
+        ListGroupsInputBuilder::default()
+    }
+}
+
+/// Builds a [`ListGroupsInput`](struct.ListGroupsInput.html).
+#[derive(Default, Debug)]
+pub struct ListGroupsInputBuilder {
+    filters: Option<Vec<GroupFilter>>,
+    max_results: Option<i64>,
+    next_token: Option<String>,
+}
+
+impl ListGroupsInputBuilder {
+    /// Sets the [`filters`](struct.ListGroupsInput.html#structfield.filters) member.
+    pub fn filters<T: Into<Vec<GroupFilter>>>(mut self, value: T) -> Self {
+        self.filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`max_results`](struct.ListGroupsInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.ListGroupsInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `ListGroupsInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<ListGroupsInput, ::rusoto_core::BuildError> {
+        Ok(ListGroupsInput {
+            filters: self.filters,
+            max_results: self.max_results,
+            next_token: self.next_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct ListGroupsOutput {
@@ -252,24 +605,262 @@ pub struct QueryError {
     /// <p>Possible values are <code>CLOUDFORMATION_STACK_INACTIVE</code> and <code>CLOUDFORMATION_STACK_NOT_EXISTING</code>.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<QueryErrorCode>,
     /// <p>A message that explains the <code>ErrorCode</code> value. Messages might state that the specified CloudFormation stack does not exist (or no longer exists). For <code>CLOUDFORMATION_STACK_INACTIVE</code>, the message typically states that the CloudFormation stack has a status that is not (or no longer) active, such as <code>CREATE_FAILED</code>.</p>
     #[serde(rename = "Message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
 
+/// <p>Possible values are <code>CLOUDFORMATION_STACK_INACTIVE</code> and <code>CLOUDFORMATION_STACK_NOT_EXISTING</code>.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QueryErrorCode {
+    /// <p><code>CLOUDFORMATION_STACK_INACTIVE</code></p>
+    CloudformationStackInactive,
+    /// <p><code>CLOUDFORMATION_STACK_NOT_EXISTING</code></p>
+    CloudformationStackNotExisting,
+    /// <p><code>CLOUDFORMATION_STACK_UNASSUMABLE_ROLE</code></p>
+    CloudformationStackUnassumableRole,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl QueryErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueryErrorCode::CloudformationStackInactive => "CLOUDFORMATION_STACK_INACTIVE",
+            QueryErrorCode::CloudformationStackNotExisting => "CLOUDFORMATION_STACK_NOT_EXISTING",
+            QueryErrorCode::CloudformationStackUnassumableRole => {
+                "CLOUDFORMATION_STACK_UNASSUMABLE_ROLE"
+            }
+            QueryErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for QueryErrorCode {
+    fn from(value: &'a str) -> QueryErrorCode {
+        match value {
+            "CLOUDFORMATION_STACK_INACTIVE" => QueryErrorCode::CloudformationStackInactive,
+            "CLOUDFORMATION_STACK_NOT_EXISTING" => QueryErrorCode::CloudformationStackNotExisting,
+            "CLOUDFORMATION_STACK_UNASSUMABLE_ROLE" => {
+                QueryErrorCode::CloudformationStackUnassumableRole
+            }
+            _ => QueryErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueryErrorCode {
+    fn from(value: String) -> QueryErrorCode {
+        QueryErrorCode::from(value.as_str())
+    }
+}
+
+impl From<QueryErrorCode> for String {
+    fn from(value: QueryErrorCode) -> String {
+        match value {
+            QueryErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for QueryErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QueryErrorCode {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QueryErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QueryErrorCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<QueryErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(QueryErrorCode::from(value))
+    }
+}
+
+/// <p>The type of the query. The valid values in this release are <code>TAG_FILTERS_1_0</code> and <code>CLOUDFORMATION_STACK_1_0</code>.</p> <p> <i> <code>TAG_FILTERS_1_0:</code> </i> A JSON syntax that lets you specify a collection of simple tag filters for resource types and tags, as supported by the AWS Tagging API <a href="https://docs.aws.amazon.com/resourcegroupstagging/latest/APIReference/API_GetResources.html">GetResources</a> operation. If you specify more than one tag key, only resources that match all tag keys, and at least one value of each specified tag key, are returned in your query. If you specify more than one value for a tag key, a resource matches the filter if it has a tag key value that matches <i>any</i> of the specified values.</p> <p>For example, consider the following sample query for resources that have two tags, <code>Stage</code> and <code>Version</code>, with two values each. (<code>[{"Key":"Stage","Values":["Test","Deploy"]},{"Key":"Version","Values":["1","2"]}]</code>) The results of this query might include the following.</p> <ul> <li> <p>An EC2 instance that has the following two tags: <code>{"Key":"Stage","Value":"Deploy"}</code>, and <code>{"Key":"Version","Value":"2"}</code> </p> </li> <li> <p>An S3 bucket that has the following two tags: {"Key":"Stage","Value":"Test"}, and {"Key":"Version","Value":"1"}</p> </li> </ul> <p>The query would not return the following results, however. The following EC2 instance does not have all tag keys specified in the filter, so it is rejected. The RDS database has all of the tag keys, but no values that match at least one of the specified tag key values in the filter.</p> <ul> <li> <p>An EC2 instance that has only the following tag: <code>{"Key":"Stage","Value":"Deploy"}</code>.</p> </li> <li> <p>An RDS database that has the following two tags: <code>{"Key":"Stage","Value":"Archived"}</code>, and <code>{"Key":"Version","Value":"4"}</code> </p> </li> </ul> <p> <i> <code>CLOUDFORMATION_STACK_1_0:</code> </i> A JSON syntax that lets you specify a CloudFormation stack ARN.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QueryType {
+    /// <p><code>TAG_FILTERS_1_0</code></p>
+    TagFilters10,
+    /// <p><code>CLOUDFORMATION_STACK_1_0</code></p>
+    CloudformationStack10,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl QueryType {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            QueryType::TagFilters10 => "TAG_FILTERS_1_0",
+            QueryType::CloudformationStack10 => "CLOUDFORMATION_STACK_1_0",
+            QueryType::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for QueryType {
+    fn from(value: &'a str) -> QueryType {
+        match value {
+            "TAG_FILTERS_1_0" => QueryType::TagFilters10,
+            "CLOUDFORMATION_STACK_1_0" => QueryType::CloudformationStack10,
+            _ => QueryType::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for QueryType {
+    fn from(value: String) -> QueryType {
+        QueryType::from(value.as_str())
+    }
+}
+
+impl From<QueryType> for String {
+    fn from(value: QueryType) -> String {
+        match value {
+            QueryType::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for QueryType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for QueryType {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for QueryType {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for QueryType {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<QueryType, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(QueryType::from(value))
+    }
+}
+
 /// <p>A filter name and value pair that is used to obtain more specific results from a list of resources.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ResourceFilter {
     /// <p>The name of the filter. Filter names are case-sensitive.</p>
     #[serde(rename = "Name")]
-    pub name: String,
+    pub name: ResourceFilterName,
     /// <p>One or more filter values. Allowed filter values vary by resource filter name, and are case-sensitive.</p>
     #[serde(rename = "Values")]
     pub values: Vec<String>,
 }
 
+/// <p>The name of the filter. Filter names are case-sensitive.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceFilterName {
+    /// <p><code>resource-type</code></p>
+    ResourceType,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ResourceFilterName {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ResourceFilterName::ResourceType => "resource-type",
+            ResourceFilterName::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ResourceFilterName {
+    fn from(value: &'a str) -> ResourceFilterName {
+        match value {
+            "resource-type" => ResourceFilterName::ResourceType,
+            _ => ResourceFilterName::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ResourceFilterName {
+    fn from(value: String) -> ResourceFilterName {
+        ResourceFilterName::from(value.as_str())
+    }
+}
+
+impl From<ResourceFilterName> for String {
+    fn from(value: ResourceFilterName) -> String {
+        match value {
+            ResourceFilterName::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ResourceFilterName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::rusoto_core::param::ToParam for ResourceFilterName {
+    fn to_param(&self) -> String {
+        self.as_str().to_owned()
+    }
+}
+
+impl ::serde::Serialize for ResourceFilterName {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ResourceFilterName {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ResourceFilterName, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ResourceFilterName::from(value))
+    }
+}
+
 /// <p>The ARN of a resource, and its resource type.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -285,17 +876,17 @@ pub struct ResourceIdentifier {
 }
 
 /// <p>The query that is used to define a resource group or a search for resources.</p>
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResourceQuery {
     /// <p>The query that defines a group or a search.</p>
     #[serde(rename = "Query")]
     pub query: String,
     /// <p>The type of the query. The valid values in this release are <code>TAG_FILTERS_1_0</code> and <code>CLOUDFORMATION_STACK_1_0</code>.</p> <p> <i> <code>TAG_FILTERS_1_0:</code> </i> A JSON syntax that lets you specify a collection of simple tag filters for resource types and tags, as supported by the AWS Tagging API <a href="https://docs.aws.amazon.com/resourcegroupstagging/latest/APIReference/API_GetResources.html">GetResources</a> operation. If you specify more than one tag key, only resources that match all tag keys, and at least one value of each specified tag key, are returned in your query. If you specify more than one value for a tag key, a resource matches the filter if it has a tag key value that matches <i>any</i> of the specified values.</p> <p>For example, consider the following sample query for resources that have two tags, <code>Stage</code> and <code>Version</code>, with two values each. (<code>[{"Key":"Stage","Values":["Test","Deploy"]},{"Key":"Version","Values":["1","2"]}]</code>) The results of this query might include the following.</p> <ul> <li> <p>An EC2 instance that has the following two tags: <code>{"Key":"Stage","Value":"Deploy"}</code>, and <code>{"Key":"Version","Value":"2"}</code> </p> </li> <li> <p>An S3 bucket that has the following two tags: {"Key":"Stage","Value":"Test"}, and {"Key":"Version","Value":"1"}</p> </li> </ul> <p>The query would not return the following results, however. The following EC2 instance does not have all tag keys specified in the filter, so it is rejected. The RDS database has all of the tag keys, but no values that match at least one of the specified tag key values in the filter.</p> <ul> <li> <p>An EC2 instance that has only the following tag: <code>{"Key":"Stage","Value":"Deploy"}</code>.</p> </li> <li> <p>An RDS database that has the following two tags: <code>{"Key":"Stage","Value":"Archived"}</code>, and <code>{"Key":"Version","Value":"4"}</code> </p> </li> </ul> <p> <i> <code>CLOUDFORMATION_STACK_1_0:</code> </i> A JSON syntax that lets you specify a CloudFormation stack ARN.</p>
     #[serde(rename = "Type")]
-    pub type_: String,
+    pub type_: QueryType,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SearchResourcesInput {
     /// <p>The maximum number of group member ARNs returned by <code>SearchResources</code> in paginated output. By default, this number is 50.</p>
     #[serde(rename = "MaxResults")]
@@ -310,6 +901,52 @@ pub struct SearchResourcesInput {
     pub resource_query: ResourceQuery,
 }
 
+impl SearchResourcesInput {
+    /// Returns a builder for `SearchResourcesInput`, checking its required members when built.
+    pub fn builder() -> SearchResourcesInputBuilder {
+        SearchResourcesInputBuilder::default()
+    }
+}
+
+/// Builds a [`SearchResourcesInput`](struct.SearchResourcesInput.html).
+#[derive(Default, Debug)]
+pub struct SearchResourcesInputBuilder {
+    max_results: Option<i64>,
+    next_token: Option<String>,
+    resource_query: Option<ResourceQuery>,
+}
+
+impl SearchResourcesInputBuilder {
+    /// Sets the [`max_results`](struct.SearchResourcesInput.html#structfield.max_results) member.
+    pub fn max_results<T: Into<i64>>(mut self, value: T) -> Self {
+        self.max_results = Some(value.into());
+        self
+    }
+
+    /// Sets the [`next_token`](struct.SearchResourcesInput.html#structfield.next_token) member.
+    pub fn next_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.next_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_query`](struct.SearchResourcesInput.html#structfield.resource_query) member.
+    pub fn resource_query<T: Into<ResourceQuery>>(mut self, value: T) -> Self {
+        self.resource_query = Some(value.into());
+        self
+    }
+
+    /// Builds the `SearchResourcesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<SearchResourcesInput, ::rusoto_core::BuildError> {
+        Ok(SearchResourcesInput {
+            max_results: self.max_results,
+            next_token: self.next_token,
+            resource_query: self
+                .resource_query
+                .ok_or(::rusoto_core::BuildError::missing("resource_query"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct SearchResourcesOutput {
@@ -337,6 +974,44 @@ pub struct TagInput {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagInput {
+    /// Returns a builder for `TagInput`, checking its required members when built.
+    pub fn builder() -> TagInputBuilder {
+        TagInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagInput`](struct.TagInput.html).
+#[derive(Default, Debug)]
+pub struct TagInputBuilder {
+    arn: Option<String>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagInputBuilder {
+    /// Sets the [`arn`](struct.TagInput.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagInput.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagInput, ::rusoto_core::BuildError> {
+        Ok(TagInput {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagOutput {
@@ -360,6 +1035,44 @@ pub struct UntagInput {
     pub keys: Vec<String>,
 }
 
+impl UntagInput {
+    /// Returns a builder for `UntagInput`, checking its required members when built.
+    pub fn builder() -> UntagInputBuilder {
+        UntagInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagInput`](struct.UntagInput.html).
+#[derive(Default, Debug)]
+pub struct UntagInputBuilder {
+    arn: Option<String>,
+    keys: Option<Vec<String>>,
+}
+
+impl UntagInputBuilder {
+    /// Sets the [`arn`](struct.UntagInput.html#structfield.arn) member.
+    pub fn arn<T: Into<String>>(mut self, value: T) -> Self {
+        self.arn = Some(value.into());
+        self
+    }
+
+    /// Sets the [`keys`](struct.UntagInput.html#structfield.keys) member.
+    pub fn keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagInput, ::rusoto_core::BuildError> {
+        Ok(UntagInput {
+            arn: self.arn.ok_or(::rusoto_core::BuildError::missing("arn"))?,
+            keys: self
+                .keys
+                .ok_or(::rusoto_core::BuildError::missing("keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagOutput {
@@ -384,6 +1097,44 @@ pub struct UpdateGroupInput {
     pub group_name: String,
 }
 
+impl UpdateGroupInput {
+    /// Returns a builder for `UpdateGroupInput`, checking its required members when built.
+    pub fn builder() -> UpdateGroupInputBuilder {
+        UpdateGroupInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGroupInput`](struct.UpdateGroupInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateGroupInputBuilder {
+    description: Option<String>,
+    group_name: Option<String>,
+}
+
+impl UpdateGroupInputBuilder {
+    /// Sets the [`description`](struct.UpdateGroupInput.html#structfield.description) member.
+    pub fn description<T: Into<String>>(mut self, value: T) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    /// Sets the [`group_name`](struct.UpdateGroupInput.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGroupInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateGroupInput, ::rusoto_core::BuildError> {
+        Ok(UpdateGroupInput {
+            description: self.description,
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateGroupOutput {
@@ -393,7 +1144,7 @@ pub struct UpdateGroupOutput {
     pub group: Option<Group>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateGroupQueryInput {
     /// <p>The name of the resource group for which you want to edit the query.</p>
     #[serde(rename = "GroupName")]
@@ -403,6 +1154,46 @@ pub struct UpdateGroupQueryInput {
     pub resource_query: ResourceQuery,
 }
 
+impl UpdateGroupQueryInput {
+    /// Returns a builder for `UpdateGroupQueryInput`, checking its required members when built.
+    pub fn builder() -> UpdateGroupQueryInputBuilder {
+        UpdateGroupQueryInputBuilder::default()
+    }
+}
+
+/// Builds a [`UpdateGroupQueryInput`](struct.UpdateGroupQueryInput.html).
+#[derive(Default, Debug)]
+pub struct UpdateGroupQueryInputBuilder {
+    group_name: Option<String>,
+    resource_query: Option<ResourceQuery>,
+}
+
+impl UpdateGroupQueryInputBuilder {
+    /// Sets the [`group_name`](struct.UpdateGroupQueryInput.html#structfield.group_name) member.
+    pub fn group_name<T: Into<String>>(mut self, value: T) -> Self {
+        self.group_name = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_query`](struct.UpdateGroupQueryInput.html#structfield.resource_query) member.
+    pub fn resource_query<T: Into<ResourceQuery>>(mut self, value: T) -> Self {
+        self.resource_query = Some(value.into());
+        self
+    }
+
+    /// Builds the `UpdateGroupQueryInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UpdateGroupQueryInput, ::rusoto_core::BuildError> {
+        Ok(UpdateGroupQueryInput {
+            group_name: self
+                .group_name
+                .ok_or(::rusoto_core::BuildError::missing("group_name"))?,
+            resource_query: self
+                .resource_query
+                .ok_or(::rusoto_core::BuildError::missing("resource_query"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UpdateGroupQueryOutput {
@@ -1222,6 +2013,75 @@ pub trait ResourceGroups {
         input: UpdateGroupQueryInput,
     ) -> RusotoFuture<UpdateGroupQueryOutput, UpdateGroupQueryError>;
 }
+impl<T: ?Sized + ResourceGroups> ResourceGroups for ::std::sync::Arc<T> {
+    fn create_group(
+        &self,
+        input: CreateGroupInput,
+    ) -> RusotoFuture<CreateGroupOutput, CreateGroupError> {
+        (**self).create_group(input)
+    }
+    fn delete_group(
+        &self,
+        input: DeleteGroupInput,
+    ) -> RusotoFuture<DeleteGroupOutput, DeleteGroupError> {
+        (**self).delete_group(input)
+    }
+    fn get_group(&self, input: GetGroupInput) -> RusotoFuture<GetGroupOutput, GetGroupError> {
+        (**self).get_group(input)
+    }
+    fn get_group_query(
+        &self,
+        input: GetGroupQueryInput,
+    ) -> RusotoFuture<GetGroupQueryOutput, GetGroupQueryError> {
+        (**self).get_group_query(input)
+    }
+    fn get_tags(&self, input: GetTagsInput) -> RusotoFuture<GetTagsOutput, GetTagsError> {
+        (**self).get_tags(input)
+    }
+    fn list_group_resources(
+        &self,
+        input: ListGroupResourcesInput,
+    ) -> RusotoFuture<ListGroupResourcesOutput, ListGroupResourcesError> {
+        (**self).list_group_resources(input)
+    }
+    fn list_groups(
+        &self,
+        input: ListGroupsInput,
+    ) -> RusotoFuture<ListGroupsOutput, ListGroupsError> {
+        (**self).list_groups(input)
+    }
+    fn search_resources(
+        &self,
+        input: SearchResourcesInput,
+    ) -> RusotoFuture<SearchResourcesOutput, SearchResourcesError> {
+        (**self).search_resources(input)
+    }
+    fn tag(&self, input: TagInput) -> RusotoFuture<TagOutput, TagError> {
+        (**self).tag(input)
+    }
+    fn untag(&self, input: UntagInput) -> RusotoFuture<UntagOutput, UntagError> {
+        (**self).untag(input)
+    }
+    fn update_group(
+        &self,
+        input: UpdateGroupInput,
+    ) -> RusotoFuture<UpdateGroupOutput, UpdateGroupError> {
+        (**self).update_group(input)
+    }
+    fn update_group_query(
+        &self,
+        input: UpdateGroupQueryInput,
+    ) -> RusotoFuture<UpdateGroupQueryOutput, UpdateGroupQueryError> {
+        (**self).update_group_query(input)
+    }
+}
+/// The error codes Resource Groups retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &["TooManyRequestsException"],
+        transient: &[],
+    };
+
 /// A client for the Resource Groups API.
 #[derive(Clone)]
 pub struct ResourceGroupsClient {
@@ -1237,6 +2097,27 @@ impl ResourceGroupsClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_RESOURCE_GROUPS`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ResourceGroupsClient {
+        Self::new(region::Region::from_env_for_service("RESOURCE_GROUPS"))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `resource-groups`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ResourceGroupsClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("resource-groups", "RESOURCE_GROUPS"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1255,7 +2136,54 @@ impl ResourceGroupsClient {
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ResourceGroupsClient {
-        ResourceGroupsClient { client, region }
+        ResourceGroupsClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "resource-groups")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "resource-groups",
+            "resource-groups",
+            "RESOURCE_GROUPS",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ResourceGroups` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ResourceGroups + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ResourceGroupsClient {
+    fn service_name(&self) -> &'static str {
+        "Resource Groups"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "resource-groups"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ResourceGroupsClient {
+        Self::new_with_client(client, region)
     }
 }
 
@@ -0,0 +1,37 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of AWS Resource Groups Tagging API clients.
+//!
+//! Makes a single read-only `GetResources` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_resourcegroupstaggingapi::{
+    GetResourcesInput, ResourceGroupsTaggingApi, ResourceGroupsTaggingApiClient,
+};
+
+fn main() {
+    let client = ResourceGroupsTaggingApiClient::from_env();
+    match client.get_resources(GetResourcesInput::default()).sync() {
+        Ok(_) => println!("GetResources succeeded"),
+        Err(error) => {
+            eprintln!("GetResources failed: {}", error);
+            process::exit(1);
+        }
+    }
+}
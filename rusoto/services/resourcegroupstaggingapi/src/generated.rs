@@ -22,7 +22,80 @@ use std::fmt;
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
+use rusoto_core::timestamp::EpochTimestamp;
 use serde_json;
+/// <p>The code of the common error. Valid values include <code>InternalServiceException</code>, <code>InvalidParameterException</code>, and any valid error code returned by the AWS service that hosts the resource that you want to tag.</p>
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    /// <p><code>InternalServiceException</code></p>
+    InternalServiceException,
+    /// <p><code>InvalidParameterException</code></p>
+    InvalidParameterException,
+    /// A value which wasn't known when this crate was generated
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The value as sent to and received from AWS.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ErrorCode::InternalServiceException => "InternalServiceException",
+            ErrorCode::InvalidParameterException => "InvalidParameterException",
+            ErrorCode::Unknown(ref value) => value,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ErrorCode {
+    fn from(value: &'a str) -> ErrorCode {
+        match value {
+            "InternalServiceException" => ErrorCode::InternalServiceException,
+            "InvalidParameterException" => ErrorCode::InvalidParameterException,
+            _ => ErrorCode::Unknown(value.to_owned()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(value: String) -> ErrorCode {
+        ErrorCode::from(value.as_str())
+    }
+}
+
+impl From<ErrorCode> for String {
+    fn from(value: ErrorCode) -> String {
+        match value {
+            ErrorCode::Unknown(value) => value,
+            value => value.as_str().to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ::serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> ::std::result::Result<ErrorCode, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(ErrorCode::from(value))
+    }
+}
+
 /// <p>Details of the common errors that all actions return.</p>
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
@@ -30,7 +103,7 @@ pub struct FailureInfo {
     /// <p>The code of the common error. Valid values include <code>InternalServiceException</code>, <code>InvalidParameterException</code>, and any valid error code returned by the AWS service that hosts the resource that you want to tag.</p>
     #[serde(rename = "ErrorCode")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// <p>The message of the common error.</p>
     #[serde(rename = "ErrorMessage")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -65,6 +138,66 @@ pub struct GetResourcesInput {
     pub tags_per_page: Option<i64>,
 }
 
+impl GetResourcesInput {
+    /// Returns a builder for `GetResourcesInput`, checking its required members when built.
+    pub fn builder() -> GetResourcesInputBuilder {
+        GetResourcesInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetResourcesInput`](struct.GetResourcesInput.html).
+#[derive(Default, Debug)]
+pub struct GetResourcesInputBuilder {
+    pagination_token: Option<String>,
+    resource_type_filters: Option<Vec<String>>,
+    resources_per_page: Option<i64>,
+    tag_filters: Option<Vec<TagFilter>>,
+    tags_per_page: Option<i64>,
+}
+
+impl GetResourcesInputBuilder {
+    /// Sets the [`pagination_token`](struct.GetResourcesInput.html#structfield.pagination_token) member.
+    pub fn pagination_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.pagination_token = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resource_type_filters`](struct.GetResourcesInput.html#structfield.resource_type_filters) member.
+    pub fn resource_type_filters<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_type_filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`resources_per_page`](struct.GetResourcesInput.html#structfield.resources_per_page) member.
+    pub fn resources_per_page<T: Into<i64>>(mut self, value: T) -> Self {
+        self.resources_per_page = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_filters`](struct.GetResourcesInput.html#structfield.tag_filters) member.
+    pub fn tag_filters<T: Into<Vec<TagFilter>>>(mut self, value: T) -> Self {
+        self.tag_filters = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags_per_page`](struct.GetResourcesInput.html#structfield.tags_per_page) member.
+    pub fn tags_per_page<T: Into<i64>>(mut self, value: T) -> Self {
+        self.tags_per_page = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetResourcesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetResourcesInput, ::rusoto_core::BuildError> {
+        Ok(GetResourcesInput {
+            pagination_token: self.pagination_token,
+            resource_type_filters: self.resource_type_filters,
+            resources_per_page: self.resources_per_page,
+            tag_filters: self.tag_filters,
+            tags_per_page: self.tags_per_page,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetResourcesOutput {
@@ -86,6 +219,34 @@ pub struct GetTagKeysInput {
     pub pagination_token: Option<String>,
 }
 
+impl GetTagKeysInput {
+    /// Returns a builder for `GetTagKeysInput`, checking its required members when built.
+    pub fn builder() -> GetTagKeysInputBuilder {
+        GetTagKeysInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetTagKeysInput`](struct.GetTagKeysInput.html).
+#[derive(Default, Debug)]
+pub struct GetTagKeysInputBuilder {
+    pagination_token: Option<String>,
+}
+
+impl GetTagKeysInputBuilder {
+    /// Sets the [`pagination_token`](struct.GetTagKeysInput.html#structfield.pagination_token) member.
+    pub fn pagination_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.pagination_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTagKeysInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTagKeysInput, ::rusoto_core::BuildError> {
+        Ok(GetTagKeysInput {
+            pagination_token: self.pagination_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTagKeysOutput {
@@ -110,6 +271,42 @@ pub struct GetTagValuesInput {
     pub pagination_token: Option<String>,
 }
 
+impl GetTagValuesInput {
+    /// Returns a builder for `GetTagValuesInput`, checking its required members when built.
+    pub fn builder() -> GetTagValuesInputBuilder {
+        GetTagValuesInputBuilder::default()
+    }
+}
+
+/// Builds a [`GetTagValuesInput`](struct.GetTagValuesInput.html).
+#[derive(Default, Debug)]
+pub struct GetTagValuesInputBuilder {
+    key: Option<String>,
+    pagination_token: Option<String>,
+}
+
+impl GetTagValuesInputBuilder {
+    /// Sets the [`key`](struct.GetTagValuesInput.html#structfield.key) member.
+    pub fn key<T: Into<String>>(mut self, value: T) -> Self {
+        self.key = Some(value.into());
+        self
+    }
+
+    /// Sets the [`pagination_token`](struct.GetTagValuesInput.html#structfield.pagination_token) member.
+    pub fn pagination_token<T: Into<String>>(mut self, value: T) -> Self {
+        self.pagination_token = Some(value.into());
+        self
+    }
+
+    /// Builds the `GetTagValuesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<GetTagValuesInput, ::rusoto_core::BuildError> {
+        Ok(GetTagValuesInput {
+            key: self.key.ok_or(::rusoto_core::BuildError::missing("key"))?,
+            pagination_token: self.pagination_token,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct GetTagValuesOutput {
@@ -172,6 +369,46 @@ pub struct TagResourcesInput {
     pub tags: ::std::collections::HashMap<String, String>,
 }
 
+impl TagResourcesInput {
+    /// Returns a builder for `TagResourcesInput`, checking its required members when built.
+    pub fn builder() -> TagResourcesInputBuilder {
+        TagResourcesInputBuilder::default()
+    }
+}
+
+/// Builds a [`TagResourcesInput`](struct.TagResourcesInput.html).
+#[derive(Default, Debug)]
+pub struct TagResourcesInputBuilder {
+    resource_arn_list: Option<Vec<String>>,
+    tags: Option<::std::collections::HashMap<String, String>>,
+}
+
+impl TagResourcesInputBuilder {
+    /// Sets the [`resource_arn_list`](struct.TagResourcesInput.html#structfield.resource_arn_list) member.
+    pub fn resource_arn_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arn_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tags`](struct.TagResourcesInput.html#structfield.tags) member.
+    pub fn tags<T: Into<::std::collections::HashMap<String, String>>>(mut self, value: T) -> Self {
+        self.tags = Some(value.into());
+        self
+    }
+
+    /// Builds the `TagResourcesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<TagResourcesInput, ::rusoto_core::BuildError> {
+        Ok(TagResourcesInput {
+            resource_arn_list: self
+                .resource_arn_list
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn_list"))?,
+            tags: self
+                .tags
+                .ok_or(::rusoto_core::BuildError::missing("tags"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct TagResourcesOutput {
@@ -191,6 +428,46 @@ pub struct UntagResourcesInput {
     pub tag_keys: Vec<String>,
 }
 
+impl UntagResourcesInput {
+    /// Returns a builder for `UntagResourcesInput`, checking its required members when built.
+    pub fn builder() -> UntagResourcesInputBuilder {
+        UntagResourcesInputBuilder::default()
+    }
+}
+
+/// Builds a [`UntagResourcesInput`](struct.UntagResourcesInput.html).
+#[derive(Default, Debug)]
+pub struct UntagResourcesInputBuilder {
+    resource_arn_list: Option<Vec<String>>,
+    tag_keys: Option<Vec<String>>,
+}
+
+impl UntagResourcesInputBuilder {
+    /// Sets the [`resource_arn_list`](struct.UntagResourcesInput.html#structfield.resource_arn_list) member.
+    pub fn resource_arn_list<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.resource_arn_list = Some(value.into());
+        self
+    }
+
+    /// Sets the [`tag_keys`](struct.UntagResourcesInput.html#structfield.tag_keys) member.
+    pub fn tag_keys<T: Into<Vec<String>>>(mut self, value: T) -> Self {
+        self.tag_keys = Some(value.into());
+        self
+    }
+
+    /// Builds the `UntagResourcesInput`, failing if a required member hasn't been set.
+    pub fn build(self) -> ::std::result::Result<UntagResourcesInput, ::rusoto_core::BuildError> {
+        Ok(UntagResourcesInput {
+            resource_arn_list: self
+                .resource_arn_list
+                .ok_or(::rusoto_core::BuildError::missing("resource_arn_list"))?,
+            tag_keys: self
+                .tag_keys
+                .ok_or(::rusoto_core::BuildError::missing("tag_keys"))?,
+        })
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
 #[cfg_attr(test, derive(Serialize))]
 pub struct UntagResourcesOutput {
@@ -475,6 +752,45 @@ pub trait ResourceGroupsTaggingApi {
         input: UntagResourcesInput,
     ) -> RusotoFuture<UntagResourcesOutput, UntagResourcesError>;
 }
+impl<T: ?Sized + ResourceGroupsTaggingApi> ResourceGroupsTaggingApi for ::std::sync::Arc<T> {
+    fn get_resources(
+        &self,
+        input: GetResourcesInput,
+    ) -> RusotoFuture<GetResourcesOutput, GetResourcesError> {
+        (**self).get_resources(input)
+    }
+    fn get_tag_keys(
+        &self,
+        input: GetTagKeysInput,
+    ) -> RusotoFuture<GetTagKeysOutput, GetTagKeysError> {
+        (**self).get_tag_keys(input)
+    }
+    fn get_tag_values(
+        &self,
+        input: GetTagValuesInput,
+    ) -> RusotoFuture<GetTagValuesOutput, GetTagValuesError> {
+        (**self).get_tag_values(input)
+    }
+    fn tag_resources(
+        &self,
+        input: TagResourcesInput,
+    ) -> RusotoFuture<TagResourcesOutput, TagResourcesError> {
+        (**self).tag_resources(input)
+    }
+    fn untag_resources(
+        &self,
+        input: UntagResourcesInput,
+    ) -> RusotoFuture<UntagResourcesOutput, UntagResourcesError> {
+        (**self).untag_resources(input)
+    }
+}
+/// The error codes AWS Resource Groups Tagging API retries, in addition to the ones retried for every service.
+pub const RETRY_CODES: rusoto_core::retry::ServiceRetryCodes =
+    rusoto_core::retry::ServiceRetryCodes {
+        throttling: &[],
+        transient: &[],
+    };
+
 /// A client for the AWS Resource Groups Tagging API API.
 #[derive(Clone)]
 pub struct ResourceGroupsTaggingApiClient {
@@ -490,6 +806,29 @@ impl ResourceGroupsTaggingApiClient {
         Self::new_with_client(Client::shared(), region)
     }
 
+    /// Creates a client with its region, credentials and endpoint resolved from the environment.
+    ///
+    /// The region and credentials are resolved as for `Region::default()` and the default
+    /// credentials provider. The endpoint can be overridden with the `AWS_ENDPOINT_URL_RESOURCEGROUPSTAGGINGAPI`
+    /// or `AWS_ENDPOINT_URL` environment variables.
+    pub fn from_env() -> ResourceGroupsTaggingApiClient {
+        Self::new(region::Region::from_env_for_service(
+            "RESOURCEGROUPSTAGGINGAPI",
+        ))
+    }
+
+    /// Creates a client with the credentials, retries, timeouts, `User-Agent` and proxy of
+    /// `config`.
+    ///
+    /// Requests are sent to the region of `config`, or to the one `from_env` resolves if it
+    /// sets none, at the endpoint its endpoint resolver returns for `tagging`, if any.
+    pub fn from_config(config: &rusoto_core::config::Config) -> ResourceGroupsTaggingApiClient {
+        Self::new_with_client(
+            config.client().clone(),
+            config.region_for("tagging", "RESOURCEGROUPSTAGGINGAPI"),
+        )
+    }
+
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -511,7 +850,54 @@ impl ResourceGroupsTaggingApiClient {
         client: Client,
         region: region::Region,
     ) -> ResourceGroupsTaggingApiClient {
-        ResourceGroupsTaggingApiClient { client, region }
+        ResourceGroupsTaggingApiClient {
+            client: client.with_retry_codes(RETRY_CODES),
+            region,
+        }
+    }
+
+    /// Returns the scheme and host this client sends its requests to.
+    pub fn regional_endpoint(&self) -> String {
+        rusoto_core::config::regional_endpoint(&self.region, "tagging")
+    }
+
+    /// Returns the configuration this client sends requests with, and where each value came from.
+    pub fn effective_config(&self) -> rusoto_core::config::EffectiveConfig {
+        rusoto_core::config::EffectiveConfig::resolve(
+            &self.region,
+            "tagging",
+            "tagging",
+            "RESOURCEGROUPSTAGGINGAPI",
+        )
+        .with_client(&self.client)
+    }
+
+    /// Moves the client behind a shared `ResourceGroupsTaggingApi` trait object, so it can be
+    /// passed around interchangeably with other implementations such as mocks.
+    pub fn into_shared(self) -> ::std::sync::Arc<dyn ResourceGroupsTaggingApi + Send + Sync> {
+        ::std::sync::Arc::new(self)
+    }
+}
+
+impl rusoto_core::AwsService for ResourceGroupsTaggingApiClient {
+    fn service_name(&self) -> &'static str {
+        "AWS Resource Groups Tagging API"
+    }
+
+    fn endpoint_prefix(&self) -> &'static str {
+        "tagging"
+    }
+
+    fn region(&self) -> &region::Region {
+        &self.region
+    }
+
+    fn client(&self) -> &Client {
+        &self.client
+    }
+
+    fn from_client(client: Client, region: region::Region) -> ResourceGroupsTaggingApiClient {
+        Self::new_with_client(client, region)
     }
 }
 
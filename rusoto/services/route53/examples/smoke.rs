@@ -0,0 +1,38 @@
+// =================================================================
+//
+//                           * WARNING *
+//
+//                    This file is generated!
+//
+//  Changes made to this file will be overwritten. If changes are
+//  required to the generated code, the service_crategen project
+//  must be updated to generate the changes.
+//
+// =================================================================
+
+//! Checks the configuration of Amazon Route 53 clients.
+//!
+//! Makes a single read-only `ListGeoLocations` call with the region,
+//! credentials and endpoint set in the environment:
+//!
+//! ```sh
+//! AWS_REGION=us-west-2 cargo run --example smoke --features smoke
+//! ```
+
+use std::process;
+
+use rusoto_route53::{ListGeoLocationsRequest, Route53, Route53Client};
+
+fn main() {
+    let client = Route53Client::from_env();
+    match client
+        .list_geo_locations(ListGeoLocationsRequest::default())
+        .sync()
+    {
+        Ok(_) => println!("ListGeoLocations succeeded"),
+        Err(error) => {
+            eprintln!("ListGeoLocations failed: {}", error);
+            process::exit(1);
+        }
+    }
+}